@@ -4,14 +4,18 @@ Learn more about Rust for Windows here: <https://github.com/microsoft/windows-rs
 
 mod args;
 mod error;
+mod manifest;
 mod metadata;
 mod rdl;
+mod reg;
 mod rust;
+mod scaffold;
 mod tokens;
 mod tree;
 mod winmd;
 
 pub use error::{Error, Result};
+pub use scaffold::scaffold;
 use tree::Tree;
 
 enum ArgKind {
@@ -120,7 +124,13 @@ where
         "rdl" => rdl::from_reader(reader, config, &output)?,
         "winmd" => winmd::from_reader(reader, config, &output)?,
         "rs" => rust::from_reader(reader, config, &output)?,
-        _ => return Err(Error::new("output extension must be one of winmd/rdl/rs")),
+        "manifest" => manifest::from_reader(reader, config, &output)?,
+        "reg" => reg::from_reader(reader, config, &output)?,
+        _ => {
+            return Err(Error::new(
+                "output extension must be one of winmd/rdl/rs/manifest/reg",
+            ))
+        }
     }
 
     let elapsed = time.elapsed().as_secs_f32();
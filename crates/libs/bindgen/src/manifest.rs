@@ -0,0 +1,165 @@
+use super::*;
+use metadata::HasAttributes;
+
+/// Writes the `activatableClass` and `comClass` entries needed for registration-free activation of the
+/// runtime classes and COM classes in the reader to an application manifest.
+pub fn from_reader(
+    reader: &metadata::Reader,
+    mut config: std::collections::BTreeMap<&str, &str>,
+    output: &str,
+) -> Result<()> {
+    // The component's DLL defaults to one named after the manifest, so `Component.manifest`
+    // describes the classes implemented by `Component.dll`.
+    let dll = match config.remove("dll") {
+        Some(dll) => dll.to_string(),
+        None => format!("{}.dll", output_stem(output)),
+    };
+
+    let com_threading_model = com_threading_model(&mut config)?;
+    let progid = progid_prefix(&mut config, output);
+
+    if let Some((key, _)) = config.first_key_value() {
+        return Err(Error::new(&format!("invalid configuration value `{key}`")));
+    }
+
+    let mut classes = vec![];
+
+    for item in reader.items() {
+        let metadata::Item::Type(def) = item else {
+            continue;
+        };
+
+        if def.kind() != metadata::TypeKind::Class
+            || !def
+                .flags()
+                .contains(metadata::TypeAttributes::WindowsRuntime)
+        {
+            continue;
+        }
+
+        // Only classes with an activation factory, whether for construction, statics, or composition,
+        // can be activated.
+        if !def.attributes().any(|attribute| {
+            matches!(
+                attribute.name(),
+                "ActivatableAttribute" | "StaticAttribute" | "ComposableAttribute"
+            )
+        }) {
+            continue;
+        }
+
+        classes.push((
+            format!("{}.{}", def.namespace(), def.name()),
+            threading_model(def),
+        ));
+    }
+
+    let com_classes = com_classes(reader);
+
+    if classes.is_empty() && com_classes.is_empty() {
+        return Err(Error::new("no activatable classes"));
+    }
+
+    classes.sort();
+    classes.dedup();
+
+    let mut manifest = String::from(
+        r#"<?xml version="1.0" encoding="utf-8" standalone="yes"?>
+<assembly xmlns="urn:schemas-microsoft-com:asm.v1" manifestVersion="1.0">
+"#,
+    );
+
+    manifest.push_str(&format!("  <file name=\"{dll}\">\n"));
+
+    for (name, clsid) in &com_classes {
+        manifest.push_str(&format!(
+            "    <comClass clsid=\"{clsid}\" threadingModel=\"{com_threading_model}\" progid=\"{progid}.{name}\" />\n"
+        ));
+    }
+
+    for (name, threading_model) in &classes {
+        manifest.push_str(&format!(
+            "    <activatableClass name=\"{name}\" threadingModel=\"{threading_model}\" xmlns=\"urn:schemas-microsoft-com:winrt.v1\" />\n"
+        ));
+    }
+
+    manifest.push_str("  </file>\n</assembly>\n");
+    write_to_file(output, manifest)
+}
+
+/// Returns the name and registry-formatted CLSID of the COM classes in the reader.
+///
+/// Win32 metadata describes a COM class as a struct without fields whose `GuidAttribute` is the class's CLSID.
+pub fn com_classes(reader: &metadata::Reader) -> Vec<(&'static str, String)> {
+    let mut classes = vec![];
+
+    for item in reader.items() {
+        let metadata::Item::Type(def) = item else {
+            continue;
+        };
+
+        if def.kind() != metadata::TypeKind::Struct
+            || def
+                .flags()
+                .contains(metadata::TypeAttributes::WindowsRuntime)
+            || def.fields().next().is_some()
+        {
+            continue;
+        }
+
+        if let Some(clsid) = metadata::type_def_guid(def) {
+            classes.push((def.name(), format!("{{{clsid:?}}}").to_uppercase()));
+        }
+    }
+
+    classes.sort();
+    classes.dedup();
+    classes
+}
+
+/// Returns the threading model of the COM classes, which may be configured with `threading=<model>`.
+pub fn com_threading_model(
+    config: &mut std::collections::BTreeMap<&str, &str>,
+) -> Result<&'static str> {
+    match config.remove("threading") {
+        None | Some("both") => Ok("Both"),
+        Some("apartment") => Ok("Apartment"),
+        Some("free") => Ok("Free"),
+        Some("neutral") => Ok("Neutral"),
+        Some(value) => Err(Error::new(&format!(
+            "invalid threading model `{value}`, expected one of both/apartment/free/neutral"
+        ))),
+    }
+}
+
+/// Returns the prefix of the COM classes' ProgIDs, which defaults to the name of the output file.
+pub fn progid_prefix(config: &mut std::collections::BTreeMap<&str, &str>, output: &str) -> String {
+    config
+        .remove("progid")
+        .unwrap_or_else(|| output_stem(output))
+        .to_string()
+}
+
+/// Returns the name of the output file without its directory or extension.
+pub fn output_stem(output: &str) -> &str {
+    let stem = output
+        .rsplit_once(['/', '\\'])
+        .map_or(output, |(_, name)| name);
+    stem.rsplit_once('.').map_or(stem, |(stem, _)| stem)
+}
+
+fn threading_model(def: metadata::TypeDef) -> &'static str {
+    let Some(attribute) = def.find_attribute("ThreadingAttribute") else {
+        return "both";
+    };
+
+    // The `Windows.Foundation.Metadata.ThreadingModel` values.
+    match attribute.args().first() {
+        Some((_, metadata::Value::EnumDef(_, value))) => match **value {
+            metadata::Value::I32(1) => "sta",
+            metadata::Value::I32(2) => "mta",
+            _ => "both",
+        },
+        _ => "both",
+    }
+}
@@ -0,0 +1,76 @@
+use super::*;
+use manifest::{com_classes, com_threading_model, output_stem, progid_prefix};
+
+/// Writes a registry script that registers the COM classes in the reader, along with their ProgIDs.
+pub fn from_reader(
+    reader: &metadata::Reader,
+    mut config: std::collections::BTreeMap<&str, &str>,
+    output: &str,
+) -> Result<()> {
+    // Classes are implemented by an in-process server by default, or by a local server with `exe=<path>`.
+    let (server, path) = match (config.remove("dll"), config.remove("exe")) {
+        (Some(_), Some(_)) => {
+            return Err(Error::new("only one of `dll` and `exe` may be specified"))
+        }
+        (None, Some(exe)) => ("LocalServer32", exe.to_string()),
+        (Some(dll), None) => ("InprocServer32", dll.to_string()),
+        (None, None) => ("InprocServer32", format!("{}.dll", output_stem(output))),
+    };
+
+    let threading_model = com_threading_model(&mut config)?;
+    let progid = progid_prefix(&mut config, output);
+
+    // Per-user registration doesn't require elevation, so it's the default.
+    let root = match config.remove("root") {
+        None | Some("hkcu") => r"HKEY_CURRENT_USER\Software\Classes",
+        Some("hklm") => r"HKEY_LOCAL_MACHINE\SOFTWARE\Classes",
+        Some(value) => {
+            return Err(Error::new(&format!(
+                "invalid registry root `{value}`, expected one of hkcu/hklm"
+            )))
+        }
+    };
+
+    if let Some((key, _)) = config.first_key_value() {
+        return Err(Error::new(&format!("invalid configuration value `{key}`")));
+    }
+
+    let classes = com_classes(reader);
+
+    if classes.is_empty() {
+        return Err(Error::new("no COM classes"));
+    }
+
+    let path = path.replace('\\', r"\\");
+    let mut reg = String::from("Windows Registry Editor Version 5.00\r\n");
+
+    for (name, clsid) in &classes {
+        let progid = format!("{progid}.{name}");
+        reg.push_str(&format!("\r\n[{root}\\CLSID\\{clsid}]\r\n@=\"{name}\"\r\n"));
+        reg.push_str(&format!(
+            "\r\n[{root}\\CLSID\\{clsid}\\{server}]\r\n@=\"{path}\"\r\n"
+        ));
+
+        // The threading model only applies to in-process servers.
+        if server == "InprocServer32" {
+            reg.push_str(&format!("\"ThreadingModel\"=\"{threading_model}\"\r\n"));
+        }
+
+        reg.push_str(&format!(
+            "\r\n[{root}\\CLSID\\{clsid}\\ProgID]\r\n@=\"{progid}\"\r\n"
+        ));
+        reg.push_str(&format!("\r\n[{root}\\{progid}]\r\n@=\"{name}\"\r\n"));
+        reg.push_str(&format!(
+            "\r\n[{root}\\{progid}\\CLSID]\r\n@=\"{clsid}\"\r\n"
+        ));
+    }
+
+    // Registry scripts with this header are read as UTF-16 with a byte order mark.
+    let bytes: Vec<u8> = [0xFEFF]
+        .into_iter()
+        .chain(reg.encode_utf16())
+        .flat_map(u16::to_le_bytes)
+        .collect();
+
+    write_to_file(output, bytes)
+}
@@ -81,14 +81,21 @@ pub fn writer(writer: &Writer, def: metadata::TypeDef) -> TokenStream {
             impl windows_core::TypeKind for #ident {
                 type TypeKind = windows_core::CopyType;
             }
-            #features
-            impl core::fmt::Debug for #ident {
-                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                    f.debug_tuple(#name).field(&self.0).finish()
-                }
-            }
         });
 
+        if !writer.no_debug {
+            let cfg_debug = writer.cfg_debug();
+            tokens.combine(&quote! {
+                #features
+                #cfg_debug
+                impl core::fmt::Debug for #ident {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        f.debug_tuple(#name).field(&self.0).finish()
+                    }
+                }
+            });
+        }
+
         // Win32 enums use the Flags attribute. WinRT enums don't have the Flags attribute but are paritioned merely based
         // on whether they are signed.
         // TODO: Win32 metadata should just follow WinRT's example here.
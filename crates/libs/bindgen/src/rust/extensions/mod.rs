@@ -18,8 +18,137 @@ fn include_ext(relative_path: &str) -> TokenStream {
 }
 
 /// Generates extension code for a specific namespace
-pub fn gen_mod(_writer: &Writer, namespace: &str) -> TokenStream {
+pub fn gen_mod(writer: &Writer, namespace: &str) -> TokenStream {
     match namespace {
+        "Windows.Foundation" if writer.package && !writer.sys => [
+            "Foundation/Async.rs",
+            "Foundation/Deferral.rs",
+            "Foundation/Deferrals.rs",
+            "Foundation/EventStream.rs",
+            "Foundation/PropertyValue.rs",
+        ]
+        .into_iter()
+        .map(include_ext)
+        .collect(),
+        "Windows.Foundation.Collections" if writer.package && !writer.sys => [
+            "Foundation/Collections/Change.rs",
+            "Foundation/Collections/PropertySet.rs",
+        ]
+        .into_iter()
+        .map(include_ext)
+        .collect(),
+        "Windows.System" if writer.package && !writer.sys => {
+            include_ext("System/DispatcherQueue.rs")
+        }
+        "Windows.UI.Notifications" if writer.package && !writer.sys => {
+            include_ext("UI/Notifications/Toast.rs")
+        }
+        "Windows.Win32.Foundation" if writer.package && !writer.sys => {
+            include_ext("Win32/Foundation/RawWindow.rs")
+        }
+        "Windows.Win32.Graphics.Gdi" if writer.package && !writer.sys => {
+            include_ext("Win32/Graphics/Gdi/DeviceContext.rs")
+        }
+        "Windows.Win32.Networking.WinHttp" if writer.package && !writer.sys => {
+            include_ext("Win32/Networking/WinHttp/HttpSession.rs")
+        }
+        "Windows.Win32.Security" if writer.package && !writer.sys => [
+            "Win32/Security/SecurityDescriptor.rs",
+            "Win32/Security/Sid.rs",
+            "Win32/Security/Token.rs",
+        ]
+        .into_iter()
+        .map(include_ext)
+        .collect(),
+        "Windows.Win32.Security.Cryptography" if writer.package && !writer.sys => [
+            "Win32/Security/Cryptography/Bcrypt.rs",
+            "Win32/Security/Cryptography/DataProtection.rs",
+        ]
+        .into_iter()
+        .map(include_ext)
+        .collect(),
+        "Windows.Win32.System.Com" if writer.package && !writer.sys => [
+            "Win32/System/Com/Persist.rs",
+            "Win32/System/Com/Security.rs",
+        ]
+        .into_iter()
+        .map(include_ext)
+        .collect(),
+        "Windows.Win32.System.Console" if writer.package && !writer.sys => {
+            include_ext("Win32/System/Console/ConsoleInput.rs")
+        }
+        "Windows.Win32.System.DataExchange" if writer.package && !writer.sys => {
+            include_ext("Win32/System/DataExchange/Clipboard.rs")
+        }
+        "Windows.Win32.System.Diagnostics.Debug" if writer.package && !writer.sys => {
+            include_ext("Win32/System/Diagnostics/Debug/Seh.rs")
+        }
+        "Windows.Win32.System.Diagnostics.Etw" if writer.package && !writer.sys => {
+            include_ext("Win32/System/Diagnostics/Etw/TraceLogging.rs")
+        }
+        "Windows.Win32.System.Diagnostics.ToolHelp" if writer.package && !writer.sys => {
+            include_ext("Win32/System/Diagnostics/ToolHelp/Snapshot.rs")
+        }
+        "Windows.Win32.System.EventLog" if writer.package && !writer.sys => {
+            include_ext("Win32/System/EventLog/EventQuery.rs")
+        }
+        "Windows.Win32.System.IO" if writer.package && !writer.sys => {
+            include_ext("Win32/System/IO/CompletionPort.rs")
+        }
+        "Windows.Win32.System.JobObjects" if writer.package && !writer.sys => {
+            include_ext("Win32/System/JobObjects/Job.rs")
+        }
+        "Windows.Win32.System.Memory" if writer.package && !writer.sys => [
+            "Win32/System/Memory/FileMapping.rs",
+            "Win32/System/Memory/VirtualMemory.rs",
+        ]
+        .into_iter()
+        .map(include_ext)
+        .collect(),
+        "Windows.Win32.System.Pipes" if writer.package && !writer.sys => {
+            include_ext("Win32/System/Pipes/NamedPipe.rs")
+        }
+        "Windows.Win32.System.Ole" if writer.package && !writer.sys => {
+            include_ext("Win32/System/Ole/SafeArray.rs")
+        }
+        "Windows.Win32.System.Performance" if writer.package && !writer.sys => {
+            include_ext("Win32/System/Performance/CounterQuery.rs")
+        }
+        "Windows.Win32.System.Power" if writer.package && !writer.sys => {
+            include_ext("Win32/System/Power/PowerNotifications.rs")
+        }
+        "Windows.Win32.System.RemoteDesktop" if writer.package && !writer.sys => {
+            include_ext("Win32/System/RemoteDesktop/Session.rs")
+        }
+        "Windows.Win32.System.RestartManager" if writer.package && !writer.sys => {
+            include_ext("Win32/System/RestartManager/RestartSession.rs")
+        }
+        "Windows.Win32.System.Threading" if writer.package && !writer.sys => [
+            "Win32/System/Threading/Process.rs",
+            "Win32/System/Threading/WaitAsync.rs",
+        ]
+        .into_iter()
+        .map(include_ext)
+        .collect(),
+        "Windows.Win32.System.Variant" if writer.package && !writer.sys => {
+            include_ext("Win32/System/Variant/OleDate.rs")
+        }
+        "Windows.Win32.System.Wmi" if writer.package && !writer.sys => {
+            include_ext("Win32/System/Wmi/WmiConnection.rs")
+        }
+        "Windows.Win32.UI.Accessibility" if writer.package && !writer.sys => {
+            include_ext("Win32/UI/Accessibility/Automation.rs")
+        }
+        "Windows.Win32.UI.WindowsAndMessaging" if writer.package && !writer.sys => [
+            "Win32/UI/WindowsAndMessaging/Hook.rs",
+            "Win32/UI/WindowsAndMessaging/Msg.rs",
+            "Win32/UI/WindowsAndMessaging/Window.rs",
+            "Win32/UI/WindowsAndMessaging/WindowExt.rs",
+            "Win32/UI/WindowsAndMessaging/WindowLong.rs",
+        ]
+        .into_iter()
+        .map(include_ext)
+        .collect(),
         "Windows.Win32.UI.WindowsAndMessaging" => {
             include_ext("Win32/UI/WindowsAndMessaging/WindowLong.rs")
         }
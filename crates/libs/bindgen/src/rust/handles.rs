@@ -90,9 +90,19 @@ pub fn gen_win_handle(writer: &Writer, def: metadata::TypeDef) -> TokenStream {
         quote! {}
     };
 
+    let derive = if writer.derive_debug_inline() {
+        quote! { #[derive(Clone, Copy, Debug, PartialEq, Eq)] }
+    } else {
+        let debug = writer.derive_debug();
+        quote! {
+            #[derive(Clone, Copy, PartialEq, Eq)]
+            #debug
+        }
+    };
+
     let mut tokens = quote! {
         #[repr(transparent)]
-        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        #derive
         pub struct #ident(pub #signature);
         #is_invalid
         #free
@@ -12,6 +12,7 @@ mod index;
 mod interfaces;
 mod iterators;
 mod method_names;
+mod proxies;
 mod standalone;
 mod structs;
 mod try_format;
@@ -37,6 +38,8 @@ pub fn from_reader(
     writer.no_inner_attributes = config.remove("no-inner-attributes").is_some();
     writer.no_bindgen_comment = config.remove("no-bindgen-comment").is_some();
     writer.vtbl = config.remove("vtbl").is_some();
+    writer.no_debug = config.remove("no-debug").is_some();
+    writer.proxy = config.remove("proxy").is_some();
     writer.rustfmt_config = if let Some(config) = config.remove("rustfmt-config") {
         config.to_string()
     } else {
@@ -55,6 +58,12 @@ pub fn from_reader(
         ));
     }
 
+    if writer.proxy && writer.sys {
+        return Err(Error::new(
+            "cannot combine `proxy` and `sys` configuration values",
+        ));
+    }
+
     config.retain(|key, value| {
         if let Some(full_name) = key.strip_prefix("prepend:") {
             if let Some(index) = full_name.rfind('.') {
@@ -121,10 +130,20 @@ fn gen_package(writer: &Writer) -> Result<()> {
             String::new()
         };
 
+        let tokens_proxy = if !writer.sys {
+            namespace_proxy(writer, tree)
+        } else {
+            String::new()
+        };
+
         if !writer.sys && !tokens_impl.is_empty() {
             tokens.push_str("#[cfg(feature = \"implement\")]\ncore::include!(\"impl.rs\");\n");
         }
 
+        if !tokens_proxy.is_empty() {
+            tokens.push_str("#[cfg(feature = \"proxy\")]\ncore::include!(\"proxy.rs\");\n");
+        }
+
         let output = format!("{directory}/mod.rs");
         write_to_file(&output, try_format(writer, &tokens))?;
 
@@ -133,6 +152,11 @@ fn gen_package(writer: &Writer) -> Result<()> {
             write_to_file(&output, try_format(writer, &tokens_impl))?;
         }
 
+        if !tokens_proxy.is_empty() {
+            let output = format!("{directory}/proxy.rs");
+            write_to_file(&output, try_format(writer, &tokens_proxy))?;
+        }
+
         Ok::<(), Error>(())
     })?;
 
@@ -253,6 +277,10 @@ fn namespace(writer: &Writer, tree: &Tree) -> String {
         tokens.push_str(&namespace_impl(writer, tree));
     }
 
+    if writer.proxy {
+        tokens.push_str(&namespace_proxy(writer, tree));
+    }
+
     tokens.into_string()
 }
 
@@ -288,6 +316,33 @@ fn namespace_impl(writer: &Writer, tree: &Tree) -> String {
     tokens.into_string()
 }
 
+fn namespace_proxy(writer: &Writer, tree: &Tree) -> String {
+    let writer = &mut writer.clone();
+    writer.namespace = tree.namespace;
+    let mut types = std::collections::BTreeMap::new();
+
+    for item in writer.reader.namespace_items(tree.namespace) {
+        if let metadata::Item::Type(def) = item {
+            let type_name = def.type_name();
+            if writer.reader.core_type(type_name).is_some() {
+                continue;
+            }
+            let tokens = proxies::writer(writer, def);
+
+            if !tokens.is_empty() {
+                types.insert(type_name.name(), tokens);
+            }
+        }
+    }
+
+    let types = types.values();
+
+    quote! {
+        #(#types)*
+    }
+    .into_string()
+}
+
 /// Expand a possibly empty generics list with a new generic
 fn expand_generics(generics: TokenStream, new: TokenStream) -> TokenStream {
     if generics.is_empty() {
@@ -0,0 +1,164 @@
+use super::*;
+
+pub fn writer(writer: &Writer, def: metadata::TypeDef) -> TokenStream {
+    let generics = &metadata::type_def_generics(def);
+    let vtables = metadata::type_def_vtables(def);
+
+    if def.kind() != metadata::TypeKind::Interface
+        || !generics.is_empty()
+        || !matches!(
+            vtables.first(),
+            Some(metadata::Type::Name(metadata::TypeName::IUnknown))
+        )
+    {
+        return quote! {};
+    }
+
+    let cfg = cfg::type_def_cfg_impl(writer, def, generics);
+
+    if !cfg.included(writer) {
+        return quote! {};
+    }
+
+    let features = writer.cfg_features(&cfg);
+    let type_ident = to_ident(def.name());
+    let vtbl_ident = type_ident.join("_Vtbl");
+    let interface = def.name();
+    let is_winrt = def
+        .flags()
+        .contains(metadata::TypeAttributes::WindowsRuntime);
+
+    let base = match vtables.last() {
+        Some(metadata::Type::Name(metadata::TypeName::IUnknown)) => {
+            quote! { windows_core::IUnknown_Vtbl }
+        }
+        Some(metadata::Type::Object) => quote! { windows_core::IInspectable_Vtbl },
+        Some(metadata::Type::TypeDef(def, _)) => writer.type_def_vtbl_name(*def, &[]),
+        _ => return quote! {},
+    };
+
+    // The slot of the first method is the number of methods in the vtables it derives from.
+    let first_slot: usize = vtables
+        .iter()
+        .map(|ty| match ty {
+            metadata::Type::TypeDef(def, _) => def
+                .methods()
+                .filter(|method| method.name() != ".ctor")
+                .count(),
+            _ => 3,
+        })
+        .sum();
+
+    let mut method_names = MethodNames::new();
+    let mut entries = quote! {};
+
+    let functions = def
+        .methods()
+        .filter(|method| method.name() != ".ctor")
+        .enumerate()
+        .map(|(index, method)| {
+            let name = method_names.add(method);
+            let name_string = name.as_str();
+            let slot = first_slot + index;
+            let signature = metadata::method_def_signature(def.namespace(), method, generics);
+            let mut cfg = cfg::signature_cfg(writer, method);
+            cfg.add_feature(def.namespace());
+
+            // Methods whose types are unavailable have a placeholder slot in the vtable, so the proxy can't forward
+            // them either.
+            if !cfg.included(writer) {
+                entries.combine(&quote! { #name: 0, });
+                return quote! {};
+            }
+
+            let cfg_all = writer.cfg_features(&cfg);
+            let cfg_not = writer.cfg_not_features(&cfg);
+            let vtbl_signature = writer.vtbl_signature(def, true, &signature);
+            let args = gen_args(writer, is_winrt, &signature);
+
+            let result = if is_winrt
+                || matches!(
+                    signature.return_type,
+                    metadata::Type::Name(metadata::TypeName::HResult)
+                ) {
+                quote! { |result| Some(*result) }
+            } else {
+                quote! { |_| None }
+            };
+
+            if cfg_all.is_empty() {
+                entries.combine(&quote! { #name, });
+            } else {
+                entries.combine(&quote! {
+                    #cfg_all
+                    #name,
+                    #cfg_not
+                    #name: 0,
+                });
+            }
+
+            quote! {
+                #cfg_all
+                unsafe extern "system" fn #name #vtbl_signature {
+                    windows_core::imp::proxy_call(
+                        this,
+                        #interface,
+                        #name_string,
+                        #slot,
+                        |this| ((**(this as *const *const #vtbl_ident)).#name)(this, #args),
+                        #result,
+                    )
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    quote! {
+        #features
+        impl windows_core::imp::ProxyVtable for #vtbl_ident {
+            const PROXY: Self = {
+                #(#functions)*
+                Self { base__: <#base as windows_core::imp::ProxyVtable>::PROXY, #entries }
+            };
+            const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+            fn matches(iid: &windows_core::GUID) -> bool {
+                iid == &<#type_ident as windows_core::Interface>::IID
+                    || <#base as windows_core::imp::ProxyVtable>::matches(iid)
+            }
+        }
+    }
+}
+
+// Forwards the parameters in the same order as the named parameters of `Writer::vtbl_signature`.
+fn gen_args(writer: &Writer, is_winrt: bool, signature: &metadata::Signature) -> TokenStream {
+    let mut args = quote! {};
+
+    if !is_winrt && metadata::type_is_struct(&signature.return_type) {
+        args.combine(&quote! { result__, });
+    }
+
+    for param in &signature.params {
+        let name = writer.param_name(param.def);
+
+        if is_winrt
+            && (param.ty.is_winrt_array()
+                || (!param.def.flags().contains(metadata::ParamAttributes::In)
+                    && param.ty.is_winrt_array_ref()))
+        {
+            let size: TokenStream = format!("{}_array_size", param.def.name()).into();
+            args.combine(&quote! { #size, });
+        }
+
+        args.combine(&quote! { #name, });
+    }
+
+    if is_winrt {
+        match &signature.return_type {
+            metadata::Type::Void => {}
+            metadata::Type::WinrtArray(_) => args.combine(&quote! { result_size__, result__, }),
+            _ => args.combine(&quote! { result__, }),
+        }
+    }
+
+    args
+}
@@ -95,6 +95,7 @@ fn gen_struct_with_name(
     };
 
     tokens.combine(&gen_struct_constants(writer, def, &name, &cfg));
+    tokens.combine(&gen_bitfields(writer, def, &name, &cfg));
     tokens.combine(&gen_clone(writer, def, &name, &cfg));
     tokens.combine(&gen_windows_traits(writer, def, &name, &cfg));
 
@@ -167,12 +168,17 @@ fn gen_windows_traits(
 
 fn gen_derive(writer: &Writer, def: metadata::TypeDef) -> TokenStream {
     let mut derive = std::collections::BTreeSet::new();
+    let mut debug = quote! {};
 
     if !writer.sys
         && !metadata::type_def_has_explicit_layout(def)
         && !metadata::type_def_has_packing(def)
     {
-        derive.insert(to_ident("Debug"));
+        if writer.derive_debug_inline() {
+            derive.insert(to_ident("Debug"));
+        } else {
+            debug = writer.derive_debug();
+        }
     }
 
     if writer.sys || metadata::type_def_is_copyable(def) {
@@ -198,10 +204,11 @@ fn gen_derive(writer: &Writer, def: metadata::TypeDef) -> TokenStream {
     }
 
     if derive.is_empty() {
-        quote! {}
+        debug
     } else {
         quote! {
             #[derive(#(#derive),*)]
+            #debug
         }
     }
 }
@@ -271,6 +278,109 @@ fn gen_struct_constants(
     tokens
 }
 
+fn gen_bitfields(
+    writer: &Writer,
+    def: metadata::TypeDef,
+    struct_name: &TokenStream,
+    cfg: &cfg::Cfg,
+) -> TokenStream {
+    // Union fields can only be read unsafely so bitfield accessors are only generated for structs.
+    if writer.sys
+        || def
+            .flags()
+            .contains(metadata::TypeAttributes::ExplicitLayout)
+    {
+        return quote! {};
+    }
+
+    let mut accessors = quote! {};
+
+    for field in def.fields() {
+        let ty = field.ty(Some(def));
+
+        let bits = match ty {
+            metadata::Type::I8 | metadata::Type::U8 => 8,
+            metadata::Type::I16 | metadata::Type::U16 => 16,
+            metadata::Type::I32 | metadata::Type::U32 => 32,
+            metadata::Type::I64 | metadata::Type::U64 => 64,
+            _ => continue,
+        };
+
+        let field_name = to_ident(field.name());
+        let ty = writer.type_default_name(&ty);
+
+        for attribute in field.attributes() {
+            if attribute.name() != "NativeBitfieldAttribute" {
+                continue;
+            }
+
+            let args = attribute.args();
+
+            let (
+                Some((_, metadata::Value::String(name))),
+                Some((_, metadata::Value::I64(offset))),
+                Some((_, metadata::Value::I64(length))),
+            ) = (args.first(), args.get(1), args.get(2))
+            else {
+                continue;
+            };
+
+            let getter = to_ident(name);
+            let setter = to_ident(&format!("set_{name}"));
+
+            if *length >= bits {
+                accessors.combine(&quote! {
+                    pub const fn #getter(&self) -> #ty {
+                        self.#field_name
+                    }
+                    pub fn #setter(&mut self, value: #ty) {
+                        self.#field_name = value;
+                    }
+                });
+            } else {
+                let mask = Literal::i64_unsuffixed(((1u64 << length) - 1) as i64);
+
+                let (read, mask_in_place, value_in_place) = if *offset == 0 {
+                    (
+                        quote! { self.#field_name },
+                        quote! { #mask },
+                        quote! { (value & #mask) },
+                    )
+                } else {
+                    let offset = Literal::i64_unsuffixed(*offset);
+                    (
+                        quote! { (self.#field_name >> #offset) },
+                        quote! { (#mask << #offset) },
+                        quote! { ((value & #mask) << #offset) },
+                    )
+                };
+
+                accessors.combine(&quote! {
+                    pub const fn #getter(&self) -> #ty {
+                        #read & #mask
+                    }
+                    pub fn #setter(&mut self, value: #ty) {
+                        self.#field_name = (self.#field_name & !#mask_in_place) | #value_in_place;
+                    }
+                });
+            }
+        }
+    }
+
+    if accessors.is_empty() {
+        return accessors;
+    }
+
+    let features = writer.cfg_features(cfg);
+
+    quote! {
+        #features
+        impl #struct_name {
+            #accessors
+        }
+    }
+}
+
 fn clsid(def: metadata::TypeDef) -> Option<metadata::Guid> {
     if def.fields().next().is_none() {
         return metadata::type_def_guid(def);
@@ -20,6 +20,8 @@ pub struct Writer {
     pub no_inner_attributes: bool, // skips the inner attributes at the start of the file
     pub no_bindgen_comment: bool, // skips the bindgen comment at the start of the file
     pub vtbl: bool,    // include minimal vtbl layout support for interfaces
+    pub no_debug: bool, // omits the Debug impls for structs, enums, and handles
+    pub proxy: bool,   // generates the vtables used by `windows_core::proxy`
     pub prepend: std::collections::HashMap<metadata::TypeDef, String>,
     /// If this is not empty, then it is passed to rustfmt in a `--config` argument.
     pub rustfmt_config: String,
@@ -39,11 +41,37 @@ impl Writer {
             no_inner_attributes: false,
             no_bindgen_comment: false,
             vtbl: false,
+            no_debug: false,
+            proxy: false,
             prepend: Default::default(),
             rustfmt_config: String::new(),
         }
     }
 
+    /// Returns true if `Debug` is derived inline. For packages it's derived separately so that it can be compiled
+    /// out with `--cfg windows_no_debug`.
+    pub fn derive_debug_inline(&self) -> bool {
+        !self.no_debug && !self.package
+    }
+
+    /// Returns the separate attribute deriving `Debug` for packages.
+    pub fn derive_debug(&self) -> TokenStream {
+        if self.no_debug || !self.package {
+            quote! {}
+        } else {
+            quote! { #[cfg_attr(not(windows_no_debug), derive(Debug))] }
+        }
+    }
+
+    /// Returns the cfg guarding a handwritten `Debug` impl, which isn't generated at all if `no_debug` is set.
+    pub fn cfg_debug(&self) -> TokenStream {
+        if self.package {
+            quote! { #[cfg(not(windows_no_debug))] }
+        } else {
+            quote! {}
+        }
+    }
+
     //
     // metadata::TypeDef
     //
@@ -477,7 +505,7 @@ impl Writer {
         compact
     }
 
-    fn cfg_not_features(&self, cfg: &cfg::Cfg) -> TokenStream {
+    pub(crate) fn cfg_not_features(&self, cfg: &cfg::Cfg) -> TokenStream {
         let features = self.cfg_features_imp(cfg, self.namespace);
         if features.is_empty() {
             quote! {}
@@ -682,7 +710,7 @@ impl Writer {
 
             let namespace = self.namespace("Windows.Foundation");
 
-            quote! {
+            let mut tokens = quote! {
                 #features
                 impl<#constraints> #ident {
                     pub fn get(&self) -> windows_core::Result<#return_type> {
@@ -697,7 +725,66 @@ impl Writer {
                         self.GetResults()
                     }
                 }
+            };
+
+            // The `Async` trait and its adapters are only available to the `windows` crate itself.
+            if self.package {
+                tokens.combine(&quote! {
+                    #features
+                    #[cfg(feature = "std")]
+                    impl<#constraints> #namespace Async for #ident {
+                        type Output = #return_type;
+                        fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+                            self.SetCompleted(&#namespace #handler::new(move |_sender, _args| {
+                                handler();
+                                Ok(())
+                            }))
+                        }
+                        fn get_results(&self) -> windows_core::Result<Self::Output> {
+                            self.GetResults()
+                        }
+                    }
+                    #features
+                    #[cfg(feature = "std")]
+                    impl<#constraints> core::future::IntoFuture for #ident {
+                        type Output = windows_core::Result<#return_type>;
+                        type IntoFuture = #namespace AsyncFuture<Self>;
+                        fn into_future(self) -> Self::IntoFuture {
+                            #namespace AsyncFuture::new(self)
+                        }
+                    }
+                });
+
+                let progress = match kind {
+                    metadata::AsyncKind::ActionWithProgress => {
+                        Some((&async_generics[0], quote! { AsyncActionProgressHandler }))
+                    }
+                    metadata::AsyncKind::OperationWithProgress => {
+                        Some((&async_generics[1], quote! { AsyncOperationProgressHandler }))
+                    }
+                    _ => None,
+                };
+
+                if let Some((progress_type, progress_handler)) = progress {
+                    let progress_type = self.type_name(progress_type);
+
+                    tokens.combine(&quote! {
+                        #features
+                        #[cfg(feature = "std")]
+                        impl<#constraints> #namespace AsyncWithProgress for #ident {
+                            type Progress = #progress_type;
+                            fn set_progress<F: Fn(Self::Progress) + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+                                self.SetProgress(&#namespace #progress_handler::new(move |_sender, value| {
+                                    handler(windows_core::Type::from_default(value)?);
+                                    Ok(())
+                                }))
+                            }
+                        }
+                    });
+                }
             }
+
+            tokens
         }
     }
     pub fn interface_winrt_trait(
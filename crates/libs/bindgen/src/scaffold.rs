@@ -0,0 +1,256 @@
+use super::*;
+
+/// Creates a new crate from a template.
+///
+/// `--component <namespace>` creates a WinRT component crate whose runtime classes are described by
+/// an IDL file in the given namespace, along with a build script that generates the bindings and the
+/// registration-free activation manifest, stub implementations of the classes, and a smoke test.
+/// The crate is created in the directory given by `--out`, or a directory named after the crate.
+pub fn scaffold<I, S>(args: I) -> Result<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let args = args::expand(args)?;
+
+    let mut kind = ArgKind::None;
+    let mut component = None;
+    let mut output = None;
+
+    for arg in &args {
+        if arg.starts_with('-') {
+            kind = ArgKind::None;
+        }
+
+        match kind {
+            ArgKind::None => match arg.as_str() {
+                "--component" => kind = ArgKind::Input,
+                "-o" | "--out" => kind = ArgKind::Output,
+                _ => return Err(Error::new(&format!("invalid option `{arg}`"))),
+            },
+            ArgKind::Input if component.is_none() => component = Some(arg.as_str()),
+            ArgKind::Output if output.is_none() => output = Some(arg.as_str()),
+            _ => return Err(Error::new(&format!("unexpected argument `{arg}`"))),
+        }
+    }
+
+    let Some(namespace) = component else {
+        return Err(Error::new("`--component <namespace>` must be specified"));
+    };
+
+    if !namespace.split('.').all(is_identifier) {
+        return Err(Error::new(&format!("invalid namespace `{namespace}`")));
+    }
+
+    let name = namespace.to_lowercase().replace('.', "_");
+    let output = output.unwrap_or(&name).trim_end_matches(['/', '\\']);
+
+    let path = std::path::Path::new(output);
+
+    if path
+        .read_dir()
+        .map_or(path.exists(), |mut entries| entries.next().is_some())
+    {
+        return Err(Error::new("output directory is not empty").with_path(output));
+    }
+
+    let files = [
+        ("Cargo.toml", CARGO_TOML),
+        ("build.rs", BUILD_RS),
+        ("src/lib.rs", LIB_RS),
+        ("src/$crate$.idl", IDL),
+        ("tests/smoke.rs", SMOKE_RS),
+        (".gitignore", GITIGNORE),
+    ];
+
+    for (file, template) in files {
+        let expand = |text: &str| {
+            text.replace("$namespace$", namespace)
+                .replace("$crate$", &name)
+                .replace("$version$", env!("CARGO_PKG_VERSION"))
+        };
+
+        write_to_file(&format!("{output}/{}", expand(file)), expand(template))?;
+    }
+
+    Ok(format!("  Created component `{name}` in `{output}`"))
+}
+
+fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+const CARGO_TOML: &str = r#"[package]
+name = "$crate$"
+version = "0.1.0"
+edition = "2021"
+publish = false
+
+[lib]
+crate-type = ["cdylib", "rlib"]
+
+[dependencies.windows]
+version = "$version$"
+features = [
+    "implement",
+    "Win32_Foundation",
+    "Win32_System_WinRT",
+]
+
+[dependencies.windows-core]
+version = "$version$"
+
+[build-dependencies.windows-bindgen]
+version = "$version$"
+"#;
+
+const BUILD_RS: &str = r#"fn main() {
+    println!("cargo:rerun-if-changed=src/$crate$.idl");
+    let metadata_dir = format!("{}\\System32\\WinMetadata", env!("windir"));
+
+    // Compiles the IDL to metadata with `midlrt.exe`, which is included in the Windows SDK.
+    let status = std::process::Command::new("midlrt.exe")
+        .args([
+            "/winrt",
+            "/nomidl",
+            "/h",
+            "nul",
+            "/metadata_dir",
+            &metadata_dir,
+            "/reference",
+            &format!("{metadata_dir}\\Windows.Foundation.winmd"),
+            "/winmd",
+            "$namespace$.winmd",
+            "src/$crate$.idl",
+        ])
+        .status()
+        .expect("failed to run midlrt.exe");
+
+    if !status.success() {
+        panic!("failed to compile src/$crate$.idl");
+    }
+
+    // Generates the bindings for the classes that are implemented in `src/lib.rs`.
+    bindgen(&[
+        "--in",
+        "$namespace$.winmd",
+        &metadata_dir,
+        "--out",
+        "src/bindings.rs",
+        "--filter",
+        "$namespace$",
+        "--config",
+        "implement",
+    ]);
+
+    // Generates the manifest that lets apps activate the classes without registration.
+    bindgen(&[
+        "--in",
+        "$namespace$.winmd",
+        &metadata_dir,
+        "--out",
+        "$namespace$.manifest",
+        "--filter",
+        "$namespace$",
+        "--config",
+        "dll=$crate$.dll",
+    ]);
+}
+
+fn bindgen(args: &[&str]) {
+    if let Err(error) = windows_bindgen::bindgen(args) {
+        panic!("{error}");
+    }
+}
+"#;
+
+const LIB_RS: &str = r#"pub mod bindings;
+
+use std::sync::RwLock;
+use windows::{core::*, Win32::Foundation::*, Win32::System::WinRT::*};
+
+#[implement(bindings::Class)]
+#[derive(Default)]
+struct Class {
+    property: RwLock<i32>,
+}
+
+impl bindings::IClass_Impl for Class_Impl {
+    fn Property(&self) -> Result<i32> {
+        Ok(*self.property.read().unwrap())
+    }
+
+    fn SetProperty(&self, value: i32) -> Result<()> {
+        *self.property.write().unwrap() = value;
+        Ok(())
+    }
+}
+
+#[implement(IActivationFactory)]
+struct ClassFactory;
+
+impl IActivationFactory_Impl for ClassFactory_Impl {
+    fn ActivateInstance(&self) -> Result<IInspectable> {
+        Ok(Class::default().into())
+    }
+}
+
+/// Returns the activation factory for the runtime class with the given name.
+pub fn activation_factory(name: &HSTRING) -> Option<IActivationFactory> {
+    if *name == "$namespace$.Class" {
+        Some(ClassFactory.into())
+    } else {
+        None
+    }
+}
+
+#[no_mangle]
+unsafe extern "system" fn DllGetActivationFactory(
+    name: Ref<HSTRING>,
+    factory: OutRef<IActivationFactory>,
+) -> HRESULT {
+    let result = activation_factory(&name);
+    let found = result.is_some();
+    _ = factory.write(result);
+
+    if found {
+        S_OK
+    } else {
+        CLASS_E_CLASSNOTAVAILABLE
+    }
+}
+"#;
+
+const IDL: &str = r#"namespace $namespace$
+{
+    runtimeclass Class
+    {
+        Class();
+        Int32 Property;
+    }
+}
+"#;
+
+const SMOKE_RS: &str = r#"use windows::core::*;
+
+#[test]
+fn activate() -> Result<()> {
+    let factory = $crate$::activation_factory(h!("$namespace$.Class")).expect("factory");
+    let class: $crate$::bindings::Class = unsafe { factory.ActivateInstance()? }.cast()?;
+
+    assert_eq!(class.Property()?, 0);
+    class.SetProperty(123)?;
+    assert_eq!(class.Property()?, 123);
+
+    assert!($crate$::activation_factory(h!("$namespace$.Missing")).is_none());
+    Ok(())
+}
+"#;
+
+const GITIGNORE: &str = r#"/target
+*.winmd
+*.manifest
+"#;
@@ -0,0 +1,115 @@
+use super::*;
+
+/// A container that makes any COM/WinRT interface safe to share across threads, including objects
+/// that belong to a single-threaded apartment.
+///
+/// The object is held through an [`AgileReference`] along with the context of the apartment in which
+/// the cell was created. Use [`AgileCell::get`] to resolve a proxy that is usable from the calling
+/// apartment, or [`AgileCell::with`] to run a closure in the originating apartment against the
+/// original object.
+pub struct AgileCell<T> {
+    reference: AgileReference<T>,
+    context: imp::IContextCallback,
+}
+
+unsafe impl<T: Interface> Send for AgileCell<T> {}
+unsafe impl<T: Interface> Sync for AgileCell<T> {}
+
+impl<T: Interface> AgileCell<T> {
+    /// Stores the object, capturing the context of the calling apartment.
+    pub fn new(object: &T) -> Result<Self> {
+        Ok(Self {
+            reference: AgileReference::new(object)?,
+            context: unsafe { imp::CoGetObjectContext()? },
+        })
+    }
+
+    /// Resolves the object for use by the calling apartment. This is the original object when
+    /// called from the originating apartment and a proxy otherwise.
+    pub fn get(&self) -> Result<T> {
+        self.reference.resolve()
+    }
+
+    /// Returns `true` if the calling thread belongs to the apartment in which the cell was created.
+    pub fn is_current_context(&self) -> bool {
+        unsafe { imp::CoGetObjectContext::<imp::IContextCallback>() }
+            .is_ok_and(|context| context == self.context)
+    }
+
+    /// Calls `f` with the original object from within the apartment in which the cell was created,
+    /// blocking until it returns.
+    ///
+    /// For a single-threaded apartment the call is marshaled to the apartment's thread, which must
+    /// be pumping messages. When already in the originating apartment, `f` is called directly.
+    pub fn with<F, R>(&self, f: F) -> Result<R>
+    where
+        F: FnOnce(&T) -> Result<R> + Send,
+        R: Send,
+    {
+        if self.is_current_context() {
+            return f(&self.get()?);
+        }
+
+        struct Call<'a, T, F, R> {
+            reference: &'a AgileReference<T>,
+            f: Option<F>,
+            result: Option<Result<R>>,
+        }
+
+        unsafe extern "system" fn callback<T: Interface, F: FnOnce(&T) -> Result<R>, R>(
+            data: *mut imp::ComCallData,
+        ) -> HRESULT {
+            let call = &mut *((*data).pUserDefined as *mut Call<'_, T, F, R>);
+
+            if let Some(f) = call.f.take() {
+                call.result = Some(call.reference.resolve().and_then(|object| f(&object)));
+            }
+
+            HRESULT(0)
+        }
+
+        // Calls to `ContextCallback` are required to name this interface and method.
+        const IID_ICALLBACKWITHNOREENTRANCYTOAPPLICATIONSTA: GUID =
+            GUID::from_u128(0x0a299774_3e4e_fc42_1d9d_72cee105ca57);
+
+        let mut call = Call {
+            reference: &self.reference,
+            f: Some(f),
+            result: None,
+        };
+
+        let data = imp::ComCallData {
+            dwDispid: 0,
+            dwReserved: 0,
+            pUserDefined: &mut call as *mut _ as *mut _,
+        };
+
+        unsafe {
+            self.context.ContextCallback(
+                Some(callback::<T, F, R>),
+                &data,
+                &IID_ICALLBACKWITHNOREENTRANCYTOAPPLICATIONSTA,
+                5,
+                None,
+            )?;
+        }
+
+        call.result
+            .unwrap_or_else(|| Err(Error::from_hresult(imp::E_ABORT)))
+    }
+}
+
+impl<T: Interface> Clone for AgileCell<T> {
+    fn clone(&self) -> Self {
+        Self {
+            reference: self.reference.clone(),
+            context: self.context.clone(),
+        }
+    }
+}
+
+impl<T> core::fmt::Debug for AgileCell<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "AgileCell({:?})", &self.reference)
+    }
+}
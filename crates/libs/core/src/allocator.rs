@@ -0,0 +1,117 @@
+use super::*;
+use core::alloc::{GlobalAlloc, Layout};
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+const UNSET: u8 = 0;
+const WRITING: u8 = 1;
+const READY: u8 = 2;
+
+struct Slot(UnsafeCell<Option<&'static (dyn GlobalAlloc + Sync)>>);
+
+unsafe impl Sync for Slot {}
+
+static STATE: AtomicU8 = AtomicU8::new(UNSET);
+static ALLOCATOR: Slot = Slot(UnsafeCell::new(None));
+
+/// Sets the allocator used for memory that `windows-core` allocates internally, such as the
+/// delegate lists of an `Event` and the tear-offs that support weak references.
+///
+/// By default these allocations use the Rust global allocator. The allocator can only be set once
+/// and only before `windows-core` makes its first internal allocation, otherwise this function
+/// fails with `E_ILLEGAL_METHOD_CALL`. This ensures that memory is always freed by the allocator
+/// that allocated it.
+pub fn set_allocator(allocator: &'static (dyn GlobalAlloc + Sync)) -> Result<()> {
+    STATE
+        .compare_exchange(UNSET, WRITING, Ordering::Acquire, Ordering::Acquire)
+        .map_err(|_| Error::from_hresult(imp::E_ILLEGAL_METHOD_CALL))?;
+
+    // SAFETY: the `WRITING` state guarantees exclusive access to the slot.
+    unsafe { *ALLOCATOR.0.get() = Some(allocator) };
+    STATE.store(READY, Ordering::Release);
+    Ok(())
+}
+
+fn allocator() -> Option<&'static (dyn GlobalAlloc + Sync)> {
+    loop {
+        match STATE.compare_exchange_weak(UNSET, READY, Ordering::Acquire, Ordering::Acquire) {
+            // SAFETY: the slot is never written once the state is `READY`.
+            Ok(_) | Err(READY) => return unsafe { *ALLOCATOR.0.get() },
+            Err(_) => core::hint::spin_loop(),
+        }
+    }
+}
+
+/// Allocates memory for `layout` with the allocator chosen by `set_allocator`.
+pub(crate) unsafe fn allocate(layout: Layout) -> *mut u8 {
+    let ptr = match allocator() {
+        Some(allocator) => allocator.alloc(layout),
+        None => alloc::alloc::alloc(layout),
+    };
+
+    if ptr.is_null() {
+        alloc::alloc::handle_alloc_error(layout);
+    }
+
+    ptr
+}
+
+/// Frees memory previously returned by `allocate` for the same `layout`.
+pub(crate) unsafe fn deallocate(ptr: *mut u8, layout: Layout) {
+    match allocator() {
+        Some(allocator) => allocator.dealloc(ptr, layout),
+        None => alloc::alloc::dealloc(ptr, layout),
+    }
+}
+
+/// Moves `value` into memory obtained from `allocate`.
+pub(crate) fn allocate_value<T>(value: T) -> *mut T {
+    unsafe {
+        let ptr = allocate(Layout::new::<T>()) as *mut T;
+        ptr.write(value);
+        ptr
+    }
+}
+
+/// Drops and frees a value created by `allocate_value`.
+pub(crate) unsafe fn deallocate_value<T>(ptr: *mut T) {
+    ptr.drop_in_place();
+    deallocate(ptr as *mut u8, Layout::new::<T>());
+}
+
+/// An allocator that uses the C runtime's `malloc` and `free` functions, for use with `set_allocator`.
+#[cfg(windows)]
+pub struct CrtAllocator;
+
+#[cfg(windows)]
+extern "C" {
+    fn malloc(size: usize) -> *mut core::ffi::c_void;
+    fn free(ptr: *mut core::ffi::c_void);
+    fn _aligned_malloc(size: usize, alignment: usize) -> *mut core::ffi::c_void;
+    fn _aligned_free(ptr: *mut core::ffi::c_void);
+}
+
+#[cfg(windows)]
+impl CrtAllocator {
+    // `malloc` aligns to 16 bytes on 64-bit targets and 8 bytes on 32-bit targets.
+    const MIN_ALIGN: usize = 2 * core::mem::size_of::<usize>();
+}
+
+#[cfg(windows)]
+unsafe impl GlobalAlloc for CrtAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if layout.align() <= Self::MIN_ALIGN {
+            malloc(layout.size()) as *mut u8
+        } else {
+            _aligned_malloc(layout.size(), layout.align()) as *mut u8
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if layout.align() <= Self::MIN_ALIGN {
+            free(ptr as *mut _)
+        } else {
+            _aligned_free(ptr as *mut _)
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use alloc::vec::Vec;
 
 /// A WinRT array stores elements contiguously in a heap-allocated buffer.
 pub struct Array<T: Type<T>> {
@@ -105,6 +106,66 @@ impl<T: Type<T>> Array<T> {
         }
     }
 
+    /// Creates an array by calling a function that receives the array as an out parameter.
+    ///
+    /// This is a convenience for methods that return an array through a `&mut Array<T>` parameter.
+    pub fn receive<F: FnOnce(&mut Self) -> Result<()>>(f: F) -> Result<Self> {
+        let mut array = Self::new();
+        f(&mut array)?;
+        Ok(array)
+    }
+
+    /// Creates an array by calling a function that fills a slice of the given length and returns the number
+    /// of elements that it filled, such as the `GetMany` methods of WinRT collections.
+    pub fn fill<F: FnOnce(&mut [T::Default]) -> Result<u32>>(len: usize, f: F) -> Result<Self> {
+        let mut array = Self::with_len(len);
+        let filled = f(&mut array)?;
+        array.truncate(filled as usize);
+        Ok(array)
+    }
+
+    /// Shortens the array, keeping the first `len` elements and dropping the rest.
+    ///
+    /// This has no effect if `len` is greater than or equal to the array's current length.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len() {
+            return;
+        }
+
+        let old_len = self.len();
+        self.len = len as u32;
+
+        // SAFETY: the elements beyond the new length are no longer reachable through the array, and the
+        // memory is still freed as a whole when the array is cleared.
+        unsafe {
+            core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(
+                self.data.add(len),
+                old_len - len,
+            ));
+        }
+    }
+
+    /// Converts the array into a `Vec`, moving its elements and freeing the array's buffer.
+    pub fn into_vec(mut self) -> Vec<T::Default> {
+        let data = core::mem::replace(&mut self.data, core::ptr::null_mut());
+        let len = core::mem::replace(&mut self.len, 0) as usize;
+        let mut vec = Vec::with_capacity(len);
+
+        // SAFETY: each element is moved out exactly once and the array no longer refers to the buffer, so
+        // the buffer can be freed without dropping the elements again.
+        unsafe {
+            for index in 0..len {
+                vec.push(data.add(index).read());
+            }
+
+            if !data.is_null() {
+                imp::CoTaskMemFree(data as _);
+            }
+        }
+
+        vec
+    }
+
     #[doc(hidden)]
     /// Get a mutable pointer to the array's length
     ///
@@ -155,6 +216,98 @@ impl<T: Type<T>> Drop for Array<T> {
     }
 }
 
+impl<T: Type<T>> Clone for Array<T>
+where
+    T::Default: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::from_slice(self)
+    }
+}
+
+impl<T: Type<T>> PartialEq for Array<T>
+where
+    T::Default: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Type<T>> Eq for Array<T> where T::Default: Eq {}
+
+impl<T: Type<T>> core::fmt::Debug for Array<T>
+where
+    T::Default: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Type<T>> From<Vec<T::Default>> for Array<T> {
+    fn from(values: Vec<T::Default>) -> Self {
+        let mut array = Self::with_len(values.len());
+
+        for (element, value) in array.iter_mut().zip(values) {
+            *element = value;
+        }
+
+        array
+    }
+}
+
+impl<T: Type<T>> From<alloc::boxed::Box<[T::Default]>> for Array<T> {
+    fn from(values: alloc::boxed::Box<[T::Default]>) -> Self {
+        values.into_vec().into()
+    }
+}
+
+impl<T: Type<T>> From<Array<T>> for Vec<T::Default> {
+    fn from(array: Array<T>) -> Self {
+        array.into_vec()
+    }
+}
+
+impl<T: Type<T>> From<Array<T>> for alloc::boxed::Box<[T::Default]> {
+    fn from(array: Array<T>) -> Self {
+        array.into_vec().into_boxed_slice()
+    }
+}
+
+impl<T: Type<T>> FromIterator<T::Default> for Array<T> {
+    fn from_iter<I: IntoIterator<Item = T::Default>>(iter: I) -> Self {
+        iter.into_iter().collect::<Vec<_>>().into()
+    }
+}
+
+impl<T: Type<T>> IntoIterator for Array<T> {
+    type Item = T::Default;
+    type IntoIter = alloc::vec::IntoIter<T::Default>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter()
+    }
+}
+
+impl<'a, T: Type<T>> IntoIterator for &'a Array<T> {
+    type Item = &'a T::Default;
+    type IntoIter = core::slice::Iter<'a, T::Default>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Type<T>> IntoIterator for &'a mut Array<T> {
+    type Item = &'a mut T::Default;
+    type IntoIter = core::slice::IterMut<'a, T::Default>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 #[doc(hidden)]
 pub struct ArrayProxy<T: Type<T>> {
     data: *mut *mut T::Default,
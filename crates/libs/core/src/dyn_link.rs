@@ -0,0 +1,32 @@
+/// Declares functions that are resolved at run time, for calling APIs that may not be available on every version
+/// of Windows that an app supports.
+///
+/// Each declaration produces a function that returns the typed function pointer, or `None` if the library or function
+/// could not be found. The library is loaded from the system's default search directories and the lookup only happens
+/// the first time.
+///
+/// ```rust,ignore
+/// windows_core::dyn_link! {
+///     "kernel32.dll" "system" fn SetThreadDescription(thread: HANDLE, description: PCWSTR) -> HRESULT;
+/// }
+///
+/// if let Some(SetThreadDescription) = SetThreadDescription() {
+///     unsafe { SetThreadDescription(GetCurrentThread(), w!("worker")).ok()? };
+/// }
+/// ```
+#[macro_export]
+macro_rules! dyn_link {
+    ($($library:literal $abi:literal $(#[$attr:meta])* $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $return:ty)?;)*) => {
+        $(
+            $(#[$attr])*
+            #[allow(non_snake_case)]
+            $vis fn $name() -> ::core::option::Option<unsafe extern $abi fn($($arg: $ty),*) $(-> $return)?> {
+                static LINK: $crate::imp::DynLink = $crate::imp::DynLink::new();
+                unsafe {
+                    LINK.load(::core::concat!($library, "\0").as_bytes(), ::core::concat!(::core::stringify!($name), "\0").as_bytes())
+                        .map(|address| ::core::mem::transmute::<*mut ::core::ffi::c_void, unsafe extern $abi fn($($ty),*) $(-> $return)?>(address))
+                }
+            }
+        )*
+    };
+}
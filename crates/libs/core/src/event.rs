@@ -1,13 +1,18 @@
 use super::*;
-use core::{iter::once, mem::transmute_copy};
-use std::sync::{Arc, RwLock};
+use core::alloc::Layout;
+use core::sync::atomic::{AtomicI64, AtomicPtr, AtomicUsize, Ordering};
+use core::{iter::once, ptr::NonNull};
+use std::sync::{Mutex, MutexGuard};
 
 /// A type that you can use to declare and implement an event of a specified delegate type.
 ///
 /// The implementation is thread-safe and designed to avoid contention between events being
-/// raised and delegates being added or removed.
+/// raised and delegates being added or removed. Raising an event never blocks, while adding
+/// or removing delegates is serialized.
 pub struct Event<T: Interface> {
-    delegates: RwLock<Option<Arc<[Delegate<T>]>>>,
+    delegates: AtomicArray<T>,
+    disconnect_policy: fn(HRESULT) -> bool,
+    next_token: AtomicI64,
 }
 
 unsafe impl<T: Interface> Send for Event<T> {}
@@ -22,49 +27,102 @@ impl<T: Interface> Default for Event<T> {
 impl<T: Interface> Event<T> {
     /// Creates a new, empty `Event<T>`.
     pub fn new() -> Self {
+        Self::with_disconnect_policy(is_disconnected)
+    }
+
+    /// Creates a new, empty `Event<T>` that uses `policy` to decide whether a delegate that fails
+    /// with a given error is disconnected and should be removed when the event is raised.
+    ///
+    /// By default, delegates are removed when they fail with `RPC_E_DISCONNECTED`,
+    /// `JSCRIPT_E_CANTEXECUTE`, or `HRESULT_FROM_WIN32(RPC_S_SERVER_UNAVAILABLE)`. The error is
+    /// still reported by `try_call` and `call_with_results` either way.
+    pub fn with_disconnect_policy(policy: fn(HRESULT) -> bool) -> Self {
         Self {
-            delegates: RwLock::new(None),
+            delegates: AtomicArray::new(),
+            disconnect_policy: policy,
+            next_token: AtomicI64::new(1),
         }
     }
 
     /// Registers a delegate with the event object.
+    ///
+    /// Every registration gets a distinct token, even if the same delegate is added more than once.
     pub fn add(&self, delegate: &T) -> Result<i64> {
-        let new_delegate = Delegate::new(delegate)?;
-        let token = new_delegate.to_token();
+        self.insert(|token| Delegate::new(delegate, token))
+    }
+
+    fn insert<F: FnOnce(i64) -> Result<Delegate<T>>>(&self, new: F) -> Result<i64> {
+        let token = self.next_token.fetch_add(1, Ordering::Relaxed);
+        let new_delegate = new(token)?;
         let new_iter = once(new_delegate);
-        let mut guard = self.delegates.write().unwrap();
+        let guard = self.delegates.lock();
 
-        let new_list = if let Some(old_delegates) = guard.as_ref() {
-            Arc::from_iter(old_delegates.iter().cloned().chain(new_iter))
+        let new_list = if let Some(old_delegates) = self.delegates.load() {
+            Array::new(
+                old_delegates.len() + 1,
+                old_delegates.iter().cloned().chain(new_iter),
+            )
         } else {
-            Arc::from_iter(new_iter)
+            Array::new(1, new_iter)
         };
 
-        let old_list = guard.replace(new_list);
+        let old_list = self.delegates.replace(&guard, Some(new_list));
         drop(guard);
         drop(old_list); // drop the old delegates _after_ releasing lock
 
         Ok(token)
     }
 
+    /// Registers a delegate with the event object, returning a guard that revokes the registration
+    /// when it is dropped.
+    pub fn add_guarded(&self, delegate: &T) -> Result<EventGuard<'_, T>> {
+        Ok(EventGuard {
+            event: self,
+            token: self.add(delegate)?,
+        })
+    }
+
+    /// Registers a closure with the event object, creating a delegate of the event's type to call it.
+    ///
+    /// ```rust,ignore
+    /// let event = Event::<TypedEventHandler<IInspectable, i32>>::new();
+    /// event.add_fn(|_sender, args| {
+    ///     println!("{args}");
+    ///     Ok(())
+    /// })?;
+    /// ```
+    pub fn add_fn<F>(&self, callback: F) -> Result<i64>
+    where
+        T: EventDelegate,
+        F: FnMut(
+                &<T::Sender as Type<T::Sender>>::Default,
+                &<T::Args as Type<T::Args>>::Default,
+            ) -> Result<()>
+            + Send
+            + 'static,
+    {
+        self.add(&T::from_fn(callback))
+    }
+
     /// Revokes a delegate's registration from the event object.
     pub fn remove(&self, token: i64) {
-        let mut guard = self.delegates.write().unwrap();
+        let guard = self.delegates.lock();
         let mut old_list = None;
-        if let Some(old_delegates) = guard.as_ref() {
+        if let Some(old_delegates) = self.delegates.load() {
             // `self.delegates` is only modified if the token is found.
             if let Some(i) = old_delegates
                 .iter()
                 .position(|old_delegate| old_delegate.to_token() == token)
             {
-                let new_list = Arc::from_iter(
+                let new_list = Array::new(
+                    old_delegates.len() - 1,
                     old_delegates[..i]
                         .iter()
                         .chain(old_delegates[i + 1..].iter())
                         .cloned(),
                 );
 
-                old_list = guard.replace(new_list);
+                old_list = self.delegates.replace(&guard, Some(new_list));
             }
         }
         drop(guard);
@@ -73,72 +131,494 @@ impl<T: Interface> Event<T> {
 
     /// Clears the event, removing all delegates.
     pub fn clear(&self) {
-        let mut guard = self.delegates.write().unwrap();
-        let old_list = guard.take();
+        let guard = self.delegates.lock();
+        let old_list = self.delegates.replace(&guard, None);
         drop(guard);
         drop(old_list); // drop the old delegates _after_ releasing lock
     }
 
+    /// Returns the number of registered delegates.
+    pub fn len(&self) -> usize {
+        self.snapshot().map_or(0, |delegates| delegates.len())
+    }
+
+    /// Returns `true` if no delegates are registered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the tokens of the registered delegates in the order that they were added.
+    pub fn tokens(&self) -> Vec<i64> {
+        self.snapshot().map_or_else(Vec::new, |delegates| {
+            delegates.iter().map(Delegate::to_token).collect()
+        })
+    }
+
+    fn snapshot(&self) -> Option<Array<T>> {
+        self.delegates.load()
+    }
+
     /// Invokes all of the event object's registered delegates with the provided callback.
-    pub fn call<F: FnMut(&T) -> Result<()>>(&self, mut callback: F) {
-        let delegates = {
-            let guard = self.delegates.read().unwrap();
-            if let Some(delegates) = guard.as_ref() {
-                delegates.clone()
-            } else {
-                // No delegates to call.
-                return;
+    pub fn call<F: FnMut(&T) -> Result<()>>(&self, callback: F) {
+        self.invoke(callback, |_| {});
+    }
+
+    /// Invokes all of the event object's registered delegates with the provided callback, returning
+    /// the first error from a delegate if any of them fail.
+    ///
+    /// Every delegate is invoked even if an earlier one fails.
+    pub fn try_call<F: FnMut(&T) -> Result<()>>(&self, callback: F) -> Result<()> {
+        let mut first = Ok(());
+
+        self.invoke(callback, |result| {
+            if first.is_ok() {
+                first = result;
             }
-            // <-- lock is released here
+        });
+
+        first
+    }
+
+    /// Invokes all of the event object's registered delegates with the provided callback, returning
+    /// the result of each delegate in the order that they were added.
+    ///
+    /// As with `call`, delegates that fail because they are disconnected are also removed.
+    pub fn call_with_results<F: FnMut(&T) -> Result<()>>(&self, callback: F) -> Vec<Result<()>> {
+        let mut results = Vec::new();
+        self.invoke(callback, |result| results.push(result));
+        results
+    }
+
+    fn invoke<F: FnMut(&T) -> Result<()>, R: FnMut(Result<()>)>(
+        &self,
+        mut callback: F,
+        mut on_result: R,
+    ) {
+        let delegates = if let Some(delegates) = self.snapshot() {
+            delegates
+        } else {
+            // No delegates to call.
+            return;
         };
 
         for delegate in delegates.iter() {
-            if let Err(error) = delegate.call(&mut callback) {
-                const RPC_E_SERVER_UNAVAILABLE: HRESULT = HRESULT(-2147023174); // HRESULT_FROM_WIN32(RPC_S_SERVER_UNAVAILABLE)
-                if matches!(
-                    error.code(),
-                    imp::RPC_E_DISCONNECTED | imp::JSCRIPT_E_CANTEXECUTE | RPC_E_SERVER_UNAVAILABLE
-                ) {
+            let result = if let Some(result) = delegate.call(&mut callback) {
+                result
+            } else {
+                // The weakly referenced delegate has been destroyed.
+                self.remove(delegate.to_token());
+                continue;
+            };
+
+            if let Err(error) = &result {
+                if (self.disconnect_policy)(error.code()) {
                     self.remove(delegate.to_token());
                 }
             }
+
+            on_result(result);
         }
     }
 }
 
+/// The default disconnect policy, matching the errors that indicate that a delegate's server has gone away.
+fn is_disconnected(code: HRESULT) -> bool {
+    const RPC_E_SERVER_UNAVAILABLE: HRESULT = HRESULT(-2147023174); // HRESULT_FROM_WIN32(RPC_S_SERVER_UNAVAILABLE)
+    matches!(
+        code,
+        imp::RPC_E_DISCONNECTED | imp::JSCRIPT_E_CANTEXECUTE | RPC_E_SERVER_UNAVAILABLE
+    )
+}
+
+impl<T: Interface> core::fmt::Debug for Event<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let delegates = self.snapshot();
+        let delegates = delegates.as_deref().unwrap_or_default();
+
+        // Delegates that aren't agile are called through an agile reference.
+        let agile = delegates
+            .iter()
+            .filter(|delegate| !matches!(delegate.reference, Reference::Indirect(_)))
+            .count();
+
+        f.debug_struct("Event")
+            .field("len", &delegates.len())
+            .field("agile", &agile)
+            .finish()
+    }
+}
+
+/// Revokes a delegate's registration from an event when dropped.
+///
+/// This is returned by `Event::add_guarded`.
+#[must_use = "the delegate is revoked as soon as the guard is dropped"]
+pub struct EventGuard<'a, T: Interface> {
+    event: &'a Event<T>,
+    token: i64,
+}
+
+impl<T: Interface> EventGuard<'_, T> {
+    /// Returns the registration token.
+    pub fn token(&self) -> i64 {
+        self.token
+    }
+
+    /// Returns the registration token without revoking the registration, which can later be
+    /// revoked by passing the token to `Event::remove`.
+    pub fn into_token(self) -> i64 {
+        let token = self.token;
+        core::mem::forget(self);
+        token
+    }
+
+    /// Leaves the delegate registered until the event is cleared or dropped.
+    pub fn forget(self) {
+        self.into_token();
+    }
+}
+
+impl<T: Interface> Drop for EventGuard<'_, T> {
+    fn drop(&mut self) {
+        self.event.remove(self.token);
+    }
+}
+
+/// Delegate types with the `(sender, args)` signature of event handlers, such as `TypedEventHandler`
+/// and `EventHandler`, so that closures can be registered with `Event::add_fn`.
+pub trait EventDelegate: Interface {
+    /// The type of the event's sender.
+    type Sender: RuntimeType;
+
+    /// The type of the event's arguments.
+    type Args: RuntimeType;
+
+    /// Creates a delegate that calls the closure.
+    fn from_fn<F>(callback: F) -> Self
+    where
+        F: FnMut(
+                &<Self::Sender as Type<Self::Sender>>::Default,
+                &<Self::Args as Type<Self::Args>>::Default,
+            ) -> Result<()>
+            + Send
+            + 'static;
+}
+
+/// An event that holds weak references to its delegates where possible, so that registering a
+/// delegate doesn't keep the object that implements it alive.
+///
+/// This avoids the reference cycle that forms when an object registers itself, or a delegate that
+/// holds it, with an event owned by something that the object also holds. Delegates that are agile
+/// and support weak references, such as those implemented with `#[implement]`, are held weakly and
+/// are removed when the event is raised after they have been destroyed. Other delegates, including
+/// the closures wrapped by generated delegate types, are held strongly as with `Event`.
+pub struct WeakEvent<T: Interface> {
+    event: Event<T>,
+}
+
+impl<T: Interface> Default for WeakEvent<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Interface> WeakEvent<T> {
+    /// Creates a new, empty `WeakEvent<T>`.
+    pub fn new() -> Self {
+        Self::with_disconnect_policy(is_disconnected)
+    }
+
+    /// Creates a new, empty `WeakEvent<T>` with a disconnect policy, as with `Event::with_disconnect_policy`.
+    pub fn with_disconnect_policy(policy: fn(HRESULT) -> bool) -> Self {
+        Self {
+            event: Event::with_disconnect_policy(policy),
+        }
+    }
+
+    /// Registers a delegate with the event object, holding a weak reference to it if possible.
+    pub fn add(&self, delegate: &T) -> Result<i64> {
+        self.event
+            .insert(|token| Delegate::new_weak(delegate, token))
+    }
+
+    /// Revokes a delegate's registration from the event object.
+    pub fn remove(&self, token: i64) {
+        self.event.remove(token)
+    }
+
+    /// Clears the event, removing all delegates.
+    pub fn clear(&self) {
+        self.event.clear()
+    }
+
+    /// Returns the number of registered delegates, including any destroyed delegates that haven't
+    /// been removed yet.
+    pub fn len(&self) -> usize {
+        self.event.len()
+    }
+
+    /// Returns `true` if no delegates are registered.
+    pub fn is_empty(&self) -> bool {
+        self.event.is_empty()
+    }
+
+    /// Returns the tokens of the registered delegates in the order that they were added.
+    pub fn tokens(&self) -> Vec<i64> {
+        self.event.tokens()
+    }
+
+    /// Invokes all of the event object's live delegates with the provided callback, removing the
+    /// delegates that have been destroyed.
+    pub fn call<F: FnMut(&T) -> Result<()>>(&self, callback: F) {
+        self.event.call(callback)
+    }
+
+    /// Invokes all of the event object's live delegates with the provided callback, returning the
+    /// first error from a delegate if any of them fail.
+    pub fn try_call<F: FnMut(&T) -> Result<()>>(&self, callback: F) -> Result<()> {
+        self.event.try_call(callback)
+    }
+
+    /// Invokes all of the event object's live delegates with the provided callback, returning the
+    /// result of each of them in the order that they were added.
+    pub fn call_with_results<F: FnMut(&T) -> Result<()>>(&self, callback: F) -> Vec<Result<()>> {
+        self.event.call_with_results(callback)
+    }
+}
+
+impl<T: Interface> core::fmt::Debug for WeakEvent<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("WeakEvent").field(&self.event).finish()
+    }
+}
+
+/// Holds the current `Array<T>` so that it can be loaded without blocking while writers, which
+/// are serialized by a mutex, replace it.
+///
+/// Readers register themselves in one of two counters, chosen by the current epoch, while they
+/// load the pointer and add a reference to the array. A writer swaps the pointer, flips the epoch,
+/// and then waits for the readers registered in the previous epoch before releasing the old array.
+/// A reader that registers in an epoch that has already been flipped away retries, since the
+/// writer that flipped it may have stopped waiting on that counter. Readers that start after the
+/// swap can only observe the new array, so the writer never waits on more than the handful of
+/// readers that were already in flight.
+///
+/// The epoch check and the writer's wait use read-modify-write operations rather than loads, so
+/// that each is guaranteed to observe the other side's latest update.
+struct AtomicArray<T: Interface> {
+    current: AtomicPtr<Buffer<T>>,
+    epoch: AtomicUsize,
+    readers: [AtomicUsize; 2],
+    swap: Mutex<()>,
+}
+
+impl<T: Interface> AtomicArray<T> {
+    fn new() -> Self {
+        Self {
+            current: AtomicPtr::new(core::ptr::null_mut()),
+            epoch: AtomicUsize::new(0),
+            readers: [AtomicUsize::new(0), AtomicUsize::new(0)],
+            swap: Mutex::new(()),
+        }
+    }
+
+    /// Returns a new reference to the current array without blocking.
+    fn load(&self) -> Option<Array<T>> {
+        let readers = loop {
+            let epoch = self.epoch.load(Ordering::SeqCst);
+            let readers = &self.readers[epoch];
+            readers.fetch_add(1, Ordering::SeqCst);
+
+            if self.epoch.fetch_add(0, Ordering::AcqRel) == epoch {
+                break readers;
+            }
+
+            readers.fetch_sub(1, Ordering::Release);
+        };
+
+        let array = NonNull::new(self.current.load(Ordering::SeqCst)).map(|buffer| unsafe {
+            buffer.as_ref().count.add_ref();
+            Array { buffer }
+        });
+
+        readers.fetch_sub(1, Ordering::Release);
+        array
+    }
+
+    /// Serializes writers, which must hold the guard while loading and replacing the array.
+    fn lock(&self) -> MutexGuard<'_, ()> {
+        self.swap.lock().unwrap()
+    }
+
+    /// Replaces the current array, returning the previous one once no reader can still be about to
+    /// add a reference to it.
+    fn replace(&self, _guard: &MutexGuard<'_, ()>, new: Option<Array<T>>) -> Option<Array<T>> {
+        let new = new.map_or(core::ptr::null_mut(), Array::into_raw);
+        let old = self.current.swap(new, Ordering::SeqCst);
+        let epoch = self.epoch.fetch_xor(1, Ordering::SeqCst);
+
+        while self.readers[epoch].fetch_add(0, Ordering::AcqRel) != 0 {
+            std::thread::yield_now();
+        }
+
+        NonNull::new(old).map(|buffer| Array { buffer })
+    }
+}
+
+impl<T: Interface> Drop for AtomicArray<T> {
+    fn drop(&mut self) {
+        if let Some(buffer) = NonNull::new(*self.current.get_mut()) {
+            drop(Array { buffer });
+        }
+    }
+}
+
+/// A reference-counted, immutable list of delegates whose memory comes from the allocator
+/// chosen by `set_allocator`.
+struct Array<T: Interface> {
+    buffer: NonNull<Buffer<T>>,
+}
+
+#[repr(C)]
+struct Buffer<T: Interface> {
+    count: imp::RefCount,
+    len: usize,
+    delegates: [Delegate<T>; 0],
+}
+
+impl<T: Interface> Array<T> {
+    /// Creates a new `Array<T>` from exactly `len` delegates.
+    fn new<I: Iterator<Item = Delegate<T>>>(len: usize, delegates: I) -> Self {
+        unsafe {
+            let buffer = crate::allocator::allocate(Self::layout(len)) as *mut Buffer<T>;
+            buffer.write(Buffer {
+                count: imp::RefCount::new(1),
+                len: 0,
+                delegates: [],
+            });
+
+            let first = (*buffer).delegates.as_mut_ptr();
+
+            for delegate in delegates.take(len) {
+                first.add((*buffer).len).write(delegate);
+                (*buffer).len += 1;
+            }
+
+            // The buffer is freed with the layout of its length, so it must match the allocation.
+            assert_eq!((*buffer).len, len);
+            Self {
+                buffer: NonNull::new_unchecked(buffer),
+            }
+        }
+    }
+
+    /// Consumes the array, returning the buffer along with its reference.
+    fn into_raw(self) -> *mut Buffer<T> {
+        let buffer = self.buffer.as_ptr();
+        core::mem::forget(self);
+        buffer
+    }
+
+    fn layout(len: usize) -> Layout {
+        Layout::array::<Delegate<T>>(len)
+            .and_then(|delegates| Layout::new::<Buffer<T>>().extend(delegates))
+            .expect("Attempted to allocate too large an Array")
+            .0
+            .pad_to_align()
+    }
+}
+
+impl<T: Interface> core::ops::Deref for Array<T> {
+    type Target = [Delegate<T>];
+
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            let buffer = self.buffer.as_ptr();
+            core::slice::from_raw_parts((*buffer).delegates.as_ptr(), (*buffer).len)
+        }
+    }
+}
+
+impl<T: Interface> Clone for Array<T> {
+    fn clone(&self) -> Self {
+        unsafe { self.buffer.as_ref().count.add_ref() };
+        Self {
+            buffer: self.buffer,
+        }
+    }
+}
+
+impl<T: Interface> Drop for Array<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let buffer = self.buffer.as_ptr();
+
+            if (*buffer).count.release() == 0 {
+                let len = (*buffer).len;
+                core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(
+                    (*buffer).delegates.as_mut_ptr(),
+                    len,
+                ));
+                crate::allocator::deallocate(buffer as *mut u8, Self::layout(len));
+            }
+        }
+    }
+}
+
+/// A registered delegate along with the token that identifies its registration.
+#[derive(Clone)]
+struct Delegate<T: Interface> {
+    token: i64,
+    reference: Reference<T>,
+}
+
 /// Holds either a direct or indirect reference to a delegate. A direct reference is typically
 /// agile while an indirect reference is an agile wrapper.
 #[derive(Clone)]
-enum Delegate<T> {
+enum Reference<T: Interface> {
     Direct(T),
     Indirect(AgileReference<T>),
+    Weak(Weak<T>),
 }
 
 impl<T: Interface> Delegate<T> {
     /// Creates a new `Delegate<T>`, containing a suitable reference to the specified delegate.
-    fn new(delegate: &T) -> Result<Self> {
-        if delegate.cast::<imp::IAgileObject>().is_ok() {
-            Ok(Self::Direct(delegate.clone()))
+    fn new(delegate: &T, token: i64) -> Result<Self> {
+        let reference = if delegate.cast::<imp::IAgileObject>().is_ok() {
+            Reference::Direct(delegate.clone())
         } else {
-            Ok(Self::Indirect(AgileReference::new(delegate)?))
-        }
+            Reference::Indirect(AgileReference::new(delegate)?)
+        };
+
+        Ok(Self { token, reference })
     }
 
-    /// Returns an encoded token to identify the delegate.
-    fn to_token(&self) -> i64 {
-        unsafe {
-            match self {
-                Self::Direct(delegate) => imp::EncodePointer(transmute_copy(delegate)) as i64,
-                Self::Indirect(delegate) => imp::EncodePointer(transmute_copy(delegate)) as i64,
+    /// Creates a new `Delegate<T>`, containing a weak reference to the specified delegate if it
+    /// is agile and supports weak references.
+    fn new_weak(delegate: &T, token: i64) -> Result<Self> {
+        if delegate.cast::<imp::IAgileObject>().is_ok() {
+            if let Ok(weak) = delegate.downgrade() {
+                return Ok(Self {
+                    token,
+                    reference: Reference::Weak(weak),
+                });
             }
         }
+
+        Self::new(delegate, token)
+    }
+
+    /// Returns the token that identifies the delegate's registration.
+    fn to_token(&self) -> i64 {
+        self.token
     }
 
-    /// Invokes the delegates with the provided callback.
-    fn call<F: FnMut(&T) -> Result<()>>(&self, mut callback: F) -> Result<()> {
-        match self {
-            Self::Direct(delegate) => callback(delegate),
-            Self::Indirect(delegate) => callback(&delegate.resolve()?),
+    /// Invokes the delegates with the provided callback, returning `None` if the delegate is weakly
+    /// referenced and has been destroyed.
+    fn call<F: FnMut(&T) -> Result<()>>(&self, mut callback: F) -> Option<Result<()>> {
+        match &self.reference {
+            Reference::Direct(delegate) => Some(callback(delegate)),
+            Reference::Indirect(delegate) => Some(delegate.resolve().and_then(|d| callback(&d))),
+            Reference::Weak(delegate) => delegate.upgrade().map(|d| callback(&d)),
         }
     }
 }
@@ -62,7 +62,153 @@ impl GUID {
             + u64::from_be_bytes(self.data4) as u128
     }
 
+    /// Creates a `GUID` from its 16 bytes in big-endian (RFC 9562) order, as used by `to_u128` and on the wire.
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self::from_u128(u128::from_be_bytes(bytes))
+    }
+
+    /// Converts a `GUID` to its 16 bytes in big-endian (RFC 9562) order.
+    pub const fn to_bytes(&self) -> [u8; 16] {
+        self.to_u128().to_be_bytes()
+    }
+
+    /// Creates a `GUID` from its 16 bytes in memory order, where the first three fields are little-endian.
+    pub const fn from_bytes_le(bytes: [u8; 16]) -> Self {
+        Self {
+            data1: u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            data2: u16::from_le_bytes([bytes[4], bytes[5]]),
+            data3: u16::from_le_bytes([bytes[6], bytes[7]]),
+            data4: [
+                bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14],
+                bytes[15],
+            ],
+        }
+    }
+
+    /// Converts a `GUID` to its 16 bytes in memory order, where the first three fields are little-endian.
+    pub const fn to_bytes_le(&self) -> [u8; 16] {
+        let data1 = self.data1.to_le_bytes();
+        let data2 = self.data2.to_le_bytes();
+        let data3 = self.data3.to_le_bytes();
+        let data4 = self.data4;
+        [
+            data1[0], data1[1], data1[2], data1[3], data2[0], data2[1], data3[0], data3[1],
+            data4[0], data4[1], data4[2], data4[3], data4[4], data4[5], data4[6], data4[7],
+        ]
+    }
+
+    /// Creates a random (version 4) `GUID`.
+    ///
+    /// This is the same as `new`, since `CoCreateGuid` always produces version 4 values.
+    pub fn new_v4() -> Result<Self> {
+        Self::new()
+    }
+
+    /// Creates a name-based (version 5) `GUID` by hashing `name` within `namespace`, as described by RFC 9562.
+    ///
+    /// The same namespace and name always produce the same `GUID`, so this may be used in const contexts.
+    ///
+    /// ```rust,ignore
+    /// const ID: GUID = GUID::new_v5(&GUID::NAMESPACE_DNS, b"www.example.com");
+    /// ```
+    pub const fn new_v5(namespace: &GUID, name: &[u8]) -> Self {
+        let hash = imp::sha1_concat(&namespace.to_bytes(), name).bytes();
+        let mut bytes = [0; 16];
+        let mut i = 0;
+
+        while i < 16 {
+            bytes[i] = hash[i];
+            i += 1;
+        }
+
+        bytes[6] = (bytes[6] & 0x0f) | (5 << 4);
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+        Self::from_bytes(bytes)
+    }
+
+    /// The namespace for fully-qualified domain names, for use with `new_v5`.
+    pub const NAMESPACE_DNS: Self = Self::from_u128(0x6ba7b810_9dad_11d1_80b4_00c04fd430c8);
+
+    /// The namespace for URLs, for use with `new_v5`.
+    pub const NAMESPACE_URL: Self = Self::from_u128(0x6ba7b811_9dad_11d1_80b4_00c04fd430c8);
+
+    /// The namespace for ISO object identifiers, for use with `new_v5`.
+    pub const NAMESPACE_OID: Self = Self::from_u128(0x6ba7b812_9dad_11d1_80b4_00c04fd430c8);
+
+    /// The namespace for X.500 distinguished names, for use with `new_v5`.
+    pub const NAMESPACE_X500: Self = Self::from_u128(0x6ba7b814_9dad_11d1_80b4_00c04fd430c8);
+
+    /// Returns the version number stored in the `GUID`, such as 4 for random values or 5 for name-based values.
+    pub const fn version(&self) -> u8 {
+        (self.data3 >> 12) as u8
+    }
+
+    /// Returns a value that formats the `GUID` in the given form.
+    ///
+    /// The `Display` implementation of the result uses upper case, except for `GuidFormat::Urn` which uses lower
+    /// case. Use the `{:x}` or `{:X}` format specifiers to choose the case explicitly.
+    ///
+    /// ```rust,ignore
+    /// let guid = GUID::from_u128(0x1fd63fef_c0d2_42fe_823a_53a4052b8c8f);
+    /// assert_eq!(guid.display(GuidFormat::Urn).to_string(), "urn:uuid:1fd63fef-c0d2-42fe-823a-53a4052b8c8f");
+    /// assert_eq!(format!("{:x}", guid.display(GuidFormat::Simple)), "1fd63fefc0d242fe823a53a4052b8c8f");
+    /// ```
+    pub const fn display(&self, format: GuidFormat) -> GuidDisplay {
+        GuidDisplay {
+            guid: *self,
+            format,
+        }
+    }
+
+    /// Parses a `GUID` from any of its common textual forms. Hexadecimal digits may be either upper or lower case.
+    ///
+    /// The supported forms are the hyphenated form, optionally surrounded by braces or parentheses or prefixed
+    /// with `urn:uuid:`, the 32 hexadecimal digits without hyphens, with or without braces, and the C initializer
+    /// form such as `{0x1fd63fef, 0xc0d2, 0x42fe, {0x82, 0x3a, 0x53, 0xa4, 0x05, 0x2b, 0x8c, 0x8f}}`.
+    ///
+    /// ```rust,ignore
+    /// let guid = GUID::parse_str("{1FD63FEF-C0D2-42FE-823A-53A4052B8C8F}")?;
+    /// assert_eq!(guid, GUID::parse_str("1fd63fefc0d242fe823a53a4052b8c8f")?);
+    /// ```
+    pub fn parse_str(value: &str) -> Result<Self> {
+        if value.contains(',') {
+            return parse_fields(value);
+        }
+
+        let value = if let Some(value) = value.strip_prefix('{') {
+            value.strip_suffix('}').ok_or_else(invalid_guid)?
+        } else if let Some(value) = value.strip_prefix('(') {
+            value.strip_suffix(')').ok_or_else(invalid_guid)?
+        } else if value
+            .get(..9)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("urn:uuid:"))
+        {
+            &value[9..]
+        } else {
+            value
+        };
+
+        if value.len() != 32 {
+            return Self::try_from(value);
+        }
+
+        let bytes = &mut value.bytes();
+        let data1 = try_u32(bytes, false)?;
+        let data2 = try_u16(bytes, false)?;
+        let data3 = try_u16(bytes, false)?;
+        let mut data4 = [0; 8];
+
+        for byte in &mut data4 {
+            *byte = try_u8(bytes, false)?;
+        }
+
+        Ok(Self::from_values(data1, data2, data3, data4))
+    }
+
     /// Creates a `GUID` for a "generic" WinRT type.
+    ///
+    /// The derivation is a `const fn` so the IIDs of parameterized interfaces are computed at compile
+    /// time and may be used in const contexts.
     pub const fn from_signature(signature: imp::ConstBuffer) -> Self {
         let data = imp::ConstBuffer::from_slice(&[
             0x11, 0xf4, 0x7a, 0xd5, 0x7b, 0x73, 0x42, 0xc0, 0xab, 0xae, 0x87, 0x8b, 0x1e, 0x16,
@@ -157,6 +303,205 @@ impl From<GUID> for u128 {
     }
 }
 
+#[cfg(feature = "windows-sys")]
+impl From<windows_sys::core::GUID> for GUID {
+    fn from(value: windows_sys::core::GUID) -> Self {
+        Self::from_values(value.data1, value.data2, value.data3, value.data4)
+    }
+}
+
+#[cfg(feature = "windows-sys")]
+impl From<GUID> for windows_sys::core::GUID {
+    fn from(value: GUID) -> Self {
+        Self {
+            data1: value.data1,
+            data2: value.data2,
+            data3: value.data3,
+            data4: value.data4,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for GUID {
+    /// Serializes the `GUID` in its string form, such as "00000000-0000-0000-C000-000000000046".
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{self:?}"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GUID {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = GUID;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("a GUID string such as \"00000000-0000-0000-C000-000000000046\"")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> core::result::Result<GUID, E> {
+                GUID::try_from(value)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+impl core::fmt::Display for GUID {
+    /// Formats the `GUID` in upper case with surrounding braces, as the registry does.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{{{self:?}}}")
+    }
+}
+
+impl core::str::FromStr for GUID {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        Self::parse_str(value)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for GUID {
+    fn from(value: uuid::Uuid) -> Self {
+        Self::from_u128(value.as_u128())
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<GUID> for uuid::Uuid {
+    fn from(value: GUID) -> Self {
+        Self::from_u128(value.to_u128())
+    }
+}
+
+/// The textual forms of a `GUID` supported by `GUID::display`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GuidFormat {
+    /// The hyphenated form surrounded by braces, as used by the registry and COM, such as
+    /// `{1FD63FEF-C0D2-42FE-823A-53A4052B8C8F}`.
+    Braced,
+    /// The hyphenated form, such as `1FD63FEF-C0D2-42FE-823A-53A4052B8C8F`.
+    Hyphenated,
+    /// The 32 hexadecimal digits without hyphens, such as `1FD63FEFC0D242FE823A53A4052B8C8F`.
+    Simple,
+    /// The URN form, such as `urn:uuid:1fd63fef-c0d2-42fe-823a-53a4052b8c8f`.
+    Urn,
+}
+
+/// Formats a `GUID` in a particular form. Returned by `GUID::display`.
+#[derive(Clone, Copy, Debug)]
+pub struct GuidDisplay {
+    guid: GUID,
+    format: GuidFormat,
+}
+
+impl GuidDisplay {
+    fn write(&self, f: &mut core::fmt::Formatter<'_>, upper: bool) -> core::fmt::Result {
+        let (prefix, hyphen, suffix) = match self.format {
+            GuidFormat::Braced => ("{", "-", "}"),
+            GuidFormat::Hyphenated => ("", "-", ""),
+            GuidFormat::Simple => ("", "", ""),
+            GuidFormat::Urn => ("urn:uuid:", "-", ""),
+        };
+
+        f.write_str(prefix)?;
+
+        for (index, byte) in self.guid.to_bytes().iter().enumerate() {
+            if matches!(index, 4 | 6 | 8 | 10) {
+                f.write_str(hyphen)?;
+            }
+
+            if upper {
+                write!(f, "{byte:02X}")?;
+            } else {
+                write!(f, "{byte:02x}")?;
+            }
+        }
+
+        f.write_str(suffix)
+    }
+}
+
+impl core::fmt::Display for GuidDisplay {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.write(f, self.format != GuidFormat::Urn)
+    }
+}
+
+impl core::fmt::LowerHex for GuidDisplay {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.write(f, false)
+    }
+}
+
+impl core::fmt::UpperHex for GuidDisplay {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.write(f, true)
+    }
+}
+
+/// Parses the C initializer form, such as `{0x1fd63fef, 0xc0d2, 0x42fe, {0x82, 0x3a, 0x53, 0xa4, 0x05, 0x2b, 0x8c, 0x8f}}`.
+fn parse_fields(value: &str) -> Result<GUID> {
+    let value = value
+        .strip_prefix('{')
+        .and_then(|value| value.strip_suffix('}'))
+        .ok_or_else(invalid_guid)?;
+
+    // The inner braces must open the fourth field and close the last one, and may appear nowhere else.
+    let mut fields =
+        value
+            .split(',')
+            .map(str::trim)
+            .enumerate()
+            .map(|(index, field)| match index {
+                3 => field.strip_prefix('{').map(str::trim_start),
+                10 => field.strip_suffix('}').map(str::trim_end),
+                _ => Some(field),
+            });
+
+    let mut next = |digits: usize| {
+        let field = fields.next().flatten().ok_or_else(invalid_guid)?;
+        let field = field
+            .strip_prefix("0x")
+            .or_else(|| field.strip_prefix("0X"))
+            .ok_or_else(invalid_guid)?;
+
+        if field.is_empty() || field.len() > digits || !field.bytes().all(|b| b.is_ascii_hexdigit())
+        {
+            return Err(invalid_guid());
+        }
+
+        u32::from_str_radix(field, 16).map_err(|_| invalid_guid())
+    };
+
+    let data1 = next(8)?;
+    let data2 = next(4)? as u16;
+    let data3 = next(4)? as u16;
+    let mut data4 = [0; 8];
+
+    for byte in &mut data4 {
+        *byte = next(2)? as u8;
+    }
+
+    if fields.next().is_some() {
+        return Err(invalid_guid());
+    }
+
+    Ok(GUID::from_values(data1, data2, data3, data4))
+}
+
 fn invalid_guid() -> Error {
     Error::from_hresult(imp::E_INVALIDARG)
 }
@@ -1,3 +1,5 @@
+use super::*;
+
 /// Custom code to free a handle.
 ///
 /// This is similar to the [`Drop`] trait, and may be used to implement [`Drop`], but allows handles
@@ -24,6 +26,37 @@ impl<T: Free> Owned<T> {
     pub unsafe fn new(x: T) -> Self {
         Self(x)
     }
+
+    /// Calls a function that returns a handle through an out parameter and takes ownership of the handle.
+    ///
+    /// The handle is freed if the function fails after returning it.
+    ///
+    /// # Safety
+    ///
+    /// Any handle returned by the function must be owned by the caller and safe to free.
+    pub unsafe fn receive<F: FnOnce(&mut T) -> Result<()>>(f: F) -> Result<Self>
+    where
+        T: Default,
+    {
+        let mut owned = Self(T::default());
+        f(&mut owned.0)?;
+        Ok(owned)
+    }
+
+    /// Returns a borrowed handle that cannot outlive this owned handle.
+    pub fn borrow(&self) -> Borrowed<'_, T>
+    where
+        T: Copy,
+    {
+        Borrowed(self.0, core::marker::PhantomData)
+    }
+
+    /// Releases ownership of the handle without freeing it.
+    pub fn into_raw(self) -> T {
+        let this = core::mem::ManuallyDrop::new(self);
+        // SAFETY: the handle is moved out of the wrapper exactly once and the wrapper is not dropped.
+        unsafe { core::ptr::read(&this.0) }
+    }
 }
 
 impl<T: Free> Drop for Owned<T> {
@@ -44,3 +77,196 @@ impl<T: Free> core::ops::DerefMut for Owned<T> {
         &mut self.0
     }
 }
+
+/// A handle borrowed from an owner for the lifetime `'a`.
+///
+/// This is the counterpart to [`Owned`] for functions that use a handle without taking ownership of it.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Borrowed<'a, T>(T, core::marker::PhantomData<&'a T>);
+
+impl<T: Copy> Borrowed<'_, T> {
+    /// Borrows a handle.
+    ///
+    /// # Safety
+    ///
+    /// The handle must remain valid for the lifetime of the borrowed handle.
+    pub unsafe fn borrow_raw(x: T) -> Self {
+        Self(x, core::marker::PhantomData)
+    }
+}
+
+impl<T> core::ops::Deref for Borrowed<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A handle to a kernel object that is closed with `CloseHandle`, which may be exchanged with the handle types in
+/// [`std::os::windows::io`].
+///
+/// This lets an [`Owned`] handle be converted to and from an [`OwnedHandle`](std::os::windows::io::OwnedHandle),
+/// and so to and from standard library types such as [`File`](std::fs::File) and [`Child`](std::process::Child).
+///
+/// # Safety
+///
+/// The handle's [`Free`] implementation must close it with `CloseHandle`, just as `OwnedHandle` does.
+#[cfg(feature = "std")]
+pub unsafe trait KernelHandle: Free + Copy {
+    /// Creates the handle from a raw handle value.
+    fn from_raw_handle(handle: std::os::windows::io::RawHandle) -> Self;
+
+    /// Returns the raw handle value.
+    fn as_raw_handle(&self) -> std::os::windows::io::RawHandle;
+}
+
+#[cfg(feature = "std")]
+impl<T: KernelHandle> From<std::os::windows::io::OwnedHandle> for Owned<T> {
+    fn from(handle: std::os::windows::io::OwnedHandle) -> Self {
+        Self(T::from_raw_handle(
+            std::os::windows::io::IntoRawHandle::into_raw_handle(handle),
+        ))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: KernelHandle> From<Owned<T>> for std::os::windows::io::OwnedHandle {
+    fn from(handle: Owned<T>) -> Self {
+        // SAFETY: the handle is owned and, per `KernelHandle`, is closed with `CloseHandle`.
+        unsafe {
+            std::os::windows::io::FromRawHandle::from_raw_handle(handle.into_raw().as_raw_handle())
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: KernelHandle> std::os::windows::io::AsRawHandle for Owned<T> {
+    fn as_raw_handle(&self) -> std::os::windows::io::RawHandle {
+        self.0.as_raw_handle()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: KernelHandle> std::os::windows::io::FromRawHandle for Owned<T> {
+    unsafe fn from_raw_handle(handle: std::os::windows::io::RawHandle) -> Self {
+        Self(T::from_raw_handle(handle))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: KernelHandle> std::os::windows::io::IntoRawHandle for Owned<T> {
+    fn into_raw_handle(self) -> std::os::windows::io::RawHandle {
+        self.into_raw().as_raw_handle()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: KernelHandle> std::os::windows::io::AsHandle for Owned<T> {
+    fn as_handle(&self) -> std::os::windows::io::BorrowedHandle<'_> {
+        // SAFETY: the handle remains open for as long as it is borrowed from the owner.
+        unsafe { std::os::windows::io::BorrowedHandle::borrow_raw(self.0.as_raw_handle()) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: KernelHandle> std::os::windows::io::AsHandle for Borrowed<'_, T> {
+    fn as_handle(&self) -> std::os::windows::io::BorrowedHandle<'_> {
+        // SAFETY: the handle remains open for the lifetime of the borrow.
+        unsafe { std::os::windows::io::BorrowedHandle::borrow_raw(self.0.as_raw_handle()) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: KernelHandle> From<std::os::windows::io::BorrowedHandle<'a>> for Borrowed<'a, T> {
+    fn from(handle: std::os::windows::io::BorrowedHandle<'a>) -> Self {
+        Self(
+            T::from_raw_handle(std::os::windows::io::AsRawHandle::as_raw_handle(&handle)),
+            core::marker::PhantomData,
+        )
+    }
+}
+
+/// A Windows Sockets handle that is closed with `closesocket`, which may be exchanged with the socket types in
+/// [`std::os::windows::io`].
+///
+/// This lets an [`Owned`] socket be converted to and from an [`OwnedSocket`](std::os::windows::io::OwnedSocket),
+/// and so to and from standard library types such as [`TcpStream`](std::net::TcpStream).
+///
+/// # Safety
+///
+/// The socket's [`Free`] implementation must close it with `closesocket`, just as `OwnedSocket` does.
+#[cfg(feature = "std")]
+pub unsafe trait SocketHandle: Free + Copy {
+    /// Creates the socket from a raw socket value.
+    fn from_raw_socket(socket: std::os::windows::io::RawSocket) -> Self;
+
+    /// Returns the raw socket value.
+    fn as_raw_socket(&self) -> std::os::windows::io::RawSocket;
+}
+
+#[cfg(feature = "std")]
+impl<T: SocketHandle> From<std::os::windows::io::OwnedSocket> for Owned<T> {
+    fn from(socket: std::os::windows::io::OwnedSocket) -> Self {
+        Self(T::from_raw_socket(
+            std::os::windows::io::IntoRawSocket::into_raw_socket(socket),
+        ))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: SocketHandle> From<Owned<T>> for std::os::windows::io::OwnedSocket {
+    fn from(socket: Owned<T>) -> Self {
+        // SAFETY: the socket is owned and, per `SocketHandle`, is closed with `closesocket`.
+        unsafe {
+            std::os::windows::io::FromRawSocket::from_raw_socket(socket.into_raw().as_raw_socket())
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: SocketHandle> std::os::windows::io::AsRawSocket for Owned<T> {
+    fn as_raw_socket(&self) -> std::os::windows::io::RawSocket {
+        self.0.as_raw_socket()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: SocketHandle> std::os::windows::io::FromRawSocket for Owned<T> {
+    unsafe fn from_raw_socket(socket: std::os::windows::io::RawSocket) -> Self {
+        Self(T::from_raw_socket(socket))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: SocketHandle> std::os::windows::io::IntoRawSocket for Owned<T> {
+    fn into_raw_socket(self) -> std::os::windows::io::RawSocket {
+        self.into_raw().as_raw_socket()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: SocketHandle> std::os::windows::io::AsSocket for Owned<T> {
+    fn as_socket(&self) -> std::os::windows::io::BorrowedSocket<'_> {
+        // SAFETY: the socket remains open for as long as it is borrowed from the owner.
+        unsafe { std::os::windows::io::BorrowedSocket::borrow_raw(self.0.as_raw_socket()) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: SocketHandle> std::os::windows::io::AsSocket for Borrowed<'_, T> {
+    fn as_socket(&self) -> std::os::windows::io::BorrowedSocket<'_> {
+        // SAFETY: the socket remains open for the lifetime of the borrow.
+        unsafe { std::os::windows::io::BorrowedSocket::borrow_raw(self.0.as_raw_socket()) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: SocketHandle> From<std::os::windows::io::BorrowedSocket<'a>> for Borrowed<'a, T> {
+    fn from(socket: std::os::windows::io::BorrowedSocket<'a>) -> Self {
+        Self(
+            T::from_raw_socket(std::os::windows::io::AsRawSocket::as_raw_socket(&socket)),
+            core::marker::PhantomData,
+        )
+    }
+}
@@ -7,30 +7,55 @@
 )]
 windows_targets::link!("api-ms-win-core-winrt-l1-1-0.dll" "system" fn RoGetActivationFactory(activatableclassid : * mut core::ffi::c_void, iid : *const GUID, factory : *mut *mut core::ffi::c_void) -> HRESULT);
 windows_targets::link!("kernel32.dll" "system" fn CloseHandle(hobject : HANDLE) -> BOOL);
+windows_targets::link!("kernel32.dll" "system" fn CloseThreadpoolTimer(pti : PTP_TIMER));
 windows_targets::link!("kernel32.dll" "system" fn CreateEventW(lpeventattributes : *const SECURITY_ATTRIBUTES, bmanualreset : BOOL, binitialstate : BOOL, lpname : PCWSTR) -> HANDLE);
-windows_targets::link!("kernel32.dll" "system" fn EncodePointer(ptr : *const core::ffi::c_void) -> *mut core::ffi::c_void);
+windows_targets::link!("kernel32.dll" "system" fn CreateThreadpoolTimer(pfnti : PTP_TIMER_CALLBACK, pv : *mut core::ffi::c_void, pcbe : *const TP_CALLBACK_ENVIRON_V3) -> PTP_TIMER);
 windows_targets::link!("kernel32.dll" "system" fn FreeLibrary(hlibmodule : HMODULE) -> BOOL);
 windows_targets::link!("kernel32.dll" "system" fn GetProcAddress(hmodule : HMODULE, lpprocname : PCSTR) -> FARPROC);
 windows_targets::link!("kernel32.dll" "system" fn LoadLibraryExA(lplibfilename : PCSTR, hfile : HANDLE, dwflags : LOAD_LIBRARY_FLAGS) -> HMODULE);
 windows_targets::link!("kernel32.dll" "system" fn SetEvent(hevent : HANDLE) -> BOOL);
+windows_targets::link!("kernel32.dll" "system" fn SetThreadpoolTimer(pti : PTP_TIMER, pftduetime : *const FILETIME, msperiod : u32, mswindowlength : u32));
+windows_targets::link!("kernel32.dll" "system" fn TrySubmitThreadpoolCallback(pfns : PTP_SIMPLE_CALLBACK, pv : *mut core::ffi::c_void, pcbe : *const TP_CALLBACK_ENVIRON_V3) -> BOOL);
 windows_targets::link!("kernel32.dll" "system" fn WaitForSingleObject(hhandle : HANDLE, dwmilliseconds : u32) -> WAIT_EVENT);
+windows_targets::link!("kernel32.dll" "system" fn WaitForThreadpoolTimerCallbacks(pti : PTP_TIMER, fcancelpendingcallbacks : BOOL));
 windows_targets::link!("ole32.dll" "system" fn CoIncrementMTAUsage(pcookie : *mut CO_MTA_USAGE_COOKIE) -> HRESULT);
 windows_targets::link!("ole32.dll" "system" fn CoTaskMemAlloc(cb : usize) -> *mut core::ffi::c_void);
 windows_targets::link!("ole32.dll" "system" fn CoTaskMemFree(pv : *const core::ffi::c_void));
+windows_targets::link!("ole32.dll" "system" fn CoWaitForMultipleHandles(dwflags : u32, dwtimeout : u32, chandles : u32, phandles : *const HANDLE, lpdwindex : *mut u32) -> HRESULT);
 windows_targets::link!("ole32.dll" "system" fn PropVariantClear(pvar : *mut PROPVARIANT) -> HRESULT);
 windows_targets::link!("ole32.dll" "system" fn PropVariantCopy(pvardest : *mut PROPVARIANT, pvarsrc : *const PROPVARIANT) -> HRESULT);
+windows_targets::link!("oleaut32.dll" "system" fn VariantChangeType(pvargdest : *mut VARIANT, pvarsrc : *const VARIANT, wflags : VAR_CHANGE_FLAGS, vt : VARENUM) -> HRESULT);
 windows_targets::link!("oleaut32.dll" "system" fn VariantClear(pvarg : *mut VARIANT) -> HRESULT);
 windows_targets::link!("oleaut32.dll" "system" fn VariantCopy(pvargdest : *mut VARIANT, pvargsrc : *const VARIANT) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn InitPropVariantFromCLSID(clsid : *const GUID, ppropvar : *mut PROPVARIANT) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn InitPropVariantFromDoubleVector(prgn : *const f64, celems : u32, ppropvar : *mut PROPVARIANT) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn InitPropVariantFromInt16Vector(prgn : *const i16, celems : u32, ppropvar : *mut PROPVARIANT) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn InitPropVariantFromInt32Vector(prgn : *const i32, celems : u32, ppropvar : *mut PROPVARIANT) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn InitPropVariantFromInt64Vector(prgn : *const i64, celems : u32, ppropvar : *mut PROPVARIANT) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn InitPropVariantFromStringVector(prgsz : *const PCWSTR, celems : u32, ppropvar : *mut PROPVARIANT) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn InitPropVariantFromUInt16Vector(prgn : *const u16, celems : u32, ppropvar : *mut PROPVARIANT) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn InitPropVariantFromUInt32Vector(prgn : *const u32, celems : u32, ppropvar : *mut PROPVARIANT) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn InitPropVariantFromUInt64Vector(prgn : *const u64, celems : u32, ppropvar : *mut PROPVARIANT) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn PropVariantChangeType(ppropvardest : *mut PROPVARIANT, propvarsrc : *const PROPVARIANT, flags : PROPVAR_CHANGE_FLAGS, vt : VARENUM) -> HRESULT);
 windows_targets::link!("propsys.dll" "system" fn PropVariantCompareEx(propvar1 : *const PROPVARIANT, propvar2 : *const PROPVARIANT, unit : PROPVAR_COMPARE_UNIT, flags : PROPVAR_COMPARE_FLAGS) -> i32);
 windows_targets::link!("propsys.dll" "system" fn PropVariantToBSTR(propvar : *const PROPVARIANT, pbstrout : *mut BSTR) -> HRESULT);
 windows_targets::link!("propsys.dll" "system" fn PropVariantToBoolean(propvarin : *const PROPVARIANT, pfret : *mut BOOL) -> HRESULT);
 windows_targets::link!("propsys.dll" "system" fn PropVariantToDouble(propvarin : *const PROPVARIANT, pdblret : *mut f64) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn PropVariantToDoubleVectorAlloc(propvar : *const PROPVARIANT, pprgn : *mut *mut f64, pcelem : *mut u32) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn PropVariantToGUID(propvar : *const PROPVARIANT, pguid : *mut GUID) -> HRESULT);
 windows_targets::link!("propsys.dll" "system" fn PropVariantToInt16(propvarin : *const PROPVARIANT, piret : *mut i16) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn PropVariantToInt16VectorAlloc(propvar : *const PROPVARIANT, pprgn : *mut *mut i16, pcelem : *mut u32) -> HRESULT);
 windows_targets::link!("propsys.dll" "system" fn PropVariantToInt32(propvarin : *const PROPVARIANT, plret : *mut i32) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn PropVariantToInt32VectorAlloc(propvar : *const PROPVARIANT, pprgn : *mut *mut i32, pcelem : *mut u32) -> HRESULT);
 windows_targets::link!("propsys.dll" "system" fn PropVariantToInt64(propvarin : *const PROPVARIANT, pllret : *mut i64) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn PropVariantToInt64VectorAlloc(propvar : *const PROPVARIANT, pprgn : *mut *mut i64, pcelem : *mut u32) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn PropVariantToStringVectorAlloc(propvar : *const PROPVARIANT, pprgsz : *mut *mut PWSTR, pcelem : *mut u32) -> HRESULT);
 windows_targets::link!("propsys.dll" "system" fn PropVariantToUInt16(propvarin : *const PROPVARIANT, puiret : *mut u16) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn PropVariantToUInt16VectorAlloc(propvar : *const PROPVARIANT, pprgn : *mut *mut u16, pcelem : *mut u32) -> HRESULT);
 windows_targets::link!("propsys.dll" "system" fn PropVariantToUInt32(propvarin : *const PROPVARIANT, pulret : *mut u32) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn PropVariantToUInt32VectorAlloc(propvar : *const PROPVARIANT, pprgn : *mut *mut u32, pcelem : *mut u32) -> HRESULT);
 windows_targets::link!("propsys.dll" "system" fn PropVariantToUInt64(propvarin : *const PROPVARIANT, pullret : *mut u64) -> HRESULT);
+windows_targets::link!("propsys.dll" "system" fn PropVariantToUInt64VectorAlloc(propvar : *const PROPVARIANT, pprgn : *mut *mut u64, pcelem : *mut u32) -> HRESULT);
 windows_targets::link!("propsys.dll" "system" fn PropVariantToVariant(ppropvar : *const PROPVARIANT, pvar : *mut VARIANT) -> HRESULT);
 windows_targets::link!("propsys.dll" "system" fn VariantToBoolean(varin : *const VARIANT, pfret : *mut BOOL) -> HRESULT);
 windows_targets::link!("propsys.dll" "system" fn VariantToDouble(varin : *const VARIANT, pdblret : *mut f64) -> HRESULT);
@@ -210,6 +235,9 @@ pub struct CLIPDATA {
     pub ulClipFmt: i32,
     pub pClipData: *mut u8,
 }
+pub const COWAIT_DISPATCH_CALLS: COWAIT_FLAGS = 8i32;
+pub const COWAIT_DISPATCH_WINDOW_MESSAGES: COWAIT_FLAGS = 16i32;
+pub type COWAIT_FLAGS = i32;
 pub type CO_MTA_USAGE_COOKIE = *mut core::ffi::c_void;
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -277,6 +305,7 @@ pub union ELEMDESC_0 {
     pub idldesc: IDLDESC,
     pub paramdesc: PARAMDESC,
 }
+pub const ERROR_TIMEOUT: WIN32_ERROR = 1460u32;
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct EXCEPINFO {
@@ -344,6 +373,7 @@ pub struct IDLDESC {
 }
 pub type IDLFLAGS = u16;
 pub type IMPLTYPEFLAGS = i32;
+pub const INFINITE: u32 = 4294967295u32;
 pub type INVOKEKIND = i32;
 pub type LOAD_LIBRARY_FLAGS = u32;
 pub const LOAD_LIBRARY_SEARCH_DEFAULT_DIRS: LOAD_LIBRARY_FLAGS = 4096u32;
@@ -461,9 +491,30 @@ pub union PROPVARIANT_0_0_0 {
     pub pparray: *mut *mut SAFEARRAY,
     pub pvarVal: *mut PROPVARIANT,
 }
+pub type PROPVAR_CHANGE_FLAGS = i32;
 pub type PROPVAR_COMPARE_FLAGS = i32;
 pub type PROPVAR_COMPARE_UNIT = i32;
 pub type PSTR = *mut u8;
+pub type PTP_CALLBACK_INSTANCE = isize;
+pub type PTP_CLEANUP_GROUP = isize;
+pub type PTP_CLEANUP_GROUP_CANCEL_CALLBACK = Option<
+    unsafe extern "system" fn(
+        objectcontext: *mut core::ffi::c_void,
+        cleanupcontext: *mut core::ffi::c_void,
+    ),
+>;
+pub type PTP_POOL = isize;
+pub type PTP_SIMPLE_CALLBACK = Option<
+    unsafe extern "system" fn(instance: PTP_CALLBACK_INSTANCE, context: *mut core::ffi::c_void),
+>;
+pub type PTP_TIMER = isize;
+pub type PTP_TIMER_CALLBACK = Option<
+    unsafe extern "system" fn(
+        instance: PTP_CALLBACK_INSTANCE,
+        context: *mut core::ffi::c_void,
+        timer: PTP_TIMER,
+    ),
+>;
 pub type PWSTR = *mut u16;
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -518,6 +569,32 @@ pub struct TLIBATTR {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct TP_CALLBACK_ENVIRON_V3 {
+    pub Version: u32,
+    pub Pool: PTP_POOL,
+    pub CleanupGroup: PTP_CLEANUP_GROUP,
+    pub CleanupGroupCancelCallback: PTP_CLEANUP_GROUP_CANCEL_CALLBACK,
+    pub RaceDll: *mut core::ffi::c_void,
+    pub ActivationContext: isize,
+    pub FinalizationCallback: PTP_SIMPLE_CALLBACK,
+    pub u: TP_CALLBACK_ENVIRON_V3_0,
+    pub CallbackPriority: TP_CALLBACK_PRIORITY,
+    pub Size: u32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union TP_CALLBACK_ENVIRON_V3_0 {
+    pub Flags: u32,
+    pub s: TP_CALLBACK_ENVIRON_V3_0_0,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TP_CALLBACK_ENVIRON_V3_0_0 {
+    pub _bitfield: u32,
+}
+pub type TP_CALLBACK_PRIORITY = i32;
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct TYPEATTR {
     pub guid: GUID,
     pub lcid: u32,
@@ -648,6 +725,7 @@ pub struct VARIANT_0_0_0_0 {
 }
 pub type VARIANT_BOOL = i16;
 pub type VARKIND = i32;
+pub type VAR_CHANGE_FLAGS = u16;
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct VERSIONEDSTREAM {
@@ -661,6 +739,7 @@ pub const VT_I1: VARENUM = 16u16;
 pub const VT_I2: VARENUM = 2u16;
 pub const VT_I4: VARENUM = 3u16;
 pub const VT_I8: VARENUM = 20u16;
+pub const VT_NULL: VARENUM = 1u16;
 pub const VT_R4: VARENUM = 4u16;
 pub const VT_R8: VARENUM = 5u16;
 pub const VT_UI1: VARENUM = 17u16;
@@ -669,3 +748,4 @@ pub const VT_UI4: VARENUM = 19u16;
 pub const VT_UI8: VARENUM = 21u16;
 pub const VT_UNKNOWN: VARENUM = 13u16;
 pub type WAIT_EVENT = u32;
+pub type WIN32_ERROR = u32;
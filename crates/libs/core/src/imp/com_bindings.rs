@@ -12,6 +12,15 @@ pub unsafe fn CoCreateGuid() -> windows_core::Result<windows_core::GUID> {
     CoCreateGuid(&mut result__).map(|| result__)
 }
 #[inline]
+pub unsafe fn CoGetObjectContext<T>() -> windows_core::Result<T>
+where
+    T: windows_core::Interface,
+{
+    windows_targets::link!("ole32.dll" "system" fn CoGetObjectContext(riid : *const windows_core::GUID, ppv : *mut *mut core::ffi::c_void) -> windows_core::HRESULT);
+    let mut result__ = core::ptr::null_mut();
+    CoGetObjectContext(&T::IID, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+}
+#[inline]
 pub unsafe fn RoGetAgileReference<P0>(
     options: AgileReferenceOptions,
     riid: *const windows_core::GUID,
@@ -40,7 +49,24 @@ impl core::fmt::Debug for AgileReferenceOptions {
     }
 }
 pub const CO_E_NOTINITIALIZED: windows_core::HRESULT = windows_core::HRESULT(0x800401F0_u32 as _);
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ComCallData {
+    pub dwDispid: u32,
+    pub dwReserved: u32,
+    pub pUserDefined: *mut core::ffi::c_void,
+}
+impl windows_core::TypeKind for ComCallData {
+    type TypeKind = windows_core::CopyType;
+}
+impl Default for ComCallData {
+    fn default() -> Self {
+        unsafe { core::mem::zeroed() }
+    }
+}
+pub const E_ABORT: windows_core::HRESULT = windows_core::HRESULT(0x80004004_u32 as _);
 pub const E_BOUNDS: windows_core::HRESULT = windows_core::HRESULT(0x8000000B_u32 as _);
+pub const E_ILLEGAL_METHOD_CALL: windows_core::HRESULT = windows_core::HRESULT(0x8000000E_u32 as _);
 pub const E_INVALIDARG: windows_core::HRESULT = windows_core::HRESULT(0x80070057_u32 as _);
 pub const E_NOINTERFACE: windows_core::HRESULT = windows_core::HRESULT(0x80004002_u32 as _);
 pub const E_OUTOFMEMORY: windows_core::HRESULT = windows_core::HRESULT(0x8007000E_u32 as _);
@@ -97,6 +123,53 @@ pub struct IAgileReference_Vtbl {
         *mut *mut core::ffi::c_void,
     ) -> windows_core::HRESULT,
 }
+windows_core::imp::define_interface!(
+    IContextCallback,
+    IContextCallback_Vtbl,
+    0x000001da_0000_0000_c000_000000000046
+);
+impl core::ops::Deref for IContextCallback {
+    type Target = windows_core::IUnknown;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IContextCallback, windows_core::IUnknown);
+impl IContextCallback {
+    pub unsafe fn ContextCallback<P0>(
+        &self,
+        pfncallback: PFNCONTEXTCALL,
+        pparam: *const ComCallData,
+        riid: *const windows_core::GUID,
+        imethod: i32,
+        punk: P0,
+    ) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::IUnknown>,
+    {
+        (windows_core::Interface::vtable(self).ContextCallback)(
+            windows_core::Interface::as_raw(self),
+            pfncallback,
+            pparam,
+            riid,
+            imethod,
+            punk.param().abi(),
+        )
+        .ok()
+    }
+}
+#[repr(C)]
+pub struct IContextCallback_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub ContextCallback: unsafe extern "system" fn(
+        *mut core::ffi::c_void,
+        PFNCONTEXTCALL,
+        *const ComCallData,
+        *const windows_core::GUID,
+        i32,
+        *mut core::ffi::c_void,
+    ) -> windows_core::HRESULT,
+}
 windows_core::imp::define_interface!(
     IWeakReference,
     IWeakReference_Vtbl,
@@ -163,5 +236,9 @@ pub struct IWeakReferenceSource_Vtbl {
     ) -> windows_core::HRESULT,
 }
 pub const JSCRIPT_E_CANTEXECUTE: windows_core::HRESULT = windows_core::HRESULT(0x89020001_u32 as _);
+pub type PFNCONTEXTCALL =
+    Option<unsafe extern "system" fn(pparam: *mut ComCallData) -> windows_core::HRESULT>;
+pub const RO_E_CLOSED: windows_core::HRESULT = windows_core::HRESULT(0x80000013_u32 as _);
 pub const RPC_E_DISCONNECTED: windows_core::HRESULT = windows_core::HRESULT(0x80010108_u32 as _);
+pub const RPC_E_WRONG_THREAD: windows_core::HRESULT = windows_core::HRESULT(0x8001010E_u32 as _);
 pub const TYPE_E_TYPEMISMATCH: windows_core::HRESULT = windows_core::HRESULT(0x80028CA0_u32 as _);
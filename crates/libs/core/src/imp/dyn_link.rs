@@ -0,0 +1,48 @@
+use super::delay_load;
+use core::ffi::c_void;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+// Marks a function that could not be found so that it isn't looked up again.
+const MISSING: *mut c_void = usize::MAX as *mut c_void;
+
+/// Caches the address of a function resolved by the `dyn_link` macro.
+#[doc(hidden)]
+pub struct DynLink(AtomicPtr<c_void>);
+
+impl DynLink {
+    pub const fn new() -> Self {
+        Self(AtomicPtr::new(core::ptr::null_mut()))
+    }
+
+    /// Returns the address of the function, loading the library and looking up the function the first time.
+    ///
+    /// # Safety
+    ///
+    /// The library and function names must be null-terminated.
+    pub unsafe fn load(&self, library: &[u8], function: &[u8]) -> Option<*mut c_void> {
+        let mut address = self.0.load(Ordering::Acquire);
+
+        if address.is_null() {
+            // Racing threads resolve the same address, so the library's extra reference is harmless.
+            address = delay_load(
+                crate::PCSTR(library.as_ptr()),
+                crate::PCSTR(function.as_ptr()),
+            )
+            .unwrap_or(MISSING);
+
+            self.0.store(address, Ordering::Release);
+        }
+
+        if address == MISSING {
+            None
+        } else {
+            Some(address)
+        }
+    }
+}
+
+impl Default for DynLink {
+    fn default() -> Self {
+        Self::new()
+    }
+}
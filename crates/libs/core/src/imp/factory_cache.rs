@@ -30,37 +30,38 @@ impl<C, I> Default for FactoryCache<C, I> {
 
 impl<C: crate::RuntimeName, I: Interface> FactoryCache<C, I> {
     pub fn call<R, F: FnOnce(&I) -> crate::Result<R>>(&self, callback: F) -> crate::Result<R> {
-        loop {
-            // Attempt to load a previously cached factory pointer.
-            let ptr = self.shared.load(Ordering::Relaxed);
+        // Each class/interface pair has its own cache so activations never contend on a shared
+        // lock. Once primed, the fast path is a single atomic load.
+        let ptr = self.shared.load(Ordering::Acquire);
 
-            // If a pointer is found, the cache is primed and we're good to go.
-            if !ptr.is_null() {
-                return callback(unsafe { transmute::<&*mut c_void, &I>(&ptr) });
-            }
+        // If a pointer is found, the cache is primed and we're good to go.
+        if !ptr.is_null() {
+            return callback(unsafe { transmute::<&*mut c_void, &I>(&ptr) });
+        }
 
-            // Otherwise, we load the factory the usual way.
-            let factory = factory::<C, I>()?;
-
-            // If the factory is agile, we can safely cache it.
-            if factory.cast::<IAgileObject>().is_ok() {
-                if self
-                    .shared
-                    .compare_exchange_weak(
-                        null_mut(),
-                        factory.as_raw(),
-                        Ordering::Relaxed,
-                        Ordering::Relaxed,
-                    )
-                    .is_ok()
-                {
-                    forget(factory);
-                }
-            } else {
-                // Otherwise, for non-agile factories we simply use the factory
-                // and discard after use as it is not safe to cache.
-                return callback(&factory);
+        // Otherwise, we load the factory the usual way.
+        let factory = factory::<C, I>()?;
+
+        // If the factory is not agile, we simply use the factory and discard after use as it is
+        // not safe to cache.
+        if factory.cast::<IAgileObject>().is_err() {
+            return callback(&factory);
+        }
+
+        // Otherwise, publish it. If another thread raced us and won, use its factory instead and
+        // let ours drop rather than loading it again.
+        match self.shared.compare_exchange(
+            null_mut(),
+            factory.as_raw(),
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => {
+                let result = callback(&factory);
+                forget(factory);
+                result
             }
+            Err(existing) => callback(unsafe { transmute::<&*mut c_void, &I>(&existing) }),
         }
     }
 }
@@ -71,14 +72,25 @@ unsafe impl<C, I> Sync for FactoryCache<C, I> {}
 /// Attempts to load the factory object for the given WinRT class.
 /// This can be used to access COM interfaces implemented on a Windows Runtime class factory.
 pub fn factory<C: crate::RuntimeName, I: Interface>() -> crate::Result<I> {
-    let mut factory: Option<I> = None;
-    let name = crate::HSTRING::from(C::NAME);
+    // SAFETY: the factory was queried using the `IID` tied to `I` so it is safe to take ownership of it as `I`.
+    factory_raw(C::NAME, &I::IID).map(|raw| unsafe { I::from_raw(raw) })
+}
+
+// This holds the bulk of `factory` and is kept free of generic parameters so that it is only compiled
+// once rather than for every class/interface pair.
+fn factory_raw(class: &str, iid: &crate::GUID) -> crate::Result<*mut c_void> {
+    #[cfg(feature = "tracing")]
+    let _span =
+        tracing::debug_span!(target: "windows", "factory", class, interface = ?iid).entered();
+
+    let mut factory: *mut c_void = null_mut();
+    let name = crate::HSTRING::from(class);
 
     let code = unsafe {
         let mut get_com_factory = || {
             crate::HRESULT(RoGetActivationFactory(
                 transmute_copy(&name),
-                &I::IID as *const _ as _,
+                iid as *const _ as _,
                 &mut factory as *mut _ as *mut _,
             ))
         };
@@ -98,7 +110,7 @@ pub fn factory<C: crate::RuntimeName, I: Interface>() -> crate::Result<I> {
     };
 
     // If this succeeded then return the resulting factory interface.
-    if let Some(factory) = factory {
+    if !factory.is_null() {
         return Ok(factory);
     }
 
@@ -107,10 +119,10 @@ pub fn factory<C: crate::RuntimeName, I: Interface>() -> crate::Result<I> {
     let original: crate::Error = code.into();
 
     // Now attempt to find the factory's implementation heuristically.
-    if let Some(i) = search_path(C::NAME, |library| unsafe {
+    if let Some(i) = search_path(class, |library| unsafe {
         get_activation_factory(library, &name)
     }) {
-        i.cast()
+        unsafe { crate::interface::query_raw(i.as_raw(), iid).map(|raw| raw.as_ptr()) }
     } else {
         Err(original)
     }
@@ -153,7 +165,7 @@ unsafe fn get_activation_factory(
     function(transmute_copy(name), &mut abi).and_then(|| crate::Type::from_abi(abi))
 }
 
-unsafe fn delay_load<T>(library: crate::PCSTR, function: crate::PCSTR) -> Option<T> {
+pub(crate) unsafe fn delay_load<T>(library: crate::PCSTR, function: crate::PCSTR) -> Option<T> {
     let library = LoadLibraryExA(
         library.0,
         core::ptr::null_mut(),
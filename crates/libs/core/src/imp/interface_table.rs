@@ -0,0 +1,73 @@
+use crate::GUID;
+
+/// A table of interface IIDs sorted at compile time so that `QueryInterface` implementations
+/// generated by `#[implement]` can find the vtable for an IID with a binary search rather than
+/// comparing against each IID in turn.
+pub struct InterfaceTable<const N: usize>([(u128, usize); N]);
+
+impl<const N: usize> InterfaceTable<N> {
+    /// Creates a table from the IIDs of the implemented interfaces, in the order of their vtables.
+    pub const fn new(iids: [GUID; N]) -> Self {
+        let mut entries = [(0, 0); N];
+        let mut index = 0;
+
+        // An insertion sort is used as it is simple to express in a `const fn` and tables are small.
+        while index < N {
+            let entry = (iids[index].to_u128(), index);
+            let mut position = index;
+
+            while position > 0 && entries[position - 1].0 > entry.0 {
+                entries[position] = entries[position - 1];
+                position -= 1;
+            }
+
+            entries[position] = entry;
+            index += 1;
+        }
+
+        Self(entries)
+    }
+
+    /// Returns the index of the vtable for the given IID, if any.
+    pub fn find(&self, iid: &GUID) -> Option<usize> {
+        let iid = iid.to_u128();
+
+        self.0
+            .binary_search_by(|(entry, _)| entry.cmp(&iid))
+            .ok()
+            .map(|position| self.0[position].1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find() {
+        const TABLE: InterfaceTable<4> = InterfaceTable::new([
+            GUID::from_u128(0x30000000_0000_0000_0000_000000000000),
+            GUID::from_u128(0x10000000_0000_0000_0000_000000000000),
+            GUID::from_u128(0x40000000_0000_0000_0000_000000000000),
+            GUID::from_u128(0x20000000_0000_0000_0000_000000000000),
+        ]);
+
+        assert_eq!(
+            TABLE.find(&GUID::from_u128(0x10000000_0000_0000_0000_000000000000)),
+            Some(1)
+        );
+        assert_eq!(
+            TABLE.find(&GUID::from_u128(0x20000000_0000_0000_0000_000000000000)),
+            Some(3)
+        );
+        assert_eq!(
+            TABLE.find(&GUID::from_u128(0x30000000_0000_0000_0000_000000000000)),
+            Some(0)
+        );
+        assert_eq!(
+            TABLE.find(&GUID::from_u128(0x40000000_0000_0000_0000_000000000000)),
+            Some(2)
+        );
+        assert_eq!(TABLE.find(&GUID::zeroed()), None);
+    }
+}
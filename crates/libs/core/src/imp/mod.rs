@@ -3,16 +3,24 @@ include!("windows.rs");
 
 mod can_into;
 mod com_bindings;
+mod interface_table;
+mod proxy_vtable;
 mod ref_count;
 mod sha1;
+mod tear_off;
 mod weak_ref_count;
 
 pub use can_into::*;
 pub use com_bindings::*;
+pub use interface_table::*;
+pub use proxy_vtable::*;
 pub use ref_count::*;
 pub use sha1::*;
+pub use tear_off::*;
 pub use weak_ref_count::*;
 
+pub use crate::proxy::proxy_call;
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! interface_hierarchy {
@@ -0,0 +1,18 @@
+use crate::GUID;
+
+/// Implemented by the vtables of interfaces that can be wrapped by [`proxy`](crate::proxy).
+///
+/// The `Marker` parameter is never specified. It only makes the bounds in the implementations generated by the
+/// `interface` macro generic, so that an interface deriving from one without a proxy vtable still compiles.
+#[doc(hidden)]
+pub trait ProxyVtable<Marker = ()>: Sized + 'static {
+    /// The vtable whose methods forward to the proxy's inner object.
+    const PROXY: Self;
+
+    /// A static reference to `PROXY`, which is only promoted where the vtable's type is known.
+    const VTABLE: &'static Self;
+
+    /// Returns `true` if the proxy implements the interface, either directly or because the proxied interface
+    /// derives from it.
+    fn matches(iid: &GUID) -> bool;
+}
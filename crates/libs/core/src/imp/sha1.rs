@@ -9,6 +9,53 @@ pub const fn sha1(data: &ConstBuffer) -> Digest {
     digest(state, len, blocks)
 }
 
+/// Computes the SHA-1 digest of `prefix` followed by `data`. Unlike `sha1`, the input is not limited
+/// to the size of a `ConstBuffer`.
+pub const fn sha1_concat(prefix: &[u8], data: &[u8]) -> Digest {
+    let state: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+    let blocks = Blocks {
+        len: 0,
+        data: [0; 64],
+    };
+    let (blocks, len, state) = update_blocks(blocks, 0, state, prefix);
+    let (blocks, len, state) = update_blocks(blocks, len, state, data);
+    digest(state, len, blocks)
+}
+
+const fn update_blocks(
+    mut blocks: Blocks,
+    mut len: u64,
+    mut state: [u32; 5],
+    data: &[u8],
+) -> (Blocks, u64, [u32; 5]) {
+    let mut i = 0;
+    while i < data.len() {
+        blocks.data[blocks.len as usize] = data[i];
+        blocks.len += 1;
+
+        if blocks.len == 64 {
+            let mut block = [0u32; 16];
+            let mut j = 0;
+            while j != 16 {
+                block[j] = u32::from_be_bytes([
+                    blocks.data[j * 4],
+                    blocks.data[j * 4 + 1],
+                    blocks.data[j * 4 + 2],
+                    blocks.data[j * 4 + 3],
+                ]);
+                j += 1;
+            }
+
+            state = process_state(state, block);
+            len += 64;
+            blocks.len = 0;
+        }
+
+        i += 1;
+    }
+    (blocks, len, state)
+}
+
 const BUFFER_SIZE: usize = 1024;
 
 pub struct ConstBuffer {
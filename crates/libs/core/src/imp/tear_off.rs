@@ -0,0 +1,93 @@
+use crate::allocator::{allocate_value, deallocate_value};
+use crate::{IUnknownImpl, GUID};
+use core::ffi::c_void;
+use core::marker::PhantomData;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// Links types declared with `#[implement(..., Owner = ...)]` with the objects generated for them.
+///
+/// Such a type is a tear-off: its interfaces are exposed by an owner object declared with
+/// `#[implement(..., TearOff = ...)]`, which creates the tear-off the first time one of its
+/// interfaces is queried and keeps it until the owner is destroyed. The tear-off shares the
+/// identity and reference count of its owner.
+pub trait TearOffInner: Sized {
+    /// The generated `<foo>_Impl` type.
+    type Outer: IUnknownImpl<Impl = Self>;
+
+    /// The generated `<owner>_Impl` type of the object that exposes the tear-off.
+    type Owner: IUnknownImpl;
+
+    /// Returns `true` if the tear-off implements the interface, without creating it.
+    fn matches(iid: &GUID) -> bool;
+
+    /// Wraps this value in a tear-off that delegates its identity and reference count to `owner`.
+    ///
+    /// # Safety
+    ///
+    /// The tear-off must not outlive `owner`.
+    unsafe fn into_tear_off(self, owner: &Self::Owner) -> Self::Outer;
+
+    /// Returns a pointer to the tear-off's vtable for the interface, without adding a reference.
+    fn find_interface(outer: &Self::Outer, iid: &GUID) -> *mut c_void;
+}
+
+/// Holds the tear-off of type `T` once it has been created for the owner object that contains the cache.
+pub struct TearOffCache<T: TearOffInner>(AtomicPtr<T::Outer>, PhantomData<T>);
+
+impl<T: TearOffInner> TearOffCache<T> {
+    pub const fn new() -> Self {
+        Self(AtomicPtr::new(null_mut()), PhantomData)
+    }
+
+    /// Returns a pointer to the tear-off's vtable for the interface, without adding a reference.
+    /// The tear-off is created with `create` if this is the first query for one of its interfaces.
+    ///
+    /// # Safety
+    ///
+    /// The cache must be contained within `owner`.
+    pub unsafe fn query(
+        &self,
+        iid: &GUID,
+        owner: &T::Owner,
+        create: impl FnOnce() -> T,
+    ) -> *mut c_void {
+        let mut tear_off = self.0.load(Ordering::Acquire);
+
+        if tear_off.is_null() {
+            let new = allocate_value(create().into_tear_off(owner));
+
+            // Another thread may have created the tear-off in the meantime, in which case that one
+            // is used so that the tear-off keeps a stable identity.
+            tear_off =
+                match self
+                    .0
+                    .compare_exchange(null_mut(), new, Ordering::AcqRel, Ordering::Acquire)
+                {
+                    Ok(_) => new,
+                    Err(existing) => {
+                        deallocate_value(new);
+                        existing
+                    }
+                };
+        }
+
+        T::find_interface(&*tear_off, iid)
+    }
+}
+
+impl<T: TearOffInner> Default for TearOffCache<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: TearOffInner> Drop for TearOffCache<T> {
+    fn drop(&mut self) {
+        let tear_off = *self.0.get_mut();
+
+        if !tear_off.is_null() {
+            unsafe { deallocate_value(tear_off) };
+        }
+    }
+}
@@ -18,6 +18,33 @@ impl Waiter {
     }
 }
 
+impl Waiter {
+    /// Waits for the `Waiter` to be signaled while dispatching COM calls and window messages, so that
+    /// waiting on a single-threaded apartment (STA) thread does not deadlock code that needs to call
+    /// back into the apartment in order to signal the `Waiter`.
+    pub fn wait_pumping(&self) -> crate::Result<()> {
+        let mut index = 0;
+
+        let result = crate::HRESULT(unsafe {
+            CoWaitForMultipleHandles(
+                (COWAIT_DISPATCH_CALLS | COWAIT_DISPATCH_WINDOW_MESSAGES) as u32,
+                INFINITE,
+                1,
+                &self.0,
+                &mut index,
+            )
+        });
+
+        // A thread that has not initialized COM has no apartment to pump, in which case dropping
+        // the `Waiter` performs the wait instead.
+        if result == CO_E_NOTINITIALIZED {
+            Ok(())
+        } else {
+            result.ok()
+        }
+    }
+}
+
 impl WaiterSignaler {
     /// # Safety
     /// Signals the `Waiter`. This is unsafe because the lifetime of `WaiterSignaler` is not tied
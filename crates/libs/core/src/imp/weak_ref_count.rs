@@ -107,7 +107,7 @@ struct TearOff {
 impl TearOff {
     #[allow(clippy::new_ret_no_self)]
     unsafe fn new(object: *mut c_void, strong_count: u32) -> IWeakReferenceSource {
-        transmute(Box::new(TearOff {
+        transmute(crate::allocator::allocate_value(TearOff {
             strong_vtable: &Self::STRONG_VTABLE,
             weak_vtable: &Self::WEAK_VTABLE,
             object,
@@ -253,7 +253,7 @@ impl TearOff {
         // If there are no remaining references, it means that the object has already been
         // destroyed. Go ahead and destroy the tear-off.
         if remaining == 0 {
-            let _ = Box::from_raw(this);
+            crate::allocator::deallocate_value(this as *mut Self);
         }
 
         remaining
@@ -1,3 +1,6 @@
+mod dyn_link;
+pub use dyn_link::*;
+
 mod factory_cache;
 pub use factory_cache::*;
 
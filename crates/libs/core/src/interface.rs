@@ -82,32 +82,51 @@ pub unsafe trait Interface: Sized + Clone {
         }
     }
 
+    /// Creates an `Interface` from a `raw` COM interface pointer that remains owned by the caller, adding a
+    /// reference so that the result may outlive the caller's reference. Returns `None` if `raw` is null.
+    ///
+    /// This is useful when another library, such as a graphics library, lends out an interface pointer that it
+    /// continues to own and release.
+    ///
+    /// # Safety
+    ///
+    /// The `raw` pointer must be null or a valid COM interface pointer. In other words, it must point to a vtable
+    /// beginning with the `IUnknown` function pointers and match the vtable of `Interface`.
+    unsafe fn from_raw_cloned(raw: *mut c_void) -> Option<Self> {
+        Self::from_raw_borrowed(&raw).cloned()
+    }
+
+    /// Creates an `Interface` by using `QueryInterface` on a `raw` COM interface pointer of any interface type,
+    /// adding a reference to the result. The `raw` pointer remains owned by the caller.
+    ///
+    /// Unlike [`Interface::from_raw`], this does not trust that `raw` points to this particular interface, which
+    /// makes it suitable for pointers that come from other libraries, or other versions of this library, whose
+    /// interface types may not line up.
+    ///
+    /// # Safety
+    ///
+    /// The `raw` pointer must be null or a valid COM interface pointer. In other words, it must point to a vtable
+    /// beginning with the `IUnknown` function pointers.
+    unsafe fn cast_from_raw(raw: *mut c_void) -> Result<Self> {
+        IUnknown::from_raw_borrowed(&raw)
+            .ok_or_else(|| Error::from(imp::E_POINTER))?
+            .cast()
+    }
+
     /// Attempts to cast the current interface to another interface using `QueryInterface`.
     ///
     /// The name `cast` is preferred to `query` because there is a WinRT method named query but not one
     /// named cast.
     #[inline(always)]
     fn cast<T: Interface>(&self) -> Result<T> {
-        // SAFETY: `result` is valid for writing an interface pointer and it is safe
-        // to cast the `result` pointer as `T` on success because we are using the `IID` tied
-        // to `T` which the implementor of `Interface` has guaranteed is correct
+        // SAFETY: it is safe to take ownership of the resulting pointer as `T` because we are using
+        // the `IID` tied to `T` which the implementor of `Interface` has guaranteed is correct
         unsafe {
-            // If query() returns a failure code then we propagate that failure code to the caller.
-            // In that case, we ignore the contents of 'result' (which will _not_ be dropped,
-            // because MaybeUninit intentionally does not drop its contents).
-            //
-            // This guards against implementations of COM interfaces which may store non-null values
-            // in 'result' but still return E_NOINTERFACE.
-            let mut result = MaybeUninit::<Option<T>>::zeroed();
-            self.query(&T::IID, result.as_mut_ptr() as _).ok()?;
-
-            // If we get here, then query() has succeeded, but we still need to double-check
-            // that the output pointer is non-null.
-            if let Some(obj) = result.assume_init() {
-                Ok(obj)
-            } else {
-                Err(imp::E_POINTER.into())
+            if !Self::UNKNOWN {
+                panic!("Non-COM interfaces cannot be queried.")
             }
+
+            query_raw(self.as_raw(), &T::IID).map(|raw| T::from_raw(raw.as_ptr()))
         }
     }
 
@@ -151,13 +170,11 @@ pub unsafe trait Interface: Sized + Clone {
         T::Outer: Any + 'static + IUnknownImpl<Impl = T>,
     {
         unsafe {
-            let mut any_ref_arg: MaybeUninit<&dyn Any> = MaybeUninit::zeroed();
-            self.query(
-                &DYNAMIC_CAST_IID,
-                any_ref_arg.as_mut_ptr() as *mut *mut c_void,
-            )
-            .ok()?;
-            Ok(any_ref_arg.assume_init())
+            if !Self::UNKNOWN {
+                panic!("Non-COM interfaces cannot be queried.")
+            }
+
+            query_any(self.as_raw())
         }
     }
 
@@ -259,6 +276,47 @@ pub unsafe trait Interface: Sized + Clone {
     }
 }
 
+// The following helpers hold the bulk of the `cast` family of methods. They deal only in raw pointers and
+// `GUID`s so that they are compiled once rather than for every pair of interfaces that are cast between.
+
+/// Calls `QueryInterface` on `this`, returning the resulting interface pointer on success.
+///
+/// # Safety
+///
+/// `this` must be a valid COM interface pointer.
+pub(crate) unsafe fn query_raw(this: *mut c_void, iid: &GUID) -> Result<NonNull<c_void>> {
+    // If `QueryInterface` returns a failure code then we propagate that failure code to the caller.
+    // In that case, we ignore the contents of `result`. This guards against implementations of COM
+    // interfaces which may store non-null values in `result` but still return E_NOINTERFACE.
+    let mut result = core::ptr::null_mut();
+    IUnknown::from_raw_borrowed(&this)
+        .unwrap_unchecked()
+        .query(iid, &mut result)
+        .ok()?;
+
+    // If we get here, then `QueryInterface` has succeeded, but we still need to double-check
+    // that the output pointer is non-null.
+    NonNull::new(result).ok_or_else(|| imp::E_POINTER.into())
+}
+
+/// Queries `this` for the `&dyn Any` of its outer implementation object using the
+/// `DYNAMIC_CAST_IID` protocol described by [`Interface::cast_to_any`].
+///
+/// # Safety
+///
+/// `this` must be a valid COM interface pointer that outlives `'a`.
+unsafe fn query_any<'a>(this: *mut c_void) -> Result<&'a dyn Any> {
+    let mut any_ref_arg: MaybeUninit<&dyn Any> = MaybeUninit::zeroed();
+    IUnknown::from_raw_borrowed(&this)
+        .unwrap_unchecked()
+        .query(
+            &DYNAMIC_CAST_IID,
+            any_ref_arg.as_mut_ptr() as *mut *mut c_void,
+        )
+        .ok()?;
+    Ok(any_ref_arg.assume_init())
+}
+
 /// # Safety
 #[doc(hidden)]
 pub unsafe fn from_raw_borrowed<T: Interface>(raw: &*mut c_void) -> Option<&T> {
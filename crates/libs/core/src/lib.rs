@@ -22,6 +22,7 @@ use alloc::boxed::Box;
 #[doc(hidden)]
 pub mod imp;
 
+mod allocator;
 mod as_impl;
 mod com_object;
 mod guid;
@@ -31,6 +32,7 @@ mod out_param;
 mod out_ref;
 mod param;
 mod param_value;
+mod proxy;
 mod r#ref;
 mod runtime_name;
 mod runtime_type;
@@ -39,6 +41,7 @@ mod r#type;
 mod unknown;
 mod weak;
 
+pub use allocator::*;
 pub use as_impl::*;
 pub use com_object::*;
 pub use guid::*;
@@ -48,6 +51,7 @@ pub use out_param::*;
 pub use out_ref::*;
 pub use param::*;
 pub use param_value::*;
+pub use proxy::{proxy, ProxyCall};
 pub use r#ref::*;
 pub use r#type::*;
 pub use runtime_name::*;
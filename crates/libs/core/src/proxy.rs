@@ -0,0 +1,212 @@
+use super::*;
+use core::ffi::c_void;
+use core::time::Duration;
+
+/// A method call made through an interface returned by [`proxy`].
+#[derive(Clone, Debug)]
+pub struct ProxyCall {
+    /// The name of the interface that declares the method.
+    pub interface: &'static str,
+    /// The name of the method.
+    pub method: &'static str,
+    /// The index of the method in the interface's vtable, counting the `IUnknown` methods.
+    pub slot: usize,
+    /// The result of the call, if the method returns an `HRESULT`.
+    pub result: Option<HRESULT>,
+    /// How long the call took, which is always zero without the `std` feature.
+    pub duration: Duration,
+}
+
+/// Wraps an interface pointer in a proxy that reports each method call to the callback before returning the
+/// result to the caller.
+///
+/// This supports interfaces defined with the [`interface`] macro that derive from `IUnknown` or `IInspectable`,
+/// directly or through other such interfaces, as well as the non-generic interfaces and classes of the `windows`
+/// crate with its `proxy` feature, and of bindings generated by `windows-bindgen` with the `proxy` option.
+///
+/// The proxy handles `IUnknown` calls itself, without reporting them, and returns itself when queried for the proxied
+/// interface or any interface it derives from, including `IUnknown`. Querying for any other interface fails with
+/// `E_NOINTERFACE`, so that every interface obtained from the proxy has the proxy's identity. Other interfaces may be
+/// queried from the inner object and proxied separately.
+///
+/// ```rust,ignore
+/// let value = proxy(&value, |call| println!("{}::{} {:?} in {:?}", call.interface, call.method, call.result, call.duration));
+/// ```
+pub fn proxy<T, F>(inner: &T, callback: F) -> T
+where
+    T: Interface,
+    T::Vtable: imp::ProxyVtable,
+    F: Fn(&ProxyCall) + Send + Sync + 'static,
+{
+    let object = Box::new(ProxyObject {
+        vtable: <T::Vtable as imp::ProxyVtable>::VTABLE as *const T::Vtable as *const c_void,
+        inner: inner.clone().into_raw(),
+        matches: <T::Vtable as imp::ProxyVtable>::matches,
+        count: imp::RefCount::new(1),
+        callback: Box::new(callback),
+    });
+
+    unsafe { T::from_raw(Box::into_raw(object) as *mut c_void) }
+}
+
+#[repr(C)]
+struct ProxyObject {
+    vtable: *const c_void,
+    inner: *mut c_void,
+    matches: fn(&GUID) -> bool,
+    count: imp::RefCount,
+    callback: Box<dyn Fn(&ProxyCall) + Send + Sync>,
+}
+
+impl Drop for ProxyObject {
+    fn drop(&mut self) {
+        unsafe { _ = IUnknown::from_raw(self.inner) };
+    }
+}
+
+/// Calls a method on the inner object of a proxy created by [`proxy`] and reports the call to its callback.
+///
+/// # Safety
+///
+/// `this` must be a proxy created by [`proxy`].
+#[doc(hidden)]
+pub unsafe fn proxy_call<R>(
+    this: *mut c_void,
+    interface: &'static str,
+    method: &'static str,
+    slot: usize,
+    call: impl FnOnce(*mut c_void) -> R,
+    result: fn(&R) -> Option<HRESULT>,
+) -> R {
+    let this = &*(this as *const ProxyObject);
+
+    #[cfg(feature = "std")]
+    let start = std::time::Instant::now();
+
+    let value = call(this.inner);
+
+    #[cfg(feature = "std")]
+    let duration = start.elapsed();
+    #[cfg(not(feature = "std"))]
+    let duration = Duration::ZERO;
+
+    (this.callback)(&ProxyCall {
+        interface,
+        method,
+        slot,
+        result: result(&value),
+        duration,
+    });
+
+    value
+}
+
+impl imp::ProxyVtable for IUnknown_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryInterface(
+            this: *mut c_void,
+            iid: *const GUID,
+            interface: *mut *mut c_void,
+        ) -> HRESULT {
+            let object = &*(this as *const ProxyObject);
+
+            if iid.is_null() || interface.is_null() {
+                return imp::E_POINTER;
+            }
+
+            if (object.matches)(&*iid) {
+                object.count.add_ref();
+                *interface = this;
+                HRESULT(0)
+            } else {
+                *interface = core::ptr::null_mut();
+                imp::E_NOINTERFACE
+            }
+        }
+        unsafe extern "system" fn AddRef(this: *mut c_void) -> u32 {
+            (*(this as *const ProxyObject)).count.add_ref()
+        }
+        unsafe extern "system" fn Release(this: *mut c_void) -> u32 {
+            let remaining = (*(this as *const ProxyObject)).count.release();
+
+            if remaining == 0 {
+                _ = Box::from_raw(this as *mut ProxyObject);
+            }
+
+            remaining
+        }
+        Self {
+            QueryInterface,
+            AddRef,
+            Release,
+        }
+    };
+
+    const VTABLE: &'static Self = &Self::PROXY;
+
+    fn matches(iid: &GUID) -> bool {
+        *iid == IUnknown::IID
+    }
+}
+
+impl imp::ProxyVtable for IInspectable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIids(
+            this: *mut c_void,
+            count: *mut u32,
+            values: *mut *mut GUID,
+        ) -> HRESULT {
+            proxy_call(
+                this,
+                "IInspectable",
+                "GetIids",
+                3,
+                |inner| {
+                    ((**(inner as *const *const IInspectable_Vtbl)).GetIids)(inner, count, values)
+                },
+                |result| Some(*result),
+            )
+        }
+        unsafe extern "system" fn GetRuntimeClassName(
+            this: *mut c_void,
+            value: *mut *mut c_void,
+        ) -> HRESULT {
+            proxy_call(
+                this,
+                "IInspectable",
+                "GetRuntimeClassName",
+                4,
+                |inner| {
+                    ((**(inner as *const *const IInspectable_Vtbl)).GetRuntimeClassName)(
+                        inner, value,
+                    )
+                },
+                |result| Some(*result),
+            )
+        }
+        unsafe extern "system" fn GetTrustLevel(this: *mut c_void, value: *mut i32) -> HRESULT {
+            proxy_call(
+                this,
+                "IInspectable",
+                "GetTrustLevel",
+                5,
+                |inner| {
+                    ((**(inner as *const *const IInspectable_Vtbl)).GetTrustLevel)(inner, value)
+                },
+                |result| Some(*result),
+            )
+        }
+        Self {
+            base: <IUnknown_Vtbl as imp::ProxyVtable>::PROXY,
+            GetIids,
+            GetRuntimeClassName,
+            GetTrustLevel,
+        }
+    };
+
+    const VTABLE: &'static Self = &Self::PROXY;
+
+    fn matches(iid: &GUID) -> bool {
+        *iid == IInspectable::IID || <IUnknown_Vtbl as imp::ProxyVtable>::matches(iid)
+    }
+}
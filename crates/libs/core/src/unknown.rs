@@ -155,7 +155,11 @@ pub trait IUnknownImpl {
     /// or a `&<Foo>_Impl` that points within a `ComObject`, this is safe.
     fn to_object(&self) -> ComObject<Self::Impl>
     where
-        Self::Impl: ComObjectInner<Outer = Self>;
+        Self::Impl: ComObjectInner<Outer = Self>,
+    {
+        self.AddRef();
+        unsafe { ComObject::from_raw(NonNull::from(self)) }
+    }
 
     /// The distance from the start of `<Foo>_Impl` to the `this` field within it, measured in
     /// pointer-sized elements. The `this` field contains the `MyApp` instance.
@@ -1,4 +1,6 @@
 use super::*;
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::mem::transmute;
 
 macro_rules! variant_from_value {
@@ -40,6 +42,61 @@ macro_rules! variant_from_value {
     }
 }
 
+macro_rules! variant_try_from_change_type {
+    ($to:ident, $vt:ident, $field:ident) => {
+        impl TryFrom<&VARIANT> for $to {
+            type Error = Error;
+            fn try_from(from: &VARIANT) -> Result<Self> {
+                let value = from.change_type(imp::$vt)?;
+                Ok(unsafe { value.0.Anonymous.Anonymous.Anonymous.$field })
+            }
+        }
+        impl TryFrom<&PROPVARIANT> for $to {
+            type Error = Error;
+            fn try_from(from: &PROPVARIANT) -> Result<Self> {
+                let value = from.change_type(imp::$vt)?;
+                Ok(unsafe { value.0.Anonymous.Anonymous.Anonymous.$field })
+            }
+        }
+    };
+}
+
+macro_rules! propvariant_vector {
+    ($ty:ident, $init:ident, $to:ident) => {
+        impl TryFrom<&[$ty]> for PROPVARIANT {
+            type Error = Error;
+            fn try_from(from: &[$ty]) -> Result<Self> {
+                unsafe {
+                    let mut value = Self::new();
+                    HRESULT(imp::$init(from.as_ptr(), from.len() as u32, &mut value.0)).map(|| value)
+                }
+            }
+        }
+        impl TryFrom<&PROPVARIANT> for Vec<$ty> {
+            type Error = Error;
+            fn try_from(from: &PROPVARIANT) -> Result<Self> {
+                unsafe {
+                    let mut data = core::ptr::null_mut();
+                    let mut len = 0;
+                    HRESULT(imp::$to(&from.0, &mut data, &mut len)).ok()?;
+                    let value = vec_from_raw(data, len);
+                    imp::CoTaskMemFree(data as _);
+                    Ok(value)
+                }
+            }
+        }
+    };
+}
+
+// Copies an array allocated by one of the `PropVariantTo*VectorAlloc` functions, which may be null if empty.
+unsafe fn vec_from_raw<T: Clone>(data: *const T, len: u32) -> Vec<T> {
+    if data.is_null() {
+        Vec::new()
+    } else {
+        core::slice::from_raw_parts(data, len as usize).to_vec()
+    }
+}
+
 /// A VARIANT ([VARIANT](https://learn.microsoft.com/en-us/windows/win32/api/oaidl/ns-oaidl-variant)) is a container that can store different types of values.
 #[repr(transparent)]
 pub struct VARIANT(imp::VARIANT);
@@ -186,6 +243,31 @@ impl VARIANT {
         unsafe { self.0.Anonymous.Anonymous.vt == imp::VT_EMPTY }
     }
 
+    /// Create a `VARIANT` that holds `VT_NULL`, which represents a missing value.
+    pub fn null() -> Self {
+        let mut value = Self::new();
+        value.0.Anonymous.Anonymous.vt = imp::VT_NULL;
+        value
+    }
+
+    /// Returns true if the `VARIANT` holds `VT_NULL`.
+    pub const fn is_null(&self) -> bool {
+        unsafe { self.0.Anonymous.Anonymous.vt == imp::VT_NULL }
+    }
+
+    /// Returns the type of the value held by the `VARIANT`, such as `VT_I4`.
+    pub const fn vt(&self) -> u16 {
+        unsafe { self.0.Anonymous.Anonymous.vt }
+    }
+
+    /// Converts the value to a `VARIANT` of the given type, using `VariantChangeType` to coerce it.
+    pub fn change_type(&self, vt: u16) -> Result<Self> {
+        unsafe {
+            let mut value = Self::new();
+            HRESULT(imp::VariantChangeType(&mut value.0, &self.0, 0, vt)).map(|| value)
+        }
+    }
+
     /// Creates a `VARIANT` by taking ownership of the raw data.
     ///
     /// # Safety
@@ -214,6 +296,31 @@ impl PROPVARIANT {
         unsafe { self.0.Anonymous.Anonymous.vt == imp::VT_EMPTY }
     }
 
+    /// Create a `PROPVARIANT` that holds `VT_NULL`, which represents a missing value.
+    pub fn null() -> Self {
+        let mut value = Self::new();
+        value.0.Anonymous.Anonymous.vt = imp::VT_NULL;
+        value
+    }
+
+    /// Returns true if the `PROPVARIANT` holds `VT_NULL`.
+    pub const fn is_null(&self) -> bool {
+        unsafe { self.0.Anonymous.Anonymous.vt == imp::VT_NULL }
+    }
+
+    /// Returns the type of the value held by the `PROPVARIANT`, such as `VT_I4`.
+    pub const fn vt(&self) -> u16 {
+        unsafe { self.0.Anonymous.Anonymous.vt }
+    }
+
+    /// Converts the value to a `PROPVARIANT` of the given type, using `PropVariantChangeType` to coerce it.
+    pub fn change_type(&self, vt: u16) -> Result<Self> {
+        unsafe {
+            let mut value = Self::new();
+            HRESULT(imp::PropVariantChangeType(&mut value.0, &self.0, 0, vt)).map(|| value)
+        }
+    }
+
     /// Creates a `PROPVARIANT` by taking ownership of the raw data.
     ///
     /// # Safety
@@ -318,6 +425,22 @@ impl TryFrom<&PROPVARIANT> for BSTR {
     }
 }
 
+impl TryFrom<&VARIANT> for String {
+    type Error = Error;
+    fn try_from(from: &VARIANT) -> Result<Self> {
+        let value = BSTR::try_from(from)?;
+        String::try_from(&value).map_err(|_| Error::from_hresult(imp::E_INVALIDARG))
+    }
+}
+
+impl TryFrom<&PROPVARIANT> for String {
+    type Error = Error;
+    fn try_from(from: &PROPVARIANT) -> Result<Self> {
+        let value = BSTR::try_from(from)?;
+        String::try_from(&value).map_err(|_| Error::from_hresult(imp::E_INVALIDARG))
+    }
+}
+
 // VT_BOOL
 
 variant_from_value!(bool, VT_BOOL, boolVal, |v: bool| if v { -1 } else { 0 } );
@@ -341,10 +464,12 @@ impl TryFrom<&PROPVARIANT> for bool {
 // VT_UI1
 
 variant_from_value!(u8, VT_UI1, bVal, |v: u8| v);
+variant_try_from_change_type!(u8, VT_UI1, bVal);
 
 // VT_I1
 
 variant_from_value!(i8, VT_I1, cVal, |v: i8| v);
+variant_try_from_change_type!(i8, VT_I1, cVal);
 
 // VT_UI2
 
@@ -529,6 +654,7 @@ impl TryFrom<&PROPVARIANT> for i64 {
 // VT_R4
 
 variant_from_value!(f32, VT_R4, fltVal, |v: f32| v);
+variant_try_from_change_type!(f32, VT_R4, fltVal);
 
 // VT_R8
 
@@ -549,3 +675,69 @@ impl TryFrom<&PROPVARIANT> for f64 {
         HRESULT(unsafe { imp::PropVariantToDouble(&from.0, &mut value) }).map(|| value)
     }
 }
+
+// VT_CLSID
+
+impl From<GUID> for PROPVARIANT {
+    fn from(value: GUID) -> Self {
+        let mut result = Self::new();
+        // This only fails if memory can't be allocated for the `GUID`.
+        unsafe { HRESULT(imp::InitPropVariantFromCLSID(&value as *const _ as *const _, &mut result.0)).unwrap() };
+        result
+    }
+}
+
+impl TryFrom<&PROPVARIANT> for GUID {
+    type Error = Error;
+    fn try_from(from: &PROPVARIANT) -> Result<Self> {
+        let mut value = GUID::zeroed();
+        HRESULT(unsafe { imp::PropVariantToGUID(&from.0, &mut value as *mut _ as *mut _) }).map(|| value)
+    }
+}
+
+// VT_VECTOR
+
+propvariant_vector!(i16, InitPropVariantFromInt16Vector, PropVariantToInt16VectorAlloc);
+propvariant_vector!(u16, InitPropVariantFromUInt16Vector, PropVariantToUInt16VectorAlloc);
+propvariant_vector!(i32, InitPropVariantFromInt32Vector, PropVariantToInt32VectorAlloc);
+propvariant_vector!(u32, InitPropVariantFromUInt32Vector, PropVariantToUInt32VectorAlloc);
+propvariant_vector!(i64, InitPropVariantFromInt64Vector, PropVariantToInt64VectorAlloc);
+propvariant_vector!(u64, InitPropVariantFromUInt64Vector, PropVariantToUInt64VectorAlloc);
+propvariant_vector!(f64, InitPropVariantFromDoubleVector, PropVariantToDoubleVectorAlloc);
+
+impl TryFrom<&[&str]> for PROPVARIANT {
+    type Error = Error;
+    fn try_from(from: &[&str]) -> Result<Self> {
+        let strings: Vec<HSTRING> = from.iter().map(|value| HSTRING::from(*value)).collect();
+        let pointers: Vec<imp::PCWSTR> = strings.iter().map(|value| value.as_ptr()).collect();
+
+        unsafe {
+            let mut value = Self::new();
+            HRESULT(imp::InitPropVariantFromStringVector(pointers.as_ptr(), pointers.len() as u32, &mut value.0)).map(|| value)
+        }
+    }
+}
+
+impl TryFrom<&PROPVARIANT> for Vec<String> {
+    type Error = Error;
+    fn try_from(from: &PROPVARIANT) -> Result<Self> {
+        unsafe {
+            let mut data = core::ptr::null_mut();
+            let mut len = 0;
+            HRESULT(imp::PropVariantToStringVectorAlloc(&from.0, &mut data, &mut len)).ok()?;
+            let strings = vec_from_raw(data, len);
+
+            let value = strings
+                .iter()
+                .map(|string| {
+                    let value = PCWSTR(*string).to_string().unwrap_or_default();
+                    imp::CoTaskMemFree(*string as _);
+                    value
+                })
+                .collect();
+
+            imp::CoTaskMemFree(data as _);
+            Ok(value)
+        }
+    }
+}
@@ -1,9 +1,14 @@
+mod agile_cell;
+pub use agile_cell::*;
+
 mod agile_reference;
 pub use agile_reference::*;
 
 mod array;
 pub use array::*;
 
+mod dyn_link;
+
 #[cfg(feature = "std")]
 mod event;
 #[cfg(feature = "std")]
@@ -4,6 +4,9 @@ Learn more about Rust for Windows here: <https://github.com/microsoft/windows-rs
 
 use quote::{quote, ToTokens};
 
+/// The number of interfaces from which `QueryInterface` uses a sorted table of IIDs.
+const INTERFACE_TABLE_THRESHOLD: usize = 4;
+
 /// Implements one or more COM interfaces.
 ///
 /// # Example
@@ -32,12 +35,54 @@ use quote::{quote, ToTokens};
 ///     // You can now call interface methods on com_object.
 /// }
 /// ```
+///
+/// # Tear-offs
+///
+/// Interfaces that are rarely queried can be implemented by a separate tear-off type, so that their
+/// vtables and state aren't carried by every object. The tear-off is created with `From<&Owner>` the
+/// first time one of its interfaces is queried, is cached until the owner is destroyed, and shares
+/// the identity and reference count of the owner.
+///
+/// ```rust,ignore
+/// #[implement(IShellExtInit, TearOff = ContextMenu)]
+/// struct Extension { /* ... */ }
+///
+/// #[implement(IContextMenu, Owner = Extension)]
+/// struct ContextMenu { /* ... */ }
+///
+/// impl From<&Extension> for ContextMenu {
+///     fn from(extension: &Extension) -> Self {
+///         ContextMenu { /* ... */ }
+///     }
+/// }
+///
+/// impl IContextMenu_Impl for ContextMenu_Impl {
+///     // `self.owner()` returns the `Extension_Impl` that the tear-off belongs to.
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn implement(
     attributes: proc_macro::TokenStream,
     original_type: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
     let attributes = syn::parse_macro_input!(attributes as ImplementAttributes);
+
+    if attributes.owner.is_some() {
+        let message = if attributes.implement.is_empty() {
+            Some("A tear-off must implement at least one interface")
+        } else if !attributes.tear_offs.is_empty() {
+            Some("A tear-off cannot have tear-offs of its own")
+        } else {
+            None
+        };
+
+        if let Some(message) = message {
+            return syn::Error::new(proc_macro2::Span::call_site(), message)
+                .to_compile_error()
+                .into();
+        }
+    }
+
     let interfaces_len = proc_macro2::Literal::usize_unsuffixed(attributes.implement.len());
 
     let identity_type = if let Some(first) = attributes.implement.first() {
@@ -70,6 +115,7 @@ pub fn implement(
         .iter()
         .map(|implement| implement.to_vtbl_ident());
     let vtbl_idents2 = vtbl_idents.clone();
+    let vtbl_idents3 = vtbl_idents.clone();
 
     let vtable_news = attributes
         .implement
@@ -101,6 +147,39 @@ pub fn implement(
             }
         });
 
+    // Types implementing many interfaces look up the vtable for an interface's own IID with a binary
+    // search, only falling back to comparing against each interface hierarchy in turn for base interfaces.
+    let (interface_table, table_query) = if attributes.implement.len() >= INTERFACE_TABLE_THRESHOLD
+    {
+        let interface_idents = attributes
+            .implement
+            .iter()
+            .map(|implement| implement.to_ident());
+
+        let arms = (0..attributes.implement.len()).map(|index| {
+            let offset = proc_macro2::Literal::usize_unsuffixed(index);
+            quote! {
+                #offset => &self.vtables.#offset as *const _ as *mut _,
+            }
+        });
+
+        (
+            quote! {
+                const INTERFACE_TABLE: ::windows_core::imp::InterfaceTable<#interfaces_len> = ::windows_core::imp::InterfaceTable::new([#(<#interface_idents as ::windows_core::Interface>::IID,)*]);
+            },
+            quote! {
+                else if let Some(index) = Self::INTERFACE_TABLE.find(iid) {
+                    match index {
+                        #(#arms)*
+                        _ => ::core::ptr::null_mut(),
+                    }
+                }
+            },
+        )
+    } else {
+        (quote!(), quote!())
+    };
+
     // Dynamic casting requires that the object not contain non-static lifetimes.
     let enable_dyn_casting = original_type2.generics.lifetimes().count() == 0;
     let dynamic_cast_query = if enable_dyn_casting {
@@ -120,6 +199,36 @@ pub fn implement(
         quote!()
     };
 
+    let tear_off_idents: Vec<_> = attributes
+        .tear_offs
+        .iter()
+        .map(|tear_off| tear_off.to_ident())
+        .collect();
+
+    // Tear-offs are created on the first query for one of their interfaces and then cached for the
+    // lifetime of the object.
+    let tear_off_queries = tear_off_idents.iter().enumerate().map(|(index, tear_off)| {
+        let offset = proc_macro2::Literal::usize_unsuffixed(index);
+        quote! {
+            else if <#tear_off as ::windows_core::imp::TearOffInner>::matches(iid) {
+                self.tear_offs.#offset.query(iid, self, || <#tear_off as ::core::convert::From<&#original_ident::#generics>>::from(&self.this))
+            }
+        }
+    });
+
+    let (tear_offs_field, tear_offs_new) = if tear_off_idents.is_empty() {
+        (quote!(), quote!())
+    } else {
+        (
+            quote! {
+                tear_offs: (#(::windows_core::imp::TearOffCache<#tear_off_idents>,)*),
+            },
+            quote! {
+                tear_offs: (#(::windows_core::imp::TearOffCache::<#tear_off_idents>::new(),)*),
+            },
+        )
+    };
+
     // The distance from the beginning of the generated type to the 'this' field, in units of pointers (not bytes).
     let offset_of_this_in_pointers = 1 + attributes.implement.len();
     let offset_of_this_in_pointers_token =
@@ -130,14 +239,24 @@ pub fn implement(
     let conversions = attributes.implement.iter().enumerate().map(|(enumerate, implement)| {
         let interface_ident = implement.to_ident();
         let offset = proc_macro2::Literal::usize_unsuffixed(enumerate);
-        quote! {
-            impl #generics ::core::convert::From<#original_ident::#generics> for #interface_ident where #constraints {
-                #[inline(always)]
-                fn from(this: #original_ident::#generics) -> Self {
-                    let com_object = ::windows_core::ComObject::new(this);
-                    com_object.into_interface()
+
+        // Tear-offs can only be reached by querying their owner.
+        let from = if attributes.owner.is_none() {
+            quote! {
+                impl #generics ::core::convert::From<#original_ident::#generics> for #interface_ident where #constraints {
+                    #[inline(always)]
+                    fn from(this: #original_ident::#generics) -> Self {
+                        let com_object = ::windows_core::ComObject::new(this);
+                        com_object.into_interface()
+                    }
                 }
             }
+        } else {
+            quote!()
+        };
+
+        quote! {
+            #from
 
             impl #generics ::windows_core::ComObjectInterface<#interface_ident> for #impl_ident::#generics where #constraints {
                 #[inline(always)]
@@ -164,248 +283,355 @@ pub fn implement(
         }
     });
 
-    let tokens = quote! {
-        #[repr(C)]
-        #[allow(non_camel_case_types)]
-        #vis struct #impl_ident #generics where #constraints {
-            identity: &'static ::windows_core::IInspectable_Vtbl,
-            vtables: (#(&'static #vtbl_idents,)*),
-            this: #original_ident::#generics,
-            count: ::windows_core::imp::WeakRefCount,
-        }
-
-        impl #generics #impl_ident::#generics where #constraints {
-            const VTABLES: (#(#vtbl_idents2,)*) = (#(#vtable_news,)*);
-            const IDENTITY: ::windows_core::IInspectable_Vtbl = ::windows_core::IInspectable_Vtbl::new::<Self, #identity_type, 0>();
-        }
-
+    let common = quote! {
         impl #generics #original_ident::#generics where #constraints {
-            /// This converts a partially-constructed COM object (in the sense that it contains
-            /// application state but does not yet have vtable and reference count constructed)
-            /// into a `StaticComObject`. This allows the COM object to be stored in static
-            /// (global) variables.
-            pub const fn into_static(self) -> ::windows_core::StaticComObject<Self> {
-                ::windows_core::StaticComObject::from_outer(self.into_outer())
+            /// Try casting as the provided interface
+            ///
+            /// # Safety
+            ///
+            /// This function can only be safely called if `self` has been heap allocated and pinned using
+            /// the mechanisms provided by `implement` macro.
+            #[inline(always)]
+            unsafe fn cast<I: ::windows_core::Interface>(&self) -> ::windows_core::Result<I> {
+                let boxed = (self as *const _ as *const *mut ::core::ffi::c_void).sub(1 + #interfaces_len) as *mut #impl_ident::#generics;
+                let mut result = ::core::ptr::null_mut();
+                _ = <#impl_ident::#generics as ::windows_core::IUnknownImpl>::QueryInterface(&*boxed, &I::IID, &mut result);
+                ::windows_core::Type::from_abi(result)
             }
+        }
+
+        impl #generics ::core::ops::Deref for #impl_ident::#generics where #constraints {
+            type Target = #original_ident::#generics;
 
-            // This constructs an "outer" object. This should only be used by the implementation
-            // of the outer object, never by application code.
-            //
-            // The callers of this function (`into_static` and `into_object`) are both responsible
-            // for maintaining one of our invariants: Application code never has an owned instance
-            // of the outer (implementation) type. into_static() maintains this invariant by
-            // returning a wrapped StaticComObject value, which owns its contents but never gives
-            // application code a way to mutably access its contents. This prevents the refcount
-            // shearing problem.
-            //
-            // TODO: Make it impossible for app code to call this function, by placing it in a
-            // module and marking this as private to the module.
             #[inline(always)]
-            const fn into_outer(self) -> #impl_ident::#generics {
-                #impl_ident::#generics {
-                    identity: &#impl_ident::#generics::IDENTITY,
-                    vtables: (#(&#impl_ident::#generics::VTABLES.#offset,)*),
-                    this: self,
-                    count: ::windows_core::imp::WeakRefCount::new(),
-                }
+            fn deref(&self) -> &Self::Target {
+                &self.this
             }
         }
 
-        impl #generics ::windows_core::ComObjectInner for #original_ident::#generics where #constraints {
-            type Outer = #impl_ident::#generics;
-
-            // IMPORTANT! This function handles assembling the "boxed" type of a COM object.
-            // It immediately moves the box into a heap allocation (box) and returns only a ComObject
-            // reference that points to it. We intentionally _do not_ expose any owned instances of
-            // Foo_Impl to safe Rust code, because doing so would allow unsound behavior in safe Rust
-            // code, due to the adjustments of the reference count that Foo_Impl permits.
-            //
-            // This is why this function returns ComObject<Self> instead of returning #impl_ident.
-
-            fn into_object(self) -> ::windows_core::ComObject<Self> {
-                let boxed = ::windows_core::imp::Box::<#impl_ident::#generics>::new(self.into_outer());
-                unsafe {
-                    let ptr = ::windows_core::imp::Box::into_raw(boxed);
-                    ::windows_core::ComObject::from_raw(
-                        ::core::ptr::NonNull::new_unchecked(ptr)
-                    )
-                }
-            }
+        // We intentionally do not provide a DerefMut impl, due to paranoia around soundness.
+
+        #(#conversions)*
+    };
+
+    let iunknown_common = quote! {
+        type Impl = #original_ident::#generics;
+
+        #[inline(always)]
+        fn get_impl(&self) -> &Self::Impl {
+            &self.this
         }
 
-        impl #generics ::windows_core::IUnknownImpl for #impl_ident::#generics where #constraints {
-            type Impl = #original_ident::#generics;
+        #[inline(always)]
+        fn get_impl_mut(&mut self) -> &mut Self::Impl {
+            &mut self.this
+        }
 
-            #[inline(always)]
-            fn get_impl(&self) -> &Self::Impl {
-                &self.this
-            }
+        #[inline(always)]
+        fn into_inner(self) -> Self::Impl {
+            self.this
+        }
 
-            #[inline(always)]
-            fn get_impl_mut(&mut self) -> &mut Self::Impl {
-                &mut self.this
+        unsafe fn GetTrustLevel(&self, value: *mut i32) -> ::windows_core::HRESULT {
+            if value.is_null() {
+                return ::windows_core::imp::E_POINTER;
             }
+            *value = #trust_level;
+            ::windows_core::HRESULT(0)
+        }
 
-            #[inline(always)]
-            fn is_reference_count_one(&self) -> bool {
-                self.count.is_one()
+        unsafe fn from_inner_ref(inner: &Self::Impl) -> &Self {
+            &*((inner as *const Self::Impl as *const *const ::core::ffi::c_void)
+                .sub(#offset_of_this_in_pointers_token) as *const Self)
+        }
+
+        const INNER_OFFSET_IN_POINTERS: usize = #offset_of_this_in_pointers_token;
+    };
+
+    let tokens = if let Some(owner) = &attributes.owner {
+        let owner = owner.to_ident();
+        let owner_outer = quote! { <#owner as ::windows_core::ComObjectInner>::Outer };
+
+        quote! {
+            #[repr(C)]
+            #[allow(non_camel_case_types)]
+            #vis struct #impl_ident #generics where #constraints {
+                identity: &'static ::windows_core::IInspectable_Vtbl,
+                vtables: (#(&'static #vtbl_idents,)*),
+                this: #original_ident::#generics,
+                owner: ::core::ptr::NonNull<#owner_outer>,
             }
 
-            #[inline(always)]
-            fn into_inner(self) -> Self::Impl {
-                self.this
+            impl #generics #impl_ident::#generics where #constraints {
+                const VTABLES: (#(#vtbl_idents2,)*) = (#(#vtable_news,)*);
+                const IDENTITY: ::windows_core::IInspectable_Vtbl = ::windows_core::IInspectable_Vtbl::new::<Self, #identity_type, 0>();
+                #interface_table
+
+                /// Returns the object that this tear-off belongs to.
+                #[inline(always)]
+                pub fn owner(&self) -> &#owner_outer {
+                    unsafe { self.owner.as_ref() }
+                }
+
+                fn find_interface(&self, iid: &::windows_core::GUID) -> *mut ::core::ffi::c_void {
+                    // The identity interfaces are answered by the owner so that the tear-off shares its identity.
+                    if iid == &<::windows_core::IUnknown as ::windows_core::Interface>::IID
+                        || iid == &<::windows_core::IInspectable as ::windows_core::Interface>::IID {
+                        ::core::ptr::null_mut()
+                    }
+                    #table_query
+                    #(#queries)*
+                    else {
+                        ::core::ptr::null_mut()
+                    }
+                }
             }
 
-            unsafe fn QueryInterface(&self, iid: *const ::windows_core::GUID, interface: *mut *mut ::core::ffi::c_void) -> ::windows_core::HRESULT {
-                if iid.is_null() || interface.is_null() {
-                    return ::windows_core::imp::E_POINTER;
+            impl #generics ::windows_core::imp::TearOffInner for #original_ident::#generics where #constraints {
+                type Outer = #impl_ident::#generics;
+                type Owner = #owner_outer;
+
+                #[inline(always)]
+                fn matches(iid: &::windows_core::GUID) -> bool {
+                    #(#vtbl_idents3::matches(iid))||*
                 }
 
-                let iid = &*iid;
+                unsafe fn into_tear_off(self, owner: &Self::Owner) -> Self::Outer {
+                    #impl_ident::#generics {
+                        identity: &#impl_ident::#generics::IDENTITY,
+                        vtables: (#(&#impl_ident::#generics::VTABLES.#offset,)*),
+                        this: self,
+                        owner: ::core::ptr::NonNull::from(owner),
+                    }
+                }
 
-                let interface_ptr: *mut ::core::ffi::c_void = if iid == &<::windows_core::IUnknown as ::windows_core::Interface>::IID
-                    || iid == &<::windows_core::IInspectable as ::windows_core::Interface>::IID
-                    || iid == &<::windows_core::imp::IAgileObject as ::windows_core::Interface>::IID {
-                        &self.identity as *const _ as *mut _
+                #[inline(always)]
+                fn find_interface(outer: &Self::Outer, iid: &::windows_core::GUID) -> *mut ::core::ffi::c_void {
+                    outer.find_interface(iid)
                 }
-                #(#queries)*
-                #dynamic_cast_query
-                else {
-                    ::core::ptr::null_mut()
-                };
+            }
 
-                if !interface_ptr.is_null() {
-                    *interface = interface_ptr;
-                    self.count.add_ref();
-                    return ::windows_core::HRESULT(0);
+            impl #generics ::windows_core::IUnknownImpl for #impl_ident::#generics where #constraints {
+                #iunknown_common
+
+                #[inline(always)]
+                fn is_reference_count_one(&self) -> bool {
+                    self.owner().is_reference_count_one()
                 }
 
-                let interface_ptr = self.count.query(iid, &self.identity as *const _ as *mut _);
-                *interface = interface_ptr;
+                unsafe fn QueryInterface(&self, iid: *const ::windows_core::GUID, interface: *mut *mut ::core::ffi::c_void) -> ::windows_core::HRESULT {
+                    if iid.is_null() || interface.is_null() {
+                        return ::windows_core::imp::E_POINTER;
+                    }
+
+                    let interface_ptr = self.find_interface(&*iid);
 
-                if interface_ptr.is_null() {
-                    ::windows_core::imp::E_NOINTERFACE
-                } else {
+                    if interface_ptr.is_null() {
+                        return self.owner().QueryInterface(iid, interface);
+                    }
+
+                    *interface = interface_ptr;
+                    self.owner().AddRef();
                     ::windows_core::HRESULT(0)
                 }
-            }
 
-            #[inline(always)]
-            fn AddRef(&self) -> u32 {
-                self.count.add_ref()
-            }
+                #[inline(always)]
+                fn AddRef(&self) -> u32 {
+                    self.owner().AddRef()
+                }
 
-            #[inline(always)]
-            unsafe fn Release(self_: *mut Self) -> u32 {
-                let remaining = (*self_).count.release();
-                if remaining == 0 {
-                    _ = ::windows_core::imp::Box::from_raw(self_);
+                #[inline(always)]
+                unsafe fn Release(self_: *mut Self) -> u32 {
+                    // The tear-off is destroyed along with its owner.
+                    <#owner_outer as ::windows_core::IUnknownImpl>::Release((*self_).owner.as_ptr())
                 }
-                remaining
             }
 
-            unsafe fn GetTrustLevel(&self, value: *mut i32) -> ::windows_core::HRESULT {
-                if value.is_null() {
-                    return ::windows_core::imp::E_POINTER;
-                }
-                *value = #trust_level;
-                ::windows_core::HRESULT(0)
+            #common
+        }
+    } else {
+        quote! {
+            #[repr(C)]
+            #[allow(non_camel_case_types)]
+            #vis struct #impl_ident #generics where #constraints {
+                identity: &'static ::windows_core::IInspectable_Vtbl,
+                vtables: (#(&'static #vtbl_idents,)*),
+                this: #original_ident::#generics,
+                count: ::windows_core::imp::WeakRefCount,
+                #tear_offs_field
             }
 
-            unsafe fn from_inner_ref(inner: &Self::Impl) -> &Self {
-                &*((inner as *const Self::Impl as *const *const ::core::ffi::c_void)
-                    .sub(#offset_of_this_in_pointers_token) as *const Self)
+            impl #generics #impl_ident::#generics where #constraints {
+                const VTABLES: (#(#vtbl_idents2,)*) = (#(#vtable_news,)*);
+                const IDENTITY: ::windows_core::IInspectable_Vtbl = ::windows_core::IInspectable_Vtbl::new::<Self, #identity_type, 0>();
+                #interface_table
             }
 
-            fn to_object(&self) -> ::windows_core::ComObject<Self::Impl> {
-                self.count.add_ref();
-                unsafe {
-                    ::windows_core::ComObject::from_raw(
-                        ::core::ptr::NonNull::new_unchecked(self as *const Self as *mut Self)
-                    )
+            impl #generics #original_ident::#generics where #constraints {
+                /// This converts a partially-constructed COM object (in the sense that it contains
+                /// application state but does not yet have vtable and reference count constructed)
+                /// into a `StaticComObject`. This allows the COM object to be stored in static
+                /// (global) variables.
+                pub const fn into_static(self) -> ::windows_core::StaticComObject<Self> {
+                    ::windows_core::StaticComObject::from_outer(self.into_outer())
+                }
+
+                // This constructs an "outer" object. This should only be used by the implementation
+                // of the outer object, never by application code.
+                //
+                // The callers of this function (`into_static` and `into_object`) are both responsible
+                // for maintaining one of our invariants: Application code never has an owned instance
+                // of the outer (implementation) type. into_static() maintains this invariant by
+                // returning a wrapped StaticComObject value, which owns its contents but never gives
+                // application code a way to mutably access its contents. This prevents the refcount
+                // shearing problem.
+                //
+                // TODO: Make it impossible for app code to call this function, by placing it in a
+                // module and marking this as private to the module.
+                #[inline(always)]
+                const fn into_outer(self) -> #impl_ident::#generics {
+                    #impl_ident::#generics {
+                        identity: &#impl_ident::#generics::IDENTITY,
+                        vtables: (#(&#impl_ident::#generics::VTABLES.#offset,)*),
+                        this: self,
+                        count: ::windows_core::imp::WeakRefCount::new(),
+                        #tear_offs_new
+                    }
                 }
             }
 
-            const INNER_OFFSET_IN_POINTERS: usize = #offset_of_this_in_pointers_token;
-        }
+            impl #generics ::windows_core::ComObjectInner for #original_ident::#generics where #constraints {
+                type Outer = #impl_ident::#generics;
 
-        impl #generics #original_ident::#generics where #constraints {
-            /// Try casting as the provided interface
-            ///
-            /// # Safety
-            ///
-            /// This function can only be safely called if `self` has been heap allocated and pinned using
-            /// the mechanisms provided by `implement` macro.
-            #[inline(always)]
-            unsafe fn cast<I: ::windows_core::Interface>(&self) -> ::windows_core::Result<I> {
-                let boxed = (self as *const _ as *const *mut ::core::ffi::c_void).sub(1 + #interfaces_len) as *mut #impl_ident::#generics;
-                let mut result = ::core::ptr::null_mut();
-                _ = <#impl_ident::#generics as ::windows_core::IUnknownImpl>::QueryInterface(&*boxed, &I::IID, &mut result);
-                ::windows_core::Type::from_abi(result)
-            }
-        }
+                // IMPORTANT! This function handles assembling the "boxed" type of a COM object.
+                // It immediately moves the box into a heap allocation (box) and returns only a ComObject
+                // reference that points to it. We intentionally _do not_ expose any owned instances of
+                // Foo_Impl to safe Rust code, because doing so would allow unsound behavior in safe Rust
+                // code, due to the adjustments of the reference count that Foo_Impl permits.
+                //
+                // This is why this function returns ComObject<Self> instead of returning #impl_ident.
 
-        impl #generics ::core::convert::From<#original_ident::#generics> for ::windows_core::IUnknown where #constraints {
-            #[inline(always)]
-            fn from(this: #original_ident::#generics) -> Self {
-                let com_object = ::windows_core::ComObject::new(this);
-                com_object.into_interface()
+                fn into_object(self) -> ::windows_core::ComObject<Self> {
+                    let boxed = ::windows_core::imp::Box::<#impl_ident::#generics>::new(self.into_outer());
+                    unsafe {
+                        let ptr = ::windows_core::imp::Box::into_raw(boxed);
+                        ::windows_core::ComObject::from_raw(
+                            ::core::ptr::NonNull::new_unchecked(ptr)
+                        )
+                    }
+                }
             }
-        }
 
-        impl #generics ::core::convert::From<#original_ident::#generics> for ::windows_core::IInspectable where #constraints {
-            #[inline(always)]
-            fn from(this: #original_ident::#generics) -> Self {
-                let com_object = ::windows_core::ComObject::new(this);
-                com_object.into_interface()
-            }
-        }
+            impl #generics ::windows_core::IUnknownImpl for #impl_ident::#generics where #constraints {
+                #iunknown_common
 
-        impl #generics ::windows_core::ComObjectInterface<::windows_core::IUnknown> for #impl_ident::#generics where #constraints {
-            #[inline(always)]
-            fn as_interface_ref(&self) -> ::windows_core::InterfaceRef<'_, ::windows_core::IUnknown> {
-                unsafe {
-                    let interface_ptr = &self.identity;
-                    ::core::mem::transmute(interface_ptr)
+                #[inline(always)]
+                fn is_reference_count_one(&self) -> bool {
+                    self.count.is_one()
+                }
+
+                unsafe fn QueryInterface(&self, iid: *const ::windows_core::GUID, interface: *mut *mut ::core::ffi::c_void) -> ::windows_core::HRESULT {
+                    if iid.is_null() || interface.is_null() {
+                        return ::windows_core::imp::E_POINTER;
+                    }
+
+                    let iid = &*iid;
+
+                    let interface_ptr: *mut ::core::ffi::c_void = if iid == &<::windows_core::IUnknown as ::windows_core::Interface>::IID
+                        || iid == &<::windows_core::IInspectable as ::windows_core::Interface>::IID
+                        || iid == &<::windows_core::imp::IAgileObject as ::windows_core::Interface>::IID {
+                            &self.identity as *const _ as *mut _
+                    }
+                    #table_query
+                    #(#queries)*
+                    #(#tear_off_queries)*
+                    #dynamic_cast_query
+                    else {
+                        ::core::ptr::null_mut()
+                    };
+
+                    if !interface_ptr.is_null() {
+                        *interface = interface_ptr;
+                        self.count.add_ref();
+                        return ::windows_core::HRESULT(0);
+                    }
+
+                    let interface_ptr = self.count.query(iid, &self.identity as *const _ as *mut _);
+                    *interface = interface_ptr;
+
+                    if interface_ptr.is_null() {
+                        ::windows_core::imp::E_NOINTERFACE
+                    } else {
+                        ::windows_core::HRESULT(0)
+                    }
+                }
+
+                #[inline(always)]
+                fn AddRef(&self) -> u32 {
+                    self.count.add_ref()
+                }
+
+                #[inline(always)]
+                unsafe fn Release(self_: *mut Self) -> u32 {
+                    let remaining = (*self_).count.release();
+                    if remaining == 0 {
+                        _ = ::windows_core::imp::Box::from_raw(self_);
+                    }
+                    remaining
                 }
             }
-        }
 
-        impl #generics ::windows_core::ComObjectInterface<::windows_core::IInspectable> for #impl_ident::#generics where #constraints {
-            #[inline(always)]
-            fn as_interface_ref(&self) -> ::windows_core::InterfaceRef<'_, ::windows_core::IInspectable> {
-                unsafe {
-                    let interface_ptr = &self.identity;
-                    ::core::mem::transmute(interface_ptr)
+            impl #generics ::core::convert::From<#original_ident::#generics> for ::windows_core::IUnknown where #constraints {
+                #[inline(always)]
+                fn from(this: #original_ident::#generics) -> Self {
+                    let com_object = ::windows_core::ComObject::new(this);
+                    com_object.into_interface()
                 }
             }
-        }
 
-        impl #generics ::windows_core::AsImpl<#original_ident::#generics> for ::windows_core::IUnknown where #constraints {
-            // SAFETY: the offset is guranteed to be in bounds, and the implementation struct
-            // is guaranteed to live at least as long as `self`.
-            #[inline(always)]
-            unsafe fn as_impl_ptr(&self) -> ::core::ptr::NonNull<#original_ident::#generics> {
-                let this = ::windows_core::Interface::as_raw(self);
-                // Subtract away the vtable offset plus 1, for the `identity` field, to get
-                // to the impl struct which contains that original implementation type.
-                let this = (this as *mut *mut ::core::ffi::c_void).sub(1) as *mut #impl_ident::#generics;
-                ::core::ptr::NonNull::new_unchecked(::core::ptr::addr_of!((*this).this) as *const #original_ident::#generics as *mut #original_ident::#generics)
+            impl #generics ::core::convert::From<#original_ident::#generics> for ::windows_core::IInspectable where #constraints {
+                #[inline(always)]
+                fn from(this: #original_ident::#generics) -> Self {
+                    let com_object = ::windows_core::ComObject::new(this);
+                    com_object.into_interface()
+                }
             }
-        }
 
-        impl #generics ::core::ops::Deref for #impl_ident::#generics where #constraints {
-            type Target = #original_ident::#generics;
+            impl #generics ::windows_core::ComObjectInterface<::windows_core::IUnknown> for #impl_ident::#generics where #constraints {
+                #[inline(always)]
+                fn as_interface_ref(&self) -> ::windows_core::InterfaceRef<'_, ::windows_core::IUnknown> {
+                    unsafe {
+                        let interface_ptr = &self.identity;
+                        ::core::mem::transmute(interface_ptr)
+                    }
+                }
+            }
 
-            #[inline(always)]
-            fn deref(&self) -> &Self::Target {
-                &self.this
+            impl #generics ::windows_core::ComObjectInterface<::windows_core::IInspectable> for #impl_ident::#generics where #constraints {
+                #[inline(always)]
+                fn as_interface_ref(&self) -> ::windows_core::InterfaceRef<'_, ::windows_core::IInspectable> {
+                    unsafe {
+                        let interface_ptr = &self.identity;
+                        ::core::mem::transmute(interface_ptr)
+                    }
+                }
             }
-        }
 
-        // We intentionally do not provide a DerefMut impl, due to paranoia around soundness.
+            impl #generics ::windows_core::AsImpl<#original_ident::#generics> for ::windows_core::IUnknown where #constraints {
+                // SAFETY: the offset is guranteed to be in bounds, and the implementation struct
+                // is guaranteed to live at least as long as `self`.
+                #[inline(always)]
+                unsafe fn as_impl_ptr(&self) -> ::core::ptr::NonNull<#original_ident::#generics> {
+                    let this = ::windows_core::Interface::as_raw(self);
+                    // Subtract away the vtable offset plus 1, for the `identity` field, to get
+                    // to the impl struct which contains that original implementation type.
+                    let this = (this as *mut *mut ::core::ffi::c_void).sub(1) as *mut #impl_ident::#generics;
+                    ::core::ptr::NonNull::new_unchecked(::core::ptr::addr_of!((*this).this) as *const #original_ident::#generics as *mut #original_ident::#generics)
+                }
+            }
 
-        #(#conversions)*
+            #common
+        }
     };
 
     let mut tokens: proc_macro::TokenStream = tokens.into();
@@ -438,6 +664,8 @@ impl ImplementType {
 struct ImplementAttributes {
     pub implement: Vec<ImplementType>,
     pub trust_level: usize,
+    pub tear_offs: Vec<ImplementType>,
+    pub owner: Option<ImplementType>,
 }
 
 impl syn::parse::Parse for ImplementAttributes {
@@ -487,6 +715,10 @@ impl ImplementAttributes {
                 }
             }
             UseTree2::TrustLevel(input) => self.trust_level = *input,
+            UseTree2::TearOff(input) => self
+                .tear_offs
+                .push(input.to_element_type(&mut String::new())?),
+            UseTree2::Owner(input) => self.owner = Some(input.to_element_type(&mut String::new())?),
         }
 
         Ok(())
@@ -498,6 +730,8 @@ enum UseTree2 {
     Name(UseName2),
     Group(UseGroup2),
     TrustLevel(usize),
+    TearOff(Box<UseTree2>),
+    Owner(Box<UseTree2>),
 }
 
 impl UseTree2 {
@@ -566,6 +800,15 @@ impl syn::parse::Parse for UseTree2 {
                     tree: Box::new(input.parse()?),
                 }))
             } else if input.peek(syn::Token![=]) {
+                if ident == "TearOff" || ident == "Owner" {
+                    input.parse::<syn::Token![=]>()?;
+                    let tree = Box::new(input.parse()?);
+                    return Ok(if ident == "TearOff" {
+                        UseTree2::TearOff(tree)
+                    } else {
+                        UseTree2::Owner(tree)
+                    });
+                }
                 if ident != "TrustLevel" {
                     return Err(syn::parse::Error::new(
                         ident.span(),
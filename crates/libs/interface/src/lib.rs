@@ -2,7 +2,7 @@
 Learn more about Rust for Windows here: <https://github.com/microsoft/windows-rs>
 */
 
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::spanned::Spanned;
 
 /// Defines a COM interface to call or implement.
@@ -295,6 +295,8 @@ impl Interface {
                 })
                 .collect::<Vec<_>>();
 
+            let proxy = self.gen_proxy_vtable(vtable_name, &parent_vtable);
+
             quote! {
                 #[repr(C)]
                 #[doc(hidden)]
@@ -320,6 +322,7 @@ impl Interface {
                         #or_parent_matches
                     }
                 }
+                #proxy
             }
         } else {
             let entries = self
@@ -358,6 +361,72 @@ impl Interface {
         }
     }
 
+    /// Generates the vtable used by `windows_core::proxy` to forward calls to, and report calls on, an inner object
+    fn gen_proxy_vtable(
+        &self,
+        vtable_name: &syn::Ident,
+        parent_vtable: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        let name_string = name.to_string();
+
+        let functions = self
+            .methods
+            .iter()
+            .enumerate()
+            .map(|(index, m)| {
+                let method = &m.name;
+                let method_string = method.to_string();
+                let args = m.gen_args();
+                let params = m.args.iter().map(|a| &a.pat);
+
+                let (ret, result) = if m.is_result() {
+                    (quote!(-> ::windows_core::HRESULT), quote!(|result| Some(*result)))
+                } else if m.is_hresult() {
+                    (m.ret.to_token_stream(), quote!(|result| Some(*result)))
+                } else {
+                    (m.ret.to_token_stream(), quote!(|_| None))
+                };
+
+                quote! {
+                    unsafe extern "system" fn #method(this: *mut ::core::ffi::c_void, #(#args),*) #ret {
+                        ::windows_core::imp::proxy_call(
+                            this,
+                            #name_string,
+                            #method_string,
+                            ::core::mem::size_of::<#parent_vtable>() / ::core::mem::size_of::<usize>() + #index,
+                            |inner| ((**(inner as *const *const #vtable_name)).#method)(inner, #(#params),*),
+                            #result,
+                        )
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let entries = self.methods.iter().map(|m| &m.name);
+
+        // The `Marker` parameter keeps the bound on the parent vtable from being checked unless the proxy is
+        // used, since the parent may be an interface without a proxy vtable.
+        quote! {
+            impl<Marker> ::windows_core::imp::ProxyVtable<Marker> for #vtable_name
+            where
+                #parent_vtable: ::windows_core::imp::ProxyVtable<Marker>
+            {
+                const PROXY: Self = {
+                    #(#functions)*
+                    Self { base__: <#parent_vtable as ::windows_core::imp::ProxyVtable<Marker>>::PROXY, #(#entries),* }
+                };
+
+                const VTABLE: &'static Self = &<Self as ::windows_core::imp::ProxyVtable<Marker>>::PROXY;
+
+                fn matches(iid: &::windows_core::GUID) -> bool {
+                    *iid == <#name as ::windows_core::Interface>::IID
+                        || <#parent_vtable as ::windows_core::imp::ProxyVtable<Marker>>::matches(iid)
+                }
+            }
+        }
+    }
+
     /// Generates various conversions such as from and to `IUnknown`
     fn gen_conversions(&self) -> proc_macro2::TokenStream {
         let name = &self.name;
@@ -618,6 +687,18 @@ impl InterfaceMethod {
         false
     }
 
+    fn is_hresult(&self) -> bool {
+        if let syn::ReturnType::Type(_, ty) = &self.ret {
+            if let syn::Type::Path(path) = &**ty {
+                if let Some(segment) = path.path.segments.last() {
+                    return segment.ident == "HRESULT";
+                }
+            }
+        }
+
+        false
+    }
+
     /// Generates arguments (of the form `$pat: $type`)
     fn gen_args(&self) -> Vec<proc_macro2::TokenStream> {
         self.args
@@ -88,6 +88,7 @@ impl Error {
 
     /// Creates a new error object, capturing the stack and other information about the
     /// point of failure.
+    #[cfg_attr(feature = "tracing", track_caller)]
     pub fn new<T: AsRef<str>>(code: HRESULT, message: T) -> Self {
         #[cfg(windows)]
         {
@@ -107,14 +108,17 @@ impl Error {
     }
 
     /// Creates a new error object with an error code, but without additional error information.
+    #[cfg_attr(feature = "tracing", track_caller)]
     pub fn from_hresult(code: HRESULT) -> Self {
         Self {
             code: nonzero_hresult(code),
             info: ErrorInfo::empty(),
         }
+        .trace()
     }
 
     /// Creates a new `Error` from the Win32 error code returned by `GetLastError()`.
+    #[cfg_attr(feature = "tracing", track_caller)]
     pub fn from_win32() -> Self {
         #[cfg(windows)]
         {
@@ -137,6 +141,9 @@ impl Error {
     }
 
     /// The error message describing the error.
+    ///
+    /// The message is not captured when the error is created. Only the error code and error info
+    /// object are stored, and the text is retrieved from them each time this method is called.
     pub fn message(&self) -> String {
         if let Some(message) = self.info.message() {
             return message;
@@ -151,6 +158,26 @@ impl Error {
     pub fn as_ptr(&self) -> *mut core::ffi::c_void {
         self.info.as_ptr()
     }
+
+    // Emits a `tracing` event for the newly created error, attributed to the caller that created it.
+    // The message is only retrieved if a subscriber is actually interested in the event.
+    #[cfg(feature = "tracing")]
+    #[track_caller]
+    fn trace(self) -> Self {
+        tracing::debug!(
+            target: "windows",
+            code = %self.code(),
+            message = %self.message(),
+            location = %core::panic::Location::caller()
+        );
+        self
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    #[inline]
+    fn trace(self) -> Self {
+        self
+    }
 }
 
 #[cfg(feature = "std")]
@@ -165,11 +192,13 @@ impl From<Error> for HRESULT {
 }
 
 impl From<HRESULT> for Error {
+    #[cfg_attr(feature = "tracing", track_caller)]
     fn from(code: HRESULT) -> Self {
         Self {
             code: nonzero_hresult(code),
             info: ErrorInfo::from_thread(),
         }
+        .trace()
     }
 }
 
@@ -224,7 +253,7 @@ impl core::fmt::Display for Error {
         if message.is_empty() {
             core::write!(fmt, "{}", self.code())
         } else {
-            core::write!(fmt, "{} ({})", self.message(), self.code())
+            core::write!(fmt, "{} ({})", message, self.code())
         }
     }
 }
@@ -32,6 +32,7 @@ impl HRESULT {
 
     /// Converts the [`HRESULT`] to [`Result<()>`][Result<_>].
     #[inline]
+    #[cfg_attr(feature = "tracing", track_caller)]
     pub fn ok(self) -> Result<()> {
         if self.is_ok() {
             Ok(())
@@ -43,6 +44,7 @@ impl HRESULT {
     /// Calls `op` if `self` is a success code, otherwise returns [`HRESULT`]
     /// converted to [`Result<T>`].
     #[inline]
+    #[cfg_attr(feature = "tracing", track_caller)]
     pub fn map<F, T>(self, op: F) -> Result<T>
     where
         F: FnOnce() -> T,
@@ -54,6 +56,7 @@ impl HRESULT {
     /// Calls `op` if `self` is a success code, otherwise returns [`HRESULT`]
     /// converted to [`Result<T>`].
     #[inline]
+    #[cfg_attr(feature = "tracing", track_caller)]
     pub fn and_then<F, T>(self, op: F) -> Result<T>
     where
         F: FnOnce() -> Result<T>,
@@ -63,8 +66,11 @@ impl HRESULT {
     }
 
     /// The error message describing the error.
+    ///
+    /// This is always empty if compiled with `--cfg windows_no_debug`, which omits the message formatting
+    /// path to reduce binary size.
     pub fn message(self) -> String {
-        #[cfg(windows)]
+        #[cfg(all(windows, not(windows_no_debug)))]
         {
             let mut message = HeapString::default();
             let mut code = self.0;
@@ -107,6 +113,11 @@ impl HRESULT {
             }
         }
 
+        #[cfg(all(windows, windows_no_debug))]
+        {
+            String::new()
+        }
+
         #[cfg(not(windows))]
         {
             return alloc::format!("0x{:08x}", self.0 as u32);
@@ -152,3 +163,22 @@ impl core::fmt::Debug for HRESULT {
         f.write_fmt(format_args!("HRESULT({})", self))
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for HRESULT {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HRESULT {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        i32::deserialize(deserializer).map(Self)
+    }
+}
@@ -20,9 +20,9 @@ use bindings::*;
 #[cfg(all(windows, not(windows_slim_errors)))]
 mod com;
 
-#[cfg(windows)]
+#[cfg(all(windows, not(all(windows_slim_errors, windows_no_debug))))]
 mod strings;
-#[cfg(windows)]
+#[cfg(all(windows, not(all(windows_slim_errors, windows_no_debug))))]
 use strings::*;
 
 #[cfg(all(windows, not(windows_slim_errors)))]
@@ -1,13 +1,17 @@
+#[cfg(not(windows_no_debug))]
 use super::*;
 
+#[cfg(not(windows_no_debug))]
 pub struct HeapString(pub *mut u16);
 
+#[cfg(not(windows_no_debug))]
 impl Default for HeapString {
     fn default() -> Self {
         Self(core::ptr::null_mut())
     }
 }
 
+#[cfg(not(windows_no_debug))]
 impl Drop for HeapString {
     fn drop(&mut self) {
         if !self.0.is_null() {
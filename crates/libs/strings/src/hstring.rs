@@ -45,7 +45,19 @@ impl HSTRING {
 
     /// Create a `HSTRING` from a slice of 16 bit characters (wchars).
     pub fn from_wide(value: &[u16]) -> Result<Self> {
-        unsafe { Self::from_wide_iter(value.iter().copied(), value.len()) }
+        if value.is_empty() {
+            return Ok(Self::new());
+        }
+
+        let ptr = HStringHeader::alloc(value.len().try_into()?)?;
+
+        // SAFETY: the buffer was allocated to hold `value.len()` characters plus the terminating null character.
+        unsafe {
+            core::ptr::copy_nonoverlapping(value.as_ptr(), (*ptr).data, value.len());
+            (*ptr).data.add(value.len()).write(0);
+        }
+
+        Ok(Self(ptr))
     }
 
     /// Get the contents of this `HSTRING` as a String lossily.
@@ -67,21 +79,49 @@ impl HSTRING {
         }
 
         let ptr = HStringHeader::alloc(len.try_into()?)?;
+        let data = (*ptr).data;
+        let mut count = 0;
 
-        // Place each utf-16 character into the buffer and
-        // increase len as we go along.
-        for (index, wide) in iter.enumerate() {
-            debug_assert!(index < len);
+        // Place each utf-16 character into the buffer, only updating the header's
+        // length once all of the characters have been written.
+        for wide in iter {
+            debug_assert!(count < len);
 
-            (*ptr).data.add(index).write(wide);
-            (*ptr).len = index as u32 + 1;
+            data.add(count).write(wide);
+            count += 1;
         }
 
         // Write a 0 byte to the end of the buffer.
-        (*ptr).data.offset((*ptr).len as isize).write(0);
+        data.add(count).write(0);
+        (*ptr).len = count as u32;
         Ok(Self(ptr))
     }
 
+    /// Creates an `HSTRING` from ASCII text, widening each byte without decoding UTF-8.
+    ///
+    /// # Safety
+    /// `value` must be ASCII.
+    unsafe fn from_ascii(value: &[u8]) -> Result<Self> {
+        if value.is_empty() {
+            return Ok(Self::new());
+        }
+
+        let ptr = HStringHeader::alloc(value.len().try_into()?)?;
+        let data = core::slice::from_raw_parts_mut((*ptr).data, value.len() + 1);
+
+        for (wide, byte) in data.iter_mut().zip(value) {
+            *wide = *byte as u16;
+        }
+
+        data[value.len()] = 0;
+        Ok(Self(ptr))
+    }
+
+    /// Returns `true` if both strings share the same buffer, such as when one was cloned from the other.
+    fn same_buffer(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
     fn as_header(&self) -> Option<&HStringHeader> {
         unsafe { self.0.as_ref() }
     }
@@ -138,7 +178,15 @@ impl core::fmt::Debug for HSTRING {
 
 impl From<&str> for HSTRING {
     fn from(value: &str) -> Self {
-        unsafe { Self::from_wide_iter(value.encode_utf16(), value.len()).unwrap() }
+        // ASCII text, which most strings are, has exactly one UTF-16 code unit per byte so it can
+        // be copied directly rather than decoded and the buffer is no larger than needed.
+        unsafe {
+            if value.is_ascii() {
+                Self::from_ascii(value.as_bytes()).unwrap()
+            } else {
+                Self::from_wide_iter(value.encode_utf16(), value.len()).unwrap()
+            }
+        }
     }
 }
 
@@ -210,7 +258,10 @@ impl PartialOrd for HSTRING {
 
 impl PartialEq for HSTRING {
     fn eq(&self, other: &Self) -> bool {
-        *self.as_wide() == *other.as_wide()
+        // Strings sharing a buffer are equal without comparing their contents, and strings of different
+        // lengths are unequal, both of which only need the length-prefixed header.
+        self.same_buffer(other)
+            || (self.len() == other.len() && *self.as_wide() == *other.as_wide())
     }
 }
 
@@ -234,6 +285,23 @@ impl PartialEq<&String> for HSTRING {
 
 impl PartialEq<str> for HSTRING {
     fn eq(&self, other: &str) -> bool {
+        // Each UTF-16 code unit is encoded by between one and three UTF-8 bytes, so strings whose
+        // lengths fall outside of that range cannot be equal and need not be decoded.
+        let len = self.len();
+
+        if len > other.len() || len * 3 < other.len() {
+            return false;
+        }
+
+        if other.is_ascii() {
+            return len == other.len()
+                && self
+                    .as_wide()
+                    .iter()
+                    .zip(other.as_bytes())
+                    .all(|(wide, byte)| *wide == *byte as u16);
+        }
+
         self.as_wide().iter().copied().eq(other.encode_utf16())
     }
 }
@@ -402,3 +470,40 @@ impl From<HSTRING> for std::ffi::OsString {
         Self::from(&hstring)
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for HSTRING {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        let value = String::try_from(self).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HSTRING {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = HSTRING;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: serde::de::Error>(
+                self,
+                value: &str,
+            ) -> core::result::Result<HSTRING, E> {
+                Ok(HSTRING::from(value))
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
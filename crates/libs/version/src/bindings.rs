@@ -5,7 +5,37 @@
     dead_code,
     clippy::all
 )]
+windows_targets::link!("advapi32.dll" "system" fn RegGetValueW(hkey : HKEY, lpsubkey : PCWSTR, lpvalue : PCWSTR, dwflags : REG_ROUTINE_FLAGS, pdwtype : *mut REG_VALUE_TYPE, pvdata : *mut core::ffi::c_void, pcbdata : *mut u32) -> WIN32_ERROR);
+windows_targets::link!("api-ms-win-core-winrt-l1-1-0.dll" "system" fn RoGetActivationFactory(activatableclassid : * mut core::ffi::c_void, iid : *const GUID, factory : *mut *mut core::ffi::c_void) -> HRESULT);
+windows_targets::link!("api-ms-win-core-winrt-string-l1-1-0.dll" "system" fn WindowsCreateString(sourcestring : PCWSTR, length : u32, string : *mut * mut core::ffi::c_void) -> HRESULT);
+windows_targets::link!("api-ms-win-core-winrt-string-l1-1-0.dll" "system" fn WindowsDeleteString(string : * mut core::ffi::c_void) -> HRESULT);
+windows_targets::link!("kernel32.dll" "system" fn GetProductInfo(dwosmajorversion : u32, dwosminorversion : u32, dwspmajorversion : u32, dwspminorversion : u32, pdwreturnedproducttype : *mut OS_PRODUCT_TYPE) -> BOOL);
 windows_targets::link!("ntdll.dll" "system" fn RtlGetVersion(lpversioninformation : *mut OSVERSIONINFOW) -> NTSTATUS);
+windows_targets::link!("ole32.dll" "system" fn CoIncrementMTAUsage(pcookie : *mut CO_MTA_USAGE_COOKIE) -> HRESULT);
+pub type BOOL = i32;
+pub const CO_E_NOTINITIALIZED: HRESULT = 0x800401F0_u32 as _;
+pub type CO_MTA_USAGE_COOKIE = *mut core::ffi::c_void;
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct GUID {
+    pub data1: u32,
+    pub data2: u16,
+    pub data3: u16,
+    pub data4: [u8; 8],
+}
+impl GUID {
+    pub const fn from_u128(uuid: u128) -> Self {
+        Self {
+            data1: (uuid >> 96) as u32,
+            data2: (uuid >> 80 & 0xffff) as u16,
+            data3: (uuid >> 64 & 0xffff) as u16,
+            data4: (uuid as u64).to_be_bytes(),
+        }
+    }
+}
+pub type HKEY = *mut core::ffi::c_void;
+pub const HKEY_LOCAL_MACHINE: HKEY = -2147483646i32 as _;
+pub type HRESULT = i32;
 pub type NTSTATUS = i32;
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -32,4 +62,13 @@ pub struct OSVERSIONINFOW {
     pub dwPlatformId: u32,
     pub szCSDVersion: [u16; 128],
 }
+pub type OS_PRODUCT_TYPE = u32;
+pub type PCWSTR = *const u16;
+pub type REG_ROUTINE_FLAGS = u32;
+pub type REG_VALUE_TYPE = u32;
+pub const RRF_RT_REG_DWORD: REG_ROUTINE_FLAGS = 16u32;
+pub const RRF_RT_REG_SZ: REG_ROUTINE_FLAGS = 2u32;
+pub const VER_NT_DOMAIN_CONTROLLER: u32 = 2u32;
+pub const VER_NT_SERVER: u32 = 3u32;
 pub const VER_NT_WORKSTATION: u32 = 1u32;
+pub type WIN32_ERROR = u32;
@@ -0,0 +1,199 @@
+use super::bindings::*;
+use alloc::vec::Vec;
+use core::ffi::c_void;
+
+/// The name of the contract that includes most of the Windows Runtime APIs.
+pub const UNIVERSAL_API_CONTRACT: &str = "Windows.Foundation.UniversalApiContract";
+
+/// The name of the contract that includes the foundational Windows Runtime types, such as `Uri`.
+pub const FOUNDATION_CONTRACT: &str = "Windows.Foundation.FoundationContract";
+
+/// A Windows Runtime API contract, which is a versioned set of APIs that are available together.
+///
+/// Checking for a contract is more reliable than comparing build numbers, since a contract can be
+/// serviced to older releases and isn't affected by the application manifest:
+///
+/// ```rust,no_run
+/// use windows_version::*;
+///
+/// const CONTRACT: ApiContract = ApiContract::new(UNIVERSAL_API_CONTRACT, 15, 0);
+///
+/// if CONTRACT.is_present() {
+///     println!("Can use the APIs introduced by Windows 11 version 22H2.");
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ApiContract {
+    /// The name of the contract, such as `Windows.Foundation.UniversalApiContract`.
+    pub name: &'static str,
+
+    /// The major version of the contract.
+    pub major: u16,
+
+    /// The minor version of the contract.
+    pub minor: u16,
+}
+
+impl ApiContract {
+    /// The version of the universal contract introduced by Windows 10 version 1507.
+    pub const UNIVERSAL_V1: Self = Self::new(UNIVERSAL_API_CONTRACT, 1, 0);
+
+    /// The version of the universal contract introduced by Windows 10 version 1511.
+    pub const UNIVERSAL_V2: Self = Self::new(UNIVERSAL_API_CONTRACT, 2, 0);
+
+    /// The version of the universal contract introduced by Windows 10 version 1607.
+    pub const UNIVERSAL_V3: Self = Self::new(UNIVERSAL_API_CONTRACT, 3, 0);
+
+    /// The version of the universal contract introduced by Windows 10 version 1703.
+    pub const UNIVERSAL_V4: Self = Self::new(UNIVERSAL_API_CONTRACT, 4, 0);
+
+    /// The version of the universal contract introduced by Windows 10 version 1709.
+    pub const UNIVERSAL_V5: Self = Self::new(UNIVERSAL_API_CONTRACT, 5, 0);
+
+    /// The version of the universal contract introduced by Windows 10 version 1803.
+    pub const UNIVERSAL_V6: Self = Self::new(UNIVERSAL_API_CONTRACT, 6, 0);
+
+    /// The version of the universal contract introduced by Windows 10 version 1809.
+    pub const UNIVERSAL_V7: Self = Self::new(UNIVERSAL_API_CONTRACT, 7, 0);
+
+    /// The version of the universal contract introduced by Windows 10 version 1903.
+    pub const UNIVERSAL_V8: Self = Self::new(UNIVERSAL_API_CONTRACT, 8, 0);
+
+    /// The version of the universal contract introduced by Windows 10 version 2004.
+    pub const UNIVERSAL_V10: Self = Self::new(UNIVERSAL_API_CONTRACT, 10, 0);
+
+    /// The version of the universal contract introduced by Windows 11 version 21H2.
+    pub const UNIVERSAL_V14: Self = Self::new(UNIVERSAL_API_CONTRACT, 14, 0);
+
+    /// The version of the universal contract introduced by Windows 11 version 22H2.
+    pub const UNIVERSAL_V15: Self = Self::new(UNIVERSAL_API_CONTRACT, 15, 0);
+
+    /// Creates a new `ApiContract` with the given name and version.
+    pub const fn new(name: &'static str, major: u16, minor: u16) -> Self {
+        Self { name, major, minor }
+    }
+
+    /// Determines if the contract, at this version or later, is present on the currently running operating system.
+    pub fn is_present(&self) -> bool {
+        is_api_contract_present(self.name, self.major, self.minor)
+    }
+}
+
+/// Determines if the named API contract, at the given version or later, is present on the currently running
+/// operating system.
+///
+/// Returns `false` if the Windows Runtime isn't available.
+pub fn is_api_contract_present(name: &str, major: u16, minor: u16) -> bool {
+    check(name, |statics, name, present| unsafe {
+        ((**statics).IsApiContractPresentByMajorAndMinor)(
+            statics as *mut _,
+            name,
+            major,
+            minor,
+            present,
+        )
+    })
+}
+
+/// Determines if the named Windows Runtime type, such as `Windows.Foundation.Uri`, is present on the currently
+/// running operating system.
+///
+/// Returns `false` if the Windows Runtime isn't available.
+pub fn is_type_present(name: &str) -> bool {
+    check(name, |statics, name, present| unsafe {
+        ((**statics).IsTypePresent)(statics as *mut _, name, present)
+    })
+}
+
+// Calls one of the `ApiInformation` methods that take a name and return whether it's present.
+fn check<F>(name: &str, f: F) -> bool
+where
+    F: FnOnce(*mut *const IApiInformationStatics_Vtbl, *mut c_void, &mut bool) -> HRESULT,
+{
+    let (statics, name) = match (ApiInformation::get(), HString::new(name)) {
+        (Some(statics), Some(name)) => (statics, name),
+        _ => return false,
+    };
+
+    let mut present = false;
+    f(statics.0, name.0, &mut present) >= 0 && present
+}
+
+// An owned `HSTRING`, since this crate doesn't depend on `windows-strings`.
+struct HString(*mut c_void);
+
+impl HString {
+    fn new(value: &str) -> Option<Self> {
+        let value: Vec<u16> = value.encode_utf16().collect();
+        let mut string = core::ptr::null_mut();
+        let result =
+            unsafe { WindowsCreateString(value.as_ptr(), value.len() as u32, &mut string) };
+
+        if result >= 0 {
+            Some(Self(string))
+        } else {
+            None
+        }
+    }
+}
+
+impl Drop for HString {
+    fn drop(&mut self) {
+        unsafe { WindowsDeleteString(self.0) };
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+struct IApiInformationStatics_Vtbl {
+    QueryInterface: usize,
+    AddRef: usize,
+    Release: unsafe extern "system" fn(*mut c_void) -> u32,
+    GetIids: usize,
+    GetRuntimeClassName: usize,
+    GetTrustLevel: usize,
+    IsTypePresent: unsafe extern "system" fn(*mut c_void, *mut c_void, *mut bool) -> HRESULT,
+    IsMethodPresent: usize,
+    IsMethodPresentWithArity: usize,
+    IsEventPresent: usize,
+    IsPropertyPresent: usize,
+    IsReadOnlyPropertyPresent: usize,
+    IsWriteablePropertyPresent: usize,
+    IsEnumNamedValuePresent: usize,
+    IsApiContractPresentByMajor: usize,
+    IsApiContractPresentByMajorAndMinor:
+        unsafe extern "system" fn(*mut c_void, *mut c_void, u16, u16, *mut bool) -> HRESULT,
+}
+
+// The activation factory of `Windows.Foundation.Metadata.ApiInformation`.
+struct ApiInformation(*mut *const IApiInformationStatics_Vtbl);
+
+impl ApiInformation {
+    const IID: GUID = GUID::from_u128(0x997439fe_f681_4a11_b416_c13a47e8ba36);
+
+    fn get() -> Option<Self> {
+        let name = HString::new("Windows.Foundation.Metadata.ApiInformation")?;
+        let mut factory = core::ptr::null_mut();
+        let mut get = || unsafe { RoGetActivationFactory(name.0, &Self::IID, &mut factory) };
+        let mut result = get();
+
+        // Like `windows-core`, make sure the calling thread is in an apartment so that the check "just works".
+        if result == CO_E_NOTINITIALIZED {
+            let mut cookie = core::ptr::null_mut();
+            unsafe { CoIncrementMTAUsage(&mut cookie) };
+            result = get();
+        }
+
+        if result >= 0 && !factory.is_null() {
+            Some(Self(factory as *mut _))
+        } else {
+            None
+        }
+    }
+}
+
+impl Drop for ApiInformation {
+    fn drop(&mut self) {
+        unsafe { ((**self.0).Release)(self.0 as *mut _) };
+    }
+}
@@ -5,10 +5,32 @@ Learn more about Rust for Windows here: <https://github.com/microsoft/windows-rs
 #![cfg(windows)]
 #![cfg_attr(not(test), no_std)]
 
+extern crate alloc;
+
 mod bindings;
+use alloc::string::String;
 use bindings::*;
 
+mod contract;
+pub use contract::*;
+
+// The registry key that holds the version details that aren't provided by `RtlGetVersion`.
+const CURRENT_VERSION: &str = r"SOFTWARE\Microsoft\Windows NT\CurrentVersion";
+
 /// Operating system version information.
+///
+/// Versions are ordered by their major, minor, service pack, and build numbers, in that order, so that
+/// a version may be compared against a minimum version:
+///
+/// ```rust,no_run
+/// use windows_version::*;
+///
+/// if OsVersion::current() >= OsVersion::new(10, 0, 0, 19041) {
+///     println!("Running on Windows 10 version 2004 or later.");
+/// }
+/// ```
+///
+/// Windows 11 reports the same major and minor version as Windows 10, so use the build number to tell them apart.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct OsVersion {
     /// The major version number of the operating system.
@@ -36,22 +58,19 @@ impl OsVersion {
     }
 
     /// Gets the version information of the currently running operating system.
+    ///
+    /// The version is read directly from the kernel using `RtlGetVersion`, so it is accurate regardless of the
+    /// application manifest. By contrast, `GetVersionEx` and the version helper functions report Windows 8 to
+    /// applications that don't declare their compatibility with later versions in their manifest.
     #[cfg(not(test))]
     pub fn current() -> Self {
-        unsafe {
-            let mut info = OSVERSIONINFOEXW {
-                dwOSVersionInfoSize: core::mem::size_of::<OSVERSIONINFOEXW>() as u32,
-                ..core::mem::zeroed()
-            };
+        let info = version_info();
 
-            RtlGetVersion(&mut info as *mut _ as *mut _);
-
-            OsVersion {
-                major: info.dwMajorVersion,
-                minor: info.dwMinorVersion,
-                pack: info.wServicePackMajor as u32,
-                build: info.dwBuildNumber,
-            }
+        OsVersion {
+            major: info.dwMajorVersion,
+            minor: info.dwMinorVersion,
+            pack: info.wServicePackMajor as u32,
+            build: info.dwBuildNumber,
         }
     }
 
@@ -60,10 +79,150 @@ impl OsVersion {
     fn current() -> Self {
         test::test_current()
     }
+
+    /// Determines if the currently running operating system is the given version or later, ignoring the service pack.
+    ///
+    /// Like `current`, this is accurate regardless of the application manifest.
+    ///
+    /// ```rust,no_run
+    /// use windows_version::*;
+    ///
+    /// if OsVersion::at_least(10, 0, 22000) {
+    ///     println!("Running on Windows 11 or later.");
+    /// }
+    /// ```
+    pub fn at_least(major: u32, minor: u32, build: u32) -> bool {
+        let current = Self::current();
+        (current.major, current.minor, current.build) >= (major, minor, build)
+    }
+}
+
+/// The product type of an operating system, which distinguishes workstation and server releases.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ProductType {
+    /// A workstation release, such as Windows 11 Pro.
+    Workstation,
+
+    /// A server release that is a domain controller.
+    DomainController,
+
+    /// A server release that isn't a domain controller.
+    Server,
+}
+
+impl ProductType {
+    /// Gets the product type of the currently running operating system.
+    pub fn current() -> Self {
+        match version_info().wProductType as u32 {
+            VER_NT_DOMAIN_CONTROLLER => Self::DomainController,
+            VER_NT_SERVER => Self::Server,
+            _ => Self::Workstation,
+        }
+    }
 }
 
 /// Determines if the currently running operating system is a Windows Server release.
 pub fn is_server() -> bool {
+    ProductType::current() != ProductType::Workstation
+}
+
+/// The edition, or SKU, of an operating system, such as Pro or Enterprise.
+///
+/// The value is one of the `PRODUCT_*` constants returned by `GetProductInfo`, of which the common
+/// editions are provided as associated constants:
+///
+/// ```rust,no_run
+/// use windows_version::*;
+///
+/// let edition = Edition::current();
+///
+/// if edition.is_multi_session() {
+///     println!("Other users may be signed in to this computer.");
+/// } else if edition == Edition::PROFESSIONAL {
+///     println!("Running on Windows Pro.");
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Edition(pub u32);
+
+impl Edition {
+    /// The edition is unknown.
+    pub const UNDEFINED: Self = Self(0x00);
+
+    /// Enterprise.
+    pub const ENTERPRISE: Self = Self(0x04);
+
+    /// Server Standard.
+    pub const STANDARD_SERVER: Self = Self(0x07);
+
+    /// Server Datacenter.
+    pub const DATACENTER_SERVER: Self = Self(0x08);
+
+    /// Pro.
+    pub const PROFESSIONAL: Self = Self(0x30);
+
+    /// Home.
+    pub const HOME: Self = Self(0x65);
+
+    /// Education.
+    pub const EDUCATION: Self = Self(0x79);
+
+    /// IoT Core.
+    pub const IOT_CORE: Self = Self(0x7B);
+
+    /// Enterprise LTSC.
+    pub const ENTERPRISE_LTSC: Self = Self(0x7D);
+
+    /// IoT Core Commercial.
+    pub const IOT_CORE_COMMERCIAL: Self = Self(0x83);
+
+    /// Enterprise multi-session, formerly Enterprise for Virtual Desktops.
+    pub const ENTERPRISE_MULTI_SESSION: Self = Self(0xAF);
+
+    /// IoT Enterprise.
+    pub const IOT_ENTERPRISE: Self = Self(0xBC);
+
+    /// IoT Enterprise LTSC.
+    pub const IOT_ENTERPRISE_LTSC: Self = Self(0xBF);
+
+    /// Gets the edition of the currently running operating system.
+    pub fn current() -> Self {
+        let info = version_info();
+        let mut edition = 0;
+
+        unsafe {
+            GetProductInfo(
+                info.dwMajorVersion,
+                info.dwMinorVersion,
+                info.wServicePackMajor as u32,
+                info.wServicePackMinor as u32,
+                &mut edition,
+            )
+        };
+
+        Self(edition)
+    }
+
+    /// Determines if the edition is one of the IoT editions.
+    pub fn is_iot(self) -> bool {
+        matches!(
+            self,
+            Self::IOT_CORE
+                | Self::IOT_CORE_COMMERCIAL
+                | Self::IOT_ENTERPRISE
+                | Self::IOT_ENTERPRISE_LTSC
+        )
+    }
+
+    /// Determines if the edition is Enterprise multi-session, which is a workstation release that allows
+    /// multiple interactive sessions like a server release does.
+    pub fn is_multi_session(self) -> bool {
+        self == Self::ENTERPRISE_MULTI_SESSION
+    }
+}
+
+// Gets the version information directly from the kernel, which doesn't depend on the application manifest.
+fn version_info() -> OSVERSIONINFOEXW {
     unsafe {
         let mut info = OSVERSIONINFOEXW {
             dwOSVersionInfoSize: core::mem::size_of::<OSVERSIONINFOEXW>() as u32,
@@ -71,13 +230,97 @@ pub fn is_server() -> bool {
         };
 
         RtlGetVersion(&mut info as *mut _ as *mut _);
-        info.wProductType as u32 != VER_NT_WORKSTATION
+        info
+    }
+}
+
+/// Gets the update build revision (UBR) of the currently running operating system, such as `3155` for build `22631.3155`.
+///
+/// The revision is incremented by monthly updates, whereas `OsVersion::build` only changes with feature updates.
+/// Returns zero if the revision isn't available.
+pub fn revision() -> u32 {
+    let mut revision = 0u32;
+
+    unsafe {
+        read_value(
+            "UBR",
+            RRF_RT_REG_DWORD,
+            &mut revision as *mut _ as *mut _,
+            core::mem::size_of::<u32>() as u32,
+        )
+    };
+
+    revision
+}
+
+/// Gets the display version of the currently running operating system, such as `23H2`.
+///
+/// Returns `None` if the display version isn't available, as is the case before Windows 10 version 1511.
+pub fn display_version() -> Option<String> {
+    // `DisplayVersion` replaced `ReleaseId` in Windows 10 version 20H2, which froze `ReleaseId` at `2009`.
+    ["DisplayVersion", "ReleaseId"]
+        .into_iter()
+        .find_map(|name| {
+            let mut buffer = [0u16; 64];
+
+            let len = unsafe {
+                read_value(
+                    name,
+                    RRF_RT_REG_SZ,
+                    buffer.as_mut_ptr() as *mut _,
+                    core::mem::size_of_val(&buffer) as u32,
+                )?
+            };
+
+            let value = &buffer[..len as usize / 2];
+            let len = value.iter().position(|c| *c == 0).unwrap_or(value.len());
+
+            if len == 0 {
+                None
+            } else {
+                Some(String::from_utf16_lossy(&value[..len]))
+            }
+        })
+}
+
+// Encodes a short string as a null-terminated wide string.
+fn wide(value: &str) -> [u16; 64] {
+    let mut wide = [0; 64];
+
+    for (dest, src) in wide[..63].iter_mut().zip(value.encode_utf16()) {
+        *dest = src;
+    }
+
+    wide
+}
+
+// Reads a value from the `CurrentVersion` key, returning the number of bytes written.
+unsafe fn read_value(
+    name: &str,
+    flags: REG_ROUTINE_FLAGS,
+    data: *mut core::ffi::c_void,
+    mut len: u32,
+) -> Option<u32> {
+    let result = RegGetValueW(
+        HKEY_LOCAL_MACHINE,
+        wide(CURRENT_VERSION).as_ptr(),
+        wide(name).as_ptr(),
+        flags,
+        core::ptr::null_mut(),
+        data,
+        &mut len,
+    );
+
+    if result == 0 {
+        Some(len)
+    } else {
+        None
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::OsVersion;
+    use super::*;
     use std::sync::RwLock;
 
     static TEST_CURRENT: RwLock<OsVersion> = RwLock::new(OsVersion::new(0, 0, 0, 0));
@@ -116,5 +359,46 @@ mod test {
         assert!(OsVersion::current() >= OsVersion::new(10, 100, 1_000, 9_999));
         assert!(OsVersion::current() >= OsVersion::new(10, 100, 1_000, 10_000));
         assert!(!(OsVersion::current() >= OsVersion::new(10, 100, 1_000, 10_001)));
+
+        set_current(OsVersion::new(10, 0, 0, 19041));
+        assert!(OsVersion::at_least(6, 3, 9600));
+        assert!(OsVersion::at_least(10, 0, 19041));
+        assert!(!OsVersion::at_least(10, 0, 22000));
+        assert!(!OsVersion::at_least(10, 1, 0));
+        assert!(!OsVersion::at_least(11, 0, 0));
+
+        set_current(OsVersion::new(6, 1, 1, 7601));
+        assert!(OsVersion::at_least(6, 1, 7600));
+        assert!(!OsVersion::at_least(6, 2, 0));
+    }
+
+    #[test]
+    fn registry() {
+        // Every supported release has a display version and a revision.
+        let display_version = display_version().unwrap();
+        assert!(display_version.len() == 4, "{display_version}");
+        assert!(revision() > 0);
+    }
+
+    #[test]
+    fn edition() {
+        assert_ne!(Edition::current(), Edition::UNDEFINED);
+        assert!(!Edition(0x04).is_iot());
+        assert!(Edition(0xBC).is_iot());
+        assert!(Edition(0xAF).is_multi_session());
+
+        let product_type = ProductType::current();
+        assert_eq!(is_server(), product_type != ProductType::Workstation);
+    }
+
+    #[test]
+    fn contract() {
+        assert!(ApiContract::UNIVERSAL_V1.is_present());
+        assert!(ApiContract::new(FOUNDATION_CONTRACT, 1, 0).is_present());
+        assert!(!ApiContract::new(UNIVERSAL_API_CONTRACT, 1000, 0).is_present());
+        assert!(!ApiContract::new("Windows.NotAContract", 1, 0).is_present());
+
+        assert!(is_type_present("Windows.Foundation.Uri"));
+        assert!(!is_type_present("Windows.Foundation.NotAType"));
     }
 }
@@ -3485,6 +3485,7 @@ impl LearningModelDeviceKind {
 impl windows_core::TypeKind for LearningModelDeviceKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LearningModelDeviceKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LearningModelDeviceKind").field(&self.0).finish()
@@ -3505,6 +3506,7 @@ impl LearningModelFeatureKind {
 impl windows_core::TypeKind for LearningModelFeatureKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LearningModelFeatureKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LearningModelFeatureKind").field(&self.0).finish()
@@ -3524,6 +3526,7 @@ impl LearningModelPixelRange {
 impl windows_core::TypeKind for LearningModelPixelRange {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LearningModelPixelRange {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LearningModelPixelRange").field(&self.0).finish()
@@ -3556,6 +3559,7 @@ impl TensorKind {
 impl windows_core::TypeKind for TensorKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TensorKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TensorKind").field(&self.0).finish()
@@ -3566,3 +3570,5 @@ impl windows_core::RuntimeType for TensorKind {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
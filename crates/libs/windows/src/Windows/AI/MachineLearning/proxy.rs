@@ -0,0 +1,1660 @@
+#[cfg(feature = "Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for IImageFeatureDescriptor_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn BitmapPixelFormat(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Imaging::BitmapPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageFeatureDescriptor", "BitmapPixelFormat", 6usize, |this| ((**(this as *const *const IImageFeatureDescriptor_Vtbl)).BitmapPixelFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn BitmapAlphaMode(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Imaging::BitmapAlphaMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageFeatureDescriptor", "BitmapAlphaMode", 7usize, |this| ((**(this as *const *const IImageFeatureDescriptor_Vtbl)).BitmapAlphaMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageFeatureDescriptor", "Width", 8usize, |this| ((**(this as *const *const IImageFeatureDescriptor_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageFeatureDescriptor", "Height", 9usize, |this| ((**(this as *const *const IImageFeatureDescriptor_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Imaging")]
+            BitmapPixelFormat,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            BitmapPixelFormat: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            BitmapAlphaMode,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            BitmapAlphaMode: 0,
+            Width,
+            Height,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageFeatureDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImageFeatureDescriptor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PixelRange(this: *mut core::ffi::c_void, result__: *mut LearningModelPixelRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageFeatureDescriptor2", "PixelRange", 6usize, |this| ((**(this as *const *const IImageFeatureDescriptor2_Vtbl)).PixelRange)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PixelRange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageFeatureDescriptor2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media")]
+impl windows_core::imp::ProxyVtable for IImageFeatureValue_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media")]
+        unsafe extern "system" fn VideoFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageFeatureValue", "VideoFrame", 6usize, |this| ((**(this as *const *const IImageFeatureValue_Vtbl)).VideoFrame)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media")]
+            VideoFrame,
+            #[cfg(not(feature = "Media"))]
+            VideoFrame: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageFeatureValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media")]
+impl windows_core::imp::ProxyVtable for IImageFeatureValueStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media")]
+        unsafe extern "system" fn CreateFromVideoFrame(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageFeatureValueStatics", "CreateFromVideoFrame", 6usize, |this| ((**(this as *const *const IImageFeatureValueStatics_Vtbl)).CreateFromVideoFrame)(this, image, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media")]
+            CreateFromVideoFrame,
+            #[cfg(not(feature = "Media"))]
+            CreateFromVideoFrame: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageFeatureValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ILearningModel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Author(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModel", "Author", 6usize, |this| ((**(this as *const *const ILearningModel_Vtbl)).Author)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModel", "Name", 7usize, |this| ((**(this as *const *const ILearningModel_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Domain(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModel", "Domain", 8usize, |this| ((**(this as *const *const ILearningModel_Vtbl)).Domain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModel", "Description", 9usize, |this| ((**(this as *const *const ILearningModel_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModel", "Version", 10usize, |this| ((**(this as *const *const ILearningModel_Vtbl)).Version)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Metadata(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModel", "Metadata", 11usize, |this| ((**(this as *const *const ILearningModel_Vtbl)).Metadata)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn InputFeatures(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModel", "InputFeatures", 12usize, |this| ((**(this as *const *const ILearningModel_Vtbl)).InputFeatures)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn OutputFeatures(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModel", "OutputFeatures", 13usize, |this| ((**(this as *const *const ILearningModel_Vtbl)).OutputFeatures)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Author,
+            Name,
+            Domain,
+            Description,
+            Version,
+            #[cfg(feature = "Foundation_Collections")]
+            Metadata,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Metadata: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            InputFeatures,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            InputFeatures: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            OutputFeatures,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            OutputFeatures: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModel as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ILearningModelBinding_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Bind(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelBinding", "Bind", 6usize, |this| ((**(this as *const *const ILearningModelBinding_Vtbl)).Bind)(this, name, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BindWithProperties(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: *mut core::ffi::c_void, props: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelBinding", "BindWithProperties", 7usize, |this| ((**(this as *const *const ILearningModelBinding_Vtbl)).BindWithProperties)(this, name, value, props), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelBinding", "Clear", 8usize, |this| ((**(this as *const *const ILearningModelBinding_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Bind,
+            #[cfg(feature = "Foundation_Collections")]
+            BindWithProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BindWithProperties: 0,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelBinding as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILearningModelBindingFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromSession(this: *mut core::ffi::c_void, session: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelBindingFactory", "CreateFromSession", 6usize, |this| ((**(this as *const *const ILearningModelBindingFactory_Vtbl)).CreateFromSession)(this, session, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelBindingFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX_Direct3D11")]
+impl windows_core::imp::ProxyVtable for ILearningModelDevice_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn AdapterId(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::DisplayAdapterId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelDevice", "AdapterId", 6usize, |this| ((**(this as *const *const ILearningModelDevice_Vtbl)).AdapterId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn Direct3D11Device(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelDevice", "Direct3D11Device", 7usize, |this| ((**(this as *const *const ILearningModelDevice_Vtbl)).Direct3D11Device)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics")]
+            AdapterId,
+            #[cfg(not(feature = "Graphics"))]
+            AdapterId: 0,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            Direct3D11Device,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            Direct3D11Device: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILearningModelDeviceFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, devicekind: LearningModelDeviceKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelDeviceFactory", "Create", 6usize, |this| ((**(this as *const *const ILearningModelDeviceFactory_Vtbl)).Create)(this, devicekind, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelDeviceFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX_Direct3D11")]
+impl windows_core::imp::ProxyVtable for ILearningModelDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn CreateFromDirect3D11Device(this: *mut core::ffi::c_void, device: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelDeviceStatics", "CreateFromDirect3D11Device", 6usize, |this| ((**(this as *const *const ILearningModelDeviceStatics_Vtbl)).CreateFromDirect3D11Device)(this, device, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            CreateFromDirect3D11Device,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            CreateFromDirect3D11Device: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ILearningModelEvaluationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CorrelationId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelEvaluationResult", "CorrelationId", 6usize, |this| ((**(this as *const *const ILearningModelEvaluationResult_Vtbl)).CorrelationId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorStatus(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelEvaluationResult", "ErrorStatus", 7usize, |this| ((**(this as *const *const ILearningModelEvaluationResult_Vtbl)).ErrorStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Succeeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelEvaluationResult", "Succeeded", 8usize, |this| ((**(this as *const *const ILearningModelEvaluationResult_Vtbl)).Succeeded)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Outputs(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelEvaluationResult", "Outputs", 9usize, |this| ((**(this as *const *const ILearningModelEvaluationResult_Vtbl)).Outputs)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CorrelationId,
+            ErrorStatus,
+            Succeeded,
+            #[cfg(feature = "Foundation_Collections")]
+            Outputs,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Outputs: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelEvaluationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILearningModelFeatureDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelFeatureDescriptor", "Name", 6usize, |this| ((**(this as *const *const ILearningModelFeatureDescriptor_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelFeatureDescriptor", "Description", 7usize, |this| ((**(this as *const *const ILearningModelFeatureDescriptor_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut LearningModelFeatureKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelFeatureDescriptor", "Kind", 8usize, |this| ((**(this as *const *const ILearningModelFeatureDescriptor_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRequired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelFeatureDescriptor", "IsRequired", 9usize, |this| ((**(this as *const *const ILearningModelFeatureDescriptor_Vtbl)).IsRequired)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, Description, Kind, IsRequired }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelFeatureDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILearningModelFeatureValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut LearningModelFeatureKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelFeatureValue", "Kind", 6usize, |this| ((**(this as *const *const ILearningModelFeatureValue_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelFeatureValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILearningModelOperatorProvider_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelOperatorProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ILearningModelSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Model(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSession", "Model", 6usize, |this| ((**(this as *const *const ILearningModelSession_Vtbl)).Model)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Device(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSession", "Device", 7usize, |this| ((**(this as *const *const ILearningModelSession_Vtbl)).Device)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EvaluationProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSession", "EvaluationProperties", 8usize, |this| ((**(this as *const *const ILearningModelSession_Vtbl)).EvaluationProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EvaluateAsync(this: *mut core::ffi::c_void, bindings: *mut core::ffi::c_void, correlationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSession", "EvaluateAsync", 9usize, |this| ((**(this as *const *const ILearningModelSession_Vtbl)).EvaluateAsync)(this, bindings, correlationid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EvaluateFeaturesAsync(this: *mut core::ffi::c_void, features: *mut core::ffi::c_void, correlationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSession", "EvaluateFeaturesAsync", 10usize, |this| ((**(this as *const *const ILearningModelSession_Vtbl)).EvaluateFeaturesAsync)(this, features, correlationid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Evaluate(this: *mut core::ffi::c_void, bindings: *mut core::ffi::c_void, correlationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSession", "Evaluate", 11usize, |this| ((**(this as *const *const ILearningModelSession_Vtbl)).Evaluate)(this, bindings, correlationid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EvaluateFeatures(this: *mut core::ffi::c_void, features: *mut core::ffi::c_void, correlationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSession", "EvaluateFeatures", 12usize, |this| ((**(this as *const *const ILearningModelSession_Vtbl)).EvaluateFeatures)(this, features, correlationid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Model,
+            Device,
+            #[cfg(feature = "Foundation_Collections")]
+            EvaluationProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EvaluationProperties: 0,
+            EvaluateAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            EvaluateFeaturesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EvaluateFeaturesAsync: 0,
+            Evaluate,
+            #[cfg(feature = "Foundation_Collections")]
+            EvaluateFeatures,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EvaluateFeatures: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILearningModelSessionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromModel(this: *mut core::ffi::c_void, model: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSessionFactory", "CreateFromModel", 6usize, |this| ((**(this as *const *const ILearningModelSessionFactory_Vtbl)).CreateFromModel)(this, model, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromModelOnDevice(this: *mut core::ffi::c_void, model: *mut core::ffi::c_void, devicetorunon: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSessionFactory", "CreateFromModelOnDevice", 7usize, |this| ((**(this as *const *const ILearningModelSessionFactory_Vtbl)).CreateFromModelOnDevice)(this, model, devicetorunon, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromModel, CreateFromModelOnDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelSessionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILearningModelSessionFactory2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromModelOnDeviceWithSessionOptions(this: *mut core::ffi::c_void, model: *mut core::ffi::c_void, devicetorunon: *mut core::ffi::c_void, learningmodelsessionoptions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSessionFactory2", "CreateFromModelOnDeviceWithSessionOptions", 6usize, |this| ((**(this as *const *const ILearningModelSessionFactory2_Vtbl)).CreateFromModelOnDeviceWithSessionOptions)(this, model, devicetorunon, learningmodelsessionoptions, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromModelOnDeviceWithSessionOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelSessionFactory2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILearningModelSessionOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BatchSizeOverride(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSessionOptions", "BatchSizeOverride", 6usize, |this| ((**(this as *const *const ILearningModelSessionOptions_Vtbl)).BatchSizeOverride)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBatchSizeOverride(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSessionOptions", "SetBatchSizeOverride", 7usize, |this| ((**(this as *const *const ILearningModelSessionOptions_Vtbl)).SetBatchSizeOverride)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BatchSizeOverride, SetBatchSizeOverride }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelSessionOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILearningModelSessionOptions2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CloseModelOnSessionCreation(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSessionOptions2", "CloseModelOnSessionCreation", 6usize, |this| ((**(this as *const *const ILearningModelSessionOptions2_Vtbl)).CloseModelOnSessionCreation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCloseModelOnSessionCreation(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSessionOptions2", "SetCloseModelOnSessionCreation", 7usize, |this| ((**(this as *const *const ILearningModelSessionOptions2_Vtbl)).SetCloseModelOnSessionCreation)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CloseModelOnSessionCreation, SetCloseModelOnSessionCreation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelSessionOptions2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILearningModelSessionOptions3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OverrideNamedDimension(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, dimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSessionOptions3", "OverrideNamedDimension", 6usize, |this| ((**(this as *const *const ILearningModelSessionOptions3_Vtbl)).OverrideNamedDimension)(this, name, dimension), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OverrideNamedDimension }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelSessionOptions3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ILearningModelStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LoadFromStorageFileAsync(this: *mut core::ffi::c_void, modelfile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelStatics", "LoadFromStorageFileAsync", 6usize, |this| ((**(this as *const *const ILearningModelStatics_Vtbl)).LoadFromStorageFileAsync)(this, modelfile, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn LoadFromStreamAsync(this: *mut core::ffi::c_void, modelstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelStatics", "LoadFromStreamAsync", 7usize, |this| ((**(this as *const *const ILearningModelStatics_Vtbl)).LoadFromStreamAsync)(this, modelstream, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadFromFilePath(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelStatics", "LoadFromFilePath", 8usize, |this| ((**(this as *const *const ILearningModelStatics_Vtbl)).LoadFromFilePath)(this, filepath, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn LoadFromStream(this: *mut core::ffi::c_void, modelstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelStatics", "LoadFromStream", 9usize, |this| ((**(this as *const *const ILearningModelStatics_Vtbl)).LoadFromStream)(this, modelstream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LoadFromStorageFileWithOperatorProviderAsync(this: *mut core::ffi::c_void, modelfile: *mut core::ffi::c_void, operatorprovider: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelStatics", "LoadFromStorageFileWithOperatorProviderAsync", 10usize, |this| ((**(this as *const *const ILearningModelStatics_Vtbl)).LoadFromStorageFileWithOperatorProviderAsync)(this, modelfile, operatorprovider, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn LoadFromStreamWithOperatorProviderAsync(this: *mut core::ffi::c_void, modelstream: *mut core::ffi::c_void, operatorprovider: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelStatics", "LoadFromStreamWithOperatorProviderAsync", 11usize, |this| ((**(this as *const *const ILearningModelStatics_Vtbl)).LoadFromStreamWithOperatorProviderAsync)(this, modelstream, operatorprovider, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadFromFilePathWithOperatorProvider(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::HSTRING>, operatorprovider: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelStatics", "LoadFromFilePathWithOperatorProvider", 12usize, |this| ((**(this as *const *const ILearningModelStatics_Vtbl)).LoadFromFilePathWithOperatorProvider)(this, filepath, operatorprovider, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn LoadFromStreamWithOperatorProvider(this: *mut core::ffi::c_void, modelstream: *mut core::ffi::c_void, operatorprovider: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelStatics", "LoadFromStreamWithOperatorProvider", 13usize, |this| ((**(this as *const *const ILearningModelStatics_Vtbl)).LoadFromStreamWithOperatorProvider)(this, modelstream, operatorprovider, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            LoadFromStorageFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            LoadFromStorageFileAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            LoadFromStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            LoadFromStreamAsync: 0,
+            LoadFromFilePath,
+            #[cfg(feature = "Storage_Streams")]
+            LoadFromStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            LoadFromStream: 0,
+            #[cfg(feature = "Storage")]
+            LoadFromStorageFileWithOperatorProviderAsync,
+            #[cfg(not(feature = "Storage"))]
+            LoadFromStorageFileWithOperatorProviderAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            LoadFromStreamWithOperatorProviderAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            LoadFromStreamWithOperatorProviderAsync: 0,
+            LoadFromFilePathWithOperatorProvider,
+            #[cfg(feature = "Storage_Streams")]
+            LoadFromStreamWithOperatorProvider,
+            #[cfg(not(feature = "Storage_Streams"))]
+            LoadFromStreamWithOperatorProvider: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapFeatureDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KeyKind(this: *mut core::ffi::c_void, result__: *mut TensorKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapFeatureDescriptor", "KeyKind", 6usize, |this| ((**(this as *const *const IMapFeatureDescriptor_Vtbl)).KeyKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValueDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapFeatureDescriptor", "ValueDescriptor", 7usize, |this| ((**(this as *const *const IMapFeatureDescriptor_Vtbl)).ValueDescriptor)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KeyKind, ValueDescriptor }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapFeatureDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISequenceFeatureDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ElementDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISequenceFeatureDescriptor", "ElementDescriptor", 6usize, |this| ((**(this as *const *const ISequenceFeatureDescriptor_Vtbl)).ElementDescriptor)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ElementDescriptor }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISequenceFeatureDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TensorKind(this: *mut core::ffi::c_void, result__: *mut TensorKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensor", "TensorKind", 6usize, |this| ((**(this as *const *const ITensor_Vtbl)).TensorKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Shape(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensor", "Shape", 7usize, |this| ((**(this as *const *const ITensor_Vtbl)).Shape)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TensorKind,
+            #[cfg(feature = "Foundation_Collections")]
+            Shape,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Shape: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorBoolean_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAsVectorView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorBoolean", "GetAsVectorView", 6usize, |this| ((**(this as *const *const ITensorBoolean_Vtbl)).GetAsVectorView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAsVectorView,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAsVectorView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorBoolean as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorBooleanStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorBooleanStatics", "Create", 6usize, |this| ((**(this as *const *const ITensorBooleanStatics_Vtbl)).Create)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create2(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorBooleanStatics", "Create2", 7usize, |this| ((**(this as *const *const ITensorBooleanStatics_Vtbl)).Create2)(this, shape, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromArray(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data_array_size: u32, data: *const bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorBooleanStatics", "CreateFromArray", 8usize, |this| ((**(this as *const *const ITensorBooleanStatics_Vtbl)).CreateFromArray)(this, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIterable(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorBooleanStatics", "CreateFromIterable", 9usize, |this| ((**(this as *const *const ITensorBooleanStatics_Vtbl)).CreateFromIterable)(this, shape, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            Create2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create2: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIterable,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIterable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorBooleanStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITensorBooleanStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromShapeArrayAndDataArray(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, data_array_size: u32, data: *const bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorBooleanStatics2", "CreateFromShapeArrayAndDataArray", 6usize, |this| ((**(this as *const *const ITensorBooleanStatics2_Vtbl)).CreateFromShapeArrayAndDataArray)(this, shape_array_size, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromBuffer(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorBooleanStatics2", "CreateFromBuffer", 7usize, |this| ((**(this as *const *const ITensorBooleanStatics2_Vtbl)).CreateFromBuffer)(this, shape_array_size, shape, buffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromShapeArrayAndDataArray,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorBooleanStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorDouble_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAsVectorView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorDouble", "GetAsVectorView", 6usize, |this| ((**(this as *const *const ITensorDouble_Vtbl)).GetAsVectorView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAsVectorView,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAsVectorView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorDouble as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorDoubleStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorDoubleStatics", "Create", 6usize, |this| ((**(this as *const *const ITensorDoubleStatics_Vtbl)).Create)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create2(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorDoubleStatics", "Create2", 7usize, |this| ((**(this as *const *const ITensorDoubleStatics_Vtbl)).Create2)(this, shape, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromArray(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data_array_size: u32, data: *const f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorDoubleStatics", "CreateFromArray", 8usize, |this| ((**(this as *const *const ITensorDoubleStatics_Vtbl)).CreateFromArray)(this, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIterable(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorDoubleStatics", "CreateFromIterable", 9usize, |this| ((**(this as *const *const ITensorDoubleStatics_Vtbl)).CreateFromIterable)(this, shape, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            Create2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create2: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIterable,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIterable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorDoubleStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITensorDoubleStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromShapeArrayAndDataArray(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, data_array_size: u32, data: *const f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorDoubleStatics2", "CreateFromShapeArrayAndDataArray", 6usize, |this| ((**(this as *const *const ITensorDoubleStatics2_Vtbl)).CreateFromShapeArrayAndDataArray)(this, shape_array_size, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromBuffer(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorDoubleStatics2", "CreateFromBuffer", 7usize, |this| ((**(this as *const *const ITensorDoubleStatics2_Vtbl)).CreateFromBuffer)(this, shape_array_size, shape, buffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromShapeArrayAndDataArray,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorDoubleStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorFeatureDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TensorKind(this: *mut core::ffi::c_void, result__: *mut TensorKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFeatureDescriptor", "TensorKind", 6usize, |this| ((**(this as *const *const ITensorFeatureDescriptor_Vtbl)).TensorKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Shape(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFeatureDescriptor", "Shape", 7usize, |this| ((**(this as *const *const ITensorFeatureDescriptor_Vtbl)).Shape)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TensorKind,
+            #[cfg(feature = "Foundation_Collections")]
+            Shape,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Shape: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorFeatureDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorFloat_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAsVectorView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFloat", "GetAsVectorView", 6usize, |this| ((**(this as *const *const ITensorFloat_Vtbl)).GetAsVectorView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAsVectorView,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAsVectorView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorFloat as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorFloat16Bit_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAsVectorView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFloat16Bit", "GetAsVectorView", 6usize, |this| ((**(this as *const *const ITensorFloat16Bit_Vtbl)).GetAsVectorView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAsVectorView,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAsVectorView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorFloat16Bit as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorFloat16BitStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFloat16BitStatics", "Create", 6usize, |this| ((**(this as *const *const ITensorFloat16BitStatics_Vtbl)).Create)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create2(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFloat16BitStatics", "Create2", 7usize, |this| ((**(this as *const *const ITensorFloat16BitStatics_Vtbl)).Create2)(this, shape, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromArray(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data_array_size: u32, data: *const f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFloat16BitStatics", "CreateFromArray", 8usize, |this| ((**(this as *const *const ITensorFloat16BitStatics_Vtbl)).CreateFromArray)(this, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIterable(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFloat16BitStatics", "CreateFromIterable", 9usize, |this| ((**(this as *const *const ITensorFloat16BitStatics_Vtbl)).CreateFromIterable)(this, shape, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            Create2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create2: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIterable,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIterable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorFloat16BitStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITensorFloat16BitStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromShapeArrayAndDataArray(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, data_array_size: u32, data: *const f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFloat16BitStatics2", "CreateFromShapeArrayAndDataArray", 6usize, |this| ((**(this as *const *const ITensorFloat16BitStatics2_Vtbl)).CreateFromShapeArrayAndDataArray)(this, shape_array_size, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromBuffer(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFloat16BitStatics2", "CreateFromBuffer", 7usize, |this| ((**(this as *const *const ITensorFloat16BitStatics2_Vtbl)).CreateFromBuffer)(this, shape_array_size, shape, buffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromShapeArrayAndDataArray,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorFloat16BitStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorFloatStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFloatStatics", "Create", 6usize, |this| ((**(this as *const *const ITensorFloatStatics_Vtbl)).Create)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create2(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFloatStatics", "Create2", 7usize, |this| ((**(this as *const *const ITensorFloatStatics_Vtbl)).Create2)(this, shape, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromArray(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data_array_size: u32, data: *const f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFloatStatics", "CreateFromArray", 8usize, |this| ((**(this as *const *const ITensorFloatStatics_Vtbl)).CreateFromArray)(this, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIterable(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFloatStatics", "CreateFromIterable", 9usize, |this| ((**(this as *const *const ITensorFloatStatics_Vtbl)).CreateFromIterable)(this, shape, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            Create2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create2: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIterable,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIterable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorFloatStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITensorFloatStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromShapeArrayAndDataArray(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, data_array_size: u32, data: *const f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFloatStatics2", "CreateFromShapeArrayAndDataArray", 6usize, |this| ((**(this as *const *const ITensorFloatStatics2_Vtbl)).CreateFromShapeArrayAndDataArray)(this, shape_array_size, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromBuffer(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorFloatStatics2", "CreateFromBuffer", 7usize, |this| ((**(this as *const *const ITensorFloatStatics2_Vtbl)).CreateFromBuffer)(this, shape_array_size, shape, buffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromShapeArrayAndDataArray,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorFloatStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorInt16Bit_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAsVectorView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt16Bit", "GetAsVectorView", 6usize, |this| ((**(this as *const *const ITensorInt16Bit_Vtbl)).GetAsVectorView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAsVectorView,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAsVectorView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorInt16Bit as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorInt16BitStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt16BitStatics", "Create", 6usize, |this| ((**(this as *const *const ITensorInt16BitStatics_Vtbl)).Create)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create2(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt16BitStatics", "Create2", 7usize, |this| ((**(this as *const *const ITensorInt16BitStatics_Vtbl)).Create2)(this, shape, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromArray(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data_array_size: u32, data: *const i16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt16BitStatics", "CreateFromArray", 8usize, |this| ((**(this as *const *const ITensorInt16BitStatics_Vtbl)).CreateFromArray)(this, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIterable(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt16BitStatics", "CreateFromIterable", 9usize, |this| ((**(this as *const *const ITensorInt16BitStatics_Vtbl)).CreateFromIterable)(this, shape, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            Create2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create2: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIterable,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIterable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorInt16BitStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITensorInt16BitStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromShapeArrayAndDataArray(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, data_array_size: u32, data: *const i16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt16BitStatics2", "CreateFromShapeArrayAndDataArray", 6usize, |this| ((**(this as *const *const ITensorInt16BitStatics2_Vtbl)).CreateFromShapeArrayAndDataArray)(this, shape_array_size, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromBuffer(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt16BitStatics2", "CreateFromBuffer", 7usize, |this| ((**(this as *const *const ITensorInt16BitStatics2_Vtbl)).CreateFromBuffer)(this, shape_array_size, shape, buffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromShapeArrayAndDataArray,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorInt16BitStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorInt32Bit_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAsVectorView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt32Bit", "GetAsVectorView", 6usize, |this| ((**(this as *const *const ITensorInt32Bit_Vtbl)).GetAsVectorView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAsVectorView,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAsVectorView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorInt32Bit as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorInt32BitStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt32BitStatics", "Create", 6usize, |this| ((**(this as *const *const ITensorInt32BitStatics_Vtbl)).Create)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create2(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt32BitStatics", "Create2", 7usize, |this| ((**(this as *const *const ITensorInt32BitStatics_Vtbl)).Create2)(this, shape, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromArray(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data_array_size: u32, data: *const i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt32BitStatics", "CreateFromArray", 8usize, |this| ((**(this as *const *const ITensorInt32BitStatics_Vtbl)).CreateFromArray)(this, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIterable(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt32BitStatics", "CreateFromIterable", 9usize, |this| ((**(this as *const *const ITensorInt32BitStatics_Vtbl)).CreateFromIterable)(this, shape, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            Create2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create2: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIterable,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIterable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorInt32BitStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITensorInt32BitStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromShapeArrayAndDataArray(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, data_array_size: u32, data: *const i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt32BitStatics2", "CreateFromShapeArrayAndDataArray", 6usize, |this| ((**(this as *const *const ITensorInt32BitStatics2_Vtbl)).CreateFromShapeArrayAndDataArray)(this, shape_array_size, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromBuffer(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt32BitStatics2", "CreateFromBuffer", 7usize, |this| ((**(this as *const *const ITensorInt32BitStatics2_Vtbl)).CreateFromBuffer)(this, shape_array_size, shape, buffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromShapeArrayAndDataArray,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorInt32BitStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorInt64Bit_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAsVectorView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt64Bit", "GetAsVectorView", 6usize, |this| ((**(this as *const *const ITensorInt64Bit_Vtbl)).GetAsVectorView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAsVectorView,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAsVectorView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorInt64Bit as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorInt64BitStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt64BitStatics", "Create", 6usize, |this| ((**(this as *const *const ITensorInt64BitStatics_Vtbl)).Create)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create2(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt64BitStatics", "Create2", 7usize, |this| ((**(this as *const *const ITensorInt64BitStatics_Vtbl)).Create2)(this, shape, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromArray(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data_array_size: u32, data: *const i64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt64BitStatics", "CreateFromArray", 8usize, |this| ((**(this as *const *const ITensorInt64BitStatics_Vtbl)).CreateFromArray)(this, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIterable(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt64BitStatics", "CreateFromIterable", 9usize, |this| ((**(this as *const *const ITensorInt64BitStatics_Vtbl)).CreateFromIterable)(this, shape, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            Create2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create2: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIterable,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIterable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorInt64BitStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITensorInt64BitStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromShapeArrayAndDataArray(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, data_array_size: u32, data: *const i64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt64BitStatics2", "CreateFromShapeArrayAndDataArray", 6usize, |this| ((**(this as *const *const ITensorInt64BitStatics2_Vtbl)).CreateFromShapeArrayAndDataArray)(this, shape_array_size, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromBuffer(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt64BitStatics2", "CreateFromBuffer", 7usize, |this| ((**(this as *const *const ITensorInt64BitStatics2_Vtbl)).CreateFromBuffer)(this, shape_array_size, shape, buffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromShapeArrayAndDataArray,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorInt64BitStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorInt8Bit_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAsVectorView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt8Bit", "GetAsVectorView", 6usize, |this| ((**(this as *const *const ITensorInt8Bit_Vtbl)).GetAsVectorView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAsVectorView,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAsVectorView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorInt8Bit as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorInt8BitStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt8BitStatics", "Create", 6usize, |this| ((**(this as *const *const ITensorInt8BitStatics_Vtbl)).Create)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create2(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt8BitStatics", "Create2", 7usize, |this| ((**(this as *const *const ITensorInt8BitStatics_Vtbl)).Create2)(this, shape, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromArray(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data_array_size: u32, data: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt8BitStatics", "CreateFromArray", 8usize, |this| ((**(this as *const *const ITensorInt8BitStatics_Vtbl)).CreateFromArray)(this, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIterable(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt8BitStatics", "CreateFromIterable", 9usize, |this| ((**(this as *const *const ITensorInt8BitStatics_Vtbl)).CreateFromIterable)(this, shape, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            Create2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create2: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIterable,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIterable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorInt8BitStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITensorInt8BitStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromShapeArrayAndDataArray(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, data_array_size: u32, data: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt8BitStatics2", "CreateFromShapeArrayAndDataArray", 6usize, |this| ((**(this as *const *const ITensorInt8BitStatics2_Vtbl)).CreateFromShapeArrayAndDataArray)(this, shape_array_size, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromBuffer(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorInt8BitStatics2", "CreateFromBuffer", 7usize, |this| ((**(this as *const *const ITensorInt8BitStatics2_Vtbl)).CreateFromBuffer)(this, shape_array_size, shape, buffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromShapeArrayAndDataArray,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorInt8BitStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorString_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAsVectorView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorString", "GetAsVectorView", 6usize, |this| ((**(this as *const *const ITensorString_Vtbl)).GetAsVectorView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAsVectorView,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAsVectorView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorString as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorStringStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorStringStatics", "Create", 6usize, |this| ((**(this as *const *const ITensorStringStatics_Vtbl)).Create)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create2(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorStringStatics", "Create2", 7usize, |this| ((**(this as *const *const ITensorStringStatics_Vtbl)).Create2)(this, shape, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromArray(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data_array_size: u32, data: *const core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorStringStatics", "CreateFromArray", 8usize, |this| ((**(this as *const *const ITensorStringStatics_Vtbl)).CreateFromArray)(this, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIterable(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorStringStatics", "CreateFromIterable", 9usize, |this| ((**(this as *const *const ITensorStringStatics_Vtbl)).CreateFromIterable)(this, shape, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            Create2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create2: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIterable,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIterable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorStringStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITensorStringStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromShapeArrayAndDataArray(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, data_array_size: u32, data: *const core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorStringStatics2", "CreateFromShapeArrayAndDataArray", 6usize, |this| ((**(this as *const *const ITensorStringStatics2_Vtbl)).CreateFromShapeArrayAndDataArray)(this, shape_array_size, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromShapeArrayAndDataArray }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorStringStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorUInt16Bit_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAsVectorView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt16Bit", "GetAsVectorView", 6usize, |this| ((**(this as *const *const ITensorUInt16Bit_Vtbl)).GetAsVectorView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAsVectorView,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAsVectorView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorUInt16Bit as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorUInt16BitStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt16BitStatics", "Create", 6usize, |this| ((**(this as *const *const ITensorUInt16BitStatics_Vtbl)).Create)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create2(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt16BitStatics", "Create2", 7usize, |this| ((**(this as *const *const ITensorUInt16BitStatics_Vtbl)).Create2)(this, shape, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromArray(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data_array_size: u32, data: *const u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt16BitStatics", "CreateFromArray", 8usize, |this| ((**(this as *const *const ITensorUInt16BitStatics_Vtbl)).CreateFromArray)(this, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIterable(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt16BitStatics", "CreateFromIterable", 9usize, |this| ((**(this as *const *const ITensorUInt16BitStatics_Vtbl)).CreateFromIterable)(this, shape, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            Create2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create2: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIterable,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIterable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorUInt16BitStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITensorUInt16BitStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromShapeArrayAndDataArray(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, data_array_size: u32, data: *const u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt16BitStatics2", "CreateFromShapeArrayAndDataArray", 6usize, |this| ((**(this as *const *const ITensorUInt16BitStatics2_Vtbl)).CreateFromShapeArrayAndDataArray)(this, shape_array_size, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromBuffer(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt16BitStatics2", "CreateFromBuffer", 7usize, |this| ((**(this as *const *const ITensorUInt16BitStatics2_Vtbl)).CreateFromBuffer)(this, shape_array_size, shape, buffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromShapeArrayAndDataArray,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorUInt16BitStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorUInt32Bit_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAsVectorView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt32Bit", "GetAsVectorView", 6usize, |this| ((**(this as *const *const ITensorUInt32Bit_Vtbl)).GetAsVectorView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAsVectorView,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAsVectorView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorUInt32Bit as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorUInt32BitStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt32BitStatics", "Create", 6usize, |this| ((**(this as *const *const ITensorUInt32BitStatics_Vtbl)).Create)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create2(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt32BitStatics", "Create2", 7usize, |this| ((**(this as *const *const ITensorUInt32BitStatics_Vtbl)).Create2)(this, shape, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromArray(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data_array_size: u32, data: *const u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt32BitStatics", "CreateFromArray", 8usize, |this| ((**(this as *const *const ITensorUInt32BitStatics_Vtbl)).CreateFromArray)(this, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIterable(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt32BitStatics", "CreateFromIterable", 9usize, |this| ((**(this as *const *const ITensorUInt32BitStatics_Vtbl)).CreateFromIterable)(this, shape, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            Create2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create2: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIterable,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIterable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorUInt32BitStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITensorUInt32BitStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromShapeArrayAndDataArray(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, data_array_size: u32, data: *const u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt32BitStatics2", "CreateFromShapeArrayAndDataArray", 6usize, |this| ((**(this as *const *const ITensorUInt32BitStatics2_Vtbl)).CreateFromShapeArrayAndDataArray)(this, shape_array_size, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromBuffer(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt32BitStatics2", "CreateFromBuffer", 7usize, |this| ((**(this as *const *const ITensorUInt32BitStatics2_Vtbl)).CreateFromBuffer)(this, shape_array_size, shape, buffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromShapeArrayAndDataArray,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorUInt32BitStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorUInt64Bit_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAsVectorView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt64Bit", "GetAsVectorView", 6usize, |this| ((**(this as *const *const ITensorUInt64Bit_Vtbl)).GetAsVectorView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAsVectorView,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAsVectorView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorUInt64Bit as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorUInt64BitStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt64BitStatics", "Create", 6usize, |this| ((**(this as *const *const ITensorUInt64BitStatics_Vtbl)).Create)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create2(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt64BitStatics", "Create2", 7usize, |this| ((**(this as *const *const ITensorUInt64BitStatics_Vtbl)).Create2)(this, shape, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromArray(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data_array_size: u32, data: *const u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt64BitStatics", "CreateFromArray", 8usize, |this| ((**(this as *const *const ITensorUInt64BitStatics_Vtbl)).CreateFromArray)(this, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIterable(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt64BitStatics", "CreateFromIterable", 9usize, |this| ((**(this as *const *const ITensorUInt64BitStatics_Vtbl)).CreateFromIterable)(this, shape, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            Create2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create2: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIterable,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIterable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorUInt64BitStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITensorUInt64BitStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromShapeArrayAndDataArray(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, data_array_size: u32, data: *const u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt64BitStatics2", "CreateFromShapeArrayAndDataArray", 6usize, |this| ((**(this as *const *const ITensorUInt64BitStatics2_Vtbl)).CreateFromShapeArrayAndDataArray)(this, shape_array_size, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromBuffer(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt64BitStatics2", "CreateFromBuffer", 7usize, |this| ((**(this as *const *const ITensorUInt64BitStatics2_Vtbl)).CreateFromBuffer)(this, shape_array_size, shape, buffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromShapeArrayAndDataArray,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorUInt64BitStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorUInt8Bit_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAsVectorView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt8Bit", "GetAsVectorView", 6usize, |this| ((**(this as *const *const ITensorUInt8Bit_Vtbl)).GetAsVectorView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAsVectorView,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAsVectorView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorUInt8Bit as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITensorUInt8BitStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt8BitStatics", "Create", 6usize, |this| ((**(this as *const *const ITensorUInt8BitStatics_Vtbl)).Create)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create2(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt8BitStatics", "Create2", 7usize, |this| ((**(this as *const *const ITensorUInt8BitStatics_Vtbl)).Create2)(this, shape, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromArray(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data_array_size: u32, data: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt8BitStatics", "CreateFromArray", 8usize, |this| ((**(this as *const *const ITensorUInt8BitStatics_Vtbl)).CreateFromArray)(this, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIterable(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt8BitStatics", "CreateFromIterable", 9usize, |this| ((**(this as *const *const ITensorUInt8BitStatics_Vtbl)).CreateFromIterable)(this, shape, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            Create2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create2: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIterable,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIterable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorUInt8BitStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITensorUInt8BitStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromShapeArrayAndDataArray(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, data_array_size: u32, data: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt8BitStatics2", "CreateFromShapeArrayAndDataArray", 6usize, |this| ((**(this as *const *const ITensorUInt8BitStatics2_Vtbl)).CreateFromShapeArrayAndDataArray)(this, shape_array_size, shape, data_array_size, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromBuffer(this: *mut core::ffi::c_void, shape_array_size: u32, shape: *const i64, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorUInt8BitStatics2", "CreateFromBuffer", 7usize, |this| ((**(this as *const *const ITensorUInt8BitStatics2_Vtbl)).CreateFromBuffer)(this, shape_array_size, shape, buffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromShapeArrayAndDataArray,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorUInt8BitStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
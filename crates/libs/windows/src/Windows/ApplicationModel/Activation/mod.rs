@@ -6424,6 +6424,7 @@ impl ActivationKind {
 impl windows_core::TypeKind for ActivationKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ActivationKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ActivationKind").field(&self.0).finish()
@@ -6445,6 +6446,7 @@ impl ApplicationExecutionState {
 impl windows_core::TypeKind for ApplicationExecutionState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ApplicationExecutionState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ApplicationExecutionState").field(&self.0).finish()
@@ -6455,3 +6457,5 @@ impl windows_core::RuntimeType for ApplicationExecutionState {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1195 @@
+impl windows_core::imp::ProxyVtable for IActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut ActivationKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivatedEventArgs", "Kind", 6usize, |this| ((**(this as *const *const IActivatedEventArgs_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreviousExecutionState(this: *mut core::ffi::c_void, result__: *mut ApplicationExecutionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivatedEventArgs", "PreviousExecutionState", 7usize, |this| ((**(this as *const *const IActivatedEventArgs_Vtbl)).PreviousExecutionState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SplashScreen(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivatedEventArgs", "SplashScreen", 8usize, |this| ((**(this as *const *const IActivatedEventArgs_Vtbl)).SplashScreen)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind, PreviousExecutionState, SplashScreen }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IActivatedEventArgsWithUser_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivatedEventArgsWithUser", "User", 6usize, |this| ((**(this as *const *const IActivatedEventArgsWithUser_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivatedEventArgsWithUser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationViewActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentlyShownApplicationViewId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewActivatedEventArgs", "CurrentlyShownApplicationViewId", 6usize, |this| ((**(this as *const *const IApplicationViewActivatedEventArgs_Vtbl)).CurrentlyShownApplicationViewId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CurrentlyShownApplicationViewId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentsProviderActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Verb(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentsProviderActivatedEventArgs", "Verb", 6usize, |this| ((**(this as *const *const IAppointmentsProviderActivatedEventArgs_Vtbl)).Verb)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Verb }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentsProviderActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Appointments_AppointmentsProvider")]
+impl windows_core::imp::ProxyVtable for IAppointmentsProviderAddAppointmentActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_Appointments_AppointmentsProvider")]
+        unsafe extern "system" fn AddAppointmentOperation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentsProviderAddAppointmentActivatedEventArgs", "AddAppointmentOperation", 6usize, |this| ((**(this as *const *const IAppointmentsProviderAddAppointmentActivatedEventArgs_Vtbl)).AddAppointmentOperation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_Appointments_AppointmentsProvider")]
+            AddAppointmentOperation,
+            #[cfg(not(feature = "ApplicationModel_Appointments_AppointmentsProvider"))]
+            AddAppointmentOperation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentsProviderAddAppointmentActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Appointments_AppointmentsProvider")]
+impl windows_core::imp::ProxyVtable for IAppointmentsProviderRemoveAppointmentActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_Appointments_AppointmentsProvider")]
+        unsafe extern "system" fn RemoveAppointmentOperation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentsProviderRemoveAppointmentActivatedEventArgs", "RemoveAppointmentOperation", 6usize, |this| ((**(this as *const *const IAppointmentsProviderRemoveAppointmentActivatedEventArgs_Vtbl)).RemoveAppointmentOperation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_Appointments_AppointmentsProvider")]
+            RemoveAppointmentOperation,
+            #[cfg(not(feature = "ApplicationModel_Appointments_AppointmentsProvider"))]
+            RemoveAppointmentOperation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentsProviderRemoveAppointmentActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Appointments_AppointmentsProvider")]
+impl windows_core::imp::ProxyVtable for IAppointmentsProviderReplaceAppointmentActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_Appointments_AppointmentsProvider")]
+        unsafe extern "system" fn ReplaceAppointmentOperation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentsProviderReplaceAppointmentActivatedEventArgs", "ReplaceAppointmentOperation", 6usize, |this| ((**(this as *const *const IAppointmentsProviderReplaceAppointmentActivatedEventArgs_Vtbl)).ReplaceAppointmentOperation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_Appointments_AppointmentsProvider")]
+            ReplaceAppointmentOperation,
+            #[cfg(not(feature = "ApplicationModel_Appointments_AppointmentsProvider"))]
+            ReplaceAppointmentOperation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentsProviderReplaceAppointmentActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstanceStartDate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs", "InstanceStartDate", 6usize, |this| ((**(this as *const *const IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs_Vtbl)).InstanceStartDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs", "LocalId", 7usize, |this| ((**(this as *const *const IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs_Vtbl)).LocalId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoamingId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs", "RoamingId", 8usize, |this| ((**(this as *const *const IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs_Vtbl)).RoamingId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InstanceStartDate, LocalId, RoamingId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentsProviderShowTimeFrameActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TimeToShow(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentsProviderShowTimeFrameActivatedEventArgs", "TimeToShow", 6usize, |this| ((**(this as *const *const IAppointmentsProviderShowTimeFrameActivatedEventArgs_Vtbl)).TimeToShow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentsProviderShowTimeFrameActivatedEventArgs", "Duration", 7usize, |this| ((**(this as *const *const IAppointmentsProviderShowTimeFrameActivatedEventArgs_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TimeToShow, Duration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentsProviderShowTimeFrameActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Background")]
+impl windows_core::imp::ProxyVtable for IBackgroundActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_Background")]
+        unsafe extern "system" fn TaskInstance(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundActivatedEventArgs", "TaskInstance", 6usize, |this| ((**(this as *const *const IBackgroundActivatedEventArgs_Vtbl)).TaskInstance)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_Background")]
+            TaskInstance,
+            #[cfg(not(feature = "ApplicationModel_Background"))]
+            TaskInstance: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerPreviewActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectionId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerPreviewActivatedEventArgs", "ConnectionId", 6usize, |this| ((**(this as *const *const IBarcodeScannerPreviewActivatedEventArgs_Vtbl)).ConnectionId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectionId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerPreviewActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Provider")]
+impl windows_core::imp::ProxyVtable for ICachedFileUpdaterActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Provider")]
+        unsafe extern "system" fn CachedFileUpdaterUI(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileUpdaterActivatedEventArgs", "CachedFileUpdaterUI", 6usize, |this| ((**(this as *const *const ICachedFileUpdaterActivatedEventArgs_Vtbl)).CachedFileUpdaterUI)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Provider")]
+            CachedFileUpdaterUI,
+            #[cfg(not(feature = "Storage_Provider"))]
+            CachedFileUpdaterUI: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICachedFileUpdaterActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICameraSettingsActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VideoDeviceController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraSettingsActivatedEventArgs", "VideoDeviceController", 6usize, |this| ((**(this as *const *const ICameraSettingsActivatedEventArgs_Vtbl)).VideoDeviceController)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoDeviceExtension(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraSettingsActivatedEventArgs", "VideoDeviceExtension", 7usize, |this| ((**(this as *const *const ICameraSettingsActivatedEventArgs_Vtbl)).VideoDeviceExtension)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, VideoDeviceController, VideoDeviceExtension }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraSettingsActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICommandLineActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Operation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandLineActivatedEventArgs", "Operation", 6usize, |this| ((**(this as *const *const ICommandLineActivatedEventArgs_Vtbl)).Operation)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Operation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommandLineActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICommandLineActivationOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Arguments(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandLineActivationOperation", "Arguments", 6usize, |this| ((**(this as *const *const ICommandLineActivationOperation_Vtbl)).Arguments)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentDirectoryPath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandLineActivationOperation", "CurrentDirectoryPath", 7usize, |this| ((**(this as *const *const ICommandLineActivationOperation_Vtbl)).CurrentDirectoryPath)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExitCode(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandLineActivationOperation", "SetExitCode", 8usize, |this| ((**(this as *const *const ICommandLineActivationOperation_Vtbl)).SetExitCode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExitCode(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandLineActivationOperation", "ExitCode", 9usize, |this| ((**(this as *const *const ICommandLineActivationOperation_Vtbl)).ExitCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandLineActivationOperation", "GetDeferral", 10usize, |this| ((**(this as *const *const ICommandLineActivationOperation_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Arguments,
+            CurrentDirectoryPath,
+            SetExitCode,
+            ExitCode,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommandLineActivationOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Verb(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactActivatedEventArgs", "Verb", 6usize, |this| ((**(this as *const *const IContactActivatedEventArgs_Vtbl)).Verb)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Verb }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Contacts")]
+impl windows_core::imp::ProxyVtable for IContactCallActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServiceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactCallActivatedEventArgs", "ServiceId", 6usize, |this| ((**(this as *const *const IContactCallActivatedEventArgs_Vtbl)).ServiceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceUserId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactCallActivatedEventArgs", "ServiceUserId", 7usize, |this| ((**(this as *const *const IContactCallActivatedEventArgs_Vtbl)).ServiceUserId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Contacts")]
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactCallActivatedEventArgs", "Contact", 8usize, |this| ((**(this as *const *const IContactCallActivatedEventArgs_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServiceId,
+            ServiceUserId,
+            #[cfg(feature = "ApplicationModel_Contacts")]
+            Contact,
+            #[cfg(not(feature = "ApplicationModel_Contacts"))]
+            Contact: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactCallActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Contacts")]
+impl windows_core::imp::ProxyVtable for IContactMapActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_Contacts")]
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactMapActivatedEventArgs", "Address", 6usize, |this| ((**(this as *const *const IContactMapActivatedEventArgs_Vtbl)).Address)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Contacts")]
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactMapActivatedEventArgs", "Contact", 7usize, |this| ((**(this as *const *const IContactMapActivatedEventArgs_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_Contacts")]
+            Address,
+            #[cfg(not(feature = "ApplicationModel_Contacts"))]
+            Address: 0,
+            #[cfg(feature = "ApplicationModel_Contacts")]
+            Contact,
+            #[cfg(not(feature = "ApplicationModel_Contacts"))]
+            Contact: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactMapActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Contacts")]
+impl windows_core::imp::ProxyVtable for IContactMessageActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServiceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactMessageActivatedEventArgs", "ServiceId", 6usize, |this| ((**(this as *const *const IContactMessageActivatedEventArgs_Vtbl)).ServiceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceUserId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactMessageActivatedEventArgs", "ServiceUserId", 7usize, |this| ((**(this as *const *const IContactMessageActivatedEventArgs_Vtbl)).ServiceUserId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Contacts")]
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactMessageActivatedEventArgs", "Contact", 8usize, |this| ((**(this as *const *const IContactMessageActivatedEventArgs_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServiceId,
+            ServiceUserId,
+            #[cfg(feature = "ApplicationModel_Contacts")]
+            Contact,
+            #[cfg(not(feature = "ApplicationModel_Contacts"))]
+            Contact: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactMessageActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Contacts")]
+impl windows_core::imp::ProxyVtable for IContactPanelActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_Contacts")]
+        unsafe extern "system" fn ContactPanel(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPanelActivatedEventArgs", "ContactPanel", 6usize, |this| ((**(this as *const *const IContactPanelActivatedEventArgs_Vtbl)).ContactPanel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Contacts")]
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPanelActivatedEventArgs", "Contact", 7usize, |this| ((**(this as *const *const IContactPanelActivatedEventArgs_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_Contacts")]
+            ContactPanel,
+            #[cfg(not(feature = "ApplicationModel_Contacts"))]
+            ContactPanel: 0,
+            #[cfg(feature = "ApplicationModel_Contacts")]
+            Contact,
+            #[cfg(not(feature = "ApplicationModel_Contacts"))]
+            Contact: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPanelActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Contacts_Provider")]
+impl windows_core::imp::ProxyVtable for IContactPickerActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_Contacts_Provider")]
+        unsafe extern "system" fn ContactPickerUI(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPickerActivatedEventArgs", "ContactPickerUI", 6usize, |this| ((**(this as *const *const IContactPickerActivatedEventArgs_Vtbl)).ContactPickerUI)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_Contacts_Provider")]
+            ContactPickerUI,
+            #[cfg(not(feature = "ApplicationModel_Contacts_Provider"))]
+            ContactPickerUI: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPickerActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Contacts")]
+impl windows_core::imp::ProxyVtable for IContactPostActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServiceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPostActivatedEventArgs", "ServiceId", 6usize, |this| ((**(this as *const *const IContactPostActivatedEventArgs_Vtbl)).ServiceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceUserId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPostActivatedEventArgs", "ServiceUserId", 7usize, |this| ((**(this as *const *const IContactPostActivatedEventArgs_Vtbl)).ServiceUserId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Contacts")]
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPostActivatedEventArgs", "Contact", 8usize, |this| ((**(this as *const *const IContactPostActivatedEventArgs_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServiceId,
+            ServiceUserId,
+            #[cfg(feature = "ApplicationModel_Contacts")]
+            Contact,
+            #[cfg(not(feature = "ApplicationModel_Contacts"))]
+            Contact: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPostActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Contacts")]
+impl windows_core::imp::ProxyVtable for IContactVideoCallActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServiceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactVideoCallActivatedEventArgs", "ServiceId", 6usize, |this| ((**(this as *const *const IContactVideoCallActivatedEventArgs_Vtbl)).ServiceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceUserId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactVideoCallActivatedEventArgs", "ServiceUserId", 7usize, |this| ((**(this as *const *const IContactVideoCallActivatedEventArgs_Vtbl)).ServiceUserId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Contacts")]
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactVideoCallActivatedEventArgs", "Contact", 8usize, |this| ((**(this as *const *const IContactVideoCallActivatedEventArgs_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServiceId,
+            ServiceUserId,
+            #[cfg(feature = "ApplicationModel_Contacts")]
+            Contact,
+            #[cfg(not(feature = "ApplicationModel_Contacts"))]
+            Contact: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactVideoCallActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactsProviderActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Verb(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactsProviderActivatedEventArgs", "Verb", 6usize, |this| ((**(this as *const *const IContactsProviderActivatedEventArgs_Vtbl)).Verb)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Verb }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactsProviderActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContinuationActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ContinuationData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContinuationActivatedEventArgs", "ContinuationData", 6usize, |this| ((**(this as *const *const IContinuationActivatedEventArgs_Vtbl)).ContinuationData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ContinuationData,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ContinuationData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContinuationActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceInformationId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceActivatedEventArgs", "DeviceInformationId", 6usize, |this| ((**(this as *const *const IDeviceActivatedEventArgs_Vtbl)).DeviceInformationId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Verb(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceActivatedEventArgs", "Verb", 7usize, |this| ((**(this as *const *const IDeviceActivatedEventArgs_Vtbl)).Verb)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceInformationId, Verb }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Enumeration")]
+impl windows_core::imp::ProxyVtable for IDevicePairingActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn DeviceInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePairingActivatedEventArgs", "DeviceInformation", 6usize, |this| ((**(this as *const *const IDevicePairingActivatedEventArgs_Vtbl)).DeviceInformation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Enumeration")]
+            DeviceInformation,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            DeviceInformation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePairingActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDialReceiverActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialReceiverActivatedEventArgs", "AppName", 6usize, |this| ((**(this as *const *const IDialReceiverActivatedEventArgs_Vtbl)).AppName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AppName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialReceiverActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IFileActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn Files(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileActivatedEventArgs", "Files", 6usize, |this| ((**(this as *const *const IFileActivatedEventArgs_Vtbl)).Files)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Verb(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileActivatedEventArgs", "Verb", 7usize, |this| ((**(this as *const *const IFileActivatedEventArgs_Vtbl)).Verb)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            Files,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            Files: 0,
+            Verb,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileActivatedEventArgsWithCallerPackageFamilyName_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CallerPackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileActivatedEventArgsWithCallerPackageFamilyName", "CallerPackageFamilyName", 6usize, |this| ((**(this as *const *const IFileActivatedEventArgsWithCallerPackageFamilyName_Vtbl)).CallerPackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CallerPackageFamilyName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileActivatedEventArgsWithCallerPackageFamilyName as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Search"))]
+impl windows_core::imp::ProxyVtable for IFileActivatedEventArgsWithNeighboringFiles_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Search")]
+        unsafe extern "system" fn NeighboringFilesQuery(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileActivatedEventArgsWithNeighboringFiles", "NeighboringFilesQuery", 6usize, |this| ((**(this as *const *const IFileActivatedEventArgsWithNeighboringFiles_Vtbl)).NeighboringFilesQuery)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Search")]
+            NeighboringFilesQuery,
+            #[cfg(not(feature = "Storage_Search"))]
+            NeighboringFilesQuery: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileActivatedEventArgsWithNeighboringFiles as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Pickers_Provider")]
+impl windows_core::imp::ProxyVtable for IFileOpenPickerActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Pickers_Provider")]
+        unsafe extern "system" fn FileOpenPickerUI(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerActivatedEventArgs", "FileOpenPickerUI", 6usize, |this| ((**(this as *const *const IFileOpenPickerActivatedEventArgs_Vtbl)).FileOpenPickerUI)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Pickers_Provider")]
+            FileOpenPickerUI,
+            #[cfg(not(feature = "Storage_Pickers_Provider"))]
+            FileOpenPickerUI: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileOpenPickerActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileOpenPickerActivatedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CallerPackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerActivatedEventArgs2", "CallerPackageFamilyName", 6usize, |this| ((**(this as *const *const IFileOpenPickerActivatedEventArgs2_Vtbl)).CallerPackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CallerPackageFamilyName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileOpenPickerActivatedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IFileOpenPickerContinuationEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage", feature = "deprecated"))]
+        unsafe extern "system" fn Files(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerContinuationEventArgs", "Files", 6usize, |this| ((**(this as *const *const IFileOpenPickerContinuationEventArgs_Vtbl)).Files)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage", feature = "deprecated"))]
+            Files,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage", feature = "deprecated")))]
+            Files: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileOpenPickerContinuationEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Pickers_Provider")]
+impl windows_core::imp::ProxyVtable for IFileSavePickerActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Pickers_Provider")]
+        unsafe extern "system" fn FileSavePickerUI(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePickerActivatedEventArgs", "FileSavePickerUI", 6usize, |this| ((**(this as *const *const IFileSavePickerActivatedEventArgs_Vtbl)).FileSavePickerUI)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Pickers_Provider")]
+            FileSavePickerUI,
+            #[cfg(not(feature = "Storage_Pickers_Provider"))]
+            FileSavePickerUI: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSavePickerActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileSavePickerActivatedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CallerPackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePickerActivatedEventArgs2", "CallerPackageFamilyName", 6usize, |this| ((**(this as *const *const IFileSavePickerActivatedEventArgs2_Vtbl)).CallerPackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnterpriseId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePickerActivatedEventArgs2", "EnterpriseId", 7usize, |this| ((**(this as *const *const IFileSavePickerActivatedEventArgs2_Vtbl)).EnterpriseId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CallerPackageFamilyName, EnterpriseId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSavePickerActivatedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IFileSavePickerContinuationEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Storage", feature = "deprecated"))]
+        unsafe extern "system" fn File(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePickerContinuationEventArgs", "File", 6usize, |this| ((**(this as *const *const IFileSavePickerContinuationEventArgs_Vtbl)).File)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Storage", feature = "deprecated"))]
+            File,
+            #[cfg(not(all(feature = "Storage", feature = "deprecated")))]
+            File: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSavePickerContinuationEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IFolderPickerContinuationEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Storage", feature = "deprecated"))]
+        unsafe extern "system" fn Folder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderPickerContinuationEventArgs", "Folder", 6usize, |this| ((**(this as *const *const IFolderPickerContinuationEventArgs_Vtbl)).Folder)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Storage", feature = "deprecated"))]
+            Folder,
+            #[cfg(not(all(feature = "Storage", feature = "deprecated")))]
+            Folder: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFolderPickerContinuationEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILaunchActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Arguments(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILaunchActivatedEventArgs", "Arguments", 6usize, |this| ((**(this as *const *const ILaunchActivatedEventArgs_Vtbl)).Arguments)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TileId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILaunchActivatedEventArgs", "TileId", 7usize, |this| ((**(this as *const *const ILaunchActivatedEventArgs_Vtbl)).TileId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Arguments, TileId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILaunchActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILaunchActivatedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TileActivatedInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILaunchActivatedEventArgs2", "TileActivatedInfo", 6usize, |this| ((**(this as *const *const ILaunchActivatedEventArgs2_Vtbl)).TileActivatedInfo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TileActivatedInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILaunchActivatedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILockScreenActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Info(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenActivatedEventArgs", "Info", 6usize, |this| ((**(this as *const *const ILockScreenActivatedEventArgs_Vtbl)).Info)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Info }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILockScreenActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Calls")]
+impl windows_core::imp::ProxyVtable for ILockScreenCallActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_Calls")]
+        unsafe extern "system" fn CallUI(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenCallActivatedEventArgs", "CallUI", 6usize, |this| ((**(this as *const *const ILockScreenCallActivatedEventArgs_Vtbl)).CallUI)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_Calls")]
+            CallUI,
+            #[cfg(not(feature = "ApplicationModel_Calls"))]
+            CallUI: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILockScreenCallActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneCallActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LineId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallActivatedEventArgs", "LineId", 6usize, |this| ((**(this as *const *const IPhoneCallActivatedEventArgs_Vtbl)).LineId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LineId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPickerReturnedActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PickerOperationId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPickerReturnedActivatedEventArgs", "PickerOperationId", 6usize, |this| ((**(this as *const *const IPickerReturnedActivatedEventArgs_Vtbl)).PickerOperationId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PickerOperationId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPickerReturnedActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrelaunchActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrelaunchActivated(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrelaunchActivatedEventArgs", "PrelaunchActivated", 6usize, |this| ((**(this as *const *const IPrelaunchActivatedEventArgs_Vtbl)).PrelaunchActivated)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrelaunchActivated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrelaunchActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Printers_Extensions")]
+impl windows_core::imp::ProxyVtable for IPrint3DWorkflowActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Printers_Extensions")]
+        unsafe extern "system" fn Workflow(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DWorkflowActivatedEventArgs", "Workflow", 6usize, |this| ((**(this as *const *const IPrint3DWorkflowActivatedEventArgs_Vtbl)).Workflow)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Printers_Extensions")]
+            Workflow,
+            #[cfg(not(feature = "Devices_Printers_Extensions"))]
+            Workflow: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint3DWorkflowActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Printers_Extensions")]
+impl windows_core::imp::ProxyVtable for IPrintTaskSettingsActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Printers_Extensions")]
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskSettingsActivatedEventArgs", "Configuration", 6usize, |this| ((**(this as *const *const IPrintTaskSettingsActivatedEventArgs_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Printers_Extensions")]
+            Configuration,
+            #[cfg(not(feature = "Devices_Printers_Extensions"))]
+            Configuration: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskSettingsActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProtocolActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtocolActivatedEventArgs", "Uri", 6usize, |this| ((**(this as *const *const IProtocolActivatedEventArgs_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtocolActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IProtocolActivatedEventArgsWithCallerPackageFamilyNameAndData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CallerPackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtocolActivatedEventArgsWithCallerPackageFamilyNameAndData", "CallerPackageFamilyName", 6usize, |this| ((**(this as *const *const IProtocolActivatedEventArgsWithCallerPackageFamilyNameAndData_Vtbl)).CallerPackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtocolActivatedEventArgsWithCallerPackageFamilyNameAndData", "Data", 7usize, |this| ((**(this as *const *const IProtocolActivatedEventArgsWithCallerPackageFamilyNameAndData_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CallerPackageFamilyName,
+            #[cfg(feature = "Foundation_Collections")]
+            Data,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Data: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtocolActivatedEventArgsWithCallerPackageFamilyNameAndData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IProtocolForResultsActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ProtocolForResultsOperation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtocolForResultsActivatedEventArgs", "ProtocolForResultsOperation", 6usize, |this| ((**(this as *const *const IProtocolForResultsActivatedEventArgs_Vtbl)).ProtocolForResultsOperation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            ProtocolForResultsOperation,
+            #[cfg(not(feature = "System"))]
+            ProtocolForResultsOperation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtocolForResultsActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRestrictedLaunchActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SharedContext(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRestrictedLaunchActivatedEventArgs", "SharedContext", 6usize, |this| ((**(this as *const *const IRestrictedLaunchActivatedEventArgs_Vtbl)).SharedContext)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SharedContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRestrictedLaunchActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISearchActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchActivatedEventArgs", "QueryText", 6usize, |this| ((**(this as *const *const ISearchActivatedEventArgs_Vtbl)).QueryText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchActivatedEventArgs", "Language", 7usize, |this| ((**(this as *const *const ISearchActivatedEventArgs_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryText, Language }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Search")]
+impl windows_core::imp::ProxyVtable for ISearchActivatedEventArgsWithLinguisticDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_Search")]
+        unsafe extern "system" fn LinguisticDetails(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchActivatedEventArgsWithLinguisticDetails", "LinguisticDetails", 6usize, |this| ((**(this as *const *const ISearchActivatedEventArgsWithLinguisticDetails_Vtbl)).LinguisticDetails)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_Search")]
+            LinguisticDetails,
+            #[cfg(not(feature = "ApplicationModel_Search"))]
+            LinguisticDetails: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchActivatedEventArgsWithLinguisticDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_DataTransfer_ShareTarget")]
+impl windows_core::imp::ProxyVtable for IShareTargetActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_DataTransfer_ShareTarget")]
+        unsafe extern "system" fn ShareOperation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareTargetActivatedEventArgs", "ShareOperation", 6usize, |this| ((**(this as *const *const IShareTargetActivatedEventArgs_Vtbl)).ShareOperation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_DataTransfer_ShareTarget")]
+            ShareOperation,
+            #[cfg(not(feature = "ApplicationModel_DataTransfer_ShareTarget"))]
+            ShareOperation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareTargetActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISplashScreen_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ImageLocation(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISplashScreen", "ImageLocation", 6usize, |this| ((**(this as *const *const ISplashScreen_Vtbl)).ImageLocation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Dismissed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISplashScreen", "Dismissed", 7usize, |this| ((**(this as *const *const ISplashScreen_Vtbl)).Dismissed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDismissed(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISplashScreen", "RemoveDismissed", 8usize, |this| ((**(this as *const *const ISplashScreen_Vtbl)).RemoveDismissed)(this, cookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ImageLocation, Dismissed, RemoveDismissed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISplashScreen as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStartupTaskActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TaskId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStartupTaskActivatedEventArgs", "TaskId", 6usize, |this| ((**(this as *const *const IStartupTaskActivatedEventArgs_Vtbl)).TaskId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TaskId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStartupTaskActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "UI_Notifications"))]
+impl windows_core::imp::ProxyVtable for ITileActivatedInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "UI_Notifications"))]
+        unsafe extern "system" fn RecentlyShownNotifications(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileActivatedInfo", "RecentlyShownNotifications", 6usize, |this| ((**(this as *const *const ITileActivatedInfo_Vtbl)).RecentlyShownNotifications)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "UI_Notifications"))]
+            RecentlyShownNotifications,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "UI_Notifications")))]
+            RecentlyShownNotifications: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITileActivatedInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IToastNotificationActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Argument(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationActivatedEventArgs", "Argument", 6usize, |this| ((**(this as *const *const IToastNotificationActivatedEventArgs_Vtbl)).Argument)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UserInput(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationActivatedEventArgs", "UserInput", 7usize, |this| ((**(this as *const *const IToastNotificationActivatedEventArgs_Vtbl)).UserInput)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Argument,
+            #[cfg(feature = "Foundation_Collections")]
+            UserInput,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UserInput: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_UserDataAccounts_Provider")]
+impl windows_core::imp::ProxyVtable for IUserDataAccountProviderActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_UserDataAccounts_Provider")]
+        unsafe extern "system" fn Operation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountProviderActivatedEventArgs", "Operation", 6usize, |this| ((**(this as *const *const IUserDataAccountProviderActivatedEventArgs_Vtbl)).Operation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_UserDataAccounts_Provider")]
+            Operation,
+            #[cfg(not(feature = "ApplicationModel_UserDataAccounts_Provider"))]
+            Operation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccountProviderActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_ViewManagement")]
+impl windows_core::imp::ProxyVtable for IViewSwitcherProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_ViewManagement")]
+        unsafe extern "system" fn ViewSwitcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewSwitcherProvider", "ViewSwitcher", 6usize, |this| ((**(this as *const *const IViewSwitcherProvider_Vtbl)).ViewSwitcher)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_ViewManagement")]
+            ViewSwitcher,
+            #[cfg(not(feature = "UI_ViewManagement"))]
+            ViewSwitcher: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IViewSwitcherProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_SpeechRecognition")]
+impl windows_core::imp::ProxyVtable for IVoiceCommandActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_SpeechRecognition")]
+        unsafe extern "system" fn Result(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandActivatedEventArgs", "Result", 6usize, |this| ((**(this as *const *const IVoiceCommandActivatedEventArgs_Vtbl)).Result)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_SpeechRecognition")]
+            Result,
+            #[cfg(not(feature = "Media_SpeechRecognition"))]
+            Result: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceCommandActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Wallet", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IWalletActionActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ItemId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletActionActivatedEventArgs", "ItemId", 6usize, |this| ((**(this as *const *const IWalletActionActivatedEventArgs_Vtbl)).ItemId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel_Wallet", feature = "deprecated"))]
+        unsafe extern "system" fn ActionKind(this: *mut core::ffi::c_void, result__: *mut super::Wallet::WalletActionKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletActionActivatedEventArgs", "ActionKind", 7usize, |this| ((**(this as *const *const IWalletActionActivatedEventArgs_Vtbl)).ActionKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ActionId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletActionActivatedEventArgs", "ActionId", 8usize, |this| ((**(this as *const *const IWalletActionActivatedEventArgs_Vtbl)).ActionId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            ItemId,
+            #[cfg(not(feature = "deprecated"))]
+            ItemId: 0,
+            #[cfg(all(feature = "ApplicationModel_Wallet", feature = "deprecated"))]
+            ActionKind,
+            #[cfg(not(all(feature = "ApplicationModel_Wallet", feature = "deprecated")))]
+            ActionKind: 0,
+            #[cfg(feature = "deprecated")]
+            ActionId,
+            #[cfg(not(feature = "deprecated"))]
+            ActionId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletActionActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Authentication_Web_Provider")]
+impl windows_core::imp::ProxyVtable for IWebAccountProviderActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Authentication_Web_Provider")]
+        unsafe extern "system" fn Operation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderActivatedEventArgs", "Operation", 6usize, |this| ((**(this as *const *const IWebAccountProviderActivatedEventArgs_Vtbl)).Operation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Authentication_Web_Provider")]
+            Operation,
+            #[cfg(not(feature = "Security_Authentication_Web_Provider"))]
+            Operation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Authentication_Web"))]
+impl windows_core::imp::ProxyVtable for IWebAuthenticationBrokerContinuationEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Authentication_Web")]
+        unsafe extern "system" fn WebAuthenticationResult(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationBrokerContinuationEventArgs", "WebAuthenticationResult", 6usize, |this| ((**(this as *const *const IWebAuthenticationBrokerContinuationEventArgs_Vtbl)).WebAuthenticationResult)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Authentication_Web")]
+            WebAuthenticationResult,
+            #[cfg(not(feature = "Security_Authentication_Web"))]
+            WebAuthenticationResult: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAuthenticationBrokerContinuationEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
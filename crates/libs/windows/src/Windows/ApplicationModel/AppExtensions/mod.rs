@@ -542,3 +542,5 @@ impl windows_core::RuntimeName for AppExtensionPackageUpdatingEventArgs {
 }
 unsafe impl Send for AppExtensionPackageUpdatingEventArgs {}
 unsafe impl Sync for AppExtensionPackageUpdatingEventArgs {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
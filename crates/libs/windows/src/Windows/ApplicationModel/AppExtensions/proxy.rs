@@ -0,0 +1,289 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IAppExtension_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtension", "Id", 6usize, |this| ((**(this as *const *const IAppExtension_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtension", "DisplayName", 7usize, |this| ((**(this as *const *const IAppExtension_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtension", "Description", 8usize, |this| ((**(this as *const *const IAppExtension_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtension", "Package", 9usize, |this| ((**(this as *const *const IAppExtension_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtension", "AppInfo", 10usize, |this| ((**(this as *const *const IAppExtension_Vtbl)).AppInfo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetExtensionPropertiesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtension", "GetExtensionPropertiesAsync", 11usize, |this| ((**(this as *const *const IAppExtension_Vtbl)).GetExtensionPropertiesAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn GetPublicFolderAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtension", "GetPublicFolderAsync", 12usize, |this| ((**(this as *const *const IAppExtension_Vtbl)).GetPublicFolderAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            DisplayName,
+            Description,
+            Package,
+            AppInfo,
+            #[cfg(feature = "Foundation_Collections")]
+            GetExtensionPropertiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetExtensionPropertiesAsync: 0,
+            #[cfg(feature = "Storage")]
+            GetPublicFolderAsync,
+            #[cfg(not(feature = "Storage"))]
+            GetPublicFolderAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppExtension as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppExtension2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppUserModelId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtension2", "AppUserModelId", 6usize, |this| ((**(this as *const *const IAppExtension2_Vtbl)).AppUserModelId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AppUserModelId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppExtension2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IAppExtension3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetExtensionProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtension3", "GetExtensionProperties", 6usize, |this| ((**(this as *const *const IAppExtension3_Vtbl)).GetExtensionProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPublicPath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtension3", "GetPublicPath", 7usize, |this| ((**(this as *const *const IAppExtension3_Vtbl)).GetPublicPath)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn GetPublicFolder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtension3", "GetPublicFolder", 8usize, |this| ((**(this as *const *const IAppExtension3_Vtbl)).GetPublicFolder)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetExtensionProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetExtensionProperties: 0,
+            GetPublicPath,
+            #[cfg(feature = "Storage")]
+            GetPublicFolder,
+            #[cfg(not(feature = "Storage"))]
+            GetPublicFolder: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppExtension3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppExtensionCatalog_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionCatalog", "FindAllAsync", 6usize, |this| ((**(this as *const *const IAppExtensionCatalog_Vtbl)).FindAllAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestRemovePackageAsync(this: *mut core::ffi::c_void, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionCatalog", "RequestRemovePackageAsync", 7usize, |this| ((**(this as *const *const IAppExtensionCatalog_Vtbl)).RequestRemovePackageAsync)(this, packagefullname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageInstalled(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionCatalog", "PackageInstalled", 8usize, |this| ((**(this as *const *const IAppExtensionCatalog_Vtbl)).PackageInstalled)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageInstalled(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionCatalog", "RemovePackageInstalled", 9usize, |this| ((**(this as *const *const IAppExtensionCatalog_Vtbl)).RemovePackageInstalled)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageUpdating(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionCatalog", "PackageUpdating", 10usize, |this| ((**(this as *const *const IAppExtensionCatalog_Vtbl)).PackageUpdating)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageUpdating(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionCatalog", "RemovePackageUpdating", 11usize, |this| ((**(this as *const *const IAppExtensionCatalog_Vtbl)).RemovePackageUpdating)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionCatalog", "PackageUpdated", 12usize, |this| ((**(this as *const *const IAppExtensionCatalog_Vtbl)).PackageUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionCatalog", "RemovePackageUpdated", 13usize, |this| ((**(this as *const *const IAppExtensionCatalog_Vtbl)).RemovePackageUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageUninstalling(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionCatalog", "PackageUninstalling", 14usize, |this| ((**(this as *const *const IAppExtensionCatalog_Vtbl)).PackageUninstalling)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageUninstalling(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionCatalog", "RemovePackageUninstalling", 15usize, |this| ((**(this as *const *const IAppExtensionCatalog_Vtbl)).RemovePackageUninstalling)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionCatalog", "PackageStatusChanged", 16usize, |this| ((**(this as *const *const IAppExtensionCatalog_Vtbl)).PackageStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionCatalog", "RemovePackageStatusChanged", 17usize, |this| ((**(this as *const *const IAppExtensionCatalog_Vtbl)).RemovePackageStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsync: 0,
+            RequestRemovePackageAsync,
+            PackageInstalled,
+            RemovePackageInstalled,
+            PackageUpdating,
+            RemovePackageUpdating,
+            PackageUpdated,
+            RemovePackageUpdated,
+            PackageUninstalling,
+            RemovePackageUninstalling,
+            PackageStatusChanged,
+            RemovePackageStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppExtensionCatalog as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppExtensionCatalog2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAll(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionCatalog2", "FindAll", 6usize, |this| ((**(this as *const *const IAppExtensionCatalog2_Vtbl)).FindAll)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAll,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAll: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppExtensionCatalog2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppExtensionCatalogStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, appextensionname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionCatalogStatics", "Open", 6usize, |this| ((**(this as *const *const IAppExtensionCatalogStatics_Vtbl)).Open)(this, appextensionname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Open }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppExtensionCatalogStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppExtensionPackageInstalledEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppExtensionName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionPackageInstalledEventArgs", "AppExtensionName", 6usize, |this| ((**(this as *const *const IAppExtensionPackageInstalledEventArgs_Vtbl)).AppExtensionName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionPackageInstalledEventArgs", "Package", 7usize, |this| ((**(this as *const *const IAppExtensionPackageInstalledEventArgs_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Extensions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionPackageInstalledEventArgs", "Extensions", 8usize, |this| ((**(this as *const *const IAppExtensionPackageInstalledEventArgs_Vtbl)).Extensions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppExtensionName,
+            Package,
+            #[cfg(feature = "Foundation_Collections")]
+            Extensions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Extensions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppExtensionPackageInstalledEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppExtensionPackageStatusChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppExtensionName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionPackageStatusChangedEventArgs", "AppExtensionName", 6usize, |this| ((**(this as *const *const IAppExtensionPackageStatusChangedEventArgs_Vtbl)).AppExtensionName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionPackageStatusChangedEventArgs", "Package", 7usize, |this| ((**(this as *const *const IAppExtensionPackageStatusChangedEventArgs_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AppExtensionName, Package }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppExtensionPackageStatusChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppExtensionPackageUninstallingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppExtensionName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionPackageUninstallingEventArgs", "AppExtensionName", 6usize, |this| ((**(this as *const *const IAppExtensionPackageUninstallingEventArgs_Vtbl)).AppExtensionName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionPackageUninstallingEventArgs", "Package", 7usize, |this| ((**(this as *const *const IAppExtensionPackageUninstallingEventArgs_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AppExtensionName, Package }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppExtensionPackageUninstallingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppExtensionPackageUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppExtensionName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionPackageUpdatedEventArgs", "AppExtensionName", 6usize, |this| ((**(this as *const *const IAppExtensionPackageUpdatedEventArgs_Vtbl)).AppExtensionName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionPackageUpdatedEventArgs", "Package", 7usize, |this| ((**(this as *const *const IAppExtensionPackageUpdatedEventArgs_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Extensions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionPackageUpdatedEventArgs", "Extensions", 8usize, |this| ((**(this as *const *const IAppExtensionPackageUpdatedEventArgs_Vtbl)).Extensions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppExtensionName,
+            Package,
+            #[cfg(feature = "Foundation_Collections")]
+            Extensions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Extensions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppExtensionPackageUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppExtensionPackageUpdatingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppExtensionName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionPackageUpdatingEventArgs", "AppExtensionName", 6usize, |this| ((**(this as *const *const IAppExtensionPackageUpdatingEventArgs_Vtbl)).AppExtensionName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExtensionPackageUpdatingEventArgs", "Package", 7usize, |this| ((**(this as *const *const IAppExtensionPackageUpdatingEventArgs_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AppExtensionName, Package }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppExtensionPackageUpdatingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
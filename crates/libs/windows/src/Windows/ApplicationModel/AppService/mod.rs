@@ -585,6 +585,7 @@ impl AppServiceClosedStatus {
 impl windows_core::TypeKind for AppServiceClosedStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppServiceClosedStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppServiceClosedStatus").field(&self.0).finish()
@@ -613,6 +614,7 @@ impl AppServiceConnectionStatus {
 impl windows_core::TypeKind for AppServiceConnectionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppServiceConnectionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppServiceConnectionStatus").field(&self.0).finish()
@@ -640,6 +642,7 @@ impl AppServiceResponseStatus {
 impl windows_core::TypeKind for AppServiceResponseStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppServiceResponseStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppServiceResponseStatus").field(&self.0).finish()
@@ -671,6 +674,7 @@ impl StatelessAppServiceResponseStatus {
 impl windows_core::TypeKind for StatelessAppServiceResponseStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StatelessAppServiceResponseStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StatelessAppServiceResponseStatus").field(&self.0).finish()
@@ -679,3 +683,5 @@ impl core::fmt::Debug for StatelessAppServiceResponseStatus {
 impl windows_core::RuntimeType for StatelessAppServiceResponseStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.AppService.StatelessAppServiceResponseStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
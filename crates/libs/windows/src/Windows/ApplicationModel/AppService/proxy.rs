@@ -0,0 +1,301 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppServiceCatalogStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAppServiceProvidersAsync(this: *mut core::ffi::c_void, appservicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceCatalogStatics", "FindAppServiceProvidersAsync", 6usize, |this| ((**(this as *const *const IAppServiceCatalogStatics_Vtbl)).FindAppServiceProvidersAsync)(this, appservicename, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAppServiceProvidersAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAppServiceProvidersAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppServiceCatalogStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppServiceClosedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut AppServiceClosedStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceClosedEventArgs", "Status", 6usize, |this| ((**(this as *const *const IAppServiceClosedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppServiceClosedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppServiceConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppServiceName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceConnection", "AppServiceName", 6usize, |this| ((**(this as *const *const IAppServiceConnection_Vtbl)).AppServiceName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppServiceName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceConnection", "SetAppServiceName", 7usize, |this| ((**(this as *const *const IAppServiceConnection_Vtbl)).SetAppServiceName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceConnection", "PackageFamilyName", 8usize, |this| ((**(this as *const *const IAppServiceConnection_Vtbl)).PackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPackageFamilyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceConnection", "SetPackageFamilyName", 9usize, |this| ((**(this as *const *const IAppServiceConnection_Vtbl)).SetPackageFamilyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceConnection", "OpenAsync", 10usize, |this| ((**(this as *const *const IAppServiceConnection_Vtbl)).OpenAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SendMessageAsync(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceConnection", "SendMessageAsync", 11usize, |this| ((**(this as *const *const IAppServiceConnection_Vtbl)).SendMessageAsync)(this, message, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceConnection", "RequestReceived", 12usize, |this| ((**(this as *const *const IAppServiceConnection_Vtbl)).RequestReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRequestReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceConnection", "RemoveRequestReceived", 13usize, |this| ((**(this as *const *const IAppServiceConnection_Vtbl)).RemoveRequestReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceClosed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceConnection", "ServiceClosed", 14usize, |this| ((**(this as *const *const IAppServiceConnection_Vtbl)).ServiceClosed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveServiceClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceConnection", "RemoveServiceClosed", 15usize, |this| ((**(this as *const *const IAppServiceConnection_Vtbl)).RemoveServiceClosed)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppServiceName,
+            SetAppServiceName,
+            PackageFamilyName,
+            SetPackageFamilyName,
+            OpenAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            SendMessageAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SendMessageAsync: 0,
+            RequestReceived,
+            RemoveRequestReceived,
+            ServiceClosed,
+            RemoveServiceClosed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppServiceConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System_RemoteSystems")]
+impl windows_core::imp::ProxyVtable for IAppServiceConnection2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System_RemoteSystems")]
+        unsafe extern "system" fn OpenRemoteAsync(this: *mut core::ffi::c_void, remotesystemconnectionrequest: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceConnection2", "OpenRemoteAsync", 6usize, |this| ((**(this as *const *const IAppServiceConnection2_Vtbl)).OpenRemoteAsync)(this, remotesystemconnectionrequest, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceConnection2", "User", 7usize, |this| ((**(this as *const *const IAppServiceConnection2_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetUser(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceConnection2", "SetUser", 8usize, |this| ((**(this as *const *const IAppServiceConnection2_Vtbl)).SetUser)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System_RemoteSystems")]
+            OpenRemoteAsync,
+            #[cfg(not(feature = "System_RemoteSystems"))]
+            OpenRemoteAsync: 0,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+            #[cfg(feature = "System")]
+            SetUser,
+            #[cfg(not(feature = "System"))]
+            SetUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppServiceConnection2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "System_RemoteSystems"))]
+impl windows_core::imp::ProxyVtable for IAppServiceConnectionStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "System_RemoteSystems"))]
+        unsafe extern "system" fn SendStatelessMessageAsync(this: *mut core::ffi::c_void, connection: *mut core::ffi::c_void, connectionrequest: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceConnectionStatics", "SendStatelessMessageAsync", 6usize, |this| ((**(this as *const *const IAppServiceConnectionStatics_Vtbl)).SendStatelessMessageAsync)(this, connection, connectionrequest, message, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "System_RemoteSystems"))]
+            SendStatelessMessageAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "System_RemoteSystems")))]
+            SendStatelessMessageAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppServiceConnectionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppServiceDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceDeferral", "Complete", 6usize, |this| ((**(this as *const *const IAppServiceDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppServiceDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppServiceRequest_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceRequest", "Message", 6usize, |this| ((**(this as *const *const IAppServiceRequest_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SendResponseAsync(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceRequest", "SendResponseAsync", 7usize, |this| ((**(this as *const *const IAppServiceRequest_Vtbl)).SendResponseAsync)(this, message, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Message,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Message: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SendResponseAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SendResponseAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppServiceRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppServiceRequestReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceRequestReceivedEventArgs", "Request", 6usize, |this| ((**(this as *const *const IAppServiceRequestReceivedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceRequestReceivedEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IAppServiceRequestReceivedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppServiceRequestReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppServiceResponse_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceResponse", "Message", 6usize, |this| ((**(this as *const *const IAppServiceResponse_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut AppServiceResponseStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceResponse", "Status", 7usize, |this| ((**(this as *const *const IAppServiceResponse_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Message,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Message: 0,
+            Status,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppServiceResponse as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppServiceTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceTriggerDetails", "Name", 6usize, |this| ((**(this as *const *const IAppServiceTriggerDetails_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallerPackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceTriggerDetails", "CallerPackageFamilyName", 7usize, |this| ((**(this as *const *const IAppServiceTriggerDetails_Vtbl)).CallerPackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppServiceConnection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceTriggerDetails", "AppServiceConnection", 8usize, |this| ((**(this as *const *const IAppServiceTriggerDetails_Vtbl)).AppServiceConnection)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, CallerPackageFamilyName, AppServiceConnection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppServiceTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppServiceTriggerDetails2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsRemoteSystemConnection(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceTriggerDetails2", "IsRemoteSystemConnection", 6usize, |this| ((**(this as *const *const IAppServiceTriggerDetails2_Vtbl)).IsRemoteSystemConnection)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsRemoteSystemConnection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppServiceTriggerDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppServiceTriggerDetails3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CheckCallerForCapabilityAsync(this: *mut core::ffi::c_void, capabilityname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceTriggerDetails3", "CheckCallerForCapabilityAsync", 6usize, |this| ((**(this as *const *const IAppServiceTriggerDetails3_Vtbl)).CheckCallerForCapabilityAsync)(this, capabilityname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CheckCallerForCapabilityAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppServiceTriggerDetails3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppServiceTriggerDetails4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CallerRemoteConnectionToken(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceTriggerDetails4", "CallerRemoteConnectionToken", 6usize, |this| ((**(this as *const *const IAppServiceTriggerDetails4_Vtbl)).CallerRemoteConnectionToken)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CallerRemoteConnectionToken }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppServiceTriggerDetails4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStatelessAppServiceResponse_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatelessAppServiceResponse", "Message", 6usize, |this| ((**(this as *const *const IStatelessAppServiceResponse_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut StatelessAppServiceResponseStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatelessAppServiceResponse", "Status", 7usize, |this| ((**(this as *const *const IStatelessAppServiceResponse_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Message,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Message: 0,
+            Status,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStatelessAppServiceResponse as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
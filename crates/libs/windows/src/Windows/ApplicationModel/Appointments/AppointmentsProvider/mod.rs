@@ -275,3 +275,5 @@ impl windows_core::RuntimeName for ReplaceAppointmentOperation {
 }
 unsafe impl Send for ReplaceAppointmentOperation {}
 unsafe impl Sync for ReplaceAppointmentOperation {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,156 @@
+impl windows_core::imp::ProxyVtable for IAddAppointmentOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppointmentInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddAppointmentOperation", "AppointmentInformation", 6usize, |this| ((**(this as *const *const IAddAppointmentOperation_Vtbl)).AppointmentInformation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourcePackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddAppointmentOperation", "SourcePackageFamilyName", 7usize, |this| ((**(this as *const *const IAddAppointmentOperation_Vtbl)).SourcePackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompleted(this: *mut core::ffi::c_void, itemid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddAppointmentOperation", "ReportCompleted", 8usize, |this| ((**(this as *const *const IAddAppointmentOperation_Vtbl)).ReportCompleted)(this, itemid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCanceled(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddAppointmentOperation", "ReportCanceled", 9usize, |this| ((**(this as *const *const IAddAppointmentOperation_Vtbl)).ReportCanceled)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportError(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddAppointmentOperation", "ReportError", 10usize, |this| ((**(this as *const *const IAddAppointmentOperation_Vtbl)).ReportError)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DismissUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddAppointmentOperation", "DismissUI", 11usize, |this| ((**(this as *const *const IAddAppointmentOperation_Vtbl)).DismissUI)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppointmentInformation,
+            SourcePackageFamilyName,
+            ReportCompleted,
+            ReportCanceled,
+            ReportError,
+            DismissUI,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAddAppointmentOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentsProviderLaunchActionVerbsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddAppointment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentsProviderLaunchActionVerbsStatics", "AddAppointment", 6usize, |this| ((**(this as *const *const IAppointmentsProviderLaunchActionVerbsStatics_Vtbl)).AddAppointment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplaceAppointment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentsProviderLaunchActionVerbsStatics", "ReplaceAppointment", 7usize, |this| ((**(this as *const *const IAppointmentsProviderLaunchActionVerbsStatics_Vtbl)).ReplaceAppointment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAppointment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentsProviderLaunchActionVerbsStatics", "RemoveAppointment", 8usize, |this| ((**(this as *const *const IAppointmentsProviderLaunchActionVerbsStatics_Vtbl)).RemoveAppointment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowTimeFrame(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentsProviderLaunchActionVerbsStatics", "ShowTimeFrame", 9usize, |this| ((**(this as *const *const IAppointmentsProviderLaunchActionVerbsStatics_Vtbl)).ShowTimeFrame)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddAppointment,
+            ReplaceAppointment,
+            RemoveAppointment,
+            ShowTimeFrame,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentsProviderLaunchActionVerbsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentsProviderLaunchActionVerbsStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowAppointmentDetails(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentsProviderLaunchActionVerbsStatics2", "ShowAppointmentDetails", 6usize, |this| ((**(this as *const *const IAppointmentsProviderLaunchActionVerbsStatics2_Vtbl)).ShowAppointmentDetails)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowAppointmentDetails }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentsProviderLaunchActionVerbsStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoveAppointmentOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppointmentId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoveAppointmentOperation", "AppointmentId", 6usize, |this| ((**(this as *const *const IRemoveAppointmentOperation_Vtbl)).AppointmentId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstanceStartDate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoveAppointmentOperation", "InstanceStartDate", 7usize, |this| ((**(this as *const *const IRemoveAppointmentOperation_Vtbl)).InstanceStartDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourcePackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoveAppointmentOperation", "SourcePackageFamilyName", 8usize, |this| ((**(this as *const *const IRemoveAppointmentOperation_Vtbl)).SourcePackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompleted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoveAppointmentOperation", "ReportCompleted", 9usize, |this| ((**(this as *const *const IRemoveAppointmentOperation_Vtbl)).ReportCompleted)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCanceled(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoveAppointmentOperation", "ReportCanceled", 10usize, |this| ((**(this as *const *const IRemoveAppointmentOperation_Vtbl)).ReportCanceled)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportError(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoveAppointmentOperation", "ReportError", 11usize, |this| ((**(this as *const *const IRemoveAppointmentOperation_Vtbl)).ReportError)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DismissUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoveAppointmentOperation", "DismissUI", 12usize, |this| ((**(this as *const *const IRemoveAppointmentOperation_Vtbl)).DismissUI)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppointmentId,
+            InstanceStartDate,
+            SourcePackageFamilyName,
+            ReportCompleted,
+            ReportCanceled,
+            ReportError,
+            DismissUI,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoveAppointmentOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IReplaceAppointmentOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppointmentId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReplaceAppointmentOperation", "AppointmentId", 6usize, |this| ((**(this as *const *const IReplaceAppointmentOperation_Vtbl)).AppointmentId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppointmentInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReplaceAppointmentOperation", "AppointmentInformation", 7usize, |this| ((**(this as *const *const IReplaceAppointmentOperation_Vtbl)).AppointmentInformation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstanceStartDate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReplaceAppointmentOperation", "InstanceStartDate", 8usize, |this| ((**(this as *const *const IReplaceAppointmentOperation_Vtbl)).InstanceStartDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourcePackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReplaceAppointmentOperation", "SourcePackageFamilyName", 9usize, |this| ((**(this as *const *const IReplaceAppointmentOperation_Vtbl)).SourcePackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompleted(this: *mut core::ffi::c_void, itemid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReplaceAppointmentOperation", "ReportCompleted", 10usize, |this| ((**(this as *const *const IReplaceAppointmentOperation_Vtbl)).ReportCompleted)(this, itemid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCanceled(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReplaceAppointmentOperation", "ReportCanceled", 11usize, |this| ((**(this as *const *const IReplaceAppointmentOperation_Vtbl)).ReportCanceled)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportError(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReplaceAppointmentOperation", "ReportError", 12usize, |this| ((**(this as *const *const IReplaceAppointmentOperation_Vtbl)).ReportError)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DismissUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReplaceAppointmentOperation", "DismissUI", 13usize, |this| ((**(this as *const *const IReplaceAppointmentOperation_Vtbl)).DismissUI)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppointmentId,
+            AppointmentInformation,
+            InstanceStartDate,
+            SourcePackageFamilyName,
+            ReportCompleted,
+            ReportCanceled,
+            ReportError,
+            DismissUI,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReplaceAppointmentOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
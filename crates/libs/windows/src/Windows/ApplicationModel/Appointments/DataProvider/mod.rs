@@ -930,3 +930,5 @@ impl windows_core::RuntimeName for AppointmentDataProviderTriggerDetails {
 }
 unsafe impl Send for AppointmentDataProviderTriggerDetails {}
 unsafe impl Sync for AppointmentDataProviderTriggerDetails {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
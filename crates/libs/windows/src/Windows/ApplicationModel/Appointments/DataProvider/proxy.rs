@@ -0,0 +1,417 @@
+impl windows_core::imp::ProxyVtable for IAppointmentCalendarCancelMeetingRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppointmentCalendarLocalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCancelMeetingRequest", "AppointmentCalendarLocalId", 6usize, |this| ((**(this as *const *const IAppointmentCalendarCancelMeetingRequest_Vtbl)).AppointmentCalendarLocalId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppointmentLocalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCancelMeetingRequest", "AppointmentLocalId", 7usize, |this| ((**(this as *const *const IAppointmentCalendarCancelMeetingRequest_Vtbl)).AppointmentLocalId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppointmentOriginalStartTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCancelMeetingRequest", "AppointmentOriginalStartTime", 8usize, |this| ((**(this as *const *const IAppointmentCalendarCancelMeetingRequest_Vtbl)).AppointmentOriginalStartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCancelMeetingRequest", "Subject", 9usize, |this| ((**(this as *const *const IAppointmentCalendarCancelMeetingRequest_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Comment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCancelMeetingRequest", "Comment", 10usize, |this| ((**(this as *const *const IAppointmentCalendarCancelMeetingRequest_Vtbl)).Comment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyInvitees(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCancelMeetingRequest", "NotifyInvitees", 11usize, |this| ((**(this as *const *const IAppointmentCalendarCancelMeetingRequest_Vtbl)).NotifyInvitees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCancelMeetingRequest", "ReportCompletedAsync", 12usize, |this| ((**(this as *const *const IAppointmentCalendarCancelMeetingRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCancelMeetingRequest", "ReportFailedAsync", 13usize, |this| ((**(this as *const *const IAppointmentCalendarCancelMeetingRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppointmentCalendarLocalId,
+            AppointmentLocalId,
+            AppointmentOriginalStartTime,
+            Subject,
+            Comment,
+            NotifyInvitees,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendarCancelMeetingRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentCalendarCancelMeetingRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCancelMeetingRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IAppointmentCalendarCancelMeetingRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCancelMeetingRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IAppointmentCalendarCancelMeetingRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendarCancelMeetingRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppointmentCalendarCreateOrUpdateAppointmentRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppointmentCalendarLocalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCreateOrUpdateAppointmentRequest", "AppointmentCalendarLocalId", 6usize, |this| ((**(this as *const *const IAppointmentCalendarCreateOrUpdateAppointmentRequest_Vtbl)).AppointmentCalendarLocalId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Appointment(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCreateOrUpdateAppointmentRequest", "Appointment", 7usize, |this| ((**(this as *const *const IAppointmentCalendarCreateOrUpdateAppointmentRequest_Vtbl)).Appointment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyInvitees(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCreateOrUpdateAppointmentRequest", "NotifyInvitees", 8usize, |this| ((**(this as *const *const IAppointmentCalendarCreateOrUpdateAppointmentRequest_Vtbl)).NotifyInvitees)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ChangedProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCreateOrUpdateAppointmentRequest", "ChangedProperties", 9usize, |this| ((**(this as *const *const IAppointmentCalendarCreateOrUpdateAppointmentRequest_Vtbl)).ChangedProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, createdorupdatedappointment: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCreateOrUpdateAppointmentRequest", "ReportCompletedAsync", 10usize, |this| ((**(this as *const *const IAppointmentCalendarCreateOrUpdateAppointmentRequest_Vtbl)).ReportCompletedAsync)(this, createdorupdatedappointment, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCreateOrUpdateAppointmentRequest", "ReportFailedAsync", 11usize, |this| ((**(this as *const *const IAppointmentCalendarCreateOrUpdateAppointmentRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppointmentCalendarLocalId,
+            Appointment,
+            NotifyInvitees,
+            #[cfg(feature = "Foundation_Collections")]
+            ChangedProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ChangedProperties: 0,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendarCreateOrUpdateAppointmentRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentCalendarCreateOrUpdateAppointmentRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCreateOrUpdateAppointmentRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IAppointmentCalendarCreateOrUpdateAppointmentRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarCreateOrUpdateAppointmentRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IAppointmentCalendarCreateOrUpdateAppointmentRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendarCreateOrUpdateAppointmentRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppointmentCalendarForwardMeetingRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppointmentCalendarLocalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarForwardMeetingRequest", "AppointmentCalendarLocalId", 6usize, |this| ((**(this as *const *const IAppointmentCalendarForwardMeetingRequest_Vtbl)).AppointmentCalendarLocalId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppointmentLocalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarForwardMeetingRequest", "AppointmentLocalId", 7usize, |this| ((**(this as *const *const IAppointmentCalendarForwardMeetingRequest_Vtbl)).AppointmentLocalId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppointmentOriginalStartTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarForwardMeetingRequest", "AppointmentOriginalStartTime", 8usize, |this| ((**(this as *const *const IAppointmentCalendarForwardMeetingRequest_Vtbl)).AppointmentOriginalStartTime)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Invitees(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarForwardMeetingRequest", "Invitees", 9usize, |this| ((**(this as *const *const IAppointmentCalendarForwardMeetingRequest_Vtbl)).Invitees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarForwardMeetingRequest", "Subject", 10usize, |this| ((**(this as *const *const IAppointmentCalendarForwardMeetingRequest_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForwardHeader(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarForwardMeetingRequest", "ForwardHeader", 11usize, |this| ((**(this as *const *const IAppointmentCalendarForwardMeetingRequest_Vtbl)).ForwardHeader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Comment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarForwardMeetingRequest", "Comment", 12usize, |this| ((**(this as *const *const IAppointmentCalendarForwardMeetingRequest_Vtbl)).Comment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarForwardMeetingRequest", "ReportCompletedAsync", 13usize, |this| ((**(this as *const *const IAppointmentCalendarForwardMeetingRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarForwardMeetingRequest", "ReportFailedAsync", 14usize, |this| ((**(this as *const *const IAppointmentCalendarForwardMeetingRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppointmentCalendarLocalId,
+            AppointmentLocalId,
+            AppointmentOriginalStartTime,
+            #[cfg(feature = "Foundation_Collections")]
+            Invitees,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Invitees: 0,
+            Subject,
+            ForwardHeader,
+            Comment,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendarForwardMeetingRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentCalendarForwardMeetingRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarForwardMeetingRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IAppointmentCalendarForwardMeetingRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarForwardMeetingRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IAppointmentCalendarForwardMeetingRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendarForwardMeetingRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentCalendarProposeNewTimeForMeetingRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppointmentCalendarLocalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarProposeNewTimeForMeetingRequest", "AppointmentCalendarLocalId", 6usize, |this| ((**(this as *const *const IAppointmentCalendarProposeNewTimeForMeetingRequest_Vtbl)).AppointmentCalendarLocalId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppointmentLocalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarProposeNewTimeForMeetingRequest", "AppointmentLocalId", 7usize, |this| ((**(this as *const *const IAppointmentCalendarProposeNewTimeForMeetingRequest_Vtbl)).AppointmentLocalId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppointmentOriginalStartTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarProposeNewTimeForMeetingRequest", "AppointmentOriginalStartTime", 8usize, |this| ((**(this as *const *const IAppointmentCalendarProposeNewTimeForMeetingRequest_Vtbl)).AppointmentOriginalStartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewStartTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarProposeNewTimeForMeetingRequest", "NewStartTime", 9usize, |this| ((**(this as *const *const IAppointmentCalendarProposeNewTimeForMeetingRequest_Vtbl)).NewStartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewDuration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarProposeNewTimeForMeetingRequest", "NewDuration", 10usize, |this| ((**(this as *const *const IAppointmentCalendarProposeNewTimeForMeetingRequest_Vtbl)).NewDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarProposeNewTimeForMeetingRequest", "Subject", 11usize, |this| ((**(this as *const *const IAppointmentCalendarProposeNewTimeForMeetingRequest_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Comment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarProposeNewTimeForMeetingRequest", "Comment", 12usize, |this| ((**(this as *const *const IAppointmentCalendarProposeNewTimeForMeetingRequest_Vtbl)).Comment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarProposeNewTimeForMeetingRequest", "ReportCompletedAsync", 13usize, |this| ((**(this as *const *const IAppointmentCalendarProposeNewTimeForMeetingRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarProposeNewTimeForMeetingRequest", "ReportFailedAsync", 14usize, |this| ((**(this as *const *const IAppointmentCalendarProposeNewTimeForMeetingRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppointmentCalendarLocalId,
+            AppointmentLocalId,
+            AppointmentOriginalStartTime,
+            NewStartTime,
+            NewDuration,
+            Subject,
+            Comment,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendarProposeNewTimeForMeetingRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentCalendarProposeNewTimeForMeetingRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarProposeNewTimeForMeetingRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IAppointmentCalendarProposeNewTimeForMeetingRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarProposeNewTimeForMeetingRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IAppointmentCalendarProposeNewTimeForMeetingRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendarProposeNewTimeForMeetingRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentCalendarSyncManagerSyncRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppointmentCalendarLocalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarSyncManagerSyncRequest", "AppointmentCalendarLocalId", 6usize, |this| ((**(this as *const *const IAppointmentCalendarSyncManagerSyncRequest_Vtbl)).AppointmentCalendarLocalId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarSyncManagerSyncRequest", "ReportCompletedAsync", 7usize, |this| ((**(this as *const *const IAppointmentCalendarSyncManagerSyncRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarSyncManagerSyncRequest", "ReportFailedAsync", 8usize, |this| ((**(this as *const *const IAppointmentCalendarSyncManagerSyncRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppointmentCalendarLocalId,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendarSyncManagerSyncRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentCalendarSyncManagerSyncRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarSyncManagerSyncRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IAppointmentCalendarSyncManagerSyncRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarSyncManagerSyncRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IAppointmentCalendarSyncManagerSyncRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendarSyncManagerSyncRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentCalendarUpdateMeetingResponseRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppointmentCalendarLocalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarUpdateMeetingResponseRequest", "AppointmentCalendarLocalId", 6usize, |this| ((**(this as *const *const IAppointmentCalendarUpdateMeetingResponseRequest_Vtbl)).AppointmentCalendarLocalId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppointmentLocalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarUpdateMeetingResponseRequest", "AppointmentLocalId", 7usize, |this| ((**(this as *const *const IAppointmentCalendarUpdateMeetingResponseRequest_Vtbl)).AppointmentLocalId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppointmentOriginalStartTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarUpdateMeetingResponseRequest", "AppointmentOriginalStartTime", 8usize, |this| ((**(this as *const *const IAppointmentCalendarUpdateMeetingResponseRequest_Vtbl)).AppointmentOriginalStartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Response(this: *mut core::ffi::c_void, result__: *mut super::AppointmentParticipantResponse) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarUpdateMeetingResponseRequest", "Response", 9usize, |this| ((**(this as *const *const IAppointmentCalendarUpdateMeetingResponseRequest_Vtbl)).Response)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarUpdateMeetingResponseRequest", "Subject", 10usize, |this| ((**(this as *const *const IAppointmentCalendarUpdateMeetingResponseRequest_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Comment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarUpdateMeetingResponseRequest", "Comment", 11usize, |this| ((**(this as *const *const IAppointmentCalendarUpdateMeetingResponseRequest_Vtbl)).Comment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendUpdate(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarUpdateMeetingResponseRequest", "SendUpdate", 12usize, |this| ((**(this as *const *const IAppointmentCalendarUpdateMeetingResponseRequest_Vtbl)).SendUpdate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarUpdateMeetingResponseRequest", "ReportCompletedAsync", 13usize, |this| ((**(this as *const *const IAppointmentCalendarUpdateMeetingResponseRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarUpdateMeetingResponseRequest", "ReportFailedAsync", 14usize, |this| ((**(this as *const *const IAppointmentCalendarUpdateMeetingResponseRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppointmentCalendarLocalId,
+            AppointmentLocalId,
+            AppointmentOriginalStartTime,
+            Response,
+            Subject,
+            Comment,
+            SendUpdate,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendarUpdateMeetingResponseRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentCalendarUpdateMeetingResponseRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarUpdateMeetingResponseRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IAppointmentCalendarUpdateMeetingResponseRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarUpdateMeetingResponseRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IAppointmentCalendarUpdateMeetingResponseRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendarUpdateMeetingResponseRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentDataProviderConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SyncRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentDataProviderConnection", "SyncRequested", 6usize, |this| ((**(this as *const *const IAppointmentDataProviderConnection_Vtbl)).SyncRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSyncRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentDataProviderConnection", "RemoveSyncRequested", 7usize, |this| ((**(this as *const *const IAppointmentDataProviderConnection_Vtbl)).RemoveSyncRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOrUpdateAppointmentRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentDataProviderConnection", "CreateOrUpdateAppointmentRequested", 8usize, |this| ((**(this as *const *const IAppointmentDataProviderConnection_Vtbl)).CreateOrUpdateAppointmentRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCreateOrUpdateAppointmentRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentDataProviderConnection", "RemoveCreateOrUpdateAppointmentRequested", 9usize, |this| ((**(this as *const *const IAppointmentDataProviderConnection_Vtbl)).RemoveCreateOrUpdateAppointmentRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelMeetingRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentDataProviderConnection", "CancelMeetingRequested", 10usize, |this| ((**(this as *const *const IAppointmentDataProviderConnection_Vtbl)).CancelMeetingRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCancelMeetingRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentDataProviderConnection", "RemoveCancelMeetingRequested", 11usize, |this| ((**(this as *const *const IAppointmentDataProviderConnection_Vtbl)).RemoveCancelMeetingRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForwardMeetingRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentDataProviderConnection", "ForwardMeetingRequested", 12usize, |this| ((**(this as *const *const IAppointmentDataProviderConnection_Vtbl)).ForwardMeetingRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveForwardMeetingRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentDataProviderConnection", "RemoveForwardMeetingRequested", 13usize, |this| ((**(this as *const *const IAppointmentDataProviderConnection_Vtbl)).RemoveForwardMeetingRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProposeNewTimeForMeetingRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentDataProviderConnection", "ProposeNewTimeForMeetingRequested", 14usize, |this| ((**(this as *const *const IAppointmentDataProviderConnection_Vtbl)).ProposeNewTimeForMeetingRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProposeNewTimeForMeetingRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentDataProviderConnection", "RemoveProposeNewTimeForMeetingRequested", 15usize, |this| ((**(this as *const *const IAppointmentDataProviderConnection_Vtbl)).RemoveProposeNewTimeForMeetingRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateMeetingResponseRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentDataProviderConnection", "UpdateMeetingResponseRequested", 16usize, |this| ((**(this as *const *const IAppointmentDataProviderConnection_Vtbl)).UpdateMeetingResponseRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUpdateMeetingResponseRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentDataProviderConnection", "RemoveUpdateMeetingResponseRequested", 17usize, |this| ((**(this as *const *const IAppointmentDataProviderConnection_Vtbl)).RemoveUpdateMeetingResponseRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentDataProviderConnection", "Start", 18usize, |this| ((**(this as *const *const IAppointmentDataProviderConnection_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SyncRequested,
+            RemoveSyncRequested,
+            CreateOrUpdateAppointmentRequested,
+            RemoveCreateOrUpdateAppointmentRequested,
+            CancelMeetingRequested,
+            RemoveCancelMeetingRequested,
+            ForwardMeetingRequested,
+            RemoveForwardMeetingRequested,
+            ProposeNewTimeForMeetingRequested,
+            RemoveProposeNewTimeForMeetingRequested,
+            UpdateMeetingResponseRequested,
+            RemoveUpdateMeetingResponseRequested,
+            Start,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentDataProviderConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentDataProviderTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentDataProviderTriggerDetails", "Connection", 6usize, |this| ((**(this as *const *const IAppointmentDataProviderTriggerDetails_Vtbl)).Connection)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Connection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentDataProviderTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
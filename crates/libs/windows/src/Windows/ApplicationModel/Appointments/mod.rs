@@ -2678,6 +2678,7 @@ impl AppointmentBusyStatus {
 impl windows_core::TypeKind for AppointmentBusyStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppointmentBusyStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppointmentBusyStatus").field(&self.0).finish()
@@ -2698,6 +2699,7 @@ impl AppointmentCalendarOtherAppReadAccess {
 impl windows_core::TypeKind for AppointmentCalendarOtherAppReadAccess {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppointmentCalendarOtherAppReadAccess {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppointmentCalendarOtherAppReadAccess").field(&self.0).finish()
@@ -2717,6 +2719,7 @@ impl AppointmentCalendarOtherAppWriteAccess {
 impl windows_core::TypeKind for AppointmentCalendarOtherAppWriteAccess {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppointmentCalendarOtherAppWriteAccess {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppointmentCalendarOtherAppWriteAccess").field(&self.0).finish()
@@ -2740,6 +2743,7 @@ impl AppointmentCalendarSyncStatus {
 impl windows_core::TypeKind for AppointmentCalendarSyncStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppointmentCalendarSyncStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppointmentCalendarSyncStatus").field(&self.0).finish()
@@ -2759,6 +2763,7 @@ impl AppointmentConflictType {
 impl windows_core::TypeKind for AppointmentConflictType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppointmentConflictType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppointmentConflictType").field(&self.0).finish()
@@ -2783,6 +2788,7 @@ impl AppointmentDaysOfWeek {
 impl windows_core::TypeKind for AppointmentDaysOfWeek {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppointmentDaysOfWeek {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppointmentDaysOfWeek").field(&self.0).finish()
@@ -2834,6 +2840,7 @@ impl AppointmentDetailsKind {
 impl windows_core::TypeKind for AppointmentDetailsKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppointmentDetailsKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppointmentDetailsKind").field(&self.0).finish()
@@ -2855,6 +2862,7 @@ impl AppointmentParticipantResponse {
 impl windows_core::TypeKind for AppointmentParticipantResponse {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppointmentParticipantResponse {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppointmentParticipantResponse").field(&self.0).finish()
@@ -2874,6 +2882,7 @@ impl AppointmentParticipantRole {
 impl windows_core::TypeKind for AppointmentParticipantRole {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppointmentParticipantRole {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppointmentParticipantRole").field(&self.0).finish()
@@ -2896,6 +2905,7 @@ impl AppointmentRecurrenceUnit {
 impl windows_core::TypeKind for AppointmentRecurrenceUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppointmentRecurrenceUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppointmentRecurrenceUnit").field(&self.0).finish()
@@ -2914,6 +2924,7 @@ impl AppointmentSensitivity {
 impl windows_core::TypeKind for AppointmentSensitivity {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppointmentSensitivity {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppointmentSensitivity").field(&self.0).finish()
@@ -2933,6 +2944,7 @@ impl AppointmentStoreAccessType {
 impl windows_core::TypeKind for AppointmentStoreAccessType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppointmentStoreAccessType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppointmentStoreAccessType").field(&self.0).finish()
@@ -2956,6 +2968,7 @@ impl AppointmentStoreChangeType {
 impl windows_core::TypeKind for AppointmentStoreChangeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppointmentStoreChangeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppointmentStoreChangeType").field(&self.0).finish()
@@ -2974,6 +2987,7 @@ impl AppointmentSummaryCardView {
 impl windows_core::TypeKind for AppointmentSummaryCardView {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppointmentSummaryCardView {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppointmentSummaryCardView").field(&self.0).finish()
@@ -2995,6 +3009,7 @@ impl AppointmentWeekOfMonth {
 impl windows_core::TypeKind for AppointmentWeekOfMonth {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppointmentWeekOfMonth {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppointmentWeekOfMonth").field(&self.0).finish()
@@ -3013,6 +3028,7 @@ impl FindAppointmentCalendarsOptions {
 impl windows_core::TypeKind for FindAppointmentCalendarsOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FindAppointmentCalendarsOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FindAppointmentCalendarsOptions").field(&self.0).finish()
@@ -3065,6 +3081,7 @@ impl RecurrenceType {
 impl windows_core::TypeKind for RecurrenceType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RecurrenceType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RecurrenceType").field(&self.0).finish()
@@ -3075,3 +3092,5 @@ impl windows_core::RuntimeType for RecurrenceType {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1422 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppointment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "StartTime", 6usize, |this| ((**(this as *const *const IAppointment_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "SetStartTime", 7usize, |this| ((**(this as *const *const IAppointment_Vtbl)).SetStartTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "Duration", 8usize, |this| ((**(this as *const *const IAppointment_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "SetDuration", 9usize, |this| ((**(this as *const *const IAppointment_Vtbl)).SetDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Location(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "Location", 10usize, |this| ((**(this as *const *const IAppointment_Vtbl)).Location)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocation(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "SetLocation", 11usize, |this| ((**(this as *const *const IAppointment_Vtbl)).SetLocation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "Subject", 12usize, |this| ((**(this as *const *const IAppointment_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubject(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "SetSubject", 13usize, |this| ((**(this as *const *const IAppointment_Vtbl)).SetSubject)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Details(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "Details", 14usize, |this| ((**(this as *const *const IAppointment_Vtbl)).Details)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDetails(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "SetDetails", 15usize, |this| ((**(this as *const *const IAppointment_Vtbl)).SetDetails)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reminder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "Reminder", 16usize, |this| ((**(this as *const *const IAppointment_Vtbl)).Reminder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReminder(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "SetReminder", 17usize, |this| ((**(this as *const *const IAppointment_Vtbl)).SetReminder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Organizer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "Organizer", 18usize, |this| ((**(this as *const *const IAppointment_Vtbl)).Organizer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOrganizer(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "SetOrganizer", 19usize, |this| ((**(this as *const *const IAppointment_Vtbl)).SetOrganizer)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Invitees(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "Invitees", 20usize, |this| ((**(this as *const *const IAppointment_Vtbl)).Invitees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Recurrence(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "Recurrence", 21usize, |this| ((**(this as *const *const IAppointment_Vtbl)).Recurrence)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecurrence(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "SetRecurrence", 22usize, |this| ((**(this as *const *const IAppointment_Vtbl)).SetRecurrence)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BusyStatus(this: *mut core::ffi::c_void, result__: *mut AppointmentBusyStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "BusyStatus", 23usize, |this| ((**(this as *const *const IAppointment_Vtbl)).BusyStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBusyStatus(this: *mut core::ffi::c_void, value: AppointmentBusyStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "SetBusyStatus", 24usize, |this| ((**(this as *const *const IAppointment_Vtbl)).SetBusyStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllDay(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "AllDay", 25usize, |this| ((**(this as *const *const IAppointment_Vtbl)).AllDay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllDay(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "SetAllDay", 26usize, |this| ((**(this as *const *const IAppointment_Vtbl)).SetAllDay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sensitivity(this: *mut core::ffi::c_void, result__: *mut AppointmentSensitivity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "Sensitivity", 27usize, |this| ((**(this as *const *const IAppointment_Vtbl)).Sensitivity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSensitivity(this: *mut core::ffi::c_void, value: AppointmentSensitivity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "SetSensitivity", 28usize, |this| ((**(this as *const *const IAppointment_Vtbl)).SetSensitivity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "Uri", 29usize, |this| ((**(this as *const *const IAppointment_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment", "SetUri", 30usize, |this| ((**(this as *const *const IAppointment_Vtbl)).SetUri)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartTime,
+            SetStartTime,
+            Duration,
+            SetDuration,
+            Location,
+            SetLocation,
+            Subject,
+            SetSubject,
+            Details,
+            SetDetails,
+            Reminder,
+            SetReminder,
+            Organizer,
+            SetOrganizer,
+            #[cfg(feature = "Foundation_Collections")]
+            Invitees,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Invitees: 0,
+            Recurrence,
+            SetRecurrence,
+            BusyStatus,
+            SetBusyStatus,
+            AllDay,
+            SetAllDay,
+            Sensitivity,
+            SetSensitivity,
+            Uri,
+            SetUri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppointment2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LocalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "LocalId", 6usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).LocalId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CalendarId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "CalendarId", 7usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).CalendarId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoamingId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "RoamingId", 8usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).RoamingId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoamingId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "SetRoamingId", 9usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).SetRoamingId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OriginalStartTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "OriginalStartTime", 10usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).OriginalStartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsResponseRequested(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "IsResponseRequested", 11usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).IsResponseRequested)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsResponseRequested(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "SetIsResponseRequested", 12usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).SetIsResponseRequested)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowNewTimeProposal(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "AllowNewTimeProposal", 13usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).AllowNewTimeProposal)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowNewTimeProposal(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "SetAllowNewTimeProposal", 14usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).SetAllowNewTimeProposal)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnlineMeetingLink(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "OnlineMeetingLink", 15usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).OnlineMeetingLink)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOnlineMeetingLink(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "SetOnlineMeetingLink", 16usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).SetOnlineMeetingLink)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplyTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "ReplyTime", 17usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).ReplyTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReplyTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "SetReplyTime", 18usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).SetReplyTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserResponse(this: *mut core::ffi::c_void, result__: *mut AppointmentParticipantResponse) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "UserResponse", 19usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).UserResponse)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserResponse(this: *mut core::ffi::c_void, value: AppointmentParticipantResponse) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "SetUserResponse", 20usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).SetUserResponse)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasInvitees(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "HasInvitees", 21usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).HasInvitees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCanceledMeeting(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "IsCanceledMeeting", 22usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).IsCanceledMeeting)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCanceledMeeting(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "SetIsCanceledMeeting", 23usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).SetIsCanceledMeeting)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOrganizedByUser(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "IsOrganizedByUser", 24usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).IsOrganizedByUser)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsOrganizedByUser(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment2", "SetIsOrganizedByUser", 25usize, |this| ((**(this as *const *const IAppointment2_Vtbl)).SetIsOrganizedByUser)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LocalId,
+            CalendarId,
+            RoamingId,
+            SetRoamingId,
+            OriginalStartTime,
+            IsResponseRequested,
+            SetIsResponseRequested,
+            AllowNewTimeProposal,
+            SetAllowNewTimeProposal,
+            OnlineMeetingLink,
+            SetOnlineMeetingLink,
+            ReplyTime,
+            SetReplyTime,
+            UserResponse,
+            SetUserResponse,
+            HasInvitees,
+            IsCanceledMeeting,
+            SetIsCanceledMeeting,
+            IsOrganizedByUser,
+            SetIsOrganizedByUser,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointment2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppointment3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeNumber(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment3", "ChangeNumber", 6usize, |this| ((**(this as *const *const IAppointment3_Vtbl)).ChangeNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteChangeNumber(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment3", "RemoteChangeNumber", 7usize, |this| ((**(this as *const *const IAppointment3_Vtbl)).RemoteChangeNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteChangeNumber(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment3", "SetRemoteChangeNumber", 8usize, |this| ((**(this as *const *const IAppointment3_Vtbl)).SetRemoteChangeNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetailsKind(this: *mut core::ffi::c_void, result__: *mut AppointmentDetailsKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment3", "DetailsKind", 9usize, |this| ((**(this as *const *const IAppointment3_Vtbl)).DetailsKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDetailsKind(this: *mut core::ffi::c_void, value: AppointmentDetailsKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointment3", "SetDetailsKind", 10usize, |this| ((**(this as *const *const IAppointment3_Vtbl)).SetDetailsKind)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChangeNumber,
+            RemoteChangeNumber,
+            SetRemoteChangeNumber,
+            DetailsKind,
+            SetDetailsKind,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointment3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "UI"))]
+impl windows_core::imp::ProxyVtable for IAppointmentCalendar_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn DisplayColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "DisplayColor", 6usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).DisplayColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "DisplayName", 7usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "SetDisplayName", 8usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "LocalId", 9usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).LocalId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHidden(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "IsHidden", 10usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).IsHidden)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherAppReadAccess(this: *mut core::ffi::c_void, result__: *mut AppointmentCalendarOtherAppReadAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "OtherAppReadAccess", 11usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).OtherAppReadAccess)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOtherAppReadAccess(this: *mut core::ffi::c_void, value: AppointmentCalendarOtherAppReadAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "SetOtherAppReadAccess", 12usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).SetOtherAppReadAccess)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherAppWriteAccess(this: *mut core::ffi::c_void, result__: *mut AppointmentCalendarOtherAppWriteAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "OtherAppWriteAccess", 13usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).OtherAppWriteAccess)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOtherAppWriteAccess(this: *mut core::ffi::c_void, value: AppointmentCalendarOtherAppWriteAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "SetOtherAppWriteAccess", 14usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).SetOtherAppWriteAccess)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "SourceDisplayName", 15usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).SourceDisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SummaryCardView(this: *mut core::ffi::c_void, result__: *mut AppointmentSummaryCardView) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "SummaryCardView", 16usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).SummaryCardView)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSummaryCardView(this: *mut core::ffi::c_void, value: AppointmentSummaryCardView) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "SetSummaryCardView", 17usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).SetSummaryCardView)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAppointmentsAsync(this: *mut core::ffi::c_void, rangestart: super::super::Foundation::DateTime, rangelength: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "FindAppointmentsAsync", 18usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).FindAppointmentsAsync)(this, rangestart, rangelength, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAppointmentsAsyncWithOptions(this: *mut core::ffi::c_void, rangestart: super::super::Foundation::DateTime, rangelength: super::super::Foundation::TimeSpan, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "FindAppointmentsAsyncWithOptions", 19usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).FindAppointmentsAsyncWithOptions)(this, rangestart, rangelength, options, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindExceptionsFromMasterAsync(this: *mut core::ffi::c_void, masterlocalid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "FindExceptionsFromMasterAsync", 20usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).FindExceptionsFromMasterAsync)(this, masterlocalid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllInstancesAsync(this: *mut core::ffi::c_void, masterlocalid: core::mem::MaybeUninit<windows_core::HSTRING>, rangestart: super::super::Foundation::DateTime, rangelength: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "FindAllInstancesAsync", 21usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).FindAllInstancesAsync)(this, masterlocalid, rangestart, rangelength, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllInstancesAsyncWithOptions(this: *mut core::ffi::c_void, masterlocalid: core::mem::MaybeUninit<windows_core::HSTRING>, rangestart: super::super::Foundation::DateTime, rangelength: super::super::Foundation::TimeSpan, poptions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "FindAllInstancesAsyncWithOptions", 22usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).FindAllInstancesAsyncWithOptions)(this, masterlocalid, rangestart, rangelength, poptions, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppointmentAsync(this: *mut core::ffi::c_void, localid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "GetAppointmentAsync", 23usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).GetAppointmentAsync)(this, localid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppointmentInstanceAsync(this: *mut core::ffi::c_void, localid: core::mem::MaybeUninit<windows_core::HSTRING>, instancestarttime: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "GetAppointmentInstanceAsync", 24usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).GetAppointmentInstanceAsync)(this, localid, instancestarttime, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindUnexpandedAppointmentsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "FindUnexpandedAppointmentsAsync", 25usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).FindUnexpandedAppointmentsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindUnexpandedAppointmentsAsyncWithOptions(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "FindUnexpandedAppointmentsAsyncWithOptions", 26usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).FindUnexpandedAppointmentsAsyncWithOptions)(this, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "DeleteAsync", 27usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).DeleteAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "SaveAsync", 28usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).SaveAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAppointmentAsync(this: *mut core::ffi::c_void, localid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "DeleteAppointmentAsync", 29usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).DeleteAppointmentAsync)(this, localid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAppointmentInstanceAsync(this: *mut core::ffi::c_void, localid: core::mem::MaybeUninit<windows_core::HSTRING>, instancestarttime: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "DeleteAppointmentInstanceAsync", 30usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).DeleteAppointmentInstanceAsync)(this, localid, instancestarttime, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAppointmentAsync(this: *mut core::ffi::c_void, pappointment: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar", "SaveAppointmentAsync", 31usize, |this| ((**(this as *const *const IAppointmentCalendar_Vtbl)).SaveAppointmentAsync)(this, pappointment, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI")]
+            DisplayColor,
+            #[cfg(not(feature = "UI"))]
+            DisplayColor: 0,
+            DisplayName,
+            SetDisplayName,
+            LocalId,
+            IsHidden,
+            OtherAppReadAccess,
+            SetOtherAppReadAccess,
+            OtherAppWriteAccess,
+            SetOtherAppWriteAccess,
+            SourceDisplayName,
+            SummaryCardView,
+            SetSummaryCardView,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAppointmentsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAppointmentsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAppointmentsAsyncWithOptions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAppointmentsAsyncWithOptions: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindExceptionsFromMasterAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindExceptionsFromMasterAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllInstancesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllInstancesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllInstancesAsyncWithOptions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllInstancesAsyncWithOptions: 0,
+            GetAppointmentAsync,
+            GetAppointmentInstanceAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            FindUnexpandedAppointmentsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindUnexpandedAppointmentsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindUnexpandedAppointmentsAsyncWithOptions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindUnexpandedAppointmentsAsyncWithOptions: 0,
+            DeleteAsync,
+            SaveAsync,
+            DeleteAppointmentAsync,
+            DeleteAppointmentInstanceAsync,
+            SaveAppointmentAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendar as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "UI"))]
+impl windows_core::imp::ProxyVtable for IAppointmentCalendar2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SyncManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "SyncManager", 6usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).SyncManager)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "RemoteId", 7usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).RemoteId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "SetRemoteId", 8usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).SetRemoteId)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetDisplayColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "SetDisplayColor", 9usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).SetDisplayColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsHidden(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "SetIsHidden", 10usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).SetIsHidden)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserDataAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "UserDataAccountId", 11usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).UserDataAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanCreateOrUpdateAppointments(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "CanCreateOrUpdateAppointments", 12usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).CanCreateOrUpdateAppointments)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanCreateOrUpdateAppointments(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "SetCanCreateOrUpdateAppointments", 13usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).SetCanCreateOrUpdateAppointments)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanCancelMeetings(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "CanCancelMeetings", 14usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).CanCancelMeetings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanCancelMeetings(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "SetCanCancelMeetings", 15usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).SetCanCancelMeetings)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanForwardMeetings(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "CanForwardMeetings", 16usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).CanForwardMeetings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanForwardMeetings(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "SetCanForwardMeetings", 17usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).SetCanForwardMeetings)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanProposeNewTimeForMeetings(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "CanProposeNewTimeForMeetings", 18usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).CanProposeNewTimeForMeetings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanProposeNewTimeForMeetings(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "SetCanProposeNewTimeForMeetings", 19usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).SetCanProposeNewTimeForMeetings)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanUpdateMeetingResponses(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "CanUpdateMeetingResponses", 20usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).CanUpdateMeetingResponses)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanUpdateMeetingResponses(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "SetCanUpdateMeetingResponses", 21usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).SetCanUpdateMeetingResponses)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanNotifyInvitees(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "CanNotifyInvitees", 22usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).CanNotifyInvitees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanNotifyInvitees(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "SetCanNotifyInvitees", 23usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).SetCanNotifyInvitees)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MustNofityInvitees(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "MustNofityInvitees", 24usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).MustNofityInvitees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMustNofityInvitees(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "SetMustNofityInvitees", 25usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).SetMustNofityInvitees)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryCreateOrUpdateAppointmentAsync(this: *mut core::ffi::c_void, appointment: *mut core::ffi::c_void, notifyinvitees: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "TryCreateOrUpdateAppointmentAsync", 26usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).TryCreateOrUpdateAppointmentAsync)(this, appointment, notifyinvitees, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryCancelMeetingAsync(this: *mut core::ffi::c_void, meeting: *mut core::ffi::c_void, subject: core::mem::MaybeUninit<windows_core::HSTRING>, comment: core::mem::MaybeUninit<windows_core::HSTRING>, notifyinvitees: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "TryCancelMeetingAsync", 27usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).TryCancelMeetingAsync)(this, meeting, subject, comment, notifyinvitees, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TryForwardMeetingAsync(this: *mut core::ffi::c_void, meeting: *mut core::ffi::c_void, invitees: *mut core::ffi::c_void, subject: core::mem::MaybeUninit<windows_core::HSTRING>, forwardheader: core::mem::MaybeUninit<windows_core::HSTRING>, comment: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "TryForwardMeetingAsync", 28usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).TryForwardMeetingAsync)(this, meeting, invitees, subject, forwardheader, comment, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryProposeNewTimeForMeetingAsync(this: *mut core::ffi::c_void, meeting: *mut core::ffi::c_void, newstarttime: super::super::Foundation::DateTime, newduration: super::super::Foundation::TimeSpan, subject: core::mem::MaybeUninit<windows_core::HSTRING>, comment: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "TryProposeNewTimeForMeetingAsync", 29usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).TryProposeNewTimeForMeetingAsync)(this, meeting, newstarttime, newduration, subject, comment, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryUpdateMeetingResponseAsync(this: *mut core::ffi::c_void, meeting: *mut core::ffi::c_void, response: AppointmentParticipantResponse, subject: core::mem::MaybeUninit<windows_core::HSTRING>, comment: core::mem::MaybeUninit<windows_core::HSTRING>, sendupdate: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar2", "TryUpdateMeetingResponseAsync", 30usize, |this| ((**(this as *const *const IAppointmentCalendar2_Vtbl)).TryUpdateMeetingResponseAsync)(this, meeting, response, subject, comment, sendupdate, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SyncManager,
+            RemoteId,
+            SetRemoteId,
+            #[cfg(feature = "UI")]
+            SetDisplayColor,
+            #[cfg(not(feature = "UI"))]
+            SetDisplayColor: 0,
+            SetIsHidden,
+            UserDataAccountId,
+            CanCreateOrUpdateAppointments,
+            SetCanCreateOrUpdateAppointments,
+            CanCancelMeetings,
+            SetCanCancelMeetings,
+            CanForwardMeetings,
+            SetCanForwardMeetings,
+            CanProposeNewTimeForMeetings,
+            SetCanProposeNewTimeForMeetings,
+            CanUpdateMeetingResponses,
+            SetCanUpdateMeetingResponses,
+            CanNotifyInvitees,
+            SetCanNotifyInvitees,
+            MustNofityInvitees,
+            SetMustNofityInvitees,
+            TryCreateOrUpdateAppointmentAsync,
+            TryCancelMeetingAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            TryForwardMeetingAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TryForwardMeetingAsync: 0,
+            TryProposeNewTimeForMeetingAsync,
+            TryUpdateMeetingResponseAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendar2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentCalendar3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterSyncManagerAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendar3", "RegisterSyncManagerAsync", 6usize, |this| ((**(this as *const *const IAppointmentCalendar3_Vtbl)).RegisterSyncManagerAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterSyncManagerAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendar3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentCalendarSyncManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut AppointmentCalendarSyncStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarSyncManager", "Status", 6usize, |this| ((**(this as *const *const IAppointmentCalendarSyncManager_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastSuccessfulSyncTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarSyncManager", "LastSuccessfulSyncTime", 7usize, |this| ((**(this as *const *const IAppointmentCalendarSyncManager_Vtbl)).LastSuccessfulSyncTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastAttemptedSyncTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarSyncManager", "LastAttemptedSyncTime", 8usize, |this| ((**(this as *const *const IAppointmentCalendarSyncManager_Vtbl)).LastAttemptedSyncTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarSyncManager", "SyncAsync", 9usize, |this| ((**(this as *const *const IAppointmentCalendarSyncManager_Vtbl)).SyncAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarSyncManager", "SyncStatusChanged", 10usize, |this| ((**(this as *const *const IAppointmentCalendarSyncManager_Vtbl)).SyncStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSyncStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarSyncManager", "RemoveSyncStatusChanged", 11usize, |this| ((**(this as *const *const IAppointmentCalendarSyncManager_Vtbl)).RemoveSyncStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            LastSuccessfulSyncTime,
+            LastAttemptedSyncTime,
+            SyncAsync,
+            SyncStatusChanged,
+            RemoveSyncStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendarSyncManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentCalendarSyncManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, value: AppointmentCalendarSyncStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarSyncManager2", "SetStatus", 6usize, |this| ((**(this as *const *const IAppointmentCalendarSyncManager2_Vtbl)).SetStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastSuccessfulSyncTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarSyncManager2", "SetLastSuccessfulSyncTime", 7usize, |this| ((**(this as *const *const IAppointmentCalendarSyncManager2_Vtbl)).SetLastSuccessfulSyncTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastAttemptedSyncTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentCalendarSyncManager2", "SetLastAttemptedSyncTime", 8usize, |this| ((**(this as *const *const IAppointmentCalendarSyncManager2_Vtbl)).SetLastAttemptedSyncTime)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetStatus,
+            SetLastSuccessfulSyncTime,
+            SetLastAttemptedSyncTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentCalendarSyncManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentConflictResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut AppointmentConflictType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentConflictResult", "Type", 6usize, |this| ((**(this as *const *const IAppointmentConflictResult_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Date(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentConflictResult", "Date", 7usize, |this| ((**(this as *const *const IAppointmentConflictResult_Vtbl)).Date)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Type, Date }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentConflictResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppointmentException_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Appointment(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentException", "Appointment", 6usize, |this| ((**(this as *const *const IAppointmentException_Vtbl)).Appointment)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ExceptionProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentException", "ExceptionProperties", 7usize, |this| ((**(this as *const *const IAppointmentException_Vtbl)).ExceptionProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDeleted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentException", "IsDeleted", 8usize, |this| ((**(this as *const *const IAppointmentException_Vtbl)).IsDeleted)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Appointment,
+            #[cfg(feature = "Foundation_Collections")]
+            ExceptionProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ExceptionProperties: 0,
+            IsDeleted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentException as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentInvitee_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Role(this: *mut core::ffi::c_void, result__: *mut AppointmentParticipantRole) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentInvitee", "Role", 6usize, |this| ((**(this as *const *const IAppointmentInvitee_Vtbl)).Role)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRole(this: *mut core::ffi::c_void, value: AppointmentParticipantRole) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentInvitee", "SetRole", 7usize, |this| ((**(this as *const *const IAppointmentInvitee_Vtbl)).SetRole)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Response(this: *mut core::ffi::c_void, result__: *mut AppointmentParticipantResponse) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentInvitee", "Response", 8usize, |this| ((**(this as *const *const IAppointmentInvitee_Vtbl)).Response)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResponse(this: *mut core::ffi::c_void, value: AppointmentParticipantResponse) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentInvitee", "SetResponse", 9usize, |this| ((**(this as *const *const IAppointmentInvitee_Vtbl)).SetResponse)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Role, SetRole, Response, SetResponse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentInvitee as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "System", feature = "UI_Popups"))]
+impl windows_core::imp::ProxyVtable for IAppointmentManagerForUser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowAddAppointmentAsync(this: *mut core::ffi::c_void, appointment: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerForUser", "ShowAddAppointmentAsync", 6usize, |this| ((**(this as *const *const IAppointmentManagerForUser_Vtbl)).ShowAddAppointmentAsync)(this, appointment, selection, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowAddAppointmentWithPlacementAsync(this: *mut core::ffi::c_void, appointment: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerForUser", "ShowAddAppointmentWithPlacementAsync", 7usize, |this| ((**(this as *const *const IAppointmentManagerForUser_Vtbl)).ShowAddAppointmentWithPlacementAsync)(this, appointment, selection, preferredplacement, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowReplaceAppointmentAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, appointment: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerForUser", "ShowReplaceAppointmentAsync", 8usize, |this| ((**(this as *const *const IAppointmentManagerForUser_Vtbl)).ShowReplaceAppointmentAsync)(this, appointmentid, appointment, selection, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowReplaceAppointmentWithPlacementAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, appointment: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerForUser", "ShowReplaceAppointmentWithPlacementAsync", 9usize, |this| ((**(this as *const *const IAppointmentManagerForUser_Vtbl)).ShowReplaceAppointmentWithPlacementAsync)(this, appointmentid, appointment, selection, preferredplacement, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowReplaceAppointmentWithPlacementAndDateAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, appointment: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, instancestartdate: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerForUser", "ShowReplaceAppointmentWithPlacementAndDateAsync", 10usize, |this| ((**(this as *const *const IAppointmentManagerForUser_Vtbl)).ShowReplaceAppointmentWithPlacementAndDateAsync)(this, appointmentid, appointment, selection, preferredplacement, instancestartdate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowRemoveAppointmentAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, selection: super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerForUser", "ShowRemoveAppointmentAsync", 11usize, |this| ((**(this as *const *const IAppointmentManagerForUser_Vtbl)).ShowRemoveAppointmentAsync)(this, appointmentid, selection, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowRemoveAppointmentWithPlacementAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerForUser", "ShowRemoveAppointmentWithPlacementAsync", 12usize, |this| ((**(this as *const *const IAppointmentManagerForUser_Vtbl)).ShowRemoveAppointmentWithPlacementAsync)(this, appointmentid, selection, preferredplacement, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowRemoveAppointmentWithPlacementAndDateAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, instancestartdate: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerForUser", "ShowRemoveAppointmentWithPlacementAndDateAsync", 13usize, |this| ((**(this as *const *const IAppointmentManagerForUser_Vtbl)).ShowRemoveAppointmentWithPlacementAndDateAsync)(this, appointmentid, selection, preferredplacement, instancestartdate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowTimeFrameAsync(this: *mut core::ffi::c_void, timetoshow: super::super::Foundation::DateTime, duration: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerForUser", "ShowTimeFrameAsync", 14usize, |this| ((**(this as *const *const IAppointmentManagerForUser_Vtbl)).ShowTimeFrameAsync)(this, timetoshow, duration, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAppointmentDetailsAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerForUser", "ShowAppointmentDetailsAsync", 15usize, |this| ((**(this as *const *const IAppointmentManagerForUser_Vtbl)).ShowAppointmentDetailsAsync)(this, appointmentid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAppointmentDetailsWithDateAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, instancestartdate: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerForUser", "ShowAppointmentDetailsWithDateAsync", 16usize, |this| ((**(this as *const *const IAppointmentManagerForUser_Vtbl)).ShowAppointmentDetailsWithDateAsync)(this, appointmentid, instancestartdate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowEditNewAppointmentAsync(this: *mut core::ffi::c_void, appointment: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerForUser", "ShowEditNewAppointmentAsync", 17usize, |this| ((**(this as *const *const IAppointmentManagerForUser_Vtbl)).ShowEditNewAppointmentAsync)(this, appointment, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, options: AppointmentStoreAccessType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerForUser", "RequestStoreAsync", 18usize, |this| ((**(this as *const *const IAppointmentManagerForUser_Vtbl)).RequestStoreAsync)(this, options, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerForUser", "User", 19usize, |this| ((**(this as *const *const IAppointmentManagerForUser_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ShowAddAppointmentAsync,
+            #[cfg(feature = "UI_Popups")]
+            ShowAddAppointmentWithPlacementAsync,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowAddAppointmentWithPlacementAsync: 0,
+            ShowReplaceAppointmentAsync,
+            #[cfg(feature = "UI_Popups")]
+            ShowReplaceAppointmentWithPlacementAsync,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowReplaceAppointmentWithPlacementAsync: 0,
+            #[cfg(feature = "UI_Popups")]
+            ShowReplaceAppointmentWithPlacementAndDateAsync,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowReplaceAppointmentWithPlacementAndDateAsync: 0,
+            ShowRemoveAppointmentAsync,
+            #[cfg(feature = "UI_Popups")]
+            ShowRemoveAppointmentWithPlacementAsync,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowRemoveAppointmentWithPlacementAsync: 0,
+            #[cfg(feature = "UI_Popups")]
+            ShowRemoveAppointmentWithPlacementAndDateAsync,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowRemoveAppointmentWithPlacementAndDateAsync: 0,
+            ShowTimeFrameAsync,
+            ShowAppointmentDetailsAsync,
+            ShowAppointmentDetailsWithDateAsync,
+            ShowEditNewAppointmentAsync,
+            RequestStoreAsync,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentManagerForUser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Popups")]
+impl windows_core::imp::ProxyVtable for IAppointmentManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowAddAppointmentAsync(this: *mut core::ffi::c_void, appointment: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerStatics", "ShowAddAppointmentAsync", 6usize, |this| ((**(this as *const *const IAppointmentManagerStatics_Vtbl)).ShowAddAppointmentAsync)(this, appointment, selection, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowAddAppointmentWithPlacementAsync(this: *mut core::ffi::c_void, appointment: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerStatics", "ShowAddAppointmentWithPlacementAsync", 7usize, |this| ((**(this as *const *const IAppointmentManagerStatics_Vtbl)).ShowAddAppointmentWithPlacementAsync)(this, appointment, selection, preferredplacement, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowReplaceAppointmentAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, appointment: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerStatics", "ShowReplaceAppointmentAsync", 8usize, |this| ((**(this as *const *const IAppointmentManagerStatics_Vtbl)).ShowReplaceAppointmentAsync)(this, appointmentid, appointment, selection, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowReplaceAppointmentWithPlacementAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, appointment: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerStatics", "ShowReplaceAppointmentWithPlacementAsync", 9usize, |this| ((**(this as *const *const IAppointmentManagerStatics_Vtbl)).ShowReplaceAppointmentWithPlacementAsync)(this, appointmentid, appointment, selection, preferredplacement, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowReplaceAppointmentWithPlacementAndDateAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, appointment: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, instancestartdate: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerStatics", "ShowReplaceAppointmentWithPlacementAndDateAsync", 10usize, |this| ((**(this as *const *const IAppointmentManagerStatics_Vtbl)).ShowReplaceAppointmentWithPlacementAndDateAsync)(this, appointmentid, appointment, selection, preferredplacement, instancestartdate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowRemoveAppointmentAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, selection: super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerStatics", "ShowRemoveAppointmentAsync", 11usize, |this| ((**(this as *const *const IAppointmentManagerStatics_Vtbl)).ShowRemoveAppointmentAsync)(this, appointmentid, selection, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowRemoveAppointmentWithPlacementAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerStatics", "ShowRemoveAppointmentWithPlacementAsync", 12usize, |this| ((**(this as *const *const IAppointmentManagerStatics_Vtbl)).ShowRemoveAppointmentWithPlacementAsync)(this, appointmentid, selection, preferredplacement, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowRemoveAppointmentWithPlacementAndDateAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, instancestartdate: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerStatics", "ShowRemoveAppointmentWithPlacementAndDateAsync", 13usize, |this| ((**(this as *const *const IAppointmentManagerStatics_Vtbl)).ShowRemoveAppointmentWithPlacementAndDateAsync)(this, appointmentid, selection, preferredplacement, instancestartdate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowTimeFrameAsync(this: *mut core::ffi::c_void, timetoshow: super::super::Foundation::DateTime, duration: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerStatics", "ShowTimeFrameAsync", 14usize, |this| ((**(this as *const *const IAppointmentManagerStatics_Vtbl)).ShowTimeFrameAsync)(this, timetoshow, duration, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ShowAddAppointmentAsync,
+            #[cfg(feature = "UI_Popups")]
+            ShowAddAppointmentWithPlacementAsync,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowAddAppointmentWithPlacementAsync: 0,
+            ShowReplaceAppointmentAsync,
+            #[cfg(feature = "UI_Popups")]
+            ShowReplaceAppointmentWithPlacementAsync,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowReplaceAppointmentWithPlacementAsync: 0,
+            #[cfg(feature = "UI_Popups")]
+            ShowReplaceAppointmentWithPlacementAndDateAsync,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowReplaceAppointmentWithPlacementAndDateAsync: 0,
+            ShowRemoveAppointmentAsync,
+            #[cfg(feature = "UI_Popups")]
+            ShowRemoveAppointmentWithPlacementAsync,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowRemoveAppointmentWithPlacementAsync: 0,
+            #[cfg(feature = "UI_Popups")]
+            ShowRemoveAppointmentWithPlacementAndDateAsync,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowRemoveAppointmentWithPlacementAndDateAsync: 0,
+            ShowTimeFrameAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowAppointmentDetailsAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerStatics2", "ShowAppointmentDetailsAsync", 6usize, |this| ((**(this as *const *const IAppointmentManagerStatics2_Vtbl)).ShowAppointmentDetailsAsync)(this, appointmentid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAppointmentDetailsWithDateAsync(this: *mut core::ffi::c_void, appointmentid: core::mem::MaybeUninit<windows_core::HSTRING>, instancestartdate: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerStatics2", "ShowAppointmentDetailsWithDateAsync", 7usize, |this| ((**(this as *const *const IAppointmentManagerStatics2_Vtbl)).ShowAppointmentDetailsWithDateAsync)(this, appointmentid, instancestartdate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowEditNewAppointmentAsync(this: *mut core::ffi::c_void, appointment: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerStatics2", "ShowEditNewAppointmentAsync", 8usize, |this| ((**(this as *const *const IAppointmentManagerStatics2_Vtbl)).ShowEditNewAppointmentAsync)(this, appointment, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, options: AppointmentStoreAccessType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerStatics2", "RequestStoreAsync", 9usize, |this| ((**(this as *const *const IAppointmentManagerStatics2_Vtbl)).RequestStoreAsync)(this, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ShowAppointmentDetailsAsync,
+            ShowAppointmentDetailsWithDateAsync,
+            ShowEditNewAppointmentAsync,
+            RequestStoreAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IAppointmentManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentManagerStatics3", "GetForUser", 6usize, |this| ((**(this as *const *const IAppointmentManagerStatics3_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentParticipant_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentParticipant", "DisplayName", 6usize, |this| ((**(this as *const *const IAppointmentParticipant_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentParticipant", "SetDisplayName", 7usize, |this| ((**(this as *const *const IAppointmentParticipant_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentParticipant", "Address", 8usize, |this| ((**(this as *const *const IAppointmentParticipant_Vtbl)).Address)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAddress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentParticipant", "SetAddress", 9usize, |this| ((**(this as *const *const IAppointmentParticipant_Vtbl)).SetAddress)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayName, SetDisplayName, Address, SetAddress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentParticipant as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppointmentPropertiesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "Subject", 6usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Location(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "Location", 7usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).Location)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "StartTime", 8usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "Duration", 9usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reminder(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "Reminder", 10usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).Reminder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BusyStatus(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "BusyStatus", 11usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).BusyStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sensitivity(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "Sensitivity", 12usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).Sensitivity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OriginalStartTime(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "OriginalStartTime", 13usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).OriginalStartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsResponseRequested(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "IsResponseRequested", 14usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).IsResponseRequested)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowNewTimeProposal(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "AllowNewTimeProposal", 15usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).AllowNewTimeProposal)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllDay(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "AllDay", 16usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).AllDay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Details(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "Details", 17usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).Details)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnlineMeetingLink(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "OnlineMeetingLink", 18usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).OnlineMeetingLink)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplyTime(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "ReplyTime", 19usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).ReplyTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Organizer(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "Organizer", 20usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).Organizer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserResponse(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "UserResponse", 21usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).UserResponse)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasInvitees(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "HasInvitees", 22usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).HasInvitees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCanceledMeeting(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "IsCanceledMeeting", 23usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).IsCanceledMeeting)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOrganizedByUser(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "IsOrganizedByUser", 24usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).IsOrganizedByUser)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Recurrence(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "Recurrence", 25usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).Recurrence)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "Uri", 26usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invitees(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "Invitees", 27usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).Invitees)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DefaultProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics", "DefaultProperties", 28usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics_Vtbl)).DefaultProperties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Subject,
+            Location,
+            StartTime,
+            Duration,
+            Reminder,
+            BusyStatus,
+            Sensitivity,
+            OriginalStartTime,
+            IsResponseRequested,
+            AllowNewTimeProposal,
+            AllDay,
+            Details,
+            OnlineMeetingLink,
+            ReplyTime,
+            Organizer,
+            UserResponse,
+            HasInvitees,
+            IsCanceledMeeting,
+            IsOrganizedByUser,
+            Recurrence,
+            Uri,
+            Invitees,
+            #[cfg(feature = "Foundation_Collections")]
+            DefaultProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DefaultProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentPropertiesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppointmentPropertiesStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics2", "ChangeNumber", 6usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics2_Vtbl)).ChangeNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteChangeNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics2", "RemoteChangeNumber", 7usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics2_Vtbl)).RemoteChangeNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetailsKind(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentPropertiesStatics2", "DetailsKind", 8usize, |this| ((**(this as *const *const IAppointmentPropertiesStatics2_Vtbl)).DetailsKind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ChangeNumber, RemoteChangeNumber, DetailsKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentPropertiesStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentRecurrence_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Unit(this: *mut core::ffi::c_void, result__: *mut AppointmentRecurrenceUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "Unit", 6usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).Unit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnit(this: *mut core::ffi::c_void, value: AppointmentRecurrenceUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "SetUnit", 7usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).SetUnit)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Occurrences(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "Occurrences", 8usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).Occurrences)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOccurrences(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "SetOccurrences", 9usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).SetOccurrences)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Until(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "Until", 10usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).Until)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUntil(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "SetUntil", 11usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).SetUntil)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Interval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "Interval", 12usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).Interval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterval(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "SetInterval", 13usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).SetInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DaysOfWeek(this: *mut core::ffi::c_void, result__: *mut AppointmentDaysOfWeek) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "DaysOfWeek", 14usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).DaysOfWeek)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDaysOfWeek(this: *mut core::ffi::c_void, value: AppointmentDaysOfWeek) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "SetDaysOfWeek", 15usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).SetDaysOfWeek)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WeekOfMonth(this: *mut core::ffi::c_void, result__: *mut AppointmentWeekOfMonth) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "WeekOfMonth", 16usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).WeekOfMonth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWeekOfMonth(this: *mut core::ffi::c_void, value: AppointmentWeekOfMonth) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "SetWeekOfMonth", 17usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).SetWeekOfMonth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Month(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "Month", 18usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).Month)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMonth(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "SetMonth", 19usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).SetMonth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Day(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "Day", 20usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).Day)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDay(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence", "SetDay", 21usize, |this| ((**(this as *const *const IAppointmentRecurrence_Vtbl)).SetDay)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Unit,
+            SetUnit,
+            Occurrences,
+            SetOccurrences,
+            Until,
+            SetUntil,
+            Interval,
+            SetInterval,
+            DaysOfWeek,
+            SetDaysOfWeek,
+            WeekOfMonth,
+            SetWeekOfMonth,
+            Month,
+            SetMonth,
+            Day,
+            SetDay,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentRecurrence as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentRecurrence2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RecurrenceType(this: *mut core::ffi::c_void, result__: *mut RecurrenceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence2", "RecurrenceType", 6usize, |this| ((**(this as *const *const IAppointmentRecurrence2_Vtbl)).RecurrenceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeZone(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence2", "TimeZone", 7usize, |this| ((**(this as *const *const IAppointmentRecurrence2_Vtbl)).TimeZone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimeZone(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence2", "SetTimeZone", 8usize, |this| ((**(this as *const *const IAppointmentRecurrence2_Vtbl)).SetTimeZone)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RecurrenceType, TimeZone, SetTimeZone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentRecurrence2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentRecurrence3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CalendarIdentifier(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentRecurrence3", "CalendarIdentifier", 6usize, |this| ((**(this as *const *const IAppointmentRecurrence3_Vtbl)).CalendarIdentifier)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CalendarIdentifier }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentRecurrence3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "UI_Popups"))]
+impl windows_core::imp::ProxyVtable for IAppointmentStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeTracker(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "ChangeTracker", 6usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).ChangeTracker)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAppointmentCalendarAsync(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "CreateAppointmentCalendarAsync", 7usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).CreateAppointmentCalendarAsync)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppointmentCalendarAsync(this: *mut core::ffi::c_void, calendarid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "GetAppointmentCalendarAsync", 8usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).GetAppointmentCalendarAsync)(this, calendarid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppointmentAsync(this: *mut core::ffi::c_void, localid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "GetAppointmentAsync", 9usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).GetAppointmentAsync)(this, localid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppointmentInstanceAsync(this: *mut core::ffi::c_void, localid: core::mem::MaybeUninit<windows_core::HSTRING>, instancestarttime: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "GetAppointmentInstanceAsync", 10usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).GetAppointmentInstanceAsync)(this, localid, instancestarttime, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAppointmentCalendarsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "FindAppointmentCalendarsAsync", 11usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).FindAppointmentCalendarsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAppointmentCalendarsAsyncWithOptions(this: *mut core::ffi::c_void, options: FindAppointmentCalendarsOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "FindAppointmentCalendarsAsyncWithOptions", 12usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).FindAppointmentCalendarsAsyncWithOptions)(this, options, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAppointmentsAsync(this: *mut core::ffi::c_void, rangestart: super::super::Foundation::DateTime, rangelength: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "FindAppointmentsAsync", 13usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).FindAppointmentsAsync)(this, rangestart, rangelength, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAppointmentsAsyncWithOptions(this: *mut core::ffi::c_void, rangestart: super::super::Foundation::DateTime, rangelength: super::super::Foundation::TimeSpan, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "FindAppointmentsAsyncWithOptions", 14usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).FindAppointmentsAsyncWithOptions)(this, rangestart, rangelength, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindConflictAsync(this: *mut core::ffi::c_void, appointment: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "FindConflictAsync", 15usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).FindConflictAsync)(this, appointment, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindConflictAsyncWithInstanceStart(this: *mut core::ffi::c_void, appointment: *mut core::ffi::c_void, instancestarttime: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "FindConflictAsyncWithInstanceStart", 16usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).FindConflictAsyncWithInstanceStart)(this, appointment, instancestarttime, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveAppointmentAsync(this: *mut core::ffi::c_void, appointment: *mut core::ffi::c_void, destinationcalendar: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "MoveAppointmentAsync", 17usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).MoveAppointmentAsync)(this, appointment, destinationcalendar, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAddAppointmentAsync(this: *mut core::ffi::c_void, appointment: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "ShowAddAppointmentAsync", 18usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).ShowAddAppointmentAsync)(this, appointment, selection, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowReplaceAppointmentAsync(this: *mut core::ffi::c_void, localid: core::mem::MaybeUninit<windows_core::HSTRING>, appointment: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "ShowReplaceAppointmentAsync", 19usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).ShowReplaceAppointmentAsync)(this, localid, appointment, selection, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowReplaceAppointmentWithPlacementAndDateAsync(this: *mut core::ffi::c_void, localid: core::mem::MaybeUninit<windows_core::HSTRING>, appointment: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, instancestartdate: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "ShowReplaceAppointmentWithPlacementAndDateAsync", 20usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).ShowReplaceAppointmentWithPlacementAndDateAsync)(this, localid, appointment, selection, preferredplacement, instancestartdate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowRemoveAppointmentAsync(this: *mut core::ffi::c_void, localid: core::mem::MaybeUninit<windows_core::HSTRING>, selection: super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "ShowRemoveAppointmentAsync", 21usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).ShowRemoveAppointmentAsync)(this, localid, selection, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowRemoveAppointmentWithPlacementAndDateAsync(this: *mut core::ffi::c_void, localid: core::mem::MaybeUninit<windows_core::HSTRING>, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, instancestartdate: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "ShowRemoveAppointmentWithPlacementAndDateAsync", 22usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).ShowRemoveAppointmentWithPlacementAndDateAsync)(this, localid, selection, preferredplacement, instancestartdate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAppointmentDetailsAsync(this: *mut core::ffi::c_void, localid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "ShowAppointmentDetailsAsync", 23usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).ShowAppointmentDetailsAsync)(this, localid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAppointmentDetailsWithDateAsync(this: *mut core::ffi::c_void, localid: core::mem::MaybeUninit<windows_core::HSTRING>, instancestartdate: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "ShowAppointmentDetailsWithDateAsync", 24usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).ShowAppointmentDetailsWithDateAsync)(this, localid, instancestartdate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowEditNewAppointmentAsync(this: *mut core::ffi::c_void, appointment: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "ShowEditNewAppointmentAsync", 25usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).ShowEditNewAppointmentAsync)(this, appointment, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindLocalIdsFromRoamingIdAsync(this: *mut core::ffi::c_void, roamingid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore", "FindLocalIdsFromRoamingIdAsync", 26usize, |this| ((**(this as *const *const IAppointmentStore_Vtbl)).FindLocalIdsFromRoamingIdAsync)(this, roamingid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChangeTracker,
+            CreateAppointmentCalendarAsync,
+            GetAppointmentCalendarAsync,
+            GetAppointmentAsync,
+            GetAppointmentInstanceAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAppointmentCalendarsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAppointmentCalendarsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAppointmentCalendarsAsyncWithOptions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAppointmentCalendarsAsyncWithOptions: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAppointmentsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAppointmentsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAppointmentsAsyncWithOptions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAppointmentsAsyncWithOptions: 0,
+            FindConflictAsync,
+            FindConflictAsyncWithInstanceStart,
+            MoveAppointmentAsync,
+            ShowAddAppointmentAsync,
+            ShowReplaceAppointmentAsync,
+            #[cfg(feature = "UI_Popups")]
+            ShowReplaceAppointmentWithPlacementAndDateAsync,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowReplaceAppointmentWithPlacementAndDateAsync: 0,
+            ShowRemoveAppointmentAsync,
+            #[cfg(feature = "UI_Popups")]
+            ShowRemoveAppointmentWithPlacementAndDateAsync,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowRemoveAppointmentWithPlacementAndDateAsync: 0,
+            ShowAppointmentDetailsAsync,
+            ShowAppointmentDetailsWithDateAsync,
+            ShowEditNewAppointmentAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            FindLocalIdsFromRoamingIdAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindLocalIdsFromRoamingIdAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "UI_Popups"))]
+impl windows_core::imp::ProxyVtable for IAppointmentStore2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StoreChanged(this: *mut core::ffi::c_void, phandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore2", "StoreChanged", 6usize, |this| ((**(this as *const *const IAppointmentStore2_Vtbl)).StoreChanged)(this, phandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStoreChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore2", "RemoveStoreChanged", 7usize, |this| ((**(this as *const *const IAppointmentStore2_Vtbl)).RemoveStoreChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAppointmentCalendarInAccountAsync(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, userdataaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore2", "CreateAppointmentCalendarInAccountAsync", 8usize, |this| ((**(this as *const *const IAppointmentStore2_Vtbl)).CreateAppointmentCalendarInAccountAsync)(this, name, userdataaccountid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StoreChanged,
+            RemoveStoreChanged,
+            CreateAppointmentCalendarInAccountAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentStore2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentStore3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetChangeTracker(this: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStore3", "GetChangeTracker", 6usize, |this| ((**(this as *const *const IAppointmentStore3_Vtbl)).GetChangeTracker)(this, identity, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetChangeTracker }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentStore3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentStoreChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Appointment(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStoreChange", "Appointment", 6usize, |this| ((**(this as *const *const IAppointmentStoreChange_Vtbl)).Appointment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeType(this: *mut core::ffi::c_void, result__: *mut AppointmentStoreChangeType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStoreChange", "ChangeType", 7usize, |this| ((**(this as *const *const IAppointmentStoreChange_Vtbl)).ChangeType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Appointment, ChangeType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentStoreChange as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentStoreChange2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppointmentCalendar(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStoreChange2", "AppointmentCalendar", 6usize, |this| ((**(this as *const *const IAppointmentStoreChange2_Vtbl)).AppointmentCalendar)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AppointmentCalendar }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentStoreChange2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppointmentStoreChangeReader_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadBatchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStoreChangeReader", "ReadBatchAsync", 6usize, |this| ((**(this as *const *const IAppointmentStoreChangeReader_Vtbl)).ReadBatchAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptChanges(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStoreChangeReader", "AcceptChanges", 7usize, |this| ((**(this as *const *const IAppointmentStoreChangeReader_Vtbl)).AcceptChanges)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptChangesThrough(this: *mut core::ffi::c_void, lastchangetoaccept: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStoreChangeReader", "AcceptChangesThrough", 8usize, |this| ((**(this as *const *const IAppointmentStoreChangeReader_Vtbl)).AcceptChangesThrough)(this, lastchangetoaccept), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadBatchAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadBatchAsync: 0,
+            AcceptChanges,
+            AcceptChangesThrough,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentStoreChangeReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentStoreChangeTracker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetChangeReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStoreChangeTracker", "GetChangeReader", 6usize, |this| ((**(this as *const *const IAppointmentStoreChangeTracker_Vtbl)).GetChangeReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStoreChangeTracker", "Enable", 7usize, |this| ((**(this as *const *const IAppointmentStoreChangeTracker_Vtbl)).Enable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStoreChangeTracker", "Reset", 8usize, |this| ((**(this as *const *const IAppointmentStoreChangeTracker_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetChangeReader, Enable, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentStoreChangeTracker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentStoreChangeTracker2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTracking(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStoreChangeTracker2", "IsTracking", 6usize, |this| ((**(this as *const *const IAppointmentStoreChangeTracker2_Vtbl)).IsTracking)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsTracking }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentStoreChangeTracker2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentStoreChangedDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStoreChangedDeferral", "Complete", 6usize, |this| ((**(this as *const *const IAppointmentStoreChangedDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentStoreChangedDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentStoreChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppointmentStoreChangedEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const IAppointmentStoreChangedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentStoreChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentStoreNotificationTriggerDetails_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentStoreNotificationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IFindAppointmentsOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CalendarIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindAppointmentsOptions", "CalendarIds", 6usize, |this| ((**(this as *const *const IFindAppointmentsOptions_Vtbl)).CalendarIds)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FetchProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindAppointmentsOptions", "FetchProperties", 7usize, |this| ((**(this as *const *const IFindAppointmentsOptions_Vtbl)).FetchProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeHidden(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindAppointmentsOptions", "IncludeHidden", 8usize, |this| ((**(this as *const *const IFindAppointmentsOptions_Vtbl)).IncludeHidden)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeHidden(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindAppointmentsOptions", "SetIncludeHidden", 9usize, |this| ((**(this as *const *const IFindAppointmentsOptions_Vtbl)).SetIncludeHidden)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindAppointmentsOptions", "MaxCount", 10usize, |this| ((**(this as *const *const IFindAppointmentsOptions_Vtbl)).MaxCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxCount(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindAppointmentsOptions", "SetMaxCount", 11usize, |this| ((**(this as *const *const IFindAppointmentsOptions_Vtbl)).SetMaxCount)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CalendarIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CalendarIds: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FetchProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FetchProperties: 0,
+            IncludeHidden,
+            SetIncludeHidden,
+            MaxCount,
+            SetMaxCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFindAppointmentsOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -4409,6 +4409,7 @@ impl AlarmAccessStatus {
 impl windows_core::TypeKind for AlarmAccessStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AlarmAccessStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AlarmAccessStatus").field(&self.0).finish()
@@ -4429,6 +4430,7 @@ impl ApplicationTriggerResult {
 impl windows_core::TypeKind for ApplicationTriggerResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ApplicationTriggerResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ApplicationTriggerResult").field(&self.0).finish()
@@ -4447,6 +4449,7 @@ impl BackgroundAccessRequestKind {
 impl windows_core::TypeKind for BackgroundAccessRequestKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BackgroundAccessRequestKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BackgroundAccessRequestKind").field(&self.0).finish()
@@ -4471,6 +4474,7 @@ impl BackgroundAccessStatus {
 impl windows_core::TypeKind for BackgroundAccessStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BackgroundAccessStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BackgroundAccessStatus").field(&self.0).finish()
@@ -4499,6 +4503,7 @@ impl BackgroundTaskCancellationReason {
 impl windows_core::TypeKind for BackgroundTaskCancellationReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BackgroundTaskCancellationReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BackgroundTaskCancellationReason").field(&self.0).finish()
@@ -4518,6 +4523,7 @@ impl BackgroundTaskThrottleCounter {
 impl windows_core::TypeKind for BackgroundTaskThrottleCounter {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BackgroundTaskThrottleCounter {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BackgroundTaskThrottleCounter").field(&self.0).finish()
@@ -4537,6 +4543,7 @@ impl BackgroundWorkCostValue {
 impl windows_core::TypeKind for BackgroundWorkCostValue {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BackgroundWorkCostValue {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BackgroundWorkCostValue").field(&self.0).finish()
@@ -4555,6 +4562,7 @@ impl CustomSystemEventTriggerRecurrence {
 impl windows_core::TypeKind for CustomSystemEventTriggerRecurrence {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CustomSystemEventTriggerRecurrence {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CustomSystemEventTriggerRecurrence").field(&self.0).finish()
@@ -4575,6 +4583,7 @@ impl DeviceTriggerResult {
 impl windows_core::TypeKind for DeviceTriggerResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceTriggerResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceTriggerResult").field(&self.0).finish()
@@ -4595,6 +4604,7 @@ impl EnergyUseLevel {
 impl windows_core::TypeKind for EnergyUseLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnergyUseLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnergyUseLevel").field(&self.0).finish()
@@ -4612,6 +4622,7 @@ impl LocationTriggerType {
 impl windows_core::TypeKind for LocationTriggerType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LocationTriggerType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LocationTriggerType").field(&self.0).finish()
@@ -4632,6 +4643,7 @@ impl MediaProcessingTriggerResult {
 impl windows_core::TypeKind for MediaProcessingTriggerResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaProcessingTriggerResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaProcessingTriggerResult").field(&self.0).finish()
@@ -4657,6 +4669,7 @@ impl SystemConditionType {
 impl windows_core::TypeKind for SystemConditionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SystemConditionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SystemConditionType").field(&self.0).finish()
@@ -4689,6 +4702,7 @@ impl SystemTriggerType {
 impl windows_core::TypeKind for SystemTriggerType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SystemTriggerType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SystemTriggerType").field(&self.0).finish()
@@ -4881,3 +4895,5 @@ pub struct BackgroundTaskProgressEventHandler_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1860 @@
+#[cfg(all(feature = "Devices_Sensors", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IActivitySensorTrigger_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Devices_Sensors", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn SubscribedActivities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorTrigger", "SubscribedActivities", 6usize, |this| ((**(this as *const *const IActivitySensorTrigger_Vtbl)).SubscribedActivities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorTrigger", "ReportInterval", 7usize, |this| ((**(this as *const *const IActivitySensorTrigger_Vtbl)).ReportInterval)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Sensors", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn SupportedActivities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorTrigger", "SupportedActivities", 8usize, |this| ((**(this as *const *const IActivitySensorTrigger_Vtbl)).SupportedActivities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorTrigger", "MinimumReportInterval", 9usize, |this| ((**(this as *const *const IActivitySensorTrigger_Vtbl)).MinimumReportInterval)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Devices_Sensors", feature = "Foundation_Collections"))]
+            SubscribedActivities,
+            #[cfg(not(all(feature = "Devices_Sensors", feature = "Foundation_Collections")))]
+            SubscribedActivities: 0,
+            ReportInterval,
+            #[cfg(all(feature = "Devices_Sensors", feature = "Foundation_Collections"))]
+            SupportedActivities,
+            #[cfg(not(all(feature = "Devices_Sensors", feature = "Foundation_Collections")))]
+            SupportedActivities: 0,
+            MinimumReportInterval,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivitySensorTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActivitySensorTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, reportintervalinmilliseconds: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const IActivitySensorTriggerFactory_Vtbl)).Create)(this, reportintervalinmilliseconds, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivitySensorTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAlarmApplicationManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlarmApplicationManagerStatics", "RequestAccessAsync", 6usize, |this| ((**(this as *const *const IAlarmApplicationManagerStatics_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccessStatus(this: *mut core::ffi::c_void, result__: *mut AlarmAccessStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlarmApplicationManagerStatics", "GetAccessStatus", 7usize, |this| ((**(this as *const *const IAlarmApplicationManagerStatics_Vtbl)).GetAccessStatus)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestAccessAsync, GetAccessStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAlarmApplicationManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProviderInfo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTrigger", "SetProviderInfo", 6usize, |this| ((**(this as *const *const IAppBroadcastTrigger_Vtbl)).SetProviderInfo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTrigger", "ProviderInfo", 7usize, |this| ((**(this as *const *const IAppBroadcastTrigger_Vtbl)).ProviderInfo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetProviderInfo, ProviderInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAppBroadcastTrigger(this: *mut core::ffi::c_void, providerkey: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTriggerFactory", "CreateAppBroadcastTrigger", 6usize, |this| ((**(this as *const *const IAppBroadcastTriggerFactory_Vtbl)).CreateAppBroadcastTrigger)(this, providerkey, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateAppBroadcastTrigger }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastTriggerProviderInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDisplayNameResource(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTriggerProviderInfo", "SetDisplayNameResource", 6usize, |this| ((**(this as *const *const IAppBroadcastTriggerProviderInfo_Vtbl)).SetDisplayNameResource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayNameResource(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTriggerProviderInfo", "DisplayNameResource", 7usize, |this| ((**(this as *const *const IAppBroadcastTriggerProviderInfo_Vtbl)).DisplayNameResource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogoResource(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTriggerProviderInfo", "SetLogoResource", 8usize, |this| ((**(this as *const *const IAppBroadcastTriggerProviderInfo_Vtbl)).SetLogoResource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogoResource(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTriggerProviderInfo", "LogoResource", 9usize, |this| ((**(this as *const *const IAppBroadcastTriggerProviderInfo_Vtbl)).LogoResource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoKeyFrameInterval(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTriggerProviderInfo", "SetVideoKeyFrameInterval", 10usize, |this| ((**(this as *const *const IAppBroadcastTriggerProviderInfo_Vtbl)).SetVideoKeyFrameInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoKeyFrameInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTriggerProviderInfo", "VideoKeyFrameInterval", 11usize, |this| ((**(this as *const *const IAppBroadcastTriggerProviderInfo_Vtbl)).VideoKeyFrameInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxVideoBitrate(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTriggerProviderInfo", "SetMaxVideoBitrate", 12usize, |this| ((**(this as *const *const IAppBroadcastTriggerProviderInfo_Vtbl)).SetMaxVideoBitrate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxVideoBitrate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTriggerProviderInfo", "MaxVideoBitrate", 13usize, |this| ((**(this as *const *const IAppBroadcastTriggerProviderInfo_Vtbl)).MaxVideoBitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxVideoWidth(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTriggerProviderInfo", "SetMaxVideoWidth", 14usize, |this| ((**(this as *const *const IAppBroadcastTriggerProviderInfo_Vtbl)).SetMaxVideoWidth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxVideoWidth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTriggerProviderInfo", "MaxVideoWidth", 15usize, |this| ((**(this as *const *const IAppBroadcastTriggerProviderInfo_Vtbl)).MaxVideoWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxVideoHeight(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTriggerProviderInfo", "SetMaxVideoHeight", 16usize, |this| ((**(this as *const *const IAppBroadcastTriggerProviderInfo_Vtbl)).SetMaxVideoHeight)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxVideoHeight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTriggerProviderInfo", "MaxVideoHeight", 17usize, |this| ((**(this as *const *const IAppBroadcastTriggerProviderInfo_Vtbl)).MaxVideoHeight)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDisplayNameResource,
+            DisplayNameResource,
+            SetLogoResource,
+            LogoResource,
+            SetVideoKeyFrameInterval,
+            VideoKeyFrameInterval,
+            SetMaxVideoBitrate,
+            MaxVideoBitrate,
+            SetMaxVideoWidth,
+            MaxVideoWidth,
+            SetMaxVideoHeight,
+            MaxVideoHeight,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastTriggerProviderInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IApplicationTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationTrigger", "RequestAsync", 6usize, |this| ((**(this as *const *const IApplicationTrigger_Vtbl)).RequestAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestAsyncWithArguments(this: *mut core::ffi::c_void, arguments: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationTrigger", "RequestAsyncWithArguments", 7usize, |this| ((**(this as *const *const IApplicationTrigger_Vtbl)).RequestAsyncWithArguments)(this, arguments, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestAsyncWithArguments,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestAsyncWithArguments: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IApplicationTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Arguments(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationTriggerDetails", "Arguments", 6usize, |this| ((**(this as *const *const IApplicationTriggerDetails_Vtbl)).Arguments)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Arguments,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Arguments: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppointmentStoreNotificationTrigger_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppointmentStoreNotificationTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCondition_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCondition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundExecutionManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundExecutionManagerStatics", "RequestAccessAsync", 6usize, |this| ((**(this as *const *const IBackgroundExecutionManagerStatics_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessForApplicationAsync(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundExecutionManagerStatics", "RequestAccessForApplicationAsync", 7usize, |this| ((**(this as *const *const IBackgroundExecutionManagerStatics_Vtbl)).RequestAccessForApplicationAsync)(this, applicationid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAccess(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundExecutionManagerStatics", "RemoveAccess", 8usize, |this| ((**(this as *const *const IBackgroundExecutionManagerStatics_Vtbl)).RemoveAccess)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAccessForApplication(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundExecutionManagerStatics", "RemoveAccessForApplication", 9usize, |this| ((**(this as *const *const IBackgroundExecutionManagerStatics_Vtbl)).RemoveAccessForApplication)(this, applicationid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccessStatus(this: *mut core::ffi::c_void, result__: *mut BackgroundAccessStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundExecutionManagerStatics", "GetAccessStatus", 10usize, |this| ((**(this as *const *const IBackgroundExecutionManagerStatics_Vtbl)).GetAccessStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccessStatusForApplication(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut BackgroundAccessStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundExecutionManagerStatics", "GetAccessStatusForApplication", 11usize, |this| ((**(this as *const *const IBackgroundExecutionManagerStatics_Vtbl)).GetAccessStatusForApplication)(this, applicationid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestAccessAsync,
+            RequestAccessForApplicationAsync,
+            RemoveAccess,
+            RemoveAccessForApplication,
+            GetAccessStatus,
+            GetAccessStatusForApplication,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundExecutionManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundExecutionManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAccessKindAsync(this: *mut core::ffi::c_void, requestedaccess: BackgroundAccessRequestKind, reason: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundExecutionManagerStatics2", "RequestAccessKindAsync", 6usize, |this| ((**(this as *const *const IBackgroundExecutionManagerStatics2_Vtbl)).RequestAccessKindAsync)(this, requestedaccess, reason, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestAccessKindAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundExecutionManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundExecutionManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAccessKindForModernStandbyAsync(this: *mut core::ffi::c_void, requestedaccess: BackgroundAccessRequestKind, reason: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundExecutionManagerStatics3", "RequestAccessKindForModernStandbyAsync", 6usize, |this| ((**(this as *const *const IBackgroundExecutionManagerStatics3_Vtbl)).RequestAccessKindForModernStandbyAsync)(this, requestedaccess, reason, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccessStatusForModernStandby(this: *mut core::ffi::c_void, result__: *mut BackgroundAccessStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundExecutionManagerStatics3", "GetAccessStatusForModernStandby", 7usize, |this| ((**(this as *const *const IBackgroundExecutionManagerStatics3_Vtbl)).GetAccessStatusForModernStandby)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccessStatusForModernStandbyForApplication(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut BackgroundAccessStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundExecutionManagerStatics3", "GetAccessStatusForModernStandbyForApplication", 8usize, |this| ((**(this as *const *const IBackgroundExecutionManagerStatics3_Vtbl)).GetAccessStatusForModernStandbyForApplication)(this, applicationid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestAccessKindForModernStandbyAsync,
+            GetAccessStatusForModernStandby,
+            GetAccessStatusForModernStandbyForApplication,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundExecutionManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTask_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Run(this: *mut core::ffi::c_void, taskinstance: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTask", "Run", 6usize, |this| ((**(this as *const *const IBackgroundTask_Vtbl)).Run)(this, taskinstance), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Run }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTask as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTaskEntryPoint(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder", "SetTaskEntryPoint", 6usize, |this| ((**(this as *const *const IBackgroundTaskBuilder_Vtbl)).SetTaskEntryPoint)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskEntryPoint(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder", "TaskEntryPoint", 7usize, |this| ((**(this as *const *const IBackgroundTaskBuilder_Vtbl)).TaskEntryPoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrigger(this: *mut core::ffi::c_void, trigger: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder", "SetTrigger", 8usize, |this| ((**(this as *const *const IBackgroundTaskBuilder_Vtbl)).SetTrigger)(this, trigger), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddCondition(this: *mut core::ffi::c_void, condition: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder", "AddCondition", 9usize, |this| ((**(this as *const *const IBackgroundTaskBuilder_Vtbl)).AddCondition)(this, condition), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder", "SetName", 10usize, |this| ((**(this as *const *const IBackgroundTaskBuilder_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder", "Name", 11usize, |this| ((**(this as *const *const IBackgroundTaskBuilder_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder", "Register", 12usize, |this| ((**(this as *const *const IBackgroundTaskBuilder_Vtbl)).Register)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetTaskEntryPoint,
+            TaskEntryPoint,
+            SetTrigger,
+            AddCondition,
+            SetName,
+            Name,
+            Register,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskBuilder as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskBuilder2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCancelOnConditionLoss(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder2", "SetCancelOnConditionLoss", 6usize, |this| ((**(this as *const *const IBackgroundTaskBuilder2_Vtbl)).SetCancelOnConditionLoss)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelOnConditionLoss(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder2", "CancelOnConditionLoss", 7usize, |this| ((**(this as *const *const IBackgroundTaskBuilder2_Vtbl)).CancelOnConditionLoss)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetCancelOnConditionLoss, CancelOnConditionLoss }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskBuilder2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskBuilder3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIsNetworkRequested(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder3", "SetIsNetworkRequested", 6usize, |this| ((**(this as *const *const IBackgroundTaskBuilder3_Vtbl)).SetIsNetworkRequested)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNetworkRequested(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder3", "IsNetworkRequested", 7usize, |this| ((**(this as *const *const IBackgroundTaskBuilder3_Vtbl)).IsNetworkRequested)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetIsNetworkRequested, IsNetworkRequested }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskBuilder3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskBuilder4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TaskGroup(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder4", "TaskGroup", 6usize, |this| ((**(this as *const *const IBackgroundTaskBuilder4_Vtbl)).TaskGroup)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTaskGroup(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder4", "SetTaskGroup", 7usize, |this| ((**(this as *const *const IBackgroundTaskBuilder4_Vtbl)).SetTaskGroup)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TaskGroup, SetTaskGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskBuilder4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskBuilder5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTaskEntryPointClsid(this: *mut core::ffi::c_void, taskentrypoint: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder5", "SetTaskEntryPointClsid", 6usize, |this| ((**(this as *const *const IBackgroundTaskBuilder5_Vtbl)).SetTaskEntryPointClsid)(this, taskentrypoint), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTaskEntryPointClsid }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskBuilder5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskBuilder6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowRunningTaskInStandby(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder6", "AllowRunningTaskInStandby", 6usize, |this| ((**(this as *const *const IBackgroundTaskBuilder6_Vtbl)).AllowRunningTaskInStandby)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowRunningTaskInStandby(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder6", "SetAllowRunningTaskInStandby", 7usize, |this| ((**(this as *const *const IBackgroundTaskBuilder6_Vtbl)).SetAllowRunningTaskInStandby)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Validate(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder6", "Validate", 8usize, |this| ((**(this as *const *const IBackgroundTaskBuilder6_Vtbl)).Validate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void, taskname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilder6", "Register", 9usize, |this| ((**(this as *const *const IBackgroundTaskBuilder6_Vtbl)).Register)(this, taskname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AllowRunningTaskInStandby,
+            SetAllowRunningTaskInStandby,
+            Validate,
+            Register,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskBuilder6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskBuilderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsRunningTaskInStandbySupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskBuilderStatics", "IsRunningTaskInStandbySupported", 6usize, |this| ((**(this as *const *const IBackgroundTaskBuilderStatics_Vtbl)).IsRunningTaskInStandbySupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsRunningTaskInStandbySupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskBuilderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstanceId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskCompletedEventArgs", "InstanceId", 6usize, |this| ((**(this as *const *const IBackgroundTaskCompletedEventArgs_Vtbl)).InstanceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckResult(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskCompletedEventArgs", "CheckResult", 7usize, |this| ((**(this as *const *const IBackgroundTaskCompletedEventArgs_Vtbl)).CheckResult)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InstanceId, CheckResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskDeferral", "Complete", 6usize, |this| ((**(this as *const *const IBackgroundTaskDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskInstance_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstanceId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskInstance", "InstanceId", 6usize, |this| ((**(this as *const *const IBackgroundTaskInstance_Vtbl)).InstanceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Task(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskInstance", "Task", 7usize, |this| ((**(this as *const *const IBackgroundTaskInstance_Vtbl)).Task)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskInstance", "Progress", 8usize, |this| ((**(this as *const *const IBackgroundTaskInstance_Vtbl)).Progress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProgress(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskInstance", "SetProgress", 9usize, |this| ((**(this as *const *const IBackgroundTaskInstance_Vtbl)).SetProgress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TriggerDetails(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskInstance", "TriggerDetails", 10usize, |this| ((**(this as *const *const IBackgroundTaskInstance_Vtbl)).TriggerDetails)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Canceled(this: *mut core::ffi::c_void, cancelhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskInstance", "Canceled", 11usize, |this| ((**(this as *const *const IBackgroundTaskInstance_Vtbl)).Canceled)(this, cancelhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCanceled(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskInstance", "RemoveCanceled", 12usize, |this| ((**(this as *const *const IBackgroundTaskInstance_Vtbl)).RemoveCanceled)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuspendedCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskInstance", "SuspendedCount", 13usize, |this| ((**(this as *const *const IBackgroundTaskInstance_Vtbl)).SuspendedCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskInstance", "GetDeferral", 14usize, |this| ((**(this as *const *const IBackgroundTaskInstance_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InstanceId,
+            Task,
+            Progress,
+            SetProgress,
+            TriggerDetails,
+            Canceled,
+            RemoveCanceled,
+            SuspendedCount,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskInstance as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskInstance2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetThrottleCount(this: *mut core::ffi::c_void, counter: BackgroundTaskThrottleCounter, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskInstance2", "GetThrottleCount", 6usize, |this| ((**(this as *const *const IBackgroundTaskInstance2_Vtbl)).GetThrottleCount)(this, counter, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetThrottleCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskInstance2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IBackgroundTaskInstance4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskInstance4", "User", 6usize, |this| ((**(this as *const *const IBackgroundTaskInstance4_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskInstance4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskProgressEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstanceId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskProgressEventArgs", "InstanceId", 6usize, |this| ((**(this as *const *const IBackgroundTaskProgressEventArgs_Vtbl)).InstanceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskProgressEventArgs", "Progress", 7usize, |this| ((**(this as *const *const IBackgroundTaskProgressEventArgs_Vtbl)).Progress)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InstanceId, Progress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskProgressEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskRegistration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TaskId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistration", "TaskId", 6usize, |this| ((**(this as *const *const IBackgroundTaskRegistration_Vtbl)).TaskId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistration", "Name", 7usize, |this| ((**(this as *const *const IBackgroundTaskRegistration_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistration", "Progress", 8usize, |this| ((**(this as *const *const IBackgroundTaskRegistration_Vtbl)).Progress)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProgress(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistration", "RemoveProgress", 9usize, |this| ((**(this as *const *const IBackgroundTaskRegistration_Vtbl)).RemoveProgress)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Completed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistration", "Completed", 10usize, |this| ((**(this as *const *const IBackgroundTaskRegistration_Vtbl)).Completed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCompleted(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistration", "RemoveCompleted", 11usize, |this| ((**(this as *const *const IBackgroundTaskRegistration_Vtbl)).RemoveCompleted)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unregister(this: *mut core::ffi::c_void, canceltask: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistration", "Unregister", 12usize, |this| ((**(this as *const *const IBackgroundTaskRegistration_Vtbl)).Unregister)(this, canceltask), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TaskId,
+            Name,
+            Progress,
+            RemoveProgress,
+            Completed,
+            RemoveCompleted,
+            Unregister,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskRegistration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskRegistration2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Trigger(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistration2", "Trigger", 6usize, |this| ((**(this as *const *const IBackgroundTaskRegistration2_Vtbl)).Trigger)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Trigger }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskRegistration2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskRegistration3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TaskGroup(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistration3", "TaskGroup", 6usize, |this| ((**(this as *const *const IBackgroundTaskRegistration3_Vtbl)).TaskGroup)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TaskGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskRegistration3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskRegistration4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TaskLastThrottledInStandbyTimestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistration4", "TaskLastThrottledInStandbyTimestamp", 6usize, |this| ((**(this as *const *const IBackgroundTaskRegistration4_Vtbl)).TaskLastThrottledInStandbyTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppEnergyUsePredictionContribution(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistration4", "AppEnergyUsePredictionContribution", 7usize, |this| ((**(this as *const *const IBackgroundTaskRegistration4_Vtbl)).AppEnergyUsePredictionContribution)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TaskLastThrottledInStandbyTimestamp,
+            AppEnergyUsePredictionContribution,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskRegistration4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Activation", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IBackgroundTaskRegistrationGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistrationGroup", "Id", 6usize, |this| ((**(this as *const *const IBackgroundTaskRegistrationGroup_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistrationGroup", "Name", 7usize, |this| ((**(this as *const *const IBackgroundTaskRegistrationGroup_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Activation")]
+        unsafe extern "system" fn BackgroundActivated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistrationGroup", "BackgroundActivated", 8usize, |this| ((**(this as *const *const IBackgroundTaskRegistrationGroup_Vtbl)).BackgroundActivated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBackgroundActivated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistrationGroup", "RemoveBackgroundActivated", 9usize, |this| ((**(this as *const *const IBackgroundTaskRegistrationGroup_Vtbl)).RemoveBackgroundActivated)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AllTasks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistrationGroup", "AllTasks", 10usize, |this| ((**(this as *const *const IBackgroundTaskRegistrationGroup_Vtbl)).AllTasks)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            Name,
+            #[cfg(feature = "ApplicationModel_Activation")]
+            BackgroundActivated,
+            #[cfg(not(feature = "ApplicationModel_Activation"))]
+            BackgroundActivated: 0,
+            RemoveBackgroundActivated,
+            #[cfg(feature = "Foundation_Collections")]
+            AllTasks,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AllTasks: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskRegistrationGroup as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTaskRegistrationGroupFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistrationGroupFactory", "Create", 6usize, |this| ((**(this as *const *const IBackgroundTaskRegistrationGroupFactory_Vtbl)).Create)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithName(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistrationGroupFactory", "CreateWithName", 7usize, |this| ((**(this as *const *const IBackgroundTaskRegistrationGroupFactory_Vtbl)).CreateWithName)(this, id, name, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskRegistrationGroupFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBackgroundTaskRegistrationStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AllTasks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistrationStatics", "AllTasks", 6usize, |this| ((**(this as *const *const IBackgroundTaskRegistrationStatics_Vtbl)).AllTasks)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AllTasks,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AllTasks: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskRegistrationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBackgroundTaskRegistrationStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AllTaskGroups(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistrationStatics2", "AllTaskGroups", 6usize, |this| ((**(this as *const *const IBackgroundTaskRegistrationStatics2_Vtbl)).AllTaskGroups)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTaskGroup(this: *mut core::ffi::c_void, groupid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTaskRegistrationStatics2", "GetTaskGroup", 7usize, |this| ((**(this as *const *const IBackgroundTaskRegistrationStatics2_Vtbl)).GetTaskGroup)(this, groupid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AllTaskGroups,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AllTaskGroups: 0,
+            GetTaskGroup,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTaskRegistrationStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTrigger_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundWorkCostStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentBackgroundWorkCost(this: *mut core::ffi::c_void, result__: *mut BackgroundWorkCostValue) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundWorkCostStatics", "CurrentBackgroundWorkCost", 6usize, |this| ((**(this as *const *const IBackgroundWorkCostStatics_Vtbl)).CurrentBackgroundWorkCost)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CurrentBackgroundWorkCost }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundWorkCostStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundWorkCostStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppEnergyUseLevel(this: *mut core::ffi::c_void, result__: *mut EnergyUseLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundWorkCostStatics2", "AppEnergyUseLevel", 6usize, |this| ((**(this as *const *const IBackgroundWorkCostStatics2_Vtbl)).AppEnergyUseLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppEnergyUsePrediction(this: *mut core::ffi::c_void, result__: *mut EnergyUseLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundWorkCostStatics2", "AppEnergyUsePrediction", 7usize, |this| ((**(this as *const *const IBackgroundWorkCostStatics2_Vtbl)).AppEnergyUsePrediction)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppLastThrottledInStandbyTimestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundWorkCostStatics2", "AppLastThrottledInStandbyTimestamp", 8usize, |this| ((**(this as *const *const IBackgroundWorkCostStatics2_Vtbl)).AppLastThrottledInStandbyTimestamp)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppEnergyUseLevel,
+            AppEnergyUsePrediction,
+            AppLastThrottledInStandbyTimestamp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundWorkCostStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Bluetooth_Advertisement")]
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementPublisherTrigger_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Bluetooth_Advertisement")]
+        unsafe extern "system" fn Advertisement(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherTrigger", "Advertisement", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherTrigger_Vtbl)).Advertisement)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Bluetooth_Advertisement")]
+            Advertisement,
+            #[cfg(not(feature = "Devices_Bluetooth_Advertisement"))]
+            Advertisement: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementPublisherTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementPublisherTrigger2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PreferredTransmitPowerLevelInDBm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherTrigger2", "PreferredTransmitPowerLevelInDBm", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherTrigger2_Vtbl)).PreferredTransmitPowerLevelInDBm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredTransmitPowerLevelInDBm(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherTrigger2", "SetPreferredTransmitPowerLevelInDBm", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherTrigger2_Vtbl)).SetPreferredTransmitPowerLevelInDBm)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseExtendedFormat(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherTrigger2", "UseExtendedFormat", 8usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherTrigger2_Vtbl)).UseExtendedFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseExtendedFormat(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherTrigger2", "SetUseExtendedFormat", 9usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherTrigger2_Vtbl)).SetUseExtendedFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAnonymous(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherTrigger2", "IsAnonymous", 10usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherTrigger2_Vtbl)).IsAnonymous)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsAnonymous(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherTrigger2", "SetIsAnonymous", 11usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherTrigger2_Vtbl)).SetIsAnonymous)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeTransmitPowerLevel(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherTrigger2", "IncludeTransmitPowerLevel", 12usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherTrigger2_Vtbl)).IncludeTransmitPowerLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeTransmitPowerLevel(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherTrigger2", "SetIncludeTransmitPowerLevel", 13usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherTrigger2_Vtbl)).SetIncludeTransmitPowerLevel)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PreferredTransmitPowerLevelInDBm,
+            SetPreferredTransmitPowerLevelInDBm,
+            UseExtendedFormat,
+            SetUseExtendedFormat,
+            IsAnonymous,
+            SetIsAnonymous,
+            IncludeTransmitPowerLevel,
+            SetIncludeTransmitPowerLevel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementPublisherTrigger2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Bluetooth_Advertisement")]
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementWatcherTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinSamplingInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcherTrigger", "MinSamplingInterval", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcherTrigger_Vtbl)).MinSamplingInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSamplingInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcherTrigger", "MaxSamplingInterval", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcherTrigger_Vtbl)).MaxSamplingInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinOutOfRangeTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcherTrigger", "MinOutOfRangeTimeout", 8usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcherTrigger_Vtbl)).MinOutOfRangeTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxOutOfRangeTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcherTrigger", "MaxOutOfRangeTimeout", 9usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcherTrigger_Vtbl)).MaxOutOfRangeTimeout)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth")]
+        unsafe extern "system" fn SignalStrengthFilter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcherTrigger", "SignalStrengthFilter", 10usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcherTrigger_Vtbl)).SignalStrengthFilter)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth")]
+        unsafe extern "system" fn SetSignalStrengthFilter(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcherTrigger", "SetSignalStrengthFilter", 11usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcherTrigger_Vtbl)).SetSignalStrengthFilter)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_Advertisement")]
+        unsafe extern "system" fn AdvertisementFilter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcherTrigger", "AdvertisementFilter", 12usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcherTrigger_Vtbl)).AdvertisementFilter)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_Advertisement")]
+        unsafe extern "system" fn SetAdvertisementFilter(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcherTrigger", "SetAdvertisementFilter", 13usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcherTrigger_Vtbl)).SetAdvertisementFilter)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MinSamplingInterval,
+            MaxSamplingInterval,
+            MinOutOfRangeTimeout,
+            MaxOutOfRangeTimeout,
+            #[cfg(feature = "Devices_Bluetooth")]
+            SignalStrengthFilter,
+            #[cfg(not(feature = "Devices_Bluetooth"))]
+            SignalStrengthFilter: 0,
+            #[cfg(feature = "Devices_Bluetooth")]
+            SetSignalStrengthFilter,
+            #[cfg(not(feature = "Devices_Bluetooth"))]
+            SetSignalStrengthFilter: 0,
+            #[cfg(feature = "Devices_Bluetooth_Advertisement")]
+            AdvertisementFilter,
+            #[cfg(not(feature = "Devices_Bluetooth_Advertisement"))]
+            AdvertisementFilter: 0,
+            #[cfg(feature = "Devices_Bluetooth_Advertisement")]
+            SetAdvertisementFilter,
+            #[cfg(not(feature = "Devices_Bluetooth_Advertisement"))]
+            SetAdvertisementFilter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementWatcherTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementWatcherTrigger2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowExtendedAdvertisements(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcherTrigger2", "AllowExtendedAdvertisements", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcherTrigger2_Vtbl)).AllowExtendedAdvertisements)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowExtendedAdvertisements(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcherTrigger2", "SetAllowExtendedAdvertisements", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcherTrigger2_Vtbl)).SetAllowExtendedAdvertisements)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AllowExtendedAdvertisements, SetAllowExtendedAdvertisements }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementWatcherTrigger2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICachedFileUpdaterTrigger_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICachedFileUpdaterTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Provider")]
+impl windows_core::imp::ProxyVtable for ICachedFileUpdaterTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Provider")]
+        unsafe extern "system" fn UpdateTarget(this: *mut core::ffi::c_void, result__: *mut super::super::Storage::Provider::CachedFileTarget) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileUpdaterTriggerDetails", "UpdateTarget", 6usize, |this| ((**(this as *const *const ICachedFileUpdaterTriggerDetails_Vtbl)).UpdateTarget)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Provider")]
+        unsafe extern "system" fn UpdateRequest(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileUpdaterTriggerDetails", "UpdateRequest", 7usize, |this| ((**(this as *const *const ICachedFileUpdaterTriggerDetails_Vtbl)).UpdateRequest)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanRequestUserInput(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileUpdaterTriggerDetails", "CanRequestUserInput", 8usize, |this| ((**(this as *const *const ICachedFileUpdaterTriggerDetails_Vtbl)).CanRequestUserInput)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Provider")]
+            UpdateTarget,
+            #[cfg(not(feature = "Storage_Provider"))]
+            UpdateTarget: 0,
+            #[cfg(feature = "Storage_Provider")]
+            UpdateRequest,
+            #[cfg(not(feature = "Storage_Provider"))]
+            UpdateRequest: 0,
+            CanRequestUserInput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICachedFileUpdaterTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatMessageNotificationTrigger_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageNotificationTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatMessageReceivedNotificationTrigger_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageReceivedNotificationTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICommunicationBlockingAppSetAsActiveTrigger_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommunicationBlockingAppSetAsActiveTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactStoreNotificationTrigger_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactStoreNotificationTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContentPrefetchTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WaitInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentPrefetchTrigger", "WaitInterval", 6usize, |this| ((**(this as *const *const IContentPrefetchTrigger_Vtbl)).WaitInterval)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WaitInterval }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContentPrefetchTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContentPrefetchTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, waitinterval: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentPrefetchTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const IContentPrefetchTriggerFactory_Vtbl)).Create)(this, waitinterval, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContentPrefetchTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICustomSystemEventTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TriggerId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSystemEventTrigger", "TriggerId", 6usize, |this| ((**(this as *const *const ICustomSystemEventTrigger_Vtbl)).TriggerId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Recurrence(this: *mut core::ffi::c_void, result__: *mut CustomSystemEventTriggerRecurrence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSystemEventTrigger", "Recurrence", 7usize, |this| ((**(this as *const *const ICustomSystemEventTrigger_Vtbl)).Recurrence)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TriggerId, Recurrence }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICustomSystemEventTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICustomSystemEventTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, triggerid: core::mem::MaybeUninit<windows_core::HSTRING>, recurrence: CustomSystemEventTriggerRecurrence, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSystemEventTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const ICustomSystemEventTriggerFactory_Vtbl)).Create)(this, triggerid, recurrence, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICustomSystemEventTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceConnectionChangeTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceConnectionChangeTrigger", "DeviceId", 6usize, |this| ((**(this as *const *const IDeviceConnectionChangeTrigger_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanMaintainConnection(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceConnectionChangeTrigger", "CanMaintainConnection", 7usize, |this| ((**(this as *const *const IDeviceConnectionChangeTrigger_Vtbl)).CanMaintainConnection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaintainConnection(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceConnectionChangeTrigger", "MaintainConnection", 8usize, |this| ((**(this as *const *const IDeviceConnectionChangeTrigger_Vtbl)).MaintainConnection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaintainConnection(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceConnectionChangeTrigger", "SetMaintainConnection", 9usize, |this| ((**(this as *const *const IDeviceConnectionChangeTrigger_Vtbl)).SetMaintainConnection)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            CanMaintainConnection,
+            MaintainConnection,
+            SetMaintainConnection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceConnectionChangeTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceConnectionChangeTriggerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceConnectionChangeTriggerStatics", "FromIdAsync", 6usize, |this| ((**(this as *const *const IDeviceConnectionChangeTriggerStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceConnectionChangeTriggerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IDeviceManufacturerNotificationTrigger_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TriggerQualifier(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceManufacturerNotificationTrigger", "TriggerQualifier", 6usize, |this| ((**(this as *const *const IDeviceManufacturerNotificationTrigger_Vtbl)).TriggerQualifier)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn OneShot(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceManufacturerNotificationTrigger", "OneShot", 7usize, |this| ((**(this as *const *const IDeviceManufacturerNotificationTrigger_Vtbl)).OneShot)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            TriggerQualifier,
+            #[cfg(not(feature = "deprecated"))]
+            TriggerQualifier: 0,
+            #[cfg(feature = "deprecated")]
+            OneShot,
+            #[cfg(not(feature = "deprecated"))]
+            OneShot: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceManufacturerNotificationTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IDeviceManufacturerNotificationTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, triggerqualifier: core::mem::MaybeUninit<windows_core::HSTRING>, oneshot: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceManufacturerNotificationTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const IDeviceManufacturerNotificationTriggerFactory_Vtbl)).Create)(this, triggerqualifier, oneshot, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Create,
+            #[cfg(not(feature = "deprecated"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceManufacturerNotificationTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceServicingTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAsyncSimple(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, expectedduration: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceServicingTrigger", "RequestAsyncSimple", 6usize, |this| ((**(this as *const *const IDeviceServicingTrigger_Vtbl)).RequestAsyncSimple)(this, deviceid, expectedduration, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAsyncWithArguments(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, expectedduration: super::super::Foundation::TimeSpan, arguments: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceServicingTrigger", "RequestAsyncWithArguments", 7usize, |this| ((**(this as *const *const IDeviceServicingTrigger_Vtbl)).RequestAsyncWithArguments)(this, deviceid, expectedduration, arguments, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestAsyncSimple, RequestAsyncWithArguments }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceServicingTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceUseTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAsyncSimple(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceUseTrigger", "RequestAsyncSimple", 6usize, |this| ((**(this as *const *const IDeviceUseTrigger_Vtbl)).RequestAsyncSimple)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAsyncWithArguments(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, arguments: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceUseTrigger", "RequestAsyncWithArguments", 7usize, |this| ((**(this as *const *const IDeviceUseTrigger_Vtbl)).RequestAsyncWithArguments)(this, deviceid, arguments, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestAsyncSimple, RequestAsyncWithArguments }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceUseTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceWatcherTrigger_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceWatcherTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailStoreNotificationTrigger_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailStoreNotificationTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+impl windows_core::imp::ProxyVtable for IGattCharacteristicNotificationTrigger_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+        unsafe extern "system" fn Characteristic(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicNotificationTrigger", "Characteristic", 6usize, |this| ((**(this as *const *const IGattCharacteristicNotificationTrigger_Vtbl)).Characteristic)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+            Characteristic,
+            #[cfg(not(feature = "Devices_Bluetooth_GenericAttributeProfile"))]
+            Characteristic: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattCharacteristicNotificationTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Bluetooth_Background")]
+impl windows_core::imp::ProxyVtable for IGattCharacteristicNotificationTrigger2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Bluetooth_Background")]
+        unsafe extern "system" fn EventTriggeringMode(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Bluetooth::Background::BluetoothEventTriggeringMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicNotificationTrigger2", "EventTriggeringMode", 6usize, |this| ((**(this as *const *const IGattCharacteristicNotificationTrigger2_Vtbl)).EventTriggeringMode)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Bluetooth_Background")]
+            EventTriggeringMode,
+            #[cfg(not(feature = "Devices_Bluetooth_Background"))]
+            EventTriggeringMode: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattCharacteristicNotificationTrigger2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+impl windows_core::imp::ProxyVtable for IGattCharacteristicNotificationTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, characteristic: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicNotificationTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const IGattCharacteristicNotificationTriggerFactory_Vtbl)).Create)(this, characteristic, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+            Create,
+            #[cfg(not(feature = "Devices_Bluetooth_GenericAttributeProfile"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattCharacteristicNotificationTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Bluetooth_Background", feature = "Devices_Bluetooth_GenericAttributeProfile"))]
+impl windows_core::imp::ProxyVtable for IGattCharacteristicNotificationTriggerFactory2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Devices_Bluetooth_Background", feature = "Devices_Bluetooth_GenericAttributeProfile"))]
+        unsafe extern "system" fn CreateWithEventTriggeringMode(this: *mut core::ffi::c_void, characteristic: *mut core::ffi::c_void, eventtriggeringmode: super::super::Devices::Bluetooth::Background::BluetoothEventTriggeringMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicNotificationTriggerFactory2", "CreateWithEventTriggeringMode", 6usize, |this| ((**(this as *const *const IGattCharacteristicNotificationTriggerFactory2_Vtbl)).CreateWithEventTriggeringMode)(this, characteristic, eventtriggeringmode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Devices_Bluetooth_Background", feature = "Devices_Bluetooth_GenericAttributeProfile"))]
+            CreateWithEventTriggeringMode,
+            #[cfg(not(all(feature = "Devices_Bluetooth_Background", feature = "Devices_Bluetooth_GenericAttributeProfile")))]
+            CreateWithEventTriggeringMode: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattCharacteristicNotificationTriggerFactory2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+impl windows_core::imp::ProxyVtable for IGattServiceProviderTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TriggerId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderTrigger", "TriggerId", 6usize, |this| ((**(this as *const *const IGattServiceProviderTrigger_Vtbl)).TriggerId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+        unsafe extern "system" fn Service(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderTrigger", "Service", 7usize, |this| ((**(this as *const *const IGattServiceProviderTrigger_Vtbl)).Service)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+        unsafe extern "system" fn SetAdvertisingParameters(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderTrigger", "SetAdvertisingParameters", 8usize, |this| ((**(this as *const *const IGattServiceProviderTrigger_Vtbl)).SetAdvertisingParameters)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+        unsafe extern "system" fn AdvertisingParameters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderTrigger", "AdvertisingParameters", 9usize, |this| ((**(this as *const *const IGattServiceProviderTrigger_Vtbl)).AdvertisingParameters)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TriggerId,
+            #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+            Service,
+            #[cfg(not(feature = "Devices_Bluetooth_GenericAttributeProfile"))]
+            Service: 0,
+            #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+            SetAdvertisingParameters,
+            #[cfg(not(feature = "Devices_Bluetooth_GenericAttributeProfile"))]
+            SetAdvertisingParameters: 0,
+            #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+            AdvertisingParameters,
+            #[cfg(not(feature = "Devices_Bluetooth_GenericAttributeProfile"))]
+            AdvertisingParameters: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattServiceProviderTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Bluetooth")]
+impl windows_core::imp::ProxyVtable for IGattServiceProviderTriggerResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Trigger(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderTriggerResult", "Trigger", 6usize, |this| ((**(this as *const *const IGattServiceProviderTriggerResult_Vtbl)).Trigger)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth")]
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Bluetooth::BluetoothError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderTriggerResult", "Error", 7usize, |this| ((**(this as *const *const IGattServiceProviderTriggerResult_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Trigger,
+            #[cfg(feature = "Devices_Bluetooth")]
+            Error,
+            #[cfg(not(feature = "Devices_Bluetooth"))]
+            Error: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattServiceProviderTriggerResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattServiceProviderTriggerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAsync(this: *mut core::ffi::c_void, triggerid: core::mem::MaybeUninit<windows_core::HSTRING>, serviceuuid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderTriggerStatics", "CreateAsync", 6usize, |this| ((**(this as *const *const IGattServiceProviderTriggerStatics_Vtbl)).CreateAsync)(this, triggerid, serviceuuid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattServiceProviderTriggerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for IGeovisitTrigger_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn MonitoringScope(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Geolocation::VisitMonitoringScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeovisitTrigger", "MonitoringScope", 6usize, |this| ((**(this as *const *const IGeovisitTrigger_Vtbl)).MonitoringScope)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn SetMonitoringScope(this: *mut core::ffi::c_void, value: super::super::Devices::Geolocation::VisitMonitoringScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeovisitTrigger", "SetMonitoringScope", 7usize, |this| ((**(this as *const *const IGeovisitTrigger_Vtbl)).SetMonitoringScope)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            MonitoringScope,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            MonitoringScope: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            SetMonitoringScope,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            SetMonitoringScope: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeovisitTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILocationTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TriggerType(this: *mut core::ffi::c_void, result__: *mut LocationTriggerType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationTrigger", "TriggerType", 6usize, |this| ((**(this as *const *const ILocationTrigger_Vtbl)).TriggerType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TriggerType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocationTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILocationTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, triggertype: LocationTriggerType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const ILocationTriggerFactory_Vtbl)).Create)(this, triggertype, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocationTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMaintenanceTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FreshnessTime(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMaintenanceTrigger", "FreshnessTime", 6usize, |this| ((**(this as *const *const IMaintenanceTrigger_Vtbl)).FreshnessTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OneShot(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMaintenanceTrigger", "OneShot", 7usize, |this| ((**(this as *const *const IMaintenanceTrigger_Vtbl)).OneShot)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FreshnessTime, OneShot }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMaintenanceTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMaintenanceTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, freshnesstime: u32, oneshot: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMaintenanceTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const IMaintenanceTriggerFactory_Vtbl)).Create)(this, freshnesstime, oneshot, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMaintenanceTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaProcessingTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaProcessingTrigger", "RequestAsync", 6usize, |this| ((**(this as *const *const IMediaProcessingTrigger_Vtbl)).RequestAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestAsyncWithArguments(this: *mut core::ffi::c_void, arguments: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaProcessingTrigger", "RequestAsyncWithArguments", 7usize, |this| ((**(this as *const *const IMediaProcessingTrigger_Vtbl)).RequestAsyncWithArguments)(this, arguments, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestAsyncWithArguments,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestAsyncWithArguments: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaProcessingTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkOperatorHotspotAuthenticationTrigger_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorHotspotAuthenticationTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkOperatorNotificationTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NetworkAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorNotificationTrigger", "NetworkAccountId", 6usize, |this| ((**(this as *const *const INetworkOperatorNotificationTrigger_Vtbl)).NetworkAccountId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NetworkAccountId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorNotificationTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkOperatorNotificationTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, networkaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorNotificationTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const INetworkOperatorNotificationTriggerFactory_Vtbl)).Create)(this, networkaccountid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorNotificationTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Calls_Background")]
+impl windows_core::imp::ProxyVtable for IPhoneTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OneShot(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneTrigger", "OneShot", 6usize, |this| ((**(this as *const *const IPhoneTrigger_Vtbl)).OneShot)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Calls_Background")]
+        unsafe extern "system" fn TriggerType(this: *mut core::ffi::c_void, result__: *mut super::Calls::Background::PhoneTriggerType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneTrigger", "TriggerType", 7usize, |this| ((**(this as *const *const IPhoneTrigger_Vtbl)).TriggerType)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OneShot,
+            #[cfg(feature = "ApplicationModel_Calls_Background")]
+            TriggerType,
+            #[cfg(not(feature = "ApplicationModel_Calls_Background"))]
+            TriggerType: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Calls_Background")]
+impl windows_core::imp::ProxyVtable for IPhoneTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_Calls_Background")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, r#type: super::Calls::Background::PhoneTriggerType, oneshot: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const IPhoneTriggerFactory_Vtbl)).Create)(this, r#type, oneshot, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_Calls_Background")]
+            Create,
+            #[cfg(not(feature = "ApplicationModel_Calls_Background"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPushNotificationTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const IPushNotificationTriggerFactory_Vtbl)).Create)(this, applicationid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPushNotificationTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRcsEndUserMessageAvailableTrigger_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRcsEndUserMessageAvailableTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Bluetooth_Background", feature = "Networking_Sockets"))]
+impl windows_core::imp::ProxyVtable for IRfcommConnectionTrigger_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Bluetooth_Background")]
+        unsafe extern "system" fn InboundConnection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommConnectionTrigger", "InboundConnection", 6usize, |this| ((**(this as *const *const IRfcommConnectionTrigger_Vtbl)).InboundConnection)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_Background")]
+        unsafe extern "system" fn OutboundConnection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommConnectionTrigger", "OutboundConnection", 7usize, |this| ((**(this as *const *const IRfcommConnectionTrigger_Vtbl)).OutboundConnection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowMultipleConnections(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommConnectionTrigger", "AllowMultipleConnections", 8usize, |this| ((**(this as *const *const IRfcommConnectionTrigger_Vtbl)).AllowMultipleConnections)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowMultipleConnections(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommConnectionTrigger", "SetAllowMultipleConnections", 9usize, |this| ((**(this as *const *const IRfcommConnectionTrigger_Vtbl)).SetAllowMultipleConnections)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn ProtectionLevel(this: *mut core::ffi::c_void, result__: *mut super::super::Networking::Sockets::SocketProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommConnectionTrigger", "ProtectionLevel", 10usize, |this| ((**(this as *const *const IRfcommConnectionTrigger_Vtbl)).ProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn SetProtectionLevel(this: *mut core::ffi::c_void, value: super::super::Networking::Sockets::SocketProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommConnectionTrigger", "SetProtectionLevel", 11usize, |this| ((**(this as *const *const IRfcommConnectionTrigger_Vtbl)).SetProtectionLevel)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking")]
+        unsafe extern "system" fn RemoteHostName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommConnectionTrigger", "RemoteHostName", 12usize, |this| ((**(this as *const *const IRfcommConnectionTrigger_Vtbl)).RemoteHostName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking")]
+        unsafe extern "system" fn SetRemoteHostName(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommConnectionTrigger", "SetRemoteHostName", 13usize, |this| ((**(this as *const *const IRfcommConnectionTrigger_Vtbl)).SetRemoteHostName)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Bluetooth_Background")]
+            InboundConnection,
+            #[cfg(not(feature = "Devices_Bluetooth_Background"))]
+            InboundConnection: 0,
+            #[cfg(feature = "Devices_Bluetooth_Background")]
+            OutboundConnection,
+            #[cfg(not(feature = "Devices_Bluetooth_Background"))]
+            OutboundConnection: 0,
+            AllowMultipleConnections,
+            SetAllowMultipleConnections,
+            #[cfg(feature = "Networking_Sockets")]
+            ProtectionLevel,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            ProtectionLevel: 0,
+            #[cfg(feature = "Networking_Sockets")]
+            SetProtectionLevel,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            SetProtectionLevel: 0,
+            #[cfg(feature = "Networking")]
+            RemoteHostName,
+            #[cfg(not(feature = "Networking"))]
+            RemoteHostName: 0,
+            #[cfg(feature = "Networking")]
+            SetRemoteHostName,
+            #[cfg(not(feature = "Networking"))]
+            SetRemoteHostName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRfcommConnectionTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISecondaryAuthenticationFactorAuthenticationTrigger_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecondaryAuthenticationFactorAuthenticationTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISensorDataThresholdTrigger_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensorDataThresholdTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Sensors")]
+impl windows_core::imp::ProxyVtable for ISensorDataThresholdTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Sensors")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, threshold: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorDataThresholdTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const ISensorDataThresholdTriggerFactory_Vtbl)).Create)(this, threshold, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Sensors")]
+            Create,
+            #[cfg(not(feature = "Devices_Sensors"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensorDataThresholdTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_SmartCards")]
+impl windows_core::imp::ProxyVtable for ISmartCardTrigger_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_SmartCards")]
+        unsafe extern "system" fn TriggerType(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::SmartCards::SmartCardTriggerType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardTrigger", "TriggerType", 6usize, |this| ((**(this as *const *const ISmartCardTrigger_Vtbl)).TriggerType)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_SmartCards")]
+            TriggerType,
+            #[cfg(not(feature = "Devices_SmartCards"))]
+            TriggerType: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_SmartCards")]
+impl windows_core::imp::ProxyVtable for ISmartCardTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_SmartCards")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, triggertype: super::super::Devices::SmartCards::SmartCardTriggerType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const ISmartCardTriggerFactory_Vtbl)).Create)(this, triggertype, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_SmartCards")]
+            Create,
+            #[cfg(not(feature = "Devices_SmartCards"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Sms")]
+impl windows_core::imp::ProxyVtable for ISmsMessageReceivedTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Sms")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, filterrules: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageReceivedTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const ISmsMessageReceivedTriggerFactory_Vtbl)).Create)(this, filterrules, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Sms")]
+            Create,
+            #[cfg(not(feature = "Devices_Sms"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsMessageReceivedTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISocketActivityTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsWakeFromLowPowerSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISocketActivityTrigger", "IsWakeFromLowPowerSupported", 6usize, |this| ((**(this as *const *const ISocketActivityTrigger_Vtbl)).IsWakeFromLowPowerSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsWakeFromLowPowerSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISocketActivityTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IStorageLibraryChangeTrackerTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, tracker: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChangeTrackerTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const IStorageLibraryChangeTrackerTriggerFactory_Vtbl)).Create)(this, tracker, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            Create,
+            #[cfg(not(feature = "Storage"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibraryChangeTrackerTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageLibraryContentChangedTrigger_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibraryContentChangedTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IStorageLibraryContentChangedTriggerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, storagelibrary: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryContentChangedTriggerStatics", "Create", 6usize, |this| ((**(this as *const *const IStorageLibraryContentChangedTriggerStatics_Vtbl)).Create)(this, storagelibrary, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn CreateFromLibraries(this: *mut core::ffi::c_void, storagelibraries: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryContentChangedTriggerStatics", "CreateFromLibraries", 7usize, |this| ((**(this as *const *const IStorageLibraryContentChangedTriggerStatics_Vtbl)).CreateFromLibraries)(this, storagelibraries, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            Create,
+            #[cfg(not(feature = "Storage"))]
+            Create: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            CreateFromLibraries,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            CreateFromLibraries: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibraryContentChangedTriggerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemCondition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConditionType(this: *mut core::ffi::c_void, result__: *mut SystemConditionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemCondition", "ConditionType", 6usize, |this| ((**(this as *const *const ISystemCondition_Vtbl)).ConditionType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConditionType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemCondition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemConditionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, conditiontype: SystemConditionType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemConditionFactory", "Create", 6usize, |this| ((**(this as *const *const ISystemConditionFactory_Vtbl)).Create)(this, conditiontype, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemConditionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OneShot(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemTrigger", "OneShot", 6usize, |this| ((**(this as *const *const ISystemTrigger_Vtbl)).OneShot)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TriggerType(this: *mut core::ffi::c_void, result__: *mut SystemTriggerType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemTrigger", "TriggerType", 7usize, |this| ((**(this as *const *const ISystemTrigger_Vtbl)).TriggerType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OneShot, TriggerType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, triggertype: SystemTriggerType, oneshot: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const ISystemTriggerFactory_Vtbl)).Create)(this, triggertype, oneshot, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimeTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FreshnessTime(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimeTrigger", "FreshnessTime", 6usize, |this| ((**(this as *const *const ITimeTrigger_Vtbl)).FreshnessTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OneShot(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimeTrigger", "OneShot", 7usize, |this| ((**(this as *const *const ITimeTrigger_Vtbl)).OneShot)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FreshnessTime, OneShot }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimeTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimeTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, freshnesstime: u32, oneshot: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimeTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const ITimeTriggerFactory_Vtbl)).Create)(this, freshnesstime, oneshot, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimeTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotificationActionTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationActionTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const IToastNotificationActionTriggerFactory_Vtbl)).Create)(this, applicationid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationActionTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotificationHistoryChangedTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationHistoryChangedTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const IToastNotificationHistoryChangedTriggerFactory_Vtbl)).Create)(this, applicationid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationHistoryChangedTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Notifications")]
+impl windows_core::imp::ProxyVtable for IUserNotificationChangedTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, notificationkinds: super::super::UI::Notifications::NotificationKinds, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotificationChangedTriggerFactory", "Create", 6usize, |this| ((**(this as *const *const IUserNotificationChangedTriggerFactory_Vtbl)).Create)(this, notificationkinds, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Notifications")]
+            Create,
+            #[cfg(not(feature = "UI_Notifications"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserNotificationChangedTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -371,6 +371,7 @@ impl PhoneCallBlockedReason {
 impl windows_core::TypeKind for PhoneCallBlockedReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallBlockedReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallBlockedReason").field(&self.0).finish()
@@ -395,6 +396,7 @@ impl windows_core::TypeKind for PhoneIncomingCallDismissedReason {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneIncomingCallDismissedReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneIncomingCallDismissedReason").field(&self.0).finish()
@@ -415,6 +417,7 @@ impl PhoneLineChangeKind {
 impl windows_core::TypeKind for PhoneLineChangeKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneLineChangeKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneLineChangeKind").field(&self.0).finish()
@@ -441,6 +444,7 @@ impl PhoneLineProperties {
 impl windows_core::TypeKind for PhoneLineProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneLineProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneLineProperties").field(&self.0).finish()
@@ -498,6 +502,7 @@ impl PhoneTriggerType {
 impl windows_core::TypeKind for PhoneTriggerType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneTriggerType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneTriggerType").field(&self.0).finish()
@@ -506,3 +511,5 @@ impl core::fmt::Debug for PhoneTriggerType {
 impl windows_core::RuntimeType for PhoneTriggerType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.Calls.Background.PhoneTriggerType;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
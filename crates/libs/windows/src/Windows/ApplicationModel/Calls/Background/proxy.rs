@@ -0,0 +1,157 @@
+impl windows_core::imp::ProxyVtable for IPhoneCallBlockedTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PhoneNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallBlockedTriggerDetails", "PhoneNumber", 6usize, |this| ((**(this as *const *const IPhoneCallBlockedTriggerDetails_Vtbl)).PhoneNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallBlockedTriggerDetails", "LineId", 7usize, |this| ((**(this as *const *const IPhoneCallBlockedTriggerDetails_Vtbl)).LineId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallBlockedReason(this: *mut core::ffi::c_void, result__: *mut PhoneCallBlockedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallBlockedTriggerDetails", "CallBlockedReason", 8usize, |this| ((**(this as *const *const IPhoneCallBlockedTriggerDetails_Vtbl)).CallBlockedReason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PhoneNumber, LineId, CallBlockedReason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallBlockedTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPhoneCallOriginDataRequestTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RequestId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOriginDataRequestTriggerDetails", "RequestId", 6usize, |this| ((**(this as *const *const IPhoneCallOriginDataRequestTriggerDetails_Vtbl)).RequestId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PhoneNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOriginDataRequestTriggerDetails", "PhoneNumber", 7usize, |this| ((**(this as *const *const IPhoneCallOriginDataRequestTriggerDetails_Vtbl)).PhoneNumber)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            RequestId,
+            #[cfg(not(feature = "deprecated"))]
+            RequestId: 0,
+            #[cfg(feature = "deprecated")]
+            PhoneNumber,
+            #[cfg(not(feature = "deprecated"))]
+            PhoneNumber: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallOriginDataRequestTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPhoneIncomingCallDismissedTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn LineId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneIncomingCallDismissedTriggerDetails", "LineId", 6usize, |this| ((**(this as *const *const IPhoneIncomingCallDismissedTriggerDetails_Vtbl)).LineId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PhoneNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneIncomingCallDismissedTriggerDetails", "PhoneNumber", 7usize, |this| ((**(this as *const *const IPhoneIncomingCallDismissedTriggerDetails_Vtbl)).PhoneNumber)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneIncomingCallDismissedTriggerDetails", "DisplayName", 8usize, |this| ((**(this as *const *const IPhoneIncomingCallDismissedTriggerDetails_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DismissalTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneIncomingCallDismissedTriggerDetails", "DismissalTime", 9usize, |this| ((**(this as *const *const IPhoneIncomingCallDismissedTriggerDetails_Vtbl)).DismissalTime)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TextReplyMessage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneIncomingCallDismissedTriggerDetails", "TextReplyMessage", 10usize, |this| ((**(this as *const *const IPhoneIncomingCallDismissedTriggerDetails_Vtbl)).TextReplyMessage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut PhoneIncomingCallDismissedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneIncomingCallDismissedTriggerDetails", "Reason", 11usize, |this| ((**(this as *const *const IPhoneIncomingCallDismissedTriggerDetails_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            LineId,
+            #[cfg(not(feature = "deprecated"))]
+            LineId: 0,
+            #[cfg(feature = "deprecated")]
+            PhoneNumber,
+            #[cfg(not(feature = "deprecated"))]
+            PhoneNumber: 0,
+            #[cfg(feature = "deprecated")]
+            DisplayName,
+            #[cfg(not(feature = "deprecated"))]
+            DisplayName: 0,
+            #[cfg(feature = "deprecated")]
+            DismissalTime,
+            #[cfg(not(feature = "deprecated"))]
+            DismissalTime: 0,
+            #[cfg(feature = "deprecated")]
+            TextReplyMessage,
+            #[cfg(not(feature = "deprecated"))]
+            TextReplyMessage: 0,
+            #[cfg(feature = "deprecated")]
+            Reason,
+            #[cfg(not(feature = "deprecated"))]
+            Reason: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneIncomingCallDismissedTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneIncomingCallNotificationTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LineId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneIncomingCallNotificationTriggerDetails", "LineId", 6usize, |this| ((**(this as *const *const IPhoneIncomingCallNotificationTriggerDetails_Vtbl)).LineId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneIncomingCallNotificationTriggerDetails", "CallId", 7usize, |this| ((**(this as *const *const IPhoneIncomingCallNotificationTriggerDetails_Vtbl)).CallId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LineId, CallId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneIncomingCallNotificationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneLineChangedTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LineId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineChangedTriggerDetails", "LineId", 6usize, |this| ((**(this as *const *const IPhoneLineChangedTriggerDetails_Vtbl)).LineId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeType(this: *mut core::ffi::c_void, result__: *mut PhoneLineChangeKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineChangedTriggerDetails", "ChangeType", 7usize, |this| ((**(this as *const *const IPhoneLineChangedTriggerDetails_Vtbl)).ChangeType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasLinePropertyChanged(this: *mut core::ffi::c_void, lineproperty: PhoneLineProperties, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineChangedTriggerDetails", "HasLinePropertyChanged", 8usize, |this| ((**(this as *const *const IPhoneLineChangedTriggerDetails_Vtbl)).HasLinePropertyChanged)(this, lineproperty, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LineId, ChangeType, HasLinePropertyChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneLineChangedTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneNewVoicemailMessageTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LineId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNewVoicemailMessageTriggerDetails", "LineId", 6usize, |this| ((**(this as *const *const IPhoneNewVoicemailMessageTriggerDetails_Vtbl)).LineId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VoicemailCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNewVoicemailMessageTriggerDetails", "VoicemailCount", 7usize, |this| ((**(this as *const *const IPhoneNewVoicemailMessageTriggerDetails_Vtbl)).VoicemailCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OperatorMessage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNewVoicemailMessageTriggerDetails", "OperatorMessage", 8usize, |this| ((**(this as *const *const IPhoneNewVoicemailMessageTriggerDetails_Vtbl)).OperatorMessage)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LineId, VoicemailCount, OperatorMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneNewVoicemailMessageTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -284,3 +284,5 @@ impl PhoneCallOriginManager {
 impl windows_core::RuntimeName for PhoneCallOriginManager {
     const NAME: &'static str = "Windows.ApplicationModel.Calls.Provider.PhoneCallOriginManager";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,192 @@
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPhoneCallOrigin_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Category(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOrigin", "Category", 6usize, |this| ((**(this as *const *const IPhoneCallOrigin_Vtbl)).Category)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetCategory(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOrigin", "SetCategory", 7usize, |this| ((**(this as *const *const IPhoneCallOrigin_Vtbl)).SetCategory)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CategoryDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOrigin", "CategoryDescription", 8usize, |this| ((**(this as *const *const IPhoneCallOrigin_Vtbl)).CategoryDescription)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetCategoryDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOrigin", "SetCategoryDescription", 9usize, |this| ((**(this as *const *const IPhoneCallOrigin_Vtbl)).SetCategoryDescription)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Location(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOrigin", "Location", 10usize, |this| ((**(this as *const *const IPhoneCallOrigin_Vtbl)).Location)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetLocation(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOrigin", "SetLocation", 11usize, |this| ((**(this as *const *const IPhoneCallOrigin_Vtbl)).SetLocation)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Category,
+            #[cfg(not(feature = "deprecated"))]
+            Category: 0,
+            #[cfg(feature = "deprecated")]
+            SetCategory,
+            #[cfg(not(feature = "deprecated"))]
+            SetCategory: 0,
+            #[cfg(feature = "deprecated")]
+            CategoryDescription,
+            #[cfg(not(feature = "deprecated"))]
+            CategoryDescription: 0,
+            #[cfg(feature = "deprecated")]
+            SetCategoryDescription,
+            #[cfg(not(feature = "deprecated"))]
+            SetCategoryDescription: 0,
+            #[cfg(feature = "deprecated")]
+            Location,
+            #[cfg(not(feature = "deprecated"))]
+            Location: 0,
+            #[cfg(feature = "deprecated")]
+            SetLocation,
+            #[cfg(not(feature = "deprecated"))]
+            SetLocation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallOrigin as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPhoneCallOrigin2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOrigin2", "DisplayName", 6usize, |this| ((**(this as *const *const IPhoneCallOrigin2_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOrigin2", "SetDisplayName", 7usize, |this| ((**(this as *const *const IPhoneCallOrigin2_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            DisplayName,
+            #[cfg(not(feature = "deprecated"))]
+            DisplayName: 0,
+            #[cfg(feature = "deprecated")]
+            SetDisplayName,
+            #[cfg(not(feature = "deprecated"))]
+            SetDisplayName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallOrigin2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IPhoneCallOrigin3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Storage", feature = "deprecated"))]
+        unsafe extern "system" fn DisplayPicture(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOrigin3", "DisplayPicture", 6usize, |this| ((**(this as *const *const IPhoneCallOrigin3_Vtbl)).DisplayPicture)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage", feature = "deprecated"))]
+        unsafe extern "system" fn SetDisplayPicture(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOrigin3", "SetDisplayPicture", 7usize, |this| ((**(this as *const *const IPhoneCallOrigin3_Vtbl)).SetDisplayPicture)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Storage", feature = "deprecated"))]
+            DisplayPicture,
+            #[cfg(not(all(feature = "Storage", feature = "deprecated")))]
+            DisplayPicture: 0,
+            #[cfg(all(feature = "Storage", feature = "deprecated"))]
+            SetDisplayPicture,
+            #[cfg(not(all(feature = "Storage", feature = "deprecated")))]
+            SetDisplayPicture: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallOrigin3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPhoneCallOriginManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsCurrentAppActiveCallOriginApp(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOriginManagerStatics", "IsCurrentAppActiveCallOriginApp", 6usize, |this| ((**(this as *const *const IPhoneCallOriginManagerStatics_Vtbl)).IsCurrentAppActiveCallOriginApp)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ShowPhoneCallOriginSettingsUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOriginManagerStatics", "ShowPhoneCallOriginSettingsUI", 7usize, |this| ((**(this as *const *const IPhoneCallOriginManagerStatics_Vtbl)).ShowPhoneCallOriginSettingsUI)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetCallOrigin(this: *mut core::ffi::c_void, requestid: windows_core::GUID, callorigin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOriginManagerStatics", "SetCallOrigin", 8usize, |this| ((**(this as *const *const IPhoneCallOriginManagerStatics_Vtbl)).SetCallOrigin)(this, requestid, callorigin), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            IsCurrentAppActiveCallOriginApp,
+            #[cfg(not(feature = "deprecated"))]
+            IsCurrentAppActiveCallOriginApp: 0,
+            #[cfg(feature = "deprecated")]
+            ShowPhoneCallOriginSettingsUI,
+            #[cfg(not(feature = "deprecated"))]
+            ShowPhoneCallOriginSettingsUI: 0,
+            #[cfg(feature = "deprecated")]
+            SetCallOrigin,
+            #[cfg(not(feature = "deprecated"))]
+            SetCallOrigin: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallOriginManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPhoneCallOriginManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RequestSetAsActiveCallOriginAppAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOriginManagerStatics2", "RequestSetAsActiveCallOriginAppAsync", 6usize, |this| ((**(this as *const *const IPhoneCallOriginManagerStatics2_Vtbl)).RequestSetAsActiveCallOriginAppAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            RequestSetAsActiveCallOriginAppAsync,
+            #[cfg(not(feature = "deprecated"))]
+            RequestSetAsActiveCallOriginAppAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallOriginManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPhoneCallOriginManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallOriginManagerStatics3", "IsSupported", 6usize, |this| ((**(this as *const *const IPhoneCallOriginManagerStatics3_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            IsSupported,
+            #[cfg(not(feature = "deprecated"))]
+            IsSupported: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallOriginManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
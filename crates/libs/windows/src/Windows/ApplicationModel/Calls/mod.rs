@@ -3005,6 +3005,7 @@ impl CellularDtmfMode {
 impl windows_core::TypeKind for CellularDtmfMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CellularDtmfMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CellularDtmfMode").field(&self.0).finish()
@@ -3033,6 +3034,7 @@ impl DtmfKey {
 impl windows_core::TypeKind for DtmfKey {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DtmfKey {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DtmfKey").field(&self.0).finish()
@@ -3051,6 +3053,7 @@ impl DtmfToneAudioPlayback {
 impl windows_core::TypeKind for DtmfToneAudioPlayback {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DtmfToneAudioPlayback {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DtmfToneAudioPlayback").field(&self.0).finish()
@@ -3070,6 +3073,7 @@ impl PhoneAudioRoutingEndpoint {
 impl windows_core::TypeKind for PhoneAudioRoutingEndpoint {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneAudioRoutingEndpoint {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneAudioRoutingEndpoint").field(&self.0).finish()
@@ -3089,6 +3093,7 @@ impl PhoneCallAudioDevice {
 impl windows_core::TypeKind for PhoneCallAudioDevice {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallAudioDevice {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallAudioDevice").field(&self.0).finish()
@@ -3108,6 +3113,7 @@ impl PhoneCallDirection {
 impl windows_core::TypeKind for PhoneCallDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallDirection").field(&self.0).finish()
@@ -3126,6 +3132,7 @@ impl PhoneCallHistoryEntryMedia {
 impl windows_core::TypeKind for PhoneCallHistoryEntryMedia {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallHistoryEntryMedia {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallHistoryEntryMedia").field(&self.0).finish()
@@ -3144,6 +3151,7 @@ impl PhoneCallHistoryEntryOtherAppReadAccess {
 impl windows_core::TypeKind for PhoneCallHistoryEntryOtherAppReadAccess {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallHistoryEntryOtherAppReadAccess {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallHistoryEntryOtherAppReadAccess").field(&self.0).finish()
@@ -3164,6 +3172,7 @@ impl PhoneCallHistoryEntryQueryDesiredMedia {
 impl windows_core::TypeKind for PhoneCallHistoryEntryQueryDesiredMedia {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallHistoryEntryQueryDesiredMedia {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallHistoryEntryQueryDesiredMedia").field(&self.0).finish()
@@ -3215,6 +3224,7 @@ impl PhoneCallHistoryEntryRawAddressKind {
 impl windows_core::TypeKind for PhoneCallHistoryEntryRawAddressKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallHistoryEntryRawAddressKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallHistoryEntryRawAddressKind").field(&self.0).finish()
@@ -3233,6 +3243,7 @@ impl PhoneCallHistorySourceIdKind {
 impl windows_core::TypeKind for PhoneCallHistorySourceIdKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallHistorySourceIdKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallHistorySourceIdKind").field(&self.0).finish()
@@ -3252,6 +3263,7 @@ impl PhoneCallHistoryStoreAccessType {
 impl windows_core::TypeKind for PhoneCallHistoryStoreAccessType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallHistoryStoreAccessType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallHistoryStoreAccessType").field(&self.0).finish()
@@ -3271,6 +3283,7 @@ impl PhoneCallMedia {
 impl windows_core::TypeKind for PhoneCallMedia {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallMedia {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallMedia").field(&self.0).finish()
@@ -3292,6 +3305,7 @@ impl PhoneCallOperationStatus {
 impl windows_core::TypeKind for PhoneCallOperationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallOperationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallOperationStatus").field(&self.0).finish()
@@ -3314,6 +3328,7 @@ impl PhoneCallStatus {
 impl windows_core::TypeKind for PhoneCallStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallStatus").field(&self.0).finish()
@@ -3334,6 +3349,7 @@ impl PhoneLineNetworkOperatorDisplayTextLocation {
 impl windows_core::TypeKind for PhoneLineNetworkOperatorDisplayTextLocation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneLineNetworkOperatorDisplayTextLocation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneLineNetworkOperatorDisplayTextLocation").field(&self.0).finish()
@@ -3355,6 +3371,7 @@ impl PhoneLineOperationStatus {
 impl windows_core::TypeKind for PhoneLineOperationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneLineOperationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneLineOperationStatus").field(&self.0).finish()
@@ -3374,6 +3391,7 @@ impl PhoneLineTransport {
 impl windows_core::TypeKind for PhoneLineTransport {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneLineTransport {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneLineTransport").field(&self.0).finish()
@@ -3394,6 +3412,7 @@ impl PhoneLineWatcherStatus {
 impl windows_core::TypeKind for PhoneLineWatcherStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneLineWatcherStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneLineWatcherStatus").field(&self.0).finish()
@@ -3418,6 +3437,7 @@ impl PhoneNetworkState {
 impl windows_core::TypeKind for PhoneNetworkState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneNetworkState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneNetworkState").field(&self.0).finish()
@@ -3442,6 +3462,7 @@ impl PhoneSimState {
 impl windows_core::TypeKind for PhoneSimState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneSimState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneSimState").field(&self.0).finish()
@@ -3461,6 +3482,7 @@ impl PhoneVoicemailType {
 impl windows_core::TypeKind for PhoneVoicemailType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneVoicemailType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneVoicemailType").field(&self.0).finish()
@@ -3480,6 +3502,7 @@ impl TransportDeviceAudioRoutingStatus {
 impl windows_core::TypeKind for TransportDeviceAudioRoutingStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TransportDeviceAudioRoutingStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TransportDeviceAudioRoutingStatus").field(&self.0).finish()
@@ -3499,6 +3522,7 @@ impl VoipPhoneCallMedia {
 impl windows_core::TypeKind for VoipPhoneCallMedia {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VoipPhoneCallMedia {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VoipPhoneCallMedia").field(&self.0).finish()
@@ -3553,6 +3577,7 @@ impl VoipPhoneCallRejectReason {
 impl windows_core::TypeKind for VoipPhoneCallRejectReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VoipPhoneCallRejectReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VoipPhoneCallRejectReason").field(&self.0).finish()
@@ -3571,6 +3596,7 @@ impl VoipPhoneCallResourceReservationStatus {
 impl windows_core::TypeKind for VoipPhoneCallResourceReservationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VoipPhoneCallResourceReservationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VoipPhoneCallResourceReservationStatus").field(&self.0).finish()
@@ -3592,6 +3618,7 @@ impl VoipPhoneCallState {
 impl windows_core::TypeKind for VoipPhoneCallState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VoipPhoneCallState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VoipPhoneCallState").field(&self.0).finish()
@@ -3600,3 +3627,5 @@ impl core::fmt::Debug for VoipPhoneCallState {
 impl windows_core::RuntimeType for VoipPhoneCallState {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.Calls.VoipPhoneCallState;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
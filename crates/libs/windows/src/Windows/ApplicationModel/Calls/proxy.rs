@@ -0,0 +1,1563 @@
+impl windows_core::imp::ProxyVtable for ICallAnswerEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcceptedMedia(this: *mut core::ffi::c_void, result__: *mut VoipPhoneCallMedia) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallAnswerEventArgs", "AcceptedMedia", 6usize, |this| ((**(this as *const *const ICallAnswerEventArgs_Vtbl)).AcceptedMedia)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AcceptedMedia }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICallAnswerEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICallRejectEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RejectReason(this: *mut core::ffi::c_void, result__: *mut VoipPhoneCallRejectReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallRejectEventArgs", "RejectReason", 6usize, |this| ((**(this as *const *const ICallRejectEventArgs_Vtbl)).RejectReason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RejectReason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICallRejectEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICallStateChangeEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut VoipPhoneCallState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallStateChangeEventArgs", "State", 6usize, |this| ((**(this as *const *const ICallStateChangeEventArgs_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, State }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICallStateChangeEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILockScreenCallEndCallDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenCallEndCallDeferral", "Complete", 6usize, |this| ((**(this as *const *const ILockScreenCallEndCallDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILockScreenCallEndCallDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILockScreenCallEndRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenCallEndRequestedEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const ILockScreenCallEndRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenCallEndRequestedEventArgs", "Deadline", 7usize, |this| ((**(this as *const *const ILockScreenCallEndRequestedEventArgs_Vtbl)).Deadline)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral, Deadline }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILockScreenCallEndRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILockScreenCallUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Dismiss(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenCallUI", "Dismiss", 6usize, |this| ((**(this as *const *const ILockScreenCallUI_Vtbl)).Dismiss)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenCallUI", "EndRequested", 7usize, |this| ((**(this as *const *const ILockScreenCallUI_Vtbl)).EndRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEndRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenCallUI", "RemoveEndRequested", 8usize, |this| ((**(this as *const *const ILockScreenCallUI_Vtbl)).RemoveEndRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenCallUI", "Closed", 9usize, |this| ((**(this as *const *const ILockScreenCallUI_Vtbl)).Closed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenCallUI", "RemoveClosed", 10usize, |this| ((**(this as *const *const ILockScreenCallUI_Vtbl)).RemoveClosed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallTitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenCallUI", "CallTitle", 11usize, |this| ((**(this as *const *const ILockScreenCallUI_Vtbl)).CallTitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCallTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenCallUI", "SetCallTitle", 12usize, |this| ((**(this as *const *const ILockScreenCallUI_Vtbl)).SetCallTitle)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Dismiss,
+            EndRequested,
+            RemoveEndRequested,
+            Closed,
+            RemoveClosed,
+            CallTitle,
+            SetCallTitle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILockScreenCallUI as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMuteChangeEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Muted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMuteChangeEventArgs", "Muted", 6usize, |this| ((**(this as *const *const IMuteChangeEventArgs_Vtbl)).Muted)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Muted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMuteChangeEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneCall_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "StatusChanged", 6usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).StatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "RemoveStatusChanged", 7usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).RemoveStatusChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioDeviceChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "AudioDeviceChanged", 8usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).AudioDeviceChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAudioDeviceChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "RemoveAudioDeviceChanged", 9usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).RemoveAudioDeviceChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMutedChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "IsMutedChanged", 10usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).IsMutedChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveIsMutedChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "RemoveIsMutedChanged", 11usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).RemoveIsMutedChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "CallId", 12usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).CallId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMuted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "IsMuted", 13usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).IsMuted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut PhoneCallStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "Status", 14usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioDevice(this: *mut core::ffi::c_void, result__: *mut PhoneCallAudioDevice) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "AudioDevice", 15usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).AudioDevice)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPhoneCallInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "GetPhoneCallInfo", 16usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).GetPhoneCallInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPhoneCallInfoAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "GetPhoneCallInfoAsync", 17usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).GetPhoneCallInfoAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void, result__: *mut PhoneCallOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "End", 18usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).End)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "EndAsync", 19usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).EndAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendDtmfKey(this: *mut core::ffi::c_void, key: DtmfKey, dtmftoneaudioplayback: DtmfToneAudioPlayback, result__: *mut PhoneCallOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "SendDtmfKey", 20usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).SendDtmfKey)(this, key, dtmftoneaudioplayback, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendDtmfKeyAsync(this: *mut core::ffi::c_void, key: DtmfKey, dtmftoneaudioplayback: DtmfToneAudioPlayback, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "SendDtmfKeyAsync", 21usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).SendDtmfKeyAsync)(this, key, dtmftoneaudioplayback, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptIncoming(this: *mut core::ffi::c_void, result__: *mut PhoneCallOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "AcceptIncoming", 22usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).AcceptIncoming)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptIncomingAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "AcceptIncomingAsync", 23usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).AcceptIncomingAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hold(this: *mut core::ffi::c_void, result__: *mut PhoneCallOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "Hold", 24usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).Hold)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoldAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "HoldAsync", 25usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).HoldAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeFromHold(this: *mut core::ffi::c_void, result__: *mut PhoneCallOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "ResumeFromHold", 26usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).ResumeFromHold)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeFromHoldAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "ResumeFromHoldAsync", 27usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).ResumeFromHoldAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mute(this: *mut core::ffi::c_void, result__: *mut PhoneCallOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "Mute", 28usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).Mute)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MuteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "MuteAsync", 29usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).MuteAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unmute(this: *mut core::ffi::c_void, result__: *mut PhoneCallOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "Unmute", 30usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).Unmute)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnmuteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "UnmuteAsync", 31usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).UnmuteAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RejectIncoming(this: *mut core::ffi::c_void, result__: *mut PhoneCallOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "RejectIncoming", 32usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).RejectIncoming)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RejectIncomingAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "RejectIncomingAsync", 33usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).RejectIncomingAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeAudioDevice(this: *mut core::ffi::c_void, endpoint: PhoneCallAudioDevice, result__: *mut PhoneCallOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "ChangeAudioDevice", 34usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).ChangeAudioDevice)(this, endpoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeAudioDeviceAsync(this: *mut core::ffi::c_void, endpoint: PhoneCallAudioDevice, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCall", "ChangeAudioDeviceAsync", 35usize, |this| ((**(this as *const *const IPhoneCall_Vtbl)).ChangeAudioDeviceAsync)(this, endpoint, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StatusChanged,
+            RemoveStatusChanged,
+            AudioDeviceChanged,
+            RemoveAudioDeviceChanged,
+            IsMutedChanged,
+            RemoveIsMutedChanged,
+            CallId,
+            IsMuted,
+            Status,
+            AudioDevice,
+            GetPhoneCallInfo,
+            GetPhoneCallInfoAsync,
+            End,
+            EndAsync,
+            SendDtmfKey,
+            SendDtmfKeyAsync,
+            AcceptIncoming,
+            AcceptIncomingAsync,
+            Hold,
+            HoldAsync,
+            ResumeFromHold,
+            ResumeFromHoldAsync,
+            Mute,
+            MuteAsync,
+            Unmute,
+            UnmuteAsync,
+            RejectIncoming,
+            RejectIncomingAsync,
+            ChangeAudioDevice,
+            ChangeAudioDeviceAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCall as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPhoneCallBlockingStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BlockUnknownNumbers(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallBlockingStatics", "BlockUnknownNumbers", 6usize, |this| ((**(this as *const *const IPhoneCallBlockingStatics_Vtbl)).BlockUnknownNumbers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlockUnknownNumbers(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallBlockingStatics", "SetBlockUnknownNumbers", 7usize, |this| ((**(this as *const *const IPhoneCallBlockingStatics_Vtbl)).SetBlockUnknownNumbers)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlockPrivateNumbers(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallBlockingStatics", "BlockPrivateNumbers", 8usize, |this| ((**(this as *const *const IPhoneCallBlockingStatics_Vtbl)).BlockPrivateNumbers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlockPrivateNumbers(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallBlockingStatics", "SetBlockPrivateNumbers", 9usize, |this| ((**(this as *const *const IPhoneCallBlockingStatics_Vtbl)).SetBlockPrivateNumbers)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetCallBlockingListAsync(this: *mut core::ffi::c_void, phonenumberlist: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallBlockingStatics", "SetCallBlockingListAsync", 10usize, |this| ((**(this as *const *const IPhoneCallBlockingStatics_Vtbl)).SetCallBlockingListAsync)(this, phonenumberlist, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BlockUnknownNumbers,
+            SetBlockUnknownNumbers,
+            BlockPrivateNumbers,
+            SetBlockPrivateNumbers,
+            #[cfg(feature = "Foundation_Collections")]
+            SetCallBlockingListAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetCallBlockingListAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallBlockingStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneCallHistoryEntry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "Id", 6usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "Address", 7usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).Address)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAddress(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetAddress", 8usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "Duration", 9usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetDuration", 10usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCallerIdBlocked(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "IsCallerIdBlocked", 11usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).IsCallerIdBlocked)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCallerIdBlocked(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetIsCallerIdBlocked", 12usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetIsCallerIdBlocked)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEmergency(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "IsEmergency", 13usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).IsEmergency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEmergency(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetIsEmergency", 14usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetIsEmergency)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIncoming(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "IsIncoming", 15usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).IsIncoming)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsIncoming(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetIsIncoming", 16usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetIsIncoming)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMissed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "IsMissed", 17usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).IsMissed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsMissed(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetIsMissed", 18usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetIsMissed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRinging(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "IsRinging", 19usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).IsRinging)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsRinging(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetIsRinging", 20usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetIsRinging)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSeen(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "IsSeen", 21usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).IsSeen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsSeen(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetIsSeen", 22usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetIsSeen)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSuppressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "IsSuppressed", 23usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).IsSuppressed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsSuppressed(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetIsSuppressed", 24usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetIsSuppressed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVoicemail(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "IsVoicemail", 25usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).IsVoicemail)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsVoicemail(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetIsVoicemail", 26usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetIsVoicemail)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Media(this: *mut core::ffi::c_void, result__: *mut PhoneCallHistoryEntryMedia) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "Media", 27usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).Media)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMedia(this: *mut core::ffi::c_void, value: PhoneCallHistoryEntryMedia) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetMedia", 28usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetMedia)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherAppReadAccess(this: *mut core::ffi::c_void, result__: *mut PhoneCallHistoryEntryOtherAppReadAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "OtherAppReadAccess", 29usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).OtherAppReadAccess)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOtherAppReadAccess(this: *mut core::ffi::c_void, value: PhoneCallHistoryEntryOtherAppReadAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetOtherAppReadAccess", 30usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetOtherAppReadAccess)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "RemoteId", 31usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).RemoteId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetRemoteId", 32usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetRemoteId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SourceDisplayName", 33usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SourceDisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SourceId", 34usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SourceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetSourceId", 35usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetSourceId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceIdKind(this: *mut core::ffi::c_void, result__: *mut PhoneCallHistorySourceIdKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SourceIdKind", 36usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SourceIdKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceIdKind(this: *mut core::ffi::c_void, value: PhoneCallHistorySourceIdKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetSourceIdKind", 37usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetSourceIdKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "StartTime", 38usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntry", "SetStartTime", 39usize, |this| ((**(this as *const *const IPhoneCallHistoryEntry_Vtbl)).SetStartTime)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            Address,
+            SetAddress,
+            Duration,
+            SetDuration,
+            IsCallerIdBlocked,
+            SetIsCallerIdBlocked,
+            IsEmergency,
+            SetIsEmergency,
+            IsIncoming,
+            SetIsIncoming,
+            IsMissed,
+            SetIsMissed,
+            IsRinging,
+            SetIsRinging,
+            IsSeen,
+            SetIsSeen,
+            IsSuppressed,
+            SetIsSuppressed,
+            IsVoicemail,
+            SetIsVoicemail,
+            Media,
+            SetMedia,
+            OtherAppReadAccess,
+            SetOtherAppReadAccess,
+            RemoteId,
+            SetRemoteId,
+            SourceDisplayName,
+            SourceId,
+            SetSourceId,
+            SourceIdKind,
+            SetSourceIdKind,
+            StartTime,
+            SetStartTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallHistoryEntry as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneCallHistoryEntryAddress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntryAddress", "ContactId", 6usize, |this| ((**(this as *const *const IPhoneCallHistoryEntryAddress_Vtbl)).ContactId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContactId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntryAddress", "SetContactId", 7usize, |this| ((**(this as *const *const IPhoneCallHistoryEntryAddress_Vtbl)).SetContactId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntryAddress", "DisplayName", 8usize, |this| ((**(this as *const *const IPhoneCallHistoryEntryAddress_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntryAddress", "SetDisplayName", 9usize, |this| ((**(this as *const *const IPhoneCallHistoryEntryAddress_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntryAddress", "RawAddress", 10usize, |this| ((**(this as *const *const IPhoneCallHistoryEntryAddress_Vtbl)).RawAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRawAddress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntryAddress", "SetRawAddress", 11usize, |this| ((**(this as *const *const IPhoneCallHistoryEntryAddress_Vtbl)).SetRawAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawAddressKind(this: *mut core::ffi::c_void, result__: *mut PhoneCallHistoryEntryRawAddressKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntryAddress", "RawAddressKind", 12usize, |this| ((**(this as *const *const IPhoneCallHistoryEntryAddress_Vtbl)).RawAddressKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRawAddressKind(this: *mut core::ffi::c_void, value: PhoneCallHistoryEntryRawAddressKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntryAddress", "SetRawAddressKind", 13usize, |this| ((**(this as *const *const IPhoneCallHistoryEntryAddress_Vtbl)).SetRawAddressKind)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ContactId,
+            SetContactId,
+            DisplayName,
+            SetDisplayName,
+            RawAddress,
+            SetRawAddress,
+            RawAddressKind,
+            SetRawAddressKind,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallHistoryEntryAddress as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneCallHistoryEntryAddressFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, rawaddress: core::mem::MaybeUninit<windows_core::HSTRING>, rawaddresskind: PhoneCallHistoryEntryRawAddressKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntryAddressFactory", "Create", 6usize, |this| ((**(this as *const *const IPhoneCallHistoryEntryAddressFactory_Vtbl)).Create)(this, rawaddress, rawaddresskind, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallHistoryEntryAddressFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPhoneCallHistoryEntryQueryOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DesiredMedia(this: *mut core::ffi::c_void, result__: *mut PhoneCallHistoryEntryQueryDesiredMedia) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntryQueryOptions", "DesiredMedia", 6usize, |this| ((**(this as *const *const IPhoneCallHistoryEntryQueryOptions_Vtbl)).DesiredMedia)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredMedia(this: *mut core::ffi::c_void, value: PhoneCallHistoryEntryQueryDesiredMedia) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntryQueryOptions", "SetDesiredMedia", 7usize, |this| ((**(this as *const *const IPhoneCallHistoryEntryQueryOptions_Vtbl)).SetDesiredMedia)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SourceIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntryQueryOptions", "SourceIds", 8usize, |this| ((**(this as *const *const IPhoneCallHistoryEntryQueryOptions_Vtbl)).SourceIds)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DesiredMedia,
+            SetDesiredMedia,
+            #[cfg(feature = "Foundation_Collections")]
+            SourceIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SourceIds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallHistoryEntryQueryOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPhoneCallHistoryEntryReader_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadBatchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryEntryReader", "ReadBatchAsync", 6usize, |this| ((**(this as *const *const IPhoneCallHistoryEntryReader_Vtbl)).ReadBatchAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadBatchAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadBatchAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallHistoryEntryReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IPhoneCallHistoryManagerForUser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, accesstype: PhoneCallHistoryStoreAccessType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryManagerForUser", "RequestStoreAsync", 6usize, |this| ((**(this as *const *const IPhoneCallHistoryManagerForUser_Vtbl)).RequestStoreAsync)(this, accesstype, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryManagerForUser", "User", 7usize, |this| ((**(this as *const *const IPhoneCallHistoryManagerForUser_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestStoreAsync,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallHistoryManagerForUser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneCallHistoryManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, accesstype: PhoneCallHistoryStoreAccessType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryManagerStatics", "RequestStoreAsync", 6usize, |this| ((**(this as *const *const IPhoneCallHistoryManagerStatics_Vtbl)).RequestStoreAsync)(this, accesstype, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestStoreAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallHistoryManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IPhoneCallHistoryManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryManagerStatics2", "GetForUser", 6usize, |this| ((**(this as *const *const IPhoneCallHistoryManagerStatics2_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallHistoryManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPhoneCallHistoryStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEntryAsync(this: *mut core::ffi::c_void, callhistoryentryid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryStore", "GetEntryAsync", 6usize, |this| ((**(this as *const *const IPhoneCallHistoryStore_Vtbl)).GetEntryAsync)(this, callhistoryentryid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEntryReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryStore", "GetEntryReader", 7usize, |this| ((**(this as *const *const IPhoneCallHistoryStore_Vtbl)).GetEntryReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEntryReaderWithOptions(this: *mut core::ffi::c_void, queryoptions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryStore", "GetEntryReaderWithOptions", 8usize, |this| ((**(this as *const *const IPhoneCallHistoryStore_Vtbl)).GetEntryReaderWithOptions)(this, queryoptions, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveEntryAsync(this: *mut core::ffi::c_void, callhistoryentry: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryStore", "SaveEntryAsync", 9usize, |this| ((**(this as *const *const IPhoneCallHistoryStore_Vtbl)).SaveEntryAsync)(this, callhistoryentry, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteEntryAsync(this: *mut core::ffi::c_void, callhistoryentry: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryStore", "DeleteEntryAsync", 10usize, |this| ((**(this as *const *const IPhoneCallHistoryStore_Vtbl)).DeleteEntryAsync)(this, callhistoryentry, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DeleteEntriesAsync(this: *mut core::ffi::c_void, callhistoryentries: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryStore", "DeleteEntriesAsync", 11usize, |this| ((**(this as *const *const IPhoneCallHistoryStore_Vtbl)).DeleteEntriesAsync)(this, callhistoryentries, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkEntryAsSeenAsync(this: *mut core::ffi::c_void, callhistoryentry: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryStore", "MarkEntryAsSeenAsync", 12usize, |this| ((**(this as *const *const IPhoneCallHistoryStore_Vtbl)).MarkEntryAsSeenAsync)(this, callhistoryentry, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MarkEntriesAsSeenAsync(this: *mut core::ffi::c_void, callhistoryentries: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryStore", "MarkEntriesAsSeenAsync", 13usize, |this| ((**(this as *const *const IPhoneCallHistoryStore_Vtbl)).MarkEntriesAsSeenAsync)(this, callhistoryentries, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUnseenCountAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryStore", "GetUnseenCountAsync", 14usize, |this| ((**(this as *const *const IPhoneCallHistoryStore_Vtbl)).GetUnseenCountAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkAllAsSeenAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryStore", "MarkAllAsSeenAsync", 15usize, |this| ((**(this as *const *const IPhoneCallHistoryStore_Vtbl)).MarkAllAsSeenAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSourcesUnseenCountAsync(this: *mut core::ffi::c_void, sourceids: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryStore", "GetSourcesUnseenCountAsync", 16usize, |this| ((**(this as *const *const IPhoneCallHistoryStore_Vtbl)).GetSourcesUnseenCountAsync)(this, sourceids, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MarkSourcesAsSeenAsync(this: *mut core::ffi::c_void, sourceids: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallHistoryStore", "MarkSourcesAsSeenAsync", 17usize, |this| ((**(this as *const *const IPhoneCallHistoryStore_Vtbl)).MarkSourcesAsSeenAsync)(this, sourceids, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetEntryAsync,
+            GetEntryReader,
+            GetEntryReaderWithOptions,
+            SaveEntryAsync,
+            DeleteEntryAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            DeleteEntriesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DeleteEntriesAsync: 0,
+            MarkEntryAsSeenAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            MarkEntriesAsSeenAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MarkEntriesAsSeenAsync: 0,
+            GetUnseenCountAsync,
+            MarkAllAsSeenAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSourcesUnseenCountAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSourcesUnseenCountAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            MarkSourcesAsSeenAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MarkSourcesAsSeenAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallHistoryStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneCallInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LineId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallInfo", "LineId", 6usize, |this| ((**(this as *const *const IPhoneCallInfo_Vtbl)).LineId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHoldSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallInfo", "IsHoldSupported", 7usize, |this| ((**(this as *const *const IPhoneCallInfo_Vtbl)).IsHoldSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallInfo", "StartTime", 8usize, |this| ((**(this as *const *const IPhoneCallInfo_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhoneNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallInfo", "PhoneNumber", 9usize, |this| ((**(this as *const *const IPhoneCallInfo_Vtbl)).PhoneNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallInfo", "DisplayName", 10usize, |this| ((**(this as *const *const IPhoneCallInfo_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallDirection(this: *mut core::ffi::c_void, result__: *mut PhoneCallDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallInfo", "CallDirection", 11usize, |this| ((**(this as *const *const IPhoneCallInfo_Vtbl)).CallDirection)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LineId,
+            IsHoldSupported,
+            StartTime,
+            PhoneNumber,
+            DisplayName,
+            CallDirection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneCallManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowPhoneCallUI(this: *mut core::ffi::c_void, phonenumber: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallManagerStatics", "ShowPhoneCallUI", 6usize, |this| ((**(this as *const *const IPhoneCallManagerStatics_Vtbl)).ShowPhoneCallUI)(this, phonenumber, displayname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowPhoneCallUI }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneCallManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CallStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallManagerStatics2", "CallStateChanged", 6usize, |this| ((**(this as *const *const IPhoneCallManagerStatics2_Vtbl)).CallStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCallStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallManagerStatics2", "RemoveCallStateChanged", 7usize, |this| ((**(this as *const *const IPhoneCallManagerStatics2_Vtbl)).RemoveCallStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCallActive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallManagerStatics2", "IsCallActive", 8usize, |this| ((**(this as *const *const IPhoneCallManagerStatics2_Vtbl)).IsCallActive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCallIncoming(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallManagerStatics2", "IsCallIncoming", 9usize, |this| ((**(this as *const *const IPhoneCallManagerStatics2_Vtbl)).IsCallIncoming)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowPhoneCallSettingsUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallManagerStatics2", "ShowPhoneCallSettingsUI", 10usize, |this| ((**(this as *const *const IPhoneCallManagerStatics2_Vtbl)).ShowPhoneCallSettingsUI)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallManagerStatics2", "RequestStoreAsync", 11usize, |this| ((**(this as *const *const IPhoneCallManagerStatics2_Vtbl)).RequestStoreAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CallStateChanged,
+            RemoveCallStateChanged,
+            IsCallActive,
+            IsCallIncoming,
+            ShowPhoneCallSettingsUI,
+            RequestStoreAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneCallStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFromId(this: *mut core::ffi::c_void, callid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallStatics", "GetFromId", 6usize, |this| ((**(this as *const *const IPhoneCallStatics_Vtbl)).GetFromId)(this, callid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFromId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneCallStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEmergencyPhoneNumberAsync(this: *mut core::ffi::c_void, number: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallStore", "IsEmergencyPhoneNumberAsync", 6usize, |this| ((**(this as *const *const IPhoneCallStore_Vtbl)).IsEmergencyPhoneNumberAsync)(this, number, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultLineAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallStore", "GetDefaultLineAsync", 7usize, |this| ((**(this as *const *const IPhoneCallStore_Vtbl)).GetDefaultLineAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestLineWatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallStore", "RequestLineWatcher", 8usize, |this| ((**(this as *const *const IPhoneCallStore_Vtbl)).RequestLineWatcher)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEmergencyPhoneNumberAsync,
+            GetDefaultLineAsync,
+            RequestLineWatcher,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneCallVideoCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsVideoCallingCapable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallVideoCapabilities", "IsVideoCallingCapable", 6usize, |this| ((**(this as *const *const IPhoneCallVideoCapabilities_Vtbl)).IsVideoCallingCapable)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsVideoCallingCapable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallVideoCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneCallVideoCapabilitiesManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCapabilitiesAsync(this: *mut core::ffi::c_void, phonenumber: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallVideoCapabilitiesManagerStatics", "GetCapabilitiesAsync", 6usize, |this| ((**(this as *const *const IPhoneCallVideoCapabilitiesManagerStatics_Vtbl)).GetCapabilitiesAsync)(this, phonenumber, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCapabilitiesAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallVideoCapabilitiesManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPhoneCallsResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OperationStatus(this: *mut core::ffi::c_void, result__: *mut PhoneLineOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallsResult", "OperationStatus", 6usize, |this| ((**(this as *const *const IPhoneCallsResult_Vtbl)).OperationStatus)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AllActivePhoneCalls(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallsResult", "AllActivePhoneCalls", 7usize, |this| ((**(this as *const *const IPhoneCallsResult_Vtbl)).AllActivePhoneCalls)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OperationStatus,
+            #[cfg(feature = "Foundation_Collections")]
+            AllActivePhoneCalls,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AllActivePhoneCalls: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallsResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Contacts")]
+impl windows_core::imp::ProxyVtable for IPhoneDialOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Number(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneDialOptions", "Number", 6usize, |this| ((**(this as *const *const IPhoneDialOptions_Vtbl)).Number)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumber(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneDialOptions", "SetNumber", 7usize, |this| ((**(this as *const *const IPhoneDialOptions_Vtbl)).SetNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneDialOptions", "DisplayName", 8usize, |this| ((**(this as *const *const IPhoneDialOptions_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneDialOptions", "SetDisplayName", 9usize, |this| ((**(this as *const *const IPhoneDialOptions_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Contacts")]
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneDialOptions", "Contact", 10usize, |this| ((**(this as *const *const IPhoneDialOptions_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Contacts")]
+        unsafe extern "system" fn SetContact(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneDialOptions", "SetContact", 11usize, |this| ((**(this as *const *const IPhoneDialOptions_Vtbl)).SetContact)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Contacts")]
+        unsafe extern "system" fn ContactPhone(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneDialOptions", "ContactPhone", 12usize, |this| ((**(this as *const *const IPhoneDialOptions_Vtbl)).ContactPhone)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Contacts")]
+        unsafe extern "system" fn SetContactPhone(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneDialOptions", "SetContactPhone", 13usize, |this| ((**(this as *const *const IPhoneDialOptions_Vtbl)).SetContactPhone)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Media(this: *mut core::ffi::c_void, result__: *mut PhoneCallMedia) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneDialOptions", "Media", 14usize, |this| ((**(this as *const *const IPhoneDialOptions_Vtbl)).Media)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMedia(this: *mut core::ffi::c_void, value: PhoneCallMedia) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneDialOptions", "SetMedia", 15usize, |this| ((**(this as *const *const IPhoneDialOptions_Vtbl)).SetMedia)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioEndpoint(this: *mut core::ffi::c_void, result__: *mut PhoneAudioRoutingEndpoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneDialOptions", "AudioEndpoint", 16usize, |this| ((**(this as *const *const IPhoneDialOptions_Vtbl)).AudioEndpoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioEndpoint(this: *mut core::ffi::c_void, value: PhoneAudioRoutingEndpoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneDialOptions", "SetAudioEndpoint", 17usize, |this| ((**(this as *const *const IPhoneDialOptions_Vtbl)).SetAudioEndpoint)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Number,
+            SetNumber,
+            DisplayName,
+            SetDisplayName,
+            #[cfg(feature = "ApplicationModel_Contacts")]
+            Contact,
+            #[cfg(not(feature = "ApplicationModel_Contacts"))]
+            Contact: 0,
+            #[cfg(feature = "ApplicationModel_Contacts")]
+            SetContact,
+            #[cfg(not(feature = "ApplicationModel_Contacts"))]
+            SetContact: 0,
+            #[cfg(feature = "ApplicationModel_Contacts")]
+            ContactPhone,
+            #[cfg(not(feature = "ApplicationModel_Contacts"))]
+            ContactPhone: 0,
+            #[cfg(feature = "ApplicationModel_Contacts")]
+            SetContactPhone,
+            #[cfg(not(feature = "ApplicationModel_Contacts"))]
+            SetContactPhone: 0,
+            Media,
+            SetMedia,
+            AudioEndpoint,
+            SetAudioEndpoint,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneDialOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for IPhoneLine_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LineChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "LineChanged", 6usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).LineChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLineChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "RemoveLineChanged", 7usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).RemoveLineChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "Id", 8usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn DisplayColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "DisplayColor", 9usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).DisplayColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkState(this: *mut core::ffi::c_void, result__: *mut PhoneNetworkState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "NetworkState", 10usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).NetworkState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "DisplayName", 11usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Voicemail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "Voicemail", 12usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).Voicemail)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "NetworkName", 13usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).NetworkName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CellularDetails(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "CellularDetails", 14usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).CellularDetails)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Transport(this: *mut core::ffi::c_void, result__: *mut PhoneLineTransport) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "Transport", 15usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).Transport)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanDial(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "CanDial", 16usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).CanDial)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportsTile(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "SupportsTile", 17usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).SupportsTile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoCallingCapabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "VideoCallingCapabilities", 18usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).VideoCallingCapabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineConfiguration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "LineConfiguration", 19usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).LineConfiguration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsImmediateDialNumberAsync(this: *mut core::ffi::c_void, number: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "IsImmediateDialNumberAsync", 20usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).IsImmediateDialNumberAsync)(this, number, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Dial(this: *mut core::ffi::c_void, number: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "Dial", 21usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).Dial)(this, number, displayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn DialWithOptions(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine", "DialWithOptions", 22usize, |this| ((**(this as *const *const IPhoneLine_Vtbl)).DialWithOptions)(this, options), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LineChanged,
+            RemoveLineChanged,
+            Id,
+            #[cfg(feature = "UI")]
+            DisplayColor,
+            #[cfg(not(feature = "UI"))]
+            DisplayColor: 0,
+            NetworkState,
+            DisplayName,
+            Voicemail,
+            NetworkName,
+            CellularDetails,
+            Transport,
+            CanDial,
+            SupportsTile,
+            VideoCallingCapabilities,
+            LineConfiguration,
+            IsImmediateDialNumberAsync,
+            Dial,
+            DialWithOptions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneLine as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneLine2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn EnableTextReply(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine2", "EnableTextReply", 6usize, |this| ((**(this as *const *const IPhoneLine2_Vtbl)).EnableTextReply)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine2", "TransportDeviceId", 7usize, |this| ((**(this as *const *const IPhoneLine2_Vtbl)).TransportDeviceId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            EnableTextReply,
+            #[cfg(not(feature = "deprecated"))]
+            EnableTextReply: 0,
+            TransportDeviceId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneLine2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneLine3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DialWithResult(this: *mut core::ffi::c_void, number: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine3", "DialWithResult", 6usize, |this| ((**(this as *const *const IPhoneLine3_Vtbl)).DialWithResult)(this, number, displayname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DialWithResultAsync(this: *mut core::ffi::c_void, number: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine3", "DialWithResultAsync", 7usize, |this| ((**(this as *const *const IPhoneLine3_Vtbl)).DialWithResultAsync)(this, number, displayname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllActivePhoneCalls(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine3", "GetAllActivePhoneCalls", 8usize, |this| ((**(this as *const *const IPhoneLine3_Vtbl)).GetAllActivePhoneCalls)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllActivePhoneCallsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLine3", "GetAllActivePhoneCallsAsync", 9usize, |this| ((**(this as *const *const IPhoneLine3_Vtbl)).GetAllActivePhoneCallsAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DialWithResult,
+            DialWithResultAsync,
+            GetAllActivePhoneCalls,
+            GetAllActivePhoneCallsAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneLine3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneLineCellularDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SimState(this: *mut core::ffi::c_void, result__: *mut PhoneSimState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineCellularDetails", "SimState", 6usize, |this| ((**(this as *const *const IPhoneLineCellularDetails_Vtbl)).SimState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SimSlotIndex(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineCellularDetails", "SimSlotIndex", 7usize, |this| ((**(this as *const *const IPhoneLineCellularDetails_Vtbl)).SimSlotIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsModemOn(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineCellularDetails", "IsModemOn", 8usize, |this| ((**(this as *const *const IPhoneLineCellularDetails_Vtbl)).IsModemOn)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegistrationRejectCode(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineCellularDetails", "RegistrationRejectCode", 9usize, |this| ((**(this as *const *const IPhoneLineCellularDetails_Vtbl)).RegistrationRejectCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkOperatorDisplayText(this: *mut core::ffi::c_void, location: PhoneLineNetworkOperatorDisplayTextLocation, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineCellularDetails", "GetNetworkOperatorDisplayText", 10usize, |this| ((**(this as *const *const IPhoneLineCellularDetails_Vtbl)).GetNetworkOperatorDisplayText)(this, location, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SimState,
+            SimSlotIndex,
+            IsModemOn,
+            RegistrationRejectCode,
+            GetNetworkOperatorDisplayText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneLineCellularDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPhoneLineConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsVideoCallingEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineConfiguration", "IsVideoCallingEnabled", 6usize, |this| ((**(this as *const *const IPhoneLineConfiguration_Vtbl)).IsVideoCallingEnabled)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ExtendedProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineConfiguration", "ExtendedProperties", 7usize, |this| ((**(this as *const *const IPhoneLineConfiguration_Vtbl)).ExtendedProperties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsVideoCallingEnabled,
+            #[cfg(feature = "Foundation_Collections")]
+            ExtendedProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ExtendedProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneLineConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneLineDialResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DialCallStatus(this: *mut core::ffi::c_void, result__: *mut PhoneCallOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineDialResult", "DialCallStatus", 6usize, |this| ((**(this as *const *const IPhoneLineDialResult_Vtbl)).DialCallStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DialedCall(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineDialResult", "DialedCall", 7usize, |this| ((**(this as *const *const IPhoneLineDialResult_Vtbl)).DialedCall)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DialCallStatus, DialedCall }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneLineDialResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneLineStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, lineid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineStatics", "FromIdAsync", 6usize, |this| ((**(this as *const *const IPhoneLineStatics_Vtbl)).FromIdAsync)(this, lineid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneLineStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IPhoneLineTransportDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice", "DeviceId", 6usize, |this| ((**(this as *const *const IPhoneLineTransportDevice_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Transport(this: *mut core::ffi::c_void, result__: *mut PhoneLineTransport) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice", "Transport", 7usize, |this| ((**(this as *const *const IPhoneLineTransportDevice_Vtbl)).Transport)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice", "RequestAccessAsync", 8usize, |this| ((**(this as *const *const IPhoneLineTransportDevice_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterApp(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice", "RegisterApp", 9usize, |this| ((**(this as *const *const IPhoneLineTransportDevice_Vtbl)).RegisterApp)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn RegisterAppForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice", "RegisterAppForUser", 10usize, |this| ((**(this as *const *const IPhoneLineTransportDevice_Vtbl)).RegisterAppForUser)(this, user), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterApp(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice", "UnregisterApp", 11usize, |this| ((**(this as *const *const IPhoneLineTransportDevice_Vtbl)).UnregisterApp)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn UnregisterAppForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice", "UnregisterAppForUser", 12usize, |this| ((**(this as *const *const IPhoneLineTransportDevice_Vtbl)).UnregisterAppForUser)(this, user), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRegistered(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice", "IsRegistered", 13usize, |this| ((**(this as *const *const IPhoneLineTransportDevice_Vtbl)).IsRegistered)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice", "Connect", 14usize, |this| ((**(this as *const *const IPhoneLineTransportDevice_Vtbl)).Connect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice", "ConnectAsync", 15usize, |this| ((**(this as *const *const IPhoneLineTransportDevice_Vtbl)).ConnectAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            Transport,
+            #[cfg(feature = "Devices_Enumeration")]
+            RequestAccessAsync,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            RequestAccessAsync: 0,
+            RegisterApp,
+            #[cfg(feature = "System")]
+            RegisterAppForUser,
+            #[cfg(not(feature = "System"))]
+            RegisterAppForUser: 0,
+            UnregisterApp,
+            #[cfg(feature = "System")]
+            UnregisterAppForUser,
+            #[cfg(not(feature = "System"))]
+            UnregisterAppForUser: 0,
+            IsRegistered,
+            Connect,
+            ConnectAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneLineTransportDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneLineTransportDevice2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AudioRoutingStatus(this: *mut core::ffi::c_void, result__: *mut TransportDeviceAudioRoutingStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice2", "AudioRoutingStatus", 6usize, |this| ((**(this as *const *const IPhoneLineTransportDevice2_Vtbl)).AudioRoutingStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioRoutingStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice2", "AudioRoutingStatusChanged", 7usize, |this| ((**(this as *const *const IPhoneLineTransportDevice2_Vtbl)).AudioRoutingStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAudioRoutingStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice2", "RemoveAudioRoutingStatusChanged", 8usize, |this| ((**(this as *const *const IPhoneLineTransportDevice2_Vtbl)).RemoveAudioRoutingStatusChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn InBandRingingEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice2", "InBandRingingEnabled", 9usize, |this| ((**(this as *const *const IPhoneLineTransportDevice2_Vtbl)).InBandRingingEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InBandRingingEnabledChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice2", "InBandRingingEnabledChanged", 10usize, |this| ((**(this as *const *const IPhoneLineTransportDevice2_Vtbl)).InBandRingingEnabledChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveInBandRingingEnabledChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDevice2", "RemoveInBandRingingEnabledChanged", 11usize, |this| ((**(this as *const *const IPhoneLineTransportDevice2_Vtbl)).RemoveInBandRingingEnabledChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AudioRoutingStatus,
+            AudioRoutingStatusChanged,
+            RemoveAudioRoutingStatusChanged,
+            InBandRingingEnabled,
+            InBandRingingEnabledChanged,
+            RemoveInBandRingingEnabledChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneLineTransportDevice2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneLineTransportDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromId(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDeviceStatics", "FromId", 6usize, |this| ((**(this as *const *const IPhoneLineTransportDeviceStatics_Vtbl)).FromId)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDeviceStatics", "GetDeviceSelector", 7usize, |this| ((**(this as *const *const IPhoneLineTransportDeviceStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorForPhoneLineTransport(this: *mut core::ffi::c_void, transport: PhoneLineTransport, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineTransportDeviceStatics", "GetDeviceSelectorForPhoneLineTransport", 8usize, |this| ((**(this as *const *const IPhoneLineTransportDeviceStatics_Vtbl)).GetDeviceSelectorForPhoneLineTransport)(this, transport, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FromId,
+            GetDeviceSelector,
+            GetDeviceSelectorForPhoneLineTransport,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneLineTransportDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneLineWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineWatcher", "Start", 6usize, |this| ((**(this as *const *const IPhoneLineWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineWatcher", "Stop", 7usize, |this| ((**(this as *const *const IPhoneLineWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineAdded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineWatcher", "LineAdded", 8usize, |this| ((**(this as *const *const IPhoneLineWatcher_Vtbl)).LineAdded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLineAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineWatcher", "RemoveLineAdded", 9usize, |this| ((**(this as *const *const IPhoneLineWatcher_Vtbl)).RemoveLineAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineRemoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineWatcher", "LineRemoved", 10usize, |this| ((**(this as *const *const IPhoneLineWatcher_Vtbl)).LineRemoved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLineRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineWatcher", "RemoveLineRemoved", 11usize, |this| ((**(this as *const *const IPhoneLineWatcher_Vtbl)).RemoveLineRemoved)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineWatcher", "LineUpdated", 12usize, |this| ((**(this as *const *const IPhoneLineWatcher_Vtbl)).LineUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLineUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineWatcher", "RemoveLineUpdated", 13usize, |this| ((**(this as *const *const IPhoneLineWatcher_Vtbl)).RemoveLineUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineWatcher", "EnumerationCompleted", 14usize, |this| ((**(this as *const *const IPhoneLineWatcher_Vtbl)).EnumerationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnumerationCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineWatcher", "RemoveEnumerationCompleted", 15usize, |this| ((**(this as *const *const IPhoneLineWatcher_Vtbl)).RemoveEnumerationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stopped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineWatcher", "Stopped", 16usize, |this| ((**(this as *const *const IPhoneLineWatcher_Vtbl)).Stopped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStopped(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineWatcher", "RemoveStopped", 17usize, |this| ((**(this as *const *const IPhoneLineWatcher_Vtbl)).RemoveStopped)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut PhoneLineWatcherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineWatcher", "Status", 18usize, |this| ((**(this as *const *const IPhoneLineWatcher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Start,
+            Stop,
+            LineAdded,
+            RemoveLineAdded,
+            LineRemoved,
+            RemoveLineRemoved,
+            LineUpdated,
+            RemoveLineUpdated,
+            EnumerationCompleted,
+            RemoveEnumerationCompleted,
+            Stopped,
+            RemoveStopped,
+            Status,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneLineWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneLineWatcherEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LineId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineWatcherEventArgs", "LineId", 6usize, |this| ((**(this as *const *const IPhoneLineWatcherEventArgs_Vtbl)).LineId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LineId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneLineWatcherEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneVoicemail_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Number(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneVoicemail", "Number", 6usize, |this| ((**(this as *const *const IPhoneVoicemail_Vtbl)).Number)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneVoicemail", "MessageCount", 7usize, |this| ((**(this as *const *const IPhoneVoicemail_Vtbl)).MessageCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut PhoneVoicemailType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneVoicemail", "Type", 8usize, |this| ((**(this as *const *const IPhoneVoicemail_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DialVoicemailAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneVoicemail", "DialVoicemailAsync", 9usize, |this| ((**(this as *const *const IPhoneVoicemail_Vtbl)).DialVoicemailAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Number, MessageCount, Type, DialVoicemailAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneVoicemail as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVoipCallCoordinator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReserveCallResourcesAsync(this: *mut core::ffi::c_void, taskentrypoint: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinator", "ReserveCallResourcesAsync", 6usize, |this| ((**(this as *const *const IVoipCallCoordinator_Vtbl)).ReserveCallResourcesAsync)(this, taskentrypoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MuteStateChanged(this: *mut core::ffi::c_void, mutechangehandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinator", "MuteStateChanged", 7usize, |this| ((**(this as *const *const IVoipCallCoordinator_Vtbl)).MuteStateChanged)(this, mutechangehandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMuteStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinator", "RemoveMuteStateChanged", 8usize, |this| ((**(this as *const *const IVoipCallCoordinator_Vtbl)).RemoveMuteStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestNewIncomingCall(this: *mut core::ffi::c_void, context: core::mem::MaybeUninit<windows_core::HSTRING>, contactname: core::mem::MaybeUninit<windows_core::HSTRING>, contactnumber: core::mem::MaybeUninit<windows_core::HSTRING>, contactimage: *mut core::ffi::c_void, servicename: core::mem::MaybeUninit<windows_core::HSTRING>, brandingimage: *mut core::ffi::c_void, calldetails: core::mem::MaybeUninit<windows_core::HSTRING>, ringtone: *mut core::ffi::c_void, media: VoipPhoneCallMedia, ringtimeout: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinator", "RequestNewIncomingCall", 9usize, |this| ((**(this as *const *const IVoipCallCoordinator_Vtbl)).RequestNewIncomingCall)(this, context, contactname, contactnumber, contactimage, servicename, brandingimage, calldetails, ringtone, media, ringtimeout, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestNewOutgoingCall(this: *mut core::ffi::c_void, context: core::mem::MaybeUninit<windows_core::HSTRING>, contactname: core::mem::MaybeUninit<windows_core::HSTRING>, servicename: core::mem::MaybeUninit<windows_core::HSTRING>, media: VoipPhoneCallMedia, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinator", "RequestNewOutgoingCall", 10usize, |this| ((**(this as *const *const IVoipCallCoordinator_Vtbl)).RequestNewOutgoingCall)(this, context, contactname, servicename, media, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyMuted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinator", "NotifyMuted", 11usize, |this| ((**(this as *const *const IVoipCallCoordinator_Vtbl)).NotifyMuted)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyUnmuted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinator", "NotifyUnmuted", 12usize, |this| ((**(this as *const *const IVoipCallCoordinator_Vtbl)).NotifyUnmuted)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestOutgoingUpgradeToVideoCall(this: *mut core::ffi::c_void, callupgradeguid: windows_core::GUID, context: core::mem::MaybeUninit<windows_core::HSTRING>, contactname: core::mem::MaybeUninit<windows_core::HSTRING>, servicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinator", "RequestOutgoingUpgradeToVideoCall", 13usize, |this| ((**(this as *const *const IVoipCallCoordinator_Vtbl)).RequestOutgoingUpgradeToVideoCall)(this, callupgradeguid, context, contactname, servicename, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestIncomingUpgradeToVideoCall(this: *mut core::ffi::c_void, context: core::mem::MaybeUninit<windows_core::HSTRING>, contactname: core::mem::MaybeUninit<windows_core::HSTRING>, contactnumber: core::mem::MaybeUninit<windows_core::HSTRING>, contactimage: *mut core::ffi::c_void, servicename: core::mem::MaybeUninit<windows_core::HSTRING>, brandingimage: *mut core::ffi::c_void, calldetails: core::mem::MaybeUninit<windows_core::HSTRING>, ringtone: *mut core::ffi::c_void, ringtimeout: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinator", "RequestIncomingUpgradeToVideoCall", 14usize, |this| ((**(this as *const *const IVoipCallCoordinator_Vtbl)).RequestIncomingUpgradeToVideoCall)(this, context, contactname, contactnumber, contactimage, servicename, brandingimage, calldetails, ringtone, ringtimeout, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminateCellularCall(this: *mut core::ffi::c_void, callupgradeguid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinator", "TerminateCellularCall", 15usize, |this| ((**(this as *const *const IVoipCallCoordinator_Vtbl)).TerminateCellularCall)(this, callupgradeguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelUpgrade(this: *mut core::ffi::c_void, callupgradeguid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinator", "CancelUpgrade", 16usize, |this| ((**(this as *const *const IVoipCallCoordinator_Vtbl)).CancelUpgrade)(this, callupgradeguid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReserveCallResourcesAsync,
+            MuteStateChanged,
+            RemoveMuteStateChanged,
+            RequestNewIncomingCall,
+            RequestNewOutgoingCall,
+            NotifyMuted,
+            NotifyUnmuted,
+            RequestOutgoingUpgradeToVideoCall,
+            RequestIncomingUpgradeToVideoCall,
+            TerminateCellularCall,
+            CancelUpgrade,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoipCallCoordinator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVoipCallCoordinator2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetupNewAcceptedCall(this: *mut core::ffi::c_void, context: core::mem::MaybeUninit<windows_core::HSTRING>, contactname: core::mem::MaybeUninit<windows_core::HSTRING>, contactnumber: core::mem::MaybeUninit<windows_core::HSTRING>, servicename: core::mem::MaybeUninit<windows_core::HSTRING>, media: VoipPhoneCallMedia, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinator2", "SetupNewAcceptedCall", 6usize, |this| ((**(this as *const *const IVoipCallCoordinator2_Vtbl)).SetupNewAcceptedCall)(this, context, contactname, contactnumber, servicename, media, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetupNewAcceptedCall }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoipCallCoordinator2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVoipCallCoordinator3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestNewAppInitiatedCall(this: *mut core::ffi::c_void, context: core::mem::MaybeUninit<windows_core::HSTRING>, contactname: core::mem::MaybeUninit<windows_core::HSTRING>, contactnumber: core::mem::MaybeUninit<windows_core::HSTRING>, servicename: core::mem::MaybeUninit<windows_core::HSTRING>, media: VoipPhoneCallMedia, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinator3", "RequestNewAppInitiatedCall", 6usize, |this| ((**(this as *const *const IVoipCallCoordinator3_Vtbl)).RequestNewAppInitiatedCall)(this, context, contactname, contactnumber, servicename, media, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestNewIncomingCallWithContactRemoteId(this: *mut core::ffi::c_void, context: core::mem::MaybeUninit<windows_core::HSTRING>, contactname: core::mem::MaybeUninit<windows_core::HSTRING>, contactnumber: core::mem::MaybeUninit<windows_core::HSTRING>, contactimage: *mut core::ffi::c_void, servicename: core::mem::MaybeUninit<windows_core::HSTRING>, brandingimage: *mut core::ffi::c_void, calldetails: core::mem::MaybeUninit<windows_core::HSTRING>, ringtone: *mut core::ffi::c_void, media: VoipPhoneCallMedia, ringtimeout: super::super::Foundation::TimeSpan, contactremoteid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinator3", "RequestNewIncomingCallWithContactRemoteId", 7usize, |this| ((**(this as *const *const IVoipCallCoordinator3_Vtbl)).RequestNewIncomingCallWithContactRemoteId)(this, context, contactname, contactnumber, contactimage, servicename, brandingimage, calldetails, ringtone, media, ringtimeout, contactremoteid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestNewAppInitiatedCall,
+            RequestNewIncomingCallWithContactRemoteId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoipCallCoordinator3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVoipCallCoordinator4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReserveOneProcessCallResourcesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinator4", "ReserveOneProcessCallResourcesAsync", 6usize, |this| ((**(this as *const *const IVoipCallCoordinator4_Vtbl)).ReserveOneProcessCallResourcesAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReserveOneProcessCallResourcesAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoipCallCoordinator4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVoipCallCoordinatorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipCallCoordinatorStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IVoipCallCoordinatorStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoipCallCoordinatorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVoipPhoneCall_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EndRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "EndRequested", 6usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).EndRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEndRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "RemoveEndRequested", 7usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).RemoveEndRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoldRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "HoldRequested", 8usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).HoldRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHoldRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "RemoveHoldRequested", 9usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).RemoveHoldRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "ResumeRequested", 10usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).ResumeRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveResumeRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "RemoveResumeRequested", 11usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).RemoveResumeRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnswerRequested(this: *mut core::ffi::c_void, accepthandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "AnswerRequested", 12usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).AnswerRequested)(this, accepthandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAnswerRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "RemoveAnswerRequested", 13usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).RemoveAnswerRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RejectRequested(this: *mut core::ffi::c_void, rejecthandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "RejectRequested", 14usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).RejectRequested)(this, rejecthandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRejectRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "RemoveRejectRequested", 15usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).RemoveRejectRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyCallHeld(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "NotifyCallHeld", 16usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).NotifyCallHeld)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyCallActive(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "NotifyCallActive", 17usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).NotifyCallActive)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyCallEnded(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "NotifyCallEnded", 18usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).NotifyCallEnded)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContactName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "ContactName", 19usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).ContactName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContactName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "SetContactName", 20usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).SetContactName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "StartTime", 21usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "SetStartTime", 22usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).SetStartTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallMedia(this: *mut core::ffi::c_void, result__: *mut VoipPhoneCallMedia) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "CallMedia", 23usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).CallMedia)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCallMedia(this: *mut core::ffi::c_void, value: VoipPhoneCallMedia) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "SetCallMedia", 24usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).SetCallMedia)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyCallReady(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall", "NotifyCallReady", 25usize, |this| ((**(this as *const *const IVoipPhoneCall_Vtbl)).NotifyCallReady)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EndRequested,
+            RemoveEndRequested,
+            HoldRequested,
+            RemoveHoldRequested,
+            ResumeRequested,
+            RemoveResumeRequested,
+            AnswerRequested,
+            RemoveAnswerRequested,
+            RejectRequested,
+            RemoveRejectRequested,
+            NotifyCallHeld,
+            NotifyCallActive,
+            NotifyCallEnded,
+            ContactName,
+            SetContactName,
+            StartTime,
+            SetStartTime,
+            CallMedia,
+            SetCallMedia,
+            NotifyCallReady,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoipPhoneCall as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVoipPhoneCall2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryShowAppUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall2", "TryShowAppUI", 6usize, |this| ((**(this as *const *const IVoipPhoneCall2_Vtbl)).TryShowAppUI)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryShowAppUI }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoipPhoneCall2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVoipPhoneCall3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifyCallAccepted(this: *mut core::ffi::c_void, media: VoipPhoneCallMedia) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoipPhoneCall3", "NotifyCallAccepted", 6usize, |this| ((**(this as *const *const IVoipPhoneCall3_Vtbl)).NotifyCallAccepted)(this, media), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NotifyCallAccepted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoipPhoneCall3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -3082,6 +3082,7 @@ impl ChatConversationThreadingKind {
 impl windows_core::TypeKind for ChatConversationThreadingKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ChatConversationThreadingKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ChatConversationThreadingKind").field(&self.0).finish()
@@ -3100,6 +3101,7 @@ impl ChatItemKind {
 impl windows_core::TypeKind for ChatItemKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ChatItemKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ChatItemKind").field(&self.0).finish()
@@ -3120,6 +3122,7 @@ impl ChatMessageChangeType {
 impl windows_core::TypeKind for ChatMessageChangeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ChatMessageChangeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ChatMessageChangeType").field(&self.0).finish()
@@ -3143,6 +3146,7 @@ impl ChatMessageKind {
 impl windows_core::TypeKind for ChatMessageKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ChatMessageKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ChatMessageKind").field(&self.0).finish()
@@ -3163,6 +3167,7 @@ impl ChatMessageOperatorKind {
 impl windows_core::TypeKind for ChatMessageOperatorKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ChatMessageOperatorKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ChatMessageOperatorKind").field(&self.0).finish()
@@ -3193,6 +3198,7 @@ impl ChatMessageStatus {
 impl windows_core::TypeKind for ChatMessageStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ChatMessageStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ChatMessageStatus").field(&self.0).finish()
@@ -3213,6 +3219,7 @@ impl ChatMessageTransportKind {
 impl windows_core::TypeKind for ChatMessageTransportKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ChatMessageTransportKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ChatMessageTransportKind").field(&self.0).finish()
@@ -3243,6 +3250,7 @@ impl ChatMessageValidationStatus {
 impl windows_core::TypeKind for ChatMessageValidationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ChatMessageValidationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ChatMessageValidationStatus").field(&self.0).finish()
@@ -3262,6 +3270,7 @@ impl ChatRestoreHistorySpan {
 impl windows_core::TypeKind for ChatRestoreHistorySpan {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ChatRestoreHistorySpan {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ChatRestoreHistorySpan").field(&self.0).finish()
@@ -3286,6 +3295,7 @@ impl ChatStoreChangedEventKind {
 impl windows_core::TypeKind for ChatStoreChangedEventKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ChatStoreChangedEventKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ChatStoreChangedEventKind").field(&self.0).finish()
@@ -3306,6 +3316,7 @@ impl ChatTransportErrorCodeCategory {
 impl windows_core::TypeKind for ChatTransportErrorCodeCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ChatTransportErrorCodeCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ChatTransportErrorCodeCategory").field(&self.0).finish()
@@ -3328,6 +3339,7 @@ impl ChatTransportInterpretedErrorCode {
 impl windows_core::TypeKind for ChatTransportInterpretedErrorCode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ChatTransportInterpretedErrorCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ChatTransportInterpretedErrorCode").field(&self.0).finish()
@@ -3348,6 +3360,7 @@ impl RcsServiceKind {
 impl windows_core::TypeKind for RcsServiceKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RcsServiceKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RcsServiceKind").field(&self.0).finish()
@@ -3358,3 +3371,5 @@ impl windows_core::RuntimeType for RcsServiceKind {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1595 @@
+impl windows_core::imp::ProxyVtable for IChatCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsOnline(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatCapabilities", "IsOnline", 6usize, |this| ((**(this as *const *const IChatCapabilities_Vtbl)).IsOnline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsChatCapable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatCapabilities", "IsChatCapable", 7usize, |this| ((**(this as *const *const IChatCapabilities_Vtbl)).IsChatCapable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFileTransferCapable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatCapabilities", "IsFileTransferCapable", 8usize, |this| ((**(this as *const *const IChatCapabilities_Vtbl)).IsFileTransferCapable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGeoLocationPushCapable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatCapabilities", "IsGeoLocationPushCapable", 9usize, |this| ((**(this as *const *const IChatCapabilities_Vtbl)).IsGeoLocationPushCapable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIntegratedMessagingCapable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatCapabilities", "IsIntegratedMessagingCapable", 10usize, |this| ((**(this as *const *const IChatCapabilities_Vtbl)).IsIntegratedMessagingCapable)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsOnline,
+            IsChatCapable,
+            IsFileTransferCapable,
+            IsGeoLocationPushCapable,
+            IsIntegratedMessagingCapable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatCapabilitiesManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCachedCapabilitiesAsync(this: *mut core::ffi::c_void, address: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatCapabilitiesManagerStatics", "GetCachedCapabilitiesAsync", 6usize, |this| ((**(this as *const *const IChatCapabilitiesManagerStatics_Vtbl)).GetCachedCapabilitiesAsync)(this, address, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCapabilitiesFromNetworkAsync(this: *mut core::ffi::c_void, address: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatCapabilitiesManagerStatics", "GetCapabilitiesFromNetworkAsync", 7usize, |this| ((**(this as *const *const IChatCapabilitiesManagerStatics_Vtbl)).GetCapabilitiesFromNetworkAsync)(this, address, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCachedCapabilitiesAsync, GetCapabilitiesFromNetworkAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatCapabilitiesManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatCapabilitiesManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCachedCapabilitiesForTransportAsync(this: *mut core::ffi::c_void, address: core::mem::MaybeUninit<windows_core::HSTRING>, transportid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatCapabilitiesManagerStatics2", "GetCachedCapabilitiesForTransportAsync", 6usize, |this| ((**(this as *const *const IChatCapabilitiesManagerStatics2_Vtbl)).GetCachedCapabilitiesForTransportAsync)(this, address, transportid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCapabilitiesFromNetworkForTransportAsync(this: *mut core::ffi::c_void, address: core::mem::MaybeUninit<windows_core::HSTRING>, transportid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatCapabilitiesManagerStatics2", "GetCapabilitiesFromNetworkForTransportAsync", 7usize, |this| ((**(this as *const *const IChatCapabilitiesManagerStatics2_Vtbl)).GetCapabilitiesFromNetworkForTransportAsync)(this, address, transportid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCachedCapabilitiesForTransportAsync,
+            GetCapabilitiesFromNetworkForTransportAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatCapabilitiesManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChatConversation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasUnreadMessages(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "HasUnreadMessages", 6usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).HasUnreadMessages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "Id", 7usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "Subject", 8usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubject(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "SetSubject", 9usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).SetSubject)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConversationMuted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "IsConversationMuted", 10usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).IsConversationMuted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsConversationMuted(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "SetIsConversationMuted", 11usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).SetIsConversationMuted)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MostRecentMessageId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "MostRecentMessageId", 12usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).MostRecentMessageId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Participants(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "Participants", 13usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).Participants)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThreadingInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "ThreadingInfo", 14usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).ThreadingInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "DeleteAsync", 15usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).DeleteAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "GetMessageReader", 16usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).GetMessageReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkAllMessagesAsReadAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "MarkAllMessagesAsReadAsync", 17usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).MarkAllMessagesAsReadAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkMessagesAsReadAsync(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "MarkMessagesAsReadAsync", 18usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).MarkMessagesAsReadAsync)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "SaveAsync", 19usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).SaveAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyLocalParticipantComposing(this: *mut core::ffi::c_void, transportid: core::mem::MaybeUninit<windows_core::HSTRING>, participantaddress: core::mem::MaybeUninit<windows_core::HSTRING>, iscomposing: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "NotifyLocalParticipantComposing", 20usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).NotifyLocalParticipantComposing)(this, transportid, participantaddress, iscomposing), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyRemoteParticipantComposing(this: *mut core::ffi::c_void, transportid: core::mem::MaybeUninit<windows_core::HSTRING>, participantaddress: core::mem::MaybeUninit<windows_core::HSTRING>, iscomposing: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "NotifyRemoteParticipantComposing", 21usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).NotifyRemoteParticipantComposing)(this, transportid, participantaddress, iscomposing), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteParticipantComposingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "RemoteParticipantComposingChanged", 22usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).RemoteParticipantComposingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoteParticipantComposingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation", "RemoveRemoteParticipantComposingChanged", 23usize, |this| ((**(this as *const *const IChatConversation_Vtbl)).RemoveRemoteParticipantComposingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HasUnreadMessages,
+            Id,
+            Subject,
+            SetSubject,
+            IsConversationMuted,
+            SetIsConversationMuted,
+            MostRecentMessageId,
+            #[cfg(feature = "Foundation_Collections")]
+            Participants,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Participants: 0,
+            ThreadingInfo,
+            DeleteAsync,
+            GetMessageReader,
+            MarkAllMessagesAsReadAsync,
+            MarkMessagesAsReadAsync,
+            SaveAsync,
+            NotifyLocalParticipantComposing,
+            NotifyRemoteParticipantComposing,
+            RemoteParticipantComposingChanged,
+            RemoveRemoteParticipantComposingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatConversation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatConversation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanModifyParticipants(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation2", "CanModifyParticipants", 6usize, |this| ((**(this as *const *const IChatConversation2_Vtbl)).CanModifyParticipants)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanModifyParticipants(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversation2", "SetCanModifyParticipants", 7usize, |this| ((**(this as *const *const IChatConversation2_Vtbl)).SetCanModifyParticipants)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CanModifyParticipants, SetCanModifyParticipants }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatConversation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChatConversationReader_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadBatchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversationReader", "ReadBatchAsync", 6usize, |this| ((**(this as *const *const IChatConversationReader_Vtbl)).ReadBatchAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadBatchWithCountAsync(this: *mut core::ffi::c_void, count: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversationReader", "ReadBatchWithCountAsync", 7usize, |this| ((**(this as *const *const IChatConversationReader_Vtbl)).ReadBatchWithCountAsync)(this, count, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadBatchAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadBatchAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadBatchWithCountAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadBatchWithCountAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatConversationReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChatConversationThreadingInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversationThreadingInfo", "ContactId", 6usize, |this| ((**(this as *const *const IChatConversationThreadingInfo_Vtbl)).ContactId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContactId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversationThreadingInfo", "SetContactId", 7usize, |this| ((**(this as *const *const IChatConversationThreadingInfo_Vtbl)).SetContactId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Custom(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversationThreadingInfo", "Custom", 8usize, |this| ((**(this as *const *const IChatConversationThreadingInfo_Vtbl)).Custom)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustom(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversationThreadingInfo", "SetCustom", 9usize, |this| ((**(this as *const *const IChatConversationThreadingInfo_Vtbl)).SetCustom)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConversationId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversationThreadingInfo", "ConversationId", 10usize, |this| ((**(this as *const *const IChatConversationThreadingInfo_Vtbl)).ConversationId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConversationId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversationThreadingInfo", "SetConversationId", 11usize, |this| ((**(this as *const *const IChatConversationThreadingInfo_Vtbl)).SetConversationId)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Participants(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversationThreadingInfo", "Participants", 12usize, |this| ((**(this as *const *const IChatConversationThreadingInfo_Vtbl)).Participants)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut ChatConversationThreadingKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversationThreadingInfo", "Kind", 13usize, |this| ((**(this as *const *const IChatConversationThreadingInfo_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKind(this: *mut core::ffi::c_void, value: ChatConversationThreadingKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatConversationThreadingInfo", "SetKind", 14usize, |this| ((**(this as *const *const IChatConversationThreadingInfo_Vtbl)).SetKind)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ContactId,
+            SetContactId,
+            Custom,
+            SetCustom,
+            ConversationId,
+            SetConversationId,
+            #[cfg(feature = "Foundation_Collections")]
+            Participants,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Participants: 0,
+            Kind,
+            SetKind,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatConversationThreadingInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ItemKind(this: *mut core::ffi::c_void, result__: *mut ChatItemKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatItem", "ItemKind", 6usize, |this| ((**(this as *const *const IChatItem_Vtbl)).ItemKind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ItemKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChatMessage_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Attachments(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "Attachments", 6usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).Attachments)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Body(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "Body", 7usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).Body)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBody(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "SetBody", 8usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).SetBody)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn From(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "From", 9usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).From)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "Id", 10usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsForwardingDisabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "IsForwardingDisabled", 11usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).IsForwardingDisabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIncoming(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "IsIncoming", 12usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).IsIncoming)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRead(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "IsRead", 13usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).IsRead)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalTimestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "LocalTimestamp", 14usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).LocalTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkTimestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "NetworkTimestamp", 15usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).NetworkTimestamp)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Recipients(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "Recipients", 16usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).Recipients)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RecipientSendStatuses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "RecipientSendStatuses", 17usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).RecipientSendStatuses)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut ChatMessageStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "Status", 18usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "Subject", 19usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportFriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "TransportFriendlyName", 20usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).TransportFriendlyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "TransportId", 21usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).TransportId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransportId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage", "SetTransportId", 22usize, |this| ((**(this as *const *const IChatMessage_Vtbl)).SetTransportId)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Attachments,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Attachments: 0,
+            Body,
+            SetBody,
+            From,
+            Id,
+            IsForwardingDisabled,
+            IsIncoming,
+            IsRead,
+            LocalTimestamp,
+            NetworkTimestamp,
+            #[cfg(feature = "Foundation_Collections")]
+            Recipients,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Recipients: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RecipientSendStatuses,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RecipientSendStatuses: 0,
+            Status,
+            Subject,
+            TransportFriendlyName,
+            TransportId,
+            SetTransportId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChatMessage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EstimatedDownloadSize(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "EstimatedDownloadSize", 6usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).EstimatedDownloadSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEstimatedDownloadSize(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetEstimatedDownloadSize", 7usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetEstimatedDownloadSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrom(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetFrom", 8usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetFrom)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAutoReply(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "IsAutoReply", 9usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).IsAutoReply)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsAutoReply(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetIsAutoReply", 10usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetIsAutoReply)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsForwardingDisabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetIsForwardingDisabled", 11usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetIsForwardingDisabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReplyDisabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "IsReplyDisabled", 12usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).IsReplyDisabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsIncoming(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetIsIncoming", 13usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetIsIncoming)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsRead(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetIsRead", 14usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetIsRead)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSeen(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "IsSeen", 15usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).IsSeen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsSeen(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetIsSeen", 16usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetIsSeen)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSimMessage(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "IsSimMessage", 17usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).IsSimMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalTimestamp(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetLocalTimestamp", 18usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetLocalTimestamp)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageKind(this: *mut core::ffi::c_void, result__: *mut ChatMessageKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "MessageKind", 19usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).MessageKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageKind(this: *mut core::ffi::c_void, value: ChatMessageKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetMessageKind", 20usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetMessageKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageOperatorKind(this: *mut core::ffi::c_void, result__: *mut ChatMessageOperatorKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "MessageOperatorKind", 21usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).MessageOperatorKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageOperatorKind(this: *mut core::ffi::c_void, value: ChatMessageOperatorKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetMessageOperatorKind", 22usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetMessageOperatorKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkTimestamp(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetNetworkTimestamp", 23usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetNetworkTimestamp)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReceivedDuringQuietHours(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "IsReceivedDuringQuietHours", 24usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).IsReceivedDuringQuietHours)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsReceivedDuringQuietHours(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetIsReceivedDuringQuietHours", 25usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetIsReceivedDuringQuietHours)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetRemoteId", 26usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetRemoteId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, value: ChatMessageStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetStatus", 27usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubject(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetSubject", 28usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetSubject)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShouldSuppressNotification(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "ShouldSuppressNotification", 29usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).ShouldSuppressNotification)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShouldSuppressNotification(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetShouldSuppressNotification", 30usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetShouldSuppressNotification)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThreadingInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "ThreadingInfo", 31usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).ThreadingInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThreadingInfo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "SetThreadingInfo", 32usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).SetThreadingInfo)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RecipientsDeliveryInfos(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage2", "RecipientsDeliveryInfos", 33usize, |this| ((**(this as *const *const IChatMessage2_Vtbl)).RecipientsDeliveryInfos)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EstimatedDownloadSize,
+            SetEstimatedDownloadSize,
+            SetFrom,
+            IsAutoReply,
+            SetIsAutoReply,
+            SetIsForwardingDisabled,
+            IsReplyDisabled,
+            SetIsIncoming,
+            SetIsRead,
+            IsSeen,
+            SetIsSeen,
+            IsSimMessage,
+            SetLocalTimestamp,
+            MessageKind,
+            SetMessageKind,
+            MessageOperatorKind,
+            SetMessageOperatorKind,
+            SetNetworkTimestamp,
+            IsReceivedDuringQuietHours,
+            SetIsReceivedDuringQuietHours,
+            SetRemoteId,
+            SetStatus,
+            SetSubject,
+            ShouldSuppressNotification,
+            SetShouldSuppressNotification,
+            ThreadingInfo,
+            SetThreadingInfo,
+            #[cfg(feature = "Foundation_Collections")]
+            RecipientsDeliveryInfos,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RecipientsDeliveryInfos: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessage2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChatMessage3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoteId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage3", "RemoteId", 6usize, |this| ((**(this as *const *const IChatMessage3_Vtbl)).RemoteId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RemoteId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessage3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChatMessage4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SyncId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage4", "SyncId", 6usize, |this| ((**(this as *const *const IChatMessage4_Vtbl)).SyncId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessage4", "SetSyncId", 7usize, |this| ((**(this as *const *const IChatMessage4_Vtbl)).SetSyncId)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SyncId, SetSyncId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessage4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IChatMessageAttachment_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn DataStreamReference(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageAttachment", "DataStreamReference", 6usize, |this| ((**(this as *const *const IChatMessageAttachment_Vtbl)).DataStreamReference)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetDataStreamReference(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageAttachment", "SetDataStreamReference", 7usize, |this| ((**(this as *const *const IChatMessageAttachment_Vtbl)).SetDataStreamReference)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GroupId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageAttachment", "GroupId", 8usize, |this| ((**(this as *const *const IChatMessageAttachment_Vtbl)).GroupId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroupId(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageAttachment", "SetGroupId", 9usize, |this| ((**(this as *const *const IChatMessageAttachment_Vtbl)).SetGroupId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MimeType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageAttachment", "MimeType", 10usize, |this| ((**(this as *const *const IChatMessageAttachment_Vtbl)).MimeType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMimeType(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageAttachment", "SetMimeType", 11usize, |this| ((**(this as *const *const IChatMessageAttachment_Vtbl)).SetMimeType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageAttachment", "Text", 12usize, |this| ((**(this as *const *const IChatMessageAttachment_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageAttachment", "SetText", 13usize, |this| ((**(this as *const *const IChatMessageAttachment_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            DataStreamReference,
+            #[cfg(not(feature = "Storage_Streams"))]
+            DataStreamReference: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetDataStreamReference,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetDataStreamReference: 0,
+            GroupId,
+            SetGroupId,
+            MimeType,
+            SetMimeType,
+            Text,
+            SetText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageAttachment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IChatMessageAttachment2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageAttachment2", "Thumbnail", 6usize, |this| ((**(this as *const *const IChatMessageAttachment2_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetThumbnail(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageAttachment2", "SetThumbnail", 7usize, |this| ((**(this as *const *const IChatMessageAttachment2_Vtbl)).SetThumbnail)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferProgress(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageAttachment2", "TransferProgress", 8usize, |this| ((**(this as *const *const IChatMessageAttachment2_Vtbl)).TransferProgress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransferProgress(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageAttachment2", "SetTransferProgress", 9usize, |this| ((**(this as *const *const IChatMessageAttachment2_Vtbl)).SetTransferProgress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OriginalFileName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageAttachment2", "OriginalFileName", 10usize, |this| ((**(this as *const *const IChatMessageAttachment2_Vtbl)).OriginalFileName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOriginalFileName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageAttachment2", "SetOriginalFileName", 11usize, |this| ((**(this as *const *const IChatMessageAttachment2_Vtbl)).SetOriginalFileName)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Thumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Thumbnail: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetThumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetThumbnail: 0,
+            TransferProgress,
+            SetTransferProgress,
+            OriginalFileName,
+            SetOriginalFileName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageAttachment2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IChatMessageAttachmentFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateChatMessageAttachment(this: *mut core::ffi::c_void, mimetype: core::mem::MaybeUninit<windows_core::HSTRING>, datastreamreference: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageAttachmentFactory", "CreateChatMessageAttachment", 6usize, |this| ((**(this as *const *const IChatMessageAttachmentFactory_Vtbl)).CreateChatMessageAttachment)(this, mimetype, datastreamreference, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateChatMessageAttachment,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateChatMessageAttachment: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageAttachmentFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatMessageBlockingStatic_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MarkMessageAsBlockedAsync(this: *mut core::ffi::c_void, localchatmessageid: core::mem::MaybeUninit<windows_core::HSTRING>, blocked: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageBlockingStatic", "MarkMessageAsBlockedAsync", 6usize, |this| ((**(this as *const *const IChatMessageBlockingStatic_Vtbl)).MarkMessageAsBlockedAsync)(this, localchatmessageid, blocked, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MarkMessageAsBlockedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageBlockingStatic as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatMessageChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeType(this: *mut core::ffi::c_void, result__: *mut ChatMessageChangeType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageChange", "ChangeType", 6usize, |this| ((**(this as *const *const IChatMessageChange_Vtbl)).ChangeType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageChange", "Message", 7usize, |this| ((**(this as *const *const IChatMessageChange_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ChangeType, Message }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageChange as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChatMessageChangeReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcceptChanges(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageChangeReader", "AcceptChanges", 6usize, |this| ((**(this as *const *const IChatMessageChangeReader_Vtbl)).AcceptChanges)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptChangesThrough(this: *mut core::ffi::c_void, lastchangetoacknowledge: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageChangeReader", "AcceptChangesThrough", 7usize, |this| ((**(this as *const *const IChatMessageChangeReader_Vtbl)).AcceptChangesThrough)(this, lastchangetoacknowledge), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadBatchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageChangeReader", "ReadBatchAsync", 8usize, |this| ((**(this as *const *const IChatMessageChangeReader_Vtbl)).ReadBatchAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AcceptChanges,
+            AcceptChangesThrough,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadBatchAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadBatchAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageChangeReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatMessageChangeTracker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageChangeTracker", "Enable", 6usize, |this| ((**(this as *const *const IChatMessageChangeTracker_Vtbl)).Enable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageChangeTracker", "GetChangeReader", 7usize, |this| ((**(this as *const *const IChatMessageChangeTracker_Vtbl)).GetChangeReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageChangeTracker", "Reset", 8usize, |this| ((**(this as *const *const IChatMessageChangeTracker_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Enable, GetChangeReader, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageChangeTracker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatMessageChangedDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageChangedDeferral", "Complete", 6usize, |this| ((**(this as *const *const IChatMessageChangedDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageChangedDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatMessageChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageChangedEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const IChatMessageChangedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChatMessageManager2Statics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterTransportAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageManager2Statics", "RegisterTransportAsync", 6usize, |this| ((**(this as *const *const IChatMessageManager2Statics_Vtbl)).RegisterTransportAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransportAsync(this: *mut core::ffi::c_void, transportid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageManager2Statics", "GetTransportAsync", 7usize, |this| ((**(this as *const *const IChatMessageManager2Statics_Vtbl)).GetTransportAsync)(this, transportid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterTransportAsync, GetTransportAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageManager2Statics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChatMessageManagerStatic_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetTransportsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageManagerStatic", "GetTransportsAsync", 6usize, |this| ((**(this as *const *const IChatMessageManagerStatic_Vtbl)).GetTransportsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageManagerStatic", "RequestStoreAsync", 7usize, |this| ((**(this as *const *const IChatMessageManagerStatic_Vtbl)).RequestStoreAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowComposeSmsMessageAsync(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageManagerStatic", "ShowComposeSmsMessageAsync", 8usize, |this| ((**(this as *const *const IChatMessageManagerStatic_Vtbl)).ShowComposeSmsMessageAsync)(this, message, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowSmsSettings(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageManagerStatic", "ShowSmsSettings", 9usize, |this| ((**(this as *const *const IChatMessageManagerStatic_Vtbl)).ShowSmsSettings)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetTransportsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetTransportsAsync: 0,
+            RequestStoreAsync,
+            ShowComposeSmsMessageAsync,
+            ShowSmsSettings,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageManagerStatic as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChatMessageManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestSyncManagerAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageManagerStatics3", "RequestSyncManagerAsync", 6usize, |this| ((**(this as *const *const IChatMessageManagerStatics3_Vtbl)).RequestSyncManagerAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestSyncManagerAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatMessageNotificationTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChatMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageNotificationTriggerDetails", "ChatMessage", 6usize, |this| ((**(this as *const *const IChatMessageNotificationTriggerDetails_Vtbl)).ChatMessage)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ChatMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageNotificationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatMessageNotificationTriggerDetails2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShouldDisplayToast(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageNotificationTriggerDetails2", "ShouldDisplayToast", 6usize, |this| ((**(this as *const *const IChatMessageNotificationTriggerDetails2_Vtbl)).ShouldDisplayToast)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShouldUpdateDetailText(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageNotificationTriggerDetails2", "ShouldUpdateDetailText", 7usize, |this| ((**(this as *const *const IChatMessageNotificationTriggerDetails2_Vtbl)).ShouldUpdateDetailText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShouldUpdateBadge(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageNotificationTriggerDetails2", "ShouldUpdateBadge", 8usize, |this| ((**(this as *const *const IChatMessageNotificationTriggerDetails2_Vtbl)).ShouldUpdateBadge)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShouldUpdateActionCenter(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageNotificationTriggerDetails2", "ShouldUpdateActionCenter", 9usize, |this| ((**(this as *const *const IChatMessageNotificationTriggerDetails2_Vtbl)).ShouldUpdateActionCenter)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ShouldDisplayToast,
+            ShouldUpdateDetailText,
+            ShouldUpdateBadge,
+            ShouldUpdateActionCenter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageNotificationTriggerDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChatMessageReader_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadBatchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageReader", "ReadBatchAsync", 6usize, |this| ((**(this as *const *const IChatMessageReader_Vtbl)).ReadBatchAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadBatchAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadBatchAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChatMessageReader2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadBatchWithCountAsync(this: *mut core::ffi::c_void, count: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageReader2", "ReadBatchWithCountAsync", 6usize, |this| ((**(this as *const *const IChatMessageReader2_Vtbl)).ReadBatchWithCountAsync)(this, count, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadBatchWithCountAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadBatchWithCountAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageReader2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatMessageStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeTracker(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore", "ChangeTracker", 6usize, |this| ((**(this as *const *const IChatMessageStore_Vtbl)).ChangeTracker)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteMessageAsync(this: *mut core::ffi::c_void, localmessageid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore", "DeleteMessageAsync", 7usize, |this| ((**(this as *const *const IChatMessageStore_Vtbl)).DeleteMessageAsync)(this, localmessageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadMessageAsync(this: *mut core::ffi::c_void, localchatmessageid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore", "DownloadMessageAsync", 8usize, |this| ((**(this as *const *const IChatMessageStore_Vtbl)).DownloadMessageAsync)(this, localchatmessageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageAsync(this: *mut core::ffi::c_void, localchatmessageid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore", "GetMessageAsync", 9usize, |this| ((**(this as *const *const IChatMessageStore_Vtbl)).GetMessageAsync)(this, localchatmessageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageReader1(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore", "GetMessageReader1", 10usize, |this| ((**(this as *const *const IChatMessageStore_Vtbl)).GetMessageReader1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageReader2(this: *mut core::ffi::c_void, recenttimelimit: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore", "GetMessageReader2", 11usize, |this| ((**(this as *const *const IChatMessageStore_Vtbl)).GetMessageReader2)(this, recenttimelimit, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkMessageReadAsync(this: *mut core::ffi::c_void, localchatmessageid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore", "MarkMessageReadAsync", 12usize, |this| ((**(this as *const *const IChatMessageStore_Vtbl)).MarkMessageReadAsync)(this, localchatmessageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetrySendMessageAsync(this: *mut core::ffi::c_void, localchatmessageid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore", "RetrySendMessageAsync", 13usize, |this| ((**(this as *const *const IChatMessageStore_Vtbl)).RetrySendMessageAsync)(this, localchatmessageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendMessageAsync(this: *mut core::ffi::c_void, chatmessage: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore", "SendMessageAsync", 14usize, |this| ((**(this as *const *const IChatMessageStore_Vtbl)).SendMessageAsync)(this, chatmessage, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateMessage(this: *mut core::ffi::c_void, chatmessage: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore", "ValidateMessage", 15usize, |this| ((**(this as *const *const IChatMessageStore_Vtbl)).ValidateMessage)(this, chatmessage, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore", "MessageChanged", 16usize, |this| ((**(this as *const *const IChatMessageStore_Vtbl)).MessageChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMessageChanged(this: *mut core::ffi::c_void, value: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore", "RemoveMessageChanged", 17usize, |this| ((**(this as *const *const IChatMessageStore_Vtbl)).RemoveMessageChanged)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChangeTracker,
+            DeleteMessageAsync,
+            DownloadMessageAsync,
+            GetMessageAsync,
+            GetMessageReader1,
+            GetMessageReader2,
+            MarkMessageReadAsync,
+            RetrySendMessageAsync,
+            SendMessageAsync,
+            ValidateMessage,
+            MessageChanged,
+            RemoveMessageChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChatMessageStore2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ForwardMessageAsync(this: *mut core::ffi::c_void, localchatmessageid: core::mem::MaybeUninit<windows_core::HSTRING>, addresses: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "ForwardMessageAsync", 6usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).ForwardMessageAsync)(this, localchatmessageid, addresses, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversationAsync(this: *mut core::ffi::c_void, conversationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "GetConversationAsync", 7usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).GetConversationAsync)(this, conversationid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetConversationForTransportsAsync(this: *mut core::ffi::c_void, conversationid: core::mem::MaybeUninit<windows_core::HSTRING>, transportids: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "GetConversationForTransportsAsync", 8usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).GetConversationForTransportsAsync)(this, conversationid, transportids, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversationFromThreadingInfoAsync(this: *mut core::ffi::c_void, threadinginfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "GetConversationFromThreadingInfoAsync", 9usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).GetConversationFromThreadingInfoAsync)(this, threadinginfo, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversationReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "GetConversationReader", 10usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).GetConversationReader)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetConversationForTransportsReader(this: *mut core::ffi::c_void, transportids: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "GetConversationForTransportsReader", 11usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).GetConversationForTransportsReader)(this, transportids, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageByRemoteIdAsync(this: *mut core::ffi::c_void, transportid: core::mem::MaybeUninit<windows_core::HSTRING>, remoteid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "GetMessageByRemoteIdAsync", 12usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).GetMessageByRemoteIdAsync)(this, transportid, remoteid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUnseenCountAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "GetUnseenCountAsync", 13usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).GetUnseenCountAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetUnseenCountForTransportsReaderAsync(this: *mut core::ffi::c_void, transportids: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "GetUnseenCountForTransportsReaderAsync", 14usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).GetUnseenCountForTransportsReaderAsync)(this, transportids, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkAsSeenAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "MarkAsSeenAsync", 15usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).MarkAsSeenAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MarkAsSeenForTransportsAsync(this: *mut core::ffi::c_void, transportids: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "MarkAsSeenForTransportsAsync", 16usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).MarkAsSeenForTransportsAsync)(this, transportids, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSearchReader(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "GetSearchReader", 17usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).GetSearchReader)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveMessageAsync(this: *mut core::ffi::c_void, chatmessage: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "SaveMessageAsync", 18usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).SaveMessageAsync)(this, chatmessage, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryCancelDownloadMessageAsync(this: *mut core::ffi::c_void, localchatmessageid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "TryCancelDownloadMessageAsync", 19usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).TryCancelDownloadMessageAsync)(this, localchatmessageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryCancelSendMessageAsync(this: *mut core::ffi::c_void, localchatmessageid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "TryCancelSendMessageAsync", 20usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).TryCancelSendMessageAsync)(this, localchatmessageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StoreChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "StoreChanged", 21usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).StoreChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStoreChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore2", "RemoveStoreChanged", 22usize, |this| ((**(this as *const *const IChatMessageStore2_Vtbl)).RemoveStoreChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ForwardMessageAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ForwardMessageAsync: 0,
+            GetConversationAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetConversationForTransportsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetConversationForTransportsAsync: 0,
+            GetConversationFromThreadingInfoAsync,
+            GetConversationReader,
+            #[cfg(feature = "Foundation_Collections")]
+            GetConversationForTransportsReader,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetConversationForTransportsReader: 0,
+            GetMessageByRemoteIdAsync,
+            GetUnseenCountAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetUnseenCountForTransportsReaderAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetUnseenCountForTransportsReaderAsync: 0,
+            MarkAsSeenAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            MarkAsSeenForTransportsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MarkAsSeenForTransportsAsync: 0,
+            GetSearchReader,
+            SaveMessageAsync,
+            TryCancelDownloadMessageAsync,
+            TryCancelSendMessageAsync,
+            StoreChanged,
+            RemoveStoreChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageStore2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatMessageStore3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMessageBySyncIdAsync(this: *mut core::ffi::c_void, syncid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStore3", "GetMessageBySyncIdAsync", 6usize, |this| ((**(this as *const *const IChatMessageStore3_Vtbl)).GetMessageBySyncIdAsync)(this, syncid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMessageBySyncIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageStore3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatMessageStoreChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStoreChangedEventArgs", "Id", 6usize, |this| ((**(this as *const *const IChatMessageStoreChangedEventArgs_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut ChatStoreChangedEventKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageStoreChangedEventArgs", "Kind", 7usize, |this| ((**(this as *const *const IChatMessageStoreChangedEventArgs_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Kind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageStoreChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatMessageTransport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAppSetAsNotificationProvider(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageTransport", "IsAppSetAsNotificationProvider", 6usize, |this| ((**(this as *const *const IChatMessageTransport_Vtbl)).IsAppSetAsNotificationProvider)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsActive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageTransport", "IsActive", 7usize, |this| ((**(this as *const *const IChatMessageTransport_Vtbl)).IsActive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportFriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageTransport", "TransportFriendlyName", 8usize, |this| ((**(this as *const *const IChatMessageTransport_Vtbl)).TransportFriendlyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageTransport", "TransportId", 9usize, |this| ((**(this as *const *const IChatMessageTransport_Vtbl)).TransportId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestSetAsNotificationProviderAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageTransport", "RequestSetAsNotificationProviderAsync", 10usize, |this| ((**(this as *const *const IChatMessageTransport_Vtbl)).RequestSetAsNotificationProviderAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsAppSetAsNotificationProvider,
+            IsActive,
+            TransportFriendlyName,
+            TransportId,
+            RequestSetAsNotificationProviderAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageTransport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatMessageTransport2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageTransport2", "Configuration", 6usize, |this| ((**(this as *const *const IChatMessageTransport2_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportKind(this: *mut core::ffi::c_void, result__: *mut ChatMessageTransportKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageTransport2", "TransportKind", 7usize, |this| ((**(this as *const *const IChatMessageTransport2_Vtbl)).TransportKind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Configuration, TransportKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageTransport2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IChatMessageTransportConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxAttachmentCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageTransportConfiguration", "MaxAttachmentCount", 6usize, |this| ((**(this as *const *const IChatMessageTransportConfiguration_Vtbl)).MaxAttachmentCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxMessageSizeInKilobytes(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageTransportConfiguration", "MaxMessageSizeInKilobytes", 7usize, |this| ((**(this as *const *const IChatMessageTransportConfiguration_Vtbl)).MaxMessageSizeInKilobytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxRecipientCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageTransportConfiguration", "MaxRecipientCount", 8usize, |this| ((**(this as *const *const IChatMessageTransportConfiguration_Vtbl)).MaxRecipientCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn SupportedVideoFormat(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageTransportConfiguration", "SupportedVideoFormat", 9usize, |this| ((**(this as *const *const IChatMessageTransportConfiguration_Vtbl)).SupportedVideoFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ExtendedProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageTransportConfiguration", "ExtendedProperties", 10usize, |this| ((**(this as *const *const IChatMessageTransportConfiguration_Vtbl)).ExtendedProperties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaxAttachmentCount,
+            MaxMessageSizeInKilobytes,
+            MaxRecipientCount,
+            #[cfg(feature = "Media_MediaProperties")]
+            SupportedVideoFormat,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            SupportedVideoFormat: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ExtendedProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ExtendedProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageTransportConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatMessageValidationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxPartCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageValidationResult", "MaxPartCount", 6usize, |this| ((**(this as *const *const IChatMessageValidationResult_Vtbl)).MaxPartCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PartCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageValidationResult", "PartCount", 7usize, |this| ((**(this as *const *const IChatMessageValidationResult_Vtbl)).PartCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemainingCharacterCountInPart(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageValidationResult", "RemainingCharacterCountInPart", 8usize, |this| ((**(this as *const *const IChatMessageValidationResult_Vtbl)).RemainingCharacterCountInPart)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut ChatMessageValidationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatMessageValidationResult", "Status", 9usize, |this| ((**(this as *const *const IChatMessageValidationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaxPartCount,
+            PartCount,
+            RemainingCharacterCountInPart,
+            Status,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatMessageValidationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatQueryOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SearchString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatQueryOptions", "SearchString", 6usize, |this| ((**(this as *const *const IChatQueryOptions_Vtbl)).SearchString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSearchString(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatQueryOptions", "SetSearchString", 7usize, |this| ((**(this as *const *const IChatQueryOptions_Vtbl)).SetSearchString)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SearchString, SetSearchString }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatQueryOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatRecipientDeliveryInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransportAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatRecipientDeliveryInfo", "TransportAddress", 6usize, |this| ((**(this as *const *const IChatRecipientDeliveryInfo_Vtbl)).TransportAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransportAddress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatRecipientDeliveryInfo", "SetTransportAddress", 7usize, |this| ((**(this as *const *const IChatRecipientDeliveryInfo_Vtbl)).SetTransportAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeliveryTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatRecipientDeliveryInfo", "DeliveryTime", 8usize, |this| ((**(this as *const *const IChatRecipientDeliveryInfo_Vtbl)).DeliveryTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeliveryTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatRecipientDeliveryInfo", "SetDeliveryTime", 9usize, |this| ((**(this as *const *const IChatRecipientDeliveryInfo_Vtbl)).SetDeliveryTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatRecipientDeliveryInfo", "ReadTime", 10usize, |this| ((**(this as *const *const IChatRecipientDeliveryInfo_Vtbl)).ReadTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReadTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatRecipientDeliveryInfo", "SetReadTime", 11usize, |this| ((**(this as *const *const IChatRecipientDeliveryInfo_Vtbl)).SetReadTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportErrorCodeCategory(this: *mut core::ffi::c_void, result__: *mut ChatTransportErrorCodeCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatRecipientDeliveryInfo", "TransportErrorCodeCategory", 12usize, |this| ((**(this as *const *const IChatRecipientDeliveryInfo_Vtbl)).TransportErrorCodeCategory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportInterpretedErrorCode(this: *mut core::ffi::c_void, result__: *mut ChatTransportInterpretedErrorCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatRecipientDeliveryInfo", "TransportInterpretedErrorCode", 13usize, |this| ((**(this as *const *const IChatRecipientDeliveryInfo_Vtbl)).TransportInterpretedErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportErrorCode(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatRecipientDeliveryInfo", "TransportErrorCode", 14usize, |this| ((**(this as *const *const IChatRecipientDeliveryInfo_Vtbl)).TransportErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsErrorPermanent(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatRecipientDeliveryInfo", "IsErrorPermanent", 15usize, |this| ((**(this as *const *const IChatRecipientDeliveryInfo_Vtbl)).IsErrorPermanent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut ChatMessageStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatRecipientDeliveryInfo", "Status", 16usize, |this| ((**(this as *const *const IChatRecipientDeliveryInfo_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TransportAddress,
+            SetTransportAddress,
+            DeliveryTime,
+            SetDeliveryTime,
+            ReadTime,
+            SetReadTime,
+            TransportErrorCodeCategory,
+            TransportInterpretedErrorCode,
+            TransportErrorCode,
+            IsErrorPermanent,
+            Status,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatRecipientDeliveryInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChatSearchReader_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadBatchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatSearchReader", "ReadBatchAsync", 6usize, |this| ((**(this as *const *const IChatSearchReader_Vtbl)).ReadBatchAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadBatchWithCountAsync(this: *mut core::ffi::c_void, count: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatSearchReader", "ReadBatchWithCountAsync", 7usize, |this| ((**(this as *const *const IChatSearchReader_Vtbl)).ReadBatchWithCountAsync)(this, count, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadBatchAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadBatchAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadBatchWithCountAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadBatchWithCountAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatSearchReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChatSyncConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSyncEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatSyncConfiguration", "IsSyncEnabled", 6usize, |this| ((**(this as *const *const IChatSyncConfiguration_Vtbl)).IsSyncEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsSyncEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatSyncConfiguration", "SetIsSyncEnabled", 7usize, |this| ((**(this as *const *const IChatSyncConfiguration_Vtbl)).SetIsSyncEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreHistorySpan(this: *mut core::ffi::c_void, result__: *mut ChatRestoreHistorySpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatSyncConfiguration", "RestoreHistorySpan", 8usize, |this| ((**(this as *const *const IChatSyncConfiguration_Vtbl)).RestoreHistorySpan)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRestoreHistorySpan(this: *mut core::ffi::c_void, value: ChatRestoreHistorySpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatSyncConfiguration", "SetRestoreHistorySpan", 9usize, |this| ((**(this as *const *const IChatSyncConfiguration_Vtbl)).SetRestoreHistorySpan)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSyncEnabled,
+            SetIsSyncEnabled,
+            RestoreHistorySpan,
+            SetRestoreHistorySpan,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatSyncConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IChatSyncManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatSyncManager", "Configuration", 6usize, |this| ((**(this as *const *const IChatSyncManager_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn AssociateAccountAsync(this: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatSyncManager", "AssociateAccountAsync", 7usize, |this| ((**(this as *const *const IChatSyncManager_Vtbl)).AssociateAccountAsync)(this, webaccount, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnassociateAccountAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatSyncManager", "UnassociateAccountAsync", 8usize, |this| ((**(this as *const *const IChatSyncManager_Vtbl)).UnassociateAccountAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn IsAccountAssociated(this: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatSyncManager", "IsAccountAssociated", 9usize, |this| ((**(this as *const *const IChatSyncManager_Vtbl)).IsAccountAssociated)(this, webaccount, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartSync(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatSyncManager", "StartSync", 10usize, |this| ((**(this as *const *const IChatSyncManager_Vtbl)).StartSync)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConfigurationAsync(this: *mut core::ffi::c_void, configuration: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChatSyncManager", "SetConfigurationAsync", 11usize, |this| ((**(this as *const *const IChatSyncManager_Vtbl)).SetConfigurationAsync)(this, configuration, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Configuration,
+            #[cfg(feature = "Security_Credentials")]
+            AssociateAccountAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            AssociateAccountAsync: 0,
+            UnassociateAccountAsync,
+            #[cfg(feature = "Security_Credentials")]
+            IsAccountAssociated,
+            #[cfg(not(feature = "Security_Credentials"))]
+            IsAccountAssociated: 0,
+            StartSync,
+            SetConfigurationAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChatSyncManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRcsEndUserMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransportId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsEndUserMessage", "TransportId", 6usize, |this| ((**(this as *const *const IRcsEndUserMessage_Vtbl)).TransportId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsEndUserMessage", "Title", 7usize, |this| ((**(this as *const *const IRcsEndUserMessage_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsEndUserMessage", "Text", 8usize, |this| ((**(this as *const *const IRcsEndUserMessage_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPinRequired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsEndUserMessage", "IsPinRequired", 9usize, |this| ((**(this as *const *const IRcsEndUserMessage_Vtbl)).IsPinRequired)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Actions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsEndUserMessage", "Actions", 10usize, |this| ((**(this as *const *const IRcsEndUserMessage_Vtbl)).Actions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendResponseAsync(this: *mut core::ffi::c_void, action: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsEndUserMessage", "SendResponseAsync", 11usize, |this| ((**(this as *const *const IRcsEndUserMessage_Vtbl)).SendResponseAsync)(this, action, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendResponseWithPinAsync(this: *mut core::ffi::c_void, action: *mut core::ffi::c_void, pin: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsEndUserMessage", "SendResponseWithPinAsync", 12usize, |this| ((**(this as *const *const IRcsEndUserMessage_Vtbl)).SendResponseWithPinAsync)(this, action, pin, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TransportId,
+            Title,
+            Text,
+            IsPinRequired,
+            #[cfg(feature = "Foundation_Collections")]
+            Actions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Actions: 0,
+            SendResponseAsync,
+            SendResponseWithPinAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRcsEndUserMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRcsEndUserMessageAction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsEndUserMessageAction", "Label", 6usize, |this| ((**(this as *const *const IRcsEndUserMessageAction_Vtbl)).Label)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Label }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRcsEndUserMessageAction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRcsEndUserMessageAvailableEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsMessageAvailable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsEndUserMessageAvailableEventArgs", "IsMessageAvailable", 6usize, |this| ((**(this as *const *const IRcsEndUserMessageAvailableEventArgs_Vtbl)).IsMessageAvailable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsEndUserMessageAvailableEventArgs", "Message", 7usize, |this| ((**(this as *const *const IRcsEndUserMessageAvailableEventArgs_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsMessageAvailable, Message }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRcsEndUserMessageAvailableEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRcsEndUserMessageAvailableTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsEndUserMessageAvailableTriggerDetails", "Title", 6usize, |this| ((**(this as *const *const IRcsEndUserMessageAvailableTriggerDetails_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsEndUserMessageAvailableTriggerDetails", "Text", 7usize, |this| ((**(this as *const *const IRcsEndUserMessageAvailableTriggerDetails_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Title, Text }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRcsEndUserMessageAvailableTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRcsEndUserMessageManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MessageAvailableChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsEndUserMessageManager", "MessageAvailableChanged", 6usize, |this| ((**(this as *const *const IRcsEndUserMessageManager_Vtbl)).MessageAvailableChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMessageAvailableChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsEndUserMessageManager", "RemoveMessageAvailableChanged", 7usize, |this| ((**(this as *const *const IRcsEndUserMessageManager_Vtbl)).RemoveMessageAvailableChanged)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MessageAvailableChanged, RemoveMessageAvailableChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRcsEndUserMessageManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRcsManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEndUserMessageManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsManagerStatics", "GetEndUserMessageManager", 6usize, |this| ((**(this as *const *const IRcsManagerStatics_Vtbl)).GetEndUserMessageManager)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetTransportsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsManagerStatics", "GetTransportsAsync", 7usize, |this| ((**(this as *const *const IRcsManagerStatics_Vtbl)).GetTransportsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransportAsync(this: *mut core::ffi::c_void, transportid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsManagerStatics", "GetTransportAsync", 8usize, |this| ((**(this as *const *const IRcsManagerStatics_Vtbl)).GetTransportAsync)(this, transportid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeaveConversationAsync(this: *mut core::ffi::c_void, conversation: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsManagerStatics", "LeaveConversationAsync", 9usize, |this| ((**(this as *const *const IRcsManagerStatics_Vtbl)).LeaveConversationAsync)(this, conversation, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetEndUserMessageManager,
+            #[cfg(feature = "Foundation_Collections")]
+            GetTransportsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetTransportsAsync: 0,
+            GetTransportAsync,
+            LeaveConversationAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRcsManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRcsManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransportListChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsManagerStatics2", "TransportListChanged", 6usize, |this| ((**(this as *const *const IRcsManagerStatics2_Vtbl)).TransportListChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTransportListChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsManagerStatics2", "RemoveTransportListChanged", 7usize, |this| ((**(this as *const *const IRcsManagerStatics2_Vtbl)).RemoveTransportListChanged)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TransportListChanged, RemoveTransportListChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRcsManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRcsServiceKindSupportedChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServiceKind(this: *mut core::ffi::c_void, result__: *mut RcsServiceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsServiceKindSupportedChangedEventArgs", "ServiceKind", 6usize, |this| ((**(this as *const *const IRcsServiceKindSupportedChangedEventArgs_Vtbl)).ServiceKind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ServiceKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRcsServiceKindSupportedChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRcsTransport_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ExtendedProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsTransport", "ExtendedProperties", 6usize, |this| ((**(this as *const *const IRcsTransport_Vtbl)).ExtendedProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsActive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsTransport", "IsActive", 7usize, |this| ((**(this as *const *const IRcsTransport_Vtbl)).IsActive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportFriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsTransport", "TransportFriendlyName", 8usize, |this| ((**(this as *const *const IRcsTransport_Vtbl)).TransportFriendlyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsTransport", "TransportId", 9usize, |this| ((**(this as *const *const IRcsTransport_Vtbl)).TransportId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsTransport", "Configuration", 10usize, |this| ((**(this as *const *const IRcsTransport_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStoreAndForwardEnabled(this: *mut core::ffi::c_void, servicekind: RcsServiceKind, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsTransport", "IsStoreAndForwardEnabled", 11usize, |this| ((**(this as *const *const IRcsTransport_Vtbl)).IsStoreAndForwardEnabled)(this, servicekind, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsServiceKindSupported(this: *mut core::ffi::c_void, servicekind: RcsServiceKind, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsTransport", "IsServiceKindSupported", 12usize, |this| ((**(this as *const *const IRcsTransport_Vtbl)).IsServiceKindSupported)(this, servicekind, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceKindSupportedChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsTransport", "ServiceKindSupportedChanged", 13usize, |this| ((**(this as *const *const IRcsTransport_Vtbl)).ServiceKindSupportedChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveServiceKindSupportedChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsTransport", "RemoveServiceKindSupportedChanged", 14usize, |this| ((**(this as *const *const IRcsTransport_Vtbl)).RemoveServiceKindSupportedChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ExtendedProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ExtendedProperties: 0,
+            IsActive,
+            TransportFriendlyName,
+            TransportId,
+            Configuration,
+            IsStoreAndForwardEnabled,
+            IsServiceKindSupported,
+            ServiceKindSupportedChanged,
+            RemoveServiceKindSupportedChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRcsTransport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRcsTransportConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxAttachmentCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsTransportConfiguration", "MaxAttachmentCount", 6usize, |this| ((**(this as *const *const IRcsTransportConfiguration_Vtbl)).MaxAttachmentCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxMessageSizeInKilobytes(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsTransportConfiguration", "MaxMessageSizeInKilobytes", 7usize, |this| ((**(this as *const *const IRcsTransportConfiguration_Vtbl)).MaxMessageSizeInKilobytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxGroupMessageSizeInKilobytes(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsTransportConfiguration", "MaxGroupMessageSizeInKilobytes", 8usize, |this| ((**(this as *const *const IRcsTransportConfiguration_Vtbl)).MaxGroupMessageSizeInKilobytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxRecipientCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsTransportConfiguration", "MaxRecipientCount", 9usize, |this| ((**(this as *const *const IRcsTransportConfiguration_Vtbl)).MaxRecipientCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxFileSizeInKilobytes(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsTransportConfiguration", "MaxFileSizeInKilobytes", 10usize, |this| ((**(this as *const *const IRcsTransportConfiguration_Vtbl)).MaxFileSizeInKilobytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningFileSizeInKilobytes(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRcsTransportConfiguration", "WarningFileSizeInKilobytes", 11usize, |this| ((**(this as *const *const IRcsTransportConfiguration_Vtbl)).WarningFileSizeInKilobytes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaxAttachmentCount,
+            MaxMessageSizeInKilobytes,
+            MaxGroupMessageSizeInKilobytes,
+            MaxRecipientCount,
+            MaxFileSizeInKilobytes,
+            WarningFileSizeInKilobytes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRcsTransportConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteParticipantComposingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransportId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteParticipantComposingChangedEventArgs", "TransportId", 6usize, |this| ((**(this as *const *const IRemoteParticipantComposingChangedEventArgs_Vtbl)).TransportId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParticipantAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteParticipantComposingChangedEventArgs", "ParticipantAddress", 7usize, |this| ((**(this as *const *const IRemoteParticipantComposingChangedEventArgs_Vtbl)).ParticipantAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsComposing(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteParticipantComposingChangedEventArgs", "IsComposing", 8usize, |this| ((**(this as *const *const IRemoteParticipantComposingChangedEventArgs_Vtbl)).IsComposing)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TransportId, ParticipantAddress, IsComposing }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteParticipantComposingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -117,3 +117,5 @@ impl CommunicationBlockingAppManager {
 impl windows_core::RuntimeName for CommunicationBlockingAppManager {
     const NAME: &'static str = "Windows.ApplicationModel.CommunicationBlocking.CommunicationBlockingAppManager";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
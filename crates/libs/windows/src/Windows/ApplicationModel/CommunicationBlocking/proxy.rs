@@ -0,0 +1,75 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICommunicationBlockingAccessManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsBlockingActive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommunicationBlockingAccessManagerStatics", "IsBlockingActive", 6usize, |this| ((**(this as *const *const ICommunicationBlockingAccessManagerStatics_Vtbl)).IsBlockingActive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBlockedNumberAsync(this: *mut core::ffi::c_void, number: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommunicationBlockingAccessManagerStatics", "IsBlockedNumberAsync", 7usize, |this| ((**(this as *const *const ICommunicationBlockingAccessManagerStatics_Vtbl)).IsBlockedNumberAsync)(this, number, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ShowBlockNumbersUI(this: *mut core::ffi::c_void, phonenumbers: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommunicationBlockingAccessManagerStatics", "ShowBlockNumbersUI", 8usize, |this| ((**(this as *const *const ICommunicationBlockingAccessManagerStatics_Vtbl)).ShowBlockNumbersUI)(this, phonenumbers, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ShowUnblockNumbersUI(this: *mut core::ffi::c_void, phonenumbers: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommunicationBlockingAccessManagerStatics", "ShowUnblockNumbersUI", 9usize, |this| ((**(this as *const *const ICommunicationBlockingAccessManagerStatics_Vtbl)).ShowUnblockNumbersUI)(this, phonenumbers, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowBlockedCallsUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommunicationBlockingAccessManagerStatics", "ShowBlockedCallsUI", 10usize, |this| ((**(this as *const *const ICommunicationBlockingAccessManagerStatics_Vtbl)).ShowBlockedCallsUI)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowBlockedMessagesUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommunicationBlockingAccessManagerStatics", "ShowBlockedMessagesUI", 11usize, |this| ((**(this as *const *const ICommunicationBlockingAccessManagerStatics_Vtbl)).ShowBlockedMessagesUI)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsBlockingActive,
+            IsBlockedNumberAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            ShowBlockNumbersUI,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ShowBlockNumbersUI: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ShowUnblockNumbersUI,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ShowUnblockNumbersUI: 0,
+            ShowBlockedCallsUI,
+            ShowBlockedMessagesUI,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommunicationBlockingAccessManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICommunicationBlockingAppManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsCurrentAppActiveBlockingApp(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommunicationBlockingAppManagerStatics", "IsCurrentAppActiveBlockingApp", 6usize, |this| ((**(this as *const *const ICommunicationBlockingAppManagerStatics_Vtbl)).IsCurrentAppActiveBlockingApp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowCommunicationBlockingSettingsUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommunicationBlockingAppManagerStatics", "ShowCommunicationBlockingSettingsUI", 7usize, |this| ((**(this as *const *const ICommunicationBlockingAppManagerStatics_Vtbl)).ShowCommunicationBlockingSettingsUI)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsCurrentAppActiveBlockingApp,
+            ShowCommunicationBlockingSettingsUI,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommunicationBlockingAppManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICommunicationBlockingAppManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestSetAsActiveBlockingAppAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommunicationBlockingAppManagerStatics2", "RequestSetAsActiveBlockingAppAsync", 6usize, |this| ((**(this as *const *const ICommunicationBlockingAppManagerStatics2_Vtbl)).RequestSetAsActiveBlockingAppAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestSetAsActiveBlockingAppAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommunicationBlockingAppManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
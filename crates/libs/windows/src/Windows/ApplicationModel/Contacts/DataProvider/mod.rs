@@ -557,3 +557,5 @@ impl windows_core::RuntimeName for ContactListSyncManagerSyncRequestEventArgs {
 }
 unsafe impl Send for ContactListSyncManagerSyncRequestEventArgs {}
 unsafe impl Sync for ContactListSyncManagerSyncRequestEventArgs {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
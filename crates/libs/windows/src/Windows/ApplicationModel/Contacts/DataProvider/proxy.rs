@@ -0,0 +1,241 @@
+impl windows_core::imp::ProxyVtable for IContactDataProviderConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SyncRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDataProviderConnection", "SyncRequested", 6usize, |this| ((**(this as *const *const IContactDataProviderConnection_Vtbl)).SyncRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSyncRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDataProviderConnection", "RemoveSyncRequested", 7usize, |this| ((**(this as *const *const IContactDataProviderConnection_Vtbl)).RemoveSyncRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerSearchReadBatchRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDataProviderConnection", "ServerSearchReadBatchRequested", 8usize, |this| ((**(this as *const *const IContactDataProviderConnection_Vtbl)).ServerSearchReadBatchRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveServerSearchReadBatchRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDataProviderConnection", "RemoveServerSearchReadBatchRequested", 9usize, |this| ((**(this as *const *const IContactDataProviderConnection_Vtbl)).RemoveServerSearchReadBatchRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDataProviderConnection", "Start", 10usize, |this| ((**(this as *const *const IContactDataProviderConnection_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SyncRequested,
+            RemoveSyncRequested,
+            ServerSearchReadBatchRequested,
+            RemoveServerSearchReadBatchRequested,
+            Start,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactDataProviderConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactDataProviderConnection2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateOrUpdateContactRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDataProviderConnection2", "CreateOrUpdateContactRequested", 6usize, |this| ((**(this as *const *const IContactDataProviderConnection2_Vtbl)).CreateOrUpdateContactRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCreateOrUpdateContactRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDataProviderConnection2", "RemoveCreateOrUpdateContactRequested", 7usize, |this| ((**(this as *const *const IContactDataProviderConnection2_Vtbl)).RemoveCreateOrUpdateContactRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteContactRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDataProviderConnection2", "DeleteContactRequested", 8usize, |this| ((**(this as *const *const IContactDataProviderConnection2_Vtbl)).DeleteContactRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDeleteContactRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDataProviderConnection2", "RemoveDeleteContactRequested", 9usize, |this| ((**(this as *const *const IContactDataProviderConnection2_Vtbl)).RemoveDeleteContactRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateOrUpdateContactRequested,
+            RemoveCreateOrUpdateContactRequested,
+            DeleteContactRequested,
+            RemoveDeleteContactRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactDataProviderConnection2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactDataProviderTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDataProviderTriggerDetails", "Connection", 6usize, |this| ((**(this as *const *const IContactDataProviderTriggerDetails_Vtbl)).Connection)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Connection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactDataProviderTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactListCreateOrUpdateContactRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactListId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListCreateOrUpdateContactRequest", "ContactListId", 6usize, |this| ((**(this as *const *const IContactListCreateOrUpdateContactRequest_Vtbl)).ContactListId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListCreateOrUpdateContactRequest", "Contact", 7usize, |this| ((**(this as *const *const IContactListCreateOrUpdateContactRequest_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, createdorupdatedcontact: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListCreateOrUpdateContactRequest", "ReportCompletedAsync", 8usize, |this| ((**(this as *const *const IContactListCreateOrUpdateContactRequest_Vtbl)).ReportCompletedAsync)(this, createdorupdatedcontact, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListCreateOrUpdateContactRequest", "ReportFailedAsync", 9usize, |this| ((**(this as *const *const IContactListCreateOrUpdateContactRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ContactListId,
+            Contact,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactListCreateOrUpdateContactRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactListCreateOrUpdateContactRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListCreateOrUpdateContactRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IContactListCreateOrUpdateContactRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListCreateOrUpdateContactRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IContactListCreateOrUpdateContactRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactListCreateOrUpdateContactRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactListDeleteContactRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactListId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListDeleteContactRequest", "ContactListId", 6usize, |this| ((**(this as *const *const IContactListDeleteContactRequest_Vtbl)).ContactListId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContactId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListDeleteContactRequest", "ContactId", 7usize, |this| ((**(this as *const *const IContactListDeleteContactRequest_Vtbl)).ContactId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListDeleteContactRequest", "ReportCompletedAsync", 8usize, |this| ((**(this as *const *const IContactListDeleteContactRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListDeleteContactRequest", "ReportFailedAsync", 9usize, |this| ((**(this as *const *const IContactListDeleteContactRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ContactListId,
+            ContactId,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactListDeleteContactRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactListDeleteContactRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListDeleteContactRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IContactListDeleteContactRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListDeleteContactRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IContactListDeleteContactRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactListDeleteContactRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactListServerSearchReadBatchRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SessionId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListServerSearchReadBatchRequest", "SessionId", 6usize, |this| ((**(this as *const *const IContactListServerSearchReadBatchRequest_Vtbl)).SessionId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContactListId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListServerSearchReadBatchRequest", "ContactListId", 7usize, |this| ((**(this as *const *const IContactListServerSearchReadBatchRequest_Vtbl)).ContactListId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Options(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListServerSearchReadBatchRequest", "Options", 8usize, |this| ((**(this as *const *const IContactListServerSearchReadBatchRequest_Vtbl)).Options)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuggestedBatchSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListServerSearchReadBatchRequest", "SuggestedBatchSize", 9usize, |this| ((**(this as *const *const IContactListServerSearchReadBatchRequest_Vtbl)).SuggestedBatchSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveContactAsync(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListServerSearchReadBatchRequest", "SaveContactAsync", 10usize, |this| ((**(this as *const *const IContactListServerSearchReadBatchRequest_Vtbl)).SaveContactAsync)(this, contact, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListServerSearchReadBatchRequest", "ReportCompletedAsync", 11usize, |this| ((**(this as *const *const IContactListServerSearchReadBatchRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, batchstatus: super::ContactBatchStatus, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListServerSearchReadBatchRequest", "ReportFailedAsync", 12usize, |this| ((**(this as *const *const IContactListServerSearchReadBatchRequest_Vtbl)).ReportFailedAsync)(this, batchstatus, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SessionId,
+            ContactListId,
+            Options,
+            SuggestedBatchSize,
+            SaveContactAsync,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactListServerSearchReadBatchRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactListServerSearchReadBatchRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListServerSearchReadBatchRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IContactListServerSearchReadBatchRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListServerSearchReadBatchRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IContactListServerSearchReadBatchRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactListServerSearchReadBatchRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactListSyncManagerSyncRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactListId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncManagerSyncRequest", "ContactListId", 6usize, |this| ((**(this as *const *const IContactListSyncManagerSyncRequest_Vtbl)).ContactListId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncManagerSyncRequest", "ReportCompletedAsync", 7usize, |this| ((**(this as *const *const IContactListSyncManagerSyncRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncManagerSyncRequest", "ReportFailedAsync", 8usize, |this| ((**(this as *const *const IContactListSyncManagerSyncRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContactListId, ReportCompletedAsync, ReportFailedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactListSyncManagerSyncRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactListSyncManagerSyncRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncManagerSyncRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IContactListSyncManagerSyncRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncManagerSyncRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IContactListSyncManagerSyncRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactListSyncManagerSyncRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -160,6 +160,7 @@ impl AddContactResult {
 impl windows_core::TypeKind for AddContactResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AddContactResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AddContactResult").field(&self.0).finish()
@@ -168,3 +169,5 @@ impl core::fmt::Debug for AddContactResult {
 impl windows_core::RuntimeType for AddContactResult {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.Contacts.Provider.AddContactResult;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,84 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactPickerUI_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AddContact(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, contact: *mut core::ffi::c_void, result__: *mut AddContactResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPickerUI", "AddContact", 6usize, |this| ((**(this as *const *const IContactPickerUI_Vtbl)).AddContact)(this, id, contact, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveContact(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPickerUI", "RemoveContact", 7usize, |this| ((**(this as *const *const IContactPickerUI_Vtbl)).RemoveContact)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainsContact(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPickerUI", "ContainsContact", 8usize, |this| ((**(this as *const *const IContactPickerUI_Vtbl)).ContainsContact)(this, id, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn DesiredFields(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPickerUI", "DesiredFields", 9usize, |this| ((**(this as *const *const IContactPickerUI_Vtbl)).DesiredFields)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectionMode(this: *mut core::ffi::c_void, result__: *mut super::ContactSelectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPickerUI", "SelectionMode", 10usize, |this| ((**(this as *const *const IContactPickerUI_Vtbl)).SelectionMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContactRemoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPickerUI", "ContactRemoved", 11usize, |this| ((**(this as *const *const IContactPickerUI_Vtbl)).ContactRemoved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveContactRemoved(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPickerUI", "RemoveContactRemoved", 12usize, |this| ((**(this as *const *const IContactPickerUI_Vtbl)).RemoveContactRemoved)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            AddContact,
+            #[cfg(not(feature = "deprecated"))]
+            AddContact: 0,
+            RemoveContact,
+            ContainsContact,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            DesiredFields,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            DesiredFields: 0,
+            SelectionMode,
+            ContactRemoved,
+            RemoveContactRemoved,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPickerUI as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactPickerUI2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddContact(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, result__: *mut AddContactResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPickerUI2", "AddContact", 6usize, |this| ((**(this as *const *const IContactPickerUI2_Vtbl)).AddContact)(this, contact, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DesiredFieldsWithContactFieldType(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPickerUI2", "DesiredFieldsWithContactFieldType", 7usize, |this| ((**(this as *const *const IContactPickerUI2_Vtbl)).DesiredFieldsWithContactFieldType)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddContact,
+            #[cfg(feature = "Foundation_Collections")]
+            DesiredFieldsWithContactFieldType,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DesiredFieldsWithContactFieldType: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPickerUI2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactRemovedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactRemovedEventArgs", "Id", 6usize, |this| ((**(this as *const *const IContactRemovedEventArgs_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactRemovedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
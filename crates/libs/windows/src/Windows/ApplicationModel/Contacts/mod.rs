@@ -5385,6 +5385,7 @@ impl ContactAddressKind {
 impl windows_core::TypeKind for ContactAddressKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactAddressKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactAddressKind").field(&self.0).finish()
@@ -5408,6 +5409,7 @@ impl ContactAnnotationOperations {
 impl windows_core::TypeKind for ContactAnnotationOperations {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactAnnotationOperations {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactAnnotationOperations").field(&self.0).finish()
@@ -5459,6 +5461,7 @@ impl ContactAnnotationStoreAccessType {
 impl windows_core::TypeKind for ContactAnnotationStoreAccessType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactAnnotationStoreAccessType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactAnnotationStoreAccessType").field(&self.0).finish()
@@ -5478,6 +5481,7 @@ impl ContactBatchStatus {
 impl windows_core::TypeKind for ContactBatchStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactBatchStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactBatchStatus").field(&self.0).finish()
@@ -5497,6 +5501,7 @@ impl ContactCardHeaderKind {
 impl windows_core::TypeKind for ContactCardHeaderKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactCardHeaderKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactCardHeaderKind").field(&self.0).finish()
@@ -5519,6 +5524,7 @@ impl ContactCardTabKind {
 impl windows_core::TypeKind for ContactCardTabKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactCardTabKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactCardTabKind").field(&self.0).finish()
@@ -5539,6 +5545,7 @@ impl ContactChangeType {
 impl windows_core::TypeKind for ContactChangeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactChangeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactChangeType").field(&self.0).finish()
@@ -5558,6 +5565,7 @@ impl ContactDateKind {
 impl windows_core::TypeKind for ContactDateKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactDateKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactDateKind").field(&self.0).finish()
@@ -5577,6 +5585,7 @@ impl ContactEmailKind {
 impl windows_core::TypeKind for ContactEmailKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactEmailKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactEmailKind").field(&self.0).finish()
@@ -5598,6 +5607,7 @@ impl ContactFieldCategory {
 impl windows_core::TypeKind for ContactFieldCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactFieldCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactFieldCategory").field(&self.0).finish()
@@ -5626,6 +5636,7 @@ impl ContactFieldType {
 impl windows_core::TypeKind for ContactFieldType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactFieldType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactFieldType").field(&self.0).finish()
@@ -5646,6 +5657,7 @@ impl ContactListOtherAppReadAccess {
 impl windows_core::TypeKind for ContactListOtherAppReadAccess {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactListOtherAppReadAccess {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactListOtherAppReadAccess").field(&self.0).finish()
@@ -5665,6 +5677,7 @@ impl ContactListOtherAppWriteAccess {
 impl windows_core::TypeKind for ContactListOtherAppWriteAccess {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactListOtherAppWriteAccess {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactListOtherAppWriteAccess").field(&self.0).finish()
@@ -5688,6 +5701,7 @@ impl ContactListSyncStatus {
 impl windows_core::TypeKind for ContactListSyncStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactListSyncStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactListSyncStatus").field(&self.0).finish()
@@ -5710,6 +5724,7 @@ impl ContactMatchReasonKind {
 impl windows_core::TypeKind for ContactMatchReasonKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactMatchReasonKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactMatchReasonKind").field(&self.0).finish()
@@ -5728,6 +5743,7 @@ impl ContactNameOrder {
 impl windows_core::TypeKind for ContactNameOrder {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactNameOrder {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactNameOrder").field(&self.0).finish()
@@ -5754,6 +5770,7 @@ impl ContactPhoneKind {
 impl windows_core::TypeKind for ContactPhoneKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactPhoneKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactPhoneKind").field(&self.0).finish()
@@ -5774,6 +5791,7 @@ impl ContactQueryDesiredFields {
 impl windows_core::TypeKind for ContactQueryDesiredFields {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactQueryDesiredFields {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactQueryDesiredFields").field(&self.0).finish()
@@ -5828,6 +5846,7 @@ impl ContactQuerySearchFields {
 impl windows_core::TypeKind for ContactQuerySearchFields {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactQuerySearchFields {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactQuerySearchFields").field(&self.0).finish()
@@ -5879,6 +5898,7 @@ impl ContactQuerySearchScope {
 impl windows_core::TypeKind for ContactQuerySearchScope {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactQuerySearchScope {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactQuerySearchScope").field(&self.0).finish()
@@ -5901,6 +5921,7 @@ impl ContactRelationship {
 impl windows_core::TypeKind for ContactRelationship {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactRelationship {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactRelationship").field(&self.0).finish()
@@ -5919,6 +5940,7 @@ impl ContactSelectionMode {
 impl windows_core::TypeKind for ContactSelectionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactSelectionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactSelectionMode").field(&self.0).finish()
@@ -5938,6 +5960,7 @@ impl ContactStoreAccessType {
 impl windows_core::TypeKind for ContactStoreAccessType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactStoreAccessType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactStoreAccessType").field(&self.0).finish()
@@ -5956,6 +5979,7 @@ impl PinnedContactSurface {
 impl windows_core::TypeKind for PinnedContactSurface {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PinnedContactSurface {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PinnedContactSurface").field(&self.0).finish()
@@ -5966,3 +5990,5 @@ impl windows_core::RuntimeType for PinnedContactSurface {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,2746 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAggregateContactManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindRawContactsAsync(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAggregateContactManager", "FindRawContactsAsync", 6usize, |this| ((**(this as *const *const IAggregateContactManager_Vtbl)).FindRawContactsAsync)(this, contact, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryLinkContactsAsync(this: *mut core::ffi::c_void, primarycontact: *mut core::ffi::c_void, secondarycontact: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAggregateContactManager", "TryLinkContactsAsync", 7usize, |this| ((**(this as *const *const IAggregateContactManager_Vtbl)).TryLinkContactsAsync)(this, primarycontact, secondarycontact, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlinkRawContactAsync(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAggregateContactManager", "UnlinkRawContactAsync", 8usize, |this| ((**(this as *const *const IAggregateContactManager_Vtbl)).UnlinkRawContactAsync)(this, contact, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySetPreferredSourceForPictureAsync(this: *mut core::ffi::c_void, aggregatecontact: *mut core::ffi::c_void, rawcontact: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAggregateContactManager", "TrySetPreferredSourceForPictureAsync", 9usize, |this| ((**(this as *const *const IAggregateContactManager_Vtbl)).TrySetPreferredSourceForPictureAsync)(this, aggregatecontact, rawcontact, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindRawContactsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindRawContactsAsync: 0,
+            TryLinkContactsAsync,
+            UnlinkRawContactAsync,
+            TrySetPreferredSourceForPictureAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAggregateContactManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAggregateContactManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRemoteIdentificationInformationAsync(this: *mut core::ffi::c_void, contactlistid: core::mem::MaybeUninit<windows_core::HSTRING>, remotesourceid: core::mem::MaybeUninit<windows_core::HSTRING>, accountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAggregateContactManager2", "SetRemoteIdentificationInformationAsync", 6usize, |this| ((**(this as *const *const IAggregateContactManager2_Vtbl)).SetRemoteIdentificationInformationAsync)(this, contactlistid, remotesourceid, accountid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetRemoteIdentificationInformationAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAggregateContactManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IContact_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact", "Name", 6usize, |this| ((**(this as *const *const IContact_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact", "SetName", 7usize, |this| ((**(this as *const *const IContact_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact", "Thumbnail", 8usize, |this| ((**(this as *const *const IContact_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetThumbnail(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact", "SetThumbnail", 9usize, |this| ((**(this as *const *const IContact_Vtbl)).SetThumbnail)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Fields(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact", "Fields", 10usize, |this| ((**(this as *const *const IContact_Vtbl)).Fields)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            #[cfg(feature = "Storage_Streams")]
+            Thumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Thumbnail: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetThumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetThumbnail: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Fields,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Fields: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContact as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IContact2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact2", "Id", 6usize, |this| ((**(this as *const *const IContact2_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact2", "SetId", 7usize, |this| ((**(this as *const *const IContact2_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Notes(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact2", "Notes", 8usize, |this| ((**(this as *const *const IContact2_Vtbl)).Notes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotes(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact2", "SetNotes", 9usize, |this| ((**(this as *const *const IContact2_Vtbl)).SetNotes)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Phones(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact2", "Phones", 10usize, |this| ((**(this as *const *const IContact2_Vtbl)).Phones)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Emails(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact2", "Emails", 11usize, |this| ((**(this as *const *const IContact2_Vtbl)).Emails)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Addresses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact2", "Addresses", 12usize, |this| ((**(this as *const *const IContact2_Vtbl)).Addresses)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ConnectedServiceAccounts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact2", "ConnectedServiceAccounts", 13usize, |this| ((**(this as *const *const IContact2_Vtbl)).ConnectedServiceAccounts)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ImportantDates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact2", "ImportantDates", 14usize, |this| ((**(this as *const *const IContact2_Vtbl)).ImportantDates)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DataSuppliers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact2", "DataSuppliers", 15usize, |this| ((**(this as *const *const IContact2_Vtbl)).DataSuppliers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn JobInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact2", "JobInfo", 16usize, |this| ((**(this as *const *const IContact2_Vtbl)).JobInfo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SignificantOthers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact2", "SignificantOthers", 17usize, |this| ((**(this as *const *const IContact2_Vtbl)).SignificantOthers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Websites(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact2", "Websites", 18usize, |this| ((**(this as *const *const IContact2_Vtbl)).Websites)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ProviderProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact2", "ProviderProperties", 19usize, |this| ((**(this as *const *const IContact2_Vtbl)).ProviderProperties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            SetId,
+            Notes,
+            SetNotes,
+            #[cfg(feature = "Foundation_Collections")]
+            Phones,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Phones: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Emails,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Emails: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Addresses,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Addresses: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ConnectedServiceAccounts,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ConnectedServiceAccounts: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ImportantDates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ImportantDates: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            DataSuppliers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DataSuppliers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            JobInfo,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            JobInfo: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SignificantOthers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SignificantOthers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Websites,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Websites: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ProviderProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ProviderProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContact2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IContact3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactListId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "ContactListId", 6usize, |this| ((**(this as *const *const IContact3_Vtbl)).ContactListId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayPictureUserUpdateTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "DisplayPictureUserUpdateTime", 7usize, |this| ((**(this as *const *const IContact3_Vtbl)).DisplayPictureUserUpdateTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayPictureUserUpdateTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "SetDisplayPictureUserUpdateTime", 8usize, |this| ((**(this as *const *const IContact3_Vtbl)).SetDisplayPictureUserUpdateTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMe(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "IsMe", 9usize, |this| ((**(this as *const *const IContact3_Vtbl)).IsMe)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AggregateId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "AggregateId", 10usize, |this| ((**(this as *const *const IContact3_Vtbl)).AggregateId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "RemoteId", 11usize, |this| ((**(this as *const *const IContact3_Vtbl)).RemoteId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "SetRemoteId", 12usize, |this| ((**(this as *const *const IContact3_Vtbl)).SetRemoteId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RingToneToken(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "RingToneToken", 13usize, |this| ((**(this as *const *const IContact3_Vtbl)).RingToneToken)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRingToneToken(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "SetRingToneToken", 14usize, |this| ((**(this as *const *const IContact3_Vtbl)).SetRingToneToken)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDisplayPictureManuallySet(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "IsDisplayPictureManuallySet", 15usize, |this| ((**(this as *const *const IContact3_Vtbl)).IsDisplayPictureManuallySet)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn LargeDisplayPicture(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "LargeDisplayPicture", 16usize, |this| ((**(this as *const *const IContact3_Vtbl)).LargeDisplayPicture)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SmallDisplayPicture(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "SmallDisplayPicture", 17usize, |this| ((**(this as *const *const IContact3_Vtbl)).SmallDisplayPicture)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SourceDisplayPicture(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "SourceDisplayPicture", 18usize, |this| ((**(this as *const *const IContact3_Vtbl)).SourceDisplayPicture)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetSourceDisplayPicture(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "SetSourceDisplayPicture", 19usize, |this| ((**(this as *const *const IContact3_Vtbl)).SetSourceDisplayPicture)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextToneToken(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "TextToneToken", 20usize, |this| ((**(this as *const *const IContact3_Vtbl)).TextToneToken)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextToneToken(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "SetTextToneToken", 21usize, |this| ((**(this as *const *const IContact3_Vtbl)).SetTextToneToken)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAggregate(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "IsAggregate", 22usize, |this| ((**(this as *const *const IContact3_Vtbl)).IsAggregate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FullName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "FullName", 23usize, |this| ((**(this as *const *const IContact3_Vtbl)).FullName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayNameOverride(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "DisplayNameOverride", 24usize, |this| ((**(this as *const *const IContact3_Vtbl)).DisplayNameOverride)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayNameOverride(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "SetDisplayNameOverride", 25usize, |this| ((**(this as *const *const IContact3_Vtbl)).SetDisplayNameOverride)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Nickname(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "Nickname", 26usize, |this| ((**(this as *const *const IContact3_Vtbl)).Nickname)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNickname(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "SetNickname", 27usize, |this| ((**(this as *const *const IContact3_Vtbl)).SetNickname)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SortName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact3", "SortName", 28usize, |this| ((**(this as *const *const IContact3_Vtbl)).SortName)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ContactListId,
+            DisplayPictureUserUpdateTime,
+            SetDisplayPictureUserUpdateTime,
+            IsMe,
+            AggregateId,
+            RemoteId,
+            SetRemoteId,
+            RingToneToken,
+            SetRingToneToken,
+            IsDisplayPictureManuallySet,
+            #[cfg(feature = "Storage_Streams")]
+            LargeDisplayPicture,
+            #[cfg(not(feature = "Storage_Streams"))]
+            LargeDisplayPicture: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SmallDisplayPicture,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SmallDisplayPicture: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SourceDisplayPicture,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SourceDisplayPicture: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetSourceDisplayPicture,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetSourceDisplayPicture: 0,
+            TextToneToken,
+            SetTextToneToken,
+            IsAggregate,
+            FullName,
+            DisplayNameOverride,
+            SetDisplayNameOverride,
+            Nickname,
+            SetNickname,
+            SortName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContact3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactAddress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StreetAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "StreetAddress", 6usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).StreetAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreetAddress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "SetStreetAddress", 7usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).SetStreetAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Locality(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "Locality", 8usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).Locality)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocality(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "SetLocality", 9usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).SetLocality)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Region(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "Region", 10usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).Region)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRegion(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "SetRegion", 11usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).SetRegion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Country(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "Country", 12usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).Country)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCountry(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "SetCountry", 13usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).SetCountry)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostalCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "PostalCode", 14usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).PostalCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPostalCode(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "SetPostalCode", 15usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).SetPostalCode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut ContactAddressKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "Kind", 16usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKind(this: *mut core::ffi::c_void, value: ContactAddressKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "SetKind", 17usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).SetKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "Description", 18usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "SetDescription", 19usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StreetAddress,
+            SetStreetAddress,
+            Locality,
+            SetLocality,
+            Region,
+            SetRegion,
+            Country,
+            SetCountry,
+            PostalCode,
+            SetPostalCode,
+            Kind,
+            SetKind,
+            Description,
+            SetDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAddress as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactAnnotation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotation", "Id", 6usize, |this| ((**(this as *const *const IContactAnnotation_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnnotationListId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotation", "AnnotationListId", 7usize, |this| ((**(this as *const *const IContactAnnotation_Vtbl)).AnnotationListId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContactId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotation", "ContactId", 8usize, |this| ((**(this as *const *const IContactAnnotation_Vtbl)).ContactId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContactId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotation", "SetContactId", 9usize, |this| ((**(this as *const *const IContactAnnotation_Vtbl)).SetContactId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotation", "RemoteId", 10usize, |this| ((**(this as *const *const IContactAnnotation_Vtbl)).RemoteId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotation", "SetRemoteId", 11usize, |this| ((**(this as *const *const IContactAnnotation_Vtbl)).SetRemoteId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedOperations(this: *mut core::ffi::c_void, result__: *mut ContactAnnotationOperations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotation", "SupportedOperations", 12usize, |this| ((**(this as *const *const IContactAnnotation_Vtbl)).SupportedOperations)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportedOperations(this: *mut core::ffi::c_void, value: ContactAnnotationOperations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotation", "SetSupportedOperations", 13usize, |this| ((**(this as *const *const IContactAnnotation_Vtbl)).SetSupportedOperations)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDisabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotation", "IsDisabled", 14usize, |this| ((**(this as *const *const IContactAnnotation_Vtbl)).IsDisabled)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ProviderProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotation", "ProviderProperties", 15usize, |this| ((**(this as *const *const IContactAnnotation_Vtbl)).ProviderProperties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            AnnotationListId,
+            ContactId,
+            SetContactId,
+            RemoteId,
+            SetRemoteId,
+            SupportedOperations,
+            SetSupportedOperations,
+            IsDisabled,
+            #[cfg(feature = "Foundation_Collections")]
+            ProviderProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ProviderProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAnnotation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactAnnotation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactListId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotation2", "ContactListId", 6usize, |this| ((**(this as *const *const IContactAnnotation2_Vtbl)).ContactListId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContactListId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotation2", "SetContactListId", 7usize, |this| ((**(this as *const *const IContactAnnotation2_Vtbl)).SetContactListId)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContactListId, SetContactListId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAnnotation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactAnnotationList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationList", "Id", 6usize, |this| ((**(this as *const *const IContactAnnotationList_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderPackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationList", "ProviderPackageFamilyName", 7usize, |this| ((**(this as *const *const IContactAnnotationList_Vtbl)).ProviderPackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserDataAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationList", "UserDataAccountId", 8usize, |this| ((**(this as *const *const IContactAnnotationList_Vtbl)).UserDataAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationList", "DeleteAsync", 9usize, |this| ((**(this as *const *const IContactAnnotationList_Vtbl)).DeleteAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySaveAnnotationAsync(this: *mut core::ffi::c_void, annotation: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationList", "TrySaveAnnotationAsync", 10usize, |this| ((**(this as *const *const IContactAnnotationList_Vtbl)).TrySaveAnnotationAsync)(this, annotation, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAnnotationAsync(this: *mut core::ffi::c_void, annotationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationList", "GetAnnotationAsync", 11usize, |this| ((**(this as *const *const IContactAnnotationList_Vtbl)).GetAnnotationAsync)(this, annotationid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAnnotationsByRemoteIdAsync(this: *mut core::ffi::c_void, remoteid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationList", "FindAnnotationsByRemoteIdAsync", 12usize, |this| ((**(this as *const *const IContactAnnotationList_Vtbl)).FindAnnotationsByRemoteIdAsync)(this, remoteid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAnnotationsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationList", "FindAnnotationsAsync", 13usize, |this| ((**(this as *const *const IContactAnnotationList_Vtbl)).FindAnnotationsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAnnotationAsync(this: *mut core::ffi::c_void, annotation: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationList", "DeleteAnnotationAsync", 14usize, |this| ((**(this as *const *const IContactAnnotationList_Vtbl)).DeleteAnnotationAsync)(this, annotation, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            ProviderPackageFamilyName,
+            UserDataAccountId,
+            DeleteAsync,
+            TrySaveAnnotationAsync,
+            GetAnnotationAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAnnotationsByRemoteIdAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAnnotationsByRemoteIdAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAnnotationsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAnnotationsAsync: 0,
+            DeleteAnnotationAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAnnotationList as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactAnnotationStore_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindContactIdsByEmailAsync(this: *mut core::ffi::c_void, emailaddress: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationStore", "FindContactIdsByEmailAsync", 6usize, |this| ((**(this as *const *const IContactAnnotationStore_Vtbl)).FindContactIdsByEmailAsync)(this, emailaddress, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindContactIdsByPhoneNumberAsync(this: *mut core::ffi::c_void, phonenumber: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationStore", "FindContactIdsByPhoneNumberAsync", 7usize, |this| ((**(this as *const *const IContactAnnotationStore_Vtbl)).FindContactIdsByPhoneNumberAsync)(this, phonenumber, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAnnotationsForContactAsync(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationStore", "FindAnnotationsForContactAsync", 8usize, |this| ((**(this as *const *const IContactAnnotationStore_Vtbl)).FindAnnotationsForContactAsync)(this, contact, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableAnnotationAsync(this: *mut core::ffi::c_void, annotation: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationStore", "DisableAnnotationAsync", 9usize, |this| ((**(this as *const *const IContactAnnotationStore_Vtbl)).DisableAnnotationAsync)(this, annotation, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAnnotationListAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationStore", "CreateAnnotationListAsync", 10usize, |this| ((**(this as *const *const IContactAnnotationStore_Vtbl)).CreateAnnotationListAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAnnotationListInAccountAsync(this: *mut core::ffi::c_void, userdataaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationStore", "CreateAnnotationListInAccountAsync", 11usize, |this| ((**(this as *const *const IContactAnnotationStore_Vtbl)).CreateAnnotationListInAccountAsync)(this, userdataaccountid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAnnotationListAsync(this: *mut core::ffi::c_void, annotationlistid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationStore", "GetAnnotationListAsync", 12usize, |this| ((**(this as *const *const IContactAnnotationStore_Vtbl)).GetAnnotationListAsync)(this, annotationlistid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAnnotationListsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationStore", "FindAnnotationListsAsync", 13usize, |this| ((**(this as *const *const IContactAnnotationStore_Vtbl)).FindAnnotationListsAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindContactIdsByEmailAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindContactIdsByEmailAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindContactIdsByPhoneNumberAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindContactIdsByPhoneNumberAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAnnotationsForContactAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAnnotationsForContactAsync: 0,
+            DisableAnnotationAsync,
+            CreateAnnotationListAsync,
+            CreateAnnotationListInAccountAsync,
+            GetAnnotationListAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAnnotationListsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAnnotationListsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAnnotationStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactAnnotationStore2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAnnotationsForContactListAsync(this: *mut core::ffi::c_void, contactlistid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAnnotationStore2", "FindAnnotationsForContactListAsync", 6usize, |this| ((**(this as *const *const IContactAnnotationStore2_Vtbl)).FindAnnotationsForContactListAsync)(this, contactlistid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAnnotationsForContactListAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAnnotationsForContactListAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAnnotationStore2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactBatch_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Contacts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactBatch", "Contacts", 6usize, |this| ((**(this as *const *const IContactBatch_Vtbl)).Contacts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut ContactBatchStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactBatch", "Status", 7usize, |this| ((**(this as *const *const IContactBatch_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Contacts,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Contacts: 0,
+            Status,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactBatch as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactCardDelayedDataLoader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactCardDelayedDataLoader", "SetData", 6usize, |this| ((**(this as *const *const IContactCardDelayedDataLoader_Vtbl)).SetData)(this, contact), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactCardDelayedDataLoader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactCardOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HeaderKind(this: *mut core::ffi::c_void, result__: *mut ContactCardHeaderKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactCardOptions", "HeaderKind", 6usize, |this| ((**(this as *const *const IContactCardOptions_Vtbl)).HeaderKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHeaderKind(this: *mut core::ffi::c_void, value: ContactCardHeaderKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactCardOptions", "SetHeaderKind", 7usize, |this| ((**(this as *const *const IContactCardOptions_Vtbl)).SetHeaderKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialTabKind(this: *mut core::ffi::c_void, result__: *mut ContactCardTabKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactCardOptions", "InitialTabKind", 8usize, |this| ((**(this as *const *const IContactCardOptions_Vtbl)).InitialTabKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialTabKind(this: *mut core::ffi::c_void, value: ContactCardTabKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactCardOptions", "SetInitialTabKind", 9usize, |this| ((**(this as *const *const IContactCardOptions_Vtbl)).SetInitialTabKind)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HeaderKind, SetHeaderKind, InitialTabKind, SetInitialTabKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactCardOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactCardOptions2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ServerSearchContactListIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactCardOptions2", "ServerSearchContactListIds", 6usize, |this| ((**(this as *const *const IContactCardOptions2_Vtbl)).ServerSearchContactListIds)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ServerSearchContactListIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ServerSearchContactListIds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactCardOptions2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeType(this: *mut core::ffi::c_void, result__: *mut ContactChangeType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactChange", "ChangeType", 6usize, |this| ((**(this as *const *const IContactChange_Vtbl)).ChangeType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactChange", "Contact", 7usize, |this| ((**(this as *const *const IContactChange_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ChangeType, Contact }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactChange as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactChangeReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcceptChanges(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactChangeReader", "AcceptChanges", 6usize, |this| ((**(this as *const *const IContactChangeReader_Vtbl)).AcceptChanges)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptChangesThrough(this: *mut core::ffi::c_void, lastchangetoaccept: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactChangeReader", "AcceptChangesThrough", 7usize, |this| ((**(this as *const *const IContactChangeReader_Vtbl)).AcceptChangesThrough)(this, lastchangetoaccept), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadBatchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactChangeReader", "ReadBatchAsync", 8usize, |this| ((**(this as *const *const IContactChangeReader_Vtbl)).ReadBatchAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AcceptChanges,
+            AcceptChangesThrough,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadBatchAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadBatchAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactChangeReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactChangeTracker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactChangeTracker", "Enable", 6usize, |this| ((**(this as *const *const IContactChangeTracker_Vtbl)).Enable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactChangeTracker", "GetChangeReader", 7usize, |this| ((**(this as *const *const IContactChangeTracker_Vtbl)).GetChangeReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactChangeTracker", "Reset", 8usize, |this| ((**(this as *const *const IContactChangeTracker_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Enable, GetChangeReader, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactChangeTracker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactChangeTracker2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTracking(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactChangeTracker2", "IsTracking", 6usize, |this| ((**(this as *const *const IContactChangeTracker2_Vtbl)).IsTracking)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsTracking }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactChangeTracker2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactChangedDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactChangedDeferral", "Complete", 6usize, |this| ((**(this as *const *const IContactChangedDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactChangedDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactChangedEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const IContactChangedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactConnectedServiceAccount_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactConnectedServiceAccount", "Id", 6usize, |this| ((**(this as *const *const IContactConnectedServiceAccount_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactConnectedServiceAccount", "SetId", 7usize, |this| ((**(this as *const *const IContactConnectedServiceAccount_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactConnectedServiceAccount", "ServiceName", 8usize, |this| ((**(this as *const *const IContactConnectedServiceAccount_Vtbl)).ServiceName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactConnectedServiceAccount", "SetServiceName", 9usize, |this| ((**(this as *const *const IContactConnectedServiceAccount_Vtbl)).SetServiceName)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, SetId, ServiceName, SetServiceName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactConnectedServiceAccount as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactDate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Day(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDate", "Day", 6usize, |this| ((**(this as *const *const IContactDate_Vtbl)).Day)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDay(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDate", "SetDay", 7usize, |this| ((**(this as *const *const IContactDate_Vtbl)).SetDay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Month(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDate", "Month", 8usize, |this| ((**(this as *const *const IContactDate_Vtbl)).Month)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMonth(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDate", "SetMonth", 9usize, |this| ((**(this as *const *const IContactDate_Vtbl)).SetMonth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Year(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDate", "Year", 10usize, |this| ((**(this as *const *const IContactDate_Vtbl)).Year)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetYear(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDate", "SetYear", 11usize, |this| ((**(this as *const *const IContactDate_Vtbl)).SetYear)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut ContactDateKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDate", "Kind", 12usize, |this| ((**(this as *const *const IContactDate_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKind(this: *mut core::ffi::c_void, value: ContactDateKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDate", "SetKind", 13usize, |this| ((**(this as *const *const IContactDate_Vtbl)).SetKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDate", "Description", 14usize, |this| ((**(this as *const *const IContactDate_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactDate", "SetDescription", 15usize, |this| ((**(this as *const *const IContactDate_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Day,
+            SetDay,
+            Month,
+            SetMonth,
+            Year,
+            SetYear,
+            Kind,
+            SetKind,
+            Description,
+            SetDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactDate as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactEmail_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactEmail", "Address", 6usize, |this| ((**(this as *const *const IContactEmail_Vtbl)).Address)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAddress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactEmail", "SetAddress", 7usize, |this| ((**(this as *const *const IContactEmail_Vtbl)).SetAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut ContactEmailKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactEmail", "Kind", 8usize, |this| ((**(this as *const *const IContactEmail_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKind(this: *mut core::ffi::c_void, value: ContactEmailKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactEmail", "SetKind", 9usize, |this| ((**(this as *const *const IContactEmail_Vtbl)).SetKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactEmail", "Description", 10usize, |this| ((**(this as *const *const IContactEmail_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactEmail", "SetDescription", 11usize, |this| ((**(this as *const *const IContactEmail_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Address,
+            SetAddress,
+            Kind,
+            SetKind,
+            Description,
+            SetDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactEmail as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactField_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut ContactFieldType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactField", "Type", 6usize, |this| ((**(this as *const *const IContactField_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Category(this: *mut core::ffi::c_void, result__: *mut ContactFieldCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactField", "Category", 7usize, |this| ((**(this as *const *const IContactField_Vtbl)).Category)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactField", "Name", 8usize, |this| ((**(this as *const *const IContactField_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactField", "Value", 9usize, |this| ((**(this as *const *const IContactField_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Type, Category, Name, Value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactField as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactFieldFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateField_Default(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, r#type: ContactFieldType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactFieldFactory", "CreateField_Default", 6usize, |this| ((**(this as *const *const IContactFieldFactory_Vtbl)).CreateField_Default)(this, value, r#type, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateField_Category(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, r#type: ContactFieldType, category: ContactFieldCategory, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactFieldFactory", "CreateField_Category", 7usize, |this| ((**(this as *const *const IContactFieldFactory_Vtbl)).CreateField_Category)(this, value, r#type, category, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateField_Custom(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>, r#type: ContactFieldType, category: ContactFieldCategory, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactFieldFactory", "CreateField_Custom", 8usize, |this| ((**(this as *const *const IContactFieldFactory_Vtbl)).CreateField_Custom)(this, name, value, r#type, category, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateField_Default,
+            CreateField_Category,
+            CreateField_Custom,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactFieldFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactGroup_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactGroup as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IContactInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "Name", 6usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetThumbnailAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "GetThumbnailAsync", 7usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).GetThumbnailAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Emails(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "Emails", 8usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).Emails)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PhoneNumbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "PhoneNumbers", 9usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).PhoneNumbers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Locations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "Locations", 10usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).Locations)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn InstantMessages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "InstantMessages", 11usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).InstantMessages)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CustomFields(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "CustomFields", 12usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).CustomFields)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn QueryCustomFields(this: *mut core::ffi::c_void, customname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "QueryCustomFields", 13usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).QueryCustomFields)(this, customname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            #[cfg(feature = "Storage_Streams")]
+            GetThumbnailAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetThumbnailAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Emails,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Emails: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            PhoneNumbers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PhoneNumbers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Locations,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Locations: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            InstantMessages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            InstantMessages: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CustomFields,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CustomFields: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            QueryCustomFields,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            QueryCustomFields: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactInstantMessageField_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInstantMessageField", "UserName", 6usize, |this| ((**(this as *const *const IContactInstantMessageField_Vtbl)).UserName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Service(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInstantMessageField", "Service", 7usize, |this| ((**(this as *const *const IContactInstantMessageField_Vtbl)).Service)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInstantMessageField", "DisplayText", 8usize, |this| ((**(this as *const *const IContactInstantMessageField_Vtbl)).DisplayText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInstantMessageField", "LaunchUri", 9usize, |this| ((**(this as *const *const IContactInstantMessageField_Vtbl)).LaunchUri)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserName, Service, DisplayText, LaunchUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactInstantMessageField as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactInstantMessageFieldFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstantMessage_Default(this: *mut core::ffi::c_void, username: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInstantMessageFieldFactory", "CreateInstantMessage_Default", 6usize, |this| ((**(this as *const *const IContactInstantMessageFieldFactory_Vtbl)).CreateInstantMessage_Default)(this, username, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstantMessage_Category(this: *mut core::ffi::c_void, username: core::mem::MaybeUninit<windows_core::HSTRING>, category: ContactFieldCategory, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInstantMessageFieldFactory", "CreateInstantMessage_Category", 7usize, |this| ((**(this as *const *const IContactInstantMessageFieldFactory_Vtbl)).CreateInstantMessage_Category)(this, username, category, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstantMessage_All(this: *mut core::ffi::c_void, username: core::mem::MaybeUninit<windows_core::HSTRING>, category: ContactFieldCategory, service: core::mem::MaybeUninit<windows_core::HSTRING>, displaytext: core::mem::MaybeUninit<windows_core::HSTRING>, verb: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInstantMessageFieldFactory", "CreateInstantMessage_All", 8usize, |this| ((**(this as *const *const IContactInstantMessageFieldFactory_Vtbl)).CreateInstantMessage_All)(this, username, category, service, displaytext, verb, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateInstantMessage_Default,
+            CreateInstantMessage_Category,
+            CreateInstantMessage_All,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactInstantMessageFieldFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactJobInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CompanyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "CompanyName", 6usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).CompanyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompanyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "SetCompanyName", 7usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).SetCompanyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompanyYomiName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "CompanyYomiName", 8usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).CompanyYomiName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompanyYomiName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "SetCompanyYomiName", 9usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).SetCompanyYomiName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Department(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "Department", 10usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).Department)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDepartment(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "SetDepartment", 11usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).SetDepartment)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "Title", 12usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "SetTitle", 13usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Manager(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "Manager", 14usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).Manager)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetManager(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "SetManager", 15usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).SetManager)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Office(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "Office", 16usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).Office)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffice(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "SetOffice", 17usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).SetOffice)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompanyAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "CompanyAddress", 18usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).CompanyAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompanyAddress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "SetCompanyAddress", 19usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).SetCompanyAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "Description", 20usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactJobInfo", "SetDescription", 21usize, |this| ((**(this as *const *const IContactJobInfo_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CompanyName,
+            SetCompanyName,
+            CompanyYomiName,
+            SetCompanyYomiName,
+            Department,
+            SetDepartment,
+            Title,
+            SetTitle,
+            Manager,
+            SetManager,
+            Office,
+            SetOffice,
+            CompanyAddress,
+            SetCompanyAddress,
+            Description,
+            SetDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactJobInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactLaunchActionVerbsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactLaunchActionVerbsStatics", "Call", 6usize, |this| ((**(this as *const *const IContactLaunchActionVerbsStatics_Vtbl)).Call)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactLaunchActionVerbsStatics", "Message", 7usize, |this| ((**(this as *const *const IContactLaunchActionVerbsStatics_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Map(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactLaunchActionVerbsStatics", "Map", 8usize, |this| ((**(this as *const *const IContactLaunchActionVerbsStatics_Vtbl)).Map)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Post(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactLaunchActionVerbsStatics", "Post", 9usize, |this| ((**(this as *const *const IContactLaunchActionVerbsStatics_Vtbl)).Post)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoCall(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactLaunchActionVerbsStatics", "VideoCall", 10usize, |this| ((**(this as *const *const IContactLaunchActionVerbsStatics_Vtbl)).VideoCall)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Call, Message, Map, Post, VideoCall }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactLaunchActionVerbsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "Id", 6usize, |this| ((**(this as *const *const IContactList_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "DisplayName", 7usize, |this| ((**(this as *const *const IContactList_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "SetDisplayName", 8usize, |this| ((**(this as *const *const IContactList_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "SourceDisplayName", 9usize, |this| ((**(this as *const *const IContactList_Vtbl)).SourceDisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHidden(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "IsHidden", 10usize, |this| ((**(this as *const *const IContactList_Vtbl)).IsHidden)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsHidden(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "SetIsHidden", 11usize, |this| ((**(this as *const *const IContactList_Vtbl)).SetIsHidden)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherAppReadAccess(this: *mut core::ffi::c_void, result__: *mut ContactListOtherAppReadAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "OtherAppReadAccess", 12usize, |this| ((**(this as *const *const IContactList_Vtbl)).OtherAppReadAccess)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOtherAppReadAccess(this: *mut core::ffi::c_void, value: ContactListOtherAppReadAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "SetOtherAppReadAccess", 13usize, |this| ((**(this as *const *const IContactList_Vtbl)).SetOtherAppReadAccess)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherAppWriteAccess(this: *mut core::ffi::c_void, result__: *mut ContactListOtherAppWriteAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "OtherAppWriteAccess", 14usize, |this| ((**(this as *const *const IContactList_Vtbl)).OtherAppWriteAccess)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOtherAppWriteAccess(this: *mut core::ffi::c_void, value: ContactListOtherAppWriteAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "SetOtherAppWriteAccess", 15usize, |this| ((**(this as *const *const IContactList_Vtbl)).SetOtherAppWriteAccess)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeTracker(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "ChangeTracker", 16usize, |this| ((**(this as *const *const IContactList_Vtbl)).ChangeTracker)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "SyncManager", 17usize, |this| ((**(this as *const *const IContactList_Vtbl)).SyncManager)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportsServerSearch(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "SupportsServerSearch", 18usize, |this| ((**(this as *const *const IContactList_Vtbl)).SupportsServerSearch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserDataAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "UserDataAccountId", 19usize, |this| ((**(this as *const *const IContactList_Vtbl)).UserDataAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContactChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "ContactChanged", 20usize, |this| ((**(this as *const *const IContactList_Vtbl)).ContactChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveContactChanged(this: *mut core::ffi::c_void, value: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "RemoveContactChanged", 21usize, |this| ((**(this as *const *const IContactList_Vtbl)).RemoveContactChanged)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "SaveAsync", 22usize, |this| ((**(this as *const *const IContactList_Vtbl)).SaveAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "DeleteAsync", 23usize, |this| ((**(this as *const *const IContactList_Vtbl)).DeleteAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContactFromRemoteIdAsync(this: *mut core::ffi::c_void, remoteid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "GetContactFromRemoteIdAsync", 24usize, |this| ((**(this as *const *const IContactList_Vtbl)).GetContactFromRemoteIdAsync)(this, remoteid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMeContactAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "GetMeContactAsync", 25usize, |this| ((**(this as *const *const IContactList_Vtbl)).GetMeContactAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContactReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "GetContactReader", 26usize, |this| ((**(this as *const *const IContactList_Vtbl)).GetContactReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContactReaderWithOptions(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "GetContactReaderWithOptions", 27usize, |this| ((**(this as *const *const IContactList_Vtbl)).GetContactReaderWithOptions)(this, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveContactAsync(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "SaveContactAsync", 28usize, |this| ((**(this as *const *const IContactList_Vtbl)).SaveContactAsync)(this, contact, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteContactAsync(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "DeleteContactAsync", 29usize, |this| ((**(this as *const *const IContactList_Vtbl)).DeleteContactAsync)(this, contact, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContactAsync(this: *mut core::ffi::c_void, contactid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList", "GetContactAsync", 30usize, |this| ((**(this as *const *const IContactList_Vtbl)).GetContactAsync)(this, contactid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            DisplayName,
+            SetDisplayName,
+            SourceDisplayName,
+            IsHidden,
+            SetIsHidden,
+            OtherAppReadAccess,
+            SetOtherAppReadAccess,
+            OtherAppWriteAccess,
+            SetOtherAppWriteAccess,
+            ChangeTracker,
+            SyncManager,
+            SupportsServerSearch,
+            UserDataAccountId,
+            ContactChanged,
+            RemoveContactChanged,
+            SaveAsync,
+            DeleteAsync,
+            GetContactFromRemoteIdAsync,
+            GetMeContactAsync,
+            GetContactReader,
+            GetContactReaderWithOptions,
+            SaveContactAsync,
+            DeleteContactAsync,
+            GetContactAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactList as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactList2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterSyncManagerAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList2", "RegisterSyncManagerAsync", 6usize, |this| ((**(this as *const *const IContactList2_Vtbl)).RegisterSyncManagerAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportsServerSearch(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList2", "SetSupportsServerSearch", 7usize, |this| ((**(this as *const *const IContactList2_Vtbl)).SetSupportsServerSearch)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncConstraints(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList2", "SyncConstraints", 8usize, |this| ((**(this as *const *const IContactList2_Vtbl)).SyncConstraints)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterSyncManagerAsync,
+            SetSupportsServerSearch,
+            SyncConstraints,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactList2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactList3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LimitedWriteOperations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList3", "LimitedWriteOperations", 6usize, |this| ((**(this as *const *const IContactList3_Vtbl)).LimitedWriteOperations)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeTracker(this: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactList3", "GetChangeTracker", 7usize, |this| ((**(this as *const *const IContactList3_Vtbl)).GetChangeTracker)(this, identity, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LimitedWriteOperations, GetChangeTracker }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactList3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactListLimitedWriteOperations_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryCreateOrUpdateContactAsync(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListLimitedWriteOperations", "TryCreateOrUpdateContactAsync", 6usize, |this| ((**(this as *const *const IContactListLimitedWriteOperations_Vtbl)).TryCreateOrUpdateContactAsync)(this, contact, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryDeleteContactAsync(this: *mut core::ffi::c_void, contactid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListLimitedWriteOperations", "TryDeleteContactAsync", 7usize, |this| ((**(this as *const *const IContactListLimitedWriteOperations_Vtbl)).TryDeleteContactAsync)(this, contactid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryCreateOrUpdateContactAsync, TryDeleteContactAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactListLimitedWriteOperations as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactListSyncConstraints_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanSyncDescriptions(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "CanSyncDescriptions", 6usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).CanSyncDescriptions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanSyncDescriptions(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetCanSyncDescriptions", 7usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetCanSyncDescriptions)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxHomePhoneNumbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxHomePhoneNumbers", 8usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxHomePhoneNumbers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxHomePhoneNumbers(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxHomePhoneNumbers", 9usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxHomePhoneNumbers)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxMobilePhoneNumbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxMobilePhoneNumbers", 10usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxMobilePhoneNumbers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxMobilePhoneNumbers(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxMobilePhoneNumbers", 11usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxMobilePhoneNumbers)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxWorkPhoneNumbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxWorkPhoneNumbers", 12usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxWorkPhoneNumbers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxWorkPhoneNumbers(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxWorkPhoneNumbers", 13usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxWorkPhoneNumbers)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxOtherPhoneNumbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxOtherPhoneNumbers", 14usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxOtherPhoneNumbers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxOtherPhoneNumbers(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxOtherPhoneNumbers", 15usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxOtherPhoneNumbers)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPagerPhoneNumbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxPagerPhoneNumbers", 16usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxPagerPhoneNumbers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxPagerPhoneNumbers(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxPagerPhoneNumbers", 17usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxPagerPhoneNumbers)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBusinessFaxPhoneNumbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxBusinessFaxPhoneNumbers", 18usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxBusinessFaxPhoneNumbers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxBusinessFaxPhoneNumbers(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxBusinessFaxPhoneNumbers", 19usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxBusinessFaxPhoneNumbers)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxHomeFaxPhoneNumbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxHomeFaxPhoneNumbers", 20usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxHomeFaxPhoneNumbers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxHomeFaxPhoneNumbers(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxHomeFaxPhoneNumbers", 21usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxHomeFaxPhoneNumbers)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxCompanyPhoneNumbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxCompanyPhoneNumbers", 22usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxCompanyPhoneNumbers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxCompanyPhoneNumbers(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxCompanyPhoneNumbers", 23usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxCompanyPhoneNumbers)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxAssistantPhoneNumbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxAssistantPhoneNumbers", 24usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxAssistantPhoneNumbers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxAssistantPhoneNumbers(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxAssistantPhoneNumbers", 25usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxAssistantPhoneNumbers)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxRadioPhoneNumbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxRadioPhoneNumbers", 26usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxRadioPhoneNumbers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxRadioPhoneNumbers(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxRadioPhoneNumbers", 27usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxRadioPhoneNumbers)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPersonalEmailAddresses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxPersonalEmailAddresses", 28usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxPersonalEmailAddresses)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxPersonalEmailAddresses(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxPersonalEmailAddresses", 29usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxPersonalEmailAddresses)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxWorkEmailAddresses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxWorkEmailAddresses", 30usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxWorkEmailAddresses)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxWorkEmailAddresses(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxWorkEmailAddresses", 31usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxWorkEmailAddresses)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxOtherEmailAddresses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxOtherEmailAddresses", 32usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxOtherEmailAddresses)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxOtherEmailAddresses(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxOtherEmailAddresses", 33usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxOtherEmailAddresses)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxHomeAddresses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxHomeAddresses", 34usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxHomeAddresses)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxHomeAddresses(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxHomeAddresses", 35usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxHomeAddresses)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxWorkAddresses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxWorkAddresses", 36usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxWorkAddresses)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxWorkAddresses(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxWorkAddresses", 37usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxWorkAddresses)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxOtherAddresses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxOtherAddresses", 38usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxOtherAddresses)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxOtherAddresses(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxOtherAddresses", 39usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxOtherAddresses)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBirthdayDates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxBirthdayDates", 40usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxBirthdayDates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxBirthdayDates(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxBirthdayDates", 41usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxBirthdayDates)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxAnniversaryDates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxAnniversaryDates", 42usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxAnniversaryDates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxAnniversaryDates(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxAnniversaryDates", 43usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxAnniversaryDates)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxOtherDates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxOtherDates", 44usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxOtherDates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxOtherDates(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxOtherDates", 45usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxOtherDates)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxOtherRelationships(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxOtherRelationships", 46usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxOtherRelationships)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxOtherRelationships(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxOtherRelationships", 47usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxOtherRelationships)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSpouseRelationships(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxSpouseRelationships", 48usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxSpouseRelationships)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxSpouseRelationships(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxSpouseRelationships", 49usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxSpouseRelationships)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPartnerRelationships(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxPartnerRelationships", 50usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxPartnerRelationships)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxPartnerRelationships(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxPartnerRelationships", 51usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxPartnerRelationships)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSiblingRelationships(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxSiblingRelationships", 52usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxSiblingRelationships)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxSiblingRelationships(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxSiblingRelationships", 53usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxSiblingRelationships)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxParentRelationships(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxParentRelationships", 54usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxParentRelationships)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxParentRelationships(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxParentRelationships", 55usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxParentRelationships)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxChildRelationships(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxChildRelationships", 56usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxChildRelationships)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxChildRelationships(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxChildRelationships", 57usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxChildRelationships)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxJobInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxJobInfo", 58usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxJobInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxJobInfo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxJobInfo", 59usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxJobInfo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxWebsites(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "MaxWebsites", 60usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).MaxWebsites)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxWebsites(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncConstraints", "SetMaxWebsites", 61usize, |this| ((**(this as *const *const IContactListSyncConstraints_Vtbl)).SetMaxWebsites)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanSyncDescriptions,
+            SetCanSyncDescriptions,
+            MaxHomePhoneNumbers,
+            SetMaxHomePhoneNumbers,
+            MaxMobilePhoneNumbers,
+            SetMaxMobilePhoneNumbers,
+            MaxWorkPhoneNumbers,
+            SetMaxWorkPhoneNumbers,
+            MaxOtherPhoneNumbers,
+            SetMaxOtherPhoneNumbers,
+            MaxPagerPhoneNumbers,
+            SetMaxPagerPhoneNumbers,
+            MaxBusinessFaxPhoneNumbers,
+            SetMaxBusinessFaxPhoneNumbers,
+            MaxHomeFaxPhoneNumbers,
+            SetMaxHomeFaxPhoneNumbers,
+            MaxCompanyPhoneNumbers,
+            SetMaxCompanyPhoneNumbers,
+            MaxAssistantPhoneNumbers,
+            SetMaxAssistantPhoneNumbers,
+            MaxRadioPhoneNumbers,
+            SetMaxRadioPhoneNumbers,
+            MaxPersonalEmailAddresses,
+            SetMaxPersonalEmailAddresses,
+            MaxWorkEmailAddresses,
+            SetMaxWorkEmailAddresses,
+            MaxOtherEmailAddresses,
+            SetMaxOtherEmailAddresses,
+            MaxHomeAddresses,
+            SetMaxHomeAddresses,
+            MaxWorkAddresses,
+            SetMaxWorkAddresses,
+            MaxOtherAddresses,
+            SetMaxOtherAddresses,
+            MaxBirthdayDates,
+            SetMaxBirthdayDates,
+            MaxAnniversaryDates,
+            SetMaxAnniversaryDates,
+            MaxOtherDates,
+            SetMaxOtherDates,
+            MaxOtherRelationships,
+            SetMaxOtherRelationships,
+            MaxSpouseRelationships,
+            SetMaxSpouseRelationships,
+            MaxPartnerRelationships,
+            SetMaxPartnerRelationships,
+            MaxSiblingRelationships,
+            SetMaxSiblingRelationships,
+            MaxParentRelationships,
+            SetMaxParentRelationships,
+            MaxChildRelationships,
+            SetMaxChildRelationships,
+            MaxJobInfo,
+            SetMaxJobInfo,
+            MaxWebsites,
+            SetMaxWebsites,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactListSyncConstraints as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactListSyncManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut ContactListSyncStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncManager", "Status", 6usize, |this| ((**(this as *const *const IContactListSyncManager_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastSuccessfulSyncTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncManager", "LastSuccessfulSyncTime", 7usize, |this| ((**(this as *const *const IContactListSyncManager_Vtbl)).LastSuccessfulSyncTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastAttemptedSyncTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncManager", "LastAttemptedSyncTime", 8usize, |this| ((**(this as *const *const IContactListSyncManager_Vtbl)).LastAttemptedSyncTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncManager", "SyncAsync", 9usize, |this| ((**(this as *const *const IContactListSyncManager_Vtbl)).SyncAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncManager", "SyncStatusChanged", 10usize, |this| ((**(this as *const *const IContactListSyncManager_Vtbl)).SyncStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSyncStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncManager", "RemoveSyncStatusChanged", 11usize, |this| ((**(this as *const *const IContactListSyncManager_Vtbl)).RemoveSyncStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            LastSuccessfulSyncTime,
+            LastAttemptedSyncTime,
+            SyncAsync,
+            SyncStatusChanged,
+            RemoveSyncStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactListSyncManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactListSyncManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, value: ContactListSyncStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncManager2", "SetStatus", 6usize, |this| ((**(this as *const *const IContactListSyncManager2_Vtbl)).SetStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastSuccessfulSyncTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncManager2", "SetLastSuccessfulSyncTime", 7usize, |this| ((**(this as *const *const IContactListSyncManager2_Vtbl)).SetLastSuccessfulSyncTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastAttemptedSyncTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactListSyncManager2", "SetLastAttemptedSyncTime", 8usize, |this| ((**(this as *const *const IContactListSyncManager2_Vtbl)).SetLastAttemptedSyncTime)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetStatus,
+            SetLastSuccessfulSyncTime,
+            SetLastAttemptedSyncTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactListSyncManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactLocationField_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UnstructuredAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactLocationField", "UnstructuredAddress", 6usize, |this| ((**(this as *const *const IContactLocationField_Vtbl)).UnstructuredAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Street(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactLocationField", "Street", 7usize, |this| ((**(this as *const *const IContactLocationField_Vtbl)).Street)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn City(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactLocationField", "City", 8usize, |this| ((**(this as *const *const IContactLocationField_Vtbl)).City)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Region(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactLocationField", "Region", 9usize, |this| ((**(this as *const *const IContactLocationField_Vtbl)).Region)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Country(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactLocationField", "Country", 10usize, |this| ((**(this as *const *const IContactLocationField_Vtbl)).Country)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostalCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactLocationField", "PostalCode", 11usize, |this| ((**(this as *const *const IContactLocationField_Vtbl)).PostalCode)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UnstructuredAddress,
+            Street,
+            City,
+            Region,
+            Country,
+            PostalCode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactLocationField as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactLocationFieldFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateLocation_Default(this: *mut core::ffi::c_void, unstructuredaddress: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactLocationFieldFactory", "CreateLocation_Default", 6usize, |this| ((**(this as *const *const IContactLocationFieldFactory_Vtbl)).CreateLocation_Default)(this, unstructuredaddress, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLocation_Category(this: *mut core::ffi::c_void, unstructuredaddress: core::mem::MaybeUninit<windows_core::HSTRING>, category: ContactFieldCategory, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactLocationFieldFactory", "CreateLocation_Category", 7usize, |this| ((**(this as *const *const IContactLocationFieldFactory_Vtbl)).CreateLocation_Category)(this, unstructuredaddress, category, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLocation_All(this: *mut core::ffi::c_void, unstructuredaddress: core::mem::MaybeUninit<windows_core::HSTRING>, category: ContactFieldCategory, street: core::mem::MaybeUninit<windows_core::HSTRING>, city: core::mem::MaybeUninit<windows_core::HSTRING>, region: core::mem::MaybeUninit<windows_core::HSTRING>, country: core::mem::MaybeUninit<windows_core::HSTRING>, postalcode: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactLocationFieldFactory", "CreateLocation_All", 8usize, |this| ((**(this as *const *const IContactLocationFieldFactory_Vtbl)).CreateLocation_All)(this, unstructuredaddress, category, street, city, region, country, postalcode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateLocation_Default,
+            CreateLocation_Category,
+            CreateLocation_All,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactLocationFieldFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_Streams", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IContactManagerForUser_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ConvertContactToVCardAsync(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerForUser", "ConvertContactToVCardAsync", 6usize, |this| ((**(this as *const *const IContactManagerForUser_Vtbl)).ConvertContactToVCardAsync)(this, contact, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ConvertContactToVCardAsyncWithMaxBytes(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, maxbytes: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerForUser", "ConvertContactToVCardAsyncWithMaxBytes", 7usize, |this| ((**(this as *const *const IContactManagerForUser_Vtbl)).ConvertContactToVCardAsyncWithMaxBytes)(this, contact, maxbytes, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ConvertVCardToContactAsync(this: *mut core::ffi::c_void, vcard: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerForUser", "ConvertVCardToContactAsync", 8usize, |this| ((**(this as *const *const IContactManagerForUser_Vtbl)).ConvertVCardToContactAsync)(this, vcard, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, accesstype: ContactStoreAccessType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerForUser", "RequestStoreAsync", 9usize, |this| ((**(this as *const *const IContactManagerForUser_Vtbl)).RequestStoreAsync)(this, accesstype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAnnotationStoreAsync(this: *mut core::ffi::c_void, accesstype: ContactAnnotationStoreAccessType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerForUser", "RequestAnnotationStoreAsync", 10usize, |this| ((**(this as *const *const IContactManagerForUser_Vtbl)).RequestAnnotationStoreAsync)(this, accesstype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemDisplayNameOrder(this: *mut core::ffi::c_void, result__: *mut ContactNameOrder) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerForUser", "SystemDisplayNameOrder", 11usize, |this| ((**(this as *const *const IContactManagerForUser_Vtbl)).SystemDisplayNameOrder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSystemDisplayNameOrder(this: *mut core::ffi::c_void, value: ContactNameOrder) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerForUser", "SetSystemDisplayNameOrder", 12usize, |this| ((**(this as *const *const IContactManagerForUser_Vtbl)).SetSystemDisplayNameOrder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemSortOrder(this: *mut core::ffi::c_void, result__: *mut ContactNameOrder) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerForUser", "SystemSortOrder", 13usize, |this| ((**(this as *const *const IContactManagerForUser_Vtbl)).SystemSortOrder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSystemSortOrder(this: *mut core::ffi::c_void, value: ContactNameOrder) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerForUser", "SetSystemSortOrder", 14usize, |this| ((**(this as *const *const IContactManagerForUser_Vtbl)).SetSystemSortOrder)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerForUser", "User", 15usize, |this| ((**(this as *const *const IContactManagerForUser_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            ConvertContactToVCardAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ConvertContactToVCardAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ConvertContactToVCardAsyncWithMaxBytes,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ConvertContactToVCardAsyncWithMaxBytes: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ConvertVCardToContactAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ConvertVCardToContactAsync: 0,
+            RequestStoreAsync,
+            RequestAnnotationStoreAsync,
+            SystemDisplayNameOrder,
+            SetSystemDisplayNameOrder,
+            SystemSortOrder,
+            SetSystemSortOrder,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactManagerForUser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactManagerForUser2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowFullContactCard(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, fullcontactcardoptions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerForUser2", "ShowFullContactCard", 6usize, |this| ((**(this as *const *const IContactManagerForUser2_Vtbl)).ShowFullContactCard)(this, contact, fullcontactcardoptions), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowFullContactCard }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactManagerForUser2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Popups")]
+impl windows_core::imp::ProxyVtable for IContactManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowContactCard(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, selection: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics", "ShowContactCard", 6usize, |this| ((**(this as *const *const IContactManagerStatics_Vtbl)).ShowContactCard)(this, contact, selection), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowContactCardWithPlacement(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics", "ShowContactCardWithPlacement", 7usize, |this| ((**(this as *const *const IContactManagerStatics_Vtbl)).ShowContactCardWithPlacement)(this, contact, selection, preferredplacement), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowDelayLoadedContactCard(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics", "ShowDelayLoadedContactCard", 8usize, |this| ((**(this as *const *const IContactManagerStatics_Vtbl)).ShowDelayLoadedContactCard)(this, contact, selection, preferredplacement, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ShowContactCard,
+            #[cfg(feature = "UI_Popups")]
+            ShowContactCardWithPlacement,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowContactCardWithPlacement: 0,
+            #[cfg(feature = "UI_Popups")]
+            ShowDelayLoadedContactCard,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowDelayLoadedContactCard: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Popups")]
+impl windows_core::imp::ProxyVtable for IContactManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics2", "RequestStoreAsync", 6usize, |this| ((**(this as *const *const IContactManagerStatics2_Vtbl)).RequestStoreAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestStoreAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_Streams", feature = "UI_Popups"))]
+impl windows_core::imp::ProxyVtable for IContactManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ConvertContactToVCardAsync(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics3", "ConvertContactToVCardAsync", 6usize, |this| ((**(this as *const *const IContactManagerStatics3_Vtbl)).ConvertContactToVCardAsync)(this, contact, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ConvertContactToVCardAsyncWithMaxBytes(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, maxbytes: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics3", "ConvertContactToVCardAsyncWithMaxBytes", 7usize, |this| ((**(this as *const *const IContactManagerStatics3_Vtbl)).ConvertContactToVCardAsyncWithMaxBytes)(this, contact, maxbytes, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ConvertVCardToContactAsync(this: *mut core::ffi::c_void, vcard: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics3", "ConvertVCardToContactAsync", 8usize, |this| ((**(this as *const *const IContactManagerStatics3_Vtbl)).ConvertVCardToContactAsync)(this, vcard, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStoreAsyncWithAccessType(this: *mut core::ffi::c_void, accesstype: ContactStoreAccessType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics3", "RequestStoreAsyncWithAccessType", 9usize, |this| ((**(this as *const *const IContactManagerStatics3_Vtbl)).RequestStoreAsyncWithAccessType)(this, accesstype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAnnotationStoreAsync(this: *mut core::ffi::c_void, accesstype: ContactAnnotationStoreAccessType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics3", "RequestAnnotationStoreAsync", 10usize, |this| ((**(this as *const *const IContactManagerStatics3_Vtbl)).RequestAnnotationStoreAsync)(this, accesstype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsShowContactCardSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics3", "IsShowContactCardSupported", 11usize, |this| ((**(this as *const *const IContactManagerStatics3_Vtbl)).IsShowContactCardSupported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowContactCardWithOptions(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, contactcardoptions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics3", "ShowContactCardWithOptions", 12usize, |this| ((**(this as *const *const IContactManagerStatics3_Vtbl)).ShowContactCardWithOptions)(this, contact, selection, preferredplacement, contactcardoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsShowDelayLoadedContactCardSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics3", "IsShowDelayLoadedContactCardSupported", 13usize, |this| ((**(this as *const *const IContactManagerStatics3_Vtbl)).IsShowDelayLoadedContactCardSupported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowDelayLoadedContactCardWithOptions(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, contactcardoptions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics3", "ShowDelayLoadedContactCardWithOptions", 14usize, |this| ((**(this as *const *const IContactManagerStatics3_Vtbl)).ShowDelayLoadedContactCardWithOptions)(this, contact, selection, preferredplacement, contactcardoptions, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowFullContactCard(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, fullcontactcardoptions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics3", "ShowFullContactCard", 15usize, |this| ((**(this as *const *const IContactManagerStatics3_Vtbl)).ShowFullContactCard)(this, contact, fullcontactcardoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemDisplayNameOrder(this: *mut core::ffi::c_void, result__: *mut ContactNameOrder) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics3", "SystemDisplayNameOrder", 16usize, |this| ((**(this as *const *const IContactManagerStatics3_Vtbl)).SystemDisplayNameOrder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSystemDisplayNameOrder(this: *mut core::ffi::c_void, value: ContactNameOrder) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics3", "SetSystemDisplayNameOrder", 17usize, |this| ((**(this as *const *const IContactManagerStatics3_Vtbl)).SetSystemDisplayNameOrder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemSortOrder(this: *mut core::ffi::c_void, result__: *mut ContactNameOrder) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics3", "SystemSortOrder", 18usize, |this| ((**(this as *const *const IContactManagerStatics3_Vtbl)).SystemSortOrder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSystemSortOrder(this: *mut core::ffi::c_void, value: ContactNameOrder) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics3", "SetSystemSortOrder", 19usize, |this| ((**(this as *const *const IContactManagerStatics3_Vtbl)).SetSystemSortOrder)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            ConvertContactToVCardAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ConvertContactToVCardAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ConvertContactToVCardAsyncWithMaxBytes,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ConvertContactToVCardAsyncWithMaxBytes: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ConvertVCardToContactAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ConvertVCardToContactAsync: 0,
+            RequestStoreAsyncWithAccessType,
+            RequestAnnotationStoreAsync,
+            IsShowContactCardSupported,
+            #[cfg(feature = "UI_Popups")]
+            ShowContactCardWithOptions,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowContactCardWithOptions: 0,
+            IsShowDelayLoadedContactCardSupported,
+            #[cfg(feature = "UI_Popups")]
+            ShowDelayLoadedContactCardWithOptions,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowDelayLoadedContactCardWithOptions: 0,
+            ShowFullContactCard,
+            SystemDisplayNameOrder,
+            SetSystemDisplayNameOrder,
+            SystemSortOrder,
+            SetSystemSortOrder,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IContactManagerStatics4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics4", "GetForUser", 6usize, |this| ((**(this as *const *const IContactManagerStatics4_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactManagerStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactManagerStatics5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsShowFullContactCardSupportedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics5", "IsShowFullContactCardSupportedAsync", 6usize, |this| ((**(this as *const *const IContactManagerStatics5_Vtbl)).IsShowFullContactCardSupportedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeMiddleNameInSystemDisplayAndSort(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics5", "IncludeMiddleNameInSystemDisplayAndSort", 7usize, |this| ((**(this as *const *const IContactManagerStatics5_Vtbl)).IncludeMiddleNameInSystemDisplayAndSort)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeMiddleNameInSystemDisplayAndSort(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManagerStatics5", "SetIncludeMiddleNameInSystemDisplayAndSort", 8usize, |this| ((**(this as *const *const IContactManagerStatics5_Vtbl)).SetIncludeMiddleNameInSystemDisplayAndSort)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsShowFullContactCardSupportedAsync,
+            IncludeMiddleNameInSystemDisplayAndSort,
+            SetIncludeMiddleNameInSystemDisplayAndSort,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactManagerStatics5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Text", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IContactMatchReason_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Field(this: *mut core::ffi::c_void, result__: *mut ContactMatchReasonKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactMatchReason", "Field", 6usize, |this| ((**(this as *const *const IContactMatchReason_Vtbl)).Field)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Data_Text", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn Segments(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactMatchReason", "Segments", 7usize, |this| ((**(this as *const *const IContactMatchReason_Vtbl)).Segments)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactMatchReason", "Text", 8usize, |this| ((**(this as *const *const IContactMatchReason_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Field,
+            #[cfg(all(feature = "Data_Text", feature = "Foundation_Collections"))]
+            Segments,
+            #[cfg(not(all(feature = "Data_Text", feature = "Foundation_Collections")))]
+            Segments: 0,
+            Text,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactMatchReason as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactName_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FirstName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "FirstName", 6usize, |this| ((**(this as *const *const IContactName_Vtbl)).FirstName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFirstName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "SetFirstName", 7usize, |this| ((**(this as *const *const IContactName_Vtbl)).SetFirstName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "LastName", 8usize, |this| ((**(this as *const *const IContactName_Vtbl)).LastName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "SetLastName", 9usize, |this| ((**(this as *const *const IContactName_Vtbl)).SetLastName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MiddleName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "MiddleName", 10usize, |this| ((**(this as *const *const IContactName_Vtbl)).MiddleName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMiddleName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "SetMiddleName", 11usize, |this| ((**(this as *const *const IContactName_Vtbl)).SetMiddleName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn YomiGivenName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "YomiGivenName", 12usize, |this| ((**(this as *const *const IContactName_Vtbl)).YomiGivenName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetYomiGivenName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "SetYomiGivenName", 13usize, |this| ((**(this as *const *const IContactName_Vtbl)).SetYomiGivenName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn YomiFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "YomiFamilyName", 14usize, |this| ((**(this as *const *const IContactName_Vtbl)).YomiFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetYomiFamilyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "SetYomiFamilyName", 15usize, |this| ((**(this as *const *const IContactName_Vtbl)).SetYomiFamilyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HonorificNameSuffix(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "HonorificNameSuffix", 16usize, |this| ((**(this as *const *const IContactName_Vtbl)).HonorificNameSuffix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHonorificNameSuffix(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "SetHonorificNameSuffix", 17usize, |this| ((**(this as *const *const IContactName_Vtbl)).SetHonorificNameSuffix)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HonorificNamePrefix(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "HonorificNamePrefix", 18usize, |this| ((**(this as *const *const IContactName_Vtbl)).HonorificNamePrefix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHonorificNamePrefix(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "SetHonorificNamePrefix", 19usize, |this| ((**(this as *const *const IContactName_Vtbl)).SetHonorificNamePrefix)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "DisplayName", 20usize, |this| ((**(this as *const *const IContactName_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn YomiDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactName", "YomiDisplayName", 21usize, |this| ((**(this as *const *const IContactName_Vtbl)).YomiDisplayName)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FirstName,
+            SetFirstName,
+            LastName,
+            SetLastName,
+            MiddleName,
+            SetMiddleName,
+            YomiGivenName,
+            SetYomiGivenName,
+            YomiFamilyName,
+            SetYomiFamilyName,
+            HonorificNameSuffix,
+            SetHonorificNameSuffix,
+            HonorificNamePrefix,
+            SetHonorificNamePrefix,
+            DisplayName,
+            YomiDisplayName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactName as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for IContactPanel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClosePanel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPanel", "ClosePanel", 6usize, |this| ((**(this as *const *const IContactPanel_Vtbl)).ClosePanel)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn HeaderColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPanel", "HeaderColor", 7usize, |this| ((**(this as *const *const IContactPanel_Vtbl)).HeaderColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetHeaderColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPanel", "SetHeaderColor", 8usize, |this| ((**(this as *const *const IContactPanel_Vtbl)).SetHeaderColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchFullAppRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPanel", "LaunchFullAppRequested", 9usize, |this| ((**(this as *const *const IContactPanel_Vtbl)).LaunchFullAppRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLaunchFullAppRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPanel", "RemoveLaunchFullAppRequested", 10usize, |this| ((**(this as *const *const IContactPanel_Vtbl)).RemoveLaunchFullAppRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Closing(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPanel", "Closing", 11usize, |this| ((**(this as *const *const IContactPanel_Vtbl)).Closing)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosing(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPanel", "RemoveClosing", 12usize, |this| ((**(this as *const *const IContactPanel_Vtbl)).RemoveClosing)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ClosePanel,
+            #[cfg(feature = "UI")]
+            HeaderColor,
+            #[cfg(not(feature = "UI"))]
+            HeaderColor: 0,
+            #[cfg(feature = "UI")]
+            SetHeaderColor,
+            #[cfg(not(feature = "UI"))]
+            SetHeaderColor: 0,
+            LaunchFullAppRequested,
+            RemoveLaunchFullAppRequested,
+            Closing,
+            RemoveClosing,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPanel as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactPanelClosingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPanelClosingEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const IContactPanelClosingEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPanelClosingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactPanelLaunchFullAppRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPanelLaunchFullAppRequestedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IContactPanelLaunchFullAppRequestedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPanelLaunchFullAppRequestedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IContactPanelLaunchFullAppRequestedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPanelLaunchFullAppRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactPhone_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Number(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPhone", "Number", 6usize, |this| ((**(this as *const *const IContactPhone_Vtbl)).Number)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumber(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPhone", "SetNumber", 7usize, |this| ((**(this as *const *const IContactPhone_Vtbl)).SetNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut ContactPhoneKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPhone", "Kind", 8usize, |this| ((**(this as *const *const IContactPhone_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKind(this: *mut core::ffi::c_void, value: ContactPhoneKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPhone", "SetKind", 9usize, |this| ((**(this as *const *const IContactPhone_Vtbl)).SetKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPhone", "Description", 10usize, |this| ((**(this as *const *const IContactPhone_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPhone", "SetDescription", 11usize, |this| ((**(this as *const *const IContactPhone_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Number,
+            SetNumber,
+            Kind,
+            SetKind,
+            Description,
+            SetDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPhone as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactPicker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CommitButtonText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPicker", "CommitButtonText", 6usize, |this| ((**(this as *const *const IContactPicker_Vtbl)).CommitButtonText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCommitButtonText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPicker", "SetCommitButtonText", 7usize, |this| ((**(this as *const *const IContactPicker_Vtbl)).SetCommitButtonText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectionMode(this: *mut core::ffi::c_void, result__: *mut ContactSelectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPicker", "SelectionMode", 8usize, |this| ((**(this as *const *const IContactPicker_Vtbl)).SelectionMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelectionMode(this: *mut core::ffi::c_void, value: ContactSelectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPicker", "SetSelectionMode", 9usize, |this| ((**(this as *const *const IContactPicker_Vtbl)).SetSelectionMode)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DesiredFields(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPicker", "DesiredFields", 10usize, |this| ((**(this as *const *const IContactPicker_Vtbl)).DesiredFields)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PickSingleContactAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPicker", "PickSingleContactAsync", 11usize, |this| ((**(this as *const *const IContactPicker_Vtbl)).PickSingleContactAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PickMultipleContactsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPicker", "PickMultipleContactsAsync", 12usize, |this| ((**(this as *const *const IContactPicker_Vtbl)).PickMultipleContactsAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CommitButtonText,
+            SetCommitButtonText,
+            SelectionMode,
+            SetSelectionMode,
+            #[cfg(feature = "Foundation_Collections")]
+            DesiredFields,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DesiredFields: 0,
+            PickSingleContactAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            PickMultipleContactsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PickMultipleContactsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPicker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactPicker2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DesiredFieldsWithContactFieldType(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPicker2", "DesiredFieldsWithContactFieldType", 6usize, |this| ((**(this as *const *const IContactPicker2_Vtbl)).DesiredFieldsWithContactFieldType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PickContactAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPicker2", "PickContactAsync", 7usize, |this| ((**(this as *const *const IContactPicker2_Vtbl)).PickContactAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PickContactsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPicker2", "PickContactsAsync", 8usize, |this| ((**(this as *const *const IContactPicker2_Vtbl)).PickContactsAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            DesiredFieldsWithContactFieldType,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DesiredFieldsWithContactFieldType: 0,
+            PickContactAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            PickContactsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PickContactsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPicker2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IContactPicker3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPicker3", "User", 6usize, |this| ((**(this as *const *const IContactPicker3_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPicker3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IContactPickerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn CreateForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPickerStatics", "CreateForUser", 6usize, |this| ((**(this as *const *const IContactPickerStatics_Vtbl)).CreateForUser)(this, user, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupportedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPickerStatics", "IsSupportedAsync", 7usize, |this| ((**(this as *const *const IContactPickerStatics_Vtbl)).IsSupportedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            CreateForUser,
+            #[cfg(not(feature = "System"))]
+            CreateForUser: 0,
+            IsSupportedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPickerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactQueryOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TextSearch(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryOptions", "TextSearch", 6usize, |this| ((**(this as *const *const IContactQueryOptions_Vtbl)).TextSearch)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ContactListIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryOptions", "ContactListIds", 7usize, |this| ((**(this as *const *const IContactQueryOptions_Vtbl)).ContactListIds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeContactsFromHiddenLists(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryOptions", "IncludeContactsFromHiddenLists", 8usize, |this| ((**(this as *const *const IContactQueryOptions_Vtbl)).IncludeContactsFromHiddenLists)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeContactsFromHiddenLists(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryOptions", "SetIncludeContactsFromHiddenLists", 9usize, |this| ((**(this as *const *const IContactQueryOptions_Vtbl)).SetIncludeContactsFromHiddenLists)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredFields(this: *mut core::ffi::c_void, result__: *mut ContactQueryDesiredFields) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryOptions", "DesiredFields", 10usize, |this| ((**(this as *const *const IContactQueryOptions_Vtbl)).DesiredFields)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredFields(this: *mut core::ffi::c_void, value: ContactQueryDesiredFields) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryOptions", "SetDesiredFields", 11usize, |this| ((**(this as *const *const IContactQueryOptions_Vtbl)).SetDesiredFields)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredOperations(this: *mut core::ffi::c_void, result__: *mut ContactAnnotationOperations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryOptions", "DesiredOperations", 12usize, |this| ((**(this as *const *const IContactQueryOptions_Vtbl)).DesiredOperations)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredOperations(this: *mut core::ffi::c_void, value: ContactAnnotationOperations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryOptions", "SetDesiredOperations", 13usize, |this| ((**(this as *const *const IContactQueryOptions_Vtbl)).SetDesiredOperations)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AnnotationListIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryOptions", "AnnotationListIds", 14usize, |this| ((**(this as *const *const IContactQueryOptions_Vtbl)).AnnotationListIds)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TextSearch,
+            #[cfg(feature = "Foundation_Collections")]
+            ContactListIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ContactListIds: 0,
+            IncludeContactsFromHiddenLists,
+            SetIncludeContactsFromHiddenLists,
+            DesiredFields,
+            SetDesiredFields,
+            DesiredOperations,
+            SetDesiredOperations,
+            #[cfg(feature = "Foundation_Collections")]
+            AnnotationListIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AnnotationListIds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactQueryOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactQueryOptionsFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithText(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryOptionsFactory", "CreateWithText", 6usize, |this| ((**(this as *const *const IContactQueryOptionsFactory_Vtbl)).CreateWithText)(this, text, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithTextAndFields(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, fields: ContactQuerySearchFields, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryOptionsFactory", "CreateWithTextAndFields", 7usize, |this| ((**(this as *const *const IContactQueryOptionsFactory_Vtbl)).CreateWithTextAndFields)(this, text, fields, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithText, CreateWithTextAndFields }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactQueryOptionsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactQueryTextSearch_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Fields(this: *mut core::ffi::c_void, result__: *mut ContactQuerySearchFields) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryTextSearch", "Fields", 6usize, |this| ((**(this as *const *const IContactQueryTextSearch_Vtbl)).Fields)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFields(this: *mut core::ffi::c_void, value: ContactQuerySearchFields) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryTextSearch", "SetFields", 7usize, |this| ((**(this as *const *const IContactQueryTextSearch_Vtbl)).SetFields)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryTextSearch", "Text", 8usize, |this| ((**(this as *const *const IContactQueryTextSearch_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryTextSearch", "SetText", 9usize, |this| ((**(this as *const *const IContactQueryTextSearch_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchScope(this: *mut core::ffi::c_void, result__: *mut ContactQuerySearchScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryTextSearch", "SearchScope", 10usize, |this| ((**(this as *const *const IContactQueryTextSearch_Vtbl)).SearchScope)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSearchScope(this: *mut core::ffi::c_void, value: ContactQuerySearchScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryTextSearch", "SetSearchScope", 11usize, |this| ((**(this as *const *const IContactQueryTextSearch_Vtbl)).SetSearchScope)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Fields,
+            SetFields,
+            Text,
+            SetText,
+            SearchScope,
+            SetSearchScope,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactQueryTextSearch as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadBatchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactReader", "ReadBatchAsync", 6usize, |this| ((**(this as *const *const IContactReader_Vtbl)).ReadBatchAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetMatchingPropertiesWithMatchReason(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactReader", "GetMatchingPropertiesWithMatchReason", 7usize, |this| ((**(this as *const *const IContactReader_Vtbl)).GetMatchingPropertiesWithMatchReason)(this, contact, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReadBatchAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetMatchingPropertiesWithMatchReason,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetMatchingPropertiesWithMatchReason: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactSignificantOther_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactSignificantOther", "Name", 6usize, |this| ((**(this as *const *const IContactSignificantOther_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactSignificantOther", "SetName", 7usize, |this| ((**(this as *const *const IContactSignificantOther_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactSignificantOther", "Description", 8usize, |this| ((**(this as *const *const IContactSignificantOther_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactSignificantOther", "SetDescription", 9usize, |this| ((**(this as *const *const IContactSignificantOther_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, SetName, Description, SetDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactSignificantOther as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactSignificantOther2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Relationship(this: *mut core::ffi::c_void, result__: *mut ContactRelationship) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactSignificantOther2", "Relationship", 6usize, |this| ((**(this as *const *const IContactSignificantOther2_Vtbl)).Relationship)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRelationship(this: *mut core::ffi::c_void, value: ContactRelationship) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactSignificantOther2", "SetRelationship", 7usize, |this| ((**(this as *const *const IContactSignificantOther2_Vtbl)).SetRelationship)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Relationship, SetRelationship }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactSignificantOther2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactStore_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindContactsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore", "FindContactsAsync", 6usize, |this| ((**(this as *const *const IContactStore_Vtbl)).FindContactsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindContactsWithSearchTextAsync(this: *mut core::ffi::c_void, searchtext: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore", "FindContactsWithSearchTextAsync", 7usize, |this| ((**(this as *const *const IContactStore_Vtbl)).FindContactsWithSearchTextAsync)(this, searchtext, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContactAsync(this: *mut core::ffi::c_void, contactid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore", "GetContactAsync", 8usize, |this| ((**(this as *const *const IContactStore_Vtbl)).GetContactAsync)(this, contactid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindContactsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindContactsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindContactsWithSearchTextAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindContactsWithSearchTextAsync: 0,
+            GetContactAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactStore2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeTracker(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore2", "ChangeTracker", 6usize, |this| ((**(this as *const *const IContactStore2_Vtbl)).ChangeTracker)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContactChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore2", "ContactChanged", 7usize, |this| ((**(this as *const *const IContactStore2_Vtbl)).ContactChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveContactChanged(this: *mut core::ffi::c_void, value: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore2", "RemoveContactChanged", 8usize, |this| ((**(this as *const *const IContactStore2_Vtbl)).RemoveContactChanged)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AggregateContactManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore2", "AggregateContactManager", 9usize, |this| ((**(this as *const *const IContactStore2_Vtbl)).AggregateContactManager)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindContactListsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore2", "FindContactListsAsync", 10usize, |this| ((**(this as *const *const IContactStore2_Vtbl)).FindContactListsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContactListAsync(this: *mut core::ffi::c_void, contactlistid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore2", "GetContactListAsync", 11usize, |this| ((**(this as *const *const IContactStore2_Vtbl)).GetContactListAsync)(this, contactlistid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateContactListAsync(this: *mut core::ffi::c_void, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore2", "CreateContactListAsync", 12usize, |this| ((**(this as *const *const IContactStore2_Vtbl)).CreateContactListAsync)(this, displayname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMeContactAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore2", "GetMeContactAsync", 13usize, |this| ((**(this as *const *const IContactStore2_Vtbl)).GetMeContactAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContactReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore2", "GetContactReader", 14usize, |this| ((**(this as *const *const IContactStore2_Vtbl)).GetContactReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContactReaderWithOptions(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore2", "GetContactReaderWithOptions", 15usize, |this| ((**(this as *const *const IContactStore2_Vtbl)).GetContactReaderWithOptions)(this, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateContactListInAccountAsync(this: *mut core::ffi::c_void, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, userdataaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore2", "CreateContactListInAccountAsync", 16usize, |this| ((**(this as *const *const IContactStore2_Vtbl)).CreateContactListInAccountAsync)(this, displayname, userdataaccountid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChangeTracker,
+            ContactChanged,
+            RemoveContactChanged,
+            AggregateContactManager,
+            #[cfg(feature = "Foundation_Collections")]
+            FindContactListsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindContactListsAsync: 0,
+            GetContactListAsync,
+            CreateContactListAsync,
+            GetMeContactAsync,
+            GetContactReader,
+            GetContactReaderWithOptions,
+            CreateContactListInAccountAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactStore2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactStore3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetChangeTracker(this: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore3", "GetChangeTracker", 6usize, |this| ((**(this as *const *const IContactStore3_Vtbl)).GetChangeTracker)(this, identity, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetChangeTracker }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactStore3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactStoreNotificationTriggerDetails_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactStoreNotificationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactWebsite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactWebsite", "Uri", 6usize, |this| ((**(this as *const *const IContactWebsite_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactWebsite", "SetUri", 7usize, |this| ((**(this as *const *const IContactWebsite_Vtbl)).SetUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactWebsite", "Description", 8usize, |this| ((**(this as *const *const IContactWebsite_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactWebsite", "SetDescription", 9usize, |this| ((**(this as *const *const IContactWebsite_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uri, SetUri, Description, SetDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactWebsite as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactWebsite2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RawValue(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactWebsite2", "RawValue", 6usize, |this| ((**(this as *const *const IContactWebsite2_Vtbl)).RawValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRawValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactWebsite2", "SetRawValue", 7usize, |this| ((**(this as *const *const IContactWebsite2_Vtbl)).SetRawValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RawValue, SetRawValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactWebsite2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_ViewManagement")]
+impl windows_core::imp::ProxyVtable for IFullContactCardOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_ViewManagement")]
+        unsafe extern "system" fn DesiredRemainingView(this: *mut core::ffi::c_void, result__: *mut super::super::UI::ViewManagement::ViewSizePreference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullContactCardOptions", "DesiredRemainingView", 6usize, |this| ((**(this as *const *const IFullContactCardOptions_Vtbl)).DesiredRemainingView)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_ViewManagement")]
+        unsafe extern "system" fn SetDesiredRemainingView(this: *mut core::ffi::c_void, value: super::super::UI::ViewManagement::ViewSizePreference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullContactCardOptions", "SetDesiredRemainingView", 7usize, |this| ((**(this as *const *const IFullContactCardOptions_Vtbl)).SetDesiredRemainingView)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_ViewManagement")]
+            DesiredRemainingView,
+            #[cfg(not(feature = "UI_ViewManagement"))]
+            DesiredRemainingView: 0,
+            #[cfg(feature = "UI_ViewManagement")]
+            SetDesiredRemainingView,
+            #[cfg(not(feature = "UI_ViewManagement"))]
+            SetDesiredRemainingView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFullContactCardOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IKnownContactFieldStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Email(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactFieldStatics", "Email", 6usize, |this| ((**(this as *const *const IKnownContactFieldStatics_Vtbl)).Email)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PhoneNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactFieldStatics", "PhoneNumber", 7usize, |this| ((**(this as *const *const IKnownContactFieldStatics_Vtbl)).PhoneNumber)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Location(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactFieldStatics", "Location", 8usize, |this| ((**(this as *const *const IKnownContactFieldStatics_Vtbl)).Location)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn InstantMessage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactFieldStatics", "InstantMessage", 9usize, |this| ((**(this as *const *const IKnownContactFieldStatics_Vtbl)).InstantMessage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ConvertNameToType(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut ContactFieldType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactFieldStatics", "ConvertNameToType", 10usize, |this| ((**(this as *const *const IKnownContactFieldStatics_Vtbl)).ConvertNameToType)(this, name, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ConvertTypeToName(this: *mut core::ffi::c_void, r#type: ContactFieldType, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactFieldStatics", "ConvertTypeToName", 11usize, |this| ((**(this as *const *const IKnownContactFieldStatics_Vtbl)).ConvertTypeToName)(this, r#type, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Email,
+            #[cfg(not(feature = "deprecated"))]
+            Email: 0,
+            #[cfg(feature = "deprecated")]
+            PhoneNumber,
+            #[cfg(not(feature = "deprecated"))]
+            PhoneNumber: 0,
+            #[cfg(feature = "deprecated")]
+            Location,
+            #[cfg(not(feature = "deprecated"))]
+            Location: 0,
+            #[cfg(feature = "deprecated")]
+            InstantMessage,
+            #[cfg(not(feature = "deprecated"))]
+            InstantMessage: 0,
+            #[cfg(feature = "deprecated")]
+            ConvertNameToType,
+            #[cfg(not(feature = "deprecated"))]
+            ConvertNameToType: 0,
+            #[cfg(feature = "deprecated")]
+            ConvertTypeToName,
+            #[cfg(not(feature = "deprecated"))]
+            ConvertTypeToName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownContactFieldStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPinnedContactIdsQueryResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ContactIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinnedContactIdsQueryResult", "ContactIds", 6usize, |this| ((**(this as *const *const IPinnedContactIdsQueryResult_Vtbl)).ContactIds)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ContactIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ContactIds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPinnedContactIdsQueryResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IPinnedContactManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinnedContactManager", "User", 6usize, |this| ((**(this as *const *const IPinnedContactManager_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPinSurfaceSupported(this: *mut core::ffi::c_void, surface: PinnedContactSurface, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinnedContactManager", "IsPinSurfaceSupported", 7usize, |this| ((**(this as *const *const IPinnedContactManager_Vtbl)).IsPinSurfaceSupported)(this, surface, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsContactPinned(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, surface: PinnedContactSurface, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinnedContactManager", "IsContactPinned", 8usize, |this| ((**(this as *const *const IPinnedContactManager_Vtbl)).IsContactPinned)(this, contact, surface, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPinContactAsync(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, surface: PinnedContactSurface, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinnedContactManager", "RequestPinContactAsync", 9usize, |this| ((**(this as *const *const IPinnedContactManager_Vtbl)).RequestPinContactAsync)(this, contact, surface, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestPinContactsAsync(this: *mut core::ffi::c_void, contacts: *mut core::ffi::c_void, surface: PinnedContactSurface, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinnedContactManager", "RequestPinContactsAsync", 10usize, |this| ((**(this as *const *const IPinnedContactManager_Vtbl)).RequestPinContactsAsync)(this, contacts, surface, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestUnpinContactAsync(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, surface: PinnedContactSurface, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinnedContactManager", "RequestUnpinContactAsync", 11usize, |this| ((**(this as *const *const IPinnedContactManager_Vtbl)).RequestUnpinContactAsync)(this, contact, surface, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalContactActivity(this: *mut core::ffi::c_void, contact: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinnedContactManager", "SignalContactActivity", 12usize, |this| ((**(this as *const *const IPinnedContactManager_Vtbl)).SignalContactActivity)(this, contact), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPinnedContactIdsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinnedContactManager", "GetPinnedContactIdsAsync", 13usize, |this| ((**(this as *const *const IPinnedContactManager_Vtbl)).GetPinnedContactIdsAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+            IsPinSurfaceSupported,
+            IsContactPinned,
+            RequestPinContactAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestPinContactsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestPinContactsAsync: 0,
+            RequestUnpinContactAsync,
+            SignalContactActivity,
+            GetPinnedContactIdsAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPinnedContactManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IPinnedContactManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinnedContactManagerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IPinnedContactManagerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinnedContactManagerStatics", "GetForUser", 7usize, |this| ((**(this as *const *const IPinnedContactManagerStatics_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinnedContactManagerStatics", "IsSupported", 8usize, |this| ((**(this as *const *const IPinnedContactManagerStatics_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDefault,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+            IsSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPinnedContactManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
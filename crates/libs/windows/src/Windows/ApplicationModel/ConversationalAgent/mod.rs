@@ -1471,6 +1471,7 @@ impl ActivationSignalDetectionConfigurationCreationStatus {
 impl windows_core::TypeKind for ActivationSignalDetectionConfigurationCreationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ActivationSignalDetectionConfigurationCreationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ActivationSignalDetectionConfigurationCreationStatus").field(&self.0).finish()
@@ -1491,6 +1492,7 @@ impl ActivationSignalDetectionConfigurationRemovalResult {
 impl windows_core::TypeKind for ActivationSignalDetectionConfigurationRemovalResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ActivationSignalDetectionConfigurationRemovalResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ActivationSignalDetectionConfigurationRemovalResult").field(&self.0).finish()
@@ -1515,6 +1517,7 @@ impl ActivationSignalDetectionConfigurationSetModelDataResult {
 impl windows_core::TypeKind for ActivationSignalDetectionConfigurationSetModelDataResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ActivationSignalDetectionConfigurationSetModelDataResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ActivationSignalDetectionConfigurationSetModelDataResult").field(&self.0).finish()
@@ -1534,6 +1537,7 @@ impl ActivationSignalDetectionConfigurationStateChangeResult {
 impl windows_core::TypeKind for ActivationSignalDetectionConfigurationStateChangeResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ActivationSignalDetectionConfigurationStateChangeResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ActivationSignalDetectionConfigurationStateChangeResult").field(&self.0).finish()
@@ -1561,6 +1565,7 @@ impl ActivationSignalDetectionTrainingDataFormat {
 impl windows_core::TypeKind for ActivationSignalDetectionTrainingDataFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ActivationSignalDetectionTrainingDataFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ActivationSignalDetectionTrainingDataFormat").field(&self.0).finish()
@@ -1580,6 +1585,7 @@ impl ActivationSignalDetectorKind {
 impl windows_core::TypeKind for ActivationSignalDetectorKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ActivationSignalDetectorKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ActivationSignalDetectorKind").field(&self.0).finish()
@@ -1599,6 +1605,7 @@ impl ActivationSignalDetectorPowerState {
 impl windows_core::TypeKind for ActivationSignalDetectorPowerState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ActivationSignalDetectorPowerState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ActivationSignalDetectorPowerState").field(&self.0).finish()
@@ -1617,6 +1624,7 @@ impl ConversationalAgentActivationKind {
 impl windows_core::TypeKind for ConversationalAgentActivationKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ConversationalAgentActivationKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ConversationalAgentActivationKind").field(&self.0).finish()
@@ -1637,6 +1645,7 @@ impl ConversationalAgentActivationResult {
 impl windows_core::TypeKind for ConversationalAgentActivationResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ConversationalAgentActivationResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ConversationalAgentActivationResult").field(&self.0).finish()
@@ -1655,6 +1664,7 @@ impl ConversationalAgentSessionUpdateResponse {
 impl windows_core::TypeKind for ConversationalAgentSessionUpdateResponse {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ConversationalAgentSessionUpdateResponse {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ConversationalAgentSessionUpdateResponse").field(&self.0).finish()
@@ -1677,6 +1687,7 @@ impl ConversationalAgentState {
 impl windows_core::TypeKind for ConversationalAgentState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ConversationalAgentState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ConversationalAgentState").field(&self.0).finish()
@@ -1697,6 +1708,7 @@ impl ConversationalAgentSystemStateChangeType {
 impl windows_core::TypeKind for ConversationalAgentSystemStateChangeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ConversationalAgentSystemStateChangeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ConversationalAgentSystemStateChangeType").field(&self.0).finish()
@@ -1719,6 +1731,7 @@ impl ConversationalAgentVoiceActivationPrerequisiteKind {
 impl windows_core::TypeKind for ConversationalAgentVoiceActivationPrerequisiteKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ConversationalAgentVoiceActivationPrerequisiteKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ConversationalAgentVoiceActivationPrerequisiteKind").field(&self.0).finish()
@@ -1738,6 +1751,7 @@ impl DetectionConfigurationAvailabilityChangeKind {
 impl windows_core::TypeKind for DetectionConfigurationAvailabilityChangeKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DetectionConfigurationAvailabilityChangeKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DetectionConfigurationAvailabilityChangeKind").field(&self.0).finish()
@@ -1764,6 +1778,7 @@ impl DetectionConfigurationTrainingStatus {
 impl windows_core::TypeKind for DetectionConfigurationTrainingStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DetectionConfigurationTrainingStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DetectionConfigurationTrainingStatus").field(&self.0).finish()
@@ -1794,6 +1809,7 @@ impl SignalDetectorResourceKind {
 impl windows_core::TypeKind for SignalDetectorResourceKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SignalDetectorResourceKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SignalDetectorResourceKind").field(&self.0).finish()
@@ -1802,3 +1818,5 @@ impl core::fmt::Debug for SignalDetectorResourceKind {
 impl windows_core::RuntimeType for SignalDetectorResourceKind {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.ConversationalAgent.SignalDetectorResourceKind;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,796 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IActivationSignalDetectionConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SignalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "SignalId", 6usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).SignalId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModelId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "ModelId", 7usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).ModelId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "DisplayName", 8usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsActive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "IsActive", 9usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).IsActive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "SetEnabled", 10usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).SetEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabledAsync(this: *mut core::ffi::c_void, value: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "SetEnabledAsync", 11usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).SetEnabledAsync)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvailabilityInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "AvailabilityInfo", 12usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).AvailabilityInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvailabilityChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "AvailabilityChanged", 13usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).AvailabilityChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAvailabilityChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "RemoveAvailabilityChanged", 14usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).RemoveAvailabilityChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetModelData(this: *mut core::ffi::c_void, datatype: core::mem::MaybeUninit<windows_core::HSTRING>, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "SetModelData", 15usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).SetModelData)(this, datatype, data), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetModelDataAsync(this: *mut core::ffi::c_void, datatype: core::mem::MaybeUninit<windows_core::HSTRING>, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "SetModelDataAsync", 16usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).SetModelDataAsync)(this, datatype, data, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModelDataType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "GetModelDataType", 17usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).GetModelDataType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModelDataTypeAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "GetModelDataTypeAsync", 18usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).GetModelDataTypeAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetModelData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "GetModelData", 19usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).GetModelData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetModelDataAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "GetModelDataAsync", 20usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).GetModelDataAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearModelData(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "ClearModelData", 21usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).ClearModelData)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearModelDataAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "ClearModelDataAsync", 22usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).ClearModelDataAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrainingStepsCompleted(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "TrainingStepsCompleted", 23usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).TrainingStepsCompleted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrainingStepsRemaining(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "TrainingStepsRemaining", 24usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).TrainingStepsRemaining)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrainingDataFormat(this: *mut core::ffi::c_void, result__: *mut ActivationSignalDetectionTrainingDataFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "TrainingDataFormat", 25usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).TrainingDataFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ApplyTrainingData(this: *mut core::ffi::c_void, trainingdataformat: ActivationSignalDetectionTrainingDataFormat, trainingdata: *mut core::ffi::c_void, result__: *mut DetectionConfigurationTrainingStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "ApplyTrainingData", 26usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).ApplyTrainingData)(this, trainingdataformat, trainingdata, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ApplyTrainingDataAsync(this: *mut core::ffi::c_void, trainingdataformat: ActivationSignalDetectionTrainingDataFormat, trainingdata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "ApplyTrainingDataAsync", 27usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).ApplyTrainingDataAsync)(this, trainingdataformat, trainingdata, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearTrainingData(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "ClearTrainingData", 28usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).ClearTrainingData)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearTrainingDataAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration", "ClearTrainingDataAsync", 29usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration_Vtbl)).ClearTrainingDataAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SignalId,
+            ModelId,
+            DisplayName,
+            IsActive,
+            SetEnabled,
+            SetEnabledAsync,
+            AvailabilityInfo,
+            AvailabilityChanged,
+            RemoveAvailabilityChanged,
+            #[cfg(feature = "Storage_Streams")]
+            SetModelData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetModelData: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetModelDataAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetModelDataAsync: 0,
+            GetModelDataType,
+            GetModelDataTypeAsync,
+            #[cfg(feature = "Storage_Streams")]
+            GetModelData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetModelData: 0,
+            #[cfg(feature = "Storage_Streams")]
+            GetModelDataAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetModelDataAsync: 0,
+            ClearModelData,
+            ClearModelDataAsync,
+            TrainingStepsCompleted,
+            TrainingStepsRemaining,
+            TrainingDataFormat,
+            #[cfg(feature = "Storage_Streams")]
+            ApplyTrainingData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ApplyTrainingData: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ApplyTrainingDataAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ApplyTrainingDataAsync: 0,
+            ClearTrainingData,
+            ClearTrainingDataAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivationSignalDetectionConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IActivationSignalDetectionConfiguration2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetModelDataWithResult(this: *mut core::ffi::c_void, datatype: core::mem::MaybeUninit<windows_core::HSTRING>, data: *mut core::ffi::c_void, result__: *mut ActivationSignalDetectionConfigurationSetModelDataResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration2", "SetModelDataWithResult", 6usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration2_Vtbl)).SetModelDataWithResult)(this, datatype, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetModelDataWithResultAsync(this: *mut core::ffi::c_void, datatype: core::mem::MaybeUninit<windows_core::HSTRING>, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration2", "SetModelDataWithResultAsync", 7usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration2_Vtbl)).SetModelDataWithResultAsync)(this, datatype, data, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabledWithResultAsync(this: *mut core::ffi::c_void, value: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration2", "SetEnabledWithResultAsync", 8usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration2_Vtbl)).SetEnabledWithResultAsync)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabledWithResult(this: *mut core::ffi::c_void, value: bool, result__: *mut ActivationSignalDetectionConfigurationStateChangeResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration2", "SetEnabledWithResult", 9usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration2_Vtbl)).SetEnabledWithResult)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrainingStepCompletionMaxAllowedTime(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfiguration2", "TrainingStepCompletionMaxAllowedTime", 10usize, |this| ((**(this as *const *const IActivationSignalDetectionConfiguration2_Vtbl)).TrainingStepCompletionMaxAllowedTime)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            SetModelDataWithResult,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetModelDataWithResult: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetModelDataWithResultAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetModelDataWithResultAsync: 0,
+            SetEnabledWithResultAsync,
+            SetEnabledWithResult,
+            TrainingStepCompletionMaxAllowedTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivationSignalDetectionConfiguration2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActivationSignalDetectionConfigurationCreationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut ActivationSignalDetectionConfigurationCreationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfigurationCreationResult", "Status", 6usize, |this| ((**(this as *const *const IActivationSignalDetectionConfigurationCreationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetectionConfigurationCreationResult", "Configuration", 7usize, |this| ((**(this as *const *const IActivationSignalDetectionConfigurationCreationResult_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Configuration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivationSignalDetectionConfigurationCreationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IActivationSignalDetector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "ProviderId", 6usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).ProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut ActivationSignalDetectorKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "Kind", 7usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanCreateConfigurations(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "CanCreateConfigurations", 8usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).CanCreateConfigurations)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedModelDataTypes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "SupportedModelDataTypes", 9usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).SupportedModelDataTypes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedTrainingDataFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "SupportedTrainingDataFormats", 10usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).SupportedTrainingDataFormats)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedPowerStates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "SupportedPowerStates", 11usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).SupportedPowerStates)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSupportedModelIdsForSignalId(this: *mut core::ffi::c_void, signalid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "GetSupportedModelIdsForSignalId", 12usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).GetSupportedModelIdsForSignalId)(this, signalid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSupportedModelIdsForSignalIdAsync(this: *mut core::ffi::c_void, signalid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "GetSupportedModelIdsForSignalIdAsync", 13usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).GetSupportedModelIdsForSignalIdAsync)(this, signalid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateConfiguration(this: *mut core::ffi::c_void, signalid: core::mem::MaybeUninit<windows_core::HSTRING>, modelid: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "CreateConfiguration", 14usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).CreateConfiguration)(this, signalid, modelid, displayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateConfigurationAsync(this: *mut core::ffi::c_void, signalid: core::mem::MaybeUninit<windows_core::HSTRING>, modelid: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "CreateConfigurationAsync", 15usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).CreateConfigurationAsync)(this, signalid, modelid, displayname, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetConfigurations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "GetConfigurations", 16usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).GetConfigurations)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetConfigurationsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "GetConfigurationsAsync", 17usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).GetConfigurationsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConfiguration(this: *mut core::ffi::c_void, signalid: core::mem::MaybeUninit<windows_core::HSTRING>, modelid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "GetConfiguration", 18usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).GetConfiguration)(this, signalid, modelid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConfigurationAsync(this: *mut core::ffi::c_void, signalid: core::mem::MaybeUninit<windows_core::HSTRING>, modelid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "GetConfigurationAsync", 19usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).GetConfigurationAsync)(this, signalid, modelid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConfiguration(this: *mut core::ffi::c_void, signalid: core::mem::MaybeUninit<windows_core::HSTRING>, modelid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "RemoveConfiguration", 20usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).RemoveConfiguration)(this, signalid, modelid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConfigurationAsync(this: *mut core::ffi::c_void, signalid: core::mem::MaybeUninit<windows_core::HSTRING>, modelid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector", "RemoveConfigurationAsync", 21usize, |this| ((**(this as *const *const IActivationSignalDetector_Vtbl)).RemoveConfigurationAsync)(this, signalid, modelid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProviderId,
+            Kind,
+            CanCreateConfigurations,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedModelDataTypes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedModelDataTypes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedTrainingDataFormats,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedTrainingDataFormats: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedPowerStates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedPowerStates: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSupportedModelIdsForSignalId,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSupportedModelIdsForSignalId: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSupportedModelIdsForSignalIdAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSupportedModelIdsForSignalIdAsync: 0,
+            CreateConfiguration,
+            CreateConfigurationAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetConfigurations,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetConfigurations: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetConfigurationsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetConfigurationsAsync: 0,
+            GetConfiguration,
+            GetConfigurationAsync,
+            RemoveConfiguration,
+            RemoveConfigurationAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivationSignalDetector as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IActivationSignalDetector2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAvailableModelIdsForSignalIdAsync(this: *mut core::ffi::c_void, signalid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector2", "GetAvailableModelIdsForSignalIdAsync", 6usize, |this| ((**(this as *const *const IActivationSignalDetector2_Vtbl)).GetAvailableModelIdsForSignalIdAsync)(this, signalid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAvailableModelIdsForSignalId(this: *mut core::ffi::c_void, signalid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector2", "GetAvailableModelIdsForSignalId", 7usize, |this| ((**(this as *const *const IActivationSignalDetector2_Vtbl)).GetAvailableModelIdsForSignalId)(this, signalid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateConfigurationWithResultAsync(this: *mut core::ffi::c_void, signalid: core::mem::MaybeUninit<windows_core::HSTRING>, modelid: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector2", "CreateConfigurationWithResultAsync", 8usize, |this| ((**(this as *const *const IActivationSignalDetector2_Vtbl)).CreateConfigurationWithResultAsync)(this, signalid, modelid, displayname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateConfigurationWithResult(this: *mut core::ffi::c_void, signalid: core::mem::MaybeUninit<windows_core::HSTRING>, modelid: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector2", "CreateConfigurationWithResult", 9usize, |this| ((**(this as *const *const IActivationSignalDetector2_Vtbl)).CreateConfigurationWithResult)(this, signalid, modelid, displayname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConfigurationWithResultAsync(this: *mut core::ffi::c_void, signalid: core::mem::MaybeUninit<windows_core::HSTRING>, modelid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector2", "RemoveConfigurationWithResultAsync", 10usize, |this| ((**(this as *const *const IActivationSignalDetector2_Vtbl)).RemoveConfigurationWithResultAsync)(this, signalid, modelid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConfigurationWithResult(this: *mut core::ffi::c_void, signalid: core::mem::MaybeUninit<windows_core::HSTRING>, modelid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut ActivationSignalDetectionConfigurationRemovalResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector2", "RemoveConfigurationWithResult", 11usize, |this| ((**(this as *const *const IActivationSignalDetector2_Vtbl)).RemoveConfigurationWithResult)(this, signalid, modelid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetectorId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationSignalDetector2", "DetectorId", 12usize, |this| ((**(this as *const *const IActivationSignalDetector2_Vtbl)).DetectorId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAvailableModelIdsForSignalIdAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAvailableModelIdsForSignalIdAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAvailableModelIdsForSignalId,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAvailableModelIdsForSignalId: 0,
+            CreateConfigurationWithResultAsync,
+            CreateConfigurationWithResult,
+            RemoveConfigurationWithResultAsync,
+            RemoveConfigurationWithResult,
+            DetectorId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivationSignalDetector2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IConversationalAgentDetectorManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAllActivationSignalDetectors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentDetectorManager", "GetAllActivationSignalDetectors", 6usize, |this| ((**(this as *const *const IConversationalAgentDetectorManager_Vtbl)).GetAllActivationSignalDetectors)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAllActivationSignalDetectorsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentDetectorManager", "GetAllActivationSignalDetectorsAsync", 7usize, |this| ((**(this as *const *const IConversationalAgentDetectorManager_Vtbl)).GetAllActivationSignalDetectorsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetActivationSignalDetectors(this: *mut core::ffi::c_void, kind: ActivationSignalDetectorKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentDetectorManager", "GetActivationSignalDetectors", 8usize, |this| ((**(this as *const *const IConversationalAgentDetectorManager_Vtbl)).GetActivationSignalDetectors)(this, kind, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetActivationSignalDetectorsAsync(this: *mut core::ffi::c_void, kind: ActivationSignalDetectorKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentDetectorManager", "GetActivationSignalDetectorsAsync", 9usize, |this| ((**(this as *const *const IConversationalAgentDetectorManager_Vtbl)).GetActivationSignalDetectorsAsync)(this, kind, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAllActivationSignalDetectors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAllActivationSignalDetectors: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAllActivationSignalDetectorsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAllActivationSignalDetectorsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetActivationSignalDetectors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetActivationSignalDetectors: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetActivationSignalDetectorsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetActivationSignalDetectorsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConversationalAgentDetectorManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConversationalAgentDetectorManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetActivationSignalDetectorFromId(this: *mut core::ffi::c_void, detectorid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentDetectorManager2", "GetActivationSignalDetectorFromId", 6usize, |this| ((**(this as *const *const IConversationalAgentDetectorManager2_Vtbl)).GetActivationSignalDetectorFromId)(this, detectorid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActivationSignalDetectorFromIdAsync(this: *mut core::ffi::c_void, detectorid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentDetectorManager2", "GetActivationSignalDetectorFromIdAsync", 7usize, |this| ((**(this as *const *const IConversationalAgentDetectorManager2_Vtbl)).GetActivationSignalDetectorFromIdAsync)(this, detectorid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetActivationSignalDetectorFromId,
+            GetActivationSignalDetectorFromIdAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConversationalAgentDetectorManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConversationalAgentDetectorManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Default(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentDetectorManagerStatics", "Default", 6usize, |this| ((**(this as *const *const IConversationalAgentDetectorManagerStatics_Vtbl)).Default)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Default }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConversationalAgentDetectorManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Audio"))]
+impl windows_core::imp::ProxyVtable for IConversationalAgentSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SessionInterrupted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "SessionInterrupted", 6usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).SessionInterrupted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSessionInterrupted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "RemoveSessionInterrupted", 7usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).RemoveSessionInterrupted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalDetected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "SignalDetected", 8usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).SignalDetected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSignalDetected(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "RemoveSignalDetected", 9usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).RemoveSignalDetected)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "SystemStateChanged", 10usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).SystemStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSystemStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "RemoveSystemStateChanged", 11usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).RemoveSystemStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AgentState(this: *mut core::ffi::c_void, result__: *mut ConversationalAgentState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "AgentState", 12usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).AgentState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Signal(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "Signal", 13usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).Signal)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIndicatorLightAvailable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "IsIndicatorLightAvailable", 14usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).IsIndicatorLightAvailable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsScreenAvailable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "IsScreenAvailable", 15usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).IsScreenAvailable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUserAuthenticated(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "IsUserAuthenticated", 16usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).IsUserAuthenticated)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVoiceActivationAvailable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "IsVoiceActivationAvailable", 17usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).IsVoiceActivationAvailable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInterruptible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "IsInterruptible", 18usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).IsInterruptible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInterrupted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "IsInterrupted", 19usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).IsInterrupted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestInterruptibleAsync(this: *mut core::ffi::c_void, interruptible: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "RequestInterruptibleAsync", 20usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).RequestInterruptibleAsync)(this, interruptible, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestInterruptible(this: *mut core::ffi::c_void, interruptible: bool, result__: *mut ConversationalAgentSessionUpdateResponse) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "RequestInterruptible", 21usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).RequestInterruptible)(this, interruptible, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAgentStateChangeAsync(this: *mut core::ffi::c_void, state: ConversationalAgentState, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "RequestAgentStateChangeAsync", 22usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).RequestAgentStateChangeAsync)(this, state, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAgentStateChange(this: *mut core::ffi::c_void, state: ConversationalAgentState, result__: *mut ConversationalAgentSessionUpdateResponse) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "RequestAgentStateChange", 23usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).RequestAgentStateChange)(this, state, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestForegroundActivationAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "RequestForegroundActivationAsync", 24usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).RequestForegroundActivationAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestForegroundActivation(this: *mut core::ffi::c_void, result__: *mut ConversationalAgentSessionUpdateResponse) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "RequestForegroundActivation", 25usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).RequestForegroundActivation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioClientAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "GetAudioClientAsync", 26usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).GetAudioClientAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioClient(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "GetAudioClient", 27usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).GetAudioClient)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Audio")]
+        unsafe extern "system" fn CreateAudioDeviceInputNodeAsync(this: *mut core::ffi::c_void, graph: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "CreateAudioDeviceInputNodeAsync", 28usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).CreateAudioDeviceInputNodeAsync)(this, graph, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Audio")]
+        unsafe extern "system" fn CreateAudioDeviceInputNode(this: *mut core::ffi::c_void, graph: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "CreateAudioDeviceInputNode", 29usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).CreateAudioDeviceInputNode)(this, graph, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioCaptureDeviceIdAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "GetAudioCaptureDeviceIdAsync", 30usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).GetAudioCaptureDeviceIdAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioCaptureDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "GetAudioCaptureDeviceId", 31usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).GetAudioCaptureDeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioRenderDeviceIdAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "GetAudioRenderDeviceIdAsync", 32usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).GetAudioRenderDeviceIdAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioRenderDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "GetAudioRenderDeviceId", 33usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).GetAudioRenderDeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignalModelIdAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "GetSignalModelIdAsync", 34usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).GetSignalModelIdAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignalModelId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "GetSignalModelId", 35usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).GetSignalModelId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignalModelIdAsync(this: *mut core::ffi::c_void, signalmodelid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "SetSignalModelIdAsync", 36usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).SetSignalModelIdAsync)(this, signalmodelid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignalModelId(this: *mut core::ffi::c_void, signalmodelid: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "SetSignalModelId", 37usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).SetSignalModelId)(this, signalmodelid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSupportedSignalModelIdsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "GetSupportedSignalModelIdsAsync", 38usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).GetSupportedSignalModelIdsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSupportedSignalModelIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession", "GetSupportedSignalModelIds", 39usize, |this| ((**(this as *const *const IConversationalAgentSession_Vtbl)).GetSupportedSignalModelIds)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SessionInterrupted,
+            RemoveSessionInterrupted,
+            SignalDetected,
+            RemoveSignalDetected,
+            SystemStateChanged,
+            RemoveSystemStateChanged,
+            AgentState,
+            Signal,
+            IsIndicatorLightAvailable,
+            IsScreenAvailable,
+            IsUserAuthenticated,
+            IsVoiceActivationAvailable,
+            IsInterruptible,
+            IsInterrupted,
+            RequestInterruptibleAsync,
+            RequestInterruptible,
+            RequestAgentStateChangeAsync,
+            RequestAgentStateChange,
+            RequestForegroundActivationAsync,
+            RequestForegroundActivation,
+            GetAudioClientAsync,
+            GetAudioClient,
+            #[cfg(feature = "Media_Audio")]
+            CreateAudioDeviceInputNodeAsync,
+            #[cfg(not(feature = "Media_Audio"))]
+            CreateAudioDeviceInputNodeAsync: 0,
+            #[cfg(feature = "Media_Audio")]
+            CreateAudioDeviceInputNode,
+            #[cfg(not(feature = "Media_Audio"))]
+            CreateAudioDeviceInputNode: 0,
+            GetAudioCaptureDeviceIdAsync,
+            GetAudioCaptureDeviceId,
+            GetAudioRenderDeviceIdAsync,
+            GetAudioRenderDeviceId,
+            GetSignalModelIdAsync,
+            GetSignalModelId,
+            SetSignalModelIdAsync,
+            SetSignalModelId,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSupportedSignalModelIdsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSupportedSignalModelIdsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSupportedSignalModelIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSupportedSignalModelIds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConversationalAgentSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IConversationalAgentSession2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestActivationAsync(this: *mut core::ffi::c_void, activationkind: ConversationalAgentActivationKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession2", "RequestActivationAsync", 6usize, |this| ((**(this as *const *const IConversationalAgentSession2_Vtbl)).RequestActivationAsync)(this, activationkind, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestActivation(this: *mut core::ffi::c_void, activationkind: ConversationalAgentActivationKind, result__: *mut ConversationalAgentActivationResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession2", "RequestActivation", 7usize, |this| ((**(this as *const *const IConversationalAgentSession2_Vtbl)).RequestActivation)(this, activationkind, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportLockScreenActivationAsync(this: *mut core::ffi::c_void, lockscreenactivationsupported: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession2", "SetSupportLockScreenActivationAsync", 8usize, |this| ((**(this as *const *const IConversationalAgentSession2_Vtbl)).SetSupportLockScreenActivationAsync)(this, lockscreenactivationsupported, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportLockScreenActivation(this: *mut core::ffi::c_void, lockscreenactivationsupported: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession2", "SetSupportLockScreenActivation", 9usize, |this| ((**(this as *const *const IConversationalAgentSession2_Vtbl)).SetSupportLockScreenActivation)(this, lockscreenactivationsupported), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetMissingPrerequisites(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession2", "GetMissingPrerequisites", 10usize, |this| ((**(this as *const *const IConversationalAgentSession2_Vtbl)).GetMissingPrerequisites)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetMissingPrerequisitesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSession2", "GetMissingPrerequisitesAsync", 11usize, |this| ((**(this as *const *const IConversationalAgentSession2_Vtbl)).GetMissingPrerequisitesAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestActivationAsync,
+            RequestActivation,
+            SetSupportLockScreenActivationAsync,
+            SetSupportLockScreenActivation,
+            #[cfg(feature = "Foundation_Collections")]
+            GetMissingPrerequisites,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetMissingPrerequisites: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetMissingPrerequisitesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetMissingPrerequisitesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConversationalAgentSession2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConversationalAgentSessionInterruptedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConversationalAgentSessionInterruptedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConversationalAgentSessionStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentSessionAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSessionStatics", "GetCurrentSessionAsync", 6usize, |this| ((**(this as *const *const IConversationalAgentSessionStatics_Vtbl)).GetCurrentSessionAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentSessionSync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSessionStatics", "GetCurrentSessionSync", 7usize, |this| ((**(this as *const *const IConversationalAgentSessionStatics_Vtbl)).GetCurrentSessionSync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrentSessionAsync, GetCurrentSessionSync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConversationalAgentSessionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConversationalAgentSignal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSignalVerificationRequired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSignal", "IsSignalVerificationRequired", 6usize, |this| ((**(this as *const *const IConversationalAgentSignal_Vtbl)).IsSignalVerificationRequired)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsSignalVerificationRequired(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSignal", "SetIsSignalVerificationRequired", 7usize, |this| ((**(this as *const *const IConversationalAgentSignal_Vtbl)).SetIsSignalVerificationRequired)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSignal", "SignalId", 8usize, |this| ((**(this as *const *const IConversationalAgentSignal_Vtbl)).SignalId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignalId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSignal", "SetSignalId", 9usize, |this| ((**(this as *const *const IConversationalAgentSignal_Vtbl)).SetSignalId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSignal", "SignalName", 10usize, |this| ((**(this as *const *const IConversationalAgentSignal_Vtbl)).SignalName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignalName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSignal", "SetSignalName", 11usize, |this| ((**(this as *const *const IConversationalAgentSignal_Vtbl)).SetSignalName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalContext(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSignal", "SignalContext", 12usize, |this| ((**(this as *const *const IConversationalAgentSignal_Vtbl)).SignalContext)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignalContext(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSignal", "SetSignalContext", 13usize, |this| ((**(this as *const *const IConversationalAgentSignal_Vtbl)).SetSignalContext)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalStart(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSignal", "SignalStart", 14usize, |this| ((**(this as *const *const IConversationalAgentSignal_Vtbl)).SignalStart)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignalStart(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSignal", "SetSignalStart", 15usize, |this| ((**(this as *const *const IConversationalAgentSignal_Vtbl)).SetSignalStart)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalEnd(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSignal", "SignalEnd", 16usize, |this| ((**(this as *const *const IConversationalAgentSignal_Vtbl)).SignalEnd)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignalEnd(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSignal", "SetSignalEnd", 17usize, |this| ((**(this as *const *const IConversationalAgentSignal_Vtbl)).SetSignalEnd)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSignalVerificationRequired,
+            SetIsSignalVerificationRequired,
+            SignalId,
+            SetSignalId,
+            SignalName,
+            SetSignalName,
+            SignalContext,
+            SetSignalContext,
+            SignalStart,
+            SetSignalStart,
+            SignalEnd,
+            SetSignalEnd,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConversationalAgentSignal as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConversationalAgentSignal2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DetectorId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSignal2", "DetectorId", 6usize, |this| ((**(this as *const *const IConversationalAgentSignal2_Vtbl)).DetectorId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetectorKind(this: *mut core::ffi::c_void, result__: *mut ActivationSignalDetectorKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSignal2", "DetectorKind", 7usize, |this| ((**(this as *const *const IConversationalAgentSignal2_Vtbl)).DetectorKind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DetectorId, DetectorKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConversationalAgentSignal2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConversationalAgentSignalDetectedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConversationalAgentSignalDetectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConversationalAgentSystemStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SystemStateChangeType(this: *mut core::ffi::c_void, result__: *mut ConversationalAgentSystemStateChangeType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConversationalAgentSystemStateChangedEventArgs", "SystemStateChangeType", 6usize, |this| ((**(this as *const *const IConversationalAgentSystemStateChangedEventArgs_Vtbl)).SystemStateChangeType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SystemStateChangeType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConversationalAgentSystemStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDetectionConfigurationAvailabilityChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut DetectionConfigurationAvailabilityChangeKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDetectionConfigurationAvailabilityChangedEventArgs", "Kind", 6usize, |this| ((**(this as *const *const IDetectionConfigurationAvailabilityChangedEventArgs_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDetectionConfigurationAvailabilityChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDetectionConfigurationAvailabilityInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDetectionConfigurationAvailabilityInfo", "IsEnabled", 6usize, |this| ((**(this as *const *const IDetectionConfigurationAvailabilityInfo_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasSystemResourceAccess(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDetectionConfigurationAvailabilityInfo", "HasSystemResourceAccess", 7usize, |this| ((**(this as *const *const IDetectionConfigurationAvailabilityInfo_Vtbl)).HasSystemResourceAccess)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasPermission(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDetectionConfigurationAvailabilityInfo", "HasPermission", 8usize, |this| ((**(this as *const *const IDetectionConfigurationAvailabilityInfo_Vtbl)).HasPermission)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasLockScreenPermission(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDetectionConfigurationAvailabilityInfo", "HasLockScreenPermission", 9usize, |this| ((**(this as *const *const IDetectionConfigurationAvailabilityInfo_Vtbl)).HasLockScreenPermission)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEnabled,
+            HasSystemResourceAccess,
+            HasPermission,
+            HasLockScreenPermission,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDetectionConfigurationAvailabilityInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDetectionConfigurationAvailabilityInfo2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UnavailableSystemResources(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDetectionConfigurationAvailabilityInfo2", "UnavailableSystemResources", 6usize, |this| ((**(this as *const *const IDetectionConfigurationAvailabilityInfo2_Vtbl)).UnavailableSystemResources)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            UnavailableSystemResources,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UnavailableSystemResources: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDetectionConfigurationAvailabilityInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -930,6 +930,7 @@ impl AppRestartFailureReason {
 impl windows_core::TypeKind for AppRestartFailureReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppRestartFailureReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppRestartFailureReason").field(&self.0).finish()
@@ -940,3 +941,5 @@ impl windows_core::RuntimeType for AppRestartFailureReason {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,544 @@
+impl windows_core::imp::ProxyVtable for IAppListEntry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppListEntry", "DisplayInfo", 6usize, |this| ((**(this as *const *const IAppListEntry_Vtbl)).DisplayInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppListEntry", "LaunchAsync", 7usize, |this| ((**(this as *const *const IAppListEntry_Vtbl)).LaunchAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayInfo, LaunchAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppListEntry as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppListEntry2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppUserModelId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppListEntry2", "AppUserModelId", 6usize, |this| ((**(this as *const *const IAppListEntry2_Vtbl)).AppUserModelId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AppUserModelId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppListEntry2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IAppListEntry3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn LaunchForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppListEntry3", "LaunchForUserAsync", 6usize, |this| ((**(this as *const *const IAppListEntry3_Vtbl)).LaunchForUserAsync)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            LaunchForUserAsync,
+            #[cfg(not(feature = "System"))]
+            LaunchForUserAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppListEntry3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppListEntry4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppListEntry4", "AppInfo", 6usize, |this| ((**(this as *const *const IAppListEntry4_Vtbl)).AppInfo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AppInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppListEntry4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICoreApplication_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication", "Id", 6usize, |this| ((**(this as *const *const ICoreApplication_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Suspending(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication", "Suspending", 7usize, |this| ((**(this as *const *const ICoreApplication_Vtbl)).Suspending)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSuspending(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication", "RemoveSuspending", 8usize, |this| ((**(this as *const *const ICoreApplication_Vtbl)).RemoveSuspending)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resuming(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication", "Resuming", 9usize, |this| ((**(this as *const *const ICoreApplication_Vtbl)).Resuming)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveResuming(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication", "RemoveResuming", 10usize, |this| ((**(this as *const *const ICoreApplication_Vtbl)).RemoveResuming)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication", "Properties", 11usize, |this| ((**(this as *const *const ICoreApplication_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication", "GetCurrentView", 12usize, |this| ((**(this as *const *const ICoreApplication_Vtbl)).GetCurrentView)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Run(this: *mut core::ffi::c_void, viewsource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication", "Run", 13usize, |this| ((**(this as *const *const ICoreApplication_Vtbl)).Run)(this, viewsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunWithActivationFactories(this: *mut core::ffi::c_void, activationfactorycallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication", "RunWithActivationFactories", 14usize, |this| ((**(this as *const *const ICoreApplication_Vtbl)).RunWithActivationFactories)(this, activationfactorycallback), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            Suspending,
+            RemoveSuspending,
+            Resuming,
+            RemoveResuming,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            GetCurrentView,
+            Run,
+            RunWithActivationFactories,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreApplication as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Activation")]
+impl windows_core::imp::ProxyVtable for ICoreApplication2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_Activation")]
+        unsafe extern "system" fn BackgroundActivated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication2", "BackgroundActivated", 6usize, |this| ((**(this as *const *const ICoreApplication2_Vtbl)).BackgroundActivated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBackgroundActivated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication2", "RemoveBackgroundActivated", 7usize, |this| ((**(this as *const *const ICoreApplication2_Vtbl)).RemoveBackgroundActivated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeavingBackground(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication2", "LeavingBackground", 8usize, |this| ((**(this as *const *const ICoreApplication2_Vtbl)).LeavingBackground)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLeavingBackground(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication2", "RemoveLeavingBackground", 9usize, |this| ((**(this as *const *const ICoreApplication2_Vtbl)).RemoveLeavingBackground)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnteredBackground(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication2", "EnteredBackground", 10usize, |this| ((**(this as *const *const ICoreApplication2_Vtbl)).EnteredBackground)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnteredBackground(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication2", "RemoveEnteredBackground", 11usize, |this| ((**(this as *const *const ICoreApplication2_Vtbl)).RemoveEnteredBackground)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnablePrelaunch(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication2", "EnablePrelaunch", 12usize, |this| ((**(this as *const *const ICoreApplication2_Vtbl)).EnablePrelaunch)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_Activation")]
+            BackgroundActivated,
+            #[cfg(not(feature = "ApplicationModel_Activation"))]
+            BackgroundActivated: 0,
+            RemoveBackgroundActivated,
+            LeavingBackground,
+            RemoveLeavingBackground,
+            EnteredBackground,
+            RemoveEnteredBackground,
+            EnablePrelaunch,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreApplication2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for ICoreApplication3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestRestartAsync(this: *mut core::ffi::c_void, launcharguments: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication3", "RequestRestartAsync", 6usize, |this| ((**(this as *const *const ICoreApplication3_Vtbl)).RequestRestartAsync)(this, launcharguments, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn RequestRestartForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, launcharguments: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplication3", "RequestRestartForUserAsync", 7usize, |this| ((**(this as *const *const ICoreApplication3_Vtbl)).RequestRestartForUserAsync)(this, user, launcharguments, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestRestartAsync,
+            #[cfg(feature = "System")]
+            RequestRestartForUserAsync,
+            #[cfg(not(feature = "System"))]
+            RequestRestartForUserAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreApplication3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreApplicationExit_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Exit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationExit", "Exit", 6usize, |this| ((**(this as *const *const ICoreApplicationExit_Vtbl)).Exit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Exiting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationExit", "Exiting", 7usize, |this| ((**(this as *const *const ICoreApplicationExit_Vtbl)).Exiting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveExiting(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationExit", "RemoveExiting", 8usize, |this| ((**(this as *const *const ICoreApplicationExit_Vtbl)).RemoveExiting)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Exit, Exiting, RemoveExiting }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreApplicationExit as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreApplicationUnhandledError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UnhandledErrorDetected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationUnhandledError", "UnhandledErrorDetected", 6usize, |this| ((**(this as *const *const ICoreApplicationUnhandledError_Vtbl)).UnhandledErrorDetected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUnhandledErrorDetected(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationUnhandledError", "RemoveUnhandledErrorDetected", 7usize, |this| ((**(this as *const *const ICoreApplicationUnhandledError_Vtbl)).RemoveUnhandledErrorDetected)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UnhandledErrorDetected, RemoveUnhandledErrorDetected }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreApplicationUnhandledError as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreApplicationUseCount_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IncrementApplicationUseCount(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationUseCount", "IncrementApplicationUseCount", 6usize, |this| ((**(this as *const *const ICoreApplicationUseCount_Vtbl)).IncrementApplicationUseCount)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecrementApplicationUseCount(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationUseCount", "DecrementApplicationUseCount", 7usize, |this| ((**(this as *const *const ICoreApplicationUseCount_Vtbl)).DecrementApplicationUseCount)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IncrementApplicationUseCount, DecrementApplicationUseCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreApplicationUseCount as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Activation", feature = "UI_Core"))]
+impl windows_core::imp::ProxyVtable for ICoreApplicationView_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn CoreWindow(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationView", "CoreWindow", 6usize, |this| ((**(this as *const *const ICoreApplicationView_Vtbl)).CoreWindow)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Activation")]
+        unsafe extern "system" fn Activated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationView", "Activated", 7usize, |this| ((**(this as *const *const ICoreApplicationView_Vtbl)).Activated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveActivated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationView", "RemoveActivated", 8usize, |this| ((**(this as *const *const ICoreApplicationView_Vtbl)).RemoveActivated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMain(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationView", "IsMain", 9usize, |this| ((**(this as *const *const ICoreApplicationView_Vtbl)).IsMain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHosted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationView", "IsHosted", 10usize, |this| ((**(this as *const *const ICoreApplicationView_Vtbl)).IsHosted)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Core")]
+            CoreWindow,
+            #[cfg(not(feature = "UI_Core"))]
+            CoreWindow: 0,
+            #[cfg(feature = "ApplicationModel_Activation")]
+            Activated,
+            #[cfg(not(feature = "ApplicationModel_Activation"))]
+            Activated: 0,
+            RemoveActivated,
+            IsMain,
+            IsHosted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreApplicationView as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Core")]
+impl windows_core::imp::ProxyVtable for ICoreApplicationView2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn Dispatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationView2", "Dispatcher", 6usize, |this| ((**(this as *const *const ICoreApplicationView2_Vtbl)).Dispatcher)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Core")]
+            Dispatcher,
+            #[cfg(not(feature = "UI_Core"))]
+            Dispatcher: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreApplicationView2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreApplicationView3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsComponent(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationView3", "IsComponent", 6usize, |this| ((**(this as *const *const ICoreApplicationView3_Vtbl)).IsComponent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TitleBar(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationView3", "TitleBar", 7usize, |this| ((**(this as *const *const ICoreApplicationView3_Vtbl)).TitleBar)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HostedViewClosing(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationView3", "HostedViewClosing", 8usize, |this| ((**(this as *const *const ICoreApplicationView3_Vtbl)).HostedViewClosing)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHostedViewClosing(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationView3", "RemoveHostedViewClosing", 9usize, |this| ((**(this as *const *const ICoreApplicationView3_Vtbl)).RemoveHostedViewClosing)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsComponent,
+            TitleBar,
+            HostedViewClosing,
+            RemoveHostedViewClosing,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreApplicationView3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICoreApplicationView5_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationView5", "Properties", 6usize, |this| ((**(this as *const *const ICoreApplicationView5_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreApplicationView5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for ICoreApplicationView6_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn DispatcherQueue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationView6", "DispatcherQueue", 6usize, |this| ((**(this as *const *const ICoreApplicationView6_Vtbl)).DispatcherQueue)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            DispatcherQueue,
+            #[cfg(not(feature = "System"))]
+            DispatcherQueue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreApplicationView6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreApplicationViewTitleBar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetExtendViewIntoTitleBar(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationViewTitleBar", "SetExtendViewIntoTitleBar", 6usize, |this| ((**(this as *const *const ICoreApplicationViewTitleBar_Vtbl)).SetExtendViewIntoTitleBar)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendViewIntoTitleBar(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationViewTitleBar", "ExtendViewIntoTitleBar", 7usize, |this| ((**(this as *const *const ICoreApplicationViewTitleBar_Vtbl)).ExtendViewIntoTitleBar)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemOverlayLeftInset(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationViewTitleBar", "SystemOverlayLeftInset", 8usize, |this| ((**(this as *const *const ICoreApplicationViewTitleBar_Vtbl)).SystemOverlayLeftInset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemOverlayRightInset(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationViewTitleBar", "SystemOverlayRightInset", 9usize, |this| ((**(this as *const *const ICoreApplicationViewTitleBar_Vtbl)).SystemOverlayRightInset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationViewTitleBar", "Height", 10usize, |this| ((**(this as *const *const ICoreApplicationViewTitleBar_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LayoutMetricsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationViewTitleBar", "LayoutMetricsChanged", 11usize, |this| ((**(this as *const *const ICoreApplicationViewTitleBar_Vtbl)).LayoutMetricsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLayoutMetricsChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationViewTitleBar", "RemoveLayoutMetricsChanged", 12usize, |this| ((**(this as *const *const ICoreApplicationViewTitleBar_Vtbl)).RemoveLayoutMetricsChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationViewTitleBar", "IsVisible", 13usize, |this| ((**(this as *const *const ICoreApplicationViewTitleBar_Vtbl)).IsVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVisibleChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationViewTitleBar", "IsVisibleChanged", 14usize, |this| ((**(this as *const *const ICoreApplicationViewTitleBar_Vtbl)).IsVisibleChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveIsVisibleChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreApplicationViewTitleBar", "RemoveIsVisibleChanged", 15usize, |this| ((**(this as *const *const ICoreApplicationViewTitleBar_Vtbl)).RemoveIsVisibleChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetExtendViewIntoTitleBar,
+            ExtendViewIntoTitleBar,
+            SystemOverlayLeftInset,
+            SystemOverlayRightInset,
+            Height,
+            LayoutMetricsChanged,
+            RemoveLayoutMetricsChanged,
+            IsVisible,
+            IsVisibleChanged,
+            RemoveIsVisibleChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreApplicationViewTitleBar as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICoreImmersiveApplication_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Views(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreImmersiveApplication", "Views", 6usize, |this| ((**(this as *const *const ICoreImmersiveApplication_Vtbl)).Views)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNewView(this: *mut core::ffi::c_void, runtimetype: core::mem::MaybeUninit<windows_core::HSTRING>, entrypoint: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreImmersiveApplication", "CreateNewView", 7usize, |this| ((**(this as *const *const ICoreImmersiveApplication_Vtbl)).CreateNewView)(this, runtimetype, entrypoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreImmersiveApplication", "MainView", 8usize, |this| ((**(this as *const *const ICoreImmersiveApplication_Vtbl)).MainView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Views,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Views: 0,
+            CreateNewView,
+            MainView,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreImmersiveApplication as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreImmersiveApplication2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateNewViewFromMainView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreImmersiveApplication2", "CreateNewViewFromMainView", 6usize, |this| ((**(this as *const *const ICoreImmersiveApplication2_Vtbl)).CreateNewViewFromMainView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateNewViewFromMainView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreImmersiveApplication2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreImmersiveApplication3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateNewViewWithViewSource(this: *mut core::ffi::c_void, viewsource: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreImmersiveApplication3", "CreateNewViewWithViewSource", 6usize, |this| ((**(this as *const *const ICoreImmersiveApplication3_Vtbl)).CreateNewViewWithViewSource)(this, viewsource, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateNewViewWithViewSource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreImmersiveApplication3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Core")]
+impl windows_core::imp::ProxyVtable for IFrameworkView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, applicationview: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameworkView", "Initialize", 6usize, |this| ((**(this as *const *const IFrameworkView_Vtbl)).Initialize)(this, applicationview), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn SetWindow(this: *mut core::ffi::c_void, window: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameworkView", "SetWindow", 7usize, |this| ((**(this as *const *const IFrameworkView_Vtbl)).SetWindow)(this, window), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, entrypoint: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameworkView", "Load", 8usize, |this| ((**(this as *const *const IFrameworkView_Vtbl)).Load)(this, entrypoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn Run(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameworkView", "Run", 9usize, |this| ((**(this as *const *const IFrameworkView_Vtbl)).Run)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uninitialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameworkView", "Uninitialize", 10usize, |this| ((**(this as *const *const IFrameworkView_Vtbl)).Uninitialize)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            #[cfg(feature = "UI_Core")]
+            SetWindow,
+            #[cfg(not(feature = "UI_Core"))]
+            SetWindow: 0,
+            Load,
+            Run,
+            Uninitialize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameworkView as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameworkViewSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameworkViewSource", "CreateView", 6usize, |this| ((**(this as *const *const IFrameworkViewSource_Vtbl)).CreateView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameworkViewSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostedViewClosingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostedViewClosingEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const IHostedViewClosingEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostedViewClosingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUnhandledError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnhandledError", "Handled", 6usize, |this| ((**(this as *const *const IUnhandledError_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Propagate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnhandledError", "Propagate", 7usize, |this| ((**(this as *const *const IUnhandledError_Vtbl)).Propagate)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, Propagate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUnhandledError as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUnhandledErrorDetectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UnhandledError(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnhandledErrorDetectedEventArgs", "UnhandledError", 6usize, |this| ((**(this as *const *const IUnhandledErrorDetectedEventArgs_Vtbl)).UnhandledError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UnhandledError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUnhandledErrorDetectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -465,6 +465,7 @@ impl CoreDragUIContentMode {
 impl windows_core::TypeKind for CoreDragUIContentMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreDragUIContentMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreDragUIContentMode").field(&self.0).finish()
@@ -508,3 +509,5 @@ impl windows_core::RuntimeType for CoreDragUIContentMode {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,230 @@
+impl windows_core::imp::ProxyVtable for ICoreDragDropManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TargetRequested(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragDropManager", "TargetRequested", 6usize, |this| ((**(this as *const *const ICoreDragDropManager_Vtbl)).TargetRequested)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTargetRequested(this: *mut core::ffi::c_void, value: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragDropManager", "RemoveTargetRequested", 7usize, |this| ((**(this as *const *const ICoreDragDropManager_Vtbl)).RemoveTargetRequested)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AreConcurrentOperationsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragDropManager", "AreConcurrentOperationsEnabled", 8usize, |this| ((**(this as *const *const ICoreDragDropManager_Vtbl)).AreConcurrentOperationsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAreConcurrentOperationsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragDropManager", "SetAreConcurrentOperationsEnabled", 9usize, |this| ((**(this as *const *const ICoreDragDropManager_Vtbl)).SetAreConcurrentOperationsEnabled)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TargetRequested,
+            RemoveTargetRequested,
+            AreConcurrentOperationsEnabled,
+            SetAreConcurrentOperationsEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreDragDropManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreDragDropManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragDropManagerStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const ICoreDragDropManagerStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreDragDropManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreDragInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragInfo", "Data", 6usize, |this| ((**(this as *const *const ICoreDragInfo_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Modifiers(this: *mut core::ffi::c_void, result__: *mut super::DragDropModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragInfo", "Modifiers", 7usize, |this| ((**(this as *const *const ICoreDragInfo_Vtbl)).Modifiers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragInfo", "Position", 8usize, |this| ((**(this as *const *const ICoreDragInfo_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Data, Modifiers, Position }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreDragInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreDragInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowedOperations(this: *mut core::ffi::c_void, result__: *mut super::super::DataPackageOperation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragInfo2", "AllowedOperations", 6usize, |this| ((**(this as *const *const ICoreDragInfo2_Vtbl)).AllowedOperations)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AllowedOperations }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreDragInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for ICoreDragOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragOperation", "Data", 6usize, |this| ((**(this as *const *const ICoreDragOperation_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPointerId(this: *mut core::ffi::c_void, pointerid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragOperation", "SetPointerId", 7usize, |this| ((**(this as *const *const ICoreDragOperation_Vtbl)).SetPointerId)(this, pointerid), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SetDragUIContentFromSoftwareBitmap(this: *mut core::ffi::c_void, softwarebitmap: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragOperation", "SetDragUIContentFromSoftwareBitmap", 8usize, |this| ((**(this as *const *const ICoreDragOperation_Vtbl)).SetDragUIContentFromSoftwareBitmap)(this, softwarebitmap), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SetDragUIContentFromSoftwareBitmapWithAnchorPoint(this: *mut core::ffi::c_void, softwarebitmap: *mut core::ffi::c_void, anchorpoint: super::super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragOperation", "SetDragUIContentFromSoftwareBitmapWithAnchorPoint", 9usize, |this| ((**(this as *const *const ICoreDragOperation_Vtbl)).SetDragUIContentFromSoftwareBitmapWithAnchorPoint)(this, softwarebitmap, anchorpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn DragUIContentMode(this: *mut core::ffi::c_void, result__: *mut CoreDragUIContentMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragOperation", "DragUIContentMode", 10usize, |this| ((**(this as *const *const ICoreDragOperation_Vtbl)).DragUIContentMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDragUIContentMode(this: *mut core::ffi::c_void, value: CoreDragUIContentMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragOperation", "SetDragUIContentMode", 11usize, |this| ((**(this as *const *const ICoreDragOperation_Vtbl)).SetDragUIContentMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragOperation", "StartAsync", 12usize, |this| ((**(this as *const *const ICoreDragOperation_Vtbl)).StartAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Data,
+            SetPointerId,
+            #[cfg(feature = "Graphics_Imaging")]
+            SetDragUIContentFromSoftwareBitmap,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SetDragUIContentFromSoftwareBitmap: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            SetDragUIContentFromSoftwareBitmapWithAnchorPoint,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SetDragUIContentFromSoftwareBitmapWithAnchorPoint: 0,
+            DragUIContentMode,
+            SetDragUIContentMode,
+            StartAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreDragOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for ICoreDragOperation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowedOperations(this: *mut core::ffi::c_void, result__: *mut super::super::DataPackageOperation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragOperation2", "AllowedOperations", 6usize, |this| ((**(this as *const *const ICoreDragOperation2_Vtbl)).AllowedOperations)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowedOperations(this: *mut core::ffi::c_void, value: super::super::DataPackageOperation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragOperation2", "SetAllowedOperations", 7usize, |this| ((**(this as *const *const ICoreDragOperation2_Vtbl)).SetAllowedOperations)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AllowedOperations, SetAllowedOperations }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreDragOperation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for ICoreDragUIOverride_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SetContentFromSoftwareBitmap(this: *mut core::ffi::c_void, softwarebitmap: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragUIOverride", "SetContentFromSoftwareBitmap", 6usize, |this| ((**(this as *const *const ICoreDragUIOverride_Vtbl)).SetContentFromSoftwareBitmap)(this, softwarebitmap), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SetContentFromSoftwareBitmapWithAnchorPoint(this: *mut core::ffi::c_void, softwarebitmap: *mut core::ffi::c_void, anchorpoint: super::super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragUIOverride", "SetContentFromSoftwareBitmapWithAnchorPoint", 7usize, |this| ((**(this as *const *const ICoreDragUIOverride_Vtbl)).SetContentFromSoftwareBitmapWithAnchorPoint)(this, softwarebitmap, anchorpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsContentVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragUIOverride", "IsContentVisible", 8usize, |this| ((**(this as *const *const ICoreDragUIOverride_Vtbl)).IsContentVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsContentVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragUIOverride", "SetIsContentVisible", 9usize, |this| ((**(this as *const *const ICoreDragUIOverride_Vtbl)).SetIsContentVisible)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Caption(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragUIOverride", "Caption", 10usize, |this| ((**(this as *const *const ICoreDragUIOverride_Vtbl)).Caption)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCaption(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragUIOverride", "SetCaption", 11usize, |this| ((**(this as *const *const ICoreDragUIOverride_Vtbl)).SetCaption)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCaptionVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragUIOverride", "IsCaptionVisible", 12usize, |this| ((**(this as *const *const ICoreDragUIOverride_Vtbl)).IsCaptionVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCaptionVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragUIOverride", "SetIsCaptionVisible", 13usize, |this| ((**(this as *const *const ICoreDragUIOverride_Vtbl)).SetIsCaptionVisible)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGlyphVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragUIOverride", "IsGlyphVisible", 14usize, |this| ((**(this as *const *const ICoreDragUIOverride_Vtbl)).IsGlyphVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsGlyphVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragUIOverride", "SetIsGlyphVisible", 15usize, |this| ((**(this as *const *const ICoreDragUIOverride_Vtbl)).SetIsGlyphVisible)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDragUIOverride", "Clear", 16usize, |this| ((**(this as *const *const ICoreDragUIOverride_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Imaging")]
+            SetContentFromSoftwareBitmap,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SetContentFromSoftwareBitmap: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            SetContentFromSoftwareBitmapWithAnchorPoint,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SetContentFromSoftwareBitmapWithAnchorPoint: 0,
+            IsContentVisible,
+            SetIsContentVisible,
+            Caption,
+            SetCaption,
+            IsCaptionVisible,
+            SetIsCaptionVisible,
+            IsGlyphVisible,
+            SetIsGlyphVisible,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreDragUIOverride as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreDropOperationTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnterAsync(this: *mut core::ffi::c_void, draginfo: *mut core::ffi::c_void, draguioverride: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDropOperationTarget", "EnterAsync", 6usize, |this| ((**(this as *const *const ICoreDropOperationTarget_Vtbl)).EnterAsync)(this, draginfo, draguioverride, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OverAsync(this: *mut core::ffi::c_void, draginfo: *mut core::ffi::c_void, draguioverride: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDropOperationTarget", "OverAsync", 7usize, |this| ((**(this as *const *const ICoreDropOperationTarget_Vtbl)).OverAsync)(this, draginfo, draguioverride, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeaveAsync(this: *mut core::ffi::c_void, draginfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDropOperationTarget", "LeaveAsync", 8usize, |this| ((**(this as *const *const ICoreDropOperationTarget_Vtbl)).LeaveAsync)(this, draginfo, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DropAsync(this: *mut core::ffi::c_void, draginfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDropOperationTarget", "DropAsync", 9usize, |this| ((**(this as *const *const ICoreDropOperationTarget_Vtbl)).DropAsync)(this, draginfo, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnterAsync, OverAsync, LeaveAsync, DropAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreDropOperationTarget as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreDropOperationTargetRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTarget(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDropOperationTargetRequestedEventArgs", "SetTarget", 6usize, |this| ((**(this as *const *const ICoreDropOperationTargetRequestedEventArgs_Vtbl)).SetTarget)(this, target), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTarget }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreDropOperationTargetRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
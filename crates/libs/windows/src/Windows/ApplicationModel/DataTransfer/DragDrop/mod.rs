@@ -15,6 +15,7 @@ impl DragDropModifiers {
 impl windows_core::TypeKind for DragDropModifiers {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DragDropModifiers {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DragDropModifiers").field(&self.0).finish()
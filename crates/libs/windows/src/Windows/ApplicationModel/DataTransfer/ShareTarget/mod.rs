@@ -214,3 +214,5 @@ unsafe impl windows_core::Interface for ShareOperation {
 impl windows_core::RuntimeName for ShareOperation {
     const NAME: &'static str = "Windows.ApplicationModel.DataTransfer.ShareTarget.ShareOperation";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,139 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IQuickLink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuickLink", "Title", 6usize, |this| ((**(this as *const *const IQuickLink_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuickLink", "SetTitle", 7usize, |this| ((**(this as *const *const IQuickLink_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuickLink", "Thumbnail", 8usize, |this| ((**(this as *const *const IQuickLink_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetThumbnail(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuickLink", "SetThumbnail", 9usize, |this| ((**(this as *const *const IQuickLink_Vtbl)).SetThumbnail)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuickLink", "Id", 10usize, |this| ((**(this as *const *const IQuickLink_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuickLink", "SetId", 11usize, |this| ((**(this as *const *const IQuickLink_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedDataFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuickLink", "SupportedDataFormats", 12usize, |this| ((**(this as *const *const IQuickLink_Vtbl)).SupportedDataFormats)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedFileTypes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuickLink", "SupportedFileTypes", 13usize, |this| ((**(this as *const *const IQuickLink_Vtbl)).SupportedFileTypes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Title,
+            SetTitle,
+            #[cfg(feature = "Storage_Streams")]
+            Thumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Thumbnail: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetThumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetThumbnail: 0,
+            Id,
+            SetId,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedDataFormats,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedDataFormats: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedFileTypes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedFileTypes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQuickLink as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IShareOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareOperation", "Data", 6usize, |this| ((**(this as *const *const IShareOperation_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuickLinkId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareOperation", "QuickLinkId", 7usize, |this| ((**(this as *const *const IShareOperation_Vtbl)).QuickLinkId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveThisQuickLink(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareOperation", "RemoveThisQuickLink", 8usize, |this| ((**(this as *const *const IShareOperation_Vtbl)).RemoveThisQuickLink)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportStarted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareOperation", "ReportStarted", 9usize, |this| ((**(this as *const *const IShareOperation_Vtbl)).ReportStarted)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportDataRetrieved(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareOperation", "ReportDataRetrieved", 10usize, |this| ((**(this as *const *const IShareOperation_Vtbl)).ReportDataRetrieved)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportSubmittedBackgroundTask(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareOperation", "ReportSubmittedBackgroundTask", 11usize, |this| ((**(this as *const *const IShareOperation_Vtbl)).ReportSubmittedBackgroundTask)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedWithQuickLink(this: *mut core::ffi::c_void, quicklink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareOperation", "ReportCompletedWithQuickLink", 12usize, |this| ((**(this as *const *const IShareOperation_Vtbl)).ReportCompletedWithQuickLink)(this, quicklink), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompleted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareOperation", "ReportCompleted", 13usize, |this| ((**(this as *const *const IShareOperation_Vtbl)).ReportCompleted)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportError(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareOperation", "ReportError", 14usize, |this| ((**(this as *const *const IShareOperation_Vtbl)).ReportError)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Data,
+            QuickLinkId,
+            RemoveThisQuickLink,
+            ReportStarted,
+            ReportDataRetrieved,
+            ReportSubmittedBackgroundTask,
+            ReportCompletedWithQuickLink,
+            ReportCompleted,
+            ReportError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IShareOperation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DismissUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareOperation2", "DismissUI", 6usize, |this| ((**(this as *const *const IShareOperation2_Vtbl)).DismissUI)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DismissUI }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareOperation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Contacts", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IShareOperation3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "ApplicationModel_Contacts", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn Contacts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareOperation3", "Contacts", 6usize, |this| ((**(this as *const *const IShareOperation3_Vtbl)).Contacts)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "ApplicationModel_Contacts", feature = "Foundation_Collections"))]
+            Contacts,
+            #[cfg(not(all(feature = "ApplicationModel_Contacts", feature = "Foundation_Collections")))]
+            Contacts: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareOperation3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
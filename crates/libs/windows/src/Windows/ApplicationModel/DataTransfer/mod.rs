@@ -2435,6 +2435,7 @@ impl ClipboardHistoryItemsResultStatus {
 impl windows_core::TypeKind for ClipboardHistoryItemsResultStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ClipboardHistoryItemsResultStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ClipboardHistoryItemsResultStatus").field(&self.0).finish()
@@ -2455,6 +2456,7 @@ impl DataPackageOperation {
 impl windows_core::TypeKind for DataPackageOperation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DataPackageOperation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DataPackageOperation").field(&self.0).finish()
@@ -2507,6 +2509,7 @@ impl SetHistoryItemAsContentStatus {
 impl windows_core::TypeKind for SetHistoryItemAsContentStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SetHistoryItemAsContentStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SetHistoryItemAsContentStatus").field(&self.0).finish()
@@ -2526,6 +2529,7 @@ impl ShareUITheme {
 impl windows_core::TypeKind for ShareUITheme {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ShareUITheme {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ShareUITheme").field(&self.0).finish()
@@ -2654,3 +2658,5 @@ pub struct ShareProviderHandler_Vtbl {
     pub base__: windows_core::IUnknown_Vtbl,
     pub Invoke: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1246 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IClipboardContentOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsRoamable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardContentOptions", "IsRoamable", 6usize, |this| ((**(this as *const *const IClipboardContentOptions_Vtbl)).IsRoamable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsRoamable(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardContentOptions", "SetIsRoamable", 7usize, |this| ((**(this as *const *const IClipboardContentOptions_Vtbl)).SetIsRoamable)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAllowedInHistory(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardContentOptions", "IsAllowedInHistory", 8usize, |this| ((**(this as *const *const IClipboardContentOptions_Vtbl)).IsAllowedInHistory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsAllowedInHistory(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardContentOptions", "SetIsAllowedInHistory", 9usize, |this| ((**(this as *const *const IClipboardContentOptions_Vtbl)).SetIsAllowedInHistory)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RoamingFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardContentOptions", "RoamingFormats", 10usize, |this| ((**(this as *const *const IClipboardContentOptions_Vtbl)).RoamingFormats)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn HistoryFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardContentOptions", "HistoryFormats", 11usize, |this| ((**(this as *const *const IClipboardContentOptions_Vtbl)).HistoryFormats)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsRoamable,
+            SetIsRoamable,
+            IsAllowedInHistory,
+            SetIsAllowedInHistory,
+            #[cfg(feature = "Foundation_Collections")]
+            RoamingFormats,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RoamingFormats: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            HistoryFormats,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            HistoryFormats: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClipboardContentOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClipboardHistoryChangedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClipboardHistoryChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClipboardHistoryItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardHistoryItem", "Id", 6usize, |this| ((**(this as *const *const IClipboardHistoryItem_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardHistoryItem", "Timestamp", 7usize, |this| ((**(this as *const *const IClipboardHistoryItem_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardHistoryItem", "Content", 8usize, |this| ((**(this as *const *const IClipboardHistoryItem_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Timestamp, Content }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClipboardHistoryItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IClipboardHistoryItemsResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut ClipboardHistoryItemsResultStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardHistoryItemsResult", "Status", 6usize, |this| ((**(this as *const *const IClipboardHistoryItemsResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Items(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardHistoryItemsResult", "Items", 7usize, |this| ((**(this as *const *const IClipboardHistoryItemsResult_Vtbl)).Items)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Foundation_Collections")]
+            Items,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Items: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClipboardHistoryItemsResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClipboardStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetContent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics", "GetContent", 6usize, |this| ((**(this as *const *const IClipboardStatics_Vtbl)).GetContent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContent(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics", "SetContent", 7usize, |this| ((**(this as *const *const IClipboardStatics_Vtbl)).SetContent)(this, content), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics", "Flush", 8usize, |this| ((**(this as *const *const IClipboardStatics_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics", "Clear", 9usize, |this| ((**(this as *const *const IClipboardStatics_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics", "ContentChanged", 10usize, |this| ((**(this as *const *const IClipboardStatics_Vtbl)).ContentChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveContentChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics", "RemoveContentChanged", 11usize, |this| ((**(this as *const *const IClipboardStatics_Vtbl)).RemoveContentChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetContent,
+            SetContent,
+            Flush,
+            Clear,
+            ContentChanged,
+            RemoveContentChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClipboardStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClipboardStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHistoryItemsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics2", "GetHistoryItemsAsync", 6usize, |this| ((**(this as *const *const IClipboardStatics2_Vtbl)).GetHistoryItemsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearHistory(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics2", "ClearHistory", 7usize, |this| ((**(this as *const *const IClipboardStatics2_Vtbl)).ClearHistory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteItemFromHistory(this: *mut core::ffi::c_void, item: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics2", "DeleteItemFromHistory", 8usize, |this| ((**(this as *const *const IClipboardStatics2_Vtbl)).DeleteItemFromHistory)(this, item, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHistoryItemAsContent(this: *mut core::ffi::c_void, item: *mut core::ffi::c_void, result__: *mut SetHistoryItemAsContentStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics2", "SetHistoryItemAsContent", 9usize, |this| ((**(this as *const *const IClipboardStatics2_Vtbl)).SetHistoryItemAsContent)(this, item, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHistoryEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics2", "IsHistoryEnabled", 10usize, |this| ((**(this as *const *const IClipboardStatics2_Vtbl)).IsHistoryEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRoamingEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics2", "IsRoamingEnabled", 11usize, |this| ((**(this as *const *const IClipboardStatics2_Vtbl)).IsRoamingEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentWithOptions(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics2", "SetContentWithOptions", 12usize, |this| ((**(this as *const *const IClipboardStatics2_Vtbl)).SetContentWithOptions)(this, content, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HistoryChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics2", "HistoryChanged", 13usize, |this| ((**(this as *const *const IClipboardStatics2_Vtbl)).HistoryChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHistoryChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics2", "RemoveHistoryChanged", 14usize, |this| ((**(this as *const *const IClipboardStatics2_Vtbl)).RemoveHistoryChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoamingEnabledChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics2", "RoamingEnabledChanged", 15usize, |this| ((**(this as *const *const IClipboardStatics2_Vtbl)).RoamingEnabledChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRoamingEnabledChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics2", "RemoveRoamingEnabledChanged", 16usize, |this| ((**(this as *const *const IClipboardStatics2_Vtbl)).RemoveRoamingEnabledChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn HistoryEnabledChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics2", "HistoryEnabledChanged", 17usize, |this| ((**(this as *const *const IClipboardStatics2_Vtbl)).HistoryEnabledChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHistoryEnabledChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipboardStatics2", "RemoveHistoryEnabledChanged", 18usize, |this| ((**(this as *const *const IClipboardStatics2_Vtbl)).RemoveHistoryEnabledChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetHistoryItemsAsync,
+            ClearHistory,
+            DeleteItemFromHistory,
+            SetHistoryItemAsContent,
+            IsHistoryEnabled,
+            IsRoamingEnabled,
+            SetContentWithOptions,
+            HistoryChanged,
+            RemoveHistoryChanged,
+            RoamingEnabledChanged,
+            RemoveRoamingEnabledChanged,
+            HistoryEnabledChanged,
+            RemoveHistoryEnabledChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClipboardStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IDataPackage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "GetView", 6usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).GetView)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "Properties", 7usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestedOperation(this: *mut core::ffi::c_void, result__: *mut DataPackageOperation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "RequestedOperation", 8usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).RequestedOperation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestedOperation(this: *mut core::ffi::c_void, value: DataPackageOperation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "SetRequestedOperation", 9usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).SetRequestedOperation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OperationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "OperationCompleted", 10usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).OperationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOperationCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "RemoveOperationCompleted", 11usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).RemoveOperationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Destroyed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "Destroyed", 12usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).Destroyed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDestroyed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "RemoveDestroyed", 13usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).RemoveDestroyed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, formatid: core::mem::MaybeUninit<windows_core::HSTRING>, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "SetData", 14usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).SetData)(this, formatid, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataProvider(this: *mut core::ffi::c_void, formatid: core::mem::MaybeUninit<windows_core::HSTRING>, delayrenderer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "SetDataProvider", 15usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).SetDataProvider)(this, formatid, delayrenderer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "SetText", 16usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "SetUri", 17usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).SetUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHtmlFormat(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "SetHtmlFormat", 18usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).SetHtmlFormat)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn ResourceMap(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "ResourceMap", 19usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).ResourceMap)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRtf(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "SetRtf", 20usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).SetRtf)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetBitmap(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "SetBitmap", 21usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).SetBitmap)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn SetStorageItemsReadOnly(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "SetStorageItemsReadOnly", 22usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).SetStorageItemsReadOnly)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn SetStorageItems(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, readonly: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage", "SetStorageItems", 23usize, |this| ((**(this as *const *const IDataPackage_Vtbl)).SetStorageItems)(this, value, readonly), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetView,
+            Properties,
+            RequestedOperation,
+            SetRequestedOperation,
+            OperationCompleted,
+            RemoveOperationCompleted,
+            Destroyed,
+            RemoveDestroyed,
+            SetData,
+            SetDataProvider,
+            SetText,
+            #[cfg(feature = "deprecated")]
+            SetUri,
+            #[cfg(not(feature = "deprecated"))]
+            SetUri: 0,
+            SetHtmlFormat,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            ResourceMap,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            ResourceMap: 0,
+            SetRtf,
+            #[cfg(feature = "Storage_Streams")]
+            SetBitmap,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetBitmap: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            SetStorageItemsReadOnly,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            SetStorageItemsReadOnly: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            SetStorageItems,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            SetStorageItems: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataPackage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetApplicationLink(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage2", "SetApplicationLink", 6usize, |this| ((**(this as *const *const IDataPackage2_Vtbl)).SetApplicationLink)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWebLink(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage2", "SetWebLink", 7usize, |this| ((**(this as *const *const IDataPackage2_Vtbl)).SetWebLink)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetApplicationLink, SetWebLink }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackage2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataPackage3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShareCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage3", "ShareCompleted", 6usize, |this| ((**(this as *const *const IDataPackage3_Vtbl)).ShareCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveShareCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage3", "RemoveShareCompleted", 7usize, |this| ((**(this as *const *const IDataPackage3_Vtbl)).RemoveShareCompleted)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShareCompleted, RemoveShareCompleted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackage3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataPackage4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShareCanceled(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage4", "ShareCanceled", 6usize, |this| ((**(this as *const *const IDataPackage4_Vtbl)).ShareCanceled)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveShareCanceled(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackage4", "RemoveShareCanceled", 7usize, |this| ((**(this as *const *const IDataPackage4_Vtbl)).RemoveShareCanceled)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShareCanceled, RemoveShareCanceled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackage4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IDataPackagePropertySet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet", "Title", 6usize, |this| ((**(this as *const *const IDataPackagePropertySet_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet", "SetTitle", 7usize, |this| ((**(this as *const *const IDataPackagePropertySet_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet", "Description", 8usize, |this| ((**(this as *const *const IDataPackagePropertySet_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet", "SetDescription", 9usize, |this| ((**(this as *const *const IDataPackagePropertySet_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet", "Thumbnail", 10usize, |this| ((**(this as *const *const IDataPackagePropertySet_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetThumbnail(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet", "SetThumbnail", 11usize, |this| ((**(this as *const *const IDataPackagePropertySet_Vtbl)).SetThumbnail)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FileTypes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet", "FileTypes", 12usize, |this| ((**(this as *const *const IDataPackagePropertySet_Vtbl)).FileTypes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet", "ApplicationName", 13usize, |this| ((**(this as *const *const IDataPackagePropertySet_Vtbl)).ApplicationName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet", "SetApplicationName", 14usize, |this| ((**(this as *const *const IDataPackagePropertySet_Vtbl)).SetApplicationName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationListingUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet", "ApplicationListingUri", 15usize, |this| ((**(this as *const *const IDataPackagePropertySet_Vtbl)).ApplicationListingUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationListingUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet", "SetApplicationListingUri", 16usize, |this| ((**(this as *const *const IDataPackagePropertySet_Vtbl)).SetApplicationListingUri)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Title,
+            SetTitle,
+            Description,
+            SetDescription,
+            #[cfg(feature = "Storage_Streams")]
+            Thumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Thumbnail: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetThumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetThumbnail: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FileTypes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FileTypes: 0,
+            ApplicationName,
+            SetApplicationName,
+            ApplicationListingUri,
+            SetApplicationListingUri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackagePropertySet as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_Streams", feature = "UI"))]
+impl windows_core::imp::ProxyVtable for IDataPackagePropertySet2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContentSourceWebLink(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet2", "ContentSourceWebLink", 6usize, |this| ((**(this as *const *const IDataPackagePropertySet2_Vtbl)).ContentSourceWebLink)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentSourceWebLink(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet2", "SetContentSourceWebLink", 7usize, |this| ((**(this as *const *const IDataPackagePropertySet2_Vtbl)).SetContentSourceWebLink)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentSourceApplicationLink(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet2", "ContentSourceApplicationLink", 8usize, |this| ((**(this as *const *const IDataPackagePropertySet2_Vtbl)).ContentSourceApplicationLink)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentSourceApplicationLink(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet2", "SetContentSourceApplicationLink", 9usize, |this| ((**(this as *const *const IDataPackagePropertySet2_Vtbl)).SetContentSourceApplicationLink)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet2", "PackageFamilyName", 10usize, |this| ((**(this as *const *const IDataPackagePropertySet2_Vtbl)).PackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPackageFamilyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet2", "SetPackageFamilyName", 11usize, |this| ((**(this as *const *const IDataPackagePropertySet2_Vtbl)).SetPackageFamilyName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Square30x30Logo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet2", "Square30x30Logo", 12usize, |this| ((**(this as *const *const IDataPackagePropertySet2_Vtbl)).Square30x30Logo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetSquare30x30Logo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet2", "SetSquare30x30Logo", 13usize, |this| ((**(this as *const *const IDataPackagePropertySet2_Vtbl)).SetSquare30x30Logo)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn LogoBackgroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet2", "LogoBackgroundColor", 14usize, |this| ((**(this as *const *const IDataPackagePropertySet2_Vtbl)).LogoBackgroundColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetLogoBackgroundColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet2", "SetLogoBackgroundColor", 15usize, |this| ((**(this as *const *const IDataPackagePropertySet2_Vtbl)).SetLogoBackgroundColor)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ContentSourceWebLink,
+            SetContentSourceWebLink,
+            ContentSourceApplicationLink,
+            SetContentSourceApplicationLink,
+            PackageFamilyName,
+            SetPackageFamilyName,
+            #[cfg(feature = "Storage_Streams")]
+            Square30x30Logo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Square30x30Logo: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetSquare30x30Logo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetSquare30x30Logo: 0,
+            #[cfg(feature = "UI")]
+            LogoBackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            LogoBackgroundColor: 0,
+            #[cfg(feature = "UI")]
+            SetLogoBackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            SetLogoBackgroundColor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackagePropertySet2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataPackagePropertySet3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnterpriseId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet3", "EnterpriseId", 6usize, |this| ((**(this as *const *const IDataPackagePropertySet3_Vtbl)).EnterpriseId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnterpriseId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet3", "SetEnterpriseId", 7usize, |this| ((**(this as *const *const IDataPackagePropertySet3_Vtbl)).SetEnterpriseId)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnterpriseId, SetEnterpriseId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackagePropertySet3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataPackagePropertySet4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContentSourceUserActivityJson(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet4", "ContentSourceUserActivityJson", 6usize, |this| ((**(this as *const *const IDataPackagePropertySet4_Vtbl)).ContentSourceUserActivityJson)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentSourceUserActivityJson(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySet4", "SetContentSourceUserActivityJson", 7usize, |this| ((**(this as *const *const IDataPackagePropertySet4_Vtbl)).SetContentSourceUserActivityJson)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ContentSourceUserActivityJson,
+            SetContentSourceUserActivityJson,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackagePropertySet4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IDataPackagePropertySetView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySetView", "Title", 6usize, |this| ((**(this as *const *const IDataPackagePropertySetView_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySetView", "Description", 7usize, |this| ((**(this as *const *const IDataPackagePropertySetView_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySetView", "Thumbnail", 8usize, |this| ((**(this as *const *const IDataPackagePropertySetView_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FileTypes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySetView", "FileTypes", 9usize, |this| ((**(this as *const *const IDataPackagePropertySetView_Vtbl)).FileTypes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySetView", "ApplicationName", 10usize, |this| ((**(this as *const *const IDataPackagePropertySetView_Vtbl)).ApplicationName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationListingUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySetView", "ApplicationListingUri", 11usize, |this| ((**(this as *const *const IDataPackagePropertySetView_Vtbl)).ApplicationListingUri)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Title,
+            Description,
+            #[cfg(feature = "Storage_Streams")]
+            Thumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Thumbnail: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FileTypes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FileTypes: 0,
+            ApplicationName,
+            ApplicationListingUri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackagePropertySetView as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_Streams", feature = "UI"))]
+impl windows_core::imp::ProxyVtable for IDataPackagePropertySetView2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySetView2", "PackageFamilyName", 6usize, |this| ((**(this as *const *const IDataPackagePropertySetView2_Vtbl)).PackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentSourceWebLink(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySetView2", "ContentSourceWebLink", 7usize, |this| ((**(this as *const *const IDataPackagePropertySetView2_Vtbl)).ContentSourceWebLink)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentSourceApplicationLink(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySetView2", "ContentSourceApplicationLink", 8usize, |this| ((**(this as *const *const IDataPackagePropertySetView2_Vtbl)).ContentSourceApplicationLink)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Square30x30Logo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySetView2", "Square30x30Logo", 9usize, |this| ((**(this as *const *const IDataPackagePropertySetView2_Vtbl)).Square30x30Logo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn LogoBackgroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySetView2", "LogoBackgroundColor", 10usize, |this| ((**(this as *const *const IDataPackagePropertySetView2_Vtbl)).LogoBackgroundColor)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PackageFamilyName,
+            ContentSourceWebLink,
+            ContentSourceApplicationLink,
+            #[cfg(feature = "Storage_Streams")]
+            Square30x30Logo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Square30x30Logo: 0,
+            #[cfg(feature = "UI")]
+            LogoBackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            LogoBackgroundColor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackagePropertySetView2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataPackagePropertySetView3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnterpriseId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySetView3", "EnterpriseId", 6usize, |this| ((**(this as *const *const IDataPackagePropertySetView3_Vtbl)).EnterpriseId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnterpriseId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackagePropertySetView3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataPackagePropertySetView4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContentSourceUserActivityJson(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySetView4", "ContentSourceUserActivityJson", 6usize, |this| ((**(this as *const *const IDataPackagePropertySetView4_Vtbl)).ContentSourceUserActivityJson)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContentSourceUserActivityJson }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackagePropertySetView4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataPackagePropertySetView5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsFromRoamingClipboard(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackagePropertySetView5", "IsFromRoamingClipboard", 6usize, |this| ((**(this as *const *const IDataPackagePropertySetView5_Vtbl)).IsFromRoamingClipboard)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsFromRoamingClipboard }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackagePropertySetView5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IDataPackageView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView", "Properties", 6usize, |this| ((**(this as *const *const IDataPackageView_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestedOperation(this: *mut core::ffi::c_void, result__: *mut DataPackageOperation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView", "RequestedOperation", 7usize, |this| ((**(this as *const *const IDataPackageView_Vtbl)).RequestedOperation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportOperationCompleted(this: *mut core::ffi::c_void, value: DataPackageOperation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView", "ReportOperationCompleted", 8usize, |this| ((**(this as *const *const IDataPackageView_Vtbl)).ReportOperationCompleted)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AvailableFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView", "AvailableFormats", 9usize, |this| ((**(this as *const *const IDataPackageView_Vtbl)).AvailableFormats)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Contains(this: *mut core::ffi::c_void, formatid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView", "Contains", 10usize, |this| ((**(this as *const *const IDataPackageView_Vtbl)).Contains)(this, formatid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataAsync(this: *mut core::ffi::c_void, formatid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView", "GetDataAsync", 11usize, |this| ((**(this as *const *const IDataPackageView_Vtbl)).GetDataAsync)(this, formatid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView", "GetTextAsync", 12usize, |this| ((**(this as *const *const IDataPackageView_Vtbl)).GetTextAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCustomTextAsync(this: *mut core::ffi::c_void, formatid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView", "GetCustomTextAsync", 13usize, |this| ((**(this as *const *const IDataPackageView_Vtbl)).GetCustomTextAsync)(this, formatid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetUriAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView", "GetUriAsync", 14usize, |this| ((**(this as *const *const IDataPackageView_Vtbl)).GetUriAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHtmlFormatAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView", "GetHtmlFormatAsync", 15usize, |this| ((**(this as *const *const IDataPackageView_Vtbl)).GetHtmlFormatAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GetResourceMapAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView", "GetResourceMapAsync", 16usize, |this| ((**(this as *const *const IDataPackageView_Vtbl)).GetResourceMapAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRtfAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView", "GetRtfAsync", 17usize, |this| ((**(this as *const *const IDataPackageView_Vtbl)).GetRtfAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetBitmapAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView", "GetBitmapAsync", 18usize, |this| ((**(this as *const *const IDataPackageView_Vtbl)).GetBitmapAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn GetStorageItemsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView", "GetStorageItemsAsync", 19usize, |this| ((**(this as *const *const IDataPackageView_Vtbl)).GetStorageItemsAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Properties,
+            RequestedOperation,
+            ReportOperationCompleted,
+            #[cfg(feature = "Foundation_Collections")]
+            AvailableFormats,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AvailableFormats: 0,
+            Contains,
+            GetDataAsync,
+            GetTextAsync,
+            GetCustomTextAsync,
+            #[cfg(feature = "deprecated")]
+            GetUriAsync,
+            #[cfg(not(feature = "deprecated"))]
+            GetUriAsync: 0,
+            GetHtmlFormatAsync,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            GetResourceMapAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            GetResourceMapAsync: 0,
+            GetRtfAsync,
+            #[cfg(feature = "Storage_Streams")]
+            GetBitmapAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetBitmapAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            GetStorageItemsAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            GetStorageItemsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackageView as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataPackageView2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetApplicationLinkAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView2", "GetApplicationLinkAsync", 6usize, |this| ((**(this as *const *const IDataPackageView2_Vtbl)).GetApplicationLinkAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWebLinkAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView2", "GetWebLinkAsync", 7usize, |this| ((**(this as *const *const IDataPackageView2_Vtbl)).GetWebLinkAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetApplicationLinkAsync, GetWebLinkAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackageView2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_EnterpriseData")]
+impl windows_core::imp::ProxyVtable for IDataPackageView3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_EnterpriseData")]
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView3", "RequestAccessAsync", 6usize, |this| ((**(this as *const *const IDataPackageView3_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_EnterpriseData")]
+        unsafe extern "system" fn RequestAccessWithEnterpriseIdAsync(this: *mut core::ffi::c_void, enterpriseid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView3", "RequestAccessWithEnterpriseIdAsync", 7usize, |this| ((**(this as *const *const IDataPackageView3_Vtbl)).RequestAccessWithEnterpriseIdAsync)(this, enterpriseid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_EnterpriseData")]
+        unsafe extern "system" fn UnlockAndAssumeEnterpriseIdentity(this: *mut core::ffi::c_void, result__: *mut super::super::Security::EnterpriseData::ProtectionPolicyEvaluationResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView3", "UnlockAndAssumeEnterpriseIdentity", 8usize, |this| ((**(this as *const *const IDataPackageView3_Vtbl)).UnlockAndAssumeEnterpriseIdentity)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_EnterpriseData")]
+            RequestAccessAsync,
+            #[cfg(not(feature = "Security_EnterpriseData"))]
+            RequestAccessAsync: 0,
+            #[cfg(feature = "Security_EnterpriseData")]
+            RequestAccessWithEnterpriseIdAsync,
+            #[cfg(not(feature = "Security_EnterpriseData"))]
+            RequestAccessWithEnterpriseIdAsync: 0,
+            #[cfg(feature = "Security_EnterpriseData")]
+            UnlockAndAssumeEnterpriseIdentity,
+            #[cfg(not(feature = "Security_EnterpriseData"))]
+            UnlockAndAssumeEnterpriseIdentity: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackageView3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataPackageView4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAcceptedFormatId(this: *mut core::ffi::c_void, formatid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPackageView4", "SetAcceptedFormatId", 6usize, |this| ((**(this as *const *const IDataPackageView4_Vtbl)).SetAcceptedFormatId)(this, formatid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAcceptedFormatId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPackageView4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataProviderDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProviderDeferral", "Complete", 6usize, |this| ((**(this as *const *const IDataProviderDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataProviderDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataProviderRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FormatId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProviderRequest", "FormatId", 6usize, |this| ((**(this as *const *const IDataProviderRequest_Vtbl)).FormatId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProviderRequest", "Deadline", 7usize, |this| ((**(this as *const *const IDataProviderRequest_Vtbl)).Deadline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProviderRequest", "GetDeferral", 8usize, |this| ((**(this as *const *const IDataProviderRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProviderRequest", "SetData", 9usize, |this| ((**(this as *const *const IDataProviderRequest_Vtbl)).SetData)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FormatId, Deadline, GetDeferral, SetData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataProviderRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataRequest", "Data", 6usize, |this| ((**(this as *const *const IDataRequest_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataRequest", "SetData", 7usize, |this| ((**(this as *const *const IDataRequest_Vtbl)).SetData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataRequest", "Deadline", 8usize, |this| ((**(this as *const *const IDataRequest_Vtbl)).Deadline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FailWithDisplayText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataRequest", "FailWithDisplayText", 9usize, |this| ((**(this as *const *const IDataRequest_Vtbl)).FailWithDisplayText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataRequest", "GetDeferral", 10usize, |this| ((**(this as *const *const IDataRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Data, SetData, Deadline, FailWithDisplayText, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataRequestDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataRequestDeferral", "Complete", 6usize, |this| ((**(this as *const *const IDataRequestDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataRequestDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const IDataRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataTransferManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DataRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataTransferManager", "DataRequested", 6usize, |this| ((**(this as *const *const IDataTransferManager_Vtbl)).DataRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDataRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataTransferManager", "RemoveDataRequested", 7usize, |this| ((**(this as *const *const IDataTransferManager_Vtbl)).RemoveDataRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetApplicationChosen(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataTransferManager", "TargetApplicationChosen", 8usize, |this| ((**(this as *const *const IDataTransferManager_Vtbl)).TargetApplicationChosen)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTargetApplicationChosen(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataTransferManager", "RemoveTargetApplicationChosen", 9usize, |this| ((**(this as *const *const IDataTransferManager_Vtbl)).RemoveTargetApplicationChosen)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DataRequested,
+            RemoveDataRequested,
+            TargetApplicationChosen,
+            RemoveTargetApplicationChosen,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataTransferManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataTransferManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShareProvidersRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataTransferManager2", "ShareProvidersRequested", 6usize, |this| ((**(this as *const *const IDataTransferManager2_Vtbl)).ShareProvidersRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveShareProvidersRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataTransferManager2", "RemoveShareProvidersRequested", 7usize, |this| ((**(this as *const *const IDataTransferManager2_Vtbl)).RemoveShareProvidersRequested)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShareProvidersRequested, RemoveShareProvidersRequested }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataTransferManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataTransferManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowShareUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataTransferManagerStatics", "ShowShareUI", 6usize, |this| ((**(this as *const *const IDataTransferManagerStatics_Vtbl)).ShowShareUI)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataTransferManagerStatics", "GetForCurrentView", 7usize, |this| ((**(this as *const *const IDataTransferManagerStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowShareUI, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataTransferManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataTransferManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataTransferManagerStatics2", "IsSupported", 6usize, |this| ((**(this as *const *const IDataTransferManagerStatics2_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataTransferManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataTransferManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowShareUIWithOptions(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataTransferManagerStatics3", "ShowShareUIWithOptions", 6usize, |this| ((**(this as *const *const IDataTransferManagerStatics3_Vtbl)).ShowShareUIWithOptions)(this, options), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowShareUIWithOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataTransferManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHtmlFormatHelperStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStaticFragment(this: *mut core::ffi::c_void, htmlformat: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlFormatHelperStatics", "GetStaticFragment", 6usize, |this| ((**(this as *const *const IHtmlFormatHelperStatics_Vtbl)).GetStaticFragment)(this, htmlformat, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateHtmlFormat(this: *mut core::ffi::c_void, htmlfragment: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlFormatHelperStatics", "CreateHtmlFormat", 7usize, |this| ((**(this as *const *const IHtmlFormatHelperStatics_Vtbl)).CreateHtmlFormat)(this, htmlfragment, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStaticFragment, CreateHtmlFormat }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHtmlFormatHelperStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOperationCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Operation(this: *mut core::ffi::c_void, result__: *mut DataPackageOperation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOperationCompletedEventArgs", "Operation", 6usize, |this| ((**(this as *const *const IOperationCompletedEventArgs_Vtbl)).Operation)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Operation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOperationCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOperationCompletedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcceptedFormatId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOperationCompletedEventArgs2", "AcceptedFormatId", 6usize, |this| ((**(this as *const *const IOperationCompletedEventArgs2_Vtbl)).AcceptedFormatId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AcceptedFormatId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOperationCompletedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IShareCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShareTarget(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareCompletedEventArgs", "ShareTarget", 6usize, |this| ((**(this as *const *const IShareCompletedEventArgs_Vtbl)).ShareTarget)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShareTarget }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_Streams", feature = "UI"))]
+impl windows_core::imp::ProxyVtable for IShareProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareProvider", "Title", 6usize, |this| ((**(this as *const *const IShareProvider_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn DisplayIcon(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareProvider", "DisplayIcon", 7usize, |this| ((**(this as *const *const IShareProvider_Vtbl)).DisplayIcon)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn BackgroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareProvider", "BackgroundColor", 8usize, |this| ((**(this as *const *const IShareProvider_Vtbl)).BackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tag(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareProvider", "Tag", 9usize, |this| ((**(this as *const *const IShareProvider_Vtbl)).Tag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareProvider", "SetTag", 10usize, |this| ((**(this as *const *const IShareProvider_Vtbl)).SetTag)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Title,
+            #[cfg(feature = "Storage_Streams")]
+            DisplayIcon,
+            #[cfg(not(feature = "Storage_Streams"))]
+            DisplayIcon: 0,
+            #[cfg(feature = "UI")]
+            BackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            BackgroundColor: 0,
+            Tag,
+            SetTag,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_Streams", feature = "UI"))]
+impl windows_core::imp::ProxyVtable for IShareProviderFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Storage_Streams", feature = "UI"))]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, title: core::mem::MaybeUninit<windows_core::HSTRING>, displayicon: *mut core::ffi::c_void, backgroundcolor: super::super::UI::Color, handler: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareProviderFactory", "Create", 6usize, |this| ((**(this as *const *const IShareProviderFactory_Vtbl)).Create)(this, title, displayicon, backgroundcolor, handler, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Storage_Streams", feature = "UI"))]
+            Create,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "UI")))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareProviderFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IShareProviderOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareProviderOperation", "Data", 6usize, |this| ((**(this as *const *const IShareProviderOperation_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Provider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareProviderOperation", "Provider", 7usize, |this| ((**(this as *const *const IShareProviderOperation_Vtbl)).Provider)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompleted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareProviderOperation", "ReportCompleted", 8usize, |this| ((**(this as *const *const IShareProviderOperation_Vtbl)).ReportCompleted)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Data, Provider, ReportCompleted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareProviderOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IShareProvidersRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Providers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareProvidersRequestedEventArgs", "Providers", 6usize, |this| ((**(this as *const *const IShareProvidersRequestedEventArgs_Vtbl)).Providers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareProvidersRequestedEventArgs", "Data", 7usize, |this| ((**(this as *const *const IShareProvidersRequestedEventArgs_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareProvidersRequestedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IShareProvidersRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Providers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Providers: 0,
+            Data,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareProvidersRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IShareTargetInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppUserModelId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareTargetInfo", "AppUserModelId", 6usize, |this| ((**(this as *const *const IShareTargetInfo_Vtbl)).AppUserModelId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShareProvider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareTargetInfo", "ShareProvider", 7usize, |this| ((**(this as *const *const IShareTargetInfo_Vtbl)).ShareProvider)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AppUserModelId, ShareProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareTargetInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IShareUIOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Theme(this: *mut core::ffi::c_void, result__: *mut ShareUITheme) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareUIOptions", "Theme", 6usize, |this| ((**(this as *const *const IShareUIOptions_Vtbl)).Theme)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTheme(this: *mut core::ffi::c_void, value: ShareUITheme) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareUIOptions", "SetTheme", 7usize, |this| ((**(this as *const *const IShareUIOptions_Vtbl)).SetTheme)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectionRect(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareUIOptions", "SelectionRect", 8usize, |this| ((**(this as *const *const IShareUIOptions_Vtbl)).SelectionRect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelectionRect(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareUIOptions", "SetSelectionRect", 9usize, |this| ((**(this as *const *const IShareUIOptions_Vtbl)).SetSelectionRect)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Theme, SetTheme, SelectionRect, SetSelectionRect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareUIOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for ISharedStorageAccessManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn AddFile(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedStorageAccessManagerStatics", "AddFile", 6usize, |this| ((**(this as *const *const ISharedStorageAccessManagerStatics_Vtbl)).AddFile)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn RedeemTokenForFileAsync(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedStorageAccessManagerStatics", "RedeemTokenForFileAsync", 7usize, |this| ((**(this as *const *const ISharedStorageAccessManagerStatics_Vtbl)).RedeemTokenForFileAsync)(this, token, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFile(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedStorageAccessManagerStatics", "RemoveFile", 8usize, |this| ((**(this as *const *const ISharedStorageAccessManagerStatics_Vtbl)).RemoveFile)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            AddFile,
+            #[cfg(not(feature = "Storage"))]
+            AddFile: 0,
+            #[cfg(feature = "Storage")]
+            RedeemTokenForFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            RedeemTokenForFileAsync: 0,
+            RemoveFile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISharedStorageAccessManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStandardDataFormatsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardDataFormatsStatics", "Text", 6usize, |this| ((**(this as *const *const IStandardDataFormatsStatics_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardDataFormatsStatics", "Uri", 7usize, |this| ((**(this as *const *const IStandardDataFormatsStatics_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Html(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardDataFormatsStatics", "Html", 8usize, |this| ((**(this as *const *const IStandardDataFormatsStatics_Vtbl)).Html)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rtf(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardDataFormatsStatics", "Rtf", 9usize, |this| ((**(this as *const *const IStandardDataFormatsStatics_Vtbl)).Rtf)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bitmap(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardDataFormatsStatics", "Bitmap", 10usize, |this| ((**(this as *const *const IStandardDataFormatsStatics_Vtbl)).Bitmap)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StorageItems(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardDataFormatsStatics", "StorageItems", 11usize, |this| ((**(this as *const *const IStandardDataFormatsStatics_Vtbl)).StorageItems)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Text,
+            #[cfg(feature = "deprecated")]
+            Uri,
+            #[cfg(not(feature = "deprecated"))]
+            Uri: 0,
+            Html,
+            Rtf,
+            Bitmap,
+            StorageItems,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStandardDataFormatsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStandardDataFormatsStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WebLink(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardDataFormatsStatics2", "WebLink", 6usize, |this| ((**(this as *const *const IStandardDataFormatsStatics2_Vtbl)).WebLink)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationLink(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardDataFormatsStatics2", "ApplicationLink", 7usize, |this| ((**(this as *const *const IStandardDataFormatsStatics2_Vtbl)).ApplicationLink)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WebLink, ApplicationLink }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStandardDataFormatsStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStandardDataFormatsStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserActivityJsonArray(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardDataFormatsStatics3", "UserActivityJsonArray", 6usize, |this| ((**(this as *const *const IStandardDataFormatsStatics3_Vtbl)).UserActivityJsonArray)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserActivityJsonArray }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStandardDataFormatsStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetApplicationChosenEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ApplicationName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetApplicationChosenEventArgs", "ApplicationName", 6usize, |this| ((**(this as *const *const ITargetApplicationChosenEventArgs_Vtbl)).ApplicationName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ApplicationName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetApplicationChosenEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
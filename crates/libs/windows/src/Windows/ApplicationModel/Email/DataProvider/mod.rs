@@ -1998,3 +1998,5 @@ impl windows_core::RuntimeName for EmailMailboxValidateCertificatesRequestEventA
 }
 unsafe impl Send for EmailMailboxValidateCertificatesRequestEventArgs {}
 unsafe impl Sync for EmailMailboxValidateCertificatesRequestEventArgs {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
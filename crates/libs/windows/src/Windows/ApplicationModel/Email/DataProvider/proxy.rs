@@ -0,0 +1,875 @@
+impl windows_core::imp::ProxyVtable for IEmailDataProviderConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MailboxSyncRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "MailboxSyncRequested", 6usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).MailboxSyncRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMailboxSyncRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "RemoveMailboxSyncRequested", 7usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).RemoveMailboxSyncRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadMessageRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "DownloadMessageRequested", 8usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).DownloadMessageRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDownloadMessageRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "RemoveDownloadMessageRequested", 9usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).RemoveDownloadMessageRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadAttachmentRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "DownloadAttachmentRequested", 10usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).DownloadAttachmentRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDownloadAttachmentRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "RemoveDownloadAttachmentRequested", 11usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).RemoveDownloadAttachmentRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFolderRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "CreateFolderRequested", 12usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).CreateFolderRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCreateFolderRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "RemoveCreateFolderRequested", 13usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).RemoveCreateFolderRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteFolderRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "DeleteFolderRequested", 14usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).DeleteFolderRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDeleteFolderRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "RemoveDeleteFolderRequested", 15usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).RemoveDeleteFolderRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmptyFolderRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "EmptyFolderRequested", 16usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).EmptyFolderRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEmptyFolderRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "RemoveEmptyFolderRequested", 17usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).RemoveEmptyFolderRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveFolderRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "MoveFolderRequested", 18usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).MoveFolderRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMoveFolderRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "RemoveMoveFolderRequested", 19usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).RemoveMoveFolderRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateMeetingResponseRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "UpdateMeetingResponseRequested", 20usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).UpdateMeetingResponseRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUpdateMeetingResponseRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "RemoveUpdateMeetingResponseRequested", 21usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).RemoveUpdateMeetingResponseRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForwardMeetingRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "ForwardMeetingRequested", 22usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).ForwardMeetingRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveForwardMeetingRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "RemoveForwardMeetingRequested", 23usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).RemoveForwardMeetingRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProposeNewTimeForMeetingRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "ProposeNewTimeForMeetingRequested", 24usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).ProposeNewTimeForMeetingRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProposeNewTimeForMeetingRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "RemoveProposeNewTimeForMeetingRequested", 25usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).RemoveProposeNewTimeForMeetingRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoReplySettingsRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "SetAutoReplySettingsRequested", 26usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).SetAutoReplySettingsRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSetAutoReplySettingsRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "RemoveSetAutoReplySettingsRequested", 27usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).RemoveSetAutoReplySettingsRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAutoReplySettingsRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "GetAutoReplySettingsRequested", 28usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).GetAutoReplySettingsRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGetAutoReplySettingsRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "RemoveGetAutoReplySettingsRequested", 29usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).RemoveGetAutoReplySettingsRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolveRecipientsRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "ResolveRecipientsRequested", 30usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).ResolveRecipientsRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveResolveRecipientsRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "RemoveResolveRecipientsRequested", 31usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).RemoveResolveRecipientsRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateCertificatesRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "ValidateCertificatesRequested", 32usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).ValidateCertificatesRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveValidateCertificatesRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "RemoveValidateCertificatesRequested", 33usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).RemoveValidateCertificatesRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerSearchReadBatchRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "ServerSearchReadBatchRequested", 34usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).ServerSearchReadBatchRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveServerSearchReadBatchRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "RemoveServerSearchReadBatchRequested", 35usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).RemoveServerSearchReadBatchRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderConnection", "Start", 36usize, |this| ((**(this as *const *const IEmailDataProviderConnection_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MailboxSyncRequested,
+            RemoveMailboxSyncRequested,
+            DownloadMessageRequested,
+            RemoveDownloadMessageRequested,
+            DownloadAttachmentRequested,
+            RemoveDownloadAttachmentRequested,
+            CreateFolderRequested,
+            RemoveCreateFolderRequested,
+            DeleteFolderRequested,
+            RemoveDeleteFolderRequested,
+            EmptyFolderRequested,
+            RemoveEmptyFolderRequested,
+            MoveFolderRequested,
+            RemoveMoveFolderRequested,
+            UpdateMeetingResponseRequested,
+            RemoveUpdateMeetingResponseRequested,
+            ForwardMeetingRequested,
+            RemoveForwardMeetingRequested,
+            ProposeNewTimeForMeetingRequested,
+            RemoveProposeNewTimeForMeetingRequested,
+            SetAutoReplySettingsRequested,
+            RemoveSetAutoReplySettingsRequested,
+            GetAutoReplySettingsRequested,
+            RemoveGetAutoReplySettingsRequested,
+            ResolveRecipientsRequested,
+            RemoveResolveRecipientsRequested,
+            ValidateCertificatesRequested,
+            RemoveValidateCertificatesRequested,
+            ServerSearchReadBatchRequested,
+            RemoveServerSearchReadBatchRequested,
+            Start,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailDataProviderConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailDataProviderTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailDataProviderTriggerDetails", "Connection", 6usize, |this| ((**(this as *const *const IEmailDataProviderTriggerDetails_Vtbl)).Connection)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Connection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailDataProviderTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxCreateFolderRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmailMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCreateFolderRequest", "EmailMailboxId", 6usize, |this| ((**(this as *const *const IEmailMailboxCreateFolderRequest_Vtbl)).EmailMailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentFolderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCreateFolderRequest", "ParentFolderId", 7usize, |this| ((**(this as *const *const IEmailMailboxCreateFolderRequest_Vtbl)).ParentFolderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCreateFolderRequest", "Name", 8usize, |this| ((**(this as *const *const IEmailMailboxCreateFolderRequest_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, folder: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCreateFolderRequest", "ReportCompletedAsync", 9usize, |this| ((**(this as *const *const IEmailMailboxCreateFolderRequest_Vtbl)).ReportCompletedAsync)(this, folder, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, status: super::EmailMailboxCreateFolderStatus, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCreateFolderRequest", "ReportFailedAsync", 10usize, |this| ((**(this as *const *const IEmailMailboxCreateFolderRequest_Vtbl)).ReportFailedAsync)(this, status, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmailMailboxId,
+            ParentFolderId,
+            Name,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxCreateFolderRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxCreateFolderRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCreateFolderRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IEmailMailboxCreateFolderRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCreateFolderRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IEmailMailboxCreateFolderRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxCreateFolderRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxDeleteFolderRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmailMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDeleteFolderRequest", "EmailMailboxId", 6usize, |this| ((**(this as *const *const IEmailMailboxDeleteFolderRequest_Vtbl)).EmailMailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmailFolderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDeleteFolderRequest", "EmailFolderId", 7usize, |this| ((**(this as *const *const IEmailMailboxDeleteFolderRequest_Vtbl)).EmailFolderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDeleteFolderRequest", "ReportCompletedAsync", 8usize, |this| ((**(this as *const *const IEmailMailboxDeleteFolderRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, status: super::EmailMailboxDeleteFolderStatus, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDeleteFolderRequest", "ReportFailedAsync", 9usize, |this| ((**(this as *const *const IEmailMailboxDeleteFolderRequest_Vtbl)).ReportFailedAsync)(this, status, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmailMailboxId,
+            EmailFolderId,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxDeleteFolderRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxDeleteFolderRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDeleteFolderRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IEmailMailboxDeleteFolderRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDeleteFolderRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IEmailMailboxDeleteFolderRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxDeleteFolderRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxDownloadAttachmentRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmailMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDownloadAttachmentRequest", "EmailMailboxId", 6usize, |this| ((**(this as *const *const IEmailMailboxDownloadAttachmentRequest_Vtbl)).EmailMailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmailMessageId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDownloadAttachmentRequest", "EmailMessageId", 7usize, |this| ((**(this as *const *const IEmailMailboxDownloadAttachmentRequest_Vtbl)).EmailMessageId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmailAttachmentId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDownloadAttachmentRequest", "EmailAttachmentId", 8usize, |this| ((**(this as *const *const IEmailMailboxDownloadAttachmentRequest_Vtbl)).EmailAttachmentId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDownloadAttachmentRequest", "ReportCompletedAsync", 9usize, |this| ((**(this as *const *const IEmailMailboxDownloadAttachmentRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDownloadAttachmentRequest", "ReportFailedAsync", 10usize, |this| ((**(this as *const *const IEmailMailboxDownloadAttachmentRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmailMailboxId,
+            EmailMessageId,
+            EmailAttachmentId,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxDownloadAttachmentRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxDownloadAttachmentRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDownloadAttachmentRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IEmailMailboxDownloadAttachmentRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDownloadAttachmentRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IEmailMailboxDownloadAttachmentRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxDownloadAttachmentRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxDownloadMessageRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmailMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDownloadMessageRequest", "EmailMailboxId", 6usize, |this| ((**(this as *const *const IEmailMailboxDownloadMessageRequest_Vtbl)).EmailMailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmailMessageId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDownloadMessageRequest", "EmailMessageId", 7usize, |this| ((**(this as *const *const IEmailMailboxDownloadMessageRequest_Vtbl)).EmailMessageId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDownloadMessageRequest", "ReportCompletedAsync", 8usize, |this| ((**(this as *const *const IEmailMailboxDownloadMessageRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDownloadMessageRequest", "ReportFailedAsync", 9usize, |this| ((**(this as *const *const IEmailMailboxDownloadMessageRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmailMailboxId,
+            EmailMessageId,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxDownloadMessageRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxDownloadMessageRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDownloadMessageRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IEmailMailboxDownloadMessageRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxDownloadMessageRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IEmailMailboxDownloadMessageRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxDownloadMessageRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxEmptyFolderRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmailMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxEmptyFolderRequest", "EmailMailboxId", 6usize, |this| ((**(this as *const *const IEmailMailboxEmptyFolderRequest_Vtbl)).EmailMailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmailFolderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxEmptyFolderRequest", "EmailFolderId", 7usize, |this| ((**(this as *const *const IEmailMailboxEmptyFolderRequest_Vtbl)).EmailFolderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxEmptyFolderRequest", "ReportCompletedAsync", 8usize, |this| ((**(this as *const *const IEmailMailboxEmptyFolderRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, status: super::EmailMailboxEmptyFolderStatus, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxEmptyFolderRequest", "ReportFailedAsync", 9usize, |this| ((**(this as *const *const IEmailMailboxEmptyFolderRequest_Vtbl)).ReportFailedAsync)(this, status, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmailMailboxId,
+            EmailFolderId,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxEmptyFolderRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxEmptyFolderRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxEmptyFolderRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IEmailMailboxEmptyFolderRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxEmptyFolderRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IEmailMailboxEmptyFolderRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxEmptyFolderRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEmailMailboxForwardMeetingRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmailMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxForwardMeetingRequest", "EmailMailboxId", 6usize, |this| ((**(this as *const *const IEmailMailboxForwardMeetingRequest_Vtbl)).EmailMailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmailMessageId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxForwardMeetingRequest", "EmailMessageId", 7usize, |this| ((**(this as *const *const IEmailMailboxForwardMeetingRequest_Vtbl)).EmailMessageId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Recipients(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxForwardMeetingRequest", "Recipients", 8usize, |this| ((**(this as *const *const IEmailMailboxForwardMeetingRequest_Vtbl)).Recipients)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxForwardMeetingRequest", "Subject", 9usize, |this| ((**(this as *const *const IEmailMailboxForwardMeetingRequest_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForwardHeaderType(this: *mut core::ffi::c_void, result__: *mut super::EmailMessageBodyKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxForwardMeetingRequest", "ForwardHeaderType", 10usize, |this| ((**(this as *const *const IEmailMailboxForwardMeetingRequest_Vtbl)).ForwardHeaderType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForwardHeader(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxForwardMeetingRequest", "ForwardHeader", 11usize, |this| ((**(this as *const *const IEmailMailboxForwardMeetingRequest_Vtbl)).ForwardHeader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Comment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxForwardMeetingRequest", "Comment", 12usize, |this| ((**(this as *const *const IEmailMailboxForwardMeetingRequest_Vtbl)).Comment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxForwardMeetingRequest", "ReportCompletedAsync", 13usize, |this| ((**(this as *const *const IEmailMailboxForwardMeetingRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxForwardMeetingRequest", "ReportFailedAsync", 14usize, |this| ((**(this as *const *const IEmailMailboxForwardMeetingRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmailMailboxId,
+            EmailMessageId,
+            #[cfg(feature = "Foundation_Collections")]
+            Recipients,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Recipients: 0,
+            Subject,
+            ForwardHeaderType,
+            ForwardHeader,
+            Comment,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxForwardMeetingRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxForwardMeetingRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxForwardMeetingRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IEmailMailboxForwardMeetingRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxForwardMeetingRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IEmailMailboxForwardMeetingRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxForwardMeetingRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxGetAutoReplySettingsRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmailMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxGetAutoReplySettingsRequest", "EmailMailboxId", 6usize, |this| ((**(this as *const *const IEmailMailboxGetAutoReplySettingsRequest_Vtbl)).EmailMailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestedFormat(this: *mut core::ffi::c_void, result__: *mut super::EmailMailboxAutoReplyMessageResponseKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxGetAutoReplySettingsRequest", "RequestedFormat", 7usize, |this| ((**(this as *const *const IEmailMailboxGetAutoReplySettingsRequest_Vtbl)).RequestedFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, autoreplysettings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxGetAutoReplySettingsRequest", "ReportCompletedAsync", 8usize, |this| ((**(this as *const *const IEmailMailboxGetAutoReplySettingsRequest_Vtbl)).ReportCompletedAsync)(this, autoreplysettings, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxGetAutoReplySettingsRequest", "ReportFailedAsync", 9usize, |this| ((**(this as *const *const IEmailMailboxGetAutoReplySettingsRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmailMailboxId,
+            RequestedFormat,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxGetAutoReplySettingsRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxGetAutoReplySettingsRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxGetAutoReplySettingsRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IEmailMailboxGetAutoReplySettingsRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxGetAutoReplySettingsRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IEmailMailboxGetAutoReplySettingsRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxGetAutoReplySettingsRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxMoveFolderRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmailMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxMoveFolderRequest", "EmailMailboxId", 6usize, |this| ((**(this as *const *const IEmailMailboxMoveFolderRequest_Vtbl)).EmailMailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmailFolderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxMoveFolderRequest", "EmailFolderId", 7usize, |this| ((**(this as *const *const IEmailMailboxMoveFolderRequest_Vtbl)).EmailFolderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewParentFolderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxMoveFolderRequest", "NewParentFolderId", 8usize, |this| ((**(this as *const *const IEmailMailboxMoveFolderRequest_Vtbl)).NewParentFolderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewFolderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxMoveFolderRequest", "NewFolderName", 9usize, |this| ((**(this as *const *const IEmailMailboxMoveFolderRequest_Vtbl)).NewFolderName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxMoveFolderRequest", "ReportCompletedAsync", 10usize, |this| ((**(this as *const *const IEmailMailboxMoveFolderRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxMoveFolderRequest", "ReportFailedAsync", 11usize, |this| ((**(this as *const *const IEmailMailboxMoveFolderRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmailMailboxId,
+            EmailFolderId,
+            NewParentFolderId,
+            NewFolderName,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxMoveFolderRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxMoveFolderRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxMoveFolderRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IEmailMailboxMoveFolderRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxMoveFolderRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IEmailMailboxMoveFolderRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxMoveFolderRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxProposeNewTimeForMeetingRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmailMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxProposeNewTimeForMeetingRequest", "EmailMailboxId", 6usize, |this| ((**(this as *const *const IEmailMailboxProposeNewTimeForMeetingRequest_Vtbl)).EmailMailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmailMessageId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxProposeNewTimeForMeetingRequest", "EmailMessageId", 7usize, |this| ((**(this as *const *const IEmailMailboxProposeNewTimeForMeetingRequest_Vtbl)).EmailMessageId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewStartTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxProposeNewTimeForMeetingRequest", "NewStartTime", 8usize, |this| ((**(this as *const *const IEmailMailboxProposeNewTimeForMeetingRequest_Vtbl)).NewStartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewDuration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxProposeNewTimeForMeetingRequest", "NewDuration", 9usize, |this| ((**(this as *const *const IEmailMailboxProposeNewTimeForMeetingRequest_Vtbl)).NewDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxProposeNewTimeForMeetingRequest", "Subject", 10usize, |this| ((**(this as *const *const IEmailMailboxProposeNewTimeForMeetingRequest_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Comment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxProposeNewTimeForMeetingRequest", "Comment", 11usize, |this| ((**(this as *const *const IEmailMailboxProposeNewTimeForMeetingRequest_Vtbl)).Comment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxProposeNewTimeForMeetingRequest", "ReportCompletedAsync", 12usize, |this| ((**(this as *const *const IEmailMailboxProposeNewTimeForMeetingRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxProposeNewTimeForMeetingRequest", "ReportFailedAsync", 13usize, |this| ((**(this as *const *const IEmailMailboxProposeNewTimeForMeetingRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmailMailboxId,
+            EmailMessageId,
+            NewStartTime,
+            NewDuration,
+            Subject,
+            Comment,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxProposeNewTimeForMeetingRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxProposeNewTimeForMeetingRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxProposeNewTimeForMeetingRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IEmailMailboxProposeNewTimeForMeetingRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxProposeNewTimeForMeetingRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IEmailMailboxProposeNewTimeForMeetingRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxProposeNewTimeForMeetingRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEmailMailboxResolveRecipientsRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmailMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxResolveRecipientsRequest", "EmailMailboxId", 6usize, |this| ((**(this as *const *const IEmailMailboxResolveRecipientsRequest_Vtbl)).EmailMailboxId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Recipients(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxResolveRecipientsRequest", "Recipients", 7usize, |this| ((**(this as *const *const IEmailMailboxResolveRecipientsRequest_Vtbl)).Recipients)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, resolutionresults: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxResolveRecipientsRequest", "ReportCompletedAsync", 8usize, |this| ((**(this as *const *const IEmailMailboxResolveRecipientsRequest_Vtbl)).ReportCompletedAsync)(this, resolutionresults, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxResolveRecipientsRequest", "ReportFailedAsync", 9usize, |this| ((**(this as *const *const IEmailMailboxResolveRecipientsRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmailMailboxId,
+            #[cfg(feature = "Foundation_Collections")]
+            Recipients,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Recipients: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ReportCompletedAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReportCompletedAsync: 0,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxResolveRecipientsRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxResolveRecipientsRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxResolveRecipientsRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IEmailMailboxResolveRecipientsRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxResolveRecipientsRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IEmailMailboxResolveRecipientsRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxResolveRecipientsRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxServerSearchReadBatchRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SessionId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxServerSearchReadBatchRequest", "SessionId", 6usize, |this| ((**(this as *const *const IEmailMailboxServerSearchReadBatchRequest_Vtbl)).SessionId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmailMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxServerSearchReadBatchRequest", "EmailMailboxId", 7usize, |this| ((**(this as *const *const IEmailMailboxServerSearchReadBatchRequest_Vtbl)).EmailMailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmailFolderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxServerSearchReadBatchRequest", "EmailFolderId", 8usize, |this| ((**(this as *const *const IEmailMailboxServerSearchReadBatchRequest_Vtbl)).EmailFolderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Options(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxServerSearchReadBatchRequest", "Options", 9usize, |this| ((**(this as *const *const IEmailMailboxServerSearchReadBatchRequest_Vtbl)).Options)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuggestedBatchSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxServerSearchReadBatchRequest", "SuggestedBatchSize", 10usize, |this| ((**(this as *const *const IEmailMailboxServerSearchReadBatchRequest_Vtbl)).SuggestedBatchSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveMessageAsync(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxServerSearchReadBatchRequest", "SaveMessageAsync", 11usize, |this| ((**(this as *const *const IEmailMailboxServerSearchReadBatchRequest_Vtbl)).SaveMessageAsync)(this, message, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxServerSearchReadBatchRequest", "ReportCompletedAsync", 12usize, |this| ((**(this as *const *const IEmailMailboxServerSearchReadBatchRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, batchstatus: super::EmailBatchStatus, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxServerSearchReadBatchRequest", "ReportFailedAsync", 13usize, |this| ((**(this as *const *const IEmailMailboxServerSearchReadBatchRequest_Vtbl)).ReportFailedAsync)(this, batchstatus, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SessionId,
+            EmailMailboxId,
+            EmailFolderId,
+            Options,
+            SuggestedBatchSize,
+            SaveMessageAsync,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxServerSearchReadBatchRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxServerSearchReadBatchRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxServerSearchReadBatchRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IEmailMailboxServerSearchReadBatchRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxServerSearchReadBatchRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IEmailMailboxServerSearchReadBatchRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxServerSearchReadBatchRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxSetAutoReplySettingsRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmailMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSetAutoReplySettingsRequest", "EmailMailboxId", 6usize, |this| ((**(this as *const *const IEmailMailboxSetAutoReplySettingsRequest_Vtbl)).EmailMailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoReplySettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSetAutoReplySettingsRequest", "AutoReplySettings", 7usize, |this| ((**(this as *const *const IEmailMailboxSetAutoReplySettingsRequest_Vtbl)).AutoReplySettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSetAutoReplySettingsRequest", "ReportCompletedAsync", 8usize, |this| ((**(this as *const *const IEmailMailboxSetAutoReplySettingsRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSetAutoReplySettingsRequest", "ReportFailedAsync", 9usize, |this| ((**(this as *const *const IEmailMailboxSetAutoReplySettingsRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmailMailboxId,
+            AutoReplySettings,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxSetAutoReplySettingsRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxSetAutoReplySettingsRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSetAutoReplySettingsRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IEmailMailboxSetAutoReplySettingsRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSetAutoReplySettingsRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IEmailMailboxSetAutoReplySettingsRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxSetAutoReplySettingsRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxSyncManagerSyncRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmailMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSyncManagerSyncRequest", "EmailMailboxId", 6usize, |this| ((**(this as *const *const IEmailMailboxSyncManagerSyncRequest_Vtbl)).EmailMailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSyncManagerSyncRequest", "ReportCompletedAsync", 7usize, |this| ((**(this as *const *const IEmailMailboxSyncManagerSyncRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSyncManagerSyncRequest", "ReportFailedAsync", 8usize, |this| ((**(this as *const *const IEmailMailboxSyncManagerSyncRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EmailMailboxId, ReportCompletedAsync, ReportFailedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxSyncManagerSyncRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxSyncManagerSyncRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSyncManagerSyncRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IEmailMailboxSyncManagerSyncRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSyncManagerSyncRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IEmailMailboxSyncManagerSyncRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxSyncManagerSyncRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxUpdateMeetingResponseRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmailMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxUpdateMeetingResponseRequest", "EmailMailboxId", 6usize, |this| ((**(this as *const *const IEmailMailboxUpdateMeetingResponseRequest_Vtbl)).EmailMailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmailMessageId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxUpdateMeetingResponseRequest", "EmailMessageId", 7usize, |this| ((**(this as *const *const IEmailMailboxUpdateMeetingResponseRequest_Vtbl)).EmailMessageId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Response(this: *mut core::ffi::c_void, result__: *mut super::EmailMeetingResponseType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxUpdateMeetingResponseRequest", "Response", 8usize, |this| ((**(this as *const *const IEmailMailboxUpdateMeetingResponseRequest_Vtbl)).Response)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxUpdateMeetingResponseRequest", "Subject", 9usize, |this| ((**(this as *const *const IEmailMailboxUpdateMeetingResponseRequest_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Comment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxUpdateMeetingResponseRequest", "Comment", 10usize, |this| ((**(this as *const *const IEmailMailboxUpdateMeetingResponseRequest_Vtbl)).Comment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendUpdate(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxUpdateMeetingResponseRequest", "SendUpdate", 11usize, |this| ((**(this as *const *const IEmailMailboxUpdateMeetingResponseRequest_Vtbl)).SendUpdate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxUpdateMeetingResponseRequest", "ReportCompletedAsync", 12usize, |this| ((**(this as *const *const IEmailMailboxUpdateMeetingResponseRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxUpdateMeetingResponseRequest", "ReportFailedAsync", 13usize, |this| ((**(this as *const *const IEmailMailboxUpdateMeetingResponseRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmailMailboxId,
+            EmailMessageId,
+            Response,
+            Subject,
+            Comment,
+            SendUpdate,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxUpdateMeetingResponseRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxUpdateMeetingResponseRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxUpdateMeetingResponseRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IEmailMailboxUpdateMeetingResponseRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxUpdateMeetingResponseRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IEmailMailboxUpdateMeetingResponseRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxUpdateMeetingResponseRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+impl windows_core::imp::ProxyVtable for IEmailMailboxValidateCertificatesRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmailMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxValidateCertificatesRequest", "EmailMailboxId", 6usize, |this| ((**(this as *const *const IEmailMailboxValidateCertificatesRequest_Vtbl)).EmailMailboxId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn Certificates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxValidateCertificatesRequest", "Certificates", 7usize, |this| ((**(this as *const *const IEmailMailboxValidateCertificatesRequest_Vtbl)).Certificates)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, validationstatuses: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxValidateCertificatesRequest", "ReportCompletedAsync", 8usize, |this| ((**(this as *const *const IEmailMailboxValidateCertificatesRequest_Vtbl)).ReportCompletedAsync)(this, validationstatuses, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxValidateCertificatesRequest", "ReportFailedAsync", 9usize, |this| ((**(this as *const *const IEmailMailboxValidateCertificatesRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmailMailboxId,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            Certificates,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            Certificates: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ReportCompletedAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReportCompletedAsync: 0,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxValidateCertificatesRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxValidateCertificatesRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxValidateCertificatesRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IEmailMailboxValidateCertificatesRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxValidateCertificatesRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IEmailMailboxValidateCertificatesRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxValidateCertificatesRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -4053,6 +4053,7 @@ impl EmailAttachmentDownloadState {
 impl windows_core::TypeKind for EmailAttachmentDownloadState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailAttachmentDownloadState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailAttachmentDownloadState").field(&self.0).finish()
@@ -4072,6 +4073,7 @@ impl EmailBatchStatus {
 impl windows_core::TypeKind for EmailBatchStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailBatchStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailBatchStatus").field(&self.0).finish()
@@ -4099,6 +4101,7 @@ impl EmailCertificateValidationStatus {
 impl windows_core::TypeKind for EmailCertificateValidationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailCertificateValidationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailCertificateValidationStatus").field(&self.0).finish()
@@ -4119,6 +4122,7 @@ impl EmailFlagState {
 impl windows_core::TypeKind for EmailFlagState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailFlagState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailFlagState").field(&self.0).finish()
@@ -4138,6 +4142,7 @@ impl EmailImportance {
 impl windows_core::TypeKind for EmailImportance {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailImportance {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailImportance").field(&self.0).finish()
@@ -4165,6 +4170,7 @@ impl EmailMailboxActionKind {
 impl windows_core::TypeKind for EmailMailboxActionKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMailboxActionKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMailboxActionKind").field(&self.0).finish()
@@ -4184,6 +4190,7 @@ impl EmailMailboxAllowedSmimeEncryptionAlgorithmNegotiation {
 impl windows_core::TypeKind for EmailMailboxAllowedSmimeEncryptionAlgorithmNegotiation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMailboxAllowedSmimeEncryptionAlgorithmNegotiation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMailboxAllowedSmimeEncryptionAlgorithmNegotiation").field(&self.0).finish()
@@ -4202,6 +4209,7 @@ impl EmailMailboxAutoReplyMessageResponseKind {
 impl windows_core::TypeKind for EmailMailboxAutoReplyMessageResponseKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMailboxAutoReplyMessageResponseKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMailboxAutoReplyMessageResponseKind").field(&self.0).finish()
@@ -4225,6 +4233,7 @@ impl EmailMailboxChangeType {
 impl windows_core::TypeKind for EmailMailboxChangeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMailboxChangeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMailboxChangeType").field(&self.0).finish()
@@ -4248,6 +4257,7 @@ impl EmailMailboxCreateFolderStatus {
 impl windows_core::TypeKind for EmailMailboxCreateFolderStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMailboxCreateFolderStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMailboxCreateFolderStatus").field(&self.0).finish()
@@ -4270,6 +4280,7 @@ impl EmailMailboxDeleteFolderStatus {
 impl windows_core::TypeKind for EmailMailboxDeleteFolderStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMailboxDeleteFolderStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMailboxDeleteFolderStatus").field(&self.0).finish()
@@ -4292,6 +4303,7 @@ impl EmailMailboxEmptyFolderStatus {
 impl windows_core::TypeKind for EmailMailboxEmptyFolderStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMailboxEmptyFolderStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMailboxEmptyFolderStatus").field(&self.0).finish()
@@ -4311,6 +4323,7 @@ impl EmailMailboxOtherAppReadAccess {
 impl windows_core::TypeKind for EmailMailboxOtherAppReadAccess {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMailboxOtherAppReadAccess {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMailboxOtherAppReadAccess").field(&self.0).finish()
@@ -4329,6 +4342,7 @@ impl EmailMailboxOtherAppWriteAccess {
 impl windows_core::TypeKind for EmailMailboxOtherAppWriteAccess {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMailboxOtherAppWriteAccess {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMailboxOtherAppWriteAccess").field(&self.0).finish()
@@ -4351,6 +4365,7 @@ impl EmailMailboxSmimeEncryptionAlgorithm {
 impl windows_core::TypeKind for EmailMailboxSmimeEncryptionAlgorithm {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMailboxSmimeEncryptionAlgorithm {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMailboxSmimeEncryptionAlgorithm").field(&self.0).finish()
@@ -4370,6 +4385,7 @@ impl EmailMailboxSmimeSigningAlgorithm {
 impl windows_core::TypeKind for EmailMailboxSmimeSigningAlgorithm {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMailboxSmimeSigningAlgorithm {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMailboxSmimeSigningAlgorithm").field(&self.0).finish()
@@ -4393,6 +4409,7 @@ impl EmailMailboxSyncStatus {
 impl windows_core::TypeKind for EmailMailboxSyncStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMailboxSyncStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMailboxSyncStatus").field(&self.0).finish()
@@ -4412,6 +4429,7 @@ impl EmailMeetingResponseType {
 impl windows_core::TypeKind for EmailMeetingResponseType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMeetingResponseType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMeetingResponseType").field(&self.0).finish()
@@ -4430,6 +4448,7 @@ impl EmailMessageBodyKind {
 impl windows_core::TypeKind for EmailMessageBodyKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMessageBodyKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMessageBodyKind").field(&self.0).finish()
@@ -4450,6 +4469,7 @@ impl EmailMessageDownloadState {
 impl windows_core::TypeKind for EmailMessageDownloadState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMessageDownloadState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMessageDownloadState").field(&self.0).finish()
@@ -4470,6 +4490,7 @@ impl EmailMessageResponseKind {
 impl windows_core::TypeKind for EmailMessageResponseKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMessageResponseKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMessageResponseKind").field(&self.0).finish()
@@ -4490,6 +4511,7 @@ impl EmailMessageSmimeKind {
 impl windows_core::TypeKind for EmailMessageSmimeKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailMessageSmimeKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailMessageSmimeKind").field(&self.0).finish()
@@ -4512,6 +4534,7 @@ impl EmailQueryKind {
 impl windows_core::TypeKind for EmailQueryKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailQueryKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailQueryKind").field(&self.0).finish()
@@ -4534,6 +4557,7 @@ impl EmailQuerySearchFields {
 impl windows_core::TypeKind for EmailQuerySearchFields {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailQuerySearchFields {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailQuerySearchFields").field(&self.0).finish()
@@ -4585,6 +4609,7 @@ impl EmailQuerySearchScope {
 impl windows_core::TypeKind for EmailQuerySearchScope {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailQuerySearchScope {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailQuerySearchScope").field(&self.0).finish()
@@ -4603,6 +4628,7 @@ impl EmailQuerySortDirection {
 impl windows_core::TypeKind for EmailQuerySortDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailQuerySortDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailQuerySortDirection").field(&self.0).finish()
@@ -4620,6 +4646,7 @@ impl EmailQuerySortProperty {
 impl windows_core::TypeKind for EmailQuerySortProperty {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailQuerySortProperty {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailQuerySortProperty").field(&self.0).finish()
@@ -4644,6 +4671,7 @@ impl EmailRecipientResolutionStatus {
 impl windows_core::TypeKind for EmailRecipientResolutionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailRecipientResolutionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailRecipientResolutionStatus").field(&self.0).finish()
@@ -4667,6 +4695,7 @@ impl EmailSpecialFolderKind {
 impl windows_core::TypeKind for EmailSpecialFolderKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailSpecialFolderKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailSpecialFolderKind").field(&self.0).finish()
@@ -4685,6 +4714,7 @@ impl EmailStoreAccessType {
 impl windows_core::TypeKind for EmailStoreAccessType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmailStoreAccessType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmailStoreAccessType").field(&self.0).finish()
@@ -4693,3 +4723,5 @@ impl core::fmt::Debug for EmailStoreAccessType {
 impl windows_core::RuntimeType for EmailStoreAccessType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.Email.EmailStoreAccessType;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,2165 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IEmailAttachment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FileName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment", "FileName", 6usize, |this| ((**(this as *const *const IEmailAttachment_Vtbl)).FileName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment", "SetFileName", 7usize, |this| ((**(this as *const *const IEmailAttachment_Vtbl)).SetFileName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment", "Data", 8usize, |this| ((**(this as *const *const IEmailAttachment_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment", "SetData", 9usize, |this| ((**(this as *const *const IEmailAttachment_Vtbl)).SetData)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FileName,
+            SetFileName,
+            #[cfg(feature = "Storage_Streams")]
+            Data,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Data: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailAttachment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailAttachment2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment2", "Id", 6usize, |this| ((**(this as *const *const IEmailAttachment2_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment2", "ContentId", 7usize, |this| ((**(this as *const *const IEmailAttachment2_Vtbl)).ContentId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment2", "SetContentId", 8usize, |this| ((**(this as *const *const IEmailAttachment2_Vtbl)).SetContentId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentLocation(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment2", "ContentLocation", 9usize, |this| ((**(this as *const *const IEmailAttachment2_Vtbl)).ContentLocation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentLocation(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment2", "SetContentLocation", 10usize, |this| ((**(this as *const *const IEmailAttachment2_Vtbl)).SetContentLocation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadState(this: *mut core::ffi::c_void, result__: *mut EmailAttachmentDownloadState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment2", "DownloadState", 11usize, |this| ((**(this as *const *const IEmailAttachment2_Vtbl)).DownloadState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDownloadState(this: *mut core::ffi::c_void, value: EmailAttachmentDownloadState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment2", "SetDownloadState", 12usize, |this| ((**(this as *const *const IEmailAttachment2_Vtbl)).SetDownloadState)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EstimatedDownloadSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment2", "EstimatedDownloadSizeInBytes", 13usize, |this| ((**(this as *const *const IEmailAttachment2_Vtbl)).EstimatedDownloadSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEstimatedDownloadSizeInBytes(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment2", "SetEstimatedDownloadSizeInBytes", 14usize, |this| ((**(this as *const *const IEmailAttachment2_Vtbl)).SetEstimatedDownloadSizeInBytes)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFromBaseMessage(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment2", "IsFromBaseMessage", 15usize, |this| ((**(this as *const *const IEmailAttachment2_Vtbl)).IsFromBaseMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInline(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment2", "IsInline", 16usize, |this| ((**(this as *const *const IEmailAttachment2_Vtbl)).IsInline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsInline(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment2", "SetIsInline", 17usize, |this| ((**(this as *const *const IEmailAttachment2_Vtbl)).SetIsInline)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MimeType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment2", "MimeType", 18usize, |this| ((**(this as *const *const IEmailAttachment2_Vtbl)).MimeType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMimeType(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachment2", "SetMimeType", 19usize, |this| ((**(this as *const *const IEmailAttachment2_Vtbl)).SetMimeType)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            ContentId,
+            SetContentId,
+            ContentLocation,
+            SetContentLocation,
+            DownloadState,
+            SetDownloadState,
+            EstimatedDownloadSizeInBytes,
+            SetEstimatedDownloadSizeInBytes,
+            IsFromBaseMessage,
+            IsInline,
+            SetIsInline,
+            MimeType,
+            SetMimeType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailAttachment2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IEmailAttachmentFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::HSTRING>, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachmentFactory", "Create", 6usize, |this| ((**(this as *const *const IEmailAttachmentFactory_Vtbl)).Create)(this, filename, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Create,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailAttachmentFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IEmailAttachmentFactory2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::HSTRING>, data: *mut core::ffi::c_void, mimetype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAttachmentFactory2", "Create", 6usize, |this| ((**(this as *const *const IEmailAttachmentFactory2_Vtbl)).Create)(this, filename, data, mimetype, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Create,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailAttachmentFactory2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEmailConversation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversation", "Id", 6usize, |this| ((**(this as *const *const IEmailConversation_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversation", "MailboxId", 7usize, |this| ((**(this as *const *const IEmailConversation_Vtbl)).MailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlagState(this: *mut core::ffi::c_void, result__: *mut EmailFlagState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversation", "FlagState", 8usize, |this| ((**(this as *const *const IEmailConversation_Vtbl)).FlagState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasAttachment(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversation", "HasAttachment", 9usize, |this| ((**(this as *const *const IEmailConversation_Vtbl)).HasAttachment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Importance(this: *mut core::ffi::c_void, result__: *mut EmailImportance) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversation", "Importance", 10usize, |this| ((**(this as *const *const IEmailConversation_Vtbl)).Importance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastEmailResponseKind(this: *mut core::ffi::c_void, result__: *mut EmailMessageResponseKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversation", "LastEmailResponseKind", 11usize, |this| ((**(this as *const *const IEmailConversation_Vtbl)).LastEmailResponseKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversation", "MessageCount", 12usize, |this| ((**(this as *const *const IEmailConversation_Vtbl)).MessageCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MostRecentMessageId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversation", "MostRecentMessageId", 13usize, |this| ((**(this as *const *const IEmailConversation_Vtbl)).MostRecentMessageId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MostRecentMessageTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversation", "MostRecentMessageTime", 14usize, |this| ((**(this as *const *const IEmailConversation_Vtbl)).MostRecentMessageTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Preview(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversation", "Preview", 15usize, |this| ((**(this as *const *const IEmailConversation_Vtbl)).Preview)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LatestSender(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversation", "LatestSender", 16usize, |this| ((**(this as *const *const IEmailConversation_Vtbl)).LatestSender)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversation", "Subject", 17usize, |this| ((**(this as *const *const IEmailConversation_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnreadMessageCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversation", "UnreadMessageCount", 18usize, |this| ((**(this as *const *const IEmailConversation_Vtbl)).UnreadMessageCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindMessagesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversation", "FindMessagesAsync", 19usize, |this| ((**(this as *const *const IEmailConversation_Vtbl)).FindMessagesAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindMessagesWithCountAsync(this: *mut core::ffi::c_void, count: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversation", "FindMessagesWithCountAsync", 20usize, |this| ((**(this as *const *const IEmailConversation_Vtbl)).FindMessagesWithCountAsync)(this, count, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            MailboxId,
+            FlagState,
+            HasAttachment,
+            Importance,
+            LastEmailResponseKind,
+            MessageCount,
+            MostRecentMessageId,
+            MostRecentMessageTime,
+            Preview,
+            LatestSender,
+            Subject,
+            UnreadMessageCount,
+            #[cfg(feature = "Foundation_Collections")]
+            FindMessagesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindMessagesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindMessagesWithCountAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindMessagesWithCountAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailConversation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEmailConversationBatch_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Conversations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversationBatch", "Conversations", 6usize, |this| ((**(this as *const *const IEmailConversationBatch_Vtbl)).Conversations)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut EmailBatchStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversationBatch", "Status", 7usize, |this| ((**(this as *const *const IEmailConversationBatch_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Conversations,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Conversations: 0,
+            Status,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailConversationBatch as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailConversationReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadBatchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailConversationReader", "ReadBatchAsync", 6usize, |this| ((**(this as *const *const IEmailConversationReader_Vtbl)).ReadBatchAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReadBatchAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailConversationReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEmailFolder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "Id", 6usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "RemoteId", 7usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).RemoteId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "SetRemoteId", 8usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).SetRemoteId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "MailboxId", 9usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).MailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentFolderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "ParentFolderId", 10usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).ParentFolderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "DisplayName", 11usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "SetDisplayName", 12usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSyncEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "IsSyncEnabled", 13usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).IsSyncEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsSyncEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "SetIsSyncEnabled", 14usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).SetIsSyncEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastSuccessfulSyncTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "LastSuccessfulSyncTime", 15usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).LastSuccessfulSyncTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastSuccessfulSyncTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "SetLastSuccessfulSyncTime", 16usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).SetLastSuccessfulSyncTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut EmailSpecialFolderKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "Kind", 17usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFolderAsync(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "CreateFolderAsync", 18usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).CreateFolderAsync)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "DeleteAsync", 19usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).DeleteAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindChildFoldersAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "FindChildFoldersAsync", 20usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).FindChildFoldersAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversationReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "GetConversationReader", 21usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).GetConversationReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversationReaderWithOptions(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "GetConversationReaderWithOptions", 22usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).GetConversationReaderWithOptions)(this, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "GetMessageAsync", 23usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).GetMessageAsync)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "GetMessageReader", 24usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).GetMessageReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageReaderWithOptions(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "GetMessageReaderWithOptions", 25usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).GetMessageReaderWithOptions)(this, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageCountsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "GetMessageCountsAsync", 26usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).GetMessageCountsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryMoveAsync(this: *mut core::ffi::c_void, newparentfolder: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "TryMoveAsync", 27usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).TryMoveAsync)(this, newparentfolder, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryMoveWithNewNameAsync(this: *mut core::ffi::c_void, newparentfolder: *mut core::ffi::c_void, newfoldername: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "TryMoveWithNewNameAsync", 28usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).TryMoveWithNewNameAsync)(this, newparentfolder, newfoldername, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySaveAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "TrySaveAsync", 29usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).TrySaveAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveMessageAsync(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolder", "SaveMessageAsync", 30usize, |this| ((**(this as *const *const IEmailFolder_Vtbl)).SaveMessageAsync)(this, message, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            RemoteId,
+            SetRemoteId,
+            MailboxId,
+            ParentFolderId,
+            DisplayName,
+            SetDisplayName,
+            IsSyncEnabled,
+            SetIsSyncEnabled,
+            LastSuccessfulSyncTime,
+            SetLastSuccessfulSyncTime,
+            Kind,
+            CreateFolderAsync,
+            DeleteAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            FindChildFoldersAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindChildFoldersAsync: 0,
+            GetConversationReader,
+            GetConversationReaderWithOptions,
+            GetMessageAsync,
+            GetMessageReader,
+            GetMessageReaderWithOptions,
+            GetMessageCountsAsync,
+            TryMoveAsync,
+            TryMoveWithNewNameAsync,
+            TrySaveAsync,
+            SaveMessageAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailFolder as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailIrmInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanEdit(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "CanEdit", 6usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).CanEdit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanEdit(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "SetCanEdit", 7usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).SetCanEdit)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanExtractData(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "CanExtractData", 8usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).CanExtractData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanExtractData(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "SetCanExtractData", 9usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).SetCanExtractData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanForward(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "CanForward", 10usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).CanForward)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanForward(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "SetCanForward", 11usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).SetCanForward)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanModifyRecipientsOnResponse(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "CanModifyRecipientsOnResponse", 12usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).CanModifyRecipientsOnResponse)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanModifyRecipientsOnResponse(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "SetCanModifyRecipientsOnResponse", 13usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).SetCanModifyRecipientsOnResponse)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanPrintData(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "CanPrintData", 14usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).CanPrintData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanPrintData(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "SetCanPrintData", 15usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).SetCanPrintData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanRemoveIrmOnResponse(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "CanRemoveIrmOnResponse", 16usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).CanRemoveIrmOnResponse)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanRemoveIrmOnResponse(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "SetCanRemoveIrmOnResponse", 17usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).SetCanRemoveIrmOnResponse)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanReply(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "CanReply", 18usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).CanReply)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanReply(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "SetCanReply", 19usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).SetCanReply)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanReplyAll(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "CanReplyAll", 20usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).CanReplyAll)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanReplyAll(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "SetCanReplyAll", 21usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).SetCanReplyAll)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationDate(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "ExpirationDate", 22usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).ExpirationDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExpirationDate(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "SetExpirationDate", 23usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).SetExpirationDate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIrmOriginator(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "IsIrmOriginator", 24usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).IsIrmOriginator)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsIrmOriginator(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "SetIsIrmOriginator", 25usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).SetIsIrmOriginator)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsProgramaticAccessAllowed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "IsProgramaticAccessAllowed", 26usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).IsProgramaticAccessAllowed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsProgramaticAccessAllowed(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "SetIsProgramaticAccessAllowed", 27usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).SetIsProgramaticAccessAllowed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Template(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "Template", 28usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).Template)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTemplate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfo", "SetTemplate", 29usize, |this| ((**(this as *const *const IEmailIrmInfo_Vtbl)).SetTemplate)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanEdit,
+            SetCanEdit,
+            CanExtractData,
+            SetCanExtractData,
+            CanForward,
+            SetCanForward,
+            CanModifyRecipientsOnResponse,
+            SetCanModifyRecipientsOnResponse,
+            CanPrintData,
+            SetCanPrintData,
+            CanRemoveIrmOnResponse,
+            SetCanRemoveIrmOnResponse,
+            CanReply,
+            SetCanReply,
+            CanReplyAll,
+            SetCanReplyAll,
+            ExpirationDate,
+            SetExpirationDate,
+            IsIrmOriginator,
+            SetIsIrmOriginator,
+            IsProgramaticAccessAllowed,
+            SetIsProgramaticAccessAllowed,
+            Template,
+            SetTemplate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailIrmInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailIrmInfoFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, expiration: super::super::Foundation::DateTime, irmtemplate: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmInfoFactory", "Create", 6usize, |this| ((**(this as *const *const IEmailIrmInfoFactory_Vtbl)).Create)(this, expiration, irmtemplate, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailIrmInfoFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailIrmTemplate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmTemplate", "Id", 6usize, |this| ((**(this as *const *const IEmailIrmTemplate_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmTemplate", "SetId", 7usize, |this| ((**(this as *const *const IEmailIrmTemplate_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmTemplate", "Description", 8usize, |this| ((**(this as *const *const IEmailIrmTemplate_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmTemplate", "SetDescription", 9usize, |this| ((**(this as *const *const IEmailIrmTemplate_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmTemplate", "Name", 10usize, |this| ((**(this as *const *const IEmailIrmTemplate_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmTemplate", "SetName", 11usize, |this| ((**(this as *const *const IEmailIrmTemplate_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, SetId, Description, SetDescription, Name, SetName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailIrmTemplate as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailIrmTemplateFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, name: core::mem::MaybeUninit<windows_core::HSTRING>, description: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailIrmTemplateFactory", "Create", 6usize, |this| ((**(this as *const *const IEmailIrmTemplateFactory_Vtbl)).Create)(this, id, name, description, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailIrmTemplateFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailItemCounts_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Flagged(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailItemCounts", "Flagged", 6usize, |this| ((**(this as *const *const IEmailItemCounts_Vtbl)).Flagged)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Important(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailItemCounts", "Important", 7usize, |this| ((**(this as *const *const IEmailItemCounts_Vtbl)).Important)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Total(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailItemCounts", "Total", 8usize, |this| ((**(this as *const *const IEmailItemCounts_Vtbl)).Total)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unread(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailItemCounts", "Unread", 9usize, |this| ((**(this as *const *const IEmailItemCounts_Vtbl)).Unread)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Flagged, Important, Total, Unread }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailItemCounts as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEmailMailbox_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Capabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "Capabilities", 6usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).Capabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeTracker(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "ChangeTracker", 7usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).ChangeTracker)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "DisplayName", 8usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "SetDisplayName", 9usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "Id", 10usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOwnedByCurrentApp(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "IsOwnedByCurrentApp", 11usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).IsOwnedByCurrentApp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDataEncryptedUnderLock(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "IsDataEncryptedUnderLock", 12usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).IsDataEncryptedUnderLock)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MailAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "MailAddress", 13usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).MailAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMailAddress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "SetMailAddress", 14usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).SetMailAddress)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MailAddressAliases(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "MailAddressAliases", 15usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).MailAddressAliases)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherAppReadAccess(this: *mut core::ffi::c_void, result__: *mut EmailMailboxOtherAppReadAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "OtherAppReadAccess", 16usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).OtherAppReadAccess)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOtherAppReadAccess(this: *mut core::ffi::c_void, value: EmailMailboxOtherAppReadAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "SetOtherAppReadAccess", 17usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).SetOtherAppReadAccess)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherAppWriteAccess(this: *mut core::ffi::c_void, result__: *mut EmailMailboxOtherAppWriteAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "OtherAppWriteAccess", 18usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).OtherAppWriteAccess)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOtherAppWriteAccess(this: *mut core::ffi::c_void, value: EmailMailboxOtherAppWriteAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "SetOtherAppWriteAccess", 19usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).SetOtherAppWriteAccess)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Policies(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "Policies", 20usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).Policies)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "SourceDisplayName", 21usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).SourceDisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "SyncManager", 22usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).SyncManager)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserDataAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "UserDataAccountId", 23usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).UserDataAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversationReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "GetConversationReader", 24usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).GetConversationReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversationReaderWithOptions(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "GetConversationReaderWithOptions", 25usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).GetConversationReaderWithOptions)(this, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "GetMessageReader", 26usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).GetMessageReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageReaderWithOptions(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "GetMessageReaderWithOptions", 27usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).GetMessageReaderWithOptions)(this, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "DeleteAsync", 28usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).DeleteAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversationAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "GetConversationAsync", 29usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).GetConversationAsync)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFolderAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "GetFolderAsync", 30usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).GetFolderAsync)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "GetMessageAsync", 31usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).GetMessageAsync)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpecialFolderAsync(this: *mut core::ffi::c_void, foldertype: EmailSpecialFolderKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "GetSpecialFolderAsync", 32usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).GetSpecialFolderAsync)(this, foldertype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "SaveAsync", 33usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).SaveAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkMessageAsSeenAsync(this: *mut core::ffi::c_void, messageid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "MarkMessageAsSeenAsync", 34usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).MarkMessageAsSeenAsync)(this, messageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkFolderAsSeenAsync(this: *mut core::ffi::c_void, folderid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "MarkFolderAsSeenAsync", 35usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).MarkFolderAsSeenAsync)(this, folderid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkMessageReadAsync(this: *mut core::ffi::c_void, messageid: core::mem::MaybeUninit<windows_core::HSTRING>, isread: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "MarkMessageReadAsync", 36usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).MarkMessageReadAsync)(this, messageid, isread, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeMessageFlagStateAsync(this: *mut core::ffi::c_void, messageid: core::mem::MaybeUninit<windows_core::HSTRING>, flagstate: EmailFlagState, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "ChangeMessageFlagStateAsync", 37usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).ChangeMessageFlagStateAsync)(this, messageid, flagstate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryMoveMessageAsync(this: *mut core::ffi::c_void, messageid: core::mem::MaybeUninit<windows_core::HSTRING>, newparentfolderid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "TryMoveMessageAsync", 38usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).TryMoveMessageAsync)(this, messageid, newparentfolderid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryMoveFolderAsync(this: *mut core::ffi::c_void, folderid: core::mem::MaybeUninit<windows_core::HSTRING>, newparentfolderid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "TryMoveFolderAsync", 39usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).TryMoveFolderAsync)(this, folderid, newparentfolderid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryMoveFolderWithNewNameAsync(this: *mut core::ffi::c_void, folderid: core::mem::MaybeUninit<windows_core::HSTRING>, newparentfolderid: core::mem::MaybeUninit<windows_core::HSTRING>, newfoldername: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "TryMoveFolderWithNewNameAsync", 40usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).TryMoveFolderWithNewNameAsync)(this, folderid, newparentfolderid, newfoldername, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteMessageAsync(this: *mut core::ffi::c_void, messageid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "DeleteMessageAsync", 41usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).DeleteMessageAsync)(this, messageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkFolderSyncEnabledAsync(this: *mut core::ffi::c_void, folderid: core::mem::MaybeUninit<windows_core::HSTRING>, issyncenabled: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "MarkFolderSyncEnabledAsync", 42usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).MarkFolderSyncEnabledAsync)(this, folderid, issyncenabled, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendMessageAsync(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "SendMessageAsync", 43usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).SendMessageAsync)(this, message, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveDraftAsync(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "SaveDraftAsync", 44usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).SaveDraftAsync)(this, message, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadMessageAsync(this: *mut core::ffi::c_void, messageid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "DownloadMessageAsync", 45usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).DownloadMessageAsync)(this, messageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadAttachmentAsync(this: *mut core::ffi::c_void, attachmentid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "DownloadAttachmentAsync", 46usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).DownloadAttachmentAsync)(this, attachmentid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateResponseMessageAsync(this: *mut core::ffi::c_void, messageid: core::mem::MaybeUninit<windows_core::HSTRING>, responsetype: EmailMessageResponseKind, subject: core::mem::MaybeUninit<windows_core::HSTRING>, responseheadertype: EmailMessageBodyKind, responseheader: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "CreateResponseMessageAsync", 47usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).CreateResponseMessageAsync)(this, messageid, responsetype, subject, responseheadertype, responseheader, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryUpdateMeetingResponseAsync(this: *mut core::ffi::c_void, meeting: *mut core::ffi::c_void, response: EmailMeetingResponseType, subject: core::mem::MaybeUninit<windows_core::HSTRING>, comment: core::mem::MaybeUninit<windows_core::HSTRING>, sendupdate: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "TryUpdateMeetingResponseAsync", 48usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).TryUpdateMeetingResponseAsync)(this, meeting, response, subject, comment, sendupdate, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TryForwardMeetingAsync(this: *mut core::ffi::c_void, meeting: *mut core::ffi::c_void, recipients: *mut core::ffi::c_void, subject: core::mem::MaybeUninit<windows_core::HSTRING>, forwardheadertype: EmailMessageBodyKind, forwardheader: core::mem::MaybeUninit<windows_core::HSTRING>, comment: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "TryForwardMeetingAsync", 49usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).TryForwardMeetingAsync)(this, meeting, recipients, subject, forwardheadertype, forwardheader, comment, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryProposeNewTimeForMeetingAsync(this: *mut core::ffi::c_void, meeting: *mut core::ffi::c_void, newstarttime: super::super::Foundation::DateTime, newduration: super::super::Foundation::TimeSpan, subject: core::mem::MaybeUninit<windows_core::HSTRING>, comment: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "TryProposeNewTimeForMeetingAsync", 50usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).TryProposeNewTimeForMeetingAsync)(this, meeting, newstarttime, newduration, subject, comment, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MailboxChanged(this: *mut core::ffi::c_void, phandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "MailboxChanged", 51usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).MailboxChanged)(this, phandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMailboxChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "RemoveMailboxChanged", 52usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).RemoveMailboxChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SmartSendMessageAsync(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, smartsend: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "SmartSendMessageAsync", 53usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).SmartSendMessageAsync)(this, message, smartsend, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySetAutoReplySettingsAsync(this: *mut core::ffi::c_void, autoreplysettings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "TrySetAutoReplySettingsAsync", 54usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).TrySetAutoReplySettingsAsync)(this, autoreplysettings, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetAutoReplySettingsAsync(this: *mut core::ffi::c_void, requestedformat: EmailMailboxAutoReplyMessageResponseKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox", "TryGetAutoReplySettingsAsync", 55usize, |this| ((**(this as *const *const IEmailMailbox_Vtbl)).TryGetAutoReplySettingsAsync)(this, requestedformat, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Capabilities,
+            ChangeTracker,
+            DisplayName,
+            SetDisplayName,
+            Id,
+            IsOwnedByCurrentApp,
+            IsDataEncryptedUnderLock,
+            MailAddress,
+            SetMailAddress,
+            #[cfg(feature = "Foundation_Collections")]
+            MailAddressAliases,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MailAddressAliases: 0,
+            OtherAppReadAccess,
+            SetOtherAppReadAccess,
+            OtherAppWriteAccess,
+            SetOtherAppWriteAccess,
+            Policies,
+            SourceDisplayName,
+            SyncManager,
+            UserDataAccountId,
+            GetConversationReader,
+            GetConversationReaderWithOptions,
+            GetMessageReader,
+            GetMessageReaderWithOptions,
+            DeleteAsync,
+            GetConversationAsync,
+            GetFolderAsync,
+            GetMessageAsync,
+            GetSpecialFolderAsync,
+            SaveAsync,
+            MarkMessageAsSeenAsync,
+            MarkFolderAsSeenAsync,
+            MarkMessageReadAsync,
+            ChangeMessageFlagStateAsync,
+            TryMoveMessageAsync,
+            TryMoveFolderAsync,
+            TryMoveFolderWithNewNameAsync,
+            DeleteMessageAsync,
+            MarkFolderSyncEnabledAsync,
+            SendMessageAsync,
+            SaveDraftAsync,
+            DownloadMessageAsync,
+            DownloadAttachmentAsync,
+            CreateResponseMessageAsync,
+            TryUpdateMeetingResponseAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            TryForwardMeetingAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TryForwardMeetingAsync: 0,
+            TryProposeNewTimeForMeetingAsync,
+            MailboxChanged,
+            RemoveMailboxChanged,
+            SmartSendMessageAsync,
+            TrySetAutoReplySettingsAsync,
+            TryGetAutoReplySettingsAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailbox as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEmailMailbox2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LinkedMailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox2", "LinkedMailboxId", 6usize, |this| ((**(this as *const *const IEmailMailbox2_Vtbl)).LinkedMailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox2", "NetworkAccountId", 7usize, |this| ((**(this as *const *const IEmailMailbox2_Vtbl)).NetworkAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox2", "NetworkId", 8usize, |this| ((**(this as *const *const IEmailMailbox2_Vtbl)).NetworkId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LinkedMailboxId, NetworkAccountId, NetworkId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailbox2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+impl windows_core::imp::ProxyVtable for IEmailMailbox3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ResolveRecipientsAsync(this: *mut core::ffi::c_void, recipients: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox3", "ResolveRecipientsAsync", 6usize, |this| ((**(this as *const *const IEmailMailbox3_Vtbl)).ResolveRecipientsAsync)(this, recipients, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn ValidateCertificatesAsync(this: *mut core::ffi::c_void, certificates: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox3", "ValidateCertificatesAsync", 7usize, |this| ((**(this as *const *const IEmailMailbox3_Vtbl)).ValidateCertificatesAsync)(this, certificates, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryEmptyFolderAsync(this: *mut core::ffi::c_void, folderid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox3", "TryEmptyFolderAsync", 8usize, |this| ((**(this as *const *const IEmailMailbox3_Vtbl)).TryEmptyFolderAsync)(this, folderid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryCreateFolderAsync(this: *mut core::ffi::c_void, parentfolderid: core::mem::MaybeUninit<windows_core::HSTRING>, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox3", "TryCreateFolderAsync", 9usize, |this| ((**(this as *const *const IEmailMailbox3_Vtbl)).TryCreateFolderAsync)(this, parentfolderid, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryDeleteFolderAsync(this: *mut core::ffi::c_void, folderid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox3", "TryDeleteFolderAsync", 10usize, |this| ((**(this as *const *const IEmailMailbox3_Vtbl)).TryDeleteFolderAsync)(this, folderid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ResolveRecipientsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ResolveRecipientsAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            ValidateCertificatesAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            ValidateCertificatesAsync: 0,
+            TryEmptyFolderAsync,
+            TryCreateFolderAsync,
+            TryDeleteFolderAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailbox3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailbox4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterSyncManagerAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox4", "RegisterSyncManagerAsync", 6usize, |this| ((**(this as *const *const IEmailMailbox4_Vtbl)).RegisterSyncManagerAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterSyncManagerAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailbox4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailbox5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetChangeTracker(this: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailbox5", "GetChangeTracker", 6usize, |this| ((**(this as *const *const IEmailMailbox5_Vtbl)).GetChangeTracker)(this, identity, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetChangeTracker }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailbox5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxAction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut EmailMailboxActionKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAction", "Kind", 6usize, |this| ((**(this as *const *const IEmailMailboxAction_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeNumber(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAction", "ChangeNumber", 7usize, |this| ((**(this as *const *const IEmailMailboxAction_Vtbl)).ChangeNumber)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind, ChangeNumber }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxAction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxAutoReply_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAutoReply", "IsEnabled", 6usize, |this| ((**(this as *const *const IEmailMailboxAutoReply_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAutoReply", "SetIsEnabled", 7usize, |this| ((**(this as *const *const IEmailMailboxAutoReply_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Response(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAutoReply", "Response", 8usize, |this| ((**(this as *const *const IEmailMailboxAutoReply_Vtbl)).Response)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResponse(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAutoReply", "SetResponse", 9usize, |this| ((**(this as *const *const IEmailMailboxAutoReply_Vtbl)).SetResponse)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsEnabled, SetIsEnabled, Response, SetResponse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxAutoReply as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxAutoReplySettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAutoReplySettings", "IsEnabled", 6usize, |this| ((**(this as *const *const IEmailMailboxAutoReplySettings_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAutoReplySettings", "SetIsEnabled", 7usize, |this| ((**(this as *const *const IEmailMailboxAutoReplySettings_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseKind(this: *mut core::ffi::c_void, result__: *mut EmailMailboxAutoReplyMessageResponseKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAutoReplySettings", "ResponseKind", 8usize, |this| ((**(this as *const *const IEmailMailboxAutoReplySettings_Vtbl)).ResponseKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResponseKind(this: *mut core::ffi::c_void, value: EmailMailboxAutoReplyMessageResponseKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAutoReplySettings", "SetResponseKind", 9usize, |this| ((**(this as *const *const IEmailMailboxAutoReplySettings_Vtbl)).SetResponseKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAutoReplySettings", "StartTime", 10usize, |this| ((**(this as *const *const IEmailMailboxAutoReplySettings_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAutoReplySettings", "SetStartTime", 11usize, |this| ((**(this as *const *const IEmailMailboxAutoReplySettings_Vtbl)).SetStartTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAutoReplySettings", "EndTime", 12usize, |this| ((**(this as *const *const IEmailMailboxAutoReplySettings_Vtbl)).EndTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEndTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAutoReplySettings", "SetEndTime", 13usize, |this| ((**(this as *const *const IEmailMailboxAutoReplySettings_Vtbl)).SetEndTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InternalReply(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAutoReplySettings", "InternalReply", 14usize, |this| ((**(this as *const *const IEmailMailboxAutoReplySettings_Vtbl)).InternalReply)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KnownExternalReply(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAutoReplySettings", "KnownExternalReply", 15usize, |this| ((**(this as *const *const IEmailMailboxAutoReplySettings_Vtbl)).KnownExternalReply)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnknownExternalReply(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxAutoReplySettings", "UnknownExternalReply", 16usize, |this| ((**(this as *const *const IEmailMailboxAutoReplySettings_Vtbl)).UnknownExternalReply)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEnabled,
+            SetIsEnabled,
+            ResponseKind,
+            SetResponseKind,
+            StartTime,
+            SetStartTime,
+            EndTime,
+            SetEndTime,
+            InternalReply,
+            KnownExternalReply,
+            UnknownExternalReply,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxAutoReplySettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanForwardMeetings(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities", "CanForwardMeetings", 6usize, |this| ((**(this as *const *const IEmailMailboxCapabilities_Vtbl)).CanForwardMeetings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanGetAndSetExternalAutoReplies(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities", "CanGetAndSetExternalAutoReplies", 7usize, |this| ((**(this as *const *const IEmailMailboxCapabilities_Vtbl)).CanGetAndSetExternalAutoReplies)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanGetAndSetInternalAutoReplies(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities", "CanGetAndSetInternalAutoReplies", 8usize, |this| ((**(this as *const *const IEmailMailboxCapabilities_Vtbl)).CanGetAndSetInternalAutoReplies)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanUpdateMeetingResponses(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities", "CanUpdateMeetingResponses", 9usize, |this| ((**(this as *const *const IEmailMailboxCapabilities_Vtbl)).CanUpdateMeetingResponses)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanServerSearchFolders(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities", "CanServerSearchFolders", 10usize, |this| ((**(this as *const *const IEmailMailboxCapabilities_Vtbl)).CanServerSearchFolders)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanServerSearchMailbox(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities", "CanServerSearchMailbox", 11usize, |this| ((**(this as *const *const IEmailMailboxCapabilities_Vtbl)).CanServerSearchMailbox)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanProposeNewTimeForMeetings(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities", "CanProposeNewTimeForMeetings", 12usize, |this| ((**(this as *const *const IEmailMailboxCapabilities_Vtbl)).CanProposeNewTimeForMeetings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanSmartSend(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities", "CanSmartSend", 13usize, |this| ((**(this as *const *const IEmailMailboxCapabilities_Vtbl)).CanSmartSend)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanForwardMeetings,
+            CanGetAndSetExternalAutoReplies,
+            CanGetAndSetInternalAutoReplies,
+            CanUpdateMeetingResponses,
+            CanServerSearchFolders,
+            CanServerSearchMailbox,
+            CanProposeNewTimeForMeetings,
+            CanSmartSend,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxCapabilities2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanResolveRecipients(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities2", "CanResolveRecipients", 6usize, |this| ((**(this as *const *const IEmailMailboxCapabilities2_Vtbl)).CanResolveRecipients)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanValidateCertificates(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities2", "CanValidateCertificates", 7usize, |this| ((**(this as *const *const IEmailMailboxCapabilities2_Vtbl)).CanValidateCertificates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanEmptyFolder(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities2", "CanEmptyFolder", 8usize, |this| ((**(this as *const *const IEmailMailboxCapabilities2_Vtbl)).CanEmptyFolder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanCreateFolder(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities2", "CanCreateFolder", 9usize, |this| ((**(this as *const *const IEmailMailboxCapabilities2_Vtbl)).CanCreateFolder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanDeleteFolder(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities2", "CanDeleteFolder", 10usize, |this| ((**(this as *const *const IEmailMailboxCapabilities2_Vtbl)).CanDeleteFolder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanMoveFolder(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities2", "CanMoveFolder", 11usize, |this| ((**(this as *const *const IEmailMailboxCapabilities2_Vtbl)).CanMoveFolder)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanResolveRecipients,
+            CanValidateCertificates,
+            CanEmptyFolder,
+            CanCreateFolder,
+            CanDeleteFolder,
+            CanMoveFolder,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxCapabilities2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxCapabilities3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCanForwardMeetings(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities3", "SetCanForwardMeetings", 6usize, |this| ((**(this as *const *const IEmailMailboxCapabilities3_Vtbl)).SetCanForwardMeetings)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanGetAndSetExternalAutoReplies(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities3", "SetCanGetAndSetExternalAutoReplies", 7usize, |this| ((**(this as *const *const IEmailMailboxCapabilities3_Vtbl)).SetCanGetAndSetExternalAutoReplies)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanGetAndSetInternalAutoReplies(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities3", "SetCanGetAndSetInternalAutoReplies", 8usize, |this| ((**(this as *const *const IEmailMailboxCapabilities3_Vtbl)).SetCanGetAndSetInternalAutoReplies)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanUpdateMeetingResponses(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities3", "SetCanUpdateMeetingResponses", 9usize, |this| ((**(this as *const *const IEmailMailboxCapabilities3_Vtbl)).SetCanUpdateMeetingResponses)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanServerSearchFolders(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities3", "SetCanServerSearchFolders", 10usize, |this| ((**(this as *const *const IEmailMailboxCapabilities3_Vtbl)).SetCanServerSearchFolders)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanServerSearchMailbox(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities3", "SetCanServerSearchMailbox", 11usize, |this| ((**(this as *const *const IEmailMailboxCapabilities3_Vtbl)).SetCanServerSearchMailbox)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanProposeNewTimeForMeetings(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities3", "SetCanProposeNewTimeForMeetings", 12usize, |this| ((**(this as *const *const IEmailMailboxCapabilities3_Vtbl)).SetCanProposeNewTimeForMeetings)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanSmartSend(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities3", "SetCanSmartSend", 13usize, |this| ((**(this as *const *const IEmailMailboxCapabilities3_Vtbl)).SetCanSmartSend)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanResolveRecipients(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities3", "SetCanResolveRecipients", 14usize, |this| ((**(this as *const *const IEmailMailboxCapabilities3_Vtbl)).SetCanResolveRecipients)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanValidateCertificates(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities3", "SetCanValidateCertificates", 15usize, |this| ((**(this as *const *const IEmailMailboxCapabilities3_Vtbl)).SetCanValidateCertificates)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanEmptyFolder(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities3", "SetCanEmptyFolder", 16usize, |this| ((**(this as *const *const IEmailMailboxCapabilities3_Vtbl)).SetCanEmptyFolder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanCreateFolder(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities3", "SetCanCreateFolder", 17usize, |this| ((**(this as *const *const IEmailMailboxCapabilities3_Vtbl)).SetCanCreateFolder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanDeleteFolder(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities3", "SetCanDeleteFolder", 18usize, |this| ((**(this as *const *const IEmailMailboxCapabilities3_Vtbl)).SetCanDeleteFolder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanMoveFolder(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCapabilities3", "SetCanMoveFolder", 19usize, |this| ((**(this as *const *const IEmailMailboxCapabilities3_Vtbl)).SetCanMoveFolder)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetCanForwardMeetings,
+            SetCanGetAndSetExternalAutoReplies,
+            SetCanGetAndSetInternalAutoReplies,
+            SetCanUpdateMeetingResponses,
+            SetCanServerSearchFolders,
+            SetCanServerSearchMailbox,
+            SetCanProposeNewTimeForMeetings,
+            SetCanSmartSend,
+            SetCanResolveRecipients,
+            SetCanValidateCertificates,
+            SetCanEmptyFolder,
+            SetCanCreateFolder,
+            SetCanDeleteFolder,
+            SetCanMoveFolder,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxCapabilities3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEmailMailboxChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeType(this: *mut core::ffi::c_void, result__: *mut EmailMailboxChangeType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxChange", "ChangeType", 6usize, |this| ((**(this as *const *const IEmailMailboxChange_Vtbl)).ChangeType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MailboxActions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxChange", "MailboxActions", 7usize, |this| ((**(this as *const *const IEmailMailboxChange_Vtbl)).MailboxActions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxChange", "Message", 8usize, |this| ((**(this as *const *const IEmailMailboxChange_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Folder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxChange", "Folder", 9usize, |this| ((**(this as *const *const IEmailMailboxChange_Vtbl)).Folder)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChangeType,
+            #[cfg(feature = "Foundation_Collections")]
+            MailboxActions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MailboxActions: 0,
+            Message,
+            Folder,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxChange as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEmailMailboxChangeReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcceptChanges(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxChangeReader", "AcceptChanges", 6usize, |this| ((**(this as *const *const IEmailMailboxChangeReader_Vtbl)).AcceptChanges)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptChangesThrough(this: *mut core::ffi::c_void, lastchangetoacknowledge: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxChangeReader", "AcceptChangesThrough", 7usize, |this| ((**(this as *const *const IEmailMailboxChangeReader_Vtbl)).AcceptChangesThrough)(this, lastchangetoacknowledge), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadBatchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxChangeReader", "ReadBatchAsync", 8usize, |this| ((**(this as *const *const IEmailMailboxChangeReader_Vtbl)).ReadBatchAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AcceptChanges,
+            AcceptChangesThrough,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadBatchAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadBatchAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxChangeReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxChangeTracker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTracking(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxChangeTracker", "IsTracking", 6usize, |this| ((**(this as *const *const IEmailMailboxChangeTracker_Vtbl)).IsTracking)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxChangeTracker", "Enable", 7usize, |this| ((**(this as *const *const IEmailMailboxChangeTracker_Vtbl)).Enable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxChangeTracker", "GetChangeReader", 8usize, |this| ((**(this as *const *const IEmailMailboxChangeTracker_Vtbl)).GetChangeReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxChangeTracker", "Reset", 9usize, |this| ((**(this as *const *const IEmailMailboxChangeTracker_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsTracking, Enable, GetChangeReader, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxChangeTracker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxChangedDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxChangedDeferral", "Complete", 6usize, |this| ((**(this as *const *const IEmailMailboxChangedDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxChangedDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxChangedEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const IEmailMailboxChangedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxCreateFolderResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut EmailMailboxCreateFolderStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCreateFolderResult", "Status", 6usize, |this| ((**(this as *const *const IEmailMailboxCreateFolderResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Folder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxCreateFolderResult", "Folder", 7usize, |this| ((**(this as *const *const IEmailMailboxCreateFolderResult_Vtbl)).Folder)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Folder }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxCreateFolderResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxPolicies_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowedSmimeEncryptionAlgorithmNegotiation(this: *mut core::ffi::c_void, result__: *mut EmailMailboxAllowedSmimeEncryptionAlgorithmNegotiation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxPolicies", "AllowedSmimeEncryptionAlgorithmNegotiation", 6usize, |this| ((**(this as *const *const IEmailMailboxPolicies_Vtbl)).AllowedSmimeEncryptionAlgorithmNegotiation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowSmimeSoftCertificates(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxPolicies", "AllowSmimeSoftCertificates", 7usize, |this| ((**(this as *const *const IEmailMailboxPolicies_Vtbl)).AllowSmimeSoftCertificates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequiredSmimeEncryptionAlgorithm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxPolicies", "RequiredSmimeEncryptionAlgorithm", 8usize, |this| ((**(this as *const *const IEmailMailboxPolicies_Vtbl)).RequiredSmimeEncryptionAlgorithm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequiredSmimeSigningAlgorithm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxPolicies", "RequiredSmimeSigningAlgorithm", 9usize, |this| ((**(this as *const *const IEmailMailboxPolicies_Vtbl)).RequiredSmimeSigningAlgorithm)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AllowedSmimeEncryptionAlgorithmNegotiation,
+            AllowSmimeSoftCertificates,
+            RequiredSmimeEncryptionAlgorithm,
+            RequiredSmimeSigningAlgorithm,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxPolicies as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxPolicies2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MustEncryptSmimeMessages(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxPolicies2", "MustEncryptSmimeMessages", 6usize, |this| ((**(this as *const *const IEmailMailboxPolicies2_Vtbl)).MustEncryptSmimeMessages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MustSignSmimeMessages(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxPolicies2", "MustSignSmimeMessages", 7usize, |this| ((**(this as *const *const IEmailMailboxPolicies2_Vtbl)).MustSignSmimeMessages)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MustEncryptSmimeMessages, MustSignSmimeMessages }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxPolicies2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxPolicies3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllowedSmimeEncryptionAlgorithmNegotiation(this: *mut core::ffi::c_void, value: EmailMailboxAllowedSmimeEncryptionAlgorithmNegotiation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxPolicies3", "SetAllowedSmimeEncryptionAlgorithmNegotiation", 6usize, |this| ((**(this as *const *const IEmailMailboxPolicies3_Vtbl)).SetAllowedSmimeEncryptionAlgorithmNegotiation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowSmimeSoftCertificates(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxPolicies3", "SetAllowSmimeSoftCertificates", 7usize, |this| ((**(this as *const *const IEmailMailboxPolicies3_Vtbl)).SetAllowSmimeSoftCertificates)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequiredSmimeEncryptionAlgorithm(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxPolicies3", "SetRequiredSmimeEncryptionAlgorithm", 8usize, |this| ((**(this as *const *const IEmailMailboxPolicies3_Vtbl)).SetRequiredSmimeEncryptionAlgorithm)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequiredSmimeSigningAlgorithm(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxPolicies3", "SetRequiredSmimeSigningAlgorithm", 9usize, |this| ((**(this as *const *const IEmailMailboxPolicies3_Vtbl)).SetRequiredSmimeSigningAlgorithm)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMustEncryptSmimeMessages(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxPolicies3", "SetMustEncryptSmimeMessages", 10usize, |this| ((**(this as *const *const IEmailMailboxPolicies3_Vtbl)).SetMustEncryptSmimeMessages)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMustSignSmimeMessages(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxPolicies3", "SetMustSignSmimeMessages", 11usize, |this| ((**(this as *const *const IEmailMailboxPolicies3_Vtbl)).SetMustSignSmimeMessages)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAllowedSmimeEncryptionAlgorithmNegotiation,
+            SetAllowSmimeSoftCertificates,
+            SetRequiredSmimeEncryptionAlgorithm,
+            SetRequiredSmimeSigningAlgorithm,
+            SetMustEncryptSmimeMessages,
+            SetMustSignSmimeMessages,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxPolicies3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxSyncManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut EmailMailboxSyncStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSyncManager", "Status", 6usize, |this| ((**(this as *const *const IEmailMailboxSyncManager_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastSuccessfulSyncTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSyncManager", "LastSuccessfulSyncTime", 7usize, |this| ((**(this as *const *const IEmailMailboxSyncManager_Vtbl)).LastSuccessfulSyncTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastAttemptedSyncTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSyncManager", "LastAttemptedSyncTime", 8usize, |this| ((**(this as *const *const IEmailMailboxSyncManager_Vtbl)).LastAttemptedSyncTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSyncManager", "SyncAsync", 9usize, |this| ((**(this as *const *const IEmailMailboxSyncManager_Vtbl)).SyncAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSyncManager", "SyncStatusChanged", 10usize, |this| ((**(this as *const *const IEmailMailboxSyncManager_Vtbl)).SyncStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSyncStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSyncManager", "RemoveSyncStatusChanged", 11usize, |this| ((**(this as *const *const IEmailMailboxSyncManager_Vtbl)).RemoveSyncStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            LastSuccessfulSyncTime,
+            LastAttemptedSyncTime,
+            SyncAsync,
+            SyncStatusChanged,
+            RemoveSyncStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxSyncManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMailboxSyncManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, value: EmailMailboxSyncStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSyncManager2", "SetStatus", 6usize, |this| ((**(this as *const *const IEmailMailboxSyncManager2_Vtbl)).SetStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastSuccessfulSyncTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSyncManager2", "SetLastSuccessfulSyncTime", 7usize, |this| ((**(this as *const *const IEmailMailboxSyncManager2_Vtbl)).SetLastSuccessfulSyncTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastAttemptedSyncTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMailboxSyncManager2", "SetLastAttemptedSyncTime", 8usize, |this| ((**(this as *const *const IEmailMailboxSyncManager2_Vtbl)).SetLastAttemptedSyncTime)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetStatus,
+            SetLastSuccessfulSyncTime,
+            SetLastAttemptedSyncTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMailboxSyncManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IEmailManagerForUser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowComposeNewEmailAsync(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailManagerForUser", "ShowComposeNewEmailAsync", 6usize, |this| ((**(this as *const *const IEmailManagerForUser_Vtbl)).ShowComposeNewEmailAsync)(this, message, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, accesstype: EmailStoreAccessType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailManagerForUser", "RequestStoreAsync", 7usize, |this| ((**(this as *const *const IEmailManagerForUser_Vtbl)).RequestStoreAsync)(this, accesstype, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailManagerForUser", "User", 8usize, |this| ((**(this as *const *const IEmailManagerForUser_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ShowComposeNewEmailAsync,
+            RequestStoreAsync,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailManagerForUser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowComposeNewEmailAsync(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailManagerStatics", "ShowComposeNewEmailAsync", 6usize, |this| ((**(this as *const *const IEmailManagerStatics_Vtbl)).ShowComposeNewEmailAsync)(this, message, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowComposeNewEmailAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, accesstype: EmailStoreAccessType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailManagerStatics2", "RequestStoreAsync", 6usize, |this| ((**(this as *const *const IEmailManagerStatics2_Vtbl)).RequestStoreAsync)(this, accesstype, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestStoreAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IEmailManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailManagerStatics3", "GetForUser", 6usize, |this| ((**(this as *const *const IEmailManagerStatics3_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Appointments")]
+impl windows_core::imp::ProxyVtable for IEmailMeetingInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowNewTimeProposal(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "AllowNewTimeProposal", 6usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).AllowNewTimeProposal)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowNewTimeProposal(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "SetAllowNewTimeProposal", 7usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).SetAllowNewTimeProposal)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppointmentRoamingId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "AppointmentRoamingId", 8usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).AppointmentRoamingId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppointmentRoamingId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "SetAppointmentRoamingId", 9usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).SetAppointmentRoamingId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppointmentOriginalStartTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "AppointmentOriginalStartTime", 10usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).AppointmentOriginalStartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppointmentOriginalStartTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "SetAppointmentOriginalStartTime", 11usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).SetAppointmentOriginalStartTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "Duration", 12usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "SetDuration", 13usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).SetDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAllDay(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "IsAllDay", 14usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).IsAllDay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsAllDay(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "SetIsAllDay", 15usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).SetIsAllDay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsResponseRequested(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "IsResponseRequested", 16usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).IsResponseRequested)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsResponseRequested(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "SetIsResponseRequested", 17usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).SetIsResponseRequested)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Location(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "Location", 18usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).Location)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocation(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "SetLocation", 19usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).SetLocation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProposedStartTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "ProposedStartTime", 20usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).ProposedStartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProposedStartTime(this: *mut core::ffi::c_void, proposedstarttime: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "SetProposedStartTime", 21usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).SetProposedStartTime)(this, proposedstarttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProposedDuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "ProposedDuration", 22usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).ProposedDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProposedDuration(this: *mut core::ffi::c_void, duration: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "SetProposedDuration", 23usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).SetProposedDuration)(this, duration), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecurrenceStartTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "RecurrenceStartTime", 24usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).RecurrenceStartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecurrenceStartTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "SetRecurrenceStartTime", 25usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).SetRecurrenceStartTime)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Appointments")]
+        unsafe extern "system" fn Recurrence(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "Recurrence", 26usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).Recurrence)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Appointments")]
+        unsafe extern "system" fn SetRecurrence(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "SetRecurrence", 27usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).SetRecurrence)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteChangeNumber(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "RemoteChangeNumber", 28usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).RemoteChangeNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteChangeNumber(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "SetRemoteChangeNumber", 29usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).SetRemoteChangeNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "StartTime", 30usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo", "SetStartTime", 31usize, |this| ((**(this as *const *const IEmailMeetingInfo_Vtbl)).SetStartTime)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AllowNewTimeProposal,
+            SetAllowNewTimeProposal,
+            AppointmentRoamingId,
+            SetAppointmentRoamingId,
+            AppointmentOriginalStartTime,
+            SetAppointmentOriginalStartTime,
+            Duration,
+            SetDuration,
+            IsAllDay,
+            SetIsAllDay,
+            IsResponseRequested,
+            SetIsResponseRequested,
+            Location,
+            SetLocation,
+            ProposedStartTime,
+            SetProposedStartTime,
+            ProposedDuration,
+            SetProposedDuration,
+            RecurrenceStartTime,
+            SetRecurrenceStartTime,
+            #[cfg(feature = "ApplicationModel_Appointments")]
+            Recurrence,
+            #[cfg(not(feature = "ApplicationModel_Appointments"))]
+            Recurrence: 0,
+            #[cfg(feature = "ApplicationModel_Appointments")]
+            SetRecurrence,
+            #[cfg(not(feature = "ApplicationModel_Appointments"))]
+            SetRecurrence: 0,
+            RemoteChangeNumber,
+            SetRemoteChangeNumber,
+            StartTime,
+            SetStartTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMeetingInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMeetingInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsReportedOutOfDateByServer(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMeetingInfo2", "IsReportedOutOfDateByServer", 6usize, |this| ((**(this as *const *const IEmailMeetingInfo2_Vtbl)).IsReportedOutOfDateByServer)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsReportedOutOfDateByServer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMeetingInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEmailMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage", "Subject", 6usize, |this| ((**(this as *const *const IEmailMessage_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubject(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage", "SetSubject", 7usize, |this| ((**(this as *const *const IEmailMessage_Vtbl)).SetSubject)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Body(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage", "Body", 8usize, |this| ((**(this as *const *const IEmailMessage_Vtbl)).Body)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBody(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage", "SetBody", 9usize, |this| ((**(this as *const *const IEmailMessage_Vtbl)).SetBody)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn To(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage", "To", 10usize, |this| ((**(this as *const *const IEmailMessage_Vtbl)).To)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CC(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage", "CC", 11usize, |this| ((**(this as *const *const IEmailMessage_Vtbl)).CC)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Bcc(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage", "Bcc", 12usize, |this| ((**(this as *const *const IEmailMessage_Vtbl)).Bcc)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Attachments(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage", "Attachments", 13usize, |this| ((**(this as *const *const IEmailMessage_Vtbl)).Attachments)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Subject,
+            SetSubject,
+            Body,
+            SetBody,
+            #[cfg(feature = "Foundation_Collections")]
+            To,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            To: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CC,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CC: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Bcc,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Bcc: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Attachments,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Attachments: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IEmailMessage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "Id", 6usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "RemoteId", 7usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).RemoteId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetRemoteId", 8usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetRemoteId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MailboxId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "MailboxId", 9usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).MailboxId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConversationId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "ConversationId", 10usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).ConversationId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FolderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "FolderId", 11usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).FolderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowInternetImages(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "AllowInternetImages", 12usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).AllowInternetImages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowInternetImages(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetAllowInternetImages", 13usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetAllowInternetImages)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeNumber(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "ChangeNumber", 14usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).ChangeNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadState(this: *mut core::ffi::c_void, result__: *mut EmailMessageDownloadState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "DownloadState", 15usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).DownloadState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDownloadState(this: *mut core::ffi::c_void, value: EmailMessageDownloadState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetDownloadState", 16usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetDownloadState)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EstimatedDownloadSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "EstimatedDownloadSizeInBytes", 17usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).EstimatedDownloadSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEstimatedDownloadSizeInBytes(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetEstimatedDownloadSizeInBytes", 18usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetEstimatedDownloadSizeInBytes)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlagState(this: *mut core::ffi::c_void, result__: *mut EmailFlagState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "FlagState", 19usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).FlagState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlagState(this: *mut core::ffi::c_void, value: EmailFlagState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetFlagState", 20usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetFlagState)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasPartialBodies(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "HasPartialBodies", 21usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).HasPartialBodies)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Importance(this: *mut core::ffi::c_void, result__: *mut EmailImportance) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "Importance", 22usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).Importance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetImportance(this: *mut core::ffi::c_void, value: EmailImportance) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetImportance", 23usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetImportance)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InResponseToMessageId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "InResponseToMessageId", 24usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).InResponseToMessageId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IrmInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "IrmInfo", 25usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).IrmInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIrmInfo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetIrmInfo", 26usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetIrmInfo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDraftMessage(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "IsDraftMessage", 27usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).IsDraftMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRead(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "IsRead", 28usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).IsRead)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsRead(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetIsRead", 29usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetIsRead)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSeen(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "IsSeen", 30usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).IsSeen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsSeen(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetIsSeen", 31usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetIsSeen)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsServerSearchMessage(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "IsServerSearchMessage", 32usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).IsServerSearchMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSmartSendable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "IsSmartSendable", 33usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).IsSmartSendable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageClass(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "MessageClass", 34usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).MessageClass)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageClass(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetMessageClass", 35usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetMessageClass)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NormalizedSubject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "NormalizedSubject", 36usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).NormalizedSubject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OriginalCodePage(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "OriginalCodePage", 37usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).OriginalCodePage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOriginalCodePage(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetOriginalCodePage", 38usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetOriginalCodePage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Preview(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "Preview", 39usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).Preview)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreview(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetPreview", 40usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetPreview)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastResponseKind(this: *mut core::ffi::c_void, result__: *mut EmailMessageResponseKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "LastResponseKind", 41usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).LastResponseKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastResponseKind(this: *mut core::ffi::c_void, value: EmailMessageResponseKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetLastResponseKind", 42usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetLastResponseKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sender(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "Sender", 43usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).Sender)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSender(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetSender", 44usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetSender)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SentTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SentTime", 45usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SentTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSentTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetSentTime", 46usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetSentTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MeetingInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "MeetingInfo", 47usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).MeetingInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMeetingInfo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetMeetingInfo", 48usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetMeetingInfo)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetBodyStream(this: *mut core::ffi::c_void, r#type: EmailMessageBodyKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "GetBodyStream", 49usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).GetBodyStream)(this, r#type, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetBodyStream(this: *mut core::ffi::c_void, r#type: EmailMessageBodyKind, stream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage2", "SetBodyStream", 50usize, |this| ((**(this as *const *const IEmailMessage2_Vtbl)).SetBodyStream)(this, r#type, stream), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            RemoteId,
+            SetRemoteId,
+            MailboxId,
+            ConversationId,
+            FolderId,
+            AllowInternetImages,
+            SetAllowInternetImages,
+            ChangeNumber,
+            DownloadState,
+            SetDownloadState,
+            EstimatedDownloadSizeInBytes,
+            SetEstimatedDownloadSizeInBytes,
+            FlagState,
+            SetFlagState,
+            HasPartialBodies,
+            Importance,
+            SetImportance,
+            InResponseToMessageId,
+            IrmInfo,
+            SetIrmInfo,
+            IsDraftMessage,
+            IsRead,
+            SetIsRead,
+            IsSeen,
+            SetIsSeen,
+            IsServerSearchMessage,
+            IsSmartSendable,
+            MessageClass,
+            SetMessageClass,
+            NormalizedSubject,
+            OriginalCodePage,
+            SetOriginalCodePage,
+            Preview,
+            SetPreview,
+            LastResponseKind,
+            SetLastResponseKind,
+            Sender,
+            SetSender,
+            SentTime,
+            SetSentTime,
+            MeetingInfo,
+            SetMeetingInfo,
+            #[cfg(feature = "Storage_Streams")]
+            GetBodyStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetBodyStream: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetBodyStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetBodyStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMessage2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IEmailMessage3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SmimeData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage3", "SmimeData", 6usize, |this| ((**(this as *const *const IEmailMessage3_Vtbl)).SmimeData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetSmimeData(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage3", "SetSmimeData", 7usize, |this| ((**(this as *const *const IEmailMessage3_Vtbl)).SetSmimeData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SmimeKind(this: *mut core::ffi::c_void, result__: *mut EmailMessageSmimeKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage3", "SmimeKind", 8usize, |this| ((**(this as *const *const IEmailMessage3_Vtbl)).SmimeKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSmimeKind(this: *mut core::ffi::c_void, value: EmailMessageSmimeKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage3", "SetSmimeKind", 9usize, |this| ((**(this as *const *const IEmailMessage3_Vtbl)).SetSmimeKind)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            SmimeData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SmimeData: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetSmimeData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetSmimeData: 0,
+            SmimeKind,
+            SetSmimeKind,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMessage3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEmailMessage4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReplyTo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage4", "ReplyTo", 6usize, |this| ((**(this as *const *const IEmailMessage4_Vtbl)).ReplyTo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SentRepresenting(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage4", "SentRepresenting", 7usize, |this| ((**(this as *const *const IEmailMessage4_Vtbl)).SentRepresenting)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSentRepresenting(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessage4", "SetSentRepresenting", 8usize, |this| ((**(this as *const *const IEmailMessage4_Vtbl)).SetSentRepresenting)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ReplyTo,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReplyTo: 0,
+            SentRepresenting,
+            SetSentRepresenting,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMessage4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEmailMessageBatch_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Messages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessageBatch", "Messages", 6usize, |this| ((**(this as *const *const IEmailMessageBatch_Vtbl)).Messages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut EmailBatchStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessageBatch", "Status", 7usize, |this| ((**(this as *const *const IEmailMessageBatch_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Messages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Messages: 0,
+            Status,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMessageBatch as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailMessageReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadBatchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailMessageReader", "ReadBatchAsync", 6usize, |this| ((**(this as *const *const IEmailMessageReader_Vtbl)).ReadBatchAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReadBatchAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailMessageReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEmailQueryOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TextSearch(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryOptions", "TextSearch", 6usize, |this| ((**(this as *const *const IEmailQueryOptions_Vtbl)).TextSearch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SortDirection(this: *mut core::ffi::c_void, result__: *mut EmailQuerySortDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryOptions", "SortDirection", 7usize, |this| ((**(this as *const *const IEmailQueryOptions_Vtbl)).SortDirection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSortDirection(this: *mut core::ffi::c_void, value: EmailQuerySortDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryOptions", "SetSortDirection", 8usize, |this| ((**(this as *const *const IEmailQueryOptions_Vtbl)).SetSortDirection)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SortProperty(this: *mut core::ffi::c_void, result__: *mut EmailQuerySortProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryOptions", "SortProperty", 9usize, |this| ((**(this as *const *const IEmailQueryOptions_Vtbl)).SortProperty)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSortProperty(this: *mut core::ffi::c_void, value: EmailQuerySortProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryOptions", "SetSortProperty", 10usize, |this| ((**(this as *const *const IEmailQueryOptions_Vtbl)).SetSortProperty)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut EmailQueryKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryOptions", "Kind", 11usize, |this| ((**(this as *const *const IEmailQueryOptions_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKind(this: *mut core::ffi::c_void, value: EmailQueryKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryOptions", "SetKind", 12usize, |this| ((**(this as *const *const IEmailQueryOptions_Vtbl)).SetKind)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FolderIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryOptions", "FolderIds", 13usize, |this| ((**(this as *const *const IEmailQueryOptions_Vtbl)).FolderIds)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TextSearch,
+            SortDirection,
+            SetSortDirection,
+            SortProperty,
+            SetSortProperty,
+            Kind,
+            SetKind,
+            #[cfg(feature = "Foundation_Collections")]
+            FolderIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FolderIds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailQueryOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailQueryOptionsFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithText(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryOptionsFactory", "CreateWithText", 6usize, |this| ((**(this as *const *const IEmailQueryOptionsFactory_Vtbl)).CreateWithText)(this, text, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithTextAndFields(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, fields: EmailQuerySearchFields, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryOptionsFactory", "CreateWithTextAndFields", 7usize, |this| ((**(this as *const *const IEmailQueryOptionsFactory_Vtbl)).CreateWithTextAndFields)(this, text, fields, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithText, CreateWithTextAndFields }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailQueryOptionsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailQueryTextSearch_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Fields(this: *mut core::ffi::c_void, result__: *mut EmailQuerySearchFields) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryTextSearch", "Fields", 6usize, |this| ((**(this as *const *const IEmailQueryTextSearch_Vtbl)).Fields)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFields(this: *mut core::ffi::c_void, value: EmailQuerySearchFields) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryTextSearch", "SetFields", 7usize, |this| ((**(this as *const *const IEmailQueryTextSearch_Vtbl)).SetFields)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchScope(this: *mut core::ffi::c_void, result__: *mut EmailQuerySearchScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryTextSearch", "SearchScope", 8usize, |this| ((**(this as *const *const IEmailQueryTextSearch_Vtbl)).SearchScope)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSearchScope(this: *mut core::ffi::c_void, value: EmailQuerySearchScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryTextSearch", "SetSearchScope", 9usize, |this| ((**(this as *const *const IEmailQueryTextSearch_Vtbl)).SetSearchScope)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryTextSearch", "Text", 10usize, |this| ((**(this as *const *const IEmailQueryTextSearch_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailQueryTextSearch", "SetText", 11usize, |this| ((**(this as *const *const IEmailQueryTextSearch_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Fields,
+            SetFields,
+            SearchScope,
+            SetSearchScope,
+            Text,
+            SetText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailQueryTextSearch as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailRecipient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailRecipient", "Name", 6usize, |this| ((**(this as *const *const IEmailRecipient_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailRecipient", "SetName", 7usize, |this| ((**(this as *const *const IEmailRecipient_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailRecipient", "Address", 8usize, |this| ((**(this as *const *const IEmailRecipient_Vtbl)).Address)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAddress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailRecipient", "SetAddress", 9usize, |this| ((**(this as *const *const IEmailRecipient_Vtbl)).SetAddress)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, SetName, Address, SetAddress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailRecipient as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailRecipientFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, address: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailRecipientFactory", "Create", 6usize, |this| ((**(this as *const *const IEmailRecipientFactory_Vtbl)).Create)(this, address, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithName(this: *mut core::ffi::c_void, address: core::mem::MaybeUninit<windows_core::HSTRING>, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailRecipientFactory", "CreateWithName", 7usize, |this| ((**(this as *const *const IEmailRecipientFactory_Vtbl)).CreateWithName)(this, address, name, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailRecipientFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+impl windows_core::imp::ProxyVtable for IEmailRecipientResolutionResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut EmailRecipientResolutionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailRecipientResolutionResult", "Status", 6usize, |this| ((**(this as *const *const IEmailRecipientResolutionResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn PublicKeys(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailRecipientResolutionResult", "PublicKeys", 7usize, |this| ((**(this as *const *const IEmailRecipientResolutionResult_Vtbl)).PublicKeys)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            PublicKeys,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            PublicKeys: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailRecipientResolutionResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+impl windows_core::imp::ProxyVtable for IEmailRecipientResolutionResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, value: EmailRecipientResolutionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailRecipientResolutionResult2", "SetStatus", 6usize, |this| ((**(this as *const *const IEmailRecipientResolutionResult2_Vtbl)).SetStatus)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn SetPublicKeys(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailRecipientResolutionResult2", "SetPublicKeys", 7usize, |this| ((**(this as *const *const IEmailRecipientResolutionResult2_Vtbl)).SetPublicKeys)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetStatus,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            SetPublicKeys,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            SetPublicKeys: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailRecipientResolutionResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEmailStore_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindMailboxesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailStore", "FindMailboxesAsync", 6usize, |this| ((**(this as *const *const IEmailStore_Vtbl)).FindMailboxesAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversationReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailStore", "GetConversationReader", 7usize, |this| ((**(this as *const *const IEmailStore_Vtbl)).GetConversationReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversationReaderWithOptions(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailStore", "GetConversationReaderWithOptions", 8usize, |this| ((**(this as *const *const IEmailStore_Vtbl)).GetConversationReaderWithOptions)(this, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailStore", "GetMessageReader", 9usize, |this| ((**(this as *const *const IEmailStore_Vtbl)).GetMessageReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageReaderWithOptions(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailStore", "GetMessageReaderWithOptions", 10usize, |this| ((**(this as *const *const IEmailStore_Vtbl)).GetMessageReaderWithOptions)(this, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMailboxAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailStore", "GetMailboxAsync", 11usize, |this| ((**(this as *const *const IEmailStore_Vtbl)).GetMailboxAsync)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversationAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailStore", "GetConversationAsync", 12usize, |this| ((**(this as *const *const IEmailStore_Vtbl)).GetConversationAsync)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFolderAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailStore", "GetFolderAsync", 13usize, |this| ((**(this as *const *const IEmailStore_Vtbl)).GetFolderAsync)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailStore", "GetMessageAsync", 14usize, |this| ((**(this as *const *const IEmailStore_Vtbl)).GetMessageAsync)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMailboxAsync(this: *mut core::ffi::c_void, accountname: core::mem::MaybeUninit<windows_core::HSTRING>, accountaddress: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailStore", "CreateMailboxAsync", 15usize, |this| ((**(this as *const *const IEmailStore_Vtbl)).CreateMailboxAsync)(this, accountname, accountaddress, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMailboxInAccountAsync(this: *mut core::ffi::c_void, accountname: core::mem::MaybeUninit<windows_core::HSTRING>, accountaddress: core::mem::MaybeUninit<windows_core::HSTRING>, userdataaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailStore", "CreateMailboxInAccountAsync", 16usize, |this| ((**(this as *const *const IEmailStore_Vtbl)).CreateMailboxInAccountAsync)(this, accountname, accountaddress, userdataaccountid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindMailboxesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindMailboxesAsync: 0,
+            GetConversationReader,
+            GetConversationReaderWithOptions,
+            GetMessageReader,
+            GetMessageReaderWithOptions,
+            GetMailboxAsync,
+            GetConversationAsync,
+            GetFolderAsync,
+            GetMessageAsync,
+            CreateMailboxAsync,
+            CreateMailboxInAccountAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailStoreNotificationTriggerDetails_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailStoreNotificationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
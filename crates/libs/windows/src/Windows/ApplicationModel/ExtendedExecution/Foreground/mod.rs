@@ -132,6 +132,7 @@ impl ExtendedExecutionForegroundReason {
 impl windows_core::TypeKind for ExtendedExecutionForegroundReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ExtendedExecutionForegroundReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ExtendedExecutionForegroundReason").field(&self.0).finish()
@@ -150,6 +151,7 @@ impl ExtendedExecutionForegroundResult {
 impl windows_core::TypeKind for ExtendedExecutionForegroundResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ExtendedExecutionForegroundResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ExtendedExecutionForegroundResult").field(&self.0).finish()
@@ -168,6 +170,7 @@ impl ExtendedExecutionForegroundRevokedReason {
 impl windows_core::TypeKind for ExtendedExecutionForegroundRevokedReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ExtendedExecutionForegroundRevokedReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ExtendedExecutionForegroundRevokedReason").field(&self.0).finish()
@@ -176,3 +179,5 @@ impl core::fmt::Debug for ExtendedExecutionForegroundRevokedReason {
 impl windows_core::RuntimeType for ExtendedExecutionForegroundRevokedReason {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.ExtendedExecution.Foreground.ExtendedExecutionForegroundRevokedReason;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
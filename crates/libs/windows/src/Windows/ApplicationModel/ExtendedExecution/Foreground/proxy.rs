@@ -0,0 +1,51 @@
+impl windows_core::imp::ProxyVtable for IExtendedExecutionForegroundRevokedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut ExtendedExecutionForegroundRevokedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionForegroundRevokedEventArgs", "Reason", 6usize, |this| ((**(this as *const *const IExtendedExecutionForegroundRevokedEventArgs_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExtendedExecutionForegroundRevokedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IExtendedExecutionForegroundSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionForegroundSession", "Description", 6usize, |this| ((**(this as *const *const IExtendedExecutionForegroundSession_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionForegroundSession", "SetDescription", 7usize, |this| ((**(this as *const *const IExtendedExecutionForegroundSession_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Revoked(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionForegroundSession", "Revoked", 8usize, |this| ((**(this as *const *const IExtendedExecutionForegroundSession_Vtbl)).Revoked)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRevoked(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionForegroundSession", "RemoveRevoked", 9usize, |this| ((**(this as *const *const IExtendedExecutionForegroundSession_Vtbl)).RemoveRevoked)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestExtensionAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionForegroundSession", "RequestExtensionAsync", 10usize, |this| ((**(this as *const *const IExtendedExecutionForegroundSession_Vtbl)).RequestExtensionAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut ExtendedExecutionForegroundReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionForegroundSession", "Reason", 11usize, |this| ((**(this as *const *const IExtendedExecutionForegroundSession_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReason(this: *mut core::ffi::c_void, value: ExtendedExecutionForegroundReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionForegroundSession", "SetReason", 12usize, |this| ((**(this as *const *const IExtendedExecutionForegroundSession_Vtbl)).SetReason)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Description,
+            SetDescription,
+            Revoked,
+            RemoveRevoked,
+            RequestExtensionAsync,
+            Reason,
+            SetReason,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExtendedExecutionForegroundSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
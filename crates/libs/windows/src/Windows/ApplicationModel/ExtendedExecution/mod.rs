@@ -146,6 +146,7 @@ impl ExtendedExecutionReason {
 impl windows_core::TypeKind for ExtendedExecutionReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ExtendedExecutionReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ExtendedExecutionReason").field(&self.0).finish()
@@ -164,6 +165,7 @@ impl ExtendedExecutionResult {
 impl windows_core::TypeKind for ExtendedExecutionResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ExtendedExecutionResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ExtendedExecutionResult").field(&self.0).finish()
@@ -182,6 +184,7 @@ impl ExtendedExecutionRevokedReason {
 impl windows_core::TypeKind for ExtendedExecutionRevokedReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ExtendedExecutionRevokedReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ExtendedExecutionRevokedReason").field(&self.0).finish()
@@ -190,3 +193,5 @@ impl core::fmt::Debug for ExtendedExecutionRevokedReason {
 impl windows_core::RuntimeType for ExtendedExecutionRevokedReason {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.ExtendedExecution.ExtendedExecutionRevokedReason;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,59 @@
+impl windows_core::imp::ProxyVtable for IExtendedExecutionRevokedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut ExtendedExecutionRevokedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionRevokedEventArgs", "Reason", 6usize, |this| ((**(this as *const *const IExtendedExecutionRevokedEventArgs_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExtendedExecutionRevokedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IExtendedExecutionSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut ExtendedExecutionReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionSession", "Reason", 6usize, |this| ((**(this as *const *const IExtendedExecutionSession_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReason(this: *mut core::ffi::c_void, value: ExtendedExecutionReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionSession", "SetReason", 7usize, |this| ((**(this as *const *const IExtendedExecutionSession_Vtbl)).SetReason)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionSession", "Description", 8usize, |this| ((**(this as *const *const IExtendedExecutionSession_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionSession", "SetDescription", 9usize, |this| ((**(this as *const *const IExtendedExecutionSession_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PercentProgress(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionSession", "PercentProgress", 10usize, |this| ((**(this as *const *const IExtendedExecutionSession_Vtbl)).PercentProgress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPercentProgress(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionSession", "SetPercentProgress", 11usize, |this| ((**(this as *const *const IExtendedExecutionSession_Vtbl)).SetPercentProgress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Revoked(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionSession", "Revoked", 12usize, |this| ((**(this as *const *const IExtendedExecutionSession_Vtbl)).Revoked)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRevoked(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionSession", "RemoveRevoked", 13usize, |this| ((**(this as *const *const IExtendedExecutionSession_Vtbl)).RemoveRevoked)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestExtensionAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendedExecutionSession", "RequestExtensionAsync", 14usize, |this| ((**(this as *const *const IExtendedExecutionSession_Vtbl)).RequestExtensionAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Reason,
+            SetReason,
+            Description,
+            SetDescription,
+            PercentProgress,
+            SetPercentProgress,
+            Revoked,
+            RemoveRevoked,
+            RequestExtensionAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExtendedExecutionSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
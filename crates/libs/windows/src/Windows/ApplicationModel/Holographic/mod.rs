@@ -73,3 +73,5 @@ impl windows_core::RuntimeName for HolographicKeyboard {
 }
 unsafe impl Send for HolographicKeyboard {}
 unsafe impl Sync for HolographicKeyboard {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,44 @@
+#[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for IHolographicKeyboard_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn SetPlacementOverride(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, topcenterposition: super::super::Foundation::Numerics::Vector3, orientation: super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicKeyboard", "SetPlacementOverride", 6usize, |this| ((**(this as *const *const IHolographicKeyboard_Vtbl)).SetPlacementOverride)(this, coordinatesystem, topcenterposition, orientation), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn SetPlacementOverrideWithMaxSize(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, topcenterposition: super::super::Foundation::Numerics::Vector3, orientation: super::super::Foundation::Numerics::Quaternion, maxsize: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicKeyboard", "SetPlacementOverrideWithMaxSize", 7usize, |this| ((**(this as *const *const IHolographicKeyboard_Vtbl)).SetPlacementOverrideWithMaxSize)(this, coordinatesystem, topcenterposition, orientation, maxsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetPlacementOverride(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicKeyboard", "ResetPlacementOverride", 8usize, |this| ((**(this as *const *const IHolographicKeyboard_Vtbl)).ResetPlacementOverride)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            SetPlacementOverride,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            SetPlacementOverride: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            SetPlacementOverrideWithMaxSize,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            SetPlacementOverrideWithMaxSize: 0,
+            ResetPlacementOverride,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicKeyboard as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicKeyboardStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicKeyboardStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IHolographicKeyboardStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicKeyboardStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
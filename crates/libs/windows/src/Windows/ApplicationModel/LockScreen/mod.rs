@@ -347,3 +347,5 @@ impl windows_core::RuntimeName for LockScreenUnlockingEventArgs {
 }
 unsafe impl Send for LockScreenUnlockingEventArgs {}
 unsafe impl Sync for LockScreenUnlockingEventArgs {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
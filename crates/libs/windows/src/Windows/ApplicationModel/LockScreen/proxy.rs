@@ -0,0 +1,173 @@
+impl windows_core::imp::ProxyVtable for ILockApplicationHost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestUnlock(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockApplicationHost", "RequestUnlock", 6usize, |this| ((**(this as *const *const ILockApplicationHost_Vtbl)).RequestUnlock)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unlocking(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockApplicationHost", "Unlocking", 7usize, |this| ((**(this as *const *const ILockApplicationHost_Vtbl)).Unlocking)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUnlocking(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockApplicationHost", "RemoveUnlocking", 8usize, |this| ((**(this as *const *const ILockApplicationHost_Vtbl)).RemoveUnlocking)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestUnlock, Unlocking, RemoveUnlocking }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILockApplicationHost as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILockApplicationHostStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockApplicationHostStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const ILockApplicationHostStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILockApplicationHostStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ILockScreenBadge_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Logo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenBadge", "Logo", 6usize, |this| ((**(this as *const *const ILockScreenBadge_Vtbl)).Logo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Glyph(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenBadge", "Glyph", 7usize, |this| ((**(this as *const *const ILockScreenBadge_Vtbl)).Glyph)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Number(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenBadge", "Number", 8usize, |this| ((**(this as *const *const ILockScreenBadge_Vtbl)).Number)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutomationName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenBadge", "AutomationName", 9usize, |this| ((**(this as *const *const ILockScreenBadge_Vtbl)).AutomationName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchApp(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenBadge", "LaunchApp", 10usize, |this| ((**(this as *const *const ILockScreenBadge_Vtbl)).LaunchApp)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Logo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Logo: 0,
+            #[cfg(feature = "Storage_Streams")]
+            Glyph,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Glyph: 0,
+            Number,
+            AutomationName,
+            LaunchApp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILockScreenBadge as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ILockScreenInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LockScreenImageChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenInfo", "LockScreenImageChanged", 6usize, |this| ((**(this as *const *const ILockScreenInfo_Vtbl)).LockScreenImageChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLockScreenImageChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenInfo", "RemoveLockScreenImageChanged", 7usize, |this| ((**(this as *const *const ILockScreenInfo_Vtbl)).RemoveLockScreenImageChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn LockScreenImage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenInfo", "LockScreenImage", 8usize, |this| ((**(this as *const *const ILockScreenInfo_Vtbl)).LockScreenImage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BadgesChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenInfo", "BadgesChanged", 9usize, |this| ((**(this as *const *const ILockScreenInfo_Vtbl)).BadgesChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBadgesChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenInfo", "RemoveBadgesChanged", 10usize, |this| ((**(this as *const *const ILockScreenInfo_Vtbl)).RemoveBadgesChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Badges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenInfo", "Badges", 11usize, |this| ((**(this as *const *const ILockScreenInfo_Vtbl)).Badges)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetailTextChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenInfo", "DetailTextChanged", 12usize, |this| ((**(this as *const *const ILockScreenInfo_Vtbl)).DetailTextChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDetailTextChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenInfo", "RemoveDetailTextChanged", 13usize, |this| ((**(this as *const *const ILockScreenInfo_Vtbl)).RemoveDetailTextChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DetailText(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenInfo", "DetailText", 14usize, |this| ((**(this as *const *const ILockScreenInfo_Vtbl)).DetailText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlarmIconChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenInfo", "AlarmIconChanged", 15usize, |this| ((**(this as *const *const ILockScreenInfo_Vtbl)).AlarmIconChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAlarmIconChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenInfo", "RemoveAlarmIconChanged", 16usize, |this| ((**(this as *const *const ILockScreenInfo_Vtbl)).RemoveAlarmIconChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AlarmIcon(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenInfo", "AlarmIcon", 17usize, |this| ((**(this as *const *const ILockScreenInfo_Vtbl)).AlarmIcon)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LockScreenImageChanged,
+            RemoveLockScreenImageChanged,
+            #[cfg(feature = "Storage_Streams")]
+            LockScreenImage,
+            #[cfg(not(feature = "Storage_Streams"))]
+            LockScreenImage: 0,
+            BadgesChanged,
+            RemoveBadgesChanged,
+            #[cfg(feature = "Foundation_Collections")]
+            Badges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Badges: 0,
+            DetailTextChanged,
+            RemoveDetailTextChanged,
+            #[cfg(feature = "Foundation_Collections")]
+            DetailText,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DetailText: 0,
+            AlarmIconChanged,
+            RemoveAlarmIconChanged,
+            #[cfg(feature = "Storage_Streams")]
+            AlarmIcon,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AlarmIcon: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILockScreenInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILockScreenUnlockingDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenUnlockingDeferral", "Complete", 6usize, |this| ((**(this as *const *const ILockScreenUnlockingDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILockScreenUnlockingDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILockScreenUnlockingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenUnlockingEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const ILockScreenUnlockingEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenUnlockingEventArgs", "Deadline", 7usize, |this| ((**(this as *const *const ILockScreenUnlockingEventArgs_Vtbl)).Deadline)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral, Deadline }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILockScreenUnlockingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
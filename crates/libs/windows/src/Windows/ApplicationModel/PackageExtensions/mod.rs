@@ -504,3 +504,5 @@ impl windows_core::RuntimeName for PackageExtensionPackageUpdatingEventArgs {
 }
 unsafe impl Send for PackageExtensionPackageUpdatingEventArgs {}
 unsafe impl Sync for PackageExtensionPackageUpdatingEventArgs {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
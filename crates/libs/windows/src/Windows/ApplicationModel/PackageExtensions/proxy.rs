@@ -0,0 +1,249 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IPackageExtension_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtension", "Id", 6usize, |this| ((**(this as *const *const IPackageExtension_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtension", "DisplayName", 7usize, |this| ((**(this as *const *const IPackageExtension_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtension", "Description", 8usize, |this| ((**(this as *const *const IPackageExtension_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtension", "Package", 9usize, |this| ((**(this as *const *const IPackageExtension_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetExtensionProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtension", "GetExtensionProperties", 10usize, |this| ((**(this as *const *const IPackageExtension_Vtbl)).GetExtensionProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetExtensionPropertiesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtension", "GetExtensionPropertiesAsync", 11usize, |this| ((**(this as *const *const IPackageExtension_Vtbl)).GetExtensionPropertiesAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPublicPath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtension", "GetPublicPath", 12usize, |this| ((**(this as *const *const IPackageExtension_Vtbl)).GetPublicPath)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn GetPublicFolder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtension", "GetPublicFolder", 13usize, |this| ((**(this as *const *const IPackageExtension_Vtbl)).GetPublicFolder)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn GetPublicFolderAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtension", "GetPublicFolderAsync", 14usize, |this| ((**(this as *const *const IPackageExtension_Vtbl)).GetPublicFolderAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            DisplayName,
+            Description,
+            Package,
+            #[cfg(feature = "Foundation_Collections")]
+            GetExtensionProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetExtensionProperties: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetExtensionPropertiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetExtensionPropertiesAsync: 0,
+            GetPublicPath,
+            #[cfg(feature = "Storage")]
+            GetPublicFolder,
+            #[cfg(not(feature = "Storage"))]
+            GetPublicFolder: 0,
+            #[cfg(feature = "Storage")]
+            GetPublicFolderAsync,
+            #[cfg(not(feature = "Storage"))]
+            GetPublicFolderAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageExtension as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPackageExtensionCatalog_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAll(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionCatalog", "FindAll", 6usize, |this| ((**(this as *const *const IPackageExtensionCatalog_Vtbl)).FindAll)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionCatalog", "FindAllAsync", 7usize, |this| ((**(this as *const *const IPackageExtensionCatalog_Vtbl)).FindAllAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestRemovePackageAsync(this: *mut core::ffi::c_void, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionCatalog", "RequestRemovePackageAsync", 8usize, |this| ((**(this as *const *const IPackageExtensionCatalog_Vtbl)).RequestRemovePackageAsync)(this, packagefullname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageInstalled(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionCatalog", "PackageInstalled", 9usize, |this| ((**(this as *const *const IPackageExtensionCatalog_Vtbl)).PackageInstalled)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageInstalled(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionCatalog", "RemovePackageInstalled", 10usize, |this| ((**(this as *const *const IPackageExtensionCatalog_Vtbl)).RemovePackageInstalled)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageUpdating(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionCatalog", "PackageUpdating", 11usize, |this| ((**(this as *const *const IPackageExtensionCatalog_Vtbl)).PackageUpdating)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageUpdating(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionCatalog", "RemovePackageUpdating", 12usize, |this| ((**(this as *const *const IPackageExtensionCatalog_Vtbl)).RemovePackageUpdating)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionCatalog", "PackageUpdated", 13usize, |this| ((**(this as *const *const IPackageExtensionCatalog_Vtbl)).PackageUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionCatalog", "RemovePackageUpdated", 14usize, |this| ((**(this as *const *const IPackageExtensionCatalog_Vtbl)).RemovePackageUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageUninstalling(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionCatalog", "PackageUninstalling", 15usize, |this| ((**(this as *const *const IPackageExtensionCatalog_Vtbl)).PackageUninstalling)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageUninstalling(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionCatalog", "RemovePackageUninstalling", 16usize, |this| ((**(this as *const *const IPackageExtensionCatalog_Vtbl)).RemovePackageUninstalling)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionCatalog", "PackageStatusChanged", 17usize, |this| ((**(this as *const *const IPackageExtensionCatalog_Vtbl)).PackageStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionCatalog", "RemovePackageStatusChanged", 18usize, |this| ((**(this as *const *const IPackageExtensionCatalog_Vtbl)).RemovePackageStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAll,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAll: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsync: 0,
+            RequestRemovePackageAsync,
+            PackageInstalled,
+            RemovePackageInstalled,
+            PackageUpdating,
+            RemovePackageUpdating,
+            PackageUpdated,
+            RemovePackageUpdated,
+            PackageUninstalling,
+            RemovePackageUninstalling,
+            PackageStatusChanged,
+            RemovePackageStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageExtensionCatalog as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageExtensionCatalogStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, packageextensionname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionCatalogStatics", "Open", 6usize, |this| ((**(this as *const *const IPackageExtensionCatalogStatics_Vtbl)).Open)(this, packageextensionname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Open }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageExtensionCatalogStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPackageExtensionPackageInstalledEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PackageExtensionName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionPackageInstalledEventArgs", "PackageExtensionName", 6usize, |this| ((**(this as *const *const IPackageExtensionPackageInstalledEventArgs_Vtbl)).PackageExtensionName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionPackageInstalledEventArgs", "Package", 7usize, |this| ((**(this as *const *const IPackageExtensionPackageInstalledEventArgs_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Extensions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionPackageInstalledEventArgs", "Extensions", 8usize, |this| ((**(this as *const *const IPackageExtensionPackageInstalledEventArgs_Vtbl)).Extensions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PackageExtensionName,
+            Package,
+            #[cfg(feature = "Foundation_Collections")]
+            Extensions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Extensions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageExtensionPackageInstalledEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageExtensionPackageStatusChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PackageExtensionName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionPackageStatusChangedEventArgs", "PackageExtensionName", 6usize, |this| ((**(this as *const *const IPackageExtensionPackageStatusChangedEventArgs_Vtbl)).PackageExtensionName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionPackageStatusChangedEventArgs", "Package", 7usize, |this| ((**(this as *const *const IPackageExtensionPackageStatusChangedEventArgs_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PackageExtensionName, Package }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageExtensionPackageStatusChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageExtensionPackageUninstallingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PackageExtensionName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionPackageUninstallingEventArgs", "PackageExtensionName", 6usize, |this| ((**(this as *const *const IPackageExtensionPackageUninstallingEventArgs_Vtbl)).PackageExtensionName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionPackageUninstallingEventArgs", "Package", 7usize, |this| ((**(this as *const *const IPackageExtensionPackageUninstallingEventArgs_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PackageExtensionName, Package }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageExtensionPackageUninstallingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPackageExtensionPackageUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PackageExtensionName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionPackageUpdatedEventArgs", "PackageExtensionName", 6usize, |this| ((**(this as *const *const IPackageExtensionPackageUpdatedEventArgs_Vtbl)).PackageExtensionName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionPackageUpdatedEventArgs", "Package", 7usize, |this| ((**(this as *const *const IPackageExtensionPackageUpdatedEventArgs_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Extensions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionPackageUpdatedEventArgs", "Extensions", 8usize, |this| ((**(this as *const *const IPackageExtensionPackageUpdatedEventArgs_Vtbl)).Extensions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PackageExtensionName,
+            Package,
+            #[cfg(feature = "Foundation_Collections")]
+            Extensions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Extensions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageExtensionPackageUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageExtensionPackageUpdatingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PackageExtensionName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionPackageUpdatingEventArgs", "PackageExtensionName", 6usize, |this| ((**(this as *const *const IPackageExtensionPackageUpdatingEventArgs_Vtbl)).PackageExtensionName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageExtensionPackageUpdatingEventArgs", "Package", 7usize, |this| ((**(this as *const *const IPackageExtensionPackageUpdatingEventArgs_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PackageExtensionName, Package }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageExtensionPackageUpdatingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -274,3 +274,5 @@ impl windows_core::RuntimeName for PaymentTransactionAcceptResult {
 }
 unsafe impl Send for PaymentTransactionAcceptResult {}
 unsafe impl Sync for PaymentTransactionAcceptResult {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
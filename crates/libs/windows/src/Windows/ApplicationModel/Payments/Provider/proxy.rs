@@ -0,0 +1,130 @@
+impl windows_core::imp::ProxyVtable for IPaymentAppCanMakePaymentTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAppCanMakePaymentTriggerDetails", "Request", 6usize, |this| ((**(this as *const *const IPaymentAppCanMakePaymentTriggerDetails_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCanMakePaymentResult(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAppCanMakePaymentTriggerDetails", "ReportCanMakePaymentResult", 7usize, |this| ((**(this as *const *const IPaymentAppCanMakePaymentTriggerDetails_Vtbl)).ReportCanMakePaymentResult)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, ReportCanMakePaymentResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentAppCanMakePaymentTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPaymentAppManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RegisterAsync(this: *mut core::ffi::c_void, supportedpaymentmethodids: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAppManager", "RegisterAsync", 6usize, |this| ((**(this as *const *const IPaymentAppManager_Vtbl)).RegisterAsync)(this, supportedpaymentmethodids, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAppManager", "UnregisterAsync", 7usize, |this| ((**(this as *const *const IPaymentAppManager_Vtbl)).UnregisterAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            RegisterAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RegisterAsync: 0,
+            UnregisterAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentAppManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentAppManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAppManagerStatics", "Current", 6usize, |this| ((**(this as *const *const IPaymentAppManagerStatics_Vtbl)).Current)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Current }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentAppManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentTransaction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PaymentRequest(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentTransaction", "PaymentRequest", 6usize, |this| ((**(this as *const *const IPaymentTransaction_Vtbl)).PaymentRequest)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PayerEmail(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentTransaction", "PayerEmail", 7usize, |this| ((**(this as *const *const IPaymentTransaction_Vtbl)).PayerEmail)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPayerEmail(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentTransaction", "SetPayerEmail", 8usize, |this| ((**(this as *const *const IPaymentTransaction_Vtbl)).SetPayerEmail)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PayerName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentTransaction", "PayerName", 9usize, |this| ((**(this as *const *const IPaymentTransaction_Vtbl)).PayerName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPayerName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentTransaction", "SetPayerName", 10usize, |this| ((**(this as *const *const IPaymentTransaction_Vtbl)).SetPayerName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PayerPhoneNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentTransaction", "PayerPhoneNumber", 11usize, |this| ((**(this as *const *const IPaymentTransaction_Vtbl)).PayerPhoneNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPayerPhoneNumber(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentTransaction", "SetPayerPhoneNumber", 12usize, |this| ((**(this as *const *const IPaymentTransaction_Vtbl)).SetPayerPhoneNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateShippingAddressAsync(this: *mut core::ffi::c_void, shippingaddress: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentTransaction", "UpdateShippingAddressAsync", 13usize, |this| ((**(this as *const *const IPaymentTransaction_Vtbl)).UpdateShippingAddressAsync)(this, shippingaddress, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateSelectedShippingOptionAsync(this: *mut core::ffi::c_void, selectedshippingoption: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentTransaction", "UpdateSelectedShippingOptionAsync", 14usize, |this| ((**(this as *const *const IPaymentTransaction_Vtbl)).UpdateSelectedShippingOptionAsync)(this, selectedshippingoption, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptAsync(this: *mut core::ffi::c_void, paymenttoken: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentTransaction", "AcceptAsync", 15usize, |this| ((**(this as *const *const IPaymentTransaction_Vtbl)).AcceptAsync)(this, paymenttoken, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reject(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentTransaction", "Reject", 16usize, |this| ((**(this as *const *const IPaymentTransaction_Vtbl)).Reject)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PaymentRequest,
+            PayerEmail,
+            SetPayerEmail,
+            PayerName,
+            SetPayerName,
+            PayerPhoneNumber,
+            SetPayerPhoneNumber,
+            UpdateShippingAddressAsync,
+            UpdateSelectedShippingOptionAsync,
+            AcceptAsync,
+            Reject,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentTransaction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentTransactionAcceptResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut super::PaymentRequestCompletionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentTransactionAcceptResult", "Status", 6usize, |this| ((**(this as *const *const IPaymentTransactionAcceptResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentTransactionAcceptResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentTransactionStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentTransactionStatics", "FromIdAsync", 6usize, |this| ((**(this as *const *const IPaymentTransactionStatics_Vtbl)).FromIdAsync)(this, id, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentTransactionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
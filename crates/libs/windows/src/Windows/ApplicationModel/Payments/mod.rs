@@ -1722,6 +1722,7 @@ impl PaymentCanMakePaymentResultStatus {
 impl windows_core::TypeKind for PaymentCanMakePaymentResultStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PaymentCanMakePaymentResultStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PaymentCanMakePaymentResultStatus").field(&self.0).finish()
@@ -1741,6 +1742,7 @@ impl PaymentOptionPresence {
 impl windows_core::TypeKind for PaymentOptionPresence {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PaymentOptionPresence {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PaymentOptionPresence").field(&self.0).finish()
@@ -1759,6 +1761,7 @@ impl PaymentRequestChangeKind {
 impl windows_core::TypeKind for PaymentRequestChangeKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PaymentRequestChangeKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PaymentRequestChangeKind").field(&self.0).finish()
@@ -1778,6 +1781,7 @@ impl PaymentRequestCompletionStatus {
 impl windows_core::TypeKind for PaymentRequestCompletionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PaymentRequestCompletionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PaymentRequestCompletionStatus").field(&self.0).finish()
@@ -1797,6 +1801,7 @@ impl PaymentRequestStatus {
 impl windows_core::TypeKind for PaymentRequestStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PaymentRequestStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PaymentRequestStatus").field(&self.0).finish()
@@ -1816,6 +1821,7 @@ impl PaymentShippingType {
 impl windows_core::TypeKind for PaymentShippingType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PaymentShippingType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PaymentShippingType").field(&self.0).finish()
@@ -1885,3 +1891,5 @@ pub struct PaymentRequestChangedHandler_Vtbl {
     pub base__: windows_core::IUnknown_Vtbl,
     pub Invoke: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,885 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPaymentAddress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Country(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "Country", 6usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).Country)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCountry(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "SetCountry", 7usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).SetCountry)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AddressLines(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "AddressLines", 8usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).AddressLines)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetAddressLines(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "SetAddressLines", 9usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).SetAddressLines)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Region(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "Region", 10usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).Region)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRegion(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "SetRegion", 11usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).SetRegion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn City(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "City", 12usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).City)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCity(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "SetCity", 13usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).SetCity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DependentLocality(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "DependentLocality", 14usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).DependentLocality)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDependentLocality(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "SetDependentLocality", 15usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).SetDependentLocality)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostalCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "PostalCode", 16usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).PostalCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPostalCode(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "SetPostalCode", 17usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).SetPostalCode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SortingCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "SortingCode", 18usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).SortingCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSortingCode(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "SetSortingCode", 19usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).SetSortingCode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LanguageCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "LanguageCode", 20usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).LanguageCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguageCode(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "SetLanguageCode", 21usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).SetLanguageCode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Organization(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "Organization", 22usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).Organization)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOrganization(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "SetOrganization", 23usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).SetOrganization)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Recipient(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "Recipient", 24usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).Recipient)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecipient(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "SetRecipient", 25usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).SetRecipient)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhoneNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "PhoneNumber", 26usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).PhoneNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPhoneNumber(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "SetPhoneNumber", 27usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).SetPhoneNumber)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentAddress", "Properties", 28usize, |this| ((**(this as *const *const IPaymentAddress_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Country,
+            SetCountry,
+            #[cfg(feature = "Foundation_Collections")]
+            AddressLines,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AddressLines: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetAddressLines,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetAddressLines: 0,
+            Region,
+            SetRegion,
+            City,
+            SetCity,
+            DependentLocality,
+            SetDependentLocality,
+            PostalCode,
+            SetPostalCode,
+            SortingCode,
+            SetSortingCode,
+            LanguageCode,
+            SetLanguageCode,
+            Organization,
+            SetOrganization,
+            Recipient,
+            SetRecipient,
+            PhoneNumber,
+            SetPhoneNumber,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentAddress as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentCanMakePaymentResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut PaymentCanMakePaymentResultStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentCanMakePaymentResult", "Status", 6usize, |this| ((**(this as *const *const IPaymentCanMakePaymentResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentCanMakePaymentResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentCanMakePaymentResultFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, value: PaymentCanMakePaymentResultStatus, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentCanMakePaymentResultFactory", "Create", 6usize, |this| ((**(this as *const *const IPaymentCanMakePaymentResultFactory_Vtbl)).Create)(this, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentCanMakePaymentResultFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentCurrencyAmount_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Currency(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentCurrencyAmount", "Currency", 6usize, |this| ((**(this as *const *const IPaymentCurrencyAmount_Vtbl)).Currency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrency(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentCurrencyAmount", "SetCurrency", 7usize, |this| ((**(this as *const *const IPaymentCurrencyAmount_Vtbl)).SetCurrency)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrencySystem(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentCurrencyAmount", "CurrencySystem", 8usize, |this| ((**(this as *const *const IPaymentCurrencyAmount_Vtbl)).CurrencySystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrencySystem(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentCurrencyAmount", "SetCurrencySystem", 9usize, |this| ((**(this as *const *const IPaymentCurrencyAmount_Vtbl)).SetCurrencySystem)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentCurrencyAmount", "Value", 10usize, |this| ((**(this as *const *const IPaymentCurrencyAmount_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentCurrencyAmount", "SetValue", 11usize, |this| ((**(this as *const *const IPaymentCurrencyAmount_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Currency,
+            SetCurrency,
+            CurrencySystem,
+            SetCurrencySystem,
+            Value,
+            SetValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentCurrencyAmount as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentCurrencyAmountFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, currency: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentCurrencyAmountFactory", "Create", 6usize, |this| ((**(this as *const *const IPaymentCurrencyAmountFactory_Vtbl)).Create)(this, value, currency, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithCurrencySystem(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, currency: core::mem::MaybeUninit<windows_core::HSTRING>, currencysystem: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentCurrencyAmountFactory", "CreateWithCurrencySystem", 7usize, |this| ((**(this as *const *const IPaymentCurrencyAmountFactory_Vtbl)).CreateWithCurrencySystem)(this, value, currency, currencysystem, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithCurrencySystem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentCurrencyAmountFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPaymentDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Total(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetails", "Total", 6usize, |this| ((**(this as *const *const IPaymentDetails_Vtbl)).Total)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTotal(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetails", "SetTotal", 7usize, |this| ((**(this as *const *const IPaymentDetails_Vtbl)).SetTotal)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DisplayItems(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetails", "DisplayItems", 8usize, |this| ((**(this as *const *const IPaymentDetails_Vtbl)).DisplayItems)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetDisplayItems(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetails", "SetDisplayItems", 9usize, |this| ((**(this as *const *const IPaymentDetails_Vtbl)).SetDisplayItems)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ShippingOptions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetails", "ShippingOptions", 10usize, |this| ((**(this as *const *const IPaymentDetails_Vtbl)).ShippingOptions)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetShippingOptions(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetails", "SetShippingOptions", 11usize, |this| ((**(this as *const *const IPaymentDetails_Vtbl)).SetShippingOptions)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Modifiers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetails", "Modifiers", 12usize, |this| ((**(this as *const *const IPaymentDetails_Vtbl)).Modifiers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetModifiers(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetails", "SetModifiers", 13usize, |this| ((**(this as *const *const IPaymentDetails_Vtbl)).SetModifiers)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Total,
+            SetTotal,
+            #[cfg(feature = "Foundation_Collections")]
+            DisplayItems,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DisplayItems: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetDisplayItems,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetDisplayItems: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ShippingOptions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ShippingOptions: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetShippingOptions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetShippingOptions: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Modifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Modifiers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetModifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetModifiers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPaymentDetailsFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, total: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetailsFactory", "Create", 6usize, |this| ((**(this as *const *const IPaymentDetailsFactory_Vtbl)).Create)(this, total, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWithDisplayItems(this: *mut core::ffi::c_void, total: *mut core::ffi::c_void, displayitems: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetailsFactory", "CreateWithDisplayItems", 7usize, |this| ((**(this as *const *const IPaymentDetailsFactory_Vtbl)).CreateWithDisplayItems)(this, total, displayitems, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWithDisplayItems,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWithDisplayItems: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentDetailsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPaymentDetailsModifier_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn JsonData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetailsModifier", "JsonData", 6usize, |this| ((**(this as *const *const IPaymentDetailsModifier_Vtbl)).JsonData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedMethodIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetailsModifier", "SupportedMethodIds", 7usize, |this| ((**(this as *const *const IPaymentDetailsModifier_Vtbl)).SupportedMethodIds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Total(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetailsModifier", "Total", 8usize, |this| ((**(this as *const *const IPaymentDetailsModifier_Vtbl)).Total)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AdditionalDisplayItems(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetailsModifier", "AdditionalDisplayItems", 9usize, |this| ((**(this as *const *const IPaymentDetailsModifier_Vtbl)).AdditionalDisplayItems)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            JsonData,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedMethodIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedMethodIds: 0,
+            Total,
+            #[cfg(feature = "Foundation_Collections")]
+            AdditionalDisplayItems,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AdditionalDisplayItems: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentDetailsModifier as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPaymentDetailsModifierFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, supportedmethodids: *mut core::ffi::c_void, total: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetailsModifierFactory", "Create", 6usize, |this| ((**(this as *const *const IPaymentDetailsModifierFactory_Vtbl)).Create)(this, supportedmethodids, total, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWithAdditionalDisplayItems(this: *mut core::ffi::c_void, supportedmethodids: *mut core::ffi::c_void, total: *mut core::ffi::c_void, additionaldisplayitems: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetailsModifierFactory", "CreateWithAdditionalDisplayItems", 7usize, |this| ((**(this as *const *const IPaymentDetailsModifierFactory_Vtbl)).CreateWithAdditionalDisplayItems)(this, supportedmethodids, total, additionaldisplayitems, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWithAdditionalDisplayItemsAndJsonData(this: *mut core::ffi::c_void, supportedmethodids: *mut core::ffi::c_void, total: *mut core::ffi::c_void, additionaldisplayitems: *mut core::ffi::c_void, jsondata: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentDetailsModifierFactory", "CreateWithAdditionalDisplayItemsAndJsonData", 8usize, |this| ((**(this as *const *const IPaymentDetailsModifierFactory_Vtbl)).CreateWithAdditionalDisplayItemsAndJsonData)(this, supportedmethodids, total, additionaldisplayitems, jsondata, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Create,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWithAdditionalDisplayItems,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWithAdditionalDisplayItems: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWithAdditionalDisplayItemsAndJsonData,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWithAdditionalDisplayItemsAndJsonData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentDetailsModifierFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentItem", "Label", 6usize, |this| ((**(this as *const *const IPaymentItem_Vtbl)).Label)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentItem", "SetLabel", 7usize, |this| ((**(this as *const *const IPaymentItem_Vtbl)).SetLabel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Amount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentItem", "Amount", 8usize, |this| ((**(this as *const *const IPaymentItem_Vtbl)).Amount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAmount(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentItem", "SetAmount", 9usize, |this| ((**(this as *const *const IPaymentItem_Vtbl)).SetAmount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pending(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentItem", "Pending", 10usize, |this| ((**(this as *const *const IPaymentItem_Vtbl)).Pending)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPending(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentItem", "SetPending", 11usize, |this| ((**(this as *const *const IPaymentItem_Vtbl)).SetPending)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Label, SetLabel, Amount, SetAmount, Pending, SetPending }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentItemFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, label: core::mem::MaybeUninit<windows_core::HSTRING>, amount: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentItemFactory", "Create", 6usize, |this| ((**(this as *const *const IPaymentItemFactory_Vtbl)).Create)(this, label, amount, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentItemFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPaymentMediator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSupportedMethodIdsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentMediator", "GetSupportedMethodIdsAsync", 6usize, |this| ((**(this as *const *const IPaymentMediator_Vtbl)).GetSupportedMethodIdsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubmitPaymentRequestAsync(this: *mut core::ffi::c_void, paymentrequest: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentMediator", "SubmitPaymentRequestAsync", 7usize, |this| ((**(this as *const *const IPaymentMediator_Vtbl)).SubmitPaymentRequestAsync)(this, paymentrequest, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubmitPaymentRequestWithChangeHandlerAsync(this: *mut core::ffi::c_void, paymentrequest: *mut core::ffi::c_void, changehandler: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentMediator", "SubmitPaymentRequestWithChangeHandlerAsync", 8usize, |this| ((**(this as *const *const IPaymentMediator_Vtbl)).SubmitPaymentRequestWithChangeHandlerAsync)(this, paymentrequest, changehandler, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSupportedMethodIdsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSupportedMethodIdsAsync: 0,
+            SubmitPaymentRequestAsync,
+            SubmitPaymentRequestWithChangeHandlerAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentMediator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentMediator2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanMakePaymentAsync(this: *mut core::ffi::c_void, paymentrequest: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentMediator2", "CanMakePaymentAsync", 6usize, |this| ((**(this as *const *const IPaymentMediator2_Vtbl)).CanMakePaymentAsync)(this, paymentrequest, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CanMakePaymentAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentMediator2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentMerchantInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PackageFullName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentMerchantInfo", "PackageFullName", 6usize, |this| ((**(this as *const *const IPaymentMerchantInfo_Vtbl)).PackageFullName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentMerchantInfo", "Uri", 7usize, |this| ((**(this as *const *const IPaymentMerchantInfo_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PackageFullName, Uri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentMerchantInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentMerchantInfoFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentMerchantInfoFactory", "Create", 6usize, |this| ((**(this as *const *const IPaymentMerchantInfoFactory_Vtbl)).Create)(this, uri, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentMerchantInfoFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPaymentMethodData_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedMethodIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentMethodData", "SupportedMethodIds", 6usize, |this| ((**(this as *const *const IPaymentMethodData_Vtbl)).SupportedMethodIds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JsonData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentMethodData", "JsonData", 7usize, |this| ((**(this as *const *const IPaymentMethodData_Vtbl)).JsonData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedMethodIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedMethodIds: 0,
+            JsonData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentMethodData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPaymentMethodDataFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, supportedmethodids: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentMethodDataFactory", "Create", 6usize, |this| ((**(this as *const *const IPaymentMethodDataFactory_Vtbl)).Create)(this, supportedmethodids, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWithJsonData(this: *mut core::ffi::c_void, supportedmethodids: *mut core::ffi::c_void, jsondata: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentMethodDataFactory", "CreateWithJsonData", 7usize, |this| ((**(this as *const *const IPaymentMethodDataFactory_Vtbl)).CreateWithJsonData)(this, supportedmethodids, jsondata, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Create,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWithJsonData,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWithJsonData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentMethodDataFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestPayerEmail(this: *mut core::ffi::c_void, result__: *mut PaymentOptionPresence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentOptions", "RequestPayerEmail", 6usize, |this| ((**(this as *const *const IPaymentOptions_Vtbl)).RequestPayerEmail)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestPayerEmail(this: *mut core::ffi::c_void, value: PaymentOptionPresence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentOptions", "SetRequestPayerEmail", 7usize, |this| ((**(this as *const *const IPaymentOptions_Vtbl)).SetRequestPayerEmail)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPayerName(this: *mut core::ffi::c_void, result__: *mut PaymentOptionPresence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentOptions", "RequestPayerName", 8usize, |this| ((**(this as *const *const IPaymentOptions_Vtbl)).RequestPayerName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestPayerName(this: *mut core::ffi::c_void, value: PaymentOptionPresence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentOptions", "SetRequestPayerName", 9usize, |this| ((**(this as *const *const IPaymentOptions_Vtbl)).SetRequestPayerName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPayerPhoneNumber(this: *mut core::ffi::c_void, result__: *mut PaymentOptionPresence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentOptions", "RequestPayerPhoneNumber", 10usize, |this| ((**(this as *const *const IPaymentOptions_Vtbl)).RequestPayerPhoneNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestPayerPhoneNumber(this: *mut core::ffi::c_void, value: PaymentOptionPresence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentOptions", "SetRequestPayerPhoneNumber", 11usize, |this| ((**(this as *const *const IPaymentOptions_Vtbl)).SetRequestPayerPhoneNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestShipping(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentOptions", "RequestShipping", 12usize, |this| ((**(this as *const *const IPaymentOptions_Vtbl)).RequestShipping)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestShipping(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentOptions", "SetRequestShipping", 13usize, |this| ((**(this as *const *const IPaymentOptions_Vtbl)).SetRequestShipping)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShippingType(this: *mut core::ffi::c_void, result__: *mut PaymentShippingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentOptions", "ShippingType", 14usize, |this| ((**(this as *const *const IPaymentOptions_Vtbl)).ShippingType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShippingType(this: *mut core::ffi::c_void, value: PaymentShippingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentOptions", "SetShippingType", 15usize, |this| ((**(this as *const *const IPaymentOptions_Vtbl)).SetShippingType)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestPayerEmail,
+            SetRequestPayerEmail,
+            RequestPayerName,
+            SetRequestPayerName,
+            RequestPayerPhoneNumber,
+            SetRequestPayerPhoneNumber,
+            RequestShipping,
+            SetRequestShipping,
+            ShippingType,
+            SetShippingType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPaymentRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MerchantInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequest", "MerchantInfo", 6usize, |this| ((**(this as *const *const IPaymentRequest_Vtbl)).MerchantInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Details(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequest", "Details", 7usize, |this| ((**(this as *const *const IPaymentRequest_Vtbl)).Details)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MethodData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequest", "MethodData", 8usize, |this| ((**(this as *const *const IPaymentRequest_Vtbl)).MethodData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Options(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequest", "Options", 9usize, |this| ((**(this as *const *const IPaymentRequest_Vtbl)).Options)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MerchantInfo,
+            Details,
+            #[cfg(feature = "Foundation_Collections")]
+            MethodData,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MethodData: 0,
+            Options,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequest2", "Id", 6usize, |this| ((**(this as *const *const IPaymentRequest2_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentRequest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentRequestChangedArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeKind(this: *mut core::ffi::c_void, result__: *mut PaymentRequestChangeKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestChangedArgs", "ChangeKind", 6usize, |this| ((**(this as *const *const IPaymentRequestChangedArgs_Vtbl)).ChangeKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShippingAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestChangedArgs", "ShippingAddress", 7usize, |this| ((**(this as *const *const IPaymentRequestChangedArgs_Vtbl)).ShippingAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedShippingOption(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestChangedArgs", "SelectedShippingOption", 8usize, |this| ((**(this as *const *const IPaymentRequestChangedArgs_Vtbl)).SelectedShippingOption)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Acknowledge(this: *mut core::ffi::c_void, changeresult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestChangedArgs", "Acknowledge", 9usize, |this| ((**(this as *const *const IPaymentRequestChangedArgs_Vtbl)).Acknowledge)(this, changeresult), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChangeKind,
+            ShippingAddress,
+            SelectedShippingOption,
+            Acknowledge,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentRequestChangedArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentRequestChangedResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeAcceptedByMerchant(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestChangedResult", "ChangeAcceptedByMerchant", 6usize, |this| ((**(this as *const *const IPaymentRequestChangedResult_Vtbl)).ChangeAcceptedByMerchant)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChangeAcceptedByMerchant(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestChangedResult", "SetChangeAcceptedByMerchant", 7usize, |this| ((**(this as *const *const IPaymentRequestChangedResult_Vtbl)).SetChangeAcceptedByMerchant)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestChangedResult", "Message", 8usize, |this| ((**(this as *const *const IPaymentRequestChangedResult_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestChangedResult", "SetMessage", 9usize, |this| ((**(this as *const *const IPaymentRequestChangedResult_Vtbl)).SetMessage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdatedPaymentDetails(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestChangedResult", "UpdatedPaymentDetails", 10usize, |this| ((**(this as *const *const IPaymentRequestChangedResult_Vtbl)).UpdatedPaymentDetails)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUpdatedPaymentDetails(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestChangedResult", "SetUpdatedPaymentDetails", 11usize, |this| ((**(this as *const *const IPaymentRequestChangedResult_Vtbl)).SetUpdatedPaymentDetails)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChangeAcceptedByMerchant,
+            SetChangeAcceptedByMerchant,
+            Message,
+            SetMessage,
+            UpdatedPaymentDetails,
+            SetUpdatedPaymentDetails,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentRequestChangedResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentRequestChangedResultFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, changeacceptedbymerchant: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestChangedResultFactory", "Create", 6usize, |this| ((**(this as *const *const IPaymentRequestChangedResultFactory_Vtbl)).Create)(this, changeacceptedbymerchant, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithPaymentDetails(this: *mut core::ffi::c_void, changeacceptedbymerchant: bool, updatedpaymentdetails: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestChangedResultFactory", "CreateWithPaymentDetails", 7usize, |this| ((**(this as *const *const IPaymentRequestChangedResultFactory_Vtbl)).CreateWithPaymentDetails)(this, changeacceptedbymerchant, updatedpaymentdetails, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithPaymentDetails }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentRequestChangedResultFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPaymentRequestFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, details: *mut core::ffi::c_void, methoddata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestFactory", "Create", 6usize, |this| ((**(this as *const *const IPaymentRequestFactory_Vtbl)).Create)(this, details, methoddata, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWithMerchantInfo(this: *mut core::ffi::c_void, details: *mut core::ffi::c_void, methoddata: *mut core::ffi::c_void, merchantinfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestFactory", "CreateWithMerchantInfo", 7usize, |this| ((**(this as *const *const IPaymentRequestFactory_Vtbl)).CreateWithMerchantInfo)(this, details, methoddata, merchantinfo, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWithMerchantInfoAndOptions(this: *mut core::ffi::c_void, details: *mut core::ffi::c_void, methoddata: *mut core::ffi::c_void, merchantinfo: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestFactory", "CreateWithMerchantInfoAndOptions", 8usize, |this| ((**(this as *const *const IPaymentRequestFactory_Vtbl)).CreateWithMerchantInfoAndOptions)(this, details, methoddata, merchantinfo, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Create,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWithMerchantInfo,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWithMerchantInfo: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWithMerchantInfoAndOptions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWithMerchantInfoAndOptions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentRequestFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPaymentRequestFactory2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWithMerchantInfoOptionsAndId(this: *mut core::ffi::c_void, details: *mut core::ffi::c_void, methoddata: *mut core::ffi::c_void, merchantinfo: *mut core::ffi::c_void, options: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestFactory2", "CreateWithMerchantInfoOptionsAndId", 6usize, |this| ((**(this as *const *const IPaymentRequestFactory2_Vtbl)).CreateWithMerchantInfoOptionsAndId)(this, details, methoddata, merchantinfo, options, id, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWithMerchantInfoOptionsAndId,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWithMerchantInfoOptionsAndId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentRequestFactory2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentRequestSubmitResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut PaymentRequestStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestSubmitResult", "Status", 6usize, |this| ((**(this as *const *const IPaymentRequestSubmitResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Response(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentRequestSubmitResult", "Response", 7usize, |this| ((**(this as *const *const IPaymentRequestSubmitResult_Vtbl)).Response)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Response }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentRequestSubmitResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentResponse_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PaymentToken(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentResponse", "PaymentToken", 6usize, |this| ((**(this as *const *const IPaymentResponse_Vtbl)).PaymentToken)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShippingOption(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentResponse", "ShippingOption", 7usize, |this| ((**(this as *const *const IPaymentResponse_Vtbl)).ShippingOption)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShippingAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentResponse", "ShippingAddress", 8usize, |this| ((**(this as *const *const IPaymentResponse_Vtbl)).ShippingAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PayerEmail(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentResponse", "PayerEmail", 9usize, |this| ((**(this as *const *const IPaymentResponse_Vtbl)).PayerEmail)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PayerName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentResponse", "PayerName", 10usize, |this| ((**(this as *const *const IPaymentResponse_Vtbl)).PayerName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PayerPhoneNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentResponse", "PayerPhoneNumber", 11usize, |this| ((**(this as *const *const IPaymentResponse_Vtbl)).PayerPhoneNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompleteAsync(this: *mut core::ffi::c_void, status: PaymentRequestCompletionStatus, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentResponse", "CompleteAsync", 12usize, |this| ((**(this as *const *const IPaymentResponse_Vtbl)).CompleteAsync)(this, status, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PaymentToken,
+            ShippingOption,
+            ShippingAddress,
+            PayerEmail,
+            PayerName,
+            PayerPhoneNumber,
+            CompleteAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentResponse as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentShippingOption_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentShippingOption", "Label", 6usize, |this| ((**(this as *const *const IPaymentShippingOption_Vtbl)).Label)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentShippingOption", "SetLabel", 7usize, |this| ((**(this as *const *const IPaymentShippingOption_Vtbl)).SetLabel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Amount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentShippingOption", "Amount", 8usize, |this| ((**(this as *const *const IPaymentShippingOption_Vtbl)).Amount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAmount(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentShippingOption", "SetAmount", 9usize, |this| ((**(this as *const *const IPaymentShippingOption_Vtbl)).SetAmount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tag(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentShippingOption", "Tag", 10usize, |this| ((**(this as *const *const IPaymentShippingOption_Vtbl)).Tag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentShippingOption", "SetTag", 11usize, |this| ((**(this as *const *const IPaymentShippingOption_Vtbl)).SetTag)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSelected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentShippingOption", "IsSelected", 12usize, |this| ((**(this as *const *const IPaymentShippingOption_Vtbl)).IsSelected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsSelected(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentShippingOption", "SetIsSelected", 13usize, |this| ((**(this as *const *const IPaymentShippingOption_Vtbl)).SetIsSelected)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Label,
+            SetLabel,
+            Amount,
+            SetAmount,
+            Tag,
+            SetTag,
+            IsSelected,
+            SetIsSelected,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentShippingOption as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentShippingOptionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, label: core::mem::MaybeUninit<windows_core::HSTRING>, amount: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentShippingOptionFactory", "Create", 6usize, |this| ((**(this as *const *const IPaymentShippingOptionFactory_Vtbl)).Create)(this, label, amount, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithSelected(this: *mut core::ffi::c_void, label: core::mem::MaybeUninit<windows_core::HSTRING>, amount: *mut core::ffi::c_void, selected: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentShippingOptionFactory", "CreateWithSelected", 7usize, |this| ((**(this as *const *const IPaymentShippingOptionFactory_Vtbl)).CreateWithSelected)(this, label, amount, selected, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithSelectedAndTag(this: *mut core::ffi::c_void, label: core::mem::MaybeUninit<windows_core::HSTRING>, amount: *mut core::ffi::c_void, selected: bool, tag: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentShippingOptionFactory", "CreateWithSelectedAndTag", 8usize, |this| ((**(this as *const *const IPaymentShippingOptionFactory_Vtbl)).CreateWithSelectedAndTag)(this, label, amount, selected, tag, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithSelected, CreateWithSelectedAndTag }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentShippingOptionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentToken_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PaymentMethodId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentToken", "PaymentMethodId", 6usize, |this| ((**(this as *const *const IPaymentToken_Vtbl)).PaymentMethodId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JsonDetails(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentToken", "JsonDetails", 7usize, |this| ((**(this as *const *const IPaymentToken_Vtbl)).JsonDetails)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PaymentMethodId, JsonDetails }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentToken as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPaymentTokenFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, paymentmethodid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentTokenFactory", "Create", 6usize, |this| ((**(this as *const *const IPaymentTokenFactory_Vtbl)).Create)(this, paymentmethodid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithJsonDetails(this: *mut core::ffi::c_void, paymentmethodid: core::mem::MaybeUninit<windows_core::HSTRING>, jsondetails: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPaymentTokenFactory", "CreateWithJsonDetails", 7usize, |this| ((**(this as *const *const IPaymentTokenFactory_Vtbl)).CreateWithJsonDetails)(this, paymentmethodid, jsondetails, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithJsonDetails }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPaymentTokenFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -136,3 +136,5 @@ impl windows_core::RuntimeName for HolographicKeyboardPlacementOverridePreview {
 unsafe impl Send for HolographicKeyboardPlacementOverridePreview {}
 #[cfg(feature = "deprecated")]
 unsafe impl Sync for HolographicKeyboardPlacementOverridePreview {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
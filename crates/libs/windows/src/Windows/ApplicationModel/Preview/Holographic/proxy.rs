@@ -0,0 +1,80 @@
+#[cfg(feature = "ApplicationModel_Activation")]
+impl windows_core::imp::ProxyVtable for IHolographicApplicationPreviewStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsCurrentViewPresentedOnHolographicDisplay(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicApplicationPreviewStatics", "IsCurrentViewPresentedOnHolographicDisplay", 6usize, |this| ((**(this as *const *const IHolographicApplicationPreviewStatics_Vtbl)).IsCurrentViewPresentedOnHolographicDisplay)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Activation")]
+        unsafe extern "system" fn IsHolographicActivation(this: *mut core::ffi::c_void, activatedeventargs: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicApplicationPreviewStatics", "IsHolographicActivation", 7usize, |this| ((**(this as *const *const IHolographicApplicationPreviewStatics_Vtbl)).IsHolographicActivation)(this, activatedeventargs, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsCurrentViewPresentedOnHolographicDisplay,
+            #[cfg(feature = "ApplicationModel_Activation")]
+            IsHolographicActivation,
+            #[cfg(not(feature = "ApplicationModel_Activation"))]
+            IsHolographicActivation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicApplicationPreviewStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IHolographicKeyboardPlacementOverridePreview_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial", feature = "deprecated"))]
+        unsafe extern "system" fn SetPlacementOverride(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, topcenterposition: super::super::super::Foundation::Numerics::Vector3, normal: super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicKeyboardPlacementOverridePreview", "SetPlacementOverride", 6usize, |this| ((**(this as *const *const IHolographicKeyboardPlacementOverridePreview_Vtbl)).SetPlacementOverride)(this, coordinatesystem, topcenterposition, normal), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial", feature = "deprecated"))]
+        unsafe extern "system" fn SetPlacementOverrideWithMaxSize(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, topcenterposition: super::super::super::Foundation::Numerics::Vector3, normal: super::super::super::Foundation::Numerics::Vector3, maxsize: super::super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicKeyboardPlacementOverridePreview", "SetPlacementOverrideWithMaxSize", 7usize, |this| ((**(this as *const *const IHolographicKeyboardPlacementOverridePreview_Vtbl)).SetPlacementOverrideWithMaxSize)(this, coordinatesystem, topcenterposition, normal, maxsize), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ResetPlacementOverride(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicKeyboardPlacementOverridePreview", "ResetPlacementOverride", 8usize, |this| ((**(this as *const *const IHolographicKeyboardPlacementOverridePreview_Vtbl)).ResetPlacementOverride)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial", feature = "deprecated"))]
+            SetPlacementOverride,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial", feature = "deprecated")))]
+            SetPlacementOverride: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial", feature = "deprecated"))]
+            SetPlacementOverrideWithMaxSize,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial", feature = "deprecated")))]
+            SetPlacementOverrideWithMaxSize: 0,
+            #[cfg(feature = "deprecated")]
+            ResetPlacementOverride,
+            #[cfg(not(feature = "deprecated"))]
+            ResetPlacementOverride: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicKeyboardPlacementOverridePreview as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IHolographicKeyboardPlacementOverridePreviewStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicKeyboardPlacementOverridePreviewStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IHolographicKeyboardPlacementOverridePreviewStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            GetForCurrentView,
+            #[cfg(not(feature = "deprecated"))]
+            GetForCurrentView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicKeyboardPlacementOverridePreviewStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
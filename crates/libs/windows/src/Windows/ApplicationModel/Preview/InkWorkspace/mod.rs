@@ -59,3 +59,5 @@ impl windows_core::RuntimeName for InkWorkspaceHostedAppManager {
 }
 unsafe impl Send for InkWorkspaceHostedAppManager {}
 unsafe impl Sync for InkWorkspaceHostedAppManager {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,32 @@
+#[cfg(feature = "Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for IInkWorkspaceHostedAppManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SetThumbnailAsync(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkWorkspaceHostedAppManager", "SetThumbnailAsync", 6usize, |this| ((**(this as *const *const IInkWorkspaceHostedAppManager_Vtbl)).SetThumbnailAsync)(this, bitmap, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Imaging")]
+            SetThumbnailAsync,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SetThumbnailAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkWorkspaceHostedAppManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkWorkspaceHostedAppManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentApp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkWorkspaceHostedAppManagerStatics", "GetForCurrentApp", 6usize, |this| ((**(this as *const *const IInkWorkspaceHostedAppManagerStatics_Vtbl)).GetForCurrentApp)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentApp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkWorkspaceHostedAppManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -338,3 +338,5 @@ impl windows_core::RuntimeName for NotesWindowManagerPreviewShowNoteOptions {
 }
 unsafe impl Send for NotesWindowManagerPreviewShowNoteOptions {}
 unsafe impl Sync for NotesWindowManagerPreviewShowNoteOptions {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,174 @@
+impl windows_core::imp::ProxyVtable for INotePlacementChangedPreviewEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ViewId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotePlacementChangedPreviewEventArgs", "ViewId", 6usize, |this| ((**(this as *const *const INotePlacementChangedPreviewEventArgs_Vtbl)).ViewId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ViewId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INotePlacementChangedPreviewEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INoteVisibilityChangedPreviewEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ViewId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INoteVisibilityChangedPreviewEventArgs", "ViewId", 6usize, |this| ((**(this as *const *const INoteVisibilityChangedPreviewEventArgs_Vtbl)).ViewId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INoteVisibilityChangedPreviewEventArgs", "IsVisible", 7usize, |this| ((**(this as *const *const INoteVisibilityChangedPreviewEventArgs_Vtbl)).IsVisible)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ViewId, IsVisible }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INoteVisibilityChangedPreviewEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for INotesWindowManagerPreview_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsScreenLocked(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview", "IsScreenLocked", 6usize, |this| ((**(this as *const *const INotesWindowManagerPreview_Vtbl)).IsScreenLocked)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowNote(this: *mut core::ffi::c_void, noteviewid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview", "ShowNote", 7usize, |this| ((**(this as *const *const INotesWindowManagerPreview_Vtbl)).ShowNote)(this, noteviewid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowNoteRelativeTo(this: *mut core::ffi::c_void, noteviewid: i32, anchornoteviewid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview", "ShowNoteRelativeTo", 8usize, |this| ((**(this as *const *const INotesWindowManagerPreview_Vtbl)).ShowNoteRelativeTo)(this, noteviewid, anchornoteviewid), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ShowNoteWithPlacement(this: *mut core::ffi::c_void, noteviewid: i32, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview", "ShowNoteWithPlacement", 9usize, |this| ((**(this as *const *const INotesWindowManagerPreview_Vtbl)).ShowNoteWithPlacement)(this, noteviewid, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn HideNote(this: *mut core::ffi::c_void, noteviewid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview", "HideNote", 10usize, |this| ((**(this as *const *const INotesWindowManagerPreview_Vtbl)).HideNote)(this, noteviewid), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetNotePlacement(this: *mut core::ffi::c_void, noteviewid: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview", "GetNotePlacement", 11usize, |this| ((**(this as *const *const INotesWindowManagerPreview_Vtbl)).GetNotePlacement)(this, noteviewid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySetNoteSize(this: *mut core::ffi::c_void, noteviewid: i32, size: super::super::super::Foundation::Size, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview", "TrySetNoteSize", 12usize, |this| ((**(this as *const *const INotesWindowManagerPreview_Vtbl)).TrySetNoteSize)(this, noteviewid, size, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFocusToNextView(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview", "SetFocusToNextView", 13usize, |this| ((**(this as *const *const INotesWindowManagerPreview_Vtbl)).SetFocusToNextView)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetNotesThumbnailAsync(this: *mut core::ffi::c_void, thumbnail: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview", "SetNotesThumbnailAsync", 14usize, |this| ((**(this as *const *const INotesWindowManagerPreview_Vtbl)).SetNotesThumbnailAsync)(this, thumbnail, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemLockStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview", "SystemLockStateChanged", 15usize, |this| ((**(this as *const *const INotesWindowManagerPreview_Vtbl)).SystemLockStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSystemLockStateChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview", "RemoveSystemLockStateChanged", 16usize, |this| ((**(this as *const *const INotesWindowManagerPreview_Vtbl)).RemoveSystemLockStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotePlacementChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview", "NotePlacementChanged", 17usize, |this| ((**(this as *const *const INotesWindowManagerPreview_Vtbl)).NotePlacementChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNotePlacementChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview", "RemoveNotePlacementChanged", 18usize, |this| ((**(this as *const *const INotesWindowManagerPreview_Vtbl)).RemoveNotePlacementChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn NoteVisibilityChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview", "NoteVisibilityChanged", 19usize, |this| ((**(this as *const *const INotesWindowManagerPreview_Vtbl)).NoteVisibilityChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNoteVisibilityChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview", "RemoveNoteVisibilityChanged", 20usize, |this| ((**(this as *const *const INotesWindowManagerPreview_Vtbl)).RemoveNoteVisibilityChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsScreenLocked,
+            ShowNote,
+            ShowNoteRelativeTo,
+            #[cfg(feature = "Storage_Streams")]
+            ShowNoteWithPlacement,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ShowNoteWithPlacement: 0,
+            HideNote,
+            #[cfg(feature = "Storage_Streams")]
+            GetNotePlacement,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetNotePlacement: 0,
+            TrySetNoteSize,
+            SetFocusToNextView,
+            #[cfg(feature = "Storage_Streams")]
+            SetNotesThumbnailAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetNotesThumbnailAsync: 0,
+            SystemLockStateChanged,
+            RemoveSystemLockStateChanged,
+            NotePlacementChanged,
+            RemoveNotePlacementChanged,
+            NoteVisibilityChanged,
+            RemoveNoteVisibilityChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INotesWindowManagerPreview as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Graphics_Imaging", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for INotesWindowManagerPreview2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowNoteRelativeToWithOptions(this: *mut core::ffi::c_void, noteviewid: i32, anchornoteviewid: i32, options: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview2", "ShowNoteRelativeToWithOptions", 6usize, |this| ((**(this as *const *const INotesWindowManagerPreview2_Vtbl)).ShowNoteRelativeToWithOptions)(this, noteviewid, anchornoteviewid, options), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ShowNoteWithPlacementWithOptions(this: *mut core::ffi::c_void, noteviewid: i32, data: *mut core::ffi::c_void, options: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview2", "ShowNoteWithPlacementWithOptions", 7usize, |this| ((**(this as *const *const INotesWindowManagerPreview2_Vtbl)).ShowNoteWithPlacementWithOptions)(this, noteviewid, data, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFocusToPreviousView(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview2", "SetFocusToPreviousView", 8usize, |this| ((**(this as *const *const INotesWindowManagerPreview2_Vtbl)).SetFocusToPreviousView)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SetThumbnailImageForTaskSwitcherAsync(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreview2", "SetThumbnailImageForTaskSwitcherAsync", 9usize, |this| ((**(this as *const *const INotesWindowManagerPreview2_Vtbl)).SetThumbnailImageForTaskSwitcherAsync)(this, bitmap, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ShowNoteRelativeToWithOptions,
+            #[cfg(feature = "Storage_Streams")]
+            ShowNoteWithPlacementWithOptions,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ShowNoteWithPlacementWithOptions: 0,
+            SetFocusToPreviousView,
+            #[cfg(feature = "Graphics_Imaging")]
+            SetThumbnailImageForTaskSwitcherAsync,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SetThumbnailImageForTaskSwitcherAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INotesWindowManagerPreview2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INotesWindowManagerPreviewShowNoteOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowWithFocus(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreviewShowNoteOptions", "ShowWithFocus", 6usize, |this| ((**(this as *const *const INotesWindowManagerPreviewShowNoteOptions_Vtbl)).ShowWithFocus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowWithFocus(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreviewShowNoteOptions", "SetShowWithFocus", 7usize, |this| ((**(this as *const *const INotesWindowManagerPreviewShowNoteOptions_Vtbl)).SetShowWithFocus)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowWithFocus, SetShowWithFocus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INotesWindowManagerPreviewShowNoteOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INotesWindowManagerPreviewStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentApp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotesWindowManagerPreviewStatics", "GetForCurrentApp", 6usize, |this| ((**(this as *const *const INotesWindowManagerPreviewStatics_Vtbl)).GetForCurrentApp)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentApp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INotesWindowManagerPreviewStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
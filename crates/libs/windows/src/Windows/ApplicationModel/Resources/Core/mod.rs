@@ -1427,6 +1427,7 @@ impl ResourceCandidateKind {
 impl windows_core::TypeKind for ResourceCandidateKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ResourceCandidateKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ResourceCandidateKind").field(&self.0).finish()
@@ -1445,6 +1446,7 @@ impl ResourceQualifierPersistence {
 impl windows_core::TypeKind for ResourceQualifierPersistence {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ResourceQualifierPersistence {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ResourceQualifierPersistence").field(&self.0).finish()
@@ -1454,7 +1456,8 @@ impl windows_core::RuntimeType for ResourceQualifierPersistence {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.Resources.Core.ResourceQualifierPersistence;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ResourceLayoutInfo {
     pub MajorVersion: u32,
     pub MinorVersion: u32,
@@ -1473,3 +1476,5 @@ impl Default for ResourceLayoutInfo {
         unsafe { core::mem::zeroed() }
     }
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
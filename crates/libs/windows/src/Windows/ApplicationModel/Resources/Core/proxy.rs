@@ -0,0 +1,409 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for INamedResource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedResource", "Uri", 6usize, |this| ((**(this as *const *const INamedResource_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Candidates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedResource", "Candidates", 7usize, |this| ((**(this as *const *const INamedResource_Vtbl)).Candidates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resolve(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedResource", "Resolve", 8usize, |this| ((**(this as *const *const INamedResource_Vtbl)).Resolve)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolveForContext(this: *mut core::ffi::c_void, resourcecontext: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedResource", "ResolveForContext", 9usize, |this| ((**(this as *const *const INamedResource_Vtbl)).ResolveForContext)(this, resourcecontext, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ResolveAll(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedResource", "ResolveAll", 10usize, |this| ((**(this as *const *const INamedResource_Vtbl)).ResolveAll)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ResolveAllForContext(this: *mut core::ffi::c_void, resourcecontext: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedResource", "ResolveAllForContext", 11usize, |this| ((**(this as *const *const INamedResource_Vtbl)).ResolveAllForContext)(this, resourcecontext, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Uri,
+            #[cfg(feature = "Foundation_Collections")]
+            Candidates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Candidates: 0,
+            Resolve,
+            ResolveForContext,
+            #[cfg(feature = "Foundation_Collections")]
+            ResolveAll,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ResolveAll: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ResolveAllForContext,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ResolveAllForContext: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INamedResource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IResourceCandidate_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Qualifiers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceCandidate", "Qualifiers", 6usize, |this| ((**(this as *const *const IResourceCandidate_Vtbl)).Qualifiers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMatch(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceCandidate", "IsMatch", 7usize, |this| ((**(this as *const *const IResourceCandidate_Vtbl)).IsMatch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMatchAsDefault(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceCandidate", "IsMatchAsDefault", 8usize, |this| ((**(this as *const *const IResourceCandidate_Vtbl)).IsMatchAsDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDefault(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceCandidate", "IsDefault", 9usize, |this| ((**(this as *const *const IResourceCandidate_Vtbl)).IsDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValueAsString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceCandidate", "ValueAsString", 10usize, |this| ((**(this as *const *const IResourceCandidate_Vtbl)).ValueAsString)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn GetValueAsFileAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceCandidate", "GetValueAsFileAsync", 11usize, |this| ((**(this as *const *const IResourceCandidate_Vtbl)).GetValueAsFileAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQualifierValue(this: *mut core::ffi::c_void, qualifiername: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceCandidate", "GetQualifierValue", 12usize, |this| ((**(this as *const *const IResourceCandidate_Vtbl)).GetQualifierValue)(this, qualifiername, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Qualifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Qualifiers: 0,
+            IsMatch,
+            IsMatchAsDefault,
+            IsDefault,
+            ValueAsString,
+            #[cfg(feature = "Storage")]
+            GetValueAsFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            GetValueAsFileAsync: 0,
+            GetQualifierValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceCandidate as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IResourceCandidate2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetValueAsStreamAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceCandidate2", "GetValueAsStreamAsync", 6usize, |this| ((**(this as *const *const IResourceCandidate2_Vtbl)).GetValueAsStreamAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            GetValueAsStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetValueAsStreamAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceCandidate2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceCandidate3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut ResourceCandidateKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceCandidate3", "Kind", 6usize, |this| ((**(this as *const *const IResourceCandidate3_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceCandidate3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IResourceContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn QualifierValues(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceContext", "QualifierValues", 6usize, |this| ((**(this as *const *const IResourceContext_Vtbl)).QualifierValues)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceContext", "Reset", 7usize, |this| ((**(this as *const *const IResourceContext_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ResetQualifierValues(this: *mut core::ffi::c_void, qualifiernames: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceContext", "ResetQualifierValues", 8usize, |this| ((**(this as *const *const IResourceContext_Vtbl)).ResetQualifierValues)(this, qualifiernames), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn OverrideToMatch(this: *mut core::ffi::c_void, result: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceContext", "OverrideToMatch", 9usize, |this| ((**(this as *const *const IResourceContext_Vtbl)).OverrideToMatch)(this, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceContext", "Clone", 10usize, |this| ((**(this as *const *const IResourceContext_Vtbl)).Clone)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Languages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceContext", "Languages", 11usize, |this| ((**(this as *const *const IResourceContext_Vtbl)).Languages)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetLanguages(this: *mut core::ffi::c_void, languages: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceContext", "SetLanguages", 12usize, |this| ((**(this as *const *const IResourceContext_Vtbl)).SetLanguages)(this, languages), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            QualifierValues,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            QualifierValues: 0,
+            Reset,
+            #[cfg(feature = "Foundation_Collections")]
+            ResetQualifierValues,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ResetQualifierValues: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            OverrideToMatch,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            OverrideToMatch: 0,
+            Clone,
+            #[cfg(feature = "Foundation_Collections")]
+            Languages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Languages: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetLanguages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetLanguages: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IResourceContextStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateMatchingContext(this: *mut core::ffi::c_void, result: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceContextStatics", "CreateMatchingContext", 6usize, |this| ((**(this as *const *const IResourceContextStatics_Vtbl)).CreateMatchingContext)(this, result, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateMatchingContext,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateMatchingContext: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceContextStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IResourceContextStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceContextStatics2", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IResourceContextStatics2_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGlobalQualifierValue(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceContextStatics2", "SetGlobalQualifierValue", 7usize, |this| ((**(this as *const *const IResourceContextStatics2_Vtbl)).SetGlobalQualifierValue)(this, key, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetGlobalQualifierValues(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceContextStatics2", "ResetGlobalQualifierValues", 8usize, |this| ((**(this as *const *const IResourceContextStatics2_Vtbl)).ResetGlobalQualifierValues)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ResetGlobalQualifierValuesForSpecifiedQualifiers(this: *mut core::ffi::c_void, qualifiernames: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceContextStatics2", "ResetGlobalQualifierValuesForSpecifiedQualifiers", 9usize, |this| ((**(this as *const *const IResourceContextStatics2_Vtbl)).ResetGlobalQualifierValuesForSpecifiedQualifiers)(this, qualifiernames), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForViewIndependentUse(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceContextStatics2", "GetForViewIndependentUse", 10usize, |this| ((**(this as *const *const IResourceContextStatics2_Vtbl)).GetForViewIndependentUse)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetForCurrentView,
+            SetGlobalQualifierValue,
+            ResetGlobalQualifierValues,
+            #[cfg(feature = "Foundation_Collections")]
+            ResetGlobalQualifierValuesForSpecifiedQualifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ResetGlobalQualifierValuesForSpecifiedQualifiers: 0,
+            GetForViewIndependentUse,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceContextStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceContextStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetGlobalQualifierValueWithPersistence(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>, persistence: ResourceQualifierPersistence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceContextStatics3", "SetGlobalQualifierValueWithPersistence", 6usize, |this| ((**(this as *const *const IResourceContextStatics3_Vtbl)).SetGlobalQualifierValueWithPersistence)(this, key, value, persistence), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetGlobalQualifierValueWithPersistence }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceContextStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for IResourceContextStatics4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn GetForUIContext(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceContextStatics4", "GetForUIContext", 6usize, |this| ((**(this as *const *const IResourceContextStatics4_Vtbl)).GetForUIContext)(this, context, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI")]
+            GetForUIContext,
+            #[cfg(not(feature = "UI"))]
+            GetForUIContext: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceContextStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IResourceManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MainResourceMap(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "MainResourceMap", 6usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).MainResourceMap)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AllResourceMaps(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "AllResourceMaps", 7usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).AllResourceMaps)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultContext(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "DefaultContext", 8usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).DefaultContext)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn LoadPriFiles(this: *mut core::ffi::c_void, files: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "LoadPriFiles", 9usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).LoadPriFiles)(this, files), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn UnloadPriFiles(this: *mut core::ffi::c_void, files: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "UnloadPriFiles", 10usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).UnloadPriFiles)(this, files), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MainResourceMap,
+            #[cfg(feature = "Foundation_Collections")]
+            AllResourceMaps,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AllResourceMaps: 0,
+            DefaultContext,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            LoadPriFiles,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            LoadPriFiles: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            UnloadPriFiles,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            UnloadPriFiles: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IResourceManager2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAllNamedResourcesForPackage(this: *mut core::ffi::c_void, packagename: core::mem::MaybeUninit<windows_core::HSTRING>, resourcelayoutinfo: ResourceLayoutInfo, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager2", "GetAllNamedResourcesForPackage", 6usize, |this| ((**(this as *const *const IResourceManager2_Vtbl)).GetAllNamedResourcesForPackage)(this, packagename, resourcelayoutinfo, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAllSubtreesForPackage(this: *mut core::ffi::c_void, packagename: core::mem::MaybeUninit<windows_core::HSTRING>, resourcelayoutinfo: ResourceLayoutInfo, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager2", "GetAllSubtreesForPackage", 7usize, |this| ((**(this as *const *const IResourceManager2_Vtbl)).GetAllSubtreesForPackage)(this, packagename, resourcelayoutinfo, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAllNamedResourcesForPackage,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAllNamedResourcesForPackage: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAllSubtreesForPackage,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAllSubtreesForPackage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManagerStatics", "Current", 6usize, |this| ((**(this as *const *const IResourceManagerStatics_Vtbl)).Current)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsResourceReference(this: *mut core::ffi::c_void, resourcereference: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManagerStatics", "IsResourceReference", 7usize, |this| ((**(this as *const *const IResourceManagerStatics_Vtbl)).IsResourceReference)(this, resourcereference, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Current, IsResourceReference }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IResourceMap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceMap", "Uri", 6usize, |this| ((**(this as *const *const IResourceMap_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, resource: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceMap", "GetValue", 7usize, |this| ((**(this as *const *const IResourceMap_Vtbl)).GetValue)(this, resource, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValueForContext(this: *mut core::ffi::c_void, resource: core::mem::MaybeUninit<windows_core::HSTRING>, context: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceMap", "GetValueForContext", 8usize, |this| ((**(this as *const *const IResourceMap_Vtbl)).GetValueForContext)(this, resource, context, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubtree(this: *mut core::ffi::c_void, reference: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceMap", "GetSubtree", 9usize, |this| ((**(this as *const *const IResourceMap_Vtbl)).GetSubtree)(this, reference, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uri, GetValue, GetValueForContext, GetSubtree }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceMap as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceQualifier_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QualifierName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceQualifier", "QualifierName", 6usize, |this| ((**(this as *const *const IResourceQualifier_Vtbl)).QualifierName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn QualifierValue(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceQualifier", "QualifierValue", 7usize, |this| ((**(this as *const *const IResourceQualifier_Vtbl)).QualifierValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDefault(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceQualifier", "IsDefault", 8usize, |this| ((**(this as *const *const IResourceQualifier_Vtbl)).IsDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMatch(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceQualifier", "IsMatch", 9usize, |this| ((**(this as *const *const IResourceQualifier_Vtbl)).IsMatch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Score(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceQualifier", "Score", 10usize, |this| ((**(this as *const *const IResourceQualifier_Vtbl)).Score)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QualifierName, QualifierValue, IsDefault, IsMatch, Score }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceQualifier as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -263,6 +263,7 @@ impl IndexedResourceType {
 impl windows_core::TypeKind for IndexedResourceType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IndexedResourceType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IndexedResourceType").field(&self.0).finish()
@@ -271,3 +272,5 @@ impl core::fmt::Debug for IndexedResourceType {
 impl windows_core::RuntimeType for IndexedResourceType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.Resources.Management.IndexedResourceType;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
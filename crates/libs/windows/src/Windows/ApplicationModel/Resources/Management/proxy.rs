@@ -0,0 +1,127 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IIndexedResourceCandidate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut IndexedResourceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexedResourceCandidate", "Type", 6usize, |this| ((**(this as *const *const IIndexedResourceCandidate_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexedResourceCandidate", "Uri", 7usize, |this| ((**(this as *const *const IIndexedResourceCandidate_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Metadata(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexedResourceCandidate", "Metadata", 8usize, |this| ((**(this as *const *const IIndexedResourceCandidate_Vtbl)).Metadata)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Qualifiers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexedResourceCandidate", "Qualifiers", 9usize, |this| ((**(this as *const *const IIndexedResourceCandidate_Vtbl)).Qualifiers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValueAsString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexedResourceCandidate", "ValueAsString", 10usize, |this| ((**(this as *const *const IIndexedResourceCandidate_Vtbl)).ValueAsString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQualifierValue(this: *mut core::ffi::c_void, qualifiername: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexedResourceCandidate", "GetQualifierValue", 11usize, |this| ((**(this as *const *const IIndexedResourceCandidate_Vtbl)).GetQualifierValue)(this, qualifiername, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            Uri,
+            #[cfg(feature = "Foundation_Collections")]
+            Metadata,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Metadata: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Qualifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Qualifiers: 0,
+            ValueAsString,
+            GetQualifierValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIndexedResourceCandidate as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIndexedResourceQualifier_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QualifierName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexedResourceQualifier", "QualifierName", 6usize, |this| ((**(this as *const *const IIndexedResourceQualifier_Vtbl)).QualifierName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn QualifierValue(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexedResourceQualifier", "QualifierValue", 7usize, |this| ((**(this as *const *const IIndexedResourceQualifier_Vtbl)).QualifierValue)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QualifierName, QualifierValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIndexedResourceQualifier as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IResourceIndexer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IndexFilePath(this: *mut core::ffi::c_void, filepath: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceIndexer", "IndexFilePath", 6usize, |this| ((**(this as *const *const IResourceIndexer_Vtbl)).IndexFilePath)(this, filepath, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn IndexFileContentsAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceIndexer", "IndexFileContentsAsync", 7usize, |this| ((**(this as *const *const IResourceIndexer_Vtbl)).IndexFileContentsAsync)(this, file, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            IndexFilePath,
+            #[cfg(not(feature = "deprecated"))]
+            IndexFilePath: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            IndexFileContentsAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            IndexFileContentsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceIndexer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IResourceIndexerFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreateResourceIndexer(this: *mut core::ffi::c_void, projectroot: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceIndexerFactory", "CreateResourceIndexer", 6usize, |this| ((**(this as *const *const IResourceIndexerFactory_Vtbl)).CreateResourceIndexer)(this, projectroot, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CreateResourceIndexer,
+            #[cfg(not(feature = "deprecated"))]
+            CreateResourceIndexer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceIndexerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IResourceIndexerFactory2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreateResourceIndexerWithExtension(this: *mut core::ffi::c_void, projectroot: *mut core::ffi::c_void, extensiondllpath: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceIndexerFactory2", "CreateResourceIndexerWithExtension", 6usize, |this| ((**(this as *const *const IResourceIndexerFactory2_Vtbl)).CreateResourceIndexerWithExtension)(this, projectroot, extensiondllpath, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CreateResourceIndexerWithExtension,
+            #[cfg(not(feature = "deprecated"))]
+            CreateResourceIndexerWithExtension: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceIndexerFactory2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -193,3 +193,5 @@ impl windows_core::RuntimeName for ResourceLoader {
 }
 unsafe impl Send for ResourceLoader {}
 unsafe impl Sync for ResourceLoader {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,107 @@
+impl windows_core::imp::ProxyVtable for IResourceLoader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, resource: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceLoader", "GetString", 6usize, |this| ((**(this as *const *const IResourceLoader_Vtbl)).GetString)(this, resource, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetString }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceLoader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceLoader2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStringForUri(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceLoader2", "GetStringForUri", 6usize, |this| ((**(this as *const *const IResourceLoader2_Vtbl)).GetStringForUri)(this, uri, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStringForUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceLoader2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceLoaderFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateResourceLoaderByName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceLoaderFactory", "CreateResourceLoaderByName", 6usize, |this| ((**(this as *const *const IResourceLoaderFactory_Vtbl)).CreateResourceLoaderByName)(this, name, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateResourceLoaderByName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceLoaderFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceLoaderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStringForReference(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceLoaderStatics", "GetStringForReference", 6usize, |this| ((**(this as *const *const IResourceLoaderStatics_Vtbl)).GetStringForReference)(this, uri, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStringForReference }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceLoaderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceLoaderStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceLoaderStatics2", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IResourceLoaderStatics2_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForCurrentViewWithName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceLoaderStatics2", "GetForCurrentViewWithName", 7usize, |this| ((**(this as *const *const IResourceLoaderStatics2_Vtbl)).GetForCurrentViewWithName)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForViewIndependentUse(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceLoaderStatics2", "GetForViewIndependentUse", 8usize, |this| ((**(this as *const *const IResourceLoaderStatics2_Vtbl)).GetForViewIndependentUse)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForViewIndependentUseWithName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceLoaderStatics2", "GetForViewIndependentUseWithName", 9usize, |this| ((**(this as *const *const IResourceLoaderStatics2_Vtbl)).GetForViewIndependentUseWithName)(this, name, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetForCurrentView,
+            GetForCurrentViewWithName,
+            GetForViewIndependentUse,
+            GetForViewIndependentUseWithName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceLoaderStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for IResourceLoaderStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn GetForUIContext(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceLoaderStatics3", "GetForUIContext", 6usize, |this| ((**(this as *const *const IResourceLoaderStatics3_Vtbl)).GetForUIContext)(this, context, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI")]
+            GetForUIContext,
+            #[cfg(not(feature = "UI"))]
+            GetForUIContext: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceLoaderStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceLoaderStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultPriPath(this: *mut core::ffi::c_void, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceLoaderStatics4", "GetDefaultPriPath", 6usize, |this| ((**(this as *const *const IResourceLoaderStatics4_Vtbl)).GetDefaultPriPath)(this, packagefullname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefaultPriPath }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceLoaderStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -310,6 +310,7 @@ impl SearchSuggestionKind {
 impl windows_core::TypeKind for SearchSuggestionKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SearchSuggestionKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SearchSuggestionKind").field(&self.0).finish()
@@ -318,3 +319,5 @@ impl core::fmt::Debug for SearchSuggestionKind {
 impl windows_core::RuntimeType for SearchSuggestionKind {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.Search.Core.SearchSuggestionKind;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,148 @@
+impl windows_core::imp::ProxyVtable for IRequestingFocusOnKeyboardInputEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRequestingFocusOnKeyboardInputEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISearchSuggestion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut SearchSuggestionKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestion", "Kind", 6usize, |this| ((**(this as *const *const ISearchSuggestion_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestion", "Text", 7usize, |this| ((**(this as *const *const ISearchSuggestion_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tag(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestion", "Tag", 8usize, |this| ((**(this as *const *const ISearchSuggestion_Vtbl)).Tag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetailText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestion", "DetailText", 9usize, |this| ((**(this as *const *const ISearchSuggestion_Vtbl)).DetailText)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Image(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestion", "Image", 10usize, |this| ((**(this as *const *const ISearchSuggestion_Vtbl)).Image)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImageAlternateText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestion", "ImageAlternateText", 11usize, |this| ((**(this as *const *const ISearchSuggestion_Vtbl)).ImageAlternateText)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Kind,
+            Text,
+            Tag,
+            DetailText,
+            #[cfg(feature = "Storage_Streams")]
+            Image,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Image: 0,
+            ImageAlternateText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchSuggestion as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISearchSuggestionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SearchHistoryEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "SearchHistoryEnabled", 6usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).SearchHistoryEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSearchHistoryEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "SetSearchHistoryEnabled", 7usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).SetSearchHistoryEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchHistoryContext(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "SearchHistoryContext", 8usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).SearchHistoryContext)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSearchHistoryContext(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "SetSearchHistoryContext", 9usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).SetSearchHistoryContext)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalContentSuggestionSettings(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "SetLocalContentSuggestionSettings", 10usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).SetLocalContentSuggestionSettings)(this, settings), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuery(this: *mut core::ffi::c_void, querytext: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "SetQuery", 11usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).SetQuery)(this, querytext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQueryWithLanguage(this: *mut core::ffi::c_void, querytext: core::mem::MaybeUninit<windows_core::HSTRING>, language: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "SetQueryWithLanguage", 12usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).SetQueryWithLanguage)(this, querytext, language), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQueryWithSearchQueryLinguisticDetails(this: *mut core::ffi::c_void, querytext: core::mem::MaybeUninit<windows_core::HSTRING>, language: core::mem::MaybeUninit<windows_core::HSTRING>, linguisticdetails: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "SetQueryWithSearchQueryLinguisticDetails", 13usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).SetQueryWithSearchQueryLinguisticDetails)(this, querytext, language, linguisticdetails), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Suggestions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "Suggestions", 14usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).Suggestions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddToHistory(this: *mut core::ffi::c_void, querytext: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "AddToHistory", 15usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).AddToHistory)(this, querytext), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddToHistoryWithLanguage(this: *mut core::ffi::c_void, querytext: core::mem::MaybeUninit<windows_core::HSTRING>, language: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "AddToHistoryWithLanguage", 16usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).AddToHistoryWithLanguage)(this, querytext, language), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearHistory(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "ClearHistory", 17usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).ClearHistory)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuggestionsRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "SuggestionsRequested", 18usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).SuggestionsRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSuggestionsRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "RemoveSuggestionsRequested", 19usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).RemoveSuggestionsRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestingFocusOnKeyboardInput(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "RequestingFocusOnKeyboardInput", 20usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).RequestingFocusOnKeyboardInput)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRequestingFocusOnKeyboardInput(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionManager", "RemoveRequestingFocusOnKeyboardInput", 21usize, |this| ((**(this as *const *const ISearchSuggestionManager_Vtbl)).RemoveRequestingFocusOnKeyboardInput)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SearchHistoryEnabled,
+            SetSearchHistoryEnabled,
+            SearchHistoryContext,
+            SetSearchHistoryContext,
+            SetLocalContentSuggestionSettings,
+            SetQuery,
+            SetQueryWithLanguage,
+            SetQueryWithSearchQueryLinguisticDetails,
+            #[cfg(feature = "Foundation_Collections")]
+            Suggestions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Suggestions: 0,
+            AddToHistory,
+            AddToHistoryWithLanguage,
+            ClearHistory,
+            SuggestionsRequested,
+            RemoveSuggestionsRequested,
+            RequestingFocusOnKeyboardInput,
+            RemoveRequestingFocusOnKeyboardInput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchSuggestionManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISearchSuggestionsRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionsRequestedEventArgs", "QueryText", 6usize, |this| ((**(this as *const *const ISearchSuggestionsRequestedEventArgs_Vtbl)).QueryText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionsRequestedEventArgs", "Language", 7usize, |this| ((**(this as *const *const ISearchSuggestionsRequestedEventArgs_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LinguisticDetails(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionsRequestedEventArgs", "LinguisticDetails", 8usize, |this| ((**(this as *const *const ISearchSuggestionsRequestedEventArgs_Vtbl)).LinguisticDetails)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionsRequestedEventArgs", "Request", 9usize, |this| ((**(this as *const *const ISearchSuggestionsRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryText, Language, LinguisticDetails, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchSuggestionsRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
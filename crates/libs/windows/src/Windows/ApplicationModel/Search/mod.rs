@@ -1222,3 +1222,5 @@ unsafe impl Send for SearchSuggestionsRequestDeferral {}
 unsafe impl Sync for SearchSuggestionsRequestDeferral {}
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
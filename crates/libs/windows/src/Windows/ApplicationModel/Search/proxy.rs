@@ -0,0 +1,642 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for ILocalContentSuggestionSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalContentSuggestionSettings", "SetEnabled", 6usize, |this| ((**(this as *const *const ILocalContentSuggestionSettings_Vtbl)).SetEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalContentSuggestionSettings", "Enabled", 7usize, |this| ((**(this as *const *const ILocalContentSuggestionSettings_Vtbl)).Enabled)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn Locations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalContentSuggestionSettings", "Locations", 8usize, |this| ((**(this as *const *const ILocalContentSuggestionSettings_Vtbl)).Locations)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAqsFilter(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalContentSuggestionSettings", "SetAqsFilter", 9usize, |this| ((**(this as *const *const ILocalContentSuggestionSettings_Vtbl)).SetAqsFilter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AqsFilter(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalContentSuggestionSettings", "AqsFilter", 10usize, |this| ((**(this as *const *const ILocalContentSuggestionSettings_Vtbl)).AqsFilter)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PropertiesToMatch(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalContentSuggestionSettings", "PropertiesToMatch", 11usize, |this| ((**(this as *const *const ILocalContentSuggestionSettings_Vtbl)).PropertiesToMatch)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetEnabled,
+            Enabled,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            Locations,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            Locations: 0,
+            SetAqsFilter,
+            AqsFilter,
+            #[cfg(feature = "Foundation_Collections")]
+            PropertiesToMatch,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PropertiesToMatch: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocalContentSuggestionSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISearchPane_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetSearchHistoryEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "SetSearchHistoryEnabled", 6usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).SetSearchHistoryEnabled)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SearchHistoryEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "SearchHistoryEnabled", 7usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).SearchHistoryEnabled)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetSearchHistoryContext(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "SetSearchHistoryContext", 8usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).SetSearchHistoryContext)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SearchHistoryContext(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "SearchHistoryContext", 9usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).SearchHistoryContext)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetPlaceholderText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "SetPlaceholderText", 10usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).SetPlaceholderText)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PlaceholderText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "PlaceholderText", 11usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).PlaceholderText)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn QueryText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "QueryText", 12usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).QueryText)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "Language", 13usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Visible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "Visible", 14usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).Visible)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn VisibilityChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "VisibilityChanged", 15usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).VisibilityChanged)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveVisibilityChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "RemoveVisibilityChanged", 16usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).RemoveVisibilityChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn QueryChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "QueryChanged", 17usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).QueryChanged)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveQueryChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "RemoveQueryChanged", 18usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).RemoveQueryChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SuggestionsRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "SuggestionsRequested", 19usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).SuggestionsRequested)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveSuggestionsRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "RemoveSuggestionsRequested", 20usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).RemoveSuggestionsRequested)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn QuerySubmitted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "QuerySubmitted", 21usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).QuerySubmitted)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveQuerySubmitted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "RemoveQuerySubmitted", 22usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).RemoveQuerySubmitted)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ResultSuggestionChosen(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "ResultSuggestionChosen", 23usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).ResultSuggestionChosen)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveResultSuggestionChosen(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "RemoveResultSuggestionChosen", 24usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).RemoveResultSuggestionChosen)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetLocalContentSuggestionSettings(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "SetLocalContentSuggestionSettings", 25usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).SetLocalContentSuggestionSettings)(this, settings), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ShowOverloadDefault(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "ShowOverloadDefault", 26usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).ShowOverloadDefault)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ShowOverloadWithQuery(this: *mut core::ffi::c_void, query: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "ShowOverloadWithQuery", 27usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).ShowOverloadWithQuery)(this, query), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetShowOnKeyboardInput(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "SetShowOnKeyboardInput", 28usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).SetShowOnKeyboardInput)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ShowOnKeyboardInput(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "ShowOnKeyboardInput", 29usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).ShowOnKeyboardInput)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TrySetQueryText(this: *mut core::ffi::c_void, query: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPane", "TrySetQueryText", 30usize, |this| ((**(this as *const *const ISearchPane_Vtbl)).TrySetQueryText)(this, query, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            SetSearchHistoryEnabled,
+            #[cfg(not(feature = "deprecated"))]
+            SetSearchHistoryEnabled: 0,
+            #[cfg(feature = "deprecated")]
+            SearchHistoryEnabled,
+            #[cfg(not(feature = "deprecated"))]
+            SearchHistoryEnabled: 0,
+            #[cfg(feature = "deprecated")]
+            SetSearchHistoryContext,
+            #[cfg(not(feature = "deprecated"))]
+            SetSearchHistoryContext: 0,
+            #[cfg(feature = "deprecated")]
+            SearchHistoryContext,
+            #[cfg(not(feature = "deprecated"))]
+            SearchHistoryContext: 0,
+            #[cfg(feature = "deprecated")]
+            SetPlaceholderText,
+            #[cfg(not(feature = "deprecated"))]
+            SetPlaceholderText: 0,
+            #[cfg(feature = "deprecated")]
+            PlaceholderText,
+            #[cfg(not(feature = "deprecated"))]
+            PlaceholderText: 0,
+            #[cfg(feature = "deprecated")]
+            QueryText,
+            #[cfg(not(feature = "deprecated"))]
+            QueryText: 0,
+            #[cfg(feature = "deprecated")]
+            Language,
+            #[cfg(not(feature = "deprecated"))]
+            Language: 0,
+            #[cfg(feature = "deprecated")]
+            Visible,
+            #[cfg(not(feature = "deprecated"))]
+            Visible: 0,
+            #[cfg(feature = "deprecated")]
+            VisibilityChanged,
+            #[cfg(not(feature = "deprecated"))]
+            VisibilityChanged: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveVisibilityChanged,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveVisibilityChanged: 0,
+            #[cfg(feature = "deprecated")]
+            QueryChanged,
+            #[cfg(not(feature = "deprecated"))]
+            QueryChanged: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveQueryChanged,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveQueryChanged: 0,
+            #[cfg(feature = "deprecated")]
+            SuggestionsRequested,
+            #[cfg(not(feature = "deprecated"))]
+            SuggestionsRequested: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveSuggestionsRequested,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveSuggestionsRequested: 0,
+            #[cfg(feature = "deprecated")]
+            QuerySubmitted,
+            #[cfg(not(feature = "deprecated"))]
+            QuerySubmitted: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveQuerySubmitted,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveQuerySubmitted: 0,
+            #[cfg(feature = "deprecated")]
+            ResultSuggestionChosen,
+            #[cfg(not(feature = "deprecated"))]
+            ResultSuggestionChosen: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveResultSuggestionChosen,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveResultSuggestionChosen: 0,
+            #[cfg(feature = "deprecated")]
+            SetLocalContentSuggestionSettings,
+            #[cfg(not(feature = "deprecated"))]
+            SetLocalContentSuggestionSettings: 0,
+            #[cfg(feature = "deprecated")]
+            ShowOverloadDefault,
+            #[cfg(not(feature = "deprecated"))]
+            ShowOverloadDefault: 0,
+            #[cfg(feature = "deprecated")]
+            ShowOverloadWithQuery,
+            #[cfg(not(feature = "deprecated"))]
+            ShowOverloadWithQuery: 0,
+            #[cfg(feature = "deprecated")]
+            SetShowOnKeyboardInput,
+            #[cfg(not(feature = "deprecated"))]
+            SetShowOnKeyboardInput: 0,
+            #[cfg(feature = "deprecated")]
+            ShowOnKeyboardInput,
+            #[cfg(not(feature = "deprecated"))]
+            ShowOnKeyboardInput: 0,
+            #[cfg(feature = "deprecated")]
+            TrySetQueryText,
+            #[cfg(not(feature = "deprecated"))]
+            TrySetQueryText: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchPane as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISearchPaneQueryChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn QueryText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneQueryChangedEventArgs", "QueryText", 6usize, |this| ((**(this as *const *const ISearchPaneQueryChangedEventArgs_Vtbl)).QueryText)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneQueryChangedEventArgs", "Language", 7usize, |this| ((**(this as *const *const ISearchPaneQueryChangedEventArgs_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn LinguisticDetails(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneQueryChangedEventArgs", "LinguisticDetails", 8usize, |this| ((**(this as *const *const ISearchPaneQueryChangedEventArgs_Vtbl)).LinguisticDetails)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            QueryText,
+            #[cfg(not(feature = "deprecated"))]
+            QueryText: 0,
+            #[cfg(feature = "deprecated")]
+            Language,
+            #[cfg(not(feature = "deprecated"))]
+            Language: 0,
+            #[cfg(feature = "deprecated")]
+            LinguisticDetails,
+            #[cfg(not(feature = "deprecated"))]
+            LinguisticDetails: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchPaneQueryChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISearchPaneQueryLinguisticDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn QueryTextAlternatives(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneQueryLinguisticDetails", "QueryTextAlternatives", 6usize, |this| ((**(this as *const *const ISearchPaneQueryLinguisticDetails_Vtbl)).QueryTextAlternatives)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryTextCompositionStart(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneQueryLinguisticDetails", "QueryTextCompositionStart", 7usize, |this| ((**(this as *const *const ISearchPaneQueryLinguisticDetails_Vtbl)).QueryTextCompositionStart)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryTextCompositionLength(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneQueryLinguisticDetails", "QueryTextCompositionLength", 8usize, |this| ((**(this as *const *const ISearchPaneQueryLinguisticDetails_Vtbl)).QueryTextCompositionLength)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            QueryTextAlternatives,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            QueryTextAlternatives: 0,
+            QueryTextCompositionStart,
+            QueryTextCompositionLength,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchPaneQueryLinguisticDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISearchPaneQuerySubmittedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn QueryText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneQuerySubmittedEventArgs", "QueryText", 6usize, |this| ((**(this as *const *const ISearchPaneQuerySubmittedEventArgs_Vtbl)).QueryText)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneQuerySubmittedEventArgs", "Language", 7usize, |this| ((**(this as *const *const ISearchPaneQuerySubmittedEventArgs_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            QueryText,
+            #[cfg(not(feature = "deprecated"))]
+            QueryText: 0,
+            #[cfg(feature = "deprecated")]
+            Language,
+            #[cfg(not(feature = "deprecated"))]
+            Language: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchPaneQuerySubmittedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISearchPaneQuerySubmittedEventArgsWithLinguisticDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn LinguisticDetails(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneQuerySubmittedEventArgsWithLinguisticDetails", "LinguisticDetails", 6usize, |this| ((**(this as *const *const ISearchPaneQuerySubmittedEventArgsWithLinguisticDetails_Vtbl)).LinguisticDetails)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            LinguisticDetails,
+            #[cfg(not(feature = "deprecated"))]
+            LinguisticDetails: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchPaneQuerySubmittedEventArgsWithLinguisticDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISearchPaneResultSuggestionChosenEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Tag(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneResultSuggestionChosenEventArgs", "Tag", 6usize, |this| ((**(this as *const *const ISearchPaneResultSuggestionChosenEventArgs_Vtbl)).Tag)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Tag,
+            #[cfg(not(feature = "deprecated"))]
+            Tag: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchPaneResultSuggestionChosenEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISearchPaneStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const ISearchPaneStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            GetForCurrentView,
+            #[cfg(not(feature = "deprecated"))]
+            GetForCurrentView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchPaneStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISearchPaneStaticsWithHideThisApplication_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn HideThisApplication(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneStaticsWithHideThisApplication", "HideThisApplication", 6usize, |this| ((**(this as *const *const ISearchPaneStaticsWithHideThisApplication_Vtbl)).HideThisApplication)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            HideThisApplication,
+            #[cfg(not(feature = "deprecated"))]
+            HideThisApplication: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchPaneStaticsWithHideThisApplication as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISearchPaneSuggestionsRequest_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsCanceled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneSuggestionsRequest", "IsCanceled", 6usize, |this| ((**(this as *const *const ISearchPaneSuggestionsRequest_Vtbl)).IsCanceled)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SearchSuggestionCollection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneSuggestionsRequest", "SearchSuggestionCollection", 7usize, |this| ((**(this as *const *const ISearchPaneSuggestionsRequest_Vtbl)).SearchSuggestionCollection)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneSuggestionsRequest", "GetDeferral", 8usize, |this| ((**(this as *const *const ISearchPaneSuggestionsRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            IsCanceled,
+            #[cfg(not(feature = "deprecated"))]
+            IsCanceled: 0,
+            #[cfg(feature = "deprecated")]
+            SearchSuggestionCollection,
+            #[cfg(not(feature = "deprecated"))]
+            SearchSuggestionCollection: 0,
+            #[cfg(feature = "deprecated")]
+            GetDeferral,
+            #[cfg(not(feature = "deprecated"))]
+            GetDeferral: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchPaneSuggestionsRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISearchPaneSuggestionsRequestDeferral_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneSuggestionsRequestDeferral", "Complete", 6usize, |this| ((**(this as *const *const ISearchPaneSuggestionsRequestDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Complete,
+            #[cfg(not(feature = "deprecated"))]
+            Complete: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchPaneSuggestionsRequestDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISearchPaneSuggestionsRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneSuggestionsRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const ISearchPaneSuggestionsRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Request,
+            #[cfg(not(feature = "deprecated"))]
+            Request: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchPaneSuggestionsRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISearchPaneVisibilityChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Visible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPaneVisibilityChangedEventArgs", "Visible", 6usize, |this| ((**(this as *const *const ISearchPaneVisibilityChangedEventArgs_Vtbl)).Visible)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Visible,
+            #[cfg(not(feature = "deprecated"))]
+            Visible: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchPaneVisibilityChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISearchQueryLinguisticDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn QueryTextAlternatives(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryLinguisticDetails", "QueryTextAlternatives", 6usize, |this| ((**(this as *const *const ISearchQueryLinguisticDetails_Vtbl)).QueryTextAlternatives)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryTextCompositionStart(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryLinguisticDetails", "QueryTextCompositionStart", 7usize, |this| ((**(this as *const *const ISearchQueryLinguisticDetails_Vtbl)).QueryTextCompositionStart)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryTextCompositionLength(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryLinguisticDetails", "QueryTextCompositionLength", 8usize, |this| ((**(this as *const *const ISearchQueryLinguisticDetails_Vtbl)).QueryTextCompositionLength)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            QueryTextAlternatives,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            QueryTextAlternatives: 0,
+            QueryTextCompositionStart,
+            QueryTextCompositionLength,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchQueryLinguisticDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISearchQueryLinguisticDetailsFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, querytextalternatives: *mut core::ffi::c_void, querytextcompositionstart: u32, querytextcompositionlength: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryLinguisticDetailsFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const ISearchQueryLinguisticDetailsFactory_Vtbl)).CreateInstance)(this, querytextalternatives, querytextcompositionstart, querytextcompositionlength, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateInstance,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateInstance: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchQueryLinguisticDetailsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ISearchSuggestionCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionCollection", "Size", 6usize, |this| ((**(this as *const *const ISearchSuggestionCollection_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendQuerySuggestion(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionCollection", "AppendQuerySuggestion", 7usize, |this| ((**(this as *const *const ISearchSuggestionCollection_Vtbl)).AppendQuerySuggestion)(this, text), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AppendQuerySuggestions(this: *mut core::ffi::c_void, suggestions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionCollection", "AppendQuerySuggestions", 8usize, |this| ((**(this as *const *const ISearchSuggestionCollection_Vtbl)).AppendQuerySuggestions)(this, suggestions), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AppendResultSuggestion(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, detailtext: core::mem::MaybeUninit<windows_core::HSTRING>, tag: core::mem::MaybeUninit<windows_core::HSTRING>, image: *mut core::ffi::c_void, imagealternatetext: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionCollection", "AppendResultSuggestion", 9usize, |this| ((**(this as *const *const ISearchSuggestionCollection_Vtbl)).AppendResultSuggestion)(this, text, detailtext, tag, image, imagealternatetext), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendSearchSeparator(this: *mut core::ffi::c_void, label: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionCollection", "AppendSearchSeparator", 10usize, |this| ((**(this as *const *const ISearchSuggestionCollection_Vtbl)).AppendSearchSeparator)(this, label), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Size,
+            AppendQuerySuggestion,
+            #[cfg(feature = "Foundation_Collections")]
+            AppendQuerySuggestions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AppendQuerySuggestions: 0,
+            #[cfg(feature = "Storage_Streams")]
+            AppendResultSuggestion,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AppendResultSuggestion: 0,
+            AppendSearchSeparator,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchSuggestionCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISearchSuggestionsRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsCanceled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionsRequest", "IsCanceled", 6usize, |this| ((**(this as *const *const ISearchSuggestionsRequest_Vtbl)).IsCanceled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchSuggestionCollection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionsRequest", "SearchSuggestionCollection", 7usize, |this| ((**(this as *const *const ISearchSuggestionsRequest_Vtbl)).SearchSuggestionCollection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionsRequest", "GetDeferral", 8usize, |this| ((**(this as *const *const ISearchSuggestionsRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsCanceled, SearchSuggestionCollection, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchSuggestionsRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISearchSuggestionsRequestDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchSuggestionsRequestDeferral", "Complete", 6usize, |this| ((**(this as *const *const ISearchSuggestionsRequestDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchSuggestionsRequestDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
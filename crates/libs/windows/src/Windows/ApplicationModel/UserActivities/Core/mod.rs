@@ -37,3 +37,5 @@ impl CoreUserActivityManager {
 impl windows_core::RuntimeName for CoreUserActivityManager {
     const NAME: &'static str = "Windows.ApplicationModel.UserActivities.Core.CoreUserActivityManager";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
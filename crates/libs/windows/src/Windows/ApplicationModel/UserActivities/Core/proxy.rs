@@ -0,0 +1,19 @@
+impl windows_core::imp::ProxyVtable for ICoreUserActivityManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateUserActivitySessionInBackground(this: *mut core::ffi::c_void, activity: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreUserActivityManagerStatics", "CreateUserActivitySessionInBackground", 6usize, |this| ((**(this as *const *const ICoreUserActivityManagerStatics_Vtbl)).CreateUserActivitySessionInBackground)(this, activity, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteUserActivitySessionsInTimeRangeAsync(this: *mut core::ffi::c_void, channel: *mut core::ffi::c_void, starttime: super::super::super::Foundation::DateTime, endtime: super::super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreUserActivityManagerStatics", "DeleteUserActivitySessionsInTimeRangeAsync", 7usize, |this| ((**(this as *const *const ICoreUserActivityManagerStatics_Vtbl)).DeleteUserActivitySessionsInTimeRangeAsync)(this, channel, starttime, endtime, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateUserActivitySessionInBackground,
+            DeleteUserActivitySessionsInTimeRangeAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreUserActivityManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
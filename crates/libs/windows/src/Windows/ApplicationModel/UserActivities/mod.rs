@@ -943,6 +943,7 @@ impl UserActivityState {
 impl windows_core::TypeKind for UserActivityState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserActivityState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserActivityState").field(&self.0).finish()
@@ -953,3 +954,5 @@ impl windows_core::RuntimeType for UserActivityState {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,487 @@
+impl windows_core::imp::ProxyVtable for IUserActivity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut UserActivityState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity", "State", 6usize, |this| ((**(this as *const *const IUserActivity_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivityId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity", "ActivityId", 7usize, |this| ((**(this as *const *const IUserActivity_Vtbl)).ActivityId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VisualElements(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity", "VisualElements", 8usize, |this| ((**(this as *const *const IUserActivity_Vtbl)).VisualElements)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity", "ContentUri", 9usize, |this| ((**(this as *const *const IUserActivity_Vtbl)).ContentUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity", "SetContentUri", 10usize, |this| ((**(this as *const *const IUserActivity_Vtbl)).SetContentUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity", "ContentType", 11usize, |this| ((**(this as *const *const IUserActivity_Vtbl)).ContentType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentType(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity", "SetContentType", 12usize, |this| ((**(this as *const *const IUserActivity_Vtbl)).SetContentType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FallbackUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity", "FallbackUri", 13usize, |this| ((**(this as *const *const IUserActivity_Vtbl)).FallbackUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFallbackUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity", "SetFallbackUri", 14usize, |this| ((**(this as *const *const IUserActivity_Vtbl)).SetFallbackUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivationUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity", "ActivationUri", 15usize, |this| ((**(this as *const *const IUserActivity_Vtbl)).ActivationUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActivationUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity", "SetActivationUri", 16usize, |this| ((**(this as *const *const IUserActivity_Vtbl)).SetActivationUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity", "ContentInfo", 17usize, |this| ((**(this as *const *const IUserActivity_Vtbl)).ContentInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentInfo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity", "SetContentInfo", 18usize, |this| ((**(this as *const *const IUserActivity_Vtbl)).SetContentInfo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity", "SaveAsync", 19usize, |this| ((**(this as *const *const IUserActivity_Vtbl)).SaveAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSession(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity", "CreateSession", 20usize, |this| ((**(this as *const *const IUserActivity_Vtbl)).CreateSession)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            State,
+            ActivityId,
+            VisualElements,
+            ContentUri,
+            SetContentUri,
+            ContentType,
+            SetContentType,
+            FallbackUri,
+            SetFallbackUri,
+            ActivationUri,
+            SetActivationUri,
+            ContentInfo,
+            SetContentInfo,
+            SaveAsync,
+            CreateSession,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivity as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivity2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ToJson(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity2", "ToJson", 6usize, |this| ((**(this as *const *const IUserActivity2_Vtbl)).ToJson)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ToJson }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivity2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivity3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsRoamable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity3", "IsRoamable", 6usize, |this| ((**(this as *const *const IUserActivity3_Vtbl)).IsRoamable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsRoamable(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivity3", "SetIsRoamable", 7usize, |this| ((**(this as *const *const IUserActivity3_Vtbl)).SetIsRoamable)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsRoamable, SetIsRoamable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivity3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivityAttribution_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IconUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityAttribution", "IconUri", 6usize, |this| ((**(this as *const *const IUserActivityAttribution_Vtbl)).IconUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIconUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityAttribution", "SetIconUri", 7usize, |this| ((**(this as *const *const IUserActivityAttribution_Vtbl)).SetIconUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlternateText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityAttribution", "AlternateText", 8usize, |this| ((**(this as *const *const IUserActivityAttribution_Vtbl)).AlternateText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlternateText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityAttribution", "SetAlternateText", 9usize, |this| ((**(this as *const *const IUserActivityAttribution_Vtbl)).SetAlternateText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddImageQuery(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityAttribution", "AddImageQuery", 10usize, |this| ((**(this as *const *const IUserActivityAttribution_Vtbl)).AddImageQuery)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAddImageQuery(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityAttribution", "SetAddImageQuery", 11usize, |this| ((**(this as *const *const IUserActivityAttribution_Vtbl)).SetAddImageQuery)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IconUri,
+            SetIconUri,
+            AlternateText,
+            SetAlternateText,
+            AddImageQuery,
+            SetAddImageQuery,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityAttribution as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivityAttributionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithUri(this: *mut core::ffi::c_void, iconuri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityAttributionFactory", "CreateWithUri", 6usize, |this| ((**(this as *const *const IUserActivityAttributionFactory_Vtbl)).CreateWithUri)(this, iconuri, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityAttributionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivityChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOrCreateUserActivityAsync(this: *mut core::ffi::c_void, activityid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityChannel", "GetOrCreateUserActivityAsync", 6usize, |this| ((**(this as *const *const IUserActivityChannel_Vtbl)).GetOrCreateUserActivityAsync)(this, activityid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteActivityAsync(this: *mut core::ffi::c_void, activityid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityChannel", "DeleteActivityAsync", 7usize, |this| ((**(this as *const *const IUserActivityChannel_Vtbl)).DeleteActivityAsync)(this, activityid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAllActivitiesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityChannel", "DeleteAllActivitiesAsync", 8usize, |this| ((**(this as *const *const IUserActivityChannel_Vtbl)).DeleteAllActivitiesAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOrCreateUserActivityAsync,
+            DeleteActivityAsync,
+            DeleteAllActivitiesAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityChannel as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUserActivityChannel2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetRecentUserActivitiesAsync(this: *mut core::ffi::c_void, maxuniqueactivities: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityChannel2", "GetRecentUserActivitiesAsync", 6usize, |this| ((**(this as *const *const IUserActivityChannel2_Vtbl)).GetRecentUserActivitiesAsync)(this, maxuniqueactivities, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSessionHistoryItemsForUserActivityAsync(this: *mut core::ffi::c_void, activityid: core::mem::MaybeUninit<windows_core::HSTRING>, starttime: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityChannel2", "GetSessionHistoryItemsForUserActivityAsync", 7usize, |this| ((**(this as *const *const IUserActivityChannel2_Vtbl)).GetSessionHistoryItemsForUserActivityAsync)(this, activityid, starttime, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetRecentUserActivitiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetRecentUserActivitiesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSessionHistoryItemsForUserActivityAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSessionHistoryItemsForUserActivityAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityChannel2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivityChannelStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityChannelStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IUserActivityChannelStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityChannelStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IUserActivityChannelStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisableAutoSessionCreation(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityChannelStatics2", "DisableAutoSessionCreation", 6usize, |this| ((**(this as *const *const IUserActivityChannelStatics2_Vtbl)).DisableAutoSessionCreation)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn TryGetForWebAccount(this: *mut core::ffi::c_void, account: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityChannelStatics2", "TryGetForWebAccount", 7usize, |this| ((**(this as *const *const IUserActivityChannelStatics2_Vtbl)).TryGetForWebAccount)(this, account, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisableAutoSessionCreation,
+            #[cfg(feature = "Security_Credentials")]
+            TryGetForWebAccount,
+            #[cfg(not(feature = "Security_Credentials"))]
+            TryGetForWebAccount: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityChannelStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IUserActivityChannelStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityChannelStatics3", "GetForUser", 6usize, |this| ((**(this as *const *const IUserActivityChannelStatics3_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityChannelStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivityContentInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ToJson(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityContentInfo", "ToJson", 6usize, |this| ((**(this as *const *const IUserActivityContentInfo_Vtbl)).ToJson)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ToJson }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityContentInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivityContentInfoStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromJson(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityContentInfoStatics", "FromJson", 6usize, |this| ((**(this as *const *const IUserActivityContentInfoStatics_Vtbl)).FromJson)(this, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromJson }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityContentInfoStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivityFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithActivityId(this: *mut core::ffi::c_void, activityid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityFactory", "CreateWithActivityId", 6usize, |this| ((**(this as *const *const IUserActivityFactory_Vtbl)).CreateWithActivityId)(this, activityid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithActivityId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivityRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetUserActivity(this: *mut core::ffi::c_void, activity: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityRequest", "SetUserActivity", 6usize, |this| ((**(this as *const *const IUserActivityRequest_Vtbl)).SetUserActivity)(this, activity), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetUserActivity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivityRequestManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserActivityRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityRequestManager", "UserActivityRequested", 6usize, |this| ((**(this as *const *const IUserActivityRequestManager_Vtbl)).UserActivityRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUserActivityRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityRequestManager", "RemoveUserActivityRequested", 7usize, |this| ((**(this as *const *const IUserActivityRequestManager_Vtbl)).RemoveUserActivityRequested)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserActivityRequested, RemoveUserActivityRequested }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityRequestManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivityRequestManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityRequestManagerStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IUserActivityRequestManagerStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityRequestManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivityRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const IUserActivityRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityRequestedEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IUserActivityRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivitySession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivityId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivitySession", "ActivityId", 6usize, |this| ((**(this as *const *const IUserActivitySession_Vtbl)).ActivityId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ActivityId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivitySession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivitySessionHistoryItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserActivity(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivitySessionHistoryItem", "UserActivity", 6usize, |this| ((**(this as *const *const IUserActivitySessionHistoryItem_Vtbl)).UserActivity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivitySessionHistoryItem", "StartTime", 7usize, |this| ((**(this as *const *const IUserActivitySessionHistoryItem_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivitySessionHistoryItem", "EndTime", 8usize, |this| ((**(this as *const *const IUserActivitySessionHistoryItem_Vtbl)).EndTime)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserActivity, StartTime, EndTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivitySessionHistoryItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUserActivityStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryParseFromJson(this: *mut core::ffi::c_void, json: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityStatics", "TryParseFromJson", 6usize, |this| ((**(this as *const *const IUserActivityStatics_Vtbl)).TryParseFromJson)(this, json, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TryParseFromJsonArray(this: *mut core::ffi::c_void, json: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityStatics", "TryParseFromJsonArray", 7usize, |this| ((**(this as *const *const IUserActivityStatics_Vtbl)).TryParseFromJsonArray)(this, json, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ToJsonArray(this: *mut core::ffi::c_void, activities: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityStatics", "ToJsonArray", 8usize, |this| ((**(this as *const *const IUserActivityStatics_Vtbl)).ToJsonArray)(this, activities, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TryParseFromJson,
+            #[cfg(feature = "Foundation_Collections")]
+            TryParseFromJsonArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TryParseFromJsonArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ToJsonArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ToJsonArray: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Shell")]
+impl windows_core::imp::ProxyVtable for IUserActivityVisualElements_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityVisualElements", "DisplayText", 6usize, |this| ((**(this as *const *const IUserActivityVisualElements_Vtbl)).DisplayText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityVisualElements", "SetDisplayText", 7usize, |this| ((**(this as *const *const IUserActivityVisualElements_Vtbl)).SetDisplayText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityVisualElements", "Description", 8usize, |this| ((**(this as *const *const IUserActivityVisualElements_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityVisualElements", "SetDescription", 9usize, |this| ((**(this as *const *const IUserActivityVisualElements_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn BackgroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityVisualElements", "BackgroundColor", 10usize, |this| ((**(this as *const *const IUserActivityVisualElements_Vtbl)).BackgroundColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetBackgroundColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityVisualElements", "SetBackgroundColor", 11usize, |this| ((**(this as *const *const IUserActivityVisualElements_Vtbl)).SetBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Attribution(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityVisualElements", "Attribution", 12usize, |this| ((**(this as *const *const IUserActivityVisualElements_Vtbl)).Attribution)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttribution(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityVisualElements", "SetAttribution", 13usize, |this| ((**(this as *const *const IUserActivityVisualElements_Vtbl)).SetAttribution)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Shell")]
+        unsafe extern "system" fn SetContent(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityVisualElements", "SetContent", 14usize, |this| ((**(this as *const *const IUserActivityVisualElements_Vtbl)).SetContent)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Shell")]
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityVisualElements", "Content", 15usize, |this| ((**(this as *const *const IUserActivityVisualElements_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayText,
+            SetDisplayText,
+            Description,
+            SetDescription,
+            #[cfg(feature = "UI")]
+            BackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            BackgroundColor: 0,
+            #[cfg(feature = "UI")]
+            SetBackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            SetBackgroundColor: 0,
+            Attribution,
+            SetAttribution,
+            #[cfg(feature = "UI_Shell")]
+            SetContent,
+            #[cfg(not(feature = "UI_Shell"))]
+            SetContent: 0,
+            #[cfg(feature = "UI_Shell")]
+            Content,
+            #[cfg(not(feature = "UI_Shell"))]
+            Content: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityVisualElements as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivityVisualElements2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AttributionDisplayText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityVisualElements2", "AttributionDisplayText", 6usize, |this| ((**(this as *const *const IUserActivityVisualElements2_Vtbl)).AttributionDisplayText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttributionDisplayText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityVisualElements2", "SetAttributionDisplayText", 7usize, |this| ((**(this as *const *const IUserActivityVisualElements2_Vtbl)).SetAttributionDisplayText)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AttributionDisplayText, SetAttributionDisplayText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityVisualElements2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
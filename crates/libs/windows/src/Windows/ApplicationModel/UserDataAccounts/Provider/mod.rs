@@ -237,6 +237,7 @@ impl UserDataAccountProviderOperationKind {
 impl windows_core::TypeKind for UserDataAccountProviderOperationKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataAccountProviderOperationKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataAccountProviderOperationKind").field(&self.0).finish()
@@ -255,6 +256,7 @@ impl UserDataAccountProviderPartnerAccountKind {
 impl windows_core::TypeKind for UserDataAccountProviderPartnerAccountKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataAccountProviderPartnerAccountKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataAccountProviderPartnerAccountKind").field(&self.0).finish()
@@ -265,3 +267,5 @@ impl windows_core::RuntimeType for UserDataAccountProviderPartnerAccountKind {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,88 @@
+impl windows_core::imp::ProxyVtable for IUserDataAccountPartnerAccountInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountPartnerAccountInfo", "DisplayName", 6usize, |this| ((**(this as *const *const IUserDataAccountPartnerAccountInfo_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountPartnerAccountInfo", "Priority", 7usize, |this| ((**(this as *const *const IUserDataAccountPartnerAccountInfo_Vtbl)).Priority)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccountKind(this: *mut core::ffi::c_void, result__: *mut UserDataAccountProviderPartnerAccountKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountPartnerAccountInfo", "AccountKind", 8usize, |this| ((**(this as *const *const IUserDataAccountPartnerAccountInfo_Vtbl)).AccountKind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayName, Priority, AccountKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccountPartnerAccountInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUserDataAccountProviderAddAccountOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContentKinds(this: *mut core::ffi::c_void, result__: *mut super::UserDataAccountContentKinds) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountProviderAddAccountOperation", "ContentKinds", 6usize, |this| ((**(this as *const *const IUserDataAccountProviderAddAccountOperation_Vtbl)).ContentKinds)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PartnerAccountInfos(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountProviderAddAccountOperation", "PartnerAccountInfos", 7usize, |this| ((**(this as *const *const IUserDataAccountProviderAddAccountOperation_Vtbl)).PartnerAccountInfos)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompleted(this: *mut core::ffi::c_void, userdataaccountid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountProviderAddAccountOperation", "ReportCompleted", 8usize, |this| ((**(this as *const *const IUserDataAccountProviderAddAccountOperation_Vtbl)).ReportCompleted)(this, userdataaccountid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ContentKinds,
+            #[cfg(feature = "Foundation_Collections")]
+            PartnerAccountInfos,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PartnerAccountInfos: 0,
+            ReportCompleted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccountProviderAddAccountOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataAccountProviderOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut UserDataAccountProviderOperationKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountProviderOperation", "Kind", 6usize, |this| ((**(this as *const *const IUserDataAccountProviderOperation_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccountProviderOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataAccountProviderResolveErrorsOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserDataAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountProviderResolveErrorsOperation", "UserDataAccountId", 6usize, |this| ((**(this as *const *const IUserDataAccountProviderResolveErrorsOperation_Vtbl)).UserDataAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompleted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountProviderResolveErrorsOperation", "ReportCompleted", 7usize, |this| ((**(this as *const *const IUserDataAccountProviderResolveErrorsOperation_Vtbl)).ReportCompleted)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserDataAccountId, ReportCompleted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccountProviderResolveErrorsOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataAccountProviderSettingsOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserDataAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountProviderSettingsOperation", "UserDataAccountId", 6usize, |this| ((**(this as *const *const IUserDataAccountProviderSettingsOperation_Vtbl)).UserDataAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompleted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountProviderSettingsOperation", "ReportCompleted", 7usize, |this| ((**(this as *const *const IUserDataAccountProviderSettingsOperation_Vtbl)).ReportCompleted)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserDataAccountId, ReportCompleted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccountProviderSettingsOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -730,6 +730,7 @@ impl DeviceAccountAuthenticationType {
 impl windows_core::TypeKind for DeviceAccountAuthenticationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceAccountAuthenticationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceAccountAuthenticationType").field(&self.0).finish()
@@ -750,6 +751,7 @@ impl DeviceAccountIconId {
 impl windows_core::TypeKind for DeviceAccountIconId {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceAccountIconId {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceAccountIconId").field(&self.0).finish()
@@ -773,6 +775,7 @@ impl DeviceAccountMailAgeFilter {
 impl windows_core::TypeKind for DeviceAccountMailAgeFilter {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceAccountMailAgeFilter {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceAccountMailAgeFilter").field(&self.0).finish()
@@ -792,6 +795,7 @@ impl DeviceAccountServerType {
 impl windows_core::TypeKind for DeviceAccountServerType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceAccountServerType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceAccountServerType").field(&self.0).finish()
@@ -815,6 +819,7 @@ impl DeviceAccountSyncScheduleKind {
 impl windows_core::TypeKind for DeviceAccountSyncScheduleKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceAccountSyncScheduleKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceAccountSyncScheduleKind").field(&self.0).finish()
@@ -823,3 +828,5 @@ impl core::fmt::Debug for DeviceAccountSyncScheduleKind {
 impl windows_core::RuntimeType for DeviceAccountSyncScheduleKind {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.UserDataAccounts.SystemAccess.DeviceAccountSyncScheduleKind;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
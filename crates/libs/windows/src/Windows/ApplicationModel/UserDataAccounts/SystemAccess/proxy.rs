@@ -0,0 +1,438 @@
+impl windows_core::imp::ProxyVtable for IDeviceAccountConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccountName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "AccountName", 6usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).AccountName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccountName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetAccountName", 7usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetAccountName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceAccountTypeId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "DeviceAccountTypeId", 8usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).DeviceAccountTypeId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeviceAccountTypeId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetDeviceAccountTypeId", 9usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetDeviceAccountTypeId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerType(this: *mut core::ffi::c_void, result__: *mut DeviceAccountServerType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "ServerType", 10usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).ServerType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServerType(this: *mut core::ffi::c_void, value: DeviceAccountServerType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetServerType", 11usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetServerType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmailAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "EmailAddress", 12usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).EmailAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEmailAddress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetEmailAddress", 13usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetEmailAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Domain(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "Domain", 14usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).Domain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDomain(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetDomain", 15usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetDomain)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmailSyncEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "EmailSyncEnabled", 16usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).EmailSyncEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEmailSyncEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetEmailSyncEnabled", 17usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetEmailSyncEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContactsSyncEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "ContactsSyncEnabled", 18usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).ContactsSyncEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContactsSyncEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetContactsSyncEnabled", 19usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetContactsSyncEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CalendarSyncEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "CalendarSyncEnabled", 20usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).CalendarSyncEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCalendarSyncEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetCalendarSyncEnabled", 21usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetCalendarSyncEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncomingServerAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "IncomingServerAddress", 22usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).IncomingServerAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncomingServerAddress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetIncomingServerAddress", 23usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetIncomingServerAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncomingServerPort(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "IncomingServerPort", 24usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).IncomingServerPort)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncomingServerPort(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetIncomingServerPort", 25usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetIncomingServerPort)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncomingServerRequiresSsl(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "IncomingServerRequiresSsl", 26usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).IncomingServerRequiresSsl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncomingServerRequiresSsl(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetIncomingServerRequiresSsl", 27usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetIncomingServerRequiresSsl)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncomingServerUsername(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "IncomingServerUsername", 28usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).IncomingServerUsername)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncomingServerUsername(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetIncomingServerUsername", 29usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetIncomingServerUsername)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutgoingServerAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "OutgoingServerAddress", 30usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).OutgoingServerAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutgoingServerAddress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetOutgoingServerAddress", 31usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetOutgoingServerAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutgoingServerPort(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "OutgoingServerPort", 32usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).OutgoingServerPort)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutgoingServerPort(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetOutgoingServerPort", 33usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetOutgoingServerPort)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutgoingServerRequiresSsl(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "OutgoingServerRequiresSsl", 34usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).OutgoingServerRequiresSsl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutgoingServerRequiresSsl(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetOutgoingServerRequiresSsl", 35usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetOutgoingServerRequiresSsl)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutgoingServerUsername(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "OutgoingServerUsername", 36usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).OutgoingServerUsername)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutgoingServerUsername(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration", "SetOutgoingServerUsername", 37usize, |this| ((**(this as *const *const IDeviceAccountConfiguration_Vtbl)).SetOutgoingServerUsername)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AccountName,
+            SetAccountName,
+            DeviceAccountTypeId,
+            SetDeviceAccountTypeId,
+            ServerType,
+            SetServerType,
+            EmailAddress,
+            SetEmailAddress,
+            Domain,
+            SetDomain,
+            EmailSyncEnabled,
+            SetEmailSyncEnabled,
+            ContactsSyncEnabled,
+            SetContactsSyncEnabled,
+            CalendarSyncEnabled,
+            SetCalendarSyncEnabled,
+            IncomingServerAddress,
+            SetIncomingServerAddress,
+            IncomingServerPort,
+            SetIncomingServerPort,
+            IncomingServerRequiresSsl,
+            SetIncomingServerRequiresSsl,
+            IncomingServerUsername,
+            SetIncomingServerUsername,
+            OutgoingServerAddress,
+            SetOutgoingServerAddress,
+            OutgoingServerPort,
+            SetOutgoingServerPort,
+            OutgoingServerRequiresSsl,
+            SetOutgoingServerRequiresSsl,
+            OutgoingServerUsername,
+            SetOutgoingServerUsername,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceAccountConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IDeviceAccountConfiguration2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn IncomingServerCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "IncomingServerCredential", 6usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).IncomingServerCredential)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetIncomingServerCredential(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetIncomingServerCredential", 7usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetIncomingServerCredential)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn OutgoingServerCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "OutgoingServerCredential", 8usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).OutgoingServerCredential)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetOutgoingServerCredential(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetOutgoingServerCredential", 9usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetOutgoingServerCredential)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OAuthRefreshToken(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "OAuthRefreshToken", 10usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).OAuthRefreshToken)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOAuthRefreshToken(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetOAuthRefreshToken", 11usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetOAuthRefreshToken)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsExternallyManaged(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "IsExternallyManaged", 12usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).IsExternallyManaged)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsExternallyManaged(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetIsExternallyManaged", 13usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetIsExternallyManaged)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccountIconId(this: *mut core::ffi::c_void, result__: *mut DeviceAccountIconId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "AccountIconId", 14usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).AccountIconId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccountIconId(this: *mut core::ffi::c_void, value: DeviceAccountIconId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetAccountIconId", 15usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetAccountIconId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationType(this: *mut core::ffi::c_void, result__: *mut DeviceAccountAuthenticationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "AuthenticationType", 16usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).AuthenticationType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationType(this: *mut core::ffi::c_void, value: DeviceAccountAuthenticationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetAuthenticationType", 17usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetAuthenticationType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSsoAuthenticationSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "IsSsoAuthenticationSupported", 18usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).IsSsoAuthenticationSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SsoAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SsoAccountId", 19usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SsoAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSsoAccountId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetSsoAccountId", 20usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetSsoAccountId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlwaysDownloadFullMessage(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "AlwaysDownloadFullMessage", 21usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).AlwaysDownloadFullMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlwaysDownloadFullMessage(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetAlwaysDownloadFullMessage", 22usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetAlwaysDownloadFullMessage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoesPolicyAllowMailSync(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "DoesPolicyAllowMailSync", 23usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).DoesPolicyAllowMailSync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncScheduleKind(this: *mut core::ffi::c_void, result__: *mut DeviceAccountSyncScheduleKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SyncScheduleKind", 24usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SyncScheduleKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncScheduleKind(this: *mut core::ffi::c_void, value: DeviceAccountSyncScheduleKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetSyncScheduleKind", 25usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetSyncScheduleKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MailAgeFilter(this: *mut core::ffi::c_void, result__: *mut DeviceAccountMailAgeFilter) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "MailAgeFilter", 26usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).MailAgeFilter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMailAgeFilter(this: *mut core::ffi::c_void, value: DeviceAccountMailAgeFilter) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetMailAgeFilter", 27usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetMailAgeFilter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsClientAuthenticationCertificateRequired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "IsClientAuthenticationCertificateRequired", 28usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).IsClientAuthenticationCertificateRequired)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsClientAuthenticationCertificateRequired(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetIsClientAuthenticationCertificateRequired", 29usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetIsClientAuthenticationCertificateRequired)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoSelectAuthenticationCertificate(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "AutoSelectAuthenticationCertificate", 30usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).AutoSelectAuthenticationCertificate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoSelectAuthenticationCertificate(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetAutoSelectAuthenticationCertificate", 31usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetAutoSelectAuthenticationCertificate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationCertificateId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "AuthenticationCertificateId", 32usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).AuthenticationCertificateId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationCertificateId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetAuthenticationCertificateId", 33usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetAuthenticationCertificateId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CardDavSyncScheduleKind(this: *mut core::ffi::c_void, result__: *mut DeviceAccountSyncScheduleKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "CardDavSyncScheduleKind", 34usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).CardDavSyncScheduleKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCardDavSyncScheduleKind(this: *mut core::ffi::c_void, value: DeviceAccountSyncScheduleKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetCardDavSyncScheduleKind", 35usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetCardDavSyncScheduleKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CalDavSyncScheduleKind(this: *mut core::ffi::c_void, result__: *mut DeviceAccountSyncScheduleKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "CalDavSyncScheduleKind", 36usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).CalDavSyncScheduleKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCalDavSyncScheduleKind(this: *mut core::ffi::c_void, value: DeviceAccountSyncScheduleKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetCalDavSyncScheduleKind", 37usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetCalDavSyncScheduleKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CardDavServerUrl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "CardDavServerUrl", 38usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).CardDavServerUrl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCardDavServerUrl(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetCardDavServerUrl", 39usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetCardDavServerUrl)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CardDavRequiresSsl(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "CardDavRequiresSsl", 40usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).CardDavRequiresSsl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCardDavRequiresSsl(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetCardDavRequiresSsl", 41usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetCardDavRequiresSsl)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CalDavServerUrl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "CalDavServerUrl", 42usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).CalDavServerUrl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCalDavServerUrl(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetCalDavServerUrl", 43usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetCalDavServerUrl)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CalDavRequiresSsl(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "CalDavRequiresSsl", 44usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).CalDavRequiresSsl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCalDavRequiresSsl(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetCalDavRequiresSsl", 45usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetCalDavRequiresSsl)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WasModifiedByUser(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "WasModifiedByUser", 46usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).WasModifiedByUser)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWasModifiedByUser(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetWasModifiedByUser", 47usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetWasModifiedByUser)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WasIncomingServerCertificateHashConfirmed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "WasIncomingServerCertificateHashConfirmed", 48usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).WasIncomingServerCertificateHashConfirmed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWasIncomingServerCertificateHashConfirmed(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetWasIncomingServerCertificateHashConfirmed", 49usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetWasIncomingServerCertificateHashConfirmed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncomingServerCertificateHash(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "IncomingServerCertificateHash", 50usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).IncomingServerCertificateHash)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncomingServerCertificateHash(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetIncomingServerCertificateHash", 51usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetIncomingServerCertificateHash)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOutgoingServerAuthenticationRequired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "IsOutgoingServerAuthenticationRequired", 52usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).IsOutgoingServerAuthenticationRequired)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsOutgoingServerAuthenticationRequired(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetIsOutgoingServerAuthenticationRequired", 53usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetIsOutgoingServerAuthenticationRequired)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOutgoingServerAuthenticationEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "IsOutgoingServerAuthenticationEnabled", 54usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).IsOutgoingServerAuthenticationEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsOutgoingServerAuthenticationEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetIsOutgoingServerAuthenticationEnabled", 55usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetIsOutgoingServerAuthenticationEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WasOutgoingServerCertificateHashConfirmed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "WasOutgoingServerCertificateHashConfirmed", 56usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).WasOutgoingServerCertificateHashConfirmed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWasOutgoingServerCertificateHashConfirmed(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetWasOutgoingServerCertificateHashConfirmed", 57usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetWasOutgoingServerCertificateHashConfirmed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutgoingServerCertificateHash(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "OutgoingServerCertificateHash", 58usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).OutgoingServerCertificateHash)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutgoingServerCertificateHash(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetOutgoingServerCertificateHash", 59usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetOutgoingServerCertificateHash)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSyncScheduleManagedBySystem(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "IsSyncScheduleManagedBySystem", 60usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).IsSyncScheduleManagedBySystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsSyncScheduleManagedBySystem(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccountConfiguration2", "SetIsSyncScheduleManagedBySystem", 61usize, |this| ((**(this as *const *const IDeviceAccountConfiguration2_Vtbl)).SetIsSyncScheduleManagedBySystem)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            IncomingServerCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            IncomingServerCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetIncomingServerCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetIncomingServerCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            OutgoingServerCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            OutgoingServerCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetOutgoingServerCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetOutgoingServerCredential: 0,
+            OAuthRefreshToken,
+            SetOAuthRefreshToken,
+            IsExternallyManaged,
+            SetIsExternallyManaged,
+            AccountIconId,
+            SetAccountIconId,
+            AuthenticationType,
+            SetAuthenticationType,
+            IsSsoAuthenticationSupported,
+            SsoAccountId,
+            SetSsoAccountId,
+            AlwaysDownloadFullMessage,
+            SetAlwaysDownloadFullMessage,
+            DoesPolicyAllowMailSync,
+            SyncScheduleKind,
+            SetSyncScheduleKind,
+            MailAgeFilter,
+            SetMailAgeFilter,
+            IsClientAuthenticationCertificateRequired,
+            SetIsClientAuthenticationCertificateRequired,
+            AutoSelectAuthenticationCertificate,
+            SetAutoSelectAuthenticationCertificate,
+            AuthenticationCertificateId,
+            SetAuthenticationCertificateId,
+            CardDavSyncScheduleKind,
+            SetCardDavSyncScheduleKind,
+            CalDavSyncScheduleKind,
+            SetCalDavSyncScheduleKind,
+            CardDavServerUrl,
+            SetCardDavServerUrl,
+            CardDavRequiresSsl,
+            SetCardDavRequiresSsl,
+            CalDavServerUrl,
+            SetCalDavServerUrl,
+            CalDavRequiresSsl,
+            SetCalDavRequiresSsl,
+            WasModifiedByUser,
+            SetWasModifiedByUser,
+            WasIncomingServerCertificateHashConfirmed,
+            SetWasIncomingServerCertificateHashConfirmed,
+            IncomingServerCertificateHash,
+            SetIncomingServerCertificateHash,
+            IsOutgoingServerAuthenticationRequired,
+            SetIsOutgoingServerAuthenticationRequired,
+            IsOutgoingServerAuthenticationEnabled,
+            SetIsOutgoingServerAuthenticationEnabled,
+            WasOutgoingServerCertificateHashConfirmed,
+            SetWasOutgoingServerCertificateHashConfirmed,
+            OutgoingServerCertificateHash,
+            SetOutgoingServerCertificateHash,
+            IsSyncScheduleManagedBySystem,
+            SetIsSyncScheduleManagedBySystem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceAccountConfiguration2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUserDataAccountSystemAccessManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AddAndShowDeviceAccountsAsync(this: *mut core::ffi::c_void, accounts: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountSystemAccessManagerStatics", "AddAndShowDeviceAccountsAsync", 6usize, |this| ((**(this as *const *const IUserDataAccountSystemAccessManagerStatics_Vtbl)).AddAndShowDeviceAccountsAsync)(this, accounts, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AddAndShowDeviceAccountsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AddAndShowDeviceAccountsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccountSystemAccessManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataAccountSystemAccessManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SuppressLocalAccountWithAccountAsync(this: *mut core::ffi::c_void, userdataaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountSystemAccessManagerStatics2", "SuppressLocalAccountWithAccountAsync", 6usize, |this| ((**(this as *const *const IUserDataAccountSystemAccessManagerStatics2_Vtbl)).SuppressLocalAccountWithAccountAsync)(this, userdataaccountid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDeviceAccountAsync(this: *mut core::ffi::c_void, account: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountSystemAccessManagerStatics2", "CreateDeviceAccountAsync", 7usize, |this| ((**(this as *const *const IUserDataAccountSystemAccessManagerStatics2_Vtbl)).CreateDeviceAccountAsync)(this, account, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteDeviceAccountAsync(this: *mut core::ffi::c_void, accountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountSystemAccessManagerStatics2", "DeleteDeviceAccountAsync", 8usize, |this| ((**(this as *const *const IUserDataAccountSystemAccessManagerStatics2_Vtbl)).DeleteDeviceAccountAsync)(this, accountid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceAccountConfigurationAsync(this: *mut core::ffi::c_void, accountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountSystemAccessManagerStatics2", "GetDeviceAccountConfigurationAsync", 9usize, |this| ((**(this as *const *const IUserDataAccountSystemAccessManagerStatics2_Vtbl)).GetDeviceAccountConfigurationAsync)(this, accountid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SuppressLocalAccountWithAccountAsync,
+            CreateDeviceAccountAsync,
+            DeleteDeviceAccountAsync,
+            GetDeviceAccountConfigurationAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccountSystemAccessManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -560,6 +560,7 @@ impl UserDataAccountContentKinds {
 impl windows_core::TypeKind for UserDataAccountContentKinds {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataAccountContentKinds {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataAccountContentKinds").field(&self.0).finish()
@@ -612,6 +613,7 @@ impl UserDataAccountOtherAppReadAccess {
 impl windows_core::TypeKind for UserDataAccountOtherAppReadAccess {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataAccountOtherAppReadAccess {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataAccountOtherAppReadAccess").field(&self.0).finish()
@@ -630,6 +632,7 @@ impl UserDataAccountStoreAccessType {
 impl windows_core::TypeKind for UserDataAccountStoreAccessType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataAccountStoreAccessType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataAccountStoreAccessType").field(&self.0).finish()
@@ -638,3 +641,5 @@ impl core::fmt::Debug for UserDataAccountStoreAccessType {
 impl windows_core::RuntimeType for UserDataAccountStoreAccessType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.UserDataAccounts.UserDataAccountStoreAccessType;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
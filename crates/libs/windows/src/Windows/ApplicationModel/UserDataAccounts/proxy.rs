@@ -0,0 +1,340 @@
+#[cfg(all(feature = "ApplicationModel_Appointments", feature = "ApplicationModel_Contacts", feature = "ApplicationModel_Email", feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IUserDataAccount_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount", "Id", 6usize, |this| ((**(this as *const *const IUserDataAccount_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount", "UserDisplayName", 7usize, |this| ((**(this as *const *const IUserDataAccount_Vtbl)).UserDisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount", "SetUserDisplayName", 8usize, |this| ((**(this as *const *const IUserDataAccount_Vtbl)).SetUserDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherAppReadAccess(this: *mut core::ffi::c_void, result__: *mut UserDataAccountOtherAppReadAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount", "OtherAppReadAccess", 9usize, |this| ((**(this as *const *const IUserDataAccount_Vtbl)).OtherAppReadAccess)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOtherAppReadAccess(this: *mut core::ffi::c_void, value: UserDataAccountOtherAppReadAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount", "SetOtherAppReadAccess", 10usize, |this| ((**(this as *const *const IUserDataAccount_Vtbl)).SetOtherAppReadAccess)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Icon(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount", "Icon", 11usize, |this| ((**(this as *const *const IUserDataAccount_Vtbl)).Icon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceAccountTypeId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount", "DeviceAccountTypeId", 12usize, |this| ((**(this as *const *const IUserDataAccount_Vtbl)).DeviceAccountTypeId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount", "PackageFamilyName", 13usize, |this| ((**(this as *const *const IUserDataAccount_Vtbl)).PackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount", "SaveAsync", 14usize, |this| ((**(this as *const *const IUserDataAccount_Vtbl)).SaveAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount", "DeleteAsync", 15usize, |this| ((**(this as *const *const IUserDataAccount_Vtbl)).DeleteAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel_Appointments", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindAppointmentCalendarsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount", "FindAppointmentCalendarsAsync", 16usize, |this| ((**(this as *const *const IUserDataAccount_Vtbl)).FindAppointmentCalendarsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel_Email", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindEmailMailboxesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount", "FindEmailMailboxesAsync", 17usize, |this| ((**(this as *const *const IUserDataAccount_Vtbl)).FindEmailMailboxesAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel_Contacts", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindContactListsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount", "FindContactListsAsync", 18usize, |this| ((**(this as *const *const IUserDataAccount_Vtbl)).FindContactListsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel_Contacts", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindContactAnnotationListsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount", "FindContactAnnotationListsAsync", 19usize, |this| ((**(this as *const *const IUserDataAccount_Vtbl)).FindContactAnnotationListsAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            UserDisplayName,
+            SetUserDisplayName,
+            OtherAppReadAccess,
+            SetOtherAppReadAccess,
+            #[cfg(feature = "Storage_Streams")]
+            Icon,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Icon: 0,
+            DeviceAccountTypeId,
+            PackageFamilyName,
+            SaveAsync,
+            DeleteAsync,
+            #[cfg(all(feature = "ApplicationModel_Appointments", feature = "Foundation_Collections"))]
+            FindAppointmentCalendarsAsync,
+            #[cfg(not(all(feature = "ApplicationModel_Appointments", feature = "Foundation_Collections")))]
+            FindAppointmentCalendarsAsync: 0,
+            #[cfg(all(feature = "ApplicationModel_Email", feature = "Foundation_Collections"))]
+            FindEmailMailboxesAsync,
+            #[cfg(not(all(feature = "ApplicationModel_Email", feature = "Foundation_Collections")))]
+            FindEmailMailboxesAsync: 0,
+            #[cfg(all(feature = "ApplicationModel_Contacts", feature = "Foundation_Collections"))]
+            FindContactListsAsync,
+            #[cfg(not(all(feature = "ApplicationModel_Contacts", feature = "Foundation_Collections")))]
+            FindContactListsAsync: 0,
+            #[cfg(all(feature = "ApplicationModel_Contacts", feature = "Foundation_Collections"))]
+            FindContactAnnotationListsAsync,
+            #[cfg(not(all(feature = "ApplicationModel_Contacts", feature = "Foundation_Collections")))]
+            FindContactAnnotationListsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccount as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Appointments", feature = "ApplicationModel_Contacts", feature = "ApplicationModel_Email", feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IUserDataAccount2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnterpriseId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount2", "EnterpriseId", 6usize, |this| ((**(this as *const *const IUserDataAccount2_Vtbl)).EnterpriseId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsProtectedUnderLock(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount2", "IsProtectedUnderLock", 7usize, |this| ((**(this as *const *const IUserDataAccount2_Vtbl)).IsProtectedUnderLock)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnterpriseId, IsProtectedUnderLock }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccount2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUserDataAccount3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ExplictReadAccessPackageFamilyNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount3", "ExplictReadAccessPackageFamilyNames", 6usize, |this| ((**(this as *const *const IUserDataAccount3_Vtbl)).ExplictReadAccessPackageFamilyNames)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount3", "DisplayName", 7usize, |this| ((**(this as *const *const IUserDataAccount3_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount3", "SetDisplayName", 8usize, |this| ((**(this as *const *const IUserDataAccount3_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ExplictReadAccessPackageFamilyNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ExplictReadAccessPackageFamilyNames: 0,
+            DisplayName,
+            SetDisplayName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccount3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Contacts", feature = "ApplicationModel_UserDataTasks", feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IUserDataAccount4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanShowCreateContactGroup(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount4", "CanShowCreateContactGroup", 6usize, |this| ((**(this as *const *const IUserDataAccount4_Vtbl)).CanShowCreateContactGroup)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanShowCreateContactGroup(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount4", "SetCanShowCreateContactGroup", 7usize, |this| ((**(this as *const *const IUserDataAccount4_Vtbl)).SetCanShowCreateContactGroup)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ProviderProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount4", "ProviderProperties", 8usize, |this| ((**(this as *const *const IUserDataAccount4_Vtbl)).ProviderProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel_UserDataTasks", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindUserDataTaskListsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount4", "FindUserDataTaskListsAsync", 9usize, |this| ((**(this as *const *const IUserDataAccount4_Vtbl)).FindUserDataTaskListsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel_Contacts", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindContactGroupsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount4", "FindContactGroupsAsync", 10usize, |this| ((**(this as *const *const IUserDataAccount4_Vtbl)).FindContactGroupsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryShowCreateContactGroupAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount4", "TryShowCreateContactGroupAsync", 11usize, |this| ((**(this as *const *const IUserDataAccount4_Vtbl)).TryShowCreateContactGroupAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsProtectedUnderLock(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount4", "SetIsProtectedUnderLock", 12usize, |this| ((**(this as *const *const IUserDataAccount4_Vtbl)).SetIsProtectedUnderLock)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetIcon(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccount4", "SetIcon", 13usize, |this| ((**(this as *const *const IUserDataAccount4_Vtbl)).SetIcon)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanShowCreateContactGroup,
+            SetCanShowCreateContactGroup,
+            #[cfg(feature = "Foundation_Collections")]
+            ProviderProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ProviderProperties: 0,
+            #[cfg(all(feature = "ApplicationModel_UserDataTasks", feature = "Foundation_Collections"))]
+            FindUserDataTaskListsAsync,
+            #[cfg(not(all(feature = "ApplicationModel_UserDataTasks", feature = "Foundation_Collections")))]
+            FindUserDataTaskListsAsync: 0,
+            #[cfg(all(feature = "ApplicationModel_Contacts", feature = "Foundation_Collections"))]
+            FindContactGroupsAsync,
+            #[cfg(not(all(feature = "ApplicationModel_Contacts", feature = "Foundation_Collections")))]
+            FindContactGroupsAsync: 0,
+            TryShowCreateContactGroupAsync,
+            SetIsProtectedUnderLock,
+            #[cfg(feature = "Storage_Streams")]
+            SetIcon,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetIcon: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccount4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IUserDataAccountManagerForUser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, storeaccesstype: UserDataAccountStoreAccessType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountManagerForUser", "RequestStoreAsync", 6usize, |this| ((**(this as *const *const IUserDataAccountManagerForUser_Vtbl)).RequestStoreAsync)(this, storeaccesstype, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountManagerForUser", "User", 7usize, |this| ((**(this as *const *const IUserDataAccountManagerForUser_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestStoreAsync,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccountManagerForUser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataAccountManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, storeaccesstype: UserDataAccountStoreAccessType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountManagerStatics", "RequestStoreAsync", 6usize, |this| ((**(this as *const *const IUserDataAccountManagerStatics_Vtbl)).RequestStoreAsync)(this, storeaccesstype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAddAccountAsync(this: *mut core::ffi::c_void, contentkinds: UserDataAccountContentKinds, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountManagerStatics", "ShowAddAccountAsync", 7usize, |this| ((**(this as *const *const IUserDataAccountManagerStatics_Vtbl)).ShowAddAccountAsync)(this, contentkinds, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAccountSettingsAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountManagerStatics", "ShowAccountSettingsAsync", 8usize, |this| ((**(this as *const *const IUserDataAccountManagerStatics_Vtbl)).ShowAccountSettingsAsync)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAccountErrorResolverAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountManagerStatics", "ShowAccountErrorResolverAsync", 9usize, |this| ((**(this as *const *const IUserDataAccountManagerStatics_Vtbl)).ShowAccountErrorResolverAsync)(this, id, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestStoreAsync,
+            ShowAddAccountAsync,
+            ShowAccountSettingsAsync,
+            ShowAccountErrorResolverAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccountManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IUserDataAccountManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountManagerStatics2", "GetForUser", 6usize, |this| ((**(this as *const *const IUserDataAccountManagerStatics2_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccountManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUserDataAccountStore_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAccountsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountStore", "FindAccountsAsync", 6usize, |this| ((**(this as *const *const IUserDataAccountStore_Vtbl)).FindAccountsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccountAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountStore", "GetAccountAsync", 7usize, |this| ((**(this as *const *const IUserDataAccountStore_Vtbl)).GetAccountAsync)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAccountAsync(this: *mut core::ffi::c_void, userdisplayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountStore", "CreateAccountAsync", 8usize, |this| ((**(this as *const *const IUserDataAccountStore_Vtbl)).CreateAccountAsync)(this, userdisplayname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAccountsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAccountsAsync: 0,
+            GetAccountAsync,
+            CreateAccountAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccountStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUserDataAccountStore2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAccountWithPackageRelativeAppIdAsync(this: *mut core::ffi::c_void, userdisplayname: core::mem::MaybeUninit<windows_core::HSTRING>, packagerelativeappid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountStore2", "CreateAccountWithPackageRelativeAppIdAsync", 6usize, |this| ((**(this as *const *const IUserDataAccountStore2_Vtbl)).CreateAccountWithPackageRelativeAppIdAsync)(this, userdisplayname, packagerelativeappid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StoreChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountStore2", "StoreChanged", 7usize, |this| ((**(this as *const *const IUserDataAccountStore2_Vtbl)).StoreChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStoreChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountStore2", "RemoveStoreChanged", 8usize, |this| ((**(this as *const *const IUserDataAccountStore2_Vtbl)).RemoveStoreChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateAccountWithPackageRelativeAppIdAsync,
+            StoreChanged,
+            RemoveStoreChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccountStore2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUserDataAccountStore3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAccountWithPackageRelativeAppIdAndEnterpriseIdAsync(this: *mut core::ffi::c_void, userdisplayname: core::mem::MaybeUninit<windows_core::HSTRING>, packagerelativeappid: core::mem::MaybeUninit<windows_core::HSTRING>, enterpriseid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountStore3", "CreateAccountWithPackageRelativeAppIdAndEnterpriseIdAsync", 6usize, |this| ((**(this as *const *const IUserDataAccountStore3_Vtbl)).CreateAccountWithPackageRelativeAppIdAndEnterpriseIdAsync)(this, userdisplayname, packagerelativeappid, enterpriseid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateAccountWithPackageRelativeAppIdAndEnterpriseIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccountStore3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataAccountStoreChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAccountStoreChangedEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const IUserDataAccountStoreChangedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAccountStoreChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -638,3 +638,5 @@ impl windows_core::RuntimeName for UserDataTaskListSyncManagerSyncRequestEventAr
 }
 unsafe impl Send for UserDataTaskListSyncManagerSyncRequestEventArgs {}
 unsafe impl Sync for UserDataTaskListSyncManagerSyncRequestEventArgs {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,244 @@
+impl windows_core::imp::ProxyVtable for IUserDataTaskDataProviderConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateOrUpdateTaskRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskDataProviderConnection", "CreateOrUpdateTaskRequested", 6usize, |this| ((**(this as *const *const IUserDataTaskDataProviderConnection_Vtbl)).CreateOrUpdateTaskRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCreateOrUpdateTaskRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskDataProviderConnection", "RemoveCreateOrUpdateTaskRequested", 7usize, |this| ((**(this as *const *const IUserDataTaskDataProviderConnection_Vtbl)).RemoveCreateOrUpdateTaskRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskDataProviderConnection", "SyncRequested", 8usize, |this| ((**(this as *const *const IUserDataTaskDataProviderConnection_Vtbl)).SyncRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSyncRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskDataProviderConnection", "RemoveSyncRequested", 9usize, |this| ((**(this as *const *const IUserDataTaskDataProviderConnection_Vtbl)).RemoveSyncRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SkipOccurrenceRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskDataProviderConnection", "SkipOccurrenceRequested", 10usize, |this| ((**(this as *const *const IUserDataTaskDataProviderConnection_Vtbl)).SkipOccurrenceRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSkipOccurrenceRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskDataProviderConnection", "RemoveSkipOccurrenceRequested", 11usize, |this| ((**(this as *const *const IUserDataTaskDataProviderConnection_Vtbl)).RemoveSkipOccurrenceRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompleteTaskRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskDataProviderConnection", "CompleteTaskRequested", 12usize, |this| ((**(this as *const *const IUserDataTaskDataProviderConnection_Vtbl)).CompleteTaskRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCompleteTaskRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskDataProviderConnection", "RemoveCompleteTaskRequested", 13usize, |this| ((**(this as *const *const IUserDataTaskDataProviderConnection_Vtbl)).RemoveCompleteTaskRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteTaskRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskDataProviderConnection", "DeleteTaskRequested", 14usize, |this| ((**(this as *const *const IUserDataTaskDataProviderConnection_Vtbl)).DeleteTaskRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDeleteTaskRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskDataProviderConnection", "RemoveDeleteTaskRequested", 15usize, |this| ((**(this as *const *const IUserDataTaskDataProviderConnection_Vtbl)).RemoveDeleteTaskRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskDataProviderConnection", "Start", 16usize, |this| ((**(this as *const *const IUserDataTaskDataProviderConnection_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateOrUpdateTaskRequested,
+            RemoveCreateOrUpdateTaskRequested,
+            SyncRequested,
+            RemoveSyncRequested,
+            SkipOccurrenceRequested,
+            RemoveSkipOccurrenceRequested,
+            CompleteTaskRequested,
+            RemoveCompleteTaskRequested,
+            DeleteTaskRequested,
+            RemoveDeleteTaskRequested,
+            Start,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskDataProviderConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskDataProviderTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskDataProviderTriggerDetails", "Connection", 6usize, |this| ((**(this as *const *const IUserDataTaskDataProviderTriggerDetails_Vtbl)).Connection)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Connection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskDataProviderTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskListCompleteTaskRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TaskListId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListCompleteTaskRequest", "TaskListId", 6usize, |this| ((**(this as *const *const IUserDataTaskListCompleteTaskRequest_Vtbl)).TaskListId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListCompleteTaskRequest", "TaskId", 7usize, |this| ((**(this as *const *const IUserDataTaskListCompleteTaskRequest_Vtbl)).TaskId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, completedtaskid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListCompleteTaskRequest", "ReportCompletedAsync", 8usize, |this| ((**(this as *const *const IUserDataTaskListCompleteTaskRequest_Vtbl)).ReportCompletedAsync)(this, completedtaskid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListCompleteTaskRequest", "ReportFailedAsync", 9usize, |this| ((**(this as *const *const IUserDataTaskListCompleteTaskRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TaskListId, TaskId, ReportCompletedAsync, ReportFailedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskListCompleteTaskRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskListCompleteTaskRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListCompleteTaskRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IUserDataTaskListCompleteTaskRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListCompleteTaskRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IUserDataTaskListCompleteTaskRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskListCompleteTaskRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskListCreateOrUpdateTaskRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TaskListId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListCreateOrUpdateTaskRequest", "TaskListId", 6usize, |this| ((**(this as *const *const IUserDataTaskListCreateOrUpdateTaskRequest_Vtbl)).TaskListId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Task(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListCreateOrUpdateTaskRequest", "Task", 7usize, |this| ((**(this as *const *const IUserDataTaskListCreateOrUpdateTaskRequest_Vtbl)).Task)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, createdorupdateduserdatatask: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListCreateOrUpdateTaskRequest", "ReportCompletedAsync", 8usize, |this| ((**(this as *const *const IUserDataTaskListCreateOrUpdateTaskRequest_Vtbl)).ReportCompletedAsync)(this, createdorupdateduserdatatask, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListCreateOrUpdateTaskRequest", "ReportFailedAsync", 9usize, |this| ((**(this as *const *const IUserDataTaskListCreateOrUpdateTaskRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TaskListId, Task, ReportCompletedAsync, ReportFailedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskListCreateOrUpdateTaskRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskListCreateOrUpdateTaskRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListCreateOrUpdateTaskRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IUserDataTaskListCreateOrUpdateTaskRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListCreateOrUpdateTaskRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IUserDataTaskListCreateOrUpdateTaskRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskListCreateOrUpdateTaskRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskListDeleteTaskRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TaskListId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListDeleteTaskRequest", "TaskListId", 6usize, |this| ((**(this as *const *const IUserDataTaskListDeleteTaskRequest_Vtbl)).TaskListId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListDeleteTaskRequest", "TaskId", 7usize, |this| ((**(this as *const *const IUserDataTaskListDeleteTaskRequest_Vtbl)).TaskId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListDeleteTaskRequest", "ReportCompletedAsync", 8usize, |this| ((**(this as *const *const IUserDataTaskListDeleteTaskRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListDeleteTaskRequest", "ReportFailedAsync", 9usize, |this| ((**(this as *const *const IUserDataTaskListDeleteTaskRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TaskListId, TaskId, ReportCompletedAsync, ReportFailedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskListDeleteTaskRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskListDeleteTaskRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListDeleteTaskRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IUserDataTaskListDeleteTaskRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListDeleteTaskRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IUserDataTaskListDeleteTaskRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskListDeleteTaskRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskListSkipOccurrenceRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TaskListId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSkipOccurrenceRequest", "TaskListId", 6usize, |this| ((**(this as *const *const IUserDataTaskListSkipOccurrenceRequest_Vtbl)).TaskListId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSkipOccurrenceRequest", "TaskId", 7usize, |this| ((**(this as *const *const IUserDataTaskListSkipOccurrenceRequest_Vtbl)).TaskId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSkipOccurrenceRequest", "ReportCompletedAsync", 8usize, |this| ((**(this as *const *const IUserDataTaskListSkipOccurrenceRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSkipOccurrenceRequest", "ReportFailedAsync", 9usize, |this| ((**(this as *const *const IUserDataTaskListSkipOccurrenceRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TaskListId, TaskId, ReportCompletedAsync, ReportFailedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskListSkipOccurrenceRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskListSkipOccurrenceRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSkipOccurrenceRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IUserDataTaskListSkipOccurrenceRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSkipOccurrenceRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IUserDataTaskListSkipOccurrenceRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskListSkipOccurrenceRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskListSyncManagerSyncRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TaskListId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSyncManagerSyncRequest", "TaskListId", 6usize, |this| ((**(this as *const *const IUserDataTaskListSyncManagerSyncRequest_Vtbl)).TaskListId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSyncManagerSyncRequest", "ReportCompletedAsync", 7usize, |this| ((**(this as *const *const IUserDataTaskListSyncManagerSyncRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSyncManagerSyncRequest", "ReportFailedAsync", 8usize, |this| ((**(this as *const *const IUserDataTaskListSyncManagerSyncRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TaskListId, ReportCompletedAsync, ReportFailedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskListSyncManagerSyncRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskListSyncManagerSyncRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSyncManagerSyncRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IUserDataTaskListSyncManagerSyncRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSyncManagerSyncRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IUserDataTaskListSyncManagerSyncRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskListSyncManagerSyncRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
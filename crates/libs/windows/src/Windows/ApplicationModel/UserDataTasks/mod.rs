@@ -1091,6 +1091,7 @@ impl UserDataTaskDaysOfWeek {
 impl windows_core::TypeKind for UserDataTaskDaysOfWeek {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataTaskDaysOfWeek {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataTaskDaysOfWeek").field(&self.0).finish()
@@ -1142,6 +1143,7 @@ impl UserDataTaskDetailsKind {
 impl windows_core::TypeKind for UserDataTaskDetailsKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataTaskDetailsKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataTaskDetailsKind").field(&self.0).finish()
@@ -1161,6 +1163,7 @@ impl UserDataTaskKind {
 impl windows_core::TypeKind for UserDataTaskKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataTaskKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataTaskKind").field(&self.0).finish()
@@ -1180,6 +1183,7 @@ impl UserDataTaskListOtherAppReadAccess {
 impl windows_core::TypeKind for UserDataTaskListOtherAppReadAccess {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataTaskListOtherAppReadAccess {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataTaskListOtherAppReadAccess").field(&self.0).finish()
@@ -1198,6 +1202,7 @@ impl UserDataTaskListOtherAppWriteAccess {
 impl windows_core::TypeKind for UserDataTaskListOtherAppWriteAccess {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataTaskListOtherAppWriteAccess {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataTaskListOtherAppWriteAccess").field(&self.0).finish()
@@ -1220,6 +1225,7 @@ impl UserDataTaskListSyncStatus {
 impl windows_core::TypeKind for UserDataTaskListSyncStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataTaskListSyncStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataTaskListSyncStatus").field(&self.0).finish()
@@ -1239,6 +1245,7 @@ impl UserDataTaskPriority {
 impl windows_core::TypeKind for UserDataTaskPriority {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataTaskPriority {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataTaskPriority").field(&self.0).finish()
@@ -1258,6 +1265,7 @@ impl UserDataTaskQueryKind {
 impl windows_core::TypeKind for UserDataTaskQueryKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataTaskQueryKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataTaskQueryKind").field(&self.0).finish()
@@ -1275,6 +1283,7 @@ impl UserDataTaskQuerySortProperty {
 impl windows_core::TypeKind for UserDataTaskQuerySortProperty {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataTaskQuerySortProperty {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataTaskQuerySortProperty").field(&self.0).finish()
@@ -1297,6 +1306,7 @@ impl UserDataTaskRecurrenceUnit {
 impl windows_core::TypeKind for UserDataTaskRecurrenceUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataTaskRecurrenceUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataTaskRecurrenceUnit").field(&self.0).finish()
@@ -1317,6 +1327,7 @@ impl UserDataTaskRegenerationUnit {
 impl windows_core::TypeKind for UserDataTaskRegenerationUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataTaskRegenerationUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataTaskRegenerationUnit").field(&self.0).finish()
@@ -1335,6 +1346,7 @@ impl UserDataTaskSensitivity {
 impl windows_core::TypeKind for UserDataTaskSensitivity {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataTaskSensitivity {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataTaskSensitivity").field(&self.0).finish()
@@ -1353,6 +1365,7 @@ impl UserDataTaskStoreAccessType {
 impl windows_core::TypeKind for UserDataTaskStoreAccessType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataTaskStoreAccessType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataTaskStoreAccessType").field(&self.0).finish()
@@ -1374,6 +1387,7 @@ impl UserDataTaskWeekOfMonth {
 impl windows_core::TypeKind for UserDataTaskWeekOfMonth {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataTaskWeekOfMonth {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataTaskWeekOfMonth").field(&self.0).finish()
@@ -1382,3 +1396,5 @@ impl core::fmt::Debug for UserDataTaskWeekOfMonth {
 impl windows_core::RuntimeType for UserDataTaskWeekOfMonth {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.UserDataTasks.UserDataTaskWeekOfMonth;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
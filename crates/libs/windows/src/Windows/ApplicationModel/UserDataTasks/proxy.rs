@@ -0,0 +1,531 @@
+impl windows_core::imp::ProxyVtable for IUserDataTask_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "Id", 6usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ListId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "ListId", 7usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).ListId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "RemoteId", 8usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).RemoteId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "SetRemoteId", 9usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).SetRemoteId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompletedDate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "CompletedDate", 10usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).CompletedDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompletedDate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "SetCompletedDate", 11usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).SetCompletedDate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Details(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "Details", 12usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).Details)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDetails(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "SetDetails", 13usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).SetDetails)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetailsKind(this: *mut core::ffi::c_void, result__: *mut UserDataTaskDetailsKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "DetailsKind", 14usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).DetailsKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDetailsKind(this: *mut core::ffi::c_void, value: UserDataTaskDetailsKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "SetDetailsKind", 15usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).SetDetailsKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DueDate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "DueDate", 16usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).DueDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDueDate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "SetDueDate", 17usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).SetDueDate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut UserDataTaskKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "Kind", 18usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, result__: *mut UserDataTaskPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "Priority", 19usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).Priority)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, value: UserDataTaskPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "SetPriority", 20usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).SetPriority)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecurrenceProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "RecurrenceProperties", 21usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).RecurrenceProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecurrenceProperties(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "SetRecurrenceProperties", 22usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).SetRecurrenceProperties)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegenerationProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "RegenerationProperties", 23usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).RegenerationProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRegenerationProperties(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "SetRegenerationProperties", 24usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).SetRegenerationProperties)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reminder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "Reminder", 25usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).Reminder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReminder(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "SetReminder", 26usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).SetReminder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sensitivity(this: *mut core::ffi::c_void, result__: *mut UserDataTaskSensitivity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "Sensitivity", 27usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).Sensitivity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSensitivity(this: *mut core::ffi::c_void, value: UserDataTaskSensitivity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "SetSensitivity", 28usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).SetSensitivity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "Subject", 29usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubject(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "SetSubject", 30usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).SetSubject)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartDate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "StartDate", 31usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).StartDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartDate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTask", "SetStartDate", 32usize, |this| ((**(this as *const *const IUserDataTask_Vtbl)).SetStartDate)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            ListId,
+            RemoteId,
+            SetRemoteId,
+            CompletedDate,
+            SetCompletedDate,
+            Details,
+            SetDetails,
+            DetailsKind,
+            SetDetailsKind,
+            DueDate,
+            SetDueDate,
+            Kind,
+            Priority,
+            SetPriority,
+            RecurrenceProperties,
+            SetRecurrenceProperties,
+            RegenerationProperties,
+            SetRegenerationProperties,
+            Reminder,
+            SetReminder,
+            Sensitivity,
+            SetSensitivity,
+            Subject,
+            SetSubject,
+            StartDate,
+            SetStartDate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTask as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUserDataTaskBatch_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Tasks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskBatch", "Tasks", 6usize, |this| ((**(this as *const *const IUserDataTaskBatch_Vtbl)).Tasks)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Tasks,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Tasks: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskBatch as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "Id", 6usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserDataAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "UserDataAccountId", 7usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).UserDataAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "DisplayName", 8usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "SetDisplayName", 9usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "SourceDisplayName", 10usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).SourceDisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherAppReadAccess(this: *mut core::ffi::c_void, result__: *mut UserDataTaskListOtherAppReadAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "OtherAppReadAccess", 11usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).OtherAppReadAccess)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOtherAppReadAccess(this: *mut core::ffi::c_void, value: UserDataTaskListOtherAppReadAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "SetOtherAppReadAccess", 12usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).SetOtherAppReadAccess)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherAppWriteAccess(this: *mut core::ffi::c_void, result__: *mut UserDataTaskListOtherAppWriteAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "OtherAppWriteAccess", 13usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).OtherAppWriteAccess)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOtherAppWriteAccess(this: *mut core::ffi::c_void, value: UserDataTaskListOtherAppWriteAccess) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "SetOtherAppWriteAccess", 14usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).SetOtherAppWriteAccess)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LimitedWriteOperations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "LimitedWriteOperations", 15usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).LimitedWriteOperations)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "SyncManager", 16usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).SyncManager)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterSyncManagerAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "RegisterSyncManagerAsync", 17usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).RegisterSyncManagerAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTaskReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "GetTaskReader", 18usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).GetTaskReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTaskReaderWithOptions(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "GetTaskReaderWithOptions", 19usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).GetTaskReaderWithOptions)(this, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTaskAsync(this: *mut core::ffi::c_void, userdatatask: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "GetTaskAsync", 20usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).GetTaskAsync)(this, userdatatask, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveTaskAsync(this: *mut core::ffi::c_void, userdatatask: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "SaveTaskAsync", 21usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).SaveTaskAsync)(this, userdatatask, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteTaskAsync(this: *mut core::ffi::c_void, userdatataskid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "DeleteTaskAsync", 22usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).DeleteTaskAsync)(this, userdatataskid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "DeleteAsync", 23usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).DeleteAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskList", "SaveAsync", 24usize, |this| ((**(this as *const *const IUserDataTaskList_Vtbl)).SaveAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            UserDataAccountId,
+            DisplayName,
+            SetDisplayName,
+            SourceDisplayName,
+            OtherAppReadAccess,
+            SetOtherAppReadAccess,
+            OtherAppWriteAccess,
+            SetOtherAppWriteAccess,
+            LimitedWriteOperations,
+            SyncManager,
+            RegisterSyncManagerAsync,
+            GetTaskReader,
+            GetTaskReaderWithOptions,
+            GetTaskAsync,
+            SaveTaskAsync,
+            DeleteTaskAsync,
+            DeleteAsync,
+            SaveAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskList as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskListLimitedWriteOperations_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryCompleteTaskAsync(this: *mut core::ffi::c_void, userdatataskid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListLimitedWriteOperations", "TryCompleteTaskAsync", 6usize, |this| ((**(this as *const *const IUserDataTaskListLimitedWriteOperations_Vtbl)).TryCompleteTaskAsync)(this, userdatataskid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryCreateOrUpdateTaskAsync(this: *mut core::ffi::c_void, userdatatask: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListLimitedWriteOperations", "TryCreateOrUpdateTaskAsync", 7usize, |this| ((**(this as *const *const IUserDataTaskListLimitedWriteOperations_Vtbl)).TryCreateOrUpdateTaskAsync)(this, userdatatask, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryDeleteTaskAsync(this: *mut core::ffi::c_void, userdatataskid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListLimitedWriteOperations", "TryDeleteTaskAsync", 8usize, |this| ((**(this as *const *const IUserDataTaskListLimitedWriteOperations_Vtbl)).TryDeleteTaskAsync)(this, userdatataskid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySkipOccurrenceAsync(this: *mut core::ffi::c_void, userdatataskid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListLimitedWriteOperations", "TrySkipOccurrenceAsync", 9usize, |this| ((**(this as *const *const IUserDataTaskListLimitedWriteOperations_Vtbl)).TrySkipOccurrenceAsync)(this, userdatataskid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TryCompleteTaskAsync,
+            TryCreateOrUpdateTaskAsync,
+            TryDeleteTaskAsync,
+            TrySkipOccurrenceAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskListLimitedWriteOperations as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskListSyncManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LastAttemptedSyncTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSyncManager", "LastAttemptedSyncTime", 6usize, |this| ((**(this as *const *const IUserDataTaskListSyncManager_Vtbl)).LastAttemptedSyncTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastAttemptedSyncTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSyncManager", "SetLastAttemptedSyncTime", 7usize, |this| ((**(this as *const *const IUserDataTaskListSyncManager_Vtbl)).SetLastAttemptedSyncTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastSuccessfulSyncTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSyncManager", "LastSuccessfulSyncTime", 8usize, |this| ((**(this as *const *const IUserDataTaskListSyncManager_Vtbl)).LastSuccessfulSyncTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastSuccessfulSyncTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSyncManager", "SetLastSuccessfulSyncTime", 9usize, |this| ((**(this as *const *const IUserDataTaskListSyncManager_Vtbl)).SetLastSuccessfulSyncTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut UserDataTaskListSyncStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSyncManager", "Status", 10usize, |this| ((**(this as *const *const IUserDataTaskListSyncManager_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, value: UserDataTaskListSyncStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSyncManager", "SetStatus", 11usize, |this| ((**(this as *const *const IUserDataTaskListSyncManager_Vtbl)).SetStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSyncManager", "SyncAsync", 12usize, |this| ((**(this as *const *const IUserDataTaskListSyncManager_Vtbl)).SyncAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSyncManager", "SyncStatusChanged", 13usize, |this| ((**(this as *const *const IUserDataTaskListSyncManager_Vtbl)).SyncStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSyncStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskListSyncManager", "RemoveSyncStatusChanged", 14usize, |this| ((**(this as *const *const IUserDataTaskListSyncManager_Vtbl)).RemoveSyncStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LastAttemptedSyncTime,
+            SetLastAttemptedSyncTime,
+            LastSuccessfulSyncTime,
+            SetLastSuccessfulSyncTime,
+            Status,
+            SetStatus,
+            SyncAsync,
+            SyncStatusChanged,
+            RemoveSyncStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskListSyncManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IUserDataTaskManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, accesstype: UserDataTaskStoreAccessType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskManager", "RequestStoreAsync", 6usize, |this| ((**(this as *const *const IUserDataTaskManager_Vtbl)).RequestStoreAsync)(this, accesstype, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskManager", "User", 7usize, |this| ((**(this as *const *const IUserDataTaskManager_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestStoreAsync,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IUserDataTaskManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskManagerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IUserDataTaskManagerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskManagerStatics", "GetForUser", 7usize, |this| ((**(this as *const *const IUserDataTaskManagerStatics_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDefault,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskQueryOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SortProperty(this: *mut core::ffi::c_void, result__: *mut UserDataTaskQuerySortProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskQueryOptions", "SortProperty", 6usize, |this| ((**(this as *const *const IUserDataTaskQueryOptions_Vtbl)).SortProperty)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSortProperty(this: *mut core::ffi::c_void, value: UserDataTaskQuerySortProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskQueryOptions", "SetSortProperty", 7usize, |this| ((**(this as *const *const IUserDataTaskQueryOptions_Vtbl)).SetSortProperty)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut UserDataTaskQueryKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskQueryOptions", "Kind", 8usize, |this| ((**(this as *const *const IUserDataTaskQueryOptions_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKind(this: *mut core::ffi::c_void, value: UserDataTaskQueryKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskQueryOptions", "SetKind", 9usize, |this| ((**(this as *const *const IUserDataTaskQueryOptions_Vtbl)).SetKind)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SortProperty, SetSortProperty, Kind, SetKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskQueryOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadBatchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskReader", "ReadBatchAsync", 6usize, |this| ((**(this as *const *const IUserDataTaskReader_Vtbl)).ReadBatchAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReadBatchAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskRecurrenceProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Unit(this: *mut core::ffi::c_void, result__: *mut UserDataTaskRecurrenceUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "Unit", 6usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).Unit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnit(this: *mut core::ffi::c_void, value: UserDataTaskRecurrenceUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "SetUnit", 7usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).SetUnit)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Occurrences(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "Occurrences", 8usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).Occurrences)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOccurrences(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "SetOccurrences", 9usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).SetOccurrences)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Until(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "Until", 10usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).Until)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUntil(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "SetUntil", 11usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).SetUntil)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Interval(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "Interval", 12usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).Interval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterval(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "SetInterval", 13usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).SetInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DaysOfWeek(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "DaysOfWeek", 14usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).DaysOfWeek)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDaysOfWeek(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "SetDaysOfWeek", 15usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).SetDaysOfWeek)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WeekOfMonth(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "WeekOfMonth", 16usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).WeekOfMonth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWeekOfMonth(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "SetWeekOfMonth", 17usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).SetWeekOfMonth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Month(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "Month", 18usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).Month)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMonth(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "SetMonth", 19usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).SetMonth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Day(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "Day", 20usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).Day)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDay(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRecurrenceProperties", "SetDay", 21usize, |this| ((**(this as *const *const IUserDataTaskRecurrenceProperties_Vtbl)).SetDay)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Unit,
+            SetUnit,
+            Occurrences,
+            SetOccurrences,
+            Until,
+            SetUntil,
+            Interval,
+            SetInterval,
+            DaysOfWeek,
+            SetDaysOfWeek,
+            WeekOfMonth,
+            SetWeekOfMonth,
+            Month,
+            SetMonth,
+            Day,
+            SetDay,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskRecurrenceProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataTaskRegenerationProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Unit(this: *mut core::ffi::c_void, result__: *mut UserDataTaskRegenerationUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRegenerationProperties", "Unit", 6usize, |this| ((**(this as *const *const IUserDataTaskRegenerationProperties_Vtbl)).Unit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnit(this: *mut core::ffi::c_void, value: UserDataTaskRegenerationUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRegenerationProperties", "SetUnit", 7usize, |this| ((**(this as *const *const IUserDataTaskRegenerationProperties_Vtbl)).SetUnit)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Occurrences(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRegenerationProperties", "Occurrences", 8usize, |this| ((**(this as *const *const IUserDataTaskRegenerationProperties_Vtbl)).Occurrences)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOccurrences(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRegenerationProperties", "SetOccurrences", 9usize, |this| ((**(this as *const *const IUserDataTaskRegenerationProperties_Vtbl)).SetOccurrences)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Until(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRegenerationProperties", "Until", 10usize, |this| ((**(this as *const *const IUserDataTaskRegenerationProperties_Vtbl)).Until)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUntil(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRegenerationProperties", "SetUntil", 11usize, |this| ((**(this as *const *const IUserDataTaskRegenerationProperties_Vtbl)).SetUntil)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Interval(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRegenerationProperties", "Interval", 12usize, |this| ((**(this as *const *const IUserDataTaskRegenerationProperties_Vtbl)).Interval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterval(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskRegenerationProperties", "SetInterval", 13usize, |this| ((**(this as *const *const IUserDataTaskRegenerationProperties_Vtbl)).SetInterval)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Unit,
+            SetUnit,
+            Occurrences,
+            SetOccurrences,
+            Until,
+            SetUntil,
+            Interval,
+            SetInterval,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskRegenerationProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUserDataTaskStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateListAsync(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskStore", "CreateListAsync", 6usize, |this| ((**(this as *const *const IUserDataTaskStore_Vtbl)).CreateListAsync)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateListInAccountAsync(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, userdataaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskStore", "CreateListInAccountAsync", 7usize, |this| ((**(this as *const *const IUserDataTaskStore_Vtbl)).CreateListInAccountAsync)(this, name, userdataaccountid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindListsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskStore", "FindListsAsync", 8usize, |this| ((**(this as *const *const IUserDataTaskStore_Vtbl)).FindListsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListAsync(this: *mut core::ffi::c_void, tasklistid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataTaskStore", "GetListAsync", 9usize, |this| ((**(this as *const *const IUserDataTaskStore_Vtbl)).GetListAsync)(this, tasklistid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateListAsync,
+            CreateListInAccountAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            FindListsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindListsAsync: 0,
+            GetListAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataTaskStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -794,6 +794,7 @@ impl VoiceCommandCompletionReason {
 impl windows_core::TypeKind for VoiceCommandCompletionReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VoiceCommandCompletionReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VoiceCommandCompletionReason").field(&self.0).finish()
@@ -818,6 +819,7 @@ impl VoiceCommandContentTileType {
 impl windows_core::TypeKind for VoiceCommandContentTileType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VoiceCommandContentTileType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VoiceCommandContentTileType").field(&self.0).finish()
@@ -826,3 +828,5 @@ impl core::fmt::Debug for VoiceCommandContentTileType {
 impl windows_core::RuntimeType for VoiceCommandContentTileType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.VoiceCommands.VoiceCommandContentTileType;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
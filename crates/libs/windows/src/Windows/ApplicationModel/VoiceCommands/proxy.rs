@@ -0,0 +1,395 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_SpeechRecognition"))]
+impl windows_core::imp::ProxyVtable for IVoiceCommand_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CommandName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommand", "CommandName", 6usize, |this| ((**(this as *const *const IVoiceCommand_Vtbl)).CommandName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommand", "Properties", 7usize, |this| ((**(this as *const *const IVoiceCommand_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_SpeechRecognition")]
+        unsafe extern "system" fn SpeechRecognitionResult(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommand", "SpeechRecognitionResult", 8usize, |this| ((**(this as *const *const IVoiceCommand_Vtbl)).SpeechRecognitionResult)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CommandName,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            #[cfg(feature = "Media_SpeechRecognition")]
+            SpeechRecognitionResult,
+            #[cfg(not(feature = "Media_SpeechRecognition"))]
+            SpeechRecognitionResult: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceCommand as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVoiceCommandCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut VoiceCommandCompletionReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandCompletedEventArgs", "Reason", 6usize, |this| ((**(this as *const *const IVoiceCommandCompletedEventArgs_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceCommandCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVoiceCommandConfirmationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Confirmed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandConfirmationResult", "Confirmed", 6usize, |this| ((**(this as *const *const IVoiceCommandConfirmationResult_Vtbl)).Confirmed)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Confirmed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceCommandConfirmationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IVoiceCommandContentTile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "Title", 6usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "SetTitle", 7usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextLine1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "TextLine1", 8usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).TextLine1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextLine1(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "SetTextLine1", 9usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).SetTextLine1)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextLine2(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "TextLine2", 10usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).TextLine2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextLine2(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "SetTextLine2", 11usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).SetTextLine2)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextLine3(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "TextLine3", 12usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).TextLine3)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextLine3(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "SetTextLine3", 13usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).SetTextLine3)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn Image(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "Image", 14usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).Image)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SetImage(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "SetImage", 15usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).SetImage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppContext(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "AppContext", 16usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).AppContext)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppContext(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "SetAppContext", 17usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).SetAppContext)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppLaunchArgument(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "AppLaunchArgument", 18usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).AppLaunchArgument)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppLaunchArgument(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "SetAppLaunchArgument", 19usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).SetAppLaunchArgument)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentTileType(this: *mut core::ffi::c_void, result__: *mut VoiceCommandContentTileType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "ContentTileType", 20usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).ContentTileType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentTileType(this: *mut core::ffi::c_void, value: VoiceCommandContentTileType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandContentTile", "SetContentTileType", 21usize, |this| ((**(this as *const *const IVoiceCommandContentTile_Vtbl)).SetContentTileType)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Title,
+            SetTitle,
+            TextLine1,
+            SetTextLine1,
+            TextLine2,
+            SetTextLine2,
+            TextLine3,
+            SetTextLine3,
+            #[cfg(feature = "Storage")]
+            Image,
+            #[cfg(not(feature = "Storage"))]
+            Image: 0,
+            #[cfg(feature = "Storage")]
+            SetImage,
+            #[cfg(not(feature = "Storage"))]
+            SetImage: 0,
+            AppContext,
+            SetAppContext,
+            AppLaunchArgument,
+            SetAppLaunchArgument,
+            ContentTileType,
+            SetContentTileType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceCommandContentTile as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVoiceCommandDefinition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandDefinition", "Language", 6usize, |this| ((**(this as *const *const IVoiceCommandDefinition_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandDefinition", "Name", 7usize, |this| ((**(this as *const *const IVoiceCommandDefinition_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetPhraseListAsync(this: *mut core::ffi::c_void, phraselistname: core::mem::MaybeUninit<windows_core::HSTRING>, phraselist: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandDefinition", "SetPhraseListAsync", 8usize, |this| ((**(this as *const *const IVoiceCommandDefinition_Vtbl)).SetPhraseListAsync)(this, phraselistname, phraselist, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Language,
+            Name,
+            #[cfg(feature = "Foundation_Collections")]
+            SetPhraseListAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetPhraseListAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceCommandDefinition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IVoiceCommandDefinitionManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn InstallCommandDefinitionsFromStorageFileAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandDefinitionManagerStatics", "InstallCommandDefinitionsFromStorageFileAsync", 6usize, |this| ((**(this as *const *const IVoiceCommandDefinitionManagerStatics_Vtbl)).InstallCommandDefinitionsFromStorageFileAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn InstalledCommandDefinitions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandDefinitionManagerStatics", "InstalledCommandDefinitions", 7usize, |this| ((**(this as *const *const IVoiceCommandDefinitionManagerStatics_Vtbl)).InstalledCommandDefinitions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            InstallCommandDefinitionsFromStorageFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            InstallCommandDefinitionsFromStorageFileAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            InstalledCommandDefinitions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            InstalledCommandDefinitions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceCommandDefinitionManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVoiceCommandDisambiguationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SelectedItem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandDisambiguationResult", "SelectedItem", 6usize, |this| ((**(this as *const *const IVoiceCommandDisambiguationResult_Vtbl)).SelectedItem)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SelectedItem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceCommandDisambiguationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVoiceCommandResponse_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandResponse", "Message", 6usize, |this| ((**(this as *const *const IVoiceCommandResponse_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessage(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandResponse", "SetMessage", 7usize, |this| ((**(this as *const *const IVoiceCommandResponse_Vtbl)).SetMessage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RepeatMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandResponse", "RepeatMessage", 8usize, |this| ((**(this as *const *const IVoiceCommandResponse_Vtbl)).RepeatMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRepeatMessage(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandResponse", "SetRepeatMessage", 9usize, |this| ((**(this as *const *const IVoiceCommandResponse_Vtbl)).SetRepeatMessage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppLaunchArgument(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandResponse", "AppLaunchArgument", 10usize, |this| ((**(this as *const *const IVoiceCommandResponse_Vtbl)).AppLaunchArgument)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppLaunchArgument(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandResponse", "SetAppLaunchArgument", 11usize, |this| ((**(this as *const *const IVoiceCommandResponse_Vtbl)).SetAppLaunchArgument)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn VoiceCommandContentTiles(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandResponse", "VoiceCommandContentTiles", 12usize, |this| ((**(this as *const *const IVoiceCommandResponse_Vtbl)).VoiceCommandContentTiles)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Message,
+            SetMessage,
+            RepeatMessage,
+            SetRepeatMessage,
+            AppLaunchArgument,
+            SetAppLaunchArgument,
+            #[cfg(feature = "Foundation_Collections")]
+            VoiceCommandContentTiles,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            VoiceCommandContentTiles: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceCommandResponse as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVoiceCommandResponseStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxSupportedVoiceCommandContentTiles(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandResponseStatics", "MaxSupportedVoiceCommandContentTiles", 6usize, |this| ((**(this as *const *const IVoiceCommandResponseStatics_Vtbl)).MaxSupportedVoiceCommandContentTiles)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateResponse(this: *mut core::ffi::c_void, usermessage: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandResponseStatics", "CreateResponse", 7usize, |this| ((**(this as *const *const IVoiceCommandResponseStatics_Vtbl)).CreateResponse)(this, usermessage, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateResponseWithTiles(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, contenttiles: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandResponseStatics", "CreateResponseWithTiles", 8usize, |this| ((**(this as *const *const IVoiceCommandResponseStatics_Vtbl)).CreateResponseWithTiles)(this, message, contenttiles, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateResponseForPrompt(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, repeatmessage: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandResponseStatics", "CreateResponseForPrompt", 9usize, |this| ((**(this as *const *const IVoiceCommandResponseStatics_Vtbl)).CreateResponseForPrompt)(this, message, repeatmessage, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateResponseForPromptWithTiles(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, repeatmessage: *mut core::ffi::c_void, contenttiles: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandResponseStatics", "CreateResponseForPromptWithTiles", 10usize, |this| ((**(this as *const *const IVoiceCommandResponseStatics_Vtbl)).CreateResponseForPromptWithTiles)(this, message, repeatmessage, contenttiles, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaxSupportedVoiceCommandContentTiles,
+            CreateResponse,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateResponseWithTiles,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateResponseWithTiles: 0,
+            CreateResponseForPrompt,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateResponseForPromptWithTiles,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateResponseForPromptWithTiles: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceCommandResponseStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Globalization")]
+impl windows_core::imp::ProxyVtable for IVoiceCommandServiceConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVoiceCommandAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandServiceConnection", "GetVoiceCommandAsync", 6usize, |this| ((**(this as *const *const IVoiceCommandServiceConnection_Vtbl)).GetVoiceCommandAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestConfirmationAsync(this: *mut core::ffi::c_void, response: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandServiceConnection", "RequestConfirmationAsync", 7usize, |this| ((**(this as *const *const IVoiceCommandServiceConnection_Vtbl)).RequestConfirmationAsync)(this, response, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestDisambiguationAsync(this: *mut core::ffi::c_void, response: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandServiceConnection", "RequestDisambiguationAsync", 8usize, |this| ((**(this as *const *const IVoiceCommandServiceConnection_Vtbl)).RequestDisambiguationAsync)(this, response, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportProgressAsync(this: *mut core::ffi::c_void, response: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandServiceConnection", "ReportProgressAsync", 9usize, |this| ((**(this as *const *const IVoiceCommandServiceConnection_Vtbl)).ReportProgressAsync)(this, response, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportSuccessAsync(this: *mut core::ffi::c_void, response: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandServiceConnection", "ReportSuccessAsync", 10usize, |this| ((**(this as *const *const IVoiceCommandServiceConnection_Vtbl)).ReportSuccessAsync)(this, response, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailureAsync(this: *mut core::ffi::c_void, response: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandServiceConnection", "ReportFailureAsync", 11usize, |this| ((**(this as *const *const IVoiceCommandServiceConnection_Vtbl)).ReportFailureAsync)(this, response, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAppLaunchAsync(this: *mut core::ffi::c_void, response: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandServiceConnection", "RequestAppLaunchAsync", 12usize, |this| ((**(this as *const *const IVoiceCommandServiceConnection_Vtbl)).RequestAppLaunchAsync)(this, response, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Globalization")]
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandServiceConnection", "Language", 13usize, |this| ((**(this as *const *const IVoiceCommandServiceConnection_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VoiceCommandCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandServiceConnection", "VoiceCommandCompleted", 14usize, |this| ((**(this as *const *const IVoiceCommandServiceConnection_Vtbl)).VoiceCommandCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVoiceCommandCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandServiceConnection", "RemoveVoiceCommandCompleted", 15usize, |this| ((**(this as *const *const IVoiceCommandServiceConnection_Vtbl)).RemoveVoiceCommandCompleted)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetVoiceCommandAsync,
+            RequestConfirmationAsync,
+            RequestDisambiguationAsync,
+            ReportProgressAsync,
+            ReportSuccessAsync,
+            ReportFailureAsync,
+            RequestAppLaunchAsync,
+            #[cfg(feature = "Globalization")]
+            Language,
+            #[cfg(not(feature = "Globalization"))]
+            Language: 0,
+            VoiceCommandCompleted,
+            RemoveVoiceCommandCompleted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceCommandServiceConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_AppService")]
+impl windows_core::imp::ProxyVtable for IVoiceCommandServiceConnectionStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_AppService")]
+        unsafe extern "system" fn FromAppServiceTriggerDetails(this: *mut core::ffi::c_void, triggerdetails: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandServiceConnectionStatics", "FromAppServiceTriggerDetails", 6usize, |this| ((**(this as *const *const IVoiceCommandServiceConnectionStatics_Vtbl)).FromAppServiceTriggerDetails)(this, triggerdetails, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_AppService")]
+            FromAppServiceTriggerDetails,
+            #[cfg(not(feature = "ApplicationModel_AppService"))]
+            FromAppServiceTriggerDetails: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceCommandServiceConnectionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVoiceCommandUserMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayMessage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandUserMessage", "DisplayMessage", 6usize, |this| ((**(this as *const *const IVoiceCommandUserMessage_Vtbl)).DisplayMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayMessage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandUserMessage", "SetDisplayMessage", 7usize, |this| ((**(this as *const *const IVoiceCommandUserMessage_Vtbl)).SetDisplayMessage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpokenMessage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandUserMessage", "SpokenMessage", 8usize, |this| ((**(this as *const *const IVoiceCommandUserMessage_Vtbl)).SpokenMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpokenMessage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandUserMessage", "SetSpokenMessage", 9usize, |this| ((**(this as *const *const IVoiceCommandUserMessage_Vtbl)).SetSpokenMessage)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayMessage,
+            SetDisplayMessage,
+            SpokenMessage,
+            SetSpokenMessage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceCommandUserMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
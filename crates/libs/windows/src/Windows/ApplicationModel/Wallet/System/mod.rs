@@ -194,6 +194,7 @@ impl windows_core::TypeKind for WalletItemAppAssociation {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WalletItemAppAssociation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WalletItemAppAssociation").field(&self.0).finish()
@@ -203,3 +204,5 @@ impl core::fmt::Debug for WalletItemAppAssociation {
 impl windows_core::RuntimeType for WalletItemAppAssociation {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.Wallet.System.WalletItemAppAssociation;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,100 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IWalletItemSystemStore_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn GetItemsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemSystemStore", "GetItemsAsync", 6usize, |this| ((**(this as *const *const IWalletItemSystemStore_Vtbl)).GetItemsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, item: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemSystemStore", "DeleteAsync", 7usize, |this| ((**(this as *const *const IWalletItemSystemStore_Vtbl)).DeleteAsync)(this, item, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn ImportItemAsync(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemSystemStore", "ImportItemAsync", 8usize, |this| ((**(this as *const *const IWalletItemSystemStore_Vtbl)).ImportItemAsync)(this, stream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetAppStatusForItem(this: *mut core::ffi::c_void, item: *mut core::ffi::c_void, result__: *mut WalletItemAppAssociation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemSystemStore", "GetAppStatusForItem", 9usize, |this| ((**(this as *const *const IWalletItemSystemStore_Vtbl)).GetAppStatusForItem)(this, item, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn LaunchAppForItemAsync(this: *mut core::ffi::c_void, item: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemSystemStore", "LaunchAppForItemAsync", 10usize, |this| ((**(this as *const *const IWalletItemSystemStore_Vtbl)).LaunchAppForItemAsync)(this, item, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            GetItemsAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            GetItemsAsync: 0,
+            #[cfg(feature = "deprecated")]
+            DeleteAsync,
+            #[cfg(not(feature = "deprecated"))]
+            DeleteAsync: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            ImportItemAsync,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            ImportItemAsync: 0,
+            #[cfg(feature = "deprecated")]
+            GetAppStatusForItem,
+            #[cfg(not(feature = "deprecated"))]
+            GetAppStatusForItem: 0,
+            #[cfg(feature = "deprecated")]
+            LaunchAppForItemAsync,
+            #[cfg(not(feature = "deprecated"))]
+            LaunchAppForItemAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletItemSystemStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IWalletItemSystemStore2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ItemsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemSystemStore2", "ItemsChanged", 6usize, |this| ((**(this as *const *const IWalletItemSystemStore2_Vtbl)).ItemsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveItemsChanged(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemSystemStore2", "RemoveItemsChanged", 7usize, |this| ((**(this as *const *const IWalletItemSystemStore2_Vtbl)).RemoveItemsChanged)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            ItemsChanged,
+            #[cfg(not(feature = "deprecated"))]
+            ItemsChanged: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveItemsChanged,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveItemsChanged: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletItemSystemStore2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IWalletManagerSystemStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletManagerSystemStatics", "RequestStoreAsync", 6usize, |this| ((**(this as *const *const IWalletManagerSystemStatics_Vtbl)).RequestStoreAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            RequestStoreAsync,
+            #[cfg(not(feature = "deprecated"))]
+            RequestStoreAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletManagerSystemStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
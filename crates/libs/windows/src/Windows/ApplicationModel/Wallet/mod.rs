@@ -1522,6 +1522,7 @@ impl windows_core::TypeKind for WalletActionKind {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WalletActionKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WalletActionKind").field(&self.0).finish()
@@ -1555,6 +1556,7 @@ impl windows_core::TypeKind for WalletBarcodeSymbology {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WalletBarcodeSymbology {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WalletBarcodeSymbology").field(&self.0).finish()
@@ -1591,6 +1593,7 @@ impl windows_core::TypeKind for WalletDetailViewPosition {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WalletDetailViewPosition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WalletDetailViewPosition").field(&self.0).finish()
@@ -1619,6 +1622,7 @@ impl windows_core::TypeKind for WalletItemKind {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WalletItemKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WalletItemKind").field(&self.0).finish()
@@ -1643,6 +1647,7 @@ impl windows_core::TypeKind for WalletSummaryViewPosition {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WalletSummaryViewPosition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WalletSummaryViewPosition").field(&self.0).finish()
@@ -1652,3 +1657,5 @@ impl core::fmt::Debug for WalletSummaryViewPosition {
 impl windows_core::RuntimeType for WalletSummaryViewPosition {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.Wallet.WalletSummaryViewPosition;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,964 @@
+#[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IWalletBarcode_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Symbology(this: *mut core::ffi::c_void, result__: *mut WalletBarcodeSymbology) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletBarcode", "Symbology", 6usize, |this| ((**(this as *const *const IWalletBarcode_Vtbl)).Symbology)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletBarcode", "Value", 7usize, |this| ((**(this as *const *const IWalletBarcode_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn GetImageAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletBarcode", "GetImageAsync", 8usize, |this| ((**(this as *const *const IWalletBarcode_Vtbl)).GetImageAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Symbology,
+            #[cfg(not(feature = "deprecated"))]
+            Symbology: 0,
+            #[cfg(feature = "deprecated")]
+            Value,
+            #[cfg(not(feature = "deprecated"))]
+            Value: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            GetImageAsync,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            GetImageAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletBarcode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IWalletBarcodeFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreateWalletBarcode(this: *mut core::ffi::c_void, symbology: WalletBarcodeSymbology, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletBarcodeFactory", "CreateWalletBarcode", 6usize, |this| ((**(this as *const *const IWalletBarcodeFactory_Vtbl)).CreateWalletBarcode)(this, symbology, value, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn CreateCustomWalletBarcode(this: *mut core::ffi::c_void, streamtobarcodeimage: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletBarcodeFactory", "CreateCustomWalletBarcode", 7usize, |this| ((**(this as *const *const IWalletBarcodeFactory_Vtbl)).CreateCustomWalletBarcode)(this, streamtobarcodeimage, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CreateWalletBarcode,
+            #[cfg(not(feature = "deprecated"))]
+            CreateWalletBarcode: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            CreateCustomWalletBarcode,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            CreateCustomWalletBarcode: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletBarcodeFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams", feature = "UI", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IWalletItem_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "DisplayName", 6usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetDisplayName", 7usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "Id", 8usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsAcknowledged(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "IsAcknowledged", 9usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).IsAcknowledged)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetIsAcknowledged(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetIsAcknowledged", 10usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetIsAcknowledged)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IssuerDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "IssuerDisplayName", 11usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).IssuerDisplayName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetIssuerDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetIssuerDisplayName", 12usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetIssuerDisplayName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn LastUpdated(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "LastUpdated", 13usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).LastUpdated)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetLastUpdated(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetLastUpdated", 14usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetLastUpdated)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut WalletItemKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "Kind", 15usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Barcode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "Barcode", 16usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).Barcode)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetBarcode(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetBarcode", 17usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetBarcode)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ExpirationDate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "ExpirationDate", 18usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).ExpirationDate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetExpirationDate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetExpirationDate", 19usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetExpirationDate)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn Logo159x159(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "Logo159x159", 20usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).Logo159x159)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn SetLogo159x159(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetLogo159x159", 21usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetLogo159x159)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn Logo336x336(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "Logo336x336", 22usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).Logo336x336)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn SetLogo336x336(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetLogo336x336", 23usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetLogo336x336)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn Logo99x99(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "Logo99x99", 24usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).Logo99x99)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn SetLogo99x99(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetLogo99x99", 25usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetLogo99x99)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DisplayMessage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "DisplayMessage", 26usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).DisplayMessage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetDisplayMessage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetDisplayMessage", 27usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetDisplayMessage)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsDisplayMessageLaunchable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "IsDisplayMessageLaunchable", 28usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).IsDisplayMessageLaunchable)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetIsDisplayMessageLaunchable(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetIsDisplayMessageLaunchable", 29usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetIsDisplayMessageLaunchable)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn LogoText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "LogoText", 30usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).LogoText)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetLogoText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetLogoText", 31usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetLogoText)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "UI", feature = "deprecated"))]
+        unsafe extern "system" fn HeaderColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "HeaderColor", 32usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).HeaderColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "UI", feature = "deprecated"))]
+        unsafe extern "system" fn SetHeaderColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetHeaderColor", 33usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetHeaderColor)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "UI", feature = "deprecated"))]
+        unsafe extern "system" fn BodyColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "BodyColor", 34usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).BodyColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "UI", feature = "deprecated"))]
+        unsafe extern "system" fn SetBodyColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetBodyColor", 35usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetBodyColor)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "UI", feature = "deprecated"))]
+        unsafe extern "system" fn HeaderFontColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "HeaderFontColor", 36usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).HeaderFontColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "UI", feature = "deprecated"))]
+        unsafe extern "system" fn SetHeaderFontColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetHeaderFontColor", 37usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetHeaderFontColor)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "UI", feature = "deprecated"))]
+        unsafe extern "system" fn BodyFontColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "BodyFontColor", 38usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).BodyFontColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "UI", feature = "deprecated"))]
+        unsafe extern "system" fn SetBodyFontColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetBodyFontColor", 39usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetBodyFontColor)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn HeaderBackgroundImage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "HeaderBackgroundImage", 40usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).HeaderBackgroundImage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn SetHeaderBackgroundImage(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetHeaderBackgroundImage", 41usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetHeaderBackgroundImage)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn BodyBackgroundImage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "BodyBackgroundImage", 42usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).BodyBackgroundImage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn SetBodyBackgroundImage(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetBodyBackgroundImage", 43usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetBodyBackgroundImage)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn LogoImage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "LogoImage", 44usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).LogoImage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn SetLogoImage(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetLogoImage", 45usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetLogoImage)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn PromotionalImage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "PromotionalImage", 46usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).PromotionalImage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn SetPromotionalImage(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetPromotionalImage", 47usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetPromotionalImage)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RelevantDate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "RelevantDate", 48usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).RelevantDate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetRelevantDate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetRelevantDate", 49usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetRelevantDate)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RelevantDateDisplayMessage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "RelevantDateDisplayMessage", 50usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).RelevantDateDisplayMessage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetRelevantDateDisplayMessage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetRelevantDateDisplayMessage", 51usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetRelevantDateDisplayMessage)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn TransactionHistory(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "TransactionHistory", 52usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).TransactionHistory)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn RelevantLocations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "RelevantLocations", 53usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).RelevantLocations)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsMoreTransactionHistoryLaunchable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "IsMoreTransactionHistoryLaunchable", 54usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).IsMoreTransactionHistoryLaunchable)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetIsMoreTransactionHistoryLaunchable(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "SetIsMoreTransactionHistoryLaunchable", 55usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).SetIsMoreTransactionHistoryLaunchable)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn DisplayProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "DisplayProperties", 56usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).DisplayProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn Verbs(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItem", "Verbs", 57usize, |this| ((**(this as *const *const IWalletItem_Vtbl)).Verbs)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            DisplayName,
+            #[cfg(not(feature = "deprecated"))]
+            DisplayName: 0,
+            #[cfg(feature = "deprecated")]
+            SetDisplayName,
+            #[cfg(not(feature = "deprecated"))]
+            SetDisplayName: 0,
+            #[cfg(feature = "deprecated")]
+            Id,
+            #[cfg(not(feature = "deprecated"))]
+            Id: 0,
+            #[cfg(feature = "deprecated")]
+            IsAcknowledged,
+            #[cfg(not(feature = "deprecated"))]
+            IsAcknowledged: 0,
+            #[cfg(feature = "deprecated")]
+            SetIsAcknowledged,
+            #[cfg(not(feature = "deprecated"))]
+            SetIsAcknowledged: 0,
+            #[cfg(feature = "deprecated")]
+            IssuerDisplayName,
+            #[cfg(not(feature = "deprecated"))]
+            IssuerDisplayName: 0,
+            #[cfg(feature = "deprecated")]
+            SetIssuerDisplayName,
+            #[cfg(not(feature = "deprecated"))]
+            SetIssuerDisplayName: 0,
+            #[cfg(feature = "deprecated")]
+            LastUpdated,
+            #[cfg(not(feature = "deprecated"))]
+            LastUpdated: 0,
+            #[cfg(feature = "deprecated")]
+            SetLastUpdated,
+            #[cfg(not(feature = "deprecated"))]
+            SetLastUpdated: 0,
+            #[cfg(feature = "deprecated")]
+            Kind,
+            #[cfg(not(feature = "deprecated"))]
+            Kind: 0,
+            #[cfg(feature = "deprecated")]
+            Barcode,
+            #[cfg(not(feature = "deprecated"))]
+            Barcode: 0,
+            #[cfg(feature = "deprecated")]
+            SetBarcode,
+            #[cfg(not(feature = "deprecated"))]
+            SetBarcode: 0,
+            #[cfg(feature = "deprecated")]
+            ExpirationDate,
+            #[cfg(not(feature = "deprecated"))]
+            ExpirationDate: 0,
+            #[cfg(feature = "deprecated")]
+            SetExpirationDate,
+            #[cfg(not(feature = "deprecated"))]
+            SetExpirationDate: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            Logo159x159,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            Logo159x159: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            SetLogo159x159,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            SetLogo159x159: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            Logo336x336,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            Logo336x336: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            SetLogo336x336,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            SetLogo336x336: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            Logo99x99,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            Logo99x99: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            SetLogo99x99,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            SetLogo99x99: 0,
+            #[cfg(feature = "deprecated")]
+            DisplayMessage,
+            #[cfg(not(feature = "deprecated"))]
+            DisplayMessage: 0,
+            #[cfg(feature = "deprecated")]
+            SetDisplayMessage,
+            #[cfg(not(feature = "deprecated"))]
+            SetDisplayMessage: 0,
+            #[cfg(feature = "deprecated")]
+            IsDisplayMessageLaunchable,
+            #[cfg(not(feature = "deprecated"))]
+            IsDisplayMessageLaunchable: 0,
+            #[cfg(feature = "deprecated")]
+            SetIsDisplayMessageLaunchable,
+            #[cfg(not(feature = "deprecated"))]
+            SetIsDisplayMessageLaunchable: 0,
+            #[cfg(feature = "deprecated")]
+            LogoText,
+            #[cfg(not(feature = "deprecated"))]
+            LogoText: 0,
+            #[cfg(feature = "deprecated")]
+            SetLogoText,
+            #[cfg(not(feature = "deprecated"))]
+            SetLogoText: 0,
+            #[cfg(all(feature = "UI", feature = "deprecated"))]
+            HeaderColor,
+            #[cfg(not(all(feature = "UI", feature = "deprecated")))]
+            HeaderColor: 0,
+            #[cfg(all(feature = "UI", feature = "deprecated"))]
+            SetHeaderColor,
+            #[cfg(not(all(feature = "UI", feature = "deprecated")))]
+            SetHeaderColor: 0,
+            #[cfg(all(feature = "UI", feature = "deprecated"))]
+            BodyColor,
+            #[cfg(not(all(feature = "UI", feature = "deprecated")))]
+            BodyColor: 0,
+            #[cfg(all(feature = "UI", feature = "deprecated"))]
+            SetBodyColor,
+            #[cfg(not(all(feature = "UI", feature = "deprecated")))]
+            SetBodyColor: 0,
+            #[cfg(all(feature = "UI", feature = "deprecated"))]
+            HeaderFontColor,
+            #[cfg(not(all(feature = "UI", feature = "deprecated")))]
+            HeaderFontColor: 0,
+            #[cfg(all(feature = "UI", feature = "deprecated"))]
+            SetHeaderFontColor,
+            #[cfg(not(all(feature = "UI", feature = "deprecated")))]
+            SetHeaderFontColor: 0,
+            #[cfg(all(feature = "UI", feature = "deprecated"))]
+            BodyFontColor,
+            #[cfg(not(all(feature = "UI", feature = "deprecated")))]
+            BodyFontColor: 0,
+            #[cfg(all(feature = "UI", feature = "deprecated"))]
+            SetBodyFontColor,
+            #[cfg(not(all(feature = "UI", feature = "deprecated")))]
+            SetBodyFontColor: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            HeaderBackgroundImage,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            HeaderBackgroundImage: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            SetHeaderBackgroundImage,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            SetHeaderBackgroundImage: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            BodyBackgroundImage,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            BodyBackgroundImage: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            SetBodyBackgroundImage,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            SetBodyBackgroundImage: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            LogoImage,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            LogoImage: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            SetLogoImage,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            SetLogoImage: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            PromotionalImage,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            PromotionalImage: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            SetPromotionalImage,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            SetPromotionalImage: 0,
+            #[cfg(feature = "deprecated")]
+            RelevantDate,
+            #[cfg(not(feature = "deprecated"))]
+            RelevantDate: 0,
+            #[cfg(feature = "deprecated")]
+            SetRelevantDate,
+            #[cfg(not(feature = "deprecated"))]
+            SetRelevantDate: 0,
+            #[cfg(feature = "deprecated")]
+            RelevantDateDisplayMessage,
+            #[cfg(not(feature = "deprecated"))]
+            RelevantDateDisplayMessage: 0,
+            #[cfg(feature = "deprecated")]
+            SetRelevantDateDisplayMessage,
+            #[cfg(not(feature = "deprecated"))]
+            SetRelevantDateDisplayMessage: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            TransactionHistory,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            TransactionHistory: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            RelevantLocations,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            RelevantLocations: 0,
+            #[cfg(feature = "deprecated")]
+            IsMoreTransactionHistoryLaunchable,
+            #[cfg(not(feature = "deprecated"))]
+            IsMoreTransactionHistoryLaunchable: 0,
+            #[cfg(feature = "deprecated")]
+            SetIsMoreTransactionHistoryLaunchable,
+            #[cfg(not(feature = "deprecated"))]
+            SetIsMoreTransactionHistoryLaunchable: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            DisplayProperties,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            DisplayProperties: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            Verbs,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            Verbs: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IWalletItemCustomProperty_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemCustomProperty", "Name", 6usize, |this| ((**(this as *const *const IWalletItemCustomProperty_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemCustomProperty", "SetName", 7usize, |this| ((**(this as *const *const IWalletItemCustomProperty_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemCustomProperty", "Value", 8usize, |this| ((**(this as *const *const IWalletItemCustomProperty_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemCustomProperty", "SetValue", 9usize, |this| ((**(this as *const *const IWalletItemCustomProperty_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AutoDetectLinks(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemCustomProperty", "AutoDetectLinks", 10usize, |this| ((**(this as *const *const IWalletItemCustomProperty_Vtbl)).AutoDetectLinks)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetAutoDetectLinks(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemCustomProperty", "SetAutoDetectLinks", 11usize, |this| ((**(this as *const *const IWalletItemCustomProperty_Vtbl)).SetAutoDetectLinks)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DetailViewPosition(this: *mut core::ffi::c_void, result__: *mut WalletDetailViewPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemCustomProperty", "DetailViewPosition", 12usize, |this| ((**(this as *const *const IWalletItemCustomProperty_Vtbl)).DetailViewPosition)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetDetailViewPosition(this: *mut core::ffi::c_void, value: WalletDetailViewPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemCustomProperty", "SetDetailViewPosition", 13usize, |this| ((**(this as *const *const IWalletItemCustomProperty_Vtbl)).SetDetailViewPosition)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SummaryViewPosition(this: *mut core::ffi::c_void, result__: *mut WalletSummaryViewPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemCustomProperty", "SummaryViewPosition", 14usize, |this| ((**(this as *const *const IWalletItemCustomProperty_Vtbl)).SummaryViewPosition)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetSummaryViewPosition(this: *mut core::ffi::c_void, value: WalletSummaryViewPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemCustomProperty", "SetSummaryViewPosition", 15usize, |this| ((**(this as *const *const IWalletItemCustomProperty_Vtbl)).SetSummaryViewPosition)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Name,
+            #[cfg(not(feature = "deprecated"))]
+            Name: 0,
+            #[cfg(feature = "deprecated")]
+            SetName,
+            #[cfg(not(feature = "deprecated"))]
+            SetName: 0,
+            #[cfg(feature = "deprecated")]
+            Value,
+            #[cfg(not(feature = "deprecated"))]
+            Value: 0,
+            #[cfg(feature = "deprecated")]
+            SetValue,
+            #[cfg(not(feature = "deprecated"))]
+            SetValue: 0,
+            #[cfg(feature = "deprecated")]
+            AutoDetectLinks,
+            #[cfg(not(feature = "deprecated"))]
+            AutoDetectLinks: 0,
+            #[cfg(feature = "deprecated")]
+            SetAutoDetectLinks,
+            #[cfg(not(feature = "deprecated"))]
+            SetAutoDetectLinks: 0,
+            #[cfg(feature = "deprecated")]
+            DetailViewPosition,
+            #[cfg(not(feature = "deprecated"))]
+            DetailViewPosition: 0,
+            #[cfg(feature = "deprecated")]
+            SetDetailViewPosition,
+            #[cfg(not(feature = "deprecated"))]
+            SetDetailViewPosition: 0,
+            #[cfg(feature = "deprecated")]
+            SummaryViewPosition,
+            #[cfg(not(feature = "deprecated"))]
+            SummaryViewPosition: 0,
+            #[cfg(feature = "deprecated")]
+            SetSummaryViewPosition,
+            #[cfg(not(feature = "deprecated"))]
+            SetSummaryViewPosition: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletItemCustomProperty as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IWalletItemCustomPropertyFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreateWalletItemCustomProperty(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemCustomPropertyFactory", "CreateWalletItemCustomProperty", 6usize, |this| ((**(this as *const *const IWalletItemCustomPropertyFactory_Vtbl)).CreateWalletItemCustomProperty)(this, name, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CreateWalletItemCustomProperty,
+            #[cfg(not(feature = "deprecated"))]
+            CreateWalletItemCustomProperty: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletItemCustomPropertyFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IWalletItemFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreateWalletItem(this: *mut core::ffi::c_void, kind: WalletItemKind, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemFactory", "CreateWalletItem", 6usize, |this| ((**(this as *const *const IWalletItemFactory_Vtbl)).CreateWalletItem)(this, kind, displayname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CreateWalletItem,
+            #[cfg(not(feature = "deprecated"))]
+            CreateWalletItem: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletItemFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IWalletItemStore_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AddAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, item: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemStore", "AddAsync", 6usize, |this| ((**(this as *const *const IWalletItemStore_Vtbl)).AddAsync)(this, id, item, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ClearAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemStore", "ClearAsync", 7usize, |this| ((**(this as *const *const IWalletItemStore_Vtbl)).ClearAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetWalletItemAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemStore", "GetWalletItemAsync", 8usize, |this| ((**(this as *const *const IWalletItemStore_Vtbl)).GetWalletItemAsync)(this, id, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn GetItemsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemStore", "GetItemsAsync", 9usize, |this| ((**(this as *const *const IWalletItemStore_Vtbl)).GetItemsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn GetItemsWithKindAsync(this: *mut core::ffi::c_void, kind: WalletItemKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemStore", "GetItemsWithKindAsync", 10usize, |this| ((**(this as *const *const IWalletItemStore_Vtbl)).GetItemsWithKindAsync)(this, kind, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn ImportItemAsync(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemStore", "ImportItemAsync", 11usize, |this| ((**(this as *const *const IWalletItemStore_Vtbl)).ImportItemAsync)(this, stream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemStore", "DeleteAsync", 12usize, |this| ((**(this as *const *const IWalletItemStore_Vtbl)).DeleteAsync)(this, id, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ShowAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemStore", "ShowAsync", 13usize, |this| ((**(this as *const *const IWalletItemStore_Vtbl)).ShowAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ShowItemAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemStore", "ShowItemAsync", 14usize, |this| ((**(this as *const *const IWalletItemStore_Vtbl)).ShowItemAsync)(this, id, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn UpdateAsync(this: *mut core::ffi::c_void, item: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemStore", "UpdateAsync", 15usize, |this| ((**(this as *const *const IWalletItemStore_Vtbl)).UpdateAsync)(this, item, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            AddAsync,
+            #[cfg(not(feature = "deprecated"))]
+            AddAsync: 0,
+            #[cfg(feature = "deprecated")]
+            ClearAsync,
+            #[cfg(not(feature = "deprecated"))]
+            ClearAsync: 0,
+            #[cfg(feature = "deprecated")]
+            GetWalletItemAsync,
+            #[cfg(not(feature = "deprecated"))]
+            GetWalletItemAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            GetItemsAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            GetItemsAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            GetItemsWithKindAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            GetItemsWithKindAsync: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            ImportItemAsync,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            ImportItemAsync: 0,
+            #[cfg(feature = "deprecated")]
+            DeleteAsync,
+            #[cfg(not(feature = "deprecated"))]
+            DeleteAsync: 0,
+            #[cfg(feature = "deprecated")]
+            ShowAsync,
+            #[cfg(not(feature = "deprecated"))]
+            ShowAsync: 0,
+            #[cfg(feature = "deprecated")]
+            ShowItemAsync,
+            #[cfg(not(feature = "deprecated"))]
+            ShowItemAsync: 0,
+            #[cfg(feature = "deprecated")]
+            UpdateAsync,
+            #[cfg(not(feature = "deprecated"))]
+            UpdateAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletItemStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IWalletItemStore2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ItemsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemStore2", "ItemsChanged", 6usize, |this| ((**(this as *const *const IWalletItemStore2_Vtbl)).ItemsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveItemsChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletItemStore2", "RemoveItemsChanged", 7usize, |this| ((**(this as *const *const IWalletItemStore2_Vtbl)).RemoveItemsChanged)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            ItemsChanged,
+            #[cfg(not(feature = "deprecated"))]
+            ItemsChanged: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveItemsChanged,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveItemsChanged: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletItemStore2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IWalletManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletManagerStatics", "RequestStoreAsync", 6usize, |this| ((**(this as *const *const IWalletManagerStatics_Vtbl)).RequestStoreAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            RequestStoreAsync,
+            #[cfg(not(feature = "deprecated"))]
+            RequestStoreAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Geolocation", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IWalletRelevantLocation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Devices_Geolocation", feature = "deprecated"))]
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Geolocation::BasicGeoposition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletRelevantLocation", "Position", 6usize, |this| ((**(this as *const *const IWalletRelevantLocation_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Geolocation", feature = "deprecated"))]
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, value: super::super::Devices::Geolocation::BasicGeoposition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletRelevantLocation", "SetPosition", 7usize, |this| ((**(this as *const *const IWalletRelevantLocation_Vtbl)).SetPosition)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DisplayMessage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletRelevantLocation", "DisplayMessage", 8usize, |this| ((**(this as *const *const IWalletRelevantLocation_Vtbl)).DisplayMessage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetDisplayMessage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletRelevantLocation", "SetDisplayMessage", 9usize, |this| ((**(this as *const *const IWalletRelevantLocation_Vtbl)).SetDisplayMessage)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Devices_Geolocation", feature = "deprecated"))]
+            Position,
+            #[cfg(not(all(feature = "Devices_Geolocation", feature = "deprecated")))]
+            Position: 0,
+            #[cfg(all(feature = "Devices_Geolocation", feature = "deprecated"))]
+            SetPosition,
+            #[cfg(not(all(feature = "Devices_Geolocation", feature = "deprecated")))]
+            SetPosition: 0,
+            #[cfg(feature = "deprecated")]
+            DisplayMessage,
+            #[cfg(not(feature = "deprecated"))]
+            DisplayMessage: 0,
+            #[cfg(feature = "deprecated")]
+            SetDisplayMessage,
+            #[cfg(not(feature = "deprecated"))]
+            SetDisplayMessage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletRelevantLocation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IWalletTransaction_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletTransaction", "Description", 6usize, |this| ((**(this as *const *const IWalletTransaction_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletTransaction", "SetDescription", 7usize, |this| ((**(this as *const *const IWalletTransaction_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DisplayAmount(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletTransaction", "DisplayAmount", 8usize, |this| ((**(this as *const *const IWalletTransaction_Vtbl)).DisplayAmount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetDisplayAmount(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletTransaction", "SetDisplayAmount", 9usize, |this| ((**(this as *const *const IWalletTransaction_Vtbl)).SetDisplayAmount)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IgnoreTimeOfDay(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletTransaction", "IgnoreTimeOfDay", 10usize, |this| ((**(this as *const *const IWalletTransaction_Vtbl)).IgnoreTimeOfDay)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetIgnoreTimeOfDay(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletTransaction", "SetIgnoreTimeOfDay", 11usize, |this| ((**(this as *const *const IWalletTransaction_Vtbl)).SetIgnoreTimeOfDay)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DisplayLocation(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletTransaction", "DisplayLocation", 12usize, |this| ((**(this as *const *const IWalletTransaction_Vtbl)).DisplayLocation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetDisplayLocation(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletTransaction", "SetDisplayLocation", 13usize, |this| ((**(this as *const *const IWalletTransaction_Vtbl)).SetDisplayLocation)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TransactionDate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletTransaction", "TransactionDate", 14usize, |this| ((**(this as *const *const IWalletTransaction_Vtbl)).TransactionDate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetTransactionDate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletTransaction", "SetTransactionDate", 15usize, |this| ((**(this as *const *const IWalletTransaction_Vtbl)).SetTransactionDate)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsLaunchable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletTransaction", "IsLaunchable", 16usize, |this| ((**(this as *const *const IWalletTransaction_Vtbl)).IsLaunchable)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetIsLaunchable(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletTransaction", "SetIsLaunchable", 17usize, |this| ((**(this as *const *const IWalletTransaction_Vtbl)).SetIsLaunchable)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Description,
+            #[cfg(not(feature = "deprecated"))]
+            Description: 0,
+            #[cfg(feature = "deprecated")]
+            SetDescription,
+            #[cfg(not(feature = "deprecated"))]
+            SetDescription: 0,
+            #[cfg(feature = "deprecated")]
+            DisplayAmount,
+            #[cfg(not(feature = "deprecated"))]
+            DisplayAmount: 0,
+            #[cfg(feature = "deprecated")]
+            SetDisplayAmount,
+            #[cfg(not(feature = "deprecated"))]
+            SetDisplayAmount: 0,
+            #[cfg(feature = "deprecated")]
+            IgnoreTimeOfDay,
+            #[cfg(not(feature = "deprecated"))]
+            IgnoreTimeOfDay: 0,
+            #[cfg(feature = "deprecated")]
+            SetIgnoreTimeOfDay,
+            #[cfg(not(feature = "deprecated"))]
+            SetIgnoreTimeOfDay: 0,
+            #[cfg(feature = "deprecated")]
+            DisplayLocation,
+            #[cfg(not(feature = "deprecated"))]
+            DisplayLocation: 0,
+            #[cfg(feature = "deprecated")]
+            SetDisplayLocation,
+            #[cfg(not(feature = "deprecated"))]
+            SetDisplayLocation: 0,
+            #[cfg(feature = "deprecated")]
+            TransactionDate,
+            #[cfg(not(feature = "deprecated"))]
+            TransactionDate: 0,
+            #[cfg(feature = "deprecated")]
+            SetTransactionDate,
+            #[cfg(not(feature = "deprecated"))]
+            SetTransactionDate: 0,
+            #[cfg(feature = "deprecated")]
+            IsLaunchable,
+            #[cfg(not(feature = "deprecated"))]
+            IsLaunchable: 0,
+            #[cfg(feature = "deprecated")]
+            SetIsLaunchable,
+            #[cfg(not(feature = "deprecated"))]
+            SetIsLaunchable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletTransaction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IWalletVerb_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletVerb", "Name", 6usize, |this| ((**(this as *const *const IWalletVerb_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletVerb", "SetName", 7usize, |this| ((**(this as *const *const IWalletVerb_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Name,
+            #[cfg(not(feature = "deprecated"))]
+            Name: 0,
+            #[cfg(feature = "deprecated")]
+            SetName,
+            #[cfg(not(feature = "deprecated"))]
+            SetName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletVerb as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IWalletVerbFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreateWalletVerb(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWalletVerbFactory", "CreateWalletVerb", 6usize, |this| ((**(this as *const *const IWalletVerbFactory_Vtbl)).CreateWalletVerb)(this, name, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CreateWalletVerb,
+            #[cfg(not(feature = "deprecated"))]
+            CreateWalletVerb: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWalletVerbFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
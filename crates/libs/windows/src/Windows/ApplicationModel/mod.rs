@@ -2993,6 +2993,7 @@ impl AddResourcePackageOptions {
 impl windows_core::TypeKind for AddResourcePackageOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AddResourcePackageOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AddResourcePackageOptions").field(&self.0).finish()
@@ -3045,6 +3046,7 @@ impl AppExecutionContext {
 impl windows_core::TypeKind for AppExecutionContext {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppExecutionContext {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppExecutionContext").field(&self.0).finish()
@@ -3063,6 +3065,7 @@ impl AppInstallerPolicySource {
 impl windows_core::TypeKind for AppInstallerPolicySource {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppInstallerPolicySource {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppInstallerPolicySource").field(&self.0).finish()
@@ -3083,6 +3086,7 @@ impl FullTrustLaunchResult {
 impl windows_core::TypeKind for FullTrustLaunchResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FullTrustLaunchResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FullTrustLaunchResult").field(&self.0).finish()
@@ -3103,6 +3107,7 @@ impl LimitedAccessFeatureStatus {
 impl windows_core::TypeKind for LimitedAccessFeatureStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LimitedAccessFeatureStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LimitedAccessFeatureStatus").field(&self.0).finish()
@@ -3123,6 +3128,7 @@ impl PackageContentGroupState {
 impl windows_core::TypeKind for PackageContentGroupState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PackageContentGroupState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PackageContentGroupState").field(&self.0).finish()
@@ -3142,6 +3148,7 @@ impl PackageRelationship {
 impl windows_core::TypeKind for PackageRelationship {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PackageRelationship {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PackageRelationship").field(&self.0).finish()
@@ -3163,6 +3170,7 @@ impl PackageSignatureKind {
 impl windows_core::TypeKind for PackageSignatureKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PackageSignatureKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PackageSignatureKind").field(&self.0).finish()
@@ -3184,6 +3192,7 @@ impl PackageUpdateAvailability {
 impl windows_core::TypeKind for PackageUpdateAvailability {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PackageUpdateAvailability {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PackageUpdateAvailability").field(&self.0).finish()
@@ -3205,6 +3214,7 @@ impl StartupTaskState {
 impl windows_core::TypeKind for StartupTaskState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StartupTaskState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StartupTaskState").field(&self.0).finish()
@@ -3214,7 +3224,8 @@ impl windows_core::RuntimeType for StartupTaskState {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.ApplicationModel.StartupTaskState;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PackageInstallProgress {
     pub PercentComplete: u32,
 }
@@ -3230,7 +3241,8 @@ impl Default for PackageInstallProgress {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PackageVersion {
     pub Major: u16,
     pub Minor: u16,
@@ -3250,3 +3262,5 @@ impl Default for PackageVersion {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
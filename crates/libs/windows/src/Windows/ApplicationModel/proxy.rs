@@ -0,0 +1,1486 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IAppDisplayInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDisplayInfo", "DisplayName", 6usize, |this| ((**(this as *const *const IAppDisplayInfo_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDisplayInfo", "Description", 7usize, |this| ((**(this as *const *const IAppDisplayInfo_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetLogo(this: *mut core::ffi::c_void, size: super::Foundation::Size, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDisplayInfo", "GetLogo", 8usize, |this| ((**(this as *const *const IAppDisplayInfo_Vtbl)).GetLogo)(this, size, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayName,
+            Description,
+            #[cfg(feature = "Storage_Streams")]
+            GetLogo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetLogo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppDisplayInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInfo", "Id", 6usize, |this| ((**(this as *const *const IAppInfo_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppUserModelId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInfo", "AppUserModelId", 7usize, |this| ((**(this as *const *const IAppInfo_Vtbl)).AppUserModelId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInfo", "DisplayInfo", 8usize, |this| ((**(this as *const *const IAppInfo_Vtbl)).DisplayInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInfo", "PackageFamilyName", 9usize, |this| ((**(this as *const *const IAppInfo_Vtbl)).PackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, AppUserModelId, DisplayInfo, PackageFamilyName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInfo2", "Package", 6usize, |this| ((**(this as *const *const IAppInfo2_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Package }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppInfo3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExecutionContext(this: *mut core::ffi::c_void, result__: *mut AppExecutionContext) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInfo3", "ExecutionContext", 6usize, |this| ((**(this as *const *const IAppInfo3_Vtbl)).ExecutionContext)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExecutionContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppInfo3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppInfo4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SupportedFileExtensions(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInfo4", "SupportedFileExtensions", 6usize, |this| ((**(this as *const *const IAppInfo4_Vtbl)).SupportedFileExtensions)(this, result_size__, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SupportedFileExtensions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppInfo4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IAppInfoStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInfoStatics", "Current", 6usize, |this| ((**(this as *const *const IAppInfoStatics_Vtbl)).Current)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFromAppUserModelId(this: *mut core::ffi::c_void, appusermodelid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInfoStatics", "GetFromAppUserModelId", 7usize, |this| ((**(this as *const *const IAppInfoStatics_Vtbl)).GetFromAppUserModelId)(this, appusermodelid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetFromAppUserModelIdForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, appusermodelid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInfoStatics", "GetFromAppUserModelIdForUser", 8usize, |this| ((**(this as *const *const IAppInfoStatics_Vtbl)).GetFromAppUserModelIdForUser)(this, user, appusermodelid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Current,
+            GetFromAppUserModelId,
+            #[cfg(feature = "System")]
+            GetFromAppUserModelIdForUser,
+            #[cfg(not(feature = "System"))]
+            GetFromAppUserModelIdForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppInfoStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppInstallerInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo", "Uri", 6usize, |this| ((**(this as *const *const IAppInstallerInfo_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppInstallerInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppInstallerInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnLaunch(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo2", "OnLaunch", 6usize, |this| ((**(this as *const *const IAppInstallerInfo2_Vtbl)).OnLaunch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoursBetweenUpdateChecks(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo2", "HoursBetweenUpdateChecks", 7usize, |this| ((**(this as *const *const IAppInstallerInfo2_Vtbl)).HoursBetweenUpdateChecks)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowPrompt(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo2", "ShowPrompt", 8usize, |this| ((**(this as *const *const IAppInstallerInfo2_Vtbl)).ShowPrompt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateBlocksActivation(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo2", "UpdateBlocksActivation", 9usize, |this| ((**(this as *const *const IAppInstallerInfo2_Vtbl)).UpdateBlocksActivation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutomaticBackgroundTask(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo2", "AutomaticBackgroundTask", 10usize, |this| ((**(this as *const *const IAppInstallerInfo2_Vtbl)).AutomaticBackgroundTask)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceUpdateFromAnyVersion(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo2", "ForceUpdateFromAnyVersion", 11usize, |this| ((**(this as *const *const IAppInstallerInfo2_Vtbl)).ForceUpdateFromAnyVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAutoRepairEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo2", "IsAutoRepairEnabled", 12usize, |this| ((**(this as *const *const IAppInstallerInfo2_Vtbl)).IsAutoRepairEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, result__: *mut PackageVersion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo2", "Version", 13usize, |this| ((**(this as *const *const IAppInstallerInfo2_Vtbl)).Version)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastChecked(this: *mut core::ffi::c_void, result__: *mut super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo2", "LastChecked", 14usize, |this| ((**(this as *const *const IAppInstallerInfo2_Vtbl)).LastChecked)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PausedUntil(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo2", "PausedUntil", 15usize, |this| ((**(this as *const *const IAppInstallerInfo2_Vtbl)).PausedUntil)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UpdateUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo2", "UpdateUris", 16usize, |this| ((**(this as *const *const IAppInstallerInfo2_Vtbl)).UpdateUris)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RepairUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo2", "RepairUris", 17usize, |this| ((**(this as *const *const IAppInstallerInfo2_Vtbl)).RepairUris)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DependencyPackageUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo2", "DependencyPackageUris", 18usize, |this| ((**(this as *const *const IAppInstallerInfo2_Vtbl)).DependencyPackageUris)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn OptionalPackageUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo2", "OptionalPackageUris", 19usize, |this| ((**(this as *const *const IAppInstallerInfo2_Vtbl)).OptionalPackageUris)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicySource(this: *mut core::ffi::c_void, result__: *mut AppInstallerPolicySource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerInfo2", "PolicySource", 20usize, |this| ((**(this as *const *const IAppInstallerInfo2_Vtbl)).PolicySource)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnLaunch,
+            HoursBetweenUpdateChecks,
+            ShowPrompt,
+            UpdateBlocksActivation,
+            AutomaticBackgroundTask,
+            ForceUpdateFromAnyVersion,
+            IsAutoRepairEnabled,
+            Version,
+            LastChecked,
+            PausedUntil,
+            #[cfg(feature = "Foundation_Collections")]
+            UpdateUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UpdateUris: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RepairUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RepairUris: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            DependencyPackageUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DependencyPackageUris: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            OptionalPackageUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            OptionalPackageUris: 0,
+            PolicySource,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppInstallerInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppInstance_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Key(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstance", "Key", 6usize, |this| ((**(this as *const *const IAppInstance_Vtbl)).Key)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCurrentInstance(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstance", "IsCurrentInstance", 7usize, |this| ((**(this as *const *const IAppInstance_Vtbl)).IsCurrentInstance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedirectActivationTo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstance", "RedirectActivationTo", 8usize, |this| ((**(this as *const *const IAppInstance_Vtbl)).RedirectActivationTo)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Key, IsCurrentInstance, RedirectActivationTo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppInstance as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Activation", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IAppInstanceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RecommendedInstance(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstanceStatics", "RecommendedInstance", 6usize, |this| ((**(this as *const *const IAppInstanceStatics_Vtbl)).RecommendedInstance)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Activation")]
+        unsafe extern "system" fn GetActivatedEventArgs(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstanceStatics", "GetActivatedEventArgs", 7usize, |this| ((**(this as *const *const IAppInstanceStatics_Vtbl)).GetActivatedEventArgs)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindOrRegisterInstanceForKey(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstanceStatics", "FindOrRegisterInstanceForKey", 8usize, |this| ((**(this as *const *const IAppInstanceStatics_Vtbl)).FindOrRegisterInstanceForKey)(this, key, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unregister(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstanceStatics", "Unregister", 9usize, |this| ((**(this as *const *const IAppInstanceStatics_Vtbl)).Unregister)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetInstances(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstanceStatics", "GetInstances", 10usize, |this| ((**(this as *const *const IAppInstanceStatics_Vtbl)).GetInstances)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RecommendedInstance,
+            #[cfg(feature = "ApplicationModel_Activation")]
+            GetActivatedEventArgs,
+            #[cfg(not(feature = "ApplicationModel_Activation"))]
+            GetActivatedEventArgs: 0,
+            FindOrRegisterInstanceForKey,
+            Unregister,
+            #[cfg(feature = "Foundation_Collections")]
+            GetInstances,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetInstances: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppInstanceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICameraApplicationManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowInstalledApplicationsUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraApplicationManagerStatics", "ShowInstalledApplicationsUI", 6usize, |this| ((**(this as *const *const ICameraApplicationManagerStatics_Vtbl)).ShowInstalledApplicationsUI)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowInstalledApplicationsUI }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraApplicationManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDesignModeStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DesignModeEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDesignModeStatics", "DesignModeEnabled", 6usize, |this| ((**(this as *const *const IDesignModeStatics_Vtbl)).DesignModeEnabled)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DesignModeEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDesignModeStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDesignModeStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DesignMode2Enabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDesignModeStatics2", "DesignMode2Enabled", 6usize, |this| ((**(this as *const *const IDesignModeStatics2_Vtbl)).DesignMode2Enabled)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DesignMode2Enabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDesignModeStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnteredBackgroundEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnteredBackgroundEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const IEnteredBackgroundEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnteredBackgroundEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFindRelatedPackagesOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Relationship(this: *mut core::ffi::c_void, result__: *mut PackageRelationship) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindRelatedPackagesOptions", "Relationship", 6usize, |this| ((**(this as *const *const IFindRelatedPackagesOptions_Vtbl)).Relationship)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRelationship(this: *mut core::ffi::c_void, value: PackageRelationship) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindRelatedPackagesOptions", "SetRelationship", 7usize, |this| ((**(this as *const *const IFindRelatedPackagesOptions_Vtbl)).SetRelationship)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeFrameworks(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindRelatedPackagesOptions", "IncludeFrameworks", 8usize, |this| ((**(this as *const *const IFindRelatedPackagesOptions_Vtbl)).IncludeFrameworks)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeFrameworks(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindRelatedPackagesOptions", "SetIncludeFrameworks", 9usize, |this| ((**(this as *const *const IFindRelatedPackagesOptions_Vtbl)).SetIncludeFrameworks)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeHostRuntimes(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindRelatedPackagesOptions", "IncludeHostRuntimes", 10usize, |this| ((**(this as *const *const IFindRelatedPackagesOptions_Vtbl)).IncludeHostRuntimes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeHostRuntimes(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindRelatedPackagesOptions", "SetIncludeHostRuntimes", 11usize, |this| ((**(this as *const *const IFindRelatedPackagesOptions_Vtbl)).SetIncludeHostRuntimes)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeOptionals(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindRelatedPackagesOptions", "IncludeOptionals", 12usize, |this| ((**(this as *const *const IFindRelatedPackagesOptions_Vtbl)).IncludeOptionals)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeOptionals(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindRelatedPackagesOptions", "SetIncludeOptionals", 13usize, |this| ((**(this as *const *const IFindRelatedPackagesOptions_Vtbl)).SetIncludeOptionals)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeResources(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindRelatedPackagesOptions", "IncludeResources", 14usize, |this| ((**(this as *const *const IFindRelatedPackagesOptions_Vtbl)).IncludeResources)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeResources(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindRelatedPackagesOptions", "SetIncludeResources", 15usize, |this| ((**(this as *const *const IFindRelatedPackagesOptions_Vtbl)).SetIncludeResources)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Relationship,
+            SetRelationship,
+            IncludeFrameworks,
+            SetIncludeFrameworks,
+            IncludeHostRuntimes,
+            SetIncludeHostRuntimes,
+            IncludeOptionals,
+            SetIncludeOptionals,
+            IncludeResources,
+            SetIncludeResources,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFindRelatedPackagesOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFindRelatedPackagesOptionsFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, relationship: PackageRelationship, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindRelatedPackagesOptionsFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IFindRelatedPackagesOptionsFactory_Vtbl)).CreateInstance)(this, relationship, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFindRelatedPackagesOptionsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFullTrustProcessLaunchResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LaunchResult(this: *mut core::ffi::c_void, result__: *mut FullTrustLaunchResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullTrustProcessLaunchResult", "LaunchResult", 6usize, |this| ((**(this as *const *const IFullTrustProcessLaunchResult_Vtbl)).LaunchResult)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullTrustProcessLaunchResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IFullTrustProcessLaunchResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LaunchResult, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFullTrustProcessLaunchResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFullTrustProcessLauncherStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LaunchFullTrustProcessForCurrentAppAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullTrustProcessLauncherStatics", "LaunchFullTrustProcessForCurrentAppAsync", 6usize, |this| ((**(this as *const *const IFullTrustProcessLauncherStatics_Vtbl)).LaunchFullTrustProcessForCurrentAppAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchFullTrustProcessForCurrentAppWithParametersAsync(this: *mut core::ffi::c_void, parametergroupid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullTrustProcessLauncherStatics", "LaunchFullTrustProcessForCurrentAppWithParametersAsync", 7usize, |this| ((**(this as *const *const IFullTrustProcessLauncherStatics_Vtbl)).LaunchFullTrustProcessForCurrentAppWithParametersAsync)(this, parametergroupid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchFullTrustProcessForAppAsync(this: *mut core::ffi::c_void, fulltrustpackagerelativeappid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullTrustProcessLauncherStatics", "LaunchFullTrustProcessForAppAsync", 8usize, |this| ((**(this as *const *const IFullTrustProcessLauncherStatics_Vtbl)).LaunchFullTrustProcessForAppAsync)(this, fulltrustpackagerelativeappid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchFullTrustProcessForAppWithParametersAsync(this: *mut core::ffi::c_void, fulltrustpackagerelativeappid: core::mem::MaybeUninit<windows_core::HSTRING>, parametergroupid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullTrustProcessLauncherStatics", "LaunchFullTrustProcessForAppWithParametersAsync", 9usize, |this| ((**(this as *const *const IFullTrustProcessLauncherStatics_Vtbl)).LaunchFullTrustProcessForAppWithParametersAsync)(this, fulltrustpackagerelativeappid, parametergroupid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LaunchFullTrustProcessForCurrentAppAsync,
+            LaunchFullTrustProcessForCurrentAppWithParametersAsync,
+            LaunchFullTrustProcessForAppAsync,
+            LaunchFullTrustProcessForAppWithParametersAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFullTrustProcessLauncherStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFullTrustProcessLauncherStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LaunchFullTrustProcessForCurrentAppWithArgumentsAsync(this: *mut core::ffi::c_void, commandline: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullTrustProcessLauncherStatics2", "LaunchFullTrustProcessForCurrentAppWithArgumentsAsync", 6usize, |this| ((**(this as *const *const IFullTrustProcessLauncherStatics2_Vtbl)).LaunchFullTrustProcessForCurrentAppWithArgumentsAsync)(this, commandline, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchFullTrustProcessForAppWithArgumentsAsync(this: *mut core::ffi::c_void, fulltrustpackagerelativeappid: core::mem::MaybeUninit<windows_core::HSTRING>, commandline: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullTrustProcessLauncherStatics2", "LaunchFullTrustProcessForAppWithArgumentsAsync", 7usize, |this| ((**(this as *const *const IFullTrustProcessLauncherStatics2_Vtbl)).LaunchFullTrustProcessForAppWithArgumentsAsync)(this, fulltrustpackagerelativeappid, commandline, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LaunchFullTrustProcessForCurrentAppWithArgumentsAsync,
+            LaunchFullTrustProcessForAppWithArgumentsAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFullTrustProcessLauncherStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILeavingBackgroundEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILeavingBackgroundEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const ILeavingBackgroundEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILeavingBackgroundEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILimitedAccessFeatureRequestResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FeatureId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILimitedAccessFeatureRequestResult", "FeatureId", 6usize, |this| ((**(this as *const *const ILimitedAccessFeatureRequestResult_Vtbl)).FeatureId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut LimitedAccessFeatureStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILimitedAccessFeatureRequestResult", "Status", 7usize, |this| ((**(this as *const *const ILimitedAccessFeatureRequestResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EstimatedRemovalDate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILimitedAccessFeatureRequestResult", "EstimatedRemovalDate", 8usize, |this| ((**(this as *const *const ILimitedAccessFeatureRequestResult_Vtbl)).EstimatedRemovalDate)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FeatureId, Status, EstimatedRemovalDate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILimitedAccessFeatureRequestResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILimitedAccessFeaturesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryUnlockFeature(this: *mut core::ffi::c_void, featureid: core::mem::MaybeUninit<windows_core::HSTRING>, token: core::mem::MaybeUninit<windows_core::HSTRING>, attestation: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILimitedAccessFeaturesStatics", "TryUnlockFeature", 6usize, |this| ((**(this as *const *const ILimitedAccessFeaturesStatics_Vtbl)).TryUnlockFeature)(this, featureid, token, attestation, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryUnlockFeature }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILimitedAccessFeaturesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IPackage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage", "Id", 6usize, |this| ((**(this as *const *const IPackage_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn InstalledLocation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage", "InstalledLocation", 7usize, |this| ((**(this as *const *const IPackage_Vtbl)).InstalledLocation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFramework(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage", "IsFramework", 8usize, |this| ((**(this as *const *const IPackage_Vtbl)).IsFramework)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Dependencies(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage", "Dependencies", 9usize, |this| ((**(this as *const *const IPackage_Vtbl)).Dependencies)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            #[cfg(feature = "Storage")]
+            InstalledLocation,
+            #[cfg(not(feature = "Storage"))]
+            InstalledLocation: 0,
+            IsFramework,
+            #[cfg(feature = "Foundation_Collections")]
+            Dependencies,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Dependencies: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage2", "DisplayName", 6usize, |this| ((**(this as *const *const IPackage2_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublisherDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage2", "PublisherDisplayName", 7usize, |this| ((**(this as *const *const IPackage2_Vtbl)).PublisherDisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage2", "Description", 8usize, |this| ((**(this as *const *const IPackage2_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Logo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage2", "Logo", 9usize, |this| ((**(this as *const *const IPackage2_Vtbl)).Logo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsResourcePackage(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage2", "IsResourcePackage", 10usize, |this| ((**(this as *const *const IPackage2_Vtbl)).IsResourcePackage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBundle(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage2", "IsBundle", 11usize, |this| ((**(this as *const *const IPackage2_Vtbl)).IsBundle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDevelopmentMode(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage2", "IsDevelopmentMode", 12usize, |this| ((**(this as *const *const IPackage2_Vtbl)).IsDevelopmentMode)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayName,
+            PublisherDisplayName,
+            Description,
+            Logo,
+            IsResourcePackage,
+            IsBundle,
+            IsDevelopmentMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackage2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Core", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IPackage3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage3", "Status", 6usize, |this| ((**(this as *const *const IPackage3_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstalledDate(this: *mut core::ffi::c_void, result__: *mut super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage3", "InstalledDate", 7usize, |this| ((**(this as *const *const IPackage3_Vtbl)).InstalledDate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel_Core", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetAppListEntriesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage3", "GetAppListEntriesAsync", 8usize, |this| ((**(this as *const *const IPackage3_Vtbl)).GetAppListEntriesAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            InstalledDate,
+            #[cfg(all(feature = "ApplicationModel_Core", feature = "Foundation_Collections"))]
+            GetAppListEntriesAsync,
+            #[cfg(not(all(feature = "ApplicationModel_Core", feature = "Foundation_Collections")))]
+            GetAppListEntriesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackage3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackage4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SignatureKind(this: *mut core::ffi::c_void, result__: *mut PackageSignatureKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage4", "SignatureKind", 6usize, |this| ((**(this as *const *const IPackage4_Vtbl)).SignatureKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOptional(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage4", "IsOptional", 7usize, |this| ((**(this as *const *const IPackage4_Vtbl)).IsOptional)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerifyContentIntegrityAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage4", "VerifyContentIntegrityAsync", 8usize, |this| ((**(this as *const *const IPackage4_Vtbl)).VerifyContentIntegrityAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SignatureKind, IsOptional, VerifyContentIntegrityAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackage4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPackage5_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetContentGroupsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage5", "GetContentGroupsAsync", 6usize, |this| ((**(this as *const *const IPackage5_Vtbl)).GetContentGroupsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentGroupAsync(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage5", "GetContentGroupAsync", 7usize, |this| ((**(this as *const *const IPackage5_Vtbl)).GetContentGroupAsync)(this, name, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StageContentGroupsAsync(this: *mut core::ffi::c_void, names: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage5", "StageContentGroupsAsync", 8usize, |this| ((**(this as *const *const IPackage5_Vtbl)).StageContentGroupsAsync)(this, names, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StageContentGroupsWithPriorityAsync(this: *mut core::ffi::c_void, names: *mut core::ffi::c_void, movetoheadofqueue: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage5", "StageContentGroupsWithPriorityAsync", 9usize, |this| ((**(this as *const *const IPackage5_Vtbl)).StageContentGroupsWithPriorityAsync)(this, names, movetoheadofqueue, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInUseAsync(this: *mut core::ffi::c_void, inuse: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage5", "SetInUseAsync", 10usize, |this| ((**(this as *const *const IPackage5_Vtbl)).SetInUseAsync)(this, inuse, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetContentGroupsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetContentGroupsAsync: 0,
+            GetContentGroupAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            StageContentGroupsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StageContentGroupsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            StageContentGroupsWithPriorityAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StageContentGroupsWithPriorityAsync: 0,
+            SetInUseAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackage5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackage6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAppInstallerInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage6", "GetAppInstallerInfo", 6usize, |this| ((**(this as *const *const IPackage6_Vtbl)).GetAppInstallerInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckUpdateAvailabilityAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage6", "CheckUpdateAvailabilityAsync", 7usize, |this| ((**(this as *const *const IPackage6_Vtbl)).CheckUpdateAvailabilityAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAppInstallerInfo, CheckUpdateAvailabilityAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackage6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IPackage7_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn MutableLocation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage7", "MutableLocation", 6usize, |this| ((**(this as *const *const IPackage7_Vtbl)).MutableLocation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn EffectiveLocation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage7", "EffectiveLocation", 7usize, |this| ((**(this as *const *const IPackage7_Vtbl)).EffectiveLocation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            MutableLocation,
+            #[cfg(not(feature = "Storage"))]
+            MutableLocation: 0,
+            #[cfg(feature = "Storage")]
+            EffectiveLocation,
+            #[cfg(not(feature = "Storage"))]
+            EffectiveLocation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackage7 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Core", feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IPackage8_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn EffectiveExternalLocation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage8", "EffectiveExternalLocation", 6usize, |this| ((**(this as *const *const IPackage8_Vtbl)).EffectiveExternalLocation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn MachineExternalLocation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage8", "MachineExternalLocation", 7usize, |this| ((**(this as *const *const IPackage8_Vtbl)).MachineExternalLocation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn UserExternalLocation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage8", "UserExternalLocation", 8usize, |this| ((**(this as *const *const IPackage8_Vtbl)).UserExternalLocation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstalledPath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage8", "InstalledPath", 9usize, |this| ((**(this as *const *const IPackage8_Vtbl)).InstalledPath)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MutablePath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage8", "MutablePath", 10usize, |this| ((**(this as *const *const IPackage8_Vtbl)).MutablePath)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EffectivePath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage8", "EffectivePath", 11usize, |this| ((**(this as *const *const IPackage8_Vtbl)).EffectivePath)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EffectiveExternalPath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage8", "EffectiveExternalPath", 12usize, |this| ((**(this as *const *const IPackage8_Vtbl)).EffectiveExternalPath)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MachineExternalPath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage8", "MachineExternalPath", 13usize, |this| ((**(this as *const *const IPackage8_Vtbl)).MachineExternalPath)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserExternalPath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage8", "UserExternalPath", 14usize, |this| ((**(this as *const *const IPackage8_Vtbl)).UserExternalPath)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetLogoAsRandomAccessStreamReference(this: *mut core::ffi::c_void, size: super::Foundation::Size, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage8", "GetLogoAsRandomAccessStreamReference", 15usize, |this| ((**(this as *const *const IPackage8_Vtbl)).GetLogoAsRandomAccessStreamReference)(this, size, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel_Core", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetAppListEntries(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage8", "GetAppListEntries", 16usize, |this| ((**(this as *const *const IPackage8_Vtbl)).GetAppListEntries)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStub(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage8", "IsStub", 17usize, |this| ((**(this as *const *const IPackage8_Vtbl)).IsStub)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            EffectiveExternalLocation,
+            #[cfg(not(feature = "Storage"))]
+            EffectiveExternalLocation: 0,
+            #[cfg(feature = "Storage")]
+            MachineExternalLocation,
+            #[cfg(not(feature = "Storage"))]
+            MachineExternalLocation: 0,
+            #[cfg(feature = "Storage")]
+            UserExternalLocation,
+            #[cfg(not(feature = "Storage"))]
+            UserExternalLocation: 0,
+            InstalledPath,
+            MutablePath,
+            EffectivePath,
+            EffectiveExternalPath,
+            MachineExternalPath,
+            UserExternalPath,
+            #[cfg(feature = "Storage_Streams")]
+            GetLogoAsRandomAccessStreamReference,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetLogoAsRandomAccessStreamReference: 0,
+            #[cfg(all(feature = "ApplicationModel_Core", feature = "Foundation_Collections"))]
+            GetAppListEntries,
+            #[cfg(not(all(feature = "ApplicationModel_Core", feature = "Foundation_Collections")))]
+            GetAppListEntries: 0,
+            IsStub,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackage8 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPackage9_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindRelatedPackages(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage9", "FindRelatedPackages", 6usize, |this| ((**(this as *const *const IPackage9_Vtbl)).FindRelatedPackages)(this, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceUriSchemeName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackage9", "SourceUriSchemeName", 7usize, |this| ((**(this as *const *const IPackage9_Vtbl)).SourceUriSchemeName)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindRelatedPackages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindRelatedPackages: 0,
+            SourceUriSchemeName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackage9 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageCatalog_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PackageStaging(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog", "PackageStaging", 6usize, |this| ((**(this as *const *const IPackageCatalog_Vtbl)).PackageStaging)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageStaging(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog", "RemovePackageStaging", 7usize, |this| ((**(this as *const *const IPackageCatalog_Vtbl)).RemovePackageStaging)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageInstalling(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog", "PackageInstalling", 8usize, |this| ((**(this as *const *const IPackageCatalog_Vtbl)).PackageInstalling)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageInstalling(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog", "RemovePackageInstalling", 9usize, |this| ((**(this as *const *const IPackageCatalog_Vtbl)).RemovePackageInstalling)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageUpdating(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog", "PackageUpdating", 10usize, |this| ((**(this as *const *const IPackageCatalog_Vtbl)).PackageUpdating)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageUpdating(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog", "RemovePackageUpdating", 11usize, |this| ((**(this as *const *const IPackageCatalog_Vtbl)).RemovePackageUpdating)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageUninstalling(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog", "PackageUninstalling", 12usize, |this| ((**(this as *const *const IPackageCatalog_Vtbl)).PackageUninstalling)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageUninstalling(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog", "RemovePackageUninstalling", 13usize, |this| ((**(this as *const *const IPackageCatalog_Vtbl)).RemovePackageUninstalling)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog", "PackageStatusChanged", 14usize, |this| ((**(this as *const *const IPackageCatalog_Vtbl)).PackageStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageStatusChanged(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog", "RemovePackageStatusChanged", 15usize, |this| ((**(this as *const *const IPackageCatalog_Vtbl)).RemovePackageStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PackageStaging,
+            RemovePackageStaging,
+            PackageInstalling,
+            RemovePackageInstalling,
+            PackageUpdating,
+            RemovePackageUpdating,
+            PackageUninstalling,
+            RemovePackageUninstalling,
+            PackageStatusChanged,
+            RemovePackageStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageCatalog as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageCatalog2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PackageContentGroupStaging(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog2", "PackageContentGroupStaging", 6usize, |this| ((**(this as *const *const IPackageCatalog2_Vtbl)).PackageContentGroupStaging)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageContentGroupStaging(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog2", "RemovePackageContentGroupStaging", 7usize, |this| ((**(this as *const *const IPackageCatalog2_Vtbl)).RemovePackageContentGroupStaging)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddOptionalPackageAsync(this: *mut core::ffi::c_void, optionalpackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog2", "AddOptionalPackageAsync", 8usize, |this| ((**(this as *const *const IPackageCatalog2_Vtbl)).AddOptionalPackageAsync)(this, optionalpackagefamilyname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PackageContentGroupStaging,
+            RemovePackageContentGroupStaging,
+            AddOptionalPackageAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageCatalog2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPackageCatalog3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RemoveOptionalPackagesAsync(this: *mut core::ffi::c_void, optionalpackagefamilynames: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog3", "RemoveOptionalPackagesAsync", 6usize, |this| ((**(this as *const *const IPackageCatalog3_Vtbl)).RemoveOptionalPackagesAsync)(this, optionalpackagefamilynames, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            RemoveOptionalPackagesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RemoveOptionalPackagesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageCatalog3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPackageCatalog4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddResourcePackageAsync(this: *mut core::ffi::c_void, resourcepackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, resourceid: core::mem::MaybeUninit<windows_core::HSTRING>, options: AddResourcePackageOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog4", "AddResourcePackageAsync", 6usize, |this| ((**(this as *const *const IPackageCatalog4_Vtbl)).AddResourcePackageAsync)(this, resourcepackagefamilyname, resourceid, options, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RemoveResourcePackagesAsync(this: *mut core::ffi::c_void, resourcepackages: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalog4", "RemoveResourcePackagesAsync", 7usize, |this| ((**(this as *const *const IPackageCatalog4_Vtbl)).RemoveResourcePackagesAsync)(this, resourcepackages, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddResourcePackageAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            RemoveResourcePackagesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RemoveResourcePackagesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageCatalog4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageCatalogAddOptionalPackageResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalogAddOptionalPackageResult", "Package", 6usize, |this| ((**(this as *const *const IPackageCatalogAddOptionalPackageResult_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalogAddOptionalPackageResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IPackageCatalogAddOptionalPackageResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Package, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageCatalogAddOptionalPackageResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageCatalogAddResourcePackageResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalogAddResourcePackageResult", "Package", 6usize, |this| ((**(this as *const *const IPackageCatalogAddResourcePackageResult_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsComplete(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalogAddResourcePackageResult", "IsComplete", 7usize, |this| ((**(this as *const *const IPackageCatalogAddResourcePackageResult_Vtbl)).IsComplete)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalogAddResourcePackageResult", "ExtendedError", 8usize, |this| ((**(this as *const *const IPackageCatalogAddResourcePackageResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Package, IsComplete, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageCatalogAddResourcePackageResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPackageCatalogRemoveOptionalPackagesResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PackagesRemoved(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalogRemoveOptionalPackagesResult", "PackagesRemoved", 6usize, |this| ((**(this as *const *const IPackageCatalogRemoveOptionalPackagesResult_Vtbl)).PackagesRemoved)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalogRemoveOptionalPackagesResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IPackageCatalogRemoveOptionalPackagesResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            PackagesRemoved,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PackagesRemoved: 0,
+            ExtendedError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageCatalogRemoveOptionalPackagesResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPackageCatalogRemoveResourcePackagesResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PackagesRemoved(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalogRemoveResourcePackagesResult", "PackagesRemoved", 6usize, |this| ((**(this as *const *const IPackageCatalogRemoveResourcePackagesResult_Vtbl)).PackagesRemoved)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalogRemoveResourcePackagesResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IPackageCatalogRemoveResourcePackagesResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            PackagesRemoved,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PackagesRemoved: 0,
+            ExtendedError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageCatalogRemoveResourcePackagesResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageCatalogStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenForCurrentPackage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalogStatics", "OpenForCurrentPackage", 6usize, |this| ((**(this as *const *const IPackageCatalogStatics_Vtbl)).OpenForCurrentPackage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenForCurrentUser(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalogStatics", "OpenForCurrentUser", 7usize, |this| ((**(this as *const *const IPackageCatalogStatics_Vtbl)).OpenForCurrentUser)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenForCurrentPackage, OpenForCurrentUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageCatalogStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageCatalogStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenForPackage(this: *mut core::ffi::c_void, package: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageCatalogStatics2", "OpenForPackage", 6usize, |this| ((**(this as *const *const IPackageCatalogStatics2_Vtbl)).OpenForPackage)(this, package, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenForPackage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageCatalogStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageContentGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageContentGroup", "Package", 6usize, |this| ((**(this as *const *const IPackageContentGroup_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageContentGroup", "Name", 7usize, |this| ((**(this as *const *const IPackageContentGroup_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut PackageContentGroupState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageContentGroup", "State", 8usize, |this| ((**(this as *const *const IPackageContentGroup_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRequired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageContentGroup", "IsRequired", 9usize, |this| ((**(this as *const *const IPackageContentGroup_Vtbl)).IsRequired)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Package, Name, State, IsRequired }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageContentGroup as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageContentGroupStagingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivityId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageContentGroupStagingEventArgs", "ActivityId", 6usize, |this| ((**(this as *const *const IPackageContentGroupStagingEventArgs_Vtbl)).ActivityId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageContentGroupStagingEventArgs", "Package", 7usize, |this| ((**(this as *const *const IPackageContentGroupStagingEventArgs_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageContentGroupStagingEventArgs", "Progress", 8usize, |this| ((**(this as *const *const IPackageContentGroupStagingEventArgs_Vtbl)).Progress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsComplete(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageContentGroupStagingEventArgs", "IsComplete", 9usize, |this| ((**(this as *const *const IPackageContentGroupStagingEventArgs_Vtbl)).IsComplete)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageContentGroupStagingEventArgs", "ErrorCode", 10usize, |this| ((**(this as *const *const IPackageContentGroupStagingEventArgs_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentGroupName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageContentGroupStagingEventArgs", "ContentGroupName", 11usize, |this| ((**(this as *const *const IPackageContentGroupStagingEventArgs_Vtbl)).ContentGroupName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsContentGroupRequired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageContentGroupStagingEventArgs", "IsContentGroupRequired", 12usize, |this| ((**(this as *const *const IPackageContentGroupStagingEventArgs_Vtbl)).IsContentGroupRequired)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ActivityId,
+            Package,
+            Progress,
+            IsComplete,
+            ErrorCode,
+            ContentGroupName,
+            IsContentGroupRequired,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageContentGroupStagingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageContentGroupStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequiredGroupName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageContentGroupStatics", "RequiredGroupName", 6usize, |this| ((**(this as *const *const IPackageContentGroupStatics_Vtbl)).RequiredGroupName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequiredGroupName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageContentGroupStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IPackageId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageId", "Name", 6usize, |this| ((**(this as *const *const IPackageId_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, result__: *mut PackageVersion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageId", "Version", 7usize, |this| ((**(this as *const *const IPackageId_Vtbl)).Version)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn Architecture(this: *mut core::ffi::c_void, result__: *mut super::System::ProcessorArchitecture) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageId", "Architecture", 8usize, |this| ((**(this as *const *const IPackageId_Vtbl)).Architecture)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageId", "ResourceId", 9usize, |this| ((**(this as *const *const IPackageId_Vtbl)).ResourceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Publisher(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageId", "Publisher", 10usize, |this| ((**(this as *const *const IPackageId_Vtbl)).Publisher)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublisherId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageId", "PublisherId", 11usize, |this| ((**(this as *const *const IPackageId_Vtbl)).PublisherId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FullName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageId", "FullName", 12usize, |this| ((**(this as *const *const IPackageId_Vtbl)).FullName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageId", "FamilyName", 13usize, |this| ((**(this as *const *const IPackageId_Vtbl)).FamilyName)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Version,
+            #[cfg(feature = "System")]
+            Architecture,
+            #[cfg(not(feature = "System"))]
+            Architecture: 0,
+            ResourceId,
+            Publisher,
+            PublisherId,
+            FullName,
+            FamilyName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageIdWithMetadata_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProductId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageIdWithMetadata", "ProductId", 6usize, |this| ((**(this as *const *const IPackageIdWithMetadata_Vtbl)).ProductId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Author(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageIdWithMetadata", "Author", 7usize, |this| ((**(this as *const *const IPackageIdWithMetadata_Vtbl)).Author)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProductId, Author }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageIdWithMetadata as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageInstallingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivityId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageInstallingEventArgs", "ActivityId", 6usize, |this| ((**(this as *const *const IPackageInstallingEventArgs_Vtbl)).ActivityId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageInstallingEventArgs", "Package", 7usize, |this| ((**(this as *const *const IPackageInstallingEventArgs_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageInstallingEventArgs", "Progress", 8usize, |this| ((**(this as *const *const IPackageInstallingEventArgs_Vtbl)).Progress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsComplete(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageInstallingEventArgs", "IsComplete", 9usize, |this| ((**(this as *const *const IPackageInstallingEventArgs_Vtbl)).IsComplete)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageInstallingEventArgs", "ErrorCode", 10usize, |this| ((**(this as *const *const IPackageInstallingEventArgs_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ActivityId, Package, Progress, IsComplete, ErrorCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageInstallingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageStagingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivityId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStagingEventArgs", "ActivityId", 6usize, |this| ((**(this as *const *const IPackageStagingEventArgs_Vtbl)).ActivityId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStagingEventArgs", "Package", 7usize, |this| ((**(this as *const *const IPackageStagingEventArgs_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStagingEventArgs", "Progress", 8usize, |this| ((**(this as *const *const IPackageStagingEventArgs_Vtbl)).Progress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsComplete(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStagingEventArgs", "IsComplete", 9usize, |this| ((**(this as *const *const IPackageStagingEventArgs_Vtbl)).IsComplete)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStagingEventArgs", "ErrorCode", 10usize, |this| ((**(this as *const *const IPackageStagingEventArgs_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ActivityId, Package, Progress, IsComplete, ErrorCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageStagingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStatics", "Current", 6usize, |this| ((**(this as *const *const IPackageStatics_Vtbl)).Current)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Current }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VerifyIsOK(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStatus", "VerifyIsOK", 6usize, |this| ((**(this as *const *const IPackageStatus_Vtbl)).VerifyIsOK)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotAvailable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStatus", "NotAvailable", 7usize, |this| ((**(this as *const *const IPackageStatus_Vtbl)).NotAvailable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageOffline(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStatus", "PackageOffline", 8usize, |this| ((**(this as *const *const IPackageStatus_Vtbl)).PackageOffline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataOffline(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStatus", "DataOffline", 9usize, |this| ((**(this as *const *const IPackageStatus_Vtbl)).DataOffline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStatus", "Disabled", 10usize, |this| ((**(this as *const *const IPackageStatus_Vtbl)).Disabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NeedsRemediation(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStatus", "NeedsRemediation", 11usize, |this| ((**(this as *const *const IPackageStatus_Vtbl)).NeedsRemediation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LicenseIssue(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStatus", "LicenseIssue", 12usize, |this| ((**(this as *const *const IPackageStatus_Vtbl)).LicenseIssue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Modified(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStatus", "Modified", 13usize, |this| ((**(this as *const *const IPackageStatus_Vtbl)).Modified)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tampered(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStatus", "Tampered", 14usize, |this| ((**(this as *const *const IPackageStatus_Vtbl)).Tampered)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DependencyIssue(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStatus", "DependencyIssue", 15usize, |this| ((**(this as *const *const IPackageStatus_Vtbl)).DependencyIssue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Servicing(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStatus", "Servicing", 16usize, |this| ((**(this as *const *const IPackageStatus_Vtbl)).Servicing)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeploymentInProgress(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStatus", "DeploymentInProgress", 17usize, |this| ((**(this as *const *const IPackageStatus_Vtbl)).DeploymentInProgress)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            VerifyIsOK,
+            NotAvailable,
+            PackageOffline,
+            DataOffline,
+            Disabled,
+            NeedsRemediation,
+            LicenseIssue,
+            Modified,
+            Tampered,
+            DependencyIssue,
+            Servicing,
+            DeploymentInProgress,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageStatus as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageStatus2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPartiallyStaged(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStatus2", "IsPartiallyStaged", 6usize, |this| ((**(this as *const *const IPackageStatus2_Vtbl)).IsPartiallyStaged)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsPartiallyStaged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageStatus2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageStatusChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageStatusChangedEventArgs", "Package", 6usize, |this| ((**(this as *const *const IPackageStatusChangedEventArgs_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Package }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageStatusChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageUninstallingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivityId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUninstallingEventArgs", "ActivityId", 6usize, |this| ((**(this as *const *const IPackageUninstallingEventArgs_Vtbl)).ActivityId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUninstallingEventArgs", "Package", 7usize, |this| ((**(this as *const *const IPackageUninstallingEventArgs_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUninstallingEventArgs", "Progress", 8usize, |this| ((**(this as *const *const IPackageUninstallingEventArgs_Vtbl)).Progress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsComplete(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUninstallingEventArgs", "IsComplete", 9usize, |this| ((**(this as *const *const IPackageUninstallingEventArgs_Vtbl)).IsComplete)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUninstallingEventArgs", "ErrorCode", 10usize, |this| ((**(this as *const *const IPackageUninstallingEventArgs_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ActivityId, Package, Progress, IsComplete, ErrorCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageUninstallingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageUpdateAvailabilityResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Availability(this: *mut core::ffi::c_void, result__: *mut PackageUpdateAvailability) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUpdateAvailabilityResult", "Availability", 6usize, |this| ((**(this as *const *const IPackageUpdateAvailabilityResult_Vtbl)).Availability)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUpdateAvailabilityResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IPackageUpdateAvailabilityResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Availability, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageUpdateAvailabilityResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageUpdatingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivityId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUpdatingEventArgs", "ActivityId", 6usize, |this| ((**(this as *const *const IPackageUpdatingEventArgs_Vtbl)).ActivityId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourcePackage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUpdatingEventArgs", "SourcePackage", 7usize, |this| ((**(this as *const *const IPackageUpdatingEventArgs_Vtbl)).SourcePackage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetPackage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUpdatingEventArgs", "TargetPackage", 8usize, |this| ((**(this as *const *const IPackageUpdatingEventArgs_Vtbl)).TargetPackage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUpdatingEventArgs", "Progress", 9usize, |this| ((**(this as *const *const IPackageUpdatingEventArgs_Vtbl)).Progress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsComplete(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUpdatingEventArgs", "IsComplete", 10usize, |this| ((**(this as *const *const IPackageUpdatingEventArgs_Vtbl)).IsComplete)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUpdatingEventArgs", "ErrorCode", 11usize, |this| ((**(this as *const *const IPackageUpdatingEventArgs_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ActivityId,
+            SourcePackage,
+            TargetPackage,
+            Progress,
+            IsComplete,
+            ErrorCode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageUpdatingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageWithMetadata_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstallDate(this: *mut core::ffi::c_void, result__: *mut super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageWithMetadata", "InstallDate", 6usize, |this| ((**(this as *const *const IPackageWithMetadata_Vtbl)).InstallDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThumbnailToken(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageWithMetadata", "GetThumbnailToken", 7usize, |this| ((**(this as *const *const IPackageWithMetadata_Vtbl)).GetThumbnailToken)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Launch(this: *mut core::ffi::c_void, parameters: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageWithMetadata", "Launch", 8usize, |this| ((**(this as *const *const IPackageWithMetadata_Vtbl)).Launch)(this, parameters), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InstallDate,
+            GetThumbnailToken,
+            #[cfg(feature = "deprecated")]
+            Launch,
+            #[cfg(not(feature = "deprecated"))]
+            Launch: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageWithMetadata as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStartupTask_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestEnableAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStartupTask", "RequestEnableAsync", 6usize, |this| ((**(this as *const *const IStartupTask_Vtbl)).RequestEnableAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStartupTask", "Disable", 7usize, |this| ((**(this as *const *const IStartupTask_Vtbl)).Disable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut StartupTaskState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStartupTask", "State", 8usize, |this| ((**(this as *const *const IStartupTask_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStartupTask", "TaskId", 9usize, |this| ((**(this as *const *const IStartupTask_Vtbl)).TaskId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestEnableAsync, Disable, State, TaskId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStartupTask as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStartupTaskStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetForCurrentPackageAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStartupTaskStatics", "GetForCurrentPackageAsync", 6usize, |this| ((**(this as *const *const IStartupTaskStatics_Vtbl)).GetForCurrentPackageAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAsync(this: *mut core::ffi::c_void, taskid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStartupTaskStatics", "GetAsync", 7usize, |this| ((**(this as *const *const IStartupTaskStatics_Vtbl)).GetAsync)(this, taskid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetForCurrentPackageAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetForCurrentPackageAsync: 0,
+            GetAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStartupTaskStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISuspendingDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISuspendingDeferral", "Complete", 6usize, |this| ((**(this as *const *const ISuspendingDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISuspendingDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISuspendingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SuspendingOperation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISuspendingEventArgs", "SuspendingOperation", 6usize, |this| ((**(this as *const *const ISuspendingEventArgs_Vtbl)).SuspendingOperation)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SuspendingOperation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISuspendingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISuspendingOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISuspendingOperation", "GetDeferral", 6usize, |this| ((**(this as *const *const ISuspendingOperation_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, result__: *mut super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISuspendingOperation", "Deadline", 7usize, |this| ((**(this as *const *const ISuspendingOperation_Vtbl)).Deadline)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral, Deadline }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISuspendingOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -24,3 +24,5 @@ impl HtmlUtilities {
 impl windows_core::RuntimeName for HtmlUtilities {
     const NAME: &'static str = "Windows.Data.Html.HtmlUtilities";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
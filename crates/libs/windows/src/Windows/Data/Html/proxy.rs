@@ -0,0 +1,12 @@
+impl windows_core::imp::ProxyVtable for IHtmlUtilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConvertToText(this: *mut core::ffi::c_void, html: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlUtilities", "ConvertToText", 6usize, |this| ((**(this as *const *const IHtmlUtilities_Vtbl)).ConvertToText)(this, html, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConvertToText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHtmlUtilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
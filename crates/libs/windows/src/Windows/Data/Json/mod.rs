@@ -838,6 +838,7 @@ impl JsonErrorStatus {
 impl windows_core::TypeKind for JsonErrorStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for JsonErrorStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("JsonErrorStatus").field(&self.0).finish()
@@ -860,6 +861,7 @@ impl JsonValueType {
 impl windows_core::TypeKind for JsonValueType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for JsonValueType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("JsonValueType").field(&self.0).finish()
@@ -870,3 +872,5 @@ impl windows_core::RuntimeType for JsonValueType {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,229 @@
+impl windows_core::imp::ProxyVtable for IJsonArray_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObjectAt(this: *mut core::ffi::c_void, index: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonArray", "GetObjectAt", 6usize, |this| ((**(this as *const *const IJsonArray_Vtbl)).GetObjectAt)(this, index, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetArrayAt(this: *mut core::ffi::c_void, index: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonArray", "GetArrayAt", 7usize, |this| ((**(this as *const *const IJsonArray_Vtbl)).GetArrayAt)(this, index, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringAt(this: *mut core::ffi::c_void, index: u32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonArray", "GetStringAt", 8usize, |this| ((**(this as *const *const IJsonArray_Vtbl)).GetStringAt)(this, index, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberAt(this: *mut core::ffi::c_void, index: u32, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonArray", "GetNumberAt", 9usize, |this| ((**(this as *const *const IJsonArray_Vtbl)).GetNumberAt)(this, index, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBooleanAt(this: *mut core::ffi::c_void, index: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonArray", "GetBooleanAt", 10usize, |this| ((**(this as *const *const IJsonArray_Vtbl)).GetBooleanAt)(this, index, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetObjectAt,
+            GetArrayAt,
+            GetStringAt,
+            GetNumberAt,
+            GetBooleanAt,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsonArray as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsonArrayStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonArrayStatics", "Parse", 6usize, |this| ((**(this as *const *const IJsonArrayStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonArrayStatics", "TryParse", 7usize, |this| ((**(this as *const *const IJsonArrayStatics_Vtbl)).TryParse)(this, input, result, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsonArrayStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsonErrorStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetJsonStatus(this: *mut core::ffi::c_void, hresult: i32, result__: *mut JsonErrorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonErrorStatics2", "GetJsonStatus", 6usize, |this| ((**(this as *const *const IJsonErrorStatics2_Vtbl)).GetJsonStatus)(this, hresult, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetJsonStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsonErrorStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsonObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNamedValue(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonObject", "GetNamedValue", 6usize, |this| ((**(this as *const *const IJsonObject_Vtbl)).GetNamedValue)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNamedValue(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonObject", "SetNamedValue", 7usize, |this| ((**(this as *const *const IJsonObject_Vtbl)).SetNamedValue)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedObject(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonObject", "GetNamedObject", 8usize, |this| ((**(this as *const *const IJsonObject_Vtbl)).GetNamedObject)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedArray(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonObject", "GetNamedArray", 9usize, |this| ((**(this as *const *const IJsonObject_Vtbl)).GetNamedArray)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedString(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonObject", "GetNamedString", 10usize, |this| ((**(this as *const *const IJsonObject_Vtbl)).GetNamedString)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedNumber(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonObject", "GetNamedNumber", 11usize, |this| ((**(this as *const *const IJsonObject_Vtbl)).GetNamedNumber)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedBoolean(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonObject", "GetNamedBoolean", 12usize, |this| ((**(this as *const *const IJsonObject_Vtbl)).GetNamedBoolean)(this, name, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNamedValue,
+            SetNamedValue,
+            GetNamedObject,
+            GetNamedArray,
+            GetNamedString,
+            GetNamedNumber,
+            GetNamedBoolean,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsonObject as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsonObjectStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonObjectStatics", "Parse", 6usize, |this| ((**(this as *const *const IJsonObjectStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonObjectStatics", "TryParse", 7usize, |this| ((**(this as *const *const IJsonObjectStatics_Vtbl)).TryParse)(this, input, result, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsonObjectStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsonObjectWithDefaultValues_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNamedValueOrDefault(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, defaultvalue: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonObjectWithDefaultValues", "GetNamedValueOrDefault", 6usize, |this| ((**(this as *const *const IJsonObjectWithDefaultValues_Vtbl)).GetNamedValueOrDefault)(this, name, defaultvalue, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedObjectOrDefault(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, defaultvalue: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonObjectWithDefaultValues", "GetNamedObjectOrDefault", 7usize, |this| ((**(this as *const *const IJsonObjectWithDefaultValues_Vtbl)).GetNamedObjectOrDefault)(this, name, defaultvalue, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedStringOrDefault(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, defaultvalue: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonObjectWithDefaultValues", "GetNamedStringOrDefault", 8usize, |this| ((**(this as *const *const IJsonObjectWithDefaultValues_Vtbl)).GetNamedStringOrDefault)(this, name, defaultvalue, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedArrayOrDefault(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, defaultvalue: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonObjectWithDefaultValues", "GetNamedArrayOrDefault", 9usize, |this| ((**(this as *const *const IJsonObjectWithDefaultValues_Vtbl)).GetNamedArrayOrDefault)(this, name, defaultvalue, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedNumberOrDefault(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, defaultvalue: f64, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonObjectWithDefaultValues", "GetNamedNumberOrDefault", 10usize, |this| ((**(this as *const *const IJsonObjectWithDefaultValues_Vtbl)).GetNamedNumberOrDefault)(this, name, defaultvalue, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedBooleanOrDefault(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, defaultvalue: bool, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonObjectWithDefaultValues", "GetNamedBooleanOrDefault", 11usize, |this| ((**(this as *const *const IJsonObjectWithDefaultValues_Vtbl)).GetNamedBooleanOrDefault)(this, name, defaultvalue, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNamedValueOrDefault,
+            GetNamedObjectOrDefault,
+            GetNamedStringOrDefault,
+            GetNamedArrayOrDefault,
+            GetNamedNumberOrDefault,
+            GetNamedBooleanOrDefault,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsonObjectWithDefaultValues as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsonValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ValueType(this: *mut core::ffi::c_void, result__: *mut JsonValueType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonValue", "ValueType", 6usize, |this| ((**(this as *const *const IJsonValue_Vtbl)).ValueType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stringify(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonValue", "Stringify", 7usize, |this| ((**(this as *const *const IJsonValue_Vtbl)).Stringify)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonValue", "GetString", 8usize, |this| ((**(this as *const *const IJsonValue_Vtbl)).GetString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumber(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonValue", "GetNumber", 9usize, |this| ((**(this as *const *const IJsonValue_Vtbl)).GetNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBoolean(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonValue", "GetBoolean", 10usize, |this| ((**(this as *const *const IJsonValue_Vtbl)).GetBoolean)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonValue", "GetArray", 11usize, |this| ((**(this as *const *const IJsonValue_Vtbl)).GetArray)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonValue", "GetObject", 12usize, |this| ((**(this as *const *const IJsonValue_Vtbl)).GetObject)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ValueType,
+            Stringify,
+            GetString,
+            GetNumber,
+            GetBoolean,
+            GetArray,
+            GetObject,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsonValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsonValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IJsonValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IJsonValueStatics_Vtbl)).TryParse)(this, input, result, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBooleanValue(this: *mut core::ffi::c_void, input: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonValueStatics", "CreateBooleanValue", 8usize, |this| ((**(this as *const *const IJsonValueStatics_Vtbl)).CreateBooleanValue)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNumberValue(this: *mut core::ffi::c_void, input: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonValueStatics", "CreateNumberValue", 9usize, |this| ((**(this as *const *const IJsonValueStatics_Vtbl)).CreateNumberValue)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStringValue(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonValueStatics", "CreateStringValue", 10usize, |this| ((**(this as *const *const IJsonValueStatics_Vtbl)).CreateStringValue)(this, input, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Parse,
+            TryParse,
+            CreateBooleanValue,
+            CreateNumberValue,
+            CreateStringValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsonValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsonValueStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateNullValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsonValueStatics2", "CreateNullValue", 6usize, |this| ((**(this as *const *const IJsonValueStatics2_Vtbl)).CreateNullValue)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateNullValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsonValueStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
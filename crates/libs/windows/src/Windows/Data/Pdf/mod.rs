@@ -424,6 +424,7 @@ impl PdfPageRotation {
 impl windows_core::TypeKind for PdfPageRotation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PdfPageRotation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PdfPageRotation").field(&self.0).finish()
@@ -432,3 +433,5 @@ impl core::fmt::Debug for PdfPageRotation {
 impl windows_core::RuntimeType for PdfPageRotation {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Data.Pdf.PdfPageRotation;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
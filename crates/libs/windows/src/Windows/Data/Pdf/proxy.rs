@@ -0,0 +1,206 @@
+impl windows_core::imp::ProxyVtable for IPdfDocument_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPage(this: *mut core::ffi::c_void, pageindex: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfDocument", "GetPage", 6usize, |this| ((**(this as *const *const IPdfDocument_Vtbl)).GetPage)(this, pageindex, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfDocument", "PageCount", 7usize, |this| ((**(this as *const *const IPdfDocument_Vtbl)).PageCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPasswordProtected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfDocument", "IsPasswordProtected", 8usize, |this| ((**(this as *const *const IPdfDocument_Vtbl)).IsPasswordProtected)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPage, PageCount, IsPasswordProtected }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPdfDocument as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPdfDocumentStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LoadFromFileAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfDocumentStatics", "LoadFromFileAsync", 6usize, |this| ((**(this as *const *const IPdfDocumentStatics_Vtbl)).LoadFromFileAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LoadFromFileWithPasswordAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, password: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfDocumentStatics", "LoadFromFileWithPasswordAsync", 7usize, |this| ((**(this as *const *const IPdfDocumentStatics_Vtbl)).LoadFromFileWithPasswordAsync)(this, file, password, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn LoadFromStreamAsync(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfDocumentStatics", "LoadFromStreamAsync", 8usize, |this| ((**(this as *const *const IPdfDocumentStatics_Vtbl)).LoadFromStreamAsync)(this, inputstream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn LoadFromStreamWithPasswordAsync(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, password: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfDocumentStatics", "LoadFromStreamWithPasswordAsync", 9usize, |this| ((**(this as *const *const IPdfDocumentStatics_Vtbl)).LoadFromStreamWithPasswordAsync)(this, inputstream, password, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            LoadFromFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            LoadFromFileAsync: 0,
+            #[cfg(feature = "Storage")]
+            LoadFromFileWithPasswordAsync,
+            #[cfg(not(feature = "Storage"))]
+            LoadFromFileWithPasswordAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            LoadFromStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            LoadFromStreamAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            LoadFromStreamWithPasswordAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            LoadFromStreamWithPasswordAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPdfDocumentStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPdfPage_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn RenderToStreamAsync(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPage", "RenderToStreamAsync", 6usize, |this| ((**(this as *const *const IPdfPage_Vtbl)).RenderToStreamAsync)(this, outputstream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn RenderWithOptionsToStreamAsync(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPage", "RenderWithOptionsToStreamAsync", 7usize, |this| ((**(this as *const *const IPdfPage_Vtbl)).RenderWithOptionsToStreamAsync)(this, outputstream, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreparePageAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPage", "PreparePageAsync", 8usize, |this| ((**(this as *const *const IPdfPage_Vtbl)).PreparePageAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Index(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPage", "Index", 9usize, |this| ((**(this as *const *const IPdfPage_Vtbl)).Index)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPage", "Size", 10usize, |this| ((**(this as *const *const IPdfPage_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Dimensions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPage", "Dimensions", 11usize, |this| ((**(this as *const *const IPdfPage_Vtbl)).Dimensions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rotation(this: *mut core::ffi::c_void, result__: *mut PdfPageRotation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPage", "Rotation", 12usize, |this| ((**(this as *const *const IPdfPage_Vtbl)).Rotation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredZoom(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPage", "PreferredZoom", 13usize, |this| ((**(this as *const *const IPdfPage_Vtbl)).PreferredZoom)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            RenderToStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            RenderToStreamAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            RenderWithOptionsToStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            RenderWithOptionsToStreamAsync: 0,
+            PreparePageAsync,
+            Index,
+            Size,
+            Dimensions,
+            Rotation,
+            PreferredZoom,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPdfPage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPdfPageDimensions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MediaBox(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageDimensions", "MediaBox", 6usize, |this| ((**(this as *const *const IPdfPageDimensions_Vtbl)).MediaBox)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CropBox(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageDimensions", "CropBox", 7usize, |this| ((**(this as *const *const IPdfPageDimensions_Vtbl)).CropBox)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BleedBox(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageDimensions", "BleedBox", 8usize, |this| ((**(this as *const *const IPdfPageDimensions_Vtbl)).BleedBox)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrimBox(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageDimensions", "TrimBox", 9usize, |this| ((**(this as *const *const IPdfPageDimensions_Vtbl)).TrimBox)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ArtBox(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageDimensions", "ArtBox", 10usize, |this| ((**(this as *const *const IPdfPageDimensions_Vtbl)).ArtBox)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MediaBox, CropBox, BleedBox, TrimBox, ArtBox }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPdfPageDimensions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for IPdfPageRenderOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SourceRect(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageRenderOptions", "SourceRect", 6usize, |this| ((**(this as *const *const IPdfPageRenderOptions_Vtbl)).SourceRect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceRect(this: *mut core::ffi::c_void, value: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageRenderOptions", "SetSourceRect", 7usize, |this| ((**(this as *const *const IPdfPageRenderOptions_Vtbl)).SetSourceRect)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationWidth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageRenderOptions", "DestinationWidth", 8usize, |this| ((**(this as *const *const IPdfPageRenderOptions_Vtbl)).DestinationWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestinationWidth(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageRenderOptions", "SetDestinationWidth", 9usize, |this| ((**(this as *const *const IPdfPageRenderOptions_Vtbl)).SetDestinationWidth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationHeight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageRenderOptions", "DestinationHeight", 10usize, |this| ((**(this as *const *const IPdfPageRenderOptions_Vtbl)).DestinationHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestinationHeight(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageRenderOptions", "SetDestinationHeight", 11usize, |this| ((**(this as *const *const IPdfPageRenderOptions_Vtbl)).SetDestinationHeight)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn BackgroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageRenderOptions", "BackgroundColor", 12usize, |this| ((**(this as *const *const IPdfPageRenderOptions_Vtbl)).BackgroundColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetBackgroundColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageRenderOptions", "SetBackgroundColor", 13usize, |this| ((**(this as *const *const IPdfPageRenderOptions_Vtbl)).SetBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIgnoringHighContrast(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageRenderOptions", "IsIgnoringHighContrast", 14usize, |this| ((**(this as *const *const IPdfPageRenderOptions_Vtbl)).IsIgnoringHighContrast)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsIgnoringHighContrast(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageRenderOptions", "SetIsIgnoringHighContrast", 15usize, |this| ((**(this as *const *const IPdfPageRenderOptions_Vtbl)).SetIsIgnoringHighContrast)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitmapEncoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageRenderOptions", "BitmapEncoderId", 16usize, |this| ((**(this as *const *const IPdfPageRenderOptions_Vtbl)).BitmapEncoderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBitmapEncoderId(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfPageRenderOptions", "SetBitmapEncoderId", 17usize, |this| ((**(this as *const *const IPdfPageRenderOptions_Vtbl)).SetBitmapEncoderId)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SourceRect,
+            SetSourceRect,
+            DestinationWidth,
+            SetDestinationWidth,
+            DestinationHeight,
+            SetDestinationHeight,
+            #[cfg(feature = "UI")]
+            BackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            BackgroundColor: 0,
+            #[cfg(feature = "UI")]
+            SetBackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            SetBackgroundColor: 0,
+            IsIgnoringHighContrast,
+            SetIsIgnoringHighContrast,
+            BitmapEncoderId,
+            SetBitmapEncoderId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPdfPageRenderOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -904,6 +904,7 @@ impl AlternateNormalizationFormat {
 impl windows_core::TypeKind for AlternateNormalizationFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AlternateNormalizationFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AlternateNormalizationFormat").field(&self.0).finish()
@@ -923,6 +924,7 @@ impl TextPredictionOptions {
 impl windows_core::TypeKind for TextPredictionOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TextPredictionOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TextPredictionOptions").field(&self.0).finish()
@@ -1002,6 +1004,7 @@ impl UnicodeGeneralCategory {
 impl windows_core::TypeKind for UnicodeGeneralCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UnicodeGeneralCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UnicodeGeneralCategory").field(&self.0).finish()
@@ -1022,6 +1025,7 @@ impl UnicodeNumericType {
 impl windows_core::TypeKind for UnicodeNumericType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UnicodeNumericType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UnicodeNumericType").field(&self.0).finish()
@@ -1031,7 +1035,8 @@ impl windows_core::RuntimeType for UnicodeNumericType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Data.Text.UnicodeNumericType;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TextSegment {
     pub StartPosition: u32,
     pub Length: u32,
@@ -1189,3 +1194,5 @@ pub struct WordSegmentsTokenizingHandler_Vtbl {
     #[cfg(not(feature = "Foundation_Collections"))]
     Invoke: usize,
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,489 @@
+impl windows_core::imp::ProxyVtable for IAlternateWordForm_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SourceTextSegment(this: *mut core::ffi::c_void, result__: *mut TextSegment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternateWordForm", "SourceTextSegment", 6usize, |this| ((**(this as *const *const IAlternateWordForm_Vtbl)).SourceTextSegment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlternateText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternateWordForm", "AlternateText", 7usize, |this| ((**(this as *const *const IAlternateWordForm_Vtbl)).AlternateText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NormalizationFormat(this: *mut core::ffi::c_void, result__: *mut AlternateNormalizationFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternateWordForm", "NormalizationFormat", 8usize, |this| ((**(this as *const *const IAlternateWordForm_Vtbl)).NormalizationFormat)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SourceTextSegment, AlternateText, NormalizationFormat }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAlternateWordForm as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISelectableWordSegment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectableWordSegment", "Text", 6usize, |this| ((**(this as *const *const ISelectableWordSegment_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceTextSegment(this: *mut core::ffi::c_void, result__: *mut TextSegment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectableWordSegment", "SourceTextSegment", 7usize, |this| ((**(this as *const *const ISelectableWordSegment_Vtbl)).SourceTextSegment)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Text, SourceTextSegment }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISelectableWordSegment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISelectableWordsSegmenter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResolvedLanguage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectableWordsSegmenter", "ResolvedLanguage", 6usize, |this| ((**(this as *const *const ISelectableWordsSegmenter_Vtbl)).ResolvedLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTokenAt(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, startindex: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectableWordsSegmenter", "GetTokenAt", 7usize, |this| ((**(this as *const *const ISelectableWordsSegmenter_Vtbl)).GetTokenAt)(this, text, startindex, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetTokens(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectableWordsSegmenter", "GetTokens", 8usize, |this| ((**(this as *const *const ISelectableWordsSegmenter_Vtbl)).GetTokens)(this, text, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Tokenize(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, startindex: u32, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectableWordsSegmenter", "Tokenize", 9usize, |this| ((**(this as *const *const ISelectableWordsSegmenter_Vtbl)).Tokenize)(this, text, startindex, handler), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ResolvedLanguage,
+            GetTokenAt,
+            #[cfg(feature = "Foundation_Collections")]
+            GetTokens,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetTokens: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Tokenize,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Tokenize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISelectableWordsSegmenter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISelectableWordsSegmenterFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithLanguage(this: *mut core::ffi::c_void, language: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectableWordsSegmenterFactory", "CreateWithLanguage", 6usize, |this| ((**(this as *const *const ISelectableWordsSegmenterFactory_Vtbl)).CreateWithLanguage)(this, language, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithLanguage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISelectableWordsSegmenterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISemanticTextQuery_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Find(this: *mut core::ffi::c_void, content: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISemanticTextQuery", "Find", 6usize, |this| ((**(this as *const *const ISemanticTextQuery_Vtbl)).Find)(this, content, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindInProperty(this: *mut core::ffi::c_void, propertycontent: core::mem::MaybeUninit<windows_core::HSTRING>, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISemanticTextQuery", "FindInProperty", 7usize, |this| ((**(this as *const *const ISemanticTextQuery_Vtbl)).FindInProperty)(this, propertycontent, propertyname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Find,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Find: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindInProperty,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindInProperty: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISemanticTextQuery as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISemanticTextQueryFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, aqsfilter: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISemanticTextQueryFactory", "Create", 6usize, |this| ((**(this as *const *const ISemanticTextQueryFactory_Vtbl)).Create)(this, aqsfilter, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithLanguage(this: *mut core::ffi::c_void, aqsfilter: core::mem::MaybeUninit<windows_core::HSTRING>, filterlanguage: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISemanticTextQueryFactory", "CreateWithLanguage", 7usize, |this| ((**(this as *const *const ISemanticTextQueryFactory_Vtbl)).CreateWithLanguage)(this, aqsfilter, filterlanguage, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithLanguage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISemanticTextQueryFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITextConversionGenerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResolvedLanguage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextConversionGenerator", "ResolvedLanguage", 6usize, |this| ((**(this as *const *const ITextConversionGenerator_Vtbl)).ResolvedLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LanguageAvailableButNotInstalled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextConversionGenerator", "LanguageAvailableButNotInstalled", 7usize, |this| ((**(this as *const *const ITextConversionGenerator_Vtbl)).LanguageAvailableButNotInstalled)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCandidatesAsync(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextConversionGenerator", "GetCandidatesAsync", 8usize, |this| ((**(this as *const *const ITextConversionGenerator_Vtbl)).GetCandidatesAsync)(this, input, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCandidatesWithMaxCountAsync(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, maxcandidates: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextConversionGenerator", "GetCandidatesWithMaxCountAsync", 9usize, |this| ((**(this as *const *const ITextConversionGenerator_Vtbl)).GetCandidatesWithMaxCountAsync)(this, input, maxcandidates, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ResolvedLanguage,
+            LanguageAvailableButNotInstalled,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCandidatesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCandidatesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCandidatesWithMaxCountAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCandidatesWithMaxCountAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextConversionGenerator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextConversionGeneratorFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, languagetag: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextConversionGeneratorFactory", "Create", 6usize, |this| ((**(this as *const *const ITextConversionGeneratorFactory_Vtbl)).Create)(this, languagetag, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextConversionGeneratorFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextPhoneme_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPhoneme", "DisplayText", 6usize, |this| ((**(this as *const *const ITextPhoneme_Vtbl)).DisplayText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPhoneme", "ReadingText", 7usize, |this| ((**(this as *const *const ITextPhoneme_Vtbl)).ReadingText)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayText, ReadingText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextPhoneme as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITextPredictionGenerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResolvedLanguage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPredictionGenerator", "ResolvedLanguage", 6usize, |this| ((**(this as *const *const ITextPredictionGenerator_Vtbl)).ResolvedLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LanguageAvailableButNotInstalled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPredictionGenerator", "LanguageAvailableButNotInstalled", 7usize, |this| ((**(this as *const *const ITextPredictionGenerator_Vtbl)).LanguageAvailableButNotInstalled)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCandidatesAsync(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPredictionGenerator", "GetCandidatesAsync", 8usize, |this| ((**(this as *const *const ITextPredictionGenerator_Vtbl)).GetCandidatesAsync)(this, input, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCandidatesWithMaxCountAsync(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, maxcandidates: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPredictionGenerator", "GetCandidatesWithMaxCountAsync", 9usize, |this| ((**(this as *const *const ITextPredictionGenerator_Vtbl)).GetCandidatesWithMaxCountAsync)(this, input, maxcandidates, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ResolvedLanguage,
+            LanguageAvailableButNotInstalled,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCandidatesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCandidatesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCandidatesWithMaxCountAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCandidatesWithMaxCountAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextPredictionGenerator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "UI_Text_Core"))]
+impl windows_core::imp::ProxyVtable for ITextPredictionGenerator2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCandidatesWithParametersAsync(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, maxcandidates: u32, predictionoptions: TextPredictionOptions, previousstrings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPredictionGenerator2", "GetCandidatesWithParametersAsync", 6usize, |this| ((**(this as *const *const ITextPredictionGenerator2_Vtbl)).GetCandidatesWithParametersAsync)(this, input, maxcandidates, predictionoptions, previousstrings, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetNextWordCandidatesAsync(this: *mut core::ffi::c_void, maxcandidates: u32, previousstrings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPredictionGenerator2", "GetNextWordCandidatesAsync", 7usize, |this| ((**(this as *const *const ITextPredictionGenerator2_Vtbl)).GetNextWordCandidatesAsync)(this, maxcandidates, previousstrings, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Text_Core")]
+        unsafe extern "system" fn InputScope(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Text::Core::CoreTextInputScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPredictionGenerator2", "InputScope", 8usize, |this| ((**(this as *const *const ITextPredictionGenerator2_Vtbl)).InputScope)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Text_Core")]
+        unsafe extern "system" fn SetInputScope(this: *mut core::ffi::c_void, value: super::super::UI::Text::Core::CoreTextInputScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPredictionGenerator2", "SetInputScope", 9usize, |this| ((**(this as *const *const ITextPredictionGenerator2_Vtbl)).SetInputScope)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCandidatesWithParametersAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCandidatesWithParametersAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetNextWordCandidatesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetNextWordCandidatesAsync: 0,
+            #[cfg(feature = "UI_Text_Core")]
+            InputScope,
+            #[cfg(not(feature = "UI_Text_Core"))]
+            InputScope: 0,
+            #[cfg(feature = "UI_Text_Core")]
+            SetInputScope,
+            #[cfg(not(feature = "UI_Text_Core"))]
+            SetInputScope: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextPredictionGenerator2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextPredictionGeneratorFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, languagetag: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPredictionGeneratorFactory", "Create", 6usize, |this| ((**(this as *const *const ITextPredictionGeneratorFactory_Vtbl)).Create)(this, languagetag, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextPredictionGeneratorFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextReverseConversionGenerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResolvedLanguage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextReverseConversionGenerator", "ResolvedLanguage", 6usize, |this| ((**(this as *const *const ITextReverseConversionGenerator_Vtbl)).ResolvedLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LanguageAvailableButNotInstalled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextReverseConversionGenerator", "LanguageAvailableButNotInstalled", 7usize, |this| ((**(this as *const *const ITextReverseConversionGenerator_Vtbl)).LanguageAvailableButNotInstalled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertBackAsync(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextReverseConversionGenerator", "ConvertBackAsync", 8usize, |this| ((**(this as *const *const ITextReverseConversionGenerator_Vtbl)).ConvertBackAsync)(this, input, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ResolvedLanguage,
+            LanguageAvailableButNotInstalled,
+            ConvertBackAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextReverseConversionGenerator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITextReverseConversionGenerator2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetPhonemesAsync(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextReverseConversionGenerator2", "GetPhonemesAsync", 6usize, |this| ((**(this as *const *const ITextReverseConversionGenerator2_Vtbl)).GetPhonemesAsync)(this, input, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetPhonemesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetPhonemesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextReverseConversionGenerator2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextReverseConversionGeneratorFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, languagetag: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextReverseConversionGeneratorFactory", "Create", 6usize, |this| ((**(this as *const *const ITextReverseConversionGeneratorFactory_Vtbl)).Create)(this, languagetag, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextReverseConversionGeneratorFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUnicodeCharactersStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCodepointFromSurrogatePair(this: *mut core::ffi::c_void, highsurrogate: u32, lowsurrogate: u32, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "GetCodepointFromSurrogatePair", 6usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).GetCodepointFromSurrogatePair)(this, highsurrogate, lowsurrogate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSurrogatePairFromCodepoint(this: *mut core::ffi::c_void, codepoint: u32, highsurrogate: *mut u16, lowsurrogate: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "GetSurrogatePairFromCodepoint", 7usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).GetSurrogatePairFromCodepoint)(this, codepoint, highsurrogate, lowsurrogate), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHighSurrogate(this: *mut core::ffi::c_void, codepoint: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "IsHighSurrogate", 8usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).IsHighSurrogate)(this, codepoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLowSurrogate(this: *mut core::ffi::c_void, codepoint: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "IsLowSurrogate", 9usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).IsLowSurrogate)(this, codepoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupplementary(this: *mut core::ffi::c_void, codepoint: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "IsSupplementary", 10usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).IsSupplementary)(this, codepoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNoncharacter(this: *mut core::ffi::c_void, codepoint: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "IsNoncharacter", 11usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).IsNoncharacter)(this, codepoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWhitespace(this: *mut core::ffi::c_void, codepoint: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "IsWhitespace", 12usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).IsWhitespace)(this, codepoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAlphabetic(this: *mut core::ffi::c_void, codepoint: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "IsAlphabetic", 13usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).IsAlphabetic)(this, codepoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCased(this: *mut core::ffi::c_void, codepoint: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "IsCased", 14usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).IsCased)(this, codepoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUppercase(this: *mut core::ffi::c_void, codepoint: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "IsUppercase", 15usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).IsUppercase)(this, codepoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLowercase(this: *mut core::ffi::c_void, codepoint: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "IsLowercase", 16usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).IsLowercase)(this, codepoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIdStart(this: *mut core::ffi::c_void, codepoint: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "IsIdStart", 17usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).IsIdStart)(this, codepoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIdContinue(this: *mut core::ffi::c_void, codepoint: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "IsIdContinue", 18usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).IsIdContinue)(this, codepoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGraphemeBase(this: *mut core::ffi::c_void, codepoint: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "IsGraphemeBase", 19usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).IsGraphemeBase)(this, codepoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGraphemeExtend(this: *mut core::ffi::c_void, codepoint: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "IsGraphemeExtend", 20usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).IsGraphemeExtend)(this, codepoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumericType(this: *mut core::ffi::c_void, codepoint: u32, result__: *mut UnicodeNumericType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "GetNumericType", 21usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).GetNumericType)(this, codepoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGeneralCategory(this: *mut core::ffi::c_void, codepoint: u32, result__: *mut UnicodeGeneralCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnicodeCharactersStatics", "GetGeneralCategory", 22usize, |this| ((**(this as *const *const IUnicodeCharactersStatics_Vtbl)).GetGeneralCategory)(this, codepoint, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCodepointFromSurrogatePair,
+            GetSurrogatePairFromCodepoint,
+            IsHighSurrogate,
+            IsLowSurrogate,
+            IsSupplementary,
+            IsNoncharacter,
+            IsWhitespace,
+            IsAlphabetic,
+            IsCased,
+            IsUppercase,
+            IsLowercase,
+            IsIdStart,
+            IsIdContinue,
+            IsGraphemeBase,
+            IsGraphemeExtend,
+            GetNumericType,
+            GetGeneralCategory,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUnicodeCharactersStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWordSegment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordSegment", "Text", 6usize, |this| ((**(this as *const *const IWordSegment_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceTextSegment(this: *mut core::ffi::c_void, result__: *mut TextSegment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordSegment", "SourceTextSegment", 7usize, |this| ((**(this as *const *const IWordSegment_Vtbl)).SourceTextSegment)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AlternateForms(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordSegment", "AlternateForms", 8usize, |this| ((**(this as *const *const IWordSegment_Vtbl)).AlternateForms)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Text,
+            SourceTextSegment,
+            #[cfg(feature = "Foundation_Collections")]
+            AlternateForms,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AlternateForms: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWordSegment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWordsSegmenter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResolvedLanguage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordsSegmenter", "ResolvedLanguage", 6usize, |this| ((**(this as *const *const IWordsSegmenter_Vtbl)).ResolvedLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTokenAt(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, startindex: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordsSegmenter", "GetTokenAt", 7usize, |this| ((**(this as *const *const IWordsSegmenter_Vtbl)).GetTokenAt)(this, text, startindex, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetTokens(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordsSegmenter", "GetTokens", 8usize, |this| ((**(this as *const *const IWordsSegmenter_Vtbl)).GetTokens)(this, text, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Tokenize(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, startindex: u32, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordsSegmenter", "Tokenize", 9usize, |this| ((**(this as *const *const IWordsSegmenter_Vtbl)).Tokenize)(this, text, startindex, handler), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ResolvedLanguage,
+            GetTokenAt,
+            #[cfg(feature = "Foundation_Collections")]
+            GetTokens,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetTokens: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Tokenize,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Tokenize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWordsSegmenter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWordsSegmenterFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithLanguage(this: *mut core::ffi::c_void, language: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordsSegmenterFactory", "CreateWithLanguage", 6usize, |this| ((**(this as *const *const IWordsSegmenterFactory_Vtbl)).CreateWithLanguage)(this, language, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithLanguage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWordsSegmenterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -5012,6 +5012,7 @@ impl NodeType {
 impl windows_core::TypeKind for NodeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NodeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NodeType").field(&self.0).finish()
@@ -5022,3 +5023,5 @@ impl windows_core::RuntimeType for NodeType {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,678 @@
+impl windows_core::imp::ProxyVtable for IDtdEntity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PublicId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtdEntity", "PublicId", 6usize, |this| ((**(this as *const *const IDtdEntity_Vtbl)).PublicId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtdEntity", "SystemId", 7usize, |this| ((**(this as *const *const IDtdEntity_Vtbl)).SystemId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotationName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtdEntity", "NotationName", 8usize, |this| ((**(this as *const *const IDtdEntity_Vtbl)).NotationName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PublicId, SystemId, NotationName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtdEntity as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtdNotation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PublicId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtdNotation", "PublicId", 6usize, |this| ((**(this as *const *const IDtdNotation_Vtbl)).PublicId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtdNotation", "SystemId", 7usize, |this| ((**(this as *const *const IDtdNotation_Vtbl)).SystemId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PublicId, SystemId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtdNotation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlAttribute_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlAttribute", "Name", 6usize, |this| ((**(this as *const *const IXmlAttribute_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Specified(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlAttribute", "Specified", 7usize, |this| ((**(this as *const *const IXmlAttribute_Vtbl)).Specified)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlAttribute", "Value", 8usize, |this| ((**(this as *const *const IXmlAttribute_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlAttribute", "SetValue", 9usize, |this| ((**(this as *const *const IXmlAttribute_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, Specified, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlAttribute as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlCDataSection_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlCDataSection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlCharacterData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlCharacterData", "Data", 6usize, |this| ((**(this as *const *const IXmlCharacterData_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlCharacterData", "SetData", 7usize, |this| ((**(this as *const *const IXmlCharacterData_Vtbl)).SetData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlCharacterData", "Length", 8usize, |this| ((**(this as *const *const IXmlCharacterData_Vtbl)).Length)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubstringData(this: *mut core::ffi::c_void, offset: u32, count: u32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlCharacterData", "SubstringData", 9usize, |this| ((**(this as *const *const IXmlCharacterData_Vtbl)).SubstringData)(this, offset, count, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendData(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlCharacterData", "AppendData", 10usize, |this| ((**(this as *const *const IXmlCharacterData_Vtbl)).AppendData)(this, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertData(this: *mut core::ffi::c_void, offset: u32, data: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlCharacterData", "InsertData", 11usize, |this| ((**(this as *const *const IXmlCharacterData_Vtbl)).InsertData)(this, offset, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteData(this: *mut core::ffi::c_void, offset: u32, count: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlCharacterData", "DeleteData", 12usize, |this| ((**(this as *const *const IXmlCharacterData_Vtbl)).DeleteData)(this, offset, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplaceData(this: *mut core::ffi::c_void, offset: u32, count: u32, data: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlCharacterData", "ReplaceData", 13usize, |this| ((**(this as *const *const IXmlCharacterData_Vtbl)).ReplaceData)(this, offset, count, data), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Data,
+            SetData,
+            Length,
+            SubstringData,
+            AppendData,
+            InsertData,
+            DeleteData,
+            ReplaceData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlCharacterData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlComment_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlComment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlDocument_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Doctype(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "Doctype", 6usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).Doctype)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Implementation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "Implementation", 7usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).Implementation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentElement(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "DocumentElement", 8usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).DocumentElement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateElement(this: *mut core::ffi::c_void, tagname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "CreateElement", 9usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).CreateElement)(this, tagname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDocumentFragment(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "CreateDocumentFragment", 10usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).CreateDocumentFragment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTextNode(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "CreateTextNode", 11usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).CreateTextNode)(this, data, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateComment(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "CreateComment", 12usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).CreateComment)(this, data, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateProcessingInstruction(this: *mut core::ffi::c_void, target: core::mem::MaybeUninit<windows_core::HSTRING>, data: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "CreateProcessingInstruction", 13usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).CreateProcessingInstruction)(this, target, data, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAttribute(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "CreateAttribute", 14usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).CreateAttribute)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEntityReference(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "CreateEntityReference", 15usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).CreateEntityReference)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetElementsByTagName(this: *mut core::ffi::c_void, tagname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "GetElementsByTagName", 16usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).GetElementsByTagName)(this, tagname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCDataSection(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "CreateCDataSection", 17usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).CreateCDataSection)(this, data, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentUri(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "DocumentUri", 18usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).DocumentUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAttributeNS(this: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, qualifiedname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "CreateAttributeNS", 19usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).CreateAttributeNS)(this, namespaceuri, qualifiedname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateElementNS(this: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, qualifiedname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "CreateElementNS", 20usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).CreateElementNS)(this, namespaceuri, qualifiedname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetElementById(this: *mut core::ffi::c_void, elementid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "GetElementById", 21usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).GetElementById)(this, elementid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportNode(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, deep: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocument", "ImportNode", 22usize, |this| ((**(this as *const *const IXmlDocument_Vtbl)).ImportNode)(this, node, deep, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Doctype,
+            Implementation,
+            DocumentElement,
+            CreateElement,
+            CreateDocumentFragment,
+            CreateTextNode,
+            CreateComment,
+            CreateProcessingInstruction,
+            CreateAttribute,
+            CreateEntityReference,
+            GetElementsByTagName,
+            CreateCDataSection,
+            DocumentUri,
+            CreateAttributeNS,
+            CreateElementNS,
+            GetElementById,
+            ImportNode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlDocument as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlDocumentFragment_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlDocumentFragment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IXmlDocumentIO_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadXml(this: *mut core::ffi::c_void, xml: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocumentIO", "LoadXml", 6usize, |this| ((**(this as *const *const IXmlDocumentIO_Vtbl)).LoadXml)(this, xml), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadXmlWithSettings(this: *mut core::ffi::c_void, xml: core::mem::MaybeUninit<windows_core::HSTRING>, loadsettings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocumentIO", "LoadXmlWithSettings", 7usize, |this| ((**(this as *const *const IXmlDocumentIO_Vtbl)).LoadXmlWithSettings)(this, xml, loadsettings), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SaveToFileAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocumentIO", "SaveToFileAsync", 8usize, |this| ((**(this as *const *const IXmlDocumentIO_Vtbl)).SaveToFileAsync)(this, file, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LoadXml,
+            LoadXmlWithSettings,
+            #[cfg(feature = "Storage")]
+            SaveToFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            SaveToFileAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlDocumentIO as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IXmlDocumentIO2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn LoadXmlFromBuffer(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocumentIO2", "LoadXmlFromBuffer", 6usize, |this| ((**(this as *const *const IXmlDocumentIO2_Vtbl)).LoadXmlFromBuffer)(this, buffer), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn LoadXmlFromBufferWithSettings(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, loadsettings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocumentIO2", "LoadXmlFromBufferWithSettings", 7usize, |this| ((**(this as *const *const IXmlDocumentIO2_Vtbl)).LoadXmlFromBufferWithSettings)(this, buffer, loadsettings), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            LoadXmlFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            LoadXmlFromBuffer: 0,
+            #[cfg(feature = "Storage_Streams")]
+            LoadXmlFromBufferWithSettings,
+            #[cfg(not(feature = "Storage_Streams"))]
+            LoadXmlFromBufferWithSettings: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlDocumentIO2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IXmlDocumentStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadFromUriAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocumentStatics", "LoadFromUriAsync", 6usize, |this| ((**(this as *const *const IXmlDocumentStatics_Vtbl)).LoadFromUriAsync)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadFromUriWithSettingsAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, loadsettings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocumentStatics", "LoadFromUriWithSettingsAsync", 7usize, |this| ((**(this as *const *const IXmlDocumentStatics_Vtbl)).LoadFromUriWithSettingsAsync)(this, uri, loadsettings, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LoadFromFileAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocumentStatics", "LoadFromFileAsync", 8usize, |this| ((**(this as *const *const IXmlDocumentStatics_Vtbl)).LoadFromFileAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LoadFromFileWithSettingsAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, loadsettings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocumentStatics", "LoadFromFileWithSettingsAsync", 9usize, |this| ((**(this as *const *const IXmlDocumentStatics_Vtbl)).LoadFromFileWithSettingsAsync)(this, file, loadsettings, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LoadFromUriAsync,
+            LoadFromUriWithSettingsAsync,
+            #[cfg(feature = "Storage")]
+            LoadFromFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            LoadFromFileAsync: 0,
+            #[cfg(feature = "Storage")]
+            LoadFromFileWithSettingsAsync,
+            #[cfg(not(feature = "Storage"))]
+            LoadFromFileWithSettingsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlDocumentStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlDocumentType_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocumentType", "Name", 6usize, |this| ((**(this as *const *const IXmlDocumentType_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Entities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocumentType", "Entities", 7usize, |this| ((**(this as *const *const IXmlDocumentType_Vtbl)).Entities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Notations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDocumentType", "Notations", 8usize, |this| ((**(this as *const *const IXmlDocumentType_Vtbl)).Notations)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, Entities, Notations }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlDocumentType as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlDomImplementation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasFeature(this: *mut core::ffi::c_void, feature: core::mem::MaybeUninit<windows_core::HSTRING>, version: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlDomImplementation", "HasFeature", 6usize, |this| ((**(this as *const *const IXmlDomImplementation_Vtbl)).HasFeature)(this, feature, version, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HasFeature }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlDomImplementation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TagName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlElement", "TagName", 6usize, |this| ((**(this as *const *const IXmlElement_Vtbl)).TagName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttribute(this: *mut core::ffi::c_void, attributename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlElement", "GetAttribute", 7usize, |this| ((**(this as *const *const IXmlElement_Vtbl)).GetAttribute)(this, attributename, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttribute(this: *mut core::ffi::c_void, attributename: core::mem::MaybeUninit<windows_core::HSTRING>, attributevalue: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlElement", "SetAttribute", 8usize, |this| ((**(this as *const *const IXmlElement_Vtbl)).SetAttribute)(this, attributename, attributevalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAttribute(this: *mut core::ffi::c_void, attributename: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlElement", "RemoveAttribute", 9usize, |this| ((**(this as *const *const IXmlElement_Vtbl)).RemoveAttribute)(this, attributename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeNode(this: *mut core::ffi::c_void, attributename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlElement", "GetAttributeNode", 10usize, |this| ((**(this as *const *const IXmlElement_Vtbl)).GetAttributeNode)(this, attributename, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttributeNode(this: *mut core::ffi::c_void, newattribute: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlElement", "SetAttributeNode", 11usize, |this| ((**(this as *const *const IXmlElement_Vtbl)).SetAttributeNode)(this, newattribute, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAttributeNode(this: *mut core::ffi::c_void, attributenode: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlElement", "RemoveAttributeNode", 12usize, |this| ((**(this as *const *const IXmlElement_Vtbl)).RemoveAttributeNode)(this, attributenode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetElementsByTagName(this: *mut core::ffi::c_void, tagname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlElement", "GetElementsByTagName", 13usize, |this| ((**(this as *const *const IXmlElement_Vtbl)).GetElementsByTagName)(this, tagname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttributeNS(this: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, qualifiedname: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlElement", "SetAttributeNS", 14usize, |this| ((**(this as *const *const IXmlElement_Vtbl)).SetAttributeNS)(this, namespaceuri, qualifiedname, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeNS(this: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, localname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlElement", "GetAttributeNS", 15usize, |this| ((**(this as *const *const IXmlElement_Vtbl)).GetAttributeNS)(this, namespaceuri, localname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAttributeNS(this: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, localname: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlElement", "RemoveAttributeNS", 16usize, |this| ((**(this as *const *const IXmlElement_Vtbl)).RemoveAttributeNS)(this, namespaceuri, localname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttributeNodeNS(this: *mut core::ffi::c_void, newattribute: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlElement", "SetAttributeNodeNS", 17usize, |this| ((**(this as *const *const IXmlElement_Vtbl)).SetAttributeNodeNS)(this, newattribute, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeNodeNS(this: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, localname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlElement", "GetAttributeNodeNS", 18usize, |this| ((**(this as *const *const IXmlElement_Vtbl)).GetAttributeNodeNS)(this, namespaceuri, localname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TagName,
+            GetAttribute,
+            SetAttribute,
+            RemoveAttribute,
+            GetAttributeNode,
+            SetAttributeNode,
+            RemoveAttributeNode,
+            GetElementsByTagName,
+            SetAttributeNS,
+            GetAttributeNS,
+            RemoveAttributeNS,
+            SetAttributeNodeNS,
+            GetAttributeNodeNS,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlElement as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlEntityReference_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlEntityReference as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlLoadSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxElementDepth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlLoadSettings", "MaxElementDepth", 6usize, |this| ((**(this as *const *const IXmlLoadSettings_Vtbl)).MaxElementDepth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxElementDepth(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlLoadSettings", "SetMaxElementDepth", 7usize, |this| ((**(this as *const *const IXmlLoadSettings_Vtbl)).SetMaxElementDepth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProhibitDtd(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlLoadSettings", "ProhibitDtd", 8usize, |this| ((**(this as *const *const IXmlLoadSettings_Vtbl)).ProhibitDtd)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProhibitDtd(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlLoadSettings", "SetProhibitDtd", 9usize, |this| ((**(this as *const *const IXmlLoadSettings_Vtbl)).SetProhibitDtd)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolveExternals(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlLoadSettings", "ResolveExternals", 10usize, |this| ((**(this as *const *const IXmlLoadSettings_Vtbl)).ResolveExternals)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResolveExternals(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlLoadSettings", "SetResolveExternals", 11usize, |this| ((**(this as *const *const IXmlLoadSettings_Vtbl)).SetResolveExternals)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateOnParse(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlLoadSettings", "ValidateOnParse", 12usize, |this| ((**(this as *const *const IXmlLoadSettings_Vtbl)).ValidateOnParse)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValidateOnParse(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlLoadSettings", "SetValidateOnParse", 13usize, |this| ((**(this as *const *const IXmlLoadSettings_Vtbl)).SetValidateOnParse)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ElementContentWhiteSpace(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlLoadSettings", "ElementContentWhiteSpace", 14usize, |this| ((**(this as *const *const IXmlLoadSettings_Vtbl)).ElementContentWhiteSpace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetElementContentWhiteSpace(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlLoadSettings", "SetElementContentWhiteSpace", 15usize, |this| ((**(this as *const *const IXmlLoadSettings_Vtbl)).SetElementContentWhiteSpace)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaxElementDepth,
+            SetMaxElementDepth,
+            ProhibitDtd,
+            SetProhibitDtd,
+            ResolveExternals,
+            SetResolveExternals,
+            ValidateOnParse,
+            SetValidateOnParse,
+            ElementContentWhiteSpace,
+            SetElementContentWhiteSpace,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlLoadSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IXmlNamedNodeMap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNamedNodeMap", "Length", 6usize, |this| ((**(this as *const *const IXmlNamedNodeMap_Vtbl)).Length)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNamedNodeMap", "Item", 7usize, |this| ((**(this as *const *const IXmlNamedNodeMap_Vtbl)).Item)(this, index, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedItem(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNamedNodeMap", "GetNamedItem", 8usize, |this| ((**(this as *const *const IXmlNamedNodeMap_Vtbl)).GetNamedItem)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNamedItem(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNamedNodeMap", "SetNamedItem", 9usize, |this| ((**(this as *const *const IXmlNamedNodeMap_Vtbl)).SetNamedItem)(this, node, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNamedItem(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNamedNodeMap", "RemoveNamedItem", 10usize, |this| ((**(this as *const *const IXmlNamedNodeMap_Vtbl)).RemoveNamedItem)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedItemNS(this: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNamedNodeMap", "GetNamedItemNS", 11usize, |this| ((**(this as *const *const IXmlNamedNodeMap_Vtbl)).GetNamedItemNS)(this, namespaceuri, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNamedItemNS(this: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNamedNodeMap", "RemoveNamedItemNS", 12usize, |this| ((**(this as *const *const IXmlNamedNodeMap_Vtbl)).RemoveNamedItemNS)(this, namespaceuri, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNamedItemNS(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNamedNodeMap", "SetNamedItemNS", 13usize, |this| ((**(this as *const *const IXmlNamedNodeMap_Vtbl)).SetNamedItemNS)(this, node, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Length,
+            Item,
+            GetNamedItem,
+            SetNamedItem,
+            RemoveNamedItem,
+            GetNamedItemNS,
+            RemoveNamedItemNS,
+            SetNamedItemNS,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlNamedNodeMap as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlNode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NodeValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "NodeValue", 6usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).NodeValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNodeValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "SetNodeValue", 7usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).SetNodeValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NodeType(this: *mut core::ffi::c_void, result__: *mut NodeType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "NodeType", 8usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).NodeType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NodeName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "NodeName", 9usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).NodeName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentNode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "ParentNode", 10usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).ParentNode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChildNodes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "ChildNodes", 11usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).ChildNodes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstChild(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "FirstChild", 12usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).FirstChild)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastChild(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "LastChild", 13usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).LastChild)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreviousSibling(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "PreviousSibling", 14usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).PreviousSibling)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextSibling(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "NextSibling", 15usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).NextSibling)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Attributes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "Attributes", 16usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).Attributes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasChildNodes(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "HasChildNodes", 17usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).HasChildNodes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OwnerDocument(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "OwnerDocument", 18usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).OwnerDocument)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertBefore(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, referencechild: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "InsertBefore", 19usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).InsertBefore)(this, newchild, referencechild, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplaceChild(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, referencechild: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "ReplaceChild", 20usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).ReplaceChild)(this, newchild, referencechild, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveChild(this: *mut core::ffi::c_void, childnode: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "RemoveChild", 21usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).RemoveChild)(this, childnode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendChild(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "AppendChild", 22usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).AppendChild)(this, newchild, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloneNode(this: *mut core::ffi::c_void, deep: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "CloneNode", 23usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).CloneNode)(this, deep, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NamespaceUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "NamespaceUri", 24usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).NamespaceUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "LocalName", 25usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).LocalName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Prefix(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "Prefix", 26usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).Prefix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Normalize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "Normalize", 27usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).Normalize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrefix(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNode", "SetPrefix", 28usize, |this| ((**(this as *const *const IXmlNode_Vtbl)).SetPrefix)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NodeValue,
+            SetNodeValue,
+            NodeType,
+            NodeName,
+            ParentNode,
+            ChildNodes,
+            FirstChild,
+            LastChild,
+            PreviousSibling,
+            NextSibling,
+            Attributes,
+            HasChildNodes,
+            OwnerDocument,
+            InsertBefore,
+            ReplaceChild,
+            RemoveChild,
+            AppendChild,
+            CloneNode,
+            NamespaceUri,
+            LocalName,
+            Prefix,
+            Normalize,
+            SetPrefix,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlNode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IXmlNodeList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNodeList", "Length", 6usize, |this| ((**(this as *const *const IXmlNodeList_Vtbl)).Length)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNodeList", "Item", 7usize, |this| ((**(this as *const *const IXmlNodeList_Vtbl)).Item)(this, index, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Length, Item }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlNodeList as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlNodeSelector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SelectSingleNode(this: *mut core::ffi::c_void, xpath: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNodeSelector", "SelectSingleNode", 6usize, |this| ((**(this as *const *const IXmlNodeSelector_Vtbl)).SelectSingleNode)(this, xpath, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectNodes(this: *mut core::ffi::c_void, xpath: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNodeSelector", "SelectNodes", 7usize, |this| ((**(this as *const *const IXmlNodeSelector_Vtbl)).SelectNodes)(this, xpath, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectSingleNodeNS(this: *mut core::ffi::c_void, xpath: core::mem::MaybeUninit<windows_core::HSTRING>, namespaces: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNodeSelector", "SelectSingleNodeNS", 8usize, |this| ((**(this as *const *const IXmlNodeSelector_Vtbl)).SelectSingleNodeNS)(this, xpath, namespaces, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectNodesNS(this: *mut core::ffi::c_void, xpath: core::mem::MaybeUninit<windows_core::HSTRING>, namespaces: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNodeSelector", "SelectNodesNS", 9usize, |this| ((**(this as *const *const IXmlNodeSelector_Vtbl)).SelectNodesNS)(this, xpath, namespaces, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SelectSingleNode,
+            SelectNodes,
+            SelectSingleNodeNS,
+            SelectNodesNS,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlNodeSelector as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlNodeSerializer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetXml(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNodeSerializer", "GetXml", 6usize, |this| ((**(this as *const *const IXmlNodeSerializer_Vtbl)).GetXml)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InnerText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNodeSerializer", "InnerText", 7usize, |this| ((**(this as *const *const IXmlNodeSerializer_Vtbl)).InnerText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInnerText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlNodeSerializer", "SetInnerText", 8usize, |this| ((**(this as *const *const IXmlNodeSerializer_Vtbl)).SetInnerText)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetXml, InnerText, SetInnerText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlNodeSerializer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlProcessingInstruction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Target(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlProcessingInstruction", "Target", 6usize, |this| ((**(this as *const *const IXmlProcessingInstruction_Vtbl)).Target)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlProcessingInstruction", "Data", 7usize, |this| ((**(this as *const *const IXmlProcessingInstruction_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlProcessingInstruction", "SetData", 8usize, |this| ((**(this as *const *const IXmlProcessingInstruction_Vtbl)).SetData)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Target, Data, SetData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlProcessingInstruction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlText_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SplitText(this: *mut core::ffi::c_void, offset: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlText", "SplitText", 6usize, |this| ((**(this as *const *const IXmlText_Vtbl)).SplitText)(this, offset, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SplitText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlText as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
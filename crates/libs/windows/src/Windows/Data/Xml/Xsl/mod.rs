@@ -89,3 +89,5 @@ impl windows_core::RuntimeName for XsltProcessor {
 }
 unsafe impl Send for XsltProcessor {}
 unsafe impl Sync for XsltProcessor {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
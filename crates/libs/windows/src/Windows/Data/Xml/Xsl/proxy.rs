@@ -0,0 +1,60 @@
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IXsltProcessor_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn TransformToString(this: *mut core::ffi::c_void, inputnode: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXsltProcessor", "TransformToString", 6usize, |this| ((**(this as *const *const IXsltProcessor_Vtbl)).TransformToString)(this, inputnode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            TransformToString,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            TransformToString: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXsltProcessor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IXsltProcessor2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn TransformToDocument(this: *mut core::ffi::c_void, inputnode: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXsltProcessor2", "TransformToDocument", 6usize, |this| ((**(this as *const *const IXsltProcessor2_Vtbl)).TransformToDocument)(this, inputnode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            TransformToDocument,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            TransformToDocument: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXsltProcessor2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IXsltProcessorFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, document: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXsltProcessorFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IXsltProcessorFactory_Vtbl)).CreateInstance)(this, document, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            CreateInstance,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            CreateInstance: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXsltProcessorFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -125,6 +125,7 @@ impl ProviderAdcChannelMode {
 impl windows_core::TypeKind for ProviderAdcChannelMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProviderAdcChannelMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProviderAdcChannelMode").field(&self.0).finish()
@@ -135,3 +136,5 @@ impl windows_core::RuntimeType for ProviderAdcChannelMode {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,71 @@
+impl windows_core::imp::ProxyVtable for IAdcControllerProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChannelCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcControllerProvider", "ChannelCount", 6usize, |this| ((**(this as *const *const IAdcControllerProvider_Vtbl)).ChannelCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolutionInBits(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcControllerProvider", "ResolutionInBits", 7usize, |this| ((**(this as *const *const IAdcControllerProvider_Vtbl)).ResolutionInBits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinValue(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcControllerProvider", "MinValue", 8usize, |this| ((**(this as *const *const IAdcControllerProvider_Vtbl)).MinValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxValue(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcControllerProvider", "MaxValue", 9usize, |this| ((**(this as *const *const IAdcControllerProvider_Vtbl)).MaxValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChannelMode(this: *mut core::ffi::c_void, result__: *mut ProviderAdcChannelMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcControllerProvider", "ChannelMode", 10usize, |this| ((**(this as *const *const IAdcControllerProvider_Vtbl)).ChannelMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChannelMode(this: *mut core::ffi::c_void, value: ProviderAdcChannelMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcControllerProvider", "SetChannelMode", 11usize, |this| ((**(this as *const *const IAdcControllerProvider_Vtbl)).SetChannelMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsChannelModeSupported(this: *mut core::ffi::c_void, channelmode: ProviderAdcChannelMode, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcControllerProvider", "IsChannelModeSupported", 12usize, |this| ((**(this as *const *const IAdcControllerProvider_Vtbl)).IsChannelModeSupported)(this, channelmode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcquireChannel(this: *mut core::ffi::c_void, channel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcControllerProvider", "AcquireChannel", 13usize, |this| ((**(this as *const *const IAdcControllerProvider_Vtbl)).AcquireChannel)(this, channel), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseChannel(this: *mut core::ffi::c_void, channel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcControllerProvider", "ReleaseChannel", 14usize, |this| ((**(this as *const *const IAdcControllerProvider_Vtbl)).ReleaseChannel)(this, channel), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadValue(this: *mut core::ffi::c_void, channelnumber: i32, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcControllerProvider", "ReadValue", 15usize, |this| ((**(this as *const *const IAdcControllerProvider_Vtbl)).ReadValue)(this, channelnumber, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChannelCount,
+            ResolutionInBits,
+            MinValue,
+            MaxValue,
+            ChannelMode,
+            SetChannelMode,
+            IsChannelModeSupported,
+            AcquireChannel,
+            ReleaseChannel,
+            ReadValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdcControllerProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAdcProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetControllers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcProvider", "GetControllers", 6usize, |this| ((**(this as *const *const IAdcProvider_Vtbl)).GetControllers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetControllers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetControllers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdcProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
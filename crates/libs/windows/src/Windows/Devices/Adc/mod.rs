@@ -199,6 +199,7 @@ impl AdcChannelMode {
 impl windows_core::TypeKind for AdcChannelMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AdcChannelMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AdcChannelMode").field(&self.0).finish()
@@ -207,3 +208,5 @@ impl core::fmt::Debug for AdcChannelMode {
 impl windows_core::RuntimeType for AdcChannelMode {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Adc.AdcChannelMode;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,93 @@
+impl windows_core::imp::ProxyVtable for IAdcChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Controller(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcChannel", "Controller", 6usize, |this| ((**(this as *const *const IAdcChannel_Vtbl)).Controller)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadValue(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcChannel", "ReadValue", 7usize, |this| ((**(this as *const *const IAdcChannel_Vtbl)).ReadValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadRatio(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcChannel", "ReadRatio", 8usize, |this| ((**(this as *const *const IAdcChannel_Vtbl)).ReadRatio)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Controller, ReadValue, ReadRatio }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdcChannel as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdcController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChannelCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcController", "ChannelCount", 6usize, |this| ((**(this as *const *const IAdcController_Vtbl)).ChannelCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolutionInBits(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcController", "ResolutionInBits", 7usize, |this| ((**(this as *const *const IAdcController_Vtbl)).ResolutionInBits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinValue(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcController", "MinValue", 8usize, |this| ((**(this as *const *const IAdcController_Vtbl)).MinValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxValue(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcController", "MaxValue", 9usize, |this| ((**(this as *const *const IAdcController_Vtbl)).MaxValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChannelMode(this: *mut core::ffi::c_void, result__: *mut AdcChannelMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcController", "ChannelMode", 10usize, |this| ((**(this as *const *const IAdcController_Vtbl)).ChannelMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChannelMode(this: *mut core::ffi::c_void, value: AdcChannelMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcController", "SetChannelMode", 11usize, |this| ((**(this as *const *const IAdcController_Vtbl)).SetChannelMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsChannelModeSupported(this: *mut core::ffi::c_void, channelmode: AdcChannelMode, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcController", "IsChannelModeSupported", 12usize, |this| ((**(this as *const *const IAdcController_Vtbl)).IsChannelModeSupported)(this, channelmode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenChannel(this: *mut core::ffi::c_void, channelnumber: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcController", "OpenChannel", 13usize, |this| ((**(this as *const *const IAdcController_Vtbl)).OpenChannel)(this, channelnumber, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChannelCount,
+            ResolutionInBits,
+            MinValue,
+            MaxValue,
+            ChannelMode,
+            SetChannelMode,
+            IsChannelModeSupported,
+            OpenChannel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdcController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Adc_Provider", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IAdcControllerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Devices_Adc_Provider", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetControllersAsync(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcControllerStatics", "GetControllersAsync", 6usize, |this| ((**(this as *const *const IAdcControllerStatics_Vtbl)).GetControllersAsync)(this, provider, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Devices_Adc_Provider", feature = "Foundation_Collections"))]
+            GetControllersAsync,
+            #[cfg(not(all(feature = "Devices_Adc_Provider", feature = "Foundation_Collections")))]
+            GetControllersAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdcControllerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdcControllerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdcControllerStatics2", "GetDefaultAsync", 6usize, |this| ((**(this as *const *const IAdcControllerStatics2_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefaultAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdcControllerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -90,3 +90,5 @@ impl windows_core::RuntimeName for DeviceUseDetails {
 }
 unsafe impl Send for DeviceUseDetails {}
 unsafe impl Sync for DeviceUseDetails {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
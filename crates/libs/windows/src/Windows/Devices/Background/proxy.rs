@@ -0,0 +1,33 @@
+impl windows_core::imp::ProxyVtable for IDeviceServicingDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceServicingDetails", "DeviceId", 6usize, |this| ((**(this as *const *const IDeviceServicingDetails_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Arguments(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceServicingDetails", "Arguments", 7usize, |this| ((**(this as *const *const IDeviceServicingDetails_Vtbl)).Arguments)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpectedDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceServicingDetails", "ExpectedDuration", 8usize, |this| ((**(this as *const *const IDeviceServicingDetails_Vtbl)).ExpectedDuration)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId, Arguments, ExpectedDuration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceServicingDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceUseDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceUseDetails", "DeviceId", 6usize, |this| ((**(this as *const *const IDeviceUseDetails_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Arguments(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceUseDetails", "Arguments", 7usize, |this| ((**(this as *const *const IDeviceUseDetails_Vtbl)).Arguments)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId, Arguments }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceUseDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
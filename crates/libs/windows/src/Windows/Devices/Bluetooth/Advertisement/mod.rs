@@ -1259,6 +1259,7 @@ impl BluetoothLEAdvertisementFlags {
 impl windows_core::TypeKind for BluetoothLEAdvertisementFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BluetoothLEAdvertisementFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BluetoothLEAdvertisementFlags").field(&self.0).finish()
@@ -1314,6 +1315,7 @@ impl BluetoothLEAdvertisementPublisherStatus {
 impl windows_core::TypeKind for BluetoothLEAdvertisementPublisherStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BluetoothLEAdvertisementPublisherStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BluetoothLEAdvertisementPublisherStatus").field(&self.0).finish()
@@ -1336,6 +1338,7 @@ impl BluetoothLEAdvertisementType {
 impl windows_core::TypeKind for BluetoothLEAdvertisementType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BluetoothLEAdvertisementType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BluetoothLEAdvertisementType").field(&self.0).finish()
@@ -1357,6 +1360,7 @@ impl BluetoothLEAdvertisementWatcherStatus {
 impl windows_core::TypeKind for BluetoothLEAdvertisementWatcherStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BluetoothLEAdvertisementWatcherStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BluetoothLEAdvertisementWatcherStatus").field(&self.0).finish()
@@ -1376,6 +1380,7 @@ impl BluetoothLEScanningMode {
 impl windows_core::TypeKind for BluetoothLEScanningMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BluetoothLEScanningMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BluetoothLEScanningMode").field(&self.0).finish()
@@ -1384,3 +1389,5 @@ impl core::fmt::Debug for BluetoothLEScanningMode {
 impl windows_core::RuntimeType for BluetoothLEScanningMode {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Bluetooth.Advertisement.BluetoothLEScanningMode;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,676 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Flags(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisement", "Flags", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisement_Vtbl)).Flags)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisement", "SetFlags", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisement_Vtbl)).SetFlags)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisement", "LocalName", 8usize, |this| ((**(this as *const *const IBluetoothLEAdvertisement_Vtbl)).LocalName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisement", "SetLocalName", 9usize, |this| ((**(this as *const *const IBluetoothLEAdvertisement_Vtbl)).SetLocalName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ServiceUuids(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisement", "ServiceUuids", 10usize, |this| ((**(this as *const *const IBluetoothLEAdvertisement_Vtbl)).ServiceUuids)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ManufacturerData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisement", "ManufacturerData", 11usize, |this| ((**(this as *const *const IBluetoothLEAdvertisement_Vtbl)).ManufacturerData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DataSections(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisement", "DataSections", 12usize, |this| ((**(this as *const *const IBluetoothLEAdvertisement_Vtbl)).DataSections)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetManufacturerDataByCompanyId(this: *mut core::ffi::c_void, companyid: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisement", "GetManufacturerDataByCompanyId", 13usize, |this| ((**(this as *const *const IBluetoothLEAdvertisement_Vtbl)).GetManufacturerDataByCompanyId)(this, companyid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSectionsByType(this: *mut core::ffi::c_void, r#type: u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisement", "GetSectionsByType", 14usize, |this| ((**(this as *const *const IBluetoothLEAdvertisement_Vtbl)).GetSectionsByType)(this, r#type, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Flags,
+            SetFlags,
+            LocalName,
+            SetLocalName,
+            #[cfg(feature = "Foundation_Collections")]
+            ServiceUuids,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ServiceUuids: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ManufacturerData,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ManufacturerData: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            DataSections,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DataSections: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetManufacturerDataByCompanyId,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetManufacturerDataByCompanyId: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSectionsByType,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSectionsByType: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisement as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementBytePattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DataType(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementBytePattern", "DataType", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementBytePattern_Vtbl)).DataType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataType(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementBytePattern", "SetDataType", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementBytePattern_Vtbl)).SetDataType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementBytePattern", "Offset", 8usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementBytePattern_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, value: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementBytePattern", "SetOffset", 9usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementBytePattern_Vtbl)).SetOffset)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementBytePattern", "Data", 10usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementBytePattern_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementBytePattern", "SetData", 11usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementBytePattern_Vtbl)).SetData)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DataType,
+            SetDataType,
+            Offset,
+            SetOffset,
+            #[cfg(feature = "Storage_Streams")]
+            Data,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Data: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementBytePattern as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementBytePatternFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, datatype: u8, offset: i16, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementBytePatternFactory", "Create", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementBytePatternFactory_Vtbl)).Create)(this, datatype, offset, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Create,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementBytePatternFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementDataSection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DataType(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataSection", "DataType", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataSection_Vtbl)).DataType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataType(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataSection", "SetDataType", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataSection_Vtbl)).SetDataType)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataSection", "Data", 8usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataSection_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataSection", "SetData", 9usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataSection_Vtbl)).SetData)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DataType,
+            SetDataType,
+            #[cfg(feature = "Storage_Streams")]
+            Data,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Data: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementDataSection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementDataSectionFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, datatype: u8, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataSectionFactory", "Create", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataSectionFactory_Vtbl)).Create)(this, datatype, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Create,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementDataSectionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementDataTypesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Flags(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "Flags", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).Flags)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncompleteService16BitUuids(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "IncompleteService16BitUuids", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).IncompleteService16BitUuids)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompleteService16BitUuids(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "CompleteService16BitUuids", 8usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).CompleteService16BitUuids)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncompleteService32BitUuids(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "IncompleteService32BitUuids", 9usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).IncompleteService32BitUuids)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompleteService32BitUuids(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "CompleteService32BitUuids", 10usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).CompleteService32BitUuids)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncompleteService128BitUuids(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "IncompleteService128BitUuids", 11usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).IncompleteService128BitUuids)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompleteService128BitUuids(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "CompleteService128BitUuids", 12usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).CompleteService128BitUuids)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShortenedLocalName(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "ShortenedLocalName", 13usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).ShortenedLocalName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompleteLocalName(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "CompleteLocalName", 14usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).CompleteLocalName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxPowerLevel(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "TxPowerLevel", 15usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).TxPowerLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PeripheralConnectionIntervalRange(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "PeripheralConnectionIntervalRange", 16usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).PeripheralConnectionIntervalRange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceSolicitation16BitUuids(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "ServiceSolicitation16BitUuids", 17usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).ServiceSolicitation16BitUuids)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceSolicitation32BitUuids(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "ServiceSolicitation32BitUuids", 18usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).ServiceSolicitation32BitUuids)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceSolicitation128BitUuids(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "ServiceSolicitation128BitUuids", 19usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).ServiceSolicitation128BitUuids)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceData16BitUuids(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "ServiceData16BitUuids", 20usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).ServiceData16BitUuids)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceData32BitUuids(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "ServiceData32BitUuids", 21usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).ServiceData32BitUuids)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceData128BitUuids(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "ServiceData128BitUuids", 22usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).ServiceData128BitUuids)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublicTargetAddress(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "PublicTargetAddress", 23usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).PublicTargetAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RandomTargetAddress(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "RandomTargetAddress", 24usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).RandomTargetAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Appearance(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "Appearance", 25usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).Appearance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdvertisingInterval(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "AdvertisingInterval", 26usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).AdvertisingInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManufacturerSpecificData(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementDataTypesStatics", "ManufacturerSpecificData", 27usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementDataTypesStatics_Vtbl)).ManufacturerSpecificData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Flags,
+            IncompleteService16BitUuids,
+            CompleteService16BitUuids,
+            IncompleteService32BitUuids,
+            CompleteService32BitUuids,
+            IncompleteService128BitUuids,
+            CompleteService128BitUuids,
+            ShortenedLocalName,
+            CompleteLocalName,
+            TxPowerLevel,
+            PeripheralConnectionIntervalRange,
+            ServiceSolicitation16BitUuids,
+            ServiceSolicitation32BitUuids,
+            ServiceSolicitation128BitUuids,
+            ServiceData16BitUuids,
+            ServiceData32BitUuids,
+            ServiceData128BitUuids,
+            PublicTargetAddress,
+            RandomTargetAddress,
+            Appearance,
+            AdvertisingInterval,
+            ManufacturerSpecificData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementDataTypesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Advertisement(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementFilter", "Advertisement", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementFilter_Vtbl)).Advertisement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAdvertisement(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementFilter", "SetAdvertisement", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementFilter_Vtbl)).SetAdvertisement)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BytePatterns(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementFilter", "BytePatterns", 8usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementFilter_Vtbl)).BytePatterns)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Advertisement,
+            SetAdvertisement,
+            #[cfg(feature = "Foundation_Collections")]
+            BytePatterns,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BytePatterns: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementPublisher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut BluetoothLEAdvertisementPublisherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisher", "Status", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Advertisement(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisher", "Advertisement", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisher_Vtbl)).Advertisement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisher", "Start", 8usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisher", "Stop", 9usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisher", "StatusChanged", 10usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisher_Vtbl)).StatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisher", "RemoveStatusChanged", 11usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisher_Vtbl)).RemoveStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            Advertisement,
+            Start,
+            Stop,
+            StatusChanged,
+            RemoveStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementPublisher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementPublisher2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PreferredTransmitPowerLevelInDBm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisher2", "PreferredTransmitPowerLevelInDBm", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisher2_Vtbl)).PreferredTransmitPowerLevelInDBm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredTransmitPowerLevelInDBm(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisher2", "SetPreferredTransmitPowerLevelInDBm", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisher2_Vtbl)).SetPreferredTransmitPowerLevelInDBm)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseExtendedAdvertisement(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisher2", "UseExtendedAdvertisement", 8usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisher2_Vtbl)).UseExtendedAdvertisement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseExtendedAdvertisement(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisher2", "SetUseExtendedAdvertisement", 9usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisher2_Vtbl)).SetUseExtendedAdvertisement)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAnonymous(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisher2", "IsAnonymous", 10usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisher2_Vtbl)).IsAnonymous)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsAnonymous(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisher2", "SetIsAnonymous", 11usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisher2_Vtbl)).SetIsAnonymous)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeTransmitPowerLevel(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisher2", "IncludeTransmitPowerLevel", 12usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisher2_Vtbl)).IncludeTransmitPowerLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeTransmitPowerLevel(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisher2", "SetIncludeTransmitPowerLevel", 13usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisher2_Vtbl)).SetIncludeTransmitPowerLevel)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PreferredTransmitPowerLevelInDBm,
+            SetPreferredTransmitPowerLevelInDBm,
+            UseExtendedAdvertisement,
+            SetUseExtendedAdvertisement,
+            IsAnonymous,
+            SetIsAnonymous,
+            IncludeTransmitPowerLevel,
+            SetIncludeTransmitPowerLevel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementPublisher2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementPublisherFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, advertisement: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherFactory", "Create", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherFactory_Vtbl)).Create)(this, advertisement, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementPublisherFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementPublisherStatusChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut BluetoothLEAdvertisementPublisherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherStatusChangedEventArgs", "Status", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherStatusChangedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut super::BluetoothError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherStatusChangedEventArgs", "Error", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherStatusChangedEventArgs_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Error }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementPublisherStatusChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementPublisherStatusChangedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SelectedTransmitPowerLevelInDBm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherStatusChangedEventArgs2", "SelectedTransmitPowerLevelInDBm", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherStatusChangedEventArgs2_Vtbl)).SelectedTransmitPowerLevelInDBm)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SelectedTransmitPowerLevelInDBm }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementPublisherStatusChangedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RawSignalStrengthInDBm(this: *mut core::ffi::c_void, result__: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementReceivedEventArgs", "RawSignalStrengthInDBm", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementReceivedEventArgs_Vtbl)).RawSignalStrengthInDBm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BluetoothAddress(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementReceivedEventArgs", "BluetoothAddress", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementReceivedEventArgs_Vtbl)).BluetoothAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdvertisementType(this: *mut core::ffi::c_void, result__: *mut BluetoothLEAdvertisementType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementReceivedEventArgs", "AdvertisementType", 8usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementReceivedEventArgs_Vtbl)).AdvertisementType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementReceivedEventArgs", "Timestamp", 9usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementReceivedEventArgs_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Advertisement(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementReceivedEventArgs", "Advertisement", 10usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementReceivedEventArgs_Vtbl)).Advertisement)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RawSignalStrengthInDBm,
+            BluetoothAddress,
+            AdvertisementType,
+            Timestamp,
+            Advertisement,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementReceivedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BluetoothAddressType(this: *mut core::ffi::c_void, result__: *mut super::BluetoothAddressType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementReceivedEventArgs2", "BluetoothAddressType", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementReceivedEventArgs2_Vtbl)).BluetoothAddressType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransmitPowerLevelInDBm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementReceivedEventArgs2", "TransmitPowerLevelInDBm", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementReceivedEventArgs2_Vtbl)).TransmitPowerLevelInDBm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAnonymous(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementReceivedEventArgs2", "IsAnonymous", 8usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementReceivedEventArgs2_Vtbl)).IsAnonymous)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnectable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementReceivedEventArgs2", "IsConnectable", 9usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementReceivedEventArgs2_Vtbl)).IsConnectable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsScannable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementReceivedEventArgs2", "IsScannable", 10usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementReceivedEventArgs2_Vtbl)).IsScannable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDirected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementReceivedEventArgs2", "IsDirected", 11usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementReceivedEventArgs2_Vtbl)).IsDirected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsScanResponse(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementReceivedEventArgs2", "IsScanResponse", 12usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementReceivedEventArgs2_Vtbl)).IsScanResponse)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BluetoothAddressType,
+            TransmitPowerLevelInDBm,
+            IsAnonymous,
+            IsConnectable,
+            IsScannable,
+            IsDirected,
+            IsScanResponse,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementReceivedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinSamplingInterval(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "MinSamplingInterval", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).MinSamplingInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSamplingInterval(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "MaxSamplingInterval", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).MaxSamplingInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinOutOfRangeTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "MinOutOfRangeTimeout", 8usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).MinOutOfRangeTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxOutOfRangeTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "MaxOutOfRangeTimeout", 9usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).MaxOutOfRangeTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut BluetoothLEAdvertisementWatcherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "Status", 10usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScanningMode(this: *mut core::ffi::c_void, result__: *mut BluetoothLEScanningMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "ScanningMode", 11usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).ScanningMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScanningMode(this: *mut core::ffi::c_void, value: BluetoothLEScanningMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "SetScanningMode", 12usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).SetScanningMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalStrengthFilter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "SignalStrengthFilter", 13usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).SignalStrengthFilter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignalStrengthFilter(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "SetSignalStrengthFilter", 14usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).SetSignalStrengthFilter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdvertisementFilter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "AdvertisementFilter", 15usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).AdvertisementFilter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAdvertisementFilter(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "SetAdvertisementFilter", 16usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).SetAdvertisementFilter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "Start", 17usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "Stop", 18usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Received(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "Received", 19usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).Received)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReceived(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "RemoveReceived", 20usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).RemoveReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stopped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "Stopped", 21usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).Stopped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStopped(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher", "RemoveStopped", 22usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher_Vtbl)).RemoveStopped)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MinSamplingInterval,
+            MaxSamplingInterval,
+            MinOutOfRangeTimeout,
+            MaxOutOfRangeTimeout,
+            Status,
+            ScanningMode,
+            SetScanningMode,
+            SignalStrengthFilter,
+            SetSignalStrengthFilter,
+            AdvertisementFilter,
+            SetAdvertisementFilter,
+            Start,
+            Stop,
+            Received,
+            RemoveReceived,
+            Stopped,
+            RemoveStopped,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementWatcher2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowExtendedAdvertisements(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher2", "AllowExtendedAdvertisements", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher2_Vtbl)).AllowExtendedAdvertisements)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowExtendedAdvertisements(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcher2", "SetAllowExtendedAdvertisements", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcher2_Vtbl)).SetAllowExtendedAdvertisements)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AllowExtendedAdvertisements, SetAllowExtendedAdvertisements }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementWatcher2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementWatcherFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, advertisementfilter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcherFactory", "Create", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcherFactory_Vtbl)).Create)(this, advertisementfilter, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementWatcherFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementWatcherStoppedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut super::BluetoothError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcherStoppedEventArgs", "Error", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcherStoppedEventArgs_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Error }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementWatcherStoppedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IBluetoothLEManufacturerData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CompanyId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEManufacturerData", "CompanyId", 6usize, |this| ((**(this as *const *const IBluetoothLEManufacturerData_Vtbl)).CompanyId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompanyId(this: *mut core::ffi::c_void, value: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEManufacturerData", "SetCompanyId", 7usize, |this| ((**(this as *const *const IBluetoothLEManufacturerData_Vtbl)).SetCompanyId)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEManufacturerData", "Data", 8usize, |this| ((**(this as *const *const IBluetoothLEManufacturerData_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEManufacturerData", "SetData", 9usize, |this| ((**(this as *const *const IBluetoothLEManufacturerData_Vtbl)).SetData)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CompanyId,
+            SetCompanyId,
+            #[cfg(feature = "Storage_Streams")]
+            Data,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Data: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEManufacturerData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IBluetoothLEManufacturerDataFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, companyid: u16, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEManufacturerDataFactory", "Create", 6usize, |this| ((**(this as *const *const IBluetoothLEManufacturerDataFactory_Vtbl)).Create)(this, companyid, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Create,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEManufacturerDataFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -511,6 +511,7 @@ impl BluetoothEventTriggeringMode {
 impl windows_core::TypeKind for BluetoothEventTriggeringMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BluetoothEventTriggeringMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BluetoothEventTriggeringMode").field(&self.0).finish()
@@ -519,3 +520,5 @@ impl core::fmt::Debug for BluetoothEventTriggeringMode {
 impl windows_core::RuntimeType for BluetoothEventTriggeringMode {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Bluetooth.Background.BluetoothEventTriggeringMode;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,288 @@
+#[cfg(feature = "Devices_Bluetooth_Advertisement")]
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementPublisherTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Bluetooth_Advertisement")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut super::Advertisement::BluetoothLEAdvertisementPublisherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherTriggerDetails", "Status", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherTriggerDetails_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut super::BluetoothError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherTriggerDetails", "Error", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherTriggerDetails_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Bluetooth_Advertisement")]
+            Status,
+            #[cfg(not(feature = "Devices_Bluetooth_Advertisement"))]
+            Status: 0,
+            Error,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementPublisherTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementPublisherTriggerDetails2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SelectedTransmitPowerLevelInDBm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementPublisherTriggerDetails2", "SelectedTransmitPowerLevelInDBm", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementPublisherTriggerDetails2_Vtbl)).SelectedTransmitPowerLevelInDBm)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SelectedTransmitPowerLevelInDBm }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementPublisherTriggerDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Bluetooth_Advertisement", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IBluetoothLEAdvertisementWatcherTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut super::BluetoothError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcherTriggerDetails", "Error", 6usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcherTriggerDetails_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Bluetooth_Advertisement", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn Advertisements(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcherTriggerDetails", "Advertisements", 7usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcherTriggerDetails_Vtbl)).Advertisements)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalStrengthFilter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAdvertisementWatcherTriggerDetails", "SignalStrengthFilter", 8usize, |this| ((**(this as *const *const IBluetoothLEAdvertisementWatcherTriggerDetails_Vtbl)).SignalStrengthFilter)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Error,
+            #[cfg(all(feature = "Devices_Bluetooth_Advertisement", feature = "Foundation_Collections"))]
+            Advertisements,
+            #[cfg(not(all(feature = "Devices_Bluetooth_Advertisement", feature = "Foundation_Collections")))]
+            Advertisements: 0,
+            SignalStrengthFilter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAdvertisementWatcherTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Bluetooth_GenericAttributeProfile", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IGattCharacteristicNotificationTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+        unsafe extern "system" fn Characteristic(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicNotificationTriggerDetails", "Characteristic", 6usize, |this| ((**(this as *const *const IGattCharacteristicNotificationTriggerDetails_Vtbl)).Characteristic)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicNotificationTriggerDetails", "Value", 7usize, |this| ((**(this as *const *const IGattCharacteristicNotificationTriggerDetails_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+            Characteristic,
+            #[cfg(not(feature = "Devices_Bluetooth_GenericAttributeProfile"))]
+            Characteristic: 0,
+            #[cfg(feature = "Storage_Streams")]
+            Value,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Value: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattCharacteristicNotificationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Bluetooth_GenericAttributeProfile", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IGattCharacteristicNotificationTriggerDetails2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut super::BluetoothError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicNotificationTriggerDetails2", "Error", 6usize, |this| ((**(this as *const *const IGattCharacteristicNotificationTriggerDetails2_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventTriggeringMode(this: *mut core::ffi::c_void, result__: *mut BluetoothEventTriggeringMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicNotificationTriggerDetails2", "EventTriggeringMode", 7usize, |this| ((**(this as *const *const IGattCharacteristicNotificationTriggerDetails2_Vtbl)).EventTriggeringMode)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Bluetooth_GenericAttributeProfile", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn ValueChangedEvents(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicNotificationTriggerDetails2", "ValueChangedEvents", 8usize, |this| ((**(this as *const *const IGattCharacteristicNotificationTriggerDetails2_Vtbl)).ValueChangedEvents)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Error,
+            EventTriggeringMode,
+            #[cfg(all(feature = "Devices_Bluetooth_GenericAttributeProfile", feature = "Foundation_Collections"))]
+            ValueChangedEvents,
+            #[cfg(not(all(feature = "Devices_Bluetooth_GenericAttributeProfile", feature = "Foundation_Collections")))]
+            ValueChangedEvents: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattCharacteristicNotificationTriggerDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+impl windows_core::imp::ProxyVtable for IGattServiceProviderConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TriggerId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderConnection", "TriggerId", 6usize, |this| ((**(this as *const *const IGattServiceProviderConnection_Vtbl)).TriggerId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+        unsafe extern "system" fn Service(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderConnection", "Service", 7usize, |this| ((**(this as *const *const IGattServiceProviderConnection_Vtbl)).Service)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderConnection", "Start", 8usize, |this| ((**(this as *const *const IGattServiceProviderConnection_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TriggerId,
+            #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+            Service,
+            #[cfg(not(feature = "Devices_Bluetooth_GenericAttributeProfile"))]
+            Service: 0,
+            Start,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattServiceProviderConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGattServiceProviderConnectionStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AllServices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderConnectionStatics", "AllServices", 6usize, |this| ((**(this as *const *const IGattServiceProviderConnectionStatics_Vtbl)).AllServices)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AllServices,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AllServices: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattServiceProviderConnectionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattServiceProviderTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderTriggerDetails", "Connection", 6usize, |this| ((**(this as *const *const IGattServiceProviderTriggerDetails_Vtbl)).Connection)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Connection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattServiceProviderTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking_Sockets")]
+impl windows_core::imp::ProxyVtable for IRfcommConnectionTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn Socket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommConnectionTriggerDetails", "Socket", 6usize, |this| ((**(this as *const *const IRfcommConnectionTriggerDetails_Vtbl)).Socket)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Incoming(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommConnectionTriggerDetails", "Incoming", 7usize, |this| ((**(this as *const *const IRfcommConnectionTriggerDetails_Vtbl)).Incoming)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteDevice(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommConnectionTriggerDetails", "RemoteDevice", 8usize, |this| ((**(this as *const *const IRfcommConnectionTriggerDetails_Vtbl)).RemoteDevice)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Networking_Sockets")]
+            Socket,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            Socket: 0,
+            Incoming,
+            RemoteDevice,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRfcommConnectionTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Bluetooth_Rfcomm", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IRfcommInboundConnectionInformation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SdpRecord(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommInboundConnectionInformation", "SdpRecord", 6usize, |this| ((**(this as *const *const IRfcommInboundConnectionInformation_Vtbl)).SdpRecord)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetSdpRecord(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommInboundConnectionInformation", "SetSdpRecord", 7usize, |this| ((**(this as *const *const IRfcommInboundConnectionInformation_Vtbl)).SetSdpRecord)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+        unsafe extern "system" fn LocalServiceId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommInboundConnectionInformation", "LocalServiceId", 8usize, |this| ((**(this as *const *const IRfcommInboundConnectionInformation_Vtbl)).LocalServiceId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+        unsafe extern "system" fn SetLocalServiceId(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommInboundConnectionInformation", "SetLocalServiceId", 9usize, |this| ((**(this as *const *const IRfcommInboundConnectionInformation_Vtbl)).SetLocalServiceId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceCapabilities(this: *mut core::ffi::c_void, result__: *mut super::BluetoothServiceCapabilities) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommInboundConnectionInformation", "ServiceCapabilities", 10usize, |this| ((**(this as *const *const IRfcommInboundConnectionInformation_Vtbl)).ServiceCapabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceCapabilities(this: *mut core::ffi::c_void, value: super::BluetoothServiceCapabilities) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommInboundConnectionInformation", "SetServiceCapabilities", 11usize, |this| ((**(this as *const *const IRfcommInboundConnectionInformation_Vtbl)).SetServiceCapabilities)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            SdpRecord,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SdpRecord: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetSdpRecord,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetSdpRecord: 0,
+            #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+            LocalServiceId,
+            #[cfg(not(feature = "Devices_Bluetooth_Rfcomm"))]
+            LocalServiceId: 0,
+            #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+            SetLocalServiceId,
+            #[cfg(not(feature = "Devices_Bluetooth_Rfcomm"))]
+            SetLocalServiceId: 0,
+            ServiceCapabilities,
+            SetServiceCapabilities,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRfcommInboundConnectionInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+impl windows_core::imp::ProxyVtable for IRfcommOutboundConnectionInformation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+        unsafe extern "system" fn RemoteServiceId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommOutboundConnectionInformation", "RemoteServiceId", 6usize, |this| ((**(this as *const *const IRfcommOutboundConnectionInformation_Vtbl)).RemoteServiceId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+        unsafe extern "system" fn SetRemoteServiceId(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommOutboundConnectionInformation", "SetRemoteServiceId", 7usize, |this| ((**(this as *const *const IRfcommOutboundConnectionInformation_Vtbl)).SetRemoteServiceId)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+            RemoteServiceId,
+            #[cfg(not(feature = "Devices_Bluetooth_Rfcomm"))]
+            RemoteServiceId: 0,
+            #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+            SetRemoteServiceId,
+            #[cfg(not(feature = "Devices_Bluetooth_Rfcomm"))]
+            SetRemoteServiceId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRfcommOutboundConnectionInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -4079,6 +4079,7 @@ impl GattCharacteristicProperties {
 impl windows_core::TypeKind for GattCharacteristicProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GattCharacteristicProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GattCharacteristicProperties").field(&self.0).finish()
@@ -4131,6 +4132,7 @@ impl GattClientCharacteristicConfigurationDescriptorValue {
 impl windows_core::TypeKind for GattClientCharacteristicConfigurationDescriptorValue {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GattClientCharacteristicConfigurationDescriptorValue {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GattClientCharacteristicConfigurationDescriptorValue").field(&self.0).finish()
@@ -4151,6 +4153,7 @@ impl GattCommunicationStatus {
 impl windows_core::TypeKind for GattCommunicationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GattCommunicationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GattCommunicationStatus").field(&self.0).finish()
@@ -4173,6 +4176,7 @@ impl GattOpenStatus {
 impl windows_core::TypeKind for GattOpenStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GattOpenStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GattOpenStatus").field(&self.0).finish()
@@ -4193,6 +4197,7 @@ impl GattProtectionLevel {
 impl windows_core::TypeKind for GattProtectionLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GattProtectionLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GattProtectionLevel").field(&self.0).finish()
@@ -4212,6 +4217,7 @@ impl GattRequestState {
 impl windows_core::TypeKind for GattRequestState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GattRequestState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GattRequestState").field(&self.0).finish()
@@ -4233,6 +4239,7 @@ impl GattServiceProviderAdvertisementStatus {
 impl windows_core::TypeKind for GattServiceProviderAdvertisementStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GattServiceProviderAdvertisementStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GattServiceProviderAdvertisementStatus").field(&self.0).finish()
@@ -4251,6 +4258,7 @@ impl GattSessionStatus {
 impl windows_core::TypeKind for GattSessionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GattSessionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GattSessionStatus").field(&self.0).finish()
@@ -4271,6 +4279,7 @@ impl GattSharingMode {
 impl windows_core::TypeKind for GattSharingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GattSharingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GattSharingMode").field(&self.0).finish()
@@ -4289,6 +4298,7 @@ impl GattWriteOption {
 impl windows_core::TypeKind for GattWriteOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GattWriteOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GattWriteOption").field(&self.0).finish()
@@ -4297,3 +4307,5 @@ impl core::fmt::Debug for GattWriteOption {
 impl windows_core::RuntimeType for GattWriteOption {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Bluetooth.GenericAttributeProfile.GattWriteOption;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,2187 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IGattCharacteristic_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn GetDescriptors(this: *mut core::ffi::c_void, descriptoruuid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "GetDescriptors", 6usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).GetDescriptors)(this, descriptoruuid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CharacteristicProperties(this: *mut core::ffi::c_void, result__: *mut GattCharacteristicProperties) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "CharacteristicProperties", 7usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).CharacteristicProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectionLevel(this: *mut core::ffi::c_void, result__: *mut GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "ProtectionLevel", 8usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).ProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProtectionLevel(this: *mut core::ffi::c_void, value: GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "SetProtectionLevel", 9usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).SetProtectionLevel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "UserDescription", 10usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).UserDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uuid(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "Uuid", 11usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).Uuid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttributeHandle(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "AttributeHandle", 12usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).AttributeHandle)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PresentationFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "PresentationFormats", 13usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).PresentationFormats)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadValueAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "ReadValueAsync", 14usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).ReadValueAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadValueWithCacheModeAsync(this: *mut core::ffi::c_void, cachemode: super::BluetoothCacheMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "ReadValueWithCacheModeAsync", 15usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).ReadValueWithCacheModeAsync)(this, cachemode, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn WriteValueAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "WriteValueAsync", 16usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).WriteValueAsync)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn WriteValueWithOptionAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, writeoption: GattWriteOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "WriteValueWithOptionAsync", 17usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).WriteValueWithOptionAsync)(this, value, writeoption, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadClientCharacteristicConfigurationDescriptorAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "ReadClientCharacteristicConfigurationDescriptorAsync", 18usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).ReadClientCharacteristicConfigurationDescriptorAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteClientCharacteristicConfigurationDescriptorAsync(this: *mut core::ffi::c_void, clientcharacteristicconfigurationdescriptorvalue: GattClientCharacteristicConfigurationDescriptorValue, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "WriteClientCharacteristicConfigurationDescriptorAsync", 19usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).WriteClientCharacteristicConfigurationDescriptorAsync)(this, clientcharacteristicconfigurationdescriptorvalue, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValueChanged(this: *mut core::ffi::c_void, valuechangedhandler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "ValueChanged", 20usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).ValueChanged)(this, valuechangedhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveValueChanged(this: *mut core::ffi::c_void, valuechangedeventcookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic", "RemoveValueChanged", 21usize, |this| ((**(this as *const *const IGattCharacteristic_Vtbl)).RemoveValueChanged)(this, valuechangedeventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            GetDescriptors,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            GetDescriptors: 0,
+            CharacteristicProperties,
+            ProtectionLevel,
+            SetProtectionLevel,
+            UserDescription,
+            Uuid,
+            AttributeHandle,
+            #[cfg(feature = "Foundation_Collections")]
+            PresentationFormats,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PresentationFormats: 0,
+            ReadValueAsync,
+            ReadValueWithCacheModeAsync,
+            #[cfg(feature = "Storage_Streams")]
+            WriteValueAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            WriteValueAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            WriteValueWithOptionAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            WriteValueWithOptionAsync: 0,
+            ReadClientCharacteristicConfigurationDescriptorAsync,
+            WriteClientCharacteristicConfigurationDescriptorAsync,
+            ValueChanged,
+            RemoveValueChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattCharacteristic as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IGattCharacteristic2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Service(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic2", "Service", 6usize, |this| ((**(this as *const *const IGattCharacteristic2_Vtbl)).Service)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn GetAllDescriptors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic2", "GetAllDescriptors", 7usize, |this| ((**(this as *const *const IGattCharacteristic2_Vtbl)).GetAllDescriptors)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Service,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            GetAllDescriptors,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            GetAllDescriptors: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattCharacteristic2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IGattCharacteristic3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDescriptorsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic3", "GetDescriptorsAsync", 6usize, |this| ((**(this as *const *const IGattCharacteristic3_Vtbl)).GetDescriptorsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescriptorsWithCacheModeAsync(this: *mut core::ffi::c_void, cachemode: super::BluetoothCacheMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic3", "GetDescriptorsWithCacheModeAsync", 7usize, |this| ((**(this as *const *const IGattCharacteristic3_Vtbl)).GetDescriptorsWithCacheModeAsync)(this, cachemode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescriptorsForUuidAsync(this: *mut core::ffi::c_void, descriptoruuid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic3", "GetDescriptorsForUuidAsync", 8usize, |this| ((**(this as *const *const IGattCharacteristic3_Vtbl)).GetDescriptorsForUuidAsync)(this, descriptoruuid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescriptorsForUuidWithCacheModeAsync(this: *mut core::ffi::c_void, descriptoruuid: windows_core::GUID, cachemode: super::BluetoothCacheMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic3", "GetDescriptorsForUuidWithCacheModeAsync", 9usize, |this| ((**(this as *const *const IGattCharacteristic3_Vtbl)).GetDescriptorsForUuidWithCacheModeAsync)(this, descriptoruuid, cachemode, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn WriteValueWithResultAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic3", "WriteValueWithResultAsync", 10usize, |this| ((**(this as *const *const IGattCharacteristic3_Vtbl)).WriteValueWithResultAsync)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn WriteValueWithResultAndOptionAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, writeoption: GattWriteOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic3", "WriteValueWithResultAndOptionAsync", 11usize, |this| ((**(this as *const *const IGattCharacteristic3_Vtbl)).WriteValueWithResultAndOptionAsync)(this, value, writeoption, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteClientCharacteristicConfigurationDescriptorWithResultAsync(this: *mut core::ffi::c_void, clientcharacteristicconfigurationdescriptorvalue: GattClientCharacteristicConfigurationDescriptorValue, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristic3", "WriteClientCharacteristicConfigurationDescriptorWithResultAsync", 12usize, |this| ((**(this as *const *const IGattCharacteristic3_Vtbl)).WriteClientCharacteristicConfigurationDescriptorWithResultAsync)(this, clientcharacteristicconfigurationdescriptorvalue, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDescriptorsAsync,
+            GetDescriptorsWithCacheModeAsync,
+            GetDescriptorsForUuidAsync,
+            GetDescriptorsForUuidWithCacheModeAsync,
+            #[cfg(feature = "Storage_Streams")]
+            WriteValueWithResultAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            WriteValueWithResultAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            WriteValueWithResultAndOptionAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            WriteValueWithResultAndOptionAsync: 0,
+            WriteClientCharacteristicConfigurationDescriptorWithResultAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattCharacteristic3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattCharacteristicStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ConvertShortIdToUuid(this: *mut core::ffi::c_void, shortid: u16, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicStatics", "ConvertShortIdToUuid", 6usize, |this| ((**(this as *const *const IGattCharacteristicStatics_Vtbl)).ConvertShortIdToUuid)(this, shortid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            ConvertShortIdToUuid,
+            #[cfg(not(feature = "deprecated"))]
+            ConvertShortIdToUuid: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattCharacteristicStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattCharacteristicUuidsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BatteryLevel(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "BatteryLevel", 6usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).BatteryLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BloodPressureFeature(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "BloodPressureFeature", 7usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).BloodPressureFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BloodPressureMeasurement(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "BloodPressureMeasurement", 8usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).BloodPressureMeasurement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BodySensorLocation(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "BodySensorLocation", 9usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).BodySensorLocation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CscFeature(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "CscFeature", 10usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).CscFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CscMeasurement(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "CscMeasurement", 11usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).CscMeasurement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GlucoseFeature(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "GlucoseFeature", 12usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).GlucoseFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GlucoseMeasurement(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "GlucoseMeasurement", 13usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).GlucoseMeasurement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GlucoseMeasurementContext(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "GlucoseMeasurementContext", 14usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).GlucoseMeasurementContext)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeartRateControlPoint(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "HeartRateControlPoint", 15usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).HeartRateControlPoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeartRateMeasurement(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "HeartRateMeasurement", 16usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).HeartRateMeasurement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IntermediateCuffPressure(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "IntermediateCuffPressure", 17usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).IntermediateCuffPressure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IntermediateTemperature(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "IntermediateTemperature", 18usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).IntermediateTemperature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MeasurementInterval(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "MeasurementInterval", 19usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).MeasurementInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordAccessControlPoint(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "RecordAccessControlPoint", 20usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).RecordAccessControlPoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RscFeature(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "RscFeature", 21usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).RscFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RscMeasurement(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "RscMeasurement", 22usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).RscMeasurement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SCControlPoint(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "SCControlPoint", 23usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).SCControlPoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SensorLocation(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "SensorLocation", 24usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).SensorLocation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TemperatureMeasurement(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "TemperatureMeasurement", 25usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).TemperatureMeasurement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TemperatureType(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics", "TemperatureType", 26usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics_Vtbl)).TemperatureType)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BatteryLevel,
+            BloodPressureFeature,
+            BloodPressureMeasurement,
+            BodySensorLocation,
+            CscFeature,
+            CscMeasurement,
+            GlucoseFeature,
+            GlucoseMeasurement,
+            GlucoseMeasurementContext,
+            HeartRateControlPoint,
+            HeartRateMeasurement,
+            IntermediateCuffPressure,
+            IntermediateTemperature,
+            MeasurementInterval,
+            RecordAccessControlPoint,
+            RscFeature,
+            RscMeasurement,
+            SCControlPoint,
+            SensorLocation,
+            TemperatureMeasurement,
+            TemperatureType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattCharacteristicUuidsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattCharacteristicUuidsStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AlertCategoryId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "AlertCategoryId", 6usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).AlertCategoryId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlertCategoryIdBitMask(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "AlertCategoryIdBitMask", 7usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).AlertCategoryIdBitMask)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlertLevel(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "AlertLevel", 8usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).AlertLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlertNotificationControlPoint(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "AlertNotificationControlPoint", 9usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).AlertNotificationControlPoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlertStatus(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "AlertStatus", 10usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).AlertStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GapAppearance(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "GapAppearance", 11usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).GapAppearance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BootKeyboardInputReport(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "BootKeyboardInputReport", 12usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).BootKeyboardInputReport)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BootKeyboardOutputReport(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "BootKeyboardOutputReport", 13usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).BootKeyboardOutputReport)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BootMouseInputReport(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "BootMouseInputReport", 14usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).BootMouseInputReport)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentTime(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "CurrentTime", 15usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).CurrentTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CyclingPowerControlPoint(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "CyclingPowerControlPoint", 16usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).CyclingPowerControlPoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CyclingPowerFeature(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "CyclingPowerFeature", 17usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).CyclingPowerFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CyclingPowerMeasurement(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "CyclingPowerMeasurement", 18usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).CyclingPowerMeasurement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CyclingPowerVector(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "CyclingPowerVector", 19usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).CyclingPowerVector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DateTime(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "DateTime", 20usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).DateTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DayDateTime(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "DayDateTime", 21usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).DayDateTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DayOfWeek(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "DayOfWeek", 22usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).DayOfWeek)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GapDeviceName(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "GapDeviceName", 23usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).GapDeviceName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DstOffset(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "DstOffset", 24usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).DstOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExactTime256(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "ExactTime256", 25usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).ExactTime256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirmwareRevisionString(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "FirmwareRevisionString", 26usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).FirmwareRevisionString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareRevisionString(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "HardwareRevisionString", 27usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).HardwareRevisionString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HidControlPoint(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "HidControlPoint", 28usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).HidControlPoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HidInformation(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "HidInformation", 29usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).HidInformation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ieee1107320601RegulatoryCertificationDataList(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "Ieee1107320601RegulatoryCertificationDataList", 30usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).Ieee1107320601RegulatoryCertificationDataList)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LnControlPoint(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "LnControlPoint", 31usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).LnControlPoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LnFeature(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "LnFeature", 32usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).LnFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalTimeInformation(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "LocalTimeInformation", 33usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).LocalTimeInformation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocationAndSpeed(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "LocationAndSpeed", 34usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).LocationAndSpeed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManufacturerNameString(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "ManufacturerNameString", 35usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).ManufacturerNameString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModelNumberString(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "ModelNumberString", 36usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).ModelNumberString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Navigation(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "Navigation", 37usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).Navigation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewAlert(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "NewAlert", 38usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).NewAlert)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GapPeripheralPreferredConnectionParameters(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "GapPeripheralPreferredConnectionParameters", 39usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).GapPeripheralPreferredConnectionParameters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GapPeripheralPrivacyFlag(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "GapPeripheralPrivacyFlag", 40usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).GapPeripheralPrivacyFlag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PnpId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "PnpId", 41usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).PnpId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionQuality(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "PositionQuality", 42usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).PositionQuality)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtocolMode(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "ProtocolMode", 43usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).ProtocolMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GapReconnectionAddress(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "GapReconnectionAddress", 44usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).GapReconnectionAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReferenceTimeInformation(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "ReferenceTimeInformation", 45usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).ReferenceTimeInformation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Report(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "Report", 46usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).Report)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportMap(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "ReportMap", 47usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).ReportMap)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RingerControlPoint(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "RingerControlPoint", 48usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).RingerControlPoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RingerSetting(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "RingerSetting", 49usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).RingerSetting)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScanIntervalWindow(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "ScanIntervalWindow", 50usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).ScanIntervalWindow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScanRefresh(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "ScanRefresh", 51usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).ScanRefresh)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SerialNumberString(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "SerialNumberString", 52usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).SerialNumberString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GattServiceChanged(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "GattServiceChanged", 53usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).GattServiceChanged)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SoftwareRevisionString(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "SoftwareRevisionString", 54usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).SoftwareRevisionString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedNewAlertCategory(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "SupportedNewAlertCategory", 55usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).SupportedNewAlertCategory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportUnreadAlertCategory(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "SupportUnreadAlertCategory", 56usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).SupportUnreadAlertCategory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "SystemId", 57usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).SystemId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeAccuracy(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "TimeAccuracy", 58usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).TimeAccuracy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeSource(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "TimeSource", 59usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).TimeSource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeUpdateControlPoint(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "TimeUpdateControlPoint", 60usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).TimeUpdateControlPoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeUpdateState(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "TimeUpdateState", 61usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).TimeUpdateState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeWithDst(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "TimeWithDst", 62usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).TimeWithDst)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeZone(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "TimeZone", 63usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).TimeZone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxPowerLevel(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "TxPowerLevel", 64usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).TxPowerLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnreadAlertStatus(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicUuidsStatics2", "UnreadAlertStatus", 65usize, |this| ((**(this as *const *const IGattCharacteristicUuidsStatics2_Vtbl)).UnreadAlertStatus)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AlertCategoryId,
+            AlertCategoryIdBitMask,
+            AlertLevel,
+            AlertNotificationControlPoint,
+            AlertStatus,
+            GapAppearance,
+            BootKeyboardInputReport,
+            BootKeyboardOutputReport,
+            BootMouseInputReport,
+            CurrentTime,
+            CyclingPowerControlPoint,
+            CyclingPowerFeature,
+            CyclingPowerMeasurement,
+            CyclingPowerVector,
+            DateTime,
+            DayDateTime,
+            DayOfWeek,
+            GapDeviceName,
+            DstOffset,
+            ExactTime256,
+            FirmwareRevisionString,
+            HardwareRevisionString,
+            HidControlPoint,
+            HidInformation,
+            Ieee1107320601RegulatoryCertificationDataList,
+            LnControlPoint,
+            LnFeature,
+            LocalTimeInformation,
+            LocationAndSpeed,
+            ManufacturerNameString,
+            ModelNumberString,
+            Navigation,
+            NewAlert,
+            GapPeripheralPreferredConnectionParameters,
+            GapPeripheralPrivacyFlag,
+            PnpId,
+            PositionQuality,
+            ProtocolMode,
+            GapReconnectionAddress,
+            ReferenceTimeInformation,
+            Report,
+            ReportMap,
+            RingerControlPoint,
+            RingerSetting,
+            ScanIntervalWindow,
+            ScanRefresh,
+            SerialNumberString,
+            GattServiceChanged,
+            SoftwareRevisionString,
+            SupportedNewAlertCategory,
+            SupportUnreadAlertCategory,
+            SystemId,
+            TimeAccuracy,
+            TimeSource,
+            TimeUpdateControlPoint,
+            TimeUpdateState,
+            TimeWithDst,
+            TimeZone,
+            TxPowerLevel,
+            UnreadAlertStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattCharacteristicUuidsStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGattCharacteristicsResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GattCommunicationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicsResult", "Status", 6usize, |this| ((**(this as *const *const IGattCharacteristicsResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtocolError(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicsResult", "ProtocolError", 7usize, |this| ((**(this as *const *const IGattCharacteristicsResult_Vtbl)).ProtocolError)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Characteristics(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattCharacteristicsResult", "Characteristics", 8usize, |this| ((**(this as *const *const IGattCharacteristicsResult_Vtbl)).Characteristics)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            ProtocolError,
+            #[cfg(feature = "Foundation_Collections")]
+            Characteristics,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Characteristics: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattCharacteristicsResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattClientNotificationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SubscribedClient(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattClientNotificationResult", "SubscribedClient", 6usize, |this| ((**(this as *const *const IGattClientNotificationResult_Vtbl)).SubscribedClient)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GattCommunicationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattClientNotificationResult", "Status", 7usize, |this| ((**(this as *const *const IGattClientNotificationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtocolError(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattClientNotificationResult", "ProtocolError", 8usize, |this| ((**(this as *const *const IGattClientNotificationResult_Vtbl)).ProtocolError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SubscribedClient, Status, ProtocolError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattClientNotificationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattClientNotificationResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BytesSent(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattClientNotificationResult2", "BytesSent", 6usize, |this| ((**(this as *const *const IGattClientNotificationResult2_Vtbl)).BytesSent)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BytesSent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattClientNotificationResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IGattDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProtectionLevel(this: *mut core::ffi::c_void, result__: *mut GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptor", "ProtectionLevel", 6usize, |this| ((**(this as *const *const IGattDescriptor_Vtbl)).ProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProtectionLevel(this: *mut core::ffi::c_void, value: GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptor", "SetProtectionLevel", 7usize, |this| ((**(this as *const *const IGattDescriptor_Vtbl)).SetProtectionLevel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uuid(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptor", "Uuid", 8usize, |this| ((**(this as *const *const IGattDescriptor_Vtbl)).Uuid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttributeHandle(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptor", "AttributeHandle", 9usize, |this| ((**(this as *const *const IGattDescriptor_Vtbl)).AttributeHandle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadValueAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptor", "ReadValueAsync", 10usize, |this| ((**(this as *const *const IGattDescriptor_Vtbl)).ReadValueAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadValueWithCacheModeAsync(this: *mut core::ffi::c_void, cachemode: super::BluetoothCacheMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptor", "ReadValueWithCacheModeAsync", 11usize, |this| ((**(this as *const *const IGattDescriptor_Vtbl)).ReadValueWithCacheModeAsync)(this, cachemode, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn WriteValueAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptor", "WriteValueAsync", 12usize, |this| ((**(this as *const *const IGattDescriptor_Vtbl)).WriteValueAsync)(this, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProtectionLevel,
+            SetProtectionLevel,
+            Uuid,
+            AttributeHandle,
+            ReadValueAsync,
+            ReadValueWithCacheModeAsync,
+            #[cfg(feature = "Storage_Streams")]
+            WriteValueAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            WriteValueAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IGattDescriptor2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn WriteValueWithResultAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptor2", "WriteValueWithResultAsync", 6usize, |this| ((**(this as *const *const IGattDescriptor2_Vtbl)).WriteValueWithResultAsync)(this, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            WriteValueWithResultAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            WriteValueWithResultAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattDescriptor2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattDescriptorStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ConvertShortIdToUuid(this: *mut core::ffi::c_void, shortid: u16, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptorStatics", "ConvertShortIdToUuid", 6usize, |this| ((**(this as *const *const IGattDescriptorStatics_Vtbl)).ConvertShortIdToUuid)(this, shortid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            ConvertShortIdToUuid,
+            #[cfg(not(feature = "deprecated"))]
+            ConvertShortIdToUuid: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattDescriptorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattDescriptorUuidsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CharacteristicAggregateFormat(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptorUuidsStatics", "CharacteristicAggregateFormat", 6usize, |this| ((**(this as *const *const IGattDescriptorUuidsStatics_Vtbl)).CharacteristicAggregateFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CharacteristicExtendedProperties(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptorUuidsStatics", "CharacteristicExtendedProperties", 7usize, |this| ((**(this as *const *const IGattDescriptorUuidsStatics_Vtbl)).CharacteristicExtendedProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CharacteristicPresentationFormat(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptorUuidsStatics", "CharacteristicPresentationFormat", 8usize, |this| ((**(this as *const *const IGattDescriptorUuidsStatics_Vtbl)).CharacteristicPresentationFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CharacteristicUserDescription(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptorUuidsStatics", "CharacteristicUserDescription", 9usize, |this| ((**(this as *const *const IGattDescriptorUuidsStatics_Vtbl)).CharacteristicUserDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientCharacteristicConfiguration(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptorUuidsStatics", "ClientCharacteristicConfiguration", 10usize, |this| ((**(this as *const *const IGattDescriptorUuidsStatics_Vtbl)).ClientCharacteristicConfiguration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerCharacteristicConfiguration(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptorUuidsStatics", "ServerCharacteristicConfiguration", 11usize, |this| ((**(this as *const *const IGattDescriptorUuidsStatics_Vtbl)).ServerCharacteristicConfiguration)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CharacteristicAggregateFormat,
+            CharacteristicExtendedProperties,
+            CharacteristicPresentationFormat,
+            CharacteristicUserDescription,
+            ClientCharacteristicConfiguration,
+            ServerCharacteristicConfiguration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattDescriptorUuidsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGattDescriptorsResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GattCommunicationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptorsResult", "Status", 6usize, |this| ((**(this as *const *const IGattDescriptorsResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtocolError(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptorsResult", "ProtocolError", 7usize, |this| ((**(this as *const *const IGattDescriptorsResult_Vtbl)).ProtocolError)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Descriptors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDescriptorsResult", "Descriptors", 8usize, |this| ((**(this as *const *const IGattDescriptorsResult_Vtbl)).Descriptors)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            ProtocolError,
+            #[cfg(feature = "Foundation_Collections")]
+            Descriptors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Descriptors: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattDescriptorsResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGattDeviceService_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn GetCharacteristics(this: *mut core::ffi::c_void, characteristicuuid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService", "GetCharacteristics", 6usize, |this| ((**(this as *const *const IGattDeviceService_Vtbl)).GetCharacteristics)(this, characteristicuuid, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn GetIncludedServices(this: *mut core::ffi::c_void, serviceuuid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService", "GetIncludedServices", 7usize, |this| ((**(this as *const *const IGattDeviceService_Vtbl)).GetIncludedServices)(this, serviceuuid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService", "DeviceId", 8usize, |this| ((**(this as *const *const IGattDeviceService_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uuid(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService", "Uuid", 9usize, |this| ((**(this as *const *const IGattDeviceService_Vtbl)).Uuid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttributeHandle(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService", "AttributeHandle", 10usize, |this| ((**(this as *const *const IGattDeviceService_Vtbl)).AttributeHandle)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            GetCharacteristics,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            GetCharacteristics: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            GetIncludedServices,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            GetIncludedServices: 0,
+            DeviceId,
+            Uuid,
+            AttributeHandle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattDeviceService as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGattDeviceService2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Device(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService2", "Device", 6usize, |this| ((**(this as *const *const IGattDeviceService2_Vtbl)).Device)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn ParentServices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService2", "ParentServices", 7usize, |this| ((**(this as *const *const IGattDeviceService2_Vtbl)).ParentServices)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn GetAllCharacteristics(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService2", "GetAllCharacteristics", 8usize, |this| ((**(this as *const *const IGattDeviceService2_Vtbl)).GetAllCharacteristics)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn GetAllIncludedServices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService2", "GetAllIncludedServices", 9usize, |this| ((**(this as *const *const IGattDeviceService2_Vtbl)).GetAllIncludedServices)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Device,
+            #[cfg(not(feature = "deprecated"))]
+            Device: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            ParentServices,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            ParentServices: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            GetAllCharacteristics,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            GetAllCharacteristics: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            GetAllIncludedServices,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            GetAllIncludedServices: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattDeviceService2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Enumeration")]
+impl windows_core::imp::ProxyVtable for IGattDeviceService3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn DeviceAccessInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService3", "DeviceAccessInformation", 6usize, |this| ((**(this as *const *const IGattDeviceService3_Vtbl)).DeviceAccessInformation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService3", "Session", 7usize, |this| ((**(this as *const *const IGattDeviceService3_Vtbl)).Session)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SharingMode(this: *mut core::ffi::c_void, result__: *mut GattSharingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService3", "SharingMode", 8usize, |this| ((**(this as *const *const IGattDeviceService3_Vtbl)).SharingMode)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService3", "RequestAccessAsync", 9usize, |this| ((**(this as *const *const IGattDeviceService3_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenAsync(this: *mut core::ffi::c_void, sharingmode: GattSharingMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService3", "OpenAsync", 10usize, |this| ((**(this as *const *const IGattDeviceService3_Vtbl)).OpenAsync)(this, sharingmode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCharacteristicsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService3", "GetCharacteristicsAsync", 11usize, |this| ((**(this as *const *const IGattDeviceService3_Vtbl)).GetCharacteristicsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCharacteristicsWithCacheModeAsync(this: *mut core::ffi::c_void, cachemode: super::BluetoothCacheMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService3", "GetCharacteristicsWithCacheModeAsync", 12usize, |this| ((**(this as *const *const IGattDeviceService3_Vtbl)).GetCharacteristicsWithCacheModeAsync)(this, cachemode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCharacteristicsForUuidAsync(this: *mut core::ffi::c_void, characteristicuuid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService3", "GetCharacteristicsForUuidAsync", 13usize, |this| ((**(this as *const *const IGattDeviceService3_Vtbl)).GetCharacteristicsForUuidAsync)(this, characteristicuuid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCharacteristicsForUuidWithCacheModeAsync(this: *mut core::ffi::c_void, characteristicuuid: windows_core::GUID, cachemode: super::BluetoothCacheMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService3", "GetCharacteristicsForUuidWithCacheModeAsync", 14usize, |this| ((**(this as *const *const IGattDeviceService3_Vtbl)).GetCharacteristicsForUuidWithCacheModeAsync)(this, characteristicuuid, cachemode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIncludedServicesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService3", "GetIncludedServicesAsync", 15usize, |this| ((**(this as *const *const IGattDeviceService3_Vtbl)).GetIncludedServicesAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIncludedServicesWithCacheModeAsync(this: *mut core::ffi::c_void, cachemode: super::BluetoothCacheMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService3", "GetIncludedServicesWithCacheModeAsync", 16usize, |this| ((**(this as *const *const IGattDeviceService3_Vtbl)).GetIncludedServicesWithCacheModeAsync)(this, cachemode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIncludedServicesForUuidAsync(this: *mut core::ffi::c_void, serviceuuid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService3", "GetIncludedServicesForUuidAsync", 17usize, |this| ((**(this as *const *const IGattDeviceService3_Vtbl)).GetIncludedServicesForUuidAsync)(this, serviceuuid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIncludedServicesForUuidWithCacheModeAsync(this: *mut core::ffi::c_void, serviceuuid: windows_core::GUID, cachemode: super::BluetoothCacheMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceService3", "GetIncludedServicesForUuidWithCacheModeAsync", 18usize, |this| ((**(this as *const *const IGattDeviceService3_Vtbl)).GetIncludedServicesForUuidWithCacheModeAsync)(this, serviceuuid, cachemode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Enumeration")]
+            DeviceAccessInformation,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            DeviceAccessInformation: 0,
+            Session,
+            SharingMode,
+            #[cfg(feature = "Devices_Enumeration")]
+            RequestAccessAsync,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            RequestAccessAsync: 0,
+            OpenAsync,
+            GetCharacteristicsAsync,
+            GetCharacteristicsWithCacheModeAsync,
+            GetCharacteristicsForUuidAsync,
+            GetCharacteristicsForUuidWithCacheModeAsync,
+            GetIncludedServicesAsync,
+            GetIncludedServicesWithCacheModeAsync,
+            GetIncludedServicesForUuidAsync,
+            GetIncludedServicesForUuidWithCacheModeAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattDeviceService3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattDeviceServiceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceServiceStatics", "FromIdAsync", 6usize, |this| ((**(this as *const *const IGattDeviceServiceStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromUuid(this: *mut core::ffi::c_void, serviceuuid: windows_core::GUID, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceServiceStatics", "GetDeviceSelectorFromUuid", 7usize, |this| ((**(this as *const *const IGattDeviceServiceStatics_Vtbl)).GetDeviceSelectorFromUuid)(this, serviceuuid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetDeviceSelectorFromShortId(this: *mut core::ffi::c_void, serviceshortid: u16, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceServiceStatics", "GetDeviceSelectorFromShortId", 8usize, |this| ((**(this as *const *const IGattDeviceServiceStatics_Vtbl)).GetDeviceSelectorFromShortId)(this, serviceshortid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ConvertShortIdToUuid(this: *mut core::ffi::c_void, shortid: u16, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceServiceStatics", "ConvertShortIdToUuid", 9usize, |this| ((**(this as *const *const IGattDeviceServiceStatics_Vtbl)).ConvertShortIdToUuid)(this, shortid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FromIdAsync,
+            GetDeviceSelectorFromUuid,
+            #[cfg(feature = "deprecated")]
+            GetDeviceSelectorFromShortId,
+            #[cfg(not(feature = "deprecated"))]
+            GetDeviceSelectorFromShortId: 0,
+            #[cfg(feature = "deprecated")]
+            ConvertShortIdToUuid,
+            #[cfg(not(feature = "deprecated"))]
+            ConvertShortIdToUuid: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattDeviceServiceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattDeviceServiceStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdWithSharingModeAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, sharingmode: GattSharingMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceServiceStatics2", "FromIdWithSharingModeAsync", 6usize, |this| ((**(this as *const *const IGattDeviceServiceStatics2_Vtbl)).FromIdWithSharingModeAsync)(this, deviceid, sharingmode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorForBluetoothDeviceId(this: *mut core::ffi::c_void, bluetoothdeviceid: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceServiceStatics2", "GetDeviceSelectorForBluetoothDeviceId", 7usize, |this| ((**(this as *const *const IGattDeviceServiceStatics2_Vtbl)).GetDeviceSelectorForBluetoothDeviceId)(this, bluetoothdeviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorForBluetoothDeviceIdWithCacheMode(this: *mut core::ffi::c_void, bluetoothdeviceid: *mut core::ffi::c_void, cachemode: super::BluetoothCacheMode, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceServiceStatics2", "GetDeviceSelectorForBluetoothDeviceIdWithCacheMode", 8usize, |this| ((**(this as *const *const IGattDeviceServiceStatics2_Vtbl)).GetDeviceSelectorForBluetoothDeviceIdWithCacheMode)(this, bluetoothdeviceid, cachemode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorForBluetoothDeviceIdAndUuid(this: *mut core::ffi::c_void, bluetoothdeviceid: *mut core::ffi::c_void, serviceuuid: windows_core::GUID, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceServiceStatics2", "GetDeviceSelectorForBluetoothDeviceIdAndUuid", 9usize, |this| ((**(this as *const *const IGattDeviceServiceStatics2_Vtbl)).GetDeviceSelectorForBluetoothDeviceIdAndUuid)(this, bluetoothdeviceid, serviceuuid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorForBluetoothDeviceIdAndUuidWithCacheMode(this: *mut core::ffi::c_void, bluetoothdeviceid: *mut core::ffi::c_void, serviceuuid: windows_core::GUID, cachemode: super::BluetoothCacheMode, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceServiceStatics2", "GetDeviceSelectorForBluetoothDeviceIdAndUuidWithCacheMode", 10usize, |this| ((**(this as *const *const IGattDeviceServiceStatics2_Vtbl)).GetDeviceSelectorForBluetoothDeviceIdAndUuidWithCacheMode)(this, bluetoothdeviceid, serviceuuid, cachemode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FromIdWithSharingModeAsync,
+            GetDeviceSelectorForBluetoothDeviceId,
+            GetDeviceSelectorForBluetoothDeviceIdWithCacheMode,
+            GetDeviceSelectorForBluetoothDeviceIdAndUuid,
+            GetDeviceSelectorForBluetoothDeviceIdAndUuidWithCacheMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattDeviceServiceStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGattDeviceServicesResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GattCommunicationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceServicesResult", "Status", 6usize, |this| ((**(this as *const *const IGattDeviceServicesResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtocolError(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceServicesResult", "ProtocolError", 7usize, |this| ((**(this as *const *const IGattDeviceServicesResult_Vtbl)).ProtocolError)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Services(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattDeviceServicesResult", "Services", 8usize, |this| ((**(this as *const *const IGattDeviceServicesResult_Vtbl)).Services)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            ProtocolError,
+            #[cfg(feature = "Foundation_Collections")]
+            Services,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Services: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattDeviceServicesResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IGattLocalCharacteristic_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uuid(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "Uuid", 6usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).Uuid)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn StaticValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "StaticValue", 7usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).StaticValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CharacteristicProperties(this: *mut core::ffi::c_void, result__: *mut GattCharacteristicProperties) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "CharacteristicProperties", 8usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).CharacteristicProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadProtectionLevel(this: *mut core::ffi::c_void, result__: *mut GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "ReadProtectionLevel", 9usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).ReadProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteProtectionLevel(this: *mut core::ffi::c_void, result__: *mut GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "WriteProtectionLevel", 10usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).WriteProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDescriptorAsync(this: *mut core::ffi::c_void, descriptoruuid: windows_core::GUID, parameters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "CreateDescriptorAsync", 11usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).CreateDescriptorAsync)(this, descriptoruuid, parameters, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Descriptors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "Descriptors", 12usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).Descriptors)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "UserDescription", 13usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).UserDescription)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PresentationFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "PresentationFormats", 14usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).PresentationFormats)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SubscribedClients(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "SubscribedClients", 15usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).SubscribedClients)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubscribedClientsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "SubscribedClientsChanged", 16usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).SubscribedClientsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSubscribedClientsChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "RemoveSubscribedClientsChanged", 17usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).RemoveSubscribedClientsChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "ReadRequested", 18usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).ReadRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "RemoveReadRequested", 19usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).RemoveReadRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "WriteRequested", 20usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).WriteRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveWriteRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "RemoveWriteRequested", 21usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).RemoveWriteRequested)(this, token), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn NotifyValueAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "NotifyValueAsync", 22usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).NotifyValueAsync)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn NotifyValueForSubscribedClientAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, subscribedclient: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristic", "NotifyValueForSubscribedClientAsync", 23usize, |this| ((**(this as *const *const IGattLocalCharacteristic_Vtbl)).NotifyValueForSubscribedClientAsync)(this, value, subscribedclient, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Uuid,
+            #[cfg(feature = "Storage_Streams")]
+            StaticValue,
+            #[cfg(not(feature = "Storage_Streams"))]
+            StaticValue: 0,
+            CharacteristicProperties,
+            ReadProtectionLevel,
+            WriteProtectionLevel,
+            CreateDescriptorAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            Descriptors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Descriptors: 0,
+            UserDescription,
+            #[cfg(feature = "Foundation_Collections")]
+            PresentationFormats,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PresentationFormats: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SubscribedClients,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SubscribedClients: 0,
+            SubscribedClientsChanged,
+            RemoveSubscribedClientsChanged,
+            ReadRequested,
+            RemoveReadRequested,
+            WriteRequested,
+            RemoveWriteRequested,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            NotifyValueAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            NotifyValueAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            NotifyValueForSubscribedClientAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            NotifyValueForSubscribedClientAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattLocalCharacteristic as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IGattLocalCharacteristicParameters_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetStaticValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristicParameters", "SetStaticValue", 6usize, |this| ((**(this as *const *const IGattLocalCharacteristicParameters_Vtbl)).SetStaticValue)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn StaticValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristicParameters", "StaticValue", 7usize, |this| ((**(this as *const *const IGattLocalCharacteristicParameters_Vtbl)).StaticValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCharacteristicProperties(this: *mut core::ffi::c_void, value: GattCharacteristicProperties) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristicParameters", "SetCharacteristicProperties", 8usize, |this| ((**(this as *const *const IGattLocalCharacteristicParameters_Vtbl)).SetCharacteristicProperties)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CharacteristicProperties(this: *mut core::ffi::c_void, result__: *mut GattCharacteristicProperties) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristicParameters", "CharacteristicProperties", 9usize, |this| ((**(this as *const *const IGattLocalCharacteristicParameters_Vtbl)).CharacteristicProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReadProtectionLevel(this: *mut core::ffi::c_void, value: GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristicParameters", "SetReadProtectionLevel", 10usize, |this| ((**(this as *const *const IGattLocalCharacteristicParameters_Vtbl)).SetReadProtectionLevel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadProtectionLevel(this: *mut core::ffi::c_void, result__: *mut GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristicParameters", "ReadProtectionLevel", 11usize, |this| ((**(this as *const *const IGattLocalCharacteristicParameters_Vtbl)).ReadProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWriteProtectionLevel(this: *mut core::ffi::c_void, value: GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristicParameters", "SetWriteProtectionLevel", 12usize, |this| ((**(this as *const *const IGattLocalCharacteristicParameters_Vtbl)).SetWriteProtectionLevel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteProtectionLevel(this: *mut core::ffi::c_void, result__: *mut GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristicParameters", "WriteProtectionLevel", 13usize, |this| ((**(this as *const *const IGattLocalCharacteristicParameters_Vtbl)).WriteProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristicParameters", "SetUserDescription", 14usize, |this| ((**(this as *const *const IGattLocalCharacteristicParameters_Vtbl)).SetUserDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristicParameters", "UserDescription", 15usize, |this| ((**(this as *const *const IGattLocalCharacteristicParameters_Vtbl)).UserDescription)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PresentationFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristicParameters", "PresentationFormats", 16usize, |this| ((**(this as *const *const IGattLocalCharacteristicParameters_Vtbl)).PresentationFormats)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            SetStaticValue,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetStaticValue: 0,
+            #[cfg(feature = "Storage_Streams")]
+            StaticValue,
+            #[cfg(not(feature = "Storage_Streams"))]
+            StaticValue: 0,
+            SetCharacteristicProperties,
+            CharacteristicProperties,
+            SetReadProtectionLevel,
+            ReadProtectionLevel,
+            SetWriteProtectionLevel,
+            WriteProtectionLevel,
+            SetUserDescription,
+            UserDescription,
+            #[cfg(feature = "Foundation_Collections")]
+            PresentationFormats,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PresentationFormats: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattLocalCharacteristicParameters as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattLocalCharacteristicResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Characteristic(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristicResult", "Characteristic", 6usize, |this| ((**(this as *const *const IGattLocalCharacteristicResult_Vtbl)).Characteristic)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut super::BluetoothError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalCharacteristicResult", "Error", 7usize, |this| ((**(this as *const *const IGattLocalCharacteristicResult_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Characteristic, Error }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattLocalCharacteristicResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IGattLocalDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uuid(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptor", "Uuid", 6usize, |this| ((**(this as *const *const IGattLocalDescriptor_Vtbl)).Uuid)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn StaticValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptor", "StaticValue", 7usize, |this| ((**(this as *const *const IGattLocalDescriptor_Vtbl)).StaticValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadProtectionLevel(this: *mut core::ffi::c_void, result__: *mut GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptor", "ReadProtectionLevel", 8usize, |this| ((**(this as *const *const IGattLocalDescriptor_Vtbl)).ReadProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteProtectionLevel(this: *mut core::ffi::c_void, result__: *mut GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptor", "WriteProtectionLevel", 9usize, |this| ((**(this as *const *const IGattLocalDescriptor_Vtbl)).WriteProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptor", "ReadRequested", 10usize, |this| ((**(this as *const *const IGattLocalDescriptor_Vtbl)).ReadRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptor", "RemoveReadRequested", 11usize, |this| ((**(this as *const *const IGattLocalDescriptor_Vtbl)).RemoveReadRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptor", "WriteRequested", 12usize, |this| ((**(this as *const *const IGattLocalDescriptor_Vtbl)).WriteRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveWriteRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptor", "RemoveWriteRequested", 13usize, |this| ((**(this as *const *const IGattLocalDescriptor_Vtbl)).RemoveWriteRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Uuid,
+            #[cfg(feature = "Storage_Streams")]
+            StaticValue,
+            #[cfg(not(feature = "Storage_Streams"))]
+            StaticValue: 0,
+            ReadProtectionLevel,
+            WriteProtectionLevel,
+            ReadRequested,
+            RemoveReadRequested,
+            WriteRequested,
+            RemoveWriteRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattLocalDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IGattLocalDescriptorParameters_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetStaticValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptorParameters", "SetStaticValue", 6usize, |this| ((**(this as *const *const IGattLocalDescriptorParameters_Vtbl)).SetStaticValue)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn StaticValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptorParameters", "StaticValue", 7usize, |this| ((**(this as *const *const IGattLocalDescriptorParameters_Vtbl)).StaticValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReadProtectionLevel(this: *mut core::ffi::c_void, value: GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptorParameters", "SetReadProtectionLevel", 8usize, |this| ((**(this as *const *const IGattLocalDescriptorParameters_Vtbl)).SetReadProtectionLevel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadProtectionLevel(this: *mut core::ffi::c_void, result__: *mut GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptorParameters", "ReadProtectionLevel", 9usize, |this| ((**(this as *const *const IGattLocalDescriptorParameters_Vtbl)).ReadProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWriteProtectionLevel(this: *mut core::ffi::c_void, value: GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptorParameters", "SetWriteProtectionLevel", 10usize, |this| ((**(this as *const *const IGattLocalDescriptorParameters_Vtbl)).SetWriteProtectionLevel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteProtectionLevel(this: *mut core::ffi::c_void, result__: *mut GattProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptorParameters", "WriteProtectionLevel", 11usize, |this| ((**(this as *const *const IGattLocalDescriptorParameters_Vtbl)).WriteProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            SetStaticValue,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetStaticValue: 0,
+            #[cfg(feature = "Storage_Streams")]
+            StaticValue,
+            #[cfg(not(feature = "Storage_Streams"))]
+            StaticValue: 0,
+            SetReadProtectionLevel,
+            ReadProtectionLevel,
+            SetWriteProtectionLevel,
+            WriteProtectionLevel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattLocalDescriptorParameters as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattLocalDescriptorResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Descriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptorResult", "Descriptor", 6usize, |this| ((**(this as *const *const IGattLocalDescriptorResult_Vtbl)).Descriptor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut super::BluetoothError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalDescriptorResult", "Error", 7usize, |this| ((**(this as *const *const IGattLocalDescriptorResult_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Descriptor, Error }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattLocalDescriptorResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGattLocalService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uuid(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalService", "Uuid", 6usize, |this| ((**(this as *const *const IGattLocalService_Vtbl)).Uuid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCharacteristicAsync(this: *mut core::ffi::c_void, characteristicuuid: windows_core::GUID, parameters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalService", "CreateCharacteristicAsync", 7usize, |this| ((**(this as *const *const IGattLocalService_Vtbl)).CreateCharacteristicAsync)(this, characteristicuuid, parameters, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Characteristics(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattLocalService", "Characteristics", 8usize, |this| ((**(this as *const *const IGattLocalService_Vtbl)).Characteristics)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Uuid,
+            CreateCharacteristicAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            Characteristics,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Characteristics: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattLocalService as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattPresentationFormat_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FormatType(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormat", "FormatType", 6usize, |this| ((**(this as *const *const IGattPresentationFormat_Vtbl)).FormatType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Exponent(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormat", "Exponent", 7usize, |this| ((**(this as *const *const IGattPresentationFormat_Vtbl)).Exponent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unit(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormat", "Unit", 8usize, |this| ((**(this as *const *const IGattPresentationFormat_Vtbl)).Unit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Namespace(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormat", "Namespace", 9usize, |this| ((**(this as *const *const IGattPresentationFormat_Vtbl)).Namespace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormat", "Description", 10usize, |this| ((**(this as *const *const IGattPresentationFormat_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FormatType, Exponent, Unit, Namespace, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattPresentationFormat as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattPresentationFormatStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BluetoothSigAssignedNumbers(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatStatics", "BluetoothSigAssignedNumbers", 6usize, |this| ((**(this as *const *const IGattPresentationFormatStatics_Vtbl)).BluetoothSigAssignedNumbers)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BluetoothSigAssignedNumbers }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattPresentationFormatStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattPresentationFormatStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromParts(this: *mut core::ffi::c_void, formattype: u8, exponent: i32, unit: u16, namespaceid: u8, description: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatStatics2", "FromParts", 6usize, |this| ((**(this as *const *const IGattPresentationFormatStatics2_Vtbl)).FromParts)(this, formattype, exponent, unit, namespaceid, description, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromParts }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattPresentationFormatStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattPresentationFormatTypesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Boolean(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "Boolean", 6usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).Boolean)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bit2(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "Bit2", 7usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).Bit2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Nibble(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "Nibble", 8usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).Nibble)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UInt8(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "UInt8", 9usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).UInt8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UInt12(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "UInt12", 10usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).UInt12)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UInt16(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "UInt16", 11usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).UInt16)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UInt24(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "UInt24", 12usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).UInt24)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UInt32(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "UInt32", 13usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).UInt32)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UInt48(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "UInt48", 14usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).UInt48)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UInt64(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "UInt64", 15usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).UInt64)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UInt128(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "UInt128", 16usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).UInt128)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SInt8(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "SInt8", 17usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).SInt8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SInt12(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "SInt12", 18usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).SInt12)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SInt16(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "SInt16", 19usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).SInt16)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SInt24(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "SInt24", 20usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).SInt24)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SInt32(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "SInt32", 21usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).SInt32)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SInt48(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "SInt48", 22usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).SInt48)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SInt64(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "SInt64", 23usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).SInt64)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SInt128(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "SInt128", 24usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).SInt128)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Float32(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "Float32", 25usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).Float32)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Float64(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "Float64", 26usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).Float64)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SFloat(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "SFloat", 27usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).SFloat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Float(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "Float", 28usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).Float)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DUInt16(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "DUInt16", 29usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).DUInt16)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Utf8(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "Utf8", 30usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).Utf8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Utf16(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "Utf16", 31usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).Utf16)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Struct(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattPresentationFormatTypesStatics", "Struct", 32usize, |this| ((**(this as *const *const IGattPresentationFormatTypesStatics_Vtbl)).Struct)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Boolean,
+            Bit2,
+            Nibble,
+            UInt8,
+            UInt12,
+            UInt16,
+            UInt24,
+            UInt32,
+            UInt48,
+            UInt64,
+            UInt128,
+            SInt8,
+            SInt12,
+            SInt16,
+            SInt24,
+            SInt32,
+            SInt48,
+            SInt64,
+            SInt128,
+            Float32,
+            Float64,
+            SFloat,
+            Float,
+            DUInt16,
+            Utf8,
+            Utf16,
+            Struct,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattPresentationFormatTypesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattProtocolErrorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InvalidHandle(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "InvalidHandle", 6usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).InvalidHandle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadNotPermitted(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "ReadNotPermitted", 7usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).ReadNotPermitted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteNotPermitted(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "WriteNotPermitted", 8usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).WriteNotPermitted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvalidPdu(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "InvalidPdu", 9usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).InvalidPdu)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsufficientAuthentication(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "InsufficientAuthentication", 10usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).InsufficientAuthentication)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestNotSupported(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "RequestNotSupported", 11usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).RequestNotSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvalidOffset(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "InvalidOffset", 12usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).InvalidOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsufficientAuthorization(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "InsufficientAuthorization", 13usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).InsufficientAuthorization)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrepareQueueFull(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "PrepareQueueFull", 14usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).PrepareQueueFull)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttributeNotFound(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "AttributeNotFound", 15usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).AttributeNotFound)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttributeNotLong(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "AttributeNotLong", 16usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).AttributeNotLong)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsufficientEncryptionKeySize(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "InsufficientEncryptionKeySize", 17usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).InsufficientEncryptionKeySize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvalidAttributeValueLength(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "InvalidAttributeValueLength", 18usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).InvalidAttributeValueLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlikelyError(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "UnlikelyError", 19usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).UnlikelyError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsufficientEncryption(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "InsufficientEncryption", 20usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).InsufficientEncryption)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnsupportedGroupType(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "UnsupportedGroupType", 21usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).UnsupportedGroupType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsufficientResources(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattProtocolErrorStatics", "InsufficientResources", 22usize, |this| ((**(this as *const *const IGattProtocolErrorStatics_Vtbl)).InsufficientResources)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InvalidHandle,
+            ReadNotPermitted,
+            WriteNotPermitted,
+            InvalidPdu,
+            InsufficientAuthentication,
+            RequestNotSupported,
+            InvalidOffset,
+            InsufficientAuthorization,
+            PrepareQueueFull,
+            AttributeNotFound,
+            AttributeNotLong,
+            InsufficientEncryptionKeySize,
+            InvalidAttributeValueLength,
+            UnlikelyError,
+            InsufficientEncryption,
+            UnsupportedGroupType,
+            InsufficientResources,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattProtocolErrorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattReadClientCharacteristicConfigurationDescriptorResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GattCommunicationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadClientCharacteristicConfigurationDescriptorResult", "Status", 6usize, |this| ((**(this as *const *const IGattReadClientCharacteristicConfigurationDescriptorResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientCharacteristicConfigurationDescriptor(this: *mut core::ffi::c_void, result__: *mut GattClientCharacteristicConfigurationDescriptorValue) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadClientCharacteristicConfigurationDescriptorResult", "ClientCharacteristicConfigurationDescriptor", 7usize, |this| ((**(this as *const *const IGattReadClientCharacteristicConfigurationDescriptorResult_Vtbl)).ClientCharacteristicConfigurationDescriptor)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, ClientCharacteristicConfigurationDescriptor }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattReadClientCharacteristicConfigurationDescriptorResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattReadClientCharacteristicConfigurationDescriptorResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProtocolError(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadClientCharacteristicConfigurationDescriptorResult2", "ProtocolError", 6usize, |this| ((**(this as *const *const IGattReadClientCharacteristicConfigurationDescriptorResult2_Vtbl)).ProtocolError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProtocolError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattReadClientCharacteristicConfigurationDescriptorResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IGattReadRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadRequest", "Offset", 6usize, |this| ((**(this as *const *const IGattReadRequest_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadRequest", "Length", 7usize, |this| ((**(this as *const *const IGattReadRequest_Vtbl)).Length)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut GattRequestState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadRequest", "State", 8usize, |this| ((**(this as *const *const IGattReadRequest_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadRequest", "StateChanged", 9usize, |this| ((**(this as *const *const IGattReadRequest_Vtbl)).StateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStateChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadRequest", "RemoveStateChanged", 10usize, |this| ((**(this as *const *const IGattReadRequest_Vtbl)).RemoveStateChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn RespondWithValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadRequest", "RespondWithValue", 11usize, |this| ((**(this as *const *const IGattReadRequest_Vtbl)).RespondWithValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RespondWithProtocolError(this: *mut core::ffi::c_void, protocolerror: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadRequest", "RespondWithProtocolError", 12usize, |this| ((**(this as *const *const IGattReadRequest_Vtbl)).RespondWithProtocolError)(this, protocolerror), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Offset,
+            Length,
+            State,
+            StateChanged,
+            RemoveStateChanged,
+            #[cfg(feature = "Storage_Streams")]
+            RespondWithValue,
+            #[cfg(not(feature = "Storage_Streams"))]
+            RespondWithValue: 0,
+            RespondWithProtocolError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattReadRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattReadRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadRequestedEventArgs", "Session", 6usize, |this| ((**(this as *const *const IGattReadRequestedEventArgs_Vtbl)).Session)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadRequestedEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IGattReadRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequestAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadRequestedEventArgs", "GetRequestAsync", 8usize, |this| ((**(this as *const *const IGattReadRequestedEventArgs_Vtbl)).GetRequestAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Session, GetDeferral, GetRequestAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattReadRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IGattReadResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GattCommunicationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadResult", "Status", 6usize, |this| ((**(this as *const *const IGattReadResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadResult", "Value", 7usize, |this| ((**(this as *const *const IGattReadResult_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Storage_Streams")]
+            Value,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Value: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattReadResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattReadResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProtocolError(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReadResult2", "ProtocolError", 6usize, |this| ((**(this as *const *const IGattReadResult2_Vtbl)).ProtocolError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProtocolError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattReadResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IGattReliableWriteTransaction_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn WriteValue(this: *mut core::ffi::c_void, characteristic: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReliableWriteTransaction", "WriteValue", 6usize, |this| ((**(this as *const *const IGattReliableWriteTransaction_Vtbl)).WriteValue)(this, characteristic, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReliableWriteTransaction", "CommitAsync", 7usize, |this| ((**(this as *const *const IGattReliableWriteTransaction_Vtbl)).CommitAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            WriteValue,
+            #[cfg(not(feature = "Storage_Streams"))]
+            WriteValue: 0,
+            CommitAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattReliableWriteTransaction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattReliableWriteTransaction2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CommitWithResultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattReliableWriteTransaction2", "CommitWithResultAsync", 6usize, |this| ((**(this as *const *const IGattReliableWriteTransaction2_Vtbl)).CommitWithResultAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CommitWithResultAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattReliableWriteTransaction2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattRequestStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut GattRequestState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattRequestStateChangedEventArgs", "State", 6usize, |this| ((**(this as *const *const IGattRequestStateChangedEventArgs_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut super::BluetoothError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattRequestStateChangedEventArgs", "Error", 7usize, |this| ((**(this as *const *const IGattRequestStateChangedEventArgs_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, State, Error }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattRequestStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattServiceProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Service(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProvider", "Service", 6usize, |this| ((**(this as *const *const IGattServiceProvider_Vtbl)).Service)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdvertisementStatus(this: *mut core::ffi::c_void, result__: *mut GattServiceProviderAdvertisementStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProvider", "AdvertisementStatus", 7usize, |this| ((**(this as *const *const IGattServiceProvider_Vtbl)).AdvertisementStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdvertisementStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProvider", "AdvertisementStatusChanged", 8usize, |this| ((**(this as *const *const IGattServiceProvider_Vtbl)).AdvertisementStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdvertisementStatusChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProvider", "RemoveAdvertisementStatusChanged", 9usize, |this| ((**(this as *const *const IGattServiceProvider_Vtbl)).RemoveAdvertisementStatusChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAdvertising(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProvider", "StartAdvertising", 10usize, |this| ((**(this as *const *const IGattServiceProvider_Vtbl)).StartAdvertising)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAdvertisingWithParameters(this: *mut core::ffi::c_void, parameters: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProvider", "StartAdvertisingWithParameters", 11usize, |this| ((**(this as *const *const IGattServiceProvider_Vtbl)).StartAdvertisingWithParameters)(this, parameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAdvertising(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProvider", "StopAdvertising", 12usize, |this| ((**(this as *const *const IGattServiceProvider_Vtbl)).StopAdvertising)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Service,
+            AdvertisementStatus,
+            AdvertisementStatusChanged,
+            RemoveAdvertisementStatusChanged,
+            StartAdvertising,
+            StartAdvertisingWithParameters,
+            StopAdvertising,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattServiceProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattServiceProviderAdvertisementStatusChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut super::BluetoothError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderAdvertisementStatusChangedEventArgs", "Error", 6usize, |this| ((**(this as *const *const IGattServiceProviderAdvertisementStatusChangedEventArgs_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GattServiceProviderAdvertisementStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderAdvertisementStatusChangedEventArgs", "Status", 7usize, |this| ((**(this as *const *const IGattServiceProviderAdvertisementStatusChangedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Error, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattServiceProviderAdvertisementStatusChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattServiceProviderAdvertisingParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIsConnectable(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderAdvertisingParameters", "SetIsConnectable", 6usize, |this| ((**(this as *const *const IGattServiceProviderAdvertisingParameters_Vtbl)).SetIsConnectable)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnectable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderAdvertisingParameters", "IsConnectable", 7usize, |this| ((**(this as *const *const IGattServiceProviderAdvertisingParameters_Vtbl)).IsConnectable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsDiscoverable(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderAdvertisingParameters", "SetIsDiscoverable", 8usize, |this| ((**(this as *const *const IGattServiceProviderAdvertisingParameters_Vtbl)).SetIsDiscoverable)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDiscoverable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderAdvertisingParameters", "IsDiscoverable", 9usize, |this| ((**(this as *const *const IGattServiceProviderAdvertisingParameters_Vtbl)).IsDiscoverable)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetIsConnectable,
+            IsConnectable,
+            SetIsDiscoverable,
+            IsDiscoverable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattServiceProviderAdvertisingParameters as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IGattServiceProviderAdvertisingParameters2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetServiceData(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderAdvertisingParameters2", "SetServiceData", 6usize, |this| ((**(this as *const *const IGattServiceProviderAdvertisingParameters2_Vtbl)).SetServiceData)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ServiceData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderAdvertisingParameters2", "ServiceData", 7usize, |this| ((**(this as *const *const IGattServiceProviderAdvertisingParameters2_Vtbl)).ServiceData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            SetServiceData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetServiceData: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ServiceData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ServiceData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattServiceProviderAdvertisingParameters2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattServiceProviderResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut super::BluetoothError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderResult", "Error", 6usize, |this| ((**(this as *const *const IGattServiceProviderResult_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceProvider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderResult", "ServiceProvider", 7usize, |this| ((**(this as *const *const IGattServiceProviderResult_Vtbl)).ServiceProvider)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Error, ServiceProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattServiceProviderResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattServiceProviderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAsync(this: *mut core::ffi::c_void, serviceuuid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceProviderStatics", "CreateAsync", 6usize, |this| ((**(this as *const *const IGattServiceProviderStatics_Vtbl)).CreateAsync)(this, serviceuuid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattServiceProviderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattServiceUuidsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Battery(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics", "Battery", 6usize, |this| ((**(this as *const *const IGattServiceUuidsStatics_Vtbl)).Battery)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BloodPressure(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics", "BloodPressure", 7usize, |this| ((**(this as *const *const IGattServiceUuidsStatics_Vtbl)).BloodPressure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CyclingSpeedAndCadence(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics", "CyclingSpeedAndCadence", 8usize, |this| ((**(this as *const *const IGattServiceUuidsStatics_Vtbl)).CyclingSpeedAndCadence)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenericAccess(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics", "GenericAccess", 9usize, |this| ((**(this as *const *const IGattServiceUuidsStatics_Vtbl)).GenericAccess)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenericAttribute(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics", "GenericAttribute", 10usize, |this| ((**(this as *const *const IGattServiceUuidsStatics_Vtbl)).GenericAttribute)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Glucose(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics", "Glucose", 11usize, |this| ((**(this as *const *const IGattServiceUuidsStatics_Vtbl)).Glucose)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HealthThermometer(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics", "HealthThermometer", 12usize, |this| ((**(this as *const *const IGattServiceUuidsStatics_Vtbl)).HealthThermometer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeartRate(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics", "HeartRate", 13usize, |this| ((**(this as *const *const IGattServiceUuidsStatics_Vtbl)).HeartRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunningSpeedAndCadence(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics", "RunningSpeedAndCadence", 14usize, |this| ((**(this as *const *const IGattServiceUuidsStatics_Vtbl)).RunningSpeedAndCadence)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Battery,
+            BloodPressure,
+            CyclingSpeedAndCadence,
+            GenericAccess,
+            GenericAttribute,
+            Glucose,
+            HealthThermometer,
+            HeartRate,
+            RunningSpeedAndCadence,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattServiceUuidsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattServiceUuidsStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AlertNotification(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics2", "AlertNotification", 6usize, |this| ((**(this as *const *const IGattServiceUuidsStatics2_Vtbl)).AlertNotification)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentTime(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics2", "CurrentTime", 7usize, |this| ((**(this as *const *const IGattServiceUuidsStatics2_Vtbl)).CurrentTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CyclingPower(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics2", "CyclingPower", 8usize, |this| ((**(this as *const *const IGattServiceUuidsStatics2_Vtbl)).CyclingPower)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceInformation(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics2", "DeviceInformation", 9usize, |this| ((**(this as *const *const IGattServiceUuidsStatics2_Vtbl)).DeviceInformation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HumanInterfaceDevice(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics2", "HumanInterfaceDevice", 10usize, |this| ((**(this as *const *const IGattServiceUuidsStatics2_Vtbl)).HumanInterfaceDevice)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImmediateAlert(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics2", "ImmediateAlert", 11usize, |this| ((**(this as *const *const IGattServiceUuidsStatics2_Vtbl)).ImmediateAlert)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LinkLoss(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics2", "LinkLoss", 12usize, |this| ((**(this as *const *const IGattServiceUuidsStatics2_Vtbl)).LinkLoss)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocationAndNavigation(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics2", "LocationAndNavigation", 13usize, |this| ((**(this as *const *const IGattServiceUuidsStatics2_Vtbl)).LocationAndNavigation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextDstChange(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics2", "NextDstChange", 14usize, |this| ((**(this as *const *const IGattServiceUuidsStatics2_Vtbl)).NextDstChange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhoneAlertStatus(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics2", "PhoneAlertStatus", 15usize, |this| ((**(this as *const *const IGattServiceUuidsStatics2_Vtbl)).PhoneAlertStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReferenceTimeUpdate(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics2", "ReferenceTimeUpdate", 16usize, |this| ((**(this as *const *const IGattServiceUuidsStatics2_Vtbl)).ReferenceTimeUpdate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScanParameters(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics2", "ScanParameters", 17usize, |this| ((**(this as *const *const IGattServiceUuidsStatics2_Vtbl)).ScanParameters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxPower(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattServiceUuidsStatics2", "TxPower", 18usize, |this| ((**(this as *const *const IGattServiceUuidsStatics2_Vtbl)).TxPower)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AlertNotification,
+            CurrentTime,
+            CyclingPower,
+            DeviceInformation,
+            HumanInterfaceDevice,
+            ImmediateAlert,
+            LinkLoss,
+            LocationAndNavigation,
+            NextDstChange,
+            PhoneAlertStatus,
+            ReferenceTimeUpdate,
+            ScanParameters,
+            TxPower,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattServiceUuidsStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSession", "DeviceId", 6usize, |this| ((**(this as *const *const IGattSession_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanMaintainConnection(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSession", "CanMaintainConnection", 7usize, |this| ((**(this as *const *const IGattSession_Vtbl)).CanMaintainConnection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaintainConnection(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSession", "SetMaintainConnection", 8usize, |this| ((**(this as *const *const IGattSession_Vtbl)).SetMaintainConnection)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaintainConnection(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSession", "MaintainConnection", 9usize, |this| ((**(this as *const *const IGattSession_Vtbl)).MaintainConnection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPduSize(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSession", "MaxPduSize", 10usize, |this| ((**(this as *const *const IGattSession_Vtbl)).MaxPduSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionStatus(this: *mut core::ffi::c_void, result__: *mut GattSessionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSession", "SessionStatus", 11usize, |this| ((**(this as *const *const IGattSession_Vtbl)).SessionStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPduSizeChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSession", "MaxPduSizeChanged", 12usize, |this| ((**(this as *const *const IGattSession_Vtbl)).MaxPduSizeChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMaxPduSizeChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSession", "RemoveMaxPduSizeChanged", 13usize, |this| ((**(this as *const *const IGattSession_Vtbl)).RemoveMaxPduSizeChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSession", "SessionStatusChanged", 14usize, |this| ((**(this as *const *const IGattSession_Vtbl)).SessionStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSessionStatusChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSession", "RemoveSessionStatusChanged", 15usize, |this| ((**(this as *const *const IGattSession_Vtbl)).RemoveSessionStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            CanMaintainConnection,
+            SetMaintainConnection,
+            MaintainConnection,
+            MaxPduSize,
+            SessionStatus,
+            MaxPduSizeChanged,
+            RemoveMaxPduSizeChanged,
+            SessionStatusChanged,
+            RemoveSessionStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattSessionStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromDeviceIdAsync(this: *mut core::ffi::c_void, deviceid: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSessionStatics", "FromDeviceIdAsync", 6usize, |this| ((**(this as *const *const IGattSessionStatics_Vtbl)).FromDeviceIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromDeviceIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattSessionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattSessionStatusChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut super::BluetoothError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSessionStatusChangedEventArgs", "Error", 6usize, |this| ((**(this as *const *const IGattSessionStatusChangedEventArgs_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GattSessionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSessionStatusChangedEventArgs", "Status", 7usize, |this| ((**(this as *const *const IGattSessionStatusChangedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Error, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattSessionStatusChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattSubscribedClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSubscribedClient", "Session", 6usize, |this| ((**(this as *const *const IGattSubscribedClient_Vtbl)).Session)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxNotificationSize(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSubscribedClient", "MaxNotificationSize", 7usize, |this| ((**(this as *const *const IGattSubscribedClient_Vtbl)).MaxNotificationSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxNotificationSizeChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSubscribedClient", "MaxNotificationSizeChanged", 8usize, |this| ((**(this as *const *const IGattSubscribedClient_Vtbl)).MaxNotificationSizeChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMaxNotificationSizeChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattSubscribedClient", "RemoveMaxNotificationSizeChanged", 9usize, |this| ((**(this as *const *const IGattSubscribedClient_Vtbl)).RemoveMaxNotificationSizeChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Session,
+            MaxNotificationSize,
+            MaxNotificationSizeChanged,
+            RemoveMaxNotificationSizeChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattSubscribedClient as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IGattValueChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CharacteristicValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattValueChangedEventArgs", "CharacteristicValue", 6usize, |this| ((**(this as *const *const IGattValueChangedEventArgs_Vtbl)).CharacteristicValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattValueChangedEventArgs", "Timestamp", 7usize, |this| ((**(this as *const *const IGattValueChangedEventArgs_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CharacteristicValue,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CharacteristicValue: 0,
+            Timestamp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattValueChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IGattWriteRequest_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattWriteRequest", "Value", 6usize, |this| ((**(this as *const *const IGattWriteRequest_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattWriteRequest", "Offset", 7usize, |this| ((**(this as *const *const IGattWriteRequest_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Option(this: *mut core::ffi::c_void, result__: *mut GattWriteOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattWriteRequest", "Option", 8usize, |this| ((**(this as *const *const IGattWriteRequest_Vtbl)).Option)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut GattRequestState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattWriteRequest", "State", 9usize, |this| ((**(this as *const *const IGattWriteRequest_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattWriteRequest", "StateChanged", 10usize, |this| ((**(this as *const *const IGattWriteRequest_Vtbl)).StateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStateChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattWriteRequest", "RemoveStateChanged", 11usize, |this| ((**(this as *const *const IGattWriteRequest_Vtbl)).RemoveStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Respond(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattWriteRequest", "Respond", 12usize, |this| ((**(this as *const *const IGattWriteRequest_Vtbl)).Respond)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RespondWithProtocolError(this: *mut core::ffi::c_void, protocolerror: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattWriteRequest", "RespondWithProtocolError", 13usize, |this| ((**(this as *const *const IGattWriteRequest_Vtbl)).RespondWithProtocolError)(this, protocolerror), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Value,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Value: 0,
+            Offset,
+            Option,
+            State,
+            StateChanged,
+            RemoveStateChanged,
+            Respond,
+            RespondWithProtocolError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattWriteRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattWriteRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattWriteRequestedEventArgs", "Session", 6usize, |this| ((**(this as *const *const IGattWriteRequestedEventArgs_Vtbl)).Session)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattWriteRequestedEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IGattWriteRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequestAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattWriteRequestedEventArgs", "GetRequestAsync", 8usize, |this| ((**(this as *const *const IGattWriteRequestedEventArgs_Vtbl)).GetRequestAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Session, GetDeferral, GetRequestAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattWriteRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGattWriteResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GattCommunicationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattWriteResult", "Status", 6usize, |this| ((**(this as *const *const IGattWriteResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtocolError(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGattWriteResult", "ProtocolError", 7usize, |this| ((**(this as *const *const IGattWriteResult_Vtbl)).ProtocolError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, ProtocolError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGattWriteResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -509,3 +509,5 @@ impl windows_core::RuntimeName for RfcommServiceProvider {
 }
 unsafe impl Send for RfcommServiceProvider {}
 unsafe impl Sync for RfcommServiceProvider {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,296 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Networking_Sockets", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IRfcommDeviceService_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Networking")]
+        unsafe extern "system" fn ConnectionHostName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceService", "ConnectionHostName", 6usize, |this| ((**(this as *const *const IRfcommDeviceService_Vtbl)).ConnectionHostName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionServiceName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceService", "ConnectionServiceName", 7usize, |this| ((**(this as *const *const IRfcommDeviceService_Vtbl)).ConnectionServiceName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceService", "ServiceId", 8usize, |this| ((**(this as *const *const IRfcommDeviceService_Vtbl)).ServiceId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn ProtectionLevel(this: *mut core::ffi::c_void, result__: *mut super::super::super::Networking::Sockets::SocketProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceService", "ProtectionLevel", 9usize, |this| ((**(this as *const *const IRfcommDeviceService_Vtbl)).ProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn MaxProtectionLevel(this: *mut core::ffi::c_void, result__: *mut super::super::super::Networking::Sockets::SocketProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceService", "MaxProtectionLevel", 10usize, |this| ((**(this as *const *const IRfcommDeviceService_Vtbl)).MaxProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GetSdpRawAttributesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceService", "GetSdpRawAttributesAsync", 11usize, |this| ((**(this as *const *const IRfcommDeviceService_Vtbl)).GetSdpRawAttributesAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GetSdpRawAttributesWithCacheModeAsync(this: *mut core::ffi::c_void, cachemode: super::BluetoothCacheMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceService", "GetSdpRawAttributesWithCacheModeAsync", 12usize, |this| ((**(this as *const *const IRfcommDeviceService_Vtbl)).GetSdpRawAttributesWithCacheModeAsync)(this, cachemode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Networking")]
+            ConnectionHostName,
+            #[cfg(not(feature = "Networking"))]
+            ConnectionHostName: 0,
+            ConnectionServiceName,
+            ServiceId,
+            #[cfg(feature = "Networking_Sockets")]
+            ProtectionLevel,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            ProtectionLevel: 0,
+            #[cfg(feature = "Networking_Sockets")]
+            MaxProtectionLevel,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            MaxProtectionLevel: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            GetSdpRawAttributesAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            GetSdpRawAttributesAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            GetSdpRawAttributesWithCacheModeAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            GetSdpRawAttributesWithCacheModeAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRfcommDeviceService as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Networking_Sockets", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IRfcommDeviceService2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Device(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceService2", "Device", 6usize, |this| ((**(this as *const *const IRfcommDeviceService2_Vtbl)).Device)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Device }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRfcommDeviceService2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "Foundation_Collections", feature = "Networking_Sockets", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IRfcommDeviceService3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn DeviceAccessInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceService3", "DeviceAccessInformation", 6usize, |this| ((**(this as *const *const IRfcommDeviceService3_Vtbl)).DeviceAccessInformation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceService3", "RequestAccessAsync", 7usize, |this| ((**(this as *const *const IRfcommDeviceService3_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Enumeration")]
+            DeviceAccessInformation,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            DeviceAccessInformation: 0,
+            #[cfg(feature = "Devices_Enumeration")]
+            RequestAccessAsync,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            RequestAccessAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRfcommDeviceService3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRfcommDeviceServiceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceServiceStatics", "FromIdAsync", 6usize, |this| ((**(this as *const *const IRfcommDeviceServiceStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, serviceid: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceServiceStatics", "GetDeviceSelector", 7usize, |this| ((**(this as *const *const IRfcommDeviceServiceStatics_Vtbl)).GetDeviceSelector)(this, serviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromIdAsync, GetDeviceSelector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRfcommDeviceServiceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRfcommDeviceServiceStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelectorForBluetoothDevice(this: *mut core::ffi::c_void, bluetoothdevice: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceServiceStatics2", "GetDeviceSelectorForBluetoothDevice", 6usize, |this| ((**(this as *const *const IRfcommDeviceServiceStatics2_Vtbl)).GetDeviceSelectorForBluetoothDevice)(this, bluetoothdevice, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorForBluetoothDeviceWithCacheMode(this: *mut core::ffi::c_void, bluetoothdevice: *mut core::ffi::c_void, cachemode: super::BluetoothCacheMode, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceServiceStatics2", "GetDeviceSelectorForBluetoothDeviceWithCacheMode", 7usize, |this| ((**(this as *const *const IRfcommDeviceServiceStatics2_Vtbl)).GetDeviceSelectorForBluetoothDeviceWithCacheMode)(this, bluetoothdevice, cachemode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorForBluetoothDeviceAndServiceId(this: *mut core::ffi::c_void, bluetoothdevice: *mut core::ffi::c_void, serviceid: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceServiceStatics2", "GetDeviceSelectorForBluetoothDeviceAndServiceId", 8usize, |this| ((**(this as *const *const IRfcommDeviceServiceStatics2_Vtbl)).GetDeviceSelectorForBluetoothDeviceAndServiceId)(this, bluetoothdevice, serviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorForBluetoothDeviceAndServiceIdWithCacheMode(this: *mut core::ffi::c_void, bluetoothdevice: *mut core::ffi::c_void, serviceid: *mut core::ffi::c_void, cachemode: super::BluetoothCacheMode, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceServiceStatics2", "GetDeviceSelectorForBluetoothDeviceAndServiceIdWithCacheMode", 9usize, |this| ((**(this as *const *const IRfcommDeviceServiceStatics2_Vtbl)).GetDeviceSelectorForBluetoothDeviceAndServiceIdWithCacheMode)(this, bluetoothdevice, serviceid, cachemode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceSelectorForBluetoothDevice,
+            GetDeviceSelectorForBluetoothDeviceWithCacheMode,
+            GetDeviceSelectorForBluetoothDeviceAndServiceId,
+            GetDeviceSelectorForBluetoothDeviceAndServiceIdWithCacheMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRfcommDeviceServiceStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRfcommDeviceServicesResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut super::BluetoothError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceServicesResult", "Error", 6usize, |this| ((**(this as *const *const IRfcommDeviceServicesResult_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Services(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommDeviceServicesResult", "Services", 7usize, |this| ((**(this as *const *const IRfcommDeviceServicesResult_Vtbl)).Services)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Error,
+            #[cfg(feature = "Foundation_Collections")]
+            Services,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Services: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRfcommDeviceServicesResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRfcommServiceId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uuid(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceId", "Uuid", 6usize, |this| ((**(this as *const *const IRfcommServiceId_Vtbl)).Uuid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsShortId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceId", "AsShortId", 7usize, |this| ((**(this as *const *const IRfcommServiceId_Vtbl)).AsShortId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceId", "AsString", 8usize, |this| ((**(this as *const *const IRfcommServiceId_Vtbl)).AsString)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uuid, AsShortId, AsString }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRfcommServiceId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRfcommServiceIdStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromUuid(this: *mut core::ffi::c_void, uuid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceIdStatics", "FromUuid", 6usize, |this| ((**(this as *const *const IRfcommServiceIdStatics_Vtbl)).FromUuid)(this, uuid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromShortId(this: *mut core::ffi::c_void, shortid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceIdStatics", "FromShortId", 7usize, |this| ((**(this as *const *const IRfcommServiceIdStatics_Vtbl)).FromShortId)(this, shortid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SerialPort(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceIdStatics", "SerialPort", 8usize, |this| ((**(this as *const *const IRfcommServiceIdStatics_Vtbl)).SerialPort)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ObexObjectPush(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceIdStatics", "ObexObjectPush", 9usize, |this| ((**(this as *const *const IRfcommServiceIdStatics_Vtbl)).ObexObjectPush)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ObexFileTransfer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceIdStatics", "ObexFileTransfer", 10usize, |this| ((**(this as *const *const IRfcommServiceIdStatics_Vtbl)).ObexFileTransfer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhoneBookAccessPce(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceIdStatics", "PhoneBookAccessPce", 11usize, |this| ((**(this as *const *const IRfcommServiceIdStatics_Vtbl)).PhoneBookAccessPce)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhoneBookAccessPse(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceIdStatics", "PhoneBookAccessPse", 12usize, |this| ((**(this as *const *const IRfcommServiceIdStatics_Vtbl)).PhoneBookAccessPse)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenericFileTransfer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceIdStatics", "GenericFileTransfer", 13usize, |this| ((**(this as *const *const IRfcommServiceIdStatics_Vtbl)).GenericFileTransfer)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FromUuid,
+            FromShortId,
+            SerialPort,
+            ObexObjectPush,
+            ObexFileTransfer,
+            PhoneBookAccessPce,
+            PhoneBookAccessPse,
+            GenericFileTransfer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRfcommServiceIdStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Networking_Sockets", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IRfcommServiceProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServiceId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceProvider", "ServiceId", 6usize, |this| ((**(this as *const *const IRfcommServiceProvider_Vtbl)).ServiceId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn SdpRawAttributes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceProvider", "SdpRawAttributes", 7usize, |this| ((**(this as *const *const IRfcommServiceProvider_Vtbl)).SdpRawAttributes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn StartAdvertising(this: *mut core::ffi::c_void, listener: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceProvider", "StartAdvertising", 8usize, |this| ((**(this as *const *const IRfcommServiceProvider_Vtbl)).StartAdvertising)(this, listener), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAdvertising(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceProvider", "StopAdvertising", 9usize, |this| ((**(this as *const *const IRfcommServiceProvider_Vtbl)).StopAdvertising)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServiceId,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            SdpRawAttributes,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            SdpRawAttributes: 0,
+            #[cfg(feature = "Networking_Sockets")]
+            StartAdvertising,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            StartAdvertising: 0,
+            StopAdvertising,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRfcommServiceProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Networking_Sockets", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IRfcommServiceProvider2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn StartAdvertisingWithRadioDiscoverability(this: *mut core::ffi::c_void, listener: *mut core::ffi::c_void, radiodiscoverable: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceProvider2", "StartAdvertisingWithRadioDiscoverability", 6usize, |this| ((**(this as *const *const IRfcommServiceProvider2_Vtbl)).StartAdvertisingWithRadioDiscoverability)(this, listener, radiodiscoverable), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Networking_Sockets")]
+            StartAdvertisingWithRadioDiscoverability,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            StartAdvertisingWithRadioDiscoverability: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRfcommServiceProvider2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRfcommServiceProviderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAsync(this: *mut core::ffi::c_void, serviceid: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRfcommServiceProviderStatics", "CreateAsync", 6usize, |this| ((**(this as *const *const IRfcommServiceProviderStatics_Vtbl)).CreateAsync)(this, serviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRfcommServiceProviderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
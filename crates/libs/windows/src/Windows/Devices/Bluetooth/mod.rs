@@ -2057,6 +2057,7 @@ impl BluetoothAddressType {
 impl windows_core::TypeKind for BluetoothAddressType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BluetoothAddressType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BluetoothAddressType").field(&self.0).finish()
@@ -2075,6 +2076,7 @@ impl BluetoothCacheMode {
 impl windows_core::TypeKind for BluetoothCacheMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BluetoothCacheMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BluetoothCacheMode").field(&self.0).finish()
@@ -2093,6 +2095,7 @@ impl BluetoothConnectionStatus {
 impl windows_core::TypeKind for BluetoothConnectionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BluetoothConnectionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BluetoothConnectionStatus").field(&self.0).finish()
@@ -2119,6 +2122,7 @@ impl BluetoothError {
 impl windows_core::TypeKind for BluetoothError {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BluetoothError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BluetoothError").field(&self.0).finish()
@@ -2139,6 +2143,7 @@ impl BluetoothLEPreferredConnectionParametersRequestStatus {
 impl windows_core::TypeKind for BluetoothLEPreferredConnectionParametersRequestStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BluetoothLEPreferredConnectionParametersRequestStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BluetoothLEPreferredConnectionParametersRequestStatus").field(&self.0).finish()
@@ -2165,6 +2170,7 @@ impl BluetoothMajorClass {
 impl windows_core::TypeKind for BluetoothMajorClass {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BluetoothMajorClass {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BluetoothMajorClass").field(&self.0).finish()
@@ -2252,6 +2258,7 @@ impl BluetoothMinorClass {
 impl windows_core::TypeKind for BluetoothMinorClass {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BluetoothMinorClass {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BluetoothMinorClass").field(&self.0).finish()
@@ -2278,6 +2285,7 @@ impl BluetoothServiceCapabilities {
 impl windows_core::TypeKind for BluetoothServiceCapabilities {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BluetoothServiceCapabilities {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BluetoothServiceCapabilities").field(&self.0).finish()
@@ -2319,3 +2327,5 @@ impl core::ops::Not for BluetoothServiceCapabilities {
 impl windows_core::RuntimeType for BluetoothServiceCapabilities {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Bluetooth.BluetoothServiceCapabilities;u4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
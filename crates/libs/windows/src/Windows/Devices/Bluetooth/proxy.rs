@@ -0,0 +1,1110 @@
+#[cfg(feature = "Devices_Radios")]
+impl windows_core::imp::ProxyVtable for IBluetoothAdapter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothAdapter", "DeviceId", 6usize, |this| ((**(this as *const *const IBluetoothAdapter_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BluetoothAddress(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothAdapter", "BluetoothAddress", 7usize, |this| ((**(this as *const *const IBluetoothAdapter_Vtbl)).BluetoothAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsClassicSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothAdapter", "IsClassicSupported", 8usize, |this| ((**(this as *const *const IBluetoothAdapter_Vtbl)).IsClassicSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLowEnergySupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothAdapter", "IsLowEnergySupported", 9usize, |this| ((**(this as *const *const IBluetoothAdapter_Vtbl)).IsLowEnergySupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPeripheralRoleSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothAdapter", "IsPeripheralRoleSupported", 10usize, |this| ((**(this as *const *const IBluetoothAdapter_Vtbl)).IsPeripheralRoleSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCentralRoleSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothAdapter", "IsCentralRoleSupported", 11usize, |this| ((**(this as *const *const IBluetoothAdapter_Vtbl)).IsCentralRoleSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAdvertisementOffloadSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothAdapter", "IsAdvertisementOffloadSupported", 12usize, |this| ((**(this as *const *const IBluetoothAdapter_Vtbl)).IsAdvertisementOffloadSupported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Radios")]
+        unsafe extern "system" fn GetRadioAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothAdapter", "GetRadioAsync", 13usize, |this| ((**(this as *const *const IBluetoothAdapter_Vtbl)).GetRadioAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            BluetoothAddress,
+            IsClassicSupported,
+            IsLowEnergySupported,
+            IsPeripheralRoleSupported,
+            IsCentralRoleSupported,
+            IsAdvertisementOffloadSupported,
+            #[cfg(feature = "Devices_Radios")]
+            GetRadioAsync,
+            #[cfg(not(feature = "Devices_Radios"))]
+            GetRadioAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothAdapter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothAdapter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AreClassicSecureConnectionsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothAdapter2", "AreClassicSecureConnectionsSupported", 6usize, |this| ((**(this as *const *const IBluetoothAdapter2_Vtbl)).AreClassicSecureConnectionsSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AreLowEnergySecureConnectionsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothAdapter2", "AreLowEnergySecureConnectionsSupported", 7usize, |this| ((**(this as *const *const IBluetoothAdapter2_Vtbl)).AreLowEnergySecureConnectionsSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AreClassicSecureConnectionsSupported,
+            AreLowEnergySecureConnectionsSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothAdapter2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothAdapter3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsExtendedAdvertisingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothAdapter3", "IsExtendedAdvertisingSupported", 6usize, |this| ((**(this as *const *const IBluetoothAdapter3_Vtbl)).IsExtendedAdvertisingSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxAdvertisementDataLength(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothAdapter3", "MaxAdvertisementDataLength", 7usize, |this| ((**(this as *const *const IBluetoothAdapter3_Vtbl)).MaxAdvertisementDataLength)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsExtendedAdvertisingSupported, MaxAdvertisementDataLength }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothAdapter3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothAdapterStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothAdapterStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IBluetoothAdapterStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothAdapterStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const IBluetoothAdapterStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothAdapterStatics", "GetDefaultAsync", 8usize, |this| ((**(this as *const *const IBluetoothAdapterStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync, GetDefaultAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothAdapterStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothClassOfDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RawValue(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothClassOfDevice", "RawValue", 6usize, |this| ((**(this as *const *const IBluetoothClassOfDevice_Vtbl)).RawValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MajorClass(this: *mut core::ffi::c_void, result__: *mut BluetoothMajorClass) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothClassOfDevice", "MajorClass", 7usize, |this| ((**(this as *const *const IBluetoothClassOfDevice_Vtbl)).MajorClass)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinorClass(this: *mut core::ffi::c_void, result__: *mut BluetoothMinorClass) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothClassOfDevice", "MinorClass", 8usize, |this| ((**(this as *const *const IBluetoothClassOfDevice_Vtbl)).MinorClass)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceCapabilities(this: *mut core::ffi::c_void, result__: *mut BluetoothServiceCapabilities) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothClassOfDevice", "ServiceCapabilities", 9usize, |this| ((**(this as *const *const IBluetoothClassOfDevice_Vtbl)).ServiceCapabilities)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RawValue, MajorClass, MinorClass, ServiceCapabilities }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothClassOfDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothClassOfDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromRawValue(this: *mut core::ffi::c_void, rawvalue: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothClassOfDeviceStatics", "FromRawValue", 6usize, |this| ((**(this as *const *const IBluetoothClassOfDeviceStatics_Vtbl)).FromRawValue)(this, rawvalue, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromParts(this: *mut core::ffi::c_void, majorclass: BluetoothMajorClass, minorclass: BluetoothMinorClass, servicecapabilities: BluetoothServiceCapabilities, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothClassOfDeviceStatics", "FromParts", 7usize, |this| ((**(this as *const *const IBluetoothClassOfDeviceStatics_Vtbl)).FromParts)(this, majorclass, minorclass, servicecapabilities, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromRawValue, FromParts }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothClassOfDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Bluetooth_Rfcomm", feature = "Foundation_Collections", feature = "Networking", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IBluetoothDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice", "DeviceId", 6usize, |this| ((**(this as *const *const IBluetoothDevice_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking")]
+        unsafe extern "system" fn HostName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice", "HostName", 7usize, |this| ((**(this as *const *const IBluetoothDevice_Vtbl)).HostName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice", "Name", 8usize, |this| ((**(this as *const *const IBluetoothDevice_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClassOfDevice(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice", "ClassOfDevice", 9usize, |this| ((**(this as *const *const IBluetoothDevice_Vtbl)).ClassOfDevice)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn SdpRecords(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice", "SdpRecords", 10usize, |this| ((**(this as *const *const IBluetoothDevice_Vtbl)).SdpRecords)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Bluetooth_Rfcomm", feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn RfcommServices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice", "RfcommServices", 11usize, |this| ((**(this as *const *const IBluetoothDevice_Vtbl)).RfcommServices)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionStatus(this: *mut core::ffi::c_void, result__: *mut BluetoothConnectionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice", "ConnectionStatus", 12usize, |this| ((**(this as *const *const IBluetoothDevice_Vtbl)).ConnectionStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BluetoothAddress(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice", "BluetoothAddress", 13usize, |this| ((**(this as *const *const IBluetoothDevice_Vtbl)).BluetoothAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NameChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice", "NameChanged", 14usize, |this| ((**(this as *const *const IBluetoothDevice_Vtbl)).NameChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNameChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice", "RemoveNameChanged", 15usize, |this| ((**(this as *const *const IBluetoothDevice_Vtbl)).RemoveNameChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SdpRecordsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice", "SdpRecordsChanged", 16usize, |this| ((**(this as *const *const IBluetoothDevice_Vtbl)).SdpRecordsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSdpRecordsChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice", "RemoveSdpRecordsChanged", 17usize, |this| ((**(this as *const *const IBluetoothDevice_Vtbl)).RemoveSdpRecordsChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice", "ConnectionStatusChanged", 18usize, |this| ((**(this as *const *const IBluetoothDevice_Vtbl)).ConnectionStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConnectionStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice", "RemoveConnectionStatusChanged", 19usize, |this| ((**(this as *const *const IBluetoothDevice_Vtbl)).RemoveConnectionStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            #[cfg(feature = "Networking")]
+            HostName,
+            #[cfg(not(feature = "Networking"))]
+            HostName: 0,
+            Name,
+            ClassOfDevice,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            SdpRecords,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            SdpRecords: 0,
+            #[cfg(all(feature = "Devices_Bluetooth_Rfcomm", feature = "Foundation_Collections", feature = "deprecated"))]
+            RfcommServices,
+            #[cfg(not(all(feature = "Devices_Bluetooth_Rfcomm", feature = "Foundation_Collections", feature = "deprecated")))]
+            RfcommServices: 0,
+            ConnectionStatus,
+            BluetoothAddress,
+            NameChanged,
+            RemoveNameChanged,
+            SdpRecordsChanged,
+            RemoveSdpRecordsChanged,
+            ConnectionStatusChanged,
+            RemoveConnectionStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Enumeration")]
+impl windows_core::imp::ProxyVtable for IBluetoothDevice2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn DeviceInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice2", "DeviceInformation", 6usize, |this| ((**(this as *const *const IBluetoothDevice2_Vtbl)).DeviceInformation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Enumeration")]
+            DeviceInformation,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            DeviceInformation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothDevice2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Bluetooth_Rfcomm", feature = "Devices_Enumeration"))]
+impl windows_core::imp::ProxyVtable for IBluetoothDevice3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn DeviceAccessInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice3", "DeviceAccessInformation", 6usize, |this| ((**(this as *const *const IBluetoothDevice3_Vtbl)).DeviceAccessInformation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice3", "RequestAccessAsync", 7usize, |this| ((**(this as *const *const IBluetoothDevice3_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+        unsafe extern "system" fn GetRfcommServicesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice3", "GetRfcommServicesAsync", 8usize, |this| ((**(this as *const *const IBluetoothDevice3_Vtbl)).GetRfcommServicesAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+        unsafe extern "system" fn GetRfcommServicesWithCacheModeAsync(this: *mut core::ffi::c_void, cachemode: BluetoothCacheMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice3", "GetRfcommServicesWithCacheModeAsync", 9usize, |this| ((**(this as *const *const IBluetoothDevice3_Vtbl)).GetRfcommServicesWithCacheModeAsync)(this, cachemode, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+        unsafe extern "system" fn GetRfcommServicesForIdAsync(this: *mut core::ffi::c_void, serviceid: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice3", "GetRfcommServicesForIdAsync", 10usize, |this| ((**(this as *const *const IBluetoothDevice3_Vtbl)).GetRfcommServicesForIdAsync)(this, serviceid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+        unsafe extern "system" fn GetRfcommServicesForIdWithCacheModeAsync(this: *mut core::ffi::c_void, serviceid: *mut core::ffi::c_void, cachemode: BluetoothCacheMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice3", "GetRfcommServicesForIdWithCacheModeAsync", 11usize, |this| ((**(this as *const *const IBluetoothDevice3_Vtbl)).GetRfcommServicesForIdWithCacheModeAsync)(this, serviceid, cachemode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Enumeration")]
+            DeviceAccessInformation,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            DeviceAccessInformation: 0,
+            #[cfg(feature = "Devices_Enumeration")]
+            RequestAccessAsync,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            RequestAccessAsync: 0,
+            #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+            GetRfcommServicesAsync,
+            #[cfg(not(feature = "Devices_Bluetooth_Rfcomm"))]
+            GetRfcommServicesAsync: 0,
+            #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+            GetRfcommServicesWithCacheModeAsync,
+            #[cfg(not(feature = "Devices_Bluetooth_Rfcomm"))]
+            GetRfcommServicesWithCacheModeAsync: 0,
+            #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+            GetRfcommServicesForIdAsync,
+            #[cfg(not(feature = "Devices_Bluetooth_Rfcomm"))]
+            GetRfcommServicesForIdAsync: 0,
+            #[cfg(feature = "Devices_Bluetooth_Rfcomm")]
+            GetRfcommServicesForIdWithCacheModeAsync,
+            #[cfg(not(feature = "Devices_Bluetooth_Rfcomm"))]
+            GetRfcommServicesForIdWithCacheModeAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothDevice3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothDevice4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BluetoothDeviceId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice4", "BluetoothDeviceId", 6usize, |this| ((**(this as *const *const IBluetoothDevice4_Vtbl)).BluetoothDeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BluetoothDeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothDevice4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothDevice5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WasSecureConnectionUsedForPairing(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDevice5", "WasSecureConnectionUsedForPairing", 6usize, |this| ((**(this as *const *const IBluetoothDevice5_Vtbl)).WasSecureConnectionUsedForPairing)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WasSecureConnectionUsedForPairing }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothDevice5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothDeviceId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDeviceId", "Id", 6usize, |this| ((**(this as *const *const IBluetoothDeviceId_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsClassicDevice(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDeviceId", "IsClassicDevice", 7usize, |this| ((**(this as *const *const IBluetoothDeviceId_Vtbl)).IsClassicDevice)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLowEnergyDevice(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDeviceId", "IsLowEnergyDevice", 8usize, |this| ((**(this as *const *const IBluetoothDeviceId_Vtbl)).IsLowEnergyDevice)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, IsClassicDevice, IsLowEnergyDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothDeviceId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothDeviceIdStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromId(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDeviceIdStatics", "FromId", 6usize, |this| ((**(this as *const *const IBluetoothDeviceIdStatics_Vtbl)).FromId)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothDeviceIdStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking")]
+impl windows_core::imp::ProxyVtable for IBluetoothDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDeviceStatics", "FromIdAsync", 6usize, |this| ((**(this as *const *const IBluetoothDeviceStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking")]
+        unsafe extern "system" fn FromHostNameAsync(this: *mut core::ffi::c_void, hostname: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDeviceStatics", "FromHostNameAsync", 7usize, |this| ((**(this as *const *const IBluetoothDeviceStatics_Vtbl)).FromHostNameAsync)(this, hostname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromBluetoothAddressAsync(this: *mut core::ffi::c_void, address: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDeviceStatics", "FromBluetoothAddressAsync", 8usize, |this| ((**(this as *const *const IBluetoothDeviceStatics_Vtbl)).FromBluetoothAddressAsync)(this, address, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDeviceStatics", "GetDeviceSelector", 9usize, |this| ((**(this as *const *const IBluetoothDeviceStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FromIdAsync,
+            #[cfg(feature = "Networking")]
+            FromHostNameAsync,
+            #[cfg(not(feature = "Networking"))]
+            FromHostNameAsync: 0,
+            FromBluetoothAddressAsync,
+            GetDeviceSelector,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothDeviceStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelectorFromPairingState(this: *mut core::ffi::c_void, pairingstate: bool, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDeviceStatics2", "GetDeviceSelectorFromPairingState", 6usize, |this| ((**(this as *const *const IBluetoothDeviceStatics2_Vtbl)).GetDeviceSelectorFromPairingState)(this, pairingstate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromConnectionStatus(this: *mut core::ffi::c_void, connectionstatus: BluetoothConnectionStatus, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDeviceStatics2", "GetDeviceSelectorFromConnectionStatus", 7usize, |this| ((**(this as *const *const IBluetoothDeviceStatics2_Vtbl)).GetDeviceSelectorFromConnectionStatus)(this, connectionstatus, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromDeviceName(this: *mut core::ffi::c_void, devicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDeviceStatics2", "GetDeviceSelectorFromDeviceName", 8usize, |this| ((**(this as *const *const IBluetoothDeviceStatics2_Vtbl)).GetDeviceSelectorFromDeviceName)(this, devicename, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromBluetoothAddress(this: *mut core::ffi::c_void, bluetoothaddress: u64, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDeviceStatics2", "GetDeviceSelectorFromBluetoothAddress", 9usize, |this| ((**(this as *const *const IBluetoothDeviceStatics2_Vtbl)).GetDeviceSelectorFromBluetoothAddress)(this, bluetoothaddress, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromClassOfDevice(this: *mut core::ffi::c_void, classofdevice: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothDeviceStatics2", "GetDeviceSelectorFromClassOfDevice", 10usize, |this| ((**(this as *const *const IBluetoothDeviceStatics2_Vtbl)).GetDeviceSelectorFromClassOfDevice)(this, classofdevice, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceSelectorFromPairingState,
+            GetDeviceSelectorFromConnectionStatus,
+            GetDeviceSelectorFromDeviceName,
+            GetDeviceSelectorFromBluetoothAddress,
+            GetDeviceSelectorFromClassOfDevice,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothDeviceStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAppearance_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RawValue(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearance", "RawValue", 6usize, |this| ((**(this as *const *const IBluetoothLEAppearance_Vtbl)).RawValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Category(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearance", "Category", 7usize, |this| ((**(this as *const *const IBluetoothLEAppearance_Vtbl)).Category)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubCategory(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearance", "SubCategory", 8usize, |this| ((**(this as *const *const IBluetoothLEAppearance_Vtbl)).SubCategory)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RawValue, Category, SubCategory }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAppearance as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAppearanceCategoriesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uncategorized(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "Uncategorized", 6usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).Uncategorized)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Phone(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "Phone", 7usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).Phone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Computer(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "Computer", 8usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).Computer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Watch(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "Watch", 9usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).Watch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clock(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "Clock", 10usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).Clock)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Display(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "Display", 11usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).Display)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteControl(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "RemoteControl", 12usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).RemoteControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EyeGlasses(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "EyeGlasses", 13usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).EyeGlasses)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tag(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "Tag", 14usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).Tag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Keyring(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "Keyring", 15usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).Keyring)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaPlayer(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "MediaPlayer", 16usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).MediaPlayer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BarcodeScanner(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "BarcodeScanner", 17usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).BarcodeScanner)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Thermometer(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "Thermometer", 18usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).Thermometer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeartRate(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "HeartRate", 19usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).HeartRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BloodPressure(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "BloodPressure", 20usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).BloodPressure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HumanInterfaceDevice(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "HumanInterfaceDevice", 21usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).HumanInterfaceDevice)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GlucoseMeter(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "GlucoseMeter", 22usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).GlucoseMeter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunningWalking(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "RunningWalking", 23usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).RunningWalking)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cycling(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "Cycling", 24usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).Cycling)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PulseOximeter(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "PulseOximeter", 25usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).PulseOximeter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WeightScale(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "WeightScale", 26usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).WeightScale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutdoorSportActivity(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceCategoriesStatics", "OutdoorSportActivity", 27usize, |this| ((**(this as *const *const IBluetoothLEAppearanceCategoriesStatics_Vtbl)).OutdoorSportActivity)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Uncategorized,
+            Phone,
+            Computer,
+            Watch,
+            Clock,
+            Display,
+            RemoteControl,
+            EyeGlasses,
+            Tag,
+            Keyring,
+            MediaPlayer,
+            BarcodeScanner,
+            Thermometer,
+            HeartRate,
+            BloodPressure,
+            HumanInterfaceDevice,
+            GlucoseMeter,
+            RunningWalking,
+            Cycling,
+            PulseOximeter,
+            WeightScale,
+            OutdoorSportActivity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAppearanceCategoriesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAppearanceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromRawValue(this: *mut core::ffi::c_void, rawvalue: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceStatics", "FromRawValue", 6usize, |this| ((**(this as *const *const IBluetoothLEAppearanceStatics_Vtbl)).FromRawValue)(this, rawvalue, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromParts(this: *mut core::ffi::c_void, appearancecategory: u16, appearancesubcategory: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceStatics", "FromParts", 7usize, |this| ((**(this as *const *const IBluetoothLEAppearanceStatics_Vtbl)).FromParts)(this, appearancecategory, appearancesubcategory, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromRawValue, FromParts }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAppearanceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEAppearanceSubcategoriesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Generic(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "Generic", 6usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).Generic)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SportsWatch(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "SportsWatch", 7usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).SportsWatch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThermometerEar(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "ThermometerEar", 8usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).ThermometerEar)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeartRateBelt(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "HeartRateBelt", 9usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).HeartRateBelt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BloodPressureArm(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "BloodPressureArm", 10usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).BloodPressureArm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BloodPressureWrist(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "BloodPressureWrist", 11usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).BloodPressureWrist)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Keyboard(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "Keyboard", 12usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).Keyboard)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mouse(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "Mouse", 13usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).Mouse)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Joystick(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "Joystick", 14usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).Joystick)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gamepad(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "Gamepad", 15usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).Gamepad)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DigitizerTablet(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "DigitizerTablet", 16usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).DigitizerTablet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CardReader(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "CardReader", 17usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).CardReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DigitalPen(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "DigitalPen", 18usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).DigitalPen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BarcodeScanner(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "BarcodeScanner", 19usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).BarcodeScanner)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunningWalkingInShoe(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "RunningWalkingInShoe", 20usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).RunningWalkingInShoe)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunningWalkingOnShoe(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "RunningWalkingOnShoe", 21usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).RunningWalkingOnShoe)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunningWalkingOnHip(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "RunningWalkingOnHip", 22usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).RunningWalkingOnHip)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CyclingComputer(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "CyclingComputer", 23usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).CyclingComputer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CyclingSpeedSensor(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "CyclingSpeedSensor", 24usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).CyclingSpeedSensor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CyclingCadenceSensor(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "CyclingCadenceSensor", 25usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).CyclingCadenceSensor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CyclingPowerSensor(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "CyclingPowerSensor", 26usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).CyclingPowerSensor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CyclingSpeedCadenceSensor(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "CyclingSpeedCadenceSensor", 27usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).CyclingSpeedCadenceSensor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OximeterFingertip(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "OximeterFingertip", 28usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).OximeterFingertip)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OximeterWristWorn(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "OximeterWristWorn", 29usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).OximeterWristWorn)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocationDisplay(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "LocationDisplay", 30usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).LocationDisplay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocationNavigationDisplay(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "LocationNavigationDisplay", 31usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).LocationNavigationDisplay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocationPod(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "LocationPod", 32usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).LocationPod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocationNavigationPod(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEAppearanceSubcategoriesStatics", "LocationNavigationPod", 33usize, |this| ((**(this as *const *const IBluetoothLEAppearanceSubcategoriesStatics_Vtbl)).LocationNavigationPod)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Generic,
+            SportsWatch,
+            ThermometerEar,
+            HeartRateBelt,
+            BloodPressureArm,
+            BloodPressureWrist,
+            Keyboard,
+            Mouse,
+            Joystick,
+            Gamepad,
+            DigitizerTablet,
+            CardReader,
+            DigitalPen,
+            BarcodeScanner,
+            RunningWalkingInShoe,
+            RunningWalkingOnShoe,
+            RunningWalkingOnHip,
+            CyclingComputer,
+            CyclingSpeedSensor,
+            CyclingCadenceSensor,
+            CyclingPowerSensor,
+            CyclingSpeedCadenceSensor,
+            OximeterFingertip,
+            OximeterWristWorn,
+            LocationDisplay,
+            LocationNavigationDisplay,
+            LocationPod,
+            LocationNavigationPod,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEAppearanceSubcategoriesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEConnectionParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LinkTimeout(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEConnectionParameters", "LinkTimeout", 6usize, |this| ((**(this as *const *const IBluetoothLEConnectionParameters_Vtbl)).LinkTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionLatency(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEConnectionParameters", "ConnectionLatency", 7usize, |this| ((**(this as *const *const IBluetoothLEConnectionParameters_Vtbl)).ConnectionLatency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionInterval(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEConnectionParameters", "ConnectionInterval", 8usize, |this| ((**(this as *const *const IBluetoothLEConnectionParameters_Vtbl)).ConnectionInterval)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LinkTimeout, ConnectionLatency, ConnectionInterval }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEConnectionParameters as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEConnectionPhy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransmitInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEConnectionPhy", "TransmitInfo", 6usize, |this| ((**(this as *const *const IBluetoothLEConnectionPhy_Vtbl)).TransmitInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceiveInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEConnectionPhy", "ReceiveInfo", 7usize, |this| ((**(this as *const *const IBluetoothLEConnectionPhy_Vtbl)).ReceiveInfo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TransmitInfo, ReceiveInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEConnectionPhy as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEConnectionPhyInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsUncoded1MPhy(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEConnectionPhyInfo", "IsUncoded1MPhy", 6usize, |this| ((**(this as *const *const IBluetoothLEConnectionPhyInfo_Vtbl)).IsUncoded1MPhy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUncoded2MPhy(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEConnectionPhyInfo", "IsUncoded2MPhy", 7usize, |this| ((**(this as *const *const IBluetoothLEConnectionPhyInfo_Vtbl)).IsUncoded2MPhy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCodedPhy(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEConnectionPhyInfo", "IsCodedPhy", 8usize, |this| ((**(this as *const *const IBluetoothLEConnectionPhyInfo_Vtbl)).IsCodedPhy)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsUncoded1MPhy, IsUncoded2MPhy, IsCodedPhy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEConnectionPhyInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Bluetooth_GenericAttributeProfile", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IBluetoothLEDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice", "DeviceId", 6usize, |this| ((**(this as *const *const IBluetoothLEDevice_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice", "Name", 7usize, |this| ((**(this as *const *const IBluetoothLEDevice_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Bluetooth_GenericAttributeProfile", feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn GattServices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice", "GattServices", 8usize, |this| ((**(this as *const *const IBluetoothLEDevice_Vtbl)).GattServices)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionStatus(this: *mut core::ffi::c_void, result__: *mut BluetoothConnectionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice", "ConnectionStatus", 9usize, |this| ((**(this as *const *const IBluetoothLEDevice_Vtbl)).ConnectionStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BluetoothAddress(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice", "BluetoothAddress", 10usize, |this| ((**(this as *const *const IBluetoothLEDevice_Vtbl)).BluetoothAddress)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Bluetooth_GenericAttributeProfile", feature = "deprecated"))]
+        unsafe extern "system" fn GetGattService(this: *mut core::ffi::c_void, serviceuuid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice", "GetGattService", 11usize, |this| ((**(this as *const *const IBluetoothLEDevice_Vtbl)).GetGattService)(this, serviceuuid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NameChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice", "NameChanged", 12usize, |this| ((**(this as *const *const IBluetoothLEDevice_Vtbl)).NameChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNameChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice", "RemoveNameChanged", 13usize, |this| ((**(this as *const *const IBluetoothLEDevice_Vtbl)).RemoveNameChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn GattServicesChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice", "GattServicesChanged", 14usize, |this| ((**(this as *const *const IBluetoothLEDevice_Vtbl)).GattServicesChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGattServicesChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice", "RemoveGattServicesChanged", 15usize, |this| ((**(this as *const *const IBluetoothLEDevice_Vtbl)).RemoveGattServicesChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice", "ConnectionStatusChanged", 16usize, |this| ((**(this as *const *const IBluetoothLEDevice_Vtbl)).ConnectionStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConnectionStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice", "RemoveConnectionStatusChanged", 17usize, |this| ((**(this as *const *const IBluetoothLEDevice_Vtbl)).RemoveConnectionStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            Name,
+            #[cfg(all(feature = "Devices_Bluetooth_GenericAttributeProfile", feature = "Foundation_Collections", feature = "deprecated"))]
+            GattServices,
+            #[cfg(not(all(feature = "Devices_Bluetooth_GenericAttributeProfile", feature = "Foundation_Collections", feature = "deprecated")))]
+            GattServices: 0,
+            ConnectionStatus,
+            BluetoothAddress,
+            #[cfg(all(feature = "Devices_Bluetooth_GenericAttributeProfile", feature = "deprecated"))]
+            GetGattService,
+            #[cfg(not(all(feature = "Devices_Bluetooth_GenericAttributeProfile", feature = "deprecated")))]
+            GetGattService: 0,
+            NameChanged,
+            RemoveNameChanged,
+            GattServicesChanged,
+            RemoveGattServicesChanged,
+            ConnectionStatusChanged,
+            RemoveConnectionStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Enumeration")]
+impl windows_core::imp::ProxyVtable for IBluetoothLEDevice2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn DeviceInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice2", "DeviceInformation", 6usize, |this| ((**(this as *const *const IBluetoothLEDevice2_Vtbl)).DeviceInformation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Appearance(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice2", "Appearance", 7usize, |this| ((**(this as *const *const IBluetoothLEDevice2_Vtbl)).Appearance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BluetoothAddressType(this: *mut core::ffi::c_void, result__: *mut BluetoothAddressType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice2", "BluetoothAddressType", 8usize, |this| ((**(this as *const *const IBluetoothLEDevice2_Vtbl)).BluetoothAddressType)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Enumeration")]
+            DeviceInformation,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            DeviceInformation: 0,
+            Appearance,
+            BluetoothAddressType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEDevice2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Bluetooth_GenericAttributeProfile", feature = "Devices_Enumeration"))]
+impl windows_core::imp::ProxyVtable for IBluetoothLEDevice3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn DeviceAccessInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice3", "DeviceAccessInformation", 6usize, |this| ((**(this as *const *const IBluetoothLEDevice3_Vtbl)).DeviceAccessInformation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice3", "RequestAccessAsync", 7usize, |this| ((**(this as *const *const IBluetoothLEDevice3_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+        unsafe extern "system" fn GetGattServicesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice3", "GetGattServicesAsync", 8usize, |this| ((**(this as *const *const IBluetoothLEDevice3_Vtbl)).GetGattServicesAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+        unsafe extern "system" fn GetGattServicesWithCacheModeAsync(this: *mut core::ffi::c_void, cachemode: BluetoothCacheMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice3", "GetGattServicesWithCacheModeAsync", 9usize, |this| ((**(this as *const *const IBluetoothLEDevice3_Vtbl)).GetGattServicesWithCacheModeAsync)(this, cachemode, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+        unsafe extern "system" fn GetGattServicesForUuidAsync(this: *mut core::ffi::c_void, serviceuuid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice3", "GetGattServicesForUuidAsync", 10usize, |this| ((**(this as *const *const IBluetoothLEDevice3_Vtbl)).GetGattServicesForUuidAsync)(this, serviceuuid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+        unsafe extern "system" fn GetGattServicesForUuidWithCacheModeAsync(this: *mut core::ffi::c_void, serviceuuid: windows_core::GUID, cachemode: BluetoothCacheMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice3", "GetGattServicesForUuidWithCacheModeAsync", 11usize, |this| ((**(this as *const *const IBluetoothLEDevice3_Vtbl)).GetGattServicesForUuidWithCacheModeAsync)(this, serviceuuid, cachemode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Enumeration")]
+            DeviceAccessInformation,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            DeviceAccessInformation: 0,
+            #[cfg(feature = "Devices_Enumeration")]
+            RequestAccessAsync,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            RequestAccessAsync: 0,
+            #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+            GetGattServicesAsync,
+            #[cfg(not(feature = "Devices_Bluetooth_GenericAttributeProfile"))]
+            GetGattServicesAsync: 0,
+            #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+            GetGattServicesWithCacheModeAsync,
+            #[cfg(not(feature = "Devices_Bluetooth_GenericAttributeProfile"))]
+            GetGattServicesWithCacheModeAsync: 0,
+            #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+            GetGattServicesForUuidAsync,
+            #[cfg(not(feature = "Devices_Bluetooth_GenericAttributeProfile"))]
+            GetGattServicesForUuidAsync: 0,
+            #[cfg(feature = "Devices_Bluetooth_GenericAttributeProfile")]
+            GetGattServicesForUuidWithCacheModeAsync,
+            #[cfg(not(feature = "Devices_Bluetooth_GenericAttributeProfile"))]
+            GetGattServicesForUuidWithCacheModeAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEDevice3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEDevice4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BluetoothDeviceId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice4", "BluetoothDeviceId", 6usize, |this| ((**(this as *const *const IBluetoothLEDevice4_Vtbl)).BluetoothDeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BluetoothDeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEDevice4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEDevice5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WasSecureConnectionUsedForPairing(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice5", "WasSecureConnectionUsedForPairing", 6usize, |this| ((**(this as *const *const IBluetoothLEDevice5_Vtbl)).WasSecureConnectionUsedForPairing)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WasSecureConnectionUsedForPairing }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEDevice5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEDevice6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetConnectionParameters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice6", "GetConnectionParameters", 6usize, |this| ((**(this as *const *const IBluetoothLEDevice6_Vtbl)).GetConnectionParameters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectionPhy(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice6", "GetConnectionPhy", 7usize, |this| ((**(this as *const *const IBluetoothLEDevice6_Vtbl)).GetConnectionPhy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPreferredConnectionParameters(this: *mut core::ffi::c_void, preferredconnectionparameters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice6", "RequestPreferredConnectionParameters", 8usize, |this| ((**(this as *const *const IBluetoothLEDevice6_Vtbl)).RequestPreferredConnectionParameters)(this, preferredconnectionparameters, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionParametersChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice6", "ConnectionParametersChanged", 9usize, |this| ((**(this as *const *const IBluetoothLEDevice6_Vtbl)).ConnectionParametersChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConnectionParametersChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice6", "RemoveConnectionParametersChanged", 10usize, |this| ((**(this as *const *const IBluetoothLEDevice6_Vtbl)).RemoveConnectionParametersChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionPhyChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice6", "ConnectionPhyChanged", 11usize, |this| ((**(this as *const *const IBluetoothLEDevice6_Vtbl)).ConnectionPhyChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConnectionPhyChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDevice6", "RemoveConnectionPhyChanged", 12usize, |this| ((**(this as *const *const IBluetoothLEDevice6_Vtbl)).RemoveConnectionPhyChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetConnectionParameters,
+            GetConnectionPhy,
+            RequestPreferredConnectionParameters,
+            ConnectionParametersChanged,
+            RemoveConnectionParametersChanged,
+            ConnectionPhyChanged,
+            RemoveConnectionPhyChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEDevice6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDeviceStatics", "FromIdAsync", 6usize, |this| ((**(this as *const *const IBluetoothLEDeviceStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromBluetoothAddressAsync(this: *mut core::ffi::c_void, bluetoothaddress: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDeviceStatics", "FromBluetoothAddressAsync", 7usize, |this| ((**(this as *const *const IBluetoothLEDeviceStatics_Vtbl)).FromBluetoothAddressAsync)(this, bluetoothaddress, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDeviceStatics", "GetDeviceSelector", 8usize, |this| ((**(this as *const *const IBluetoothLEDeviceStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromIdAsync, FromBluetoothAddressAsync, GetDeviceSelector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEDeviceStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelectorFromPairingState(this: *mut core::ffi::c_void, pairingstate: bool, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDeviceStatics2", "GetDeviceSelectorFromPairingState", 6usize, |this| ((**(this as *const *const IBluetoothLEDeviceStatics2_Vtbl)).GetDeviceSelectorFromPairingState)(this, pairingstate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromConnectionStatus(this: *mut core::ffi::c_void, connectionstatus: BluetoothConnectionStatus, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDeviceStatics2", "GetDeviceSelectorFromConnectionStatus", 7usize, |this| ((**(this as *const *const IBluetoothLEDeviceStatics2_Vtbl)).GetDeviceSelectorFromConnectionStatus)(this, connectionstatus, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromDeviceName(this: *mut core::ffi::c_void, devicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDeviceStatics2", "GetDeviceSelectorFromDeviceName", 8usize, |this| ((**(this as *const *const IBluetoothLEDeviceStatics2_Vtbl)).GetDeviceSelectorFromDeviceName)(this, devicename, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromBluetoothAddress(this: *mut core::ffi::c_void, bluetoothaddress: u64, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDeviceStatics2", "GetDeviceSelectorFromBluetoothAddress", 9usize, |this| ((**(this as *const *const IBluetoothLEDeviceStatics2_Vtbl)).GetDeviceSelectorFromBluetoothAddress)(this, bluetoothaddress, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromBluetoothAddressWithBluetoothAddressType(this: *mut core::ffi::c_void, bluetoothaddress: u64, bluetoothaddresstype: BluetoothAddressType, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDeviceStatics2", "GetDeviceSelectorFromBluetoothAddressWithBluetoothAddressType", 10usize, |this| ((**(this as *const *const IBluetoothLEDeviceStatics2_Vtbl)).GetDeviceSelectorFromBluetoothAddressWithBluetoothAddressType)(this, bluetoothaddress, bluetoothaddresstype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromAppearance(this: *mut core::ffi::c_void, appearance: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDeviceStatics2", "GetDeviceSelectorFromAppearance", 11usize, |this| ((**(this as *const *const IBluetoothLEDeviceStatics2_Vtbl)).GetDeviceSelectorFromAppearance)(this, appearance, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromBluetoothAddressWithBluetoothAddressTypeAsync(this: *mut core::ffi::c_void, bluetoothaddress: u64, bluetoothaddresstype: BluetoothAddressType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEDeviceStatics2", "FromBluetoothAddressWithBluetoothAddressTypeAsync", 12usize, |this| ((**(this as *const *const IBluetoothLEDeviceStatics2_Vtbl)).FromBluetoothAddressWithBluetoothAddressTypeAsync)(this, bluetoothaddress, bluetoothaddresstype, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceSelectorFromPairingState,
+            GetDeviceSelectorFromConnectionStatus,
+            GetDeviceSelectorFromDeviceName,
+            GetDeviceSelectorFromBluetoothAddress,
+            GetDeviceSelectorFromBluetoothAddressWithBluetoothAddressType,
+            GetDeviceSelectorFromAppearance,
+            FromBluetoothAddressWithBluetoothAddressTypeAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEDeviceStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEPreferredConnectionParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LinkTimeout(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEPreferredConnectionParameters", "LinkTimeout", 6usize, |this| ((**(this as *const *const IBluetoothLEPreferredConnectionParameters_Vtbl)).LinkTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionLatency(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEPreferredConnectionParameters", "ConnectionLatency", 7usize, |this| ((**(this as *const *const IBluetoothLEPreferredConnectionParameters_Vtbl)).ConnectionLatency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinConnectionInterval(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEPreferredConnectionParameters", "MinConnectionInterval", 8usize, |this| ((**(this as *const *const IBluetoothLEPreferredConnectionParameters_Vtbl)).MinConnectionInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxConnectionInterval(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEPreferredConnectionParameters", "MaxConnectionInterval", 9usize, |this| ((**(this as *const *const IBluetoothLEPreferredConnectionParameters_Vtbl)).MaxConnectionInterval)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LinkTimeout,
+            ConnectionLatency,
+            MinConnectionInterval,
+            MaxConnectionInterval,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEPreferredConnectionParameters as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEPreferredConnectionParametersRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut BluetoothLEPreferredConnectionParametersRequestStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEPreferredConnectionParametersRequest", "Status", 6usize, |this| ((**(this as *const *const IBluetoothLEPreferredConnectionParametersRequest_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEPreferredConnectionParametersRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothLEPreferredConnectionParametersStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Balanced(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEPreferredConnectionParametersStatics", "Balanced", 6usize, |this| ((**(this as *const *const IBluetoothLEPreferredConnectionParametersStatics_Vtbl)).Balanced)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThroughputOptimized(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEPreferredConnectionParametersStatics", "ThroughputOptimized", 7usize, |this| ((**(this as *const *const IBluetoothLEPreferredConnectionParametersStatics_Vtbl)).ThroughputOptimized)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerOptimized(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothLEPreferredConnectionParametersStatics", "PowerOptimized", 8usize, |this| ((**(this as *const *const IBluetoothLEPreferredConnectionParametersStatics_Vtbl)).PowerOptimized)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Balanced, ThroughputOptimized, PowerOptimized }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothLEPreferredConnectionParametersStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothSignalStrengthFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InRangeThresholdInDBm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothSignalStrengthFilter", "InRangeThresholdInDBm", 6usize, |this| ((**(this as *const *const IBluetoothSignalStrengthFilter_Vtbl)).InRangeThresholdInDBm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInRangeThresholdInDBm(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothSignalStrengthFilter", "SetInRangeThresholdInDBm", 7usize, |this| ((**(this as *const *const IBluetoothSignalStrengthFilter_Vtbl)).SetInRangeThresholdInDBm)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutOfRangeThresholdInDBm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothSignalStrengthFilter", "OutOfRangeThresholdInDBm", 8usize, |this| ((**(this as *const *const IBluetoothSignalStrengthFilter_Vtbl)).OutOfRangeThresholdInDBm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutOfRangeThresholdInDBm(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothSignalStrengthFilter", "SetOutOfRangeThresholdInDBm", 9usize, |this| ((**(this as *const *const IBluetoothSignalStrengthFilter_Vtbl)).SetOutOfRangeThresholdInDBm)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutOfRangeTimeout(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothSignalStrengthFilter", "OutOfRangeTimeout", 10usize, |this| ((**(this as *const *const IBluetoothSignalStrengthFilter_Vtbl)).OutOfRangeTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutOfRangeTimeout(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothSignalStrengthFilter", "SetOutOfRangeTimeout", 11usize, |this| ((**(this as *const *const IBluetoothSignalStrengthFilter_Vtbl)).SetOutOfRangeTimeout)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SamplingInterval(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothSignalStrengthFilter", "SamplingInterval", 12usize, |this| ((**(this as *const *const IBluetoothSignalStrengthFilter_Vtbl)).SamplingInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSamplingInterval(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothSignalStrengthFilter", "SetSamplingInterval", 13usize, |this| ((**(this as *const *const IBluetoothSignalStrengthFilter_Vtbl)).SetSamplingInterval)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InRangeThresholdInDBm,
+            SetInRangeThresholdInDBm,
+            OutOfRangeThresholdInDBm,
+            SetOutOfRangeThresholdInDBm,
+            OutOfRangeTimeout,
+            SetOutOfRangeTimeout,
+            SamplingInterval,
+            SetSamplingInterval,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothSignalStrengthFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBluetoothUuidHelperStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromShortId(this: *mut core::ffi::c_void, shortid: u32, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothUuidHelperStatics", "FromShortId", 6usize, |this| ((**(this as *const *const IBluetoothUuidHelperStatics_Vtbl)).FromShortId)(this, shortid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetShortId(this: *mut core::ffi::c_void, uuid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBluetoothUuidHelperStatics", "TryGetShortId", 7usize, |this| ((**(this as *const *const IBluetoothUuidHelperStatics_Vtbl)).TryGetShortId)(this, uuid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromShortId, TryGetShortId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBluetoothUuidHelperStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -277,6 +277,7 @@ impl DeviceAccessMode {
 impl windows_core::TypeKind for DeviceAccessMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceAccessMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceAccessMode").field(&self.0).finish()
@@ -295,6 +296,7 @@ impl DeviceSharingMode {
 impl windows_core::TypeKind for DeviceSharingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceSharingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceSharingMode").field(&self.0).finish()
@@ -315,6 +317,7 @@ impl IOControlAccessMode {
 impl windows_core::TypeKind for IOControlAccessMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IOControlAccessMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IOControlAccessMode").field(&self.0).finish()
@@ -335,6 +338,7 @@ impl IOControlBufferingMethod {
 impl windows_core::TypeKind for IOControlBufferingMethod {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IOControlBufferingMethod {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IOControlBufferingMethod").field(&self.0).finish()
@@ -345,3 +349,5 @@ impl windows_core::RuntimeType for IOControlBufferingMethod {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
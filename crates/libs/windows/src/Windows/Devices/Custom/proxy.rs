@@ -0,0 +1,114 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ICustomDevice_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn InputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomDevice", "InputStream", 6usize, |this| ((**(this as *const *const ICustomDevice_Vtbl)).InputStream)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn OutputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomDevice", "OutputStream", 7usize, |this| ((**(this as *const *const ICustomDevice_Vtbl)).OutputStream)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SendIOControlAsync(this: *mut core::ffi::c_void, iocontrolcode: *mut core::ffi::c_void, inputbuffer: *mut core::ffi::c_void, outputbuffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomDevice", "SendIOControlAsync", 8usize, |this| ((**(this as *const *const ICustomDevice_Vtbl)).SendIOControlAsync)(this, iocontrolcode, inputbuffer, outputbuffer, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn TrySendIOControlAsync(this: *mut core::ffi::c_void, iocontrolcode: *mut core::ffi::c_void, inputbuffer: *mut core::ffi::c_void, outputbuffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomDevice", "TrySendIOControlAsync", 9usize, |this| ((**(this as *const *const ICustomDevice_Vtbl)).TrySendIOControlAsync)(this, iocontrolcode, inputbuffer, outputbuffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            InputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            InputStream: 0,
+            #[cfg(feature = "Storage_Streams")]
+            OutputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            OutputStream: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SendIOControlAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SendIOControlAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            TrySendIOControlAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            TrySendIOControlAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICustomDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICustomDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, classguid: windows_core::GUID, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomDeviceStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const ICustomDeviceStatics_Vtbl)).GetDeviceSelector)(this, classguid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, desiredaccess: DeviceAccessMode, sharingmode: DeviceSharingMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomDeviceStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const ICustomDeviceStatics_Vtbl)).FromIdAsync)(this, deviceid, desiredaccess, sharingmode, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICustomDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIOControlCode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccessMode(this: *mut core::ffi::c_void, result__: *mut IOControlAccessMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIOControlCode", "AccessMode", 6usize, |this| ((**(this as *const *const IIOControlCode_Vtbl)).AccessMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BufferingMethod(this: *mut core::ffi::c_void, result__: *mut IOControlBufferingMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIOControlCode", "BufferingMethod", 7usize, |this| ((**(this as *const *const IIOControlCode_Vtbl)).BufferingMethod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Function(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIOControlCode", "Function", 8usize, |this| ((**(this as *const *const IIOControlCode_Vtbl)).Function)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceType(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIOControlCode", "DeviceType", 9usize, |this| ((**(this as *const *const IIOControlCode_Vtbl)).DeviceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControlCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIOControlCode", "ControlCode", 10usize, |this| ((**(this as *const *const IIOControlCode_Vtbl)).ControlCode)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AccessMode,
+            BufferingMethod,
+            Function,
+            DeviceType,
+            ControlCode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIOControlCode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIOControlCodeFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateIOControlCode(this: *mut core::ffi::c_void, devicetype: u16, function: u16, accessmode: IOControlAccessMode, bufferingmethod: IOControlBufferingMethod, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIOControlCodeFactory", "CreateIOControlCode", 6usize, |this| ((**(this as *const *const IIOControlCodeFactory_Vtbl)).CreateIOControlCode)(this, devicetype, function, accessmode, bufferingmethod, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateIOControlCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIOControlCodeFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKnownDeviceTypesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Unknown(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownDeviceTypesStatics", "Unknown", 6usize, |this| ((**(this as *const *const IKnownDeviceTypesStatics_Vtbl)).Unknown)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Unknown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownDeviceTypesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
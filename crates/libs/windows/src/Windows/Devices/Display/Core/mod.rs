@@ -2422,6 +2422,7 @@ impl DisplayBitsPerChannel {
 impl windows_core::TypeKind for DisplayBitsPerChannel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayBitsPerChannel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayBitsPerChannel").field(&self.0).finish()
@@ -2472,6 +2473,7 @@ impl DisplayDeviceCapability {
 impl windows_core::TypeKind for DisplayDeviceCapability {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayDeviceCapability {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayDeviceCapability").field(&self.0).finish()
@@ -2491,6 +2493,7 @@ impl DisplayManagerOptions {
 impl windows_core::TypeKind for DisplayManagerOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayManagerOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayManagerOptions").field(&self.0).finish()
@@ -2545,6 +2548,7 @@ impl DisplayManagerResult {
 impl windows_core::TypeKind for DisplayManagerResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayManagerResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayManagerResult").field(&self.0).finish()
@@ -2563,6 +2567,7 @@ impl DisplayModeQueryOptions {
 impl windows_core::TypeKind for DisplayModeQueryOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayModeQueryOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayModeQueryOptions").field(&self.0).finish()
@@ -2618,6 +2623,7 @@ impl DisplayPathScaling {
 impl windows_core::TypeKind for DisplayPathScaling {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayPathScaling {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayPathScaling").field(&self.0).finish()
@@ -2640,6 +2646,7 @@ impl DisplayPathStatus {
 impl windows_core::TypeKind for DisplayPathStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayPathStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayPathStatus").field(&self.0).finish()
@@ -2662,6 +2669,7 @@ impl DisplayPresentStatus {
 impl windows_core::TypeKind for DisplayPresentStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayPresentStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayPresentStatus").field(&self.0).finish()
@@ -2682,6 +2690,7 @@ impl DisplayRotation {
 impl windows_core::TypeKind for DisplayRotation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayRotation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayRotation").field(&self.0).finish()
@@ -2700,6 +2709,7 @@ impl DisplayScanoutOptions {
 impl windows_core::TypeKind for DisplayScanoutOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayScanoutOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayScanoutOptions").field(&self.0).finish()
@@ -2754,6 +2764,7 @@ impl DisplaySourceStatus {
 impl windows_core::TypeKind for DisplaySourceStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplaySourceStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplaySourceStatus").field(&self.0).finish()
@@ -2774,6 +2785,7 @@ impl DisplayStateApplyOptions {
 impl windows_core::TypeKind for DisplayStateApplyOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayStateApplyOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayStateApplyOptions").field(&self.0).finish()
@@ -2826,6 +2838,7 @@ impl DisplayStateFunctionalizeOptions {
 impl windows_core::TypeKind for DisplayStateFunctionalizeOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayStateFunctionalizeOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayStateFunctionalizeOptions").field(&self.0).finish()
@@ -2883,6 +2896,7 @@ impl DisplayStateOperationStatus {
 impl windows_core::TypeKind for DisplayStateOperationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayStateOperationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayStateOperationStatus").field(&self.0).finish()
@@ -2903,6 +2917,7 @@ impl DisplayTargetPersistence {
 impl windows_core::TypeKind for DisplayTargetPersistence {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayTargetPersistence {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayTargetPersistence").field(&self.0).finish()
@@ -2921,6 +2936,7 @@ impl DisplayTaskSignalKind {
 impl windows_core::TypeKind for DisplayTaskSignalKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayTaskSignalKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayTaskSignalKind").field(&self.0).finish()
@@ -2940,6 +2956,7 @@ impl DisplayWireFormatColorSpace {
 impl windows_core::TypeKind for DisplayWireFormatColorSpace {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayWireFormatColorSpace {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayWireFormatColorSpace").field(&self.0).finish()
@@ -2958,6 +2975,7 @@ impl DisplayWireFormatEotf {
 impl windows_core::TypeKind for DisplayWireFormatEotf {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayWireFormatEotf {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayWireFormatEotf").field(&self.0).finish()
@@ -2978,6 +2996,7 @@ impl DisplayWireFormatHdrMetadata {
 impl windows_core::TypeKind for DisplayWireFormatHdrMetadata {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayWireFormatHdrMetadata {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayWireFormatHdrMetadata").field(&self.0).finish()
@@ -2999,6 +3018,7 @@ impl DisplayWireFormatPixelEncoding {
 impl windows_core::TypeKind for DisplayWireFormatPixelEncoding {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayWireFormatPixelEncoding {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayWireFormatPixelEncoding").field(&self.0).finish()
@@ -3009,7 +3029,8 @@ impl windows_core::RuntimeType for DisplayWireFormatPixelEncoding {
 }
 #[repr(C)]
 #[cfg(feature = "Foundation_Numerics")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DisplayPresentationRate {
     pub VerticalSyncRate: super::super::super::Foundation::Numerics::Rational,
     pub VerticalSyncsPerPresentation: i32,
@@ -3028,3 +3049,5 @@ impl Default for DisplayPresentationRate {
         unsafe { core::mem::zeroed() }
     }
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1269 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics"))]
+impl windows_core::imp::ProxyVtable for IDisplayAdapter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::DisplayAdapterId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayAdapter", "Id", 6usize, |this| ((**(this as *const *const IDisplayAdapter_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceInterfacePath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayAdapter", "DeviceInterfacePath", 7usize, |this| ((**(this as *const *const IDisplayAdapter_Vtbl)).DeviceInterfacePath)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayAdapter", "SourceCount", 8usize, |this| ((**(this as *const *const IDisplayAdapter_Vtbl)).SourceCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PciVendorId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayAdapter", "PciVendorId", 9usize, |this| ((**(this as *const *const IDisplayAdapter_Vtbl)).PciVendorId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PciDeviceId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayAdapter", "PciDeviceId", 10usize, |this| ((**(this as *const *const IDisplayAdapter_Vtbl)).PciDeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PciSubSystemId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayAdapter", "PciSubSystemId", 11usize, |this| ((**(this as *const *const IDisplayAdapter_Vtbl)).PciSubSystemId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PciRevision(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayAdapter", "PciRevision", 12usize, |this| ((**(this as *const *const IDisplayAdapter_Vtbl)).PciRevision)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayAdapter", "Properties", 13usize, |this| ((**(this as *const *const IDisplayAdapter_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics")]
+            Id,
+            #[cfg(not(feature = "Graphics"))]
+            Id: 0,
+            DeviceInterfacePath,
+            SourceCount,
+            PciVendorId,
+            PciDeviceId,
+            PciSubSystemId,
+            PciRevision,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayAdapter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayAdapter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsIndirectDisplayDevice(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayAdapter2", "IsIndirectDisplayDevice", 6usize, |this| ((**(this as *const *const IDisplayAdapter2_Vtbl)).IsIndirectDisplayDevice)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredRenderAdapter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayAdapter2", "PreferredRenderAdapter", 7usize, |this| ((**(this as *const *const IDisplayAdapter2_Vtbl)).PreferredRenderAdapter)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsIndirectDisplayDevice, PreferredRenderAdapter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayAdapter2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics")]
+impl windows_core::imp::ProxyVtable for IDisplayAdapterStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn FromId(this: *mut core::ffi::c_void, id: super::super::super::Graphics::DisplayAdapterId, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayAdapterStatics", "FromId", 6usize, |this| ((**(this as *const *const IDisplayAdapterStatics_Vtbl)).FromId)(this, id, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics")]
+            FromId,
+            #[cfg(not(feature = "Graphics"))]
+            FromId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayAdapterStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateScanoutSource(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayDevice", "CreateScanoutSource", 6usize, |this| ((**(this as *const *const IDisplayDevice_Vtbl)).CreateScanoutSource)(this, target, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePrimary(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, desc: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayDevice", "CreatePrimary", 7usize, |this| ((**(this as *const *const IDisplayDevice_Vtbl)).CreatePrimary)(this, target, desc, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTaskPool(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayDevice", "CreateTaskPool", 8usize, |this| ((**(this as *const *const IDisplayDevice_Vtbl)).CreateTaskPool)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePeriodicFence(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, offsetfromvblank: super::super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayDevice", "CreatePeriodicFence", 9usize, |this| ((**(this as *const *const IDisplayDevice_Vtbl)).CreatePeriodicFence)(this, target, offsetfromvblank, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForVBlank(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayDevice", "WaitForVBlank", 10usize, |this| ((**(this as *const *const IDisplayDevice_Vtbl)).WaitForVBlank)(this, source), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSimpleScanout(this: *mut core::ffi::c_void, psource: *mut core::ffi::c_void, psurface: *mut core::ffi::c_void, subresourceindex: u32, syncinterval: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayDevice", "CreateSimpleScanout", 11usize, |this| ((**(this as *const *const IDisplayDevice_Vtbl)).CreateSimpleScanout)(this, psource, psurface, subresourceindex, syncinterval, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCapabilitySupported(this: *mut core::ffi::c_void, capability: DisplayDeviceCapability, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayDevice", "IsCapabilitySupported", 12usize, |this| ((**(this as *const *const IDisplayDevice_Vtbl)).IsCapabilitySupported)(this, capability, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateScanoutSource,
+            CreatePrimary,
+            CreateTaskPool,
+            CreatePeriodicFence,
+            WaitForVBlank,
+            CreateSimpleScanout,
+            IsCapabilitySupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics"))]
+impl windows_core::imp::ProxyVtable for IDisplayDevice2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics"))]
+        unsafe extern "system" fn CreateSimpleScanoutWithDirtyRectsAndOptions(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, surface: *mut core::ffi::c_void, subresourceindex: u32, syncinterval: u32, dirtyrects: *mut core::ffi::c_void, options: DisplayScanoutOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayDevice2", "CreateSimpleScanoutWithDirtyRectsAndOptions", 6usize, |this| ((**(this as *const *const IDisplayDevice2_Vtbl)).CreateSimpleScanoutWithDirtyRectsAndOptions)(this, source, surface, subresourceindex, syncinterval, dirtyrects, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics"))]
+            CreateSimpleScanoutWithDirtyRectsAndOptions,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics")))]
+            CreateSimpleScanoutWithDirtyRectsAndOptions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayDevice2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics")]
+impl windows_core::imp::ProxyVtable for IDisplayDeviceRenderAdapter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn RenderAdapterId(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::DisplayAdapterId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayDeviceRenderAdapter", "RenderAdapterId", 6usize, |this| ((**(this as *const *const IDisplayDeviceRenderAdapter_Vtbl)).RenderAdapterId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics")]
+            RenderAdapterId,
+            #[cfg(not(feature = "Graphics"))]
+            RenderAdapterId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayDeviceRenderAdapter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayFence_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayFence as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDisplayManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCurrentTargets(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "GetCurrentTargets", 6usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).GetCurrentTargets)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCurrentAdapters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "GetCurrentAdapters", 7usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).GetCurrentAdapters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryAcquireTarget(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, result__: *mut DisplayManagerResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "TryAcquireTarget", 8usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).TryAcquireTarget)(this, target, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseTarget(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "ReleaseTarget", 9usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).ReleaseTarget)(this, target), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryReadCurrentStateForAllTargets(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "TryReadCurrentStateForAllTargets", 10usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).TryReadCurrentStateForAllTargets)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TryAcquireTargetsAndReadCurrentState(this: *mut core::ffi::c_void, targets: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "TryAcquireTargetsAndReadCurrentState", 11usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).TryAcquireTargetsAndReadCurrentState)(this, targets, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TryAcquireTargetsAndCreateEmptyState(this: *mut core::ffi::c_void, targets: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "TryAcquireTargetsAndCreateEmptyState", 12usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).TryAcquireTargetsAndCreateEmptyState)(this, targets, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TryAcquireTargetsAndCreateSubstate(this: *mut core::ffi::c_void, existingstate: *mut core::ffi::c_void, targets: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "TryAcquireTargetsAndCreateSubstate", 13usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).TryAcquireTargetsAndCreateSubstate)(this, existingstate, targets, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDisplayDevice(this: *mut core::ffi::c_void, adapter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "CreateDisplayDevice", 14usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).CreateDisplayDevice)(this, adapter, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "Enabled", 15usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).Enabled)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnabled(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "RemoveEnabled", 16usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).RemoveEnabled)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disabled(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "Disabled", 17usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).Disabled)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDisabled(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "RemoveDisabled", 18usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).RemoveDisabled)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Changed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "Changed", 19usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).Changed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "RemoveChanged", 20usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).RemoveChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PathsFailedOrInvalidated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "PathsFailedOrInvalidated", 21usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).PathsFailedOrInvalidated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePathsFailedOrInvalidated(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "RemovePathsFailedOrInvalidated", 22usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).RemovePathsFailedOrInvalidated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "Start", 23usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager", "Stop", 24usize, |this| ((**(this as *const *const IDisplayManager_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCurrentTargets,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCurrentTargets: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCurrentAdapters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCurrentAdapters: 0,
+            TryAcquireTarget,
+            ReleaseTarget,
+            TryReadCurrentStateForAllTargets,
+            #[cfg(feature = "Foundation_Collections")]
+            TryAcquireTargetsAndReadCurrentState,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TryAcquireTargetsAndReadCurrentState: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            TryAcquireTargetsAndCreateEmptyState,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TryAcquireTargetsAndCreateEmptyState: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            TryAcquireTargetsAndCreateSubstate,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TryAcquireTargetsAndCreateSubstate: 0,
+            CreateDisplayDevice,
+            Enabled,
+            RemoveEnabled,
+            Disabled,
+            RemoveDisabled,
+            Changed,
+            RemoveChanged,
+            PathsFailedOrInvalidated,
+            RemovePathsFailedOrInvalidated,
+            Start,
+            Stop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryReadCurrentStateForModeQuery(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager2", "TryReadCurrentStateForModeQuery", 6usize, |this| ((**(this as *const *const IDisplayManager2_Vtbl)).TryReadCurrentStateForModeQuery)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryReadCurrentStateForModeQuery }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayManager3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDisplayDeviceForIndirectAdapter(this: *mut core::ffi::c_void, indirectadapter: *mut core::ffi::c_void, renderadapter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManager3", "CreateDisplayDeviceForIndirectAdapter", 6usize, |this| ((**(this as *const *const IDisplayManager3_Vtbl)).CreateDisplayDeviceForIndirectAdapter)(this, indirectadapter, renderadapter, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateDisplayDeviceForIndirectAdapter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayManager3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayManagerChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerChangedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IDisplayManagerChangedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerChangedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IDisplayManagerChangedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerChangedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IDisplayManagerChangedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayManagerChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayManagerDisabledEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerDisabledEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IDisplayManagerDisabledEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerDisabledEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IDisplayManagerDisabledEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerDisabledEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IDisplayManagerDisabledEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayManagerDisabledEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayManagerEnabledEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerEnabledEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IDisplayManagerEnabledEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerEnabledEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IDisplayManagerEnabledEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerEnabledEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IDisplayManagerEnabledEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayManagerEnabledEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayManagerPathsFailedOrInvalidatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerPathsFailedOrInvalidatedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IDisplayManagerPathsFailedOrInvalidatedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerPathsFailedOrInvalidatedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IDisplayManagerPathsFailedOrInvalidatedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerPathsFailedOrInvalidatedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IDisplayManagerPathsFailedOrInvalidatedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayManagerPathsFailedOrInvalidatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayManagerResultWithState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut DisplayManagerResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerResultWithState", "ErrorCode", 6usize, |this| ((**(this as *const *const IDisplayManagerResultWithState_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedErrorCode(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerResultWithState", "ExtendedErrorCode", 7usize, |this| ((**(this as *const *const IDisplayManagerResultWithState_Vtbl)).ExtendedErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerResultWithState", "State", 8usize, |this| ((**(this as *const *const IDisplayManagerResultWithState_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ErrorCode, ExtendedErrorCode, State }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayManagerResultWithState as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, options: DisplayManagerOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayManagerStatics", "Create", 6usize, |this| ((**(this as *const *const IDisplayManagerStatics_Vtbl)).Create)(this, options, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Foundation_Numerics", feature = "Graphics_DirectX"))]
+impl windows_core::imp::ProxyVtable for IDisplayModeInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn SourceResolution(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::SizeInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayModeInfo", "SourceResolution", 6usize, |this| ((**(this as *const *const IDisplayModeInfo_Vtbl)).SourceResolution)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStereo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayModeInfo", "IsStereo", 7usize, |this| ((**(this as *const *const IDisplayModeInfo_Vtbl)).IsStereo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn SourcePixelFormat(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayModeInfo", "SourcePixelFormat", 8usize, |this| ((**(this as *const *const IDisplayModeInfo_Vtbl)).SourcePixelFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn TargetResolution(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::SizeInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayModeInfo", "TargetResolution", 9usize, |this| ((**(this as *const *const IDisplayModeInfo_Vtbl)).TargetResolution)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn PresentationRate(this: *mut core::ffi::c_void, result__: *mut DisplayPresentationRate) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayModeInfo", "PresentationRate", 10usize, |this| ((**(this as *const *const IDisplayModeInfo_Vtbl)).PresentationRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInterlaced(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayModeInfo", "IsInterlaced", 11usize, |this| ((**(this as *const *const IDisplayModeInfo_Vtbl)).IsInterlaced)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWireFormatSupportedBitsPerChannel(this: *mut core::ffi::c_void, encoding: DisplayWireFormatPixelEncoding, result__: *mut DisplayBitsPerChannel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayModeInfo", "GetWireFormatSupportedBitsPerChannel", 12usize, |this| ((**(this as *const *const IDisplayModeInfo_Vtbl)).GetWireFormatSupportedBitsPerChannel)(this, encoding, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWireFormatSupported(this: *mut core::ffi::c_void, wireformat: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayModeInfo", "IsWireFormatSupported", 13usize, |this| ((**(this as *const *const IDisplayModeInfo_Vtbl)).IsWireFormatSupported)(this, wireformat, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayModeInfo", "Properties", 14usize, |this| ((**(this as *const *const IDisplayModeInfo_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics")]
+            SourceResolution,
+            #[cfg(not(feature = "Graphics"))]
+            SourceResolution: 0,
+            IsStereo,
+            #[cfg(feature = "Graphics_DirectX")]
+            SourcePixelFormat,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            SourcePixelFormat: 0,
+            #[cfg(feature = "Graphics")]
+            TargetResolution,
+            #[cfg(not(feature = "Graphics"))]
+            TargetResolution: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            PresentationRate,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            PresentationRate: 0,
+            IsInterlaced,
+            GetWireFormatSupportedBitsPerChannel,
+            IsWireFormatSupported,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayModeInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IDisplayModeInfo2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn PhysicalPresentationRate(this: *mut core::ffi::c_void, result__: *mut DisplayPresentationRate) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayModeInfo2", "PhysicalPresentationRate", 6usize, |this| ((**(this as *const *const IDisplayModeInfo2_Vtbl)).PhysicalPresentationRate)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            PhysicalPresentationRate,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            PhysicalPresentationRate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayModeInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDisplayMuxDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMuxDevice", "Id", 6usize, |this| ((**(this as *const *const IDisplayMuxDevice_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsActive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMuxDevice", "IsActive", 7usize, |this| ((**(this as *const *const IDisplayMuxDevice_Vtbl)).IsActive)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAvailableMuxTargets(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMuxDevice", "GetAvailableMuxTargets", 8usize, |this| ((**(this as *const *const IDisplayMuxDevice_Vtbl)).GetAvailableMuxTargets)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentTarget(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMuxDevice", "CurrentTarget", 9usize, |this| ((**(this as *const *const IDisplayMuxDevice_Vtbl)).CurrentTarget)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredTarget(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMuxDevice", "PreferredTarget", 10usize, |this| ((**(this as *const *const IDisplayMuxDevice_Vtbl)).PreferredTarget)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAutomaticTargetSwitchingEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMuxDevice", "IsAutomaticTargetSwitchingEnabled", 11usize, |this| ((**(this as *const *const IDisplayMuxDevice_Vtbl)).IsAutomaticTargetSwitchingEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredTarget(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMuxDevice", "SetPreferredTarget", 12usize, |this| ((**(this as *const *const IDisplayMuxDevice_Vtbl)).SetPreferredTarget)(this, target, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutomaticTargetSwitching(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMuxDevice", "SetAutomaticTargetSwitching", 13usize, |this| ((**(this as *const *const IDisplayMuxDevice_Vtbl)).SetAutomaticTargetSwitching)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Changed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMuxDevice", "Changed", 14usize, |this| ((**(this as *const *const IDisplayMuxDevice_Vtbl)).Changed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMuxDevice", "RemoveChanged", 15usize, |this| ((**(this as *const *const IDisplayMuxDevice_Vtbl)).RemoveChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            IsActive,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAvailableMuxTargets,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAvailableMuxTargets: 0,
+            CurrentTarget,
+            PreferredTarget,
+            IsAutomaticTargetSwitchingEnabled,
+            SetPreferredTarget,
+            SetAutomaticTargetSwitching,
+            Changed,
+            RemoveChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayMuxDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayMuxDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMuxDeviceStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IDisplayMuxDeviceStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceinterfaceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMuxDeviceStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const IDisplayMuxDeviceStatics_Vtbl)).FromIdAsync)(this, deviceinterfaceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayMuxDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Foundation_Numerics", feature = "Graphics_DirectX"))]
+impl windows_core::imp::ProxyVtable for IDisplayPath_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn View(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "View", 6usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).View)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Target(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "Target", 7usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).Target)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut DisplayPathStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "Status", 8usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn SourceResolution(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "SourceResolution", 9usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).SourceResolution)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn SetSourceResolution(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "SetSourceResolution", 10usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).SetSourceResolution)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn SourcePixelFormat(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "SourcePixelFormat", 11usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).SourcePixelFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn SetSourcePixelFormat(this: *mut core::ffi::c_void, value: super::super::super::Graphics::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "SetSourcePixelFormat", 12usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).SetSourcePixelFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStereo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "IsStereo", 13usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).IsStereo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsStereo(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "SetIsStereo", 14usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).SetIsStereo)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn TargetResolution(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "TargetResolution", 15usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).TargetResolution)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn SetTargetResolution(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "SetTargetResolution", 16usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).SetTargetResolution)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn PresentationRate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "PresentationRate", 17usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).PresentationRate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetPresentationRate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "SetPresentationRate", 18usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).SetPresentationRate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInterlaced(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "IsInterlaced", 19usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).IsInterlaced)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsInterlaced(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "SetIsInterlaced", 20usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).SetIsInterlaced)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WireFormat(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "WireFormat", 21usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).WireFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWireFormat(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "SetWireFormat", 22usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).SetWireFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rotation(this: *mut core::ffi::c_void, result__: *mut DisplayRotation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "Rotation", 23usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).Rotation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotation(this: *mut core::ffi::c_void, value: DisplayRotation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "SetRotation", 24usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).SetRotation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scaling(this: *mut core::ffi::c_void, result__: *mut DisplayPathScaling) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "Scaling", 25usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).Scaling)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaling(this: *mut core::ffi::c_void, value: DisplayPathScaling) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "SetScaling", 26usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).SetScaling)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindModes(this: *mut core::ffi::c_void, flags: DisplayModeQueryOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "FindModes", 27usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).FindModes)(this, flags, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyPropertiesFromMode(this: *mut core::ffi::c_void, moderesult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "ApplyPropertiesFromMode", 28usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).ApplyPropertiesFromMode)(this, moderesult), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath", "Properties", 29usize, |this| ((**(this as *const *const IDisplayPath_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            View,
+            Target,
+            Status,
+            #[cfg(feature = "Graphics")]
+            SourceResolution,
+            #[cfg(not(feature = "Graphics"))]
+            SourceResolution: 0,
+            #[cfg(feature = "Graphics")]
+            SetSourceResolution,
+            #[cfg(not(feature = "Graphics"))]
+            SetSourceResolution: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            SourcePixelFormat,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            SourcePixelFormat: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            SetSourcePixelFormat,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            SetSourcePixelFormat: 0,
+            IsStereo,
+            SetIsStereo,
+            #[cfg(feature = "Graphics")]
+            TargetResolution,
+            #[cfg(not(feature = "Graphics"))]
+            TargetResolution: 0,
+            #[cfg(feature = "Graphics")]
+            SetTargetResolution,
+            #[cfg(not(feature = "Graphics"))]
+            SetTargetResolution: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            PresentationRate,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            PresentationRate: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetPresentationRate,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetPresentationRate: 0,
+            IsInterlaced,
+            SetIsInterlaced,
+            WireFormat,
+            SetWireFormat,
+            Rotation,
+            SetRotation,
+            Scaling,
+            SetScaling,
+            #[cfg(feature = "Foundation_Collections")]
+            FindModes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindModes: 0,
+            ApplyPropertiesFromMode,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayPath as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IDisplayPath2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn PhysicalPresentationRate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath2", "PhysicalPresentationRate", 6usize, |this| ((**(this as *const *const IDisplayPath2_Vtbl)).PhysicalPresentationRate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetPhysicalPresentationRate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPath2", "SetPhysicalPresentationRate", 7usize, |this| ((**(this as *const *const IDisplayPath2_Vtbl)).SetPhysicalPresentationRate)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            PhysicalPresentationRate,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            PhysicalPresentationRate: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetPhysicalPresentationRate,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetPhysicalPresentationRate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayPath2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX_Direct3D11"))]
+impl windows_core::imp::ProxyVtable for IDisplayPrimaryDescription_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPrimaryDescription", "Width", 6usize, |this| ((**(this as *const *const IDisplayPrimaryDescription_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPrimaryDescription", "Height", 7usize, |this| ((**(this as *const *const IDisplayPrimaryDescription_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPrimaryDescription", "Format", 8usize, |this| ((**(this as *const *const IDisplayPrimaryDescription_Vtbl)).Format)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn ColorSpace(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::DirectX::DirectXColorSpace) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPrimaryDescription", "ColorSpace", 9usize, |this| ((**(this as *const *const IDisplayPrimaryDescription_Vtbl)).ColorSpace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStereo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPrimaryDescription", "IsStereo", 10usize, |this| ((**(this as *const *const IDisplayPrimaryDescription_Vtbl)).IsStereo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn MultisampleDescription(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::DirectX::Direct3D11::Direct3DMultisampleDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPrimaryDescription", "MultisampleDescription", 11usize, |this| ((**(this as *const *const IDisplayPrimaryDescription_Vtbl)).MultisampleDescription)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPrimaryDescription", "Properties", 12usize, |this| ((**(this as *const *const IDisplayPrimaryDescription_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Width,
+            Height,
+            #[cfg(feature = "Graphics_DirectX")]
+            Format,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            Format: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            ColorSpace,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            ColorSpace: 0,
+            IsStereo,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            MultisampleDescription,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            MultisampleDescription: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayPrimaryDescription as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX_Direct3D11")]
+impl windows_core::imp::ProxyVtable for IDisplayPrimaryDescriptionFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, width: u32, height: u32, pixelformat: super::super::super::Graphics::DirectX::DirectXPixelFormat, colorspace: super::super::super::Graphics::DirectX::DirectXColorSpace, isstereo: bool, multisampledescription: super::super::super::Graphics::DirectX::Direct3D11::Direct3DMultisampleDescription, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPrimaryDescriptionFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IDisplayPrimaryDescriptionFactory_Vtbl)).CreateInstance)(this, width, height, pixelformat, colorspace, isstereo, multisampledescription, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            CreateInstance,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            CreateInstance: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayPrimaryDescriptionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX_Direct3D11"))]
+impl windows_core::imp::ProxyVtable for IDisplayPrimaryDescriptionStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX_Direct3D11"))]
+        unsafe extern "system" fn CreateWithProperties(this: *mut core::ffi::c_void, extraproperties: *mut core::ffi::c_void, width: u32, height: u32, pixelformat: super::super::super::Graphics::DirectX::DirectXPixelFormat, colorspace: super::super::super::Graphics::DirectX::DirectXColorSpace, isstereo: bool, multisampledescription: super::super::super::Graphics::DirectX::Direct3D11::Direct3DMultisampleDescription, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPrimaryDescriptionStatics", "CreateWithProperties", 6usize, |this| ((**(this as *const *const IDisplayPrimaryDescriptionStatics_Vtbl)).CreateWithProperties)(this, extraproperties, width, height, pixelformat, colorspace, isstereo, multisampledescription, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX_Direct3D11"))]
+            CreateWithProperties,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics_DirectX_Direct3D11")))]
+            CreateWithProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayPrimaryDescriptionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayScanout_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayScanout as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Graphics", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IDisplaySource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn AdapterId(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::DisplayAdapterId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplaySource", "AdapterId", 6usize, |this| ((**(this as *const *const IDisplaySource_Vtbl)).AdapterId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplaySource", "SourceId", 7usize, |this| ((**(this as *const *const IDisplaySource_Vtbl)).SourceId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetMetadata(this: *mut core::ffi::c_void, key: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplaySource", "GetMetadata", 8usize, |this| ((**(this as *const *const IDisplaySource_Vtbl)).GetMetadata)(this, key, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics")]
+            AdapterId,
+            #[cfg(not(feature = "Graphics"))]
+            AdapterId: 0,
+            SourceId,
+            #[cfg(feature = "Storage_Streams")]
+            GetMetadata,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetMetadata: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplaySource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplaySource2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut DisplaySourceStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplaySource2", "Status", 6usize, |this| ((**(this as *const *const IDisplaySource2_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplaySource2", "StatusChanged", 7usize, |this| ((**(this as *const *const IDisplaySource2_Vtbl)).StatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplaySource2", "RemoveStatusChanged", 8usize, |this| ((**(this as *const *const IDisplaySource2_Vtbl)).RemoveStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, StatusChanged, RemoveStatusChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplaySource2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDisplayState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsReadOnly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayState", "IsReadOnly", 6usize, |this| ((**(this as *const *const IDisplayState_Vtbl)).IsReadOnly)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStale(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayState", "IsStale", 7usize, |this| ((**(this as *const *const IDisplayState_Vtbl)).IsStale)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Targets(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayState", "Targets", 8usize, |this| ((**(this as *const *const IDisplayState_Vtbl)).Targets)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Views(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayState", "Views", 9usize, |this| ((**(this as *const *const IDisplayState_Vtbl)).Views)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayState", "Properties", 10usize, |this| ((**(this as *const *const IDisplayState_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectTarget(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayState", "ConnectTarget", 11usize, |this| ((**(this as *const *const IDisplayState_Vtbl)).ConnectTarget)(this, target, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectTargetToView(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, view: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayState", "ConnectTargetToView", 12usize, |this| ((**(this as *const *const IDisplayState_Vtbl)).ConnectTargetToView)(this, target, view, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanConnectTargetToView(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, view: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayState", "CanConnectTargetToView", 13usize, |this| ((**(this as *const *const IDisplayState_Vtbl)).CanConnectTargetToView)(this, target, view, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetViewForTarget(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayState", "GetViewForTarget", 14usize, |this| ((**(this as *const *const IDisplayState_Vtbl)).GetViewForTarget)(this, target, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPathForTarget(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayState", "GetPathForTarget", 15usize, |this| ((**(this as *const *const IDisplayState_Vtbl)).GetPathForTarget)(this, target, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectTarget(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayState", "DisconnectTarget", 16usize, |this| ((**(this as *const *const IDisplayState_Vtbl)).DisconnectTarget)(this, target), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryFunctionalize(this: *mut core::ffi::c_void, options: DisplayStateFunctionalizeOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayState", "TryFunctionalize", 17usize, |this| ((**(this as *const *const IDisplayState_Vtbl)).TryFunctionalize)(this, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryApply(this: *mut core::ffi::c_void, options: DisplayStateApplyOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayState", "TryApply", 18usize, |this| ((**(this as *const *const IDisplayState_Vtbl)).TryApply)(this, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayState", "Clone", 19usize, |this| ((**(this as *const *const IDisplayState_Vtbl)).Clone)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsReadOnly,
+            IsStale,
+            #[cfg(feature = "Foundation_Collections")]
+            Targets,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Targets: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Views,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Views: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            ConnectTarget,
+            ConnectTargetToView,
+            CanConnectTargetToView,
+            GetViewForTarget,
+            GetPathForTarget,
+            DisconnectTarget,
+            TryFunctionalize,
+            TryApply,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayState as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayStateOperationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut DisplayStateOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayStateOperationResult", "Status", 6usize, |this| ((**(this as *const *const IDisplayStateOperationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedErrorCode(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayStateOperationResult", "ExtendedErrorCode", 7usize, |this| ((**(this as *const *const IDisplayStateOperationResult_Vtbl)).ExtendedErrorCode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, ExtendedErrorCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayStateOperationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplaySurface_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplaySurface as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDisplayTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Adapter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTarget", "Adapter", 6usize, |this| ((**(this as *const *const IDisplayTarget_Vtbl)).Adapter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceInterfacePath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTarget", "DeviceInterfacePath", 7usize, |this| ((**(this as *const *const IDisplayTarget_Vtbl)).DeviceInterfacePath)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdapterRelativeId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTarget", "AdapterRelativeId", 8usize, |this| ((**(this as *const *const IDisplayTarget_Vtbl)).AdapterRelativeId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTarget", "IsConnected", 9usize, |this| ((**(this as *const *const IDisplayTarget_Vtbl)).IsConnected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVirtualModeEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTarget", "IsVirtualModeEnabled", 10usize, |this| ((**(this as *const *const IDisplayTarget_Vtbl)).IsVirtualModeEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVirtualTopologyEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTarget", "IsVirtualTopologyEnabled", 11usize, |this| ((**(this as *const *const IDisplayTarget_Vtbl)).IsVirtualTopologyEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsageKind(this: *mut core::ffi::c_void, result__: *mut super::DisplayMonitorUsageKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTarget", "UsageKind", 12usize, |this| ((**(this as *const *const IDisplayTarget_Vtbl)).UsageKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonitorPersistence(this: *mut core::ffi::c_void, result__: *mut DisplayTargetPersistence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTarget", "MonitorPersistence", 13usize, |this| ((**(this as *const *const IDisplayTarget_Vtbl)).MonitorPersistence)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StableMonitorId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTarget", "StableMonitorId", 14usize, |this| ((**(this as *const *const IDisplayTarget_Vtbl)).StableMonitorId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetMonitor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTarget", "TryGetMonitor", 15usize, |this| ((**(this as *const *const IDisplayTarget_Vtbl)).TryGetMonitor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTarget", "Properties", 16usize, |this| ((**(this as *const *const IDisplayTarget_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStale(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTarget", "IsStale", 17usize, |this| ((**(this as *const *const IDisplayTarget_Vtbl)).IsStale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSame(this: *mut core::ffi::c_void, othertarget: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTarget", "IsSame", 18usize, |this| ((**(this as *const *const IDisplayTarget_Vtbl)).IsSame)(this, othertarget, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, othertarget: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTarget", "IsEqual", 19usize, |this| ((**(this as *const *const IDisplayTarget_Vtbl)).IsEqual)(this, othertarget, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Adapter,
+            DeviceInterfacePath,
+            AdapterRelativeId,
+            IsConnected,
+            IsVirtualModeEnabled,
+            IsVirtualTopologyEnabled,
+            UsageKind,
+            MonitorPersistence,
+            StableMonitorId,
+            TryGetMonitor,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            IsStale,
+            IsSame,
+            IsEqual,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayTarget as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayTask_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetScanout(this: *mut core::ffi::c_void, scanout: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTask", "SetScanout", 6usize, |this| ((**(this as *const *const IDisplayTask_Vtbl)).SetScanout)(this, scanout), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWait(this: *mut core::ffi::c_void, readyfence: *mut core::ffi::c_void, readyfencevalue: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTask", "SetWait", 7usize, |this| ((**(this as *const *const IDisplayTask_Vtbl)).SetWait)(this, readyfence, readyfencevalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetScanout, SetWait }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayTask as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayTask2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSignal(this: *mut core::ffi::c_void, signalkind: DisplayTaskSignalKind, fence: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTask2", "SetSignal", 6usize, |this| ((**(this as *const *const IDisplayTask2_Vtbl)).SetSignal)(this, signalkind, fence), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSignal }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayTask2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayTaskPool_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTask(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTaskPool", "CreateTask", 6usize, |this| ((**(this as *const *const IDisplayTaskPool_Vtbl)).CreateTask)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ExecuteTask(this: *mut core::ffi::c_void, task: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTaskPool", "ExecuteTask", 7usize, |this| ((**(this as *const *const IDisplayTaskPool_Vtbl)).ExecuteTask)(this, task), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateTask,
+            #[cfg(feature = "deprecated")]
+            ExecuteTask,
+            #[cfg(not(feature = "deprecated"))]
+            ExecuteTask: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayTaskPool as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayTaskPool2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryExecuteTask(this: *mut core::ffi::c_void, task: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTaskPool2", "TryExecuteTask", 6usize, |this| ((**(this as *const *const IDisplayTaskPool2_Vtbl)).TryExecuteTask)(this, task, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryExecuteTask }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayTaskPool2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayTaskResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PresentStatus(this: *mut core::ffi::c_void, result__: *mut DisplayPresentStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTaskResult", "PresentStatus", 6usize, |this| ((**(this as *const *const IDisplayTaskResult_Vtbl)).PresentStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PresentId(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTaskResult", "PresentId", 7usize, |this| ((**(this as *const *const IDisplayTaskResult_Vtbl)).PresentId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceStatus(this: *mut core::ffi::c_void, result__: *mut DisplaySourceStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayTaskResult", "SourceStatus", 8usize, |this| ((**(this as *const *const IDisplayTaskResult_Vtbl)).SourceStatus)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PresentStatus, PresentId, SourceStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayTaskResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics"))]
+impl windows_core::imp::ProxyVtable for IDisplayView_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Paths(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayView", "Paths", 6usize, |this| ((**(this as *const *const IDisplayView_Vtbl)).Paths)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn ContentResolution(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayView", "ContentResolution", 7usize, |this| ((**(this as *const *const IDisplayView_Vtbl)).ContentResolution)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn SetContentResolution(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayView", "SetContentResolution", 8usize, |this| ((**(this as *const *const IDisplayView_Vtbl)).SetContentResolution)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrimaryPath(this: *mut core::ffi::c_void, path: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayView", "SetPrimaryPath", 9usize, |this| ((**(this as *const *const IDisplayView_Vtbl)).SetPrimaryPath)(this, path), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayView", "Properties", 10usize, |this| ((**(this as *const *const IDisplayView_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Paths,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Paths: 0,
+            #[cfg(feature = "Graphics")]
+            ContentResolution,
+            #[cfg(not(feature = "Graphics"))]
+            ContentResolution: 0,
+            #[cfg(feature = "Graphics")]
+            SetContentResolution,
+            #[cfg(not(feature = "Graphics"))]
+            SetContentResolution: 0,
+            SetPrimaryPath,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayView as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDisplayWireFormat_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PixelEncoding(this: *mut core::ffi::c_void, result__: *mut DisplayWireFormatPixelEncoding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayWireFormat", "PixelEncoding", 6usize, |this| ((**(this as *const *const IDisplayWireFormat_Vtbl)).PixelEncoding)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitsPerChannel(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayWireFormat", "BitsPerChannel", 7usize, |this| ((**(this as *const *const IDisplayWireFormat_Vtbl)).BitsPerChannel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorSpace(this: *mut core::ffi::c_void, result__: *mut DisplayWireFormatColorSpace) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayWireFormat", "ColorSpace", 8usize, |this| ((**(this as *const *const IDisplayWireFormat_Vtbl)).ColorSpace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Eotf(this: *mut core::ffi::c_void, result__: *mut DisplayWireFormatEotf) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayWireFormat", "Eotf", 9usize, |this| ((**(this as *const *const IDisplayWireFormat_Vtbl)).Eotf)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HdrMetadata(this: *mut core::ffi::c_void, result__: *mut DisplayWireFormatHdrMetadata) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayWireFormat", "HdrMetadata", 10usize, |this| ((**(this as *const *const IDisplayWireFormat_Vtbl)).HdrMetadata)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayWireFormat", "Properties", 11usize, |this| ((**(this as *const *const IDisplayWireFormat_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PixelEncoding,
+            BitsPerChannel,
+            ColorSpace,
+            Eotf,
+            HdrMetadata,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayWireFormat as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayWireFormatFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, pixelencoding: DisplayWireFormatPixelEncoding, bitsperchannel: i32, colorspace: DisplayWireFormatColorSpace, eotf: DisplayWireFormatEotf, hdrmetadata: DisplayWireFormatHdrMetadata, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayWireFormatFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IDisplayWireFormatFactory_Vtbl)).CreateInstance)(this, pixelencoding, bitsperchannel, colorspace, eotf, hdrmetadata, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayWireFormatFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDisplayWireFormatStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWithProperties(this: *mut core::ffi::c_void, extraproperties: *mut core::ffi::c_void, pixelencoding: DisplayWireFormatPixelEncoding, bitsperchannel: i32, colorspace: DisplayWireFormatColorSpace, eotf: DisplayWireFormatEotf, hdrmetadata: DisplayWireFormatHdrMetadata, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayWireFormatStatics", "CreateWithProperties", 6usize, |this| ((**(this as *const *const IDisplayWireFormatStatics_Vtbl)).CreateWithProperties)(this, extraproperties, pixelencoding, bitsperchannel, colorspace, eotf, hdrmetadata, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWithProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWithProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayWireFormatStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -256,6 +256,7 @@ impl DisplayMonitorConnectionKind {
 impl windows_core::TypeKind for DisplayMonitorConnectionKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayMonitorConnectionKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayMonitorConnectionKind").field(&self.0).finish()
@@ -274,6 +275,7 @@ impl DisplayMonitorDescriptorKind {
 impl windows_core::TypeKind for DisplayMonitorDescriptorKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayMonitorDescriptorKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayMonitorDescriptorKind").field(&self.0).finish()
@@ -298,6 +300,7 @@ impl DisplayMonitorPhysicalConnectorKind {
 impl windows_core::TypeKind for DisplayMonitorPhysicalConnectorKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayMonitorPhysicalConnectorKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayMonitorPhysicalConnectorKind").field(&self.0).finish()
@@ -317,6 +320,7 @@ impl DisplayMonitorUsageKind {
 impl windows_core::TypeKind for DisplayMonitorUsageKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayMonitorUsageKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayMonitorUsageKind").field(&self.0).finish()
@@ -325,3 +329,5 @@ impl core::fmt::Debug for DisplayMonitorUsageKind {
 impl windows_core::RuntimeType for DisplayMonitorUsageKind {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Display.DisplayMonitorUsageKind;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
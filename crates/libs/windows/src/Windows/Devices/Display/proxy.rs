@@ -0,0 +1,130 @@
+#[cfg(feature = "Graphics")]
+impl windows_core::imp::ProxyVtable for IDisplayMonitor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "DeviceId", 6usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "DisplayName", 7usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionKind(this: *mut core::ffi::c_void, result__: *mut DisplayMonitorConnectionKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "ConnectionKind", 8usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).ConnectionKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhysicalConnector(this: *mut core::ffi::c_void, result__: *mut DisplayMonitorPhysicalConnectorKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "PhysicalConnector", 9usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).PhysicalConnector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayAdapterDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "DisplayAdapterDeviceId", 10usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).DisplayAdapterDeviceId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn DisplayAdapterId(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::DisplayAdapterId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "DisplayAdapterId", 11usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).DisplayAdapterId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayAdapterTargetId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "DisplayAdapterTargetId", 12usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).DisplayAdapterTargetId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsageKind(this: *mut core::ffi::c_void, result__: *mut DisplayMonitorUsageKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "UsageKind", 13usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).UsageKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn NativeResolutionInRawPixels(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::SizeInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "NativeResolutionInRawPixels", 14usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).NativeResolutionInRawPixels)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhysicalSizeInInches(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "PhysicalSizeInInches", 15usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).PhysicalSizeInInches)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawDpiX(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "RawDpiX", 16usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).RawDpiX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawDpiY(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "RawDpiY", 17usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).RawDpiY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedPrimary(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "RedPrimary", 18usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).RedPrimary)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GreenPrimary(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "GreenPrimary", 19usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).GreenPrimary)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BluePrimary(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "BluePrimary", 20usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).BluePrimary)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WhitePoint(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "WhitePoint", 21usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).WhitePoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxLuminanceInNits(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "MaxLuminanceInNits", 22usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).MaxLuminanceInNits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinLuminanceInNits(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "MinLuminanceInNits", 23usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).MinLuminanceInNits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxAverageFullFrameLuminanceInNits(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "MaxAverageFullFrameLuminanceInNits", 24usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).MaxAverageFullFrameLuminanceInNits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescriptor(this: *mut core::ffi::c_void, descriptorkind: DisplayMonitorDescriptorKind, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor", "GetDescriptor", 25usize, |this| ((**(this as *const *const IDisplayMonitor_Vtbl)).GetDescriptor)(this, descriptorkind, result_size__, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            DisplayName,
+            ConnectionKind,
+            PhysicalConnector,
+            DisplayAdapterDeviceId,
+            #[cfg(feature = "Graphics")]
+            DisplayAdapterId,
+            #[cfg(not(feature = "Graphics"))]
+            DisplayAdapterId: 0,
+            DisplayAdapterTargetId,
+            UsageKind,
+            #[cfg(feature = "Graphics")]
+            NativeResolutionInRawPixels,
+            #[cfg(not(feature = "Graphics"))]
+            NativeResolutionInRawPixels: 0,
+            PhysicalSizeInInches,
+            RawDpiX,
+            RawDpiY,
+            RedPrimary,
+            GreenPrimary,
+            BluePrimary,
+            WhitePoint,
+            MaxLuminanceInNits,
+            MinLuminanceInNits,
+            MaxAverageFullFrameLuminanceInNits,
+            GetDescriptor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayMonitor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayMonitor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDolbyVisionSupportedInHdrMode(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitor2", "IsDolbyVisionSupportedInHdrMode", 6usize, |this| ((**(this as *const *const IDisplayMonitor2_Vtbl)).IsDolbyVisionSupportedInHdrMode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDolbyVisionSupportedInHdrMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayMonitor2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayMonitorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitorStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IDisplayMonitorStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitorStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const IDisplayMonitorStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromInterfaceIdAsync(this: *mut core::ffi::c_void, deviceinterfaceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayMonitorStatics", "FromInterfaceIdAsync", 8usize, |this| ((**(this as *const *const IDisplayMonitorStatics_Vtbl)).FromInterfaceIdAsync)(this, deviceinterfaceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync, FromInterfaceIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayMonitorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -426,6 +426,7 @@ impl PnpObjectType {
 impl windows_core::TypeKind for PnpObjectType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PnpObjectType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PnpObjectType").field(&self.0).finish()
@@ -434,3 +435,5 @@ impl core::fmt::Debug for PnpObjectType {
 impl windows_core::RuntimeType for PnpObjectType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Enumeration.Pnp.PnpObjectType;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
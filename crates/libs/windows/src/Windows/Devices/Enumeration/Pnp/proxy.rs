@@ -0,0 +1,175 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPnpObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut PnpObjectType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObject", "Type", 6usize, |this| ((**(this as *const *const IPnpObject_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObject", "Id", 7usize, |this| ((**(this as *const *const IPnpObject_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObject", "Properties", 8usize, |this| ((**(this as *const *const IPnpObject_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, updateinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObject", "Update", 9usize, |this| ((**(this as *const *const IPnpObject_Vtbl)).Update)(this, updateinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            Id,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            Update,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPnpObject as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPnpObjectStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIdAsync(this: *mut core::ffi::c_void, r#type: PnpObjectType, id: core::mem::MaybeUninit<windows_core::HSTRING>, requestedproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectStatics", "CreateFromIdAsync", 6usize, |this| ((**(this as *const *const IPnpObjectStatics_Vtbl)).CreateFromIdAsync)(this, r#type, id, requestedproperties, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsync(this: *mut core::ffi::c_void, r#type: PnpObjectType, requestedproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectStatics", "FindAllAsync", 7usize, |this| ((**(this as *const *const IPnpObjectStatics_Vtbl)).FindAllAsync)(this, r#type, requestedproperties, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsyncAqsFilter(this: *mut core::ffi::c_void, r#type: PnpObjectType, requestedproperties: *mut core::ffi::c_void, aqsfilter: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectStatics", "FindAllAsyncAqsFilter", 8usize, |this| ((**(this as *const *const IPnpObjectStatics_Vtbl)).FindAllAsyncAqsFilter)(this, r#type, requestedproperties, aqsfilter, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWatcher(this: *mut core::ffi::c_void, r#type: PnpObjectType, requestedproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectStatics", "CreateWatcher", 9usize, |this| ((**(this as *const *const IPnpObjectStatics_Vtbl)).CreateWatcher)(this, r#type, requestedproperties, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWatcherAqsFilter(this: *mut core::ffi::c_void, r#type: PnpObjectType, requestedproperties: *mut core::ffi::c_void, aqsfilter: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectStatics", "CreateWatcherAqsFilter", 10usize, |this| ((**(this as *const *const IPnpObjectStatics_Vtbl)).CreateWatcherAqsFilter)(this, r#type, requestedproperties, aqsfilter, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIdAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIdAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsyncAqsFilter,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsyncAqsFilter: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWatcher,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWatcher: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWatcherAqsFilter,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWatcherAqsFilter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPnpObjectStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPnpObjectUpdate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut PnpObjectType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectUpdate", "Type", 6usize, |this| ((**(this as *const *const IPnpObjectUpdate_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectUpdate", "Id", 7usize, |this| ((**(this as *const *const IPnpObjectUpdate_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectUpdate", "Properties", 8usize, |this| ((**(this as *const *const IPnpObjectUpdate_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            Id,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPnpObjectUpdate as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPnpObjectWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Added(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectWatcher", "Added", 6usize, |this| ((**(this as *const *const IPnpObjectWatcher_Vtbl)).Added)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdded(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectWatcher", "RemoveAdded", 7usize, |this| ((**(this as *const *const IPnpObjectWatcher_Vtbl)).RemoveAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Updated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectWatcher", "Updated", 8usize, |this| ((**(this as *const *const IPnpObjectWatcher_Vtbl)).Updated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUpdated(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectWatcher", "RemoveUpdated", 9usize, |this| ((**(this as *const *const IPnpObjectWatcher_Vtbl)).RemoveUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Removed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectWatcher", "Removed", 10usize, |this| ((**(this as *const *const IPnpObjectWatcher_Vtbl)).Removed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoved(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectWatcher", "RemoveRemoved", 11usize, |this| ((**(this as *const *const IPnpObjectWatcher_Vtbl)).RemoveRemoved)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectWatcher", "EnumerationCompleted", 12usize, |this| ((**(this as *const *const IPnpObjectWatcher_Vtbl)).EnumerationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnumerationCompleted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectWatcher", "RemoveEnumerationCompleted", 13usize, |this| ((**(this as *const *const IPnpObjectWatcher_Vtbl)).RemoveEnumerationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stopped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectWatcher", "Stopped", 14usize, |this| ((**(this as *const *const IPnpObjectWatcher_Vtbl)).Stopped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStopped(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectWatcher", "RemoveStopped", 15usize, |this| ((**(this as *const *const IPnpObjectWatcher_Vtbl)).RemoveStopped)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut super::DeviceWatcherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectWatcher", "Status", 16usize, |this| ((**(this as *const *const IPnpObjectWatcher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectWatcher", "Start", 17usize, |this| ((**(this as *const *const IPnpObjectWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPnpObjectWatcher", "Stop", 18usize, |this| ((**(this as *const *const IPnpObjectWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Added,
+            RemoveAdded,
+            Updated,
+            RemoveUpdated,
+            Removed,
+            RemoveRemoved,
+            EnumerationCompleted,
+            RemoveEnumerationCompleted,
+            Stopped,
+            RemoveStopped,
+            Status,
+            Start,
+            Stop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPnpObjectWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
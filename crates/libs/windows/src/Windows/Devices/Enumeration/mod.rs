@@ -2112,6 +2112,7 @@ impl DeviceAccessStatus {
 impl windows_core::TypeKind for DeviceAccessStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceAccessStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceAccessStatus").field(&self.0).finish()
@@ -2135,6 +2136,7 @@ impl DeviceClass {
 impl windows_core::TypeKind for DeviceClass {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceClass {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceClass").field(&self.0).finish()
@@ -2161,6 +2163,7 @@ impl DeviceInformationKind {
 impl windows_core::TypeKind for DeviceInformationKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceInformationKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceInformationKind").field(&self.0).finish()
@@ -2183,6 +2186,7 @@ impl DevicePairingAddPairingSetMemberStatus {
 impl windows_core::TypeKind for DevicePairingAddPairingSetMemberStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DevicePairingAddPairingSetMemberStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DevicePairingAddPairingSetMemberStatus").field(&self.0).finish()
@@ -2206,6 +2210,7 @@ impl DevicePairingKinds {
 impl windows_core::TypeKind for DevicePairingKinds {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DevicePairingKinds {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DevicePairingKinds").field(&self.0).finish()
@@ -2259,6 +2264,7 @@ impl DevicePairingProtectionLevel {
 impl windows_core::TypeKind for DevicePairingProtectionLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DevicePairingProtectionLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DevicePairingProtectionLevel").field(&self.0).finish()
@@ -2295,6 +2301,7 @@ impl DevicePairingResultStatus {
 impl windows_core::TypeKind for DevicePairingResultStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DevicePairingResultStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DevicePairingResultStatus").field(&self.0).finish()
@@ -2315,6 +2322,7 @@ impl DevicePickerDisplayStatusOptions {
 impl windows_core::TypeKind for DevicePickerDisplayStatusOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DevicePickerDisplayStatusOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DevicePickerDisplayStatusOptions").field(&self.0).finish()
@@ -2369,6 +2377,7 @@ impl DeviceUnpairingResultStatus {
 impl windows_core::TypeKind for DeviceUnpairingResultStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceUnpairingResultStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceUnpairingResultStatus").field(&self.0).finish()
@@ -2388,6 +2397,7 @@ impl DeviceWatcherEventKind {
 impl windows_core::TypeKind for DeviceWatcherEventKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceWatcherEventKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceWatcherEventKind").field(&self.0).finish()
@@ -2410,6 +2420,7 @@ impl DeviceWatcherStatus {
 impl windows_core::TypeKind for DeviceWatcherStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceWatcherStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceWatcherStatus").field(&self.0).finish()
@@ -2433,6 +2444,7 @@ impl Panel {
 impl windows_core::TypeKind for Panel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Panel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Panel").field(&self.0).finish()
@@ -2443,3 +2455,5 @@ impl windows_core::RuntimeType for Panel {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1024 @@
+impl windows_core::imp::ProxyVtable for IDeviceAccessChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut DeviceAccessStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccessChangedEventArgs", "Status", 6usize, |this| ((**(this as *const *const IDeviceAccessChangedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceAccessChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceAccessChangedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccessChangedEventArgs2", "Id", 6usize, |this| ((**(this as *const *const IDeviceAccessChangedEventArgs2_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceAccessChangedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceAccessChangedEventArgs3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserPromptRequired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccessChangedEventArgs3", "UserPromptRequired", 6usize, |this| ((**(this as *const *const IDeviceAccessChangedEventArgs3_Vtbl)).UserPromptRequired)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserPromptRequired }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceAccessChangedEventArgs3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceAccessInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccessChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccessInformation", "AccessChanged", 6usize, |this| ((**(this as *const *const IDeviceAccessInformation_Vtbl)).AccessChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAccessChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccessInformation", "RemoveAccessChanged", 7usize, |this| ((**(this as *const *const IDeviceAccessInformation_Vtbl)).RemoveAccessChanged)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentStatus(this: *mut core::ffi::c_void, result__: *mut DeviceAccessStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccessInformation", "CurrentStatus", 8usize, |this| ((**(this as *const *const IDeviceAccessInformation_Vtbl)).CurrentStatus)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AccessChanged, RemoveAccessChanged, CurrentStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceAccessInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceAccessInformation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserPromptRequired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccessInformation2", "UserPromptRequired", 6usize, |this| ((**(this as *const *const IDeviceAccessInformation2_Vtbl)).UserPromptRequired)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserPromptRequired }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceAccessInformation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceAccessInformationStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromId(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccessInformationStatics", "CreateFromId", 6usize, |this| ((**(this as *const *const IDeviceAccessInformationStatics_Vtbl)).CreateFromId)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromDeviceClassId(this: *mut core::ffi::c_void, deviceclassid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccessInformationStatics", "CreateFromDeviceClassId", 7usize, |this| ((**(this as *const *const IDeviceAccessInformationStatics_Vtbl)).CreateFromDeviceClassId)(this, deviceclassid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromDeviceClass(this: *mut core::ffi::c_void, deviceclass: DeviceClass, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceAccessInformationStatics", "CreateFromDeviceClass", 8usize, |this| ((**(this as *const *const IDeviceAccessInformationStatics_Vtbl)).CreateFromDeviceClass)(this, deviceclass, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromId, CreateFromDeviceClassId, CreateFromDeviceClass }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceAccessInformationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceConnectionChangeTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceConnectionChangeTriggerDetails", "DeviceId", 6usize, |this| ((**(this as *const *const IDeviceConnectionChangeTriggerDetails_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceConnectionChangeTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceDisconnectButtonClickedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Device(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceDisconnectButtonClickedEventArgs", "Device", 6usize, |this| ((**(this as *const *const IDeviceDisconnectButtonClickedEventArgs_Vtbl)).Device)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Device }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceDisconnectButtonClickedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceEnumerationSettings_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceEnumerationSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IDeviceInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformation", "Id", 6usize, |this| ((**(this as *const *const IDeviceInformation_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformation", "Name", 7usize, |this| ((**(this as *const *const IDeviceInformation_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformation", "IsEnabled", 8usize, |this| ((**(this as *const *const IDeviceInformation_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDefault(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformation", "IsDefault", 9usize, |this| ((**(this as *const *const IDeviceInformation_Vtbl)).IsDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnclosureLocation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformation", "EnclosureLocation", 10usize, |this| ((**(this as *const *const IDeviceInformation_Vtbl)).EnclosureLocation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformation", "Properties", 11usize, |this| ((**(this as *const *const IDeviceInformation_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, updateinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformation", "Update", 12usize, |this| ((**(this as *const *const IDeviceInformation_Vtbl)).Update)(this, updateinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetThumbnailAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformation", "GetThumbnailAsync", 13usize, |this| ((**(this as *const *const IDeviceInformation_Vtbl)).GetThumbnailAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetGlyphThumbnailAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformation", "GetGlyphThumbnailAsync", 14usize, |this| ((**(this as *const *const IDeviceInformation_Vtbl)).GetGlyphThumbnailAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            Name,
+            IsEnabled,
+            IsDefault,
+            EnclosureLocation,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            Update,
+            #[cfg(feature = "Storage_Streams")]
+            GetThumbnailAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetThumbnailAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            GetGlyphThumbnailAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetGlyphThumbnailAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceInformation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut DeviceInformationKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformation2", "Kind", 6usize, |this| ((**(this as *const *const IDeviceInformation2_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pairing(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformation2", "Pairing", 7usize, |this| ((**(this as *const *const IDeviceInformation2_Vtbl)).Pairing)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind, Pairing }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceInformation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceInformationCustomPairing_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PairAsync(this: *mut core::ffi::c_void, pairingkindssupported: DevicePairingKinds, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationCustomPairing", "PairAsync", 6usize, |this| ((**(this as *const *const IDeviceInformationCustomPairing_Vtbl)).PairAsync)(this, pairingkindssupported, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PairWithProtectionLevelAsync(this: *mut core::ffi::c_void, pairingkindssupported: DevicePairingKinds, minprotectionlevel: DevicePairingProtectionLevel, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationCustomPairing", "PairWithProtectionLevelAsync", 7usize, |this| ((**(this as *const *const IDeviceInformationCustomPairing_Vtbl)).PairWithProtectionLevelAsync)(this, pairingkindssupported, minprotectionlevel, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PairWithProtectionLevelAndSettingsAsync(this: *mut core::ffi::c_void, pairingkindssupported: DevicePairingKinds, minprotectionlevel: DevicePairingProtectionLevel, devicepairingsettings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationCustomPairing", "PairWithProtectionLevelAndSettingsAsync", 8usize, |this| ((**(this as *const *const IDeviceInformationCustomPairing_Vtbl)).PairWithProtectionLevelAndSettingsAsync)(this, pairingkindssupported, minprotectionlevel, devicepairingsettings, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PairingRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationCustomPairing", "PairingRequested", 9usize, |this| ((**(this as *const *const IDeviceInformationCustomPairing_Vtbl)).PairingRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePairingRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationCustomPairing", "RemovePairingRequested", 10usize, |this| ((**(this as *const *const IDeviceInformationCustomPairing_Vtbl)).RemovePairingRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PairAsync,
+            PairWithProtectionLevelAsync,
+            PairWithProtectionLevelAndSettingsAsync,
+            PairingRequested,
+            RemovePairingRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceInformationCustomPairing as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceInformationCustomPairing2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddPairingSetMember(this: *mut core::ffi::c_void, device: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationCustomPairing2", "AddPairingSetMember", 6usize, |this| ((**(this as *const *const IDeviceInformationCustomPairing2_Vtbl)).AddPairingSetMember)(this, device), |result| Some(*result))
+        }
+        unsafe extern "system" fn PairingSetMembersRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationCustomPairing2", "PairingSetMembersRequested", 7usize, |this| ((**(this as *const *const IDeviceInformationCustomPairing2_Vtbl)).PairingSetMembersRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePairingSetMembersRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationCustomPairing2", "RemovePairingSetMembersRequested", 8usize, |this| ((**(this as *const *const IDeviceInformationCustomPairing2_Vtbl)).RemovePairingSetMembersRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddPairingSetMember,
+            PairingSetMembersRequested,
+            RemovePairingSetMembersRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceInformationCustomPairing2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceInformationPairing_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPaired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationPairing", "IsPaired", 6usize, |this| ((**(this as *const *const IDeviceInformationPairing_Vtbl)).IsPaired)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanPair(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationPairing", "CanPair", 7usize, |this| ((**(this as *const *const IDeviceInformationPairing_Vtbl)).CanPair)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PairAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationPairing", "PairAsync", 8usize, |this| ((**(this as *const *const IDeviceInformationPairing_Vtbl)).PairAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PairWithProtectionLevelAsync(this: *mut core::ffi::c_void, minprotectionlevel: DevicePairingProtectionLevel, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationPairing", "PairWithProtectionLevelAsync", 9usize, |this| ((**(this as *const *const IDeviceInformationPairing_Vtbl)).PairWithProtectionLevelAsync)(this, minprotectionlevel, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsPaired, CanPair, PairAsync, PairWithProtectionLevelAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceInformationPairing as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceInformationPairing2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProtectionLevel(this: *mut core::ffi::c_void, result__: *mut DevicePairingProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationPairing2", "ProtectionLevel", 6usize, |this| ((**(this as *const *const IDeviceInformationPairing2_Vtbl)).ProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Custom(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationPairing2", "Custom", 7usize, |this| ((**(this as *const *const IDeviceInformationPairing2_Vtbl)).Custom)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PairWithProtectionLevelAndSettingsAsync(this: *mut core::ffi::c_void, minprotectionlevel: DevicePairingProtectionLevel, devicepairingsettings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationPairing2", "PairWithProtectionLevelAndSettingsAsync", 8usize, |this| ((**(this as *const *const IDeviceInformationPairing2_Vtbl)).PairWithProtectionLevelAndSettingsAsync)(this, minprotectionlevel, devicepairingsettings, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnpairAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationPairing2", "UnpairAsync", 9usize, |this| ((**(this as *const *const IDeviceInformationPairing2_Vtbl)).UnpairAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProtectionLevel,
+            Custom,
+            PairWithProtectionLevelAndSettingsAsync,
+            UnpairAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceInformationPairing2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceInformationPairingStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryRegisterForAllInboundPairingRequests(this: *mut core::ffi::c_void, pairingkindssupported: DevicePairingKinds, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationPairingStatics", "TryRegisterForAllInboundPairingRequests", 6usize, |this| ((**(this as *const *const IDeviceInformationPairingStatics_Vtbl)).TryRegisterForAllInboundPairingRequests)(this, pairingkindssupported, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryRegisterForAllInboundPairingRequests }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceInformationPairingStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceInformationPairingStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryRegisterForAllInboundPairingRequestsWithProtectionLevel(this: *mut core::ffi::c_void, pairingkindssupported: DevicePairingKinds, minprotectionlevel: DevicePairingProtectionLevel, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationPairingStatics2", "TryRegisterForAllInboundPairingRequestsWithProtectionLevel", 6usize, |this| ((**(this as *const *const IDeviceInformationPairingStatics2_Vtbl)).TryRegisterForAllInboundPairingRequestsWithProtectionLevel)(this, pairingkindssupported, minprotectionlevel, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryRegisterForAllInboundPairingRequestsWithProtectionLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceInformationPairingStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDeviceInformationStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics", "CreateFromIdAsync", 6usize, |this| ((**(this as *const *const IDeviceInformationStatics_Vtbl)).CreateFromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIdAsyncAdditionalProperties(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, additionalproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics", "CreateFromIdAsyncAdditionalProperties", 7usize, |this| ((**(this as *const *const IDeviceInformationStatics_Vtbl)).CreateFromIdAsyncAdditionalProperties)(this, deviceid, additionalproperties, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics", "FindAllAsync", 8usize, |this| ((**(this as *const *const IDeviceInformationStatics_Vtbl)).FindAllAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsyncDeviceClass(this: *mut core::ffi::c_void, deviceclass: DeviceClass, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics", "FindAllAsyncDeviceClass", 9usize, |this| ((**(this as *const *const IDeviceInformationStatics_Vtbl)).FindAllAsyncDeviceClass)(this, deviceclass, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsyncAqsFilter(this: *mut core::ffi::c_void, aqsfilter: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics", "FindAllAsyncAqsFilter", 10usize, |this| ((**(this as *const *const IDeviceInformationStatics_Vtbl)).FindAllAsyncAqsFilter)(this, aqsfilter, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsyncAqsFilterAndAdditionalProperties(this: *mut core::ffi::c_void, aqsfilter: core::mem::MaybeUninit<windows_core::HSTRING>, additionalproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics", "FindAllAsyncAqsFilterAndAdditionalProperties", 11usize, |this| ((**(this as *const *const IDeviceInformationStatics_Vtbl)).FindAllAsyncAqsFilterAndAdditionalProperties)(this, aqsfilter, additionalproperties, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics", "CreateWatcher", 12usize, |this| ((**(this as *const *const IDeviceInformationStatics_Vtbl)).CreateWatcher)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWatcherDeviceClass(this: *mut core::ffi::c_void, deviceclass: DeviceClass, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics", "CreateWatcherDeviceClass", 13usize, |this| ((**(this as *const *const IDeviceInformationStatics_Vtbl)).CreateWatcherDeviceClass)(this, deviceclass, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWatcherAqsFilter(this: *mut core::ffi::c_void, aqsfilter: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics", "CreateWatcherAqsFilter", 14usize, |this| ((**(this as *const *const IDeviceInformationStatics_Vtbl)).CreateWatcherAqsFilter)(this, aqsfilter, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWatcherAqsFilterAndAdditionalProperties(this: *mut core::ffi::c_void, aqsfilter: core::mem::MaybeUninit<windows_core::HSTRING>, additionalproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics", "CreateWatcherAqsFilterAndAdditionalProperties", 15usize, |this| ((**(this as *const *const IDeviceInformationStatics_Vtbl)).CreateWatcherAqsFilterAndAdditionalProperties)(this, aqsfilter, additionalproperties, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromIdAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIdAsyncAdditionalProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIdAsyncAdditionalProperties: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsyncDeviceClass,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsyncDeviceClass: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsyncAqsFilter,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsyncAqsFilter: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsyncAqsFilterAndAdditionalProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsyncAqsFilterAndAdditionalProperties: 0,
+            CreateWatcher,
+            CreateWatcherDeviceClass,
+            CreateWatcherAqsFilter,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWatcherAqsFilterAndAdditionalProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWatcherAqsFilterAndAdditionalProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceInformationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDeviceInformationStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAqsFilterFromDeviceClass(this: *mut core::ffi::c_void, deviceclass: DeviceClass, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics2", "GetAqsFilterFromDeviceClass", 6usize, |this| ((**(this as *const *const IDeviceInformationStatics2_Vtbl)).GetAqsFilterFromDeviceClass)(this, deviceclass, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIdAsyncWithKindAndAdditionalProperties(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, additionalproperties: *mut core::ffi::c_void, kind: DeviceInformationKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics2", "CreateFromIdAsyncWithKindAndAdditionalProperties", 7usize, |this| ((**(this as *const *const IDeviceInformationStatics2_Vtbl)).CreateFromIdAsyncWithKindAndAdditionalProperties)(this, deviceid, additionalproperties, kind, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsyncWithKindAqsFilterAndAdditionalProperties(this: *mut core::ffi::c_void, aqsfilter: core::mem::MaybeUninit<windows_core::HSTRING>, additionalproperties: *mut core::ffi::c_void, kind: DeviceInformationKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics2", "FindAllAsyncWithKindAqsFilterAndAdditionalProperties", 8usize, |this| ((**(this as *const *const IDeviceInformationStatics2_Vtbl)).FindAllAsyncWithKindAqsFilterAndAdditionalProperties)(this, aqsfilter, additionalproperties, kind, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWatcherWithKindAqsFilterAndAdditionalProperties(this: *mut core::ffi::c_void, aqsfilter: core::mem::MaybeUninit<windows_core::HSTRING>, additionalproperties: *mut core::ffi::c_void, kind: DeviceInformationKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics2", "CreateWatcherWithKindAqsFilterAndAdditionalProperties", 9usize, |this| ((**(this as *const *const IDeviceInformationStatics2_Vtbl)).CreateWatcherWithKindAqsFilterAndAdditionalProperties)(this, aqsfilter, additionalproperties, kind, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAqsFilterFromDeviceClass,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIdAsyncWithKindAndAdditionalProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIdAsyncWithKindAndAdditionalProperties: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsyncWithKindAqsFilterAndAdditionalProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsyncWithKindAqsFilterAndAdditionalProperties: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWatcherWithKindAqsFilterAndAdditionalProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWatcherWithKindAqsFilterAndAdditionalProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceInformationStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDeviceInformationStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateFromIdAsyncWithAdditionalPropertiesKindAndSettings(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, additionalproperties: *mut core::ffi::c_void, kind: DeviceInformationKind, settings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics3", "CreateFromIdAsyncWithAdditionalPropertiesKindAndSettings", 6usize, |this| ((**(this as *const *const IDeviceInformationStatics3_Vtbl)).CreateFromIdAsyncWithAdditionalPropertiesKindAndSettings)(this, deviceid, additionalproperties, kind, settings, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsyncWithAqsFilterAdditionalPropertiesKindAndSettings(this: *mut core::ffi::c_void, aqsfilter: core::mem::MaybeUninit<windows_core::HSTRING>, additionalproperties: *mut core::ffi::c_void, kind: DeviceInformationKind, settings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics3", "FindAllAsyncWithAqsFilterAdditionalPropertiesKindAndSettings", 7usize, |this| ((**(this as *const *const IDeviceInformationStatics3_Vtbl)).FindAllAsyncWithAqsFilterAdditionalPropertiesKindAndSettings)(this, aqsfilter, additionalproperties, kind, settings, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWatcherWithAqsFilterAdditionalPropertiesKindAndSettings(this: *mut core::ffi::c_void, aqsfilter: core::mem::MaybeUninit<windows_core::HSTRING>, additionalproperties: *mut core::ffi::c_void, kind: DeviceInformationKind, settings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationStatics3", "CreateWatcherWithAqsFilterAdditionalPropertiesKindAndSettings", 8usize, |this| ((**(this as *const *const IDeviceInformationStatics3_Vtbl)).CreateWatcherWithAqsFilterAdditionalPropertiesKindAndSettings)(this, aqsfilter, additionalproperties, kind, settings, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateFromIdAsyncWithAdditionalPropertiesKindAndSettings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateFromIdAsyncWithAdditionalPropertiesKindAndSettings: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsyncWithAqsFilterAdditionalPropertiesKindAndSettings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsyncWithAqsFilterAdditionalPropertiesKindAndSettings: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWatcherWithAqsFilterAdditionalPropertiesKindAndSettings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWatcherWithAqsFilterAdditionalPropertiesKindAndSettings: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceInformationStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDeviceInformationUpdate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationUpdate", "Id", 6usize, |this| ((**(this as *const *const IDeviceInformationUpdate_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationUpdate", "Properties", 7usize, |this| ((**(this as *const *const IDeviceInformationUpdate_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceInformationUpdate as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceInformationUpdate2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut DeviceInformationKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceInformationUpdate2", "Kind", 6usize, |this| ((**(this as *const *const IDeviceInformationUpdate2_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceInformationUpdate2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDevicePairingRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePairingRequestedEventArgs", "DeviceInformation", 6usize, |this| ((**(this as *const *const IDevicePairingRequestedEventArgs_Vtbl)).DeviceInformation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PairingKind(this: *mut core::ffi::c_void, result__: *mut DevicePairingKinds) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePairingRequestedEventArgs", "PairingKind", 7usize, |this| ((**(this as *const *const IDevicePairingRequestedEventArgs_Vtbl)).PairingKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pin(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePairingRequestedEventArgs", "Pin", 8usize, |this| ((**(this as *const *const IDevicePairingRequestedEventArgs_Vtbl)).Pin)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Accept(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePairingRequestedEventArgs", "Accept", 9usize, |this| ((**(this as *const *const IDevicePairingRequestedEventArgs_Vtbl)).Accept)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptWithPin(this: *mut core::ffi::c_void, pin: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePairingRequestedEventArgs", "AcceptWithPin", 10usize, |this| ((**(this as *const *const IDevicePairingRequestedEventArgs_Vtbl)).AcceptWithPin)(this, pin), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePairingRequestedEventArgs", "GetDeferral", 11usize, |this| ((**(this as *const *const IDevicePairingRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceInformation,
+            PairingKind,
+            Pin,
+            Accept,
+            AcceptWithPin,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePairingRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IDevicePairingRequestedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn AcceptWithPasswordCredential(this: *mut core::ffi::c_void, passwordcredential: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePairingRequestedEventArgs2", "AcceptWithPasswordCredential", 6usize, |this| ((**(this as *const *const IDevicePairingRequestedEventArgs2_Vtbl)).AcceptWithPasswordCredential)(this, passwordcredential), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            AcceptWithPasswordCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            AcceptWithPasswordCredential: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePairingRequestedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDevicePairingRequestedEventArgs3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcceptWithAddress(this: *mut core::ffi::c_void, address: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePairingRequestedEventArgs3", "AcceptWithAddress", 6usize, |this| ((**(this as *const *const IDevicePairingRequestedEventArgs3_Vtbl)).AcceptWithAddress)(this, address), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AcceptWithAddress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePairingRequestedEventArgs3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDevicePairingResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut DevicePairingResultStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePairingResult", "Status", 6usize, |this| ((**(this as *const *const IDevicePairingResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectionLevelUsed(this: *mut core::ffi::c_void, result__: *mut DevicePairingProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePairingResult", "ProtectionLevelUsed", 7usize, |this| ((**(this as *const *const IDevicePairingResult_Vtbl)).ProtectionLevelUsed)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, ProtectionLevelUsed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePairingResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDevicePairingSetMembersRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut DevicePairingAddPairingSetMemberStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePairingSetMembersRequestedEventArgs", "Status", 6usize, |this| ((**(this as *const *const IDevicePairingSetMembersRequestedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentDeviceInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePairingSetMembersRequestedEventArgs", "ParentDeviceInformation", 7usize, |this| ((**(this as *const *const IDevicePairingSetMembersRequestedEventArgs_Vtbl)).ParentDeviceInformation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PairingSetMembers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePairingSetMembersRequestedEventArgs", "PairingSetMembers", 8usize, |this| ((**(this as *const *const IDevicePairingSetMembersRequestedEventArgs_Vtbl)).PairingSetMembers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            ParentDeviceInformation,
+            #[cfg(feature = "Foundation_Collections")]
+            PairingSetMembers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PairingSetMembers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePairingSetMembersRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDevicePairingSettings_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePairingSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "UI_Popups"))]
+impl windows_core::imp::ProxyVtable for IDevicePicker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Filter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePicker", "Filter", 6usize, |this| ((**(this as *const *const IDevicePicker_Vtbl)).Filter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Appearance(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePicker", "Appearance", 7usize, |this| ((**(this as *const *const IDevicePicker_Vtbl)).Appearance)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestedProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePicker", "RequestedProperties", 8usize, |this| ((**(this as *const *const IDevicePicker_Vtbl)).RequestedProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceSelected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePicker", "DeviceSelected", 9usize, |this| ((**(this as *const *const IDevicePicker_Vtbl)).DeviceSelected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDeviceSelected(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePicker", "RemoveDeviceSelected", 10usize, |this| ((**(this as *const *const IDevicePicker_Vtbl)).RemoveDeviceSelected)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectButtonClicked(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePicker", "DisconnectButtonClicked", 11usize, |this| ((**(this as *const *const IDevicePicker_Vtbl)).DisconnectButtonClicked)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDisconnectButtonClicked(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePicker", "RemoveDisconnectButtonClicked", 12usize, |this| ((**(this as *const *const IDevicePicker_Vtbl)).RemoveDisconnectButtonClicked)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DevicePickerDismissed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePicker", "DevicePickerDismissed", 13usize, |this| ((**(this as *const *const IDevicePicker_Vtbl)).DevicePickerDismissed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDevicePickerDismissed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePicker", "RemoveDevicePickerDismissed", 14usize, |this| ((**(this as *const *const IDevicePicker_Vtbl)).RemoveDevicePickerDismissed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePicker", "Show", 15usize, |this| ((**(this as *const *const IDevicePicker_Vtbl)).Show)(this, selection), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowWithPlacement(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, placement: super::super::UI::Popups::Placement) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePicker", "ShowWithPlacement", 16usize, |this| ((**(this as *const *const IDevicePicker_Vtbl)).ShowWithPlacement)(this, selection, placement), |result| Some(*result))
+        }
+        unsafe extern "system" fn PickSingleDeviceAsync(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePicker", "PickSingleDeviceAsync", 17usize, |this| ((**(this as *const *const IDevicePicker_Vtbl)).PickSingleDeviceAsync)(this, selection, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn PickSingleDeviceAsyncWithPlacement(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, placement: super::super::UI::Popups::Placement, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePicker", "PickSingleDeviceAsyncWithPlacement", 18usize, |this| ((**(this as *const *const IDevicePicker_Vtbl)).PickSingleDeviceAsyncWithPlacement)(this, selection, placement, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hide(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePicker", "Hide", 19usize, |this| ((**(this as *const *const IDevicePicker_Vtbl)).Hide)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayStatus(this: *mut core::ffi::c_void, device: *mut core::ffi::c_void, status: core::mem::MaybeUninit<windows_core::HSTRING>, options: DevicePickerDisplayStatusOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePicker", "SetDisplayStatus", 20usize, |this| ((**(this as *const *const IDevicePicker_Vtbl)).SetDisplayStatus)(this, device, status, options), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Filter,
+            Appearance,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestedProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestedProperties: 0,
+            DeviceSelected,
+            RemoveDeviceSelected,
+            DisconnectButtonClicked,
+            RemoveDisconnectButtonClicked,
+            DevicePickerDismissed,
+            RemoveDevicePickerDismissed,
+            Show,
+            #[cfg(feature = "UI_Popups")]
+            ShowWithPlacement,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowWithPlacement: 0,
+            PickSingleDeviceAsync,
+            #[cfg(feature = "UI_Popups")]
+            PickSingleDeviceAsyncWithPlacement,
+            #[cfg(not(feature = "UI_Popups"))]
+            PickSingleDeviceAsyncWithPlacement: 0,
+            Hide,
+            SetDisplayStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePicker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for IDevicePickerAppearance_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerAppearance", "Title", 6usize, |this| ((**(this as *const *const IDevicePickerAppearance_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerAppearance", "SetTitle", 7usize, |this| ((**(this as *const *const IDevicePickerAppearance_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn ForegroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerAppearance", "ForegroundColor", 8usize, |this| ((**(this as *const *const IDevicePickerAppearance_Vtbl)).ForegroundColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetForegroundColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerAppearance", "SetForegroundColor", 9usize, |this| ((**(this as *const *const IDevicePickerAppearance_Vtbl)).SetForegroundColor)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn BackgroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerAppearance", "BackgroundColor", 10usize, |this| ((**(this as *const *const IDevicePickerAppearance_Vtbl)).BackgroundColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetBackgroundColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerAppearance", "SetBackgroundColor", 11usize, |this| ((**(this as *const *const IDevicePickerAppearance_Vtbl)).SetBackgroundColor)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn AccentColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerAppearance", "AccentColor", 12usize, |this| ((**(this as *const *const IDevicePickerAppearance_Vtbl)).AccentColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetAccentColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerAppearance", "SetAccentColor", 13usize, |this| ((**(this as *const *const IDevicePickerAppearance_Vtbl)).SetAccentColor)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SelectedForegroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerAppearance", "SelectedForegroundColor", 14usize, |this| ((**(this as *const *const IDevicePickerAppearance_Vtbl)).SelectedForegroundColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetSelectedForegroundColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerAppearance", "SetSelectedForegroundColor", 15usize, |this| ((**(this as *const *const IDevicePickerAppearance_Vtbl)).SetSelectedForegroundColor)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SelectedBackgroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerAppearance", "SelectedBackgroundColor", 16usize, |this| ((**(this as *const *const IDevicePickerAppearance_Vtbl)).SelectedBackgroundColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetSelectedBackgroundColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerAppearance", "SetSelectedBackgroundColor", 17usize, |this| ((**(this as *const *const IDevicePickerAppearance_Vtbl)).SetSelectedBackgroundColor)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SelectedAccentColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerAppearance", "SelectedAccentColor", 18usize, |this| ((**(this as *const *const IDevicePickerAppearance_Vtbl)).SelectedAccentColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetSelectedAccentColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerAppearance", "SetSelectedAccentColor", 19usize, |this| ((**(this as *const *const IDevicePickerAppearance_Vtbl)).SetSelectedAccentColor)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Title,
+            SetTitle,
+            #[cfg(feature = "UI")]
+            ForegroundColor,
+            #[cfg(not(feature = "UI"))]
+            ForegroundColor: 0,
+            #[cfg(feature = "UI")]
+            SetForegroundColor,
+            #[cfg(not(feature = "UI"))]
+            SetForegroundColor: 0,
+            #[cfg(feature = "UI")]
+            BackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            BackgroundColor: 0,
+            #[cfg(feature = "UI")]
+            SetBackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            SetBackgroundColor: 0,
+            #[cfg(feature = "UI")]
+            AccentColor,
+            #[cfg(not(feature = "UI"))]
+            AccentColor: 0,
+            #[cfg(feature = "UI")]
+            SetAccentColor,
+            #[cfg(not(feature = "UI"))]
+            SetAccentColor: 0,
+            #[cfg(feature = "UI")]
+            SelectedForegroundColor,
+            #[cfg(not(feature = "UI"))]
+            SelectedForegroundColor: 0,
+            #[cfg(feature = "UI")]
+            SetSelectedForegroundColor,
+            #[cfg(not(feature = "UI"))]
+            SetSelectedForegroundColor: 0,
+            #[cfg(feature = "UI")]
+            SelectedBackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            SelectedBackgroundColor: 0,
+            #[cfg(feature = "UI")]
+            SetSelectedBackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            SetSelectedBackgroundColor: 0,
+            #[cfg(feature = "UI")]
+            SelectedAccentColor,
+            #[cfg(not(feature = "UI"))]
+            SelectedAccentColor: 0,
+            #[cfg(feature = "UI")]
+            SetSelectedAccentColor,
+            #[cfg(not(feature = "UI"))]
+            SetSelectedAccentColor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePickerAppearance as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDevicePickerFilter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedDeviceClasses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerFilter", "SupportedDeviceClasses", 6usize, |this| ((**(this as *const *const IDevicePickerFilter_Vtbl)).SupportedDeviceClasses)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedDeviceSelectors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePickerFilter", "SupportedDeviceSelectors", 7usize, |this| ((**(this as *const *const IDevicePickerFilter_Vtbl)).SupportedDeviceSelectors)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedDeviceClasses,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedDeviceClasses: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedDeviceSelectors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedDeviceSelectors: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePickerFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceSelectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SelectedDevice(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceSelectedEventArgs", "SelectedDevice", 6usize, |this| ((**(this as *const *const IDeviceSelectedEventArgs_Vtbl)).SelectedDevice)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SelectedDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceSelectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceUnpairingResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut DeviceUnpairingResultStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceUnpairingResult", "Status", 6usize, |this| ((**(this as *const *const IDeviceUnpairingResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceUnpairingResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Added(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcher", "Added", 6usize, |this| ((**(this as *const *const IDeviceWatcher_Vtbl)).Added)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcher", "RemoveAdded", 7usize, |this| ((**(this as *const *const IDeviceWatcher_Vtbl)).RemoveAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Updated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcher", "Updated", 8usize, |this| ((**(this as *const *const IDeviceWatcher_Vtbl)).Updated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcher", "RemoveUpdated", 9usize, |this| ((**(this as *const *const IDeviceWatcher_Vtbl)).RemoveUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Removed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcher", "Removed", 10usize, |this| ((**(this as *const *const IDeviceWatcher_Vtbl)).Removed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcher", "RemoveRemoved", 11usize, |this| ((**(this as *const *const IDeviceWatcher_Vtbl)).RemoveRemoved)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcher", "EnumerationCompleted", 12usize, |this| ((**(this as *const *const IDeviceWatcher_Vtbl)).EnumerationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnumerationCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcher", "RemoveEnumerationCompleted", 13usize, |this| ((**(this as *const *const IDeviceWatcher_Vtbl)).RemoveEnumerationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stopped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcher", "Stopped", 14usize, |this| ((**(this as *const *const IDeviceWatcher_Vtbl)).Stopped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStopped(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcher", "RemoveStopped", 15usize, |this| ((**(this as *const *const IDeviceWatcher_Vtbl)).RemoveStopped)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut DeviceWatcherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcher", "Status", 16usize, |this| ((**(this as *const *const IDeviceWatcher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcher", "Start", 17usize, |this| ((**(this as *const *const IDeviceWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcher", "Stop", 18usize, |this| ((**(this as *const *const IDeviceWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Added,
+            RemoveAdded,
+            Updated,
+            RemoveUpdated,
+            Removed,
+            RemoveRemoved,
+            EnumerationCompleted,
+            RemoveEnumerationCompleted,
+            Stopped,
+            RemoveStopped,
+            Status,
+            Start,
+            Stop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Background", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IDeviceWatcher2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "ApplicationModel_Background", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetBackgroundTrigger(this: *mut core::ffi::c_void, requestedeventkinds: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcher2", "GetBackgroundTrigger", 6usize, |this| ((**(this as *const *const IDeviceWatcher2_Vtbl)).GetBackgroundTrigger)(this, requestedeventkinds, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "ApplicationModel_Background", feature = "Foundation_Collections"))]
+            GetBackgroundTrigger,
+            #[cfg(not(all(feature = "ApplicationModel_Background", feature = "Foundation_Collections")))]
+            GetBackgroundTrigger: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceWatcher2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceWatcherEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut DeviceWatcherEventKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcherEvent", "Kind", 6usize, |this| ((**(this as *const *const IDeviceWatcherEvent_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcherEvent", "DeviceInformation", 7usize, |this| ((**(this as *const *const IDeviceWatcherEvent_Vtbl)).DeviceInformation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceInformationUpdate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcherEvent", "DeviceInformationUpdate", 8usize, |this| ((**(this as *const *const IDeviceWatcherEvent_Vtbl)).DeviceInformationUpdate)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind, DeviceInformation, DeviceInformationUpdate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceWatcherEvent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDeviceWatcherTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DeviceWatcherEvents(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceWatcherTriggerDetails", "DeviceWatcherEvents", 6usize, |this| ((**(this as *const *const IDeviceWatcherTriggerDetails_Vtbl)).DeviceWatcherEvents)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            DeviceWatcherEvents,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DeviceWatcherEvents: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceWatcherTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnclosureLocation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InDock(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnclosureLocation", "InDock", 6usize, |this| ((**(this as *const *const IEnclosureLocation_Vtbl)).InDock)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InLid(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnclosureLocation", "InLid", 7usize, |this| ((**(this as *const *const IEnclosureLocation_Vtbl)).InLid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Panel(this: *mut core::ffi::c_void, result__: *mut Panel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnclosureLocation", "Panel", 8usize, |this| ((**(this as *const *const IEnclosureLocation_Vtbl)).Panel)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InDock, InLid, Panel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnclosureLocation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnclosureLocation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RotationAngleInDegreesClockwise(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnclosureLocation2", "RotationAngleInDegreesClockwise", 6usize, |this| ((**(this as *const *const IEnclosureLocation2_Vtbl)).RotationAngleInDegreesClockwise)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RotationAngleInDegreesClockwise }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnclosureLocation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -323,6 +323,7 @@ impl GeofenceMonitorStatus {
 impl windows_core::TypeKind for GeofenceMonitorStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GeofenceMonitorStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GeofenceMonitorStatus").field(&self.0).finish()
@@ -341,6 +342,7 @@ impl GeofenceRemovalReason {
 impl windows_core::TypeKind for GeofenceRemovalReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GeofenceRemovalReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GeofenceRemovalReason").field(&self.0).finish()
@@ -361,6 +363,7 @@ impl GeofenceState {
 impl windows_core::TypeKind for GeofenceState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GeofenceState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GeofenceState").field(&self.0).finish()
@@ -414,6 +417,7 @@ impl MonitoredGeofenceStates {
 impl windows_core::TypeKind for MonitoredGeofenceStates {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MonitoredGeofenceStates {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MonitoredGeofenceStates").field(&self.0).finish()
@@ -455,3 +459,5 @@ impl core::ops::Not for MonitoredGeofenceStates {
 impl windows_core::RuntimeType for MonitoredGeofenceStates {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Geolocation.Geofencing.MonitoredGeofenceStates;u4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
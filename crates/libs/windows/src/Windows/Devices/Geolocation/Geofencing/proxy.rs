@@ -0,0 +1,151 @@
+impl windows_core::imp::ProxyVtable for IGeofence_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofence", "StartTime", 6usize, |this| ((**(this as *const *const IGeofence_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofence", "Duration", 7usize, |this| ((**(this as *const *const IGeofence_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DwellTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofence", "DwellTime", 8usize, |this| ((**(this as *const *const IGeofence_Vtbl)).DwellTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofence", "Id", 9usize, |this| ((**(this as *const *const IGeofence_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonitoredStates(this: *mut core::ffi::c_void, result__: *mut MonitoredGeofenceStates) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofence", "MonitoredStates", 10usize, |this| ((**(this as *const *const IGeofence_Vtbl)).MonitoredStates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Geoshape(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofence", "Geoshape", 11usize, |this| ((**(this as *const *const IGeofence_Vtbl)).Geoshape)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SingleUse(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofence", "SingleUse", 12usize, |this| ((**(this as *const *const IGeofence_Vtbl)).SingleUse)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartTime,
+            Duration,
+            DwellTime,
+            Id,
+            MonitoredStates,
+            Geoshape,
+            SingleUse,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeofence as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeofenceFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, geoshape: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceFactory", "Create", 6usize, |this| ((**(this as *const *const IGeofenceFactory_Vtbl)).Create)(this, id, geoshape, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithMonitorStates(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, geoshape: *mut core::ffi::c_void, monitoredstates: MonitoredGeofenceStates, singleuse: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceFactory", "CreateWithMonitorStates", 7usize, |this| ((**(this as *const *const IGeofenceFactory_Vtbl)).CreateWithMonitorStates)(this, id, geoshape, monitoredstates, singleuse, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithMonitorStatesAndDwellTime(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, geoshape: *mut core::ffi::c_void, monitoredstates: MonitoredGeofenceStates, singleuse: bool, dwelltime: super::super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceFactory", "CreateWithMonitorStatesAndDwellTime", 8usize, |this| ((**(this as *const *const IGeofenceFactory_Vtbl)).CreateWithMonitorStatesAndDwellTime)(this, id, geoshape, monitoredstates, singleuse, dwelltime, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithMonitorStatesDwellTimeStartTimeAndDuration(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, geoshape: *mut core::ffi::c_void, monitoredstates: MonitoredGeofenceStates, singleuse: bool, dwelltime: super::super::super::Foundation::TimeSpan, starttime: super::super::super::Foundation::DateTime, duration: super::super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceFactory", "CreateWithMonitorStatesDwellTimeStartTimeAndDuration", 9usize, |this| ((**(this as *const *const IGeofenceFactory_Vtbl)).CreateWithMonitorStatesDwellTimeStartTimeAndDuration)(this, id, geoshape, monitoredstates, singleuse, dwelltime, starttime, duration, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            CreateWithMonitorStates,
+            CreateWithMonitorStatesAndDwellTime,
+            CreateWithMonitorStatesDwellTimeStartTimeAndDuration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeofenceFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGeofenceMonitor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GeofenceMonitorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceMonitor", "Status", 6usize, |this| ((**(this as *const *const IGeofenceMonitor_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Geofences(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceMonitor", "Geofences", 7usize, |this| ((**(this as *const *const IGeofenceMonitor_Vtbl)).Geofences)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastKnownGeoposition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceMonitor", "LastKnownGeoposition", 8usize, |this| ((**(this as *const *const IGeofenceMonitor_Vtbl)).LastKnownGeoposition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GeofenceStateChanged(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceMonitor", "GeofenceStateChanged", 9usize, |this| ((**(this as *const *const IGeofenceMonitor_Vtbl)).GeofenceStateChanged)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGeofenceStateChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceMonitor", "RemoveGeofenceStateChanged", 10usize, |this| ((**(this as *const *const IGeofenceMonitor_Vtbl)).RemoveGeofenceStateChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadReports(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceMonitor", "ReadReports", 11usize, |this| ((**(this as *const *const IGeofenceMonitor_Vtbl)).ReadReports)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusChanged(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceMonitor", "StatusChanged", 12usize, |this| ((**(this as *const *const IGeofenceMonitor_Vtbl)).StatusChanged)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceMonitor", "RemoveStatusChanged", 13usize, |this| ((**(this as *const *const IGeofenceMonitor_Vtbl)).RemoveStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Foundation_Collections")]
+            Geofences,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Geofences: 0,
+            LastKnownGeoposition,
+            GeofenceStateChanged,
+            RemoveGeofenceStateChanged,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadReports,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadReports: 0,
+            StatusChanged,
+            RemoveStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeofenceMonitor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeofenceMonitorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceMonitorStatics", "Current", 6usize, |this| ((**(this as *const *const IGeofenceMonitorStatics_Vtbl)).Current)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Current }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeofenceMonitorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeofenceStateChangeReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NewState(this: *mut core::ffi::c_void, result__: *mut GeofenceState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceStateChangeReport", "NewState", 6usize, |this| ((**(this as *const *const IGeofenceStateChangeReport_Vtbl)).NewState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Geofence(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceStateChangeReport", "Geofence", 7usize, |this| ((**(this as *const *const IGeofenceStateChangeReport_Vtbl)).Geofence)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Geoposition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceStateChangeReport", "Geoposition", 8usize, |this| ((**(this as *const *const IGeofenceStateChangeReport_Vtbl)).Geoposition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovalReason(this: *mut core::ffi::c_void, result__: *mut GeofenceRemovalReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeofenceStateChangeReport", "RemovalReason", 9usize, |this| ((**(this as *const *const IGeofenceStateChangeReport_Vtbl)).RemovalReason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NewState, Geofence, Geoposition, RemovalReason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeofenceStateChangeReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -80,6 +80,7 @@ impl LocationOverrideStatus {
 impl windows_core::TypeKind for LocationOverrideStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LocationOverrideStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LocationOverrideStatus").field(&self.0).finish()
@@ -88,3 +89,5 @@ impl core::fmt::Debug for LocationOverrideStatus {
 impl windows_core::RuntimeType for LocationOverrideStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Geolocation.Provider.LocationOverrideStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
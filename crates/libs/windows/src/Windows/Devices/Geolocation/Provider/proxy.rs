@@ -0,0 +1,31 @@
+impl windows_core::imp::ProxyVtable for IGeolocationProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsOverridden(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocationProvider", "IsOverridden", 6usize, |this| ((**(this as *const *const IGeolocationProvider_Vtbl)).IsOverridden)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOverridePosition(this: *mut core::ffi::c_void, newposition: super::BasicGeoposition, positionsource: super::PositionSource, accuracyinmeters: f64, result__: *mut LocationOverrideStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocationProvider", "SetOverridePosition", 7usize, |this| ((**(this as *const *const IGeolocationProvider_Vtbl)).SetOverridePosition)(this, newposition, positionsource, accuracyinmeters, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearOverridePosition(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocationProvider", "ClearOverridePosition", 8usize, |this| ((**(this as *const *const IGeolocationProvider_Vtbl)).ClearOverridePosition)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOverriddenChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocationProvider", "IsOverriddenChanged", 9usize, |this| ((**(this as *const *const IGeolocationProvider_Vtbl)).IsOverriddenChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveIsOverriddenChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocationProvider", "RemoveIsOverriddenChanged", 10usize, |this| ((**(this as *const *const IGeolocationProvider_Vtbl)).RemoveIsOverriddenChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsOverridden,
+            SetOverridePosition,
+            ClearOverridePosition,
+            IsOverriddenChanged,
+            RemoveIsOverriddenChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeolocationProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1468,6 +1468,7 @@ impl AltitudeReferenceSystem {
 impl windows_core::TypeKind for AltitudeReferenceSystem {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AltitudeReferenceSystem {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AltitudeReferenceSystem").field(&self.0).finish()
@@ -1487,6 +1488,7 @@ impl GeolocationAccessStatus {
 impl windows_core::TypeKind for GeolocationAccessStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GeolocationAccessStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GeolocationAccessStatus").field(&self.0).finish()
@@ -1507,6 +1509,7 @@ impl GeoshapeType {
 impl windows_core::TypeKind for GeoshapeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GeoshapeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GeoshapeType").field(&self.0).finish()
@@ -1525,6 +1528,7 @@ impl PositionAccuracy {
 impl windows_core::TypeKind for PositionAccuracy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PositionAccuracy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PositionAccuracy").field(&self.0).finish()
@@ -1548,6 +1552,7 @@ impl PositionSource {
 impl windows_core::TypeKind for PositionSource {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PositionSource {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PositionSource").field(&self.0).finish()
@@ -1570,6 +1575,7 @@ impl PositionStatus {
 impl windows_core::TypeKind for PositionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PositionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PositionStatus").field(&self.0).finish()
@@ -1588,6 +1594,7 @@ impl VisitMonitoringScope {
 impl windows_core::TypeKind for VisitMonitoringScope {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VisitMonitoringScope {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VisitMonitoringScope").field(&self.0).finish()
@@ -1608,6 +1615,7 @@ impl VisitStateChange {
 impl windows_core::TypeKind for VisitStateChange {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VisitStateChange {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VisitStateChange").field(&self.0).finish()
@@ -1617,7 +1625,8 @@ impl windows_core::RuntimeType for VisitStateChange {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Geolocation.VisitStateChange;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BasicGeoposition {
     pub Latitude: f64,
     pub Longitude: f64,
@@ -1636,3 +1645,5 @@ impl Default for BasicGeoposition {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
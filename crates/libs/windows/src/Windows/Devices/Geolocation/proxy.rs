@@ -0,0 +1,709 @@
+impl windows_core::imp::ProxyVtable for ICivicAddress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Country(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICivicAddress", "Country", 6usize, |this| ((**(this as *const *const ICivicAddress_Vtbl)).Country)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICivicAddress", "State", 7usize, |this| ((**(this as *const *const ICivicAddress_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn City(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICivicAddress", "City", 8usize, |this| ((**(this as *const *const ICivicAddress_Vtbl)).City)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostalCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICivicAddress", "PostalCode", 9usize, |this| ((**(this as *const *const ICivicAddress_Vtbl)).PostalCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICivicAddress", "Timestamp", 10usize, |this| ((**(this as *const *const ICivicAddress_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Country, State, City, PostalCode, Timestamp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICivicAddress as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeoboundingBox_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NorthwestCorner(this: *mut core::ffi::c_void, result__: *mut BasicGeoposition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoboundingBox", "NorthwestCorner", 6usize, |this| ((**(this as *const *const IGeoboundingBox_Vtbl)).NorthwestCorner)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SoutheastCorner(this: *mut core::ffi::c_void, result__: *mut BasicGeoposition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoboundingBox", "SoutheastCorner", 7usize, |this| ((**(this as *const *const IGeoboundingBox_Vtbl)).SoutheastCorner)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Center(this: *mut core::ffi::c_void, result__: *mut BasicGeoposition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoboundingBox", "Center", 8usize, |this| ((**(this as *const *const IGeoboundingBox_Vtbl)).Center)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinAltitude(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoboundingBox", "MinAltitude", 9usize, |this| ((**(this as *const *const IGeoboundingBox_Vtbl)).MinAltitude)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxAltitude(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoboundingBox", "MaxAltitude", 10usize, |this| ((**(this as *const *const IGeoboundingBox_Vtbl)).MaxAltitude)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NorthwestCorner,
+            SoutheastCorner,
+            Center,
+            MinAltitude,
+            MaxAltitude,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeoboundingBox as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeoboundingBoxFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, northwestcorner: BasicGeoposition, southeastcorner: BasicGeoposition, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoboundingBoxFactory", "Create", 6usize, |this| ((**(this as *const *const IGeoboundingBoxFactory_Vtbl)).Create)(this, northwestcorner, southeastcorner, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithAltitudeReference(this: *mut core::ffi::c_void, northwestcorner: BasicGeoposition, southeastcorner: BasicGeoposition, altitudereferencesystem: AltitudeReferenceSystem, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoboundingBoxFactory", "CreateWithAltitudeReference", 7usize, |this| ((**(this as *const *const IGeoboundingBoxFactory_Vtbl)).CreateWithAltitudeReference)(this, northwestcorner, southeastcorner, altitudereferencesystem, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithAltitudeReferenceAndSpatialReference(this: *mut core::ffi::c_void, northwestcorner: BasicGeoposition, southeastcorner: BasicGeoposition, altitudereferencesystem: AltitudeReferenceSystem, spatialreferenceid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoboundingBoxFactory", "CreateWithAltitudeReferenceAndSpatialReference", 8usize, |this| ((**(this as *const *const IGeoboundingBoxFactory_Vtbl)).CreateWithAltitudeReferenceAndSpatialReference)(this, northwestcorner, southeastcorner, altitudereferencesystem, spatialreferenceid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            CreateWithAltitudeReference,
+            CreateWithAltitudeReferenceAndSpatialReference,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeoboundingBoxFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGeoboundingBoxStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TryCompute(this: *mut core::ffi::c_void, positions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoboundingBoxStatics", "TryCompute", 6usize, |this| ((**(this as *const *const IGeoboundingBoxStatics_Vtbl)).TryCompute)(this, positions, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TryComputeWithAltitudeReference(this: *mut core::ffi::c_void, positions: *mut core::ffi::c_void, altituderefsystem: AltitudeReferenceSystem, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoboundingBoxStatics", "TryComputeWithAltitudeReference", 7usize, |this| ((**(this as *const *const IGeoboundingBoxStatics_Vtbl)).TryComputeWithAltitudeReference)(this, positions, altituderefsystem, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TryComputeWithAltitudeReferenceAndSpatialReference(this: *mut core::ffi::c_void, positions: *mut core::ffi::c_void, altituderefsystem: AltitudeReferenceSystem, spatialreferenceid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoboundingBoxStatics", "TryComputeWithAltitudeReferenceAndSpatialReference", 8usize, |this| ((**(this as *const *const IGeoboundingBoxStatics_Vtbl)).TryComputeWithAltitudeReferenceAndSpatialReference)(this, positions, altituderefsystem, spatialreferenceid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            TryCompute,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TryCompute: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            TryComputeWithAltitudeReference,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TryComputeWithAltitudeReference: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            TryComputeWithAltitudeReferenceAndSpatialReference,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TryComputeWithAltitudeReferenceAndSpatialReference: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeoboundingBoxStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeocircle_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Center(this: *mut core::ffi::c_void, result__: *mut BasicGeoposition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocircle", "Center", 6usize, |this| ((**(this as *const *const IGeocircle_Vtbl)).Center)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Radius(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocircle", "Radius", 7usize, |this| ((**(this as *const *const IGeocircle_Vtbl)).Radius)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Center, Radius }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeocircle as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeocircleFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, position: BasicGeoposition, radius: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocircleFactory", "Create", 6usize, |this| ((**(this as *const *const IGeocircleFactory_Vtbl)).Create)(this, position, radius, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithAltitudeReferenceSystem(this: *mut core::ffi::c_void, position: BasicGeoposition, radius: f64, altitudereferencesystem: AltitudeReferenceSystem, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocircleFactory", "CreateWithAltitudeReferenceSystem", 7usize, |this| ((**(this as *const *const IGeocircleFactory_Vtbl)).CreateWithAltitudeReferenceSystem)(this, position, radius, altitudereferencesystem, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithAltitudeReferenceSystemAndSpatialReferenceId(this: *mut core::ffi::c_void, position: BasicGeoposition, radius: f64, altitudereferencesystem: AltitudeReferenceSystem, spatialreferenceid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocircleFactory", "CreateWithAltitudeReferenceSystemAndSpatialReferenceId", 8usize, |this| ((**(this as *const *const IGeocircleFactory_Vtbl)).CreateWithAltitudeReferenceSystemAndSpatialReferenceId)(this, position, radius, altitudereferencesystem, spatialreferenceid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            CreateWithAltitudeReferenceSystem,
+            CreateWithAltitudeReferenceSystemAndSpatialReferenceId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeocircleFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeocoordinate_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Latitude(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinate", "Latitude", 6usize, |this| ((**(this as *const *const IGeocoordinate_Vtbl)).Latitude)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Longitude(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinate", "Longitude", 7usize, |this| ((**(this as *const *const IGeocoordinate_Vtbl)).Longitude)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Altitude(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinate", "Altitude", 8usize, |this| ((**(this as *const *const IGeocoordinate_Vtbl)).Altitude)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Accuracy(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinate", "Accuracy", 9usize, |this| ((**(this as *const *const IGeocoordinate_Vtbl)).Accuracy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AltitudeAccuracy(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinate", "AltitudeAccuracy", 10usize, |this| ((**(this as *const *const IGeocoordinate_Vtbl)).AltitudeAccuracy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Heading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinate", "Heading", 11usize, |this| ((**(this as *const *const IGeocoordinate_Vtbl)).Heading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Speed(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinate", "Speed", 12usize, |this| ((**(this as *const *const IGeocoordinate_Vtbl)).Speed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinate", "Timestamp", 13usize, |this| ((**(this as *const *const IGeocoordinate_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Latitude,
+            #[cfg(not(feature = "deprecated"))]
+            Latitude: 0,
+            #[cfg(feature = "deprecated")]
+            Longitude,
+            #[cfg(not(feature = "deprecated"))]
+            Longitude: 0,
+            #[cfg(feature = "deprecated")]
+            Altitude,
+            #[cfg(not(feature = "deprecated"))]
+            Altitude: 0,
+            Accuracy,
+            AltitudeAccuracy,
+            Heading,
+            Speed,
+            Timestamp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeocoordinate as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeocoordinateSatelliteData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PositionDilutionOfPrecision(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinateSatelliteData", "PositionDilutionOfPrecision", 6usize, |this| ((**(this as *const *const IGeocoordinateSatelliteData_Vtbl)).PositionDilutionOfPrecision)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HorizontalDilutionOfPrecision(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinateSatelliteData", "HorizontalDilutionOfPrecision", 7usize, |this| ((**(this as *const *const IGeocoordinateSatelliteData_Vtbl)).HorizontalDilutionOfPrecision)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerticalDilutionOfPrecision(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinateSatelliteData", "VerticalDilutionOfPrecision", 8usize, |this| ((**(this as *const *const IGeocoordinateSatelliteData_Vtbl)).VerticalDilutionOfPrecision)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PositionDilutionOfPrecision,
+            HorizontalDilutionOfPrecision,
+            VerticalDilutionOfPrecision,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeocoordinateSatelliteData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeocoordinateSatelliteData2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GeometricDilutionOfPrecision(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinateSatelliteData2", "GeometricDilutionOfPrecision", 6usize, |this| ((**(this as *const *const IGeocoordinateSatelliteData2_Vtbl)).GeometricDilutionOfPrecision)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeDilutionOfPrecision(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinateSatelliteData2", "TimeDilutionOfPrecision", 7usize, |this| ((**(this as *const *const IGeocoordinateSatelliteData2_Vtbl)).TimeDilutionOfPrecision)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GeometricDilutionOfPrecision, TimeDilutionOfPrecision }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeocoordinateSatelliteData2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeocoordinateWithPoint_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Point(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinateWithPoint", "Point", 6usize, |this| ((**(this as *const *const IGeocoordinateWithPoint_Vtbl)).Point)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Point }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeocoordinateWithPoint as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeocoordinateWithPositionData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PositionSource(this: *mut core::ffi::c_void, result__: *mut PositionSource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinateWithPositionData", "PositionSource", 6usize, |this| ((**(this as *const *const IGeocoordinateWithPositionData_Vtbl)).PositionSource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SatelliteData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinateWithPositionData", "SatelliteData", 7usize, |this| ((**(this as *const *const IGeocoordinateWithPositionData_Vtbl)).SatelliteData)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PositionSource, SatelliteData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeocoordinateWithPositionData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeocoordinateWithPositionSourceTimestamp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PositionSourceTimestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinateWithPositionSourceTimestamp", "PositionSourceTimestamp", 6usize, |this| ((**(this as *const *const IGeocoordinateWithPositionSourceTimestamp_Vtbl)).PositionSourceTimestamp)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PositionSourceTimestamp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeocoordinateWithPositionSourceTimestamp as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeocoordinateWithRemoteSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsRemoteSource(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeocoordinateWithRemoteSource", "IsRemoteSource", 6usize, |this| ((**(this as *const *const IGeocoordinateWithRemoteSource_Vtbl)).IsRemoteSource)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsRemoteSource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeocoordinateWithRemoteSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeolocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DesiredAccuracy(this: *mut core::ffi::c_void, result__: *mut PositionAccuracy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocator", "DesiredAccuracy", 6usize, |this| ((**(this as *const *const IGeolocator_Vtbl)).DesiredAccuracy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredAccuracy(this: *mut core::ffi::c_void, value: PositionAccuracy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocator", "SetDesiredAccuracy", 7usize, |this| ((**(this as *const *const IGeolocator_Vtbl)).SetDesiredAccuracy)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MovementThreshold(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocator", "MovementThreshold", 8usize, |this| ((**(this as *const *const IGeolocator_Vtbl)).MovementThreshold)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMovementThreshold(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocator", "SetMovementThreshold", 9usize, |this| ((**(this as *const *const IGeolocator_Vtbl)).SetMovementThreshold)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocator", "ReportInterval", 10usize, |this| ((**(this as *const *const IGeolocator_Vtbl)).ReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportInterval(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocator", "SetReportInterval", 11usize, |this| ((**(this as *const *const IGeolocator_Vtbl)).SetReportInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocationStatus(this: *mut core::ffi::c_void, result__: *mut PositionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocator", "LocationStatus", 12usize, |this| ((**(this as *const *const IGeolocator_Vtbl)).LocationStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGeopositionAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocator", "GetGeopositionAsync", 13usize, |this| ((**(this as *const *const IGeolocator_Vtbl)).GetGeopositionAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGeopositionAsyncWithAgeAndTimeout(this: *mut core::ffi::c_void, maximumage: super::super::Foundation::TimeSpan, timeout: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocator", "GetGeopositionAsyncWithAgeAndTimeout", 14usize, |this| ((**(this as *const *const IGeolocator_Vtbl)).GetGeopositionAsyncWithAgeAndTimeout)(this, maximumage, timeout, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocator", "PositionChanged", 15usize, |this| ((**(this as *const *const IGeolocator_Vtbl)).PositionChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePositionChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocator", "RemovePositionChanged", 16usize, |this| ((**(this as *const *const IGeolocator_Vtbl)).RemovePositionChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocator", "StatusChanged", 17usize, |this| ((**(this as *const *const IGeolocator_Vtbl)).StatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocator", "RemoveStatusChanged", 18usize, |this| ((**(this as *const *const IGeolocator_Vtbl)).RemoveStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DesiredAccuracy,
+            SetDesiredAccuracy,
+            MovementThreshold,
+            SetMovementThreshold,
+            ReportInterval,
+            SetReportInterval,
+            LocationStatus,
+            GetGeopositionAsync,
+            GetGeopositionAsyncWithAgeAndTimeout,
+            PositionChanged,
+            RemovePositionChanged,
+            StatusChanged,
+            RemoveStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeolocator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeolocator2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowFallbackToConsentlessPositions(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocator2", "AllowFallbackToConsentlessPositions", 6usize, |this| ((**(this as *const *const IGeolocator2_Vtbl)).AllowFallbackToConsentlessPositions)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AllowFallbackToConsentlessPositions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeolocator2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGeolocatorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocatorStatics", "RequestAccessAsync", 6usize, |this| ((**(this as *const *const IGeolocatorStatics_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetGeopositionHistoryAsync(this: *mut core::ffi::c_void, starttime: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocatorStatics", "GetGeopositionHistoryAsync", 7usize, |this| ((**(this as *const *const IGeolocatorStatics_Vtbl)).GetGeopositionHistoryAsync)(this, starttime, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetGeopositionHistoryWithDurationAsync(this: *mut core::ffi::c_void, starttime: super::super::Foundation::DateTime, duration: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocatorStatics", "GetGeopositionHistoryWithDurationAsync", 8usize, |this| ((**(this as *const *const IGeolocatorStatics_Vtbl)).GetGeopositionHistoryWithDurationAsync)(this, starttime, duration, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestAccessAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetGeopositionHistoryAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetGeopositionHistoryAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetGeopositionHistoryWithDurationAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetGeopositionHistoryWithDurationAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeolocatorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeolocatorStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDefaultGeopositionRecommended(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocatorStatics2", "IsDefaultGeopositionRecommended", 6usize, |this| ((**(this as *const *const IGeolocatorStatics2_Vtbl)).IsDefaultGeopositionRecommended)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultGeoposition(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocatorStatics2", "SetDefaultGeoposition", 7usize, |this| ((**(this as *const *const IGeolocatorStatics2_Vtbl)).SetDefaultGeoposition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultGeoposition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocatorStatics2", "DefaultGeoposition", 8usize, |this| ((**(this as *const *const IGeolocatorStatics2_Vtbl)).DefaultGeoposition)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsDefaultGeopositionRecommended,
+            SetDefaultGeoposition,
+            DefaultGeoposition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeolocatorStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeolocatorWithScalarAccuracy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DesiredAccuracyInMeters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocatorWithScalarAccuracy", "DesiredAccuracyInMeters", 6usize, |this| ((**(this as *const *const IGeolocatorWithScalarAccuracy_Vtbl)).DesiredAccuracyInMeters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredAccuracyInMeters(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeolocatorWithScalarAccuracy", "SetDesiredAccuracyInMeters", 7usize, |this| ((**(this as *const *const IGeolocatorWithScalarAccuracy_Vtbl)).SetDesiredAccuracyInMeters)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DesiredAccuracyInMeters, SetDesiredAccuracyInMeters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeolocatorWithScalarAccuracy as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGeopath_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Positions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeopath", "Positions", 6usize, |this| ((**(this as *const *const IGeopath_Vtbl)).Positions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Positions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Positions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeopath as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGeopathFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, positions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeopathFactory", "Create", 6usize, |this| ((**(this as *const *const IGeopathFactory_Vtbl)).Create)(this, positions, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWithAltitudeReference(this: *mut core::ffi::c_void, positions: *mut core::ffi::c_void, altitudereferencesystem: AltitudeReferenceSystem, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeopathFactory", "CreateWithAltitudeReference", 7usize, |this| ((**(this as *const *const IGeopathFactory_Vtbl)).CreateWithAltitudeReference)(this, positions, altitudereferencesystem, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWithAltitudeReferenceAndSpatialReference(this: *mut core::ffi::c_void, positions: *mut core::ffi::c_void, altitudereferencesystem: AltitudeReferenceSystem, spatialreferenceid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeopathFactory", "CreateWithAltitudeReferenceAndSpatialReference", 8usize, |this| ((**(this as *const *const IGeopathFactory_Vtbl)).CreateWithAltitudeReferenceAndSpatialReference)(this, positions, altitudereferencesystem, spatialreferenceid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Create,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWithAltitudeReference,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWithAltitudeReference: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWithAltitudeReferenceAndSpatialReference,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWithAltitudeReferenceAndSpatialReference: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeopathFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeopoint_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut BasicGeoposition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeopoint", "Position", 6usize, |this| ((**(this as *const *const IGeopoint_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Position }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeopoint as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeopointFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, position: BasicGeoposition, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeopointFactory", "Create", 6usize, |this| ((**(this as *const *const IGeopointFactory_Vtbl)).Create)(this, position, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithAltitudeReferenceSystem(this: *mut core::ffi::c_void, position: BasicGeoposition, altitudereferencesystem: AltitudeReferenceSystem, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeopointFactory", "CreateWithAltitudeReferenceSystem", 7usize, |this| ((**(this as *const *const IGeopointFactory_Vtbl)).CreateWithAltitudeReferenceSystem)(this, position, altitudereferencesystem, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithAltitudeReferenceSystemAndSpatialReferenceId(this: *mut core::ffi::c_void, position: BasicGeoposition, altitudereferencesystem: AltitudeReferenceSystem, spatialreferenceid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeopointFactory", "CreateWithAltitudeReferenceSystemAndSpatialReferenceId", 8usize, |this| ((**(this as *const *const IGeopointFactory_Vtbl)).CreateWithAltitudeReferenceSystemAndSpatialReferenceId)(this, position, altitudereferencesystem, spatialreferenceid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            CreateWithAltitudeReferenceSystem,
+            CreateWithAltitudeReferenceSystemAndSpatialReferenceId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeopointFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeoposition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Coordinate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoposition", "Coordinate", 6usize, |this| ((**(this as *const *const IGeoposition_Vtbl)).Coordinate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CivicAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoposition", "CivicAddress", 7usize, |this| ((**(this as *const *const IGeoposition_Vtbl)).CivicAddress)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Coordinate, CivicAddress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeoposition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeoposition2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VenueData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoposition2", "VenueData", 6usize, |this| ((**(this as *const *const IGeoposition2_Vtbl)).VenueData)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, VenueData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeoposition2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeoshape_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GeoshapeType(this: *mut core::ffi::c_void, result__: *mut GeoshapeType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoshape", "GeoshapeType", 6usize, |this| ((**(this as *const *const IGeoshape_Vtbl)).GeoshapeType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpatialReferenceId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoshape", "SpatialReferenceId", 7usize, |this| ((**(this as *const *const IGeoshape_Vtbl)).SpatialReferenceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AltitudeReferenceSystem(this: *mut core::ffi::c_void, result__: *mut AltitudeReferenceSystem) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeoshape", "AltitudeReferenceSystem", 8usize, |this| ((**(this as *const *const IGeoshape_Vtbl)).AltitudeReferenceSystem)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GeoshapeType, SpatialReferenceId, AltitudeReferenceSystem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeoshape as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeovisit_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeovisit", "Position", 6usize, |this| ((**(this as *const *const IGeovisit_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateChange(this: *mut core::ffi::c_void, result__: *mut VisitStateChange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeovisit", "StateChange", 7usize, |this| ((**(this as *const *const IGeovisit_Vtbl)).StateChange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeovisit", "Timestamp", 8usize, |this| ((**(this as *const *const IGeovisit_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Position, StateChange, Timestamp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeovisit as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeovisitMonitor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MonitoringScope(this: *mut core::ffi::c_void, result__: *mut VisitMonitoringScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeovisitMonitor", "MonitoringScope", 6usize, |this| ((**(this as *const *const IGeovisitMonitor_Vtbl)).MonitoringScope)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, value: VisitMonitoringScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeovisitMonitor", "Start", 7usize, |this| ((**(this as *const *const IGeovisitMonitor_Vtbl)).Start)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeovisitMonitor", "Stop", 8usize, |this| ((**(this as *const *const IGeovisitMonitor_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn VisitStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeovisitMonitor", "VisitStateChanged", 9usize, |this| ((**(this as *const *const IGeovisitMonitor_Vtbl)).VisitStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVisitStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeovisitMonitor", "RemoveVisitStateChanged", 10usize, |this| ((**(this as *const *const IGeovisitMonitor_Vtbl)).RemoveVisitStateChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MonitoringScope,
+            Start,
+            Stop,
+            VisitStateChanged,
+            RemoveVisitStateChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeovisitMonitor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeovisitMonitorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLastReportAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeovisitMonitorStatics", "GetLastReportAsync", 6usize, |this| ((**(this as *const *const IGeovisitMonitorStatics_Vtbl)).GetLastReportAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLastReportAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeovisitMonitorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeovisitStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Visit(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeovisitStateChangedEventArgs", "Visit", 6usize, |this| ((**(this as *const *const IGeovisitStateChangedEventArgs_Vtbl)).Visit)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Visit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeovisitStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGeovisitTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadReports(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeovisitTriggerDetails", "ReadReports", 6usize, |this| ((**(this as *const *const IGeovisitTriggerDetails_Vtbl)).ReadReports)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadReports,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadReports: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeovisitTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPositionChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPositionChangedEventArgs", "Position", 6usize, |this| ((**(this as *const *const IPositionChangedEventArgs_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Position }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPositionChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStatusChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut PositionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusChangedEventArgs", "Status", 6usize, |this| ((**(this as *const *const IStatusChangedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStatusChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVenueData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVenueData", "Id", 6usize, |this| ((**(this as *const *const IVenueData_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Level(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVenueData", "Level", 7usize, |this| ((**(this as *const *const IVenueData_Vtbl)).Level)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Level }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVenueData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
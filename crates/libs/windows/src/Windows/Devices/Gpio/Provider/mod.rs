@@ -226,6 +226,7 @@ impl ProviderGpioPinDriveMode {
 impl windows_core::TypeKind for ProviderGpioPinDriveMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProviderGpioPinDriveMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProviderGpioPinDriveMode").field(&self.0).finish()
@@ -244,6 +245,7 @@ impl ProviderGpioPinEdge {
 impl windows_core::TypeKind for ProviderGpioPinEdge {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProviderGpioPinEdge {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProviderGpioPinEdge").field(&self.0).finish()
@@ -262,6 +264,7 @@ impl ProviderGpioPinValue {
 impl windows_core::TypeKind for ProviderGpioPinValue {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProviderGpioPinValue {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProviderGpioPinValue").field(&self.0).finish()
@@ -280,6 +283,7 @@ impl ProviderGpioSharingMode {
 impl windows_core::TypeKind for ProviderGpioSharingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProviderGpioSharingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProviderGpioSharingMode").field(&self.0).finish()
@@ -290,3 +294,5 @@ impl windows_core::RuntimeType for ProviderGpioSharingMode {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,114 @@
+impl windows_core::imp::ProxyVtable for IGpioControllerProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PinCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioControllerProvider", "PinCount", 6usize, |this| ((**(this as *const *const IGpioControllerProvider_Vtbl)).PinCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenPinProvider(this: *mut core::ffi::c_void, pin: i32, sharingmode: ProviderGpioSharingMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioControllerProvider", "OpenPinProvider", 7usize, |this| ((**(this as *const *const IGpioControllerProvider_Vtbl)).OpenPinProvider)(this, pin, sharingmode, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PinCount, OpenPinProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGpioControllerProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGpioPinProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ValueChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPinProvider", "ValueChanged", 6usize, |this| ((**(this as *const *const IGpioPinProvider_Vtbl)).ValueChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveValueChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPinProvider", "RemoveValueChanged", 7usize, |this| ((**(this as *const *const IGpioPinProvider_Vtbl)).RemoveValueChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DebounceTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPinProvider", "DebounceTimeout", 8usize, |this| ((**(this as *const *const IGpioPinProvider_Vtbl)).DebounceTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDebounceTimeout(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPinProvider", "SetDebounceTimeout", 9usize, |this| ((**(this as *const *const IGpioPinProvider_Vtbl)).SetDebounceTimeout)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PinNumber(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPinProvider", "PinNumber", 10usize, |this| ((**(this as *const *const IGpioPinProvider_Vtbl)).PinNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SharingMode(this: *mut core::ffi::c_void, result__: *mut ProviderGpioSharingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPinProvider", "SharingMode", 11usize, |this| ((**(this as *const *const IGpioPinProvider_Vtbl)).SharingMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDriveModeSupported(this: *mut core::ffi::c_void, drivemode: ProviderGpioPinDriveMode, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPinProvider", "IsDriveModeSupported", 12usize, |this| ((**(this as *const *const IGpioPinProvider_Vtbl)).IsDriveModeSupported)(this, drivemode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDriveMode(this: *mut core::ffi::c_void, result__: *mut ProviderGpioPinDriveMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPinProvider", "GetDriveMode", 13usize, |this| ((**(this as *const *const IGpioPinProvider_Vtbl)).GetDriveMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDriveMode(this: *mut core::ffi::c_void, value: ProviderGpioPinDriveMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPinProvider", "SetDriveMode", 14usize, |this| ((**(this as *const *const IGpioPinProvider_Vtbl)).SetDriveMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, value: ProviderGpioPinValue) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPinProvider", "Write", 15usize, |this| ((**(this as *const *const IGpioPinProvider_Vtbl)).Write)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, result__: *mut ProviderGpioPinValue) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPinProvider", "Read", 16usize, |this| ((**(this as *const *const IGpioPinProvider_Vtbl)).Read)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ValueChanged,
+            RemoveValueChanged,
+            DebounceTimeout,
+            SetDebounceTimeout,
+            PinNumber,
+            SharingMode,
+            IsDriveModeSupported,
+            GetDriveMode,
+            SetDriveMode,
+            Write,
+            Read,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGpioPinProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGpioPinProviderValueChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Edge(this: *mut core::ffi::c_void, result__: *mut ProviderGpioPinEdge) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPinProviderValueChangedEventArgs", "Edge", 6usize, |this| ((**(this as *const *const IGpioPinProviderValueChangedEventArgs_Vtbl)).Edge)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Edge }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGpioPinProviderValueChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGpioPinProviderValueChangedEventArgsFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, edge: ProviderGpioPinEdge, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPinProviderValueChangedEventArgsFactory", "Create", 6usize, |this| ((**(this as *const *const IGpioPinProviderValueChangedEventArgsFactory_Vtbl)).Create)(this, edge, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGpioPinProviderValueChangedEventArgsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGpioProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetControllers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioProvider", "GetControllers", 6usize, |this| ((**(this as *const *const IGpioProvider_Vtbl)).GetControllers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetControllers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetControllers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGpioProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
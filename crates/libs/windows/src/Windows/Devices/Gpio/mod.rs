@@ -536,6 +536,7 @@ impl GpioChangePolarity {
 impl windows_core::TypeKind for GpioChangePolarity {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GpioChangePolarity {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GpioChangePolarity").field(&self.0).finish()
@@ -557,6 +558,7 @@ impl GpioOpenStatus {
 impl windows_core::TypeKind for GpioOpenStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GpioOpenStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GpioOpenStatus").field(&self.0).finish()
@@ -581,6 +583,7 @@ impl GpioPinDriveMode {
 impl windows_core::TypeKind for GpioPinDriveMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GpioPinDriveMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GpioPinDriveMode").field(&self.0).finish()
@@ -599,6 +602,7 @@ impl GpioPinEdge {
 impl windows_core::TypeKind for GpioPinEdge {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GpioPinEdge {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GpioPinEdge").field(&self.0).finish()
@@ -617,6 +621,7 @@ impl GpioPinValue {
 impl windows_core::TypeKind for GpioPinValue {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GpioPinValue {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GpioPinValue").field(&self.0).finish()
@@ -635,6 +640,7 @@ impl GpioSharingMode {
 impl windows_core::TypeKind for GpioSharingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GpioSharingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GpioSharingMode").field(&self.0).finish()
@@ -644,7 +650,8 @@ impl windows_core::RuntimeType for GpioSharingMode {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Gpio.GpioSharingMode;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpioChangeCount {
     pub Count: u64,
     pub RelativeTime: super::super::Foundation::TimeSpan,
@@ -661,7 +668,8 @@ impl Default for GpioChangeCount {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpioChangeRecord {
     pub RelativeTime: super::super::Foundation::TimeSpan,
     pub Edge: GpioPinEdge,
@@ -677,3 +685,5 @@ impl Default for GpioChangeRecord {
         unsafe { core::mem::zeroed() }
     }
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
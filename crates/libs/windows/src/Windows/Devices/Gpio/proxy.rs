@@ -0,0 +1,253 @@
+impl windows_core::imp::ProxyVtable for IGpioChangeCounter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPolarity(this: *mut core::ffi::c_void, value: GpioChangePolarity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeCounter", "SetPolarity", 6usize, |this| ((**(this as *const *const IGpioChangeCounter_Vtbl)).SetPolarity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Polarity(this: *mut core::ffi::c_void, result__: *mut GpioChangePolarity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeCounter", "Polarity", 7usize, |this| ((**(this as *const *const IGpioChangeCounter_Vtbl)).Polarity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStarted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeCounter", "IsStarted", 8usize, |this| ((**(this as *const *const IGpioChangeCounter_Vtbl)).IsStarted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeCounter", "Start", 9usize, |this| ((**(this as *const *const IGpioChangeCounter_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeCounter", "Stop", 10usize, |this| ((**(this as *const *const IGpioChangeCounter_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, result__: *mut GpioChangeCount) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeCounter", "Read", 11usize, |this| ((**(this as *const *const IGpioChangeCounter_Vtbl)).Read)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, result__: *mut GpioChangeCount) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeCounter", "Reset", 12usize, |this| ((**(this as *const *const IGpioChangeCounter_Vtbl)).Reset)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPolarity, Polarity, IsStarted, Start, Stop, Read, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGpioChangeCounter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGpioChangeCounterFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeCounterFactory", "Create", 6usize, |this| ((**(this as *const *const IGpioChangeCounterFactory_Vtbl)).Create)(this, pin, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGpioChangeCounterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGpioChangeReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Capacity(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReader", "Capacity", 6usize, |this| ((**(this as *const *const IGpioChangeReader_Vtbl)).Capacity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReader", "Length", 7usize, |this| ((**(this as *const *const IGpioChangeReader_Vtbl)).Length)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEmpty(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReader", "IsEmpty", 8usize, |this| ((**(this as *const *const IGpioChangeReader_Vtbl)).IsEmpty)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOverflowed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReader", "IsOverflowed", 9usize, |this| ((**(this as *const *const IGpioChangeReader_Vtbl)).IsOverflowed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPolarity(this: *mut core::ffi::c_void, value: GpioChangePolarity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReader", "SetPolarity", 10usize, |this| ((**(this as *const *const IGpioChangeReader_Vtbl)).SetPolarity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Polarity(this: *mut core::ffi::c_void, result__: *mut GpioChangePolarity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReader", "Polarity", 11usize, |this| ((**(this as *const *const IGpioChangeReader_Vtbl)).Polarity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStarted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReader", "IsStarted", 12usize, |this| ((**(this as *const *const IGpioChangeReader_Vtbl)).IsStarted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReader", "Start", 13usize, |this| ((**(this as *const *const IGpioChangeReader_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReader", "Stop", 14usize, |this| ((**(this as *const *const IGpioChangeReader_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReader", "Clear", 15usize, |this| ((**(this as *const *const IGpioChangeReader_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextItem(this: *mut core::ffi::c_void, result__: *mut GpioChangeRecord) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReader", "GetNextItem", 16usize, |this| ((**(this as *const *const IGpioChangeReader_Vtbl)).GetNextItem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PeekNextItem(this: *mut core::ffi::c_void, result__: *mut GpioChangeRecord) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReader", "PeekNextItem", 17usize, |this| ((**(this as *const *const IGpioChangeReader_Vtbl)).PeekNextItem)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAllItems(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReader", "GetAllItems", 18usize, |this| ((**(this as *const *const IGpioChangeReader_Vtbl)).GetAllItems)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForItemsAsync(this: *mut core::ffi::c_void, count: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReader", "WaitForItemsAsync", 19usize, |this| ((**(this as *const *const IGpioChangeReader_Vtbl)).WaitForItemsAsync)(this, count, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Capacity,
+            Length,
+            IsEmpty,
+            IsOverflowed,
+            SetPolarity,
+            Polarity,
+            IsStarted,
+            Start,
+            Stop,
+            Clear,
+            GetNextItem,
+            PeekNextItem,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAllItems,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAllItems: 0,
+            WaitForItemsAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGpioChangeReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGpioChangeReaderFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReaderFactory", "Create", 6usize, |this| ((**(this as *const *const IGpioChangeReaderFactory_Vtbl)).Create)(this, pin, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithCapacity(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void, mincapacity: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioChangeReaderFactory", "CreateWithCapacity", 7usize, |this| ((**(this as *const *const IGpioChangeReaderFactory_Vtbl)).CreateWithCapacity)(this, pin, mincapacity, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithCapacity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGpioChangeReaderFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGpioController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PinCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioController", "PinCount", 6usize, |this| ((**(this as *const *const IGpioController_Vtbl)).PinCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenPin(this: *mut core::ffi::c_void, pinnumber: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioController", "OpenPin", 7usize, |this| ((**(this as *const *const IGpioController_Vtbl)).OpenPin)(this, pinnumber, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenPinWithSharingMode(this: *mut core::ffi::c_void, pinnumber: i32, sharingmode: GpioSharingMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioController", "OpenPinWithSharingMode", 8usize, |this| ((**(this as *const *const IGpioController_Vtbl)).OpenPinWithSharingMode)(this, pinnumber, sharingmode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryOpenPin(this: *mut core::ffi::c_void, pinnumber: i32, sharingmode: GpioSharingMode, pin: *mut *mut core::ffi::c_void, openstatus: *mut GpioOpenStatus, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioController", "TryOpenPin", 9usize, |this| ((**(this as *const *const IGpioController_Vtbl)).TryOpenPin)(this, pinnumber, sharingmode, pin, openstatus, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PinCount, OpenPin, OpenPinWithSharingMode, TryOpenPin }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGpioController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGpioControllerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioControllerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IGpioControllerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGpioControllerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Gpio_Provider", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IGpioControllerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Devices_Gpio_Provider", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetControllersAsync(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioControllerStatics2", "GetControllersAsync", 6usize, |this| ((**(this as *const *const IGpioControllerStatics2_Vtbl)).GetControllersAsync)(this, provider, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioControllerStatics2", "GetDefaultAsync", 7usize, |this| ((**(this as *const *const IGpioControllerStatics2_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Devices_Gpio_Provider", feature = "Foundation_Collections"))]
+            GetControllersAsync,
+            #[cfg(not(all(feature = "Devices_Gpio_Provider", feature = "Foundation_Collections")))]
+            GetControllersAsync: 0,
+            GetDefaultAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGpioControllerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGpioPin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ValueChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPin", "ValueChanged", 6usize, |this| ((**(this as *const *const IGpioPin_Vtbl)).ValueChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveValueChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPin", "RemoveValueChanged", 7usize, |this| ((**(this as *const *const IGpioPin_Vtbl)).RemoveValueChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DebounceTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPin", "DebounceTimeout", 8usize, |this| ((**(this as *const *const IGpioPin_Vtbl)).DebounceTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDebounceTimeout(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPin", "SetDebounceTimeout", 9usize, |this| ((**(this as *const *const IGpioPin_Vtbl)).SetDebounceTimeout)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PinNumber(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPin", "PinNumber", 10usize, |this| ((**(this as *const *const IGpioPin_Vtbl)).PinNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SharingMode(this: *mut core::ffi::c_void, result__: *mut GpioSharingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPin", "SharingMode", 11usize, |this| ((**(this as *const *const IGpioPin_Vtbl)).SharingMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDriveModeSupported(this: *mut core::ffi::c_void, drivemode: GpioPinDriveMode, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPin", "IsDriveModeSupported", 12usize, |this| ((**(this as *const *const IGpioPin_Vtbl)).IsDriveModeSupported)(this, drivemode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDriveMode(this: *mut core::ffi::c_void, result__: *mut GpioPinDriveMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPin", "GetDriveMode", 13usize, |this| ((**(this as *const *const IGpioPin_Vtbl)).GetDriveMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDriveMode(this: *mut core::ffi::c_void, value: GpioPinDriveMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPin", "SetDriveMode", 14usize, |this| ((**(this as *const *const IGpioPin_Vtbl)).SetDriveMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, value: GpioPinValue) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPin", "Write", 15usize, |this| ((**(this as *const *const IGpioPin_Vtbl)).Write)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, result__: *mut GpioPinValue) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPin", "Read", 16usize, |this| ((**(this as *const *const IGpioPin_Vtbl)).Read)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ValueChanged,
+            RemoveValueChanged,
+            DebounceTimeout,
+            SetDebounceTimeout,
+            PinNumber,
+            SharingMode,
+            IsDriveModeSupported,
+            GetDriveMode,
+            SetDriveMode,
+            Write,
+            Read,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGpioPin as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGpioPinValueChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Edge(this: *mut core::ffi::c_void, result__: *mut GpioPinEdge) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpioPinValueChangedEventArgs", "Edge", 6usize, |this| ((**(this as *const *const IGpioPinValueChangedEventArgs_Vtbl)).Edge)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Edge }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGpioPinValueChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -398,6 +398,7 @@ impl VibrationAccessStatus {
 impl windows_core::TypeKind for VibrationAccessStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VibrationAccessStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VibrationAccessStatus").field(&self.0).finish()
@@ -406,3 +407,5 @@ impl core::fmt::Debug for VibrationAccessStatus {
 impl windows_core::RuntimeType for VibrationAccessStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Haptics.VibrationAccessStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
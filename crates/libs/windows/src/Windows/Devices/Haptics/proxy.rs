@@ -0,0 +1,201 @@
+impl windows_core::imp::ProxyVtable for IKnownSimpleHapticsControllerWaveformsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Click(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimpleHapticsControllerWaveformsStatics", "Click", 6usize, |this| ((**(this as *const *const IKnownSimpleHapticsControllerWaveformsStatics_Vtbl)).Click)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuzzContinuous(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimpleHapticsControllerWaveformsStatics", "BuzzContinuous", 7usize, |this| ((**(this as *const *const IKnownSimpleHapticsControllerWaveformsStatics_Vtbl)).BuzzContinuous)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RumbleContinuous(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimpleHapticsControllerWaveformsStatics", "RumbleContinuous", 8usize, |this| ((**(this as *const *const IKnownSimpleHapticsControllerWaveformsStatics_Vtbl)).RumbleContinuous)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Press(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimpleHapticsControllerWaveformsStatics", "Press", 9usize, |this| ((**(this as *const *const IKnownSimpleHapticsControllerWaveformsStatics_Vtbl)).Press)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Release(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimpleHapticsControllerWaveformsStatics", "Release", 10usize, |this| ((**(this as *const *const IKnownSimpleHapticsControllerWaveformsStatics_Vtbl)).Release)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Click, BuzzContinuous, RumbleContinuous, Press, Release }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownSimpleHapticsControllerWaveformsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKnownSimpleHapticsControllerWaveformsStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BrushContinuous(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimpleHapticsControllerWaveformsStatics2", "BrushContinuous", 6usize, |this| ((**(this as *const *const IKnownSimpleHapticsControllerWaveformsStatics2_Vtbl)).BrushContinuous)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChiselMarkerContinuous(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimpleHapticsControllerWaveformsStatics2", "ChiselMarkerContinuous", 7usize, |this| ((**(this as *const *const IKnownSimpleHapticsControllerWaveformsStatics2_Vtbl)).ChiselMarkerContinuous)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EraserContinuous(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimpleHapticsControllerWaveformsStatics2", "EraserContinuous", 8usize, |this| ((**(this as *const *const IKnownSimpleHapticsControllerWaveformsStatics2_Vtbl)).EraserContinuous)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimpleHapticsControllerWaveformsStatics2", "Error", 9usize, |this| ((**(this as *const *const IKnownSimpleHapticsControllerWaveformsStatics2_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GalaxyPenContinuous(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimpleHapticsControllerWaveformsStatics2", "GalaxyPenContinuous", 10usize, |this| ((**(this as *const *const IKnownSimpleHapticsControllerWaveformsStatics2_Vtbl)).GalaxyPenContinuous)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hover(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimpleHapticsControllerWaveformsStatics2", "Hover", 11usize, |this| ((**(this as *const *const IKnownSimpleHapticsControllerWaveformsStatics2_Vtbl)).Hover)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InkContinuous(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimpleHapticsControllerWaveformsStatics2", "InkContinuous", 12usize, |this| ((**(this as *const *const IKnownSimpleHapticsControllerWaveformsStatics2_Vtbl)).InkContinuous)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkerContinuous(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimpleHapticsControllerWaveformsStatics2", "MarkerContinuous", 13usize, |this| ((**(this as *const *const IKnownSimpleHapticsControllerWaveformsStatics2_Vtbl)).MarkerContinuous)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PencilContinuous(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimpleHapticsControllerWaveformsStatics2", "PencilContinuous", 14usize, |this| ((**(this as *const *const IKnownSimpleHapticsControllerWaveformsStatics2_Vtbl)).PencilContinuous)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Success(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimpleHapticsControllerWaveformsStatics2", "Success", 15usize, |this| ((**(this as *const *const IKnownSimpleHapticsControllerWaveformsStatics2_Vtbl)).Success)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BrushContinuous,
+            ChiselMarkerContinuous,
+            EraserContinuous,
+            Error,
+            GalaxyPenContinuous,
+            Hover,
+            InkContinuous,
+            MarkerContinuous,
+            PencilContinuous,
+            Success,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownSimpleHapticsControllerWaveformsStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISimpleHapticsController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleHapticsController", "Id", 6usize, |this| ((**(this as *const *const ISimpleHapticsController_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedFeedback(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleHapticsController", "SupportedFeedback", 7usize, |this| ((**(this as *const *const ISimpleHapticsController_Vtbl)).SupportedFeedback)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIntensitySupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleHapticsController", "IsIntensitySupported", 8usize, |this| ((**(this as *const *const ISimpleHapticsController_Vtbl)).IsIntensitySupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPlayCountSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleHapticsController", "IsPlayCountSupported", 9usize, |this| ((**(this as *const *const ISimpleHapticsController_Vtbl)).IsPlayCountSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPlayDurationSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleHapticsController", "IsPlayDurationSupported", 10usize, |this| ((**(this as *const *const ISimpleHapticsController_Vtbl)).IsPlayDurationSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReplayPauseIntervalSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleHapticsController", "IsReplayPauseIntervalSupported", 11usize, |this| ((**(this as *const *const ISimpleHapticsController_Vtbl)).IsReplayPauseIntervalSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopFeedback(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleHapticsController", "StopFeedback", 12usize, |this| ((**(this as *const *const ISimpleHapticsController_Vtbl)).StopFeedback)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendHapticFeedback(this: *mut core::ffi::c_void, feedback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleHapticsController", "SendHapticFeedback", 13usize, |this| ((**(this as *const *const ISimpleHapticsController_Vtbl)).SendHapticFeedback)(this, feedback), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendHapticFeedbackWithIntensity(this: *mut core::ffi::c_void, feedback: *mut core::ffi::c_void, intensity: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleHapticsController", "SendHapticFeedbackWithIntensity", 14usize, |this| ((**(this as *const *const ISimpleHapticsController_Vtbl)).SendHapticFeedbackWithIntensity)(this, feedback, intensity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendHapticFeedbackForDuration(this: *mut core::ffi::c_void, feedback: *mut core::ffi::c_void, intensity: f64, playduration: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleHapticsController", "SendHapticFeedbackForDuration", 15usize, |this| ((**(this as *const *const ISimpleHapticsController_Vtbl)).SendHapticFeedbackForDuration)(this, feedback, intensity, playduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendHapticFeedbackForPlayCount(this: *mut core::ffi::c_void, feedback: *mut core::ffi::c_void, intensity: f64, playcount: i32, replaypauseinterval: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleHapticsController", "SendHapticFeedbackForPlayCount", 16usize, |this| ((**(this as *const *const ISimpleHapticsController_Vtbl)).SendHapticFeedbackForPlayCount)(this, feedback, intensity, playcount, replaypauseinterval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedFeedback,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedFeedback: 0,
+            IsIntensitySupported,
+            IsPlayCountSupported,
+            IsPlayDurationSupported,
+            IsReplayPauseIntervalSupported,
+            StopFeedback,
+            SendHapticFeedback,
+            SendHapticFeedbackWithIntensity,
+            SendHapticFeedbackForDuration,
+            SendHapticFeedbackForPlayCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimpleHapticsController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimpleHapticsControllerFeedback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Waveform(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleHapticsControllerFeedback", "Waveform", 6usize, |this| ((**(this as *const *const ISimpleHapticsControllerFeedback_Vtbl)).Waveform)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleHapticsControllerFeedback", "Duration", 7usize, |this| ((**(this as *const *const ISimpleHapticsControllerFeedback_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Waveform, Duration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimpleHapticsControllerFeedback as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVibrationDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVibrationDevice", "Id", 6usize, |this| ((**(this as *const *const IVibrationDevice_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SimpleHapticsController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVibrationDevice", "SimpleHapticsController", 7usize, |this| ((**(this as *const *const IVibrationDevice_Vtbl)).SimpleHapticsController)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, SimpleHapticsController }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVibrationDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVibrationDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVibrationDeviceStatics", "RequestAccessAsync", 6usize, |this| ((**(this as *const *const IVibrationDeviceStatics_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVibrationDeviceStatics", "GetDeviceSelector", 7usize, |this| ((**(this as *const *const IVibrationDeviceStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVibrationDeviceStatics", "FromIdAsync", 8usize, |this| ((**(this as *const *const IVibrationDeviceStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVibrationDeviceStatics", "GetDefaultAsync", 9usize, |this| ((**(this as *const *const IVibrationDeviceStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVibrationDeviceStatics", "FindAllAsync", 10usize, |this| ((**(this as *const *const IVibrationDeviceStatics_Vtbl)).FindAllAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestAccessAsync,
+            GetDeviceSelector,
+            FromIdAsync,
+            GetDefaultAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVibrationDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
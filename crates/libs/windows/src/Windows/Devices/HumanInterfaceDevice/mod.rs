@@ -1056,6 +1056,7 @@ impl HidCollectionType {
 impl windows_core::TypeKind for HidCollectionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HidCollectionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HidCollectionType").field(&self.0).finish()
@@ -1075,6 +1076,7 @@ impl HidReportType {
 impl windows_core::TypeKind for HidReportType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HidReportType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HidReportType").field(&self.0).finish()
@@ -1083,3 +1085,5 @@ impl core::fmt::Debug for HidReportType {
 impl windows_core::RuntimeType for HidReportType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.HumanInterfaceDevice.HidReportType;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
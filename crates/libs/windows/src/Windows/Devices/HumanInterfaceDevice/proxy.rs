@@ -0,0 +1,523 @@
+impl windows_core::imp::ProxyVtable for IHidBooleanControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidBooleanControl", "Id", 6usize, |this| ((**(this as *const *const IHidBooleanControl_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsagePage(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidBooleanControl", "UsagePage", 7usize, |this| ((**(this as *const *const IHidBooleanControl_Vtbl)).UsagePage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsageId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidBooleanControl", "UsageId", 8usize, |this| ((**(this as *const *const IHidBooleanControl_Vtbl)).UsageId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsActive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidBooleanControl", "IsActive", 9usize, |this| ((**(this as *const *const IHidBooleanControl_Vtbl)).IsActive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsActive(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidBooleanControl", "SetIsActive", 10usize, |this| ((**(this as *const *const IHidBooleanControl_Vtbl)).SetIsActive)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControlDescription(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidBooleanControl", "ControlDescription", 11usize, |this| ((**(this as *const *const IHidBooleanControl_Vtbl)).ControlDescription)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            UsagePage,
+            UsageId,
+            IsActive,
+            SetIsActive,
+            ControlDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHidBooleanControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHidBooleanControlDescription_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidBooleanControlDescription", "Id", 6usize, |this| ((**(this as *const *const IHidBooleanControlDescription_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidBooleanControlDescription", "ReportId", 7usize, |this| ((**(this as *const *const IHidBooleanControlDescription_Vtbl)).ReportId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportType(this: *mut core::ffi::c_void, result__: *mut HidReportType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidBooleanControlDescription", "ReportType", 8usize, |this| ((**(this as *const *const IHidBooleanControlDescription_Vtbl)).ReportType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsagePage(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidBooleanControlDescription", "UsagePage", 9usize, |this| ((**(this as *const *const IHidBooleanControlDescription_Vtbl)).UsagePage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsageId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidBooleanControlDescription", "UsageId", 10usize, |this| ((**(this as *const *const IHidBooleanControlDescription_Vtbl)).UsageId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ParentCollections(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidBooleanControlDescription", "ParentCollections", 11usize, |this| ((**(this as *const *const IHidBooleanControlDescription_Vtbl)).ParentCollections)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            ReportId,
+            ReportType,
+            UsagePage,
+            UsageId,
+            #[cfg(feature = "Foundation_Collections")]
+            ParentCollections,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ParentCollections: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHidBooleanControlDescription as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHidBooleanControlDescription2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAbsolute(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidBooleanControlDescription2", "IsAbsolute", 6usize, |this| ((**(this as *const *const IHidBooleanControlDescription2_Vtbl)).IsAbsolute)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsAbsolute }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHidBooleanControlDescription2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHidCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidCollection", "Id", 6usize, |this| ((**(this as *const *const IHidCollection_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut HidCollectionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidCollection", "Type", 7usize, |this| ((**(this as *const *const IHidCollection_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsagePage(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidCollection", "UsagePage", 8usize, |this| ((**(this as *const *const IHidCollection_Vtbl)).UsagePage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsageId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidCollection", "UsageId", 9usize, |this| ((**(this as *const *const IHidCollection_Vtbl)).UsageId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Type, UsagePage, UsageId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHidCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHidDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VendorId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "VendorId", 6usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).VendorId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProductId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "ProductId", 7usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).ProductId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "Version", 8usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).Version)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsagePage(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "UsagePage", 9usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).UsagePage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsageId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "UsageId", 10usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).UsageId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputReportAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "GetInputReportAsync", 11usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).GetInputReportAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputReportByIdAsync(this: *mut core::ffi::c_void, reportid: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "GetInputReportByIdAsync", 12usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).GetInputReportByIdAsync)(this, reportid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeatureReportAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "GetFeatureReportAsync", 13usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).GetFeatureReportAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeatureReportByIdAsync(this: *mut core::ffi::c_void, reportid: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "GetFeatureReportByIdAsync", 14usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).GetFeatureReportByIdAsync)(this, reportid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOutputReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "CreateOutputReport", 15usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).CreateOutputReport)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOutputReportById(this: *mut core::ffi::c_void, reportid: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "CreateOutputReportById", 16usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).CreateOutputReportById)(this, reportid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFeatureReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "CreateFeatureReport", 17usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).CreateFeatureReport)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFeatureReportById(this: *mut core::ffi::c_void, reportid: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "CreateFeatureReportById", 18usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).CreateFeatureReportById)(this, reportid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendOutputReportAsync(this: *mut core::ffi::c_void, outputreport: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "SendOutputReportAsync", 19usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).SendOutputReportAsync)(this, outputreport, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendFeatureReportAsync(this: *mut core::ffi::c_void, featurereport: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "SendFeatureReportAsync", 20usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).SendFeatureReportAsync)(this, featurereport, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetBooleanControlDescriptions(this: *mut core::ffi::c_void, reporttype: HidReportType, usagepage: u16, usageid: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "GetBooleanControlDescriptions", 21usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).GetBooleanControlDescriptions)(this, reporttype, usagepage, usageid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetNumericControlDescriptions(this: *mut core::ffi::c_void, reporttype: HidReportType, usagepage: u16, usageid: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "GetNumericControlDescriptions", 22usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).GetNumericControlDescriptions)(this, reporttype, usagepage, usageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputReportReceived(this: *mut core::ffi::c_void, reporthandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "InputReportReceived", 23usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).InputReportReceived)(this, reporthandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveInputReportReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDevice", "RemoveInputReportReceived", 24usize, |this| ((**(this as *const *const IHidDevice_Vtbl)).RemoveInputReportReceived)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            VendorId,
+            ProductId,
+            Version,
+            UsagePage,
+            UsageId,
+            GetInputReportAsync,
+            GetInputReportByIdAsync,
+            GetFeatureReportAsync,
+            GetFeatureReportByIdAsync,
+            CreateOutputReport,
+            CreateOutputReportById,
+            CreateFeatureReport,
+            CreateFeatureReportById,
+            SendOutputReportAsync,
+            SendFeatureReportAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetBooleanControlDescriptions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetBooleanControlDescriptions: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetNumericControlDescriptions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetNumericControlDescriptions: 0,
+            InputReportReceived,
+            RemoveInputReportReceived,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHidDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IHidDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, usagepage: u16, usageid: u16, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDeviceStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IHidDeviceStatics_Vtbl)).GetDeviceSelector)(this, usagepage, usageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorVidPid(this: *mut core::ffi::c_void, usagepage: u16, usageid: u16, vendorid: u16, productid: u16, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDeviceStatics", "GetDeviceSelectorVidPid", 7usize, |this| ((**(this as *const *const IHidDeviceStatics_Vtbl)).GetDeviceSelectorVidPid)(this, usagepage, usageid, vendorid, productid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, accessmode: super::super::Storage::FileAccessMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidDeviceStatics", "FromIdAsync", 8usize, |this| ((**(this as *const *const IHidDeviceStatics_Vtbl)).FromIdAsync)(this, deviceid, accessmode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceSelector,
+            GetDeviceSelectorVidPid,
+            #[cfg(feature = "Storage")]
+            FromIdAsync,
+            #[cfg(not(feature = "Storage"))]
+            FromIdAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHidDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IHidFeatureReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidFeatureReport", "Id", 6usize, |this| ((**(this as *const *const IHidFeatureReport_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidFeatureReport", "Data", 7usize, |this| ((**(this as *const *const IHidFeatureReport_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidFeatureReport", "SetData", 8usize, |this| ((**(this as *const *const IHidFeatureReport_Vtbl)).SetData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBooleanControl(this: *mut core::ffi::c_void, usagepage: u16, usageid: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidFeatureReport", "GetBooleanControl", 9usize, |this| ((**(this as *const *const IHidFeatureReport_Vtbl)).GetBooleanControl)(this, usagepage, usageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBooleanControlByDescription(this: *mut core::ffi::c_void, controldescription: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidFeatureReport", "GetBooleanControlByDescription", 10usize, |this| ((**(this as *const *const IHidFeatureReport_Vtbl)).GetBooleanControlByDescription)(this, controldescription, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumericControl(this: *mut core::ffi::c_void, usagepage: u16, usageid: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidFeatureReport", "GetNumericControl", 11usize, |this| ((**(this as *const *const IHidFeatureReport_Vtbl)).GetNumericControl)(this, usagepage, usageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumericControlByDescription(this: *mut core::ffi::c_void, controldescription: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidFeatureReport", "GetNumericControlByDescription", 12usize, |this| ((**(this as *const *const IHidFeatureReport_Vtbl)).GetNumericControlByDescription)(this, controldescription, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            #[cfg(feature = "Storage_Streams")]
+            Data,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Data: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetData: 0,
+            GetBooleanControl,
+            GetBooleanControlByDescription,
+            GetNumericControl,
+            GetNumericControlByDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHidFeatureReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IHidInputReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidInputReport", "Id", 6usize, |this| ((**(this as *const *const IHidInputReport_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidInputReport", "Data", 7usize, |this| ((**(this as *const *const IHidInputReport_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ActivatedBooleanControls(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidInputReport", "ActivatedBooleanControls", 8usize, |this| ((**(this as *const *const IHidInputReport_Vtbl)).ActivatedBooleanControls)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TransitionedBooleanControls(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidInputReport", "TransitionedBooleanControls", 9usize, |this| ((**(this as *const *const IHidInputReport_Vtbl)).TransitionedBooleanControls)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBooleanControl(this: *mut core::ffi::c_void, usagepage: u16, usageid: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidInputReport", "GetBooleanControl", 10usize, |this| ((**(this as *const *const IHidInputReport_Vtbl)).GetBooleanControl)(this, usagepage, usageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBooleanControlByDescription(this: *mut core::ffi::c_void, controldescription: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidInputReport", "GetBooleanControlByDescription", 11usize, |this| ((**(this as *const *const IHidInputReport_Vtbl)).GetBooleanControlByDescription)(this, controldescription, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumericControl(this: *mut core::ffi::c_void, usagepage: u16, usageid: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidInputReport", "GetNumericControl", 12usize, |this| ((**(this as *const *const IHidInputReport_Vtbl)).GetNumericControl)(this, usagepage, usageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumericControlByDescription(this: *mut core::ffi::c_void, controldescription: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidInputReport", "GetNumericControlByDescription", 13usize, |this| ((**(this as *const *const IHidInputReport_Vtbl)).GetNumericControlByDescription)(this, controldescription, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            #[cfg(feature = "Storage_Streams")]
+            Data,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Data: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ActivatedBooleanControls,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ActivatedBooleanControls: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            TransitionedBooleanControls,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TransitionedBooleanControls: 0,
+            GetBooleanControl,
+            GetBooleanControlByDescription,
+            GetNumericControl,
+            GetNumericControlByDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHidInputReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHidInputReportReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Report(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidInputReportReceivedEventArgs", "Report", 6usize, |this| ((**(this as *const *const IHidInputReportReceivedEventArgs_Vtbl)).Report)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Report }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHidInputReportReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHidNumericControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControl", "Id", 6usize, |this| ((**(this as *const *const IHidNumericControl_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGrouped(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControl", "IsGrouped", 7usize, |this| ((**(this as *const *const IHidNumericControl_Vtbl)).IsGrouped)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsagePage(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControl", "UsagePage", 8usize, |this| ((**(this as *const *const IHidNumericControl_Vtbl)).UsagePage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsageId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControl", "UsageId", 9usize, |this| ((**(this as *const *const IHidNumericControl_Vtbl)).UsageId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControl", "Value", 10usize, |this| ((**(this as *const *const IHidNumericControl_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControl", "SetValue", 11usize, |this| ((**(this as *const *const IHidNumericControl_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaledValue(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControl", "ScaledValue", 12usize, |this| ((**(this as *const *const IHidNumericControl_Vtbl)).ScaledValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaledValue(this: *mut core::ffi::c_void, value: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControl", "SetScaledValue", 13usize, |this| ((**(this as *const *const IHidNumericControl_Vtbl)).SetScaledValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControlDescription(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControl", "ControlDescription", 14usize, |this| ((**(this as *const *const IHidNumericControl_Vtbl)).ControlDescription)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            IsGrouped,
+            UsagePage,
+            UsageId,
+            Value,
+            SetValue,
+            ScaledValue,
+            SetScaledValue,
+            ControlDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHidNumericControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHidNumericControlDescription_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "Id", 6usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "ReportId", 7usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).ReportId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportType(this: *mut core::ffi::c_void, result__: *mut HidReportType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "ReportType", 8usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).ReportType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "ReportSize", 9usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).ReportSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "ReportCount", 10usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).ReportCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsagePage(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "UsagePage", 11usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).UsagePage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsageId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "UsageId", 12usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).UsageId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogicalMinimum(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "LogicalMinimum", 13usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).LogicalMinimum)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogicalMaximum(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "LogicalMaximum", 14usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).LogicalMaximum)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhysicalMinimum(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "PhysicalMinimum", 15usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).PhysicalMinimum)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhysicalMaximum(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "PhysicalMaximum", 16usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).PhysicalMaximum)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnitExponent(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "UnitExponent", 17usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).UnitExponent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unit(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "Unit", 18usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).Unit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAbsolute(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "IsAbsolute", 19usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).IsAbsolute)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasNull(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "HasNull", 20usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).HasNull)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ParentCollections(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidNumericControlDescription", "ParentCollections", 21usize, |this| ((**(this as *const *const IHidNumericControlDescription_Vtbl)).ParentCollections)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            ReportId,
+            ReportType,
+            ReportSize,
+            ReportCount,
+            UsagePage,
+            UsageId,
+            LogicalMinimum,
+            LogicalMaximum,
+            PhysicalMinimum,
+            PhysicalMaximum,
+            UnitExponent,
+            Unit,
+            IsAbsolute,
+            HasNull,
+            #[cfg(feature = "Foundation_Collections")]
+            ParentCollections,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ParentCollections: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHidNumericControlDescription as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IHidOutputReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidOutputReport", "Id", 6usize, |this| ((**(this as *const *const IHidOutputReport_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidOutputReport", "Data", 7usize, |this| ((**(this as *const *const IHidOutputReport_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidOutputReport", "SetData", 8usize, |this| ((**(this as *const *const IHidOutputReport_Vtbl)).SetData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBooleanControl(this: *mut core::ffi::c_void, usagepage: u16, usageid: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidOutputReport", "GetBooleanControl", 9usize, |this| ((**(this as *const *const IHidOutputReport_Vtbl)).GetBooleanControl)(this, usagepage, usageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBooleanControlByDescription(this: *mut core::ffi::c_void, controldescription: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidOutputReport", "GetBooleanControlByDescription", 10usize, |this| ((**(this as *const *const IHidOutputReport_Vtbl)).GetBooleanControlByDescription)(this, controldescription, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumericControl(this: *mut core::ffi::c_void, usagepage: u16, usageid: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidOutputReport", "GetNumericControl", 11usize, |this| ((**(this as *const *const IHidOutputReport_Vtbl)).GetNumericControl)(this, usagepage, usageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumericControlByDescription(this: *mut core::ffi::c_void, controldescription: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidOutputReport", "GetNumericControlByDescription", 12usize, |this| ((**(this as *const *const IHidOutputReport_Vtbl)).GetNumericControlByDescription)(this, controldescription, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            #[cfg(feature = "Storage_Streams")]
+            Data,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Data: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetData: 0,
+            GetBooleanControl,
+            GetBooleanControlByDescription,
+            GetNumericControl,
+            GetNumericControlByDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHidOutputReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -199,6 +199,7 @@ impl ProviderI2cBusSpeed {
 impl windows_core::TypeKind for ProviderI2cBusSpeed {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProviderI2cBusSpeed {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProviderI2cBusSpeed").field(&self.0).finish()
@@ -217,6 +218,7 @@ impl ProviderI2cSharingMode {
 impl windows_core::TypeKind for ProviderI2cSharingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProviderI2cSharingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProviderI2cSharingMode").field(&self.0).finish()
@@ -236,6 +238,7 @@ impl ProviderI2cTransferStatus {
 impl windows_core::TypeKind for ProviderI2cTransferStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProviderI2cTransferStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProviderI2cTransferStatus").field(&self.0).finish()
@@ -245,7 +248,8 @@ impl windows_core::RuntimeType for ProviderI2cTransferStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.I2c.Provider.ProviderI2cTransferStatus;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ProviderI2cTransferResult {
     pub Status: ProviderI2cTransferStatus,
     pub BytesTransferred: u32,
@@ -263,3 +267,5 @@ impl Default for ProviderI2cTransferResult {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
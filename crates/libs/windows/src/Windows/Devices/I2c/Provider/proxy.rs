@@ -0,0 +1,106 @@
+impl windows_core::imp::ProxyVtable for II2cControllerProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceProvider(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cControllerProvider", "GetDeviceProvider", 6usize, |this| ((**(this as *const *const II2cControllerProvider_Vtbl)).GetDeviceProvider)(this, settings, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<II2cControllerProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for II2cDeviceProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDeviceProvider", "DeviceId", 6usize, |this| ((**(this as *const *const II2cDeviceProvider_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, buffer_array_size: u32, buffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDeviceProvider", "Write", 7usize, |this| ((**(this as *const *const II2cDeviceProvider_Vtbl)).Write)(this, buffer_array_size, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn WritePartial(this: *mut core::ffi::c_void, buffer_array_size: u32, buffer: *const u8, result__: *mut ProviderI2cTransferResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDeviceProvider", "WritePartial", 8usize, |this| ((**(this as *const *const II2cDeviceProvider_Vtbl)).WritePartial)(this, buffer_array_size, buffer, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, buffer_array_size: u32, buffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDeviceProvider", "Read", 9usize, |this| ((**(this as *const *const II2cDeviceProvider_Vtbl)).Read)(this, buffer_array_size, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadPartial(this: *mut core::ffi::c_void, buffer_array_size: u32, buffer: *mut u8, result__: *mut ProviderI2cTransferResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDeviceProvider", "ReadPartial", 10usize, |this| ((**(this as *const *const II2cDeviceProvider_Vtbl)).ReadPartial)(this, buffer_array_size, buffer, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteRead(this: *mut core::ffi::c_void, writeBuffer_array_size: u32, writebuffer: *const u8, readBuffer_array_size: u32, readbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDeviceProvider", "WriteRead", 11usize, |this| ((**(this as *const *const II2cDeviceProvider_Vtbl)).WriteRead)(this, writeBuffer_array_size, writebuffer, readBuffer_array_size, readbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteReadPartial(this: *mut core::ffi::c_void, writeBuffer_array_size: u32, writebuffer: *const u8, readBuffer_array_size: u32, readbuffer: *mut u8, result__: *mut ProviderI2cTransferResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDeviceProvider", "WriteReadPartial", 12usize, |this| ((**(this as *const *const II2cDeviceProvider_Vtbl)).WriteReadPartial)(this, writeBuffer_array_size, writebuffer, readBuffer_array_size, readbuffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            Write,
+            WritePartial,
+            Read,
+            ReadPartial,
+            WriteRead,
+            WriteReadPartial,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<II2cDeviceProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for II2cProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetControllersAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cProvider", "GetControllersAsync", 6usize, |this| ((**(this as *const *const II2cProvider_Vtbl)).GetControllersAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetControllersAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetControllersAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<II2cProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProviderI2cConnectionSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SlaveAddress(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderI2cConnectionSettings", "SlaveAddress", 6usize, |this| ((**(this as *const *const IProviderI2cConnectionSettings_Vtbl)).SlaveAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSlaveAddress(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderI2cConnectionSettings", "SetSlaveAddress", 7usize, |this| ((**(this as *const *const IProviderI2cConnectionSettings_Vtbl)).SetSlaveAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BusSpeed(this: *mut core::ffi::c_void, result__: *mut ProviderI2cBusSpeed) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderI2cConnectionSettings", "BusSpeed", 8usize, |this| ((**(this as *const *const IProviderI2cConnectionSettings_Vtbl)).BusSpeed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBusSpeed(this: *mut core::ffi::c_void, value: ProviderI2cBusSpeed) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderI2cConnectionSettings", "SetBusSpeed", 9usize, |this| ((**(this as *const *const IProviderI2cConnectionSettings_Vtbl)).SetBusSpeed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SharingMode(this: *mut core::ffi::c_void, result__: *mut ProviderI2cSharingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderI2cConnectionSettings", "SharingMode", 10usize, |this| ((**(this as *const *const IProviderI2cConnectionSettings_Vtbl)).SharingMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSharingMode(this: *mut core::ffi::c_void, value: ProviderI2cSharingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderI2cConnectionSettings", "SetSharingMode", 11usize, |this| ((**(this as *const *const IProviderI2cConnectionSettings_Vtbl)).SetSharingMode)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SlaveAddress,
+            SetSlaveAddress,
+            BusSpeed,
+            SetBusSpeed,
+            SharingMode,
+            SetSharingMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProviderI2cConnectionSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
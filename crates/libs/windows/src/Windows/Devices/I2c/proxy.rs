@@ -0,0 +1,149 @@
+impl windows_core::imp::ProxyVtable for II2cConnectionSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SlaveAddress(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cConnectionSettings", "SlaveAddress", 6usize, |this| ((**(this as *const *const II2cConnectionSettings_Vtbl)).SlaveAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSlaveAddress(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cConnectionSettings", "SetSlaveAddress", 7usize, |this| ((**(this as *const *const II2cConnectionSettings_Vtbl)).SetSlaveAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BusSpeed(this: *mut core::ffi::c_void, result__: *mut I2cBusSpeed) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cConnectionSettings", "BusSpeed", 8usize, |this| ((**(this as *const *const II2cConnectionSettings_Vtbl)).BusSpeed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBusSpeed(this: *mut core::ffi::c_void, value: I2cBusSpeed) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cConnectionSettings", "SetBusSpeed", 9usize, |this| ((**(this as *const *const II2cConnectionSettings_Vtbl)).SetBusSpeed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SharingMode(this: *mut core::ffi::c_void, result__: *mut I2cSharingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cConnectionSettings", "SharingMode", 10usize, |this| ((**(this as *const *const II2cConnectionSettings_Vtbl)).SharingMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSharingMode(this: *mut core::ffi::c_void, value: I2cSharingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cConnectionSettings", "SetSharingMode", 11usize, |this| ((**(this as *const *const II2cConnectionSettings_Vtbl)).SetSharingMode)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SlaveAddress,
+            SetSlaveAddress,
+            BusSpeed,
+            SetBusSpeed,
+            SharingMode,
+            SetSharingMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<II2cConnectionSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for II2cConnectionSettingsFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, slaveaddress: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cConnectionSettingsFactory", "Create", 6usize, |this| ((**(this as *const *const II2cConnectionSettingsFactory_Vtbl)).Create)(this, slaveaddress, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<II2cConnectionSettingsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for II2cController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cController", "GetDevice", 6usize, |this| ((**(this as *const *const II2cController_Vtbl)).GetDevice)(this, settings, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<II2cController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_I2c_Provider", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for II2cControllerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Devices_I2c_Provider", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetControllersAsync(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cControllerStatics", "GetControllersAsync", 6usize, |this| ((**(this as *const *const II2cControllerStatics_Vtbl)).GetControllersAsync)(this, provider, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cControllerStatics", "GetDefaultAsync", 7usize, |this| ((**(this as *const *const II2cControllerStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Devices_I2c_Provider", feature = "Foundation_Collections"))]
+            GetControllersAsync,
+            #[cfg(not(all(feature = "Devices_I2c_Provider", feature = "Foundation_Collections")))]
+            GetControllersAsync: 0,
+            GetDefaultAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<II2cControllerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for II2cDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDevice", "DeviceId", 6usize, |this| ((**(this as *const *const II2cDevice_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDevice", "ConnectionSettings", 7usize, |this| ((**(this as *const *const II2cDevice_Vtbl)).ConnectionSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, buffer_array_size: u32, buffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDevice", "Write", 8usize, |this| ((**(this as *const *const II2cDevice_Vtbl)).Write)(this, buffer_array_size, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn WritePartial(this: *mut core::ffi::c_void, buffer_array_size: u32, buffer: *const u8, result__: *mut I2cTransferResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDevice", "WritePartial", 9usize, |this| ((**(this as *const *const II2cDevice_Vtbl)).WritePartial)(this, buffer_array_size, buffer, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, buffer_array_size: u32, buffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDevice", "Read", 10usize, |this| ((**(this as *const *const II2cDevice_Vtbl)).Read)(this, buffer_array_size, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadPartial(this: *mut core::ffi::c_void, buffer_array_size: u32, buffer: *mut u8, result__: *mut I2cTransferResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDevice", "ReadPartial", 11usize, |this| ((**(this as *const *const II2cDevice_Vtbl)).ReadPartial)(this, buffer_array_size, buffer, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteRead(this: *mut core::ffi::c_void, writeBuffer_array_size: u32, writebuffer: *const u8, readBuffer_array_size: u32, readbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDevice", "WriteRead", 12usize, |this| ((**(this as *const *const II2cDevice_Vtbl)).WriteRead)(this, writeBuffer_array_size, writebuffer, readBuffer_array_size, readbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteReadPartial(this: *mut core::ffi::c_void, writeBuffer_array_size: u32, writebuffer: *const u8, readBuffer_array_size: u32, readbuffer: *mut u8, result__: *mut I2cTransferResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDevice", "WriteReadPartial", 13usize, |this| ((**(this as *const *const II2cDevice_Vtbl)).WriteReadPartial)(this, writeBuffer_array_size, writebuffer, readBuffer_array_size, readbuffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            ConnectionSettings,
+            Write,
+            WritePartial,
+            Read,
+            ReadPartial,
+            WriteRead,
+            WriteReadPartial,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<II2cDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for II2cDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDeviceStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const II2cDeviceStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromFriendlyName(this: *mut core::ffi::c_void, friendlyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDeviceStatics", "GetDeviceSelectorFromFriendlyName", 7usize, |this| ((**(this as *const *const II2cDeviceStatics_Vtbl)).GetDeviceSelectorFromFriendlyName)(this, friendlyname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, settings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "II2cDeviceStatics", "FromIdAsync", 8usize, |this| ((**(this as *const *const II2cDeviceStatics_Vtbl)).FromIdAsync)(this, deviceid, settings, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceSelector,
+            GetDeviceSelectorFromFriendlyName,
+            FromIdAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<II2cDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
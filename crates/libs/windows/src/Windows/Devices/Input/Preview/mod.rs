@@ -627,6 +627,7 @@ impl GazeDeviceConfigurationStatePreview {
 impl windows_core::TypeKind for GazeDeviceConfigurationStatePreview {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GazeDeviceConfigurationStatePreview {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GazeDeviceConfigurationStatePreview").field(&self.0).finish()
@@ -635,3 +636,5 @@ impl core::fmt::Debug for GazeDeviceConfigurationStatePreview {
 impl windows_core::RuntimeType for GazeDeviceConfigurationStatePreview {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Input.Preview.GazeDeviceConfigurationStatePreview;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
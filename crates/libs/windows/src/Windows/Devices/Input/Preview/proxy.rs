@@ -0,0 +1,289 @@
+#[cfg(all(feature = "Devices_HumanInterfaceDevice", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IGazeDevicePreview_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDevicePreview", "Id", 6usize, |this| ((**(this as *const *const IGazeDevicePreview_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanTrackEyes(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDevicePreview", "CanTrackEyes", 7usize, |this| ((**(this as *const *const IGazeDevicePreview_Vtbl)).CanTrackEyes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanTrackHead(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDevicePreview", "CanTrackHead", 8usize, |this| ((**(this as *const *const IGazeDevicePreview_Vtbl)).CanTrackHead)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConfigurationState(this: *mut core::ffi::c_void, result__: *mut GazeDeviceConfigurationStatePreview) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDevicePreview", "ConfigurationState", 9usize, |this| ((**(this as *const *const IGazeDevicePreview_Vtbl)).ConfigurationState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestCalibrationAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDevicePreview", "RequestCalibrationAsync", 10usize, |this| ((**(this as *const *const IGazeDevicePreview_Vtbl)).RequestCalibrationAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_HumanInterfaceDevice", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetNumericControlDescriptions(this: *mut core::ffi::c_void, usagepage: u16, usageid: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDevicePreview", "GetNumericControlDescriptions", 11usize, |this| ((**(this as *const *const IGazeDevicePreview_Vtbl)).GetNumericControlDescriptions)(this, usagepage, usageid, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_HumanInterfaceDevice", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetBooleanControlDescriptions(this: *mut core::ffi::c_void, usagepage: u16, usageid: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDevicePreview", "GetBooleanControlDescriptions", 12usize, |this| ((**(this as *const *const IGazeDevicePreview_Vtbl)).GetBooleanControlDescriptions)(this, usagepage, usageid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            CanTrackEyes,
+            CanTrackHead,
+            ConfigurationState,
+            RequestCalibrationAsync,
+            #[cfg(all(feature = "Devices_HumanInterfaceDevice", feature = "Foundation_Collections"))]
+            GetNumericControlDescriptions,
+            #[cfg(not(all(feature = "Devices_HumanInterfaceDevice", feature = "Foundation_Collections")))]
+            GetNumericControlDescriptions: 0,
+            #[cfg(all(feature = "Devices_HumanInterfaceDevice", feature = "Foundation_Collections"))]
+            GetBooleanControlDescriptions,
+            #[cfg(not(all(feature = "Devices_HumanInterfaceDevice", feature = "Foundation_Collections")))]
+            GetBooleanControlDescriptions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGazeDevicePreview as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGazeDeviceWatcherAddedPreviewEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Device(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDeviceWatcherAddedPreviewEventArgs", "Device", 6usize, |this| ((**(this as *const *const IGazeDeviceWatcherAddedPreviewEventArgs_Vtbl)).Device)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Device }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGazeDeviceWatcherAddedPreviewEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGazeDeviceWatcherPreview_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Added(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDeviceWatcherPreview", "Added", 6usize, |this| ((**(this as *const *const IGazeDeviceWatcherPreview_Vtbl)).Added)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdded(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDeviceWatcherPreview", "RemoveAdded", 7usize, |this| ((**(this as *const *const IGazeDeviceWatcherPreview_Vtbl)).RemoveAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Removed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDeviceWatcherPreview", "Removed", 8usize, |this| ((**(this as *const *const IGazeDeviceWatcherPreview_Vtbl)).Removed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoved(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDeviceWatcherPreview", "RemoveRemoved", 9usize, |this| ((**(this as *const *const IGazeDeviceWatcherPreview_Vtbl)).RemoveRemoved)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Updated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDeviceWatcherPreview", "Updated", 10usize, |this| ((**(this as *const *const IGazeDeviceWatcherPreview_Vtbl)).Updated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUpdated(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDeviceWatcherPreview", "RemoveUpdated", 11usize, |this| ((**(this as *const *const IGazeDeviceWatcherPreview_Vtbl)).RemoveUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDeviceWatcherPreview", "EnumerationCompleted", 12usize, |this| ((**(this as *const *const IGazeDeviceWatcherPreview_Vtbl)).EnumerationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnumerationCompleted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDeviceWatcherPreview", "RemoveEnumerationCompleted", 13usize, |this| ((**(this as *const *const IGazeDeviceWatcherPreview_Vtbl)).RemoveEnumerationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDeviceWatcherPreview", "Start", 14usize, |this| ((**(this as *const *const IGazeDeviceWatcherPreview_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDeviceWatcherPreview", "Stop", 15usize, |this| ((**(this as *const *const IGazeDeviceWatcherPreview_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Added,
+            RemoveAdded,
+            Removed,
+            RemoveRemoved,
+            Updated,
+            RemoveUpdated,
+            EnumerationCompleted,
+            RemoveEnumerationCompleted,
+            Start,
+            Stop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGazeDeviceWatcherPreview as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGazeDeviceWatcherRemovedPreviewEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Device(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDeviceWatcherRemovedPreviewEventArgs", "Device", 6usize, |this| ((**(this as *const *const IGazeDeviceWatcherRemovedPreviewEventArgs_Vtbl)).Device)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Device }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGazeDeviceWatcherRemovedPreviewEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGazeDeviceWatcherUpdatedPreviewEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Device(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeDeviceWatcherUpdatedPreviewEventArgs", "Device", 6usize, |this| ((**(this as *const *const IGazeDeviceWatcherUpdatedPreviewEventArgs_Vtbl)).Device)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Device }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGazeDeviceWatcherUpdatedPreviewEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGazeEnteredPreviewEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeEnteredPreviewEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IGazeEnteredPreviewEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeEnteredPreviewEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IGazeEnteredPreviewEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPoint(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeEnteredPreviewEventArgs", "CurrentPoint", 8usize, |this| ((**(this as *const *const IGazeEnteredPreviewEventArgs_Vtbl)).CurrentPoint)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, CurrentPoint }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGazeEnteredPreviewEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGazeExitedPreviewEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeExitedPreviewEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IGazeExitedPreviewEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeExitedPreviewEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IGazeExitedPreviewEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPoint(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeExitedPreviewEventArgs", "CurrentPoint", 8usize, |this| ((**(this as *const *const IGazeExitedPreviewEventArgs_Vtbl)).CurrentPoint)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, CurrentPoint }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGazeExitedPreviewEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGazeInputSourcePreview_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GazeMoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeInputSourcePreview", "GazeMoved", 6usize, |this| ((**(this as *const *const IGazeInputSourcePreview_Vtbl)).GazeMoved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGazeMoved(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeInputSourcePreview", "RemoveGazeMoved", 7usize, |this| ((**(this as *const *const IGazeInputSourcePreview_Vtbl)).RemoveGazeMoved)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn GazeEntered(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeInputSourcePreview", "GazeEntered", 8usize, |this| ((**(this as *const *const IGazeInputSourcePreview_Vtbl)).GazeEntered)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGazeEntered(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeInputSourcePreview", "RemoveGazeEntered", 9usize, |this| ((**(this as *const *const IGazeInputSourcePreview_Vtbl)).RemoveGazeEntered)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn GazeExited(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeInputSourcePreview", "GazeExited", 10usize, |this| ((**(this as *const *const IGazeInputSourcePreview_Vtbl)).GazeExited)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGazeExited(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeInputSourcePreview", "RemoveGazeExited", 11usize, |this| ((**(this as *const *const IGazeInputSourcePreview_Vtbl)).RemoveGazeExited)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GazeMoved,
+            RemoveGazeMoved,
+            GazeEntered,
+            RemoveGazeEntered,
+            GazeExited,
+            RemoveGazeExited,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGazeInputSourcePreview as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGazeInputSourcePreviewStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeInputSourcePreviewStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IGazeInputSourcePreviewStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeInputSourcePreviewStatics", "CreateWatcher", 7usize, |this| ((**(this as *const *const IGazeInputSourcePreviewStatics_Vtbl)).CreateWatcher)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView, CreateWatcher }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGazeInputSourcePreviewStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGazeMovedPreviewEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeMovedPreviewEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IGazeMovedPreviewEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeMovedPreviewEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IGazeMovedPreviewEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPoint(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeMovedPreviewEventArgs", "CurrentPoint", 8usize, |this| ((**(this as *const *const IGazeMovedPreviewEventArgs_Vtbl)).CurrentPoint)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetIntermediatePoints(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazeMovedPreviewEventArgs", "GetIntermediatePoints", 9usize, |this| ((**(this as *const *const IGazeMovedPreviewEventArgs_Vtbl)).GetIntermediatePoints)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Handled,
+            SetHandled,
+            CurrentPoint,
+            #[cfg(feature = "Foundation_Collections")]
+            GetIntermediatePoints,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetIntermediatePoints: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGazeMovedPreviewEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_HumanInterfaceDevice")]
+impl windows_core::imp::ProxyVtable for IGazePointPreview_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SourceDevice(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazePointPreview", "SourceDevice", 6usize, |this| ((**(this as *const *const IGazePointPreview_Vtbl)).SourceDevice)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EyeGazePosition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazePointPreview", "EyeGazePosition", 7usize, |this| ((**(this as *const *const IGazePointPreview_Vtbl)).EyeGazePosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeadGazePosition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazePointPreview", "HeadGazePosition", 8usize, |this| ((**(this as *const *const IGazePointPreview_Vtbl)).HeadGazePosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazePointPreview", "Timestamp", 9usize, |this| ((**(this as *const *const IGazePointPreview_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_HumanInterfaceDevice")]
+        unsafe extern "system" fn HidInputReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGazePointPreview", "HidInputReport", 10usize, |this| ((**(this as *const *const IGazePointPreview_Vtbl)).HidInputReport)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SourceDevice,
+            EyeGazePosition,
+            HeadGazePosition,
+            Timestamp,
+            #[cfg(feature = "Devices_HumanInterfaceDevice")]
+            HidInputReport,
+            #[cfg(not(feature = "Devices_HumanInterfaceDevice"))]
+            HidInputReport: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGazePointPreview as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -808,6 +808,7 @@ impl PointerDeviceType {
 impl windows_core::TypeKind for PointerDeviceType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PointerDeviceType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PointerDeviceType").field(&self.0).finish()
@@ -817,7 +818,8 @@ impl windows_core::RuntimeType for PointerDeviceType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Input.PointerDeviceType;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MouseDelta {
     pub X: i32,
     pub Y: i32,
@@ -834,7 +836,8 @@ impl Default for MouseDelta {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PointerDeviceUsage {
     pub UsagePage: u32,
     pub Usage: u32,
@@ -856,3 +859,5 @@ impl Default for PointerDeviceUsage {
         unsafe { core::mem::zeroed() }
     }
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
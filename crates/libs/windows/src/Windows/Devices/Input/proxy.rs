@@ -0,0 +1,362 @@
+impl windows_core::imp::ProxyVtable for IKeyboardCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KeyboardPresent(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyboardCapabilities", "KeyboardPresent", 6usize, |this| ((**(this as *const *const IKeyboardCapabilities_Vtbl)).KeyboardPresent)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KeyboardPresent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyboardCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMouseCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MousePresent(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseCapabilities", "MousePresent", 6usize, |this| ((**(this as *const *const IMouseCapabilities_Vtbl)).MousePresent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerticalWheelPresent(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseCapabilities", "VerticalWheelPresent", 7usize, |this| ((**(this as *const *const IMouseCapabilities_Vtbl)).VerticalWheelPresent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HorizontalWheelPresent(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseCapabilities", "HorizontalWheelPresent", 8usize, |this| ((**(this as *const *const IMouseCapabilities_Vtbl)).HorizontalWheelPresent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwapButtons(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseCapabilities", "SwapButtons", 9usize, |this| ((**(this as *const *const IMouseCapabilities_Vtbl)).SwapButtons)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfButtons(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseCapabilities", "NumberOfButtons", 10usize, |this| ((**(this as *const *const IMouseCapabilities_Vtbl)).NumberOfButtons)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MousePresent,
+            VerticalWheelPresent,
+            HorizontalWheelPresent,
+            SwapButtons,
+            NumberOfButtons,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMouseCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMouseDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MouseMoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseDevice", "MouseMoved", 6usize, |this| ((**(this as *const *const IMouseDevice_Vtbl)).MouseMoved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMouseMoved(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseDevice", "RemoveMouseMoved", 7usize, |this| ((**(this as *const *const IMouseDevice_Vtbl)).RemoveMouseMoved)(this, cookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MouseMoved, RemoveMouseMoved }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMouseDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMouseDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseDeviceStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IMouseDeviceStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMouseDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMouseEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MouseDelta(this: *mut core::ffi::c_void, result__: *mut MouseDelta) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseEventArgs", "MouseDelta", 6usize, |this| ((**(this as *const *const IMouseEventArgs_Vtbl)).MouseDelta)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MouseDelta }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMouseEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPenButtonListener_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenButtonListener", "IsSupported", 6usize, |this| ((**(this as *const *const IPenButtonListener_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupportedChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenButtonListener", "IsSupportedChanged", 7usize, |this| ((**(this as *const *const IPenButtonListener_Vtbl)).IsSupportedChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveIsSupportedChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenButtonListener", "RemoveIsSupportedChanged", 8usize, |this| ((**(this as *const *const IPenButtonListener_Vtbl)).RemoveIsSupportedChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TailButtonClicked(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenButtonListener", "TailButtonClicked", 9usize, |this| ((**(this as *const *const IPenButtonListener_Vtbl)).TailButtonClicked)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTailButtonClicked(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenButtonListener", "RemoveTailButtonClicked", 10usize, |this| ((**(this as *const *const IPenButtonListener_Vtbl)).RemoveTailButtonClicked)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TailButtonDoubleClicked(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenButtonListener", "TailButtonDoubleClicked", 11usize, |this| ((**(this as *const *const IPenButtonListener_Vtbl)).TailButtonDoubleClicked)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTailButtonDoubleClicked(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenButtonListener", "RemoveTailButtonDoubleClicked", 12usize, |this| ((**(this as *const *const IPenButtonListener_Vtbl)).RemoveTailButtonDoubleClicked)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TailButtonLongPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenButtonListener", "TailButtonLongPressed", 13usize, |this| ((**(this as *const *const IPenButtonListener_Vtbl)).TailButtonLongPressed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTailButtonLongPressed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenButtonListener", "RemoveTailButtonLongPressed", 14usize, |this| ((**(this as *const *const IPenButtonListener_Vtbl)).RemoveTailButtonLongPressed)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupported,
+            IsSupportedChanged,
+            RemoveIsSupportedChanged,
+            TailButtonClicked,
+            RemoveTailButtonClicked,
+            TailButtonDoubleClicked,
+            RemoveTailButtonDoubleClicked,
+            TailButtonLongPressed,
+            RemoveTailButtonLongPressed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenButtonListener as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPenButtonListenerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenButtonListenerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IPenButtonListenerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenButtonListenerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPenDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PenId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenDevice", "PenId", 6usize, |this| ((**(this as *const *const IPenDevice_Vtbl)).PenId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PenId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Haptics")]
+impl windows_core::imp::ProxyVtable for IPenDevice2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Haptics")]
+        unsafe extern "system" fn SimpleHapticsController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenDevice2", "SimpleHapticsController", 6usize, |this| ((**(this as *const *const IPenDevice2_Vtbl)).SimpleHapticsController)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Haptics")]
+            SimpleHapticsController,
+            #[cfg(not(feature = "Devices_Haptics"))]
+            SimpleHapticsController: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenDevice2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPenDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFromPointerId(this: *mut core::ffi::c_void, pointerid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenDeviceStatics", "GetFromPointerId", 6usize, |this| ((**(this as *const *const IPenDeviceStatics_Vtbl)).GetFromPointerId)(this, pointerid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFromPointerId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPenDockListener_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenDockListener", "IsSupported", 6usize, |this| ((**(this as *const *const IPenDockListener_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupportedChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenDockListener", "IsSupportedChanged", 7usize, |this| ((**(this as *const *const IPenDockListener_Vtbl)).IsSupportedChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveIsSupportedChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenDockListener", "RemoveIsSupportedChanged", 8usize, |this| ((**(this as *const *const IPenDockListener_Vtbl)).RemoveIsSupportedChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Docked(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenDockListener", "Docked", 9usize, |this| ((**(this as *const *const IPenDockListener_Vtbl)).Docked)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDocked(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenDockListener", "RemoveDocked", 10usize, |this| ((**(this as *const *const IPenDockListener_Vtbl)).RemoveDocked)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Undocked(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenDockListener", "Undocked", 11usize, |this| ((**(this as *const *const IPenDockListener_Vtbl)).Undocked)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUndocked(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenDockListener", "RemoveUndocked", 12usize, |this| ((**(this as *const *const IPenDockListener_Vtbl)).RemoveUndocked)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupported,
+            IsSupportedChanged,
+            RemoveIsSupportedChanged,
+            Docked,
+            RemoveDocked,
+            Undocked,
+            RemoveUndocked,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenDockListener as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPenDockListenerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenDockListenerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IPenDockListenerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenDockListenerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPenDockedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenDockedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPenTailButtonClickedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenTailButtonClickedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPenTailButtonDoubleClickedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenTailButtonDoubleClickedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPenTailButtonLongPressedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenTailButtonLongPressedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPenUndockedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenUndockedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPointerDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PointerDeviceType(this: *mut core::ffi::c_void, result__: *mut PointerDeviceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerDevice", "PointerDeviceType", 6usize, |this| ((**(this as *const *const IPointerDevice_Vtbl)).PointerDeviceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIntegrated(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerDevice", "IsIntegrated", 7usize, |this| ((**(this as *const *const IPointerDevice_Vtbl)).IsIntegrated)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxContacts(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerDevice", "MaxContacts", 8usize, |this| ((**(this as *const *const IPointerDevice_Vtbl)).MaxContacts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhysicalDeviceRect(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerDevice", "PhysicalDeviceRect", 9usize, |this| ((**(this as *const *const IPointerDevice_Vtbl)).PhysicalDeviceRect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScreenRect(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerDevice", "ScreenRect", 10usize, |this| ((**(this as *const *const IPointerDevice_Vtbl)).ScreenRect)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedUsages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerDevice", "SupportedUsages", 11usize, |this| ((**(this as *const *const IPointerDevice_Vtbl)).SupportedUsages)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PointerDeviceType,
+            IsIntegrated,
+            MaxContacts,
+            PhysicalDeviceRect,
+            ScreenRect,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedUsages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedUsages: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPointerDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPointerDevice2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxPointersWithZDistance(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerDevice2", "MaxPointersWithZDistance", 6usize, |this| ((**(this as *const *const IPointerDevice2_Vtbl)).MaxPointersWithZDistance)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MaxPointersWithZDistance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPointerDevice2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPointerDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPointerDevice(this: *mut core::ffi::c_void, pointerid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerDeviceStatics", "GetPointerDevice", 6usize, |this| ((**(this as *const *const IPointerDeviceStatics_Vtbl)).GetPointerDevice)(this, pointerid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetPointerDevices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerDeviceStatics", "GetPointerDevices", 7usize, |this| ((**(this as *const *const IPointerDeviceStatics_Vtbl)).GetPointerDevices)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPointerDevice,
+            #[cfg(feature = "Foundation_Collections")]
+            GetPointerDevices,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetPointerDevices: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPointerDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITouchCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TouchPresent(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITouchCapabilities", "TouchPresent", 6usize, |this| ((**(this as *const *const ITouchCapabilities_Vtbl)).TouchPresent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Contacts(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITouchCapabilities", "Contacts", 7usize, |this| ((**(this as *const *const ITouchCapabilities_Vtbl)).Contacts)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TouchPresent, Contacts }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITouchCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
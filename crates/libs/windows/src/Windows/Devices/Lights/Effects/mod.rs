@@ -1003,6 +1003,7 @@ impl LampArrayEffectCompletionBehavior {
 impl windows_core::TypeKind for LampArrayEffectCompletionBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LampArrayEffectCompletionBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LampArrayEffectCompletionBehavior").field(&self.0).finish()
@@ -1021,6 +1022,7 @@ impl LampArrayEffectStartMode {
 impl windows_core::TypeKind for LampArrayEffectStartMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LampArrayEffectStartMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LampArrayEffectStartMode").field(&self.0).finish()
@@ -1039,6 +1041,7 @@ impl LampArrayRepetitionMode {
 impl windows_core::TypeKind for LampArrayRepetitionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LampArrayRepetitionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LampArrayRepetitionMode").field(&self.0).finish()
@@ -1049,3 +1052,5 @@ impl windows_core::RuntimeType for LampArrayRepetitionMode {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
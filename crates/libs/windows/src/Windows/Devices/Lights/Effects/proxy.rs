@@ -0,0 +1,508 @@
+impl windows_core::imp::ProxyVtable for ILampArrayBitmapEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBitmapEffect", "Duration", 6usize, |this| ((**(this as *const *const ILampArrayBitmapEffect_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBitmapEffect", "SetDuration", 7usize, |this| ((**(this as *const *const ILampArrayBitmapEffect_Vtbl)).SetDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartDelay(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBitmapEffect", "StartDelay", 8usize, |this| ((**(this as *const *const ILampArrayBitmapEffect_Vtbl)).StartDelay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartDelay(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBitmapEffect", "SetStartDelay", 9usize, |this| ((**(this as *const *const ILampArrayBitmapEffect_Vtbl)).SetStartDelay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateInterval(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBitmapEffect", "UpdateInterval", 10usize, |this| ((**(this as *const *const ILampArrayBitmapEffect_Vtbl)).UpdateInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUpdateInterval(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBitmapEffect", "SetUpdateInterval", 11usize, |this| ((**(this as *const *const ILampArrayBitmapEffect_Vtbl)).SetUpdateInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuggestedBitmapSize(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBitmapEffect", "SuggestedBitmapSize", 12usize, |this| ((**(this as *const *const ILampArrayBitmapEffect_Vtbl)).SuggestedBitmapSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitmapRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBitmapEffect", "BitmapRequested", 13usize, |this| ((**(this as *const *const ILampArrayBitmapEffect_Vtbl)).BitmapRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBitmapRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBitmapEffect", "RemoveBitmapRequested", 14usize, |this| ((**(this as *const *const ILampArrayBitmapEffect_Vtbl)).RemoveBitmapRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Duration,
+            SetDuration,
+            StartDelay,
+            SetStartDelay,
+            UpdateInterval,
+            SetUpdateInterval,
+            SuggestedBitmapSize,
+            BitmapRequested,
+            RemoveBitmapRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArrayBitmapEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILampArrayBitmapEffectFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, lamparray: *mut core::ffi::c_void, lampIndexes_array_size: u32, lampindexes: *const i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBitmapEffectFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const ILampArrayBitmapEffectFactory_Vtbl)).CreateInstance)(this, lamparray, lampIndexes_array_size, lampindexes, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArrayBitmapEffectFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for ILampArrayBitmapRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SinceStarted(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBitmapRequestedEventArgs", "SinceStarted", 6usize, |this| ((**(this as *const *const ILampArrayBitmapRequestedEventArgs_Vtbl)).SinceStarted)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn UpdateBitmap(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBitmapRequestedEventArgs", "UpdateBitmap", 7usize, |this| ((**(this as *const *const ILampArrayBitmapRequestedEventArgs_Vtbl)).UpdateBitmap)(this, bitmap), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SinceStarted,
+            #[cfg(feature = "Graphics_Imaging")]
+            UpdateBitmap,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            UpdateBitmap: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArrayBitmapRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for ILampArrayBlinkEffect_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, result__: *mut super::super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "Color", 6usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).Color)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, value: super::super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "SetColor", 7usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).SetColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttackDuration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "AttackDuration", 8usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).AttackDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttackDuration(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "SetAttackDuration", 9usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).SetAttackDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SustainDuration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "SustainDuration", 10usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).SustainDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSustainDuration(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "SetSustainDuration", 11usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).SetSustainDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecayDuration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "DecayDuration", 12usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).DecayDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDecayDuration(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "SetDecayDuration", 13usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).SetDecayDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RepetitionDelay(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "RepetitionDelay", 14usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).RepetitionDelay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRepetitionDelay(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "SetRepetitionDelay", 15usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).SetRepetitionDelay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartDelay(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "StartDelay", 16usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).StartDelay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartDelay(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "SetStartDelay", 17usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).SetStartDelay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Occurrences(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "Occurrences", 18usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).Occurrences)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOccurrences(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "SetOccurrences", 19usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).SetOccurrences)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RepetitionMode(this: *mut core::ffi::c_void, result__: *mut LampArrayRepetitionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "RepetitionMode", 20usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).RepetitionMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRepetitionMode(this: *mut core::ffi::c_void, value: LampArrayRepetitionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffect", "SetRepetitionMode", 21usize, |this| ((**(this as *const *const ILampArrayBlinkEffect_Vtbl)).SetRepetitionMode)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI")]
+            Color,
+            #[cfg(not(feature = "UI"))]
+            Color: 0,
+            #[cfg(feature = "UI")]
+            SetColor,
+            #[cfg(not(feature = "UI"))]
+            SetColor: 0,
+            AttackDuration,
+            SetAttackDuration,
+            SustainDuration,
+            SetSustainDuration,
+            DecayDuration,
+            SetDecayDuration,
+            RepetitionDelay,
+            SetRepetitionDelay,
+            StartDelay,
+            SetStartDelay,
+            Occurrences,
+            SetOccurrences,
+            RepetitionMode,
+            SetRepetitionMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArrayBlinkEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILampArrayBlinkEffectFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, lamparray: *mut core::ffi::c_void, lampIndexes_array_size: u32, lampindexes: *const i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayBlinkEffectFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const ILampArrayBlinkEffectFactory_Vtbl)).CreateInstance)(this, lamparray, lampIndexes_array_size, lampindexes, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArrayBlinkEffectFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for ILampArrayColorRampEffect_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, result__: *mut super::super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayColorRampEffect", "Color", 6usize, |this| ((**(this as *const *const ILampArrayColorRampEffect_Vtbl)).Color)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, value: super::super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayColorRampEffect", "SetColor", 7usize, |this| ((**(this as *const *const ILampArrayColorRampEffect_Vtbl)).SetColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RampDuration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayColorRampEffect", "RampDuration", 8usize, |this| ((**(this as *const *const ILampArrayColorRampEffect_Vtbl)).RampDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRampDuration(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayColorRampEffect", "SetRampDuration", 9usize, |this| ((**(this as *const *const ILampArrayColorRampEffect_Vtbl)).SetRampDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartDelay(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayColorRampEffect", "StartDelay", 10usize, |this| ((**(this as *const *const ILampArrayColorRampEffect_Vtbl)).StartDelay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartDelay(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayColorRampEffect", "SetStartDelay", 11usize, |this| ((**(this as *const *const ILampArrayColorRampEffect_Vtbl)).SetStartDelay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompletionBehavior(this: *mut core::ffi::c_void, result__: *mut LampArrayEffectCompletionBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayColorRampEffect", "CompletionBehavior", 12usize, |this| ((**(this as *const *const ILampArrayColorRampEffect_Vtbl)).CompletionBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompletionBehavior(this: *mut core::ffi::c_void, value: LampArrayEffectCompletionBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayColorRampEffect", "SetCompletionBehavior", 13usize, |this| ((**(this as *const *const ILampArrayColorRampEffect_Vtbl)).SetCompletionBehavior)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI")]
+            Color,
+            #[cfg(not(feature = "UI"))]
+            Color: 0,
+            #[cfg(feature = "UI")]
+            SetColor,
+            #[cfg(not(feature = "UI"))]
+            SetColor: 0,
+            RampDuration,
+            SetRampDuration,
+            StartDelay,
+            SetStartDelay,
+            CompletionBehavior,
+            SetCompletionBehavior,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArrayColorRampEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILampArrayColorRampEffectFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, lamparray: *mut core::ffi::c_void, lampIndexes_array_size: u32, lampindexes: *const i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayColorRampEffectFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const ILampArrayColorRampEffectFactory_Vtbl)).CreateInstance)(this, lamparray, lampIndexes_array_size, lampindexes, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArrayColorRampEffectFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILampArrayCustomEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayCustomEffect", "Duration", 6usize, |this| ((**(this as *const *const ILampArrayCustomEffect_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayCustomEffect", "SetDuration", 7usize, |this| ((**(this as *const *const ILampArrayCustomEffect_Vtbl)).SetDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateInterval(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayCustomEffect", "UpdateInterval", 8usize, |this| ((**(this as *const *const ILampArrayCustomEffect_Vtbl)).UpdateInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUpdateInterval(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayCustomEffect", "SetUpdateInterval", 9usize, |this| ((**(this as *const *const ILampArrayCustomEffect_Vtbl)).SetUpdateInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayCustomEffect", "UpdateRequested", 10usize, |this| ((**(this as *const *const ILampArrayCustomEffect_Vtbl)).UpdateRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUpdateRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayCustomEffect", "RemoveUpdateRequested", 11usize, |this| ((**(this as *const *const ILampArrayCustomEffect_Vtbl)).RemoveUpdateRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Duration,
+            SetDuration,
+            UpdateInterval,
+            SetUpdateInterval,
+            UpdateRequested,
+            RemoveUpdateRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArrayCustomEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILampArrayCustomEffectFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, lamparray: *mut core::ffi::c_void, lampIndexes_array_size: u32, lampindexes: *const i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayCustomEffectFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const ILampArrayCustomEffectFactory_Vtbl)).CreateInstance)(this, lamparray, lampIndexes_array_size, lampindexes, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArrayCustomEffectFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILampArrayEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ZIndex(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffect", "ZIndex", 6usize, |this| ((**(this as *const *const ILampArrayEffect_Vtbl)).ZIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetZIndex(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffect", "SetZIndex", 7usize, |this| ((**(this as *const *const ILampArrayEffect_Vtbl)).SetZIndex)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ZIndex, SetZIndex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArrayEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILampArrayEffectPlaylist_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, effect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffectPlaylist", "Append", 6usize, |this| ((**(this as *const *const ILampArrayEffectPlaylist_Vtbl)).Append)(this, effect), |result| Some(*result))
+        }
+        unsafe extern "system" fn OverrideZIndex(this: *mut core::ffi::c_void, zindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffectPlaylist", "OverrideZIndex", 7usize, |this| ((**(this as *const *const ILampArrayEffectPlaylist_Vtbl)).OverrideZIndex)(this, zindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffectPlaylist", "Start", 8usize, |this| ((**(this as *const *const ILampArrayEffectPlaylist_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffectPlaylist", "Stop", 9usize, |this| ((**(this as *const *const ILampArrayEffectPlaylist_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffectPlaylist", "Pause", 10usize, |this| ((**(this as *const *const ILampArrayEffectPlaylist_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EffectStartMode(this: *mut core::ffi::c_void, result__: *mut LampArrayEffectStartMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffectPlaylist", "EffectStartMode", 11usize, |this| ((**(this as *const *const ILampArrayEffectPlaylist_Vtbl)).EffectStartMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEffectStartMode(this: *mut core::ffi::c_void, value: LampArrayEffectStartMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffectPlaylist", "SetEffectStartMode", 12usize, |this| ((**(this as *const *const ILampArrayEffectPlaylist_Vtbl)).SetEffectStartMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Occurrences(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffectPlaylist", "Occurrences", 13usize, |this| ((**(this as *const *const ILampArrayEffectPlaylist_Vtbl)).Occurrences)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOccurrences(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffectPlaylist", "SetOccurrences", 14usize, |this| ((**(this as *const *const ILampArrayEffectPlaylist_Vtbl)).SetOccurrences)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RepetitionMode(this: *mut core::ffi::c_void, result__: *mut LampArrayRepetitionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffectPlaylist", "RepetitionMode", 15usize, |this| ((**(this as *const *const ILampArrayEffectPlaylist_Vtbl)).RepetitionMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRepetitionMode(this: *mut core::ffi::c_void, value: LampArrayRepetitionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffectPlaylist", "SetRepetitionMode", 16usize, |this| ((**(this as *const *const ILampArrayEffectPlaylist_Vtbl)).SetRepetitionMode)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Append,
+            OverrideZIndex,
+            Start,
+            Stop,
+            Pause,
+            EffectStartMode,
+            SetEffectStartMode,
+            Occurrences,
+            SetOccurrences,
+            RepetitionMode,
+            SetRepetitionMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArrayEffectPlaylist as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ILampArrayEffectPlaylistStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StartAll(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffectPlaylistStatics", "StartAll", 6usize, |this| ((**(this as *const *const ILampArrayEffectPlaylistStatics_Vtbl)).StartAll)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StopAll(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffectPlaylistStatics", "StopAll", 7usize, |this| ((**(this as *const *const ILampArrayEffectPlaylistStatics_Vtbl)).StopAll)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PauseAll(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayEffectPlaylistStatics", "PauseAll", 8usize, |this| ((**(this as *const *const ILampArrayEffectPlaylistStatics_Vtbl)).PauseAll)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            StartAll,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StartAll: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            StopAll,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StopAll: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            PauseAll,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PauseAll: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArrayEffectPlaylistStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for ILampArraySolidEffect_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, result__: *mut super::super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArraySolidEffect", "Color", 6usize, |this| ((**(this as *const *const ILampArraySolidEffect_Vtbl)).Color)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, value: super::super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArraySolidEffect", "SetColor", 7usize, |this| ((**(this as *const *const ILampArraySolidEffect_Vtbl)).SetColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArraySolidEffect", "Duration", 8usize, |this| ((**(this as *const *const ILampArraySolidEffect_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArraySolidEffect", "SetDuration", 9usize, |this| ((**(this as *const *const ILampArraySolidEffect_Vtbl)).SetDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartDelay(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArraySolidEffect", "StartDelay", 10usize, |this| ((**(this as *const *const ILampArraySolidEffect_Vtbl)).StartDelay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartDelay(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArraySolidEffect", "SetStartDelay", 11usize, |this| ((**(this as *const *const ILampArraySolidEffect_Vtbl)).SetStartDelay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompletionBehavior(this: *mut core::ffi::c_void, result__: *mut LampArrayEffectCompletionBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArraySolidEffect", "CompletionBehavior", 12usize, |this| ((**(this as *const *const ILampArraySolidEffect_Vtbl)).CompletionBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompletionBehavior(this: *mut core::ffi::c_void, value: LampArrayEffectCompletionBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArraySolidEffect", "SetCompletionBehavior", 13usize, |this| ((**(this as *const *const ILampArraySolidEffect_Vtbl)).SetCompletionBehavior)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI")]
+            Color,
+            #[cfg(not(feature = "UI"))]
+            Color: 0,
+            #[cfg(feature = "UI")]
+            SetColor,
+            #[cfg(not(feature = "UI"))]
+            SetColor: 0,
+            Duration,
+            SetDuration,
+            StartDelay,
+            SetStartDelay,
+            CompletionBehavior,
+            SetCompletionBehavior,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArraySolidEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILampArraySolidEffectFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, lamparray: *mut core::ffi::c_void, lampIndexes_array_size: u32, lampindexes: *const i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArraySolidEffectFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const ILampArraySolidEffectFactory_Vtbl)).CreateInstance)(this, lamparray, lampIndexes_array_size, lampindexes, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArraySolidEffectFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for ILampArrayUpdateRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SinceStarted(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayUpdateRequestedEventArgs", "SinceStarted", 6usize, |this| ((**(this as *const *const ILampArrayUpdateRequestedEventArgs_Vtbl)).SinceStarted)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, desiredcolor: super::super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayUpdateRequestedEventArgs", "SetColor", 7usize, |this| ((**(this as *const *const ILampArrayUpdateRequestedEventArgs_Vtbl)).SetColor)(this, desiredcolor), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetColorForIndex(this: *mut core::ffi::c_void, lampindex: i32, desiredcolor: super::super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayUpdateRequestedEventArgs", "SetColorForIndex", 8usize, |this| ((**(this as *const *const ILampArrayUpdateRequestedEventArgs_Vtbl)).SetColorForIndex)(this, lampindex, desiredcolor), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetSingleColorForIndices(this: *mut core::ffi::c_void, desiredcolor: super::super::super::UI::Color, lampIndexes_array_size: u32, lampindexes: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayUpdateRequestedEventArgs", "SetSingleColorForIndices", 9usize, |this| ((**(this as *const *const ILampArrayUpdateRequestedEventArgs_Vtbl)).SetSingleColorForIndices)(this, desiredcolor, lampIndexes_array_size, lampindexes), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetColorsForIndices(this: *mut core::ffi::c_void, desiredColors_array_size: u32, desiredcolors: *const super::super::super::UI::Color, lampIndexes_array_size: u32, lampindexes: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayUpdateRequestedEventArgs", "SetColorsForIndices", 10usize, |this| ((**(this as *const *const ILampArrayUpdateRequestedEventArgs_Vtbl)).SetColorsForIndices)(this, desiredColors_array_size, desiredcolors, lampIndexes_array_size, lampindexes), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SinceStarted,
+            #[cfg(feature = "UI")]
+            SetColor,
+            #[cfg(not(feature = "UI"))]
+            SetColor: 0,
+            #[cfg(feature = "UI")]
+            SetColorForIndex,
+            #[cfg(not(feature = "UI"))]
+            SetColorForIndex: 0,
+            #[cfg(feature = "UI")]
+            SetSingleColorForIndices,
+            #[cfg(not(feature = "UI"))]
+            SetSingleColorForIndices: 0,
+            #[cfg(feature = "UI")]
+            SetColorsForIndices,
+            #[cfg(not(feature = "UI"))]
+            SetColorsForIndices: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArrayUpdateRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
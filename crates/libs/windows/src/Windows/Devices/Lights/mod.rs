@@ -631,6 +631,7 @@ impl LampArrayKind {
 impl windows_core::TypeKind for LampArrayKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LampArrayKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LampArrayKind").field(&self.0).finish()
@@ -654,6 +655,7 @@ impl LampPurposes {
 impl windows_core::TypeKind for LampPurposes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LampPurposes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LampPurposes").field(&self.0).finish()
@@ -695,3 +697,5 @@ impl core::ops::Not for LampPurposes {
 impl windows_core::RuntimeType for LampPurposes {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Lights.LampPurposes;u4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
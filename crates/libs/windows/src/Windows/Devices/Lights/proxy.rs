@@ -0,0 +1,347 @@
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for ILamp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILamp", "DeviceId", 6usize, |this| ((**(this as *const *const ILamp_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILamp", "IsEnabled", 7usize, |this| ((**(this as *const *const ILamp_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILamp", "SetIsEnabled", 8usize, |this| ((**(this as *const *const ILamp_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrightnessLevel(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILamp", "BrightnessLevel", 9usize, |this| ((**(this as *const *const ILamp_Vtbl)).BrightnessLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBrightnessLevel(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILamp", "SetBrightnessLevel", 10usize, |this| ((**(this as *const *const ILamp_Vtbl)).SetBrightnessLevel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsColorSettable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILamp", "IsColorSettable", 11usize, |this| ((**(this as *const *const ILamp_Vtbl)).IsColorSettable)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILamp", "Color", 12usize, |this| ((**(this as *const *const ILamp_Vtbl)).Color)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILamp", "SetColor", 13usize, |this| ((**(this as *const *const ILamp_Vtbl)).SetColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvailabilityChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILamp", "AvailabilityChanged", 14usize, |this| ((**(this as *const *const ILamp_Vtbl)).AvailabilityChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAvailabilityChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILamp", "RemoveAvailabilityChanged", 15usize, |this| ((**(this as *const *const ILamp_Vtbl)).RemoveAvailabilityChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            IsEnabled,
+            SetIsEnabled,
+            BrightnessLevel,
+            SetBrightnessLevel,
+            IsColorSettable,
+            #[cfg(feature = "UI")]
+            Color,
+            #[cfg(not(feature = "UI"))]
+            Color: 0,
+            #[cfg(feature = "UI")]
+            SetColor,
+            #[cfg(not(feature = "UI"))]
+            SetColor: 0,
+            AvailabilityChanged,
+            RemoveAvailabilityChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILamp as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Storage_Streams", feature = "System", feature = "UI"))]
+impl windows_core::imp::ProxyVtable for ILampArray_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "DeviceId", 6usize, |this| ((**(this as *const *const ILampArray_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareVendorId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "HardwareVendorId", 7usize, |this| ((**(this as *const *const ILampArray_Vtbl)).HardwareVendorId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareProductId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "HardwareProductId", 8usize, |this| ((**(this as *const *const ILampArray_Vtbl)).HardwareProductId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareVersion(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "HardwareVersion", 9usize, |this| ((**(this as *const *const ILampArray_Vtbl)).HardwareVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LampArrayKind(this: *mut core::ffi::c_void, result__: *mut LampArrayKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "LampArrayKind", 10usize, |this| ((**(this as *const *const ILampArray_Vtbl)).LampArrayKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LampCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "LampCount", 11usize, |this| ((**(this as *const *const ILampArray_Vtbl)).LampCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinUpdateInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "MinUpdateInterval", 12usize, |this| ((**(this as *const *const ILampArray_Vtbl)).MinUpdateInterval)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn BoundingBox(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "BoundingBox", 13usize, |this| ((**(this as *const *const ILampArray_Vtbl)).BoundingBox)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "IsEnabled", 14usize, |this| ((**(this as *const *const ILampArray_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "SetIsEnabled", 15usize, |this| ((**(this as *const *const ILampArray_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrightnessLevel(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "BrightnessLevel", 16usize, |this| ((**(this as *const *const ILampArray_Vtbl)).BrightnessLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBrightnessLevel(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "SetBrightnessLevel", 17usize, |this| ((**(this as *const *const ILampArray_Vtbl)).SetBrightnessLevel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "IsConnected", 18usize, |this| ((**(this as *const *const ILampArray_Vtbl)).IsConnected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportsVirtualKeys(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "SupportsVirtualKeys", 19usize, |this| ((**(this as *const *const ILampArray_Vtbl)).SupportsVirtualKeys)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLampInfo(this: *mut core::ffi::c_void, lampindex: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "GetLampInfo", 20usize, |this| ((**(this as *const *const ILampArray_Vtbl)).GetLampInfo)(this, lampindex, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetIndicesForKey(this: *mut core::ffi::c_void, key: super::super::System::VirtualKey, result_size__: *mut u32, result__: *mut *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "GetIndicesForKey", 21usize, |this| ((**(this as *const *const ILampArray_Vtbl)).GetIndicesForKey)(this, key, result_size__, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndicesForPurposes(this: *mut core::ffi::c_void, purposes: LampPurposes, result_size__: *mut u32, result__: *mut *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "GetIndicesForPurposes", 22usize, |this| ((**(this as *const *const ILampArray_Vtbl)).GetIndicesForPurposes)(this, purposes, result_size__, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, desiredcolor: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "SetColor", 23usize, |this| ((**(this as *const *const ILampArray_Vtbl)).SetColor)(this, desiredcolor), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetColorForIndex(this: *mut core::ffi::c_void, lampindex: i32, desiredcolor: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "SetColorForIndex", 24usize, |this| ((**(this as *const *const ILampArray_Vtbl)).SetColorForIndex)(this, lampindex, desiredcolor), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetSingleColorForIndices(this: *mut core::ffi::c_void, desiredcolor: super::super::UI::Color, lampIndexes_array_size: u32, lampindexes: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "SetSingleColorForIndices", 25usize, |this| ((**(this as *const *const ILampArray_Vtbl)).SetSingleColorForIndices)(this, desiredcolor, lampIndexes_array_size, lampindexes), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetColorsForIndices(this: *mut core::ffi::c_void, desiredColors_array_size: u32, desiredcolors: *const super::super::UI::Color, lampIndexes_array_size: u32, lampindexes: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "SetColorsForIndices", 26usize, |this| ((**(this as *const *const ILampArray_Vtbl)).SetColorsForIndices)(this, desiredColors_array_size, desiredcolors, lampIndexes_array_size, lampindexes), |result| Some(*result))
+        }
+        #[cfg(all(feature = "System", feature = "UI"))]
+        unsafe extern "system" fn SetColorsForKey(this: *mut core::ffi::c_void, desiredcolor: super::super::UI::Color, key: super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "SetColorsForKey", 27usize, |this| ((**(this as *const *const ILampArray_Vtbl)).SetColorsForKey)(this, desiredcolor, key), |result| Some(*result))
+        }
+        #[cfg(all(feature = "System", feature = "UI"))]
+        unsafe extern "system" fn SetColorsForKeys(this: *mut core::ffi::c_void, desiredColors_array_size: u32, desiredcolors: *const super::super::UI::Color, keys_array_size: u32, keys: *const super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "SetColorsForKeys", 28usize, |this| ((**(this as *const *const ILampArray_Vtbl)).SetColorsForKeys)(this, desiredColors_array_size, desiredcolors, keys_array_size, keys), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetColorsForPurposes(this: *mut core::ffi::c_void, desiredcolor: super::super::UI::Color, purposes: LampPurposes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "SetColorsForPurposes", 29usize, |this| ((**(this as *const *const ILampArray_Vtbl)).SetColorsForPurposes)(this, desiredcolor, purposes), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SendMessageAsync(this: *mut core::ffi::c_void, messageid: i32, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "SendMessageAsync", 30usize, |this| ((**(this as *const *const ILampArray_Vtbl)).SendMessageAsync)(this, messageid, message, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn RequestMessageAsync(this: *mut core::ffi::c_void, messageid: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray", "RequestMessageAsync", 31usize, |this| ((**(this as *const *const ILampArray_Vtbl)).RequestMessageAsync)(this, messageid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            HardwareVendorId,
+            HardwareProductId,
+            HardwareVersion,
+            LampArrayKind,
+            LampCount,
+            MinUpdateInterval,
+            #[cfg(feature = "Foundation_Numerics")]
+            BoundingBox,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            BoundingBox: 0,
+            IsEnabled,
+            SetIsEnabled,
+            BrightnessLevel,
+            SetBrightnessLevel,
+            IsConnected,
+            SupportsVirtualKeys,
+            GetLampInfo,
+            #[cfg(feature = "System")]
+            GetIndicesForKey,
+            #[cfg(not(feature = "System"))]
+            GetIndicesForKey: 0,
+            GetIndicesForPurposes,
+            #[cfg(feature = "UI")]
+            SetColor,
+            #[cfg(not(feature = "UI"))]
+            SetColor: 0,
+            #[cfg(feature = "UI")]
+            SetColorForIndex,
+            #[cfg(not(feature = "UI"))]
+            SetColorForIndex: 0,
+            #[cfg(feature = "UI")]
+            SetSingleColorForIndices,
+            #[cfg(not(feature = "UI"))]
+            SetSingleColorForIndices: 0,
+            #[cfg(feature = "UI")]
+            SetColorsForIndices,
+            #[cfg(not(feature = "UI"))]
+            SetColorsForIndices: 0,
+            #[cfg(all(feature = "System", feature = "UI"))]
+            SetColorsForKey,
+            #[cfg(not(all(feature = "System", feature = "UI")))]
+            SetColorsForKey: 0,
+            #[cfg(all(feature = "System", feature = "UI"))]
+            SetColorsForKeys,
+            #[cfg(not(all(feature = "System", feature = "UI")))]
+            SetColorsForKeys: 0,
+            #[cfg(feature = "UI")]
+            SetColorsForPurposes,
+            #[cfg(not(feature = "UI"))]
+            SetColorsForPurposes: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SendMessageAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SendMessageAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            RequestMessageAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            RequestMessageAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArray as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILampArray2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAvailable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray2", "IsAvailable", 6usize, |this| ((**(this as *const *const ILampArray2_Vtbl)).IsAvailable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvailabilityChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray2", "AvailabilityChanged", 7usize, |this| ((**(this as *const *const ILampArray2_Vtbl)).AvailabilityChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAvailabilityChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArray2", "RemoveAvailabilityChanged", 8usize, |this| ((**(this as *const *const ILampArray2_Vtbl)).RemoveAvailabilityChanged)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsAvailable, AvailabilityChanged, RemoveAvailabilityChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArray2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILampArrayStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const ILampArrayStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampArrayStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const ILampArrayStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampArrayStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILampAvailabilityChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAvailable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampAvailabilityChangedEventArgs", "IsAvailable", 6usize, |this| ((**(this as *const *const ILampAvailabilityChangedEventArgs_Vtbl)).IsAvailable)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsAvailable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampAvailabilityChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "UI"))]
+impl windows_core::imp::ProxyVtable for ILampInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Index(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampInfo", "Index", 6usize, |this| ((**(this as *const *const ILampInfo_Vtbl)).Index)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Purposes(this: *mut core::ffi::c_void, result__: *mut LampPurposes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampInfo", "Purposes", 7usize, |this| ((**(this as *const *const ILampInfo_Vtbl)).Purposes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampInfo", "Position", 8usize, |this| ((**(this as *const *const ILampInfo_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedLevelCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampInfo", "RedLevelCount", 9usize, |this| ((**(this as *const *const ILampInfo_Vtbl)).RedLevelCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GreenLevelCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampInfo", "GreenLevelCount", 10usize, |this| ((**(this as *const *const ILampInfo_Vtbl)).GreenLevelCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlueLevelCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampInfo", "BlueLevelCount", 11usize, |this| ((**(this as *const *const ILampInfo_Vtbl)).BlueLevelCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GainLevelCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampInfo", "GainLevelCount", 12usize, |this| ((**(this as *const *const ILampInfo_Vtbl)).GainLevelCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn FixedColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampInfo", "FixedColor", 13usize, |this| ((**(this as *const *const ILampInfo_Vtbl)).FixedColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn GetNearestSupportedColor(this: *mut core::ffi::c_void, desiredcolor: super::super::UI::Color, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampInfo", "GetNearestSupportedColor", 14usize, |this| ((**(this as *const *const ILampInfo_Vtbl)).GetNearestSupportedColor)(this, desiredcolor, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateLatency(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampInfo", "UpdateLatency", 15usize, |this| ((**(this as *const *const ILampInfo_Vtbl)).UpdateLatency)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Index,
+            Purposes,
+            #[cfg(feature = "Foundation_Numerics")]
+            Position,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Position: 0,
+            RedLevelCount,
+            GreenLevelCount,
+            BlueLevelCount,
+            GainLevelCount,
+            #[cfg(feature = "UI")]
+            FixedColor,
+            #[cfg(not(feature = "UI"))]
+            FixedColor: 0,
+            #[cfg(feature = "UI")]
+            GetNearestSupportedColor,
+            #[cfg(not(feature = "UI"))]
+            GetNearestSupportedColor: 0,
+            UpdateLatency,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILampStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const ILampStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const ILampStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILampStatics", "GetDefaultAsync", 8usize, |this| ((**(this as *const *const ILampStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync, GetDefaultAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILampStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1705,6 +1705,7 @@ impl MidiMessageType {
 impl windows_core::TypeKind for MidiMessageType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MidiMessageType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MidiMessageType").field(&self.0).finish()
@@ -1715,3 +1716,5 @@ impl windows_core::RuntimeType for MidiMessageType {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,482 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMidiChannelPressureMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Channel(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiChannelPressureMessage", "Channel", 6usize, |this| ((**(this as *const *const IMidiChannelPressureMessage_Vtbl)).Channel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pressure(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiChannelPressureMessage", "Pressure", 7usize, |this| ((**(this as *const *const IMidiChannelPressureMessage_Vtbl)).Pressure)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Channel, Pressure }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiChannelPressureMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMidiChannelPressureMessageFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMidiChannelPressureMessage(this: *mut core::ffi::c_void, channel: u8, pressure: u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiChannelPressureMessageFactory", "CreateMidiChannelPressureMessage", 6usize, |this| ((**(this as *const *const IMidiChannelPressureMessageFactory_Vtbl)).CreateMidiChannelPressureMessage)(this, channel, pressure, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMidiChannelPressureMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiChannelPressureMessageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMidiControlChangeMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Channel(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiControlChangeMessage", "Channel", 6usize, |this| ((**(this as *const *const IMidiControlChangeMessage_Vtbl)).Channel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Controller(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiControlChangeMessage", "Controller", 7usize, |this| ((**(this as *const *const IMidiControlChangeMessage_Vtbl)).Controller)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControlValue(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiControlChangeMessage", "ControlValue", 8usize, |this| ((**(this as *const *const IMidiControlChangeMessage_Vtbl)).ControlValue)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Channel, Controller, ControlValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiControlChangeMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMidiControlChangeMessageFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMidiControlChangeMessage(this: *mut core::ffi::c_void, channel: u8, controller: u8, controlvalue: u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiControlChangeMessageFactory", "CreateMidiControlChangeMessage", 6usize, |this| ((**(this as *const *const IMidiControlChangeMessageFactory_Vtbl)).CreateMidiControlChangeMessage)(this, channel, controller, controlvalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMidiControlChangeMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiControlChangeMessageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMidiInPort_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MessageReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiInPort", "MessageReceived", 6usize, |this| ((**(this as *const *const IMidiInPort_Vtbl)).MessageReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMessageReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiInPort", "RemoveMessageReceived", 7usize, |this| ((**(this as *const *const IMidiInPort_Vtbl)).RemoveMessageReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiInPort", "DeviceId", 8usize, |this| ((**(this as *const *const IMidiInPort_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MessageReceived, RemoveMessageReceived, DeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiInPort as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMidiInPortStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiInPortStatics", "FromIdAsync", 6usize, |this| ((**(this as *const *const IMidiInPortStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiInPortStatics", "GetDeviceSelector", 7usize, |this| ((**(this as *const *const IMidiInPortStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromIdAsync, GetDeviceSelector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiInPortStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMidiMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiMessage", "Timestamp", 6usize, |this| ((**(this as *const *const IMidiMessage_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn RawData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiMessage", "RawData", 7usize, |this| ((**(this as *const *const IMidiMessage_Vtbl)).RawData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut MidiMessageType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiMessage", "Type", 8usize, |this| ((**(this as *const *const IMidiMessage_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Timestamp,
+            #[cfg(feature = "Storage_Streams")]
+            RawData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            RawData: 0,
+            Type,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMidiMessageReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiMessageReceivedEventArgs", "Message", 6usize, |this| ((**(this as *const *const IMidiMessageReceivedEventArgs_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Message }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiMessageReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMidiNoteOffMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Channel(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiNoteOffMessage", "Channel", 6usize, |this| ((**(this as *const *const IMidiNoteOffMessage_Vtbl)).Channel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Note(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiNoteOffMessage", "Note", 7usize, |this| ((**(this as *const *const IMidiNoteOffMessage_Vtbl)).Note)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Velocity(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiNoteOffMessage", "Velocity", 8usize, |this| ((**(this as *const *const IMidiNoteOffMessage_Vtbl)).Velocity)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Channel, Note, Velocity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiNoteOffMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMidiNoteOffMessageFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMidiNoteOffMessage(this: *mut core::ffi::c_void, channel: u8, note: u8, velocity: u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiNoteOffMessageFactory", "CreateMidiNoteOffMessage", 6usize, |this| ((**(this as *const *const IMidiNoteOffMessageFactory_Vtbl)).CreateMidiNoteOffMessage)(this, channel, note, velocity, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMidiNoteOffMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiNoteOffMessageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMidiNoteOnMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Channel(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiNoteOnMessage", "Channel", 6usize, |this| ((**(this as *const *const IMidiNoteOnMessage_Vtbl)).Channel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Note(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiNoteOnMessage", "Note", 7usize, |this| ((**(this as *const *const IMidiNoteOnMessage_Vtbl)).Note)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Velocity(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiNoteOnMessage", "Velocity", 8usize, |this| ((**(this as *const *const IMidiNoteOnMessage_Vtbl)).Velocity)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Channel, Note, Velocity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiNoteOnMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMidiNoteOnMessageFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMidiNoteOnMessage(this: *mut core::ffi::c_void, channel: u8, note: u8, velocity: u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiNoteOnMessageFactory", "CreateMidiNoteOnMessage", 6usize, |this| ((**(this as *const *const IMidiNoteOnMessageFactory_Vtbl)).CreateMidiNoteOnMessage)(this, channel, note, velocity, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMidiNoteOnMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiNoteOnMessageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMidiOutPort_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendMessage(this: *mut core::ffi::c_void, midimessage: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiOutPort", "SendMessage", 6usize, |this| ((**(this as *const *const IMidiOutPort_Vtbl)).SendMessage)(this, midimessage), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SendBuffer(this: *mut core::ffi::c_void, mididata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiOutPort", "SendBuffer", 7usize, |this| ((**(this as *const *const IMidiOutPort_Vtbl)).SendBuffer)(this, mididata), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiOutPort", "DeviceId", 8usize, |this| ((**(this as *const *const IMidiOutPort_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SendMessage,
+            #[cfg(feature = "Storage_Streams")]
+            SendBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SendBuffer: 0,
+            DeviceId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiOutPort as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMidiOutPortStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiOutPortStatics", "FromIdAsync", 6usize, |this| ((**(this as *const *const IMidiOutPortStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiOutPortStatics", "GetDeviceSelector", 7usize, |this| ((**(this as *const *const IMidiOutPortStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromIdAsync, GetDeviceSelector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiOutPortStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMidiPitchBendChangeMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Channel(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiPitchBendChangeMessage", "Channel", 6usize, |this| ((**(this as *const *const IMidiPitchBendChangeMessage_Vtbl)).Channel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bend(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiPitchBendChangeMessage", "Bend", 7usize, |this| ((**(this as *const *const IMidiPitchBendChangeMessage_Vtbl)).Bend)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Channel, Bend }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiPitchBendChangeMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMidiPitchBendChangeMessageFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMidiPitchBendChangeMessage(this: *mut core::ffi::c_void, channel: u8, bend: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiPitchBendChangeMessageFactory", "CreateMidiPitchBendChangeMessage", 6usize, |this| ((**(this as *const *const IMidiPitchBendChangeMessageFactory_Vtbl)).CreateMidiPitchBendChangeMessage)(this, channel, bend, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMidiPitchBendChangeMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiPitchBendChangeMessageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMidiPolyphonicKeyPressureMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Channel(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiPolyphonicKeyPressureMessage", "Channel", 6usize, |this| ((**(this as *const *const IMidiPolyphonicKeyPressureMessage_Vtbl)).Channel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Note(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiPolyphonicKeyPressureMessage", "Note", 7usize, |this| ((**(this as *const *const IMidiPolyphonicKeyPressureMessage_Vtbl)).Note)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pressure(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiPolyphonicKeyPressureMessage", "Pressure", 8usize, |this| ((**(this as *const *const IMidiPolyphonicKeyPressureMessage_Vtbl)).Pressure)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Channel, Note, Pressure }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiPolyphonicKeyPressureMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMidiPolyphonicKeyPressureMessageFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMidiPolyphonicKeyPressureMessage(this: *mut core::ffi::c_void, channel: u8, note: u8, pressure: u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiPolyphonicKeyPressureMessageFactory", "CreateMidiPolyphonicKeyPressureMessage", 6usize, |this| ((**(this as *const *const IMidiPolyphonicKeyPressureMessageFactory_Vtbl)).CreateMidiPolyphonicKeyPressureMessage)(this, channel, note, pressure, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMidiPolyphonicKeyPressureMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiPolyphonicKeyPressureMessageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMidiProgramChangeMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Channel(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiProgramChangeMessage", "Channel", 6usize, |this| ((**(this as *const *const IMidiProgramChangeMessage_Vtbl)).Channel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Program(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiProgramChangeMessage", "Program", 7usize, |this| ((**(this as *const *const IMidiProgramChangeMessage_Vtbl)).Program)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Channel, Program }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiProgramChangeMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMidiProgramChangeMessageFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMidiProgramChangeMessage(this: *mut core::ffi::c_void, channel: u8, program: u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiProgramChangeMessageFactory", "CreateMidiProgramChangeMessage", 6usize, |this| ((**(this as *const *const IMidiProgramChangeMessageFactory_Vtbl)).CreateMidiProgramChangeMessage)(this, channel, program, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMidiProgramChangeMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiProgramChangeMessageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMidiSongPositionPointerMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Beats(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiSongPositionPointerMessage", "Beats", 6usize, |this| ((**(this as *const *const IMidiSongPositionPointerMessage_Vtbl)).Beats)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Beats }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiSongPositionPointerMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMidiSongPositionPointerMessageFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMidiSongPositionPointerMessage(this: *mut core::ffi::c_void, beats: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiSongPositionPointerMessageFactory", "CreateMidiSongPositionPointerMessage", 6usize, |this| ((**(this as *const *const IMidiSongPositionPointerMessageFactory_Vtbl)).CreateMidiSongPositionPointerMessage)(this, beats, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMidiSongPositionPointerMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiSongPositionPointerMessageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMidiSongSelectMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Song(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiSongSelectMessage", "Song", 6usize, |this| ((**(this as *const *const IMidiSongSelectMessage_Vtbl)).Song)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Song }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiSongSelectMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMidiSongSelectMessageFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMidiSongSelectMessage(this: *mut core::ffi::c_void, song: u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiSongSelectMessageFactory", "CreateMidiSongSelectMessage", 6usize, |this| ((**(this as *const *const IMidiSongSelectMessageFactory_Vtbl)).CreateMidiSongSelectMessage)(this, song, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMidiSongSelectMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiSongSelectMessageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMidiSynthesizer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn AudioDevice(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiSynthesizer", "AudioDevice", 6usize, |this| ((**(this as *const *const IMidiSynthesizer_Vtbl)).AudioDevice)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Volume(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiSynthesizer", "Volume", 7usize, |this| ((**(this as *const *const IMidiSynthesizer_Vtbl)).Volume)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolume(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiSynthesizer", "SetVolume", 8usize, |this| ((**(this as *const *const IMidiSynthesizer_Vtbl)).SetVolume)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Enumeration")]
+            AudioDevice,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            AudioDevice: 0,
+            Volume,
+            SetVolume,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiSynthesizer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Enumeration")]
+impl windows_core::imp::ProxyVtable for IMidiSynthesizerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiSynthesizerStatics", "CreateAsync", 6usize, |this| ((**(this as *const *const IMidiSynthesizerStatics_Vtbl)).CreateAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn CreateFromAudioDeviceAsync(this: *mut core::ffi::c_void, audiodevice: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiSynthesizerStatics", "CreateFromAudioDeviceAsync", 7usize, |this| ((**(this as *const *const IMidiSynthesizerStatics_Vtbl)).CreateFromAudioDeviceAsync)(this, audiodevice, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn IsSynthesizer(this: *mut core::ffi::c_void, mididevice: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiSynthesizerStatics", "IsSynthesizer", 8usize, |this| ((**(this as *const *const IMidiSynthesizerStatics_Vtbl)).IsSynthesizer)(this, mididevice, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateAsync,
+            #[cfg(feature = "Devices_Enumeration")]
+            CreateFromAudioDeviceAsync,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            CreateFromAudioDeviceAsync: 0,
+            #[cfg(feature = "Devices_Enumeration")]
+            IsSynthesizer,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            IsSynthesizer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiSynthesizerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMidiSystemExclusiveMessageFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateMidiSystemExclusiveMessage(this: *mut core::ffi::c_void, rawdata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiSystemExclusiveMessageFactory", "CreateMidiSystemExclusiveMessage", 6usize, |this| ((**(this as *const *const IMidiSystemExclusiveMessageFactory_Vtbl)).CreateMidiSystemExclusiveMessage)(this, rawdata, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateMidiSystemExclusiveMessage,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateMidiSystemExclusiveMessage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiSystemExclusiveMessageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMidiTimeCodeMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FrameType(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiTimeCodeMessage", "FrameType", 6usize, |this| ((**(this as *const *const IMidiTimeCodeMessage_Vtbl)).FrameType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Values(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiTimeCodeMessage", "Values", 7usize, |this| ((**(this as *const *const IMidiTimeCodeMessage_Vtbl)).Values)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FrameType, Values }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiTimeCodeMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMidiTimeCodeMessageFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMidiTimeCodeMessage(this: *mut core::ffi::c_void, frametype: u8, values: u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMidiTimeCodeMessageFactory", "CreateMidiTimeCodeMessage", 6usize, |this| ((**(this as *const *const IMidiTimeCodeMessageFactory_Vtbl)).CreateMidiTimeCodeMessage)(this, frametype, values, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMidiTimeCodeMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMidiTimeCodeMessageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1522,6 +1522,7 @@ impl BarcodeScannerTriggerState {
 impl windows_core::TypeKind for BarcodeScannerTriggerState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BarcodeScannerTriggerState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BarcodeScannerTriggerState").field(&self.0).finish()
@@ -1530,3 +1531,5 @@ impl core::fmt::Debug for BarcodeScannerTriggerState {
 impl windows_core::RuntimeType for BarcodeScannerTriggerState {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.PointOfService.Provider.BarcodeScannerTriggerState;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,722 @@
+impl windows_core::imp::ProxyVtable for IBarcodeScannerDisableScannerRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerDisableScannerRequest", "ReportCompletedAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerDisableScannerRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerDisableScannerRequest", "ReportFailedAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerDisableScannerRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportCompletedAsync, ReportFailedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerDisableScannerRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerDisableScannerRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportFailedWithFailedReasonAsync(this: *mut core::ffi::c_void, reason: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerDisableScannerRequest2", "ReportFailedWithFailedReasonAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerDisableScannerRequest2_Vtbl)).ReportFailedWithFailedReasonAsync)(this, reason, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedWithFailedReasonAndDescriptionAsync(this: *mut core::ffi::c_void, reason: i32, failedreasondescription: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerDisableScannerRequest2", "ReportFailedWithFailedReasonAndDescriptionAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerDisableScannerRequest2_Vtbl)).ReportFailedWithFailedReasonAndDescriptionAsync)(this, reason, failedreasondescription, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReportFailedWithFailedReasonAsync,
+            ReportFailedWithFailedReasonAndDescriptionAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerDisableScannerRequest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerDisableScannerRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerDisableScannerRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IBarcodeScannerDisableScannerRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerDisableScannerRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IBarcodeScannerDisableScannerRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerDisableScannerRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerEnableScannerRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerEnableScannerRequest", "ReportCompletedAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerEnableScannerRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerEnableScannerRequest", "ReportFailedAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerEnableScannerRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportCompletedAsync, ReportFailedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerEnableScannerRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerEnableScannerRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportFailedWithFailedReasonAsync(this: *mut core::ffi::c_void, reason: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerEnableScannerRequest2", "ReportFailedWithFailedReasonAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerEnableScannerRequest2_Vtbl)).ReportFailedWithFailedReasonAsync)(this, reason, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedWithFailedReasonAndDescriptionAsync(this: *mut core::ffi::c_void, reason: i32, failedreasondescription: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerEnableScannerRequest2", "ReportFailedWithFailedReasonAndDescriptionAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerEnableScannerRequest2_Vtbl)).ReportFailedWithFailedReasonAndDescriptionAsync)(this, reason, failedreasondescription, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReportFailedWithFailedReasonAsync,
+            ReportFailedWithFailedReasonAndDescriptionAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerEnableScannerRequest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerEnableScannerRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerEnableScannerRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IBarcodeScannerEnableScannerRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerEnableScannerRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IBarcodeScannerEnableScannerRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerEnableScannerRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerFrameReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerFrameReader", "StartAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerFrameReader_Vtbl)).StartAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerFrameReader", "StopAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerFrameReader_Vtbl)).StopAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryAcquireLatestFrameAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerFrameReader", "TryAcquireLatestFrameAsync", 8usize, |this| ((**(this as *const *const IBarcodeScannerFrameReader_Vtbl)).TryAcquireLatestFrameAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerFrameReader", "Connection", 9usize, |this| ((**(this as *const *const IBarcodeScannerFrameReader_Vtbl)).Connection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameArrived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerFrameReader", "FrameArrived", 10usize, |this| ((**(this as *const *const IBarcodeScannerFrameReader_Vtbl)).FrameArrived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFrameArrived(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerFrameReader", "RemoveFrameArrived", 11usize, |this| ((**(this as *const *const IBarcodeScannerFrameReader_Vtbl)).RemoveFrameArrived)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartAsync,
+            StopAsync,
+            TryAcquireLatestFrameAsync,
+            Connection,
+            FrameArrived,
+            RemoveFrameArrived,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerFrameReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerFrameReaderFrameArrivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerFrameReaderFrameArrivedEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const IBarcodeScannerFrameReaderFrameArrivedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerFrameReaderFrameArrivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerGetSymbologyAttributesRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Symbology(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerGetSymbologyAttributesRequest", "Symbology", 6usize, |this| ((**(this as *const *const IBarcodeScannerGetSymbologyAttributesRequest_Vtbl)).Symbology)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, attributes: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerGetSymbologyAttributesRequest", "ReportCompletedAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerGetSymbologyAttributesRequest_Vtbl)).ReportCompletedAsync)(this, attributes, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerGetSymbologyAttributesRequest", "ReportFailedAsync", 8usize, |this| ((**(this as *const *const IBarcodeScannerGetSymbologyAttributesRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Symbology, ReportCompletedAsync, ReportFailedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerGetSymbologyAttributesRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerGetSymbologyAttributesRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportFailedWithFailedReasonAsync(this: *mut core::ffi::c_void, reason: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerGetSymbologyAttributesRequest2", "ReportFailedWithFailedReasonAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerGetSymbologyAttributesRequest2_Vtbl)).ReportFailedWithFailedReasonAsync)(this, reason, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedWithFailedReasonAndDescriptionAsync(this: *mut core::ffi::c_void, reason: i32, failedreasondescription: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerGetSymbologyAttributesRequest2", "ReportFailedWithFailedReasonAndDescriptionAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerGetSymbologyAttributesRequest2_Vtbl)).ReportFailedWithFailedReasonAndDescriptionAsync)(this, reason, failedreasondescription, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReportFailedWithFailedReasonAsync,
+            ReportFailedWithFailedReasonAndDescriptionAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerGetSymbologyAttributesRequest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerGetSymbologyAttributesRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerGetSymbologyAttributesRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IBarcodeScannerGetSymbologyAttributesRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerGetSymbologyAttributesRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IBarcodeScannerGetSymbologyAttributesRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerGetSymbologyAttributesRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerHideVideoPreviewRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerHideVideoPreviewRequest", "ReportCompletedAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerHideVideoPreviewRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerHideVideoPreviewRequest", "ReportFailedAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerHideVideoPreviewRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportCompletedAsync, ReportFailedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerHideVideoPreviewRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerHideVideoPreviewRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportFailedWithFailedReasonAsync(this: *mut core::ffi::c_void, reason: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerHideVideoPreviewRequest2", "ReportFailedWithFailedReasonAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerHideVideoPreviewRequest2_Vtbl)).ReportFailedWithFailedReasonAsync)(this, reason, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedWithFailedReasonAndDescriptionAsync(this: *mut core::ffi::c_void, reason: i32, failedreasondescription: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerHideVideoPreviewRequest2", "ReportFailedWithFailedReasonAndDescriptionAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerHideVideoPreviewRequest2_Vtbl)).ReportFailedWithFailedReasonAndDescriptionAsync)(this, reason, failedreasondescription, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReportFailedWithFailedReasonAsync,
+            ReportFailedWithFailedReasonAndDescriptionAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerHideVideoPreviewRequest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerHideVideoPreviewRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerHideVideoPreviewRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IBarcodeScannerHideVideoPreviewRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerHideVideoPreviewRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IBarcodeScannerHideVideoPreviewRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerHideVideoPreviewRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBarcodeScannerProviderConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "Id", 6usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "VideoDeviceId", 7usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).VideoDeviceId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedSymbologies(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "SupportedSymbologies", 8usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).SupportedSymbologies)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompanyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "CompanyName", 9usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).CompanyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompanyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "SetCompanyName", 10usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).SetCompanyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "Name", 11usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "SetName", 12usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "Version", 13usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).Version)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVersion(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "SetVersion", 14usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).SetVersion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "Start", 15usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportScannedDataAsync(this: *mut core::ffi::c_void, report: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "ReportScannedDataAsync", 16usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).ReportScannedDataAsync)(this, report, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportTriggerStateAsync(this: *mut core::ffi::c_void, state: BarcodeScannerTriggerState, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "ReportTriggerStateAsync", 17usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).ReportTriggerStateAsync)(this, state, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportErrorAsync(this: *mut core::ffi::c_void, errordata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "ReportErrorAsync", 18usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).ReportErrorAsync)(this, errordata, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportErrorAsyncWithScanReport(this: *mut core::ffi::c_void, errordata: *mut core::ffi::c_void, isretriable: bool, scanreport: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "ReportErrorAsyncWithScanReport", 19usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).ReportErrorAsyncWithScanReport)(this, errordata, isretriable, scanreport, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableScannerRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "EnableScannerRequested", 20usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).EnableScannerRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnableScannerRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "RemoveEnableScannerRequested", 21usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).RemoveEnableScannerRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableScannerRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "DisableScannerRequested", 22usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).DisableScannerRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDisableScannerRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "RemoveDisableScannerRequested", 23usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).RemoveDisableScannerRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActiveSymbologiesRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "SetActiveSymbologiesRequested", 24usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).SetActiveSymbologiesRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSetActiveSymbologiesRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "RemoveSetActiveSymbologiesRequested", 25usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).RemoveSetActiveSymbologiesRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartSoftwareTriggerRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "StartSoftwareTriggerRequested", 26usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).StartSoftwareTriggerRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStartSoftwareTriggerRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "RemoveStartSoftwareTriggerRequested", 27usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).RemoveStartSoftwareTriggerRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopSoftwareTriggerRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "StopSoftwareTriggerRequested", 28usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).StopSoftwareTriggerRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStopSoftwareTriggerRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "RemoveStopSoftwareTriggerRequested", 29usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).RemoveStopSoftwareTriggerRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBarcodeSymbologyAttributesRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "GetBarcodeSymbologyAttributesRequested", 30usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).GetBarcodeSymbologyAttributesRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGetBarcodeSymbologyAttributesRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "RemoveGetBarcodeSymbologyAttributesRequested", 31usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).RemoveGetBarcodeSymbologyAttributesRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBarcodeSymbologyAttributesRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "SetBarcodeSymbologyAttributesRequested", 32usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).SetBarcodeSymbologyAttributesRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSetBarcodeSymbologyAttributesRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "RemoveSetBarcodeSymbologyAttributesRequested", 33usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).RemoveSetBarcodeSymbologyAttributesRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn HideVideoPreviewRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "HideVideoPreviewRequested", 34usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).HideVideoPreviewRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHideVideoPreviewRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection", "RemoveHideVideoPreviewRequested", 35usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection_Vtbl)).RemoveHideVideoPreviewRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            VideoDeviceId,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedSymbologies,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedSymbologies: 0,
+            CompanyName,
+            SetCompanyName,
+            Name,
+            SetName,
+            Version,
+            SetVersion,
+            Start,
+            ReportScannedDataAsync,
+            ReportTriggerStateAsync,
+            ReportErrorAsync,
+            ReportErrorAsyncWithScanReport,
+            EnableScannerRequested,
+            RemoveEnableScannerRequested,
+            DisableScannerRequested,
+            RemoveDisableScannerRequested,
+            SetActiveSymbologiesRequested,
+            RemoveSetActiveSymbologiesRequested,
+            StartSoftwareTriggerRequested,
+            RemoveStartSoftwareTriggerRequested,
+            StopSoftwareTriggerRequested,
+            RemoveStopSoftwareTriggerRequested,
+            GetBarcodeSymbologyAttributesRequested,
+            RemoveGetBarcodeSymbologyAttributesRequested,
+            SetBarcodeSymbologyAttributesRequested,
+            RemoveSetBarcodeSymbologyAttributesRequested,
+            HideVideoPreviewRequested,
+            RemoveHideVideoPreviewRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerProviderConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for IBarcodeScannerProviderConnection2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFrameReaderAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection2", "CreateFrameReaderAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection2_Vtbl)).CreateFrameReaderAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn CreateFrameReaderWithFormatAsync(this: *mut core::ffi::c_void, preferredformat: super::super::super::Graphics::Imaging::BitmapPixelFormat, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection2", "CreateFrameReaderWithFormatAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection2_Vtbl)).CreateFrameReaderWithFormatAsync)(this, preferredformat, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn CreateFrameReaderWithFormatAndSizeAsync(this: *mut core::ffi::c_void, preferredformat: super::super::super::Graphics::Imaging::BitmapPixelFormat, preferredsize: super::super::super::Graphics::Imaging::BitmapSize, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderConnection2", "CreateFrameReaderWithFormatAndSizeAsync", 8usize, |this| ((**(this as *const *const IBarcodeScannerProviderConnection2_Vtbl)).CreateFrameReaderWithFormatAndSizeAsync)(this, preferredformat, preferredsize, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFrameReaderAsync,
+            #[cfg(feature = "Graphics_Imaging")]
+            CreateFrameReaderWithFormatAsync,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            CreateFrameReaderWithFormatAsync: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            CreateFrameReaderWithFormatAndSizeAsync,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            CreateFrameReaderWithFormatAndSizeAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerProviderConnection2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerProviderTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerProviderTriggerDetails", "Connection", 6usize, |this| ((**(this as *const *const IBarcodeScannerProviderTriggerDetails_Vtbl)).Connection)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Connection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerProviderTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBarcodeScannerSetActiveSymbologiesRequest_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Symbologies(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerSetActiveSymbologiesRequest", "Symbologies", 6usize, |this| ((**(this as *const *const IBarcodeScannerSetActiveSymbologiesRequest_Vtbl)).Symbologies)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerSetActiveSymbologiesRequest", "ReportCompletedAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerSetActiveSymbologiesRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerSetActiveSymbologiesRequest", "ReportFailedAsync", 8usize, |this| ((**(this as *const *const IBarcodeScannerSetActiveSymbologiesRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Symbologies,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Symbologies: 0,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerSetActiveSymbologiesRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerSetActiveSymbologiesRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportFailedWithFailedReasonAsync(this: *mut core::ffi::c_void, reason: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerSetActiveSymbologiesRequest2", "ReportFailedWithFailedReasonAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerSetActiveSymbologiesRequest2_Vtbl)).ReportFailedWithFailedReasonAsync)(this, reason, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedWithFailedReasonAndDescriptionAsync(this: *mut core::ffi::c_void, reason: i32, failedreasondescription: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerSetActiveSymbologiesRequest2", "ReportFailedWithFailedReasonAndDescriptionAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerSetActiveSymbologiesRequest2_Vtbl)).ReportFailedWithFailedReasonAndDescriptionAsync)(this, reason, failedreasondescription, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReportFailedWithFailedReasonAsync,
+            ReportFailedWithFailedReasonAndDescriptionAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerSetActiveSymbologiesRequest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerSetActiveSymbologiesRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerSetActiveSymbologiesRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IBarcodeScannerSetActiveSymbologiesRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerSetActiveSymbologiesRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IBarcodeScannerSetActiveSymbologiesRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerSetActiveSymbologiesRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerSetSymbologyAttributesRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Symbology(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerSetSymbologyAttributesRequest", "Symbology", 6usize, |this| ((**(this as *const *const IBarcodeScannerSetSymbologyAttributesRequest_Vtbl)).Symbology)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Attributes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerSetSymbologyAttributesRequest", "Attributes", 7usize, |this| ((**(this as *const *const IBarcodeScannerSetSymbologyAttributesRequest_Vtbl)).Attributes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerSetSymbologyAttributesRequest", "ReportCompletedAsync", 8usize, |this| ((**(this as *const *const IBarcodeScannerSetSymbologyAttributesRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerSetSymbologyAttributesRequest", "ReportFailedAsync", 9usize, |this| ((**(this as *const *const IBarcodeScannerSetSymbologyAttributesRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Symbology,
+            Attributes,
+            ReportCompletedAsync,
+            ReportFailedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerSetSymbologyAttributesRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerSetSymbologyAttributesRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportFailedWithFailedReasonAsync(this: *mut core::ffi::c_void, reason: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerSetSymbologyAttributesRequest2", "ReportFailedWithFailedReasonAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerSetSymbologyAttributesRequest2_Vtbl)).ReportFailedWithFailedReasonAsync)(this, reason, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedWithFailedReasonAndDescriptionAsync(this: *mut core::ffi::c_void, reason: i32, failedreasondescription: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerSetSymbologyAttributesRequest2", "ReportFailedWithFailedReasonAndDescriptionAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerSetSymbologyAttributesRequest2_Vtbl)).ReportFailedWithFailedReasonAndDescriptionAsync)(this, reason, failedreasondescription, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReportFailedWithFailedReasonAsync,
+            ReportFailedWithFailedReasonAndDescriptionAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerSetSymbologyAttributesRequest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerSetSymbologyAttributesRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerSetSymbologyAttributesRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IBarcodeScannerSetSymbologyAttributesRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerSetSymbologyAttributesRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IBarcodeScannerSetSymbologyAttributesRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerSetSymbologyAttributesRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerStartSoftwareTriggerRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStartSoftwareTriggerRequest", "ReportCompletedAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerStartSoftwareTriggerRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStartSoftwareTriggerRequest", "ReportFailedAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerStartSoftwareTriggerRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportCompletedAsync, ReportFailedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerStartSoftwareTriggerRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerStartSoftwareTriggerRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportFailedWithFailedReasonAsync(this: *mut core::ffi::c_void, reason: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStartSoftwareTriggerRequest2", "ReportFailedWithFailedReasonAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerStartSoftwareTriggerRequest2_Vtbl)).ReportFailedWithFailedReasonAsync)(this, reason, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedWithFailedReasonAndDescriptionAsync(this: *mut core::ffi::c_void, reason: i32, failedreasondescription: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStartSoftwareTriggerRequest2", "ReportFailedWithFailedReasonAndDescriptionAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerStartSoftwareTriggerRequest2_Vtbl)).ReportFailedWithFailedReasonAndDescriptionAsync)(this, reason, failedreasondescription, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReportFailedWithFailedReasonAsync,
+            ReportFailedWithFailedReasonAndDescriptionAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerStartSoftwareTriggerRequest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerStartSoftwareTriggerRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStartSoftwareTriggerRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IBarcodeScannerStartSoftwareTriggerRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStartSoftwareTriggerRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IBarcodeScannerStartSoftwareTriggerRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerStartSoftwareTriggerRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerStopSoftwareTriggerRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStopSoftwareTriggerRequest", "ReportCompletedAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerStopSoftwareTriggerRequest_Vtbl)).ReportCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStopSoftwareTriggerRequest", "ReportFailedAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerStopSoftwareTriggerRequest_Vtbl)).ReportFailedAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportCompletedAsync, ReportFailedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerStopSoftwareTriggerRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerStopSoftwareTriggerRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportFailedWithFailedReasonAsync(this: *mut core::ffi::c_void, reason: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStopSoftwareTriggerRequest2", "ReportFailedWithFailedReasonAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerStopSoftwareTriggerRequest2_Vtbl)).ReportFailedWithFailedReasonAsync)(this, reason, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFailedWithFailedReasonAndDescriptionAsync(this: *mut core::ffi::c_void, reason: i32, failedreasondescription: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStopSoftwareTriggerRequest2", "ReportFailedWithFailedReasonAndDescriptionAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerStopSoftwareTriggerRequest2_Vtbl)).ReportFailedWithFailedReasonAndDescriptionAsync)(this, reason, failedreasondescription, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReportFailedWithFailedReasonAsync,
+            ReportFailedWithFailedReasonAndDescriptionAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerStopSoftwareTriggerRequest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerStopSoftwareTriggerRequestEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStopSoftwareTriggerRequestEventArgs", "Request", 6usize, |this| ((**(this as *const *const IBarcodeScannerStopSoftwareTriggerRequestEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStopSoftwareTriggerRequestEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IBarcodeScannerStopSoftwareTriggerRequestEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerStopSoftwareTriggerRequestEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Graphics_Imaging", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IBarcodeScannerVideoFrame_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::Imaging::BitmapPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerVideoFrame", "Format", 6usize, |this| ((**(this as *const *const IBarcodeScannerVideoFrame_Vtbl)).Format)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerVideoFrame", "Width", 7usize, |this| ((**(this as *const *const IBarcodeScannerVideoFrame_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerVideoFrame", "Height", 8usize, |this| ((**(this as *const *const IBarcodeScannerVideoFrame_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn PixelData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerVideoFrame", "PixelData", 9usize, |this| ((**(this as *const *const IBarcodeScannerVideoFrame_Vtbl)).PixelData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Imaging")]
+            Format,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            Format: 0,
+            Width,
+            Height,
+            #[cfg(feature = "Storage_Streams")]
+            PixelData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            PixelData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerVideoFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeSymbologyAttributesBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsCheckDigitValidationSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributesBuilder", "IsCheckDigitValidationSupported", 6usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributesBuilder_Vtbl)).IsCheckDigitValidationSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCheckDigitValidationSupported(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributesBuilder", "SetIsCheckDigitValidationSupported", 7usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributesBuilder_Vtbl)).SetIsCheckDigitValidationSupported)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCheckDigitTransmissionSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributesBuilder", "IsCheckDigitTransmissionSupported", 8usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributesBuilder_Vtbl)).IsCheckDigitTransmissionSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCheckDigitTransmissionSupported(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributesBuilder", "SetIsCheckDigitTransmissionSupported", 9usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributesBuilder_Vtbl)).SetIsCheckDigitTransmissionSupported)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDecodeLengthSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributesBuilder", "IsDecodeLengthSupported", 10usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributesBuilder_Vtbl)).IsDecodeLengthSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsDecodeLengthSupported(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributesBuilder", "SetIsDecodeLengthSupported", 11usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributesBuilder_Vtbl)).SetIsDecodeLengthSupported)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAttributes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributesBuilder", "CreateAttributes", 12usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributesBuilder_Vtbl)).CreateAttributes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsCheckDigitValidationSupported,
+            SetIsCheckDigitValidationSupported,
+            IsCheckDigitTransmissionSupported,
+            SetIsCheckDigitTransmissionSupported,
+            IsDecodeLengthSupported,
+            SetIsDecodeLengthSupported,
+            CreateAttributes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeSymbologyAttributesBuilder as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -8704,6 +8704,7 @@ impl BarcodeScannerStatus {
 impl windows_core::TypeKind for BarcodeScannerStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BarcodeScannerStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BarcodeScannerStatus").field(&self.0).finish()
@@ -8723,6 +8724,7 @@ impl BarcodeSymbologyDecodeLengthKind {
 impl windows_core::TypeKind for BarcodeSymbologyDecodeLengthKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BarcodeSymbologyDecodeLengthKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BarcodeSymbologyDecodeLengthKind").field(&self.0).finish()
@@ -8744,6 +8746,7 @@ impl CashDrawerStatusKind {
 impl windows_core::TypeKind for CashDrawerStatusKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CashDrawerStatusKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CashDrawerStatusKind").field(&self.0).finish()
@@ -8766,6 +8769,7 @@ impl LineDisplayCursorType {
 impl windows_core::TypeKind for LineDisplayCursorType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LineDisplayCursorType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LineDisplayCursorType").field(&self.0).finish()
@@ -8785,6 +8789,7 @@ impl LineDisplayDescriptorState {
 impl windows_core::TypeKind for LineDisplayDescriptorState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LineDisplayDescriptorState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LineDisplayDescriptorState").field(&self.0).finish()
@@ -8804,6 +8809,7 @@ impl LineDisplayHorizontalAlignment {
 impl windows_core::TypeKind for LineDisplayHorizontalAlignment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LineDisplayHorizontalAlignment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LineDisplayHorizontalAlignment").field(&self.0).finish()
@@ -8823,6 +8829,7 @@ impl LineDisplayMarqueeFormat {
 impl windows_core::TypeKind for LineDisplayMarqueeFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LineDisplayMarqueeFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LineDisplayMarqueeFormat").field(&self.0).finish()
@@ -8844,6 +8851,7 @@ impl LineDisplayPowerStatus {
 impl windows_core::TypeKind for LineDisplayPowerStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LineDisplayPowerStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LineDisplayPowerStatus").field(&self.0).finish()
@@ -8864,6 +8872,7 @@ impl LineDisplayScrollDirection {
 impl windows_core::TypeKind for LineDisplayScrollDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LineDisplayScrollDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LineDisplayScrollDirection").field(&self.0).finish()
@@ -8884,6 +8893,7 @@ impl LineDisplayTextAttribute {
 impl windows_core::TypeKind for LineDisplayTextAttribute {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LineDisplayTextAttribute {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LineDisplayTextAttribute").field(&self.0).finish()
@@ -8903,6 +8913,7 @@ impl LineDisplayTextAttributeGranularity {
 impl windows_core::TypeKind for LineDisplayTextAttributeGranularity {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LineDisplayTextAttributeGranularity {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LineDisplayTextAttributeGranularity").field(&self.0).finish()
@@ -8922,6 +8933,7 @@ impl LineDisplayVerticalAlignment {
 impl windows_core::TypeKind for LineDisplayVerticalAlignment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LineDisplayVerticalAlignment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LineDisplayVerticalAlignment").field(&self.0).finish()
@@ -8941,6 +8953,7 @@ impl MagneticStripeReaderAuthenticationLevel {
 impl windows_core::TypeKind for MagneticStripeReaderAuthenticationLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MagneticStripeReaderAuthenticationLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MagneticStripeReaderAuthenticationLevel").field(&self.0).finish()
@@ -8959,6 +8972,7 @@ impl MagneticStripeReaderAuthenticationProtocol {
 impl windows_core::TypeKind for MagneticStripeReaderAuthenticationProtocol {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MagneticStripeReaderAuthenticationProtocol {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MagneticStripeReaderAuthenticationProtocol").field(&self.0).finish()
@@ -8977,6 +8991,7 @@ impl MagneticStripeReaderErrorReportingType {
 impl windows_core::TypeKind for MagneticStripeReaderErrorReportingType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MagneticStripeReaderErrorReportingType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MagneticStripeReaderErrorReportingType").field(&self.0).finish()
@@ -8996,6 +9011,7 @@ impl MagneticStripeReaderStatus {
 impl windows_core::TypeKind for MagneticStripeReaderStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MagneticStripeReaderStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MagneticStripeReaderStatus").field(&self.0).finish()
@@ -9018,6 +9034,7 @@ impl MagneticStripeReaderTrackErrorType {
 impl windows_core::TypeKind for MagneticStripeReaderTrackErrorType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MagneticStripeReaderTrackErrorType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MagneticStripeReaderTrackErrorType").field(&self.0).finish()
@@ -9039,6 +9056,7 @@ impl MagneticStripeReaderTrackIds {
 impl windows_core::TypeKind for MagneticStripeReaderTrackIds {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MagneticStripeReaderTrackIds {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MagneticStripeReaderTrackIds").field(&self.0).finish()
@@ -9059,6 +9077,7 @@ impl PosConnectionTypes {
 impl windows_core::TypeKind for PosConnectionTypes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PosConnectionTypes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PosConnectionTypes").field(&self.0).finish()
@@ -9111,6 +9130,7 @@ impl PosPrinterAlignment {
 impl windows_core::TypeKind for PosPrinterAlignment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PosPrinterAlignment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PosPrinterAlignment").field(&self.0).finish()
@@ -9130,6 +9150,7 @@ impl PosPrinterBarcodeTextPosition {
 impl windows_core::TypeKind for PosPrinterBarcodeTextPosition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PosPrinterBarcodeTextPosition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PosPrinterBarcodeTextPosition").field(&self.0).finish()
@@ -9151,6 +9172,7 @@ impl PosPrinterCartridgeSensors {
 impl windows_core::TypeKind for PosPrinterCartridgeSensors {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PosPrinterCartridgeSensors {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PosPrinterCartridgeSensors").field(&self.0).finish()
@@ -9212,6 +9234,7 @@ impl PosPrinterColorCapabilities {
 impl windows_core::TypeKind for PosPrinterColorCapabilities {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PosPrinterColorCapabilities {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PosPrinterColorCapabilities").field(&self.0).finish()
@@ -9272,6 +9295,7 @@ impl PosPrinterColorCartridge {
 impl windows_core::TypeKind for PosPrinterColorCartridge {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PosPrinterColorCartridge {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PosPrinterColorCartridge").field(&self.0).finish()
@@ -9290,6 +9314,7 @@ impl PosPrinterLineDirection {
 impl windows_core::TypeKind for PosPrinterLineDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PosPrinterLineDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PosPrinterLineDirection").field(&self.0).finish()
@@ -9310,6 +9335,7 @@ impl PosPrinterLineStyle {
 impl windows_core::TypeKind for PosPrinterLineStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PosPrinterLineStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PosPrinterLineStyle").field(&self.0).finish()
@@ -9330,6 +9356,7 @@ impl PosPrinterMapMode {
 impl windows_core::TypeKind for PosPrinterMapMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PosPrinterMapMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PosPrinterMapMode").field(&self.0).finish()
@@ -9351,6 +9378,7 @@ impl PosPrinterMarkFeedCapabilities {
 impl windows_core::TypeKind for PosPrinterMarkFeedCapabilities {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PosPrinterMarkFeedCapabilities {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PosPrinterMarkFeedCapabilities").field(&self.0).finish()
@@ -9404,6 +9432,7 @@ impl PosPrinterMarkFeedKind {
 impl windows_core::TypeKind for PosPrinterMarkFeedKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PosPrinterMarkFeedKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PosPrinterMarkFeedKind").field(&self.0).finish()
@@ -9423,6 +9452,7 @@ impl PosPrinterPrintSide {
 impl windows_core::TypeKind for PosPrinterPrintSide {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PosPrinterPrintSide {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PosPrinterPrintSide").field(&self.0).finish()
@@ -9443,6 +9473,7 @@ impl PosPrinterRotation {
 impl windows_core::TypeKind for PosPrinterRotation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PosPrinterRotation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PosPrinterRotation").field(&self.0).finish()
@@ -9462,6 +9493,7 @@ impl PosPrinterRuledLineCapabilities {
 impl windows_core::TypeKind for PosPrinterRuledLineCapabilities {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PosPrinterRuledLineCapabilities {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PosPrinterRuledLineCapabilities").field(&self.0).finish()
@@ -9516,6 +9548,7 @@ impl PosPrinterStatusKind {
 impl windows_core::TypeKind for PosPrinterStatusKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PosPrinterStatusKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PosPrinterStatusKind").field(&self.0).finish()
@@ -9543,6 +9576,7 @@ impl UnifiedPosErrorReason {
 impl windows_core::TypeKind for UnifiedPosErrorReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UnifiedPosErrorReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UnifiedPosErrorReason").field(&self.0).finish()
@@ -9565,6 +9599,7 @@ impl UnifiedPosErrorSeverity {
 impl windows_core::TypeKind for UnifiedPosErrorSeverity {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UnifiedPosErrorSeverity {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UnifiedPosErrorSeverity").field(&self.0).finish()
@@ -9585,6 +9620,7 @@ impl UnifiedPosHealthCheckLevel {
 impl windows_core::TypeKind for UnifiedPosHealthCheckLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UnifiedPosHealthCheckLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UnifiedPosHealthCheckLevel").field(&self.0).finish()
@@ -9604,6 +9640,7 @@ impl UnifiedPosPowerReportingType {
 impl windows_core::TypeKind for UnifiedPosPowerReportingType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UnifiedPosPowerReportingType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UnifiedPosPowerReportingType").field(&self.0).finish()
@@ -9613,7 +9650,8 @@ impl windows_core::RuntimeType for UnifiedPosPowerReportingType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.PointOfService.UnifiedPosPowerReportingType;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SizeUInt32 {
     pub Width: u32,
     pub Height: u32,
@@ -9631,3 +9669,5 @@ impl Default for SizeUInt32 {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
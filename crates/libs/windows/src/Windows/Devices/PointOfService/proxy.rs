@@ -0,0 +1,3879 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IBarcodeScanner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScanner", "DeviceId", 6usize, |this| ((**(this as *const *const IBarcodeScanner_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Capabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScanner", "Capabilities", 7usize, |this| ((**(this as *const *const IBarcodeScanner_Vtbl)).Capabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClaimScannerAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScanner", "ClaimScannerAsync", 8usize, |this| ((**(this as *const *const IBarcodeScanner_Vtbl)).ClaimScannerAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckHealthAsync(this: *mut core::ffi::c_void, level: UnifiedPosHealthCheckLevel, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScanner", "CheckHealthAsync", 9usize, |this| ((**(this as *const *const IBarcodeScanner_Vtbl)).CheckHealthAsync)(this, level, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSupportedSymbologiesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScanner", "GetSupportedSymbologiesAsync", 10usize, |this| ((**(this as *const *const IBarcodeScanner_Vtbl)).GetSupportedSymbologiesAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSymbologySupportedAsync(this: *mut core::ffi::c_void, barcodesymbology: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScanner", "IsSymbologySupportedAsync", 11usize, |this| ((**(this as *const *const IBarcodeScanner_Vtbl)).IsSymbologySupportedAsync)(this, barcodesymbology, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn RetrieveStatisticsAsync(this: *mut core::ffi::c_void, statisticscategories: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScanner", "RetrieveStatisticsAsync", 12usize, |this| ((**(this as *const *const IBarcodeScanner_Vtbl)).RetrieveStatisticsAsync)(this, statisticscategories, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSupportedProfiles(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScanner", "GetSupportedProfiles", 13usize, |this| ((**(this as *const *const IBarcodeScanner_Vtbl)).GetSupportedProfiles)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsProfileSupported(this: *mut core::ffi::c_void, profile: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScanner", "IsProfileSupported", 14usize, |this| ((**(this as *const *const IBarcodeScanner_Vtbl)).IsProfileSupported)(this, profile, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScanner", "StatusUpdated", 15usize, |this| ((**(this as *const *const IBarcodeScanner_Vtbl)).StatusUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScanner", "RemoveStatusUpdated", 16usize, |this| ((**(this as *const *const IBarcodeScanner_Vtbl)).RemoveStatusUpdated)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            Capabilities,
+            ClaimScannerAsync,
+            CheckHealthAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSupportedSymbologiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSupportedSymbologiesAsync: 0,
+            IsSymbologySupportedAsync,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            RetrieveStatisticsAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            RetrieveStatisticsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSupportedProfiles,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSupportedProfiles: 0,
+            IsProfileSupported,
+            StatusUpdated,
+            RemoveStatusUpdated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScanner as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScanner2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VideoDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScanner2", "VideoDeviceId", 6usize, |this| ((**(this as *const *const IBarcodeScanner2_Vtbl)).VideoDeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, VideoDeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScanner2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PowerReportingType(this: *mut core::ffi::c_void, result__: *mut UnifiedPosPowerReportingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerCapabilities", "PowerReportingType", 6usize, |this| ((**(this as *const *const IBarcodeScannerCapabilities_Vtbl)).PowerReportingType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStatisticsReportingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerCapabilities", "IsStatisticsReportingSupported", 7usize, |this| ((**(this as *const *const IBarcodeScannerCapabilities_Vtbl)).IsStatisticsReportingSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStatisticsUpdatingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerCapabilities", "IsStatisticsUpdatingSupported", 8usize, |this| ((**(this as *const *const IBarcodeScannerCapabilities_Vtbl)).IsStatisticsUpdatingSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsImagePreviewSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerCapabilities", "IsImagePreviewSupported", 9usize, |this| ((**(this as *const *const IBarcodeScannerCapabilities_Vtbl)).IsImagePreviewSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PowerReportingType,
+            IsStatisticsReportingSupported,
+            IsStatisticsUpdatingSupported,
+            IsImagePreviewSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerCapabilities1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSoftwareTriggerSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerCapabilities1", "IsSoftwareTriggerSupported", 6usize, |this| ((**(this as *const *const IBarcodeScannerCapabilities1_Vtbl)).IsSoftwareTriggerSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSoftwareTriggerSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerCapabilities1 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerCapabilities2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsVideoPreviewSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerCapabilities2", "IsVideoPreviewSupported", 6usize, |this| ((**(this as *const *const IBarcodeScannerCapabilities2_Vtbl)).IsVideoPreviewSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsVideoPreviewSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerCapabilities2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerDataReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Report(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerDataReceivedEventArgs", "Report", 6usize, |this| ((**(this as *const *const IBarcodeScannerDataReceivedEventArgs_Vtbl)).Report)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Report }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerDataReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerErrorOccurredEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PartialInputData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerErrorOccurredEventArgs", "PartialInputData", 6usize, |this| ((**(this as *const *const IBarcodeScannerErrorOccurredEventArgs_Vtbl)).PartialInputData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRetriable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerErrorOccurredEventArgs", "IsRetriable", 7usize, |this| ((**(this as *const *const IBarcodeScannerErrorOccurredEventArgs_Vtbl)).IsRetriable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerErrorOccurredEventArgs", "ErrorData", 8usize, |this| ((**(this as *const *const IBarcodeScannerErrorOccurredEventArgs_Vtbl)).ErrorData)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PartialInputData, IsRetriable, ErrorData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerErrorOccurredEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IBarcodeScannerImagePreviewReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Preview(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerImagePreviewReceivedEventArgs", "Preview", 6usize, |this| ((**(this as *const *const IBarcodeScannerImagePreviewReceivedEventArgs_Vtbl)).Preview)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Preview,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Preview: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerImagePreviewReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IBarcodeScannerReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScanDataType(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerReport", "ScanDataType", 6usize, |this| ((**(this as *const *const IBarcodeScannerReport_Vtbl)).ScanDataType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ScanData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerReport", "ScanData", 7usize, |this| ((**(this as *const *const IBarcodeScannerReport_Vtbl)).ScanData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ScanDataLabel(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerReport", "ScanDataLabel", 8usize, |this| ((**(this as *const *const IBarcodeScannerReport_Vtbl)).ScanDataLabel)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ScanDataType,
+            #[cfg(feature = "Storage_Streams")]
+            ScanData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ScanData: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ScanDataLabel,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ScanDataLabel: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IBarcodeScannerReportFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, scandatatype: u32, scandata: *mut core::ffi::c_void, scandatalabel: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerReportFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IBarcodeScannerReportFactory_Vtbl)).CreateInstance)(this, scandatatype, scandata, scandatalabel, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateInstance,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateInstance: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerReportFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStatics", "GetDefaultAsync", 6usize, |this| ((**(this as *const *const IBarcodeScannerStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const IBarcodeScannerStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStatics", "GetDeviceSelector", 8usize, |this| ((**(this as *const *const IBarcodeScannerStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefaultAsync, FromIdAsync, GetDeviceSelector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelectorWithConnectionTypes(this: *mut core::ffi::c_void, connectiontypes: PosConnectionTypes, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStatics2", "GetDeviceSelectorWithConnectionTypes", 6usize, |this| ((**(this as *const *const IBarcodeScannerStatics2_Vtbl)).GetDeviceSelectorWithConnectionTypes)(this, connectiontypes, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelectorWithConnectionTypes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeScannerStatusUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut BarcodeScannerStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStatusUpdatedEventArgs", "Status", 6usize, |this| ((**(this as *const *const IBarcodeScannerStatusUpdatedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedStatus(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeScannerStatusUpdatedEventArgs", "ExtendedStatus", 7usize, |this| ((**(this as *const *const IBarcodeScannerStatusUpdatedEventArgs_Vtbl)).ExtendedStatus)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, ExtendedStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeScannerStatusUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeSymbologiesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Unknown(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Unknown", 6usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Unknown)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ean8(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Ean8", 7usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Ean8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ean8Add2(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Ean8Add2", 8usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Ean8Add2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ean8Add5(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Ean8Add5", 9usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Ean8Add5)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Eanv(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Eanv", 10usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Eanv)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EanvAdd2(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "EanvAdd2", 11usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).EanvAdd2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EanvAdd5(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "EanvAdd5", 12usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).EanvAdd5)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ean13(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Ean13", 13usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Ean13)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ean13Add2(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Ean13Add2", 14usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Ean13Add2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ean13Add5(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Ean13Add5", 15usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Ean13Add5)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Isbn(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Isbn", 16usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Isbn)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsbnAdd5(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "IsbnAdd5", 17usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).IsbnAdd5)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ismn(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Ismn", 18usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Ismn)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsmnAdd2(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "IsmnAdd2", 19usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).IsmnAdd2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsmnAdd5(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "IsmnAdd5", 20usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).IsmnAdd5)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Issn(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Issn", 21usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Issn)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IssnAdd2(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "IssnAdd2", 22usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).IssnAdd2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IssnAdd5(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "IssnAdd5", 23usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).IssnAdd5)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ean99(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Ean99", 24usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Ean99)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ean99Add2(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Ean99Add2", 25usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Ean99Add2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ean99Add5(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Ean99Add5", 26usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Ean99Add5)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Upca(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Upca", 27usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Upca)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpcaAdd2(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "UpcaAdd2", 28usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).UpcaAdd2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpcaAdd5(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "UpcaAdd5", 29usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).UpcaAdd5)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Upce(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Upce", 30usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Upce)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpceAdd2(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "UpceAdd2", 31usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).UpceAdd2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpceAdd5(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "UpceAdd5", 32usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).UpceAdd5)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpcCoupon(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "UpcCoupon", 33usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).UpcCoupon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TfStd(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "TfStd", 34usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).TfStd)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TfDis(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "TfDis", 35usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).TfDis)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TfInt(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "TfInt", 36usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).TfInt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TfInd(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "TfInd", 37usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).TfInd)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TfMat(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "TfMat", 38usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).TfMat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TfIata(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "TfIata", 39usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).TfIata)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gs1DatabarType1(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Gs1DatabarType1", 40usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Gs1DatabarType1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gs1DatabarType2(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Gs1DatabarType2", 41usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Gs1DatabarType2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gs1DatabarType3(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Gs1DatabarType3", 42usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Gs1DatabarType3)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Code39(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Code39", 43usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Code39)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Code39Ex(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Code39Ex", 44usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Code39Ex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Trioptic39(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Trioptic39", 45usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Trioptic39)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Code32(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Code32", 46usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Code32)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pzn(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Pzn", 47usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Pzn)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Code93(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Code93", 48usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Code93)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Code93Ex(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Code93Ex", 49usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Code93Ex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Code128(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Code128", 50usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Code128)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gs1128(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Gs1128", 51usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Gs1128)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gs1128Coupon(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Gs1128Coupon", 52usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Gs1128Coupon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UccEan128(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "UccEan128", 53usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).UccEan128)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sisac(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Sisac", 54usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Sisac)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Isbt(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Isbt", 55usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Isbt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Codabar(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Codabar", 56usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Codabar)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Code11(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Code11", 57usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Code11)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Msi(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Msi", 58usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Msi)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Plessey(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Plessey", 59usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Plessey)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Telepen(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Telepen", 60usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Telepen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Code16k(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Code16k", 61usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Code16k)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CodablockA(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "CodablockA", 62usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).CodablockA)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CodablockF(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "CodablockF", 63usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).CodablockF)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Codablock128(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Codablock128", 64usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Codablock128)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Code49(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Code49", 65usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Code49)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Aztec(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Aztec", 66usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Aztec)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "DataCode", 67usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).DataCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataMatrix(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "DataMatrix", 68usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).DataMatrix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HanXin(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "HanXin", 69usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).HanXin)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Maxicode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Maxicode", 70usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Maxicode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MicroPdf417(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "MicroPdf417", 71usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).MicroPdf417)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MicroQr(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "MicroQr", 72usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).MicroQr)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pdf417(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Pdf417", 73usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Pdf417)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Qr(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Qr", 74usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Qr)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MsTag(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "MsTag", 75usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).MsTag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ccab(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Ccab", 76usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Ccab)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ccc(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Ccc", 77usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Ccc)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tlc39(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Tlc39", 78usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Tlc39)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AusPost(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "AusPost", 79usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).AusPost)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanPost(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "CanPost", 80usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).CanPost)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChinaPost(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "ChinaPost", 81usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).ChinaPost)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DutchKix(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "DutchKix", 82usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).DutchKix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InfoMail(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "InfoMail", 83usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).InfoMail)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItalianPost25(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "ItalianPost25", 84usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).ItalianPost25)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItalianPost39(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "ItalianPost39", 85usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).ItalianPost39)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JapanPost(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "JapanPost", 86usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).JapanPost)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KoreanPost(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "KoreanPost", 87usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).KoreanPost)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwedenPost(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "SwedenPost", 88usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).SwedenPost)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UkPost(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "UkPost", 89usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).UkPost)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsIntelligent(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "UsIntelligent", 90usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).UsIntelligent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsIntelligentPkg(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "UsIntelligentPkg", 91usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).UsIntelligentPkg)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsPlanet(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "UsPlanet", 92usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).UsPlanet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsPostNet(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "UsPostNet", 93usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).UsPostNet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Us4StateFics(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Us4StateFics", 94usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Us4StateFics)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OcrA(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "OcrA", 95usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).OcrA)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OcrB(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "OcrB", 96usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).OcrB)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Micr(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "Micr", 97usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).Micr)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedBase(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "ExtendedBase", 98usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).ExtendedBase)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, scandatatype: u32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics", "GetName", 99usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics_Vtbl)).GetName)(this, scandatatype, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Unknown,
+            Ean8,
+            Ean8Add2,
+            Ean8Add5,
+            Eanv,
+            EanvAdd2,
+            EanvAdd5,
+            Ean13,
+            Ean13Add2,
+            Ean13Add5,
+            Isbn,
+            IsbnAdd5,
+            Ismn,
+            IsmnAdd2,
+            IsmnAdd5,
+            Issn,
+            IssnAdd2,
+            IssnAdd5,
+            Ean99,
+            Ean99Add2,
+            Ean99Add5,
+            Upca,
+            UpcaAdd2,
+            UpcaAdd5,
+            Upce,
+            UpceAdd2,
+            UpceAdd5,
+            UpcCoupon,
+            TfStd,
+            TfDis,
+            TfInt,
+            TfInd,
+            TfMat,
+            TfIata,
+            Gs1DatabarType1,
+            Gs1DatabarType2,
+            Gs1DatabarType3,
+            Code39,
+            Code39Ex,
+            Trioptic39,
+            Code32,
+            Pzn,
+            Code93,
+            Code93Ex,
+            Code128,
+            Gs1128,
+            Gs1128Coupon,
+            UccEan128,
+            Sisac,
+            Isbt,
+            Codabar,
+            Code11,
+            Msi,
+            Plessey,
+            Telepen,
+            Code16k,
+            CodablockA,
+            CodablockF,
+            Codablock128,
+            Code49,
+            Aztec,
+            DataCode,
+            DataMatrix,
+            HanXin,
+            Maxicode,
+            MicroPdf417,
+            MicroQr,
+            Pdf417,
+            Qr,
+            MsTag,
+            Ccab,
+            Ccc,
+            Tlc39,
+            AusPost,
+            CanPost,
+            ChinaPost,
+            DutchKix,
+            InfoMail,
+            ItalianPost25,
+            ItalianPost39,
+            JapanPost,
+            KoreanPost,
+            SwedenPost,
+            UkPost,
+            UsIntelligent,
+            UsIntelligentPkg,
+            UsPlanet,
+            UsPostNet,
+            Us4StateFics,
+            OcrA,
+            OcrB,
+            Micr,
+            ExtendedBase,
+            GetName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeSymbologiesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeSymbologiesStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Gs1DWCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologiesStatics2", "Gs1DWCode", 6usize, |this| ((**(this as *const *const IBarcodeSymbologiesStatics2_Vtbl)).Gs1DWCode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Gs1DWCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeSymbologiesStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarcodeSymbologyAttributes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsCheckDigitValidationEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributes", "IsCheckDigitValidationEnabled", 6usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributes_Vtbl)).IsCheckDigitValidationEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCheckDigitValidationEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributes", "SetIsCheckDigitValidationEnabled", 7usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributes_Vtbl)).SetIsCheckDigitValidationEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCheckDigitValidationSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributes", "IsCheckDigitValidationSupported", 8usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributes_Vtbl)).IsCheckDigitValidationSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCheckDigitTransmissionEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributes", "IsCheckDigitTransmissionEnabled", 9usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributes_Vtbl)).IsCheckDigitTransmissionEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCheckDigitTransmissionEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributes", "SetIsCheckDigitTransmissionEnabled", 10usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributes_Vtbl)).SetIsCheckDigitTransmissionEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCheckDigitTransmissionSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributes", "IsCheckDigitTransmissionSupported", 11usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributes_Vtbl)).IsCheckDigitTransmissionSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecodeLength1(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributes", "DecodeLength1", 12usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributes_Vtbl)).DecodeLength1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDecodeLength1(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributes", "SetDecodeLength1", 13usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributes_Vtbl)).SetDecodeLength1)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecodeLength2(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributes", "DecodeLength2", 14usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributes_Vtbl)).DecodeLength2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDecodeLength2(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributes", "SetDecodeLength2", 15usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributes_Vtbl)).SetDecodeLength2)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecodeLengthKind(this: *mut core::ffi::c_void, result__: *mut BarcodeSymbologyDecodeLengthKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributes", "DecodeLengthKind", 16usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributes_Vtbl)).DecodeLengthKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDecodeLengthKind(this: *mut core::ffi::c_void, value: BarcodeSymbologyDecodeLengthKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributes", "SetDecodeLengthKind", 17usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributes_Vtbl)).SetDecodeLengthKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDecodeLengthSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarcodeSymbologyAttributes", "IsDecodeLengthSupported", 18usize, |this| ((**(this as *const *const IBarcodeSymbologyAttributes_Vtbl)).IsDecodeLengthSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsCheckDigitValidationEnabled,
+            SetIsCheckDigitValidationEnabled,
+            IsCheckDigitValidationSupported,
+            IsCheckDigitTransmissionEnabled,
+            SetIsCheckDigitTransmissionEnabled,
+            IsCheckDigitTransmissionSupported,
+            DecodeLength1,
+            SetDecodeLength1,
+            DecodeLength2,
+            SetDecodeLength2,
+            DecodeLengthKind,
+            SetDecodeLengthKind,
+            IsDecodeLengthSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarcodeSymbologyAttributes as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICashDrawer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawer", "DeviceId", 6usize, |this| ((**(this as *const *const ICashDrawer_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Capabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawer", "Capabilities", 7usize, |this| ((**(this as *const *const ICashDrawer_Vtbl)).Capabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawer", "Status", 8usize, |this| ((**(this as *const *const ICashDrawer_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDrawerOpen(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawer", "IsDrawerOpen", 9usize, |this| ((**(this as *const *const ICashDrawer_Vtbl)).IsDrawerOpen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawerEventSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawer", "DrawerEventSource", 10usize, |this| ((**(this as *const *const ICashDrawer_Vtbl)).DrawerEventSource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClaimDrawerAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawer", "ClaimDrawerAsync", 11usize, |this| ((**(this as *const *const ICashDrawer_Vtbl)).ClaimDrawerAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckHealthAsync(this: *mut core::ffi::c_void, level: UnifiedPosHealthCheckLevel, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawer", "CheckHealthAsync", 12usize, |this| ((**(this as *const *const ICashDrawer_Vtbl)).CheckHealthAsync)(this, level, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetStatisticsAsync(this: *mut core::ffi::c_void, statisticscategories: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawer", "GetStatisticsAsync", 13usize, |this| ((**(this as *const *const ICashDrawer_Vtbl)).GetStatisticsAsync)(this, statisticscategories, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawer", "StatusUpdated", 14usize, |this| ((**(this as *const *const ICashDrawer_Vtbl)).StatusUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawer", "RemoveStatusUpdated", 15usize, |this| ((**(this as *const *const ICashDrawer_Vtbl)).RemoveStatusUpdated)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            Capabilities,
+            Status,
+            IsDrawerOpen,
+            DrawerEventSource,
+            ClaimDrawerAsync,
+            CheckHealthAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetStatisticsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetStatisticsAsync: 0,
+            StatusUpdated,
+            RemoveStatusUpdated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICashDrawer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICashDrawerCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PowerReportingType(this: *mut core::ffi::c_void, result__: *mut UnifiedPosPowerReportingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCapabilities", "PowerReportingType", 6usize, |this| ((**(this as *const *const ICashDrawerCapabilities_Vtbl)).PowerReportingType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStatisticsReportingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCapabilities", "IsStatisticsReportingSupported", 7usize, |this| ((**(this as *const *const ICashDrawerCapabilities_Vtbl)).IsStatisticsReportingSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStatisticsUpdatingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCapabilities", "IsStatisticsUpdatingSupported", 8usize, |this| ((**(this as *const *const ICashDrawerCapabilities_Vtbl)).IsStatisticsUpdatingSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStatusReportingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCapabilities", "IsStatusReportingSupported", 9usize, |this| ((**(this as *const *const ICashDrawerCapabilities_Vtbl)).IsStatusReportingSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStatusMultiDrawerDetectSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCapabilities", "IsStatusMultiDrawerDetectSupported", 10usize, |this| ((**(this as *const *const ICashDrawerCapabilities_Vtbl)).IsStatusMultiDrawerDetectSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDrawerOpenSensorAvailable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCapabilities", "IsDrawerOpenSensorAvailable", 11usize, |this| ((**(this as *const *const ICashDrawerCapabilities_Vtbl)).IsDrawerOpenSensorAvailable)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PowerReportingType,
+            IsStatisticsReportingSupported,
+            IsStatisticsUpdatingSupported,
+            IsStatusReportingSupported,
+            IsStatusMultiDrawerDetectSupported,
+            IsDrawerOpenSensorAvailable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICashDrawerCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICashDrawerCloseAlarm_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAlarmTimeout(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCloseAlarm", "SetAlarmTimeout", 6usize, |this| ((**(this as *const *const ICashDrawerCloseAlarm_Vtbl)).SetAlarmTimeout)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlarmTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCloseAlarm", "AlarmTimeout", 7usize, |this| ((**(this as *const *const ICashDrawerCloseAlarm_Vtbl)).AlarmTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBeepFrequency(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCloseAlarm", "SetBeepFrequency", 8usize, |this| ((**(this as *const *const ICashDrawerCloseAlarm_Vtbl)).SetBeepFrequency)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeepFrequency(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCloseAlarm", "BeepFrequency", 9usize, |this| ((**(this as *const *const ICashDrawerCloseAlarm_Vtbl)).BeepFrequency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBeepDuration(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCloseAlarm", "SetBeepDuration", 10usize, |this| ((**(this as *const *const ICashDrawerCloseAlarm_Vtbl)).SetBeepDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeepDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCloseAlarm", "BeepDuration", 11usize, |this| ((**(this as *const *const ICashDrawerCloseAlarm_Vtbl)).BeepDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBeepDelay(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCloseAlarm", "SetBeepDelay", 12usize, |this| ((**(this as *const *const ICashDrawerCloseAlarm_Vtbl)).SetBeepDelay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeepDelay(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCloseAlarm", "BeepDelay", 13usize, |this| ((**(this as *const *const ICashDrawerCloseAlarm_Vtbl)).BeepDelay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlarmTimeoutExpired(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCloseAlarm", "AlarmTimeoutExpired", 14usize, |this| ((**(this as *const *const ICashDrawerCloseAlarm_Vtbl)).AlarmTimeoutExpired)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAlarmTimeoutExpired(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCloseAlarm", "RemoveAlarmTimeoutExpired", 15usize, |this| ((**(this as *const *const ICashDrawerCloseAlarm_Vtbl)).RemoveAlarmTimeoutExpired)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerCloseAlarm", "StartAsync", 16usize, |this| ((**(this as *const *const ICashDrawerCloseAlarm_Vtbl)).StartAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAlarmTimeout,
+            AlarmTimeout,
+            SetBeepFrequency,
+            BeepFrequency,
+            SetBeepDuration,
+            BeepDuration,
+            SetBeepDelay,
+            BeepDelay,
+            AlarmTimeoutExpired,
+            RemoveAlarmTimeoutExpired,
+            StartAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICashDrawerCloseAlarm as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICashDrawerEventSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DrawerClosed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerEventSource", "DrawerClosed", 6usize, |this| ((**(this as *const *const ICashDrawerEventSource_Vtbl)).DrawerClosed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDrawerClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerEventSource", "RemoveDrawerClosed", 7usize, |this| ((**(this as *const *const ICashDrawerEventSource_Vtbl)).RemoveDrawerClosed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawerOpened(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerEventSource", "DrawerOpened", 8usize, |this| ((**(this as *const *const ICashDrawerEventSource_Vtbl)).DrawerOpened)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDrawerOpened(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerEventSource", "RemoveDrawerOpened", 9usize, |this| ((**(this as *const *const ICashDrawerEventSource_Vtbl)).RemoveDrawerOpened)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DrawerClosed,
+            RemoveDrawerClosed,
+            DrawerOpened,
+            RemoveDrawerOpened,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICashDrawerEventSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICashDrawerEventSourceEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CashDrawer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerEventSourceEventArgs", "CashDrawer", 6usize, |this| ((**(this as *const *const ICashDrawerEventSourceEventArgs_Vtbl)).CashDrawer)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CashDrawer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICashDrawerEventSourceEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICashDrawerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerStatics", "GetDefaultAsync", 6usize, |this| ((**(this as *const *const ICashDrawerStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const ICashDrawerStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerStatics", "GetDeviceSelector", 8usize, |this| ((**(this as *const *const ICashDrawerStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefaultAsync, FromIdAsync, GetDeviceSelector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICashDrawerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICashDrawerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelectorWithConnectionTypes(this: *mut core::ffi::c_void, connectiontypes: PosConnectionTypes, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerStatics2", "GetDeviceSelectorWithConnectionTypes", 6usize, |this| ((**(this as *const *const ICashDrawerStatics2_Vtbl)).GetDeviceSelectorWithConnectionTypes)(this, connectiontypes, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelectorWithConnectionTypes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICashDrawerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICashDrawerStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StatusKind(this: *mut core::ffi::c_void, result__: *mut CashDrawerStatusKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerStatus", "StatusKind", 6usize, |this| ((**(this as *const *const ICashDrawerStatus_Vtbl)).StatusKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedStatus(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerStatus", "ExtendedStatus", 7usize, |this| ((**(this as *const *const ICashDrawerStatus_Vtbl)).ExtendedStatus)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StatusKind, ExtendedStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICashDrawerStatus as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICashDrawerStatusUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICashDrawerStatusUpdatedEventArgs", "Status", 6usize, |this| ((**(this as *const *const ICashDrawerStatusUpdatedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICashDrawerStatusUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IClaimedBarcodeScanner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "DeviceId", 6usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "IsEnabled", 7usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsDisabledOnDataReceived(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "SetIsDisabledOnDataReceived", 8usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).SetIsDisabledOnDataReceived)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDisabledOnDataReceived(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "IsDisabledOnDataReceived", 9usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).IsDisabledOnDataReceived)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsDecodeDataEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "SetIsDecodeDataEnabled", 10usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).SetIsDecodeDataEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDecodeDataEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "IsDecodeDataEnabled", 11usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).IsDecodeDataEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "EnableAsync", 12usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).EnableAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "DisableAsync", 13usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).DisableAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetainDevice(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "RetainDevice", 14usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).RetainDevice)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetActiveSymbologiesAsync(this: *mut core::ffi::c_void, symbologies: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "SetActiveSymbologiesAsync", 15usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).SetActiveSymbologiesAsync)(this, symbologies, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ResetStatisticsAsync(this: *mut core::ffi::c_void, statisticscategories: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "ResetStatisticsAsync", 16usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).ResetStatisticsAsync)(this, statisticscategories, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UpdateStatisticsAsync(this: *mut core::ffi::c_void, statistics: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "UpdateStatisticsAsync", 17usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).UpdateStatisticsAsync)(this, statistics, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActiveProfileAsync(this: *mut core::ffi::c_void, profile: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "SetActiveProfileAsync", 18usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).SetActiveProfileAsync)(this, profile, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "DataReceived", 19usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).DataReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDataReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "RemoveDataReceived", 20usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).RemoveDataReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TriggerPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "TriggerPressed", 21usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).TriggerPressed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTriggerPressed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "RemoveTriggerPressed", 22usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).RemoveTriggerPressed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TriggerReleased(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "TriggerReleased", 23usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).TriggerReleased)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTriggerReleased(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "RemoveTriggerReleased", 24usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).RemoveTriggerReleased)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseDeviceRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "ReleaseDeviceRequested", 25usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).ReleaseDeviceRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReleaseDeviceRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "RemoveReleaseDeviceRequested", 26usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).RemoveReleaseDeviceRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImagePreviewReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "ImagePreviewReceived", 27usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).ImagePreviewReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveImagePreviewReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "RemoveImagePreviewReceived", 28usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).RemoveImagePreviewReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorOccurred(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "ErrorOccurred", 29usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).ErrorOccurred)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveErrorOccurred(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner", "RemoveErrorOccurred", 30usize, |this| ((**(this as *const *const IClaimedBarcodeScanner_Vtbl)).RemoveErrorOccurred)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            IsEnabled,
+            SetIsDisabledOnDataReceived,
+            IsDisabledOnDataReceived,
+            SetIsDecodeDataEnabled,
+            IsDecodeDataEnabled,
+            EnableAsync,
+            DisableAsync,
+            RetainDevice,
+            #[cfg(feature = "Foundation_Collections")]
+            SetActiveSymbologiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetActiveSymbologiesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ResetStatisticsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ResetStatisticsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            UpdateStatisticsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UpdateStatisticsAsync: 0,
+            SetActiveProfileAsync,
+            DataReceived,
+            RemoveDataReceived,
+            TriggerPressed,
+            RemoveTriggerPressed,
+            TriggerReleased,
+            RemoveTriggerReleased,
+            ReleaseDeviceRequested,
+            RemoveReleaseDeviceRequested,
+            ImagePreviewReceived,
+            RemoveImagePreviewReceived,
+            ErrorOccurred,
+            RemoveErrorOccurred,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedBarcodeScanner as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedBarcodeScanner1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartSoftwareTriggerAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner1", "StartSoftwareTriggerAsync", 6usize, |this| ((**(this as *const *const IClaimedBarcodeScanner1_Vtbl)).StartSoftwareTriggerAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopSoftwareTriggerAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner1", "StopSoftwareTriggerAsync", 7usize, |this| ((**(this as *const *const IClaimedBarcodeScanner1_Vtbl)).StopSoftwareTriggerAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartSoftwareTriggerAsync, StopSoftwareTriggerAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedBarcodeScanner1 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedBarcodeScanner2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSymbologyAttributesAsync(this: *mut core::ffi::c_void, barcodesymbology: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner2", "GetSymbologyAttributesAsync", 6usize, |this| ((**(this as *const *const IClaimedBarcodeScanner2_Vtbl)).GetSymbologyAttributesAsync)(this, barcodesymbology, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSymbologyAttributesAsync(this: *mut core::ffi::c_void, barcodesymbology: u32, attributes: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner2", "SetSymbologyAttributesAsync", 7usize, |this| ((**(this as *const *const IClaimedBarcodeScanner2_Vtbl)).SetSymbologyAttributesAsync)(this, barcodesymbology, attributes, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSymbologyAttributesAsync, SetSymbologyAttributesAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedBarcodeScanner2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedBarcodeScanner3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowVideoPreviewAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner3", "ShowVideoPreviewAsync", 6usize, |this| ((**(this as *const *const IClaimedBarcodeScanner3_Vtbl)).ShowVideoPreviewAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HideVideoPreview(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner3", "HideVideoPreview", 7usize, |this| ((**(this as *const *const IClaimedBarcodeScanner3_Vtbl)).HideVideoPreview)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsVideoPreviewShownOnEnable(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner3", "SetIsVideoPreviewShownOnEnable", 8usize, |this| ((**(this as *const *const IClaimedBarcodeScanner3_Vtbl)).SetIsVideoPreviewShownOnEnable)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVideoPreviewShownOnEnable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner3", "IsVideoPreviewShownOnEnable", 9usize, |this| ((**(this as *const *const IClaimedBarcodeScanner3_Vtbl)).IsVideoPreviewShownOnEnable)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ShowVideoPreviewAsync,
+            HideVideoPreview,
+            SetIsVideoPreviewShownOnEnable,
+            IsVideoPreviewShownOnEnable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedBarcodeScanner3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedBarcodeScanner4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner4", "Closed", 6usize, |this| ((**(this as *const *const IClaimedBarcodeScanner4_Vtbl)).Closed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedBarcodeScanner4", "RemoveClosed", 7usize, |this| ((**(this as *const *const IClaimedBarcodeScanner4_Vtbl)).RemoveClosed)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Closed, RemoveClosed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedBarcodeScanner4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedBarcodeScannerClosedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedBarcodeScannerClosedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IClaimedCashDrawer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedCashDrawer", "DeviceId", 6usize, |this| ((**(this as *const *const IClaimedCashDrawer_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedCashDrawer", "IsEnabled", 7usize, |this| ((**(this as *const *const IClaimedCashDrawer_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDrawerOpen(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedCashDrawer", "IsDrawerOpen", 8usize, |this| ((**(this as *const *const IClaimedCashDrawer_Vtbl)).IsDrawerOpen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseAlarm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedCashDrawer", "CloseAlarm", 9usize, |this| ((**(this as *const *const IClaimedCashDrawer_Vtbl)).CloseAlarm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenDrawerAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedCashDrawer", "OpenDrawerAsync", 10usize, |this| ((**(this as *const *const IClaimedCashDrawer_Vtbl)).OpenDrawerAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedCashDrawer", "EnableAsync", 11usize, |this| ((**(this as *const *const IClaimedCashDrawer_Vtbl)).EnableAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedCashDrawer", "DisableAsync", 12usize, |this| ((**(this as *const *const IClaimedCashDrawer_Vtbl)).DisableAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetainDeviceAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedCashDrawer", "RetainDeviceAsync", 13usize, |this| ((**(this as *const *const IClaimedCashDrawer_Vtbl)).RetainDeviceAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ResetStatisticsAsync(this: *mut core::ffi::c_void, statisticscategories: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedCashDrawer", "ResetStatisticsAsync", 14usize, |this| ((**(this as *const *const IClaimedCashDrawer_Vtbl)).ResetStatisticsAsync)(this, statisticscategories, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UpdateStatisticsAsync(this: *mut core::ffi::c_void, statistics: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedCashDrawer", "UpdateStatisticsAsync", 15usize, |this| ((**(this as *const *const IClaimedCashDrawer_Vtbl)).UpdateStatisticsAsync)(this, statistics, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseDeviceRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedCashDrawer", "ReleaseDeviceRequested", 16usize, |this| ((**(this as *const *const IClaimedCashDrawer_Vtbl)).ReleaseDeviceRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReleaseDeviceRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedCashDrawer", "RemoveReleaseDeviceRequested", 17usize, |this| ((**(this as *const *const IClaimedCashDrawer_Vtbl)).RemoveReleaseDeviceRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            IsEnabled,
+            IsDrawerOpen,
+            CloseAlarm,
+            OpenDrawerAsync,
+            EnableAsync,
+            DisableAsync,
+            RetainDeviceAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            ResetStatisticsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ResetStatisticsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            UpdateStatisticsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UpdateStatisticsAsync: 0,
+            ReleaseDeviceRequested,
+            RemoveReleaseDeviceRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedCashDrawer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedCashDrawer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedCashDrawer2", "Closed", 6usize, |this| ((**(this as *const *const IClaimedCashDrawer2_Vtbl)).Closed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedCashDrawer2", "RemoveClosed", 7usize, |this| ((**(this as *const *const IClaimedCashDrawer2_Vtbl)).RemoveClosed)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Closed, RemoveClosed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedCashDrawer2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedCashDrawerClosedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedCashDrawerClosedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedJournalPrinter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateJob(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedJournalPrinter", "CreateJob", 6usize, |this| ((**(this as *const *const IClaimedJournalPrinter_Vtbl)).CreateJob)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateJob }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedJournalPrinter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedLineDisplay_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay", "DeviceId", 6usize, |this| ((**(this as *const *const IClaimedLineDisplay_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Capabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay", "Capabilities", 7usize, |this| ((**(this as *const *const IClaimedLineDisplay_Vtbl)).Capabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhysicalDeviceName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay", "PhysicalDeviceName", 8usize, |this| ((**(this as *const *const IClaimedLineDisplay_Vtbl)).PhysicalDeviceName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhysicalDeviceDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay", "PhysicalDeviceDescription", 9usize, |this| ((**(this as *const *const IClaimedLineDisplay_Vtbl)).PhysicalDeviceDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceControlDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay", "DeviceControlDescription", 10usize, |this| ((**(this as *const *const IClaimedLineDisplay_Vtbl)).DeviceControlDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceControlVersion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay", "DeviceControlVersion", 11usize, |this| ((**(this as *const *const IClaimedLineDisplay_Vtbl)).DeviceControlVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceServiceVersion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay", "DeviceServiceVersion", 12usize, |this| ((**(this as *const *const IClaimedLineDisplay_Vtbl)).DeviceServiceVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultWindow(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay", "DefaultWindow", 13usize, |this| ((**(this as *const *const IClaimedLineDisplay_Vtbl)).DefaultWindow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetainDevice(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay", "RetainDevice", 14usize, |this| ((**(this as *const *const IClaimedLineDisplay_Vtbl)).RetainDevice)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseDeviceRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay", "ReleaseDeviceRequested", 15usize, |this| ((**(this as *const *const IClaimedLineDisplay_Vtbl)).ReleaseDeviceRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReleaseDeviceRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay", "RemoveReleaseDeviceRequested", 16usize, |this| ((**(this as *const *const IClaimedLineDisplay_Vtbl)).RemoveReleaseDeviceRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            Capabilities,
+            PhysicalDeviceName,
+            PhysicalDeviceDescription,
+            DeviceControlDescription,
+            DeviceControlVersion,
+            DeviceServiceVersion,
+            DefaultWindow,
+            RetainDevice,
+            ReleaseDeviceRequested,
+            RemoveReleaseDeviceRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedLineDisplay as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IClaimedLineDisplay2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetStatisticsAsync(this: *mut core::ffi::c_void, statisticscategories: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "GetStatisticsAsync", 6usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).GetStatisticsAsync)(this, statisticscategories, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckHealthAsync(this: *mut core::ffi::c_void, level: UnifiedPosHealthCheckLevel, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "CheckHealthAsync", 7usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).CheckHealthAsync)(this, level, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckPowerStatusAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "CheckPowerStatusAsync", 8usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).CheckPowerStatusAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "StatusUpdated", 9usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).StatusUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "RemoveStatusUpdated", 10usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).RemoveStatusUpdated)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedScreenSizesInCharacters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "SupportedScreenSizesInCharacters", 11usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).SupportedScreenSizesInCharacters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBitmapSizeInPixels(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "MaxBitmapSizeInPixels", 12usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).MaxBitmapSizeInPixels)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedCharacterSets(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "SupportedCharacterSets", 13usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).SupportedCharacterSets)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomGlyphs(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "CustomGlyphs", 14usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).CustomGlyphs)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "GetAttributes", 15usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).GetAttributes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryUpdateAttributesAsync(this: *mut core::ffi::c_void, attributes: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "TryUpdateAttributesAsync", 16usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).TryUpdateAttributesAsync)(this, attributes, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySetDescriptorAsync(this: *mut core::ffi::c_void, descriptor: u32, descriptorstate: LineDisplayDescriptorState, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "TrySetDescriptorAsync", 17usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).TrySetDescriptorAsync)(this, descriptor, descriptorstate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryClearDescriptorsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "TryClearDescriptorsAsync", 18usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).TryClearDescriptorsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryCreateWindowAsync(this: *mut core::ffi::c_void, viewport: super::super::Foundation::Rect, windowsize: super::super::Foundation::Size, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "TryCreateWindowAsync", 19usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).TryCreateWindowAsync)(this, viewport, windowsize, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn TryStoreStorageFileBitmapAsync(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "TryStoreStorageFileBitmapAsync", 20usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).TryStoreStorageFileBitmapAsync)(this, bitmap, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn TryStoreStorageFileBitmapWithAlignmentAsync(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, horizontalalignment: LineDisplayHorizontalAlignment, verticalalignment: LineDisplayVerticalAlignment, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "TryStoreStorageFileBitmapWithAlignmentAsync", 21usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).TryStoreStorageFileBitmapWithAlignmentAsync)(this, bitmap, horizontalalignment, verticalalignment, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn TryStoreStorageFileBitmapWithAlignmentAndWidthAsync(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, horizontalalignment: LineDisplayHorizontalAlignment, verticalalignment: LineDisplayVerticalAlignment, widthinpixels: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay2", "TryStoreStorageFileBitmapWithAlignmentAndWidthAsync", 22usize, |this| ((**(this as *const *const IClaimedLineDisplay2_Vtbl)).TryStoreStorageFileBitmapWithAlignmentAndWidthAsync)(this, bitmap, horizontalalignment, verticalalignment, widthinpixels, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetStatisticsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetStatisticsAsync: 0,
+            CheckHealthAsync,
+            CheckPowerStatusAsync,
+            StatusUpdated,
+            RemoveStatusUpdated,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedScreenSizesInCharacters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedScreenSizesInCharacters: 0,
+            MaxBitmapSizeInPixels,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedCharacterSets,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedCharacterSets: 0,
+            CustomGlyphs,
+            GetAttributes,
+            TryUpdateAttributesAsync,
+            TrySetDescriptorAsync,
+            TryClearDescriptorsAsync,
+            TryCreateWindowAsync,
+            #[cfg(feature = "Storage")]
+            TryStoreStorageFileBitmapAsync,
+            #[cfg(not(feature = "Storage"))]
+            TryStoreStorageFileBitmapAsync: 0,
+            #[cfg(feature = "Storage")]
+            TryStoreStorageFileBitmapWithAlignmentAsync,
+            #[cfg(not(feature = "Storage"))]
+            TryStoreStorageFileBitmapWithAlignmentAsync: 0,
+            #[cfg(feature = "Storage")]
+            TryStoreStorageFileBitmapWithAlignmentAndWidthAsync,
+            #[cfg(not(feature = "Storage"))]
+            TryStoreStorageFileBitmapWithAlignmentAndWidthAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedLineDisplay2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedLineDisplay3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay3", "Closed", 6usize, |this| ((**(this as *const *const IClaimedLineDisplay3_Vtbl)).Closed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplay3", "RemoveClosed", 7usize, |this| ((**(this as *const *const IClaimedLineDisplay3_Vtbl)).RemoveClosed)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Closed, RemoveClosed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedLineDisplay3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedLineDisplayClosedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedLineDisplayClosedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedLineDisplayStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplayStatics", "FromIdAsync", 6usize, |this| ((**(this as *const *const IClaimedLineDisplayStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplayStatics", "GetDeviceSelector", 7usize, |this| ((**(this as *const *const IClaimedLineDisplayStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorWithConnectionTypes(this: *mut core::ffi::c_void, connectiontypes: PosConnectionTypes, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedLineDisplayStatics", "GetDeviceSelectorWithConnectionTypes", 8usize, |this| ((**(this as *const *const IClaimedLineDisplayStatics_Vtbl)).GetDeviceSelectorWithConnectionTypes)(this, connectiontypes, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FromIdAsync,
+            GetDeviceSelector,
+            GetDeviceSelectorWithConnectionTypes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedLineDisplayStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IClaimedMagneticStripeReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "DeviceId", 6usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "IsEnabled", 7usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsDisabledOnDataReceived(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "SetIsDisabledOnDataReceived", 8usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).SetIsDisabledOnDataReceived)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDisabledOnDataReceived(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "IsDisabledOnDataReceived", 9usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).IsDisabledOnDataReceived)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsDecodeDataEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "SetIsDecodeDataEnabled", 10usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).SetIsDecodeDataEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDecodeDataEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "IsDecodeDataEnabled", 11usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).IsDecodeDataEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDeviceAuthenticated(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "IsDeviceAuthenticated", 12usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).IsDeviceAuthenticated)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataEncryptionAlgorithm(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "SetDataEncryptionAlgorithm", 13usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).SetDataEncryptionAlgorithm)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataEncryptionAlgorithm(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "DataEncryptionAlgorithm", 14usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).DataEncryptionAlgorithm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTracksToRead(this: *mut core::ffi::c_void, value: MagneticStripeReaderTrackIds) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "SetTracksToRead", 15usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).SetTracksToRead)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TracksToRead(this: *mut core::ffi::c_void, result__: *mut MagneticStripeReaderTrackIds) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "TracksToRead", 16usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).TracksToRead)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsTransmitSentinelsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "SetIsTransmitSentinelsEnabled", 17usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).SetIsTransmitSentinelsEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTransmitSentinelsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "IsTransmitSentinelsEnabled", 18usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).IsTransmitSentinelsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "EnableAsync", 19usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).EnableAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "DisableAsync", 20usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).DisableAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetainDevice(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "RetainDevice", 21usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).RetainDevice)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetErrorReportingType(this: *mut core::ffi::c_void, value: MagneticStripeReaderErrorReportingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "SetErrorReportingType", 22usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).SetErrorReportingType)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn RetrieveDeviceAuthenticationDataAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "RetrieveDeviceAuthenticationDataAsync", 23usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).RetrieveDeviceAuthenticationDataAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticateDeviceAsync(this: *mut core::ffi::c_void, responseToken_array_size: u32, responsetoken: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "AuthenticateDeviceAsync", 24usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).AuthenticateDeviceAsync)(this, responseToken_array_size, responsetoken, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeAuthenticateDeviceAsync(this: *mut core::ffi::c_void, responseToken_array_size: u32, responsetoken: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "DeAuthenticateDeviceAsync", 25usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).DeAuthenticateDeviceAsync)(this, responseToken_array_size, responsetoken, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateKeyAsync(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>, keyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "UpdateKeyAsync", 26usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).UpdateKeyAsync)(this, key, keyname, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ResetStatisticsAsync(this: *mut core::ffi::c_void, statisticscategories: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "ResetStatisticsAsync", 27usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).ResetStatisticsAsync)(this, statisticscategories, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UpdateStatisticsAsync(this: *mut core::ffi::c_void, statistics: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "UpdateStatisticsAsync", 28usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).UpdateStatisticsAsync)(this, statistics, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BankCardDataReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "BankCardDataReceived", 29usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).BankCardDataReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBankCardDataReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "RemoveBankCardDataReceived", 30usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).RemoveBankCardDataReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AamvaCardDataReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "AamvaCardDataReceived", 31usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).AamvaCardDataReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAamvaCardDataReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "RemoveAamvaCardDataReceived", 32usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).RemoveAamvaCardDataReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn VendorSpecificDataReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "VendorSpecificDataReceived", 33usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).VendorSpecificDataReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVendorSpecificDataReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "RemoveVendorSpecificDataReceived", 34usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).RemoveVendorSpecificDataReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseDeviceRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "ReleaseDeviceRequested", 35usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).ReleaseDeviceRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReleaseDeviceRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "RemoveReleaseDeviceRequested", 36usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).RemoveReleaseDeviceRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorOccurred(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "ErrorOccurred", 37usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).ErrorOccurred)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveErrorOccurred(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader", "RemoveErrorOccurred", 38usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader_Vtbl)).RemoveErrorOccurred)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            IsEnabled,
+            SetIsDisabledOnDataReceived,
+            IsDisabledOnDataReceived,
+            SetIsDecodeDataEnabled,
+            IsDecodeDataEnabled,
+            IsDeviceAuthenticated,
+            SetDataEncryptionAlgorithm,
+            DataEncryptionAlgorithm,
+            SetTracksToRead,
+            TracksToRead,
+            SetIsTransmitSentinelsEnabled,
+            IsTransmitSentinelsEnabled,
+            EnableAsync,
+            DisableAsync,
+            RetainDevice,
+            SetErrorReportingType,
+            #[cfg(feature = "Storage_Streams")]
+            RetrieveDeviceAuthenticationDataAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            RetrieveDeviceAuthenticationDataAsync: 0,
+            AuthenticateDeviceAsync,
+            DeAuthenticateDeviceAsync,
+            UpdateKeyAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            ResetStatisticsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ResetStatisticsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            UpdateStatisticsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UpdateStatisticsAsync: 0,
+            BankCardDataReceived,
+            RemoveBankCardDataReceived,
+            AamvaCardDataReceived,
+            RemoveAamvaCardDataReceived,
+            VendorSpecificDataReceived,
+            RemoveVendorSpecificDataReceived,
+            ReleaseDeviceRequested,
+            RemoveReleaseDeviceRequested,
+            ErrorOccurred,
+            RemoveErrorOccurred,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedMagneticStripeReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedMagneticStripeReader2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader2", "Closed", 6usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader2_Vtbl)).Closed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedMagneticStripeReader2", "RemoveClosed", 7usize, |this| ((**(this as *const *const IClaimedMagneticStripeReader2_Vtbl)).RemoveClosed)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Closed, RemoveClosed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedMagneticStripeReader2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedMagneticStripeReaderClosedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedMagneticStripeReaderClosedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IClaimedPosPrinter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "DeviceId", 6usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "IsEnabled", 7usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCharacterSet(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "SetCharacterSet", 8usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).SetCharacterSet)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CharacterSet(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "CharacterSet", 9usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).CharacterSet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCoverOpen(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "IsCoverOpen", 10usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).IsCoverOpen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCharacterSetMappingEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "SetIsCharacterSetMappingEnabled", 11usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).SetIsCharacterSetMappingEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCharacterSetMappingEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "IsCharacterSetMappingEnabled", 12usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).IsCharacterSetMappingEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMapMode(this: *mut core::ffi::c_void, value: PosPrinterMapMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "SetMapMode", 13usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).SetMapMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MapMode(this: *mut core::ffi::c_void, result__: *mut PosPrinterMapMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "MapMode", 14usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).MapMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Receipt(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "Receipt", 15usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).Receipt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Slip(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "Slip", 16usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).Slip)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Journal(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "Journal", 17usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).Journal)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "EnableAsync", 18usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).EnableAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "DisableAsync", 19usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).DisableAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetainDeviceAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "RetainDeviceAsync", 20usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).RetainDeviceAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ResetStatisticsAsync(this: *mut core::ffi::c_void, statisticscategories: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "ResetStatisticsAsync", 21usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).ResetStatisticsAsync)(this, statisticscategories, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UpdateStatisticsAsync(this: *mut core::ffi::c_void, statistics: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "UpdateStatisticsAsync", 22usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).UpdateStatisticsAsync)(this, statistics, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseDeviceRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "ReleaseDeviceRequested", 23usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).ReleaseDeviceRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReleaseDeviceRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter", "RemoveReleaseDeviceRequested", 24usize, |this| ((**(this as *const *const IClaimedPosPrinter_Vtbl)).RemoveReleaseDeviceRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            IsEnabled,
+            SetCharacterSet,
+            CharacterSet,
+            IsCoverOpen,
+            SetIsCharacterSetMappingEnabled,
+            IsCharacterSetMappingEnabled,
+            SetMapMode,
+            MapMode,
+            Receipt,
+            Slip,
+            Journal,
+            EnableAsync,
+            DisableAsync,
+            RetainDeviceAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            ResetStatisticsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ResetStatisticsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            UpdateStatisticsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UpdateStatisticsAsync: 0,
+            ReleaseDeviceRequested,
+            RemoveReleaseDeviceRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedPosPrinter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedPosPrinter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter2", "Closed", 6usize, |this| ((**(this as *const *const IClaimedPosPrinter2_Vtbl)).Closed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedPosPrinter2", "RemoveClosed", 7usize, |this| ((**(this as *const *const IClaimedPosPrinter2_Vtbl)).RemoveClosed)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Closed, RemoveClosed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedPosPrinter2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedPosPrinterClosedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedPosPrinterClosedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedReceiptPrinter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SidewaysMaxLines(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedReceiptPrinter", "SidewaysMaxLines", 6usize, |this| ((**(this as *const *const IClaimedReceiptPrinter_Vtbl)).SidewaysMaxLines)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SidewaysMaxChars(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedReceiptPrinter", "SidewaysMaxChars", 7usize, |this| ((**(this as *const *const IClaimedReceiptPrinter_Vtbl)).SidewaysMaxChars)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LinesToPaperCut(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedReceiptPrinter", "LinesToPaperCut", 8usize, |this| ((**(this as *const *const IClaimedReceiptPrinter_Vtbl)).LinesToPaperCut)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedReceiptPrinter", "PageSize", 9usize, |this| ((**(this as *const *const IClaimedReceiptPrinter_Vtbl)).PageSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintArea(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedReceiptPrinter", "PrintArea", 10usize, |this| ((**(this as *const *const IClaimedReceiptPrinter_Vtbl)).PrintArea)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateJob(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedReceiptPrinter", "CreateJob", 11usize, |this| ((**(this as *const *const IClaimedReceiptPrinter_Vtbl)).CreateJob)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SidewaysMaxLines,
+            SidewaysMaxChars,
+            LinesToPaperCut,
+            PageSize,
+            PrintArea,
+            CreateJob,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedReceiptPrinter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClaimedSlipPrinter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SidewaysMaxLines(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedSlipPrinter", "SidewaysMaxLines", 6usize, |this| ((**(this as *const *const IClaimedSlipPrinter_Vtbl)).SidewaysMaxLines)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SidewaysMaxChars(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedSlipPrinter", "SidewaysMaxChars", 7usize, |this| ((**(this as *const *const IClaimedSlipPrinter_Vtbl)).SidewaysMaxChars)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxLines(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedSlipPrinter", "MaxLines", 8usize, |this| ((**(this as *const *const IClaimedSlipPrinter_Vtbl)).MaxLines)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LinesNearEndToEnd(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedSlipPrinter", "LinesNearEndToEnd", 9usize, |this| ((**(this as *const *const IClaimedSlipPrinter_Vtbl)).LinesNearEndToEnd)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintSide(this: *mut core::ffi::c_void, result__: *mut PosPrinterPrintSide) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedSlipPrinter", "PrintSide", 10usize, |this| ((**(this as *const *const IClaimedSlipPrinter_Vtbl)).PrintSide)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedSlipPrinter", "PageSize", 11usize, |this| ((**(this as *const *const IClaimedSlipPrinter_Vtbl)).PageSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintArea(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedSlipPrinter", "PrintArea", 12usize, |this| ((**(this as *const *const IClaimedSlipPrinter_Vtbl)).PrintArea)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenJaws(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedSlipPrinter", "OpenJaws", 13usize, |this| ((**(this as *const *const IClaimedSlipPrinter_Vtbl)).OpenJaws)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseJaws(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedSlipPrinter", "CloseJaws", 14usize, |this| ((**(this as *const *const IClaimedSlipPrinter_Vtbl)).CloseJaws)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertSlipAsync(this: *mut core::ffi::c_void, timeout: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedSlipPrinter", "InsertSlipAsync", 15usize, |this| ((**(this as *const *const IClaimedSlipPrinter_Vtbl)).InsertSlipAsync)(this, timeout, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSlipAsync(this: *mut core::ffi::c_void, timeout: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedSlipPrinter", "RemoveSlipAsync", 16usize, |this| ((**(this as *const *const IClaimedSlipPrinter_Vtbl)).RemoveSlipAsync)(this, timeout, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangePrintSide(this: *mut core::ffi::c_void, printside: PosPrinterPrintSide) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedSlipPrinter", "ChangePrintSide", 17usize, |this| ((**(this as *const *const IClaimedSlipPrinter_Vtbl)).ChangePrintSide)(this, printside), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateJob(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClaimedSlipPrinter", "CreateJob", 18usize, |this| ((**(this as *const *const IClaimedSlipPrinter_Vtbl)).CreateJob)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SidewaysMaxLines,
+            SidewaysMaxChars,
+            MaxLines,
+            LinesNearEndToEnd,
+            PrintSide,
+            PageSize,
+            PrintArea,
+            OpenJaws,
+            CloseJaws,
+            InsertSlipAsync,
+            RemoveSlipAsync,
+            ChangePrintSide,
+            CreateJob,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClaimedSlipPrinter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICommonClaimedPosPrinterStation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCharactersPerLine(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "SetCharactersPerLine", 6usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).SetCharactersPerLine)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CharactersPerLine(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "CharactersPerLine", 7usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).CharactersPerLine)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLineHeight(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "SetLineHeight", 8usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).SetLineHeight)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineHeight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "LineHeight", 9usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).LineHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLineSpacing(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "SetLineSpacing", 10usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).SetLineSpacing)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineSpacing(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "LineSpacing", 11usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).LineSpacing)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineWidth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "LineWidth", 12usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).LineWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsLetterQuality(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "SetIsLetterQuality", 13usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).SetIsLetterQuality)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLetterQuality(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "IsLetterQuality", 14usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).IsLetterQuality)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPaperNearEnd(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "IsPaperNearEnd", 15usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).IsPaperNearEnd)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorCartridge(this: *mut core::ffi::c_void, value: PosPrinterColorCartridge) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "SetColorCartridge", 16usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).SetColorCartridge)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorCartridge(this: *mut core::ffi::c_void, result__: *mut PosPrinterColorCartridge) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "ColorCartridge", 17usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).ColorCartridge)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCoverOpen(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "IsCoverOpen", 18usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).IsCoverOpen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCartridgeRemoved(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "IsCartridgeRemoved", 19usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).IsCartridgeRemoved)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCartridgeEmpty(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "IsCartridgeEmpty", 20usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).IsCartridgeEmpty)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHeadCleaning(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "IsHeadCleaning", 21usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).IsHeadCleaning)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPaperEmpty(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "IsPaperEmpty", 22usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).IsPaperEmpty)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReadyToPrint(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "IsReadyToPrint", 23usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).IsReadyToPrint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateData(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonClaimedPosPrinterStation", "ValidateData", 24usize, |this| ((**(this as *const *const ICommonClaimedPosPrinterStation_Vtbl)).ValidateData)(this, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetCharactersPerLine,
+            CharactersPerLine,
+            SetLineHeight,
+            LineHeight,
+            SetLineSpacing,
+            LineSpacing,
+            LineWidth,
+            SetIsLetterQuality,
+            IsLetterQuality,
+            IsPaperNearEnd,
+            SetColorCartridge,
+            ColorCartridge,
+            IsCoverOpen,
+            IsCartridgeRemoved,
+            IsCartridgeEmpty,
+            IsHeadCleaning,
+            IsPaperEmpty,
+            IsReadyToPrint,
+            ValidateData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommonClaimedPosPrinterStation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICommonPosPrintStationCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPrinterPresent(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonPosPrintStationCapabilities", "IsPrinterPresent", 6usize, |this| ((**(this as *const *const ICommonPosPrintStationCapabilities_Vtbl)).IsPrinterPresent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDualColorSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonPosPrintStationCapabilities", "IsDualColorSupported", 7usize, |this| ((**(this as *const *const ICommonPosPrintStationCapabilities_Vtbl)).IsDualColorSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorCartridgeCapabilities(this: *mut core::ffi::c_void, result__: *mut PosPrinterColorCapabilities) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonPosPrintStationCapabilities", "ColorCartridgeCapabilities", 8usize, |this| ((**(this as *const *const ICommonPosPrintStationCapabilities_Vtbl)).ColorCartridgeCapabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CartridgeSensors(this: *mut core::ffi::c_void, result__: *mut PosPrinterCartridgeSensors) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonPosPrintStationCapabilities", "CartridgeSensors", 9usize, |this| ((**(this as *const *const ICommonPosPrintStationCapabilities_Vtbl)).CartridgeSensors)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBoldSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonPosPrintStationCapabilities", "IsBoldSupported", 10usize, |this| ((**(this as *const *const ICommonPosPrintStationCapabilities_Vtbl)).IsBoldSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsItalicSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonPosPrintStationCapabilities", "IsItalicSupported", 11usize, |this| ((**(this as *const *const ICommonPosPrintStationCapabilities_Vtbl)).IsItalicSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUnderlineSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonPosPrintStationCapabilities", "IsUnderlineSupported", 12usize, |this| ((**(this as *const *const ICommonPosPrintStationCapabilities_Vtbl)).IsUnderlineSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDoubleHighPrintSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonPosPrintStationCapabilities", "IsDoubleHighPrintSupported", 13usize, |this| ((**(this as *const *const ICommonPosPrintStationCapabilities_Vtbl)).IsDoubleHighPrintSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDoubleWidePrintSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonPosPrintStationCapabilities", "IsDoubleWidePrintSupported", 14usize, |this| ((**(this as *const *const ICommonPosPrintStationCapabilities_Vtbl)).IsDoubleWidePrintSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDoubleHighDoubleWidePrintSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonPosPrintStationCapabilities", "IsDoubleHighDoubleWidePrintSupported", 15usize, |this| ((**(this as *const *const ICommonPosPrintStationCapabilities_Vtbl)).IsDoubleHighDoubleWidePrintSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPaperEmptySensorSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonPosPrintStationCapabilities", "IsPaperEmptySensorSupported", 16usize, |this| ((**(this as *const *const ICommonPosPrintStationCapabilities_Vtbl)).IsPaperEmptySensorSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPaperNearEndSensorSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonPosPrintStationCapabilities", "IsPaperNearEndSensorSupported", 17usize, |this| ((**(this as *const *const ICommonPosPrintStationCapabilities_Vtbl)).IsPaperNearEndSensorSupported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedCharactersPerLine(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonPosPrintStationCapabilities", "SupportedCharactersPerLine", 18usize, |this| ((**(this as *const *const ICommonPosPrintStationCapabilities_Vtbl)).SupportedCharactersPerLine)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsPrinterPresent,
+            IsDualColorSupported,
+            ColorCartridgeCapabilities,
+            CartridgeSensors,
+            IsBoldSupported,
+            IsItalicSupported,
+            IsUnderlineSupported,
+            IsDoubleHighPrintSupported,
+            IsDoubleWidePrintSupported,
+            IsDoubleHighDoubleWidePrintSupported,
+            IsPaperEmptySensorSupported,
+            IsPaperNearEndSensorSupported,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedCharactersPerLine,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedCharactersPerLine: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommonPosPrintStationCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICommonReceiptSlipCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsBarcodeSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonReceiptSlipCapabilities", "IsBarcodeSupported", 6usize, |this| ((**(this as *const *const ICommonReceiptSlipCapabilities_Vtbl)).IsBarcodeSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBitmapSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonReceiptSlipCapabilities", "IsBitmapSupported", 7usize, |this| ((**(this as *const *const ICommonReceiptSlipCapabilities_Vtbl)).IsBitmapSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLeft90RotationSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonReceiptSlipCapabilities", "IsLeft90RotationSupported", 8usize, |this| ((**(this as *const *const ICommonReceiptSlipCapabilities_Vtbl)).IsLeft90RotationSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRight90RotationSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonReceiptSlipCapabilities", "IsRight90RotationSupported", 9usize, |this| ((**(this as *const *const ICommonReceiptSlipCapabilities_Vtbl)).IsRight90RotationSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Is180RotationSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonReceiptSlipCapabilities", "Is180RotationSupported", 10usize, |this| ((**(this as *const *const ICommonReceiptSlipCapabilities_Vtbl)).Is180RotationSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPrintAreaSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonReceiptSlipCapabilities", "IsPrintAreaSupported", 11usize, |this| ((**(this as *const *const ICommonReceiptSlipCapabilities_Vtbl)).IsPrintAreaSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RuledLineCapabilities(this: *mut core::ffi::c_void, result__: *mut PosPrinterRuledLineCapabilities) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonReceiptSlipCapabilities", "RuledLineCapabilities", 12usize, |this| ((**(this as *const *const ICommonReceiptSlipCapabilities_Vtbl)).RuledLineCapabilities)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedBarcodeRotations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonReceiptSlipCapabilities", "SupportedBarcodeRotations", 13usize, |this| ((**(this as *const *const ICommonReceiptSlipCapabilities_Vtbl)).SupportedBarcodeRotations)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedBitmapRotations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonReceiptSlipCapabilities", "SupportedBitmapRotations", 14usize, |this| ((**(this as *const *const ICommonReceiptSlipCapabilities_Vtbl)).SupportedBitmapRotations)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsBarcodeSupported,
+            IsBitmapSupported,
+            IsLeft90RotationSupported,
+            IsRight90RotationSupported,
+            Is180RotationSupported,
+            IsPrintAreaSupported,
+            RuledLineCapabilities,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedBarcodeRotations,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedBarcodeRotations: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedBitmapRotations,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedBitmapRotations: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommonReceiptSlipCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJournalPrintJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Print(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::HSTRING>, printoptions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJournalPrintJob", "Print", 6usize, |this| ((**(this as *const *const IJournalPrintJob_Vtbl)).Print)(this, data, printoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedPaperByLine(this: *mut core::ffi::c_void, linecount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJournalPrintJob", "FeedPaperByLine", 7usize, |this| ((**(this as *const *const IJournalPrintJob_Vtbl)).FeedPaperByLine)(this, linecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedPaperByMapModeUnit(this: *mut core::ffi::c_void, distance: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJournalPrintJob", "FeedPaperByMapModeUnit", 8usize, |this| ((**(this as *const *const IJournalPrintJob_Vtbl)).FeedPaperByMapModeUnit)(this, distance), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Print, FeedPaperByLine, FeedPaperByMapModeUnit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJournalPrintJob as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJournalPrinterCapabilities_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJournalPrinterCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJournalPrinterCapabilities2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsReverseVideoSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJournalPrinterCapabilities2", "IsReverseVideoSupported", 6usize, |this| ((**(this as *const *const IJournalPrinterCapabilities2_Vtbl)).IsReverseVideoSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStrikethroughSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJournalPrinterCapabilities2", "IsStrikethroughSupported", 7usize, |this| ((**(this as *const *const IJournalPrinterCapabilities2_Vtbl)).IsStrikethroughSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSuperscriptSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJournalPrinterCapabilities2", "IsSuperscriptSupported", 8usize, |this| ((**(this as *const *const IJournalPrinterCapabilities2_Vtbl)).IsSuperscriptSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSubscriptSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJournalPrinterCapabilities2", "IsSubscriptSupported", 9usize, |this| ((**(this as *const *const IJournalPrinterCapabilities2_Vtbl)).IsSubscriptSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReversePaperFeedByLineSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJournalPrinterCapabilities2", "IsReversePaperFeedByLineSupported", 10usize, |this| ((**(this as *const *const IJournalPrinterCapabilities2_Vtbl)).IsReversePaperFeedByLineSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReversePaperFeedByMapModeUnitSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJournalPrinterCapabilities2", "IsReversePaperFeedByMapModeUnitSupported", 11usize, |this| ((**(this as *const *const IJournalPrinterCapabilities2_Vtbl)).IsReversePaperFeedByMapModeUnitSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsReverseVideoSupported,
+            IsStrikethroughSupported,
+            IsSuperscriptSupported,
+            IsSubscriptSupported,
+            IsReversePaperFeedByLineSupported,
+            IsReversePaperFeedByMapModeUnitSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJournalPrinterCapabilities2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILineDisplay_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplay", "DeviceId", 6usize, |this| ((**(this as *const *const ILineDisplay_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Capabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplay", "Capabilities", 7usize, |this| ((**(this as *const *const ILineDisplay_Vtbl)).Capabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhysicalDeviceName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplay", "PhysicalDeviceName", 8usize, |this| ((**(this as *const *const ILineDisplay_Vtbl)).PhysicalDeviceName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhysicalDeviceDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplay", "PhysicalDeviceDescription", 9usize, |this| ((**(this as *const *const ILineDisplay_Vtbl)).PhysicalDeviceDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceControlDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplay", "DeviceControlDescription", 10usize, |this| ((**(this as *const *const ILineDisplay_Vtbl)).DeviceControlDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceControlVersion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplay", "DeviceControlVersion", 11usize, |this| ((**(this as *const *const ILineDisplay_Vtbl)).DeviceControlVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceServiceVersion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplay", "DeviceServiceVersion", 12usize, |this| ((**(this as *const *const ILineDisplay_Vtbl)).DeviceServiceVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClaimAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplay", "ClaimAsync", 13usize, |this| ((**(this as *const *const ILineDisplay_Vtbl)).ClaimAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            Capabilities,
+            PhysicalDeviceName,
+            PhysicalDeviceDescription,
+            DeviceControlDescription,
+            DeviceControlVersion,
+            DeviceServiceVersion,
+            ClaimAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILineDisplay as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILineDisplay2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CheckPowerStatusAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplay2", "CheckPowerStatusAsync", 6usize, |this| ((**(this as *const *const ILineDisplay2_Vtbl)).CheckPowerStatusAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CheckPowerStatusAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILineDisplay2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILineDisplayAttributes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPowerNotifyEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayAttributes", "IsPowerNotifyEnabled", 6usize, |this| ((**(this as *const *const ILineDisplayAttributes_Vtbl)).IsPowerNotifyEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsPowerNotifyEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayAttributes", "SetIsPowerNotifyEnabled", 7usize, |this| ((**(this as *const *const ILineDisplayAttributes_Vtbl)).SetIsPowerNotifyEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Brightness(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayAttributes", "Brightness", 8usize, |this| ((**(this as *const *const ILineDisplayAttributes_Vtbl)).Brightness)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBrightness(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayAttributes", "SetBrightness", 9usize, |this| ((**(this as *const *const ILineDisplayAttributes_Vtbl)).SetBrightness)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlinkRate(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayAttributes", "BlinkRate", 10usize, |this| ((**(this as *const *const ILineDisplayAttributes_Vtbl)).BlinkRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlinkRate(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayAttributes", "SetBlinkRate", 11usize, |this| ((**(this as *const *const ILineDisplayAttributes_Vtbl)).SetBlinkRate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScreenSizeInCharacters(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayAttributes", "ScreenSizeInCharacters", 12usize, |this| ((**(this as *const *const ILineDisplayAttributes_Vtbl)).ScreenSizeInCharacters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScreenSizeInCharacters(this: *mut core::ffi::c_void, value: super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayAttributes", "SetScreenSizeInCharacters", 13usize, |this| ((**(this as *const *const ILineDisplayAttributes_Vtbl)).SetScreenSizeInCharacters)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CharacterSet(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayAttributes", "CharacterSet", 14usize, |this| ((**(this as *const *const ILineDisplayAttributes_Vtbl)).CharacterSet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCharacterSet(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayAttributes", "SetCharacterSet", 15usize, |this| ((**(this as *const *const ILineDisplayAttributes_Vtbl)).SetCharacterSet)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCharacterSetMappingEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayAttributes", "IsCharacterSetMappingEnabled", 16usize, |this| ((**(this as *const *const ILineDisplayAttributes_Vtbl)).IsCharacterSetMappingEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCharacterSetMappingEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayAttributes", "SetIsCharacterSetMappingEnabled", 17usize, |this| ((**(this as *const *const ILineDisplayAttributes_Vtbl)).SetIsCharacterSetMappingEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentWindow(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayAttributes", "CurrentWindow", 18usize, |this| ((**(this as *const *const ILineDisplayAttributes_Vtbl)).CurrentWindow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentWindow(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayAttributes", "SetCurrentWindow", 19usize, |this| ((**(this as *const *const ILineDisplayAttributes_Vtbl)).SetCurrentWindow)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsPowerNotifyEnabled,
+            SetIsPowerNotifyEnabled,
+            Brightness,
+            SetBrightness,
+            BlinkRate,
+            SetBlinkRate,
+            ScreenSizeInCharacters,
+            SetScreenSizeInCharacters,
+            CharacterSet,
+            SetCharacterSet,
+            IsCharacterSetMappingEnabled,
+            SetIsCharacterSetMappingEnabled,
+            CurrentWindow,
+            SetCurrentWindow,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILineDisplayAttributes as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILineDisplayCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsStatisticsReportingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "IsStatisticsReportingSupported", 6usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).IsStatisticsReportingSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStatisticsUpdatingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "IsStatisticsUpdatingSupported", 7usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).IsStatisticsUpdatingSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerReportingType(this: *mut core::ffi::c_void, result__: *mut UnifiedPosPowerReportingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "PowerReportingType", 8usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).PowerReportingType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanChangeScreenSize(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "CanChangeScreenSize", 9usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).CanChangeScreenSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanDisplayBitmaps(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "CanDisplayBitmaps", 10usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).CanDisplayBitmaps)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanReadCharacterAtCursor(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "CanReadCharacterAtCursor", 11usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).CanReadCharacterAtCursor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanMapCharacterSets(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "CanMapCharacterSets", 12usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).CanMapCharacterSets)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanDisplayCustomGlyphs(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "CanDisplayCustomGlyphs", 13usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).CanDisplayCustomGlyphs)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanReverse(this: *mut core::ffi::c_void, result__: *mut LineDisplayTextAttributeGranularity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "CanReverse", 14usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).CanReverse)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanBlink(this: *mut core::ffi::c_void, result__: *mut LineDisplayTextAttributeGranularity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "CanBlink", 15usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).CanBlink)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanChangeBlinkRate(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "CanChangeBlinkRate", 16usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).CanChangeBlinkRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBrightnessSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "IsBrightnessSupported", 17usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).IsBrightnessSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCursorSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "IsCursorSupported", 18usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).IsCursorSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHorizontalMarqueeSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "IsHorizontalMarqueeSupported", 19usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).IsHorizontalMarqueeSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVerticalMarqueeSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "IsVerticalMarqueeSupported", 20usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).IsVerticalMarqueeSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInterCharacterWaitSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "IsInterCharacterWaitSupported", 21usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).IsInterCharacterWaitSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedDescriptors(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "SupportedDescriptors", 22usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).SupportedDescriptors)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedWindows(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCapabilities", "SupportedWindows", 23usize, |this| ((**(this as *const *const ILineDisplayCapabilities_Vtbl)).SupportedWindows)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsStatisticsReportingSupported,
+            IsStatisticsUpdatingSupported,
+            PowerReportingType,
+            CanChangeScreenSize,
+            CanDisplayBitmaps,
+            CanReadCharacterAtCursor,
+            CanMapCharacterSets,
+            CanDisplayCustomGlyphs,
+            CanReverse,
+            CanBlink,
+            CanChangeBlinkRate,
+            IsBrightnessSupported,
+            IsCursorSupported,
+            IsHorizontalMarqueeSupported,
+            IsVerticalMarqueeSupported,
+            IsInterCharacterWaitSupported,
+            SupportedDescriptors,
+            SupportedWindows,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILineDisplayCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILineDisplayCursor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanCustomize(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursor", "CanCustomize", 6usize, |this| ((**(this as *const *const ILineDisplayCursor_Vtbl)).CanCustomize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBlinkSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursor", "IsBlinkSupported", 7usize, |this| ((**(this as *const *const ILineDisplayCursor_Vtbl)).IsBlinkSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBlockSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursor", "IsBlockSupported", 8usize, |this| ((**(this as *const *const ILineDisplayCursor_Vtbl)).IsBlockSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHalfBlockSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursor", "IsHalfBlockSupported", 9usize, |this| ((**(this as *const *const ILineDisplayCursor_Vtbl)).IsHalfBlockSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUnderlineSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursor", "IsUnderlineSupported", 10usize, |this| ((**(this as *const *const ILineDisplayCursor_Vtbl)).IsUnderlineSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReverseSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursor", "IsReverseSupported", 11usize, |this| ((**(this as *const *const ILineDisplayCursor_Vtbl)).IsReverseSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOtherSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursor", "IsOtherSupported", 12usize, |this| ((**(this as *const *const ILineDisplayCursor_Vtbl)).IsOtherSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursor", "GetAttributes", 13usize, |this| ((**(this as *const *const ILineDisplayCursor_Vtbl)).GetAttributes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryUpdateAttributesAsync(this: *mut core::ffi::c_void, attributes: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursor", "TryUpdateAttributesAsync", 14usize, |this| ((**(this as *const *const ILineDisplayCursor_Vtbl)).TryUpdateAttributesAsync)(this, attributes, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanCustomize,
+            IsBlinkSupported,
+            IsBlockSupported,
+            IsHalfBlockSupported,
+            IsUnderlineSupported,
+            IsReverseSupported,
+            IsOtherSupported,
+            GetAttributes,
+            TryUpdateAttributesAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILineDisplayCursor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILineDisplayCursorAttributes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsBlinkEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursorAttributes", "IsBlinkEnabled", 6usize, |this| ((**(this as *const *const ILineDisplayCursorAttributes_Vtbl)).IsBlinkEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsBlinkEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursorAttributes", "SetIsBlinkEnabled", 7usize, |this| ((**(this as *const *const ILineDisplayCursorAttributes_Vtbl)).SetIsBlinkEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CursorType(this: *mut core::ffi::c_void, result__: *mut LineDisplayCursorType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursorAttributes", "CursorType", 8usize, |this| ((**(this as *const *const ILineDisplayCursorAttributes_Vtbl)).CursorType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCursorType(this: *mut core::ffi::c_void, value: LineDisplayCursorType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursorAttributes", "SetCursorType", 9usize, |this| ((**(this as *const *const ILineDisplayCursorAttributes_Vtbl)).SetCursorType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAutoAdvanceEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursorAttributes", "IsAutoAdvanceEnabled", 10usize, |this| ((**(this as *const *const ILineDisplayCursorAttributes_Vtbl)).IsAutoAdvanceEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsAutoAdvanceEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursorAttributes", "SetIsAutoAdvanceEnabled", 11usize, |this| ((**(this as *const *const ILineDisplayCursorAttributes_Vtbl)).SetIsAutoAdvanceEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursorAttributes", "Position", 12usize, |this| ((**(this as *const *const ILineDisplayCursorAttributes_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, value: super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCursorAttributes", "SetPosition", 13usize, |this| ((**(this as *const *const ILineDisplayCursorAttributes_Vtbl)).SetPosition)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsBlinkEnabled,
+            SetIsBlinkEnabled,
+            CursorType,
+            SetCursorType,
+            IsAutoAdvanceEnabled,
+            SetIsAutoAdvanceEnabled,
+            Position,
+            SetPosition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILineDisplayCursorAttributes as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ILineDisplayCustomGlyphs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SizeInPixels(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCustomGlyphs", "SizeInPixels", 6usize, |this| ((**(this as *const *const ILineDisplayCustomGlyphs_Vtbl)).SizeInPixels)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedGlyphCodes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCustomGlyphs", "SupportedGlyphCodes", 7usize, |this| ((**(this as *const *const ILineDisplayCustomGlyphs_Vtbl)).SupportedGlyphCodes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn TryRedefineAsync(this: *mut core::ffi::c_void, glyphcode: u32, glyphdata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayCustomGlyphs", "TryRedefineAsync", 8usize, |this| ((**(this as *const *const ILineDisplayCustomGlyphs_Vtbl)).TryRedefineAsync)(this, glyphcode, glyphdata, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SizeInPixels,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedGlyphCodes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedGlyphCodes: 0,
+            #[cfg(feature = "Storage_Streams")]
+            TryRedefineAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            TryRedefineAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILineDisplayCustomGlyphs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILineDisplayMarquee_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, result__: *mut LineDisplayMarqueeFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayMarquee", "Format", 6usize, |this| ((**(this as *const *const ILineDisplayMarquee_Vtbl)).Format)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, value: LineDisplayMarqueeFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayMarquee", "SetFormat", 7usize, |this| ((**(this as *const *const ILineDisplayMarquee_Vtbl)).SetFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RepeatWaitInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayMarquee", "RepeatWaitInterval", 8usize, |this| ((**(this as *const *const ILineDisplayMarquee_Vtbl)).RepeatWaitInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRepeatWaitInterval(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayMarquee", "SetRepeatWaitInterval", 9usize, |this| ((**(this as *const *const ILineDisplayMarquee_Vtbl)).SetRepeatWaitInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScrollWaitInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayMarquee", "ScrollWaitInterval", 10usize, |this| ((**(this as *const *const ILineDisplayMarquee_Vtbl)).ScrollWaitInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScrollWaitInterval(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayMarquee", "SetScrollWaitInterval", 11usize, |this| ((**(this as *const *const ILineDisplayMarquee_Vtbl)).SetScrollWaitInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryStartScrollingAsync(this: *mut core::ffi::c_void, direction: LineDisplayScrollDirection, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayMarquee", "TryStartScrollingAsync", 12usize, |this| ((**(this as *const *const ILineDisplayMarquee_Vtbl)).TryStartScrollingAsync)(this, direction, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryStopScrollingAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayMarquee", "TryStopScrollingAsync", 13usize, |this| ((**(this as *const *const ILineDisplayMarquee_Vtbl)).TryStopScrollingAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Format,
+            SetFormat,
+            RepeatWaitInterval,
+            SetRepeatWaitInterval,
+            ScrollWaitInterval,
+            SetScrollWaitInterval,
+            TryStartScrollingAsync,
+            TryStopScrollingAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILineDisplayMarquee as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILineDisplayStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayStatics", "FromIdAsync", 6usize, |this| ((**(this as *const *const ILineDisplayStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayStatics", "GetDefaultAsync", 7usize, |this| ((**(this as *const *const ILineDisplayStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayStatics", "GetDeviceSelector", 8usize, |this| ((**(this as *const *const ILineDisplayStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorWithConnectionTypes(this: *mut core::ffi::c_void, connectiontypes: PosConnectionTypes, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayStatics", "GetDeviceSelectorWithConnectionTypes", 9usize, |this| ((**(this as *const *const ILineDisplayStatics_Vtbl)).GetDeviceSelectorWithConnectionTypes)(this, connectiontypes, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FromIdAsync,
+            GetDefaultAsync,
+            GetDeviceSelector,
+            GetDeviceSelectorWithConnectionTypes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILineDisplayStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILineDisplayStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StatisticsCategorySelector(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayStatics2", "StatisticsCategorySelector", 6usize, |this| ((**(this as *const *const ILineDisplayStatics2_Vtbl)).StatisticsCategorySelector)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StatisticsCategorySelector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILineDisplayStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILineDisplayStatisticsCategorySelector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllStatistics(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayStatisticsCategorySelector", "AllStatistics", 6usize, |this| ((**(this as *const *const ILineDisplayStatisticsCategorySelector_Vtbl)).AllStatistics)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnifiedPosStatistics(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayStatisticsCategorySelector", "UnifiedPosStatistics", 7usize, |this| ((**(this as *const *const ILineDisplayStatisticsCategorySelector_Vtbl)).UnifiedPosStatistics)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManufacturerStatistics(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayStatisticsCategorySelector", "ManufacturerStatistics", 8usize, |this| ((**(this as *const *const ILineDisplayStatisticsCategorySelector_Vtbl)).ManufacturerStatistics)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AllStatistics, UnifiedPosStatistics, ManufacturerStatistics }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILineDisplayStatisticsCategorySelector as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILineDisplayStatusUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut LineDisplayPowerStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayStatusUpdatedEventArgs", "Status", 6usize, |this| ((**(this as *const *const ILineDisplayStatusUpdatedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILineDisplayStatusUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILineDisplayStoredBitmap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EscapeSequence(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayStoredBitmap", "EscapeSequence", 6usize, |this| ((**(this as *const *const ILineDisplayStoredBitmap_Vtbl)).EscapeSequence)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryDeleteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayStoredBitmap", "TryDeleteAsync", 7usize, |this| ((**(this as *const *const ILineDisplayStoredBitmap_Vtbl)).TryDeleteAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EscapeSequence, TryDeleteAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILineDisplayStoredBitmap as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILineDisplayWindow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SizeInCharacters(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow", "SizeInCharacters", 6usize, |this| ((**(this as *const *const ILineDisplayWindow_Vtbl)).SizeInCharacters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InterCharacterWaitInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow", "InterCharacterWaitInterval", 7usize, |this| ((**(this as *const *const ILineDisplayWindow_Vtbl)).InterCharacterWaitInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterCharacterWaitInterval(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow", "SetInterCharacterWaitInterval", 8usize, |this| ((**(this as *const *const ILineDisplayWindow_Vtbl)).SetInterCharacterWaitInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryRefreshAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow", "TryRefreshAsync", 9usize, |this| ((**(this as *const *const ILineDisplayWindow_Vtbl)).TryRefreshAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryDisplayTextAsync(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, displayattribute: LineDisplayTextAttribute, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow", "TryDisplayTextAsync", 10usize, |this| ((**(this as *const *const ILineDisplayWindow_Vtbl)).TryDisplayTextAsync)(this, text, displayattribute, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryDisplayTextAtPositionAsync(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, displayattribute: LineDisplayTextAttribute, startposition: super::super::Foundation::Point, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow", "TryDisplayTextAtPositionAsync", 11usize, |this| ((**(this as *const *const ILineDisplayWindow_Vtbl)).TryDisplayTextAtPositionAsync)(this, text, displayattribute, startposition, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryDisplayTextNormalAsync(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow", "TryDisplayTextNormalAsync", 12usize, |this| ((**(this as *const *const ILineDisplayWindow_Vtbl)).TryDisplayTextNormalAsync)(this, text, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryScrollTextAsync(this: *mut core::ffi::c_void, direction: LineDisplayScrollDirection, numberofcolumnsorrows: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow", "TryScrollTextAsync", 13usize, |this| ((**(this as *const *const ILineDisplayWindow_Vtbl)).TryScrollTextAsync)(this, direction, numberofcolumnsorrows, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryClearTextAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow", "TryClearTextAsync", 14usize, |this| ((**(this as *const *const ILineDisplayWindow_Vtbl)).TryClearTextAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SizeInCharacters,
+            InterCharacterWaitInterval,
+            SetInterCharacterWaitInterval,
+            TryRefreshAsync,
+            TryDisplayTextAsync,
+            TryDisplayTextAtPositionAsync,
+            TryDisplayTextNormalAsync,
+            TryScrollTextAsync,
+            TryClearTextAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILineDisplayWindow as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for ILineDisplayWindow2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cursor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow2", "Cursor", 6usize, |this| ((**(this as *const *const ILineDisplayWindow2_Vtbl)).Cursor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Marquee(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow2", "Marquee", 7usize, |this| ((**(this as *const *const ILineDisplayWindow2_Vtbl)).Marquee)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadCharacterAtCursorAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow2", "ReadCharacterAtCursorAsync", 8usize, |this| ((**(this as *const *const ILineDisplayWindow2_Vtbl)).ReadCharacterAtCursorAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryDisplayStoredBitmapAtCursorAsync(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow2", "TryDisplayStoredBitmapAtCursorAsync", 9usize, |this| ((**(this as *const *const ILineDisplayWindow2_Vtbl)).TryDisplayStoredBitmapAtCursorAsync)(this, bitmap, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn TryDisplayStorageFileBitmapAtCursorAsync(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow2", "TryDisplayStorageFileBitmapAtCursorAsync", 10usize, |this| ((**(this as *const *const ILineDisplayWindow2_Vtbl)).TryDisplayStorageFileBitmapAtCursorAsync)(this, bitmap, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn TryDisplayStorageFileBitmapAtCursorWithAlignmentAsync(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, horizontalalignment: LineDisplayHorizontalAlignment, verticalalignment: LineDisplayVerticalAlignment, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow2", "TryDisplayStorageFileBitmapAtCursorWithAlignmentAsync", 11usize, |this| ((**(this as *const *const ILineDisplayWindow2_Vtbl)).TryDisplayStorageFileBitmapAtCursorWithAlignmentAsync)(this, bitmap, horizontalalignment, verticalalignment, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn TryDisplayStorageFileBitmapAtCursorWithAlignmentAndWidthAsync(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, horizontalalignment: LineDisplayHorizontalAlignment, verticalalignment: LineDisplayVerticalAlignment, widthinpixels: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow2", "TryDisplayStorageFileBitmapAtCursorWithAlignmentAndWidthAsync", 12usize, |this| ((**(this as *const *const ILineDisplayWindow2_Vtbl)).TryDisplayStorageFileBitmapAtCursorWithAlignmentAndWidthAsync)(this, bitmap, horizontalalignment, verticalalignment, widthinpixels, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn TryDisplayStorageFileBitmapAtPointAsync(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, offsetinpixels: super::super::Foundation::Point, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow2", "TryDisplayStorageFileBitmapAtPointAsync", 13usize, |this| ((**(this as *const *const ILineDisplayWindow2_Vtbl)).TryDisplayStorageFileBitmapAtPointAsync)(this, bitmap, offsetinpixels, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn TryDisplayStorageFileBitmapAtPointWithWidthAsync(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, offsetinpixels: super::super::Foundation::Point, widthinpixels: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILineDisplayWindow2", "TryDisplayStorageFileBitmapAtPointWithWidthAsync", 14usize, |this| ((**(this as *const *const ILineDisplayWindow2_Vtbl)).TryDisplayStorageFileBitmapAtPointWithWidthAsync)(this, bitmap, offsetinpixels, widthinpixels, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Cursor,
+            Marquee,
+            ReadCharacterAtCursorAsync,
+            TryDisplayStoredBitmapAtCursorAsync,
+            #[cfg(feature = "Storage")]
+            TryDisplayStorageFileBitmapAtCursorAsync,
+            #[cfg(not(feature = "Storage"))]
+            TryDisplayStorageFileBitmapAtCursorAsync: 0,
+            #[cfg(feature = "Storage")]
+            TryDisplayStorageFileBitmapAtCursorWithAlignmentAsync,
+            #[cfg(not(feature = "Storage"))]
+            TryDisplayStorageFileBitmapAtCursorWithAlignmentAsync: 0,
+            #[cfg(feature = "Storage")]
+            TryDisplayStorageFileBitmapAtCursorWithAlignmentAndWidthAsync,
+            #[cfg(not(feature = "Storage"))]
+            TryDisplayStorageFileBitmapAtCursorWithAlignmentAndWidthAsync: 0,
+            #[cfg(feature = "Storage")]
+            TryDisplayStorageFileBitmapAtPointAsync,
+            #[cfg(not(feature = "Storage"))]
+            TryDisplayStorageFileBitmapAtPointAsync: 0,
+            #[cfg(feature = "Storage")]
+            TryDisplayStorageFileBitmapAtPointWithWidthAsync,
+            #[cfg(not(feature = "Storage"))]
+            TryDisplayStorageFileBitmapAtPointWithWidthAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILineDisplayWindow2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMagneticStripeReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReader", "DeviceId", 6usize, |this| ((**(this as *const *const IMagneticStripeReader_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Capabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReader", "Capabilities", 7usize, |this| ((**(this as *const *const IMagneticStripeReader_Vtbl)).Capabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedCardTypes(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReader", "SupportedCardTypes", 8usize, |this| ((**(this as *const *const IMagneticStripeReader_Vtbl)).SupportedCardTypes)(this, result_size__, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceAuthenticationProtocol(this: *mut core::ffi::c_void, result__: *mut MagneticStripeReaderAuthenticationProtocol) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReader", "DeviceAuthenticationProtocol", 9usize, |this| ((**(this as *const *const IMagneticStripeReader_Vtbl)).DeviceAuthenticationProtocol)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckHealthAsync(this: *mut core::ffi::c_void, level: UnifiedPosHealthCheckLevel, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReader", "CheckHealthAsync", 10usize, |this| ((**(this as *const *const IMagneticStripeReader_Vtbl)).CheckHealthAsync)(this, level, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClaimReaderAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReader", "ClaimReaderAsync", 11usize, |this| ((**(this as *const *const IMagneticStripeReader_Vtbl)).ClaimReaderAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn RetrieveStatisticsAsync(this: *mut core::ffi::c_void, statisticscategories: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReader", "RetrieveStatisticsAsync", 12usize, |this| ((**(this as *const *const IMagneticStripeReader_Vtbl)).RetrieveStatisticsAsync)(this, statisticscategories, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorReportingType(this: *mut core::ffi::c_void, result__: *mut MagneticStripeReaderErrorReportingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReader", "GetErrorReportingType", 13usize, |this| ((**(this as *const *const IMagneticStripeReader_Vtbl)).GetErrorReportingType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReader", "StatusUpdated", 14usize, |this| ((**(this as *const *const IMagneticStripeReader_Vtbl)).StatusUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReader", "RemoveStatusUpdated", 15usize, |this| ((**(this as *const *const IMagneticStripeReader_Vtbl)).RemoveStatusUpdated)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            Capabilities,
+            SupportedCardTypes,
+            DeviceAuthenticationProtocol,
+            CheckHealthAsync,
+            ClaimReaderAsync,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            RetrieveStatisticsAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            RetrieveStatisticsAsync: 0,
+            GetErrorReportingType,
+            StatusUpdated,
+            RemoveStatusUpdated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagneticStripeReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Report(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "Report", 6usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).Report)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LicenseNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "LicenseNumber", 7usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).LicenseNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationDate(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "ExpirationDate", 8usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).ExpirationDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Restrictions(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "Restrictions", 9usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).Restrictions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Class(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "Class", 10usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).Class)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Endorsements(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "Endorsements", 11usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).Endorsements)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BirthDate(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "BirthDate", 12usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).BirthDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "FirstName", 13usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).FirstName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Surname(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "Surname", 14usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).Surname)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Suffix(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "Suffix", 15usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).Suffix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gender(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "Gender", 16usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).Gender)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HairColor(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "HairColor", 17usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).HairColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EyeColor(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "EyeColor", 18usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).EyeColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "Height", 19usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Weight(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "Weight", 20usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).Weight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "Address", 21usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).Address)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn City(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "City", 22usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).City)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "State", 23usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostalCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderAamvaCardDataReceivedEventArgs", "PostalCode", 24usize, |this| ((**(this as *const *const IMagneticStripeReaderAamvaCardDataReceivedEventArgs_Vtbl)).PostalCode)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Report,
+            LicenseNumber,
+            ExpirationDate,
+            Restrictions,
+            Class,
+            Endorsements,
+            BirthDate,
+            FirstName,
+            Surname,
+            Suffix,
+            Gender,
+            HairColor,
+            EyeColor,
+            Height,
+            Weight,
+            Address,
+            City,
+            State,
+            PostalCode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagneticStripeReaderAamvaCardDataReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagneticStripeReaderBankCardDataReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Report(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderBankCardDataReceivedEventArgs", "Report", 6usize, |this| ((**(this as *const *const IMagneticStripeReaderBankCardDataReceivedEventArgs_Vtbl)).Report)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccountNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderBankCardDataReceivedEventArgs", "AccountNumber", 7usize, |this| ((**(this as *const *const IMagneticStripeReaderBankCardDataReceivedEventArgs_Vtbl)).AccountNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationDate(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderBankCardDataReceivedEventArgs", "ExpirationDate", 8usize, |this| ((**(this as *const *const IMagneticStripeReaderBankCardDataReceivedEventArgs_Vtbl)).ExpirationDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderBankCardDataReceivedEventArgs", "ServiceCode", 9usize, |this| ((**(this as *const *const IMagneticStripeReaderBankCardDataReceivedEventArgs_Vtbl)).ServiceCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderBankCardDataReceivedEventArgs", "Title", 10usize, |this| ((**(this as *const *const IMagneticStripeReaderBankCardDataReceivedEventArgs_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderBankCardDataReceivedEventArgs", "FirstName", 11usize, |this| ((**(this as *const *const IMagneticStripeReaderBankCardDataReceivedEventArgs_Vtbl)).FirstName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MiddleInitial(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderBankCardDataReceivedEventArgs", "MiddleInitial", 12usize, |this| ((**(this as *const *const IMagneticStripeReaderBankCardDataReceivedEventArgs_Vtbl)).MiddleInitial)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Surname(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderBankCardDataReceivedEventArgs", "Surname", 13usize, |this| ((**(this as *const *const IMagneticStripeReaderBankCardDataReceivedEventArgs_Vtbl)).Surname)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Suffix(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderBankCardDataReceivedEventArgs", "Suffix", 14usize, |this| ((**(this as *const *const IMagneticStripeReaderBankCardDataReceivedEventArgs_Vtbl)).Suffix)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Report,
+            AccountNumber,
+            ExpirationDate,
+            ServiceCode,
+            Title,
+            FirstName,
+            MiddleInitial,
+            Surname,
+            Suffix,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagneticStripeReaderBankCardDataReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagneticStripeReaderCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CardAuthentication(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderCapabilities", "CardAuthentication", 6usize, |this| ((**(this as *const *const IMagneticStripeReaderCapabilities_Vtbl)).CardAuthentication)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedEncryptionAlgorithms(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderCapabilities", "SupportedEncryptionAlgorithms", 7usize, |this| ((**(this as *const *const IMagneticStripeReaderCapabilities_Vtbl)).SupportedEncryptionAlgorithms)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationLevel(this: *mut core::ffi::c_void, result__: *mut MagneticStripeReaderAuthenticationLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderCapabilities", "AuthenticationLevel", 8usize, |this| ((**(this as *const *const IMagneticStripeReaderCapabilities_Vtbl)).AuthenticationLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIsoSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderCapabilities", "IsIsoSupported", 9usize, |this| ((**(this as *const *const IMagneticStripeReaderCapabilities_Vtbl)).IsIsoSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsJisOneSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderCapabilities", "IsJisOneSupported", 10usize, |this| ((**(this as *const *const IMagneticStripeReaderCapabilities_Vtbl)).IsJisOneSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsJisTwoSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderCapabilities", "IsJisTwoSupported", 11usize, |this| ((**(this as *const *const IMagneticStripeReaderCapabilities_Vtbl)).IsJisTwoSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerReportingType(this: *mut core::ffi::c_void, result__: *mut UnifiedPosPowerReportingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderCapabilities", "PowerReportingType", 12usize, |this| ((**(this as *const *const IMagneticStripeReaderCapabilities_Vtbl)).PowerReportingType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStatisticsReportingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderCapabilities", "IsStatisticsReportingSupported", 13usize, |this| ((**(this as *const *const IMagneticStripeReaderCapabilities_Vtbl)).IsStatisticsReportingSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStatisticsUpdatingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderCapabilities", "IsStatisticsUpdatingSupported", 14usize, |this| ((**(this as *const *const IMagneticStripeReaderCapabilities_Vtbl)).IsStatisticsUpdatingSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTrackDataMaskingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderCapabilities", "IsTrackDataMaskingSupported", 15usize, |this| ((**(this as *const *const IMagneticStripeReaderCapabilities_Vtbl)).IsTrackDataMaskingSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTransmitSentinelsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderCapabilities", "IsTransmitSentinelsSupported", 16usize, |this| ((**(this as *const *const IMagneticStripeReaderCapabilities_Vtbl)).IsTransmitSentinelsSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CardAuthentication,
+            SupportedEncryptionAlgorithms,
+            AuthenticationLevel,
+            IsIsoSupported,
+            IsJisOneSupported,
+            IsJisTwoSupported,
+            PowerReportingType,
+            IsStatisticsReportingSupported,
+            IsStatisticsUpdatingSupported,
+            IsTrackDataMaskingSupported,
+            IsTransmitSentinelsSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagneticStripeReaderCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagneticStripeReaderCardTypesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Unknown(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderCardTypesStatics", "Unknown", 6usize, |this| ((**(this as *const *const IMagneticStripeReaderCardTypesStatics_Vtbl)).Unknown)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bank(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderCardTypesStatics", "Bank", 7usize, |this| ((**(this as *const *const IMagneticStripeReaderCardTypesStatics_Vtbl)).Bank)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Aamva(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderCardTypesStatics", "Aamva", 8usize, |this| ((**(this as *const *const IMagneticStripeReaderCardTypesStatics_Vtbl)).Aamva)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedBase(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderCardTypesStatics", "ExtendedBase", 9usize, |this| ((**(this as *const *const IMagneticStripeReaderCardTypesStatics_Vtbl)).ExtendedBase)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Unknown, Bank, Aamva, ExtendedBase }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagneticStripeReaderCardTypesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagneticStripeReaderEncryptionAlgorithmsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn None(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderEncryptionAlgorithmsStatics", "None", 6usize, |this| ((**(this as *const *const IMagneticStripeReaderEncryptionAlgorithmsStatics_Vtbl)).None)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TripleDesDukpt(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderEncryptionAlgorithmsStatics", "TripleDesDukpt", 7usize, |this| ((**(this as *const *const IMagneticStripeReaderEncryptionAlgorithmsStatics_Vtbl)).TripleDesDukpt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedBase(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderEncryptionAlgorithmsStatics", "ExtendedBase", 8usize, |this| ((**(this as *const *const IMagneticStripeReaderEncryptionAlgorithmsStatics_Vtbl)).ExtendedBase)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, None, TripleDesDukpt, ExtendedBase }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagneticStripeReaderEncryptionAlgorithmsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagneticStripeReaderErrorOccurredEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Track1Status(this: *mut core::ffi::c_void, result__: *mut MagneticStripeReaderTrackErrorType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderErrorOccurredEventArgs", "Track1Status", 6usize, |this| ((**(this as *const *const IMagneticStripeReaderErrorOccurredEventArgs_Vtbl)).Track1Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Track2Status(this: *mut core::ffi::c_void, result__: *mut MagneticStripeReaderTrackErrorType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderErrorOccurredEventArgs", "Track2Status", 7usize, |this| ((**(this as *const *const IMagneticStripeReaderErrorOccurredEventArgs_Vtbl)).Track2Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Track3Status(this: *mut core::ffi::c_void, result__: *mut MagneticStripeReaderTrackErrorType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderErrorOccurredEventArgs", "Track3Status", 8usize, |this| ((**(this as *const *const IMagneticStripeReaderErrorOccurredEventArgs_Vtbl)).Track3Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Track4Status(this: *mut core::ffi::c_void, result__: *mut MagneticStripeReaderTrackErrorType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderErrorOccurredEventArgs", "Track4Status", 9usize, |this| ((**(this as *const *const IMagneticStripeReaderErrorOccurredEventArgs_Vtbl)).Track4Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderErrorOccurredEventArgs", "ErrorData", 10usize, |this| ((**(this as *const *const IMagneticStripeReaderErrorOccurredEventArgs_Vtbl)).ErrorData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PartialInputData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderErrorOccurredEventArgs", "PartialInputData", 11usize, |this| ((**(this as *const *const IMagneticStripeReaderErrorOccurredEventArgs_Vtbl)).PartialInputData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Track1Status,
+            Track2Status,
+            Track3Status,
+            Track4Status,
+            ErrorData,
+            PartialInputData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagneticStripeReaderErrorOccurredEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMagneticStripeReaderReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CardType(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderReport", "CardType", 6usize, |this| ((**(this as *const *const IMagneticStripeReaderReport_Vtbl)).CardType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Track1(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderReport", "Track1", 7usize, |this| ((**(this as *const *const IMagneticStripeReaderReport_Vtbl)).Track1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Track2(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderReport", "Track2", 8usize, |this| ((**(this as *const *const IMagneticStripeReaderReport_Vtbl)).Track2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Track3(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderReport", "Track3", 9usize, |this| ((**(this as *const *const IMagneticStripeReaderReport_Vtbl)).Track3)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Track4(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderReport", "Track4", 10usize, |this| ((**(this as *const *const IMagneticStripeReaderReport_Vtbl)).Track4)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderReport", "Properties", 11usize, |this| ((**(this as *const *const IMagneticStripeReaderReport_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CardAuthenticationData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderReport", "CardAuthenticationData", 12usize, |this| ((**(this as *const *const IMagneticStripeReaderReport_Vtbl)).CardAuthenticationData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CardAuthenticationDataLength(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderReport", "CardAuthenticationDataLength", 13usize, |this| ((**(this as *const *const IMagneticStripeReaderReport_Vtbl)).CardAuthenticationDataLength)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AdditionalSecurityInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderReport", "AdditionalSecurityInformation", 14usize, |this| ((**(this as *const *const IMagneticStripeReaderReport_Vtbl)).AdditionalSecurityInformation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CardType,
+            Track1,
+            Track2,
+            Track3,
+            Track4,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CardAuthenticationData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CardAuthenticationData: 0,
+            CardAuthenticationDataLength,
+            #[cfg(feature = "Storage_Streams")]
+            AdditionalSecurityInformation,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AdditionalSecurityInformation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagneticStripeReaderReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagneticStripeReaderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderStatics", "GetDefaultAsync", 6usize, |this| ((**(this as *const *const IMagneticStripeReaderStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const IMagneticStripeReaderStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderStatics", "GetDeviceSelector", 8usize, |this| ((**(this as *const *const IMagneticStripeReaderStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefaultAsync, FromIdAsync, GetDeviceSelector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagneticStripeReaderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagneticStripeReaderStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelectorWithConnectionTypes(this: *mut core::ffi::c_void, connectiontypes: PosConnectionTypes, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderStatics2", "GetDeviceSelectorWithConnectionTypes", 6usize, |this| ((**(this as *const *const IMagneticStripeReaderStatics2_Vtbl)).GetDeviceSelectorWithConnectionTypes)(this, connectiontypes, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelectorWithConnectionTypes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagneticStripeReaderStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagneticStripeReaderStatusUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut MagneticStripeReaderStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderStatusUpdatedEventArgs", "Status", 6usize, |this| ((**(this as *const *const IMagneticStripeReaderStatusUpdatedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedStatus(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderStatusUpdatedEventArgs", "ExtendedStatus", 7usize, |this| ((**(this as *const *const IMagneticStripeReaderStatusUpdatedEventArgs_Vtbl)).ExtendedStatus)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, ExtendedStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagneticStripeReaderStatusUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMagneticStripeReaderTrackData_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderTrackData", "Data", 6usize, |this| ((**(this as *const *const IMagneticStripeReaderTrackData_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn DiscretionaryData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderTrackData", "DiscretionaryData", 7usize, |this| ((**(this as *const *const IMagneticStripeReaderTrackData_Vtbl)).DiscretionaryData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn EncryptedData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderTrackData", "EncryptedData", 8usize, |this| ((**(this as *const *const IMagneticStripeReaderTrackData_Vtbl)).EncryptedData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Data,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Data: 0,
+            #[cfg(feature = "Storage_Streams")]
+            DiscretionaryData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            DiscretionaryData: 0,
+            #[cfg(feature = "Storage_Streams")]
+            EncryptedData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            EncryptedData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagneticStripeReaderTrackData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagneticStripeReaderVendorSpecificCardDataReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Report(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagneticStripeReaderVendorSpecificCardDataReceivedEventArgs", "Report", 6usize, |this| ((**(this as *const *const IMagneticStripeReaderVendorSpecificCardDataReceivedEventArgs_Vtbl)).Report)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Report }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagneticStripeReaderVendorSpecificCardDataReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPosPrinter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinter", "DeviceId", 6usize, |this| ((**(this as *const *const IPosPrinter_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Capabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinter", "Capabilities", 7usize, |this| ((**(this as *const *const IPosPrinter_Vtbl)).Capabilities)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedCharacterSets(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinter", "SupportedCharacterSets", 8usize, |this| ((**(this as *const *const IPosPrinter_Vtbl)).SupportedCharacterSets)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedTypeFaces(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinter", "SupportedTypeFaces", 9usize, |this| ((**(this as *const *const IPosPrinter_Vtbl)).SupportedTypeFaces)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinter", "Status", 10usize, |this| ((**(this as *const *const IPosPrinter_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClaimPrinterAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinter", "ClaimPrinterAsync", 11usize, |this| ((**(this as *const *const IPosPrinter_Vtbl)).ClaimPrinterAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckHealthAsync(this: *mut core::ffi::c_void, level: UnifiedPosHealthCheckLevel, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinter", "CheckHealthAsync", 12usize, |this| ((**(this as *const *const IPosPrinter_Vtbl)).CheckHealthAsync)(this, level, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetStatisticsAsync(this: *mut core::ffi::c_void, statisticscategories: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinter", "GetStatisticsAsync", 13usize, |this| ((**(this as *const *const IPosPrinter_Vtbl)).GetStatisticsAsync)(this, statisticscategories, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinter", "StatusUpdated", 14usize, |this| ((**(this as *const *const IPosPrinter_Vtbl)).StatusUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinter", "RemoveStatusUpdated", 15usize, |this| ((**(this as *const *const IPosPrinter_Vtbl)).RemoveStatusUpdated)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            Capabilities,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedCharacterSets,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedCharacterSets: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedTypeFaces,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedTypeFaces: 0,
+            Status,
+            ClaimPrinterAsync,
+            CheckHealthAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetStatisticsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetStatisticsAsync: 0,
+            StatusUpdated,
+            RemoveStatusUpdated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPosPrinter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPosPrinter2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedBarcodeSymbologies(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinter2", "SupportedBarcodeSymbologies", 6usize, |this| ((**(this as *const *const IPosPrinter2_Vtbl)).SupportedBarcodeSymbologies)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontProperty(this: *mut core::ffi::c_void, typeface: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinter2", "GetFontProperty", 7usize, |this| ((**(this as *const *const IPosPrinter2_Vtbl)).GetFontProperty)(this, typeface, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedBarcodeSymbologies,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedBarcodeSymbologies: 0,
+            GetFontProperty,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPosPrinter2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPosPrinterCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PowerReportingType(this: *mut core::ffi::c_void, result__: *mut UnifiedPosPowerReportingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterCapabilities", "PowerReportingType", 6usize, |this| ((**(this as *const *const IPosPrinterCapabilities_Vtbl)).PowerReportingType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStatisticsReportingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterCapabilities", "IsStatisticsReportingSupported", 7usize, |this| ((**(this as *const *const IPosPrinterCapabilities_Vtbl)).IsStatisticsReportingSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStatisticsUpdatingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterCapabilities", "IsStatisticsUpdatingSupported", 8usize, |this| ((**(this as *const *const IPosPrinterCapabilities_Vtbl)).IsStatisticsUpdatingSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultCharacterSet(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterCapabilities", "DefaultCharacterSet", 9usize, |this| ((**(this as *const *const IPosPrinterCapabilities_Vtbl)).DefaultCharacterSet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasCoverSensor(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterCapabilities", "HasCoverSensor", 10usize, |this| ((**(this as *const *const IPosPrinterCapabilities_Vtbl)).HasCoverSensor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanMapCharacterSet(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterCapabilities", "CanMapCharacterSet", 11usize, |this| ((**(this as *const *const IPosPrinterCapabilities_Vtbl)).CanMapCharacterSet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTransactionSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterCapabilities", "IsTransactionSupported", 12usize, |this| ((**(this as *const *const IPosPrinterCapabilities_Vtbl)).IsTransactionSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Receipt(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterCapabilities", "Receipt", 13usize, |this| ((**(this as *const *const IPosPrinterCapabilities_Vtbl)).Receipt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Slip(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterCapabilities", "Slip", 14usize, |this| ((**(this as *const *const IPosPrinterCapabilities_Vtbl)).Slip)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Journal(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterCapabilities", "Journal", 15usize, |this| ((**(this as *const *const IPosPrinterCapabilities_Vtbl)).Journal)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PowerReportingType,
+            IsStatisticsReportingSupported,
+            IsStatisticsUpdatingSupported,
+            DefaultCharacterSet,
+            HasCoverSensor,
+            CanMapCharacterSet,
+            IsTransactionSupported,
+            Receipt,
+            Slip,
+            Journal,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPosPrinterCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPosPrinterCharacterSetIdsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Utf16LE(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterCharacterSetIdsStatics", "Utf16LE", 6usize, |this| ((**(this as *const *const IPosPrinterCharacterSetIdsStatics_Vtbl)).Utf16LE)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ascii(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterCharacterSetIdsStatics", "Ascii", 7usize, |this| ((**(this as *const *const IPosPrinterCharacterSetIdsStatics_Vtbl)).Ascii)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ansi(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterCharacterSetIdsStatics", "Ansi", 8usize, |this| ((**(this as *const *const IPosPrinterCharacterSetIdsStatics_Vtbl)).Ansi)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Utf16LE, Ascii, Ansi }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPosPrinterCharacterSetIdsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPosPrinterFontProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TypeFace(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterFontProperty", "TypeFace", 6usize, |this| ((**(this as *const *const IPosPrinterFontProperty_Vtbl)).TypeFace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsScalableToAnySize(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterFontProperty", "IsScalableToAnySize", 7usize, |this| ((**(this as *const *const IPosPrinterFontProperty_Vtbl)).IsScalableToAnySize)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CharacterSizes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterFontProperty", "CharacterSizes", 8usize, |this| ((**(this as *const *const IPosPrinterFontProperty_Vtbl)).CharacterSizes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TypeFace,
+            IsScalableToAnySize,
+            #[cfg(feature = "Foundation_Collections")]
+            CharacterSizes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CharacterSizes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPosPrinterFontProperty as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPosPrinterJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Print(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterJob", "Print", 6usize, |this| ((**(this as *const *const IPosPrinterJob_Vtbl)).Print)(this, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintLine(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterJob", "PrintLine", 7usize, |this| ((**(this as *const *const IPosPrinterJob_Vtbl)).PrintLine)(this, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintNewline(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterJob", "PrintNewline", 8usize, |this| ((**(this as *const *const IPosPrinterJob_Vtbl)).PrintNewline)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecuteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterJob", "ExecuteAsync", 9usize, |this| ((**(this as *const *const IPosPrinterJob_Vtbl)).ExecuteAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Print, PrintLine, PrintNewline, ExecuteAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPosPrinterJob as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPosPrinterPrintOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TypeFace(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "TypeFace", 6usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).TypeFace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTypeFace(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "SetTypeFace", 7usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).SetTypeFace)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CharacterHeight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "CharacterHeight", 8usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).CharacterHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCharacterHeight(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "SetCharacterHeight", 9usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).SetCharacterHeight)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bold(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "Bold", 10usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).Bold)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBold(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "SetBold", 11usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).SetBold)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Italic(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "Italic", 12usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).Italic)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetItalic(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "SetItalic", 13usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).SetItalic)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Underline(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "Underline", 14usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).Underline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnderline(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "SetUnderline", 15usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).SetUnderline)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReverseVideo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "ReverseVideo", 16usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).ReverseVideo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReverseVideo(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "SetReverseVideo", 17usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).SetReverseVideo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Strikethrough(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "Strikethrough", 18usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).Strikethrough)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrikethrough(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "SetStrikethrough", 19usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).SetStrikethrough)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Superscript(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "Superscript", 20usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).Superscript)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuperscript(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "SetSuperscript", 21usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).SetSuperscript)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subscript(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "Subscript", 22usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).Subscript)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubscript(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "SetSubscript", 23usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).SetSubscript)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoubleWide(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "DoubleWide", 24usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).DoubleWide)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDoubleWide(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "SetDoubleWide", 25usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).SetDoubleWide)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoubleHigh(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "DoubleHigh", 26usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).DoubleHigh)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDoubleHigh(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "SetDoubleHigh", 27usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).SetDoubleHigh)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Alignment(this: *mut core::ffi::c_void, result__: *mut PosPrinterAlignment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "Alignment", 28usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).Alignment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlignment(this: *mut core::ffi::c_void, value: PosPrinterAlignment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "SetAlignment", 29usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).SetAlignment)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CharacterSet(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "CharacterSet", 30usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).CharacterSet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCharacterSet(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterPrintOptions", "SetCharacterSet", 31usize, |this| ((**(this as *const *const IPosPrinterPrintOptions_Vtbl)).SetCharacterSet)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TypeFace,
+            SetTypeFace,
+            CharacterHeight,
+            SetCharacterHeight,
+            Bold,
+            SetBold,
+            Italic,
+            SetItalic,
+            Underline,
+            SetUnderline,
+            ReverseVideo,
+            SetReverseVideo,
+            Strikethrough,
+            SetStrikethrough,
+            Superscript,
+            SetSuperscript,
+            Subscript,
+            SetSubscript,
+            DoubleWide,
+            SetDoubleWide,
+            DoubleHigh,
+            SetDoubleHigh,
+            Alignment,
+            SetAlignment,
+            CharacterSet,
+            SetCharacterSet,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPosPrinterPrintOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPosPrinterReleaseDeviceRequestedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPosPrinterReleaseDeviceRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPosPrinterStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterStatics", "GetDefaultAsync", 6usize, |this| ((**(this as *const *const IPosPrinterStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const IPosPrinterStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterStatics", "GetDeviceSelector", 8usize, |this| ((**(this as *const *const IPosPrinterStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefaultAsync, FromIdAsync, GetDeviceSelector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPosPrinterStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPosPrinterStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelectorWithConnectionTypes(this: *mut core::ffi::c_void, connectiontypes: PosConnectionTypes, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterStatics2", "GetDeviceSelectorWithConnectionTypes", 6usize, |this| ((**(this as *const *const IPosPrinterStatics2_Vtbl)).GetDeviceSelectorWithConnectionTypes)(this, connectiontypes, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelectorWithConnectionTypes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPosPrinterStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPosPrinterStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StatusKind(this: *mut core::ffi::c_void, result__: *mut PosPrinterStatusKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterStatus", "StatusKind", 6usize, |this| ((**(this as *const *const IPosPrinterStatus_Vtbl)).StatusKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedStatus(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterStatus", "ExtendedStatus", 7usize, |this| ((**(this as *const *const IPosPrinterStatus_Vtbl)).ExtendedStatus)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StatusKind, ExtendedStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPosPrinterStatus as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPosPrinterStatusUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPosPrinterStatusUpdatedEventArgs", "Status", 6usize, |this| ((**(this as *const *const IPosPrinterStatusUpdatedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPosPrinterStatusUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for IReceiptOrSlipJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBarcodeRotation(this: *mut core::ffi::c_void, value: PosPrinterRotation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptOrSlipJob", "SetBarcodeRotation", 6usize, |this| ((**(this as *const *const IReceiptOrSlipJob_Vtbl)).SetBarcodeRotation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrintRotation(this: *mut core::ffi::c_void, value: PosPrinterRotation, includebitmaps: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptOrSlipJob", "SetPrintRotation", 7usize, |this| ((**(this as *const *const IReceiptOrSlipJob_Vtbl)).SetPrintRotation)(this, value, includebitmaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrintArea(this: *mut core::ffi::c_void, value: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptOrSlipJob", "SetPrintArea", 8usize, |this| ((**(this as *const *const IReceiptOrSlipJob_Vtbl)).SetPrintArea)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SetBitmap(this: *mut core::ffi::c_void, bitmapnumber: u32, bitmap: *mut core::ffi::c_void, alignment: PosPrinterAlignment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptOrSlipJob", "SetBitmap", 9usize, |this| ((**(this as *const *const IReceiptOrSlipJob_Vtbl)).SetBitmap)(this, bitmapnumber, bitmap, alignment), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SetBitmapCustomWidthStandardAlign(this: *mut core::ffi::c_void, bitmapnumber: u32, bitmap: *mut core::ffi::c_void, alignment: PosPrinterAlignment, width: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptOrSlipJob", "SetBitmapCustomWidthStandardAlign", 10usize, |this| ((**(this as *const *const IReceiptOrSlipJob_Vtbl)).SetBitmapCustomWidthStandardAlign)(this, bitmapnumber, bitmap, alignment, width), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SetCustomAlignedBitmap(this: *mut core::ffi::c_void, bitmapnumber: u32, bitmap: *mut core::ffi::c_void, alignmentdistance: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptOrSlipJob", "SetCustomAlignedBitmap", 11usize, |this| ((**(this as *const *const IReceiptOrSlipJob_Vtbl)).SetCustomAlignedBitmap)(this, bitmapnumber, bitmap, alignmentdistance), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SetBitmapCustomWidthCustomAlign(this: *mut core::ffi::c_void, bitmapnumber: u32, bitmap: *mut core::ffi::c_void, alignmentdistance: u32, width: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptOrSlipJob", "SetBitmapCustomWidthCustomAlign", 12usize, |this| ((**(this as *const *const IReceiptOrSlipJob_Vtbl)).SetBitmapCustomWidthCustomAlign)(this, bitmapnumber, bitmap, alignmentdistance, width), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintSavedBitmap(this: *mut core::ffi::c_void, bitmapnumber: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptOrSlipJob", "PrintSavedBitmap", 13usize, |this| ((**(this as *const *const IReceiptOrSlipJob_Vtbl)).PrintSavedBitmap)(this, bitmapnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawRuledLine(this: *mut core::ffi::c_void, positionlist: core::mem::MaybeUninit<windows_core::HSTRING>, linedirection: PosPrinterLineDirection, linewidth: u32, linestyle: PosPrinterLineStyle, linecolor: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptOrSlipJob", "DrawRuledLine", 14usize, |this| ((**(this as *const *const IReceiptOrSlipJob_Vtbl)).DrawRuledLine)(this, positionlist, linedirection, linewidth, linestyle, linecolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintBarcode(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::HSTRING>, symbology: u32, height: u32, width: u32, textposition: PosPrinterBarcodeTextPosition, alignment: PosPrinterAlignment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptOrSlipJob", "PrintBarcode", 15usize, |this| ((**(this as *const *const IReceiptOrSlipJob_Vtbl)).PrintBarcode)(this, data, symbology, height, width, textposition, alignment), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintBarcodeCustomAlign(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::HSTRING>, symbology: u32, height: u32, width: u32, textposition: PosPrinterBarcodeTextPosition, alignmentdistance: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptOrSlipJob", "PrintBarcodeCustomAlign", 16usize, |this| ((**(this as *const *const IReceiptOrSlipJob_Vtbl)).PrintBarcodeCustomAlign)(this, data, symbology, height, width, textposition, alignmentdistance), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn PrintBitmap(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, alignment: PosPrinterAlignment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptOrSlipJob", "PrintBitmap", 17usize, |this| ((**(this as *const *const IReceiptOrSlipJob_Vtbl)).PrintBitmap)(this, bitmap, alignment), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn PrintBitmapCustomWidthStandardAlign(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, alignment: PosPrinterAlignment, width: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptOrSlipJob", "PrintBitmapCustomWidthStandardAlign", 18usize, |this| ((**(this as *const *const IReceiptOrSlipJob_Vtbl)).PrintBitmapCustomWidthStandardAlign)(this, bitmap, alignment, width), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn PrintCustomAlignedBitmap(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, alignmentdistance: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptOrSlipJob", "PrintCustomAlignedBitmap", 19usize, |this| ((**(this as *const *const IReceiptOrSlipJob_Vtbl)).PrintCustomAlignedBitmap)(this, bitmap, alignmentdistance), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn PrintBitmapCustomWidthCustomAlign(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, alignmentdistance: u32, width: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptOrSlipJob", "PrintBitmapCustomWidthCustomAlign", 20usize, |this| ((**(this as *const *const IReceiptOrSlipJob_Vtbl)).PrintBitmapCustomWidthCustomAlign)(this, bitmap, alignmentdistance, width), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetBarcodeRotation,
+            SetPrintRotation,
+            SetPrintArea,
+            #[cfg(feature = "Graphics_Imaging")]
+            SetBitmap,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SetBitmap: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            SetBitmapCustomWidthStandardAlign,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SetBitmapCustomWidthStandardAlign: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            SetCustomAlignedBitmap,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SetCustomAlignedBitmap: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            SetBitmapCustomWidthCustomAlign,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SetBitmapCustomWidthCustomAlign: 0,
+            PrintSavedBitmap,
+            DrawRuledLine,
+            PrintBarcode,
+            PrintBarcodeCustomAlign,
+            #[cfg(feature = "Graphics_Imaging")]
+            PrintBitmap,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            PrintBitmap: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            PrintBitmapCustomWidthStandardAlign,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            PrintBitmapCustomWidthStandardAlign: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            PrintCustomAlignedBitmap,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            PrintCustomAlignedBitmap: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            PrintBitmapCustomWidthCustomAlign,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            PrintBitmapCustomWidthCustomAlign: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReceiptOrSlipJob as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IReceiptPrintJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MarkFeed(this: *mut core::ffi::c_void, kind: PosPrinterMarkFeedKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrintJob", "MarkFeed", 6usize, |this| ((**(this as *const *const IReceiptPrintJob_Vtbl)).MarkFeed)(this, kind), |result| Some(*result))
+        }
+        unsafe extern "system" fn CutPaper(this: *mut core::ffi::c_void, percentage: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrintJob", "CutPaper", 7usize, |this| ((**(this as *const *const IReceiptPrintJob_Vtbl)).CutPaper)(this, percentage), |result| Some(*result))
+        }
+        unsafe extern "system" fn CutPaperDefault(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrintJob", "CutPaperDefault", 8usize, |this| ((**(this as *const *const IReceiptPrintJob_Vtbl)).CutPaperDefault)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MarkFeed, CutPaper, CutPaperDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReceiptPrintJob as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IReceiptPrintJob2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StampPaper(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrintJob2", "StampPaper", 6usize, |this| ((**(this as *const *const IReceiptPrintJob2_Vtbl)).StampPaper)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Print(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::HSTRING>, printoptions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrintJob2", "Print", 7usize, |this| ((**(this as *const *const IReceiptPrintJob2_Vtbl)).Print)(this, data, printoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedPaperByLine(this: *mut core::ffi::c_void, linecount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrintJob2", "FeedPaperByLine", 8usize, |this| ((**(this as *const *const IReceiptPrintJob2_Vtbl)).FeedPaperByLine)(this, linecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedPaperByMapModeUnit(this: *mut core::ffi::c_void, distance: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrintJob2", "FeedPaperByMapModeUnit", 9usize, |this| ((**(this as *const *const IReceiptPrintJob2_Vtbl)).FeedPaperByMapModeUnit)(this, distance), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StampPaper, Print, FeedPaperByLine, FeedPaperByMapModeUnit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReceiptPrintJob2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IReceiptPrinterCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanCutPaper(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrinterCapabilities", "CanCutPaper", 6usize, |this| ((**(this as *const *const IReceiptPrinterCapabilities_Vtbl)).CanCutPaper)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStampSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrinterCapabilities", "IsStampSupported", 7usize, |this| ((**(this as *const *const IReceiptPrinterCapabilities_Vtbl)).IsStampSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkFeedCapabilities(this: *mut core::ffi::c_void, result__: *mut PosPrinterMarkFeedCapabilities) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrinterCapabilities", "MarkFeedCapabilities", 8usize, |this| ((**(this as *const *const IReceiptPrinterCapabilities_Vtbl)).MarkFeedCapabilities)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CanCutPaper, IsStampSupported, MarkFeedCapabilities }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReceiptPrinterCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IReceiptPrinterCapabilities2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsReverseVideoSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrinterCapabilities2", "IsReverseVideoSupported", 6usize, |this| ((**(this as *const *const IReceiptPrinterCapabilities2_Vtbl)).IsReverseVideoSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStrikethroughSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrinterCapabilities2", "IsStrikethroughSupported", 7usize, |this| ((**(this as *const *const IReceiptPrinterCapabilities2_Vtbl)).IsStrikethroughSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSuperscriptSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrinterCapabilities2", "IsSuperscriptSupported", 8usize, |this| ((**(this as *const *const IReceiptPrinterCapabilities2_Vtbl)).IsSuperscriptSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSubscriptSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrinterCapabilities2", "IsSubscriptSupported", 9usize, |this| ((**(this as *const *const IReceiptPrinterCapabilities2_Vtbl)).IsSubscriptSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReversePaperFeedByLineSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrinterCapabilities2", "IsReversePaperFeedByLineSupported", 10usize, |this| ((**(this as *const *const IReceiptPrinterCapabilities2_Vtbl)).IsReversePaperFeedByLineSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReversePaperFeedByMapModeUnitSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReceiptPrinterCapabilities2", "IsReversePaperFeedByMapModeUnitSupported", 11usize, |this| ((**(this as *const *const IReceiptPrinterCapabilities2_Vtbl)).IsReversePaperFeedByMapModeUnitSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsReverseVideoSupported,
+            IsStrikethroughSupported,
+            IsSuperscriptSupported,
+            IsSubscriptSupported,
+            IsReversePaperFeedByLineSupported,
+            IsReversePaperFeedByMapModeUnitSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReceiptPrinterCapabilities2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISlipPrintJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Print(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::HSTRING>, printoptions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISlipPrintJob", "Print", 6usize, |this| ((**(this as *const *const ISlipPrintJob_Vtbl)).Print)(this, data, printoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedPaperByLine(this: *mut core::ffi::c_void, linecount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISlipPrintJob", "FeedPaperByLine", 7usize, |this| ((**(this as *const *const ISlipPrintJob_Vtbl)).FeedPaperByLine)(this, linecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedPaperByMapModeUnit(this: *mut core::ffi::c_void, distance: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISlipPrintJob", "FeedPaperByMapModeUnit", 8usize, |this| ((**(this as *const *const ISlipPrintJob_Vtbl)).FeedPaperByMapModeUnit)(this, distance), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Print, FeedPaperByLine, FeedPaperByMapModeUnit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISlipPrintJob as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISlipPrinterCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsFullLengthSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISlipPrinterCapabilities", "IsFullLengthSupported", 6usize, |this| ((**(this as *const *const ISlipPrinterCapabilities_Vtbl)).IsFullLengthSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBothSidesPrintingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISlipPrinterCapabilities", "IsBothSidesPrintingSupported", 7usize, |this| ((**(this as *const *const ISlipPrinterCapabilities_Vtbl)).IsBothSidesPrintingSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsFullLengthSupported, IsBothSidesPrintingSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISlipPrinterCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISlipPrinterCapabilities2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsReverseVideoSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISlipPrinterCapabilities2", "IsReverseVideoSupported", 6usize, |this| ((**(this as *const *const ISlipPrinterCapabilities2_Vtbl)).IsReverseVideoSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStrikethroughSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISlipPrinterCapabilities2", "IsStrikethroughSupported", 7usize, |this| ((**(this as *const *const ISlipPrinterCapabilities2_Vtbl)).IsStrikethroughSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSuperscriptSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISlipPrinterCapabilities2", "IsSuperscriptSupported", 8usize, |this| ((**(this as *const *const ISlipPrinterCapabilities2_Vtbl)).IsSuperscriptSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSubscriptSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISlipPrinterCapabilities2", "IsSubscriptSupported", 9usize, |this| ((**(this as *const *const ISlipPrinterCapabilities2_Vtbl)).IsSubscriptSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReversePaperFeedByLineSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISlipPrinterCapabilities2", "IsReversePaperFeedByLineSupported", 10usize, |this| ((**(this as *const *const ISlipPrinterCapabilities2_Vtbl)).IsReversePaperFeedByLineSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReversePaperFeedByMapModeUnitSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISlipPrinterCapabilities2", "IsReversePaperFeedByMapModeUnitSupported", 11usize, |this| ((**(this as *const *const ISlipPrinterCapabilities2_Vtbl)).IsReversePaperFeedByMapModeUnitSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsReverseVideoSupported,
+            IsStrikethroughSupported,
+            IsSuperscriptSupported,
+            IsSubscriptSupported,
+            IsReversePaperFeedByLineSupported,
+            IsReversePaperFeedByMapModeUnitSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISlipPrinterCapabilities2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUnifiedPosErrorData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnifiedPosErrorData", "Message", 6usize, |this| ((**(this as *const *const IUnifiedPosErrorData_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Severity(this: *mut core::ffi::c_void, result__: *mut UnifiedPosErrorSeverity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnifiedPosErrorData", "Severity", 7usize, |this| ((**(this as *const *const IUnifiedPosErrorData_Vtbl)).Severity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut UnifiedPosErrorReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnifiedPosErrorData", "Reason", 8usize, |this| ((**(this as *const *const IUnifiedPosErrorData_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedReason(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnifiedPosErrorData", "ExtendedReason", 9usize, |this| ((**(this as *const *const IUnifiedPosErrorData_Vtbl)).ExtendedReason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Message, Severity, Reason, ExtendedReason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUnifiedPosErrorData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUnifiedPosErrorDataFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, message: core::mem::MaybeUninit<windows_core::HSTRING>, severity: UnifiedPosErrorSeverity, reason: UnifiedPosErrorReason, extendedreason: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnifiedPosErrorDataFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IUnifiedPosErrorDataFactory_Vtbl)).CreateInstance)(this, message, severity, reason, extendedreason, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUnifiedPosErrorDataFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
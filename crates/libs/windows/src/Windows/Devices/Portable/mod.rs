@@ -83,6 +83,7 @@ impl ServiceDeviceType {
 impl windows_core::TypeKind for ServiceDeviceType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ServiceDeviceType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ServiceDeviceType").field(&self.0).finish()
@@ -91,3 +92,5 @@ impl core::fmt::Debug for ServiceDeviceType {
 impl windows_core::RuntimeType for ServiceDeviceType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Portable.ServiceDeviceType;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
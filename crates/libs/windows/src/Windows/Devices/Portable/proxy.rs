@@ -0,0 +1,39 @@
+impl windows_core::imp::ProxyVtable for IServiceDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, servicetype: ServiceDeviceType, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceDeviceStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IServiceDeviceStatics_Vtbl)).GetDeviceSelector)(this, servicetype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromServiceId(this: *mut core::ffi::c_void, serviceid: windows_core::GUID, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceDeviceStatics", "GetDeviceSelectorFromServiceId", 7usize, |this| ((**(this as *const *const IServiceDeviceStatics_Vtbl)).GetDeviceSelectorFromServiceId)(this, serviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, GetDeviceSelectorFromServiceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IStorageDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn FromId(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageDeviceStatics", "FromId", 6usize, |this| ((**(this as *const *const IStorageDeviceStatics_Vtbl)).FromId)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageDeviceStatics", "GetDeviceSelector", 7usize, |this| ((**(this as *const *const IStorageDeviceStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            FromId,
+            #[cfg(not(feature = "Storage"))]
+            FromId: 0,
+            GetDeviceSelector,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -290,3 +290,5 @@ impl windows_core::RuntimeName for PowerGridForecast {
 }
 unsafe impl Send for PowerGridForecast {}
 unsafe impl Sync for PowerGridForecast {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
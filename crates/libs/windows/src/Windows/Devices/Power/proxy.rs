@@ -0,0 +1,136 @@
+impl windows_core::imp::ProxyVtable for IBattery_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBattery", "DeviceId", 6usize, |this| ((**(this as *const *const IBattery_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBattery", "GetReport", 7usize, |this| ((**(this as *const *const IBattery_Vtbl)).GetReport)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBattery", "ReportUpdated", 8usize, |this| ((**(this as *const *const IBattery_Vtbl)).ReportUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReportUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBattery", "RemoveReportUpdated", 9usize, |this| ((**(this as *const *const IBattery_Vtbl)).RemoveReportUpdated)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId, GetReport, ReportUpdated, RemoveReportUpdated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBattery as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System_Power")]
+impl windows_core::imp::ProxyVtable for IBatteryReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChargeRateInMilliwatts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBatteryReport", "ChargeRateInMilliwatts", 6usize, |this| ((**(this as *const *const IBatteryReport_Vtbl)).ChargeRateInMilliwatts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesignCapacityInMilliwattHours(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBatteryReport", "DesignCapacityInMilliwattHours", 7usize, |this| ((**(this as *const *const IBatteryReport_Vtbl)).DesignCapacityInMilliwattHours)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FullChargeCapacityInMilliwattHours(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBatteryReport", "FullChargeCapacityInMilliwattHours", 8usize, |this| ((**(this as *const *const IBatteryReport_Vtbl)).FullChargeCapacityInMilliwattHours)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemainingCapacityInMilliwattHours(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBatteryReport", "RemainingCapacityInMilliwattHours", 9usize, |this| ((**(this as *const *const IBatteryReport_Vtbl)).RemainingCapacityInMilliwattHours)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System_Power")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut super::super::System::Power::BatteryStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBatteryReport", "Status", 10usize, |this| ((**(this as *const *const IBatteryReport_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChargeRateInMilliwatts,
+            DesignCapacityInMilliwattHours,
+            FullChargeCapacityInMilliwattHours,
+            RemainingCapacityInMilliwattHours,
+            #[cfg(feature = "System_Power")]
+            Status,
+            #[cfg(not(feature = "System_Power"))]
+            Status: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBatteryReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBatteryStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AggregateBattery(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBatteryStatics", "AggregateBattery", 6usize, |this| ((**(this as *const *const IBatteryStatics_Vtbl)).AggregateBattery)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBatteryStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const IBatteryStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBatteryStatics", "GetDeviceSelector", 8usize, |this| ((**(this as *const *const IBatteryStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AggregateBattery, FromIdAsync, GetDeviceSelector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBatteryStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPowerGridData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Severity(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerGridData", "Severity", 6usize, |this| ((**(this as *const *const IPowerGridData_Vtbl)).Severity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLowUserExperienceImpact(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerGridData", "IsLowUserExperienceImpact", 7usize, |this| ((**(this as *const *const IPowerGridData_Vtbl)).IsLowUserExperienceImpact)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Severity, IsLowUserExperienceImpact }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPowerGridData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPowerGridForecast_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerGridForecast", "StartTime", 6usize, |this| ((**(this as *const *const IPowerGridForecast_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlockDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerGridForecast", "BlockDuration", 7usize, |this| ((**(this as *const *const IPowerGridForecast_Vtbl)).BlockDuration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Forecast(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerGridForecast", "Forecast", 8usize, |this| ((**(this as *const *const IPowerGridForecast_Vtbl)).Forecast)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartTime,
+            BlockDuration,
+            #[cfg(feature = "Foundation_Collections")]
+            Forecast,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Forecast: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPowerGridForecast as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPowerGridForecastStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForecast(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerGridForecastStatics", "GetForecast", 6usize, |this| ((**(this as *const *const IPowerGridForecastStatics_Vtbl)).GetForecast)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForecastUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerGridForecastStatics", "ForecastUpdated", 7usize, |this| ((**(this as *const *const IPowerGridForecastStatics_Vtbl)).ForecastUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveForecastUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerGridForecastStatics", "RemoveForecastUpdated", 8usize, |this| ((**(this as *const *const IPowerGridForecastStatics_Vtbl)).RemoveForecastUpdated)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForecast, ForecastUpdated, RemoveForecastUpdated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPowerGridForecastStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
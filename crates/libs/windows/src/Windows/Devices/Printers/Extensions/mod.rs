@@ -429,6 +429,7 @@ impl Print3DWorkflowDetail {
 impl windows_core::TypeKind for Print3DWorkflowDetail {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Print3DWorkflowDetail {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Print3DWorkflowDetail").field(&self.0).finish()
@@ -450,6 +451,7 @@ impl Print3DWorkflowStatus {
 impl windows_core::TypeKind for Print3DWorkflowStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Print3DWorkflowStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Print3DWorkflowStatus").field(&self.0).finish()
@@ -458,3 +460,5 @@ impl core::fmt::Debug for Print3DWorkflowStatus {
 impl windows_core::RuntimeType for Print3DWorkflowStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Printers.Extensions.Print3DWorkflowStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
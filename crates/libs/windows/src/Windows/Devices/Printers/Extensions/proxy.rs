@@ -0,0 +1,176 @@
+impl windows_core::imp::ProxyVtable for IPrint3DWorkflow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceID(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DWorkflow", "DeviceID", 6usize, |this| ((**(this as *const *const IPrint3DWorkflow_Vtbl)).DeviceID)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrintModelPackage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DWorkflow", "GetPrintModelPackage", 7usize, |this| ((**(this as *const *const IPrint3DWorkflow_Vtbl)).GetPrintModelPackage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPrintReady(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DWorkflow", "IsPrintReady", 8usize, |this| ((**(this as *const *const IPrint3DWorkflow_Vtbl)).IsPrintReady)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsPrintReady(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DWorkflow", "SetIsPrintReady", 9usize, |this| ((**(this as *const *const IPrint3DWorkflow_Vtbl)).SetIsPrintReady)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintRequested(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DWorkflow", "PrintRequested", 10usize, |this| ((**(this as *const *const IPrint3DWorkflow_Vtbl)).PrintRequested)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePrintRequested(this: *mut core::ffi::c_void, eventcookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DWorkflow", "RemovePrintRequested", 11usize, |this| ((**(this as *const *const IPrint3DWorkflow_Vtbl)).RemovePrintRequested)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceID,
+            GetPrintModelPackage,
+            IsPrintReady,
+            SetIsPrintReady,
+            PrintRequested,
+            RemovePrintRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint3DWorkflow as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrint3DWorkflow2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrinterChanged(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DWorkflow2", "PrinterChanged", 6usize, |this| ((**(this as *const *const IPrint3DWorkflow2_Vtbl)).PrinterChanged)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePrinterChanged(this: *mut core::ffi::c_void, eventcookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DWorkflow2", "RemovePrinterChanged", 7usize, |this| ((**(this as *const *const IPrint3DWorkflow2_Vtbl)).RemovePrinterChanged)(this, eventcookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrinterChanged, RemovePrinterChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint3DWorkflow2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrint3DWorkflowPrintRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut Print3DWorkflowStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DWorkflowPrintRequestedEventArgs", "Status", 6usize, |this| ((**(this as *const *const IPrint3DWorkflowPrintRequestedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtendedStatus(this: *mut core::ffi::c_void, value: Print3DWorkflowDetail) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DWorkflowPrintRequestedEventArgs", "SetExtendedStatus", 7usize, |this| ((**(this as *const *const IPrint3DWorkflowPrintRequestedEventArgs_Vtbl)).SetExtendedStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DWorkflowPrintRequestedEventArgs", "SetSource", 8usize, |this| ((**(this as *const *const IPrint3DWorkflowPrintRequestedEventArgs_Vtbl)).SetSource)(this, source), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceChanged(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DWorkflowPrintRequestedEventArgs", "SetSourceChanged", 9usize, |this| ((**(this as *const *const IPrint3DWorkflowPrintRequestedEventArgs_Vtbl)).SetSourceChanged)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, SetExtendedStatus, SetSource, SetSourceChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint3DWorkflowPrintRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrint3DWorkflowPrinterChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NewDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DWorkflowPrinterChangedEventArgs", "NewDeviceId", 6usize, |this| ((**(this as *const *const IPrint3DWorkflowPrinterChangedEventArgs_Vtbl)).NewDeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NewDeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint3DWorkflowPrinterChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintExtensionContextStatic_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromDeviceId(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintExtensionContextStatic", "FromDeviceId", 6usize, |this| ((**(this as *const *const IPrintExtensionContextStatic_Vtbl)).FromDeviceId)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromDeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintExtensionContextStatic as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintNotificationEventDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrinterName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintNotificationEventDetails", "PrinterName", 6usize, |this| ((**(this as *const *const IPrintNotificationEventDetails_Vtbl)).PrinterName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintNotificationEventDetails", "EventData", 7usize, |this| ((**(this as *const *const IPrintNotificationEventDetails_Vtbl)).EventData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventData(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintNotificationEventDetails", "SetEventData", 8usize, |this| ((**(this as *const *const IPrintNotificationEventDetails_Vtbl)).SetEventData)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrinterName, EventData, SetEventData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintNotificationEventDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrinterExtensionContext(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskConfiguration", "PrinterExtensionContext", 6usize, |this| ((**(this as *const *const IPrintTaskConfiguration_Vtbl)).PrinterExtensionContext)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveRequested(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskConfiguration", "SaveRequested", 7usize, |this| ((**(this as *const *const IPrintTaskConfiguration_Vtbl)).SaveRequested)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSaveRequested(this: *mut core::ffi::c_void, eventcookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskConfiguration", "RemoveSaveRequested", 8usize, |this| ((**(this as *const *const IPrintTaskConfiguration_Vtbl)).RemoveSaveRequested)(this, eventcookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrinterExtensionContext, SaveRequested, RemoveSaveRequested }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskConfigurationSaveRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskConfigurationSaveRequest", "Cancel", 6usize, |this| ((**(this as *const *const IPrintTaskConfigurationSaveRequest_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, printerextensioncontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskConfigurationSaveRequest", "Save", 7usize, |this| ((**(this as *const *const IPrintTaskConfigurationSaveRequest_Vtbl)).Save)(this, printerextensioncontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskConfigurationSaveRequest", "GetDeferral", 8usize, |this| ((**(this as *const *const IPrintTaskConfigurationSaveRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskConfigurationSaveRequest", "Deadline", 9usize, |this| ((**(this as *const *const IPrintTaskConfigurationSaveRequest_Vtbl)).Deadline)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cancel, Save, GetDeferral, Deadline }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskConfigurationSaveRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskConfigurationSaveRequestedDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskConfigurationSaveRequestedDeferral", "Complete", 6usize, |this| ((**(this as *const *const IPrintTaskConfigurationSaveRequestedDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskConfigurationSaveRequestedDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskConfigurationSaveRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskConfigurationSaveRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const IPrintTaskConfigurationSaveRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskConfigurationSaveRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
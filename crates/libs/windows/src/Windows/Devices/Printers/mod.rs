@@ -1572,6 +1572,7 @@ impl IppAttributeErrorReason {
 impl windows_core::TypeKind for IppAttributeErrorReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IppAttributeErrorReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IppAttributeErrorReason").field(&self.0).finish()
@@ -1609,6 +1610,7 @@ impl IppAttributeValueKind {
 impl windows_core::TypeKind for IppAttributeValueKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IppAttributeValueKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IppAttributeValueKind").field(&self.0).finish()
@@ -1628,6 +1630,7 @@ impl IppPrintDeviceKind {
 impl windows_core::TypeKind for IppPrintDeviceKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IppPrintDeviceKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IppPrintDeviceKind").field(&self.0).finish()
@@ -1646,6 +1649,7 @@ impl IppResolutionUnit {
 impl windows_core::TypeKind for IppResolutionUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IppResolutionUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IppResolutionUnit").field(&self.0).finish()
@@ -1664,6 +1668,7 @@ impl PageConfigurationSource {
 impl windows_core::TypeKind for PageConfigurationSource {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PageConfigurationSource {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PageConfigurationSource").field(&self.0).finish()
@@ -1672,3 +1677,5 @@ impl core::fmt::Debug for PageConfigurationSource {
 impl windows_core::RuntimeType for PageConfigurationSource {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Printers.PageConfigurationSource;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,854 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IIppAttributeError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut IppAttributeErrorReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeError", "Reason", 6usize, |this| ((**(this as *const *const IIppAttributeError_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeError", "ExtendedError", 7usize, |this| ((**(this as *const *const IIppAttributeError_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetUnsupportedValues(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeError", "GetUnsupportedValues", 8usize, |this| ((**(this as *const *const IIppAttributeError_Vtbl)).GetUnsupportedValues)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Reason,
+            ExtendedError,
+            #[cfg(feature = "Foundation_Collections")]
+            GetUnsupportedValues,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetUnsupportedValues: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIppAttributeError as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IIppAttributeValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut IppAttributeValueKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "Kind", 6usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetIntegerArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetIntegerArray", 7usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetIntegerArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetBooleanArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetBooleanArray", 8usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetBooleanArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetEnumArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetEnumArray", 9usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetEnumArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GetOctetStringArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetOctetStringArray", 10usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetOctetStringArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetDateTimeArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetDateTimeArray", 11usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetDateTimeArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetResolutionArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetResolutionArray", 12usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetResolutionArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetRangeOfIntegerArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetRangeOfIntegerArray", 13usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetRangeOfIntegerArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCollectionArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetCollectionArray", 14usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetCollectionArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetTextWithLanguageArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetTextWithLanguageArray", 15usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetTextWithLanguageArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetNameWithLanguageArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetNameWithLanguageArray", 16usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetNameWithLanguageArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetTextWithoutLanguageArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetTextWithoutLanguageArray", 17usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetTextWithoutLanguageArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetNameWithoutLanguageArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetNameWithoutLanguageArray", 18usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetNameWithoutLanguageArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetKeywordArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetKeywordArray", 19usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetKeywordArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetUriArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetUriArray", 20usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetUriArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetUriSchemaArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetUriSchemaArray", 21usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetUriSchemaArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCharsetArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetCharsetArray", 22usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetCharsetArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetNaturalLanguageArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetNaturalLanguageArray", 23usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetNaturalLanguageArray)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetMimeMediaTypeArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValue", "GetMimeMediaTypeArray", 24usize, |this| ((**(this as *const *const IIppAttributeValue_Vtbl)).GetMimeMediaTypeArray)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Kind,
+            #[cfg(feature = "Foundation_Collections")]
+            GetIntegerArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetIntegerArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetBooleanArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetBooleanArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetEnumArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetEnumArray: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            GetOctetStringArray,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            GetOctetStringArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetDateTimeArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetDateTimeArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetResolutionArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetResolutionArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetRangeOfIntegerArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetRangeOfIntegerArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCollectionArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCollectionArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetTextWithLanguageArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetTextWithLanguageArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetNameWithLanguageArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetNameWithLanguageArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetTextWithoutLanguageArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetTextWithoutLanguageArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetNameWithoutLanguageArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetNameWithoutLanguageArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetKeywordArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetKeywordArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetUriArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetUriArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetUriSchemaArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetUriSchemaArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCharsetArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCharsetArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetNaturalLanguageArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetNaturalLanguageArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetMimeMediaTypeArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetMimeMediaTypeArray: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIppAttributeValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IIppAttributeValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateUnsupported(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateUnsupported", 6usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateUnsupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUnknown(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateUnknown", 7usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateUnknown)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNoValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateNoValue", 8usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateNoValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInteger(this: *mut core::ffi::c_void, value: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateInteger", 9usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateInteger)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateIntegerArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateIntegerArray", 10usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateIntegerArray)(this, values, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBoolean(this: *mut core::ffi::c_void, value: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateBoolean", 11usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateBoolean)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateBooleanArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateBooleanArray", 12usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateBooleanArray)(this, values, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEnum(this: *mut core::ffi::c_void, value: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateEnum", 13usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateEnum)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateEnumArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateEnumArray", 14usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateEnumArray)(this, values, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateOctetString(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateOctetString", 15usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateOctetString)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn CreateOctetStringArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateOctetStringArray", 16usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateOctetStringArray)(this, values, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDateTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateDateTime", 17usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateDateTime)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateDateTimeArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateDateTimeArray", 18usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateDateTimeArray)(this, values, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateResolution(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateResolution", 19usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateResolution)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateResolutionArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateResolutionArray", 20usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateResolutionArray)(this, values, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRangeOfInteger(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateRangeOfInteger", 21usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateRangeOfInteger)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateRangeOfIntegerArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateRangeOfIntegerArray", 22usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateRangeOfIntegerArray)(this, values, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateCollection(this: *mut core::ffi::c_void, memberattributes: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateCollection", 23usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateCollection)(this, memberattributes, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateCollectionArray(this: *mut core::ffi::c_void, memberattributesarray: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateCollectionArray", 24usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateCollectionArray)(this, memberattributesarray, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTextWithLanguage(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateTextWithLanguage", 25usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateTextWithLanguage)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateTextWithLanguageArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateTextWithLanguageArray", 26usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateTextWithLanguageArray)(this, values, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNameWithLanguage(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateNameWithLanguage", 27usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateNameWithLanguage)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateNameWithLanguageArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateNameWithLanguageArray", 28usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateNameWithLanguageArray)(this, values, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTextWithoutLanguage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateTextWithoutLanguage", 29usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateTextWithoutLanguage)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateTextWithoutLanguageArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateTextWithoutLanguageArray", 30usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateTextWithoutLanguageArray)(this, values, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNameWithoutLanguage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateNameWithoutLanguage", 31usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateNameWithoutLanguage)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateNameWithoutLanguageArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateNameWithoutLanguageArray", 32usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateNameWithoutLanguageArray)(this, values, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateKeyword(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateKeyword", 33usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateKeyword)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateKeywordArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateKeywordArray", 34usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateKeywordArray)(this, values, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateUri", 35usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateUri)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateUriArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateUriArray", 36usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateUriArray)(this, values, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUriSchema(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateUriSchema", 37usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateUriSchema)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateUriSchemaArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateUriSchemaArray", 38usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateUriSchemaArray)(this, values, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCharset(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateCharset", 39usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateCharset)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateCharsetArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateCharsetArray", 40usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateCharsetArray)(this, values, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNaturalLanguage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateNaturalLanguage", 41usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateNaturalLanguage)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateNaturalLanguageArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateNaturalLanguageArray", 42usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateNaturalLanguageArray)(this, values, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMimeMedia(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateMimeMedia", 43usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateMimeMedia)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateMimeMediaArray(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppAttributeValueStatics", "CreateMimeMediaArray", 44usize, |this| ((**(this as *const *const IIppAttributeValueStatics_Vtbl)).CreateMimeMediaArray)(this, values, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateUnsupported,
+            CreateUnknown,
+            CreateNoValue,
+            CreateInteger,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateIntegerArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateIntegerArray: 0,
+            CreateBoolean,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateBooleanArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateBooleanArray: 0,
+            CreateEnum,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateEnumArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateEnumArray: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateOctetString,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateOctetString: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            CreateOctetStringArray,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            CreateOctetStringArray: 0,
+            CreateDateTime,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateDateTimeArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateDateTimeArray: 0,
+            CreateResolution,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateResolutionArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateResolutionArray: 0,
+            CreateRangeOfInteger,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateRangeOfIntegerArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateRangeOfIntegerArray: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateCollection,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateCollection: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateCollectionArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateCollectionArray: 0,
+            CreateTextWithLanguage,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateTextWithLanguageArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateTextWithLanguageArray: 0,
+            CreateNameWithLanguage,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateNameWithLanguageArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateNameWithLanguageArray: 0,
+            CreateTextWithoutLanguage,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateTextWithoutLanguageArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateTextWithoutLanguageArray: 0,
+            CreateNameWithoutLanguage,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateNameWithoutLanguageArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateNameWithoutLanguageArray: 0,
+            CreateKeyword,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateKeywordArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateKeywordArray: 0,
+            CreateUri,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateUriArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateUriArray: 0,
+            CreateUriSchema,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateUriSchemaArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateUriSchemaArray: 0,
+            CreateCharset,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateCharsetArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateCharsetArray: 0,
+            CreateNaturalLanguage,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateNaturalLanguageArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateNaturalLanguageArray: 0,
+            CreateMimeMedia,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateMimeMediaArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateMimeMediaArray: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIppAttributeValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIppIntegerRange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppIntegerRange", "Start", 6usize, |this| ((**(this as *const *const IIppIntegerRange_Vtbl)).Start)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppIntegerRange", "End", 7usize, |this| ((**(this as *const *const IIppIntegerRange_Vtbl)).End)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, End }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIppIntegerRange as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIppIntegerRangeFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, start: i32, end: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppIntegerRangeFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IIppIntegerRangeFactory_Vtbl)).CreateInstance)(this, start, end, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIppIntegerRangeFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IIppPrintDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrinterName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice", "PrinterName", 6usize, |this| ((**(this as *const *const IIppPrintDevice_Vtbl)).PrinterName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrinterUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice", "PrinterUri", 7usize, |this| ((**(this as *const *const IIppPrintDevice_Vtbl)).PrinterUri)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GetPrinterAttributesAsBuffer(this: *mut core::ffi::c_void, attributenames: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice", "GetPrinterAttributesAsBuffer", 8usize, |this| ((**(this as *const *const IIppPrintDevice_Vtbl)).GetPrinterAttributesAsBuffer)(this, attributenames, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetPrinterAttributes(this: *mut core::ffi::c_void, attributenames: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice", "GetPrinterAttributes", 9usize, |this| ((**(this as *const *const IIppPrintDevice_Vtbl)).GetPrinterAttributes)(this, attributenames, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetPrinterAttributesFromBuffer(this: *mut core::ffi::c_void, printerattributesbuffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice", "SetPrinterAttributesFromBuffer", 10usize, |this| ((**(this as *const *const IIppPrintDevice_Vtbl)).SetPrinterAttributesFromBuffer)(this, printerattributesbuffer, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetPrinterAttributes(this: *mut core::ffi::c_void, printerattributes: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice", "SetPrinterAttributes", 11usize, |this| ((**(this as *const *const IIppPrintDevice_Vtbl)).SetPrinterAttributes)(this, printerattributes, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PrinterName,
+            PrinterUri,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            GetPrinterAttributesAsBuffer,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            GetPrinterAttributesAsBuffer: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetPrinterAttributes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetPrinterAttributes: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetPrinterAttributesFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetPrinterAttributesFromBuffer: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetPrinterAttributes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetPrinterAttributes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIppPrintDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIppPrintDevice2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMaxSupportedPdfSize(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice2", "GetMaxSupportedPdfSize", 6usize, |this| ((**(this as *const *const IIppPrintDevice2_Vtbl)).GetMaxSupportedPdfSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxSupportedPdfVersion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice2", "GetMaxSupportedPdfVersion", 7usize, |this| ((**(this as *const *const IIppPrintDevice2_Vtbl)).GetMaxSupportedPdfVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPdlPassthroughSupported(this: *mut core::ffi::c_void, pdlcontenttype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice2", "IsPdlPassthroughSupported", 8usize, |this| ((**(this as *const *const IIppPrintDevice2_Vtbl)).IsPdlPassthroughSupported)(this, pdlcontenttype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPdlPassthroughProvider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice2", "GetPdlPassthroughProvider", 9usize, |this| ((**(this as *const *const IIppPrintDevice2_Vtbl)).GetPdlPassthroughProvider)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMaxSupportedPdfSize,
+            GetMaxSupportedPdfVersion,
+            IsPdlPassthroughSupported,
+            GetPdlPassthroughProvider,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIppPrintDevice2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIppPrintDevice3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsIppFaxOutPrinter(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice3", "IsIppFaxOutPrinter", 6usize, |this| ((**(this as *const *const IIppPrintDevice3_Vtbl)).IsIppFaxOutPrinter)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsIppFaxOutPrinter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIppPrintDevice3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Printing_PrintTicket")]
+impl windows_core::imp::ProxyVtable for IIppPrintDevice4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceKind(this: *mut core::ffi::c_void, result__: *mut IppPrintDeviceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice4", "DeviceKind", 6usize, |this| ((**(this as *const *const IIppPrintDevice4_Vtbl)).DeviceKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanModifyUserDefaultPrintTicket(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice4", "CanModifyUserDefaultPrintTicket", 7usize, |this| ((**(this as *const *const IIppPrintDevice4_Vtbl)).CanModifyUserDefaultPrintTicket)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Printing_PrintTicket")]
+        unsafe extern "system" fn UserDefaultPrintTicket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice4", "UserDefaultPrintTicket", 8usize, |this| ((**(this as *const *const IIppPrintDevice4_Vtbl)).UserDefaultPrintTicket)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Printing_PrintTicket")]
+        unsafe extern "system" fn SetUserDefaultPrintTicket(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice4", "SetUserDefaultPrintTicket", 9usize, |this| ((**(this as *const *const IIppPrintDevice4_Vtbl)).SetUserDefaultPrintTicket)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefreshPrintDeviceCapabilities(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice4", "RefreshPrintDeviceCapabilities", 10usize, |this| ((**(this as *const *const IIppPrintDevice4_Vtbl)).RefreshPrintDeviceCapabilities)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxSupportedPdlVersion(this: *mut core::ffi::c_void, pdlcontenttype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDevice4", "GetMaxSupportedPdlVersion", 11usize, |this| ((**(this as *const *const IIppPrintDevice4_Vtbl)).GetMaxSupportedPdlVersion)(this, pdlcontenttype, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceKind,
+            CanModifyUserDefaultPrintTicket,
+            #[cfg(feature = "Graphics_Printing_PrintTicket")]
+            UserDefaultPrintTicket,
+            #[cfg(not(feature = "Graphics_Printing_PrintTicket"))]
+            UserDefaultPrintTicket: 0,
+            #[cfg(feature = "Graphics_Printing_PrintTicket")]
+            SetUserDefaultPrintTicket,
+            #[cfg(not(feature = "Graphics_Printing_PrintTicket"))]
+            SetUserDefaultPrintTicket: 0,
+            RefreshPrintDeviceCapabilities,
+            GetMaxSupportedPdlVersion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIppPrintDevice4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIppPrintDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDeviceStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IIppPrintDeviceStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromId(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDeviceStatics", "FromId", 7usize, |this| ((**(this as *const *const IIppPrintDeviceStatics_Vtbl)).FromId)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromPrinterName(this: *mut core::ffi::c_void, printername: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDeviceStatics", "FromPrinterName", 8usize, |this| ((**(this as *const *const IIppPrintDeviceStatics_Vtbl)).FromPrinterName)(this, printername, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIppPrinter(this: *mut core::ffi::c_void, printername: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppPrintDeviceStatics", "IsIppPrinter", 9usize, |this| ((**(this as *const *const IIppPrintDeviceStatics_Vtbl)).IsIppPrinter)(this, printername, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromId, FromPrinterName, IsIppPrinter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIppPrintDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIppResolution_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppResolution", "Width", 6usize, |this| ((**(this as *const *const IIppResolution_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppResolution", "Height", 7usize, |this| ((**(this as *const *const IIppResolution_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unit(this: *mut core::ffi::c_void, result__: *mut IppResolutionUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppResolution", "Unit", 8usize, |this| ((**(this as *const *const IIppResolution_Vtbl)).Unit)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Width, Height, Unit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIppResolution as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIppResolutionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, width: i32, height: i32, unit: IppResolutionUnit, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppResolutionFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IIppResolutionFactory_Vtbl)).CreateInstance)(this, width, height, unit, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIppResolutionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IIppSetAttributesResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Succeeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppSetAttributesResult", "Succeeded", 6usize, |this| ((**(this as *const *const IIppSetAttributesResult_Vtbl)).Succeeded)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AttributeErrors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppSetAttributesResult", "AttributeErrors", 7usize, |this| ((**(this as *const *const IIppSetAttributesResult_Vtbl)).AttributeErrors)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Succeeded,
+            #[cfg(feature = "Foundation_Collections")]
+            AttributeErrors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AttributeErrors: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIppSetAttributesResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIppTextWithLanguage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppTextWithLanguage", "Language", 6usize, |this| ((**(this as *const *const IIppTextWithLanguage_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppTextWithLanguage", "Value", 7usize, |this| ((**(this as *const *const IIppTextWithLanguage_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Language, Value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIppTextWithLanguage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIppTextWithLanguageFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, language: core::mem::MaybeUninit<windows_core::HSTRING>, text: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIppTextWithLanguageFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IIppTextWithLanguageFactory_Vtbl)).CreateInstance)(this, language, text, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIppTextWithLanguageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPageConfigurationSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OrientationSource(this: *mut core::ffi::c_void, result__: *mut PageConfigurationSource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPageConfigurationSettings", "OrientationSource", 6usize, |this| ((**(this as *const *const IPageConfigurationSettings_Vtbl)).OrientationSource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOrientationSource(this: *mut core::ffi::c_void, value: PageConfigurationSource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPageConfigurationSettings", "SetOrientationSource", 7usize, |this| ((**(this as *const *const IPageConfigurationSettings_Vtbl)).SetOrientationSource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeSource(this: *mut core::ffi::c_void, result__: *mut PageConfigurationSource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPageConfigurationSettings", "SizeSource", 8usize, |this| ((**(this as *const *const IPageConfigurationSettings_Vtbl)).SizeSource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSizeSource(this: *mut core::ffi::c_void, value: PageConfigurationSource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPageConfigurationSettings", "SetSizeSource", 9usize, |this| ((**(this as *const *const IPageConfigurationSettings_Vtbl)).SetSizeSource)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OrientationSource,
+            SetOrientationSource,
+            SizeSource,
+            SetSizeSource,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPageConfigurationSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Printing", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IPdlPassthroughProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedPdlContentTypes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdlPassthroughProvider", "SupportedPdlContentTypes", 6usize, |this| ((**(this as *const *const IPdlPassthroughProvider_Vtbl)).SupportedPdlContentTypes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Printing")]
+        unsafe extern "system" fn StartPrintJobWithTaskOptions(this: *mut core::ffi::c_void, jobname: core::mem::MaybeUninit<windows_core::HSTRING>, pdlcontenttype: core::mem::MaybeUninit<windows_core::HSTRING>, taskoptions: *mut core::ffi::c_void, pageconfigurationsettings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdlPassthroughProvider", "StartPrintJobWithTaskOptions", 7usize, |this| ((**(this as *const *const IPdlPassthroughProvider_Vtbl)).StartPrintJobWithTaskOptions)(this, jobname, pdlcontenttype, taskoptions, pageconfigurationsettings, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn StartPrintJobWithPrintTicket(this: *mut core::ffi::c_void, jobname: core::mem::MaybeUninit<windows_core::HSTRING>, pdlcontenttype: core::mem::MaybeUninit<windows_core::HSTRING>, printticket: *mut core::ffi::c_void, pageconfigurationsettings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdlPassthroughProvider", "StartPrintJobWithPrintTicket", 8usize, |this| ((**(this as *const *const IPdlPassthroughProvider_Vtbl)).StartPrintJobWithPrintTicket)(this, jobname, pdlcontenttype, printticket, pageconfigurationsettings, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedPdlContentTypes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedPdlContentTypes: 0,
+            #[cfg(feature = "Graphics_Printing")]
+            StartPrintJobWithTaskOptions,
+            #[cfg(not(feature = "Graphics_Printing"))]
+            StartPrintJobWithTaskOptions: 0,
+            #[cfg(feature = "Storage_Streams")]
+            StartPrintJobWithPrintTicket,
+            #[cfg(not(feature = "Storage_Streams"))]
+            StartPrintJobWithPrintTicket: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPdlPassthroughProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPdlPassthroughTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrintJobId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdlPassthroughTarget", "PrintJobId", 6usize, |this| ((**(this as *const *const IPdlPassthroughTarget_Vtbl)).PrintJobId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetOutputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdlPassthroughTarget", "GetOutputStream", 7usize, |this| ((**(this as *const *const IPdlPassthroughTarget_Vtbl)).GetOutputStream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Submit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdlPassthroughTarget", "Submit", 8usize, |this| ((**(this as *const *const IPdlPassthroughTarget_Vtbl)).Submit)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PrintJobId,
+            #[cfg(feature = "Storage_Streams")]
+            GetOutputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetOutputStream: 0,
+            Submit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPdlPassthroughTarget as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrint3DDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrintSchema(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DDevice", "PrintSchema", 6usize, |this| ((**(this as *const *const IPrint3DDevice_Vtbl)).PrintSchema)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrintSchema }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint3DDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrint3DDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DDeviceStatics", "FromIdAsync", 6usize, |this| ((**(this as *const *const IPrint3DDeviceStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DDeviceStatics", "GetDeviceSelector", 7usize, |this| ((**(this as *const *const IPrint3DDeviceStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromIdAsync, GetDeviceSelector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint3DDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPrintSchema_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetDefaultPrintTicketAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchema", "GetDefaultPrintTicketAsync", 6usize, |this| ((**(this as *const *const IPrintSchema_Vtbl)).GetDefaultPrintTicketAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetCapabilitiesAsync(this: *mut core::ffi::c_void, constrainticket: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchema", "GetCapabilitiesAsync", 7usize, |this| ((**(this as *const *const IPrintSchema_Vtbl)).GetCapabilitiesAsync)(this, constrainticket, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn MergeAndValidateWithDefaultPrintTicketAsync(this: *mut core::ffi::c_void, deltaticket: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchema", "MergeAndValidateWithDefaultPrintTicketAsync", 8usize, |this| ((**(this as *const *const IPrintSchema_Vtbl)).MergeAndValidateWithDefaultPrintTicketAsync)(this, deltaticket, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            GetDefaultPrintTicketAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetDefaultPrintTicketAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            GetCapabilitiesAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetCapabilitiesAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            MergeAndValidateWithDefaultPrintTicketAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            MergeAndValidateWithDefaultPrintTicketAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchema as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
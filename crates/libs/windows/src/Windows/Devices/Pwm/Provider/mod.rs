@@ -111,3 +111,5 @@ pub struct IPwmProvider_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
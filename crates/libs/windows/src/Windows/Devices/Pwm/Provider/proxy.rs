@@ -0,0 +1,71 @@
+impl windows_core::imp::ProxyVtable for IPwmControllerProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PinCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmControllerProvider", "PinCount", 6usize, |this| ((**(this as *const *const IPwmControllerProvider_Vtbl)).PinCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActualFrequency(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmControllerProvider", "ActualFrequency", 7usize, |this| ((**(this as *const *const IPwmControllerProvider_Vtbl)).ActualFrequency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredFrequency(this: *mut core::ffi::c_void, frequency: f64, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmControllerProvider", "SetDesiredFrequency", 8usize, |this| ((**(this as *const *const IPwmControllerProvider_Vtbl)).SetDesiredFrequency)(this, frequency, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxFrequency(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmControllerProvider", "MaxFrequency", 9usize, |this| ((**(this as *const *const IPwmControllerProvider_Vtbl)).MaxFrequency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinFrequency(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmControllerProvider", "MinFrequency", 10usize, |this| ((**(this as *const *const IPwmControllerProvider_Vtbl)).MinFrequency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcquirePin(this: *mut core::ffi::c_void, pin: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmControllerProvider", "AcquirePin", 11usize, |this| ((**(this as *const *const IPwmControllerProvider_Vtbl)).AcquirePin)(this, pin), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleasePin(this: *mut core::ffi::c_void, pin: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmControllerProvider", "ReleasePin", 12usize, |this| ((**(this as *const *const IPwmControllerProvider_Vtbl)).ReleasePin)(this, pin), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnablePin(this: *mut core::ffi::c_void, pin: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmControllerProvider", "EnablePin", 13usize, |this| ((**(this as *const *const IPwmControllerProvider_Vtbl)).EnablePin)(this, pin), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisablePin(this: *mut core::ffi::c_void, pin: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmControllerProvider", "DisablePin", 14usize, |this| ((**(this as *const *const IPwmControllerProvider_Vtbl)).DisablePin)(this, pin), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPulseParameters(this: *mut core::ffi::c_void, pin: i32, dutycycle: f64, invertpolarity: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmControllerProvider", "SetPulseParameters", 15usize, |this| ((**(this as *const *const IPwmControllerProvider_Vtbl)).SetPulseParameters)(this, pin, dutycycle, invertpolarity), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PinCount,
+            ActualFrequency,
+            SetDesiredFrequency,
+            MaxFrequency,
+            MinFrequency,
+            AcquirePin,
+            ReleasePin,
+            EnablePin,
+            DisablePin,
+            SetPulseParameters,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPwmControllerProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPwmProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetControllers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmProvider", "GetControllers", 6usize, |this| ((**(this as *const *const IPwmProvider_Vtbl)).GetControllers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetControllers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetControllers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPwmProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -248,6 +248,7 @@ impl PwmPulsePolarity {
 impl windows_core::TypeKind for PwmPulsePolarity {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PwmPulsePolarity {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PwmPulsePolarity").field(&self.0).finish()
@@ -256,3 +257,5 @@ impl core::fmt::Debug for PwmPulsePolarity {
 impl windows_core::RuntimeType for PwmPulsePolarity {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Pwm.PwmPulsePolarity;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
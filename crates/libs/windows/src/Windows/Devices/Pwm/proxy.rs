@@ -0,0 +1,133 @@
+impl windows_core::imp::ProxyVtable for IPwmController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PinCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmController", "PinCount", 6usize, |this| ((**(this as *const *const IPwmController_Vtbl)).PinCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActualFrequency(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmController", "ActualFrequency", 7usize, |this| ((**(this as *const *const IPwmController_Vtbl)).ActualFrequency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredFrequency(this: *mut core::ffi::c_void, desiredfrequency: f64, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmController", "SetDesiredFrequency", 8usize, |this| ((**(this as *const *const IPwmController_Vtbl)).SetDesiredFrequency)(this, desiredfrequency, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinFrequency(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmController", "MinFrequency", 9usize, |this| ((**(this as *const *const IPwmController_Vtbl)).MinFrequency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxFrequency(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmController", "MaxFrequency", 10usize, |this| ((**(this as *const *const IPwmController_Vtbl)).MaxFrequency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenPin(this: *mut core::ffi::c_void, pinnumber: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmController", "OpenPin", 11usize, |this| ((**(this as *const *const IPwmController_Vtbl)).OpenPin)(this, pinnumber, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PinCount,
+            ActualFrequency,
+            SetDesiredFrequency,
+            MinFrequency,
+            MaxFrequency,
+            OpenPin,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPwmController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Pwm_Provider", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IPwmControllerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Devices_Pwm_Provider", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetControllersAsync(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmControllerStatics", "GetControllersAsync", 6usize, |this| ((**(this as *const *const IPwmControllerStatics_Vtbl)).GetControllersAsync)(this, provider, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Devices_Pwm_Provider", feature = "Foundation_Collections"))]
+            GetControllersAsync,
+            #[cfg(not(all(feature = "Devices_Pwm_Provider", feature = "Foundation_Collections")))]
+            GetControllersAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPwmControllerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPwmControllerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmControllerStatics2", "GetDefaultAsync", 6usize, |this| ((**(this as *const *const IPwmControllerStatics2_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefaultAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPwmControllerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPwmControllerStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmControllerStatics3", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IPwmControllerStatics3_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromFriendlyName(this: *mut core::ffi::c_void, friendlyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmControllerStatics3", "GetDeviceSelectorFromFriendlyName", 7usize, |this| ((**(this as *const *const IPwmControllerStatics3_Vtbl)).GetDeviceSelectorFromFriendlyName)(this, friendlyname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmControllerStatics3", "FromIdAsync", 8usize, |this| ((**(this as *const *const IPwmControllerStatics3_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceSelector,
+            GetDeviceSelectorFromFriendlyName,
+            FromIdAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPwmControllerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPwmPin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Controller(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmPin", "Controller", 6usize, |this| ((**(this as *const *const IPwmPin_Vtbl)).Controller)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveDutyCyclePercentage(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmPin", "GetActiveDutyCyclePercentage", 7usize, |this| ((**(this as *const *const IPwmPin_Vtbl)).GetActiveDutyCyclePercentage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActiveDutyCyclePercentage(this: *mut core::ffi::c_void, dutycyclepercentage: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmPin", "SetActiveDutyCyclePercentage", 8usize, |this| ((**(this as *const *const IPwmPin_Vtbl)).SetActiveDutyCyclePercentage)(this, dutycyclepercentage), |result| Some(*result))
+        }
+        unsafe extern "system" fn Polarity(this: *mut core::ffi::c_void, result__: *mut PwmPulsePolarity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmPin", "Polarity", 9usize, |this| ((**(this as *const *const IPwmPin_Vtbl)).Polarity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPolarity(this: *mut core::ffi::c_void, value: PwmPulsePolarity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmPin", "SetPolarity", 10usize, |this| ((**(this as *const *const IPwmPin_Vtbl)).SetPolarity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmPin", "Start", 11usize, |this| ((**(this as *const *const IPwmPin_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmPin", "Stop", 12usize, |this| ((**(this as *const *const IPwmPin_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStarted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPwmPin", "IsStarted", 13usize, |this| ((**(this as *const *const IPwmPin_Vtbl)).IsStarted)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Controller,
+            GetActiveDutyCyclePercentage,
+            SetActiveDutyCyclePercentage,
+            Polarity,
+            SetPolarity,
+            Start,
+            Stop,
+            IsStarted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPwmPin as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
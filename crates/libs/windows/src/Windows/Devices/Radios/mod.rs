@@ -129,6 +129,7 @@ impl RadioAccessStatus {
 impl windows_core::TypeKind for RadioAccessStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RadioAccessStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RadioAccessStatus").field(&self.0).finish()
@@ -150,6 +151,7 @@ impl RadioKind {
 impl windows_core::TypeKind for RadioKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RadioKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RadioKind").field(&self.0).finish()
@@ -170,6 +172,7 @@ impl RadioState {
 impl windows_core::TypeKind for RadioState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RadioState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RadioState").field(&self.0).finish()
@@ -178,3 +181,5 @@ impl core::fmt::Debug for RadioState {
 impl windows_core::RuntimeType for RadioState {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Radios.RadioState;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
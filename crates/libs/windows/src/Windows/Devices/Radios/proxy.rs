@@ -0,0 +1,67 @@
+impl windows_core::imp::ProxyVtable for IRadio_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStateAsync(this: *mut core::ffi::c_void, value: RadioState, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadio", "SetStateAsync", 6usize, |this| ((**(this as *const *const IRadio_Vtbl)).SetStateAsync)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadio", "StateChanged", 7usize, |this| ((**(this as *const *const IRadio_Vtbl)).StateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStateChanged(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadio", "RemoveStateChanged", 8usize, |this| ((**(this as *const *const IRadio_Vtbl)).RemoveStateChanged)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut RadioState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadio", "State", 9usize, |this| ((**(this as *const *const IRadio_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadio", "Name", 10usize, |this| ((**(this as *const *const IRadio_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut RadioKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadio", "Kind", 11usize, |this| ((**(this as *const *const IRadio_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetStateAsync,
+            StateChanged,
+            RemoveStateChanged,
+            State,
+            Name,
+            Kind,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadio as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRadioStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetRadiosAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadioStatics", "GetRadiosAsync", 6usize, |this| ((**(this as *const *const IRadioStatics_Vtbl)).GetRadiosAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadioStatics", "GetDeviceSelector", 7usize, |this| ((**(this as *const *const IRadioStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadioStatics", "FromIdAsync", 8usize, |this| ((**(this as *const *const IRadioStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadioStatics", "RequestAccessAsync", 9usize, |this| ((**(this as *const *const IRadioStatics_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetRadiosAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetRadiosAsync: 0,
+            GetDeviceSelector,
+            FromIdAsync,
+            RequestAccessAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadioStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1179,6 +1179,7 @@ impl ImageScannerAutoCroppingMode {
 impl windows_core::TypeKind for ImageScannerAutoCroppingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ImageScannerAutoCroppingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ImageScannerAutoCroppingMode").field(&self.0).finish()
@@ -1199,6 +1200,7 @@ impl ImageScannerColorMode {
 impl windows_core::TypeKind for ImageScannerColorMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ImageScannerColorMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ImageScannerColorMode").field(&self.0).finish()
@@ -1222,6 +1224,7 @@ impl ImageScannerFormat {
 impl windows_core::TypeKind for ImageScannerFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ImageScannerFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ImageScannerFormat").field(&self.0).finish()
@@ -1242,6 +1245,7 @@ impl ImageScannerScanSource {
 impl windows_core::TypeKind for ImageScannerScanSource {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ImageScannerScanSource {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ImageScannerScanSource").field(&self.0).finish()
@@ -1251,7 +1255,8 @@ impl windows_core::RuntimeType for ImageScannerScanSource {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Scanners.ImageScannerScanSource;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ImageScannerResolution {
     pub DpiX: f32,
     pub DpiY: f32,
@@ -1269,3 +1274,5 @@ impl Default for ImageScannerResolution {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
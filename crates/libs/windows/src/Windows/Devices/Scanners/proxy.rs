@@ -0,0 +1,354 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IImageScanner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScanner", "DeviceId", 6usize, |this| ((**(this as *const *const IImageScanner_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultScanSource(this: *mut core::ffi::c_void, result__: *mut ImageScannerScanSource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScanner", "DefaultScanSource", 7usize, |this| ((**(this as *const *const IImageScanner_Vtbl)).DefaultScanSource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsScanSourceSupported(this: *mut core::ffi::c_void, value: ImageScannerScanSource, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScanner", "IsScanSourceSupported", 8usize, |this| ((**(this as *const *const IImageScanner_Vtbl)).IsScanSourceSupported)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlatbedConfiguration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScanner", "FlatbedConfiguration", 9usize, |this| ((**(this as *const *const IImageScanner_Vtbl)).FlatbedConfiguration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeederConfiguration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScanner", "FeederConfiguration", 10usize, |this| ((**(this as *const *const IImageScanner_Vtbl)).FeederConfiguration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoConfiguration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScanner", "AutoConfiguration", 11usize, |this| ((**(this as *const *const IImageScanner_Vtbl)).AutoConfiguration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPreviewSupported(this: *mut core::ffi::c_void, scansource: ImageScannerScanSource, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScanner", "IsPreviewSupported", 12usize, |this| ((**(this as *const *const IImageScanner_Vtbl)).IsPreviewSupported)(this, scansource, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ScanPreviewToStreamAsync(this: *mut core::ffi::c_void, scansource: ImageScannerScanSource, targetstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScanner", "ScanPreviewToStreamAsync", 13usize, |this| ((**(this as *const *const IImageScanner_Vtbl)).ScanPreviewToStreamAsync)(this, scansource, targetstream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn ScanFilesToFolderAsync(this: *mut core::ffi::c_void, scansource: ImageScannerScanSource, storagefolder: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScanner", "ScanFilesToFolderAsync", 14usize, |this| ((**(this as *const *const IImageScanner_Vtbl)).ScanFilesToFolderAsync)(this, scansource, storagefolder, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            DefaultScanSource,
+            IsScanSourceSupported,
+            FlatbedConfiguration,
+            FeederConfiguration,
+            AutoConfiguration,
+            IsPreviewSupported,
+            #[cfg(feature = "Storage_Streams")]
+            ScanPreviewToStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ScanPreviewToStreamAsync: 0,
+            #[cfg(feature = "Storage")]
+            ScanFilesToFolderAsync,
+            #[cfg(not(feature = "Storage"))]
+            ScanFilesToFolderAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageScanner as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Printing")]
+impl windows_core::imp::ProxyVtable for IImageScannerFeederConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanAutoDetectPageSize(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "CanAutoDetectPageSize", 6usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).CanAutoDetectPageSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoDetectPageSize(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "AutoDetectPageSize", 7usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).AutoDetectPageSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoDetectPageSize(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "SetAutoDetectPageSize", 8usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).SetAutoDetectPageSize)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Printing")]
+        unsafe extern "system" fn PageSize(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Printing::PrintMediaSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "PageSize", 9usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).PageSize)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Printing")]
+        unsafe extern "system" fn SetPageSize(this: *mut core::ffi::c_void, value: super::super::Graphics::Printing::PrintMediaSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "SetPageSize", 10usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).SetPageSize)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Printing")]
+        unsafe extern "system" fn PageOrientation(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Printing::PrintOrientation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "PageOrientation", 11usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).PageOrientation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Printing")]
+        unsafe extern "system" fn SetPageOrientation(this: *mut core::ffi::c_void, value: super::super::Graphics::Printing::PrintOrientation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "SetPageOrientation", 12usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).SetPageOrientation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageSizeDimensions(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "PageSizeDimensions", 13usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).PageSizeDimensions)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Printing")]
+        unsafe extern "system" fn IsPageSizeSupported(this: *mut core::ffi::c_void, pagesize: super::super::Graphics::Printing::PrintMediaSize, pageorientation: super::super::Graphics::Printing::PrintOrientation, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "IsPageSizeSupported", 14usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).IsPageSizeSupported)(this, pagesize, pageorientation, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxNumberOfPages(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "MaxNumberOfPages", 15usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).MaxNumberOfPages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxNumberOfPages(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "SetMaxNumberOfPages", 16usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).SetMaxNumberOfPages)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanScanDuplex(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "CanScanDuplex", 17usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).CanScanDuplex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duplex(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "Duplex", 18usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).Duplex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuplex(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "SetDuplex", 19usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).SetDuplex)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanScanAhead(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "CanScanAhead", 20usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).CanScanAhead)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScanAhead(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "ScanAhead", 21usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).ScanAhead)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScanAhead(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFeederConfiguration", "SetScanAhead", 22usize, |this| ((**(this as *const *const IImageScannerFeederConfiguration_Vtbl)).SetScanAhead)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanAutoDetectPageSize,
+            AutoDetectPageSize,
+            SetAutoDetectPageSize,
+            #[cfg(feature = "Graphics_Printing")]
+            PageSize,
+            #[cfg(not(feature = "Graphics_Printing"))]
+            PageSize: 0,
+            #[cfg(feature = "Graphics_Printing")]
+            SetPageSize,
+            #[cfg(not(feature = "Graphics_Printing"))]
+            SetPageSize: 0,
+            #[cfg(feature = "Graphics_Printing")]
+            PageOrientation,
+            #[cfg(not(feature = "Graphics_Printing"))]
+            PageOrientation: 0,
+            #[cfg(feature = "Graphics_Printing")]
+            SetPageOrientation,
+            #[cfg(not(feature = "Graphics_Printing"))]
+            SetPageOrientation: 0,
+            PageSizeDimensions,
+            #[cfg(feature = "Graphics_Printing")]
+            IsPageSizeSupported,
+            #[cfg(not(feature = "Graphics_Printing"))]
+            IsPageSizeSupported: 0,
+            MaxNumberOfPages,
+            SetMaxNumberOfPages,
+            CanScanDuplex,
+            Duplex,
+            SetDuplex,
+            CanScanAhead,
+            ScanAhead,
+            SetScanAhead,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageScannerFeederConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImageScannerFormatConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DefaultFormat(this: *mut core::ffi::c_void, result__: *mut ImageScannerFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFormatConfiguration", "DefaultFormat", 6usize, |this| ((**(this as *const *const IImageScannerFormatConfiguration_Vtbl)).DefaultFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, result__: *mut ImageScannerFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFormatConfiguration", "Format", 7usize, |this| ((**(this as *const *const IImageScannerFormatConfiguration_Vtbl)).Format)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, value: ImageScannerFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFormatConfiguration", "SetFormat", 8usize, |this| ((**(this as *const *const IImageScannerFormatConfiguration_Vtbl)).SetFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFormatSupported(this: *mut core::ffi::c_void, value: ImageScannerFormat, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerFormatConfiguration", "IsFormatSupported", 9usize, |this| ((**(this as *const *const IImageScannerFormatConfiguration_Vtbl)).IsFormatSupported)(this, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DefaultFormat, Format, SetFormat, IsFormatSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageScannerFormatConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImageScannerPreviewResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Succeeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerPreviewResult", "Succeeded", 6usize, |this| ((**(this as *const *const IImageScannerPreviewResult_Vtbl)).Succeeded)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, result__: *mut ImageScannerFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerPreviewResult", "Format", 7usize, |this| ((**(this as *const *const IImageScannerPreviewResult_Vtbl)).Format)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Succeeded, Format }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageScannerPreviewResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IImageScannerScanResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn ScannedFiles(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerScanResult", "ScannedFiles", 6usize, |this| ((**(this as *const *const IImageScannerScanResult_Vtbl)).ScannedFiles)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            ScannedFiles,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            ScannedFiles: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageScannerScanResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImageScannerSourceConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinScanArea(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "MinScanArea", 6usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).MinScanArea)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxScanArea(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "MaxScanArea", 7usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).MaxScanArea)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedScanRegion(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "SelectedScanRegion", 8usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).SelectedScanRegion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelectedScanRegion(this: *mut core::ffi::c_void, value: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "SetSelectedScanRegion", 9usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).SetSelectedScanRegion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoCroppingMode(this: *mut core::ffi::c_void, result__: *mut ImageScannerAutoCroppingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "AutoCroppingMode", 10usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).AutoCroppingMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoCroppingMode(this: *mut core::ffi::c_void, value: ImageScannerAutoCroppingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "SetAutoCroppingMode", 11usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).SetAutoCroppingMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAutoCroppingModeSupported(this: *mut core::ffi::c_void, value: ImageScannerAutoCroppingMode, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "IsAutoCroppingModeSupported", 12usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).IsAutoCroppingModeSupported)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinResolution(this: *mut core::ffi::c_void, result__: *mut ImageScannerResolution) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "MinResolution", 13usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).MinResolution)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxResolution(this: *mut core::ffi::c_void, result__: *mut ImageScannerResolution) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "MaxResolution", 14usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).MaxResolution)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpticalResolution(this: *mut core::ffi::c_void, result__: *mut ImageScannerResolution) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "OpticalResolution", 15usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).OpticalResolution)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredResolution(this: *mut core::ffi::c_void, result__: *mut ImageScannerResolution) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "DesiredResolution", 16usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).DesiredResolution)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredResolution(this: *mut core::ffi::c_void, value: ImageScannerResolution) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "SetDesiredResolution", 17usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).SetDesiredResolution)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActualResolution(this: *mut core::ffi::c_void, result__: *mut ImageScannerResolution) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "ActualResolution", 18usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).ActualResolution)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultColorMode(this: *mut core::ffi::c_void, result__: *mut ImageScannerColorMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "DefaultColorMode", 19usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).DefaultColorMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorMode(this: *mut core::ffi::c_void, result__: *mut ImageScannerColorMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "ColorMode", 20usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).ColorMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorMode(this: *mut core::ffi::c_void, value: ImageScannerColorMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "SetColorMode", 21usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).SetColorMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsColorModeSupported(this: *mut core::ffi::c_void, value: ImageScannerColorMode, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "IsColorModeSupported", 22usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).IsColorModeSupported)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinBrightness(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "MinBrightness", 23usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).MinBrightness)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBrightness(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "MaxBrightness", 24usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).MaxBrightness)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrightnessStep(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "BrightnessStep", 25usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).BrightnessStep)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultBrightness(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "DefaultBrightness", 26usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).DefaultBrightness)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Brightness(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "Brightness", 27usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).Brightness)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBrightness(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "SetBrightness", 28usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).SetBrightness)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinContrast(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "MinContrast", 29usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).MinContrast)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxContrast(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "MaxContrast", 30usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).MaxContrast)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContrastStep(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "ContrastStep", 31usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).ContrastStep)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultContrast(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "DefaultContrast", 32usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).DefaultContrast)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Contrast(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "Contrast", 33usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).Contrast)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContrast(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerSourceConfiguration", "SetContrast", 34usize, |this| ((**(this as *const *const IImageScannerSourceConfiguration_Vtbl)).SetContrast)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MinScanArea,
+            MaxScanArea,
+            SelectedScanRegion,
+            SetSelectedScanRegion,
+            AutoCroppingMode,
+            SetAutoCroppingMode,
+            IsAutoCroppingModeSupported,
+            MinResolution,
+            MaxResolution,
+            OpticalResolution,
+            DesiredResolution,
+            SetDesiredResolution,
+            ActualResolution,
+            DefaultColorMode,
+            ColorMode,
+            SetColorMode,
+            IsColorModeSupported,
+            MinBrightness,
+            MaxBrightness,
+            BrightnessStep,
+            DefaultBrightness,
+            Brightness,
+            SetBrightness,
+            MinContrast,
+            MaxContrast,
+            ContrastStep,
+            DefaultContrast,
+            Contrast,
+            SetContrast,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageScannerSourceConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImageScannerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerStatics", "FromIdAsync", 6usize, |this| ((**(this as *const *const IImageScannerStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageScannerStatics", "GetDeviceSelector", 7usize, |this| ((**(this as *const *const IImageScannerStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromIdAsync, GetDeviceSelector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageScannerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
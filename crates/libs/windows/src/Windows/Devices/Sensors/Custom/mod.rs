@@ -229,3 +229,5 @@ impl windows_core::RuntimeName for CustomSensorReadingChangedEventArgs {
 }
 unsafe impl Send for CustomSensorReadingChangedEventArgs {}
 unsafe impl Sync for CustomSensorReadingChangedEventArgs {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
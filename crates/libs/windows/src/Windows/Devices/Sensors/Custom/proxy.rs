@@ -0,0 +1,120 @@
+impl windows_core::imp::ProxyVtable for ICustomSensor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensor", "GetCurrentReading", 6usize, |this| ((**(this as *const *const ICustomSensor_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensor", "MinimumReportInterval", 7usize, |this| ((**(this as *const *const ICustomSensor_Vtbl)).MinimumReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportInterval(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensor", "SetReportInterval", 8usize, |this| ((**(this as *const *const ICustomSensor_Vtbl)).SetReportInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensor", "ReportInterval", 9usize, |this| ((**(this as *const *const ICustomSensor_Vtbl)).ReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensor", "DeviceId", 10usize, |this| ((**(this as *const *const ICustomSensor_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensor", "ReadingChanged", 11usize, |this| ((**(this as *const *const ICustomSensor_Vtbl)).ReadingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadingChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensor", "RemoveReadingChanged", 12usize, |this| ((**(this as *const *const ICustomSensor_Vtbl)).RemoveReadingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentReading,
+            MinimumReportInterval,
+            SetReportInterval,
+            ReportInterval,
+            DeviceId,
+            ReadingChanged,
+            RemoveReadingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICustomSensor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICustomSensor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetReportLatency(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensor2", "SetReportLatency", 6usize, |this| ((**(this as *const *const ICustomSensor2_Vtbl)).SetReportLatency)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportLatency(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensor2", "ReportLatency", 7usize, |this| ((**(this as *const *const ICustomSensor2_Vtbl)).ReportLatency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBatchSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensor2", "MaxBatchSize", 8usize, |this| ((**(this as *const *const ICustomSensor2_Vtbl)).MaxBatchSize)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetReportLatency, ReportLatency, MaxBatchSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICustomSensor2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICustomSensorReading_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensorReading", "Timestamp", 6usize, |this| ((**(this as *const *const ICustomSensorReading_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensorReading", "Properties", 7usize, |this| ((**(this as *const *const ICustomSensorReading_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Timestamp,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICustomSensorReading as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICustomSensorReading2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PerformanceCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensorReading2", "PerformanceCount", 6usize, |this| ((**(this as *const *const ICustomSensorReading2_Vtbl)).PerformanceCount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PerformanceCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICustomSensorReading2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICustomSensorReadingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensorReadingChangedEventArgs", "Reading", 6usize, |this| ((**(this as *const *const ICustomSensorReadingChangedEventArgs_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICustomSensorReadingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICustomSensorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, interfaceid: windows_core::GUID, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensorStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const ICustomSensorStatics_Vtbl)).GetDeviceSelector)(this, interfaceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, sensorid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomSensorStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const ICustomSensorStatics_Vtbl)).FromIdAsync)(this, sensorid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICustomSensorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
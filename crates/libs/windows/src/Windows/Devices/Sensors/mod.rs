@@ -6038,6 +6038,7 @@ impl AccelerometerReadingType {
 impl windows_core::TypeKind for AccelerometerReadingType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AccelerometerReadingType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AccelerometerReadingType").field(&self.0).finish()
@@ -6056,6 +6057,7 @@ impl ActivitySensorReadingConfidence {
 impl windows_core::TypeKind for ActivitySensorReadingConfidence {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ActivitySensorReadingConfidence {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ActivitySensorReadingConfidence").field(&self.0).finish()
@@ -6080,6 +6082,7 @@ impl ActivityType {
 impl windows_core::TypeKind for ActivityType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ActivityType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ActivityType").field(&self.0).finish()
@@ -6099,6 +6102,7 @@ impl HumanEngagement {
 impl windows_core::TypeKind for HumanEngagement {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HumanEngagement {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HumanEngagement").field(&self.0).finish()
@@ -6118,6 +6122,7 @@ impl HumanPresence {
 impl windows_core::TypeKind for HumanPresence {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HumanPresence {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HumanPresence").field(&self.0).finish()
@@ -6138,6 +6143,7 @@ impl MagnetometerAccuracy {
 impl windows_core::TypeKind for MagnetometerAccuracy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MagnetometerAccuracy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MagnetometerAccuracy").field(&self.0).finish()
@@ -6157,6 +6163,7 @@ impl PedometerStepKind {
 impl windows_core::TypeKind for PedometerStepKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PedometerStepKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PedometerStepKind").field(&self.0).finish()
@@ -6175,6 +6182,7 @@ impl SensorOptimizationGoal {
 impl windows_core::TypeKind for SensorOptimizationGoal {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SensorOptimizationGoal {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SensorOptimizationGoal").field(&self.0).finish()
@@ -6193,6 +6201,7 @@ impl SensorReadingType {
 impl windows_core::TypeKind for SensorReadingType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SensorReadingType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SensorReadingType").field(&self.0).finish()
@@ -6223,6 +6232,7 @@ impl SensorType {
 impl windows_core::TypeKind for SensorType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SensorType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SensorType").field(&self.0).finish()
@@ -6245,6 +6255,7 @@ impl SimpleOrientation {
 impl windows_core::TypeKind for SimpleOrientation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SimpleOrientation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SimpleOrientation").field(&self.0).finish()
@@ -6255,3 +6266,5 @@ impl windows_core::RuntimeType for SimpleOrientation {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
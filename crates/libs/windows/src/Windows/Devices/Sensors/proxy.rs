@@ -0,0 +1,3238 @@
+impl windows_core::imp::ProxyVtable for IAccelerometer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometer", "GetCurrentReading", 6usize, |this| ((**(this as *const *const IAccelerometer_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometer", "MinimumReportInterval", 7usize, |this| ((**(this as *const *const IAccelerometer_Vtbl)).MinimumReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportInterval(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometer", "SetReportInterval", 8usize, |this| ((**(this as *const *const IAccelerometer_Vtbl)).SetReportInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometer", "ReportInterval", 9usize, |this| ((**(this as *const *const IAccelerometer_Vtbl)).ReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometer", "ReadingChanged", 10usize, |this| ((**(this as *const *const IAccelerometer_Vtbl)).ReadingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometer", "RemoveReadingChanged", 11usize, |this| ((**(this as *const *const IAccelerometer_Vtbl)).RemoveReadingChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shaken(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometer", "Shaken", 12usize, |this| ((**(this as *const *const IAccelerometer_Vtbl)).Shaken)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveShaken(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometer", "RemoveShaken", 13usize, |this| ((**(this as *const *const IAccelerometer_Vtbl)).RemoveShaken)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentReading,
+            MinimumReportInterval,
+            SetReportInterval,
+            ReportInterval,
+            ReadingChanged,
+            RemoveReadingChanged,
+            Shaken,
+            RemoveShaken,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccelerometer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Display")]
+impl windows_core::imp::ProxyVtable for IAccelerometer2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Display")]
+        unsafe extern "system" fn SetReadingTransform(this: *mut core::ffi::c_void, value: super::super::Graphics::Display::DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometer2", "SetReadingTransform", 6usize, |this| ((**(this as *const *const IAccelerometer2_Vtbl)).SetReadingTransform)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Display")]
+        unsafe extern "system" fn ReadingTransform(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Display::DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometer2", "ReadingTransform", 7usize, |this| ((**(this as *const *const IAccelerometer2_Vtbl)).ReadingTransform)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Display")]
+            SetReadingTransform,
+            #[cfg(not(feature = "Graphics_Display"))]
+            SetReadingTransform: 0,
+            #[cfg(feature = "Graphics_Display")]
+            ReadingTransform,
+            #[cfg(not(feature = "Graphics_Display"))]
+            ReadingTransform: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccelerometer2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccelerometer3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetReportLatency(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometer3", "SetReportLatency", 6usize, |this| ((**(this as *const *const IAccelerometer3_Vtbl)).SetReportLatency)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportLatency(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometer3", "ReportLatency", 7usize, |this| ((**(this as *const *const IAccelerometer3_Vtbl)).ReportLatency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBatchSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometer3", "MaxBatchSize", 8usize, |this| ((**(this as *const *const IAccelerometer3_Vtbl)).MaxBatchSize)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetReportLatency, ReportLatency, MaxBatchSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccelerometer3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccelerometer4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadingType(this: *mut core::ffi::c_void, result__: *mut AccelerometerReadingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometer4", "ReadingType", 6usize, |this| ((**(this as *const *const IAccelerometer4_Vtbl)).ReadingType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReadingType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccelerometer4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccelerometer5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportThreshold(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometer5", "ReportThreshold", 6usize, |this| ((**(this as *const *const IAccelerometer5_Vtbl)).ReportThreshold)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportThreshold }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccelerometer5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccelerometerDataThreshold_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn XAxisInGForce(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerDataThreshold", "XAxisInGForce", 6usize, |this| ((**(this as *const *const IAccelerometerDataThreshold_Vtbl)).XAxisInGForce)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetXAxisInGForce(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerDataThreshold", "SetXAxisInGForce", 7usize, |this| ((**(this as *const *const IAccelerometerDataThreshold_Vtbl)).SetXAxisInGForce)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn YAxisInGForce(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerDataThreshold", "YAxisInGForce", 8usize, |this| ((**(this as *const *const IAccelerometerDataThreshold_Vtbl)).YAxisInGForce)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetYAxisInGForce(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerDataThreshold", "SetYAxisInGForce", 9usize, |this| ((**(this as *const *const IAccelerometerDataThreshold_Vtbl)).SetYAxisInGForce)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZAxisInGForce(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerDataThreshold", "ZAxisInGForce", 10usize, |this| ((**(this as *const *const IAccelerometerDataThreshold_Vtbl)).ZAxisInGForce)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetZAxisInGForce(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerDataThreshold", "SetZAxisInGForce", 11usize, |this| ((**(this as *const *const IAccelerometerDataThreshold_Vtbl)).SetZAxisInGForce)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            XAxisInGForce,
+            SetXAxisInGForce,
+            YAxisInGForce,
+            SetYAxisInGForce,
+            ZAxisInGForce,
+            SetZAxisInGForce,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccelerometerDataThreshold as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccelerometerDeviceId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerDeviceId", "DeviceId", 6usize, |this| ((**(this as *const *const IAccelerometerDeviceId_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccelerometerDeviceId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccelerometerReading_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerReading", "Timestamp", 6usize, |this| ((**(this as *const *const IAccelerometerReading_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccelerationX(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerReading", "AccelerationX", 7usize, |this| ((**(this as *const *const IAccelerometerReading_Vtbl)).AccelerationX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccelerationY(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerReading", "AccelerationY", 8usize, |this| ((**(this as *const *const IAccelerometerReading_Vtbl)).AccelerationY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccelerationZ(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerReading", "AccelerationZ", 9usize, |this| ((**(this as *const *const IAccelerometerReading_Vtbl)).AccelerationZ)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, AccelerationX, AccelerationY, AccelerationZ }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccelerometerReading as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAccelerometerReading2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PerformanceCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerReading2", "PerformanceCount", 6usize, |this| ((**(this as *const *const IAccelerometerReading2_Vtbl)).PerformanceCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerReading2", "Properties", 7usize, |this| ((**(this as *const *const IAccelerometerReading2_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PerformanceCount,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccelerometerReading2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccelerometerReadingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerReadingChangedEventArgs", "Reading", 6usize, |this| ((**(this as *const *const IAccelerometerReadingChangedEventArgs_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccelerometerReadingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccelerometerShakenEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerShakenEventArgs", "Timestamp", 6usize, |this| ((**(this as *const *const IAccelerometerShakenEventArgs_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccelerometerShakenEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccelerometerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IAccelerometerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccelerometerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccelerometerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultWithAccelerometerReadingType(this: *mut core::ffi::c_void, readingtype: AccelerometerReadingType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerStatics2", "GetDefaultWithAccelerometerReadingType", 6usize, |this| ((**(this as *const *const IAccelerometerStatics2_Vtbl)).GetDefaultWithAccelerometerReadingType)(this, readingtype, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefaultWithAccelerometerReadingType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccelerometerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccelerometerStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerStatics3", "FromIdAsync", 6usize, |this| ((**(this as *const *const IAccelerometerStatics3_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, readingtype: AccelerometerReadingType, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccelerometerStatics3", "GetDeviceSelector", 7usize, |this| ((**(this as *const *const IAccelerometerStatics3_Vtbl)).GetDeviceSelector)(this, readingtype, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromIdAsync, GetDeviceSelector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccelerometerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IActivitySensor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentReadingAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensor", "GetCurrentReadingAsync", 6usize, |this| ((**(this as *const *const IActivitySensor_Vtbl)).GetCurrentReadingAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SubscribedActivities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensor", "SubscribedActivities", 7usize, |this| ((**(this as *const *const IActivitySensor_Vtbl)).SubscribedActivities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerInMilliwatts(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensor", "PowerInMilliwatts", 8usize, |this| ((**(this as *const *const IActivitySensor_Vtbl)).PowerInMilliwatts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensor", "DeviceId", 9usize, |this| ((**(this as *const *const IActivitySensor_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedActivities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensor", "SupportedActivities", 10usize, |this| ((**(this as *const *const IActivitySensor_Vtbl)).SupportedActivities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensor", "MinimumReportInterval", 11usize, |this| ((**(this as *const *const IActivitySensor_Vtbl)).MinimumReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensor", "ReadingChanged", 12usize, |this| ((**(this as *const *const IActivitySensor_Vtbl)).ReadingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensor", "RemoveReadingChanged", 13usize, |this| ((**(this as *const *const IActivitySensor_Vtbl)).RemoveReadingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentReadingAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            SubscribedActivities,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SubscribedActivities: 0,
+            PowerInMilliwatts,
+            DeviceId,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedActivities,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedActivities: 0,
+            MinimumReportInterval,
+            ReadingChanged,
+            RemoveReadingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivitySensor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActivitySensorReading_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorReading", "Timestamp", 6usize, |this| ((**(this as *const *const IActivitySensorReading_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Activity(this: *mut core::ffi::c_void, result__: *mut ActivityType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorReading", "Activity", 7usize, |this| ((**(this as *const *const IActivitySensorReading_Vtbl)).Activity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Confidence(this: *mut core::ffi::c_void, result__: *mut ActivitySensorReadingConfidence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorReading", "Confidence", 8usize, |this| ((**(this as *const *const IActivitySensorReading_Vtbl)).Confidence)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, Activity, Confidence }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivitySensorReading as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActivitySensorReadingChangeReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorReadingChangeReport", "Reading", 6usize, |this| ((**(this as *const *const IActivitySensorReadingChangeReport_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivitySensorReadingChangeReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActivitySensorReadingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorReadingChangedEventArgs", "Reading", 6usize, |this| ((**(this as *const *const IActivitySensorReadingChangedEventArgs_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivitySensorReadingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IActivitySensorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorStatics", "GetDefaultAsync", 6usize, |this| ((**(this as *const *const IActivitySensorStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorStatics", "GetDeviceSelector", 7usize, |this| ((**(this as *const *const IActivitySensorStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorStatics", "FromIdAsync", 8usize, |this| ((**(this as *const *const IActivitySensorStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSystemHistoryAsync(this: *mut core::ffi::c_void, fromtime: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorStatics", "GetSystemHistoryAsync", 9usize, |this| ((**(this as *const *const IActivitySensorStatics_Vtbl)).GetSystemHistoryAsync)(this, fromtime, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSystemHistoryWithDurationAsync(this: *mut core::ffi::c_void, fromtime: super::super::Foundation::DateTime, duration: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorStatics", "GetSystemHistoryWithDurationAsync", 10usize, |this| ((**(this as *const *const IActivitySensorStatics_Vtbl)).GetSystemHistoryWithDurationAsync)(this, fromtime, duration, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDefaultAsync,
+            GetDeviceSelector,
+            FromIdAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSystemHistoryAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSystemHistoryAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSystemHistoryWithDurationAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSystemHistoryWithDurationAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivitySensorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IActivitySensorTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadReports(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivitySensorTriggerDetails", "ReadReports", 6usize, |this| ((**(this as *const *const IActivitySensorTriggerDetails_Vtbl)).ReadReports)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadReports,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadReports: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivitySensorTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveDimmingOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowWhenExternalDisplayConnected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveDimmingOptions", "AllowWhenExternalDisplayConnected", 6usize, |this| ((**(this as *const *const IAdaptiveDimmingOptions_Vtbl)).AllowWhenExternalDisplayConnected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowWhenExternalDisplayConnected(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveDimmingOptions", "SetAllowWhenExternalDisplayConnected", 7usize, |this| ((**(this as *const *const IAdaptiveDimmingOptions_Vtbl)).SetAllowWhenExternalDisplayConnected)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AllowWhenExternalDisplayConnected,
+            SetAllowWhenExternalDisplayConnected,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveDimmingOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAltimeter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeter", "GetCurrentReading", 6usize, |this| ((**(this as *const *const IAltimeter_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeter", "DeviceId", 7usize, |this| ((**(this as *const *const IAltimeter_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeter", "MinimumReportInterval", 8usize, |this| ((**(this as *const *const IAltimeter_Vtbl)).MinimumReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportInterval(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeter", "SetReportInterval", 9usize, |this| ((**(this as *const *const IAltimeter_Vtbl)).SetReportInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeter", "ReportInterval", 10usize, |this| ((**(this as *const *const IAltimeter_Vtbl)).ReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeter", "ReadingChanged", 11usize, |this| ((**(this as *const *const IAltimeter_Vtbl)).ReadingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeter", "RemoveReadingChanged", 12usize, |this| ((**(this as *const *const IAltimeter_Vtbl)).RemoveReadingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentReading,
+            DeviceId,
+            MinimumReportInterval,
+            SetReportInterval,
+            ReportInterval,
+            ReadingChanged,
+            RemoveReadingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAltimeter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAltimeter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetReportLatency(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeter2", "SetReportLatency", 6usize, |this| ((**(this as *const *const IAltimeter2_Vtbl)).SetReportLatency)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportLatency(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeter2", "ReportLatency", 7usize, |this| ((**(this as *const *const IAltimeter2_Vtbl)).ReportLatency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBatchSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeter2", "MaxBatchSize", 8usize, |this| ((**(this as *const *const IAltimeter2_Vtbl)).MaxBatchSize)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetReportLatency, ReportLatency, MaxBatchSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAltimeter2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAltimeterReading_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeterReading", "Timestamp", 6usize, |this| ((**(this as *const *const IAltimeterReading_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AltitudeChangeInMeters(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeterReading", "AltitudeChangeInMeters", 7usize, |this| ((**(this as *const *const IAltimeterReading_Vtbl)).AltitudeChangeInMeters)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, AltitudeChangeInMeters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAltimeterReading as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAltimeterReading2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PerformanceCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeterReading2", "PerformanceCount", 6usize, |this| ((**(this as *const *const IAltimeterReading2_Vtbl)).PerformanceCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeterReading2", "Properties", 7usize, |this| ((**(this as *const *const IAltimeterReading2_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PerformanceCount,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAltimeterReading2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAltimeterReadingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeterReadingChangedEventArgs", "Reading", 6usize, |this| ((**(this as *const *const IAltimeterReadingChangedEventArgs_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAltimeterReadingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAltimeterStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAltimeterStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IAltimeterStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAltimeterStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarometer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometer", "GetCurrentReading", 6usize, |this| ((**(this as *const *const IBarometer_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometer", "DeviceId", 7usize, |this| ((**(this as *const *const IBarometer_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometer", "MinimumReportInterval", 8usize, |this| ((**(this as *const *const IBarometer_Vtbl)).MinimumReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportInterval(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometer", "SetReportInterval", 9usize, |this| ((**(this as *const *const IBarometer_Vtbl)).SetReportInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometer", "ReportInterval", 10usize, |this| ((**(this as *const *const IBarometer_Vtbl)).ReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometer", "ReadingChanged", 11usize, |this| ((**(this as *const *const IBarometer_Vtbl)).ReadingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometer", "RemoveReadingChanged", 12usize, |this| ((**(this as *const *const IBarometer_Vtbl)).RemoveReadingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentReading,
+            DeviceId,
+            MinimumReportInterval,
+            SetReportInterval,
+            ReportInterval,
+            ReadingChanged,
+            RemoveReadingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarometer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarometer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetReportLatency(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometer2", "SetReportLatency", 6usize, |this| ((**(this as *const *const IBarometer2_Vtbl)).SetReportLatency)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportLatency(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometer2", "ReportLatency", 7usize, |this| ((**(this as *const *const IBarometer2_Vtbl)).ReportLatency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBatchSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometer2", "MaxBatchSize", 8usize, |this| ((**(this as *const *const IBarometer2_Vtbl)).MaxBatchSize)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetReportLatency, ReportLatency, MaxBatchSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarometer2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarometer3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportThreshold(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometer3", "ReportThreshold", 6usize, |this| ((**(this as *const *const IBarometer3_Vtbl)).ReportThreshold)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportThreshold }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarometer3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarometerDataThreshold_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Hectopascals(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometerDataThreshold", "Hectopascals", 6usize, |this| ((**(this as *const *const IBarometerDataThreshold_Vtbl)).Hectopascals)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHectopascals(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometerDataThreshold", "SetHectopascals", 7usize, |this| ((**(this as *const *const IBarometerDataThreshold_Vtbl)).SetHectopascals)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Hectopascals, SetHectopascals }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarometerDataThreshold as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarometerReading_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometerReading", "Timestamp", 6usize, |this| ((**(this as *const *const IBarometerReading_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StationPressureInHectopascals(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometerReading", "StationPressureInHectopascals", 7usize, |this| ((**(this as *const *const IBarometerReading_Vtbl)).StationPressureInHectopascals)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, StationPressureInHectopascals }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarometerReading as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBarometerReading2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PerformanceCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometerReading2", "PerformanceCount", 6usize, |this| ((**(this as *const *const IBarometerReading2_Vtbl)).PerformanceCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometerReading2", "Properties", 7usize, |this| ((**(this as *const *const IBarometerReading2_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PerformanceCount,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarometerReading2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarometerReadingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometerReadingChangedEventArgs", "Reading", 6usize, |this| ((**(this as *const *const IBarometerReadingChangedEventArgs_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarometerReadingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarometerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IBarometerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarometerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBarometerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometerStatics2", "FromIdAsync", 6usize, |this| ((**(this as *const *const IBarometerStatics2_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBarometerStatics2", "GetDeviceSelector", 7usize, |this| ((**(this as *const *const IBarometerStatics2_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromIdAsync, GetDeviceSelector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBarometerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompass_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompass", "GetCurrentReading", 6usize, |this| ((**(this as *const *const ICompass_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompass", "MinimumReportInterval", 7usize, |this| ((**(this as *const *const ICompass_Vtbl)).MinimumReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportInterval(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompass", "SetReportInterval", 8usize, |this| ((**(this as *const *const ICompass_Vtbl)).SetReportInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompass", "ReportInterval", 9usize, |this| ((**(this as *const *const ICompass_Vtbl)).ReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompass", "ReadingChanged", 10usize, |this| ((**(this as *const *const ICompass_Vtbl)).ReadingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompass", "RemoveReadingChanged", 11usize, |this| ((**(this as *const *const ICompass_Vtbl)).RemoveReadingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentReading,
+            MinimumReportInterval,
+            SetReportInterval,
+            ReportInterval,
+            ReadingChanged,
+            RemoveReadingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompass as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Display")]
+impl windows_core::imp::ProxyVtable for ICompass2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Display")]
+        unsafe extern "system" fn SetReadingTransform(this: *mut core::ffi::c_void, value: super::super::Graphics::Display::DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompass2", "SetReadingTransform", 6usize, |this| ((**(this as *const *const ICompass2_Vtbl)).SetReadingTransform)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Display")]
+        unsafe extern "system" fn ReadingTransform(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Display::DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompass2", "ReadingTransform", 7usize, |this| ((**(this as *const *const ICompass2_Vtbl)).ReadingTransform)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Display")]
+            SetReadingTransform,
+            #[cfg(not(feature = "Graphics_Display"))]
+            SetReadingTransform: 0,
+            #[cfg(feature = "Graphics_Display")]
+            ReadingTransform,
+            #[cfg(not(feature = "Graphics_Display"))]
+            ReadingTransform: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompass2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompass3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetReportLatency(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompass3", "SetReportLatency", 6usize, |this| ((**(this as *const *const ICompass3_Vtbl)).SetReportLatency)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportLatency(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompass3", "ReportLatency", 7usize, |this| ((**(this as *const *const ICompass3_Vtbl)).ReportLatency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBatchSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompass3", "MaxBatchSize", 8usize, |this| ((**(this as *const *const ICompass3_Vtbl)).MaxBatchSize)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetReportLatency, ReportLatency, MaxBatchSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompass3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompass4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportThreshold(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompass4", "ReportThreshold", 6usize, |this| ((**(this as *const *const ICompass4_Vtbl)).ReportThreshold)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportThreshold }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompass4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompassDataThreshold_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Degrees(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompassDataThreshold", "Degrees", 6usize, |this| ((**(this as *const *const ICompassDataThreshold_Vtbl)).Degrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDegrees(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompassDataThreshold", "SetDegrees", 7usize, |this| ((**(this as *const *const ICompassDataThreshold_Vtbl)).SetDegrees)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Degrees, SetDegrees }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompassDataThreshold as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompassDeviceId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompassDeviceId", "DeviceId", 6usize, |this| ((**(this as *const *const ICompassDeviceId_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompassDeviceId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompassReading_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompassReading", "Timestamp", 6usize, |this| ((**(this as *const *const ICompassReading_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeadingMagneticNorth(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompassReading", "HeadingMagneticNorth", 7usize, |this| ((**(this as *const *const ICompassReading_Vtbl)).HeadingMagneticNorth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeadingTrueNorth(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompassReading", "HeadingTrueNorth", 8usize, |this| ((**(this as *const *const ICompassReading_Vtbl)).HeadingTrueNorth)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, HeadingMagneticNorth, HeadingTrueNorth }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompassReading as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICompassReading2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PerformanceCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompassReading2", "PerformanceCount", 6usize, |this| ((**(this as *const *const ICompassReading2_Vtbl)).PerformanceCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompassReading2", "Properties", 7usize, |this| ((**(this as *const *const ICompassReading2_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PerformanceCount,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompassReading2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompassReadingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompassReadingChangedEventArgs", "Reading", 6usize, |this| ((**(this as *const *const ICompassReadingChangedEventArgs_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompassReadingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompassReadingHeadingAccuracy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HeadingAccuracy(this: *mut core::ffi::c_void, result__: *mut MagnetometerAccuracy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompassReadingHeadingAccuracy", "HeadingAccuracy", 6usize, |this| ((**(this as *const *const ICompassReadingHeadingAccuracy_Vtbl)).HeadingAccuracy)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HeadingAccuracy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompassReadingHeadingAccuracy as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompassStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompassStatics", "GetDefault", 6usize, |this| ((**(this as *const *const ICompassStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompassStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompassStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompassStatics2", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const ICompassStatics2_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompassStatics2", "FromIdAsync", 7usize, |this| ((**(this as *const *const ICompassStatics2_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompassStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDetectedPerson_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Engagement(this: *mut core::ffi::c_void, result__: *mut HumanEngagement) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDetectedPerson", "Engagement", 6usize, |this| ((**(this as *const *const IDetectedPerson_Vtbl)).Engagement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DistanceInMillimeters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDetectedPerson", "DistanceInMillimeters", 7usize, |this| ((**(this as *const *const IDetectedPerson_Vtbl)).DistanceInMillimeters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeadOrientation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDetectedPerson", "HeadOrientation", 8usize, |this| ((**(this as *const *const IDetectedPerson_Vtbl)).HeadOrientation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeadPosition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDetectedPerson", "HeadPosition", 9usize, |this| ((**(this as *const *const IDetectedPerson_Vtbl)).HeadPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PersonId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDetectedPerson", "PersonId", 10usize, |this| ((**(this as *const *const IDetectedPerson_Vtbl)).PersonId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Engagement,
+            DistanceInMillimeters,
+            HeadOrientation,
+            HeadPosition,
+            PersonId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDetectedPerson as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGyrometer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometer", "GetCurrentReading", 6usize, |this| ((**(this as *const *const IGyrometer_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometer", "MinimumReportInterval", 7usize, |this| ((**(this as *const *const IGyrometer_Vtbl)).MinimumReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportInterval(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometer", "SetReportInterval", 8usize, |this| ((**(this as *const *const IGyrometer_Vtbl)).SetReportInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometer", "ReportInterval", 9usize, |this| ((**(this as *const *const IGyrometer_Vtbl)).ReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometer", "ReadingChanged", 10usize, |this| ((**(this as *const *const IGyrometer_Vtbl)).ReadingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometer", "RemoveReadingChanged", 11usize, |this| ((**(this as *const *const IGyrometer_Vtbl)).RemoveReadingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentReading,
+            MinimumReportInterval,
+            SetReportInterval,
+            ReportInterval,
+            ReadingChanged,
+            RemoveReadingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGyrometer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Display")]
+impl windows_core::imp::ProxyVtable for IGyrometer2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Display")]
+        unsafe extern "system" fn SetReadingTransform(this: *mut core::ffi::c_void, value: super::super::Graphics::Display::DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometer2", "SetReadingTransform", 6usize, |this| ((**(this as *const *const IGyrometer2_Vtbl)).SetReadingTransform)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Display")]
+        unsafe extern "system" fn ReadingTransform(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Display::DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometer2", "ReadingTransform", 7usize, |this| ((**(this as *const *const IGyrometer2_Vtbl)).ReadingTransform)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Display")]
+            SetReadingTransform,
+            #[cfg(not(feature = "Graphics_Display"))]
+            SetReadingTransform: 0,
+            #[cfg(feature = "Graphics_Display")]
+            ReadingTransform,
+            #[cfg(not(feature = "Graphics_Display"))]
+            ReadingTransform: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGyrometer2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGyrometer3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetReportLatency(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometer3", "SetReportLatency", 6usize, |this| ((**(this as *const *const IGyrometer3_Vtbl)).SetReportLatency)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportLatency(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometer3", "ReportLatency", 7usize, |this| ((**(this as *const *const IGyrometer3_Vtbl)).ReportLatency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBatchSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometer3", "MaxBatchSize", 8usize, |this| ((**(this as *const *const IGyrometer3_Vtbl)).MaxBatchSize)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetReportLatency, ReportLatency, MaxBatchSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGyrometer3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGyrometer4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportThreshold(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometer4", "ReportThreshold", 6usize, |this| ((**(this as *const *const IGyrometer4_Vtbl)).ReportThreshold)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportThreshold }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGyrometer4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGyrometerDataThreshold_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn XAxisInDegreesPerSecond(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerDataThreshold", "XAxisInDegreesPerSecond", 6usize, |this| ((**(this as *const *const IGyrometerDataThreshold_Vtbl)).XAxisInDegreesPerSecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetXAxisInDegreesPerSecond(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerDataThreshold", "SetXAxisInDegreesPerSecond", 7usize, |this| ((**(this as *const *const IGyrometerDataThreshold_Vtbl)).SetXAxisInDegreesPerSecond)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn YAxisInDegreesPerSecond(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerDataThreshold", "YAxisInDegreesPerSecond", 8usize, |this| ((**(this as *const *const IGyrometerDataThreshold_Vtbl)).YAxisInDegreesPerSecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetYAxisInDegreesPerSecond(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerDataThreshold", "SetYAxisInDegreesPerSecond", 9usize, |this| ((**(this as *const *const IGyrometerDataThreshold_Vtbl)).SetYAxisInDegreesPerSecond)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZAxisInDegreesPerSecond(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerDataThreshold", "ZAxisInDegreesPerSecond", 10usize, |this| ((**(this as *const *const IGyrometerDataThreshold_Vtbl)).ZAxisInDegreesPerSecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetZAxisInDegreesPerSecond(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerDataThreshold", "SetZAxisInDegreesPerSecond", 11usize, |this| ((**(this as *const *const IGyrometerDataThreshold_Vtbl)).SetZAxisInDegreesPerSecond)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            XAxisInDegreesPerSecond,
+            SetXAxisInDegreesPerSecond,
+            YAxisInDegreesPerSecond,
+            SetYAxisInDegreesPerSecond,
+            ZAxisInDegreesPerSecond,
+            SetZAxisInDegreesPerSecond,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGyrometerDataThreshold as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGyrometerDeviceId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerDeviceId", "DeviceId", 6usize, |this| ((**(this as *const *const IGyrometerDeviceId_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGyrometerDeviceId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGyrometerReading_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerReading", "Timestamp", 6usize, |this| ((**(this as *const *const IGyrometerReading_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AngularVelocityX(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerReading", "AngularVelocityX", 7usize, |this| ((**(this as *const *const IGyrometerReading_Vtbl)).AngularVelocityX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AngularVelocityY(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerReading", "AngularVelocityY", 8usize, |this| ((**(this as *const *const IGyrometerReading_Vtbl)).AngularVelocityY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AngularVelocityZ(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerReading", "AngularVelocityZ", 9usize, |this| ((**(this as *const *const IGyrometerReading_Vtbl)).AngularVelocityZ)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Timestamp,
+            AngularVelocityX,
+            AngularVelocityY,
+            AngularVelocityZ,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGyrometerReading as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGyrometerReading2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PerformanceCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerReading2", "PerformanceCount", 6usize, |this| ((**(this as *const *const IGyrometerReading2_Vtbl)).PerformanceCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerReading2", "Properties", 7usize, |this| ((**(this as *const *const IGyrometerReading2_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PerformanceCount,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGyrometerReading2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGyrometerReadingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerReadingChangedEventArgs", "Reading", 6usize, |this| ((**(this as *const *const IGyrometerReadingChangedEventArgs_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGyrometerReadingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGyrometerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IGyrometerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGyrometerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGyrometerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerStatics2", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IGyrometerStatics2_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGyrometerStatics2", "FromIdAsync", 7usize, |this| ((**(this as *const *const IGyrometerStatics2_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGyrometerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHeadOrientation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RollInDegrees(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeadOrientation", "RollInDegrees", 6usize, |this| ((**(this as *const *const IHeadOrientation_Vtbl)).RollInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PitchInDegrees(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeadOrientation", "PitchInDegrees", 7usize, |this| ((**(this as *const *const IHeadOrientation_Vtbl)).PitchInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn YawInDegrees(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeadOrientation", "YawInDegrees", 8usize, |this| ((**(this as *const *const IHeadOrientation_Vtbl)).YawInDegrees)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RollInDegrees, PitchInDegrees, YawInDegrees }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHeadOrientation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHeadPosition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AzimuthInDegrees(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeadPosition", "AzimuthInDegrees", 6usize, |this| ((**(this as *const *const IHeadPosition_Vtbl)).AzimuthInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AltitudeInDegrees(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeadPosition", "AltitudeInDegrees", 7usize, |this| ((**(this as *const *const IHeadPosition_Vtbl)).AltitudeInDegrees)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AzimuthInDegrees, AltitudeInDegrees }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHeadPosition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHingeAngleReading_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHingeAngleReading", "Timestamp", 6usize, |this| ((**(this as *const *const IHingeAngleReading_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AngleInDegrees(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHingeAngleReading", "AngleInDegrees", 7usize, |this| ((**(this as *const *const IHingeAngleReading_Vtbl)).AngleInDegrees)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHingeAngleReading", "Properties", 8usize, |this| ((**(this as *const *const IHingeAngleReading_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Timestamp,
+            AngleInDegrees,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHingeAngleReading as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHingeAngleSensor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentReadingAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHingeAngleSensor", "GetCurrentReadingAsync", 6usize, |this| ((**(this as *const *const IHingeAngleSensor_Vtbl)).GetCurrentReadingAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHingeAngleSensor", "DeviceId", 7usize, |this| ((**(this as *const *const IHingeAngleSensor_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinReportThresholdInDegrees(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHingeAngleSensor", "MinReportThresholdInDegrees", 8usize, |this| ((**(this as *const *const IHingeAngleSensor_Vtbl)).MinReportThresholdInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportThresholdInDegrees(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHingeAngleSensor", "ReportThresholdInDegrees", 9usize, |this| ((**(this as *const *const IHingeAngleSensor_Vtbl)).ReportThresholdInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportThresholdInDegrees(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHingeAngleSensor", "SetReportThresholdInDegrees", 10usize, |this| ((**(this as *const *const IHingeAngleSensor_Vtbl)).SetReportThresholdInDegrees)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHingeAngleSensor", "ReadingChanged", 11usize, |this| ((**(this as *const *const IHingeAngleSensor_Vtbl)).ReadingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHingeAngleSensor", "RemoveReadingChanged", 12usize, |this| ((**(this as *const *const IHingeAngleSensor_Vtbl)).RemoveReadingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentReadingAsync,
+            DeviceId,
+            MinReportThresholdInDegrees,
+            ReportThresholdInDegrees,
+            SetReportThresholdInDegrees,
+            ReadingChanged,
+            RemoveReadingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHingeAngleSensor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHingeAngleSensorReadingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHingeAngleSensorReadingChangedEventArgs", "Reading", 6usize, |this| ((**(this as *const *const IHingeAngleSensorReadingChangedEventArgs_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHingeAngleSensorReadingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHingeAngleSensorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHingeAngleSensorStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IHingeAngleSensorStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHingeAngleSensorStatics", "GetDefaultAsync", 7usize, |this| ((**(this as *const *const IHingeAngleSensorStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRelatedToAdjacentPanelsAsync(this: *mut core::ffi::c_void, firstpanelid: core::mem::MaybeUninit<windows_core::HSTRING>, secondpanelid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHingeAngleSensorStatics", "GetRelatedToAdjacentPanelsAsync", 8usize, |this| ((**(this as *const *const IHingeAngleSensorStatics_Vtbl)).GetRelatedToAdjacentPanelsAsync)(this, firstpanelid, secondpanelid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHingeAngleSensorStatics", "FromIdAsync", 9usize, |this| ((**(this as *const *const IHingeAngleSensorStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceSelector,
+            GetDefaultAsync,
+            GetRelatedToAdjacentPanelsAsync,
+            FromIdAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHingeAngleSensorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHumanPresenceFeatures_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SensorId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceFeatures", "SensorId", 6usize, |this| ((**(this as *const *const IHumanPresenceFeatures_Vtbl)).SensorId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedWakeOrLockDistancesInMillimeters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceFeatures", "SupportedWakeOrLockDistancesInMillimeters", 7usize, |this| ((**(this as *const *const IHumanPresenceFeatures_Vtbl)).SupportedWakeOrLockDistancesInMillimeters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWakeOnApproachSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceFeatures", "IsWakeOnApproachSupported", 8usize, |this| ((**(this as *const *const IHumanPresenceFeatures_Vtbl)).IsWakeOnApproachSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLockOnLeaveSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceFeatures", "IsLockOnLeaveSupported", 9usize, |this| ((**(this as *const *const IHumanPresenceFeatures_Vtbl)).IsLockOnLeaveSupported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsAttentionAwareDimmingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceFeatures", "IsAttentionAwareDimmingSupported", 10usize, |this| ((**(this as *const *const IHumanPresenceFeatures_Vtbl)).IsAttentionAwareDimmingSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SensorId,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedWakeOrLockDistancesInMillimeters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedWakeOrLockDistancesInMillimeters: 0,
+            IsWakeOnApproachSupported,
+            IsLockOnLeaveSupported,
+            #[cfg(feature = "deprecated")]
+            IsAttentionAwareDimmingSupported,
+            #[cfg(not(feature = "deprecated"))]
+            IsAttentionAwareDimmingSupported: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceFeatures as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHumanPresenceFeatures2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAdaptiveDimmingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceFeatures2", "IsAdaptiveDimmingSupported", 6usize, |this| ((**(this as *const *const IHumanPresenceFeatures2_Vtbl)).IsAdaptiveDimmingSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsAdaptiveDimmingSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceFeatures2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHumanPresenceSensor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensor", "DeviceId", 6usize, |this| ((**(this as *const *const IHumanPresenceSensor_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxDetectableDistanceInMillimeters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensor", "MaxDetectableDistanceInMillimeters", 7usize, |this| ((**(this as *const *const IHumanPresenceSensor_Vtbl)).MaxDetectableDistanceInMillimeters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinDetectableDistanceInMillimeters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensor", "MinDetectableDistanceInMillimeters", 8usize, |this| ((**(this as *const *const IHumanPresenceSensor_Vtbl)).MinDetectableDistanceInMillimeters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensor", "GetCurrentReading", 9usize, |this| ((**(this as *const *const IHumanPresenceSensor_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensor", "ReadingChanged", 10usize, |this| ((**(this as *const *const IHumanPresenceSensor_Vtbl)).ReadingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensor", "RemoveReadingChanged", 11usize, |this| ((**(this as *const *const IHumanPresenceSensor_Vtbl)).RemoveReadingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            MaxDetectableDistanceInMillimeters,
+            MinDetectableDistanceInMillimeters,
+            GetCurrentReading,
+            ReadingChanged,
+            RemoveReadingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceSensor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHumanPresenceSensor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPresenceSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensor2", "IsPresenceSupported", 6usize, |this| ((**(this as *const *const IHumanPresenceSensor2_Vtbl)).IsPresenceSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEngagementSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensor2", "IsEngagementSupported", 7usize, |this| ((**(this as *const *const IHumanPresenceSensor2_Vtbl)).IsEngagementSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsPresenceSupported, IsEngagementSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceSensor2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHumanPresenceSensor3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxDetectablePersons(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensor3", "MaxDetectablePersons", 6usize, |this| ((**(this as *const *const IHumanPresenceSensor3_Vtbl)).MaxDetectablePersons)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinDetectableAzimuthInDegrees(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensor3", "MinDetectableAzimuthInDegrees", 7usize, |this| ((**(this as *const *const IHumanPresenceSensor3_Vtbl)).MinDetectableAzimuthInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxDetectableAzimuthInDegrees(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensor3", "MaxDetectableAzimuthInDegrees", 8usize, |this| ((**(this as *const *const IHumanPresenceSensor3_Vtbl)).MaxDetectableAzimuthInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinDetectableAltitudeInDegrees(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensor3", "MinDetectableAltitudeInDegrees", 9usize, |this| ((**(this as *const *const IHumanPresenceSensor3_Vtbl)).MinDetectableAltitudeInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxDetectableAltitudeInDegrees(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensor3", "MaxDetectableAltitudeInDegrees", 10usize, |this| ((**(this as *const *const IHumanPresenceSensor3_Vtbl)).MaxDetectableAltitudeInDegrees)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaxDetectablePersons,
+            MinDetectableAzimuthInDegrees,
+            MaxDetectableAzimuthInDegrees,
+            MinDetectableAltitudeInDegrees,
+            MaxDetectableAltitudeInDegrees,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceSensor3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHumanPresenceSensorExtension_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, deviceinterface: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorExtension", "Initialize", 6usize, |this| ((**(this as *const *const IHumanPresenceSensorExtension_Vtbl)).Initialize)(this, deviceinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorExtension", "Start", 7usize, |this| ((**(this as *const *const IHumanPresenceSensorExtension_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessReading(this: *mut core::ffi::c_void, reading: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorExtension", "ProcessReading", 8usize, |this| ((**(this as *const *const IHumanPresenceSensorExtension_Vtbl)).ProcessReading)(this, reading, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessReadingTimeoutExpired(this: *mut core::ffi::c_void, reading: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorExtension", "ProcessReadingTimeoutExpired", 9usize, |this| ((**(this as *const *const IHumanPresenceSensorExtension_Vtbl)).ProcessReadingTimeoutExpired)(this, reading), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorExtension", "Stop", 10usize, |this| ((**(this as *const *const IHumanPresenceSensorExtension_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uninitialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorExtension", "Uninitialize", 11usize, |this| ((**(this as *const *const IHumanPresenceSensorExtension_Vtbl)).Uninitialize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorExtension", "Reset", 12usize, |this| ((**(this as *const *const IHumanPresenceSensorExtension_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Start,
+            ProcessReading,
+            ProcessReadingTimeoutExpired,
+            Stop,
+            Uninitialize,
+            Reset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceSensorExtension as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHumanPresenceSensorReading_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReading", "Timestamp", 6usize, |this| ((**(this as *const *const IHumanPresenceSensorReading_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Presence(this: *mut core::ffi::c_void, result__: *mut HumanPresence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReading", "Presence", 7usize, |this| ((**(this as *const *const IHumanPresenceSensorReading_Vtbl)).Presence)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Engagement(this: *mut core::ffi::c_void, result__: *mut HumanEngagement) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReading", "Engagement", 8usize, |this| ((**(this as *const *const IHumanPresenceSensorReading_Vtbl)).Engagement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DistanceInMillimeters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReading", "DistanceInMillimeters", 9usize, |this| ((**(this as *const *const IHumanPresenceSensorReading_Vtbl)).DistanceInMillimeters)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, Presence, Engagement, DistanceInMillimeters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceSensorReading as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHumanPresenceSensorReading2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReading2", "Properties", 6usize, |this| ((**(this as *const *const IHumanPresenceSensorReading2_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceSensorReading2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHumanPresenceSensorReading3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnlookerPresence(this: *mut core::ffi::c_void, result__: *mut HumanPresence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReading3", "OnlookerPresence", 6usize, |this| ((**(this as *const *const IHumanPresenceSensorReading3_Vtbl)).OnlookerPresence)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DetectedPersons(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReading3", "DetectedPersons", 7usize, |this| ((**(this as *const *const IHumanPresenceSensorReading3_Vtbl)).DetectedPersons)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnlookerPresence,
+            #[cfg(feature = "Foundation_Collections")]
+            DetectedPersons,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DetectedPersons: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceSensorReading3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHumanPresenceSensorReadingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReadingChangedEventArgs", "Reading", 6usize, |this| ((**(this as *const *const IHumanPresenceSensorReadingChangedEventArgs_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceSensorReadingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHumanPresenceSensorReadingUpdate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReadingUpdate", "Timestamp", 6usize, |this| ((**(this as *const *const IHumanPresenceSensorReadingUpdate_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimestamp(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReadingUpdate", "SetTimestamp", 7usize, |this| ((**(this as *const *const IHumanPresenceSensorReadingUpdate_Vtbl)).SetTimestamp)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Presence(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReadingUpdate", "Presence", 8usize, |this| ((**(this as *const *const IHumanPresenceSensorReadingUpdate_Vtbl)).Presence)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPresence(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReadingUpdate", "SetPresence", 9usize, |this| ((**(this as *const *const IHumanPresenceSensorReadingUpdate_Vtbl)).SetPresence)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Engagement(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReadingUpdate", "Engagement", 10usize, |this| ((**(this as *const *const IHumanPresenceSensorReadingUpdate_Vtbl)).Engagement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEngagement(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReadingUpdate", "SetEngagement", 11usize, |this| ((**(this as *const *const IHumanPresenceSensorReadingUpdate_Vtbl)).SetEngagement)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DistanceInMillimeters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReadingUpdate", "DistanceInMillimeters", 12usize, |this| ((**(this as *const *const IHumanPresenceSensorReadingUpdate_Vtbl)).DistanceInMillimeters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDistanceInMillimeters(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorReadingUpdate", "SetDistanceInMillimeters", 13usize, |this| ((**(this as *const *const IHumanPresenceSensorReadingUpdate_Vtbl)).SetDistanceInMillimeters)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Timestamp,
+            SetTimestamp,
+            Presence,
+            SetPresence,
+            Engagement,
+            SetEngagement,
+            DistanceInMillimeters,
+            SetDistanceInMillimeters,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceSensorReadingUpdate as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHumanPresenceSensorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IHumanPresenceSensorStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, sensorid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const IHumanPresenceSensorStatics_Vtbl)).FromIdAsync)(this, sensorid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorStatics", "GetDefaultAsync", 8usize, |this| ((**(this as *const *const IHumanPresenceSensorStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync, GetDefaultAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceSensorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHumanPresenceSensorStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromId(this: *mut core::ffi::c_void, sensorid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorStatics2", "FromId", 6usize, |this| ((**(this as *const *const IHumanPresenceSensorStatics2_Vtbl)).FromId)(this, sensorid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSensorStatics2", "GetDefault", 7usize, |this| ((**(this as *const *const IHumanPresenceSensorStatics2_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromId, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceSensorStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHumanPresenceSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SensorId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings", "SensorId", 6usize, |this| ((**(this as *const *const IHumanPresenceSettings_Vtbl)).SensorId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSensorId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings", "SetSensorId", 7usize, |this| ((**(this as *const *const IHumanPresenceSettings_Vtbl)).SetSensorId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWakeOnApproachEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings", "IsWakeOnApproachEnabled", 8usize, |this| ((**(this as *const *const IHumanPresenceSettings_Vtbl)).IsWakeOnApproachEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsWakeOnApproachEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings", "SetIsWakeOnApproachEnabled", 9usize, |this| ((**(this as *const *const IHumanPresenceSettings_Vtbl)).SetIsWakeOnApproachEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WakeOnApproachDistanceInMillimeters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings", "WakeOnApproachDistanceInMillimeters", 10usize, |this| ((**(this as *const *const IHumanPresenceSettings_Vtbl)).WakeOnApproachDistanceInMillimeters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWakeOnApproachDistanceInMillimeters(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings", "SetWakeOnApproachDistanceInMillimeters", 11usize, |this| ((**(this as *const *const IHumanPresenceSettings_Vtbl)).SetWakeOnApproachDistanceInMillimeters)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLockOnLeaveEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings", "IsLockOnLeaveEnabled", 12usize, |this| ((**(this as *const *const IHumanPresenceSettings_Vtbl)).IsLockOnLeaveEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsLockOnLeaveEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings", "SetIsLockOnLeaveEnabled", 13usize, |this| ((**(this as *const *const IHumanPresenceSettings_Vtbl)).SetIsLockOnLeaveEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockOnLeaveDistanceInMillimeters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings", "LockOnLeaveDistanceInMillimeters", 14usize, |this| ((**(this as *const *const IHumanPresenceSettings_Vtbl)).LockOnLeaveDistanceInMillimeters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLockOnLeaveDistanceInMillimeters(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings", "SetLockOnLeaveDistanceInMillimeters", 15usize, |this| ((**(this as *const *const IHumanPresenceSettings_Vtbl)).SetLockOnLeaveDistanceInMillimeters)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockOnLeaveTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings", "LockOnLeaveTimeout", 16usize, |this| ((**(this as *const *const IHumanPresenceSettings_Vtbl)).LockOnLeaveTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLockOnLeaveTimeout(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings", "SetLockOnLeaveTimeout", 17usize, |this| ((**(this as *const *const IHumanPresenceSettings_Vtbl)).SetLockOnLeaveTimeout)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsAttentionAwareDimmingEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings", "IsAttentionAwareDimmingEnabled", 18usize, |this| ((**(this as *const *const IHumanPresenceSettings_Vtbl)).IsAttentionAwareDimmingEnabled)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetIsAttentionAwareDimmingEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings", "SetIsAttentionAwareDimmingEnabled", 19usize, |this| ((**(this as *const *const IHumanPresenceSettings_Vtbl)).SetIsAttentionAwareDimmingEnabled)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SensorId,
+            SetSensorId,
+            IsWakeOnApproachEnabled,
+            SetIsWakeOnApproachEnabled,
+            WakeOnApproachDistanceInMillimeters,
+            SetWakeOnApproachDistanceInMillimeters,
+            IsLockOnLeaveEnabled,
+            SetIsLockOnLeaveEnabled,
+            LockOnLeaveDistanceInMillimeters,
+            SetLockOnLeaveDistanceInMillimeters,
+            LockOnLeaveTimeout,
+            SetLockOnLeaveTimeout,
+            #[cfg(feature = "deprecated")]
+            IsAttentionAwareDimmingEnabled,
+            #[cfg(not(feature = "deprecated"))]
+            IsAttentionAwareDimmingEnabled: 0,
+            #[cfg(feature = "deprecated")]
+            SetIsAttentionAwareDimmingEnabled,
+            #[cfg(not(feature = "deprecated"))]
+            SetIsAttentionAwareDimmingEnabled: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHumanPresenceSettings2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAdaptiveDimmingEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings2", "IsAdaptiveDimmingEnabled", 6usize, |this| ((**(this as *const *const IHumanPresenceSettings2_Vtbl)).IsAdaptiveDimmingEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsAdaptiveDimmingEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings2", "SetIsAdaptiveDimmingEnabled", 7usize, |this| ((**(this as *const *const IHumanPresenceSettings2_Vtbl)).SetIsAdaptiveDimmingEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WakeOptions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings2", "WakeOptions", 8usize, |this| ((**(this as *const *const IHumanPresenceSettings2_Vtbl)).WakeOptions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DimmingOptions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings2", "DimmingOptions", 9usize, |this| ((**(this as *const *const IHumanPresenceSettings2_Vtbl)).DimmingOptions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockOptions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettings2", "LockOptions", 10usize, |this| ((**(this as *const *const IHumanPresenceSettings2_Vtbl)).LockOptions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsAdaptiveDimmingEnabled,
+            SetIsAdaptiveDimmingEnabled,
+            WakeOptions,
+            DimmingOptions,
+            LockOptions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceSettings2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHumanPresenceSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentSettingsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettingsStatics", "GetCurrentSettingsAsync", 6usize, |this| ((**(this as *const *const IHumanPresenceSettingsStatics_Vtbl)).GetCurrentSettingsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettingsStatics", "GetCurrentSettings", 7usize, |this| ((**(this as *const *const IHumanPresenceSettingsStatics_Vtbl)).GetCurrentSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateSettingsAsync(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettingsStatics", "UpdateSettingsAsync", 8usize, |this| ((**(this as *const *const IHumanPresenceSettingsStatics_Vtbl)).UpdateSettingsAsync)(this, settings, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateSettings(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettingsStatics", "UpdateSettings", 9usize, |this| ((**(this as *const *const IHumanPresenceSettingsStatics_Vtbl)).UpdateSettings)(this, settings), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedFeaturesForSensorIdAsync(this: *mut core::ffi::c_void, sensorid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettingsStatics", "GetSupportedFeaturesForSensorIdAsync", 10usize, |this| ((**(this as *const *const IHumanPresenceSettingsStatics_Vtbl)).GetSupportedFeaturesForSensorIdAsync)(this, sensorid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedFeaturesForSensorId(this: *mut core::ffi::c_void, sensorid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettingsStatics", "GetSupportedFeaturesForSensorId", 11usize, |this| ((**(this as *const *const IHumanPresenceSettingsStatics_Vtbl)).GetSupportedFeaturesForSensorId)(this, sensorid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSupportedLockOnLeaveTimeouts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettingsStatics", "GetSupportedLockOnLeaveTimeouts", 12usize, |this| ((**(this as *const *const IHumanPresenceSettingsStatics_Vtbl)).GetSupportedLockOnLeaveTimeouts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SettingsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettingsStatics", "SettingsChanged", 13usize, |this| ((**(this as *const *const IHumanPresenceSettingsStatics_Vtbl)).SettingsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSettingsChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHumanPresenceSettingsStatics", "RemoveSettingsChanged", 14usize, |this| ((**(this as *const *const IHumanPresenceSettingsStatics_Vtbl)).RemoveSettingsChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentSettingsAsync,
+            GetCurrentSettings,
+            UpdateSettingsAsync,
+            UpdateSettings,
+            GetSupportedFeaturesForSensorIdAsync,
+            GetSupportedFeaturesForSensorId,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSupportedLockOnLeaveTimeouts,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSupportedLockOnLeaveTimeouts: 0,
+            SettingsChanged,
+            RemoveSettingsChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHumanPresenceSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInclinometer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometer", "GetCurrentReading", 6usize, |this| ((**(this as *const *const IInclinometer_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometer", "MinimumReportInterval", 7usize, |this| ((**(this as *const *const IInclinometer_Vtbl)).MinimumReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportInterval(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometer", "SetReportInterval", 8usize, |this| ((**(this as *const *const IInclinometer_Vtbl)).SetReportInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometer", "ReportInterval", 9usize, |this| ((**(this as *const *const IInclinometer_Vtbl)).ReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometer", "ReadingChanged", 10usize, |this| ((**(this as *const *const IInclinometer_Vtbl)).ReadingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometer", "RemoveReadingChanged", 11usize, |this| ((**(this as *const *const IInclinometer_Vtbl)).RemoveReadingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentReading,
+            MinimumReportInterval,
+            SetReportInterval,
+            ReportInterval,
+            ReadingChanged,
+            RemoveReadingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInclinometer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Display")]
+impl windows_core::imp::ProxyVtable for IInclinometer2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Display")]
+        unsafe extern "system" fn SetReadingTransform(this: *mut core::ffi::c_void, value: super::super::Graphics::Display::DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometer2", "SetReadingTransform", 6usize, |this| ((**(this as *const *const IInclinometer2_Vtbl)).SetReadingTransform)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Display")]
+        unsafe extern "system" fn ReadingTransform(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Display::DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometer2", "ReadingTransform", 7usize, |this| ((**(this as *const *const IInclinometer2_Vtbl)).ReadingTransform)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingType(this: *mut core::ffi::c_void, result__: *mut SensorReadingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometer2", "ReadingType", 8usize, |this| ((**(this as *const *const IInclinometer2_Vtbl)).ReadingType)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Display")]
+            SetReadingTransform,
+            #[cfg(not(feature = "Graphics_Display"))]
+            SetReadingTransform: 0,
+            #[cfg(feature = "Graphics_Display")]
+            ReadingTransform,
+            #[cfg(not(feature = "Graphics_Display"))]
+            ReadingTransform: 0,
+            ReadingType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInclinometer2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInclinometer3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetReportLatency(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometer3", "SetReportLatency", 6usize, |this| ((**(this as *const *const IInclinometer3_Vtbl)).SetReportLatency)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportLatency(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometer3", "ReportLatency", 7usize, |this| ((**(this as *const *const IInclinometer3_Vtbl)).ReportLatency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBatchSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometer3", "MaxBatchSize", 8usize, |this| ((**(this as *const *const IInclinometer3_Vtbl)).MaxBatchSize)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetReportLatency, ReportLatency, MaxBatchSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInclinometer3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInclinometer4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportThreshold(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometer4", "ReportThreshold", 6usize, |this| ((**(this as *const *const IInclinometer4_Vtbl)).ReportThreshold)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportThreshold }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInclinometer4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInclinometerDataThreshold_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PitchInDegrees(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerDataThreshold", "PitchInDegrees", 6usize, |this| ((**(this as *const *const IInclinometerDataThreshold_Vtbl)).PitchInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPitchInDegrees(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerDataThreshold", "SetPitchInDegrees", 7usize, |this| ((**(this as *const *const IInclinometerDataThreshold_Vtbl)).SetPitchInDegrees)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RollInDegrees(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerDataThreshold", "RollInDegrees", 8usize, |this| ((**(this as *const *const IInclinometerDataThreshold_Vtbl)).RollInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRollInDegrees(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerDataThreshold", "SetRollInDegrees", 9usize, |this| ((**(this as *const *const IInclinometerDataThreshold_Vtbl)).SetRollInDegrees)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn YawInDegrees(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerDataThreshold", "YawInDegrees", 10usize, |this| ((**(this as *const *const IInclinometerDataThreshold_Vtbl)).YawInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetYawInDegrees(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerDataThreshold", "SetYawInDegrees", 11usize, |this| ((**(this as *const *const IInclinometerDataThreshold_Vtbl)).SetYawInDegrees)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PitchInDegrees,
+            SetPitchInDegrees,
+            RollInDegrees,
+            SetRollInDegrees,
+            YawInDegrees,
+            SetYawInDegrees,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInclinometerDataThreshold as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInclinometerDeviceId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerDeviceId", "DeviceId", 6usize, |this| ((**(this as *const *const IInclinometerDeviceId_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInclinometerDeviceId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInclinometerReading_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerReading", "Timestamp", 6usize, |this| ((**(this as *const *const IInclinometerReading_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PitchDegrees(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerReading", "PitchDegrees", 7usize, |this| ((**(this as *const *const IInclinometerReading_Vtbl)).PitchDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RollDegrees(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerReading", "RollDegrees", 8usize, |this| ((**(this as *const *const IInclinometerReading_Vtbl)).RollDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn YawDegrees(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerReading", "YawDegrees", 9usize, |this| ((**(this as *const *const IInclinometerReading_Vtbl)).YawDegrees)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, PitchDegrees, RollDegrees, YawDegrees }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInclinometerReading as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInclinometerReading2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PerformanceCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerReading2", "PerformanceCount", 6usize, |this| ((**(this as *const *const IInclinometerReading2_Vtbl)).PerformanceCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerReading2", "Properties", 7usize, |this| ((**(this as *const *const IInclinometerReading2_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PerformanceCount,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInclinometerReading2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInclinometerReadingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerReadingChangedEventArgs", "Reading", 6usize, |this| ((**(this as *const *const IInclinometerReadingChangedEventArgs_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInclinometerReadingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInclinometerReadingYawAccuracy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn YawAccuracy(this: *mut core::ffi::c_void, result__: *mut MagnetometerAccuracy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerReadingYawAccuracy", "YawAccuracy", 6usize, |this| ((**(this as *const *const IInclinometerReadingYawAccuracy_Vtbl)).YawAccuracy)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, YawAccuracy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInclinometerReadingYawAccuracy as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInclinometerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IInclinometerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInclinometerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInclinometerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultForRelativeReadings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerStatics2", "GetDefaultForRelativeReadings", 6usize, |this| ((**(this as *const *const IInclinometerStatics2_Vtbl)).GetDefaultForRelativeReadings)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefaultForRelativeReadings }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInclinometerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInclinometerStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultWithSensorReadingType(this: *mut core::ffi::c_void, sensorreadingtype: SensorReadingType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerStatics3", "GetDefaultWithSensorReadingType", 6usize, |this| ((**(this as *const *const IInclinometerStatics3_Vtbl)).GetDefaultWithSensorReadingType)(this, sensorreadingtype, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefaultWithSensorReadingType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInclinometerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInclinometerStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, readingtype: SensorReadingType, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerStatics4", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IInclinometerStatics4_Vtbl)).GetDeviceSelector)(this, readingtype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInclinometerStatics4", "FromIdAsync", 7usize, |this| ((**(this as *const *const IInclinometerStatics4_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInclinometerStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILightSensor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensor", "GetCurrentReading", 6usize, |this| ((**(this as *const *const ILightSensor_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensor", "MinimumReportInterval", 7usize, |this| ((**(this as *const *const ILightSensor_Vtbl)).MinimumReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportInterval(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensor", "SetReportInterval", 8usize, |this| ((**(this as *const *const ILightSensor_Vtbl)).SetReportInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensor", "ReportInterval", 9usize, |this| ((**(this as *const *const ILightSensor_Vtbl)).ReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensor", "ReadingChanged", 10usize, |this| ((**(this as *const *const ILightSensor_Vtbl)).ReadingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensor", "RemoveReadingChanged", 11usize, |this| ((**(this as *const *const ILightSensor_Vtbl)).RemoveReadingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentReading,
+            MinimumReportInterval,
+            SetReportInterval,
+            ReportInterval,
+            ReadingChanged,
+            RemoveReadingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILightSensor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILightSensor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetReportLatency(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensor2", "SetReportLatency", 6usize, |this| ((**(this as *const *const ILightSensor2_Vtbl)).SetReportLatency)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportLatency(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensor2", "ReportLatency", 7usize, |this| ((**(this as *const *const ILightSensor2_Vtbl)).ReportLatency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBatchSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensor2", "MaxBatchSize", 8usize, |this| ((**(this as *const *const ILightSensor2_Vtbl)).MaxBatchSize)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetReportLatency, ReportLatency, MaxBatchSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILightSensor2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILightSensor3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportThreshold(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensor3", "ReportThreshold", 6usize, |this| ((**(this as *const *const ILightSensor3_Vtbl)).ReportThreshold)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportThreshold }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILightSensor3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILightSensorDataThreshold_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LuxPercentage(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensorDataThreshold", "LuxPercentage", 6usize, |this| ((**(this as *const *const ILightSensorDataThreshold_Vtbl)).LuxPercentage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLuxPercentage(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensorDataThreshold", "SetLuxPercentage", 7usize, |this| ((**(this as *const *const ILightSensorDataThreshold_Vtbl)).SetLuxPercentage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbsoluteLux(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensorDataThreshold", "AbsoluteLux", 8usize, |this| ((**(this as *const *const ILightSensorDataThreshold_Vtbl)).AbsoluteLux)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAbsoluteLux(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensorDataThreshold", "SetAbsoluteLux", 9usize, |this| ((**(this as *const *const ILightSensorDataThreshold_Vtbl)).SetAbsoluteLux)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LuxPercentage, SetLuxPercentage, AbsoluteLux, SetAbsoluteLux }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILightSensorDataThreshold as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILightSensorDeviceId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensorDeviceId", "DeviceId", 6usize, |this| ((**(this as *const *const ILightSensorDeviceId_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILightSensorDeviceId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILightSensorReading_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensorReading", "Timestamp", 6usize, |this| ((**(this as *const *const ILightSensorReading_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IlluminanceInLux(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensorReading", "IlluminanceInLux", 7usize, |this| ((**(this as *const *const ILightSensorReading_Vtbl)).IlluminanceInLux)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, IlluminanceInLux }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILightSensorReading as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ILightSensorReading2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PerformanceCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensorReading2", "PerformanceCount", 6usize, |this| ((**(this as *const *const ILightSensorReading2_Vtbl)).PerformanceCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensorReading2", "Properties", 7usize, |this| ((**(this as *const *const ILightSensorReading2_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PerformanceCount,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILightSensorReading2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILightSensorReadingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensorReadingChangedEventArgs", "Reading", 6usize, |this| ((**(this as *const *const ILightSensorReadingChangedEventArgs_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILightSensorReadingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILightSensorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensorStatics", "GetDefault", 6usize, |this| ((**(this as *const *const ILightSensorStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILightSensorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILightSensorStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensorStatics2", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const ILightSensorStatics2_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILightSensorStatics2", "FromIdAsync", 7usize, |this| ((**(this as *const *const ILightSensorStatics2_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILightSensorStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILockOnLeaveOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowWhenExternalDisplayConnected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockOnLeaveOptions", "AllowWhenExternalDisplayConnected", 6usize, |this| ((**(this as *const *const ILockOnLeaveOptions_Vtbl)).AllowWhenExternalDisplayConnected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowWhenExternalDisplayConnected(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockOnLeaveOptions", "SetAllowWhenExternalDisplayConnected", 7usize, |this| ((**(this as *const *const ILockOnLeaveOptions_Vtbl)).SetAllowWhenExternalDisplayConnected)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AllowWhenExternalDisplayConnected,
+            SetAllowWhenExternalDisplayConnected,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILockOnLeaveOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagnetometer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometer", "GetCurrentReading", 6usize, |this| ((**(this as *const *const IMagnetometer_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometer", "MinimumReportInterval", 7usize, |this| ((**(this as *const *const IMagnetometer_Vtbl)).MinimumReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportInterval(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometer", "SetReportInterval", 8usize, |this| ((**(this as *const *const IMagnetometer_Vtbl)).SetReportInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometer", "ReportInterval", 9usize, |this| ((**(this as *const *const IMagnetometer_Vtbl)).ReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometer", "ReadingChanged", 10usize, |this| ((**(this as *const *const IMagnetometer_Vtbl)).ReadingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometer", "RemoveReadingChanged", 11usize, |this| ((**(this as *const *const IMagnetometer_Vtbl)).RemoveReadingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentReading,
+            MinimumReportInterval,
+            SetReportInterval,
+            ReportInterval,
+            ReadingChanged,
+            RemoveReadingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagnetometer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Display")]
+impl windows_core::imp::ProxyVtable for IMagnetometer2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Display")]
+        unsafe extern "system" fn SetReadingTransform(this: *mut core::ffi::c_void, value: super::super::Graphics::Display::DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometer2", "SetReadingTransform", 6usize, |this| ((**(this as *const *const IMagnetometer2_Vtbl)).SetReadingTransform)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Display")]
+        unsafe extern "system" fn ReadingTransform(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Display::DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometer2", "ReadingTransform", 7usize, |this| ((**(this as *const *const IMagnetometer2_Vtbl)).ReadingTransform)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Display")]
+            SetReadingTransform,
+            #[cfg(not(feature = "Graphics_Display"))]
+            SetReadingTransform: 0,
+            #[cfg(feature = "Graphics_Display")]
+            ReadingTransform,
+            #[cfg(not(feature = "Graphics_Display"))]
+            ReadingTransform: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagnetometer2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagnetometer3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetReportLatency(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometer3", "SetReportLatency", 6usize, |this| ((**(this as *const *const IMagnetometer3_Vtbl)).SetReportLatency)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportLatency(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometer3", "ReportLatency", 7usize, |this| ((**(this as *const *const IMagnetometer3_Vtbl)).ReportLatency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBatchSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometer3", "MaxBatchSize", 8usize, |this| ((**(this as *const *const IMagnetometer3_Vtbl)).MaxBatchSize)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetReportLatency, ReportLatency, MaxBatchSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagnetometer3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagnetometer4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportThreshold(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometer4", "ReportThreshold", 6usize, |this| ((**(this as *const *const IMagnetometer4_Vtbl)).ReportThreshold)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportThreshold }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagnetometer4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagnetometerDataThreshold_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn XAxisMicroteslas(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerDataThreshold", "XAxisMicroteslas", 6usize, |this| ((**(this as *const *const IMagnetometerDataThreshold_Vtbl)).XAxisMicroteslas)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetXAxisMicroteslas(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerDataThreshold", "SetXAxisMicroteslas", 7usize, |this| ((**(this as *const *const IMagnetometerDataThreshold_Vtbl)).SetXAxisMicroteslas)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn YAxisMicroteslas(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerDataThreshold", "YAxisMicroteslas", 8usize, |this| ((**(this as *const *const IMagnetometerDataThreshold_Vtbl)).YAxisMicroteslas)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetYAxisMicroteslas(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerDataThreshold", "SetYAxisMicroteslas", 9usize, |this| ((**(this as *const *const IMagnetometerDataThreshold_Vtbl)).SetYAxisMicroteslas)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZAxisMicroteslas(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerDataThreshold", "ZAxisMicroteslas", 10usize, |this| ((**(this as *const *const IMagnetometerDataThreshold_Vtbl)).ZAxisMicroteslas)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetZAxisMicroteslas(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerDataThreshold", "SetZAxisMicroteslas", 11usize, |this| ((**(this as *const *const IMagnetometerDataThreshold_Vtbl)).SetZAxisMicroteslas)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            XAxisMicroteslas,
+            SetXAxisMicroteslas,
+            YAxisMicroteslas,
+            SetYAxisMicroteslas,
+            ZAxisMicroteslas,
+            SetZAxisMicroteslas,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagnetometerDataThreshold as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagnetometerDeviceId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerDeviceId", "DeviceId", 6usize, |this| ((**(this as *const *const IMagnetometerDeviceId_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagnetometerDeviceId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagnetometerReading_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerReading", "Timestamp", 6usize, |this| ((**(this as *const *const IMagnetometerReading_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MagneticFieldX(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerReading", "MagneticFieldX", 7usize, |this| ((**(this as *const *const IMagnetometerReading_Vtbl)).MagneticFieldX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MagneticFieldY(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerReading", "MagneticFieldY", 8usize, |this| ((**(this as *const *const IMagnetometerReading_Vtbl)).MagneticFieldY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MagneticFieldZ(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerReading", "MagneticFieldZ", 9usize, |this| ((**(this as *const *const IMagnetometerReading_Vtbl)).MagneticFieldZ)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DirectionalAccuracy(this: *mut core::ffi::c_void, result__: *mut MagnetometerAccuracy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerReading", "DirectionalAccuracy", 10usize, |this| ((**(this as *const *const IMagnetometerReading_Vtbl)).DirectionalAccuracy)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Timestamp,
+            MagneticFieldX,
+            MagneticFieldY,
+            MagneticFieldZ,
+            DirectionalAccuracy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagnetometerReading as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMagnetometerReading2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PerformanceCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerReading2", "PerformanceCount", 6usize, |this| ((**(this as *const *const IMagnetometerReading2_Vtbl)).PerformanceCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerReading2", "Properties", 7usize, |this| ((**(this as *const *const IMagnetometerReading2_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PerformanceCount,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagnetometerReading2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagnetometerReadingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerReadingChangedEventArgs", "Reading", 6usize, |this| ((**(this as *const *const IMagnetometerReadingChangedEventArgs_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagnetometerReadingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagnetometerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IMagnetometerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagnetometerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMagnetometerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerStatics2", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IMagnetometerStatics2_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMagnetometerStatics2", "FromIdAsync", 7usize, |this| ((**(this as *const *const IMagnetometerStatics2_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMagnetometerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOrientationSensor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensor", "GetCurrentReading", 6usize, |this| ((**(this as *const *const IOrientationSensor_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensor", "MinimumReportInterval", 7usize, |this| ((**(this as *const *const IOrientationSensor_Vtbl)).MinimumReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportInterval(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensor", "SetReportInterval", 8usize, |this| ((**(this as *const *const IOrientationSensor_Vtbl)).SetReportInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensor", "ReportInterval", 9usize, |this| ((**(this as *const *const IOrientationSensor_Vtbl)).ReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensor", "ReadingChanged", 10usize, |this| ((**(this as *const *const IOrientationSensor_Vtbl)).ReadingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensor", "RemoveReadingChanged", 11usize, |this| ((**(this as *const *const IOrientationSensor_Vtbl)).RemoveReadingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentReading,
+            MinimumReportInterval,
+            SetReportInterval,
+            ReportInterval,
+            ReadingChanged,
+            RemoveReadingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOrientationSensor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Display")]
+impl windows_core::imp::ProxyVtable for IOrientationSensor2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Display")]
+        unsafe extern "system" fn SetReadingTransform(this: *mut core::ffi::c_void, value: super::super::Graphics::Display::DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensor2", "SetReadingTransform", 6usize, |this| ((**(this as *const *const IOrientationSensor2_Vtbl)).SetReadingTransform)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Display")]
+        unsafe extern "system" fn ReadingTransform(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Display::DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensor2", "ReadingTransform", 7usize, |this| ((**(this as *const *const IOrientationSensor2_Vtbl)).ReadingTransform)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingType(this: *mut core::ffi::c_void, result__: *mut SensorReadingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensor2", "ReadingType", 8usize, |this| ((**(this as *const *const IOrientationSensor2_Vtbl)).ReadingType)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Display")]
+            SetReadingTransform,
+            #[cfg(not(feature = "Graphics_Display"))]
+            SetReadingTransform: 0,
+            #[cfg(feature = "Graphics_Display")]
+            ReadingTransform,
+            #[cfg(not(feature = "Graphics_Display"))]
+            ReadingTransform: 0,
+            ReadingType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOrientationSensor2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOrientationSensor3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetReportLatency(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensor3", "SetReportLatency", 6usize, |this| ((**(this as *const *const IOrientationSensor3_Vtbl)).SetReportLatency)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportLatency(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensor3", "ReportLatency", 7usize, |this| ((**(this as *const *const IOrientationSensor3_Vtbl)).ReportLatency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBatchSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensor3", "MaxBatchSize", 8usize, |this| ((**(this as *const *const IOrientationSensor3_Vtbl)).MaxBatchSize)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetReportLatency, ReportLatency, MaxBatchSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOrientationSensor3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOrientationSensorDeviceId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensorDeviceId", "DeviceId", 6usize, |this| ((**(this as *const *const IOrientationSensorDeviceId_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOrientationSensorDeviceId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOrientationSensorReading_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensorReading", "Timestamp", 6usize, |this| ((**(this as *const *const IOrientationSensorReading_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationMatrix(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensorReading", "RotationMatrix", 7usize, |this| ((**(this as *const *const IOrientationSensorReading_Vtbl)).RotationMatrix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Quaternion(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensorReading", "Quaternion", 8usize, |this| ((**(this as *const *const IOrientationSensorReading_Vtbl)).Quaternion)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, RotationMatrix, Quaternion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOrientationSensorReading as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IOrientationSensorReading2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PerformanceCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensorReading2", "PerformanceCount", 6usize, |this| ((**(this as *const *const IOrientationSensorReading2_Vtbl)).PerformanceCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensorReading2", "Properties", 7usize, |this| ((**(this as *const *const IOrientationSensorReading2_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PerformanceCount,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOrientationSensorReading2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOrientationSensorReadingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensorReadingChangedEventArgs", "Reading", 6usize, |this| ((**(this as *const *const IOrientationSensorReadingChangedEventArgs_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOrientationSensorReadingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOrientationSensorReadingYawAccuracy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn YawAccuracy(this: *mut core::ffi::c_void, result__: *mut MagnetometerAccuracy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensorReadingYawAccuracy", "YawAccuracy", 6usize, |this| ((**(this as *const *const IOrientationSensorReadingYawAccuracy_Vtbl)).YawAccuracy)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, YawAccuracy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOrientationSensorReadingYawAccuracy as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOrientationSensorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensorStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IOrientationSensorStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOrientationSensorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOrientationSensorStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultForRelativeReadings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensorStatics2", "GetDefaultForRelativeReadings", 6usize, |this| ((**(this as *const *const IOrientationSensorStatics2_Vtbl)).GetDefaultForRelativeReadings)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefaultForRelativeReadings }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOrientationSensorStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOrientationSensorStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultWithSensorReadingType(this: *mut core::ffi::c_void, sensorreadingtype: SensorReadingType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensorStatics3", "GetDefaultWithSensorReadingType", 6usize, |this| ((**(this as *const *const IOrientationSensorStatics3_Vtbl)).GetDefaultWithSensorReadingType)(this, sensorreadingtype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultWithSensorReadingTypeAndSensorOptimizationGoal(this: *mut core::ffi::c_void, sensorreadingtype: SensorReadingType, optimizationgoal: SensorOptimizationGoal, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensorStatics3", "GetDefaultWithSensorReadingTypeAndSensorOptimizationGoal", 7usize, |this| ((**(this as *const *const IOrientationSensorStatics3_Vtbl)).GetDefaultWithSensorReadingTypeAndSensorOptimizationGoal)(this, sensorreadingtype, optimizationgoal, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDefaultWithSensorReadingType,
+            GetDefaultWithSensorReadingTypeAndSensorOptimizationGoal,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOrientationSensorStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOrientationSensorStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, readingtype: SensorReadingType, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensorStatics4", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IOrientationSensorStatics4_Vtbl)).GetDeviceSelector)(this, readingtype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorWithSensorReadingTypeAndSensorOptimizationGoal(this: *mut core::ffi::c_void, readingtype: SensorReadingType, optimizationgoal: SensorOptimizationGoal, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensorStatics4", "GetDeviceSelectorWithSensorReadingTypeAndSensorOptimizationGoal", 7usize, |this| ((**(this as *const *const IOrientationSensorStatics4_Vtbl)).GetDeviceSelectorWithSensorReadingTypeAndSensorOptimizationGoal)(this, readingtype, optimizationgoal, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOrientationSensorStatics4", "FromIdAsync", 8usize, |this| ((**(this as *const *const IOrientationSensorStatics4_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceSelector,
+            GetDeviceSelectorWithSensorReadingTypeAndSensorOptimizationGoal,
+            FromIdAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOrientationSensorStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPedometer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometer", "DeviceId", 6usize, |this| ((**(this as *const *const IPedometer_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerInMilliwatts(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometer", "PowerInMilliwatts", 7usize, |this| ((**(this as *const *const IPedometer_Vtbl)).PowerInMilliwatts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometer", "MinimumReportInterval", 8usize, |this| ((**(this as *const *const IPedometer_Vtbl)).MinimumReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportInterval(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometer", "SetReportInterval", 9usize, |this| ((**(this as *const *const IPedometer_Vtbl)).SetReportInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInterval(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometer", "ReportInterval", 10usize, |this| ((**(this as *const *const IPedometer_Vtbl)).ReportInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometer", "ReadingChanged", 11usize, |this| ((**(this as *const *const IPedometer_Vtbl)).ReadingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometer", "RemoveReadingChanged", 12usize, |this| ((**(this as *const *const IPedometer_Vtbl)).RemoveReadingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            PowerInMilliwatts,
+            MinimumReportInterval,
+            SetReportInterval,
+            ReportInterval,
+            ReadingChanged,
+            RemoveReadingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPedometer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPedometer2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCurrentReadings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometer2", "GetCurrentReadings", 6usize, |this| ((**(this as *const *const IPedometer2_Vtbl)).GetCurrentReadings)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCurrentReadings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCurrentReadings: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPedometer2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPedometerDataThresholdFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, sensor: *mut core::ffi::c_void, stepgoal: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometerDataThresholdFactory", "Create", 6usize, |this| ((**(this as *const *const IPedometerDataThresholdFactory_Vtbl)).Create)(this, sensor, stepgoal, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPedometerDataThresholdFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPedometerReading_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StepKind(this: *mut core::ffi::c_void, result__: *mut PedometerStepKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometerReading", "StepKind", 6usize, |this| ((**(this as *const *const IPedometerReading_Vtbl)).StepKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CumulativeSteps(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometerReading", "CumulativeSteps", 7usize, |this| ((**(this as *const *const IPedometerReading_Vtbl)).CumulativeSteps)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometerReading", "Timestamp", 8usize, |this| ((**(this as *const *const IPedometerReading_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CumulativeStepsDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometerReading", "CumulativeStepsDuration", 9usize, |this| ((**(this as *const *const IPedometerReading_Vtbl)).CumulativeStepsDuration)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StepKind,
+            CumulativeSteps,
+            Timestamp,
+            CumulativeStepsDuration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPedometerReading as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPedometerReadingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometerReadingChangedEventArgs", "Reading", 6usize, |this| ((**(this as *const *const IPedometerReadingChangedEventArgs_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPedometerReadingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPedometerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometerStatics", "FromIdAsync", 6usize, |this| ((**(this as *const *const IPedometerStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometerStatics", "GetDefaultAsync", 7usize, |this| ((**(this as *const *const IPedometerStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometerStatics", "GetDeviceSelector", 8usize, |this| ((**(this as *const *const IPedometerStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSystemHistoryAsync(this: *mut core::ffi::c_void, fromtime: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometerStatics", "GetSystemHistoryAsync", 9usize, |this| ((**(this as *const *const IPedometerStatics_Vtbl)).GetSystemHistoryAsync)(this, fromtime, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSystemHistoryWithDurationAsync(this: *mut core::ffi::c_void, fromtime: super::super::Foundation::DateTime, duration: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometerStatics", "GetSystemHistoryWithDurationAsync", 10usize, |this| ((**(this as *const *const IPedometerStatics_Vtbl)).GetSystemHistoryWithDurationAsync)(this, fromtime, duration, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FromIdAsync,
+            GetDefaultAsync,
+            GetDeviceSelector,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSystemHistoryAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSystemHistoryAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSystemHistoryWithDurationAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSystemHistoryWithDurationAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPedometerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPedometerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetReadingsFromTriggerDetails(this: *mut core::ffi::c_void, triggerdetails: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPedometerStatics2", "GetReadingsFromTriggerDetails", 6usize, |this| ((**(this as *const *const IPedometerStatics2_Vtbl)).GetReadingsFromTriggerDetails)(this, triggerdetails, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetReadingsFromTriggerDetails,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetReadingsFromTriggerDetails: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPedometerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProximitySensor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximitySensor", "DeviceId", 6usize, |this| ((**(this as *const *const IProximitySensor_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxDistanceInMillimeters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximitySensor", "MaxDistanceInMillimeters", 7usize, |this| ((**(this as *const *const IProximitySensor_Vtbl)).MaxDistanceInMillimeters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinDistanceInMillimeters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximitySensor", "MinDistanceInMillimeters", 8usize, |this| ((**(this as *const *const IProximitySensor_Vtbl)).MinDistanceInMillimeters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximitySensor", "GetCurrentReading", 9usize, |this| ((**(this as *const *const IProximitySensor_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximitySensor", "ReadingChanged", 10usize, |this| ((**(this as *const *const IProximitySensor_Vtbl)).ReadingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveReadingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximitySensor", "RemoveReadingChanged", 11usize, |this| ((**(this as *const *const IProximitySensor_Vtbl)).RemoveReadingChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDisplayOnOffController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximitySensor", "CreateDisplayOnOffController", 12usize, |this| ((**(this as *const *const IProximitySensor_Vtbl)).CreateDisplayOnOffController)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            MaxDistanceInMillimeters,
+            MinDistanceInMillimeters,
+            GetCurrentReading,
+            ReadingChanged,
+            RemoveReadingChanged,
+            CreateDisplayOnOffController,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProximitySensor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProximitySensorDataThresholdFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, sensor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximitySensorDataThresholdFactory", "Create", 6usize, |this| ((**(this as *const *const IProximitySensorDataThresholdFactory_Vtbl)).Create)(this, sensor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProximitySensorDataThresholdFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProximitySensorReading_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximitySensorReading", "Timestamp", 6usize, |this| ((**(this as *const *const IProximitySensorReading_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDetected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximitySensorReading", "IsDetected", 7usize, |this| ((**(this as *const *const IProximitySensorReading_Vtbl)).IsDetected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DistanceInMillimeters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximitySensorReading", "DistanceInMillimeters", 8usize, |this| ((**(this as *const *const IProximitySensorReading_Vtbl)).DistanceInMillimeters)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, IsDetected, DistanceInMillimeters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProximitySensorReading as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProximitySensorReadingChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximitySensorReadingChangedEventArgs", "Reading", 6usize, |this| ((**(this as *const *const IProximitySensorReadingChangedEventArgs_Vtbl)).Reading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProximitySensorReadingChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProximitySensorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximitySensorStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IProximitySensorStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromId(this: *mut core::ffi::c_void, sensorid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximitySensorStatics", "FromId", 7usize, |this| ((**(this as *const *const IProximitySensorStatics_Vtbl)).FromId)(this, sensorid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProximitySensorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IProximitySensorStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetReadingsFromTriggerDetails(this: *mut core::ffi::c_void, triggerdetails: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximitySensorStatics2", "GetReadingsFromTriggerDetails", 6usize, |this| ((**(this as *const *const IProximitySensorStatics2_Vtbl)).GetReadingsFromTriggerDetails)(this, triggerdetails, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetReadingsFromTriggerDetails,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetReadingsFromTriggerDetails: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProximitySensorStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISensorDataThreshold_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensorDataThreshold as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISensorDataThresholdTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorDataThresholdTriggerDetails", "DeviceId", 6usize, |this| ((**(this as *const *const ISensorDataThresholdTriggerDetails_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SensorType(this: *mut core::ffi::c_void, result__: *mut SensorType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorDataThresholdTriggerDetails", "SensorType", 7usize, |this| ((**(this as *const *const ISensorDataThresholdTriggerDetails_Vtbl)).SensorType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId, SensorType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensorDataThresholdTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISensorQuaternion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn W(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorQuaternion", "W", 6usize, |this| ((**(this as *const *const ISensorQuaternion_Vtbl)).W)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn X(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorQuaternion", "X", 7usize, |this| ((**(this as *const *const ISensorQuaternion_Vtbl)).X)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Y(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorQuaternion", "Y", 8usize, |this| ((**(this as *const *const ISensorQuaternion_Vtbl)).Y)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Z(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorQuaternion", "Z", 9usize, |this| ((**(this as *const *const ISensorQuaternion_Vtbl)).Z)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, W, X, Y, Z }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensorQuaternion as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISensorRotationMatrix_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn M11(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorRotationMatrix", "M11", 6usize, |this| ((**(this as *const *const ISensorRotationMatrix_Vtbl)).M11)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn M12(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorRotationMatrix", "M12", 7usize, |this| ((**(this as *const *const ISensorRotationMatrix_Vtbl)).M12)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn M13(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorRotationMatrix", "M13", 8usize, |this| ((**(this as *const *const ISensorRotationMatrix_Vtbl)).M13)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn M21(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorRotationMatrix", "M21", 9usize, |this| ((**(this as *const *const ISensorRotationMatrix_Vtbl)).M21)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn M22(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorRotationMatrix", "M22", 10usize, |this| ((**(this as *const *const ISensorRotationMatrix_Vtbl)).M22)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn M23(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorRotationMatrix", "M23", 11usize, |this| ((**(this as *const *const ISensorRotationMatrix_Vtbl)).M23)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn M31(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorRotationMatrix", "M31", 12usize, |this| ((**(this as *const *const ISensorRotationMatrix_Vtbl)).M31)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn M32(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorRotationMatrix", "M32", 13usize, |this| ((**(this as *const *const ISensorRotationMatrix_Vtbl)).M32)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn M33(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorRotationMatrix", "M33", 14usize, |this| ((**(this as *const *const ISensorRotationMatrix_Vtbl)).M33)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, M11, M12, M13, M21, M22, M23, M31, M32, M33 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensorRotationMatrix as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimpleOrientationSensor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentOrientation(this: *mut core::ffi::c_void, result__: *mut SimpleOrientation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleOrientationSensor", "GetCurrentOrientation", 6usize, |this| ((**(this as *const *const ISimpleOrientationSensor_Vtbl)).GetCurrentOrientation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OrientationChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleOrientationSensor", "OrientationChanged", 7usize, |this| ((**(this as *const *const ISimpleOrientationSensor_Vtbl)).OrientationChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOrientationChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleOrientationSensor", "RemoveOrientationChanged", 8usize, |this| ((**(this as *const *const ISimpleOrientationSensor_Vtbl)).RemoveOrientationChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentOrientation,
+            OrientationChanged,
+            RemoveOrientationChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimpleOrientationSensor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Display")]
+impl windows_core::imp::ProxyVtable for ISimpleOrientationSensor2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Display")]
+        unsafe extern "system" fn SetReadingTransform(this: *mut core::ffi::c_void, value: super::super::Graphics::Display::DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleOrientationSensor2", "SetReadingTransform", 6usize, |this| ((**(this as *const *const ISimpleOrientationSensor2_Vtbl)).SetReadingTransform)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Display")]
+        unsafe extern "system" fn ReadingTransform(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Display::DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleOrientationSensor2", "ReadingTransform", 7usize, |this| ((**(this as *const *const ISimpleOrientationSensor2_Vtbl)).ReadingTransform)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Display")]
+            SetReadingTransform,
+            #[cfg(not(feature = "Graphics_Display"))]
+            SetReadingTransform: 0,
+            #[cfg(feature = "Graphics_Display")]
+            ReadingTransform,
+            #[cfg(not(feature = "Graphics_Display"))]
+            ReadingTransform: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimpleOrientationSensor2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimpleOrientationSensorDeviceId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleOrientationSensorDeviceId", "DeviceId", 6usize, |this| ((**(this as *const *const ISimpleOrientationSensorDeviceId_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimpleOrientationSensorDeviceId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimpleOrientationSensorOrientationChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleOrientationSensorOrientationChangedEventArgs", "Timestamp", 6usize, |this| ((**(this as *const *const ISimpleOrientationSensorOrientationChangedEventArgs_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut SimpleOrientation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleOrientationSensorOrientationChangedEventArgs", "Orientation", 7usize, |this| ((**(this as *const *const ISimpleOrientationSensorOrientationChangedEventArgs_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, Orientation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimpleOrientationSensorOrientationChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimpleOrientationSensorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleOrientationSensorStatics", "GetDefault", 6usize, |this| ((**(this as *const *const ISimpleOrientationSensorStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimpleOrientationSensorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimpleOrientationSensorStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleOrientationSensorStatics2", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const ISimpleOrientationSensorStatics2_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleOrientationSensorStatics2", "FromIdAsync", 7usize, |this| ((**(this as *const *const ISimpleOrientationSensorStatics2_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimpleOrientationSensorStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWakeOnApproachOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowWhenExternalDisplayConnected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWakeOnApproachOptions", "AllowWhenExternalDisplayConnected", 6usize, |this| ((**(this as *const *const IWakeOnApproachOptions_Vtbl)).AllowWhenExternalDisplayConnected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowWhenExternalDisplayConnected(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWakeOnApproachOptions", "SetAllowWhenExternalDisplayConnected", 7usize, |this| ((**(this as *const *const IWakeOnApproachOptions_Vtbl)).SetAllowWhenExternalDisplayConnected)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableWhenBatterySaverOn(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWakeOnApproachOptions", "DisableWhenBatterySaverOn", 8usize, |this| ((**(this as *const *const IWakeOnApproachOptions_Vtbl)).DisableWhenBatterySaverOn)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisableWhenBatterySaverOn(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWakeOnApproachOptions", "SetDisableWhenBatterySaverOn", 9usize, |this| ((**(this as *const *const IWakeOnApproachOptions_Vtbl)).SetDisableWhenBatterySaverOn)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AllowWhenExternalDisplayConnected,
+            SetAllowWhenExternalDisplayConnected,
+            DisableWhenBatterySaverOn,
+            SetDisableWhenBatterySaverOn,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWakeOnApproachOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
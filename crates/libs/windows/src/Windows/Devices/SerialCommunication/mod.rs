@@ -393,6 +393,7 @@ impl SerialError {
 impl windows_core::TypeKind for SerialError {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SerialError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SerialError").field(&self.0).finish()
@@ -413,6 +414,7 @@ impl SerialHandshake {
 impl windows_core::TypeKind for SerialHandshake {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SerialHandshake {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SerialHandshake").field(&self.0).finish()
@@ -434,6 +436,7 @@ impl SerialParity {
 impl windows_core::TypeKind for SerialParity {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SerialParity {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SerialParity").field(&self.0).finish()
@@ -455,6 +458,7 @@ impl SerialPinChange {
 impl windows_core::TypeKind for SerialPinChange {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SerialPinChange {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SerialPinChange").field(&self.0).finish()
@@ -474,6 +478,7 @@ impl SerialStopBitCount {
 impl windows_core::TypeKind for SerialStopBitCount {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SerialStopBitCount {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SerialStopBitCount").field(&self.0).finish()
@@ -482,3 +487,5 @@ impl core::fmt::Debug for SerialStopBitCount {
 impl windows_core::RuntimeType for SerialStopBitCount {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.SerialCommunication.SerialStopBitCount;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,203 @@
+impl windows_core::imp::ProxyVtable for IErrorReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut SerialError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorReceivedEventArgs", "Error", 6usize, |this| ((**(this as *const *const IErrorReceivedEventArgs_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Error }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IErrorReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPinChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PinChange(this: *mut core::ffi::c_void, result__: *mut SerialPinChange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinChangedEventArgs", "PinChange", 6usize, |this| ((**(this as *const *const IPinChangedEventArgs_Vtbl)).PinChange)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PinChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPinChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISerialDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BaudRate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "BaudRate", 6usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).BaudRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBaudRate(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "SetBaudRate", 7usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).SetBaudRate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BreakSignalState(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "BreakSignalState", 8usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).BreakSignalState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBreakSignalState(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "SetBreakSignalState", 9usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).SetBreakSignalState)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BytesReceived(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "BytesReceived", 10usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).BytesReceived)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CarrierDetectState(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "CarrierDetectState", 11usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).CarrierDetectState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearToSendState(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "ClearToSendState", 12usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).ClearToSendState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataBits(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "DataBits", 13usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).DataBits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataBits(this: *mut core::ffi::c_void, value: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "SetDataBits", 14usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).SetDataBits)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataSetReadyState(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "DataSetReadyState", 15usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).DataSetReadyState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handshake(this: *mut core::ffi::c_void, result__: *mut SerialHandshake) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "Handshake", 16usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).Handshake)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandshake(this: *mut core::ffi::c_void, value: SerialHandshake) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "SetHandshake", 17usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).SetHandshake)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDataTerminalReadyEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "IsDataTerminalReadyEnabled", 18usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).IsDataTerminalReadyEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsDataTerminalReadyEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "SetIsDataTerminalReadyEnabled", 19usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).SetIsDataTerminalReadyEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRequestToSendEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "IsRequestToSendEnabled", 20usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).IsRequestToSendEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsRequestToSendEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "SetIsRequestToSendEnabled", 21usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).SetIsRequestToSendEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parity(this: *mut core::ffi::c_void, result__: *mut SerialParity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "Parity", 22usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).Parity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParity(this: *mut core::ffi::c_void, value: SerialParity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "SetParity", 23usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).SetParity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PortName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "PortName", 24usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).PortName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "ReadTimeout", 25usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).ReadTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReadTimeout(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "SetReadTimeout", 26usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).SetReadTimeout)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopBits(this: *mut core::ffi::c_void, result__: *mut SerialStopBitCount) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "StopBits", 27usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).StopBits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStopBits(this: *mut core::ffi::c_void, value: SerialStopBitCount) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "SetStopBits", 28usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).SetStopBits)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsbVendorId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "UsbVendorId", 29usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).UsbVendorId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsbProductId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "UsbProductId", 30usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).UsbProductId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "WriteTimeout", 31usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).WriteTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWriteTimeout(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "SetWriteTimeout", 32usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).SetWriteTimeout)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn InputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "InputStream", 33usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).InputStream)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn OutputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "OutputStream", 34usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).OutputStream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorReceived(this: *mut core::ffi::c_void, reporthandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "ErrorReceived", 35usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).ErrorReceived)(this, reporthandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveErrorReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "RemoveErrorReceived", 36usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).RemoveErrorReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PinChanged(this: *mut core::ffi::c_void, reporthandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "PinChanged", 37usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).PinChanged)(this, reporthandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePinChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDevice", "RemovePinChanged", 38usize, |this| ((**(this as *const *const ISerialDevice_Vtbl)).RemovePinChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BaudRate,
+            SetBaudRate,
+            BreakSignalState,
+            SetBreakSignalState,
+            BytesReceived,
+            CarrierDetectState,
+            ClearToSendState,
+            DataBits,
+            SetDataBits,
+            DataSetReadyState,
+            Handshake,
+            SetHandshake,
+            IsDataTerminalReadyEnabled,
+            SetIsDataTerminalReadyEnabled,
+            IsRequestToSendEnabled,
+            SetIsRequestToSendEnabled,
+            Parity,
+            SetParity,
+            PortName,
+            ReadTimeout,
+            SetReadTimeout,
+            StopBits,
+            SetStopBits,
+            UsbVendorId,
+            UsbProductId,
+            WriteTimeout,
+            SetWriteTimeout,
+            #[cfg(feature = "Storage_Streams")]
+            InputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            InputStream: 0,
+            #[cfg(feature = "Storage_Streams")]
+            OutputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            OutputStream: 0,
+            ErrorReceived,
+            RemoveErrorReceived,
+            PinChanged,
+            RemovePinChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISerialDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISerialDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDeviceStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const ISerialDeviceStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromPortName(this: *mut core::ffi::c_void, portname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDeviceStatics", "GetDeviceSelectorFromPortName", 7usize, |this| ((**(this as *const *const ISerialDeviceStatics_Vtbl)).GetDeviceSelectorFromPortName)(this, portname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromUsbVidPid(this: *mut core::ffi::c_void, vendorid: u16, productid: u16, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDeviceStatics", "GetDeviceSelectorFromUsbVidPid", 8usize, |this| ((**(this as *const *const ISerialDeviceStatics_Vtbl)).GetDeviceSelectorFromUsbVidPid)(this, vendorid, productid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISerialDeviceStatics", "FromIdAsync", 9usize, |this| ((**(this as *const *const ISerialDeviceStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceSelector,
+            GetDeviceSelectorFromPortName,
+            GetDeviceSelectorFromUsbVidPid,
+            FromIdAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISerialDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
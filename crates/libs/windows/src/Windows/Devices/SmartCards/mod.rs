@@ -2910,6 +2910,7 @@ impl SmartCardActivationPolicyChangeResult {
 impl windows_core::TypeKind for SmartCardActivationPolicyChangeResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardActivationPolicyChangeResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardActivationPolicyChangeResult").field(&self.0).finish()
@@ -2929,6 +2930,7 @@ impl SmartCardAppletIdGroupActivationPolicy {
 impl windows_core::TypeKind for SmartCardAppletIdGroupActivationPolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardAppletIdGroupActivationPolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardAppletIdGroupActivationPolicy").field(&self.0).finish()
@@ -2948,6 +2950,7 @@ impl SmartCardAutomaticResponseStatus {
 impl windows_core::TypeKind for SmartCardAutomaticResponseStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardAutomaticResponseStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardAutomaticResponseStatus").field(&self.0).finish()
@@ -2973,6 +2976,7 @@ impl SmartCardCryptogramAlgorithm {
 impl windows_core::TypeKind for SmartCardCryptogramAlgorithm {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardCryptogramAlgorithm {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardCryptogramAlgorithm").field(&self.0).finish()
@@ -3003,6 +3007,7 @@ impl SmartCardCryptogramGeneratorOperationStatus {
 impl windows_core::TypeKind for SmartCardCryptogramGeneratorOperationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardCryptogramGeneratorOperationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardCryptogramGeneratorOperationStatus").field(&self.0).finish()
@@ -3021,6 +3026,7 @@ impl SmartCardCryptogramMaterialPackageConfirmationResponseFormat {
 impl windows_core::TypeKind for SmartCardCryptogramMaterialPackageConfirmationResponseFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardCryptogramMaterialPackageConfirmationResponseFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardCryptogramMaterialPackageConfirmationResponseFormat").field(&self.0).finish()
@@ -3039,6 +3045,7 @@ impl SmartCardCryptogramMaterialPackageFormat {
 impl windows_core::TypeKind for SmartCardCryptogramMaterialPackageFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardCryptogramMaterialPackageFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardCryptogramMaterialPackageFormat").field(&self.0).finish()
@@ -3057,6 +3064,7 @@ impl SmartCardCryptogramMaterialProtectionMethod {
 impl windows_core::TypeKind for SmartCardCryptogramMaterialProtectionMethod {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardCryptogramMaterialProtectionMethod {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardCryptogramMaterialProtectionMethod").field(&self.0).finish()
@@ -3078,6 +3086,7 @@ impl SmartCardCryptogramMaterialType {
 impl windows_core::TypeKind for SmartCardCryptogramMaterialType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardCryptogramMaterialType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardCryptogramMaterialType").field(&self.0).finish()
@@ -3097,6 +3106,7 @@ impl SmartCardCryptogramPlacementOptions {
 impl windows_core::TypeKind for SmartCardCryptogramPlacementOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardCryptogramPlacementOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardCryptogramPlacementOptions").field(&self.0).finish()
@@ -3148,6 +3158,7 @@ impl SmartCardCryptogramStorageKeyAlgorithm {
 impl windows_core::TypeKind for SmartCardCryptogramStorageKeyAlgorithm {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardCryptogramStorageKeyAlgorithm {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardCryptogramStorageKeyAlgorithm").field(&self.0).finish()
@@ -3167,6 +3178,7 @@ impl SmartCardCryptogramStorageKeyCapabilities {
 impl windows_core::TypeKind for SmartCardCryptogramStorageKeyCapabilities {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardCryptogramStorageKeyCapabilities {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardCryptogramStorageKeyCapabilities").field(&self.0).finish()
@@ -3224,6 +3236,7 @@ impl SmartCardCryptographicKeyAttestationStatus {
 impl windows_core::TypeKind for SmartCardCryptographicKeyAttestationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardCryptographicKeyAttestationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardCryptographicKeyAttestationStatus").field(&self.0).finish()
@@ -3242,6 +3255,7 @@ impl SmartCardEmulationCategory {
 impl windows_core::TypeKind for SmartCardEmulationCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardEmulationCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardEmulationCategory").field(&self.0).finish()
@@ -3261,6 +3275,7 @@ impl SmartCardEmulationType {
 impl windows_core::TypeKind for SmartCardEmulationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardEmulationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardEmulationType").field(&self.0).finish()
@@ -3279,6 +3294,7 @@ impl SmartCardEmulatorConnectionDeactivatedReason {
 impl windows_core::TypeKind for SmartCardEmulatorConnectionDeactivatedReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardEmulatorConnectionDeactivatedReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardEmulatorConnectionDeactivatedReason").field(&self.0).finish()
@@ -3297,6 +3313,7 @@ impl SmartCardEmulatorConnectionSource {
 impl windows_core::TypeKind for SmartCardEmulatorConnectionSource {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardEmulatorConnectionSource {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardEmulatorConnectionSource").field(&self.0).finish()
@@ -3317,6 +3334,7 @@ impl SmartCardEmulatorEnablementPolicy {
 impl windows_core::TypeKind for SmartCardEmulatorEnablementPolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardEmulatorEnablementPolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardEmulatorEnablementPolicy").field(&self.0).finish()
@@ -3335,6 +3353,7 @@ impl SmartCardLaunchBehavior {
 impl windows_core::TypeKind for SmartCardLaunchBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardLaunchBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardLaunchBehavior").field(&self.0).finish()
@@ -3354,6 +3373,7 @@ impl SmartCardPinCharacterPolicyOption {
 impl windows_core::TypeKind for SmartCardPinCharacterPolicyOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardPinCharacterPolicyOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardPinCharacterPolicyOption").field(&self.0).finish()
@@ -3376,6 +3396,7 @@ impl SmartCardReaderKind {
 impl windows_core::TypeKind for SmartCardReaderKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardReaderKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardReaderKind").field(&self.0).finish()
@@ -3395,6 +3416,7 @@ impl SmartCardReaderStatus {
 impl windows_core::TypeKind for SmartCardReaderStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardReaderStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardReaderStatus").field(&self.0).finish()
@@ -3416,6 +3438,7 @@ impl SmartCardStatus {
 impl windows_core::TypeKind for SmartCardStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardStatus").field(&self.0).finish()
@@ -3438,6 +3461,7 @@ impl SmartCardTriggerType {
 impl windows_core::TypeKind for SmartCardTriggerType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardTriggerType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardTriggerType").field(&self.0).finish()
@@ -3457,6 +3481,7 @@ impl SmartCardUnlockPromptingBehavior {
 impl windows_core::TypeKind for SmartCardUnlockPromptingBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardUnlockPromptingBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardUnlockPromptingBehavior").field(&self.0).finish()
@@ -3526,3 +3551,5 @@ pub struct SmartCardPinResetHandler_Vtbl {
     pub base__: windows_core::IUnknown_Vtbl,
     pub Invoke: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1586 @@
+impl windows_core::imp::ProxyVtable for ICardAddedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SmartCard(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICardAddedEventArgs", "SmartCard", 6usize, |this| ((**(this as *const *const ICardAddedEventArgs_Vtbl)).SmartCard)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SmartCard }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICardAddedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICardRemovedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SmartCard(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICardRemovedEventArgs", "SmartCard", 6usize, |this| ((**(this as *const *const ICardRemovedEventArgs_Vtbl)).SmartCard)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SmartCard }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICardRemovedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IKnownSmartCardAppletIds_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn PaymentSystemEnvironment(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSmartCardAppletIds", "PaymentSystemEnvironment", 6usize, |this| ((**(this as *const *const IKnownSmartCardAppletIds_Vtbl)).PaymentSystemEnvironment)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ProximityPaymentSystemEnvironment(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSmartCardAppletIds", "ProximityPaymentSystemEnvironment", 7usize, |this| ((**(this as *const *const IKnownSmartCardAppletIds_Vtbl)).ProximityPaymentSystemEnvironment)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            PaymentSystemEnvironment,
+            #[cfg(not(feature = "Storage_Streams"))]
+            PaymentSystemEnvironment: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ProximityPaymentSystemEnvironment,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ProximityPaymentSystemEnvironment: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownSmartCardAppletIds as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISmartCard_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCard", "Reader", 6usize, |this| ((**(this as *const *const ISmartCard_Vtbl)).Reader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatusAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCard", "GetStatusAsync", 7usize, |this| ((**(this as *const *const ISmartCard_Vtbl)).GetStatusAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetAnswerToResetAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCard", "GetAnswerToResetAsync", 8usize, |this| ((**(this as *const *const ISmartCard_Vtbl)).GetAnswerToResetAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Reader,
+            GetStatusAsync,
+            #[cfg(feature = "Storage_Streams")]
+            GetAnswerToResetAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetAnswerToResetAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCard as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ISmartCardAppletIdGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup", "DisplayName", 6usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup", "SetDisplayName", 7usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn AppletIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup", "AppletIds", 8usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup_Vtbl)).AppletIds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SmartCardEmulationCategory(this: *mut core::ffi::c_void, result__: *mut SmartCardEmulationCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup", "SmartCardEmulationCategory", 9usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup_Vtbl)).SmartCardEmulationCategory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSmartCardEmulationCategory(this: *mut core::ffi::c_void, value: SmartCardEmulationCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup", "SetSmartCardEmulationCategory", 10usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup_Vtbl)).SetSmartCardEmulationCategory)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SmartCardEmulationType(this: *mut core::ffi::c_void, result__: *mut SmartCardEmulationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup", "SmartCardEmulationType", 11usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup_Vtbl)).SmartCardEmulationType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSmartCardEmulationType(this: *mut core::ffi::c_void, value: SmartCardEmulationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup", "SetSmartCardEmulationType", 12usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup_Vtbl)).SetSmartCardEmulationType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutomaticEnablement(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup", "AutomaticEnablement", 13usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup_Vtbl)).AutomaticEnablement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutomaticEnablement(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup", "SetAutomaticEnablement", 14usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup_Vtbl)).SetAutomaticEnablement)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayName,
+            SetDisplayName,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            AppletIds,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            AppletIds: 0,
+            SmartCardEmulationCategory,
+            SetSmartCardEmulationCategory,
+            SmartCardEmulationType,
+            SetSmartCardEmulationType,
+            AutomaticEnablement,
+            SetAutomaticEnablement,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardAppletIdGroup as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ISmartCardAppletIdGroup2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Logo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup2", "Logo", 6usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup2_Vtbl)).Logo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetLogo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup2", "SetLogo", 7usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup2_Vtbl)).SetLogo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup2", "Description", 8usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup2_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup2", "SetDescription", 9usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup2_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup2", "Properties", 10usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup2_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecureUserAuthenticationRequired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup2", "SecureUserAuthenticationRequired", 11usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup2_Vtbl)).SecureUserAuthenticationRequired)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecureUserAuthenticationRequired(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroup2", "SetSecureUserAuthenticationRequired", 12usize, |this| ((**(this as *const *const ISmartCardAppletIdGroup2_Vtbl)).SetSecureUserAuthenticationRequired)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Logo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Logo: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetLogo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetLogo: 0,
+            Description,
+            SetDescription,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            SecureUserAuthenticationRequired,
+            SetSecureUserAuthenticationRequired,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardAppletIdGroup2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ISmartCardAppletIdGroupFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, appletids: *mut core::ffi::c_void, emulationcategory: SmartCardEmulationCategory, emulationtype: SmartCardEmulationType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroupFactory", "Create", 6usize, |this| ((**(this as *const *const ISmartCardAppletIdGroupFactory_Vtbl)).Create)(this, displayname, appletids, emulationcategory, emulationtype, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            Create,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardAppletIdGroupFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISmartCardAppletIdGroupRegistration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivationPolicy(this: *mut core::ffi::c_void, result__: *mut SmartCardAppletIdGroupActivationPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroupRegistration", "ActivationPolicy", 6usize, |this| ((**(this as *const *const ISmartCardAppletIdGroupRegistration_Vtbl)).ActivationPolicy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppletIdGroup(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroupRegistration", "AppletIdGroup", 7usize, |this| ((**(this as *const *const ISmartCardAppletIdGroupRegistration_Vtbl)).AppletIdGroup)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestActivationPolicyChangeAsync(this: *mut core::ffi::c_void, policy: SmartCardAppletIdGroupActivationPolicy, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroupRegistration", "RequestActivationPolicyChangeAsync", 8usize, |this| ((**(this as *const *const ISmartCardAppletIdGroupRegistration_Vtbl)).RequestActivationPolicyChangeAsync)(this, policy, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroupRegistration", "Id", 9usize, |this| ((**(this as *const *const ISmartCardAppletIdGroupRegistration_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetAutomaticResponseApdusAsync(this: *mut core::ffi::c_void, apdus: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroupRegistration", "SetAutomaticResponseApdusAsync", 10usize, |this| ((**(this as *const *const ISmartCardAppletIdGroupRegistration_Vtbl)).SetAutomaticResponseApdusAsync)(this, apdus, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ActivationPolicy,
+            AppletIdGroup,
+            RequestActivationPolicyChangeAsync,
+            Id,
+            #[cfg(feature = "Foundation_Collections")]
+            SetAutomaticResponseApdusAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetAutomaticResponseApdusAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardAppletIdGroupRegistration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISmartCardAppletIdGroupRegistration2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SmartCardReaderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroupRegistration2", "SmartCardReaderId", 6usize, |this| ((**(this as *const *const ISmartCardAppletIdGroupRegistration2_Vtbl)).SmartCardReaderId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetPropertiesAsync(this: *mut core::ffi::c_void, props: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroupRegistration2", "SetPropertiesAsync", 7usize, |this| ((**(this as *const *const ISmartCardAppletIdGroupRegistration2_Vtbl)).SetPropertiesAsync)(this, props, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SmartCardReaderId,
+            #[cfg(feature = "Foundation_Collections")]
+            SetPropertiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetPropertiesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardAppletIdGroupRegistration2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardAppletIdGroupStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxAppletIds(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAppletIdGroupStatics", "MaxAppletIds", 6usize, |this| ((**(this as *const *const ISmartCardAppletIdGroupStatics_Vtbl)).MaxAppletIds)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MaxAppletIds }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardAppletIdGroupStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISmartCardAutomaticResponseApdu_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CommandApdu(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu", "CommandApdu", 6usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu_Vtbl)).CommandApdu)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetCommandApdu(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu", "SetCommandApdu", 7usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu_Vtbl)).SetCommandApdu)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CommandApduBitMask(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu", "CommandApduBitMask", 8usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu_Vtbl)).CommandApduBitMask)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetCommandApduBitMask(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu", "SetCommandApduBitMask", 9usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu_Vtbl)).SetCommandApduBitMask)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShouldMatchLength(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu", "ShouldMatchLength", 10usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu_Vtbl)).ShouldMatchLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShouldMatchLength(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu", "SetShouldMatchLength", 11usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu_Vtbl)).SetShouldMatchLength)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AppletId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu", "AppletId", 12usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu_Vtbl)).AppletId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetAppletId(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu", "SetAppletId", 13usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu_Vtbl)).SetAppletId)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ResponseApdu(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu", "ResponseApdu", 14usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu_Vtbl)).ResponseApdu)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetResponseApdu(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu", "SetResponseApdu", 15usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu_Vtbl)).SetResponseApdu)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CommandApdu,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CommandApdu: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetCommandApdu,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetCommandApdu: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CommandApduBitMask,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CommandApduBitMask: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetCommandApduBitMask,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetCommandApduBitMask: 0,
+            ShouldMatchLength,
+            SetShouldMatchLength,
+            #[cfg(feature = "Storage_Streams")]
+            AppletId,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AppletId: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetAppletId,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetAppletId: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ResponseApdu,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ResponseApdu: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetResponseApdu,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetResponseApdu: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardAutomaticResponseApdu as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardAutomaticResponseApdu2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InputState(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu2", "InputState", 6usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu2_Vtbl)).InputState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputState(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu2", "SetInputState", 7usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu2_Vtbl)).SetInputState)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutputState(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu2", "OutputState", 8usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu2_Vtbl)).OutputState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputState(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu2", "SetOutputState", 9usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu2_Vtbl)).SetOutputState)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InputState, SetInputState, OutputState, SetOutputState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardAutomaticResponseApdu2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardAutomaticResponseApdu3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowWhenCryptogramGeneratorNotPrepared(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu3", "AllowWhenCryptogramGeneratorNotPrepared", 6usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu3_Vtbl)).AllowWhenCryptogramGeneratorNotPrepared)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowWhenCryptogramGeneratorNotPrepared(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApdu3", "SetAllowWhenCryptogramGeneratorNotPrepared", 7usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApdu3_Vtbl)).SetAllowWhenCryptogramGeneratorNotPrepared)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AllowWhenCryptogramGeneratorNotPrepared,
+            SetAllowWhenCryptogramGeneratorNotPrepared,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardAutomaticResponseApdu3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISmartCardAutomaticResponseApduFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, commandapdu: *mut core::ffi::c_void, responseapdu: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardAutomaticResponseApduFactory", "Create", 6usize, |this| ((**(this as *const *const ISmartCardAutomaticResponseApduFactory_Vtbl)).Create)(this, commandapdu, responseapdu, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Create,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardAutomaticResponseApduFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISmartCardChallengeContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Challenge(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardChallengeContext", "Challenge", 6usize, |this| ((**(this as *const *const ISmartCardChallengeContext_Vtbl)).Challenge)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn VerifyResponseAsync(this: *mut core::ffi::c_void, response: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardChallengeContext", "VerifyResponseAsync", 7usize, |this| ((**(this as *const *const ISmartCardChallengeContext_Vtbl)).VerifyResponseAsync)(this, response, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ProvisionAsync(this: *mut core::ffi::c_void, response: *mut core::ffi::c_void, formatcard: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardChallengeContext", "ProvisionAsync", 8usize, |this| ((**(this as *const *const ISmartCardChallengeContext_Vtbl)).ProvisionAsync)(this, response, formatcard, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ProvisionAsyncWithNewCardId(this: *mut core::ffi::c_void, response: *mut core::ffi::c_void, formatcard: bool, newcardid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardChallengeContext", "ProvisionAsyncWithNewCardId", 9usize, |this| ((**(this as *const *const ISmartCardChallengeContext_Vtbl)).ProvisionAsyncWithNewCardId)(this, response, formatcard, newcardid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ChangeAdministrativeKeyAsync(this: *mut core::ffi::c_void, response: *mut core::ffi::c_void, newadministrativekey: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardChallengeContext", "ChangeAdministrativeKeyAsync", 10usize, |this| ((**(this as *const *const ISmartCardChallengeContext_Vtbl)).ChangeAdministrativeKeyAsync)(this, response, newadministrativekey, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Challenge,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Challenge: 0,
+            #[cfg(feature = "Storage_Streams")]
+            VerifyResponseAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            VerifyResponseAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ProvisionAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ProvisionAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ProvisionAsyncWithNewCardId,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ProvisionAsyncWithNewCardId: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ChangeAdministrativeKeyAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ChangeAdministrativeKeyAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardChallengeContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardConnect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardConnect", "ConnectAsync", 6usize, |this| ((**(this as *const *const ISmartCardConnect_Vtbl)).ConnectAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardConnect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISmartCardConnection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn TransmitAsync(this: *mut core::ffi::c_void, command: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardConnection", "TransmitAsync", 6usize, |this| ((**(this as *const *const ISmartCardConnection_Vtbl)).TransmitAsync)(this, command, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            TransmitAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            TransmitAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Core", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ISmartCardCryptogramGenerator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedCryptogramMaterialTypes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator", "SupportedCryptogramMaterialTypes", 6usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator_Vtbl)).SupportedCryptogramMaterialTypes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedCryptogramAlgorithms(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator", "SupportedCryptogramAlgorithms", 7usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator_Vtbl)).SupportedCryptogramAlgorithms)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedCryptogramMaterialPackageFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator", "SupportedCryptogramMaterialPackageFormats", 8usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator_Vtbl)).SupportedCryptogramMaterialPackageFormats)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedCryptogramMaterialPackageConfirmationResponseFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator", "SupportedCryptogramMaterialPackageConfirmationResponseFormats", 9usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator_Vtbl)).SupportedCryptogramMaterialPackageConfirmationResponseFormats)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedSmartCardCryptogramStorageKeyCapabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator", "SupportedSmartCardCryptogramStorageKeyCapabilities", 10usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator_Vtbl)).SupportedSmartCardCryptogramStorageKeyCapabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteCryptogramMaterialStorageKeyAsync(this: *mut core::ffi::c_void, storagekeyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator", "DeleteCryptogramMaterialStorageKeyAsync", 11usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator_Vtbl)).DeleteCryptogramMaterialStorageKeyAsync)(this, storagekeyname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCryptogramMaterialStorageKeyAsync(this: *mut core::ffi::c_void, promptingbehavior: SmartCardUnlockPromptingBehavior, storagekeyname: core::mem::MaybeUninit<windows_core::HSTRING>, algorithm: SmartCardCryptogramStorageKeyAlgorithm, capabilities: SmartCardCryptogramStorageKeyCapabilities, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator", "CreateCryptogramMaterialStorageKeyAsync", 12usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator_Vtbl)).CreateCryptogramMaterialStorageKeyAsync)(this, promptingbehavior, storagekeyname, algorithm, capabilities, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Core")]
+        unsafe extern "system" fn RequestCryptogramMaterialStorageKeyInfoAsync(this: *mut core::ffi::c_void, promptingbehavior: SmartCardUnlockPromptingBehavior, storagekeyname: core::mem::MaybeUninit<windows_core::HSTRING>, format: super::super::Security::Cryptography::Core::CryptographicPublicKeyBlobType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator", "RequestCryptogramMaterialStorageKeyInfoAsync", 13usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator_Vtbl)).RequestCryptogramMaterialStorageKeyInfoAsync)(this, promptingbehavior, storagekeyname, format, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ImportCryptogramMaterialPackageAsync(this: *mut core::ffi::c_void, format: SmartCardCryptogramMaterialPackageFormat, storagekeyname: core::mem::MaybeUninit<windows_core::HSTRING>, materialpackagename: core::mem::MaybeUninit<windows_core::HSTRING>, cryptogrammaterialpackage: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator", "ImportCryptogramMaterialPackageAsync", 14usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator_Vtbl)).ImportCryptogramMaterialPackageAsync)(this, format, storagekeyname, materialpackagename, cryptogrammaterialpackage, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn TryProvePossessionOfCryptogramMaterialPackageAsync(this: *mut core::ffi::c_void, promptingbehavior: SmartCardUnlockPromptingBehavior, responseformat: SmartCardCryptogramMaterialPackageConfirmationResponseFormat, materialpackagename: core::mem::MaybeUninit<windows_core::HSTRING>, materialname: core::mem::MaybeUninit<windows_core::HSTRING>, challenge: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator", "TryProvePossessionOfCryptogramMaterialPackageAsync", 15usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator_Vtbl)).TryProvePossessionOfCryptogramMaterialPackageAsync)(this, promptingbehavior, responseformat, materialpackagename, materialname, challenge, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestUnlockCryptogramMaterialForUseAsync(this: *mut core::ffi::c_void, promptingbehavior: SmartCardUnlockPromptingBehavior, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator", "RequestUnlockCryptogramMaterialForUseAsync", 16usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator_Vtbl)).RequestUnlockCryptogramMaterialForUseAsync)(this, promptingbehavior, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteCryptogramMaterialPackageAsync(this: *mut core::ffi::c_void, materialpackagename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator", "DeleteCryptogramMaterialPackageAsync", 17usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator_Vtbl)).DeleteCryptogramMaterialPackageAsync)(this, materialpackagename, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedCryptogramMaterialTypes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedCryptogramMaterialTypes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedCryptogramAlgorithms,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedCryptogramAlgorithms: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedCryptogramMaterialPackageFormats,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedCryptogramMaterialPackageFormats: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedCryptogramMaterialPackageConfirmationResponseFormats,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedCryptogramMaterialPackageConfirmationResponseFormats: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedSmartCardCryptogramStorageKeyCapabilities,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedSmartCardCryptogramStorageKeyCapabilities: 0,
+            DeleteCryptogramMaterialStorageKeyAsync,
+            CreateCryptogramMaterialStorageKeyAsync,
+            #[cfg(feature = "Security_Cryptography_Core")]
+            RequestCryptogramMaterialStorageKeyInfoAsync,
+            #[cfg(not(feature = "Security_Cryptography_Core"))]
+            RequestCryptogramMaterialStorageKeyInfoAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ImportCryptogramMaterialPackageAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ImportCryptogramMaterialPackageAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            TryProvePossessionOfCryptogramMaterialPackageAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            TryProvePossessionOfCryptogramMaterialPackageAsync: 0,
+            RequestUnlockCryptogramMaterialForUseAsync,
+            DeleteCryptogramMaterialPackageAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardCryptogramGenerator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ISmartCardCryptogramGenerator2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn ValidateRequestApduAsync(this: *mut core::ffi::c_void, promptingbehavior: SmartCardUnlockPromptingBehavior, apdutovalidate: *mut core::ffi::c_void, cryptogramplacementsteps: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator2", "ValidateRequestApduAsync", 6usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator2_Vtbl)).ValidateRequestApduAsync)(this, promptingbehavior, apdutovalidate, cryptogramplacementsteps, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllCryptogramStorageKeyCharacteristicsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator2", "GetAllCryptogramStorageKeyCharacteristicsAsync", 7usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator2_Vtbl)).GetAllCryptogramStorageKeyCharacteristicsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllCryptogramMaterialPackageCharacteristicsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator2", "GetAllCryptogramMaterialPackageCharacteristicsAsync", 8usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator2_Vtbl)).GetAllCryptogramMaterialPackageCharacteristicsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllCryptogramMaterialPackageCharacteristicsWithStorageKeyAsync(this: *mut core::ffi::c_void, storagekeyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator2", "GetAllCryptogramMaterialPackageCharacteristicsWithStorageKeyAsync", 9usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator2_Vtbl)).GetAllCryptogramMaterialPackageCharacteristicsWithStorageKeyAsync)(this, storagekeyname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllCryptogramMaterialCharacteristicsAsync(this: *mut core::ffi::c_void, promptingbehavior: SmartCardUnlockPromptingBehavior, materialpackagename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGenerator2", "GetAllCryptogramMaterialCharacteristicsAsync", 10usize, |this| ((**(this as *const *const ISmartCardCryptogramGenerator2_Vtbl)).GetAllCryptogramMaterialCharacteristicsAsync)(this, promptingbehavior, materialpackagename, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            ValidateRequestApduAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            ValidateRequestApduAsync: 0,
+            GetAllCryptogramStorageKeyCharacteristicsAsync,
+            GetAllCryptogramMaterialPackageCharacteristicsAsync,
+            GetAllCryptogramMaterialPackageCharacteristicsWithStorageKeyAsync,
+            GetAllCryptogramMaterialCharacteristicsAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardCryptogramGenerator2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardCryptogramGeneratorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSmartCardCryptogramGeneratorAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGeneratorStatics", "GetSmartCardCryptogramGeneratorAsync", 6usize, |this| ((**(this as *const *const ISmartCardCryptogramGeneratorStatics_Vtbl)).GetSmartCardCryptogramGeneratorAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSmartCardCryptogramGeneratorAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardCryptogramGeneratorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardCryptogramGeneratorStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGeneratorStatics2", "IsSupported", 6usize, |this| ((**(this as *const *const ISmartCardCryptogramGeneratorStatics2_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardCryptogramGeneratorStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISmartCardCryptogramGetAllCryptogramMaterialCharacteristicsResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OperationStatus(this: *mut core::ffi::c_void, result__: *mut SmartCardCryptogramGeneratorOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGetAllCryptogramMaterialCharacteristicsResult", "OperationStatus", 6usize, |this| ((**(this as *const *const ISmartCardCryptogramGetAllCryptogramMaterialCharacteristicsResult_Vtbl)).OperationStatus)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Characteristics(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGetAllCryptogramMaterialCharacteristicsResult", "Characteristics", 7usize, |this| ((**(this as *const *const ISmartCardCryptogramGetAllCryptogramMaterialCharacteristicsResult_Vtbl)).Characteristics)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OperationStatus,
+            #[cfg(feature = "Foundation_Collections")]
+            Characteristics,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Characteristics: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardCryptogramGetAllCryptogramMaterialCharacteristicsResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISmartCardCryptogramGetAllCryptogramMaterialPackageCharacteristicsResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OperationStatus(this: *mut core::ffi::c_void, result__: *mut SmartCardCryptogramGeneratorOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGetAllCryptogramMaterialPackageCharacteristicsResult", "OperationStatus", 6usize, |this| ((**(this as *const *const ISmartCardCryptogramGetAllCryptogramMaterialPackageCharacteristicsResult_Vtbl)).OperationStatus)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Characteristics(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGetAllCryptogramMaterialPackageCharacteristicsResult", "Characteristics", 7usize, |this| ((**(this as *const *const ISmartCardCryptogramGetAllCryptogramMaterialPackageCharacteristicsResult_Vtbl)).Characteristics)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OperationStatus,
+            #[cfg(feature = "Foundation_Collections")]
+            Characteristics,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Characteristics: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardCryptogramGetAllCryptogramMaterialPackageCharacteristicsResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISmartCardCryptogramGetAllCryptogramStorageKeyCharacteristicsResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OperationStatus(this: *mut core::ffi::c_void, result__: *mut SmartCardCryptogramGeneratorOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGetAllCryptogramStorageKeyCharacteristicsResult", "OperationStatus", 6usize, |this| ((**(this as *const *const ISmartCardCryptogramGetAllCryptogramStorageKeyCharacteristicsResult_Vtbl)).OperationStatus)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Characteristics(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramGetAllCryptogramStorageKeyCharacteristicsResult", "Characteristics", 7usize, |this| ((**(this as *const *const ISmartCardCryptogramGetAllCryptogramStorageKeyCharacteristicsResult_Vtbl)).Characteristics)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OperationStatus,
+            #[cfg(feature = "Foundation_Collections")]
+            Characteristics,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Characteristics: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardCryptogramGetAllCryptogramStorageKeyCharacteristicsResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISmartCardCryptogramMaterialCharacteristics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaterialName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramMaterialCharacteristics", "MaterialName", 6usize, |this| ((**(this as *const *const ISmartCardCryptogramMaterialCharacteristics_Vtbl)).MaterialName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AllowedAlgorithms(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramMaterialCharacteristics", "AllowedAlgorithms", 7usize, |this| ((**(this as *const *const ISmartCardCryptogramMaterialCharacteristics_Vtbl)).AllowedAlgorithms)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AllowedProofOfPossessionAlgorithms(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramMaterialCharacteristics", "AllowedProofOfPossessionAlgorithms", 8usize, |this| ((**(this as *const *const ISmartCardCryptogramMaterialCharacteristics_Vtbl)).AllowedProofOfPossessionAlgorithms)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AllowedValidations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramMaterialCharacteristics", "AllowedValidations", 9usize, |this| ((**(this as *const *const ISmartCardCryptogramMaterialCharacteristics_Vtbl)).AllowedValidations)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaterialType(this: *mut core::ffi::c_void, result__: *mut SmartCardCryptogramMaterialType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramMaterialCharacteristics", "MaterialType", 10usize, |this| ((**(this as *const *const ISmartCardCryptogramMaterialCharacteristics_Vtbl)).MaterialType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectionMethod(this: *mut core::ffi::c_void, result__: *mut SmartCardCryptogramMaterialProtectionMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramMaterialCharacteristics", "ProtectionMethod", 11usize, |this| ((**(this as *const *const ISmartCardCryptogramMaterialCharacteristics_Vtbl)).ProtectionMethod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectionVersion(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramMaterialCharacteristics", "ProtectionVersion", 12usize, |this| ((**(this as *const *const ISmartCardCryptogramMaterialCharacteristics_Vtbl)).ProtectionVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaterialLength(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramMaterialCharacteristics", "MaterialLength", 13usize, |this| ((**(this as *const *const ISmartCardCryptogramMaterialCharacteristics_Vtbl)).MaterialLength)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaterialName,
+            #[cfg(feature = "Foundation_Collections")]
+            AllowedAlgorithms,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AllowedAlgorithms: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            AllowedProofOfPossessionAlgorithms,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AllowedProofOfPossessionAlgorithms: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            AllowedValidations,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AllowedValidations: 0,
+            MaterialType,
+            ProtectionMethod,
+            ProtectionVersion,
+            MaterialLength,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardCryptogramMaterialCharacteristics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardCryptogramMaterialPackageCharacteristics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PackageName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramMaterialPackageCharacteristics", "PackageName", 6usize, |this| ((**(this as *const *const ISmartCardCryptogramMaterialPackageCharacteristics_Vtbl)).PackageName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StorageKeyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramMaterialPackageCharacteristics", "StorageKeyName", 7usize, |this| ((**(this as *const *const ISmartCardCryptogramMaterialPackageCharacteristics_Vtbl)).StorageKeyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DateImported(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramMaterialPackageCharacteristics", "DateImported", 8usize, |this| ((**(this as *const *const ISmartCardCryptogramMaterialPackageCharacteristics_Vtbl)).DateImported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageFormat(this: *mut core::ffi::c_void, result__: *mut SmartCardCryptogramMaterialPackageFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramMaterialPackageCharacteristics", "PackageFormat", 9usize, |this| ((**(this as *const *const ISmartCardCryptogramMaterialPackageCharacteristics_Vtbl)).PackageFormat)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PackageName, StorageKeyName, DateImported, PackageFormat }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardCryptogramMaterialPackageCharacteristics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISmartCardCryptogramMaterialPossessionProof_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OperationStatus(this: *mut core::ffi::c_void, result__: *mut SmartCardCryptogramGeneratorOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramMaterialPossessionProof", "OperationStatus", 6usize, |this| ((**(this as *const *const ISmartCardCryptogramMaterialPossessionProof_Vtbl)).OperationStatus)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Proof(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramMaterialPossessionProof", "Proof", 7usize, |this| ((**(this as *const *const ISmartCardCryptogramMaterialPossessionProof_Vtbl)).Proof)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OperationStatus,
+            #[cfg(feature = "Storage_Streams")]
+            Proof,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Proof: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardCryptogramMaterialPossessionProof as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISmartCardCryptogramPlacementStep_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Algorithm(this: *mut core::ffi::c_void, result__: *mut SmartCardCryptogramAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "Algorithm", 6usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).Algorithm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlgorithm(this: *mut core::ffi::c_void, value: SmartCardCryptogramAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "SetAlgorithm", 7usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).SetAlgorithm)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SourceData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "SourceData", 8usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).SourceData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetSourceData(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "SetSourceData", 9usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).SetSourceData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CryptogramMaterialPackageName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "CryptogramMaterialPackageName", 10usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).CryptogramMaterialPackageName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCryptogramMaterialPackageName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "SetCryptogramMaterialPackageName", 11usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).SetCryptogramMaterialPackageName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CryptogramMaterialName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "CryptogramMaterialName", 12usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).CryptogramMaterialName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCryptogramMaterialName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "SetCryptogramMaterialName", 13usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).SetCryptogramMaterialName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TemplateOffset(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "TemplateOffset", 14usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).TemplateOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTemplateOffset(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "SetTemplateOffset", 15usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).SetTemplateOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CryptogramOffset(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "CryptogramOffset", 16usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).CryptogramOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCryptogramOffset(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "SetCryptogramOffset", 17usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).SetCryptogramOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CryptogramLength(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "CryptogramLength", 18usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).CryptogramLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCryptogramLength(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "SetCryptogramLength", 19usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).SetCryptogramLength)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CryptogramPlacementOptions(this: *mut core::ffi::c_void, result__: *mut SmartCardCryptogramPlacementOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "CryptogramPlacementOptions", 20usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).CryptogramPlacementOptions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCryptogramPlacementOptions(this: *mut core::ffi::c_void, value: SmartCardCryptogramPlacementOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "SetCryptogramPlacementOptions", 21usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).SetCryptogramPlacementOptions)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChainedOutputStep(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "ChainedOutputStep", 22usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).ChainedOutputStep)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChainedOutputStep(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramPlacementStep", "SetChainedOutputStep", 23usize, |this| ((**(this as *const *const ISmartCardCryptogramPlacementStep_Vtbl)).SetChainedOutputStep)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Algorithm,
+            SetAlgorithm,
+            #[cfg(feature = "Storage_Streams")]
+            SourceData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SourceData: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetSourceData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetSourceData: 0,
+            CryptogramMaterialPackageName,
+            SetCryptogramMaterialPackageName,
+            CryptogramMaterialName,
+            SetCryptogramMaterialName,
+            TemplateOffset,
+            SetTemplateOffset,
+            CryptogramOffset,
+            SetCryptogramOffset,
+            CryptogramLength,
+            SetCryptogramLength,
+            CryptogramPlacementOptions,
+            SetCryptogramPlacementOptions,
+            ChainedOutputStep,
+            SetChainedOutputStep,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardCryptogramPlacementStep as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardCryptogramStorageKeyCharacteristics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StorageKeyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramStorageKeyCharacteristics", "StorageKeyName", 6usize, |this| ((**(this as *const *const ISmartCardCryptogramStorageKeyCharacteristics_Vtbl)).StorageKeyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DateCreated(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramStorageKeyCharacteristics", "DateCreated", 7usize, |this| ((**(this as *const *const ISmartCardCryptogramStorageKeyCharacteristics_Vtbl)).DateCreated)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Algorithm(this: *mut core::ffi::c_void, result__: *mut SmartCardCryptogramStorageKeyAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramStorageKeyCharacteristics", "Algorithm", 8usize, |this| ((**(this as *const *const ISmartCardCryptogramStorageKeyCharacteristics_Vtbl)).Algorithm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Capabilities(this: *mut core::ffi::c_void, result__: *mut SmartCardCryptogramStorageKeyCapabilities) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramStorageKeyCharacteristics", "Capabilities", 9usize, |this| ((**(this as *const *const ISmartCardCryptogramStorageKeyCharacteristics_Vtbl)).Capabilities)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StorageKeyName, DateCreated, Algorithm, Capabilities }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardCryptogramStorageKeyCharacteristics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Security_Cryptography_Core", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ISmartCardCryptogramStorageKeyInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OperationStatus(this: *mut core::ffi::c_void, result__: *mut SmartCardCryptogramGeneratorOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramStorageKeyInfo", "OperationStatus", 6usize, |this| ((**(this as *const *const ISmartCardCryptogramStorageKeyInfo_Vtbl)).OperationStatus)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Core")]
+        unsafe extern "system" fn PublicKeyBlobType(this: *mut core::ffi::c_void, result__: *mut super::super::Security::Cryptography::Core::CryptographicPublicKeyBlobType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramStorageKeyInfo", "PublicKeyBlobType", 7usize, |this| ((**(this as *const *const ISmartCardCryptogramStorageKeyInfo_Vtbl)).PublicKeyBlobType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn PublicKey(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramStorageKeyInfo", "PublicKey", 8usize, |this| ((**(this as *const *const ISmartCardCryptogramStorageKeyInfo_Vtbl)).PublicKey)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttestationStatus(this: *mut core::ffi::c_void, result__: *mut SmartCardCryptographicKeyAttestationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramStorageKeyInfo", "AttestationStatus", 9usize, |this| ((**(this as *const *const ISmartCardCryptogramStorageKeyInfo_Vtbl)).AttestationStatus)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Attestation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramStorageKeyInfo", "Attestation", 10usize, |this| ((**(this as *const *const ISmartCardCryptogramStorageKeyInfo_Vtbl)).Attestation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AttestationCertificateChain(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramStorageKeyInfo", "AttestationCertificateChain", 11usize, |this| ((**(this as *const *const ISmartCardCryptogramStorageKeyInfo_Vtbl)).AttestationCertificateChain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Capabilities(this: *mut core::ffi::c_void, result__: *mut SmartCardCryptogramStorageKeyCapabilities) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramStorageKeyInfo", "Capabilities", 12usize, |this| ((**(this as *const *const ISmartCardCryptogramStorageKeyInfo_Vtbl)).Capabilities)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OperationStatus,
+            #[cfg(feature = "Security_Cryptography_Core")]
+            PublicKeyBlobType,
+            #[cfg(not(feature = "Security_Cryptography_Core"))]
+            PublicKeyBlobType: 0,
+            #[cfg(feature = "Storage_Streams")]
+            PublicKey,
+            #[cfg(not(feature = "Storage_Streams"))]
+            PublicKey: 0,
+            AttestationStatus,
+            #[cfg(feature = "Storage_Streams")]
+            Attestation,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Attestation: 0,
+            #[cfg(feature = "Storage_Streams")]
+            AttestationCertificateChain,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AttestationCertificateChain: 0,
+            Capabilities,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardCryptogramStorageKeyInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardCryptogramStorageKeyInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OperationalRequirements(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardCryptogramStorageKeyInfo2", "OperationalRequirements", 6usize, |this| ((**(this as *const *const ISmartCardCryptogramStorageKeyInfo2_Vtbl)).OperationalRequirements)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OperationalRequirements }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardCryptogramStorageKeyInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardEmulator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnablementPolicy(this: *mut core::ffi::c_void, result__: *mut SmartCardEmulatorEnablementPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulator", "EnablementPolicy", 6usize, |this| ((**(this as *const *const ISmartCardEmulator_Vtbl)).EnablementPolicy)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnablementPolicy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardEmulator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardEmulator2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ApduReceived(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulator2", "ApduReceived", 6usize, |this| ((**(this as *const *const ISmartCardEmulator2_Vtbl)).ApduReceived)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveApduReceived(this: *mut core::ffi::c_void, value: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulator2", "RemoveApduReceived", 7usize, |this| ((**(this as *const *const ISmartCardEmulator2_Vtbl)).RemoveApduReceived)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionDeactivated(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulator2", "ConnectionDeactivated", 8usize, |this| ((**(this as *const *const ISmartCardEmulator2_Vtbl)).ConnectionDeactivated)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConnectionDeactivated(this: *mut core::ffi::c_void, value: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulator2", "RemoveConnectionDeactivated", 9usize, |this| ((**(this as *const *const ISmartCardEmulator2_Vtbl)).RemoveConnectionDeactivated)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulator2", "Start", 10usize, |this| ((**(this as *const *const ISmartCardEmulator2_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHostCardEmulationSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulator2", "IsHostCardEmulationSupported", 11usize, |this| ((**(this as *const *const ISmartCardEmulator2_Vtbl)).IsHostCardEmulationSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ApduReceived,
+            RemoveApduReceived,
+            ConnectionDeactivated,
+            RemoveConnectionDeactivated,
+            Start,
+            IsHostCardEmulationSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardEmulator2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISmartCardEmulatorApduReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CommandApdu(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorApduReceivedEventArgs", "CommandApdu", 6usize, |this| ((**(this as *const *const ISmartCardEmulatorApduReceivedEventArgs_Vtbl)).CommandApdu)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorApduReceivedEventArgs", "ConnectionProperties", 7usize, |this| ((**(this as *const *const ISmartCardEmulatorApduReceivedEventArgs_Vtbl)).ConnectionProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn TryRespondAsync(this: *mut core::ffi::c_void, responseapdu: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorApduReceivedEventArgs", "TryRespondAsync", 8usize, |this| ((**(this as *const *const ISmartCardEmulatorApduReceivedEventArgs_Vtbl)).TryRespondAsync)(this, responseapdu, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutomaticResponseStatus(this: *mut core::ffi::c_void, result__: *mut SmartCardAutomaticResponseStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorApduReceivedEventArgs", "AutomaticResponseStatus", 9usize, |this| ((**(this as *const *const ISmartCardEmulatorApduReceivedEventArgs_Vtbl)).AutomaticResponseStatus)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CommandApdu,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CommandApdu: 0,
+            ConnectionProperties,
+            #[cfg(feature = "Storage_Streams")]
+            TryRespondAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            TryRespondAsync: 0,
+            AutomaticResponseStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardEmulatorApduReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISmartCardEmulatorApduReceivedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorApduReceivedEventArgs2", "State", 6usize, |this| ((**(this as *const *const ISmartCardEmulatorApduReceivedEventArgs2_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn TryRespondWithStateAsync(this: *mut core::ffi::c_void, responseapdu: *mut core::ffi::c_void, nextstate: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorApduReceivedEventArgs2", "TryRespondWithStateAsync", 7usize, |this| ((**(this as *const *const ISmartCardEmulatorApduReceivedEventArgs2_Vtbl)).TryRespondWithStateAsync)(this, responseapdu, nextstate, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            State,
+            #[cfg(feature = "Storage_Streams")]
+            TryRespondWithStateAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            TryRespondWithStateAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardEmulatorApduReceivedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ISmartCardEmulatorApduReceivedEventArgsWithCryptograms_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn TryRespondWithCryptogramsAsync(this: *mut core::ffi::c_void, responsetemplate: *mut core::ffi::c_void, cryptogramplacementsteps: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorApduReceivedEventArgsWithCryptograms", "TryRespondWithCryptogramsAsync", 6usize, |this| ((**(this as *const *const ISmartCardEmulatorApduReceivedEventArgsWithCryptograms_Vtbl)).TryRespondWithCryptogramsAsync)(this, responsetemplate, cryptogramplacementsteps, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn TryRespondWithCryptogramsAndStateAsync(this: *mut core::ffi::c_void, responsetemplate: *mut core::ffi::c_void, cryptogramplacementsteps: *mut core::ffi::c_void, nextstate: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorApduReceivedEventArgsWithCryptograms", "TryRespondWithCryptogramsAndStateAsync", 7usize, |this| ((**(this as *const *const ISmartCardEmulatorApduReceivedEventArgsWithCryptograms_Vtbl)).TryRespondWithCryptogramsAndStateAsync)(this, responsetemplate, cryptogramplacementsteps, nextstate, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            TryRespondWithCryptogramsAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            TryRespondWithCryptogramsAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            TryRespondWithCryptogramsAndStateAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            TryRespondWithCryptogramsAndStateAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardEmulatorApduReceivedEventArgsWithCryptograms as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardEmulatorConnectionDeactivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectionProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorConnectionDeactivatedEventArgs", "ConnectionProperties", 6usize, |this| ((**(this as *const *const ISmartCardEmulatorConnectionDeactivatedEventArgs_Vtbl)).ConnectionProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut SmartCardEmulatorConnectionDeactivatedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorConnectionDeactivatedEventArgs", "Reason", 7usize, |this| ((**(this as *const *const ISmartCardEmulatorConnectionDeactivatedEventArgs_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectionProperties, Reason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardEmulatorConnectionDeactivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardEmulatorConnectionProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorConnectionProperties", "Id", 6usize, |this| ((**(this as *const *const ISmartCardEmulatorConnectionProperties_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut SmartCardEmulatorConnectionSource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorConnectionProperties", "Source", 7usize, |this| ((**(this as *const *const ISmartCardEmulatorConnectionProperties_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Source }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardEmulatorConnectionProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardEmulatorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorStatics", "GetDefaultAsync", 6usize, |this| ((**(this as *const *const ISmartCardEmulatorStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefaultAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardEmulatorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISmartCardEmulatorStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAppletIdGroupRegistrationsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorStatics2", "GetAppletIdGroupRegistrationsAsync", 6usize, |this| ((**(this as *const *const ISmartCardEmulatorStatics2_Vtbl)).GetAppletIdGroupRegistrationsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterAppletIdGroupAsync(this: *mut core::ffi::c_void, appletidgroup: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorStatics2", "RegisterAppletIdGroupAsync", 7usize, |this| ((**(this as *const *const ISmartCardEmulatorStatics2_Vtbl)).RegisterAppletIdGroupAsync)(this, appletidgroup, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterAppletIdGroupAsync(this: *mut core::ffi::c_void, registration: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorStatics2", "UnregisterAppletIdGroupAsync", 8usize, |this| ((**(this as *const *const ISmartCardEmulatorStatics2_Vtbl)).UnregisterAppletIdGroupAsync)(this, registration, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxAppletIdGroupRegistrations(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorStatics2", "MaxAppletIdGroupRegistrations", 9usize, |this| ((**(this as *const *const ISmartCardEmulatorStatics2_Vtbl)).MaxAppletIdGroupRegistrations)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAppletIdGroupRegistrationsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAppletIdGroupRegistrationsAsync: 0,
+            RegisterAppletIdGroupAsync,
+            UnregisterAppletIdGroupAsync,
+            MaxAppletIdGroupRegistrations,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardEmulatorStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardEmulatorStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardEmulatorStatics3", "IsSupported", 6usize, |this| ((**(this as *const *const ISmartCardEmulatorStatics3_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardEmulatorStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardPinPolicy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinLength(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinPolicy", "MinLength", 6usize, |this| ((**(this as *const *const ISmartCardPinPolicy_Vtbl)).MinLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinLength(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinPolicy", "SetMinLength", 7usize, |this| ((**(this as *const *const ISmartCardPinPolicy_Vtbl)).SetMinLength)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxLength(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinPolicy", "MaxLength", 8usize, |this| ((**(this as *const *const ISmartCardPinPolicy_Vtbl)).MaxLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxLength(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinPolicy", "SetMaxLength", 9usize, |this| ((**(this as *const *const ISmartCardPinPolicy_Vtbl)).SetMaxLength)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UppercaseLetters(this: *mut core::ffi::c_void, result__: *mut SmartCardPinCharacterPolicyOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinPolicy", "UppercaseLetters", 10usize, |this| ((**(this as *const *const ISmartCardPinPolicy_Vtbl)).UppercaseLetters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUppercaseLetters(this: *mut core::ffi::c_void, value: SmartCardPinCharacterPolicyOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinPolicy", "SetUppercaseLetters", 11usize, |this| ((**(this as *const *const ISmartCardPinPolicy_Vtbl)).SetUppercaseLetters)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LowercaseLetters(this: *mut core::ffi::c_void, result__: *mut SmartCardPinCharacterPolicyOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinPolicy", "LowercaseLetters", 12usize, |this| ((**(this as *const *const ISmartCardPinPolicy_Vtbl)).LowercaseLetters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLowercaseLetters(this: *mut core::ffi::c_void, value: SmartCardPinCharacterPolicyOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinPolicy", "SetLowercaseLetters", 13usize, |this| ((**(this as *const *const ISmartCardPinPolicy_Vtbl)).SetLowercaseLetters)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Digits(this: *mut core::ffi::c_void, result__: *mut SmartCardPinCharacterPolicyOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinPolicy", "Digits", 14usize, |this| ((**(this as *const *const ISmartCardPinPolicy_Vtbl)).Digits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDigits(this: *mut core::ffi::c_void, value: SmartCardPinCharacterPolicyOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinPolicy", "SetDigits", 15usize, |this| ((**(this as *const *const ISmartCardPinPolicy_Vtbl)).SetDigits)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpecialCharacters(this: *mut core::ffi::c_void, result__: *mut SmartCardPinCharacterPolicyOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinPolicy", "SpecialCharacters", 16usize, |this| ((**(this as *const *const ISmartCardPinPolicy_Vtbl)).SpecialCharacters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpecialCharacters(this: *mut core::ffi::c_void, value: SmartCardPinCharacterPolicyOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinPolicy", "SetSpecialCharacters", 17usize, |this| ((**(this as *const *const ISmartCardPinPolicy_Vtbl)).SetSpecialCharacters)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MinLength,
+            SetMinLength,
+            MaxLength,
+            SetMaxLength,
+            UppercaseLetters,
+            SetUppercaseLetters,
+            LowercaseLetters,
+            SetLowercaseLetters,
+            Digits,
+            SetDigits,
+            SpecialCharacters,
+            SetSpecialCharacters,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardPinPolicy as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardPinResetDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinResetDeferral", "Complete", 6usize, |this| ((**(this as *const *const ISmartCardPinResetDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardPinResetDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISmartCardPinResetRequest_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Challenge(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinResetRequest", "Challenge", 6usize, |this| ((**(this as *const *const ISmartCardPinResetRequest_Vtbl)).Challenge)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinResetRequest", "Deadline", 7usize, |this| ((**(this as *const *const ISmartCardPinResetRequest_Vtbl)).Deadline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinResetRequest", "GetDeferral", 8usize, |this| ((**(this as *const *const ISmartCardPinResetRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetResponse(this: *mut core::ffi::c_void, response: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardPinResetRequest", "SetResponse", 9usize, |this| ((**(this as *const *const ISmartCardPinResetRequest_Vtbl)).SetResponse)(this, response), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Challenge,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Challenge: 0,
+            Deadline,
+            GetDeferral,
+            #[cfg(feature = "Storage_Streams")]
+            SetResponse,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetResponse: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardPinResetRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardProvisioning_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SmartCard(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardProvisioning", "SmartCard", 6usize, |this| ((**(this as *const *const ISmartCardProvisioning_Vtbl)).SmartCard)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIdAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardProvisioning", "GetIdAsync", 7usize, |this| ((**(this as *const *const ISmartCardProvisioning_Vtbl)).GetIdAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNameAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardProvisioning", "GetNameAsync", 8usize, |this| ((**(this as *const *const ISmartCardProvisioning_Vtbl)).GetNameAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChallengeContextAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardProvisioning", "GetChallengeContextAsync", 9usize, |this| ((**(this as *const *const ISmartCardProvisioning_Vtbl)).GetChallengeContextAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPinChangeAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardProvisioning", "RequestPinChangeAsync", 10usize, |this| ((**(this as *const *const ISmartCardProvisioning_Vtbl)).RequestPinChangeAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPinResetAsync(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardProvisioning", "RequestPinResetAsync", 11usize, |this| ((**(this as *const *const ISmartCardProvisioning_Vtbl)).RequestPinResetAsync)(this, handler, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SmartCard,
+            GetIdAsync,
+            GetNameAsync,
+            GetChallengeContextAsync,
+            RequestPinChangeAsync,
+            RequestPinResetAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardProvisioning as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardProvisioning2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAuthorityKeyContainerNameAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardProvisioning2", "GetAuthorityKeyContainerNameAsync", 6usize, |this| ((**(this as *const *const ISmartCardProvisioning2_Vtbl)).GetAuthorityKeyContainerNameAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAuthorityKeyContainerNameAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardProvisioning2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISmartCardProvisioningStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromSmartCardAsync(this: *mut core::ffi::c_void, card: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardProvisioningStatics", "FromSmartCardAsync", 6usize, |this| ((**(this as *const *const ISmartCardProvisioningStatics_Vtbl)).FromSmartCardAsync)(this, card, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn RequestVirtualSmartCardCreationAsync(this: *mut core::ffi::c_void, friendlyname: core::mem::MaybeUninit<windows_core::HSTRING>, administrativekey: *mut core::ffi::c_void, pinpolicy: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardProvisioningStatics", "RequestVirtualSmartCardCreationAsync", 7usize, |this| ((**(this as *const *const ISmartCardProvisioningStatics_Vtbl)).RequestVirtualSmartCardCreationAsync)(this, friendlyname, administrativekey, pinpolicy, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn RequestVirtualSmartCardCreationAsyncWithCardId(this: *mut core::ffi::c_void, friendlyname: core::mem::MaybeUninit<windows_core::HSTRING>, administrativekey: *mut core::ffi::c_void, pinpolicy: *mut core::ffi::c_void, cardid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardProvisioningStatics", "RequestVirtualSmartCardCreationAsyncWithCardId", 8usize, |this| ((**(this as *const *const ISmartCardProvisioningStatics_Vtbl)).RequestVirtualSmartCardCreationAsyncWithCardId)(this, friendlyname, administrativekey, pinpolicy, cardid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestVirtualSmartCardDeletionAsync(this: *mut core::ffi::c_void, card: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardProvisioningStatics", "RequestVirtualSmartCardDeletionAsync", 9usize, |this| ((**(this as *const *const ISmartCardProvisioningStatics_Vtbl)).RequestVirtualSmartCardDeletionAsync)(this, card, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FromSmartCardAsync,
+            #[cfg(feature = "Storage_Streams")]
+            RequestVirtualSmartCardCreationAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            RequestVirtualSmartCardCreationAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            RequestVirtualSmartCardCreationAsyncWithCardId,
+            #[cfg(not(feature = "Storage_Streams"))]
+            RequestVirtualSmartCardCreationAsyncWithCardId: 0,
+            RequestVirtualSmartCardDeletionAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardProvisioningStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISmartCardProvisioningStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn RequestAttestedVirtualSmartCardCreationAsync(this: *mut core::ffi::c_void, friendlyname: core::mem::MaybeUninit<windows_core::HSTRING>, administrativekey: *mut core::ffi::c_void, pinpolicy: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardProvisioningStatics2", "RequestAttestedVirtualSmartCardCreationAsync", 6usize, |this| ((**(this as *const *const ISmartCardProvisioningStatics2_Vtbl)).RequestAttestedVirtualSmartCardCreationAsync)(this, friendlyname, administrativekey, pinpolicy, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn RequestAttestedVirtualSmartCardCreationAsyncWithCardId(this: *mut core::ffi::c_void, friendlyname: core::mem::MaybeUninit<windows_core::HSTRING>, administrativekey: *mut core::ffi::c_void, pinpolicy: *mut core::ffi::c_void, cardid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardProvisioningStatics2", "RequestAttestedVirtualSmartCardCreationAsyncWithCardId", 7usize, |this| ((**(this as *const *const ISmartCardProvisioningStatics2_Vtbl)).RequestAttestedVirtualSmartCardCreationAsyncWithCardId)(this, friendlyname, administrativekey, pinpolicy, cardid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            RequestAttestedVirtualSmartCardCreationAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            RequestAttestedVirtualSmartCardCreationAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            RequestAttestedVirtualSmartCardCreationAsyncWithCardId,
+            #[cfg(not(feature = "Storage_Streams"))]
+            RequestAttestedVirtualSmartCardCreationAsyncWithCardId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardProvisioningStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISmartCardReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardReader", "DeviceId", 6usize, |this| ((**(this as *const *const ISmartCardReader_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardReader", "Name", 7usize, |this| ((**(this as *const *const ISmartCardReader_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut SmartCardReaderKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardReader", "Kind", 8usize, |this| ((**(this as *const *const ISmartCardReader_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatusAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardReader", "GetStatusAsync", 9usize, |this| ((**(this as *const *const ISmartCardReader_Vtbl)).GetStatusAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllCardsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardReader", "FindAllCardsAsync", 10usize, |this| ((**(this as *const *const ISmartCardReader_Vtbl)).FindAllCardsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CardAdded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardReader", "CardAdded", 11usize, |this| ((**(this as *const *const ISmartCardReader_Vtbl)).CardAdded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCardAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardReader", "RemoveCardAdded", 12usize, |this| ((**(this as *const *const ISmartCardReader_Vtbl)).RemoveCardAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CardRemoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardReader", "CardRemoved", 13usize, |this| ((**(this as *const *const ISmartCardReader_Vtbl)).CardRemoved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCardRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardReader", "RemoveCardRemoved", 14usize, |this| ((**(this as *const *const ISmartCardReader_Vtbl)).RemoveCardRemoved)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            Name,
+            Kind,
+            GetStatusAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllCardsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllCardsAsync: 0,
+            CardAdded,
+            RemoveCardAdded,
+            CardRemoved,
+            RemoveCardRemoved,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardReaderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardReaderStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const ISmartCardReaderStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorWithKind(this: *mut core::ffi::c_void, kind: SmartCardReaderKind, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardReaderStatics", "GetDeviceSelectorWithKind", 7usize, |this| ((**(this as *const *const ISmartCardReaderStatics_Vtbl)).GetDeviceSelectorWithKind)(this, kind, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardReaderStatics", "FromIdAsync", 8usize, |this| ((**(this as *const *const ISmartCardReaderStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, GetDeviceSelectorWithKind, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardReaderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISmartCardTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TriggerType(this: *mut core::ffi::c_void, result__: *mut SmartCardTriggerType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardTriggerDetails", "TriggerType", 6usize, |this| ((**(this as *const *const ISmartCardTriggerDetails_Vtbl)).TriggerType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SourceAppletId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardTriggerDetails", "SourceAppletId", 7usize, |this| ((**(this as *const *const ISmartCardTriggerDetails_Vtbl)).SourceAppletId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn TriggerData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardTriggerDetails", "TriggerData", 8usize, |this| ((**(this as *const *const ISmartCardTriggerDetails_Vtbl)).TriggerData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TriggerType,
+            #[cfg(feature = "Storage_Streams")]
+            SourceAppletId,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SourceAppletId: 0,
+            #[cfg(feature = "Storage_Streams")]
+            TriggerData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            TriggerData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardTriggerDetails2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Emulator(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardTriggerDetails2", "Emulator", 6usize, |this| ((**(this as *const *const ISmartCardTriggerDetails2_Vtbl)).Emulator)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryLaunchCurrentAppAsync(this: *mut core::ffi::c_void, arguments: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardTriggerDetails2", "TryLaunchCurrentAppAsync", 7usize, |this| ((**(this as *const *const ISmartCardTriggerDetails2_Vtbl)).TryLaunchCurrentAppAsync)(this, arguments, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryLaunchCurrentAppWithBehaviorAsync(this: *mut core::ffi::c_void, arguments: core::mem::MaybeUninit<windows_core::HSTRING>, behavior: SmartCardLaunchBehavior, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardTriggerDetails2", "TryLaunchCurrentAppWithBehaviorAsync", 8usize, |this| ((**(this as *const *const ISmartCardTriggerDetails2_Vtbl)).TryLaunchCurrentAppWithBehaviorAsync)(this, arguments, behavior, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Emulator,
+            TryLaunchCurrentAppAsync,
+            TryLaunchCurrentAppWithBehaviorAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardTriggerDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartCardTriggerDetails3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SmartCard(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartCardTriggerDetails3", "SmartCard", 6usize, |this| ((**(this as *const *const ISmartCardTriggerDetails3_Vtbl)).SmartCard)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SmartCard }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartCardTriggerDetails3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1036,6 +1036,29 @@ impl DeleteSmsMessageOperation {
     }
 }
 #[cfg(feature = "deprecated")]
+#[cfg(feature = "std")]
+impl super::super::Foundation::Async for DeleteSmsMessageOperation {
+    type Output = ();
+    fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetCompleted(&super::super::Foundation::AsyncActionCompletedHandler::new(move |_sender, _args| {
+            handler();
+            Ok(())
+        }))
+    }
+    fn get_results(&self) -> windows_core::Result<Self::Output> {
+        self.GetResults()
+    }
+}
+#[cfg(feature = "deprecated")]
+#[cfg(feature = "std")]
+impl core::future::IntoFuture for DeleteSmsMessageOperation {
+    type Output = windows_core::Result<()>;
+    type IntoFuture = super::super::Foundation::AsyncFuture<Self>;
+    fn into_future(self) -> Self::IntoFuture {
+        super::super::Foundation::AsyncFuture::new(self)
+    }
+}
+#[cfg(feature = "deprecated")]
 #[repr(transparent)]
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct DeleteSmsMessagesOperation(windows_core::IUnknown);
@@ -1122,6 +1145,29 @@ impl DeleteSmsMessagesOperation {
     }
 }
 #[cfg(feature = "deprecated")]
+#[cfg(feature = "std")]
+impl super::super::Foundation::Async for DeleteSmsMessagesOperation {
+    type Output = ();
+    fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetCompleted(&super::super::Foundation::AsyncActionCompletedHandler::new(move |_sender, _args| {
+            handler();
+            Ok(())
+        }))
+    }
+    fn get_results(&self) -> windows_core::Result<Self::Output> {
+        self.GetResults()
+    }
+}
+#[cfg(feature = "deprecated")]
+#[cfg(feature = "std")]
+impl core::future::IntoFuture for DeleteSmsMessagesOperation {
+    type Output = windows_core::Result<()>;
+    type IntoFuture = super::super::Foundation::AsyncFuture<Self>;
+    fn into_future(self) -> Self::IntoFuture {
+        super::super::Foundation::AsyncFuture::new(self)
+    }
+}
+#[cfg(feature = "deprecated")]
 #[repr(transparent)]
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct GetSmsDeviceOperation(windows_core::IUnknown);
@@ -1211,6 +1257,29 @@ impl GetSmsDeviceOperation {
     }
 }
 #[cfg(feature = "deprecated")]
+#[cfg(feature = "std")]
+impl super::super::Foundation::Async for GetSmsDeviceOperation {
+    type Output = SmsDevice;
+    fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetCompleted(&super::super::Foundation::AsyncOperationCompletedHandler::new(move |_sender, _args| {
+            handler();
+            Ok(())
+        }))
+    }
+    fn get_results(&self) -> windows_core::Result<Self::Output> {
+        self.GetResults()
+    }
+}
+#[cfg(feature = "deprecated")]
+#[cfg(feature = "std")]
+impl core::future::IntoFuture for GetSmsDeviceOperation {
+    type Output = windows_core::Result<SmsDevice>;
+    type IntoFuture = super::super::Foundation::AsyncFuture<Self>;
+    fn into_future(self) -> Self::IntoFuture {
+        super::super::Foundation::AsyncFuture::new(self)
+    }
+}
+#[cfg(feature = "deprecated")]
 #[repr(transparent)]
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct GetSmsMessageOperation(windows_core::IUnknown);
@@ -1299,6 +1368,29 @@ impl GetSmsMessageOperation {
         self.GetResults()
     }
 }
+#[cfg(feature = "deprecated")]
+#[cfg(feature = "std")]
+impl super::super::Foundation::Async for GetSmsMessageOperation {
+    type Output = ISmsMessage;
+    fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetCompleted(&super::super::Foundation::AsyncOperationCompletedHandler::new(move |_sender, _args| {
+            handler();
+            Ok(())
+        }))
+    }
+    fn get_results(&self) -> windows_core::Result<Self::Output> {
+        self.GetResults()
+    }
+}
+#[cfg(feature = "deprecated")]
+#[cfg(feature = "std")]
+impl core::future::IntoFuture for GetSmsMessageOperation {
+    type Output = windows_core::Result<ISmsMessage>;
+    type IntoFuture = super::super::Foundation::AsyncFuture<Self>;
+    fn into_future(self) -> Self::IntoFuture {
+        super::super::Foundation::AsyncFuture::new(self)
+    }
+}
 #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
 #[repr(transparent)]
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -1407,6 +1499,40 @@ impl GetSmsMessagesOperation {
         self.GetResults()
     }
 }
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+#[cfg(feature = "std")]
+impl super::super::Foundation::Async for GetSmsMessagesOperation {
+    type Output = super::super::Foundation::Collections::IVectorView<ISmsMessage>;
+    fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetCompleted(&super::super::Foundation::AsyncOperationWithProgressCompletedHandler::new(move |_sender, _args| {
+            handler();
+            Ok(())
+        }))
+    }
+    fn get_results(&self) -> windows_core::Result<Self::Output> {
+        self.GetResults()
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+#[cfg(feature = "std")]
+impl core::future::IntoFuture for GetSmsMessagesOperation {
+    type Output = windows_core::Result<super::super::Foundation::Collections::IVectorView<ISmsMessage>>;
+    type IntoFuture = super::super::Foundation::AsyncFuture<Self>;
+    fn into_future(self) -> Self::IntoFuture {
+        super::super::Foundation::AsyncFuture::new(self)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+#[cfg(feature = "std")]
+impl super::super::Foundation::AsyncWithProgress for GetSmsMessagesOperation {
+    type Progress = i32;
+    fn set_progress<F: Fn(Self::Progress) + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetProgress(&super::super::Foundation::AsyncOperationProgressHandler::new(move |_sender, value| {
+            handler(windows_core::Type::from_default(value)?);
+            Ok(())
+        }))
+    }
+}
 #[cfg(feature = "deprecated")]
 #[repr(transparent)]
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -1493,6 +1619,29 @@ impl SendSmsMessageOperation {
         self.GetResults()
     }
 }
+#[cfg(feature = "deprecated")]
+#[cfg(feature = "std")]
+impl super::super::Foundation::Async for SendSmsMessageOperation {
+    type Output = ();
+    fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetCompleted(&super::super::Foundation::AsyncActionCompletedHandler::new(move |_sender, _args| {
+            handler();
+            Ok(())
+        }))
+    }
+    fn get_results(&self) -> windows_core::Result<Self::Output> {
+        self.GetResults()
+    }
+}
+#[cfg(feature = "deprecated")]
+#[cfg(feature = "std")]
+impl core::future::IntoFuture for SendSmsMessageOperation {
+    type Output = windows_core::Result<()>;
+    type IntoFuture = super::super::Foundation::AsyncFuture<Self>;
+    fn into_future(self) -> Self::IntoFuture {
+        super::super::Foundation::AsyncFuture::new(self)
+    }
+}
 #[repr(transparent)]
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct SmsAppMessage(windows_core::IUnknown);
@@ -3319,6 +3468,7 @@ impl CellularClass {
 impl windows_core::TypeKind for CellularClass {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CellularClass {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CellularClass").field(&self.0).finish()
@@ -3350,6 +3500,7 @@ impl SmsBroadcastType {
 impl windows_core::TypeKind for SmsBroadcastType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmsBroadcastType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmsBroadcastType").field(&self.0).finish()
@@ -3371,6 +3522,7 @@ impl SmsDataFormat {
 impl windows_core::TypeKind for SmsDataFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmsDataFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmsDataFormat").field(&self.0).finish()
@@ -3395,6 +3547,7 @@ impl SmsDeviceStatus {
 impl windows_core::TypeKind for SmsDeviceStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmsDeviceStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmsDeviceStatus").field(&self.0).finish()
@@ -3422,6 +3575,7 @@ impl SmsEncoding {
 impl windows_core::TypeKind for SmsEncoding {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmsEncoding {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmsEncoding").field(&self.0).finish()
@@ -3442,6 +3596,7 @@ impl SmsFilterActionType {
 impl windows_core::TypeKind for SmsFilterActionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmsFilterActionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmsFilterActionType").field(&self.0).finish()
@@ -3463,6 +3618,7 @@ impl SmsGeographicalScope {
 impl windows_core::TypeKind for SmsGeographicalScope {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmsGeographicalScope {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmsGeographicalScope").field(&self.0).finish()
@@ -3484,6 +3640,7 @@ impl SmsMessageClass {
 impl windows_core::TypeKind for SmsMessageClass {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmsMessageClass {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmsMessageClass").field(&self.0).finish()
@@ -3509,6 +3666,7 @@ impl windows_core::TypeKind for SmsMessageFilter {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmsMessageFilter {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmsMessageFilter").field(&self.0).finish()
@@ -3533,6 +3691,7 @@ impl SmsMessageType {
 impl windows_core::TypeKind for SmsMessageType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmsMessageType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmsMessageType").field(&self.0).finish()
@@ -3561,6 +3720,7 @@ impl SmsModemErrorCode {
 impl windows_core::TypeKind for SmsModemErrorCode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmsModemErrorCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmsModemErrorCode").field(&self.0).finish()
@@ -3570,7 +3730,8 @@ impl windows_core::RuntimeType for SmsModemErrorCode {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Sms.SmsModemErrorCode;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SmsEncodedLength {
     pub SegmentCount: u32,
     pub CharacterCountLastSegment: u32,
@@ -3732,3 +3893,5 @@ pub struct SmsMessageReceivedEventHandler_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1225 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISmsAppMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "Timestamp", 6usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn To(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "To", 7usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).To)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTo(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "SetTo", 8usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).SetTo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn From(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "From", 9usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).From)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Body(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "Body", 10usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).Body)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBody(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "SetBody", 11usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).SetBody)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallbackNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "CallbackNumber", 12usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).CallbackNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCallbackNumber(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "SetCallbackNumber", 13usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).SetCallbackNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDeliveryNotificationEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "IsDeliveryNotificationEnabled", 14usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).IsDeliveryNotificationEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsDeliveryNotificationEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "SetIsDeliveryNotificationEnabled", 15usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).SetIsDeliveryNotificationEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetryAttemptCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "RetryAttemptCount", 16usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).RetryAttemptCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRetryAttemptCount(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "SetRetryAttemptCount", 17usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).SetRetryAttemptCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Encoding(this: *mut core::ffi::c_void, result__: *mut SmsEncoding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "Encoding", 18usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).Encoding)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEncoding(this: *mut core::ffi::c_void, value: SmsEncoding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "SetEncoding", 19usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).SetEncoding)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PortNumber(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "PortNumber", 20usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).PortNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPortNumber(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "SetPortNumber", 21usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).SetPortNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TeleserviceId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "TeleserviceId", 22usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).TeleserviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTeleserviceId(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "SetTeleserviceId", 23usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).SetTeleserviceId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtocolId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "ProtocolId", 24usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).ProtocolId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProtocolId(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "SetProtocolId", 25usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).SetProtocolId)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn BinaryBody(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "BinaryBody", 26usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).BinaryBody)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetBinaryBody(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsAppMessage", "SetBinaryBody", 27usize, |this| ((**(this as *const *const ISmsAppMessage_Vtbl)).SetBinaryBody)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Timestamp,
+            To,
+            SetTo,
+            From,
+            Body,
+            SetBody,
+            CallbackNumber,
+            SetCallbackNumber,
+            IsDeliveryNotificationEnabled,
+            SetIsDeliveryNotificationEnabled,
+            RetryAttemptCount,
+            SetRetryAttemptCount,
+            Encoding,
+            SetEncoding,
+            PortNumber,
+            SetPortNumber,
+            TeleserviceId,
+            SetTeleserviceId,
+            ProtocolId,
+            SetProtocolId,
+            #[cfg(feature = "Storage_Streams")]
+            BinaryBody,
+            #[cfg(not(feature = "Storage_Streams"))]
+            BinaryBody: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetBinaryBody,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetBinaryBody: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsAppMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISmsBinaryMessage_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, result__: *mut SmsDataFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsBinaryMessage", "Format", 6usize, |this| ((**(this as *const *const ISmsBinaryMessage_Vtbl)).Format)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, value: SmsDataFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsBinaryMessage", "SetFormat", 7usize, |this| ((**(this as *const *const ISmsBinaryMessage_Vtbl)).SetFormat)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsBinaryMessage", "GetData", 8usize, |this| ((**(this as *const *const ISmsBinaryMessage_Vtbl)).GetData)(this, result_size__, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsBinaryMessage", "SetData", 9usize, |this| ((**(this as *const *const ISmsBinaryMessage_Vtbl)).SetData)(this, value_array_size, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Format,
+            #[cfg(not(feature = "deprecated"))]
+            Format: 0,
+            #[cfg(feature = "deprecated")]
+            SetFormat,
+            #[cfg(not(feature = "deprecated"))]
+            SetFormat: 0,
+            #[cfg(feature = "deprecated")]
+            GetData,
+            #[cfg(not(feature = "deprecated"))]
+            GetData: 0,
+            #[cfg(feature = "deprecated")]
+            SetData,
+            #[cfg(not(feature = "deprecated"))]
+            SetData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsBinaryMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmsBroadcastMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsBroadcastMessage", "Timestamp", 6usize, |this| ((**(this as *const *const ISmsBroadcastMessage_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn To(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsBroadcastMessage", "To", 7usize, |this| ((**(this as *const *const ISmsBroadcastMessage_Vtbl)).To)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Body(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsBroadcastMessage", "Body", 8usize, |this| ((**(this as *const *const ISmsBroadcastMessage_Vtbl)).Body)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Channel(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsBroadcastMessage", "Channel", 9usize, |this| ((**(this as *const *const ISmsBroadcastMessage_Vtbl)).Channel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GeographicalScope(this: *mut core::ffi::c_void, result__: *mut SmsGeographicalScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsBroadcastMessage", "GeographicalScope", 10usize, |this| ((**(this as *const *const ISmsBroadcastMessage_Vtbl)).GeographicalScope)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageCode(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsBroadcastMessage", "MessageCode", 11usize, |this| ((**(this as *const *const ISmsBroadcastMessage_Vtbl)).MessageCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateNumber(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsBroadcastMessage", "UpdateNumber", 12usize, |this| ((**(this as *const *const ISmsBroadcastMessage_Vtbl)).UpdateNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BroadcastType(this: *mut core::ffi::c_void, result__: *mut SmsBroadcastType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsBroadcastMessage", "BroadcastType", 13usize, |this| ((**(this as *const *const ISmsBroadcastMessage_Vtbl)).BroadcastType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEmergencyAlert(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsBroadcastMessage", "IsEmergencyAlert", 14usize, |this| ((**(this as *const *const ISmsBroadcastMessage_Vtbl)).IsEmergencyAlert)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUserPopupRequested(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsBroadcastMessage", "IsUserPopupRequested", 15usize, |this| ((**(this as *const *const ISmsBroadcastMessage_Vtbl)).IsUserPopupRequested)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Timestamp,
+            To,
+            Body,
+            Channel,
+            GeographicalScope,
+            MessageCode,
+            UpdateNumber,
+            BroadcastType,
+            IsEmergencyAlert,
+            IsUserPopupRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsBroadcastMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISmsDevice_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SendMessageAsync(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice", "SendMessageAsync", 6usize, |this| ((**(this as *const *const ISmsDevice_Vtbl)).SendMessageAsync)(this, message, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CalculateLength(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut SmsEncodedLength) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice", "CalculateLength", 7usize, |this| ((**(this as *const *const ISmsDevice_Vtbl)).CalculateLength)(this, message, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AccountPhoneNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice", "AccountPhoneNumber", 8usize, |this| ((**(this as *const *const ISmsDevice_Vtbl)).AccountPhoneNumber)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CellularClass(this: *mut core::ffi::c_void, result__: *mut CellularClass) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice", "CellularClass", 9usize, |this| ((**(this as *const *const ISmsDevice_Vtbl)).CellularClass)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn MessageStore(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice", "MessageStore", 10usize, |this| ((**(this as *const *const ISmsDevice_Vtbl)).MessageStore)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DeviceStatus(this: *mut core::ffi::c_void, result__: *mut SmsDeviceStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice", "DeviceStatus", 11usize, |this| ((**(this as *const *const ISmsDevice_Vtbl)).DeviceStatus)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SmsMessageReceived(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice", "SmsMessageReceived", 12usize, |this| ((**(this as *const *const ISmsDevice_Vtbl)).SmsMessageReceived)(this, eventhandler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveSmsMessageReceived(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice", "RemoveSmsMessageReceived", 13usize, |this| ((**(this as *const *const ISmsDevice_Vtbl)).RemoveSmsMessageReceived)(this, eventcookie), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SmsDeviceStatusChanged(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice", "SmsDeviceStatusChanged", 14usize, |this| ((**(this as *const *const ISmsDevice_Vtbl)).SmsDeviceStatusChanged)(this, eventhandler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveSmsDeviceStatusChanged(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice", "RemoveSmsDeviceStatusChanged", 15usize, |this| ((**(this as *const *const ISmsDevice_Vtbl)).RemoveSmsDeviceStatusChanged)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            SendMessageAsync,
+            #[cfg(not(feature = "deprecated"))]
+            SendMessageAsync: 0,
+            #[cfg(feature = "deprecated")]
+            CalculateLength,
+            #[cfg(not(feature = "deprecated"))]
+            CalculateLength: 0,
+            #[cfg(feature = "deprecated")]
+            AccountPhoneNumber,
+            #[cfg(not(feature = "deprecated"))]
+            AccountPhoneNumber: 0,
+            #[cfg(feature = "deprecated")]
+            CellularClass,
+            #[cfg(not(feature = "deprecated"))]
+            CellularClass: 0,
+            #[cfg(feature = "deprecated")]
+            MessageStore,
+            #[cfg(not(feature = "deprecated"))]
+            MessageStore: 0,
+            #[cfg(feature = "deprecated")]
+            DeviceStatus,
+            #[cfg(not(feature = "deprecated"))]
+            DeviceStatus: 0,
+            #[cfg(feature = "deprecated")]
+            SmsMessageReceived,
+            #[cfg(not(feature = "deprecated"))]
+            SmsMessageReceived: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveSmsMessageReceived,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveSmsMessageReceived: 0,
+            #[cfg(feature = "deprecated")]
+            SmsDeviceStatusChanged,
+            #[cfg(not(feature = "deprecated"))]
+            SmsDeviceStatusChanged: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveSmsDeviceStatusChanged,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveSmsDeviceStatusChanged: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmsDevice2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SmscAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice2", "SmscAddress", 6usize, |this| ((**(this as *const *const ISmsDevice2_Vtbl)).SmscAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSmscAddress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice2", "SetSmscAddress", 7usize, |this| ((**(this as *const *const ISmsDevice2_Vtbl)).SetSmscAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice2", "DeviceId", 8usize, |this| ((**(this as *const *const ISmsDevice2_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice2", "ParentDeviceId", 9usize, |this| ((**(this as *const *const ISmsDevice2_Vtbl)).ParentDeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccountPhoneNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice2", "AccountPhoneNumber", 10usize, |this| ((**(this as *const *const ISmsDevice2_Vtbl)).AccountPhoneNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CellularClass(this: *mut core::ffi::c_void, result__: *mut CellularClass) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice2", "CellularClass", 11usize, |this| ((**(this as *const *const ISmsDevice2_Vtbl)).CellularClass)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceStatus(this: *mut core::ffi::c_void, result__: *mut SmsDeviceStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice2", "DeviceStatus", 12usize, |this| ((**(this as *const *const ISmsDevice2_Vtbl)).DeviceStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CalculateLength(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut SmsEncodedLength) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice2", "CalculateLength", 13usize, |this| ((**(this as *const *const ISmsDevice2_Vtbl)).CalculateLength)(this, message, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendMessageAndGetResultAsync(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice2", "SendMessageAndGetResultAsync", 14usize, |this| ((**(this as *const *const ISmsDevice2_Vtbl)).SendMessageAndGetResultAsync)(this, message, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceStatusChanged(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice2", "DeviceStatusChanged", 15usize, |this| ((**(this as *const *const ISmsDevice2_Vtbl)).DeviceStatusChanged)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDeviceStatusChanged(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice2", "RemoveDeviceStatusChanged", 16usize, |this| ((**(this as *const *const ISmsDevice2_Vtbl)).RemoveDeviceStatusChanged)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SmscAddress,
+            SetSmscAddress,
+            DeviceId,
+            ParentDeviceId,
+            AccountPhoneNumber,
+            CellularClass,
+            DeviceStatus,
+            CalculateLength,
+            SendMessageAndGetResultAsync,
+            DeviceStatusChanged,
+            RemoveDeviceStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsDevice2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmsDevice2Statics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice2Statics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const ISmsDevice2Statics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromId(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice2Statics", "FromId", 7usize, |this| ((**(this as *const *const ISmsDevice2Statics_Vtbl)).FromId)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice2Statics", "GetDefault", 8usize, |this| ((**(this as *const *const ISmsDevice2Statics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromParentId(this: *mut core::ffi::c_void, parentdeviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDevice2Statics", "FromParentId", 9usize, |this| ((**(this as *const *const ISmsDevice2Statics_Vtbl)).FromParentId)(this, parentdeviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromId, GetDefault, FromParentId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsDevice2Statics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for ISmsDeviceMessageStore_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DeleteMessageAsync(this: *mut core::ffi::c_void, messageid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDeviceMessageStore", "DeleteMessageAsync", 6usize, |this| ((**(this as *const *const ISmsDeviceMessageStore_Vtbl)).DeleteMessageAsync)(this, messageid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DeleteMessagesAsync(this: *mut core::ffi::c_void, messagefilter: SmsMessageFilter, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDeviceMessageStore", "DeleteMessagesAsync", 7usize, |this| ((**(this as *const *const ISmsDeviceMessageStore_Vtbl)).DeleteMessagesAsync)(this, messagefilter, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetMessageAsync(this: *mut core::ffi::c_void, messageid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDeviceMessageStore", "GetMessageAsync", 8usize, |this| ((**(this as *const *const ISmsDeviceMessageStore_Vtbl)).GetMessageAsync)(this, messageid, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn GetMessagesAsync(this: *mut core::ffi::c_void, messagefilter: SmsMessageFilter, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDeviceMessageStore", "GetMessagesAsync", 9usize, |this| ((**(this as *const *const ISmsDeviceMessageStore_Vtbl)).GetMessagesAsync)(this, messagefilter, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn MaxMessages(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDeviceMessageStore", "MaxMessages", 10usize, |this| ((**(this as *const *const ISmsDeviceMessageStore_Vtbl)).MaxMessages)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            DeleteMessageAsync,
+            #[cfg(not(feature = "deprecated"))]
+            DeleteMessageAsync: 0,
+            #[cfg(feature = "deprecated")]
+            DeleteMessagesAsync,
+            #[cfg(not(feature = "deprecated"))]
+            DeleteMessagesAsync: 0,
+            #[cfg(feature = "deprecated")]
+            GetMessageAsync,
+            #[cfg(not(feature = "deprecated"))]
+            GetMessageAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            GetMessagesAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            GetMessagesAsync: 0,
+            #[cfg(feature = "deprecated")]
+            MaxMessages,
+            #[cfg(not(feature = "deprecated"))]
+            MaxMessages: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsDeviceMessageStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISmsDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDeviceStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const ISmsDeviceStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDeviceStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const ISmsDeviceStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDeviceStatics", "GetDefaultAsync", 8usize, |this| ((**(this as *const *const ISmsDeviceStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            GetDeviceSelector,
+            #[cfg(not(feature = "deprecated"))]
+            GetDeviceSelector: 0,
+            #[cfg(feature = "deprecated")]
+            FromIdAsync,
+            #[cfg(not(feature = "deprecated"))]
+            FromIdAsync: 0,
+            #[cfg(feature = "deprecated")]
+            GetDefaultAsync,
+            #[cfg(not(feature = "deprecated"))]
+            GetDefaultAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISmsDeviceStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn FromNetworkAccountIdAsync(this: *mut core::ffi::c_void, networkaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsDeviceStatics2", "FromNetworkAccountIdAsync", 6usize, |this| ((**(this as *const *const ISmsDeviceStatics2_Vtbl)).FromNetworkAccountIdAsync)(this, networkaccountid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            FromNetworkAccountIdAsync,
+            #[cfg(not(feature = "deprecated"))]
+            FromNetworkAccountIdAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsDeviceStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISmsFilterRule_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MessageType(this: *mut core::ffi::c_void, result__: *mut SmsMessageType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRule", "MessageType", 6usize, |this| ((**(this as *const *const ISmsFilterRule_Vtbl)).MessageType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ImsiPrefixes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRule", "ImsiPrefixes", 7usize, |this| ((**(this as *const *const ISmsFilterRule_Vtbl)).ImsiPrefixes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DeviceIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRule", "DeviceIds", 8usize, |this| ((**(this as *const *const ISmsFilterRule_Vtbl)).DeviceIds)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SenderNumbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRule", "SenderNumbers", 9usize, |this| ((**(this as *const *const ISmsFilterRule_Vtbl)).SenderNumbers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TextMessagePrefixes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRule", "TextMessagePrefixes", 10usize, |this| ((**(this as *const *const ISmsFilterRule_Vtbl)).TextMessagePrefixes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PortNumbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRule", "PortNumbers", 11usize, |this| ((**(this as *const *const ISmsFilterRule_Vtbl)).PortNumbers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CellularClass(this: *mut core::ffi::c_void, result__: *mut CellularClass) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRule", "CellularClass", 12usize, |this| ((**(this as *const *const ISmsFilterRule_Vtbl)).CellularClass)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCellularClass(this: *mut core::ffi::c_void, value: CellularClass) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRule", "SetCellularClass", 13usize, |this| ((**(this as *const *const ISmsFilterRule_Vtbl)).SetCellularClass)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ProtocolIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRule", "ProtocolIds", 14usize, |this| ((**(this as *const *const ISmsFilterRule_Vtbl)).ProtocolIds)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TeleserviceIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRule", "TeleserviceIds", 15usize, |this| ((**(this as *const *const ISmsFilterRule_Vtbl)).TeleserviceIds)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn WapApplicationIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRule", "WapApplicationIds", 16usize, |this| ((**(this as *const *const ISmsFilterRule_Vtbl)).WapApplicationIds)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn WapContentTypes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRule", "WapContentTypes", 17usize, |this| ((**(this as *const *const ISmsFilterRule_Vtbl)).WapContentTypes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BroadcastTypes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRule", "BroadcastTypes", 18usize, |this| ((**(this as *const *const ISmsFilterRule_Vtbl)).BroadcastTypes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BroadcastChannels(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRule", "BroadcastChannels", 19usize, |this| ((**(this as *const *const ISmsFilterRule_Vtbl)).BroadcastChannels)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MessageType,
+            #[cfg(feature = "Foundation_Collections")]
+            ImsiPrefixes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ImsiPrefixes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            DeviceIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DeviceIds: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SenderNumbers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SenderNumbers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            TextMessagePrefixes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TextMessagePrefixes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            PortNumbers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PortNumbers: 0,
+            CellularClass,
+            SetCellularClass,
+            #[cfg(feature = "Foundation_Collections")]
+            ProtocolIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ProtocolIds: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            TeleserviceIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TeleserviceIds: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            WapApplicationIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            WapApplicationIds: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            WapContentTypes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            WapContentTypes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            BroadcastTypes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BroadcastTypes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            BroadcastChannels,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BroadcastChannels: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsFilterRule as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmsFilterRuleFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFilterRule(this: *mut core::ffi::c_void, messagetype: SmsMessageType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRuleFactory", "CreateFilterRule", 6usize, |this| ((**(this as *const *const ISmsFilterRuleFactory_Vtbl)).CreateFilterRule)(this, messagetype, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFilterRule }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsFilterRuleFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISmsFilterRules_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActionType(this: *mut core::ffi::c_void, result__: *mut SmsFilterActionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRules", "ActionType", 6usize, |this| ((**(this as *const *const ISmsFilterRules_Vtbl)).ActionType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Rules(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRules", "Rules", 7usize, |this| ((**(this as *const *const ISmsFilterRules_Vtbl)).Rules)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ActionType,
+            #[cfg(feature = "Foundation_Collections")]
+            Rules,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Rules: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsFilterRules as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmsFilterRulesFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFilterRules(this: *mut core::ffi::c_void, actiontype: SmsFilterActionType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsFilterRulesFactory", "CreateFilterRules", 6usize, |this| ((**(this as *const *const ISmsFilterRulesFactory_Vtbl)).CreateFilterRules)(this, actiontype, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFilterRules }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsFilterRulesFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmsMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessage", "Id", 6usize, |this| ((**(this as *const *const ISmsMessage_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageClass(this: *mut core::ffi::c_void, result__: *mut SmsMessageClass) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessage", "MessageClass", 7usize, |this| ((**(this as *const *const ISmsMessage_Vtbl)).MessageClass)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, MessageClass }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmsMessageBase_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MessageType(this: *mut core::ffi::c_void, result__: *mut SmsMessageType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageBase", "MessageType", 6usize, |this| ((**(this as *const *const ISmsMessageBase_Vtbl)).MessageType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageBase", "DeviceId", 7usize, |this| ((**(this as *const *const ISmsMessageBase_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CellularClass(this: *mut core::ffi::c_void, result__: *mut CellularClass) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageBase", "CellularClass", 8usize, |this| ((**(this as *const *const ISmsMessageBase_Vtbl)).CellularClass)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageClass(this: *mut core::ffi::c_void, result__: *mut SmsMessageClass) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageBase", "MessageClass", 9usize, |this| ((**(this as *const *const ISmsMessageBase_Vtbl)).MessageClass)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SimIccId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageBase", "SimIccId", 10usize, |this| ((**(this as *const *const ISmsMessageBase_Vtbl)).SimIccId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MessageType, DeviceId, CellularClass, MessageClass, SimIccId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsMessageBase as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISmsMessageReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TextMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageReceivedEventArgs", "TextMessage", 6usize, |this| ((**(this as *const *const ISmsMessageReceivedEventArgs_Vtbl)).TextMessage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn BinaryMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageReceivedEventArgs", "BinaryMessage", 7usize, |this| ((**(this as *const *const ISmsMessageReceivedEventArgs_Vtbl)).BinaryMessage)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            TextMessage,
+            #[cfg(not(feature = "deprecated"))]
+            TextMessage: 0,
+            #[cfg(feature = "deprecated")]
+            BinaryMessage,
+            #[cfg(not(feature = "deprecated"))]
+            BinaryMessage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsMessageReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmsMessageReceivedTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MessageType(this: *mut core::ffi::c_void, result__: *mut SmsMessageType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageReceivedTriggerDetails", "MessageType", 6usize, |this| ((**(this as *const *const ISmsMessageReceivedTriggerDetails_Vtbl)).MessageType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageReceivedTriggerDetails", "TextMessage", 7usize, |this| ((**(this as *const *const ISmsMessageReceivedTriggerDetails_Vtbl)).TextMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WapMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageReceivedTriggerDetails", "WapMessage", 8usize, |this| ((**(this as *const *const ISmsMessageReceivedTriggerDetails_Vtbl)).WapMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageReceivedTriggerDetails", "AppMessage", 9usize, |this| ((**(this as *const *const ISmsMessageReceivedTriggerDetails_Vtbl)).AppMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BroadcastMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageReceivedTriggerDetails", "BroadcastMessage", 10usize, |this| ((**(this as *const *const ISmsMessageReceivedTriggerDetails_Vtbl)).BroadcastMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VoicemailMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageReceivedTriggerDetails", "VoicemailMessage", 11usize, |this| ((**(this as *const *const ISmsMessageReceivedTriggerDetails_Vtbl)).VoicemailMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageReceivedTriggerDetails", "StatusMessage", 12usize, |this| ((**(this as *const *const ISmsMessageReceivedTriggerDetails_Vtbl)).StatusMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Drop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageReceivedTriggerDetails", "Drop", 13usize, |this| ((**(this as *const *const ISmsMessageReceivedTriggerDetails_Vtbl)).Drop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Accept(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageReceivedTriggerDetails", "Accept", 14usize, |this| ((**(this as *const *const ISmsMessageReceivedTriggerDetails_Vtbl)).Accept)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MessageType,
+            TextMessage,
+            WapMessage,
+            AppMessage,
+            BroadcastMessage,
+            VoicemailMessage,
+            StatusMessage,
+            Drop,
+            Accept,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsMessageReceivedTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmsMessageRegistration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageRegistration", "Id", 6usize, |this| ((**(this as *const *const ISmsMessageRegistration_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unregister(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageRegistration", "Unregister", 7usize, |this| ((**(this as *const *const ISmsMessageRegistration_Vtbl)).Unregister)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageReceived(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageRegistration", "MessageReceived", 8usize, |this| ((**(this as *const *const ISmsMessageRegistration_Vtbl)).MessageReceived)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMessageReceived(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageRegistration", "RemoveMessageReceived", 9usize, |this| ((**(this as *const *const ISmsMessageRegistration_Vtbl)).RemoveMessageReceived)(this, eventcookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Unregister, MessageReceived, RemoveMessageReceived }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsMessageRegistration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISmsMessageRegistrationStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AllRegistrations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageRegistrationStatics", "AllRegistrations", 6usize, |this| ((**(this as *const *const ISmsMessageRegistrationStatics_Vtbl)).AllRegistrations)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, filterrules: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsMessageRegistrationStatics", "Register", 7usize, |this| ((**(this as *const *const ISmsMessageRegistrationStatics_Vtbl)).Register)(this, id, filterrules, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AllRegistrations,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AllRegistrations: 0,
+            Register,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsMessageRegistrationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISmsReceivedEventDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsReceivedEventDetails", "DeviceId", 6usize, |this| ((**(this as *const *const ISmsReceivedEventDetails_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn MessageIndex(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsReceivedEventDetails", "MessageIndex", 7usize, |this| ((**(this as *const *const ISmsReceivedEventDetails_Vtbl)).MessageIndex)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            DeviceId,
+            #[cfg(not(feature = "deprecated"))]
+            DeviceId: 0,
+            #[cfg(feature = "deprecated")]
+            MessageIndex,
+            #[cfg(not(feature = "deprecated"))]
+            MessageIndex: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsReceivedEventDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISmsReceivedEventDetails2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn MessageClass(this: *mut core::ffi::c_void, result__: *mut SmsMessageClass) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsReceivedEventDetails2", "MessageClass", 6usize, |this| ((**(this as *const *const ISmsReceivedEventDetails2_Vtbl)).MessageClass)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn BinaryMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsReceivedEventDetails2", "BinaryMessage", 7usize, |this| ((**(this as *const *const ISmsReceivedEventDetails2_Vtbl)).BinaryMessage)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            MessageClass,
+            #[cfg(not(feature = "deprecated"))]
+            MessageClass: 0,
+            #[cfg(feature = "deprecated")]
+            BinaryMessage,
+            #[cfg(not(feature = "deprecated"))]
+            BinaryMessage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsReceivedEventDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISmsSendMessageResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSuccessful(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsSendMessageResult", "IsSuccessful", 6usize, |this| ((**(this as *const *const ISmsSendMessageResult_Vtbl)).IsSuccessful)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MessageReferenceNumbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsSendMessageResult", "MessageReferenceNumbers", 7usize, |this| ((**(this as *const *const ISmsSendMessageResult_Vtbl)).MessageReferenceNumbers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CellularClass(this: *mut core::ffi::c_void, result__: *mut CellularClass) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsSendMessageResult", "CellularClass", 8usize, |this| ((**(this as *const *const ISmsSendMessageResult_Vtbl)).CellularClass)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModemErrorCode(this: *mut core::ffi::c_void, result__: *mut SmsModemErrorCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsSendMessageResult", "ModemErrorCode", 9usize, |this| ((**(this as *const *const ISmsSendMessageResult_Vtbl)).ModemErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsErrorTransient(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsSendMessageResult", "IsErrorTransient", 10usize, |this| ((**(this as *const *const ISmsSendMessageResult_Vtbl)).IsErrorTransient)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkCauseCode(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsSendMessageResult", "NetworkCauseCode", 11usize, |this| ((**(this as *const *const ISmsSendMessageResult_Vtbl)).NetworkCauseCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportFailureCause(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsSendMessageResult", "TransportFailureCause", 12usize, |this| ((**(this as *const *const ISmsSendMessageResult_Vtbl)).TransportFailureCause)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSuccessful,
+            #[cfg(feature = "Foundation_Collections")]
+            MessageReferenceNumbers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MessageReferenceNumbers: 0,
+            CellularClass,
+            ModemErrorCode,
+            IsErrorTransient,
+            NetworkCauseCode,
+            TransportFailureCause,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsSendMessageResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmsStatusMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn To(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsStatusMessage", "To", 6usize, |this| ((**(this as *const *const ISmsStatusMessage_Vtbl)).To)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn From(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsStatusMessage", "From", 7usize, |this| ((**(this as *const *const ISmsStatusMessage_Vtbl)).From)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Body(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsStatusMessage", "Body", 8usize, |this| ((**(this as *const *const ISmsStatusMessage_Vtbl)).Body)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsStatusMessage", "Status", 9usize, |this| ((**(this as *const *const ISmsStatusMessage_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageReferenceNumber(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsStatusMessage", "MessageReferenceNumber", 10usize, |this| ((**(this as *const *const ISmsStatusMessage_Vtbl)).MessageReferenceNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceCenterTimestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsStatusMessage", "ServiceCenterTimestamp", 11usize, |this| ((**(this as *const *const ISmsStatusMessage_Vtbl)).ServiceCenterTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DischargeTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsStatusMessage", "DischargeTime", 12usize, |this| ((**(this as *const *const ISmsStatusMessage_Vtbl)).DischargeTime)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            To,
+            From,
+            Body,
+            Status,
+            MessageReferenceNumber,
+            ServiceCenterTimestamp,
+            DischargeTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsStatusMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for ISmsTextMessage_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage", "Timestamp", 6usize, |this| ((**(this as *const *const ISmsTextMessage_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PartReferenceId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage", "PartReferenceId", 7usize, |this| ((**(this as *const *const ISmsTextMessage_Vtbl)).PartReferenceId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PartNumber(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage", "PartNumber", 8usize, |this| ((**(this as *const *const ISmsTextMessage_Vtbl)).PartNumber)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PartCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage", "PartCount", 9usize, |this| ((**(this as *const *const ISmsTextMessage_Vtbl)).PartCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn To(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage", "To", 10usize, |this| ((**(this as *const *const ISmsTextMessage_Vtbl)).To)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetTo(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage", "SetTo", 11usize, |this| ((**(this as *const *const ISmsTextMessage_Vtbl)).SetTo)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn From(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage", "From", 12usize, |this| ((**(this as *const *const ISmsTextMessage_Vtbl)).From)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetFrom(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage", "SetFrom", 13usize, |this| ((**(this as *const *const ISmsTextMessage_Vtbl)).SetFrom)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Body(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage", "Body", 14usize, |this| ((**(this as *const *const ISmsTextMessage_Vtbl)).Body)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetBody(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage", "SetBody", 15usize, |this| ((**(this as *const *const ISmsTextMessage_Vtbl)).SetBody)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Encoding(this: *mut core::ffi::c_void, result__: *mut SmsEncoding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage", "Encoding", 16usize, |this| ((**(this as *const *const ISmsTextMessage_Vtbl)).Encoding)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetEncoding(this: *mut core::ffi::c_void, value: SmsEncoding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage", "SetEncoding", 17usize, |this| ((**(this as *const *const ISmsTextMessage_Vtbl)).SetEncoding)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn ToBinaryMessages(this: *mut core::ffi::c_void, format: SmsDataFormat, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage", "ToBinaryMessages", 18usize, |this| ((**(this as *const *const ISmsTextMessage_Vtbl)).ToBinaryMessages)(this, format, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Timestamp,
+            #[cfg(not(feature = "deprecated"))]
+            Timestamp: 0,
+            #[cfg(feature = "deprecated")]
+            PartReferenceId,
+            #[cfg(not(feature = "deprecated"))]
+            PartReferenceId: 0,
+            #[cfg(feature = "deprecated")]
+            PartNumber,
+            #[cfg(not(feature = "deprecated"))]
+            PartNumber: 0,
+            #[cfg(feature = "deprecated")]
+            PartCount,
+            #[cfg(not(feature = "deprecated"))]
+            PartCount: 0,
+            #[cfg(feature = "deprecated")]
+            To,
+            #[cfg(not(feature = "deprecated"))]
+            To: 0,
+            #[cfg(feature = "deprecated")]
+            SetTo,
+            #[cfg(not(feature = "deprecated"))]
+            SetTo: 0,
+            #[cfg(feature = "deprecated")]
+            From,
+            #[cfg(not(feature = "deprecated"))]
+            From: 0,
+            #[cfg(feature = "deprecated")]
+            SetFrom,
+            #[cfg(not(feature = "deprecated"))]
+            SetFrom: 0,
+            #[cfg(feature = "deprecated")]
+            Body,
+            #[cfg(not(feature = "deprecated"))]
+            Body: 0,
+            #[cfg(feature = "deprecated")]
+            SetBody,
+            #[cfg(not(feature = "deprecated"))]
+            SetBody: 0,
+            #[cfg(feature = "deprecated")]
+            Encoding,
+            #[cfg(not(feature = "deprecated"))]
+            Encoding: 0,
+            #[cfg(feature = "deprecated")]
+            SetEncoding,
+            #[cfg(not(feature = "deprecated"))]
+            SetEncoding: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            ToBinaryMessages,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            ToBinaryMessages: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsTextMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmsTextMessage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "Timestamp", 6usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn To(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "To", 7usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).To)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTo(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "SetTo", 8usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).SetTo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn From(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "From", 9usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).From)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Body(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "Body", 10usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).Body)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBody(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "SetBody", 11usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).SetBody)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Encoding(this: *mut core::ffi::c_void, result__: *mut SmsEncoding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "Encoding", 12usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).Encoding)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEncoding(this: *mut core::ffi::c_void, value: SmsEncoding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "SetEncoding", 13usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).SetEncoding)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallbackNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "CallbackNumber", 14usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).CallbackNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCallbackNumber(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "SetCallbackNumber", 15usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).SetCallbackNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDeliveryNotificationEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "IsDeliveryNotificationEnabled", 16usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).IsDeliveryNotificationEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsDeliveryNotificationEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "SetIsDeliveryNotificationEnabled", 17usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).SetIsDeliveryNotificationEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetryAttemptCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "RetryAttemptCount", 18usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).RetryAttemptCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRetryAttemptCount(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "SetRetryAttemptCount", 19usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).SetRetryAttemptCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TeleserviceId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "TeleserviceId", 20usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).TeleserviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtocolId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessage2", "ProtocolId", 21usize, |this| ((**(this as *const *const ISmsTextMessage2_Vtbl)).ProtocolId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Timestamp,
+            To,
+            SetTo,
+            From,
+            Body,
+            SetBody,
+            Encoding,
+            SetEncoding,
+            CallbackNumber,
+            SetCallbackNumber,
+            IsDeliveryNotificationEnabled,
+            SetIsDeliveryNotificationEnabled,
+            RetryAttemptCount,
+            SetRetryAttemptCount,
+            TeleserviceId,
+            ProtocolId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsTextMessage2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISmsTextMessageStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn FromBinaryMessage(this: *mut core::ffi::c_void, binarymessage: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessageStatics", "FromBinaryMessage", 6usize, |this| ((**(this as *const *const ISmsTextMessageStatics_Vtbl)).FromBinaryMessage)(this, binarymessage, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn FromBinaryData(this: *mut core::ffi::c_void, format: SmsDataFormat, value_array_size: u32, value: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsTextMessageStatics", "FromBinaryData", 7usize, |this| ((**(this as *const *const ISmsTextMessageStatics_Vtbl)).FromBinaryData)(this, format, value_array_size, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            FromBinaryMessage,
+            #[cfg(not(feature = "deprecated"))]
+            FromBinaryMessage: 0,
+            #[cfg(feature = "deprecated")]
+            FromBinaryData,
+            #[cfg(not(feature = "deprecated"))]
+            FromBinaryData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsTextMessageStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmsVoicemailMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsVoicemailMessage", "Timestamp", 6usize, |this| ((**(this as *const *const ISmsVoicemailMessage_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn To(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsVoicemailMessage", "To", 7usize, |this| ((**(this as *const *const ISmsVoicemailMessage_Vtbl)).To)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Body(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsVoicemailMessage", "Body", 8usize, |this| ((**(this as *const *const ISmsVoicemailMessage_Vtbl)).Body)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsVoicemailMessage", "MessageCount", 9usize, |this| ((**(this as *const *const ISmsVoicemailMessage_Vtbl)).MessageCount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, To, Body, MessageCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsVoicemailMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ISmsWapMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsWapMessage", "Timestamp", 6usize, |this| ((**(this as *const *const ISmsWapMessage_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn To(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsWapMessage", "To", 7usize, |this| ((**(this as *const *const ISmsWapMessage_Vtbl)).To)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn From(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsWapMessage", "From", 8usize, |this| ((**(this as *const *const ISmsWapMessage_Vtbl)).From)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsWapMessage", "ApplicationId", 9usize, |this| ((**(this as *const *const ISmsWapMessage_Vtbl)).ApplicationId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsWapMessage", "ContentType", 10usize, |this| ((**(this as *const *const ISmsWapMessage_Vtbl)).ContentType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn BinaryBody(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsWapMessage", "BinaryBody", 11usize, |this| ((**(this as *const *const ISmsWapMessage_Vtbl)).BinaryBody)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Headers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmsWapMessage", "Headers", 12usize, |this| ((**(this as *const *const ISmsWapMessage_Vtbl)).Headers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Timestamp,
+            To,
+            From,
+            ApplicationId,
+            ContentType,
+            #[cfg(feature = "Storage_Streams")]
+            BinaryBody,
+            #[cfg(not(feature = "Storage_Streams"))]
+            BinaryBody: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Headers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Headers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmsWapMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -236,6 +236,7 @@ impl ProviderSpiMode {
 impl windows_core::TypeKind for ProviderSpiMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProviderSpiMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProviderSpiMode").field(&self.0).finish()
@@ -254,6 +255,7 @@ impl ProviderSpiSharingMode {
 impl windows_core::TypeKind for ProviderSpiSharingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProviderSpiSharingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProviderSpiSharingMode").field(&self.0).finish()
@@ -264,3 +266,5 @@ impl windows_core::RuntimeType for ProviderSpiSharingMode {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
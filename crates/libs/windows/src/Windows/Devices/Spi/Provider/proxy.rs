@@ -0,0 +1,130 @@
+impl windows_core::imp::ProxyVtable for IProviderSpiConnectionSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChipSelectLine(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderSpiConnectionSettings", "ChipSelectLine", 6usize, |this| ((**(this as *const *const IProviderSpiConnectionSettings_Vtbl)).ChipSelectLine)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChipSelectLine(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderSpiConnectionSettings", "SetChipSelectLine", 7usize, |this| ((**(this as *const *const IProviderSpiConnectionSettings_Vtbl)).SetChipSelectLine)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut ProviderSpiMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderSpiConnectionSettings", "Mode", 8usize, |this| ((**(this as *const *const IProviderSpiConnectionSettings_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, value: ProviderSpiMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderSpiConnectionSettings", "SetMode", 9usize, |this| ((**(this as *const *const IProviderSpiConnectionSettings_Vtbl)).SetMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataBitLength(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderSpiConnectionSettings", "DataBitLength", 10usize, |this| ((**(this as *const *const IProviderSpiConnectionSettings_Vtbl)).DataBitLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataBitLength(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderSpiConnectionSettings", "SetDataBitLength", 11usize, |this| ((**(this as *const *const IProviderSpiConnectionSettings_Vtbl)).SetDataBitLength)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClockFrequency(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderSpiConnectionSettings", "ClockFrequency", 12usize, |this| ((**(this as *const *const IProviderSpiConnectionSettings_Vtbl)).ClockFrequency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClockFrequency(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderSpiConnectionSettings", "SetClockFrequency", 13usize, |this| ((**(this as *const *const IProviderSpiConnectionSettings_Vtbl)).SetClockFrequency)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SharingMode(this: *mut core::ffi::c_void, result__: *mut ProviderSpiSharingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderSpiConnectionSettings", "SharingMode", 14usize, |this| ((**(this as *const *const IProviderSpiConnectionSettings_Vtbl)).SharingMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSharingMode(this: *mut core::ffi::c_void, value: ProviderSpiSharingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderSpiConnectionSettings", "SetSharingMode", 15usize, |this| ((**(this as *const *const IProviderSpiConnectionSettings_Vtbl)).SetSharingMode)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChipSelectLine,
+            SetChipSelectLine,
+            Mode,
+            SetMode,
+            DataBitLength,
+            SetDataBitLength,
+            ClockFrequency,
+            SetClockFrequency,
+            SharingMode,
+            SetSharingMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProviderSpiConnectionSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProviderSpiConnectionSettingsFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, chipselectline: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderSpiConnectionSettingsFactory", "Create", 6usize, |this| ((**(this as *const *const IProviderSpiConnectionSettingsFactory_Vtbl)).Create)(this, chipselectline, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProviderSpiConnectionSettingsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpiControllerProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceProvider(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiControllerProvider", "GetDeviceProvider", 6usize, |this| ((**(this as *const *const ISpiControllerProvider_Vtbl)).GetDeviceProvider)(this, settings, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpiControllerProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpiDeviceProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDeviceProvider", "DeviceId", 6usize, |this| ((**(this as *const *const ISpiDeviceProvider_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDeviceProvider", "ConnectionSettings", 7usize, |this| ((**(this as *const *const ISpiDeviceProvider_Vtbl)).ConnectionSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, buffer_array_size: u32, buffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDeviceProvider", "Write", 8usize, |this| ((**(this as *const *const ISpiDeviceProvider_Vtbl)).Write)(this, buffer_array_size, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, buffer_array_size: u32, buffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDeviceProvider", "Read", 9usize, |this| ((**(this as *const *const ISpiDeviceProvider_Vtbl)).Read)(this, buffer_array_size, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferSequential(this: *mut core::ffi::c_void, writeBuffer_array_size: u32, writebuffer: *const u8, readBuffer_array_size: u32, readbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDeviceProvider", "TransferSequential", 10usize, |this| ((**(this as *const *const ISpiDeviceProvider_Vtbl)).TransferSequential)(this, writeBuffer_array_size, writebuffer, readBuffer_array_size, readbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferFullDuplex(this: *mut core::ffi::c_void, writeBuffer_array_size: u32, writebuffer: *const u8, readBuffer_array_size: u32, readbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDeviceProvider", "TransferFullDuplex", 11usize, |this| ((**(this as *const *const ISpiDeviceProvider_Vtbl)).TransferFullDuplex)(this, writeBuffer_array_size, writebuffer, readBuffer_array_size, readbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            ConnectionSettings,
+            Write,
+            Read,
+            TransferSequential,
+            TransferFullDuplex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpiDeviceProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISpiProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetControllersAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiProvider", "GetControllersAsync", 6usize, |this| ((**(this as *const *const ISpiProvider_Vtbl)).GetControllersAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetControllersAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetControllersAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpiProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
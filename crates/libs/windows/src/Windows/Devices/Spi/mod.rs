@@ -407,6 +407,7 @@ impl SpiMode {
 impl windows_core::TypeKind for SpiMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpiMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpiMode").field(&self.0).finish()
@@ -425,6 +426,7 @@ impl SpiSharingMode {
 impl windows_core::TypeKind for SpiSharingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpiSharingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpiSharingMode").field(&self.0).finish()
@@ -435,3 +437,5 @@ impl windows_core::RuntimeType for SpiSharingMode {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,193 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISpiBusInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChipSelectLineCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiBusInfo", "ChipSelectLineCount", 6usize, |this| ((**(this as *const *const ISpiBusInfo_Vtbl)).ChipSelectLineCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinClockFrequency(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiBusInfo", "MinClockFrequency", 7usize, |this| ((**(this as *const *const ISpiBusInfo_Vtbl)).MinClockFrequency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxClockFrequency(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiBusInfo", "MaxClockFrequency", 8usize, |this| ((**(this as *const *const ISpiBusInfo_Vtbl)).MaxClockFrequency)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedDataBitLengths(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiBusInfo", "SupportedDataBitLengths", 9usize, |this| ((**(this as *const *const ISpiBusInfo_Vtbl)).SupportedDataBitLengths)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChipSelectLineCount,
+            MinClockFrequency,
+            MaxClockFrequency,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedDataBitLengths,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedDataBitLengths: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpiBusInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpiConnectionSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChipSelectLine(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiConnectionSettings", "ChipSelectLine", 6usize, |this| ((**(this as *const *const ISpiConnectionSettings_Vtbl)).ChipSelectLine)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChipSelectLine(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiConnectionSettings", "SetChipSelectLine", 7usize, |this| ((**(this as *const *const ISpiConnectionSettings_Vtbl)).SetChipSelectLine)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut SpiMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiConnectionSettings", "Mode", 8usize, |this| ((**(this as *const *const ISpiConnectionSettings_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, value: SpiMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiConnectionSettings", "SetMode", 9usize, |this| ((**(this as *const *const ISpiConnectionSettings_Vtbl)).SetMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataBitLength(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiConnectionSettings", "DataBitLength", 10usize, |this| ((**(this as *const *const ISpiConnectionSettings_Vtbl)).DataBitLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataBitLength(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiConnectionSettings", "SetDataBitLength", 11usize, |this| ((**(this as *const *const ISpiConnectionSettings_Vtbl)).SetDataBitLength)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClockFrequency(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiConnectionSettings", "ClockFrequency", 12usize, |this| ((**(this as *const *const ISpiConnectionSettings_Vtbl)).ClockFrequency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClockFrequency(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiConnectionSettings", "SetClockFrequency", 13usize, |this| ((**(this as *const *const ISpiConnectionSettings_Vtbl)).SetClockFrequency)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SharingMode(this: *mut core::ffi::c_void, result__: *mut SpiSharingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiConnectionSettings", "SharingMode", 14usize, |this| ((**(this as *const *const ISpiConnectionSettings_Vtbl)).SharingMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSharingMode(this: *mut core::ffi::c_void, value: SpiSharingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiConnectionSettings", "SetSharingMode", 15usize, |this| ((**(this as *const *const ISpiConnectionSettings_Vtbl)).SetSharingMode)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChipSelectLine,
+            SetChipSelectLine,
+            Mode,
+            SetMode,
+            DataBitLength,
+            SetDataBitLength,
+            ClockFrequency,
+            SetClockFrequency,
+            SharingMode,
+            SetSharingMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpiConnectionSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpiConnectionSettingsFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, chipselectline: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiConnectionSettingsFactory", "Create", 6usize, |this| ((**(this as *const *const ISpiConnectionSettingsFactory_Vtbl)).Create)(this, chipselectline, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpiConnectionSettingsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpiController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiController", "GetDevice", 6usize, |this| ((**(this as *const *const ISpiController_Vtbl)).GetDevice)(this, settings, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpiController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Spi_Provider", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for ISpiControllerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiControllerStatics", "GetDefaultAsync", 6usize, |this| ((**(this as *const *const ISpiControllerStatics_Vtbl)).GetDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Spi_Provider", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetControllersAsync(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiControllerStatics", "GetControllersAsync", 7usize, |this| ((**(this as *const *const ISpiControllerStatics_Vtbl)).GetControllersAsync)(this, provider, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDefaultAsync,
+            #[cfg(all(feature = "Devices_Spi_Provider", feature = "Foundation_Collections"))]
+            GetControllersAsync,
+            #[cfg(not(all(feature = "Devices_Spi_Provider", feature = "Foundation_Collections")))]
+            GetControllersAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpiControllerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpiDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDevice", "DeviceId", 6usize, |this| ((**(this as *const *const ISpiDevice_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDevice", "ConnectionSettings", 7usize, |this| ((**(this as *const *const ISpiDevice_Vtbl)).ConnectionSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, buffer_array_size: u32, buffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDevice", "Write", 8usize, |this| ((**(this as *const *const ISpiDevice_Vtbl)).Write)(this, buffer_array_size, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, buffer_array_size: u32, buffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDevice", "Read", 9usize, |this| ((**(this as *const *const ISpiDevice_Vtbl)).Read)(this, buffer_array_size, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferSequential(this: *mut core::ffi::c_void, writeBuffer_array_size: u32, writebuffer: *const u8, readBuffer_array_size: u32, readbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDevice", "TransferSequential", 10usize, |this| ((**(this as *const *const ISpiDevice_Vtbl)).TransferSequential)(this, writeBuffer_array_size, writebuffer, readBuffer_array_size, readbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferFullDuplex(this: *mut core::ffi::c_void, writeBuffer_array_size: u32, writebuffer: *const u8, readBuffer_array_size: u32, readbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDevice", "TransferFullDuplex", 11usize, |this| ((**(this as *const *const ISpiDevice_Vtbl)).TransferFullDuplex)(this, writeBuffer_array_size, writebuffer, readBuffer_array_size, readbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            ConnectionSettings,
+            Write,
+            Read,
+            TransferSequential,
+            TransferFullDuplex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpiDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpiDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDeviceStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const ISpiDeviceStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromFriendlyName(this: *mut core::ffi::c_void, friendlyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDeviceStatics", "GetDeviceSelectorFromFriendlyName", 7usize, |this| ((**(this as *const *const ISpiDeviceStatics_Vtbl)).GetDeviceSelectorFromFriendlyName)(this, friendlyname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBusInfo(this: *mut core::ffi::c_void, busid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDeviceStatics", "GetBusInfo", 8usize, |this| ((**(this as *const *const ISpiDeviceStatics_Vtbl)).GetBusInfo)(this, busid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, busid: core::mem::MaybeUninit<windows_core::HSTRING>, settings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpiDeviceStatics", "FromIdAsync", 9usize, |this| ((**(this as *const *const ISpiDeviceStatics_Vtbl)).FromIdAsync)(this, busid, settings, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceSelector,
+            GetDeviceSelectorFromFriendlyName,
+            GetBusInfo,
+            FromIdAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpiDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1801,6 +1801,7 @@ impl UsbControlRecipient {
 impl windows_core::TypeKind for UsbControlRecipient {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UsbControlRecipient {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UsbControlRecipient").field(&self.0).finish()
@@ -1820,6 +1821,7 @@ impl UsbControlTransferType {
 impl windows_core::TypeKind for UsbControlTransferType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UsbControlTransferType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UsbControlTransferType").field(&self.0).finish()
@@ -1840,6 +1842,7 @@ impl UsbEndpointType {
 impl windows_core::TypeKind for UsbEndpointType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UsbEndpointType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UsbEndpointType").field(&self.0).finish()
@@ -1861,6 +1864,7 @@ impl UsbReadOptions {
 impl windows_core::TypeKind for UsbReadOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UsbReadOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UsbReadOptions").field(&self.0).finish()
@@ -1912,6 +1916,7 @@ impl UsbTransferDirection {
 impl windows_core::TypeKind for UsbTransferDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UsbTransferDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UsbTransferDirection").field(&self.0).finish()
@@ -1931,6 +1936,7 @@ impl UsbWriteOptions {
 impl windows_core::TypeKind for UsbWriteOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UsbWriteOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UsbWriteOptions").field(&self.0).finish()
@@ -1972,3 +1978,5 @@ impl core::ops::Not for UsbWriteOptions {
 impl windows_core::RuntimeType for UsbWriteOptions {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.Usb.UsbWriteOptions;u4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,892 @@
+impl windows_core::imp::ProxyVtable for IUsbBulkInEndpointDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxPacketSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkInEndpointDescriptor", "MaxPacketSize", 6usize, |this| ((**(this as *const *const IUsbBulkInEndpointDescriptor_Vtbl)).MaxPacketSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndpointNumber(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkInEndpointDescriptor", "EndpointNumber", 7usize, |this| ((**(this as *const *const IUsbBulkInEndpointDescriptor_Vtbl)).EndpointNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pipe(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkInEndpointDescriptor", "Pipe", 8usize, |this| ((**(this as *const *const IUsbBulkInEndpointDescriptor_Vtbl)).Pipe)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MaxPacketSize, EndpointNumber, Pipe }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbBulkInEndpointDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IUsbBulkInPipe_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxTransferSizeBytes(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkInPipe", "MaxTransferSizeBytes", 6usize, |this| ((**(this as *const *const IUsbBulkInPipe_Vtbl)).MaxTransferSizeBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndpointDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkInPipe", "EndpointDescriptor", 7usize, |this| ((**(this as *const *const IUsbBulkInPipe_Vtbl)).EndpointDescriptor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearStallAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkInPipe", "ClearStallAsync", 8usize, |this| ((**(this as *const *const IUsbBulkInPipe_Vtbl)).ClearStallAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReadOptions(this: *mut core::ffi::c_void, value: UsbReadOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkInPipe", "SetReadOptions", 9usize, |this| ((**(this as *const *const IUsbBulkInPipe_Vtbl)).SetReadOptions)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadOptions(this: *mut core::ffi::c_void, result__: *mut UsbReadOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkInPipe", "ReadOptions", 10usize, |this| ((**(this as *const *const IUsbBulkInPipe_Vtbl)).ReadOptions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushBuffer(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkInPipe", "FlushBuffer", 11usize, |this| ((**(this as *const *const IUsbBulkInPipe_Vtbl)).FlushBuffer)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn InputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkInPipe", "InputStream", 12usize, |this| ((**(this as *const *const IUsbBulkInPipe_Vtbl)).InputStream)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaxTransferSizeBytes,
+            EndpointDescriptor,
+            ClearStallAsync,
+            SetReadOptions,
+            ReadOptions,
+            FlushBuffer,
+            #[cfg(feature = "Storage_Streams")]
+            InputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            InputStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbBulkInPipe as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbBulkOutEndpointDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxPacketSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkOutEndpointDescriptor", "MaxPacketSize", 6usize, |this| ((**(this as *const *const IUsbBulkOutEndpointDescriptor_Vtbl)).MaxPacketSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndpointNumber(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkOutEndpointDescriptor", "EndpointNumber", 7usize, |this| ((**(this as *const *const IUsbBulkOutEndpointDescriptor_Vtbl)).EndpointNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pipe(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkOutEndpointDescriptor", "Pipe", 8usize, |this| ((**(this as *const *const IUsbBulkOutEndpointDescriptor_Vtbl)).Pipe)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MaxPacketSize, EndpointNumber, Pipe }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbBulkOutEndpointDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IUsbBulkOutPipe_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EndpointDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkOutPipe", "EndpointDescriptor", 6usize, |this| ((**(this as *const *const IUsbBulkOutPipe_Vtbl)).EndpointDescriptor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearStallAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkOutPipe", "ClearStallAsync", 7usize, |this| ((**(this as *const *const IUsbBulkOutPipe_Vtbl)).ClearStallAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWriteOptions(this: *mut core::ffi::c_void, value: UsbWriteOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkOutPipe", "SetWriteOptions", 8usize, |this| ((**(this as *const *const IUsbBulkOutPipe_Vtbl)).SetWriteOptions)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteOptions(this: *mut core::ffi::c_void, result__: *mut UsbWriteOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkOutPipe", "WriteOptions", 9usize, |this| ((**(this as *const *const IUsbBulkOutPipe_Vtbl)).WriteOptions)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn OutputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbBulkOutPipe", "OutputStream", 10usize, |this| ((**(this as *const *const IUsbBulkOutPipe_Vtbl)).OutputStream)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EndpointDescriptor,
+            ClearStallAsync,
+            SetWriteOptions,
+            WriteOptions,
+            #[cfg(feature = "Storage_Streams")]
+            OutputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            OutputStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbBulkOutPipe as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUsbConfiguration_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UsbInterfaces(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbConfiguration", "UsbInterfaces", 6usize, |this| ((**(this as *const *const IUsbConfiguration_Vtbl)).UsbInterfaces)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConfigurationDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbConfiguration", "ConfigurationDescriptor", 7usize, |this| ((**(this as *const *const IUsbConfiguration_Vtbl)).ConfigurationDescriptor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Descriptors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbConfiguration", "Descriptors", 8usize, |this| ((**(this as *const *const IUsbConfiguration_Vtbl)).Descriptors)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            UsbInterfaces,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UsbInterfaces: 0,
+            ConfigurationDescriptor,
+            #[cfg(feature = "Foundation_Collections")]
+            Descriptors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Descriptors: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbConfigurationDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConfigurationValue(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbConfigurationDescriptor", "ConfigurationValue", 6usize, |this| ((**(this as *const *const IUsbConfigurationDescriptor_Vtbl)).ConfigurationValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPowerMilliamps(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbConfigurationDescriptor", "MaxPowerMilliamps", 7usize, |this| ((**(this as *const *const IUsbConfigurationDescriptor_Vtbl)).MaxPowerMilliamps)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelfPowered(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbConfigurationDescriptor", "SelfPowered", 8usize, |this| ((**(this as *const *const IUsbConfigurationDescriptor_Vtbl)).SelfPowered)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteWakeup(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbConfigurationDescriptor", "RemoteWakeup", 9usize, |this| ((**(this as *const *const IUsbConfigurationDescriptor_Vtbl)).RemoteWakeup)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConfigurationValue,
+            MaxPowerMilliamps,
+            SelfPowered,
+            RemoteWakeup,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbConfigurationDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbConfigurationDescriptorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, descriptor: *mut core::ffi::c_void, parsed: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbConfigurationDescriptorStatics", "TryParse", 6usize, |this| ((**(this as *const *const IUsbConfigurationDescriptorStatics_Vtbl)).TryParse)(this, descriptor, parsed, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, descriptor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbConfigurationDescriptorStatics", "Parse", 7usize, |this| ((**(this as *const *const IUsbConfigurationDescriptorStatics_Vtbl)).Parse)(this, descriptor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryParse, Parse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbConfigurationDescriptorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbControlRequestType_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Direction(this: *mut core::ffi::c_void, result__: *mut UsbTransferDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbControlRequestType", "Direction", 6usize, |this| ((**(this as *const *const IUsbControlRequestType_Vtbl)).Direction)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDirection(this: *mut core::ffi::c_void, value: UsbTransferDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbControlRequestType", "SetDirection", 7usize, |this| ((**(this as *const *const IUsbControlRequestType_Vtbl)).SetDirection)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControlTransferType(this: *mut core::ffi::c_void, result__: *mut UsbControlTransferType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbControlRequestType", "ControlTransferType", 8usize, |this| ((**(this as *const *const IUsbControlRequestType_Vtbl)).ControlTransferType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetControlTransferType(this: *mut core::ffi::c_void, value: UsbControlTransferType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbControlRequestType", "SetControlTransferType", 9usize, |this| ((**(this as *const *const IUsbControlRequestType_Vtbl)).SetControlTransferType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Recipient(this: *mut core::ffi::c_void, result__: *mut UsbControlRecipient) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbControlRequestType", "Recipient", 10usize, |this| ((**(this as *const *const IUsbControlRequestType_Vtbl)).Recipient)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecipient(this: *mut core::ffi::c_void, value: UsbControlRecipient) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbControlRequestType", "SetRecipient", 11usize, |this| ((**(this as *const *const IUsbControlRequestType_Vtbl)).SetRecipient)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsByte(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbControlRequestType", "AsByte", 12usize, |this| ((**(this as *const *const IUsbControlRequestType_Vtbl)).AsByte)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAsByte(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbControlRequestType", "SetAsByte", 13usize, |this| ((**(this as *const *const IUsbControlRequestType_Vtbl)).SetAsByte)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Direction,
+            SetDirection,
+            ControlTransferType,
+            SetControlTransferType,
+            Recipient,
+            SetRecipient,
+            AsByte,
+            SetAsByte,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbControlRequestType as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IUsbDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDescriptor", "Length", 6usize, |this| ((**(this as *const *const IUsbDescriptor_Vtbl)).Length)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DescriptorType(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDescriptor", "DescriptorType", 7usize, |this| ((**(this as *const *const IUsbDescriptor_Vtbl)).DescriptorType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ReadDescriptorBuffer(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDescriptor", "ReadDescriptorBuffer", 8usize, |this| ((**(this as *const *const IUsbDescriptor_Vtbl)).ReadDescriptorBuffer)(this, buffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Length,
+            DescriptorType,
+            #[cfg(feature = "Storage_Streams")]
+            ReadDescriptorBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ReadDescriptorBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IUsbDevice_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SendControlOutTransferAsync(this: *mut core::ffi::c_void, setuppacket: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDevice", "SendControlOutTransferAsync", 6usize, |this| ((**(this as *const *const IUsbDevice_Vtbl)).SendControlOutTransferAsync)(this, setuppacket, buffer, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendControlOutTransferAsyncNoBuffer(this: *mut core::ffi::c_void, setuppacket: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDevice", "SendControlOutTransferAsyncNoBuffer", 7usize, |this| ((**(this as *const *const IUsbDevice_Vtbl)).SendControlOutTransferAsyncNoBuffer)(this, setuppacket, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SendControlInTransferAsync(this: *mut core::ffi::c_void, setuppacket: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDevice", "SendControlInTransferAsync", 8usize, |this| ((**(this as *const *const IUsbDevice_Vtbl)).SendControlInTransferAsync)(this, setuppacket, buffer, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SendControlInTransferAsyncNoBuffer(this: *mut core::ffi::c_void, setuppacket: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDevice", "SendControlInTransferAsyncNoBuffer", 9usize, |this| ((**(this as *const *const IUsbDevice_Vtbl)).SendControlInTransferAsyncNoBuffer)(this, setuppacket, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultInterface(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDevice", "DefaultInterface", 10usize, |this| ((**(this as *const *const IUsbDevice_Vtbl)).DefaultInterface)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDevice", "DeviceDescriptor", 11usize, |this| ((**(this as *const *const IUsbDevice_Vtbl)).DeviceDescriptor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDevice", "Configuration", 12usize, |this| ((**(this as *const *const IUsbDevice_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            SendControlOutTransferAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SendControlOutTransferAsync: 0,
+            SendControlOutTransferAsyncNoBuffer,
+            #[cfg(feature = "Storage_Streams")]
+            SendControlInTransferAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SendControlInTransferAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SendControlInTransferAsyncNoBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SendControlInTransferAsyncNoBuffer: 0,
+            DefaultInterface,
+            DeviceDescriptor,
+            Configuration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbDeviceClass_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClassCode(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceClass", "ClassCode", 6usize, |this| ((**(this as *const *const IUsbDeviceClass_Vtbl)).ClassCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClassCode(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceClass", "SetClassCode", 7usize, |this| ((**(this as *const *const IUsbDeviceClass_Vtbl)).SetClassCode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubclassCode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceClass", "SubclassCode", 8usize, |this| ((**(this as *const *const IUsbDeviceClass_Vtbl)).SubclassCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubclassCode(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceClass", "SetSubclassCode", 9usize, |this| ((**(this as *const *const IUsbDeviceClass_Vtbl)).SetSubclassCode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtocolCode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceClass", "ProtocolCode", 10usize, |this| ((**(this as *const *const IUsbDeviceClass_Vtbl)).ProtocolCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProtocolCode(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceClass", "SetProtocolCode", 11usize, |this| ((**(this as *const *const IUsbDeviceClass_Vtbl)).SetProtocolCode)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ClassCode,
+            SetClassCode,
+            SubclassCode,
+            SetSubclassCode,
+            ProtocolCode,
+            SetProtocolCode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbDeviceClass as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbDeviceClasses_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbDeviceClasses as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbDeviceClassesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CdcControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceClassesStatics", "CdcControl", 6usize, |this| ((**(this as *const *const IUsbDeviceClassesStatics_Vtbl)).CdcControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Physical(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceClassesStatics", "Physical", 7usize, |this| ((**(this as *const *const IUsbDeviceClassesStatics_Vtbl)).Physical)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PersonalHealthcare(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceClassesStatics", "PersonalHealthcare", 8usize, |this| ((**(this as *const *const IUsbDeviceClassesStatics_Vtbl)).PersonalHealthcare)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActiveSync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceClassesStatics", "ActiveSync", 9usize, |this| ((**(this as *const *const IUsbDeviceClassesStatics_Vtbl)).ActiveSync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PalmSync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceClassesStatics", "PalmSync", 10usize, |this| ((**(this as *const *const IUsbDeviceClassesStatics_Vtbl)).PalmSync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceFirmwareUpdate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceClassesStatics", "DeviceFirmwareUpdate", 11usize, |this| ((**(this as *const *const IUsbDeviceClassesStatics_Vtbl)).DeviceFirmwareUpdate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Irda(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceClassesStatics", "Irda", 12usize, |this| ((**(this as *const *const IUsbDeviceClassesStatics_Vtbl)).Irda)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Measurement(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceClassesStatics", "Measurement", 13usize, |this| ((**(this as *const *const IUsbDeviceClassesStatics_Vtbl)).Measurement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VendorSpecific(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceClassesStatics", "VendorSpecific", 14usize, |this| ((**(this as *const *const IUsbDeviceClassesStatics_Vtbl)).VendorSpecific)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CdcControl,
+            Physical,
+            PersonalHealthcare,
+            ActiveSync,
+            PalmSync,
+            DeviceFirmwareUpdate,
+            Irda,
+            Measurement,
+            VendorSpecific,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbDeviceClassesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbDeviceDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BcdUsb(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceDescriptor", "BcdUsb", 6usize, |this| ((**(this as *const *const IUsbDeviceDescriptor_Vtbl)).BcdUsb)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPacketSize0(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceDescriptor", "MaxPacketSize0", 7usize, |this| ((**(this as *const *const IUsbDeviceDescriptor_Vtbl)).MaxPacketSize0)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VendorId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceDescriptor", "VendorId", 8usize, |this| ((**(this as *const *const IUsbDeviceDescriptor_Vtbl)).VendorId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProductId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceDescriptor", "ProductId", 9usize, |this| ((**(this as *const *const IUsbDeviceDescriptor_Vtbl)).ProductId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BcdDeviceRevision(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceDescriptor", "BcdDeviceRevision", 10usize, |this| ((**(this as *const *const IUsbDeviceDescriptor_Vtbl)).BcdDeviceRevision)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfConfigurations(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceDescriptor", "NumberOfConfigurations", 11usize, |this| ((**(this as *const *const IUsbDeviceDescriptor_Vtbl)).NumberOfConfigurations)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BcdUsb,
+            MaxPacketSize0,
+            VendorId,
+            ProductId,
+            BcdDeviceRevision,
+            NumberOfConfigurations,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbDeviceDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, vendorid: u32, productid: u32, winusbinterfaceclass: windows_core::GUID, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IUsbDeviceStatics_Vtbl)).GetDeviceSelector)(this, vendorid, productid, winusbinterfaceclass, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorGuidOnly(this: *mut core::ffi::c_void, winusbinterfaceclass: windows_core::GUID, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceStatics", "GetDeviceSelectorGuidOnly", 7usize, |this| ((**(this as *const *const IUsbDeviceStatics_Vtbl)).GetDeviceSelectorGuidOnly)(this, winusbinterfaceclass, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorVidPidOnly(this: *mut core::ffi::c_void, vendorid: u32, productid: u32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceStatics", "GetDeviceSelectorVidPidOnly", 8usize, |this| ((**(this as *const *const IUsbDeviceStatics_Vtbl)).GetDeviceSelectorVidPidOnly)(this, vendorid, productid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceClassSelector(this: *mut core::ffi::c_void, usbclass: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceStatics", "GetDeviceClassSelector", 9usize, |this| ((**(this as *const *const IUsbDeviceStatics_Vtbl)).GetDeviceClassSelector)(this, usbclass, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbDeviceStatics", "FromIdAsync", 10usize, |this| ((**(this as *const *const IUsbDeviceStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceSelector,
+            GetDeviceSelectorGuidOnly,
+            GetDeviceSelectorVidPidOnly,
+            GetDeviceClassSelector,
+            FromIdAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbEndpointDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EndpointNumber(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbEndpointDescriptor", "EndpointNumber", 6usize, |this| ((**(this as *const *const IUsbEndpointDescriptor_Vtbl)).EndpointNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Direction(this: *mut core::ffi::c_void, result__: *mut UsbTransferDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbEndpointDescriptor", "Direction", 7usize, |this| ((**(this as *const *const IUsbEndpointDescriptor_Vtbl)).Direction)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndpointType(this: *mut core::ffi::c_void, result__: *mut UsbEndpointType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbEndpointDescriptor", "EndpointType", 8usize, |this| ((**(this as *const *const IUsbEndpointDescriptor_Vtbl)).EndpointType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsBulkInEndpointDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbEndpointDescriptor", "AsBulkInEndpointDescriptor", 9usize, |this| ((**(this as *const *const IUsbEndpointDescriptor_Vtbl)).AsBulkInEndpointDescriptor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsInterruptInEndpointDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbEndpointDescriptor", "AsInterruptInEndpointDescriptor", 10usize, |this| ((**(this as *const *const IUsbEndpointDescriptor_Vtbl)).AsInterruptInEndpointDescriptor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsBulkOutEndpointDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbEndpointDescriptor", "AsBulkOutEndpointDescriptor", 11usize, |this| ((**(this as *const *const IUsbEndpointDescriptor_Vtbl)).AsBulkOutEndpointDescriptor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsInterruptOutEndpointDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbEndpointDescriptor", "AsInterruptOutEndpointDescriptor", 12usize, |this| ((**(this as *const *const IUsbEndpointDescriptor_Vtbl)).AsInterruptOutEndpointDescriptor)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EndpointNumber,
+            Direction,
+            EndpointType,
+            AsBulkInEndpointDescriptor,
+            AsInterruptInEndpointDescriptor,
+            AsBulkOutEndpointDescriptor,
+            AsInterruptOutEndpointDescriptor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbEndpointDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbEndpointDescriptorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, descriptor: *mut core::ffi::c_void, parsed: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbEndpointDescriptorStatics", "TryParse", 6usize, |this| ((**(this as *const *const IUsbEndpointDescriptorStatics_Vtbl)).TryParse)(this, descriptor, parsed, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, descriptor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbEndpointDescriptorStatics", "Parse", 7usize, |this| ((**(this as *const *const IUsbEndpointDescriptorStatics_Vtbl)).Parse)(this, descriptor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryParse, Parse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbEndpointDescriptorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUsbInterface_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BulkInPipes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterface", "BulkInPipes", 6usize, |this| ((**(this as *const *const IUsbInterface_Vtbl)).BulkInPipes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn InterruptInPipes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterface", "InterruptInPipes", 7usize, |this| ((**(this as *const *const IUsbInterface_Vtbl)).InterruptInPipes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BulkOutPipes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterface", "BulkOutPipes", 8usize, |this| ((**(this as *const *const IUsbInterface_Vtbl)).BulkOutPipes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn InterruptOutPipes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterface", "InterruptOutPipes", 9usize, |this| ((**(this as *const *const IUsbInterface_Vtbl)).InterruptOutPipes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn InterfaceSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterface", "InterfaceSettings", 10usize, |this| ((**(this as *const *const IUsbInterface_Vtbl)).InterfaceSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InterfaceNumber(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterface", "InterfaceNumber", 11usize, |this| ((**(this as *const *const IUsbInterface_Vtbl)).InterfaceNumber)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Descriptors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterface", "Descriptors", 12usize, |this| ((**(this as *const *const IUsbInterface_Vtbl)).Descriptors)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            BulkInPipes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BulkInPipes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            InterruptInPipes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            InterruptInPipes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            BulkOutPipes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BulkOutPipes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            InterruptOutPipes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            InterruptOutPipes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            InterfaceSettings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            InterfaceSettings: 0,
+            InterfaceNumber,
+            #[cfg(feature = "Foundation_Collections")]
+            Descriptors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Descriptors: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbInterface as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbInterfaceDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClassCode(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterfaceDescriptor", "ClassCode", 6usize, |this| ((**(this as *const *const IUsbInterfaceDescriptor_Vtbl)).ClassCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubclassCode(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterfaceDescriptor", "SubclassCode", 7usize, |this| ((**(this as *const *const IUsbInterfaceDescriptor_Vtbl)).SubclassCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtocolCode(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterfaceDescriptor", "ProtocolCode", 8usize, |this| ((**(this as *const *const IUsbInterfaceDescriptor_Vtbl)).ProtocolCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlternateSettingNumber(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterfaceDescriptor", "AlternateSettingNumber", 9usize, |this| ((**(this as *const *const IUsbInterfaceDescriptor_Vtbl)).AlternateSettingNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InterfaceNumber(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterfaceDescriptor", "InterfaceNumber", 10usize, |this| ((**(this as *const *const IUsbInterfaceDescriptor_Vtbl)).InterfaceNumber)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ClassCode,
+            SubclassCode,
+            ProtocolCode,
+            AlternateSettingNumber,
+            InterfaceNumber,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbInterfaceDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbInterfaceDescriptorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, descriptor: *mut core::ffi::c_void, parsed: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterfaceDescriptorStatics", "TryParse", 6usize, |this| ((**(this as *const *const IUsbInterfaceDescriptorStatics_Vtbl)).TryParse)(this, descriptor, parsed, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, descriptor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterfaceDescriptorStatics", "Parse", 7usize, |this| ((**(this as *const *const IUsbInterfaceDescriptorStatics_Vtbl)).Parse)(this, descriptor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryParse, Parse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbInterfaceDescriptorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUsbInterfaceSetting_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BulkInEndpoints(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterfaceSetting", "BulkInEndpoints", 6usize, |this| ((**(this as *const *const IUsbInterfaceSetting_Vtbl)).BulkInEndpoints)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn InterruptInEndpoints(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterfaceSetting", "InterruptInEndpoints", 7usize, |this| ((**(this as *const *const IUsbInterfaceSetting_Vtbl)).InterruptInEndpoints)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BulkOutEndpoints(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterfaceSetting", "BulkOutEndpoints", 8usize, |this| ((**(this as *const *const IUsbInterfaceSetting_Vtbl)).BulkOutEndpoints)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn InterruptOutEndpoints(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterfaceSetting", "InterruptOutEndpoints", 9usize, |this| ((**(this as *const *const IUsbInterfaceSetting_Vtbl)).InterruptOutEndpoints)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Selected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterfaceSetting", "Selected", 10usize, |this| ((**(this as *const *const IUsbInterfaceSetting_Vtbl)).Selected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectSettingAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterfaceSetting", "SelectSettingAsync", 11usize, |this| ((**(this as *const *const IUsbInterfaceSetting_Vtbl)).SelectSettingAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InterfaceDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterfaceSetting", "InterfaceDescriptor", 12usize, |this| ((**(this as *const *const IUsbInterfaceSetting_Vtbl)).InterfaceDescriptor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Descriptors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterfaceSetting", "Descriptors", 13usize, |this| ((**(this as *const *const IUsbInterfaceSetting_Vtbl)).Descriptors)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            BulkInEndpoints,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BulkInEndpoints: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            InterruptInEndpoints,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            InterruptInEndpoints: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            BulkOutEndpoints,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BulkOutEndpoints: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            InterruptOutEndpoints,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            InterruptOutEndpoints: 0,
+            Selected,
+            SelectSettingAsync,
+            InterfaceDescriptor,
+            #[cfg(feature = "Foundation_Collections")]
+            Descriptors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Descriptors: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbInterfaceSetting as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbInterruptInEndpointDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxPacketSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptInEndpointDescriptor", "MaxPacketSize", 6usize, |this| ((**(this as *const *const IUsbInterruptInEndpointDescriptor_Vtbl)).MaxPacketSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndpointNumber(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptInEndpointDescriptor", "EndpointNumber", 7usize, |this| ((**(this as *const *const IUsbInterruptInEndpointDescriptor_Vtbl)).EndpointNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Interval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptInEndpointDescriptor", "Interval", 8usize, |this| ((**(this as *const *const IUsbInterruptInEndpointDescriptor_Vtbl)).Interval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pipe(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptInEndpointDescriptor", "Pipe", 9usize, |this| ((**(this as *const *const IUsbInterruptInEndpointDescriptor_Vtbl)).Pipe)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MaxPacketSize, EndpointNumber, Interval, Pipe }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbInterruptInEndpointDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IUsbInterruptInEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn InterruptData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptInEventArgs", "InterruptData", 6usize, |this| ((**(this as *const *const IUsbInterruptInEventArgs_Vtbl)).InterruptData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            InterruptData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            InterruptData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbInterruptInEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbInterruptInPipe_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EndpointDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptInPipe", "EndpointDescriptor", 6usize, |this| ((**(this as *const *const IUsbInterruptInPipe_Vtbl)).EndpointDescriptor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearStallAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptInPipe", "ClearStallAsync", 7usize, |this| ((**(this as *const *const IUsbInterruptInPipe_Vtbl)).ClearStallAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptInPipe", "DataReceived", 8usize, |this| ((**(this as *const *const IUsbInterruptInPipe_Vtbl)).DataReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDataReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptInPipe", "RemoveDataReceived", 9usize, |this| ((**(this as *const *const IUsbInterruptInPipe_Vtbl)).RemoveDataReceived)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EndpointDescriptor,
+            ClearStallAsync,
+            DataReceived,
+            RemoveDataReceived,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbInterruptInPipe as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbInterruptOutEndpointDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxPacketSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptOutEndpointDescriptor", "MaxPacketSize", 6usize, |this| ((**(this as *const *const IUsbInterruptOutEndpointDescriptor_Vtbl)).MaxPacketSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndpointNumber(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptOutEndpointDescriptor", "EndpointNumber", 7usize, |this| ((**(this as *const *const IUsbInterruptOutEndpointDescriptor_Vtbl)).EndpointNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Interval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptOutEndpointDescriptor", "Interval", 8usize, |this| ((**(this as *const *const IUsbInterruptOutEndpointDescriptor_Vtbl)).Interval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pipe(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptOutEndpointDescriptor", "Pipe", 9usize, |this| ((**(this as *const *const IUsbInterruptOutEndpointDescriptor_Vtbl)).Pipe)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MaxPacketSize, EndpointNumber, Interval, Pipe }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbInterruptOutEndpointDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IUsbInterruptOutPipe_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EndpointDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptOutPipe", "EndpointDescriptor", 6usize, |this| ((**(this as *const *const IUsbInterruptOutPipe_Vtbl)).EndpointDescriptor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearStallAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptOutPipe", "ClearStallAsync", 7usize, |this| ((**(this as *const *const IUsbInterruptOutPipe_Vtbl)).ClearStallAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWriteOptions(this: *mut core::ffi::c_void, value: UsbWriteOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptOutPipe", "SetWriteOptions", 8usize, |this| ((**(this as *const *const IUsbInterruptOutPipe_Vtbl)).SetWriteOptions)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteOptions(this: *mut core::ffi::c_void, result__: *mut UsbWriteOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptOutPipe", "WriteOptions", 9usize, |this| ((**(this as *const *const IUsbInterruptOutPipe_Vtbl)).WriteOptions)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn OutputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbInterruptOutPipe", "OutputStream", 10usize, |this| ((**(this as *const *const IUsbInterruptOutPipe_Vtbl)).OutputStream)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EndpointDescriptor,
+            ClearStallAsync,
+            SetWriteOptions,
+            WriteOptions,
+            #[cfg(feature = "Storage_Streams")]
+            OutputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            OutputStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbInterruptOutPipe as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUsbSetupPacket_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestType(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbSetupPacket", "RequestType", 6usize, |this| ((**(this as *const *const IUsbSetupPacket_Vtbl)).RequestType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestType(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbSetupPacket", "SetRequestType", 7usize, |this| ((**(this as *const *const IUsbSetupPacket_Vtbl)).SetRequestType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbSetupPacket", "Request", 8usize, |this| ((**(this as *const *const IUsbSetupPacket_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequest(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbSetupPacket", "SetRequest", 9usize, |this| ((**(this as *const *const IUsbSetupPacket_Vtbl)).SetRequest)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbSetupPacket", "Value", 10usize, |this| ((**(this as *const *const IUsbSetupPacket_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbSetupPacket", "SetValue", 11usize, |this| ((**(this as *const *const IUsbSetupPacket_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Index(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbSetupPacket", "Index", 12usize, |this| ((**(this as *const *const IUsbSetupPacket_Vtbl)).Index)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIndex(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbSetupPacket", "SetIndex", 13usize, |this| ((**(this as *const *const IUsbSetupPacket_Vtbl)).SetIndex)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbSetupPacket", "Length", 14usize, |this| ((**(this as *const *const IUsbSetupPacket_Vtbl)).Length)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLength(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbSetupPacket", "SetLength", 15usize, |this| ((**(this as *const *const IUsbSetupPacket_Vtbl)).SetLength)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestType,
+            SetRequestType,
+            Request,
+            SetRequest,
+            Value,
+            SetValue,
+            Index,
+            SetIndex,
+            Length,
+            SetLength,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbSetupPacket as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IUsbSetupPacketFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateWithEightByteBuffer(this: *mut core::ffi::c_void, eightbytebuffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUsbSetupPacketFactory", "CreateWithEightByteBuffer", 6usize, |this| ((**(this as *const *const IUsbSetupPacketFactory_Vtbl)).CreateWithEightByteBuffer)(this, eightbytebuffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateWithEightByteBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateWithEightByteBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUsbSetupPacketFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
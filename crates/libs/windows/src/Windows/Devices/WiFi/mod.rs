@@ -745,6 +745,7 @@ impl WiFiAccessStatus {
 impl windows_core::TypeKind for WiFiAccessStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiAccessStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiAccessStatus").field(&self.0).finish()
@@ -764,6 +765,7 @@ impl WiFiConnectionMethod {
 impl windows_core::TypeKind for WiFiConnectionMethod {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiConnectionMethod {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiConnectionMethod").field(&self.0).finish()
@@ -787,6 +789,7 @@ impl WiFiConnectionStatus {
 impl windows_core::TypeKind for WiFiConnectionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiConnectionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiConnectionStatus").field(&self.0).finish()
@@ -806,6 +809,7 @@ impl WiFiNetworkKind {
 impl windows_core::TypeKind for WiFiNetworkKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiNetworkKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiNetworkKind").field(&self.0).finish()
@@ -824,6 +828,7 @@ impl WiFiOnDemandHotspotAvailability {
 impl windows_core::TypeKind for WiFiOnDemandHotspotAvailability {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiOnDemandHotspotAvailability {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiOnDemandHotspotAvailability").field(&self.0).finish()
@@ -846,6 +851,7 @@ impl WiFiOnDemandHotspotCellularBars {
 impl windows_core::TypeKind for WiFiOnDemandHotspotCellularBars {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiOnDemandHotspotCellularBars {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiOnDemandHotspotCellularBars").field(&self.0).finish()
@@ -881,6 +887,7 @@ impl WiFiOnDemandHotspotConnectStatus {
 impl windows_core::TypeKind for WiFiOnDemandHotspotConnectStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiOnDemandHotspotConnectStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiOnDemandHotspotConnectStatus").field(&self.0).finish()
@@ -909,6 +916,7 @@ impl WiFiPhyKind {
 impl windows_core::TypeKind for WiFiPhyKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiPhyKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiPhyKind").field(&self.0).finish()
@@ -927,6 +935,7 @@ impl WiFiReconnectionKind {
 impl windows_core::TypeKind for WiFiReconnectionKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiReconnectionKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiReconnectionKind").field(&self.0).finish()
@@ -946,6 +955,7 @@ impl WiFiWpsConfigurationStatus {
 impl windows_core::TypeKind for WiFiWpsConfigurationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiWpsConfigurationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiWpsConfigurationStatus").field(&self.0).finish()
@@ -968,6 +978,7 @@ impl WiFiWpsKind {
 impl windows_core::TypeKind for WiFiWpsKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiWpsKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiWpsKind").field(&self.0).finish()
@@ -976,3 +987,5 @@ impl core::fmt::Debug for WiFiWpsKind {
 impl windows_core::RuntimeType for WiFiWpsKind {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.WiFi.WiFiWpsKind;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,375 @@
+#[cfg(all(feature = "Networking_Connectivity", feature = "Security_Credentials"))]
+impl windows_core::imp::ProxyVtable for IWiFiAdapter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Networking_Connectivity")]
+        unsafe extern "system" fn NetworkAdapter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAdapter", "NetworkAdapter", 6usize, |this| ((**(this as *const *const IWiFiAdapter_Vtbl)).NetworkAdapter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScanAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAdapter", "ScanAsync", 7usize, |this| ((**(this as *const *const IWiFiAdapter_Vtbl)).ScanAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAdapter", "NetworkReport", 8usize, |this| ((**(this as *const *const IWiFiAdapter_Vtbl)).NetworkReport)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvailableNetworksChanged(this: *mut core::ffi::c_void, args: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAdapter", "AvailableNetworksChanged", 9usize, |this| ((**(this as *const *const IWiFiAdapter_Vtbl)).AvailableNetworksChanged)(this, args, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAvailableNetworksChanged(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAdapter", "RemoveAvailableNetworksChanged", 10usize, |this| ((**(this as *const *const IWiFiAdapter_Vtbl)).RemoveAvailableNetworksChanged)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectAsync(this: *mut core::ffi::c_void, availablenetwork: *mut core::ffi::c_void, reconnectionkind: WiFiReconnectionKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAdapter", "ConnectAsync", 11usize, |this| ((**(this as *const *const IWiFiAdapter_Vtbl)).ConnectAsync)(this, availablenetwork, reconnectionkind, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn ConnectWithPasswordCredentialAsync(this: *mut core::ffi::c_void, availablenetwork: *mut core::ffi::c_void, reconnectionkind: WiFiReconnectionKind, passwordcredential: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAdapter", "ConnectWithPasswordCredentialAsync", 12usize, |this| ((**(this as *const *const IWiFiAdapter_Vtbl)).ConnectWithPasswordCredentialAsync)(this, availablenetwork, reconnectionkind, passwordcredential, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn ConnectWithPasswordCredentialAndSsidAsync(this: *mut core::ffi::c_void, availablenetwork: *mut core::ffi::c_void, reconnectionkind: WiFiReconnectionKind, passwordcredential: *mut core::ffi::c_void, ssid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAdapter", "ConnectWithPasswordCredentialAndSsidAsync", 13usize, |this| ((**(this as *const *const IWiFiAdapter_Vtbl)).ConnectWithPasswordCredentialAndSsidAsync)(this, availablenetwork, reconnectionkind, passwordcredential, ssid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAdapter", "Disconnect", 14usize, |this| ((**(this as *const *const IWiFiAdapter_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Networking_Connectivity")]
+            NetworkAdapter,
+            #[cfg(not(feature = "Networking_Connectivity"))]
+            NetworkAdapter: 0,
+            ScanAsync,
+            NetworkReport,
+            AvailableNetworksChanged,
+            RemoveAvailableNetworksChanged,
+            ConnectAsync,
+            #[cfg(feature = "Security_Credentials")]
+            ConnectWithPasswordCredentialAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            ConnectWithPasswordCredentialAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            ConnectWithPasswordCredentialAndSsidAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            ConnectWithPasswordCredentialAndSsidAsync: 0,
+            Disconnect,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiAdapter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IWiFiAdapter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetWpsConfigurationAsync(this: *mut core::ffi::c_void, availablenetwork: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAdapter2", "GetWpsConfigurationAsync", 6usize, |this| ((**(this as *const *const IWiFiAdapter2_Vtbl)).GetWpsConfigurationAsync)(this, availablenetwork, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn ConnectWithPasswordCredentialAndSsidAndConnectionMethodAsync(this: *mut core::ffi::c_void, availablenetwork: *mut core::ffi::c_void, reconnectionkind: WiFiReconnectionKind, passwordcredential: *mut core::ffi::c_void, ssid: core::mem::MaybeUninit<windows_core::HSTRING>, connectionmethod: WiFiConnectionMethod, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAdapter2", "ConnectWithPasswordCredentialAndSsidAndConnectionMethodAsync", 7usize, |this| ((**(this as *const *const IWiFiAdapter2_Vtbl)).ConnectWithPasswordCredentialAndSsidAndConnectionMethodAsync)(this, availablenetwork, reconnectionkind, passwordcredential, ssid, connectionmethod, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetWpsConfigurationAsync,
+            #[cfg(feature = "Security_Credentials")]
+            ConnectWithPasswordCredentialAndSsidAndConnectionMethodAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            ConnectWithPasswordCredentialAndSsidAndConnectionMethodAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiAdapter2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWiFiAdapterStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAdaptersAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAdapterStatics", "FindAllAdaptersAsync", 6usize, |this| ((**(this as *const *const IWiFiAdapterStatics_Vtbl)).FindAllAdaptersAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAdapterStatics", "GetDeviceSelector", 7usize, |this| ((**(this as *const *const IWiFiAdapterStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAdapterStatics", "FromIdAsync", 8usize, |this| ((**(this as *const *const IWiFiAdapterStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAdapterStatics", "RequestAccessAsync", 9usize, |this| ((**(this as *const *const IWiFiAdapterStatics_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAdaptersAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAdaptersAsync: 0,
+            GetDeviceSelector,
+            FromIdAsync,
+            RequestAccessAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiAdapterStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking_Connectivity")]
+impl windows_core::imp::ProxyVtable for IWiFiAvailableNetwork_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uptime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAvailableNetwork", "Uptime", 6usize, |this| ((**(this as *const *const IWiFiAvailableNetwork_Vtbl)).Uptime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ssid(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAvailableNetwork", "Ssid", 7usize, |this| ((**(this as *const *const IWiFiAvailableNetwork_Vtbl)).Ssid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bssid(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAvailableNetwork", "Bssid", 8usize, |this| ((**(this as *const *const IWiFiAvailableNetwork_Vtbl)).Bssid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChannelCenterFrequencyInKilohertz(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAvailableNetwork", "ChannelCenterFrequencyInKilohertz", 9usize, |this| ((**(this as *const *const IWiFiAvailableNetwork_Vtbl)).ChannelCenterFrequencyInKilohertz)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkRssiInDecibelMilliwatts(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAvailableNetwork", "NetworkRssiInDecibelMilliwatts", 10usize, |this| ((**(this as *const *const IWiFiAvailableNetwork_Vtbl)).NetworkRssiInDecibelMilliwatts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalBars(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAvailableNetwork", "SignalBars", 11usize, |this| ((**(this as *const *const IWiFiAvailableNetwork_Vtbl)).SignalBars)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkKind(this: *mut core::ffi::c_void, result__: *mut WiFiNetworkKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAvailableNetwork", "NetworkKind", 12usize, |this| ((**(this as *const *const IWiFiAvailableNetwork_Vtbl)).NetworkKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhyKind(this: *mut core::ffi::c_void, result__: *mut WiFiPhyKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAvailableNetwork", "PhyKind", 13usize, |this| ((**(this as *const *const IWiFiAvailableNetwork_Vtbl)).PhyKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Connectivity")]
+        unsafe extern "system" fn SecuritySettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAvailableNetwork", "SecuritySettings", 14usize, |this| ((**(this as *const *const IWiFiAvailableNetwork_Vtbl)).SecuritySettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeaconInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAvailableNetwork", "BeaconInterval", 15usize, |this| ((**(this as *const *const IWiFiAvailableNetwork_Vtbl)).BeaconInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWiFiDirect(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiAvailableNetwork", "IsWiFiDirect", 16usize, |this| ((**(this as *const *const IWiFiAvailableNetwork_Vtbl)).IsWiFiDirect)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Uptime,
+            Ssid,
+            Bssid,
+            ChannelCenterFrequencyInKilohertz,
+            NetworkRssiInDecibelMilliwatts,
+            SignalBars,
+            NetworkKind,
+            PhyKind,
+            #[cfg(feature = "Networking_Connectivity")]
+            SecuritySettings,
+            #[cfg(not(feature = "Networking_Connectivity"))]
+            SecuritySettings: 0,
+            BeaconInterval,
+            IsWiFiDirect,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiAvailableNetwork as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiFiConnectionResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectionStatus(this: *mut core::ffi::c_void, result__: *mut WiFiConnectionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiConnectionResult", "ConnectionStatus", 6usize, |this| ((**(this as *const *const IWiFiConnectionResult_Vtbl)).ConnectionStatus)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectionStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiConnectionResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWiFiNetworkReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiNetworkReport", "Timestamp", 6usize, |this| ((**(this as *const *const IWiFiNetworkReport_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AvailableNetworks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiNetworkReport", "AvailableNetworks", 7usize, |this| ((**(this as *const *const IWiFiNetworkReport_Vtbl)).AvailableNetworks)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Timestamp,
+            #[cfg(feature = "Foundation_Collections")]
+            AvailableNetworks,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AvailableNetworks: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiNetworkReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiFiOnDemandHotspotConnectTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestedNetwork(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotConnectTriggerDetails", "RequestedNetwork", 6usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotConnectTriggerDetails_Vtbl)).RequestedNetwork)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportError(this: *mut core::ffi::c_void, status: WiFiOnDemandHotspotConnectStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotConnectTriggerDetails", "ReportError", 7usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotConnectTriggerDetails_Vtbl)).ReportError)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotConnectTriggerDetails", "ConnectAsync", 8usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotConnectTriggerDetails_Vtbl)).ConnectAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotConnectTriggerDetails", "Connect", 9usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotConnectTriggerDetails_Vtbl)).Connect)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestedNetwork, ReportError, ConnectAsync, Connect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiOnDemandHotspotConnectTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiFiOnDemandHotspotConnectionResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut WiFiOnDemandHotspotConnectStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotConnectionResult", "Status", 6usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotConnectionResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiOnDemandHotspotConnectionResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiFiOnDemandHotspotNetwork_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetwork", "GetProperties", 6usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetwork_Vtbl)).GetProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateProperties(this: *mut core::ffi::c_void, newproperties: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetwork", "UpdateProperties", 7usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetwork_Vtbl)).UpdateProperties)(this, newproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetwork", "Id", 8usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetwork_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperties, UpdateProperties, Id }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiOnDemandHotspotNetwork as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IWiFiOnDemandHotspotNetworkProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetworkProperties", "DisplayName", 6usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetworkProperties_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetworkProperties", "SetDisplayName", 7usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetworkProperties_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Availability(this: *mut core::ffi::c_void, result__: *mut WiFiOnDemandHotspotAvailability) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetworkProperties", "Availability", 8usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetworkProperties_Vtbl)).Availability)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAvailability(this: *mut core::ffi::c_void, value: WiFiOnDemandHotspotAvailability) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetworkProperties", "SetAvailability", 9usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetworkProperties_Vtbl)).SetAvailability)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemainingBatteryPercent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetworkProperties", "RemainingBatteryPercent", 10usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetworkProperties_Vtbl)).RemainingBatteryPercent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemainingBatteryPercent(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetworkProperties", "SetRemainingBatteryPercent", 11usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetworkProperties_Vtbl)).SetRemainingBatteryPercent)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CellularBars(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetworkProperties", "CellularBars", 12usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetworkProperties_Vtbl)).CellularBars)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCellularBars(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetworkProperties", "SetCellularBars", 13usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetworkProperties_Vtbl)).SetCellularBars)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMetered(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetworkProperties", "IsMetered", 14usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetworkProperties_Vtbl)).IsMetered)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsMetered(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetworkProperties", "SetIsMetered", 15usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetworkProperties_Vtbl)).SetIsMetered)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ssid(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetworkProperties", "Ssid", 16usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetworkProperties_Vtbl)).Ssid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSsid(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetworkProperties", "SetSsid", 17usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetworkProperties_Vtbl)).SetSsid)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn Password(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetworkProperties", "Password", 18usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetworkProperties_Vtbl)).Password)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetPassword(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetworkProperties", "SetPassword", 19usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetworkProperties_Vtbl)).SetPassword)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayName,
+            SetDisplayName,
+            Availability,
+            SetAvailability,
+            RemainingBatteryPercent,
+            SetRemainingBatteryPercent,
+            CellularBars,
+            SetCellularBars,
+            IsMetered,
+            SetIsMetered,
+            Ssid,
+            SetSsid,
+            #[cfg(feature = "Security_Credentials")]
+            Password,
+            #[cfg(not(feature = "Security_Credentials"))]
+            Password: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetPassword,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetPassword: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiOnDemandHotspotNetworkProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiFiOnDemandHotspotNetworkStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOrCreateById(this: *mut core::ffi::c_void, networkid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiOnDemandHotspotNetworkStatics", "GetOrCreateById", 6usize, |this| ((**(this as *const *const IWiFiOnDemandHotspotNetworkStatics_Vtbl)).GetOrCreateById)(this, networkid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOrCreateById }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiOnDemandHotspotNetworkStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWiFiWpsConfigurationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut WiFiWpsConfigurationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiWpsConfigurationResult", "Status", 6usize, |this| ((**(this as *const *const IWiFiWpsConfigurationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedWpsKinds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiWpsConfigurationResult", "SupportedWpsKinds", 7usize, |this| ((**(this as *const *const IWiFiWpsConfigurationResult_Vtbl)).SupportedWpsKinds)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedWpsKinds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedWpsKinds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiWpsConfigurationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -898,6 +898,7 @@ impl WiFiDirectServiceAdvertisementStatus {
 impl windows_core::TypeKind for WiFiDirectServiceAdvertisementStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiDirectServiceAdvertisementStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiDirectServiceAdvertisementStatus").field(&self.0).finish()
@@ -917,6 +918,7 @@ impl WiFiDirectServiceConfigurationMethod {
 impl windows_core::TypeKind for WiFiDirectServiceConfigurationMethod {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiDirectServiceConfigurationMethod {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiDirectServiceConfigurationMethod").field(&self.0).finish()
@@ -938,6 +940,7 @@ impl WiFiDirectServiceError {
 impl windows_core::TypeKind for WiFiDirectServiceError {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiDirectServiceError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiDirectServiceError").field(&self.0).finish()
@@ -956,6 +959,7 @@ impl WiFiDirectServiceIPProtocol {
 impl windows_core::TypeKind for WiFiDirectServiceIPProtocol {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiDirectServiceIPProtocol {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiDirectServiceIPProtocol").field(&self.0).finish()
@@ -978,6 +982,7 @@ impl WiFiDirectServiceSessionErrorStatus {
 impl windows_core::TypeKind for WiFiDirectServiceSessionErrorStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiDirectServiceSessionErrorStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiDirectServiceSessionErrorStatus").field(&self.0).finish()
@@ -998,6 +1003,7 @@ impl WiFiDirectServiceSessionStatus {
 impl windows_core::TypeKind for WiFiDirectServiceSessionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiDirectServiceSessionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiDirectServiceSessionStatus").field(&self.0).finish()
@@ -1017,6 +1023,7 @@ impl WiFiDirectServiceStatus {
 impl windows_core::TypeKind for WiFiDirectServiceStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiDirectServiceStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiDirectServiceStatus").field(&self.0).finish()
@@ -1025,3 +1032,5 @@ impl core::fmt::Debug for WiFiDirectServiceStatus {
 impl windows_core::RuntimeType for WiFiDirectServiceStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.WiFiDirect.Services.WiFiDirectServiceStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
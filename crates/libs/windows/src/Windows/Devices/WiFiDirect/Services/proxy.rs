@@ -0,0 +1,475 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IWiFiDirectService_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn RemoteServiceInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectService", "RemoteServiceInfo", 6usize, |this| ((**(this as *const *const IWiFiDirectService_Vtbl)).RemoteServiceInfo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedConfigurationMethods(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectService", "SupportedConfigurationMethods", 7usize, |this| ((**(this as *const *const IWiFiDirectService_Vtbl)).SupportedConfigurationMethods)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferGroupOwnerMode(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectService", "PreferGroupOwnerMode", 8usize, |this| ((**(this as *const *const IWiFiDirectService_Vtbl)).PreferGroupOwnerMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferGroupOwnerMode(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectService", "SetPreferGroupOwnerMode", 9usize, |this| ((**(this as *const *const IWiFiDirectService_Vtbl)).SetPreferGroupOwnerMode)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SessionInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectService", "SessionInfo", 10usize, |this| ((**(this as *const *const IWiFiDirectService_Vtbl)).SessionInfo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetSessionInfo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectService", "SetSessionInfo", 11usize, |this| ((**(this as *const *const IWiFiDirectService_Vtbl)).SetSessionInfo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceError(this: *mut core::ffi::c_void, result__: *mut WiFiDirectServiceError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectService", "ServiceError", 12usize, |this| ((**(this as *const *const IWiFiDirectService_Vtbl)).ServiceError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionDeferred(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectService", "SessionDeferred", 13usize, |this| ((**(this as *const *const IWiFiDirectService_Vtbl)).SessionDeferred)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSessionDeferred(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectService", "RemoveSessionDeferred", 14usize, |this| ((**(this as *const *const IWiFiDirectService_Vtbl)).RemoveSessionDeferred)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProvisioningInfoAsync(this: *mut core::ffi::c_void, selectedconfigurationmethod: WiFiDirectServiceConfigurationMethod, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectService", "GetProvisioningInfoAsync", 15usize, |this| ((**(this as *const *const IWiFiDirectService_Vtbl)).GetProvisioningInfoAsync)(this, selectedconfigurationmethod, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectService", "ConnectAsync", 16usize, |this| ((**(this as *const *const IWiFiDirectService_Vtbl)).ConnectAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectAsyncWithPin(this: *mut core::ffi::c_void, pin: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectService", "ConnectAsyncWithPin", 17usize, |this| ((**(this as *const *const IWiFiDirectService_Vtbl)).ConnectAsyncWithPin)(this, pin, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            RemoteServiceInfo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            RemoteServiceInfo: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedConfigurationMethods,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedConfigurationMethods: 0,
+            PreferGroupOwnerMode,
+            SetPreferGroupOwnerMode,
+            #[cfg(feature = "Storage_Streams")]
+            SessionInfo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SessionInfo: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetSessionInfo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetSessionInfo: 0,
+            ServiceError,
+            SessionDeferred,
+            RemoveSessionDeferred,
+            GetProvisioningInfoAsync,
+            ConnectAsync,
+            ConnectAsyncWithPin,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectService as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IWiFiDirectServiceAdvertiser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServiceName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "ServiceName", 6usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).ServiceName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ServiceNamePrefixes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "ServiceNamePrefixes", 7usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).ServiceNamePrefixes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ServiceInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "ServiceInfo", 8usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).ServiceInfo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetServiceInfo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "SetServiceInfo", 9usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).SetServiceInfo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoAcceptSession(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "AutoAcceptSession", 10usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).AutoAcceptSession)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoAcceptSession(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "SetAutoAcceptSession", 11usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).SetAutoAcceptSession)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferGroupOwnerMode(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "PreferGroupOwnerMode", 12usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).PreferGroupOwnerMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferGroupOwnerMode(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "SetPreferGroupOwnerMode", 13usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).SetPreferGroupOwnerMode)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PreferredConfigurationMethods(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "PreferredConfigurationMethods", 14usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).PreferredConfigurationMethods)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceStatus(this: *mut core::ffi::c_void, result__: *mut WiFiDirectServiceStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "ServiceStatus", 15usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).ServiceStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceStatus(this: *mut core::ffi::c_void, value: WiFiDirectServiceStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "SetServiceStatus", 16usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).SetServiceStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomServiceStatusCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "CustomServiceStatusCode", 17usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).CustomServiceStatusCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomServiceStatusCode(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "SetCustomServiceStatusCode", 18usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).SetCustomServiceStatusCode)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn DeferredSessionInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "DeferredSessionInfo", 19usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).DeferredSessionInfo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetDeferredSessionInfo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "SetDeferredSessionInfo", 20usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).SetDeferredSessionInfo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdvertisementStatus(this: *mut core::ffi::c_void, result__: *mut WiFiDirectServiceAdvertisementStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "AdvertisementStatus", 21usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).AdvertisementStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceError(this: *mut core::ffi::c_void, result__: *mut WiFiDirectServiceError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "ServiceError", 22usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).ServiceError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "SessionRequested", 23usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).SessionRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSessionRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "RemoveSessionRequested", 24usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).RemoveSessionRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoAcceptSessionConnected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "AutoAcceptSessionConnected", 25usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).AutoAcceptSessionConnected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAutoAcceptSessionConnected(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "RemoveAutoAcceptSessionConnected", 26usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).RemoveAutoAcceptSessionConnected)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdvertisementStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "AdvertisementStatusChanged", 27usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).AdvertisementStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdvertisementStatusChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "RemoveAdvertisementStatusChanged", 28usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).RemoveAdvertisementStatusChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn ConnectAsync(this: *mut core::ffi::c_void, deviceinfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "ConnectAsync", 29usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).ConnectAsync)(this, deviceinfo, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn ConnectAsyncWithPin(this: *mut core::ffi::c_void, deviceinfo: *mut core::ffi::c_void, pin: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "ConnectAsyncWithPin", 30usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).ConnectAsyncWithPin)(this, deviceinfo, pin, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "Start", 31usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiser", "Stop", 32usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiser_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServiceName,
+            #[cfg(feature = "Foundation_Collections")]
+            ServiceNamePrefixes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ServiceNamePrefixes: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ServiceInfo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ServiceInfo: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetServiceInfo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetServiceInfo: 0,
+            AutoAcceptSession,
+            SetAutoAcceptSession,
+            PreferGroupOwnerMode,
+            SetPreferGroupOwnerMode,
+            #[cfg(feature = "Foundation_Collections")]
+            PreferredConfigurationMethods,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PreferredConfigurationMethods: 0,
+            ServiceStatus,
+            SetServiceStatus,
+            CustomServiceStatusCode,
+            SetCustomServiceStatusCode,
+            #[cfg(feature = "Storage_Streams")]
+            DeferredSessionInfo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            DeferredSessionInfo: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetDeferredSessionInfo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetDeferredSessionInfo: 0,
+            AdvertisementStatus,
+            ServiceError,
+            SessionRequested,
+            RemoveSessionRequested,
+            AutoAcceptSessionConnected,
+            RemoveAutoAcceptSessionConnected,
+            AdvertisementStatusChanged,
+            RemoveAdvertisementStatusChanged,
+            #[cfg(feature = "Devices_Enumeration")]
+            ConnectAsync,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            ConnectAsync: 0,
+            #[cfg(feature = "Devices_Enumeration")]
+            ConnectAsyncWithPin,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            ConnectAsyncWithPin: 0,
+            Start,
+            Stop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectServiceAdvertiser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiFiDirectServiceAdvertiserFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWiFiDirectServiceAdvertiser(this: *mut core::ffi::c_void, servicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAdvertiserFactory", "CreateWiFiDirectServiceAdvertiser", 6usize, |this| ((**(this as *const *const IWiFiDirectServiceAdvertiserFactory_Vtbl)).CreateWiFiDirectServiceAdvertiser)(this, servicename, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWiFiDirectServiceAdvertiser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectServiceAdvertiserFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IWiFiDirectServiceAutoAcceptSessionConnectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAutoAcceptSessionConnectedEventArgs", "Session", 6usize, |this| ((**(this as *const *const IWiFiDirectServiceAutoAcceptSessionConnectedEventArgs_Vtbl)).Session)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SessionInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceAutoAcceptSessionConnectedEventArgs", "SessionInfo", 7usize, |this| ((**(this as *const *const IWiFiDirectServiceAutoAcceptSessionConnectedEventArgs_Vtbl)).SessionInfo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Session,
+            #[cfg(feature = "Storage_Streams")]
+            SessionInfo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SessionInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectServiceAutoAcceptSessionConnectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiFiDirectServiceProvisioningInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SelectedConfigurationMethod(this: *mut core::ffi::c_void, result__: *mut WiFiDirectServiceConfigurationMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceProvisioningInfo", "SelectedConfigurationMethod", 6usize, |this| ((**(this as *const *const IWiFiDirectServiceProvisioningInfo_Vtbl)).SelectedConfigurationMethod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGroupFormationNeeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceProvisioningInfo", "IsGroupFormationNeeded", 7usize, |this| ((**(this as *const *const IWiFiDirectServiceProvisioningInfo_Vtbl)).IsGroupFormationNeeded)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SelectedConfigurationMethod, IsGroupFormationNeeded }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectServiceProvisioningInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Networking"))]
+impl windows_core::imp::ProxyVtable for IWiFiDirectServiceRemotePortAddedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Networking"))]
+        unsafe extern "system" fn EndpointPairs(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceRemotePortAddedEventArgs", "EndpointPairs", 6usize, |this| ((**(this as *const *const IWiFiDirectServiceRemotePortAddedEventArgs_Vtbl)).EndpointPairs)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Protocol(this: *mut core::ffi::c_void, result__: *mut WiFiDirectServiceIPProtocol) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceRemotePortAddedEventArgs", "Protocol", 7usize, |this| ((**(this as *const *const IWiFiDirectServiceRemotePortAddedEventArgs_Vtbl)).Protocol)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Networking"))]
+            EndpointPairs,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Networking")))]
+            EndpointPairs: 0,
+            Protocol,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectServiceRemotePortAddedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Networking_Sockets"))]
+impl windows_core::imp::ProxyVtable for IWiFiDirectServiceSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServiceName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSession", "ServiceName", 6usize, |this| ((**(this as *const *const IWiFiDirectServiceSession_Vtbl)).ServiceName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut WiFiDirectServiceSessionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSession", "Status", 7usize, |this| ((**(this as *const *const IWiFiDirectServiceSession_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorStatus(this: *mut core::ffi::c_void, result__: *mut WiFiDirectServiceSessionErrorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSession", "ErrorStatus", 8usize, |this| ((**(this as *const *const IWiFiDirectServiceSession_Vtbl)).ErrorStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSession", "SessionId", 9usize, |this| ((**(this as *const *const IWiFiDirectServiceSession_Vtbl)).SessionId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdvertisementId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSession", "AdvertisementId", 10usize, |this| ((**(this as *const *const IWiFiDirectServiceSession_Vtbl)).AdvertisementId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSession", "ServiceAddress", 11usize, |this| ((**(this as *const *const IWiFiDirectServiceSession_Vtbl)).ServiceAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSession", "SessionAddress", 12usize, |this| ((**(this as *const *const IWiFiDirectServiceSession_Vtbl)).SessionAddress)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Networking"))]
+        unsafe extern "system" fn GetConnectionEndpointPairs(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSession", "GetConnectionEndpointPairs", 13usize, |this| ((**(this as *const *const IWiFiDirectServiceSession_Vtbl)).GetConnectionEndpointPairs)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSession", "SessionStatusChanged", 14usize, |this| ((**(this as *const *const IWiFiDirectServiceSession_Vtbl)).SessionStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSessionStatusChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSession", "RemoveSessionStatusChanged", 15usize, |this| ((**(this as *const *const IWiFiDirectServiceSession_Vtbl)).RemoveSessionStatusChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn AddStreamSocketListenerAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSession", "AddStreamSocketListenerAsync", 16usize, |this| ((**(this as *const *const IWiFiDirectServiceSession_Vtbl)).AddStreamSocketListenerAsync)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn AddDatagramSocketAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSession", "AddDatagramSocketAsync", 17usize, |this| ((**(this as *const *const IWiFiDirectServiceSession_Vtbl)).AddDatagramSocketAsync)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemotePortAdded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSession", "RemotePortAdded", 18usize, |this| ((**(this as *const *const IWiFiDirectServiceSession_Vtbl)).RemotePortAdded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemotePortAdded(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSession", "RemoveRemotePortAdded", 19usize, |this| ((**(this as *const *const IWiFiDirectServiceSession_Vtbl)).RemoveRemotePortAdded)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServiceName,
+            Status,
+            ErrorStatus,
+            SessionId,
+            AdvertisementId,
+            ServiceAddress,
+            SessionAddress,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Networking"))]
+            GetConnectionEndpointPairs,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Networking")))]
+            GetConnectionEndpointPairs: 0,
+            SessionStatusChanged,
+            RemoveSessionStatusChanged,
+            #[cfg(feature = "Networking_Sockets")]
+            AddStreamSocketListenerAsync,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            AddStreamSocketListenerAsync: 0,
+            #[cfg(feature = "Networking_Sockets")]
+            AddDatagramSocketAsync,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            AddDatagramSocketAsync: 0,
+            RemotePortAdded,
+            RemoveRemotePortAdded,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectServiceSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IWiFiDirectServiceSessionDeferredEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn DeferredSessionInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSessionDeferredEventArgs", "DeferredSessionInfo", 6usize, |this| ((**(this as *const *const IWiFiDirectServiceSessionDeferredEventArgs_Vtbl)).DeferredSessionInfo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            DeferredSessionInfo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            DeferredSessionInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectServiceSessionDeferredEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IWiFiDirectServiceSessionRequest_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn DeviceInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSessionRequest", "DeviceInformation", 6usize, |this| ((**(this as *const *const IWiFiDirectServiceSessionRequest_Vtbl)).DeviceInformation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProvisioningInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSessionRequest", "ProvisioningInfo", 7usize, |this| ((**(this as *const *const IWiFiDirectServiceSessionRequest_Vtbl)).ProvisioningInfo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SessionInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSessionRequest", "SessionInfo", 8usize, |this| ((**(this as *const *const IWiFiDirectServiceSessionRequest_Vtbl)).SessionInfo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Enumeration")]
+            DeviceInformation,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            DeviceInformation: 0,
+            ProvisioningInfo,
+            #[cfg(feature = "Storage_Streams")]
+            SessionInfo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SessionInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectServiceSessionRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiFiDirectServiceSessionRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSessionRequest(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceSessionRequestedEventArgs", "GetSessionRequest", 6usize, |this| ((**(this as *const *const IWiFiDirectServiceSessionRequestedEventArgs_Vtbl)).GetSessionRequest)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSessionRequest }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectServiceSessionRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IWiFiDirectServiceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSelector(this: *mut core::ffi::c_void, servicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceStatics", "GetSelector", 6usize, |this| ((**(this as *const *const IWiFiDirectServiceStatics_Vtbl)).GetSelector)(this, servicename, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetSelectorWithFilter(this: *mut core::ffi::c_void, servicename: core::mem::MaybeUninit<windows_core::HSTRING>, serviceinfofilter: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceStatics", "GetSelectorWithFilter", 7usize, |this| ((**(this as *const *const IWiFiDirectServiceStatics_Vtbl)).GetSelectorWithFilter)(this, servicename, serviceinfofilter, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectServiceStatics", "FromIdAsync", 8usize, |this| ((**(this as *const *const IWiFiDirectServiceStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSelector,
+            #[cfg(feature = "Storage_Streams")]
+            GetSelectorWithFilter,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetSelectorWithFilter: 0,
+            FromIdAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectServiceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -806,6 +806,7 @@ impl WiFiDirectAdvertisementListenStateDiscoverability {
 impl windows_core::TypeKind for WiFiDirectAdvertisementListenStateDiscoverability {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiDirectAdvertisementListenStateDiscoverability {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiDirectAdvertisementListenStateDiscoverability").field(&self.0).finish()
@@ -826,6 +827,7 @@ impl WiFiDirectAdvertisementPublisherStatus {
 impl windows_core::TypeKind for WiFiDirectAdvertisementPublisherStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiDirectAdvertisementPublisherStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiDirectAdvertisementPublisherStatus").field(&self.0).finish()
@@ -845,6 +847,7 @@ impl WiFiDirectConfigurationMethod {
 impl windows_core::TypeKind for WiFiDirectConfigurationMethod {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiDirectConfigurationMethod {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiDirectConfigurationMethod").field(&self.0).finish()
@@ -863,6 +866,7 @@ impl WiFiDirectConnectionStatus {
 impl windows_core::TypeKind for WiFiDirectConnectionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiDirectConnectionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiDirectConnectionStatus").field(&self.0).finish()
@@ -881,6 +885,7 @@ impl WiFiDirectDeviceSelectorType {
 impl windows_core::TypeKind for WiFiDirectDeviceSelectorType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiDirectDeviceSelectorType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiDirectDeviceSelectorType").field(&self.0).finish()
@@ -900,6 +905,7 @@ impl WiFiDirectError {
 impl windows_core::TypeKind for WiFiDirectError {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiDirectError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiDirectError").field(&self.0).finish()
@@ -918,6 +924,7 @@ impl WiFiDirectPairingProcedure {
 impl windows_core::TypeKind for WiFiDirectPairingProcedure {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WiFiDirectPairingProcedure {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WiFiDirectPairingProcedure").field(&self.0).finish()
@@ -926,3 +933,5 @@ impl core::fmt::Debug for WiFiDirectPairingProcedure {
 impl windows_core::RuntimeType for WiFiDirectPairingProcedure {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Devices.WiFiDirect.WiFiDirectPairingProcedure;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,418 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWiFiDirectAdvertisement_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn InformationElements(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisement", "InformationElements", 6usize, |this| ((**(this as *const *const IWiFiDirectAdvertisement_Vtbl)).InformationElements)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetInformationElements(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisement", "SetInformationElements", 7usize, |this| ((**(this as *const *const IWiFiDirectAdvertisement_Vtbl)).SetInformationElements)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ListenStateDiscoverability(this: *mut core::ffi::c_void, result__: *mut WiFiDirectAdvertisementListenStateDiscoverability) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisement", "ListenStateDiscoverability", 8usize, |this| ((**(this as *const *const IWiFiDirectAdvertisement_Vtbl)).ListenStateDiscoverability)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetListenStateDiscoverability(this: *mut core::ffi::c_void, value: WiFiDirectAdvertisementListenStateDiscoverability) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisement", "SetListenStateDiscoverability", 9usize, |this| ((**(this as *const *const IWiFiDirectAdvertisement_Vtbl)).SetListenStateDiscoverability)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAutonomousGroupOwnerEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisement", "IsAutonomousGroupOwnerEnabled", 10usize, |this| ((**(this as *const *const IWiFiDirectAdvertisement_Vtbl)).IsAutonomousGroupOwnerEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsAutonomousGroupOwnerEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisement", "SetIsAutonomousGroupOwnerEnabled", 11usize, |this| ((**(this as *const *const IWiFiDirectAdvertisement_Vtbl)).SetIsAutonomousGroupOwnerEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LegacySettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisement", "LegacySettings", 12usize, |this| ((**(this as *const *const IWiFiDirectAdvertisement_Vtbl)).LegacySettings)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            InformationElements,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            InformationElements: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetInformationElements,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetInformationElements: 0,
+            ListenStateDiscoverability,
+            SetListenStateDiscoverability,
+            IsAutonomousGroupOwnerEnabled,
+            SetIsAutonomousGroupOwnerEnabled,
+            LegacySettings,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectAdvertisement as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWiFiDirectAdvertisement2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedConfigurationMethods(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisement2", "SupportedConfigurationMethods", 6usize, |this| ((**(this as *const *const IWiFiDirectAdvertisement2_Vtbl)).SupportedConfigurationMethods)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedConfigurationMethods,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedConfigurationMethods: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectAdvertisement2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiFiDirectAdvertisementPublisher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Advertisement(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisementPublisher", "Advertisement", 6usize, |this| ((**(this as *const *const IWiFiDirectAdvertisementPublisher_Vtbl)).Advertisement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut WiFiDirectAdvertisementPublisherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisementPublisher", "Status", 7usize, |this| ((**(this as *const *const IWiFiDirectAdvertisementPublisher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisementPublisher", "StatusChanged", 8usize, |this| ((**(this as *const *const IWiFiDirectAdvertisementPublisher_Vtbl)).StatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisementPublisher", "RemoveStatusChanged", 9usize, |this| ((**(this as *const *const IWiFiDirectAdvertisementPublisher_Vtbl)).RemoveStatusChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisementPublisher", "Start", 10usize, |this| ((**(this as *const *const IWiFiDirectAdvertisementPublisher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisementPublisher", "Stop", 11usize, |this| ((**(this as *const *const IWiFiDirectAdvertisementPublisher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Advertisement,
+            Status,
+            StatusChanged,
+            RemoveStatusChanged,
+            Start,
+            Stop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectAdvertisementPublisher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiFiDirectAdvertisementPublisherStatusChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut WiFiDirectAdvertisementPublisherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisementPublisherStatusChangedEventArgs", "Status", 6usize, |this| ((**(this as *const *const IWiFiDirectAdvertisementPublisherStatusChangedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut WiFiDirectError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectAdvertisementPublisherStatusChangedEventArgs", "Error", 7usize, |this| ((**(this as *const *const IWiFiDirectAdvertisementPublisherStatusChangedEventArgs_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Error }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectAdvertisementPublisherStatusChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiFiDirectConnectionListener_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectionRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectConnectionListener", "ConnectionRequested", 6usize, |this| ((**(this as *const *const IWiFiDirectConnectionListener_Vtbl)).ConnectionRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConnectionRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectConnectionListener", "RemoveConnectionRequested", 7usize, |this| ((**(this as *const *const IWiFiDirectConnectionListener_Vtbl)).RemoveConnectionRequested)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectionRequested, RemoveConnectionRequested }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectConnectionListener as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiFiDirectConnectionParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GroupOwnerIntent(this: *mut core::ffi::c_void, result__: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectConnectionParameters", "GroupOwnerIntent", 6usize, |this| ((**(this as *const *const IWiFiDirectConnectionParameters_Vtbl)).GroupOwnerIntent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroupOwnerIntent(this: *mut core::ffi::c_void, value: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectConnectionParameters", "SetGroupOwnerIntent", 7usize, |this| ((**(this as *const *const IWiFiDirectConnectionParameters_Vtbl)).SetGroupOwnerIntent)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GroupOwnerIntent, SetGroupOwnerIntent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectConnectionParameters as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWiFiDirectConnectionParameters2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PreferenceOrderedConfigurationMethods(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectConnectionParameters2", "PreferenceOrderedConfigurationMethods", 6usize, |this| ((**(this as *const *const IWiFiDirectConnectionParameters2_Vtbl)).PreferenceOrderedConfigurationMethods)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredPairingProcedure(this: *mut core::ffi::c_void, result__: *mut WiFiDirectPairingProcedure) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectConnectionParameters2", "PreferredPairingProcedure", 7usize, |this| ((**(this as *const *const IWiFiDirectConnectionParameters2_Vtbl)).PreferredPairingProcedure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredPairingProcedure(this: *mut core::ffi::c_void, value: WiFiDirectPairingProcedure) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectConnectionParameters2", "SetPreferredPairingProcedure", 8usize, |this| ((**(this as *const *const IWiFiDirectConnectionParameters2_Vtbl)).SetPreferredPairingProcedure)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            PreferenceOrderedConfigurationMethods,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PreferenceOrderedConfigurationMethods: 0,
+            PreferredPairingProcedure,
+            SetPreferredPairingProcedure,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectConnectionParameters2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Enumeration")]
+impl windows_core::imp::ProxyVtable for IWiFiDirectConnectionParametersStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn GetDevicePairingKinds(this: *mut core::ffi::c_void, configurationmethod: WiFiDirectConfigurationMethod, result__: *mut super::Enumeration::DevicePairingKinds) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectConnectionParametersStatics", "GetDevicePairingKinds", 6usize, |this| ((**(this as *const *const IWiFiDirectConnectionParametersStatics_Vtbl)).GetDevicePairingKinds)(this, configurationmethod, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Enumeration")]
+            GetDevicePairingKinds,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            GetDevicePairingKinds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectConnectionParametersStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Enumeration")]
+impl windows_core::imp::ProxyVtable for IWiFiDirectConnectionRequest_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn DeviceInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectConnectionRequest", "DeviceInformation", 6usize, |this| ((**(this as *const *const IWiFiDirectConnectionRequest_Vtbl)).DeviceInformation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Enumeration")]
+            DeviceInformation,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            DeviceInformation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectConnectionRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiFiDirectConnectionRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetConnectionRequest(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectConnectionRequestedEventArgs", "GetConnectionRequest", 6usize, |this| ((**(this as *const *const IWiFiDirectConnectionRequestedEventArgs_Vtbl)).GetConnectionRequest)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetConnectionRequest }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectConnectionRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Networking"))]
+impl windows_core::imp::ProxyVtable for IWiFiDirectDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectionStatus(this: *mut core::ffi::c_void, result__: *mut WiFiDirectConnectionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectDevice", "ConnectionStatus", 6usize, |this| ((**(this as *const *const IWiFiDirectDevice_Vtbl)).ConnectionStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectDevice", "DeviceId", 7usize, |this| ((**(this as *const *const IWiFiDirectDevice_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectDevice", "ConnectionStatusChanged", 8usize, |this| ((**(this as *const *const IWiFiDirectDevice_Vtbl)).ConnectionStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConnectionStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectDevice", "RemoveConnectionStatusChanged", 9usize, |this| ((**(this as *const *const IWiFiDirectDevice_Vtbl)).RemoveConnectionStatusChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Networking"))]
+        unsafe extern "system" fn GetConnectionEndpointPairs(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectDevice", "GetConnectionEndpointPairs", 10usize, |this| ((**(this as *const *const IWiFiDirectDevice_Vtbl)).GetConnectionEndpointPairs)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConnectionStatus,
+            DeviceId,
+            ConnectionStatusChanged,
+            RemoveConnectionStatusChanged,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Networking"))]
+            GetConnectionEndpointPairs,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Networking")))]
+            GetConnectionEndpointPairs: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiFiDirectDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectDeviceStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IWiFiDirectDeviceStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectDeviceStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const IWiFiDirectDeviceStatics_Vtbl)).FromIdAsync)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiFiDirectDeviceStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, r#type: WiFiDirectDeviceSelectorType, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectDeviceStatics2", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IWiFiDirectDeviceStatics2_Vtbl)).GetDeviceSelector)(this, r#type, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, connectionparameters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectDeviceStatics2", "FromIdAsync", 7usize, |this| ((**(this as *const *const IWiFiDirectDeviceStatics2_Vtbl)).FromIdAsync)(this, deviceid, connectionparameters, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectDeviceStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IWiFiDirectInformationElement_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Oui(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectInformationElement", "Oui", 6usize, |this| ((**(this as *const *const IWiFiDirectInformationElement_Vtbl)).Oui)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetOui(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectInformationElement", "SetOui", 7usize, |this| ((**(this as *const *const IWiFiDirectInformationElement_Vtbl)).SetOui)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OuiType(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectInformationElement", "OuiType", 8usize, |this| ((**(this as *const *const IWiFiDirectInformationElement_Vtbl)).OuiType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOuiType(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectInformationElement", "SetOuiType", 9usize, |this| ((**(this as *const *const IWiFiDirectInformationElement_Vtbl)).SetOuiType)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectInformationElement", "Value", 10usize, |this| ((**(this as *const *const IWiFiDirectInformationElement_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectInformationElement", "SetValue", 11usize, |this| ((**(this as *const *const IWiFiDirectInformationElement_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Oui,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Oui: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetOui,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetOui: 0,
+            OuiType,
+            SetOuiType,
+            #[cfg(feature = "Storage_Streams")]
+            Value,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Value: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetValue,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetValue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectInformationElement as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IWiFiDirectInformationElementStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn CreateFromBuffer(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectInformationElementStatics", "CreateFromBuffer", 6usize, |this| ((**(this as *const *const IWiFiDirectInformationElementStatics_Vtbl)).CreateFromBuffer)(this, buffer, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Enumeration", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn CreateFromDeviceInformation(this: *mut core::ffi::c_void, deviceinformation: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectInformationElementStatics", "CreateFromDeviceInformation", 7usize, |this| ((**(this as *const *const IWiFiDirectInformationElementStatics_Vtbl)).CreateFromDeviceInformation)(this, deviceinformation, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            CreateFromBuffer,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            CreateFromBuffer: 0,
+            #[cfg(all(feature = "Devices_Enumeration", feature = "Foundation_Collections"))]
+            CreateFromDeviceInformation,
+            #[cfg(not(all(feature = "Devices_Enumeration", feature = "Foundation_Collections")))]
+            CreateFromDeviceInformation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectInformationElementStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IWiFiDirectLegacySettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectLegacySettings", "IsEnabled", 6usize, |this| ((**(this as *const *const IWiFiDirectLegacySettings_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectLegacySettings", "SetIsEnabled", 7usize, |this| ((**(this as *const *const IWiFiDirectLegacySettings_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ssid(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectLegacySettings", "Ssid", 8usize, |this| ((**(this as *const *const IWiFiDirectLegacySettings_Vtbl)).Ssid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSsid(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectLegacySettings", "SetSsid", 9usize, |this| ((**(this as *const *const IWiFiDirectLegacySettings_Vtbl)).SetSsid)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn Passphrase(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectLegacySettings", "Passphrase", 10usize, |this| ((**(this as *const *const IWiFiDirectLegacySettings_Vtbl)).Passphrase)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetPassphrase(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiFiDirectLegacySettings", "SetPassphrase", 11usize, |this| ((**(this as *const *const IWiFiDirectLegacySettings_Vtbl)).SetPassphrase)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEnabled,
+            SetIsEnabled,
+            Ssid,
+            SetSsid,
+            #[cfg(feature = "Security_Credentials")]
+            Passphrase,
+            #[cfg(not(feature = "Security_Credentials"))]
+            Passphrase: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetPassphrase,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetPassphrase: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiFiDirectLegacySettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
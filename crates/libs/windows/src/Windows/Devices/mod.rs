@@ -278,3 +278,5 @@ unsafe impl Send for LowLevelDevicesController {}
 unsafe impl Sync for LowLevelDevicesController {}
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,94 @@
+#[cfg(all(feature = "Devices_Adc_Provider", feature = "Devices_Gpio_Provider", feature = "Devices_I2c_Provider", feature = "Devices_Pwm_Provider", feature = "Devices_Spi_Provider"))]
+impl windows_core::imp::ProxyVtable for ILowLevelDevicesAggregateProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Adc_Provider")]
+        unsafe extern "system" fn AdcControllerProvider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLevelDevicesAggregateProvider", "AdcControllerProvider", 6usize, |this| ((**(this as *const *const ILowLevelDevicesAggregateProvider_Vtbl)).AdcControllerProvider)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Pwm_Provider")]
+        unsafe extern "system" fn PwmControllerProvider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLevelDevicesAggregateProvider", "PwmControllerProvider", 7usize, |this| ((**(this as *const *const ILowLevelDevicesAggregateProvider_Vtbl)).PwmControllerProvider)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Gpio_Provider")]
+        unsafe extern "system" fn GpioControllerProvider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLevelDevicesAggregateProvider", "GpioControllerProvider", 8usize, |this| ((**(this as *const *const ILowLevelDevicesAggregateProvider_Vtbl)).GpioControllerProvider)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_I2c_Provider")]
+        unsafe extern "system" fn I2cControllerProvider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLevelDevicesAggregateProvider", "I2cControllerProvider", 9usize, |this| ((**(this as *const *const ILowLevelDevicesAggregateProvider_Vtbl)).I2cControllerProvider)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Spi_Provider")]
+        unsafe extern "system" fn SpiControllerProvider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLevelDevicesAggregateProvider", "SpiControllerProvider", 10usize, |this| ((**(this as *const *const ILowLevelDevicesAggregateProvider_Vtbl)).SpiControllerProvider)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Adc_Provider")]
+            AdcControllerProvider,
+            #[cfg(not(feature = "Devices_Adc_Provider"))]
+            AdcControllerProvider: 0,
+            #[cfg(feature = "Devices_Pwm_Provider")]
+            PwmControllerProvider,
+            #[cfg(not(feature = "Devices_Pwm_Provider"))]
+            PwmControllerProvider: 0,
+            #[cfg(feature = "Devices_Gpio_Provider")]
+            GpioControllerProvider,
+            #[cfg(not(feature = "Devices_Gpio_Provider"))]
+            GpioControllerProvider: 0,
+            #[cfg(feature = "Devices_I2c_Provider")]
+            I2cControllerProvider,
+            #[cfg(not(feature = "Devices_I2c_Provider"))]
+            I2cControllerProvider: 0,
+            #[cfg(feature = "Devices_Spi_Provider")]
+            SpiControllerProvider,
+            #[cfg(not(feature = "Devices_Spi_Provider"))]
+            SpiControllerProvider: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILowLevelDevicesAggregateProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Adc_Provider", feature = "Devices_Gpio_Provider", feature = "Devices_I2c_Provider", feature = "Devices_Pwm_Provider", feature = "Devices_Spi_Provider"))]
+impl windows_core::imp::ProxyVtable for ILowLevelDevicesAggregateProviderFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Devices_Adc_Provider", feature = "Devices_Gpio_Provider", feature = "Devices_I2c_Provider", feature = "Devices_Pwm_Provider", feature = "Devices_Spi_Provider"))]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, adc: *mut core::ffi::c_void, pwm: *mut core::ffi::c_void, gpio: *mut core::ffi::c_void, i2c: *mut core::ffi::c_void, spi: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLevelDevicesAggregateProviderFactory", "Create", 6usize, |this| ((**(this as *const *const ILowLevelDevicesAggregateProviderFactory_Vtbl)).Create)(this, adc, pwm, gpio, i2c, spi, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Devices_Adc_Provider", feature = "Devices_Gpio_Provider", feature = "Devices_I2c_Provider", feature = "Devices_Pwm_Provider", feature = "Devices_Spi_Provider"))]
+            Create,
+            #[cfg(not(all(feature = "Devices_Adc_Provider", feature = "Devices_Gpio_Provider", feature = "Devices_I2c_Provider", feature = "Devices_Pwm_Provider", feature = "Devices_Spi_Provider")))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILowLevelDevicesAggregateProviderFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILowLevelDevicesController_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILowLevelDevicesController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILowLevelDevicesControllerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DefaultProvider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLevelDevicesControllerStatics", "DefaultProvider", 6usize, |this| ((**(this as *const *const ILowLevelDevicesControllerStatics_Vtbl)).DefaultProvider)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultProvider(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLevelDevicesControllerStatics", "SetDefaultProvider", 7usize, |this| ((**(this as *const *const ILowLevelDevicesControllerStatics_Vtbl)).SetDefaultProvider)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DefaultProvider, SetDefaultProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILowLevelDevicesControllerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
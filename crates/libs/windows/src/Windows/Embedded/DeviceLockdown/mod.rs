@@ -83,3 +83,5 @@ impl windows_core::RuntimeName for DeviceLockdownProfileInformation {
 }
 unsafe impl Send for DeviceLockdownProfileInformation {}
 unsafe impl Sync for DeviceLockdownProfileInformation {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,44 @@
+impl windows_core::imp::ProxyVtable for IDeviceLockdownProfileInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceLockdownProfileInformation", "Name", 6usize, |this| ((**(this as *const *const IDeviceLockdownProfileInformation_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceLockdownProfileInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDeviceLockdownProfileStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSupportedLockdownProfiles(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceLockdownProfileStatics", "GetSupportedLockdownProfiles", 6usize, |this| ((**(this as *const *const IDeviceLockdownProfileStatics_Vtbl)).GetSupportedLockdownProfiles)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentLockdownProfile(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceLockdownProfileStatics", "GetCurrentLockdownProfile", 7usize, |this| ((**(this as *const *const IDeviceLockdownProfileStatics_Vtbl)).GetCurrentLockdownProfile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyLockdownProfileAsync(this: *mut core::ffi::c_void, profileid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceLockdownProfileStatics", "ApplyLockdownProfileAsync", 8usize, |this| ((**(this as *const *const IDeviceLockdownProfileStatics_Vtbl)).ApplyLockdownProfileAsync)(this, profileid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLockdownProfileInformation(this: *mut core::ffi::c_void, profileid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceLockdownProfileStatics", "GetLockdownProfileInformation", 9usize, |this| ((**(this as *const *const IDeviceLockdownProfileStatics_Vtbl)).GetLockdownProfileInformation)(this, profileid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSupportedLockdownProfiles,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSupportedLockdownProfiles: 0,
+            GetCurrentLockdownProfile,
+            ApplyLockdownProfileAsync,
+            GetLockdownProfileInformation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceLockdownProfileStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
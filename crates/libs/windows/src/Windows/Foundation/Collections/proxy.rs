@@ -0,0 +1,22 @@
+impl windows_core::imp::ProxyVtable for IPropertySet_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertySet as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVectorChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CollectionChange(this: *mut core::ffi::c_void, result__: *mut CollectionChange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVectorChangedEventArgs", "CollectionChange", 6usize, |this| ((**(this as *const *const IVectorChangedEventArgs_Vtbl)).CollectionChange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Index(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVectorChangedEventArgs", "Index", 7usize, |this| ((**(this as *const *const IVectorChangedEventArgs_Vtbl)).Index)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CollectionChange, Index }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVectorChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
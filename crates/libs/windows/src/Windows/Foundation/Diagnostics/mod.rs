@@ -2042,6 +2042,7 @@ impl CausalityRelation {
 impl windows_core::TypeKind for CausalityRelation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CausalityRelation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CausalityRelation").field(&self.0).finish()
@@ -2061,6 +2062,7 @@ impl CausalitySource {
 impl windows_core::TypeKind for CausalitySource {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CausalitySource {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CausalitySource").field(&self.0).finish()
@@ -2080,6 +2082,7 @@ impl CausalitySynchronousWork {
 impl windows_core::TypeKind for CausalitySynchronousWork {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CausalitySynchronousWork {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CausalitySynchronousWork").field(&self.0).finish()
@@ -2099,6 +2102,7 @@ impl CausalityTraceLevel {
 impl windows_core::TypeKind for CausalityTraceLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CausalityTraceLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CausalityTraceLevel").field(&self.0).finish()
@@ -2120,6 +2124,7 @@ impl ErrorOptions {
 impl windows_core::TypeKind for ErrorOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ErrorOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ErrorOptions").field(&self.0).finish()
@@ -2188,6 +2193,7 @@ impl LoggingFieldFormat {
 impl windows_core::TypeKind for LoggingFieldFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LoggingFieldFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LoggingFieldFormat").field(&self.0).finish()
@@ -2209,6 +2215,7 @@ impl LoggingLevel {
 impl windows_core::TypeKind for LoggingLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LoggingLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LoggingLevel").field(&self.0).finish()
@@ -2232,6 +2239,7 @@ impl LoggingOpcode {
 impl windows_core::TypeKind for LoggingOpcode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LoggingOpcode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LoggingOpcode").field(&self.0).finish()
@@ -2242,3 +2250,5 @@ impl windows_core::RuntimeType for LoggingOpcode {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
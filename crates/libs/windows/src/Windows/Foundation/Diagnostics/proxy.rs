@@ -0,0 +1,997 @@
+impl windows_core::imp::ProxyVtable for IAsyncCausalityTracerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TraceOperationCreation(this: *mut core::ffi::c_void, tracelevel: CausalityTraceLevel, source: CausalitySource, platformid: windows_core::GUID, operationid: u64, operationname: core::mem::MaybeUninit<windows_core::HSTRING>, relatedcontext: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncCausalityTracerStatics", "TraceOperationCreation", 6usize, |this| ((**(this as *const *const IAsyncCausalityTracerStatics_Vtbl)).TraceOperationCreation)(this, tracelevel, source, platformid, operationid, operationname, relatedcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn TraceOperationCompletion(this: *mut core::ffi::c_void, tracelevel: CausalityTraceLevel, source: CausalitySource, platformid: windows_core::GUID, operationid: u64, status: super::AsyncStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncCausalityTracerStatics", "TraceOperationCompletion", 7usize, |this| ((**(this as *const *const IAsyncCausalityTracerStatics_Vtbl)).TraceOperationCompletion)(this, tracelevel, source, platformid, operationid, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn TraceOperationRelation(this: *mut core::ffi::c_void, tracelevel: CausalityTraceLevel, source: CausalitySource, platformid: windows_core::GUID, operationid: u64, relation: CausalityRelation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncCausalityTracerStatics", "TraceOperationRelation", 8usize, |this| ((**(this as *const *const IAsyncCausalityTracerStatics_Vtbl)).TraceOperationRelation)(this, tracelevel, source, platformid, operationid, relation), |result| Some(*result))
+        }
+        unsafe extern "system" fn TraceSynchronousWorkStart(this: *mut core::ffi::c_void, tracelevel: CausalityTraceLevel, source: CausalitySource, platformid: windows_core::GUID, operationid: u64, work: CausalitySynchronousWork) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncCausalityTracerStatics", "TraceSynchronousWorkStart", 9usize, |this| ((**(this as *const *const IAsyncCausalityTracerStatics_Vtbl)).TraceSynchronousWorkStart)(this, tracelevel, source, platformid, operationid, work), |result| Some(*result))
+        }
+        unsafe extern "system" fn TraceSynchronousWorkCompletion(this: *mut core::ffi::c_void, tracelevel: CausalityTraceLevel, source: CausalitySource, work: CausalitySynchronousWork) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncCausalityTracerStatics", "TraceSynchronousWorkCompletion", 10usize, |this| ((**(this as *const *const IAsyncCausalityTracerStatics_Vtbl)).TraceSynchronousWorkCompletion)(this, tracelevel, source, work), |result| Some(*result))
+        }
+        unsafe extern "system" fn TracingStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncCausalityTracerStatics", "TracingStatusChanged", 11usize, |this| ((**(this as *const *const IAsyncCausalityTracerStatics_Vtbl)).TracingStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTracingStatusChanged(this: *mut core::ffi::c_void, cookie: super::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncCausalityTracerStatics", "RemoveTracingStatusChanged", 12usize, |this| ((**(this as *const *const IAsyncCausalityTracerStatics_Vtbl)).RemoveTracingStatusChanged)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TraceOperationCreation,
+            TraceOperationCompletion,
+            TraceOperationRelation,
+            TraceSynchronousWorkStart,
+            TraceSynchronousWorkCompletion,
+            TracingStatusChanged,
+            RemoveTracingStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAsyncCausalityTracerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IErrorDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorDetails", "Description", 6usize, |this| ((**(this as *const *const IErrorDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LongDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorDetails", "LongDescription", 7usize, |this| ((**(this as *const *const IErrorDetails_Vtbl)).LongDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HelpUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorDetails", "HelpUri", 8usize, |this| ((**(this as *const *const IErrorDetails_Vtbl)).HelpUri)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Description, LongDescription, HelpUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IErrorDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IErrorDetailsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromHResultAsync(this: *mut core::ffi::c_void, errorcode: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorDetailsStatics", "CreateFromHResultAsync", 6usize, |this| ((**(this as *const *const IErrorDetailsStatics_Vtbl)).CreateFromHResultAsync)(this, errorcode, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromHResultAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IErrorDetailsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IErrorReportingSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetErrorOptions(this: *mut core::ffi::c_void, value: ErrorOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorReportingSettings", "SetErrorOptions", 6usize, |this| ((**(this as *const *const IErrorReportingSettings_Vtbl)).SetErrorOptions)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorOptions(this: *mut core::ffi::c_void, result__: *mut ErrorOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorReportingSettings", "GetErrorOptions", 7usize, |this| ((**(this as *const *const IErrorReportingSettings_Vtbl)).GetErrorOptions)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetErrorOptions, GetErrorOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IErrorReportingSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IFileLoggingSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileLoggingSession", "Name", 6usize, |this| ((**(this as *const *const IFileLoggingSession_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddLoggingChannel(this: *mut core::ffi::c_void, loggingchannel: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileLoggingSession", "AddLoggingChannel", 7usize, |this| ((**(this as *const *const IFileLoggingSession_Vtbl)).AddLoggingChannel)(this, loggingchannel), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddLoggingChannelWithLevel(this: *mut core::ffi::c_void, loggingchannel: *mut core::ffi::c_void, maxlevel: LoggingLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileLoggingSession", "AddLoggingChannelWithLevel", 8usize, |this| ((**(this as *const *const IFileLoggingSession_Vtbl)).AddLoggingChannelWithLevel)(this, loggingchannel, maxlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLoggingChannel(this: *mut core::ffi::c_void, loggingchannel: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileLoggingSession", "RemoveLoggingChannel", 9usize, |this| ((**(this as *const *const IFileLoggingSession_Vtbl)).RemoveLoggingChannel)(this, loggingchannel), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CloseAndSaveToFileAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileLoggingSession", "CloseAndSaveToFileAsync", 10usize, |this| ((**(this as *const *const IFileLoggingSession_Vtbl)).CloseAndSaveToFileAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogFileGenerated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileLoggingSession", "LogFileGenerated", 11usize, |this| ((**(this as *const *const IFileLoggingSession_Vtbl)).LogFileGenerated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLogFileGenerated(this: *mut core::ffi::c_void, token: super::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileLoggingSession", "RemoveLogFileGenerated", 12usize, |this| ((**(this as *const *const IFileLoggingSession_Vtbl)).RemoveLogFileGenerated)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            AddLoggingChannel,
+            AddLoggingChannelWithLevel,
+            RemoveLoggingChannel,
+            #[cfg(feature = "Storage")]
+            CloseAndSaveToFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            CloseAndSaveToFileAsync: 0,
+            LogFileGenerated,
+            RemoveLogFileGenerated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileLoggingSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileLoggingSessionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileLoggingSessionFactory", "Create", 6usize, |this| ((**(this as *const *const IFileLoggingSessionFactory_Vtbl)).Create)(this, name, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileLoggingSessionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for ILogFileGeneratedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn File(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILogFileGeneratedEventArgs", "File", 6usize, |this| ((**(this as *const *const ILogFileGeneratedEventArgs_Vtbl)).File)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            File,
+            #[cfg(not(feature = "Storage"))]
+            File: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILogFileGeneratedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILoggingActivity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingActivity", "Name", 6usize, |this| ((**(this as *const *const ILoggingActivity_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingActivity", "Id", 7usize, |this| ((**(this as *const *const ILoggingActivity_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, Id }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoggingActivity as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILoggingActivity2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Channel(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingActivity2", "Channel", 6usize, |this| ((**(this as *const *const ILoggingActivity2_Vtbl)).Channel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopActivity(this: *mut core::ffi::c_void, stopeventname: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingActivity2", "StopActivity", 7usize, |this| ((**(this as *const *const ILoggingActivity2_Vtbl)).StopActivity)(this, stopeventname), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopActivityWithFields(this: *mut core::ffi::c_void, stopeventname: core::mem::MaybeUninit<windows_core::HSTRING>, fields: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingActivity2", "StopActivityWithFields", 8usize, |this| ((**(this as *const *const ILoggingActivity2_Vtbl)).StopActivityWithFields)(this, stopeventname, fields), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopActivityWithFieldsAndOptions(this: *mut core::ffi::c_void, stopeventname: core::mem::MaybeUninit<windows_core::HSTRING>, fields: *mut core::ffi::c_void, options: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingActivity2", "StopActivityWithFieldsAndOptions", 9usize, |this| ((**(this as *const *const ILoggingActivity2_Vtbl)).StopActivityWithFieldsAndOptions)(this, stopeventname, fields, options), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Channel,
+            StopActivity,
+            StopActivityWithFields,
+            StopActivityWithFieldsAndOptions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoggingActivity2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILoggingActivityFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateLoggingActivity(this: *mut core::ffi::c_void, activityname: core::mem::MaybeUninit<windows_core::HSTRING>, loggingchannel: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingActivityFactory", "CreateLoggingActivity", 6usize, |this| ((**(this as *const *const ILoggingActivityFactory_Vtbl)).CreateLoggingActivity)(this, activityname, loggingchannel, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLoggingActivityWithLevel(this: *mut core::ffi::c_void, activityname: core::mem::MaybeUninit<windows_core::HSTRING>, loggingchannel: *mut core::ffi::c_void, level: LoggingLevel, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingActivityFactory", "CreateLoggingActivityWithLevel", 7usize, |this| ((**(this as *const *const ILoggingActivityFactory_Vtbl)).CreateLoggingActivityWithLevel)(this, activityname, loggingchannel, level, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateLoggingActivity, CreateLoggingActivityWithLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoggingActivityFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILoggingChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannel", "Name", 6usize, |this| ((**(this as *const *const ILoggingChannel_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannel", "Enabled", 7usize, |this| ((**(this as *const *const ILoggingChannel_Vtbl)).Enabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Level(this: *mut core::ffi::c_void, result__: *mut LoggingLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannel", "Level", 8usize, |this| ((**(this as *const *const ILoggingChannel_Vtbl)).Level)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogMessage(this: *mut core::ffi::c_void, eventstring: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannel", "LogMessage", 9usize, |this| ((**(this as *const *const ILoggingChannel_Vtbl)).LogMessage)(this, eventstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogMessageWithLevel(this: *mut core::ffi::c_void, eventstring: core::mem::MaybeUninit<windows_core::HSTRING>, level: LoggingLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannel", "LogMessageWithLevel", 10usize, |this| ((**(this as *const *const ILoggingChannel_Vtbl)).LogMessageWithLevel)(this, eventstring, level), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogValuePair(this: *mut core::ffi::c_void, value1: core::mem::MaybeUninit<windows_core::HSTRING>, value2: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannel", "LogValuePair", 11usize, |this| ((**(this as *const *const ILoggingChannel_Vtbl)).LogValuePair)(this, value1, value2), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogValuePairWithLevel(this: *mut core::ffi::c_void, value1: core::mem::MaybeUninit<windows_core::HSTRING>, value2: i32, level: LoggingLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannel", "LogValuePairWithLevel", 12usize, |this| ((**(this as *const *const ILoggingChannel_Vtbl)).LogValuePairWithLevel)(this, value1, value2, level), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoggingEnabled(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannel", "LoggingEnabled", 13usize, |this| ((**(this as *const *const ILoggingChannel_Vtbl)).LoggingEnabled)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLoggingEnabled(this: *mut core::ffi::c_void, token: super::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannel", "RemoveLoggingEnabled", 14usize, |this| ((**(this as *const *const ILoggingChannel_Vtbl)).RemoveLoggingEnabled)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Enabled,
+            Level,
+            LogMessage,
+            LogMessageWithLevel,
+            LogValuePair,
+            LogValuePairWithLevel,
+            LoggingEnabled,
+            RemoveLoggingEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoggingChannel as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILoggingChannel2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannel2", "Id", 6usize, |this| ((**(this as *const *const ILoggingChannel2_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoggingChannel2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILoggingChannelFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannelFactory", "Create", 6usize, |this| ((**(this as *const *const ILoggingChannelFactory_Vtbl)).Create)(this, name, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Create,
+            #[cfg(not(feature = "deprecated"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoggingChannelFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILoggingChannelFactory2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithOptions(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannelFactory2", "CreateWithOptions", 6usize, |this| ((**(this as *const *const ILoggingChannelFactory2_Vtbl)).CreateWithOptions)(this, name, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithOptionsAndId(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, options: *mut core::ffi::c_void, id: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannelFactory2", "CreateWithOptionsAndId", 7usize, |this| ((**(this as *const *const ILoggingChannelFactory2_Vtbl)).CreateWithOptionsAndId)(this, name, options, id, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithOptions, CreateWithOptionsAndId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoggingChannelFactory2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILoggingChannelOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Group(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannelOptions", "Group", 6usize, |this| ((**(this as *const *const ILoggingChannelOptions_Vtbl)).Group)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroup(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannelOptions", "SetGroup", 7usize, |this| ((**(this as *const *const ILoggingChannelOptions_Vtbl)).SetGroup)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Group, SetGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoggingChannelOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILoggingChannelOptionsFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, group: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingChannelOptionsFactory", "Create", 6usize, |this| ((**(this as *const *const ILoggingChannelOptionsFactory_Vtbl)).Create)(this, group, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoggingChannelOptionsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILoggingFields_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "Clear", 6usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginStruct(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "BeginStruct", 7usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).BeginStruct)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginStructWithTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "BeginStructWithTags", 8usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).BeginStructWithTags)(this, name, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndStruct(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "EndStruct", 9usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).EndStruct)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEmpty(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddEmpty", 10usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddEmpty)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEmptyWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddEmptyWithFormat", 11usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddEmptyWithFormat)(this, name, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEmptyWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddEmptyWithFormatAndTags", 12usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddEmptyWithFormatAndTags)(this, name, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt8(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt8", 13usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt8)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt8WithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: u8, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt8WithFormat", 14usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt8WithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt8WithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: u8, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt8WithFormatAndTags", 15usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt8WithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt8Array(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt8Array", 16usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt8Array)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt8ArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const u8, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt8ArrayWithFormat", 17usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt8ArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt8ArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const u8, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt8ArrayWithFormatAndTags", 18usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt8ArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt16(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt16", 19usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt16)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt16WithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: i16, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt16WithFormat", 20usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt16WithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt16WithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: i16, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt16WithFormatAndTags", 21usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt16WithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt16Array(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt16Array", 22usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt16Array)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt16ArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const i16, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt16ArrayWithFormat", 23usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt16ArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt16ArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const i16, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt16ArrayWithFormatAndTags", 24usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt16ArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt16(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt16", 25usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt16)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt16WithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: u16, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt16WithFormat", 26usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt16WithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt16WithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: u16, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt16WithFormatAndTags", 27usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt16WithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt16Array(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt16Array", 28usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt16Array)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt16ArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const u16, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt16ArrayWithFormat", 29usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt16ArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt16ArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const u16, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt16ArrayWithFormatAndTags", 30usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt16ArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt32(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt32", 31usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt32)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt32WithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: i32, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt32WithFormat", 32usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt32WithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt32WithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: i32, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt32WithFormatAndTags", 33usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt32WithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt32Array(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt32Array", 34usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt32Array)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt32ArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const i32, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt32ArrayWithFormat", 35usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt32ArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt32ArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const i32, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt32ArrayWithFormatAndTags", 36usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt32ArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt32(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt32", 37usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt32)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt32WithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: u32, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt32WithFormat", 38usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt32WithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt32WithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: u32, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt32WithFormatAndTags", 39usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt32WithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt32Array(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt32Array", 40usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt32Array)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt32ArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const u32, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt32ArrayWithFormat", 41usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt32ArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt32ArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const u32, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt32ArrayWithFormatAndTags", 42usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt32ArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt64(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt64", 43usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt64)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt64WithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: i64, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt64WithFormat", 44usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt64WithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt64WithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: i64, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt64WithFormatAndTags", 45usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt64WithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt64Array(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt64Array", 46usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt64Array)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt64ArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const i64, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt64ArrayWithFormat", 47usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt64ArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt64ArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const i64, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddInt64ArrayWithFormatAndTags", 48usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddInt64ArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt64(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt64", 49usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt64)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt64WithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: u64, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt64WithFormat", 50usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt64WithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt64WithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: u64, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt64WithFormatAndTags", 51usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt64WithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt64Array(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt64Array", 52usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt64Array)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt64ArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const u64, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt64ArrayWithFormat", 53usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt64ArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUInt64ArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const u64, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddUInt64ArrayWithFormatAndTags", 54usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddUInt64ArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSingle(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddSingle", 55usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddSingle)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSingleWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: f32, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddSingleWithFormat", 56usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddSingleWithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSingleWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: f32, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddSingleWithFormatAndTags", 57usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddSingleWithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSingleArray(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddSingleArray", 58usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddSingleArray)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSingleArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const f32, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddSingleArrayWithFormat", 59usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddSingleArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSingleArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const f32, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddSingleArrayWithFormatAndTags", 60usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddSingleArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDouble(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddDouble", 61usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddDouble)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDoubleWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: f64, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddDoubleWithFormat", 62usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddDoubleWithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDoubleWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: f64, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddDoubleWithFormatAndTags", 63usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddDoubleWithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDoubleArray(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddDoubleArray", 64usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddDoubleArray)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDoubleArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const f64, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddDoubleArrayWithFormat", 65usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddDoubleArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDoubleArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const f64, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddDoubleArrayWithFormatAndTags", 66usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddDoubleArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddChar16(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddChar16", 67usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddChar16)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddChar16WithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: u16, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddChar16WithFormat", 68usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddChar16WithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddChar16WithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: u16, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddChar16WithFormatAndTags", 69usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddChar16WithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddChar16Array(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddChar16Array", 70usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddChar16Array)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddChar16ArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const u16, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddChar16ArrayWithFormat", 71usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddChar16ArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddChar16ArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const u16, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddChar16ArrayWithFormatAndTags", 72usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddChar16ArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddBoolean(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddBoolean", 73usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddBoolean)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddBooleanWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: bool, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddBooleanWithFormat", 74usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddBooleanWithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddBooleanWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: bool, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddBooleanWithFormatAndTags", 75usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddBooleanWithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddBooleanArray(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddBooleanArray", 76usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddBooleanArray)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddBooleanArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const bool, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddBooleanArrayWithFormat", 77usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddBooleanArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddBooleanArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const bool, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddBooleanArrayWithFormatAndTags", 78usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddBooleanArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddString(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddString", 79usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddString)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStringWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddStringWithFormat", 80usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddStringWithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStringWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddStringWithFormatAndTags", 81usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddStringWithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStringArray(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddStringArray", 82usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddStringArray)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStringArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const core::mem::MaybeUninit<windows_core::HSTRING>, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddStringArrayWithFormat", 83usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddStringArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStringArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const core::mem::MaybeUninit<windows_core::HSTRING>, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddStringArrayWithFormatAndTags", 84usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddStringArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddGuid(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddGuid", 85usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddGuid)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddGuidWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: windows_core::GUID, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddGuidWithFormat", 86usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddGuidWithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddGuidWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: windows_core::GUID, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddGuidWithFormatAndTags", 87usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddGuidWithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddGuidArray(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddGuidArray", 88usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddGuidArray)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddGuidArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const windows_core::GUID, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddGuidArrayWithFormat", 89usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddGuidArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddGuidArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const windows_core::GUID, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddGuidArrayWithFormatAndTags", 90usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddGuidArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDateTime(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddDateTime", 91usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddDateTime)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDateTimeWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::DateTime, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddDateTimeWithFormat", 92usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddDateTimeWithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDateTimeWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::DateTime, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddDateTimeWithFormatAndTags", 93usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddDateTimeWithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDateTimeArray(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const super::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddDateTimeArray", 94usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddDateTimeArray)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDateTimeArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const super::DateTime, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddDateTimeArrayWithFormat", 95usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddDateTimeArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDateTimeArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const super::DateTime, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddDateTimeArrayWithFormatAndTags", 96usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddDateTimeArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTimeSpan(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddTimeSpan", 97usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddTimeSpan)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTimeSpanWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::TimeSpan, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddTimeSpanWithFormat", 98usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddTimeSpanWithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTimeSpanWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::TimeSpan, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddTimeSpanWithFormatAndTags", 99usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddTimeSpanWithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTimeSpanArray(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const super::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddTimeSpanArray", 100usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddTimeSpanArray)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTimeSpanArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const super::TimeSpan, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddTimeSpanArrayWithFormat", 101usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddTimeSpanArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTimeSpanArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const super::TimeSpan, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddTimeSpanArrayWithFormatAndTags", 102usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddTimeSpanArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPoint(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddPoint", 103usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddPoint)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPointWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::Point, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddPointWithFormat", 104usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddPointWithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPointWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::Point, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddPointWithFormatAndTags", 105usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddPointWithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPointArray(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const super::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddPointArray", 106usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddPointArray)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPointArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const super::Point, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddPointArrayWithFormat", 107usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddPointArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPointArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const super::Point, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddPointArrayWithFormatAndTags", 108usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddPointArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSize(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddSize", 109usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddSize)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSizeWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::Size, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddSizeWithFormat", 110usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddSizeWithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSizeWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::Size, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddSizeWithFormatAndTags", 111usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddSizeWithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSizeArray(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const super::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddSizeArray", 112usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddSizeArray)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSizeArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const super::Size, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddSizeArrayWithFormat", 113usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddSizeArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSizeArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const super::Size, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddSizeArrayWithFormatAndTags", 114usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddSizeArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRect(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddRect", 115usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddRect)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRectWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::Rect, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddRectWithFormat", 116usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddRectWithFormat)(this, name, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRectWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::Rect, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddRectWithFormatAndTags", 117usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddRectWithFormatAndTags)(this, name, value, format, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRectArray(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const super::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddRectArray", 118usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddRectArray)(this, name, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRectArrayWithFormat(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const super::Rect, format: LoggingFieldFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddRectArrayWithFormat", 119usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddRectArrayWithFormat)(this, name, value_array_size, value, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRectArrayWithFormatAndTags(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value_array_size: u32, value: *const super::Rect, format: LoggingFieldFormat, tags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingFields", "AddRectArrayWithFormatAndTags", 120usize, |this| ((**(this as *const *const ILoggingFields_Vtbl)).AddRectArrayWithFormatAndTags)(this, name, value_array_size, value, format, tags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Clear,
+            BeginStruct,
+            BeginStructWithTags,
+            EndStruct,
+            AddEmpty,
+            AddEmptyWithFormat,
+            AddEmptyWithFormatAndTags,
+            AddUInt8,
+            AddUInt8WithFormat,
+            AddUInt8WithFormatAndTags,
+            AddUInt8Array,
+            AddUInt8ArrayWithFormat,
+            AddUInt8ArrayWithFormatAndTags,
+            AddInt16,
+            AddInt16WithFormat,
+            AddInt16WithFormatAndTags,
+            AddInt16Array,
+            AddInt16ArrayWithFormat,
+            AddInt16ArrayWithFormatAndTags,
+            AddUInt16,
+            AddUInt16WithFormat,
+            AddUInt16WithFormatAndTags,
+            AddUInt16Array,
+            AddUInt16ArrayWithFormat,
+            AddUInt16ArrayWithFormatAndTags,
+            AddInt32,
+            AddInt32WithFormat,
+            AddInt32WithFormatAndTags,
+            AddInt32Array,
+            AddInt32ArrayWithFormat,
+            AddInt32ArrayWithFormatAndTags,
+            AddUInt32,
+            AddUInt32WithFormat,
+            AddUInt32WithFormatAndTags,
+            AddUInt32Array,
+            AddUInt32ArrayWithFormat,
+            AddUInt32ArrayWithFormatAndTags,
+            AddInt64,
+            AddInt64WithFormat,
+            AddInt64WithFormatAndTags,
+            AddInt64Array,
+            AddInt64ArrayWithFormat,
+            AddInt64ArrayWithFormatAndTags,
+            AddUInt64,
+            AddUInt64WithFormat,
+            AddUInt64WithFormatAndTags,
+            AddUInt64Array,
+            AddUInt64ArrayWithFormat,
+            AddUInt64ArrayWithFormatAndTags,
+            AddSingle,
+            AddSingleWithFormat,
+            AddSingleWithFormatAndTags,
+            AddSingleArray,
+            AddSingleArrayWithFormat,
+            AddSingleArrayWithFormatAndTags,
+            AddDouble,
+            AddDoubleWithFormat,
+            AddDoubleWithFormatAndTags,
+            AddDoubleArray,
+            AddDoubleArrayWithFormat,
+            AddDoubleArrayWithFormatAndTags,
+            AddChar16,
+            AddChar16WithFormat,
+            AddChar16WithFormatAndTags,
+            AddChar16Array,
+            AddChar16ArrayWithFormat,
+            AddChar16ArrayWithFormatAndTags,
+            AddBoolean,
+            AddBooleanWithFormat,
+            AddBooleanWithFormatAndTags,
+            AddBooleanArray,
+            AddBooleanArrayWithFormat,
+            AddBooleanArrayWithFormatAndTags,
+            AddString,
+            AddStringWithFormat,
+            AddStringWithFormatAndTags,
+            AddStringArray,
+            AddStringArrayWithFormat,
+            AddStringArrayWithFormatAndTags,
+            AddGuid,
+            AddGuidWithFormat,
+            AddGuidWithFormatAndTags,
+            AddGuidArray,
+            AddGuidArrayWithFormat,
+            AddGuidArrayWithFormatAndTags,
+            AddDateTime,
+            AddDateTimeWithFormat,
+            AddDateTimeWithFormatAndTags,
+            AddDateTimeArray,
+            AddDateTimeArrayWithFormat,
+            AddDateTimeArrayWithFormatAndTags,
+            AddTimeSpan,
+            AddTimeSpanWithFormat,
+            AddTimeSpanWithFormatAndTags,
+            AddTimeSpanArray,
+            AddTimeSpanArrayWithFormat,
+            AddTimeSpanArrayWithFormatAndTags,
+            AddPoint,
+            AddPointWithFormat,
+            AddPointWithFormatAndTags,
+            AddPointArray,
+            AddPointArrayWithFormat,
+            AddPointArrayWithFormatAndTags,
+            AddSize,
+            AddSizeWithFormat,
+            AddSizeWithFormatAndTags,
+            AddSizeArray,
+            AddSizeArrayWithFormat,
+            AddSizeArrayWithFormatAndTags,
+            AddRect,
+            AddRectWithFormat,
+            AddRectWithFormatAndTags,
+            AddRectArray,
+            AddRectArrayWithFormat,
+            AddRectArrayWithFormatAndTags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoggingFields as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILoggingOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Keywords(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingOptions", "Keywords", 6usize, |this| ((**(this as *const *const ILoggingOptions_Vtbl)).Keywords)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeywords(this: *mut core::ffi::c_void, value: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingOptions", "SetKeywords", 7usize, |this| ((**(this as *const *const ILoggingOptions_Vtbl)).SetKeywords)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tags(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingOptions", "Tags", 8usize, |this| ((**(this as *const *const ILoggingOptions_Vtbl)).Tags)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTags(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingOptions", "SetTags", 9usize, |this| ((**(this as *const *const ILoggingOptions_Vtbl)).SetTags)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Task(this: *mut core::ffi::c_void, result__: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingOptions", "Task", 10usize, |this| ((**(this as *const *const ILoggingOptions_Vtbl)).Task)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTask(this: *mut core::ffi::c_void, value: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingOptions", "SetTask", 11usize, |this| ((**(this as *const *const ILoggingOptions_Vtbl)).SetTask)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Opcode(this: *mut core::ffi::c_void, result__: *mut LoggingOpcode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingOptions", "Opcode", 12usize, |this| ((**(this as *const *const ILoggingOptions_Vtbl)).Opcode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpcode(this: *mut core::ffi::c_void, value: LoggingOpcode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingOptions", "SetOpcode", 13usize, |this| ((**(this as *const *const ILoggingOptions_Vtbl)).SetOpcode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivityId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingOptions", "ActivityId", 14usize, |this| ((**(this as *const *const ILoggingOptions_Vtbl)).ActivityId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActivityId(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingOptions", "SetActivityId", 15usize, |this| ((**(this as *const *const ILoggingOptions_Vtbl)).SetActivityId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RelatedActivityId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingOptions", "RelatedActivityId", 16usize, |this| ((**(this as *const *const ILoggingOptions_Vtbl)).RelatedActivityId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRelatedActivityId(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingOptions", "SetRelatedActivityId", 17usize, |this| ((**(this as *const *const ILoggingOptions_Vtbl)).SetRelatedActivityId)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Keywords,
+            SetKeywords,
+            Tags,
+            SetTags,
+            Task,
+            SetTask,
+            Opcode,
+            SetOpcode,
+            ActivityId,
+            SetActivityId,
+            RelatedActivityId,
+            SetRelatedActivityId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoggingOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILoggingOptionsFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithKeywords(this: *mut core::ffi::c_void, keywords: i64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingOptionsFactory", "CreateWithKeywords", 6usize, |this| ((**(this as *const *const ILoggingOptionsFactory_Vtbl)).CreateWithKeywords)(this, keywords, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithKeywords }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoggingOptionsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for ILoggingSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingSession", "Name", 6usize, |this| ((**(this as *const *const ILoggingSession_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SaveToFileAsync(this: *mut core::ffi::c_void, folder: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingSession", "SaveToFileAsync", 7usize, |this| ((**(this as *const *const ILoggingSession_Vtbl)).SaveToFileAsync)(this, folder, filename, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddLoggingChannel(this: *mut core::ffi::c_void, loggingchannel: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingSession", "AddLoggingChannel", 8usize, |this| ((**(this as *const *const ILoggingSession_Vtbl)).AddLoggingChannel)(this, loggingchannel), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddLoggingChannelWithLevel(this: *mut core::ffi::c_void, loggingchannel: *mut core::ffi::c_void, maxlevel: LoggingLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingSession", "AddLoggingChannelWithLevel", 9usize, |this| ((**(this as *const *const ILoggingSession_Vtbl)).AddLoggingChannelWithLevel)(this, loggingchannel, maxlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLoggingChannel(this: *mut core::ffi::c_void, loggingchannel: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingSession", "RemoveLoggingChannel", 10usize, |this| ((**(this as *const *const ILoggingSession_Vtbl)).RemoveLoggingChannel)(this, loggingchannel), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            #[cfg(feature = "Storage")]
+            SaveToFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            SaveToFileAsync: 0,
+            AddLoggingChannel,
+            AddLoggingChannelWithLevel,
+            RemoveLoggingChannel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoggingSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILoggingSessionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingSessionFactory", "Create", 6usize, |this| ((**(this as *const *const ILoggingSessionFactory_Vtbl)).Create)(this, name, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoggingSessionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILoggingTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingTarget", "IsEnabled", 6usize, |this| ((**(this as *const *const ILoggingTarget_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabledWithLevel(this: *mut core::ffi::c_void, level: LoggingLevel, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingTarget", "IsEnabledWithLevel", 7usize, |this| ((**(this as *const *const ILoggingTarget_Vtbl)).IsEnabledWithLevel)(this, level, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabledWithLevelAndKeywords(this: *mut core::ffi::c_void, level: LoggingLevel, keywords: i64, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingTarget", "IsEnabledWithLevelAndKeywords", 8usize, |this| ((**(this as *const *const ILoggingTarget_Vtbl)).IsEnabledWithLevelAndKeywords)(this, level, keywords, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogEvent(this: *mut core::ffi::c_void, eventname: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingTarget", "LogEvent", 9usize, |this| ((**(this as *const *const ILoggingTarget_Vtbl)).LogEvent)(this, eventname), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogEventWithFields(this: *mut core::ffi::c_void, eventname: core::mem::MaybeUninit<windows_core::HSTRING>, fields: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingTarget", "LogEventWithFields", 10usize, |this| ((**(this as *const *const ILoggingTarget_Vtbl)).LogEventWithFields)(this, eventname, fields), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogEventWithFieldsAndLevel(this: *mut core::ffi::c_void, eventname: core::mem::MaybeUninit<windows_core::HSTRING>, fields: *mut core::ffi::c_void, level: LoggingLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingTarget", "LogEventWithFieldsAndLevel", 11usize, |this| ((**(this as *const *const ILoggingTarget_Vtbl)).LogEventWithFieldsAndLevel)(this, eventname, fields, level), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogEventWithFieldsAndOptions(this: *mut core::ffi::c_void, eventname: core::mem::MaybeUninit<windows_core::HSTRING>, fields: *mut core::ffi::c_void, level: LoggingLevel, options: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingTarget", "LogEventWithFieldsAndOptions", 12usize, |this| ((**(this as *const *const ILoggingTarget_Vtbl)).LogEventWithFieldsAndOptions)(this, eventname, fields, level, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartActivity(this: *mut core::ffi::c_void, starteventname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingTarget", "StartActivity", 13usize, |this| ((**(this as *const *const ILoggingTarget_Vtbl)).StartActivity)(this, starteventname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartActivityWithFields(this: *mut core::ffi::c_void, starteventname: core::mem::MaybeUninit<windows_core::HSTRING>, fields: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingTarget", "StartActivityWithFields", 14usize, |this| ((**(this as *const *const ILoggingTarget_Vtbl)).StartActivityWithFields)(this, starteventname, fields, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartActivityWithFieldsAndLevel(this: *mut core::ffi::c_void, starteventname: core::mem::MaybeUninit<windows_core::HSTRING>, fields: *mut core::ffi::c_void, level: LoggingLevel, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingTarget", "StartActivityWithFieldsAndLevel", 15usize, |this| ((**(this as *const *const ILoggingTarget_Vtbl)).StartActivityWithFieldsAndLevel)(this, starteventname, fields, level, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartActivityWithFieldsAndOptions(this: *mut core::ffi::c_void, starteventname: core::mem::MaybeUninit<windows_core::HSTRING>, fields: *mut core::ffi::c_void, level: LoggingLevel, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoggingTarget", "StartActivityWithFieldsAndOptions", 16usize, |this| ((**(this as *const *const ILoggingTarget_Vtbl)).StartActivityWithFieldsAndOptions)(this, starteventname, fields, level, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEnabled,
+            IsEnabledWithLevel,
+            IsEnabledWithLevelAndKeywords,
+            LogEvent,
+            LogEventWithFields,
+            LogEventWithFieldsAndLevel,
+            LogEventWithFieldsAndOptions,
+            StartActivity,
+            StartActivityWithFields,
+            StartActivityWithFieldsAndLevel,
+            StartActivityWithFieldsAndOptions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoggingTarget as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITracingStatusChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITracingStatusChangedEventArgs", "Enabled", 6usize, |this| ((**(this as *const *const ITracingStatusChangedEventArgs_Vtbl)).Enabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TraceLevel(this: *mut core::ffi::c_void, result__: *mut CausalityTraceLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITracingStatusChangedEventArgs", "TraceLevel", 7usize, |this| ((**(this as *const *const ITracingStatusChangedEventArgs_Vtbl)).TraceLevel)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Enabled, TraceLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITracingStatusChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
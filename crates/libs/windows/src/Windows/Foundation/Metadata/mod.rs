@@ -108,6 +108,7 @@ impl AttributeTargets {
 impl windows_core::TypeKind for AttributeTargets {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AttributeTargets {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AttributeTargets").field(&self.0).finish()
@@ -159,6 +160,7 @@ impl CompositionType {
 impl windows_core::TypeKind for CompositionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionType").field(&self.0).finish()
@@ -177,6 +179,7 @@ impl DeprecationType {
 impl windows_core::TypeKind for DeprecationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeprecationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeprecationType").field(&self.0).finish()
@@ -197,6 +200,7 @@ impl FeatureStage {
 impl windows_core::TypeKind for FeatureStage {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FeatureStage {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FeatureStage").field(&self.0).finish()
@@ -216,6 +220,7 @@ impl GCPressureAmount {
 impl windows_core::TypeKind for GCPressureAmount {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GCPressureAmount {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GCPressureAmount").field(&self.0).finish()
@@ -236,6 +241,7 @@ impl MarshalingType {
 impl windows_core::TypeKind for MarshalingType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MarshalingType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MarshalingType").field(&self.0).finish()
@@ -254,6 +260,7 @@ impl Platform {
 impl windows_core::TypeKind for Platform {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Platform {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Platform").field(&self.0).finish()
@@ -274,6 +281,7 @@ impl ThreadingModel {
 impl windows_core::TypeKind for ThreadingModel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ThreadingModel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ThreadingModel").field(&self.0).finish()
@@ -282,3 +290,5 @@ impl core::fmt::Debug for ThreadingModel {
 impl windows_core::RuntimeType for ThreadingModel {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Foundation.Metadata.ThreadingModel;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,51 @@
+impl windows_core::imp::ProxyVtable for IApiInformationStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTypePresent(this: *mut core::ffi::c_void, typename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiInformationStatics", "IsTypePresent", 6usize, |this| ((**(this as *const *const IApiInformationStatics_Vtbl)).IsTypePresent)(this, typename, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMethodPresent(this: *mut core::ffi::c_void, typename: core::mem::MaybeUninit<windows_core::HSTRING>, methodname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiInformationStatics", "IsMethodPresent", 7usize, |this| ((**(this as *const *const IApiInformationStatics_Vtbl)).IsMethodPresent)(this, typename, methodname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMethodPresentWithArity(this: *mut core::ffi::c_void, typename: core::mem::MaybeUninit<windows_core::HSTRING>, methodname: core::mem::MaybeUninit<windows_core::HSTRING>, inputparametercount: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiInformationStatics", "IsMethodPresentWithArity", 8usize, |this| ((**(this as *const *const IApiInformationStatics_Vtbl)).IsMethodPresentWithArity)(this, typename, methodname, inputparametercount, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEventPresent(this: *mut core::ffi::c_void, typename: core::mem::MaybeUninit<windows_core::HSTRING>, eventname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiInformationStatics", "IsEventPresent", 9usize, |this| ((**(this as *const *const IApiInformationStatics_Vtbl)).IsEventPresent)(this, typename, eventname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPropertyPresent(this: *mut core::ffi::c_void, typename: core::mem::MaybeUninit<windows_core::HSTRING>, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiInformationStatics", "IsPropertyPresent", 10usize, |this| ((**(this as *const *const IApiInformationStatics_Vtbl)).IsPropertyPresent)(this, typename, propertyname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReadOnlyPropertyPresent(this: *mut core::ffi::c_void, typename: core::mem::MaybeUninit<windows_core::HSTRING>, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiInformationStatics", "IsReadOnlyPropertyPresent", 11usize, |this| ((**(this as *const *const IApiInformationStatics_Vtbl)).IsReadOnlyPropertyPresent)(this, typename, propertyname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWriteablePropertyPresent(this: *mut core::ffi::c_void, typename: core::mem::MaybeUninit<windows_core::HSTRING>, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiInformationStatics", "IsWriteablePropertyPresent", 12usize, |this| ((**(this as *const *const IApiInformationStatics_Vtbl)).IsWriteablePropertyPresent)(this, typename, propertyname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnumNamedValuePresent(this: *mut core::ffi::c_void, enumtypename: core::mem::MaybeUninit<windows_core::HSTRING>, valuename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiInformationStatics", "IsEnumNamedValuePresent", 13usize, |this| ((**(this as *const *const IApiInformationStatics_Vtbl)).IsEnumNamedValuePresent)(this, enumtypename, valuename, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsApiContractPresentByMajor(this: *mut core::ffi::c_void, contractname: core::mem::MaybeUninit<windows_core::HSTRING>, majorversion: u16, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiInformationStatics", "IsApiContractPresentByMajor", 14usize, |this| ((**(this as *const *const IApiInformationStatics_Vtbl)).IsApiContractPresentByMajor)(this, contractname, majorversion, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsApiContractPresentByMajorAndMinor(this: *mut core::ffi::c_void, contractname: core::mem::MaybeUninit<windows_core::HSTRING>, majorversion: u16, minorversion: u16, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiInformationStatics", "IsApiContractPresentByMajorAndMinor", 15usize, |this| ((**(this as *const *const IApiInformationStatics_Vtbl)).IsApiContractPresentByMajorAndMinor)(this, contractname, majorversion, minorversion, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsTypePresent,
+            IsMethodPresent,
+            IsMethodPresentWithArity,
+            IsEventPresent,
+            IsPropertyPresent,
+            IsReadOnlyPropertyPresent,
+            IsWriteablePropertyPresent,
+            IsEnumNamedValuePresent,
+            IsApiContractPresentByMajor,
+            IsApiContractPresentByMajorAndMinor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApiInformationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
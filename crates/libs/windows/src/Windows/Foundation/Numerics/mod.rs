@@ -1,5 +1,6 @@
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Matrix3x2 {
     pub M11: f32,
     pub M12: f32,
@@ -20,7 +21,8 @@ impl Default for Matrix3x2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Matrix4x4 {
     pub M11: f32,
     pub M12: f32,
@@ -51,7 +53,8 @@ impl Default for Matrix4x4 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Plane {
     pub Normal: Vector3,
     pub D: f32,
@@ -68,7 +71,8 @@ impl Default for Plane {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Quaternion {
     pub X: f32,
     pub Y: f32,
@@ -87,7 +91,8 @@ impl Default for Quaternion {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Rational {
     pub Numerator: u32,
     pub Denominator: u32,
@@ -104,7 +109,8 @@ impl Default for Rational {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Vector2 {
     pub X: f32,
     pub Y: f32,
@@ -121,7 +127,8 @@ impl Default for Vector2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Vector3 {
     pub X: f32,
     pub Y: f32,
@@ -139,7 +146,8 @@ impl Default for Vector3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Vector4 {
     pub X: f32,
     pub Y: f32,
@@ -78,6 +78,27 @@ impl IAsyncAction {
         self.GetResults()
     }
 }
+#[cfg(feature = "std")]
+impl Async for IAsyncAction {
+    type Output = ();
+    fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetCompleted(&AsyncActionCompletedHandler::new(move |_sender, _args| {
+            handler();
+            Ok(())
+        }))
+    }
+    fn get_results(&self) -> windows_core::Result<Self::Output> {
+        self.GetResults()
+    }
+}
+#[cfg(feature = "std")]
+impl core::future::IntoFuture for IAsyncAction {
+    type Output = windows_core::Result<()>;
+    type IntoFuture = AsyncFuture<Self>;
+    fn into_future(self) -> Self::IntoFuture {
+        AsyncFuture::new(self)
+    }
+}
 unsafe impl Send for IAsyncAction {}
 unsafe impl Sync for IAsyncAction {}
 impl windows_core::RuntimeType for IAsyncAction {
@@ -183,6 +204,37 @@ impl<TProgress: windows_core::RuntimeType + 'static> IAsyncActionWithProgress<TP
         self.GetResults()
     }
 }
+#[cfg(feature = "std")]
+impl<TProgress: windows_core::RuntimeType + 'static> Async for IAsyncActionWithProgress<TProgress> {
+    type Output = ();
+    fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetCompleted(&AsyncActionWithProgressCompletedHandler::new(move |_sender, _args| {
+            handler();
+            Ok(())
+        }))
+    }
+    fn get_results(&self) -> windows_core::Result<Self::Output> {
+        self.GetResults()
+    }
+}
+#[cfg(feature = "std")]
+impl<TProgress: windows_core::RuntimeType + 'static> core::future::IntoFuture for IAsyncActionWithProgress<TProgress> {
+    type Output = windows_core::Result<()>;
+    type IntoFuture = AsyncFuture<Self>;
+    fn into_future(self) -> Self::IntoFuture {
+        AsyncFuture::new(self)
+    }
+}
+#[cfg(feature = "std")]
+impl<TProgress: windows_core::RuntimeType + 'static> AsyncWithProgress for IAsyncActionWithProgress<TProgress> {
+    type Progress = TProgress;
+    fn set_progress<F: Fn(Self::Progress) + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetProgress(&AsyncActionProgressHandler::new(move |_sender, value| {
+            handler(windows_core::Type::from_default(value)?);
+            Ok(())
+        }))
+    }
+}
 unsafe impl<TProgress: windows_core::RuntimeType + 'static> Send for IAsyncActionWithProgress<TProgress> {}
 unsafe impl<TProgress: windows_core::RuntimeType + 'static> Sync for IAsyncActionWithProgress<TProgress> {}
 impl<TProgress: windows_core::RuntimeType + 'static> windows_core::RuntimeType for IAsyncActionWithProgress<TProgress> {
@@ -338,6 +390,27 @@ impl<TResult: windows_core::RuntimeType + 'static> IAsyncOperation<TResult> {
         self.GetResults()
     }
 }
+#[cfg(feature = "std")]
+impl<TResult: windows_core::RuntimeType + 'static> Async for IAsyncOperation<TResult> {
+    type Output = TResult;
+    fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetCompleted(&AsyncOperationCompletedHandler::new(move |_sender, _args| {
+            handler();
+            Ok(())
+        }))
+    }
+    fn get_results(&self) -> windows_core::Result<Self::Output> {
+        self.GetResults()
+    }
+}
+#[cfg(feature = "std")]
+impl<TResult: windows_core::RuntimeType + 'static> core::future::IntoFuture for IAsyncOperation<TResult> {
+    type Output = windows_core::Result<TResult>;
+    type IntoFuture = AsyncFuture<Self>;
+    fn into_future(self) -> Self::IntoFuture {
+        AsyncFuture::new(self)
+    }
+}
 unsafe impl<TResult: windows_core::RuntimeType + 'static> Send for IAsyncOperation<TResult> {}
 unsafe impl<TResult: windows_core::RuntimeType + 'static> Sync for IAsyncOperation<TResult> {}
 impl<TResult: windows_core::RuntimeType + 'static> windows_core::RuntimeType for IAsyncOperation<TResult> {
@@ -455,6 +528,37 @@ impl<TResult: windows_core::RuntimeType + 'static, TProgress: windows_core::Runt
         self.GetResults()
     }
 }
+#[cfg(feature = "std")]
+impl<TResult: windows_core::RuntimeType + 'static, TProgress: windows_core::RuntimeType + 'static> Async for IAsyncOperationWithProgress<TResult, TProgress> {
+    type Output = TResult;
+    fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetCompleted(&AsyncOperationWithProgressCompletedHandler::new(move |_sender, _args| {
+            handler();
+            Ok(())
+        }))
+    }
+    fn get_results(&self) -> windows_core::Result<Self::Output> {
+        self.GetResults()
+    }
+}
+#[cfg(feature = "std")]
+impl<TResult: windows_core::RuntimeType + 'static, TProgress: windows_core::RuntimeType + 'static> core::future::IntoFuture for IAsyncOperationWithProgress<TResult, TProgress> {
+    type Output = windows_core::Result<TResult>;
+    type IntoFuture = AsyncFuture<Self>;
+    fn into_future(self) -> Self::IntoFuture {
+        AsyncFuture::new(self)
+    }
+}
+#[cfg(feature = "std")]
+impl<TResult: windows_core::RuntimeType + 'static, TProgress: windows_core::RuntimeType + 'static> AsyncWithProgress for IAsyncOperationWithProgress<TResult, TProgress> {
+    type Progress = TProgress;
+    fn set_progress<F: Fn(Self::Progress) + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetProgress(&AsyncOperationProgressHandler::new(move |_sender, value| {
+            handler(windows_core::Type::from_default(value)?);
+            Ok(())
+        }))
+    }
+}
 unsafe impl<TResult: windows_core::RuntimeType + 'static, TProgress: windows_core::RuntimeType + 'static> Send for IAsyncOperationWithProgress<TResult, TProgress> {}
 unsafe impl<TResult: windows_core::RuntimeType + 'static, TProgress: windows_core::RuntimeType + 'static> Sync for IAsyncOperationWithProgress<TResult, TProgress> {}
 impl<TResult: windows_core::RuntimeType + 'static, TProgress: windows_core::RuntimeType + 'static> windows_core::RuntimeType for IAsyncOperationWithProgress<TResult, TProgress> {
@@ -2301,6 +2405,7 @@ impl AsyncStatus {
 impl windows_core::TypeKind for AsyncStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AsyncStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AsyncStatus").field(&self.0).finish()
@@ -2358,6 +2463,7 @@ impl PropertyType {
 impl windows_core::TypeKind for PropertyType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PropertyType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PropertyType").field(&self.0).finish()
@@ -2367,7 +2473,8 @@ impl windows_core::RuntimeType for PropertyType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Foundation.PropertyType;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DateTime {
     pub UniversalTime: i64,
 }
@@ -2383,7 +2490,8 @@ impl Default for DateTime {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EventRegistrationToken {
     pub Value: i64,
 }
@@ -2399,7 +2507,8 @@ impl Default for EventRegistrationToken {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Point {
     pub X: f32,
     pub Y: f32,
@@ -2416,7 +2525,8 @@ impl Default for Point {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Rect {
     pub X: f32,
     pub Y: f32,
@@ -2435,7 +2545,8 @@ impl Default for Rect {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Size {
     pub Width: f32,
     pub Height: f32,
@@ -2452,7 +2563,8 @@ impl Default for Size {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TimeSpan {
     pub Duration: i64,
 }
@@ -3156,5 +3268,12 @@ where
     pub TSender: core::marker::PhantomData<TSender>,
     pub TResult: core::marker::PhantomData<TResult>,
 }
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Foundation/Async.rs"));
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Foundation/Deferral.rs"));
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Foundation/Deferrals.rs"));
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Foundation/EventStream.rs"));
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Foundation/PropertyValue.rs"));
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,660 @@
+impl windows_core::imp::ProxyVtable for IAsyncAction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncAction", "SetCompleted", 6usize, |this| ((**(this as *const *const IAsyncAction_Vtbl)).SetCompleted)(this, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn Completed(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncAction", "Completed", 7usize, |this| ((**(this as *const *const IAsyncAction_Vtbl)).Completed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResults(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncAction", "GetResults", 8usize, |this| ((**(this as *const *const IAsyncAction_Vtbl)).GetResults)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetCompleted, Completed, GetResults }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAsyncAction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAsyncInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncInfo", "Id", 6usize, |this| ((**(this as *const *const IAsyncInfo_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut AsyncStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncInfo", "Status", 7usize, |this| ((**(this as *const *const IAsyncInfo_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncInfo", "ErrorCode", 8usize, |this| ((**(this as *const *const IAsyncInfo_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncInfo", "Cancel", 9usize, |this| ((**(this as *const *const IAsyncInfo_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncInfo", "Close", 10usize, |this| ((**(this as *const *const IAsyncInfo_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Status, ErrorCode, Cancel, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAsyncInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClosable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosable", "Close", 6usize, |this| ((**(this as *const *const IClosable_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClosable as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeferral", "Complete", 6usize, |this| ((**(this as *const *const IDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeferralFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeferralFactory", "Create", 6usize, |this| ((**(this as *const *const IDeferralFactory_Vtbl)).Create)(this, handler, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeferralFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetActivationFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetActivationFactory(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetActivationFactory", "GetActivationFactory", 6usize, |this| ((**(this as *const *const IGetActivationFactory_Vtbl)).GetActivationFactory)(this, activatableclassid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetActivationFactory }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetActivationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGuidHelperStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateNewGuid(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidHelperStatics", "CreateNewGuid", 6usize, |this| ((**(this as *const *const IGuidHelperStatics_Vtbl)).CreateNewGuid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Empty(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidHelperStatics", "Empty", 7usize, |this| ((**(this as *const *const IGuidHelperStatics_Vtbl)).Empty)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Equals(this: *mut core::ffi::c_void, target: &windows_core::GUID, value: &windows_core::GUID, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidHelperStatics", "Equals", 8usize, |this| ((**(this as *const *const IGuidHelperStatics_Vtbl)).Equals)(this, target, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateNewGuid, Empty, Equals }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidHelperStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMemoryBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateReference(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryBuffer", "CreateReference", 6usize, |this| ((**(this as *const *const IMemoryBuffer_Vtbl)).CreateReference)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateReference }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMemoryBuffer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMemoryBufferFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, capacity: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryBufferFactory", "Create", 6usize, |this| ((**(this as *const *const IMemoryBufferFactory_Vtbl)).Create)(this, capacity, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMemoryBufferFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMemoryBufferReference_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Capacity(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryBufferReference", "Capacity", 6usize, |this| ((**(this as *const *const IMemoryBufferReference_Vtbl)).Capacity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryBufferReference", "Closed", 7usize, |this| ((**(this as *const *const IMemoryBufferReference_Vtbl)).Closed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, cookie: EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryBufferReference", "RemoveClosed", 8usize, |this| ((**(this as *const *const IMemoryBufferReference_Vtbl)).RemoveClosed)(this, cookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Capacity, Closed, RemoveClosed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMemoryBufferReference as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut PropertyType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "Type", 6usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNumericScalar(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "IsNumericScalar", 7usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).IsNumericScalar)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUInt8(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetUInt8", 8usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetUInt8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInt16(this: *mut core::ffi::c_void, result__: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetInt16", 9usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetInt16)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUInt16(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetUInt16", 10usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetUInt16)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInt32(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetInt32", 11usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetInt32)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUInt32(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetUInt32", 12usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetUInt32)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInt64(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetInt64", 13usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetInt64)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUInt64(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetUInt64", 14usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetUInt64)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSingle(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetSingle", 15usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetSingle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDouble(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetDouble", 16usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetDouble)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChar16(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetChar16", 17usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetChar16)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBoolean(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetBoolean", 18usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetBoolean)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetString", 19usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGuid(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetGuid", 20usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetGuid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDateTime(this: *mut core::ffi::c_void, result__: *mut DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetDateTime", 21usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetDateTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimeSpan(this: *mut core::ffi::c_void, result__: *mut TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetTimeSpan", 22usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetTimeSpan)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPoint(this: *mut core::ffi::c_void, result__: *mut Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetPoint", 23usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetPoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, result__: *mut Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetSize", 24usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRect(this: *mut core::ffi::c_void, result__: *mut Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetRect", 25usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetRect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUInt8Array(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetUInt8Array", 26usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetUInt8Array)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInt16Array(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetInt16Array", 27usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetInt16Array)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUInt16Array(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetUInt16Array", 28usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetUInt16Array)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInt32Array(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetInt32Array", 29usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetInt32Array)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUInt32Array(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetUInt32Array", 30usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetUInt32Array)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInt64Array(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetInt64Array", 31usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetInt64Array)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUInt64Array(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetUInt64Array", 32usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetUInt64Array)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSingleArray(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetSingleArray", 33usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetSingleArray)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDoubleArray(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetDoubleArray", 34usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetDoubleArray)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChar16Array(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetChar16Array", 35usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetChar16Array)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBooleanArray(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetBooleanArray", 36usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetBooleanArray)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringArray(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetStringArray", 37usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetStringArray)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInspectableArray(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetInspectableArray", 38usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetInspectableArray)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGuidArray(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetGuidArray", 39usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetGuidArray)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDateTimeArray(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetDateTimeArray", 40usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetDateTimeArray)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimeSpanArray(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetTimeSpanArray", 41usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetTimeSpanArray)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPointArray(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetPointArray", 42usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetPointArray)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSizeArray(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetSizeArray", 43usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetSizeArray)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRectArray(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValue", "GetRectArray", 44usize, |this| ((**(this as *const *const IPropertyValue_Vtbl)).GetRectArray)(this, value_array_size, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            IsNumericScalar,
+            GetUInt8,
+            GetInt16,
+            GetUInt16,
+            GetInt32,
+            GetUInt32,
+            GetInt64,
+            GetUInt64,
+            GetSingle,
+            GetDouble,
+            GetChar16,
+            GetBoolean,
+            GetString,
+            GetGuid,
+            GetDateTime,
+            GetTimeSpan,
+            GetPoint,
+            GetSize,
+            GetRect,
+            GetUInt8Array,
+            GetInt16Array,
+            GetUInt16Array,
+            GetInt32Array,
+            GetUInt32Array,
+            GetInt64Array,
+            GetUInt64Array,
+            GetSingleArray,
+            GetDoubleArray,
+            GetChar16Array,
+            GetBooleanArray,
+            GetStringArray,
+            GetInspectableArray,
+            GetGuidArray,
+            GetDateTimeArray,
+            GetTimeSpanArray,
+            GetPointArray,
+            GetSizeArray,
+            GetRectArray,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateEmpty(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateEmpty", 6usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateEmpty)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUInt8(this: *mut core::ffi::c_void, value: u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateUInt8", 7usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateUInt8)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInt16(this: *mut core::ffi::c_void, value: i16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateInt16", 8usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateInt16)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUInt16(this: *mut core::ffi::c_void, value: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateUInt16", 9usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateUInt16)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInt32(this: *mut core::ffi::c_void, value: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateInt32", 10usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateInt32)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUInt32(this: *mut core::ffi::c_void, value: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateUInt32", 11usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateUInt32)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInt64(this: *mut core::ffi::c_void, value: i64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateInt64", 12usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateInt64)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUInt64(this: *mut core::ffi::c_void, value: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateUInt64", 13usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateUInt64)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSingle(this: *mut core::ffi::c_void, value: f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateSingle", 14usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateSingle)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDouble(this: *mut core::ffi::c_void, value: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateDouble", 15usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateDouble)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateChar16(this: *mut core::ffi::c_void, value: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateChar16", 16usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateChar16)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBoolean(this: *mut core::ffi::c_void, value: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateBoolean", 17usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateBoolean)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateString(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateString", 18usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateString)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInspectable(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateInspectable", 19usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateInspectable)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGuid(this: *mut core::ffi::c_void, value: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateGuid", 20usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateGuid)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDateTime(this: *mut core::ffi::c_void, value: DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateDateTime", 21usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateDateTime)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTimeSpan(this: *mut core::ffi::c_void, value: TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateTimeSpan", 22usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateTimeSpan)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePoint(this: *mut core::ffi::c_void, value: Point, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreatePoint", 23usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreatePoint)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSize(this: *mut core::ffi::c_void, value: Size, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateSize", 24usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateSize)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRect(this: *mut core::ffi::c_void, value: Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateRect", 25usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateRect)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUInt8Array(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateUInt8Array", 26usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateUInt8Array)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInt16Array(this: *mut core::ffi::c_void, value_array_size: u32, value: *const i16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateInt16Array", 27usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateInt16Array)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUInt16Array(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateUInt16Array", 28usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateUInt16Array)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInt32Array(this: *mut core::ffi::c_void, value_array_size: u32, value: *const i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateInt32Array", 29usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateInt32Array)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUInt32Array(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateUInt32Array", 30usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateUInt32Array)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInt64Array(this: *mut core::ffi::c_void, value_array_size: u32, value: *const i64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateInt64Array", 31usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateInt64Array)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUInt64Array(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateUInt64Array", 32usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateUInt64Array)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSingleArray(this: *mut core::ffi::c_void, value_array_size: u32, value: *const f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateSingleArray", 33usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateSingleArray)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDoubleArray(this: *mut core::ffi::c_void, value_array_size: u32, value: *const f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateDoubleArray", 34usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateDoubleArray)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateChar16Array(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateChar16Array", 35usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateChar16Array)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBooleanArray(this: *mut core::ffi::c_void, value_array_size: u32, value: *const bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateBooleanArray", 36usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateBooleanArray)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStringArray(this: *mut core::ffi::c_void, value_array_size: u32, value: *const core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateStringArray", 37usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateStringArray)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInspectableArray(this: *mut core::ffi::c_void, value_array_size: u32, value: *const *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateInspectableArray", 38usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateInspectableArray)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGuidArray(this: *mut core::ffi::c_void, value_array_size: u32, value: *const windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateGuidArray", 39usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateGuidArray)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDateTimeArray(this: *mut core::ffi::c_void, value_array_size: u32, value: *const DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateDateTimeArray", 40usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateDateTimeArray)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTimeSpanArray(this: *mut core::ffi::c_void, value_array_size: u32, value: *const TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateTimeSpanArray", 41usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateTimeSpanArray)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePointArray(this: *mut core::ffi::c_void, value_array_size: u32, value: *const Point, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreatePointArray", 42usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreatePointArray)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSizeArray(this: *mut core::ffi::c_void, value_array_size: u32, value: *const Size, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateSizeArray", 43usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateSizeArray)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRectArray(this: *mut core::ffi::c_void, value_array_size: u32, value: *const Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyValueStatics", "CreateRectArray", 44usize, |this| ((**(this as *const *const IPropertyValueStatics_Vtbl)).CreateRectArray)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateEmpty,
+            CreateUInt8,
+            CreateInt16,
+            CreateUInt16,
+            CreateInt32,
+            CreateUInt32,
+            CreateInt64,
+            CreateUInt64,
+            CreateSingle,
+            CreateDouble,
+            CreateChar16,
+            CreateBoolean,
+            CreateString,
+            CreateInspectable,
+            CreateGuid,
+            CreateDateTime,
+            CreateTimeSpan,
+            CreatePoint,
+            CreateSize,
+            CreateRect,
+            CreateUInt8Array,
+            CreateInt16Array,
+            CreateUInt16Array,
+            CreateInt32Array,
+            CreateUInt32Array,
+            CreateInt64Array,
+            CreateUInt64Array,
+            CreateSingleArray,
+            CreateDoubleArray,
+            CreateChar16Array,
+            CreateBooleanArray,
+            CreateStringArray,
+            CreateInspectableArray,
+            CreateGuidArray,
+            CreateDateTimeArray,
+            CreateTimeSpanArray,
+            CreatePointArray,
+            CreateSizeArray,
+            CreateRectArray,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStringable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ToString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringable", "ToString", 6usize, |this| ((**(this as *const *const IStringable_Vtbl)).ToString)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ToString }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStringable as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUriEscapeStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UnescapeComponent(this: *mut core::ffi::c_void, tounescape: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriEscapeStatics", "UnescapeComponent", 6usize, |this| ((**(this as *const *const IUriEscapeStatics_Vtbl)).UnescapeComponent)(this, tounescape, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EscapeComponent(this: *mut core::ffi::c_void, toescape: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriEscapeStatics", "EscapeComponent", 7usize, |this| ((**(this as *const *const IUriEscapeStatics_Vtbl)).EscapeComponent)(this, toescape, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UnescapeComponent, EscapeComponent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUriEscapeStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUriRuntimeClass_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AbsoluteUri(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "AbsoluteUri", 6usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).AbsoluteUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayUri(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "DisplayUri", 7usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).DisplayUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Domain(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "Domain", 8usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).Domain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Extension(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "Extension", 9usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).Extension)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Fragment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "Fragment", 10usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).Fragment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Host(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "Host", 11usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).Host)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Password(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "Password", 12usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).Password)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "Path", 13usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).Path)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Query(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "Query", 14usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).Query)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryParsed(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "QueryParsed", 15usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).QueryParsed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawUri(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "RawUri", 16usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).RawUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SchemeName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "SchemeName", 17usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).SchemeName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "UserName", 18usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).UserName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Port(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "Port", 19usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).Port)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Suspicious(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "Suspicious", 20usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).Suspicious)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Equals(this: *mut core::ffi::c_void, puri: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "Equals", 21usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).Equals)(this, puri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CombineUri(this: *mut core::ffi::c_void, relativeuri: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClass", "CombineUri", 22usize, |this| ((**(this as *const *const IUriRuntimeClass_Vtbl)).CombineUri)(this, relativeuri, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AbsoluteUri,
+            DisplayUri,
+            Domain,
+            Extension,
+            Fragment,
+            Host,
+            Password,
+            Path,
+            Query,
+            QueryParsed,
+            RawUri,
+            SchemeName,
+            UserName,
+            Port,
+            Suspicious,
+            Equals,
+            CombineUri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUriRuntimeClass as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUriRuntimeClassFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateUri(this: *mut core::ffi::c_void, uri: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClassFactory", "CreateUri", 6usize, |this| ((**(this as *const *const IUriRuntimeClassFactory_Vtbl)).CreateUri)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithRelativeUri(this: *mut core::ffi::c_void, baseuri: core::mem::MaybeUninit<windows_core::HSTRING>, relativeuri: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClassFactory", "CreateWithRelativeUri", 7usize, |this| ((**(this as *const *const IUriRuntimeClassFactory_Vtbl)).CreateWithRelativeUri)(this, baseuri, relativeuri, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateUri, CreateWithRelativeUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUriRuntimeClassFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUriRuntimeClassWithAbsoluteCanonicalUri_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AbsoluteCanonicalUri(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClassWithAbsoluteCanonicalUri", "AbsoluteCanonicalUri", 6usize, |this| ((**(this as *const *const IUriRuntimeClassWithAbsoluteCanonicalUri_Vtbl)).AbsoluteCanonicalUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayIri(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriRuntimeClassWithAbsoluteCanonicalUri", "DisplayIri", 7usize, |this| ((**(this as *const *const IUriRuntimeClassWithAbsoluteCanonicalUri_Vtbl)).DisplayIri)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AbsoluteCanonicalUri, DisplayIri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUriRuntimeClassWithAbsoluteCanonicalUri as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWwwFormUrlDecoderEntry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWwwFormUrlDecoderEntry", "Name", 6usize, |this| ((**(this as *const *const IWwwFormUrlDecoderEntry_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWwwFormUrlDecoderEntry", "Value", 7usize, |this| ((**(this as *const *const IWwwFormUrlDecoderEntry_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, Value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWwwFormUrlDecoderEntry as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWwwFormUrlDecoderRuntimeClass_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFirstValueByName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWwwFormUrlDecoderRuntimeClass", "GetFirstValueByName", 6usize, |this| ((**(this as *const *const IWwwFormUrlDecoderRuntimeClass_Vtbl)).GetFirstValueByName)(this, name, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFirstValueByName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWwwFormUrlDecoderRuntimeClass as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWwwFormUrlDecoderRuntimeClassFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWwwFormUrlDecoder(this: *mut core::ffi::c_void, query: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWwwFormUrlDecoderRuntimeClassFactory", "CreateWwwFormUrlDecoder", 6usize, |this| ((**(this as *const *const IWwwFormUrlDecoderRuntimeClassFactory_Vtbl)).CreateWwwFormUrlDecoder)(this, query, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWwwFormUrlDecoder }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWwwFormUrlDecoderRuntimeClassFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
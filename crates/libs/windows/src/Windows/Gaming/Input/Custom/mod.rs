@@ -596,6 +596,7 @@ impl GipFirmwareUpdateStatus {
 impl windows_core::TypeKind for GipFirmwareUpdateStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GipFirmwareUpdateStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GipFirmwareUpdateStatus").field(&self.0).finish()
@@ -615,6 +616,7 @@ impl GipMessageClass {
 impl windows_core::TypeKind for GipMessageClass {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GipMessageClass {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GipMessageClass").field(&self.0).finish()
@@ -642,6 +644,7 @@ impl XusbDeviceSubtype {
 impl windows_core::TypeKind for XusbDeviceSubtype {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XusbDeviceSubtype {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XusbDeviceSubtype").field(&self.0).finish()
@@ -660,6 +663,7 @@ impl XusbDeviceType {
 impl windows_core::TypeKind for XusbDeviceType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XusbDeviceType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XusbDeviceType").field(&self.0).finish()
@@ -669,7 +673,8 @@ impl windows_core::RuntimeType for XusbDeviceType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Gaming.Input.Custom.XusbDeviceType;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GameControllerVersionInfo {
     pub Major: u16,
     pub Minor: u16,
@@ -688,7 +693,8 @@ impl Default for GameControllerVersionInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GipFirmwareUpdateProgress {
     pub PercentCompleted: f64,
     pub CurrentComponentId: u32,
@@ -706,3 +712,5 @@ impl Default for GipFirmwareUpdateProgress {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
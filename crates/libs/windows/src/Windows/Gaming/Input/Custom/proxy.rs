@@ -0,0 +1,232 @@
+impl windows_core::imp::ProxyVtable for ICustomGameControllerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateGameController(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomGameControllerFactory", "CreateGameController", 6usize, |this| ((**(this as *const *const ICustomGameControllerFactory_Vtbl)).CreateGameController)(this, provider, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnGameControllerAdded(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomGameControllerFactory", "OnGameControllerAdded", 7usize, |this| ((**(this as *const *const ICustomGameControllerFactory_Vtbl)).OnGameControllerAdded)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnGameControllerRemoved(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomGameControllerFactory", "OnGameControllerRemoved", 8usize, |this| ((**(this as *const *const ICustomGameControllerFactory_Vtbl)).OnGameControllerRemoved)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateGameController,
+            OnGameControllerAdded,
+            OnGameControllerRemoved,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICustomGameControllerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameControllerFactoryManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterCustomFactoryForGipInterface(this: *mut core::ffi::c_void, factory: *mut core::ffi::c_void, interfaceid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameControllerFactoryManagerStatics", "RegisterCustomFactoryForGipInterface", 6usize, |this| ((**(this as *const *const IGameControllerFactoryManagerStatics_Vtbl)).RegisterCustomFactoryForGipInterface)(this, factory, interfaceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterCustomFactoryForHardwareId(this: *mut core::ffi::c_void, factory: *mut core::ffi::c_void, hardwarevendorid: u16, hardwareproductid: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameControllerFactoryManagerStatics", "RegisterCustomFactoryForHardwareId", 7usize, |this| ((**(this as *const *const IGameControllerFactoryManagerStatics_Vtbl)).RegisterCustomFactoryForHardwareId)(this, factory, hardwarevendorid, hardwareproductid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterCustomFactoryForXusbType(this: *mut core::ffi::c_void, factory: *mut core::ffi::c_void, xusbtype: XusbDeviceType, xusbsubtype: XusbDeviceSubtype) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameControllerFactoryManagerStatics", "RegisterCustomFactoryForXusbType", 8usize, |this| ((**(this as *const *const IGameControllerFactoryManagerStatics_Vtbl)).RegisterCustomFactoryForXusbType)(this, factory, xusbtype, xusbsubtype), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterCustomFactoryForGipInterface,
+            RegisterCustomFactoryForHardwareId,
+            RegisterCustomFactoryForXusbType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameControllerFactoryManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameControllerFactoryManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryGetFactoryControllerFromGameController(this: *mut core::ffi::c_void, factory: *mut core::ffi::c_void, gamecontroller: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameControllerFactoryManagerStatics2", "TryGetFactoryControllerFromGameController", 6usize, |this| ((**(this as *const *const IGameControllerFactoryManagerStatics2_Vtbl)).TryGetFactoryControllerFromGameController)(this, factory, gamecontroller, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryGetFactoryControllerFromGameController }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameControllerFactoryManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameControllerInputSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnInputResumed(this: *mut core::ffi::c_void, timestamp: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameControllerInputSink", "OnInputResumed", 6usize, |this| ((**(this as *const *const IGameControllerInputSink_Vtbl)).OnInputResumed)(this, timestamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnInputSuspended(this: *mut core::ffi::c_void, timestamp: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameControllerInputSink", "OnInputSuspended", 7usize, |this| ((**(this as *const *const IGameControllerInputSink_Vtbl)).OnInputSuspended)(this, timestamp), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnInputResumed, OnInputSuspended }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameControllerInputSink as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameControllerProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FirmwareVersionInfo(this: *mut core::ffi::c_void, result__: *mut GameControllerVersionInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameControllerProvider", "FirmwareVersionInfo", 6usize, |this| ((**(this as *const *const IGameControllerProvider_Vtbl)).FirmwareVersionInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareProductId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameControllerProvider", "HardwareProductId", 7usize, |this| ((**(this as *const *const IGameControllerProvider_Vtbl)).HardwareProductId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareVendorId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameControllerProvider", "HardwareVendorId", 8usize, |this| ((**(this as *const *const IGameControllerProvider_Vtbl)).HardwareVendorId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareVersionInfo(this: *mut core::ffi::c_void, result__: *mut GameControllerVersionInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameControllerProvider", "HardwareVersionInfo", 9usize, |this| ((**(this as *const *const IGameControllerProvider_Vtbl)).HardwareVersionInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameControllerProvider", "IsConnected", 10usize, |this| ((**(this as *const *const IGameControllerProvider_Vtbl)).IsConnected)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FirmwareVersionInfo,
+            HardwareProductId,
+            HardwareVendorId,
+            HardwareVersionInfo,
+            IsConnected,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameControllerProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGipFirmwareUpdateResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedErrorCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGipFirmwareUpdateResult", "ExtendedErrorCode", 6usize, |this| ((**(this as *const *const IGipFirmwareUpdateResult_Vtbl)).ExtendedErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinalComponentId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGipFirmwareUpdateResult", "FinalComponentId", 7usize, |this| ((**(this as *const *const IGipFirmwareUpdateResult_Vtbl)).FinalComponentId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GipFirmwareUpdateStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGipFirmwareUpdateResult", "Status", 8usize, |this| ((**(this as *const *const IGipFirmwareUpdateResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedErrorCode, FinalComponentId, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGipFirmwareUpdateResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGipGameControllerInputSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnKeyReceived(this: *mut core::ffi::c_void, timestamp: u64, keycode: u8, ispressed: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGipGameControllerInputSink", "OnKeyReceived", 6usize, |this| ((**(this as *const *const IGipGameControllerInputSink_Vtbl)).OnKeyReceived)(this, timestamp, keycode, ispressed), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnMessageReceived(this: *mut core::ffi::c_void, timestamp: u64, messageclass: GipMessageClass, messageid: u8, sequenceid: u8, messageBuffer_array_size: u32, messagebuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGipGameControllerInputSink", "OnMessageReceived", 7usize, |this| ((**(this as *const *const IGipGameControllerInputSink_Vtbl)).OnMessageReceived)(this, timestamp, messageclass, messageid, sequenceid, messageBuffer_array_size, messagebuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnKeyReceived, OnMessageReceived }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGipGameControllerInputSink as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IGipGameControllerProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendMessage(this: *mut core::ffi::c_void, messageclass: GipMessageClass, messageid: u8, messageBuffer_array_size: u32, messagebuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGipGameControllerProvider", "SendMessage", 6usize, |this| ((**(this as *const *const IGipGameControllerProvider_Vtbl)).SendMessage)(this, messageclass, messageid, messageBuffer_array_size, messagebuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendReceiveMessage(this: *mut core::ffi::c_void, messageclass: GipMessageClass, messageid: u8, requestMessageBuffer_array_size: u32, requestmessagebuffer: *const u8, responseMessageBuffer_array_size: u32, responsemessagebuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGipGameControllerProvider", "SendReceiveMessage", 7usize, |this| ((**(this as *const *const IGipGameControllerProvider_Vtbl)).SendReceiveMessage)(this, messageclass, messageid, requestMessageBuffer_array_size, requestmessagebuffer, responseMessageBuffer_array_size, responsemessagebuffer), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn UpdateFirmwareAsync(this: *mut core::ffi::c_void, firmwareimage: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGipGameControllerProvider", "UpdateFirmwareAsync", 8usize, |this| ((**(this as *const *const IGipGameControllerProvider_Vtbl)).UpdateFirmwareAsync)(this, firmwareimage, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SendMessage,
+            SendReceiveMessage,
+            #[cfg(feature = "Storage_Streams")]
+            UpdateFirmwareAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            UpdateFirmwareAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGipGameControllerProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHidGameControllerInputSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnInputReportReceived(this: *mut core::ffi::c_void, timestamp: u64, reportid: u8, reportBuffer_array_size: u32, reportbuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidGameControllerInputSink", "OnInputReportReceived", 6usize, |this| ((**(this as *const *const IHidGameControllerInputSink_Vtbl)).OnInputReportReceived)(this, timestamp, reportid, reportBuffer_array_size, reportbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnInputReportReceived }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHidGameControllerInputSink as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHidGameControllerProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UsageId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidGameControllerProvider", "UsageId", 6usize, |this| ((**(this as *const *const IHidGameControllerProvider_Vtbl)).UsageId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsagePage(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidGameControllerProvider", "UsagePage", 7usize, |this| ((**(this as *const *const IHidGameControllerProvider_Vtbl)).UsagePage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeatureReport(this: *mut core::ffi::c_void, reportid: u8, reportBuffer_array_size: u32, reportbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidGameControllerProvider", "GetFeatureReport", 8usize, |this| ((**(this as *const *const IHidGameControllerProvider_Vtbl)).GetFeatureReport)(this, reportid, reportBuffer_array_size, reportbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendFeatureReport(this: *mut core::ffi::c_void, reportid: u8, reportBuffer_array_size: u32, reportbuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidGameControllerProvider", "SendFeatureReport", 9usize, |this| ((**(this as *const *const IHidGameControllerProvider_Vtbl)).SendFeatureReport)(this, reportid, reportBuffer_array_size, reportbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendOutputReport(this: *mut core::ffi::c_void, reportid: u8, reportBuffer_array_size: u32, reportbuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHidGameControllerProvider", "SendOutputReport", 10usize, |this| ((**(this as *const *const IHidGameControllerProvider_Vtbl)).SendOutputReport)(this, reportid, reportBuffer_array_size, reportbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UsageId,
+            UsagePage,
+            GetFeatureReport,
+            SendFeatureReport,
+            SendOutputReport,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHidGameControllerProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXusbGameControllerInputSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnInputReceived(this: *mut core::ffi::c_void, timestamp: u64, reportid: u8, inputBuffer_array_size: u32, inputbuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXusbGameControllerInputSink", "OnInputReceived", 6usize, |this| ((**(this as *const *const IXusbGameControllerInputSink_Vtbl)).OnInputReceived)(this, timestamp, reportid, inputBuffer_array_size, inputbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnInputReceived }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXusbGameControllerInputSink as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXusbGameControllerProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetVibration(this: *mut core::ffi::c_void, lowfrequencymotorspeed: f64, highfrequencymotorspeed: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXusbGameControllerProvider", "SetVibration", 6usize, |this| ((**(this as *const *const IXusbGameControllerProvider_Vtbl)).SetVibration)(this, lowfrequencymotorspeed, highfrequencymotorspeed), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetVibration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXusbGameControllerProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -527,6 +527,7 @@ impl ConditionForceEffectKind {
 impl windows_core::TypeKind for ConditionForceEffectKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ConditionForceEffectKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ConditionForceEffectKind").field(&self.0).finish()
@@ -547,6 +548,7 @@ impl ForceFeedbackEffectAxes {
 impl windows_core::TypeKind for ForceFeedbackEffectAxes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ForceFeedbackEffectAxes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ForceFeedbackEffectAxes").field(&self.0).finish()
@@ -600,6 +602,7 @@ impl ForceFeedbackEffectState {
 impl windows_core::TypeKind for ForceFeedbackEffectState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ForceFeedbackEffectState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ForceFeedbackEffectState").field(&self.0).finish()
@@ -619,6 +622,7 @@ impl ForceFeedbackLoadEffectResult {
 impl windows_core::TypeKind for ForceFeedbackLoadEffectResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ForceFeedbackLoadEffectResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ForceFeedbackLoadEffectResult").field(&self.0).finish()
@@ -640,6 +644,7 @@ impl PeriodicForceEffectKind {
 impl windows_core::TypeKind for PeriodicForceEffectKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PeriodicForceEffectKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PeriodicForceEffectKind").field(&self.0).finish()
@@ -650,3 +655,5 @@ impl windows_core::RuntimeType for PeriodicForceEffectKind {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,223 @@
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IConditionForceEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut ConditionForceEffectKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionForceEffect", "Kind", 6usize, |this| ((**(this as *const *const IConditionForceEffect_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetParameters(this: *mut core::ffi::c_void, direction: super::super::super::Foundation::Numerics::Vector3, positivecoefficient: f32, negativecoefficient: f32, maxpositivemagnitude: f32, maxnegativemagnitude: f32, deadzone: f32, bias: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionForceEffect", "SetParameters", 7usize, |this| ((**(this as *const *const IConditionForceEffect_Vtbl)).SetParameters)(this, direction, positivecoefficient, negativecoefficient, maxpositivemagnitude, maxnegativemagnitude, deadzone, bias), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Kind,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetParameters,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetParameters: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConditionForceEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConditionForceEffectFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, effectkind: ConditionForceEffectKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionForceEffectFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IConditionForceEffectFactory_Vtbl)).CreateInstance)(this, effectkind, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConditionForceEffectFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IConstantForceEffect_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetParameters(this: *mut core::ffi::c_void, vector: super::super::super::Foundation::Numerics::Vector3, duration: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConstantForceEffect", "SetParameters", 6usize, |this| ((**(this as *const *const IConstantForceEffect_Vtbl)).SetParameters)(this, vector, duration), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetParametersWithEnvelope(this: *mut core::ffi::c_void, vector: super::super::super::Foundation::Numerics::Vector3, attackgain: f32, sustaingain: f32, releasegain: f32, startdelay: super::super::super::Foundation::TimeSpan, attackduration: super::super::super::Foundation::TimeSpan, sustainduration: super::super::super::Foundation::TimeSpan, releaseduration: super::super::super::Foundation::TimeSpan, repeatcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConstantForceEffect", "SetParametersWithEnvelope", 7usize, |this| ((**(this as *const *const IConstantForceEffect_Vtbl)).SetParametersWithEnvelope)(this, vector, attackgain, sustaingain, releasegain, startdelay, attackduration, sustainduration, releaseduration, repeatcount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetParameters,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetParameters: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetParametersWithEnvelope,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetParametersWithEnvelope: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConstantForceEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IForceFeedbackEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Gain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackEffect", "Gain", 6usize, |this| ((**(this as *const *const IForceFeedbackEffect_Vtbl)).Gain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGain(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackEffect", "SetGain", 7usize, |this| ((**(this as *const *const IForceFeedbackEffect_Vtbl)).SetGain)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut ForceFeedbackEffectState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackEffect", "State", 8usize, |this| ((**(this as *const *const IForceFeedbackEffect_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackEffect", "Start", 9usize, |this| ((**(this as *const *const IForceFeedbackEffect_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackEffect", "Stop", 10usize, |this| ((**(this as *const *const IForceFeedbackEffect_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Gain, SetGain, State, Start, Stop }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IForceFeedbackEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IForceFeedbackMotor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AreEffectsPaused(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackMotor", "AreEffectsPaused", 6usize, |this| ((**(this as *const *const IForceFeedbackMotor_Vtbl)).AreEffectsPaused)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MasterGain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackMotor", "MasterGain", 7usize, |this| ((**(this as *const *const IForceFeedbackMotor_Vtbl)).MasterGain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMasterGain(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackMotor", "SetMasterGain", 8usize, |this| ((**(this as *const *const IForceFeedbackMotor_Vtbl)).SetMasterGain)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackMotor", "IsEnabled", 9usize, |this| ((**(this as *const *const IForceFeedbackMotor_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedAxes(this: *mut core::ffi::c_void, result__: *mut ForceFeedbackEffectAxes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackMotor", "SupportedAxes", 10usize, |this| ((**(this as *const *const IForceFeedbackMotor_Vtbl)).SupportedAxes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadEffectAsync(this: *mut core::ffi::c_void, effect: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackMotor", "LoadEffectAsync", 11usize, |this| ((**(this as *const *const IForceFeedbackMotor_Vtbl)).LoadEffectAsync)(this, effect, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PauseAllEffects(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackMotor", "PauseAllEffects", 12usize, |this| ((**(this as *const *const IForceFeedbackMotor_Vtbl)).PauseAllEffects)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeAllEffects(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackMotor", "ResumeAllEffects", 13usize, |this| ((**(this as *const *const IForceFeedbackMotor_Vtbl)).ResumeAllEffects)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAllEffects(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackMotor", "StopAllEffects", 14usize, |this| ((**(this as *const *const IForceFeedbackMotor_Vtbl)).StopAllEffects)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryDisableAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackMotor", "TryDisableAsync", 15usize, |this| ((**(this as *const *const IForceFeedbackMotor_Vtbl)).TryDisableAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryEnableAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackMotor", "TryEnableAsync", 16usize, |this| ((**(this as *const *const IForceFeedbackMotor_Vtbl)).TryEnableAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryResetAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackMotor", "TryResetAsync", 17usize, |this| ((**(this as *const *const IForceFeedbackMotor_Vtbl)).TryResetAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryUnloadEffectAsync(this: *mut core::ffi::c_void, effect: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForceFeedbackMotor", "TryUnloadEffectAsync", 18usize, |this| ((**(this as *const *const IForceFeedbackMotor_Vtbl)).TryUnloadEffectAsync)(this, effect, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AreEffectsPaused,
+            MasterGain,
+            SetMasterGain,
+            IsEnabled,
+            SupportedAxes,
+            LoadEffectAsync,
+            PauseAllEffects,
+            ResumeAllEffects,
+            StopAllEffects,
+            TryDisableAsync,
+            TryEnableAsync,
+            TryResetAsync,
+            TryUnloadEffectAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IForceFeedbackMotor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IPeriodicForceEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut PeriodicForceEffectKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeriodicForceEffect", "Kind", 6usize, |this| ((**(this as *const *const IPeriodicForceEffect_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetParameters(this: *mut core::ffi::c_void, vector: super::super::super::Foundation::Numerics::Vector3, frequency: f32, phase: f32, bias: f32, duration: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeriodicForceEffect", "SetParameters", 7usize, |this| ((**(this as *const *const IPeriodicForceEffect_Vtbl)).SetParameters)(this, vector, frequency, phase, bias, duration), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetParametersWithEnvelope(this: *mut core::ffi::c_void, vector: super::super::super::Foundation::Numerics::Vector3, frequency: f32, phase: f32, bias: f32, attackgain: f32, sustaingain: f32, releasegain: f32, startdelay: super::super::super::Foundation::TimeSpan, attackduration: super::super::super::Foundation::TimeSpan, sustainduration: super::super::super::Foundation::TimeSpan, releaseduration: super::super::super::Foundation::TimeSpan, repeatcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeriodicForceEffect", "SetParametersWithEnvelope", 8usize, |this| ((**(this as *const *const IPeriodicForceEffect_Vtbl)).SetParametersWithEnvelope)(this, vector, frequency, phase, bias, attackgain, sustaingain, releasegain, startdelay, attackduration, sustainduration, releaseduration, repeatcount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Kind,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetParameters,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetParameters: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetParametersWithEnvelope,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetParametersWithEnvelope: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPeriodicForceEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPeriodicForceEffectFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, effectkind: PeriodicForceEffectKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeriodicForceEffectFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IPeriodicForceEffectFactory_Vtbl)).CreateInstance)(this, effectkind, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPeriodicForceEffectFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IRampForceEffect_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetParameters(this: *mut core::ffi::c_void, startvector: super::super::super::Foundation::Numerics::Vector3, endvector: super::super::super::Foundation::Numerics::Vector3, duration: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRampForceEffect", "SetParameters", 6usize, |this| ((**(this as *const *const IRampForceEffect_Vtbl)).SetParameters)(this, startvector, endvector, duration), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetParametersWithEnvelope(this: *mut core::ffi::c_void, startvector: super::super::super::Foundation::Numerics::Vector3, endvector: super::super::super::Foundation::Numerics::Vector3, attackgain: f32, sustaingain: f32, releasegain: f32, startdelay: super::super::super::Foundation::TimeSpan, attackduration: super::super::super::Foundation::TimeSpan, sustainduration: super::super::super::Foundation::TimeSpan, releaseduration: super::super::super::Foundation::TimeSpan, repeatcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRampForceEffect", "SetParametersWithEnvelope", 7usize, |this| ((**(this as *const *const IRampForceEffect_Vtbl)).SetParametersWithEnvelope)(this, startvector, endvector, attackgain, sustaingain, releasegain, startdelay, attackduration, sustainduration, releaseduration, repeatcount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetParameters,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetParameters: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetParametersWithEnvelope,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetParametersWithEnvelope: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRampForceEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
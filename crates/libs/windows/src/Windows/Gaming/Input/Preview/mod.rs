@@ -302,6 +302,7 @@ impl DeviceCommand {
 impl windows_core::TypeKind for DeviceCommand {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceCommand {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceCommand").field(&self.0).finish()
@@ -322,6 +323,7 @@ impl GameControllerBatteryChargingState {
 impl windows_core::TypeKind for GameControllerBatteryChargingState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameControllerBatteryChargingState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameControllerBatteryChargingState").field(&self.0).finish()
@@ -342,6 +344,7 @@ impl GameControllerBatteryKind {
 impl windows_core::TypeKind for GameControllerBatteryKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameControllerBatteryKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameControllerBatteryKind").field(&self.0).finish()
@@ -363,6 +366,7 @@ impl GameControllerBatteryLevel {
 impl windows_core::TypeKind for GameControllerBatteryLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameControllerBatteryLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameControllerBatteryLevel").field(&self.0).finish()
@@ -386,6 +390,7 @@ impl GameControllerFirmwareCorruptReason {
 impl windows_core::TypeKind for GameControllerFirmwareCorruptReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameControllerFirmwareCorruptReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameControllerFirmwareCorruptReason").field(&self.0).finish()
@@ -406,6 +411,7 @@ impl HeadsetLevel {
 impl windows_core::TypeKind for HeadsetLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HeadsetLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HeadsetLevel").field(&self.0).finish()
@@ -428,6 +434,7 @@ impl HeadsetOperation {
 impl windows_core::TypeKind for HeadsetOperation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HeadsetOperation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HeadsetOperation").field(&self.0).finish()
@@ -456,6 +463,7 @@ impl RemappingButtonCategory {
 impl windows_core::TypeKind for RemappingButtonCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemappingButtonCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemappingButtonCategory").field(&self.0).finish()
@@ -465,7 +473,8 @@ impl windows_core::RuntimeType for RemappingButtonCategory {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Gaming.Input.Preview.RemappingButtonCategory;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HeadsetGeqGains {
     pub band1Gain: i32,
     pub band2Gain: i32,
@@ -484,3 +493,5 @@ impl Default for HeadsetGeqGains {
         unsafe { core::mem::zeroed() }
     }
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,172 @@
+#[cfg(feature = "Gaming_Input_Custom")]
+impl windows_core::imp::ProxyVtable for IGameControllerProviderInfoStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Gaming_Input_Custom")]
+        unsafe extern "system" fn GetParentProviderId(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameControllerProviderInfoStatics", "GetParentProviderId", 6usize, |this| ((**(this as *const *const IGameControllerProviderInfoStatics_Vtbl)).GetParentProviderId)(this, provider, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Gaming_Input_Custom")]
+        unsafe extern "system" fn GetProviderId(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameControllerProviderInfoStatics", "GetProviderId", 7usize, |this| ((**(this as *const *const IGameControllerProviderInfoStatics_Vtbl)).GetProviderId)(this, provider, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Gaming_Input_Custom")]
+            GetParentProviderId,
+            #[cfg(not(feature = "Gaming_Input_Custom"))]
+            GetParentProviderId: 0,
+            #[cfg(feature = "Gaming_Input_Custom")]
+            GetProviderId,
+            #[cfg(not(feature = "Gaming_Input_Custom"))]
+            GetProviderId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameControllerProviderInfoStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+impl windows_core::imp::ProxyVtable for ILegacyGipGameControllerProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BatteryChargingState(this: *mut core::ffi::c_void, result__: *mut GameControllerBatteryChargingState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "BatteryChargingState", 6usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).BatteryChargingState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BatteryKind(this: *mut core::ffi::c_void, result__: *mut GameControllerBatteryKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "BatteryKind", 7usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).BatteryKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BatteryLevel(this: *mut core::ffi::c_void, result__: *mut GameControllerBatteryLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "BatteryLevel", 8usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).BatteryLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceFirmwareCorruptionState(this: *mut core::ffi::c_void, result__: *mut GameControllerFirmwareCorruptReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "GetDeviceFirmwareCorruptionState", 9usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).GetDeviceFirmwareCorruptionState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFirmwareCorrupted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "IsFirmwareCorrupted", 10usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).IsFirmwareCorrupted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInterfaceSupported(this: *mut core::ffi::c_void, interfaceid: windows_core::GUID, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "IsInterfaceSupported", 11usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).IsInterfaceSupported)(this, interfaceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSyntheticDevice(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "IsSyntheticDevice", 12usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).IsSyntheticDevice)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PreferredTypes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "PreferredTypes", 13usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).PreferredTypes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecuteCommand(this: *mut core::ffi::c_void, command: DeviceCommand) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "ExecuteCommand", 14usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).ExecuteCommand)(this, command), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHomeLedIntensity(this: *mut core::ffi::c_void, intensity: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "SetHomeLedIntensity", 15usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).SetHomeLedIntensity)(this, intensity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExtendedDeviceInfo(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "GetExtendedDeviceInfo", 16usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).GetExtendedDeviceInfo)(this, result_size__, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHeadsetOperation(this: *mut core::ffi::c_void, operation: HeadsetOperation, buffer_array_size: u32, buffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "SetHeadsetOperation", 17usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).SetHeadsetOperation)(this, operation, buffer_array_size, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHeadsetOperation(this: *mut core::ffi::c_void, operation: HeadsetOperation, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "GetHeadsetOperation", 18usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).GetHeadsetOperation)(this, operation, result_size__, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppCompatVersion(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "AppCompatVersion", 19usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).AppCompatVersion)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+        unsafe extern "system" fn SetStandardControllerButtonRemapping(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, previous: bool, remapping: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "SetStandardControllerButtonRemapping", 20usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).SetStandardControllerButtonRemapping)(this, user, previous, remapping), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+        unsafe extern "system" fn GetStandardControllerButtonRemapping(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, previous: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProvider", "GetStandardControllerButtonRemapping", 21usize, |this| ((**(this as *const *const ILegacyGipGameControllerProvider_Vtbl)).GetStandardControllerButtonRemapping)(this, user, previous, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BatteryChargingState,
+            BatteryKind,
+            BatteryLevel,
+            GetDeviceFirmwareCorruptionState,
+            IsFirmwareCorrupted,
+            IsInterfaceSupported,
+            IsSyntheticDevice,
+            #[cfg(feature = "Foundation_Collections")]
+            PreferredTypes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PreferredTypes: 0,
+            ExecuteCommand,
+            SetHomeLedIntensity,
+            GetExtendedDeviceInfo,
+            SetHeadsetOperation,
+            GetHeadsetOperation,
+            AppCompatVersion,
+            #[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+            SetStandardControllerButtonRemapping,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "System")))]
+            SetStandardControllerButtonRemapping: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+            GetStandardControllerButtonRemapping,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "System")))]
+            GetStandardControllerButtonRemapping: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILegacyGipGameControllerProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Gaming_Input_Custom", feature = "System"))]
+impl windows_core::imp::ProxyVtable for ILegacyGipGameControllerProviderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromGameController(this: *mut core::ffi::c_void, controller: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProviderStatics", "FromGameController", 6usize, |this| ((**(this as *const *const ILegacyGipGameControllerProviderStatics_Vtbl)).FromGameController)(this, controller, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Gaming_Input_Custom")]
+        unsafe extern "system" fn FromGameControllerProvider(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProviderStatics", "FromGameControllerProvider", 7usize, |this| ((**(this as *const *const ILegacyGipGameControllerProviderStatics_Vtbl)).FromGameControllerProvider)(this, provider, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn PairPilotToCopilot(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, pilotcontrollerproviderid: core::mem::MaybeUninit<windows_core::HSTRING>, copilotcontrollerproviderid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProviderStatics", "PairPilotToCopilot", 8usize, |this| ((**(this as *const *const ILegacyGipGameControllerProviderStatics_Vtbl)).PairPilotToCopilot)(this, user, pilotcontrollerproviderid, copilotcontrollerproviderid), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ClearPairing(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, controllerproviderid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProviderStatics", "ClearPairing", 9usize, |this| ((**(this as *const *const ILegacyGipGameControllerProviderStatics_Vtbl)).ClearPairing)(this, user, controllerproviderid), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn IsPilot(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, controllerproviderid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProviderStatics", "IsPilot", 10usize, |this| ((**(this as *const *const ILegacyGipGameControllerProviderStatics_Vtbl)).IsPilot)(this, user, controllerproviderid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn IsCopilot(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, controllerproviderid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyGipGameControllerProviderStatics", "IsCopilot", 11usize, |this| ((**(this as *const *const ILegacyGipGameControllerProviderStatics_Vtbl)).IsCopilot)(this, user, controllerproviderid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FromGameController,
+            #[cfg(feature = "Gaming_Input_Custom")]
+            FromGameControllerProvider,
+            #[cfg(not(feature = "Gaming_Input_Custom"))]
+            FromGameControllerProvider: 0,
+            #[cfg(feature = "System")]
+            PairPilotToCopilot,
+            #[cfg(not(feature = "System"))]
+            PairPilotToCopilot: 0,
+            #[cfg(feature = "System")]
+            ClearPairing,
+            #[cfg(not(feature = "System"))]
+            ClearPairing: 0,
+            #[cfg(feature = "System")]
+            IsPilot,
+            #[cfg(not(feature = "System"))]
+            IsPilot: 0,
+            #[cfg(feature = "System")]
+            IsCopilot,
+            #[cfg(not(feature = "System"))]
+            IsCopilot: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILegacyGipGameControllerProviderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1507,6 +1507,7 @@ impl ArcadeStickButtons {
 impl windows_core::TypeKind for ArcadeStickButtons {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ArcadeStickButtons {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ArcadeStickButtons").field(&self.0).finish()
@@ -1559,6 +1560,7 @@ impl FlightStickButtons {
 impl windows_core::TypeKind for FlightStickButtons {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FlightStickButtons {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FlightStickButtons").field(&self.0).finish()
@@ -1677,6 +1679,7 @@ impl GameControllerButtonLabel {
 impl windows_core::TypeKind for GameControllerButtonLabel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameControllerButtonLabel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameControllerButtonLabel").field(&self.0).finish()
@@ -1696,6 +1699,7 @@ impl GameControllerSwitchKind {
 impl windows_core::TypeKind for GameControllerSwitchKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameControllerSwitchKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameControllerSwitchKind").field(&self.0).finish()
@@ -1721,6 +1725,7 @@ impl GameControllerSwitchPosition {
 impl windows_core::TypeKind for GameControllerSwitchPosition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameControllerSwitchPosition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameControllerSwitchPosition").field(&self.0).finish()
@@ -1756,6 +1761,7 @@ impl GamepadButtons {
 impl windows_core::TypeKind for GamepadButtons {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GamepadButtons {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GamepadButtons").field(&self.0).finish()
@@ -1818,6 +1824,7 @@ impl OptionalUINavigationButtons {
 impl windows_core::TypeKind for OptionalUINavigationButtons {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OptionalUINavigationButtons {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OptionalUINavigationButtons").field(&self.0).finish()
@@ -1890,6 +1897,7 @@ impl RacingWheelButtons {
 impl windows_core::TypeKind for RacingWheelButtons {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RacingWheelButtons {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RacingWheelButtons").field(&self.0).finish()
@@ -1948,6 +1956,7 @@ impl RequiredUINavigationButtons {
 impl windows_core::TypeKind for RequiredUINavigationButtons {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RequiredUINavigationButtons {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RequiredUINavigationButtons").field(&self.0).finish()
@@ -1990,7 +1999,8 @@ impl windows_core::RuntimeType for RequiredUINavigationButtons {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Gaming.Input.RequiredUINavigationButtons;u4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ArcadeStickReading {
     pub Timestamp: u64,
     pub Buttons: ArcadeStickButtons,
@@ -2007,7 +2017,8 @@ impl Default for ArcadeStickReading {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FlightStickReading {
     pub Timestamp: u64,
     pub Buttons: FlightStickButtons,
@@ -2029,7 +2040,8 @@ impl Default for FlightStickReading {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GamepadReading {
     pub Timestamp: u64,
     pub Buttons: GamepadButtons,
@@ -2052,7 +2064,8 @@ impl Default for GamepadReading {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GamepadVibration {
     pub LeftMotor: f64,
     pub RightMotor: f64,
@@ -2071,7 +2084,8 @@ impl Default for GamepadVibration {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RacingWheelReading {
     pub Timestamp: u64,
     pub Buttons: RacingWheelButtons,
@@ -2094,7 +2108,8 @@ impl Default for RacingWheelReading {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UINavigationReading {
     pub Timestamp: u64,
     pub RequiredButtons: RequiredUINavigationButtons,
@@ -2113,3 +2128,5 @@ impl Default for UINavigationReading {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,586 @@
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IArcadeStick_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetButtonLabel(this: *mut core::ffi::c_void, button: ArcadeStickButtons, result__: *mut GameControllerButtonLabel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IArcadeStick", "GetButtonLabel", 6usize, |this| ((**(this as *const *const IArcadeStick_Vtbl)).GetButtonLabel)(this, button, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut ArcadeStickReading) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IArcadeStick", "GetCurrentReading", 7usize, |this| ((**(this as *const *const IArcadeStick_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetButtonLabel, GetCurrentReading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IArcadeStick as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IArcadeStickStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ArcadeStickAdded(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IArcadeStickStatics", "ArcadeStickAdded", 6usize, |this| ((**(this as *const *const IArcadeStickStatics_Vtbl)).ArcadeStickAdded)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveArcadeStickAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IArcadeStickStatics", "RemoveArcadeStickAdded", 7usize, |this| ((**(this as *const *const IArcadeStickStatics_Vtbl)).RemoveArcadeStickAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ArcadeStickRemoved(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IArcadeStickStatics", "ArcadeStickRemoved", 8usize, |this| ((**(this as *const *const IArcadeStickStatics_Vtbl)).ArcadeStickRemoved)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveArcadeStickRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IArcadeStickStatics", "RemoveArcadeStickRemoved", 9usize, |this| ((**(this as *const *const IArcadeStickStatics_Vtbl)).RemoveArcadeStickRemoved)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ArcadeSticks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IArcadeStickStatics", "ArcadeSticks", 10usize, |this| ((**(this as *const *const IArcadeStickStatics_Vtbl)).ArcadeSticks)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ArcadeStickAdded,
+            RemoveArcadeStickAdded,
+            ArcadeStickRemoved,
+            RemoveArcadeStickRemoved,
+            #[cfg(feature = "Foundation_Collections")]
+            ArcadeSticks,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ArcadeSticks: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IArcadeStickStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IArcadeStickStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromGameController(this: *mut core::ffi::c_void, gamecontroller: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IArcadeStickStatics2", "FromGameController", 6usize, |this| ((**(this as *const *const IArcadeStickStatics2_Vtbl)).FromGameController)(this, gamecontroller, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromGameController }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IArcadeStickStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IFlightStick_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HatSwitchKind(this: *mut core::ffi::c_void, result__: *mut GameControllerSwitchKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlightStick", "HatSwitchKind", 6usize, |this| ((**(this as *const *const IFlightStick_Vtbl)).HatSwitchKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetButtonLabel(this: *mut core::ffi::c_void, button: FlightStickButtons, result__: *mut GameControllerButtonLabel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlightStick", "GetButtonLabel", 7usize, |this| ((**(this as *const *const IFlightStick_Vtbl)).GetButtonLabel)(this, button, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut FlightStickReading) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlightStick", "GetCurrentReading", 8usize, |this| ((**(this as *const *const IFlightStick_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HatSwitchKind, GetButtonLabel, GetCurrentReading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFlightStick as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IFlightStickStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FlightStickAdded(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlightStickStatics", "FlightStickAdded", 6usize, |this| ((**(this as *const *const IFlightStickStatics_Vtbl)).FlightStickAdded)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFlightStickAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlightStickStatics", "RemoveFlightStickAdded", 7usize, |this| ((**(this as *const *const IFlightStickStatics_Vtbl)).RemoveFlightStickAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlightStickRemoved(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlightStickStatics", "FlightStickRemoved", 8usize, |this| ((**(this as *const *const IFlightStickStatics_Vtbl)).FlightStickRemoved)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFlightStickRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlightStickStatics", "RemoveFlightStickRemoved", 9usize, |this| ((**(this as *const *const IFlightStickStatics_Vtbl)).RemoveFlightStickRemoved)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FlightSticks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlightStickStatics", "FlightSticks", 10usize, |this| ((**(this as *const *const IFlightStickStatics_Vtbl)).FlightSticks)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromGameController(this: *mut core::ffi::c_void, gamecontroller: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlightStickStatics", "FromGameController", 11usize, |this| ((**(this as *const *const IFlightStickStatics_Vtbl)).FromGameController)(this, gamecontroller, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FlightStickAdded,
+            RemoveFlightStickAdded,
+            FlightStickRemoved,
+            RemoveFlightStickRemoved,
+            #[cfg(feature = "Foundation_Collections")]
+            FlightSticks,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FlightSticks: 0,
+            FromGameController,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFlightStickStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IGameController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HeadsetConnected(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameController", "HeadsetConnected", 6usize, |this| ((**(this as *const *const IGameController_Vtbl)).HeadsetConnected)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHeadsetConnected(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameController", "RemoveHeadsetConnected", 7usize, |this| ((**(this as *const *const IGameController_Vtbl)).RemoveHeadsetConnected)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeadsetDisconnected(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameController", "HeadsetDisconnected", 8usize, |this| ((**(this as *const *const IGameController_Vtbl)).HeadsetDisconnected)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHeadsetDisconnected(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameController", "RemoveHeadsetDisconnected", 9usize, |this| ((**(this as *const *const IGameController_Vtbl)).RemoveHeadsetDisconnected)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn UserChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameController", "UserChanged", 10usize, |this| ((**(this as *const *const IGameController_Vtbl)).UserChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUserChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameController", "RemoveUserChanged", 11usize, |this| ((**(this as *const *const IGameController_Vtbl)).RemoveUserChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Headset(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameController", "Headset", 12usize, |this| ((**(this as *const *const IGameController_Vtbl)).Headset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWireless(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameController", "IsWireless", 13usize, |this| ((**(this as *const *const IGameController_Vtbl)).IsWireless)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameController", "User", 14usize, |this| ((**(this as *const *const IGameController_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HeadsetConnected,
+            RemoveHeadsetConnected,
+            HeadsetDisconnected,
+            RemoveHeadsetDisconnected,
+            #[cfg(feature = "System")]
+            UserChanged,
+            #[cfg(not(feature = "System"))]
+            UserChanged: 0,
+            RemoveUserChanged,
+            Headset,
+            IsWireless,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Power")]
+impl windows_core::imp::ProxyVtable for IGameControllerBatteryInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Power")]
+        unsafe extern "system" fn TryGetBatteryReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameControllerBatteryInfo", "TryGetBatteryReport", 6usize, |this| ((**(this as *const *const IGameControllerBatteryInfo_Vtbl)).TryGetBatteryReport)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Power")]
+            TryGetBatteryReport,
+            #[cfg(not(feature = "Devices_Power"))]
+            TryGetBatteryReport: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameControllerBatteryInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IGamepad_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Vibration(this: *mut core::ffi::c_void, result__: *mut GamepadVibration) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGamepad", "Vibration", 6usize, |this| ((**(this as *const *const IGamepad_Vtbl)).Vibration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVibration(this: *mut core::ffi::c_void, value: GamepadVibration) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGamepad", "SetVibration", 7usize, |this| ((**(this as *const *const IGamepad_Vtbl)).SetVibration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut GamepadReading) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGamepad", "GetCurrentReading", 8usize, |this| ((**(this as *const *const IGamepad_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Vibration, SetVibration, GetCurrentReading }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGamepad as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IGamepad2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetButtonLabel(this: *mut core::ffi::c_void, button: GamepadButtons, result__: *mut GameControllerButtonLabel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGamepad2", "GetButtonLabel", 6usize, |this| ((**(this as *const *const IGamepad2_Vtbl)).GetButtonLabel)(this, button, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetButtonLabel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGamepad2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGamepadStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GamepadAdded(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGamepadStatics", "GamepadAdded", 6usize, |this| ((**(this as *const *const IGamepadStatics_Vtbl)).GamepadAdded)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGamepadAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGamepadStatics", "RemoveGamepadAdded", 7usize, |this| ((**(this as *const *const IGamepadStatics_Vtbl)).RemoveGamepadAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn GamepadRemoved(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGamepadStatics", "GamepadRemoved", 8usize, |this| ((**(this as *const *const IGamepadStatics_Vtbl)).GamepadRemoved)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGamepadRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGamepadStatics", "RemoveGamepadRemoved", 9usize, |this| ((**(this as *const *const IGamepadStatics_Vtbl)).RemoveGamepadRemoved)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Gamepads(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGamepadStatics", "Gamepads", 10usize, |this| ((**(this as *const *const IGamepadStatics_Vtbl)).Gamepads)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GamepadAdded,
+            RemoveGamepadAdded,
+            GamepadRemoved,
+            RemoveGamepadRemoved,
+            #[cfg(feature = "Foundation_Collections")]
+            Gamepads,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Gamepads: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGamepadStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGamepadStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromGameController(this: *mut core::ffi::c_void, gamecontroller: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGamepadStatics2", "FromGameController", 6usize, |this| ((**(this as *const *const IGamepadStatics2_Vtbl)).FromGameController)(this, gamecontroller, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromGameController }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGamepadStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHeadset_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CaptureDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeadset", "CaptureDeviceId", 6usize, |this| ((**(this as *const *const IHeadset_Vtbl)).CaptureDeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenderDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeadset", "RenderDeviceId", 7usize, |this| ((**(this as *const *const IHeadset_Vtbl)).RenderDeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CaptureDeviceId, RenderDeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHeadset as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Gaming_Input_ForceFeedback", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IRacingWheel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasClutch(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRacingWheel", "HasClutch", 6usize, |this| ((**(this as *const *const IRacingWheel_Vtbl)).HasClutch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasHandbrake(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRacingWheel", "HasHandbrake", 7usize, |this| ((**(this as *const *const IRacingWheel_Vtbl)).HasHandbrake)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasPatternShifter(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRacingWheel", "HasPatternShifter", 8usize, |this| ((**(this as *const *const IRacingWheel_Vtbl)).HasPatternShifter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPatternShifterGear(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRacingWheel", "MaxPatternShifterGear", 9usize, |this| ((**(this as *const *const IRacingWheel_Vtbl)).MaxPatternShifterGear)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxWheelAngle(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRacingWheel", "MaxWheelAngle", 10usize, |this| ((**(this as *const *const IRacingWheel_Vtbl)).MaxWheelAngle)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Gaming_Input_ForceFeedback")]
+        unsafe extern "system" fn WheelMotor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRacingWheel", "WheelMotor", 11usize, |this| ((**(this as *const *const IRacingWheel_Vtbl)).WheelMotor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetButtonLabel(this: *mut core::ffi::c_void, button: RacingWheelButtons, result__: *mut GameControllerButtonLabel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRacingWheel", "GetButtonLabel", 12usize, |this| ((**(this as *const *const IRacingWheel_Vtbl)).GetButtonLabel)(this, button, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut RacingWheelReading) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRacingWheel", "GetCurrentReading", 13usize, |this| ((**(this as *const *const IRacingWheel_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HasClutch,
+            HasHandbrake,
+            HasPatternShifter,
+            MaxPatternShifterGear,
+            MaxWheelAngle,
+            #[cfg(feature = "Gaming_Input_ForceFeedback")]
+            WheelMotor,
+            #[cfg(not(feature = "Gaming_Input_ForceFeedback"))]
+            WheelMotor: 0,
+            GetButtonLabel,
+            GetCurrentReading,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRacingWheel as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRacingWheelStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RacingWheelAdded(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRacingWheelStatics", "RacingWheelAdded", 6usize, |this| ((**(this as *const *const IRacingWheelStatics_Vtbl)).RacingWheelAdded)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRacingWheelAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRacingWheelStatics", "RemoveRacingWheelAdded", 7usize, |this| ((**(this as *const *const IRacingWheelStatics_Vtbl)).RemoveRacingWheelAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RacingWheelRemoved(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRacingWheelStatics", "RacingWheelRemoved", 8usize, |this| ((**(this as *const *const IRacingWheelStatics_Vtbl)).RacingWheelRemoved)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRacingWheelRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRacingWheelStatics", "RemoveRacingWheelRemoved", 9usize, |this| ((**(this as *const *const IRacingWheelStatics_Vtbl)).RemoveRacingWheelRemoved)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RacingWheels(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRacingWheelStatics", "RacingWheels", 10usize, |this| ((**(this as *const *const IRacingWheelStatics_Vtbl)).RacingWheels)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RacingWheelAdded,
+            RemoveRacingWheelAdded,
+            RacingWheelRemoved,
+            RemoveRacingWheelRemoved,
+            #[cfg(feature = "Foundation_Collections")]
+            RacingWheels,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RacingWheels: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRacingWheelStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRacingWheelStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromGameController(this: *mut core::ffi::c_void, gamecontroller: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRacingWheelStatics2", "FromGameController", 6usize, |this| ((**(this as *const *const IRacingWheelStatics2_Vtbl)).FromGameController)(this, gamecontroller, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromGameController }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRacingWheelStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Gaming_Input_ForceFeedback", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IRawGameController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AxisCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameController", "AxisCount", 6usize, |this| ((**(this as *const *const IRawGameController_Vtbl)).AxisCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameController", "ButtonCount", 7usize, |this| ((**(this as *const *const IRawGameController_Vtbl)).ButtonCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Gaming_Input_ForceFeedback"))]
+        unsafe extern "system" fn ForceFeedbackMotors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameController", "ForceFeedbackMotors", 8usize, |this| ((**(this as *const *const IRawGameController_Vtbl)).ForceFeedbackMotors)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareProductId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameController", "HardwareProductId", 9usize, |this| ((**(this as *const *const IRawGameController_Vtbl)).HardwareProductId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareVendorId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameController", "HardwareVendorId", 10usize, |this| ((**(this as *const *const IRawGameController_Vtbl)).HardwareVendorId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwitchCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameController", "SwitchCount", 11usize, |this| ((**(this as *const *const IRawGameController_Vtbl)).SwitchCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetButtonLabel(this: *mut core::ffi::c_void, buttonindex: i32, result__: *mut GameControllerButtonLabel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameController", "GetButtonLabel", 12usize, |this| ((**(this as *const *const IRawGameController_Vtbl)).GetButtonLabel)(this, buttonindex, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, buttonArray_array_size: u32, buttonarray: *mut bool, switchArray_array_size: u32, switcharray: *mut GameControllerSwitchPosition, axisArray_array_size: u32, axisarray: *mut f64, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameController", "GetCurrentReading", 13usize, |this| ((**(this as *const *const IRawGameController_Vtbl)).GetCurrentReading)(this, buttonArray_array_size, buttonarray, switchArray_array_size, switcharray, axisArray_array_size, axisarray, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSwitchKind(this: *mut core::ffi::c_void, switchindex: i32, result__: *mut GameControllerSwitchKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameController", "GetSwitchKind", 14usize, |this| ((**(this as *const *const IRawGameController_Vtbl)).GetSwitchKind)(this, switchindex, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AxisCount,
+            ButtonCount,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Gaming_Input_ForceFeedback"))]
+            ForceFeedbackMotors,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Gaming_Input_ForceFeedback")))]
+            ForceFeedbackMotors: 0,
+            HardwareProductId,
+            HardwareVendorId,
+            SwitchCount,
+            GetButtonLabel,
+            GetCurrentReading,
+            GetSwitchKind,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawGameController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Haptics", feature = "Foundation_Collections", feature = "Gaming_Input_ForceFeedback", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IRawGameController2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Devices_Haptics", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn SimpleHapticsControllers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameController2", "SimpleHapticsControllers", 6usize, |this| ((**(this as *const *const IRawGameController2_Vtbl)).SimpleHapticsControllers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NonRoamableId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameController2", "NonRoamableId", 7usize, |this| ((**(this as *const *const IRawGameController2_Vtbl)).NonRoamableId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameController2", "DisplayName", 8usize, |this| ((**(this as *const *const IRawGameController2_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Devices_Haptics", feature = "Foundation_Collections"))]
+            SimpleHapticsControllers,
+            #[cfg(not(all(feature = "Devices_Haptics", feature = "Foundation_Collections")))]
+            SimpleHapticsControllers: 0,
+            NonRoamableId,
+            DisplayName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawGameController2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRawGameControllerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RawGameControllerAdded(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameControllerStatics", "RawGameControllerAdded", 6usize, |this| ((**(this as *const *const IRawGameControllerStatics_Vtbl)).RawGameControllerAdded)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRawGameControllerAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameControllerStatics", "RemoveRawGameControllerAdded", 7usize, |this| ((**(this as *const *const IRawGameControllerStatics_Vtbl)).RemoveRawGameControllerAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawGameControllerRemoved(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameControllerStatics", "RawGameControllerRemoved", 8usize, |this| ((**(this as *const *const IRawGameControllerStatics_Vtbl)).RawGameControllerRemoved)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRawGameControllerRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameControllerStatics", "RemoveRawGameControllerRemoved", 9usize, |this| ((**(this as *const *const IRawGameControllerStatics_Vtbl)).RemoveRawGameControllerRemoved)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RawGameControllers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameControllerStatics", "RawGameControllers", 10usize, |this| ((**(this as *const *const IRawGameControllerStatics_Vtbl)).RawGameControllers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromGameController(this: *mut core::ffi::c_void, gamecontroller: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawGameControllerStatics", "FromGameController", 11usize, |this| ((**(this as *const *const IRawGameControllerStatics_Vtbl)).FromGameController)(this, gamecontroller, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RawGameControllerAdded,
+            RemoveRawGameControllerAdded,
+            RawGameControllerRemoved,
+            RemoveRawGameControllerRemoved,
+            #[cfg(feature = "Foundation_Collections")]
+            RawGameControllers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RawGameControllers: 0,
+            FromGameController,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawGameControllerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IUINavigationController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentReading(this: *mut core::ffi::c_void, result__: *mut UINavigationReading) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUINavigationController", "GetCurrentReading", 6usize, |this| ((**(this as *const *const IUINavigationController_Vtbl)).GetCurrentReading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptionalButtonLabel(this: *mut core::ffi::c_void, button: OptionalUINavigationButtons, result__: *mut GameControllerButtonLabel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUINavigationController", "GetOptionalButtonLabel", 7usize, |this| ((**(this as *const *const IUINavigationController_Vtbl)).GetOptionalButtonLabel)(this, button, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequiredButtonLabel(this: *mut core::ffi::c_void, button: RequiredUINavigationButtons, result__: *mut GameControllerButtonLabel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUINavigationController", "GetRequiredButtonLabel", 8usize, |this| ((**(this as *const *const IUINavigationController_Vtbl)).GetRequiredButtonLabel)(this, button, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentReading,
+            GetOptionalButtonLabel,
+            GetRequiredButtonLabel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUINavigationController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUINavigationControllerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UINavigationControllerAdded(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUINavigationControllerStatics", "UINavigationControllerAdded", 6usize, |this| ((**(this as *const *const IUINavigationControllerStatics_Vtbl)).UINavigationControllerAdded)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUINavigationControllerAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUINavigationControllerStatics", "RemoveUINavigationControllerAdded", 7usize, |this| ((**(this as *const *const IUINavigationControllerStatics_Vtbl)).RemoveUINavigationControllerAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn UINavigationControllerRemoved(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUINavigationControllerStatics", "UINavigationControllerRemoved", 8usize, |this| ((**(this as *const *const IUINavigationControllerStatics_Vtbl)).UINavigationControllerRemoved)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUINavigationControllerRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUINavigationControllerStatics", "RemoveUINavigationControllerRemoved", 9usize, |this| ((**(this as *const *const IUINavigationControllerStatics_Vtbl)).RemoveUINavigationControllerRemoved)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UINavigationControllers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUINavigationControllerStatics", "UINavigationControllers", 10usize, |this| ((**(this as *const *const IUINavigationControllerStatics_Vtbl)).UINavigationControllers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UINavigationControllerAdded,
+            RemoveUINavigationControllerAdded,
+            UINavigationControllerRemoved,
+            RemoveUINavigationControllerRemoved,
+            #[cfg(feature = "Foundation_Collections")]
+            UINavigationControllers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UINavigationControllers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUINavigationControllerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUINavigationControllerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromGameController(this: *mut core::ffi::c_void, gamecontroller: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUINavigationControllerStatics2", "FromGameController", 6usize, |this| ((**(this as *const *const IUINavigationControllerStatics2_Vtbl)).FromGameController)(this, gamecontroller, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromGameController }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUINavigationControllerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -575,6 +575,7 @@ impl GameListCategory {
 impl windows_core::TypeKind for GameListCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameListCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameListCategory").field(&self.0).finish()
@@ -595,6 +596,7 @@ impl GameListEntryLaunchableState {
 impl windows_core::TypeKind for GameListEntryLaunchableState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameListEntryLaunchableState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameListEntryLaunchableState").field(&self.0).finish()
@@ -722,3 +724,5 @@ pub struct GameListRemovedEventHandler_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,296 @@
+#[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IGameListEntry_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn DisplayInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListEntry", "DisplayInfo", 6usize, |this| ((**(this as *const *const IGameListEntry_Vtbl)).DisplayInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListEntry", "LaunchAsync", 7usize, |this| ((**(this as *const *const IGameListEntry_Vtbl)).LaunchAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Category(this: *mut core::ffi::c_void, result__: *mut GameListCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListEntry", "Category", 8usize, |this| ((**(this as *const *const IGameListEntry_Vtbl)).Category)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListEntry", "Properties", 9usize, |this| ((**(this as *const *const IGameListEntry_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCategoryAsync(this: *mut core::ffi::c_void, value: GameListCategory, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListEntry", "SetCategoryAsync", 10usize, |this| ((**(this as *const *const IGameListEntry_Vtbl)).SetCategoryAsync)(this, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel")]
+            DisplayInfo,
+            #[cfg(not(feature = "ApplicationModel"))]
+            DisplayInfo: 0,
+            LaunchAsync,
+            Category,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            SetCategoryAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameListEntry as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IGameListEntry2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LaunchableState(this: *mut core::ffi::c_void, result__: *mut GameListEntryLaunchableState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListEntry2", "LaunchableState", 6usize, |this| ((**(this as *const *const IGameListEntry2_Vtbl)).LaunchableState)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LauncherExecutable(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListEntry2", "LauncherExecutable", 7usize, |this| ((**(this as *const *const IGameListEntry2_Vtbl)).LauncherExecutable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchParameters(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListEntry2", "LaunchParameters", 8usize, |this| ((**(this as *const *const IGameListEntry2_Vtbl)).LaunchParameters)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SetLauncherExecutableFileAsync(this: *mut core::ffi::c_void, executablefile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListEntry2", "SetLauncherExecutableFileAsync", 9usize, |this| ((**(this as *const *const IGameListEntry2_Vtbl)).SetLauncherExecutableFileAsync)(this, executablefile, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SetLauncherExecutableFileWithParamsAsync(this: *mut core::ffi::c_void, executablefile: *mut core::ffi::c_void, launchparams: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListEntry2", "SetLauncherExecutableFileWithParamsAsync", 10usize, |this| ((**(this as *const *const IGameListEntry2_Vtbl)).SetLauncherExecutableFileWithParamsAsync)(this, executablefile, launchparams, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TitleId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListEntry2", "TitleId", 11usize, |this| ((**(this as *const *const IGameListEntry2_Vtbl)).TitleId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitleIdAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListEntry2", "SetTitleIdAsync", 12usize, |this| ((**(this as *const *const IGameListEntry2_Vtbl)).SetTitleIdAsync)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GameModeConfiguration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListEntry2", "GameModeConfiguration", 13usize, |this| ((**(this as *const *const IGameListEntry2_Vtbl)).GameModeConfiguration)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LaunchableState,
+            #[cfg(feature = "Storage")]
+            LauncherExecutable,
+            #[cfg(not(feature = "Storage"))]
+            LauncherExecutable: 0,
+            LaunchParameters,
+            #[cfg(feature = "Storage")]
+            SetLauncherExecutableFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            SetLauncherExecutableFileAsync: 0,
+            #[cfg(feature = "Storage")]
+            SetLauncherExecutableFileWithParamsAsync,
+            #[cfg(not(feature = "Storage"))]
+            SetLauncherExecutableFileWithParamsAsync: 0,
+            TitleId,
+            SetTitleIdAsync,
+            GameModeConfiguration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameListEntry2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGameListStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListStatics", "FindAllAsync", 6usize, |this| ((**(this as *const *const IGameListStatics_Vtbl)).FindAllAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsyncPackageFamilyName(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListStatics", "FindAllAsyncPackageFamilyName", 7usize, |this| ((**(this as *const *const IGameListStatics_Vtbl)).FindAllAsyncPackageFamilyName)(this, packagefamilyname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GameAdded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListStatics", "GameAdded", 8usize, |this| ((**(this as *const *const IGameListStatics_Vtbl)).GameAdded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGameAdded(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListStatics", "RemoveGameAdded", 9usize, |this| ((**(this as *const *const IGameListStatics_Vtbl)).RemoveGameAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn GameRemoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListStatics", "GameRemoved", 10usize, |this| ((**(this as *const *const IGameListStatics_Vtbl)).GameRemoved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGameRemoved(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListStatics", "RemoveGameRemoved", 11usize, |this| ((**(this as *const *const IGameListStatics_Vtbl)).RemoveGameRemoved)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn GameUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListStatics", "GameUpdated", 12usize, |this| ((**(this as *const *const IGameListStatics_Vtbl)).GameUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGameUpdated(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListStatics", "RemoveGameUpdated", 13usize, |this| ((**(this as *const *const IGameListStatics_Vtbl)).RemoveGameUpdated)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsyncPackageFamilyName,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsyncPackageFamilyName: 0,
+            GameAdded,
+            RemoveGameAdded,
+            GameRemoved,
+            RemoveGameRemoved,
+            GameUpdated,
+            RemoveGameUpdated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameListStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGameListStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MergeEntriesAsync(this: *mut core::ffi::c_void, left: *mut core::ffi::c_void, right: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListStatics2", "MergeEntriesAsync", 6usize, |this| ((**(this as *const *const IGameListStatics2_Vtbl)).MergeEntriesAsync)(this, left, right, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UnmergeEntryAsync(this: *mut core::ffi::c_void, mergedentry: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameListStatics2", "UnmergeEntryAsync", 7usize, |this| ((**(this as *const *const IGameListStatics2_Vtbl)).UnmergeEntryAsync)(this, mergedentry, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MergeEntriesAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            UnmergeEntryAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UnmergeEntryAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameListStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGameModeConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "IsEnabled", 6usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "SetIsEnabled", 7usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RelatedProcessNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "RelatedProcessNames", 8usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).RelatedProcessNames)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PercentGpuTimeAllocatedToGame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "PercentGpuTimeAllocatedToGame", 9usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).PercentGpuTimeAllocatedToGame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPercentGpuTimeAllocatedToGame(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "SetPercentGpuTimeAllocatedToGame", 10usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).SetPercentGpuTimeAllocatedToGame)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PercentGpuMemoryAllocatedToGame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "PercentGpuMemoryAllocatedToGame", 11usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).PercentGpuMemoryAllocatedToGame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPercentGpuMemoryAllocatedToGame(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "SetPercentGpuMemoryAllocatedToGame", 12usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).SetPercentGpuMemoryAllocatedToGame)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PercentGpuMemoryAllocatedToSystemCompositor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "PercentGpuMemoryAllocatedToSystemCompositor", 13usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).PercentGpuMemoryAllocatedToSystemCompositor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPercentGpuMemoryAllocatedToSystemCompositor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "SetPercentGpuMemoryAllocatedToSystemCompositor", 14usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).SetPercentGpuMemoryAllocatedToSystemCompositor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxCpuCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "MaxCpuCount", 15usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).MaxCpuCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxCpuCount(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "SetMaxCpuCount", 16usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).SetMaxCpuCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CpuExclusivityMaskLow(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "CpuExclusivityMaskLow", 17usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).CpuExclusivityMaskLow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCpuExclusivityMaskLow(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "SetCpuExclusivityMaskLow", 18usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).SetCpuExclusivityMaskLow)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CpuExclusivityMaskHigh(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "CpuExclusivityMaskHigh", 19usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).CpuExclusivityMaskHigh)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCpuExclusivityMaskHigh(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "SetCpuExclusivityMaskHigh", 20usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).SetCpuExclusivityMaskHigh)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AffinitizeToExclusiveCpus(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "AffinitizeToExclusiveCpus", 21usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).AffinitizeToExclusiveCpus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAffinitizeToExclusiveCpus(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "SetAffinitizeToExclusiveCpus", 22usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).SetAffinitizeToExclusiveCpus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeConfiguration", "SaveAsync", 23usize, |this| ((**(this as *const *const IGameModeConfiguration_Vtbl)).SaveAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEnabled,
+            SetIsEnabled,
+            #[cfg(feature = "Foundation_Collections")]
+            RelatedProcessNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RelatedProcessNames: 0,
+            PercentGpuTimeAllocatedToGame,
+            SetPercentGpuTimeAllocatedToGame,
+            PercentGpuMemoryAllocatedToGame,
+            SetPercentGpuMemoryAllocatedToGame,
+            PercentGpuMemoryAllocatedToSystemCompositor,
+            SetPercentGpuMemoryAllocatedToSystemCompositor,
+            MaxCpuCount,
+            SetMaxCpuCount,
+            CpuExclusivityMaskLow,
+            SetCpuExclusivityMaskLow,
+            CpuExclusivityMaskHigh,
+            SetCpuExclusivityMaskHigh,
+            AffinitizeToExclusiveCpus,
+            SetAffinitizeToExclusiveCpus,
+            SaveAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameModeConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGameModeUserConfiguration_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GamingRelatedProcessNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeUserConfiguration", "GamingRelatedProcessNames", 6usize, |this| ((**(this as *const *const IGameModeUserConfiguration_Vtbl)).GamingRelatedProcessNames)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeUserConfiguration", "SaveAsync", 7usize, |this| ((**(this as *const *const IGameModeUserConfiguration_Vtbl)).SaveAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GamingRelatedProcessNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GamingRelatedProcessNames: 0,
+            SaveAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameModeUserConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameModeUserConfigurationStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameModeUserConfigurationStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IGameModeUserConfigurationStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameModeUserConfigurationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -329,6 +329,7 @@ impl GameChatMessageOrigin {
 impl windows_core::TypeKind for GameChatMessageOrigin {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameChatMessageOrigin {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameChatMessageOrigin").field(&self.0).finish()
@@ -353,6 +354,7 @@ impl GameChatOverlayPosition {
 impl windows_core::TypeKind for GameChatOverlayPosition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameChatOverlayPosition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameChatOverlayPosition").field(&self.0).finish()
@@ -361,3 +363,5 @@ impl core::fmt::Debug for GameChatOverlayPosition {
 impl windows_core::RuntimeType for GameChatOverlayPosition {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Gaming.UI.GameChatOverlayPosition;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
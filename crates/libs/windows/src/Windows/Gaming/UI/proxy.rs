@@ -0,0 +1,140 @@
+impl windows_core::imp::ProxyVtable for IGameBarStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VisibilityChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarStatics", "VisibilityChanged", 6usize, |this| ((**(this as *const *const IGameBarStatics_Vtbl)).VisibilityChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVisibilityChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarStatics", "RemoveVisibilityChanged", 7usize, |this| ((**(this as *const *const IGameBarStatics_Vtbl)).RemoveVisibilityChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInputRedirectedChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarStatics", "IsInputRedirectedChanged", 8usize, |this| ((**(this as *const *const IGameBarStatics_Vtbl)).IsInputRedirectedChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveIsInputRedirectedChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarStatics", "RemoveIsInputRedirectedChanged", 9usize, |this| ((**(this as *const *const IGameBarStatics_Vtbl)).RemoveIsInputRedirectedChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Visible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarStatics", "Visible", 10usize, |this| ((**(this as *const *const IGameBarStatics_Vtbl)).Visible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInputRedirected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarStatics", "IsInputRedirected", 11usize, |this| ((**(this as *const *const IGameBarStatics_Vtbl)).IsInputRedirected)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            VisibilityChanged,
+            RemoveVisibilityChanged,
+            IsInputRedirectedChanged,
+            RemoveIsInputRedirectedChanged,
+            Visible,
+            IsInputRedirected,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameBarStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameChatMessageReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameChatMessageReceivedEventArgs", "AppId", 6usize, |this| ((**(this as *const *const IGameChatMessageReceivedEventArgs_Vtbl)).AppId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameChatMessageReceivedEventArgs", "AppDisplayName", 7usize, |this| ((**(this as *const *const IGameChatMessageReceivedEventArgs_Vtbl)).AppDisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameChatMessageReceivedEventArgs", "SenderName", 8usize, |this| ((**(this as *const *const IGameChatMessageReceivedEventArgs_Vtbl)).SenderName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameChatMessageReceivedEventArgs", "Message", 9usize, |this| ((**(this as *const *const IGameChatMessageReceivedEventArgs_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Origin(this: *mut core::ffi::c_void, result__: *mut GameChatMessageOrigin) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameChatMessageReceivedEventArgs", "Origin", 10usize, |this| ((**(this as *const *const IGameChatMessageReceivedEventArgs_Vtbl)).Origin)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AppId, AppDisplayName, SenderName, Message, Origin }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameChatMessageReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameChatOverlay_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DesiredPosition(this: *mut core::ffi::c_void, result__: *mut GameChatOverlayPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameChatOverlay", "DesiredPosition", 6usize, |this| ((**(this as *const *const IGameChatOverlay_Vtbl)).DesiredPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredPosition(this: *mut core::ffi::c_void, value: GameChatOverlayPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameChatOverlay", "SetDesiredPosition", 7usize, |this| ((**(this as *const *const IGameChatOverlay_Vtbl)).SetDesiredPosition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddMessage(this: *mut core::ffi::c_void, sender: core::mem::MaybeUninit<windows_core::HSTRING>, message: core::mem::MaybeUninit<windows_core::HSTRING>, origin: GameChatMessageOrigin) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameChatOverlay", "AddMessage", 8usize, |this| ((**(this as *const *const IGameChatOverlay_Vtbl)).AddMessage)(this, sender, message, origin), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DesiredPosition, SetDesiredPosition, AddMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameChatOverlay as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameChatOverlayMessageSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MessageReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameChatOverlayMessageSource", "MessageReceived", 6usize, |this| ((**(this as *const *const IGameChatOverlayMessageSource_Vtbl)).MessageReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMessageReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameChatOverlayMessageSource", "RemoveMessageReceived", 7usize, |this| ((**(this as *const *const IGameChatOverlayMessageSource_Vtbl)).RemoveMessageReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelayBeforeClosingAfterMessageReceived(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameChatOverlayMessageSource", "SetDelayBeforeClosingAfterMessageReceived", 8usize, |this| ((**(this as *const *const IGameChatOverlayMessageSource_Vtbl)).SetDelayBeforeClosingAfterMessageReceived)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MessageReceived,
+            RemoveMessageReceived,
+            SetDelayBeforeClosingAfterMessageReceived,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameChatOverlayMessageSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameChatOverlayStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameChatOverlayStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IGameChatOverlayStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameChatOverlayStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Activation", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IGameUIProviderActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GameUIArgs(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameUIProviderActivatedEventArgs", "GameUIArgs", 6usize, |this| ((**(this as *const *const IGameUIProviderActivatedEventArgs_Vtbl)).GameUIArgs)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReportCompleted(this: *mut core::ffi::c_void, results: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameUIProviderActivatedEventArgs", "ReportCompleted", 7usize, |this| ((**(this as *const *const IGameUIProviderActivatedEventArgs_Vtbl)).ReportCompleted)(this, results), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GameUIArgs,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GameUIArgs: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ReportCompleted,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReportCompleted: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameUIProviderActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -686,6 +686,7 @@ impl GameSaveErrorStatus {
 impl windows_core::TypeKind for GameSaveErrorStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameSaveErrorStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameSaveErrorStatus").field(&self.0).finish()
@@ -694,3 +695,5 @@ impl core::fmt::Debug for GameSaveErrorStatus {
 impl windows_core::RuntimeType for GameSaveErrorStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Gaming.XboxLive.Storage.GameSaveErrorStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
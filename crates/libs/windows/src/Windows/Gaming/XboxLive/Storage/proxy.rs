@@ -0,0 +1,316 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IGameSaveBlobGetResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GameSaveErrorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveBlobGetResult", "Status", 6usize, |this| ((**(this as *const *const IGameSaveBlobGetResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveBlobGetResult", "Value", 7usize, |this| ((**(this as *const *const IGameSaveBlobGetResult_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            Value,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            Value: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameSaveBlobGetResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameSaveBlobInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveBlobInfo", "Name", 6usize, |this| ((**(this as *const *const IGameSaveBlobInfo_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveBlobInfo", "Size", 7usize, |this| ((**(this as *const *const IGameSaveBlobInfo_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, Size }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameSaveBlobInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGameSaveBlobInfoGetResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GameSaveErrorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveBlobInfoGetResult", "Status", 6usize, |this| ((**(this as *const *const IGameSaveBlobInfoGetResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveBlobInfoGetResult", "Value", 7usize, |this| ((**(this as *const *const IGameSaveBlobInfoGetResult_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Foundation_Collections")]
+            Value,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Value: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameSaveBlobInfoGetResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameSaveBlobInfoQuery_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBlobInfoAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveBlobInfoQuery", "GetBlobInfoAsync", 6usize, |this| ((**(this as *const *const IGameSaveBlobInfoQuery_Vtbl)).GetBlobInfoAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBlobInfoWithIndexAndMaxAsync(this: *mut core::ffi::c_void, startindex: u32, maxnumberofitems: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveBlobInfoQuery", "GetBlobInfoWithIndexAndMaxAsync", 7usize, |this| ((**(this as *const *const IGameSaveBlobInfoQuery_Vtbl)).GetBlobInfoWithIndexAndMaxAsync)(this, startindex, maxnumberofitems, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemCountAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveBlobInfoQuery", "GetItemCountAsync", 8usize, |this| ((**(this as *const *const IGameSaveBlobInfoQuery_Vtbl)).GetItemCountAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetBlobInfoAsync,
+            GetBlobInfoWithIndexAndMaxAsync,
+            GetItemCountAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameSaveBlobInfoQuery as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IGameSaveContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainer", "Name", 6usize, |this| ((**(this as *const *const IGameSaveContainer_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Provider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainer", "Provider", 7usize, |this| ((**(this as *const *const IGameSaveContainer_Vtbl)).Provider)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn SubmitUpdatesAsync(this: *mut core::ffi::c_void, blobstowrite: *mut core::ffi::c_void, blobstodelete: *mut core::ffi::c_void, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainer", "SubmitUpdatesAsync", 8usize, |this| ((**(this as *const *const IGameSaveContainer_Vtbl)).SubmitUpdatesAsync)(this, blobstowrite, blobstodelete, displayname, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn ReadAsync(this: *mut core::ffi::c_void, blobstoread: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainer", "ReadAsync", 9usize, |this| ((**(this as *const *const IGameSaveContainer_Vtbl)).ReadAsync)(this, blobstoread, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAsync(this: *mut core::ffi::c_void, blobstoread: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainer", "GetAsync", 10usize, |this| ((**(this as *const *const IGameSaveContainer_Vtbl)).GetAsync)(this, blobstoread, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SubmitPropertySetUpdatesAsync(this: *mut core::ffi::c_void, blobstowrite: *mut core::ffi::c_void, blobstodelete: *mut core::ffi::c_void, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainer", "SubmitPropertySetUpdatesAsync", 11usize, |this| ((**(this as *const *const IGameSaveContainer_Vtbl)).SubmitPropertySetUpdatesAsync)(this, blobstowrite, blobstodelete, displayname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBlobInfoQuery(this: *mut core::ffi::c_void, blobnameprefix: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainer", "CreateBlobInfoQuery", 12usize, |this| ((**(this as *const *const IGameSaveContainer_Vtbl)).CreateBlobInfoQuery)(this, blobnameprefix, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Provider,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            SubmitUpdatesAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            SubmitUpdatesAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            ReadAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            ReadAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SubmitPropertySetUpdatesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SubmitPropertySetUpdatesAsync: 0,
+            CreateBlobInfoQuery,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameSaveContainer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameSaveContainerInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainerInfo", "Name", 6usize, |this| ((**(this as *const *const IGameSaveContainerInfo_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalSize(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainerInfo", "TotalSize", 7usize, |this| ((**(this as *const *const IGameSaveContainerInfo_Vtbl)).TotalSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainerInfo", "DisplayName", 8usize, |this| ((**(this as *const *const IGameSaveContainerInfo_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastModifiedTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainerInfo", "LastModifiedTime", 9usize, |this| ((**(this as *const *const IGameSaveContainerInfo_Vtbl)).LastModifiedTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NeedsSync(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainerInfo", "NeedsSync", 10usize, |this| ((**(this as *const *const IGameSaveContainerInfo_Vtbl)).NeedsSync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, TotalSize, DisplayName, LastModifiedTime, NeedsSync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameSaveContainerInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGameSaveContainerInfoGetResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GameSaveErrorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainerInfoGetResult", "Status", 6usize, |this| ((**(this as *const *const IGameSaveContainerInfoGetResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainerInfoGetResult", "Value", 7usize, |this| ((**(this as *const *const IGameSaveContainerInfoGetResult_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Foundation_Collections")]
+            Value,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Value: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameSaveContainerInfoGetResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameSaveContainerInfoQuery_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetContainerInfoAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainerInfoQuery", "GetContainerInfoAsync", 6usize, |this| ((**(this as *const *const IGameSaveContainerInfoQuery_Vtbl)).GetContainerInfoAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContainerInfoWithIndexAndMaxAsync(this: *mut core::ffi::c_void, startindex: u32, maxnumberofitems: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainerInfoQuery", "GetContainerInfoWithIndexAndMaxAsync", 7usize, |this| ((**(this as *const *const IGameSaveContainerInfoQuery_Vtbl)).GetContainerInfoWithIndexAndMaxAsync)(this, startindex, maxnumberofitems, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemCountAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveContainerInfoQuery", "GetItemCountAsync", 8usize, |this| ((**(this as *const *const IGameSaveContainerInfoQuery_Vtbl)).GetItemCountAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetContainerInfoAsync,
+            GetContainerInfoWithIndexAndMaxAsync,
+            GetItemCountAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameSaveContainerInfoQuery as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameSaveOperationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GameSaveErrorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveOperationResult", "Status", 6usize, |this| ((**(this as *const *const IGameSaveOperationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameSaveOperationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IGameSaveProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveProvider", "User", 6usize, |this| ((**(this as *const *const IGameSaveProvider_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateContainer(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveProvider", "CreateContainer", 7usize, |this| ((**(this as *const *const IGameSaveProvider_Vtbl)).CreateContainer)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteContainerAsync(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveProvider", "DeleteContainerAsync", 8usize, |this| ((**(this as *const *const IGameSaveProvider_Vtbl)).DeleteContainerAsync)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateContainerInfoQuery(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveProvider", "CreateContainerInfoQuery", 9usize, |this| ((**(this as *const *const IGameSaveProvider_Vtbl)).CreateContainerInfoQuery)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateContainerInfoQueryWithName(this: *mut core::ffi::c_void, containernameprefix: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveProvider", "CreateContainerInfoQueryWithName", 10usize, |this| ((**(this as *const *const IGameSaveProvider_Vtbl)).CreateContainerInfoQueryWithName)(this, containernameprefix, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRemainingBytesInQuotaAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveProvider", "GetRemainingBytesInQuotaAsync", 11usize, |this| ((**(this as *const *const IGameSaveProvider_Vtbl)).GetRemainingBytesInQuotaAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ContainersChangedSinceLastSync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveProvider", "ContainersChangedSinceLastSync", 12usize, |this| ((**(this as *const *const IGameSaveProvider_Vtbl)).ContainersChangedSinceLastSync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+            CreateContainer,
+            DeleteContainerAsync,
+            CreateContainerInfoQuery,
+            CreateContainerInfoQueryWithName,
+            GetRemainingBytesInQuotaAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            ContainersChangedSinceLastSync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ContainersChangedSinceLastSync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameSaveProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameSaveProviderGetResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut GameSaveErrorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveProviderGetResult", "Status", 6usize, |this| ((**(this as *const *const IGameSaveProviderGetResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveProviderGetResult", "Value", 7usize, |this| ((**(this as *const *const IGameSaveProviderGetResult_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameSaveProviderGetResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IGameSaveProviderStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, serviceconfigid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveProviderStatics", "GetForUserAsync", 6usize, |this| ((**(this as *const *const IGameSaveProviderStatics_Vtbl)).GetForUserAsync)(this, user, serviceconfigid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetSyncOnDemandForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, serviceconfigid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameSaveProviderStatics", "GetSyncOnDemandForUserAsync", 7usize, |this| ((**(this as *const *const IGameSaveProviderStatics_Vtbl)).GetSyncOnDemandForUserAsync)(this, user, serviceconfigid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetForUserAsync,
+            #[cfg(not(feature = "System"))]
+            GetForUserAsync: 0,
+            #[cfg(feature = "System")]
+            GetSyncOnDemandForUserAsync,
+            #[cfg(not(feature = "System"))]
+            GetSyncOnDemandForUserAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameSaveProviderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -162,3 +162,5 @@ impl IntoIterator for &CharacterGroupings {
 }
 unsafe impl Send for CharacterGroupings {}
 unsafe impl Sync for CharacterGroupings {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -330,6 +330,7 @@ impl DayFormat {
 impl windows_core::TypeKind for DayFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DayFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DayFormat").field(&self.0).finish()
@@ -350,6 +351,7 @@ impl DayOfWeekFormat {
 impl windows_core::TypeKind for DayOfWeekFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DayOfWeekFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DayOfWeekFormat").field(&self.0).finish()
@@ -368,6 +370,7 @@ impl HourFormat {
 impl windows_core::TypeKind for HourFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HourFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HourFormat").field(&self.0).finish()
@@ -386,6 +389,7 @@ impl MinuteFormat {
 impl windows_core::TypeKind for MinuteFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MinuteFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MinuteFormat").field(&self.0).finish()
@@ -407,6 +411,7 @@ impl MonthFormat {
 impl windows_core::TypeKind for MonthFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MonthFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MonthFormat").field(&self.0).finish()
@@ -425,6 +430,7 @@ impl SecondFormat {
 impl windows_core::TypeKind for SecondFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SecondFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SecondFormat").field(&self.0).finish()
@@ -445,6 +451,7 @@ impl YearFormat {
 impl windows_core::TypeKind for YearFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for YearFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("YearFormat").field(&self.0).finish()
@@ -453,3 +460,5 @@ impl core::fmt::Debug for YearFormat {
 impl windows_core::RuntimeType for YearFormat {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Globalization.DateTimeFormatting.YearFormat;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,181 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDateTimeFormatter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Languages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "Languages", 6usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).Languages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GeographicRegion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "GeographicRegion", 7usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).GeographicRegion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Calendar(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "Calendar", 8usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).Calendar)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clock(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "Clock", 9usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).Clock)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumeralSystem(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "NumeralSystem", 10usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).NumeralSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumeralSystem(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "SetNumeralSystem", 11usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).SetNumeralSystem)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Patterns(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "Patterns", 12usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).Patterns)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Template(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "Template", 13usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).Template)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "Format", 14usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).Format)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeYear(this: *mut core::ffi::c_void, result__: *mut YearFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "IncludeYear", 15usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).IncludeYear)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeMonth(this: *mut core::ffi::c_void, result__: *mut MonthFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "IncludeMonth", 16usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).IncludeMonth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeDayOfWeek(this: *mut core::ffi::c_void, result__: *mut DayOfWeekFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "IncludeDayOfWeek", 17usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).IncludeDayOfWeek)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeDay(this: *mut core::ffi::c_void, result__: *mut DayFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "IncludeDay", 18usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).IncludeDay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeHour(this: *mut core::ffi::c_void, result__: *mut HourFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "IncludeHour", 19usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).IncludeHour)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeMinute(this: *mut core::ffi::c_void, result__: *mut MinuteFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "IncludeMinute", 20usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).IncludeMinute)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeSecond(this: *mut core::ffi::c_void, result__: *mut SecondFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "IncludeSecond", 21usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).IncludeSecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolvedLanguage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "ResolvedLanguage", 22usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).ResolvedLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolvedGeographicRegion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter", "ResolvedGeographicRegion", 23usize, |this| ((**(this as *const *const IDateTimeFormatter_Vtbl)).ResolvedGeographicRegion)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Languages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Languages: 0,
+            GeographicRegion,
+            Calendar,
+            Clock,
+            NumeralSystem,
+            SetNumeralSystem,
+            #[cfg(feature = "Foundation_Collections")]
+            Patterns,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Patterns: 0,
+            Template,
+            Format,
+            IncludeYear,
+            IncludeMonth,
+            IncludeDayOfWeek,
+            IncludeDay,
+            IncludeHour,
+            IncludeMinute,
+            IncludeSecond,
+            ResolvedLanguage,
+            ResolvedGeographicRegion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDateTimeFormatter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDateTimeFormatter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FormatUsingTimeZone(this: *mut core::ffi::c_void, datetime: super::super::Foundation::DateTime, timezoneid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatter2", "FormatUsingTimeZone", 6usize, |this| ((**(this as *const *const IDateTimeFormatter2_Vtbl)).FormatUsingTimeZone)(this, datetime, timezoneid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FormatUsingTimeZone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDateTimeFormatter2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDateTimeFormatterFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDateTimeFormatter(this: *mut core::ffi::c_void, formattemplate: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatterFactory", "CreateDateTimeFormatter", 6usize, |this| ((**(this as *const *const IDateTimeFormatterFactory_Vtbl)).CreateDateTimeFormatter)(this, formattemplate, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateDateTimeFormatterLanguages(this: *mut core::ffi::c_void, formattemplate: core::mem::MaybeUninit<windows_core::HSTRING>, languages: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatterFactory", "CreateDateTimeFormatterLanguages", 7usize, |this| ((**(this as *const *const IDateTimeFormatterFactory_Vtbl)).CreateDateTimeFormatterLanguages)(this, formattemplate, languages, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateDateTimeFormatterContext(this: *mut core::ffi::c_void, formattemplate: core::mem::MaybeUninit<windows_core::HSTRING>, languages: *mut core::ffi::c_void, geographicregion: core::mem::MaybeUninit<windows_core::HSTRING>, calendar: core::mem::MaybeUninit<windows_core::HSTRING>, clock: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatterFactory", "CreateDateTimeFormatterContext", 8usize, |this| ((**(this as *const *const IDateTimeFormatterFactory_Vtbl)).CreateDateTimeFormatterContext)(this, formattemplate, languages, geographicregion, calendar, clock, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDateTimeFormatterDate(this: *mut core::ffi::c_void, yearformat: YearFormat, monthformat: MonthFormat, dayformat: DayFormat, dayofweekformat: DayOfWeekFormat, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatterFactory", "CreateDateTimeFormatterDate", 9usize, |this| ((**(this as *const *const IDateTimeFormatterFactory_Vtbl)).CreateDateTimeFormatterDate)(this, yearformat, monthformat, dayformat, dayofweekformat, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDateTimeFormatterTime(this: *mut core::ffi::c_void, hourformat: HourFormat, minuteformat: MinuteFormat, secondformat: SecondFormat, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatterFactory", "CreateDateTimeFormatterTime", 10usize, |this| ((**(this as *const *const IDateTimeFormatterFactory_Vtbl)).CreateDateTimeFormatterTime)(this, hourformat, minuteformat, secondformat, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateDateTimeFormatterDateTimeLanguages(this: *mut core::ffi::c_void, yearformat: YearFormat, monthformat: MonthFormat, dayformat: DayFormat, dayofweekformat: DayOfWeekFormat, hourformat: HourFormat, minuteformat: MinuteFormat, secondformat: SecondFormat, languages: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatterFactory", "CreateDateTimeFormatterDateTimeLanguages", 11usize, |this| ((**(this as *const *const IDateTimeFormatterFactory_Vtbl)).CreateDateTimeFormatterDateTimeLanguages)(this, yearformat, monthformat, dayformat, dayofweekformat, hourformat, minuteformat, secondformat, languages, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateDateTimeFormatterDateTimeContext(this: *mut core::ffi::c_void, yearformat: YearFormat, monthformat: MonthFormat, dayformat: DayFormat, dayofweekformat: DayOfWeekFormat, hourformat: HourFormat, minuteformat: MinuteFormat, secondformat: SecondFormat, languages: *mut core::ffi::c_void, geographicregion: core::mem::MaybeUninit<windows_core::HSTRING>, calendar: core::mem::MaybeUninit<windows_core::HSTRING>, clock: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatterFactory", "CreateDateTimeFormatterDateTimeContext", 12usize, |this| ((**(this as *const *const IDateTimeFormatterFactory_Vtbl)).CreateDateTimeFormatterDateTimeContext)(this, yearformat, monthformat, dayformat, dayofweekformat, hourformat, minuteformat, secondformat, languages, geographicregion, calendar, clock, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateDateTimeFormatter,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateDateTimeFormatterLanguages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateDateTimeFormatterLanguages: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateDateTimeFormatterContext,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateDateTimeFormatterContext: 0,
+            CreateDateTimeFormatterDate,
+            CreateDateTimeFormatterTime,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateDateTimeFormatterDateTimeLanguages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateDateTimeFormatterDateTimeLanguages: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateDateTimeFormatterDateTimeContext,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateDateTimeFormatterDateTimeContext: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDateTimeFormatterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDateTimeFormatterStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LongDate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatterStatics", "LongDate", 6usize, |this| ((**(this as *const *const IDateTimeFormatterStatics_Vtbl)).LongDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LongTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatterStatics", "LongTime", 7usize, |this| ((**(this as *const *const IDateTimeFormatterStatics_Vtbl)).LongTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShortDate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatterStatics", "ShortDate", 8usize, |this| ((**(this as *const *const IDateTimeFormatterStatics_Vtbl)).ShortDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShortTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeFormatterStatics", "ShortTime", 9usize, |this| ((**(this as *const *const IDateTimeFormatterStatics_Vtbl)).ShortTime)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LongDate, LongTime, ShortDate, ShortTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDateTimeFormatterStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
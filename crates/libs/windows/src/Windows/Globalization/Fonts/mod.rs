@@ -210,3 +210,5 @@ impl windows_core::RuntimeName for LanguageFontGroup {
 }
 unsafe impl Send for LanguageFontGroup {}
 unsafe impl Sync for LanguageFontGroup {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
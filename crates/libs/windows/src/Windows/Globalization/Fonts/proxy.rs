@@ -0,0 +1,111 @@
+#[cfg(feature = "UI_Text")]
+impl windows_core::imp::ProxyVtable for ILanguageFont_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FontFamily(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFont", "FontFamily", 6usize, |this| ((**(this as *const *const ILanguageFont_Vtbl)).FontFamily)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Text")]
+        unsafe extern "system" fn FontWeight(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Text::FontWeight) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFont", "FontWeight", 7usize, |this| ((**(this as *const *const ILanguageFont_Vtbl)).FontWeight)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Text")]
+        unsafe extern "system" fn FontStretch(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Text::FontStretch) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFont", "FontStretch", 8usize, |this| ((**(this as *const *const ILanguageFont_Vtbl)).FontStretch)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Text")]
+        unsafe extern "system" fn FontStyle(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Text::FontStyle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFont", "FontStyle", 9usize, |this| ((**(this as *const *const ILanguageFont_Vtbl)).FontStyle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleFactor(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFont", "ScaleFactor", 10usize, |this| ((**(this as *const *const ILanguageFont_Vtbl)).ScaleFactor)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FontFamily,
+            #[cfg(feature = "UI_Text")]
+            FontWeight,
+            #[cfg(not(feature = "UI_Text"))]
+            FontWeight: 0,
+            #[cfg(feature = "UI_Text")]
+            FontStretch,
+            #[cfg(not(feature = "UI_Text"))]
+            FontStretch: 0,
+            #[cfg(feature = "UI_Text")]
+            FontStyle,
+            #[cfg(not(feature = "UI_Text"))]
+            FontStyle: 0,
+            ScaleFactor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguageFont as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILanguageFontGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UITextFont(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFontGroup", "UITextFont", 6usize, |this| ((**(this as *const *const ILanguageFontGroup_Vtbl)).UITextFont)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UIHeadingFont(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFontGroup", "UIHeadingFont", 7usize, |this| ((**(this as *const *const ILanguageFontGroup_Vtbl)).UIHeadingFont)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UITitleFont(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFontGroup", "UITitleFont", 8usize, |this| ((**(this as *const *const ILanguageFontGroup_Vtbl)).UITitleFont)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UICaptionFont(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFontGroup", "UICaptionFont", 9usize, |this| ((**(this as *const *const ILanguageFontGroup_Vtbl)).UICaptionFont)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UINotificationHeadingFont(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFontGroup", "UINotificationHeadingFont", 10usize, |this| ((**(this as *const *const ILanguageFontGroup_Vtbl)).UINotificationHeadingFont)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TraditionalDocumentFont(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFontGroup", "TraditionalDocumentFont", 11usize, |this| ((**(this as *const *const ILanguageFontGroup_Vtbl)).TraditionalDocumentFont)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModernDocumentFont(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFontGroup", "ModernDocumentFont", 12usize, |this| ((**(this as *const *const ILanguageFontGroup_Vtbl)).ModernDocumentFont)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentHeadingFont(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFontGroup", "DocumentHeadingFont", 13usize, |this| ((**(this as *const *const ILanguageFontGroup_Vtbl)).DocumentHeadingFont)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FixedWidthTextFont(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFontGroup", "FixedWidthTextFont", 14usize, |this| ((**(this as *const *const ILanguageFontGroup_Vtbl)).FixedWidthTextFont)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentAlternate1Font(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFontGroup", "DocumentAlternate1Font", 15usize, |this| ((**(this as *const *const ILanguageFontGroup_Vtbl)).DocumentAlternate1Font)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentAlternate2Font(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFontGroup", "DocumentAlternate2Font", 16usize, |this| ((**(this as *const *const ILanguageFontGroup_Vtbl)).DocumentAlternate2Font)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UITextFont,
+            UIHeadingFont,
+            UITitleFont,
+            UICaptionFont,
+            UINotificationHeadingFont,
+            TraditionalDocumentFont,
+            ModernDocumentFont,
+            DocumentHeadingFont,
+            FixedWidthTextFont,
+            DocumentAlternate1Font,
+            DocumentAlternate2Font,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguageFontGroup as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILanguageFontGroupFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateLanguageFontGroup(this: *mut core::ffi::c_void, languagetag: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFontGroupFactory", "CreateLanguageFontGroup", 6usize, |this| ((**(this as *const *const ILanguageFontGroupFactory_Vtbl)).CreateLanguageFontGroup)(this, languagetag, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateLanguageFontGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguageFontGroupFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1707,6 +1707,7 @@ impl CurrencyFormatterMode {
 impl windows_core::TypeKind for CurrencyFormatterMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CurrencyFormatterMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CurrencyFormatterMode").field(&self.0).finish()
@@ -1734,6 +1735,7 @@ impl RoundingAlgorithm {
 impl windows_core::TypeKind for RoundingAlgorithm {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RoundingAlgorithm {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RoundingAlgorithm").field(&self.0).finish()
@@ -1744,3 +1746,5 @@ impl windows_core::RuntimeType for RoundingAlgorithm {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
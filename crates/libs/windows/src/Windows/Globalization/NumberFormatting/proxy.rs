@@ -0,0 +1,442 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICurrencyFormatter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Currency(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyFormatter", "Currency", 6usize, |this| ((**(this as *const *const ICurrencyFormatter_Vtbl)).Currency)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetCurrency(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyFormatter", "SetCurrency", 7usize, |this| ((**(this as *const *const ICurrencyFormatter_Vtbl)).SetCurrency)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Currency,
+            #[cfg(feature = "deprecated")]
+            SetCurrency,
+            #[cfg(not(feature = "deprecated"))]
+            SetCurrency: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICurrencyFormatter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICurrencyFormatter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut CurrencyFormatterMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyFormatter2", "Mode", 6usize, |this| ((**(this as *const *const ICurrencyFormatter2_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, value: CurrencyFormatterMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyFormatter2", "SetMode", 7usize, |this| ((**(this as *const *const ICurrencyFormatter2_Vtbl)).SetMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyRoundingForCurrency(this: *mut core::ffi::c_void, roundingalgorithm: RoundingAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyFormatter2", "ApplyRoundingForCurrency", 8usize, |this| ((**(this as *const *const ICurrencyFormatter2_Vtbl)).ApplyRoundingForCurrency)(this, roundingalgorithm), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Mode, SetMode, ApplyRoundingForCurrency }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICurrencyFormatter2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICurrencyFormatterFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateCurrencyFormatterCode(this: *mut core::ffi::c_void, currencycode: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyFormatterFactory", "CreateCurrencyFormatterCode", 6usize, |this| ((**(this as *const *const ICurrencyFormatterFactory_Vtbl)).CreateCurrencyFormatterCode)(this, currencycode, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateCurrencyFormatterCodeContext(this: *mut core::ffi::c_void, currencycode: core::mem::MaybeUninit<windows_core::HSTRING>, languages: *mut core::ffi::c_void, geographicregion: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyFormatterFactory", "CreateCurrencyFormatterCodeContext", 7usize, |this| ((**(this as *const *const ICurrencyFormatterFactory_Vtbl)).CreateCurrencyFormatterCodeContext)(this, currencycode, languages, geographicregion, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateCurrencyFormatterCode,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateCurrencyFormatterCodeContext,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateCurrencyFormatterCodeContext: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICurrencyFormatterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDecimalFormatterFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateDecimalFormatter(this: *mut core::ffi::c_void, languages: *mut core::ffi::c_void, geographicregion: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDecimalFormatterFactory", "CreateDecimalFormatter", 6usize, |this| ((**(this as *const *const IDecimalFormatterFactory_Vtbl)).CreateDecimalFormatter)(this, languages, geographicregion, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateDecimalFormatter,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateDecimalFormatter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDecimalFormatterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIncrementNumberRounder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RoundingAlgorithm(this: *mut core::ffi::c_void, result__: *mut RoundingAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIncrementNumberRounder", "RoundingAlgorithm", 6usize, |this| ((**(this as *const *const IIncrementNumberRounder_Vtbl)).RoundingAlgorithm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoundingAlgorithm(this: *mut core::ffi::c_void, value: RoundingAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIncrementNumberRounder", "SetRoundingAlgorithm", 7usize, |this| ((**(this as *const *const IIncrementNumberRounder_Vtbl)).SetRoundingAlgorithm)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Increment(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIncrementNumberRounder", "Increment", 8usize, |this| ((**(this as *const *const IIncrementNumberRounder_Vtbl)).Increment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncrement(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIncrementNumberRounder", "SetIncrement", 9usize, |this| ((**(this as *const *const IIncrementNumberRounder_Vtbl)).SetIncrement)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RoundingAlgorithm,
+            SetRoundingAlgorithm,
+            Increment,
+            SetIncrement,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIncrementNumberRounder as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INumberFormatter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FormatInt(this: *mut core::ffi::c_void, value: i64, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatter", "FormatInt", 6usize, |this| ((**(this as *const *const INumberFormatter_Vtbl)).FormatInt)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatUInt(this: *mut core::ffi::c_void, value: u64, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatter", "FormatUInt", 7usize, |this| ((**(this as *const *const INumberFormatter_Vtbl)).FormatUInt)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatDouble(this: *mut core::ffi::c_void, value: f64, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatter", "FormatDouble", 8usize, |this| ((**(this as *const *const INumberFormatter_Vtbl)).FormatDouble)(this, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FormatInt, FormatUInt, FormatDouble }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INumberFormatter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INumberFormatter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FormatInt(this: *mut core::ffi::c_void, value: i64, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatter2", "FormatInt", 6usize, |this| ((**(this as *const *const INumberFormatter2_Vtbl)).FormatInt)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatUInt(this: *mut core::ffi::c_void, value: u64, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatter2", "FormatUInt", 7usize, |this| ((**(this as *const *const INumberFormatter2_Vtbl)).FormatUInt)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatDouble(this: *mut core::ffi::c_void, value: f64, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatter2", "FormatDouble", 8usize, |this| ((**(this as *const *const INumberFormatter2_Vtbl)).FormatDouble)(this, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FormatInt, FormatUInt, FormatDouble }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INumberFormatter2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for INumberFormatterOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Languages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatterOptions", "Languages", 6usize, |this| ((**(this as *const *const INumberFormatterOptions_Vtbl)).Languages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GeographicRegion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatterOptions", "GeographicRegion", 7usize, |this| ((**(this as *const *const INumberFormatterOptions_Vtbl)).GeographicRegion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IntegerDigits(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatterOptions", "IntegerDigits", 8usize, |this| ((**(this as *const *const INumberFormatterOptions_Vtbl)).IntegerDigits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIntegerDigits(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatterOptions", "SetIntegerDigits", 9usize, |this| ((**(this as *const *const INumberFormatterOptions_Vtbl)).SetIntegerDigits)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FractionDigits(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatterOptions", "FractionDigits", 10usize, |this| ((**(this as *const *const INumberFormatterOptions_Vtbl)).FractionDigits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFractionDigits(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatterOptions", "SetFractionDigits", 11usize, |this| ((**(this as *const *const INumberFormatterOptions_Vtbl)).SetFractionDigits)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGrouped(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatterOptions", "IsGrouped", 12usize, |this| ((**(this as *const *const INumberFormatterOptions_Vtbl)).IsGrouped)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsGrouped(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatterOptions", "SetIsGrouped", 13usize, |this| ((**(this as *const *const INumberFormatterOptions_Vtbl)).SetIsGrouped)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDecimalPointAlwaysDisplayed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatterOptions", "IsDecimalPointAlwaysDisplayed", 14usize, |this| ((**(this as *const *const INumberFormatterOptions_Vtbl)).IsDecimalPointAlwaysDisplayed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsDecimalPointAlwaysDisplayed(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatterOptions", "SetIsDecimalPointAlwaysDisplayed", 15usize, |this| ((**(this as *const *const INumberFormatterOptions_Vtbl)).SetIsDecimalPointAlwaysDisplayed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumeralSystem(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatterOptions", "NumeralSystem", 16usize, |this| ((**(this as *const *const INumberFormatterOptions_Vtbl)).NumeralSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumeralSystem(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatterOptions", "SetNumeralSystem", 17usize, |this| ((**(this as *const *const INumberFormatterOptions_Vtbl)).SetNumeralSystem)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolvedLanguage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatterOptions", "ResolvedLanguage", 18usize, |this| ((**(this as *const *const INumberFormatterOptions_Vtbl)).ResolvedLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolvedGeographicRegion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberFormatterOptions", "ResolvedGeographicRegion", 19usize, |this| ((**(this as *const *const INumberFormatterOptions_Vtbl)).ResolvedGeographicRegion)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Languages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Languages: 0,
+            GeographicRegion,
+            IntegerDigits,
+            SetIntegerDigits,
+            FractionDigits,
+            SetFractionDigits,
+            IsGrouped,
+            SetIsGrouped,
+            IsDecimalPointAlwaysDisplayed,
+            SetIsDecimalPointAlwaysDisplayed,
+            NumeralSystem,
+            SetNumeralSystem,
+            ResolvedLanguage,
+            ResolvedGeographicRegion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INumberFormatterOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INumberParser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseInt(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberParser", "ParseInt", 6usize, |this| ((**(this as *const *const INumberParser_Vtbl)).ParseInt)(this, text, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParseUInt(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberParser", "ParseUInt", 7usize, |this| ((**(this as *const *const INumberParser_Vtbl)).ParseUInt)(this, text, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParseDouble(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberParser", "ParseDouble", 8usize, |this| ((**(this as *const *const INumberParser_Vtbl)).ParseDouble)(this, text, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseInt, ParseUInt, ParseDouble }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INumberParser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INumberRounder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RoundInt32(this: *mut core::ffi::c_void, value: i32, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberRounder", "RoundInt32", 6usize, |this| ((**(this as *const *const INumberRounder_Vtbl)).RoundInt32)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoundUInt32(this: *mut core::ffi::c_void, value: u32, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberRounder", "RoundUInt32", 7usize, |this| ((**(this as *const *const INumberRounder_Vtbl)).RoundUInt32)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoundInt64(this: *mut core::ffi::c_void, value: i64, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberRounder", "RoundInt64", 8usize, |this| ((**(this as *const *const INumberRounder_Vtbl)).RoundInt64)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoundUInt64(this: *mut core::ffi::c_void, value: u64, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberRounder", "RoundUInt64", 9usize, |this| ((**(this as *const *const INumberRounder_Vtbl)).RoundUInt64)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoundSingle(this: *mut core::ffi::c_void, value: f32, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberRounder", "RoundSingle", 10usize, |this| ((**(this as *const *const INumberRounder_Vtbl)).RoundSingle)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoundDouble(this: *mut core::ffi::c_void, value: f64, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberRounder", "RoundDouble", 11usize, |this| ((**(this as *const *const INumberRounder_Vtbl)).RoundDouble)(this, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RoundInt32,
+            RoundUInt32,
+            RoundInt64,
+            RoundUInt64,
+            RoundSingle,
+            RoundDouble,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INumberRounder as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INumberRounderOption_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NumberRounder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberRounderOption", "NumberRounder", 6usize, |this| ((**(this as *const *const INumberRounderOption_Vtbl)).NumberRounder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumberRounder(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumberRounderOption", "SetNumberRounder", 7usize, |this| ((**(this as *const *const INumberRounderOption_Vtbl)).SetNumberRounder)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NumberRounder, SetNumberRounder }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INumberRounderOption as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for INumeralSystemTranslator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Languages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemTranslator", "Languages", 6usize, |this| ((**(this as *const *const INumeralSystemTranslator_Vtbl)).Languages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolvedLanguage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemTranslator", "ResolvedLanguage", 7usize, |this| ((**(this as *const *const INumeralSystemTranslator_Vtbl)).ResolvedLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumeralSystem(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemTranslator", "NumeralSystem", 8usize, |this| ((**(this as *const *const INumeralSystemTranslator_Vtbl)).NumeralSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumeralSystem(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemTranslator", "SetNumeralSystem", 9usize, |this| ((**(this as *const *const INumeralSystemTranslator_Vtbl)).SetNumeralSystem)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TranslateNumerals(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemTranslator", "TranslateNumerals", 10usize, |this| ((**(this as *const *const INumeralSystemTranslator_Vtbl)).TranslateNumerals)(this, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Languages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Languages: 0,
+            ResolvedLanguage,
+            NumeralSystem,
+            SetNumeralSystem,
+            TranslateNumerals,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INumeralSystemTranslator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for INumeralSystemTranslatorFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, languages: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemTranslatorFactory", "Create", 6usize, |this| ((**(this as *const *const INumeralSystemTranslatorFactory_Vtbl)).Create)(this, languages, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Create,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INumeralSystemTranslatorFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPercentFormatterFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreatePercentFormatter(this: *mut core::ffi::c_void, languages: *mut core::ffi::c_void, geographicregion: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPercentFormatterFactory", "CreatePercentFormatter", 6usize, |this| ((**(this as *const *const IPercentFormatterFactory_Vtbl)).CreatePercentFormatter)(this, languages, geographicregion, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreatePercentFormatter,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreatePercentFormatter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPercentFormatterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPermilleFormatterFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreatePermilleFormatter(this: *mut core::ffi::c_void, languages: *mut core::ffi::c_void, geographicregion: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPermilleFormatterFactory", "CreatePermilleFormatter", 6usize, |this| ((**(this as *const *const IPermilleFormatterFactory_Vtbl)).CreatePermilleFormatter)(this, languages, geographicregion, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreatePermilleFormatter,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreatePermilleFormatter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPermilleFormatterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISignedZeroOption_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsZeroSigned(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignedZeroOption", "IsZeroSigned", 6usize, |this| ((**(this as *const *const ISignedZeroOption_Vtbl)).IsZeroSigned)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsZeroSigned(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignedZeroOption", "SetIsZeroSigned", 7usize, |this| ((**(this as *const *const ISignedZeroOption_Vtbl)).SetIsZeroSigned)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsZeroSigned, SetIsZeroSigned }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISignedZeroOption as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISignificantDigitsNumberRounder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RoundingAlgorithm(this: *mut core::ffi::c_void, result__: *mut RoundingAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignificantDigitsNumberRounder", "RoundingAlgorithm", 6usize, |this| ((**(this as *const *const ISignificantDigitsNumberRounder_Vtbl)).RoundingAlgorithm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoundingAlgorithm(this: *mut core::ffi::c_void, value: RoundingAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignificantDigitsNumberRounder", "SetRoundingAlgorithm", 7usize, |this| ((**(this as *const *const ISignificantDigitsNumberRounder_Vtbl)).SetRoundingAlgorithm)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignificantDigits(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignificantDigitsNumberRounder", "SignificantDigits", 8usize, |this| ((**(this as *const *const ISignificantDigitsNumberRounder_Vtbl)).SignificantDigits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignificantDigits(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignificantDigitsNumberRounder", "SetSignificantDigits", 9usize, |this| ((**(this as *const *const ISignificantDigitsNumberRounder_Vtbl)).SetSignificantDigits)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RoundingAlgorithm,
+            SetRoundingAlgorithm,
+            SignificantDigits,
+            SetSignificantDigits,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISignificantDigitsNumberRounder as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISignificantDigitsOption_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SignificantDigits(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignificantDigitsOption", "SignificantDigits", 6usize, |this| ((**(this as *const *const ISignificantDigitsOption_Vtbl)).SignificantDigits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignificantDigits(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignificantDigitsOption", "SetSignificantDigits", 7usize, |this| ((**(this as *const *const ISignificantDigitsOption_Vtbl)).SetSignificantDigits)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SignificantDigits, SetSignificantDigits }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISignificantDigitsOption as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
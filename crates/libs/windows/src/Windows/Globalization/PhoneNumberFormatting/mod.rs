@@ -275,6 +275,7 @@ impl PhoneNumberFormat {
 impl windows_core::TypeKind for PhoneNumberFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneNumberFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneNumberFormat").field(&self.0).finish()
@@ -295,6 +296,7 @@ impl PhoneNumberMatchResult {
 impl windows_core::TypeKind for PhoneNumberMatchResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneNumberMatchResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneNumberMatchResult").field(&self.0).finish()
@@ -316,6 +318,7 @@ impl PhoneNumberParseResult {
 impl windows_core::TypeKind for PhoneNumberParseResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneNumberParseResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneNumberParseResult").field(&self.0).finish()
@@ -344,6 +347,7 @@ impl PredictedPhoneNumberKind {
 impl windows_core::TypeKind for PredictedPhoneNumberKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PredictedPhoneNumberKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PredictedPhoneNumberKind").field(&self.0).finish()
@@ -352,3 +356,5 @@ impl core::fmt::Debug for PredictedPhoneNumberKind {
 impl windows_core::RuntimeType for PredictedPhoneNumberKind {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Globalization.PhoneNumberFormatting.PredictedPhoneNumberKind;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,128 @@
+impl windows_core::imp::ProxyVtable for IPhoneNumberFormatter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, number: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberFormatter", "Format", 6usize, |this| ((**(this as *const *const IPhoneNumberFormatter_Vtbl)).Format)(this, number, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatWithOutputFormat(this: *mut core::ffi::c_void, number: *mut core::ffi::c_void, numberformat: PhoneNumberFormat, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberFormatter", "FormatWithOutputFormat", 7usize, |this| ((**(this as *const *const IPhoneNumberFormatter_Vtbl)).FormatWithOutputFormat)(this, number, numberformat, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatPartialString(this: *mut core::ffi::c_void, number: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberFormatter", "FormatPartialString", 8usize, |this| ((**(this as *const *const IPhoneNumberFormatter_Vtbl)).FormatPartialString)(this, number, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatString(this: *mut core::ffi::c_void, number: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberFormatter", "FormatString", 9usize, |this| ((**(this as *const *const IPhoneNumberFormatter_Vtbl)).FormatString)(this, number, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatStringWithLeftToRightMarkers(this: *mut core::ffi::c_void, number: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberFormatter", "FormatStringWithLeftToRightMarkers", 10usize, |this| ((**(this as *const *const IPhoneNumberFormatter_Vtbl)).FormatStringWithLeftToRightMarkers)(this, number, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Format,
+            FormatWithOutputFormat,
+            FormatPartialString,
+            FormatString,
+            FormatStringWithLeftToRightMarkers,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneNumberFormatter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneNumberFormatterStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryCreate(this: *mut core::ffi::c_void, regioncode: core::mem::MaybeUninit<windows_core::HSTRING>, phonenumber: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberFormatterStatics", "TryCreate", 6usize, |this| ((**(this as *const *const IPhoneNumberFormatterStatics_Vtbl)).TryCreate)(this, regioncode, phonenumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountryCodeForRegion(this: *mut core::ffi::c_void, regioncode: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberFormatterStatics", "GetCountryCodeForRegion", 7usize, |this| ((**(this as *const *const IPhoneNumberFormatterStatics_Vtbl)).GetCountryCodeForRegion)(this, regioncode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNationalDirectDialingPrefixForRegion(this: *mut core::ffi::c_void, regioncode: core::mem::MaybeUninit<windows_core::HSTRING>, stripnondigit: bool, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberFormatterStatics", "GetNationalDirectDialingPrefixForRegion", 8usize, |this| ((**(this as *const *const IPhoneNumberFormatterStatics_Vtbl)).GetNationalDirectDialingPrefixForRegion)(this, regioncode, stripnondigit, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WrapWithLeftToRightMarkers(this: *mut core::ffi::c_void, number: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberFormatterStatics", "WrapWithLeftToRightMarkers", 9usize, |this| ((**(this as *const *const IPhoneNumberFormatterStatics_Vtbl)).WrapWithLeftToRightMarkers)(this, number, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TryCreate,
+            GetCountryCodeForRegion,
+            GetNationalDirectDialingPrefixForRegion,
+            WrapWithLeftToRightMarkers,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneNumberFormatterStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneNumberInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CountryCode(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberInfo", "CountryCode", 6usize, |this| ((**(this as *const *const IPhoneNumberInfo_Vtbl)).CountryCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhoneNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberInfo", "PhoneNumber", 7usize, |this| ((**(this as *const *const IPhoneNumberInfo_Vtbl)).PhoneNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLengthOfGeographicalAreaCode(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberInfo", "GetLengthOfGeographicalAreaCode", 8usize, |this| ((**(this as *const *const IPhoneNumberInfo_Vtbl)).GetLengthOfGeographicalAreaCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNationalSignificantNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberInfo", "GetNationalSignificantNumber", 9usize, |this| ((**(this as *const *const IPhoneNumberInfo_Vtbl)).GetNationalSignificantNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLengthOfNationalDestinationCode(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberInfo", "GetLengthOfNationalDestinationCode", 10usize, |this| ((**(this as *const *const IPhoneNumberInfo_Vtbl)).GetLengthOfNationalDestinationCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PredictNumberKind(this: *mut core::ffi::c_void, result__: *mut PredictedPhoneNumberKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberInfo", "PredictNumberKind", 11usize, |this| ((**(this as *const *const IPhoneNumberInfo_Vtbl)).PredictNumberKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGeographicRegionCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberInfo", "GetGeographicRegionCode", 12usize, |this| ((**(this as *const *const IPhoneNumberInfo_Vtbl)).GetGeographicRegionCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckNumberMatch(this: *mut core::ffi::c_void, othernumber: *mut core::ffi::c_void, result__: *mut PhoneNumberMatchResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberInfo", "CheckNumberMatch", 13usize, |this| ((**(this as *const *const IPhoneNumberInfo_Vtbl)).CheckNumberMatch)(this, othernumber, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CountryCode,
+            PhoneNumber,
+            GetLengthOfGeographicalAreaCode,
+            GetNationalSignificantNumber,
+            GetLengthOfNationalDestinationCode,
+            PredictNumberKind,
+            GetGeographicRegionCode,
+            CheckNumberMatch,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneNumberInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneNumberInfoFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, number: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberInfoFactory", "Create", 6usize, |this| ((**(this as *const *const IPhoneNumberInfoFactory_Vtbl)).Create)(this, number, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneNumberInfoFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneNumberInfoStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, phonenumber: *mut *mut core::ffi::c_void, result__: *mut PhoneNumberParseResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberInfoStatics", "TryParse", 6usize, |this| ((**(this as *const *const IPhoneNumberInfoStatics_Vtbl)).TryParse)(this, input, phonenumber, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParseWithRegion(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, regioncode: core::mem::MaybeUninit<windows_core::HSTRING>, phonenumber: *mut *mut core::ffi::c_void, result__: *mut PhoneNumberParseResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNumberInfoStatics", "TryParseWithRegion", 7usize, |this| ((**(this as *const *const IPhoneNumberInfoStatics_Vtbl)).TryParseWithRegion)(this, input, regioncode, phonenumber, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryParse, TryParseWithRegion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneNumberInfoStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -3171,6 +3171,7 @@ impl DayOfWeek {
 impl windows_core::TypeKind for DayOfWeek {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DayOfWeek {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DayOfWeek").field(&self.0).finish()
@@ -3191,6 +3192,7 @@ impl LanguageLayoutDirection {
 impl windows_core::TypeKind for LanguageLayoutDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LanguageLayoutDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LanguageLayoutDirection").field(&self.0).finish()
@@ -3199,3 +3201,5 @@ impl core::fmt::Debug for LanguageLayoutDirection {
 impl windows_core::RuntimeType for LanguageLayoutDirection {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Globalization.LanguageLayoutDirection;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1795 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IApplicationLanguagesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrimaryLanguageOverride(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationLanguagesStatics", "PrimaryLanguageOverride", 6usize, |this| ((**(this as *const *const IApplicationLanguagesStatics_Vtbl)).PrimaryLanguageOverride)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrimaryLanguageOverride(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationLanguagesStatics", "SetPrimaryLanguageOverride", 7usize, |this| ((**(this as *const *const IApplicationLanguagesStatics_Vtbl)).SetPrimaryLanguageOverride)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Languages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationLanguagesStatics", "Languages", 8usize, |this| ((**(this as *const *const IApplicationLanguagesStatics_Vtbl)).Languages)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ManifestLanguages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationLanguagesStatics", "ManifestLanguages", 9usize, |this| ((**(this as *const *const IApplicationLanguagesStatics_Vtbl)).ManifestLanguages)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PrimaryLanguageOverride,
+            SetPrimaryLanguageOverride,
+            #[cfg(feature = "Foundation_Collections")]
+            Languages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Languages: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ManifestLanguages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ManifestLanguages: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationLanguagesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IApplicationLanguagesStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+        unsafe extern "system" fn GetLanguagesForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationLanguagesStatics2", "GetLanguagesForUser", 6usize, |this| ((**(this as *const *const IApplicationLanguagesStatics2_Vtbl)).GetLanguagesForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+            GetLanguagesForUser,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "System")))]
+            GetLanguagesForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationLanguagesStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICalendar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "Clone", 6usize, |this| ((**(this as *const *const ICalendar_Vtbl)).Clone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetToMin(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SetToMin", 7usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SetToMin)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetToMax(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SetToMax", 8usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SetToMax)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Languages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "Languages", 9usize, |this| ((**(this as *const *const ICalendar_Vtbl)).Languages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumeralSystem(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "NumeralSystem", 10usize, |this| ((**(this as *const *const ICalendar_Vtbl)).NumeralSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumeralSystem(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SetNumeralSystem", 11usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SetNumeralSystem)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCalendarSystem(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "GetCalendarSystem", 12usize, |this| ((**(this as *const *const ICalendar_Vtbl)).GetCalendarSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeCalendarSystem(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "ChangeCalendarSystem", 13usize, |this| ((**(this as *const *const ICalendar_Vtbl)).ChangeCalendarSystem)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClock(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "GetClock", 14usize, |this| ((**(this as *const *const ICalendar_Vtbl)).GetClock)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeClock(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "ChangeClock", 15usize, |this| ((**(this as *const *const ICalendar_Vtbl)).ChangeClock)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDateTime(this: *mut core::ffi::c_void, result__: *mut super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "GetDateTime", 16usize, |this| ((**(this as *const *const ICalendar_Vtbl)).GetDateTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDateTime(this: *mut core::ffi::c_void, value: super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SetDateTime", 17usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SetDateTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetToNow(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SetToNow", 18usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SetToNow)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstEra(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "FirstEra", 19usize, |this| ((**(this as *const *const ICalendar_Vtbl)).FirstEra)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastEra(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "LastEra", 20usize, |this| ((**(this as *const *const ICalendar_Vtbl)).LastEra)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfEras(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "NumberOfEras", 21usize, |this| ((**(this as *const *const ICalendar_Vtbl)).NumberOfEras)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Era(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "Era", 22usize, |this| ((**(this as *const *const ICalendar_Vtbl)).Era)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEra(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SetEra", 23usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SetEra)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEras(this: *mut core::ffi::c_void, eras: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "AddEras", 24usize, |this| ((**(this as *const *const ICalendar_Vtbl)).AddEras)(this, eras), |result| Some(*result))
+        }
+        unsafe extern "system" fn EraAsFullString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "EraAsFullString", 25usize, |this| ((**(this as *const *const ICalendar_Vtbl)).EraAsFullString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EraAsString(this: *mut core::ffi::c_void, ideallength: i32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "EraAsString", 26usize, |this| ((**(this as *const *const ICalendar_Vtbl)).EraAsString)(this, ideallength, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstYearInThisEra(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "FirstYearInThisEra", 27usize, |this| ((**(this as *const *const ICalendar_Vtbl)).FirstYearInThisEra)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastYearInThisEra(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "LastYearInThisEra", 28usize, |this| ((**(this as *const *const ICalendar_Vtbl)).LastYearInThisEra)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfYearsInThisEra(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "NumberOfYearsInThisEra", 29usize, |this| ((**(this as *const *const ICalendar_Vtbl)).NumberOfYearsInThisEra)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Year(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "Year", 30usize, |this| ((**(this as *const *const ICalendar_Vtbl)).Year)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetYear(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SetYear", 31usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SetYear)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddYears(this: *mut core::ffi::c_void, years: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "AddYears", 32usize, |this| ((**(this as *const *const ICalendar_Vtbl)).AddYears)(this, years), |result| Some(*result))
+        }
+        unsafe extern "system" fn YearAsString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "YearAsString", 33usize, |this| ((**(this as *const *const ICalendar_Vtbl)).YearAsString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn YearAsTruncatedString(this: *mut core::ffi::c_void, remainingdigits: i32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "YearAsTruncatedString", 34usize, |this| ((**(this as *const *const ICalendar_Vtbl)).YearAsTruncatedString)(this, remainingdigits, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn YearAsPaddedString(this: *mut core::ffi::c_void, mindigits: i32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "YearAsPaddedString", 35usize, |this| ((**(this as *const *const ICalendar_Vtbl)).YearAsPaddedString)(this, mindigits, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstMonthInThisYear(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "FirstMonthInThisYear", 36usize, |this| ((**(this as *const *const ICalendar_Vtbl)).FirstMonthInThisYear)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastMonthInThisYear(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "LastMonthInThisYear", 37usize, |this| ((**(this as *const *const ICalendar_Vtbl)).LastMonthInThisYear)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfMonthsInThisYear(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "NumberOfMonthsInThisYear", 38usize, |this| ((**(this as *const *const ICalendar_Vtbl)).NumberOfMonthsInThisYear)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Month(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "Month", 39usize, |this| ((**(this as *const *const ICalendar_Vtbl)).Month)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMonth(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SetMonth", 40usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SetMonth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddMonths(this: *mut core::ffi::c_void, months: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "AddMonths", 41usize, |this| ((**(this as *const *const ICalendar_Vtbl)).AddMonths)(this, months), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonthAsFullString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "MonthAsFullString", 42usize, |this| ((**(this as *const *const ICalendar_Vtbl)).MonthAsFullString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonthAsString(this: *mut core::ffi::c_void, ideallength: i32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "MonthAsString", 43usize, |this| ((**(this as *const *const ICalendar_Vtbl)).MonthAsString)(this, ideallength, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonthAsFullSoloString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "MonthAsFullSoloString", 44usize, |this| ((**(this as *const *const ICalendar_Vtbl)).MonthAsFullSoloString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonthAsSoloString(this: *mut core::ffi::c_void, ideallength: i32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "MonthAsSoloString", 45usize, |this| ((**(this as *const *const ICalendar_Vtbl)).MonthAsSoloString)(this, ideallength, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonthAsNumericString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "MonthAsNumericString", 46usize, |this| ((**(this as *const *const ICalendar_Vtbl)).MonthAsNumericString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonthAsPaddedNumericString(this: *mut core::ffi::c_void, mindigits: i32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "MonthAsPaddedNumericString", 47usize, |this| ((**(this as *const *const ICalendar_Vtbl)).MonthAsPaddedNumericString)(this, mindigits, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddWeeks(this: *mut core::ffi::c_void, weeks: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "AddWeeks", 48usize, |this| ((**(this as *const *const ICalendar_Vtbl)).AddWeeks)(this, weeks), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstDayInThisMonth(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "FirstDayInThisMonth", 49usize, |this| ((**(this as *const *const ICalendar_Vtbl)).FirstDayInThisMonth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastDayInThisMonth(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "LastDayInThisMonth", 50usize, |this| ((**(this as *const *const ICalendar_Vtbl)).LastDayInThisMonth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfDaysInThisMonth(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "NumberOfDaysInThisMonth", 51usize, |this| ((**(this as *const *const ICalendar_Vtbl)).NumberOfDaysInThisMonth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Day(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "Day", 52usize, |this| ((**(this as *const *const ICalendar_Vtbl)).Day)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDay(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SetDay", 53usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SetDay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDays(this: *mut core::ffi::c_void, days: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "AddDays", 54usize, |this| ((**(this as *const *const ICalendar_Vtbl)).AddDays)(this, days), |result| Some(*result))
+        }
+        unsafe extern "system" fn DayAsString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "DayAsString", 55usize, |this| ((**(this as *const *const ICalendar_Vtbl)).DayAsString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DayAsPaddedString(this: *mut core::ffi::c_void, mindigits: i32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "DayAsPaddedString", 56usize, |this| ((**(this as *const *const ICalendar_Vtbl)).DayAsPaddedString)(this, mindigits, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DayOfWeek(this: *mut core::ffi::c_void, result__: *mut DayOfWeek) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "DayOfWeek", 57usize, |this| ((**(this as *const *const ICalendar_Vtbl)).DayOfWeek)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DayOfWeekAsFullString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "DayOfWeekAsFullString", 58usize, |this| ((**(this as *const *const ICalendar_Vtbl)).DayOfWeekAsFullString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DayOfWeekAsString(this: *mut core::ffi::c_void, ideallength: i32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "DayOfWeekAsString", 59usize, |this| ((**(this as *const *const ICalendar_Vtbl)).DayOfWeekAsString)(this, ideallength, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DayOfWeekAsFullSoloString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "DayOfWeekAsFullSoloString", 60usize, |this| ((**(this as *const *const ICalendar_Vtbl)).DayOfWeekAsFullSoloString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DayOfWeekAsSoloString(this: *mut core::ffi::c_void, ideallength: i32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "DayOfWeekAsSoloString", 61usize, |this| ((**(this as *const *const ICalendar_Vtbl)).DayOfWeekAsSoloString)(this, ideallength, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstPeriodInThisDay(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "FirstPeriodInThisDay", 62usize, |this| ((**(this as *const *const ICalendar_Vtbl)).FirstPeriodInThisDay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastPeriodInThisDay(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "LastPeriodInThisDay", 63usize, |this| ((**(this as *const *const ICalendar_Vtbl)).LastPeriodInThisDay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfPeriodsInThisDay(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "NumberOfPeriodsInThisDay", 64usize, |this| ((**(this as *const *const ICalendar_Vtbl)).NumberOfPeriodsInThisDay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Period(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "Period", 65usize, |this| ((**(this as *const *const ICalendar_Vtbl)).Period)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPeriod(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SetPeriod", 66usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SetPeriod)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPeriods(this: *mut core::ffi::c_void, periods: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "AddPeriods", 67usize, |this| ((**(this as *const *const ICalendar_Vtbl)).AddPeriods)(this, periods), |result| Some(*result))
+        }
+        unsafe extern "system" fn PeriodAsFullString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "PeriodAsFullString", 68usize, |this| ((**(this as *const *const ICalendar_Vtbl)).PeriodAsFullString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PeriodAsString(this: *mut core::ffi::c_void, ideallength: i32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "PeriodAsString", 69usize, |this| ((**(this as *const *const ICalendar_Vtbl)).PeriodAsString)(this, ideallength, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstHourInThisPeriod(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "FirstHourInThisPeriod", 70usize, |this| ((**(this as *const *const ICalendar_Vtbl)).FirstHourInThisPeriod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastHourInThisPeriod(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "LastHourInThisPeriod", 71usize, |this| ((**(this as *const *const ICalendar_Vtbl)).LastHourInThisPeriod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfHoursInThisPeriod(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "NumberOfHoursInThisPeriod", 72usize, |this| ((**(this as *const *const ICalendar_Vtbl)).NumberOfHoursInThisPeriod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hour(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "Hour", 73usize, |this| ((**(this as *const *const ICalendar_Vtbl)).Hour)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHour(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SetHour", 74usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SetHour)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddHours(this: *mut core::ffi::c_void, hours: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "AddHours", 75usize, |this| ((**(this as *const *const ICalendar_Vtbl)).AddHours)(this, hours), |result| Some(*result))
+        }
+        unsafe extern "system" fn HourAsString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "HourAsString", 76usize, |this| ((**(this as *const *const ICalendar_Vtbl)).HourAsString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HourAsPaddedString(this: *mut core::ffi::c_void, mindigits: i32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "HourAsPaddedString", 77usize, |this| ((**(this as *const *const ICalendar_Vtbl)).HourAsPaddedString)(this, mindigits, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Minute(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "Minute", 78usize, |this| ((**(this as *const *const ICalendar_Vtbl)).Minute)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinute(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SetMinute", 79usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SetMinute)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddMinutes(this: *mut core::ffi::c_void, minutes: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "AddMinutes", 80usize, |this| ((**(this as *const *const ICalendar_Vtbl)).AddMinutes)(this, minutes), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinuteAsString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "MinuteAsString", 81usize, |this| ((**(this as *const *const ICalendar_Vtbl)).MinuteAsString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinuteAsPaddedString(this: *mut core::ffi::c_void, mindigits: i32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "MinuteAsPaddedString", 82usize, |this| ((**(this as *const *const ICalendar_Vtbl)).MinuteAsPaddedString)(this, mindigits, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Second(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "Second", 83usize, |this| ((**(this as *const *const ICalendar_Vtbl)).Second)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecond(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SetSecond", 84usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SetSecond)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSeconds(this: *mut core::ffi::c_void, seconds: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "AddSeconds", 85usize, |this| ((**(this as *const *const ICalendar_Vtbl)).AddSeconds)(this, seconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecondAsString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SecondAsString", 86usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SecondAsString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecondAsPaddedString(this: *mut core::ffi::c_void, mindigits: i32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SecondAsPaddedString", 87usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SecondAsPaddedString)(this, mindigits, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Nanosecond(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "Nanosecond", 88usize, |this| ((**(this as *const *const ICalendar_Vtbl)).Nanosecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNanosecond(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "SetNanosecond", 89usize, |this| ((**(this as *const *const ICalendar_Vtbl)).SetNanosecond)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddNanoseconds(this: *mut core::ffi::c_void, nanoseconds: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "AddNanoseconds", 90usize, |this| ((**(this as *const *const ICalendar_Vtbl)).AddNanoseconds)(this, nanoseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn NanosecondAsString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "NanosecondAsString", 91usize, |this| ((**(this as *const *const ICalendar_Vtbl)).NanosecondAsString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NanosecondAsPaddedString(this: *mut core::ffi::c_void, mindigits: i32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "NanosecondAsPaddedString", 92usize, |this| ((**(this as *const *const ICalendar_Vtbl)).NanosecondAsPaddedString)(this, mindigits, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Compare(this: *mut core::ffi::c_void, other: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "Compare", 93usize, |this| ((**(this as *const *const ICalendar_Vtbl)).Compare)(this, other, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompareDateTime(this: *mut core::ffi::c_void, other: super::Foundation::DateTime, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "CompareDateTime", 94usize, |this| ((**(this as *const *const ICalendar_Vtbl)).CompareDateTime)(this, other, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyTo(this: *mut core::ffi::c_void, other: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "CopyTo", 95usize, |this| ((**(this as *const *const ICalendar_Vtbl)).CopyTo)(this, other), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstMinuteInThisHour(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "FirstMinuteInThisHour", 96usize, |this| ((**(this as *const *const ICalendar_Vtbl)).FirstMinuteInThisHour)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastMinuteInThisHour(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "LastMinuteInThisHour", 97usize, |this| ((**(this as *const *const ICalendar_Vtbl)).LastMinuteInThisHour)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfMinutesInThisHour(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "NumberOfMinutesInThisHour", 98usize, |this| ((**(this as *const *const ICalendar_Vtbl)).NumberOfMinutesInThisHour)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstSecondInThisMinute(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "FirstSecondInThisMinute", 99usize, |this| ((**(this as *const *const ICalendar_Vtbl)).FirstSecondInThisMinute)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastSecondInThisMinute(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "LastSecondInThisMinute", 100usize, |this| ((**(this as *const *const ICalendar_Vtbl)).LastSecondInThisMinute)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfSecondsInThisMinute(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "NumberOfSecondsInThisMinute", 101usize, |this| ((**(this as *const *const ICalendar_Vtbl)).NumberOfSecondsInThisMinute)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolvedLanguage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "ResolvedLanguage", 102usize, |this| ((**(this as *const *const ICalendar_Vtbl)).ResolvedLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDaylightSavingTime(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendar", "IsDaylightSavingTime", 103usize, |this| ((**(this as *const *const ICalendar_Vtbl)).IsDaylightSavingTime)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Clone,
+            SetToMin,
+            SetToMax,
+            #[cfg(feature = "Foundation_Collections")]
+            Languages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Languages: 0,
+            NumeralSystem,
+            SetNumeralSystem,
+            GetCalendarSystem,
+            ChangeCalendarSystem,
+            GetClock,
+            ChangeClock,
+            GetDateTime,
+            SetDateTime,
+            SetToNow,
+            FirstEra,
+            LastEra,
+            NumberOfEras,
+            Era,
+            SetEra,
+            AddEras,
+            EraAsFullString,
+            EraAsString,
+            FirstYearInThisEra,
+            LastYearInThisEra,
+            NumberOfYearsInThisEra,
+            Year,
+            SetYear,
+            AddYears,
+            YearAsString,
+            YearAsTruncatedString,
+            YearAsPaddedString,
+            FirstMonthInThisYear,
+            LastMonthInThisYear,
+            NumberOfMonthsInThisYear,
+            Month,
+            SetMonth,
+            AddMonths,
+            MonthAsFullString,
+            MonthAsString,
+            MonthAsFullSoloString,
+            MonthAsSoloString,
+            MonthAsNumericString,
+            MonthAsPaddedNumericString,
+            AddWeeks,
+            FirstDayInThisMonth,
+            LastDayInThisMonth,
+            NumberOfDaysInThisMonth,
+            Day,
+            SetDay,
+            AddDays,
+            DayAsString,
+            DayAsPaddedString,
+            DayOfWeek,
+            DayOfWeekAsFullString,
+            DayOfWeekAsString,
+            DayOfWeekAsFullSoloString,
+            DayOfWeekAsSoloString,
+            FirstPeriodInThisDay,
+            LastPeriodInThisDay,
+            NumberOfPeriodsInThisDay,
+            Period,
+            SetPeriod,
+            AddPeriods,
+            PeriodAsFullString,
+            PeriodAsString,
+            FirstHourInThisPeriod,
+            LastHourInThisPeriod,
+            NumberOfHoursInThisPeriod,
+            Hour,
+            SetHour,
+            AddHours,
+            HourAsString,
+            HourAsPaddedString,
+            Minute,
+            SetMinute,
+            AddMinutes,
+            MinuteAsString,
+            MinuteAsPaddedString,
+            Second,
+            SetSecond,
+            AddSeconds,
+            SecondAsString,
+            SecondAsPaddedString,
+            Nanosecond,
+            SetNanosecond,
+            AddNanoseconds,
+            NanosecondAsString,
+            NanosecondAsPaddedString,
+            Compare,
+            CompareDateTime,
+            CopyTo,
+            FirstMinuteInThisHour,
+            LastMinuteInThisHour,
+            NumberOfMinutesInThisHour,
+            FirstSecondInThisMinute,
+            LastSecondInThisMinute,
+            NumberOfSecondsInThisMinute,
+            ResolvedLanguage,
+            IsDaylightSavingTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICalendar as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICalendarFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateCalendarDefaultCalendarAndClock(this: *mut core::ffi::c_void, languages: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarFactory", "CreateCalendarDefaultCalendarAndClock", 6usize, |this| ((**(this as *const *const ICalendarFactory_Vtbl)).CreateCalendarDefaultCalendarAndClock)(this, languages, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateCalendar(this: *mut core::ffi::c_void, languages: *mut core::ffi::c_void, calendar: core::mem::MaybeUninit<windows_core::HSTRING>, clock: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarFactory", "CreateCalendar", 7usize, |this| ((**(this as *const *const ICalendarFactory_Vtbl)).CreateCalendar)(this, languages, calendar, clock, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateCalendarDefaultCalendarAndClock,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateCalendarDefaultCalendarAndClock: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateCalendar,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateCalendar: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICalendarFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICalendarFactory2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateCalendarWithTimeZone(this: *mut core::ffi::c_void, languages: *mut core::ffi::c_void, calendar: core::mem::MaybeUninit<windows_core::HSTRING>, clock: core::mem::MaybeUninit<windows_core::HSTRING>, timezoneid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarFactory2", "CreateCalendarWithTimeZone", 6usize, |this| ((**(this as *const *const ICalendarFactory2_Vtbl)).CreateCalendarWithTimeZone)(this, languages, calendar, clock, timezoneid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateCalendarWithTimeZone,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateCalendarWithTimeZone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICalendarFactory2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICalendarIdentifiersStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Gregorian(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarIdentifiersStatics", "Gregorian", 6usize, |this| ((**(this as *const *const ICalendarIdentifiersStatics_Vtbl)).Gregorian)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hebrew(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarIdentifiersStatics", "Hebrew", 7usize, |this| ((**(this as *const *const ICalendarIdentifiersStatics_Vtbl)).Hebrew)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hijri(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarIdentifiersStatics", "Hijri", 8usize, |this| ((**(this as *const *const ICalendarIdentifiersStatics_Vtbl)).Hijri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Japanese(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarIdentifiersStatics", "Japanese", 9usize, |this| ((**(this as *const *const ICalendarIdentifiersStatics_Vtbl)).Japanese)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Julian(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarIdentifiersStatics", "Julian", 10usize, |this| ((**(this as *const *const ICalendarIdentifiersStatics_Vtbl)).Julian)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Korean(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarIdentifiersStatics", "Korean", 11usize, |this| ((**(this as *const *const ICalendarIdentifiersStatics_Vtbl)).Korean)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Taiwan(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarIdentifiersStatics", "Taiwan", 12usize, |this| ((**(this as *const *const ICalendarIdentifiersStatics_Vtbl)).Taiwan)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Thai(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarIdentifiersStatics", "Thai", 13usize, |this| ((**(this as *const *const ICalendarIdentifiersStatics_Vtbl)).Thai)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UmAlQura(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarIdentifiersStatics", "UmAlQura", 14usize, |this| ((**(this as *const *const ICalendarIdentifiersStatics_Vtbl)).UmAlQura)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Gregorian,
+            Hebrew,
+            Hijri,
+            Japanese,
+            Julian,
+            Korean,
+            Taiwan,
+            Thai,
+            UmAlQura,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICalendarIdentifiersStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICalendarIdentifiersStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Persian(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarIdentifiersStatics2", "Persian", 6usize, |this| ((**(this as *const *const ICalendarIdentifiersStatics2_Vtbl)).Persian)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Persian }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICalendarIdentifiersStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICalendarIdentifiersStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChineseLunar(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarIdentifiersStatics3", "ChineseLunar", 6usize, |this| ((**(this as *const *const ICalendarIdentifiersStatics3_Vtbl)).ChineseLunar)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JapaneseLunar(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarIdentifiersStatics3", "JapaneseLunar", 7usize, |this| ((**(this as *const *const ICalendarIdentifiersStatics3_Vtbl)).JapaneseLunar)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KoreanLunar(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarIdentifiersStatics3", "KoreanLunar", 8usize, |this| ((**(this as *const *const ICalendarIdentifiersStatics3_Vtbl)).KoreanLunar)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaiwanLunar(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarIdentifiersStatics3", "TaiwanLunar", 9usize, |this| ((**(this as *const *const ICalendarIdentifiersStatics3_Vtbl)).TaiwanLunar)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VietnameseLunar(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarIdentifiersStatics3", "VietnameseLunar", 10usize, |this| ((**(this as *const *const ICalendarIdentifiersStatics3_Vtbl)).VietnameseLunar)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChineseLunar,
+            JapaneseLunar,
+            KoreanLunar,
+            TaiwanLunar,
+            VietnameseLunar,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICalendarIdentifiersStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClockIdentifiersStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TwelveHour(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClockIdentifiersStatics", "TwelveHour", 6usize, |this| ((**(this as *const *const IClockIdentifiersStatics_Vtbl)).TwelveHour)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TwentyFourHour(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClockIdentifiersStatics", "TwentyFourHour", 7usize, |this| ((**(this as *const *const IClockIdentifiersStatics_Vtbl)).TwentyFourHour)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TwelveHour, TwentyFourHour }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClockIdentifiersStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICurrencyAmount_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Amount(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyAmount", "Amount", 6usize, |this| ((**(this as *const *const ICurrencyAmount_Vtbl)).Amount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Currency(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyAmount", "Currency", 7usize, |this| ((**(this as *const *const ICurrencyAmount_Vtbl)).Currency)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Amount, Currency }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICurrencyAmount as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICurrencyAmountFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, amount: core::mem::MaybeUninit<windows_core::HSTRING>, currency: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyAmountFactory", "Create", 6usize, |this| ((**(this as *const *const ICurrencyAmountFactory_Vtbl)).Create)(this, amount, currency, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICurrencyAmountFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICurrencyIdentifiersStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AED(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "AED", 6usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).AED)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AFN(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "AFN", 7usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).AFN)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ALL(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "ALL", 8usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).ALL)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AMD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "AMD", 9usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).AMD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ANG(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "ANG", 10usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).ANG)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AOA(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "AOA", 11usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).AOA)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ARS(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "ARS", 12usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).ARS)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AUD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "AUD", 13usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).AUD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AWG(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "AWG", 14usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).AWG)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AZN(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "AZN", 15usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).AZN)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BAM(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "BAM", 16usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).BAM)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BBD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "BBD", 17usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).BBD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BDT(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "BDT", 18usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).BDT)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BGN(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "BGN", 19usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).BGN)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BHD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "BHD", 20usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).BHD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BIF(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "BIF", 21usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).BIF)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BMD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "BMD", 22usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).BMD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BND(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "BND", 23usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).BND)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BOB(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "BOB", 24usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).BOB)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BRL(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "BRL", 25usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).BRL)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BSD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "BSD", 26usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).BSD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BTN(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "BTN", 27usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).BTN)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BWP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "BWP", 28usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).BWP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BYR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "BYR", 29usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).BYR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BZD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "BZD", 30usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).BZD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CAD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "CAD", 31usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).CAD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CDF(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "CDF", 32usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).CDF)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CHF(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "CHF", 33usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).CHF)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CLP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "CLP", 34usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).CLP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CNY(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "CNY", 35usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).CNY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn COP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "COP", 36usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).COP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CRC(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "CRC", 37usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).CRC)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CUP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "CUP", 38usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).CUP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CVE(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "CVE", 39usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).CVE)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CZK(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "CZK", 40usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).CZK)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DJF(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "DJF", 41usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).DJF)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DKK(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "DKK", 42usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).DKK)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DOP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "DOP", 43usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).DOP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DZD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "DZD", 44usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).DZD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EGP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "EGP", 45usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).EGP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ERN(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "ERN", 46usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).ERN)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ETB(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "ETB", 47usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).ETB)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EUR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "EUR", 48usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).EUR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FJD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "FJD", 49usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).FJD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FKP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "FKP", 50usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).FKP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GBP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "GBP", 51usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).GBP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GEL(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "GEL", 52usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).GEL)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GHS(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "GHS", 53usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).GHS)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GIP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "GIP", 54usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).GIP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GMD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "GMD", 55usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).GMD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GNF(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "GNF", 56usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).GNF)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GTQ(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "GTQ", 57usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).GTQ)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GYD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "GYD", 58usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).GYD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HKD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "HKD", 59usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).HKD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HNL(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "HNL", 60usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).HNL)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HRK(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "HRK", 61usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).HRK)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HTG(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "HTG", 62usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).HTG)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HUF(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "HUF", 63usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).HUF)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IDR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "IDR", 64usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).IDR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ILS(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "ILS", 65usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).ILS)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn INR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "INR", 66usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).INR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IQD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "IQD", 67usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).IQD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IRR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "IRR", 68usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).IRR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ISK(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "ISK", 69usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).ISK)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JMD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "JMD", 70usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).JMD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JOD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "JOD", 71usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).JOD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JPY(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "JPY", 72usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).JPY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KES(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "KES", 73usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).KES)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KGS(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "KGS", 74usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).KGS)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KHR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "KHR", 75usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).KHR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KMF(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "KMF", 76usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).KMF)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KPW(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "KPW", 77usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).KPW)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KRW(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "KRW", 78usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).KRW)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KWD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "KWD", 79usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).KWD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KYD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "KYD", 80usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).KYD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KZT(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "KZT", 81usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).KZT)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LAK(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "LAK", 82usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).LAK)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LBP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "LBP", 83usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).LBP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LKR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "LKR", 84usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).LKR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LRD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "LRD", 85usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).LRD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LSL(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "LSL", 86usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).LSL)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LTL(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "LTL", 87usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).LTL)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LVL(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "LVL", 88usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).LVL)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LYD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "LYD", 89usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).LYD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MAD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "MAD", 90usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).MAD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MDL(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "MDL", 91usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).MDL)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MGA(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "MGA", 92usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).MGA)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MKD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "MKD", 93usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).MKD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MMK(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "MMK", 94usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).MMK)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MNT(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "MNT", 95usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).MNT)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MOP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "MOP", 96usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).MOP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MRO(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "MRO", 97usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).MRO)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MUR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "MUR", 98usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).MUR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MVR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "MVR", 99usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).MVR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MWK(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "MWK", 100usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).MWK)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MXN(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "MXN", 101usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).MXN)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MYR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "MYR", 102usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).MYR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MZN(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "MZN", 103usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).MZN)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NAD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "NAD", 104usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).NAD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NGN(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "NGN", 105usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).NGN)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NIO(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "NIO", 106usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).NIO)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NOK(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "NOK", 107usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).NOK)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NPR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "NPR", 108usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).NPR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NZD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "NZD", 109usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).NZD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OMR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "OMR", 110usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).OMR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PAB(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "PAB", 111usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).PAB)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PEN(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "PEN", 112usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).PEN)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PGK(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "PGK", 113usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).PGK)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PHP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "PHP", 114usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).PHP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PKR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "PKR", 115usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).PKR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PLN(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "PLN", 116usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).PLN)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PYG(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "PYG", 117usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).PYG)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn QAR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "QAR", 118usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).QAR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RON(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "RON", 119usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).RON)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RSD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "RSD", 120usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).RSD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RUB(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "RUB", 121usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).RUB)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RWF(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "RWF", 122usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).RWF)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SAR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "SAR", 123usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).SAR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SBD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "SBD", 124usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).SBD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SCR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "SCR", 125usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).SCR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SDG(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "SDG", 126usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).SDG)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SEK(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "SEK", 127usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).SEK)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SGD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "SGD", 128usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).SGD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SHP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "SHP", 129usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).SHP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SLL(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "SLL", 130usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).SLL)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SOS(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "SOS", 131usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).SOS)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SRD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "SRD", 132usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).SRD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn STD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "STD", 133usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).STD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SYP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "SYP", 134usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).SYP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SZL(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "SZL", 135usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).SZL)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn THB(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "THB", 136usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).THB)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TJS(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "TJS", 137usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).TJS)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TMT(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "TMT", 138usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).TMT)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TND(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "TND", 139usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).TND)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TOP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "TOP", 140usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).TOP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TRY(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "TRY", 141usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).TRY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TTD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "TTD", 142usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).TTD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TWD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "TWD", 143usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).TWD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TZS(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "TZS", 144usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).TZS)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UAH(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "UAH", 145usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).UAH)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UGX(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "UGX", 146usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).UGX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn USD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "USD", 147usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).USD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UYU(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "UYU", 148usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).UYU)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UZS(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "UZS", 149usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).UZS)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VEF(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "VEF", 150usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).VEF)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VND(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "VND", 151usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).VND)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VUV(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "VUV", 152usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).VUV)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WST(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "WST", 153usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).WST)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn XAF(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "XAF", 154usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).XAF)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn XCD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "XCD", 155usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).XCD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn XOF(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "XOF", 156usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).XOF)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn XPF(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "XPF", 157usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).XPF)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn XXX(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "XXX", 158usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).XXX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn YER(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "YER", 159usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).YER)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZAR(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "ZAR", 160usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).ZAR)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZMW(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "ZMW", 161usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).ZMW)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZWL(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics", "ZWL", 162usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics_Vtbl)).ZWL)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AED,
+            AFN,
+            ALL,
+            AMD,
+            ANG,
+            AOA,
+            ARS,
+            AUD,
+            AWG,
+            AZN,
+            BAM,
+            BBD,
+            BDT,
+            BGN,
+            BHD,
+            BIF,
+            BMD,
+            BND,
+            BOB,
+            BRL,
+            BSD,
+            BTN,
+            BWP,
+            BYR,
+            BZD,
+            CAD,
+            CDF,
+            CHF,
+            CLP,
+            CNY,
+            COP,
+            CRC,
+            CUP,
+            CVE,
+            CZK,
+            DJF,
+            DKK,
+            DOP,
+            DZD,
+            EGP,
+            ERN,
+            ETB,
+            EUR,
+            FJD,
+            FKP,
+            GBP,
+            GEL,
+            GHS,
+            GIP,
+            GMD,
+            GNF,
+            GTQ,
+            GYD,
+            HKD,
+            HNL,
+            HRK,
+            HTG,
+            HUF,
+            IDR,
+            ILS,
+            INR,
+            IQD,
+            IRR,
+            ISK,
+            JMD,
+            JOD,
+            JPY,
+            KES,
+            KGS,
+            KHR,
+            KMF,
+            KPW,
+            KRW,
+            KWD,
+            KYD,
+            KZT,
+            LAK,
+            LBP,
+            LKR,
+            LRD,
+            LSL,
+            LTL,
+            LVL,
+            LYD,
+            MAD,
+            MDL,
+            MGA,
+            MKD,
+            MMK,
+            MNT,
+            MOP,
+            MRO,
+            MUR,
+            MVR,
+            MWK,
+            MXN,
+            MYR,
+            MZN,
+            NAD,
+            NGN,
+            NIO,
+            NOK,
+            NPR,
+            NZD,
+            OMR,
+            PAB,
+            PEN,
+            PGK,
+            PHP,
+            PKR,
+            PLN,
+            PYG,
+            QAR,
+            RON,
+            RSD,
+            RUB,
+            RWF,
+            SAR,
+            SBD,
+            SCR,
+            SDG,
+            SEK,
+            SGD,
+            SHP,
+            SLL,
+            SOS,
+            SRD,
+            STD,
+            SYP,
+            SZL,
+            THB,
+            TJS,
+            TMT,
+            TND,
+            TOP,
+            TRY,
+            TTD,
+            TWD,
+            TZS,
+            UAH,
+            UGX,
+            USD,
+            UYU,
+            UZS,
+            VEF,
+            VND,
+            VUV,
+            WST,
+            XAF,
+            XCD,
+            XOF,
+            XPF,
+            XXX,
+            YER,
+            ZAR,
+            ZMW,
+            ZWL,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICurrencyIdentifiersStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICurrencyIdentifiersStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BYN(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics2", "BYN", 6usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics2_Vtbl)).BYN)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BYN }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICurrencyIdentifiersStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICurrencyIdentifiersStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MRU(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics3", "MRU", 6usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics3_Vtbl)).MRU)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SSP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics3", "SSP", 7usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics3_Vtbl)).SSP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn STN(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics3", "STN", 8usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics3_Vtbl)).STN)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VES(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrencyIdentifiersStatics3", "VES", 9usize, |this| ((**(this as *const *const ICurrencyIdentifiersStatics3_Vtbl)).VES)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MRU, SSP, STN, VES }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICurrencyIdentifiersStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGeographicRegion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Code(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeographicRegion", "Code", 6usize, |this| ((**(this as *const *const IGeographicRegion_Vtbl)).Code)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CodeTwoLetter(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeographicRegion", "CodeTwoLetter", 7usize, |this| ((**(this as *const *const IGeographicRegion_Vtbl)).CodeTwoLetter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CodeThreeLetter(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeographicRegion", "CodeThreeLetter", 8usize, |this| ((**(this as *const *const IGeographicRegion_Vtbl)).CodeThreeLetter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CodeThreeDigit(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeographicRegion", "CodeThreeDigit", 9usize, |this| ((**(this as *const *const IGeographicRegion_Vtbl)).CodeThreeDigit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeographicRegion", "DisplayName", 10usize, |this| ((**(this as *const *const IGeographicRegion_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NativeName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeographicRegion", "NativeName", 11usize, |this| ((**(this as *const *const IGeographicRegion_Vtbl)).NativeName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CurrenciesInUse(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeographicRegion", "CurrenciesInUse", 12usize, |this| ((**(this as *const *const IGeographicRegion_Vtbl)).CurrenciesInUse)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Code,
+            CodeTwoLetter,
+            CodeThreeLetter,
+            CodeThreeDigit,
+            DisplayName,
+            NativeName,
+            #[cfg(feature = "Foundation_Collections")]
+            CurrenciesInUse,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CurrenciesInUse: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeographicRegion as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeographicRegionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateGeographicRegion(this: *mut core::ffi::c_void, geographicregioncode: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeographicRegionFactory", "CreateGeographicRegion", 6usize, |this| ((**(this as *const *const IGeographicRegionFactory_Vtbl)).CreateGeographicRegion)(this, geographicregioncode, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateGeographicRegion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeographicRegionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGeographicRegionStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, geographicregioncode: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeographicRegionStatics", "IsSupported", 6usize, |this| ((**(this as *const *const IGeographicRegionStatics_Vtbl)).IsSupported)(this, geographicregioncode, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeographicRegionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJapanesePhoneme_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJapanesePhoneme", "DisplayText", 6usize, |this| ((**(this as *const *const IJapanesePhoneme_Vtbl)).DisplayText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn YomiText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJapanesePhoneme", "YomiText", 7usize, |this| ((**(this as *const *const IJapanesePhoneme_Vtbl)).YomiText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPhraseStart(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJapanesePhoneme", "IsPhraseStart", 8usize, |this| ((**(this as *const *const IJapanesePhoneme_Vtbl)).IsPhraseStart)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayText, YomiText, IsPhraseStart }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJapanesePhoneme as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IJapanesePhoneticAnalyzerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetWords(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJapanesePhoneticAnalyzerStatics", "GetWords", 6usize, |this| ((**(this as *const *const IJapanesePhoneticAnalyzerStatics_Vtbl)).GetWords)(this, input, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetWordsWithMonoRubyOption(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, monoruby: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJapanesePhoneticAnalyzerStatics", "GetWordsWithMonoRubyOption", 7usize, |this| ((**(this as *const *const IJapanesePhoneticAnalyzerStatics_Vtbl)).GetWordsWithMonoRubyOption)(this, input, monoruby, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetWords,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetWords: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetWordsWithMonoRubyOption,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetWordsWithMonoRubyOption: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJapanesePhoneticAnalyzerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILanguage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LanguageTag(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguage", "LanguageTag", 6usize, |this| ((**(this as *const *const ILanguage_Vtbl)).LanguageTag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguage", "DisplayName", 7usize, |this| ((**(this as *const *const ILanguage_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NativeName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguage", "NativeName", 8usize, |this| ((**(this as *const *const ILanguage_Vtbl)).NativeName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Script(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguage", "Script", 9usize, |this| ((**(this as *const *const ILanguage_Vtbl)).Script)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LanguageTag, DisplayName, NativeName, Script }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILanguage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LayoutDirection(this: *mut core::ffi::c_void, result__: *mut LanguageLayoutDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguage2", "LayoutDirection", 6usize, |this| ((**(this as *const *const ILanguage2_Vtbl)).LayoutDirection)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LayoutDirection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguage2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILanguage3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AbbreviatedName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguage3", "AbbreviatedName", 6usize, |this| ((**(this as *const *const ILanguage3_Vtbl)).AbbreviatedName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AbbreviatedName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguage3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ILanguageExtensionSubtags_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetExtensionSubtags(this: *mut core::ffi::c_void, singleton: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageExtensionSubtags", "GetExtensionSubtags", 6usize, |this| ((**(this as *const *const ILanguageExtensionSubtags_Vtbl)).GetExtensionSubtags)(this, singleton, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetExtensionSubtags,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetExtensionSubtags: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguageExtensionSubtags as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILanguageFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateLanguage(this: *mut core::ffi::c_void, languagetag: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageFactory", "CreateLanguage", 6usize, |this| ((**(this as *const *const ILanguageFactory_Vtbl)).CreateLanguage)(this, languagetag, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateLanguage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILanguageStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsWellFormed(this: *mut core::ffi::c_void, languagetag: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageStatics", "IsWellFormed", 6usize, |this| ((**(this as *const *const ILanguageStatics_Vtbl)).IsWellFormed)(this, languagetag, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentInputMethodLanguageTag(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageStatics", "CurrentInputMethodLanguageTag", 7usize, |this| ((**(this as *const *const ILanguageStatics_Vtbl)).CurrentInputMethodLanguageTag)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsWellFormed, CurrentInputMethodLanguageTag }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguageStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILanguageStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TrySetInputMethodLanguageTag(this: *mut core::ffi::c_void, languagetag: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageStatics2", "TrySetInputMethodLanguageTag", 6usize, |this| ((**(this as *const *const ILanguageStatics2_Vtbl)).TrySetInputMethodLanguageTag)(this, languagetag, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TrySetInputMethodLanguageTag }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguageStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ILanguageStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetMuiCompatibleLanguageListFromLanguageTags(this: *mut core::ffi::c_void, languagetags: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageStatics3", "GetMuiCompatibleLanguageListFromLanguageTags", 6usize, |this| ((**(this as *const *const ILanguageStatics3_Vtbl)).GetMuiCompatibleLanguageListFromLanguageTags)(this, languagetags, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetMuiCompatibleLanguageListFromLanguageTags,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetMuiCompatibleLanguageListFromLanguageTags: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguageStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INumeralSystemIdentifiersStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Arab(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Arab", 6usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Arab)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ArabExt(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "ArabExt", 7usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).ArabExt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bali(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Bali", 8usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Bali)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Beng(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Beng", 9usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Beng)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cham(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Cham", 10usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Cham)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deva(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Deva", 11usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Deva)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FullWide(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "FullWide", 12usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).FullWide)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gujr(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Gujr", 13usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Gujr)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Guru(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Guru", 14usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Guru)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HaniDec(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "HaniDec", 15usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).HaniDec)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Java(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Java", 16usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Java)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kali(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Kali", 17usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Kali)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Khmr(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Khmr", 18usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Khmr)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Knda(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Knda", 19usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Knda)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Lana(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Lana", 20usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Lana)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LanaTham(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "LanaTham", 21usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).LanaTham)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Laoo(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Laoo", 22usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Laoo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Latn(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Latn", 23usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Latn)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Lepc(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Lepc", 24usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Lepc)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Limb(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Limb", 25usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Limb)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mlym(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Mlym", 26usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Mlym)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mong(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Mong", 27usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Mong)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mtei(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Mtei", 28usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Mtei)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mymr(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Mymr", 29usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Mymr)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MymrShan(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "MymrShan", 30usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).MymrShan)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Nkoo(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Nkoo", 31usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Nkoo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Olck(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Olck", 32usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Olck)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Orya(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Orya", 33usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Orya)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Saur(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Saur", 34usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Saur)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sund(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Sund", 35usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Sund)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Talu(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Talu", 36usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Talu)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TamlDec(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "TamlDec", 37usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).TamlDec)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Telu(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Telu", 38usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Telu)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Thai(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Thai", 39usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Thai)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tibt(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Tibt", 40usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Tibt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Vaii(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics", "Vaii", 41usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics_Vtbl)).Vaii)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Arab,
+            ArabExt,
+            Bali,
+            Beng,
+            Cham,
+            Deva,
+            FullWide,
+            Gujr,
+            Guru,
+            HaniDec,
+            Java,
+            Kali,
+            Khmr,
+            Knda,
+            Lana,
+            LanaTham,
+            Laoo,
+            Latn,
+            Lepc,
+            Limb,
+            Mlym,
+            Mong,
+            Mtei,
+            Mymr,
+            MymrShan,
+            Nkoo,
+            Olck,
+            Orya,
+            Saur,
+            Sund,
+            Talu,
+            TamlDec,
+            Telu,
+            Thai,
+            Tibt,
+            Vaii,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INumeralSystemIdentifiersStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INumeralSystemIdentifiersStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Brah(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics2", "Brah", 6usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics2_Vtbl)).Brah)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Osma(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics2", "Osma", 7usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics2_Vtbl)).Osma)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MathBold(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics2", "MathBold", 8usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics2_Vtbl)).MathBold)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MathDbl(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics2", "MathDbl", 9usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics2_Vtbl)).MathDbl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MathSans(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics2", "MathSans", 10usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics2_Vtbl)).MathSans)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MathSanb(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics2", "MathSanb", 11usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics2_Vtbl)).MathSanb)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MathMono(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics2", "MathMono", 12usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics2_Vtbl)).MathMono)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZmthBold(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics2", "ZmthBold", 13usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics2_Vtbl)).ZmthBold)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZmthDbl(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics2", "ZmthDbl", 14usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics2_Vtbl)).ZmthDbl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZmthSans(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics2", "ZmthSans", 15usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics2_Vtbl)).ZmthSans)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZmthSanb(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics2", "ZmthSanb", 16usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics2_Vtbl)).ZmthSanb)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZmthMono(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INumeralSystemIdentifiersStatics2", "ZmthMono", 17usize, |this| ((**(this as *const *const INumeralSystemIdentifiersStatics2_Vtbl)).ZmthMono)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Brah,
+            Osma,
+            MathBold,
+            MathDbl,
+            MathSans,
+            MathSanb,
+            MathMono,
+            ZmthBold,
+            ZmthDbl,
+            ZmthSans,
+            ZmthSanb,
+            ZmthMono,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INumeralSystemIdentifiersStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimeZoneOnCalendar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTimeZone(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimeZoneOnCalendar", "GetTimeZone", 6usize, |this| ((**(this as *const *const ITimeZoneOnCalendar_Vtbl)).GetTimeZone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeTimeZone(this: *mut core::ffi::c_void, timezoneid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimeZoneOnCalendar", "ChangeTimeZone", 7usize, |this| ((**(this as *const *const ITimeZoneOnCalendar_Vtbl)).ChangeTimeZone)(this, timezoneid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeZoneAsFullString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimeZoneOnCalendar", "TimeZoneAsFullString", 8usize, |this| ((**(this as *const *const ITimeZoneOnCalendar_Vtbl)).TimeZoneAsFullString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeZoneAsString(this: *mut core::ffi::c_void, ideallength: i32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimeZoneOnCalendar", "TimeZoneAsString", 9usize, |this| ((**(this as *const *const ITimeZoneOnCalendar_Vtbl)).TimeZoneAsString)(this, ideallength, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTimeZone,
+            ChangeTimeZone,
+            TimeZoneAsFullString,
+            TimeZoneAsString,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimeZoneOnCalendar as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -587,6 +587,7 @@ impl GraphicsCaptureAccessKind {
 impl windows_core::TypeKind for GraphicsCaptureAccessKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GraphicsCaptureAccessKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GraphicsCaptureAccessKind").field(&self.0).finish()
@@ -605,6 +606,7 @@ impl GraphicsCaptureDirtyRegionMode {
 impl windows_core::TypeKind for GraphicsCaptureDirtyRegionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GraphicsCaptureDirtyRegionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GraphicsCaptureDirtyRegionMode").field(&self.0).finish()
@@ -613,3 +615,5 @@ impl core::fmt::Debug for GraphicsCaptureDirtyRegionMode {
 impl windows_core::RuntimeType for GraphicsCaptureDirtyRegionMode {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Graphics.Capture.GraphicsCaptureDirtyRegionMode;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,332 @@
+#[cfg(feature = "Graphics_DirectX_Direct3D11")]
+impl windows_core::imp::ProxyVtable for IDirect3D11CaptureFrame_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn Surface(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D11CaptureFrame", "Surface", 6usize, |this| ((**(this as *const *const IDirect3D11CaptureFrame_Vtbl)).Surface)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemRelativeTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D11CaptureFrame", "SystemRelativeTime", 7usize, |this| ((**(this as *const *const IDirect3D11CaptureFrame_Vtbl)).SystemRelativeTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentSize(this: *mut core::ffi::c_void, result__: *mut super::SizeInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D11CaptureFrame", "ContentSize", 8usize, |this| ((**(this as *const *const IDirect3D11CaptureFrame_Vtbl)).ContentSize)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            Surface,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            Surface: 0,
+            SystemRelativeTime,
+            ContentSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3D11CaptureFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDirect3D11CaptureFrame2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DirtyRegions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D11CaptureFrame2", "DirtyRegions", 6usize, |this| ((**(this as *const *const IDirect3D11CaptureFrame2_Vtbl)).DirtyRegions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DirtyRegionMode(this: *mut core::ffi::c_void, result__: *mut GraphicsCaptureDirtyRegionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D11CaptureFrame2", "DirtyRegionMode", 7usize, |this| ((**(this as *const *const IDirect3D11CaptureFrame2_Vtbl)).DirtyRegionMode)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            DirtyRegions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DirtyRegions: 0,
+            DirtyRegionMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3D11CaptureFrame2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Graphics_DirectX_Direct3D11", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IDirect3D11CaptureFramePool_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn Recreate(this: *mut core::ffi::c_void, device: *mut core::ffi::c_void, pixelformat: super::DirectX::DirectXPixelFormat, numberofbuffers: i32, size: super::SizeInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D11CaptureFramePool", "Recreate", 6usize, |this| ((**(this as *const *const IDirect3D11CaptureFramePool_Vtbl)).Recreate)(this, device, pixelformat, numberofbuffers, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetNextFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D11CaptureFramePool", "TryGetNextFrame", 7usize, |this| ((**(this as *const *const IDirect3D11CaptureFramePool_Vtbl)).TryGetNextFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameArrived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D11CaptureFramePool", "FrameArrived", 8usize, |this| ((**(this as *const *const IDirect3D11CaptureFramePool_Vtbl)).FrameArrived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFrameArrived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D11CaptureFramePool", "RemoveFrameArrived", 9usize, |this| ((**(this as *const *const IDirect3D11CaptureFramePool_Vtbl)).RemoveFrameArrived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCaptureSession(this: *mut core::ffi::c_void, item: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D11CaptureFramePool", "CreateCaptureSession", 10usize, |this| ((**(this as *const *const IDirect3D11CaptureFramePool_Vtbl)).CreateCaptureSession)(this, item, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn DispatcherQueue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D11CaptureFramePool", "DispatcherQueue", 11usize, |this| ((**(this as *const *const IDirect3D11CaptureFramePool_Vtbl)).DispatcherQueue)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            Recreate,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            Recreate: 0,
+            TryGetNextFrame,
+            FrameArrived,
+            RemoveFrameArrived,
+            CreateCaptureSession,
+            #[cfg(feature = "System")]
+            DispatcherQueue,
+            #[cfg(not(feature = "System"))]
+            DispatcherQueue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3D11CaptureFramePool as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX_Direct3D11")]
+impl windows_core::imp::ProxyVtable for IDirect3D11CaptureFramePoolStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, device: *mut core::ffi::c_void, pixelformat: super::DirectX::DirectXPixelFormat, numberofbuffers: i32, size: super::SizeInt32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D11CaptureFramePoolStatics", "Create", 6usize, |this| ((**(this as *const *const IDirect3D11CaptureFramePoolStatics_Vtbl)).Create)(this, device, pixelformat, numberofbuffers, size, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            Create,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3D11CaptureFramePoolStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX_Direct3D11")]
+impl windows_core::imp::ProxyVtable for IDirect3D11CaptureFramePoolStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn CreateFreeThreaded(this: *mut core::ffi::c_void, device: *mut core::ffi::c_void, pixelformat: super::DirectX::DirectXPixelFormat, numberofbuffers: i32, size: super::SizeInt32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D11CaptureFramePoolStatics2", "CreateFreeThreaded", 6usize, |this| ((**(this as *const *const IDirect3D11CaptureFramePoolStatics2_Vtbl)).CreateFreeThreaded)(this, device, pixelformat, numberofbuffers, size, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            CreateFreeThreaded,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            CreateFreeThreaded: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3D11CaptureFramePoolStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Authorization_AppCapabilityAccess")]
+impl windows_core::imp::ProxyVtable for IGraphicsCaptureAccessStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Authorization_AppCapabilityAccess")]
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, request: GraphicsCaptureAccessKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureAccessStatics", "RequestAccessAsync", 6usize, |this| ((**(this as *const *const IGraphicsCaptureAccessStatics_Vtbl)).RequestAccessAsync)(this, request, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Authorization_AppCapabilityAccess")]
+            RequestAccessAsync,
+            #[cfg(not(feature = "Security_Authorization_AppCapabilityAccess"))]
+            RequestAccessAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphicsCaptureAccessStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGraphicsCaptureItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureItem", "DisplayName", 6usize, |this| ((**(this as *const *const IGraphicsCaptureItem_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut super::SizeInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureItem", "Size", 7usize, |this| ((**(this as *const *const IGraphicsCaptureItem_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureItem", "Closed", 8usize, |this| ((**(this as *const *const IGraphicsCaptureItem_Vtbl)).Closed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureItem", "RemoveClosed", 9usize, |this| ((**(this as *const *const IGraphicsCaptureItem_Vtbl)).RemoveClosed)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayName, Size, Closed, RemoveClosed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphicsCaptureItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Composition")]
+impl windows_core::imp::ProxyVtable for IGraphicsCaptureItemStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Composition")]
+        unsafe extern "system" fn CreateFromVisual(this: *mut core::ffi::c_void, visual: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureItemStatics", "CreateFromVisual", 6usize, |this| ((**(this as *const *const IGraphicsCaptureItemStatics_Vtbl)).CreateFromVisual)(this, visual, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Composition")]
+            CreateFromVisual,
+            #[cfg(not(feature = "UI_Composition"))]
+            CreateFromVisual: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphicsCaptureItemStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for IGraphicsCaptureItemStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn TryCreateFromWindowId(this: *mut core::ffi::c_void, windowid: super::super::UI::WindowId, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureItemStatics2", "TryCreateFromWindowId", 6usize, |this| ((**(this as *const *const IGraphicsCaptureItemStatics2_Vtbl)).TryCreateFromWindowId)(this, windowid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryCreateFromDisplayId(this: *mut core::ffi::c_void, displayid: super::DisplayId, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureItemStatics2", "TryCreateFromDisplayId", 7usize, |this| ((**(this as *const *const IGraphicsCaptureItemStatics2_Vtbl)).TryCreateFromDisplayId)(this, displayid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI")]
+            TryCreateFromWindowId,
+            #[cfg(not(feature = "UI"))]
+            TryCreateFromWindowId: 0,
+            TryCreateFromDisplayId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphicsCaptureItemStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGraphicsCapturePicker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PickSingleItemAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCapturePicker", "PickSingleItemAsync", 6usize, |this| ((**(this as *const *const IGraphicsCapturePicker_Vtbl)).PickSingleItemAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PickSingleItemAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphicsCapturePicker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGraphicsCaptureSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartCapture(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureSession", "StartCapture", 6usize, |this| ((**(this as *const *const IGraphicsCaptureSession_Vtbl)).StartCapture)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartCapture }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphicsCaptureSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGraphicsCaptureSession2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsCursorCaptureEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureSession2", "IsCursorCaptureEnabled", 6usize, |this| ((**(this as *const *const IGraphicsCaptureSession2_Vtbl)).IsCursorCaptureEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCursorCaptureEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureSession2", "SetIsCursorCaptureEnabled", 7usize, |this| ((**(this as *const *const IGraphicsCaptureSession2_Vtbl)).SetIsCursorCaptureEnabled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsCursorCaptureEnabled, SetIsCursorCaptureEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphicsCaptureSession2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGraphicsCaptureSession3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsBorderRequired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureSession3", "IsBorderRequired", 6usize, |this| ((**(this as *const *const IGraphicsCaptureSession3_Vtbl)).IsBorderRequired)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsBorderRequired(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureSession3", "SetIsBorderRequired", 7usize, |this| ((**(this as *const *const IGraphicsCaptureSession3_Vtbl)).SetIsBorderRequired)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsBorderRequired, SetIsBorderRequired }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphicsCaptureSession3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGraphicsCaptureSession4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DirtyRegionMode(this: *mut core::ffi::c_void, result__: *mut GraphicsCaptureDirtyRegionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureSession4", "DirtyRegionMode", 6usize, |this| ((**(this as *const *const IGraphicsCaptureSession4_Vtbl)).DirtyRegionMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDirtyRegionMode(this: *mut core::ffi::c_void, value: GraphicsCaptureDirtyRegionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureSession4", "SetDirtyRegionMode", 7usize, |this| ((**(this as *const *const IGraphicsCaptureSession4_Vtbl)).SetDirtyRegionMode)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DirtyRegionMode, SetDirtyRegionMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphicsCaptureSession4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGraphicsCaptureSession5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinUpdateInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureSession5", "MinUpdateInterval", 6usize, |this| ((**(this as *const *const IGraphicsCaptureSession5_Vtbl)).MinUpdateInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinUpdateInterval(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureSession5", "SetMinUpdateInterval", 7usize, |this| ((**(this as *const *const IGraphicsCaptureSession5_Vtbl)).SetMinUpdateInterval)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MinUpdateInterval, SetMinUpdateInterval }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphicsCaptureSession5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGraphicsCaptureSession6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IncludeSecondaryWindows(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureSession6", "IncludeSecondaryWindows", 6usize, |this| ((**(this as *const *const IGraphicsCaptureSession6_Vtbl)).IncludeSecondaryWindows)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeSecondaryWindows(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureSession6", "SetIncludeSecondaryWindows", 7usize, |this| ((**(this as *const *const IGraphicsCaptureSession6_Vtbl)).SetIncludeSecondaryWindows)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IncludeSecondaryWindows, SetIncludeSecondaryWindows }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphicsCaptureSession6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGraphicsCaptureSessionStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureSessionStatics", "IsSupported", 6usize, |this| ((**(this as *const *const IGraphicsCaptureSessionStatics_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphicsCaptureSessionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
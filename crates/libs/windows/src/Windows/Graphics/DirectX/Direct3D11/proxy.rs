@@ -0,0 +1,24 @@
+impl windows_core::imp::ProxyVtable for IDirect3DDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Trim(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice", "Trim", 6usize, |this| ((**(this as *const *const IDirect3DDevice_Vtbl)).Trim)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Trim }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirect3DSurface_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut Direct3DSurfaceDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSurface", "Description", 6usize, |this| ((**(this as *const *const IDirect3DSurface_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DSurface as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
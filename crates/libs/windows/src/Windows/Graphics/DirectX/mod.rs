@@ -12,6 +12,7 @@ impl DirectXAlphaMode {
 impl windows_core::TypeKind for DirectXAlphaMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DirectXAlphaMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DirectXAlphaMode").field(&self.0).finish()
@@ -53,6 +54,7 @@ impl DirectXColorSpace {
 impl windows_core::TypeKind for DirectXColorSpace {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DirectXColorSpace {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DirectXColorSpace").field(&self.0).finish()
@@ -191,6 +193,7 @@ impl DirectXPixelFormat {
 impl windows_core::TypeKind for DirectXPixelFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DirectXPixelFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DirectXPixelFormat").field(&self.0).finish()
@@ -213,6 +216,7 @@ impl DirectXPrimitiveTopology {
 impl windows_core::TypeKind for DirectXPrimitiveTopology {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DirectXPrimitiveTopology {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DirectXPrimitiveTopology").field(&self.0).finish()
@@ -266,6 +266,7 @@ impl HdmiDisplayColorSpace {
 impl windows_core::TypeKind for HdmiDisplayColorSpace {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HdmiDisplayColorSpace {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HdmiDisplayColorSpace").field(&self.0).finish()
@@ -286,6 +287,7 @@ impl HdmiDisplayHdrOption {
 impl windows_core::TypeKind for HdmiDisplayHdrOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HdmiDisplayHdrOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HdmiDisplayHdrOption").field(&self.0).finish()
@@ -306,6 +308,7 @@ impl HdmiDisplayPixelEncoding {
 impl windows_core::TypeKind for HdmiDisplayPixelEncoding {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HdmiDisplayPixelEncoding {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HdmiDisplayPixelEncoding").field(&self.0).finish()
@@ -315,7 +318,8 @@ impl windows_core::RuntimeType for HdmiDisplayPixelEncoding {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Graphics.Display.Core.HdmiDisplayPixelEncoding;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HdmiDisplayHdr2086Metadata {
     pub RedPrimaryX: u16,
     pub RedPrimaryY: u16,
@@ -341,3 +345,5 @@ impl Default for HdmiDisplayHdr2086Metadata {
         unsafe { core::mem::zeroed() }
     }
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,127 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHdmiDisplayInformation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSupportedDisplayModes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayInformation", "GetSupportedDisplayModes", 6usize, |this| ((**(this as *const *const IHdmiDisplayInformation_Vtbl)).GetSupportedDisplayModes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentDisplayMode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayInformation", "GetCurrentDisplayMode", 7usize, |this| ((**(this as *const *const IHdmiDisplayInformation_Vtbl)).GetCurrentDisplayMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultDisplayModeAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayInformation", "SetDefaultDisplayModeAsync", 8usize, |this| ((**(this as *const *const IHdmiDisplayInformation_Vtbl)).SetDefaultDisplayModeAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestSetCurrentDisplayModeAsync(this: *mut core::ffi::c_void, mode: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayInformation", "RequestSetCurrentDisplayModeAsync", 9usize, |this| ((**(this as *const *const IHdmiDisplayInformation_Vtbl)).RequestSetCurrentDisplayModeAsync)(this, mode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestSetCurrentDisplayModeWithHdrAsync(this: *mut core::ffi::c_void, mode: *mut core::ffi::c_void, hdroption: HdmiDisplayHdrOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayInformation", "RequestSetCurrentDisplayModeWithHdrAsync", 10usize, |this| ((**(this as *const *const IHdmiDisplayInformation_Vtbl)).RequestSetCurrentDisplayModeWithHdrAsync)(this, mode, hdroption, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestSetCurrentDisplayModeWithHdrAndMetadataAsync(this: *mut core::ffi::c_void, mode: *mut core::ffi::c_void, hdroption: HdmiDisplayHdrOption, hdrmetadata: HdmiDisplayHdr2086Metadata, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayInformation", "RequestSetCurrentDisplayModeWithHdrAndMetadataAsync", 11usize, |this| ((**(this as *const *const IHdmiDisplayInformation_Vtbl)).RequestSetCurrentDisplayModeWithHdrAndMetadataAsync)(this, mode, hdroption, hdrmetadata, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayModesChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayInformation", "DisplayModesChanged", 12usize, |this| ((**(this as *const *const IHdmiDisplayInformation_Vtbl)).DisplayModesChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDisplayModesChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayInformation", "RemoveDisplayModesChanged", 13usize, |this| ((**(this as *const *const IHdmiDisplayInformation_Vtbl)).RemoveDisplayModesChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSupportedDisplayModes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSupportedDisplayModes: 0,
+            GetCurrentDisplayMode,
+            SetDefaultDisplayModeAsync,
+            RequestSetCurrentDisplayModeAsync,
+            RequestSetCurrentDisplayModeWithHdrAsync,
+            RequestSetCurrentDisplayModeWithHdrAndMetadataAsync,
+            DisplayModesChanged,
+            RemoveDisplayModesChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHdmiDisplayInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHdmiDisplayInformationStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayInformationStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IHdmiDisplayInformationStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHdmiDisplayInformationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHdmiDisplayMode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResolutionWidthInRawPixels(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayMode", "ResolutionWidthInRawPixels", 6usize, |this| ((**(this as *const *const IHdmiDisplayMode_Vtbl)).ResolutionWidthInRawPixels)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolutionHeightInRawPixels(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayMode", "ResolutionHeightInRawPixels", 7usize, |this| ((**(this as *const *const IHdmiDisplayMode_Vtbl)).ResolutionHeightInRawPixels)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefreshRate(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayMode", "RefreshRate", 8usize, |this| ((**(this as *const *const IHdmiDisplayMode_Vtbl)).RefreshRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StereoEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayMode", "StereoEnabled", 9usize, |this| ((**(this as *const *const IHdmiDisplayMode_Vtbl)).StereoEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitsPerPixel(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayMode", "BitsPerPixel", 10usize, |this| ((**(this as *const *const IHdmiDisplayMode_Vtbl)).BitsPerPixel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, mode: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayMode", "IsEqual", 11usize, |this| ((**(this as *const *const IHdmiDisplayMode_Vtbl)).IsEqual)(this, mode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorSpace(this: *mut core::ffi::c_void, result__: *mut HdmiDisplayColorSpace) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayMode", "ColorSpace", 12usize, |this| ((**(this as *const *const IHdmiDisplayMode_Vtbl)).ColorSpace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PixelEncoding(this: *mut core::ffi::c_void, result__: *mut HdmiDisplayPixelEncoding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayMode", "PixelEncoding", 13usize, |this| ((**(this as *const *const IHdmiDisplayMode_Vtbl)).PixelEncoding)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSdrLuminanceSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayMode", "IsSdrLuminanceSupported", 14usize, |this| ((**(this as *const *const IHdmiDisplayMode_Vtbl)).IsSdrLuminanceSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSmpte2084Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayMode", "IsSmpte2084Supported", 15usize, |this| ((**(this as *const *const IHdmiDisplayMode_Vtbl)).IsSmpte2084Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Is2086MetadataSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayMode", "Is2086MetadataSupported", 16usize, |this| ((**(this as *const *const IHdmiDisplayMode_Vtbl)).Is2086MetadataSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ResolutionWidthInRawPixels,
+            ResolutionHeightInRawPixels,
+            RefreshRate,
+            StereoEnabled,
+            BitsPerPixel,
+            IsEqual,
+            ColorSpace,
+            PixelEncoding,
+            IsSdrLuminanceSupported,
+            IsSmpte2084Supported,
+            Is2086MetadataSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHdmiDisplayMode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHdmiDisplayMode2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDolbyVisionLowLatencySupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdmiDisplayMode2", "IsDolbyVisionLowLatencySupported", 6usize, |this| ((**(this as *const *const IHdmiDisplayMode2_Vtbl)).IsDolbyVisionLowLatencySupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDolbyVisionLowLatencySupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHdmiDisplayMode2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1224,6 +1224,7 @@ impl AdvancedColorKind {
 impl windows_core::TypeKind for AdvancedColorKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AdvancedColorKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AdvancedColorKind").field(&self.0).finish()
@@ -1242,6 +1243,7 @@ impl DisplayBrightnessOverrideOptions {
 impl windows_core::TypeKind for DisplayBrightnessOverrideOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayBrightnessOverrideOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayBrightnessOverrideOptions").field(&self.0).finish()
@@ -1294,6 +1296,7 @@ impl DisplayBrightnessOverrideScenario {
 impl windows_core::TypeKind for DisplayBrightnessOverrideScenario {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayBrightnessOverrideScenario {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayBrightnessOverrideScenario").field(&self.0).finish()
@@ -1314,6 +1317,7 @@ impl DisplayBrightnessScenario {
 impl windows_core::TypeKind for DisplayBrightnessScenario {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayBrightnessScenario {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayBrightnessScenario").field(&self.0).finish()
@@ -1331,6 +1335,7 @@ impl DisplayColorOverrideScenario {
 impl windows_core::TypeKind for DisplayColorOverrideScenario {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayColorOverrideScenario {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayColorOverrideScenario").field(&self.0).finish()
@@ -1352,6 +1357,7 @@ impl DisplayOrientations {
 impl windows_core::TypeKind for DisplayOrientations {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayOrientations {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayOrientations").field(&self.0).finish()
@@ -1403,6 +1409,7 @@ impl HdrMetadataFormat {
 impl windows_core::TypeKind for HdrMetadataFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HdrMetadataFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HdrMetadataFormat").field(&self.0).finish()
@@ -1436,6 +1443,7 @@ impl ResolutionScale {
 impl windows_core::TypeKind for ResolutionScale {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ResolutionScale {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ResolutionScale").field(&self.0).finish()
@@ -1445,7 +1453,8 @@ impl windows_core::RuntimeType for ResolutionScale {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Graphics.Display.ResolutionScale;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NitRange {
     pub MinNits: f32,
     pub MaxNits: f32,
@@ -1532,3 +1541,5 @@ pub struct DisplayPropertiesEventHandler_Vtbl {
     #[cfg(not(feature = "deprecated"))]
     Invoke: usize,
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,674 @@
+impl windows_core::imp::ProxyVtable for IAdvancedColorInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentAdvancedColorKind(this: *mut core::ffi::c_void, result__: *mut AdvancedColorKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedColorInfo", "CurrentAdvancedColorKind", 6usize, |this| ((**(this as *const *const IAdvancedColorInfo_Vtbl)).CurrentAdvancedColorKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedPrimary(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedColorInfo", "RedPrimary", 7usize, |this| ((**(this as *const *const IAdvancedColorInfo_Vtbl)).RedPrimary)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GreenPrimary(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedColorInfo", "GreenPrimary", 8usize, |this| ((**(this as *const *const IAdvancedColorInfo_Vtbl)).GreenPrimary)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BluePrimary(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedColorInfo", "BluePrimary", 9usize, |this| ((**(this as *const *const IAdvancedColorInfo_Vtbl)).BluePrimary)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WhitePoint(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedColorInfo", "WhitePoint", 10usize, |this| ((**(this as *const *const IAdvancedColorInfo_Vtbl)).WhitePoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxLuminanceInNits(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedColorInfo", "MaxLuminanceInNits", 11usize, |this| ((**(this as *const *const IAdvancedColorInfo_Vtbl)).MaxLuminanceInNits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinLuminanceInNits(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedColorInfo", "MinLuminanceInNits", 12usize, |this| ((**(this as *const *const IAdvancedColorInfo_Vtbl)).MinLuminanceInNits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxAverageFullFrameLuminanceInNits(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedColorInfo", "MaxAverageFullFrameLuminanceInNits", 13usize, |this| ((**(this as *const *const IAdvancedColorInfo_Vtbl)).MaxAverageFullFrameLuminanceInNits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SdrWhiteLevelInNits(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedColorInfo", "SdrWhiteLevelInNits", 14usize, |this| ((**(this as *const *const IAdvancedColorInfo_Vtbl)).SdrWhiteLevelInNits)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHdrMetadataFormatCurrentlySupported(this: *mut core::ffi::c_void, format: HdrMetadataFormat, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedColorInfo", "IsHdrMetadataFormatCurrentlySupported", 15usize, |this| ((**(this as *const *const IAdvancedColorInfo_Vtbl)).IsHdrMetadataFormatCurrentlySupported)(this, format, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAdvancedColorKindAvailable(this: *mut core::ffi::c_void, kind: AdvancedColorKind, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedColorInfo", "IsAdvancedColorKindAvailable", 16usize, |this| ((**(this as *const *const IAdvancedColorInfo_Vtbl)).IsAdvancedColorKindAvailable)(this, kind, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentAdvancedColorKind,
+            RedPrimary,
+            GreenPrimary,
+            BluePrimary,
+            WhitePoint,
+            MaxLuminanceInNits,
+            MinLuminanceInNits,
+            MaxAverageFullFrameLuminanceInNits,
+            SdrWhiteLevelInNits,
+            IsHdrMetadataFormatCurrentlySupported,
+            IsAdvancedColorKindAvailable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedColorInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBrightnessOverride_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverride", "IsSupported", 6usize, |this| ((**(this as *const *const IBrightnessOverride_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOverrideActive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverride", "IsOverrideActive", 7usize, |this| ((**(this as *const *const IBrightnessOverride_Vtbl)).IsOverrideActive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrightnessLevel(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverride", "BrightnessLevel", 8usize, |this| ((**(this as *const *const IBrightnessOverride_Vtbl)).BrightnessLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBrightnessLevel(this: *mut core::ffi::c_void, brightnesslevel: f64, options: DisplayBrightnessOverrideOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverride", "SetBrightnessLevel", 9usize, |this| ((**(this as *const *const IBrightnessOverride_Vtbl)).SetBrightnessLevel)(this, brightnesslevel, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBrightnessScenario(this: *mut core::ffi::c_void, scenario: DisplayBrightnessScenario, options: DisplayBrightnessOverrideOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverride", "SetBrightnessScenario", 10usize, |this| ((**(this as *const *const IBrightnessOverride_Vtbl)).SetBrightnessScenario)(this, scenario, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLevelForScenario(this: *mut core::ffi::c_void, scenario: DisplayBrightnessScenario, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverride", "GetLevelForScenario", 11usize, |this| ((**(this as *const *const IBrightnessOverride_Vtbl)).GetLevelForScenario)(this, scenario, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartOverride(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverride", "StartOverride", 12usize, |this| ((**(this as *const *const IBrightnessOverride_Vtbl)).StartOverride)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopOverride(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverride", "StopOverride", 13usize, |this| ((**(this as *const *const IBrightnessOverride_Vtbl)).StopOverride)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupportedChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverride", "IsSupportedChanged", 14usize, |this| ((**(this as *const *const IBrightnessOverride_Vtbl)).IsSupportedChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveIsSupportedChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverride", "RemoveIsSupportedChanged", 15usize, |this| ((**(this as *const *const IBrightnessOverride_Vtbl)).RemoveIsSupportedChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOverrideActiveChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverride", "IsOverrideActiveChanged", 16usize, |this| ((**(this as *const *const IBrightnessOverride_Vtbl)).IsOverrideActiveChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveIsOverrideActiveChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverride", "RemoveIsOverrideActiveChanged", 17usize, |this| ((**(this as *const *const IBrightnessOverride_Vtbl)).RemoveIsOverrideActiveChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrightnessLevelChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverride", "BrightnessLevelChanged", 18usize, |this| ((**(this as *const *const IBrightnessOverride_Vtbl)).BrightnessLevelChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBrightnessLevelChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverride", "RemoveBrightnessLevelChanged", 19usize, |this| ((**(this as *const *const IBrightnessOverride_Vtbl)).RemoveBrightnessLevelChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupported,
+            IsOverrideActive,
+            BrightnessLevel,
+            SetBrightnessLevel,
+            SetBrightnessScenario,
+            GetLevelForScenario,
+            StartOverride,
+            StopOverride,
+            IsSupportedChanged,
+            RemoveIsSupportedChanged,
+            IsOverrideActiveChanged,
+            RemoveIsOverrideActiveChanged,
+            BrightnessLevelChanged,
+            RemoveBrightnessLevelChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBrightnessOverride as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBrightnessOverrideSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DesiredLevel(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverrideSettings", "DesiredLevel", 6usize, |this| ((**(this as *const *const IBrightnessOverrideSettings_Vtbl)).DesiredLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredNits(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverrideSettings", "DesiredNits", 7usize, |this| ((**(this as *const *const IBrightnessOverrideSettings_Vtbl)).DesiredNits)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DesiredLevel, DesiredNits }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBrightnessOverrideSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBrightnessOverrideSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromLevel(this: *mut core::ffi::c_void, level: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverrideSettingsStatics", "CreateFromLevel", 6usize, |this| ((**(this as *const *const IBrightnessOverrideSettingsStatics_Vtbl)).CreateFromLevel)(this, level, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromNits(this: *mut core::ffi::c_void, nits: f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverrideSettingsStatics", "CreateFromNits", 7usize, |this| ((**(this as *const *const IBrightnessOverrideSettingsStatics_Vtbl)).CreateFromNits)(this, nits, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromDisplayBrightnessOverrideScenario(this: *mut core::ffi::c_void, overridescenario: DisplayBrightnessOverrideScenario, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverrideSettingsStatics", "CreateFromDisplayBrightnessOverrideScenario", 8usize, |this| ((**(this as *const *const IBrightnessOverrideSettingsStatics_Vtbl)).CreateFromDisplayBrightnessOverrideScenario)(this, overridescenario, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromLevel,
+            CreateFromNits,
+            CreateFromDisplayBrightnessOverrideScenario,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBrightnessOverrideSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBrightnessOverrideStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultForSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverrideStatics", "GetDefaultForSystem", 6usize, |this| ((**(this as *const *const IBrightnessOverrideStatics_Vtbl)).GetDefaultForSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverrideStatics", "GetForCurrentView", 7usize, |this| ((**(this as *const *const IBrightnessOverrideStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveForSystemAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBrightnessOverrideStatics", "SaveForSystemAsync", 8usize, |this| ((**(this as *const *const IBrightnessOverrideStatics_Vtbl)).SaveForSystemAsync)(this, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefaultForSystem, GetForCurrentView, SaveForSystemAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBrightnessOverrideStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IColorOverrideSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DesiredDisplayColorOverrideScenario(this: *mut core::ffi::c_void, result__: *mut DisplayColorOverrideScenario) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorOverrideSettings", "DesiredDisplayColorOverrideScenario", 6usize, |this| ((**(this as *const *const IColorOverrideSettings_Vtbl)).DesiredDisplayColorOverrideScenario)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DesiredDisplayColorOverrideScenario }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IColorOverrideSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IColorOverrideSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromDisplayColorOverrideScenario(this: *mut core::ffi::c_void, overridescenario: DisplayColorOverrideScenario, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorOverrideSettingsStatics", "CreateFromDisplayColorOverrideScenario", 6usize, |this| ((**(this as *const *const IColorOverrideSettingsStatics_Vtbl)).CreateFromDisplayColorOverrideScenario)(this, overridescenario, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromDisplayColorOverrideScenario }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IColorOverrideSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayEnhancementOverride_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ColorOverrideSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverride", "ColorOverrideSettings", 6usize, |this| ((**(this as *const *const IDisplayEnhancementOverride_Vtbl)).ColorOverrideSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorOverrideSettings(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverride", "SetColorOverrideSettings", 7usize, |this| ((**(this as *const *const IDisplayEnhancementOverride_Vtbl)).SetColorOverrideSettings)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrightnessOverrideSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverride", "BrightnessOverrideSettings", 8usize, |this| ((**(this as *const *const IDisplayEnhancementOverride_Vtbl)).BrightnessOverrideSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBrightnessOverrideSettings(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverride", "SetBrightnessOverrideSettings", 9usize, |this| ((**(this as *const *const IDisplayEnhancementOverride_Vtbl)).SetBrightnessOverrideSettings)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanOverride(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverride", "CanOverride", 10usize, |this| ((**(this as *const *const IDisplayEnhancementOverride_Vtbl)).CanOverride)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOverrideActive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverride", "IsOverrideActive", 11usize, |this| ((**(this as *const *const IDisplayEnhancementOverride_Vtbl)).IsOverrideActive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentDisplayEnhancementOverrideCapabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverride", "GetCurrentDisplayEnhancementOverrideCapabilities", 12usize, |this| ((**(this as *const *const IDisplayEnhancementOverride_Vtbl)).GetCurrentDisplayEnhancementOverrideCapabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestOverride(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverride", "RequestOverride", 13usize, |this| ((**(this as *const *const IDisplayEnhancementOverride_Vtbl)).RequestOverride)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopOverride(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverride", "StopOverride", 14usize, |this| ((**(this as *const *const IDisplayEnhancementOverride_Vtbl)).StopOverride)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanOverrideChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverride", "CanOverrideChanged", 15usize, |this| ((**(this as *const *const IDisplayEnhancementOverride_Vtbl)).CanOverrideChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCanOverrideChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverride", "RemoveCanOverrideChanged", 16usize, |this| ((**(this as *const *const IDisplayEnhancementOverride_Vtbl)).RemoveCanOverrideChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOverrideActiveChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverride", "IsOverrideActiveChanged", 17usize, |this| ((**(this as *const *const IDisplayEnhancementOverride_Vtbl)).IsOverrideActiveChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveIsOverrideActiveChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverride", "RemoveIsOverrideActiveChanged", 18usize, |this| ((**(this as *const *const IDisplayEnhancementOverride_Vtbl)).RemoveIsOverrideActiveChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayEnhancementOverrideCapabilitiesChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverride", "DisplayEnhancementOverrideCapabilitiesChanged", 19usize, |this| ((**(this as *const *const IDisplayEnhancementOverride_Vtbl)).DisplayEnhancementOverrideCapabilitiesChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDisplayEnhancementOverrideCapabilitiesChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverride", "RemoveDisplayEnhancementOverrideCapabilitiesChanged", 20usize, |this| ((**(this as *const *const IDisplayEnhancementOverride_Vtbl)).RemoveDisplayEnhancementOverrideCapabilitiesChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ColorOverrideSettings,
+            SetColorOverrideSettings,
+            BrightnessOverrideSettings,
+            SetBrightnessOverrideSettings,
+            CanOverride,
+            IsOverrideActive,
+            GetCurrentDisplayEnhancementOverrideCapabilities,
+            RequestOverride,
+            StopOverride,
+            CanOverrideChanged,
+            RemoveCanOverrideChanged,
+            IsOverrideActiveChanged,
+            RemoveIsOverrideActiveChanged,
+            DisplayEnhancementOverrideCapabilitiesChanged,
+            RemoveDisplayEnhancementOverrideCapabilitiesChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayEnhancementOverride as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDisplayEnhancementOverrideCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsBrightnessControlSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverrideCapabilities", "IsBrightnessControlSupported", 6usize, |this| ((**(this as *const *const IDisplayEnhancementOverrideCapabilities_Vtbl)).IsBrightnessControlSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBrightnessNitsControlSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverrideCapabilities", "IsBrightnessNitsControlSupported", 7usize, |this| ((**(this as *const *const IDisplayEnhancementOverrideCapabilities_Vtbl)).IsBrightnessNitsControlSupported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSupportedNitRanges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverrideCapabilities", "GetSupportedNitRanges", 8usize, |this| ((**(this as *const *const IDisplayEnhancementOverrideCapabilities_Vtbl)).GetSupportedNitRanges)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsBrightnessControlSupported,
+            IsBrightnessNitsControlSupported,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSupportedNitRanges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSupportedNitRanges: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayEnhancementOverrideCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayEnhancementOverrideCapabilitiesChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Capabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverrideCapabilitiesChangedEventArgs", "Capabilities", 6usize, |this| ((**(this as *const *const IDisplayEnhancementOverrideCapabilitiesChangedEventArgs_Vtbl)).Capabilities)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Capabilities }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayEnhancementOverrideCapabilitiesChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayEnhancementOverrideStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayEnhancementOverrideStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IDisplayEnhancementOverrideStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayEnhancementOverrideStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IDisplayInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentOrientation(this: *mut core::ffi::c_void, result__: *mut DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "CurrentOrientation", 6usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).CurrentOrientation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NativeOrientation(this: *mut core::ffi::c_void, result__: *mut DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "NativeOrientation", 7usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).NativeOrientation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OrientationChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "OrientationChanged", 8usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).OrientationChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOrientationChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "RemoveOrientationChanged", 9usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).RemoveOrientationChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolutionScale(this: *mut core::ffi::c_void, result__: *mut ResolutionScale) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "ResolutionScale", 10usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).ResolutionScale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogicalDpi(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "LogicalDpi", 11usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).LogicalDpi)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawDpiX(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "RawDpiX", 12usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).RawDpiX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawDpiY(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "RawDpiY", 13usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).RawDpiY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DpiChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "DpiChanged", 14usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).DpiChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDpiChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "RemoveDpiChanged", 15usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).RemoveDpiChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn StereoEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "StereoEnabled", 16usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).StereoEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StereoEnabledChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "StereoEnabledChanged", 17usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).StereoEnabledChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStereoEnabledChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "RemoveStereoEnabledChanged", 18usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).RemoveStereoEnabledChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetColorProfileAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "GetColorProfileAsync", 19usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).GetColorProfileAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorProfileChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "ColorProfileChanged", 20usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).ColorProfileChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveColorProfileChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation", "RemoveColorProfileChanged", 21usize, |this| ((**(this as *const *const IDisplayInformation_Vtbl)).RemoveColorProfileChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentOrientation,
+            NativeOrientation,
+            OrientationChanged,
+            RemoveOrientationChanged,
+            ResolutionScale,
+            LogicalDpi,
+            RawDpiX,
+            RawDpiY,
+            DpiChanged,
+            RemoveDpiChanged,
+            StereoEnabled,
+            StereoEnabledChanged,
+            RemoveStereoEnabledChanged,
+            #[cfg(feature = "Storage_Streams")]
+            GetColorProfileAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetColorProfileAsync: 0,
+            ColorProfileChanged,
+            RemoveColorProfileChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IDisplayInformation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RawPixelsPerViewPixel(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation2", "RawPixelsPerViewPixel", 6usize, |this| ((**(this as *const *const IDisplayInformation2_Vtbl)).RawPixelsPerViewPixel)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RawPixelsPerViewPixel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayInformation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayInformation3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DiagonalSizeInInches(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation3", "DiagonalSizeInInches", 6usize, |this| ((**(this as *const *const IDisplayInformation3_Vtbl)).DiagonalSizeInInches)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DiagonalSizeInInches }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayInformation3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayInformation4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScreenWidthInRawPixels(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation4", "ScreenWidthInRawPixels", 6usize, |this| ((**(this as *const *const IDisplayInformation4_Vtbl)).ScreenWidthInRawPixels)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScreenHeightInRawPixels(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation4", "ScreenHeightInRawPixels", 7usize, |this| ((**(this as *const *const IDisplayInformation4_Vtbl)).ScreenHeightInRawPixels)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ScreenWidthInRawPixels, ScreenHeightInRawPixels }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayInformation4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayInformation5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAdvancedColorInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation5", "GetAdvancedColorInfo", 6usize, |this| ((**(this as *const *const IDisplayInformation5_Vtbl)).GetAdvancedColorInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdvancedColorInfoChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation5", "AdvancedColorInfoChanged", 7usize, |this| ((**(this as *const *const IDisplayInformation5_Vtbl)).AdvancedColorInfoChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdvancedColorInfoChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformation5", "RemoveAdvancedColorInfoChanged", 8usize, |this| ((**(this as *const *const IDisplayInformation5_Vtbl)).RemoveAdvancedColorInfoChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAdvancedColorInfo,
+            AdvancedColorInfoChanged,
+            RemoveAdvancedColorInfoChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayInformation5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayInformationStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformationStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IDisplayInformationStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoRotationPreferences(this: *mut core::ffi::c_void, result__: *mut DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformationStatics", "AutoRotationPreferences", 7usize, |this| ((**(this as *const *const IDisplayInformationStatics_Vtbl)).AutoRotationPreferences)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoRotationPreferences(this: *mut core::ffi::c_void, value: DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformationStatics", "SetAutoRotationPreferences", 8usize, |this| ((**(this as *const *const IDisplayInformationStatics_Vtbl)).SetAutoRotationPreferences)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayContentsInvalidated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformationStatics", "DisplayContentsInvalidated", 9usize, |this| ((**(this as *const *const IDisplayInformationStatics_Vtbl)).DisplayContentsInvalidated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDisplayContentsInvalidated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayInformationStatics", "RemoveDisplayContentsInvalidated", 10usize, |this| ((**(this as *const *const IDisplayInformationStatics_Vtbl)).RemoveDisplayContentsInvalidated)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetForCurrentView,
+            AutoRotationPreferences,
+            SetAutoRotationPreferences,
+            DisplayContentsInvalidated,
+            RemoveDisplayContentsInvalidated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayInformationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IDisplayPropertiesStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CurrentOrientation(this: *mut core::ffi::c_void, result__: *mut DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "CurrentOrientation", 6usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).CurrentOrientation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn NativeOrientation(this: *mut core::ffi::c_void, result__: *mut DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "NativeOrientation", 7usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).NativeOrientation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AutoRotationPreferences(this: *mut core::ffi::c_void, result__: *mut DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "AutoRotationPreferences", 8usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).AutoRotationPreferences)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetAutoRotationPreferences(this: *mut core::ffi::c_void, value: DisplayOrientations) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "SetAutoRotationPreferences", 9usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).SetAutoRotationPreferences)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn OrientationChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "OrientationChanged", 10usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).OrientationChanged)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveOrientationChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "RemoveOrientationChanged", 11usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).RemoveOrientationChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ResolutionScale(this: *mut core::ffi::c_void, result__: *mut ResolutionScale) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "ResolutionScale", 12usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).ResolutionScale)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn LogicalDpi(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "LogicalDpi", 13usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).LogicalDpi)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn LogicalDpiChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "LogicalDpiChanged", 14usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).LogicalDpiChanged)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveLogicalDpiChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "RemoveLogicalDpiChanged", 15usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).RemoveLogicalDpiChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn StereoEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "StereoEnabled", 16usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).StereoEnabled)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn StereoEnabledChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "StereoEnabledChanged", 17usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).StereoEnabledChanged)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveStereoEnabledChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "RemoveStereoEnabledChanged", 18usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).RemoveStereoEnabledChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn GetColorProfileAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "GetColorProfileAsync", 19usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).GetColorProfileAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ColorProfileChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "ColorProfileChanged", 20usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).ColorProfileChanged)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveColorProfileChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "RemoveColorProfileChanged", 21usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).RemoveColorProfileChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DisplayContentsInvalidated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "DisplayContentsInvalidated", 22usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).DisplayContentsInvalidated)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveDisplayContentsInvalidated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPropertiesStatics", "RemoveDisplayContentsInvalidated", 23usize, |this| ((**(this as *const *const IDisplayPropertiesStatics_Vtbl)).RemoveDisplayContentsInvalidated)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CurrentOrientation,
+            #[cfg(not(feature = "deprecated"))]
+            CurrentOrientation: 0,
+            #[cfg(feature = "deprecated")]
+            NativeOrientation,
+            #[cfg(not(feature = "deprecated"))]
+            NativeOrientation: 0,
+            #[cfg(feature = "deprecated")]
+            AutoRotationPreferences,
+            #[cfg(not(feature = "deprecated"))]
+            AutoRotationPreferences: 0,
+            #[cfg(feature = "deprecated")]
+            SetAutoRotationPreferences,
+            #[cfg(not(feature = "deprecated"))]
+            SetAutoRotationPreferences: 0,
+            #[cfg(feature = "deprecated")]
+            OrientationChanged,
+            #[cfg(not(feature = "deprecated"))]
+            OrientationChanged: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveOrientationChanged,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveOrientationChanged: 0,
+            #[cfg(feature = "deprecated")]
+            ResolutionScale,
+            #[cfg(not(feature = "deprecated"))]
+            ResolutionScale: 0,
+            #[cfg(feature = "deprecated")]
+            LogicalDpi,
+            #[cfg(not(feature = "deprecated"))]
+            LogicalDpi: 0,
+            #[cfg(feature = "deprecated")]
+            LogicalDpiChanged,
+            #[cfg(not(feature = "deprecated"))]
+            LogicalDpiChanged: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveLogicalDpiChanged,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveLogicalDpiChanged: 0,
+            #[cfg(feature = "deprecated")]
+            StereoEnabled,
+            #[cfg(not(feature = "deprecated"))]
+            StereoEnabled: 0,
+            #[cfg(feature = "deprecated")]
+            StereoEnabledChanged,
+            #[cfg(not(feature = "deprecated"))]
+            StereoEnabledChanged: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveStereoEnabledChanged,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveStereoEnabledChanged: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            GetColorProfileAsync,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            GetColorProfileAsync: 0,
+            #[cfg(feature = "deprecated")]
+            ColorProfileChanged,
+            #[cfg(not(feature = "deprecated"))]
+            ColorProfileChanged: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveColorProfileChanged,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveColorProfileChanged: 0,
+            #[cfg(feature = "deprecated")]
+            DisplayContentsInvalidated,
+            #[cfg(not(feature = "deprecated"))]
+            DisplayContentsInvalidated: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveDisplayContentsInvalidated,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveDisplayContentsInvalidated: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayPropertiesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayServices_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayServices as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayServicesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindAll(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut super::DisplayId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayServicesStatics", "FindAll", 6usize, |this| ((**(this as *const *const IDisplayServicesStatics_Vtbl)).FindAll)(this, result_size__, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindAll }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayServicesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -47,3 +47,5 @@ pub struct IGraphicsEffectSource_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -1876,6 +1876,7 @@ impl HolographicDepthReprojectionMethod {
 impl windows_core::TypeKind for HolographicDepthReprojectionMethod {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HolographicDepthReprojectionMethod {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HolographicDepthReprojectionMethod").field(&self.0).finish()
@@ -1894,6 +1895,7 @@ impl HolographicFramePresentResult {
 impl windows_core::TypeKind for HolographicFramePresentResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HolographicFramePresentResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HolographicFramePresentResult").field(&self.0).finish()
@@ -1912,6 +1914,7 @@ impl HolographicFramePresentWaitBehavior {
 impl windows_core::TypeKind for HolographicFramePresentWaitBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HolographicFramePresentWaitBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HolographicFramePresentWaitBehavior").field(&self.0).finish()
@@ -1931,6 +1934,7 @@ impl HolographicReprojectionMode {
 impl windows_core::TypeKind for HolographicReprojectionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HolographicReprojectionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HolographicReprojectionMode").field(&self.0).finish()
@@ -1950,6 +1954,7 @@ impl HolographicSpaceUserPresence {
 impl windows_core::TypeKind for HolographicSpaceUserPresence {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HolographicSpaceUserPresence {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HolographicSpaceUserPresence").field(&self.0).finish()
@@ -1968,6 +1973,7 @@ impl HolographicViewConfigurationKind {
 impl windows_core::TypeKind for HolographicViewConfigurationKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HolographicViewConfigurationKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HolographicViewConfigurationKind").field(&self.0).finish()
@@ -1977,7 +1983,8 @@ impl windows_core::RuntimeType for HolographicViewConfigurationKind {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Graphics.Holographic.HolographicViewConfigurationKind;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HolographicAdapterId {
     pub LowPart: u32,
     pub HighPart: i32,
@@ -1994,7 +2001,8 @@ impl Default for HolographicAdapterId {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HolographicFrameId {
     pub Value: u64,
 }
@@ -2011,7 +2019,8 @@ impl Default for HolographicFrameId {
 }
 #[repr(C)]
 #[cfg(feature = "Foundation_Numerics")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HolographicStereoTransform {
     pub Left: super::super::Foundation::Numerics::Matrix4x4,
     pub Right: super::super::Foundation::Numerics::Matrix4x4,
@@ -2030,3 +2039,5 @@ impl Default for HolographicStereoTransform {
         unsafe { core::mem::zeroed() }
     }
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
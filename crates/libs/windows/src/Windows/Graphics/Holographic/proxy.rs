@@ -0,0 +1,1103 @@
+impl windows_core::imp::ProxyVtable for IHolographicCamera_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RenderTargetSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera", "RenderTargetSize", 6usize, |this| ((**(this as *const *const IHolographicCamera_Vtbl)).RenderTargetSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ViewportScaleFactor(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera", "ViewportScaleFactor", 7usize, |this| ((**(this as *const *const IHolographicCamera_Vtbl)).ViewportScaleFactor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewportScaleFactor(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera", "SetViewportScaleFactor", 8usize, |this| ((**(this as *const *const IHolographicCamera_Vtbl)).SetViewportScaleFactor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStereo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera", "IsStereo", 9usize, |this| ((**(this as *const *const IHolographicCamera_Vtbl)).IsStereo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera", "Id", 10usize, |this| ((**(this as *const *const IHolographicCamera_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNearPlaneDistance(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera", "SetNearPlaneDistance", 11usize, |this| ((**(this as *const *const IHolographicCamera_Vtbl)).SetNearPlaneDistance)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFarPlaneDistance(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera", "SetFarPlaneDistance", 12usize, |this| ((**(this as *const *const IHolographicCamera_Vtbl)).SetFarPlaneDistance)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RenderTargetSize,
+            ViewportScaleFactor,
+            SetViewportScaleFactor,
+            IsStereo,
+            Id,
+            SetNearPlaneDistance,
+            SetFarPlaneDistance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicCamera as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicCamera2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LeftViewportParameters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera2", "LeftViewportParameters", 6usize, |this| ((**(this as *const *const IHolographicCamera2_Vtbl)).LeftViewportParameters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RightViewportParameters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera2", "RightViewportParameters", 7usize, |this| ((**(this as *const *const IHolographicCamera2_Vtbl)).RightViewportParameters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Display(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera2", "Display", 8usize, |this| ((**(this as *const *const IHolographicCamera2_Vtbl)).Display)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LeftViewportParameters, RightViewportParameters, Display }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicCamera2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHolographicCamera3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPrimaryLayerEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera3", "IsPrimaryLayerEnabled", 6usize, |this| ((**(this as *const *const IHolographicCamera3_Vtbl)).IsPrimaryLayerEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsPrimaryLayerEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera3", "SetIsPrimaryLayerEnabled", 7usize, |this| ((**(this as *const *const IHolographicCamera3_Vtbl)).SetIsPrimaryLayerEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxQuadLayerCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera3", "MaxQuadLayerCount", 8usize, |this| ((**(this as *const *const IHolographicCamera3_Vtbl)).MaxQuadLayerCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn QuadLayers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera3", "QuadLayers", 9usize, |this| ((**(this as *const *const IHolographicCamera3_Vtbl)).QuadLayers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsPrimaryLayerEnabled,
+            SetIsPrimaryLayerEnabled,
+            MaxQuadLayerCount,
+            #[cfg(feature = "Foundation_Collections")]
+            QuadLayers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            QuadLayers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicCamera3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicCamera4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanOverrideViewport(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera4", "CanOverrideViewport", 6usize, |this| ((**(this as *const *const IHolographicCamera4_Vtbl)).CanOverrideViewport)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CanOverrideViewport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicCamera4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicCamera5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsHardwareContentProtectionSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera5", "IsHardwareContentProtectionSupported", 6usize, |this| ((**(this as *const *const IHolographicCamera5_Vtbl)).IsHardwareContentProtectionSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHardwareContentProtectionEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera5", "IsHardwareContentProtectionEnabled", 7usize, |this| ((**(this as *const *const IHolographicCamera5_Vtbl)).IsHardwareContentProtectionEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsHardwareContentProtectionEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera5", "SetIsHardwareContentProtectionEnabled", 8usize, |this| ((**(this as *const *const IHolographicCamera5_Vtbl)).SetIsHardwareContentProtectionEnabled)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsHardwareContentProtectionSupported,
+            IsHardwareContentProtectionEnabled,
+            SetIsHardwareContentProtectionEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicCamera5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicCamera6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ViewConfiguration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCamera6", "ViewConfiguration", 6usize, |this| ((**(this as *const *const IHolographicCamera6_Vtbl)).ViewConfiguration)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ViewConfiguration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicCamera6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for IHolographicCameraPose_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HolographicCamera(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraPose", "HolographicCamera", 6usize, |this| ((**(this as *const *const IHolographicCameraPose_Vtbl)).HolographicCamera)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Viewport(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraPose", "Viewport", 7usize, |this| ((**(this as *const *const IHolographicCameraPose_Vtbl)).Viewport)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn TryGetViewTransform(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraPose", "TryGetViewTransform", 8usize, |this| ((**(this as *const *const IHolographicCameraPose_Vtbl)).TryGetViewTransform)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn ProjectionTransform(this: *mut core::ffi::c_void, result__: *mut HolographicStereoTransform) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraPose", "ProjectionTransform", 9usize, |this| ((**(this as *const *const IHolographicCameraPose_Vtbl)).ProjectionTransform)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn TryGetCullingFrustum(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraPose", "TryGetCullingFrustum", 10usize, |this| ((**(this as *const *const IHolographicCameraPose_Vtbl)).TryGetCullingFrustum)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn TryGetVisibleFrustum(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraPose", "TryGetVisibleFrustum", 11usize, |this| ((**(this as *const *const IHolographicCameraPose_Vtbl)).TryGetVisibleFrustum)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NearPlaneDistance(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraPose", "NearPlaneDistance", 12usize, |this| ((**(this as *const *const IHolographicCameraPose_Vtbl)).NearPlaneDistance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FarPlaneDistance(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraPose", "FarPlaneDistance", 13usize, |this| ((**(this as *const *const IHolographicCameraPose_Vtbl)).FarPlaneDistance)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HolographicCamera,
+            Viewport,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            TryGetViewTransform,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            TryGetViewTransform: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            ProjectionTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            ProjectionTransform: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            TryGetCullingFrustum,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            TryGetCullingFrustum: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            TryGetVisibleFrustum,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            TryGetVisibleFrustum: 0,
+            NearPlaneDistance,
+            FarPlaneDistance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicCameraPose as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for IHolographicCameraPose2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn OverrideViewTransform(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, coordinatesystemtoviewtransform: HolographicStereoTransform) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraPose2", "OverrideViewTransform", 6usize, |this| ((**(this as *const *const IHolographicCameraPose2_Vtbl)).OverrideViewTransform)(this, coordinatesystem, coordinatesystemtoviewtransform), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn OverrideProjectionTransform(this: *mut core::ffi::c_void, projectiontransform: HolographicStereoTransform) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraPose2", "OverrideProjectionTransform", 7usize, |this| ((**(this as *const *const IHolographicCameraPose2_Vtbl)).OverrideProjectionTransform)(this, projectiontransform), |result| Some(*result))
+        }
+        unsafe extern "system" fn OverrideViewport(this: *mut core::ffi::c_void, leftviewport: super::super::Foundation::Rect, rightviewport: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraPose2", "OverrideViewport", 8usize, |this| ((**(this as *const *const IHolographicCameraPose2_Vtbl)).OverrideViewport)(this, leftviewport, rightviewport), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            OverrideViewTransform,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            OverrideViewTransform: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            OverrideProjectionTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            OverrideProjectionTransform: 0,
+            OverrideViewport,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicCameraPose2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Graphics_DirectX_Direct3D11", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for IHolographicCameraRenderingParameters_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn SetFocusPoint(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, position: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraRenderingParameters", "SetFocusPoint", 6usize, |this| ((**(this as *const *const IHolographicCameraRenderingParameters_Vtbl)).SetFocusPoint)(this, coordinatesystem, position), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn SetFocusPointWithNormal(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, position: super::super::Foundation::Numerics::Vector3, normal: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraRenderingParameters", "SetFocusPointWithNormal", 7usize, |this| ((**(this as *const *const IHolographicCameraRenderingParameters_Vtbl)).SetFocusPointWithNormal)(this, coordinatesystem, position, normal), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn SetFocusPointWithNormalLinearVelocity(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, position: super::super::Foundation::Numerics::Vector3, normal: super::super::Foundation::Numerics::Vector3, linearvelocity: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraRenderingParameters", "SetFocusPointWithNormalLinearVelocity", 8usize, |this| ((**(this as *const *const IHolographicCameraRenderingParameters_Vtbl)).SetFocusPointWithNormalLinearVelocity)(this, coordinatesystem, position, normal, linearvelocity), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn Direct3D11Device(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraRenderingParameters", "Direct3D11Device", 9usize, |this| ((**(this as *const *const IHolographicCameraRenderingParameters_Vtbl)).Direct3D11Device)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn Direct3D11BackBuffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraRenderingParameters", "Direct3D11BackBuffer", 10usize, |this| ((**(this as *const *const IHolographicCameraRenderingParameters_Vtbl)).Direct3D11BackBuffer)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            SetFocusPoint,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            SetFocusPoint: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            SetFocusPointWithNormal,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            SetFocusPointWithNormal: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            SetFocusPointWithNormalLinearVelocity,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            SetFocusPointWithNormalLinearVelocity: 0,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            Direct3D11Device,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            Direct3D11Device: 0,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            Direct3D11BackBuffer,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            Direct3D11BackBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicCameraRenderingParameters as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Graphics_DirectX_Direct3D11", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for IHolographicCameraRenderingParameters2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReprojectionMode(this: *mut core::ffi::c_void, result__: *mut HolographicReprojectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraRenderingParameters2", "ReprojectionMode", 6usize, |this| ((**(this as *const *const IHolographicCameraRenderingParameters2_Vtbl)).ReprojectionMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReprojectionMode(this: *mut core::ffi::c_void, value: HolographicReprojectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraRenderingParameters2", "SetReprojectionMode", 7usize, |this| ((**(this as *const *const IHolographicCameraRenderingParameters2_Vtbl)).SetReprojectionMode)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn CommitDirect3D11DepthBuffer(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraRenderingParameters2", "CommitDirect3D11DepthBuffer", 8usize, |this| ((**(this as *const *const IHolographicCameraRenderingParameters2_Vtbl)).CommitDirect3D11DepthBuffer)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReprojectionMode,
+            SetReprojectionMode,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            CommitDirect3D11DepthBuffer,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            CommitDirect3D11DepthBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicCameraRenderingParameters2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Graphics_DirectX_Direct3D11", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for IHolographicCameraRenderingParameters3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsContentProtectionEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraRenderingParameters3", "IsContentProtectionEnabled", 6usize, |this| ((**(this as *const *const IHolographicCameraRenderingParameters3_Vtbl)).IsContentProtectionEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsContentProtectionEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraRenderingParameters3", "SetIsContentProtectionEnabled", 7usize, |this| ((**(this as *const *const IHolographicCameraRenderingParameters3_Vtbl)).SetIsContentProtectionEnabled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsContentProtectionEnabled, SetIsContentProtectionEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicCameraRenderingParameters3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicCameraRenderingParameters4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DepthReprojectionMethod(this: *mut core::ffi::c_void, result__: *mut HolographicDepthReprojectionMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraRenderingParameters4", "DepthReprojectionMethod", 6usize, |this| ((**(this as *const *const IHolographicCameraRenderingParameters4_Vtbl)).DepthReprojectionMethod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDepthReprojectionMethod(this: *mut core::ffi::c_void, value: HolographicDepthReprojectionMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraRenderingParameters4", "SetDepthReprojectionMethod", 7usize, |this| ((**(this as *const *const IHolographicCameraRenderingParameters4_Vtbl)).SetDepthReprojectionMethod)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DepthReprojectionMethod, SetDepthReprojectionMethod }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicCameraRenderingParameters4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IHolographicCameraViewportParameters_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn HiddenAreaMesh(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraViewportParameters", "HiddenAreaMesh", 6usize, |this| ((**(this as *const *const IHolographicCameraViewportParameters_Vtbl)).HiddenAreaMesh)(this, result_size__, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn VisibleAreaMesh(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraViewportParameters", "VisibleAreaMesh", 7usize, |this| ((**(this as *const *const IHolographicCameraViewportParameters_Vtbl)).VisibleAreaMesh)(this, result_size__, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            HiddenAreaMesh,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            HiddenAreaMesh: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            VisibleAreaMesh,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            VisibleAreaMesh: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicCameraViewportParameters as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_Spatial")]
+impl windows_core::imp::ProxyVtable for IHolographicDisplay_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicDisplay", "DisplayName", 6usize, |this| ((**(this as *const *const IHolographicDisplay_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxViewportSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicDisplay", "MaxViewportSize", 7usize, |this| ((**(this as *const *const IHolographicDisplay_Vtbl)).MaxViewportSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStereo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicDisplay", "IsStereo", 8usize, |this| ((**(this as *const *const IHolographicDisplay_Vtbl)).IsStereo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOpaque(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicDisplay", "IsOpaque", 9usize, |this| ((**(this as *const *const IHolographicDisplay_Vtbl)).IsOpaque)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdapterId(this: *mut core::ffi::c_void, result__: *mut HolographicAdapterId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicDisplay", "AdapterId", 10usize, |this| ((**(this as *const *const IHolographicDisplay_Vtbl)).AdapterId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn SpatialLocator(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicDisplay", "SpatialLocator", 11usize, |this| ((**(this as *const *const IHolographicDisplay_Vtbl)).SpatialLocator)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayName,
+            MaxViewportSize,
+            IsStereo,
+            IsOpaque,
+            AdapterId,
+            #[cfg(feature = "Perception_Spatial")]
+            SpatialLocator,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            SpatialLocator: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicDisplay as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicDisplay2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RefreshRate(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicDisplay2", "RefreshRate", 6usize, |this| ((**(this as *const *const IHolographicDisplay2_Vtbl)).RefreshRate)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RefreshRate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicDisplay2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicDisplay3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryGetViewConfiguration(this: *mut core::ffi::c_void, kind: HolographicViewConfigurationKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicDisplay3", "TryGetViewConfiguration", 6usize, |this| ((**(this as *const *const IHolographicDisplay3_Vtbl)).TryGetViewConfiguration)(this, kind, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryGetViewConfiguration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicDisplay3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicDisplayStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicDisplayStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IHolographicDisplayStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicDisplayStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHolographicFrame_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AddedCameras(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrame", "AddedCameras", 6usize, |this| ((**(this as *const *const IHolographicFrame_Vtbl)).AddedCameras)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RemovedCameras(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrame", "RemovedCameras", 7usize, |this| ((**(this as *const *const IHolographicFrame_Vtbl)).RemovedCameras)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRenderingParameters(this: *mut core::ffi::c_void, camerapose: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrame", "GetRenderingParameters", 8usize, |this| ((**(this as *const *const IHolographicFrame_Vtbl)).GetRenderingParameters)(this, camerapose, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrame", "Duration", 9usize, |this| ((**(this as *const *const IHolographicFrame_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPrediction(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrame", "CurrentPrediction", 10usize, |this| ((**(this as *const *const IHolographicFrame_Vtbl)).CurrentPrediction)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateCurrentPrediction(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrame", "UpdateCurrentPrediction", 11usize, |this| ((**(this as *const *const IHolographicFrame_Vtbl)).UpdateCurrentPrediction)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PresentUsingCurrentPrediction(this: *mut core::ffi::c_void, result__: *mut HolographicFramePresentResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrame", "PresentUsingCurrentPrediction", 12usize, |this| ((**(this as *const *const IHolographicFrame_Vtbl)).PresentUsingCurrentPrediction)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PresentUsingCurrentPredictionWithBehavior(this: *mut core::ffi::c_void, waitbehavior: HolographicFramePresentWaitBehavior, result__: *mut HolographicFramePresentResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrame", "PresentUsingCurrentPredictionWithBehavior", 13usize, |this| ((**(this as *const *const IHolographicFrame_Vtbl)).PresentUsingCurrentPredictionWithBehavior)(this, waitbehavior, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForFrameToFinish(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrame", "WaitForFrameToFinish", 14usize, |this| ((**(this as *const *const IHolographicFrame_Vtbl)).WaitForFrameToFinish)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AddedCameras,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AddedCameras: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RemovedCameras,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RemovedCameras: 0,
+            GetRenderingParameters,
+            Duration,
+            CurrentPrediction,
+            UpdateCurrentPrediction,
+            PresentUsingCurrentPrediction,
+            PresentUsingCurrentPredictionWithBehavior,
+            WaitForFrameToFinish,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHolographicFrame2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetQuadLayerUpdateParameters(this: *mut core::ffi::c_void, layer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrame2", "GetQuadLayerUpdateParameters", 6usize, |this| ((**(this as *const *const IHolographicFrame2_Vtbl)).GetQuadLayerUpdateParameters)(this, layer, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetQuadLayerUpdateParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicFrame2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicFrame3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut HolographicFrameId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrame3", "Id", 6usize, |this| ((**(this as *const *const IHolographicFrame3_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicFrame3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Perception"))]
+impl windows_core::imp::ProxyVtable for IHolographicFramePrediction_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CameraPoses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFramePrediction", "CameraPoses", 6usize, |this| ((**(this as *const *const IHolographicFramePrediction_Vtbl)).CameraPoses)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception")]
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFramePrediction", "Timestamp", 7usize, |this| ((**(this as *const *const IHolographicFramePrediction_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CameraPoses,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CameraPoses: 0,
+            #[cfg(feature = "Perception")]
+            Timestamp,
+            #[cfg(not(feature = "Perception"))]
+            Timestamp: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicFramePrediction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IHolographicFramePresentationMonitor_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn ReadReports(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFramePresentationMonitor", "ReadReports", 6usize, |this| ((**(this as *const *const IHolographicFramePresentationMonitor_Vtbl)).ReadReports)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            ReadReports,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            ReadReports: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicFramePresentationMonitor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IHolographicFramePresentationReport_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CompositorGpuDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFramePresentationReport", "CompositorGpuDuration", 6usize, |this| ((**(this as *const *const IHolographicFramePresentationReport_Vtbl)).CompositorGpuDuration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AppGpuDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFramePresentationReport", "AppGpuDuration", 7usize, |this| ((**(this as *const *const IHolographicFramePresentationReport_Vtbl)).AppGpuDuration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AppGpuOverrun(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFramePresentationReport", "AppGpuOverrun", 8usize, |this| ((**(this as *const *const IHolographicFramePresentationReport_Vtbl)).AppGpuOverrun)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn MissedPresentationOpportunityCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFramePresentationReport", "MissedPresentationOpportunityCount", 9usize, |this| ((**(this as *const *const IHolographicFramePresentationReport_Vtbl)).MissedPresentationOpportunityCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PresentationCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFramePresentationReport", "PresentationCount", 10usize, |this| ((**(this as *const *const IHolographicFramePresentationReport_Vtbl)).PresentationCount)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CompositorGpuDuration,
+            #[cfg(not(feature = "deprecated"))]
+            CompositorGpuDuration: 0,
+            #[cfg(feature = "deprecated")]
+            AppGpuDuration,
+            #[cfg(not(feature = "deprecated"))]
+            AppGpuDuration: 0,
+            #[cfg(feature = "deprecated")]
+            AppGpuOverrun,
+            #[cfg(not(feature = "deprecated"))]
+            AppGpuOverrun: 0,
+            #[cfg(feature = "deprecated")]
+            MissedPresentationOpportunityCount,
+            #[cfg(not(feature = "deprecated"))]
+            MissedPresentationOpportunityCount: 0,
+            #[cfg(feature = "deprecated")]
+            PresentationCount,
+            #[cfg(not(feature = "deprecated"))]
+            PresentationCount: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicFramePresentationReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicFrameRenderingReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FrameId(this: *mut core::ffi::c_void, result__: *mut HolographicFrameId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrameRenderingReport", "FrameId", 6usize, |this| ((**(this as *const *const IHolographicFrameRenderingReport_Vtbl)).FrameId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MissedLatchCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrameRenderingReport", "MissedLatchCount", 7usize, |this| ((**(this as *const *const IHolographicFrameRenderingReport_Vtbl)).MissedLatchCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemRelativeFrameReadyTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrameRenderingReport", "SystemRelativeFrameReadyTime", 8usize, |this| ((**(this as *const *const IHolographicFrameRenderingReport_Vtbl)).SystemRelativeFrameReadyTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemRelativeActualGpuFinishTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrameRenderingReport", "SystemRelativeActualGpuFinishTime", 9usize, |this| ((**(this as *const *const IHolographicFrameRenderingReport_Vtbl)).SystemRelativeActualGpuFinishTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemRelativeTargetLatchTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrameRenderingReport", "SystemRelativeTargetLatchTime", 10usize, |this| ((**(this as *const *const IHolographicFrameRenderingReport_Vtbl)).SystemRelativeTargetLatchTime)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FrameId,
+            MissedLatchCount,
+            SystemRelativeFrameReadyTime,
+            SystemRelativeActualGpuFinishTime,
+            SystemRelativeTargetLatchTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicFrameRenderingReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHolographicFrameScanoutMonitor_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadReports(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrameScanoutMonitor", "ReadReports", 6usize, |this| ((**(this as *const *const IHolographicFrameScanoutMonitor_Vtbl)).ReadReports)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadReports,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadReports: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicFrameScanoutMonitor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicFrameScanoutReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RenderingReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrameScanoutReport", "RenderingReport", 6usize, |this| ((**(this as *const *const IHolographicFrameScanoutReport_Vtbl)).RenderingReport)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MissedScanoutCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrameScanoutReport", "MissedScanoutCount", 7usize, |this| ((**(this as *const *const IHolographicFrameScanoutReport_Vtbl)).MissedScanoutCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemRelativeLatchTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrameScanoutReport", "SystemRelativeLatchTime", 8usize, |this| ((**(this as *const *const IHolographicFrameScanoutReport_Vtbl)).SystemRelativeLatchTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemRelativeScanoutStartTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrameScanoutReport", "SystemRelativeScanoutStartTime", 9usize, |this| ((**(this as *const *const IHolographicFrameScanoutReport_Vtbl)).SystemRelativeScanoutStartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemRelativePhotonTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicFrameScanoutReport", "SystemRelativePhotonTime", 10usize, |this| ((**(this as *const *const IHolographicFrameScanoutReport_Vtbl)).SystemRelativePhotonTime)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RenderingReport,
+            MissedScanoutCount,
+            SystemRelativeLatchTime,
+            SystemRelativeScanoutStartTime,
+            SystemRelativePhotonTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicFrameScanoutReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX")]
+impl windows_core::imp::ProxyVtable for IHolographicQuadLayer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn PixelFormat(this: *mut core::ffi::c_void, result__: *mut super::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayer", "PixelFormat", 6usize, |this| ((**(this as *const *const IHolographicQuadLayer_Vtbl)).PixelFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayer", "Size", 7usize, |this| ((**(this as *const *const IHolographicQuadLayer_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX")]
+            PixelFormat,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            PixelFormat: 0,
+            Size,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicQuadLayer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX")]
+impl windows_core::imp::ProxyVtable for IHolographicQuadLayerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, size: super::super::Foundation::Size, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerFactory", "Create", 6usize, |this| ((**(this as *const *const IHolographicQuadLayerFactory_Vtbl)).Create)(this, size, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn CreateWithPixelFormat(this: *mut core::ffi::c_void, size: super::super::Foundation::Size, pixelformat: super::DirectX::DirectXPixelFormat, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerFactory", "CreateWithPixelFormat", 7usize, |this| ((**(this as *const *const IHolographicQuadLayerFactory_Vtbl)).CreateWithPixelFormat)(this, size, pixelformat, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Graphics_DirectX")]
+            CreateWithPixelFormat,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            CreateWithPixelFormat: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicQuadLayerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Graphics_DirectX_Direct3D11", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for IHolographicQuadLayerUpdateParameters_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn AcquireBufferToUpdateContent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerUpdateParameters", "AcquireBufferToUpdateContent", 6usize, |this| ((**(this as *const *const IHolographicQuadLayerUpdateParameters_Vtbl)).AcquireBufferToUpdateContent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateViewport(this: *mut core::ffi::c_void, value: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerUpdateParameters", "UpdateViewport", 7usize, |this| ((**(this as *const *const IHolographicQuadLayerUpdateParameters_Vtbl)).UpdateViewport)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateContentProtectionEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerUpdateParameters", "UpdateContentProtectionEnabled", 8usize, |this| ((**(this as *const *const IHolographicQuadLayerUpdateParameters_Vtbl)).UpdateContentProtectionEnabled)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn UpdateExtents(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerUpdateParameters", "UpdateExtents", 9usize, |this| ((**(this as *const *const IHolographicQuadLayerUpdateParameters_Vtbl)).UpdateExtents)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn UpdateLocationWithStationaryMode(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, position: super::super::Foundation::Numerics::Vector3, orientation: super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerUpdateParameters", "UpdateLocationWithStationaryMode", 10usize, |this| ((**(this as *const *const IHolographicQuadLayerUpdateParameters_Vtbl)).UpdateLocationWithStationaryMode)(this, coordinatesystem, position, orientation), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn UpdateLocationWithDisplayRelativeMode(this: *mut core::ffi::c_void, position: super::super::Foundation::Numerics::Vector3, orientation: super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerUpdateParameters", "UpdateLocationWithDisplayRelativeMode", 11usize, |this| ((**(this as *const *const IHolographicQuadLayerUpdateParameters_Vtbl)).UpdateLocationWithDisplayRelativeMode)(this, position, orientation), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            AcquireBufferToUpdateContent,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            AcquireBufferToUpdateContent: 0,
+            UpdateViewport,
+            UpdateContentProtectionEnabled,
+            #[cfg(feature = "Foundation_Numerics")]
+            UpdateExtents,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            UpdateExtents: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            UpdateLocationWithStationaryMode,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            UpdateLocationWithStationaryMode: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            UpdateLocationWithDisplayRelativeMode,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            UpdateLocationWithDisplayRelativeMode: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicQuadLayerUpdateParameters as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX_Direct3D11")]
+impl windows_core::imp::ProxyVtable for IHolographicQuadLayerUpdateParameters2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanAcquireWithHardwareProtection(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerUpdateParameters2", "CanAcquireWithHardwareProtection", 6usize, |this| ((**(this as *const *const IHolographicQuadLayerUpdateParameters2_Vtbl)).CanAcquireWithHardwareProtection)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn AcquireBufferToUpdateContentWithHardwareProtection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerUpdateParameters2", "AcquireBufferToUpdateContentWithHardwareProtection", 7usize, |this| ((**(this as *const *const IHolographicQuadLayerUpdateParameters2_Vtbl)).AcquireBufferToUpdateContentWithHardwareProtection)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanAcquireWithHardwareProtection,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            AcquireBufferToUpdateContentWithHardwareProtection,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            AcquireBufferToUpdateContentWithHardwareProtection: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicQuadLayerUpdateParameters2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX_Direct3D11")]
+impl windows_core::imp::ProxyVtable for IHolographicSpace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrimaryAdapterId(this: *mut core::ffi::c_void, result__: *mut HolographicAdapterId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpace", "PrimaryAdapterId", 6usize, |this| ((**(this as *const *const IHolographicSpace_Vtbl)).PrimaryAdapterId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn SetDirect3D11Device(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpace", "SetDirect3D11Device", 7usize, |this| ((**(this as *const *const IHolographicSpace_Vtbl)).SetDirect3D11Device)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CameraAdded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpace", "CameraAdded", 8usize, |this| ((**(this as *const *const IHolographicSpace_Vtbl)).CameraAdded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCameraAdded(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpace", "RemoveCameraAdded", 9usize, |this| ((**(this as *const *const IHolographicSpace_Vtbl)).RemoveCameraAdded)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn CameraRemoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpace", "CameraRemoved", 10usize, |this| ((**(this as *const *const IHolographicSpace_Vtbl)).CameraRemoved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCameraRemoved(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpace", "RemoveCameraRemoved", 11usize, |this| ((**(this as *const *const IHolographicSpace_Vtbl)).RemoveCameraRemoved)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNextFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpace", "CreateNextFrame", 12usize, |this| ((**(this as *const *const IHolographicSpace_Vtbl)).CreateNextFrame)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PrimaryAdapterId,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            SetDirect3D11Device,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            SetDirect3D11Device: 0,
+            CameraAdded,
+            RemoveCameraAdded,
+            CameraRemoved,
+            RemoveCameraRemoved,
+            CreateNextFrame,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicSpace as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicSpace2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserPresence(this: *mut core::ffi::c_void, result__: *mut HolographicSpaceUserPresence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpace2", "UserPresence", 6usize, |this| ((**(this as *const *const IHolographicSpace2_Vtbl)).UserPresence)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserPresenceChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpace2", "UserPresenceChanged", 7usize, |this| ((**(this as *const *const IHolographicSpace2_Vtbl)).UserPresenceChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUserPresenceChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpace2", "RemoveUserPresenceChanged", 8usize, |this| ((**(this as *const *const IHolographicSpace2_Vtbl)).RemoveUserPresenceChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForNextFrameReady(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpace2", "WaitForNextFrameReady", 9usize, |this| ((**(this as *const *const IHolographicSpace2_Vtbl)).WaitForNextFrameReady)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForNextFrameReadyWithHeadStart(this: *mut core::ffi::c_void, requestedheadstartduration: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpace2", "WaitForNextFrameReadyWithHeadStart", 10usize, |this| ((**(this as *const *const IHolographicSpace2_Vtbl)).WaitForNextFrameReadyWithHeadStart)(this, requestedheadstartduration), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreateFramePresentationMonitor(this: *mut core::ffi::c_void, maxqueuedreports: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpace2", "CreateFramePresentationMonitor", 11usize, |this| ((**(this as *const *const IHolographicSpace2_Vtbl)).CreateFramePresentationMonitor)(this, maxqueuedreports, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UserPresence,
+            UserPresenceChanged,
+            RemoveUserPresenceChanged,
+            WaitForNextFrameReady,
+            WaitForNextFrameReadyWithHeadStart,
+            #[cfg(feature = "deprecated")]
+            CreateFramePresentationMonitor,
+            #[cfg(not(feature = "deprecated"))]
+            CreateFramePresentationMonitor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicSpace2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicSpace3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFrameScanoutMonitor(this: *mut core::ffi::c_void, maxqueuedreports: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpace3", "CreateFrameScanoutMonitor", 6usize, |this| ((**(this as *const *const IHolographicSpace3_Vtbl)).CreateFrameScanoutMonitor)(this, maxqueuedreports, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFrameScanoutMonitor }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicSpace3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicSpaceCameraAddedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Camera(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpaceCameraAddedEventArgs", "Camera", 6usize, |this| ((**(this as *const *const IHolographicSpaceCameraAddedEventArgs_Vtbl)).Camera)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpaceCameraAddedEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IHolographicSpaceCameraAddedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Camera, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicSpaceCameraAddedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicSpaceCameraRemovedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Camera(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpaceCameraRemovedEventArgs", "Camera", 6usize, |this| ((**(this as *const *const IHolographicSpaceCameraRemovedEventArgs_Vtbl)).Camera)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Camera }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicSpaceCameraRemovedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Core")]
+impl windows_core::imp::ProxyVtable for IHolographicSpaceStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn CreateForCoreWindow(this: *mut core::ffi::c_void, window: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpaceStatics", "CreateForCoreWindow", 6usize, |this| ((**(this as *const *const IHolographicSpaceStatics_Vtbl)).CreateForCoreWindow)(this, window, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Core")]
+            CreateForCoreWindow,
+            #[cfg(not(feature = "UI_Core"))]
+            CreateForCoreWindow: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicSpaceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicSpaceStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpaceStatics2", "IsSupported", 6usize, |this| ((**(this as *const *const IHolographicSpaceStatics2_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAvailable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpaceStatics2", "IsAvailable", 7usize, |this| ((**(this as *const *const IHolographicSpaceStatics2_Vtbl)).IsAvailable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAvailableChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpaceStatics2", "IsAvailableChanged", 8usize, |this| ((**(this as *const *const IHolographicSpaceStatics2_Vtbl)).IsAvailableChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveIsAvailableChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpaceStatics2", "RemoveIsAvailableChanged", 9usize, |this| ((**(this as *const *const IHolographicSpaceStatics2_Vtbl)).RemoveIsAvailableChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupported,
+            IsAvailable,
+            IsAvailableChanged,
+            RemoveIsAvailableChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicSpaceStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicSpaceStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsConfigured(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpaceStatics3", "IsConfigured", 6usize, |this| ((**(this as *const *const IHolographicSpaceStatics3_Vtbl)).IsConfigured)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsConfigured }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicSpaceStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX"))]
+impl windows_core::imp::ProxyVtable for IHolographicViewConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NativeRenderTargetSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicViewConfiguration", "NativeRenderTargetSize", 6usize, |this| ((**(this as *const *const IHolographicViewConfiguration_Vtbl)).NativeRenderTargetSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenderTargetSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicViewConfiguration", "RenderTargetSize", 7usize, |this| ((**(this as *const *const IHolographicViewConfiguration_Vtbl)).RenderTargetSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestRenderTargetSize(this: *mut core::ffi::c_void, size: super::super::Foundation::Size, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicViewConfiguration", "RequestRenderTargetSize", 8usize, |this| ((**(this as *const *const IHolographicViewConfiguration_Vtbl)).RequestRenderTargetSize)(this, size, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX"))]
+        unsafe extern "system" fn SupportedPixelFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicViewConfiguration", "SupportedPixelFormats", 9usize, |this| ((**(this as *const *const IHolographicViewConfiguration_Vtbl)).SupportedPixelFormats)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn PixelFormat(this: *mut core::ffi::c_void, result__: *mut super::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicViewConfiguration", "PixelFormat", 10usize, |this| ((**(this as *const *const IHolographicViewConfiguration_Vtbl)).PixelFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn SetPixelFormat(this: *mut core::ffi::c_void, value: super::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicViewConfiguration", "SetPixelFormat", 11usize, |this| ((**(this as *const *const IHolographicViewConfiguration_Vtbl)).SetPixelFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStereo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicViewConfiguration", "IsStereo", 12usize, |this| ((**(this as *const *const IHolographicViewConfiguration_Vtbl)).IsStereo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefreshRate(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicViewConfiguration", "RefreshRate", 13usize, |this| ((**(this as *const *const IHolographicViewConfiguration_Vtbl)).RefreshRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut HolographicViewConfigurationKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicViewConfiguration", "Kind", 14usize, |this| ((**(this as *const *const IHolographicViewConfiguration_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Display(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicViewConfiguration", "Display", 15usize, |this| ((**(this as *const *const IHolographicViewConfiguration_Vtbl)).Display)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicViewConfiguration", "IsEnabled", 16usize, |this| ((**(this as *const *const IHolographicViewConfiguration_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicViewConfiguration", "SetIsEnabled", 17usize, |this| ((**(this as *const *const IHolographicViewConfiguration_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NativeRenderTargetSize,
+            RenderTargetSize,
+            RequestRenderTargetSize,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX"))]
+            SupportedPixelFormats,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics_DirectX")))]
+            SupportedPixelFormats: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            PixelFormat,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            PixelFormat: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            SetPixelFormat,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            SetPixelFormat: 0,
+            IsStereo,
+            RefreshRate,
+            Kind,
+            Display,
+            IsEnabled,
+            SetIsEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicViewConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHolographicViewConfiguration2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedDepthReprojectionMethods(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicViewConfiguration2", "SupportedDepthReprojectionMethods", 6usize, |this| ((**(this as *const *const IHolographicViewConfiguration2_Vtbl)).SupportedDepthReprojectionMethods)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedDepthReprojectionMethods,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedDepthReprojectionMethods: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicViewConfiguration2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1957,6 +1957,7 @@ impl BitmapAlphaMode {
 impl windows_core::TypeKind for BitmapAlphaMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BitmapAlphaMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BitmapAlphaMode").field(&self.0).finish()
@@ -1976,6 +1977,7 @@ impl BitmapBufferAccessMode {
 impl windows_core::TypeKind for BitmapBufferAccessMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BitmapBufferAccessMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BitmapBufferAccessMode").field(&self.0).finish()
@@ -1995,6 +1997,7 @@ impl BitmapFlip {
 impl windows_core::TypeKind for BitmapFlip {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BitmapFlip {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BitmapFlip").field(&self.0).finish()
@@ -2015,6 +2018,7 @@ impl BitmapInterpolationMode {
 impl windows_core::TypeKind for BitmapInterpolationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BitmapInterpolationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BitmapInterpolationMode").field(&self.0).finish()
@@ -2040,6 +2044,7 @@ impl BitmapPixelFormat {
 impl windows_core::TypeKind for BitmapPixelFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BitmapPixelFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BitmapPixelFormat").field(&self.0).finish()
@@ -2060,6 +2065,7 @@ impl BitmapRotation {
 impl windows_core::TypeKind for BitmapRotation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BitmapRotation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BitmapRotation").field(&self.0).finish()
@@ -2078,6 +2084,7 @@ impl ColorManagementMode {
 impl windows_core::TypeKind for ColorManagementMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ColorManagementMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ColorManagementMode").field(&self.0).finish()
@@ -2096,6 +2103,7 @@ impl ExifOrientationMode {
 impl windows_core::TypeKind for ExifOrientationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ExifOrientationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ExifOrientationMode").field(&self.0).finish()
@@ -2116,6 +2124,7 @@ impl JpegSubsamplingMode {
 impl windows_core::TypeKind for JpegSubsamplingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for JpegSubsamplingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("JpegSubsamplingMode").field(&self.0).finish()
@@ -2139,6 +2148,7 @@ impl PngFilterMode {
 impl windows_core::TypeKind for PngFilterMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PngFilterMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PngFilterMode").field(&self.0).finish()
@@ -2163,6 +2173,7 @@ impl TiffCompressionMode {
 impl windows_core::TypeKind for TiffCompressionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TiffCompressionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TiffCompressionMode").field(&self.0).finish()
@@ -2172,7 +2183,8 @@ impl windows_core::RuntimeType for TiffCompressionMode {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Graphics.Imaging.TiffCompressionMode;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BitmapBounds {
     pub X: u32,
     pub Y: u32,
@@ -2191,7 +2203,8 @@ impl Default for BitmapBounds {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BitmapPlaneDescription {
     pub StartIndex: i32,
     pub Width: i32,
@@ -2210,7 +2223,8 @@ impl Default for BitmapPlaneDescription {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BitmapSize {
     pub Width: u32,
     pub Height: u32,
@@ -2228,3 +2242,5 @@ impl Default for BitmapSize {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,707 @@
+impl windows_core::imp::ProxyVtable for IBitmapBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPlaneCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapBuffer", "GetPlaneCount", 6usize, |this| ((**(this as *const *const IBitmapBuffer_Vtbl)).GetPlaneCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPlaneDescription(this: *mut core::ffi::c_void, index: i32, result__: *mut BitmapPlaneDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapBuffer", "GetPlaneDescription", 7usize, |this| ((**(this as *const *const IBitmapBuffer_Vtbl)).GetPlaneDescription)(this, index, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPlaneCount, GetPlaneDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapBuffer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBitmapCodecInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CodecId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapCodecInformation", "CodecId", 6usize, |this| ((**(this as *const *const IBitmapCodecInformation_Vtbl)).CodecId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FileExtensions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapCodecInformation", "FileExtensions", 7usize, |this| ((**(this as *const *const IBitmapCodecInformation_Vtbl)).FileExtensions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapCodecInformation", "FriendlyName", 8usize, |this| ((**(this as *const *const IBitmapCodecInformation_Vtbl)).FriendlyName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MimeTypes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapCodecInformation", "MimeTypes", 9usize, |this| ((**(this as *const *const IBitmapCodecInformation_Vtbl)).MimeTypes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CodecId,
+            #[cfg(feature = "Foundation_Collections")]
+            FileExtensions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FileExtensions: 0,
+            FriendlyName,
+            #[cfg(feature = "Foundation_Collections")]
+            MimeTypes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MimeTypes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapCodecInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IBitmapDecoder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BitmapContainerProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoder", "BitmapContainerProperties", 6usize, |this| ((**(this as *const *const IBitmapDecoder_Vtbl)).BitmapContainerProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecoderInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoder", "DecoderInformation", 7usize, |this| ((**(this as *const *const IBitmapDecoder_Vtbl)).DecoderInformation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoder", "FrameCount", 8usize, |this| ((**(this as *const *const IBitmapDecoder_Vtbl)).FrameCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetPreviewAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoder", "GetPreviewAsync", 9usize, |this| ((**(this as *const *const IBitmapDecoder_Vtbl)).GetPreviewAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameAsync(this: *mut core::ffi::c_void, frameindex: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoder", "GetFrameAsync", 10usize, |this| ((**(this as *const *const IBitmapDecoder_Vtbl)).GetFrameAsync)(this, frameindex, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BitmapContainerProperties,
+            DecoderInformation,
+            FrameCount,
+            #[cfg(feature = "Storage_Streams")]
+            GetPreviewAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetPreviewAsync: 0,
+            GetFrameAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapDecoder as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IBitmapDecoderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BmpDecoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoderStatics", "BmpDecoderId", 6usize, |this| ((**(this as *const *const IBitmapDecoderStatics_Vtbl)).BmpDecoderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JpegDecoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoderStatics", "JpegDecoderId", 7usize, |this| ((**(this as *const *const IBitmapDecoderStatics_Vtbl)).JpegDecoderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PngDecoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoderStatics", "PngDecoderId", 8usize, |this| ((**(this as *const *const IBitmapDecoderStatics_Vtbl)).PngDecoderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TiffDecoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoderStatics", "TiffDecoderId", 9usize, |this| ((**(this as *const *const IBitmapDecoderStatics_Vtbl)).TiffDecoderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GifDecoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoderStatics", "GifDecoderId", 10usize, |this| ((**(this as *const *const IBitmapDecoderStatics_Vtbl)).GifDecoderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JpegXRDecoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoderStatics", "JpegXRDecoderId", 11usize, |this| ((**(this as *const *const IBitmapDecoderStatics_Vtbl)).JpegXRDecoderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IcoDecoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoderStatics", "IcoDecoderId", 12usize, |this| ((**(this as *const *const IBitmapDecoderStatics_Vtbl)).IcoDecoderId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetDecoderInformationEnumerator(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoderStatics", "GetDecoderInformationEnumerator", 13usize, |this| ((**(this as *const *const IBitmapDecoderStatics_Vtbl)).GetDecoderInformationEnumerator)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateAsync(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoderStatics", "CreateAsync", 14usize, |this| ((**(this as *const *const IBitmapDecoderStatics_Vtbl)).CreateAsync)(this, stream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateWithIdAsync(this: *mut core::ffi::c_void, decoderid: windows_core::GUID, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoderStatics", "CreateWithIdAsync", 15usize, |this| ((**(this as *const *const IBitmapDecoderStatics_Vtbl)).CreateWithIdAsync)(this, decoderid, stream, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BmpDecoderId,
+            JpegDecoderId,
+            PngDecoderId,
+            TiffDecoderId,
+            GifDecoderId,
+            JpegXRDecoderId,
+            IcoDecoderId,
+            #[cfg(feature = "Foundation_Collections")]
+            GetDecoderInformationEnumerator,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetDecoderInformationEnumerator: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateWithIdAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateWithIdAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapDecoderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBitmapDecoderStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HeifDecoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoderStatics2", "HeifDecoderId", 6usize, |this| ((**(this as *const *const IBitmapDecoderStatics2_Vtbl)).HeifDecoderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WebpDecoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapDecoderStatics2", "WebpDecoderId", 7usize, |this| ((**(this as *const *const IBitmapDecoderStatics2_Vtbl)).WebpDecoderId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HeifDecoderId, WebpDecoderId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapDecoderStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBitmapEncoder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EncoderInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoder", "EncoderInformation", 6usize, |this| ((**(this as *const *const IBitmapEncoder_Vtbl)).EncoderInformation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitmapProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoder", "BitmapProperties", 7usize, |this| ((**(this as *const *const IBitmapEncoder_Vtbl)).BitmapProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitmapContainerProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoder", "BitmapContainerProperties", 8usize, |this| ((**(this as *const *const IBitmapEncoder_Vtbl)).BitmapContainerProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsThumbnailGenerated(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoder", "IsThumbnailGenerated", 9usize, |this| ((**(this as *const *const IBitmapEncoder_Vtbl)).IsThumbnailGenerated)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsThumbnailGenerated(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoder", "SetIsThumbnailGenerated", 10usize, |this| ((**(this as *const *const IBitmapEncoder_Vtbl)).SetIsThumbnailGenerated)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GeneratedThumbnailWidth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoder", "GeneratedThumbnailWidth", 11usize, |this| ((**(this as *const *const IBitmapEncoder_Vtbl)).GeneratedThumbnailWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGeneratedThumbnailWidth(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoder", "SetGeneratedThumbnailWidth", 12usize, |this| ((**(this as *const *const IBitmapEncoder_Vtbl)).SetGeneratedThumbnailWidth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GeneratedThumbnailHeight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoder", "GeneratedThumbnailHeight", 13usize, |this| ((**(this as *const *const IBitmapEncoder_Vtbl)).GeneratedThumbnailHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGeneratedThumbnailHeight(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoder", "SetGeneratedThumbnailHeight", 14usize, |this| ((**(this as *const *const IBitmapEncoder_Vtbl)).SetGeneratedThumbnailHeight)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitmapTransform(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoder", "BitmapTransform", 15usize, |this| ((**(this as *const *const IBitmapEncoder_Vtbl)).BitmapTransform)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPixelData(this: *mut core::ffi::c_void, pixelformat: BitmapPixelFormat, alphamode: BitmapAlphaMode, width: u32, height: u32, dpix: f64, dpiy: f64, pixels_array_size: u32, pixels: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoder", "SetPixelData", 16usize, |this| ((**(this as *const *const IBitmapEncoder_Vtbl)).SetPixelData)(this, pixelformat, alphamode, width, height, dpix, dpiy, pixels_array_size, pixels), |result| Some(*result))
+        }
+        unsafe extern "system" fn GoToNextFrameAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoder", "GoToNextFrameAsync", 17usize, |this| ((**(this as *const *const IBitmapEncoder_Vtbl)).GoToNextFrameAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GoToNextFrameWithEncodingOptionsAsync(this: *mut core::ffi::c_void, encodingoptions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoder", "GoToNextFrameWithEncodingOptionsAsync", 18usize, |this| ((**(this as *const *const IBitmapEncoder_Vtbl)).GoToNextFrameWithEncodingOptionsAsync)(this, encodingoptions, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoder", "FlushAsync", 19usize, |this| ((**(this as *const *const IBitmapEncoder_Vtbl)).FlushAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EncoderInformation,
+            BitmapProperties,
+            BitmapContainerProperties,
+            IsThumbnailGenerated,
+            SetIsThumbnailGenerated,
+            GeneratedThumbnailWidth,
+            SetGeneratedThumbnailWidth,
+            GeneratedThumbnailHeight,
+            SetGeneratedThumbnailHeight,
+            BitmapTransform,
+            SetPixelData,
+            GoToNextFrameAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GoToNextFrameWithEncodingOptionsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GoToNextFrameWithEncodingOptionsAsync: 0,
+            FlushAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapEncoder as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IBitmapEncoderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BmpEncoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoderStatics", "BmpEncoderId", 6usize, |this| ((**(this as *const *const IBitmapEncoderStatics_Vtbl)).BmpEncoderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JpegEncoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoderStatics", "JpegEncoderId", 7usize, |this| ((**(this as *const *const IBitmapEncoderStatics_Vtbl)).JpegEncoderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PngEncoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoderStatics", "PngEncoderId", 8usize, |this| ((**(this as *const *const IBitmapEncoderStatics_Vtbl)).PngEncoderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TiffEncoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoderStatics", "TiffEncoderId", 9usize, |this| ((**(this as *const *const IBitmapEncoderStatics_Vtbl)).TiffEncoderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GifEncoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoderStatics", "GifEncoderId", 10usize, |this| ((**(this as *const *const IBitmapEncoderStatics_Vtbl)).GifEncoderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JpegXREncoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoderStatics", "JpegXREncoderId", 11usize, |this| ((**(this as *const *const IBitmapEncoderStatics_Vtbl)).JpegXREncoderId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetEncoderInformationEnumerator(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoderStatics", "GetEncoderInformationEnumerator", 12usize, |this| ((**(this as *const *const IBitmapEncoderStatics_Vtbl)).GetEncoderInformationEnumerator)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateAsync(this: *mut core::ffi::c_void, encoderid: windows_core::GUID, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoderStatics", "CreateAsync", 13usize, |this| ((**(this as *const *const IBitmapEncoderStatics_Vtbl)).CreateAsync)(this, encoderid, stream, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn CreateWithEncodingOptionsAsync(this: *mut core::ffi::c_void, encoderid: windows_core::GUID, stream: *mut core::ffi::c_void, encodingoptions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoderStatics", "CreateWithEncodingOptionsAsync", 14usize, |this| ((**(this as *const *const IBitmapEncoderStatics_Vtbl)).CreateWithEncodingOptionsAsync)(this, encoderid, stream, encodingoptions, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateForTranscodingAsync(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, bitmapdecoder: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoderStatics", "CreateForTranscodingAsync", 15usize, |this| ((**(this as *const *const IBitmapEncoderStatics_Vtbl)).CreateForTranscodingAsync)(this, stream, bitmapdecoder, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateForInPlacePropertyEncodingAsync(this: *mut core::ffi::c_void, bitmapdecoder: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoderStatics", "CreateForInPlacePropertyEncodingAsync", 16usize, |this| ((**(this as *const *const IBitmapEncoderStatics_Vtbl)).CreateForInPlacePropertyEncodingAsync)(this, bitmapdecoder, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BmpEncoderId,
+            JpegEncoderId,
+            PngEncoderId,
+            TiffEncoderId,
+            GifEncoderId,
+            JpegXREncoderId,
+            #[cfg(feature = "Foundation_Collections")]
+            GetEncoderInformationEnumerator,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetEncoderInformationEnumerator: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            CreateWithEncodingOptionsAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            CreateWithEncodingOptionsAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateForTranscodingAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateForTranscodingAsync: 0,
+            CreateForInPlacePropertyEncodingAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapEncoderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBitmapEncoderStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HeifEncoderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoderStatics2", "HeifEncoderId", 6usize, |this| ((**(this as *const *const IBitmapEncoderStatics2_Vtbl)).HeifEncoderId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HeifEncoderId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapEncoderStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBitmapEncoderWithSoftwareBitmap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSoftwareBitmap(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapEncoderWithSoftwareBitmap", "SetSoftwareBitmap", 6usize, |this| ((**(this as *const *const IBitmapEncoderWithSoftwareBitmap_Vtbl)).SetSoftwareBitmap)(this, bitmap), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSoftwareBitmap }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapEncoderWithSoftwareBitmap as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IBitmapFrame_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetThumbnailAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapFrame", "GetThumbnailAsync", 6usize, |this| ((**(this as *const *const IBitmapFrame_Vtbl)).GetThumbnailAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitmapProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapFrame", "BitmapProperties", 7usize, |this| ((**(this as *const *const IBitmapFrame_Vtbl)).BitmapProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitmapPixelFormat(this: *mut core::ffi::c_void, result__: *mut BitmapPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapFrame", "BitmapPixelFormat", 8usize, |this| ((**(this as *const *const IBitmapFrame_Vtbl)).BitmapPixelFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitmapAlphaMode(this: *mut core::ffi::c_void, result__: *mut BitmapAlphaMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapFrame", "BitmapAlphaMode", 9usize, |this| ((**(this as *const *const IBitmapFrame_Vtbl)).BitmapAlphaMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DpiX(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapFrame", "DpiX", 10usize, |this| ((**(this as *const *const IBitmapFrame_Vtbl)).DpiX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DpiY(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapFrame", "DpiY", 11usize, |this| ((**(this as *const *const IBitmapFrame_Vtbl)).DpiY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PixelWidth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapFrame", "PixelWidth", 12usize, |this| ((**(this as *const *const IBitmapFrame_Vtbl)).PixelWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PixelHeight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapFrame", "PixelHeight", 13usize, |this| ((**(this as *const *const IBitmapFrame_Vtbl)).PixelHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OrientedPixelWidth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapFrame", "OrientedPixelWidth", 14usize, |this| ((**(this as *const *const IBitmapFrame_Vtbl)).OrientedPixelWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OrientedPixelHeight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapFrame", "OrientedPixelHeight", 15usize, |this| ((**(this as *const *const IBitmapFrame_Vtbl)).OrientedPixelHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelDataAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapFrame", "GetPixelDataAsync", 16usize, |this| ((**(this as *const *const IBitmapFrame_Vtbl)).GetPixelDataAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelDataTransformedAsync(this: *mut core::ffi::c_void, pixelformat: BitmapPixelFormat, alphamode: BitmapAlphaMode, transform: *mut core::ffi::c_void, exiforientationmode: ExifOrientationMode, colormanagementmode: ColorManagementMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapFrame", "GetPixelDataTransformedAsync", 17usize, |this| ((**(this as *const *const IBitmapFrame_Vtbl)).GetPixelDataTransformedAsync)(this, pixelformat, alphamode, transform, exiforientationmode, colormanagementmode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            GetThumbnailAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetThumbnailAsync: 0,
+            BitmapProperties,
+            BitmapPixelFormat,
+            BitmapAlphaMode,
+            DpiX,
+            DpiY,
+            PixelWidth,
+            PixelHeight,
+            OrientedPixelWidth,
+            OrientedPixelHeight,
+            GetPixelDataAsync,
+            GetPixelDataTransformedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IBitmapFrameWithSoftwareBitmap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSoftwareBitmapAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapFrameWithSoftwareBitmap", "GetSoftwareBitmapAsync", 6usize, |this| ((**(this as *const *const IBitmapFrameWithSoftwareBitmap_Vtbl)).GetSoftwareBitmapAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSoftwareBitmapConvertedAsync(this: *mut core::ffi::c_void, pixelformat: BitmapPixelFormat, alphamode: BitmapAlphaMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapFrameWithSoftwareBitmap", "GetSoftwareBitmapConvertedAsync", 7usize, |this| ((**(this as *const *const IBitmapFrameWithSoftwareBitmap_Vtbl)).GetSoftwareBitmapConvertedAsync)(this, pixelformat, alphamode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSoftwareBitmapTransformedAsync(this: *mut core::ffi::c_void, pixelformat: BitmapPixelFormat, alphamode: BitmapAlphaMode, transform: *mut core::ffi::c_void, exiforientationmode: ExifOrientationMode, colormanagementmode: ColorManagementMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapFrameWithSoftwareBitmap", "GetSoftwareBitmapTransformedAsync", 8usize, |this| ((**(this as *const *const IBitmapFrameWithSoftwareBitmap_Vtbl)).GetSoftwareBitmapTransformedAsync)(this, pixelformat, alphamode, transform, exiforientationmode, colormanagementmode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSoftwareBitmapAsync,
+            GetSoftwareBitmapConvertedAsync,
+            GetSoftwareBitmapTransformedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapFrameWithSoftwareBitmap as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBitmapProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetPropertiesAsync(this: *mut core::ffi::c_void, propertiestoset: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapProperties", "SetPropertiesAsync", 6usize, |this| ((**(this as *const *const IBitmapProperties_Vtbl)).SetPropertiesAsync)(this, propertiestoset, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SetPropertiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetPropertiesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBitmapPropertiesView_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetPropertiesAsync(this: *mut core::ffi::c_void, propertiestoretrieve: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapPropertiesView", "GetPropertiesAsync", 6usize, |this| ((**(this as *const *const IBitmapPropertiesView_Vtbl)).GetPropertiesAsync)(this, propertiestoretrieve, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetPropertiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetPropertiesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapPropertiesView as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBitmapTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScaledWidth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapTransform", "ScaledWidth", 6usize, |this| ((**(this as *const *const IBitmapTransform_Vtbl)).ScaledWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaledWidth(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapTransform", "SetScaledWidth", 7usize, |this| ((**(this as *const *const IBitmapTransform_Vtbl)).SetScaledWidth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaledHeight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapTransform", "ScaledHeight", 8usize, |this| ((**(this as *const *const IBitmapTransform_Vtbl)).ScaledHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaledHeight(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapTransform", "SetScaledHeight", 9usize, |this| ((**(this as *const *const IBitmapTransform_Vtbl)).SetScaledHeight)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InterpolationMode(this: *mut core::ffi::c_void, result__: *mut BitmapInterpolationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapTransform", "InterpolationMode", 10usize, |this| ((**(this as *const *const IBitmapTransform_Vtbl)).InterpolationMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterpolationMode(this: *mut core::ffi::c_void, value: BitmapInterpolationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapTransform", "SetInterpolationMode", 11usize, |this| ((**(this as *const *const IBitmapTransform_Vtbl)).SetInterpolationMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flip(this: *mut core::ffi::c_void, result__: *mut BitmapFlip) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapTransform", "Flip", 12usize, |this| ((**(this as *const *const IBitmapTransform_Vtbl)).Flip)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlip(this: *mut core::ffi::c_void, value: BitmapFlip) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapTransform", "SetFlip", 13usize, |this| ((**(this as *const *const IBitmapTransform_Vtbl)).SetFlip)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rotation(this: *mut core::ffi::c_void, result__: *mut BitmapRotation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapTransform", "Rotation", 14usize, |this| ((**(this as *const *const IBitmapTransform_Vtbl)).Rotation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotation(this: *mut core::ffi::c_void, value: BitmapRotation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapTransform", "SetRotation", 15usize, |this| ((**(this as *const *const IBitmapTransform_Vtbl)).SetRotation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bounds(this: *mut core::ffi::c_void, result__: *mut BitmapBounds) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapTransform", "Bounds", 16usize, |this| ((**(this as *const *const IBitmapTransform_Vtbl)).Bounds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBounds(this: *mut core::ffi::c_void, value: BitmapBounds) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapTransform", "SetBounds", 17usize, |this| ((**(this as *const *const IBitmapTransform_Vtbl)).SetBounds)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ScaledWidth,
+            SetScaledWidth,
+            ScaledHeight,
+            SetScaledHeight,
+            InterpolationMode,
+            SetInterpolationMode,
+            Flip,
+            SetFlip,
+            Rotation,
+            SetRotation,
+            Bounds,
+            SetBounds,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapTransform as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBitmapTypedValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapTypedValue", "Value", 6usize, |this| ((**(this as *const *const IBitmapTypedValue_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::PropertyType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapTypedValue", "Type", 7usize, |this| ((**(this as *const *const IBitmapTypedValue_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Value, Type }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapTypedValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBitmapTypedValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, r#type: super::super::Foundation::PropertyType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitmapTypedValueFactory", "Create", 6usize, |this| ((**(this as *const *const IBitmapTypedValueFactory_Vtbl)).Create)(this, value, r#type, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitmapTypedValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPixelDataProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DetachPixelData(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPixelDataProvider", "DetachPixelData", 6usize, |this| ((**(this as *const *const IPixelDataProvider_Vtbl)).DetachPixelData)(this, result_size__, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DetachPixelData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPixelDataProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISoftwareBitmap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BitmapPixelFormat(this: *mut core::ffi::c_void, result__: *mut BitmapPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmap", "BitmapPixelFormat", 6usize, |this| ((**(this as *const *const ISoftwareBitmap_Vtbl)).BitmapPixelFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitmapAlphaMode(this: *mut core::ffi::c_void, result__: *mut BitmapAlphaMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmap", "BitmapAlphaMode", 7usize, |this| ((**(this as *const *const ISoftwareBitmap_Vtbl)).BitmapAlphaMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PixelWidth(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmap", "PixelWidth", 8usize, |this| ((**(this as *const *const ISoftwareBitmap_Vtbl)).PixelWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PixelHeight(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmap", "PixelHeight", 9usize, |this| ((**(this as *const *const ISoftwareBitmap_Vtbl)).PixelHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReadOnly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmap", "IsReadOnly", 10usize, |this| ((**(this as *const *const ISoftwareBitmap_Vtbl)).IsReadOnly)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDpiX(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmap", "SetDpiX", 11usize, |this| ((**(this as *const *const ISoftwareBitmap_Vtbl)).SetDpiX)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DpiX(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmap", "DpiX", 12usize, |this| ((**(this as *const *const ISoftwareBitmap_Vtbl)).DpiX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDpiY(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmap", "SetDpiY", 13usize, |this| ((**(this as *const *const ISoftwareBitmap_Vtbl)).SetDpiY)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DpiY(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmap", "DpiY", 14usize, |this| ((**(this as *const *const ISoftwareBitmap_Vtbl)).DpiY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockBuffer(this: *mut core::ffi::c_void, mode: BitmapBufferAccessMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmap", "LockBuffer", 15usize, |this| ((**(this as *const *const ISoftwareBitmap_Vtbl)).LockBuffer)(this, mode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyTo(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmap", "CopyTo", 16usize, |this| ((**(this as *const *const ISoftwareBitmap_Vtbl)).CopyTo)(this, bitmap), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CopyFromBuffer(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmap", "CopyFromBuffer", 17usize, |this| ((**(this as *const *const ISoftwareBitmap_Vtbl)).CopyFromBuffer)(this, buffer), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CopyToBuffer(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmap", "CopyToBuffer", 18usize, |this| ((**(this as *const *const ISoftwareBitmap_Vtbl)).CopyToBuffer)(this, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReadOnlyView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmap", "GetReadOnlyView", 19usize, |this| ((**(this as *const *const ISoftwareBitmap_Vtbl)).GetReadOnlyView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BitmapPixelFormat,
+            BitmapAlphaMode,
+            PixelWidth,
+            PixelHeight,
+            IsReadOnly,
+            SetDpiX,
+            DpiX,
+            SetDpiY,
+            DpiY,
+            LockBuffer,
+            CopyTo,
+            #[cfg(feature = "Storage_Streams")]
+            CopyFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CopyFromBuffer: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CopyToBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CopyToBuffer: 0,
+            GetReadOnlyView,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISoftwareBitmap as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISoftwareBitmapFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, format: BitmapPixelFormat, width: i32, height: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmapFactory", "Create", 6usize, |this| ((**(this as *const *const ISoftwareBitmapFactory_Vtbl)).Create)(this, format, width, height, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithAlpha(this: *mut core::ffi::c_void, format: BitmapPixelFormat, width: i32, height: i32, alpha: BitmapAlphaMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmapFactory", "CreateWithAlpha", 7usize, |this| ((**(this as *const *const ISoftwareBitmapFactory_Vtbl)).CreateWithAlpha)(this, format, width, height, alpha, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithAlpha }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISoftwareBitmapFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Graphics_DirectX_Direct3D11", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ISoftwareBitmapStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmapStatics", "Copy", 6usize, |this| ((**(this as *const *const ISoftwareBitmapStatics_Vtbl)).Copy)(this, source, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Convert(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, format: BitmapPixelFormat, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmapStatics", "Convert", 7usize, |this| ((**(this as *const *const ISoftwareBitmapStatics_Vtbl)).Convert)(this, source, format, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertWithAlpha(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, format: BitmapPixelFormat, alpha: BitmapAlphaMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmapStatics", "ConvertWithAlpha", 8usize, |this| ((**(this as *const *const ISoftwareBitmapStatics_Vtbl)).ConvertWithAlpha)(this, source, format, alpha, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateCopyFromBuffer(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, format: BitmapPixelFormat, width: i32, height: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmapStatics", "CreateCopyFromBuffer", 9usize, |this| ((**(this as *const *const ISoftwareBitmapStatics_Vtbl)).CreateCopyFromBuffer)(this, source, format, width, height, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateCopyWithAlphaFromBuffer(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, format: BitmapPixelFormat, width: i32, height: i32, alpha: BitmapAlphaMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmapStatics", "CreateCopyWithAlphaFromBuffer", 10usize, |this| ((**(this as *const *const ISoftwareBitmapStatics_Vtbl)).CreateCopyWithAlphaFromBuffer)(this, source, format, width, height, alpha, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn CreateCopyFromSurfaceAsync(this: *mut core::ffi::c_void, surface: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmapStatics", "CreateCopyFromSurfaceAsync", 11usize, |this| ((**(this as *const *const ISoftwareBitmapStatics_Vtbl)).CreateCopyFromSurfaceAsync)(this, surface, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn CreateCopyWithAlphaFromSurfaceAsync(this: *mut core::ffi::c_void, surface: *mut core::ffi::c_void, alpha: BitmapAlphaMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmapStatics", "CreateCopyWithAlphaFromSurfaceAsync", 12usize, |this| ((**(this as *const *const ISoftwareBitmapStatics_Vtbl)).CreateCopyWithAlphaFromSurfaceAsync)(this, surface, alpha, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Copy,
+            Convert,
+            ConvertWithAlpha,
+            #[cfg(feature = "Storage_Streams")]
+            CreateCopyFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateCopyFromBuffer: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateCopyWithAlphaFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateCopyWithAlphaFromBuffer: 0,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            CreateCopyFromSurfaceAsync,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            CreateCopyFromSurfaceAsync: 0,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            CreateCopyWithAlphaFromSurfaceAsync,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            CreateCopyWithAlphaFromSurfaceAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISoftwareBitmapStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
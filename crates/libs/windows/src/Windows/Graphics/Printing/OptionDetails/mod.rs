@@ -2535,6 +2535,7 @@ impl PrintOptionStates {
 impl windows_core::TypeKind for PrintOptionStates {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintOptionStates {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintOptionStates").field(&self.0).finish()
@@ -2589,6 +2590,7 @@ impl PrintOptionType {
 impl windows_core::TypeKind for PrintOptionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintOptionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintOptionType").field(&self.0).finish()
@@ -2599,3 +2601,5 @@ impl windows_core::RuntimeType for PrintOptionType {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
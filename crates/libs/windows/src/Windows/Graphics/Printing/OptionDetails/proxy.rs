@@ -0,0 +1,587 @@
+impl windows_core::imp::ProxyVtable for IPrintBindingOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintBindingOptionDetails", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintBindingOptionDetails_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintBindingOptionDetails", "WarningText", 7usize, |this| ((**(this as *const *const IPrintBindingOptionDetails_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintBindingOptionDetails", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintBindingOptionDetails_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintBindingOptionDetails", "Description", 9usize, |this| ((**(this as *const *const IPrintBindingOptionDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintBindingOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintBorderingOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintBorderingOptionDetails", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintBorderingOptionDetails_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintBorderingOptionDetails", "WarningText", 7usize, |this| ((**(this as *const *const IPrintBorderingOptionDetails_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintBorderingOptionDetails", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintBorderingOptionDetails_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintBorderingOptionDetails", "Description", 9usize, |this| ((**(this as *const *const IPrintBorderingOptionDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintBorderingOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintCollationOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCollationOptionDetails", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintCollationOptionDetails_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCollationOptionDetails", "WarningText", 7usize, |this| ((**(this as *const *const IPrintCollationOptionDetails_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCollationOptionDetails", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintCollationOptionDetails_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCollationOptionDetails", "Description", 9usize, |this| ((**(this as *const *const IPrintCollationOptionDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintCollationOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintColorModeOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintColorModeOptionDetails", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintColorModeOptionDetails_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintColorModeOptionDetails", "WarningText", 7usize, |this| ((**(this as *const *const IPrintColorModeOptionDetails_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintColorModeOptionDetails", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintColorModeOptionDetails_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintColorModeOptionDetails", "Description", 9usize, |this| ((**(this as *const *const IPrintColorModeOptionDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintColorModeOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintCopiesOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCopiesOptionDetails", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintCopiesOptionDetails_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCopiesOptionDetails", "WarningText", 7usize, |this| ((**(this as *const *const IPrintCopiesOptionDetails_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCopiesOptionDetails", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintCopiesOptionDetails_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCopiesOptionDetails", "Description", 9usize, |this| ((**(this as *const *const IPrintCopiesOptionDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintCopiesOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintCustomItemDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ItemId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomItemDetails", "ItemId", 6usize, |this| ((**(this as *const *const IPrintCustomItemDetails_Vtbl)).ItemId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetItemDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomItemDetails", "SetItemDisplayName", 7usize, |this| ((**(this as *const *const IPrintCustomItemDetails_Vtbl)).SetItemDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomItemDetails", "ItemDisplayName", 8usize, |this| ((**(this as *const *const IPrintCustomItemDetails_Vtbl)).ItemDisplayName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ItemId, SetItemDisplayName, ItemDisplayName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintCustomItemDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrintCustomItemListOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddItem(this: *mut core::ffi::c_void, itemid: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomItemListOptionDetails", "AddItem", 6usize, |this| ((**(this as *const *const IPrintCustomItemListOptionDetails_Vtbl)).AddItem)(this, itemid, displayname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddItem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintCustomItemListOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPrintCustomItemListOptionDetails2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AddItem(this: *mut core::ffi::c_void, itemid: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, description: core::mem::MaybeUninit<windows_core::HSTRING>, icon: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomItemListOptionDetails2", "AddItem", 6usize, |this| ((**(this as *const *const IPrintCustomItemListOptionDetails2_Vtbl)).AddItem)(this, itemid, displayname, description, icon), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            AddItem,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AddItem: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintCustomItemListOptionDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintCustomItemListOptionDetails3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomItemListOptionDetails3", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintCustomItemListOptionDetails3_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomItemListOptionDetails3", "WarningText", 7usize, |this| ((**(this as *const *const IPrintCustomItemListOptionDetails3_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomItemListOptionDetails3", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintCustomItemListOptionDetails3_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomItemListOptionDetails3", "Description", 9usize, |this| ((**(this as *const *const IPrintCustomItemListOptionDetails3_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintCustomItemListOptionDetails3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintCustomOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomOptionDetails", "SetDisplayName", 6usize, |this| ((**(this as *const *const IPrintCustomOptionDetails_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomOptionDetails", "DisplayName", 7usize, |this| ((**(this as *const *const IPrintCustomOptionDetails_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDisplayName, DisplayName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintCustomOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintCustomTextOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMaxCharacters(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomTextOptionDetails", "SetMaxCharacters", 6usize, |this| ((**(this as *const *const IPrintCustomTextOptionDetails_Vtbl)).SetMaxCharacters)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxCharacters(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomTextOptionDetails", "MaxCharacters", 7usize, |this| ((**(this as *const *const IPrintCustomTextOptionDetails_Vtbl)).MaxCharacters)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMaxCharacters, MaxCharacters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintCustomTextOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintCustomTextOptionDetails2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomTextOptionDetails2", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintCustomTextOptionDetails2_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomTextOptionDetails2", "WarningText", 7usize, |this| ((**(this as *const *const IPrintCustomTextOptionDetails2_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomTextOptionDetails2", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintCustomTextOptionDetails2_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomTextOptionDetails2", "Description", 9usize, |this| ((**(this as *const *const IPrintCustomTextOptionDetails2_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintCustomTextOptionDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintCustomToggleOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomToggleOptionDetails", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintCustomToggleOptionDetails_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomToggleOptionDetails", "WarningText", 7usize, |this| ((**(this as *const *const IPrintCustomToggleOptionDetails_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomToggleOptionDetails", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintCustomToggleOptionDetails_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCustomToggleOptionDetails", "Description", 9usize, |this| ((**(this as *const *const IPrintCustomToggleOptionDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintCustomToggleOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintDuplexOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDuplexOptionDetails", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintDuplexOptionDetails_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDuplexOptionDetails", "WarningText", 7usize, |this| ((**(this as *const *const IPrintDuplexOptionDetails_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDuplexOptionDetails", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintDuplexOptionDetails_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDuplexOptionDetails", "Description", 9usize, |this| ((**(this as *const *const IPrintDuplexOptionDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintDuplexOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintHolePunchOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintHolePunchOptionDetails", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintHolePunchOptionDetails_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintHolePunchOptionDetails", "WarningText", 7usize, |this| ((**(this as *const *const IPrintHolePunchOptionDetails_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintHolePunchOptionDetails", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintHolePunchOptionDetails_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintHolePunchOptionDetails", "Description", 9usize, |this| ((**(this as *const *const IPrintHolePunchOptionDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintHolePunchOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrintItemListOptionDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Items(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintItemListOptionDetails", "Items", 6usize, |this| ((**(this as *const *const IPrintItemListOptionDetails_Vtbl)).Items)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Items,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Items: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintItemListOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintMediaSizeOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintMediaSizeOptionDetails", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintMediaSizeOptionDetails_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintMediaSizeOptionDetails", "WarningText", 7usize, |this| ((**(this as *const *const IPrintMediaSizeOptionDetails_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintMediaSizeOptionDetails", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintMediaSizeOptionDetails_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintMediaSizeOptionDetails", "Description", 9usize, |this| ((**(this as *const *const IPrintMediaSizeOptionDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintMediaSizeOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintMediaTypeOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintMediaTypeOptionDetails", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintMediaTypeOptionDetails_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintMediaTypeOptionDetails", "WarningText", 7usize, |this| ((**(this as *const *const IPrintMediaTypeOptionDetails_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintMediaTypeOptionDetails", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintMediaTypeOptionDetails_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintMediaTypeOptionDetails", "Description", 9usize, |this| ((**(this as *const *const IPrintMediaTypeOptionDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintMediaTypeOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintNumberOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinValue(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintNumberOptionDetails", "MinValue", 6usize, |this| ((**(this as *const *const IPrintNumberOptionDetails_Vtbl)).MinValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxValue(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintNumberOptionDetails", "MaxValue", 7usize, |this| ((**(this as *const *const IPrintNumberOptionDetails_Vtbl)).MaxValue)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MinValue, MaxValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintNumberOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OptionId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOptionDetails", "OptionId", 6usize, |this| ((**(this as *const *const IPrintOptionDetails_Vtbl)).OptionId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OptionType(this: *mut core::ffi::c_void, result__: *mut PrintOptionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOptionDetails", "OptionType", 7usize, |this| ((**(this as *const *const IPrintOptionDetails_Vtbl)).OptionType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetErrorText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOptionDetails", "SetErrorText", 8usize, |this| ((**(this as *const *const IPrintOptionDetails_Vtbl)).SetErrorText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOptionDetails", "ErrorText", 9usize, |this| ((**(this as *const *const IPrintOptionDetails_Vtbl)).ErrorText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, value: PrintOptionStates) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOptionDetails", "SetState", 10usize, |this| ((**(this as *const *const IPrintOptionDetails_Vtbl)).SetState)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut PrintOptionStates) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOptionDetails", "State", 11usize, |this| ((**(this as *const *const IPrintOptionDetails_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOptionDetails", "Value", 12usize, |this| ((**(this as *const *const IPrintOptionDetails_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySetValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOptionDetails", "TrySetValue", 13usize, |this| ((**(this as *const *const IPrintOptionDetails_Vtbl)).TrySetValue)(this, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OptionId,
+            OptionType,
+            SetErrorText,
+            ErrorText,
+            SetState,
+            State,
+            Value,
+            TrySetValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintOrientationOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOrientationOptionDetails", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintOrientationOptionDetails_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOrientationOptionDetails", "WarningText", 7usize, |this| ((**(this as *const *const IPrintOrientationOptionDetails_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOrientationOptionDetails", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintOrientationOptionDetails_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOrientationOptionDetails", "Description", 9usize, |this| ((**(this as *const *const IPrintOrientationOptionDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintOrientationOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintPageRangeOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageRangeOptionDetails", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintPageRangeOptionDetails_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageRangeOptionDetails", "WarningText", 7usize, |this| ((**(this as *const *const IPrintPageRangeOptionDetails_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageRangeOptionDetails", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintPageRangeOptionDetails_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageRangeOptionDetails", "Description", 9usize, |this| ((**(this as *const *const IPrintPageRangeOptionDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintPageRangeOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintQualityOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintQualityOptionDetails", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintQualityOptionDetails_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintQualityOptionDetails", "WarningText", 7usize, |this| ((**(this as *const *const IPrintQualityOptionDetails_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintQualityOptionDetails", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintQualityOptionDetails_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintQualityOptionDetails", "Description", 9usize, |this| ((**(this as *const *const IPrintQualityOptionDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintQualityOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintStapleOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWarningText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintStapleOptionDetails", "SetWarningText", 6usize, |this| ((**(this as *const *const IPrintStapleOptionDetails_Vtbl)).SetWarningText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WarningText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintStapleOptionDetails", "WarningText", 7usize, |this| ((**(this as *const *const IPrintStapleOptionDetails_Vtbl)).WarningText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintStapleOptionDetails", "SetDescription", 8usize, |this| ((**(this as *const *const IPrintStapleOptionDetails_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintStapleOptionDetails", "Description", 9usize, |this| ((**(this as *const *const IPrintStapleOptionDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWarningText, WarningText, SetDescription, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintStapleOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskOptionChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OptionId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionChangedEventArgs", "OptionId", 6usize, |this| ((**(this as *const *const IPrintTaskOptionChangedEventArgs_Vtbl)).OptionId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OptionId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskOptionChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrintTaskOptionDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Options(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionDetails", "Options", 6usize, |this| ((**(this as *const *const IPrintTaskOptionDetails_Vtbl)).Options)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateItemListOption(this: *mut core::ffi::c_void, optionid: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionDetails", "CreateItemListOption", 7usize, |this| ((**(this as *const *const IPrintTaskOptionDetails_Vtbl)).CreateItemListOption)(this, optionid, displayname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTextOption(this: *mut core::ffi::c_void, optionid: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionDetails", "CreateTextOption", 8usize, |this| ((**(this as *const *const IPrintTaskOptionDetails_Vtbl)).CreateTextOption)(this, optionid, displayname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OptionChanged(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionDetails", "OptionChanged", 9usize, |this| ((**(this as *const *const IPrintTaskOptionDetails_Vtbl)).OptionChanged)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOptionChanged(this: *mut core::ffi::c_void, eventcookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionDetails", "RemoveOptionChanged", 10usize, |this| ((**(this as *const *const IPrintTaskOptionDetails_Vtbl)).RemoveOptionChanged)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginValidation(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionDetails", "BeginValidation", 11usize, |this| ((**(this as *const *const IPrintTaskOptionDetails_Vtbl)).BeginValidation)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBeginValidation(this: *mut core::ffi::c_void, eventcookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionDetails", "RemoveBeginValidation", 12usize, |this| ((**(this as *const *const IPrintTaskOptionDetails_Vtbl)).RemoveBeginValidation)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Options,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Options: 0,
+            CreateItemListOption,
+            CreateTextOption,
+            OptionChanged,
+            RemoveOptionChanged,
+            BeginValidation,
+            RemoveBeginValidation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskOptionDetails2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateToggleOption(this: *mut core::ffi::c_void, optionid: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionDetails2", "CreateToggleOption", 6usize, |this| ((**(this as *const *const IPrintTaskOptionDetails2_Vtbl)).CreateToggleOption)(this, optionid, displayname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateToggleOption }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskOptionDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskOptionDetailsStatic_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFromPrintTaskOptions(this: *mut core::ffi::c_void, printtaskoptions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionDetailsStatic", "GetFromPrintTaskOptions", 6usize, |this| ((**(this as *const *const IPrintTaskOptionDetailsStatic_Vtbl)).GetFromPrintTaskOptions)(this, printtaskoptions, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFromPrintTaskOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskOptionDetailsStatic as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTextOptionDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxCharacters(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTextOptionDetails", "MaxCharacters", 6usize, |this| ((**(this as *const *const IPrintTextOptionDetails_Vtbl)).MaxCharacters)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MaxCharacters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTextOptionDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1112,6 +1112,7 @@ impl IppCommunicationErrorKind {
 impl windows_core::TypeKind for IppCommunicationErrorKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IppCommunicationErrorKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IppCommunicationErrorKind").field(&self.0).finish()
@@ -1133,6 +1134,7 @@ impl IppPrinterCommunicationKind {
 impl windows_core::TypeKind for IppPrinterCommunicationKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IppPrinterCommunicationKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IppPrinterCommunicationKind").field(&self.0).finish()
@@ -1151,6 +1153,7 @@ impl SettingsLaunchKind {
 impl windows_core::TypeKind for SettingsLaunchKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SettingsLaunchKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SettingsLaunchKind").field(&self.0).finish()
@@ -1170,6 +1173,7 @@ impl WorkflowPrintTicketValidationStatus {
 impl windows_core::TypeKind for WorkflowPrintTicketValidationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WorkflowPrintTicketValidationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WorkflowPrintTicketValidationStatus").field(&self.0).finish()
@@ -1190,6 +1194,7 @@ impl XpsImageQuality {
 impl windows_core::TypeKind for XpsImageQuality {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XpsImageQuality {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XpsImageQuality").field(&self.0).finish()
@@ -1198,3 +1203,5 @@ impl core::fmt::Debug for XpsImageQuality {
 impl windows_core::RuntimeType for XpsImageQuality {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Graphics.Printing.PrintSupport.XpsImageQuality;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
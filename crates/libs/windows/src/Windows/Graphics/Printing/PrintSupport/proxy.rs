@@ -0,0 +1,580 @@
+impl windows_core::imp::ProxyVtable for IPrintSupportCommunicationErrorDetectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ErrorKind(this: *mut core::ffi::c_void, result__: *mut IppCommunicationErrorKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportCommunicationErrorDetectedEventArgs", "ErrorKind", 6usize, |this| ((**(this as *const *const IPrintSupportCommunicationErrorDetectedEventArgs_Vtbl)).ErrorKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportCommunicationErrorDetectedEventArgs", "ExtendedError", 7usize, |this| ((**(this as *const *const IPrintSupportCommunicationErrorDetectedEventArgs_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommunicationConfiguration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportCommunicationErrorDetectedEventArgs", "CommunicationConfiguration", 8usize, |this| ((**(this as *const *const IPrintSupportCommunicationErrorDetectedEventArgs_Vtbl)).CommunicationConfiguration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportCommunicationErrorDetectedEventArgs", "GetDeferral", 9usize, |this| ((**(this as *const *const IPrintSupportCommunicationErrorDetectedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ErrorKind,
+            ExtendedError,
+            CommunicationConfiguration,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportCommunicationErrorDetectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Printers")]
+impl windows_core::imp::ProxyVtable for IPrintSupportExtensionSession_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Printers")]
+        unsafe extern "system" fn Printer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportExtensionSession", "Printer", 6usize, |this| ((**(this as *const *const IPrintSupportExtensionSession_Vtbl)).Printer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintTicketValidationRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportExtensionSession", "PrintTicketValidationRequested", 7usize, |this| ((**(this as *const *const IPrintSupportExtensionSession_Vtbl)).PrintTicketValidationRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePrintTicketValidationRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportExtensionSession", "RemovePrintTicketValidationRequested", 8usize, |this| ((**(this as *const *const IPrintSupportExtensionSession_Vtbl)).RemovePrintTicketValidationRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintDeviceCapabilitiesChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportExtensionSession", "PrintDeviceCapabilitiesChanged", 9usize, |this| ((**(this as *const *const IPrintSupportExtensionSession_Vtbl)).PrintDeviceCapabilitiesChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePrintDeviceCapabilitiesChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportExtensionSession", "RemovePrintDeviceCapabilitiesChanged", 10usize, |this| ((**(this as *const *const IPrintSupportExtensionSession_Vtbl)).RemovePrintDeviceCapabilitiesChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportExtensionSession", "Start", 11usize, |this| ((**(this as *const *const IPrintSupportExtensionSession_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Printers")]
+            Printer,
+            #[cfg(not(feature = "Devices_Printers"))]
+            Printer: 0,
+            PrintTicketValidationRequested,
+            RemovePrintTicketValidationRequested,
+            PrintDeviceCapabilitiesChanged,
+            RemovePrintDeviceCapabilitiesChanged,
+            Start,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportExtensionSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintSupportExtensionSession2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrinterSelected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportExtensionSession2", "PrinterSelected", 6usize, |this| ((**(this as *const *const IPrintSupportExtensionSession2_Vtbl)).PrinterSelected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePrinterSelected(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportExtensionSession2", "RemovePrinterSelected", 7usize, |this| ((**(this as *const *const IPrintSupportExtensionSession2_Vtbl)).RemovePrinterSelected)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrinterSelected, RemovePrinterSelected }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportExtensionSession2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintSupportExtensionSession3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CommunicationErrorDetected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportExtensionSession3", "CommunicationErrorDetected", 6usize, |this| ((**(this as *const *const IPrintSupportExtensionSession3_Vtbl)).CommunicationErrorDetected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCommunicationErrorDetected(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportExtensionSession3", "RemoveCommunicationErrorDetected", 7usize, |this| ((**(this as *const *const IPrintSupportExtensionSession3_Vtbl)).RemoveCommunicationErrorDetected)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CommunicationErrorDetected, RemoveCommunicationErrorDetected }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportExtensionSession3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintSupportExtensionTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportExtensionTriggerDetails", "Session", 6usize, |this| ((**(this as *const *const IPrintSupportExtensionTriggerDetails_Vtbl)).Session)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Session }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportExtensionTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintSupportIppCommunicationConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CommunicationKind(this: *mut core::ffi::c_void, result__: *mut IppPrinterCommunicationKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportIppCommunicationConfiguration", "CommunicationKind", 6usize, |this| ((**(this as *const *const IPrintSupportIppCommunicationConfiguration_Vtbl)).CommunicationKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanModifyTimeouts(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportIppCommunicationConfiguration", "CanModifyTimeouts", 7usize, |this| ((**(this as *const *const IPrintSupportIppCommunicationConfiguration_Vtbl)).CanModifyTimeouts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IppAttributeTimeouts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportIppCommunicationConfiguration", "IppAttributeTimeouts", 8usize, |this| ((**(this as *const *const IPrintSupportIppCommunicationConfiguration_Vtbl)).IppAttributeTimeouts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IppJobTimeouts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportIppCommunicationConfiguration", "IppJobTimeouts", 9usize, |this| ((**(this as *const *const IPrintSupportIppCommunicationConfiguration_Vtbl)).IppJobTimeouts)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CommunicationKind,
+            CanModifyTimeouts,
+            IppAttributeTimeouts,
+            IppJobTimeouts,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportIppCommunicationConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintSupportIppCommunicationTimeouts_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportIppCommunicationTimeouts", "ConnectTimeout", 6usize, |this| ((**(this as *const *const IPrintSupportIppCommunicationTimeouts_Vtbl)).ConnectTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConnectTimeout(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportIppCommunicationTimeouts", "SetConnectTimeout", 7usize, |this| ((**(this as *const *const IPrintSupportIppCommunicationTimeouts_Vtbl)).SetConnectTimeout)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportIppCommunicationTimeouts", "SendTimeout", 8usize, |this| ((**(this as *const *const IPrintSupportIppCommunicationTimeouts_Vtbl)).SendTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSendTimeout(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportIppCommunicationTimeouts", "SetSendTimeout", 9usize, |this| ((**(this as *const *const IPrintSupportIppCommunicationTimeouts_Vtbl)).SetSendTimeout)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceiveTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportIppCommunicationTimeouts", "ReceiveTimeout", 10usize, |this| ((**(this as *const *const IPrintSupportIppCommunicationTimeouts_Vtbl)).ReceiveTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReceiveTimeout(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportIppCommunicationTimeouts", "SetReceiveTimeout", 11usize, |this| ((**(this as *const *const IPrintSupportIppCommunicationTimeouts_Vtbl)).SetReceiveTimeout)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConnectTimeout,
+            SetConnectTimeout,
+            SendTimeout,
+            SetSendTimeout,
+            ReceiveTimeout,
+            SetReceiveTimeout,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportIppCommunicationTimeouts as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintSupportMxdcImageQualityConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NormalOutputQuality(this: *mut core::ffi::c_void, result__: *mut XpsImageQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportMxdcImageQualityConfiguration", "NormalOutputQuality", 6usize, |this| ((**(this as *const *const IPrintSupportMxdcImageQualityConfiguration_Vtbl)).NormalOutputQuality)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNormalOutputQuality(this: *mut core::ffi::c_void, value: XpsImageQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportMxdcImageQualityConfiguration", "SetNormalOutputQuality", 7usize, |this| ((**(this as *const *const IPrintSupportMxdcImageQualityConfiguration_Vtbl)).SetNormalOutputQuality)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DraftOutputQuality(this: *mut core::ffi::c_void, result__: *mut XpsImageQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportMxdcImageQualityConfiguration", "DraftOutputQuality", 8usize, |this| ((**(this as *const *const IPrintSupportMxdcImageQualityConfiguration_Vtbl)).DraftOutputQuality)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDraftOutputQuality(this: *mut core::ffi::c_void, value: XpsImageQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportMxdcImageQualityConfiguration", "SetDraftOutputQuality", 9usize, |this| ((**(this as *const *const IPrintSupportMxdcImageQualityConfiguration_Vtbl)).SetDraftOutputQuality)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HighOutputQuality(this: *mut core::ffi::c_void, result__: *mut XpsImageQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportMxdcImageQualityConfiguration", "HighOutputQuality", 10usize, |this| ((**(this as *const *const IPrintSupportMxdcImageQualityConfiguration_Vtbl)).HighOutputQuality)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHighOutputQuality(this: *mut core::ffi::c_void, value: XpsImageQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportMxdcImageQualityConfiguration", "SetHighOutputQuality", 11usize, |this| ((**(this as *const *const IPrintSupportMxdcImageQualityConfiguration_Vtbl)).SetHighOutputQuality)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotographicOutputQuality(this: *mut core::ffi::c_void, result__: *mut XpsImageQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportMxdcImageQualityConfiguration", "PhotographicOutputQuality", 12usize, |this| ((**(this as *const *const IPrintSupportMxdcImageQualityConfiguration_Vtbl)).PhotographicOutputQuality)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPhotographicOutputQuality(this: *mut core::ffi::c_void, value: XpsImageQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportMxdcImageQualityConfiguration", "SetPhotographicOutputQuality", 13usize, |this| ((**(this as *const *const IPrintSupportMxdcImageQualityConfiguration_Vtbl)).SetPhotographicOutputQuality)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextOutputQuality(this: *mut core::ffi::c_void, result__: *mut XpsImageQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportMxdcImageQualityConfiguration", "TextOutputQuality", 14usize, |this| ((**(this as *const *const IPrintSupportMxdcImageQualityConfiguration_Vtbl)).TextOutputQuality)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextOutputQuality(this: *mut core::ffi::c_void, value: XpsImageQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportMxdcImageQualityConfiguration", "SetTextOutputQuality", 15usize, |this| ((**(this as *const *const IPrintSupportMxdcImageQualityConfiguration_Vtbl)).SetTextOutputQuality)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutomaticOutputQuality(this: *mut core::ffi::c_void, result__: *mut XpsImageQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportMxdcImageQualityConfiguration", "AutomaticOutputQuality", 16usize, |this| ((**(this as *const *const IPrintSupportMxdcImageQualityConfiguration_Vtbl)).AutomaticOutputQuality)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutomaticOutputQuality(this: *mut core::ffi::c_void, value: XpsImageQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportMxdcImageQualityConfiguration", "SetAutomaticOutputQuality", 17usize, |this| ((**(this as *const *const IPrintSupportMxdcImageQualityConfiguration_Vtbl)).SetAutomaticOutputQuality)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FaxOutputQuality(this: *mut core::ffi::c_void, result__: *mut XpsImageQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportMxdcImageQualityConfiguration", "FaxOutputQuality", 18usize, |this| ((**(this as *const *const IPrintSupportMxdcImageQualityConfiguration_Vtbl)).FaxOutputQuality)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFaxOutputQuality(this: *mut core::ffi::c_void, value: XpsImageQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportMxdcImageQualityConfiguration", "SetFaxOutputQuality", 19usize, |this| ((**(this as *const *const IPrintSupportMxdcImageQualityConfiguration_Vtbl)).SetFaxOutputQuality)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NormalOutputQuality,
+            SetNormalOutputQuality,
+            DraftOutputQuality,
+            SetDraftOutputQuality,
+            HighOutputQuality,
+            SetHighOutputQuality,
+            PhotographicOutputQuality,
+            SetPhotographicOutputQuality,
+            TextOutputQuality,
+            SetTextOutputQuality,
+            AutomaticOutputQuality,
+            SetAutomaticOutputQuality,
+            FaxOutputQuality,
+            SetFaxOutputQuality,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportMxdcImageQualityConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IPrintSupportPrintDeviceCapabilitiesChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn GetCurrentPrintDeviceCapabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintDeviceCapabilitiesChangedEventArgs", "GetCurrentPrintDeviceCapabilities", 6usize, |this| ((**(this as *const *const IPrintSupportPrintDeviceCapabilitiesChangedEventArgs_Vtbl)).GetCurrentPrintDeviceCapabilities)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn UpdatePrintDeviceCapabilities(this: *mut core::ffi::c_void, updatedpdc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintDeviceCapabilitiesChangedEventArgs", "UpdatePrintDeviceCapabilities", 7usize, |this| ((**(this as *const *const IPrintSupportPrintDeviceCapabilitiesChangedEventArgs_Vtbl)).UpdatePrintDeviceCapabilities)(this, updatedpdc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintDeviceCapabilitiesChangedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IPrintSupportPrintDeviceCapabilitiesChangedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            GetCurrentPrintDeviceCapabilities,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            GetCurrentPrintDeviceCapabilities: 0,
+            #[cfg(feature = "Data_Xml_Dom")]
+            UpdatePrintDeviceCapabilities,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            UpdatePrintDeviceCapabilities: 0,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportPrintDeviceCapabilitiesChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IPrintSupportPrintDeviceCapabilitiesChangedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetSupportedPdlPassthroughContentTypes(this: *mut core::ffi::c_void, supportedpdlcontenttypes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintDeviceCapabilitiesChangedEventArgs2", "SetSupportedPdlPassthroughContentTypes", 6usize, |this| ((**(this as *const *const IPrintSupportPrintDeviceCapabilitiesChangedEventArgs2_Vtbl)).SetSupportedPdlPassthroughContentTypes)(this, supportedpdlcontenttypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceLanguage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintDeviceCapabilitiesChangedEventArgs2", "ResourceLanguage", 7usize, |this| ((**(this as *const *const IPrintSupportPrintDeviceCapabilitiesChangedEventArgs2_Vtbl)).ResourceLanguage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn GetCurrentPrintDeviceResources(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintDeviceCapabilitiesChangedEventArgs2", "GetCurrentPrintDeviceResources", 8usize, |this| ((**(this as *const *const IPrintSupportPrintDeviceCapabilitiesChangedEventArgs2_Vtbl)).GetCurrentPrintDeviceResources)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn UpdatePrintDeviceResources(this: *mut core::ffi::c_void, updatedpdr: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintDeviceCapabilitiesChangedEventArgs2", "UpdatePrintDeviceResources", 9usize, |this| ((**(this as *const *const IPrintSupportPrintDeviceCapabilitiesChangedEventArgs2_Vtbl)).UpdatePrintDeviceResources)(this, updatedpdr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrintDeviceCapabilitiesUpdatePolicy(this: *mut core::ffi::c_void, updatepolicy: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintDeviceCapabilitiesChangedEventArgs2", "SetPrintDeviceCapabilitiesUpdatePolicy", 10usize, |this| ((**(this as *const *const IPrintSupportPrintDeviceCapabilitiesChangedEventArgs2_Vtbl)).SetPrintDeviceCapabilitiesUpdatePolicy)(this, updatepolicy), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SetSupportedPdlPassthroughContentTypes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetSupportedPdlPassthroughContentTypes: 0,
+            ResourceLanguage,
+            #[cfg(feature = "Data_Xml_Dom")]
+            GetCurrentPrintDeviceResources,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            GetCurrentPrintDeviceResources: 0,
+            #[cfg(feature = "Data_Xml_Dom")]
+            UpdatePrintDeviceResources,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            UpdatePrintDeviceResources: 0,
+            SetPrintDeviceCapabilitiesUpdatePolicy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportPrintDeviceCapabilitiesChangedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintSupportPrintDeviceCapabilitiesChangedEventArgs3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CommunicationConfiguration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintDeviceCapabilitiesChangedEventArgs3", "CommunicationConfiguration", 6usize, |this| ((**(this as *const *const IPrintSupportPrintDeviceCapabilitiesChangedEventArgs3_Vtbl)).CommunicationConfiguration)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CommunicationConfiguration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportPrintDeviceCapabilitiesChangedEventArgs3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintSupportPrintDeviceCapabilitiesChangedEventArgs4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MxdcImageQualityConfiguration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintDeviceCapabilitiesChangedEventArgs4", "MxdcImageQualityConfiguration", 6usize, |this| ((**(this as *const *const IPrintSupportPrintDeviceCapabilitiesChangedEventArgs4_Vtbl)).MxdcImageQualityConfiguration)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MxdcImageQualityConfiguration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportPrintDeviceCapabilitiesChangedEventArgs4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintSupportPrintDeviceCapabilitiesUpdatePolicy_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportPrintDeviceCapabilitiesUpdatePolicy as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintSupportPrintDeviceCapabilitiesUpdatePolicyStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreatePeriodicRefresh(this: *mut core::ffi::c_void, updateperiod: super::super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintDeviceCapabilitiesUpdatePolicyStatics", "CreatePeriodicRefresh", 6usize, |this| ((**(this as *const *const IPrintSupportPrintDeviceCapabilitiesUpdatePolicyStatics_Vtbl)).CreatePeriodicRefresh)(this, updateperiod, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePrintJobRefresh(this: *mut core::ffi::c_void, numberofjobs: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintDeviceCapabilitiesUpdatePolicyStatics", "CreatePrintJobRefresh", 7usize, |this| ((**(this as *const *const IPrintSupportPrintDeviceCapabilitiesUpdatePolicyStatics_Vtbl)).CreatePrintJobRefresh)(this, numberofjobs, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreatePeriodicRefresh, CreatePrintJobRefresh }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportPrintDeviceCapabilitiesUpdatePolicyStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintSupportPrintTicketElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LocalName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintTicketElement", "LocalName", 6usize, |this| ((**(this as *const *const IPrintSupportPrintTicketElement_Vtbl)).LocalName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintTicketElement", "SetLocalName", 7usize, |this| ((**(this as *const *const IPrintSupportPrintTicketElement_Vtbl)).SetLocalName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NamespaceUri(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintTicketElement", "NamespaceUri", 8usize, |this| ((**(this as *const *const IPrintSupportPrintTicketElement_Vtbl)).NamespaceUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNamespaceUri(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintTicketElement", "SetNamespaceUri", 9usize, |this| ((**(this as *const *const IPrintSupportPrintTicketElement_Vtbl)).SetNamespaceUri)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LocalName, SetLocalName, NamespaceUri, SetNamespaceUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportPrintTicketElement as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Printing_PrintTicket")]
+impl windows_core::imp::ProxyVtable for IPrintSupportPrintTicketValidationRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Printing_PrintTicket")]
+        unsafe extern "system" fn PrintTicket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintTicketValidationRequestedEventArgs", "PrintTicket", 6usize, |this| ((**(this as *const *const IPrintSupportPrintTicketValidationRequestedEventArgs_Vtbl)).PrintTicket)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrintTicketValidationStatus(this: *mut core::ffi::c_void, status: WorkflowPrintTicketValidationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintTicketValidationRequestedEventArgs", "SetPrintTicketValidationStatus", 7usize, |this| ((**(this as *const *const IPrintSupportPrintTicketValidationRequestedEventArgs_Vtbl)).SetPrintTicketValidationStatus)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrintTicketValidationRequestedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IPrintSupportPrintTicketValidationRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Printing_PrintTicket")]
+            PrintTicket,
+            #[cfg(not(feature = "Graphics_Printing_PrintTicket"))]
+            PrintTicket: 0,
+            SetPrintTicketValidationStatus,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportPrintTicketValidationRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections", feature = "Graphics_Printing_PrintTicket", feature = "UI_Shell"))]
+impl windows_core::imp::ProxyVtable for IPrintSupportPrinterSelectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn SourceAppInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrinterSelectedEventArgs", "SourceAppInfo", 6usize, |this| ((**(this as *const *const IPrintSupportPrinterSelectedEventArgs_Vtbl)).SourceAppInfo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Printing_PrintTicket")]
+        unsafe extern "system" fn PrintTicket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrinterSelectedEventArgs", "PrintTicket", 7usize, |this| ((**(this as *const *const IPrintSupportPrinterSelectedEventArgs_Vtbl)).PrintTicket)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Printing_PrintTicket")]
+        unsafe extern "system" fn SetPrintTicket(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrinterSelectedEventArgs", "SetPrintTicket", 8usize, |this| ((**(this as *const *const IPrintSupportPrinterSelectedEventArgs_Vtbl)).SetPrintTicket)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetAdditionalFeatures(this: *mut core::ffi::c_void, features: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrinterSelectedEventArgs", "SetAdditionalFeatures", 9usize, |this| ((**(this as *const *const IPrintSupportPrinterSelectedEventArgs_Vtbl)).SetAdditionalFeatures)(this, features), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetAdditionalParameters(this: *mut core::ffi::c_void, parameters: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrinterSelectedEventArgs", "SetAdditionalParameters", 10usize, |this| ((**(this as *const *const IPrintSupportPrinterSelectedEventArgs_Vtbl)).SetAdditionalParameters)(this, parameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowedAdditionalFeaturesAndParametersCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrinterSelectedEventArgs", "AllowedAdditionalFeaturesAndParametersCount", 11usize, |this| ((**(this as *const *const IPrintSupportPrinterSelectedEventArgs_Vtbl)).AllowedAdditionalFeaturesAndParametersCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Shell")]
+        unsafe extern "system" fn SetAdaptiveCard(this: *mut core::ffi::c_void, adaptivecard: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrinterSelectedEventArgs", "SetAdaptiveCard", 12usize, |this| ((**(this as *const *const IPrintSupportPrinterSelectedEventArgs_Vtbl)).SetAdaptiveCard)(this, adaptivecard), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportPrinterSelectedEventArgs", "GetDeferral", 13usize, |this| ((**(this as *const *const IPrintSupportPrinterSelectedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel")]
+            SourceAppInfo,
+            #[cfg(not(feature = "ApplicationModel"))]
+            SourceAppInfo: 0,
+            #[cfg(feature = "Graphics_Printing_PrintTicket")]
+            PrintTicket,
+            #[cfg(not(feature = "Graphics_Printing_PrintTicket"))]
+            PrintTicket: 0,
+            #[cfg(feature = "Graphics_Printing_PrintTicket")]
+            SetPrintTicket,
+            #[cfg(not(feature = "Graphics_Printing_PrintTicket"))]
+            SetPrintTicket: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetAdditionalFeatures,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetAdditionalFeatures: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetAdditionalParameters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetAdditionalParameters: 0,
+            AllowedAdditionalFeaturesAndParametersCount,
+            #[cfg(feature = "UI_Shell")]
+            SetAdaptiveCard,
+            #[cfg(not(feature = "UI_Shell"))]
+            SetAdaptiveCard: 0,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportPrinterSelectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel", feature = "Devices_Printers"))]
+impl windows_core::imp::ProxyVtable for IPrintSupportSessionInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn SourceAppInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportSessionInfo", "SourceAppInfo", 6usize, |this| ((**(this as *const *const IPrintSupportSessionInfo_Vtbl)).SourceAppInfo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Printers")]
+        unsafe extern "system" fn Printer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportSessionInfo", "Printer", 7usize, |this| ((**(this as *const *const IPrintSupportSessionInfo_Vtbl)).Printer)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel")]
+            SourceAppInfo,
+            #[cfg(not(feature = "ApplicationModel"))]
+            SourceAppInfo: 0,
+            #[cfg(feature = "Devices_Printers")]
+            Printer,
+            #[cfg(not(feature = "Devices_Printers"))]
+            Printer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportSessionInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintSupportSettingsActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportSettingsActivatedEventArgs", "Session", 6usize, |this| ((**(this as *const *const IPrintSupportSettingsActivatedEventArgs_Vtbl)).Session)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportSettingsActivatedEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IPrintSupportSettingsActivatedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Session, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportSettingsActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for IPrintSupportSettingsActivatedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn OwnerWindowId(this: *mut core::ffi::c_void, result__: *mut super::super::super::UI::WindowId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportSettingsActivatedEventArgs2", "OwnerWindowId", 6usize, |this| ((**(this as *const *const IPrintSupportSettingsActivatedEventArgs2_Vtbl)).OwnerWindowId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI")]
+            OwnerWindowId,
+            #[cfg(not(feature = "UI"))]
+            OwnerWindowId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportSettingsActivatedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Printing_PrintTicket")]
+impl windows_core::imp::ProxyVtable for IPrintSupportSettingsUISession_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Printing_PrintTicket")]
+        unsafe extern "system" fn SessionPrintTicket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportSettingsUISession", "SessionPrintTicket", 6usize, |this| ((**(this as *const *const IPrintSupportSettingsUISession_Vtbl)).SessionPrintTicket)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentTitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportSettingsUISession", "DocumentTitle", 7usize, |this| ((**(this as *const *const IPrintSupportSettingsUISession_Vtbl)).DocumentTitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchKind(this: *mut core::ffi::c_void, result__: *mut SettingsLaunchKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportSettingsUISession", "LaunchKind", 8usize, |this| ((**(this as *const *const IPrintSupportSettingsUISession_Vtbl)).LaunchKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Printing_PrintTicket")]
+        unsafe extern "system" fn UpdatePrintTicket(this: *mut core::ffi::c_void, printticket: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportSettingsUISession", "UpdatePrintTicket", 9usize, |this| ((**(this as *const *const IPrintSupportSettingsUISession_Vtbl)).UpdatePrintTicket)(this, printticket), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSupportSettingsUISession", "SessionInfo", 10usize, |this| ((**(this as *const *const IPrintSupportSettingsUISession_Vtbl)).SessionInfo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Printing_PrintTicket")]
+            SessionPrintTicket,
+            #[cfg(not(feature = "Graphics_Printing_PrintTicket"))]
+            SessionPrintTicket: 0,
+            DocumentTitle,
+            LaunchKind,
+            #[cfg(feature = "Graphics_Printing_PrintTicket")]
+            UpdatePrintTicket,
+            #[cfg(not(feature = "Graphics_Printing_PrintTicket"))]
+            UpdatePrintTicket: 0,
+            SessionInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSupportSettingsUISession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
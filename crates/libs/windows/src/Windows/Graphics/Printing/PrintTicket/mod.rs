@@ -895,6 +895,7 @@ impl PrintTicketFeatureSelectionType {
 impl windows_core::TypeKind for PrintTicketFeatureSelectionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintTicketFeatureSelectionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintTicketFeatureSelectionType").field(&self.0).finish()
@@ -914,6 +915,7 @@ impl PrintTicketParameterDataType {
 impl windows_core::TypeKind for PrintTicketParameterDataType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintTicketParameterDataType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintTicketParameterDataType").field(&self.0).finish()
@@ -933,6 +935,7 @@ impl PrintTicketValueType {
 impl windows_core::TypeKind for PrintTicketValueType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintTicketValueType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintTicketValueType").field(&self.0).finish()
@@ -941,3 +944,5 @@ impl core::fmt::Debug for PrintTicketValueType {
 impl windows_core::RuntimeType for PrintTicketValueType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Graphics.Printing.PrintTicket.PrintTicketValueType;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
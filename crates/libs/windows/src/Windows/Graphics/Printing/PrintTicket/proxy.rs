@@ -0,0 +1,441 @@
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IPrintTicketCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "Name", 6usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn XmlNamespace(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "XmlNamespace", 7usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).XmlNamespace)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn XmlNode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "XmlNode", 8usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).XmlNode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentBindingFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "DocumentBindingFeature", 9usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).DocumentBindingFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentCollateFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "DocumentCollateFeature", 10usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).DocumentCollateFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentDuplexFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "DocumentDuplexFeature", 11usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).DocumentDuplexFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentHolePunchFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "DocumentHolePunchFeature", 12usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).DocumentHolePunchFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentInputBinFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "DocumentInputBinFeature", 13usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).DocumentInputBinFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentNUpFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "DocumentNUpFeature", 14usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).DocumentNUpFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentStapleFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "DocumentStapleFeature", 15usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).DocumentStapleFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JobPasscodeFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "JobPasscodeFeature", 16usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).JobPasscodeFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageBorderlessFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "PageBorderlessFeature", 17usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).PageBorderlessFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageMediaSizeFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "PageMediaSizeFeature", 18usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).PageMediaSizeFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageMediaTypeFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "PageMediaTypeFeature", 19usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).PageMediaTypeFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageOrientationFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "PageOrientationFeature", 20usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).PageOrientationFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageOutputColorFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "PageOutputColorFeature", 21usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).PageOutputColorFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageOutputQualityFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "PageOutputQualityFeature", 22usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).PageOutputQualityFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageResolutionFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "PageResolutionFeature", 23usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).PageResolutionFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeature(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, xmlnamespace: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "GetFeature", 24usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).GetFeature)(this, name, xmlnamespace, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParameterDefinition(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, xmlnamespace: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketCapabilities", "GetParameterDefinition", 25usize, |this| ((**(this as *const *const IPrintTicketCapabilities_Vtbl)).GetParameterDefinition)(this, name, xmlnamespace, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            XmlNamespace,
+            #[cfg(feature = "Data_Xml_Dom")]
+            XmlNode,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            XmlNode: 0,
+            DocumentBindingFeature,
+            DocumentCollateFeature,
+            DocumentDuplexFeature,
+            DocumentHolePunchFeature,
+            DocumentInputBinFeature,
+            DocumentNUpFeature,
+            DocumentStapleFeature,
+            JobPasscodeFeature,
+            PageBorderlessFeature,
+            PageMediaSizeFeature,
+            PageMediaTypeFeature,
+            PageOrientationFeature,
+            PageOutputColorFeature,
+            PageOutputQualityFeature,
+            PageResolutionFeature,
+            GetFeature,
+            GetParameterDefinition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTicketCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IPrintTicketFeature_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketFeature", "Name", 6usize, |this| ((**(this as *const *const IPrintTicketFeature_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn XmlNamespace(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketFeature", "XmlNamespace", 7usize, |this| ((**(this as *const *const IPrintTicketFeature_Vtbl)).XmlNamespace)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn XmlNode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketFeature", "XmlNode", 8usize, |this| ((**(this as *const *const IPrintTicketFeature_Vtbl)).XmlNode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketFeature", "DisplayName", 9usize, |this| ((**(this as *const *const IPrintTicketFeature_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOption(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, xmlnamespace: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketFeature", "GetOption", 10usize, |this| ((**(this as *const *const IPrintTicketFeature_Vtbl)).GetOption)(this, name, xmlnamespace, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Options(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketFeature", "Options", 11usize, |this| ((**(this as *const *const IPrintTicketFeature_Vtbl)).Options)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelectedOption(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketFeature", "GetSelectedOption", 12usize, |this| ((**(this as *const *const IPrintTicketFeature_Vtbl)).GetSelectedOption)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelectedOption(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketFeature", "SetSelectedOption", 13usize, |this| ((**(this as *const *const IPrintTicketFeature_Vtbl)).SetSelectedOption)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectionType(this: *mut core::ffi::c_void, result__: *mut PrintTicketFeatureSelectionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketFeature", "SelectionType", 14usize, |this| ((**(this as *const *const IPrintTicketFeature_Vtbl)).SelectionType)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            XmlNamespace,
+            #[cfg(feature = "Data_Xml_Dom")]
+            XmlNode,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            XmlNode: 0,
+            DisplayName,
+            GetOption,
+            #[cfg(feature = "Foundation_Collections")]
+            Options,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Options: 0,
+            GetSelectedOption,
+            SetSelectedOption,
+            SelectionType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTicketFeature as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IPrintTicketOption_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketOption", "Name", 6usize, |this| ((**(this as *const *const IPrintTicketOption_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn XmlNamespace(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketOption", "XmlNamespace", 7usize, |this| ((**(this as *const *const IPrintTicketOption_Vtbl)).XmlNamespace)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn XmlNode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketOption", "XmlNode", 8usize, |this| ((**(this as *const *const IPrintTicketOption_Vtbl)).XmlNode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketOption", "DisplayName", 9usize, |this| ((**(this as *const *const IPrintTicketOption_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn GetPropertyNode(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, xmlnamespace: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketOption", "GetPropertyNode", 10usize, |this| ((**(this as *const *const IPrintTicketOption_Vtbl)).GetPropertyNode)(this, name, xmlnamespace, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn GetScoredPropertyNode(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, xmlnamespace: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketOption", "GetScoredPropertyNode", 11usize, |this| ((**(this as *const *const IPrintTicketOption_Vtbl)).GetScoredPropertyNode)(this, name, xmlnamespace, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyValue(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, xmlnamespace: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketOption", "GetPropertyValue", 12usize, |this| ((**(this as *const *const IPrintTicketOption_Vtbl)).GetPropertyValue)(this, name, xmlnamespace, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScoredPropertyValue(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, xmlnamespace: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketOption", "GetScoredPropertyValue", 13usize, |this| ((**(this as *const *const IPrintTicketOption_Vtbl)).GetScoredPropertyValue)(this, name, xmlnamespace, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            XmlNamespace,
+            #[cfg(feature = "Data_Xml_Dom")]
+            XmlNode,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            XmlNode: 0,
+            DisplayName,
+            #[cfg(feature = "Data_Xml_Dom")]
+            GetPropertyNode,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            GetPropertyNode: 0,
+            #[cfg(feature = "Data_Xml_Dom")]
+            GetScoredPropertyNode,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            GetScoredPropertyNode: 0,
+            GetPropertyValue,
+            GetScoredPropertyValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTicketOption as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IPrintTicketParameterDefinition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketParameterDefinition", "Name", 6usize, |this| ((**(this as *const *const IPrintTicketParameterDefinition_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn XmlNamespace(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketParameterDefinition", "XmlNamespace", 7usize, |this| ((**(this as *const *const IPrintTicketParameterDefinition_Vtbl)).XmlNamespace)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn XmlNode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketParameterDefinition", "XmlNode", 8usize, |this| ((**(this as *const *const IPrintTicketParameterDefinition_Vtbl)).XmlNode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataType(this: *mut core::ffi::c_void, result__: *mut PrintTicketParameterDataType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketParameterDefinition", "DataType", 9usize, |this| ((**(this as *const *const IPrintTicketParameterDefinition_Vtbl)).DataType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnitType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketParameterDefinition", "UnitType", 10usize, |this| ((**(this as *const *const IPrintTicketParameterDefinition_Vtbl)).UnitType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RangeMin(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketParameterDefinition", "RangeMin", 11usize, |this| ((**(this as *const *const IPrintTicketParameterDefinition_Vtbl)).RangeMin)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RangeMax(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketParameterDefinition", "RangeMax", 12usize, |this| ((**(this as *const *const IPrintTicketParameterDefinition_Vtbl)).RangeMax)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            XmlNamespace,
+            #[cfg(feature = "Data_Xml_Dom")]
+            XmlNode,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            XmlNode: 0,
+            DataType,
+            UnitType,
+            RangeMin,
+            RangeMax,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTicketParameterDefinition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IPrintTicketParameterInitializer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketParameterInitializer", "Name", 6usize, |this| ((**(this as *const *const IPrintTicketParameterInitializer_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn XmlNamespace(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketParameterInitializer", "XmlNamespace", 7usize, |this| ((**(this as *const *const IPrintTicketParameterInitializer_Vtbl)).XmlNamespace)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn XmlNode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketParameterInitializer", "XmlNode", 8usize, |this| ((**(this as *const *const IPrintTicketParameterInitializer_Vtbl)).XmlNode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketParameterInitializer", "SetValue", 9usize, |this| ((**(this as *const *const IPrintTicketParameterInitializer_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketParameterInitializer", "Value", 10usize, |this| ((**(this as *const *const IPrintTicketParameterInitializer_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            XmlNamespace,
+            #[cfg(feature = "Data_Xml_Dom")]
+            XmlNode,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            XmlNode: 0,
+            SetValue,
+            Value,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTicketParameterInitializer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTicketValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut PrintTicketValueType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketValue", "Type", 6usize, |this| ((**(this as *const *const IPrintTicketValue_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValueAsInteger(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketValue", "GetValueAsInteger", 7usize, |this| ((**(this as *const *const IPrintTicketValue_Vtbl)).GetValueAsInteger)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValueAsString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketValue", "GetValueAsString", 8usize, |this| ((**(this as *const *const IPrintTicketValue_Vtbl)).GetValueAsString)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Type, GetValueAsInteger, GetValueAsString }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTicketValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IWorkflowPrintTicket_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "Name", 6usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn XmlNamespace(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "XmlNamespace", 7usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).XmlNamespace)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn XmlNode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "XmlNode", 8usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).XmlNode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "GetCapabilities", 9usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).GetCapabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentBindingFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "DocumentBindingFeature", 10usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).DocumentBindingFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentCollateFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "DocumentCollateFeature", 11usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).DocumentCollateFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentDuplexFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "DocumentDuplexFeature", 12usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).DocumentDuplexFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentHolePunchFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "DocumentHolePunchFeature", 13usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).DocumentHolePunchFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentInputBinFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "DocumentInputBinFeature", 14usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).DocumentInputBinFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentNUpFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "DocumentNUpFeature", 15usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).DocumentNUpFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentStapleFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "DocumentStapleFeature", 16usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).DocumentStapleFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JobPasscodeFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "JobPasscodeFeature", 17usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).JobPasscodeFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageBorderlessFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "PageBorderlessFeature", 18usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).PageBorderlessFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageMediaSizeFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "PageMediaSizeFeature", 19usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).PageMediaSizeFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageMediaTypeFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "PageMediaTypeFeature", 20usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).PageMediaTypeFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageOrientationFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "PageOrientationFeature", 21usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).PageOrientationFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageOutputColorFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "PageOutputColorFeature", 22usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).PageOutputColorFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageOutputQualityFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "PageOutputQualityFeature", 23usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).PageOutputQualityFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageResolutionFeature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "PageResolutionFeature", 24usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).PageResolutionFeature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeature(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, xmlnamespace: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "GetFeature", 25usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).GetFeature)(this, name, xmlnamespace, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyXmlChangedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "NotifyXmlChangedAsync", 26usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).NotifyXmlChangedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "ValidateAsync", 27usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).ValidateAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParameterInitializer(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, xmlnamespace: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "GetParameterInitializer", 28usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).GetParameterInitializer)(this, name, xmlnamespace, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParameterInitializerAsInteger(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, xmlnamespace: core::mem::MaybeUninit<windows_core::HSTRING>, integervalue: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "SetParameterInitializerAsInteger", 29usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).SetParameterInitializerAsInteger)(this, name, xmlnamespace, integervalue, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParameterInitializerAsString(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, xmlnamespace: core::mem::MaybeUninit<windows_core::HSTRING>, stringvalue: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "SetParameterInitializerAsString", 30usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).SetParameterInitializerAsString)(this, name, xmlnamespace, stringvalue, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MergeAndValidateTicket(this: *mut core::ffi::c_void, deltashematicket: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicket", "MergeAndValidateTicket", 31usize, |this| ((**(this as *const *const IWorkflowPrintTicket_Vtbl)).MergeAndValidateTicket)(this, deltashematicket, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            XmlNamespace,
+            #[cfg(feature = "Data_Xml_Dom")]
+            XmlNode,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            XmlNode: 0,
+            GetCapabilities,
+            DocumentBindingFeature,
+            DocumentCollateFeature,
+            DocumentDuplexFeature,
+            DocumentHolePunchFeature,
+            DocumentInputBinFeature,
+            DocumentNUpFeature,
+            DocumentStapleFeature,
+            JobPasscodeFeature,
+            PageBorderlessFeature,
+            PageMediaSizeFeature,
+            PageMediaTypeFeature,
+            PageOrientationFeature,
+            PageOutputColorFeature,
+            PageOutputQualityFeature,
+            PageResolutionFeature,
+            GetFeature,
+            NotifyXmlChangedAsync,
+            ValidateAsync,
+            GetParameterInitializer,
+            SetParameterInitializerAsInteger,
+            SetParameterInitializerAsString,
+            MergeAndValidateTicket,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkflowPrintTicket as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWorkflowPrintTicketValidationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Validated(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicketValidationResult", "Validated", 6usize, |this| ((**(this as *const *const IWorkflowPrintTicketValidationResult_Vtbl)).Validated)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkflowPrintTicketValidationResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IWorkflowPrintTicketValidationResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Validated, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkflowPrintTicketValidationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
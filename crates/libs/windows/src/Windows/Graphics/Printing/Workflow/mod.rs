@@ -2182,6 +2182,7 @@ impl PdlConversionHostBasedProcessingOperations {
 impl windows_core::TypeKind for PdlConversionHostBasedProcessingOperations {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PdlConversionHostBasedProcessingOperations {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PdlConversionHostBasedProcessingOperations").field(&self.0).finish()
@@ -2234,6 +2235,7 @@ impl PrintWorkflowAttributesMergePolicy {
 impl windows_core::TypeKind for PrintWorkflowAttributesMergePolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintWorkflowAttributesMergePolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintWorkflowAttributesMergePolicy").field(&self.0).finish()
@@ -2252,6 +2254,7 @@ impl PrintWorkflowJobAbortReason {
 impl windows_core::TypeKind for PrintWorkflowJobAbortReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintWorkflowJobAbortReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintWorkflowJobAbortReason").field(&self.0).finish()
@@ -2279,6 +2282,7 @@ impl PrintWorkflowJobIssueKind {
 impl windows_core::TypeKind for PrintWorkflowJobIssueKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintWorkflowJobIssueKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintWorkflowJobIssueKind").field(&self.0).finish()
@@ -2299,6 +2303,7 @@ impl PrintWorkflowPdlConversionType {
 impl windows_core::TypeKind for PrintWorkflowPdlConversionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintWorkflowPdlConversionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintWorkflowPdlConversionType").field(&self.0).finish()
@@ -2319,6 +2324,7 @@ impl PrintWorkflowPrinterJobStatus {
 impl windows_core::TypeKind for PrintWorkflowPrinterJobStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintWorkflowPrinterJobStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintWorkflowPrinterJobStatus").field(&self.0).finish()
@@ -2340,6 +2346,7 @@ impl PrintWorkflowSessionStatus {
 impl windows_core::TypeKind for PrintWorkflowSessionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintWorkflowSessionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintWorkflowSessionStatus").field(&self.0).finish()
@@ -2359,6 +2366,7 @@ impl PrintWorkflowSubmittedStatus {
 impl windows_core::TypeKind for PrintWorkflowSubmittedStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintWorkflowSubmittedStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintWorkflowSubmittedStatus").field(&self.0).finish()
@@ -2379,6 +2387,7 @@ impl PrintWorkflowUICompletionStatus {
 impl windows_core::TypeKind for PrintWorkflowUICompletionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintWorkflowUICompletionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintWorkflowUICompletionStatus").field(&self.0).finish()
@@ -2387,3 +2396,5 @@ impl core::fmt::Debug for PrintWorkflowUICompletionStatus {
 impl windows_core::RuntimeType for PrintWorkflowUICompletionStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Graphics.Printing.Workflow.PrintWorkflowUICompletionStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
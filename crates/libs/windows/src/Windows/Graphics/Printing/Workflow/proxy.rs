@@ -0,0 +1,1051 @@
+impl windows_core::imp::ProxyVtable for IPrintWorkflowBackgroundSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetupRequested(this: *mut core::ffi::c_void, setupeventhandler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowBackgroundSession", "SetupRequested", 6usize, |this| ((**(this as *const *const IPrintWorkflowBackgroundSession_Vtbl)).SetupRequested)(this, setupeventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSetupRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowBackgroundSession", "RemoveSetupRequested", 7usize, |this| ((**(this as *const *const IPrintWorkflowBackgroundSession_Vtbl)).RemoveSetupRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Submitted(this: *mut core::ffi::c_void, submittedeventhandler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowBackgroundSession", "Submitted", 8usize, |this| ((**(this as *const *const IPrintWorkflowBackgroundSession_Vtbl)).Submitted)(this, submittedeventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSubmitted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowBackgroundSession", "RemoveSubmitted", 9usize, |this| ((**(this as *const *const IPrintWorkflowBackgroundSession_Vtbl)).RemoveSubmitted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut PrintWorkflowSessionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowBackgroundSession", "Status", 10usize, |this| ((**(this as *const *const IPrintWorkflowBackgroundSession_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowBackgroundSession", "Start", 11usize, |this| ((**(this as *const *const IPrintWorkflowBackgroundSession_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetupRequested,
+            RemoveSetupRequested,
+            Submitted,
+            RemoveSubmitted,
+            Status,
+            Start,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowBackgroundSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Printing_PrintTicket")]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowBackgroundSetupRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Printing_PrintTicket")]
+        unsafe extern "system" fn GetUserPrintTicketAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowBackgroundSetupRequestedEventArgs", "GetUserPrintTicketAsync", 6usize, |this| ((**(this as *const *const IPrintWorkflowBackgroundSetupRequestedEventArgs_Vtbl)).GetUserPrintTicketAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowBackgroundSetupRequestedEventArgs", "Configuration", 7usize, |this| ((**(this as *const *const IPrintWorkflowBackgroundSetupRequestedEventArgs_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequiresUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowBackgroundSetupRequestedEventArgs", "SetRequiresUI", 8usize, |this| ((**(this as *const *const IPrintWorkflowBackgroundSetupRequestedEventArgs_Vtbl)).SetRequiresUI)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowBackgroundSetupRequestedEventArgs", "GetDeferral", 9usize, |this| ((**(this as *const *const IPrintWorkflowBackgroundSetupRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Printing_PrintTicket")]
+            GetUserPrintTicketAsync,
+            #[cfg(not(feature = "Graphics_Printing_PrintTicket"))]
+            GetUserPrintTicketAsync: 0,
+            Configuration,
+            SetRequiresUI,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowBackgroundSetupRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SourceAppDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowConfiguration", "SourceAppDisplayName", 6usize, |this| ((**(this as *const *const IPrintWorkflowConfiguration_Vtbl)).SourceAppDisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JobTitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowConfiguration", "JobTitle", 7usize, |this| ((**(this as *const *const IPrintWorkflowConfiguration_Vtbl)).JobTitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowConfiguration", "SessionId", 8usize, |this| ((**(this as *const *const IPrintWorkflowConfiguration_Vtbl)).SessionId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SourceAppDisplayName, JobTitle, SessionId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowConfiguration2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AbortPrintFlow(this: *mut core::ffi::c_void, reason: PrintWorkflowJobAbortReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowConfiguration2", "AbortPrintFlow", 6usize, |this| ((**(this as *const *const IPrintWorkflowConfiguration2_Vtbl)).AbortPrintFlow)(this, reason), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AbortPrintFlow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowConfiguration2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowForegroundSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetupRequested(this: *mut core::ffi::c_void, setupeventhandler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowForegroundSession", "SetupRequested", 6usize, |this| ((**(this as *const *const IPrintWorkflowForegroundSession_Vtbl)).SetupRequested)(this, setupeventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSetupRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowForegroundSession", "RemoveSetupRequested", 7usize, |this| ((**(this as *const *const IPrintWorkflowForegroundSession_Vtbl)).RemoveSetupRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn XpsDataAvailable(this: *mut core::ffi::c_void, xpsdataavailableeventhandler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowForegroundSession", "XpsDataAvailable", 8usize, |this| ((**(this as *const *const IPrintWorkflowForegroundSession_Vtbl)).XpsDataAvailable)(this, xpsdataavailableeventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveXpsDataAvailable(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowForegroundSession", "RemoveXpsDataAvailable", 9usize, |this| ((**(this as *const *const IPrintWorkflowForegroundSession_Vtbl)).RemoveXpsDataAvailable)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut PrintWorkflowSessionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowForegroundSession", "Status", 10usize, |this| ((**(this as *const *const IPrintWorkflowForegroundSession_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowForegroundSession", "Start", 11usize, |this| ((**(this as *const *const IPrintWorkflowForegroundSession_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetupRequested,
+            RemoveSetupRequested,
+            XpsDataAvailable,
+            RemoveXpsDataAvailable,
+            Status,
+            Start,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowForegroundSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Printing_PrintTicket")]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowForegroundSetupRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Printing_PrintTicket")]
+        unsafe extern "system" fn GetUserPrintTicketAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowForegroundSetupRequestedEventArgs", "GetUserPrintTicketAsync", 6usize, |this| ((**(this as *const *const IPrintWorkflowForegroundSetupRequestedEventArgs_Vtbl)).GetUserPrintTicketAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowForegroundSetupRequestedEventArgs", "Configuration", 7usize, |this| ((**(this as *const *const IPrintWorkflowForegroundSetupRequestedEventArgs_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowForegroundSetupRequestedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IPrintWorkflowForegroundSetupRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Printing_PrintTicket")]
+            GetUserPrintTicketAsync,
+            #[cfg(not(feature = "Graphics_Printing_PrintTicket"))]
+            GetUserPrintTicketAsync: 0,
+            Configuration,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowForegroundSetupRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowJobActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobActivatedEventArgs", "Session", 6usize, |this| ((**(this as *const *const IPrintWorkflowJobActivatedEventArgs_Vtbl)).Session)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Session }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowJobActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowJobBackgroundSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut PrintWorkflowSessionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobBackgroundSession", "Status", 6usize, |this| ((**(this as *const *const IPrintWorkflowJobBackgroundSession_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JobStarting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobBackgroundSession", "JobStarting", 7usize, |this| ((**(this as *const *const IPrintWorkflowJobBackgroundSession_Vtbl)).JobStarting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveJobStarting(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobBackgroundSession", "RemoveJobStarting", 8usize, |this| ((**(this as *const *const IPrintWorkflowJobBackgroundSession_Vtbl)).RemoveJobStarting)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PdlModificationRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobBackgroundSession", "PdlModificationRequested", 9usize, |this| ((**(this as *const *const IPrintWorkflowJobBackgroundSession_Vtbl)).PdlModificationRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePdlModificationRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobBackgroundSession", "RemovePdlModificationRequested", 10usize, |this| ((**(this as *const *const IPrintWorkflowJobBackgroundSession_Vtbl)).RemovePdlModificationRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobBackgroundSession", "Start", 11usize, |this| ((**(this as *const *const IPrintWorkflowJobBackgroundSession_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            JobStarting,
+            RemoveJobStarting,
+            PdlModificationRequested,
+            RemovePdlModificationRequested,
+            Start,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowJobBackgroundSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowJobBackgroundSession2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn JobIssueDetected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobBackgroundSession2", "JobIssueDetected", 6usize, |this| ((**(this as *const *const IPrintWorkflowJobBackgroundSession2_Vtbl)).JobIssueDetected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveJobIssueDetected(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobBackgroundSession2", "RemoveJobIssueDetected", 7usize, |this| ((**(this as *const *const IPrintWorkflowJobBackgroundSession2_Vtbl)).RemoveJobIssueDetected)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, JobIssueDetected, RemoveJobIssueDetected }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowJobBackgroundSession2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowJobIssueDetectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn JobIssueKind(this: *mut core::ffi::c_void, result__: *mut PrintWorkflowJobIssueKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobIssueDetectedEventArgs", "JobIssueKind", 6usize, |this| ((**(this as *const *const IPrintWorkflowJobIssueDetectedEventArgs_Vtbl)).JobIssueKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobIssueDetectedEventArgs", "ExtendedError", 7usize, |this| ((**(this as *const *const IPrintWorkflowJobIssueDetectedEventArgs_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SkipSystemErrorToast(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobIssueDetectedEventArgs", "SkipSystemErrorToast", 8usize, |this| ((**(this as *const *const IPrintWorkflowJobIssueDetectedEventArgs_Vtbl)).SkipSystemErrorToast)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSkipSystemErrorToast(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobIssueDetectedEventArgs", "SetSkipSystemErrorToast", 9usize, |this| ((**(this as *const *const IPrintWorkflowJobIssueDetectedEventArgs_Vtbl)).SetSkipSystemErrorToast)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrinterJob(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobIssueDetectedEventArgs", "PrinterJob", 10usize, |this| ((**(this as *const *const IPrintWorkflowJobIssueDetectedEventArgs_Vtbl)).PrinterJob)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobIssueDetectedEventArgs", "Configuration", 11usize, |this| ((**(this as *const *const IPrintWorkflowJobIssueDetectedEventArgs_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UILauncher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobIssueDetectedEventArgs", "UILauncher", 12usize, |this| ((**(this as *const *const IPrintWorkflowJobIssueDetectedEventArgs_Vtbl)).UILauncher)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobIssueDetectedEventArgs", "GetDeferral", 13usize, |this| ((**(this as *const *const IPrintWorkflowJobIssueDetectedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            JobIssueKind,
+            ExtendedError,
+            SkipSystemErrorToast,
+            SetSkipSystemErrorToast,
+            PrinterJob,
+            Configuration,
+            UILauncher,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowJobIssueDetectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowJobNotificationEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobNotificationEventArgs", "Configuration", 6usize, |this| ((**(this as *const *const IPrintWorkflowJobNotificationEventArgs_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrinterJob(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobNotificationEventArgs", "PrinterJob", 7usize, |this| ((**(this as *const *const IPrintWorkflowJobNotificationEventArgs_Vtbl)).PrinterJob)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobNotificationEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IPrintWorkflowJobNotificationEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Configuration, PrinterJob, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowJobNotificationEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Printers")]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowJobStartingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobStartingEventArgs", "Configuration", 6usize, |this| ((**(this as *const *const IPrintWorkflowJobStartingEventArgs_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Printers")]
+        unsafe extern "system" fn Printer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobStartingEventArgs", "Printer", 7usize, |this| ((**(this as *const *const IPrintWorkflowJobStartingEventArgs_Vtbl)).Printer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSkipSystemRendering(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobStartingEventArgs", "SetSkipSystemRendering", 8usize, |this| ((**(this as *const *const IPrintWorkflowJobStartingEventArgs_Vtbl)).SetSkipSystemRendering)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobStartingEventArgs", "GetDeferral", 9usize, |this| ((**(this as *const *const IPrintWorkflowJobStartingEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Configuration,
+            #[cfg(feature = "Devices_Printers")]
+            Printer,
+            #[cfg(not(feature = "Devices_Printers"))]
+            Printer: 0,
+            SetSkipSystemRendering,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowJobStartingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowJobStartingEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsIppCompressionEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobStartingEventArgs2", "IsIppCompressionEnabled", 6usize, |this| ((**(this as *const *const IPrintWorkflowJobStartingEventArgs2_Vtbl)).IsIppCompressionEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableIppCompressionForJob(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobStartingEventArgs2", "DisableIppCompressionForJob", 7usize, |this| ((**(this as *const *const IPrintWorkflowJobStartingEventArgs2_Vtbl)).DisableIppCompressionForJob)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SkipSystemFaxUI(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobStartingEventArgs2", "SkipSystemFaxUI", 8usize, |this| ((**(this as *const *const IPrintWorkflowJobStartingEventArgs2_Vtbl)).SkipSystemFaxUI)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSkipSystemFaxUI(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobStartingEventArgs2", "SetSkipSystemFaxUI", 9usize, |this| ((**(this as *const *const IPrintWorkflowJobStartingEventArgs2_Vtbl)).SetSkipSystemFaxUI)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsIppCompressionEnabled,
+            DisableIppCompressionForJob,
+            SkipSystemFaxUI,
+            SetSkipSystemFaxUI,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowJobStartingEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowJobTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrintWorkflowJobSession(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobTriggerDetails", "PrintWorkflowJobSession", 6usize, |this| ((**(this as *const *const IPrintWorkflowJobTriggerDetails_Vtbl)).PrintWorkflowJobSession)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrintWorkflowJobSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowJobTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowJobUISession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut PrintWorkflowSessionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobUISession", "Status", 6usize, |this| ((**(this as *const *const IPrintWorkflowJobUISession_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PdlDataAvailable(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobUISession", "PdlDataAvailable", 7usize, |this| ((**(this as *const *const IPrintWorkflowJobUISession_Vtbl)).PdlDataAvailable)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePdlDataAvailable(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobUISession", "RemovePdlDataAvailable", 8usize, |this| ((**(this as *const *const IPrintWorkflowJobUISession_Vtbl)).RemovePdlDataAvailable)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn JobNotification(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobUISession", "JobNotification", 9usize, |this| ((**(this as *const *const IPrintWorkflowJobUISession_Vtbl)).JobNotification)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveJobNotification(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobUISession", "RemoveJobNotification", 10usize, |this| ((**(this as *const *const IPrintWorkflowJobUISession_Vtbl)).RemoveJobNotification)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobUISession", "Start", 11usize, |this| ((**(this as *const *const IPrintWorkflowJobUISession_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            PdlDataAvailable,
+            RemovePdlDataAvailable,
+            JobNotification,
+            RemoveJobNotification,
+            Start,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowJobUISession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowJobUISession2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VirtualPrinterUIDataAvailable(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobUISession2", "VirtualPrinterUIDataAvailable", 6usize, |this| ((**(this as *const *const IPrintWorkflowJobUISession2_Vtbl)).VirtualPrinterUIDataAvailable)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVirtualPrinterUIDataAvailable(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowJobUISession2", "RemoveVirtualPrinterUIDataAvailable", 7usize, |this| ((**(this as *const *const IPrintWorkflowJobUISession2_Vtbl)).RemoveVirtualPrinterUIDataAvailable)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            VirtualPrinterUIDataAvailable,
+            RemoveVirtualPrinterUIDataAvailable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowJobUISession2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowObjectModelSourceFileContent_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowObjectModelSourceFileContent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowObjectModelSourceFileContentFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, xpsstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowObjectModelSourceFileContentFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IPrintWorkflowObjectModelSourceFileContentFactory_Vtbl)).CreateInstance)(this, xpsstream, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateInstance,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateInstance: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowObjectModelSourceFileContentFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowObjectModelTargetPackage_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowObjectModelTargetPackage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Graphics_Printing_PrintTicket", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowPdlConverter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Graphics_Printing_PrintTicket", feature = "Storage_Streams"))]
+        unsafe extern "system" fn ConvertPdlAsync(this: *mut core::ffi::c_void, printticket: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlConverter", "ConvertPdlAsync", 6usize, |this| ((**(this as *const *const IPrintWorkflowPdlConverter_Vtbl)).ConvertPdlAsync)(this, printticket, inputstream, outputstream, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Graphics_Printing_PrintTicket", feature = "Storage_Streams"))]
+            ConvertPdlAsync,
+            #[cfg(not(all(feature = "Graphics_Printing_PrintTicket", feature = "Storage_Streams")))]
+            ConvertPdlAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowPdlConverter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Graphics_Printing_PrintTicket", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowPdlConverter2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Graphics_Printing_PrintTicket", feature = "Storage_Streams"))]
+        unsafe extern "system" fn ConvertPdlAsync(this: *mut core::ffi::c_void, printticket: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, hostbasedprocessingoperations: PdlConversionHostBasedProcessingOperations, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlConverter2", "ConvertPdlAsync", 6usize, |this| ((**(this as *const *const IPrintWorkflowPdlConverter2_Vtbl)).ConvertPdlAsync)(this, printticket, inputstream, outputstream, hostbasedprocessingoperations, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Graphics_Printing_PrintTicket", feature = "Storage_Streams"))]
+            ConvertPdlAsync,
+            #[cfg(not(all(feature = "Graphics_Printing_PrintTicket", feature = "Storage_Streams")))]
+            ConvertPdlAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowPdlConverter2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowPdlDataAvailableEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlDataAvailableEventArgs", "Configuration", 6usize, |this| ((**(this as *const *const IPrintWorkflowPdlDataAvailableEventArgs_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrinterJob(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlDataAvailableEventArgs", "PrinterJob", 7usize, |this| ((**(this as *const *const IPrintWorkflowPdlDataAvailableEventArgs_Vtbl)).PrinterJob)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceContent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlDataAvailableEventArgs", "SourceContent", 8usize, |this| ((**(this as *const *const IPrintWorkflowPdlDataAvailableEventArgs_Vtbl)).SourceContent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlDataAvailableEventArgs", "GetDeferral", 9usize, |this| ((**(this as *const *const IPrintWorkflowPdlDataAvailableEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Configuration, PrinterJob, SourceContent, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowPdlDataAvailableEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Printers", feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowPdlModificationRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlModificationRequestedEventArgs", "Configuration", 6usize, |this| ((**(this as *const *const IPrintWorkflowPdlModificationRequestedEventArgs_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrinterJob(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlModificationRequestedEventArgs", "PrinterJob", 7usize, |this| ((**(this as *const *const IPrintWorkflowPdlModificationRequestedEventArgs_Vtbl)).PrinterJob)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceContent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlModificationRequestedEventArgs", "SourceContent", 8usize, |this| ((**(this as *const *const IPrintWorkflowPdlModificationRequestedEventArgs_Vtbl)).SourceContent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UILauncher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlModificationRequestedEventArgs", "UILauncher", 9usize, |this| ((**(this as *const *const IPrintWorkflowPdlModificationRequestedEventArgs_Vtbl)).UILauncher)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateJobOnPrinter(this: *mut core::ffi::c_void, targetcontenttype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlModificationRequestedEventArgs", "CreateJobOnPrinter", 10usize, |this| ((**(this as *const *const IPrintWorkflowPdlModificationRequestedEventArgs_Vtbl)).CreateJobOnPrinter)(this, targetcontenttype, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Printers", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn CreateJobOnPrinterWithAttributes(this: *mut core::ffi::c_void, jobattributes: *mut core::ffi::c_void, targetcontenttype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlModificationRequestedEventArgs", "CreateJobOnPrinterWithAttributes", 11usize, |this| ((**(this as *const *const IPrintWorkflowPdlModificationRequestedEventArgs_Vtbl)).CreateJobOnPrinterWithAttributes)(this, jobattributes, targetcontenttype, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateJobOnPrinterWithAttributesBuffer(this: *mut core::ffi::c_void, jobattributesbuffer: *mut core::ffi::c_void, targetcontenttype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlModificationRequestedEventArgs", "CreateJobOnPrinterWithAttributesBuffer", 12usize, |this| ((**(this as *const *const IPrintWorkflowPdlModificationRequestedEventArgs_Vtbl)).CreateJobOnPrinterWithAttributesBuffer)(this, jobattributesbuffer, targetcontenttype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPdlConverter(this: *mut core::ffi::c_void, conversiontype: PrintWorkflowPdlConversionType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlModificationRequestedEventArgs", "GetPdlConverter", 13usize, |this| ((**(this as *const *const IPrintWorkflowPdlModificationRequestedEventArgs_Vtbl)).GetPdlConverter)(this, conversiontype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlModificationRequestedEventArgs", "GetDeferral", 14usize, |this| ((**(this as *const *const IPrintWorkflowPdlModificationRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Configuration,
+            PrinterJob,
+            SourceContent,
+            UILauncher,
+            CreateJobOnPrinter,
+            #[cfg(all(feature = "Devices_Printers", feature = "Foundation_Collections"))]
+            CreateJobOnPrinterWithAttributes,
+            #[cfg(not(all(feature = "Devices_Printers", feature = "Foundation_Collections")))]
+            CreateJobOnPrinterWithAttributes: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateJobOnPrinterWithAttributesBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateJobOnPrinterWithAttributesBuffer: 0,
+            GetPdlConverter,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowPdlModificationRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Printers", feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowPdlModificationRequestedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Devices_Printers", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn CreateJobOnPrinterWithAttributes(this: *mut core::ffi::c_void, jobattributes: *mut core::ffi::c_void, targetcontenttype: core::mem::MaybeUninit<windows_core::HSTRING>, operationattributes: *mut core::ffi::c_void, jobattributesmergepolicy: PrintWorkflowAttributesMergePolicy, operationattributesmergepolicy: PrintWorkflowAttributesMergePolicy, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlModificationRequestedEventArgs2", "CreateJobOnPrinterWithAttributes", 6usize, |this| ((**(this as *const *const IPrintWorkflowPdlModificationRequestedEventArgs2_Vtbl)).CreateJobOnPrinterWithAttributes)(this, jobattributes, targetcontenttype, operationattributes, jobattributesmergepolicy, operationattributesmergepolicy, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateJobOnPrinterWithAttributesBuffer(this: *mut core::ffi::c_void, jobattributesbuffer: *mut core::ffi::c_void, targetcontenttype: core::mem::MaybeUninit<windows_core::HSTRING>, operationattributesbuffer: *mut core::ffi::c_void, jobattributesmergepolicy: PrintWorkflowAttributesMergePolicy, operationattributesmergepolicy: PrintWorkflowAttributesMergePolicy, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlModificationRequestedEventArgs2", "CreateJobOnPrinterWithAttributesBuffer", 7usize, |this| ((**(this as *const *const IPrintWorkflowPdlModificationRequestedEventArgs2_Vtbl)).CreateJobOnPrinterWithAttributesBuffer)(this, jobattributesbuffer, targetcontenttype, operationattributesbuffer, jobattributesmergepolicy, operationattributesmergepolicy, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Devices_Printers", feature = "Foundation_Collections"))]
+            CreateJobOnPrinterWithAttributes,
+            #[cfg(not(all(feature = "Devices_Printers", feature = "Foundation_Collections")))]
+            CreateJobOnPrinterWithAttributes: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateJobOnPrinterWithAttributesBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateJobOnPrinterWithAttributesBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowPdlModificationRequestedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowPdlSourceContent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContentType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlSourceContent", "ContentType", 6usize, |this| ((**(this as *const *const IPrintWorkflowPdlSourceContent_Vtbl)).ContentType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetInputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlSourceContent", "GetInputStream", 7usize, |this| ((**(this as *const *const IPrintWorkflowPdlSourceContent_Vtbl)).GetInputStream)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn GetContentFileAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlSourceContent", "GetContentFileAsync", 8usize, |this| ((**(this as *const *const IPrintWorkflowPdlSourceContent_Vtbl)).GetContentFileAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ContentType,
+            #[cfg(feature = "Storage_Streams")]
+            GetInputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetInputStream: 0,
+            #[cfg(feature = "Storage")]
+            GetContentFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            GetContentFileAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowPdlSourceContent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowPdlTargetStream_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetOutputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlTargetStream", "GetOutputStream", 6usize, |this| ((**(this as *const *const IPrintWorkflowPdlTargetStream_Vtbl)).GetOutputStream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompleteStreamSubmission(this: *mut core::ffi::c_void, status: PrintWorkflowSubmittedStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPdlTargetStream", "CompleteStreamSubmission", 7usize, |this| ((**(this as *const *const IPrintWorkflowPdlTargetStream_Vtbl)).CompleteStreamSubmission)(this, status), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            GetOutputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetOutputStream: 0,
+            CompleteStreamSubmission,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowPdlTargetStream as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Printers", feature = "Foundation_Collections", feature = "Graphics_Printing_PrintTicket", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowPrinterJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn JobId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPrinterJob", "JobId", 6usize, |this| ((**(this as *const *const IPrintWorkflowPrinterJob_Vtbl)).JobId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Printers")]
+        unsafe extern "system" fn Printer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPrinterJob", "Printer", 7usize, |this| ((**(this as *const *const IPrintWorkflowPrinterJob_Vtbl)).Printer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetJobStatus(this: *mut core::ffi::c_void, result__: *mut PrintWorkflowPrinterJobStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPrinterJob", "GetJobStatus", 8usize, |this| ((**(this as *const *const IPrintWorkflowPrinterJob_Vtbl)).GetJobStatus)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Printing_PrintTicket")]
+        unsafe extern "system" fn GetJobPrintTicket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPrinterJob", "GetJobPrintTicket", 9usize, |this| ((**(this as *const *const IPrintWorkflowPrinterJob_Vtbl)).GetJobPrintTicket)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GetJobAttributesAsBuffer(this: *mut core::ffi::c_void, attributenames: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPrinterJob", "GetJobAttributesAsBuffer", 10usize, |this| ((**(this as *const *const IPrintWorkflowPrinterJob_Vtbl)).GetJobAttributesAsBuffer)(this, attributenames, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Printers", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetJobAttributes(this: *mut core::ffi::c_void, attributenames: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPrinterJob", "GetJobAttributes", 11usize, |this| ((**(this as *const *const IPrintWorkflowPrinterJob_Vtbl)).GetJobAttributes)(this, attributenames, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Printers", feature = "Storage_Streams"))]
+        unsafe extern "system" fn SetJobAttributesFromBuffer(this: *mut core::ffi::c_void, jobattributesbuffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPrinterJob", "SetJobAttributesFromBuffer", 12usize, |this| ((**(this as *const *const IPrintWorkflowPrinterJob_Vtbl)).SetJobAttributesFromBuffer)(this, jobattributesbuffer, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Printers", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn SetJobAttributes(this: *mut core::ffi::c_void, jobattributes: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPrinterJob", "SetJobAttributes", 13usize, |this| ((**(this as *const *const IPrintWorkflowPrinterJob_Vtbl)).SetJobAttributes)(this, jobattributes, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            JobId,
+            #[cfg(feature = "Devices_Printers")]
+            Printer,
+            #[cfg(not(feature = "Devices_Printers"))]
+            Printer: 0,
+            GetJobStatus,
+            #[cfg(feature = "Graphics_Printing_PrintTicket")]
+            GetJobPrintTicket,
+            #[cfg(not(feature = "Graphics_Printing_PrintTicket"))]
+            GetJobPrintTicket: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            GetJobAttributesAsBuffer,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            GetJobAttributesAsBuffer: 0,
+            #[cfg(all(feature = "Devices_Printers", feature = "Foundation_Collections"))]
+            GetJobAttributes,
+            #[cfg(not(all(feature = "Devices_Printers", feature = "Foundation_Collections")))]
+            GetJobAttributes: 0,
+            #[cfg(all(feature = "Devices_Printers", feature = "Storage_Streams"))]
+            SetJobAttributesFromBuffer,
+            #[cfg(not(all(feature = "Devices_Printers", feature = "Storage_Streams")))]
+            SetJobAttributesFromBuffer: 0,
+            #[cfg(all(feature = "Devices_Printers", feature = "Foundation_Collections"))]
+            SetJobAttributes,
+            #[cfg(not(all(feature = "Devices_Printers", feature = "Foundation_Collections")))]
+            SetJobAttributes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowPrinterJob as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Printers", feature = "Foundation_Collections", feature = "Graphics_Printing_PrintTicket"))]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowPrinterJob2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Devices_Printers", feature = "Foundation_Collections", feature = "Graphics_Printing_PrintTicket"))]
+        unsafe extern "system" fn ConvertPrintTicketToJobAttributes(this: *mut core::ffi::c_void, printticket: *mut core::ffi::c_void, targetpdlformat: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowPrinterJob2", "ConvertPrintTicketToJobAttributes", 6usize, |this| ((**(this as *const *const IPrintWorkflowPrinterJob2_Vtbl)).ConvertPrintTicketToJobAttributes)(this, printticket, targetpdlformat, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Devices_Printers", feature = "Foundation_Collections", feature = "Graphics_Printing_PrintTicket"))]
+            ConvertPrintTicketToJobAttributes,
+            #[cfg(not(all(feature = "Devices_Printers", feature = "Foundation_Collections", feature = "Graphics_Printing_PrintTicket")))]
+            ConvertPrintTicketToJobAttributes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowPrinterJob2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Printing_PrintTicket")]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowSourceContent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Printing_PrintTicket")]
+        unsafe extern "system" fn GetJobPrintTicketAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowSourceContent", "GetJobPrintTicketAsync", 6usize, |this| ((**(this as *const *const IPrintWorkflowSourceContent_Vtbl)).GetJobPrintTicketAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceSpoolDataAsStreamContent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowSourceContent", "GetSourceSpoolDataAsStreamContent", 7usize, |this| ((**(this as *const *const IPrintWorkflowSourceContent_Vtbl)).GetSourceSpoolDataAsStreamContent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceSpoolDataAsXpsObjectModel(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowSourceContent", "GetSourceSpoolDataAsXpsObjectModel", 8usize, |this| ((**(this as *const *const IPrintWorkflowSourceContent_Vtbl)).GetSourceSpoolDataAsXpsObjectModel)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Printing_PrintTicket")]
+            GetJobPrintTicketAsync,
+            #[cfg(not(feature = "Graphics_Printing_PrintTicket"))]
+            GetJobPrintTicketAsync: 0,
+            GetSourceSpoolDataAsStreamContent,
+            GetSourceSpoolDataAsXpsObjectModel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowSourceContent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowSpoolStreamContent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetInputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowSpoolStreamContent", "GetInputStream", 6usize, |this| ((**(this as *const *const IPrintWorkflowSpoolStreamContent_Vtbl)).GetInputStream)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            GetInputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetInputStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowSpoolStreamContent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowStreamTarget_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetOutputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowStreamTarget", "GetOutputStream", 6usize, |this| ((**(this as *const *const IPrintWorkflowStreamTarget_Vtbl)).GetOutputStream)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            GetOutputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetOutputStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowStreamTarget as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Printing_PrintTicket")]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowSubmittedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Operation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowSubmittedEventArgs", "Operation", 6usize, |this| ((**(this as *const *const IPrintWorkflowSubmittedEventArgs_Vtbl)).Operation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Printing_PrintTicket")]
+        unsafe extern "system" fn GetTarget(this: *mut core::ffi::c_void, jobprintticket: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowSubmittedEventArgs", "GetTarget", 7usize, |this| ((**(this as *const *const IPrintWorkflowSubmittedEventArgs_Vtbl)).GetTarget)(this, jobprintticket, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowSubmittedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IPrintWorkflowSubmittedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Operation,
+            #[cfg(feature = "Graphics_Printing_PrintTicket")]
+            GetTarget,
+            #[cfg(not(feature = "Graphics_Printing_PrintTicket"))]
+            GetTarget: 0,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowSubmittedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowSubmittedOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void, status: PrintWorkflowSubmittedStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowSubmittedOperation", "Complete", 6usize, |this| ((**(this as *const *const IPrintWorkflowSubmittedOperation_Vtbl)).Complete)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowSubmittedOperation", "Configuration", 7usize, |this| ((**(this as *const *const IPrintWorkflowSubmittedOperation_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn XpsContent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowSubmittedOperation", "XpsContent", 8usize, |this| ((**(this as *const *const IPrintWorkflowSubmittedOperation_Vtbl)).XpsContent)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete, Configuration, XpsContent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowSubmittedOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TargetAsStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowTarget", "TargetAsStream", 6usize, |this| ((**(this as *const *const IPrintWorkflowTarget_Vtbl)).TargetAsStream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetAsXpsObjectModelPackage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowTarget", "TargetAsXpsObjectModelPackage", 7usize, |this| ((**(this as *const *const IPrintWorkflowTarget_Vtbl)).TargetAsXpsObjectModelPackage)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TargetAsStream, TargetAsXpsObjectModelPackage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowTarget as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrintWorkflowSession(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowTriggerDetails", "PrintWorkflowSession", 6usize, |this| ((**(this as *const *const IPrintWorkflowTriggerDetails_Vtbl)).PrintWorkflowSession)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrintWorkflowSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Activation", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowUIActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrintWorkflowSession(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowUIActivatedEventArgs", "PrintWorkflowSession", 6usize, |this| ((**(this as *const *const IPrintWorkflowUIActivatedEventArgs_Vtbl)).PrintWorkflowSession)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrintWorkflowSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowUIActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowUILauncher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsUILaunchEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowUILauncher", "IsUILaunchEnabled", 6usize, |this| ((**(this as *const *const IPrintWorkflowUILauncher_Vtbl)).IsUILaunchEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchAndCompleteUIAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowUILauncher", "LaunchAndCompleteUIAsync", 7usize, |this| ((**(this as *const *const IPrintWorkflowUILauncher_Vtbl)).LaunchAndCompleteUIAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsUILaunchEnabled, LaunchAndCompleteUIAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowUILauncher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Graphics_Printing_PrintTicket", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowVirtualPrinterDataAvailableEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterDataAvailableEventArgs", "Configuration", 6usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterDataAvailableEventArgs_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceContent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterDataAvailableEventArgs", "SourceContent", 7usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterDataAvailableEventArgs_Vtbl)).SourceContent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UILauncher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterDataAvailableEventArgs", "UILauncher", 8usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterDataAvailableEventArgs_Vtbl)).UILauncher)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Printing_PrintTicket")]
+        unsafe extern "system" fn GetJobPrintTicket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterDataAvailableEventArgs", "GetJobPrintTicket", 9usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterDataAvailableEventArgs_Vtbl)).GetJobPrintTicket)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPdlConverter(this: *mut core::ffi::c_void, conversiontype: PrintWorkflowPdlConversionType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterDataAvailableEventArgs", "GetPdlConverter", 10usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterDataAvailableEventArgs_Vtbl)).GetPdlConverter)(this, conversiontype, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn GetTargetFileAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterDataAvailableEventArgs", "GetTargetFileAsync", 11usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterDataAvailableEventArgs_Vtbl)).GetTargetFileAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompleteJob(this: *mut core::ffi::c_void, status: PrintWorkflowSubmittedStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterDataAvailableEventArgs", "CompleteJob", 12usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterDataAvailableEventArgs_Vtbl)).CompleteJob)(this, status), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Configuration,
+            SourceContent,
+            UILauncher,
+            #[cfg(feature = "Graphics_Printing_PrintTicket")]
+            GetJobPrintTicket,
+            #[cfg(not(feature = "Graphics_Printing_PrintTicket"))]
+            GetJobPrintTicket: 0,
+            GetPdlConverter,
+            #[cfg(feature = "Storage")]
+            GetTargetFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            GetTargetFileAsync: 0,
+            CompleteJob,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowVirtualPrinterDataAvailableEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Printers")]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowVirtualPrinterSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut PrintWorkflowSessionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterSession", "Status", 6usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterSession_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Printers")]
+        unsafe extern "system" fn Printer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterSession", "Printer", 7usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterSession_Vtbl)).Printer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VirtualPrinterDataAvailable(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterSession", "VirtualPrinterDataAvailable", 8usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterSession_Vtbl)).VirtualPrinterDataAvailable)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVirtualPrinterDataAvailable(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterSession", "RemoveVirtualPrinterDataAvailable", 9usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterSession_Vtbl)).RemoveVirtualPrinterDataAvailable)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterSession", "Start", 10usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterSession_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Devices_Printers")]
+            Printer,
+            #[cfg(not(feature = "Devices_Printers"))]
+            Printer: 0,
+            VirtualPrinterDataAvailable,
+            RemoveVirtualPrinterDataAvailable,
+            Start,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowVirtualPrinterSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowVirtualPrinterTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VirtualPrinterSession(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterTriggerDetails", "VirtualPrinterSession", 6usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterTriggerDetails_Vtbl)).VirtualPrinterSession)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, VirtualPrinterSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowVirtualPrinterTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Printers", feature = "Graphics_Printing_PrintTicket"))]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowVirtualPrinterUIEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterUIEventArgs", "Configuration", 6usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterUIEventArgs_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Printers")]
+        unsafe extern "system" fn Printer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterUIEventArgs", "Printer", 7usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterUIEventArgs_Vtbl)).Printer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceContent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterUIEventArgs", "SourceContent", 8usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterUIEventArgs_Vtbl)).SourceContent)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Printing_PrintTicket")]
+        unsafe extern "system" fn GetJobPrintTicket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterUIEventArgs", "GetJobPrintTicket", 9usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterUIEventArgs_Vtbl)).GetJobPrintTicket)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowVirtualPrinterUIEventArgs", "GetDeferral", 10usize, |this| ((**(this as *const *const IPrintWorkflowVirtualPrinterUIEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Configuration,
+            #[cfg(feature = "Devices_Printers")]
+            Printer,
+            #[cfg(not(feature = "Devices_Printers"))]
+            Printer: 0,
+            SourceContent,
+            #[cfg(feature = "Graphics_Printing_PrintTicket")]
+            GetJobPrintTicket,
+            #[cfg(not(feature = "Graphics_Printing_PrintTicket"))]
+            GetJobPrintTicket: 0,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowVirtualPrinterUIEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWorkflowXpsDataAvailableEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Operation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowXpsDataAvailableEventArgs", "Operation", 6usize, |this| ((**(this as *const *const IPrintWorkflowXpsDataAvailableEventArgs_Vtbl)).Operation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowXpsDataAvailableEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IPrintWorkflowXpsDataAvailableEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Operation, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowXpsDataAvailableEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
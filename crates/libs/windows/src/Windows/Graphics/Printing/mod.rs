@@ -1421,6 +1421,7 @@ impl PrintBinding {
 impl windows_core::TypeKind for PrintBinding {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintBinding {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintBinding").field(&self.0).finish()
@@ -1442,6 +1443,7 @@ impl PrintBordering {
 impl windows_core::TypeKind for PrintBordering {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintBordering {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintBordering").field(&self.0).finish()
@@ -1463,6 +1465,7 @@ impl PrintCollation {
 impl windows_core::TypeKind for PrintCollation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintCollation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintCollation").field(&self.0).finish()
@@ -1486,6 +1489,7 @@ impl PrintColorMode {
 impl windows_core::TypeKind for PrintColorMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintColorMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintColorMode").field(&self.0).finish()
@@ -1508,6 +1512,7 @@ impl PrintDuplex {
 impl windows_core::TypeKind for PrintDuplex {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintDuplex {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintDuplex").field(&self.0).finish()
@@ -1532,6 +1537,7 @@ impl PrintHolePunch {
 impl windows_core::TypeKind for PrintHolePunch {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintHolePunch {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintHolePunch").field(&self.0).finish()
@@ -1721,6 +1727,7 @@ impl PrintMediaSize {
 impl windows_core::TypeKind for PrintMediaSize {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintMediaSize {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintMediaSize").field(&self.0).finish()
@@ -1769,6 +1776,7 @@ impl PrintMediaType {
 impl windows_core::TypeKind for PrintMediaType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintMediaType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintMediaType").field(&self.0).finish()
@@ -1792,6 +1800,7 @@ impl PrintOrientation {
 impl windows_core::TypeKind for PrintOrientation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintOrientation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintOrientation").field(&self.0).finish()
@@ -1818,6 +1827,7 @@ impl PrintQuality {
 impl windows_core::TypeKind for PrintQuality {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintQuality {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintQuality").field(&self.0).finish()
@@ -1847,6 +1857,7 @@ impl PrintStaple {
 impl windows_core::TypeKind for PrintStaple {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintStaple {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintStaple").field(&self.0).finish()
@@ -1867,6 +1878,7 @@ impl PrintTaskCompletion {
 impl windows_core::TypeKind for PrintTaskCompletion {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintTaskCompletion {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintTaskCompletion").field(&self.0).finish()
@@ -1876,7 +1888,8 @@ impl windows_core::RuntimeType for PrintTaskCompletion {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Graphics.Printing.PrintTaskCompletion;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PrintPageDescription {
     pub PageSize: super::super::Foundation::Size,
     pub ImageableRect: super::super::Foundation::Rect,
@@ -1956,3 +1969,5 @@ pub struct PrintTaskSourceRequestedHandler_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,641 @@
+impl windows_core::imp::ProxyVtable for IPrintDocumentSource_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintDocumentSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrintTaskRequested(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintManager", "PrintTaskRequested", 6usize, |this| ((**(this as *const *const IPrintManager_Vtbl)).PrintTaskRequested)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePrintTaskRequested(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintManager", "RemovePrintTaskRequested", 7usize, |this| ((**(this as *const *const IPrintManager_Vtbl)).RemovePrintTaskRequested)(this, eventcookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrintTaskRequested, RemovePrintTaskRequested }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintManagerStatic_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintManagerStatic", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IPrintManagerStatic_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowPrintUIAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintManagerStatic", "ShowPrintUIAsync", 7usize, |this| ((**(this as *const *const IPrintManagerStatic_Vtbl)).ShowPrintUIAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView, ShowPrintUIAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintManagerStatic as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintManagerStatic2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintManagerStatic2", "IsSupported", 6usize, |this| ((**(this as *const *const IPrintManagerStatic2_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintManagerStatic2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintPageInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMediaSize(this: *mut core::ffi::c_void, value: PrintMediaSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageInfo", "SetMediaSize", 6usize, |this| ((**(this as *const *const IPrintPageInfo_Vtbl)).SetMediaSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaSize(this: *mut core::ffi::c_void, result__: *mut PrintMediaSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageInfo", "MediaSize", 7usize, |this| ((**(this as *const *const IPrintPageInfo_Vtbl)).MediaSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPageSize(this: *mut core::ffi::c_void, value: super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageInfo", "SetPageSize", 8usize, |this| ((**(this as *const *const IPrintPageInfo_Vtbl)).SetPageSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageInfo", "PageSize", 9usize, |this| ((**(this as *const *const IPrintPageInfo_Vtbl)).PageSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDpiX(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageInfo", "SetDpiX", 10usize, |this| ((**(this as *const *const IPrintPageInfo_Vtbl)).SetDpiX)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DpiX(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageInfo", "DpiX", 11usize, |this| ((**(this as *const *const IPrintPageInfo_Vtbl)).DpiX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDpiY(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageInfo", "SetDpiY", 12usize, |this| ((**(this as *const *const IPrintPageInfo_Vtbl)).SetDpiY)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DpiY(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageInfo", "DpiY", 13usize, |this| ((**(this as *const *const IPrintPageInfo_Vtbl)).DpiY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOrientation(this: *mut core::ffi::c_void, value: PrintOrientation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageInfo", "SetOrientation", 14usize, |this| ((**(this as *const *const IPrintPageInfo_Vtbl)).SetOrientation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut PrintOrientation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageInfo", "Orientation", 15usize, |this| ((**(this as *const *const IPrintPageInfo_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMediaSize,
+            MediaSize,
+            SetPageSize,
+            PageSize,
+            SetDpiX,
+            DpiX,
+            SetDpiY,
+            DpiY,
+            SetOrientation,
+            Orientation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintPageInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintPageRange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FirstPageNumber(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageRange", "FirstPageNumber", 6usize, |this| ((**(this as *const *const IPrintPageRange_Vtbl)).FirstPageNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastPageNumber(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageRange", "LastPageNumber", 7usize, |this| ((**(this as *const *const IPrintPageRange_Vtbl)).LastPageNumber)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FirstPageNumber, LastPageNumber }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintPageRange as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintPageRangeFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, firstpage: i32, lastpage: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageRangeFactory", "Create", 6usize, |this| ((**(this as *const *const IPrintPageRangeFactory_Vtbl)).Create)(this, firstpage, lastpage, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithSinglePage(this: *mut core::ffi::c_void, page: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageRangeFactory", "CreateWithSinglePage", 7usize, |this| ((**(this as *const *const IPrintPageRangeFactory_Vtbl)).CreateWithSinglePage)(this, page, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithSinglePage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintPageRangeFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintPageRangeOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllowAllPages(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageRangeOptions", "SetAllowAllPages", 6usize, |this| ((**(this as *const *const IPrintPageRangeOptions_Vtbl)).SetAllowAllPages)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowAllPages(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageRangeOptions", "AllowAllPages", 7usize, |this| ((**(this as *const *const IPrintPageRangeOptions_Vtbl)).AllowAllPages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowCurrentPage(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageRangeOptions", "SetAllowCurrentPage", 8usize, |this| ((**(this as *const *const IPrintPageRangeOptions_Vtbl)).SetAllowCurrentPage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowCurrentPage(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageRangeOptions", "AllowCurrentPage", 9usize, |this| ((**(this as *const *const IPrintPageRangeOptions_Vtbl)).AllowCurrentPage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowCustomSetOfPages(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageRangeOptions", "SetAllowCustomSetOfPages", 10usize, |this| ((**(this as *const *const IPrintPageRangeOptions_Vtbl)).SetAllowCustomSetOfPages)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowCustomSetOfPages(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPageRangeOptions", "AllowCustomSetOfPages", 11usize, |this| ((**(this as *const *const IPrintPageRangeOptions_Vtbl)).AllowCustomSetOfPages)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAllowAllPages,
+            AllowAllPages,
+            SetAllowCurrentPage,
+            AllowCurrentPage,
+            SetAllowCustomSetOfPages,
+            AllowCustomSetOfPages,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintPageRangeOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_DataTransfer")]
+impl windows_core::imp::ProxyVtable for IPrintTask_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_DataTransfer")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTask", "Properties", 6usize, |this| ((**(this as *const *const IPrintTask_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTask", "Source", 7usize, |this| ((**(this as *const *const IPrintTask_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Options(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTask", "Options", 8usize, |this| ((**(this as *const *const IPrintTask_Vtbl)).Options)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Previewing(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTask", "Previewing", 9usize, |this| ((**(this as *const *const IPrintTask_Vtbl)).Previewing)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePreviewing(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTask", "RemovePreviewing", 10usize, |this| ((**(this as *const *const IPrintTask_Vtbl)).RemovePreviewing)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Submitting(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTask", "Submitting", 11usize, |this| ((**(this as *const *const IPrintTask_Vtbl)).Submitting)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSubmitting(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTask", "RemoveSubmitting", 12usize, |this| ((**(this as *const *const IPrintTask_Vtbl)).RemoveSubmitting)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Progressing(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTask", "Progressing", 13usize, |this| ((**(this as *const *const IPrintTask_Vtbl)).Progressing)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProgressing(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTask", "RemoveProgressing", 14usize, |this| ((**(this as *const *const IPrintTask_Vtbl)).RemoveProgressing)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Completed(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTask", "Completed", 15usize, |this| ((**(this as *const *const IPrintTask_Vtbl)).Completed)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCompleted(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTask", "RemoveCompleted", 16usize, |this| ((**(this as *const *const IPrintTask_Vtbl)).RemoveCompleted)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_DataTransfer")]
+            Properties,
+            #[cfg(not(feature = "ApplicationModel_DataTransfer"))]
+            Properties: 0,
+            Source,
+            Options,
+            Previewing,
+            RemovePreviewing,
+            Submitting,
+            RemoveSubmitting,
+            Progressing,
+            RemoveProgressing,
+            Completed,
+            RemoveCompleted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTask as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTask2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIsPreviewEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTask2", "SetIsPreviewEnabled", 6usize, |this| ((**(this as *const *const IPrintTask2_Vtbl)).SetIsPreviewEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPreviewEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTask2", "IsPreviewEnabled", 7usize, |this| ((**(this as *const *const IPrintTask2_Vtbl)).IsPreviewEnabled)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetIsPreviewEnabled, IsPreviewEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTask2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Completion(this: *mut core::ffi::c_void, result__: *mut PrintTaskCompletion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskCompletedEventArgs", "Completion", 6usize, |this| ((**(this as *const *const IPrintTaskCompletedEventArgs_Vtbl)).Completion)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Completion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPrintTaskOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBordering(this: *mut core::ffi::c_void, value: PrintBordering) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptions", "SetBordering", 6usize, |this| ((**(this as *const *const IPrintTaskOptions_Vtbl)).SetBordering)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bordering(this: *mut core::ffi::c_void, result__: *mut PrintBordering) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptions", "Bordering", 7usize, |this| ((**(this as *const *const IPrintTaskOptions_Vtbl)).Bordering)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetPagePrintTicket(this: *mut core::ffi::c_void, printpageinfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptions", "GetPagePrintTicket", 8usize, |this| ((**(this as *const *const IPrintTaskOptions_Vtbl)).GetPagePrintTicket)(this, printpageinfo, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetBordering,
+            Bordering,
+            #[cfg(feature = "Storage_Streams")]
+            GetPagePrintTicket,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetPagePrintTicket: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrintTaskOptions2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PageRangeOptions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptions2", "PageRangeOptions", 6usize, |this| ((**(this as *const *const IPrintTaskOptions2_Vtbl)).PageRangeOptions)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CustomPageRanges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptions2", "CustomPageRanges", 7usize, |this| ((**(this as *const *const IPrintTaskOptions2_Vtbl)).CustomPageRanges)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PageRangeOptions,
+            #[cfg(feature = "Foundation_Collections")]
+            CustomPageRanges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CustomPageRanges: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskOptions2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskOptionsCore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPageDescription(this: *mut core::ffi::c_void, jobpagenumber: u32, result__: *mut PrintPageDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCore", "GetPageDescription", 6usize, |this| ((**(this as *const *const IPrintTaskOptionsCore_Vtbl)).GetPageDescription)(this, jobpagenumber, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPageDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskOptionsCore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskOptionsCoreProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMediaSize(this: *mut core::ffi::c_void, value: PrintMediaSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "SetMediaSize", 6usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).SetMediaSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaSize(this: *mut core::ffi::c_void, result__: *mut PrintMediaSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "MediaSize", 7usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).MediaSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaType(this: *mut core::ffi::c_void, value: PrintMediaType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "SetMediaType", 8usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).SetMediaType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaType(this: *mut core::ffi::c_void, result__: *mut PrintMediaType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "MediaType", 9usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).MediaType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOrientation(this: *mut core::ffi::c_void, value: PrintOrientation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "SetOrientation", 10usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).SetOrientation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut PrintOrientation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "Orientation", 11usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrintQuality(this: *mut core::ffi::c_void, value: PrintQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "SetPrintQuality", 12usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).SetPrintQuality)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintQuality(this: *mut core::ffi::c_void, result__: *mut PrintQuality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "PrintQuality", 13usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).PrintQuality)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorMode(this: *mut core::ffi::c_void, value: PrintColorMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "SetColorMode", 14usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).SetColorMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorMode(this: *mut core::ffi::c_void, result__: *mut PrintColorMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "ColorMode", 15usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).ColorMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuplex(this: *mut core::ffi::c_void, value: PrintDuplex) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "SetDuplex", 16usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).SetDuplex)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duplex(this: *mut core::ffi::c_void, result__: *mut PrintDuplex) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "Duplex", 17usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).Duplex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCollation(this: *mut core::ffi::c_void, value: PrintCollation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "SetCollation", 18usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).SetCollation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Collation(this: *mut core::ffi::c_void, result__: *mut PrintCollation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "Collation", 19usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).Collation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStaple(this: *mut core::ffi::c_void, value: PrintStaple) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "SetStaple", 20usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).SetStaple)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Staple(this: *mut core::ffi::c_void, result__: *mut PrintStaple) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "Staple", 21usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).Staple)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHolePunch(this: *mut core::ffi::c_void, value: PrintHolePunch) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "SetHolePunch", 22usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).SetHolePunch)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HolePunch(this: *mut core::ffi::c_void, result__: *mut PrintHolePunch) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "HolePunch", 23usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).HolePunch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBinding(this: *mut core::ffi::c_void, value: PrintBinding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "SetBinding", 24usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).SetBinding)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Binding(this: *mut core::ffi::c_void, result__: *mut PrintBinding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "Binding", 25usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).Binding)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinCopies(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "MinCopies", 26usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).MinCopies)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxCopies(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "MaxCopies", 27usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).MaxCopies)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumberOfCopies(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "SetNumberOfCopies", 28usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).SetNumberOfCopies)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfCopies(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreProperties", "NumberOfCopies", 29usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreProperties_Vtbl)).NumberOfCopies)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMediaSize,
+            MediaSize,
+            SetMediaType,
+            MediaType,
+            SetOrientation,
+            Orientation,
+            SetPrintQuality,
+            PrintQuality,
+            SetColorMode,
+            ColorMode,
+            SetDuplex,
+            Duplex,
+            SetCollation,
+            Collation,
+            SetStaple,
+            Staple,
+            SetHolePunch,
+            HolePunch,
+            SetBinding,
+            Binding,
+            MinCopies,
+            MaxCopies,
+            SetNumberOfCopies,
+            NumberOfCopies,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskOptionsCoreProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrintTaskOptionsCoreUIConfiguration_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DisplayedOptions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskOptionsCoreUIConfiguration", "DisplayedOptions", 6usize, |this| ((**(this as *const *const IPrintTaskOptionsCoreUIConfiguration_Vtbl)).DisplayedOptions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            DisplayedOptions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DisplayedOptions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskOptionsCoreUIConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskProgressingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DocumentPageCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskProgressingEventArgs", "DocumentPageCount", 6usize, |this| ((**(this as *const *const IPrintTaskProgressingEventArgs_Vtbl)).DocumentPageCount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DocumentPageCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskProgressingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskRequest", "Deadline", 6usize, |this| ((**(this as *const *const IPrintTaskRequest_Vtbl)).Deadline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePrintTask(this: *mut core::ffi::c_void, title: core::mem::MaybeUninit<windows_core::HSTRING>, handler: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskRequest", "CreatePrintTask", 7usize, |this| ((**(this as *const *const IPrintTaskRequest_Vtbl)).CreatePrintTask)(this, title, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskRequest", "GetDeferral", 8usize, |this| ((**(this as *const *const IPrintTaskRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Deadline, CreatePrintTask, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskRequestedDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskRequestedDeferral", "Complete", 6usize, |this| ((**(this as *const *const IPrintTaskRequestedDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskRequestedDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const IPrintTaskRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskSourceRequestedArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskSourceRequestedArgs", "Deadline", 6usize, |this| ((**(this as *const *const IPrintTaskSourceRequestedArgs_Vtbl)).Deadline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskSourceRequestedArgs", "SetSource", 7usize, |this| ((**(this as *const *const IPrintTaskSourceRequestedArgs_Vtbl)).SetSource)(this, source), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskSourceRequestedArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IPrintTaskSourceRequestedArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Deadline, SetSource, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskSourceRequestedArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskSourceRequestedDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskSourceRequestedDeferral", "Complete", 6usize, |this| ((**(this as *const *const IPrintTaskSourceRequestedDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskSourceRequestedDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskTargetDeviceSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIsPrinterTargetEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskTargetDeviceSupport", "SetIsPrinterTargetEnabled", 6usize, |this| ((**(this as *const *const IPrintTaskTargetDeviceSupport_Vtbl)).SetIsPrinterTargetEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPrinterTargetEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskTargetDeviceSupport", "IsPrinterTargetEnabled", 7usize, |this| ((**(this as *const *const IPrintTaskTargetDeviceSupport_Vtbl)).IsPrinterTargetEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIs3DManufacturingTargetEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskTargetDeviceSupport", "SetIs3DManufacturingTargetEnabled", 8usize, |this| ((**(this as *const *const IPrintTaskTargetDeviceSupport_Vtbl)).SetIs3DManufacturingTargetEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Is3DManufacturingTargetEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskTargetDeviceSupport", "Is3DManufacturingTargetEnabled", 9usize, |this| ((**(this as *const *const IPrintTaskTargetDeviceSupport_Vtbl)).Is3DManufacturingTargetEnabled)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetIsPrinterTargetEnabled,
+            IsPrinterTargetEnabled,
+            SetIs3DManufacturingTargetEnabled,
+            Is3DManufacturingTargetEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskTargetDeviceSupport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStandardPrintTaskOptionsStatic_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MediaSize(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardPrintTaskOptionsStatic", "MediaSize", 6usize, |this| ((**(this as *const *const IStandardPrintTaskOptionsStatic_Vtbl)).MediaSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardPrintTaskOptionsStatic", "MediaType", 7usize, |this| ((**(this as *const *const IStandardPrintTaskOptionsStatic_Vtbl)).MediaType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardPrintTaskOptionsStatic", "Orientation", 8usize, |this| ((**(this as *const *const IStandardPrintTaskOptionsStatic_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintQuality(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardPrintTaskOptionsStatic", "PrintQuality", 9usize, |this| ((**(this as *const *const IStandardPrintTaskOptionsStatic_Vtbl)).PrintQuality)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorMode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardPrintTaskOptionsStatic", "ColorMode", 10usize, |this| ((**(this as *const *const IStandardPrintTaskOptionsStatic_Vtbl)).ColorMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duplex(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardPrintTaskOptionsStatic", "Duplex", 11usize, |this| ((**(this as *const *const IStandardPrintTaskOptionsStatic_Vtbl)).Duplex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Collation(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardPrintTaskOptionsStatic", "Collation", 12usize, |this| ((**(this as *const *const IStandardPrintTaskOptionsStatic_Vtbl)).Collation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Staple(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardPrintTaskOptionsStatic", "Staple", 13usize, |this| ((**(this as *const *const IStandardPrintTaskOptionsStatic_Vtbl)).Staple)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HolePunch(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardPrintTaskOptionsStatic", "HolePunch", 14usize, |this| ((**(this as *const *const IStandardPrintTaskOptionsStatic_Vtbl)).HolePunch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Binding(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardPrintTaskOptionsStatic", "Binding", 15usize, |this| ((**(this as *const *const IStandardPrintTaskOptionsStatic_Vtbl)).Binding)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copies(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardPrintTaskOptionsStatic", "Copies", 16usize, |this| ((**(this as *const *const IStandardPrintTaskOptionsStatic_Vtbl)).Copies)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NUp(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardPrintTaskOptionsStatic", "NUp", 17usize, |this| ((**(this as *const *const IStandardPrintTaskOptionsStatic_Vtbl)).NUp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputBin(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardPrintTaskOptionsStatic", "InputBin", 18usize, |this| ((**(this as *const *const IStandardPrintTaskOptionsStatic_Vtbl)).InputBin)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MediaSize,
+            MediaType,
+            Orientation,
+            PrintQuality,
+            ColorMode,
+            Duplex,
+            Collation,
+            Staple,
+            HolePunch,
+            Binding,
+            Copies,
+            NUp,
+            InputBin,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStandardPrintTaskOptionsStatic as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStandardPrintTaskOptionsStatic2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Bordering(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardPrintTaskOptionsStatic2", "Bordering", 6usize, |this| ((**(this as *const *const IStandardPrintTaskOptionsStatic2_Vtbl)).Bordering)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Bordering }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStandardPrintTaskOptionsStatic2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStandardPrintTaskOptionsStatic3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CustomPageRanges(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardPrintTaskOptionsStatic3", "CustomPageRanges", 6usize, |this| ((**(this as *const *const IStandardPrintTaskOptionsStatic3_Vtbl)).CustomPageRanges)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CustomPageRanges }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStandardPrintTaskOptionsStatic3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -2263,6 +2263,7 @@ impl Print3DTaskCompletion {
 impl windows_core::TypeKind for Print3DTaskCompletion {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Print3DTaskCompletion {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Print3DTaskCompletion").field(&self.0).finish()
@@ -2286,6 +2287,7 @@ impl Print3DTaskDetail {
 impl windows_core::TypeKind for Print3DTaskDetail {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Print3DTaskDetail {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Print3DTaskDetail").field(&self.0).finish()
@@ -2309,6 +2311,7 @@ impl Printing3DBufferFormat {
 impl windows_core::TypeKind for Printing3DBufferFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Printing3DBufferFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Printing3DBufferFormat").field(&self.0).finish()
@@ -2327,6 +2330,7 @@ impl Printing3DMeshVerificationMode {
 impl windows_core::TypeKind for Printing3DMeshVerificationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Printing3DMeshVerificationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Printing3DMeshVerificationMode").field(&self.0).finish()
@@ -2349,6 +2353,7 @@ impl Printing3DModelUnit {
 impl windows_core::TypeKind for Printing3DModelUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Printing3DModelUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Printing3DModelUnit").field(&self.0).finish()
@@ -2368,6 +2373,7 @@ impl Printing3DObjectType {
 impl windows_core::TypeKind for Printing3DObjectType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Printing3DObjectType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Printing3DObjectType").field(&self.0).finish()
@@ -2387,6 +2393,7 @@ impl Printing3DPackageCompression {
 impl windows_core::TypeKind for Printing3DPackageCompression {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Printing3DPackageCompression {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Printing3DPackageCompression").field(&self.0).finish()
@@ -2407,6 +2414,7 @@ impl Printing3DTextureEdgeBehavior {
 impl windows_core::TypeKind for Printing3DTextureEdgeBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Printing3DTextureEdgeBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Printing3DTextureEdgeBehavior").field(&self.0).finish()
@@ -2416,7 +2424,8 @@ impl windows_core::RuntimeType for Printing3DTextureEdgeBehavior {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Graphics.Printing3D.Printing3DTextureEdgeBehavior;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Printing3DBufferDescription {
     pub Format: Printing3DBufferFormat,
     pub Stride: u32,
@@ -2492,3 +2501,5 @@ pub struct Print3DTaskSourceRequestedHandler_Vtbl {
     pub base__: windows_core::IUnknown_Vtbl,
     pub Invoke: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1160 @@
+impl windows_core::imp::ProxyVtable for IPrint3DManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TaskRequested(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DManager", "TaskRequested", 6usize, |this| ((**(this as *const *const IPrint3DManager_Vtbl)).TaskRequested)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTaskRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DManager", "RemoveTaskRequested", 7usize, |this| ((**(this as *const *const IPrint3DManager_Vtbl)).RemoveTaskRequested)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TaskRequested, RemoveTaskRequested }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint3DManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrint3DManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DManagerStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IPrint3DManagerStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowPrintUIAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DManagerStatics", "ShowPrintUIAsync", 7usize, |this| ((**(this as *const *const IPrint3DManagerStatics_Vtbl)).ShowPrintUIAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView, ShowPrintUIAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint3DManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrint3DTask_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DTask", "Source", 6usize, |this| ((**(this as *const *const IPrint3DTask_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Submitting(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DTask", "Submitting", 7usize, |this| ((**(this as *const *const IPrint3DTask_Vtbl)).Submitting)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSubmitting(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DTask", "RemoveSubmitting", 8usize, |this| ((**(this as *const *const IPrint3DTask_Vtbl)).RemoveSubmitting)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Completed(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DTask", "Completed", 9usize, |this| ((**(this as *const *const IPrint3DTask_Vtbl)).Completed)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCompleted(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DTask", "RemoveCompleted", 10usize, |this| ((**(this as *const *const IPrint3DTask_Vtbl)).RemoveCompleted)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceChanged(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DTask", "SourceChanged", 11usize, |this| ((**(this as *const *const IPrint3DTask_Vtbl)).SourceChanged)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSourceChanged(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DTask", "RemoveSourceChanged", 12usize, |this| ((**(this as *const *const IPrint3DTask_Vtbl)).RemoveSourceChanged)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Source,
+            Submitting,
+            RemoveSubmitting,
+            Completed,
+            RemoveCompleted,
+            SourceChanged,
+            RemoveSourceChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint3DTask as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrint3DTaskCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Completion(this: *mut core::ffi::c_void, result__: *mut Print3DTaskCompletion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DTaskCompletedEventArgs", "Completion", 6usize, |this| ((**(this as *const *const IPrint3DTaskCompletedEventArgs_Vtbl)).Completion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedStatus(this: *mut core::ffi::c_void, result__: *mut Print3DTaskDetail) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DTaskCompletedEventArgs", "ExtendedStatus", 7usize, |this| ((**(this as *const *const IPrint3DTaskCompletedEventArgs_Vtbl)).ExtendedStatus)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Completion, ExtendedStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint3DTaskCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrint3DTaskRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTask(this: *mut core::ffi::c_void, title: core::mem::MaybeUninit<windows_core::HSTRING>, printerid: core::mem::MaybeUninit<windows_core::HSTRING>, handler: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DTaskRequest", "CreateTask", 6usize, |this| ((**(this as *const *const IPrint3DTaskRequest_Vtbl)).CreateTask)(this, title, printerid, handler, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateTask }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint3DTaskRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrint3DTaskRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DTaskRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const IPrint3DTaskRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint3DTaskRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrint3DTaskSourceChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DTaskSourceChangedEventArgs", "Source", 6usize, |this| ((**(this as *const *const IPrint3DTaskSourceChangedEventArgs_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Source }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint3DTaskSourceChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrint3DTaskSourceRequestedArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint3DTaskSourceRequestedArgs", "SetSource", 6usize, |this| ((**(this as *const *const IPrint3DTaskSourceRequestedArgs_Vtbl)).SetSource)(this, source), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint3DTaskSourceRequestedArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IPrinting3D3MFPackage_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3D3MFPackage", "SaveAsync", 6usize, |this| ((**(this as *const *const IPrinting3D3MFPackage_Vtbl)).SaveAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn PrintTicket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3D3MFPackage", "PrintTicket", 7usize, |this| ((**(this as *const *const IPrinting3D3MFPackage_Vtbl)).PrintTicket)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetPrintTicket(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3D3MFPackage", "SetPrintTicket", 8usize, |this| ((**(this as *const *const IPrinting3D3MFPackage_Vtbl)).SetPrintTicket)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ModelPart(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3D3MFPackage", "ModelPart", 9usize, |this| ((**(this as *const *const IPrinting3D3MFPackage_Vtbl)).ModelPart)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetModelPart(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3D3MFPackage", "SetModelPart", 10usize, |this| ((**(this as *const *const IPrinting3D3MFPackage_Vtbl)).SetModelPart)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3D3MFPackage", "Thumbnail", 11usize, |this| ((**(this as *const *const IPrinting3D3MFPackage_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThumbnail(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3D3MFPackage", "SetThumbnail", 12usize, |this| ((**(this as *const *const IPrinting3D3MFPackage_Vtbl)).SetThumbnail)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Textures(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3D3MFPackage", "Textures", 13usize, |this| ((**(this as *const *const IPrinting3D3MFPackage_Vtbl)).Textures)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn LoadModelFromPackageAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3D3MFPackage", "LoadModelFromPackageAsync", 14usize, |this| ((**(this as *const *const IPrinting3D3MFPackage_Vtbl)).LoadModelFromPackageAsync)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveModelToPackageAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3D3MFPackage", "SaveModelToPackageAsync", 15usize, |this| ((**(this as *const *const IPrinting3D3MFPackage_Vtbl)).SaveModelToPackageAsync)(this, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            SaveAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SaveAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            PrintTicket,
+            #[cfg(not(feature = "Storage_Streams"))]
+            PrintTicket: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetPrintTicket,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetPrintTicket: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ModelPart,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ModelPart: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetModelPart,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetModelPart: 0,
+            Thumbnail,
+            SetThumbnail,
+            #[cfg(feature = "Foundation_Collections")]
+            Textures,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Textures: 0,
+            #[cfg(feature = "Storage_Streams")]
+            LoadModelFromPackageAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            LoadModelFromPackageAsync: 0,
+            SaveModelToPackageAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3D3MFPackage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3D3MFPackage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Compression(this: *mut core::ffi::c_void, result__: *mut Printing3DPackageCompression) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3D3MFPackage2", "Compression", 6usize, |this| ((**(this as *const *const IPrinting3D3MFPackage2_Vtbl)).Compression)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompression(this: *mut core::ffi::c_void, value: Printing3DPackageCompression) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3D3MFPackage2", "SetCompression", 7usize, |this| ((**(this as *const *const IPrinting3D3MFPackage2_Vtbl)).SetCompression)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Compression, SetCompression }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3D3MFPackage2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPrinting3D3MFPackageStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn LoadAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3D3MFPackageStatics", "LoadAsync", 6usize, |this| ((**(this as *const *const IPrinting3D3MFPackageStatics_Vtbl)).LoadAsync)(this, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            LoadAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            LoadAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3D3MFPackageStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3DBaseMaterial_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DBaseMaterial", "Name", 6usize, |this| ((**(this as *const *const IPrinting3DBaseMaterial_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DBaseMaterial", "SetName", 7usize, |this| ((**(this as *const *const IPrinting3DBaseMaterial_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DBaseMaterial", "Color", 8usize, |this| ((**(this as *const *const IPrinting3DBaseMaterial_Vtbl)).Color)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DBaseMaterial", "SetColor", 9usize, |this| ((**(this as *const *const IPrinting3DBaseMaterial_Vtbl)).SetColor)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, SetName, Color, SetColor }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DBaseMaterial as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrinting3DBaseMaterialGroup_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Bases(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DBaseMaterialGroup", "Bases", 6usize, |this| ((**(this as *const *const IPrinting3DBaseMaterialGroup_Vtbl)).Bases)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaterialGroupId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DBaseMaterialGroup", "MaterialGroupId", 7usize, |this| ((**(this as *const *const IPrinting3DBaseMaterialGroup_Vtbl)).MaterialGroupId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Bases,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Bases: 0,
+            MaterialGroupId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DBaseMaterialGroup as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3DBaseMaterialGroupFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, materialgroupid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DBaseMaterialGroupFactory", "Create", 6usize, |this| ((**(this as *const *const IPrinting3DBaseMaterialGroupFactory_Vtbl)).Create)(this, materialgroupid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DBaseMaterialGroupFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3DBaseMaterialStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Abs(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DBaseMaterialStatics", "Abs", 6usize, |this| ((**(this as *const *const IPrinting3DBaseMaterialStatics_Vtbl)).Abs)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pla(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DBaseMaterialStatics", "Pla", 7usize, |this| ((**(this as *const *const IPrinting3DBaseMaterialStatics_Vtbl)).Pla)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Abs, Pla }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DBaseMaterialStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3DColorMaterial_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DColorMaterial", "Value", 6usize, |this| ((**(this as *const *const IPrinting3DColorMaterial_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DColorMaterial", "SetValue", 7usize, |this| ((**(this as *const *const IPrinting3DColorMaterial_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DColorMaterial as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for IPrinting3DColorMaterial2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DColorMaterial2", "Color", 6usize, |this| ((**(this as *const *const IPrinting3DColorMaterial2_Vtbl)).Color)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DColorMaterial2", "SetColor", 7usize, |this| ((**(this as *const *const IPrinting3DColorMaterial2_Vtbl)).SetColor)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI")]
+            Color,
+            #[cfg(not(feature = "UI"))]
+            Color: 0,
+            #[cfg(feature = "UI")]
+            SetColor,
+            #[cfg(not(feature = "UI"))]
+            SetColor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DColorMaterial2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrinting3DColorMaterialGroup_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Colors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DColorMaterialGroup", "Colors", 6usize, |this| ((**(this as *const *const IPrinting3DColorMaterialGroup_Vtbl)).Colors)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaterialGroupId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DColorMaterialGroup", "MaterialGroupId", 7usize, |this| ((**(this as *const *const IPrinting3DColorMaterialGroup_Vtbl)).MaterialGroupId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Colors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Colors: 0,
+            MaterialGroupId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DColorMaterialGroup as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3DColorMaterialGroupFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, materialgroupid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DColorMaterialGroupFactory", "Create", 6usize, |this| ((**(this as *const *const IPrinting3DColorMaterialGroupFactory_Vtbl)).Create)(this, materialgroupid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DColorMaterialGroupFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrinting3DComponent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mesh(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DComponent", "Mesh", 6usize, |this| ((**(this as *const *const IPrinting3DComponent_Vtbl)).Mesh)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMesh(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DComponent", "SetMesh", 7usize, |this| ((**(this as *const *const IPrinting3DComponent_Vtbl)).SetMesh)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Components(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DComponent", "Components", 8usize, |this| ((**(this as *const *const IPrinting3DComponent_Vtbl)).Components)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DComponent", "Thumbnail", 9usize, |this| ((**(this as *const *const IPrinting3DComponent_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThumbnail(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DComponent", "SetThumbnail", 10usize, |this| ((**(this as *const *const IPrinting3DComponent_Vtbl)).SetThumbnail)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut Printing3DObjectType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DComponent", "Type", 11usize, |this| ((**(this as *const *const IPrinting3DComponent_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, value: Printing3DObjectType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DComponent", "SetType", 12usize, |this| ((**(this as *const *const IPrinting3DComponent_Vtbl)).SetType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DComponent", "Name", 13usize, |this| ((**(this as *const *const IPrinting3DComponent_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DComponent", "SetName", 14usize, |this| ((**(this as *const *const IPrinting3DComponent_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PartNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DComponent", "PartNumber", 15usize, |this| ((**(this as *const *const IPrinting3DComponent_Vtbl)).PartNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPartNumber(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DComponent", "SetPartNumber", 16usize, |this| ((**(this as *const *const IPrinting3DComponent_Vtbl)).SetPartNumber)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Mesh,
+            SetMesh,
+            #[cfg(feature = "Foundation_Collections")]
+            Components,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Components: 0,
+            Thumbnail,
+            SetThumbnail,
+            Type,
+            SetType,
+            Name,
+            SetName,
+            PartNumber,
+            SetPartNumber,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DComponent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IPrinting3DComponentWithMatrix_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Component(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DComponentWithMatrix", "Component", 6usize, |this| ((**(this as *const *const IPrinting3DComponentWithMatrix_Vtbl)).Component)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComponent(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DComponentWithMatrix", "SetComponent", 7usize, |this| ((**(this as *const *const IPrinting3DComponentWithMatrix_Vtbl)).SetComponent)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Matrix(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Matrix4x4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DComponentWithMatrix", "Matrix", 8usize, |this| ((**(this as *const *const IPrinting3DComponentWithMatrix_Vtbl)).Matrix)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetMatrix(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Matrix4x4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DComponentWithMatrix", "SetMatrix", 9usize, |this| ((**(this as *const *const IPrinting3DComponentWithMatrix_Vtbl)).SetMatrix)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Component,
+            SetComponent,
+            #[cfg(feature = "Foundation_Numerics")]
+            Matrix,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Matrix: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetMatrix,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetMatrix: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DComponentWithMatrix as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrinting3DCompositeMaterial_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Values(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DCompositeMaterial", "Values", 6usize, |this| ((**(this as *const *const IPrinting3DCompositeMaterial_Vtbl)).Values)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Values,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Values: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DCompositeMaterial as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrinting3DCompositeMaterialGroup_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Composites(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DCompositeMaterialGroup", "Composites", 6usize, |this| ((**(this as *const *const IPrinting3DCompositeMaterialGroup_Vtbl)).Composites)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaterialGroupId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DCompositeMaterialGroup", "MaterialGroupId", 7usize, |this| ((**(this as *const *const IPrinting3DCompositeMaterialGroup_Vtbl)).MaterialGroupId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MaterialIndices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DCompositeMaterialGroup", "MaterialIndices", 8usize, |this| ((**(this as *const *const IPrinting3DCompositeMaterialGroup_Vtbl)).MaterialIndices)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Composites,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Composites: 0,
+            MaterialGroupId,
+            #[cfg(feature = "Foundation_Collections")]
+            MaterialIndices,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MaterialIndices: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DCompositeMaterialGroup as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3DCompositeMaterialGroup2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BaseMaterialGroup(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DCompositeMaterialGroup2", "BaseMaterialGroup", 6usize, |this| ((**(this as *const *const IPrinting3DCompositeMaterialGroup2_Vtbl)).BaseMaterialGroup)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBaseMaterialGroup(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DCompositeMaterialGroup2", "SetBaseMaterialGroup", 7usize, |this| ((**(this as *const *const IPrinting3DCompositeMaterialGroup2_Vtbl)).SetBaseMaterialGroup)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BaseMaterialGroup, SetBaseMaterialGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DCompositeMaterialGroup2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3DCompositeMaterialGroupFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, materialgroupid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DCompositeMaterialGroupFactory", "Create", 6usize, |this| ((**(this as *const *const IPrinting3DCompositeMaterialGroupFactory_Vtbl)).Create)(this, materialgroupid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DCompositeMaterialGroupFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3DFaceReductionOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxReductionArea(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DFaceReductionOptions", "MaxReductionArea", 6usize, |this| ((**(this as *const *const IPrinting3DFaceReductionOptions_Vtbl)).MaxReductionArea)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxReductionArea(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DFaceReductionOptions", "SetMaxReductionArea", 7usize, |this| ((**(this as *const *const IPrinting3DFaceReductionOptions_Vtbl)).SetMaxReductionArea)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetTriangleCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DFaceReductionOptions", "TargetTriangleCount", 8usize, |this| ((**(this as *const *const IPrinting3DFaceReductionOptions_Vtbl)).TargetTriangleCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetTriangleCount(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DFaceReductionOptions", "SetTargetTriangleCount", 9usize, |this| ((**(this as *const *const IPrinting3DFaceReductionOptions_Vtbl)).SetTargetTriangleCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxEdgeLength(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DFaceReductionOptions", "MaxEdgeLength", 10usize, |this| ((**(this as *const *const IPrinting3DFaceReductionOptions_Vtbl)).MaxEdgeLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxEdgeLength(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DFaceReductionOptions", "SetMaxEdgeLength", 11usize, |this| ((**(this as *const *const IPrinting3DFaceReductionOptions_Vtbl)).SetMaxEdgeLength)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaxReductionArea,
+            SetMaxReductionArea,
+            TargetTriangleCount,
+            SetTargetTriangleCount,
+            MaxEdgeLength,
+            SetMaxEdgeLength,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DFaceReductionOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrinting3DMaterial_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BaseGroups(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMaterial", "BaseGroups", 6usize, |this| ((**(this as *const *const IPrinting3DMaterial_Vtbl)).BaseGroups)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ColorGroups(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMaterial", "ColorGroups", 7usize, |this| ((**(this as *const *const IPrinting3DMaterial_Vtbl)).ColorGroups)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Texture2CoordGroups(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMaterial", "Texture2CoordGroups", 8usize, |this| ((**(this as *const *const IPrinting3DMaterial_Vtbl)).Texture2CoordGroups)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CompositeGroups(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMaterial", "CompositeGroups", 9usize, |this| ((**(this as *const *const IPrinting3DMaterial_Vtbl)).CompositeGroups)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MultiplePropertyGroups(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMaterial", "MultiplePropertyGroups", 10usize, |this| ((**(this as *const *const IPrinting3DMaterial_Vtbl)).MultiplePropertyGroups)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            BaseGroups,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BaseGroups: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ColorGroups,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ColorGroups: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Texture2CoordGroups,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Texture2CoordGroups: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CompositeGroups,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CompositeGroups: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            MultiplePropertyGroups,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MultiplePropertyGroups: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DMaterial as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IPrinting3DMesh_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VertexCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "VertexCount", 6usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).VertexCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVertexCount(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "SetVertexCount", 7usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).SetVertexCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IndexCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "IndexCount", 8usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).IndexCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIndexCount(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "SetIndexCount", 9usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).SetIndexCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VertexPositionsDescription(this: *mut core::ffi::c_void, result__: *mut Printing3DBufferDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "VertexPositionsDescription", 10usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).VertexPositionsDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVertexPositionsDescription(this: *mut core::ffi::c_void, value: Printing3DBufferDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "SetVertexPositionsDescription", 11usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).SetVertexPositionsDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VertexNormalsDescription(this: *mut core::ffi::c_void, result__: *mut Printing3DBufferDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "VertexNormalsDescription", 12usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).VertexNormalsDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVertexNormalsDescription(this: *mut core::ffi::c_void, value: Printing3DBufferDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "SetVertexNormalsDescription", 13usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).SetVertexNormalsDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TriangleIndicesDescription(this: *mut core::ffi::c_void, result__: *mut Printing3DBufferDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "TriangleIndicesDescription", 14usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).TriangleIndicesDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTriangleIndicesDescription(this: *mut core::ffi::c_void, value: Printing3DBufferDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "SetTriangleIndicesDescription", 15usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).SetTriangleIndicesDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TriangleMaterialIndicesDescription(this: *mut core::ffi::c_void, result__: *mut Printing3DBufferDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "TriangleMaterialIndicesDescription", 16usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).TriangleMaterialIndicesDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTriangleMaterialIndicesDescription(this: *mut core::ffi::c_void, value: Printing3DBufferDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "SetTriangleMaterialIndicesDescription", 17usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).SetTriangleMaterialIndicesDescription)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetVertexPositions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "GetVertexPositions", 18usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).GetVertexPositions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVertexPositions(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "CreateVertexPositions", 19usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).CreateVertexPositions)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetVertexNormals(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "GetVertexNormals", 20usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).GetVertexNormals)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVertexNormals(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "CreateVertexNormals", 21usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).CreateVertexNormals)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetTriangleIndices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "GetTriangleIndices", 22usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).GetTriangleIndices)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTriangleIndices(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "CreateTriangleIndices", 23usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).CreateTriangleIndices)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetTriangleMaterialIndices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "GetTriangleMaterialIndices", 24usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).GetTriangleMaterialIndices)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTriangleMaterialIndices(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "CreateTriangleMaterialIndices", 25usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).CreateTriangleMaterialIndices)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BufferDescriptionSet(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "BufferDescriptionSet", 26usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).BufferDescriptionSet)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BufferSet(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "BufferSet", 27usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).BufferSet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerifyAsync(this: *mut core::ffi::c_void, value: Printing3DMeshVerificationMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMesh", "VerifyAsync", 28usize, |this| ((**(this as *const *const IPrinting3DMesh_Vtbl)).VerifyAsync)(this, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            VertexCount,
+            SetVertexCount,
+            IndexCount,
+            SetIndexCount,
+            VertexPositionsDescription,
+            SetVertexPositionsDescription,
+            VertexNormalsDescription,
+            SetVertexNormalsDescription,
+            TriangleIndicesDescription,
+            SetTriangleIndicesDescription,
+            TriangleMaterialIndicesDescription,
+            SetTriangleMaterialIndicesDescription,
+            #[cfg(feature = "Storage_Streams")]
+            GetVertexPositions,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetVertexPositions: 0,
+            CreateVertexPositions,
+            #[cfg(feature = "Storage_Streams")]
+            GetVertexNormals,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetVertexNormals: 0,
+            CreateVertexNormals,
+            #[cfg(feature = "Storage_Streams")]
+            GetTriangleIndices,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetTriangleIndices: 0,
+            CreateTriangleIndices,
+            #[cfg(feature = "Storage_Streams")]
+            GetTriangleMaterialIndices,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetTriangleMaterialIndices: 0,
+            CreateTriangleMaterialIndices,
+            #[cfg(feature = "Foundation_Collections")]
+            BufferDescriptionSet,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BufferDescriptionSet: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            BufferSet,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BufferSet: 0,
+            VerifyAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DMesh as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrinting3DMeshVerificationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsValid(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMeshVerificationResult", "IsValid", 6usize, |this| ((**(this as *const *const IPrinting3DMeshVerificationResult_Vtbl)).IsValid)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn NonmanifoldTriangles(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMeshVerificationResult", "NonmanifoldTriangles", 7usize, |this| ((**(this as *const *const IPrinting3DMeshVerificationResult_Vtbl)).NonmanifoldTriangles)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReversedNormalTriangles(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMeshVerificationResult", "ReversedNormalTriangles", 8usize, |this| ((**(this as *const *const IPrinting3DMeshVerificationResult_Vtbl)).ReversedNormalTriangles)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsValid,
+            #[cfg(feature = "Foundation_Collections")]
+            NonmanifoldTriangles,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            NonmanifoldTriangles: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ReversedNormalTriangles,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReversedNormalTriangles: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DMeshVerificationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrinting3DModel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Unit(this: *mut core::ffi::c_void, result__: *mut Printing3DModelUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel", "Unit", 6usize, |this| ((**(this as *const *const IPrinting3DModel_Vtbl)).Unit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnit(this: *mut core::ffi::c_void, value: Printing3DModelUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel", "SetUnit", 7usize, |this| ((**(this as *const *const IPrinting3DModel_Vtbl)).SetUnit)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Textures(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel", "Textures", 8usize, |this| ((**(this as *const *const IPrinting3DModel_Vtbl)).Textures)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Meshes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel", "Meshes", 9usize, |this| ((**(this as *const *const IPrinting3DModel_Vtbl)).Meshes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Components(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel", "Components", 10usize, |this| ((**(this as *const *const IPrinting3DModel_Vtbl)).Components)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Material(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel", "Material", 11usize, |this| ((**(this as *const *const IPrinting3DModel_Vtbl)).Material)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaterial(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel", "SetMaterial", 12usize, |this| ((**(this as *const *const IPrinting3DModel_Vtbl)).SetMaterial)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Build(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel", "Build", 13usize, |this| ((**(this as *const *const IPrinting3DModel_Vtbl)).Build)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBuild(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel", "SetBuild", 14usize, |this| ((**(this as *const *const IPrinting3DModel_Vtbl)).SetBuild)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel", "Version", 15usize, |this| ((**(this as *const *const IPrinting3DModel_Vtbl)).Version)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVersion(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel", "SetVersion", 16usize, |this| ((**(this as *const *const IPrinting3DModel_Vtbl)).SetVersion)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequiredExtensions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel", "RequiredExtensions", 17usize, |this| ((**(this as *const *const IPrinting3DModel_Vtbl)).RequiredExtensions)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Metadata(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel", "Metadata", 18usize, |this| ((**(this as *const *const IPrinting3DModel_Vtbl)).Metadata)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RepairAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel", "RepairAsync", 19usize, |this| ((**(this as *const *const IPrinting3DModel_Vtbl)).RepairAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel", "Clone", 20usize, |this| ((**(this as *const *const IPrinting3DModel_Vtbl)).Clone)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Unit,
+            SetUnit,
+            #[cfg(feature = "Foundation_Collections")]
+            Textures,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Textures: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Meshes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Meshes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Components,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Components: 0,
+            Material,
+            SetMaterial,
+            Build,
+            SetBuild,
+            Version,
+            SetVersion,
+            #[cfg(feature = "Foundation_Collections")]
+            RequiredExtensions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequiredExtensions: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Metadata,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Metadata: 0,
+            RepairAsync,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DModel as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3DModel2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryPartialRepairAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel2", "TryPartialRepairAsync", 6usize, |this| ((**(this as *const *const IPrinting3DModel2_Vtbl)).TryPartialRepairAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryPartialRepairWithTimeAsync(this: *mut core::ffi::c_void, maxwaittime: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel2", "TryPartialRepairWithTimeAsync", 7usize, |this| ((**(this as *const *const IPrinting3DModel2_Vtbl)).TryPartialRepairWithTimeAsync)(this, maxwaittime, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryReduceFacesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel2", "TryReduceFacesAsync", 8usize, |this| ((**(this as *const *const IPrinting3DModel2_Vtbl)).TryReduceFacesAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryReduceFacesWithOptionsAsync(this: *mut core::ffi::c_void, printing3dfacereductionoptions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel2", "TryReduceFacesWithOptionsAsync", 9usize, |this| ((**(this as *const *const IPrinting3DModel2_Vtbl)).TryReduceFacesWithOptionsAsync)(this, printing3dfacereductionoptions, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryReduceFacesWithOptionsAndTimeAsync(this: *mut core::ffi::c_void, printing3dfacereductionoptions: *mut core::ffi::c_void, maxwait: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel2", "TryReduceFacesWithOptionsAndTimeAsync", 10usize, |this| ((**(this as *const *const IPrinting3DModel2_Vtbl)).TryReduceFacesWithOptionsAndTimeAsync)(this, printing3dfacereductionoptions, maxwait, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RepairWithProgressAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModel2", "RepairWithProgressAsync", 11usize, |this| ((**(this as *const *const IPrinting3DModel2_Vtbl)).RepairWithProgressAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TryPartialRepairAsync,
+            TryPartialRepairWithTimeAsync,
+            TryReduceFacesAsync,
+            TryReduceFacesWithOptionsAsync,
+            TryReduceFacesWithOptionsAndTimeAsync,
+            RepairWithProgressAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DModel2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3DModelTexture_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TextureResource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModelTexture", "TextureResource", 6usize, |this| ((**(this as *const *const IPrinting3DModelTexture_Vtbl)).TextureResource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextureResource(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModelTexture", "SetTextureResource", 7usize, |this| ((**(this as *const *const IPrinting3DModelTexture_Vtbl)).SetTextureResource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TileStyleU(this: *mut core::ffi::c_void, result__: *mut Printing3DTextureEdgeBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModelTexture", "TileStyleU", 8usize, |this| ((**(this as *const *const IPrinting3DModelTexture_Vtbl)).TileStyleU)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTileStyleU(this: *mut core::ffi::c_void, value: Printing3DTextureEdgeBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModelTexture", "SetTileStyleU", 9usize, |this| ((**(this as *const *const IPrinting3DModelTexture_Vtbl)).SetTileStyleU)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TileStyleV(this: *mut core::ffi::c_void, result__: *mut Printing3DTextureEdgeBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModelTexture", "TileStyleV", 10usize, |this| ((**(this as *const *const IPrinting3DModelTexture_Vtbl)).TileStyleV)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTileStyleV(this: *mut core::ffi::c_void, value: Printing3DTextureEdgeBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DModelTexture", "SetTileStyleV", 11usize, |this| ((**(this as *const *const IPrinting3DModelTexture_Vtbl)).SetTileStyleV)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TextureResource,
+            SetTextureResource,
+            TileStyleU,
+            SetTileStyleU,
+            TileStyleV,
+            SetTileStyleV,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DModelTexture as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrinting3DMultiplePropertyMaterial_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MaterialIndices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMultiplePropertyMaterial", "MaterialIndices", 6usize, |this| ((**(this as *const *const IPrinting3DMultiplePropertyMaterial_Vtbl)).MaterialIndices)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            MaterialIndices,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MaterialIndices: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DMultiplePropertyMaterial as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrinting3DMultiplePropertyMaterialGroup_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MultipleProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMultiplePropertyMaterialGroup", "MultipleProperties", 6usize, |this| ((**(this as *const *const IPrinting3DMultiplePropertyMaterialGroup_Vtbl)).MultipleProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MaterialGroupIndices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMultiplePropertyMaterialGroup", "MaterialGroupIndices", 7usize, |this| ((**(this as *const *const IPrinting3DMultiplePropertyMaterialGroup_Vtbl)).MaterialGroupIndices)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaterialGroupId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMultiplePropertyMaterialGroup", "MaterialGroupId", 8usize, |this| ((**(this as *const *const IPrinting3DMultiplePropertyMaterialGroup_Vtbl)).MaterialGroupId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            MultipleProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MultipleProperties: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            MaterialGroupIndices,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MaterialGroupIndices: 0,
+            MaterialGroupId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DMultiplePropertyMaterialGroup as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3DMultiplePropertyMaterialGroupFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, materialgroupid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DMultiplePropertyMaterialGroupFactory", "Create", 6usize, |this| ((**(this as *const *const IPrinting3DMultiplePropertyMaterialGroupFactory_Vtbl)).Create)(this, materialgroupid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DMultiplePropertyMaterialGroupFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3DTexture2CoordMaterial_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Texture(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DTexture2CoordMaterial", "Texture", 6usize, |this| ((**(this as *const *const IPrinting3DTexture2CoordMaterial_Vtbl)).Texture)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTexture(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DTexture2CoordMaterial", "SetTexture", 7usize, |this| ((**(this as *const *const IPrinting3DTexture2CoordMaterial_Vtbl)).SetTexture)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn U(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DTexture2CoordMaterial", "U", 8usize, |this| ((**(this as *const *const IPrinting3DTexture2CoordMaterial_Vtbl)).U)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetU(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DTexture2CoordMaterial", "SetU", 9usize, |this| ((**(this as *const *const IPrinting3DTexture2CoordMaterial_Vtbl)).SetU)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn V(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DTexture2CoordMaterial", "V", 10usize, |this| ((**(this as *const *const IPrinting3DTexture2CoordMaterial_Vtbl)).V)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetV(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DTexture2CoordMaterial", "SetV", 11usize, |this| ((**(this as *const *const IPrinting3DTexture2CoordMaterial_Vtbl)).SetV)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Texture, SetTexture, U, SetU, V, SetV }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DTexture2CoordMaterial as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPrinting3DTexture2CoordMaterialGroup_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Texture2Coords(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DTexture2CoordMaterialGroup", "Texture2Coords", 6usize, |this| ((**(this as *const *const IPrinting3DTexture2CoordMaterialGroup_Vtbl)).Texture2Coords)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaterialGroupId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DTexture2CoordMaterialGroup", "MaterialGroupId", 7usize, |this| ((**(this as *const *const IPrinting3DTexture2CoordMaterialGroup_Vtbl)).MaterialGroupId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Texture2Coords,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Texture2Coords: 0,
+            MaterialGroupId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DTexture2CoordMaterialGroup as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3DTexture2CoordMaterialGroup2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Texture(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DTexture2CoordMaterialGroup2", "Texture", 6usize, |this| ((**(this as *const *const IPrinting3DTexture2CoordMaterialGroup2_Vtbl)).Texture)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTexture(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DTexture2CoordMaterialGroup2", "SetTexture", 7usize, |this| ((**(this as *const *const IPrinting3DTexture2CoordMaterialGroup2_Vtbl)).SetTexture)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Texture, SetTexture }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DTexture2CoordMaterialGroup2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3DTexture2CoordMaterialGroupFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, materialgroupid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DTexture2CoordMaterialGroupFactory", "Create", 6usize, |this| ((**(this as *const *const IPrinting3DTexture2CoordMaterialGroupFactory_Vtbl)).Create)(this, materialgroupid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DTexture2CoordMaterialGroupFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPrinting3DTextureResource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn TextureData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DTextureResource", "TextureData", 6usize, |this| ((**(this as *const *const IPrinting3DTextureResource_Vtbl)).TextureData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetTextureData(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DTextureResource", "SetTextureData", 7usize, |this| ((**(this as *const *const IPrinting3DTextureResource_Vtbl)).SetTextureData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DTextureResource", "Name", 8usize, |this| ((**(this as *const *const IPrinting3DTextureResource_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DTextureResource", "SetName", 9usize, |this| ((**(this as *const *const IPrinting3DTextureResource_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            TextureData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            TextureData: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetTextureData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetTextureData: 0,
+            Name,
+            SetName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DTextureResource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
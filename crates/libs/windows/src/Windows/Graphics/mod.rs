@@ -31,7 +31,8 @@ pub struct IGeometrySource2D_Vtbl {
     pub base__: windows_core::IInspectable_Vtbl,
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DisplayAdapterId {
     pub LowPart: u32,
     pub HighPart: i32,
@@ -48,7 +49,8 @@ impl Default for DisplayAdapterId {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DisplayId {
     pub Value: u64,
 }
@@ -64,7 +66,8 @@ impl Default for DisplayId {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PointInt32 {
     pub X: i32,
     pub Y: i32,
@@ -81,7 +84,8 @@ impl Default for PointInt32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RectInt32 {
     pub X: i32,
     pub Y: i32,
@@ -100,7 +104,8 @@ impl Default for RectInt32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SizeInt32 {
     pub Width: i32,
     pub Height: i32,
@@ -118,3 +123,5 @@ impl Default for SizeInt32 {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
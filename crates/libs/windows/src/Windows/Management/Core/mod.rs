@@ -48,3 +48,5 @@ impl windows_core::RuntimeName for ApplicationDataManager {
 }
 unsafe impl Send for ApplicationDataManager {}
 unsafe impl Sync for ApplicationDataManager {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -66,3 +66,5 @@ impl windows_core::RuntimeName for InstalledClassicAppInfo {
 }
 unsafe impl Send for InstalledClassicAppInfo {}
 unsafe impl Sync for InstalledClassicAppInfo {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
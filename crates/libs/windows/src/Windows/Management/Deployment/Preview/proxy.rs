@@ -0,0 +1,27 @@
+impl windows_core::imp::ProxyVtable for IClassicAppManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindInstalledApp(this: *mut core::ffi::c_void, appuninstallkey: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClassicAppManagerStatics", "FindInstalledApp", 6usize, |this| ((**(this as *const *const IClassicAppManagerStatics_Vtbl)).FindInstalledApp)(this, appuninstallkey, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindInstalledApp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClassicAppManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInstalledClassicAppInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstalledClassicAppInfo", "DisplayName", 6usize, |this| ((**(this as *const *const IInstalledClassicAppInfo_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayVersion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstalledClassicAppInfo", "DisplayVersion", 7usize, |this| ((**(this as *const *const IInstalledClassicAppInfo_Vtbl)).DisplayVersion)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayName, DisplayVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInstalledClassicAppInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
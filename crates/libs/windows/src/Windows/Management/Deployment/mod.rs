@@ -3136,6 +3136,7 @@ impl AddPackageByAppInstallerOptions {
 impl windows_core::TypeKind for AddPackageByAppInstallerOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AddPackageByAppInstallerOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AddPackageByAppInstallerOptions").field(&self.0).finish()
@@ -3194,6 +3195,7 @@ impl DeploymentOptions {
 impl windows_core::TypeKind for DeploymentOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeploymentOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeploymentOptions").field(&self.0).finish()
@@ -3245,6 +3247,7 @@ impl DeploymentProgressState {
 impl windows_core::TypeKind for DeploymentProgressState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeploymentProgressState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeploymentProgressState").field(&self.0).finish()
@@ -3265,6 +3268,7 @@ impl PackageInstallState {
 impl windows_core::TypeKind for PackageInstallState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PackageInstallState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PackageInstallState").field(&self.0).finish()
@@ -3285,6 +3289,7 @@ impl PackageState {
 impl windows_core::TypeKind for PackageState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PackageState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PackageState").field(&self.0).finish()
@@ -3306,6 +3311,7 @@ impl PackageStatus {
 impl windows_core::TypeKind for PackageStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PackageStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PackageStatus").field(&self.0).finish()
@@ -3357,6 +3363,7 @@ impl PackageStubPreference {
 impl windows_core::TypeKind for PackageStubPreference {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PackageStubPreference {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PackageStubPreference").field(&self.0).finish()
@@ -3381,6 +3388,7 @@ impl PackageTypes {
 impl windows_core::TypeKind for PackageTypes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PackageTypes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PackageTypes").field(&self.0).finish()
@@ -3434,6 +3442,7 @@ impl RemovalOptions {
 impl windows_core::TypeKind for RemovalOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemovalOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemovalOptions").field(&self.0).finish()
@@ -3486,6 +3495,7 @@ impl SharedPackageContainerCreationCollisionOptions {
 impl windows_core::TypeKind for SharedPackageContainerCreationCollisionOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SharedPackageContainerCreationCollisionOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SharedPackageContainerCreationCollisionOptions").field(&self.0).finish()
@@ -3508,6 +3518,7 @@ impl SharedPackageContainerOperationStatus {
 impl windows_core::TypeKind for SharedPackageContainerOperationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SharedPackageContainerOperationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SharedPackageContainerOperationStatus").field(&self.0).finish()
@@ -3528,6 +3539,7 @@ impl StubPackageOption {
 impl windows_core::TypeKind for StubPackageOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StubPackageOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StubPackageOption").field(&self.0).finish()
@@ -3537,7 +3549,8 @@ impl windows_core::RuntimeType for StubPackageOption {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Management.Deployment.StubPackageOption;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DeploymentProgress {
     pub state: DeploymentProgressState,
     pub percentage: u32,
@@ -3553,3 +3566,5 @@ impl Default for DeploymentProgress {
         unsafe { core::mem::zeroed() }
     }
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
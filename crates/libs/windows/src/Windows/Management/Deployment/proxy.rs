@@ -0,0 +1,1727 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAddPackageOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DependencyPackageUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "DependencyPackageUris", 6usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).DependencyPackageUris)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetVolume(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "TargetVolume", 7usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).TargetVolume)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetVolume(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "SetTargetVolume", 8usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).SetTargetVolume)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn OptionalPackageFamilyNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "OptionalPackageFamilyNames", 9usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).OptionalPackageFamilyNames)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn OptionalPackageUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "OptionalPackageUris", 10usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).OptionalPackageUris)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RelatedPackageUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "RelatedPackageUris", 11usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).RelatedPackageUris)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExternalLocationUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "ExternalLocationUri", 12usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).ExternalLocationUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExternalLocationUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "SetExternalLocationUri", 13usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).SetExternalLocationUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StubPackageOption(this: *mut core::ffi::c_void, result__: *mut StubPackageOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "StubPackageOption", 14usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).StubPackageOption)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStubPackageOption(this: *mut core::ffi::c_void, value: StubPackageOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "SetStubPackageOption", 15usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).SetStubPackageOption)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeveloperMode(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "DeveloperMode", 16usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).DeveloperMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeveloperMode(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "SetDeveloperMode", 17usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).SetDeveloperMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceAppShutdown(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "ForceAppShutdown", 18usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).ForceAppShutdown)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForceAppShutdown(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "SetForceAppShutdown", 19usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).SetForceAppShutdown)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceTargetAppShutdown(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "ForceTargetAppShutdown", 20usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).ForceTargetAppShutdown)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForceTargetAppShutdown(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "SetForceTargetAppShutdown", 21usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).SetForceTargetAppShutdown)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceUpdateFromAnyVersion(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "ForceUpdateFromAnyVersion", 22usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).ForceUpdateFromAnyVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForceUpdateFromAnyVersion(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "SetForceUpdateFromAnyVersion", 23usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).SetForceUpdateFromAnyVersion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallAllResources(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "InstallAllResources", 24usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).InstallAllResources)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInstallAllResources(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "SetInstallAllResources", 25usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).SetInstallAllResources)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequiredContentGroupOnly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "RequiredContentGroupOnly", 26usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).RequiredContentGroupOnly)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequiredContentGroupOnly(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "SetRequiredContentGroupOnly", 27usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).SetRequiredContentGroupOnly)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetainFilesOnFailure(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "RetainFilesOnFailure", 28usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).RetainFilesOnFailure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRetainFilesOnFailure(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "SetRetainFilesOnFailure", 29usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).SetRetainFilesOnFailure)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StageInPlace(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "StageInPlace", 30usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).StageInPlace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStageInPlace(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "SetStageInPlace", 31usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).SetStageInPlace)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowUnsigned(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "AllowUnsigned", 32usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).AllowUnsigned)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowUnsigned(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "SetAllowUnsigned", 33usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).SetAllowUnsigned)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeferRegistrationWhenPackagesAreInUse(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "DeferRegistrationWhenPackagesAreInUse", 34usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).DeferRegistrationWhenPackagesAreInUse)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeferRegistrationWhenPackagesAreInUse(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions", "SetDeferRegistrationWhenPackagesAreInUse", 35usize, |this| ((**(this as *const *const IAddPackageOptions_Vtbl)).SetDeferRegistrationWhenPackagesAreInUse)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            DependencyPackageUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DependencyPackageUris: 0,
+            TargetVolume,
+            SetTargetVolume,
+            #[cfg(feature = "Foundation_Collections")]
+            OptionalPackageFamilyNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            OptionalPackageFamilyNames: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            OptionalPackageUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            OptionalPackageUris: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RelatedPackageUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RelatedPackageUris: 0,
+            ExternalLocationUri,
+            SetExternalLocationUri,
+            StubPackageOption,
+            SetStubPackageOption,
+            DeveloperMode,
+            SetDeveloperMode,
+            ForceAppShutdown,
+            SetForceAppShutdown,
+            ForceTargetAppShutdown,
+            SetForceTargetAppShutdown,
+            ForceUpdateFromAnyVersion,
+            SetForceUpdateFromAnyVersion,
+            InstallAllResources,
+            SetInstallAllResources,
+            RequiredContentGroupOnly,
+            SetRequiredContentGroupOnly,
+            RetainFilesOnFailure,
+            SetRetainFilesOnFailure,
+            StageInPlace,
+            SetStageInPlace,
+            AllowUnsigned,
+            SetAllowUnsigned,
+            DeferRegistrationWhenPackagesAreInUse,
+            SetDeferRegistrationWhenPackagesAreInUse,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAddPackageOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAddPackageOptions2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ExpectedDigests(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions2", "ExpectedDigests", 6usize, |this| ((**(this as *const *const IAddPackageOptions2_Vtbl)).ExpectedDigests)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LimitToExistingPackages(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions2", "LimitToExistingPackages", 7usize, |this| ((**(this as *const *const IAddPackageOptions2_Vtbl)).LimitToExistingPackages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLimitToExistingPackages(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddPackageOptions2", "SetLimitToExistingPackages", 8usize, |this| ((**(this as *const *const IAddPackageOptions2_Vtbl)).SetLimitToExistingPackages)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ExpectedDigests,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ExpectedDigests: 0,
+            LimitToExistingPackages,
+            SetLimitToExistingPackages,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAddPackageOptions2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppInstallerManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAutoUpdateSettings(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, appinstallerinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerManager", "SetAutoUpdateSettings", 6usize, |this| ((**(this as *const *const IAppInstallerManager_Vtbl)).SetAutoUpdateSettings)(this, packagefamilyname, appinstallerinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearAutoUpdateSettings(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerManager", "ClearAutoUpdateSettings", 7usize, |this| ((**(this as *const *const IAppInstallerManager_Vtbl)).ClearAutoUpdateSettings)(this, packagefamilyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn PauseAutoUpdatesUntil(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, datetime: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerManager", "PauseAutoUpdatesUntil", 8usize, |this| ((**(this as *const *const IAppInstallerManager_Vtbl)).PauseAutoUpdatesUntil)(this, packagefamilyname, datetime), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAutoUpdateSettings,
+            ClearAutoUpdateSettings,
+            PauseAutoUpdatesUntil,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppInstallerManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppInstallerManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerManagerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IAppInstallerManagerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppInstallerManagerStatics", "GetForSystem", 7usize, |this| ((**(this as *const *const IAppInstallerManagerStatics_Vtbl)).GetForSystem)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault, GetForSystem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppInstallerManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IAutoUpdateSettingsOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, result__: *mut super::super::ApplicationModel::PackageVersion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "Version", 6usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).Version)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn SetVersion(this: *mut core::ffi::c_void, value: super::super::ApplicationModel::PackageVersion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "SetVersion", 7usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).SetVersion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppInstallerUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "AppInstallerUri", 8usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).AppInstallerUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppInstallerUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "SetAppInstallerUri", 9usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).SetAppInstallerUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLaunch(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "OnLaunch", 10usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).OnLaunch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOnLaunch(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "SetOnLaunch", 11usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).SetOnLaunch)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoursBetweenUpdateChecks(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "HoursBetweenUpdateChecks", 12usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).HoursBetweenUpdateChecks)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHoursBetweenUpdateChecks(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "SetHoursBetweenUpdateChecks", 13usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).SetHoursBetweenUpdateChecks)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowPrompt(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "ShowPrompt", 14usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).ShowPrompt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowPrompt(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "SetShowPrompt", 15usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).SetShowPrompt)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateBlocksActivation(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "UpdateBlocksActivation", 16usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).UpdateBlocksActivation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUpdateBlocksActivation(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "SetUpdateBlocksActivation", 17usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).SetUpdateBlocksActivation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutomaticBackgroundTask(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "AutomaticBackgroundTask", 18usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).AutomaticBackgroundTask)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutomaticBackgroundTask(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "SetAutomaticBackgroundTask", 19usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).SetAutomaticBackgroundTask)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceUpdateFromAnyVersion(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "ForceUpdateFromAnyVersion", 20usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).ForceUpdateFromAnyVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForceUpdateFromAnyVersion(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "SetForceUpdateFromAnyVersion", 21usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).SetForceUpdateFromAnyVersion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAutoRepairEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "IsAutoRepairEnabled", 22usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).IsAutoRepairEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsAutoRepairEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "SetIsAutoRepairEnabled", 23usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).SetIsAutoRepairEnabled)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UpdateUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "UpdateUris", 24usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).UpdateUris)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RepairUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "RepairUris", 25usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).RepairUris)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DependencyPackageUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "DependencyPackageUris", 26usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).DependencyPackageUris)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn OptionalPackageUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptions", "OptionalPackageUris", 27usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptions_Vtbl)).OptionalPackageUris)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel")]
+            Version,
+            #[cfg(not(feature = "ApplicationModel"))]
+            Version: 0,
+            #[cfg(feature = "ApplicationModel")]
+            SetVersion,
+            #[cfg(not(feature = "ApplicationModel"))]
+            SetVersion: 0,
+            AppInstallerUri,
+            SetAppInstallerUri,
+            OnLaunch,
+            SetOnLaunch,
+            HoursBetweenUpdateChecks,
+            SetHoursBetweenUpdateChecks,
+            ShowPrompt,
+            SetShowPrompt,
+            UpdateBlocksActivation,
+            SetUpdateBlocksActivation,
+            AutomaticBackgroundTask,
+            SetAutomaticBackgroundTask,
+            ForceUpdateFromAnyVersion,
+            SetForceUpdateFromAnyVersion,
+            IsAutoRepairEnabled,
+            SetIsAutoRepairEnabled,
+            #[cfg(feature = "Foundation_Collections")]
+            UpdateUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UpdateUris: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RepairUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RepairUris: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            DependencyPackageUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DependencyPackageUris: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            OptionalPackageUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            OptionalPackageUris: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAutoUpdateSettingsOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel")]
+impl windows_core::imp::ProxyVtable for IAutoUpdateSettingsOptionsStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn CreateFromAppInstallerInfo(this: *mut core::ffi::c_void, appinstallerinfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoUpdateSettingsOptionsStatics", "CreateFromAppInstallerInfo", 6usize, |this| ((**(this as *const *const IAutoUpdateSettingsOptionsStatics_Vtbl)).CreateFromAppInstallerInfo)(this, appinstallerinfo, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel")]
+            CreateFromAppInstallerInfo,
+            #[cfg(not(feature = "ApplicationModel"))]
+            CreateFromAppInstallerInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAutoUpdateSettingsOptionsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICreateSharedPackageContainerOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Members(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateSharedPackageContainerOptions", "Members", 6usize, |this| ((**(this as *const *const ICreateSharedPackageContainerOptions_Vtbl)).Members)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceAppShutdown(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateSharedPackageContainerOptions", "ForceAppShutdown", 7usize, |this| ((**(this as *const *const ICreateSharedPackageContainerOptions_Vtbl)).ForceAppShutdown)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForceAppShutdown(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateSharedPackageContainerOptions", "SetForceAppShutdown", 8usize, |this| ((**(this as *const *const ICreateSharedPackageContainerOptions_Vtbl)).SetForceAppShutdown)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCollisionOption(this: *mut core::ffi::c_void, result__: *mut SharedPackageContainerCreationCollisionOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateSharedPackageContainerOptions", "CreateCollisionOption", 9usize, |this| ((**(this as *const *const ICreateSharedPackageContainerOptions_Vtbl)).CreateCollisionOption)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCreateCollisionOption(this: *mut core::ffi::c_void, value: SharedPackageContainerCreationCollisionOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateSharedPackageContainerOptions", "SetCreateCollisionOption", 10usize, |this| ((**(this as *const *const ICreateSharedPackageContainerOptions_Vtbl)).SetCreateCollisionOption)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Members,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Members: 0,
+            ForceAppShutdown,
+            SetForceAppShutdown,
+            CreateCollisionOption,
+            SetCreateCollisionOption,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateSharedPackageContainerOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateSharedPackageContainerResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Container(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateSharedPackageContainerResult", "Container", 6usize, |this| ((**(this as *const *const ICreateSharedPackageContainerResult_Vtbl)).Container)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut SharedPackageContainerOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateSharedPackageContainerResult", "Status", 7usize, |this| ((**(this as *const *const ICreateSharedPackageContainerResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateSharedPackageContainerResult", "ExtendedError", 8usize, |this| ((**(this as *const *const ICreateSharedPackageContainerResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Container, Status, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateSharedPackageContainerResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeleteSharedPackageContainerOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ForceAppShutdown(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeleteSharedPackageContainerOptions", "ForceAppShutdown", 6usize, |this| ((**(this as *const *const IDeleteSharedPackageContainerOptions_Vtbl)).ForceAppShutdown)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForceAppShutdown(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeleteSharedPackageContainerOptions", "SetForceAppShutdown", 7usize, |this| ((**(this as *const *const IDeleteSharedPackageContainerOptions_Vtbl)).SetForceAppShutdown)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllUsers(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeleteSharedPackageContainerOptions", "AllUsers", 8usize, |this| ((**(this as *const *const IDeleteSharedPackageContainerOptions_Vtbl)).AllUsers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllUsers(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeleteSharedPackageContainerOptions", "SetAllUsers", 9usize, |this| ((**(this as *const *const IDeleteSharedPackageContainerOptions_Vtbl)).SetAllUsers)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ForceAppShutdown, SetForceAppShutdown, AllUsers, SetAllUsers }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeleteSharedPackageContainerOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeleteSharedPackageContainerResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut SharedPackageContainerOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeleteSharedPackageContainerResult", "Status", 6usize, |this| ((**(this as *const *const IDeleteSharedPackageContainerResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeleteSharedPackageContainerResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IDeleteSharedPackageContainerResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeleteSharedPackageContainerResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeploymentResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ErrorText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentResult", "ErrorText", 6usize, |this| ((**(this as *const *const IDeploymentResult_Vtbl)).ErrorText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivityId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentResult", "ActivityId", 7usize, |this| ((**(this as *const *const IDeploymentResult_Vtbl)).ActivityId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedErrorCode(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentResult", "ExtendedErrorCode", 8usize, |this| ((**(this as *const *const IDeploymentResult_Vtbl)).ExtendedErrorCode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ErrorText, ActivityId, ExtendedErrorCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeploymentResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeploymentResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsRegistered(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentResult2", "IsRegistered", 6usize, |this| ((**(this as *const *const IDeploymentResult2_Vtbl)).IsRegistered)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsRegistered }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeploymentResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFindSharedPackageContainerOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindSharedPackageContainerOptions", "Name", 6usize, |this| ((**(this as *const *const IFindSharedPackageContainerOptions_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindSharedPackageContainerOptions", "SetName", 7usize, |this| ((**(this as *const *const IFindSharedPackageContainerOptions_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindSharedPackageContainerOptions", "PackageFamilyName", 8usize, |this| ((**(this as *const *const IFindSharedPackageContainerOptions_Vtbl)).PackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPackageFamilyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindSharedPackageContainerOptions", "SetPackageFamilyName", 9usize, |this| ((**(this as *const *const IFindSharedPackageContainerOptions_Vtbl)).SetPackageFamilyName)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, SetName, PackageFamilyName, SetPackageFamilyName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFindSharedPackageContainerOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPackageAllUserProvisioningOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn OptionalPackageFamilyNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageAllUserProvisioningOptions", "OptionalPackageFamilyNames", 6usize, |this| ((**(this as *const *const IPackageAllUserProvisioningOptions_Vtbl)).OptionalPackageFamilyNames)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ProjectionOrderPackageFamilyNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageAllUserProvisioningOptions", "ProjectionOrderPackageFamilyNames", 7usize, |this| ((**(this as *const *const IPackageAllUserProvisioningOptions_Vtbl)).ProjectionOrderPackageFamilyNames)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            OptionalPackageFamilyNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            OptionalPackageFamilyNames: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ProjectionOrderPackageFamilyNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ProjectionOrderPackageFamilyNames: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageAllUserProvisioningOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageAllUserProvisioningOptions2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeferAutomaticRegistration(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageAllUserProvisioningOptions2", "DeferAutomaticRegistration", 6usize, |this| ((**(this as *const *const IPackageAllUserProvisioningOptions2_Vtbl)).DeferAutomaticRegistration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeferAutomaticRegistration(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageAllUserProvisioningOptions2", "SetDeferAutomaticRegistration", 7usize, |this| ((**(this as *const *const IPackageAllUserProvisioningOptions2_Vtbl)).SetDeferAutomaticRegistration)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeferAutomaticRegistration, SetDeferAutomaticRegistration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageAllUserProvisioningOptions2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IPackageManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AddPackageAsync(this: *mut core::ffi::c_void, packageuri: *mut core::ffi::c_void, dependencypackageuris: *mut core::ffi::c_void, deploymentoptions: DeploymentOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "AddPackageAsync", 6usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).AddPackageAsync)(this, packageuri, dependencypackageuris, deploymentoptions, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UpdatePackageAsync(this: *mut core::ffi::c_void, packageuri: *mut core::ffi::c_void, dependencypackageuris: *mut core::ffi::c_void, deploymentoptions: DeploymentOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "UpdatePackageAsync", 7usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).UpdatePackageAsync)(this, packageuri, dependencypackageuris, deploymentoptions, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageAsync(this: *mut core::ffi::c_void, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "RemovePackageAsync", 8usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).RemovePackageAsync)(this, packagefullname, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StagePackageAsync(this: *mut core::ffi::c_void, packageuri: *mut core::ffi::c_void, dependencypackageuris: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "StagePackageAsync", 9usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).StagePackageAsync)(this, packageuri, dependencypackageuris, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RegisterPackageAsync(this: *mut core::ffi::c_void, manifesturi: *mut core::ffi::c_void, dependencypackageuris: *mut core::ffi::c_void, deploymentoptions: DeploymentOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "RegisterPackageAsync", 10usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).RegisterPackageAsync)(this, manifesturi, dependencypackageuris, deploymentoptions, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "FindPackages", 11usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).FindPackages)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByUserSecurityId(this: *mut core::ffi::c_void, usersecurityid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "FindPackagesByUserSecurityId", 12usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).FindPackagesByUserSecurityId)(this, usersecurityid, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByNamePublisher(this: *mut core::ffi::c_void, packagename: core::mem::MaybeUninit<windows_core::HSTRING>, packagepublisher: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "FindPackagesByNamePublisher", 13usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).FindPackagesByNamePublisher)(this, packagename, packagepublisher, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByUserSecurityIdNamePublisher(this: *mut core::ffi::c_void, usersecurityid: core::mem::MaybeUninit<windows_core::HSTRING>, packagename: core::mem::MaybeUninit<windows_core::HSTRING>, packagepublisher: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "FindPackagesByUserSecurityIdNamePublisher", 14usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).FindPackagesByUserSecurityIdNamePublisher)(this, usersecurityid, packagename, packagepublisher, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindUsers(this: *mut core::ffi::c_void, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "FindUsers", 15usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).FindUsers)(this, packagefullname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPackageState(this: *mut core::ffi::c_void, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, packagestate: PackageState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "SetPackageState", 16usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).SetPackageState)(this, packagefullname, packagestate), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn FindPackageByPackageFullName(this: *mut core::ffi::c_void, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "FindPackageByPackageFullName", 17usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).FindPackageByPackageFullName)(this, packagefullname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CleanupPackageForUserAsync(this: *mut core::ffi::c_void, packagename: core::mem::MaybeUninit<windows_core::HSTRING>, usersecurityid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "CleanupPackageForUserAsync", 18usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).CleanupPackageForUserAsync)(this, packagename, usersecurityid, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByPackageFamilyName(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "FindPackagesByPackageFamilyName", 19usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).FindPackagesByPackageFamilyName)(this, packagefamilyname, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByUserSecurityIdPackageFamilyName(this: *mut core::ffi::c_void, usersecurityid: core::mem::MaybeUninit<windows_core::HSTRING>, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "FindPackagesByUserSecurityIdPackageFamilyName", 20usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).FindPackagesByUserSecurityIdPackageFamilyName)(this, usersecurityid, packagefamilyname, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn FindPackageByUserSecurityIdPackageFullName(this: *mut core::ffi::c_void, usersecurityid: core::mem::MaybeUninit<windows_core::HSTRING>, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager", "FindPackageByUserSecurityIdPackageFullName", 21usize, |this| ((**(this as *const *const IPackageManager_Vtbl)).FindPackageByUserSecurityIdPackageFullName)(this, usersecurityid, packagefullname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AddPackageAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AddPackageAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            UpdatePackageAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UpdatePackageAsync: 0,
+            RemovePackageAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            StagePackageAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StagePackageAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RegisterPackageAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RegisterPackageAsync: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackages,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackages: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByUserSecurityId,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByUserSecurityId: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByNamePublisher,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByNamePublisher: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByUserSecurityIdNamePublisher,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByUserSecurityIdNamePublisher: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindUsers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindUsers: 0,
+            SetPackageState,
+            #[cfg(feature = "ApplicationModel")]
+            FindPackageByPackageFullName,
+            #[cfg(not(feature = "ApplicationModel"))]
+            FindPackageByPackageFullName: 0,
+            CleanupPackageForUserAsync,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByPackageFamilyName,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByPackageFamilyName: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByUserSecurityIdPackageFamilyName,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByUserSecurityIdPackageFamilyName: 0,
+            #[cfg(feature = "ApplicationModel")]
+            FindPackageByUserSecurityIdPackageFullName,
+            #[cfg(not(feature = "ApplicationModel"))]
+            FindPackageByUserSecurityIdPackageFullName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageManager10_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProvisionPackageForAllUsersWithOptionsAsync(this: *mut core::ffi::c_void, mainpackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager10", "ProvisionPackageForAllUsersWithOptionsAsync", 6usize, |this| ((**(this as *const *const IPackageManager10_Vtbl)).ProvisionPackageForAllUsersWithOptionsAsync)(this, mainpackagefamilyname, options, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProvisionPackageForAllUsersWithOptionsAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageManager10 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageManager11_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemovePackageByUriAsync(this: *mut core::ffi::c_void, packageuri: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager11", "RemovePackageByUriAsync", 6usize, |this| ((**(this as *const *const IPackageManager11_Vtbl)).RemovePackageByUriAsync)(this, packageuri, options, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RemovePackageByUriAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageManager11 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IPackageManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemovePackageWithOptionsAsync(this: *mut core::ffi::c_void, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, removaloptions: RemovalOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager2", "RemovePackageWithOptionsAsync", 6usize, |this| ((**(this as *const *const IPackageManager2_Vtbl)).RemovePackageWithOptionsAsync)(this, packagefullname, removaloptions, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StagePackageWithOptionsAsync(this: *mut core::ffi::c_void, packageuri: *mut core::ffi::c_void, dependencypackageuris: *mut core::ffi::c_void, deploymentoptions: DeploymentOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager2", "StagePackageWithOptionsAsync", 7usize, |this| ((**(this as *const *const IPackageManager2_Vtbl)).StagePackageWithOptionsAsync)(this, packageuri, dependencypackageuris, deploymentoptions, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RegisterPackageByFullNameAsync(this: *mut core::ffi::c_void, mainpackagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, dependencypackagefullnames: *mut core::ffi::c_void, deploymentoptions: DeploymentOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager2", "RegisterPackageByFullNameAsync", 8usize, |this| ((**(this as *const *const IPackageManager2_Vtbl)).RegisterPackageByFullNameAsync)(this, mainpackagefullname, dependencypackagefullnames, deploymentoptions, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesWithPackageTypes(this: *mut core::ffi::c_void, packagetypes: PackageTypes, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager2", "FindPackagesWithPackageTypes", 9usize, |this| ((**(this as *const *const IPackageManager2_Vtbl)).FindPackagesWithPackageTypes)(this, packagetypes, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByUserSecurityIdWithPackageTypes(this: *mut core::ffi::c_void, usersecurityid: core::mem::MaybeUninit<windows_core::HSTRING>, packagetypes: PackageTypes, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager2", "FindPackagesByUserSecurityIdWithPackageTypes", 10usize, |this| ((**(this as *const *const IPackageManager2_Vtbl)).FindPackagesByUserSecurityIdWithPackageTypes)(this, usersecurityid, packagetypes, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByNamePublisherWithPackageTypes(this: *mut core::ffi::c_void, packagename: core::mem::MaybeUninit<windows_core::HSTRING>, packagepublisher: core::mem::MaybeUninit<windows_core::HSTRING>, packagetypes: PackageTypes, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager2", "FindPackagesByNamePublisherWithPackageTypes", 11usize, |this| ((**(this as *const *const IPackageManager2_Vtbl)).FindPackagesByNamePublisherWithPackageTypes)(this, packagename, packagepublisher, packagetypes, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByUserSecurityIdNamePublisherWithPackageTypes(this: *mut core::ffi::c_void, usersecurityid: core::mem::MaybeUninit<windows_core::HSTRING>, packagename: core::mem::MaybeUninit<windows_core::HSTRING>, packagepublisher: core::mem::MaybeUninit<windows_core::HSTRING>, packagetypes: PackageTypes, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager2", "FindPackagesByUserSecurityIdNamePublisherWithPackageTypes", 12usize, |this| ((**(this as *const *const IPackageManager2_Vtbl)).FindPackagesByUserSecurityIdNamePublisherWithPackageTypes)(this, usersecurityid, packagename, packagepublisher, packagetypes, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByPackageFamilyNameWithPackageTypes(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, packagetypes: PackageTypes, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager2", "FindPackagesByPackageFamilyNameWithPackageTypes", 13usize, |this| ((**(this as *const *const IPackageManager2_Vtbl)).FindPackagesByPackageFamilyNameWithPackageTypes)(this, packagefamilyname, packagetypes, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByUserSecurityIdPackageFamilyNameWithPackageTypes(this: *mut core::ffi::c_void, usersecurityid: core::mem::MaybeUninit<windows_core::HSTRING>, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, packagetypes: PackageTypes, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager2", "FindPackagesByUserSecurityIdPackageFamilyNameWithPackageTypes", 14usize, |this| ((**(this as *const *const IPackageManager2_Vtbl)).FindPackagesByUserSecurityIdPackageFamilyNameWithPackageTypes)(this, usersecurityid, packagefamilyname, packagetypes, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StageUserDataAsync(this: *mut core::ffi::c_void, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager2", "StageUserDataAsync", 15usize, |this| ((**(this as *const *const IPackageManager2_Vtbl)).StageUserDataAsync)(this, packagefullname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RemovePackageWithOptionsAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            StagePackageWithOptionsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StagePackageWithOptionsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RegisterPackageByFullNameAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RegisterPackageByFullNameAsync: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesWithPackageTypes,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesWithPackageTypes: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByUserSecurityIdWithPackageTypes,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByUserSecurityIdWithPackageTypes: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByNamePublisherWithPackageTypes,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByNamePublisherWithPackageTypes: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByUserSecurityIdNamePublisherWithPackageTypes,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByUserSecurityIdNamePublisherWithPackageTypes: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByPackageFamilyNameWithPackageTypes,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByPackageFamilyNameWithPackageTypes: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByUserSecurityIdPackageFamilyNameWithPackageTypes,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByUserSecurityIdPackageFamilyNameWithPackageTypes: 0,
+            StageUserDataAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPackageManager3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddPackageVolumeAsync(this: *mut core::ffi::c_void, packagestorepath: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager3", "AddPackageVolumeAsync", 6usize, |this| ((**(this as *const *const IPackageManager3_Vtbl)).AddPackageVolumeAsync)(this, packagestorepath, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AddPackageToVolumeAsync(this: *mut core::ffi::c_void, packageuri: *mut core::ffi::c_void, dependencypackageuris: *mut core::ffi::c_void, deploymentoptions: DeploymentOptions, targetvolume: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager3", "AddPackageToVolumeAsync", 7usize, |this| ((**(this as *const *const IPackageManager3_Vtbl)).AddPackageToVolumeAsync)(this, packageuri, dependencypackageuris, deploymentoptions, targetvolume, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearPackageStatus(this: *mut core::ffi::c_void, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, status: PackageStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager3", "ClearPackageStatus", 8usize, |this| ((**(this as *const *const IPackageManager3_Vtbl)).ClearPackageStatus)(this, packagefullname, status), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RegisterPackageWithAppDataVolumeAsync(this: *mut core::ffi::c_void, manifesturi: *mut core::ffi::c_void, dependencypackageuris: *mut core::ffi::c_void, deploymentoptions: DeploymentOptions, appdatavolume: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager3", "RegisterPackageWithAppDataVolumeAsync", 9usize, |this| ((**(this as *const *const IPackageManager3_Vtbl)).RegisterPackageWithAppDataVolumeAsync)(this, manifesturi, dependencypackageuris, deploymentoptions, appdatavolume, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindPackageVolumeByName(this: *mut core::ffi::c_void, volumename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager3", "FindPackageVolumeByName", 10usize, |this| ((**(this as *const *const IPackageManager3_Vtbl)).FindPackageVolumeByName)(this, volumename, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindPackageVolumes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager3", "FindPackageVolumes", 11usize, |this| ((**(this as *const *const IPackageManager3_Vtbl)).FindPackageVolumes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultPackageVolume(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager3", "GetDefaultPackageVolume", 12usize, |this| ((**(this as *const *const IPackageManager3_Vtbl)).GetDefaultPackageVolume)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MovePackageToVolumeAsync(this: *mut core::ffi::c_void, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, deploymentoptions: DeploymentOptions, targetvolume: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager3", "MovePackageToVolumeAsync", 13usize, |this| ((**(this as *const *const IPackageManager3_Vtbl)).MovePackageToVolumeAsync)(this, packagefullname, deploymentoptions, targetvolume, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageVolumeAsync(this: *mut core::ffi::c_void, volume: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager3", "RemovePackageVolumeAsync", 14usize, |this| ((**(this as *const *const IPackageManager3_Vtbl)).RemovePackageVolumeAsync)(this, volume, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultPackageVolume(this: *mut core::ffi::c_void, volume: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager3", "SetDefaultPackageVolume", 15usize, |this| ((**(this as *const *const IPackageManager3_Vtbl)).SetDefaultPackageVolume)(this, volume), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPackageStatus(this: *mut core::ffi::c_void, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, status: PackageStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager3", "SetPackageStatus", 16usize, |this| ((**(this as *const *const IPackageManager3_Vtbl)).SetPackageStatus)(this, packagefullname, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPackageVolumeOfflineAsync(this: *mut core::ffi::c_void, packagevolume: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager3", "SetPackageVolumeOfflineAsync", 17usize, |this| ((**(this as *const *const IPackageManager3_Vtbl)).SetPackageVolumeOfflineAsync)(this, packagevolume, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPackageVolumeOnlineAsync(this: *mut core::ffi::c_void, packagevolume: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager3", "SetPackageVolumeOnlineAsync", 18usize, |this| ((**(this as *const *const IPackageManager3_Vtbl)).SetPackageVolumeOnlineAsync)(this, packagevolume, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StagePackageToVolumeAsync(this: *mut core::ffi::c_void, packageuri: *mut core::ffi::c_void, dependencypackageuris: *mut core::ffi::c_void, deploymentoptions: DeploymentOptions, targetvolume: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager3", "StagePackageToVolumeAsync", 19usize, |this| ((**(this as *const *const IPackageManager3_Vtbl)).StagePackageToVolumeAsync)(this, packageuri, dependencypackageuris, deploymentoptions, targetvolume, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StageUserDataWithOptionsAsync(this: *mut core::ffi::c_void, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, deploymentoptions: DeploymentOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager3", "StageUserDataWithOptionsAsync", 20usize, |this| ((**(this as *const *const IPackageManager3_Vtbl)).StageUserDataWithOptionsAsync)(this, packagefullname, deploymentoptions, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddPackageVolumeAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            AddPackageToVolumeAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AddPackageToVolumeAsync: 0,
+            ClearPackageStatus,
+            #[cfg(feature = "Foundation_Collections")]
+            RegisterPackageWithAppDataVolumeAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RegisterPackageWithAppDataVolumeAsync: 0,
+            FindPackageVolumeByName,
+            #[cfg(feature = "Foundation_Collections")]
+            FindPackageVolumes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindPackageVolumes: 0,
+            GetDefaultPackageVolume,
+            MovePackageToVolumeAsync,
+            RemovePackageVolumeAsync,
+            SetDefaultPackageVolume,
+            SetPackageStatus,
+            SetPackageVolumeOfflineAsync,
+            SetPackageVolumeOnlineAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            StagePackageToVolumeAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StagePackageToVolumeAsync: 0,
+            StageUserDataWithOptionsAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageManager3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPackageManager4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetPackageVolumesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager4", "GetPackageVolumesAsync", 6usize, |this| ((**(this as *const *const IPackageManager4_Vtbl)).GetPackageVolumesAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetPackageVolumesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetPackageVolumesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageManager4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPackageManager5_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AddPackageToVolumeAndOptionalPackagesAsync(this: *mut core::ffi::c_void, packageuri: *mut core::ffi::c_void, dependencypackageuris: *mut core::ffi::c_void, deploymentoptions: DeploymentOptions, targetvolume: *mut core::ffi::c_void, optionalpackagefamilynames: *mut core::ffi::c_void, externalpackageuris: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager5", "AddPackageToVolumeAndOptionalPackagesAsync", 6usize, |this| ((**(this as *const *const IPackageManager5_Vtbl)).AddPackageToVolumeAndOptionalPackagesAsync)(this, packageuri, dependencypackageuris, deploymentoptions, targetvolume, optionalpackagefamilynames, externalpackageuris, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StagePackageToVolumeAndOptionalPackagesAsync(this: *mut core::ffi::c_void, packageuri: *mut core::ffi::c_void, dependencypackageuris: *mut core::ffi::c_void, deploymentoptions: DeploymentOptions, targetvolume: *mut core::ffi::c_void, optionalpackagefamilynames: *mut core::ffi::c_void, externalpackageuris: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager5", "StagePackageToVolumeAndOptionalPackagesAsync", 7usize, |this| ((**(this as *const *const IPackageManager5_Vtbl)).StagePackageToVolumeAndOptionalPackagesAsync)(this, packageuri, dependencypackageuris, deploymentoptions, targetvolume, optionalpackagefamilynames, externalpackageuris, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RegisterPackageByFamilyNameAndOptionalPackagesAsync(this: *mut core::ffi::c_void, mainpackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, dependencypackagefamilynames: *mut core::ffi::c_void, deploymentoptions: DeploymentOptions, appdatavolume: *mut core::ffi::c_void, optionalpackagefamilynames: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager5", "RegisterPackageByFamilyNameAndOptionalPackagesAsync", 8usize, |this| ((**(this as *const *const IPackageManager5_Vtbl)).RegisterPackageByFamilyNameAndOptionalPackagesAsync)(this, mainpackagefamilyname, dependencypackagefamilynames, deploymentoptions, appdatavolume, optionalpackagefamilynames, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DebugSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager5", "DebugSettings", 9usize, |this| ((**(this as *const *const IPackageManager5_Vtbl)).DebugSettings)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AddPackageToVolumeAndOptionalPackagesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AddPackageToVolumeAndOptionalPackagesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            StagePackageToVolumeAndOptionalPackagesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StagePackageToVolumeAndOptionalPackagesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RegisterPackageByFamilyNameAndOptionalPackagesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RegisterPackageByFamilyNameAndOptionalPackagesAsync: 0,
+            DebugSettings,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageManager5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPackageManager6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProvisionPackageForAllUsersAsync(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager6", "ProvisionPackageForAllUsersAsync", 6usize, |this| ((**(this as *const *const IPackageManager6_Vtbl)).ProvisionPackageForAllUsersAsync)(this, packagefamilyname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPackageByAppInstallerFileAsync(this: *mut core::ffi::c_void, appinstallerfileuri: *mut core::ffi::c_void, options: AddPackageByAppInstallerOptions, targetvolume: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager6", "AddPackageByAppInstallerFileAsync", 7usize, |this| ((**(this as *const *const IPackageManager6_Vtbl)).AddPackageByAppInstallerFileAsync)(this, appinstallerfileuri, options, targetvolume, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAddPackageByAppInstallerFileAsync(this: *mut core::ffi::c_void, appinstallerfileuri: *mut core::ffi::c_void, options: AddPackageByAppInstallerOptions, targetvolume: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager6", "RequestAddPackageByAppInstallerFileAsync", 8usize, |this| ((**(this as *const *const IPackageManager6_Vtbl)).RequestAddPackageByAppInstallerFileAsync)(this, appinstallerfileuri, options, targetvolume, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AddPackageToVolumeAndRelatedSetAsync(this: *mut core::ffi::c_void, packageuri: *mut core::ffi::c_void, dependencypackageuris: *mut core::ffi::c_void, options: DeploymentOptions, targetvolume: *mut core::ffi::c_void, optionalpackagefamilynames: *mut core::ffi::c_void, packageuristoinstall: *mut core::ffi::c_void, relatedpackageuris: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager6", "AddPackageToVolumeAndRelatedSetAsync", 9usize, |this| ((**(this as *const *const IPackageManager6_Vtbl)).AddPackageToVolumeAndRelatedSetAsync)(this, packageuri, dependencypackageuris, options, targetvolume, optionalpackagefamilynames, packageuristoinstall, relatedpackageuris, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StagePackageToVolumeAndRelatedSetAsync(this: *mut core::ffi::c_void, packageuri: *mut core::ffi::c_void, dependencypackageuris: *mut core::ffi::c_void, options: DeploymentOptions, targetvolume: *mut core::ffi::c_void, optionalpackagefamilynames: *mut core::ffi::c_void, packageuristoinstall: *mut core::ffi::c_void, relatedpackageuris: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager6", "StagePackageToVolumeAndRelatedSetAsync", 10usize, |this| ((**(this as *const *const IPackageManager6_Vtbl)).StagePackageToVolumeAndRelatedSetAsync)(this, packageuri, dependencypackageuris, options, targetvolume, optionalpackagefamilynames, packageuristoinstall, relatedpackageuris, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestAddPackageAsync(this: *mut core::ffi::c_void, packageuri: *mut core::ffi::c_void, dependencypackageuris: *mut core::ffi::c_void, deploymentoptions: DeploymentOptions, targetvolume: *mut core::ffi::c_void, optionalpackagefamilynames: *mut core::ffi::c_void, relatedpackageuris: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager6", "RequestAddPackageAsync", 11usize, |this| ((**(this as *const *const IPackageManager6_Vtbl)).RequestAddPackageAsync)(this, packageuri, dependencypackageuris, deploymentoptions, targetvolume, optionalpackagefamilynames, relatedpackageuris, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProvisionPackageForAllUsersAsync,
+            AddPackageByAppInstallerFileAsync,
+            RequestAddPackageByAppInstallerFileAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            AddPackageToVolumeAndRelatedSetAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AddPackageToVolumeAndRelatedSetAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            StagePackageToVolumeAndRelatedSetAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StagePackageToVolumeAndRelatedSetAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestAddPackageAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestAddPackageAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageManager6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPackageManager7_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestAddPackageAndRelatedSetAsync(this: *mut core::ffi::c_void, packageuri: *mut core::ffi::c_void, dependencypackageuris: *mut core::ffi::c_void, deploymentoptions: DeploymentOptions, targetvolume: *mut core::ffi::c_void, optionalpackagefamilynames: *mut core::ffi::c_void, relatedpackageuris: *mut core::ffi::c_void, packageuristoinstall: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager7", "RequestAddPackageAndRelatedSetAsync", 6usize, |this| ((**(this as *const *const IPackageManager7_Vtbl)).RequestAddPackageAndRelatedSetAsync)(this, packageuri, dependencypackageuris, deploymentoptions, targetvolume, optionalpackagefamilynames, relatedpackageuris, packageuristoinstall, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestAddPackageAndRelatedSetAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestAddPackageAndRelatedSetAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageManager7 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageManager8_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeprovisionPackageForAllUsersAsync(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager8", "DeprovisionPackageForAllUsersAsync", 6usize, |this| ((**(this as *const *const IPackageManager8_Vtbl)).DeprovisionPackageForAllUsersAsync)(this, packagefamilyname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeprovisionPackageForAllUsersAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageManager8 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IPackageManager9_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindProvisionedPackages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager9", "FindProvisionedPackages", 6usize, |this| ((**(this as *const *const IPackageManager9_Vtbl)).FindProvisionedPackages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPackageByUriAsync(this: *mut core::ffi::c_void, packageuri: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager9", "AddPackageByUriAsync", 7usize, |this| ((**(this as *const *const IPackageManager9_Vtbl)).AddPackageByUriAsync)(this, packageuri, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StagePackageByUriAsync(this: *mut core::ffi::c_void, packageuri: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager9", "StagePackageByUriAsync", 8usize, |this| ((**(this as *const *const IPackageManager9_Vtbl)).StagePackageByUriAsync)(this, packageuri, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterPackageByUriAsync(this: *mut core::ffi::c_void, manifesturi: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager9", "RegisterPackageByUriAsync", 9usize, |this| ((**(this as *const *const IPackageManager9_Vtbl)).RegisterPackageByUriAsync)(this, manifesturi, options, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RegisterPackagesByFullNameAsync(this: *mut core::ffi::c_void, packagefullnames: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager9", "RegisterPackagesByFullNameAsync", 10usize, |this| ((**(this as *const *const IPackageManager9_Vtbl)).RegisterPackagesByFullNameAsync)(this, packagefullnames, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPackageStubPreference(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, usestub: PackageStubPreference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager9", "SetPackageStubPreference", 11usize, |this| ((**(this as *const *const IPackageManager9_Vtbl)).SetPackageStubPreference)(this, packagefamilyname, usestub), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPackageStubPreference(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut PackageStubPreference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManager9", "GetPackageStubPreference", 12usize, |this| ((**(this as *const *const IPackageManager9_Vtbl)).GetPackageStubPreference)(this, packagefamilyname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindProvisionedPackages,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindProvisionedPackages: 0,
+            AddPackageByUriAsync,
+            StagePackageByUriAsync,
+            RegisterPackageByUriAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            RegisterPackagesByFullNameAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RegisterPackagesByFullNameAsync: 0,
+            SetPackageStubPreference,
+            GetPackageStubPreference,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageManager9 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel")]
+impl windows_core::imp::ProxyVtable for IPackageManagerDebugSettings_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn SetContentGroupStateAsync(this: *mut core::ffi::c_void, package: *mut core::ffi::c_void, contentgroupname: core::mem::MaybeUninit<windows_core::HSTRING>, state: super::super::ApplicationModel::PackageContentGroupState, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManagerDebugSettings", "SetContentGroupStateAsync", 6usize, |this| ((**(this as *const *const IPackageManagerDebugSettings_Vtbl)).SetContentGroupStateAsync)(this, package, contentgroupname, state, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn SetContentGroupStateWithPercentageAsync(this: *mut core::ffi::c_void, package: *mut core::ffi::c_void, contentgroupname: core::mem::MaybeUninit<windows_core::HSTRING>, state: super::super::ApplicationModel::PackageContentGroupState, completionpercentage: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageManagerDebugSettings", "SetContentGroupStateWithPercentageAsync", 7usize, |this| ((**(this as *const *const IPackageManagerDebugSettings_Vtbl)).SetContentGroupStateWithPercentageAsync)(this, package, contentgroupname, state, completionpercentage, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel")]
+            SetContentGroupStateAsync,
+            #[cfg(not(feature = "ApplicationModel"))]
+            SetContentGroupStateAsync: 0,
+            #[cfg(feature = "ApplicationModel")]
+            SetContentGroupStateWithPercentageAsync,
+            #[cfg(not(feature = "ApplicationModel"))]
+            SetContentGroupStateWithPercentageAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageManagerDebugSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageUserInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserSecurityId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUserInformation", "UserSecurityId", 6usize, |this| ((**(this as *const *const IPackageUserInformation_Vtbl)).UserSecurityId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallState(this: *mut core::ffi::c_void, result__: *mut PackageInstallState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUserInformation", "InstallState", 7usize, |this| ((**(this as *const *const IPackageUserInformation_Vtbl)).InstallState)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserSecurityId, InstallState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageUserInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IPackageVolume_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsOffline(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "IsOffline", 6usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).IsOffline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSystemVolume(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "IsSystemVolume", 7usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).IsSystemVolume)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MountPoint(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "MountPoint", 8usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).MountPoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "Name", 9usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageStorePath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "PackageStorePath", 10usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).PackageStorePath)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportsHardLinks(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "SupportsHardLinks", 11usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).SupportsHardLinks)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "FindPackages", 12usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).FindPackages)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByNamePublisher(this: *mut core::ffi::c_void, packagename: core::mem::MaybeUninit<windows_core::HSTRING>, packagepublisher: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "FindPackagesByNamePublisher", 13usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).FindPackagesByNamePublisher)(this, packagename, packagepublisher, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByPackageFamilyName(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "FindPackagesByPackageFamilyName", 14usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).FindPackagesByPackageFamilyName)(this, packagefamilyname, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesWithPackageTypes(this: *mut core::ffi::c_void, packagetypes: PackageTypes, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "FindPackagesWithPackageTypes", 15usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).FindPackagesWithPackageTypes)(this, packagetypes, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByNamePublisherWithPackagesTypes(this: *mut core::ffi::c_void, packagetypes: PackageTypes, packagename: core::mem::MaybeUninit<windows_core::HSTRING>, packagepublisher: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "FindPackagesByNamePublisherWithPackagesTypes", 16usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).FindPackagesByNamePublisherWithPackagesTypes)(this, packagetypes, packagename, packagepublisher, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByPackageFamilyNameWithPackageTypes(this: *mut core::ffi::c_void, packagetypes: PackageTypes, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "FindPackagesByPackageFamilyNameWithPackageTypes", 17usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).FindPackagesByPackageFamilyNameWithPackageTypes)(this, packagetypes, packagefamilyname, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackageByPackageFullName(this: *mut core::ffi::c_void, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "FindPackageByPackageFullName", 18usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).FindPackageByPackageFullName)(this, packagefullname, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByUserSecurityId(this: *mut core::ffi::c_void, usersecurityid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "FindPackagesByUserSecurityId", 19usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).FindPackagesByUserSecurityId)(this, usersecurityid, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByUserSecurityIdNamePublisher(this: *mut core::ffi::c_void, usersecurityid: core::mem::MaybeUninit<windows_core::HSTRING>, packagename: core::mem::MaybeUninit<windows_core::HSTRING>, packagepublisher: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "FindPackagesByUserSecurityIdNamePublisher", 20usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).FindPackagesByUserSecurityIdNamePublisher)(this, usersecurityid, packagename, packagepublisher, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByUserSecurityIdPackageFamilyName(this: *mut core::ffi::c_void, usersecurityid: core::mem::MaybeUninit<windows_core::HSTRING>, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "FindPackagesByUserSecurityIdPackageFamilyName", 21usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).FindPackagesByUserSecurityIdPackageFamilyName)(this, usersecurityid, packagefamilyname, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByUserSecurityIdWithPackageTypes(this: *mut core::ffi::c_void, usersecurityid: core::mem::MaybeUninit<windows_core::HSTRING>, packagetypes: PackageTypes, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "FindPackagesByUserSecurityIdWithPackageTypes", 22usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).FindPackagesByUserSecurityIdWithPackageTypes)(this, usersecurityid, packagetypes, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByUserSecurityIdNamePublisherWithPackageTypes(this: *mut core::ffi::c_void, usersecurityid: core::mem::MaybeUninit<windows_core::HSTRING>, packagetypes: PackageTypes, packagename: core::mem::MaybeUninit<windows_core::HSTRING>, packagepublisher: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "FindPackagesByUserSecurityIdNamePublisherWithPackageTypes", 23usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).FindPackagesByUserSecurityIdNamePublisherWithPackageTypes)(this, usersecurityid, packagetypes, packagename, packagepublisher, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByUserSecurityIdPackageFamilyNameWithPackagesTypes(this: *mut core::ffi::c_void, usersecurityid: core::mem::MaybeUninit<windows_core::HSTRING>, packagetypes: PackageTypes, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "FindPackagesByUserSecurityIdPackageFamilyNameWithPackagesTypes", 24usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).FindPackagesByUserSecurityIdPackageFamilyNameWithPackagesTypes)(this, usersecurityid, packagetypes, packagefamilyname, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackageByUserSecurityIdPackageFullName(this: *mut core::ffi::c_void, usersecurityid: core::mem::MaybeUninit<windows_core::HSTRING>, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume", "FindPackageByUserSecurityIdPackageFullName", 25usize, |this| ((**(this as *const *const IPackageVolume_Vtbl)).FindPackageByUserSecurityIdPackageFullName)(this, usersecurityid, packagefullname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsOffline,
+            IsSystemVolume,
+            MountPoint,
+            Name,
+            PackageStorePath,
+            SupportsHardLinks,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackages,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackages: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByNamePublisher,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByNamePublisher: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByPackageFamilyName,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByPackageFamilyName: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesWithPackageTypes,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesWithPackageTypes: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByNamePublisherWithPackagesTypes,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByNamePublisherWithPackagesTypes: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByPackageFamilyNameWithPackageTypes,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByPackageFamilyNameWithPackageTypes: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackageByPackageFullName,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackageByPackageFullName: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByUserSecurityId,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByUserSecurityId: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByUserSecurityIdNamePublisher,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByUserSecurityIdNamePublisher: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByUserSecurityIdPackageFamilyName,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByUserSecurityIdPackageFamilyName: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByUserSecurityIdWithPackageTypes,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByUserSecurityIdWithPackageTypes: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByUserSecurityIdNamePublisherWithPackageTypes,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByUserSecurityIdNamePublisherWithPackageTypes: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByUserSecurityIdPackageFamilyNameWithPackagesTypes,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByUserSecurityIdPackageFamilyNameWithPackagesTypes: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackageByUserSecurityIdPackageFullName,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackageByUserSecurityIdPackageFullName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageVolume as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageVolume2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsFullTrustPackageSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume2", "IsFullTrustPackageSupported", 6usize, |this| ((**(this as *const *const IPackageVolume2_Vtbl)).IsFullTrustPackageSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAppxInstallSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume2", "IsAppxInstallSupported", 7usize, |this| ((**(this as *const *const IPackageVolume2_Vtbl)).IsAppxInstallSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAvailableSpaceAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageVolume2", "GetAvailableSpaceAsync", 8usize, |this| ((**(this as *const *const IPackageVolume2_Vtbl)).GetAvailableSpaceAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsFullTrustPackageSupported,
+            IsAppxInstallSupported,
+            GetAvailableSpaceAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageVolume2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRegisterPackageOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DependencyPackageUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "DependencyPackageUris", 6usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).DependencyPackageUris)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppDataVolume(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "AppDataVolume", 7usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).AppDataVolume)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppDataVolume(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "SetAppDataVolume", 8usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).SetAppDataVolume)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn OptionalPackageFamilyNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "OptionalPackageFamilyNames", 9usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).OptionalPackageFamilyNames)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExternalLocationUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "ExternalLocationUri", 10usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).ExternalLocationUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExternalLocationUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "SetExternalLocationUri", 11usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).SetExternalLocationUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeveloperMode(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "DeveloperMode", 12usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).DeveloperMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeveloperMode(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "SetDeveloperMode", 13usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).SetDeveloperMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceAppShutdown(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "ForceAppShutdown", 14usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).ForceAppShutdown)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForceAppShutdown(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "SetForceAppShutdown", 15usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).SetForceAppShutdown)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceTargetAppShutdown(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "ForceTargetAppShutdown", 16usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).ForceTargetAppShutdown)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForceTargetAppShutdown(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "SetForceTargetAppShutdown", 17usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).SetForceTargetAppShutdown)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceUpdateFromAnyVersion(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "ForceUpdateFromAnyVersion", 18usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).ForceUpdateFromAnyVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForceUpdateFromAnyVersion(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "SetForceUpdateFromAnyVersion", 19usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).SetForceUpdateFromAnyVersion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallAllResources(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "InstallAllResources", 20usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).InstallAllResources)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInstallAllResources(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "SetInstallAllResources", 21usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).SetInstallAllResources)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StageInPlace(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "StageInPlace", 22usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).StageInPlace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStageInPlace(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "SetStageInPlace", 23usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).SetStageInPlace)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowUnsigned(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "AllowUnsigned", 24usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).AllowUnsigned)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowUnsigned(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "SetAllowUnsigned", 25usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).SetAllowUnsigned)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeferRegistrationWhenPackagesAreInUse(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "DeferRegistrationWhenPackagesAreInUse", 26usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).DeferRegistrationWhenPackagesAreInUse)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeferRegistrationWhenPackagesAreInUse(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions", "SetDeferRegistrationWhenPackagesAreInUse", 27usize, |this| ((**(this as *const *const IRegisterPackageOptions_Vtbl)).SetDeferRegistrationWhenPackagesAreInUse)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            DependencyPackageUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DependencyPackageUris: 0,
+            AppDataVolume,
+            SetAppDataVolume,
+            #[cfg(feature = "Foundation_Collections")]
+            OptionalPackageFamilyNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            OptionalPackageFamilyNames: 0,
+            ExternalLocationUri,
+            SetExternalLocationUri,
+            DeveloperMode,
+            SetDeveloperMode,
+            ForceAppShutdown,
+            SetForceAppShutdown,
+            ForceTargetAppShutdown,
+            SetForceTargetAppShutdown,
+            ForceUpdateFromAnyVersion,
+            SetForceUpdateFromAnyVersion,
+            InstallAllResources,
+            SetInstallAllResources,
+            StageInPlace,
+            SetStageInPlace,
+            AllowUnsigned,
+            SetAllowUnsigned,
+            DeferRegistrationWhenPackagesAreInUse,
+            SetDeferRegistrationWhenPackagesAreInUse,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRegisterPackageOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRegisterPackageOptions2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ExpectedDigests(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterPackageOptions2", "ExpectedDigests", 6usize, |this| ((**(this as *const *const IRegisterPackageOptions2_Vtbl)).ExpectedDigests)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ExpectedDigests,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ExpectedDigests: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRegisterPackageOptions2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemovePackageOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PreserveApplicationData(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemovePackageOptions", "PreserveApplicationData", 6usize, |this| ((**(this as *const *const IRemovePackageOptions_Vtbl)).PreserveApplicationData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreserveApplicationData(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemovePackageOptions", "SetPreserveApplicationData", 7usize, |this| ((**(this as *const *const IRemovePackageOptions_Vtbl)).SetPreserveApplicationData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreserveRoamableApplicationData(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemovePackageOptions", "PreserveRoamableApplicationData", 8usize, |this| ((**(this as *const *const IRemovePackageOptions_Vtbl)).PreserveRoamableApplicationData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreserveRoamableApplicationData(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemovePackageOptions", "SetPreserveRoamableApplicationData", 9usize, |this| ((**(this as *const *const IRemovePackageOptions_Vtbl)).SetPreserveRoamableApplicationData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveForAllUsers(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemovePackageOptions", "RemoveForAllUsers", 10usize, |this| ((**(this as *const *const IRemovePackageOptions_Vtbl)).RemoveForAllUsers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoveForAllUsers(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemovePackageOptions", "SetRemoveForAllUsers", 11usize, |this| ((**(this as *const *const IRemovePackageOptions_Vtbl)).SetRemoveForAllUsers)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PreserveApplicationData,
+            SetPreserveApplicationData,
+            PreserveRoamableApplicationData,
+            SetPreserveRoamableApplicationData,
+            RemoveForAllUsers,
+            SetRemoveForAllUsers,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemovePackageOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISharedPackageContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPackageContainer", "Name", 6usize, |this| ((**(this as *const *const ISharedPackageContainer_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPackageContainer", "Id", 7usize, |this| ((**(this as *const *const ISharedPackageContainer_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetMembers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPackageContainer", "GetMembers", 8usize, |this| ((**(this as *const *const ISharedPackageContainer_Vtbl)).GetMembers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePackageFamily(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPackageContainer", "RemovePackageFamily", 9usize, |this| ((**(this as *const *const ISharedPackageContainer_Vtbl)).RemovePackageFamily)(this, packagefamilyname, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPackageContainer", "ResetData", 10usize, |this| ((**(this as *const *const ISharedPackageContainer_Vtbl)).ResetData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Id,
+            #[cfg(feature = "Foundation_Collections")]
+            GetMembers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetMembers: 0,
+            RemovePackageFamily,
+            ResetData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISharedPackageContainer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISharedPackageContainerManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateContainer(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPackageContainerManager", "CreateContainer", 6usize, |this| ((**(this as *const *const ISharedPackageContainerManager_Vtbl)).CreateContainer)(this, name, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteContainer(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPackageContainerManager", "DeleteContainer", 7usize, |this| ((**(this as *const *const ISharedPackageContainerManager_Vtbl)).DeleteContainer)(this, id, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContainer(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPackageContainerManager", "GetContainer", 8usize, |this| ((**(this as *const *const ISharedPackageContainerManager_Vtbl)).GetContainer)(this, id, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindContainers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPackageContainerManager", "FindContainers", 9usize, |this| ((**(this as *const *const ISharedPackageContainerManager_Vtbl)).FindContainers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindContainersWithOptions(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPackageContainerManager", "FindContainersWithOptions", 10usize, |this| ((**(this as *const *const ISharedPackageContainerManager_Vtbl)).FindContainersWithOptions)(this, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateContainer,
+            DeleteContainer,
+            GetContainer,
+            #[cfg(feature = "Foundation_Collections")]
+            FindContainers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindContainers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindContainersWithOptions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindContainersWithOptions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISharedPackageContainerManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISharedPackageContainerManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPackageContainerManagerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const ISharedPackageContainerManagerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, usersid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPackageContainerManagerStatics", "GetForUser", 7usize, |this| ((**(this as *const *const ISharedPackageContainerManagerStatics_Vtbl)).GetForUser)(this, usersid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForProvisioning(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPackageContainerManagerStatics", "GetForProvisioning", 8usize, |this| ((**(this as *const *const ISharedPackageContainerManagerStatics_Vtbl)).GetForProvisioning)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault, GetForUser, GetForProvisioning }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISharedPackageContainerManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISharedPackageContainerMember_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPackageContainerMember", "PackageFamilyName", 6usize, |this| ((**(this as *const *const ISharedPackageContainerMember_Vtbl)).PackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PackageFamilyName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISharedPackageContainerMember as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISharedPackageContainerMemberFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPackageContainerMemberFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const ISharedPackageContainerMemberFactory_Vtbl)).CreateInstance)(this, packagefamilyname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISharedPackageContainerMemberFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStagePackageOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DependencyPackageUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "DependencyPackageUris", 6usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).DependencyPackageUris)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetVolume(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "TargetVolume", 7usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).TargetVolume)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetVolume(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "SetTargetVolume", 8usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).SetTargetVolume)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn OptionalPackageFamilyNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "OptionalPackageFamilyNames", 9usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).OptionalPackageFamilyNames)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn OptionalPackageUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "OptionalPackageUris", 10usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).OptionalPackageUris)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RelatedPackageUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "RelatedPackageUris", 11usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).RelatedPackageUris)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExternalLocationUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "ExternalLocationUri", 12usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).ExternalLocationUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExternalLocationUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "SetExternalLocationUri", 13usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).SetExternalLocationUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StubPackageOption(this: *mut core::ffi::c_void, result__: *mut StubPackageOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "StubPackageOption", 14usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).StubPackageOption)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStubPackageOption(this: *mut core::ffi::c_void, value: StubPackageOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "SetStubPackageOption", 15usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).SetStubPackageOption)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeveloperMode(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "DeveloperMode", 16usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).DeveloperMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeveloperMode(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "SetDeveloperMode", 17usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).SetDeveloperMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceUpdateFromAnyVersion(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "ForceUpdateFromAnyVersion", 18usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).ForceUpdateFromAnyVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForceUpdateFromAnyVersion(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "SetForceUpdateFromAnyVersion", 19usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).SetForceUpdateFromAnyVersion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallAllResources(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "InstallAllResources", 20usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).InstallAllResources)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInstallAllResources(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "SetInstallAllResources", 21usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).SetInstallAllResources)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequiredContentGroupOnly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "RequiredContentGroupOnly", 22usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).RequiredContentGroupOnly)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequiredContentGroupOnly(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "SetRequiredContentGroupOnly", 23usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).SetRequiredContentGroupOnly)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StageInPlace(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "StageInPlace", 24usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).StageInPlace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStageInPlace(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "SetStageInPlace", 25usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).SetStageInPlace)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowUnsigned(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "AllowUnsigned", 26usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).AllowUnsigned)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowUnsigned(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions", "SetAllowUnsigned", 27usize, |this| ((**(this as *const *const IStagePackageOptions_Vtbl)).SetAllowUnsigned)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            DependencyPackageUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DependencyPackageUris: 0,
+            TargetVolume,
+            SetTargetVolume,
+            #[cfg(feature = "Foundation_Collections")]
+            OptionalPackageFamilyNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            OptionalPackageFamilyNames: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            OptionalPackageUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            OptionalPackageUris: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RelatedPackageUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RelatedPackageUris: 0,
+            ExternalLocationUri,
+            SetExternalLocationUri,
+            StubPackageOption,
+            SetStubPackageOption,
+            DeveloperMode,
+            SetDeveloperMode,
+            ForceUpdateFromAnyVersion,
+            SetForceUpdateFromAnyVersion,
+            InstallAllResources,
+            SetInstallAllResources,
+            RequiredContentGroupOnly,
+            SetRequiredContentGroupOnly,
+            StageInPlace,
+            SetStageInPlace,
+            AllowUnsigned,
+            SetAllowUnsigned,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStagePackageOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStagePackageOptions2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ExpectedDigests(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStagePackageOptions2", "ExpectedDigests", 6usize, |this| ((**(this as *const *const IStagePackageOptions2_Vtbl)).ExpectedDigests)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ExpectedDigests,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ExpectedDigests: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStagePackageOptions2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUpdateSharedPackageContainerOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ForceAppShutdown(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSharedPackageContainerOptions", "ForceAppShutdown", 6usize, |this| ((**(this as *const *const IUpdateSharedPackageContainerOptions_Vtbl)).ForceAppShutdown)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForceAppShutdown(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSharedPackageContainerOptions", "SetForceAppShutdown", 7usize, |this| ((**(this as *const *const IUpdateSharedPackageContainerOptions_Vtbl)).SetForceAppShutdown)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequirePackagesPresent(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSharedPackageContainerOptions", "RequirePackagesPresent", 8usize, |this| ((**(this as *const *const IUpdateSharedPackageContainerOptions_Vtbl)).RequirePackagesPresent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequirePackagesPresent(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSharedPackageContainerOptions", "SetRequirePackagesPresent", 9usize, |this| ((**(this as *const *const IUpdateSharedPackageContainerOptions_Vtbl)).SetRequirePackagesPresent)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ForceAppShutdown,
+            SetForceAppShutdown,
+            RequirePackagesPresent,
+            SetRequirePackagesPresent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateSharedPackageContainerOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUpdateSharedPackageContainerResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut SharedPackageContainerOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSharedPackageContainerResult", "Status", 6usize, |this| ((**(this as *const *const IUpdateSharedPackageContainerResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSharedPackageContainerResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IUpdateSharedPackageContainerResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateSharedPackageContainerResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
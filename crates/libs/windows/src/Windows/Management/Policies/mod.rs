@@ -190,6 +190,7 @@ impl NamedPolicyKind {
 impl windows_core::TypeKind for NamedPolicyKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NamedPolicyKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NamedPolicyKind").field(&self.0).finish()
@@ -198,3 +199,5 @@ impl core::fmt::Debug for NamedPolicyKind {
 impl windows_core::RuntimeType for NamedPolicyKind {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Management.Policies.NamedPolicyKind;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
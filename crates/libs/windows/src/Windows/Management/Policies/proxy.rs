@@ -0,0 +1,96 @@
+#[cfg(all(feature = "Storage_Streams", feature = "System"))]
+impl windows_core::imp::ProxyVtable for INamedPolicyData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Area(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPolicyData", "Area", 6usize, |this| ((**(this as *const *const INamedPolicyData_Vtbl)).Area)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPolicyData", "Name", 7usize, |this| ((**(this as *const *const INamedPolicyData_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut NamedPolicyKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPolicyData", "Kind", 8usize, |this| ((**(this as *const *const INamedPolicyData_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsManaged(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPolicyData", "IsManaged", 9usize, |this| ((**(this as *const *const INamedPolicyData_Vtbl)).IsManaged)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUserPolicy(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPolicyData", "IsUserPolicy", 10usize, |this| ((**(this as *const *const INamedPolicyData_Vtbl)).IsUserPolicy)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPolicyData", "User", 11usize, |this| ((**(this as *const *const INamedPolicyData_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBoolean(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPolicyData", "GetBoolean", 12usize, |this| ((**(this as *const *const INamedPolicyData_Vtbl)).GetBoolean)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetBinary(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPolicyData", "GetBinary", 13usize, |this| ((**(this as *const *const INamedPolicyData_Vtbl)).GetBinary)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInt32(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPolicyData", "GetInt32", 14usize, |this| ((**(this as *const *const INamedPolicyData_Vtbl)).GetInt32)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInt64(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPolicyData", "GetInt64", 15usize, |this| ((**(this as *const *const INamedPolicyData_Vtbl)).GetInt64)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPolicyData", "GetString", 16usize, |this| ((**(this as *const *const INamedPolicyData_Vtbl)).GetString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Changed(this: *mut core::ffi::c_void, changedhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPolicyData", "Changed", 17usize, |this| ((**(this as *const *const INamedPolicyData_Vtbl)).Changed)(this, changedhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPolicyData", "RemoveChanged", 18usize, |this| ((**(this as *const *const INamedPolicyData_Vtbl)).RemoveChanged)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Area,
+            Name,
+            Kind,
+            IsManaged,
+            IsUserPolicy,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+            GetBoolean,
+            #[cfg(feature = "Storage_Streams")]
+            GetBinary,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetBinary: 0,
+            GetInt32,
+            GetInt64,
+            GetString,
+            Changed,
+            RemoveChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INamedPolicyData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for INamedPolicyStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPolicyFromPath(this: *mut core::ffi::c_void, area: core::mem::MaybeUninit<windows_core::HSTRING>, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPolicyStatics", "GetPolicyFromPath", 6usize, |this| ((**(this as *const *const INamedPolicyStatics_Vtbl)).GetPolicyFromPath)(this, area, name, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetPolicyFromPathForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, area: core::mem::MaybeUninit<windows_core::HSTRING>, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPolicyStatics", "GetPolicyFromPathForUser", 7usize, |this| ((**(this as *const *const INamedPolicyStatics_Vtbl)).GetPolicyFromPathForUser)(this, user, area, name, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPolicyFromPath,
+            #[cfg(feature = "System")]
+            GetPolicyFromPathForUser,
+            #[cfg(not(feature = "System"))]
+            GetPolicyFromPathForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INamedPolicyStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
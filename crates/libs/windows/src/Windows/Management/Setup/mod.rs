@@ -655,6 +655,7 @@ impl DeploymentAgentProgressState {
 impl windows_core::TypeKind for DeploymentAgentProgressState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeploymentAgentProgressState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeploymentAgentProgressState").field(&self.0).finish()
@@ -678,6 +679,7 @@ impl DeploymentSessionConnectionChange {
 impl windows_core::TypeKind for DeploymentSessionConnectionChange {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeploymentSessionConnectionChange {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeploymentSessionConnectionChange").field(&self.0).finish()
@@ -697,6 +699,7 @@ impl DeploymentSessionStateChange {
 impl windows_core::TypeKind for DeploymentSessionStateChange {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeploymentSessionStateChange {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeploymentSessionStateChange").field(&self.0).finish()
@@ -721,6 +724,7 @@ impl DeploymentWorkloadState {
 impl windows_core::TypeKind for DeploymentWorkloadState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeploymentWorkloadState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeploymentWorkloadState").field(&self.0).finish()
@@ -789,3 +793,5 @@ pub struct DeploymentSessionHeartbeatRequested_Vtbl {
     pub base__: windows_core::IUnknown_Vtbl,
     pub Invoke: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,327 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAgentProvisioningProgressReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut DeploymentAgentProgressState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAgentProvisioningProgressReport", "State", 6usize, |this| ((**(this as *const *const IAgentProvisioningProgressReport_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, value: DeploymentAgentProgressState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAgentProvisioningProgressReport", "SetState", 7usize, |this| ((**(this as *const *const IAgentProvisioningProgressReport_Vtbl)).SetState)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProgressPercentage(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAgentProvisioningProgressReport", "ProgressPercentage", 8usize, |this| ((**(this as *const *const IAgentProvisioningProgressReport_Vtbl)).ProgressPercentage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProgressPercentage(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAgentProvisioningProgressReport", "SetProgressPercentage", 9usize, |this| ((**(this as *const *const IAgentProvisioningProgressReport_Vtbl)).SetProgressPercentage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EstimatedTimeRemaining(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAgentProvisioningProgressReport", "EstimatedTimeRemaining", 10usize, |this| ((**(this as *const *const IAgentProvisioningProgressReport_Vtbl)).EstimatedTimeRemaining)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEstimatedTimeRemaining(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAgentProvisioningProgressReport", "SetEstimatedTimeRemaining", 11usize, |this| ((**(this as *const *const IAgentProvisioningProgressReport_Vtbl)).SetEstimatedTimeRemaining)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayProgress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAgentProvisioningProgressReport", "DisplayProgress", 12usize, |this| ((**(this as *const *const IAgentProvisioningProgressReport_Vtbl)).DisplayProgress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayProgress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAgentProvisioningProgressReport", "SetDisplayProgress", 13usize, |this| ((**(this as *const *const IAgentProvisioningProgressReport_Vtbl)).SetDisplayProgress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayProgressSecondary(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAgentProvisioningProgressReport", "DisplayProgressSecondary", 14usize, |this| ((**(this as *const *const IAgentProvisioningProgressReport_Vtbl)).DisplayProgressSecondary)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayProgressSecondary(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAgentProvisioningProgressReport", "SetDisplayProgressSecondary", 15usize, |this| ((**(this as *const *const IAgentProvisioningProgressReport_Vtbl)).SetDisplayProgressSecondary)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Batches(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAgentProvisioningProgressReport", "Batches", 16usize, |this| ((**(this as *const *const IAgentProvisioningProgressReport_Vtbl)).Batches)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentBatchIndex(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAgentProvisioningProgressReport", "CurrentBatchIndex", 17usize, |this| ((**(this as *const *const IAgentProvisioningProgressReport_Vtbl)).CurrentBatchIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentBatchIndex(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAgentProvisioningProgressReport", "SetCurrentBatchIndex", 18usize, |this| ((**(this as *const *const IAgentProvisioningProgressReport_Vtbl)).SetCurrentBatchIndex)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            State,
+            SetState,
+            ProgressPercentage,
+            SetProgressPercentage,
+            EstimatedTimeRemaining,
+            SetEstimatedTimeRemaining,
+            DisplayProgress,
+            SetDisplayProgress,
+            DisplayProgressSecondary,
+            SetDisplayProgressSecondary,
+            #[cfg(feature = "Foundation_Collections")]
+            Batches,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Batches: 0,
+            CurrentBatchIndex,
+            SetCurrentBatchIndex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAgentProvisioningProgressReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeploymentSessionConnectionChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SessionId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentSessionConnectionChangedEventArgs", "SessionId", 6usize, |this| ((**(this as *const *const IDeploymentSessionConnectionChangedEventArgs_Vtbl)).SessionId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Change(this: *mut core::ffi::c_void, result__: *mut DeploymentSessionConnectionChange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentSessionConnectionChangedEventArgs", "Change", 7usize, |this| ((**(this as *const *const IDeploymentSessionConnectionChangedEventArgs_Vtbl)).Change)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SessionId, Change }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeploymentSessionConnectionChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeploymentSessionHeartbeatRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentSessionHeartbeatRequestedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IDeploymentSessionHeartbeatRequestedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentSessionHeartbeatRequestedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IDeploymentSessionHeartbeatRequestedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeploymentSessionHeartbeatRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeploymentSessionStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SessionId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentSessionStateChangedEventArgs", "SessionId", 6usize, |this| ((**(this as *const *const IDeploymentSessionStateChangedEventArgs_Vtbl)).SessionId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Change(this: *mut core::ffi::c_void, result__: *mut DeploymentSessionStateChange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentSessionStateChangedEventArgs", "Change", 7usize, |this| ((**(this as *const *const IDeploymentSessionStateChangedEventArgs_Vtbl)).Change)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SessionId, Change }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeploymentSessionStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeploymentWorkload_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "Id", 6usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayFriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "DisplayFriendlyName", 7usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).DisplayFriendlyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayFriendlyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "SetDisplayFriendlyName", 8usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).SetDisplayFriendlyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "StartTime", 9usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "SetStartTime", 10usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).SetStartTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "EndTime", 11usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).EndTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEndTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "SetEndTime", 12usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).SetEndTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "ErrorCode", 13usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetErrorCode(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "SetErrorCode", 14usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).SetErrorCode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorMessage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "ErrorMessage", 15usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).ErrorMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetErrorMessage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "SetErrorMessage", 16usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).SetErrorMessage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PossibleCause(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "PossibleCause", 17usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).PossibleCause)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPossibleCause(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "SetPossibleCause", 18usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).SetPossibleCause)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PossibleResolution(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "PossibleResolution", 19usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).PossibleResolution)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPossibleResolution(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "SetPossibleResolution", 20usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).SetPossibleResolution)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut DeploymentWorkloadState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "State", 21usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, value: DeploymentWorkloadState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "SetState", 22usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).SetState)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateDetails(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "StateDetails", 23usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).StateDetails)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStateDetails(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkload", "SetStateDetails", 24usize, |this| ((**(this as *const *const IDeploymentWorkload_Vtbl)).SetStateDetails)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            DisplayFriendlyName,
+            SetDisplayFriendlyName,
+            StartTime,
+            SetStartTime,
+            EndTime,
+            SetEndTime,
+            ErrorCode,
+            SetErrorCode,
+            ErrorMessage,
+            SetErrorMessage,
+            PossibleCause,
+            SetPossibleCause,
+            PossibleResolution,
+            SetPossibleResolution,
+            State,
+            SetState,
+            StateDetails,
+            SetStateDetails,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeploymentWorkload as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDeploymentWorkloadBatch_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkloadBatch", "Id", 6usize, |this| ((**(this as *const *const IDeploymentWorkloadBatch_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayCategoryTitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkloadBatch", "DisplayCategoryTitle", 7usize, |this| ((**(this as *const *const IDeploymentWorkloadBatch_Vtbl)).DisplayCategoryTitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayCategoryTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkloadBatch", "SetDisplayCategoryTitle", 8usize, |this| ((**(this as *const *const IDeploymentWorkloadBatch_Vtbl)).SetDisplayCategoryTitle)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BatchWorkloads(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkloadBatch", "BatchWorkloads", 9usize, |this| ((**(this as *const *const IDeploymentWorkloadBatch_Vtbl)).BatchWorkloads)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            DisplayCategoryTitle,
+            SetDisplayCategoryTitle,
+            #[cfg(feature = "Foundation_Collections")]
+            BatchWorkloads,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BatchWorkloads: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeploymentWorkloadBatch as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeploymentWorkloadBatchFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, id: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkloadBatchFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IDeploymentWorkloadBatchFactory_Vtbl)).CreateInstance)(this, id, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeploymentWorkloadBatchFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeploymentWorkloadFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeploymentWorkloadFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IDeploymentWorkloadFactory_Vtbl)).CreateInstance)(this, id, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeploymentWorkloadFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDevicePreparationExecutionContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Context(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePreparationExecutionContext", "Context", 6usize, |this| ((**(this as *const *const IDevicePreparationExecutionContext_Vtbl)).Context)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Context }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePreparationExecutionContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMachineProvisioningProgressReporter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SessionId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineProvisioningProgressReporter", "SessionId", 6usize, |this| ((**(this as *const *const IMachineProvisioningProgressReporter_Vtbl)).SessionId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionConnection(this: *mut core::ffi::c_void, result__: *mut DeploymentSessionConnectionChange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineProvisioningProgressReporter", "SessionConnection", 7usize, |this| ((**(this as *const *const IMachineProvisioningProgressReporter_Vtbl)).SessionConnection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionState(this: *mut core::ffi::c_void, result__: *mut DeploymentSessionStateChange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineProvisioningProgressReporter", "SessionState", 8usize, |this| ((**(this as *const *const IMachineProvisioningProgressReporter_Vtbl)).SessionState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineProvisioningProgressReporter", "SessionStateChanged", 9usize, |this| ((**(this as *const *const IMachineProvisioningProgressReporter_Vtbl)).SessionStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSessionStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineProvisioningProgressReporter", "RemoveSessionStateChanged", 10usize, |this| ((**(this as *const *const IMachineProvisioningProgressReporter_Vtbl)).RemoveSessionStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionConnectionChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineProvisioningProgressReporter", "SessionConnectionChanged", 11usize, |this| ((**(this as *const *const IMachineProvisioningProgressReporter_Vtbl)).SessionConnectionChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSessionConnectionChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineProvisioningProgressReporter", "RemoveSessionConnectionChanged", 12usize, |this| ((**(this as *const *const IMachineProvisioningProgressReporter_Vtbl)).RemoveSessionConnectionChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportProgress(this: *mut core::ffi::c_void, updatereport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineProvisioningProgressReporter", "ReportProgress", 13usize, |this| ((**(this as *const *const IMachineProvisioningProgressReporter_Vtbl)).ReportProgress)(this, updatereport), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDevicePreparationExecutionContextAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineProvisioningProgressReporter", "GetDevicePreparationExecutionContextAsync", 14usize, |this| ((**(this as *const *const IMachineProvisioningProgressReporter_Vtbl)).GetDevicePreparationExecutionContextAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SessionId,
+            SessionConnection,
+            SessionState,
+            SessionStateChanged,
+            RemoveSessionStateChanged,
+            SessionConnectionChanged,
+            RemoveSessionConnectionChanged,
+            ReportProgress,
+            GetDevicePreparationExecutionContextAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMachineProvisioningProgressReporter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMachineProvisioningProgressReporterStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForLaunchUri(this: *mut core::ffi::c_void, launchuri: *mut core::ffi::c_void, heartbeathandler: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineProvisioningProgressReporterStatics", "GetForLaunchUri", 6usize, |this| ((**(this as *const *const IMachineProvisioningProgressReporterStatics_Vtbl)).GetForLaunchUri)(this, launchuri, heartbeathandler, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForLaunchUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMachineProvisioningProgressReporterStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1414,6 +1414,7 @@ impl WindowsUpdateAdministratorOptions {
 impl windows_core::TypeKind for WindowsUpdateAdministratorOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WindowsUpdateAdministratorOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WindowsUpdateAdministratorOptions").field(&self.0).finish()
@@ -1466,6 +1467,7 @@ impl WindowsUpdateAdministratorStatus {
 impl windows_core::TypeKind for WindowsUpdateAdministratorStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WindowsUpdateAdministratorStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WindowsUpdateAdministratorStatus").field(&self.0).finish()
@@ -1515,6 +1517,7 @@ impl WindowsUpdateAttentionRequiredReason {
 impl windows_core::TypeKind for WindowsUpdateAttentionRequiredReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WindowsUpdateAttentionRequiredReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WindowsUpdateAttentionRequiredReason").field(&self.0).finish()
@@ -1523,3 +1526,5 @@ impl core::fmt::Debug for WindowsUpdateAttentionRequiredReason {
 impl windows_core::RuntimeType for WindowsUpdateAttentionRequiredReason {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Management.Update.WindowsUpdateAttentionRequiredReason;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
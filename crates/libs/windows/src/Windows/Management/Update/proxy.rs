@@ -0,0 +1,678 @@
+impl windows_core::imp::ProxyVtable for IPreviewBuildsManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ArePreviewBuildsAllowed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPreviewBuildsManager", "ArePreviewBuildsAllowed", 6usize, |this| ((**(this as *const *const IPreviewBuildsManager_Vtbl)).ArePreviewBuildsAllowed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetArePreviewBuildsAllowed(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPreviewBuildsManager", "SetArePreviewBuildsAllowed", 7usize, |this| ((**(this as *const *const IPreviewBuildsManager_Vtbl)).SetArePreviewBuildsAllowed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentState(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPreviewBuildsManager", "GetCurrentState", 8usize, |this| ((**(this as *const *const IPreviewBuildsManager_Vtbl)).GetCurrentState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPreviewBuildsManager", "SyncAsync", 9usize, |this| ((**(this as *const *const IPreviewBuildsManager_Vtbl)).SyncAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ArePreviewBuildsAllowed,
+            SetArePreviewBuildsAllowed,
+            GetCurrentState,
+            SyncAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPreviewBuildsManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPreviewBuildsManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPreviewBuildsManagerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IPreviewBuildsManagerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPreviewBuildsManagerStatics", "IsSupported", 7usize, |this| ((**(this as *const *const IPreviewBuildsManagerStatics_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault, IsSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPreviewBuildsManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPreviewBuildsState_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPreviewBuildsState", "Properties", 6usize, |this| ((**(this as *const *const IPreviewBuildsState_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPreviewBuildsState as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsUpdate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "ProviderId", 6usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).ProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "UpdateId", 7usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).UpdateId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "Title", 8usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "Description", 9usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFeatureUpdate(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "IsFeatureUpdate", 10usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).IsFeatureUpdate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMinorImpact(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "IsMinorImpact", 11usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).IsMinorImpact)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSecurity(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "IsSecurity", 12usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).IsSecurity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCritical(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "IsCritical", 13usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).IsCritical)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsForOS(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "IsForOS", 14usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).IsForOS)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDriver(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "IsDriver", 15usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).IsDriver)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMandatory(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "IsMandatory", 16usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).IsMandatory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUrgent(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "IsUrgent", 17usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).IsUrgent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSeeker(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "IsSeeker", 18usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).IsSeeker)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoreInfoUrl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "MoreInfoUrl", 19usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).MoreInfoUrl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportUrl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "SupportUrl", 20usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).SupportUrl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEulaAccepted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "IsEulaAccepted", 21usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).IsEulaAccepted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EulaText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "EulaText", 22usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).EulaText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "Deadline", 23usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).Deadline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttentionRequiredInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "AttentionRequiredInfo", 24usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).AttentionRequiredInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActionResult(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "ActionResult", 25usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).ActionResult)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentAction(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "CurrentAction", 26usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).CurrentAction)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActionProgress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "ActionProgress", 27usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).ActionProgress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyValue(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "GetPropertyValue", 28usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).GetPropertyValue)(this, propertyname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptEula(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdate", "AcceptEula", 29usize, |this| ((**(this as *const *const IWindowsUpdate_Vtbl)).AcceptEula)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProviderId,
+            UpdateId,
+            Title,
+            Description,
+            IsFeatureUpdate,
+            IsMinorImpact,
+            IsSecurity,
+            IsCritical,
+            IsForOS,
+            IsDriver,
+            IsMandatory,
+            IsUrgent,
+            IsSeeker,
+            MoreInfoUrl,
+            SupportUrl,
+            IsEulaAccepted,
+            EulaText,
+            Deadline,
+            AttentionRequiredInfo,
+            ActionResult,
+            CurrentAction,
+            ActionProgress,
+            GetPropertyValue,
+            AcceptEula,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdate as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsUpdateActionCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateActionCompletedEventArgs", "Update", 6usize, |this| ((**(this as *const *const IWindowsUpdateActionCompletedEventArgs_Vtbl)).Update)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Action(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateActionCompletedEventArgs", "Action", 7usize, |this| ((**(this as *const *const IWindowsUpdateActionCompletedEventArgs_Vtbl)).Action)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Succeeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateActionCompletedEventArgs", "Succeeded", 8usize, |this| ((**(this as *const *const IWindowsUpdateActionCompletedEventArgs_Vtbl)).Succeeded)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateActionCompletedEventArgs", "ExtendedError", 9usize, |this| ((**(this as *const *const IWindowsUpdateActionCompletedEventArgs_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Update, Action, Succeeded, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateActionCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsUpdateActionProgress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Action(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateActionProgress", "Action", 6usize, |this| ((**(this as *const *const IWindowsUpdateActionProgress_Vtbl)).Action)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateActionProgress", "Progress", 7usize, |this| ((**(this as *const *const IWindowsUpdateActionProgress_Vtbl)).Progress)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Action, Progress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateActionProgress as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsUpdateActionResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateActionResult", "Timestamp", 6usize, |this| ((**(this as *const *const IWindowsUpdateActionResult_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Succeeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateActionResult", "Succeeded", 7usize, |this| ((**(this as *const *const IWindowsUpdateActionResult_Vtbl)).Succeeded)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateActionResult", "ExtendedError", 8usize, |this| ((**(this as *const *const IWindowsUpdateActionResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Action(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateActionResult", "Action", 9usize, |this| ((**(this as *const *const IWindowsUpdateActionResult_Vtbl)).Action)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, Succeeded, ExtendedError, Action }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateActionResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWindowsUpdateAdministrator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartAdministratorScan(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAdministrator", "StartAdministratorScan", 6usize, |this| ((**(this as *const *const IWindowsUpdateAdministrator_Vtbl)).StartAdministratorScan)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApproveWindowsUpdateAction(this: *mut core::ffi::c_void, updateid: core::mem::MaybeUninit<windows_core::HSTRING>, action: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAdministrator", "ApproveWindowsUpdateAction", 7usize, |this| ((**(this as *const *const IWindowsUpdateAdministrator_Vtbl)).ApproveWindowsUpdateAction)(this, updateid, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevokeWindowsUpdateActionApproval(this: *mut core::ffi::c_void, updateid: core::mem::MaybeUninit<windows_core::HSTRING>, action: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAdministrator", "RevokeWindowsUpdateActionApproval", 8usize, |this| ((**(this as *const *const IWindowsUpdateAdministrator_Vtbl)).RevokeWindowsUpdateActionApproval)(this, updateid, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApproveWindowsUpdate(this: *mut core::ffi::c_void, updateid: core::mem::MaybeUninit<windows_core::HSTRING>, approvaldata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAdministrator", "ApproveWindowsUpdate", 9usize, |this| ((**(this as *const *const IWindowsUpdateAdministrator_Vtbl)).ApproveWindowsUpdate)(this, updateid, approvaldata), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevokeWindowsUpdateApproval(this: *mut core::ffi::c_void, updateid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAdministrator", "RevokeWindowsUpdateApproval", 10usize, |this| ((**(this as *const *const IWindowsUpdateAdministrator_Vtbl)).RevokeWindowsUpdateApproval)(this, updateid), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetUpdates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAdministrator", "GetUpdates", 11usize, |this| ((**(this as *const *const IWindowsUpdateAdministrator_Vtbl)).GetUpdates)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartAdministratorScan,
+            ApproveWindowsUpdateAction,
+            RevokeWindowsUpdateActionApproval,
+            ApproveWindowsUpdate,
+            RevokeWindowsUpdateApproval,
+            #[cfg(feature = "Foundation_Collections")]
+            GetUpdates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetUpdates: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateAdministrator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsUpdateAdministratorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRegisteredAdministrator(this: *mut core::ffi::c_void, organizationname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAdministratorStatics", "GetRegisteredAdministrator", 6usize, |this| ((**(this as *const *const IWindowsUpdateAdministratorStatics_Vtbl)).GetRegisteredAdministrator)(this, organizationname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForAdministration(this: *mut core::ffi::c_void, organizationname: core::mem::MaybeUninit<windows_core::HSTRING>, options: WindowsUpdateAdministratorOptions, result__: *mut WindowsUpdateAdministratorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAdministratorStatics", "RegisterForAdministration", 7usize, |this| ((**(this as *const *const IWindowsUpdateAdministratorStatics_Vtbl)).RegisterForAdministration)(this, organizationname, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterForAdministration(this: *mut core::ffi::c_void, organizationname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut WindowsUpdateAdministratorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAdministratorStatics", "UnregisterForAdministration", 8usize, |this| ((**(this as *const *const IWindowsUpdateAdministratorStatics_Vtbl)).UnregisterForAdministration)(this, organizationname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRegisteredAdministratorName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAdministratorStatics", "GetRegisteredAdministratorName", 9usize, |this| ((**(this as *const *const IWindowsUpdateAdministratorStatics_Vtbl)).GetRegisteredAdministratorName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestRestart(this: *mut core::ffi::c_void, restartoptions: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAdministratorStatics", "RequestRestart", 10usize, |this| ((**(this as *const *const IWindowsUpdateAdministratorStatics_Vtbl)).RequestRestart)(this, restartoptions, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelRestartRequest(this: *mut core::ffi::c_void, requestrestarttoken: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAdministratorStatics", "CancelRestartRequest", 11usize, |this| ((**(this as *const *const IWindowsUpdateAdministratorStatics_Vtbl)).CancelRestartRequest)(this, requestrestarttoken), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetRegisteredAdministrator,
+            RegisterForAdministration,
+            UnregisterForAdministration,
+            GetRegisteredAdministratorName,
+            RequestRestart,
+            CancelRestartRequest,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateAdministratorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsUpdateApprovalData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Seeker(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateApprovalData", "Seeker", 6usize, |this| ((**(this as *const *const IWindowsUpdateApprovalData_Vtbl)).Seeker)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSeeker(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateApprovalData", "SetSeeker", 7usize, |this| ((**(this as *const *const IWindowsUpdateApprovalData_Vtbl)).SetSeeker)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowDownloadOnMetered(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateApprovalData", "AllowDownloadOnMetered", 8usize, |this| ((**(this as *const *const IWindowsUpdateApprovalData_Vtbl)).AllowDownloadOnMetered)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowDownloadOnMetered(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateApprovalData", "SetAllowDownloadOnMetered", 9usize, |this| ((**(this as *const *const IWindowsUpdateApprovalData_Vtbl)).SetAllowDownloadOnMetered)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComplianceDeadlineInDays(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateApprovalData", "ComplianceDeadlineInDays", 10usize, |this| ((**(this as *const *const IWindowsUpdateApprovalData_Vtbl)).ComplianceDeadlineInDays)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComplianceDeadlineInDays(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateApprovalData", "SetComplianceDeadlineInDays", 11usize, |this| ((**(this as *const *const IWindowsUpdateApprovalData_Vtbl)).SetComplianceDeadlineInDays)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComplianceGracePeriodInDays(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateApprovalData", "ComplianceGracePeriodInDays", 12usize, |this| ((**(this as *const *const IWindowsUpdateApprovalData_Vtbl)).ComplianceGracePeriodInDays)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComplianceGracePeriodInDays(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateApprovalData", "SetComplianceGracePeriodInDays", 13usize, |this| ((**(this as *const *const IWindowsUpdateApprovalData_Vtbl)).SetComplianceGracePeriodInDays)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OptOutOfAutoReboot(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateApprovalData", "OptOutOfAutoReboot", 14usize, |this| ((**(this as *const *const IWindowsUpdateApprovalData_Vtbl)).OptOutOfAutoReboot)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOptOutOfAutoReboot(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateApprovalData", "SetOptOutOfAutoReboot", 15usize, |this| ((**(this as *const *const IWindowsUpdateApprovalData_Vtbl)).SetOptOutOfAutoReboot)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Seeker,
+            SetSeeker,
+            AllowDownloadOnMetered,
+            SetAllowDownloadOnMetered,
+            ComplianceDeadlineInDays,
+            SetComplianceDeadlineInDays,
+            ComplianceGracePeriodInDays,
+            SetComplianceGracePeriodInDays,
+            OptOutOfAutoReboot,
+            SetOptOutOfAutoReboot,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateApprovalData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsUpdateAttentionRequiredInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut WindowsUpdateAttentionRequiredReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAttentionRequiredInfo", "Reason", 6usize, |this| ((**(this as *const *const IWindowsUpdateAttentionRequiredInfo_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAttentionRequiredInfo", "Timestamp", 7usize, |this| ((**(this as *const *const IWindowsUpdateAttentionRequiredInfo_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reason, Timestamp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateAttentionRequiredInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsUpdateAttentionRequiredReasonChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAttentionRequiredReasonChangedEventArgs", "Update", 6usize, |this| ((**(this as *const *const IWindowsUpdateAttentionRequiredReasonChangedEventArgs_Vtbl)).Update)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut WindowsUpdateAttentionRequiredReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAttentionRequiredReasonChangedEventArgs", "Reason", 7usize, |this| ((**(this as *const *const IWindowsUpdateAttentionRequiredReasonChangedEventArgs_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Update, Reason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateAttentionRequiredReasonChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsUpdateGetAdministratorResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Administrator(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateGetAdministratorResult", "Administrator", 6usize, |this| ((**(this as *const *const IWindowsUpdateGetAdministratorResult_Vtbl)).Administrator)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut WindowsUpdateAdministratorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateGetAdministratorResult", "Status", 7usize, |this| ((**(this as *const *const IWindowsUpdateGetAdministratorResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Administrator, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateGetAdministratorResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsUpdateItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateItem", "ProviderId", 6usize, |this| ((**(this as *const *const IWindowsUpdateItem_Vtbl)).ProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateItem", "UpdateId", 7usize, |this| ((**(this as *const *const IWindowsUpdateItem_Vtbl)).UpdateId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateItem", "Timestamp", 8usize, |this| ((**(this as *const *const IWindowsUpdateItem_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateItem", "Title", 9usize, |this| ((**(this as *const *const IWindowsUpdateItem_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateItem", "Description", 10usize, |this| ((**(this as *const *const IWindowsUpdateItem_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoreInfoUrl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateItem", "MoreInfoUrl", 11usize, |this| ((**(this as *const *const IWindowsUpdateItem_Vtbl)).MoreInfoUrl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Category(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateItem", "Category", 12usize, |this| ((**(this as *const *const IWindowsUpdateItem_Vtbl)).Category)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Operation(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateItem", "Operation", 13usize, |this| ((**(this as *const *const IWindowsUpdateItem_Vtbl)).Operation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProviderId,
+            UpdateId,
+            Timestamp,
+            Title,
+            Description,
+            MoreInfoUrl,
+            Category,
+            Operation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWindowsUpdateManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScanningStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "ScanningStateChanged", 6usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).ScanningStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveScanningStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "RemoveScanningStateChanged", 7usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).RemoveScanningStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn WorkingStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "WorkingStateChanged", 8usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).WorkingStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveWorkingStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "RemoveWorkingStateChanged", 9usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).RemoveWorkingStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProgressChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "ProgressChanged", 10usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).ProgressChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProgressChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "RemoveProgressChanged", 11usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).RemoveProgressChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttentionRequiredReasonChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "AttentionRequiredReasonChanged", 12usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).AttentionRequiredReasonChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAttentionRequiredReasonChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "RemoveAttentionRequiredReasonChanged", 13usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).RemoveAttentionRequiredReasonChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActionCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "ActionCompleted", 14usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).ActionCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveActionCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "RemoveActionCompleted", 15usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).RemoveActionCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScanCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "ScanCompleted", 16usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).ScanCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveScanCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "RemoveScanCompleted", 17usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).RemoveScanCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsScanning(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "IsScanning", 18usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).IsScanning)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWorking(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "IsWorking", 19usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).IsWorking)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastSuccessfulScanTimestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "LastSuccessfulScanTimestamp", 20usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).LastSuccessfulScanTimestamp)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetApplicableUpdates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "GetApplicableUpdates", 21usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).GetApplicableUpdates)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetMostRecentCompletedUpdates(this: *mut core::ffi::c_void, count: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "GetMostRecentCompletedUpdates", 22usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).GetMostRecentCompletedUpdates)(this, count, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetMostRecentCompletedUpdatesAsync(this: *mut core::ffi::c_void, count: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "GetMostRecentCompletedUpdatesAsync", 23usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).GetMostRecentCompletedUpdatesAsync)(this, count, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartScan(this: *mut core::ffi::c_void, userinitiated: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManager", "StartScan", 24usize, |this| ((**(this as *const *const IWindowsUpdateManager_Vtbl)).StartScan)(this, userinitiated), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ScanningStateChanged,
+            RemoveScanningStateChanged,
+            WorkingStateChanged,
+            RemoveWorkingStateChanged,
+            ProgressChanged,
+            RemoveProgressChanged,
+            AttentionRequiredReasonChanged,
+            RemoveAttentionRequiredReasonChanged,
+            ActionCompleted,
+            RemoveActionCompleted,
+            ScanCompleted,
+            RemoveScanCompleted,
+            IsScanning,
+            IsWorking,
+            LastSuccessfulScanTimestamp,
+            #[cfg(feature = "Foundation_Collections")]
+            GetApplicableUpdates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetApplicableUpdates: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetMostRecentCompletedUpdates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetMostRecentCompletedUpdates: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetMostRecentCompletedUpdatesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetMostRecentCompletedUpdatesAsync: 0,
+            StartScan,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsUpdateManagerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, clientid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateManagerFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IWindowsUpdateManagerFactory_Vtbl)).CreateInstance)(this, clientid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateManagerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsUpdateProgressChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateProgressChangedEventArgs", "Update", 6usize, |this| ((**(this as *const *const IWindowsUpdateProgressChangedEventArgs_Vtbl)).Update)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActionProgress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateProgressChangedEventArgs", "ActionProgress", 7usize, |this| ((**(this as *const *const IWindowsUpdateProgressChangedEventArgs_Vtbl)).ActionProgress)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Update, ActionProgress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateProgressChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsUpdateRestartRequestOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateRestartRequestOptions", "Title", 6usize, |this| ((**(this as *const *const IWindowsUpdateRestartRequestOptions_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateRestartRequestOptions", "SetTitle", 7usize, |this| ((**(this as *const *const IWindowsUpdateRestartRequestOptions_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateRestartRequestOptions", "Description", 8usize, |this| ((**(this as *const *const IWindowsUpdateRestartRequestOptions_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateRestartRequestOptions", "SetDescription", 9usize, |this| ((**(this as *const *const IWindowsUpdateRestartRequestOptions_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoreInfoUrl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateRestartRequestOptions", "MoreInfoUrl", 10usize, |this| ((**(this as *const *const IWindowsUpdateRestartRequestOptions_Vtbl)).MoreInfoUrl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMoreInfoUrl(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateRestartRequestOptions", "SetMoreInfoUrl", 11usize, |this| ((**(this as *const *const IWindowsUpdateRestartRequestOptions_Vtbl)).SetMoreInfoUrl)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComplianceDeadlineInDays(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateRestartRequestOptions", "ComplianceDeadlineInDays", 12usize, |this| ((**(this as *const *const IWindowsUpdateRestartRequestOptions_Vtbl)).ComplianceDeadlineInDays)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComplianceDeadlineInDays(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateRestartRequestOptions", "SetComplianceDeadlineInDays", 13usize, |this| ((**(this as *const *const IWindowsUpdateRestartRequestOptions_Vtbl)).SetComplianceDeadlineInDays)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComplianceGracePeriodInDays(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateRestartRequestOptions", "ComplianceGracePeriodInDays", 14usize, |this| ((**(this as *const *const IWindowsUpdateRestartRequestOptions_Vtbl)).ComplianceGracePeriodInDays)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComplianceGracePeriodInDays(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateRestartRequestOptions", "SetComplianceGracePeriodInDays", 15usize, |this| ((**(this as *const *const IWindowsUpdateRestartRequestOptions_Vtbl)).SetComplianceGracePeriodInDays)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OrganizationName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateRestartRequestOptions", "OrganizationName", 16usize, |this| ((**(this as *const *const IWindowsUpdateRestartRequestOptions_Vtbl)).OrganizationName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOrganizationName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateRestartRequestOptions", "SetOrganizationName", 17usize, |this| ((**(this as *const *const IWindowsUpdateRestartRequestOptions_Vtbl)).SetOrganizationName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OptOutOfAutoReboot(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateRestartRequestOptions", "OptOutOfAutoReboot", 18usize, |this| ((**(this as *const *const IWindowsUpdateRestartRequestOptions_Vtbl)).OptOutOfAutoReboot)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOptOutOfAutoReboot(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateRestartRequestOptions", "SetOptOutOfAutoReboot", 19usize, |this| ((**(this as *const *const IWindowsUpdateRestartRequestOptions_Vtbl)).SetOptOutOfAutoReboot)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Title,
+            SetTitle,
+            Description,
+            SetDescription,
+            MoreInfoUrl,
+            SetMoreInfoUrl,
+            ComplianceDeadlineInDays,
+            SetComplianceDeadlineInDays,
+            ComplianceGracePeriodInDays,
+            SetComplianceGracePeriodInDays,
+            OrganizationName,
+            SetOrganizationName,
+            OptOutOfAutoReboot,
+            SetOptOutOfAutoReboot,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateRestartRequestOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsUpdateRestartRequestOptionsFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, title: core::mem::MaybeUninit<windows_core::HSTRING>, description: core::mem::MaybeUninit<windows_core::HSTRING>, moreinfourl: *mut core::ffi::c_void, compliancedeadlineindays: i32, compliancegraceperiodindays: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateRestartRequestOptionsFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IWindowsUpdateRestartRequestOptionsFactory_Vtbl)).CreateInstance)(this, title, description, moreinfourl, compliancedeadlineindays, compliancegraceperiodindays, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateRestartRequestOptionsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWindowsUpdateScanCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateScanCompletedEventArgs", "ProviderId", 6usize, |this| ((**(this as *const *const IWindowsUpdateScanCompletedEventArgs_Vtbl)).ProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Succeeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateScanCompletedEventArgs", "Succeeded", 7usize, |this| ((**(this as *const *const IWindowsUpdateScanCompletedEventArgs_Vtbl)).Succeeded)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateScanCompletedEventArgs", "ExtendedError", 8usize, |this| ((**(this as *const *const IWindowsUpdateScanCompletedEventArgs_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Updates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateScanCompletedEventArgs", "Updates", 9usize, |this| ((**(this as *const *const IWindowsUpdateScanCompletedEventArgs_Vtbl)).Updates)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProviderId,
+            Succeeded,
+            ExtendedError,
+            #[cfg(feature = "Foundation_Collections")]
+            Updates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Updates: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateScanCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
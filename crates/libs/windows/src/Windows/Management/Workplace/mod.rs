@@ -102,6 +102,7 @@ impl MessagingSyncPolicy {
 impl windows_core::TypeKind for MessagingSyncPolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MessagingSyncPolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MessagingSyncPolicy").field(&self.0).finish()
@@ -110,3 +111,5 @@ impl core::fmt::Debug for MessagingSyncPolicy {
 impl windows_core::RuntimeType for MessagingSyncPolicy {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Management.Workplace.MessagingSyncPolicy;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
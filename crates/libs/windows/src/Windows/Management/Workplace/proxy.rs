@@ -0,0 +1,51 @@
+impl windows_core::imp::ProxyVtable for IMdmAllowPolicyStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsBrowserAllowed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAllowPolicyStatics", "IsBrowserAllowed", 6usize, |this| ((**(this as *const *const IMdmAllowPolicyStatics_Vtbl)).IsBrowserAllowed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCameraAllowed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAllowPolicyStatics", "IsCameraAllowed", 7usize, |this| ((**(this as *const *const IMdmAllowPolicyStatics_Vtbl)).IsCameraAllowed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMicrosoftAccountAllowed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAllowPolicyStatics", "IsMicrosoftAccountAllowed", 8usize, |this| ((**(this as *const *const IMdmAllowPolicyStatics_Vtbl)).IsMicrosoftAccountAllowed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStoreAllowed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAllowPolicyStatics", "IsStoreAllowed", 9usize, |this| ((**(this as *const *const IMdmAllowPolicyStatics_Vtbl)).IsStoreAllowed)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsBrowserAllowed,
+            IsCameraAllowed,
+            IsMicrosoftAccountAllowed,
+            IsStoreAllowed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMdmAllowPolicyStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMdmPolicyStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMessagingSyncPolicy(this: *mut core::ffi::c_void, result__: *mut MessagingSyncPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmPolicyStatics2", "GetMessagingSyncPolicy", 6usize, |this| ((**(this as *const *const IMdmPolicyStatics2_Vtbl)).GetMessagingSyncPolicy)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMessagingSyncPolicy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMdmPolicyStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWorkplaceSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsMicrosoftAccountOptional(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkplaceSettingsStatics", "IsMicrosoftAccountOptional", 6usize, |this| ((**(this as *const *const IWorkplaceSettingsStatics_Vtbl)).IsMicrosoftAccountOptional)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsMicrosoftAccountOptional }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkplaceSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -283,6 +283,7 @@ impl MdmAlertDataType {
 impl windows_core::TypeKind for MdmAlertDataType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MdmAlertDataType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MdmAlertDataType").field(&self.0).finish()
@@ -304,6 +305,7 @@ impl MdmAlertMark {
 impl windows_core::TypeKind for MdmAlertMark {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MdmAlertMark {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MdmAlertMark").field(&self.0).finish()
@@ -327,6 +329,7 @@ impl MdmSessionState {
 impl windows_core::TypeKind for MdmSessionState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MdmSessionState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MdmSessionState").field(&self.0).finish()
@@ -335,3 +338,5 @@ impl core::fmt::Debug for MdmSessionState {
 impl windows_core::RuntimeType for MdmSessionState {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Management.MdmSessionState;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,147 @@
+impl windows_core::imp::ProxyVtable for IMdmAlert_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAlert", "Data", 6usize, |this| ((**(this as *const *const IMdmAlert_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAlert", "SetData", 7usize, |this| ((**(this as *const *const IMdmAlert_Vtbl)).SetData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, result__: *mut MdmAlertDataType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAlert", "Format", 8usize, |this| ((**(this as *const *const IMdmAlert_Vtbl)).Format)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, value: MdmAlertDataType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAlert", "SetFormat", 9usize, |this| ((**(this as *const *const IMdmAlert_Vtbl)).SetFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mark(this: *mut core::ffi::c_void, result__: *mut MdmAlertMark) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAlert", "Mark", 10usize, |this| ((**(this as *const *const IMdmAlert_Vtbl)).Mark)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMark(this: *mut core::ffi::c_void, value: MdmAlertMark) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAlert", "SetMark", 11usize, |this| ((**(this as *const *const IMdmAlert_Vtbl)).SetMark)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAlert", "Source", 12usize, |this| ((**(this as *const *const IMdmAlert_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAlert", "SetSource", 13usize, |this| ((**(this as *const *const IMdmAlert_Vtbl)).SetSource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAlert", "Status", 14usize, |this| ((**(this as *const *const IMdmAlert_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Target(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAlert", "Target", 15usize, |this| ((**(this as *const *const IMdmAlert_Vtbl)).Target)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTarget(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAlert", "SetTarget", 16usize, |this| ((**(this as *const *const IMdmAlert_Vtbl)).SetTarget)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAlert", "Type", 17usize, |this| ((**(this as *const *const IMdmAlert_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmAlert", "SetType", 18usize, |this| ((**(this as *const *const IMdmAlert_Vtbl)).SetType)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Data,
+            SetData,
+            Format,
+            SetFormat,
+            Mark,
+            SetMark,
+            Source,
+            SetSource,
+            Status,
+            Target,
+            SetTarget,
+            Type,
+            SetType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMdmAlert as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMdmSession_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Alerts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmSession", "Alerts", 6usize, |this| ((**(this as *const *const IMdmSession_Vtbl)).Alerts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmSession", "ExtendedError", 7usize, |this| ((**(this as *const *const IMdmSession_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmSession", "Id", 8usize, |this| ((**(this as *const *const IMdmSession_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut MdmSessionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmSession", "State", 9usize, |this| ((**(this as *const *const IMdmSession_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmSession", "AttachAsync", 10usize, |this| ((**(this as *const *const IMdmSession_Vtbl)).AttachAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmSession", "Delete", 11usize, |this| ((**(this as *const *const IMdmSession_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmSession", "StartAsync", 12usize, |this| ((**(this as *const *const IMdmSession_Vtbl)).StartAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StartWithAlertsAsync(this: *mut core::ffi::c_void, alerts: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmSession", "StartWithAlertsAsync", 13usize, |this| ((**(this as *const *const IMdmSession_Vtbl)).StartWithAlertsAsync)(this, alerts, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Alerts,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Alerts: 0,
+            ExtendedError,
+            Id,
+            State,
+            AttachAsync,
+            Delete,
+            StartAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            StartWithAlertsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StartWithAlertsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMdmSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMdmSessionManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SessionIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmSessionManagerStatics", "SessionIds", 6usize, |this| ((**(this as *const *const IMdmSessionManagerStatics_Vtbl)).SessionIds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryCreateSession(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmSessionManagerStatics", "TryCreateSession", 7usize, |this| ((**(this as *const *const IMdmSessionManagerStatics_Vtbl)).TryCreateSession)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteSessionById(this: *mut core::ffi::c_void, sessionid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmSessionManagerStatics", "DeleteSessionById", 8usize, |this| ((**(this as *const *const IMdmSessionManagerStatics_Vtbl)).DeleteSessionById)(this, sessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSessionById(this: *mut core::ffi::c_void, sessionid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMdmSessionManagerStatics", "GetSessionById", 9usize, |this| ((**(this as *const *const IMdmSessionManagerStatics_Vtbl)).GetSessionById)(this, sessionid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SessionIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SessionIds: 0,
+            TryCreateSession,
+            DeleteSessionById,
+            GetSessionById,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMdmSessionManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
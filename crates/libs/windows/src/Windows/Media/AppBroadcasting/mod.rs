@@ -260,3 +260,5 @@ impl windows_core::RuntimeName for AppBroadcastingUI {
 }
 unsafe impl Send for AppBroadcastingUI {}
 unsafe impl Sync for AppBroadcastingUI {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,120 @@
+impl windows_core::imp::ProxyVtable for IAppBroadcastingMonitor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsCurrentAppBroadcasting(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingMonitor", "IsCurrentAppBroadcasting", 6usize, |this| ((**(this as *const *const IAppBroadcastingMonitor_Vtbl)).IsCurrentAppBroadcasting)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCurrentAppBroadcastingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingMonitor", "IsCurrentAppBroadcastingChanged", 7usize, |this| ((**(this as *const *const IAppBroadcastingMonitor_Vtbl)).IsCurrentAppBroadcastingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveIsCurrentAppBroadcastingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingMonitor", "RemoveIsCurrentAppBroadcastingChanged", 8usize, |this| ((**(this as *const *const IAppBroadcastingMonitor_Vtbl)).RemoveIsCurrentAppBroadcastingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsCurrentAppBroadcasting,
+            IsCurrentAppBroadcastingChanged,
+            RemoveIsCurrentAppBroadcastingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastingMonitor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastingStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanStartBroadcast(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingStatus", "CanStartBroadcast", 6usize, |this| ((**(this as *const *const IAppBroadcastingStatus_Vtbl)).CanStartBroadcast)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Details(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingStatus", "Details", 7usize, |this| ((**(this as *const *const IAppBroadcastingStatus_Vtbl)).Details)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CanStartBroadcast, Details }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastingStatus as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastingStatusDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAnyAppBroadcasting(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingStatusDetails", "IsAnyAppBroadcasting", 6usize, |this| ((**(this as *const *const IAppBroadcastingStatusDetails_Vtbl)).IsAnyAppBroadcasting)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCaptureResourceUnavailable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingStatusDetails", "IsCaptureResourceUnavailable", 7usize, |this| ((**(this as *const *const IAppBroadcastingStatusDetails_Vtbl)).IsCaptureResourceUnavailable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGameStreamInProgress(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingStatusDetails", "IsGameStreamInProgress", 8usize, |this| ((**(this as *const *const IAppBroadcastingStatusDetails_Vtbl)).IsGameStreamInProgress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGpuConstrained(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingStatusDetails", "IsGpuConstrained", 9usize, |this| ((**(this as *const *const IAppBroadcastingStatusDetails_Vtbl)).IsGpuConstrained)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAppInactive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingStatusDetails", "IsAppInactive", 10usize, |this| ((**(this as *const *const IAppBroadcastingStatusDetails_Vtbl)).IsAppInactive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBlockedForApp(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingStatusDetails", "IsBlockedForApp", 11usize, |this| ((**(this as *const *const IAppBroadcastingStatusDetails_Vtbl)).IsBlockedForApp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDisabledByUser(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingStatusDetails", "IsDisabledByUser", 12usize, |this| ((**(this as *const *const IAppBroadcastingStatusDetails_Vtbl)).IsDisabledByUser)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDisabledBySystem(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingStatusDetails", "IsDisabledBySystem", 13usize, |this| ((**(this as *const *const IAppBroadcastingStatusDetails_Vtbl)).IsDisabledBySystem)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsAnyAppBroadcasting,
+            IsCaptureResourceUnavailable,
+            IsGameStreamInProgress,
+            IsGpuConstrained,
+            IsAppInactive,
+            IsBlockedForApp,
+            IsDisabledByUser,
+            IsDisabledBySystem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastingStatusDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastingUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingUI", "GetStatus", 6usize, |this| ((**(this as *const *const IAppBroadcastingUI_Vtbl)).GetStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowBroadcastUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingUI", "ShowBroadcastUI", 7usize, |this| ((**(this as *const *const IAppBroadcastingUI_Vtbl)).ShowBroadcastUI)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStatus, ShowBroadcastUI }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastingUI as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IAppBroadcastingUIStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingUIStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IAppBroadcastingUIStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastingUIStatics", "GetForUser", 7usize, |this| ((**(this as *const *const IAppBroadcastingUIStatics_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDefault,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastingUIStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
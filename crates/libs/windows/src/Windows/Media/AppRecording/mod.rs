@@ -434,6 +434,7 @@ impl AppRecordingSaveScreenshotOption {
 impl windows_core::TypeKind for AppRecordingSaveScreenshotOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppRecordingSaveScreenshotOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppRecordingSaveScreenshotOption").field(&self.0).finish()
@@ -442,3 +443,5 @@ impl core::fmt::Debug for AppRecordingSaveScreenshotOption {
 impl windows_core::RuntimeType for AppRecordingSaveScreenshotOption {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.AppRecording.AppRecordingSaveScreenshotOption;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
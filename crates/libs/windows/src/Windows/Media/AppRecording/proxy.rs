@@ -0,0 +1,207 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IAppRecordingManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingManager", "GetStatus", 6usize, |this| ((**(this as *const *const IAppRecordingManager_Vtbl)).GetStatus)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn StartRecordingToFileAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingManager", "StartRecordingToFileAsync", 7usize, |this| ((**(this as *const *const IAppRecordingManager_Vtbl)).StartRecordingToFileAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn RecordTimeSpanToFileAsync(this: *mut core::ffi::c_void, starttime: super::super::Foundation::DateTime, duration: super::super::Foundation::TimeSpan, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingManager", "RecordTimeSpanToFileAsync", 8usize, |this| ((**(this as *const *const IAppRecordingManager_Vtbl)).RecordTimeSpanToFileAsync)(this, starttime, duration, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedScreenshotMediaEncodingSubtypes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingManager", "SupportedScreenshotMediaEncodingSubtypes", 9usize, |this| ((**(this as *const *const IAppRecordingManager_Vtbl)).SupportedScreenshotMediaEncodingSubtypes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn SaveScreenshotToFilesAsync(this: *mut core::ffi::c_void, folder: *mut core::ffi::c_void, filenameprefix: core::mem::MaybeUninit<windows_core::HSTRING>, option: AppRecordingSaveScreenshotOption, requestedformats: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingManager", "SaveScreenshotToFilesAsync", 10usize, |this| ((**(this as *const *const IAppRecordingManager_Vtbl)).SaveScreenshotToFilesAsync)(this, folder, filenameprefix, option, requestedformats, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStatus,
+            #[cfg(feature = "Storage")]
+            StartRecordingToFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            StartRecordingToFileAsync: 0,
+            #[cfg(feature = "Storage")]
+            RecordTimeSpanToFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            RecordTimeSpanToFileAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedScreenshotMediaEncodingSubtypes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedScreenshotMediaEncodingSubtypes: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            SaveScreenshotToFilesAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            SaveScreenshotToFilesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppRecordingManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppRecordingManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingManagerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IAppRecordingManagerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppRecordingManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppRecordingResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Succeeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingResult", "Succeeded", 6usize, |this| ((**(this as *const *const IAppRecordingResult_Vtbl)).Succeeded)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IAppRecordingResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingResult", "Duration", 8usize, |this| ((**(this as *const *const IAppRecordingResult_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFileTruncated(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingResult", "IsFileTruncated", 9usize, |this| ((**(this as *const *const IAppRecordingResult_Vtbl)).IsFileTruncated)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Succeeded, ExtendedError, Duration, IsFileTruncated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppRecordingResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppRecordingSaveScreenshotResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Succeeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingSaveScreenshotResult", "Succeeded", 6usize, |this| ((**(this as *const *const IAppRecordingSaveScreenshotResult_Vtbl)).Succeeded)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingSaveScreenshotResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IAppRecordingSaveScreenshotResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SavedScreenshotInfos(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingSaveScreenshotResult", "SavedScreenshotInfos", 8usize, |this| ((**(this as *const *const IAppRecordingSaveScreenshotResult_Vtbl)).SavedScreenshotInfos)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Succeeded,
+            ExtendedError,
+            #[cfg(feature = "Foundation_Collections")]
+            SavedScreenshotInfos,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SavedScreenshotInfos: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppRecordingSaveScreenshotResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IAppRecordingSavedScreenshotInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn File(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingSavedScreenshotInfo", "File", 6usize, |this| ((**(this as *const *const IAppRecordingSavedScreenshotInfo_Vtbl)).File)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaEncodingSubtype(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingSavedScreenshotInfo", "MediaEncodingSubtype", 7usize, |this| ((**(this as *const *const IAppRecordingSavedScreenshotInfo_Vtbl)).MediaEncodingSubtype)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            File,
+            #[cfg(not(feature = "Storage"))]
+            File: 0,
+            MediaEncodingSubtype,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppRecordingSavedScreenshotInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppRecordingStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanRecord(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingStatus", "CanRecord", 6usize, |this| ((**(this as *const *const IAppRecordingStatus_Vtbl)).CanRecord)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanRecordTimeSpan(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingStatus", "CanRecordTimeSpan", 7usize, |this| ((**(this as *const *const IAppRecordingStatus_Vtbl)).CanRecordTimeSpan)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HistoricalBufferDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingStatus", "HistoricalBufferDuration", 8usize, |this| ((**(this as *const *const IAppRecordingStatus_Vtbl)).HistoricalBufferDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Details(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingStatus", "Details", 9usize, |this| ((**(this as *const *const IAppRecordingStatus_Vtbl)).Details)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanRecord,
+            CanRecordTimeSpan,
+            HistoricalBufferDuration,
+            Details,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppRecordingStatus as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppRecordingStatusDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAnyAppBroadcasting(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingStatusDetails", "IsAnyAppBroadcasting", 6usize, |this| ((**(this as *const *const IAppRecordingStatusDetails_Vtbl)).IsAnyAppBroadcasting)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCaptureResourceUnavailable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingStatusDetails", "IsCaptureResourceUnavailable", 7usize, |this| ((**(this as *const *const IAppRecordingStatusDetails_Vtbl)).IsCaptureResourceUnavailable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGameStreamInProgress(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingStatusDetails", "IsGameStreamInProgress", 8usize, |this| ((**(this as *const *const IAppRecordingStatusDetails_Vtbl)).IsGameStreamInProgress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTimeSpanRecordingDisabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingStatusDetails", "IsTimeSpanRecordingDisabled", 9usize, |this| ((**(this as *const *const IAppRecordingStatusDetails_Vtbl)).IsTimeSpanRecordingDisabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGpuConstrained(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingStatusDetails", "IsGpuConstrained", 10usize, |this| ((**(this as *const *const IAppRecordingStatusDetails_Vtbl)).IsGpuConstrained)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAppInactive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingStatusDetails", "IsAppInactive", 11usize, |this| ((**(this as *const *const IAppRecordingStatusDetails_Vtbl)).IsAppInactive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBlockedForApp(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingStatusDetails", "IsBlockedForApp", 12usize, |this| ((**(this as *const *const IAppRecordingStatusDetails_Vtbl)).IsBlockedForApp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDisabledByUser(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingStatusDetails", "IsDisabledByUser", 13usize, |this| ((**(this as *const *const IAppRecordingStatusDetails_Vtbl)).IsDisabledByUser)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDisabledBySystem(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppRecordingStatusDetails", "IsDisabledBySystem", 14usize, |this| ((**(this as *const *const IAppRecordingStatusDetails_Vtbl)).IsDisabledBySystem)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsAnyAppBroadcasting,
+            IsCaptureResourceUnavailable,
+            IsGameStreamInProgress,
+            IsTimeSpanRecordingDisabled,
+            IsGpuConstrained,
+            IsAppInactive,
+            IsBlockedForApp,
+            IsDisabledByUser,
+            IsDisabledBySystem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppRecordingStatusDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -4914,6 +4914,7 @@ impl AudioDeviceNodeCreationStatus {
 impl windows_core::TypeKind for AudioDeviceNodeCreationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioDeviceNodeCreationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioDeviceNodeCreationStatus").field(&self.0).finish()
@@ -4933,6 +4934,7 @@ impl AudioEffectsPackStatus {
 impl windows_core::TypeKind for AudioEffectsPackStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioEffectsPackStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioEffectsPackStatus").field(&self.0).finish()
@@ -4954,6 +4956,7 @@ impl AudioFileNodeCreationStatus {
 impl windows_core::TypeKind for AudioFileNodeCreationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioFileNodeCreationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioFileNodeCreationStatus").field(&self.0).finish()
@@ -4974,6 +4977,7 @@ impl AudioGraphCreationStatus {
 impl windows_core::TypeKind for AudioGraphCreationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioGraphCreationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioGraphCreationStatus").field(&self.0).finish()
@@ -4994,6 +4998,7 @@ impl AudioGraphUnrecoverableError {
 impl windows_core::TypeKind for AudioGraphUnrecoverableError {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioGraphUnrecoverableError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioGraphUnrecoverableError").field(&self.0).finish()
@@ -5012,6 +5017,7 @@ impl AudioNodeEmitterDecayKind {
 impl windows_core::TypeKind for AudioNodeEmitterDecayKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioNodeEmitterDecayKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioNodeEmitterDecayKind").field(&self.0).finish()
@@ -5030,6 +5036,7 @@ impl AudioNodeEmitterSettings {
 impl windows_core::TypeKind for AudioNodeEmitterSettings {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioNodeEmitterSettings {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioNodeEmitterSettings").field(&self.0).finish()
@@ -5081,6 +5088,7 @@ impl AudioNodeEmitterShapeKind {
 impl windows_core::TypeKind for AudioNodeEmitterShapeKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioNodeEmitterShapeKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioNodeEmitterShapeKind").field(&self.0).finish()
@@ -5101,6 +5109,7 @@ impl AudioPlaybackConnectionOpenResultStatus {
 impl windows_core::TypeKind for AudioPlaybackConnectionOpenResultStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioPlaybackConnectionOpenResultStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioPlaybackConnectionOpenResultStatus").field(&self.0).finish()
@@ -5119,6 +5128,7 @@ impl AudioPlaybackConnectionState {
 impl windows_core::TypeKind for AudioPlaybackConnectionState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioPlaybackConnectionState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioPlaybackConnectionState").field(&self.0).finish()
@@ -5139,6 +5149,7 @@ impl MediaSourceAudioInputNodeCreationStatus {
 impl windows_core::TypeKind for MediaSourceAudioInputNodeCreationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaSourceAudioInputNodeCreationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaSourceAudioInputNodeCreationStatus").field(&self.0).finish()
@@ -5157,6 +5168,7 @@ impl MixedRealitySpatialAudioFormatPolicy {
 impl windows_core::TypeKind for MixedRealitySpatialAudioFormatPolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MixedRealitySpatialAudioFormatPolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MixedRealitySpatialAudioFormatPolicy").field(&self.0).finish()
@@ -5176,6 +5188,7 @@ impl QuantumSizeSelectionMode {
 impl windows_core::TypeKind for QuantumSizeSelectionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QuantumSizeSelectionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QuantumSizeSelectionMode").field(&self.0).finish()
@@ -5198,6 +5211,7 @@ impl SetDefaultSpatialAudioFormatStatus {
 impl windows_core::TypeKind for SetDefaultSpatialAudioFormatStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SetDefaultSpatialAudioFormatStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SetDefaultSpatialAudioFormatStatus").field(&self.0).finish()
@@ -5216,6 +5230,7 @@ impl SpatialAudioModel {
 impl windows_core::TypeKind for SpatialAudioModel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialAudioModel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialAudioModel").field(&self.0).finish()
@@ -5226,3 +5241,5 @@ impl windows_core::RuntimeType for SpatialAudioModel {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,2010 @@
+#[cfg(all(feature = "Devices_Enumeration", feature = "Foundation_Collections", feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IAudioDeviceInputNode_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn Device(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceInputNode", "Device", 6usize, |this| ((**(this as *const *const IAudioDeviceInputNode_Vtbl)).Device)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Enumeration")]
+            Device,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            Device: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioDeviceInputNode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "Foundation_Collections", feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IAudioDeviceOutputNode_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn Device(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceOutputNode", "Device", 6usize, |this| ((**(this as *const *const IAudioDeviceOutputNode_Vtbl)).Device)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Enumeration")]
+            Device,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            Device: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioDeviceOutputNode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEffectsPackConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsPackConfiguration", "DeviceId", 6usize, |this| ((**(this as *const *const IAudioEffectsPackConfiguration_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EffectsPackId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsPackConfiguration", "EffectsPackId", 7usize, |this| ((**(this as *const *const IAudioEffectsPackConfiguration_Vtbl)).EffectsPackId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut AudioEffectsPackStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsPackConfiguration", "Status", 8usize, |this| ((**(this as *const *const IAudioEffectsPackConfiguration_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsPackConfiguration", "StatusChanged", 9usize, |this| ((**(this as *const *const IAudioEffectsPackConfiguration_Vtbl)).StatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsPackConfiguration", "RemoveStatusChanged", 10usize, |this| ((**(this as *const *const IAudioEffectsPackConfiguration_Vtbl)).RemoveStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            EffectsPackId,
+            Status,
+            StatusChanged,
+            RemoveStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEffectsPackConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEffectsPackConfigurationStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForDeviceId(this: *mut core::ffi::c_void, effectspackid: core::mem::MaybeUninit<windows_core::HSTRING>, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsPackConfigurationStatics", "GetForDeviceId", 6usize, |this| ((**(this as *const *const IAudioEffectsPackConfigurationStatics_Vtbl)).GetForDeviceId)(this, effectspackid, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDeviceIdSupported(this: *mut core::ffi::c_void, effectspackid: core::mem::MaybeUninit<windows_core::HSTRING>, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsPackConfigurationStatics", "IsDeviceIdSupported", 7usize, |this| ((**(this as *const *const IAudioEffectsPackConfigurationStatics_Vtbl)).IsDeviceIdSupported)(this, effectspackid, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForDeviceId, IsDeviceIdSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEffectsPackConfigurationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects", feature = "Media_MediaProperties", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IAudioFileInputNode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPlaybackSpeedFactor(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileInputNode", "SetPlaybackSpeedFactor", 6usize, |this| ((**(this as *const *const IAudioFileInputNode_Vtbl)).SetPlaybackSpeedFactor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackSpeedFactor(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileInputNode", "PlaybackSpeedFactor", 7usize, |this| ((**(this as *const *const IAudioFileInputNode_Vtbl)).PlaybackSpeedFactor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileInputNode", "Position", 8usize, |this| ((**(this as *const *const IAudioFileInputNode_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, position: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileInputNode", "Seek", 9usize, |this| ((**(this as *const *const IAudioFileInputNode_Vtbl)).Seek)(this, position), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileInputNode", "StartTime", 10usize, |this| ((**(this as *const *const IAudioFileInputNode_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileInputNode", "SetStartTime", 11usize, |this| ((**(this as *const *const IAudioFileInputNode_Vtbl)).SetStartTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileInputNode", "EndTime", 12usize, |this| ((**(this as *const *const IAudioFileInputNode_Vtbl)).EndTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEndTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileInputNode", "SetEndTime", 13usize, |this| ((**(this as *const *const IAudioFileInputNode_Vtbl)).SetEndTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoopCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileInputNode", "LoopCount", 14usize, |this| ((**(this as *const *const IAudioFileInputNode_Vtbl)).LoopCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLoopCount(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileInputNode", "SetLoopCount", 15usize, |this| ((**(this as *const *const IAudioFileInputNode_Vtbl)).SetLoopCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileInputNode", "Duration", 16usize, |this| ((**(this as *const *const IAudioFileInputNode_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SourceFile(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileInputNode", "SourceFile", 17usize, |this| ((**(this as *const *const IAudioFileInputNode_Vtbl)).SourceFile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileInputNode", "FileCompleted", 18usize, |this| ((**(this as *const *const IAudioFileInputNode_Vtbl)).FileCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFileCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileInputNode", "RemoveFileCompleted", 19usize, |this| ((**(this as *const *const IAudioFileInputNode_Vtbl)).RemoveFileCompleted)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPlaybackSpeedFactor,
+            PlaybackSpeedFactor,
+            Position,
+            Seek,
+            StartTime,
+            SetStartTime,
+            EndTime,
+            SetEndTime,
+            LoopCount,
+            SetLoopCount,
+            Duration,
+            #[cfg(feature = "Storage")]
+            SourceFile,
+            #[cfg(not(feature = "Storage"))]
+            SourceFile: 0,
+            FileCompleted,
+            RemoveFileCompleted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioFileInputNode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects", feature = "Media_MediaProperties", feature = "Media_Transcoding", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IAudioFileOutputNode_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn File(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileOutputNode", "File", 6usize, |this| ((**(this as *const *const IAudioFileOutputNode_Vtbl)).File)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn FileEncodingProfile(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileOutputNode", "FileEncodingProfile", 7usize, |this| ((**(this as *const *const IAudioFileOutputNode_Vtbl)).FileEncodingProfile)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Transcoding")]
+        unsafe extern "system" fn FinalizeAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFileOutputNode", "FinalizeAsync", 8usize, |this| ((**(this as *const *const IAudioFileOutputNode_Vtbl)).FinalizeAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            File,
+            #[cfg(not(feature = "Storage"))]
+            File: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            FileEncodingProfile,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            FileEncodingProfile: 0,
+            #[cfg(feature = "Media_Transcoding")]
+            FinalizeAsync,
+            #[cfg(not(feature = "Media_Transcoding"))]
+            FinalizeAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioFileOutputNode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioFrameCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Frame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFrameCompletedEventArgs", "Frame", 6usize, |this| ((**(this as *const *const IAudioFrameCompletedEventArgs_Vtbl)).Frame)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Frame }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioFrameCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IAudioFrameInputNode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPlaybackSpeedFactor(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFrameInputNode", "SetPlaybackSpeedFactor", 6usize, |this| ((**(this as *const *const IAudioFrameInputNode_Vtbl)).SetPlaybackSpeedFactor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackSpeedFactor(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFrameInputNode", "PlaybackSpeedFactor", 7usize, |this| ((**(this as *const *const IAudioFrameInputNode_Vtbl)).PlaybackSpeedFactor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddFrame(this: *mut core::ffi::c_void, frame: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFrameInputNode", "AddFrame", 8usize, |this| ((**(this as *const *const IAudioFrameInputNode_Vtbl)).AddFrame)(this, frame), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscardQueuedFrames(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFrameInputNode", "DiscardQueuedFrames", 9usize, |this| ((**(this as *const *const IAudioFrameInputNode_Vtbl)).DiscardQueuedFrames)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueuedSampleCount(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFrameInputNode", "QueuedSampleCount", 10usize, |this| ((**(this as *const *const IAudioFrameInputNode_Vtbl)).QueuedSampleCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFrameCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFrameInputNode", "AudioFrameCompleted", 11usize, |this| ((**(this as *const *const IAudioFrameInputNode_Vtbl)).AudioFrameCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAudioFrameCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFrameInputNode", "RemoveAudioFrameCompleted", 12usize, |this| ((**(this as *const *const IAudioFrameInputNode_Vtbl)).RemoveAudioFrameCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuantumStarted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFrameInputNode", "QuantumStarted", 13usize, |this| ((**(this as *const *const IAudioFrameInputNode_Vtbl)).QuantumStarted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveQuantumStarted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFrameInputNode", "RemoveQuantumStarted", 14usize, |this| ((**(this as *const *const IAudioFrameInputNode_Vtbl)).RemoveQuantumStarted)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPlaybackSpeedFactor,
+            PlaybackSpeedFactor,
+            AddFrame,
+            DiscardQueuedFrames,
+            QueuedSampleCount,
+            AudioFrameCompleted,
+            RemoveAudioFrameCompleted,
+            QuantumStarted,
+            RemoveQuantumStarted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioFrameInputNode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IAudioFrameOutputNode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFrameOutputNode", "GetFrame", 6usize, |this| ((**(this as *const *const IAudioFrameOutputNode_Vtbl)).GetFrame)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFrame }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioFrameOutputNode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "Media_Capture", feature = "Media_MediaProperties", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IAudioGraph_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFrameInputNode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "CreateFrameInputNode", 6usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).CreateFrameInputNode)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn CreateFrameInputNodeWithFormat(this: *mut core::ffi::c_void, encodingproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "CreateFrameInputNodeWithFormat", 7usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).CreateFrameInputNodeWithFormat)(this, encodingproperties, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Capture")]
+        unsafe extern "system" fn CreateDeviceInputNodeAsync(this: *mut core::ffi::c_void, category: super::Capture::MediaCategory, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "CreateDeviceInputNodeAsync", 8usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).CreateDeviceInputNodeAsync)(this, category, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_Capture", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn CreateDeviceInputNodeWithFormatAsync(this: *mut core::ffi::c_void, category: super::Capture::MediaCategory, encodingproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "CreateDeviceInputNodeWithFormatAsync", 9usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).CreateDeviceInputNodeWithFormatAsync)(this, category, encodingproperties, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Enumeration", feature = "Media_Capture", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn CreateDeviceInputNodeWithFormatOnDeviceAsync(this: *mut core::ffi::c_void, category: super::Capture::MediaCategory, encodingproperties: *mut core::ffi::c_void, device: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "CreateDeviceInputNodeWithFormatOnDeviceAsync", 10usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).CreateDeviceInputNodeWithFormatOnDeviceAsync)(this, category, encodingproperties, device, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFrameOutputNode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "CreateFrameOutputNode", 11usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).CreateFrameOutputNode)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn CreateFrameOutputNodeWithFormat(this: *mut core::ffi::c_void, encodingproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "CreateFrameOutputNodeWithFormat", 12usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).CreateFrameOutputNodeWithFormat)(this, encodingproperties, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDeviceOutputNodeAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "CreateDeviceOutputNodeAsync", 13usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).CreateDeviceOutputNodeAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CreateFileInputNodeAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "CreateFileInputNodeAsync", 14usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).CreateFileInputNodeAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CreateFileOutputNodeAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "CreateFileOutputNodeAsync", 15usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).CreateFileOutputNodeAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_MediaProperties", feature = "Storage"))]
+        unsafe extern "system" fn CreateFileOutputNodeWithFileProfileAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, fileencodingprofile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "CreateFileOutputNodeWithFileProfileAsync", 16usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).CreateFileOutputNodeWithFileProfileAsync)(this, file, fileencodingprofile, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSubmixNode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "CreateSubmixNode", 17usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).CreateSubmixNode)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn CreateSubmixNodeWithFormat(this: *mut core::ffi::c_void, encodingproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "CreateSubmixNodeWithFormat", 18usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).CreateSubmixNodeWithFormat)(this, encodingproperties, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "Start", 19usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "Stop", 20usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetAllNodes(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "ResetAllNodes", 21usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).ResetAllNodes)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuantumStarted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "QuantumStarted", 22usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).QuantumStarted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveQuantumStarted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "RemoveQuantumStarted", 23usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).RemoveQuantumStarted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuantumProcessed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "QuantumProcessed", 24usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).QuantumProcessed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveQuantumProcessed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "RemoveQuantumProcessed", 25usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).RemoveQuantumProcessed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnrecoverableErrorOccurred(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "UnrecoverableErrorOccurred", 26usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).UnrecoverableErrorOccurred)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUnrecoverableErrorOccurred(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "RemoveUnrecoverableErrorOccurred", 27usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).RemoveUnrecoverableErrorOccurred)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompletedQuantumCount(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "CompletedQuantumCount", 28usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).CompletedQuantumCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn EncodingProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "EncodingProperties", 29usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).EncodingProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LatencyInSamples(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "LatencyInSamples", 30usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).LatencyInSamples)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn PrimaryRenderDevice(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "PrimaryRenderDevice", 31usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).PrimaryRenderDevice)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenderDeviceAudioProcessing(this: *mut core::ffi::c_void, result__: *mut super::AudioProcessing) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "RenderDeviceAudioProcessing", 32usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).RenderDeviceAudioProcessing)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SamplesPerQuantum(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph", "SamplesPerQuantum", 33usize, |this| ((**(this as *const *const IAudioGraph_Vtbl)).SamplesPerQuantum)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFrameInputNode,
+            #[cfg(feature = "Media_MediaProperties")]
+            CreateFrameInputNodeWithFormat,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            CreateFrameInputNodeWithFormat: 0,
+            #[cfg(feature = "Media_Capture")]
+            CreateDeviceInputNodeAsync,
+            #[cfg(not(feature = "Media_Capture"))]
+            CreateDeviceInputNodeAsync: 0,
+            #[cfg(all(feature = "Media_Capture", feature = "Media_MediaProperties"))]
+            CreateDeviceInputNodeWithFormatAsync,
+            #[cfg(not(all(feature = "Media_Capture", feature = "Media_MediaProperties")))]
+            CreateDeviceInputNodeWithFormatAsync: 0,
+            #[cfg(all(feature = "Devices_Enumeration", feature = "Media_Capture", feature = "Media_MediaProperties"))]
+            CreateDeviceInputNodeWithFormatOnDeviceAsync,
+            #[cfg(not(all(feature = "Devices_Enumeration", feature = "Media_Capture", feature = "Media_MediaProperties")))]
+            CreateDeviceInputNodeWithFormatOnDeviceAsync: 0,
+            CreateFrameOutputNode,
+            #[cfg(feature = "Media_MediaProperties")]
+            CreateFrameOutputNodeWithFormat,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            CreateFrameOutputNodeWithFormat: 0,
+            CreateDeviceOutputNodeAsync,
+            #[cfg(feature = "Storage")]
+            CreateFileInputNodeAsync,
+            #[cfg(not(feature = "Storage"))]
+            CreateFileInputNodeAsync: 0,
+            #[cfg(feature = "Storage")]
+            CreateFileOutputNodeAsync,
+            #[cfg(not(feature = "Storage"))]
+            CreateFileOutputNodeAsync: 0,
+            #[cfg(all(feature = "Media_MediaProperties", feature = "Storage"))]
+            CreateFileOutputNodeWithFileProfileAsync,
+            #[cfg(not(all(feature = "Media_MediaProperties", feature = "Storage")))]
+            CreateFileOutputNodeWithFileProfileAsync: 0,
+            CreateSubmixNode,
+            #[cfg(feature = "Media_MediaProperties")]
+            CreateSubmixNodeWithFormat,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            CreateSubmixNodeWithFormat: 0,
+            Start,
+            Stop,
+            ResetAllNodes,
+            QuantumStarted,
+            RemoveQuantumStarted,
+            QuantumProcessed,
+            RemoveQuantumProcessed,
+            UnrecoverableErrorOccurred,
+            RemoveUnrecoverableErrorOccurred,
+            CompletedQuantumCount,
+            #[cfg(feature = "Media_MediaProperties")]
+            EncodingProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            EncodingProperties: 0,
+            LatencyInSamples,
+            #[cfg(feature = "Devices_Enumeration")]
+            PrimaryRenderDevice,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            PrimaryRenderDevice: 0,
+            RenderDeviceAudioProcessing,
+            SamplesPerQuantum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioGraph as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "Media_Capture", feature = "Media_MediaProperties", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IAudioGraph2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn CreateFrameInputNodeWithFormatAndEmitter(this: *mut core::ffi::c_void, encodingproperties: *mut core::ffi::c_void, emitter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph2", "CreateFrameInputNodeWithFormatAndEmitter", 6usize, |this| ((**(this as *const *const IAudioGraph2_Vtbl)).CreateFrameInputNodeWithFormatAndEmitter)(this, encodingproperties, emitter, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Enumeration", feature = "Media_Capture", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn CreateDeviceInputNodeWithFormatAndEmitterOnDeviceAsync(this: *mut core::ffi::c_void, category: super::Capture::MediaCategory, encodingproperties: *mut core::ffi::c_void, device: *mut core::ffi::c_void, emitter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph2", "CreateDeviceInputNodeWithFormatAndEmitterOnDeviceAsync", 7usize, |this| ((**(this as *const *const IAudioGraph2_Vtbl)).CreateDeviceInputNodeWithFormatAndEmitterOnDeviceAsync)(this, category, encodingproperties, device, emitter, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CreateFileInputNodeWithEmitterAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, emitter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph2", "CreateFileInputNodeWithEmitterAsync", 8usize, |this| ((**(this as *const *const IAudioGraph2_Vtbl)).CreateFileInputNodeWithEmitterAsync)(this, file, emitter, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn CreateSubmixNodeWithFormatAndEmitter(this: *mut core::ffi::c_void, encodingproperties: *mut core::ffi::c_void, emitter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph2", "CreateSubmixNodeWithFormatAndEmitter", 9usize, |this| ((**(this as *const *const IAudioGraph2_Vtbl)).CreateSubmixNodeWithFormatAndEmitter)(this, encodingproperties, emitter, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBatchUpdater(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph2", "CreateBatchUpdater", 10usize, |this| ((**(this as *const *const IAudioGraph2_Vtbl)).CreateBatchUpdater)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_MediaProperties")]
+            CreateFrameInputNodeWithFormatAndEmitter,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            CreateFrameInputNodeWithFormatAndEmitter: 0,
+            #[cfg(all(feature = "Devices_Enumeration", feature = "Media_Capture", feature = "Media_MediaProperties"))]
+            CreateDeviceInputNodeWithFormatAndEmitterOnDeviceAsync,
+            #[cfg(not(all(feature = "Devices_Enumeration", feature = "Media_Capture", feature = "Media_MediaProperties")))]
+            CreateDeviceInputNodeWithFormatAndEmitterOnDeviceAsync: 0,
+            #[cfg(feature = "Storage")]
+            CreateFileInputNodeWithEmitterAsync,
+            #[cfg(not(feature = "Storage"))]
+            CreateFileInputNodeWithEmitterAsync: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            CreateSubmixNodeWithFormatAndEmitter,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            CreateSubmixNodeWithFormatAndEmitter: 0,
+            CreateBatchUpdater,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioGraph2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Core")]
+impl windows_core::imp::ProxyVtable for IAudioGraph3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn CreateMediaSourceAudioInputNodeAsync(this: *mut core::ffi::c_void, mediasource: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph3", "CreateMediaSourceAudioInputNodeAsync", 6usize, |this| ((**(this as *const *const IAudioGraph3_Vtbl)).CreateMediaSourceAudioInputNodeAsync)(this, mediasource, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn CreateMediaSourceAudioInputNodeWithEmitterAsync(this: *mut core::ffi::c_void, mediasource: *mut core::ffi::c_void, emitter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraph3", "CreateMediaSourceAudioInputNodeWithEmitterAsync", 7usize, |this| ((**(this as *const *const IAudioGraph3_Vtbl)).CreateMediaSourceAudioInputNodeWithEmitterAsync)(this, mediasource, emitter, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Core")]
+            CreateMediaSourceAudioInputNodeAsync,
+            #[cfg(not(feature = "Media_Core"))]
+            CreateMediaSourceAudioInputNodeAsync: 0,
+            #[cfg(feature = "Media_Core")]
+            CreateMediaSourceAudioInputNodeWithEmitterAsync,
+            #[cfg(not(feature = "Media_Core"))]
+            CreateMediaSourceAudioInputNodeWithEmitterAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioGraph3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioGraphConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Destination(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphConnection", "Destination", 6usize, |this| ((**(this as *const *const IAudioGraphConnection_Vtbl)).Destination)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGain(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphConnection", "SetGain", 7usize, |this| ((**(this as *const *const IAudioGraphConnection_Vtbl)).SetGain)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphConnection", "Gain", 8usize, |this| ((**(this as *const *const IAudioGraphConnection_Vtbl)).Gain)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Destination, SetGain, Gain }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioGraphConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "Media_MediaProperties", feature = "Media_Render"))]
+impl windows_core::imp::ProxyVtable for IAudioGraphSettings_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn EncodingProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphSettings", "EncodingProperties", 6usize, |this| ((**(this as *const *const IAudioGraphSettings_Vtbl)).EncodingProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn SetEncodingProperties(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphSettings", "SetEncodingProperties", 7usize, |this| ((**(this as *const *const IAudioGraphSettings_Vtbl)).SetEncodingProperties)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn PrimaryRenderDevice(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphSettings", "PrimaryRenderDevice", 8usize, |this| ((**(this as *const *const IAudioGraphSettings_Vtbl)).PrimaryRenderDevice)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn SetPrimaryRenderDevice(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphSettings", "SetPrimaryRenderDevice", 9usize, |this| ((**(this as *const *const IAudioGraphSettings_Vtbl)).SetPrimaryRenderDevice)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuantumSizeSelectionMode(this: *mut core::ffi::c_void, result__: *mut QuantumSizeSelectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphSettings", "QuantumSizeSelectionMode", 10usize, |this| ((**(this as *const *const IAudioGraphSettings_Vtbl)).QuantumSizeSelectionMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuantumSizeSelectionMode(this: *mut core::ffi::c_void, value: QuantumSizeSelectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphSettings", "SetQuantumSizeSelectionMode", 11usize, |this| ((**(this as *const *const IAudioGraphSettings_Vtbl)).SetQuantumSizeSelectionMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredSamplesPerQuantum(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphSettings", "DesiredSamplesPerQuantum", 12usize, |this| ((**(this as *const *const IAudioGraphSettings_Vtbl)).DesiredSamplesPerQuantum)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredSamplesPerQuantum(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphSettings", "SetDesiredSamplesPerQuantum", 13usize, |this| ((**(this as *const *const IAudioGraphSettings_Vtbl)).SetDesiredSamplesPerQuantum)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Render")]
+        unsafe extern "system" fn AudioRenderCategory(this: *mut core::ffi::c_void, result__: *mut super::Render::AudioRenderCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphSettings", "AudioRenderCategory", 14usize, |this| ((**(this as *const *const IAudioGraphSettings_Vtbl)).AudioRenderCategory)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Render")]
+        unsafe extern "system" fn SetAudioRenderCategory(this: *mut core::ffi::c_void, value: super::Render::AudioRenderCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphSettings", "SetAudioRenderCategory", 15usize, |this| ((**(this as *const *const IAudioGraphSettings_Vtbl)).SetAudioRenderCategory)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredRenderDeviceAudioProcessing(this: *mut core::ffi::c_void, result__: *mut super::AudioProcessing) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphSettings", "DesiredRenderDeviceAudioProcessing", 16usize, |this| ((**(this as *const *const IAudioGraphSettings_Vtbl)).DesiredRenderDeviceAudioProcessing)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredRenderDeviceAudioProcessing(this: *mut core::ffi::c_void, value: super::AudioProcessing) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphSettings", "SetDesiredRenderDeviceAudioProcessing", 17usize, |this| ((**(this as *const *const IAudioGraphSettings_Vtbl)).SetDesiredRenderDeviceAudioProcessing)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_MediaProperties")]
+            EncodingProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            EncodingProperties: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            SetEncodingProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            SetEncodingProperties: 0,
+            #[cfg(feature = "Devices_Enumeration")]
+            PrimaryRenderDevice,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            PrimaryRenderDevice: 0,
+            #[cfg(feature = "Devices_Enumeration")]
+            SetPrimaryRenderDevice,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            SetPrimaryRenderDevice: 0,
+            QuantumSizeSelectionMode,
+            SetQuantumSizeSelectionMode,
+            DesiredSamplesPerQuantum,
+            SetDesiredSamplesPerQuantum,
+            #[cfg(feature = "Media_Render")]
+            AudioRenderCategory,
+            #[cfg(not(feature = "Media_Render"))]
+            AudioRenderCategory: 0,
+            #[cfg(feature = "Media_Render")]
+            SetAudioRenderCategory,
+            #[cfg(not(feature = "Media_Render"))]
+            SetAudioRenderCategory: 0,
+            DesiredRenderDeviceAudioProcessing,
+            SetDesiredRenderDeviceAudioProcessing,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioGraphSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioGraphSettings2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMaxPlaybackSpeedFactor(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphSettings2", "SetMaxPlaybackSpeedFactor", 6usize, |this| ((**(this as *const *const IAudioGraphSettings2_Vtbl)).SetMaxPlaybackSpeedFactor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPlaybackSpeedFactor(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphSettings2", "MaxPlaybackSpeedFactor", 7usize, |this| ((**(this as *const *const IAudioGraphSettings2_Vtbl)).MaxPlaybackSpeedFactor)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMaxPlaybackSpeedFactor, MaxPlaybackSpeedFactor }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioGraphSettings2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Render")]
+impl windows_core::imp::ProxyVtable for IAudioGraphSettingsFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Render")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, audiorendercategory: super::Render::AudioRenderCategory, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphSettingsFactory", "Create", 6usize, |this| ((**(this as *const *const IAudioGraphSettingsFactory_Vtbl)).Create)(this, audiorendercategory, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Render")]
+            Create,
+            #[cfg(not(feature = "Media_Render"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioGraphSettingsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioGraphStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAsync(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphStatics", "CreateAsync", 6usize, |this| ((**(this as *const *const IAudioGraphStatics_Vtbl)).CreateAsync)(this, settings, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioGraphStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioGraphUnrecoverableErrorOccurredEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut AudioGraphUnrecoverableError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioGraphUnrecoverableErrorOccurredEventArgs", "Error", 6usize, |this| ((**(this as *const *const IAudioGraphUnrecoverableErrorOccurredEventArgs_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Error }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioGraphUnrecoverableErrorOccurredEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IAudioInputNode_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn OutgoingConnections(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioInputNode", "OutgoingConnections", 6usize, |this| ((**(this as *const *const IAudioInputNode_Vtbl)).OutgoingConnections)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddOutgoingConnection(this: *mut core::ffi::c_void, destination: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioInputNode", "AddOutgoingConnection", 7usize, |this| ((**(this as *const *const IAudioInputNode_Vtbl)).AddOutgoingConnection)(this, destination), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddOutgoingConnectionWithGain(this: *mut core::ffi::c_void, destination: *mut core::ffi::c_void, gain: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioInputNode", "AddOutgoingConnectionWithGain", 8usize, |this| ((**(this as *const *const IAudioInputNode_Vtbl)).AddOutgoingConnectionWithGain)(this, destination, gain), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOutgoingConnection(this: *mut core::ffi::c_void, destination: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioInputNode", "RemoveOutgoingConnection", 9usize, |this| ((**(this as *const *const IAudioInputNode_Vtbl)).RemoveOutgoingConnection)(this, destination), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            OutgoingConnections,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            OutgoingConnections: 0,
+            AddOutgoingConnection,
+            AddOutgoingConnectionWithGain,
+            RemoveOutgoingConnection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioInputNode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IAudioInputNode2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Emitter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioInputNode2", "Emitter", 6usize, |this| ((**(this as *const *const IAudioInputNode2_Vtbl)).Emitter)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Emitter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioInputNode2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IAudioNode_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects"))]
+        unsafe extern "system" fn EffectDefinitions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNode", "EffectDefinitions", 6usize, |this| ((**(this as *const *const IAudioNode_Vtbl)).EffectDefinitions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutgoingGain(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNode", "SetOutgoingGain", 7usize, |this| ((**(this as *const *const IAudioNode_Vtbl)).SetOutgoingGain)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutgoingGain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNode", "OutgoingGain", 8usize, |this| ((**(this as *const *const IAudioNode_Vtbl)).OutgoingGain)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn EncodingProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNode", "EncodingProperties", 9usize, |this| ((**(this as *const *const IAudioNode_Vtbl)).EncodingProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConsumeInput(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNode", "ConsumeInput", 10usize, |this| ((**(this as *const *const IAudioNode_Vtbl)).ConsumeInput)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConsumeInput(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNode", "SetConsumeInput", 11usize, |this| ((**(this as *const *const IAudioNode_Vtbl)).SetConsumeInput)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNode", "Start", 12usize, |this| ((**(this as *const *const IAudioNode_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNode", "Stop", 13usize, |this| ((**(this as *const *const IAudioNode_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNode", "Reset", 14usize, |this| ((**(this as *const *const IAudioNode_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Effects")]
+        unsafe extern "system" fn DisableEffectsByDefinition(this: *mut core::ffi::c_void, definition: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNode", "DisableEffectsByDefinition", 15usize, |this| ((**(this as *const *const IAudioNode_Vtbl)).DisableEffectsByDefinition)(this, definition), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Effects")]
+        unsafe extern "system" fn EnableEffectsByDefinition(this: *mut core::ffi::c_void, definition: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNode", "EnableEffectsByDefinition", 16usize, |this| ((**(this as *const *const IAudioNode_Vtbl)).EnableEffectsByDefinition)(this, definition), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects"))]
+            EffectDefinitions,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Effects")))]
+            EffectDefinitions: 0,
+            SetOutgoingGain,
+            OutgoingGain,
+            #[cfg(feature = "Media_MediaProperties")]
+            EncodingProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            EncodingProperties: 0,
+            ConsumeInput,
+            SetConsumeInput,
+            Start,
+            Stop,
+            Reset,
+            #[cfg(feature = "Media_Effects")]
+            DisableEffectsByDefinition,
+            #[cfg(not(feature = "Media_Effects"))]
+            DisableEffectsByDefinition: 0,
+            #[cfg(feature = "Media_Effects")]
+            EnableEffectsByDefinition,
+            #[cfg(not(feature = "Media_Effects"))]
+            EnableEffectsByDefinition: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioNode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IAudioNodeEmitter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter", "Position", 6usize, |this| ((**(this as *const *const IAudioNodeEmitter_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter", "SetPosition", 7usize, |this| ((**(this as *const *const IAudioNodeEmitter_Vtbl)).SetPosition)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Direction(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter", "Direction", 8usize, |this| ((**(this as *const *const IAudioNodeEmitter_Vtbl)).Direction)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetDirection(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter", "SetDirection", 9usize, |this| ((**(this as *const *const IAudioNodeEmitter_Vtbl)).SetDirection)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shape(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter", "Shape", 10usize, |this| ((**(this as *const *const IAudioNodeEmitter_Vtbl)).Shape)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecayModel(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter", "DecayModel", 11usize, |this| ((**(this as *const *const IAudioNodeEmitter_Vtbl)).DecayModel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter", "Gain", 12usize, |this| ((**(this as *const *const IAudioNodeEmitter_Vtbl)).Gain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGain(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter", "SetGain", 13usize, |this| ((**(this as *const *const IAudioNodeEmitter_Vtbl)).SetGain)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DistanceScale(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter", "DistanceScale", 14usize, |this| ((**(this as *const *const IAudioNodeEmitter_Vtbl)).DistanceScale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDistanceScale(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter", "SetDistanceScale", 15usize, |this| ((**(this as *const *const IAudioNodeEmitter_Vtbl)).SetDistanceScale)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DopplerScale(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter", "DopplerScale", 16usize, |this| ((**(this as *const *const IAudioNodeEmitter_Vtbl)).DopplerScale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDopplerScale(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter", "SetDopplerScale", 17usize, |this| ((**(this as *const *const IAudioNodeEmitter_Vtbl)).SetDopplerScale)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn DopplerVelocity(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter", "DopplerVelocity", 18usize, |this| ((**(this as *const *const IAudioNodeEmitter_Vtbl)).DopplerVelocity)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetDopplerVelocity(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter", "SetDopplerVelocity", 19usize, |this| ((**(this as *const *const IAudioNodeEmitter_Vtbl)).SetDopplerVelocity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDopplerDisabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter", "IsDopplerDisabled", 20usize, |this| ((**(this as *const *const IAudioNodeEmitter_Vtbl)).IsDopplerDisabled)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Position,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Position: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetPosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetPosition: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Direction,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Direction: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetDirection,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetDirection: 0,
+            Shape,
+            DecayModel,
+            Gain,
+            SetGain,
+            DistanceScale,
+            SetDistanceScale,
+            DopplerScale,
+            SetDopplerScale,
+            #[cfg(feature = "Foundation_Numerics")]
+            DopplerVelocity,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            DopplerVelocity: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetDopplerVelocity,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetDopplerVelocity: 0,
+            IsDopplerDisabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioNodeEmitter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioNodeEmitter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SpatialAudioModel(this: *mut core::ffi::c_void, result__: *mut SpatialAudioModel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter2", "SpatialAudioModel", 6usize, |this| ((**(this as *const *const IAudioNodeEmitter2_Vtbl)).SpatialAudioModel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpatialAudioModel(this: *mut core::ffi::c_void, value: SpatialAudioModel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitter2", "SetSpatialAudioModel", 7usize, |this| ((**(this as *const *const IAudioNodeEmitter2_Vtbl)).SetSpatialAudioModel)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SpatialAudioModel, SetSpatialAudioModel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioNodeEmitter2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioNodeEmitterConeProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InnerAngle(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterConeProperties", "InnerAngle", 6usize, |this| ((**(this as *const *const IAudioNodeEmitterConeProperties_Vtbl)).InnerAngle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OuterAngle(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterConeProperties", "OuterAngle", 7usize, |this| ((**(this as *const *const IAudioNodeEmitterConeProperties_Vtbl)).OuterAngle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OuterAngleGain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterConeProperties", "OuterAngleGain", 8usize, |this| ((**(this as *const *const IAudioNodeEmitterConeProperties_Vtbl)).OuterAngleGain)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InnerAngle, OuterAngle, OuterAngleGain }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioNodeEmitterConeProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioNodeEmitterDecayModel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut AudioNodeEmitterDecayKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterDecayModel", "Kind", 6usize, |this| ((**(this as *const *const IAudioNodeEmitterDecayModel_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinGain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterDecayModel", "MinGain", 7usize, |this| ((**(this as *const *const IAudioNodeEmitterDecayModel_Vtbl)).MinGain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxGain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterDecayModel", "MaxGain", 8usize, |this| ((**(this as *const *const IAudioNodeEmitterDecayModel_Vtbl)).MaxGain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NaturalProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterDecayModel", "NaturalProperties", 9usize, |this| ((**(this as *const *const IAudioNodeEmitterDecayModel_Vtbl)).NaturalProperties)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind, MinGain, MaxGain, NaturalProperties }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioNodeEmitterDecayModel as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioNodeEmitterDecayModelStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateNatural(this: *mut core::ffi::c_void, mingain: f64, maxgain: f64, unitygaindistance: f64, cutoffdistance: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterDecayModelStatics", "CreateNatural", 6usize, |this| ((**(this as *const *const IAudioNodeEmitterDecayModelStatics_Vtbl)).CreateNatural)(this, mingain, maxgain, unitygaindistance, cutoffdistance, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCustom(this: *mut core::ffi::c_void, mingain: f64, maxgain: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterDecayModelStatics", "CreateCustom", 7usize, |this| ((**(this as *const *const IAudioNodeEmitterDecayModelStatics_Vtbl)).CreateCustom)(this, mingain, maxgain, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateNatural, CreateCustom }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioNodeEmitterDecayModelStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioNodeEmitterFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAudioNodeEmitter(this: *mut core::ffi::c_void, shape: *mut core::ffi::c_void, decaymodel: *mut core::ffi::c_void, settings: AudioNodeEmitterSettings, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterFactory", "CreateAudioNodeEmitter", 6usize, |this| ((**(this as *const *const IAudioNodeEmitterFactory_Vtbl)).CreateAudioNodeEmitter)(this, shape, decaymodel, settings, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateAudioNodeEmitter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioNodeEmitterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioNodeEmitterNaturalDecayModelProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UnityGainDistance(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterNaturalDecayModelProperties", "UnityGainDistance", 6usize, |this| ((**(this as *const *const IAudioNodeEmitterNaturalDecayModelProperties_Vtbl)).UnityGainDistance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CutoffDistance(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterNaturalDecayModelProperties", "CutoffDistance", 7usize, |this| ((**(this as *const *const IAudioNodeEmitterNaturalDecayModelProperties_Vtbl)).CutoffDistance)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UnityGainDistance, CutoffDistance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioNodeEmitterNaturalDecayModelProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioNodeEmitterShape_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut AudioNodeEmitterShapeKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterShape", "Kind", 6usize, |this| ((**(this as *const *const IAudioNodeEmitterShape_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConeProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterShape", "ConeProperties", 7usize, |this| ((**(this as *const *const IAudioNodeEmitterShape_Vtbl)).ConeProperties)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind, ConeProperties }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioNodeEmitterShape as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioNodeEmitterShapeStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateCone(this: *mut core::ffi::c_void, innerangle: f64, outerangle: f64, outeranglegain: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterShapeStatics", "CreateCone", 6usize, |this| ((**(this as *const *const IAudioNodeEmitterShapeStatics_Vtbl)).CreateCone)(this, innerangle, outerangle, outeranglegain, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOmnidirectional(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeEmitterShapeStatics", "CreateOmnidirectional", 7usize, |this| ((**(this as *const *const IAudioNodeEmitterShapeStatics_Vtbl)).CreateOmnidirectional)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateCone, CreateOmnidirectional }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioNodeEmitterShapeStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IAudioNodeListener_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeListener", "Position", 6usize, |this| ((**(this as *const *const IAudioNodeListener_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeListener", "SetPosition", 7usize, |this| ((**(this as *const *const IAudioNodeListener_Vtbl)).SetPosition)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeListener", "Orientation", 8usize, |this| ((**(this as *const *const IAudioNodeListener_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetOrientation(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeListener", "SetOrientation", 9usize, |this| ((**(this as *const *const IAudioNodeListener_Vtbl)).SetOrientation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpeedOfSound(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeListener", "SpeedOfSound", 10usize, |this| ((**(this as *const *const IAudioNodeListener_Vtbl)).SpeedOfSound)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpeedOfSound(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeListener", "SetSpeedOfSound", 11usize, |this| ((**(this as *const *const IAudioNodeListener_Vtbl)).SetSpeedOfSound)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn DopplerVelocity(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeListener", "DopplerVelocity", 12usize, |this| ((**(this as *const *const IAudioNodeListener_Vtbl)).DopplerVelocity)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetDopplerVelocity(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeListener", "SetDopplerVelocity", 13usize, |this| ((**(this as *const *const IAudioNodeListener_Vtbl)).SetDopplerVelocity)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Position,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Position: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetPosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetPosition: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Orientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Orientation: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetOrientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetOrientation: 0,
+            SpeedOfSound,
+            SetSpeedOfSound,
+            #[cfg(feature = "Foundation_Numerics")]
+            DopplerVelocity,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            DopplerVelocity: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetDopplerVelocity,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetDopplerVelocity: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioNodeListener as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IAudioNodeWithListener_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetListener(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeWithListener", "SetListener", 6usize, |this| ((**(this as *const *const IAudioNodeWithListener_Vtbl)).SetListener)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Listener(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioNodeWithListener", "Listener", 7usize, |this| ((**(this as *const *const IAudioNodeWithListener_Vtbl)).Listener)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetListener, Listener }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioNodeWithListener as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioPlaybackConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioPlaybackConnection", "Start", 6usize, |this| ((**(this as *const *const IAudioPlaybackConnection_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioPlaybackConnection", "StartAsync", 7usize, |this| ((**(this as *const *const IAudioPlaybackConnection_Vtbl)).StartAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioPlaybackConnection", "DeviceId", 8usize, |this| ((**(this as *const *const IAudioPlaybackConnection_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut AudioPlaybackConnectionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioPlaybackConnection", "State", 9usize, |this| ((**(this as *const *const IAudioPlaybackConnection_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioPlaybackConnection", "Open", 10usize, |this| ((**(this as *const *const IAudioPlaybackConnection_Vtbl)).Open)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioPlaybackConnection", "OpenAsync", 11usize, |this| ((**(this as *const *const IAudioPlaybackConnection_Vtbl)).OpenAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioPlaybackConnection", "StateChanged", 12usize, |this| ((**(this as *const *const IAudioPlaybackConnection_Vtbl)).StateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioPlaybackConnection", "RemoveStateChanged", 13usize, |this| ((**(this as *const *const IAudioPlaybackConnection_Vtbl)).RemoveStateChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Start,
+            StartAsync,
+            DeviceId,
+            State,
+            Open,
+            OpenAsync,
+            StateChanged,
+            RemoveStateChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioPlaybackConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioPlaybackConnectionOpenResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut AudioPlaybackConnectionOpenResultStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioPlaybackConnectionOpenResult", "Status", 6usize, |this| ((**(this as *const *const IAudioPlaybackConnectionOpenResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioPlaybackConnectionOpenResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IAudioPlaybackConnectionOpenResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioPlaybackConnectionOpenResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioPlaybackConnectionStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioPlaybackConnectionStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IAudioPlaybackConnectionStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryCreateFromId(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioPlaybackConnectionStatics", "TryCreateFromId", 7usize, |this| ((**(this as *const *const IAudioPlaybackConnectionStatics_Vtbl)).TryCreateFromId)(this, id, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, TryCreateFromId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioPlaybackConnectionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioStateMonitor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SoundLevelChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStateMonitor", "SoundLevelChanged", 6usize, |this| ((**(this as *const *const IAudioStateMonitor_Vtbl)).SoundLevelChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSoundLevelChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStateMonitor", "RemoveSoundLevelChanged", 7usize, |this| ((**(this as *const *const IAudioStateMonitor_Vtbl)).RemoveSoundLevelChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SoundLevel(this: *mut core::ffi::c_void, result__: *mut super::SoundLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStateMonitor", "SoundLevel", 8usize, |this| ((**(this as *const *const IAudioStateMonitor_Vtbl)).SoundLevel)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SoundLevelChanged, RemoveSoundLevelChanged, SoundLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioStateMonitor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Capture", feature = "Media_Devices", feature = "Media_Render"))]
+impl windows_core::imp::ProxyVtable for IAudioStateMonitorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateForRenderMonitoring(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStateMonitorStatics", "CreateForRenderMonitoring", 6usize, |this| ((**(this as *const *const IAudioStateMonitorStatics_Vtbl)).CreateForRenderMonitoring)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Render")]
+        unsafe extern "system" fn CreateForRenderMonitoringWithCategory(this: *mut core::ffi::c_void, category: super::Render::AudioRenderCategory, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStateMonitorStatics", "CreateForRenderMonitoringWithCategory", 7usize, |this| ((**(this as *const *const IAudioStateMonitorStatics_Vtbl)).CreateForRenderMonitoringWithCategory)(this, category, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_Devices", feature = "Media_Render"))]
+        unsafe extern "system" fn CreateForRenderMonitoringWithCategoryAndDeviceRole(this: *mut core::ffi::c_void, category: super::Render::AudioRenderCategory, role: super::Devices::AudioDeviceRole, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStateMonitorStatics", "CreateForRenderMonitoringWithCategoryAndDeviceRole", 8usize, |this| ((**(this as *const *const IAudioStateMonitorStatics_Vtbl)).CreateForRenderMonitoringWithCategoryAndDeviceRole)(this, category, role, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Render")]
+        unsafe extern "system" fn CreateForRenderMonitoringWithCategoryAndDeviceId(this: *mut core::ffi::c_void, category: super::Render::AudioRenderCategory, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStateMonitorStatics", "CreateForRenderMonitoringWithCategoryAndDeviceId", 9usize, |this| ((**(this as *const *const IAudioStateMonitorStatics_Vtbl)).CreateForRenderMonitoringWithCategoryAndDeviceId)(this, category, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateForCaptureMonitoring(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStateMonitorStatics", "CreateForCaptureMonitoring", 10usize, |this| ((**(this as *const *const IAudioStateMonitorStatics_Vtbl)).CreateForCaptureMonitoring)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Capture")]
+        unsafe extern "system" fn CreateForCaptureMonitoringWithCategory(this: *mut core::ffi::c_void, category: super::Capture::MediaCategory, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStateMonitorStatics", "CreateForCaptureMonitoringWithCategory", 11usize, |this| ((**(this as *const *const IAudioStateMonitorStatics_Vtbl)).CreateForCaptureMonitoringWithCategory)(this, category, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_Capture", feature = "Media_Devices"))]
+        unsafe extern "system" fn CreateForCaptureMonitoringWithCategoryAndDeviceRole(this: *mut core::ffi::c_void, category: super::Capture::MediaCategory, role: super::Devices::AudioDeviceRole, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStateMonitorStatics", "CreateForCaptureMonitoringWithCategoryAndDeviceRole", 12usize, |this| ((**(this as *const *const IAudioStateMonitorStatics_Vtbl)).CreateForCaptureMonitoringWithCategoryAndDeviceRole)(this, category, role, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Capture")]
+        unsafe extern "system" fn CreateForCaptureMonitoringWithCategoryAndDeviceId(this: *mut core::ffi::c_void, category: super::Capture::MediaCategory, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStateMonitorStatics", "CreateForCaptureMonitoringWithCategoryAndDeviceId", 13usize, |this| ((**(this as *const *const IAudioStateMonitorStatics_Vtbl)).CreateForCaptureMonitoringWithCategoryAndDeviceId)(this, category, deviceid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateForRenderMonitoring,
+            #[cfg(feature = "Media_Render")]
+            CreateForRenderMonitoringWithCategory,
+            #[cfg(not(feature = "Media_Render"))]
+            CreateForRenderMonitoringWithCategory: 0,
+            #[cfg(all(feature = "Media_Devices", feature = "Media_Render"))]
+            CreateForRenderMonitoringWithCategoryAndDeviceRole,
+            #[cfg(not(all(feature = "Media_Devices", feature = "Media_Render")))]
+            CreateForRenderMonitoringWithCategoryAndDeviceRole: 0,
+            #[cfg(feature = "Media_Render")]
+            CreateForRenderMonitoringWithCategoryAndDeviceId,
+            #[cfg(not(feature = "Media_Render"))]
+            CreateForRenderMonitoringWithCategoryAndDeviceId: 0,
+            CreateForCaptureMonitoring,
+            #[cfg(feature = "Media_Capture")]
+            CreateForCaptureMonitoringWithCategory,
+            #[cfg(not(feature = "Media_Capture"))]
+            CreateForCaptureMonitoringWithCategory: 0,
+            #[cfg(all(feature = "Media_Capture", feature = "Media_Devices"))]
+            CreateForCaptureMonitoringWithCategoryAndDeviceRole,
+            #[cfg(not(all(feature = "Media_Capture", feature = "Media_Devices")))]
+            CreateForCaptureMonitoringWithCategoryAndDeviceRole: 0,
+            #[cfg(feature = "Media_Capture")]
+            CreateForCaptureMonitoringWithCategoryAndDeviceId,
+            #[cfg(not(feature = "Media_Capture"))]
+            CreateForCaptureMonitoringWithCategoryAndDeviceId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioStateMonitorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateAudioDeviceInputNodeResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut AudioDeviceNodeCreationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateAudioDeviceInputNodeResult", "Status", 6usize, |this| ((**(this as *const *const ICreateAudioDeviceInputNodeResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceInputNode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateAudioDeviceInputNodeResult", "DeviceInputNode", 7usize, |this| ((**(this as *const *const ICreateAudioDeviceInputNodeResult_Vtbl)).DeviceInputNode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, DeviceInputNode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateAudioDeviceInputNodeResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateAudioDeviceInputNodeResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateAudioDeviceInputNodeResult2", "ExtendedError", 6usize, |this| ((**(this as *const *const ICreateAudioDeviceInputNodeResult2_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateAudioDeviceInputNodeResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateAudioDeviceOutputNodeResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut AudioDeviceNodeCreationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateAudioDeviceOutputNodeResult", "Status", 6usize, |this| ((**(this as *const *const ICreateAudioDeviceOutputNodeResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceOutputNode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateAudioDeviceOutputNodeResult", "DeviceOutputNode", 7usize, |this| ((**(this as *const *const ICreateAudioDeviceOutputNodeResult_Vtbl)).DeviceOutputNode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, DeviceOutputNode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateAudioDeviceOutputNodeResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateAudioDeviceOutputNodeResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateAudioDeviceOutputNodeResult2", "ExtendedError", 6usize, |this| ((**(this as *const *const ICreateAudioDeviceOutputNodeResult2_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateAudioDeviceOutputNodeResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateAudioFileInputNodeResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut AudioFileNodeCreationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateAudioFileInputNodeResult", "Status", 6usize, |this| ((**(this as *const *const ICreateAudioFileInputNodeResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileInputNode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateAudioFileInputNodeResult", "FileInputNode", 7usize, |this| ((**(this as *const *const ICreateAudioFileInputNodeResult_Vtbl)).FileInputNode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, FileInputNode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateAudioFileInputNodeResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateAudioFileInputNodeResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateAudioFileInputNodeResult2", "ExtendedError", 6usize, |this| ((**(this as *const *const ICreateAudioFileInputNodeResult2_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateAudioFileInputNodeResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateAudioFileOutputNodeResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut AudioFileNodeCreationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateAudioFileOutputNodeResult", "Status", 6usize, |this| ((**(this as *const *const ICreateAudioFileOutputNodeResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileOutputNode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateAudioFileOutputNodeResult", "FileOutputNode", 7usize, |this| ((**(this as *const *const ICreateAudioFileOutputNodeResult_Vtbl)).FileOutputNode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, FileOutputNode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateAudioFileOutputNodeResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateAudioFileOutputNodeResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateAudioFileOutputNodeResult2", "ExtendedError", 6usize, |this| ((**(this as *const *const ICreateAudioFileOutputNodeResult2_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateAudioFileOutputNodeResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateAudioGraphResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut AudioGraphCreationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateAudioGraphResult", "Status", 6usize, |this| ((**(this as *const *const ICreateAudioGraphResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Graph(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateAudioGraphResult", "Graph", 7usize, |this| ((**(this as *const *const ICreateAudioGraphResult_Vtbl)).Graph)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Graph }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateAudioGraphResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateAudioGraphResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateAudioGraphResult2", "ExtendedError", 6usize, |this| ((**(this as *const *const ICreateAudioGraphResult2_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateAudioGraphResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateMediaSourceAudioInputNodeResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut MediaSourceAudioInputNodeCreationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateMediaSourceAudioInputNodeResult", "Status", 6usize, |this| ((**(this as *const *const ICreateMediaSourceAudioInputNodeResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Node(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateMediaSourceAudioInputNodeResult", "Node", 7usize, |this| ((**(this as *const *const ICreateMediaSourceAudioInputNodeResult_Vtbl)).Node)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Node }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateMediaSourceAudioInputNodeResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateMediaSourceAudioInputNodeResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateMediaSourceAudioInputNodeResult2", "ExtendedError", 6usize, |this| ((**(this as *const *const ICreateMediaSourceAudioInputNodeResult2_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateMediaSourceAudioInputNodeResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects"))]
+impl windows_core::imp::ProxyVtable for IEchoEffectDefinition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWetDryMix(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEchoEffectDefinition", "SetWetDryMix", 6usize, |this| ((**(this as *const *const IEchoEffectDefinition_Vtbl)).SetWetDryMix)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WetDryMix(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEchoEffectDefinition", "WetDryMix", 7usize, |this| ((**(this as *const *const IEchoEffectDefinition_Vtbl)).WetDryMix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFeedback(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEchoEffectDefinition", "SetFeedback", 8usize, |this| ((**(this as *const *const IEchoEffectDefinition_Vtbl)).SetFeedback)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Feedback(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEchoEffectDefinition", "Feedback", 9usize, |this| ((**(this as *const *const IEchoEffectDefinition_Vtbl)).Feedback)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelay(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEchoEffectDefinition", "SetDelay", 10usize, |this| ((**(this as *const *const IEchoEffectDefinition_Vtbl)).SetDelay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delay(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEchoEffectDefinition", "Delay", 11usize, |this| ((**(this as *const *const IEchoEffectDefinition_Vtbl)).Delay)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetWetDryMix,
+            WetDryMix,
+            SetFeedback,
+            Feedback,
+            SetDelay,
+            Delay,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEchoEffectDefinition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEchoEffectDefinitionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, audiograph: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEchoEffectDefinitionFactory", "Create", 6usize, |this| ((**(this as *const *const IEchoEffectDefinitionFactory_Vtbl)).Create)(this, audiograph, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEchoEffectDefinitionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEqualizerBand_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Bandwidth(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEqualizerBand", "Bandwidth", 6usize, |this| ((**(this as *const *const IEqualizerBand_Vtbl)).Bandwidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBandwidth(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEqualizerBand", "SetBandwidth", 7usize, |this| ((**(this as *const *const IEqualizerBand_Vtbl)).SetBandwidth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrequencyCenter(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEqualizerBand", "FrequencyCenter", 8usize, |this| ((**(this as *const *const IEqualizerBand_Vtbl)).FrequencyCenter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrequencyCenter(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEqualizerBand", "SetFrequencyCenter", 9usize, |this| ((**(this as *const *const IEqualizerBand_Vtbl)).SetFrequencyCenter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEqualizerBand", "Gain", 10usize, |this| ((**(this as *const *const IEqualizerBand_Vtbl)).Gain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGain(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEqualizerBand", "SetGain", 11usize, |this| ((**(this as *const *const IEqualizerBand_Vtbl)).SetGain)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Bandwidth,
+            SetBandwidth,
+            FrequencyCenter,
+            SetFrequencyCenter,
+            Gain,
+            SetGain,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEqualizerBand as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects"))]
+impl windows_core::imp::ProxyVtable for IEqualizerEffectDefinition_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Bands(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEqualizerEffectDefinition", "Bands", 6usize, |this| ((**(this as *const *const IEqualizerEffectDefinition_Vtbl)).Bands)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Bands,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Bands: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEqualizerEffectDefinition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEqualizerEffectDefinitionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, audiograph: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEqualizerEffectDefinitionFactory", "Create", 6usize, |this| ((**(this as *const *const IEqualizerEffectDefinitionFactory_Vtbl)).Create)(this, audiograph, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEqualizerEffectDefinitionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameInputNodeQuantumStartedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequiredSamples(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameInputNodeQuantumStartedEventArgs", "RequiredSamples", 6usize, |this| ((**(this as *const *const IFrameInputNodeQuantumStartedEventArgs_Vtbl)).RequiredSamples)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequiredSamples }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameInputNodeQuantumStartedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects"))]
+impl windows_core::imp::ProxyVtable for ILimiterEffectDefinition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRelease(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILimiterEffectDefinition", "SetRelease", 6usize, |this| ((**(this as *const *const ILimiterEffectDefinition_Vtbl)).SetRelease)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Release(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILimiterEffectDefinition", "Release", 7usize, |this| ((**(this as *const *const ILimiterEffectDefinition_Vtbl)).Release)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLoudness(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILimiterEffectDefinition", "SetLoudness", 8usize, |this| ((**(this as *const *const ILimiterEffectDefinition_Vtbl)).SetLoudness)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Loudness(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILimiterEffectDefinition", "Loudness", 9usize, |this| ((**(this as *const *const ILimiterEffectDefinition_Vtbl)).Loudness)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetRelease, Release, SetLoudness, Loudness }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILimiterEffectDefinition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILimiterEffectDefinitionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, audiograph: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILimiterEffectDefinitionFactory", "Create", 6usize, |this| ((**(this as *const *const ILimiterEffectDefinitionFactory_Vtbl)).Create)(this, audiograph, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILimiterEffectDefinitionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Core", feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IMediaSourceAudioInputNode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPlaybackSpeedFactor(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAudioInputNode", "SetPlaybackSpeedFactor", 6usize, |this| ((**(this as *const *const IMediaSourceAudioInputNode_Vtbl)).SetPlaybackSpeedFactor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackSpeedFactor(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAudioInputNode", "PlaybackSpeedFactor", 7usize, |this| ((**(this as *const *const IMediaSourceAudioInputNode_Vtbl)).PlaybackSpeedFactor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAudioInputNode", "Position", 8usize, |this| ((**(this as *const *const IMediaSourceAudioInputNode_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, position: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAudioInputNode", "Seek", 9usize, |this| ((**(this as *const *const IMediaSourceAudioInputNode_Vtbl)).Seek)(this, position), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAudioInputNode", "StartTime", 10usize, |this| ((**(this as *const *const IMediaSourceAudioInputNode_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAudioInputNode", "SetStartTime", 11usize, |this| ((**(this as *const *const IMediaSourceAudioInputNode_Vtbl)).SetStartTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAudioInputNode", "EndTime", 12usize, |this| ((**(this as *const *const IMediaSourceAudioInputNode_Vtbl)).EndTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEndTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAudioInputNode", "SetEndTime", 13usize, |this| ((**(this as *const *const IMediaSourceAudioInputNode_Vtbl)).SetEndTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoopCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAudioInputNode", "LoopCount", 14usize, |this| ((**(this as *const *const IMediaSourceAudioInputNode_Vtbl)).LoopCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLoopCount(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAudioInputNode", "SetLoopCount", 15usize, |this| ((**(this as *const *const IMediaSourceAudioInputNode_Vtbl)).SetLoopCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAudioInputNode", "Duration", 16usize, |this| ((**(this as *const *const IMediaSourceAudioInputNode_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn MediaSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAudioInputNode", "MediaSource", 17usize, |this| ((**(this as *const *const IMediaSourceAudioInputNode_Vtbl)).MediaSource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaSourceCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAudioInputNode", "MediaSourceCompleted", 18usize, |this| ((**(this as *const *const IMediaSourceAudioInputNode_Vtbl)).MediaSourceCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMediaSourceCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAudioInputNode", "RemoveMediaSourceCompleted", 19usize, |this| ((**(this as *const *const IMediaSourceAudioInputNode_Vtbl)).RemoveMediaSourceCompleted)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPlaybackSpeedFactor,
+            PlaybackSpeedFactor,
+            Position,
+            Seek,
+            StartTime,
+            SetStartTime,
+            EndTime,
+            SetEndTime,
+            LoopCount,
+            SetLoopCount,
+            Duration,
+            #[cfg(feature = "Media_Core")]
+            MediaSource,
+            #[cfg(not(feature = "Media_Core"))]
+            MediaSource: 0,
+            MediaSourceCompleted,
+            RemoveMediaSourceCompleted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSourceAudioInputNode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects"))]
+impl windows_core::imp::ProxyVtable for IReverbEffectDefinition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWetDryMix(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetWetDryMix", 6usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetWetDryMix)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WetDryMix(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "WetDryMix", 7usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).WetDryMix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReflectionsDelay(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetReflectionsDelay", 8usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetReflectionsDelay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReflectionsDelay(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "ReflectionsDelay", 9usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).ReflectionsDelay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReverbDelay(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetReverbDelay", 10usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetReverbDelay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReverbDelay(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "ReverbDelay", 11usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).ReverbDelay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRearDelay(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetRearDelay", 12usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetRearDelay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RearDelay(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "RearDelay", 13usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).RearDelay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPositionLeft(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetPositionLeft", 14usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetPositionLeft)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionLeft(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "PositionLeft", 15usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).PositionLeft)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPositionRight(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetPositionRight", 16usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetPositionRight)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionRight(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "PositionRight", 17usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).PositionRight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPositionMatrixLeft(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetPositionMatrixLeft", 18usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetPositionMatrixLeft)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionMatrixLeft(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "PositionMatrixLeft", 19usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).PositionMatrixLeft)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPositionMatrixRight(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetPositionMatrixRight", 20usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetPositionMatrixRight)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionMatrixRight(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "PositionMatrixRight", 21usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).PositionMatrixRight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEarlyDiffusion(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetEarlyDiffusion", 22usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetEarlyDiffusion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EarlyDiffusion(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "EarlyDiffusion", 23usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).EarlyDiffusion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLateDiffusion(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetLateDiffusion", 24usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetLateDiffusion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LateDiffusion(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "LateDiffusion", 25usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).LateDiffusion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLowEQGain(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetLowEQGain", 26usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetLowEQGain)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LowEQGain(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "LowEQGain", 27usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).LowEQGain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLowEQCutoff(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetLowEQCutoff", 28usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetLowEQCutoff)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LowEQCutoff(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "LowEQCutoff", 29usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).LowEQCutoff)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHighEQGain(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetHighEQGain", 30usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetHighEQGain)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HighEQGain(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "HighEQGain", 31usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).HighEQGain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHighEQCutoff(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetHighEQCutoff", 32usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetHighEQCutoff)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HighEQCutoff(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "HighEQCutoff", 33usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).HighEQCutoff)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoomFilterFreq(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetRoomFilterFreq", 34usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetRoomFilterFreq)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoomFilterFreq(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "RoomFilterFreq", 35usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).RoomFilterFreq)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoomFilterMain(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetRoomFilterMain", 36usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetRoomFilterMain)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoomFilterMain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "RoomFilterMain", 37usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).RoomFilterMain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoomFilterHF(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetRoomFilterHF", 38usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetRoomFilterHF)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoomFilterHF(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "RoomFilterHF", 39usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).RoomFilterHF)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReflectionsGain(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetReflectionsGain", 40usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetReflectionsGain)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReflectionsGain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "ReflectionsGain", 41usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).ReflectionsGain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReverbGain(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetReverbGain", 42usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetReverbGain)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReverbGain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "ReverbGain", 43usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).ReverbGain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDecayTime(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetDecayTime", 44usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetDecayTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecayTime(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "DecayTime", 45usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).DecayTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDensity(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetDensity", 46usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetDensity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Density(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "Density", 47usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).Density)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoomSize(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetRoomSize", 48usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetRoomSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoomSize(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "RoomSize", 49usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).RoomSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisableLateField(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "SetDisableLateField", 50usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).SetDisableLateField)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableLateField(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinition", "DisableLateField", 51usize, |this| ((**(this as *const *const IReverbEffectDefinition_Vtbl)).DisableLateField)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetWetDryMix,
+            WetDryMix,
+            SetReflectionsDelay,
+            ReflectionsDelay,
+            SetReverbDelay,
+            ReverbDelay,
+            SetRearDelay,
+            RearDelay,
+            SetPositionLeft,
+            PositionLeft,
+            SetPositionRight,
+            PositionRight,
+            SetPositionMatrixLeft,
+            PositionMatrixLeft,
+            SetPositionMatrixRight,
+            PositionMatrixRight,
+            SetEarlyDiffusion,
+            EarlyDiffusion,
+            SetLateDiffusion,
+            LateDiffusion,
+            SetLowEQGain,
+            LowEQGain,
+            SetLowEQCutoff,
+            LowEQCutoff,
+            SetHighEQGain,
+            HighEQGain,
+            SetHighEQCutoff,
+            HighEQCutoff,
+            SetRoomFilterFreq,
+            RoomFilterFreq,
+            SetRoomFilterMain,
+            RoomFilterMain,
+            SetRoomFilterHF,
+            RoomFilterHF,
+            SetReflectionsGain,
+            ReflectionsGain,
+            SetReverbGain,
+            ReverbGain,
+            SetDecayTime,
+            DecayTime,
+            SetDensity,
+            Density,
+            SetRoomSize,
+            RoomSize,
+            SetDisableLateField,
+            DisableLateField,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReverbEffectDefinition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IReverbEffectDefinitionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, audiograph: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReverbEffectDefinitionFactory", "Create", 6usize, |this| ((**(this as *const *const IReverbEffectDefinitionFactory_Vtbl)).Create)(this, audiograph, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReverbEffectDefinitionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISetDefaultSpatialAudioFormatResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut SetDefaultSpatialAudioFormatStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISetDefaultSpatialAudioFormatResult", "Status", 6usize, |this| ((**(this as *const *const ISetDefaultSpatialAudioFormatResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISetDefaultSpatialAudioFormatResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioDeviceConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioDeviceConfiguration", "DeviceId", 6usize, |this| ((**(this as *const *const ISpatialAudioDeviceConfiguration_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSpatialAudioSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioDeviceConfiguration", "IsSpatialAudioSupported", 7usize, |this| ((**(this as *const *const ISpatialAudioDeviceConfiguration_Vtbl)).IsSpatialAudioSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSpatialAudioFormatSupported(this: *mut core::ffi::c_void, subtype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioDeviceConfiguration", "IsSpatialAudioFormatSupported", 8usize, |this| ((**(this as *const *const ISpatialAudioDeviceConfiguration_Vtbl)).IsSpatialAudioFormatSupported)(this, subtype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActiveSpatialAudioFormat(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioDeviceConfiguration", "ActiveSpatialAudioFormat", 9usize, |this| ((**(this as *const *const ISpatialAudioDeviceConfiguration_Vtbl)).ActiveSpatialAudioFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultSpatialAudioFormat(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioDeviceConfiguration", "DefaultSpatialAudioFormat", 10usize, |this| ((**(this as *const *const ISpatialAudioDeviceConfiguration_Vtbl)).DefaultSpatialAudioFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultSpatialAudioFormatAsync(this: *mut core::ffi::c_void, subtype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioDeviceConfiguration", "SetDefaultSpatialAudioFormatAsync", 11usize, |this| ((**(this as *const *const ISpatialAudioDeviceConfiguration_Vtbl)).SetDefaultSpatialAudioFormatAsync)(this, subtype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConfigurationChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioDeviceConfiguration", "ConfigurationChanged", 12usize, |this| ((**(this as *const *const ISpatialAudioDeviceConfiguration_Vtbl)).ConfigurationChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConfigurationChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioDeviceConfiguration", "RemoveConfigurationChanged", 13usize, |this| ((**(this as *const *const ISpatialAudioDeviceConfiguration_Vtbl)).RemoveConfigurationChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            IsSpatialAudioSupported,
+            IsSpatialAudioFormatSupported,
+            ActiveSpatialAudioFormat,
+            DefaultSpatialAudioFormat,
+            SetDefaultSpatialAudioFormatAsync,
+            ConfigurationChanged,
+            RemoveConfigurationChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioDeviceConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioDeviceConfigurationStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForDeviceId(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioDeviceConfigurationStatics", "GetForDeviceId", 6usize, |this| ((**(this as *const *const ISpatialAudioDeviceConfigurationStatics_Vtbl)).GetForDeviceId)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForDeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioDeviceConfigurationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioFormatConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportLicenseChangedAsync(this: *mut core::ffi::c_void, subtype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioFormatConfiguration", "ReportLicenseChangedAsync", 6usize, |this| ((**(this as *const *const ISpatialAudioFormatConfiguration_Vtbl)).ReportLicenseChangedAsync)(this, subtype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportConfigurationChangedAsync(this: *mut core::ffi::c_void, subtype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioFormatConfiguration", "ReportConfigurationChangedAsync", 7usize, |this| ((**(this as *const *const ISpatialAudioFormatConfiguration_Vtbl)).ReportConfigurationChangedAsync)(this, subtype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MixedRealityExclusiveModePolicy(this: *mut core::ffi::c_void, result__: *mut MixedRealitySpatialAudioFormatPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioFormatConfiguration", "MixedRealityExclusiveModePolicy", 8usize, |this| ((**(this as *const *const ISpatialAudioFormatConfiguration_Vtbl)).MixedRealityExclusiveModePolicy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMixedRealityExclusiveModePolicy(this: *mut core::ffi::c_void, value: MixedRealitySpatialAudioFormatPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioFormatConfiguration", "SetMixedRealityExclusiveModePolicy", 9usize, |this| ((**(this as *const *const ISpatialAudioFormatConfiguration_Vtbl)).SetMixedRealityExclusiveModePolicy)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReportLicenseChangedAsync,
+            ReportConfigurationChangedAsync,
+            MixedRealityExclusiveModePolicy,
+            SetMixedRealityExclusiveModePolicy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioFormatConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioFormatConfigurationStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioFormatConfigurationStatics", "GetDefault", 6usize, |this| ((**(this as *const *const ISpatialAudioFormatConfigurationStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioFormatConfigurationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioFormatSubtypeStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WindowsSonic(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioFormatSubtypeStatics", "WindowsSonic", 6usize, |this| ((**(this as *const *const ISpatialAudioFormatSubtypeStatics_Vtbl)).WindowsSonic)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DolbyAtmosForHeadphones(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioFormatSubtypeStatics", "DolbyAtmosForHeadphones", 7usize, |this| ((**(this as *const *const ISpatialAudioFormatSubtypeStatics_Vtbl)).DolbyAtmosForHeadphones)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DolbyAtmosForHomeTheater(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioFormatSubtypeStatics", "DolbyAtmosForHomeTheater", 8usize, |this| ((**(this as *const *const ISpatialAudioFormatSubtypeStatics_Vtbl)).DolbyAtmosForHomeTheater)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DolbyAtmosForSpeakers(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioFormatSubtypeStatics", "DolbyAtmosForSpeakers", 9usize, |this| ((**(this as *const *const ISpatialAudioFormatSubtypeStatics_Vtbl)).DolbyAtmosForSpeakers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DTSHeadphoneX(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioFormatSubtypeStatics", "DTSHeadphoneX", 10usize, |this| ((**(this as *const *const ISpatialAudioFormatSubtypeStatics_Vtbl)).DTSHeadphoneX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DTSXUltra(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioFormatSubtypeStatics", "DTSXUltra", 11usize, |this| ((**(this as *const *const ISpatialAudioFormatSubtypeStatics_Vtbl)).DTSXUltra)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            WindowsSonic,
+            DolbyAtmosForHeadphones,
+            DolbyAtmosForHomeTheater,
+            DolbyAtmosForSpeakers,
+            DTSHeadphoneX,
+            DTSXUltra,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioFormatSubtypeStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioFormatSubtypeStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DTSXForHomeTheater(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioFormatSubtypeStatics2", "DTSXForHomeTheater", 6usize, |this| ((**(this as *const *const ISpatialAudioFormatSubtypeStatics2_Vtbl)).DTSXForHomeTheater)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DTSXForHomeTheater }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioFormatSubtypeStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -152,3 +152,5 @@ unsafe impl windows_core::Interface for VariablePhotoSequenceCapture {
 impl windows_core::RuntimeName for VariablePhotoSequenceCapture {
     const NAME: &'static str = "Windows.Media.Capture.Core.VariablePhotoSequenceCapture";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
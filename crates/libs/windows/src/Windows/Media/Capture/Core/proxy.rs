@@ -0,0 +1,78 @@
+impl windows_core::imp::ProxyVtable for IVariablePhotoCapturedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Frame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoCapturedEventArgs", "Frame", 6usize, |this| ((**(this as *const *const IVariablePhotoCapturedEventArgs_Vtbl)).Frame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CaptureTimeOffset(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoCapturedEventArgs", "CaptureTimeOffset", 7usize, |this| ((**(this as *const *const IVariablePhotoCapturedEventArgs_Vtbl)).CaptureTimeOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsedFrameControllerIndex(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoCapturedEventArgs", "UsedFrameControllerIndex", 8usize, |this| ((**(this as *const *const IVariablePhotoCapturedEventArgs_Vtbl)).UsedFrameControllerIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CapturedFrameControlValues(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoCapturedEventArgs", "CapturedFrameControlValues", 9usize, |this| ((**(this as *const *const IVariablePhotoCapturedEventArgs_Vtbl)).CapturedFrameControlValues)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Frame,
+            CaptureTimeOffset,
+            UsedFrameControllerIndex,
+            CapturedFrameControlValues,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVariablePhotoCapturedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVariablePhotoSequenceCapture_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceCapture", "StartAsync", 6usize, |this| ((**(this as *const *const IVariablePhotoSequenceCapture_Vtbl)).StartAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceCapture", "StopAsync", 7usize, |this| ((**(this as *const *const IVariablePhotoSequenceCapture_Vtbl)).StopAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinishAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceCapture", "FinishAsync", 8usize, |this| ((**(this as *const *const IVariablePhotoSequenceCapture_Vtbl)).FinishAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotoCaptured(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceCapture", "PhotoCaptured", 9usize, |this| ((**(this as *const *const IVariablePhotoSequenceCapture_Vtbl)).PhotoCaptured)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePhotoCaptured(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceCapture", "RemovePhotoCaptured", 10usize, |this| ((**(this as *const *const IVariablePhotoSequenceCapture_Vtbl)).RemovePhotoCaptured)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stopped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceCapture", "Stopped", 11usize, |this| ((**(this as *const *const IVariablePhotoSequenceCapture_Vtbl)).Stopped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStopped(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceCapture", "RemoveStopped", 12usize, |this| ((**(this as *const *const IVariablePhotoSequenceCapture_Vtbl)).RemoveStopped)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartAsync,
+            StopAsync,
+            FinishAsync,
+            PhotoCaptured,
+            RemovePhotoCaptured,
+            Stopped,
+            RemoveStopped,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVariablePhotoSequenceCapture as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVariablePhotoSequenceCapture2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UpdateSettingsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceCapture2", "UpdateSettingsAsync", 6usize, |this| ((**(this as *const *const IVariablePhotoSequenceCapture2_Vtbl)).UpdateSettingsAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UpdateSettingsAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVariablePhotoSequenceCapture2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
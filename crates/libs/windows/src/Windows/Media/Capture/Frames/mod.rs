@@ -1439,6 +1439,7 @@ impl MediaFrameReaderAcquisitionMode {
 impl windows_core::TypeKind for MediaFrameReaderAcquisitionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaFrameReaderAcquisitionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaFrameReaderAcquisitionMode").field(&self.0).finish()
@@ -1460,6 +1461,7 @@ impl MediaFrameReaderStartStatus {
 impl windows_core::TypeKind for MediaFrameReaderStartStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaFrameReaderStartStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaFrameReaderStartStatus").field(&self.0).finish()
@@ -1482,6 +1484,7 @@ impl MediaFrameSourceGetPropertyStatus {
 impl windows_core::TypeKind for MediaFrameSourceGetPropertyStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaFrameSourceGetPropertyStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaFrameSourceGetPropertyStatus").field(&self.0).finish()
@@ -1505,6 +1508,7 @@ impl MediaFrameSourceKind {
 impl windows_core::TypeKind for MediaFrameSourceKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaFrameSourceKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaFrameSourceKind").field(&self.0).finish()
@@ -1527,6 +1531,7 @@ impl MediaFrameSourceSetPropertyStatus {
 impl windows_core::TypeKind for MediaFrameSourceSetPropertyStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaFrameSourceSetPropertyStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaFrameSourceSetPropertyStatus").field(&self.0).finish()
@@ -1548,6 +1553,7 @@ impl MultiSourceMediaFrameReaderStartStatus {
 impl windows_core::TypeKind for MultiSourceMediaFrameReaderStartStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MultiSourceMediaFrameReaderStartStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MultiSourceMediaFrameReaderStartStatus").field(&self.0).finish()
@@ -1556,3 +1562,5 @@ impl core::fmt::Debug for MultiSourceMediaFrameReaderStartStatus {
 impl windows_core::RuntimeType for MultiSourceMediaFrameReaderStartStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.Capture.Frames.MultiSourceMediaFrameReaderStartStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
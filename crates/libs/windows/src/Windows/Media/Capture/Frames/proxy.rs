@@ -0,0 +1,745 @@
+#[cfg(feature = "Media_MediaProperties")]
+impl windows_core::imp::ProxyVtable for IAudioMediaFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FrameReference(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioMediaFrame", "FrameReference", 6usize, |this| ((**(this as *const *const IAudioMediaFrame_Vtbl)).FrameReference)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn AudioEncodingProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioMediaFrame", "AudioEncodingProperties", 7usize, |this| ((**(this as *const *const IAudioMediaFrame_Vtbl)).AudioEncodingProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioMediaFrame", "GetAudioFrame", 8usize, |this| ((**(this as *const *const IAudioMediaFrame_Vtbl)).GetAudioFrame)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FrameReference,
+            #[cfg(feature = "Media_MediaProperties")]
+            AudioEncodingProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            AudioEncodingProperties: 0,
+            GetAudioFrame,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioMediaFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IBufferMediaFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FrameReference(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBufferMediaFrame", "FrameReference", 6usize, |this| ((**(this as *const *const IBufferMediaFrame_Vtbl)).FrameReference)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Buffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBufferMediaFrame", "Buffer", 7usize, |this| ((**(this as *const *const IBufferMediaFrame_Vtbl)).Buffer)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FrameReference,
+            #[cfg(feature = "Storage_Streams")]
+            Buffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Buffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBufferMediaFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Devices_Core", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for IDepthMediaFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FrameReference(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDepthMediaFrame", "FrameReference", 6usize, |this| ((**(this as *const *const IDepthMediaFrame_Vtbl)).FrameReference)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoMediaFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDepthMediaFrame", "VideoMediaFrame", 7usize, |this| ((**(this as *const *const IDepthMediaFrame_Vtbl)).VideoMediaFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DepthFormat(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDepthMediaFrame", "DepthFormat", 8usize, |this| ((**(this as *const *const IDepthMediaFrame_Vtbl)).DepthFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_Devices_Core", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn TryCreateCoordinateMapper(this: *mut core::ffi::c_void, cameraintrinsics: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDepthMediaFrame", "TryCreateCoordinateMapper", 9usize, |this| ((**(this as *const *const IDepthMediaFrame_Vtbl)).TryCreateCoordinateMapper)(this, cameraintrinsics, coordinatesystem, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FrameReference,
+            VideoMediaFrame,
+            DepthFormat,
+            #[cfg(all(feature = "Media_Devices_Core", feature = "Perception_Spatial"))]
+            TryCreateCoordinateMapper,
+            #[cfg(not(all(feature = "Media_Devices_Core", feature = "Perception_Spatial")))]
+            TryCreateCoordinateMapper: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDepthMediaFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDepthMediaFrame2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxReliableDepth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDepthMediaFrame2", "MaxReliableDepth", 6usize, |this| ((**(this as *const *const IDepthMediaFrame2_Vtbl)).MaxReliableDepth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinReliableDepth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDepthMediaFrame2", "MinReliableDepth", 7usize, |this| ((**(this as *const *const IDepthMediaFrame2_Vtbl)).MinReliableDepth)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MaxReliableDepth, MinReliableDepth }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDepthMediaFrame2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDepthMediaFrameFormat_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VideoFormat(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDepthMediaFrameFormat", "VideoFormat", 6usize, |this| ((**(this as *const *const IDepthMediaFrameFormat_Vtbl)).VideoFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DepthScaleInMeters(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDepthMediaFrameFormat", "DepthScaleInMeters", 7usize, |this| ((**(this as *const *const IDepthMediaFrameFormat_Vtbl)).DepthScaleInMeters)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, VideoFormat, DepthScaleInMeters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDepthMediaFrameFormat as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInfraredMediaFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FrameReference(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInfraredMediaFrame", "FrameReference", 6usize, |this| ((**(this as *const *const IInfraredMediaFrame_Vtbl)).FrameReference)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoMediaFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInfraredMediaFrame", "VideoMediaFrame", 7usize, |this| ((**(this as *const *const IInfraredMediaFrame_Vtbl)).VideoMediaFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIlluminated(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInfraredMediaFrame", "IsIlluminated", 8usize, |this| ((**(this as *const *const IInfraredMediaFrame_Vtbl)).IsIlluminated)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FrameReference, VideoMediaFrame, IsIlluminated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInfraredMediaFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaFrameArrivedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameArrivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IMediaFrameFormat_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MajorType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameFormat", "MajorType", 6usize, |this| ((**(this as *const *const IMediaFrameFormat_Vtbl)).MajorType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subtype(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameFormat", "Subtype", 7usize, |this| ((**(this as *const *const IMediaFrameFormat_Vtbl)).Subtype)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn FrameRate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameFormat", "FrameRate", 8usize, |this| ((**(this as *const *const IMediaFrameFormat_Vtbl)).FrameRate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameFormat", "Properties", 9usize, |this| ((**(this as *const *const IMediaFrameFormat_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormat(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameFormat", "VideoFormat", 10usize, |this| ((**(this as *const *const IMediaFrameFormat_Vtbl)).VideoFormat)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MajorType,
+            Subtype,
+            #[cfg(feature = "Media_MediaProperties")]
+            FrameRate,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            FrameRate: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            VideoFormat,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameFormat as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_MediaProperties")]
+impl windows_core::imp::ProxyVtable for IMediaFrameFormat2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn AudioEncodingProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameFormat2", "AudioEncodingProperties", 6usize, |this| ((**(this as *const *const IMediaFrameFormat2_Vtbl)).AudioEncodingProperties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_MediaProperties")]
+            AudioEncodingProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            AudioEncodingProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameFormat2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaFrameReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FrameArrived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReader", "FrameArrived", 6usize, |this| ((**(this as *const *const IMediaFrameReader_Vtbl)).FrameArrived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFrameArrived(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReader", "RemoveFrameArrived", 7usize, |this| ((**(this as *const *const IMediaFrameReader_Vtbl)).RemoveFrameArrived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryAcquireLatestFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReader", "TryAcquireLatestFrame", 8usize, |this| ((**(this as *const *const IMediaFrameReader_Vtbl)).TryAcquireLatestFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReader", "StartAsync", 9usize, |this| ((**(this as *const *const IMediaFrameReader_Vtbl)).StartAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReader", "StopAsync", 10usize, |this| ((**(this as *const *const IMediaFrameReader_Vtbl)).StopAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FrameArrived,
+            RemoveFrameArrived,
+            TryAcquireLatestFrame,
+            StartAsync,
+            StopAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaFrameReader2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAcquisitionMode(this: *mut core::ffi::c_void, value: MediaFrameReaderAcquisitionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReader2", "SetAcquisitionMode", 6usize, |this| ((**(this as *const *const IMediaFrameReader2_Vtbl)).SetAcquisitionMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcquisitionMode(this: *mut core::ffi::c_void, result__: *mut MediaFrameReaderAcquisitionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReader2", "AcquisitionMode", 7usize, |this| ((**(this as *const *const IMediaFrameReader2_Vtbl)).AcquisitionMode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAcquisitionMode, AcquisitionMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameReader2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for IMediaFrameReference_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SourceKind(this: *mut core::ffi::c_void, result__: *mut MediaFrameSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReference", "SourceKind", 6usize, |this| ((**(this as *const *const IMediaFrameReference_Vtbl)).SourceKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReference", "Format", 7usize, |this| ((**(this as *const *const IMediaFrameReference_Vtbl)).Format)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemRelativeTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReference", "SystemRelativeTime", 8usize, |this| ((**(this as *const *const IMediaFrameReference_Vtbl)).SystemRelativeTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReference", "Duration", 9usize, |this| ((**(this as *const *const IMediaFrameReference_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReference", "Properties", 10usize, |this| ((**(this as *const *const IMediaFrameReference_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BufferMediaFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReference", "BufferMediaFrame", 11usize, |this| ((**(this as *const *const IMediaFrameReference_Vtbl)).BufferMediaFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoMediaFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReference", "VideoMediaFrame", 12usize, |this| ((**(this as *const *const IMediaFrameReference_Vtbl)).VideoMediaFrame)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn CoordinateSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReference", "CoordinateSystem", 13usize, |this| ((**(this as *const *const IMediaFrameReference_Vtbl)).CoordinateSystem)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SourceKind,
+            Format,
+            SystemRelativeTime,
+            Duration,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            BufferMediaFrame,
+            VideoMediaFrame,
+            #[cfg(feature = "Perception_Spatial")]
+            CoordinateSystem,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            CoordinateSystem: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameReference as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaFrameReference2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AudioMediaFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameReference2", "AudioMediaFrame", 6usize, |this| ((**(this as *const *const IMediaFrameReference2_Vtbl)).AudioMediaFrame)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AudioMediaFrame }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameReference2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Devices_Core"))]
+impl windows_core::imp::ProxyVtable for IMediaFrameSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Info(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSource", "Info", 6usize, |this| ((**(this as *const *const IMediaFrameSource_Vtbl)).Info)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Controller(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSource", "Controller", 7usize, |this| ((**(this as *const *const IMediaFrameSource_Vtbl)).Controller)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSource", "SupportedFormats", 8usize, |this| ((**(this as *const *const IMediaFrameSource_Vtbl)).SupportedFormats)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentFormat(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSource", "CurrentFormat", 9usize, |this| ((**(this as *const *const IMediaFrameSource_Vtbl)).CurrentFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormatAsync(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSource", "SetFormatAsync", 10usize, |this| ((**(this as *const *const IMediaFrameSource_Vtbl)).SetFormatAsync)(this, format, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSource", "FormatChanged", 11usize, |this| ((**(this as *const *const IMediaFrameSource_Vtbl)).FormatChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFormatChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSource", "RemoveFormatChanged", 12usize, |this| ((**(this as *const *const IMediaFrameSource_Vtbl)).RemoveFormatChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Devices_Core")]
+        unsafe extern "system" fn TryGetCameraIntrinsics(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSource", "TryGetCameraIntrinsics", 13usize, |this| ((**(this as *const *const IMediaFrameSource_Vtbl)).TryGetCameraIntrinsics)(this, format, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Info,
+            Controller,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedFormats,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedFormats: 0,
+            CurrentFormat,
+            SetFormatAsync,
+            FormatChanged,
+            RemoveFormatChanged,
+            #[cfg(feature = "Media_Devices_Core")]
+            TryGetCameraIntrinsics,
+            #[cfg(not(feature = "Media_Devices_Core"))]
+            TryGetCameraIntrinsics: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Devices")]
+impl windows_core::imp::ProxyVtable for IMediaFrameSourceController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyAsync(this: *mut core::ffi::c_void, propertyid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceController", "GetPropertyAsync", 6usize, |this| ((**(this as *const *const IMediaFrameSourceController_Vtbl)).GetPropertyAsync)(this, propertyid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropertyAsync(this: *mut core::ffi::c_void, propertyid: core::mem::MaybeUninit<windows_core::HSTRING>, propertyvalue: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceController", "SetPropertyAsync", 7usize, |this| ((**(this as *const *const IMediaFrameSourceController_Vtbl)).SetPropertyAsync)(this, propertyid, propertyvalue, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Devices")]
+        unsafe extern "system" fn VideoDeviceController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceController", "VideoDeviceController", 8usize, |this| ((**(this as *const *const IMediaFrameSourceController_Vtbl)).VideoDeviceController)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPropertyAsync,
+            SetPropertyAsync,
+            #[cfg(feature = "Media_Devices")]
+            VideoDeviceController,
+            #[cfg(not(feature = "Media_Devices"))]
+            VideoDeviceController: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameSourceController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaFrameSourceController2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyByExtendedIdAsync(this: *mut core::ffi::c_void, extendedPropertyId_array_size: u32, extendedpropertyid: *const u8, maxpropertyvaluesize: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceController2", "GetPropertyByExtendedIdAsync", 6usize, |this| ((**(this as *const *const IMediaFrameSourceController2_Vtbl)).GetPropertyByExtendedIdAsync)(this, extendedPropertyId_array_size, extendedpropertyid, maxpropertyvaluesize, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropertyByExtendedIdAsync(this: *mut core::ffi::c_void, extendedPropertyId_array_size: u32, extendedpropertyid: *const u8, propertyValue_array_size: u32, propertyvalue: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceController2", "SetPropertyByExtendedIdAsync", 7usize, |this| ((**(this as *const *const IMediaFrameSourceController2_Vtbl)).SetPropertyByExtendedIdAsync)(this, extendedPropertyId_array_size, extendedpropertyid, propertyValue_array_size, propertyvalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPropertyByExtendedIdAsync, SetPropertyByExtendedIdAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameSourceController2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Devices")]
+impl windows_core::imp::ProxyVtable for IMediaFrameSourceController3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Devices")]
+        unsafe extern "system" fn AudioDeviceController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceController3", "AudioDeviceController", 6usize, |this| ((**(this as *const *const IMediaFrameSourceController3_Vtbl)).AudioDeviceController)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Devices")]
+            AudioDeviceController,
+            #[cfg(not(feature = "Media_Devices"))]
+            AudioDeviceController: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameSourceController3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaFrameSourceGetPropertyResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut MediaFrameSourceGetPropertyStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceGetPropertyResult", "Status", 6usize, |this| ((**(this as *const *const IMediaFrameSourceGetPropertyResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceGetPropertyResult", "Value", 7usize, |this| ((**(this as *const *const IMediaFrameSourceGetPropertyResult_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameSourceGetPropertyResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaFrameSourceGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceGroup", "Id", 6usize, |this| ((**(this as *const *const IMediaFrameSourceGroup_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceGroup", "DisplayName", 7usize, |this| ((**(this as *const *const IMediaFrameSourceGroup_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SourceInfos(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceGroup", "SourceInfos", 8usize, |this| ((**(this as *const *const IMediaFrameSourceGroup_Vtbl)).SourceInfos)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            DisplayName,
+            #[cfg(feature = "Foundation_Collections")]
+            SourceInfos,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SourceInfos: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameSourceGroup as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaFrameSourceGroupStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceGroupStatics", "FindAllAsync", 6usize, |this| ((**(this as *const *const IMediaFrameSourceGroupStatics_Vtbl)).FindAllAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceGroupStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const IMediaFrameSourceGroupStatics_Vtbl)).FromIdAsync)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceGroupStatics", "GetDeviceSelector", 8usize, |this| ((**(this as *const *const IMediaFrameSourceGroupStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsync: 0,
+            FromIdAsync,
+            GetDeviceSelector,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameSourceGroupStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "Foundation_Collections", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for IMediaFrameSourceInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceInfo", "Id", 6usize, |this| ((**(this as *const *const IMediaFrameSourceInfo_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaStreamType(this: *mut core::ffi::c_void, result__: *mut super::MediaStreamType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceInfo", "MediaStreamType", 7usize, |this| ((**(this as *const *const IMediaFrameSourceInfo_Vtbl)).MediaStreamType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceKind(this: *mut core::ffi::c_void, result__: *mut MediaFrameSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceInfo", "SourceKind", 8usize, |this| ((**(this as *const *const IMediaFrameSourceInfo_Vtbl)).SourceKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceGroup(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceInfo", "SourceGroup", 9usize, |this| ((**(this as *const *const IMediaFrameSourceInfo_Vtbl)).SourceGroup)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn DeviceInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceInfo", "DeviceInformation", 10usize, |this| ((**(this as *const *const IMediaFrameSourceInfo_Vtbl)).DeviceInformation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceInfo", "Properties", 11usize, |this| ((**(this as *const *const IMediaFrameSourceInfo_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn CoordinateSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceInfo", "CoordinateSystem", 12usize, |this| ((**(this as *const *const IMediaFrameSourceInfo_Vtbl)).CoordinateSystem)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            MediaStreamType,
+            SourceKind,
+            SourceGroup,
+            #[cfg(feature = "Devices_Enumeration")]
+            DeviceInformation,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            DeviceInformation: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            #[cfg(feature = "Perception_Spatial")]
+            CoordinateSystem,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            CoordinateSystem: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameSourceInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaFrameSourceInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProfileId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceInfo2", "ProfileId", 6usize, |this| ((**(this as *const *const IMediaFrameSourceInfo2_Vtbl)).ProfileId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn VideoProfileMediaDescription(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceInfo2", "VideoProfileMediaDescription", 7usize, |this| ((**(this as *const *const IMediaFrameSourceInfo2_Vtbl)).VideoProfileMediaDescription)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProfileId,
+            #[cfg(feature = "Foundation_Collections")]
+            VideoProfileMediaDescription,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            VideoProfileMediaDescription: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameSourceInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "UI_WindowManagement"))]
+impl windows_core::imp::ProxyVtable for IMediaFrameSourceInfo3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Devices_Enumeration", feature = "UI_WindowManagement"))]
+        unsafe extern "system" fn GetRelativePanel(this: *mut core::ffi::c_void, displayregion: *mut core::ffi::c_void, result__: *mut super::super::super::Devices::Enumeration::Panel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceInfo3", "GetRelativePanel", 6usize, |this| ((**(this as *const *const IMediaFrameSourceInfo3_Vtbl)).GetRelativePanel)(this, displayregion, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Devices_Enumeration", feature = "UI_WindowManagement"))]
+            GetRelativePanel,
+            #[cfg(not(all(feature = "Devices_Enumeration", feature = "UI_WindowManagement")))]
+            GetRelativePanel: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameSourceInfo3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaFrameSourceInfo4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsShareable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrameSourceInfo4", "IsShareable", 6usize, |this| ((**(this as *const *const IMediaFrameSourceInfo4_Vtbl)).IsShareable)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsShareable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrameSourceInfo4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMultiSourceMediaFrameArrivedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultiSourceMediaFrameArrivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMultiSourceMediaFrameReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FrameArrived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiSourceMediaFrameReader", "FrameArrived", 6usize, |this| ((**(this as *const *const IMultiSourceMediaFrameReader_Vtbl)).FrameArrived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFrameArrived(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiSourceMediaFrameReader", "RemoveFrameArrived", 7usize, |this| ((**(this as *const *const IMultiSourceMediaFrameReader_Vtbl)).RemoveFrameArrived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryAcquireLatestFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiSourceMediaFrameReader", "TryAcquireLatestFrame", 8usize, |this| ((**(this as *const *const IMultiSourceMediaFrameReader_Vtbl)).TryAcquireLatestFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiSourceMediaFrameReader", "StartAsync", 9usize, |this| ((**(this as *const *const IMultiSourceMediaFrameReader_Vtbl)).StartAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiSourceMediaFrameReader", "StopAsync", 10usize, |this| ((**(this as *const *const IMultiSourceMediaFrameReader_Vtbl)).StopAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FrameArrived,
+            RemoveFrameArrived,
+            TryAcquireLatestFrame,
+            StartAsync,
+            StopAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultiSourceMediaFrameReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMultiSourceMediaFrameReader2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAcquisitionMode(this: *mut core::ffi::c_void, value: MediaFrameReaderAcquisitionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiSourceMediaFrameReader2", "SetAcquisitionMode", 6usize, |this| ((**(this as *const *const IMultiSourceMediaFrameReader2_Vtbl)).SetAcquisitionMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcquisitionMode(this: *mut core::ffi::c_void, result__: *mut MediaFrameReaderAcquisitionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiSourceMediaFrameReader2", "AcquisitionMode", 7usize, |this| ((**(this as *const *const IMultiSourceMediaFrameReader2_Vtbl)).AcquisitionMode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAcquisitionMode, AcquisitionMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultiSourceMediaFrameReader2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMultiSourceMediaFrameReference_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryGetFrameReferenceBySourceId(this: *mut core::ffi::c_void, sourceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiSourceMediaFrameReference", "TryGetFrameReferenceBySourceId", 6usize, |this| ((**(this as *const *const IMultiSourceMediaFrameReference_Vtbl)).TryGetFrameReferenceBySourceId)(this, sourceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryGetFrameReferenceBySourceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultiSourceMediaFrameReference as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Graphics_DirectX_Direct3D11", feature = "Graphics_Imaging", feature = "Media_Devices_Core"))]
+impl windows_core::imp::ProxyVtable for IVideoMediaFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FrameReference(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoMediaFrame", "FrameReference", 6usize, |this| ((**(this as *const *const IVideoMediaFrame_Vtbl)).FrameReference)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormat(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoMediaFrame", "VideoFormat", 7usize, |this| ((**(this as *const *const IVideoMediaFrame_Vtbl)).VideoFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SoftwareBitmap(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoMediaFrame", "SoftwareBitmap", 8usize, |this| ((**(this as *const *const IVideoMediaFrame_Vtbl)).SoftwareBitmap)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn Direct3DSurface(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoMediaFrame", "Direct3DSurface", 9usize, |this| ((**(this as *const *const IVideoMediaFrame_Vtbl)).Direct3DSurface)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Devices_Core")]
+        unsafe extern "system" fn CameraIntrinsics(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoMediaFrame", "CameraIntrinsics", 10usize, |this| ((**(this as *const *const IVideoMediaFrame_Vtbl)).CameraIntrinsics)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InfraredMediaFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoMediaFrame", "InfraredMediaFrame", 11usize, |this| ((**(this as *const *const IVideoMediaFrame_Vtbl)).InfraredMediaFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DepthMediaFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoMediaFrame", "DepthMediaFrame", 12usize, |this| ((**(this as *const *const IVideoMediaFrame_Vtbl)).DepthMediaFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoMediaFrame", "GetVideoFrame", 13usize, |this| ((**(this as *const *const IVideoMediaFrame_Vtbl)).GetVideoFrame)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FrameReference,
+            VideoFormat,
+            #[cfg(feature = "Graphics_Imaging")]
+            SoftwareBitmap,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SoftwareBitmap: 0,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            Direct3DSurface,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            Direct3DSurface: 0,
+            #[cfg(feature = "Media_Devices_Core")]
+            CameraIntrinsics,
+            #[cfg(not(feature = "Media_Devices_Core"))]
+            CameraIntrinsics: 0,
+            InfraredMediaFrame,
+            DepthMediaFrame,
+            GetVideoFrame,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoMediaFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoMediaFrameFormat_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MediaFrameFormat(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoMediaFrameFormat", "MediaFrameFormat", 6usize, |this| ((**(this as *const *const IVideoMediaFrameFormat_Vtbl)).MediaFrameFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DepthFormat(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoMediaFrameFormat", "DepthFormat", 7usize, |this| ((**(this as *const *const IVideoMediaFrameFormat_Vtbl)).DepthFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoMediaFrameFormat", "Width", 8usize, |this| ((**(this as *const *const IVideoMediaFrameFormat_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoMediaFrameFormat", "Height", 9usize, |this| ((**(this as *const *const IVideoMediaFrameFormat_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MediaFrameFormat, DepthFormat, Width, Height }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoMediaFrameFormat as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -7421,6 +7421,7 @@ impl AppBroadcastCameraCaptureState {
 impl windows_core::TypeKind for AppBroadcastCameraCaptureState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppBroadcastCameraCaptureState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppBroadcastCameraCaptureState").field(&self.0).finish()
@@ -7446,6 +7447,7 @@ impl AppBroadcastCameraOverlayLocation {
 impl windows_core::TypeKind for AppBroadcastCameraOverlayLocation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppBroadcastCameraOverlayLocation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppBroadcastCameraOverlayLocation").field(&self.0).finish()
@@ -7465,6 +7467,7 @@ impl AppBroadcastCameraOverlaySize {
 impl windows_core::TypeKind for AppBroadcastCameraOverlaySize {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppBroadcastCameraOverlaySize {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppBroadcastCameraOverlaySize").field(&self.0).finish()
@@ -7483,6 +7486,7 @@ impl AppBroadcastCaptureTargetType {
 impl windows_core::TypeKind for AppBroadcastCaptureTargetType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppBroadcastCaptureTargetType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppBroadcastCaptureTargetType").field(&self.0).finish()
@@ -7503,6 +7507,7 @@ impl AppBroadcastExitBroadcastModeReason {
 impl windows_core::TypeKind for AppBroadcastExitBroadcastModeReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppBroadcastExitBroadcastModeReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppBroadcastExitBroadcastModeReason").field(&self.0).finish()
@@ -7522,6 +7527,7 @@ impl AppBroadcastMicrophoneCaptureState {
 impl windows_core::TypeKind for AppBroadcastMicrophoneCaptureState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppBroadcastMicrophoneCaptureState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppBroadcastMicrophoneCaptureState").field(&self.0).finish()
@@ -7545,6 +7551,7 @@ impl AppBroadcastPlugInState {
 impl windows_core::TypeKind for AppBroadcastPlugInState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppBroadcastPlugInState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppBroadcastPlugInState").field(&self.0).finish()
@@ -7564,6 +7571,7 @@ impl AppBroadcastPreviewState {
 impl windows_core::TypeKind for AppBroadcastPreviewState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppBroadcastPreviewState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppBroadcastPreviewState").field(&self.0).finish()
@@ -7585,6 +7593,7 @@ impl AppBroadcastSignInResult {
 impl windows_core::TypeKind for AppBroadcastSignInResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppBroadcastSignInResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppBroadcastSignInResult").field(&self.0).finish()
@@ -7606,6 +7615,7 @@ impl AppBroadcastSignInState {
 impl windows_core::TypeKind for AppBroadcastSignInState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppBroadcastSignInState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppBroadcastSignInState").field(&self.0).finish()
@@ -7627,6 +7637,7 @@ impl AppBroadcastStreamState {
 impl windows_core::TypeKind for AppBroadcastStreamState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppBroadcastStreamState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppBroadcastStreamState").field(&self.0).finish()
@@ -7653,6 +7664,7 @@ impl AppBroadcastTerminationReason {
 impl windows_core::TypeKind for AppBroadcastTerminationReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppBroadcastTerminationReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppBroadcastTerminationReason").field(&self.0).finish()
@@ -7671,6 +7683,7 @@ impl AppBroadcastVideoEncodingBitrateMode {
 impl windows_core::TypeKind for AppBroadcastVideoEncodingBitrateMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppBroadcastVideoEncodingBitrateMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppBroadcastVideoEncodingBitrateMode").field(&self.0).finish()
@@ -7689,6 +7702,7 @@ impl AppBroadcastVideoEncodingResolutionMode {
 impl windows_core::TypeKind for AppBroadcastVideoEncodingResolutionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppBroadcastVideoEncodingResolutionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppBroadcastVideoEncodingResolutionMode").field(&self.0).finish()
@@ -7707,6 +7721,7 @@ impl AppCaptureHistoricalBufferLengthUnit {
 impl windows_core::TypeKind for AppCaptureHistoricalBufferLengthUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppCaptureHistoricalBufferLengthUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppCaptureHistoricalBufferLengthUnit").field(&self.0).finish()
@@ -7725,6 +7740,7 @@ impl AppCaptureMetadataPriority {
 impl windows_core::TypeKind for AppCaptureMetadataPriority {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppCaptureMetadataPriority {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppCaptureMetadataPriority").field(&self.0).finish()
@@ -7744,6 +7760,7 @@ impl AppCaptureMicrophoneCaptureState {
 impl windows_core::TypeKind for AppCaptureMicrophoneCaptureState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppCaptureMicrophoneCaptureState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppCaptureMicrophoneCaptureState").field(&self.0).finish()
@@ -7763,6 +7780,7 @@ impl AppCaptureRecordingState {
 impl windows_core::TypeKind for AppCaptureRecordingState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppCaptureRecordingState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppCaptureRecordingState").field(&self.0).finish()
@@ -7782,6 +7800,7 @@ impl AppCaptureVideoEncodingBitrateMode {
 impl windows_core::TypeKind for AppCaptureVideoEncodingBitrateMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppCaptureVideoEncodingBitrateMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppCaptureVideoEncodingBitrateMode").field(&self.0).finish()
@@ -7800,6 +7819,7 @@ impl AppCaptureVideoEncodingFrameRateMode {
 impl windows_core::TypeKind for AppCaptureVideoEncodingFrameRateMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppCaptureVideoEncodingFrameRateMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppCaptureVideoEncodingFrameRateMode").field(&self.0).finish()
@@ -7819,6 +7839,7 @@ impl AppCaptureVideoEncodingResolutionMode {
 impl windows_core::TypeKind for AppCaptureVideoEncodingResolutionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppCaptureVideoEncodingResolutionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppCaptureVideoEncodingResolutionMode").field(&self.0).finish()
@@ -7841,6 +7862,7 @@ impl CameraCaptureUIMaxPhotoResolution {
 impl windows_core::TypeKind for CameraCaptureUIMaxPhotoResolution {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CameraCaptureUIMaxPhotoResolution {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CameraCaptureUIMaxPhotoResolution").field(&self.0).finish()
@@ -7861,6 +7883,7 @@ impl CameraCaptureUIMaxVideoResolution {
 impl windows_core::TypeKind for CameraCaptureUIMaxVideoResolution {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CameraCaptureUIMaxVideoResolution {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CameraCaptureUIMaxVideoResolution").field(&self.0).finish()
@@ -7880,6 +7903,7 @@ impl CameraCaptureUIMode {
 impl windows_core::TypeKind for CameraCaptureUIMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CameraCaptureUIMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CameraCaptureUIMode").field(&self.0).finish()
@@ -7899,6 +7923,7 @@ impl CameraCaptureUIPhotoFormat {
 impl windows_core::TypeKind for CameraCaptureUIPhotoFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CameraCaptureUIPhotoFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CameraCaptureUIPhotoFormat").field(&self.0).finish()
@@ -7917,6 +7942,7 @@ impl CameraCaptureUIVideoFormat {
 impl windows_core::TypeKind for CameraCaptureUIVideoFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CameraCaptureUIVideoFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CameraCaptureUIVideoFormat").field(&self.0).finish()
@@ -7935,6 +7961,7 @@ impl ForegroundActivationArgument {
 impl windows_core::TypeKind for ForegroundActivationArgument {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ForegroundActivationArgument {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ForegroundActivationArgument").field(&self.0).finish()
@@ -7965,6 +7992,7 @@ impl GameBarCommand {
 impl windows_core::TypeKind for GameBarCommand {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameBarCommand {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameBarCommand").field(&self.0).finish()
@@ -7984,6 +8012,7 @@ impl GameBarCommandOrigin {
 impl windows_core::TypeKind for GameBarCommandOrigin {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameBarCommandOrigin {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameBarCommandOrigin").field(&self.0).finish()
@@ -8002,6 +8031,7 @@ impl GameBarServicesDisplayMode {
 impl windows_core::TypeKind for GameBarServicesDisplayMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameBarServicesDisplayMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameBarServicesDisplayMode").field(&self.0).finish()
@@ -8023,6 +8053,7 @@ impl GameBarTargetCapturePolicy {
 impl windows_core::TypeKind for GameBarTargetCapturePolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameBarTargetCapturePolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameBarTargetCapturePolicy").field(&self.0).finish()
@@ -8050,6 +8081,7 @@ impl KnownVideoProfile {
 impl windows_core::TypeKind for KnownVideoProfile {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KnownVideoProfile {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KnownVideoProfile").field(&self.0).finish()
@@ -8068,6 +8100,7 @@ impl MediaCaptureDeviceExclusiveControlReleaseMode {
 impl windows_core::TypeKind for MediaCaptureDeviceExclusiveControlReleaseMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaCaptureDeviceExclusiveControlReleaseMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaCaptureDeviceExclusiveControlReleaseMode").field(&self.0).finish()
@@ -8086,6 +8119,7 @@ impl MediaCaptureDeviceExclusiveControlStatus {
 impl windows_core::TypeKind for MediaCaptureDeviceExclusiveControlStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaCaptureDeviceExclusiveControlStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaCaptureDeviceExclusiveControlStatus").field(&self.0).finish()
@@ -8104,6 +8138,7 @@ impl MediaCaptureMemoryPreference {
 impl windows_core::TypeKind for MediaCaptureMemoryPreference {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaCaptureMemoryPreference {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaCaptureMemoryPreference").field(&self.0).finish()
@@ -8122,6 +8157,7 @@ impl MediaCaptureSharingMode {
 impl windows_core::TypeKind for MediaCaptureSharingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaCaptureSharingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaCaptureSharingMode").field(&self.0).finish()
@@ -8140,6 +8176,7 @@ impl MediaCaptureThermalStatus {
 impl windows_core::TypeKind for MediaCaptureThermalStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaCaptureThermalStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaCaptureThermalStatus").field(&self.0).finish()
@@ -8164,6 +8201,7 @@ impl MediaCategory {
 impl windows_core::TypeKind for MediaCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaCategory").field(&self.0).finish()
@@ -8185,6 +8223,7 @@ impl MediaStreamType {
 impl windows_core::TypeKind for MediaStreamType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaStreamType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaStreamType").field(&self.0).finish()
@@ -8204,6 +8243,7 @@ impl PhotoCaptureSource {
 impl windows_core::TypeKind for PhotoCaptureSource {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhotoCaptureSource {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhotoCaptureSource").field(&self.0).finish()
@@ -8224,6 +8264,7 @@ impl PowerlineFrequency {
 impl windows_core::TypeKind for PowerlineFrequency {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PowerlineFrequency {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PowerlineFrequency").field(&self.0).finish()
@@ -8243,6 +8284,7 @@ impl StreamingCaptureMode {
 impl windows_core::TypeKind for StreamingCaptureMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StreamingCaptureMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StreamingCaptureMode").field(&self.0).finish()
@@ -8264,6 +8306,7 @@ impl VideoDeviceCharacteristic {
 impl windows_core::TypeKind for VideoDeviceCharacteristic {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VideoDeviceCharacteristic {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VideoDeviceCharacteristic").field(&self.0).finish()
@@ -8284,6 +8327,7 @@ impl VideoRotation {
 impl windows_core::TypeKind for VideoRotation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VideoRotation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VideoRotation").field(&self.0).finish()
@@ -8293,7 +8337,8 @@ impl windows_core::RuntimeType for VideoRotation {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.Capture.VideoRotation;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WhiteBalanceGain {
     pub R: f64,
     pub G: f64,
@@ -8431,3 +8476,5 @@ pub struct RecordLimitationExceededEventHandler_Vtbl {
     pub base__: windows_core::IUnknown_Vtbl,
     pub Invoke: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
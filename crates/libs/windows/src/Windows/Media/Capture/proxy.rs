@@ -0,0 +1,4010 @@
+#[cfg(feature = "Media_Devices")]
+impl windows_core::imp::ProxyVtable for IAdvancedCapturedPhoto_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Frame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedCapturedPhoto", "Frame", 6usize, |this| ((**(this as *const *const IAdvancedCapturedPhoto_Vtbl)).Frame)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Devices")]
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut super::Devices::AdvancedPhotoMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedCapturedPhoto", "Mode", 7usize, |this| ((**(this as *const *const IAdvancedCapturedPhoto_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Context(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedCapturedPhoto", "Context", 8usize, |this| ((**(this as *const *const IAdvancedCapturedPhoto_Vtbl)).Context)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Frame,
+            #[cfg(feature = "Media_Devices")]
+            Mode,
+            #[cfg(not(feature = "Media_Devices"))]
+            Mode: 0,
+            Context,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedCapturedPhoto as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdvancedCapturedPhoto2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FrameBoundsRelativeToReferencePhoto(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedCapturedPhoto2", "FrameBoundsRelativeToReferencePhoto", 6usize, |this| ((**(this as *const *const IAdvancedCapturedPhoto2_Vtbl)).FrameBoundsRelativeToReferencePhoto)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FrameBoundsRelativeToReferencePhoto }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedCapturedPhoto2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdvancedPhotoCapture_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CaptureAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedPhotoCapture", "CaptureAsync", 6usize, |this| ((**(this as *const *const IAdvancedPhotoCapture_Vtbl)).CaptureAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CaptureWithContextAsync(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedPhotoCapture", "CaptureWithContextAsync", 7usize, |this| ((**(this as *const *const IAdvancedPhotoCapture_Vtbl)).CaptureWithContextAsync)(this, context, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OptionalReferencePhotoCaptured(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedPhotoCapture", "OptionalReferencePhotoCaptured", 8usize, |this| ((**(this as *const *const IAdvancedPhotoCapture_Vtbl)).OptionalReferencePhotoCaptured)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOptionalReferencePhotoCaptured(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedPhotoCapture", "RemoveOptionalReferencePhotoCaptured", 9usize, |this| ((**(this as *const *const IAdvancedPhotoCapture_Vtbl)).RemoveOptionalReferencePhotoCaptured)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllPhotosCaptured(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedPhotoCapture", "AllPhotosCaptured", 10usize, |this| ((**(this as *const *const IAdvancedPhotoCapture_Vtbl)).AllPhotosCaptured)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllPhotosCaptured(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedPhotoCapture", "RemoveAllPhotosCaptured", 11usize, |this| ((**(this as *const *const IAdvancedPhotoCapture_Vtbl)).RemoveAllPhotosCaptured)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinishAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedPhotoCapture", "FinishAsync", 12usize, |this| ((**(this as *const *const IAdvancedPhotoCapture_Vtbl)).FinishAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CaptureAsync,
+            CaptureWithContextAsync,
+            OptionalReferencePhotoCaptured,
+            RemoveOptionalReferencePhotoCaptured,
+            AllPhotosCaptured,
+            RemoveAllPhotosCaptured,
+            FinishAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedPhotoCapture as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastBackgroundService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPlugInState(this: *mut core::ffi::c_void, value: AppBroadcastPlugInState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService", "SetPlugInState", 6usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService_Vtbl)).SetPlugInState)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlugInState(this: *mut core::ffi::c_void, result__: *mut AppBroadcastPlugInState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService", "PlugInState", 7usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService_Vtbl)).PlugInState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignInInfo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService", "SetSignInInfo", 8usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService_Vtbl)).SetSignInInfo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignInInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService", "SignInInfo", 9usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService_Vtbl)).SignInInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamInfo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService", "SetStreamInfo", 10usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService_Vtbl)).SetStreamInfo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StreamInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService", "StreamInfo", 11usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService_Vtbl)).StreamInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService", "AppId", 12usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService_Vtbl)).AppId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BroadcastTitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService", "BroadcastTitle", 13usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService_Vtbl)).BroadcastTitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewerCount(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService", "SetViewerCount", 14usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService_Vtbl)).SetViewerCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ViewerCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService", "ViewerCount", 15usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService_Vtbl)).ViewerCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminateBroadcast(this: *mut core::ffi::c_void, reason: AppBroadcastTerminationReason, providerspecificreason: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService", "TerminateBroadcast", 16usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService_Vtbl)).TerminateBroadcast)(this, reason, providerspecificreason), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeartbeatRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService", "HeartbeatRequested", 17usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService_Vtbl)).HeartbeatRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHeartbeatRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService", "RemoveHeartbeatRequested", 18usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService_Vtbl)).RemoveHeartbeatRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TitleId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService", "TitleId", 19usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService_Vtbl)).TitleId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPlugInState,
+            PlugInState,
+            SetSignInInfo,
+            SignInInfo,
+            SetStreamInfo,
+            StreamInfo,
+            AppId,
+            BroadcastTitle,
+            SetViewerCount,
+            ViewerCount,
+            TerminateBroadcast,
+            HeartbeatRequested,
+            RemoveHeartbeatRequested,
+            TitleId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastBackgroundService as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastBackgroundService2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBroadcastTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService2", "SetBroadcastTitle", 6usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService2_Vtbl)).SetBroadcastTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BroadcastLanguage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService2", "BroadcastLanguage", 7usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService2_Vtbl)).BroadcastLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBroadcastLanguage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService2", "SetBroadcastLanguage", 8usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService2_Vtbl)).SetBroadcastLanguage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BroadcastChannel(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService2", "BroadcastChannel", 9usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService2_Vtbl)).BroadcastChannel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBroadcastChannel(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService2", "SetBroadcastChannel", 10usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService2_Vtbl)).SetBroadcastChannel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BroadcastTitleChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService2", "BroadcastTitleChanged", 11usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService2_Vtbl)).BroadcastTitleChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBroadcastTitleChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService2", "RemoveBroadcastTitleChanged", 12usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService2_Vtbl)).RemoveBroadcastTitleChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn BroadcastLanguageChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService2", "BroadcastLanguageChanged", 13usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService2_Vtbl)).BroadcastLanguageChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBroadcastLanguageChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService2", "RemoveBroadcastLanguageChanged", 14usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService2_Vtbl)).RemoveBroadcastLanguageChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn BroadcastChannelChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService2", "BroadcastChannelChanged", 15usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService2_Vtbl)).BroadcastChannelChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBroadcastChannelChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundService2", "RemoveBroadcastChannelChanged", 16usize, |this| ((**(this as *const *const IAppBroadcastBackgroundService2_Vtbl)).RemoveBroadcastChannelChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetBroadcastTitle,
+            BroadcastLanguage,
+            SetBroadcastLanguage,
+            BroadcastChannel,
+            SetBroadcastChannel,
+            BroadcastTitleChanged,
+            RemoveBroadcastTitleChanged,
+            BroadcastLanguageChanged,
+            RemoveBroadcastLanguageChanged,
+            BroadcastChannelChanged,
+            RemoveBroadcastChannelChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastBackgroundService2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Authentication_Web")]
+impl windows_core::imp::ProxyVtable for IAppBroadcastBackgroundServiceSignInInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SignInState(this: *mut core::ffi::c_void, result__: *mut AppBroadcastSignInState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceSignInInfo", "SignInState", 6usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceSignInInfo_Vtbl)).SignInState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOAuthRequestUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceSignInInfo", "SetOAuthRequestUri", 7usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceSignInInfo_Vtbl)).SetOAuthRequestUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OAuthRequestUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceSignInInfo", "OAuthRequestUri", 8usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceSignInInfo_Vtbl)).OAuthRequestUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOAuthCallbackUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceSignInInfo", "SetOAuthCallbackUri", 9usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceSignInInfo_Vtbl)).SetOAuthCallbackUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OAuthCallbackUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceSignInInfo", "OAuthCallbackUri", 10usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceSignInInfo_Vtbl)).OAuthCallbackUri)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Authentication_Web")]
+        unsafe extern "system" fn AuthenticationResult(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceSignInInfo", "AuthenticationResult", 11usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceSignInInfo_Vtbl)).AuthenticationResult)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceSignInInfo", "SetUserName", 12usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceSignInInfo_Vtbl)).SetUserName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceSignInInfo", "UserName", 13usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceSignInInfo_Vtbl)).UserName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignInStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceSignInInfo", "SignInStateChanged", 14usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceSignInInfo_Vtbl)).SignInStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSignInStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceSignInInfo", "RemoveSignInStateChanged", 15usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceSignInInfo_Vtbl)).RemoveSignInStateChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SignInState,
+            SetOAuthRequestUri,
+            OAuthRequestUri,
+            SetOAuthCallbackUri,
+            OAuthCallbackUri,
+            #[cfg(feature = "Security_Authentication_Web")]
+            AuthenticationResult,
+            #[cfg(not(feature = "Security_Authentication_Web"))]
+            AuthenticationResult: 0,
+            SetUserName,
+            UserName,
+            SignInStateChanged,
+            RemoveSignInStateChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastBackgroundServiceSignInInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastBackgroundServiceSignInInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserNameChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceSignInInfo2", "UserNameChanged", 6usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceSignInInfo2_Vtbl)).UserNameChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUserNameChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceSignInInfo2", "RemoveUserNameChanged", 7usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceSignInInfo2_Vtbl)).RemoveUserNameChanged)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserNameChanged, RemoveUserNameChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastBackgroundServiceSignInInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastBackgroundServiceStreamInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StreamState(this: *mut core::ffi::c_void, result__: *mut AppBroadcastStreamState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceStreamInfo", "StreamState", 6usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceStreamInfo_Vtbl)).StreamState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredVideoEncodingBitrate(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceStreamInfo", "SetDesiredVideoEncodingBitrate", 7usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceStreamInfo_Vtbl)).SetDesiredVideoEncodingBitrate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredVideoEncodingBitrate(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceStreamInfo", "DesiredVideoEncodingBitrate", 8usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceStreamInfo_Vtbl)).DesiredVideoEncodingBitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBandwidthTestBitrate(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceStreamInfo", "SetBandwidthTestBitrate", 9usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceStreamInfo_Vtbl)).SetBandwidthTestBitrate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BandwidthTestBitrate(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceStreamInfo", "BandwidthTestBitrate", 10usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceStreamInfo_Vtbl)).BandwidthTestBitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioCodec(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceStreamInfo", "SetAudioCodec", 11usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceStreamInfo_Vtbl)).SetAudioCodec)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioCodec(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceStreamInfo", "AudioCodec", 12usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceStreamInfo_Vtbl)).AudioCodec)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BroadcastStreamReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceStreamInfo", "BroadcastStreamReader", 13usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceStreamInfo_Vtbl)).BroadcastStreamReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StreamStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceStreamInfo", "StreamStateChanged", 14usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceStreamInfo_Vtbl)).StreamStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStreamStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceStreamInfo", "RemoveStreamStateChanged", 15usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceStreamInfo_Vtbl)).RemoveStreamStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoEncodingResolutionChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceStreamInfo", "VideoEncodingResolutionChanged", 16usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceStreamInfo_Vtbl)).VideoEncodingResolutionChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVideoEncodingResolutionChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceStreamInfo", "RemoveVideoEncodingResolutionChanged", 17usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceStreamInfo_Vtbl)).RemoveVideoEncodingResolutionChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoEncodingBitrateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceStreamInfo", "VideoEncodingBitrateChanged", 18usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceStreamInfo_Vtbl)).VideoEncodingBitrateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVideoEncodingBitrateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceStreamInfo", "RemoveVideoEncodingBitrateChanged", 19usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceStreamInfo_Vtbl)).RemoveVideoEncodingBitrateChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StreamState,
+            SetDesiredVideoEncodingBitrate,
+            DesiredVideoEncodingBitrate,
+            SetBandwidthTestBitrate,
+            BandwidthTestBitrate,
+            SetAudioCodec,
+            AudioCodec,
+            BroadcastStreamReader,
+            StreamStateChanged,
+            RemoveStreamStateChanged,
+            VideoEncodingResolutionChanged,
+            RemoveVideoEncodingResolutionChanged,
+            VideoEncodingBitrateChanged,
+            RemoveVideoEncodingBitrateChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastBackgroundServiceStreamInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastBackgroundServiceStreamInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportProblemWithStream(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastBackgroundServiceStreamInfo2", "ReportProblemWithStream", 6usize, |this| ((**(this as *const *const IAppBroadcastBackgroundServiceStreamInfo2_Vtbl)).ReportProblemWithStream)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportProblemWithStream }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastBackgroundServiceStreamInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastCameraCaptureStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut AppBroadcastCameraCaptureState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastCameraCaptureStateChangedEventArgs", "State", 6usize, |this| ((**(this as *const *const IAppBroadcastCameraCaptureStateChangedEventArgs_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastCameraCaptureStateChangedEventArgs", "ErrorCode", 7usize, |this| ((**(this as *const *const IAppBroadcastCameraCaptureStateChangedEventArgs_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, State, ErrorCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastCameraCaptureStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastGlobalSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsBroadcastEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "IsBroadcastEnabled", 6usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).IsBroadcastEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDisabledByPolicy(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "IsDisabledByPolicy", 7usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).IsDisabledByPolicy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGpuConstrained(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "IsGpuConstrained", 8usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).IsGpuConstrained)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasHardwareEncoder(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "HasHardwareEncoder", 9usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).HasHardwareEncoder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsAudioCaptureEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "SetIsAudioCaptureEnabled", 10usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).SetIsAudioCaptureEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAudioCaptureEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "IsAudioCaptureEnabled", 11usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).IsAudioCaptureEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsMicrophoneCaptureEnabledByDefault(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "SetIsMicrophoneCaptureEnabledByDefault", 12usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).SetIsMicrophoneCaptureEnabledByDefault)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMicrophoneCaptureEnabledByDefault(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "IsMicrophoneCaptureEnabledByDefault", 13usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).IsMicrophoneCaptureEnabledByDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEchoCancellationEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "SetIsEchoCancellationEnabled", 14usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).SetIsEchoCancellationEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEchoCancellationEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "IsEchoCancellationEnabled", 15usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).IsEchoCancellationEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSystemAudioGain(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "SetSystemAudioGain", 16usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).SetSystemAudioGain)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemAudioGain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "SystemAudioGain", 17usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).SystemAudioGain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMicrophoneGain(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "SetMicrophoneGain", 18usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).SetMicrophoneGain)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MicrophoneGain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "MicrophoneGain", 19usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).MicrophoneGain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCameraCaptureEnabledByDefault(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "SetIsCameraCaptureEnabledByDefault", 20usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).SetIsCameraCaptureEnabledByDefault)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCameraCaptureEnabledByDefault(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "IsCameraCaptureEnabledByDefault", 21usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).IsCameraCaptureEnabledByDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelectedCameraId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "SetSelectedCameraId", 22usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).SetSelectedCameraId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedCameraId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "SelectedCameraId", 23usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).SelectedCameraId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCameraOverlayLocation(this: *mut core::ffi::c_void, value: AppBroadcastCameraOverlayLocation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "SetCameraOverlayLocation", 24usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).SetCameraOverlayLocation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CameraOverlayLocation(this: *mut core::ffi::c_void, result__: *mut AppBroadcastCameraOverlayLocation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "CameraOverlayLocation", 25usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).CameraOverlayLocation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCameraOverlaySize(this: *mut core::ffi::c_void, value: AppBroadcastCameraOverlaySize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "SetCameraOverlaySize", 26usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).SetCameraOverlaySize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CameraOverlaySize(this: *mut core::ffi::c_void, result__: *mut AppBroadcastCameraOverlaySize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "CameraOverlaySize", 27usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).CameraOverlaySize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCursorImageCaptureEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "SetIsCursorImageCaptureEnabled", 28usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).SetIsCursorImageCaptureEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCursorImageCaptureEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastGlobalSettings", "IsCursorImageCaptureEnabled", 29usize, |this| ((**(this as *const *const IAppBroadcastGlobalSettings_Vtbl)).IsCursorImageCaptureEnabled)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsBroadcastEnabled,
+            IsDisabledByPolicy,
+            IsGpuConstrained,
+            HasHardwareEncoder,
+            SetIsAudioCaptureEnabled,
+            IsAudioCaptureEnabled,
+            SetIsMicrophoneCaptureEnabledByDefault,
+            IsMicrophoneCaptureEnabledByDefault,
+            SetIsEchoCancellationEnabled,
+            IsEchoCancellationEnabled,
+            SetSystemAudioGain,
+            SystemAudioGain,
+            SetMicrophoneGain,
+            MicrophoneGain,
+            SetIsCameraCaptureEnabledByDefault,
+            IsCameraCaptureEnabledByDefault,
+            SetSelectedCameraId,
+            SelectedCameraId,
+            SetCameraOverlayLocation,
+            CameraOverlayLocation,
+            SetCameraOverlaySize,
+            CameraOverlaySize,
+            SetIsCursorImageCaptureEnabled,
+            IsCursorImageCaptureEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastGlobalSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastHeartbeatRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastHeartbeatRequestedEventArgs", "SetHandled", 6usize, |this| ((**(this as *const *const IAppBroadcastHeartbeatRequestedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastHeartbeatRequestedEventArgs", "Handled", 7usize, |this| ((**(this as *const *const IAppBroadcastHeartbeatRequestedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetHandled, Handled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastHeartbeatRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGlobalSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastManagerStatics", "GetGlobalSettings", 6usize, |this| ((**(this as *const *const IAppBroadcastManagerStatics_Vtbl)).GetGlobalSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyGlobalSettings(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastManagerStatics", "ApplyGlobalSettings", 7usize, |this| ((**(this as *const *const IAppBroadcastManagerStatics_Vtbl)).ApplyGlobalSettings)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProviderSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastManagerStatics", "GetProviderSettings", 8usize, |this| ((**(this as *const *const IAppBroadcastManagerStatics_Vtbl)).GetProviderSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyProviderSettings(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastManagerStatics", "ApplyProviderSettings", 9usize, |this| ((**(this as *const *const IAppBroadcastManagerStatics_Vtbl)).ApplyProviderSettings)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetGlobalSettings,
+            ApplyGlobalSettings,
+            GetProviderSettings,
+            ApplyProviderSettings,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastMicrophoneCaptureStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut AppBroadcastMicrophoneCaptureState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastMicrophoneCaptureStateChangedEventArgs", "State", 6usize, |this| ((**(this as *const *const IAppBroadcastMicrophoneCaptureStateChangedEventArgs_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastMicrophoneCaptureStateChangedEventArgs", "ErrorCode", 7usize, |this| ((**(this as *const *const IAppBroadcastMicrophoneCaptureStateChangedEventArgs_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, State, ErrorCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastMicrophoneCaptureStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IAppBroadcastPlugIn_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPlugIn", "AppId", 6usize, |this| ((**(this as *const *const IAppBroadcastPlugIn_Vtbl)).AppId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPlugIn", "ProviderSettings", 7usize, |this| ((**(this as *const *const IAppBroadcastPlugIn_Vtbl)).ProviderSettings)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Logo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPlugIn", "Logo", 8usize, |this| ((**(this as *const *const IAppBroadcastPlugIn_Vtbl)).Logo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPlugIn", "DisplayName", 9usize, |this| ((**(this as *const *const IAppBroadcastPlugIn_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppId,
+            ProviderSettings,
+            #[cfg(feature = "Storage_Streams")]
+            Logo,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Logo: 0,
+            DisplayName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastPlugIn as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppBroadcastPlugInManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsBroadcastProviderAvailable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPlugInManager", "IsBroadcastProviderAvailable", 6usize, |this| ((**(this as *const *const IAppBroadcastPlugInManager_Vtbl)).IsBroadcastProviderAvailable)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PlugInList(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPlugInManager", "PlugInList", 7usize, |this| ((**(this as *const *const IAppBroadcastPlugInManager_Vtbl)).PlugInList)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultPlugIn(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPlugInManager", "DefaultPlugIn", 8usize, |this| ((**(this as *const *const IAppBroadcastPlugInManager_Vtbl)).DefaultPlugIn)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultPlugIn(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPlugInManager", "SetDefaultPlugIn", 9usize, |this| ((**(this as *const *const IAppBroadcastPlugInManager_Vtbl)).SetDefaultPlugIn)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsBroadcastProviderAvailable,
+            #[cfg(feature = "Foundation_Collections")]
+            PlugInList,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PlugInList: 0,
+            DefaultPlugIn,
+            SetDefaultPlugIn,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastPlugInManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IAppBroadcastPlugInManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPlugInManagerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IAppBroadcastPlugInManagerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPlugInManagerStatics", "GetForUser", 7usize, |this| ((**(this as *const *const IAppBroadcastPlugInManagerStatics_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDefault,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastPlugInManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastPlugInStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PlugInState(this: *mut core::ffi::c_void, result__: *mut AppBroadcastPlugInState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPlugInStateChangedEventArgs", "PlugInState", 6usize, |this| ((**(this as *const *const IAppBroadcastPlugInStateChangedEventArgs_Vtbl)).PlugInState)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PlugInState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastPlugInStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastPreview_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StopPreview(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreview", "StopPreview", 6usize, |this| ((**(this as *const *const IAppBroadcastPreview_Vtbl)).StopPreview)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreviewState(this: *mut core::ffi::c_void, result__: *mut AppBroadcastPreviewState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreview", "PreviewState", 7usize, |this| ((**(this as *const *const IAppBroadcastPreview_Vtbl)).PreviewState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreview", "ErrorCode", 8usize, |this| ((**(this as *const *const IAppBroadcastPreview_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreviewStateChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreview", "PreviewStateChanged", 9usize, |this| ((**(this as *const *const IAppBroadcastPreview_Vtbl)).PreviewStateChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePreviewStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreview", "RemovePreviewStateChanged", 10usize, |this| ((**(this as *const *const IAppBroadcastPreview_Vtbl)).RemovePreviewStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreviewStreamReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreview", "PreviewStreamReader", 11usize, |this| ((**(this as *const *const IAppBroadcastPreview_Vtbl)).PreviewStreamReader)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StopPreview,
+            PreviewState,
+            ErrorCode,
+            PreviewStateChanged,
+            RemovePreviewStateChanged,
+            PreviewStreamReader,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastPreview as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastPreviewStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PreviewState(this: *mut core::ffi::c_void, result__: *mut AppBroadcastPreviewState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStateChangedEventArgs", "PreviewState", 6usize, |this| ((**(this as *const *const IAppBroadcastPreviewStateChangedEventArgs_Vtbl)).PreviewState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStateChangedEventArgs", "ErrorCode", 7usize, |this| ((**(this as *const *const IAppBroadcastPreviewStateChangedEventArgs_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PreviewState, ErrorCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastPreviewStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for IAppBroadcastPreviewStreamReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VideoWidth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStreamReader", "VideoWidth", 6usize, |this| ((**(this as *const *const IAppBroadcastPreviewStreamReader_Vtbl)).VideoWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoHeight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStreamReader", "VideoHeight", 7usize, |this| ((**(this as *const *const IAppBroadcastPreviewStreamReader_Vtbl)).VideoHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoStride(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStreamReader", "VideoStride", 8usize, |this| ((**(this as *const *const IAppBroadcastPreviewStreamReader_Vtbl)).VideoStride)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn VideoBitmapPixelFormat(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Imaging::BitmapPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStreamReader", "VideoBitmapPixelFormat", 9usize, |this| ((**(this as *const *const IAppBroadcastPreviewStreamReader_Vtbl)).VideoBitmapPixelFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn VideoBitmapAlphaMode(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Imaging::BitmapAlphaMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStreamReader", "VideoBitmapAlphaMode", 10usize, |this| ((**(this as *const *const IAppBroadcastPreviewStreamReader_Vtbl)).VideoBitmapAlphaMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetNextVideoFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStreamReader", "TryGetNextVideoFrame", 11usize, |this| ((**(this as *const *const IAppBroadcastPreviewStreamReader_Vtbl)).TryGetNextVideoFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFrameArrived(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStreamReader", "VideoFrameArrived", 12usize, |this| ((**(this as *const *const IAppBroadcastPreviewStreamReader_Vtbl)).VideoFrameArrived)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVideoFrameArrived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStreamReader", "RemoveVideoFrameArrived", 13usize, |this| ((**(this as *const *const IAppBroadcastPreviewStreamReader_Vtbl)).RemoveVideoFrameArrived)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            VideoWidth,
+            VideoHeight,
+            VideoStride,
+            #[cfg(feature = "Graphics_Imaging")]
+            VideoBitmapPixelFormat,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            VideoBitmapPixelFormat: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            VideoBitmapAlphaMode,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            VideoBitmapAlphaMode: 0,
+            TryGetNextVideoFrame,
+            VideoFrameArrived,
+            RemoveVideoFrameArrived,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastPreviewStreamReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IAppBroadcastPreviewStreamVideoFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VideoHeader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStreamVideoFrame", "VideoHeader", 6usize, |this| ((**(this as *const *const IAppBroadcastPreviewStreamVideoFrame_Vtbl)).VideoHeader)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn VideoBuffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStreamVideoFrame", "VideoBuffer", 7usize, |this| ((**(this as *const *const IAppBroadcastPreviewStreamVideoFrame_Vtbl)).VideoBuffer)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            VideoHeader,
+            #[cfg(feature = "Storage_Streams")]
+            VideoBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            VideoBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastPreviewStreamVideoFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastPreviewStreamVideoHeader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AbsoluteTimestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStreamVideoHeader", "AbsoluteTimestamp", 6usize, |this| ((**(this as *const *const IAppBroadcastPreviewStreamVideoHeader_Vtbl)).AbsoluteTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RelativeTimestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStreamVideoHeader", "RelativeTimestamp", 7usize, |this| ((**(this as *const *const IAppBroadcastPreviewStreamVideoHeader_Vtbl)).RelativeTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStreamVideoHeader", "Duration", 8usize, |this| ((**(this as *const *const IAppBroadcastPreviewStreamVideoHeader_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameId(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastPreviewStreamVideoHeader", "FrameId", 9usize, |this| ((**(this as *const *const IAppBroadcastPreviewStreamVideoHeader_Vtbl)).FrameId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AbsoluteTimestamp, RelativeTimestamp, Duration, FrameId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastPreviewStreamVideoHeader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastProviderSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDefaultBroadcastTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastProviderSettings", "SetDefaultBroadcastTitle", 6usize, |this| ((**(this as *const *const IAppBroadcastProviderSettings_Vtbl)).SetDefaultBroadcastTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultBroadcastTitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastProviderSettings", "DefaultBroadcastTitle", 7usize, |this| ((**(this as *const *const IAppBroadcastProviderSettings_Vtbl)).DefaultBroadcastTitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioEncodingBitrate(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastProviderSettings", "SetAudioEncodingBitrate", 8usize, |this| ((**(this as *const *const IAppBroadcastProviderSettings_Vtbl)).SetAudioEncodingBitrate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioEncodingBitrate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastProviderSettings", "AudioEncodingBitrate", 9usize, |this| ((**(this as *const *const IAppBroadcastProviderSettings_Vtbl)).AudioEncodingBitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomVideoEncodingBitrate(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastProviderSettings", "SetCustomVideoEncodingBitrate", 10usize, |this| ((**(this as *const *const IAppBroadcastProviderSettings_Vtbl)).SetCustomVideoEncodingBitrate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomVideoEncodingBitrate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastProviderSettings", "CustomVideoEncodingBitrate", 11usize, |this| ((**(this as *const *const IAppBroadcastProviderSettings_Vtbl)).CustomVideoEncodingBitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomVideoEncodingHeight(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastProviderSettings", "SetCustomVideoEncodingHeight", 12usize, |this| ((**(this as *const *const IAppBroadcastProviderSettings_Vtbl)).SetCustomVideoEncodingHeight)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomVideoEncodingHeight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastProviderSettings", "CustomVideoEncodingHeight", 13usize, |this| ((**(this as *const *const IAppBroadcastProviderSettings_Vtbl)).CustomVideoEncodingHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomVideoEncodingWidth(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastProviderSettings", "SetCustomVideoEncodingWidth", 14usize, |this| ((**(this as *const *const IAppBroadcastProviderSettings_Vtbl)).SetCustomVideoEncodingWidth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomVideoEncodingWidth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastProviderSettings", "CustomVideoEncodingWidth", 15usize, |this| ((**(this as *const *const IAppBroadcastProviderSettings_Vtbl)).CustomVideoEncodingWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoEncodingBitrateMode(this: *mut core::ffi::c_void, value: AppBroadcastVideoEncodingBitrateMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastProviderSettings", "SetVideoEncodingBitrateMode", 16usize, |this| ((**(this as *const *const IAppBroadcastProviderSettings_Vtbl)).SetVideoEncodingBitrateMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoEncodingBitrateMode(this: *mut core::ffi::c_void, result__: *mut AppBroadcastVideoEncodingBitrateMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastProviderSettings", "VideoEncodingBitrateMode", 17usize, |this| ((**(this as *const *const IAppBroadcastProviderSettings_Vtbl)).VideoEncodingBitrateMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoEncodingResolutionMode(this: *mut core::ffi::c_void, value: AppBroadcastVideoEncodingResolutionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastProviderSettings", "SetVideoEncodingResolutionMode", 18usize, |this| ((**(this as *const *const IAppBroadcastProviderSettings_Vtbl)).SetVideoEncodingResolutionMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoEncodingResolutionMode(this: *mut core::ffi::c_void, result__: *mut AppBroadcastVideoEncodingResolutionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastProviderSettings", "VideoEncodingResolutionMode", 19usize, |this| ((**(this as *const *const IAppBroadcastProviderSettings_Vtbl)).VideoEncodingResolutionMode)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDefaultBroadcastTitle,
+            DefaultBroadcastTitle,
+            SetAudioEncodingBitrate,
+            AudioEncodingBitrate,
+            SetCustomVideoEncodingBitrate,
+            CustomVideoEncodingBitrate,
+            SetCustomVideoEncodingHeight,
+            CustomVideoEncodingHeight,
+            SetCustomVideoEncodingWidth,
+            CustomVideoEncodingWidth,
+            SetVideoEncodingBitrateMode,
+            VideoEncodingBitrateMode,
+            SetVideoEncodingResolutionMode,
+            VideoEncodingResolutionMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastProviderSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CaptureTargetType(this: *mut core::ffi::c_void, result__: *mut AppBroadcastCaptureTargetType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastServices", "CaptureTargetType", 6usize, |this| ((**(this as *const *const IAppBroadcastServices_Vtbl)).CaptureTargetType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCaptureTargetType(this: *mut core::ffi::c_void, value: AppBroadcastCaptureTargetType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastServices", "SetCaptureTargetType", 7usize, |this| ((**(this as *const *const IAppBroadcastServices_Vtbl)).SetCaptureTargetType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BroadcastTitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastServices", "BroadcastTitle", 8usize, |this| ((**(this as *const *const IAppBroadcastServices_Vtbl)).BroadcastTitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBroadcastTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastServices", "SetBroadcastTitle", 9usize, |this| ((**(this as *const *const IAppBroadcastServices_Vtbl)).SetBroadcastTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BroadcastLanguage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastServices", "BroadcastLanguage", 10usize, |this| ((**(this as *const *const IAppBroadcastServices_Vtbl)).BroadcastLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBroadcastLanguage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastServices", "SetBroadcastLanguage", 11usize, |this| ((**(this as *const *const IAppBroadcastServices_Vtbl)).SetBroadcastLanguage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastServices", "UserName", 12usize, |this| ((**(this as *const *const IAppBroadcastServices_Vtbl)).UserName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanCapture(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastServices", "CanCapture", 13usize, |this| ((**(this as *const *const IAppBroadcastServices_Vtbl)).CanCapture)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnterBroadcastModeAsync(this: *mut core::ffi::c_void, plugin: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastServices", "EnterBroadcastModeAsync", 14usize, |this| ((**(this as *const *const IAppBroadcastServices_Vtbl)).EnterBroadcastModeAsync)(this, plugin, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExitBroadcastMode(this: *mut core::ffi::c_void, reason: AppBroadcastExitBroadcastModeReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastServices", "ExitBroadcastMode", 15usize, |this| ((**(this as *const *const IAppBroadcastServices_Vtbl)).ExitBroadcastMode)(this, reason), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartBroadcast(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastServices", "StartBroadcast", 16usize, |this| ((**(this as *const *const IAppBroadcastServices_Vtbl)).StartBroadcast)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PauseBroadcast(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastServices", "PauseBroadcast", 17usize, |this| ((**(this as *const *const IAppBroadcastServices_Vtbl)).PauseBroadcast)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeBroadcast(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastServices", "ResumeBroadcast", 18usize, |this| ((**(this as *const *const IAppBroadcastServices_Vtbl)).ResumeBroadcast)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartPreview(this: *mut core::ffi::c_void, desiredsize: super::super::Foundation::Size, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastServices", "StartPreview", 19usize, |this| ((**(this as *const *const IAppBroadcastServices_Vtbl)).StartPreview)(this, desiredsize, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastServices", "State", 20usize, |this| ((**(this as *const *const IAppBroadcastServices_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CaptureTargetType,
+            SetCaptureTargetType,
+            BroadcastTitle,
+            SetBroadcastTitle,
+            BroadcastLanguage,
+            SetBroadcastLanguage,
+            UserName,
+            CanCapture,
+            EnterBroadcastModeAsync,
+            ExitBroadcastMode,
+            StartBroadcast,
+            PauseBroadcast,
+            ResumeBroadcast,
+            StartPreview,
+            State,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastServices as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastSignInStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SignInState(this: *mut core::ffi::c_void, result__: *mut AppBroadcastSignInState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastSignInStateChangedEventArgs", "SignInState", 6usize, |this| ((**(this as *const *const IAppBroadcastSignInStateChangedEventArgs_Vtbl)).SignInState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Result(this: *mut core::ffi::c_void, result__: *mut AppBroadcastSignInResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastSignInStateChangedEventArgs", "Result", 7usize, |this| ((**(this as *const *const IAppBroadcastSignInStateChangedEventArgs_Vtbl)).Result)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SignInState, Result }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastSignInStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Authentication_Web")]
+impl windows_core::imp::ProxyVtable for IAppBroadcastState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsCaptureTargetRunning(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "IsCaptureTargetRunning", 6usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).IsCaptureTargetRunning)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ViewerCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "ViewerCount", 7usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).ViewerCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShouldCaptureMicrophone(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "ShouldCaptureMicrophone", 8usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).ShouldCaptureMicrophone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShouldCaptureMicrophone(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "SetShouldCaptureMicrophone", 9usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).SetShouldCaptureMicrophone)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestartMicrophoneCapture(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "RestartMicrophoneCapture", 10usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).RestartMicrophoneCapture)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShouldCaptureCamera(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "ShouldCaptureCamera", 11usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).ShouldCaptureCamera)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShouldCaptureCamera(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "SetShouldCaptureCamera", 12usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).SetShouldCaptureCamera)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestartCameraCapture(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "RestartCameraCapture", 13usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).RestartCameraCapture)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncodedVideoSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "EncodedVideoSize", 14usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).EncodedVideoSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MicrophoneCaptureState(this: *mut core::ffi::c_void, result__: *mut AppBroadcastMicrophoneCaptureState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "MicrophoneCaptureState", 15usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).MicrophoneCaptureState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MicrophoneCaptureError(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "MicrophoneCaptureError", 16usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).MicrophoneCaptureError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CameraCaptureState(this: *mut core::ffi::c_void, result__: *mut AppBroadcastCameraCaptureState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "CameraCaptureState", 17usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).CameraCaptureState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CameraCaptureError(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "CameraCaptureError", 18usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).CameraCaptureError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StreamState(this: *mut core::ffi::c_void, result__: *mut AppBroadcastStreamState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "StreamState", 19usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).StreamState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlugInState(this: *mut core::ffi::c_void, result__: *mut AppBroadcastPlugInState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "PlugInState", 20usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).PlugInState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OAuthRequestUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "OAuthRequestUri", 21usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).OAuthRequestUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OAuthCallbackUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "OAuthCallbackUri", 22usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).OAuthCallbackUri)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Authentication_Web")]
+        unsafe extern "system" fn AuthenticationResult(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "AuthenticationResult", 23usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).AuthenticationResult)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Authentication_Web")]
+        unsafe extern "system" fn SetAuthenticationResult(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "SetAuthenticationResult", 24usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).SetAuthenticationResult)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignInState(this: *mut core::ffi::c_void, value: AppBroadcastSignInState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "SetSignInState", 25usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).SetSignInState)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignInState(this: *mut core::ffi::c_void, result__: *mut AppBroadcastSignInState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "SignInState", 26usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).SignInState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminationReason(this: *mut core::ffi::c_void, result__: *mut AppBroadcastTerminationReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "TerminationReason", 27usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).TerminationReason)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminationReasonPlugInSpecific(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "TerminationReasonPlugInSpecific", 28usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).TerminationReasonPlugInSpecific)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ViewerCountChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "ViewerCountChanged", 29usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).ViewerCountChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveViewerCountChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "RemoveViewerCountChanged", 30usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).RemoveViewerCountChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn MicrophoneCaptureStateChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "MicrophoneCaptureStateChanged", 31usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).MicrophoneCaptureStateChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMicrophoneCaptureStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "RemoveMicrophoneCaptureStateChanged", 32usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).RemoveMicrophoneCaptureStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CameraCaptureStateChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "CameraCaptureStateChanged", 33usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).CameraCaptureStateChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCameraCaptureStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "RemoveCameraCaptureStateChanged", 34usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).RemoveCameraCaptureStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlugInStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "PlugInStateChanged", 35usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).PlugInStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePlugInStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "RemovePlugInStateChanged", 36usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).RemovePlugInStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn StreamStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "StreamStateChanged", 37usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).StreamStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStreamStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "RemoveStreamStateChanged", 38usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).RemoveStreamStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CaptureTargetClosed(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "CaptureTargetClosed", 39usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).CaptureTargetClosed)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCaptureTargetClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastState", "RemoveCaptureTargetClosed", 40usize, |this| ((**(this as *const *const IAppBroadcastState_Vtbl)).RemoveCaptureTargetClosed)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsCaptureTargetRunning,
+            ViewerCount,
+            ShouldCaptureMicrophone,
+            SetShouldCaptureMicrophone,
+            RestartMicrophoneCapture,
+            ShouldCaptureCamera,
+            SetShouldCaptureCamera,
+            RestartCameraCapture,
+            EncodedVideoSize,
+            MicrophoneCaptureState,
+            MicrophoneCaptureError,
+            CameraCaptureState,
+            CameraCaptureError,
+            StreamState,
+            PlugInState,
+            OAuthRequestUri,
+            OAuthCallbackUri,
+            #[cfg(feature = "Security_Authentication_Web")]
+            AuthenticationResult,
+            #[cfg(not(feature = "Security_Authentication_Web"))]
+            AuthenticationResult: 0,
+            #[cfg(feature = "Security_Authentication_Web")]
+            SetAuthenticationResult,
+            #[cfg(not(feature = "Security_Authentication_Web"))]
+            SetAuthenticationResult: 0,
+            SetSignInState,
+            SignInState,
+            TerminationReason,
+            TerminationReasonPlugInSpecific,
+            ViewerCountChanged,
+            RemoveViewerCountChanged,
+            MicrophoneCaptureStateChanged,
+            RemoveMicrophoneCaptureStateChanged,
+            CameraCaptureStateChanged,
+            RemoveCameraCaptureStateChanged,
+            PlugInStateChanged,
+            RemovePlugInStateChanged,
+            StreamStateChanged,
+            RemoveStreamStateChanged,
+            CaptureTargetClosed,
+            RemoveCaptureTargetClosed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastState as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IAppBroadcastStreamAudioFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AudioHeader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamAudioFrame", "AudioHeader", 6usize, |this| ((**(this as *const *const IAppBroadcastStreamAudioFrame_Vtbl)).AudioHeader)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AudioBuffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamAudioFrame", "AudioBuffer", 7usize, |this| ((**(this as *const *const IAppBroadcastStreamAudioFrame_Vtbl)).AudioBuffer)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AudioHeader,
+            #[cfg(feature = "Storage_Streams")]
+            AudioBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AudioBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastStreamAudioFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastStreamAudioHeader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AbsoluteTimestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamAudioHeader", "AbsoluteTimestamp", 6usize, |this| ((**(this as *const *const IAppBroadcastStreamAudioHeader_Vtbl)).AbsoluteTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RelativeTimestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamAudioHeader", "RelativeTimestamp", 7usize, |this| ((**(this as *const *const IAppBroadcastStreamAudioHeader_Vtbl)).RelativeTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamAudioHeader", "Duration", 8usize, |this| ((**(this as *const *const IAppBroadcastStreamAudioHeader_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasDiscontinuity(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamAudioHeader", "HasDiscontinuity", 9usize, |this| ((**(this as *const *const IAppBroadcastStreamAudioHeader_Vtbl)).HasDiscontinuity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameId(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamAudioHeader", "FrameId", 10usize, |this| ((**(this as *const *const IAppBroadcastStreamAudioHeader_Vtbl)).FrameId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AbsoluteTimestamp,
+            RelativeTimestamp,
+            Duration,
+            HasDiscontinuity,
+            FrameId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastStreamAudioHeader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IAppBroadcastStreamReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AudioChannels(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamReader", "AudioChannels", 6usize, |this| ((**(this as *const *const IAppBroadcastStreamReader_Vtbl)).AudioChannels)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioSampleRate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamReader", "AudioSampleRate", 7usize, |this| ((**(this as *const *const IAppBroadcastStreamReader_Vtbl)).AudioSampleRate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AudioAacSequence(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamReader", "AudioAacSequence", 8usize, |this| ((**(this as *const *const IAppBroadcastStreamReader_Vtbl)).AudioAacSequence)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioBitrate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamReader", "AudioBitrate", 9usize, |this| ((**(this as *const *const IAppBroadcastStreamReader_Vtbl)).AudioBitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetNextAudioFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamReader", "TryGetNextAudioFrame", 10usize, |this| ((**(this as *const *const IAppBroadcastStreamReader_Vtbl)).TryGetNextAudioFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoWidth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamReader", "VideoWidth", 11usize, |this| ((**(this as *const *const IAppBroadcastStreamReader_Vtbl)).VideoWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoHeight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamReader", "VideoHeight", 12usize, |this| ((**(this as *const *const IAppBroadcastStreamReader_Vtbl)).VideoHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoBitrate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamReader", "VideoBitrate", 13usize, |this| ((**(this as *const *const IAppBroadcastStreamReader_Vtbl)).VideoBitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetNextVideoFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamReader", "TryGetNextVideoFrame", 14usize, |this| ((**(this as *const *const IAppBroadcastStreamReader_Vtbl)).TryGetNextVideoFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFrameArrived(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamReader", "AudioFrameArrived", 15usize, |this| ((**(this as *const *const IAppBroadcastStreamReader_Vtbl)).AudioFrameArrived)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAudioFrameArrived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamReader", "RemoveAudioFrameArrived", 16usize, |this| ((**(this as *const *const IAppBroadcastStreamReader_Vtbl)).RemoveAudioFrameArrived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFrameArrived(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamReader", "VideoFrameArrived", 17usize, |this| ((**(this as *const *const IAppBroadcastStreamReader_Vtbl)).VideoFrameArrived)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVideoFrameArrived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamReader", "RemoveVideoFrameArrived", 18usize, |this| ((**(this as *const *const IAppBroadcastStreamReader_Vtbl)).RemoveVideoFrameArrived)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AudioChannels,
+            AudioSampleRate,
+            #[cfg(feature = "Storage_Streams")]
+            AudioAacSequence,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AudioAacSequence: 0,
+            AudioBitrate,
+            TryGetNextAudioFrame,
+            VideoWidth,
+            VideoHeight,
+            VideoBitrate,
+            TryGetNextVideoFrame,
+            AudioFrameArrived,
+            RemoveAudioFrameArrived,
+            VideoFrameArrived,
+            RemoveVideoFrameArrived,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastStreamReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastStreamStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StreamState(this: *mut core::ffi::c_void, result__: *mut AppBroadcastStreamState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamStateChangedEventArgs", "StreamState", 6usize, |this| ((**(this as *const *const IAppBroadcastStreamStateChangedEventArgs_Vtbl)).StreamState)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StreamState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastStreamStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IAppBroadcastStreamVideoFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VideoHeader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamVideoFrame", "VideoHeader", 6usize, |this| ((**(this as *const *const IAppBroadcastStreamVideoFrame_Vtbl)).VideoHeader)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn VideoBuffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamVideoFrame", "VideoBuffer", 7usize, |this| ((**(this as *const *const IAppBroadcastStreamVideoFrame_Vtbl)).VideoBuffer)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            VideoHeader,
+            #[cfg(feature = "Storage_Streams")]
+            VideoBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            VideoBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastStreamVideoFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastStreamVideoHeader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AbsoluteTimestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamVideoHeader", "AbsoluteTimestamp", 6usize, |this| ((**(this as *const *const IAppBroadcastStreamVideoHeader_Vtbl)).AbsoluteTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RelativeTimestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamVideoHeader", "RelativeTimestamp", 7usize, |this| ((**(this as *const *const IAppBroadcastStreamVideoHeader_Vtbl)).RelativeTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamVideoHeader", "Duration", 8usize, |this| ((**(this as *const *const IAppBroadcastStreamVideoHeader_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsKeyFrame(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamVideoHeader", "IsKeyFrame", 9usize, |this| ((**(this as *const *const IAppBroadcastStreamVideoHeader_Vtbl)).IsKeyFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasDiscontinuity(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamVideoHeader", "HasDiscontinuity", 10usize, |this| ((**(this as *const *const IAppBroadcastStreamVideoHeader_Vtbl)).HasDiscontinuity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameId(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastStreamVideoHeader", "FrameId", 11usize, |this| ((**(this as *const *const IAppBroadcastStreamVideoHeader_Vtbl)).FrameId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AbsoluteTimestamp,
+            RelativeTimestamp,
+            Duration,
+            IsKeyFrame,
+            HasDiscontinuity,
+            FrameId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastStreamVideoHeader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BackgroundService(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastTriggerDetails", "BackgroundService", 6usize, |this| ((**(this as *const *const IAppBroadcastTriggerDetails_Vtbl)).BackgroundService)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BackgroundService }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppBroadcastViewerCountChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ViewerCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppBroadcastViewerCountChangedEventArgs", "ViewerCount", 6usize, |this| ((**(this as *const *const IAppBroadcastViewerCountChangedEventArgs_Vtbl)).ViewerCount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ViewerCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppBroadcastViewerCountChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCapture_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsCapturingAudio(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapture", "IsCapturingAudio", 6usize, |this| ((**(this as *const *const IAppCapture_Vtbl)).IsCapturingAudio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCapturingVideo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapture", "IsCapturingVideo", 7usize, |this| ((**(this as *const *const IAppCapture_Vtbl)).IsCapturingVideo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CapturingChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapture", "CapturingChanged", 8usize, |this| ((**(this as *const *const IAppCapture_Vtbl)).CapturingChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCapturingChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapture", "RemoveCapturingChanged", 9usize, |this| ((**(this as *const *const IAppCapture_Vtbl)).RemoveCapturingChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsCapturingAudio,
+            IsCapturingVideo,
+            CapturingChanged,
+            RemoveCapturingChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCapture as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IAppCaptureAlternateShortcutKeys_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetToggleGameBarKey(this: *mut core::ffi::c_void, value: super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "SetToggleGameBarKey", 6usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).SetToggleGameBarKey)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ToggleGameBarKey(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "ToggleGameBarKey", 7usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).ToggleGameBarKey)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetToggleGameBarKeyModifiers(this: *mut core::ffi::c_void, value: super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "SetToggleGameBarKeyModifiers", 8usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).SetToggleGameBarKeyModifiers)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ToggleGameBarKeyModifiers(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "ToggleGameBarKeyModifiers", 9usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).ToggleGameBarKeyModifiers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetSaveHistoricalVideoKey(this: *mut core::ffi::c_void, value: super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "SetSaveHistoricalVideoKey", 10usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).SetSaveHistoricalVideoKey)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SaveHistoricalVideoKey(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "SaveHistoricalVideoKey", 11usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).SaveHistoricalVideoKey)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetSaveHistoricalVideoKeyModifiers(this: *mut core::ffi::c_void, value: super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "SetSaveHistoricalVideoKeyModifiers", 12usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).SetSaveHistoricalVideoKeyModifiers)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SaveHistoricalVideoKeyModifiers(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "SaveHistoricalVideoKeyModifiers", 13usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).SaveHistoricalVideoKeyModifiers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetToggleRecordingKey(this: *mut core::ffi::c_void, value: super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "SetToggleRecordingKey", 14usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).SetToggleRecordingKey)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ToggleRecordingKey(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "ToggleRecordingKey", 15usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).ToggleRecordingKey)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetToggleRecordingKeyModifiers(this: *mut core::ffi::c_void, value: super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "SetToggleRecordingKeyModifiers", 16usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).SetToggleRecordingKeyModifiers)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ToggleRecordingKeyModifiers(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "ToggleRecordingKeyModifiers", 17usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).ToggleRecordingKeyModifiers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetTakeScreenshotKey(this: *mut core::ffi::c_void, value: super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "SetTakeScreenshotKey", 18usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).SetTakeScreenshotKey)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn TakeScreenshotKey(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "TakeScreenshotKey", 19usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).TakeScreenshotKey)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetTakeScreenshotKeyModifiers(this: *mut core::ffi::c_void, value: super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "SetTakeScreenshotKeyModifiers", 20usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).SetTakeScreenshotKeyModifiers)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn TakeScreenshotKeyModifiers(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "TakeScreenshotKeyModifiers", 21usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).TakeScreenshotKeyModifiers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetToggleRecordingIndicatorKey(this: *mut core::ffi::c_void, value: super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "SetToggleRecordingIndicatorKey", 22usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).SetToggleRecordingIndicatorKey)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ToggleRecordingIndicatorKey(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "ToggleRecordingIndicatorKey", 23usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).ToggleRecordingIndicatorKey)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetToggleRecordingIndicatorKeyModifiers(this: *mut core::ffi::c_void, value: super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "SetToggleRecordingIndicatorKeyModifiers", 24usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).SetToggleRecordingIndicatorKeyModifiers)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ToggleRecordingIndicatorKeyModifiers(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys", "ToggleRecordingIndicatorKeyModifiers", 25usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys_Vtbl)).ToggleRecordingIndicatorKeyModifiers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            SetToggleGameBarKey,
+            #[cfg(not(feature = "System"))]
+            SetToggleGameBarKey: 0,
+            #[cfg(feature = "System")]
+            ToggleGameBarKey,
+            #[cfg(not(feature = "System"))]
+            ToggleGameBarKey: 0,
+            #[cfg(feature = "System")]
+            SetToggleGameBarKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            SetToggleGameBarKeyModifiers: 0,
+            #[cfg(feature = "System")]
+            ToggleGameBarKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            ToggleGameBarKeyModifiers: 0,
+            #[cfg(feature = "System")]
+            SetSaveHistoricalVideoKey,
+            #[cfg(not(feature = "System"))]
+            SetSaveHistoricalVideoKey: 0,
+            #[cfg(feature = "System")]
+            SaveHistoricalVideoKey,
+            #[cfg(not(feature = "System"))]
+            SaveHistoricalVideoKey: 0,
+            #[cfg(feature = "System")]
+            SetSaveHistoricalVideoKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            SetSaveHistoricalVideoKeyModifiers: 0,
+            #[cfg(feature = "System")]
+            SaveHistoricalVideoKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            SaveHistoricalVideoKeyModifiers: 0,
+            #[cfg(feature = "System")]
+            SetToggleRecordingKey,
+            #[cfg(not(feature = "System"))]
+            SetToggleRecordingKey: 0,
+            #[cfg(feature = "System")]
+            ToggleRecordingKey,
+            #[cfg(not(feature = "System"))]
+            ToggleRecordingKey: 0,
+            #[cfg(feature = "System")]
+            SetToggleRecordingKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            SetToggleRecordingKeyModifiers: 0,
+            #[cfg(feature = "System")]
+            ToggleRecordingKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            ToggleRecordingKeyModifiers: 0,
+            #[cfg(feature = "System")]
+            SetTakeScreenshotKey,
+            #[cfg(not(feature = "System"))]
+            SetTakeScreenshotKey: 0,
+            #[cfg(feature = "System")]
+            TakeScreenshotKey,
+            #[cfg(not(feature = "System"))]
+            TakeScreenshotKey: 0,
+            #[cfg(feature = "System")]
+            SetTakeScreenshotKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            SetTakeScreenshotKeyModifiers: 0,
+            #[cfg(feature = "System")]
+            TakeScreenshotKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            TakeScreenshotKeyModifiers: 0,
+            #[cfg(feature = "System")]
+            SetToggleRecordingIndicatorKey,
+            #[cfg(not(feature = "System"))]
+            SetToggleRecordingIndicatorKey: 0,
+            #[cfg(feature = "System")]
+            ToggleRecordingIndicatorKey,
+            #[cfg(not(feature = "System"))]
+            ToggleRecordingIndicatorKey: 0,
+            #[cfg(feature = "System")]
+            SetToggleRecordingIndicatorKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            SetToggleRecordingIndicatorKeyModifiers: 0,
+            #[cfg(feature = "System")]
+            ToggleRecordingIndicatorKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            ToggleRecordingIndicatorKeyModifiers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureAlternateShortcutKeys as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IAppCaptureAlternateShortcutKeys2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetToggleMicrophoneCaptureKey(this: *mut core::ffi::c_void, value: super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys2", "SetToggleMicrophoneCaptureKey", 6usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys2_Vtbl)).SetToggleMicrophoneCaptureKey)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ToggleMicrophoneCaptureKey(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys2", "ToggleMicrophoneCaptureKey", 7usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys2_Vtbl)).ToggleMicrophoneCaptureKey)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetToggleMicrophoneCaptureKeyModifiers(this: *mut core::ffi::c_void, value: super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys2", "SetToggleMicrophoneCaptureKeyModifiers", 8usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys2_Vtbl)).SetToggleMicrophoneCaptureKeyModifiers)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ToggleMicrophoneCaptureKeyModifiers(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys2", "ToggleMicrophoneCaptureKeyModifiers", 9usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys2_Vtbl)).ToggleMicrophoneCaptureKeyModifiers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            SetToggleMicrophoneCaptureKey,
+            #[cfg(not(feature = "System"))]
+            SetToggleMicrophoneCaptureKey: 0,
+            #[cfg(feature = "System")]
+            ToggleMicrophoneCaptureKey,
+            #[cfg(not(feature = "System"))]
+            ToggleMicrophoneCaptureKey: 0,
+            #[cfg(feature = "System")]
+            SetToggleMicrophoneCaptureKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            SetToggleMicrophoneCaptureKeyModifiers: 0,
+            #[cfg(feature = "System")]
+            ToggleMicrophoneCaptureKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            ToggleMicrophoneCaptureKeyModifiers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureAlternateShortcutKeys2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IAppCaptureAlternateShortcutKeys3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetToggleCameraCaptureKey(this: *mut core::ffi::c_void, value: super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys3", "SetToggleCameraCaptureKey", 6usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys3_Vtbl)).SetToggleCameraCaptureKey)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ToggleCameraCaptureKey(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys3", "ToggleCameraCaptureKey", 7usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys3_Vtbl)).ToggleCameraCaptureKey)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetToggleCameraCaptureKeyModifiers(this: *mut core::ffi::c_void, value: super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys3", "SetToggleCameraCaptureKeyModifiers", 8usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys3_Vtbl)).SetToggleCameraCaptureKeyModifiers)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ToggleCameraCaptureKeyModifiers(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys3", "ToggleCameraCaptureKeyModifiers", 9usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys3_Vtbl)).ToggleCameraCaptureKeyModifiers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetToggleBroadcastKey(this: *mut core::ffi::c_void, value: super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys3", "SetToggleBroadcastKey", 10usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys3_Vtbl)).SetToggleBroadcastKey)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ToggleBroadcastKey(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys3", "ToggleBroadcastKey", 11usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys3_Vtbl)).ToggleBroadcastKey)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn SetToggleBroadcastKeyModifiers(this: *mut core::ffi::c_void, value: super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys3", "SetToggleBroadcastKeyModifiers", 12usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys3_Vtbl)).SetToggleBroadcastKeyModifiers)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ToggleBroadcastKeyModifiers(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureAlternateShortcutKeys3", "ToggleBroadcastKeyModifiers", 13usize, |this| ((**(this as *const *const IAppCaptureAlternateShortcutKeys3_Vtbl)).ToggleBroadcastKeyModifiers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            SetToggleCameraCaptureKey,
+            #[cfg(not(feature = "System"))]
+            SetToggleCameraCaptureKey: 0,
+            #[cfg(feature = "System")]
+            ToggleCameraCaptureKey,
+            #[cfg(not(feature = "System"))]
+            ToggleCameraCaptureKey: 0,
+            #[cfg(feature = "System")]
+            SetToggleCameraCaptureKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            SetToggleCameraCaptureKeyModifiers: 0,
+            #[cfg(feature = "System")]
+            ToggleCameraCaptureKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            ToggleCameraCaptureKeyModifiers: 0,
+            #[cfg(feature = "System")]
+            SetToggleBroadcastKey,
+            #[cfg(not(feature = "System"))]
+            SetToggleBroadcastKey: 0,
+            #[cfg(feature = "System")]
+            ToggleBroadcastKey,
+            #[cfg(not(feature = "System"))]
+            ToggleBroadcastKey: 0,
+            #[cfg(feature = "System")]
+            SetToggleBroadcastKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            SetToggleBroadcastKeyModifiers: 0,
+            #[cfg(feature = "System")]
+            ToggleBroadcastKeyModifiers,
+            #[cfg(not(feature = "System"))]
+            ToggleBroadcastKeyModifiers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureAlternateShortcutKeys3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCaptureDurationGeneratedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureDurationGeneratedEventArgs", "Duration", 6usize, |this| ((**(this as *const *const IAppCaptureDurationGeneratedEventArgs_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Duration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureDurationGeneratedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IAppCaptureFileGeneratedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn File(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureFileGeneratedEventArgs", "File", 6usize, |this| ((**(this as *const *const IAppCaptureFileGeneratedEventArgs_Vtbl)).File)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            File,
+            #[cfg(not(feature = "Storage"))]
+            File: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureFileGeneratedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCaptureManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureManagerStatics", "GetCurrentSettings", 6usize, |this| ((**(this as *const *const IAppCaptureManagerStatics_Vtbl)).GetCurrentSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplySettings(this: *mut core::ffi::c_void, appcapturesettings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureManagerStatics", "ApplySettings", 7usize, |this| ((**(this as *const *const IAppCaptureManagerStatics_Vtbl)).ApplySettings)(this, appcapturesettings), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrentSettings, ApplySettings }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCaptureMetadataWriter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddStringEvent(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>, priority: AppCaptureMetadataPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureMetadataWriter", "AddStringEvent", 6usize, |this| ((**(this as *const *const IAppCaptureMetadataWriter_Vtbl)).AddStringEvent)(this, name, value, priority), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInt32Event(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: i32, priority: AppCaptureMetadataPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureMetadataWriter", "AddInt32Event", 7usize, |this| ((**(this as *const *const IAppCaptureMetadataWriter_Vtbl)).AddInt32Event)(this, name, value, priority), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDoubleEvent(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: f64, priority: AppCaptureMetadataPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureMetadataWriter", "AddDoubleEvent", 8usize, |this| ((**(this as *const *const IAppCaptureMetadataWriter_Vtbl)).AddDoubleEvent)(this, name, value, priority), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartStringState(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>, priority: AppCaptureMetadataPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureMetadataWriter", "StartStringState", 9usize, |this| ((**(this as *const *const IAppCaptureMetadataWriter_Vtbl)).StartStringState)(this, name, value, priority), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartInt32State(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: i32, priority: AppCaptureMetadataPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureMetadataWriter", "StartInt32State", 10usize, |this| ((**(this as *const *const IAppCaptureMetadataWriter_Vtbl)).StartInt32State)(this, name, value, priority), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartDoubleState(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: f64, priority: AppCaptureMetadataPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureMetadataWriter", "StartDoubleState", 11usize, |this| ((**(this as *const *const IAppCaptureMetadataWriter_Vtbl)).StartDoubleState)(this, name, value, priority), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopState(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureMetadataWriter", "StopState", 12usize, |this| ((**(this as *const *const IAppCaptureMetadataWriter_Vtbl)).StopState)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAllStates(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureMetadataWriter", "StopAllStates", 13usize, |this| ((**(this as *const *const IAppCaptureMetadataWriter_Vtbl)).StopAllStates)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemainingStorageBytesAvailable(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureMetadataWriter", "RemainingStorageBytesAvailable", 14usize, |this| ((**(this as *const *const IAppCaptureMetadataWriter_Vtbl)).RemainingStorageBytesAvailable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MetadataPurged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureMetadataWriter", "MetadataPurged", 15usize, |this| ((**(this as *const *const IAppCaptureMetadataWriter_Vtbl)).MetadataPurged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMetadataPurged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureMetadataWriter", "RemoveMetadataPurged", 16usize, |this| ((**(this as *const *const IAppCaptureMetadataWriter_Vtbl)).RemoveMetadataPurged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddStringEvent,
+            AddInt32Event,
+            AddDoubleEvent,
+            StartStringState,
+            StartInt32State,
+            StartDoubleState,
+            StopState,
+            StopAllStates,
+            RemainingStorageBytesAvailable,
+            MetadataPurged,
+            RemoveMetadataPurged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureMetadataWriter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCaptureMicrophoneCaptureStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut AppCaptureMicrophoneCaptureState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureMicrophoneCaptureStateChangedEventArgs", "State", 6usize, |this| ((**(this as *const *const IAppCaptureMicrophoneCaptureStateChangedEventArgs_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureMicrophoneCaptureStateChangedEventArgs", "ErrorCode", 7usize, |this| ((**(this as *const *const IAppCaptureMicrophoneCaptureStateChangedEventArgs_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, State, ErrorCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureMicrophoneCaptureStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IAppCaptureRecordOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StopRecording(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureRecordOperation", "StopRecording", 6usize, |this| ((**(this as *const *const IAppCaptureRecordOperation_Vtbl)).StopRecording)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut AppCaptureRecordingState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureRecordOperation", "State", 7usize, |this| ((**(this as *const *const IAppCaptureRecordOperation_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureRecordOperation", "ErrorCode", 8usize, |this| ((**(this as *const *const IAppCaptureRecordOperation_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureRecordOperation", "Duration", 9usize, |this| ((**(this as *const *const IAppCaptureRecordOperation_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn File(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureRecordOperation", "File", 10usize, |this| ((**(this as *const *const IAppCaptureRecordOperation_Vtbl)).File)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFileTruncated(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureRecordOperation", "IsFileTruncated", 11usize, |this| ((**(this as *const *const IAppCaptureRecordOperation_Vtbl)).IsFileTruncated)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureRecordOperation", "StateChanged", 12usize, |this| ((**(this as *const *const IAppCaptureRecordOperation_Vtbl)).StateChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureRecordOperation", "RemoveStateChanged", 13usize, |this| ((**(this as *const *const IAppCaptureRecordOperation_Vtbl)).RemoveStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DurationGenerated(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureRecordOperation", "DurationGenerated", 14usize, |this| ((**(this as *const *const IAppCaptureRecordOperation_Vtbl)).DurationGenerated)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDurationGenerated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureRecordOperation", "RemoveDurationGenerated", 15usize, |this| ((**(this as *const *const IAppCaptureRecordOperation_Vtbl)).RemoveDurationGenerated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileGenerated(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureRecordOperation", "FileGenerated", 16usize, |this| ((**(this as *const *const IAppCaptureRecordOperation_Vtbl)).FileGenerated)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFileGenerated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureRecordOperation", "RemoveFileGenerated", 17usize, |this| ((**(this as *const *const IAppCaptureRecordOperation_Vtbl)).RemoveFileGenerated)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StopRecording,
+            State,
+            ErrorCode,
+            Duration,
+            #[cfg(feature = "Storage")]
+            File,
+            #[cfg(not(feature = "Storage"))]
+            File: 0,
+            IsFileTruncated,
+            StateChanged,
+            RemoveStateChanged,
+            DurationGenerated,
+            RemoveDurationGenerated,
+            FileGenerated,
+            RemoveFileGenerated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureRecordOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCaptureRecordingStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut AppCaptureRecordingState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureRecordingStateChangedEventArgs", "State", 6usize, |this| ((**(this as *const *const IAppCaptureRecordingStateChangedEventArgs_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureRecordingStateChangedEventArgs", "ErrorCode", 7usize, |this| ((**(this as *const *const IAppCaptureRecordingStateChangedEventArgs_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, State, ErrorCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureRecordingStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCaptureServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Record(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureServices", "Record", 6usize, |this| ((**(this as *const *const IAppCaptureServices_Vtbl)).Record)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordTimeSpan(this: *mut core::ffi::c_void, starttime: super::super::Foundation::DateTime, duration: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureServices", "RecordTimeSpan", 7usize, |this| ((**(this as *const *const IAppCaptureServices_Vtbl)).RecordTimeSpan)(this, starttime, duration, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanCapture(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureServices", "CanCapture", 8usize, |this| ((**(this as *const *const IAppCaptureServices_Vtbl)).CanCapture)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureServices", "State", 9usize, |this| ((**(this as *const *const IAppCaptureServices_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Record, RecordTimeSpan, CanCapture, State }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureServices as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IAppCaptureSettings_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SetAppCaptureDestinationFolder(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetAppCaptureDestinationFolder", 6usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetAppCaptureDestinationFolder)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn AppCaptureDestinationFolder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "AppCaptureDestinationFolder", 7usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).AppCaptureDestinationFolder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioEncodingBitrate(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetAudioEncodingBitrate", 8usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetAudioEncodingBitrate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioEncodingBitrate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "AudioEncodingBitrate", 9usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).AudioEncodingBitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsAudioCaptureEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetIsAudioCaptureEnabled", 10usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetIsAudioCaptureEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAudioCaptureEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "IsAudioCaptureEnabled", 11usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).IsAudioCaptureEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomVideoEncodingBitrate(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetCustomVideoEncodingBitrate", 12usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetCustomVideoEncodingBitrate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomVideoEncodingBitrate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "CustomVideoEncodingBitrate", 13usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).CustomVideoEncodingBitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomVideoEncodingHeight(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetCustomVideoEncodingHeight", 14usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetCustomVideoEncodingHeight)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomVideoEncodingHeight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "CustomVideoEncodingHeight", 15usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).CustomVideoEncodingHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomVideoEncodingWidth(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetCustomVideoEncodingWidth", 16usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetCustomVideoEncodingWidth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomVideoEncodingWidth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "CustomVideoEncodingWidth", 17usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).CustomVideoEncodingWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHistoricalBufferLength(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetHistoricalBufferLength", 18usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetHistoricalBufferLength)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HistoricalBufferLength(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "HistoricalBufferLength", 19usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).HistoricalBufferLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHistoricalBufferLengthUnit(this: *mut core::ffi::c_void, value: AppCaptureHistoricalBufferLengthUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetHistoricalBufferLengthUnit", 20usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetHistoricalBufferLengthUnit)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HistoricalBufferLengthUnit(this: *mut core::ffi::c_void, result__: *mut AppCaptureHistoricalBufferLengthUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "HistoricalBufferLengthUnit", 21usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).HistoricalBufferLengthUnit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsHistoricalCaptureEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetIsHistoricalCaptureEnabled", 22usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetIsHistoricalCaptureEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHistoricalCaptureEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "IsHistoricalCaptureEnabled", 23usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).IsHistoricalCaptureEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsHistoricalCaptureOnBatteryAllowed(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetIsHistoricalCaptureOnBatteryAllowed", 24usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetIsHistoricalCaptureOnBatteryAllowed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHistoricalCaptureOnBatteryAllowed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "IsHistoricalCaptureOnBatteryAllowed", 25usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).IsHistoricalCaptureOnBatteryAllowed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsHistoricalCaptureOnWirelessDisplayAllowed(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetIsHistoricalCaptureOnWirelessDisplayAllowed", 26usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetIsHistoricalCaptureOnWirelessDisplayAllowed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHistoricalCaptureOnWirelessDisplayAllowed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "IsHistoricalCaptureOnWirelessDisplayAllowed", 27usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).IsHistoricalCaptureOnWirelessDisplayAllowed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaximumRecordLength(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetMaximumRecordLength", 28usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetMaximumRecordLength)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaximumRecordLength(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "MaximumRecordLength", 29usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).MaximumRecordLength)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SetScreenshotDestinationFolder(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetScreenshotDestinationFolder", 30usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetScreenshotDestinationFolder)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn ScreenshotDestinationFolder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "ScreenshotDestinationFolder", 31usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).ScreenshotDestinationFolder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoEncodingBitrateMode(this: *mut core::ffi::c_void, value: AppCaptureVideoEncodingBitrateMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetVideoEncodingBitrateMode", 32usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetVideoEncodingBitrateMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoEncodingBitrateMode(this: *mut core::ffi::c_void, result__: *mut AppCaptureVideoEncodingBitrateMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "VideoEncodingBitrateMode", 33usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).VideoEncodingBitrateMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoEncodingResolutionMode(this: *mut core::ffi::c_void, value: AppCaptureVideoEncodingResolutionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetVideoEncodingResolutionMode", 34usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetVideoEncodingResolutionMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoEncodingResolutionMode(this: *mut core::ffi::c_void, result__: *mut AppCaptureVideoEncodingResolutionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "VideoEncodingResolutionMode", 35usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).VideoEncodingResolutionMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsAppCaptureEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "SetIsAppCaptureEnabled", 36usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).SetIsAppCaptureEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAppCaptureEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "IsAppCaptureEnabled", 37usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).IsAppCaptureEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCpuConstrained(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "IsCpuConstrained", 38usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).IsCpuConstrained)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDisabledByPolicy(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "IsDisabledByPolicy", 39usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).IsDisabledByPolicy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMemoryConstrained(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "IsMemoryConstrained", 40usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).IsMemoryConstrained)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasHardwareEncoder(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings", "HasHardwareEncoder", 41usize, |this| ((**(this as *const *const IAppCaptureSettings_Vtbl)).HasHardwareEncoder)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            SetAppCaptureDestinationFolder,
+            #[cfg(not(feature = "Storage"))]
+            SetAppCaptureDestinationFolder: 0,
+            #[cfg(feature = "Storage")]
+            AppCaptureDestinationFolder,
+            #[cfg(not(feature = "Storage"))]
+            AppCaptureDestinationFolder: 0,
+            SetAudioEncodingBitrate,
+            AudioEncodingBitrate,
+            SetIsAudioCaptureEnabled,
+            IsAudioCaptureEnabled,
+            SetCustomVideoEncodingBitrate,
+            CustomVideoEncodingBitrate,
+            SetCustomVideoEncodingHeight,
+            CustomVideoEncodingHeight,
+            SetCustomVideoEncodingWidth,
+            CustomVideoEncodingWidth,
+            SetHistoricalBufferLength,
+            HistoricalBufferLength,
+            SetHistoricalBufferLengthUnit,
+            HistoricalBufferLengthUnit,
+            SetIsHistoricalCaptureEnabled,
+            IsHistoricalCaptureEnabled,
+            SetIsHistoricalCaptureOnBatteryAllowed,
+            IsHistoricalCaptureOnBatteryAllowed,
+            SetIsHistoricalCaptureOnWirelessDisplayAllowed,
+            IsHistoricalCaptureOnWirelessDisplayAllowed,
+            SetMaximumRecordLength,
+            MaximumRecordLength,
+            #[cfg(feature = "Storage")]
+            SetScreenshotDestinationFolder,
+            #[cfg(not(feature = "Storage"))]
+            SetScreenshotDestinationFolder: 0,
+            #[cfg(feature = "Storage")]
+            ScreenshotDestinationFolder,
+            #[cfg(not(feature = "Storage"))]
+            ScreenshotDestinationFolder: 0,
+            SetVideoEncodingBitrateMode,
+            VideoEncodingBitrateMode,
+            SetVideoEncodingResolutionMode,
+            VideoEncodingResolutionMode,
+            SetIsAppCaptureEnabled,
+            IsAppCaptureEnabled,
+            IsCpuConstrained,
+            IsDisabledByPolicy,
+            IsMemoryConstrained,
+            HasHardwareEncoder,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCaptureSettings2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsGpuConstrained(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings2", "IsGpuConstrained", 6usize, |this| ((**(this as *const *const IAppCaptureSettings2_Vtbl)).IsGpuConstrained)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlternateShortcutKeys(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings2", "AlternateShortcutKeys", 7usize, |this| ((**(this as *const *const IAppCaptureSettings2_Vtbl)).AlternateShortcutKeys)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsGpuConstrained, AlternateShortcutKeys }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureSettings2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCaptureSettings3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIsMicrophoneCaptureEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings3", "SetIsMicrophoneCaptureEnabled", 6usize, |this| ((**(this as *const *const IAppCaptureSettings3_Vtbl)).SetIsMicrophoneCaptureEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMicrophoneCaptureEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings3", "IsMicrophoneCaptureEnabled", 7usize, |this| ((**(this as *const *const IAppCaptureSettings3_Vtbl)).IsMicrophoneCaptureEnabled)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetIsMicrophoneCaptureEnabled, IsMicrophoneCaptureEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureSettings3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCaptureSettings4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIsMicrophoneCaptureEnabledByDefault(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings4", "SetIsMicrophoneCaptureEnabledByDefault", 6usize, |this| ((**(this as *const *const IAppCaptureSettings4_Vtbl)).SetIsMicrophoneCaptureEnabledByDefault)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMicrophoneCaptureEnabledByDefault(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings4", "IsMicrophoneCaptureEnabledByDefault", 7usize, |this| ((**(this as *const *const IAppCaptureSettings4_Vtbl)).IsMicrophoneCaptureEnabledByDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSystemAudioGain(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings4", "SetSystemAudioGain", 8usize, |this| ((**(this as *const *const IAppCaptureSettings4_Vtbl)).SetSystemAudioGain)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemAudioGain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings4", "SystemAudioGain", 9usize, |this| ((**(this as *const *const IAppCaptureSettings4_Vtbl)).SystemAudioGain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMicrophoneGain(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings4", "SetMicrophoneGain", 10usize, |this| ((**(this as *const *const IAppCaptureSettings4_Vtbl)).SetMicrophoneGain)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MicrophoneGain(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings4", "MicrophoneGain", 11usize, |this| ((**(this as *const *const IAppCaptureSettings4_Vtbl)).MicrophoneGain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoEncodingFrameRateMode(this: *mut core::ffi::c_void, value: AppCaptureVideoEncodingFrameRateMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings4", "SetVideoEncodingFrameRateMode", 12usize, |this| ((**(this as *const *const IAppCaptureSettings4_Vtbl)).SetVideoEncodingFrameRateMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoEncodingFrameRateMode(this: *mut core::ffi::c_void, result__: *mut AppCaptureVideoEncodingFrameRateMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings4", "VideoEncodingFrameRateMode", 13usize, |this| ((**(this as *const *const IAppCaptureSettings4_Vtbl)).VideoEncodingFrameRateMode)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetIsMicrophoneCaptureEnabledByDefault,
+            IsMicrophoneCaptureEnabledByDefault,
+            SetSystemAudioGain,
+            SystemAudioGain,
+            SetMicrophoneGain,
+            MicrophoneGain,
+            SetVideoEncodingFrameRateMode,
+            VideoEncodingFrameRateMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureSettings4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCaptureSettings5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIsEchoCancellationEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings5", "SetIsEchoCancellationEnabled", 6usize, |this| ((**(this as *const *const IAppCaptureSettings5_Vtbl)).SetIsEchoCancellationEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEchoCancellationEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings5", "IsEchoCancellationEnabled", 7usize, |this| ((**(this as *const *const IAppCaptureSettings5_Vtbl)).IsEchoCancellationEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCursorImageCaptureEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings5", "SetIsCursorImageCaptureEnabled", 8usize, |this| ((**(this as *const *const IAppCaptureSettings5_Vtbl)).SetIsCursorImageCaptureEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCursorImageCaptureEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureSettings5", "IsCursorImageCaptureEnabled", 9usize, |this| ((**(this as *const *const IAppCaptureSettings5_Vtbl)).IsCursorImageCaptureEnabled)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetIsEchoCancellationEnabled,
+            IsEchoCancellationEnabled,
+            SetIsCursorImageCaptureEnabled,
+            IsCursorImageCaptureEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureSettings5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCaptureState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTargetRunning(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureState", "IsTargetRunning", 6usize, |this| ((**(this as *const *const IAppCaptureState_Vtbl)).IsTargetRunning)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHistoricalCaptureEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureState", "IsHistoricalCaptureEnabled", 7usize, |this| ((**(this as *const *const IAppCaptureState_Vtbl)).IsHistoricalCaptureEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShouldCaptureMicrophone(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureState", "ShouldCaptureMicrophone", 8usize, |this| ((**(this as *const *const IAppCaptureState_Vtbl)).ShouldCaptureMicrophone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShouldCaptureMicrophone(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureState", "SetShouldCaptureMicrophone", 9usize, |this| ((**(this as *const *const IAppCaptureState_Vtbl)).SetShouldCaptureMicrophone)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestartMicrophoneCapture(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureState", "RestartMicrophoneCapture", 10usize, |this| ((**(this as *const *const IAppCaptureState_Vtbl)).RestartMicrophoneCapture)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn MicrophoneCaptureState(this: *mut core::ffi::c_void, result__: *mut AppCaptureMicrophoneCaptureState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureState", "MicrophoneCaptureState", 11usize, |this| ((**(this as *const *const IAppCaptureState_Vtbl)).MicrophoneCaptureState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MicrophoneCaptureError(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureState", "MicrophoneCaptureError", 12usize, |this| ((**(this as *const *const IAppCaptureState_Vtbl)).MicrophoneCaptureError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MicrophoneCaptureStateChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureState", "MicrophoneCaptureStateChanged", 13usize, |this| ((**(this as *const *const IAppCaptureState_Vtbl)).MicrophoneCaptureStateChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMicrophoneCaptureStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureState", "RemoveMicrophoneCaptureStateChanged", 14usize, |this| ((**(this as *const *const IAppCaptureState_Vtbl)).RemoveMicrophoneCaptureStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CaptureTargetClosed(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureState", "CaptureTargetClosed", 15usize, |this| ((**(this as *const *const IAppCaptureState_Vtbl)).CaptureTargetClosed)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCaptureTargetClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureState", "RemoveCaptureTargetClosed", 16usize, |this| ((**(this as *const *const IAppCaptureState_Vtbl)).RemoveCaptureTargetClosed)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsTargetRunning,
+            IsHistoricalCaptureEnabled,
+            ShouldCaptureMicrophone,
+            SetShouldCaptureMicrophone,
+            RestartMicrophoneCapture,
+            MicrophoneCaptureState,
+            MicrophoneCaptureError,
+            MicrophoneCaptureStateChanged,
+            RemoveMicrophoneCaptureStateChanged,
+            CaptureTargetClosed,
+            RemoveCaptureTargetClosed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureState as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCaptureStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IAppCaptureStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCaptureStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllowedAsync(this: *mut core::ffi::c_void, allowed: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCaptureStatics2", "SetAllowedAsync", 6usize, |this| ((**(this as *const *const IAppCaptureStatics2_Vtbl)).SetAllowedAsync)(this, allowed, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAllowedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCaptureStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for ICameraCaptureUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PhotoSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUI", "PhotoSettings", 6usize, |this| ((**(this as *const *const ICameraCaptureUI_Vtbl)).PhotoSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUI", "VideoSettings", 7usize, |this| ((**(this as *const *const ICameraCaptureUI_Vtbl)).VideoSettings)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CaptureFileAsync(this: *mut core::ffi::c_void, mode: CameraCaptureUIMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUI", "CaptureFileAsync", 8usize, |this| ((**(this as *const *const ICameraCaptureUI_Vtbl)).CaptureFileAsync)(this, mode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PhotoSettings,
+            VideoSettings,
+            #[cfg(feature = "Storage")]
+            CaptureFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            CaptureFileAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraCaptureUI as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICameraCaptureUIPhotoCaptureSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, result__: *mut CameraCaptureUIPhotoFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIPhotoCaptureSettings", "Format", 6usize, |this| ((**(this as *const *const ICameraCaptureUIPhotoCaptureSettings_Vtbl)).Format)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, value: CameraCaptureUIPhotoFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIPhotoCaptureSettings", "SetFormat", 7usize, |this| ((**(this as *const *const ICameraCaptureUIPhotoCaptureSettings_Vtbl)).SetFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxResolution(this: *mut core::ffi::c_void, result__: *mut CameraCaptureUIMaxPhotoResolution) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIPhotoCaptureSettings", "MaxResolution", 8usize, |this| ((**(this as *const *const ICameraCaptureUIPhotoCaptureSettings_Vtbl)).MaxResolution)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxResolution(this: *mut core::ffi::c_void, value: CameraCaptureUIMaxPhotoResolution) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIPhotoCaptureSettings", "SetMaxResolution", 9usize, |this| ((**(this as *const *const ICameraCaptureUIPhotoCaptureSettings_Vtbl)).SetMaxResolution)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CroppedSizeInPixels(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIPhotoCaptureSettings", "CroppedSizeInPixels", 10usize, |this| ((**(this as *const *const ICameraCaptureUIPhotoCaptureSettings_Vtbl)).CroppedSizeInPixels)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCroppedSizeInPixels(this: *mut core::ffi::c_void, value: super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIPhotoCaptureSettings", "SetCroppedSizeInPixels", 11usize, |this| ((**(this as *const *const ICameraCaptureUIPhotoCaptureSettings_Vtbl)).SetCroppedSizeInPixels)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CroppedAspectRatio(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIPhotoCaptureSettings", "CroppedAspectRatio", 12usize, |this| ((**(this as *const *const ICameraCaptureUIPhotoCaptureSettings_Vtbl)).CroppedAspectRatio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCroppedAspectRatio(this: *mut core::ffi::c_void, value: super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIPhotoCaptureSettings", "SetCroppedAspectRatio", 13usize, |this| ((**(this as *const *const ICameraCaptureUIPhotoCaptureSettings_Vtbl)).SetCroppedAspectRatio)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowCropping(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIPhotoCaptureSettings", "AllowCropping", 14usize, |this| ((**(this as *const *const ICameraCaptureUIPhotoCaptureSettings_Vtbl)).AllowCropping)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowCropping(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIPhotoCaptureSettings", "SetAllowCropping", 15usize, |this| ((**(this as *const *const ICameraCaptureUIPhotoCaptureSettings_Vtbl)).SetAllowCropping)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Format,
+            SetFormat,
+            MaxResolution,
+            SetMaxResolution,
+            CroppedSizeInPixels,
+            SetCroppedSizeInPixels,
+            CroppedAspectRatio,
+            SetCroppedAspectRatio,
+            AllowCropping,
+            SetAllowCropping,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraCaptureUIPhotoCaptureSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICameraCaptureUIVideoCaptureSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, result__: *mut CameraCaptureUIVideoFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIVideoCaptureSettings", "Format", 6usize, |this| ((**(this as *const *const ICameraCaptureUIVideoCaptureSettings_Vtbl)).Format)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, value: CameraCaptureUIVideoFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIVideoCaptureSettings", "SetFormat", 7usize, |this| ((**(this as *const *const ICameraCaptureUIVideoCaptureSettings_Vtbl)).SetFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxResolution(this: *mut core::ffi::c_void, result__: *mut CameraCaptureUIMaxVideoResolution) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIVideoCaptureSettings", "MaxResolution", 8usize, |this| ((**(this as *const *const ICameraCaptureUIVideoCaptureSettings_Vtbl)).MaxResolution)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxResolution(this: *mut core::ffi::c_void, value: CameraCaptureUIMaxVideoResolution) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIVideoCaptureSettings", "SetMaxResolution", 9usize, |this| ((**(this as *const *const ICameraCaptureUIVideoCaptureSettings_Vtbl)).SetMaxResolution)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxDurationInSeconds(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIVideoCaptureSettings", "MaxDurationInSeconds", 10usize, |this| ((**(this as *const *const ICameraCaptureUIVideoCaptureSettings_Vtbl)).MaxDurationInSeconds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxDurationInSeconds(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIVideoCaptureSettings", "SetMaxDurationInSeconds", 11usize, |this| ((**(this as *const *const ICameraCaptureUIVideoCaptureSettings_Vtbl)).SetMaxDurationInSeconds)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowTrimming(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIVideoCaptureSettings", "AllowTrimming", 12usize, |this| ((**(this as *const *const ICameraCaptureUIVideoCaptureSettings_Vtbl)).AllowTrimming)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowTrimming(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraCaptureUIVideoCaptureSettings", "SetAllowTrimming", 13usize, |this| ((**(this as *const *const ICameraCaptureUIVideoCaptureSettings_Vtbl)).SetAllowTrimming)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Format,
+            SetFormat,
+            MaxResolution,
+            SetMaxResolution,
+            MaxDurationInSeconds,
+            SetMaxDurationInSeconds,
+            AllowTrimming,
+            SetAllowTrimming,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraCaptureUIVideoCaptureSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICameraOptionsUIStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, mediacapture: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraOptionsUIStatics", "Show", 6usize, |this| ((**(this as *const *const ICameraOptionsUIStatics_Vtbl)).Show)(this, mediacapture), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Show }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraOptionsUIStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ICapturedFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrame", "Width", 6usize, |this| ((**(this as *const *const ICapturedFrame_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrame", "Height", 7usize, |this| ((**(this as *const *const ICapturedFrame_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Width, Height }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICapturedFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ICapturedFrame2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ControlValues(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrame2", "ControlValues", 6usize, |this| ((**(this as *const *const ICapturedFrame2_Vtbl)).ControlValues)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+        unsafe extern "system" fn BitmapProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrame2", "BitmapProperties", 7usize, |this| ((**(this as *const *const ICapturedFrame2_Vtbl)).BitmapProperties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ControlValues,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+            BitmapProperties,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics_Imaging")))]
+            BitmapProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICapturedFrame2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Devices")]
+impl windows_core::imp::ProxyVtable for ICapturedFrameControlValues_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Exposure(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrameControlValues", "Exposure", 6usize, |this| ((**(this as *const *const ICapturedFrameControlValues_Vtbl)).Exposure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExposureCompensation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrameControlValues", "ExposureCompensation", 7usize, |this| ((**(this as *const *const ICapturedFrameControlValues_Vtbl)).ExposureCompensation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsoSpeed(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrameControlValues", "IsoSpeed", 8usize, |this| ((**(this as *const *const ICapturedFrameControlValues_Vtbl)).IsoSpeed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Focus(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrameControlValues", "Focus", 9usize, |this| ((**(this as *const *const ICapturedFrameControlValues_Vtbl)).Focus)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Devices")]
+        unsafe extern "system" fn SceneMode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrameControlValues", "SceneMode", 10usize, |this| ((**(this as *const *const ICapturedFrameControlValues_Vtbl)).SceneMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flashed(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrameControlValues", "Flashed", 11usize, |this| ((**(this as *const *const ICapturedFrameControlValues_Vtbl)).Flashed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlashPowerPercent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrameControlValues", "FlashPowerPercent", 12usize, |this| ((**(this as *const *const ICapturedFrameControlValues_Vtbl)).FlashPowerPercent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WhiteBalance(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrameControlValues", "WhiteBalance", 13usize, |this| ((**(this as *const *const ICapturedFrameControlValues_Vtbl)).WhiteBalance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZoomFactor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrameControlValues", "ZoomFactor", 14usize, |this| ((**(this as *const *const ICapturedFrameControlValues_Vtbl)).ZoomFactor)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Exposure,
+            ExposureCompensation,
+            IsoSpeed,
+            Focus,
+            #[cfg(feature = "Media_Devices")]
+            SceneMode,
+            #[cfg(not(feature = "Media_Devices"))]
+            SceneMode: 0,
+            Flashed,
+            FlashPowerPercent,
+            WhiteBalance,
+            ZoomFactor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICapturedFrameControlValues as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Devices", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for ICapturedFrameControlValues2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Devices")]
+        unsafe extern "system" fn FocusState(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrameControlValues2", "FocusState", 6usize, |this| ((**(this as *const *const ICapturedFrameControlValues2_Vtbl)).FocusState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsoDigitalGain(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrameControlValues2", "IsoDigitalGain", 7usize, |this| ((**(this as *const *const ICapturedFrameControlValues2_Vtbl)).IsoDigitalGain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsoAnalogGain(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrameControlValues2", "IsoAnalogGain", 8usize, |this| ((**(this as *const *const ICapturedFrameControlValues2_Vtbl)).IsoAnalogGain)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn SensorFrameRate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrameControlValues2", "SensorFrameRate", 9usize, |this| ((**(this as *const *const ICapturedFrameControlValues2_Vtbl)).SensorFrameRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WhiteBalanceGain(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrameControlValues2", "WhiteBalanceGain", 10usize, |this| ((**(this as *const *const ICapturedFrameControlValues2_Vtbl)).WhiteBalanceGain)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Devices")]
+            FocusState,
+            #[cfg(not(feature = "Media_Devices"))]
+            FocusState: 0,
+            IsoDigitalGain,
+            IsoAnalogGain,
+            #[cfg(feature = "Media_MediaProperties")]
+            SensorFrameRate,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            SensorFrameRate: 0,
+            WhiteBalanceGain,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICapturedFrameControlValues2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for ICapturedFrameWithSoftwareBitmap_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SoftwareBitmap(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedFrameWithSoftwareBitmap", "SoftwareBitmap", 6usize, |this| ((**(this as *const *const ICapturedFrameWithSoftwareBitmap_Vtbl)).SoftwareBitmap)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Imaging")]
+            SoftwareBitmap,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SoftwareBitmap: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICapturedFrameWithSoftwareBitmap as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICapturedPhoto_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Frame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedPhoto", "Frame", 6usize, |this| ((**(this as *const *const ICapturedPhoto_Vtbl)).Frame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICapturedPhoto", "Thumbnail", 7usize, |this| ((**(this as *const *const ICapturedPhoto_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Frame, Thumbnail }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICapturedPhoto as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameBarServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TargetCapturePolicy(this: *mut core::ffi::c_void, result__: *mut GameBarTargetCapturePolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServices", "TargetCapturePolicy", 6usize, |this| ((**(this as *const *const IGameBarServices_Vtbl)).TargetCapturePolicy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableCapture(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServices", "EnableCapture", 7usize, |this| ((**(this as *const *const IGameBarServices_Vtbl)).EnableCapture)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableCapture(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServices", "DisableCapture", 8usize, |this| ((**(this as *const *const IGameBarServices_Vtbl)).DisableCapture)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServices", "TargetInfo", 9usize, |this| ((**(this as *const *const IGameBarServices_Vtbl)).TargetInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServices", "SessionId", 10usize, |this| ((**(this as *const *const IGameBarServices_Vtbl)).SessionId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppBroadcastServices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServices", "AppBroadcastServices", 11usize, |this| ((**(this as *const *const IGameBarServices_Vtbl)).AppBroadcastServices)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppCaptureServices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServices", "AppCaptureServices", 12usize, |this| ((**(this as *const *const IGameBarServices_Vtbl)).AppCaptureServices)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommandReceived(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServices", "CommandReceived", 13usize, |this| ((**(this as *const *const IGameBarServices_Vtbl)).CommandReceived)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCommandReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServices", "RemoveCommandReceived", 14usize, |this| ((**(this as *const *const IGameBarServices_Vtbl)).RemoveCommandReceived)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TargetCapturePolicy,
+            EnableCapture,
+            DisableCapture,
+            TargetInfo,
+            SessionId,
+            AppBroadcastServices,
+            AppCaptureServices,
+            CommandReceived,
+            RemoveCommandReceived,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameBarServices as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameBarServicesCommandEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Command(this: *mut core::ffi::c_void, result__: *mut GameBarCommand) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServicesCommandEventArgs", "Command", 6usize, |this| ((**(this as *const *const IGameBarServicesCommandEventArgs_Vtbl)).Command)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Origin(this: *mut core::ffi::c_void, result__: *mut GameBarCommandOrigin) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServicesCommandEventArgs", "Origin", 7usize, |this| ((**(this as *const *const IGameBarServicesCommandEventArgs_Vtbl)).Origin)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Command, Origin }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameBarServicesCommandEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameBarServicesManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GameBarServicesCreated(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServicesManager", "GameBarServicesCreated", 6usize, |this| ((**(this as *const *const IGameBarServicesManager_Vtbl)).GameBarServicesCreated)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGameBarServicesCreated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServicesManager", "RemoveGameBarServicesCreated", 7usize, |this| ((**(this as *const *const IGameBarServicesManager_Vtbl)).RemoveGameBarServicesCreated)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GameBarServicesCreated, RemoveGameBarServicesCreated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameBarServicesManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameBarServicesManagerGameBarServicesCreatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GameBarServices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServicesManagerGameBarServicesCreatedEventArgs", "GameBarServices", 6usize, |this| ((**(this as *const *const IGameBarServicesManagerGameBarServicesCreatedEventArgs_Vtbl)).GameBarServices)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GameBarServices }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameBarServicesManagerGameBarServicesCreatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameBarServicesManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServicesManagerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IGameBarServicesManagerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameBarServicesManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameBarServicesTargetInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServicesTargetInfo", "DisplayName", 6usize, |this| ((**(this as *const *const IGameBarServicesTargetInfo_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServicesTargetInfo", "AppId", 7usize, |this| ((**(this as *const *const IGameBarServicesTargetInfo_Vtbl)).AppId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TitleId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServicesTargetInfo", "TitleId", 8usize, |this| ((**(this as *const *const IGameBarServicesTargetInfo_Vtbl)).TitleId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayMode(this: *mut core::ffi::c_void, result__: *mut GameBarServicesDisplayMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameBarServicesTargetInfo", "DisplayMode", 9usize, |this| ((**(this as *const *const IGameBarServicesTargetInfo_Vtbl)).DisplayMode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayName, AppId, TitleId, DisplayMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameBarServicesTargetInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILowLagMediaRecording_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagMediaRecording", "StartAsync", 6usize, |this| ((**(this as *const *const ILowLagMediaRecording_Vtbl)).StartAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagMediaRecording", "StopAsync", 7usize, |this| ((**(this as *const *const ILowLagMediaRecording_Vtbl)).StopAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinishAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagMediaRecording", "FinishAsync", 8usize, |this| ((**(this as *const *const ILowLagMediaRecording_Vtbl)).FinishAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartAsync, StopAsync, FinishAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILowLagMediaRecording as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Devices")]
+impl windows_core::imp::ProxyVtable for ILowLagMediaRecording2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Devices")]
+        unsafe extern "system" fn PauseAsync(this: *mut core::ffi::c_void, behavior: super::Devices::MediaCapturePauseBehavior, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagMediaRecording2", "PauseAsync", 6usize, |this| ((**(this as *const *const ILowLagMediaRecording2_Vtbl)).PauseAsync)(this, behavior, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagMediaRecording2", "ResumeAsync", 7usize, |this| ((**(this as *const *const ILowLagMediaRecording2_Vtbl)).ResumeAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Devices")]
+            PauseAsync,
+            #[cfg(not(feature = "Media_Devices"))]
+            PauseAsync: 0,
+            ResumeAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILowLagMediaRecording2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Devices")]
+impl windows_core::imp::ProxyVtable for ILowLagMediaRecording3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Devices")]
+        unsafe extern "system" fn PauseWithResultAsync(this: *mut core::ffi::c_void, behavior: super::Devices::MediaCapturePauseBehavior, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagMediaRecording3", "PauseWithResultAsync", 6usize, |this| ((**(this as *const *const ILowLagMediaRecording3_Vtbl)).PauseWithResultAsync)(this, behavior, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopWithResultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagMediaRecording3", "StopWithResultAsync", 7usize, |this| ((**(this as *const *const ILowLagMediaRecording3_Vtbl)).StopWithResultAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Devices")]
+            PauseWithResultAsync,
+            #[cfg(not(feature = "Media_Devices"))]
+            PauseWithResultAsync: 0,
+            StopWithResultAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILowLagMediaRecording3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILowLagPhotoCapture_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CaptureAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoCapture", "CaptureAsync", 6usize, |this| ((**(this as *const *const ILowLagPhotoCapture_Vtbl)).CaptureAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinishAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoCapture", "FinishAsync", 7usize, |this| ((**(this as *const *const ILowLagPhotoCapture_Vtbl)).FinishAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CaptureAsync, FinishAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILowLagPhotoCapture as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILowLagPhotoSequenceCapture_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceCapture", "StartAsync", 6usize, |this| ((**(this as *const *const ILowLagPhotoSequenceCapture_Vtbl)).StartAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceCapture", "StopAsync", 7usize, |this| ((**(this as *const *const ILowLagPhotoSequenceCapture_Vtbl)).StopAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinishAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceCapture", "FinishAsync", 8usize, |this| ((**(this as *const *const ILowLagPhotoSequenceCapture_Vtbl)).FinishAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotoCaptured(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceCapture", "PhotoCaptured", 9usize, |this| ((**(this as *const *const ILowLagPhotoSequenceCapture_Vtbl)).PhotoCaptured)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePhotoCaptured(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceCapture", "RemovePhotoCaptured", 10usize, |this| ((**(this as *const *const ILowLagPhotoSequenceCapture_Vtbl)).RemovePhotoCaptured)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartAsync,
+            StopAsync,
+            FinishAsync,
+            PhotoCaptured,
+            RemovePhotoCaptured,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILowLagPhotoSequenceCapture as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Devices", feature = "Media_MediaProperties", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMediaCapture_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "InitializeAsync", 6usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).InitializeAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeWithSettingsAsync(this: *mut core::ffi::c_void, mediacaptureinitializationsettings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "InitializeWithSettingsAsync", 7usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).InitializeWithSettingsAsync)(this, mediacaptureinitializationsettings, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_MediaProperties", feature = "Storage"))]
+        unsafe extern "system" fn StartRecordToStorageFileAsync(this: *mut core::ffi::c_void, encodingprofile: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "StartRecordToStorageFileAsync", 8usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).StartRecordToStorageFileAsync)(this, encodingprofile, file, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_MediaProperties", feature = "Storage_Streams"))]
+        unsafe extern "system" fn StartRecordToStreamAsync(this: *mut core::ffi::c_void, encodingprofile: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "StartRecordToStreamAsync", 9usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).StartRecordToStreamAsync)(this, encodingprofile, stream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn StartRecordToCustomSinkAsync(this: *mut core::ffi::c_void, encodingprofile: *mut core::ffi::c_void, custommediasink: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "StartRecordToCustomSinkAsync", 10usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).StartRecordToCustomSinkAsync)(this, encodingprofile, custommediasink, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn StartRecordToCustomSinkIdAsync(this: *mut core::ffi::c_void, encodingprofile: *mut core::ffi::c_void, customsinkactivationid: core::mem::MaybeUninit<windows_core::HSTRING>, customsinksettings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "StartRecordToCustomSinkIdAsync", 11usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).StartRecordToCustomSinkIdAsync)(this, encodingprofile, customsinkactivationid, customsinksettings, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopRecordAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "StopRecordAsync", 12usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).StopRecordAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_MediaProperties", feature = "Storage"))]
+        unsafe extern "system" fn CapturePhotoToStorageFileAsync(this: *mut core::ffi::c_void, r#type: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "CapturePhotoToStorageFileAsync", 13usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).CapturePhotoToStorageFileAsync)(this, r#type, file, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_MediaProperties", feature = "Storage_Streams"))]
+        unsafe extern "system" fn CapturePhotoToStreamAsync(this: *mut core::ffi::c_void, r#type: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "CapturePhotoToStreamAsync", 14usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).CapturePhotoToStreamAsync)(this, r#type, stream, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn AddEffectAsync(this: *mut core::ffi::c_void, mediastreamtype: MediaStreamType, effectactivationid: core::mem::MaybeUninit<windows_core::HSTRING>, effectsettings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "AddEffectAsync", 15usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).AddEffectAsync)(this, mediastreamtype, effectactivationid, effectsettings, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearEffectsAsync(this: *mut core::ffi::c_void, mediastreamtype: MediaStreamType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "ClearEffectsAsync", 16usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).ClearEffectsAsync)(this, mediastreamtype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEncoderProperty(this: *mut core::ffi::c_void, mediastreamtype: MediaStreamType, propertyid: windows_core::GUID, propertyvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "SetEncoderProperty", 17usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).SetEncoderProperty)(this, mediastreamtype, propertyid, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEncoderProperty(this: *mut core::ffi::c_void, mediastreamtype: MediaStreamType, propertyid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "GetEncoderProperty", 18usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).GetEncoderProperty)(this, mediastreamtype, propertyid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Failed(this: *mut core::ffi::c_void, erroreventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "Failed", 19usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).Failed)(this, erroreventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFailed(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "RemoveFailed", 20usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).RemoveFailed)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordLimitationExceeded(this: *mut core::ffi::c_void, recordlimitationexceededeventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "RecordLimitationExceeded", 21usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).RecordLimitationExceeded)(this, recordlimitationexceededeventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRecordLimitationExceeded(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "RemoveRecordLimitationExceeded", 22usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).RemoveRecordLimitationExceeded)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaCaptureSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "MediaCaptureSettings", 23usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).MediaCaptureSettings)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Devices")]
+        unsafe extern "system" fn AudioDeviceController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "AudioDeviceController", 24usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).AudioDeviceController)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Devices")]
+        unsafe extern "system" fn VideoDeviceController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "VideoDeviceController", 25usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).VideoDeviceController)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreviewMirroring(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "SetPreviewMirroring", 26usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).SetPreviewMirroring)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviewMirroring(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "GetPreviewMirroring", 27usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).GetPreviewMirroring)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreviewRotation(this: *mut core::ffi::c_void, value: VideoRotation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "SetPreviewRotation", 28usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).SetPreviewRotation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviewRotation(this: *mut core::ffi::c_void, result__: *mut VideoRotation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "GetPreviewRotation", 29usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).GetPreviewRotation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecordRotation(this: *mut core::ffi::c_void, value: VideoRotation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "SetRecordRotation", 30usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).SetRecordRotation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordRotation(this: *mut core::ffi::c_void, result__: *mut VideoRotation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture", "GetRecordRotation", 31usize, |this| ((**(this as *const *const IMediaCapture_Vtbl)).GetRecordRotation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeAsync,
+            InitializeWithSettingsAsync,
+            #[cfg(all(feature = "Media_MediaProperties", feature = "Storage"))]
+            StartRecordToStorageFileAsync,
+            #[cfg(not(all(feature = "Media_MediaProperties", feature = "Storage")))]
+            StartRecordToStorageFileAsync: 0,
+            #[cfg(all(feature = "Media_MediaProperties", feature = "Storage_Streams"))]
+            StartRecordToStreamAsync,
+            #[cfg(not(all(feature = "Media_MediaProperties", feature = "Storage_Streams")))]
+            StartRecordToStreamAsync: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            StartRecordToCustomSinkAsync,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            StartRecordToCustomSinkAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+            StartRecordToCustomSinkIdAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_MediaProperties")))]
+            StartRecordToCustomSinkIdAsync: 0,
+            StopRecordAsync,
+            #[cfg(all(feature = "Media_MediaProperties", feature = "Storage"))]
+            CapturePhotoToStorageFileAsync,
+            #[cfg(not(all(feature = "Media_MediaProperties", feature = "Storage")))]
+            CapturePhotoToStorageFileAsync: 0,
+            #[cfg(all(feature = "Media_MediaProperties", feature = "Storage_Streams"))]
+            CapturePhotoToStreamAsync,
+            #[cfg(not(all(feature = "Media_MediaProperties", feature = "Storage_Streams")))]
+            CapturePhotoToStreamAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            AddEffectAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            AddEffectAsync: 0,
+            ClearEffectsAsync,
+            SetEncoderProperty,
+            GetEncoderProperty,
+            Failed,
+            RemoveFailed,
+            RecordLimitationExceeded,
+            RemoveRecordLimitationExceeded,
+            MediaCaptureSettings,
+            #[cfg(feature = "Media_Devices")]
+            AudioDeviceController,
+            #[cfg(not(feature = "Media_Devices"))]
+            AudioDeviceController: 0,
+            #[cfg(feature = "Media_Devices")]
+            VideoDeviceController,
+            #[cfg(not(feature = "Media_Devices"))]
+            VideoDeviceController: 0,
+            SetPreviewMirroring,
+            GetPreviewMirroring,
+            SetPreviewRotation,
+            GetPreviewRotation,
+            SetRecordRotation,
+            GetRecordRotation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCapture as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMediaCapture2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Media_MediaProperties", feature = "Storage"))]
+        unsafe extern "system" fn PrepareLowLagRecordToStorageFileAsync(this: *mut core::ffi::c_void, encodingprofile: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture2", "PrepareLowLagRecordToStorageFileAsync", 6usize, |this| ((**(this as *const *const IMediaCapture2_Vtbl)).PrepareLowLagRecordToStorageFileAsync)(this, encodingprofile, file, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_MediaProperties", feature = "Storage_Streams"))]
+        unsafe extern "system" fn PrepareLowLagRecordToStreamAsync(this: *mut core::ffi::c_void, encodingprofile: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture2", "PrepareLowLagRecordToStreamAsync", 7usize, |this| ((**(this as *const *const IMediaCapture2_Vtbl)).PrepareLowLagRecordToStreamAsync)(this, encodingprofile, stream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn PrepareLowLagRecordToCustomSinkAsync(this: *mut core::ffi::c_void, encodingprofile: *mut core::ffi::c_void, custommediasink: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture2", "PrepareLowLagRecordToCustomSinkAsync", 8usize, |this| ((**(this as *const *const IMediaCapture2_Vtbl)).PrepareLowLagRecordToCustomSinkAsync)(this, encodingprofile, custommediasink, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn PrepareLowLagRecordToCustomSinkIdAsync(this: *mut core::ffi::c_void, encodingprofile: *mut core::ffi::c_void, customsinkactivationid: core::mem::MaybeUninit<windows_core::HSTRING>, customsinksettings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture2", "PrepareLowLagRecordToCustomSinkIdAsync", 9usize, |this| ((**(this as *const *const IMediaCapture2_Vtbl)).PrepareLowLagRecordToCustomSinkIdAsync)(this, encodingprofile, customsinkactivationid, customsinksettings, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn PrepareLowLagPhotoCaptureAsync(this: *mut core::ffi::c_void, r#type: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture2", "PrepareLowLagPhotoCaptureAsync", 10usize, |this| ((**(this as *const *const IMediaCapture2_Vtbl)).PrepareLowLagPhotoCaptureAsync)(this, r#type, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn PrepareLowLagPhotoSequenceCaptureAsync(this: *mut core::ffi::c_void, r#type: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture2", "PrepareLowLagPhotoSequenceCaptureAsync", 11usize, |this| ((**(this as *const *const IMediaCapture2_Vtbl)).PrepareLowLagPhotoSequenceCaptureAsync)(this, r#type, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn SetEncodingPropertiesAsync(this: *mut core::ffi::c_void, mediastreamtype: MediaStreamType, mediaencodingproperties: *mut core::ffi::c_void, encoderproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture2", "SetEncodingPropertiesAsync", 12usize, |this| ((**(this as *const *const IMediaCapture2_Vtbl)).SetEncodingPropertiesAsync)(this, mediastreamtype, mediaencodingproperties, encoderproperties, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Media_MediaProperties", feature = "Storage"))]
+            PrepareLowLagRecordToStorageFileAsync,
+            #[cfg(not(all(feature = "Media_MediaProperties", feature = "Storage")))]
+            PrepareLowLagRecordToStorageFileAsync: 0,
+            #[cfg(all(feature = "Media_MediaProperties", feature = "Storage_Streams"))]
+            PrepareLowLagRecordToStreamAsync,
+            #[cfg(not(all(feature = "Media_MediaProperties", feature = "Storage_Streams")))]
+            PrepareLowLagRecordToStreamAsync: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            PrepareLowLagRecordToCustomSinkAsync,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            PrepareLowLagRecordToCustomSinkAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+            PrepareLowLagRecordToCustomSinkIdAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_MediaProperties")))]
+            PrepareLowLagRecordToCustomSinkIdAsync: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            PrepareLowLagPhotoCaptureAsync,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            PrepareLowLagPhotoCaptureAsync: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            PrepareLowLagPhotoSequenceCaptureAsync,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            PrepareLowLagPhotoSequenceCaptureAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+            SetEncodingPropertiesAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_MediaProperties")))]
+            SetEncodingPropertiesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCapture2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Capture_Core", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IMediaCapture3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Media_Capture_Core", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn PrepareVariablePhotoSequenceCaptureAsync(this: *mut core::ffi::c_void, r#type: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture3", "PrepareVariablePhotoSequenceCaptureAsync", 6usize, |this| ((**(this as *const *const IMediaCapture3_Vtbl)).PrepareVariablePhotoSequenceCaptureAsync)(this, r#type, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FocusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture3", "FocusChanged", 7usize, |this| ((**(this as *const *const IMediaCapture3_Vtbl)).FocusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFocusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture3", "RemoveFocusChanged", 8usize, |this| ((**(this as *const *const IMediaCapture3_Vtbl)).RemoveFocusChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotoConfirmationCaptured(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture3", "PhotoConfirmationCaptured", 9usize, |this| ((**(this as *const *const IMediaCapture3_Vtbl)).PhotoConfirmationCaptured)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePhotoConfirmationCaptured(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture3", "RemovePhotoConfirmationCaptured", 10usize, |this| ((**(this as *const *const IMediaCapture3_Vtbl)).RemovePhotoConfirmationCaptured)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Media_Capture_Core", feature = "Media_MediaProperties"))]
+            PrepareVariablePhotoSequenceCaptureAsync,
+            #[cfg(not(all(feature = "Media_Capture_Core", feature = "Media_MediaProperties")))]
+            PrepareVariablePhotoSequenceCaptureAsync: 0,
+            FocusChanged,
+            RemoveFocusChanged,
+            PhotoConfirmationCaptured,
+            RemovePhotoConfirmationCaptured,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCapture3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Devices", feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IMediaCapture4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Effects")]
+        unsafe extern "system" fn AddAudioEffectAsync(this: *mut core::ffi::c_void, definition: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture4", "AddAudioEffectAsync", 6usize, |this| ((**(this as *const *const IMediaCapture4_Vtbl)).AddAudioEffectAsync)(this, definition, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Effects")]
+        unsafe extern "system" fn AddVideoEffectAsync(this: *mut core::ffi::c_void, definition: *mut core::ffi::c_void, mediastreamtype: MediaStreamType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture4", "AddVideoEffectAsync", 7usize, |this| ((**(this as *const *const IMediaCapture4_Vtbl)).AddVideoEffectAsync)(this, definition, mediastreamtype, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Devices")]
+        unsafe extern "system" fn PauseRecordAsync(this: *mut core::ffi::c_void, behavior: super::Devices::MediaCapturePauseBehavior, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture4", "PauseRecordAsync", 8usize, |this| ((**(this as *const *const IMediaCapture4_Vtbl)).PauseRecordAsync)(this, behavior, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeRecordAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture4", "ResumeRecordAsync", 9usize, |this| ((**(this as *const *const IMediaCapture4_Vtbl)).ResumeRecordAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CameraStreamStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture4", "CameraStreamStateChanged", 10usize, |this| ((**(this as *const *const IMediaCapture4_Vtbl)).CameraStreamStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCameraStreamStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture4", "RemoveCameraStreamStateChanged", 11usize, |this| ((**(this as *const *const IMediaCapture4_Vtbl)).RemoveCameraStreamStateChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Devices")]
+        unsafe extern "system" fn CameraStreamState(this: *mut core::ffi::c_void, result__: *mut super::Devices::CameraStreamState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture4", "CameraStreamState", 12usize, |this| ((**(this as *const *const IMediaCapture4_Vtbl)).CameraStreamState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviewFrameAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture4", "GetPreviewFrameAsync", 13usize, |this| ((**(this as *const *const IMediaCapture4_Vtbl)).GetPreviewFrameAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviewFrameCopyAsync(this: *mut core::ffi::c_void, destination: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture4", "GetPreviewFrameCopyAsync", 14usize, |this| ((**(this as *const *const IMediaCapture4_Vtbl)).GetPreviewFrameCopyAsync)(this, destination, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThermalStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture4", "ThermalStatusChanged", 15usize, |this| ((**(this as *const *const IMediaCapture4_Vtbl)).ThermalStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveThermalStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture4", "RemoveThermalStatusChanged", 16usize, |this| ((**(this as *const *const IMediaCapture4_Vtbl)).RemoveThermalStatusChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThermalStatus(this: *mut core::ffi::c_void, result__: *mut MediaCaptureThermalStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture4", "ThermalStatus", 17usize, |this| ((**(this as *const *const IMediaCapture4_Vtbl)).ThermalStatus)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn PrepareAdvancedPhotoCaptureAsync(this: *mut core::ffi::c_void, encodingproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture4", "PrepareAdvancedPhotoCaptureAsync", 18usize, |this| ((**(this as *const *const IMediaCapture4_Vtbl)).PrepareAdvancedPhotoCaptureAsync)(this, encodingproperties, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Effects")]
+            AddAudioEffectAsync,
+            #[cfg(not(feature = "Media_Effects"))]
+            AddAudioEffectAsync: 0,
+            #[cfg(feature = "Media_Effects")]
+            AddVideoEffectAsync,
+            #[cfg(not(feature = "Media_Effects"))]
+            AddVideoEffectAsync: 0,
+            #[cfg(feature = "Media_Devices")]
+            PauseRecordAsync,
+            #[cfg(not(feature = "Media_Devices"))]
+            PauseRecordAsync: 0,
+            ResumeRecordAsync,
+            CameraStreamStateChanged,
+            RemoveCameraStreamStateChanged,
+            #[cfg(feature = "Media_Devices")]
+            CameraStreamState,
+            #[cfg(not(feature = "Media_Devices"))]
+            CameraStreamState: 0,
+            GetPreviewFrameAsync,
+            GetPreviewFrameCopyAsync,
+            ThermalStatusChanged,
+            RemoveThermalStatusChanged,
+            ThermalStatus,
+            #[cfg(feature = "Media_MediaProperties")]
+            PrepareAdvancedPhotoCaptureAsync,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            PrepareAdvancedPhotoCaptureAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCapture4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging", feature = "Media_Capture_Frames", feature = "Media_Devices"))]
+impl windows_core::imp::ProxyVtable for IMediaCapture5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoveEffectAsync(this: *mut core::ffi::c_void, effect: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture5", "RemoveEffectAsync", 6usize, |this| ((**(this as *const *const IMediaCapture5_Vtbl)).RemoveEffectAsync)(this, effect, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Devices")]
+        unsafe extern "system" fn PauseRecordWithResultAsync(this: *mut core::ffi::c_void, behavior: super::Devices::MediaCapturePauseBehavior, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture5", "PauseRecordWithResultAsync", 7usize, |this| ((**(this as *const *const IMediaCapture5_Vtbl)).PauseRecordWithResultAsync)(this, behavior, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopRecordWithResultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture5", "StopRecordWithResultAsync", 8usize, |this| ((**(this as *const *const IMediaCapture5_Vtbl)).StopRecordWithResultAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Capture_Frames"))]
+        unsafe extern "system" fn FrameSources(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture5", "FrameSources", 9usize, |this| ((**(this as *const *const IMediaCapture5_Vtbl)).FrameSources)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Capture_Frames")]
+        unsafe extern "system" fn CreateFrameReaderAsync(this: *mut core::ffi::c_void, inputsource: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture5", "CreateFrameReaderAsync", 10usize, |this| ((**(this as *const *const IMediaCapture5_Vtbl)).CreateFrameReaderAsync)(this, inputsource, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Capture_Frames")]
+        unsafe extern "system" fn CreateFrameReaderWithSubtypeAsync(this: *mut core::ffi::c_void, inputsource: *mut core::ffi::c_void, outputsubtype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture5", "CreateFrameReaderWithSubtypeAsync", 11usize, |this| ((**(this as *const *const IMediaCapture5_Vtbl)).CreateFrameReaderWithSubtypeAsync)(this, inputsource, outputsubtype, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Graphics_Imaging", feature = "Media_Capture_Frames"))]
+        unsafe extern "system" fn CreateFrameReaderWithSubtypeAndSizeAsync(this: *mut core::ffi::c_void, inputsource: *mut core::ffi::c_void, outputsubtype: core::mem::MaybeUninit<windows_core::HSTRING>, outputsize: super::super::Graphics::Imaging::BitmapSize, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture5", "CreateFrameReaderWithSubtypeAndSizeAsync", 12usize, |this| ((**(this as *const *const IMediaCapture5_Vtbl)).CreateFrameReaderWithSubtypeAndSizeAsync)(this, inputsource, outputsubtype, outputsize, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RemoveEffectAsync,
+            #[cfg(feature = "Media_Devices")]
+            PauseRecordWithResultAsync,
+            #[cfg(not(feature = "Media_Devices"))]
+            PauseRecordWithResultAsync: 0,
+            StopRecordWithResultAsync,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Capture_Frames"))]
+            FrameSources,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Capture_Frames")))]
+            FrameSources: 0,
+            #[cfg(feature = "Media_Capture_Frames")]
+            CreateFrameReaderAsync,
+            #[cfg(not(feature = "Media_Capture_Frames"))]
+            CreateFrameReaderAsync: 0,
+            #[cfg(feature = "Media_Capture_Frames")]
+            CreateFrameReaderWithSubtypeAsync,
+            #[cfg(not(feature = "Media_Capture_Frames"))]
+            CreateFrameReaderWithSubtypeAsync: 0,
+            #[cfg(all(feature = "Graphics_Imaging", feature = "Media_Capture_Frames"))]
+            CreateFrameReaderWithSubtypeAndSizeAsync,
+            #[cfg(not(all(feature = "Graphics_Imaging", feature = "Media_Capture_Frames")))]
+            CreateFrameReaderWithSubtypeAndSizeAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCapture5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Capture_Frames"))]
+impl windows_core::imp::ProxyVtable for IMediaCapture6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CaptureDeviceExclusiveControlStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture6", "CaptureDeviceExclusiveControlStatusChanged", 6usize, |this| ((**(this as *const *const IMediaCapture6_Vtbl)).CaptureDeviceExclusiveControlStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCaptureDeviceExclusiveControlStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture6", "RemoveCaptureDeviceExclusiveControlStatusChanged", 7usize, |this| ((**(this as *const *const IMediaCapture6_Vtbl)).RemoveCaptureDeviceExclusiveControlStatusChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Capture_Frames"))]
+        unsafe extern "system" fn CreateMultiSourceFrameReaderAsync(this: *mut core::ffi::c_void, inputsources: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture6", "CreateMultiSourceFrameReaderAsync", 8usize, |this| ((**(this as *const *const IMediaCapture6_Vtbl)).CreateMultiSourceFrameReaderAsync)(this, inputsources, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CaptureDeviceExclusiveControlStatusChanged,
+            RemoveCaptureDeviceExclusiveControlStatusChanged,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Capture_Frames"))]
+            CreateMultiSourceFrameReaderAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Capture_Frames")))]
+            CreateMultiSourceFrameReaderAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCapture6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_WindowManagement")]
+impl windows_core::imp::ProxyVtable for IMediaCapture7_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_WindowManagement")]
+        unsafe extern "system" fn CreateRelativePanelWatcher(this: *mut core::ffi::c_void, capturemode: StreamingCaptureMode, displayregion: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapture7", "CreateRelativePanelWatcher", 6usize, |this| ((**(this as *const *const IMediaCapture7_Vtbl)).CreateRelativePanelWatcher)(this, capturemode, displayregion, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_WindowManagement")]
+            CreateRelativePanelWatcher,
+            #[cfg(not(feature = "UI_WindowManagement"))]
+            CreateRelativePanelWatcher: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCapture7 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaCaptureDeviceExclusiveControlStatusChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureDeviceExclusiveControlStatusChangedEventArgs", "DeviceId", 6usize, |this| ((**(this as *const *const IMediaCaptureDeviceExclusiveControlStatusChangedEventArgs_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut MediaCaptureDeviceExclusiveControlStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureDeviceExclusiveControlStatusChangedEventArgs", "Status", 7usize, |this| ((**(this as *const *const IMediaCaptureDeviceExclusiveControlStatusChangedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureDeviceExclusiveControlStatusChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaCaptureFailedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureFailedEventArgs", "Message", 6usize, |this| ((**(this as *const *const IMediaCaptureFailedEventArgs_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Code(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureFailedEventArgs", "Code", 7usize, |this| ((**(this as *const *const IMediaCaptureFailedEventArgs_Vtbl)).Code)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Message, Code }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureFailedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Devices")]
+impl windows_core::imp::ProxyVtable for IMediaCaptureFocusChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Devices")]
+        unsafe extern "system" fn FocusState(this: *mut core::ffi::c_void, result__: *mut super::Devices::MediaCaptureFocusState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureFocusChangedEventArgs", "FocusState", 6usize, |this| ((**(this as *const *const IMediaCaptureFocusChangedEventArgs_Vtbl)).FocusState)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Devices")]
+            FocusState,
+            #[cfg(not(feature = "Media_Devices"))]
+            FocusState: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureFocusChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaCaptureInitializationSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAudioDeviceId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings", "SetAudioDeviceId", 6usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings_Vtbl)).SetAudioDeviceId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings", "AudioDeviceId", 7usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings_Vtbl)).AudioDeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoDeviceId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings", "SetVideoDeviceId", 8usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings_Vtbl)).SetVideoDeviceId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings", "VideoDeviceId", 9usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings_Vtbl)).VideoDeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamingCaptureMode(this: *mut core::ffi::c_void, value: StreamingCaptureMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings", "SetStreamingCaptureMode", 10usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings_Vtbl)).SetStreamingCaptureMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StreamingCaptureMode(this: *mut core::ffi::c_void, result__: *mut StreamingCaptureMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings", "StreamingCaptureMode", 11usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings_Vtbl)).StreamingCaptureMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPhotoCaptureSource(this: *mut core::ffi::c_void, value: PhotoCaptureSource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings", "SetPhotoCaptureSource", 12usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings_Vtbl)).SetPhotoCaptureSource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotoCaptureSource(this: *mut core::ffi::c_void, result__: *mut PhotoCaptureSource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings", "PhotoCaptureSource", 13usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings_Vtbl)).PhotoCaptureSource)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAudioDeviceId,
+            AudioDeviceId,
+            SetVideoDeviceId,
+            VideoDeviceId,
+            SetStreamingCaptureMode,
+            StreamingCaptureMode,
+            SetPhotoCaptureSource,
+            PhotoCaptureSource,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureInitializationSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaCaptureInitializationSettings2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMediaCategory(this: *mut core::ffi::c_void, value: MediaCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings2", "SetMediaCategory", 6usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings2_Vtbl)).SetMediaCategory)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaCategory(this: *mut core::ffi::c_void, result__: *mut MediaCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings2", "MediaCategory", 7usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings2_Vtbl)).MediaCategory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioProcessing(this: *mut core::ffi::c_void, value: super::AudioProcessing) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings2", "SetAudioProcessing", 8usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings2_Vtbl)).SetAudioProcessing)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioProcessing(this: *mut core::ffi::c_void, result__: *mut super::AudioProcessing) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings2", "AudioProcessing", 9usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings2_Vtbl)).AudioProcessing)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMediaCategory,
+            MediaCategory,
+            SetAudioProcessing,
+            AudioProcessing,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureInitializationSettings2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Core")]
+impl windows_core::imp::ProxyVtable for IMediaCaptureInitializationSettings3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn SetAudioSource(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings3", "SetAudioSource", 6usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings3_Vtbl)).SetAudioSource)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn AudioSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings3", "AudioSource", 7usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings3_Vtbl)).AudioSource)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn SetVideoSource(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings3", "SetVideoSource", 8usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings3_Vtbl)).SetVideoSource)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn VideoSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings3", "VideoSource", 9usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings3_Vtbl)).VideoSource)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Core")]
+            SetAudioSource,
+            #[cfg(not(feature = "Media_Core"))]
+            SetAudioSource: 0,
+            #[cfg(feature = "Media_Core")]
+            AudioSource,
+            #[cfg(not(feature = "Media_Core"))]
+            AudioSource: 0,
+            #[cfg(feature = "Media_Core")]
+            SetVideoSource,
+            #[cfg(not(feature = "Media_Core"))]
+            SetVideoSource: 0,
+            #[cfg(feature = "Media_Core")]
+            VideoSource,
+            #[cfg(not(feature = "Media_Core"))]
+            VideoSource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureInitializationSettings3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaCaptureInitializationSettings4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VideoProfile(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings4", "VideoProfile", 6usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings4_Vtbl)).VideoProfile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoProfile(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings4", "SetVideoProfile", 7usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings4_Vtbl)).SetVideoProfile)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreviewMediaDescription(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings4", "PreviewMediaDescription", 8usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings4_Vtbl)).PreviewMediaDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreviewMediaDescription(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings4", "SetPreviewMediaDescription", 9usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings4_Vtbl)).SetPreviewMediaDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordMediaDescription(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings4", "RecordMediaDescription", 10usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings4_Vtbl)).RecordMediaDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecordMediaDescription(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings4", "SetRecordMediaDescription", 11usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings4_Vtbl)).SetRecordMediaDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotoMediaDescription(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings4", "PhotoMediaDescription", 12usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings4_Vtbl)).PhotoMediaDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPhotoMediaDescription(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings4", "SetPhotoMediaDescription", 13usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings4_Vtbl)).SetPhotoMediaDescription)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            VideoProfile,
+            SetVideoProfile,
+            PreviewMediaDescription,
+            SetPreviewMediaDescription,
+            RecordMediaDescription,
+            SetRecordMediaDescription,
+            PhotoMediaDescription,
+            SetPhotoMediaDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureInitializationSettings4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Capture_Frames")]
+impl windows_core::imp::ProxyVtable for IMediaCaptureInitializationSettings5_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Capture_Frames")]
+        unsafe extern "system" fn SourceGroup(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings5", "SourceGroup", 6usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings5_Vtbl)).SourceGroup)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Capture_Frames")]
+        unsafe extern "system" fn SetSourceGroup(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings5", "SetSourceGroup", 7usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings5_Vtbl)).SetSourceGroup)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SharingMode(this: *mut core::ffi::c_void, result__: *mut MediaCaptureSharingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings5", "SharingMode", 8usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings5_Vtbl)).SharingMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSharingMode(this: *mut core::ffi::c_void, value: MediaCaptureSharingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings5", "SetSharingMode", 9usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings5_Vtbl)).SetSharingMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MemoryPreference(this: *mut core::ffi::c_void, result__: *mut MediaCaptureMemoryPreference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings5", "MemoryPreference", 10usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings5_Vtbl)).MemoryPreference)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMemoryPreference(this: *mut core::ffi::c_void, value: MediaCaptureMemoryPreference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings5", "SetMemoryPreference", 11usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings5_Vtbl)).SetMemoryPreference)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Capture_Frames")]
+            SourceGroup,
+            #[cfg(not(feature = "Media_Capture_Frames"))]
+            SourceGroup: 0,
+            #[cfg(feature = "Media_Capture_Frames")]
+            SetSourceGroup,
+            #[cfg(not(feature = "Media_Capture_Frames"))]
+            SetSourceGroup: 0,
+            SharingMode,
+            SetSharingMode,
+            MemoryPreference,
+            SetMemoryPreference,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureInitializationSettings5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaCaptureInitializationSettings6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AlwaysPlaySystemShutterSound(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings6", "AlwaysPlaySystemShutterSound", 6usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings6_Vtbl)).AlwaysPlaySystemShutterSound)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlwaysPlaySystemShutterSound(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings6", "SetAlwaysPlaySystemShutterSound", 7usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings6_Vtbl)).SetAlwaysPlaySystemShutterSound)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AlwaysPlaySystemShutterSound,
+            SetAlwaysPlaySystemShutterSound,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureInitializationSettings6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IMediaCaptureInitializationSettings7_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn DeviceUriPasswordCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings7", "DeviceUriPasswordCredential", 6usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings7_Vtbl)).DeviceUriPasswordCredential)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetDeviceUriPasswordCredential(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings7", "SetDeviceUriPasswordCredential", 7usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings7_Vtbl)).SetDeviceUriPasswordCredential)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings7", "DeviceUri", 8usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings7_Vtbl)).DeviceUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeviceUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureInitializationSettings7", "SetDeviceUri", 9usize, |this| ((**(this as *const *const IMediaCaptureInitializationSettings7_Vtbl)).SetDeviceUri)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            DeviceUriPasswordCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            DeviceUriPasswordCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetDeviceUriPasswordCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetDeviceUriPasswordCredential: 0,
+            DeviceUri,
+            SetDeviceUri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureInitializationSettings7 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaCapturePauseResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LastFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapturePauseResult", "LastFrame", 6usize, |this| ((**(this as *const *const IMediaCapturePauseResult_Vtbl)).LastFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCapturePauseResult", "RecordDuration", 7usize, |this| ((**(this as *const *const IMediaCapturePauseResult_Vtbl)).RecordDuration)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LastFrame, RecordDuration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCapturePauseResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Enumeration")]
+impl windows_core::imp::ProxyVtable for IMediaCaptureRelativePanelWatcher_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn RelativePanel(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Enumeration::Panel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureRelativePanelWatcher", "RelativePanel", 6usize, |this| ((**(this as *const *const IMediaCaptureRelativePanelWatcher_Vtbl)).RelativePanel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Changed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureRelativePanelWatcher", "Changed", 7usize, |this| ((**(this as *const *const IMediaCaptureRelativePanelWatcher_Vtbl)).Changed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureRelativePanelWatcher", "RemoveChanged", 8usize, |this| ((**(this as *const *const IMediaCaptureRelativePanelWatcher_Vtbl)).RemoveChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureRelativePanelWatcher", "Start", 9usize, |this| ((**(this as *const *const IMediaCaptureRelativePanelWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureRelativePanelWatcher", "Stop", 10usize, |this| ((**(this as *const *const IMediaCaptureRelativePanelWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Enumeration")]
+            RelativePanel,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            RelativePanel: 0,
+            Changed,
+            RemoveChanged,
+            Start,
+            Stop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureRelativePanelWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaCaptureSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AudioDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureSettings", "AudioDeviceId", 6usize, |this| ((**(this as *const *const IMediaCaptureSettings_Vtbl)).AudioDeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureSettings", "VideoDeviceId", 7usize, |this| ((**(this as *const *const IMediaCaptureSettings_Vtbl)).VideoDeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StreamingCaptureMode(this: *mut core::ffi::c_void, result__: *mut StreamingCaptureMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureSettings", "StreamingCaptureMode", 8usize, |this| ((**(this as *const *const IMediaCaptureSettings_Vtbl)).StreamingCaptureMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotoCaptureSource(this: *mut core::ffi::c_void, result__: *mut PhotoCaptureSource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureSettings", "PhotoCaptureSource", 9usize, |this| ((**(this as *const *const IMediaCaptureSettings_Vtbl)).PhotoCaptureSource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoDeviceCharacteristic(this: *mut core::ffi::c_void, result__: *mut VideoDeviceCharacteristic) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureSettings", "VideoDeviceCharacteristic", 10usize, |this| ((**(this as *const *const IMediaCaptureSettings_Vtbl)).VideoDeviceCharacteristic)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AudioDeviceId,
+            VideoDeviceId,
+            StreamingCaptureMode,
+            PhotoCaptureSource,
+            VideoDeviceCharacteristic,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaCaptureSettings2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConcurrentRecordAndPhotoSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureSettings2", "ConcurrentRecordAndPhotoSupported", 6usize, |this| ((**(this as *const *const IMediaCaptureSettings2_Vtbl)).ConcurrentRecordAndPhotoSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConcurrentRecordAndPhotoSequenceSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureSettings2", "ConcurrentRecordAndPhotoSequenceSupported", 7usize, |this| ((**(this as *const *const IMediaCaptureSettings2_Vtbl)).ConcurrentRecordAndPhotoSequenceSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CameraSoundRequiredForRegion(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureSettings2", "CameraSoundRequiredForRegion", 8usize, |this| ((**(this as *const *const IMediaCaptureSettings2_Vtbl)).CameraSoundRequiredForRegion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Horizontal35mmEquivalentFocalLength(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureSettings2", "Horizontal35mmEquivalentFocalLength", 9usize, |this| ((**(this as *const *const IMediaCaptureSettings2_Vtbl)).Horizontal35mmEquivalentFocalLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PitchOffsetDegrees(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureSettings2", "PitchOffsetDegrees", 10usize, |this| ((**(this as *const *const IMediaCaptureSettings2_Vtbl)).PitchOffsetDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Vertical35mmEquivalentFocalLength(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureSettings2", "Vertical35mmEquivalentFocalLength", 11usize, |this| ((**(this as *const *const IMediaCaptureSettings2_Vtbl)).Vertical35mmEquivalentFocalLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaCategory(this: *mut core::ffi::c_void, result__: *mut MediaCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureSettings2", "MediaCategory", 12usize, |this| ((**(this as *const *const IMediaCaptureSettings2_Vtbl)).MediaCategory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioProcessing(this: *mut core::ffi::c_void, result__: *mut super::AudioProcessing) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureSettings2", "AudioProcessing", 13usize, |this| ((**(this as *const *const IMediaCaptureSettings2_Vtbl)).AudioProcessing)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConcurrentRecordAndPhotoSupported,
+            ConcurrentRecordAndPhotoSequenceSupported,
+            CameraSoundRequiredForRegion,
+            Horizontal35mmEquivalentFocalLength,
+            PitchOffsetDegrees,
+            Vertical35mmEquivalentFocalLength,
+            MediaCategory,
+            AudioProcessing,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureSettings2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX_Direct3D11")]
+impl windows_core::imp::ProxyVtable for IMediaCaptureSettings3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn Direct3D11Device(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureSettings3", "Direct3D11Device", 6usize, |this| ((**(this as *const *const IMediaCaptureSettings3_Vtbl)).Direct3D11Device)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            Direct3D11Device,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            Direct3D11Device: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureSettings3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaCaptureStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsVideoProfileSupported(this: *mut core::ffi::c_void, videodeviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureStatics", "IsVideoProfileSupported", 6usize, |this| ((**(this as *const *const IMediaCaptureStatics_Vtbl)).IsVideoProfileSupported)(this, videodeviceid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllVideoProfiles(this: *mut core::ffi::c_void, videodeviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureStatics", "FindAllVideoProfiles", 7usize, |this| ((**(this as *const *const IMediaCaptureStatics_Vtbl)).FindAllVideoProfiles)(this, videodeviceid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindConcurrentProfiles(this: *mut core::ffi::c_void, videodeviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureStatics", "FindConcurrentProfiles", 8usize, |this| ((**(this as *const *const IMediaCaptureStatics_Vtbl)).FindConcurrentProfiles)(this, videodeviceid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindKnownVideoProfiles(this: *mut core::ffi::c_void, videodeviceid: core::mem::MaybeUninit<windows_core::HSTRING>, name: KnownVideoProfile, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureStatics", "FindKnownVideoProfiles", 9usize, |this| ((**(this as *const *const IMediaCaptureStatics_Vtbl)).FindKnownVideoProfiles)(this, videodeviceid, name, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsVideoProfileSupported,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllVideoProfiles,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllVideoProfiles: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindConcurrentProfiles,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindConcurrentProfiles: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindKnownVideoProfiles,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindKnownVideoProfiles: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaCaptureStopResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LastFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureStopResult", "LastFrame", 6usize, |this| ((**(this as *const *const IMediaCaptureStopResult_Vtbl)).LastFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureStopResult", "RecordDuration", 7usize, |this| ((**(this as *const *const IMediaCaptureStopResult_Vtbl)).RecordDuration)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LastFrame, RecordDuration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureStopResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IMediaCaptureVideoPreview_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartPreviewAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoPreview", "StartPreviewAsync", 6usize, |this| ((**(this as *const *const IMediaCaptureVideoPreview_Vtbl)).StartPreviewAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn StartPreviewToCustomSinkAsync(this: *mut core::ffi::c_void, encodingprofile: *mut core::ffi::c_void, custommediasink: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoPreview", "StartPreviewToCustomSinkAsync", 7usize, |this| ((**(this as *const *const IMediaCaptureVideoPreview_Vtbl)).StartPreviewToCustomSinkAsync)(this, encodingprofile, custommediasink, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn StartPreviewToCustomSinkIdAsync(this: *mut core::ffi::c_void, encodingprofile: *mut core::ffi::c_void, customsinkactivationid: core::mem::MaybeUninit<windows_core::HSTRING>, customsinksettings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoPreview", "StartPreviewToCustomSinkIdAsync", 8usize, |this| ((**(this as *const *const IMediaCaptureVideoPreview_Vtbl)).StartPreviewToCustomSinkIdAsync)(this, encodingprofile, customsinkactivationid, customsinksettings, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopPreviewAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoPreview", "StopPreviewAsync", 9usize, |this| ((**(this as *const *const IMediaCaptureVideoPreview_Vtbl)).StopPreviewAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartPreviewAsync,
+            #[cfg(feature = "Media_MediaProperties")]
+            StartPreviewToCustomSinkAsync,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            StartPreviewToCustomSinkAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+            StartPreviewToCustomSinkIdAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_MediaProperties")))]
+            StartPreviewToCustomSinkIdAsync: 0,
+            StopPreviewAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureVideoPreview as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaCaptureVideoProfile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoProfile", "Id", 6usize, |this| ((**(this as *const *const IMediaCaptureVideoProfile_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoProfile", "VideoDeviceId", 7usize, |this| ((**(this as *const *const IMediaCaptureVideoProfile_Vtbl)).VideoDeviceId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedPreviewMediaDescription(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoProfile", "SupportedPreviewMediaDescription", 8usize, |this| ((**(this as *const *const IMediaCaptureVideoProfile_Vtbl)).SupportedPreviewMediaDescription)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedRecordMediaDescription(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoProfile", "SupportedRecordMediaDescription", 9usize, |this| ((**(this as *const *const IMediaCaptureVideoProfile_Vtbl)).SupportedRecordMediaDescription)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedPhotoMediaDescription(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoProfile", "SupportedPhotoMediaDescription", 10usize, |this| ((**(this as *const *const IMediaCaptureVideoProfile_Vtbl)).SupportedPhotoMediaDescription)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetConcurrency(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoProfile", "GetConcurrency", 11usize, |this| ((**(this as *const *const IMediaCaptureVideoProfile_Vtbl)).GetConcurrency)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            VideoDeviceId,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedPreviewMediaDescription,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedPreviewMediaDescription: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedRecordMediaDescription,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedRecordMediaDescription: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedPhotoMediaDescription,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedPhotoMediaDescription: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetConcurrency,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetConcurrency: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureVideoProfile as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Capture_Frames"))]
+impl windows_core::imp::ProxyVtable for IMediaCaptureVideoProfile2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Capture_Frames"))]
+        unsafe extern "system" fn FrameSourceInfos(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoProfile2", "FrameSourceInfos", 6usize, |this| ((**(this as *const *const IMediaCaptureVideoProfile2_Vtbl)).FrameSourceInfos)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoProfile2", "Properties", 7usize, |this| ((**(this as *const *const IMediaCaptureVideoProfile2_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Capture_Frames"))]
+            FrameSourceInfos,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Capture_Frames")))]
+            FrameSourceInfos: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureVideoProfile2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaCaptureVideoProfileMediaDescription_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoProfileMediaDescription", "Width", 6usize, |this| ((**(this as *const *const IMediaCaptureVideoProfileMediaDescription_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoProfileMediaDescription", "Height", 7usize, |this| ((**(this as *const *const IMediaCaptureVideoProfileMediaDescription_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameRate(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoProfileMediaDescription", "FrameRate", 8usize, |this| ((**(this as *const *const IMediaCaptureVideoProfileMediaDescription_Vtbl)).FrameRate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsVariablePhotoSequenceSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoProfileMediaDescription", "IsVariablePhotoSequenceSupported", 9usize, |this| ((**(this as *const *const IMediaCaptureVideoProfileMediaDescription_Vtbl)).IsVariablePhotoSequenceSupported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsHdrVideoSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoProfileMediaDescription", "IsHdrVideoSupported", 10usize, |this| ((**(this as *const *const IMediaCaptureVideoProfileMediaDescription_Vtbl)).IsHdrVideoSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Width,
+            Height,
+            FrameRate,
+            #[cfg(feature = "deprecated")]
+            IsVariablePhotoSequenceSupported,
+            #[cfg(not(feature = "deprecated"))]
+            IsVariablePhotoSequenceSupported: 0,
+            #[cfg(feature = "deprecated")]
+            IsHdrVideoSupported,
+            #[cfg(not(feature = "deprecated"))]
+            IsHdrVideoSupported: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureVideoProfileMediaDescription as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaCaptureVideoProfileMediaDescription2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Subtype(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoProfileMediaDescription2", "Subtype", 6usize, |this| ((**(this as *const *const IMediaCaptureVideoProfileMediaDescription2_Vtbl)).Subtype)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCaptureVideoProfileMediaDescription2", "Properties", 7usize, |this| ((**(this as *const *const IMediaCaptureVideoProfileMediaDescription2_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Subtype,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCaptureVideoProfileMediaDescription2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOptionalReferencePhotoCapturedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Frame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOptionalReferencePhotoCapturedEventArgs", "Frame", 6usize, |this| ((**(this as *const *const IOptionalReferencePhotoCapturedEventArgs_Vtbl)).Frame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Context(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOptionalReferencePhotoCapturedEventArgs", "Context", 7usize, |this| ((**(this as *const *const IOptionalReferencePhotoCapturedEventArgs_Vtbl)).Context)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Frame, Context }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOptionalReferencePhotoCapturedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhotoCapturedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Frame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoCapturedEventArgs", "Frame", 6usize, |this| ((**(this as *const *const IPhotoCapturedEventArgs_Vtbl)).Frame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoCapturedEventArgs", "Thumbnail", 7usize, |this| ((**(this as *const *const IPhotoCapturedEventArgs_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CaptureTimeOffset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoCapturedEventArgs", "CaptureTimeOffset", 8usize, |this| ((**(this as *const *const IPhotoCapturedEventArgs_Vtbl)).CaptureTimeOffset)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Frame, Thumbnail, CaptureTimeOffset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoCapturedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhotoConfirmationCapturedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Frame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoConfirmationCapturedEventArgs", "Frame", 6usize, |this| ((**(this as *const *const IPhotoConfirmationCapturedEventArgs_Vtbl)).Frame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CaptureTimeOffset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoConfirmationCapturedEventArgs", "CaptureTimeOffset", 7usize, |this| ((**(this as *const *const IPhotoConfirmationCapturedEventArgs_Vtbl)).CaptureTimeOffset)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Frame, CaptureTimeOffset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoConfirmationCapturedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Core")]
+impl windows_core::imp::ProxyVtable for IScreenCapture_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn AudioSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScreenCapture", "AudioSource", 6usize, |this| ((**(this as *const *const IScreenCapture_Vtbl)).AudioSource)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn VideoSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScreenCapture", "VideoSource", 7usize, |this| ((**(this as *const *const IScreenCapture_Vtbl)).VideoSource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAudioSuspended(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScreenCapture", "IsAudioSuspended", 8usize, |this| ((**(this as *const *const IScreenCapture_Vtbl)).IsAudioSuspended)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVideoSuspended(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScreenCapture", "IsVideoSuspended", 9usize, |this| ((**(this as *const *const IScreenCapture_Vtbl)).IsVideoSuspended)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceSuspensionChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScreenCapture", "SourceSuspensionChanged", 10usize, |this| ((**(this as *const *const IScreenCapture_Vtbl)).SourceSuspensionChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSourceSuspensionChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScreenCapture", "RemoveSourceSuspensionChanged", 11usize, |this| ((**(this as *const *const IScreenCapture_Vtbl)).RemoveSourceSuspensionChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Core")]
+            AudioSource,
+            #[cfg(not(feature = "Media_Core"))]
+            AudioSource: 0,
+            #[cfg(feature = "Media_Core")]
+            VideoSource,
+            #[cfg(not(feature = "Media_Core"))]
+            VideoSource: 0,
+            IsAudioSuspended,
+            IsVideoSuspended,
+            SourceSuspensionChanged,
+            RemoveSourceSuspensionChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScreenCapture as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScreenCaptureStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScreenCaptureStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IScreenCaptureStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScreenCaptureStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISourceSuspensionChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAudioSuspended(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISourceSuspensionChangedEventArgs", "IsAudioSuspended", 6usize, |this| ((**(this as *const *const ISourceSuspensionChangedEventArgs_Vtbl)).IsAudioSuspended)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVideoSuspended(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISourceSuspensionChangedEventArgs", "IsVideoSuspended", 7usize, |this| ((**(this as *const *const ISourceSuspensionChangedEventArgs_Vtbl)).IsVideoSuspended)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsAudioSuspended, IsVideoSuspended }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISourceSuspensionChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_MediaProperties")]
+impl windows_core::imp::ProxyVtable for IVideoStreamConfiguration_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn InputProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoStreamConfiguration", "InputProperties", 6usize, |this| ((**(this as *const *const IVideoStreamConfiguration_Vtbl)).InputProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn OutputProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoStreamConfiguration", "OutputProperties", 7usize, |this| ((**(this as *const *const IVideoStreamConfiguration_Vtbl)).OutputProperties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_MediaProperties")]
+            InputProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            InputProperties: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            OutputProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            OutputProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoStreamConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
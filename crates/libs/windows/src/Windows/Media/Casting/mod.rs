@@ -547,6 +547,7 @@ impl CastingConnectionErrorStatus {
 impl windows_core::TypeKind for CastingConnectionErrorStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CastingConnectionErrorStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CastingConnectionErrorStatus").field(&self.0).finish()
@@ -568,6 +569,7 @@ impl CastingConnectionState {
 impl windows_core::TypeKind for CastingConnectionState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CastingConnectionState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CastingConnectionState").field(&self.0).finish()
@@ -588,6 +590,7 @@ impl CastingPlaybackTypes {
 impl windows_core::TypeKind for CastingPlaybackTypes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CastingPlaybackTypes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CastingPlaybackTypes").field(&self.0).finish()
@@ -629,3 +632,5 @@ impl core::ops::Not for CastingPlaybackTypes {
 impl windows_core::RuntimeType for CastingPlaybackTypes {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.Casting.CastingPlaybackTypes;u4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,261 @@
+impl windows_core::imp::ProxyVtable for ICastingConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut CastingConnectionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingConnection", "State", 6usize, |this| ((**(this as *const *const ICastingConnection_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Device(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingConnection", "Device", 7usize, |this| ((**(this as *const *const ICastingConnection_Vtbl)).Device)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingConnection", "Source", 8usize, |this| ((**(this as *const *const ICastingConnection_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingConnection", "SetSource", 9usize, |this| ((**(this as *const *const ICastingConnection_Vtbl)).SetSource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingConnection", "StateChanged", 10usize, |this| ((**(this as *const *const ICastingConnection_Vtbl)).StateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingConnection", "RemoveStateChanged", 11usize, |this| ((**(this as *const *const ICastingConnection_Vtbl)).RemoveStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorOccurred(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingConnection", "ErrorOccurred", 12usize, |this| ((**(this as *const *const ICastingConnection_Vtbl)).ErrorOccurred)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveErrorOccurred(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingConnection", "RemoveErrorOccurred", 13usize, |this| ((**(this as *const *const ICastingConnection_Vtbl)).RemoveErrorOccurred)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStartCastingAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingConnection", "RequestStartCastingAsync", 14usize, |this| ((**(this as *const *const ICastingConnection_Vtbl)).RequestStartCastingAsync)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingConnection", "DisconnectAsync", 15usize, |this| ((**(this as *const *const ICastingConnection_Vtbl)).DisconnectAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            State,
+            Device,
+            Source,
+            SetSource,
+            StateChanged,
+            RemoveStateChanged,
+            ErrorOccurred,
+            RemoveErrorOccurred,
+            RequestStartCastingAsync,
+            DisconnectAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICastingConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICastingConnectionErrorOccurredEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ErrorStatus(this: *mut core::ffi::c_void, result__: *mut CastingConnectionErrorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingConnectionErrorOccurredEventArgs", "ErrorStatus", 6usize, |this| ((**(this as *const *const ICastingConnectionErrorOccurredEventArgs_Vtbl)).ErrorStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingConnectionErrorOccurredEventArgs", "Message", 7usize, |this| ((**(this as *const *const ICastingConnectionErrorOccurredEventArgs_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ErrorStatus, Message }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICastingConnectionErrorOccurredEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ICastingDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevice", "Id", 6usize, |this| ((**(this as *const *const ICastingDevice_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevice", "FriendlyName", 7usize, |this| ((**(this as *const *const ICastingDevice_Vtbl)).FriendlyName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Icon(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevice", "Icon", 8usize, |this| ((**(this as *const *const ICastingDevice_Vtbl)).Icon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedCastingPlaybackTypesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevice", "GetSupportedCastingPlaybackTypesAsync", 9usize, |this| ((**(this as *const *const ICastingDevice_Vtbl)).GetSupportedCastingPlaybackTypesAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCastingConnection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevice", "CreateCastingConnection", 10usize, |this| ((**(this as *const *const ICastingDevice_Vtbl)).CreateCastingConnection)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            FriendlyName,
+            #[cfg(feature = "Storage_Streams")]
+            Icon,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Icon: 0,
+            GetSupportedCastingPlaybackTypesAsync,
+            CreateCastingConnection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICastingDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "UI_Popups"))]
+impl windows_core::imp::ProxyVtable for ICastingDevicePicker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Filter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePicker", "Filter", 6usize, |this| ((**(this as *const *const ICastingDevicePicker_Vtbl)).Filter)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn Appearance(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePicker", "Appearance", 7usize, |this| ((**(this as *const *const ICastingDevicePicker_Vtbl)).Appearance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CastingDeviceSelected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePicker", "CastingDeviceSelected", 8usize, |this| ((**(this as *const *const ICastingDevicePicker_Vtbl)).CastingDeviceSelected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCastingDeviceSelected(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePicker", "RemoveCastingDeviceSelected", 9usize, |this| ((**(this as *const *const ICastingDevicePicker_Vtbl)).RemoveCastingDeviceSelected)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CastingDevicePickerDismissed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePicker", "CastingDevicePickerDismissed", 10usize, |this| ((**(this as *const *const ICastingDevicePicker_Vtbl)).CastingDevicePickerDismissed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCastingDevicePickerDismissed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePicker", "RemoveCastingDevicePickerDismissed", 11usize, |this| ((**(this as *const *const ICastingDevicePicker_Vtbl)).RemoveCastingDevicePickerDismissed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePicker", "Show", 12usize, |this| ((**(this as *const *const ICastingDevicePicker_Vtbl)).Show)(this, selection), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowWithPlacement(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePicker", "ShowWithPlacement", 13usize, |this| ((**(this as *const *const ICastingDevicePicker_Vtbl)).ShowWithPlacement)(this, selection, preferredplacement), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hide(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePicker", "Hide", 14usize, |this| ((**(this as *const *const ICastingDevicePicker_Vtbl)).Hide)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Filter,
+            #[cfg(feature = "Devices_Enumeration")]
+            Appearance,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            Appearance: 0,
+            CastingDeviceSelected,
+            RemoveCastingDeviceSelected,
+            CastingDevicePickerDismissed,
+            RemoveCastingDevicePickerDismissed,
+            Show,
+            #[cfg(feature = "UI_Popups")]
+            ShowWithPlacement,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowWithPlacement: 0,
+            Hide,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICastingDevicePicker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICastingDevicePickerFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SupportsAudio(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePickerFilter", "SupportsAudio", 6usize, |this| ((**(this as *const *const ICastingDevicePickerFilter_Vtbl)).SupportsAudio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportsAudio(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePickerFilter", "SetSupportsAudio", 7usize, |this| ((**(this as *const *const ICastingDevicePickerFilter_Vtbl)).SetSupportsAudio)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportsVideo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePickerFilter", "SupportsVideo", 8usize, |this| ((**(this as *const *const ICastingDevicePickerFilter_Vtbl)).SupportsVideo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportsVideo(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePickerFilter", "SetSupportsVideo", 9usize, |this| ((**(this as *const *const ICastingDevicePickerFilter_Vtbl)).SetSupportsVideo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportsPictures(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePickerFilter", "SupportsPictures", 10usize, |this| ((**(this as *const *const ICastingDevicePickerFilter_Vtbl)).SupportsPictures)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportsPictures(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePickerFilter", "SetSupportsPictures", 11usize, |this| ((**(this as *const *const ICastingDevicePickerFilter_Vtbl)).SetSupportsPictures)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedCastingSources(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDevicePickerFilter", "SupportedCastingSources", 12usize, |this| ((**(this as *const *const ICastingDevicePickerFilter_Vtbl)).SupportedCastingSources)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SupportsAudio,
+            SetSupportsAudio,
+            SupportsVideo,
+            SetSupportsVideo,
+            SupportsPictures,
+            SetSupportsPictures,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedCastingSources,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedCastingSources: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICastingDevicePickerFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICastingDeviceSelectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SelectedCastingDevice(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDeviceSelectedEventArgs", "SelectedCastingDevice", 6usize, |this| ((**(this as *const *const ICastingDeviceSelectedEventArgs_Vtbl)).SelectedCastingDevice)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SelectedCastingDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICastingDeviceSelectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Enumeration")]
+impl windows_core::imp::ProxyVtable for ICastingDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, r#type: CastingPlaybackTypes, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDeviceStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const ICastingDeviceStatics_Vtbl)).GetDeviceSelector)(this, r#type, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelectorFromCastingSourceAsync(this: *mut core::ffi::c_void, castingsource: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDeviceStatics", "GetDeviceSelectorFromCastingSourceAsync", 7usize, |this| ((**(this as *const *const ICastingDeviceStatics_Vtbl)).GetDeviceSelectorFromCastingSourceAsync)(this, castingsource, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDeviceStatics", "FromIdAsync", 8usize, |this| ((**(this as *const *const ICastingDeviceStatics_Vtbl)).FromIdAsync)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn DeviceInfoSupportsCastingAsync(this: *mut core::ffi::c_void, device: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingDeviceStatics", "DeviceInfoSupportsCastingAsync", 9usize, |this| ((**(this as *const *const ICastingDeviceStatics_Vtbl)).DeviceInfoSupportsCastingAsync)(this, device, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceSelector,
+            GetDeviceSelectorFromCastingSourceAsync,
+            FromIdAsync,
+            #[cfg(feature = "Devices_Enumeration")]
+            DeviceInfoSupportsCastingAsync,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            DeviceInfoSupportsCastingAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICastingDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICastingSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PreferredSourceUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingSource", "PreferredSourceUri", 6usize, |this| ((**(this as *const *const ICastingSource_Vtbl)).PreferredSourceUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredSourceUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingSource", "SetPreferredSourceUri", 7usize, |this| ((**(this as *const *const ICastingSource_Vtbl)).SetPreferredSourceUri)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PreferredSourceUri, SetPreferredSourceUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICastingSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -157,6 +157,7 @@ impl ClosedCaptionColor {
 impl windows_core::TypeKind for ClosedCaptionColor {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ClosedCaptionColor {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ClosedCaptionColor").field(&self.0).finish()
@@ -179,6 +180,7 @@ impl ClosedCaptionEdgeEffect {
 impl windows_core::TypeKind for ClosedCaptionEdgeEffect {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ClosedCaptionEdgeEffect {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ClosedCaptionEdgeEffect").field(&self.0).finish()
@@ -200,6 +202,7 @@ impl ClosedCaptionOpacity {
 impl windows_core::TypeKind for ClosedCaptionOpacity {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ClosedCaptionOpacity {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ClosedCaptionOpacity").field(&self.0).finish()
@@ -221,6 +224,7 @@ impl ClosedCaptionSize {
 impl windows_core::TypeKind for ClosedCaptionSize {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ClosedCaptionSize {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ClosedCaptionSize").field(&self.0).finish()
@@ -245,6 +249,7 @@ impl ClosedCaptionStyle {
 impl windows_core::TypeKind for ClosedCaptionStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ClosedCaptionStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ClosedCaptionStyle").field(&self.0).finish()
@@ -253,3 +258,5 @@ impl core::fmt::Debug for ClosedCaptionStyle {
 impl windows_core::RuntimeType for ClosedCaptionStyle {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.ClosedCaptioning.ClosedCaptionStyle;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,87 @@
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for IClosedCaptionPropertiesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FontColor(this: *mut core::ffi::c_void, result__: *mut ClosedCaptionColor) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosedCaptionPropertiesStatics", "FontColor", 6usize, |this| ((**(this as *const *const IClosedCaptionPropertiesStatics_Vtbl)).FontColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn ComputedFontColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosedCaptionPropertiesStatics", "ComputedFontColor", 7usize, |this| ((**(this as *const *const IClosedCaptionPropertiesStatics_Vtbl)).ComputedFontColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FontOpacity(this: *mut core::ffi::c_void, result__: *mut ClosedCaptionOpacity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosedCaptionPropertiesStatics", "FontOpacity", 8usize, |this| ((**(this as *const *const IClosedCaptionPropertiesStatics_Vtbl)).FontOpacity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FontSize(this: *mut core::ffi::c_void, result__: *mut ClosedCaptionSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosedCaptionPropertiesStatics", "FontSize", 9usize, |this| ((**(this as *const *const IClosedCaptionPropertiesStatics_Vtbl)).FontSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FontStyle(this: *mut core::ffi::c_void, result__: *mut ClosedCaptionStyle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosedCaptionPropertiesStatics", "FontStyle", 10usize, |this| ((**(this as *const *const IClosedCaptionPropertiesStatics_Vtbl)).FontStyle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FontEffect(this: *mut core::ffi::c_void, result__: *mut ClosedCaptionEdgeEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosedCaptionPropertiesStatics", "FontEffect", 11usize, |this| ((**(this as *const *const IClosedCaptionPropertiesStatics_Vtbl)).FontEffect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundColor(this: *mut core::ffi::c_void, result__: *mut ClosedCaptionColor) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosedCaptionPropertiesStatics", "BackgroundColor", 12usize, |this| ((**(this as *const *const IClosedCaptionPropertiesStatics_Vtbl)).BackgroundColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn ComputedBackgroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosedCaptionPropertiesStatics", "ComputedBackgroundColor", 13usize, |this| ((**(this as *const *const IClosedCaptionPropertiesStatics_Vtbl)).ComputedBackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundOpacity(this: *mut core::ffi::c_void, result__: *mut ClosedCaptionOpacity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosedCaptionPropertiesStatics", "BackgroundOpacity", 14usize, |this| ((**(this as *const *const IClosedCaptionPropertiesStatics_Vtbl)).BackgroundOpacity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegionColor(this: *mut core::ffi::c_void, result__: *mut ClosedCaptionColor) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosedCaptionPropertiesStatics", "RegionColor", 15usize, |this| ((**(this as *const *const IClosedCaptionPropertiesStatics_Vtbl)).RegionColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn ComputedRegionColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosedCaptionPropertiesStatics", "ComputedRegionColor", 16usize, |this| ((**(this as *const *const IClosedCaptionPropertiesStatics_Vtbl)).ComputedRegionColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegionOpacity(this: *mut core::ffi::c_void, result__: *mut ClosedCaptionOpacity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosedCaptionPropertiesStatics", "RegionOpacity", 17usize, |this| ((**(this as *const *const IClosedCaptionPropertiesStatics_Vtbl)).RegionOpacity)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FontColor,
+            #[cfg(feature = "UI")]
+            ComputedFontColor,
+            #[cfg(not(feature = "UI"))]
+            ComputedFontColor: 0,
+            FontOpacity,
+            FontSize,
+            FontStyle,
+            FontEffect,
+            BackgroundColor,
+            #[cfg(feature = "UI")]
+            ComputedBackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            ComputedBackgroundColor: 0,
+            BackgroundOpacity,
+            RegionColor,
+            #[cfg(feature = "UI")]
+            ComputedRegionColor,
+            #[cfg(not(feature = "UI"))]
+            ComputedRegionColor: 0,
+            RegionOpacity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClosedCaptionPropertiesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClosedCaptionPropertiesStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PropertiesChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosedCaptionPropertiesStatics2", "PropertiesChanged", 6usize, |this| ((**(this as *const *const IClosedCaptionPropertiesStatics2_Vtbl)).PropertiesChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePropertiesChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosedCaptionPropertiesStatics2", "RemovePropertiesChanged", 7usize, |this| ((**(this as *const *const IClosedCaptionPropertiesStatics2_Vtbl)).RemovePropertiesChanged)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PropertiesChanged, RemovePropertiesChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClosedCaptionPropertiesStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
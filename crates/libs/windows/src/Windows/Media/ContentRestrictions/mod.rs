@@ -292,6 +292,7 @@ impl ContentAccessRestrictionLevel {
 impl windows_core::TypeKind for ContentAccessRestrictionLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContentAccessRestrictionLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContentAccessRestrictionLevel").field(&self.0).finish()
@@ -314,6 +315,7 @@ impl RatedContentCategory {
 impl windows_core::TypeKind for RatedContentCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RatedContentCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RatedContentCategory").field(&self.0).finish()
@@ -322,3 +324,5 @@ impl core::fmt::Debug for RatedContentCategory {
 impl windows_core::RuntimeType for RatedContentCategory {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.ContentRestrictions.RatedContentCategory;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
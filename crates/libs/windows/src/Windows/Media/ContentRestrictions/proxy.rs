@@ -0,0 +1,141 @@
+impl windows_core::imp::ProxyVtable for IContentRestrictionsBrowsePolicy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GeographicRegion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentRestrictionsBrowsePolicy", "GeographicRegion", 6usize, |this| ((**(this as *const *const IContentRestrictionsBrowsePolicy_Vtbl)).GeographicRegion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBrowsableAgeRating(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentRestrictionsBrowsePolicy", "MaxBrowsableAgeRating", 7usize, |this| ((**(this as *const *const IContentRestrictionsBrowsePolicy_Vtbl)).MaxBrowsableAgeRating)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredAgeRating(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentRestrictionsBrowsePolicy", "PreferredAgeRating", 8usize, |this| ((**(this as *const *const IContentRestrictionsBrowsePolicy_Vtbl)).PreferredAgeRating)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GeographicRegion, MaxBrowsableAgeRating, PreferredAgeRating }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContentRestrictionsBrowsePolicy as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IRatedContentDescription_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentDescription", "Id", 6usize, |this| ((**(this as *const *const IRatedContentDescription_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentDescription", "SetId", 7usize, |this| ((**(this as *const *const IRatedContentDescription_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentDescription", "Title", 8usize, |this| ((**(this as *const *const IRatedContentDescription_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentDescription", "SetTitle", 9usize, |this| ((**(this as *const *const IRatedContentDescription_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Image(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentDescription", "Image", 10usize, |this| ((**(this as *const *const IRatedContentDescription_Vtbl)).Image)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetImage(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentDescription", "SetImage", 11usize, |this| ((**(this as *const *const IRatedContentDescription_Vtbl)).SetImage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Category(this: *mut core::ffi::c_void, result__: *mut RatedContentCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentDescription", "Category", 12usize, |this| ((**(this as *const *const IRatedContentDescription_Vtbl)).Category)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCategory(this: *mut core::ffi::c_void, value: RatedContentCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentDescription", "SetCategory", 13usize, |this| ((**(this as *const *const IRatedContentDescription_Vtbl)).SetCategory)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Ratings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentDescription", "Ratings", 14usize, |this| ((**(this as *const *const IRatedContentDescription_Vtbl)).Ratings)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetRatings(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentDescription", "SetRatings", 15usize, |this| ((**(this as *const *const IRatedContentDescription_Vtbl)).SetRatings)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            SetId,
+            Title,
+            SetTitle,
+            #[cfg(feature = "Storage_Streams")]
+            Image,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Image: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetImage,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetImage: 0,
+            Category,
+            SetCategory,
+            #[cfg(feature = "Foundation_Collections")]
+            Ratings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Ratings: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetRatings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetRatings: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRatedContentDescription as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRatedContentDescriptionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, title: core::mem::MaybeUninit<windows_core::HSTRING>, category: RatedContentCategory, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentDescriptionFactory", "Create", 6usize, |this| ((**(this as *const *const IRatedContentDescriptionFactory_Vtbl)).Create)(this, id, title, category, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRatedContentDescriptionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRatedContentRestrictions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBrowsePolicyAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentRestrictions", "GetBrowsePolicyAsync", 6usize, |this| ((**(this as *const *const IRatedContentRestrictions_Vtbl)).GetBrowsePolicyAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRestrictionLevelAsync(this: *mut core::ffi::c_void, ratedcontentdescription: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentRestrictions", "GetRestrictionLevelAsync", 7usize, |this| ((**(this as *const *const IRatedContentRestrictions_Vtbl)).GetRestrictionLevelAsync)(this, ratedcontentdescription, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestContentAccessAsync(this: *mut core::ffi::c_void, ratedcontentdescription: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentRestrictions", "RequestContentAccessAsync", 8usize, |this| ((**(this as *const *const IRatedContentRestrictions_Vtbl)).RequestContentAccessAsync)(this, ratedcontentdescription, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestrictionsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentRestrictions", "RestrictionsChanged", 9usize, |this| ((**(this as *const *const IRatedContentRestrictions_Vtbl)).RestrictionsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRestrictionsChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentRestrictions", "RemoveRestrictionsChanged", 10usize, |this| ((**(this as *const *const IRatedContentRestrictions_Vtbl)).RemoveRestrictionsChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetBrowsePolicyAsync,
+            GetRestrictionLevelAsync,
+            RequestContentAccessAsync,
+            RestrictionsChanged,
+            RemoveRestrictionsChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRatedContentRestrictions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRatedContentRestrictionsFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithMaxAgeRating(this: *mut core::ffi::c_void, maxagerating: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRatedContentRestrictionsFactory", "CreateWithMaxAgeRating", 6usize, |this| ((**(this as *const *const IRatedContentRestrictionsFactory_Vtbl)).CreateWithMaxAgeRating)(this, maxagerating, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithMaxAgeRating }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRatedContentRestrictionsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -869,6 +869,7 @@ impl GlobalSystemMediaTransportControlsSessionPlaybackStatus {
 impl windows_core::TypeKind for GlobalSystemMediaTransportControlsSessionPlaybackStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GlobalSystemMediaTransportControlsSessionPlaybackStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GlobalSystemMediaTransportControlsSessionPlaybackStatus").field(&self.0).finish()
@@ -877,3 +878,5 @@ impl core::fmt::Debug for GlobalSystemMediaTransportControlsSessionPlaybackStatu
 impl windows_core::RuntimeType for GlobalSystemMediaTransportControlsSessionPlaybackStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.Control.GlobalSystemMediaTransportControlsSessionPlaybackStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
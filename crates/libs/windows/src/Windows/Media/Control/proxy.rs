@@ -0,0 +1,399 @@
+impl windows_core::imp::ProxyVtable for ICurrentSessionChangedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICurrentSessionChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGlobalSystemMediaTransportControlsSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SourceAppUserModelId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "SourceAppUserModelId", 6usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).SourceAppUserModelId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetMediaPropertiesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TryGetMediaPropertiesAsync", 7usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TryGetMediaPropertiesAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimelineProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "GetTimelineProperties", 8usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).GetTimelineProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPlaybackInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "GetPlaybackInfo", 9usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).GetPlaybackInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryPlayAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TryPlayAsync", 10usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TryPlayAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryPauseAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TryPauseAsync", 11usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TryPauseAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryStopAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TryStopAsync", 12usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TryStopAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryRecordAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TryRecordAsync", 13usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TryRecordAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryFastForwardAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TryFastForwardAsync", 14usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TryFastForwardAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryRewindAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TryRewindAsync", 15usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TryRewindAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySkipNextAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TrySkipNextAsync", 16usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TrySkipNextAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySkipPreviousAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TrySkipPreviousAsync", 17usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TrySkipPreviousAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryChangeChannelUpAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TryChangeChannelUpAsync", 18usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TryChangeChannelUpAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryChangeChannelDownAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TryChangeChannelDownAsync", 19usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TryChangeChannelDownAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryTogglePlayPauseAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TryTogglePlayPauseAsync", 20usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TryTogglePlayPauseAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryChangeAutoRepeatModeAsync(this: *mut core::ffi::c_void, requestedautorepeatmode: super::MediaPlaybackAutoRepeatMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TryChangeAutoRepeatModeAsync", 21usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TryChangeAutoRepeatModeAsync)(this, requestedautorepeatmode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryChangePlaybackRateAsync(this: *mut core::ffi::c_void, requestedplaybackrate: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TryChangePlaybackRateAsync", 22usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TryChangePlaybackRateAsync)(this, requestedplaybackrate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryChangeShuffleActiveAsync(this: *mut core::ffi::c_void, requestedshufflestate: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TryChangeShuffleActiveAsync", 23usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TryChangeShuffleActiveAsync)(this, requestedshufflestate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryChangePlaybackPositionAsync(this: *mut core::ffi::c_void, requestedplaybackposition: i64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TryChangePlaybackPositionAsync", 24usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TryChangePlaybackPositionAsync)(this, requestedplaybackposition, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimelinePropertiesChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "TimelinePropertiesChanged", 25usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).TimelinePropertiesChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTimelinePropertiesChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "RemoveTimelinePropertiesChanged", 26usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).RemoveTimelinePropertiesChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackInfoChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "PlaybackInfoChanged", 27usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).PlaybackInfoChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePlaybackInfoChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "RemovePlaybackInfoChanged", 28usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).RemovePlaybackInfoChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaPropertiesChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "MediaPropertiesChanged", 29usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).MediaPropertiesChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMediaPropertiesChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSession", "RemoveMediaPropertiesChanged", 30usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSession_Vtbl)).RemoveMediaPropertiesChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SourceAppUserModelId,
+            TryGetMediaPropertiesAsync,
+            GetTimelineProperties,
+            GetPlaybackInfo,
+            TryPlayAsync,
+            TryPauseAsync,
+            TryStopAsync,
+            TryRecordAsync,
+            TryFastForwardAsync,
+            TryRewindAsync,
+            TrySkipNextAsync,
+            TrySkipPreviousAsync,
+            TryChangeChannelUpAsync,
+            TryChangeChannelDownAsync,
+            TryTogglePlayPauseAsync,
+            TryChangeAutoRepeatModeAsync,
+            TryChangePlaybackRateAsync,
+            TryChangeShuffleActiveAsync,
+            TryChangePlaybackPositionAsync,
+            TimelinePropertiesChanged,
+            RemoveTimelinePropertiesChanged,
+            PlaybackInfoChanged,
+            RemovePlaybackInfoChanged,
+            MediaPropertiesChanged,
+            RemoveMediaPropertiesChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGlobalSystemMediaTransportControlsSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGlobalSystemMediaTransportControlsSessionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentSession(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionManager", "GetCurrentSession", 6usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionManager_Vtbl)).GetCurrentSession)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSessions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionManager", "GetSessions", 7usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionManager_Vtbl)).GetSessions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentSessionChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionManager", "CurrentSessionChanged", 8usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionManager_Vtbl)).CurrentSessionChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCurrentSessionChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionManager", "RemoveCurrentSessionChanged", 9usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionManager_Vtbl)).RemoveCurrentSessionChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionManager", "SessionsChanged", 10usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionManager_Vtbl)).SessionsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSessionsChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionManager", "RemoveSessionsChanged", 11usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionManager_Vtbl)).RemoveSessionsChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentSession,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSessions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSessions: 0,
+            CurrentSessionChanged,
+            RemoveCurrentSessionChanged,
+            SessionsChanged,
+            RemoveSessionsChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGlobalSystemMediaTransportControlsSessionManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGlobalSystemMediaTransportControlsSessionManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionManagerStatics", "RequestAsync", 6usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionManagerStatics_Vtbl)).RequestAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGlobalSystemMediaTransportControlsSessionManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IGlobalSystemMediaTransportControlsSessionMediaProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionMediaProperties", "Title", 6usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionMediaProperties_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subtitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionMediaProperties", "Subtitle", 7usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionMediaProperties_Vtbl)).Subtitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlbumArtist(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionMediaProperties", "AlbumArtist", 8usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionMediaProperties_Vtbl)).AlbumArtist)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Artist(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionMediaProperties", "Artist", 9usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionMediaProperties_Vtbl)).Artist)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlbumTitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionMediaProperties", "AlbumTitle", 10usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionMediaProperties_Vtbl)).AlbumTitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrackNumber(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionMediaProperties", "TrackNumber", 11usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionMediaProperties_Vtbl)).TrackNumber)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Genres(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionMediaProperties", "Genres", 12usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionMediaProperties_Vtbl)).Genres)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlbumTrackCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionMediaProperties", "AlbumTrackCount", 13usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionMediaProperties_Vtbl)).AlbumTrackCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackType(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionMediaProperties", "PlaybackType", 14usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionMediaProperties_Vtbl)).PlaybackType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionMediaProperties", "Thumbnail", 15usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionMediaProperties_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Title,
+            Subtitle,
+            AlbumArtist,
+            Artist,
+            AlbumTitle,
+            TrackNumber,
+            #[cfg(feature = "Foundation_Collections")]
+            Genres,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Genres: 0,
+            AlbumTrackCount,
+            PlaybackType,
+            #[cfg(feature = "Storage_Streams")]
+            Thumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Thumbnail: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGlobalSystemMediaTransportControlsSessionMediaProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPlayEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackControls", "IsPlayEnabled", 6usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl)).IsPlayEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPauseEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackControls", "IsPauseEnabled", 7usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl)).IsPauseEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStopEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackControls", "IsStopEnabled", 8usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl)).IsStopEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRecordEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackControls", "IsRecordEnabled", 9usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl)).IsRecordEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFastForwardEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackControls", "IsFastForwardEnabled", 10usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl)).IsFastForwardEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRewindEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackControls", "IsRewindEnabled", 11usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl)).IsRewindEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNextEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackControls", "IsNextEnabled", 12usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl)).IsNextEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPreviousEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackControls", "IsPreviousEnabled", 13usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl)).IsPreviousEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsChannelUpEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackControls", "IsChannelUpEnabled", 14usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl)).IsChannelUpEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsChannelDownEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackControls", "IsChannelDownEnabled", 15usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl)).IsChannelDownEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPlayPauseToggleEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackControls", "IsPlayPauseToggleEnabled", 16usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl)).IsPlayPauseToggleEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsShuffleEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackControls", "IsShuffleEnabled", 17usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl)).IsShuffleEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRepeatEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackControls", "IsRepeatEnabled", 18usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl)).IsRepeatEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPlaybackRateEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackControls", "IsPlaybackRateEnabled", 19usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl)).IsPlaybackRateEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPlaybackPositionEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackControls", "IsPlaybackPositionEnabled", 20usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackControls_Vtbl)).IsPlaybackPositionEnabled)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsPlayEnabled,
+            IsPauseEnabled,
+            IsStopEnabled,
+            IsRecordEnabled,
+            IsFastForwardEnabled,
+            IsRewindEnabled,
+            IsNextEnabled,
+            IsPreviousEnabled,
+            IsChannelUpEnabled,
+            IsChannelDownEnabled,
+            IsPlayPauseToggleEnabled,
+            IsShuffleEnabled,
+            IsRepeatEnabled,
+            IsPlaybackRateEnabled,
+            IsPlaybackPositionEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGlobalSystemMediaTransportControlsSessionPlaybackControls as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGlobalSystemMediaTransportControlsSessionPlaybackInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Controls(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackInfo", "Controls", 6usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackInfo_Vtbl)).Controls)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackStatus(this: *mut core::ffi::c_void, result__: *mut GlobalSystemMediaTransportControlsSessionPlaybackStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackInfo", "PlaybackStatus", 7usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackInfo_Vtbl)).PlaybackStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackType(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackInfo", "PlaybackType", 8usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackInfo_Vtbl)).PlaybackType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoRepeatMode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackInfo", "AutoRepeatMode", 9usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackInfo_Vtbl)).AutoRepeatMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackRate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackInfo", "PlaybackRate", 10usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackInfo_Vtbl)).PlaybackRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsShuffleActive(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionPlaybackInfo", "IsShuffleActive", 11usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionPlaybackInfo_Vtbl)).IsShuffleActive)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Controls,
+            PlaybackStatus,
+            PlaybackType,
+            AutoRepeatMode,
+            PlaybackRate,
+            IsShuffleActive,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGlobalSystemMediaTransportControlsSessionPlaybackInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGlobalSystemMediaTransportControlsSessionTimelineProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionTimelineProperties", "StartTime", 6usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionTimelineProperties_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionTimelineProperties", "EndTime", 7usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionTimelineProperties_Vtbl)).EndTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinSeekTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionTimelineProperties", "MinSeekTime", 8usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionTimelineProperties_Vtbl)).MinSeekTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSeekTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionTimelineProperties", "MaxSeekTime", 9usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionTimelineProperties_Vtbl)).MaxSeekTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionTimelineProperties", "Position", 10usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionTimelineProperties_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastUpdatedTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalSystemMediaTransportControlsSessionTimelineProperties", "LastUpdatedTime", 11usize, |this| ((**(this as *const *const IGlobalSystemMediaTransportControlsSessionTimelineProperties_Vtbl)).LastUpdatedTime)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartTime,
+            EndTime,
+            MinSeekTime,
+            MaxSeekTime,
+            Position,
+            LastUpdatedTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGlobalSystemMediaTransportControlsSessionTimelineProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPropertiesChangedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPropertiesChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlaybackInfoChangedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaybackInfoChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISessionsChangedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISessionsChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimelinePropertiesChangedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimelinePropertiesChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
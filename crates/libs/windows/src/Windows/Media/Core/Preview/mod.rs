@@ -38,3 +38,5 @@ impl SoundLevelBroker {
 impl windows_core::RuntimeName for SoundLevelBroker {
     const NAME: &'static str = "Windows.Media.Core.Preview.SoundLevelBroker";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
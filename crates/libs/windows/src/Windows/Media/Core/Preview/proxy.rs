@@ -0,0 +1,18 @@
+impl windows_core::imp::ProxyVtable for ISoundLevelBrokerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SoundLevel(this: *mut core::ffi::c_void, result__: *mut super::super::SoundLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoundLevelBrokerStatics", "SoundLevel", 6usize, |this| ((**(this as *const *const ISoundLevelBrokerStatics_Vtbl)).SoundLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SoundLevelChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoundLevelBrokerStatics", "SoundLevelChanged", 7usize, |this| ((**(this as *const *const ISoundLevelBrokerStatics_Vtbl)).SoundLevelChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSoundLevelChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoundLevelBrokerStatics", "RemoveSoundLevelChanged", 8usize, |this| ((**(this as *const *const ISoundLevelBrokerStatics_Vtbl)).RemoveSoundLevelChanged)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SoundLevel, SoundLevelChanged, RemoveSoundLevelChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISoundLevelBrokerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -6955,6 +6955,7 @@ impl AudioDecoderDegradation {
 impl windows_core::TypeKind for AudioDecoderDegradation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioDecoderDegradation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioDecoderDegradation").field(&self.0).finish()
@@ -6974,6 +6975,7 @@ impl AudioDecoderDegradationReason {
 impl windows_core::TypeKind for AudioDecoderDegradationReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioDecoderDegradationReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioDecoderDegradationReason").field(&self.0).finish()
@@ -6992,6 +6994,7 @@ impl CodecCategory {
 impl windows_core::TypeKind for CodecCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CodecCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CodecCategory").field(&self.0).finish()
@@ -7010,6 +7013,7 @@ impl CodecKind {
 impl windows_core::TypeKind for CodecKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CodecKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CodecKind").field(&self.0).finish()
@@ -7029,6 +7033,7 @@ impl FaceDetectionMode {
 impl windows_core::TypeKind for FaceDetectionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FaceDetectionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FaceDetectionMode").field(&self.0).finish()
@@ -7049,6 +7054,7 @@ impl MediaDecoderStatus {
 impl windows_core::TypeKind for MediaDecoderStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaDecoderStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaDecoderStatus").field(&self.0).finish()
@@ -7070,6 +7076,7 @@ impl MediaSourceState {
 impl windows_core::TypeKind for MediaSourceState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaSourceState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaSourceState").field(&self.0).finish()
@@ -7088,6 +7095,7 @@ impl MediaSourceStatus {
 impl windows_core::TypeKind for MediaSourceStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaSourceStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaSourceStatus").field(&self.0).finish()
@@ -7111,6 +7119,7 @@ impl MediaStreamSourceClosedReason {
 impl windows_core::TypeKind for MediaStreamSourceClosedReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaStreamSourceClosedReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaStreamSourceClosedReason").field(&self.0).finish()
@@ -7135,6 +7144,7 @@ impl MediaStreamSourceErrorStatus {
 impl windows_core::TypeKind for MediaStreamSourceErrorStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaStreamSourceErrorStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaStreamSourceErrorStatus").field(&self.0).finish()
@@ -7154,6 +7164,7 @@ impl MediaTrackKind {
 impl windows_core::TypeKind for MediaTrackKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaTrackKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaTrackKind").field(&self.0).finish()
@@ -7172,6 +7183,7 @@ impl MseAppendMode {
 impl windows_core::TypeKind for MseAppendMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MseAppendMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MseAppendMode").field(&self.0).finish()
@@ -7192,6 +7204,7 @@ impl MseEndOfStreamStatus {
 impl windows_core::TypeKind for MseEndOfStreamStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MseEndOfStreamStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MseEndOfStreamStatus").field(&self.0).finish()
@@ -7211,6 +7224,7 @@ impl MseReadyState {
 impl windows_core::TypeKind for MseReadyState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MseReadyState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MseReadyState").field(&self.0).finish()
@@ -7230,6 +7244,7 @@ impl SceneAnalysisRecommendation {
 impl windows_core::TypeKind for SceneAnalysisRecommendation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SceneAnalysisRecommendation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SceneAnalysisRecommendation").field(&self.0).finish()
@@ -7254,6 +7269,7 @@ impl TimedMetadataKind {
 impl windows_core::TypeKind for TimedMetadataKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedMetadataKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedMetadataKind").field(&self.0).finish()
@@ -7274,6 +7290,7 @@ impl TimedMetadataTrackErrorCode {
 impl windows_core::TypeKind for TimedMetadataTrackErrorCode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedMetadataTrackErrorCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedMetadataTrackErrorCode").field(&self.0).finish()
@@ -7293,6 +7310,7 @@ impl TimedTextBoutenPosition {
 impl windows_core::TypeKind for TimedTextBoutenPosition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedTextBoutenPosition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedTextBoutenPosition").field(&self.0).finish()
@@ -7317,6 +7335,7 @@ impl TimedTextBoutenType {
 impl windows_core::TypeKind for TimedTextBoutenType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedTextBoutenType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedTextBoutenType").field(&self.0).finish()
@@ -7336,6 +7355,7 @@ impl TimedTextDisplayAlignment {
 impl windows_core::TypeKind for TimedTextDisplayAlignment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedTextDisplayAlignment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedTextDisplayAlignment").field(&self.0).finish()
@@ -7354,6 +7374,7 @@ impl TimedTextFlowDirection {
 impl windows_core::TypeKind for TimedTextFlowDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedTextFlowDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedTextFlowDirection").field(&self.0).finish()
@@ -7373,6 +7394,7 @@ impl TimedTextFontStyle {
 impl windows_core::TypeKind for TimedTextFontStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedTextFontStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedTextFontStyle").field(&self.0).finish()
@@ -7392,6 +7414,7 @@ impl TimedTextLineAlignment {
 impl windows_core::TypeKind for TimedTextLineAlignment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedTextLineAlignment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedTextLineAlignment").field(&self.0).finish()
@@ -7414,6 +7437,7 @@ impl TimedTextRubyAlign {
 impl windows_core::TypeKind for TimedTextRubyAlign {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedTextRubyAlign {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedTextRubyAlign").field(&self.0).finish()
@@ -7433,6 +7457,7 @@ impl TimedTextRubyPosition {
 impl windows_core::TypeKind for TimedTextRubyPosition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedTextRubyPosition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedTextRubyPosition").field(&self.0).finish()
@@ -7454,6 +7479,7 @@ impl TimedTextRubyReserve {
 impl windows_core::TypeKind for TimedTextRubyReserve {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedTextRubyReserve {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedTextRubyReserve").field(&self.0).finish()
@@ -7472,6 +7498,7 @@ impl TimedTextScrollMode {
 impl windows_core::TypeKind for TimedTextScrollMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedTextScrollMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedTextScrollMode").field(&self.0).finish()
@@ -7490,6 +7517,7 @@ impl TimedTextUnit {
 impl windows_core::TypeKind for TimedTextUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedTextUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedTextUnit").field(&self.0).finish()
@@ -7508,6 +7536,7 @@ impl TimedTextWeight {
 impl windows_core::TypeKind for TimedTextWeight {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedTextWeight {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedTextWeight").field(&self.0).finish()
@@ -7526,6 +7555,7 @@ impl TimedTextWrapping {
 impl windows_core::TypeKind for TimedTextWrapping {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedTextWrapping {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedTextWrapping").field(&self.0).finish()
@@ -7549,6 +7579,7 @@ impl TimedTextWritingMode {
 impl windows_core::TypeKind for TimedTextWritingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedTextWritingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedTextWritingMode").field(&self.0).finish()
@@ -7568,6 +7599,7 @@ impl VideoStabilizationEffectEnabledChangedReason {
 impl windows_core::TypeKind for VideoStabilizationEffectEnabledChangedReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VideoStabilizationEffectEnabledChangedReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VideoStabilizationEffectEnabledChangedReason").field(&self.0).finish()
@@ -7577,7 +7609,8 @@ impl windows_core::RuntimeType for VideoStabilizationEffectEnabledChangedReason
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.Core.VideoStabilizationEffectEnabledChangedReason;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MseTimeRange {
     pub Start: super::super::Foundation::TimeSpan,
     pub End: super::super::Foundation::TimeSpan,
@@ -7594,7 +7627,8 @@ impl Default for MseTimeRange {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TimedTextDouble {
     pub Value: f64,
     pub Unit: TimedTextUnit,
@@ -7611,7 +7645,8 @@ impl Default for TimedTextDouble {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TimedTextPadding {
     pub Before: f64,
     pub After: f64,
@@ -7631,7 +7666,8 @@ impl Default for TimedTextPadding {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TimedTextPoint {
     pub X: f64,
     pub Y: f64,
@@ -7649,7 +7685,8 @@ impl Default for TimedTextPoint {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TimedTextSize {
     pub Height: f64,
     pub Width: f64,
@@ -7668,3 +7705,5 @@ impl Default for TimedTextSize {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,3255 @@
+#[cfg(feature = "Media_MediaProperties")]
+impl windows_core::imp::ProxyVtable for IAudioStreamDescriptor_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn EncodingProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStreamDescriptor", "EncodingProperties", 6usize, |this| ((**(this as *const *const IAudioStreamDescriptor_Vtbl)).EncodingProperties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_MediaProperties")]
+            EncodingProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            EncodingProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioStreamDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioStreamDescriptor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLeadingEncoderPadding(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStreamDescriptor2", "SetLeadingEncoderPadding", 6usize, |this| ((**(this as *const *const IAudioStreamDescriptor2_Vtbl)).SetLeadingEncoderPadding)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeadingEncoderPadding(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStreamDescriptor2", "LeadingEncoderPadding", 7usize, |this| ((**(this as *const *const IAudioStreamDescriptor2_Vtbl)).LeadingEncoderPadding)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrailingEncoderPadding(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStreamDescriptor2", "SetTrailingEncoderPadding", 8usize, |this| ((**(this as *const *const IAudioStreamDescriptor2_Vtbl)).SetTrailingEncoderPadding)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrailingEncoderPadding(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStreamDescriptor2", "TrailingEncoderPadding", 9usize, |this| ((**(this as *const *const IAudioStreamDescriptor2_Vtbl)).TrailingEncoderPadding)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetLeadingEncoderPadding,
+            LeadingEncoderPadding,
+            SetTrailingEncoderPadding,
+            TrailingEncoderPadding,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioStreamDescriptor2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioStreamDescriptor3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStreamDescriptor3", "Copy", 6usize, |this| ((**(this as *const *const IAudioStreamDescriptor3_Vtbl)).Copy)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Copy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioStreamDescriptor3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_MediaProperties")]
+impl windows_core::imp::ProxyVtable for IAudioStreamDescriptorFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, encodingproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStreamDescriptorFactory", "Create", 6usize, |this| ((**(this as *const *const IAudioStreamDescriptorFactory_Vtbl)).Create)(this, encodingproperties, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_MediaProperties")]
+            Create,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioStreamDescriptorFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_MediaProperties", feature = "Media_Playback"))]
+impl windows_core::imp::ProxyVtable for IAudioTrack_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenFailed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioTrack", "OpenFailed", 6usize, |this| ((**(this as *const *const IAudioTrack_Vtbl)).OpenFailed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOpenFailed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioTrack", "RemoveOpenFailed", 7usize, |this| ((**(this as *const *const IAudioTrack_Vtbl)).RemoveOpenFailed)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn GetEncodingProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioTrack", "GetEncodingProperties", 8usize, |this| ((**(this as *const *const IAudioTrack_Vtbl)).GetEncodingProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Playback")]
+        unsafe extern "system" fn PlaybackItem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioTrack", "PlaybackItem", 9usize, |this| ((**(this as *const *const IAudioTrack_Vtbl)).PlaybackItem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioTrack", "Name", 10usize, |this| ((**(this as *const *const IAudioTrack_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioTrack", "SupportInfo", 11usize, |this| ((**(this as *const *const IAudioTrack_Vtbl)).SupportInfo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OpenFailed,
+            RemoveOpenFailed,
+            #[cfg(feature = "Media_MediaProperties")]
+            GetEncodingProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            GetEncodingProperties: 0,
+            #[cfg(feature = "Media_Playback")]
+            PlaybackItem,
+            #[cfg(not(feature = "Media_Playback"))]
+            PlaybackItem: 0,
+            Name,
+            SupportInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioTrack as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioTrackOpenFailedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioTrackOpenFailedEventArgs", "ExtendedError", 6usize, |this| ((**(this as *const *const IAudioTrackOpenFailedEventArgs_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioTrackOpenFailedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioTrackSupportInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DecoderStatus(this: *mut core::ffi::c_void, result__: *mut MediaDecoderStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioTrackSupportInfo", "DecoderStatus", 6usize, |this| ((**(this as *const *const IAudioTrackSupportInfo_Vtbl)).DecoderStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Degradation(this: *mut core::ffi::c_void, result__: *mut AudioDecoderDegradation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioTrackSupportInfo", "Degradation", 7usize, |this| ((**(this as *const *const IAudioTrackSupportInfo_Vtbl)).Degradation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DegradationReason(this: *mut core::ffi::c_void, result__: *mut AudioDecoderDegradationReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioTrackSupportInfo", "DegradationReason", 8usize, |this| ((**(this as *const *const IAudioTrackSupportInfo_Vtbl)).DegradationReason)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaSourceStatus(this: *mut core::ffi::c_void, result__: *mut MediaSourceStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioTrackSupportInfo", "MediaSourceStatus", 9usize, |this| ((**(this as *const *const IAudioTrackSupportInfo_Vtbl)).MediaSourceStatus)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DecoderStatus,
+            Degradation,
+            DegradationReason,
+            MediaSourceStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioTrackSupportInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChapterCue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChapterCue", "SetTitle", 6usize, |this| ((**(this as *const *const IChapterCue_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChapterCue", "Title", 7usize, |this| ((**(this as *const *const IChapterCue_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTitle, Title }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChapterCue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICodecInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut CodecKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecInfo", "Kind", 6usize, |this| ((**(this as *const *const ICodecInfo_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Category(this: *mut core::ffi::c_void, result__: *mut CodecCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecInfo", "Category", 7usize, |this| ((**(this as *const *const ICodecInfo_Vtbl)).Category)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Subtypes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecInfo", "Subtypes", 8usize, |this| ((**(this as *const *const ICodecInfo_Vtbl)).Subtypes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecInfo", "DisplayName", 9usize, |this| ((**(this as *const *const ICodecInfo_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTrusted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecInfo", "IsTrusted", 10usize, |this| ((**(this as *const *const ICodecInfo_Vtbl)).IsTrusted)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Kind,
+            Category,
+            #[cfg(feature = "Foundation_Collections")]
+            Subtypes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Subtypes: 0,
+            DisplayName,
+            IsTrusted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICodecInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICodecQuery_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsync(this: *mut core::ffi::c_void, kind: CodecKind, category: CodecCategory, subtype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecQuery", "FindAllAsync", 6usize, |this| ((**(this as *const *const ICodecQuery_Vtbl)).FindAllAsync)(this, kind, category, subtype, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICodecQuery as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICodecSubtypesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VideoFormatDV25(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatDV25", 6usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatDV25)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatDV50(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatDV50", 7usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatDV50)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatDvc(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatDvc", 8usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatDvc)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatDvh1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatDvh1", 9usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatDvh1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatDvhD(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatDvhD", 10usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatDvhD)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatDvsd(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatDvsd", 11usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatDvsd)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatDvsl(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatDvsl", 12usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatDvsl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatH263(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatH263", 13usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatH263)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatH264(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatH264", 14usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatH264)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatH265(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatH265", 15usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatH265)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatH264ES(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatH264ES", 16usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatH264ES)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatHevc(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatHevc", 17usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatHevc)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatHevcES(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatHevcES", 18usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatHevcES)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatM4S2(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatM4S2", 19usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatM4S2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatMjpg(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatMjpg", 20usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatMjpg)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatMP43(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatMP43", 21usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatMP43)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatMP4S(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatMP4S", 22usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatMP4S)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatMP4V(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatMP4V", 23usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatMP4V)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatMpeg2(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatMpeg2", 24usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatMpeg2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatVP80(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatVP80", 25usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatVP80)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatVP90(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatVP90", 26usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatVP90)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatMpg1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatMpg1", 27usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatMpg1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatMss1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatMss1", 28usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatMss1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatMss2(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatMss2", 29usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatMss2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatWmv1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatWmv1", 30usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatWmv1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatWmv2(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatWmv2", 31usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatWmv2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatWmv3(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatWmv3", 32usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatWmv3)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormatWvc1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormatWvc1", 33usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormatWvc1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFormat420O(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "VideoFormat420O", 34usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).VideoFormat420O)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatAac(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatAac", 35usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatAac)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatAdts(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatAdts", 36usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatAdts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatAlac(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatAlac", 37usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatAlac)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatAmrNB(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatAmrNB", 38usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatAmrNB)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatAmrWB(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatAmrWB", 39usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatAmrWB)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatAmrWP(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatAmrWP", 40usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatAmrWP)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatDolbyAC3(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatDolbyAC3", 41usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatDolbyAC3)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatDolbyAC3Spdif(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatDolbyAC3Spdif", 42usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatDolbyAC3Spdif)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatDolbyDDPlus(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatDolbyDDPlus", 43usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatDolbyDDPlus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatDrm(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatDrm", 44usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatDrm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatDts(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatDts", 45usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatDts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatFlac(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatFlac", 46usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatFlac)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatFloat(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatFloat", 47usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatFloat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatMP3(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatMP3", 48usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatMP3)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatMPeg(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatMPeg", 49usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatMPeg)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatMsp1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatMsp1", 50usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatMsp1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatOpus(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatOpus", 51usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatOpus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatPcm(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatPcm", 52usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatPcm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatWmaSpdif(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatWmaSpdif", 53usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatWmaSpdif)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatWMAudioLossless(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatWMAudioLossless", 54usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatWMAudioLossless)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatWMAudioV8(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatWMAudioV8", 55usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatWMAudioV8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFormatWMAudioV9(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecSubtypesStatics", "AudioFormatWMAudioV9", 56usize, |this| ((**(this as *const *const ICodecSubtypesStatics_Vtbl)).AudioFormatWMAudioV9)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            VideoFormatDV25,
+            VideoFormatDV50,
+            VideoFormatDvc,
+            VideoFormatDvh1,
+            VideoFormatDvhD,
+            VideoFormatDvsd,
+            VideoFormatDvsl,
+            VideoFormatH263,
+            VideoFormatH264,
+            VideoFormatH265,
+            VideoFormatH264ES,
+            VideoFormatHevc,
+            VideoFormatHevcES,
+            VideoFormatM4S2,
+            VideoFormatMjpg,
+            VideoFormatMP43,
+            VideoFormatMP4S,
+            VideoFormatMP4V,
+            VideoFormatMpeg2,
+            VideoFormatVP80,
+            VideoFormatVP90,
+            VideoFormatMpg1,
+            VideoFormatMss1,
+            VideoFormatMss2,
+            VideoFormatWmv1,
+            VideoFormatWmv2,
+            VideoFormatWmv3,
+            VideoFormatWvc1,
+            VideoFormat420O,
+            AudioFormatAac,
+            AudioFormatAdts,
+            AudioFormatAlac,
+            AudioFormatAmrNB,
+            AudioFormatAmrWB,
+            AudioFormatAmrWP,
+            AudioFormatDolbyAC3,
+            AudioFormatDolbyAC3Spdif,
+            AudioFormatDolbyDDPlus,
+            AudioFormatDrm,
+            AudioFormatDts,
+            AudioFormatFlac,
+            AudioFormatFloat,
+            AudioFormatMP3,
+            AudioFormatMPeg,
+            AudioFormatMsp1,
+            AudioFormatOpus,
+            AudioFormatPcm,
+            AudioFormatWmaSpdif,
+            AudioFormatWMAudioLossless,
+            AudioFormatWMAudioV8,
+            AudioFormatWMAudioV9,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICodecSubtypesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IDataCue_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCue", "SetData", 6usize, |this| ((**(this as *const *const IDataCue_Vtbl)).SetData)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCue", "Data", 7usize, |this| ((**(this as *const *const IDataCue_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            SetData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetData: 0,
+            #[cfg(feature = "Storage_Streams")]
+            Data,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Data: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataCue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IDataCue2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCue2", "Properties", 6usize, |this| ((**(this as *const *const IDataCue2_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataCue2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFaceDetectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResultFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectedEventArgs", "ResultFrame", 6usize, |this| ((**(this as *const *const IFaceDetectedEventArgs_Vtbl)).ResultFrame)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ResultFrame }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaceDetectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IFaceDetectionEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectionEffect", "SetEnabled", 6usize, |this| ((**(this as *const *const IFaceDetectionEffect_Vtbl)).SetEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectionEffect", "Enabled", 7usize, |this| ((**(this as *const *const IFaceDetectionEffect_Vtbl)).Enabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredDetectionInterval(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectionEffect", "SetDesiredDetectionInterval", 8usize, |this| ((**(this as *const *const IFaceDetectionEffect_Vtbl)).SetDesiredDetectionInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredDetectionInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectionEffect", "DesiredDetectionInterval", 9usize, |this| ((**(this as *const *const IFaceDetectionEffect_Vtbl)).DesiredDetectionInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FaceDetected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectionEffect", "FaceDetected", 10usize, |this| ((**(this as *const *const IFaceDetectionEffect_Vtbl)).FaceDetected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFaceDetected(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectionEffect", "RemoveFaceDetected", 11usize, |this| ((**(this as *const *const IFaceDetectionEffect_Vtbl)).RemoveFaceDetected)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetEnabled,
+            Enabled,
+            SetDesiredDetectionInterval,
+            DesiredDetectionInterval,
+            FaceDetected,
+            RemoveFaceDetected,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaceDetectionEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects"))]
+impl windows_core::imp::ProxyVtable for IFaceDetectionEffectDefinition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDetectionMode(this: *mut core::ffi::c_void, value: FaceDetectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectionEffectDefinition", "SetDetectionMode", 6usize, |this| ((**(this as *const *const IFaceDetectionEffectDefinition_Vtbl)).SetDetectionMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetectionMode(this: *mut core::ffi::c_void, result__: *mut FaceDetectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectionEffectDefinition", "DetectionMode", 7usize, |this| ((**(this as *const *const IFaceDetectionEffectDefinition_Vtbl)).DetectionMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSynchronousDetectionEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectionEffectDefinition", "SetSynchronousDetectionEnabled", 8usize, |this| ((**(this as *const *const IFaceDetectionEffectDefinition_Vtbl)).SetSynchronousDetectionEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SynchronousDetectionEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectionEffectDefinition", "SynchronousDetectionEnabled", 9usize, |this| ((**(this as *const *const IFaceDetectionEffectDefinition_Vtbl)).SynchronousDetectionEnabled)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDetectionMode,
+            DetectionMode,
+            SetSynchronousDetectionEnabled,
+            SynchronousDetectionEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaceDetectionEffectDefinition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_FaceAnalysis"))]
+impl windows_core::imp::ProxyVtable for IFaceDetectionEffectFrame_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_FaceAnalysis"))]
+        unsafe extern "system" fn DetectedFaces(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectionEffectFrame", "DetectedFaces", 6usize, |this| ((**(this as *const *const IFaceDetectionEffectFrame_Vtbl)).DetectedFaces)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_FaceAnalysis"))]
+            DetectedFaces,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_FaceAnalysis")))]
+            DetectedFaces: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaceDetectionEffectFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHighDynamicRangeControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHighDynamicRangeControl", "SetEnabled", 6usize, |this| ((**(this as *const *const IHighDynamicRangeControl_Vtbl)).SetEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHighDynamicRangeControl", "Enabled", 7usize, |this| ((**(this as *const *const IHighDynamicRangeControl_Vtbl)).Enabled)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetEnabled, Enabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHighDynamicRangeControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Devices_Core"))]
+impl windows_core::imp::ProxyVtable for IHighDynamicRangeOutput_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Certainty(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHighDynamicRangeOutput", "Certainty", 6usize, |this| ((**(this as *const *const IHighDynamicRangeOutput_Vtbl)).Certainty)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Devices_Core"))]
+        unsafe extern "system" fn FrameControllers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHighDynamicRangeOutput", "FrameControllers", 7usize, |this| ((**(this as *const *const IHighDynamicRangeOutput_Vtbl)).FrameControllers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Certainty,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Devices_Core"))]
+            FrameControllers,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Devices_Core")))]
+            FrameControllers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHighDynamicRangeOutput as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for IImageCue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut TimedTextPoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageCue", "Position", 6usize, |this| ((**(this as *const *const IImageCue_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, value: TimedTextPoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageCue", "SetPosition", 7usize, |this| ((**(this as *const *const IImageCue_Vtbl)).SetPosition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Extent(this: *mut core::ffi::c_void, result__: *mut TimedTextSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageCue", "Extent", 8usize, |this| ((**(this as *const *const IImageCue_Vtbl)).Extent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtent(this: *mut core::ffi::c_void, value: TimedTextSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageCue", "SetExtent", 9usize, |this| ((**(this as *const *const IImageCue_Vtbl)).SetExtent)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SetSoftwareBitmap(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageCue", "SetSoftwareBitmap", 10usize, |this| ((**(this as *const *const IImageCue_Vtbl)).SetSoftwareBitmap)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SoftwareBitmap(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageCue", "SoftwareBitmap", 11usize, |this| ((**(this as *const *const IImageCue_Vtbl)).SoftwareBitmap)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Position,
+            SetPosition,
+            Extent,
+            SetExtent,
+            #[cfg(feature = "Graphics_Imaging")]
+            SetSoftwareBitmap,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SetSoftwareBitmap: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            SoftwareBitmap,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SoftwareBitmap: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageCue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IInitializeMediaStreamSourceRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInitializeMediaStreamSourceRequestedEventArgs", "Source", 6usize, |this| ((**(this as *const *const IInitializeMediaStreamSourceRequestedEventArgs_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn RandomAccessStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInitializeMediaStreamSourceRequestedEventArgs", "RandomAccessStream", 7usize, |this| ((**(this as *const *const IInitializeMediaStreamSourceRequestedEventArgs_Vtbl)).RandomAccessStream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInitializeMediaStreamSourceRequestedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IInitializeMediaStreamSourceRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Source,
+            #[cfg(feature = "Storage_Streams")]
+            RandomAccessStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            RandomAccessStream: 0,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInitializeMediaStreamSourceRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for ILowLightFusionResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn Frame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLightFusionResult", "Frame", 6usize, |this| ((**(this as *const *const ILowLightFusionResult_Vtbl)).Frame)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Imaging")]
+            Frame,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            Frame: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILowLightFusionResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ILowLightFusionStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+        unsafe extern "system" fn SupportedBitmapPixelFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLightFusionStatics", "SupportedBitmapPixelFormats", 6usize, |this| ((**(this as *const *const ILowLightFusionStatics_Vtbl)).SupportedBitmapPixelFormats)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSupportedFrameCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLightFusionStatics", "MaxSupportedFrameCount", 7usize, |this| ((**(this as *const *const ILowLightFusionStatics_Vtbl)).MaxSupportedFrameCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+        unsafe extern "system" fn FuseAsync(this: *mut core::ffi::c_void, frameset: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLightFusionStatics", "FuseAsync", 8usize, |this| ((**(this as *const *const ILowLightFusionStatics_Vtbl)).FuseAsync)(this, frameset, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+            SupportedBitmapPixelFormats,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics_Imaging")))]
+            SupportedBitmapPixelFormats: 0,
+            MaxSupportedFrameCount,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+            FuseAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics_Imaging")))]
+            FuseAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILowLightFusionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaBinder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Binding(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBinder", "Binding", 6usize, |this| ((**(this as *const *const IMediaBinder_Vtbl)).Binding)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBinding(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBinder", "RemoveBinding", 7usize, |this| ((**(this as *const *const IMediaBinder_Vtbl)).RemoveBinding)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Token(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBinder", "Token", 8usize, |this| ((**(this as *const *const IMediaBinder_Vtbl)).Token)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetToken(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBinder", "SetToken", 9usize, |this| ((**(this as *const *const IMediaBinder_Vtbl)).SetToken)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBinder", "Source", 10usize, |this| ((**(this as *const *const IMediaBinder_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Binding, RemoveBinding, Token, SetToken, Source }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaBinder as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMediaBindingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Canceled(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBindingEventArgs", "Canceled", 6usize, |this| ((**(this as *const *const IMediaBindingEventArgs_Vtbl)).Canceled)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCanceled(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBindingEventArgs", "RemoveCanceled", 7usize, |this| ((**(this as *const *const IMediaBindingEventArgs_Vtbl)).RemoveCanceled)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaBinder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBindingEventArgs", "MediaBinder", 8usize, |this| ((**(this as *const *const IMediaBindingEventArgs_Vtbl)).MediaBinder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBindingEventArgs", "GetDeferral", 9usize, |this| ((**(this as *const *const IMediaBindingEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUri(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBindingEventArgs", "SetUri", 10usize, |this| ((**(this as *const *const IMediaBindingEventArgs_Vtbl)).SetUri)(this, uri), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetStream(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, contenttype: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBindingEventArgs", "SetStream", 11usize, |this| ((**(this as *const *const IMediaBindingEventArgs_Vtbl)).SetStream)(this, stream, contenttype), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetStreamReference(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, contenttype: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBindingEventArgs", "SetStreamReference", 12usize, |this| ((**(this as *const *const IMediaBindingEventArgs_Vtbl)).SetStreamReference)(this, stream, contenttype), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Canceled,
+            RemoveCanceled,
+            MediaBinder,
+            GetDeferral,
+            SetUri,
+            #[cfg(feature = "Storage_Streams")]
+            SetStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetStream: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetStreamReference,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetStreamReference: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaBindingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Streaming_Adaptive", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IMediaBindingEventArgs2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Streaming_Adaptive")]
+        unsafe extern "system" fn SetAdaptiveMediaSource(this: *mut core::ffi::c_void, mediasource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBindingEventArgs2", "SetAdaptiveMediaSource", 6usize, |this| ((**(this as *const *const IMediaBindingEventArgs2_Vtbl)).SetAdaptiveMediaSource)(this, mediasource), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SetStorageFile(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBindingEventArgs2", "SetStorageFile", 7usize, |this| ((**(this as *const *const IMediaBindingEventArgs2_Vtbl)).SetStorageFile)(this, file), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Streaming_Adaptive")]
+            SetAdaptiveMediaSource,
+            #[cfg(not(feature = "Media_Streaming_Adaptive"))]
+            SetAdaptiveMediaSource: 0,
+            #[cfg(feature = "Storage")]
+            SetStorageFile,
+            #[cfg(not(feature = "Storage"))]
+            SetStorageFile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaBindingEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking_BackgroundTransfer")]
+impl windows_core::imp::ProxyVtable for IMediaBindingEventArgs3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Networking_BackgroundTransfer")]
+        unsafe extern "system" fn SetDownloadOperation(this: *mut core::ffi::c_void, downloadoperation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBindingEventArgs3", "SetDownloadOperation", 6usize, |this| ((**(this as *const *const IMediaBindingEventArgs3_Vtbl)).SetDownloadOperation)(this, downloadoperation), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Networking_BackgroundTransfer")]
+            SetDownloadOperation,
+            #[cfg(not(feature = "Networking_BackgroundTransfer"))]
+            SetDownloadOperation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaBindingEventArgs3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaCue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCue", "SetStartTime", 6usize, |this| ((**(this as *const *const IMediaCue_Vtbl)).SetStartTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCue", "StartTime", 7usize, |this| ((**(this as *const *const IMediaCue_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCue", "SetDuration", 8usize, |this| ((**(this as *const *const IMediaCue_Vtbl)).SetDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCue", "Duration", 9usize, |this| ((**(this as *const *const IMediaCue_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCue", "SetId", 10usize, |this| ((**(this as *const *const IMediaCue_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCue", "Id", 11usize, |this| ((**(this as *const *const IMediaCue_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetStartTime, StartTime, SetDuration, Duration, SetId, Id }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaCueEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCueEventArgs", "Cue", 6usize, |this| ((**(this as *const *const IMediaCueEventArgs_Vtbl)).Cue)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCueEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaSource_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Playback"))]
+impl windows_core::imp::ProxyVtable for IMediaSource2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenOperationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource2", "OpenOperationCompleted", 6usize, |this| ((**(this as *const *const IMediaSource2_Vtbl)).OpenOperationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOpenOperationCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource2", "RemoveOpenOperationCompleted", 7usize, |this| ((**(this as *const *const IMediaSource2_Vtbl)).RemoveOpenOperationCompleted)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CustomProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource2", "CustomProperties", 8usize, |this| ((**(this as *const *const IMediaSource2_Vtbl)).CustomProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource2", "Duration", 9usize, |this| ((**(this as *const *const IMediaSource2_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOpen(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource2", "IsOpen", 10usize, |this| ((**(this as *const *const IMediaSource2_Vtbl)).IsOpen)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ExternalTimedTextSources(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource2", "ExternalTimedTextSources", 11usize, |this| ((**(this as *const *const IMediaSource2_Vtbl)).ExternalTimedTextSources)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ExternalTimedMetadataTracks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource2", "ExternalTimedMetadataTracks", 12usize, |this| ((**(this as *const *const IMediaSource2_Vtbl)).ExternalTimedMetadataTracks)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OpenOperationCompleted,
+            RemoveOpenOperationCompleted,
+            #[cfg(feature = "Foundation_Collections")]
+            CustomProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CustomProperties: 0,
+            Duration,
+            IsOpen,
+            #[cfg(feature = "Foundation_Collections")]
+            ExternalTimedTextSources,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ExternalTimedTextSources: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ExternalTimedMetadataTracks,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ExternalTimedMetadataTracks: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSource2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Playback"))]
+impl windows_core::imp::ProxyVtable for IMediaSource3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource3", "StateChanged", 6usize, |this| ((**(this as *const *const IMediaSource3_Vtbl)).StateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource3", "RemoveStateChanged", 7usize, |this| ((**(this as *const *const IMediaSource3_Vtbl)).RemoveStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut MediaSourceState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource3", "State", 8usize, |this| ((**(this as *const *const IMediaSource3_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource3", "Reset", 9usize, |this| ((**(this as *const *const IMediaSource3_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StateChanged, RemoveStateChanged, State, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSource3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Playback", feature = "Media_Streaming_Adaptive"))]
+impl windows_core::imp::ProxyVtable for IMediaSource4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Streaming_Adaptive")]
+        unsafe extern "system" fn AdaptiveMediaSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource4", "AdaptiveMediaSource", 6usize, |this| ((**(this as *const *const IMediaSource4_Vtbl)).AdaptiveMediaSource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaStreamSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource4", "MediaStreamSource", 7usize, |this| ((**(this as *const *const IMediaSource4_Vtbl)).MediaStreamSource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MseStreamSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource4", "MseStreamSource", 8usize, |this| ((**(this as *const *const IMediaSource4_Vtbl)).MseStreamSource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource4", "Uri", 9usize, |this| ((**(this as *const *const IMediaSource4_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource4", "OpenAsync", 10usize, |this| ((**(this as *const *const IMediaSource4_Vtbl)).OpenAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Streaming_Adaptive")]
+            AdaptiveMediaSource,
+            #[cfg(not(feature = "Media_Streaming_Adaptive"))]
+            AdaptiveMediaSource: 0,
+            MediaStreamSource,
+            MseStreamSource,
+            Uri,
+            OpenAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSource4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking_BackgroundTransfer")]
+impl windows_core::imp::ProxyVtable for IMediaSource5_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Networking_BackgroundTransfer")]
+        unsafe extern "system" fn DownloadOperation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSource5", "DownloadOperation", 6usize, |this| ((**(this as *const *const IMediaSource5_Vtbl)).DownloadOperation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Networking_BackgroundTransfer")]
+            DownloadOperation,
+            #[cfg(not(feature = "Networking_BackgroundTransfer"))]
+            DownloadOperation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSource5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaSourceAppServiceConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeMediaStreamSourceRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAppServiceConnection", "InitializeMediaStreamSourceRequested", 6usize, |this| ((**(this as *const *const IMediaSourceAppServiceConnection_Vtbl)).InitializeMediaStreamSourceRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveInitializeMediaStreamSourceRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAppServiceConnection", "RemoveInitializeMediaStreamSourceRequested", 7usize, |this| ((**(this as *const *const IMediaSourceAppServiceConnection_Vtbl)).RemoveInitializeMediaStreamSourceRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAppServiceConnection", "Start", 8usize, |this| ((**(this as *const *const IMediaSourceAppServiceConnection_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeMediaStreamSourceRequested,
+            RemoveInitializeMediaStreamSourceRequested,
+            Start,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSourceAppServiceConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_AppService")]
+impl windows_core::imp::ProxyVtable for IMediaSourceAppServiceConnectionFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_AppService")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, appserviceconnection: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceAppServiceConnectionFactory", "Create", 6usize, |this| ((**(this as *const *const IMediaSourceAppServiceConnectionFactory_Vtbl)).Create)(this, appserviceconnection, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_AppService")]
+            Create,
+            #[cfg(not(feature = "ApplicationModel_AppService"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSourceAppServiceConnectionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaSourceError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceError", "ExtendedError", 6usize, |this| ((**(this as *const *const IMediaSourceError_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSourceError as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaSourceOpenOperationCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceOpenOperationCompletedEventArgs", "Error", 6usize, |this| ((**(this as *const *const IMediaSourceOpenOperationCompletedEventArgs_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Error }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSourceOpenOperationCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaSourceStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OldState(this: *mut core::ffi::c_void, result__: *mut MediaSourceState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceStateChangedEventArgs", "OldState", 6usize, |this| ((**(this as *const *const IMediaSourceStateChangedEventArgs_Vtbl)).OldState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewState(this: *mut core::ffi::c_void, result__: *mut MediaSourceState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceStateChangedEventArgs", "NewState", 7usize, |this| ((**(this as *const *const IMediaSourceStateChangedEventArgs_Vtbl)).NewState)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OldState, NewState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSourceStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Streaming_Adaptive", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMediaSourceStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Streaming_Adaptive")]
+        unsafe extern "system" fn CreateFromAdaptiveMediaSource(this: *mut core::ffi::c_void, mediasource: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceStatics", "CreateFromAdaptiveMediaSource", 6usize, |this| ((**(this as *const *const IMediaSourceStatics_Vtbl)).CreateFromAdaptiveMediaSource)(this, mediasource, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromMediaStreamSource(this: *mut core::ffi::c_void, mediasource: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceStatics", "CreateFromMediaStreamSource", 7usize, |this| ((**(this as *const *const IMediaSourceStatics_Vtbl)).CreateFromMediaStreamSource)(this, mediasource, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromMseStreamSource(this: *mut core::ffi::c_void, mediasource: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceStatics", "CreateFromMseStreamSource", 8usize, |this| ((**(this as *const *const IMediaSourceStatics_Vtbl)).CreateFromMseStreamSource)(this, mediasource, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromIMediaSource(this: *mut core::ffi::c_void, mediasource: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceStatics", "CreateFromIMediaSource", 9usize, |this| ((**(this as *const *const IMediaSourceStatics_Vtbl)).CreateFromIMediaSource)(this, mediasource, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CreateFromStorageFile(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceStatics", "CreateFromStorageFile", 10usize, |this| ((**(this as *const *const IMediaSourceStatics_Vtbl)).CreateFromStorageFile)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromStream(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, contenttype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceStatics", "CreateFromStream", 11usize, |this| ((**(this as *const *const IMediaSourceStatics_Vtbl)).CreateFromStream)(this, stream, contenttype, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromStreamReference(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, contenttype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceStatics", "CreateFromStreamReference", 12usize, |this| ((**(this as *const *const IMediaSourceStatics_Vtbl)).CreateFromStreamReference)(this, stream, contenttype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromUri(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceStatics", "CreateFromUri", 13usize, |this| ((**(this as *const *const IMediaSourceStatics_Vtbl)).CreateFromUri)(this, uri, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Streaming_Adaptive")]
+            CreateFromAdaptiveMediaSource,
+            #[cfg(not(feature = "Media_Streaming_Adaptive"))]
+            CreateFromAdaptiveMediaSource: 0,
+            CreateFromMediaStreamSource,
+            CreateFromMseStreamSource,
+            CreateFromIMediaSource,
+            #[cfg(feature = "Storage")]
+            CreateFromStorageFile,
+            #[cfg(not(feature = "Storage"))]
+            CreateFromStorageFile: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromStream: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromStreamReference,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromStreamReference: 0,
+            CreateFromUri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSourceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaSourceStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromMediaBinder(this: *mut core::ffi::c_void, binder: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceStatics2", "CreateFromMediaBinder", 6usize, |this| ((**(this as *const *const IMediaSourceStatics2_Vtbl)).CreateFromMediaBinder)(this, binder, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromMediaBinder }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSourceStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Capture_Frames")]
+impl windows_core::imp::ProxyVtable for IMediaSourceStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Capture_Frames")]
+        unsafe extern "system" fn CreateFromMediaFrameSource(this: *mut core::ffi::c_void, framesource: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceStatics3", "CreateFromMediaFrameSource", 6usize, |this| ((**(this as *const *const IMediaSourceStatics3_Vtbl)).CreateFromMediaFrameSource)(this, framesource, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Capture_Frames")]
+            CreateFromMediaFrameSource,
+            #[cfg(not(feature = "Media_Capture_Frames"))]
+            CreateFromMediaFrameSource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSourceStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking_BackgroundTransfer")]
+impl windows_core::imp::ProxyVtable for IMediaSourceStatics4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Networking_BackgroundTransfer")]
+        unsafe extern "system" fn CreateFromDownloadOperation(this: *mut core::ffi::c_void, downloadoperation: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSourceStatics4", "CreateFromDownloadOperation", 6usize, |this| ((**(this as *const *const IMediaSourceStatics4_Vtbl)).CreateFromDownloadOperation)(this, downloadoperation, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Networking_BackgroundTransfer")]
+            CreateFromDownloadOperation,
+            #[cfg(not(feature = "Networking_BackgroundTransfer"))]
+            CreateFromDownloadOperation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSourceStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSelected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamDescriptor", "IsSelected", 6usize, |this| ((**(this as *const *const IMediaStreamDescriptor_Vtbl)).IsSelected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamDescriptor", "SetName", 7usize, |this| ((**(this as *const *const IMediaStreamDescriptor_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamDescriptor", "Name", 8usize, |this| ((**(this as *const *const IMediaStreamDescriptor_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamDescriptor", "SetLanguage", 9usize, |this| ((**(this as *const *const IMediaStreamDescriptor_Vtbl)).SetLanguage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamDescriptor", "Language", 10usize, |this| ((**(this as *const *const IMediaStreamDescriptor_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSelected, SetName, Name, SetLanguage, Language }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamDescriptor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamDescriptor2", "SetLabel", 6usize, |this| ((**(this as *const *const IMediaStreamDescriptor2_Vtbl)).SetLabel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamDescriptor2", "Label", 7usize, |this| ((**(this as *const *const IMediaStreamDescriptor2_Vtbl)).Label)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetLabel, Label }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamDescriptor2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMediaStreamSample_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Processed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSample", "Processed", 6usize, |this| ((**(this as *const *const IMediaStreamSample_Vtbl)).Processed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProcessed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSample", "RemoveProcessed", 7usize, |this| ((**(this as *const *const IMediaStreamSample_Vtbl)).RemoveProcessed)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Buffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSample", "Buffer", 8usize, |this| ((**(this as *const *const IMediaStreamSample_Vtbl)).Buffer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSample", "Timestamp", 9usize, |this| ((**(this as *const *const IMediaStreamSample_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ExtendedProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSample", "ExtendedProperties", 10usize, |this| ((**(this as *const *const IMediaStreamSample_Vtbl)).ExtendedProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Protection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSample", "Protection", 11usize, |this| ((**(this as *const *const IMediaStreamSample_Vtbl)).Protection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDecodeTimestamp(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSample", "SetDecodeTimestamp", 12usize, |this| ((**(this as *const *const IMediaStreamSample_Vtbl)).SetDecodeTimestamp)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecodeTimestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSample", "DecodeTimestamp", 13usize, |this| ((**(this as *const *const IMediaStreamSample_Vtbl)).DecodeTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSample", "SetDuration", 14usize, |this| ((**(this as *const *const IMediaStreamSample_Vtbl)).SetDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSample", "Duration", 15usize, |this| ((**(this as *const *const IMediaStreamSample_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeyFrame(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSample", "SetKeyFrame", 16usize, |this| ((**(this as *const *const IMediaStreamSample_Vtbl)).SetKeyFrame)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyFrame(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSample", "KeyFrame", 17usize, |this| ((**(this as *const *const IMediaStreamSample_Vtbl)).KeyFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDiscontinuous(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSample", "SetDiscontinuous", 18usize, |this| ((**(this as *const *const IMediaStreamSample_Vtbl)).SetDiscontinuous)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Discontinuous(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSample", "Discontinuous", 19usize, |this| ((**(this as *const *const IMediaStreamSample_Vtbl)).Discontinuous)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Processed,
+            RemoveProcessed,
+            #[cfg(feature = "Storage_Streams")]
+            Buffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Buffer: 0,
+            Timestamp,
+            #[cfg(feature = "Foundation_Collections")]
+            ExtendedProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ExtendedProperties: 0,
+            Protection,
+            SetDecodeTimestamp,
+            DecodeTimestamp,
+            SetDuration,
+            Duration,
+            SetKeyFrame,
+            KeyFrame,
+            SetDiscontinuous,
+            Discontinuous,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSample as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX_Direct3D11")]
+impl windows_core::imp::ProxyVtable for IMediaStreamSample2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn Direct3D11Surface(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSample2", "Direct3D11Surface", 6usize, |this| ((**(this as *const *const IMediaStreamSample2_Vtbl)).Direct3D11Surface)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            Direct3D11Surface,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            Direct3D11Surface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSample2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamSampleProtectionProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetKeyIdentifier(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSampleProtectionProperties", "SetKeyIdentifier", 6usize, |this| ((**(this as *const *const IMediaStreamSampleProtectionProperties_Vtbl)).SetKeyIdentifier)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeyIdentifier(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSampleProtectionProperties", "GetKeyIdentifier", 7usize, |this| ((**(this as *const *const IMediaStreamSampleProtectionProperties_Vtbl)).GetKeyIdentifier)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitializationVector(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSampleProtectionProperties", "SetInitializationVector", 8usize, |this| ((**(this as *const *const IMediaStreamSampleProtectionProperties_Vtbl)).SetInitializationVector)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInitializationVector(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSampleProtectionProperties", "GetInitializationVector", 9usize, |this| ((**(this as *const *const IMediaStreamSampleProtectionProperties_Vtbl)).GetInitializationVector)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubSampleMapping(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSampleProtectionProperties", "SetSubSampleMapping", 10usize, |this| ((**(this as *const *const IMediaStreamSampleProtectionProperties_Vtbl)).SetSubSampleMapping)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubSampleMapping(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSampleProtectionProperties", "GetSubSampleMapping", 11usize, |this| ((**(this as *const *const IMediaStreamSampleProtectionProperties_Vtbl)).GetSubSampleMapping)(this, value_array_size, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetKeyIdentifier,
+            GetKeyIdentifier,
+            SetInitializationVector,
+            GetInitializationVector,
+            SetSubSampleMapping,
+            GetSubSampleMapping,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSampleProtectionProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMediaStreamSampleStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromBuffer(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, timestamp: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSampleStatics", "CreateFromBuffer", 6usize, |this| ((**(this as *const *const IMediaStreamSampleStatics_Vtbl)).CreateFromBuffer)(this, buffer, timestamp, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromStreamAsync(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, count: u32, timestamp: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSampleStatics", "CreateFromStreamAsync", 7usize, |this| ((**(this as *const *const IMediaStreamSampleStatics_Vtbl)).CreateFromStreamAsync)(this, stream, count, timestamp, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromBuffer: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromStreamAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSampleStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX_Direct3D11")]
+impl windows_core::imp::ProxyVtable for IMediaStreamSampleStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn CreateFromDirect3D11Surface(this: *mut core::ffi::c_void, surface: *mut core::ffi::c_void, timestamp: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSampleStatics2", "CreateFromDirect3D11Surface", 6usize, |this| ((**(this as *const *const IMediaStreamSampleStatics2_Vtbl)).CreateFromDirect3D11Surface)(this, surface, timestamp, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            CreateFromDirect3D11Surface,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            CreateFromDirect3D11Surface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSampleStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Protection", feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMediaStreamSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "Closed", 6usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).Closed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "RemoveClosed", 7usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).RemoveClosed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Starting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "Starting", 8usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).Starting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStarting(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "RemoveStarting", 9usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).RemoveStarting)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Paused(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "Paused", 10usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).Paused)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePaused(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "RemovePaused", 11usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).RemovePaused)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SampleRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "SampleRequested", 12usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).SampleRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSampleRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "RemoveSampleRequested", 13usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).RemoveSampleRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwitchStreamsRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "SwitchStreamsRequested", 14usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).SwitchStreamsRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSwitchStreamsRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "RemoveSwitchStreamsRequested", 15usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).RemoveSwitchStreamsRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyError(this: *mut core::ffi::c_void, errorstatus: MediaStreamSourceErrorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "NotifyError", 16usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).NotifyError)(this, errorstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStreamDescriptor(this: *mut core::ffi::c_void, descriptor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "AddStreamDescriptor", 17usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).AddStreamDescriptor)(this, descriptor), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Protection")]
+        unsafe extern "system" fn SetMediaProtectionManager(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "SetMediaProtectionManager", 18usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).SetMediaProtectionManager)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Protection")]
+        unsafe extern "system" fn MediaProtectionManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "MediaProtectionManager", 19usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).MediaProtectionManager)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "SetDuration", 20usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).SetDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "Duration", 21usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanSeek(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "SetCanSeek", 22usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).SetCanSeek)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanSeek(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "CanSeek", 23usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).CanSeek)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferTime(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "SetBufferTime", 24usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).SetBufferTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BufferTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "BufferTime", 25usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).BufferTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferedRange(this: *mut core::ffi::c_void, startoffset: super::super::Foundation::TimeSpan, endoffset: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "SetBufferedRange", 26usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).SetBufferedRange)(this, startoffset, endoffset), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_FileProperties")]
+        unsafe extern "system" fn MusicProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "MusicProperties", 27usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).MusicProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_FileProperties")]
+        unsafe extern "system" fn VideoProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "VideoProperties", 28usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).VideoProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetThumbnail(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "SetThumbnail", 29usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).SetThumbnail)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "Thumbnail", 30usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddProtectionKey(this: *mut core::ffi::c_void, streamdescriptor: *mut core::ffi::c_void, keyIdentifier_array_size: u32, keyidentifier: *const u8, licenseData_array_size: u32, licensedata: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource", "AddProtectionKey", 31usize, |this| ((**(this as *const *const IMediaStreamSource_Vtbl)).AddProtectionKey)(this, streamdescriptor, keyIdentifier_array_size, keyidentifier, licenseData_array_size, licensedata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Closed,
+            RemoveClosed,
+            Starting,
+            RemoveStarting,
+            Paused,
+            RemovePaused,
+            SampleRequested,
+            RemoveSampleRequested,
+            SwitchStreamsRequested,
+            RemoveSwitchStreamsRequested,
+            NotifyError,
+            AddStreamDescriptor,
+            #[cfg(feature = "Media_Protection")]
+            SetMediaProtectionManager,
+            #[cfg(not(feature = "Media_Protection"))]
+            SetMediaProtectionManager: 0,
+            #[cfg(feature = "Media_Protection")]
+            MediaProtectionManager,
+            #[cfg(not(feature = "Media_Protection"))]
+            MediaProtectionManager: 0,
+            SetDuration,
+            Duration,
+            SetCanSeek,
+            CanSeek,
+            SetBufferTime,
+            BufferTime,
+            SetBufferedRange,
+            #[cfg(feature = "Storage_FileProperties")]
+            MusicProperties,
+            #[cfg(not(feature = "Storage_FileProperties"))]
+            MusicProperties: 0,
+            #[cfg(feature = "Storage_FileProperties")]
+            VideoProperties,
+            #[cfg(not(feature = "Storage_FileProperties"))]
+            VideoProperties: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetThumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetThumbnail: 0,
+            #[cfg(feature = "Storage_Streams")]
+            Thumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Thumbnail: 0,
+            AddProtectionKey,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Protection", feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMediaStreamSource2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SampleRendered(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource2", "SampleRendered", 6usize, |this| ((**(this as *const *const IMediaStreamSource2_Vtbl)).SampleRendered)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSampleRendered(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource2", "RemoveSampleRendered", 7usize, |this| ((**(this as *const *const IMediaStreamSource2_Vtbl)).RemoveSampleRendered)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SampleRendered, RemoveSampleRendered }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSource2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Protection", feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMediaStreamSource3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMaxSupportedPlaybackRate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource3", "SetMaxSupportedPlaybackRate", 6usize, |this| ((**(this as *const *const IMediaStreamSource3_Vtbl)).SetMaxSupportedPlaybackRate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSupportedPlaybackRate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource3", "MaxSupportedPlaybackRate", 7usize, |this| ((**(this as *const *const IMediaStreamSource3_Vtbl)).MaxSupportedPlaybackRate)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMaxSupportedPlaybackRate, MaxSupportedPlaybackRate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSource3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Protection", feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMediaStreamSource4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIsLive(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource4", "SetIsLive", 6usize, |this| ((**(this as *const *const IMediaStreamSource4_Vtbl)).SetIsLive)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSource4", "IsLive", 7usize, |this| ((**(this as *const *const IMediaStreamSource4_Vtbl)).IsLive)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetIsLive, IsLive }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSource4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamSourceClosedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceClosedEventArgs", "Request", 6usize, |this| ((**(this as *const *const IMediaStreamSourceClosedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSourceClosedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamSourceClosedRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut MediaStreamSourceClosedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceClosedRequest", "Reason", 6usize, |this| ((**(this as *const *const IMediaStreamSourceClosedRequest_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSourceClosedRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamSourceFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromDescriptor(this: *mut core::ffi::c_void, descriptor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceFactory", "CreateFromDescriptor", 6usize, |this| ((**(this as *const *const IMediaStreamSourceFactory_Vtbl)).CreateFromDescriptor)(this, descriptor, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromDescriptors(this: *mut core::ffi::c_void, descriptor: *mut core::ffi::c_void, descriptor2: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceFactory", "CreateFromDescriptors", 7usize, |this| ((**(this as *const *const IMediaStreamSourceFactory_Vtbl)).CreateFromDescriptors)(this, descriptor, descriptor2, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromDescriptor, CreateFromDescriptors }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSourceFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamSourceSampleRenderedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SampleLag(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceSampleRenderedEventArgs", "SampleLag", 6usize, |this| ((**(this as *const *const IMediaStreamSourceSampleRenderedEventArgs_Vtbl)).SampleLag)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SampleLag }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSourceSampleRenderedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamSourceSampleRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StreamDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceSampleRequest", "StreamDescriptor", 6usize, |this| ((**(this as *const *const IMediaStreamSourceSampleRequest_Vtbl)).StreamDescriptor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceSampleRequest", "GetDeferral", 7usize, |this| ((**(this as *const *const IMediaStreamSourceSampleRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSample(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceSampleRequest", "SetSample", 8usize, |this| ((**(this as *const *const IMediaStreamSourceSampleRequest_Vtbl)).SetSample)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sample(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceSampleRequest", "Sample", 9usize, |this| ((**(this as *const *const IMediaStreamSourceSampleRequest_Vtbl)).Sample)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportSampleProgress(this: *mut core::ffi::c_void, progress: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceSampleRequest", "ReportSampleProgress", 10usize, |this| ((**(this as *const *const IMediaStreamSourceSampleRequest_Vtbl)).ReportSampleProgress)(this, progress), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StreamDescriptor,
+            GetDeferral,
+            SetSample,
+            Sample,
+            ReportSampleProgress,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSourceSampleRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamSourceSampleRequestDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceSampleRequestDeferral", "Complete", 6usize, |this| ((**(this as *const *const IMediaStreamSourceSampleRequestDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSourceSampleRequestDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamSourceSampleRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceSampleRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const IMediaStreamSourceSampleRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSourceSampleRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamSourceStartingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceStartingEventArgs", "Request", 6usize, |this| ((**(this as *const *const IMediaStreamSourceStartingEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSourceStartingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamSourceStartingRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartPosition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceStartingRequest", "StartPosition", 6usize, |this| ((**(this as *const *const IMediaStreamSourceStartingRequest_Vtbl)).StartPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceStartingRequest", "GetDeferral", 7usize, |this| ((**(this as *const *const IMediaStreamSourceStartingRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActualStartPosition(this: *mut core::ffi::c_void, position: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceStartingRequest", "SetActualStartPosition", 8usize, |this| ((**(this as *const *const IMediaStreamSourceStartingRequest_Vtbl)).SetActualStartPosition)(this, position), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartPosition, GetDeferral, SetActualStartPosition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSourceStartingRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamSourceStartingRequestDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceStartingRequestDeferral", "Complete", 6usize, |this| ((**(this as *const *const IMediaStreamSourceStartingRequestDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSourceStartingRequestDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamSourceSwitchStreamsRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OldStreamDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceSwitchStreamsRequest", "OldStreamDescriptor", 6usize, |this| ((**(this as *const *const IMediaStreamSourceSwitchStreamsRequest_Vtbl)).OldStreamDescriptor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewStreamDescriptor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceSwitchStreamsRequest", "NewStreamDescriptor", 7usize, |this| ((**(this as *const *const IMediaStreamSourceSwitchStreamsRequest_Vtbl)).NewStreamDescriptor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceSwitchStreamsRequest", "GetDeferral", 8usize, |this| ((**(this as *const *const IMediaStreamSourceSwitchStreamsRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OldStreamDescriptor, NewStreamDescriptor, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSourceSwitchStreamsRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamSourceSwitchStreamsRequestDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceSwitchStreamsRequestDeferral", "Complete", 6usize, |this| ((**(this as *const *const IMediaStreamSourceSwitchStreamsRequestDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSourceSwitchStreamsRequestDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStreamSourceSwitchStreamsRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamSourceSwitchStreamsRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const IMediaStreamSourceSwitchStreamsRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamSourceSwitchStreamsRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaTrack_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTrack", "Id", 6usize, |this| ((**(this as *const *const IMediaTrack_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTrack", "Language", 7usize, |this| ((**(this as *const *const IMediaTrack_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrackKind(this: *mut core::ffi::c_void, result__: *mut MediaTrackKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTrack", "TrackKind", 8usize, |this| ((**(this as *const *const IMediaTrack_Vtbl)).TrackKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTrack", "SetLabel", 9usize, |this| ((**(this as *const *const IMediaTrack_Vtbl)).SetLabel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTrack", "Label", 10usize, |this| ((**(this as *const *const IMediaTrack_Vtbl)).Label)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Language, TrackKind, SetLabel, Label }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaTrack as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMseSourceBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UpdateStarting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "UpdateStarting", 6usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).UpdateStarting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUpdateStarting(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "RemoveUpdateStarting", 7usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).RemoveUpdateStarting)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Updated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "Updated", 8usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).Updated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "RemoveUpdated", 9usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).RemoveUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateEnded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "UpdateEnded", 10usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).UpdateEnded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUpdateEnded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "RemoveUpdateEnded", 11usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).RemoveUpdateEnded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorOccurred(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "ErrorOccurred", 12usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).ErrorOccurred)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveErrorOccurred(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "RemoveErrorOccurred", 13usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).RemoveErrorOccurred)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Aborted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "Aborted", 14usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).Aborted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAborted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "RemoveAborted", 15usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).RemoveAborted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut MseAppendMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "Mode", 16usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, value: MseAppendMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "SetMode", 17usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).SetMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUpdating(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "IsUpdating", 18usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).IsUpdating)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Buffered(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "Buffered", 19usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).Buffered)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimestampOffset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "TimestampOffset", 20usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).TimestampOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimestampOffset(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "SetTimestampOffset", 21usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).SetTimestampOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendWindowStart(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "AppendWindowStart", 22usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).AppendWindowStart)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppendWindowStart(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "SetAppendWindowStart", 23usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).SetAppendWindowStart)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendWindowEnd(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "AppendWindowEnd", 24usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).AppendWindowEnd)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppendWindowEnd(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "SetAppendWindowEnd", 25usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).SetAppendWindowEnd)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AppendBuffer(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "AppendBuffer", 26usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).AppendBuffer)(this, buffer), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AppendStream(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "AppendStream", 27usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).AppendStream)(this, stream), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AppendStreamMaxSize(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, maxsize: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "AppendStreamMaxSize", 28usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).AppendStreamMaxSize)(this, stream, maxsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "Abort", 29usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, start: super::super::Foundation::TimeSpan, end: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBuffer", "Remove", 30usize, |this| ((**(this as *const *const IMseSourceBuffer_Vtbl)).Remove)(this, start, end), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UpdateStarting,
+            RemoveUpdateStarting,
+            Updated,
+            RemoveUpdated,
+            UpdateEnded,
+            RemoveUpdateEnded,
+            ErrorOccurred,
+            RemoveErrorOccurred,
+            Aborted,
+            RemoveAborted,
+            Mode,
+            SetMode,
+            IsUpdating,
+            #[cfg(feature = "Foundation_Collections")]
+            Buffered,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Buffered: 0,
+            TimestampOffset,
+            SetTimestampOffset,
+            AppendWindowStart,
+            SetAppendWindowStart,
+            AppendWindowEnd,
+            SetAppendWindowEnd,
+            #[cfg(feature = "Storage_Streams")]
+            AppendBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AppendBuffer: 0,
+            #[cfg(feature = "Storage_Streams")]
+            AppendStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AppendStream: 0,
+            #[cfg(feature = "Storage_Streams")]
+            AppendStreamMaxSize,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AppendStreamMaxSize: 0,
+            Abort,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMseSourceBuffer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMseSourceBufferList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SourceBufferAdded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBufferList", "SourceBufferAdded", 6usize, |this| ((**(this as *const *const IMseSourceBufferList_Vtbl)).SourceBufferAdded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSourceBufferAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBufferList", "RemoveSourceBufferAdded", 7usize, |this| ((**(this as *const *const IMseSourceBufferList_Vtbl)).RemoveSourceBufferAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceBufferRemoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBufferList", "SourceBufferRemoved", 8usize, |this| ((**(this as *const *const IMseSourceBufferList_Vtbl)).SourceBufferRemoved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSourceBufferRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBufferList", "RemoveSourceBufferRemoved", 9usize, |this| ((**(this as *const *const IMseSourceBufferList_Vtbl)).RemoveSourceBufferRemoved)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Buffers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseSourceBufferList", "Buffers", 10usize, |this| ((**(this as *const *const IMseSourceBufferList_Vtbl)).Buffers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SourceBufferAdded,
+            RemoveSourceBufferAdded,
+            SourceBufferRemoved,
+            RemoveSourceBufferRemoved,
+            #[cfg(feature = "Foundation_Collections")]
+            Buffers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Buffers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMseSourceBufferList as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMseStreamSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Opened(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource", "Opened", 6usize, |this| ((**(this as *const *const IMseStreamSource_Vtbl)).Opened)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOpened(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource", "RemoveOpened", 7usize, |this| ((**(this as *const *const IMseStreamSource_Vtbl)).RemoveOpened)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ended(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource", "Ended", 8usize, |this| ((**(this as *const *const IMseStreamSource_Vtbl)).Ended)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource", "RemoveEnded", 9usize, |this| ((**(this as *const *const IMseStreamSource_Vtbl)).RemoveEnded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource", "Closed", 10usize, |this| ((**(this as *const *const IMseStreamSource_Vtbl)).Closed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource", "RemoveClosed", 11usize, |this| ((**(this as *const *const IMseStreamSource_Vtbl)).RemoveClosed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceBuffers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource", "SourceBuffers", 12usize, |this| ((**(this as *const *const IMseStreamSource_Vtbl)).SourceBuffers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActiveSourceBuffers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource", "ActiveSourceBuffers", 13usize, |this| ((**(this as *const *const IMseStreamSource_Vtbl)).ActiveSourceBuffers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadyState(this: *mut core::ffi::c_void, result__: *mut MseReadyState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource", "ReadyState", 14usize, |this| ((**(this as *const *const IMseStreamSource_Vtbl)).ReadyState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource", "Duration", 15usize, |this| ((**(this as *const *const IMseStreamSource_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource", "SetDuration", 16usize, |this| ((**(this as *const *const IMseStreamSource_Vtbl)).SetDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSourceBuffer(this: *mut core::ffi::c_void, mimetype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource", "AddSourceBuffer", 17usize, |this| ((**(this as *const *const IMseStreamSource_Vtbl)).AddSourceBuffer)(this, mimetype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSourceBuffer(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource", "RemoveSourceBuffer", 18usize, |this| ((**(this as *const *const IMseStreamSource_Vtbl)).RemoveSourceBuffer)(this, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndOfStream(this: *mut core::ffi::c_void, status: MseEndOfStreamStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource", "EndOfStream", 19usize, |this| ((**(this as *const *const IMseStreamSource_Vtbl)).EndOfStream)(this, status), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Opened,
+            RemoveOpened,
+            Ended,
+            RemoveEnded,
+            Closed,
+            RemoveClosed,
+            SourceBuffers,
+            ActiveSourceBuffers,
+            ReadyState,
+            Duration,
+            SetDuration,
+            AddSourceBuffer,
+            RemoveSourceBuffer,
+            EndOfStream,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMseStreamSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMseStreamSource2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LiveSeekableRange(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource2", "LiveSeekableRange", 6usize, |this| ((**(this as *const *const IMseStreamSource2_Vtbl)).LiveSeekableRange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLiveSeekableRange(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSource2", "SetLiveSeekableRange", 7usize, |this| ((**(this as *const *const IMseStreamSource2_Vtbl)).SetLiveSeekableRange)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LiveSeekableRange, SetLiveSeekableRange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMseStreamSource2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMseStreamSourceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsContentTypeSupported(this: *mut core::ffi::c_void, contenttype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMseStreamSourceStatics", "IsContentTypeSupported", 6usize, |this| ((**(this as *const *const IMseStreamSourceStatics_Vtbl)).IsContentTypeSupported)(this, contenttype, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsContentTypeSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMseStreamSourceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISceneAnalysisEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HighDynamicRangeAnalyzer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneAnalysisEffect", "HighDynamicRangeAnalyzer", 6usize, |this| ((**(this as *const *const ISceneAnalysisEffect_Vtbl)).HighDynamicRangeAnalyzer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredAnalysisInterval(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneAnalysisEffect", "SetDesiredAnalysisInterval", 7usize, |this| ((**(this as *const *const ISceneAnalysisEffect_Vtbl)).SetDesiredAnalysisInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredAnalysisInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneAnalysisEffect", "DesiredAnalysisInterval", 8usize, |this| ((**(this as *const *const ISceneAnalysisEffect_Vtbl)).DesiredAnalysisInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SceneAnalyzed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneAnalysisEffect", "SceneAnalyzed", 9usize, |this| ((**(this as *const *const ISceneAnalysisEffect_Vtbl)).SceneAnalyzed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSceneAnalyzed(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneAnalysisEffect", "RemoveSceneAnalyzed", 10usize, |this| ((**(this as *const *const ISceneAnalysisEffect_Vtbl)).RemoveSceneAnalyzed)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HighDynamicRangeAnalyzer,
+            SetDesiredAnalysisInterval,
+            DesiredAnalysisInterval,
+            SceneAnalyzed,
+            RemoveSceneAnalyzed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneAnalysisEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Capture"))]
+impl windows_core::imp::ProxyVtable for ISceneAnalysisEffectFrame_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Capture")]
+        unsafe extern "system" fn FrameControlValues(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneAnalysisEffectFrame", "FrameControlValues", 6usize, |this| ((**(this as *const *const ISceneAnalysisEffectFrame_Vtbl)).FrameControlValues)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HighDynamicRange(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneAnalysisEffectFrame", "HighDynamicRange", 7usize, |this| ((**(this as *const *const ISceneAnalysisEffectFrame_Vtbl)).HighDynamicRange)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Capture")]
+            FrameControlValues,
+            #[cfg(not(feature = "Media_Capture"))]
+            FrameControlValues: 0,
+            HighDynamicRange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneAnalysisEffectFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISceneAnalysisEffectFrame2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AnalysisRecommendation(this: *mut core::ffi::c_void, result__: *mut SceneAnalysisRecommendation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneAnalysisEffectFrame2", "AnalysisRecommendation", 6usize, |this| ((**(this as *const *const ISceneAnalysisEffectFrame2_Vtbl)).AnalysisRecommendation)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AnalysisRecommendation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneAnalysisEffectFrame2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneAnalyzedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResultFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneAnalyzedEventArgs", "ResultFrame", 6usize, |this| ((**(this as *const *const ISceneAnalyzedEventArgs_Vtbl)).ResultFrame)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ResultFrame }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneAnalyzedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISingleSelectMediaTrackList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SelectedIndexChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISingleSelectMediaTrackList", "SelectedIndexChanged", 6usize, |this| ((**(this as *const *const ISingleSelectMediaTrackList_Vtbl)).SelectedIndexChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSelectedIndexChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISingleSelectMediaTrackList", "RemoveSelectedIndexChanged", 7usize, |this| ((**(this as *const *const ISingleSelectMediaTrackList_Vtbl)).RemoveSelectedIndexChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelectedIndex(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISingleSelectMediaTrackList", "SetSelectedIndex", 8usize, |this| ((**(this as *const *const ISingleSelectMediaTrackList_Vtbl)).SetSelectedIndex)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedIndex(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISingleSelectMediaTrackList", "SelectedIndex", 9usize, |this| ((**(this as *const *const ISingleSelectMediaTrackList_Vtbl)).SelectedIndex)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SelectedIndexChanged,
+            RemoveSelectedIndexChanged,
+            SetSelectedIndex,
+            SelectedIndex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISingleSelectMediaTrackList as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechCue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechCue", "Text", 6usize, |this| ((**(this as *const *const ISpeechCue_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechCue", "SetText", 7usize, |this| ((**(this as *const *const ISpeechCue_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartPositionInInput(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechCue", "StartPositionInInput", 8usize, |this| ((**(this as *const *const ISpeechCue_Vtbl)).StartPositionInInput)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartPositionInInput(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechCue", "SetStartPositionInInput", 9usize, |this| ((**(this as *const *const ISpeechCue_Vtbl)).SetStartPositionInInput)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndPositionInInput(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechCue", "EndPositionInInput", 10usize, |this| ((**(this as *const *const ISpeechCue_Vtbl)).EndPositionInInput)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEndPositionInInput(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechCue", "SetEndPositionInInput", 11usize, |this| ((**(this as *const *const ISpeechCue_Vtbl)).SetEndPositionInInput)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Text,
+            SetText,
+            StartPositionInInput,
+            SetStartPositionInInput,
+            EndPositionInInput,
+            SetEndPositionInInput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechCue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_MediaProperties")]
+impl windows_core::imp::ProxyVtable for ITimedMetadataStreamDescriptor_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn EncodingProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataStreamDescriptor", "EncodingProperties", 6usize, |this| ((**(this as *const *const ITimedMetadataStreamDescriptor_Vtbl)).EncodingProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataStreamDescriptor", "Copy", 7usize, |this| ((**(this as *const *const ITimedMetadataStreamDescriptor_Vtbl)).Copy)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_MediaProperties")]
+            EncodingProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            EncodingProperties: 0,
+            Copy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedMetadataStreamDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_MediaProperties")]
+impl windows_core::imp::ProxyVtable for ITimedMetadataStreamDescriptorFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, encodingproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataStreamDescriptorFactory", "Create", 6usize, |this| ((**(this as *const *const ITimedMetadataStreamDescriptorFactory_Vtbl)).Create)(this, encodingproperties, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_MediaProperties")]
+            Create,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedMetadataStreamDescriptorFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITimedMetadataTrack_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CueEntered(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrack", "CueEntered", 6usize, |this| ((**(this as *const *const ITimedMetadataTrack_Vtbl)).CueEntered)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCueEntered(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrack", "RemoveCueEntered", 7usize, |this| ((**(this as *const *const ITimedMetadataTrack_Vtbl)).RemoveCueEntered)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CueExited(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrack", "CueExited", 8usize, |this| ((**(this as *const *const ITimedMetadataTrack_Vtbl)).CueExited)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCueExited(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrack", "RemoveCueExited", 9usize, |this| ((**(this as *const *const ITimedMetadataTrack_Vtbl)).RemoveCueExited)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrackFailed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrack", "TrackFailed", 10usize, |this| ((**(this as *const *const ITimedMetadataTrack_Vtbl)).TrackFailed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTrackFailed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrack", "RemoveTrackFailed", 11usize, |this| ((**(this as *const *const ITimedMetadataTrack_Vtbl)).RemoveTrackFailed)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Cues(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrack", "Cues", 12usize, |this| ((**(this as *const *const ITimedMetadataTrack_Vtbl)).Cues)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ActiveCues(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrack", "ActiveCues", 13usize, |this| ((**(this as *const *const ITimedMetadataTrack_Vtbl)).ActiveCues)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimedMetadataKind(this: *mut core::ffi::c_void, result__: *mut TimedMetadataKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrack", "TimedMetadataKind", 14usize, |this| ((**(this as *const *const ITimedMetadataTrack_Vtbl)).TimedMetadataKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DispatchType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrack", "DispatchType", 15usize, |this| ((**(this as *const *const ITimedMetadataTrack_Vtbl)).DispatchType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddCue(this: *mut core::ffi::c_void, cue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrack", "AddCue", 16usize, |this| ((**(this as *const *const ITimedMetadataTrack_Vtbl)).AddCue)(this, cue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCue(this: *mut core::ffi::c_void, cue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrack", "RemoveCue", 17usize, |this| ((**(this as *const *const ITimedMetadataTrack_Vtbl)).RemoveCue)(this, cue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CueEntered,
+            RemoveCueEntered,
+            CueExited,
+            RemoveCueExited,
+            TrackFailed,
+            RemoveTrackFailed,
+            #[cfg(feature = "Foundation_Collections")]
+            Cues,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Cues: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ActiveCues,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ActiveCues: 0,
+            TimedMetadataKind,
+            DispatchType,
+            AddCue,
+            RemoveCue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedMetadataTrack as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Playback"))]
+impl windows_core::imp::ProxyVtable for ITimedMetadataTrack2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Playback")]
+        unsafe extern "system" fn PlaybackItem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrack2", "PlaybackItem", 6usize, |this| ((**(this as *const *const ITimedMetadataTrack2_Vtbl)).PlaybackItem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrack2", "Name", 7usize, |this| ((**(this as *const *const ITimedMetadataTrack2_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Playback")]
+            PlaybackItem,
+            #[cfg(not(feature = "Media_Playback"))]
+            PlaybackItem: 0,
+            Name,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedMetadataTrack2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimedMetadataTrackError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut TimedMetadataTrackErrorCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrackError", "ErrorCode", 6usize, |this| ((**(this as *const *const ITimedMetadataTrackError_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrackError", "ExtendedError", 7usize, |this| ((**(this as *const *const ITimedMetadataTrackError_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ErrorCode, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedMetadataTrackError as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimedMetadataTrackFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, language: core::mem::MaybeUninit<windows_core::HSTRING>, kind: TimedMetadataKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrackFactory", "Create", 6usize, |this| ((**(this as *const *const ITimedMetadataTrackFactory_Vtbl)).Create)(this, id, language, kind, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedMetadataTrackFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimedMetadataTrackFailedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrackFailedEventArgs", "Error", 6usize, |this| ((**(this as *const *const ITimedMetadataTrackFailedEventArgs_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Error }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedMetadataTrackFailedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITimedMetadataTrackProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TimedMetadataTracks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataTrackProvider", "TimedMetadataTracks", 6usize, |this| ((**(this as *const *const ITimedMetadataTrackProvider_Vtbl)).TimedMetadataTracks)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            TimedMetadataTracks,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TimedMetadataTracks: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedMetadataTrackProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for ITimedTextBouten_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut TimedTextBoutenType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextBouten", "Type", 6usize, |this| ((**(this as *const *const ITimedTextBouten_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, value: TimedTextBoutenType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextBouten", "SetType", 7usize, |this| ((**(this as *const *const ITimedTextBouten_Vtbl)).SetType)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextBouten", "Color", 8usize, |this| ((**(this as *const *const ITimedTextBouten_Vtbl)).Color)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextBouten", "SetColor", 9usize, |this| ((**(this as *const *const ITimedTextBouten_Vtbl)).SetColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut TimedTextBoutenPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextBouten", "Position", 10usize, |this| ((**(this as *const *const ITimedTextBouten_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, value: TimedTextBoutenPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextBouten", "SetPosition", 11usize, |this| ((**(this as *const *const ITimedTextBouten_Vtbl)).SetPosition)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            SetType,
+            #[cfg(feature = "UI")]
+            Color,
+            #[cfg(not(feature = "UI"))]
+            Color: 0,
+            #[cfg(feature = "UI")]
+            SetColor,
+            #[cfg(not(feature = "UI"))]
+            SetColor: 0,
+            Position,
+            SetPosition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedTextBouten as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITimedTextCue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CueRegion(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextCue", "CueRegion", 6usize, |this| ((**(this as *const *const ITimedTextCue_Vtbl)).CueRegion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCueRegion(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextCue", "SetCueRegion", 7usize, |this| ((**(this as *const *const ITimedTextCue_Vtbl)).SetCueRegion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CueStyle(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextCue", "CueStyle", 8usize, |this| ((**(this as *const *const ITimedTextCue_Vtbl)).CueStyle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCueStyle(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextCue", "SetCueStyle", 9usize, |this| ((**(this as *const *const ITimedTextCue_Vtbl)).SetCueStyle)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Lines(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextCue", "Lines", 10usize, |this| ((**(this as *const *const ITimedTextCue_Vtbl)).Lines)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CueRegion,
+            SetCueRegion,
+            CueStyle,
+            SetCueStyle,
+            #[cfg(feature = "Foundation_Collections")]
+            Lines,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Lines: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedTextCue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITimedTextLine_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextLine", "Text", 6usize, |this| ((**(this as *const *const ITimedTextLine_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextLine", "SetText", 7usize, |this| ((**(this as *const *const ITimedTextLine_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Subformats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextLine", "Subformats", 8usize, |this| ((**(this as *const *const ITimedTextLine_Vtbl)).Subformats)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Text,
+            SetText,
+            #[cfg(feature = "Foundation_Collections")]
+            Subformats,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Subformats: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedTextLine as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for ITimedTextRegion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "Name", 6usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "SetName", 7usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut TimedTextPoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "Position", 8usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, value: TimedTextPoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "SetPosition", 9usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).SetPosition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Extent(this: *mut core::ffi::c_void, result__: *mut TimedTextSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "Extent", 10usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).Extent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtent(this: *mut core::ffi::c_void, value: TimedTextSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "SetExtent", 11usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).SetExtent)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn Background(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "Background", 12usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).Background)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetBackground(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "SetBackground", 13usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).SetBackground)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WritingMode(this: *mut core::ffi::c_void, result__: *mut TimedTextWritingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "WritingMode", 14usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).WritingMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWritingMode(this: *mut core::ffi::c_void, value: TimedTextWritingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "SetWritingMode", 15usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).SetWritingMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayAlignment(this: *mut core::ffi::c_void, result__: *mut TimedTextDisplayAlignment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "DisplayAlignment", 16usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).DisplayAlignment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayAlignment(this: *mut core::ffi::c_void, value: TimedTextDisplayAlignment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "SetDisplayAlignment", 17usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).SetDisplayAlignment)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineHeight(this: *mut core::ffi::c_void, result__: *mut TimedTextDouble) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "LineHeight", 18usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).LineHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLineHeight(this: *mut core::ffi::c_void, value: TimedTextDouble) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "SetLineHeight", 19usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).SetLineHeight)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOverflowClipped(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "IsOverflowClipped", 20usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).IsOverflowClipped)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsOverflowClipped(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "SetIsOverflowClipped", 21usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).SetIsOverflowClipped)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Padding(this: *mut core::ffi::c_void, result__: *mut TimedTextPadding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "Padding", 22usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).Padding)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPadding(this: *mut core::ffi::c_void, value: TimedTextPadding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "SetPadding", 23usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).SetPadding)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextWrapping(this: *mut core::ffi::c_void, result__: *mut TimedTextWrapping) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "TextWrapping", 24usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).TextWrapping)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextWrapping(this: *mut core::ffi::c_void, value: TimedTextWrapping) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "SetTextWrapping", 25usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).SetTextWrapping)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZIndex(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "ZIndex", 26usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).ZIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetZIndex(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "SetZIndex", 27usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).SetZIndex)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScrollMode(this: *mut core::ffi::c_void, result__: *mut TimedTextScrollMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "ScrollMode", 28usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).ScrollMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScrollMode(this: *mut core::ffi::c_void, value: TimedTextScrollMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRegion", "SetScrollMode", 29usize, |this| ((**(this as *const *const ITimedTextRegion_Vtbl)).SetScrollMode)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            Position,
+            SetPosition,
+            Extent,
+            SetExtent,
+            #[cfg(feature = "UI")]
+            Background,
+            #[cfg(not(feature = "UI"))]
+            Background: 0,
+            #[cfg(feature = "UI")]
+            SetBackground,
+            #[cfg(not(feature = "UI"))]
+            SetBackground: 0,
+            WritingMode,
+            SetWritingMode,
+            DisplayAlignment,
+            SetDisplayAlignment,
+            LineHeight,
+            SetLineHeight,
+            IsOverflowClipped,
+            SetIsOverflowClipped,
+            Padding,
+            SetPadding,
+            TextWrapping,
+            SetTextWrapping,
+            ZIndex,
+            SetZIndex,
+            ScrollMode,
+            SetScrollMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedTextRegion as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimedTextRuby_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRuby", "Text", 6usize, |this| ((**(this as *const *const ITimedTextRuby_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRuby", "SetText", 7usize, |this| ((**(this as *const *const ITimedTextRuby_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut TimedTextRubyPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRuby", "Position", 8usize, |this| ((**(this as *const *const ITimedTextRuby_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, value: TimedTextRubyPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRuby", "SetPosition", 9usize, |this| ((**(this as *const *const ITimedTextRuby_Vtbl)).SetPosition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Align(this: *mut core::ffi::c_void, result__: *mut TimedTextRubyAlign) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRuby", "Align", 10usize, |this| ((**(this as *const *const ITimedTextRuby_Vtbl)).Align)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlign(this: *mut core::ffi::c_void, value: TimedTextRubyAlign) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRuby", "SetAlign", 11usize, |this| ((**(this as *const *const ITimedTextRuby_Vtbl)).SetAlign)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reserve(this: *mut core::ffi::c_void, result__: *mut TimedTextRubyReserve) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRuby", "Reserve", 12usize, |this| ((**(this as *const *const ITimedTextRuby_Vtbl)).Reserve)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReserve(this: *mut core::ffi::c_void, value: TimedTextRubyReserve) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextRuby", "SetReserve", 13usize, |this| ((**(this as *const *const ITimedTextRuby_Vtbl)).SetReserve)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Text,
+            SetText,
+            Position,
+            SetPosition,
+            Align,
+            SetAlign,
+            Reserve,
+            SetReserve,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedTextRuby as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimedTextSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Resolved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSource", "Resolved", 6usize, |this| ((**(this as *const *const ITimedTextSource_Vtbl)).Resolved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveResolved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSource", "RemoveResolved", 7usize, |this| ((**(this as *const *const ITimedTextSource_Vtbl)).RemoveResolved)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Resolved, RemoveResolved }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedTextSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITimedTextSourceResolveResultEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSourceResolveResultEventArgs", "Error", 6usize, |this| ((**(this as *const *const ITimedTextSourceResolveResultEventArgs_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Tracks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSourceResolveResultEventArgs", "Tracks", 7usize, |this| ((**(this as *const *const ITimedTextSourceResolveResultEventArgs_Vtbl)).Tracks)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Error,
+            #[cfg(feature = "Foundation_Collections")]
+            Tracks,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Tracks: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedTextSourceResolveResultEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITimedTextSourceStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromStream(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSourceStatics", "CreateFromStream", 6usize, |this| ((**(this as *const *const ITimedTextSourceStatics_Vtbl)).CreateFromStream)(this, stream, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromUri(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSourceStatics", "CreateFromUri", 7usize, |this| ((**(this as *const *const ITimedTextSourceStatics_Vtbl)).CreateFromUri)(this, uri, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromStreamWithLanguage(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, defaultlanguage: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSourceStatics", "CreateFromStreamWithLanguage", 8usize, |this| ((**(this as *const *const ITimedTextSourceStatics_Vtbl)).CreateFromStreamWithLanguage)(this, stream, defaultlanguage, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromUriWithLanguage(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, defaultlanguage: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSourceStatics", "CreateFromUriWithLanguage", 9usize, |this| ((**(this as *const *const ITimedTextSourceStatics_Vtbl)).CreateFromUriWithLanguage)(this, uri, defaultlanguage, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromStream: 0,
+            CreateFromUri,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromStreamWithLanguage,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromStreamWithLanguage: 0,
+            CreateFromUriWithLanguage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedTextSourceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITimedTextSourceStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromStreamWithIndex(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, indexstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSourceStatics2", "CreateFromStreamWithIndex", 6usize, |this| ((**(this as *const *const ITimedTextSourceStatics2_Vtbl)).CreateFromStreamWithIndex)(this, stream, indexstream, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromUriWithIndex(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, indexuri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSourceStatics2", "CreateFromUriWithIndex", 7usize, |this| ((**(this as *const *const ITimedTextSourceStatics2_Vtbl)).CreateFromUriWithIndex)(this, uri, indexuri, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromStreamWithIndexAndLanguage(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, indexstream: *mut core::ffi::c_void, defaultlanguage: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSourceStatics2", "CreateFromStreamWithIndexAndLanguage", 8usize, |this| ((**(this as *const *const ITimedTextSourceStatics2_Vtbl)).CreateFromStreamWithIndexAndLanguage)(this, stream, indexstream, defaultlanguage, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromUriWithIndexAndLanguage(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, indexuri: *mut core::ffi::c_void, defaultlanguage: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSourceStatics2", "CreateFromUriWithIndexAndLanguage", 9usize, |this| ((**(this as *const *const ITimedTextSourceStatics2_Vtbl)).CreateFromUriWithIndexAndLanguage)(this, uri, indexuri, defaultlanguage, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromStreamWithIndex,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromStreamWithIndex: 0,
+            CreateFromUriWithIndex,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromStreamWithIndexAndLanguage,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromStreamWithIndexAndLanguage: 0,
+            CreateFromUriWithIndexAndLanguage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedTextSourceStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for ITimedTextStyle_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "Name", 6usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "SetName", 7usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FontFamily(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "FontFamily", 8usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).FontFamily)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontFamily(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "SetFontFamily", 9usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).SetFontFamily)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FontSize(this: *mut core::ffi::c_void, result__: *mut TimedTextDouble) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "FontSize", 10usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).FontSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontSize(this: *mut core::ffi::c_void, value: TimedTextDouble) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "SetFontSize", 11usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).SetFontSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FontWeight(this: *mut core::ffi::c_void, result__: *mut TimedTextWeight) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "FontWeight", 12usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).FontWeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontWeight(this: *mut core::ffi::c_void, value: TimedTextWeight) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "SetFontWeight", 13usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).SetFontWeight)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn Foreground(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "Foreground", 14usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).Foreground)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetForeground(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "SetForeground", 15usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).SetForeground)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn Background(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "Background", 16usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).Background)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetBackground(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "SetBackground", 17usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).SetBackground)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBackgroundAlwaysShown(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "IsBackgroundAlwaysShown", 18usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).IsBackgroundAlwaysShown)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsBackgroundAlwaysShown(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "SetIsBackgroundAlwaysShown", 19usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).SetIsBackgroundAlwaysShown)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlowDirection(this: *mut core::ffi::c_void, result__: *mut TimedTextFlowDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "FlowDirection", 20usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).FlowDirection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlowDirection(this: *mut core::ffi::c_void, value: TimedTextFlowDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "SetFlowDirection", 21usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).SetFlowDirection)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineAlignment(this: *mut core::ffi::c_void, result__: *mut TimedTextLineAlignment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "LineAlignment", 22usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).LineAlignment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLineAlignment(this: *mut core::ffi::c_void, value: TimedTextLineAlignment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "SetLineAlignment", 23usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).SetLineAlignment)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn OutlineColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "OutlineColor", 24usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).OutlineColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetOutlineColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "SetOutlineColor", 25usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).SetOutlineColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutlineThickness(this: *mut core::ffi::c_void, result__: *mut TimedTextDouble) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "OutlineThickness", 26usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).OutlineThickness)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutlineThickness(this: *mut core::ffi::c_void, value: TimedTextDouble) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "SetOutlineThickness", 27usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).SetOutlineThickness)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutlineRadius(this: *mut core::ffi::c_void, result__: *mut TimedTextDouble) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "OutlineRadius", 28usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).OutlineRadius)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutlineRadius(this: *mut core::ffi::c_void, value: TimedTextDouble) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle", "SetOutlineRadius", 29usize, |this| ((**(this as *const *const ITimedTextStyle_Vtbl)).SetOutlineRadius)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            FontFamily,
+            SetFontFamily,
+            FontSize,
+            SetFontSize,
+            FontWeight,
+            SetFontWeight,
+            #[cfg(feature = "UI")]
+            Foreground,
+            #[cfg(not(feature = "UI"))]
+            Foreground: 0,
+            #[cfg(feature = "UI")]
+            SetForeground,
+            #[cfg(not(feature = "UI"))]
+            SetForeground: 0,
+            #[cfg(feature = "UI")]
+            Background,
+            #[cfg(not(feature = "UI"))]
+            Background: 0,
+            #[cfg(feature = "UI")]
+            SetBackground,
+            #[cfg(not(feature = "UI"))]
+            SetBackground: 0,
+            IsBackgroundAlwaysShown,
+            SetIsBackgroundAlwaysShown,
+            FlowDirection,
+            SetFlowDirection,
+            LineAlignment,
+            SetLineAlignment,
+            #[cfg(feature = "UI")]
+            OutlineColor,
+            #[cfg(not(feature = "UI"))]
+            OutlineColor: 0,
+            #[cfg(feature = "UI")]
+            SetOutlineColor,
+            #[cfg(not(feature = "UI"))]
+            SetOutlineColor: 0,
+            OutlineThickness,
+            SetOutlineThickness,
+            OutlineRadius,
+            SetOutlineRadius,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedTextStyle as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimedTextStyle2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FontStyle(this: *mut core::ffi::c_void, result__: *mut TimedTextFontStyle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle2", "FontStyle", 6usize, |this| ((**(this as *const *const ITimedTextStyle2_Vtbl)).FontStyle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontStyle(this: *mut core::ffi::c_void, value: TimedTextFontStyle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle2", "SetFontStyle", 7usize, |this| ((**(this as *const *const ITimedTextStyle2_Vtbl)).SetFontStyle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUnderlineEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle2", "IsUnderlineEnabled", 8usize, |this| ((**(this as *const *const ITimedTextStyle2_Vtbl)).IsUnderlineEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsUnderlineEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle2", "SetIsUnderlineEnabled", 9usize, |this| ((**(this as *const *const ITimedTextStyle2_Vtbl)).SetIsUnderlineEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLineThroughEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle2", "IsLineThroughEnabled", 10usize, |this| ((**(this as *const *const ITimedTextStyle2_Vtbl)).IsLineThroughEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsLineThroughEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle2", "SetIsLineThroughEnabled", 11usize, |this| ((**(this as *const *const ITimedTextStyle2_Vtbl)).SetIsLineThroughEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOverlineEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle2", "IsOverlineEnabled", 12usize, |this| ((**(this as *const *const ITimedTextStyle2_Vtbl)).IsOverlineEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsOverlineEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle2", "SetIsOverlineEnabled", 13usize, |this| ((**(this as *const *const ITimedTextStyle2_Vtbl)).SetIsOverlineEnabled)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FontStyle,
+            SetFontStyle,
+            IsUnderlineEnabled,
+            SetIsUnderlineEnabled,
+            IsLineThroughEnabled,
+            SetIsLineThroughEnabled,
+            IsOverlineEnabled,
+            SetIsOverlineEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedTextStyle2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimedTextStyle3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Ruby(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle3", "Ruby", 6usize, |this| ((**(this as *const *const ITimedTextStyle3_Vtbl)).Ruby)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bouten(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle3", "Bouten", 7usize, |this| ((**(this as *const *const ITimedTextStyle3_Vtbl)).Bouten)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTextCombined(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle3", "IsTextCombined", 8usize, |this| ((**(this as *const *const ITimedTextStyle3_Vtbl)).IsTextCombined)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsTextCombined(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle3", "SetIsTextCombined", 9usize, |this| ((**(this as *const *const ITimedTextStyle3_Vtbl)).SetIsTextCombined)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FontAngleInDegrees(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle3", "FontAngleInDegrees", 10usize, |this| ((**(this as *const *const ITimedTextStyle3_Vtbl)).FontAngleInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontAngleInDegrees(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextStyle3", "SetFontAngleInDegrees", 11usize, |this| ((**(this as *const *const ITimedTextStyle3_Vtbl)).SetFontAngleInDegrees)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Ruby,
+            Bouten,
+            IsTextCombined,
+            SetIsTextCombined,
+            FontAngleInDegrees,
+            SetFontAngleInDegrees,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedTextStyle3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimedTextSubformat_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartIndex(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSubformat", "StartIndex", 6usize, |this| ((**(this as *const *const ITimedTextSubformat_Vtbl)).StartIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartIndex(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSubformat", "SetStartIndex", 7usize, |this| ((**(this as *const *const ITimedTextSubformat_Vtbl)).SetStartIndex)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSubformat", "Length", 8usize, |this| ((**(this as *const *const ITimedTextSubformat_Vtbl)).Length)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLength(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSubformat", "SetLength", 9usize, |this| ((**(this as *const *const ITimedTextSubformat_Vtbl)).SetLength)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubformatStyle(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSubformat", "SubformatStyle", 10usize, |this| ((**(this as *const *const ITimedTextSubformat_Vtbl)).SubformatStyle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubformatStyle(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedTextSubformat", "SetSubformatStyle", 11usize, |this| ((**(this as *const *const ITimedTextSubformat_Vtbl)).SetSubformatStyle)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartIndex,
+            SetStartIndex,
+            Length,
+            SetLength,
+            SubformatStyle,
+            SetSubformatStyle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedTextSubformat as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Capture", feature = "Media_Devices", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IVideoStabilizationEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoStabilizationEffect", "SetEnabled", 6usize, |this| ((**(this as *const *const IVideoStabilizationEffect_Vtbl)).SetEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoStabilizationEffect", "Enabled", 7usize, |this| ((**(this as *const *const IVideoStabilizationEffect_Vtbl)).Enabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnabledChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoStabilizationEffect", "EnabledChanged", 8usize, |this| ((**(this as *const *const IVideoStabilizationEffect_Vtbl)).EnabledChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnabledChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoStabilizationEffect", "RemoveEnabledChanged", 9usize, |this| ((**(this as *const *const IVideoStabilizationEffect_Vtbl)).RemoveEnabledChanged)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_Capture", feature = "Media_Devices", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn GetRecommendedStreamConfiguration(this: *mut core::ffi::c_void, controller: *mut core::ffi::c_void, desiredproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoStabilizationEffect", "GetRecommendedStreamConfiguration", 10usize, |this| ((**(this as *const *const IVideoStabilizationEffect_Vtbl)).GetRecommendedStreamConfiguration)(this, controller, desiredproperties, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetEnabled,
+            Enabled,
+            EnabledChanged,
+            RemoveEnabledChanged,
+            #[cfg(all(feature = "Media_Capture", feature = "Media_Devices", feature = "Media_MediaProperties"))]
+            GetRecommendedStreamConfiguration,
+            #[cfg(not(all(feature = "Media_Capture", feature = "Media_Devices", feature = "Media_MediaProperties")))]
+            GetRecommendedStreamConfiguration: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoStabilizationEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoStabilizationEffectEnabledChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut VideoStabilizationEffectEnabledChangedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoStabilizationEffectEnabledChangedEventArgs", "Reason", 6usize, |this| ((**(this as *const *const IVideoStabilizationEffectEnabledChangedEventArgs_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoStabilizationEffectEnabledChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_MediaProperties")]
+impl windows_core::imp::ProxyVtable for IVideoStreamDescriptor_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn EncodingProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoStreamDescriptor", "EncodingProperties", 6usize, |this| ((**(this as *const *const IVideoStreamDescriptor_Vtbl)).EncodingProperties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_MediaProperties")]
+            EncodingProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            EncodingProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoStreamDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoStreamDescriptor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoStreamDescriptor2", "Copy", 6usize, |this| ((**(this as *const *const IVideoStreamDescriptor2_Vtbl)).Copy)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Copy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoStreamDescriptor2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_MediaProperties")]
+impl windows_core::imp::ProxyVtable for IVideoStreamDescriptorFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, encodingproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoStreamDescriptorFactory", "Create", 6usize, |this| ((**(this as *const *const IVideoStreamDescriptorFactory_Vtbl)).Create)(this, encodingproperties, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_MediaProperties")]
+            Create,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoStreamDescriptorFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_MediaProperties", feature = "Media_Playback"))]
+impl windows_core::imp::ProxyVtable for IVideoTrack_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenFailed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTrack", "OpenFailed", 6usize, |this| ((**(this as *const *const IVideoTrack_Vtbl)).OpenFailed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOpenFailed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTrack", "RemoveOpenFailed", 7usize, |this| ((**(this as *const *const IVideoTrack_Vtbl)).RemoveOpenFailed)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn GetEncodingProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTrack", "GetEncodingProperties", 8usize, |this| ((**(this as *const *const IVideoTrack_Vtbl)).GetEncodingProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Playback")]
+        unsafe extern "system" fn PlaybackItem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTrack", "PlaybackItem", 9usize, |this| ((**(this as *const *const IVideoTrack_Vtbl)).PlaybackItem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTrack", "Name", 10usize, |this| ((**(this as *const *const IVideoTrack_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTrack", "SupportInfo", 11usize, |this| ((**(this as *const *const IVideoTrack_Vtbl)).SupportInfo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OpenFailed,
+            RemoveOpenFailed,
+            #[cfg(feature = "Media_MediaProperties")]
+            GetEncodingProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            GetEncodingProperties: 0,
+            #[cfg(feature = "Media_Playback")]
+            PlaybackItem,
+            #[cfg(not(feature = "Media_Playback"))]
+            PlaybackItem: 0,
+            Name,
+            SupportInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoTrack as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoTrackOpenFailedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTrackOpenFailedEventArgs", "ExtendedError", 6usize, |this| ((**(this as *const *const IVideoTrackOpenFailedEventArgs_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoTrackOpenFailedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoTrackSupportInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DecoderStatus(this: *mut core::ffi::c_void, result__: *mut MediaDecoderStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTrackSupportInfo", "DecoderStatus", 6usize, |this| ((**(this as *const *const IVideoTrackSupportInfo_Vtbl)).DecoderStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaSourceStatus(this: *mut core::ffi::c_void, result__: *mut MediaSourceStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTrackSupportInfo", "MediaSourceStatus", 7usize, |this| ((**(this as *const *const IVideoTrackSupportInfo_Vtbl)).MediaSourceStatus)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DecoderStatus, MediaSourceStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoTrackSupportInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
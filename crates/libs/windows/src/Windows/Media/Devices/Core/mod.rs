@@ -1109,6 +1109,7 @@ impl FrameFlashMode {
 impl windows_core::TypeKind for FrameFlashMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FrameFlashMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FrameFlashMode").field(&self.0).finish()
@@ -1117,3 +1118,5 @@ impl core::fmt::Debug for FrameFlashMode {
 impl windows_core::RuntimeType for FrameFlashMode {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.Devices.Core.FrameFlashMode;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,547 @@
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICameraIntrinsics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn FocalLength(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsics", "FocalLength", 6usize, |this| ((**(this as *const *const ICameraIntrinsics_Vtbl)).FocalLength)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn PrincipalPoint(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsics", "PrincipalPoint", 7usize, |this| ((**(this as *const *const ICameraIntrinsics_Vtbl)).PrincipalPoint)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn RadialDistortion(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsics", "RadialDistortion", 8usize, |this| ((**(this as *const *const ICameraIntrinsics_Vtbl)).RadialDistortion)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TangentialDistortion(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsics", "TangentialDistortion", 9usize, |this| ((**(this as *const *const ICameraIntrinsics_Vtbl)).TangentialDistortion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImageWidth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsics", "ImageWidth", 10usize, |this| ((**(this as *const *const ICameraIntrinsics_Vtbl)).ImageWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImageHeight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsics", "ImageHeight", 11usize, |this| ((**(this as *const *const ICameraIntrinsics_Vtbl)).ImageHeight)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn ProjectOntoFrame(this: *mut core::ffi::c_void, coordinate: super::super::super::Foundation::Numerics::Vector3, result__: *mut super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsics", "ProjectOntoFrame", 12usize, |this| ((**(this as *const *const ICameraIntrinsics_Vtbl)).ProjectOntoFrame)(this, coordinate, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn UnprojectAtUnitDepth(this: *mut core::ffi::c_void, pixelcoordinate: super::super::super::Foundation::Point, result__: *mut super::super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsics", "UnprojectAtUnitDepth", 13usize, |this| ((**(this as *const *const ICameraIntrinsics_Vtbl)).UnprojectAtUnitDepth)(this, pixelcoordinate, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn ProjectManyOntoFrame(this: *mut core::ffi::c_void, coordinates_array_size: u32, coordinates: *const super::super::super::Foundation::Numerics::Vector3, results_array_size: u32, results: *mut super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsics", "ProjectManyOntoFrame", 14usize, |this| ((**(this as *const *const ICameraIntrinsics_Vtbl)).ProjectManyOntoFrame)(this, coordinates_array_size, coordinates, results_array_size, results), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn UnprojectPixelsAtUnitDepth(this: *mut core::ffi::c_void, pixelCoordinates_array_size: u32, pixelcoordinates: *const super::super::super::Foundation::Point, results_array_size: u32, results: *mut super::super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsics", "UnprojectPixelsAtUnitDepth", 15usize, |this| ((**(this as *const *const ICameraIntrinsics_Vtbl)).UnprojectPixelsAtUnitDepth)(this, pixelCoordinates_array_size, pixelcoordinates, results_array_size, results), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            FocalLength,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            FocalLength: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            PrincipalPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            PrincipalPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            RadialDistortion,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            RadialDistortion: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TangentialDistortion,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TangentialDistortion: 0,
+            ImageWidth,
+            ImageHeight,
+            #[cfg(feature = "Foundation_Numerics")]
+            ProjectOntoFrame,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            ProjectOntoFrame: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            UnprojectAtUnitDepth,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            UnprojectAtUnitDepth: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            ProjectManyOntoFrame,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            ProjectManyOntoFrame: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            UnprojectPixelsAtUnitDepth,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            UnprojectPixelsAtUnitDepth: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraIntrinsics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICameraIntrinsics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn UndistortedProjectionTransform(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Matrix4x4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsics2", "UndistortedProjectionTransform", 6usize, |this| ((**(this as *const *const ICameraIntrinsics2_Vtbl)).UndistortedProjectionTransform)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DistortPoint(this: *mut core::ffi::c_void, input: super::super::super::Foundation::Point, result__: *mut super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsics2", "DistortPoint", 7usize, |this| ((**(this as *const *const ICameraIntrinsics2_Vtbl)).DistortPoint)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DistortPoints(this: *mut core::ffi::c_void, inputs_array_size: u32, inputs: *const super::super::super::Foundation::Point, results_array_size: u32, results: *mut super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsics2", "DistortPoints", 8usize, |this| ((**(this as *const *const ICameraIntrinsics2_Vtbl)).DistortPoints)(this, inputs_array_size, inputs, results_array_size, results), |result| Some(*result))
+        }
+        unsafe extern "system" fn UndistortPoint(this: *mut core::ffi::c_void, input: super::super::super::Foundation::Point, result__: *mut super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsics2", "UndistortPoint", 9usize, |this| ((**(this as *const *const ICameraIntrinsics2_Vtbl)).UndistortPoint)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UndistortPoints(this: *mut core::ffi::c_void, inputs_array_size: u32, inputs: *const super::super::super::Foundation::Point, results_array_size: u32, results: *mut super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsics2", "UndistortPoints", 10usize, |this| ((**(this as *const *const ICameraIntrinsics2_Vtbl)).UndistortPoints)(this, inputs_array_size, inputs, results_array_size, results), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            UndistortedProjectionTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            UndistortedProjectionTransform: 0,
+            DistortPoint,
+            DistortPoints,
+            UndistortPoint,
+            UndistortPoints,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraIntrinsics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICameraIntrinsicsFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, focallength: super::super::super::Foundation::Numerics::Vector2, principalpoint: super::super::super::Foundation::Numerics::Vector2, radialdistortion: super::super::super::Foundation::Numerics::Vector3, tangentialdistortion: super::super::super::Foundation::Numerics::Vector2, imagewidth: u32, imageheight: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraIntrinsicsFactory", "Create", 6usize, |this| ((**(this as *const *const ICameraIntrinsicsFactory_Vtbl)).Create)(this, focallength, principalpoint, radialdistortion, tangentialdistortion, imagewidth, imageheight, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Create,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraIntrinsicsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for IDepthCorrelatedCoordinateMapper_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn UnprojectPoint(this: *mut core::ffi::c_void, sourcepoint: super::super::super::Foundation::Point, targetcoordinatesystem: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDepthCorrelatedCoordinateMapper", "UnprojectPoint", 6usize, |this| ((**(this as *const *const IDepthCorrelatedCoordinateMapper_Vtbl)).UnprojectPoint)(this, sourcepoint, targetcoordinatesystem, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn UnprojectPoints(this: *mut core::ffi::c_void, sourcePoints_array_size: u32, sourcepoints: *const super::super::super::Foundation::Point, targetcoordinatesystem: *mut core::ffi::c_void, results_array_size: u32, results: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDepthCorrelatedCoordinateMapper", "UnprojectPoints", 7usize, |this| ((**(this as *const *const IDepthCorrelatedCoordinateMapper_Vtbl)).UnprojectPoints)(this, sourcePoints_array_size, sourcepoints, targetcoordinatesystem, results_array_size, results), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn MapPoint(this: *mut core::ffi::c_void, sourcepoint: super::super::super::Foundation::Point, targetcoordinatesystem: *mut core::ffi::c_void, targetcameraintrinsics: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDepthCorrelatedCoordinateMapper", "MapPoint", 8usize, |this| ((**(this as *const *const IDepthCorrelatedCoordinateMapper_Vtbl)).MapPoint)(this, sourcepoint, targetcoordinatesystem, targetcameraintrinsics, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn MapPoints(this: *mut core::ffi::c_void, sourcePoints_array_size: u32, sourcepoints: *const super::super::super::Foundation::Point, targetcoordinatesystem: *mut core::ffi::c_void, targetcameraintrinsics: *mut core::ffi::c_void, results_array_size: u32, results: *mut super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDepthCorrelatedCoordinateMapper", "MapPoints", 9usize, |this| ((**(this as *const *const IDepthCorrelatedCoordinateMapper_Vtbl)).MapPoints)(this, sourcePoints_array_size, sourcepoints, targetcoordinatesystem, targetcameraintrinsics, results_array_size, results), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            UnprojectPoint,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            UnprojectPoint: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            UnprojectPoints,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            UnprojectPoints: 0,
+            #[cfg(feature = "Perception_Spatial")]
+            MapPoint,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            MapPoint: 0,
+            #[cfg(feature = "Perception_Spatial")]
+            MapPoints,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            MapPoints: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDepthCorrelatedCoordinateMapper as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameControlCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Exposure(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameControlCapabilities", "Exposure", 6usize, |this| ((**(this as *const *const IFrameControlCapabilities_Vtbl)).Exposure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExposureCompensation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameControlCapabilities", "ExposureCompensation", 7usize, |this| ((**(this as *const *const IFrameControlCapabilities_Vtbl)).ExposureCompensation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsoSpeed(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameControlCapabilities", "IsoSpeed", 8usize, |this| ((**(this as *const *const IFrameControlCapabilities_Vtbl)).IsoSpeed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Focus(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameControlCapabilities", "Focus", 9usize, |this| ((**(this as *const *const IFrameControlCapabilities_Vtbl)).Focus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotoConfirmationSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameControlCapabilities", "PhotoConfirmationSupported", 10usize, |this| ((**(this as *const *const IFrameControlCapabilities_Vtbl)).PhotoConfirmationSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Exposure,
+            ExposureCompensation,
+            IsoSpeed,
+            Focus,
+            PhotoConfirmationSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameControlCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameControlCapabilities2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Flash(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameControlCapabilities2", "Flash", 6usize, |this| ((**(this as *const *const IFrameControlCapabilities2_Vtbl)).Flash)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Flash }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameControlCapabilities2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExposureControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameController", "ExposureControl", 6usize, |this| ((**(this as *const *const IFrameController_Vtbl)).ExposureControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExposureCompensationControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameController", "ExposureCompensationControl", 7usize, |this| ((**(this as *const *const IFrameController_Vtbl)).ExposureCompensationControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsoSpeedControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameController", "IsoSpeedControl", 8usize, |this| ((**(this as *const *const IFrameController_Vtbl)).IsoSpeedControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FocusControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameController", "FocusControl", 9usize, |this| ((**(this as *const *const IFrameController_Vtbl)).FocusControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotoConfirmationEnabled(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameController", "PhotoConfirmationEnabled", 10usize, |this| ((**(this as *const *const IFrameController_Vtbl)).PhotoConfirmationEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPhotoConfirmationEnabled(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameController", "SetPhotoConfirmationEnabled", 11usize, |this| ((**(this as *const *const IFrameController_Vtbl)).SetPhotoConfirmationEnabled)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ExposureControl,
+            ExposureCompensationControl,
+            IsoSpeedControl,
+            FocusControl,
+            PhotoConfirmationEnabled,
+            SetPhotoConfirmationEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameController2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FlashControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameController2", "FlashControl", 6usize, |this| ((**(this as *const *const IFrameController2_Vtbl)).FlashControl)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FlashControl }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameController2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameExposureCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameExposureCapabilities", "Supported", 6usize, |this| ((**(this as *const *const IFrameExposureCapabilities_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Min(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameExposureCapabilities", "Min", 7usize, |this| ((**(this as *const *const IFrameExposureCapabilities_Vtbl)).Min)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Max(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameExposureCapabilities", "Max", 8usize, |this| ((**(this as *const *const IFrameExposureCapabilities_Vtbl)).Max)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Step(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameExposureCapabilities", "Step", 9usize, |this| ((**(this as *const *const IFrameExposureCapabilities_Vtbl)).Step)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Supported, Min, Max, Step }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameExposureCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameExposureCompensationCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameExposureCompensationCapabilities", "Supported", 6usize, |this| ((**(this as *const *const IFrameExposureCompensationCapabilities_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Min(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameExposureCompensationCapabilities", "Min", 7usize, |this| ((**(this as *const *const IFrameExposureCompensationCapabilities_Vtbl)).Min)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Max(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameExposureCompensationCapabilities", "Max", 8usize, |this| ((**(this as *const *const IFrameExposureCompensationCapabilities_Vtbl)).Max)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Step(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameExposureCompensationCapabilities", "Step", 9usize, |this| ((**(this as *const *const IFrameExposureCompensationCapabilities_Vtbl)).Step)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Supported, Min, Max, Step }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameExposureCompensationCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameExposureCompensationControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameExposureCompensationControl", "Value", 6usize, |this| ((**(this as *const *const IFrameExposureCompensationControl_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameExposureCompensationControl", "SetValue", 7usize, |this| ((**(this as *const *const IFrameExposureCompensationControl_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameExposureCompensationControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameExposureControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Auto(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameExposureControl", "Auto", 6usize, |this| ((**(this as *const *const IFrameExposureControl_Vtbl)).Auto)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuto(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameExposureControl", "SetAuto", 7usize, |this| ((**(this as *const *const IFrameExposureControl_Vtbl)).SetAuto)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameExposureControl", "Value", 8usize, |this| ((**(this as *const *const IFrameExposureControl_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameExposureControl", "SetValue", 9usize, |this| ((**(this as *const *const IFrameExposureControl_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Auto, SetAuto, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameExposureControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameFlashCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFlashCapabilities", "Supported", 6usize, |this| ((**(this as *const *const IFrameFlashCapabilities_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedEyeReductionSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFlashCapabilities", "RedEyeReductionSupported", 7usize, |this| ((**(this as *const *const IFrameFlashCapabilities_Vtbl)).RedEyeReductionSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFlashCapabilities", "PowerSupported", 8usize, |this| ((**(this as *const *const IFrameFlashCapabilities_Vtbl)).PowerSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Supported, RedEyeReductionSupported, PowerSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameFlashCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameFlashControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut FrameFlashMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFlashControl", "Mode", 6usize, |this| ((**(this as *const *const IFrameFlashControl_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, value: FrameFlashMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFlashControl", "SetMode", 7usize, |this| ((**(this as *const *const IFrameFlashControl_Vtbl)).SetMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Auto(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFlashControl", "Auto", 8usize, |this| ((**(this as *const *const IFrameFlashControl_Vtbl)).Auto)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuto(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFlashControl", "SetAuto", 9usize, |this| ((**(this as *const *const IFrameFlashControl_Vtbl)).SetAuto)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedEyeReduction(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFlashControl", "RedEyeReduction", 10usize, |this| ((**(this as *const *const IFrameFlashControl_Vtbl)).RedEyeReduction)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRedEyeReduction(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFlashControl", "SetRedEyeReduction", 11usize, |this| ((**(this as *const *const IFrameFlashControl_Vtbl)).SetRedEyeReduction)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerPercent(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFlashControl", "PowerPercent", 12usize, |this| ((**(this as *const *const IFrameFlashControl_Vtbl)).PowerPercent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPowerPercent(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFlashControl", "SetPowerPercent", 13usize, |this| ((**(this as *const *const IFrameFlashControl_Vtbl)).SetPowerPercent)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Mode,
+            SetMode,
+            Auto,
+            SetAuto,
+            RedEyeReduction,
+            SetRedEyeReduction,
+            PowerPercent,
+            SetPowerPercent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameFlashControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameFocusCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFocusCapabilities", "Supported", 6usize, |this| ((**(this as *const *const IFrameFocusCapabilities_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Min(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFocusCapabilities", "Min", 7usize, |this| ((**(this as *const *const IFrameFocusCapabilities_Vtbl)).Min)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Max(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFocusCapabilities", "Max", 8usize, |this| ((**(this as *const *const IFrameFocusCapabilities_Vtbl)).Max)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Step(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFocusCapabilities", "Step", 9usize, |this| ((**(this as *const *const IFrameFocusCapabilities_Vtbl)).Step)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Supported, Min, Max, Step }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameFocusCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameFocusControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFocusControl", "Value", 6usize, |this| ((**(this as *const *const IFrameFocusControl_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameFocusControl", "SetValue", 7usize, |this| ((**(this as *const *const IFrameFocusControl_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameFocusControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameIsoSpeedCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameIsoSpeedCapabilities", "Supported", 6usize, |this| ((**(this as *const *const IFrameIsoSpeedCapabilities_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Min(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameIsoSpeedCapabilities", "Min", 7usize, |this| ((**(this as *const *const IFrameIsoSpeedCapabilities_Vtbl)).Min)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Max(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameIsoSpeedCapabilities", "Max", 8usize, |this| ((**(this as *const *const IFrameIsoSpeedCapabilities_Vtbl)).Max)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Step(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameIsoSpeedCapabilities", "Step", 9usize, |this| ((**(this as *const *const IFrameIsoSpeedCapabilities_Vtbl)).Step)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Supported, Min, Max, Step }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameIsoSpeedCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrameIsoSpeedControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Auto(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameIsoSpeedControl", "Auto", 6usize, |this| ((**(this as *const *const IFrameIsoSpeedControl_Vtbl)).Auto)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuto(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameIsoSpeedControl", "SetAuto", 7usize, |this| ((**(this as *const *const IFrameIsoSpeedControl_Vtbl)).SetAuto)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameIsoSpeedControl", "Value", 8usize, |this| ((**(this as *const *const IFrameIsoSpeedControl_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrameIsoSpeedControl", "SetValue", 9usize, |this| ((**(this as *const *const IFrameIsoSpeedControl_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Auto, SetAuto, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrameIsoSpeedControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IVariablePhotoSequenceController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceController", "Supported", 6usize, |this| ((**(this as *const *const IVariablePhotoSequenceController_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPhotosPerSecond(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceController", "MaxPhotosPerSecond", 7usize, |this| ((**(this as *const *const IVariablePhotoSequenceController_Vtbl)).MaxPhotosPerSecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotosPerSecondLimit(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceController", "PhotosPerSecondLimit", 8usize, |this| ((**(this as *const *const IVariablePhotoSequenceController_Vtbl)).PhotosPerSecondLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPhotosPerSecondLimit(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceController", "SetPhotosPerSecondLimit", 9usize, |this| ((**(this as *const *const IVariablePhotoSequenceController_Vtbl)).SetPhotosPerSecondLimit)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn GetHighestConcurrentFrameRate(this: *mut core::ffi::c_void, captureproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceController", "GetHighestConcurrentFrameRate", 10usize, |this| ((**(this as *const *const IVariablePhotoSequenceController_Vtbl)).GetHighestConcurrentFrameRate)(this, captureproperties, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn GetCurrentFrameRate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceController", "GetCurrentFrameRate", 11usize, |this| ((**(this as *const *const IVariablePhotoSequenceController_Vtbl)).GetCurrentFrameRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameCapabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceController", "FrameCapabilities", 12usize, |this| ((**(this as *const *const IVariablePhotoSequenceController_Vtbl)).FrameCapabilities)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DesiredFrameControllers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariablePhotoSequenceController", "DesiredFrameControllers", 13usize, |this| ((**(this as *const *const IVariablePhotoSequenceController_Vtbl)).DesiredFrameControllers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Supported,
+            MaxPhotosPerSecond,
+            PhotosPerSecondLimit,
+            SetPhotosPerSecondLimit,
+            #[cfg(feature = "Media_MediaProperties")]
+            GetHighestConcurrentFrameRate,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            GetHighestConcurrentFrameRate: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            GetCurrentFrameRate,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            GetCurrentFrameRate: 0,
+            FrameCapabilities,
+            #[cfg(feature = "Foundation_Collections")]
+            DesiredFrameControllers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DesiredFrameControllers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVariablePhotoSequenceController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
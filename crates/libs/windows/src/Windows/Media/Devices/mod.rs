@@ -4142,6 +4142,7 @@ impl AdvancedPhotoMode {
 impl windows_core::TypeKind for AdvancedPhotoMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AdvancedPhotoMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AdvancedPhotoMode").field(&self.0).finish()
@@ -4160,6 +4161,7 @@ impl AudioDeviceRole {
 impl windows_core::TypeKind for AudioDeviceRole {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioDeviceRole {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioDeviceRole").field(&self.0).finish()
@@ -4179,6 +4181,7 @@ impl AutoFocusRange {
 impl windows_core::TypeKind for AutoFocusRange {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AutoFocusRange {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AutoFocusRange").field(&self.0).finish()
@@ -4197,6 +4200,7 @@ impl CameraOcclusionKind {
 impl windows_core::TypeKind for CameraOcclusionKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CameraOcclusionKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CameraOcclusionKind").field(&self.0).finish()
@@ -4217,6 +4221,7 @@ impl CameraStreamState {
 impl windows_core::TypeKind for CameraStreamState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CameraStreamState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CameraStreamState").field(&self.0).finish()
@@ -4246,6 +4251,7 @@ impl CaptureSceneMode {
 impl windows_core::TypeKind for CaptureSceneMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CaptureSceneMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CaptureSceneMode").field(&self.0).finish()
@@ -4265,6 +4271,7 @@ impl CaptureUse {
 impl windows_core::TypeKind for CaptureUse {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CaptureUse {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CaptureUse").field(&self.0).finish()
@@ -4289,6 +4296,7 @@ impl ColorTemperaturePreset {
 impl windows_core::TypeKind for ColorTemperaturePreset {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ColorTemperaturePreset {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ColorTemperaturePreset").field(&self.0).finish()
@@ -4308,6 +4316,7 @@ impl DigitalWindowMode {
 impl windows_core::TypeKind for DigitalWindowMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DigitalWindowMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DigitalWindowMode").field(&self.0).finish()
@@ -4328,6 +4337,7 @@ impl FocusMode {
 impl windows_core::TypeKind for FocusMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FocusMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FocusMode").field(&self.0).finish()
@@ -4350,6 +4360,7 @@ impl FocusPreset {
 impl windows_core::TypeKind for FocusPreset {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FocusPreset {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FocusPreset").field(&self.0).finish()
@@ -4369,6 +4380,7 @@ impl HdrVideoMode {
 impl windows_core::TypeKind for HdrVideoMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HdrVideoMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HdrVideoMode").field(&self.0).finish()
@@ -4388,6 +4400,7 @@ impl InfraredTorchMode {
 impl windows_core::TypeKind for InfraredTorchMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InfraredTorchMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InfraredTorchMode").field(&self.0).finish()
@@ -4420,6 +4433,7 @@ impl windows_core::TypeKind for IsoSpeedPreset {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsoSpeedPreset {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsoSpeedPreset").field(&self.0).finish()
@@ -4440,6 +4454,7 @@ impl ManualFocusDistance {
 impl windows_core::TypeKind for ManualFocusDistance {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ManualFocusDistance {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ManualFocusDistance").field(&self.0).finish()
@@ -4461,6 +4476,7 @@ impl MediaCaptureFocusState {
 impl windows_core::TypeKind for MediaCaptureFocusState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaCaptureFocusState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaCaptureFocusState").field(&self.0).finish()
@@ -4484,6 +4500,7 @@ impl MediaCaptureOptimization {
 impl windows_core::TypeKind for MediaCaptureOptimization {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaCaptureOptimization {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaCaptureOptimization").field(&self.0).finish()
@@ -4502,6 +4519,7 @@ impl MediaCapturePauseBehavior {
 impl windows_core::TypeKind for MediaCapturePauseBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaCapturePauseBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaCapturePauseBehavior").field(&self.0).finish()
@@ -4521,6 +4539,7 @@ impl OpticalImageStabilizationMode {
 impl windows_core::TypeKind for OpticalImageStabilizationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OpticalImageStabilizationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OpticalImageStabilizationMode").field(&self.0).finish()
@@ -4539,6 +4558,7 @@ impl RegionOfInterestType {
 impl windows_core::TypeKind for RegionOfInterestType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RegionOfInterestType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RegionOfInterestType").field(&self.0).finish()
@@ -4557,6 +4577,7 @@ impl SendCommandStatus {
 impl windows_core::TypeKind for SendCommandStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SendCommandStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SendCommandStatus").field(&self.0).finish()
@@ -4589,6 +4610,7 @@ impl TelephonyKey {
 impl windows_core::TypeKind for TelephonyKey {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TelephonyKey {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TelephonyKey").field(&self.0).finish()
@@ -4612,6 +4634,7 @@ impl VideoDeviceControllerGetDevicePropertyStatus {
 impl windows_core::TypeKind for VideoDeviceControllerGetDevicePropertyStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VideoDeviceControllerGetDevicePropertyStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VideoDeviceControllerGetDevicePropertyStatus").field(&self.0).finish()
@@ -4634,6 +4657,7 @@ impl VideoDeviceControllerSetDevicePropertyStatus {
 impl windows_core::TypeKind for VideoDeviceControllerSetDevicePropertyStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VideoDeviceControllerSetDevicePropertyStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VideoDeviceControllerSetDevicePropertyStatus").field(&self.0).finish()
@@ -4653,6 +4677,7 @@ impl VideoTemporalDenoisingMode {
 impl windows_core::TypeKind for VideoTemporalDenoisingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VideoTemporalDenoisingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VideoTemporalDenoisingMode").field(&self.0).finish()
@@ -4672,6 +4697,7 @@ impl ZoomTransitionMode {
 impl windows_core::TypeKind for ZoomTransitionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ZoomTransitionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ZoomTransitionMode").field(&self.0).finish()
@@ -4925,3 +4951,5 @@ pub struct RedialRequestedEventHandler_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
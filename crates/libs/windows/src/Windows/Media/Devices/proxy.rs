@@ -0,0 +1,2128 @@
+impl windows_core::imp::ProxyVtable for IAdvancedPhotoCaptureSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut AdvancedPhotoMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedPhotoCaptureSettings", "Mode", 6usize, |this| ((**(this as *const *const IAdvancedPhotoCaptureSettings_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, value: AdvancedPhotoMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedPhotoCaptureSettings", "SetMode", 7usize, |this| ((**(this as *const *const IAdvancedPhotoCaptureSettings_Vtbl)).SetMode)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Mode, SetMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedPhotoCaptureSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAdvancedPhotoControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedPhotoControl", "Supported", 6usize, |this| ((**(this as *const *const IAdvancedPhotoControl_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedModes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedPhotoControl", "SupportedModes", 7usize, |this| ((**(this as *const *const IAdvancedPhotoControl_Vtbl)).SupportedModes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut AdvancedPhotoMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedPhotoControl", "Mode", 8usize, |this| ((**(this as *const *const IAdvancedPhotoControl_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configure(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedPhotoControl", "Configure", 9usize, |this| ((**(this as *const *const IAdvancedPhotoControl_Vtbl)).Configure)(this, settings), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Supported,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedModes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedModes: 0,
+            Mode,
+            Configure,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedPhotoControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdvancedVideoCaptureDeviceController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDeviceProperty(this: *mut core::ffi::c_void, propertyid: core::mem::MaybeUninit<windows_core::HSTRING>, propertyvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController", "SetDeviceProperty", 6usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController_Vtbl)).SetDeviceProperty)(this, propertyid, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceProperty(this: *mut core::ffi::c_void, propertyid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController", "GetDeviceProperty", 7usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController_Vtbl)).GetDeviceProperty)(this, propertyid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDeviceProperty, GetDeviceProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedVideoCaptureDeviceController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdvancedVideoCaptureDeviceController10_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CameraOcclusionInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController10", "CameraOcclusionInfo", 6usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController10_Vtbl)).CameraOcclusionInfo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CameraOcclusionInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedVideoCaptureDeviceController10 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Capture")]
+impl windows_core::imp::ProxyVtable for IAdvancedVideoCaptureDeviceController11_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Capture")]
+        unsafe extern "system" fn TryAcquireExclusiveControl(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, mode: super::Capture::MediaCaptureDeviceExclusiveControlReleaseMode, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController11", "TryAcquireExclusiveControl", 6usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController11_Vtbl)).TryAcquireExclusiveControl)(this, deviceid, mode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Capture")]
+            TryAcquireExclusiveControl,
+            #[cfg(not(feature = "Media_Capture"))]
+            TryAcquireExclusiveControl: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedVideoCaptureDeviceController11 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdvancedVideoCaptureDeviceController2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LowLagPhotoSequence(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController2", "LowLagPhotoSequence", 6usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController2_Vtbl)).LowLagPhotoSequence)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LowLagPhoto(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController2", "LowLagPhoto", 7usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController2_Vtbl)).LowLagPhoto)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SceneModeControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController2", "SceneModeControl", 8usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController2_Vtbl)).SceneModeControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TorchControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController2", "TorchControl", 9usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController2_Vtbl)).TorchControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlashControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController2", "FlashControl", 10usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController2_Vtbl)).FlashControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WhiteBalanceControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController2", "WhiteBalanceControl", 11usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController2_Vtbl)).WhiteBalanceControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExposureControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController2", "ExposureControl", 12usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController2_Vtbl)).ExposureControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FocusControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController2", "FocusControl", 13usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController2_Vtbl)).FocusControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExposureCompensationControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController2", "ExposureCompensationControl", 14usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController2_Vtbl)).ExposureCompensationControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsoSpeedControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController2", "IsoSpeedControl", 15usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController2_Vtbl)).IsoSpeedControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegionsOfInterestControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController2", "RegionsOfInterestControl", 16usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController2_Vtbl)).RegionsOfInterestControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrimaryUse(this: *mut core::ffi::c_void, result__: *mut CaptureUse) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController2", "PrimaryUse", 17usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController2_Vtbl)).PrimaryUse)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrimaryUse(this: *mut core::ffi::c_void, value: CaptureUse) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController2", "SetPrimaryUse", 18usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController2_Vtbl)).SetPrimaryUse)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LowLagPhotoSequence,
+            LowLagPhoto,
+            SceneModeControl,
+            TorchControl,
+            FlashControl,
+            WhiteBalanceControl,
+            ExposureControl,
+            FocusControl,
+            ExposureCompensationControl,
+            IsoSpeedControl,
+            RegionsOfInterestControl,
+            PrimaryUse,
+            SetPrimaryUse,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedVideoCaptureDeviceController2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Devices_Core")]
+impl windows_core::imp::ProxyVtable for IAdvancedVideoCaptureDeviceController3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Devices_Core")]
+        unsafe extern "system" fn VariablePhotoSequenceController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController3", "VariablePhotoSequenceController", 6usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController3_Vtbl)).VariablePhotoSequenceController)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotoConfirmationControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController3", "PhotoConfirmationControl", 7usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController3_Vtbl)).PhotoConfirmationControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZoomControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController3", "ZoomControl", 8usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController3_Vtbl)).ZoomControl)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Devices_Core")]
+            VariablePhotoSequenceController,
+            #[cfg(not(feature = "Media_Devices_Core"))]
+            VariablePhotoSequenceController: 0,
+            PhotoConfirmationControl,
+            ZoomControl,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedVideoCaptureDeviceController3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdvancedVideoCaptureDeviceController4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExposurePriorityVideoControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController4", "ExposurePriorityVideoControl", 6usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController4_Vtbl)).ExposurePriorityVideoControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredOptimization(this: *mut core::ffi::c_void, result__: *mut MediaCaptureOptimization) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController4", "DesiredOptimization", 7usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController4_Vtbl)).DesiredOptimization)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredOptimization(this: *mut core::ffi::c_void, value: MediaCaptureOptimization) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController4", "SetDesiredOptimization", 8usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController4_Vtbl)).SetDesiredOptimization)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HdrVideoControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController4", "HdrVideoControl", 9usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController4_Vtbl)).HdrVideoControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpticalImageStabilizationControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController4", "OpticalImageStabilizationControl", 10usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController4_Vtbl)).OpticalImageStabilizationControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdvancedPhotoControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController4", "AdvancedPhotoControl", 11usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController4_Vtbl)).AdvancedPhotoControl)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ExposurePriorityVideoControl,
+            DesiredOptimization,
+            SetDesiredOptimization,
+            HdrVideoControl,
+            OpticalImageStabilizationControl,
+            AdvancedPhotoControl,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedVideoCaptureDeviceController4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdvancedVideoCaptureDeviceController5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController5", "Id", 6usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController5_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDevicePropertyById(this: *mut core::ffi::c_void, propertyid: core::mem::MaybeUninit<windows_core::HSTRING>, maxpropertyvaluesize: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController5", "GetDevicePropertyById", 7usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController5_Vtbl)).GetDevicePropertyById)(this, propertyid, maxpropertyvaluesize, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDevicePropertyById(this: *mut core::ffi::c_void, propertyid: core::mem::MaybeUninit<windows_core::HSTRING>, propertyvalue: *mut core::ffi::c_void, result__: *mut VideoDeviceControllerSetDevicePropertyStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController5", "SetDevicePropertyById", 8usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController5_Vtbl)).SetDevicePropertyById)(this, propertyid, propertyvalue, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDevicePropertyByExtendedId(this: *mut core::ffi::c_void, extendedPropertyId_array_size: u32, extendedpropertyid: *const u8, maxpropertyvaluesize: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController5", "GetDevicePropertyByExtendedId", 9usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController5_Vtbl)).GetDevicePropertyByExtendedId)(this, extendedPropertyId_array_size, extendedpropertyid, maxpropertyvaluesize, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDevicePropertyByExtendedId(this: *mut core::ffi::c_void, extendedPropertyId_array_size: u32, extendedpropertyid: *const u8, propertyValue_array_size: u32, propertyvalue: *const u8, result__: *mut VideoDeviceControllerSetDevicePropertyStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController5", "SetDevicePropertyByExtendedId", 10usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController5_Vtbl)).SetDevicePropertyByExtendedId)(this, extendedPropertyId_array_size, extendedpropertyid, propertyValue_array_size, propertyvalue, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            GetDevicePropertyById,
+            SetDevicePropertyById,
+            GetDevicePropertyByExtendedId,
+            SetDevicePropertyByExtendedId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedVideoCaptureDeviceController5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdvancedVideoCaptureDeviceController6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VideoTemporalDenoisingControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController6", "VideoTemporalDenoisingControl", 6usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController6_Vtbl)).VideoTemporalDenoisingControl)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, VideoTemporalDenoisingControl }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedVideoCaptureDeviceController6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdvancedVideoCaptureDeviceController7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InfraredTorchControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController7", "InfraredTorchControl", 6usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController7_Vtbl)).InfraredTorchControl)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InfraredTorchControl }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedVideoCaptureDeviceController7 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdvancedVideoCaptureDeviceController8_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PanelBasedOptimizationControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController8", "PanelBasedOptimizationControl", 6usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController8_Vtbl)).PanelBasedOptimizationControl)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PanelBasedOptimizationControl }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedVideoCaptureDeviceController8 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdvancedVideoCaptureDeviceController9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DigitalWindowControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedVideoCaptureDeviceController9", "DigitalWindowControl", 6usize, |this| ((**(this as *const *const IAdvancedVideoCaptureDeviceController9_Vtbl)).DigitalWindowControl)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DigitalWindowControl }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedVideoCaptureDeviceController9 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Capture", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IAudioDeviceController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMuted(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceController", "SetMuted", 6usize, |this| ((**(this as *const *const IAudioDeviceController_Vtbl)).SetMuted)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Muted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceController", "Muted", 7usize, |this| ((**(this as *const *const IAudioDeviceController_Vtbl)).Muted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolumePercent(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceController", "SetVolumePercent", 8usize, |this| ((**(this as *const *const IAudioDeviceController_Vtbl)).SetVolumePercent)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumePercent(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceController", "VolumePercent", 9usize, |this| ((**(this as *const *const IAudioDeviceController_Vtbl)).VolumePercent)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMuted, Muted, SetVolumePercent, VolumePercent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioDeviceController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Effects")]
+impl windows_core::imp::ProxyVtable for IAudioDeviceController2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Effects")]
+        unsafe extern "system" fn AudioCaptureEffectsManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceController2", "AudioCaptureEffectsManager", 6usize, |this| ((**(this as *const *const IAudioDeviceController2_Vtbl)).AudioCaptureEffectsManager)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Effects")]
+            AudioCaptureEffectsManager,
+            #[cfg(not(feature = "Media_Effects"))]
+            AudioCaptureEffectsManager: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioDeviceController2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IAudioDeviceModule_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClassId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceModule", "ClassId", 6usize, |this| ((**(this as *const *const IAudioDeviceModule_Vtbl)).ClassId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceModule", "DisplayName", 7usize, |this| ((**(this as *const *const IAudioDeviceModule_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstanceId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceModule", "InstanceId", 8usize, |this| ((**(this as *const *const IAudioDeviceModule_Vtbl)).InstanceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MajorVersion(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceModule", "MajorVersion", 9usize, |this| ((**(this as *const *const IAudioDeviceModule_Vtbl)).MajorVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinorVersion(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceModule", "MinorVersion", 10usize, |this| ((**(this as *const *const IAudioDeviceModule_Vtbl)).MinorVersion)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SendCommandAsync(this: *mut core::ffi::c_void, command: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceModule", "SendCommandAsync", 11usize, |this| ((**(this as *const *const IAudioDeviceModule_Vtbl)).SendCommandAsync)(this, command, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ClassId,
+            DisplayName,
+            InstanceId,
+            MajorVersion,
+            MinorVersion,
+            #[cfg(feature = "Storage_Streams")]
+            SendCommandAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SendCommandAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioDeviceModule as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IAudioDeviceModuleNotificationEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Module(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceModuleNotificationEventArgs", "Module", 6usize, |this| ((**(this as *const *const IAudioDeviceModuleNotificationEventArgs_Vtbl)).Module)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn NotificationData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceModuleNotificationEventArgs", "NotificationData", 7usize, |this| ((**(this as *const *const IAudioDeviceModuleNotificationEventArgs_Vtbl)).NotificationData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Module,
+            #[cfg(feature = "Storage_Streams")]
+            NotificationData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            NotificationData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioDeviceModuleNotificationEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAudioDeviceModulesManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ModuleNotificationReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceModulesManager", "ModuleNotificationReceived", 6usize, |this| ((**(this as *const *const IAudioDeviceModulesManager_Vtbl)).ModuleNotificationReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveModuleNotificationReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceModulesManager", "RemoveModuleNotificationReceived", 7usize, |this| ((**(this as *const *const IAudioDeviceModulesManager_Vtbl)).RemoveModuleNotificationReceived)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllById(this: *mut core::ffi::c_void, moduleid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceModulesManager", "FindAllById", 8usize, |this| ((**(this as *const *const IAudioDeviceModulesManager_Vtbl)).FindAllById)(this, moduleid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAll(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceModulesManager", "FindAll", 9usize, |this| ((**(this as *const *const IAudioDeviceModulesManager_Vtbl)).FindAll)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ModuleNotificationReceived,
+            RemoveModuleNotificationReceived,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllById,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllById: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAll,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAll: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioDeviceModulesManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioDeviceModulesManagerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceModulesManagerFactory", "Create", 6usize, |this| ((**(this as *const *const IAudioDeviceModulesManagerFactory_Vtbl)).Create)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioDeviceModulesManagerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICallControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IndicateNewIncomingCall(this: *mut core::ffi::c_void, enableringer: bool, callerid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "IndicateNewIncomingCall", 6usize, |this| ((**(this as *const *const ICallControl_Vtbl)).IndicateNewIncomingCall)(this, enableringer, callerid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IndicateNewOutgoingCall(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "IndicateNewOutgoingCall", 7usize, |this| ((**(this as *const *const ICallControl_Vtbl)).IndicateNewOutgoingCall)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IndicateActiveCall(this: *mut core::ffi::c_void, calltoken: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "IndicateActiveCall", 8usize, |this| ((**(this as *const *const ICallControl_Vtbl)).IndicateActiveCall)(this, calltoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndCall(this: *mut core::ffi::c_void, calltoken: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "EndCall", 9usize, |this| ((**(this as *const *const ICallControl_Vtbl)).EndCall)(this, calltoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasRinger(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "HasRinger", 10usize, |this| ((**(this as *const *const ICallControl_Vtbl)).HasRinger)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnswerRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "AnswerRequested", 11usize, |this| ((**(this as *const *const ICallControl_Vtbl)).AnswerRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAnswerRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "RemoveAnswerRequested", 12usize, |this| ((**(this as *const *const ICallControl_Vtbl)).RemoveAnswerRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn HangUpRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "HangUpRequested", 13usize, |this| ((**(this as *const *const ICallControl_Vtbl)).HangUpRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHangUpRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "RemoveHangUpRequested", 14usize, |this| ((**(this as *const *const ICallControl_Vtbl)).RemoveHangUpRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DialRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "DialRequested", 15usize, |this| ((**(this as *const *const ICallControl_Vtbl)).DialRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDialRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "RemoveDialRequested", 16usize, |this| ((**(this as *const *const ICallControl_Vtbl)).RemoveDialRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedialRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "RedialRequested", 17usize, |this| ((**(this as *const *const ICallControl_Vtbl)).RedialRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRedialRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "RemoveRedialRequested", 18usize, |this| ((**(this as *const *const ICallControl_Vtbl)).RemoveRedialRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeypadPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "KeypadPressed", 19usize, |this| ((**(this as *const *const ICallControl_Vtbl)).KeypadPressed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveKeypadPressed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "RemoveKeypadPressed", 20usize, |this| ((**(this as *const *const ICallControl_Vtbl)).RemoveKeypadPressed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioTransferRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "AudioTransferRequested", 21usize, |this| ((**(this as *const *const ICallControl_Vtbl)).AudioTransferRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAudioTransferRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControl", "RemoveAudioTransferRequested", 22usize, |this| ((**(this as *const *const ICallControl_Vtbl)).RemoveAudioTransferRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IndicateNewIncomingCall,
+            IndicateNewOutgoingCall,
+            IndicateActiveCall,
+            EndCall,
+            HasRinger,
+            AnswerRequested,
+            RemoveAnswerRequested,
+            HangUpRequested,
+            RemoveHangUpRequested,
+            DialRequested,
+            RemoveDialRequested,
+            RedialRequested,
+            RemoveRedialRequested,
+            KeypadPressed,
+            RemoveKeypadPressed,
+            AudioTransferRequested,
+            RemoveAudioTransferRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICallControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICallControlStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControlStatics", "GetDefault", 6usize, |this| ((**(this as *const *const ICallControlStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromId(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallControlStatics", "FromId", 7usize, |this| ((**(this as *const *const ICallControlStatics_Vtbl)).FromId)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault, FromId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICallControlStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICameraOcclusionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraOcclusionInfo", "GetState", 6usize, |this| ((**(this as *const *const ICameraOcclusionInfo_Vtbl)).GetState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOcclusionKindSupported(this: *mut core::ffi::c_void, occlusionkind: CameraOcclusionKind, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraOcclusionInfo", "IsOcclusionKindSupported", 7usize, |this| ((**(this as *const *const ICameraOcclusionInfo_Vtbl)).IsOcclusionKindSupported)(this, occlusionkind, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraOcclusionInfo", "StateChanged", 8usize, |this| ((**(this as *const *const ICameraOcclusionInfo_Vtbl)).StateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraOcclusionInfo", "RemoveStateChanged", 9usize, |this| ((**(this as *const *const ICameraOcclusionInfo_Vtbl)).RemoveStateChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetState,
+            IsOcclusionKindSupported,
+            StateChanged,
+            RemoveStateChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraOcclusionInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICameraOcclusionState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsOccluded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraOcclusionState", "IsOccluded", 6usize, |this| ((**(this as *const *const ICameraOcclusionState_Vtbl)).IsOccluded)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOcclusionKind(this: *mut core::ffi::c_void, occlusionkind: CameraOcclusionKind, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraOcclusionState", "IsOcclusionKind", 7usize, |this| ((**(this as *const *const ICameraOcclusionState_Vtbl)).IsOcclusionKind)(this, occlusionkind, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsOccluded, IsOcclusionKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraOcclusionState as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICameraOcclusionStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraOcclusionStateChangedEventArgs", "State", 6usize, |this| ((**(this as *const *const ICameraOcclusionStateChangedEventArgs_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, State }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraOcclusionStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDefaultAudioDeviceChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDefaultAudioDeviceChangedEventArgs", "Id", 6usize, |this| ((**(this as *const *const IDefaultAudioDeviceChangedEventArgs_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Role(this: *mut core::ffi::c_void, result__: *mut AudioDeviceRole) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDefaultAudioDeviceChangedEventArgs", "Role", 7usize, |this| ((**(this as *const *const IDefaultAudioDeviceChangedEventArgs_Vtbl)).Role)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Role }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDefaultAudioDeviceChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDialRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialRequestedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IDialRequestedEventArgs_Vtbl)).Handled)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialRequestedEventArgs", "Contact", 7usize, |this| ((**(this as *const *const IDialRequestedEventArgs_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, Contact }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDigitalWindowBounds_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NormalizedOriginTop(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowBounds", "NormalizedOriginTop", 6usize, |this| ((**(this as *const *const IDigitalWindowBounds_Vtbl)).NormalizedOriginTop)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNormalizedOriginTop(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowBounds", "SetNormalizedOriginTop", 7usize, |this| ((**(this as *const *const IDigitalWindowBounds_Vtbl)).SetNormalizedOriginTop)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NormalizedOriginLeft(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowBounds", "NormalizedOriginLeft", 8usize, |this| ((**(this as *const *const IDigitalWindowBounds_Vtbl)).NormalizedOriginLeft)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNormalizedOriginLeft(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowBounds", "SetNormalizedOriginLeft", 9usize, |this| ((**(this as *const *const IDigitalWindowBounds_Vtbl)).SetNormalizedOriginLeft)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scale(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowBounds", "Scale", 10usize, |this| ((**(this as *const *const IDigitalWindowBounds_Vtbl)).Scale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScale(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowBounds", "SetScale", 11usize, |this| ((**(this as *const *const IDigitalWindowBounds_Vtbl)).SetScale)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NormalizedOriginTop,
+            SetNormalizedOriginTop,
+            NormalizedOriginLeft,
+            SetNormalizedOriginLeft,
+            Scale,
+            SetScale,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDigitalWindowBounds as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDigitalWindowCapability_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowCapability", "Width", 6usize, |this| ((**(this as *const *const IDigitalWindowCapability_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowCapability", "Height", 7usize, |this| ((**(this as *const *const IDigitalWindowCapability_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinScaleValue(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowCapability", "MinScaleValue", 8usize, |this| ((**(this as *const *const IDigitalWindowCapability_Vtbl)).MinScaleValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxScaleValue(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowCapability", "MaxScaleValue", 9usize, |this| ((**(this as *const *const IDigitalWindowCapability_Vtbl)).MaxScaleValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinScaleValueWithoutUpsampling(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowCapability", "MinScaleValueWithoutUpsampling", 10usize, |this| ((**(this as *const *const IDigitalWindowCapability_Vtbl)).MinScaleValueWithoutUpsampling)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NormalizedFieldOfViewLimit(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowCapability", "NormalizedFieldOfViewLimit", 11usize, |this| ((**(this as *const *const IDigitalWindowCapability_Vtbl)).NormalizedFieldOfViewLimit)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Width,
+            Height,
+            MinScaleValue,
+            MaxScaleValue,
+            MinScaleValueWithoutUpsampling,
+            NormalizedFieldOfViewLimit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDigitalWindowCapability as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDigitalWindowControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowControl", "IsSupported", 6usize, |this| ((**(this as *const *const IDigitalWindowControl_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedModes(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut DigitalWindowMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowControl", "SupportedModes", 7usize, |this| ((**(this as *const *const IDigitalWindowControl_Vtbl)).SupportedModes)(this, result_size__, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentMode(this: *mut core::ffi::c_void, result__: *mut DigitalWindowMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowControl", "CurrentMode", 8usize, |this| ((**(this as *const *const IDigitalWindowControl_Vtbl)).CurrentMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBounds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowControl", "GetBounds", 9usize, |this| ((**(this as *const *const IDigitalWindowControl_Vtbl)).GetBounds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configure(this: *mut core::ffi::c_void, digitalwindowmode: DigitalWindowMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowControl", "Configure", 10usize, |this| ((**(this as *const *const IDigitalWindowControl_Vtbl)).Configure)(this, digitalwindowmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConfigureWithBounds(this: *mut core::ffi::c_void, digitalwindowmode: DigitalWindowMode, digitalwindowbounds: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowControl", "ConfigureWithBounds", 11usize, |this| ((**(this as *const *const IDigitalWindowControl_Vtbl)).ConfigureWithBounds)(this, digitalwindowmode, digitalwindowbounds), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedCapabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowControl", "SupportedCapabilities", 12usize, |this| ((**(this as *const *const IDigitalWindowControl_Vtbl)).SupportedCapabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCapabilityForSize(this: *mut core::ffi::c_void, width: i32, height: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalWindowControl", "GetCapabilityForSize", 13usize, |this| ((**(this as *const *const IDigitalWindowControl_Vtbl)).GetCapabilityForSize)(this, width, height, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupported,
+            SupportedModes,
+            CurrentMode,
+            GetBounds,
+            Configure,
+            ConfigureWithBounds,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedCapabilities,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedCapabilities: 0,
+            GetCapabilityForSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDigitalWindowControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IExposureCompensationControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposureCompensationControl", "Supported", 6usize, |this| ((**(this as *const *const IExposureCompensationControl_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Min(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposureCompensationControl", "Min", 7usize, |this| ((**(this as *const *const IExposureCompensationControl_Vtbl)).Min)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Max(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposureCompensationControl", "Max", 8usize, |this| ((**(this as *const *const IExposureCompensationControl_Vtbl)).Max)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Step(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposureCompensationControl", "Step", 9usize, |this| ((**(this as *const *const IExposureCompensationControl_Vtbl)).Step)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposureCompensationControl", "Value", 10usize, |this| ((**(this as *const *const IExposureCompensationControl_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueAsync(this: *mut core::ffi::c_void, value: f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposureCompensationControl", "SetValueAsync", 11usize, |this| ((**(this as *const *const IExposureCompensationControl_Vtbl)).SetValueAsync)(this, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Supported, Min, Max, Step, Value, SetValueAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExposureCompensationControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IExposureControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposureControl", "Supported", 6usize, |this| ((**(this as *const *const IExposureControl_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Auto(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposureControl", "Auto", 7usize, |this| ((**(this as *const *const IExposureControl_Vtbl)).Auto)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoAsync(this: *mut core::ffi::c_void, value: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposureControl", "SetAutoAsync", 8usize, |this| ((**(this as *const *const IExposureControl_Vtbl)).SetAutoAsync)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Min(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposureControl", "Min", 9usize, |this| ((**(this as *const *const IExposureControl_Vtbl)).Min)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Max(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposureControl", "Max", 10usize, |this| ((**(this as *const *const IExposureControl_Vtbl)).Max)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Step(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposureControl", "Step", 11usize, |this| ((**(this as *const *const IExposureControl_Vtbl)).Step)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposureControl", "Value", 12usize, |this| ((**(this as *const *const IExposureControl_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueAsync(this: *mut core::ffi::c_void, shutterduration: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposureControl", "SetValueAsync", 13usize, |this| ((**(this as *const *const IExposureControl_Vtbl)).SetValueAsync)(this, shutterduration, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Supported,
+            Auto,
+            SetAutoAsync,
+            Min,
+            Max,
+            Step,
+            Value,
+            SetValueAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExposureControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IExposurePriorityVideoControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposurePriorityVideoControl", "Supported", 6usize, |this| ((**(this as *const *const IExposurePriorityVideoControl_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposurePriorityVideoControl", "Enabled", 7usize, |this| ((**(this as *const *const IExposurePriorityVideoControl_Vtbl)).Enabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExposurePriorityVideoControl", "SetEnabled", 8usize, |this| ((**(this as *const *const IExposurePriorityVideoControl_Vtbl)).SetEnabled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Supported, Enabled, SetEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExposurePriorityVideoControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFlashControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlashControl", "Supported", 6usize, |this| ((**(this as *const *const IFlashControl_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlashControl", "PowerSupported", 7usize, |this| ((**(this as *const *const IFlashControl_Vtbl)).PowerSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedEyeReductionSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlashControl", "RedEyeReductionSupported", 8usize, |this| ((**(this as *const *const IFlashControl_Vtbl)).RedEyeReductionSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlashControl", "Enabled", 9usize, |this| ((**(this as *const *const IFlashControl_Vtbl)).Enabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlashControl", "SetEnabled", 10usize, |this| ((**(this as *const *const IFlashControl_Vtbl)).SetEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Auto(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlashControl", "Auto", 11usize, |this| ((**(this as *const *const IFlashControl_Vtbl)).Auto)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuto(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlashControl", "SetAuto", 12usize, |this| ((**(this as *const *const IFlashControl_Vtbl)).SetAuto)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedEyeReduction(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlashControl", "RedEyeReduction", 13usize, |this| ((**(this as *const *const IFlashControl_Vtbl)).RedEyeReduction)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRedEyeReduction(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlashControl", "SetRedEyeReduction", 14usize, |this| ((**(this as *const *const IFlashControl_Vtbl)).SetRedEyeReduction)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerPercent(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlashControl", "PowerPercent", 15usize, |this| ((**(this as *const *const IFlashControl_Vtbl)).PowerPercent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPowerPercent(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlashControl", "SetPowerPercent", 16usize, |this| ((**(this as *const *const IFlashControl_Vtbl)).SetPowerPercent)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Supported,
+            PowerSupported,
+            RedEyeReductionSupported,
+            Enabled,
+            SetEnabled,
+            Auto,
+            SetAuto,
+            RedEyeReduction,
+            SetRedEyeReduction,
+            PowerPercent,
+            SetPowerPercent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFlashControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFlashControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AssistantLightSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlashControl2", "AssistantLightSupported", 6usize, |this| ((**(this as *const *const IFlashControl2_Vtbl)).AssistantLightSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AssistantLightEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlashControl2", "AssistantLightEnabled", 7usize, |this| ((**(this as *const *const IFlashControl2_Vtbl)).AssistantLightEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAssistantLightEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFlashControl2", "SetAssistantLightEnabled", 8usize, |this| ((**(this as *const *const IFlashControl2_Vtbl)).SetAssistantLightEnabled)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AssistantLightSupported,
+            AssistantLightEnabled,
+            SetAssistantLightEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFlashControl2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IFocusControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl", "Supported", 6usize, |this| ((**(this as *const *const IFocusControl_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedPresets(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl", "SupportedPresets", 7usize, |this| ((**(this as *const *const IFocusControl_Vtbl)).SupportedPresets)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Preset(this: *mut core::ffi::c_void, result__: *mut FocusPreset) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl", "Preset", 8usize, |this| ((**(this as *const *const IFocusControl_Vtbl)).Preset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPresetAsync(this: *mut core::ffi::c_void, preset: FocusPreset, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl", "SetPresetAsync", 9usize, |this| ((**(this as *const *const IFocusControl_Vtbl)).SetPresetAsync)(this, preset, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPresetWithCompletionOptionAsync(this: *mut core::ffi::c_void, preset: FocusPreset, completebeforefocus: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl", "SetPresetWithCompletionOptionAsync", 10usize, |this| ((**(this as *const *const IFocusControl_Vtbl)).SetPresetWithCompletionOptionAsync)(this, preset, completebeforefocus, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Min(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl", "Min", 11usize, |this| ((**(this as *const *const IFocusControl_Vtbl)).Min)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Max(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl", "Max", 12usize, |this| ((**(this as *const *const IFocusControl_Vtbl)).Max)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Step(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl", "Step", 13usize, |this| ((**(this as *const *const IFocusControl_Vtbl)).Step)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl", "Value", 14usize, |this| ((**(this as *const *const IFocusControl_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueAsync(this: *mut core::ffi::c_void, focus: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl", "SetValueAsync", 15usize, |this| ((**(this as *const *const IFocusControl_Vtbl)).SetValueAsync)(this, focus, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FocusAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl", "FocusAsync", 16usize, |this| ((**(this as *const *const IFocusControl_Vtbl)).FocusAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Supported,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedPresets,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedPresets: 0,
+            Preset,
+            SetPresetAsync,
+            SetPresetWithCompletionOptionAsync,
+            Min,
+            Max,
+            Step,
+            Value,
+            SetValueAsync,
+            FocusAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFocusControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IFocusControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FocusChangedSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl2", "FocusChangedSupported", 6usize, |this| ((**(this as *const *const IFocusControl2_Vtbl)).FocusChangedSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForFocusSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl2", "WaitForFocusSupported", 7usize, |this| ((**(this as *const *const IFocusControl2_Vtbl)).WaitForFocusSupported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedFocusModes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl2", "SupportedFocusModes", 8usize, |this| ((**(this as *const *const IFocusControl2_Vtbl)).SupportedFocusModes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedFocusDistances(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl2", "SupportedFocusDistances", 9usize, |this| ((**(this as *const *const IFocusControl2_Vtbl)).SupportedFocusDistances)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedFocusRanges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl2", "SupportedFocusRanges", 10usize, |this| ((**(this as *const *const IFocusControl2_Vtbl)).SupportedFocusRanges)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut FocusMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl2", "Mode", 11usize, |this| ((**(this as *const *const IFocusControl2_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FocusState(this: *mut core::ffi::c_void, result__: *mut MediaCaptureFocusState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl2", "FocusState", 12usize, |this| ((**(this as *const *const IFocusControl2_Vtbl)).FocusState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl2", "UnlockAsync", 13usize, |this| ((**(this as *const *const IFocusControl2_Vtbl)).UnlockAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl2", "LockAsync", 14usize, |this| ((**(this as *const *const IFocusControl2_Vtbl)).LockAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configure(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusControl2", "Configure", 15usize, |this| ((**(this as *const *const IFocusControl2_Vtbl)).Configure)(this, settings), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FocusChangedSupported,
+            WaitForFocusSupported,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedFocusModes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedFocusModes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedFocusDistances,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedFocusDistances: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedFocusRanges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedFocusRanges: 0,
+            Mode,
+            FocusState,
+            UnlockAsync,
+            LockAsync,
+            Configure,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFocusControl2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFocusSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut FocusMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSettings", "Mode", 6usize, |this| ((**(this as *const *const IFocusSettings_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, value: FocusMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSettings", "SetMode", 7usize, |this| ((**(this as *const *const IFocusSettings_Vtbl)).SetMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoFocusRange(this: *mut core::ffi::c_void, result__: *mut AutoFocusRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSettings", "AutoFocusRange", 8usize, |this| ((**(this as *const *const IFocusSettings_Vtbl)).AutoFocusRange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoFocusRange(this: *mut core::ffi::c_void, value: AutoFocusRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSettings", "SetAutoFocusRange", 9usize, |this| ((**(this as *const *const IFocusSettings_Vtbl)).SetAutoFocusRange)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSettings", "Value", 10usize, |this| ((**(this as *const *const IFocusSettings_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSettings", "SetValue", 11usize, |this| ((**(this as *const *const IFocusSettings_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Distance(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSettings", "Distance", 12usize, |this| ((**(this as *const *const IFocusSettings_Vtbl)).Distance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDistance(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSettings", "SetDistance", 13usize, |this| ((**(this as *const *const IFocusSettings_Vtbl)).SetDistance)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForFocus(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSettings", "WaitForFocus", 14usize, |this| ((**(this as *const *const IFocusSettings_Vtbl)).WaitForFocus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWaitForFocus(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSettings", "SetWaitForFocus", 15usize, |this| ((**(this as *const *const IFocusSettings_Vtbl)).SetWaitForFocus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableDriverFallback(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSettings", "DisableDriverFallback", 16usize, |this| ((**(this as *const *const IFocusSettings_Vtbl)).DisableDriverFallback)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisableDriverFallback(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSettings", "SetDisableDriverFallback", 17usize, |this| ((**(this as *const *const IFocusSettings_Vtbl)).SetDisableDriverFallback)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Mode,
+            SetMode,
+            AutoFocusRange,
+            SetAutoFocusRange,
+            Value,
+            SetValue,
+            Distance,
+            SetDistance,
+            WaitForFocus,
+            SetWaitForFocus,
+            DisableDriverFallback,
+            SetDisableDriverFallback,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFocusSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHdrVideoControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdrVideoControl", "Supported", 6usize, |this| ((**(this as *const *const IHdrVideoControl_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedModes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdrVideoControl", "SupportedModes", 7usize, |this| ((**(this as *const *const IHdrVideoControl_Vtbl)).SupportedModes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut HdrVideoMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdrVideoControl", "Mode", 8usize, |this| ((**(this as *const *const IHdrVideoControl_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, value: HdrVideoMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdrVideoControl", "SetMode", 9usize, |this| ((**(this as *const *const IHdrVideoControl_Vtbl)).SetMode)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Supported,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedModes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedModes: 0,
+            Mode,
+            SetMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHdrVideoControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInfraredTorchControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInfraredTorchControl", "IsSupported", 6usize, |this| ((**(this as *const *const IInfraredTorchControl_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedModes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInfraredTorchControl", "SupportedModes", 7usize, |this| ((**(this as *const *const IInfraredTorchControl_Vtbl)).SupportedModes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentMode(this: *mut core::ffi::c_void, result__: *mut InfraredTorchMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInfraredTorchControl", "CurrentMode", 8usize, |this| ((**(this as *const *const IInfraredTorchControl_Vtbl)).CurrentMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentMode(this: *mut core::ffi::c_void, value: InfraredTorchMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInfraredTorchControl", "SetCurrentMode", 9usize, |this| ((**(this as *const *const IInfraredTorchControl_Vtbl)).SetCurrentMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinPower(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInfraredTorchControl", "MinPower", 10usize, |this| ((**(this as *const *const IInfraredTorchControl_Vtbl)).MinPower)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPower(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInfraredTorchControl", "MaxPower", 11usize, |this| ((**(this as *const *const IInfraredTorchControl_Vtbl)).MaxPower)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerStep(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInfraredTorchControl", "PowerStep", 12usize, |this| ((**(this as *const *const IInfraredTorchControl_Vtbl)).PowerStep)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Power(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInfraredTorchControl", "Power", 13usize, |this| ((**(this as *const *const IInfraredTorchControl_Vtbl)).Power)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPower(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInfraredTorchControl", "SetPower", 14usize, |this| ((**(this as *const *const IInfraredTorchControl_Vtbl)).SetPower)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupported,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedModes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedModes: 0,
+            CurrentMode,
+            SetCurrentMode,
+            MinPower,
+            MaxPower,
+            PowerStep,
+            Power,
+            SetPower,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInfraredTorchControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IIsoSpeedControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoSpeedControl", "Supported", 6usize, |this| ((**(this as *const *const IIsoSpeedControl_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn SupportedPresets(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoSpeedControl", "SupportedPresets", 7usize, |this| ((**(this as *const *const IIsoSpeedControl_Vtbl)).SupportedPresets)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Preset(this: *mut core::ffi::c_void, result__: *mut IsoSpeedPreset) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoSpeedControl", "Preset", 8usize, |this| ((**(this as *const *const IIsoSpeedControl_Vtbl)).Preset)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetPresetAsync(this: *mut core::ffi::c_void, preset: IsoSpeedPreset, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoSpeedControl", "SetPresetAsync", 9usize, |this| ((**(this as *const *const IIsoSpeedControl_Vtbl)).SetPresetAsync)(this, preset, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Supported,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            SupportedPresets,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            SupportedPresets: 0,
+            #[cfg(feature = "deprecated")]
+            Preset,
+            #[cfg(not(feature = "deprecated"))]
+            Preset: 0,
+            #[cfg(feature = "deprecated")]
+            SetPresetAsync,
+            #[cfg(not(feature = "deprecated"))]
+            SetPresetAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsoSpeedControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsoSpeedControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Min(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoSpeedControl2", "Min", 6usize, |this| ((**(this as *const *const IIsoSpeedControl2_Vtbl)).Min)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Max(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoSpeedControl2", "Max", 7usize, |this| ((**(this as *const *const IIsoSpeedControl2_Vtbl)).Max)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Step(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoSpeedControl2", "Step", 8usize, |this| ((**(this as *const *const IIsoSpeedControl2_Vtbl)).Step)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoSpeedControl2", "Value", 9usize, |this| ((**(this as *const *const IIsoSpeedControl2_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueAsync(this: *mut core::ffi::c_void, isospeed: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoSpeedControl2", "SetValueAsync", 10usize, |this| ((**(this as *const *const IIsoSpeedControl2_Vtbl)).SetValueAsync)(this, isospeed, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Auto(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoSpeedControl2", "Auto", 11usize, |this| ((**(this as *const *const IIsoSpeedControl2_Vtbl)).Auto)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoSpeedControl2", "SetAutoAsync", 12usize, |this| ((**(this as *const *const IIsoSpeedControl2_Vtbl)).SetAutoAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Min, Max, Step, Value, SetValueAsync, Auto, SetAutoAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsoSpeedControl2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeypadPressedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TelephonyKey(this: *mut core::ffi::c_void, result__: *mut TelephonyKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeypadPressedEventArgs", "TelephonyKey", 6usize, |this| ((**(this as *const *const IKeypadPressedEventArgs_Vtbl)).TelephonyKey)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TelephonyKey }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeypadPressedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_MediaProperties")]
+impl windows_core::imp::ProxyVtable for ILowLagPhotoControl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn GetHighestConcurrentFrameRate(this: *mut core::ffi::c_void, captureproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoControl", "GetHighestConcurrentFrameRate", 6usize, |this| ((**(this as *const *const ILowLagPhotoControl_Vtbl)).GetHighestConcurrentFrameRate)(this, captureproperties, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn GetCurrentFrameRate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoControl", "GetCurrentFrameRate", 7usize, |this| ((**(this as *const *const ILowLagPhotoControl_Vtbl)).GetCurrentFrameRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThumbnailEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoControl", "ThumbnailEnabled", 8usize, |this| ((**(this as *const *const ILowLagPhotoControl_Vtbl)).ThumbnailEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThumbnailEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoControl", "SetThumbnailEnabled", 9usize, |this| ((**(this as *const *const ILowLagPhotoControl_Vtbl)).SetThumbnailEnabled)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn ThumbnailFormat(this: *mut core::ffi::c_void, result__: *mut super::MediaProperties::MediaThumbnailFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoControl", "ThumbnailFormat", 10usize, |this| ((**(this as *const *const ILowLagPhotoControl_Vtbl)).ThumbnailFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn SetThumbnailFormat(this: *mut core::ffi::c_void, value: super::MediaProperties::MediaThumbnailFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoControl", "SetThumbnailFormat", 11usize, |this| ((**(this as *const *const ILowLagPhotoControl_Vtbl)).SetThumbnailFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredThumbnailSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoControl", "DesiredThumbnailSize", 12usize, |this| ((**(this as *const *const ILowLagPhotoControl_Vtbl)).DesiredThumbnailSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredThumbnailSize(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoControl", "SetDesiredThumbnailSize", 13usize, |this| ((**(this as *const *const ILowLagPhotoControl_Vtbl)).SetDesiredThumbnailSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareAcceleratedThumbnailSupported(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoControl", "HardwareAcceleratedThumbnailSupported", 14usize, |this| ((**(this as *const *const ILowLagPhotoControl_Vtbl)).HardwareAcceleratedThumbnailSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_MediaProperties")]
+            GetHighestConcurrentFrameRate,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            GetHighestConcurrentFrameRate: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            GetCurrentFrameRate,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            GetCurrentFrameRate: 0,
+            ThumbnailEnabled,
+            SetThumbnailEnabled,
+            #[cfg(feature = "Media_MediaProperties")]
+            ThumbnailFormat,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            ThumbnailFormat: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            SetThumbnailFormat,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            SetThumbnailFormat: 0,
+            DesiredThumbnailSize,
+            SetDesiredThumbnailSize,
+            HardwareAcceleratedThumbnailSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILowLagPhotoControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_MediaProperties")]
+impl windows_core::imp::ProxyVtable for ILowLagPhotoSequenceControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "Supported", 6usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPastPhotos(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "MaxPastPhotos", 7usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).MaxPastPhotos)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPhotosPerSecond(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "MaxPhotosPerSecond", 8usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).MaxPhotosPerSecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PastPhotoLimit(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "PastPhotoLimit", 9usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).PastPhotoLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPastPhotoLimit(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "SetPastPhotoLimit", 10usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).SetPastPhotoLimit)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotosPerSecondLimit(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "PhotosPerSecondLimit", 11usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).PhotosPerSecondLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPhotosPerSecondLimit(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "SetPhotosPerSecondLimit", 12usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).SetPhotosPerSecondLimit)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn GetHighestConcurrentFrameRate(this: *mut core::ffi::c_void, captureproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "GetHighestConcurrentFrameRate", 13usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).GetHighestConcurrentFrameRate)(this, captureproperties, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn GetCurrentFrameRate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "GetCurrentFrameRate", 14usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).GetCurrentFrameRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThumbnailEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "ThumbnailEnabled", 15usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).ThumbnailEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThumbnailEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "SetThumbnailEnabled", 16usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).SetThumbnailEnabled)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn ThumbnailFormat(this: *mut core::ffi::c_void, result__: *mut super::MediaProperties::MediaThumbnailFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "ThumbnailFormat", 17usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).ThumbnailFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn SetThumbnailFormat(this: *mut core::ffi::c_void, value: super::MediaProperties::MediaThumbnailFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "SetThumbnailFormat", 18usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).SetThumbnailFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredThumbnailSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "DesiredThumbnailSize", 19usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).DesiredThumbnailSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredThumbnailSize(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "SetDesiredThumbnailSize", 20usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).SetDesiredThumbnailSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareAcceleratedThumbnailSupported(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILowLagPhotoSequenceControl", "HardwareAcceleratedThumbnailSupported", 21usize, |this| ((**(this as *const *const ILowLagPhotoSequenceControl_Vtbl)).HardwareAcceleratedThumbnailSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Supported,
+            MaxPastPhotos,
+            MaxPhotosPerSecond,
+            PastPhotoLimit,
+            SetPastPhotoLimit,
+            PhotosPerSecondLimit,
+            SetPhotosPerSecondLimit,
+            #[cfg(feature = "Media_MediaProperties")]
+            GetHighestConcurrentFrameRate,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            GetHighestConcurrentFrameRate: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            GetCurrentFrameRate,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            GetCurrentFrameRate: 0,
+            ThumbnailEnabled,
+            SetThumbnailEnabled,
+            #[cfg(feature = "Media_MediaProperties")]
+            ThumbnailFormat,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            ThumbnailFormat: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            SetThumbnailFormat,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            SetThumbnailFormat: 0,
+            DesiredThumbnailSize,
+            SetDesiredThumbnailSize,
+            HardwareAcceleratedThumbnailSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILowLagPhotoSequenceControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaDeviceControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Capabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceControl", "Capabilities", 6usize, |this| ((**(this as *const *const IMediaDeviceControl_Vtbl)).Capabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetValue(this: *mut core::ffi::c_void, value: *mut f64, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceControl", "TryGetValue", 7usize, |this| ((**(this as *const *const IMediaDeviceControl_Vtbl)).TryGetValue)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySetValue(this: *mut core::ffi::c_void, value: f64, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceControl", "TrySetValue", 8usize, |this| ((**(this as *const *const IMediaDeviceControl_Vtbl)).TrySetValue)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetAuto(this: *mut core::ffi::c_void, value: *mut bool, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceControl", "TryGetAuto", 9usize, |this| ((**(this as *const *const IMediaDeviceControl_Vtbl)).TryGetAuto)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySetAuto(this: *mut core::ffi::c_void, value: bool, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceControl", "TrySetAuto", 10usize, |this| ((**(this as *const *const IMediaDeviceControl_Vtbl)).TrySetAuto)(this, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Capabilities,
+            TryGetValue,
+            TrySetValue,
+            TryGetAuto,
+            TrySetAuto,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaDeviceControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaDeviceControlCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceControlCapabilities", "Supported", 6usize, |this| ((**(this as *const *const IMediaDeviceControlCapabilities_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Min(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceControlCapabilities", "Min", 7usize, |this| ((**(this as *const *const IMediaDeviceControlCapabilities_Vtbl)).Min)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Max(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceControlCapabilities", "Max", 8usize, |this| ((**(this as *const *const IMediaDeviceControlCapabilities_Vtbl)).Max)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Step(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceControlCapabilities", "Step", 9usize, |this| ((**(this as *const *const IMediaDeviceControlCapabilities_Vtbl)).Step)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Default(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceControlCapabilities", "Default", 10usize, |this| ((**(this as *const *const IMediaDeviceControlCapabilities_Vtbl)).Default)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoModeSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceControlCapabilities", "AutoModeSupported", 11usize, |this| ((**(this as *const *const IMediaDeviceControlCapabilities_Vtbl)).AutoModeSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Supported, Min, Max, Step, Default, AutoModeSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaDeviceControlCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Capture", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IMediaDeviceController_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Capture", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn GetAvailableMediaStreamProperties(this: *mut core::ffi::c_void, mediastreamtype: super::Capture::MediaStreamType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceController", "GetAvailableMediaStreamProperties", 6usize, |this| ((**(this as *const *const IMediaDeviceController_Vtbl)).GetAvailableMediaStreamProperties)(this, mediastreamtype, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_Capture", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn GetMediaStreamProperties(this: *mut core::ffi::c_void, mediastreamtype: super::Capture::MediaStreamType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceController", "GetMediaStreamProperties", 7usize, |this| ((**(this as *const *const IMediaDeviceController_Vtbl)).GetMediaStreamProperties)(this, mediastreamtype, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_Capture", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn SetMediaStreamPropertiesAsync(this: *mut core::ffi::c_void, mediastreamtype: super::Capture::MediaStreamType, mediaencodingproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceController", "SetMediaStreamPropertiesAsync", 8usize, |this| ((**(this as *const *const IMediaDeviceController_Vtbl)).SetMediaStreamPropertiesAsync)(this, mediastreamtype, mediaencodingproperties, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Capture", feature = "Media_MediaProperties"))]
+            GetAvailableMediaStreamProperties,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Capture", feature = "Media_MediaProperties")))]
+            GetAvailableMediaStreamProperties: 0,
+            #[cfg(all(feature = "Media_Capture", feature = "Media_MediaProperties"))]
+            GetMediaStreamProperties,
+            #[cfg(not(all(feature = "Media_Capture", feature = "Media_MediaProperties")))]
+            GetMediaStreamProperties: 0,
+            #[cfg(all(feature = "Media_Capture", feature = "Media_MediaProperties"))]
+            SetMediaStreamPropertiesAsync,
+            #[cfg(not(all(feature = "Media_Capture", feature = "Media_MediaProperties")))]
+            SetMediaStreamPropertiesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaDeviceController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAudioCaptureSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceStatics", "GetAudioCaptureSelector", 6usize, |this| ((**(this as *const *const IMediaDeviceStatics_Vtbl)).GetAudioCaptureSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioRenderSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceStatics", "GetAudioRenderSelector", 7usize, |this| ((**(this as *const *const IMediaDeviceStatics_Vtbl)).GetAudioRenderSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoCaptureSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceStatics", "GetVideoCaptureSelector", 8usize, |this| ((**(this as *const *const IMediaDeviceStatics_Vtbl)).GetVideoCaptureSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultAudioCaptureId(this: *mut core::ffi::c_void, role: AudioDeviceRole, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceStatics", "GetDefaultAudioCaptureId", 9usize, |this| ((**(this as *const *const IMediaDeviceStatics_Vtbl)).GetDefaultAudioCaptureId)(this, role, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultAudioRenderId(this: *mut core::ffi::c_void, role: AudioDeviceRole, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceStatics", "GetDefaultAudioRenderId", 10usize, |this| ((**(this as *const *const IMediaDeviceStatics_Vtbl)).GetDefaultAudioRenderId)(this, role, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultAudioCaptureDeviceChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceStatics", "DefaultAudioCaptureDeviceChanged", 11usize, |this| ((**(this as *const *const IMediaDeviceStatics_Vtbl)).DefaultAudioCaptureDeviceChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDefaultAudioCaptureDeviceChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceStatics", "RemoveDefaultAudioCaptureDeviceChanged", 12usize, |this| ((**(this as *const *const IMediaDeviceStatics_Vtbl)).RemoveDefaultAudioCaptureDeviceChanged)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultAudioRenderDeviceChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceStatics", "DefaultAudioRenderDeviceChanged", 13usize, |this| ((**(this as *const *const IMediaDeviceStatics_Vtbl)).DefaultAudioRenderDeviceChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDefaultAudioRenderDeviceChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaDeviceStatics", "RemoveDefaultAudioRenderDeviceChanged", 14usize, |this| ((**(this as *const *const IMediaDeviceStatics_Vtbl)).RemoveDefaultAudioRenderDeviceChanged)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAudioCaptureSelector,
+            GetAudioRenderSelector,
+            GetVideoCaptureSelector,
+            GetDefaultAudioCaptureId,
+            GetDefaultAudioRenderId,
+            DefaultAudioCaptureDeviceChanged,
+            RemoveDefaultAudioCaptureDeviceChanged,
+            DefaultAudioRenderDeviceChanged,
+            RemoveDefaultAudioRenderDeviceChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IModuleCommandResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut SendCommandStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IModuleCommandResult", "Status", 6usize, |this| ((**(this as *const *const IModuleCommandResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Result(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IModuleCommandResult", "Result", 7usize, |this| ((**(this as *const *const IModuleCommandResult_Vtbl)).Result)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Storage_Streams")]
+            Result,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Result: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IModuleCommandResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IOpticalImageStabilizationControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpticalImageStabilizationControl", "Supported", 6usize, |this| ((**(this as *const *const IOpticalImageStabilizationControl_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedModes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpticalImageStabilizationControl", "SupportedModes", 7usize, |this| ((**(this as *const *const IOpticalImageStabilizationControl_Vtbl)).SupportedModes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut OpticalImageStabilizationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpticalImageStabilizationControl", "Mode", 8usize, |this| ((**(this as *const *const IOpticalImageStabilizationControl_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, value: OpticalImageStabilizationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpticalImageStabilizationControl", "SetMode", 9usize, |this| ((**(this as *const *const IOpticalImageStabilizationControl_Vtbl)).SetMode)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Supported,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedModes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedModes: 0,
+            Mode,
+            SetMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpticalImageStabilizationControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Enumeration")]
+impl windows_core::imp::ProxyVtable for IPanelBasedOptimizationControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPanelBasedOptimizationControl", "IsSupported", 6usize, |this| ((**(this as *const *const IPanelBasedOptimizationControl_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn Panel(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Enumeration::Panel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPanelBasedOptimizationControl", "Panel", 7usize, |this| ((**(this as *const *const IPanelBasedOptimizationControl_Vtbl)).Panel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn SetPanel(this: *mut core::ffi::c_void, value: super::super::Devices::Enumeration::Panel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPanelBasedOptimizationControl", "SetPanel", 8usize, |this| ((**(this as *const *const IPanelBasedOptimizationControl_Vtbl)).SetPanel)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupported,
+            #[cfg(feature = "Devices_Enumeration")]
+            Panel,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            Panel: 0,
+            #[cfg(feature = "Devices_Enumeration")]
+            SetPanel,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            SetPanel: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPanelBasedOptimizationControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_MediaProperties")]
+impl windows_core::imp::ProxyVtable for IPhotoConfirmationControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoConfirmationControl", "Supported", 6usize, |this| ((**(this as *const *const IPhotoConfirmationControl_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoConfirmationControl", "Enabled", 7usize, |this| ((**(this as *const *const IPhotoConfirmationControl_Vtbl)).Enabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoConfirmationControl", "SetEnabled", 8usize, |this| ((**(this as *const *const IPhotoConfirmationControl_Vtbl)).SetEnabled)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn PixelFormat(this: *mut core::ffi::c_void, result__: *mut super::MediaProperties::MediaPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoConfirmationControl", "PixelFormat", 9usize, |this| ((**(this as *const *const IPhotoConfirmationControl_Vtbl)).PixelFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn SetPixelFormat(this: *mut core::ffi::c_void, format: super::MediaProperties::MediaPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoConfirmationControl", "SetPixelFormat", 10usize, |this| ((**(this as *const *const IPhotoConfirmationControl_Vtbl)).SetPixelFormat)(this, format), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Supported,
+            Enabled,
+            SetEnabled,
+            #[cfg(feature = "Media_MediaProperties")]
+            PixelFormat,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            PixelFormat: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            SetPixelFormat,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            SetPixelFormat: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoConfirmationControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRedialRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRedialRequestedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IRedialRequestedEventArgs_Vtbl)).Handled)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRedialRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRegionOfInterest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AutoFocusEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionOfInterest", "AutoFocusEnabled", 6usize, |this| ((**(this as *const *const IRegionOfInterest_Vtbl)).AutoFocusEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoFocusEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionOfInterest", "SetAutoFocusEnabled", 7usize, |this| ((**(this as *const *const IRegionOfInterest_Vtbl)).SetAutoFocusEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoWhiteBalanceEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionOfInterest", "AutoWhiteBalanceEnabled", 8usize, |this| ((**(this as *const *const IRegionOfInterest_Vtbl)).AutoWhiteBalanceEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoWhiteBalanceEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionOfInterest", "SetAutoWhiteBalanceEnabled", 9usize, |this| ((**(this as *const *const IRegionOfInterest_Vtbl)).SetAutoWhiteBalanceEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoExposureEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionOfInterest", "AutoExposureEnabled", 10usize, |this| ((**(this as *const *const IRegionOfInterest_Vtbl)).AutoExposureEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoExposureEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionOfInterest", "SetAutoExposureEnabled", 11usize, |this| ((**(this as *const *const IRegionOfInterest_Vtbl)).SetAutoExposureEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bounds(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionOfInterest", "Bounds", 12usize, |this| ((**(this as *const *const IRegionOfInterest_Vtbl)).Bounds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBounds(this: *mut core::ffi::c_void, value: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionOfInterest", "SetBounds", 13usize, |this| ((**(this as *const *const IRegionOfInterest_Vtbl)).SetBounds)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AutoFocusEnabled,
+            SetAutoFocusEnabled,
+            AutoWhiteBalanceEnabled,
+            SetAutoWhiteBalanceEnabled,
+            AutoExposureEnabled,
+            SetAutoExposureEnabled,
+            Bounds,
+            SetBounds,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRegionOfInterest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRegionOfInterest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut RegionOfInterestType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionOfInterest2", "Type", 6usize, |this| ((**(this as *const *const IRegionOfInterest2_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, value: RegionOfInterestType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionOfInterest2", "SetType", 7usize, |this| ((**(this as *const *const IRegionOfInterest2_Vtbl)).SetType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BoundsNormalized(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionOfInterest2", "BoundsNormalized", 8usize, |this| ((**(this as *const *const IRegionOfInterest2_Vtbl)).BoundsNormalized)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBoundsNormalized(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionOfInterest2", "SetBoundsNormalized", 9usize, |this| ((**(this as *const *const IRegionOfInterest2_Vtbl)).SetBoundsNormalized)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Weight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionOfInterest2", "Weight", 10usize, |this| ((**(this as *const *const IRegionOfInterest2_Vtbl)).Weight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWeight(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionOfInterest2", "SetWeight", 11usize, |this| ((**(this as *const *const IRegionOfInterest2_Vtbl)).SetWeight)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            SetType,
+            BoundsNormalized,
+            SetBoundsNormalized,
+            Weight,
+            SetWeight,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRegionOfInterest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRegionsOfInterestControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxRegions(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionsOfInterestControl", "MaxRegions", 6usize, |this| ((**(this as *const *const IRegionsOfInterestControl_Vtbl)).MaxRegions)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetRegionsAsync(this: *mut core::ffi::c_void, regions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionsOfInterestControl", "SetRegionsAsync", 7usize, |this| ((**(this as *const *const IRegionsOfInterestControl_Vtbl)).SetRegionsAsync)(this, regions, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetRegionsWithLockAsync(this: *mut core::ffi::c_void, regions: *mut core::ffi::c_void, lockvalues: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionsOfInterestControl", "SetRegionsWithLockAsync", 8usize, |this| ((**(this as *const *const IRegionsOfInterestControl_Vtbl)).SetRegionsWithLockAsync)(this, regions, lockvalues, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearRegionsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionsOfInterestControl", "ClearRegionsAsync", 9usize, |this| ((**(this as *const *const IRegionsOfInterestControl_Vtbl)).ClearRegionsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoFocusSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionsOfInterestControl", "AutoFocusSupported", 10usize, |this| ((**(this as *const *const IRegionsOfInterestControl_Vtbl)).AutoFocusSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoWhiteBalanceSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionsOfInterestControl", "AutoWhiteBalanceSupported", 11usize, |this| ((**(this as *const *const IRegionsOfInterestControl_Vtbl)).AutoWhiteBalanceSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoExposureSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegionsOfInterestControl", "AutoExposureSupported", 12usize, |this| ((**(this as *const *const IRegionsOfInterestControl_Vtbl)).AutoExposureSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaxRegions,
+            #[cfg(feature = "Foundation_Collections")]
+            SetRegionsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetRegionsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetRegionsWithLockAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetRegionsWithLockAsync: 0,
+            ClearRegionsAsync,
+            AutoFocusSupported,
+            AutoWhiteBalanceSupported,
+            AutoExposureSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRegionsOfInterestControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISceneModeControl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedModes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneModeControl", "SupportedModes", 6usize, |this| ((**(this as *const *const ISceneModeControl_Vtbl)).SupportedModes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut CaptureSceneMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneModeControl", "Value", 7usize, |this| ((**(this as *const *const ISceneModeControl_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueAsync(this: *mut core::ffi::c_void, scenemode: CaptureSceneMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneModeControl", "SetValueAsync", 8usize, |this| ((**(this as *const *const ISceneModeControl_Vtbl)).SetValueAsync)(this, scenemode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedModes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedModes: 0,
+            Value,
+            SetValueAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneModeControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITorchControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITorchControl", "Supported", 6usize, |this| ((**(this as *const *const ITorchControl_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITorchControl", "PowerSupported", 7usize, |this| ((**(this as *const *const ITorchControl_Vtbl)).PowerSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITorchControl", "Enabled", 8usize, |this| ((**(this as *const *const ITorchControl_Vtbl)).Enabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITorchControl", "SetEnabled", 9usize, |this| ((**(this as *const *const ITorchControl_Vtbl)).SetEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerPercent(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITorchControl", "PowerPercent", 10usize, |this| ((**(this as *const *const ITorchControl_Vtbl)).PowerPercent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPowerPercent(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITorchControl", "SetPowerPercent", 11usize, |this| ((**(this as *const *const ITorchControl_Vtbl)).SetPowerPercent)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Supported,
+            PowerSupported,
+            Enabled,
+            SetEnabled,
+            PowerPercent,
+            SetPowerPercent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITorchControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Capture", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IVideoDeviceController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Brightness(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDeviceController", "Brightness", 6usize, |this| ((**(this as *const *const IVideoDeviceController_Vtbl)).Brightness)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Contrast(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDeviceController", "Contrast", 7usize, |this| ((**(this as *const *const IVideoDeviceController_Vtbl)).Contrast)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDeviceController", "Hue", 8usize, |this| ((**(this as *const *const IVideoDeviceController_Vtbl)).Hue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WhiteBalance(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDeviceController", "WhiteBalance", 9usize, |this| ((**(this as *const *const IVideoDeviceController_Vtbl)).WhiteBalance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BacklightCompensation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDeviceController", "BacklightCompensation", 10usize, |this| ((**(this as *const *const IVideoDeviceController_Vtbl)).BacklightCompensation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pan(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDeviceController", "Pan", 11usize, |this| ((**(this as *const *const IVideoDeviceController_Vtbl)).Pan)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tilt(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDeviceController", "Tilt", 12usize, |this| ((**(this as *const *const IVideoDeviceController_Vtbl)).Tilt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Zoom(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDeviceController", "Zoom", 13usize, |this| ((**(this as *const *const IVideoDeviceController_Vtbl)).Zoom)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Roll(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDeviceController", "Roll", 14usize, |this| ((**(this as *const *const IVideoDeviceController_Vtbl)).Roll)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Exposure(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDeviceController", "Exposure", 15usize, |this| ((**(this as *const *const IVideoDeviceController_Vtbl)).Exposure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Focus(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDeviceController", "Focus", 16usize, |this| ((**(this as *const *const IVideoDeviceController_Vtbl)).Focus)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Capture")]
+        unsafe extern "system" fn TrySetPowerlineFrequency(this: *mut core::ffi::c_void, value: super::Capture::PowerlineFrequency, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDeviceController", "TrySetPowerlineFrequency", 17usize, |this| ((**(this as *const *const IVideoDeviceController_Vtbl)).TrySetPowerlineFrequency)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Capture")]
+        unsafe extern "system" fn TryGetPowerlineFrequency(this: *mut core::ffi::c_void, value: *mut super::Capture::PowerlineFrequency, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDeviceController", "TryGetPowerlineFrequency", 18usize, |this| ((**(this as *const *const IVideoDeviceController_Vtbl)).TryGetPowerlineFrequency)(this, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Brightness,
+            Contrast,
+            Hue,
+            WhiteBalance,
+            BacklightCompensation,
+            Pan,
+            Tilt,
+            Zoom,
+            Roll,
+            Exposure,
+            Focus,
+            #[cfg(feature = "Media_Capture")]
+            TrySetPowerlineFrequency,
+            #[cfg(not(feature = "Media_Capture"))]
+            TrySetPowerlineFrequency: 0,
+            #[cfg(feature = "Media_Capture")]
+            TryGetPowerlineFrequency,
+            #[cfg(not(feature = "Media_Capture"))]
+            TryGetPowerlineFrequency: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoDeviceController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoDeviceControllerGetDevicePropertyResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut VideoDeviceControllerGetDevicePropertyStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDeviceControllerGetDevicePropertyResult", "Status", 6usize, |this| ((**(this as *const *const IVideoDeviceControllerGetDevicePropertyResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDeviceControllerGetDevicePropertyResult", "Value", 7usize, |this| ((**(this as *const *const IVideoDeviceControllerGetDevicePropertyResult_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoDeviceControllerGetDevicePropertyResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVideoTemporalDenoisingControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTemporalDenoisingControl", "Supported", 6usize, |this| ((**(this as *const *const IVideoTemporalDenoisingControl_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedModes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTemporalDenoisingControl", "SupportedModes", 7usize, |this| ((**(this as *const *const IVideoTemporalDenoisingControl_Vtbl)).SupportedModes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut VideoTemporalDenoisingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTemporalDenoisingControl", "Mode", 8usize, |this| ((**(this as *const *const IVideoTemporalDenoisingControl_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, value: VideoTemporalDenoisingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTemporalDenoisingControl", "SetMode", 9usize, |this| ((**(this as *const *const IVideoTemporalDenoisingControl_Vtbl)).SetMode)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Supported,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedModes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedModes: 0,
+            Mode,
+            SetMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoTemporalDenoisingControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWhiteBalanceControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWhiteBalanceControl", "Supported", 6usize, |this| ((**(this as *const *const IWhiteBalanceControl_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Preset(this: *mut core::ffi::c_void, result__: *mut ColorTemperaturePreset) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWhiteBalanceControl", "Preset", 7usize, |this| ((**(this as *const *const IWhiteBalanceControl_Vtbl)).Preset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPresetAsync(this: *mut core::ffi::c_void, preset: ColorTemperaturePreset, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWhiteBalanceControl", "SetPresetAsync", 8usize, |this| ((**(this as *const *const IWhiteBalanceControl_Vtbl)).SetPresetAsync)(this, preset, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Min(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWhiteBalanceControl", "Min", 9usize, |this| ((**(this as *const *const IWhiteBalanceControl_Vtbl)).Min)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Max(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWhiteBalanceControl", "Max", 10usize, |this| ((**(this as *const *const IWhiteBalanceControl_Vtbl)).Max)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Step(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWhiteBalanceControl", "Step", 11usize, |this| ((**(this as *const *const IWhiteBalanceControl_Vtbl)).Step)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWhiteBalanceControl", "Value", 12usize, |this| ((**(this as *const *const IWhiteBalanceControl_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueAsync(this: *mut core::ffi::c_void, temperature: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWhiteBalanceControl", "SetValueAsync", 13usize, |this| ((**(this as *const *const IWhiteBalanceControl_Vtbl)).SetValueAsync)(this, temperature, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Supported,
+            Preset,
+            SetPresetAsync,
+            Min,
+            Max,
+            Step,
+            Value,
+            SetValueAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWhiteBalanceControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IZoomControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Supported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoomControl", "Supported", 6usize, |this| ((**(this as *const *const IZoomControl_Vtbl)).Supported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Min(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoomControl", "Min", 7usize, |this| ((**(this as *const *const IZoomControl_Vtbl)).Min)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Max(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoomControl", "Max", 8usize, |this| ((**(this as *const *const IZoomControl_Vtbl)).Max)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Step(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoomControl", "Step", 9usize, |this| ((**(this as *const *const IZoomControl_Vtbl)).Step)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoomControl", "Value", 10usize, |this| ((**(this as *const *const IZoomControl_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoomControl", "SetValue", 11usize, |this| ((**(this as *const *const IZoomControl_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Supported, Min, Max, Step, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IZoomControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IZoomControl2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedModes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoomControl2", "SupportedModes", 6usize, |this| ((**(this as *const *const IZoomControl2_Vtbl)).SupportedModes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut ZoomTransitionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoomControl2", "Mode", 7usize, |this| ((**(this as *const *const IZoomControl2_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configure(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoomControl2", "Configure", 8usize, |this| ((**(this as *const *const IZoomControl2_Vtbl)).Configure)(this, settings), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedModes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedModes: 0,
+            Mode,
+            Configure,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IZoomControl2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IZoomSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut ZoomTransitionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoomSettings", "Mode", 6usize, |this| ((**(this as *const *const IZoomSettings_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, value: ZoomTransitionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoomSettings", "SetMode", 7usize, |this| ((**(this as *const *const IZoomSettings_Vtbl)).SetMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoomSettings", "Value", 8usize, |this| ((**(this as *const *const IZoomSettings_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoomSettings", "SetValue", 9usize, |this| ((**(this as *const *const IZoomSettings_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Mode, SetMode, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IZoomSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
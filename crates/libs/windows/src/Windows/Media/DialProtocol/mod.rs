@@ -564,6 +564,7 @@ impl DialAppLaunchResult {
 impl windows_core::TypeKind for DialAppLaunchResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DialAppLaunchResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DialAppLaunchResult").field(&self.0).finish()
@@ -584,6 +585,7 @@ impl DialAppState {
 impl windows_core::TypeKind for DialAppState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DialAppState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DialAppState").field(&self.0).finish()
@@ -604,6 +606,7 @@ impl DialAppStopResult {
 impl windows_core::TypeKind for DialAppStopResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DialAppStopResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DialAppStopResult").field(&self.0).finish()
@@ -626,6 +629,7 @@ impl DialDeviceDisplayStatus {
 impl windows_core::TypeKind for DialDeviceDisplayStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DialDeviceDisplayStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DialDeviceDisplayStatus").field(&self.0).finish()
@@ -634,3 +638,5 @@ impl core::fmt::Debug for DialDeviceDisplayStatus {
 impl windows_core::RuntimeType for DialDeviceDisplayStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.DialProtocol.DialDeviceDisplayStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,279 @@
+impl windows_core::imp::ProxyVtable for IDialApp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialApp", "AppName", 6usize, |this| ((**(this as *const *const IDialApp_Vtbl)).AppName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestLaunchAsync(this: *mut core::ffi::c_void, appargument: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialApp", "RequestLaunchAsync", 7usize, |this| ((**(this as *const *const IDialApp_Vtbl)).RequestLaunchAsync)(this, appargument, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialApp", "StopAsync", 8usize, |this| ((**(this as *const *const IDialApp_Vtbl)).StopAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppStateAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialApp", "GetAppStateAsync", 9usize, |this| ((**(this as *const *const IDialApp_Vtbl)).GetAppStateAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AppName, RequestLaunchAsync, StopAsync, GetAppStateAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialApp as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDialAppStateDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut DialAppState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialAppStateDetails", "State", 6usize, |this| ((**(this as *const *const IDialAppStateDetails_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FullXml(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialAppStateDetails", "FullXml", 7usize, |this| ((**(this as *const *const IDialAppStateDetails_Vtbl)).FullXml)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, State, FullXml }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialAppStateDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDialDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevice", "Id", 6usize, |this| ((**(this as *const *const IDialDevice_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDialApp(this: *mut core::ffi::c_void, appname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevice", "GetDialApp", 7usize, |this| ((**(this as *const *const IDialDevice_Vtbl)).GetDialApp)(this, appname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, GetDialApp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IDialDevice2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevice2", "FriendlyName", 6usize, |this| ((**(this as *const *const IDialDevice2_Vtbl)).FriendlyName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevice2", "Thumbnail", 7usize, |this| ((**(this as *const *const IDialDevice2_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FriendlyName,
+            #[cfg(feature = "Storage_Streams")]
+            Thumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Thumbnail: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialDevice2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "UI_Popups"))]
+impl windows_core::imp::ProxyVtable for IDialDevicePicker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Filter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevicePicker", "Filter", 6usize, |this| ((**(this as *const *const IDialDevicePicker_Vtbl)).Filter)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn Appearance(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevicePicker", "Appearance", 7usize, |this| ((**(this as *const *const IDialDevicePicker_Vtbl)).Appearance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DialDeviceSelected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevicePicker", "DialDeviceSelected", 8usize, |this| ((**(this as *const *const IDialDevicePicker_Vtbl)).DialDeviceSelected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDialDeviceSelected(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevicePicker", "RemoveDialDeviceSelected", 9usize, |this| ((**(this as *const *const IDialDevicePicker_Vtbl)).RemoveDialDeviceSelected)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectButtonClicked(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevicePicker", "DisconnectButtonClicked", 10usize, |this| ((**(this as *const *const IDialDevicePicker_Vtbl)).DisconnectButtonClicked)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDisconnectButtonClicked(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevicePicker", "RemoveDisconnectButtonClicked", 11usize, |this| ((**(this as *const *const IDialDevicePicker_Vtbl)).RemoveDisconnectButtonClicked)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DialDevicePickerDismissed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevicePicker", "DialDevicePickerDismissed", 12usize, |this| ((**(this as *const *const IDialDevicePicker_Vtbl)).DialDevicePickerDismissed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDialDevicePickerDismissed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevicePicker", "RemoveDialDevicePickerDismissed", 13usize, |this| ((**(this as *const *const IDialDevicePicker_Vtbl)).RemoveDialDevicePickerDismissed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevicePicker", "Show", 14usize, |this| ((**(this as *const *const IDialDevicePicker_Vtbl)).Show)(this, selection), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowWithPlacement(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevicePicker", "ShowWithPlacement", 15usize, |this| ((**(this as *const *const IDialDevicePicker_Vtbl)).ShowWithPlacement)(this, selection, preferredplacement), |result| Some(*result))
+        }
+        unsafe extern "system" fn PickSingleDialDeviceAsync(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevicePicker", "PickSingleDialDeviceAsync", 16usize, |this| ((**(this as *const *const IDialDevicePicker_Vtbl)).PickSingleDialDeviceAsync)(this, selection, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn PickSingleDialDeviceAsyncWithPlacement(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevicePicker", "PickSingleDialDeviceAsyncWithPlacement", 17usize, |this| ((**(this as *const *const IDialDevicePicker_Vtbl)).PickSingleDialDeviceAsyncWithPlacement)(this, selection, preferredplacement, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hide(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevicePicker", "Hide", 18usize, |this| ((**(this as *const *const IDialDevicePicker_Vtbl)).Hide)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayStatus(this: *mut core::ffi::c_void, device: *mut core::ffi::c_void, status: DialDeviceDisplayStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevicePicker", "SetDisplayStatus", 19usize, |this| ((**(this as *const *const IDialDevicePicker_Vtbl)).SetDisplayStatus)(this, device, status), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Filter,
+            #[cfg(feature = "Devices_Enumeration")]
+            Appearance,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            Appearance: 0,
+            DialDeviceSelected,
+            RemoveDialDeviceSelected,
+            DisconnectButtonClicked,
+            RemoveDisconnectButtonClicked,
+            DialDevicePickerDismissed,
+            RemoveDialDevicePickerDismissed,
+            Show,
+            #[cfg(feature = "UI_Popups")]
+            ShowWithPlacement,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowWithPlacement: 0,
+            PickSingleDialDeviceAsync,
+            #[cfg(feature = "UI_Popups")]
+            PickSingleDialDeviceAsyncWithPlacement,
+            #[cfg(not(feature = "UI_Popups"))]
+            PickSingleDialDeviceAsyncWithPlacement: 0,
+            Hide,
+            SetDisplayStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialDevicePicker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDialDevicePickerFilter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedAppNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDevicePickerFilter", "SupportedAppNames", 6usize, |this| ((**(this as *const *const IDialDevicePickerFilter_Vtbl)).SupportedAppNames)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedAppNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedAppNames: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialDevicePickerFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDialDeviceSelectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SelectedDialDevice(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDeviceSelectedEventArgs", "SelectedDialDevice", 6usize, |this| ((**(this as *const *const IDialDeviceSelectedEventArgs_Vtbl)).SelectedDialDevice)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SelectedDialDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialDeviceSelectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Enumeration")]
+impl windows_core::imp::ProxyVtable for IDialDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, appname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDeviceStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IDialDeviceStatics_Vtbl)).GetDeviceSelector)(this, appname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDeviceStatics", "FromIdAsync", 7usize, |this| ((**(this as *const *const IDialDeviceStatics_Vtbl)).FromIdAsync)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn DeviceInfoSupportsDialAsync(this: *mut core::ffi::c_void, device: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDeviceStatics", "DeviceInfoSupportsDialAsync", 8usize, |this| ((**(this as *const *const IDialDeviceStatics_Vtbl)).DeviceInfoSupportsDialAsync)(this, device, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceSelector,
+            FromIdAsync,
+            #[cfg(feature = "Devices_Enumeration")]
+            DeviceInfoSupportsDialAsync,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            DeviceInfoSupportsDialAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDialDisconnectButtonClickedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Device(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialDisconnectButtonClickedEventArgs", "Device", 6usize, |this| ((**(this as *const *const IDialDisconnectButtonClickedEventArgs_Vtbl)).Device)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Device }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialDisconnectButtonClickedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDialReceiverApp_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAdditionalDataAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialReceiverApp", "GetAdditionalDataAsync", 6usize, |this| ((**(this as *const *const IDialReceiverApp_Vtbl)).GetAdditionalDataAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetAdditionalDataAsync(this: *mut core::ffi::c_void, additionaldata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialReceiverApp", "SetAdditionalDataAsync", 7usize, |this| ((**(this as *const *const IDialReceiverApp_Vtbl)).SetAdditionalDataAsync)(this, additionaldata, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAdditionalDataAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAdditionalDataAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetAdditionalDataAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetAdditionalDataAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialReceiverApp as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDialReceiverApp2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUniqueDeviceNameAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialReceiverApp2", "GetUniqueDeviceNameAsync", 6usize, |this| ((**(this as *const *const IDialReceiverApp2_Vtbl)).GetUniqueDeviceNameAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUniqueDeviceNameAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialReceiverApp2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDialReceiverAppStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialReceiverAppStatics", "Current", 6usize, |this| ((**(this as *const *const IDialReceiverAppStatics_Vtbl)).Current)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Current }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialReceiverAppStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
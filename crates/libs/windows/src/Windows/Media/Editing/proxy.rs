@@ -0,0 +1,566 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IBackgroundAudioTrack_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TrimTimeFromStart(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrack", "TrimTimeFromStart", 6usize, |this| ((**(this as *const *const IBackgroundAudioTrack_Vtbl)).TrimTimeFromStart)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrimTimeFromStart(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrack", "SetTrimTimeFromStart", 7usize, |this| ((**(this as *const *const IBackgroundAudioTrack_Vtbl)).SetTrimTimeFromStart)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrimTimeFromEnd(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrack", "TrimTimeFromEnd", 8usize, |this| ((**(this as *const *const IBackgroundAudioTrack_Vtbl)).TrimTimeFromEnd)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrimTimeFromEnd(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrack", "SetTrimTimeFromEnd", 9usize, |this| ((**(this as *const *const IBackgroundAudioTrack_Vtbl)).SetTrimTimeFromEnd)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OriginalDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrack", "OriginalDuration", 10usize, |this| ((**(this as *const *const IBackgroundAudioTrack_Vtbl)).OriginalDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrimmedDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrack", "TrimmedDuration", 11usize, |this| ((**(this as *const *const IBackgroundAudioTrack_Vtbl)).TrimmedDuration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UserData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrack", "UserData", 12usize, |this| ((**(this as *const *const IBackgroundAudioTrack_Vtbl)).UserData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelay(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrack", "SetDelay", 13usize, |this| ((**(this as *const *const IBackgroundAudioTrack_Vtbl)).SetDelay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delay(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrack", "Delay", 14usize, |this| ((**(this as *const *const IBackgroundAudioTrack_Vtbl)).Delay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolume(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrack", "SetVolume", 15usize, |this| ((**(this as *const *const IBackgroundAudioTrack_Vtbl)).SetVolume)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Volume(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrack", "Volume", 16usize, |this| ((**(this as *const *const IBackgroundAudioTrack_Vtbl)).Volume)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrack", "Clone", 17usize, |this| ((**(this as *const *const IBackgroundAudioTrack_Vtbl)).Clone)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn GetAudioEncodingProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrack", "GetAudioEncodingProperties", 18usize, |this| ((**(this as *const *const IBackgroundAudioTrack_Vtbl)).GetAudioEncodingProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects"))]
+        unsafe extern "system" fn AudioEffectDefinitions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrack", "AudioEffectDefinitions", 19usize, |this| ((**(this as *const *const IBackgroundAudioTrack_Vtbl)).AudioEffectDefinitions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TrimTimeFromStart,
+            SetTrimTimeFromStart,
+            TrimTimeFromEnd,
+            SetTrimTimeFromEnd,
+            OriginalDuration,
+            TrimmedDuration,
+            #[cfg(feature = "Foundation_Collections")]
+            UserData,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UserData: 0,
+            SetDelay,
+            Delay,
+            SetVolume,
+            Volume,
+            Clone,
+            #[cfg(feature = "Media_MediaProperties")]
+            GetAudioEncodingProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            GetAudioEncodingProperties: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects"))]
+            AudioEffectDefinitions,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Effects")))]
+            AudioEffectDefinitions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundAudioTrack as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IBackgroundAudioTrackStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromEmbeddedAudioTrack(this: *mut core::ffi::c_void, embeddedaudiotrack: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrackStatics", "CreateFromEmbeddedAudioTrack", 6usize, |this| ((**(this as *const *const IBackgroundAudioTrackStatics_Vtbl)).CreateFromEmbeddedAudioTrack)(this, embeddedaudiotrack, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CreateFromFileAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundAudioTrackStatics", "CreateFromFileAsync", 7usize, |this| ((**(this as *const *const IBackgroundAudioTrackStatics_Vtbl)).CreateFromFileAsync)(this, file, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromEmbeddedAudioTrack,
+            #[cfg(feature = "Storage")]
+            CreateFromFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            CreateFromFileAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundAudioTrackStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_MediaProperties")]
+impl windows_core::imp::ProxyVtable for IEmbeddedAudioTrack_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn GetAudioEncodingProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmbeddedAudioTrack", "GetAudioEncodingProperties", 6usize, |this| ((**(this as *const *const IEmbeddedAudioTrack_Vtbl)).GetAudioEncodingProperties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_MediaProperties")]
+            GetAudioEncodingProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            GetAudioEncodingProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmbeddedAudioTrack as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IMediaClip_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TrimTimeFromStart(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "TrimTimeFromStart", 6usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).TrimTimeFromStart)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrimTimeFromStart(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "SetTrimTimeFromStart", 7usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).SetTrimTimeFromStart)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrimTimeFromEnd(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "TrimTimeFromEnd", 8usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).TrimTimeFromEnd)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrimTimeFromEnd(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "SetTrimTimeFromEnd", 9usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).SetTrimTimeFromEnd)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OriginalDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "OriginalDuration", 10usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).OriginalDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrimmedDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "TrimmedDuration", 11usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).TrimmedDuration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UserData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "UserData", 12usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).UserData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "Clone", 13usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).Clone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTimeInComposition(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "StartTimeInComposition", 14usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).StartTimeInComposition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndTimeInComposition(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "EndTimeInComposition", 15usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).EndTimeInComposition)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EmbeddedAudioTracks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "EmbeddedAudioTracks", 16usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).EmbeddedAudioTracks)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedEmbeddedAudioTrackIndex(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "SelectedEmbeddedAudioTrackIndex", 17usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).SelectedEmbeddedAudioTrackIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelectedEmbeddedAudioTrackIndex(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "SetSelectedEmbeddedAudioTrackIndex", 18usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).SetSelectedEmbeddedAudioTrackIndex)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolume(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "SetVolume", 19usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).SetVolume)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Volume(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "Volume", 20usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).Volume)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn GetVideoEncodingProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "GetVideoEncodingProperties", 21usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).GetVideoEncodingProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects"))]
+        unsafe extern "system" fn AudioEffectDefinitions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "AudioEffectDefinitions", 22usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).AudioEffectDefinitions)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects"))]
+        unsafe extern "system" fn VideoEffectDefinitions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClip", "VideoEffectDefinitions", 23usize, |this| ((**(this as *const *const IMediaClip_Vtbl)).VideoEffectDefinitions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TrimTimeFromStart,
+            SetTrimTimeFromStart,
+            TrimTimeFromEnd,
+            SetTrimTimeFromEnd,
+            OriginalDuration,
+            TrimmedDuration,
+            #[cfg(feature = "Foundation_Collections")]
+            UserData,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UserData: 0,
+            Clone,
+            StartTimeInComposition,
+            EndTimeInComposition,
+            #[cfg(feature = "Foundation_Collections")]
+            EmbeddedAudioTracks,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EmbeddedAudioTracks: 0,
+            SelectedEmbeddedAudioTrackIndex,
+            SetSelectedEmbeddedAudioTrackIndex,
+            SetVolume,
+            Volume,
+            #[cfg(feature = "Media_MediaProperties")]
+            GetVideoEncodingProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            GetVideoEncodingProperties: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects"))]
+            AudioEffectDefinitions,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Effects")))]
+            AudioEffectDefinitions: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects"))]
+            VideoEffectDefinitions,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Effects")))]
+            VideoEffectDefinitions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaClip as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage", feature = "UI"))]
+impl windows_core::imp::ProxyVtable for IMediaClipStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn CreateFromColor(this: *mut core::ffi::c_void, color: super::super::UI::Color, originalduration: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClipStatics", "CreateFromColor", 6usize, |this| ((**(this as *const *const IMediaClipStatics_Vtbl)).CreateFromColor)(this, color, originalduration, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CreateFromFileAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClipStatics", "CreateFromFileAsync", 7usize, |this| ((**(this as *const *const IMediaClipStatics_Vtbl)).CreateFromFileAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CreateFromImageFileAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, originalduration: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClipStatics", "CreateFromImageFileAsync", 8usize, |this| ((**(this as *const *const IMediaClipStatics_Vtbl)).CreateFromImageFileAsync)(this, file, originalduration, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI")]
+            CreateFromColor,
+            #[cfg(not(feature = "UI"))]
+            CreateFromColor: 0,
+            #[cfg(feature = "Storage")]
+            CreateFromFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            CreateFromFileAsync: 0,
+            #[cfg(feature = "Storage")]
+            CreateFromImageFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            CreateFromImageFileAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaClipStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX_Direct3D11")]
+impl windows_core::imp::ProxyVtable for IMediaClipStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn CreateFromSurface(this: *mut core::ffi::c_void, surface: *mut core::ffi::c_void, originalduration: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaClipStatics2", "CreateFromSurface", 6usize, |this| ((**(this as *const *const IMediaClipStatics2_Vtbl)).CreateFromSurface)(this, surface, originalduration, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            CreateFromSurface,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            CreateFromSurface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaClipStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging", feature = "Media_Core", feature = "Media_MediaProperties", feature = "Media_Transcoding", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMediaComposition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition", "Duration", 6usize, |this| ((**(this as *const *const IMediaComposition_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Clips(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition", "Clips", 7usize, |this| ((**(this as *const *const IMediaComposition_Vtbl)).Clips)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BackgroundAudioTracks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition", "BackgroundAudioTracks", 8usize, |this| ((**(this as *const *const IMediaComposition_Vtbl)).BackgroundAudioTracks)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UserData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition", "UserData", 9usize, |this| ((**(this as *const *const IMediaComposition_Vtbl)).UserData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition", "Clone", 10usize, |this| ((**(this as *const *const IMediaComposition_Vtbl)).Clone)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition", "SaveAsync", 11usize, |this| ((**(this as *const *const IMediaComposition_Vtbl)).SaveAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Graphics_Imaging", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GetThumbnailAsync(this: *mut core::ffi::c_void, timefromstart: super::super::Foundation::TimeSpan, scaledwidth: i32, scaledheight: i32, frameprecision: VideoFramePrecision, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition", "GetThumbnailAsync", 12usize, |this| ((**(this as *const *const IMediaComposition_Vtbl)).GetThumbnailAsync)(this, timefromstart, scaledwidth, scaledheight, frameprecision, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GetThumbnailsAsync(this: *mut core::ffi::c_void, timesfromstart: *mut core::ffi::c_void, scaledwidth: i32, scaledheight: i32, frameprecision: VideoFramePrecision, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition", "GetThumbnailsAsync", 13usize, |this| ((**(this as *const *const IMediaComposition_Vtbl)).GetThumbnailsAsync)(this, timesfromstart, scaledwidth, scaledheight, frameprecision, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_Transcoding", feature = "Storage"))]
+        unsafe extern "system" fn RenderToFileAsync(this: *mut core::ffi::c_void, destination: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition", "RenderToFileAsync", 14usize, |this| ((**(this as *const *const IMediaComposition_Vtbl)).RenderToFileAsync)(this, destination, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_Transcoding", feature = "Storage"))]
+        unsafe extern "system" fn RenderToFileWithTrimmingPreferenceAsync(this: *mut core::ffi::c_void, destination: *mut core::ffi::c_void, trimmingpreference: MediaTrimmingPreference, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition", "RenderToFileWithTrimmingPreferenceAsync", 15usize, |this| ((**(this as *const *const IMediaComposition_Vtbl)).RenderToFileWithTrimmingPreferenceAsync)(this, destination, trimmingpreference, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_MediaProperties", feature = "Media_Transcoding", feature = "Storage"))]
+        unsafe extern "system" fn RenderToFileWithProfileAsync(this: *mut core::ffi::c_void, destination: *mut core::ffi::c_void, trimmingpreference: MediaTrimmingPreference, encodingprofile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition", "RenderToFileWithProfileAsync", 16usize, |this| ((**(this as *const *const IMediaComposition_Vtbl)).RenderToFileWithProfileAsync)(this, destination, trimmingpreference, encodingprofile, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn CreateDefaultEncodingProfile(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition", "CreateDefaultEncodingProfile", 17usize, |this| ((**(this as *const *const IMediaComposition_Vtbl)).CreateDefaultEncodingProfile)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn GenerateMediaStreamSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition", "GenerateMediaStreamSource", 18usize, |this| ((**(this as *const *const IMediaComposition_Vtbl)).GenerateMediaStreamSource)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_Core", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn GenerateMediaStreamSourceWithProfile(this: *mut core::ffi::c_void, encodingprofile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition", "GenerateMediaStreamSourceWithProfile", 19usize, |this| ((**(this as *const *const IMediaComposition_Vtbl)).GenerateMediaStreamSourceWithProfile)(this, encodingprofile, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn GeneratePreviewMediaStreamSource(this: *mut core::ffi::c_void, scaledwidth: i32, scaledheight: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition", "GeneratePreviewMediaStreamSource", 20usize, |this| ((**(this as *const *const IMediaComposition_Vtbl)).GeneratePreviewMediaStreamSource)(this, scaledwidth, scaledheight, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Duration,
+            #[cfg(feature = "Foundation_Collections")]
+            Clips,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Clips: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            BackgroundAudioTracks,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BackgroundAudioTracks: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            UserData,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UserData: 0,
+            Clone,
+            #[cfg(feature = "Storage")]
+            SaveAsync,
+            #[cfg(not(feature = "Storage"))]
+            SaveAsync: 0,
+            #[cfg(all(feature = "Graphics_Imaging", feature = "Storage_Streams"))]
+            GetThumbnailAsync,
+            #[cfg(not(all(feature = "Graphics_Imaging", feature = "Storage_Streams")))]
+            GetThumbnailAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging", feature = "Storage_Streams"))]
+            GetThumbnailsAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics_Imaging", feature = "Storage_Streams")))]
+            GetThumbnailsAsync: 0,
+            #[cfg(all(feature = "Media_Transcoding", feature = "Storage"))]
+            RenderToFileAsync,
+            #[cfg(not(all(feature = "Media_Transcoding", feature = "Storage")))]
+            RenderToFileAsync: 0,
+            #[cfg(all(feature = "Media_Transcoding", feature = "Storage"))]
+            RenderToFileWithTrimmingPreferenceAsync,
+            #[cfg(not(all(feature = "Media_Transcoding", feature = "Storage")))]
+            RenderToFileWithTrimmingPreferenceAsync: 0,
+            #[cfg(all(feature = "Media_MediaProperties", feature = "Media_Transcoding", feature = "Storage"))]
+            RenderToFileWithProfileAsync,
+            #[cfg(not(all(feature = "Media_MediaProperties", feature = "Media_Transcoding", feature = "Storage")))]
+            RenderToFileWithProfileAsync: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            CreateDefaultEncodingProfile,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            CreateDefaultEncodingProfile: 0,
+            #[cfg(feature = "Media_Core")]
+            GenerateMediaStreamSource,
+            #[cfg(not(feature = "Media_Core"))]
+            GenerateMediaStreamSource: 0,
+            #[cfg(all(feature = "Media_Core", feature = "Media_MediaProperties"))]
+            GenerateMediaStreamSourceWithProfile,
+            #[cfg(not(all(feature = "Media_Core", feature = "Media_MediaProperties")))]
+            GenerateMediaStreamSourceWithProfile: 0,
+            #[cfg(feature = "Media_Core")]
+            GeneratePreviewMediaStreamSource,
+            #[cfg(not(feature = "Media_Core"))]
+            GeneratePreviewMediaStreamSource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaComposition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaComposition2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn OverlayLayers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaComposition2", "OverlayLayers", 6usize, |this| ((**(this as *const *const IMediaComposition2_Vtbl)).OverlayLayers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            OverlayLayers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            OverlayLayers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaComposition2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IMediaCompositionStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LoadAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaCompositionStatics", "LoadAsync", 6usize, |this| ((**(this as *const *const IMediaCompositionStatics_Vtbl)).LoadAsync)(this, file, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            LoadAsync,
+            #[cfg(not(feature = "Storage"))]
+            LoadAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaCompositionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaOverlay_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlay", "Position", 6usize, |this| ((**(this as *const *const IMediaOverlay_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, value: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlay", "SetPosition", 7usize, |this| ((**(this as *const *const IMediaOverlay_Vtbl)).SetPosition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelay(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlay", "SetDelay", 8usize, |this| ((**(this as *const *const IMediaOverlay_Vtbl)).SetDelay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delay(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlay", "Delay", 9usize, |this| ((**(this as *const *const IMediaOverlay_Vtbl)).Delay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Opacity(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlay", "Opacity", 10usize, |this| ((**(this as *const *const IMediaOverlay_Vtbl)).Opacity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpacity(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlay", "SetOpacity", 11usize, |this| ((**(this as *const *const IMediaOverlay_Vtbl)).SetOpacity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlay", "Clone", 12usize, |this| ((**(this as *const *const IMediaOverlay_Vtbl)).Clone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clip(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlay", "Clip", 13usize, |this| ((**(this as *const *const IMediaOverlay_Vtbl)).Clip)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlay", "AudioEnabled", 14usize, |this| ((**(this as *const *const IMediaOverlay_Vtbl)).AudioEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlay", "SetAudioEnabled", 15usize, |this| ((**(this as *const *const IMediaOverlay_Vtbl)).SetAudioEnabled)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Position,
+            SetPosition,
+            SetDelay,
+            Delay,
+            Opacity,
+            SetOpacity,
+            Clone,
+            Clip,
+            AudioEnabled,
+            SetAudioEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaOverlay as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaOverlayFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, clip: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlayFactory", "Create", 6usize, |this| ((**(this as *const *const IMediaOverlayFactory_Vtbl)).Create)(this, clip, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithPositionAndOpacity(this: *mut core::ffi::c_void, clip: *mut core::ffi::c_void, position: super::super::Foundation::Rect, opacity: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlayFactory", "CreateWithPositionAndOpacity", 7usize, |this| ((**(this as *const *const IMediaOverlayFactory_Vtbl)).CreateWithPositionAndOpacity)(this, clip, position, opacity, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithPositionAndOpacity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaOverlayFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Effects"))]
+impl windows_core::imp::ProxyVtable for IMediaOverlayLayer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlayLayer", "Clone", 6usize, |this| ((**(this as *const *const IMediaOverlayLayer_Vtbl)).Clone)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Overlays(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlayLayer", "Overlays", 7usize, |this| ((**(this as *const *const IMediaOverlayLayer_Vtbl)).Overlays)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Effects")]
+        unsafe extern "system" fn CustomCompositorDefinition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlayLayer", "CustomCompositorDefinition", 8usize, |this| ((**(this as *const *const IMediaOverlayLayer_Vtbl)).CustomCompositorDefinition)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Clone,
+            #[cfg(feature = "Foundation_Collections")]
+            Overlays,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Overlays: 0,
+            #[cfg(feature = "Media_Effects")]
+            CustomCompositorDefinition,
+            #[cfg(not(feature = "Media_Effects"))]
+            CustomCompositorDefinition: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaOverlayLayer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Effects")]
+impl windows_core::imp::ProxyVtable for IMediaOverlayLayerFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Effects")]
+        unsafe extern "system" fn CreateWithCompositorDefinition(this: *mut core::ffi::c_void, compositordefinition: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaOverlayLayerFactory", "CreateWithCompositorDefinition", 6usize, |this| ((**(this as *const *const IMediaOverlayLayerFactory_Vtbl)).CreateWithCompositorDefinition)(this, compositordefinition, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Effects")]
+            CreateWithCompositorDefinition,
+            #[cfg(not(feature = "Media_Effects"))]
+            CreateWithCompositorDefinition: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaOverlayLayerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1379,6 +1379,7 @@ impl AudioEffectState {
 impl windows_core::TypeKind for AudioEffectState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioEffectState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioEffectState").field(&self.0).finish()
@@ -1415,6 +1416,7 @@ impl AudioEffectType {
 impl windows_core::TypeKind for AudioEffectType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioEffectType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioEffectType").field(&self.0).finish()
@@ -1435,6 +1437,7 @@ impl MediaEffectClosedReason {
 impl windows_core::TypeKind for MediaEffectClosedReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaEffectClosedReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaEffectClosedReason").field(&self.0).finish()
@@ -1454,6 +1457,7 @@ impl MediaMemoryTypes {
 impl windows_core::TypeKind for MediaMemoryTypes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaMemoryTypes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaMemoryTypes").field(&self.0).finish()
@@ -1464,3 +1468,5 @@ impl windows_core::RuntimeType for MediaMemoryTypes {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
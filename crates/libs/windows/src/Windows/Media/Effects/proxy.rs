@@ -0,0 +1,725 @@
+impl windows_core::imp::ProxyVtable for IAcousticEchoCancellationConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEchoCancellationRenderEndpoint(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAcousticEchoCancellationConfiguration", "SetEchoCancellationRenderEndpoint", 6usize, |this| ((**(this as *const *const IAcousticEchoCancellationConfiguration_Vtbl)).SetEchoCancellationRenderEndpoint)(this, deviceid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetEchoCancellationRenderEndpoint }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAcousticEchoCancellationConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAudioCaptureEffectsManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AudioCaptureEffectsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioCaptureEffectsManager", "AudioCaptureEffectsChanged", 6usize, |this| ((**(this as *const *const IAudioCaptureEffectsManager_Vtbl)).AudioCaptureEffectsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAudioCaptureEffectsChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioCaptureEffectsManager", "RemoveAudioCaptureEffectsChanged", 7usize, |this| ((**(this as *const *const IAudioCaptureEffectsManager_Vtbl)).RemoveAudioCaptureEffectsChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAudioCaptureEffects(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioCaptureEffectsManager", "GetAudioCaptureEffects", 8usize, |this| ((**(this as *const *const IAudioCaptureEffectsManager_Vtbl)).GetAudioCaptureEffects)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AudioCaptureEffectsChanged,
+            RemoveAudioCaptureEffectsChanged,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAudioCaptureEffects,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAudioCaptureEffects: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioCaptureEffectsManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AudioEffectType(this: *mut core::ffi::c_void, result__: *mut AudioEffectType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffect", "AudioEffectType", 6usize, |this| ((**(this as *const *const IAudioEffect_Vtbl)).AudioEffectType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AudioEffectType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEffect2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcousticEchoCancellationConfiguration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffect2", "AcousticEchoCancellationConfiguration", 6usize, |this| ((**(this as *const *const IAudioEffect2_Vtbl)).AcousticEchoCancellationConfiguration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanSetState(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffect2", "CanSetState", 7usize, |this| ((**(this as *const *const IAudioEffect2_Vtbl)).CanSetState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut AudioEffectState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffect2", "State", 8usize, |this| ((**(this as *const *const IAudioEffect2_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, newstate: AudioEffectState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffect2", "SetState", 9usize, |this| ((**(this as *const *const IAudioEffect2_Vtbl)).SetState)(this, newstate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AcousticEchoCancellationConfiguration,
+            CanSetState,
+            State,
+            SetState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEffect2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAudioEffectDefinition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivatableClassId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectDefinition", "ActivatableClassId", 6usize, |this| ((**(this as *const *const IAudioEffectDefinition_Vtbl)).ActivatableClassId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectDefinition", "Properties", 7usize, |this| ((**(this as *const *const IAudioEffectDefinition_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ActivatableClassId,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEffectDefinition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAudioEffectDefinitionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectDefinitionFactory", "Create", 6usize, |this| ((**(this as *const *const IAudioEffectDefinitionFactory_Vtbl)).Create)(this, activatableclassid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWithProperties(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, props: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectDefinitionFactory", "CreateWithProperties", 7usize, |this| ((**(this as *const *const IAudioEffectDefinitionFactory_Vtbl)).CreateWithProperties)(this, activatableclassid, props, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWithProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWithProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEffectDefinitionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Capture", feature = "Media_Render"))]
+impl windows_core::imp::ProxyVtable for IAudioEffectsManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Render")]
+        unsafe extern "system" fn CreateAudioRenderEffectsManager(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, category: super::Render::AudioRenderCategory, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsManagerStatics", "CreateAudioRenderEffectsManager", 6usize, |this| ((**(this as *const *const IAudioEffectsManagerStatics_Vtbl)).CreateAudioRenderEffectsManager)(this, deviceid, category, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Render")]
+        unsafe extern "system" fn CreateAudioRenderEffectsManagerWithMode(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, category: super::Render::AudioRenderCategory, mode: super::AudioProcessing, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsManagerStatics", "CreateAudioRenderEffectsManagerWithMode", 7usize, |this| ((**(this as *const *const IAudioEffectsManagerStatics_Vtbl)).CreateAudioRenderEffectsManagerWithMode)(this, deviceid, category, mode, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Capture")]
+        unsafe extern "system" fn CreateAudioCaptureEffectsManager(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, category: super::Capture::MediaCategory, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsManagerStatics", "CreateAudioCaptureEffectsManager", 8usize, |this| ((**(this as *const *const IAudioEffectsManagerStatics_Vtbl)).CreateAudioCaptureEffectsManager)(this, deviceid, category, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Capture")]
+        unsafe extern "system" fn CreateAudioCaptureEffectsManagerWithMode(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, category: super::Capture::MediaCategory, mode: super::AudioProcessing, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsManagerStatics", "CreateAudioCaptureEffectsManagerWithMode", 9usize, |this| ((**(this as *const *const IAudioEffectsManagerStatics_Vtbl)).CreateAudioCaptureEffectsManagerWithMode)(this, deviceid, category, mode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Render")]
+            CreateAudioRenderEffectsManager,
+            #[cfg(not(feature = "Media_Render"))]
+            CreateAudioRenderEffectsManager: 0,
+            #[cfg(feature = "Media_Render")]
+            CreateAudioRenderEffectsManagerWithMode,
+            #[cfg(not(feature = "Media_Render"))]
+            CreateAudioRenderEffectsManagerWithMode: 0,
+            #[cfg(feature = "Media_Capture")]
+            CreateAudioCaptureEffectsManager,
+            #[cfg(not(feature = "Media_Capture"))]
+            CreateAudioCaptureEffectsManager: 0,
+            #[cfg(feature = "Media_Capture")]
+            CreateAudioCaptureEffectsManagerWithMode,
+            #[cfg(not(feature = "Media_Capture"))]
+            CreateAudioCaptureEffectsManagerWithMode: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEffectsManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAudioRenderEffectsManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AudioRenderEffectsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioRenderEffectsManager", "AudioRenderEffectsChanged", 6usize, |this| ((**(this as *const *const IAudioRenderEffectsManager_Vtbl)).AudioRenderEffectsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAudioRenderEffectsChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioRenderEffectsManager", "RemoveAudioRenderEffectsChanged", 7usize, |this| ((**(this as *const *const IAudioRenderEffectsManager_Vtbl)).RemoveAudioRenderEffectsChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAudioRenderEffects(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioRenderEffectsManager", "GetAudioRenderEffects", 8usize, |this| ((**(this as *const *const IAudioRenderEffectsManager_Vtbl)).GetAudioRenderEffects)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AudioRenderEffectsChanged,
+            RemoveAudioRenderEffectsChanged,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAudioRenderEffects,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAudioRenderEffects: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioRenderEffectsManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IAudioRenderEffectsManager2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn EffectsProviderThumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioRenderEffectsManager2", "EffectsProviderThumbnail", 6usize, |this| ((**(this as *const *const IAudioRenderEffectsManager2_Vtbl)).EffectsProviderThumbnail)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn EffectsProviderSettingsLabel(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioRenderEffectsManager2", "EffectsProviderSettingsLabel", 7usize, |this| ((**(this as *const *const IAudioRenderEffectsManager2_Vtbl)).EffectsProviderSettingsLabel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ShowSettingsUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioRenderEffectsManager2", "ShowSettingsUI", 8usize, |this| ((**(this as *const *const IAudioRenderEffectsManager2_Vtbl)).ShowSettingsUI)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            EffectsProviderThumbnail,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            EffectsProviderThumbnail: 0,
+            #[cfg(feature = "deprecated")]
+            EffectsProviderSettingsLabel,
+            #[cfg(not(feature = "deprecated"))]
+            EffectsProviderSettingsLabel: 0,
+            #[cfg(feature = "deprecated")]
+            ShowSettingsUI,
+            #[cfg(not(feature = "deprecated"))]
+            ShowSettingsUI: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioRenderEffectsManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IBasicAudioEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UseInputFrameForOutput(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicAudioEffect", "UseInputFrameForOutput", 6usize, |this| ((**(this as *const *const IBasicAudioEffect_Vtbl)).UseInputFrameForOutput)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn SupportedEncodingProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicAudioEffect", "SupportedEncodingProperties", 7usize, |this| ((**(this as *const *const IBasicAudioEffect_Vtbl)).SupportedEncodingProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn SetEncodingProperties(this: *mut core::ffi::c_void, encodingproperties: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicAudioEffect", "SetEncodingProperties", 8usize, |this| ((**(this as *const *const IBasicAudioEffect_Vtbl)).SetEncodingProperties)(this, encodingproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessFrame(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicAudioEffect", "ProcessFrame", 9usize, |this| ((**(this as *const *const IBasicAudioEffect_Vtbl)).ProcessFrame)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void, reason: MediaEffectClosedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicAudioEffect", "Close", 10usize, |this| ((**(this as *const *const IBasicAudioEffect_Vtbl)).Close)(this, reason), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscardQueuedFrames(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicAudioEffect", "DiscardQueuedFrames", 11usize, |this| ((**(this as *const *const IBasicAudioEffect_Vtbl)).DiscardQueuedFrames)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UseInputFrameForOutput,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+            SupportedEncodingProperties,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_MediaProperties")))]
+            SupportedEncodingProperties: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            SetEncodingProperties,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            SetEncodingProperties: 0,
+            ProcessFrame,
+            Close,
+            DiscardQueuedFrames,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBasicAudioEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX_Direct3D11", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IBasicVideoEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsReadOnly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideoEffect", "IsReadOnly", 6usize, |this| ((**(this as *const *const IBasicVideoEffect_Vtbl)).IsReadOnly)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedMemoryTypes(this: *mut core::ffi::c_void, result__: *mut MediaMemoryTypes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideoEffect", "SupportedMemoryTypes", 7usize, |this| ((**(this as *const *const IBasicVideoEffect_Vtbl)).SupportedMemoryTypes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeIndependent(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideoEffect", "TimeIndependent", 8usize, |this| ((**(this as *const *const IBasicVideoEffect_Vtbl)).TimeIndependent)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn SupportedEncodingProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideoEffect", "SupportedEncodingProperties", 9usize, |this| ((**(this as *const *const IBasicVideoEffect_Vtbl)).SupportedEncodingProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Graphics_DirectX_Direct3D11", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn SetEncodingProperties(this: *mut core::ffi::c_void, encodingproperties: *mut core::ffi::c_void, device: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideoEffect", "SetEncodingProperties", 10usize, |this| ((**(this as *const *const IBasicVideoEffect_Vtbl)).SetEncodingProperties)(this, encodingproperties, device), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessFrame(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideoEffect", "ProcessFrame", 11usize, |this| ((**(this as *const *const IBasicVideoEffect_Vtbl)).ProcessFrame)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void, reason: MediaEffectClosedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideoEffect", "Close", 12usize, |this| ((**(this as *const *const IBasicVideoEffect_Vtbl)).Close)(this, reason), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscardQueuedFrames(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideoEffect", "DiscardQueuedFrames", 13usize, |this| ((**(this as *const *const IBasicVideoEffect_Vtbl)).DiscardQueuedFrames)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsReadOnly,
+            SupportedMemoryTypes,
+            TimeIndependent,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+            SupportedEncodingProperties,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_MediaProperties")))]
+            SupportedEncodingProperties: 0,
+            #[cfg(all(feature = "Graphics_DirectX_Direct3D11", feature = "Media_MediaProperties"))]
+            SetEncodingProperties,
+            #[cfg(not(all(feature = "Graphics_DirectX_Direct3D11", feature = "Media_MediaProperties")))]
+            SetEncodingProperties: 0,
+            ProcessFrame,
+            Close,
+            DiscardQueuedFrames,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBasicVideoEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX_Direct3D11", feature = "Media_Editing"))]
+impl windows_core::imp::ProxyVtable for ICompositeVideoFrameContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX_Direct3D11"))]
+        unsafe extern "system" fn SurfacesToOverlay(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositeVideoFrameContext", "SurfacesToOverlay", 6usize, |this| ((**(this as *const *const ICompositeVideoFrameContext_Vtbl)).SurfacesToOverlay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositeVideoFrameContext", "BackgroundFrame", 7usize, |this| ((**(this as *const *const ICompositeVideoFrameContext_Vtbl)).BackgroundFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutputFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositeVideoFrameContext", "OutputFrame", 8usize, |this| ((**(this as *const *const ICompositeVideoFrameContext_Vtbl)).OutputFrame)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Graphics_DirectX_Direct3D11", feature = "Media_Editing"))]
+        unsafe extern "system" fn GetOverlayForSurface(this: *mut core::ffi::c_void, surfacetooverlay: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositeVideoFrameContext", "GetOverlayForSurface", 9usize, |this| ((**(this as *const *const ICompositeVideoFrameContext_Vtbl)).GetOverlayForSurface)(this, surfacetooverlay, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX_Direct3D11"))]
+            SurfacesToOverlay,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics_DirectX_Direct3D11")))]
+            SurfacesToOverlay: 0,
+            BackgroundFrame,
+            OutputFrame,
+            #[cfg(all(feature = "Graphics_DirectX_Direct3D11", feature = "Media_Editing"))]
+            GetOverlayForSurface,
+            #[cfg(not(all(feature = "Graphics_DirectX_Direct3D11", feature = "Media_Editing")))]
+            GetOverlayForSurface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositeVideoFrameContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessAudioFrameContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InputFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessAudioFrameContext", "InputFrame", 6usize, |this| ((**(this as *const *const IProcessAudioFrameContext_Vtbl)).InputFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutputFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessAudioFrameContext", "OutputFrame", 7usize, |this| ((**(this as *const *const IProcessAudioFrameContext_Vtbl)).OutputFrame)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InputFrame, OutputFrame }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessAudioFrameContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessVideoFrameContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InputFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessVideoFrameContext", "InputFrame", 6usize, |this| ((**(this as *const *const IProcessVideoFrameContext_Vtbl)).InputFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutputFrame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessVideoFrameContext", "OutputFrame", 7usize, |this| ((**(this as *const *const IProcessVideoFrameContext_Vtbl)).OutputFrame)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InputFrame, OutputFrame }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessVideoFrameContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISlowMotionEffectDefinition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TimeStretchRate(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISlowMotionEffectDefinition", "TimeStretchRate", 6usize, |this| ((**(this as *const *const ISlowMotionEffectDefinition_Vtbl)).TimeStretchRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimeStretchRate(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISlowMotionEffectDefinition", "SetTimeStretchRate", 7usize, |this| ((**(this as *const *const ISlowMotionEffectDefinition_Vtbl)).SetTimeStretchRate)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TimeStretchRate, SetTimeStretchRate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISlowMotionEffectDefinition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX_Direct3D11", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IVideoCompositor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TimeIndependent(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoCompositor", "TimeIndependent", 6usize, |this| ((**(this as *const *const IVideoCompositor_Vtbl)).TimeIndependent)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Graphics_DirectX_Direct3D11", feature = "Media_MediaProperties"))]
+        unsafe extern "system" fn SetEncodingProperties(this: *mut core::ffi::c_void, backgroundproperties: *mut core::ffi::c_void, device: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoCompositor", "SetEncodingProperties", 7usize, |this| ((**(this as *const *const IVideoCompositor_Vtbl)).SetEncodingProperties)(this, backgroundproperties, device), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompositeFrame(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoCompositor", "CompositeFrame", 8usize, |this| ((**(this as *const *const IVideoCompositor_Vtbl)).CompositeFrame)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void, reason: MediaEffectClosedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoCompositor", "Close", 9usize, |this| ((**(this as *const *const IVideoCompositor_Vtbl)).Close)(this, reason), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscardQueuedFrames(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoCompositor", "DiscardQueuedFrames", 10usize, |this| ((**(this as *const *const IVideoCompositor_Vtbl)).DiscardQueuedFrames)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TimeIndependent,
+            #[cfg(all(feature = "Graphics_DirectX_Direct3D11", feature = "Media_MediaProperties"))]
+            SetEncodingProperties,
+            #[cfg(not(all(feature = "Graphics_DirectX_Direct3D11", feature = "Media_MediaProperties")))]
+            SetEncodingProperties: 0,
+            CompositeFrame,
+            Close,
+            DiscardQueuedFrames,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoCompositor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVideoCompositorDefinition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivatableClassId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoCompositorDefinition", "ActivatableClassId", 6usize, |this| ((**(this as *const *const IVideoCompositorDefinition_Vtbl)).ActivatableClassId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoCompositorDefinition", "Properties", 7usize, |this| ((**(this as *const *const IVideoCompositorDefinition_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ActivatableClassId,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoCompositorDefinition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVideoCompositorDefinitionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoCompositorDefinitionFactory", "Create", 6usize, |this| ((**(this as *const *const IVideoCompositorDefinitionFactory_Vtbl)).Create)(this, activatableclassid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWithProperties(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, props: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoCompositorDefinitionFactory", "CreateWithProperties", 7usize, |this| ((**(this as *const *const IVideoCompositorDefinitionFactory_Vtbl)).CreateWithProperties)(this, activatableclassid, props, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWithProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWithProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoCompositorDefinitionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVideoEffectDefinition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivatableClassId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEffectDefinition", "ActivatableClassId", 6usize, |this| ((**(this as *const *const IVideoEffectDefinition_Vtbl)).ActivatableClassId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEffectDefinition", "Properties", 7usize, |this| ((**(this as *const *const IVideoEffectDefinition_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ActivatableClassId,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoEffectDefinition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVideoEffectDefinitionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEffectDefinitionFactory", "Create", 6usize, |this| ((**(this as *const *const IVideoEffectDefinitionFactory_Vtbl)).Create)(this, activatableclassid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWithProperties(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, props: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEffectDefinitionFactory", "CreateWithProperties", 7usize, |this| ((**(this as *const *const IVideoEffectDefinitionFactory_Vtbl)).CreateWithProperties)(this, activatableclassid, props, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWithProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWithProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoEffectDefinitionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties", feature = "Media_Transcoding", feature = "UI"))]
+impl windows_core::imp::ProxyVtable for IVideoTransformEffectDefinition_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn PaddingColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformEffectDefinition", "PaddingColor", 6usize, |this| ((**(this as *const *const IVideoTransformEffectDefinition_Vtbl)).PaddingColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetPaddingColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformEffectDefinition", "SetPaddingColor", 7usize, |this| ((**(this as *const *const IVideoTransformEffectDefinition_Vtbl)).SetPaddingColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutputSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformEffectDefinition", "OutputSize", 8usize, |this| ((**(this as *const *const IVideoTransformEffectDefinition_Vtbl)).OutputSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputSize(this: *mut core::ffi::c_void, value: super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformEffectDefinition", "SetOutputSize", 9usize, |this| ((**(this as *const *const IVideoTransformEffectDefinition_Vtbl)).SetOutputSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CropRectangle(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformEffectDefinition", "CropRectangle", 10usize, |this| ((**(this as *const *const IVideoTransformEffectDefinition_Vtbl)).CropRectangle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCropRectangle(this: *mut core::ffi::c_void, value: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformEffectDefinition", "SetCropRectangle", 11usize, |this| ((**(this as *const *const IVideoTransformEffectDefinition_Vtbl)).SetCropRectangle)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn Rotation(this: *mut core::ffi::c_void, result__: *mut super::MediaProperties::MediaRotation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformEffectDefinition", "Rotation", 12usize, |this| ((**(this as *const *const IVideoTransformEffectDefinition_Vtbl)).Rotation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn SetRotation(this: *mut core::ffi::c_void, value: super::MediaProperties::MediaRotation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformEffectDefinition", "SetRotation", 13usize, |this| ((**(this as *const *const IVideoTransformEffectDefinition_Vtbl)).SetRotation)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn Mirror(this: *mut core::ffi::c_void, result__: *mut super::MediaProperties::MediaMirroringOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformEffectDefinition", "Mirror", 14usize, |this| ((**(this as *const *const IVideoTransformEffectDefinition_Vtbl)).Mirror)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn SetMirror(this: *mut core::ffi::c_void, value: super::MediaProperties::MediaMirroringOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformEffectDefinition", "SetMirror", 15usize, |this| ((**(this as *const *const IVideoTransformEffectDefinition_Vtbl)).SetMirror)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Transcoding")]
+        unsafe extern "system" fn SetProcessingAlgorithm(this: *mut core::ffi::c_void, value: super::Transcoding::MediaVideoProcessingAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformEffectDefinition", "SetProcessingAlgorithm", 16usize, |this| ((**(this as *const *const IVideoTransformEffectDefinition_Vtbl)).SetProcessingAlgorithm)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Transcoding")]
+        unsafe extern "system" fn ProcessingAlgorithm(this: *mut core::ffi::c_void, result__: *mut super::Transcoding::MediaVideoProcessingAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformEffectDefinition", "ProcessingAlgorithm", 17usize, |this| ((**(this as *const *const IVideoTransformEffectDefinition_Vtbl)).ProcessingAlgorithm)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI")]
+            PaddingColor,
+            #[cfg(not(feature = "UI"))]
+            PaddingColor: 0,
+            #[cfg(feature = "UI")]
+            SetPaddingColor,
+            #[cfg(not(feature = "UI"))]
+            SetPaddingColor: 0,
+            OutputSize,
+            SetOutputSize,
+            CropRectangle,
+            SetCropRectangle,
+            #[cfg(feature = "Media_MediaProperties")]
+            Rotation,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            Rotation: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            SetRotation,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            SetRotation: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            Mirror,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            Mirror: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            SetMirror,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            SetMirror: 0,
+            #[cfg(feature = "Media_Transcoding")]
+            SetProcessingAlgorithm,
+            #[cfg(not(feature = "Media_Transcoding"))]
+            SetProcessingAlgorithm: 0,
+            #[cfg(feature = "Media_Transcoding")]
+            ProcessingAlgorithm,
+            #[cfg(not(feature = "Media_Transcoding"))]
+            ProcessingAlgorithm: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoTransformEffectDefinition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoTransformEffectDefinition2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SphericalProjection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformEffectDefinition2", "SphericalProjection", 6usize, |this| ((**(this as *const *const IVideoTransformEffectDefinition2_Vtbl)).SphericalProjection)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SphericalProjection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoTransformEffectDefinition2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Media_MediaProperties", feature = "Media_Playback"))]
+impl windows_core::imp::ProxyVtable for IVideoTransformSphericalProjection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformSphericalProjection", "IsEnabled", 6usize, |this| ((**(this as *const *const IVideoTransformSphericalProjection_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformSphericalProjection", "SetIsEnabled", 7usize, |this| ((**(this as *const *const IVideoTransformSphericalProjection_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn FrameFormat(this: *mut core::ffi::c_void, result__: *mut super::MediaProperties::SphericalVideoFrameFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformSphericalProjection", "FrameFormat", 8usize, |this| ((**(this as *const *const IVideoTransformSphericalProjection_Vtbl)).FrameFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn SetFrameFormat(this: *mut core::ffi::c_void, value: super::MediaProperties::SphericalVideoFrameFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformSphericalProjection", "SetFrameFormat", 9usize, |this| ((**(this as *const *const IVideoTransformSphericalProjection_Vtbl)).SetFrameFormat)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Playback")]
+        unsafe extern "system" fn ProjectionMode(this: *mut core::ffi::c_void, result__: *mut super::Playback::SphericalVideoProjectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformSphericalProjection", "ProjectionMode", 10usize, |this| ((**(this as *const *const IVideoTransformSphericalProjection_Vtbl)).ProjectionMode)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Playback")]
+        unsafe extern "system" fn SetProjectionMode(this: *mut core::ffi::c_void, value: super::Playback::SphericalVideoProjectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformSphericalProjection", "SetProjectionMode", 11usize, |this| ((**(this as *const *const IVideoTransformSphericalProjection_Vtbl)).SetProjectionMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HorizontalFieldOfViewInDegrees(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformSphericalProjection", "HorizontalFieldOfViewInDegrees", 12usize, |this| ((**(this as *const *const IVideoTransformSphericalProjection_Vtbl)).HorizontalFieldOfViewInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHorizontalFieldOfViewInDegrees(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformSphericalProjection", "SetHorizontalFieldOfViewInDegrees", 13usize, |this| ((**(this as *const *const IVideoTransformSphericalProjection_Vtbl)).SetHorizontalFieldOfViewInDegrees)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn ViewOrientation(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformSphericalProjection", "ViewOrientation", 14usize, |this| ((**(this as *const *const IVideoTransformSphericalProjection_Vtbl)).ViewOrientation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetViewOrientation(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoTransformSphericalProjection", "SetViewOrientation", 15usize, |this| ((**(this as *const *const IVideoTransformSphericalProjection_Vtbl)).SetViewOrientation)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEnabled,
+            SetIsEnabled,
+            #[cfg(feature = "Media_MediaProperties")]
+            FrameFormat,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            FrameFormat: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            SetFrameFormat,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            SetFrameFormat: 0,
+            #[cfg(feature = "Media_Playback")]
+            ProjectionMode,
+            #[cfg(not(feature = "Media_Playback"))]
+            ProjectionMode: 0,
+            #[cfg(feature = "Media_Playback")]
+            SetProjectionMode,
+            #[cfg(not(feature = "Media_Playback"))]
+            SetProjectionMode: 0,
+            HorizontalFieldOfViewInDegrees,
+            SetHorizontalFieldOfViewInDegrees,
+            #[cfg(feature = "Foundation_Numerics")]
+            ViewOrientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            ViewOrientation: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetViewOrientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetViewOrientation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoTransformSphericalProjection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -315,3 +315,5 @@ impl windows_core::RuntimeName for FaceTracker {
 }
 unsafe impl Send for FaceTracker {}
 unsafe impl Sync for FaceTracker {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
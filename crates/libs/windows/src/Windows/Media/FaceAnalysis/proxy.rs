@@ -0,0 +1,204 @@
+#[cfg(feature = "Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for IDetectedFace_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn FaceBox(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Imaging::BitmapBounds) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDetectedFace", "FaceBox", 6usize, |this| ((**(this as *const *const IDetectedFace_Vtbl)).FaceBox)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Imaging")]
+            FaceBox,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            FaceBox: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDetectedFace as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for IFaceDetector_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+        unsafe extern "system" fn DetectFacesAsync(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetector", "DetectFacesAsync", 6usize, |this| ((**(this as *const *const IFaceDetector_Vtbl)).DetectFacesAsync)(this, image, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+        unsafe extern "system" fn DetectFacesWithSearchAreaAsync(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void, searcharea: super::super::Graphics::Imaging::BitmapBounds, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetector", "DetectFacesWithSearchAreaAsync", 7usize, |this| ((**(this as *const *const IFaceDetector_Vtbl)).DetectFacesWithSearchAreaAsync)(this, image, searcharea, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn MinDetectableFaceSize(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Imaging::BitmapSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetector", "MinDetectableFaceSize", 8usize, |this| ((**(this as *const *const IFaceDetector_Vtbl)).MinDetectableFaceSize)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SetMinDetectableFaceSize(this: *mut core::ffi::c_void, value: super::super::Graphics::Imaging::BitmapSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetector", "SetMinDetectableFaceSize", 9usize, |this| ((**(this as *const *const IFaceDetector_Vtbl)).SetMinDetectableFaceSize)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn MaxDetectableFaceSize(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Imaging::BitmapSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetector", "MaxDetectableFaceSize", 10usize, |this| ((**(this as *const *const IFaceDetector_Vtbl)).MaxDetectableFaceSize)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SetMaxDetectableFaceSize(this: *mut core::ffi::c_void, value: super::super::Graphics::Imaging::BitmapSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetector", "SetMaxDetectableFaceSize", 11usize, |this| ((**(this as *const *const IFaceDetector_Vtbl)).SetMaxDetectableFaceSize)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+            DetectFacesAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics_Imaging")))]
+            DetectFacesAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+            DetectFacesWithSearchAreaAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics_Imaging")))]
+            DetectFacesWithSearchAreaAsync: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            MinDetectableFaceSize,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            MinDetectableFaceSize: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            SetMinDetectableFaceSize,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SetMinDetectableFaceSize: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            MaxDetectableFaceSize,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            MaxDetectableFaceSize: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            SetMaxDetectableFaceSize,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SetMaxDetectableFaceSize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaceDetector as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for IFaceDetectorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectorStatics", "CreateAsync", 6usize, |this| ((**(this as *const *const IFaceDetectorStatics_Vtbl)).CreateAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+        unsafe extern "system" fn GetSupportedBitmapPixelFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectorStatics", "GetSupportedBitmapPixelFormats", 7usize, |this| ((**(this as *const *const IFaceDetectorStatics_Vtbl)).GetSupportedBitmapPixelFormats)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn IsBitmapPixelFormatSupported(this: *mut core::ffi::c_void, bitmappixelformat: super::super::Graphics::Imaging::BitmapPixelFormat, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectorStatics", "IsBitmapPixelFormatSupported", 8usize, |this| ((**(this as *const *const IFaceDetectorStatics_Vtbl)).IsBitmapPixelFormatSupported)(this, bitmappixelformat, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceDetectorStatics", "IsSupported", 9usize, |this| ((**(this as *const *const IFaceDetectorStatics_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateAsync,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+            GetSupportedBitmapPixelFormats,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics_Imaging")))]
+            GetSupportedBitmapPixelFormats: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            IsBitmapPixelFormatSupported,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            IsBitmapPixelFormatSupported: 0,
+            IsSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaceDetectorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for IFaceTracker_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ProcessNextFrameAsync(this: *mut core::ffi::c_void, videoframe: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceTracker", "ProcessNextFrameAsync", 6usize, |this| ((**(this as *const *const IFaceTracker_Vtbl)).ProcessNextFrameAsync)(this, videoframe, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn MinDetectableFaceSize(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Imaging::BitmapSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceTracker", "MinDetectableFaceSize", 7usize, |this| ((**(this as *const *const IFaceTracker_Vtbl)).MinDetectableFaceSize)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SetMinDetectableFaceSize(this: *mut core::ffi::c_void, value: super::super::Graphics::Imaging::BitmapSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceTracker", "SetMinDetectableFaceSize", 8usize, |this| ((**(this as *const *const IFaceTracker_Vtbl)).SetMinDetectableFaceSize)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn MaxDetectableFaceSize(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Imaging::BitmapSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceTracker", "MaxDetectableFaceSize", 9usize, |this| ((**(this as *const *const IFaceTracker_Vtbl)).MaxDetectableFaceSize)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SetMaxDetectableFaceSize(this: *mut core::ffi::c_void, value: super::super::Graphics::Imaging::BitmapSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceTracker", "SetMaxDetectableFaceSize", 10usize, |this| ((**(this as *const *const IFaceTracker_Vtbl)).SetMaxDetectableFaceSize)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ProcessNextFrameAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ProcessNextFrameAsync: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            MinDetectableFaceSize,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            MinDetectableFaceSize: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            SetMinDetectableFaceSize,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SetMinDetectableFaceSize: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            MaxDetectableFaceSize,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            MaxDetectableFaceSize: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            SetMaxDetectableFaceSize,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SetMaxDetectableFaceSize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaceTracker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for IFaceTrackerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceTrackerStatics", "CreateAsync", 6usize, |this| ((**(this as *const *const IFaceTrackerStatics_Vtbl)).CreateAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+        unsafe extern "system" fn GetSupportedBitmapPixelFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceTrackerStatics", "GetSupportedBitmapPixelFormats", 7usize, |this| ((**(this as *const *const IFaceTrackerStatics_Vtbl)).GetSupportedBitmapPixelFormats)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn IsBitmapPixelFormatSupported(this: *mut core::ffi::c_void, bitmappixelformat: super::super::Graphics::Imaging::BitmapPixelFormat, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceTrackerStatics", "IsBitmapPixelFormatSupported", 8usize, |this| ((**(this as *const *const IFaceTrackerStatics_Vtbl)).IsBitmapPixelFormatSupported)(this, bitmappixelformat, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaceTrackerStatics", "IsSupported", 9usize, |this| ((**(this as *const *const IFaceTrackerStatics_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateAsync,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Imaging"))]
+            GetSupportedBitmapPixelFormats,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics_Imaging")))]
+            GetSupportedBitmapPixelFormats: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            IsBitmapPixelFormatSupported,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            IsBitmapPixelFormatSupported: 0,
+            IsSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaceTrackerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
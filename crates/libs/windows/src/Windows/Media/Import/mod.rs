@@ -1491,6 +1491,7 @@ impl PhotoImportAccessMode {
 impl windows_core::TypeKind for PhotoImportAccessMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhotoImportAccessMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhotoImportAccessMode").field(&self.0).finish()
@@ -1511,6 +1512,7 @@ impl PhotoImportConnectionTransport {
 impl windows_core::TypeKind for PhotoImportConnectionTransport {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhotoImportConnectionTransport {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhotoImportConnectionTransport").field(&self.0).finish()
@@ -1530,6 +1532,7 @@ impl PhotoImportContentType {
 impl windows_core::TypeKind for PhotoImportContentType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhotoImportContentType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhotoImportContentType").field(&self.0).finish()
@@ -1550,6 +1553,7 @@ impl PhotoImportContentTypeFilter {
 impl windows_core::TypeKind for PhotoImportContentTypeFilter {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhotoImportContentTypeFilter {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhotoImportContentTypeFilter").field(&self.0).finish()
@@ -1570,6 +1574,7 @@ impl PhotoImportImportMode {
 impl windows_core::TypeKind for PhotoImportImportMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhotoImportImportMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhotoImportImportMode").field(&self.0).finish()
@@ -1589,6 +1594,7 @@ impl PhotoImportItemSelectionMode {
 impl windows_core::TypeKind for PhotoImportItemSelectionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhotoImportItemSelectionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhotoImportItemSelectionMode").field(&self.0).finish()
@@ -1608,6 +1614,7 @@ impl PhotoImportPowerSource {
 impl windows_core::TypeKind for PhotoImportPowerSource {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhotoImportPowerSource {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhotoImportPowerSource").field(&self.0).finish()
@@ -1631,6 +1638,7 @@ impl PhotoImportSourceType {
 impl windows_core::TypeKind for PhotoImportSourceType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhotoImportSourceType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhotoImportSourceType").field(&self.0).finish()
@@ -1651,6 +1659,7 @@ impl PhotoImportStage {
 impl windows_core::TypeKind for PhotoImportStage {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhotoImportStage {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhotoImportStage").field(&self.0).finish()
@@ -1670,6 +1679,7 @@ impl PhotoImportStorageMediumType {
 impl windows_core::TypeKind for PhotoImportStorageMediumType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhotoImportStorageMediumType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhotoImportStorageMediumType").field(&self.0).finish()
@@ -1690,6 +1700,7 @@ impl PhotoImportSubfolderCreationMode {
 impl windows_core::TypeKind for PhotoImportSubfolderCreationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhotoImportSubfolderCreationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhotoImportSubfolderCreationMode").field(&self.0).finish()
@@ -1709,6 +1720,7 @@ impl PhotoImportSubfolderDateFormat {
 impl windows_core::TypeKind for PhotoImportSubfolderDateFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhotoImportSubfolderDateFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhotoImportSubfolderDateFormat").field(&self.0).finish()
@@ -1718,7 +1730,8 @@ impl windows_core::RuntimeType for PhotoImportSubfolderDateFormat {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.Import.PhotoImportSubfolderDateFormat;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PhotoImportProgress {
     pub ItemsImported: u32,
     pub TotalItemsToImport: u32,
@@ -1737,3 +1750,5 @@ impl Default for PhotoImportProgress {
         unsafe { core::mem::zeroed() }
     }
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,783 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPhotoImportDeleteImportedItemsFromSourceResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportDeleteImportedItemsFromSourceResult", "Session", 6usize, |this| ((**(this as *const *const IPhotoImportDeleteImportedItemsFromSourceResult_Vtbl)).Session)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasSucceeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportDeleteImportedItemsFromSourceResult", "HasSucceeded", 7usize, |this| ((**(this as *const *const IPhotoImportDeleteImportedItemsFromSourceResult_Vtbl)).HasSucceeded)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DeletedItems(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportDeleteImportedItemsFromSourceResult", "DeletedItems", 8usize, |this| ((**(this as *const *const IPhotoImportDeleteImportedItemsFromSourceResult_Vtbl)).DeletedItems)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotosCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportDeleteImportedItemsFromSourceResult", "PhotosCount", 9usize, |this| ((**(this as *const *const IPhotoImportDeleteImportedItemsFromSourceResult_Vtbl)).PhotosCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotosSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportDeleteImportedItemsFromSourceResult", "PhotosSizeInBytes", 10usize, |this| ((**(this as *const *const IPhotoImportDeleteImportedItemsFromSourceResult_Vtbl)).PhotosSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideosCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportDeleteImportedItemsFromSourceResult", "VideosCount", 11usize, |this| ((**(this as *const *const IPhotoImportDeleteImportedItemsFromSourceResult_Vtbl)).VideosCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideosSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportDeleteImportedItemsFromSourceResult", "VideosSizeInBytes", 12usize, |this| ((**(this as *const *const IPhotoImportDeleteImportedItemsFromSourceResult_Vtbl)).VideosSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SidecarsCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportDeleteImportedItemsFromSourceResult", "SidecarsCount", 13usize, |this| ((**(this as *const *const IPhotoImportDeleteImportedItemsFromSourceResult_Vtbl)).SidecarsCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SidecarsSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportDeleteImportedItemsFromSourceResult", "SidecarsSizeInBytes", 14usize, |this| ((**(this as *const *const IPhotoImportDeleteImportedItemsFromSourceResult_Vtbl)).SidecarsSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SiblingsCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportDeleteImportedItemsFromSourceResult", "SiblingsCount", 15usize, |this| ((**(this as *const *const IPhotoImportDeleteImportedItemsFromSourceResult_Vtbl)).SiblingsCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SiblingsSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportDeleteImportedItemsFromSourceResult", "SiblingsSizeInBytes", 16usize, |this| ((**(this as *const *const IPhotoImportDeleteImportedItemsFromSourceResult_Vtbl)).SiblingsSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportDeleteImportedItemsFromSourceResult", "TotalCount", 17usize, |this| ((**(this as *const *const IPhotoImportDeleteImportedItemsFromSourceResult_Vtbl)).TotalCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportDeleteImportedItemsFromSourceResult", "TotalSizeInBytes", 18usize, |this| ((**(this as *const *const IPhotoImportDeleteImportedItemsFromSourceResult_Vtbl)).TotalSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Session,
+            HasSucceeded,
+            #[cfg(feature = "Foundation_Collections")]
+            DeletedItems,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DeletedItems: 0,
+            PhotosCount,
+            PhotosSizeInBytes,
+            VideosCount,
+            VideosSizeInBytes,
+            SidecarsCount,
+            SidecarsSizeInBytes,
+            SiblingsCount,
+            SiblingsSizeInBytes,
+            TotalCount,
+            TotalSizeInBytes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportDeleteImportedItemsFromSourceResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPhotoImportFindItemsResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "Session", 6usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).Session)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasSucceeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "HasSucceeded", 7usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).HasSucceeded)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FoundItems(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "FoundItems", 8usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).FoundItems)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotosCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "PhotosCount", 9usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).PhotosCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotosSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "PhotosSizeInBytes", 10usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).PhotosSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideosCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "VideosCount", 11usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).VideosCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideosSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "VideosSizeInBytes", 12usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).VideosSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SidecarsCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SidecarsCount", 13usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SidecarsCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SidecarsSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SidecarsSizeInBytes", 14usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SidecarsSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SiblingsCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SiblingsCount", 15usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SiblingsCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SiblingsSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SiblingsSizeInBytes", 16usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SiblingsSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "TotalCount", 17usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).TotalCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "TotalSizeInBytes", 18usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).TotalSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SelectAll", 19usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SelectAll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectNone(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SelectNone", 20usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SelectNone)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectNewAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SelectNewAsync", 21usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SelectNewAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetImportMode(this: *mut core::ffi::c_void, value: PhotoImportImportMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SetImportMode", 22usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SetImportMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportMode(this: *mut core::ffi::c_void, result__: *mut PhotoImportImportMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "ImportMode", 23usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).ImportMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedPhotosCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SelectedPhotosCount", 24usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SelectedPhotosCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedPhotosSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SelectedPhotosSizeInBytes", 25usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SelectedPhotosSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedVideosCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SelectedVideosCount", 26usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SelectedVideosCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedVideosSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SelectedVideosSizeInBytes", 27usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SelectedVideosSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedSidecarsCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SelectedSidecarsCount", 28usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SelectedSidecarsCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedSidecarsSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SelectedSidecarsSizeInBytes", 29usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SelectedSidecarsSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedSiblingsCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SelectedSiblingsCount", 30usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SelectedSiblingsCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedSiblingsSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SelectedSiblingsSizeInBytes", 31usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SelectedSiblingsSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedTotalCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SelectedTotalCount", 32usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SelectedTotalCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedTotalSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SelectedTotalSizeInBytes", 33usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SelectedTotalSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectionChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "SelectionChanged", 34usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).SelectionChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSelectionChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "RemoveSelectionChanged", 35usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).RemoveSelectionChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportItemsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "ImportItemsAsync", 36usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).ImportItemsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemImported(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "ItemImported", 37usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).ItemImported)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItemImported(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult", "RemoveItemImported", 38usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult_Vtbl)).RemoveItemImported)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Session,
+            HasSucceeded,
+            #[cfg(feature = "Foundation_Collections")]
+            FoundItems,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FoundItems: 0,
+            PhotosCount,
+            PhotosSizeInBytes,
+            VideosCount,
+            VideosSizeInBytes,
+            SidecarsCount,
+            SidecarsSizeInBytes,
+            SiblingsCount,
+            SiblingsSizeInBytes,
+            TotalCount,
+            TotalSizeInBytes,
+            SelectAll,
+            SelectNone,
+            SelectNewAsync,
+            SetImportMode,
+            ImportMode,
+            SelectedPhotosCount,
+            SelectedPhotosSizeInBytes,
+            SelectedVideosCount,
+            SelectedVideosSizeInBytes,
+            SelectedSidecarsCount,
+            SelectedSidecarsSizeInBytes,
+            SelectedSiblingsCount,
+            SelectedSiblingsSizeInBytes,
+            SelectedTotalCount,
+            SelectedTotalSizeInBytes,
+            SelectionChanged,
+            RemoveSelectionChanged,
+            ImportItemsAsync,
+            ItemImported,
+            RemoveItemImported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportFindItemsResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhotoImportFindItemsResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddItemsInDateRangeToSelection(this: *mut core::ffi::c_void, rangestart: super::super::Foundation::DateTime, rangelength: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportFindItemsResult2", "AddItemsInDateRangeToSelection", 6usize, |this| ((**(this as *const *const IPhotoImportFindItemsResult2_Vtbl)).AddItemsInDateRangeToSelection)(this, rangestart, rangelength), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddItemsInDateRangeToSelection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportFindItemsResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPhotoImportImportItemsResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportImportItemsResult", "Session", 6usize, |this| ((**(this as *const *const IPhotoImportImportItemsResult_Vtbl)).Session)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasSucceeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportImportItemsResult", "HasSucceeded", 7usize, |this| ((**(this as *const *const IPhotoImportImportItemsResult_Vtbl)).HasSucceeded)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ImportedItems(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportImportItemsResult", "ImportedItems", 8usize, |this| ((**(this as *const *const IPhotoImportImportItemsResult_Vtbl)).ImportedItems)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotosCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportImportItemsResult", "PhotosCount", 9usize, |this| ((**(this as *const *const IPhotoImportImportItemsResult_Vtbl)).PhotosCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhotosSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportImportItemsResult", "PhotosSizeInBytes", 10usize, |this| ((**(this as *const *const IPhotoImportImportItemsResult_Vtbl)).PhotosSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideosCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportImportItemsResult", "VideosCount", 11usize, |this| ((**(this as *const *const IPhotoImportImportItemsResult_Vtbl)).VideosCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideosSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportImportItemsResult", "VideosSizeInBytes", 12usize, |this| ((**(this as *const *const IPhotoImportImportItemsResult_Vtbl)).VideosSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SidecarsCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportImportItemsResult", "SidecarsCount", 13usize, |this| ((**(this as *const *const IPhotoImportImportItemsResult_Vtbl)).SidecarsCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SidecarsSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportImportItemsResult", "SidecarsSizeInBytes", 14usize, |this| ((**(this as *const *const IPhotoImportImportItemsResult_Vtbl)).SidecarsSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SiblingsCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportImportItemsResult", "SiblingsCount", 15usize, |this| ((**(this as *const *const IPhotoImportImportItemsResult_Vtbl)).SiblingsCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SiblingsSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportImportItemsResult", "SiblingsSizeInBytes", 16usize, |this| ((**(this as *const *const IPhotoImportImportItemsResult_Vtbl)).SiblingsSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportImportItemsResult", "TotalCount", 17usize, |this| ((**(this as *const *const IPhotoImportImportItemsResult_Vtbl)).TotalCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportImportItemsResult", "TotalSizeInBytes", 18usize, |this| ((**(this as *const *const IPhotoImportImportItemsResult_Vtbl)).TotalSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteImportedItemsFromSourceAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportImportItemsResult", "DeleteImportedItemsFromSourceAsync", 19usize, |this| ((**(this as *const *const IPhotoImportImportItemsResult_Vtbl)).DeleteImportedItemsFromSourceAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Session,
+            HasSucceeded,
+            #[cfg(feature = "Foundation_Collections")]
+            ImportedItems,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ImportedItems: 0,
+            PhotosCount,
+            PhotosSizeInBytes,
+            VideosCount,
+            VideosSizeInBytes,
+            SidecarsCount,
+            SidecarsSizeInBytes,
+            SiblingsCount,
+            SiblingsSizeInBytes,
+            TotalCount,
+            TotalSizeInBytes,
+            DeleteImportedItemsFromSourceAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportImportItemsResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IPhotoImportItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportItem", "Name", 6usize, |this| ((**(this as *const *const IPhotoImportItem_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemKey(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportItem", "ItemKey", 7usize, |this| ((**(this as *const *const IPhotoImportItem_Vtbl)).ItemKey)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentType(this: *mut core::ffi::c_void, result__: *mut PhotoImportContentType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportItem", "ContentType", 8usize, |this| ((**(this as *const *const IPhotoImportItem_Vtbl)).ContentType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportItem", "SizeInBytes", 9usize, |this| ((**(this as *const *const IPhotoImportItem_Vtbl)).SizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Date(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportItem", "Date", 10usize, |this| ((**(this as *const *const IPhotoImportItem_Vtbl)).Date)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sibling(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportItem", "Sibling", 11usize, |this| ((**(this as *const *const IPhotoImportItem_Vtbl)).Sibling)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Sidecars(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportItem", "Sidecars", 12usize, |this| ((**(this as *const *const IPhotoImportItem_Vtbl)).Sidecars)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn VideoSegments(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportItem", "VideoSegments", 13usize, |this| ((**(this as *const *const IPhotoImportItem_Vtbl)).VideoSegments)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSelected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportItem", "IsSelected", 14usize, |this| ((**(this as *const *const IPhotoImportItem_Vtbl)).IsSelected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsSelected(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportItem", "SetIsSelected", 15usize, |this| ((**(this as *const *const IPhotoImportItem_Vtbl)).SetIsSelected)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportItem", "Thumbnail", 16usize, |this| ((**(this as *const *const IPhotoImportItem_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ImportedFileNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportItem", "ImportedFileNames", 17usize, |this| ((**(this as *const *const IPhotoImportItem_Vtbl)).ImportedFileNames)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DeletedFileNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportItem", "DeletedFileNames", 18usize, |this| ((**(this as *const *const IPhotoImportItem_Vtbl)).DeletedFileNames)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            ItemKey,
+            ContentType,
+            SizeInBytes,
+            Date,
+            Sibling,
+            #[cfg(feature = "Foundation_Collections")]
+            Sidecars,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Sidecars: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            VideoSegments,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            VideoSegments: 0,
+            IsSelected,
+            SetIsSelected,
+            #[cfg(feature = "Storage_Streams")]
+            Thumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Thumbnail: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ImportedFileNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ImportedFileNames: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            DeletedFileNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DeletedFileNames: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhotoImportItem2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportItem2", "Path", 6usize, |this| ((**(this as *const *const IPhotoImportItem2_Vtbl)).Path)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Path }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportItem2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhotoImportItemImportedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ImportedItem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportItemImportedEventArgs", "ImportedItem", 6usize, |this| ((**(this as *const *const IPhotoImportItemImportedEventArgs_Vtbl)).ImportedItem)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ImportedItem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportItemImportedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPhotoImportManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupportedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportManagerStatics", "IsSupportedAsync", 6usize, |this| ((**(this as *const *const IPhotoImportManagerStatics_Vtbl)).IsSupportedAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllSourcesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportManagerStatics", "FindAllSourcesAsync", 7usize, |this| ((**(this as *const *const IPhotoImportManagerStatics_Vtbl)).FindAllSourcesAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetPendingOperations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportManagerStatics", "GetPendingOperations", 8usize, |this| ((**(this as *const *const IPhotoImportManagerStatics_Vtbl)).GetPendingOperations)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupportedAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllSourcesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllSourcesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetPendingOperations,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetPendingOperations: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhotoImportOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Stage(this: *mut core::ffi::c_void, result__: *mut PhotoImportStage) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportOperation", "Stage", 6usize, |this| ((**(this as *const *const IPhotoImportOperation_Vtbl)).Stage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportOperation", "Session", 7usize, |this| ((**(this as *const *const IPhotoImportOperation_Vtbl)).Session)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContinueFindingItemsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportOperation", "ContinueFindingItemsAsync", 8usize, |this| ((**(this as *const *const IPhotoImportOperation_Vtbl)).ContinueFindingItemsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContinueImportingItemsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportOperation", "ContinueImportingItemsAsync", 9usize, |this| ((**(this as *const *const IPhotoImportOperation_Vtbl)).ContinueImportingItemsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContinueDeletingImportedItemsFromSourceAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportOperation", "ContinueDeletingImportedItemsFromSourceAsync", 10usize, |this| ((**(this as *const *const IPhotoImportOperation_Vtbl)).ContinueDeletingImportedItemsFromSourceAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Stage,
+            Session,
+            ContinueFindingItemsAsync,
+            ContinueImportingItemsAsync,
+            ContinueDeletingImportedItemsFromSourceAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhotoImportSelectionChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSelectionEmpty(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSelectionChangedEventArgs", "IsSelectionEmpty", 6usize, |this| ((**(this as *const *const IPhotoImportSelectionChangedEventArgs_Vtbl)).IsSelectionEmpty)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSelectionEmpty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportSelectionChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IPhotoImportSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSession", "Source", 6usize, |this| ((**(this as *const *const IPhotoImportSession_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSession", "SessionId", 7usize, |this| ((**(this as *const *const IPhotoImportSession_Vtbl)).SessionId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SetDestinationFolder(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSession", "SetDestinationFolder", 8usize, |this| ((**(this as *const *const IPhotoImportSession_Vtbl)).SetDestinationFolder)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn DestinationFolder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSession", "DestinationFolder", 9usize, |this| ((**(this as *const *const IPhotoImportSession_Vtbl)).DestinationFolder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppendSessionDateToDestinationFolder(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSession", "SetAppendSessionDateToDestinationFolder", 10usize, |this| ((**(this as *const *const IPhotoImportSession_Vtbl)).SetAppendSessionDateToDestinationFolder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendSessionDateToDestinationFolder(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSession", "AppendSessionDateToDestinationFolder", 11usize, |this| ((**(this as *const *const IPhotoImportSession_Vtbl)).AppendSessionDateToDestinationFolder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubfolderCreationMode(this: *mut core::ffi::c_void, value: PhotoImportSubfolderCreationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSession", "SetSubfolderCreationMode", 12usize, |this| ((**(this as *const *const IPhotoImportSession_Vtbl)).SetSubfolderCreationMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubfolderCreationMode(this: *mut core::ffi::c_void, result__: *mut PhotoImportSubfolderCreationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSession", "SubfolderCreationMode", 13usize, |this| ((**(this as *const *const IPhotoImportSession_Vtbl)).SubfolderCreationMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestinationFileNamePrefix(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSession", "SetDestinationFileNamePrefix", 14usize, |this| ((**(this as *const *const IPhotoImportSession_Vtbl)).SetDestinationFileNamePrefix)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationFileNamePrefix(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSession", "DestinationFileNamePrefix", 15usize, |this| ((**(this as *const *const IPhotoImportSession_Vtbl)).DestinationFileNamePrefix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindItemsAsync(this: *mut core::ffi::c_void, contenttypefilter: PhotoImportContentTypeFilter, itemselectionmode: PhotoImportItemSelectionMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSession", "FindItemsAsync", 16usize, |this| ((**(this as *const *const IPhotoImportSession_Vtbl)).FindItemsAsync)(this, contenttypefilter, itemselectionmode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Source,
+            SessionId,
+            #[cfg(feature = "Storage")]
+            SetDestinationFolder,
+            #[cfg(not(feature = "Storage"))]
+            SetDestinationFolder: 0,
+            #[cfg(feature = "Storage")]
+            DestinationFolder,
+            #[cfg(not(feature = "Storage"))]
+            DestinationFolder: 0,
+            SetAppendSessionDateToDestinationFolder,
+            AppendSessionDateToDestinationFolder,
+            SetSubfolderCreationMode,
+            SubfolderCreationMode,
+            SetDestinationFileNamePrefix,
+            DestinationFileNamePrefix,
+            FindItemsAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhotoImportSession2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSubfolderDateFormat(this: *mut core::ffi::c_void, value: PhotoImportSubfolderDateFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSession2", "SetSubfolderDateFormat", 6usize, |this| ((**(this as *const *const IPhotoImportSession2_Vtbl)).SetSubfolderDateFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubfolderDateFormat(this: *mut core::ffi::c_void, result__: *mut PhotoImportSubfolderDateFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSession2", "SubfolderDateFormat", 7usize, |this| ((**(this as *const *const IPhotoImportSession2_Vtbl)).SubfolderDateFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRememberDeselectedItems(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSession2", "SetRememberDeselectedItems", 8usize, |this| ((**(this as *const *const IPhotoImportSession2_Vtbl)).SetRememberDeselectedItems)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RememberDeselectedItems(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSession2", "RememberDeselectedItems", 9usize, |this| ((**(this as *const *const IPhotoImportSession2_Vtbl)).RememberDeselectedItems)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetSubfolderDateFormat,
+            SubfolderDateFormat,
+            SetRememberDeselectedItems,
+            RememberDeselectedItems,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportSession2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhotoImportSidecar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSidecar", "Name", 6usize, |this| ((**(this as *const *const IPhotoImportSidecar_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSidecar", "SizeInBytes", 7usize, |this| ((**(this as *const *const IPhotoImportSidecar_Vtbl)).SizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Date(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSidecar", "Date", 8usize, |this| ((**(this as *const *const IPhotoImportSidecar_Vtbl)).Date)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, SizeInBytes, Date }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportSidecar as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IPhotoImportSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "Id", 6usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "DisplayName", 7usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "Description", 8usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Manufacturer(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "Manufacturer", 9usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).Manufacturer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Model(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "Model", 10usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).Model)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SerialNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "SerialNumber", 11usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).SerialNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionProtocol(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "ConnectionProtocol", 12usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).ConnectionProtocol)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionTransport(this: *mut core::ffi::c_void, result__: *mut PhotoImportConnectionTransport) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "ConnectionTransport", 13usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).ConnectionTransport)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut PhotoImportSourceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "Type", 14usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerSource(this: *mut core::ffi::c_void, result__: *mut PhotoImportPowerSource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "PowerSource", 15usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).PowerSource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BatteryLevelPercent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "BatteryLevelPercent", 16usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).BatteryLevelPercent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DateTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "DateTime", 17usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).DateTime)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StorageMedia(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "StorageMedia", 18usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).StorageMedia)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLocked(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "IsLocked", 19usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).IsLocked)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMassStorage(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "IsMassStorage", 20usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).IsMassStorage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "Thumbnail", 21usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateImportSession(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSource", "CreateImportSession", 22usize, |this| ((**(this as *const *const IPhotoImportSource_Vtbl)).CreateImportSession)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            DisplayName,
+            Description,
+            Manufacturer,
+            Model,
+            SerialNumber,
+            ConnectionProtocol,
+            ConnectionTransport,
+            Type,
+            PowerSource,
+            BatteryLevelPercent,
+            DateTime,
+            #[cfg(feature = "Foundation_Collections")]
+            StorageMedia,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StorageMedia: 0,
+            IsLocked,
+            IsMassStorage,
+            #[cfg(feature = "Storage_Streams")]
+            Thumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Thumbnail: 0,
+            CreateImportSession,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IPhotoImportSourceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromIdAsync(this: *mut core::ffi::c_void, sourceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSourceStatics", "FromIdAsync", 6usize, |this| ((**(this as *const *const IPhotoImportSourceStatics_Vtbl)).FromIdAsync)(this, sourceid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn FromFolderAsync(this: *mut core::ffi::c_void, sourcerootfolder: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportSourceStatics", "FromFolderAsync", 7usize, |this| ((**(this as *const *const IPhotoImportSourceStatics_Vtbl)).FromFolderAsync)(this, sourcerootfolder, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FromIdAsync,
+            #[cfg(feature = "Storage")]
+            FromFolderAsync,
+            #[cfg(not(feature = "Storage"))]
+            FromFolderAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportSourceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhotoImportStorageMedium_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportStorageMedium", "Name", 6usize, |this| ((**(this as *const *const IPhotoImportStorageMedium_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportStorageMedium", "Description", 7usize, |this| ((**(this as *const *const IPhotoImportStorageMedium_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SerialNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportStorageMedium", "SerialNumber", 8usize, |this| ((**(this as *const *const IPhotoImportStorageMedium_Vtbl)).SerialNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StorageMediumType(this: *mut core::ffi::c_void, result__: *mut PhotoImportStorageMediumType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportStorageMedium", "StorageMediumType", 9usize, |this| ((**(this as *const *const IPhotoImportStorageMedium_Vtbl)).StorageMediumType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedAccessMode(this: *mut core::ffi::c_void, result__: *mut PhotoImportAccessMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportStorageMedium", "SupportedAccessMode", 10usize, |this| ((**(this as *const *const IPhotoImportStorageMedium_Vtbl)).SupportedAccessMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CapacityInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportStorageMedium", "CapacityInBytes", 11usize, |this| ((**(this as *const *const IPhotoImportStorageMedium_Vtbl)).CapacityInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvailableSpaceInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportStorageMedium", "AvailableSpaceInBytes", 12usize, |this| ((**(this as *const *const IPhotoImportStorageMedium_Vtbl)).AvailableSpaceInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportStorageMedium", "Refresh", 13usize, |this| ((**(this as *const *const IPhotoImportStorageMedium_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Description,
+            SerialNumber,
+            StorageMediumType,
+            SupportedAccessMode,
+            CapacityInBytes,
+            AvailableSpaceInBytes,
+            Refresh,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportStorageMedium as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPhotoImportVideoSegment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportVideoSegment", "Name", 6usize, |this| ((**(this as *const *const IPhotoImportVideoSegment_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportVideoSegment", "SizeInBytes", 7usize, |this| ((**(this as *const *const IPhotoImportVideoSegment_Vtbl)).SizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Date(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportVideoSegment", "Date", 8usize, |this| ((**(this as *const *const IPhotoImportVideoSegment_Vtbl)).Date)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sibling(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportVideoSegment", "Sibling", 9usize, |this| ((**(this as *const *const IPhotoImportVideoSegment_Vtbl)).Sibling)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Sidecars(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoImportVideoSegment", "Sidecars", 10usize, |this| ((**(this as *const *const IPhotoImportVideoSegment_Vtbl)).Sidecars)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SizeInBytes,
+            Date,
+            Sibling,
+            #[cfg(feature = "Foundation_Collections")]
+            Sidecars,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Sidecars: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoImportVideoSegment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
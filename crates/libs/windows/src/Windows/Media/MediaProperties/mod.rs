@@ -2374,6 +2374,7 @@ impl AudioEncodingQuality {
 impl windows_core::TypeKind for AudioEncodingQuality {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioEncodingQuality {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioEncodingQuality").field(&self.0).finish()
@@ -2393,6 +2394,7 @@ impl MediaMirroringOptions {
 impl windows_core::TypeKind for MediaMirroringOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaMirroringOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaMirroringOptions").field(&self.0).finish()
@@ -2445,6 +2447,7 @@ impl MediaPixelFormat {
 impl windows_core::TypeKind for MediaPixelFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaPixelFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaPixelFormat").field(&self.0).finish()
@@ -2465,6 +2468,7 @@ impl MediaRotation {
 impl windows_core::TypeKind for MediaRotation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaRotation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaRotation").field(&self.0).finish()
@@ -2483,6 +2487,7 @@ impl MediaThumbnailFormat {
 impl windows_core::TypeKind for MediaThumbnailFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaThumbnailFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaThumbnailFormat").field(&self.0).finish()
@@ -2502,6 +2507,7 @@ impl SphericalVideoFrameFormat {
 impl windows_core::TypeKind for SphericalVideoFrameFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SphericalVideoFrameFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SphericalVideoFrameFormat").field(&self.0).finish()
@@ -2521,6 +2527,7 @@ impl StereoscopicVideoPackingMode {
 impl windows_core::TypeKind for StereoscopicVideoPackingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StereoscopicVideoPackingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StereoscopicVideoPackingMode").field(&self.0).finish()
@@ -2547,6 +2554,7 @@ impl VideoEncodingQuality {
 impl windows_core::TypeKind for VideoEncodingQuality {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VideoEncodingQuality {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VideoEncodingQuality").field(&self.0).finish()
@@ -2557,3 +2565,5 @@ impl windows_core::RuntimeType for VideoEncodingQuality {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1187 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAudioEncodingProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBitrate(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingProperties", "SetBitrate", 6usize, |this| ((**(this as *const *const IAudioEncodingProperties_Vtbl)).SetBitrate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bitrate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingProperties", "Bitrate", 7usize, |this| ((**(this as *const *const IAudioEncodingProperties_Vtbl)).Bitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChannelCount(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingProperties", "SetChannelCount", 8usize, |this| ((**(this as *const *const IAudioEncodingProperties_Vtbl)).SetChannelCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChannelCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingProperties", "ChannelCount", 9usize, |this| ((**(this as *const *const IAudioEncodingProperties_Vtbl)).ChannelCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSampleRate(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingProperties", "SetSampleRate", 10usize, |this| ((**(this as *const *const IAudioEncodingProperties_Vtbl)).SetSampleRate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SampleRate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingProperties", "SampleRate", 11usize, |this| ((**(this as *const *const IAudioEncodingProperties_Vtbl)).SampleRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBitsPerSample(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingProperties", "SetBitsPerSample", 12usize, |this| ((**(this as *const *const IAudioEncodingProperties_Vtbl)).SetBitsPerSample)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitsPerSample(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingProperties", "BitsPerSample", 13usize, |this| ((**(this as *const *const IAudioEncodingProperties_Vtbl)).BitsPerSample)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetBitrate,
+            Bitrate,
+            SetChannelCount,
+            ChannelCount,
+            SetSampleRate,
+            SampleRate,
+            SetBitsPerSample,
+            BitsPerSample,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEncodingProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEncodingProperties2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSpatial(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingProperties2", "IsSpatial", 6usize, |this| ((**(this as *const *const IAudioEncodingProperties2_Vtbl)).IsSpatial)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSpatial }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEncodingProperties2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEncodingProperties3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingProperties3", "Copy", 6usize, |this| ((**(this as *const *const IAudioEncodingProperties3_Vtbl)).Copy)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Copy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEncodingProperties3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEncodingPropertiesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAac(this: *mut core::ffi::c_void, samplerate: u32, channelcount: u32, bitrate: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingPropertiesStatics", "CreateAac", 6usize, |this| ((**(this as *const *const IAudioEncodingPropertiesStatics_Vtbl)).CreateAac)(this, samplerate, channelcount, bitrate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAacAdts(this: *mut core::ffi::c_void, samplerate: u32, channelcount: u32, bitrate: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingPropertiesStatics", "CreateAacAdts", 7usize, |this| ((**(this as *const *const IAudioEncodingPropertiesStatics_Vtbl)).CreateAacAdts)(this, samplerate, channelcount, bitrate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMp3(this: *mut core::ffi::c_void, samplerate: u32, channelcount: u32, bitrate: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingPropertiesStatics", "CreateMp3", 8usize, |this| ((**(this as *const *const IAudioEncodingPropertiesStatics_Vtbl)).CreateMp3)(this, samplerate, channelcount, bitrate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePcm(this: *mut core::ffi::c_void, samplerate: u32, channelcount: u32, bitspersample: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingPropertiesStatics", "CreatePcm", 9usize, |this| ((**(this as *const *const IAudioEncodingPropertiesStatics_Vtbl)).CreatePcm)(this, samplerate, channelcount, bitspersample, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWma(this: *mut core::ffi::c_void, samplerate: u32, channelcount: u32, bitrate: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingPropertiesStatics", "CreateWma", 10usize, |this| ((**(this as *const *const IAudioEncodingPropertiesStatics_Vtbl)).CreateWma)(this, samplerate, channelcount, bitrate, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateAac, CreateAacAdts, CreateMp3, CreatePcm, CreateWma }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEncodingPropertiesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEncodingPropertiesStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAlac(this: *mut core::ffi::c_void, samplerate: u32, channelcount: u32, bitspersample: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingPropertiesStatics2", "CreateAlac", 6usize, |this| ((**(this as *const *const IAudioEncodingPropertiesStatics2_Vtbl)).CreateAlac)(this, samplerate, channelcount, bitspersample, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFlac(this: *mut core::ffi::c_void, samplerate: u32, channelcount: u32, bitspersample: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingPropertiesStatics2", "CreateFlac", 7usize, |this| ((**(this as *const *const IAudioEncodingPropertiesStatics2_Vtbl)).CreateFlac)(this, samplerate, channelcount, bitspersample, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateAlac, CreateFlac }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEncodingPropertiesStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEncodingPropertiesWithFormatUserData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFormatUserData(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingPropertiesWithFormatUserData", "SetFormatUserData", 6usize, |this| ((**(this as *const *const IAudioEncodingPropertiesWithFormatUserData_Vtbl)).SetFormatUserData)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormatUserData(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEncodingPropertiesWithFormatUserData", "GetFormatUserData", 7usize, |this| ((**(this as *const *const IAudioEncodingPropertiesWithFormatUserData_Vtbl)).GetFormatUserData)(this, value_array_size, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetFormatUserData, GetFormatUserData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEncodingPropertiesWithFormatUserData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAv1ProfileIdsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MainChromaSubsampling420BitDepth8(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAv1ProfileIdsStatics", "MainChromaSubsampling420BitDepth8", 6usize, |this| ((**(this as *const *const IAv1ProfileIdsStatics_Vtbl)).MainChromaSubsampling420BitDepth8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainChromaSubsampling420BitDepth10(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAv1ProfileIdsStatics", "MainChromaSubsampling420BitDepth10", 7usize, |this| ((**(this as *const *const IAv1ProfileIdsStatics_Vtbl)).MainChromaSubsampling420BitDepth10)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainChromaSubsampling400BitDepth8(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAv1ProfileIdsStatics", "MainChromaSubsampling400BitDepth8", 8usize, |this| ((**(this as *const *const IAv1ProfileIdsStatics_Vtbl)).MainChromaSubsampling400BitDepth8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainChromaSubsampling400BitDepth10(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAv1ProfileIdsStatics", "MainChromaSubsampling400BitDepth10", 9usize, |this| ((**(this as *const *const IAv1ProfileIdsStatics_Vtbl)).MainChromaSubsampling400BitDepth10)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HighChromaSubsampling444BitDepth8(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAv1ProfileIdsStatics", "HighChromaSubsampling444BitDepth8", 10usize, |this| ((**(this as *const *const IAv1ProfileIdsStatics_Vtbl)).HighChromaSubsampling444BitDepth8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HighChromaSubsampling444BitDepth10(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAv1ProfileIdsStatics", "HighChromaSubsampling444BitDepth10", 11usize, |this| ((**(this as *const *const IAv1ProfileIdsStatics_Vtbl)).HighChromaSubsampling444BitDepth10)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProfessionalChromaSubsampling420BitDepth12(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAv1ProfileIdsStatics", "ProfessionalChromaSubsampling420BitDepth12", 12usize, |this| ((**(this as *const *const IAv1ProfileIdsStatics_Vtbl)).ProfessionalChromaSubsampling420BitDepth12)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProfessionalChromaSubsampling400BitDepth12(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAv1ProfileIdsStatics", "ProfessionalChromaSubsampling400BitDepth12", 13usize, |this| ((**(this as *const *const IAv1ProfileIdsStatics_Vtbl)).ProfessionalChromaSubsampling400BitDepth12)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProfessionalChromaSubsampling444BitDepth12(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAv1ProfileIdsStatics", "ProfessionalChromaSubsampling444BitDepth12", 14usize, |this| ((**(this as *const *const IAv1ProfileIdsStatics_Vtbl)).ProfessionalChromaSubsampling444BitDepth12)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProfessionalChromaSubsampling422BitDepth8(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAv1ProfileIdsStatics", "ProfessionalChromaSubsampling422BitDepth8", 15usize, |this| ((**(this as *const *const IAv1ProfileIdsStatics_Vtbl)).ProfessionalChromaSubsampling422BitDepth8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProfessionalChromaSubsampling422BitDepth10(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAv1ProfileIdsStatics", "ProfessionalChromaSubsampling422BitDepth10", 16usize, |this| ((**(this as *const *const IAv1ProfileIdsStatics_Vtbl)).ProfessionalChromaSubsampling422BitDepth10)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProfessionalChromaSubsampling422BitDepth12(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAv1ProfileIdsStatics", "ProfessionalChromaSubsampling422BitDepth12", 17usize, |this| ((**(this as *const *const IAv1ProfileIdsStatics_Vtbl)).ProfessionalChromaSubsampling422BitDepth12)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MainChromaSubsampling420BitDepth8,
+            MainChromaSubsampling420BitDepth10,
+            MainChromaSubsampling400BitDepth8,
+            MainChromaSubsampling400BitDepth10,
+            HighChromaSubsampling444BitDepth8,
+            HighChromaSubsampling444BitDepth10,
+            ProfessionalChromaSubsampling420BitDepth12,
+            ProfessionalChromaSubsampling400BitDepth12,
+            ProfessionalChromaSubsampling444BitDepth12,
+            ProfessionalChromaSubsampling422BitDepth8,
+            ProfessionalChromaSubsampling422BitDepth10,
+            ProfessionalChromaSubsampling422BitDepth12,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAv1ProfileIdsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContainerEncodingProperties_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContainerEncodingProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContainerEncodingProperties2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContainerEncodingProperties2", "Copy", 6usize, |this| ((**(this as *const *const IContainerEncodingProperties2_Vtbl)).Copy)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Copy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContainerEncodingProperties2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IH264ProfileIdsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConstrainedBaseline(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IH264ProfileIdsStatics", "ConstrainedBaseline", 6usize, |this| ((**(this as *const *const IH264ProfileIdsStatics_Vtbl)).ConstrainedBaseline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Baseline(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IH264ProfileIdsStatics", "Baseline", 7usize, |this| ((**(this as *const *const IH264ProfileIdsStatics_Vtbl)).Baseline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Extended(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IH264ProfileIdsStatics", "Extended", 8usize, |this| ((**(this as *const *const IH264ProfileIdsStatics_Vtbl)).Extended)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Main(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IH264ProfileIdsStatics", "Main", 9usize, |this| ((**(this as *const *const IH264ProfileIdsStatics_Vtbl)).Main)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn High(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IH264ProfileIdsStatics", "High", 10usize, |this| ((**(this as *const *const IH264ProfileIdsStatics_Vtbl)).High)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn High10(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IH264ProfileIdsStatics", "High10", 11usize, |this| ((**(this as *const *const IH264ProfileIdsStatics_Vtbl)).High10)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn High422(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IH264ProfileIdsStatics", "High422", 12usize, |this| ((**(this as *const *const IH264ProfileIdsStatics_Vtbl)).High422)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn High444(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IH264ProfileIdsStatics", "High444", 13usize, |this| ((**(this as *const *const IH264ProfileIdsStatics_Vtbl)).High444)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StereoHigh(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IH264ProfileIdsStatics", "StereoHigh", 14usize, |this| ((**(this as *const *const IH264ProfileIdsStatics_Vtbl)).StereoHigh)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MultiviewHigh(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IH264ProfileIdsStatics", "MultiviewHigh", 15usize, |this| ((**(this as *const *const IH264ProfileIdsStatics_Vtbl)).MultiviewHigh)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConstrainedBaseline,
+            Baseline,
+            Extended,
+            Main,
+            High,
+            High10,
+            High422,
+            High444,
+            StereoHigh,
+            MultiviewHigh,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IH264ProfileIdsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHevcProfileIdsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MainChromaSubsampling420BitDepth8(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainChromaSubsampling420BitDepth8", 6usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainChromaSubsampling420BitDepth8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainChromaSubsampling420BitDepth10(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainChromaSubsampling420BitDepth10", 7usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainChromaSubsampling420BitDepth10)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainChromaSubsampling420BitDepth12(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainChromaSubsampling420BitDepth12", 8usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainChromaSubsampling420BitDepth12)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainChromaSubsampling422BitDepth10(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainChromaSubsampling422BitDepth10", 9usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainChromaSubsampling422BitDepth10)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainChromaSubsampling422BitDepth12(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainChromaSubsampling422BitDepth12", 10usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainChromaSubsampling422BitDepth12)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainChromaSubsampling444BitDepth8(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainChromaSubsampling444BitDepth8", 11usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainChromaSubsampling444BitDepth8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainChromaSubsampling444BitDepth10(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainChromaSubsampling444BitDepth10", 12usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainChromaSubsampling444BitDepth10)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainChromaSubsampling444BitDepth12(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainChromaSubsampling444BitDepth12", 13usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainChromaSubsampling444BitDepth12)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonochromeBitDepth12(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MonochromeBitDepth12", 14usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MonochromeBitDepth12)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonochromeBitDepth16(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MonochromeBitDepth16", 15usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MonochromeBitDepth16)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainIntraChromaSubsampling420BitDepth8(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainIntraChromaSubsampling420BitDepth8", 16usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainIntraChromaSubsampling420BitDepth8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainIntraChromaSubsampling420BitDepth10(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainIntraChromaSubsampling420BitDepth10", 17usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainIntraChromaSubsampling420BitDepth10)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainIntraChromaSubsampling420BitDepth12(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainIntraChromaSubsampling420BitDepth12", 18usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainIntraChromaSubsampling420BitDepth12)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainIntraChromaSubsampling422BitDepth10(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainIntraChromaSubsampling422BitDepth10", 19usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainIntraChromaSubsampling422BitDepth10)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainIntraChromaSubsampling422BitDepth12(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainIntraChromaSubsampling422BitDepth12", 20usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainIntraChromaSubsampling422BitDepth12)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainIntraChromaSubsampling444BitDepth8(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainIntraChromaSubsampling444BitDepth8", 21usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainIntraChromaSubsampling444BitDepth8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainIntraChromaSubsampling444BitDepth10(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainIntraChromaSubsampling444BitDepth10", 22usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainIntraChromaSubsampling444BitDepth10)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainIntraChromaSubsampling444BitDepth12(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainIntraChromaSubsampling444BitDepth12", 23usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainIntraChromaSubsampling444BitDepth12)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainIntraChromaSubsampling444BitDepth16(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainIntraChromaSubsampling444BitDepth16", 24usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainIntraChromaSubsampling444BitDepth16)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainStillChromaSubsampling420BitDepth8(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainStillChromaSubsampling420BitDepth8", 25usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainStillChromaSubsampling420BitDepth8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainStillChromaSubsampling444BitDepth8(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainStillChromaSubsampling444BitDepth8", 26usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainStillChromaSubsampling444BitDepth8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MainStillChromaSubsampling444BitDepth16(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHevcProfileIdsStatics", "MainStillChromaSubsampling444BitDepth16", 27usize, |this| ((**(this as *const *const IHevcProfileIdsStatics_Vtbl)).MainStillChromaSubsampling444BitDepth16)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MainChromaSubsampling420BitDepth8,
+            MainChromaSubsampling420BitDepth10,
+            MainChromaSubsampling420BitDepth12,
+            MainChromaSubsampling422BitDepth10,
+            MainChromaSubsampling422BitDepth12,
+            MainChromaSubsampling444BitDepth8,
+            MainChromaSubsampling444BitDepth10,
+            MainChromaSubsampling444BitDepth12,
+            MonochromeBitDepth12,
+            MonochromeBitDepth16,
+            MainIntraChromaSubsampling420BitDepth8,
+            MainIntraChromaSubsampling420BitDepth10,
+            MainIntraChromaSubsampling420BitDepth12,
+            MainIntraChromaSubsampling422BitDepth10,
+            MainIntraChromaSubsampling422BitDepth12,
+            MainIntraChromaSubsampling444BitDepth8,
+            MainIntraChromaSubsampling444BitDepth10,
+            MainIntraChromaSubsampling444BitDepth12,
+            MainIntraChromaSubsampling444BitDepth16,
+            MainStillChromaSubsampling420BitDepth8,
+            MainStillChromaSubsampling444BitDepth8,
+            MainStillChromaSubsampling444BitDepth16,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHevcProfileIdsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IImageEncodingProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWidth(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageEncodingProperties", "SetWidth", 6usize, |this| ((**(this as *const *const IImageEncodingProperties_Vtbl)).SetWidth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageEncodingProperties", "Width", 7usize, |this| ((**(this as *const *const IImageEncodingProperties_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHeight(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageEncodingProperties", "SetHeight", 8usize, |this| ((**(this as *const *const IImageEncodingProperties_Vtbl)).SetHeight)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageEncodingProperties", "Height", 9usize, |this| ((**(this as *const *const IImageEncodingProperties_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWidth, Width, SetHeight, Height }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageEncodingProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImageEncodingProperties2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageEncodingProperties2", "Copy", 6usize, |this| ((**(this as *const *const IImageEncodingProperties2_Vtbl)).Copy)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Copy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageEncodingProperties2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImageEncodingPropertiesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateJpeg(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageEncodingPropertiesStatics", "CreateJpeg", 6usize, |this| ((**(this as *const *const IImageEncodingPropertiesStatics_Vtbl)).CreateJpeg)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePng(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageEncodingPropertiesStatics", "CreatePng", 7usize, |this| ((**(this as *const *const IImageEncodingPropertiesStatics_Vtbl)).CreatePng)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateJpegXR(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageEncodingPropertiesStatics", "CreateJpegXR", 8usize, |this| ((**(this as *const *const IImageEncodingPropertiesStatics_Vtbl)).CreateJpegXR)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateJpeg, CreatePng, CreateJpegXR }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageEncodingPropertiesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImageEncodingPropertiesStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateUncompressed(this: *mut core::ffi::c_void, format: MediaPixelFormat, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageEncodingPropertiesStatics2", "CreateUncompressed", 6usize, |this| ((**(this as *const *const IImageEncodingPropertiesStatics2_Vtbl)).CreateUncompressed)(this, format, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBmp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageEncodingPropertiesStatics2", "CreateBmp", 7usize, |this| ((**(this as *const *const IImageEncodingPropertiesStatics2_Vtbl)).CreateBmp)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateUncompressed, CreateBmp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageEncodingPropertiesStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImageEncodingPropertiesStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateHeif(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageEncodingPropertiesStatics3", "CreateHeif", 6usize, |this| ((**(this as *const *const IImageEncodingPropertiesStatics3_Vtbl)).CreateHeif)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateHeif }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageEncodingPropertiesStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaEncodingProfile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAudio(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfile", "SetAudio", 6usize, |this| ((**(this as *const *const IMediaEncodingProfile_Vtbl)).SetAudio)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Audio(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfile", "Audio", 7usize, |this| ((**(this as *const *const IMediaEncodingProfile_Vtbl)).Audio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfile", "SetVideo", 8usize, |this| ((**(this as *const *const IMediaEncodingProfile_Vtbl)).SetVideo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Video(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfile", "Video", 9usize, |this| ((**(this as *const *const IMediaEncodingProfile_Vtbl)).Video)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContainer(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfile", "SetContainer", 10usize, |this| ((**(this as *const *const IMediaEncodingProfile_Vtbl)).SetContainer)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Container(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfile", "Container", 11usize, |this| ((**(this as *const *const IMediaEncodingProfile_Vtbl)).Container)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAudio, Audio, SetVideo, Video, SetContainer, Container }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEncodingProfile as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+impl windows_core::imp::ProxyVtable for IMediaEncodingProfile2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+        unsafe extern "system" fn SetAudioTracks(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfile2", "SetAudioTracks", 6usize, |this| ((**(this as *const *const IMediaEncodingProfile2_Vtbl)).SetAudioTracks)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+        unsafe extern "system" fn GetAudioTracks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfile2", "GetAudioTracks", 7usize, |this| ((**(this as *const *const IMediaEncodingProfile2_Vtbl)).GetAudioTracks)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+        unsafe extern "system" fn SetVideoTracks(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfile2", "SetVideoTracks", 8usize, |this| ((**(this as *const *const IMediaEncodingProfile2_Vtbl)).SetVideoTracks)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+        unsafe extern "system" fn GetVideoTracks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfile2", "GetVideoTracks", 9usize, |this| ((**(this as *const *const IMediaEncodingProfile2_Vtbl)).GetVideoTracks)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+            SetAudioTracks,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Core")))]
+            SetAudioTracks: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+            GetAudioTracks,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Core")))]
+            GetAudioTracks: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+            SetVideoTracks,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Core")))]
+            SetVideoTracks: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+            GetVideoTracks,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Core")))]
+            GetVideoTracks: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEncodingProfile2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+impl windows_core::imp::ProxyVtable for IMediaEncodingProfile3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+        unsafe extern "system" fn SetTimedMetadataTracks(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfile3", "SetTimedMetadataTracks", 6usize, |this| ((**(this as *const *const IMediaEncodingProfile3_Vtbl)).SetTimedMetadataTracks)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+        unsafe extern "system" fn GetTimedMetadataTracks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfile3", "GetTimedMetadataTracks", 7usize, |this| ((**(this as *const *const IMediaEncodingProfile3_Vtbl)).GetTimedMetadataTracks)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+            SetTimedMetadataTracks,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Core")))]
+            SetTimedMetadataTracks: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+            GetTimedMetadataTracks,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Core")))]
+            GetTimedMetadataTracks: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEncodingProfile3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMediaEncodingProfileStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateM4a(this: *mut core::ffi::c_void, quality: AudioEncodingQuality, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfileStatics", "CreateM4a", 6usize, |this| ((**(this as *const *const IMediaEncodingProfileStatics_Vtbl)).CreateM4a)(this, quality, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMp3(this: *mut core::ffi::c_void, quality: AudioEncodingQuality, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfileStatics", "CreateMp3", 7usize, |this| ((**(this as *const *const IMediaEncodingProfileStatics_Vtbl)).CreateMp3)(this, quality, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWma(this: *mut core::ffi::c_void, quality: AudioEncodingQuality, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfileStatics", "CreateWma", 8usize, |this| ((**(this as *const *const IMediaEncodingProfileStatics_Vtbl)).CreateWma)(this, quality, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMp4(this: *mut core::ffi::c_void, quality: VideoEncodingQuality, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfileStatics", "CreateMp4", 9usize, |this| ((**(this as *const *const IMediaEncodingProfileStatics_Vtbl)).CreateMp4)(this, quality, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWmv(this: *mut core::ffi::c_void, quality: VideoEncodingQuality, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfileStatics", "CreateWmv", 10usize, |this| ((**(this as *const *const IMediaEncodingProfileStatics_Vtbl)).CreateWmv)(this, quality, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CreateFromFileAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfileStatics", "CreateFromFileAsync", 11usize, |this| ((**(this as *const *const IMediaEncodingProfileStatics_Vtbl)).CreateFromFileAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromStreamAsync(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfileStatics", "CreateFromStreamAsync", 12usize, |this| ((**(this as *const *const IMediaEncodingProfileStatics_Vtbl)).CreateFromStreamAsync)(this, stream, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateM4a,
+            CreateMp3,
+            CreateWma,
+            CreateMp4,
+            CreateWmv,
+            #[cfg(feature = "Storage")]
+            CreateFromFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            CreateFromFileAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromStreamAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEncodingProfileStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaEncodingProfileStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWav(this: *mut core::ffi::c_void, quality: AudioEncodingQuality, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfileStatics2", "CreateWav", 6usize, |this| ((**(this as *const *const IMediaEncodingProfileStatics2_Vtbl)).CreateWav)(this, quality, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAvi(this: *mut core::ffi::c_void, quality: VideoEncodingQuality, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfileStatics2", "CreateAvi", 7usize, |this| ((**(this as *const *const IMediaEncodingProfileStatics2_Vtbl)).CreateAvi)(this, quality, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWav, CreateAvi }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEncodingProfileStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaEncodingProfileStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAlac(this: *mut core::ffi::c_void, quality: AudioEncodingQuality, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfileStatics3", "CreateAlac", 6usize, |this| ((**(this as *const *const IMediaEncodingProfileStatics3_Vtbl)).CreateAlac)(this, quality, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFlac(this: *mut core::ffi::c_void, quality: AudioEncodingQuality, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfileStatics3", "CreateFlac", 7usize, |this| ((**(this as *const *const IMediaEncodingProfileStatics3_Vtbl)).CreateFlac)(this, quality, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateHevc(this: *mut core::ffi::c_void, quality: VideoEncodingQuality, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfileStatics3", "CreateHevc", 8usize, |this| ((**(this as *const *const IMediaEncodingProfileStatics3_Vtbl)).CreateHevc)(this, quality, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateAlac, CreateFlac, CreateHevc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEncodingProfileStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaEncodingProfileStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateVp9(this: *mut core::ffi::c_void, quality: VideoEncodingQuality, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfileStatics4", "CreateVp9", 6usize, |this| ((**(this as *const *const IMediaEncodingProfileStatics4_Vtbl)).CreateVp9)(this, quality, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAv1(this: *mut core::ffi::c_void, quality: VideoEncodingQuality, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProfileStatics4", "CreateAv1", 7usize, |this| ((**(this as *const *const IMediaEncodingProfileStatics4_Vtbl)).CreateAv1)(this, quality, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateVp9, CreateAv1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEncodingProfileStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaEncodingProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProperties", "Properties", 6usize, |this| ((**(this as *const *const IMediaEncodingProperties_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProperties", "Type", 7usize, |this| ((**(this as *const *const IMediaEncodingProperties_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubtype(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProperties", "SetSubtype", 8usize, |this| ((**(this as *const *const IMediaEncodingProperties_Vtbl)).SetSubtype)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subtype(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingProperties", "Subtype", 9usize, |this| ((**(this as *const *const IMediaEncodingProperties_Vtbl)).Subtype)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            Type,
+            SetSubtype,
+            Subtype,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEncodingProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaEncodingSubtypesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Aac(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Aac", 6usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Aac)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AacAdts(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "AacAdts", 7usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).AacAdts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ac3(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Ac3", 8usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Ac3)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AmrNb(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "AmrNb", 9usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).AmrNb)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AmrWb(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "AmrWb", 10usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).AmrWb)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Argb32(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Argb32", 11usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Argb32)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Asf(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Asf", 12usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Asf)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Avi(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Avi", 13usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Avi)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bgra8(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Bgra8", 14usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Bgra8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bmp(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Bmp", 15usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Bmp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Eac3(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Eac3", 16usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Eac3)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Float(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Float", 17usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Float)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gif(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Gif", 18usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Gif)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn H263(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "H263", 19usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).H263)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn H264(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "H264", 20usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).H264)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn H264Es(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "H264Es", 21usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).H264Es)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hevc(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Hevc", 22usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Hevc)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HevcEs(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "HevcEs", 23usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).HevcEs)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Iyuv(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Iyuv", 24usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Iyuv)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Jpeg(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Jpeg", 25usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Jpeg)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JpegXr(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "JpegXr", 26usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).JpegXr)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mjpg(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Mjpg", 27usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Mjpg)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mpeg(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Mpeg", 28usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Mpeg)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mpeg1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Mpeg1", 29usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Mpeg1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mpeg2(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Mpeg2", 30usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Mpeg2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mp3(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Mp3", 31usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Mp3)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mpeg4(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Mpeg4", 32usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Mpeg4)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Nv12(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Nv12", 33usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Nv12)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pcm(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Pcm", 34usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Pcm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Png(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Png", 35usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Png)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rgb24(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Rgb24", 36usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Rgb24)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rgb32(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Rgb32", 37usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Rgb32)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tiff(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Tiff", 38usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Tiff)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wave(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Wave", 39usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Wave)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wma8(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Wma8", 40usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Wma8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wma9(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Wma9", 41usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Wma9)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wmv3(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Wmv3", 42usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Wmv3)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wvc1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Wvc1", 43usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Wvc1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Yuy2(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Yuy2", 44usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Yuy2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Yv12(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics", "Yv12", 45usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics_Vtbl)).Yv12)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Aac,
+            AacAdts,
+            Ac3,
+            AmrNb,
+            AmrWb,
+            Argb32,
+            Asf,
+            Avi,
+            Bgra8,
+            Bmp,
+            Eac3,
+            Float,
+            Gif,
+            H263,
+            H264,
+            H264Es,
+            Hevc,
+            HevcEs,
+            Iyuv,
+            Jpeg,
+            JpegXr,
+            Mjpg,
+            Mpeg,
+            Mpeg1,
+            Mpeg2,
+            Mp3,
+            Mpeg4,
+            Nv12,
+            Pcm,
+            Png,
+            Rgb24,
+            Rgb32,
+            Tiff,
+            Wave,
+            Wma8,
+            Wma9,
+            Wmv3,
+            Wvc1,
+            Yuy2,
+            Yv12,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEncodingSubtypesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaEncodingSubtypesStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Vp9(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics2", "Vp9", 6usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics2_Vtbl)).Vp9)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn L8(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics2", "L8", 7usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics2_Vtbl)).L8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn L16(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics2", "L16", 8usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics2_Vtbl)).L16)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn D16(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics2", "D16", 9usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics2_Vtbl)).D16)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Vp9, L8, L16, D16 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEncodingSubtypesStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaEncodingSubtypesStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Alac(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics3", "Alac", 6usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics3_Vtbl)).Alac)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flac(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics3", "Flac", 7usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics3_Vtbl)).Flac)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Alac, Flac }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEncodingSubtypesStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaEncodingSubtypesStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn P010(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics4", "P010", 6usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics4_Vtbl)).P010)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, P010 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEncodingSubtypesStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaEncodingSubtypesStatics5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Heif(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics5", "Heif", 6usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics5_Vtbl)).Heif)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Heif }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEncodingSubtypesStatics5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaEncodingSubtypesStatics6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Pgs(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics6", "Pgs", 6usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics6_Vtbl)).Pgs)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Srt(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics6", "Srt", 7usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics6_Vtbl)).Srt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ssa(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics6", "Ssa", 8usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics6_Vtbl)).Ssa)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VobSub(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics6", "VobSub", 9usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics6_Vtbl)).VobSub)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Pgs, Srt, Ssa, VobSub }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEncodingSubtypesStatics6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaEncodingSubtypesStatics7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Av1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEncodingSubtypesStatics7", "Av1", 6usize, |this| ((**(this as *const *const IMediaEncodingSubtypesStatics7_Vtbl)).Av1)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Av1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEncodingSubtypesStatics7 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaRatio_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetNumerator(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaRatio", "SetNumerator", 6usize, |this| ((**(this as *const *const IMediaRatio_Vtbl)).SetNumerator)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Numerator(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaRatio", "Numerator", 7usize, |this| ((**(this as *const *const IMediaRatio_Vtbl)).Numerator)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDenominator(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaRatio", "SetDenominator", 8usize, |this| ((**(this as *const *const IMediaRatio_Vtbl)).SetDenominator)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Denominator(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaRatio", "Denominator", 9usize, |this| ((**(this as *const *const IMediaRatio_Vtbl)).Denominator)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetNumerator, Numerator, SetDenominator, Denominator }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaRatio as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMpeg2ProfileIdsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Simple(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2ProfileIdsStatics", "Simple", 6usize, |this| ((**(this as *const *const IMpeg2ProfileIdsStatics_Vtbl)).Simple)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Main(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2ProfileIdsStatics", "Main", 7usize, |this| ((**(this as *const *const IMpeg2ProfileIdsStatics_Vtbl)).Main)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalNoiseRatioScalable(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2ProfileIdsStatics", "SignalNoiseRatioScalable", 8usize, |this| ((**(this as *const *const IMpeg2ProfileIdsStatics_Vtbl)).SignalNoiseRatioScalable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpatiallyScalable(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2ProfileIdsStatics", "SpatiallyScalable", 9usize, |this| ((**(this as *const *const IMpeg2ProfileIdsStatics_Vtbl)).SpatiallyScalable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn High(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2ProfileIdsStatics", "High", 10usize, |this| ((**(this as *const *const IMpeg2ProfileIdsStatics_Vtbl)).High)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Simple,
+            Main,
+            SignalNoiseRatioScalable,
+            SpatiallyScalable,
+            High,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMpeg2ProfileIdsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimedMetadataEncodingProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFormatUserData(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataEncodingProperties", "SetFormatUserData", 6usize, |this| ((**(this as *const *const ITimedMetadataEncodingProperties_Vtbl)).SetFormatUserData)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormatUserData(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataEncodingProperties", "GetFormatUserData", 7usize, |this| ((**(this as *const *const ITimedMetadataEncodingProperties_Vtbl)).GetFormatUserData)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataEncodingProperties", "Copy", 8usize, |this| ((**(this as *const *const ITimedMetadataEncodingProperties_Vtbl)).Copy)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetFormatUserData, GetFormatUserData, Copy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedMetadataEncodingProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimedMetadataEncodingPropertiesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreatePgs(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataEncodingPropertiesStatics", "CreatePgs", 6usize, |this| ((**(this as *const *const ITimedMetadataEncodingPropertiesStatics_Vtbl)).CreatePgs)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSrt(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataEncodingPropertiesStatics", "CreateSrt", 7usize, |this| ((**(this as *const *const ITimedMetadataEncodingPropertiesStatics_Vtbl)).CreateSrt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSsa(this: *mut core::ffi::c_void, formatUserData_array_size: u32, formatuserdata: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataEncodingPropertiesStatics", "CreateSsa", 8usize, |this| ((**(this as *const *const ITimedMetadataEncodingPropertiesStatics_Vtbl)).CreateSsa)(this, formatUserData_array_size, formatuserdata, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVobSub(this: *mut core::ffi::c_void, formatUserData_array_size: u32, formatuserdata: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataEncodingPropertiesStatics", "CreateVobSub", 9usize, |this| ((**(this as *const *const ITimedMetadataEncodingPropertiesStatics_Vtbl)).CreateVobSub)(this, formatUserData_array_size, formatuserdata, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreatePgs, CreateSrt, CreateSsa, CreateVobSub }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedMetadataEncodingPropertiesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVideoEncodingProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBitrate(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingProperties", "SetBitrate", 6usize, |this| ((**(this as *const *const IVideoEncodingProperties_Vtbl)).SetBitrate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bitrate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingProperties", "Bitrate", 7usize, |this| ((**(this as *const *const IVideoEncodingProperties_Vtbl)).Bitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWidth(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingProperties", "SetWidth", 8usize, |this| ((**(this as *const *const IVideoEncodingProperties_Vtbl)).SetWidth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingProperties", "Width", 9usize, |this| ((**(this as *const *const IVideoEncodingProperties_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHeight(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingProperties", "SetHeight", 10usize, |this| ((**(this as *const *const IVideoEncodingProperties_Vtbl)).SetHeight)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingProperties", "Height", 11usize, |this| ((**(this as *const *const IVideoEncodingProperties_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameRate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingProperties", "FrameRate", 12usize, |this| ((**(this as *const *const IVideoEncodingProperties_Vtbl)).FrameRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PixelAspectRatio(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingProperties", "PixelAspectRatio", 13usize, |this| ((**(this as *const *const IVideoEncodingProperties_Vtbl)).PixelAspectRatio)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetBitrate,
+            Bitrate,
+            SetWidth,
+            Width,
+            SetHeight,
+            Height,
+            FrameRate,
+            PixelAspectRatio,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoEncodingProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoEncodingProperties2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFormatUserData(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingProperties2", "SetFormatUserData", 6usize, |this| ((**(this as *const *const IVideoEncodingProperties2_Vtbl)).SetFormatUserData)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormatUserData(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingProperties2", "GetFormatUserData", 7usize, |this| ((**(this as *const *const IVideoEncodingProperties2_Vtbl)).GetFormatUserData)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProfileId(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingProperties2", "SetProfileId", 8usize, |this| ((**(this as *const *const IVideoEncodingProperties2_Vtbl)).SetProfileId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProfileId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingProperties2", "ProfileId", 9usize, |this| ((**(this as *const *const IVideoEncodingProperties2_Vtbl)).ProfileId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetFormatUserData,
+            GetFormatUserData,
+            SetProfileId,
+            ProfileId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoEncodingProperties2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoEncodingProperties3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StereoscopicVideoPackingMode(this: *mut core::ffi::c_void, result__: *mut StereoscopicVideoPackingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingProperties3", "StereoscopicVideoPackingMode", 6usize, |this| ((**(this as *const *const IVideoEncodingProperties3_Vtbl)).StereoscopicVideoPackingMode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StereoscopicVideoPackingMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoEncodingProperties3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoEncodingProperties4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SphericalVideoFrameFormat(this: *mut core::ffi::c_void, result__: *mut SphericalVideoFrameFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingProperties4", "SphericalVideoFrameFormat", 6usize, |this| ((**(this as *const *const IVideoEncodingProperties4_Vtbl)).SphericalVideoFrameFormat)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SphericalVideoFrameFormat }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoEncodingProperties4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoEncodingProperties5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingProperties5", "Copy", 6usize, |this| ((**(this as *const *const IVideoEncodingProperties5_Vtbl)).Copy)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Copy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoEncodingProperties5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoEncodingPropertiesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateH264(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingPropertiesStatics", "CreateH264", 6usize, |this| ((**(this as *const *const IVideoEncodingPropertiesStatics_Vtbl)).CreateH264)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMpeg2(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingPropertiesStatics", "CreateMpeg2", 7usize, |this| ((**(this as *const *const IVideoEncodingPropertiesStatics_Vtbl)).CreateMpeg2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUncompressed(this: *mut core::ffi::c_void, subtype: core::mem::MaybeUninit<windows_core::HSTRING>, width: u32, height: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingPropertiesStatics", "CreateUncompressed", 8usize, |this| ((**(this as *const *const IVideoEncodingPropertiesStatics_Vtbl)).CreateUncompressed)(this, subtype, width, height, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateH264, CreateMpeg2, CreateUncompressed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoEncodingPropertiesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoEncodingPropertiesStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateHevc(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingPropertiesStatics2", "CreateHevc", 6usize, |this| ((**(this as *const *const IVideoEncodingPropertiesStatics2_Vtbl)).CreateHevc)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateHevc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoEncodingPropertiesStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoEncodingPropertiesStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateVp9(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingPropertiesStatics3", "CreateVp9", 6usize, |this| ((**(this as *const *const IVideoEncodingPropertiesStatics3_Vtbl)).CreateVp9)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAv1(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEncodingPropertiesStatics3", "CreateAv1", 7usize, |this| ((**(this as *const *const IVideoEncodingPropertiesStatics3_Vtbl)).CreateAv1)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateVp9, CreateAv1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoEncodingPropertiesStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVp9ProfileIdsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Profile0ChromaSubsampling420BitDepth8(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVp9ProfileIdsStatics", "Profile0ChromaSubsampling420BitDepth8", 6usize, |this| ((**(this as *const *const IVp9ProfileIdsStatics_Vtbl)).Profile0ChromaSubsampling420BitDepth8)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Profile2ChromaSubsampling420BitDepth10(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVp9ProfileIdsStatics", "Profile2ChromaSubsampling420BitDepth10", 7usize, |this| ((**(this as *const *const IVp9ProfileIdsStatics_Vtbl)).Profile2ChromaSubsampling420BitDepth10)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Profile2ChromaSubsampling420BitDepth12(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVp9ProfileIdsStatics", "Profile2ChromaSubsampling420BitDepth12", 8usize, |this| ((**(this as *const *const IVp9ProfileIdsStatics_Vtbl)).Profile2ChromaSubsampling420BitDepth12)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Profile0ChromaSubsampling420BitDepth8,
+            Profile2ChromaSubsampling420BitDepth10,
+            Profile2ChromaSubsampling420BitDepth12,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVp9ProfileIdsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1352,6 +1352,7 @@ impl MiracastReceiverApplySettingsStatus {
 impl windows_core::TypeKind for MiracastReceiverApplySettingsStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MiracastReceiverApplySettingsStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MiracastReceiverApplySettingsStatus").field(&self.0).finish()
@@ -1372,6 +1373,7 @@ impl MiracastReceiverAuthorizationMethod {
 impl windows_core::TypeKind for MiracastReceiverAuthorizationMethod {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MiracastReceiverAuthorizationMethod {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MiracastReceiverAuthorizationMethod").field(&self.0).finish()
@@ -1396,6 +1398,7 @@ impl MiracastReceiverDisconnectReason {
 impl windows_core::TypeKind for MiracastReceiverDisconnectReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MiracastReceiverDisconnectReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MiracastReceiverDisconnectReason").field(&self.0).finish()
@@ -1414,6 +1417,7 @@ impl MiracastReceiverGameControllerDeviceUsageMode {
 impl windows_core::TypeKind for MiracastReceiverGameControllerDeviceUsageMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MiracastReceiverGameControllerDeviceUsageMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MiracastReceiverGameControllerDeviceUsageMode").field(&self.0).finish()
@@ -1436,6 +1440,7 @@ impl MiracastReceiverListeningStatus {
 impl windows_core::TypeKind for MiracastReceiverListeningStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MiracastReceiverListeningStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MiracastReceiverListeningStatus").field(&self.0).finish()
@@ -1456,6 +1461,7 @@ impl MiracastReceiverSessionStartStatus {
 impl windows_core::TypeKind for MiracastReceiverSessionStartStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MiracastReceiverSessionStartStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MiracastReceiverSessionStartStatus").field(&self.0).finish()
@@ -1476,6 +1482,7 @@ impl MiracastReceiverWiFiStatus {
 impl windows_core::TypeKind for MiracastReceiverWiFiStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MiracastReceiverWiFiStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MiracastReceiverWiFiStatus").field(&self.0).finish()
@@ -1496,6 +1503,7 @@ impl MiracastTransmitterAuthorizationStatus {
 impl windows_core::TypeKind for MiracastTransmitterAuthorizationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MiracastTransmitterAuthorizationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MiracastTransmitterAuthorizationStatus").field(&self.0).finish()
@@ -1504,3 +1512,5 @@ impl core::fmt::Debug for MiracastTransmitterAuthorizationStatus {
 impl windows_core::RuntimeType for MiracastTransmitterAuthorizationStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.Miracast.MiracastTransmitterAuthorizationStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,661 @@
+#[cfg(feature = "ApplicationModel_Core")]
+impl windows_core::imp::ProxyVtable for IMiracastReceiver_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefaultSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiver", "GetDefaultSettings", 6usize, |this| ((**(this as *const *const IMiracastReceiver_Vtbl)).GetDefaultSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiver", "GetCurrentSettings", 7usize, |this| ((**(this as *const *const IMiracastReceiver_Vtbl)).GetCurrentSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentSettingsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiver", "GetCurrentSettingsAsync", 8usize, |this| ((**(this as *const *const IMiracastReceiver_Vtbl)).GetCurrentSettingsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectAllAndApplySettings(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiver", "DisconnectAllAndApplySettings", 9usize, |this| ((**(this as *const *const IMiracastReceiver_Vtbl)).DisconnectAllAndApplySettings)(this, settings, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectAllAndApplySettingsAsync(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiver", "DisconnectAllAndApplySettingsAsync", 10usize, |this| ((**(this as *const *const IMiracastReceiver_Vtbl)).DisconnectAllAndApplySettingsAsync)(this, settings, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiver", "GetStatus", 11usize, |this| ((**(this as *const *const IMiracastReceiver_Vtbl)).GetStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatusAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiver", "GetStatusAsync", 12usize, |this| ((**(this as *const *const IMiracastReceiver_Vtbl)).GetStatusAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiver", "StatusChanged", 13usize, |this| ((**(this as *const *const IMiracastReceiver_Vtbl)).StatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiver", "RemoveStatusChanged", 14usize, |this| ((**(this as *const *const IMiracastReceiver_Vtbl)).RemoveStatusChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Core")]
+        unsafe extern "system" fn CreateSession(this: *mut core::ffi::c_void, view: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiver", "CreateSession", 15usize, |this| ((**(this as *const *const IMiracastReceiver_Vtbl)).CreateSession)(this, view, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Core")]
+        unsafe extern "system" fn CreateSessionAsync(this: *mut core::ffi::c_void, view: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiver", "CreateSessionAsync", 16usize, |this| ((**(this as *const *const IMiracastReceiver_Vtbl)).CreateSessionAsync)(this, view, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearKnownTransmitters(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiver", "ClearKnownTransmitters", 17usize, |this| ((**(this as *const *const IMiracastReceiver_Vtbl)).ClearKnownTransmitters)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveKnownTransmitter(this: *mut core::ffi::c_void, transmitter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiver", "RemoveKnownTransmitter", 18usize, |this| ((**(this as *const *const IMiracastReceiver_Vtbl)).RemoveKnownTransmitter)(this, transmitter), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDefaultSettings,
+            GetCurrentSettings,
+            GetCurrentSettingsAsync,
+            DisconnectAllAndApplySettings,
+            DisconnectAllAndApplySettingsAsync,
+            GetStatus,
+            GetStatusAsync,
+            StatusChanged,
+            RemoveStatusChanged,
+            #[cfg(feature = "ApplicationModel_Core")]
+            CreateSession,
+            #[cfg(not(feature = "ApplicationModel_Core"))]
+            CreateSession: 0,
+            #[cfg(feature = "ApplicationModel_Core")]
+            CreateSessionAsync,
+            #[cfg(not(feature = "ApplicationModel_Core"))]
+            CreateSessionAsync: 0,
+            ClearKnownTransmitters,
+            RemoveKnownTransmitter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiver as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMiracastReceiverApplySettingsResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut MiracastReceiverApplySettingsStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverApplySettingsResult", "Status", 6usize, |this| ((**(this as *const *const IMiracastReceiverApplySettingsResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverApplySettingsResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IMiracastReceiverApplySettingsResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverApplySettingsResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMiracastReceiverConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void, reason: MiracastReceiverDisconnectReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverConnection", "Disconnect", 6usize, |this| ((**(this as *const *const IMiracastReceiverConnection_Vtbl)).Disconnect)(this, reason), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectWithMessage(this: *mut core::ffi::c_void, reason: MiracastReceiverDisconnectReason, message: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverConnection", "DisconnectWithMessage", 7usize, |this| ((**(this as *const *const IMiracastReceiverConnection_Vtbl)).DisconnectWithMessage)(this, reason, message), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverConnection", "Pause", 8usize, |this| ((**(this as *const *const IMiracastReceiverConnection_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PauseAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverConnection", "PauseAsync", 9usize, |this| ((**(this as *const *const IMiracastReceiverConnection_Vtbl)).PauseAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverConnection", "Resume", 10usize, |this| ((**(this as *const *const IMiracastReceiverConnection_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverConnection", "ResumeAsync", 11usize, |this| ((**(this as *const *const IMiracastReceiverConnection_Vtbl)).ResumeAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Transmitter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverConnection", "Transmitter", 12usize, |this| ((**(this as *const *const IMiracastReceiverConnection_Vtbl)).Transmitter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputDevices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverConnection", "InputDevices", 13usize, |this| ((**(this as *const *const IMiracastReceiverConnection_Vtbl)).InputDevices)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CursorImageChannel(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverConnection", "CursorImageChannel", 14usize, |this| ((**(this as *const *const IMiracastReceiverConnection_Vtbl)).CursorImageChannel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StreamControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverConnection", "StreamControl", 15usize, |this| ((**(this as *const *const IMiracastReceiverConnection_Vtbl)).StreamControl)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Disconnect,
+            DisconnectWithMessage,
+            Pause,
+            PauseAsync,
+            Resume,
+            ResumeAsync,
+            Transmitter,
+            InputDevices,
+            CursorImageChannel,
+            StreamControl,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMiracastReceiverConnectionCreatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverConnectionCreatedEventArgs", "Connection", 6usize, |this| ((**(this as *const *const IMiracastReceiverConnectionCreatedEventArgs_Vtbl)).Connection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pin(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverConnectionCreatedEventArgs", "Pin", 7usize, |this| ((**(this as *const *const IMiracastReceiverConnectionCreatedEventArgs_Vtbl)).Pin)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverConnectionCreatedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IMiracastReceiverConnectionCreatedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Connection, Pin, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverConnectionCreatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Graphics", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMiracastReceiverCursorImageChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverCursorImageChannel", "IsEnabled", 6usize, |this| ((**(this as *const *const IMiracastReceiverCursorImageChannel_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn MaxImageSize(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::SizeInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverCursorImageChannel", "MaxImageSize", 7usize, |this| ((**(this as *const *const IMiracastReceiverCursorImageChannel_Vtbl)).MaxImageSize)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::PointInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverCursorImageChannel", "Position", 8usize, |this| ((**(this as *const *const IMiracastReceiverCursorImageChannel_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ImageStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverCursorImageChannel", "ImageStream", 9usize, |this| ((**(this as *const *const IMiracastReceiverCursorImageChannel_Vtbl)).ImageStream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImageStreamChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverCursorImageChannel", "ImageStreamChanged", 10usize, |this| ((**(this as *const *const IMiracastReceiverCursorImageChannel_Vtbl)).ImageStreamChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveImageStreamChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverCursorImageChannel", "RemoveImageStreamChanged", 11usize, |this| ((**(this as *const *const IMiracastReceiverCursorImageChannel_Vtbl)).RemoveImageStreamChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverCursorImageChannel", "PositionChanged", 12usize, |this| ((**(this as *const *const IMiracastReceiverCursorImageChannel_Vtbl)).PositionChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePositionChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverCursorImageChannel", "RemovePositionChanged", 13usize, |this| ((**(this as *const *const IMiracastReceiverCursorImageChannel_Vtbl)).RemovePositionChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEnabled,
+            #[cfg(feature = "Graphics")]
+            MaxImageSize,
+            #[cfg(not(feature = "Graphics"))]
+            MaxImageSize: 0,
+            #[cfg(feature = "Graphics")]
+            Position,
+            #[cfg(not(feature = "Graphics"))]
+            Position: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ImageStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ImageStream: 0,
+            ImageStreamChanged,
+            RemoveImageStreamChanged,
+            PositionChanged,
+            RemovePositionChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverCursorImageChannel as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics")]
+impl windows_core::imp::ProxyVtable for IMiracastReceiverCursorImageChannelSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverCursorImageChannelSettings", "IsEnabled", 6usize, |this| ((**(this as *const *const IMiracastReceiverCursorImageChannelSettings_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverCursorImageChannelSettings", "SetIsEnabled", 7usize, |this| ((**(this as *const *const IMiracastReceiverCursorImageChannelSettings_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn MaxImageSize(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::SizeInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverCursorImageChannelSettings", "MaxImageSize", 8usize, |this| ((**(this as *const *const IMiracastReceiverCursorImageChannelSettings_Vtbl)).MaxImageSize)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn SetMaxImageSize(this: *mut core::ffi::c_void, value: super::super::Graphics::SizeInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverCursorImageChannelSettings", "SetMaxImageSize", 9usize, |this| ((**(this as *const *const IMiracastReceiverCursorImageChannelSettings_Vtbl)).SetMaxImageSize)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEnabled,
+            SetIsEnabled,
+            #[cfg(feature = "Graphics")]
+            MaxImageSize,
+            #[cfg(not(feature = "Graphics"))]
+            MaxImageSize: 0,
+            #[cfg(feature = "Graphics")]
+            SetMaxImageSize,
+            #[cfg(not(feature = "Graphics"))]
+            SetMaxImageSize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverCursorImageChannelSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMiracastReceiverDisconnectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverDisconnectedEventArgs", "Connection", 6usize, |this| ((**(this as *const *const IMiracastReceiverDisconnectedEventArgs_Vtbl)).Connection)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Connection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverDisconnectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMiracastReceiverGameControllerDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransmitInput(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverGameControllerDevice", "TransmitInput", 6usize, |this| ((**(this as *const *const IMiracastReceiverGameControllerDevice_Vtbl)).TransmitInput)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransmitInput(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverGameControllerDevice", "SetTransmitInput", 7usize, |this| ((**(this as *const *const IMiracastReceiverGameControllerDevice_Vtbl)).SetTransmitInput)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRequestedByTransmitter(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverGameControllerDevice", "IsRequestedByTransmitter", 8usize, |this| ((**(this as *const *const IMiracastReceiverGameControllerDevice_Vtbl)).IsRequestedByTransmitter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTransmittingInput(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverGameControllerDevice", "IsTransmittingInput", 9usize, |this| ((**(this as *const *const IMiracastReceiverGameControllerDevice_Vtbl)).IsTransmittingInput)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut MiracastReceiverGameControllerDeviceUsageMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverGameControllerDevice", "Mode", 10usize, |this| ((**(this as *const *const IMiracastReceiverGameControllerDevice_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, value: MiracastReceiverGameControllerDeviceUsageMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverGameControllerDevice", "SetMode", 11usize, |this| ((**(this as *const *const IMiracastReceiverGameControllerDevice_Vtbl)).SetMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Changed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverGameControllerDevice", "Changed", 12usize, |this| ((**(this as *const *const IMiracastReceiverGameControllerDevice_Vtbl)).Changed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverGameControllerDevice", "RemoveChanged", 13usize, |this| ((**(this as *const *const IMiracastReceiverGameControllerDevice_Vtbl)).RemoveChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TransmitInput,
+            SetTransmitInput,
+            IsRequestedByTransmitter,
+            IsTransmittingInput,
+            Mode,
+            SetMode,
+            Changed,
+            RemoveChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverGameControllerDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMiracastReceiverInputDevices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Keyboard(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverInputDevices", "Keyboard", 6usize, |this| ((**(this as *const *const IMiracastReceiverInputDevices_Vtbl)).Keyboard)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GameController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverInputDevices", "GameController", 7usize, |this| ((**(this as *const *const IMiracastReceiverInputDevices_Vtbl)).GameController)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Keyboard, GameController }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverInputDevices as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMiracastReceiverKeyboardDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransmitInput(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverKeyboardDevice", "TransmitInput", 6usize, |this| ((**(this as *const *const IMiracastReceiverKeyboardDevice_Vtbl)).TransmitInput)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransmitInput(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverKeyboardDevice", "SetTransmitInput", 7usize, |this| ((**(this as *const *const IMiracastReceiverKeyboardDevice_Vtbl)).SetTransmitInput)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRequestedByTransmitter(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverKeyboardDevice", "IsRequestedByTransmitter", 8usize, |this| ((**(this as *const *const IMiracastReceiverKeyboardDevice_Vtbl)).IsRequestedByTransmitter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTransmittingInput(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverKeyboardDevice", "IsTransmittingInput", 9usize, |this| ((**(this as *const *const IMiracastReceiverKeyboardDevice_Vtbl)).IsTransmittingInput)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Changed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverKeyboardDevice", "Changed", 10usize, |this| ((**(this as *const *const IMiracastReceiverKeyboardDevice_Vtbl)).Changed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverKeyboardDevice", "RemoveChanged", 11usize, |this| ((**(this as *const *const IMiracastReceiverKeyboardDevice_Vtbl)).RemoveChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TransmitInput,
+            SetTransmitInput,
+            IsRequestedByTransmitter,
+            IsTransmittingInput,
+            Changed,
+            RemoveChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverKeyboardDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Core")]
+impl windows_core::imp::ProxyVtable for IMiracastReceiverMediaSourceCreatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverMediaSourceCreatedEventArgs", "Connection", 6usize, |this| ((**(this as *const *const IMiracastReceiverMediaSourceCreatedEventArgs_Vtbl)).Connection)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn MediaSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverMediaSourceCreatedEventArgs", "MediaSource", 7usize, |this| ((**(this as *const *const IMiracastReceiverMediaSourceCreatedEventArgs_Vtbl)).MediaSource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CursorImageChannelSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverMediaSourceCreatedEventArgs", "CursorImageChannelSettings", 8usize, |this| ((**(this as *const *const IMiracastReceiverMediaSourceCreatedEventArgs_Vtbl)).CursorImageChannelSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverMediaSourceCreatedEventArgs", "GetDeferral", 9usize, |this| ((**(this as *const *const IMiracastReceiverMediaSourceCreatedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Connection,
+            #[cfg(feature = "Media_Core")]
+            MediaSource,
+            #[cfg(not(feature = "Media_Core"))]
+            MediaSource: 0,
+            CursorImageChannelSettings,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverMediaSourceCreatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMiracastReceiverSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectionCreated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSession", "ConnectionCreated", 6usize, |this| ((**(this as *const *const IMiracastReceiverSession_Vtbl)).ConnectionCreated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConnectionCreated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSession", "RemoveConnectionCreated", 7usize, |this| ((**(this as *const *const IMiracastReceiverSession_Vtbl)).RemoveConnectionCreated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaSourceCreated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSession", "MediaSourceCreated", 8usize, |this| ((**(this as *const *const IMiracastReceiverSession_Vtbl)).MediaSourceCreated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMediaSourceCreated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSession", "RemoveMediaSourceCreated", 9usize, |this| ((**(this as *const *const IMiracastReceiverSession_Vtbl)).RemoveMediaSourceCreated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSession", "Disconnected", 10usize, |this| ((**(this as *const *const IMiracastReceiverSession_Vtbl)).Disconnected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDisconnected(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSession", "RemoveDisconnected", 11usize, |this| ((**(this as *const *const IMiracastReceiverSession_Vtbl)).RemoveDisconnected)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowConnectionTakeover(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSession", "AllowConnectionTakeover", 12usize, |this| ((**(this as *const *const IMiracastReceiverSession_Vtbl)).AllowConnectionTakeover)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowConnectionTakeover(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSession", "SetAllowConnectionTakeover", 13usize, |this| ((**(this as *const *const IMiracastReceiverSession_Vtbl)).SetAllowConnectionTakeover)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSimultaneousConnections(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSession", "MaxSimultaneousConnections", 14usize, |this| ((**(this as *const *const IMiracastReceiverSession_Vtbl)).MaxSimultaneousConnections)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxSimultaneousConnections(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSession", "SetMaxSimultaneousConnections", 15usize, |this| ((**(this as *const *const IMiracastReceiverSession_Vtbl)).SetMaxSimultaneousConnections)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSession", "Start", 16usize, |this| ((**(this as *const *const IMiracastReceiverSession_Vtbl)).Start)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSession", "StartAsync", 17usize, |this| ((**(this as *const *const IMiracastReceiverSession_Vtbl)).StartAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConnectionCreated,
+            RemoveConnectionCreated,
+            MediaSourceCreated,
+            RemoveMediaSourceCreated,
+            Disconnected,
+            RemoveDisconnected,
+            AllowConnectionTakeover,
+            SetAllowConnectionTakeover,
+            MaxSimultaneousConnections,
+            SetMaxSimultaneousConnections,
+            Start,
+            StartAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMiracastReceiverSessionStartResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut MiracastReceiverSessionStartStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSessionStartResult", "Status", 6usize, |this| ((**(this as *const *const IMiracastReceiverSessionStartResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSessionStartResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IMiracastReceiverSessionStartResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverSessionStartResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMiracastReceiverSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSettings", "FriendlyName", 6usize, |this| ((**(this as *const *const IMiracastReceiverSettings_Vtbl)).FriendlyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFriendlyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSettings", "SetFriendlyName", 7usize, |this| ((**(this as *const *const IMiracastReceiverSettings_Vtbl)).SetFriendlyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModelName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSettings", "ModelName", 8usize, |this| ((**(this as *const *const IMiracastReceiverSettings_Vtbl)).ModelName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetModelName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSettings", "SetModelName", 9usize, |this| ((**(this as *const *const IMiracastReceiverSettings_Vtbl)).SetModelName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModelNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSettings", "ModelNumber", 10usize, |this| ((**(this as *const *const IMiracastReceiverSettings_Vtbl)).ModelNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetModelNumber(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSettings", "SetModelNumber", 11usize, |this| ((**(this as *const *const IMiracastReceiverSettings_Vtbl)).SetModelNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthorizationMethod(this: *mut core::ffi::c_void, result__: *mut MiracastReceiverAuthorizationMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSettings", "AuthorizationMethod", 12usize, |this| ((**(this as *const *const IMiracastReceiverSettings_Vtbl)).AuthorizationMethod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthorizationMethod(this: *mut core::ffi::c_void, value: MiracastReceiverAuthorizationMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSettings", "SetAuthorizationMethod", 13usize, |this| ((**(this as *const *const IMiracastReceiverSettings_Vtbl)).SetAuthorizationMethod)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequireAuthorizationFromKnownTransmitters(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSettings", "RequireAuthorizationFromKnownTransmitters", 14usize, |this| ((**(this as *const *const IMiracastReceiverSettings_Vtbl)).RequireAuthorizationFromKnownTransmitters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequireAuthorizationFromKnownTransmitters(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverSettings", "SetRequireAuthorizationFromKnownTransmitters", 15usize, |this| ((**(this as *const *const IMiracastReceiverSettings_Vtbl)).SetRequireAuthorizationFromKnownTransmitters)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FriendlyName,
+            SetFriendlyName,
+            ModelName,
+            SetModelName,
+            ModelNumber,
+            SetModelNumber,
+            AuthorizationMethod,
+            SetAuthorizationMethod,
+            RequireAuthorizationFromKnownTransmitters,
+            SetRequireAuthorizationFromKnownTransmitters,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMiracastReceiverStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ListeningStatus(this: *mut core::ffi::c_void, result__: *mut MiracastReceiverListeningStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverStatus", "ListeningStatus", 6usize, |this| ((**(this as *const *const IMiracastReceiverStatus_Vtbl)).ListeningStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WiFiStatus(this: *mut core::ffi::c_void, result__: *mut MiracastReceiverWiFiStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverStatus", "WiFiStatus", 7usize, |this| ((**(this as *const *const IMiracastReceiverStatus_Vtbl)).WiFiStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnectionTakeoverSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverStatus", "IsConnectionTakeoverSupported", 8usize, |this| ((**(this as *const *const IMiracastReceiverStatus_Vtbl)).IsConnectionTakeoverSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSimultaneousConnections(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverStatus", "MaxSimultaneousConnections", 9usize, |this| ((**(this as *const *const IMiracastReceiverStatus_Vtbl)).MaxSimultaneousConnections)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn KnownTransmitters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverStatus", "KnownTransmitters", 10usize, |this| ((**(this as *const *const IMiracastReceiverStatus_Vtbl)).KnownTransmitters)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ListeningStatus,
+            WiFiStatus,
+            IsConnectionTakeoverSupported,
+            MaxSimultaneousConnections,
+            #[cfg(feature = "Foundation_Collections")]
+            KnownTransmitters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            KnownTransmitters: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverStatus as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMiracastReceiverStreamControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVideoStreamSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverStreamControl", "GetVideoStreamSettings", 6usize, |this| ((**(this as *const *const IMiracastReceiverStreamControl_Vtbl)).GetVideoStreamSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoStreamSettingsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverStreamControl", "GetVideoStreamSettingsAsync", 7usize, |this| ((**(this as *const *const IMiracastReceiverStreamControl_Vtbl)).GetVideoStreamSettingsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuggestVideoStreamSettings(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverStreamControl", "SuggestVideoStreamSettings", 8usize, |this| ((**(this as *const *const IMiracastReceiverStreamControl_Vtbl)).SuggestVideoStreamSettings)(this, settings), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuggestVideoStreamSettingsAsync(this: *mut core::ffi::c_void, settings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverStreamControl", "SuggestVideoStreamSettingsAsync", 9usize, |this| ((**(this as *const *const IMiracastReceiverStreamControl_Vtbl)).SuggestVideoStreamSettingsAsync)(this, settings, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MuteAudio(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverStreamControl", "MuteAudio", 10usize, |this| ((**(this as *const *const IMiracastReceiverStreamControl_Vtbl)).MuteAudio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMuteAudio(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverStreamControl", "SetMuteAudio", 11usize, |this| ((**(this as *const *const IMiracastReceiverStreamControl_Vtbl)).SetMuteAudio)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetVideoStreamSettings,
+            GetVideoStreamSettingsAsync,
+            SuggestVideoStreamSettings,
+            SuggestVideoStreamSettingsAsync,
+            MuteAudio,
+            SetMuteAudio,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverStreamControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics")]
+impl windows_core::imp::ProxyVtable for IMiracastReceiverVideoStreamSettings_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::SizeInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverVideoStreamSettings", "Size", 6usize, |this| ((**(this as *const *const IMiracastReceiverVideoStreamSettings_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, value: super::super::Graphics::SizeInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverVideoStreamSettings", "SetSize", 7usize, |this| ((**(this as *const *const IMiracastReceiverVideoStreamSettings_Vtbl)).SetSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bitrate(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverVideoStreamSettings", "Bitrate", 8usize, |this| ((**(this as *const *const IMiracastReceiverVideoStreamSettings_Vtbl)).Bitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBitrate(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastReceiverVideoStreamSettings", "SetBitrate", 9usize, |this| ((**(this as *const *const IMiracastReceiverVideoStreamSettings_Vtbl)).SetBitrate)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics")]
+            Size,
+            #[cfg(not(feature = "Graphics"))]
+            Size: 0,
+            #[cfg(feature = "Graphics")]
+            SetSize,
+            #[cfg(not(feature = "Graphics"))]
+            SetSize: 0,
+            Bitrate,
+            SetBitrate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastReceiverVideoStreamSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMiracastTransmitter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastTransmitter", "Name", 6usize, |this| ((**(this as *const *const IMiracastTransmitter_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastTransmitter", "SetName", 7usize, |this| ((**(this as *const *const IMiracastTransmitter_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthorizationStatus(this: *mut core::ffi::c_void, result__: *mut MiracastTransmitterAuthorizationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastTransmitter", "AuthorizationStatus", 8usize, |this| ((**(this as *const *const IMiracastTransmitter_Vtbl)).AuthorizationStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthorizationStatus(this: *mut core::ffi::c_void, value: MiracastTransmitterAuthorizationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastTransmitter", "SetAuthorizationStatus", 9usize, |this| ((**(this as *const *const IMiracastTransmitter_Vtbl)).SetAuthorizationStatus)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetConnections(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastTransmitter", "GetConnections", 10usize, |this| ((**(this as *const *const IMiracastTransmitter_Vtbl)).GetConnections)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MacAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastTransmitter", "MacAddress", 11usize, |this| ((**(this as *const *const IMiracastTransmitter_Vtbl)).MacAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastConnectionTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMiracastTransmitter", "LastConnectionTime", 12usize, |this| ((**(this as *const *const IMiracastTransmitter_Vtbl)).LastConnectionTime)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            AuthorizationStatus,
+            SetAuthorizationStatus,
+            #[cfg(feature = "Foundation_Collections")]
+            GetConnections,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetConnections: 0,
+            MacAddress,
+            LastConnectionTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMiracastTransmitter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -259,3 +259,5 @@ impl windows_core::RuntimeName for OcrWord {
 }
 unsafe impl Send for OcrWord {}
 unsafe impl Sync for OcrWord {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
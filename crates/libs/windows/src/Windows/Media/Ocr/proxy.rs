@@ -0,0 +1,139 @@
+#[cfg(all(feature = "Globalization", feature = "Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for IOcrEngine_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn RecognizeAsync(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOcrEngine", "RecognizeAsync", 6usize, |this| ((**(this as *const *const IOcrEngine_Vtbl)).RecognizeAsync)(this, bitmap, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Globalization")]
+        unsafe extern "system" fn RecognizerLanguage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOcrEngine", "RecognizerLanguage", 7usize, |this| ((**(this as *const *const IOcrEngine_Vtbl)).RecognizerLanguage)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Imaging")]
+            RecognizeAsync,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            RecognizeAsync: 0,
+            #[cfg(feature = "Globalization")]
+            RecognizerLanguage,
+            #[cfg(not(feature = "Globalization"))]
+            RecognizerLanguage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOcrEngine as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Globalization"))]
+impl windows_core::imp::ProxyVtable for IOcrEngineStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxImageDimension(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOcrEngineStatics", "MaxImageDimension", 6usize, |this| ((**(this as *const *const IOcrEngineStatics_Vtbl)).MaxImageDimension)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Globalization"))]
+        unsafe extern "system" fn AvailableRecognizerLanguages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOcrEngineStatics", "AvailableRecognizerLanguages", 7usize, |this| ((**(this as *const *const IOcrEngineStatics_Vtbl)).AvailableRecognizerLanguages)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Globalization")]
+        unsafe extern "system" fn IsLanguageSupported(this: *mut core::ffi::c_void, language: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOcrEngineStatics", "IsLanguageSupported", 8usize, |this| ((**(this as *const *const IOcrEngineStatics_Vtbl)).IsLanguageSupported)(this, language, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Globalization")]
+        unsafe extern "system" fn TryCreateFromLanguage(this: *mut core::ffi::c_void, language: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOcrEngineStatics", "TryCreateFromLanguage", 9usize, |this| ((**(this as *const *const IOcrEngineStatics_Vtbl)).TryCreateFromLanguage)(this, language, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryCreateFromUserProfileLanguages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOcrEngineStatics", "TryCreateFromUserProfileLanguages", 10usize, |this| ((**(this as *const *const IOcrEngineStatics_Vtbl)).TryCreateFromUserProfileLanguages)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaxImageDimension,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Globalization"))]
+            AvailableRecognizerLanguages,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Globalization")))]
+            AvailableRecognizerLanguages: 0,
+            #[cfg(feature = "Globalization")]
+            IsLanguageSupported,
+            #[cfg(not(feature = "Globalization"))]
+            IsLanguageSupported: 0,
+            #[cfg(feature = "Globalization")]
+            TryCreateFromLanguage,
+            #[cfg(not(feature = "Globalization"))]
+            TryCreateFromLanguage: 0,
+            TryCreateFromUserProfileLanguages,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOcrEngineStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IOcrLine_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Words(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOcrLine", "Words", 6usize, |this| ((**(this as *const *const IOcrLine_Vtbl)).Words)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOcrLine", "Text", 7usize, |this| ((**(this as *const *const IOcrLine_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Words,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Words: 0,
+            Text,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOcrLine as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IOcrResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Lines(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOcrResult", "Lines", 6usize, |this| ((**(this as *const *const IOcrResult_Vtbl)).Lines)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextAngle(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOcrResult", "TextAngle", 7usize, |this| ((**(this as *const *const IOcrResult_Vtbl)).TextAngle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOcrResult", "Text", 8usize, |this| ((**(this as *const *const IOcrResult_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Lines,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Lines: 0,
+            TextAngle,
+            Text,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOcrResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOcrWord_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BoundingRect(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOcrWord", "BoundingRect", 6usize, |this| ((**(this as *const *const IOcrWord_Vtbl)).BoundingRect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOcrWord", "Text", 7usize, |this| ((**(this as *const *const IOcrWord_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BoundingRect, Text }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOcrWord as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
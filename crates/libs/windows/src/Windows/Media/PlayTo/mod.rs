@@ -1411,6 +1411,7 @@ impl windows_core::TypeKind for PlayToConnectionError {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlayToConnectionError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlayToConnectionError").field(&self.0).finish()
@@ -1435,6 +1436,7 @@ impl windows_core::TypeKind for PlayToConnectionState {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlayToConnectionState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlayToConnectionState").field(&self.0).finish()
@@ -1444,3 +1446,5 @@ impl core::fmt::Debug for PlayToConnectionState {
 impl windows_core::RuntimeType for PlayToConnectionState {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.PlayTo.PlayToConnectionState;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
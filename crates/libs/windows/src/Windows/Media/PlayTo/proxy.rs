@@ -0,0 +1,740 @@
+impl windows_core::imp::ProxyVtable for ICurrentTimeChangeRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Time(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrentTimeChangeRequestedEventArgs", "Time", 6usize, |this| ((**(this as *const *const ICurrentTimeChangeRequestedEventArgs_Vtbl)).Time)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Time }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICurrentTimeChangeRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMuteChangeRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mute(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMuteChangeRequestedEventArgs", "Mute", 6usize, |this| ((**(this as *const *const IMuteChangeRequestedEventArgs_Vtbl)).Mute)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Mute }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMuteChangeRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPlayToConnection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut PlayToConnectionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToConnection", "State", 6usize, |this| ((**(this as *const *const IPlayToConnection_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn StateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToConnection", "StateChanged", 7usize, |this| ((**(this as *const *const IPlayToConnection_Vtbl)).StateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToConnection", "RemoveStateChanged", 8usize, |this| ((**(this as *const *const IPlayToConnection_Vtbl)).RemoveStateChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Transferred(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToConnection", "Transferred", 9usize, |this| ((**(this as *const *const IPlayToConnection_Vtbl)).Transferred)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveTransferred(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToConnection", "RemoveTransferred", 10usize, |this| ((**(this as *const *const IPlayToConnection_Vtbl)).RemoveTransferred)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToConnection", "Error", 11usize, |this| ((**(this as *const *const IPlayToConnection_Vtbl)).Error)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveError(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToConnection", "RemoveError", 12usize, |this| ((**(this as *const *const IPlayToConnection_Vtbl)).RemoveError)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            State,
+            #[cfg(not(feature = "deprecated"))]
+            State: 0,
+            #[cfg(feature = "deprecated")]
+            StateChanged,
+            #[cfg(not(feature = "deprecated"))]
+            StateChanged: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveStateChanged,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveStateChanged: 0,
+            #[cfg(feature = "deprecated")]
+            Transferred,
+            #[cfg(not(feature = "deprecated"))]
+            Transferred: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveTransferred,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveTransferred: 0,
+            #[cfg(feature = "deprecated")]
+            Error,
+            #[cfg(not(feature = "deprecated"))]
+            Error: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveError,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveError: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPlayToConnectionErrorEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Code(this: *mut core::ffi::c_void, result__: *mut PlayToConnectionError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToConnectionErrorEventArgs", "Code", 6usize, |this| ((**(this as *const *const IPlayToConnectionErrorEventArgs_Vtbl)).Code)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToConnectionErrorEventArgs", "Message", 7usize, |this| ((**(this as *const *const IPlayToConnectionErrorEventArgs_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Code,
+            #[cfg(not(feature = "deprecated"))]
+            Code: 0,
+            #[cfg(feature = "deprecated")]
+            Message,
+            #[cfg(not(feature = "deprecated"))]
+            Message: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToConnectionErrorEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPlayToConnectionStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PreviousState(this: *mut core::ffi::c_void, result__: *mut PlayToConnectionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToConnectionStateChangedEventArgs", "PreviousState", 6usize, |this| ((**(this as *const *const IPlayToConnectionStateChangedEventArgs_Vtbl)).PreviousState)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CurrentState(this: *mut core::ffi::c_void, result__: *mut PlayToConnectionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToConnectionStateChangedEventArgs", "CurrentState", 7usize, |this| ((**(this as *const *const IPlayToConnectionStateChangedEventArgs_Vtbl)).CurrentState)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            PreviousState,
+            #[cfg(not(feature = "deprecated"))]
+            PreviousState: 0,
+            #[cfg(feature = "deprecated")]
+            CurrentState,
+            #[cfg(not(feature = "deprecated"))]
+            CurrentState: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToConnectionStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPlayToConnectionTransferredEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PreviousSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToConnectionTransferredEventArgs", "PreviousSource", 6usize, |this| ((**(this as *const *const IPlayToConnectionTransferredEventArgs_Vtbl)).PreviousSource)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CurrentSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToConnectionTransferredEventArgs", "CurrentSource", 7usize, |this| ((**(this as *const *const IPlayToConnectionTransferredEventArgs_Vtbl)).CurrentSource)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            PreviousSource,
+            #[cfg(not(feature = "deprecated"))]
+            PreviousSource: 0,
+            #[cfg(feature = "deprecated")]
+            CurrentSource,
+            #[cfg(not(feature = "deprecated"))]
+            CurrentSource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToConnectionTransferredEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPlayToManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SourceRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToManager", "SourceRequested", 6usize, |this| ((**(this as *const *const IPlayToManager_Vtbl)).SourceRequested)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveSourceRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToManager", "RemoveSourceRequested", 7usize, |this| ((**(this as *const *const IPlayToManager_Vtbl)).RemoveSourceRequested)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SourceSelected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToManager", "SourceSelected", 8usize, |this| ((**(this as *const *const IPlayToManager_Vtbl)).SourceSelected)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveSourceSelected(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToManager", "RemoveSourceSelected", 9usize, |this| ((**(this as *const *const IPlayToManager_Vtbl)).RemoveSourceSelected)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetDefaultSourceSelection(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToManager", "SetDefaultSourceSelection", 10usize, |this| ((**(this as *const *const IPlayToManager_Vtbl)).SetDefaultSourceSelection)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DefaultSourceSelection(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToManager", "DefaultSourceSelection", 11usize, |this| ((**(this as *const *const IPlayToManager_Vtbl)).DefaultSourceSelection)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            SourceRequested,
+            #[cfg(not(feature = "deprecated"))]
+            SourceRequested: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveSourceRequested,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveSourceRequested: 0,
+            #[cfg(feature = "deprecated")]
+            SourceSelected,
+            #[cfg(not(feature = "deprecated"))]
+            SourceSelected: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveSourceSelected,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveSourceSelected: 0,
+            #[cfg(feature = "deprecated")]
+            SetDefaultSourceSelection,
+            #[cfg(not(feature = "deprecated"))]
+            SetDefaultSourceSelection: 0,
+            #[cfg(feature = "deprecated")]
+            DefaultSourceSelection,
+            #[cfg(not(feature = "deprecated"))]
+            DefaultSourceSelection: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPlayToManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToManagerStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IPlayToManagerStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ShowPlayToUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToManagerStatics", "ShowPlayToUI", 7usize, |this| ((**(this as *const *const IPlayToManagerStatics_Vtbl)).ShowPlayToUI)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            GetForCurrentView,
+            #[cfg(not(feature = "deprecated"))]
+            GetForCurrentView: 0,
+            #[cfg(feature = "deprecated")]
+            ShowPlayToUI,
+            #[cfg(not(feature = "deprecated"))]
+            ShowPlayToUI: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPlayToReceiver_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PlayRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "PlayRequested", 6usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).PlayRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePlayRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "RemovePlayRequested", 7usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).RemovePlayRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PauseRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "PauseRequested", 8usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).PauseRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePauseRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "RemovePauseRequested", 9usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).RemovePauseRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceChangeRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "SourceChangeRequested", 10usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).SourceChangeRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSourceChangeRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "RemoveSourceChangeRequested", 11usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).RemoveSourceChangeRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackRateChangeRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "PlaybackRateChangeRequested", 12usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).PlaybackRateChangeRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePlaybackRateChangeRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "RemovePlaybackRateChangeRequested", 13usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).RemovePlaybackRateChangeRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentTimeChangeRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "CurrentTimeChangeRequested", 14usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).CurrentTimeChangeRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCurrentTimeChangeRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "RemoveCurrentTimeChangeRequested", 15usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).RemoveCurrentTimeChangeRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn MuteChangeRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "MuteChangeRequested", 16usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).MuteChangeRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMuteChangeRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "RemoveMuteChangeRequested", 17usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).RemoveMuteChangeRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumeChangeRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "VolumeChangeRequested", 18usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).VolumeChangeRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVolumeChangeRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "RemoveVolumeChangeRequested", 19usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).RemoveVolumeChangeRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeUpdateRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "TimeUpdateRequested", 20usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).TimeUpdateRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTimeUpdateRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "RemoveTimeUpdateRequested", 21usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).RemoveTimeUpdateRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "StopRequested", 22usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).StopRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStopRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "RemoveStopRequested", 23usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).RemoveStopRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyVolumeChange(this: *mut core::ffi::c_void, volume: f64, mute: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "NotifyVolumeChange", 24usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).NotifyVolumeChange)(this, volume, mute), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyRateChange(this: *mut core::ffi::c_void, rate: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "NotifyRateChange", 25usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).NotifyRateChange)(this, rate), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyLoadedMetadata(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "NotifyLoadedMetadata", 26usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).NotifyLoadedMetadata)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyTimeUpdate(this: *mut core::ffi::c_void, currenttime: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "NotifyTimeUpdate", 27usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).NotifyTimeUpdate)(this, currenttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyDurationChange(this: *mut core::ffi::c_void, duration: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "NotifyDurationChange", 28usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).NotifyDurationChange)(this, duration), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifySeeking(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "NotifySeeking", 29usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).NotifySeeking)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifySeeked(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "NotifySeeked", 30usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).NotifySeeked)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyPaused(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "NotifyPaused", 31usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).NotifyPaused)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyPlaying(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "NotifyPlaying", 32usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).NotifyPlaying)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyEnded(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "NotifyEnded", 33usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).NotifyEnded)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyError(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "NotifyError", 34usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).NotifyError)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyStopped(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "NotifyStopped", 35usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).NotifyStopped)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "FriendlyName", 36usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).FriendlyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFriendlyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "SetFriendlyName", 37usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).SetFriendlyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportsImage(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "SetSupportsImage", 38usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).SetSupportsImage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportsImage(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "SupportsImage", 39usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).SupportsImage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportsAudio(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "SetSupportsAudio", 40usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).SetSupportsAudio)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportsAudio(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "SupportsAudio", 41usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).SupportsAudio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportsVideo(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "SetSupportsVideo", 42usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).SetSupportsVideo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportsVideo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "SupportsVideo", 43usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).SupportsVideo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "Properties", 44usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "StartAsync", 45usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).StartAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToReceiver", "StopAsync", 46usize, |this| ((**(this as *const *const IPlayToReceiver_Vtbl)).StopAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PlayRequested,
+            RemovePlayRequested,
+            PauseRequested,
+            RemovePauseRequested,
+            SourceChangeRequested,
+            RemoveSourceChangeRequested,
+            PlaybackRateChangeRequested,
+            RemovePlaybackRateChangeRequested,
+            CurrentTimeChangeRequested,
+            RemoveCurrentTimeChangeRequested,
+            MuteChangeRequested,
+            RemoveMuteChangeRequested,
+            VolumeChangeRequested,
+            RemoveVolumeChangeRequested,
+            TimeUpdateRequested,
+            RemoveTimeUpdateRequested,
+            StopRequested,
+            RemoveStopRequested,
+            NotifyVolumeChange,
+            NotifyRateChange,
+            NotifyLoadedMetadata,
+            NotifyTimeUpdate,
+            NotifyDurationChange,
+            NotifySeeking,
+            NotifySeeked,
+            NotifyPaused,
+            NotifyPlaying,
+            NotifyEnded,
+            NotifyError,
+            NotifyStopped,
+            FriendlyName,
+            SetFriendlyName,
+            SetSupportsImage,
+            SupportsImage,
+            SetSupportsAudio,
+            SupportsAudio,
+            SetSupportsVideo,
+            SupportsVideo,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            StartAsync,
+            StopAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToReceiver as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPlayToSource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Connection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSource", "Connection", 6usize, |this| ((**(this as *const *const IPlayToSource_Vtbl)).Connection)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSource", "Next", 7usize, |this| ((**(this as *const *const IPlayToSource_Vtbl)).Next)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetNext(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSource", "SetNext", 8usize, |this| ((**(this as *const *const IPlayToSource_Vtbl)).SetNext)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PlayNext(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSource", "PlayNext", 9usize, |this| ((**(this as *const *const IPlayToSource_Vtbl)).PlayNext)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Connection,
+            #[cfg(not(feature = "deprecated"))]
+            Connection: 0,
+            #[cfg(feature = "deprecated")]
+            Next,
+            #[cfg(not(feature = "deprecated"))]
+            Next: 0,
+            #[cfg(feature = "deprecated")]
+            SetNext,
+            #[cfg(not(feature = "deprecated"))]
+            SetNext: 0,
+            #[cfg(feature = "deprecated")]
+            PlayNext,
+            #[cfg(not(feature = "deprecated"))]
+            PlayNext: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPlayToSourceDeferral_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSourceDeferral", "Complete", 6usize, |this| ((**(this as *const *const IPlayToSourceDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Complete,
+            #[cfg(not(feature = "deprecated"))]
+            Complete: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToSourceDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPlayToSourceRequest_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSourceRequest", "Deadline", 6usize, |this| ((**(this as *const *const IPlayToSourceRequest_Vtbl)).Deadline)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn DisplayErrorString(this: *mut core::ffi::c_void, errorstring: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSourceRequest", "DisplayErrorString", 7usize, |this| ((**(this as *const *const IPlayToSourceRequest_Vtbl)).DisplayErrorString)(this, errorstring), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSourceRequest", "GetDeferral", 8usize, |this| ((**(this as *const *const IPlayToSourceRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSourceRequest", "SetSource", 9usize, |this| ((**(this as *const *const IPlayToSourceRequest_Vtbl)).SetSource)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Deadline,
+            #[cfg(not(feature = "deprecated"))]
+            Deadline: 0,
+            #[cfg(feature = "deprecated")]
+            DisplayErrorString,
+            #[cfg(not(feature = "deprecated"))]
+            DisplayErrorString: 0,
+            #[cfg(feature = "deprecated")]
+            GetDeferral,
+            #[cfg(not(feature = "deprecated"))]
+            GetDeferral: 0,
+            #[cfg(feature = "deprecated")]
+            SetSource,
+            #[cfg(not(feature = "deprecated"))]
+            SetSource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToSourceRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPlayToSourceRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SourceRequest(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSourceRequestedEventArgs", "SourceRequest", 6usize, |this| ((**(this as *const *const IPlayToSourceRequestedEventArgs_Vtbl)).SourceRequest)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            SourceRequest,
+            #[cfg(not(feature = "deprecated"))]
+            SourceRequest: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToSourceRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IPlayToSourceSelectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSourceSelectedEventArgs", "FriendlyName", 6usize, |this| ((**(this as *const *const IPlayToSourceSelectedEventArgs_Vtbl)).FriendlyName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn Icon(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSourceSelectedEventArgs", "Icon", 7usize, |this| ((**(this as *const *const IPlayToSourceSelectedEventArgs_Vtbl)).Icon)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SupportsImage(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSourceSelectedEventArgs", "SupportsImage", 8usize, |this| ((**(this as *const *const IPlayToSourceSelectedEventArgs_Vtbl)).SupportsImage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SupportsAudio(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSourceSelectedEventArgs", "SupportsAudio", 9usize, |this| ((**(this as *const *const IPlayToSourceSelectedEventArgs_Vtbl)).SupportsAudio)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SupportsVideo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSourceSelectedEventArgs", "SupportsVideo", 10usize, |this| ((**(this as *const *const IPlayToSourceSelectedEventArgs_Vtbl)).SupportsVideo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            FriendlyName,
+            #[cfg(not(feature = "deprecated"))]
+            FriendlyName: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            Icon,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            Icon: 0,
+            #[cfg(feature = "deprecated")]
+            SupportsImage,
+            #[cfg(not(feature = "deprecated"))]
+            SupportsImage: 0,
+            #[cfg(feature = "deprecated")]
+            SupportsAudio,
+            #[cfg(not(feature = "deprecated"))]
+            SupportsAudio: 0,
+            #[cfg(feature = "deprecated")]
+            SupportsVideo,
+            #[cfg(not(feature = "deprecated"))]
+            SupportsVideo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToSourceSelectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IPlayToSourceWithPreferredSourceUri_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PreferredSourceUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSourceWithPreferredSourceUri", "PreferredSourceUri", 6usize, |this| ((**(this as *const *const IPlayToSourceWithPreferredSourceUri_Vtbl)).PreferredSourceUri)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetPreferredSourceUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSourceWithPreferredSourceUri", "SetPreferredSourceUri", 7usize, |this| ((**(this as *const *const IPlayToSourceWithPreferredSourceUri_Vtbl)).SetPreferredSourceUri)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            PreferredSourceUri,
+            #[cfg(not(feature = "deprecated"))]
+            PreferredSourceUri: 0,
+            #[cfg(feature = "deprecated")]
+            SetPreferredSourceUri,
+            #[cfg(not(feature = "deprecated"))]
+            SetPreferredSourceUri: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToSourceWithPreferredSourceUri as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlaybackRateChangeRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Rate(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaybackRateChangeRequestedEventArgs", "Rate", 6usize, |this| ((**(this as *const *const IPlaybackRateChangeRequestedEventArgs_Vtbl)).Rate)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Rate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaybackRateChangeRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ISourceChangeRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Stream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISourceChangeRequestedEventArgs", "Stream", 6usize, |this| ((**(this as *const *const ISourceChangeRequestedEventArgs_Vtbl)).Stream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISourceChangeRequestedEventArgs", "Title", 7usize, |this| ((**(this as *const *const ISourceChangeRequestedEventArgs_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Author(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISourceChangeRequestedEventArgs", "Author", 8usize, |this| ((**(this as *const *const ISourceChangeRequestedEventArgs_Vtbl)).Author)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Album(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISourceChangeRequestedEventArgs", "Album", 9usize, |this| ((**(this as *const *const ISourceChangeRequestedEventArgs_Vtbl)).Album)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Genre(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISourceChangeRequestedEventArgs", "Genre", 10usize, |this| ((**(this as *const *const ISourceChangeRequestedEventArgs_Vtbl)).Genre)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISourceChangeRequestedEventArgs", "Description", 11usize, |this| ((**(this as *const *const ISourceChangeRequestedEventArgs_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Date(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISourceChangeRequestedEventArgs", "Date", 12usize, |this| ((**(this as *const *const ISourceChangeRequestedEventArgs_Vtbl)).Date)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISourceChangeRequestedEventArgs", "Thumbnail", 13usize, |this| ((**(this as *const *const ISourceChangeRequestedEventArgs_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rating(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISourceChangeRequestedEventArgs", "Rating", 14usize, |this| ((**(this as *const *const ISourceChangeRequestedEventArgs_Vtbl)).Rating)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISourceChangeRequestedEventArgs", "Properties", 15usize, |this| ((**(this as *const *const ISourceChangeRequestedEventArgs_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Stream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Stream: 0,
+            Title,
+            Author,
+            Album,
+            Genre,
+            Description,
+            Date,
+            #[cfg(feature = "Storage_Streams")]
+            Thumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Thumbnail: 0,
+            Rating,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISourceChangeRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVolumeChangeRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Volume(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVolumeChangeRequestedEventArgs", "Volume", 6usize, |this| ((**(this as *const *const IVolumeChangeRequestedEventArgs_Vtbl)).Volume)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Volume }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVolumeChangeRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
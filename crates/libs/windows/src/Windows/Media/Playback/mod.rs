@@ -4820,6 +4820,7 @@ impl AutoLoadedDisplayPropertyKind {
 impl windows_core::TypeKind for AutoLoadedDisplayPropertyKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AutoLoadedDisplayPropertyKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AutoLoadedDisplayPropertyKind").field(&self.0).finish()
@@ -4839,6 +4840,7 @@ impl FailedMediaStreamKind {
 impl windows_core::TypeKind for FailedMediaStreamKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FailedMediaStreamKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FailedMediaStreamKind").field(&self.0).finish()
@@ -4857,6 +4859,7 @@ impl MediaBreakInsertionMethod {
 impl windows_core::TypeKind for MediaBreakInsertionMethod {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaBreakInsertionMethod {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaBreakInsertionMethod").field(&self.0).finish()
@@ -4876,6 +4879,7 @@ impl MediaCommandEnablingRule {
 impl windows_core::TypeKind for MediaCommandEnablingRule {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaCommandEnablingRule {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaCommandEnablingRule").field(&self.0).finish()
@@ -4896,6 +4900,7 @@ impl MediaPlaybackItemChangedReason {
 impl windows_core::TypeKind for MediaPlaybackItemChangedReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaPlaybackItemChangedReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaPlaybackItemChangedReason").field(&self.0).finish()
@@ -4918,6 +4923,7 @@ impl MediaPlaybackItemErrorCode {
 impl windows_core::TypeKind for MediaPlaybackItemErrorCode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaPlaybackItemErrorCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaPlaybackItemErrorCode").field(&self.0).finish()
@@ -4941,6 +4947,7 @@ impl MediaPlaybackSessionVideoConstrictionReason {
 impl windows_core::TypeKind for MediaPlaybackSessionVideoConstrictionReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaPlaybackSessionVideoConstrictionReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaPlaybackSessionVideoConstrictionReason").field(&self.0).finish()
@@ -4962,6 +4969,7 @@ impl MediaPlaybackState {
 impl windows_core::TypeKind for MediaPlaybackState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaPlaybackState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaPlaybackState").field(&self.0).finish()
@@ -4988,6 +4996,7 @@ impl MediaPlayerAudioCategory {
 impl windows_core::TypeKind for MediaPlayerAudioCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaPlayerAudioCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaPlayerAudioCategory").field(&self.0).finish()
@@ -5007,6 +5016,7 @@ impl MediaPlayerAudioDeviceType {
 impl windows_core::TypeKind for MediaPlayerAudioDeviceType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaPlayerAudioDeviceType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaPlayerAudioDeviceType").field(&self.0).finish()
@@ -5028,6 +5038,7 @@ impl MediaPlayerError {
 impl windows_core::TypeKind for MediaPlayerError {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaPlayerError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaPlayerError").field(&self.0).finish()
@@ -5054,6 +5065,7 @@ impl windows_core::TypeKind for MediaPlayerState {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaPlayerState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaPlayerState").field(&self.0).finish()
@@ -5073,6 +5085,7 @@ impl SphericalVideoProjectionMode {
 impl windows_core::TypeKind for SphericalVideoProjectionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SphericalVideoProjectionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SphericalVideoProjectionMode").field(&self.0).finish()
@@ -5091,6 +5104,7 @@ impl StereoscopicVideoRenderMode {
 impl windows_core::TypeKind for StereoscopicVideoRenderMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StereoscopicVideoRenderMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StereoscopicVideoRenderMode").field(&self.0).finish()
@@ -5111,6 +5125,7 @@ impl TimedMetadataTrackPresentationMode {
 impl windows_core::TypeKind for TimedMetadataTrackPresentationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TimedMetadataTrackPresentationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TimedMetadataTrackPresentationMode").field(&self.0).finish()
@@ -5121,3 +5136,5 @@ impl windows_core::RuntimeType for TimedMetadataTrackPresentationMode {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
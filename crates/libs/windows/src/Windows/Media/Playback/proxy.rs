@@ -0,0 +1,2447 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IBackgroundMediaPlayerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundMediaPlayerStatics", "Current", 6usize, |this| ((**(this as *const *const IBackgroundMediaPlayerStatics_Vtbl)).Current)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn MessageReceivedFromBackground(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundMediaPlayerStatics", "MessageReceivedFromBackground", 7usize, |this| ((**(this as *const *const IBackgroundMediaPlayerStatics_Vtbl)).MessageReceivedFromBackground)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveMessageReceivedFromBackground(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundMediaPlayerStatics", "RemoveMessageReceivedFromBackground", 8usize, |this| ((**(this as *const *const IBackgroundMediaPlayerStatics_Vtbl)).RemoveMessageReceivedFromBackground)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn MessageReceivedFromForeground(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundMediaPlayerStatics", "MessageReceivedFromForeground", 9usize, |this| ((**(this as *const *const IBackgroundMediaPlayerStatics_Vtbl)).MessageReceivedFromForeground)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveMessageReceivedFromForeground(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundMediaPlayerStatics", "RemoveMessageReceivedFromForeground", 10usize, |this| ((**(this as *const *const IBackgroundMediaPlayerStatics_Vtbl)).RemoveMessageReceivedFromForeground)(this, token), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn SendMessageToBackground(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundMediaPlayerStatics", "SendMessageToBackground", 11usize, |this| ((**(this as *const *const IBackgroundMediaPlayerStatics_Vtbl)).SendMessageToBackground)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn SendMessageToForeground(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundMediaPlayerStatics", "SendMessageToForeground", 12usize, |this| ((**(this as *const *const IBackgroundMediaPlayerStatics_Vtbl)).SendMessageToForeground)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsMediaPlaying(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundMediaPlayerStatics", "IsMediaPlaying", 13usize, |this| ((**(this as *const *const IBackgroundMediaPlayerStatics_Vtbl)).IsMediaPlaying)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundMediaPlayerStatics", "Shutdown", 14usize, |this| ((**(this as *const *const IBackgroundMediaPlayerStatics_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Current,
+            #[cfg(not(feature = "deprecated"))]
+            Current: 0,
+            #[cfg(feature = "deprecated")]
+            MessageReceivedFromBackground,
+            #[cfg(not(feature = "deprecated"))]
+            MessageReceivedFromBackground: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveMessageReceivedFromBackground,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveMessageReceivedFromBackground: 0,
+            #[cfg(feature = "deprecated")]
+            MessageReceivedFromForeground,
+            #[cfg(not(feature = "deprecated"))]
+            MessageReceivedFromForeground: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveMessageReceivedFromForeground,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveMessageReceivedFromForeground: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            SendMessageToBackground,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            SendMessageToBackground: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            SendMessageToForeground,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            SendMessageToForeground: 0,
+            #[cfg(feature = "deprecated")]
+            IsMediaPlaying,
+            #[cfg(not(feature = "deprecated"))]
+            IsMediaPlaying: 0,
+            #[cfg(feature = "deprecated")]
+            Shutdown,
+            #[cfg(not(feature = "deprecated"))]
+            Shutdown: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundMediaPlayerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICurrentMediaPlaybackItemChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NewItem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrentMediaPlaybackItemChangedEventArgs", "NewItem", 6usize, |this| ((**(this as *const *const ICurrentMediaPlaybackItemChangedEventArgs_Vtbl)).NewItem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OldItem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrentMediaPlaybackItemChangedEventArgs", "OldItem", 7usize, |this| ((**(this as *const *const ICurrentMediaPlaybackItemChangedEventArgs_Vtbl)).OldItem)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NewItem, OldItem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICurrentMediaPlaybackItemChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICurrentMediaPlaybackItemChangedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut MediaPlaybackItemChangedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICurrentMediaPlaybackItemChangedEventArgs2", "Reason", 6usize, |this| ((**(this as *const *const ICurrentMediaPlaybackItemChangedEventArgs2_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICurrentMediaPlaybackItemChangedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaBreak_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PlaybackList(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreak", "PlaybackList", 6usize, |this| ((**(this as *const *const IMediaBreak_Vtbl)).PlaybackList)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PresentationPosition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreak", "PresentationPosition", 7usize, |this| ((**(this as *const *const IMediaBreak_Vtbl)).PresentationPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertionMethod(this: *mut core::ffi::c_void, result__: *mut MediaBreakInsertionMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreak", "InsertionMethod", 8usize, |this| ((**(this as *const *const IMediaBreak_Vtbl)).InsertionMethod)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CustomProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreak", "CustomProperties", 9usize, |this| ((**(this as *const *const IMediaBreak_Vtbl)).CustomProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanStart(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreak", "CanStart", 10usize, |this| ((**(this as *const *const IMediaBreak_Vtbl)).CanStart)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanStart(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreak", "SetCanStart", 11usize, |this| ((**(this as *const *const IMediaBreak_Vtbl)).SetCanStart)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PlaybackList,
+            PresentationPosition,
+            InsertionMethod,
+            #[cfg(feature = "Foundation_Collections")]
+            CustomProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CustomProperties: 0,
+            CanStart,
+            SetCanStart,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaBreak as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaBreakEndedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MediaBreak(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakEndedEventArgs", "MediaBreak", 6usize, |this| ((**(this as *const *const IMediaBreakEndedEventArgs_Vtbl)).MediaBreak)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MediaBreak }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaBreakEndedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaBreakFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, insertionmethod: MediaBreakInsertionMethod, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakFactory", "Create", 6usize, |this| ((**(this as *const *const IMediaBreakFactory_Vtbl)).Create)(this, insertionmethod, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithPresentationPosition(this: *mut core::ffi::c_void, insertionmethod: MediaBreakInsertionMethod, presentationposition: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakFactory", "CreateWithPresentationPosition", 7usize, |this| ((**(this as *const *const IMediaBreakFactory_Vtbl)).CreateWithPresentationPosition)(this, insertionmethod, presentationposition, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithPresentationPosition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaBreakFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaBreakManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BreaksSeekedOver(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakManager", "BreaksSeekedOver", 6usize, |this| ((**(this as *const *const IMediaBreakManager_Vtbl)).BreaksSeekedOver)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBreaksSeekedOver(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakManager", "RemoveBreaksSeekedOver", 7usize, |this| ((**(this as *const *const IMediaBreakManager_Vtbl)).RemoveBreaksSeekedOver)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn BreakStarted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakManager", "BreakStarted", 8usize, |this| ((**(this as *const *const IMediaBreakManager_Vtbl)).BreakStarted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBreakStarted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakManager", "RemoveBreakStarted", 9usize, |this| ((**(this as *const *const IMediaBreakManager_Vtbl)).RemoveBreakStarted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn BreakEnded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakManager", "BreakEnded", 10usize, |this| ((**(this as *const *const IMediaBreakManager_Vtbl)).BreakEnded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBreakEnded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakManager", "RemoveBreakEnded", 11usize, |this| ((**(this as *const *const IMediaBreakManager_Vtbl)).RemoveBreakEnded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn BreakSkipped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakManager", "BreakSkipped", 12usize, |this| ((**(this as *const *const IMediaBreakManager_Vtbl)).BreakSkipped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBreakSkipped(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakManager", "RemoveBreakSkipped", 13usize, |this| ((**(this as *const *const IMediaBreakManager_Vtbl)).RemoveBreakSkipped)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentBreak(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakManager", "CurrentBreak", 14usize, |this| ((**(this as *const *const IMediaBreakManager_Vtbl)).CurrentBreak)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackSession(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakManager", "PlaybackSession", 15usize, |this| ((**(this as *const *const IMediaBreakManager_Vtbl)).PlaybackSession)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayBreak(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakManager", "PlayBreak", 16usize, |this| ((**(this as *const *const IMediaBreakManager_Vtbl)).PlayBreak)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SkipCurrentBreak(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakManager", "SkipCurrentBreak", 17usize, |this| ((**(this as *const *const IMediaBreakManager_Vtbl)).SkipCurrentBreak)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BreaksSeekedOver,
+            RemoveBreaksSeekedOver,
+            BreakStarted,
+            RemoveBreakStarted,
+            BreakEnded,
+            RemoveBreakEnded,
+            BreakSkipped,
+            RemoveBreakSkipped,
+            CurrentBreak,
+            PlaybackSession,
+            PlayBreak,
+            SkipCurrentBreak,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaBreakManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaBreakSchedule_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScheduleChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakSchedule", "ScheduleChanged", 6usize, |this| ((**(this as *const *const IMediaBreakSchedule_Vtbl)).ScheduleChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveScheduleChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakSchedule", "RemoveScheduleChanged", 7usize, |this| ((**(this as *const *const IMediaBreakSchedule_Vtbl)).RemoveScheduleChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertMidrollBreak(this: *mut core::ffi::c_void, mediabreak: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakSchedule", "InsertMidrollBreak", 8usize, |this| ((**(this as *const *const IMediaBreakSchedule_Vtbl)).InsertMidrollBreak)(this, mediabreak), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMidrollBreak(this: *mut core::ffi::c_void, mediabreak: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakSchedule", "RemoveMidrollBreak", 9usize, |this| ((**(this as *const *const IMediaBreakSchedule_Vtbl)).RemoveMidrollBreak)(this, mediabreak), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MidrollBreaks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakSchedule", "MidrollBreaks", 10usize, |this| ((**(this as *const *const IMediaBreakSchedule_Vtbl)).MidrollBreaks)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrerollBreak(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakSchedule", "SetPrerollBreak", 11usize, |this| ((**(this as *const *const IMediaBreakSchedule_Vtbl)).SetPrerollBreak)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrerollBreak(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakSchedule", "PrerollBreak", 12usize, |this| ((**(this as *const *const IMediaBreakSchedule_Vtbl)).PrerollBreak)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPostrollBreak(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakSchedule", "SetPostrollBreak", 13usize, |this| ((**(this as *const *const IMediaBreakSchedule_Vtbl)).SetPostrollBreak)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostrollBreak(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakSchedule", "PostrollBreak", 14usize, |this| ((**(this as *const *const IMediaBreakSchedule_Vtbl)).PostrollBreak)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackItem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakSchedule", "PlaybackItem", 15usize, |this| ((**(this as *const *const IMediaBreakSchedule_Vtbl)).PlaybackItem)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ScheduleChanged,
+            RemoveScheduleChanged,
+            InsertMidrollBreak,
+            RemoveMidrollBreak,
+            #[cfg(feature = "Foundation_Collections")]
+            MidrollBreaks,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MidrollBreaks: 0,
+            SetPrerollBreak,
+            PrerollBreak,
+            SetPostrollBreak,
+            PostrollBreak,
+            PlaybackItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaBreakSchedule as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaBreakSeekedOverEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SeekedOverBreaks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakSeekedOverEventArgs", "SeekedOverBreaks", 6usize, |this| ((**(this as *const *const IMediaBreakSeekedOverEventArgs_Vtbl)).SeekedOverBreaks)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OldPosition(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakSeekedOverEventArgs", "OldPosition", 7usize, |this| ((**(this as *const *const IMediaBreakSeekedOverEventArgs_Vtbl)).OldPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewPosition(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakSeekedOverEventArgs", "NewPosition", 8usize, |this| ((**(this as *const *const IMediaBreakSeekedOverEventArgs_Vtbl)).NewPosition)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SeekedOverBreaks,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SeekedOverBreaks: 0,
+            OldPosition,
+            NewPosition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaBreakSeekedOverEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaBreakSkippedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MediaBreak(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakSkippedEventArgs", "MediaBreak", 6usize, |this| ((**(this as *const *const IMediaBreakSkippedEventArgs_Vtbl)).MediaBreak)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MediaBreak }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaBreakSkippedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaBreakStartedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MediaBreak(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBreakStartedEventArgs", "MediaBreak", 6usize, |this| ((**(this as *const *const IMediaBreakStartedEventArgs_Vtbl)).MediaBreak)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MediaBreak }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaBreakStartedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IMediaEnginePlaybackSource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CurrentItem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEnginePlaybackSource", "CurrentItem", 6usize, |this| ((**(this as *const *const IMediaEnginePlaybackSource_Vtbl)).CurrentItem)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetPlaybackSource(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEnginePlaybackSource", "SetPlaybackSource", 7usize, |this| ((**(this as *const *const IMediaEnginePlaybackSource_Vtbl)).SetPlaybackSource)(this, source), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CurrentItem,
+            #[cfg(not(feature = "deprecated"))]
+            CurrentItem: 0,
+            #[cfg(feature = "deprecated")]
+            SetPlaybackSource,
+            #[cfg(not(feature = "deprecated"))]
+            SetPlaybackSource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEnginePlaybackSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMediaItemDisplayProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut super::MediaPlaybackType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaItemDisplayProperties", "Type", 6usize, |this| ((**(this as *const *const IMediaItemDisplayProperties_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, value: super::MediaPlaybackType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaItemDisplayProperties", "SetType", 7usize, |this| ((**(this as *const *const IMediaItemDisplayProperties_Vtbl)).SetType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MusicProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaItemDisplayProperties", "MusicProperties", 8usize, |this| ((**(this as *const *const IMediaItemDisplayProperties_Vtbl)).MusicProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaItemDisplayProperties", "VideoProperties", 9usize, |this| ((**(this as *const *const IMediaItemDisplayProperties_Vtbl)).VideoProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaItemDisplayProperties", "Thumbnail", 10usize, |this| ((**(this as *const *const IMediaItemDisplayProperties_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetThumbnail(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaItemDisplayProperties", "SetThumbnail", 11usize, |this| ((**(this as *const *const IMediaItemDisplayProperties_Vtbl)).SetThumbnail)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaItemDisplayProperties", "ClearAll", 12usize, |this| ((**(this as *const *const IMediaItemDisplayProperties_Vtbl)).ClearAll)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            SetType,
+            MusicProperties,
+            VideoProperties,
+            #[cfg(feature = "Storage_Streams")]
+            Thumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Thumbnail: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetThumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetThumbnail: 0,
+            ClearAll,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaItemDisplayProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackCommandManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "IsEnabled", 6usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "SetIsEnabled", 7usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaPlayer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "MediaPlayer", 8usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).MediaPlayer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayBehavior(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "PlayBehavior", 9usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).PlayBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PauseBehavior(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "PauseBehavior", 10usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).PauseBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextBehavior(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "NextBehavior", 11usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).NextBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreviousBehavior(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "PreviousBehavior", 12usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).PreviousBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FastForwardBehavior(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "FastForwardBehavior", 13usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).FastForwardBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RewindBehavior(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "RewindBehavior", 14usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).RewindBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShuffleBehavior(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "ShuffleBehavior", 15usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).ShuffleBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoRepeatModeBehavior(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "AutoRepeatModeBehavior", 16usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).AutoRepeatModeBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionBehavior(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "PositionBehavior", 17usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).PositionBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RateBehavior(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "RateBehavior", 18usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).RateBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "PlayReceived", 19usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).PlayReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePlayReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "RemovePlayReceived", 20usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).RemovePlayReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PauseReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "PauseReceived", 21usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).PauseReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePauseReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "RemovePauseReceived", 22usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).RemovePauseReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "NextReceived", 23usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).NextReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNextReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "RemoveNextReceived", 24usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).RemoveNextReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreviousReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "PreviousReceived", 25usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).PreviousReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePreviousReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "RemovePreviousReceived", 26usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).RemovePreviousReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn FastForwardReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "FastForwardReceived", 27usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).FastForwardReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFastForwardReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "RemoveFastForwardReceived", 28usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).RemoveFastForwardReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RewindReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "RewindReceived", 29usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).RewindReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRewindReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "RemoveRewindReceived", 30usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).RemoveRewindReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShuffleReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "ShuffleReceived", 31usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).ShuffleReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveShuffleReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "RemoveShuffleReceived", 32usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).RemoveShuffleReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoRepeatModeReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "AutoRepeatModeReceived", 33usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).AutoRepeatModeReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAutoRepeatModeReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "RemoveAutoRepeatModeReceived", 34usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).RemoveAutoRepeatModeReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "PositionReceived", 35usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).PositionReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePositionReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "RemovePositionReceived", 36usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).RemovePositionReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RateReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "RateReceived", 37usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).RateReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRateReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManager", "RemoveRateReceived", 38usize, |this| ((**(this as *const *const IMediaPlaybackCommandManager_Vtbl)).RemoveRateReceived)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEnabled,
+            SetIsEnabled,
+            MediaPlayer,
+            PlayBehavior,
+            PauseBehavior,
+            NextBehavior,
+            PreviousBehavior,
+            FastForwardBehavior,
+            RewindBehavior,
+            ShuffleBehavior,
+            AutoRepeatModeBehavior,
+            PositionBehavior,
+            RateBehavior,
+            PlayReceived,
+            RemovePlayReceived,
+            PauseReceived,
+            RemovePauseReceived,
+            NextReceived,
+            RemoveNextReceived,
+            PreviousReceived,
+            RemovePreviousReceived,
+            FastForwardReceived,
+            RemoveFastForwardReceived,
+            RewindReceived,
+            RemoveRewindReceived,
+            ShuffleReceived,
+            RemoveShuffleReceived,
+            AutoRepeatModeReceived,
+            RemoveAutoRepeatModeReceived,
+            PositionReceived,
+            RemovePositionReceived,
+            RateReceived,
+            RemoveRateReceived,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackCommandManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackCommandManagerAutoRepeatModeReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerAutoRepeatModeReceivedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerAutoRepeatModeReceivedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerAutoRepeatModeReceivedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerAutoRepeatModeReceivedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoRepeatMode(this: *mut core::ffi::c_void, result__: *mut super::MediaPlaybackAutoRepeatMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerAutoRepeatModeReceivedEventArgs", "AutoRepeatMode", 8usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerAutoRepeatModeReceivedEventArgs_Vtbl)).AutoRepeatMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerAutoRepeatModeReceivedEventArgs", "GetDeferral", 9usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerAutoRepeatModeReceivedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, AutoRepeatMode, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackCommandManagerAutoRepeatModeReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackCommandManagerCommandBehavior_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CommandManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerCommandBehavior", "CommandManager", 6usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerCommandBehavior_Vtbl)).CommandManager)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerCommandBehavior", "IsEnabled", 7usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerCommandBehavior_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnablingRule(this: *mut core::ffi::c_void, result__: *mut MediaCommandEnablingRule) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerCommandBehavior", "EnablingRule", 8usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerCommandBehavior_Vtbl)).EnablingRule)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnablingRule(this: *mut core::ffi::c_void, value: MediaCommandEnablingRule) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerCommandBehavior", "SetEnablingRule", 9usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerCommandBehavior_Vtbl)).SetEnablingRule)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabledChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerCommandBehavior", "IsEnabledChanged", 10usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerCommandBehavior_Vtbl)).IsEnabledChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveIsEnabledChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerCommandBehavior", "RemoveIsEnabledChanged", 11usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerCommandBehavior_Vtbl)).RemoveIsEnabledChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CommandManager,
+            IsEnabled,
+            EnablingRule,
+            SetEnablingRule,
+            IsEnabledChanged,
+            RemoveIsEnabledChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackCommandManagerCommandBehavior as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackCommandManagerFastForwardReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerFastForwardReceivedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerFastForwardReceivedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerFastForwardReceivedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerFastForwardReceivedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerFastForwardReceivedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerFastForwardReceivedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackCommandManagerFastForwardReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackCommandManagerNextReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerNextReceivedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerNextReceivedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerNextReceivedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerNextReceivedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerNextReceivedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerNextReceivedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackCommandManagerNextReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackCommandManagerPauseReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerPauseReceivedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerPauseReceivedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerPauseReceivedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerPauseReceivedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerPauseReceivedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerPauseReceivedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackCommandManagerPauseReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackCommandManagerPlayReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerPlayReceivedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerPlayReceivedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerPlayReceivedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerPlayReceivedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerPlayReceivedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerPlayReceivedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackCommandManagerPlayReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackCommandManagerPositionReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerPositionReceivedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerPositionReceivedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerPositionReceivedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerPositionReceivedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerPositionReceivedEventArgs", "Position", 8usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerPositionReceivedEventArgs_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerPositionReceivedEventArgs", "GetDeferral", 9usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerPositionReceivedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, Position, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackCommandManagerPositionReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackCommandManagerPreviousReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerPreviousReceivedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerPreviousReceivedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerPreviousReceivedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerPreviousReceivedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerPreviousReceivedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerPreviousReceivedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackCommandManagerPreviousReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackCommandManagerRateReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerRateReceivedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerRateReceivedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerRateReceivedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerRateReceivedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackRate(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerRateReceivedEventArgs", "PlaybackRate", 8usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerRateReceivedEventArgs_Vtbl)).PlaybackRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerRateReceivedEventArgs", "GetDeferral", 9usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerRateReceivedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, PlaybackRate, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackCommandManagerRateReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackCommandManagerRewindReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerRewindReceivedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerRewindReceivedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerRewindReceivedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerRewindReceivedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerRewindReceivedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerRewindReceivedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackCommandManagerRewindReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackCommandManagerShuffleReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerShuffleReceivedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerShuffleReceivedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerShuffleReceivedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerShuffleReceivedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsShuffleRequested(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerShuffleReceivedEventArgs", "IsShuffleRequested", 8usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerShuffleReceivedEventArgs_Vtbl)).IsShuffleRequested)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackCommandManagerShuffleReceivedEventArgs", "GetDeferral", 9usize, |this| ((**(this as *const *const IMediaPlaybackCommandManagerShuffleReceivedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, IsShuffleRequested, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackCommandManagerShuffleReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+impl windows_core::imp::ProxyVtable for IMediaPlaybackItem_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AudioTracksChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem", "AudioTracksChanged", 6usize, |this| ((**(this as *const *const IMediaPlaybackItem_Vtbl)).AudioTracksChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAudioTracksChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem", "RemoveAudioTracksChanged", 7usize, |this| ((**(this as *const *const IMediaPlaybackItem_Vtbl)).RemoveAudioTracksChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn VideoTracksChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem", "VideoTracksChanged", 8usize, |this| ((**(this as *const *const IMediaPlaybackItem_Vtbl)).VideoTracksChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVideoTracksChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem", "RemoveVideoTracksChanged", 9usize, |this| ((**(this as *const *const IMediaPlaybackItem_Vtbl)).RemoveVideoTracksChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TimedMetadataTracksChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem", "TimedMetadataTracksChanged", 10usize, |this| ((**(this as *const *const IMediaPlaybackItem_Vtbl)).TimedMetadataTracksChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTimedMetadataTracksChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem", "RemoveTimedMetadataTracksChanged", 11usize, |this| ((**(this as *const *const IMediaPlaybackItem_Vtbl)).RemoveTimedMetadataTracksChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem", "Source", 12usize, |this| ((**(this as *const *const IMediaPlaybackItem_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+        unsafe extern "system" fn AudioTracks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem", "AudioTracks", 13usize, |this| ((**(this as *const *const IMediaPlaybackItem_Vtbl)).AudioTracks)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+        unsafe extern "system" fn VideoTracks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem", "VideoTracks", 14usize, |this| ((**(this as *const *const IMediaPlaybackItem_Vtbl)).VideoTracks)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+        unsafe extern "system" fn TimedMetadataTracks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem", "TimedMetadataTracks", 15usize, |this| ((**(this as *const *const IMediaPlaybackItem_Vtbl)).TimedMetadataTracks)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AudioTracksChanged,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AudioTracksChanged: 0,
+            RemoveAudioTracksChanged,
+            #[cfg(feature = "Foundation_Collections")]
+            VideoTracksChanged,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            VideoTracksChanged: 0,
+            RemoveVideoTracksChanged,
+            #[cfg(feature = "Foundation_Collections")]
+            TimedMetadataTracksChanged,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TimedMetadataTracksChanged: 0,
+            RemoveTimedMetadataTracksChanged,
+            #[cfg(feature = "Media_Core")]
+            Source,
+            #[cfg(not(feature = "Media_Core"))]
+            Source: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+            AudioTracks,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Core")))]
+            AudioTracks: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+            VideoTracks,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Core")))]
+            VideoTracks: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+            TimedMetadataTracks,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Core")))]
+            TimedMetadataTracks: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+impl windows_core::imp::ProxyVtable for IMediaPlaybackItem2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BreakSchedule(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem2", "BreakSchedule", 6usize, |this| ((**(this as *const *const IMediaPlaybackItem2_Vtbl)).BreakSchedule)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem2", "StartTime", 7usize, |this| ((**(this as *const *const IMediaPlaybackItem2_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DurationLimit(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem2", "DurationLimit", 8usize, |this| ((**(this as *const *const IMediaPlaybackItem2_Vtbl)).DurationLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanSkip(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem2", "CanSkip", 9usize, |this| ((**(this as *const *const IMediaPlaybackItem2_Vtbl)).CanSkip)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanSkip(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem2", "SetCanSkip", 10usize, |this| ((**(this as *const *const IMediaPlaybackItem2_Vtbl)).SetCanSkip)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem2", "GetDisplayProperties", 11usize, |this| ((**(this as *const *const IMediaPlaybackItem2_Vtbl)).GetDisplayProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyDisplayProperties(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem2", "ApplyDisplayProperties", 12usize, |this| ((**(this as *const *const IMediaPlaybackItem2_Vtbl)).ApplyDisplayProperties)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BreakSchedule,
+            StartTime,
+            DurationLimit,
+            CanSkip,
+            SetCanSkip,
+            GetDisplayProperties,
+            ApplyDisplayProperties,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackItem2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+impl windows_core::imp::ProxyVtable for IMediaPlaybackItem3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDisabledInPlaybackList(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem3", "IsDisabledInPlaybackList", 6usize, |this| ((**(this as *const *const IMediaPlaybackItem3_Vtbl)).IsDisabledInPlaybackList)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsDisabledInPlaybackList(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem3", "SetIsDisabledInPlaybackList", 7usize, |this| ((**(this as *const *const IMediaPlaybackItem3_Vtbl)).SetIsDisabledInPlaybackList)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalDownloadProgress(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem3", "TotalDownloadProgress", 8usize, |this| ((**(this as *const *const IMediaPlaybackItem3_Vtbl)).TotalDownloadProgress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoLoadedDisplayProperties(this: *mut core::ffi::c_void, result__: *mut AutoLoadedDisplayPropertyKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem3", "AutoLoadedDisplayProperties", 9usize, |this| ((**(this as *const *const IMediaPlaybackItem3_Vtbl)).AutoLoadedDisplayProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoLoadedDisplayProperties(this: *mut core::ffi::c_void, value: AutoLoadedDisplayPropertyKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItem3", "SetAutoLoadedDisplayProperties", 10usize, |this| ((**(this as *const *const IMediaPlaybackItem3_Vtbl)).SetAutoLoadedDisplayProperties)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsDisabledInPlaybackList,
+            SetIsDisabledInPlaybackList,
+            TotalDownloadProgress,
+            AutoLoadedDisplayProperties,
+            SetAutoLoadedDisplayProperties,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackItem3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackItemError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut MediaPlaybackItemErrorCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItemError", "ErrorCode", 6usize, |this| ((**(this as *const *const IMediaPlaybackItemError_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItemError", "ExtendedError", 7usize, |this| ((**(this as *const *const IMediaPlaybackItemError_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ErrorCode, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackItemError as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Core")]
+impl windows_core::imp::ProxyVtable for IMediaPlaybackItemFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItemFactory", "Create", 6usize, |this| ((**(this as *const *const IMediaPlaybackItemFactory_Vtbl)).Create)(this, source, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Core")]
+            Create,
+            #[cfg(not(feature = "Media_Core"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackItemFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Core")]
+impl windows_core::imp::ProxyVtable for IMediaPlaybackItemFactory2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn CreateWithStartTime(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, starttime: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItemFactory2", "CreateWithStartTime", 6usize, |this| ((**(this as *const *const IMediaPlaybackItemFactory2_Vtbl)).CreateWithStartTime)(this, source, starttime, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn CreateWithStartTimeAndDurationLimit(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, starttime: super::super::Foundation::TimeSpan, durationlimit: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItemFactory2", "CreateWithStartTimeAndDurationLimit", 7usize, |this| ((**(this as *const *const IMediaPlaybackItemFactory2_Vtbl)).CreateWithStartTimeAndDurationLimit)(this, source, starttime, durationlimit, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Core")]
+            CreateWithStartTime,
+            #[cfg(not(feature = "Media_Core"))]
+            CreateWithStartTime: 0,
+            #[cfg(feature = "Media_Core")]
+            CreateWithStartTimeAndDurationLimit,
+            #[cfg(not(feature = "Media_Core"))]
+            CreateWithStartTimeAndDurationLimit: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackItemFactory2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackItemFailedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItemFailedEventArgs", "Item", 6usize, |this| ((**(this as *const *const IMediaPlaybackItemFailedEventArgs_Vtbl)).Item)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItemFailedEventArgs", "Error", 7usize, |this| ((**(this as *const *const IMediaPlaybackItemFailedEventArgs_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Item, Error }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackItemFailedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackItemOpenedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItemOpenedEventArgs", "Item", 6usize, |this| ((**(this as *const *const IMediaPlaybackItemOpenedEventArgs_Vtbl)).Item)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Item }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackItemOpenedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Core")]
+impl windows_core::imp::ProxyVtable for IMediaPlaybackItemStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn FindFromMediaSource(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackItemStatics", "FindFromMediaSource", 6usize, |this| ((**(this as *const *const IMediaPlaybackItemStatics_Vtbl)).FindFromMediaSource)(this, source, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Core")]
+            FindFromMediaSource,
+            #[cfg(not(feature = "Media_Core"))]
+            FindFromMediaSource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackItemStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaPlaybackList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ItemFailed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "ItemFailed", 6usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).ItemFailed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItemFailed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "RemoveItemFailed", 7usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).RemoveItemFailed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentItemChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "CurrentItemChanged", 8usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).CurrentItemChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCurrentItemChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "RemoveCurrentItemChanged", 9usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).RemoveCurrentItemChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemOpened(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "ItemOpened", 10usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).ItemOpened)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItemOpened(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "RemoveItemOpened", 11usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).RemoveItemOpened)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Items(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "Items", 12usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).Items)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoRepeatEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "AutoRepeatEnabled", 13usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).AutoRepeatEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoRepeatEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "SetAutoRepeatEnabled", 14usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).SetAutoRepeatEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShuffleEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "ShuffleEnabled", 15usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).ShuffleEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShuffleEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "SetShuffleEnabled", 16usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).SetShuffleEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentItem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "CurrentItem", 17usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).CurrentItem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentItemIndex(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "CurrentItemIndex", 18usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).CurrentItemIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "MoveNext", 19usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).MoveNext)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MovePrevious(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "MovePrevious", 20usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).MovePrevious)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveTo(this: *mut core::ffi::c_void, itemindex: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList", "MoveTo", 21usize, |this| ((**(this as *const *const IMediaPlaybackList_Vtbl)).MoveTo)(this, itemindex, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ItemFailed,
+            RemoveItemFailed,
+            CurrentItemChanged,
+            RemoveCurrentItemChanged,
+            ItemOpened,
+            RemoveItemOpened,
+            #[cfg(feature = "Foundation_Collections")]
+            Items,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Items: 0,
+            AutoRepeatEnabled,
+            SetAutoRepeatEnabled,
+            ShuffleEnabled,
+            SetShuffleEnabled,
+            CurrentItem,
+            CurrentItemIndex,
+            MoveNext,
+            MovePrevious,
+            MoveTo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackList as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaPlaybackList2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxPrefetchTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList2", "MaxPrefetchTime", 6usize, |this| ((**(this as *const *const IMediaPlaybackList2_Vtbl)).MaxPrefetchTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxPrefetchTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList2", "SetMaxPrefetchTime", 7usize, |this| ((**(this as *const *const IMediaPlaybackList2_Vtbl)).SetMaxPrefetchTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartingItem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList2", "StartingItem", 8usize, |this| ((**(this as *const *const IMediaPlaybackList2_Vtbl)).StartingItem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartingItem(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList2", "SetStartingItem", 9usize, |this| ((**(this as *const *const IMediaPlaybackList2_Vtbl)).SetStartingItem)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ShuffledItems(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList2", "ShuffledItems", 10usize, |this| ((**(this as *const *const IMediaPlaybackList2_Vtbl)).ShuffledItems)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetShuffledItems(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList2", "SetShuffledItems", 11usize, |this| ((**(this as *const *const IMediaPlaybackList2_Vtbl)).SetShuffledItems)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaxPrefetchTime,
+            SetMaxPrefetchTime,
+            StartingItem,
+            SetStartingItem,
+            #[cfg(feature = "Foundation_Collections")]
+            ShuffledItems,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ShuffledItems: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetShuffledItems,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetShuffledItems: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackList2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaPlaybackList3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxPlayedItemsToKeepOpen(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList3", "MaxPlayedItemsToKeepOpen", 6usize, |this| ((**(this as *const *const IMediaPlaybackList3_Vtbl)).MaxPlayedItemsToKeepOpen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxPlayedItemsToKeepOpen(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackList3", "SetMaxPlayedItemsToKeepOpen", 7usize, |this| ((**(this as *const *const IMediaPlaybackList3_Vtbl)).SetMaxPlayedItemsToKeepOpen)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MaxPlayedItemsToKeepOpen, SetMaxPlayedItemsToKeepOpen }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackList3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_MediaProperties")]
+impl windows_core::imp::ProxyVtable for IMediaPlaybackSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PlaybackStateChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "PlaybackStateChanged", 6usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).PlaybackStateChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePlaybackStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "RemovePlaybackStateChanged", 7usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).RemovePlaybackStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackRateChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "PlaybackRateChanged", 8usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).PlaybackRateChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePlaybackRateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "RemovePlaybackRateChanged", 9usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).RemovePlaybackRateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeekCompleted(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "SeekCompleted", 10usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).SeekCompleted)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSeekCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "RemoveSeekCompleted", 11usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).RemoveSeekCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn BufferingStarted(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "BufferingStarted", 12usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).BufferingStarted)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBufferingStarted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "RemoveBufferingStarted", 13usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).RemoveBufferingStarted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn BufferingEnded(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "BufferingEnded", 14usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).BufferingEnded)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBufferingEnded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "RemoveBufferingEnded", 15usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).RemoveBufferingEnded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn BufferingProgressChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "BufferingProgressChanged", 16usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).BufferingProgressChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBufferingProgressChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "RemoveBufferingProgressChanged", 17usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).RemoveBufferingProgressChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadProgressChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "DownloadProgressChanged", 18usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).DownloadProgressChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDownloadProgressChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "RemoveDownloadProgressChanged", 19usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).RemoveDownloadProgressChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn NaturalDurationChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "NaturalDurationChanged", 20usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).NaturalDurationChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNaturalDurationChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "RemoveNaturalDurationChanged", 21usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).RemoveNaturalDurationChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "PositionChanged", 22usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).PositionChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePositionChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "RemovePositionChanged", 23usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).RemovePositionChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn NaturalVideoSizeChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "NaturalVideoSizeChanged", 24usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).NaturalVideoSizeChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNaturalVideoSizeChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "RemoveNaturalVideoSizeChanged", 25usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).RemoveNaturalVideoSizeChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaPlayer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "MediaPlayer", 26usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).MediaPlayer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NaturalDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "NaturalDuration", 27usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).NaturalDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "Position", 28usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "SetPosition", 29usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).SetPosition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackState(this: *mut core::ffi::c_void, result__: *mut MediaPlaybackState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "PlaybackState", 30usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).PlaybackState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanSeek(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "CanSeek", 31usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).CanSeek)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanPause(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "CanPause", 32usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).CanPause)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsProtected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "IsProtected", 33usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).IsProtected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackRate(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "PlaybackRate", 34usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).PlaybackRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlaybackRate(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "SetPlaybackRate", 35usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).SetPlaybackRate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BufferingProgress(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "BufferingProgress", 36usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).BufferingProgress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadProgress(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "DownloadProgress", 37usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).DownloadProgress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NaturalVideoHeight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "NaturalVideoHeight", 38usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).NaturalVideoHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NaturalVideoWidth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "NaturalVideoWidth", 39usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).NaturalVideoWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NormalizedSourceRect(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "NormalizedSourceRect", 40usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).NormalizedSourceRect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNormalizedSourceRect(this: *mut core::ffi::c_void, value: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "SetNormalizedSourceRect", 41usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).SetNormalizedSourceRect)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn StereoscopicVideoPackingMode(this: *mut core::ffi::c_void, result__: *mut super::MediaProperties::StereoscopicVideoPackingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "StereoscopicVideoPackingMode", 42usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).StereoscopicVideoPackingMode)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn SetStereoscopicVideoPackingMode(this: *mut core::ffi::c_void, value: super::MediaProperties::StereoscopicVideoPackingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession", "SetStereoscopicVideoPackingMode", 43usize, |this| ((**(this as *const *const IMediaPlaybackSession_Vtbl)).SetStereoscopicVideoPackingMode)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PlaybackStateChanged,
+            RemovePlaybackStateChanged,
+            PlaybackRateChanged,
+            RemovePlaybackRateChanged,
+            SeekCompleted,
+            RemoveSeekCompleted,
+            BufferingStarted,
+            RemoveBufferingStarted,
+            BufferingEnded,
+            RemoveBufferingEnded,
+            BufferingProgressChanged,
+            RemoveBufferingProgressChanged,
+            DownloadProgressChanged,
+            RemoveDownloadProgressChanged,
+            NaturalDurationChanged,
+            RemoveNaturalDurationChanged,
+            PositionChanged,
+            RemovePositionChanged,
+            NaturalVideoSizeChanged,
+            RemoveNaturalVideoSizeChanged,
+            MediaPlayer,
+            NaturalDuration,
+            Position,
+            SetPosition,
+            PlaybackState,
+            CanSeek,
+            CanPause,
+            IsProtected,
+            PlaybackRate,
+            SetPlaybackRate,
+            BufferingProgress,
+            DownloadProgress,
+            NaturalVideoHeight,
+            NaturalVideoWidth,
+            NormalizedSourceRect,
+            SetNormalizedSourceRect,
+            #[cfg(feature = "Media_MediaProperties")]
+            StereoscopicVideoPackingMode,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            StereoscopicVideoPackingMode: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            SetStereoscopicVideoPackingMode,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            SetStereoscopicVideoPackingMode: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaPlaybackSession2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BufferedRangesChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession2", "BufferedRangesChanged", 6usize, |this| ((**(this as *const *const IMediaPlaybackSession2_Vtbl)).BufferedRangesChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBufferedRangesChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession2", "RemoveBufferedRangesChanged", 7usize, |this| ((**(this as *const *const IMediaPlaybackSession2_Vtbl)).RemoveBufferedRangesChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayedRangesChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession2", "PlayedRangesChanged", 8usize, |this| ((**(this as *const *const IMediaPlaybackSession2_Vtbl)).PlayedRangesChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePlayedRangesChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession2", "RemovePlayedRangesChanged", 9usize, |this| ((**(this as *const *const IMediaPlaybackSession2_Vtbl)).RemovePlayedRangesChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeekableRangesChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession2", "SeekableRangesChanged", 10usize, |this| ((**(this as *const *const IMediaPlaybackSession2_Vtbl)).SeekableRangesChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSeekableRangesChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession2", "RemoveSeekableRangesChanged", 11usize, |this| ((**(this as *const *const IMediaPlaybackSession2_Vtbl)).RemoveSeekableRangesChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedPlaybackRatesChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession2", "SupportedPlaybackRatesChanged", 12usize, |this| ((**(this as *const *const IMediaPlaybackSession2_Vtbl)).SupportedPlaybackRatesChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSupportedPlaybackRatesChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession2", "RemoveSupportedPlaybackRatesChanged", 13usize, |this| ((**(this as *const *const IMediaPlaybackSession2_Vtbl)).RemoveSupportedPlaybackRatesChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SphericalVideoProjection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession2", "SphericalVideoProjection", 14usize, |this| ((**(this as *const *const IMediaPlaybackSession2_Vtbl)).SphericalVideoProjection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMirroring(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession2", "IsMirroring", 15usize, |this| ((**(this as *const *const IMediaPlaybackSession2_Vtbl)).IsMirroring)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsMirroring(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession2", "SetIsMirroring", 16usize, |this| ((**(this as *const *const IMediaPlaybackSession2_Vtbl)).SetIsMirroring)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetBufferedRanges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession2", "GetBufferedRanges", 17usize, |this| ((**(this as *const *const IMediaPlaybackSession2_Vtbl)).GetBufferedRanges)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetPlayedRanges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession2", "GetPlayedRanges", 18usize, |this| ((**(this as *const *const IMediaPlaybackSession2_Vtbl)).GetPlayedRanges)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSeekableRanges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession2", "GetSeekableRanges", 19usize, |this| ((**(this as *const *const IMediaPlaybackSession2_Vtbl)).GetSeekableRanges)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupportedPlaybackRateRange(this: *mut core::ffi::c_void, rate1: f64, rate2: f64, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession2", "IsSupportedPlaybackRateRange", 20usize, |this| ((**(this as *const *const IMediaPlaybackSession2_Vtbl)).IsSupportedPlaybackRateRange)(this, rate1, rate2, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BufferedRangesChanged,
+            RemoveBufferedRangesChanged,
+            PlayedRangesChanged,
+            RemovePlayedRangesChanged,
+            SeekableRangesChanged,
+            RemoveSeekableRangesChanged,
+            SupportedPlaybackRatesChanged,
+            RemoveSupportedPlaybackRatesChanged,
+            SphericalVideoProjection,
+            IsMirroring,
+            SetIsMirroring,
+            #[cfg(feature = "Foundation_Collections")]
+            GetBufferedRanges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetBufferedRanges: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetPlayedRanges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetPlayedRanges: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSeekableRanges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSeekableRanges: 0,
+            IsSupportedPlaybackRateRange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackSession2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_MediaProperties")]
+impl windows_core::imp::ProxyVtable for IMediaPlaybackSession3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn PlaybackRotation(this: *mut core::ffi::c_void, result__: *mut super::MediaProperties::MediaRotation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession3", "PlaybackRotation", 6usize, |this| ((**(this as *const *const IMediaPlaybackSession3_Vtbl)).PlaybackRotation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn SetPlaybackRotation(this: *mut core::ffi::c_void, value: super::MediaProperties::MediaRotation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession3", "SetPlaybackRotation", 7usize, |this| ((**(this as *const *const IMediaPlaybackSession3_Vtbl)).SetPlaybackRotation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputDegradationPolicyState(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSession3", "GetOutputDegradationPolicyState", 8usize, |this| ((**(this as *const *const IMediaPlaybackSession3_Vtbl)).GetOutputDegradationPolicyState)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_MediaProperties")]
+            PlaybackRotation,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            PlaybackRotation: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            SetPlaybackRotation,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            SetPlaybackRotation: 0,
+            GetOutputDegradationPolicyState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackSession3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackSessionBufferingStartedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPlaybackInterruption(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSessionBufferingStartedEventArgs", "IsPlaybackInterruption", 6usize, |this| ((**(this as *const *const IMediaPlaybackSessionBufferingStartedEventArgs_Vtbl)).IsPlaybackInterruption)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsPlaybackInterruption }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackSessionBufferingStartedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackSessionOutputDegradationPolicyState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VideoConstrictionReason(this: *mut core::ffi::c_void, result__: *mut MediaPlaybackSessionVideoConstrictionReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSessionOutputDegradationPolicyState", "VideoConstrictionReason", 6usize, |this| ((**(this as *const *const IMediaPlaybackSessionOutputDegradationPolicyState_Vtbl)).VideoConstrictionReason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, VideoConstrictionReason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackSessionOutputDegradationPolicyState as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlaybackSource_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Media_MediaProperties"))]
+impl windows_core::imp::ProxyVtable for IMediaPlaybackSphericalVideoProjection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSphericalVideoProjection", "IsEnabled", 6usize, |this| ((**(this as *const *const IMediaPlaybackSphericalVideoProjection_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSphericalVideoProjection", "SetIsEnabled", 7usize, |this| ((**(this as *const *const IMediaPlaybackSphericalVideoProjection_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn FrameFormat(this: *mut core::ffi::c_void, result__: *mut super::MediaProperties::SphericalVideoFrameFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSphericalVideoProjection", "FrameFormat", 8usize, |this| ((**(this as *const *const IMediaPlaybackSphericalVideoProjection_Vtbl)).FrameFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_MediaProperties")]
+        unsafe extern "system" fn SetFrameFormat(this: *mut core::ffi::c_void, value: super::MediaProperties::SphericalVideoFrameFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSphericalVideoProjection", "SetFrameFormat", 9usize, |this| ((**(this as *const *const IMediaPlaybackSphericalVideoProjection_Vtbl)).SetFrameFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HorizontalFieldOfViewInDegrees(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSphericalVideoProjection", "HorizontalFieldOfViewInDegrees", 10usize, |this| ((**(this as *const *const IMediaPlaybackSphericalVideoProjection_Vtbl)).HorizontalFieldOfViewInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHorizontalFieldOfViewInDegrees(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSphericalVideoProjection", "SetHorizontalFieldOfViewInDegrees", 11usize, |this| ((**(this as *const *const IMediaPlaybackSphericalVideoProjection_Vtbl)).SetHorizontalFieldOfViewInDegrees)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn ViewOrientation(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSphericalVideoProjection", "ViewOrientation", 12usize, |this| ((**(this as *const *const IMediaPlaybackSphericalVideoProjection_Vtbl)).ViewOrientation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetViewOrientation(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSphericalVideoProjection", "SetViewOrientation", 13usize, |this| ((**(this as *const *const IMediaPlaybackSphericalVideoProjection_Vtbl)).SetViewOrientation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProjectionMode(this: *mut core::ffi::c_void, result__: *mut SphericalVideoProjectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSphericalVideoProjection", "ProjectionMode", 14usize, |this| ((**(this as *const *const IMediaPlaybackSphericalVideoProjection_Vtbl)).ProjectionMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProjectionMode(this: *mut core::ffi::c_void, value: SphericalVideoProjectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackSphericalVideoProjection", "SetProjectionMode", 15usize, |this| ((**(this as *const *const IMediaPlaybackSphericalVideoProjection_Vtbl)).SetProjectionMode)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEnabled,
+            SetIsEnabled,
+            #[cfg(feature = "Media_MediaProperties")]
+            FrameFormat,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            FrameFormat: 0,
+            #[cfg(feature = "Media_MediaProperties")]
+            SetFrameFormat,
+            #[cfg(not(feature = "Media_MediaProperties"))]
+            SetFrameFormat: 0,
+            HorizontalFieldOfViewInDegrees,
+            SetHorizontalFieldOfViewInDegrees,
+            #[cfg(feature = "Foundation_Numerics")]
+            ViewOrientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            ViewOrientation: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetViewOrientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetViewOrientation: 0,
+            ProjectionMode,
+            SetProjectionMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackSphericalVideoProjection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+impl windows_core::imp::ProxyVtable for IMediaPlaybackTimedMetadataTrackList_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+        unsafe extern "system" fn PresentationModeChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackTimedMetadataTrackList", "PresentationModeChanged", 6usize, |this| ((**(this as *const *const IMediaPlaybackTimedMetadataTrackList_Vtbl)).PresentationModeChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePresentationModeChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackTimedMetadataTrackList", "RemovePresentationModeChanged", 7usize, |this| ((**(this as *const *const IMediaPlaybackTimedMetadataTrackList_Vtbl)).RemovePresentationModeChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresentationMode(this: *mut core::ffi::c_void, index: u32, result__: *mut TimedMetadataTrackPresentationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackTimedMetadataTrackList", "GetPresentationMode", 8usize, |this| ((**(this as *const *const IMediaPlaybackTimedMetadataTrackList_Vtbl)).GetPresentationMode)(this, index, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPresentationMode(this: *mut core::ffi::c_void, index: u32, value: TimedMetadataTrackPresentationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlaybackTimedMetadataTrackList", "SetPresentationMode", 9usize, |this| ((**(this as *const *const IMediaPlaybackTimedMetadataTrackList_Vtbl)).SetPresentationMode)(this, index, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+            PresentationModeChanged,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Core")))]
+            PresentationModeChanged: 0,
+            RemovePresentationModeChanged,
+            GetPresentationMode,
+            SetPresentationMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlaybackTimedMetadataTrackList as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlayer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AutoPlay(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "AutoPlay", 6usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).AutoPlay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoPlay(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "SetAutoPlay", 7usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).SetAutoPlay)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn NaturalDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "NaturalDuration", 8usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).NaturalDuration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "Position", 9usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "SetPosition", 10usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).SetPosition)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn BufferingProgress(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "BufferingProgress", 11usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).BufferingProgress)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CurrentState(this: *mut core::ffi::c_void, result__: *mut MediaPlayerState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "CurrentState", 12usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).CurrentState)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CanSeek(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "CanSeek", 13usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).CanSeek)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CanPause(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "CanPause", 14usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).CanPause)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLoopingEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "IsLoopingEnabled", 15usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).IsLoopingEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsLoopingEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "SetIsLoopingEnabled", 16usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).SetIsLoopingEnabled)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsProtected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "IsProtected", 17usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).IsProtected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMuted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "IsMuted", 18usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).IsMuted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsMuted(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "SetIsMuted", 19usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).SetIsMuted)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PlaybackRate(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "PlaybackRate", 20usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).PlaybackRate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetPlaybackRate(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "SetPlaybackRate", 21usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).SetPlaybackRate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Volume(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "Volume", 22usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).Volume)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolume(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "SetVolume", 23usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).SetVolume)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PlaybackMediaMarkers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "PlaybackMediaMarkers", 24usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).PlaybackMediaMarkers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaOpened(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "MediaOpened", 25usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).MediaOpened)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMediaOpened(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "RemoveMediaOpened", 26usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).RemoveMediaOpened)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaEnded(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "MediaEnded", 27usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).MediaEnded)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMediaEnded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "RemoveMediaEnded", 28usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).RemoveMediaEnded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaFailed(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "MediaFailed", 29usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).MediaFailed)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMediaFailed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "RemoveMediaFailed", 30usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).RemoveMediaFailed)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CurrentStateChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "CurrentStateChanged", 31usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).CurrentStateChanged)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveCurrentStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "RemoveCurrentStateChanged", 32usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).RemoveCurrentStateChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PlaybackMediaMarkerReached(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "PlaybackMediaMarkerReached", 33usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).PlaybackMediaMarkerReached)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemovePlaybackMediaMarkerReached(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "RemovePlaybackMediaMarkerReached", 34usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).RemovePlaybackMediaMarkerReached)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn MediaPlayerRateChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "MediaPlayerRateChanged", 35usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).MediaPlayerRateChanged)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveMediaPlayerRateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "RemoveMediaPlayerRateChanged", 36usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).RemoveMediaPlayerRateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumeChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "VolumeChanged", 37usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).VolumeChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVolumeChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "RemoveVolumeChanged", 38usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).RemoveVolumeChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SeekCompleted(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "SeekCompleted", 39usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).SeekCompleted)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveSeekCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "RemoveSeekCompleted", 40usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).RemoveSeekCompleted)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn BufferingStarted(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "BufferingStarted", 41usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).BufferingStarted)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveBufferingStarted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "RemoveBufferingStarted", 42usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).RemoveBufferingStarted)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn BufferingEnded(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "BufferingEnded", 43usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).BufferingEnded)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveBufferingEnded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "RemoveBufferingEnded", 44usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).RemoveBufferingEnded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Play(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "Play", 45usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).Play)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "Pause", 46usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetUriSource(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer", "SetUriSource", 47usize, |this| ((**(this as *const *const IMediaPlayer_Vtbl)).SetUriSource)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AutoPlay,
+            SetAutoPlay,
+            #[cfg(feature = "deprecated")]
+            NaturalDuration,
+            #[cfg(not(feature = "deprecated"))]
+            NaturalDuration: 0,
+            #[cfg(feature = "deprecated")]
+            Position,
+            #[cfg(not(feature = "deprecated"))]
+            Position: 0,
+            #[cfg(feature = "deprecated")]
+            SetPosition,
+            #[cfg(not(feature = "deprecated"))]
+            SetPosition: 0,
+            #[cfg(feature = "deprecated")]
+            BufferingProgress,
+            #[cfg(not(feature = "deprecated"))]
+            BufferingProgress: 0,
+            #[cfg(feature = "deprecated")]
+            CurrentState,
+            #[cfg(not(feature = "deprecated"))]
+            CurrentState: 0,
+            #[cfg(feature = "deprecated")]
+            CanSeek,
+            #[cfg(not(feature = "deprecated"))]
+            CanSeek: 0,
+            #[cfg(feature = "deprecated")]
+            CanPause,
+            #[cfg(not(feature = "deprecated"))]
+            CanPause: 0,
+            IsLoopingEnabled,
+            SetIsLoopingEnabled,
+            #[cfg(feature = "deprecated")]
+            IsProtected,
+            #[cfg(not(feature = "deprecated"))]
+            IsProtected: 0,
+            IsMuted,
+            SetIsMuted,
+            #[cfg(feature = "deprecated")]
+            PlaybackRate,
+            #[cfg(not(feature = "deprecated"))]
+            PlaybackRate: 0,
+            #[cfg(feature = "deprecated")]
+            SetPlaybackRate,
+            #[cfg(not(feature = "deprecated"))]
+            SetPlaybackRate: 0,
+            Volume,
+            SetVolume,
+            #[cfg(feature = "deprecated")]
+            PlaybackMediaMarkers,
+            #[cfg(not(feature = "deprecated"))]
+            PlaybackMediaMarkers: 0,
+            MediaOpened,
+            RemoveMediaOpened,
+            MediaEnded,
+            RemoveMediaEnded,
+            MediaFailed,
+            RemoveMediaFailed,
+            #[cfg(feature = "deprecated")]
+            CurrentStateChanged,
+            #[cfg(not(feature = "deprecated"))]
+            CurrentStateChanged: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveCurrentStateChanged,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveCurrentStateChanged: 0,
+            #[cfg(feature = "deprecated")]
+            PlaybackMediaMarkerReached,
+            #[cfg(not(feature = "deprecated"))]
+            PlaybackMediaMarkerReached: 0,
+            #[cfg(feature = "deprecated")]
+            RemovePlaybackMediaMarkerReached,
+            #[cfg(not(feature = "deprecated"))]
+            RemovePlaybackMediaMarkerReached: 0,
+            #[cfg(feature = "deprecated")]
+            MediaPlayerRateChanged,
+            #[cfg(not(feature = "deprecated"))]
+            MediaPlayerRateChanged: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveMediaPlayerRateChanged,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveMediaPlayerRateChanged: 0,
+            VolumeChanged,
+            RemoveVolumeChanged,
+            #[cfg(feature = "deprecated")]
+            SeekCompleted,
+            #[cfg(not(feature = "deprecated"))]
+            SeekCompleted: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveSeekCompleted,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveSeekCompleted: 0,
+            #[cfg(feature = "deprecated")]
+            BufferingStarted,
+            #[cfg(not(feature = "deprecated"))]
+            BufferingStarted: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveBufferingStarted,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveBufferingStarted: 0,
+            #[cfg(feature = "deprecated")]
+            BufferingEnded,
+            #[cfg(not(feature = "deprecated"))]
+            BufferingEnded: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveBufferingEnded,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveBufferingEnded: 0,
+            Play,
+            Pause,
+            #[cfg(feature = "deprecated")]
+            SetUriSource,
+            #[cfg(not(feature = "deprecated"))]
+            SetUriSource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlayer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlayer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SystemMediaTransportControls(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer2", "SystemMediaTransportControls", 6usize, |this| ((**(this as *const *const IMediaPlayer2_Vtbl)).SystemMediaTransportControls)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioCategory(this: *mut core::ffi::c_void, result__: *mut MediaPlayerAudioCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer2", "AudioCategory", 7usize, |this| ((**(this as *const *const IMediaPlayer2_Vtbl)).AudioCategory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioCategory(this: *mut core::ffi::c_void, value: MediaPlayerAudioCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer2", "SetAudioCategory", 8usize, |this| ((**(this as *const *const IMediaPlayer2_Vtbl)).SetAudioCategory)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioDeviceType(this: *mut core::ffi::c_void, result__: *mut MediaPlayerAudioDeviceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer2", "AudioDeviceType", 9usize, |this| ((**(this as *const *const IMediaPlayer2_Vtbl)).AudioDeviceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioDeviceType(this: *mut core::ffi::c_void, value: MediaPlayerAudioDeviceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer2", "SetAudioDeviceType", 10usize, |this| ((**(this as *const *const IMediaPlayer2_Vtbl)).SetAudioDeviceType)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SystemMediaTransportControls,
+            AudioCategory,
+            SetAudioCategory,
+            AudioDeviceType,
+            SetAudioDeviceType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlayer2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "Media_Casting"))]
+impl windows_core::imp::ProxyVtable for IMediaPlayer3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsMutedChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "IsMutedChanged", 6usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).IsMutedChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveIsMutedChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "RemoveIsMutedChanged", 7usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).RemoveIsMutedChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "SourceChanged", 8usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).SourceChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSourceChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "RemoveSourceChanged", 9usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).RemoveSourceChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioBalance(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "AudioBalance", 10usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).AudioBalance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioBalance(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "SetAudioBalance", 11usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).SetAudioBalance)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RealTimePlayback(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "RealTimePlayback", 12usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).RealTimePlayback)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRealTimePlayback(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "SetRealTimePlayback", 13usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).SetRealTimePlayback)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StereoscopicVideoRenderMode(this: *mut core::ffi::c_void, result__: *mut StereoscopicVideoRenderMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "StereoscopicVideoRenderMode", 14usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).StereoscopicVideoRenderMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStereoscopicVideoRenderMode(this: *mut core::ffi::c_void, value: StereoscopicVideoRenderMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "SetStereoscopicVideoRenderMode", 15usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).SetStereoscopicVideoRenderMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BreakManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "BreakManager", 16usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).BreakManager)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommandManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "CommandManager", 17usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).CommandManager)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn AudioDevice(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "AudioDevice", 18usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).AudioDevice)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn SetAudioDevice(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "SetAudioDevice", 19usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).SetAudioDevice)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimelineController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "TimelineController", 20usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).TimelineController)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimelineController(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "SetTimelineController", 21usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).SetTimelineController)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimelineControllerPositionOffset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "TimelineControllerPositionOffset", 22usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).TimelineControllerPositionOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimelineControllerPositionOffset(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "SetTimelineControllerPositionOffset", 23usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).SetTimelineControllerPositionOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackSession(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "PlaybackSession", 24usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).PlaybackSession)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StepForwardOneFrame(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "StepForwardOneFrame", 25usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).StepForwardOneFrame)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StepBackwardOneFrame(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "StepBackwardOneFrame", 26usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).StepBackwardOneFrame)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Casting")]
+        unsafe extern "system" fn GetAsCastingSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer3", "GetAsCastingSource", 27usize, |this| ((**(this as *const *const IMediaPlayer3_Vtbl)).GetAsCastingSource)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsMutedChanged,
+            RemoveIsMutedChanged,
+            SourceChanged,
+            RemoveSourceChanged,
+            AudioBalance,
+            SetAudioBalance,
+            RealTimePlayback,
+            SetRealTimePlayback,
+            StereoscopicVideoRenderMode,
+            SetStereoscopicVideoRenderMode,
+            BreakManager,
+            CommandManager,
+            #[cfg(feature = "Devices_Enumeration")]
+            AudioDevice,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            AudioDevice: 0,
+            #[cfg(feature = "Devices_Enumeration")]
+            SetAudioDevice,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            SetAudioDevice: 0,
+            TimelineController,
+            SetTimelineController,
+            TimelineControllerPositionOffset,
+            SetTimelineControllerPositionOffset,
+            PlaybackSession,
+            StepForwardOneFrame,
+            StepBackwardOneFrame,
+            #[cfg(feature = "Media_Casting")]
+            GetAsCastingSource,
+            #[cfg(not(feature = "Media_Casting"))]
+            GetAsCastingSource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlayer3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Composition")]
+impl windows_core::imp::ProxyVtable for IMediaPlayer4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSurfaceSize(this: *mut core::ffi::c_void, size: super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer4", "SetSurfaceSize", 6usize, |this| ((**(this as *const *const IMediaPlayer4_Vtbl)).SetSurfaceSize)(this, size), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Composition")]
+        unsafe extern "system" fn GetSurface(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer4", "GetSurface", 7usize, |this| ((**(this as *const *const IMediaPlayer4_Vtbl)).GetSurface)(this, compositor, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetSurfaceSize,
+            #[cfg(feature = "UI_Composition")]
+            GetSurface,
+            #[cfg(not(feature = "UI_Composition"))]
+            GetSurface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlayer4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX_Direct3D11")]
+impl windows_core::imp::ProxyVtable for IMediaPlayer5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VideoFrameAvailable(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer5", "VideoFrameAvailable", 6usize, |this| ((**(this as *const *const IMediaPlayer5_Vtbl)).VideoFrameAvailable)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVideoFrameAvailable(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer5", "RemoveVideoFrameAvailable", 7usize, |this| ((**(this as *const *const IMediaPlayer5_Vtbl)).RemoveVideoFrameAvailable)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVideoFrameServerEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer5", "IsVideoFrameServerEnabled", 8usize, |this| ((**(this as *const *const IMediaPlayer5_Vtbl)).IsVideoFrameServerEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsVideoFrameServerEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer5", "SetIsVideoFrameServerEnabled", 9usize, |this| ((**(this as *const *const IMediaPlayer5_Vtbl)).SetIsVideoFrameServerEnabled)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn CopyFrameToVideoSurface(this: *mut core::ffi::c_void, destination: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer5", "CopyFrameToVideoSurface", 10usize, |this| ((**(this as *const *const IMediaPlayer5_Vtbl)).CopyFrameToVideoSurface)(this, destination), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn CopyFrameToVideoSurfaceWithTargetRectangle(this: *mut core::ffi::c_void, destination: *mut core::ffi::c_void, targetrectangle: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer5", "CopyFrameToVideoSurfaceWithTargetRectangle", 11usize, |this| ((**(this as *const *const IMediaPlayer5_Vtbl)).CopyFrameToVideoSurfaceWithTargetRectangle)(this, destination, targetrectangle), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn CopyFrameToStereoscopicVideoSurfaces(this: *mut core::ffi::c_void, destinationlefteye: *mut core::ffi::c_void, destinationrighteye: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer5", "CopyFrameToStereoscopicVideoSurfaces", 12usize, |this| ((**(this as *const *const IMediaPlayer5_Vtbl)).CopyFrameToStereoscopicVideoSurfaces)(this, destinationlefteye, destinationrighteye), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            VideoFrameAvailable,
+            RemoveVideoFrameAvailable,
+            IsVideoFrameServerEnabled,
+            SetIsVideoFrameServerEnabled,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            CopyFrameToVideoSurface,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            CopyFrameToVideoSurface: 0,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            CopyFrameToVideoSurfaceWithTargetRectangle,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            CopyFrameToVideoSurfaceWithTargetRectangle: 0,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            CopyFrameToStereoscopicVideoSurfaces,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            CopyFrameToStereoscopicVideoSurfaces: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlayer5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX_Direct3D11")]
+impl windows_core::imp::ProxyVtable for IMediaPlayer6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SubtitleFrameChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer6", "SubtitleFrameChanged", 6usize, |this| ((**(this as *const *const IMediaPlayer6_Vtbl)).SubtitleFrameChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSubtitleFrameChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer6", "RemoveSubtitleFrameChanged", 7usize, |this| ((**(this as *const *const IMediaPlayer6_Vtbl)).RemoveSubtitleFrameChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn RenderSubtitlesToSurface(this: *mut core::ffi::c_void, destination: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer6", "RenderSubtitlesToSurface", 8usize, |this| ((**(this as *const *const IMediaPlayer6_Vtbl)).RenderSubtitlesToSurface)(this, destination, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn RenderSubtitlesToSurfaceWithTargetRectangle(this: *mut core::ffi::c_void, destination: *mut core::ffi::c_void, targetrectangle: super::super::Foundation::Rect, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer6", "RenderSubtitlesToSurfaceWithTargetRectangle", 9usize, |this| ((**(this as *const *const IMediaPlayer6_Vtbl)).RenderSubtitlesToSurfaceWithTargetRectangle)(this, destination, targetrectangle, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SubtitleFrameChanged,
+            RemoveSubtitleFrameChanged,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            RenderSubtitlesToSurface,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            RenderSubtitlesToSurface: 0,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            RenderSubtitlesToSurfaceWithTargetRectangle,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            RenderSubtitlesToSurfaceWithTargetRectangle: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlayer6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Audio")]
+impl windows_core::imp::ProxyVtable for IMediaPlayer7_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Audio")]
+        unsafe extern "system" fn AudioStateMonitor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayer7", "AudioStateMonitor", 6usize, |this| ((**(this as *const *const IMediaPlayer7_Vtbl)).AudioStateMonitor)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Audio")]
+            AudioStateMonitor,
+            #[cfg(not(feature = "Media_Audio"))]
+            AudioStateMonitor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlayer7 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaPlayerDataReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerDataReceivedEventArgs", "Data", 6usize, |this| ((**(this as *const *const IMediaPlayerDataReceivedEventArgs_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Data,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Data: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlayerDataReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaPlayerEffects_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AddAudioEffect(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, effectoptional: bool, configuration: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerEffects", "AddAudioEffect", 6usize, |this| ((**(this as *const *const IMediaPlayerEffects_Vtbl)).AddAudioEffect)(this, activatableclassid, effectoptional, configuration), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllEffects(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerEffects", "RemoveAllEffects", 7usize, |this| ((**(this as *const *const IMediaPlayerEffects_Vtbl)).RemoveAllEffects)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AddAudioEffect,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AddAudioEffect: 0,
+            RemoveAllEffects,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlayerEffects as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaPlayerEffects2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AddVideoEffect(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, effectoptional: bool, effectconfiguration: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerEffects2", "AddVideoEffect", 6usize, |this| ((**(this as *const *const IMediaPlayerEffects2_Vtbl)).AddVideoEffect)(this, activatableclassid, effectoptional, effectconfiguration), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AddVideoEffect,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AddVideoEffect: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlayerEffects2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlayerFailedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut MediaPlayerError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerFailedEventArgs", "Error", 6usize, |this| ((**(this as *const *const IMediaPlayerFailedEventArgs_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedErrorCode(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerFailedEventArgs", "ExtendedErrorCode", 7usize, |this| ((**(this as *const *const IMediaPlayerFailedEventArgs_Vtbl)).ExtendedErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorMessage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerFailedEventArgs", "ErrorMessage", 8usize, |this| ((**(this as *const *const IMediaPlayerFailedEventArgs_Vtbl)).ErrorMessage)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Error, ExtendedErrorCode, ErrorMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlayerFailedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlayerRateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NewRate(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerRateChangedEventArgs", "NewRate", 6usize, |this| ((**(this as *const *const IMediaPlayerRateChangedEventArgs_Vtbl)).NewRate)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NewRate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlayerRateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Core", feature = "Media_Protection", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMediaPlayerSource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Protection")]
+        unsafe extern "system" fn ProtectionManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerSource", "ProtectionManager", 6usize, |this| ((**(this as *const *const IMediaPlayerSource_Vtbl)).ProtectionManager)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Media_Protection")]
+        unsafe extern "system" fn SetProtectionManager(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerSource", "SetProtectionManager", 7usize, |this| ((**(this as *const *const IMediaPlayerSource_Vtbl)).SetProtectionManager)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage", feature = "deprecated"))]
+        unsafe extern "system" fn SetFileSource(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerSource", "SetFileSource", 8usize, |this| ((**(this as *const *const IMediaPlayerSource_Vtbl)).SetFileSource)(this, file), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn SetStreamSource(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerSource", "SetStreamSource", 9usize, |this| ((**(this as *const *const IMediaPlayerSource_Vtbl)).SetStreamSource)(this, stream), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_Core", feature = "deprecated"))]
+        unsafe extern "system" fn SetMediaSource(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerSource", "SetMediaSource", 10usize, |this| ((**(this as *const *const IMediaPlayerSource_Vtbl)).SetMediaSource)(this, source), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Protection")]
+            ProtectionManager,
+            #[cfg(not(feature = "Media_Protection"))]
+            ProtectionManager: 0,
+            #[cfg(feature = "Media_Protection")]
+            SetProtectionManager,
+            #[cfg(not(feature = "Media_Protection"))]
+            SetProtectionManager: 0,
+            #[cfg(all(feature = "Storage", feature = "deprecated"))]
+            SetFileSource,
+            #[cfg(not(all(feature = "Storage", feature = "deprecated")))]
+            SetFileSource: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            SetStreamSource,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            SetStreamSource: 0,
+            #[cfg(all(feature = "Media_Core", feature = "deprecated"))]
+            SetMediaSource,
+            #[cfg(not(all(feature = "Media_Core", feature = "deprecated")))]
+            SetMediaSource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlayerSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaPlayerSource2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerSource2", "Source", 6usize, |this| ((**(this as *const *const IMediaPlayerSource2_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerSource2", "SetSource", 7usize, |this| ((**(this as *const *const IMediaPlayerSource2_Vtbl)).SetSource)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Source, SetSource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlayerSource2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Composition")]
+impl windows_core::imp::ProxyVtable for IMediaPlayerSurface_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Composition")]
+        unsafe extern "system" fn CompositionSurface(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerSurface", "CompositionSurface", 6usize, |this| ((**(this as *const *const IMediaPlayerSurface_Vtbl)).CompositionSurface)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Composition")]
+        unsafe extern "system" fn Compositor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerSurface", "Compositor", 7usize, |this| ((**(this as *const *const IMediaPlayerSurface_Vtbl)).Compositor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaPlayer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPlayerSurface", "MediaPlayer", 8usize, |this| ((**(this as *const *const IMediaPlayerSurface_Vtbl)).MediaPlayer)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Composition")]
+            CompositionSurface,
+            #[cfg(not(feature = "UI_Composition"))]
+            CompositionSurface: 0,
+            #[cfg(feature = "UI_Composition")]
+            Compositor,
+            #[cfg(not(feature = "UI_Composition"))]
+            Compositor: 0,
+            MediaPlayer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPlayerSurface as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlaybackMediaMarker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Time(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaybackMediaMarker", "Time", 6usize, |this| ((**(this as *const *const IPlaybackMediaMarker_Vtbl)).Time)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaMarkerType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaybackMediaMarker", "MediaMarkerType", 7usize, |this| ((**(this as *const *const IPlaybackMediaMarker_Vtbl)).MediaMarkerType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaybackMediaMarker", "Text", 8usize, |this| ((**(this as *const *const IPlaybackMediaMarker_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Time, MediaMarkerType, Text }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaybackMediaMarker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlaybackMediaMarkerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromTime(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaybackMediaMarkerFactory", "CreateFromTime", 6usize, |this| ((**(this as *const *const IPlaybackMediaMarkerFactory_Vtbl)).CreateFromTime)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan, mediamarkettype: core::mem::MaybeUninit<windows_core::HSTRING>, text: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaybackMediaMarkerFactory", "Create", 7usize, |this| ((**(this as *const *const IPlaybackMediaMarkerFactory_Vtbl)).Create)(this, value, mediamarkettype, text, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromTime, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaybackMediaMarkerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlaybackMediaMarkerReachedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PlaybackMediaMarker(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaybackMediaMarkerReachedEventArgs", "PlaybackMediaMarker", 6usize, |this| ((**(this as *const *const IPlaybackMediaMarkerReachedEventArgs_Vtbl)).PlaybackMediaMarker)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PlaybackMediaMarker }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaybackMediaMarkerReachedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPlaybackMediaMarkerSequence_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaybackMediaMarkerSequence", "Size", 6usize, |this| ((**(this as *const *const IPlaybackMediaMarkerSequence_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Insert(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaybackMediaMarkerSequence", "Insert", 7usize, |this| ((**(this as *const *const IPlaybackMediaMarkerSequence_Vtbl)).Insert)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaybackMediaMarkerSequence", "Clear", 8usize, |this| ((**(this as *const *const IPlaybackMediaMarkerSequence_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Size, Insert, Clear }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaybackMediaMarkerSequence as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Core")]
+impl windows_core::imp::ProxyVtable for ITimedMetadataPresentationModeChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Core")]
+        unsafe extern "system" fn Track(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataPresentationModeChangedEventArgs", "Track", 6usize, |this| ((**(this as *const *const ITimedMetadataPresentationModeChangedEventArgs_Vtbl)).Track)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OldPresentationMode(this: *mut core::ffi::c_void, result__: *mut TimedMetadataTrackPresentationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataPresentationModeChangedEventArgs", "OldPresentationMode", 7usize, |this| ((**(this as *const *const ITimedMetadataPresentationModeChangedEventArgs_Vtbl)).OldPresentationMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewPresentationMode(this: *mut core::ffi::c_void, result__: *mut TimedMetadataTrackPresentationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimedMetadataPresentationModeChangedEventArgs", "NewPresentationMode", 8usize, |this| ((**(this as *const *const ITimedMetadataPresentationModeChangedEventArgs_Vtbl)).NewPresentationMode)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Core")]
+            Track,
+            #[cfg(not(feature = "Media_Core"))]
+            Track: 0,
+            OldPresentationMode,
+            NewPresentationMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimedMetadataPresentationModeChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -117,6 +117,7 @@ impl PlaylistFormat {
 impl windows_core::TypeKind for PlaylistFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlaylistFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlaylistFormat").field(&self.0).finish()
@@ -125,3 +126,5 @@ impl core::fmt::Debug for PlaylistFormat {
 impl windows_core::RuntimeType for PlaylistFormat {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.Playlists.PlaylistFormat;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
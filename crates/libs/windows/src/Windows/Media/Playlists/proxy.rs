@@ -0,0 +1,60 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IPlaylist_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn Files(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaylist", "Files", 6usize, |this| ((**(this as *const *const IPlaylist_Vtbl)).Files)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaylist", "SaveAsync", 7usize, |this| ((**(this as *const *const IPlaylist_Vtbl)).SaveAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SaveAsAsync(this: *mut core::ffi::c_void, savelocation: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, option: super::super::Storage::NameCollisionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaylist", "SaveAsAsync", 8usize, |this| ((**(this as *const *const IPlaylist_Vtbl)).SaveAsAsync)(this, savelocation, desiredname, option, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SaveAsWithFormatAsync(this: *mut core::ffi::c_void, savelocation: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, option: super::super::Storage::NameCollisionOption, playlistformat: PlaylistFormat, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaylist", "SaveAsWithFormatAsync", 9usize, |this| ((**(this as *const *const IPlaylist_Vtbl)).SaveAsWithFormatAsync)(this, savelocation, desiredname, option, playlistformat, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            Files,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            Files: 0,
+            SaveAsync,
+            #[cfg(feature = "Storage")]
+            SaveAsAsync,
+            #[cfg(not(feature = "Storage"))]
+            SaveAsAsync: 0,
+            #[cfg(feature = "Storage")]
+            SaveAsWithFormatAsync,
+            #[cfg(not(feature = "Storage"))]
+            SaveAsWithFormatAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaylist as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IPlaylistStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LoadAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaylistStatics", "LoadAsync", 6usize, |this| ((**(this as *const *const IPlaylistStatics_Vtbl)).LoadAsync)(this, file, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            LoadAsync,
+            #[cfg(not(feature = "Storage"))]
+            LoadAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaylistStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
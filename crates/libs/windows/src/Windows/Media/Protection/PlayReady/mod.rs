@@ -4338,6 +4338,7 @@ impl windows_core::TypeKind for NDCertificateFeature {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDCertificateFeature {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDCertificateFeature").field(&self.0).finish()
@@ -4371,6 +4372,7 @@ impl windows_core::TypeKind for NDCertificatePlatformID {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDCertificatePlatformID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDCertificatePlatformID").field(&self.0).finish()
@@ -4405,6 +4407,7 @@ impl windows_core::TypeKind for NDCertificateType {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDCertificateType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDCertificateType").field(&self.0).finish()
@@ -4429,6 +4432,7 @@ impl windows_core::TypeKind for NDClosedCaptionFormat {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDClosedCaptionFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDClosedCaptionFormat").field(&self.0).finish()
@@ -4453,6 +4457,7 @@ impl windows_core::TypeKind for NDContentIDType {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDContentIDType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDContentIDType").field(&self.0).finish()
@@ -4476,6 +4481,7 @@ impl windows_core::TypeKind for NDMediaStreamType {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDMediaStreamType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDMediaStreamType").field(&self.0).finish()
@@ -4500,6 +4506,7 @@ impl windows_core::TypeKind for NDProximityDetectionType {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDProximityDetectionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDProximityDetectionType").field(&self.0).finish()
@@ -4523,6 +4530,7 @@ impl windows_core::TypeKind for NDStartAsyncOptions {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDStartAsyncOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDStartAsyncOptions").field(&self.0).finish()
@@ -4542,6 +4550,7 @@ impl PlayReadyDecryptorSetup {
 impl windows_core::TypeKind for PlayReadyDecryptorSetup {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlayReadyDecryptorSetup {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlayReadyDecryptorSetup").field(&self.0).finish()
@@ -4564,6 +4573,7 @@ impl PlayReadyEncryptionAlgorithm {
 impl windows_core::TypeKind for PlayReadyEncryptionAlgorithm {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlayReadyEncryptionAlgorithm {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlayReadyEncryptionAlgorithm").field(&self.0).finish()
@@ -4583,6 +4593,7 @@ impl PlayReadyHardwareDRMFeatures {
 impl windows_core::TypeKind for PlayReadyHardwareDRMFeatures {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlayReadyHardwareDRMFeatures {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlayReadyHardwareDRMFeatures").field(&self.0).finish()
@@ -4601,6 +4612,7 @@ impl PlayReadyITADataFormat {
 impl windows_core::TypeKind for PlayReadyITADataFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlayReadyITADataFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlayReadyITADataFormat").field(&self.0).finish()
@@ -4611,3 +4623,5 @@ impl windows_core::RuntimeType for PlayReadyITADataFormat {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
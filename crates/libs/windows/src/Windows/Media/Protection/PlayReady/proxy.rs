@@ -0,0 +1,1583 @@
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDClient_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RegistrationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClient", "RegistrationCompleted", 6usize, |this| ((**(this as *const *const INDClient_Vtbl)).RegistrationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveRegistrationCompleted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClient", "RemoveRegistrationCompleted", 7usize, |this| ((**(this as *const *const INDClient_Vtbl)).RemoveRegistrationCompleted)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ProximityDetectionCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClient", "ProximityDetectionCompleted", 8usize, |this| ((**(this as *const *const INDClient_Vtbl)).ProximityDetectionCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveProximityDetectionCompleted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClient", "RemoveProximityDetectionCompleted", 9usize, |this| ((**(this as *const *const INDClient_Vtbl)).RemoveProximityDetectionCompleted)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn LicenseFetchCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClient", "LicenseFetchCompleted", 10usize, |this| ((**(this as *const *const INDClient_Vtbl)).LicenseFetchCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveLicenseFetchCompleted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClient", "RemoveLicenseFetchCompleted", 11usize, |this| ((**(this as *const *const INDClient_Vtbl)).RemoveLicenseFetchCompleted)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ReRegistrationNeeded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClient", "ReRegistrationNeeded", 12usize, |this| ((**(this as *const *const INDClient_Vtbl)).ReRegistrationNeeded)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveReRegistrationNeeded(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClient", "RemoveReRegistrationNeeded", 13usize, |this| ((**(this as *const *const INDClient_Vtbl)).RemoveReRegistrationNeeded)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ClosedCaptionDataReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClient", "ClosedCaptionDataReceived", 14usize, |this| ((**(this as *const *const INDClient_Vtbl)).ClosedCaptionDataReceived)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveClosedCaptionDataReceived(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClient", "RemoveClosedCaptionDataReceived", 15usize, |this| ((**(this as *const *const INDClient_Vtbl)).RemoveClosedCaptionDataReceived)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, contenturl: *mut core::ffi::c_void, startasyncoptions: u32, registrationcustomdata: *mut core::ffi::c_void, licensefetchdescriptor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClient", "StartAsync", 16usize, |this| ((**(this as *const *const INDClient_Vtbl)).StartAsync)(this, contenturl, startasyncoptions, registrationcustomdata, licensefetchdescriptor, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn LicenseFetchAsync(this: *mut core::ffi::c_void, licensefetchdescriptor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClient", "LicenseFetchAsync", 17usize, |this| ((**(this as *const *const INDClient_Vtbl)).LicenseFetchAsync)(this, licensefetchdescriptor, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ReRegistrationAsync(this: *mut core::ffi::c_void, registrationcustomdata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClient", "ReRegistrationAsync", 18usize, |this| ((**(this as *const *const INDClient_Vtbl)).ReRegistrationAsync)(this, registrationcustomdata, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClient", "Close", 19usize, |this| ((**(this as *const *const INDClient_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            RegistrationCompleted,
+            #[cfg(not(feature = "deprecated"))]
+            RegistrationCompleted: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveRegistrationCompleted,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveRegistrationCompleted: 0,
+            #[cfg(feature = "deprecated")]
+            ProximityDetectionCompleted,
+            #[cfg(not(feature = "deprecated"))]
+            ProximityDetectionCompleted: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveProximityDetectionCompleted,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveProximityDetectionCompleted: 0,
+            #[cfg(feature = "deprecated")]
+            LicenseFetchCompleted,
+            #[cfg(not(feature = "deprecated"))]
+            LicenseFetchCompleted: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveLicenseFetchCompleted,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveLicenseFetchCompleted: 0,
+            #[cfg(feature = "deprecated")]
+            ReRegistrationNeeded,
+            #[cfg(not(feature = "deprecated"))]
+            ReRegistrationNeeded: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveReRegistrationNeeded,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveReRegistrationNeeded: 0,
+            #[cfg(feature = "deprecated")]
+            ClosedCaptionDataReceived,
+            #[cfg(not(feature = "deprecated"))]
+            ClosedCaptionDataReceived: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveClosedCaptionDataReceived,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveClosedCaptionDataReceived: 0,
+            #[cfg(feature = "deprecated")]
+            StartAsync,
+            #[cfg(not(feature = "deprecated"))]
+            StartAsync: 0,
+            #[cfg(feature = "deprecated")]
+            LicenseFetchAsync,
+            #[cfg(not(feature = "deprecated"))]
+            LicenseFetchAsync: 0,
+            #[cfg(feature = "deprecated")]
+            ReRegistrationAsync,
+            #[cfg(not(feature = "deprecated"))]
+            ReRegistrationAsync: 0,
+            #[cfg(feature = "deprecated")]
+            Close,
+            #[cfg(not(feature = "deprecated"))]
+            Close: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDClient as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDClientFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, downloadengine: *mut core::ffi::c_void, streamparser: *mut core::ffi::c_void, pmessenger: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClientFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const INDClientFactory_Vtbl)).CreateInstance)(this, downloadengine, streamparser, pmessenger, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CreateInstance,
+            #[cfg(not(feature = "deprecated"))]
+            CreateInstance: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDClientFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDClosedCaptionDataReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ClosedCaptionDataFormat(this: *mut core::ffi::c_void, result__: *mut NDClosedCaptionFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClosedCaptionDataReceivedEventArgs", "ClosedCaptionDataFormat", 6usize, |this| ((**(this as *const *const INDClosedCaptionDataReceivedEventArgs_Vtbl)).ClosedCaptionDataFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PresentationTimestamp(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClosedCaptionDataReceivedEventArgs", "PresentationTimestamp", 7usize, |this| ((**(this as *const *const INDClosedCaptionDataReceivedEventArgs_Vtbl)).PresentationTimestamp)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ClosedCaptionData(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDClosedCaptionDataReceivedEventArgs", "ClosedCaptionData", 8usize, |this| ((**(this as *const *const INDClosedCaptionDataReceivedEventArgs_Vtbl)).ClosedCaptionData)(this, result_size__, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            ClosedCaptionDataFormat,
+            #[cfg(not(feature = "deprecated"))]
+            ClosedCaptionDataFormat: 0,
+            #[cfg(feature = "deprecated")]
+            PresentationTimestamp,
+            #[cfg(not(feature = "deprecated"))]
+            PresentationTimestamp: 0,
+            #[cfg(feature = "deprecated")]
+            ClosedCaptionData,
+            #[cfg(not(feature = "deprecated"))]
+            ClosedCaptionData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDClosedCaptionDataReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDCustomData_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CustomDataTypeID(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDCustomData", "CustomDataTypeID", 6usize, |this| ((**(this as *const *const INDCustomData_Vtbl)).CustomDataTypeID)(this, result_size__, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CustomData(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDCustomData", "CustomData", 7usize, |this| ((**(this as *const *const INDCustomData_Vtbl)).CustomData)(this, result_size__, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CustomDataTypeID,
+            #[cfg(not(feature = "deprecated"))]
+            CustomDataTypeID: 0,
+            #[cfg(feature = "deprecated")]
+            CustomData,
+            #[cfg(not(feature = "deprecated"))]
+            CustomData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDCustomData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDCustomDataFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, customDataTypeIDBytes_array_size: u32, customdatatypeidbytes: *const u8, customDataBytes_array_size: u32, customdatabytes: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDCustomDataFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const INDCustomDataFactory_Vtbl)).CreateInstance)(this, customDataTypeIDBytes_array_size, customdatatypeidbytes, customDataBytes_array_size, customdatabytes, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CreateInstance,
+            #[cfg(not(feature = "deprecated"))]
+            CreateInstance: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDCustomDataFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDDownloadEngine_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, sessionIDBytes_array_size: u32, sessionidbytes: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDDownloadEngine", "Open", 6usize, |this| ((**(this as *const *const INDDownloadEngine_Vtbl)).Open)(this, uri, sessionIDBytes_array_size, sessionidbytes), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDDownloadEngine", "Pause", 7usize, |this| ((**(this as *const *const INDDownloadEngine_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDDownloadEngine", "Resume", 8usize, |this| ((**(this as *const *const INDDownloadEngine_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDDownloadEngine", "Close", 9usize, |this| ((**(this as *const *const INDDownloadEngine_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, startposition: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDDownloadEngine", "Seek", 10usize, |this| ((**(this as *const *const INDDownloadEngine_Vtbl)).Seek)(this, startposition), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CanSeek(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDDownloadEngine", "CanSeek", 11usize, |this| ((**(this as *const *const INDDownloadEngine_Vtbl)).CanSeek)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn BufferFullMinThresholdInSamples(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDDownloadEngine", "BufferFullMinThresholdInSamples", 12usize, |this| ((**(this as *const *const INDDownloadEngine_Vtbl)).BufferFullMinThresholdInSamples)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn BufferFullMaxThresholdInSamples(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDDownloadEngine", "BufferFullMaxThresholdInSamples", 13usize, |this| ((**(this as *const *const INDDownloadEngine_Vtbl)).BufferFullMaxThresholdInSamples)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Notifier(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDDownloadEngine", "Notifier", 14usize, |this| ((**(this as *const *const INDDownloadEngine_Vtbl)).Notifier)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Open,
+            #[cfg(not(feature = "deprecated"))]
+            Open: 0,
+            #[cfg(feature = "deprecated")]
+            Pause,
+            #[cfg(not(feature = "deprecated"))]
+            Pause: 0,
+            #[cfg(feature = "deprecated")]
+            Resume,
+            #[cfg(not(feature = "deprecated"))]
+            Resume: 0,
+            #[cfg(feature = "deprecated")]
+            Close,
+            #[cfg(not(feature = "deprecated"))]
+            Close: 0,
+            #[cfg(feature = "deprecated")]
+            Seek,
+            #[cfg(not(feature = "deprecated"))]
+            Seek: 0,
+            #[cfg(feature = "deprecated")]
+            CanSeek,
+            #[cfg(not(feature = "deprecated"))]
+            CanSeek: 0,
+            #[cfg(feature = "deprecated")]
+            BufferFullMinThresholdInSamples,
+            #[cfg(not(feature = "deprecated"))]
+            BufferFullMinThresholdInSamples: 0,
+            #[cfg(feature = "deprecated")]
+            BufferFullMaxThresholdInSamples,
+            #[cfg(not(feature = "deprecated"))]
+            BufferFullMaxThresholdInSamples: 0,
+            #[cfg(feature = "deprecated")]
+            Notifier,
+            #[cfg(not(feature = "deprecated"))]
+            Notifier: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDDownloadEngine as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDDownloadEngineNotifier_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn OnStreamOpened(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDDownloadEngineNotifier", "OnStreamOpened", 6usize, |this| ((**(this as *const *const INDDownloadEngineNotifier_Vtbl)).OnStreamOpened)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn OnPlayReadyObjectReceived(this: *mut core::ffi::c_void, dataBytes_array_size: u32, databytes: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDDownloadEngineNotifier", "OnPlayReadyObjectReceived", 7usize, |this| ((**(this as *const *const INDDownloadEngineNotifier_Vtbl)).OnPlayReadyObjectReceived)(this, dataBytes_array_size, databytes), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn OnContentIDReceived(this: *mut core::ffi::c_void, licensefetchdescriptor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDDownloadEngineNotifier", "OnContentIDReceived", 8usize, |this| ((**(this as *const *const INDDownloadEngineNotifier_Vtbl)).OnContentIDReceived)(this, licensefetchdescriptor), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn OnDataReceived(this: *mut core::ffi::c_void, dataBytes_array_size: u32, databytes: *const u8, bytesreceived: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDDownloadEngineNotifier", "OnDataReceived", 9usize, |this| ((**(this as *const *const INDDownloadEngineNotifier_Vtbl)).OnDataReceived)(this, dataBytes_array_size, databytes, bytesreceived), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn OnEndOfStream(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDDownloadEngineNotifier", "OnEndOfStream", 10usize, |this| ((**(this as *const *const INDDownloadEngineNotifier_Vtbl)).OnEndOfStream)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn OnNetworkError(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDDownloadEngineNotifier", "OnNetworkError", 11usize, |this| ((**(this as *const *const INDDownloadEngineNotifier_Vtbl)).OnNetworkError)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            OnStreamOpened,
+            #[cfg(not(feature = "deprecated"))]
+            OnStreamOpened: 0,
+            #[cfg(feature = "deprecated")]
+            OnPlayReadyObjectReceived,
+            #[cfg(not(feature = "deprecated"))]
+            OnPlayReadyObjectReceived: 0,
+            #[cfg(feature = "deprecated")]
+            OnContentIDReceived,
+            #[cfg(not(feature = "deprecated"))]
+            OnContentIDReceived: 0,
+            #[cfg(feature = "deprecated")]
+            OnDataReceived,
+            #[cfg(not(feature = "deprecated"))]
+            OnDataReceived: 0,
+            #[cfg(feature = "deprecated")]
+            OnEndOfStream,
+            #[cfg(not(feature = "deprecated"))]
+            OnEndOfStream: 0,
+            #[cfg(feature = "deprecated")]
+            OnNetworkError,
+            #[cfg(not(feature = "deprecated"))]
+            OnNetworkError: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDDownloadEngineNotifier as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDLicenseFetchCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ResponseCustomData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDLicenseFetchCompletedEventArgs", "ResponseCustomData", 6usize, |this| ((**(this as *const *const INDLicenseFetchCompletedEventArgs_Vtbl)).ResponseCustomData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            ResponseCustomData,
+            #[cfg(not(feature = "deprecated"))]
+            ResponseCustomData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDLicenseFetchCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDLicenseFetchDescriptor_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ContentIDType(this: *mut core::ffi::c_void, result__: *mut NDContentIDType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDLicenseFetchDescriptor", "ContentIDType", 6usize, |this| ((**(this as *const *const INDLicenseFetchDescriptor_Vtbl)).ContentIDType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ContentID(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDLicenseFetchDescriptor", "ContentID", 7usize, |this| ((**(this as *const *const INDLicenseFetchDescriptor_Vtbl)).ContentID)(this, result_size__, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn LicenseFetchChallengeCustomData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDLicenseFetchDescriptor", "LicenseFetchChallengeCustomData", 8usize, |this| ((**(this as *const *const INDLicenseFetchDescriptor_Vtbl)).LicenseFetchChallengeCustomData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetLicenseFetchChallengeCustomData(this: *mut core::ffi::c_void, licensefetchchallengecustomdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDLicenseFetchDescriptor", "SetLicenseFetchChallengeCustomData", 9usize, |this| ((**(this as *const *const INDLicenseFetchDescriptor_Vtbl)).SetLicenseFetchChallengeCustomData)(this, licensefetchchallengecustomdata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            ContentIDType,
+            #[cfg(not(feature = "deprecated"))]
+            ContentIDType: 0,
+            #[cfg(feature = "deprecated")]
+            ContentID,
+            #[cfg(not(feature = "deprecated"))]
+            ContentID: 0,
+            #[cfg(feature = "deprecated")]
+            LicenseFetchChallengeCustomData,
+            #[cfg(not(feature = "deprecated"))]
+            LicenseFetchChallengeCustomData: 0,
+            #[cfg(feature = "deprecated")]
+            SetLicenseFetchChallengeCustomData,
+            #[cfg(not(feature = "deprecated"))]
+            SetLicenseFetchChallengeCustomData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDLicenseFetchDescriptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDLicenseFetchDescriptorFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, contentidtype: NDContentIDType, contentIDBytes_array_size: u32, contentidbytes: *const u8, licensefetchchallengecustomdata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDLicenseFetchDescriptorFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const INDLicenseFetchDescriptorFactory_Vtbl)).CreateInstance)(this, contentidtype, contentIDBytes_array_size, contentidbytes, licensefetchchallengecustomdata, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CreateInstance,
+            #[cfg(not(feature = "deprecated"))]
+            CreateInstance: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDLicenseFetchDescriptorFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDLicenseFetchResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ResponseCustomData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDLicenseFetchResult", "ResponseCustomData", 6usize, |this| ((**(this as *const *const INDLicenseFetchResult_Vtbl)).ResponseCustomData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            ResponseCustomData,
+            #[cfg(not(feature = "deprecated"))]
+            ResponseCustomData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDLicenseFetchResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDMessenger_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SendRegistrationRequestAsync(this: *mut core::ffi::c_void, sessionIDBytes_array_size: u32, sessionidbytes: *const u8, challengeDataBytes_array_size: u32, challengedatabytes: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDMessenger", "SendRegistrationRequestAsync", 6usize, |this| ((**(this as *const *const INDMessenger_Vtbl)).SendRegistrationRequestAsync)(this, sessionIDBytes_array_size, sessionidbytes, challengeDataBytes_array_size, challengedatabytes, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SendProximityDetectionStartAsync(this: *mut core::ffi::c_void, pdtype: NDProximityDetectionType, transmitterChannelBytes_array_size: u32, transmitterchannelbytes: *const u8, sessionIDBytes_array_size: u32, sessionidbytes: *const u8, challengeDataBytes_array_size: u32, challengedatabytes: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDMessenger", "SendProximityDetectionStartAsync", 7usize, |this| ((**(this as *const *const INDMessenger_Vtbl)).SendProximityDetectionStartAsync)(this, pdtype, transmitterChannelBytes_array_size, transmitterchannelbytes, sessionIDBytes_array_size, sessionidbytes, challengeDataBytes_array_size, challengedatabytes, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SendProximityDetectionResponseAsync(this: *mut core::ffi::c_void, pdtype: NDProximityDetectionType, transmitterChannelBytes_array_size: u32, transmitterchannelbytes: *const u8, sessionIDBytes_array_size: u32, sessionidbytes: *const u8, responseDataBytes_array_size: u32, responsedatabytes: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDMessenger", "SendProximityDetectionResponseAsync", 8usize, |this| ((**(this as *const *const INDMessenger_Vtbl)).SendProximityDetectionResponseAsync)(this, pdtype, transmitterChannelBytes_array_size, transmitterchannelbytes, sessionIDBytes_array_size, sessionidbytes, responseDataBytes_array_size, responsedatabytes, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SendLicenseFetchRequestAsync(this: *mut core::ffi::c_void, sessionIDBytes_array_size: u32, sessionidbytes: *const u8, challengeDataBytes_array_size: u32, challengedatabytes: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDMessenger", "SendLicenseFetchRequestAsync", 9usize, |this| ((**(this as *const *const INDMessenger_Vtbl)).SendLicenseFetchRequestAsync)(this, sessionIDBytes_array_size, sessionidbytes, challengeDataBytes_array_size, challengedatabytes, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            SendRegistrationRequestAsync,
+            #[cfg(not(feature = "deprecated"))]
+            SendRegistrationRequestAsync: 0,
+            #[cfg(feature = "deprecated")]
+            SendProximityDetectionStartAsync,
+            #[cfg(not(feature = "deprecated"))]
+            SendProximityDetectionStartAsync: 0,
+            #[cfg(feature = "deprecated")]
+            SendProximityDetectionResponseAsync,
+            #[cfg(not(feature = "deprecated"))]
+            SendProximityDetectionResponseAsync: 0,
+            #[cfg(feature = "deprecated")]
+            SendLicenseFetchRequestAsync,
+            #[cfg(not(feature = "deprecated"))]
+            SendLicenseFetchRequestAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDMessenger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDProximityDetectionCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ProximityDetectionRetryCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDProximityDetectionCompletedEventArgs", "ProximityDetectionRetryCount", 6usize, |this| ((**(this as *const *const INDProximityDetectionCompletedEventArgs_Vtbl)).ProximityDetectionRetryCount)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            ProximityDetectionRetryCount,
+            #[cfg(not(feature = "deprecated"))]
+            ProximityDetectionRetryCount: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDProximityDetectionCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDRegistrationCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ResponseCustomData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDRegistrationCompletedEventArgs", "ResponseCustomData", 6usize, |this| ((**(this as *const *const INDRegistrationCompletedEventArgs_Vtbl)).ResponseCustomData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TransmitterProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDRegistrationCompletedEventArgs", "TransmitterProperties", 7usize, |this| ((**(this as *const *const INDRegistrationCompletedEventArgs_Vtbl)).TransmitterProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TransmitterCertificateAccepted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDRegistrationCompletedEventArgs", "TransmitterCertificateAccepted", 8usize, |this| ((**(this as *const *const INDRegistrationCompletedEventArgs_Vtbl)).TransmitterCertificateAccepted)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetTransmitterCertificateAccepted(this: *mut core::ffi::c_void, accept: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDRegistrationCompletedEventArgs", "SetTransmitterCertificateAccepted", 9usize, |this| ((**(this as *const *const INDRegistrationCompletedEventArgs_Vtbl)).SetTransmitterCertificateAccepted)(this, accept), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            ResponseCustomData,
+            #[cfg(not(feature = "deprecated"))]
+            ResponseCustomData: 0,
+            #[cfg(feature = "deprecated")]
+            TransmitterProperties,
+            #[cfg(not(feature = "deprecated"))]
+            TransmitterProperties: 0,
+            #[cfg(feature = "deprecated")]
+            TransmitterCertificateAccepted,
+            #[cfg(not(feature = "deprecated"))]
+            TransmitterCertificateAccepted: 0,
+            #[cfg(feature = "deprecated")]
+            SetTransmitterCertificateAccepted,
+            #[cfg(not(feature = "deprecated"))]
+            SetTransmitterCertificateAccepted: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDRegistrationCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDSendResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Response(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDSendResult", "Response", 6usize, |this| ((**(this as *const *const INDSendResult_Vtbl)).Response)(this, result_size__, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Response,
+            #[cfg(not(feature = "deprecated"))]
+            Response: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDSendResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Core", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for INDStartResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Media_Core", feature = "deprecated"))]
+        unsafe extern "system" fn MediaStreamSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDStartResult", "MediaStreamSource", 6usize, |this| ((**(this as *const *const INDStartResult_Vtbl)).MediaStreamSource)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Media_Core", feature = "deprecated"))]
+            MediaStreamSource,
+            #[cfg(not(all(feature = "Media_Core", feature = "deprecated")))]
+            MediaStreamSource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDStartResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for INDStorageFileHelper_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage", feature = "deprecated"))]
+        unsafe extern "system" fn GetFileURLs(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDStorageFileHelper", "GetFileURLs", 6usize, |this| ((**(this as *const *const INDStorageFileHelper_Vtbl)).GetFileURLs)(this, file, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage", feature = "deprecated"))]
+            GetFileURLs,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage", feature = "deprecated")))]
+            GetFileURLs: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDStorageFileHelper as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Core", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for INDStreamParser_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ParseData(this: *mut core::ffi::c_void, dataBytes_array_size: u32, databytes: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDStreamParser", "ParseData", 6usize, |this| ((**(this as *const *const INDStreamParser_Vtbl)).ParseData)(this, dataBytes_array_size, databytes), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_Core", feature = "deprecated"))]
+        unsafe extern "system" fn GetStreamInformation(this: *mut core::ffi::c_void, descriptor: *mut core::ffi::c_void, streamtype: *mut NDMediaStreamType, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDStreamParser", "GetStreamInformation", 7usize, |this| ((**(this as *const *const INDStreamParser_Vtbl)).GetStreamInformation)(this, descriptor, streamtype, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn BeginOfStream(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDStreamParser", "BeginOfStream", 8usize, |this| ((**(this as *const *const INDStreamParser_Vtbl)).BeginOfStream)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn EndOfStream(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDStreamParser", "EndOfStream", 9usize, |this| ((**(this as *const *const INDStreamParser_Vtbl)).EndOfStream)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Notifier(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDStreamParser", "Notifier", 10usize, |this| ((**(this as *const *const INDStreamParser_Vtbl)).Notifier)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            ParseData,
+            #[cfg(not(feature = "deprecated"))]
+            ParseData: 0,
+            #[cfg(all(feature = "Media_Core", feature = "deprecated"))]
+            GetStreamInformation,
+            #[cfg(not(all(feature = "Media_Core", feature = "deprecated")))]
+            GetStreamInformation: 0,
+            #[cfg(feature = "deprecated")]
+            BeginOfStream,
+            #[cfg(not(feature = "deprecated"))]
+            BeginOfStream: 0,
+            #[cfg(feature = "deprecated")]
+            EndOfStream,
+            #[cfg(not(feature = "deprecated"))]
+            EndOfStream: 0,
+            #[cfg(feature = "deprecated")]
+            Notifier,
+            #[cfg(not(feature = "deprecated"))]
+            Notifier: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDStreamParser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Core", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for INDStreamParserNotifier_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn OnContentIDReceived(this: *mut core::ffi::c_void, licensefetchdescriptor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDStreamParserNotifier", "OnContentIDReceived", 6usize, |this| ((**(this as *const *const INDStreamParserNotifier_Vtbl)).OnContentIDReceived)(this, licensefetchdescriptor), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core", feature = "deprecated"))]
+        unsafe extern "system" fn OnMediaStreamDescriptorCreated(this: *mut core::ffi::c_void, audiostreamdescriptors: *mut core::ffi::c_void, videostreamdescriptors: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDStreamParserNotifier", "OnMediaStreamDescriptorCreated", 7usize, |this| ((**(this as *const *const INDStreamParserNotifier_Vtbl)).OnMediaStreamDescriptorCreated)(this, audiostreamdescriptors, videostreamdescriptors), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_Core", feature = "deprecated"))]
+        unsafe extern "system" fn OnSampleParsed(this: *mut core::ffi::c_void, streamid: u32, streamtype: NDMediaStreamType, streamsample: *mut core::ffi::c_void, pts: i64, ccformat: NDClosedCaptionFormat, ccDataBytes_array_size: u32, ccdatabytes: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDStreamParserNotifier", "OnSampleParsed", 8usize, |this| ((**(this as *const *const INDStreamParserNotifier_Vtbl)).OnSampleParsed)(this, streamid, streamtype, streamsample, pts, ccformat, ccDataBytes_array_size, ccdatabytes), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_Core", feature = "deprecated"))]
+        unsafe extern "system" fn OnBeginSetupDecryptor(this: *mut core::ffi::c_void, descriptor: *mut core::ffi::c_void, keyid: windows_core::GUID, proBytes_array_size: u32, probytes: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDStreamParserNotifier", "OnBeginSetupDecryptor", 9usize, |this| ((**(this as *const *const INDStreamParserNotifier_Vtbl)).OnBeginSetupDecryptor)(this, descriptor, keyid, proBytes_array_size, probytes), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            OnContentIDReceived,
+            #[cfg(not(feature = "deprecated"))]
+            OnContentIDReceived: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Media_Core", feature = "deprecated"))]
+            OnMediaStreamDescriptorCreated,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Media_Core", feature = "deprecated")))]
+            OnMediaStreamDescriptorCreated: 0,
+            #[cfg(all(feature = "Media_Core", feature = "deprecated"))]
+            OnSampleParsed,
+            #[cfg(not(all(feature = "Media_Core", feature = "deprecated")))]
+            OnSampleParsed: 0,
+            #[cfg(all(feature = "Media_Core", feature = "deprecated"))]
+            OnBeginSetupDecryptor,
+            #[cfg(not(all(feature = "Media_Core", feature = "deprecated")))]
+            OnBeginSetupDecryptor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDStreamParserNotifier as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDTCPMessengerFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, remotehostname: core::mem::MaybeUninit<windows_core::HSTRING>, remotehostport: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDTCPMessengerFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const INDTCPMessengerFactory_Vtbl)).CreateInstance)(this, remotehostname, remotehostport, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CreateInstance,
+            #[cfg(not(feature = "deprecated"))]
+            CreateInstance: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDTCPMessengerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for INDTransmitterProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CertificateType(this: *mut core::ffi::c_void, result__: *mut NDCertificateType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDTransmitterProperties", "CertificateType", 6usize, |this| ((**(this as *const *const INDTransmitterProperties_Vtbl)).CertificateType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PlatformIdentifier(this: *mut core::ffi::c_void, result__: *mut NDCertificatePlatformID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDTransmitterProperties", "PlatformIdentifier", 7usize, |this| ((**(this as *const *const INDTransmitterProperties_Vtbl)).PlatformIdentifier)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SupportedFeatures(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut NDCertificateFeature) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDTransmitterProperties", "SupportedFeatures", 8usize, |this| ((**(this as *const *const INDTransmitterProperties_Vtbl)).SupportedFeatures)(this, result_size__, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SecurityLevel(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDTransmitterProperties", "SecurityLevel", 9usize, |this| ((**(this as *const *const INDTransmitterProperties_Vtbl)).SecurityLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SecurityVersion(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDTransmitterProperties", "SecurityVersion", 10usize, |this| ((**(this as *const *const INDTransmitterProperties_Vtbl)).SecurityVersion)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ExpirationDate(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDTransmitterProperties", "ExpirationDate", 11usize, |this| ((**(this as *const *const INDTransmitterProperties_Vtbl)).ExpirationDate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ClientID(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDTransmitterProperties", "ClientID", 12usize, |this| ((**(this as *const *const INDTransmitterProperties_Vtbl)).ClientID)(this, result_size__, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ModelDigest(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDTransmitterProperties", "ModelDigest", 13usize, |this| ((**(this as *const *const INDTransmitterProperties_Vtbl)).ModelDigest)(this, result_size__, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ModelManufacturerName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDTransmitterProperties", "ModelManufacturerName", 14usize, |this| ((**(this as *const *const INDTransmitterProperties_Vtbl)).ModelManufacturerName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ModelName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDTransmitterProperties", "ModelName", 15usize, |this| ((**(this as *const *const INDTransmitterProperties_Vtbl)).ModelName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ModelNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDTransmitterProperties", "ModelNumber", 16usize, |this| ((**(this as *const *const INDTransmitterProperties_Vtbl)).ModelNumber)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CertificateType,
+            #[cfg(not(feature = "deprecated"))]
+            CertificateType: 0,
+            #[cfg(feature = "deprecated")]
+            PlatformIdentifier,
+            #[cfg(not(feature = "deprecated"))]
+            PlatformIdentifier: 0,
+            #[cfg(feature = "deprecated")]
+            SupportedFeatures,
+            #[cfg(not(feature = "deprecated"))]
+            SupportedFeatures: 0,
+            #[cfg(feature = "deprecated")]
+            SecurityLevel,
+            #[cfg(not(feature = "deprecated"))]
+            SecurityLevel: 0,
+            #[cfg(feature = "deprecated")]
+            SecurityVersion,
+            #[cfg(not(feature = "deprecated"))]
+            SecurityVersion: 0,
+            #[cfg(feature = "deprecated")]
+            ExpirationDate,
+            #[cfg(not(feature = "deprecated"))]
+            ExpirationDate: 0,
+            #[cfg(feature = "deprecated")]
+            ClientID,
+            #[cfg(not(feature = "deprecated"))]
+            ClientID: 0,
+            #[cfg(feature = "deprecated")]
+            ModelDigest,
+            #[cfg(not(feature = "deprecated"))]
+            ModelDigest: 0,
+            #[cfg(feature = "deprecated")]
+            ModelManufacturerName,
+            #[cfg(not(feature = "deprecated"))]
+            ModelManufacturerName: 0,
+            #[cfg(feature = "deprecated")]
+            ModelName,
+            #[cfg(not(feature = "deprecated"))]
+            ModelName: 0,
+            #[cfg(feature = "deprecated")]
+            ModelNumber,
+            #[cfg(not(feature = "deprecated"))]
+            ModelNumber: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDTransmitterProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyContentHeader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KeyId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeader", "KeyId", 6usize, |this| ((**(this as *const *const IPlayReadyContentHeader_Vtbl)).KeyId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyIdString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeader", "KeyIdString", 7usize, |this| ((**(this as *const *const IPlayReadyContentHeader_Vtbl)).KeyIdString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LicenseAcquisitionUrl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeader", "LicenseAcquisitionUrl", 8usize, |this| ((**(this as *const *const IPlayReadyContentHeader_Vtbl)).LicenseAcquisitionUrl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LicenseAcquisitionUserInterfaceUrl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeader", "LicenseAcquisitionUserInterfaceUrl", 9usize, |this| ((**(this as *const *const IPlayReadyContentHeader_Vtbl)).LicenseAcquisitionUserInterfaceUrl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainServiceId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeader", "DomainServiceId", 10usize, |this| ((**(this as *const *const IPlayReadyContentHeader_Vtbl)).DomainServiceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncryptionType(this: *mut core::ffi::c_void, result__: *mut PlayReadyEncryptionAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeader", "EncryptionType", 11usize, |this| ((**(this as *const *const IPlayReadyContentHeader_Vtbl)).EncryptionType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomAttributes(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeader", "CustomAttributes", 12usize, |this| ((**(this as *const *const IPlayReadyContentHeader_Vtbl)).CustomAttributes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecryptorSetup(this: *mut core::ffi::c_void, result__: *mut PlayReadyDecryptorSetup) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeader", "DecryptorSetup", 13usize, |this| ((**(this as *const *const IPlayReadyContentHeader_Vtbl)).DecryptorSetup)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSerializedHeader(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeader", "GetSerializedHeader", 14usize, |this| ((**(this as *const *const IPlayReadyContentHeader_Vtbl)).GetSerializedHeader)(this, result_size__, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeaderWithEmbeddedUpdates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeader", "HeaderWithEmbeddedUpdates", 15usize, |this| ((**(this as *const *const IPlayReadyContentHeader_Vtbl)).HeaderWithEmbeddedUpdates)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            KeyId,
+            KeyIdString,
+            LicenseAcquisitionUrl,
+            LicenseAcquisitionUserInterfaceUrl,
+            DomainServiceId,
+            EncryptionType,
+            CustomAttributes,
+            DecryptorSetup,
+            GetSerializedHeader,
+            HeaderWithEmbeddedUpdates,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyContentHeader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyContentHeader2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KeyIds(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeader2", "KeyIds", 6usize, |this| ((**(this as *const *const IPlayReadyContentHeader2_Vtbl)).KeyIds)(this, result_size__, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyIdStrings(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeader2", "KeyIdStrings", 7usize, |this| ((**(this as *const *const IPlayReadyContentHeader2_Vtbl)).KeyIdStrings)(this, result_size__, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KeyIds, KeyIdStrings }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyContentHeader2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyContentHeaderFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstanceFromWindowsMediaDrmHeader(this: *mut core::ffi::c_void, headerBytes_array_size: u32, headerbytes: *const u8, licenseacquisitionurl: *mut core::ffi::c_void, licenseacquisitionuserinterfaceurl: *mut core::ffi::c_void, customattributes: core::mem::MaybeUninit<windows_core::HSTRING>, domainserviceid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeaderFactory", "CreateInstanceFromWindowsMediaDrmHeader", 6usize, |this| ((**(this as *const *const IPlayReadyContentHeaderFactory_Vtbl)).CreateInstanceFromWindowsMediaDrmHeader)(this, headerBytes_array_size, headerbytes, licenseacquisitionurl, licenseacquisitionuserinterfaceurl, customattributes, domainserviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstanceFromComponents(this: *mut core::ffi::c_void, contentkeyid: windows_core::GUID, contentkeyidstring: core::mem::MaybeUninit<windows_core::HSTRING>, contentencryptionalgorithm: PlayReadyEncryptionAlgorithm, licenseacquisitionurl: *mut core::ffi::c_void, licenseacquisitionuserinterfaceurl: *mut core::ffi::c_void, customattributes: core::mem::MaybeUninit<windows_core::HSTRING>, domainserviceid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeaderFactory", "CreateInstanceFromComponents", 7usize, |this| ((**(this as *const *const IPlayReadyContentHeaderFactory_Vtbl)).CreateInstanceFromComponents)(this, contentkeyid, contentkeyidstring, contentencryptionalgorithm, licenseacquisitionurl, licenseacquisitionuserinterfaceurl, customattributes, domainserviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstanceFromPlayReadyHeader(this: *mut core::ffi::c_void, headerBytes_array_size: u32, headerbytes: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeaderFactory", "CreateInstanceFromPlayReadyHeader", 8usize, |this| ((**(this as *const *const IPlayReadyContentHeaderFactory_Vtbl)).CreateInstanceFromPlayReadyHeader)(this, headerBytes_array_size, headerbytes, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateInstanceFromWindowsMediaDrmHeader,
+            CreateInstanceFromComponents,
+            CreateInstanceFromPlayReadyHeader,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyContentHeaderFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyContentHeaderFactory2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstanceFromComponents2(this: *mut core::ffi::c_void, dwflags: u32, contentKeyIds_array_size: u32, contentkeyids: *const windows_core::GUID, contentKeyIdStrings_array_size: u32, contentkeyidstrings: *const core::mem::MaybeUninit<windows_core::HSTRING>, contentencryptionalgorithm: PlayReadyEncryptionAlgorithm, licenseacquisitionurl: *mut core::ffi::c_void, licenseacquisitionuserinterfaceurl: *mut core::ffi::c_void, customattributes: core::mem::MaybeUninit<windows_core::HSTRING>, domainserviceid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentHeaderFactory2", "CreateInstanceFromComponents2", 6usize, |this| ((**(this as *const *const IPlayReadyContentHeaderFactory2_Vtbl)).CreateInstanceFromComponents2)(this, dwflags, contentKeyIds_array_size, contentkeyids, contentKeyIdStrings_array_size, contentkeyidstrings, contentencryptionalgorithm, licenseacquisitionurl, licenseacquisitionuserinterfaceurl, customattributes, domainserviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstanceFromComponents2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyContentHeaderFactory2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyContentResolver_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServiceRequest(this: *mut core::ffi::c_void, contentheader: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyContentResolver", "ServiceRequest", 6usize, |this| ((**(this as *const *const IPlayReadyContentResolver_Vtbl)).ServiceRequest)(this, contentheader, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ServiceRequest }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyContentResolver as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyDomain_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccountId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomain", "AccountId", 6usize, |this| ((**(this as *const *const IPlayReadyDomain_Vtbl)).AccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomain", "ServiceId", 7usize, |this| ((**(this as *const *const IPlayReadyDomain_Vtbl)).ServiceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Revision(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomain", "Revision", 8usize, |this| ((**(this as *const *const IPlayReadyDomain_Vtbl)).Revision)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomain", "FriendlyName", 9usize, |this| ((**(this as *const *const IPlayReadyDomain_Vtbl)).FriendlyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainJoinUrl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomain", "DomainJoinUrl", 10usize, |this| ((**(this as *const *const IPlayReadyDomain_Vtbl)).DomainJoinUrl)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AccountId, ServiceId, Revision, FriendlyName, DomainJoinUrl }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyDomain as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPlayReadyDomainIterableFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, domainaccountid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomainIterableFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IPlayReadyDomainIterableFactory_Vtbl)).CreateInstance)(this, domainaccountid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateInstance,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateInstance: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyDomainIterableFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyDomainJoinServiceRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DomainAccountId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomainJoinServiceRequest", "DomainAccountId", 6usize, |this| ((**(this as *const *const IPlayReadyDomainJoinServiceRequest_Vtbl)).DomainAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDomainAccountId(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomainJoinServiceRequest", "SetDomainAccountId", 7usize, |this| ((**(this as *const *const IPlayReadyDomainJoinServiceRequest_Vtbl)).SetDomainAccountId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainFriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomainJoinServiceRequest", "DomainFriendlyName", 8usize, |this| ((**(this as *const *const IPlayReadyDomainJoinServiceRequest_Vtbl)).DomainFriendlyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDomainFriendlyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomainJoinServiceRequest", "SetDomainFriendlyName", 9usize, |this| ((**(this as *const *const IPlayReadyDomainJoinServiceRequest_Vtbl)).SetDomainFriendlyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainServiceId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomainJoinServiceRequest", "DomainServiceId", 10usize, |this| ((**(this as *const *const IPlayReadyDomainJoinServiceRequest_Vtbl)).DomainServiceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDomainServiceId(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomainJoinServiceRequest", "SetDomainServiceId", 11usize, |this| ((**(this as *const *const IPlayReadyDomainJoinServiceRequest_Vtbl)).SetDomainServiceId)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DomainAccountId,
+            SetDomainAccountId,
+            DomainFriendlyName,
+            SetDomainFriendlyName,
+            DomainServiceId,
+            SetDomainServiceId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyDomainJoinServiceRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyDomainLeaveServiceRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DomainAccountId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomainLeaveServiceRequest", "DomainAccountId", 6usize, |this| ((**(this as *const *const IPlayReadyDomainLeaveServiceRequest_Vtbl)).DomainAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDomainAccountId(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomainLeaveServiceRequest", "SetDomainAccountId", 7usize, |this| ((**(this as *const *const IPlayReadyDomainLeaveServiceRequest_Vtbl)).SetDomainAccountId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainServiceId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomainLeaveServiceRequest", "DomainServiceId", 8usize, |this| ((**(this as *const *const IPlayReadyDomainLeaveServiceRequest_Vtbl)).DomainServiceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDomainServiceId(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyDomainLeaveServiceRequest", "SetDomainServiceId", 9usize, |this| ((**(this as *const *const IPlayReadyDomainLeaveServiceRequest_Vtbl)).SetDomainServiceId)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DomainAccountId,
+            SetDomainAccountId,
+            DomainServiceId,
+            SetDomainServiceId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyDomainLeaveServiceRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPlayReadyITADataGenerator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GenerateData(this: *mut core::ffi::c_void, guidcpsystemid: windows_core::GUID, countofstreams: u32, configuration: *mut core::ffi::c_void, format: PlayReadyITADataFormat, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyITADataGenerator", "GenerateData", 6usize, |this| ((**(this as *const *const IPlayReadyITADataGenerator_Vtbl)).GenerateData)(this, guidcpsystemid, countofstreams, configuration, format, result_size__, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GenerateData,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GenerateData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyITADataGenerator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyIndividualizationServiceRequest_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyIndividualizationServiceRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyLicense_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FullyEvaluated(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicense", "FullyEvaluated", 6usize, |this| ((**(this as *const *const IPlayReadyLicense_Vtbl)).FullyEvaluated)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsableForPlay(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicense", "UsableForPlay", 7usize, |this| ((**(this as *const *const IPlayReadyLicense_Vtbl)).UsableForPlay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationDate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicense", "ExpirationDate", 8usize, |this| ((**(this as *const *const IPlayReadyLicense_Vtbl)).ExpirationDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpireAfterFirstPlay(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicense", "ExpireAfterFirstPlay", 9usize, |this| ((**(this as *const *const IPlayReadyLicense_Vtbl)).ExpireAfterFirstPlay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainAccountID(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicense", "DomainAccountID", 10usize, |this| ((**(this as *const *const IPlayReadyLicense_Vtbl)).DomainAccountID)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChainDepth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicense", "ChainDepth", 11usize, |this| ((**(this as *const *const IPlayReadyLicense_Vtbl)).ChainDepth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKIDAtChainDepth(this: *mut core::ffi::c_void, chaindepth: u32, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicense", "GetKIDAtChainDepth", 12usize, |this| ((**(this as *const *const IPlayReadyLicense_Vtbl)).GetKIDAtChainDepth)(this, chaindepth, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FullyEvaluated,
+            UsableForPlay,
+            ExpirationDate,
+            ExpireAfterFirstPlay,
+            DomainAccountID,
+            ChainDepth,
+            GetKIDAtChainDepth,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyLicense as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyLicense2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SecureStopId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicense2", "SecureStopId", 6usize, |this| ((**(this as *const *const IPlayReadyLicense2_Vtbl)).SecureStopId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecurityLevel(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicense2", "SecurityLevel", 7usize, |this| ((**(this as *const *const IPlayReadyLicense2_Vtbl)).SecurityLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InMemoryOnly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicense2", "InMemoryOnly", 8usize, |this| ((**(this as *const *const IPlayReadyLicense2_Vtbl)).InMemoryOnly)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpiresInRealTime(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicense2", "ExpiresInRealTime", 9usize, |this| ((**(this as *const *const IPlayReadyLicense2_Vtbl)).ExpiresInRealTime)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SecureStopId, SecurityLevel, InMemoryOnly, ExpiresInRealTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyLicense2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyLicenseAcquisitionServiceRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContentHeader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicenseAcquisitionServiceRequest", "ContentHeader", 6usize, |this| ((**(this as *const *const IPlayReadyLicenseAcquisitionServiceRequest_Vtbl)).ContentHeader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentHeader(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicenseAcquisitionServiceRequest", "SetContentHeader", 7usize, |this| ((**(this as *const *const IPlayReadyLicenseAcquisitionServiceRequest_Vtbl)).SetContentHeader)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainServiceId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicenseAcquisitionServiceRequest", "DomainServiceId", 8usize, |this| ((**(this as *const *const IPlayReadyLicenseAcquisitionServiceRequest_Vtbl)).DomainServiceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDomainServiceId(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicenseAcquisitionServiceRequest", "SetDomainServiceId", 9usize, |this| ((**(this as *const *const IPlayReadyLicenseAcquisitionServiceRequest_Vtbl)).SetDomainServiceId)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ContentHeader,
+            SetContentHeader,
+            DomainServiceId,
+            SetDomainServiceId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyLicenseAcquisitionServiceRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyLicenseAcquisitionServiceRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SessionId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicenseAcquisitionServiceRequest2", "SessionId", 6usize, |this| ((**(this as *const *const IPlayReadyLicenseAcquisitionServiceRequest2_Vtbl)).SessionId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SessionId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyLicenseAcquisitionServiceRequest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPlayReadyLicenseAcquisitionServiceRequest3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateLicenseIterable(this: *mut core::ffi::c_void, contentheader: *mut core::ffi::c_void, fullyevaluated: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicenseAcquisitionServiceRequest3", "CreateLicenseIterable", 6usize, |this| ((**(this as *const *const IPlayReadyLicenseAcquisitionServiceRequest3_Vtbl)).CreateLicenseIterable)(this, contentheader, fullyevaluated, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateLicenseIterable,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateLicenseIterable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyLicenseAcquisitionServiceRequest3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPlayReadyLicenseIterableFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, contentheader: *mut core::ffi::c_void, fullyevaluated: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicenseIterableFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IPlayReadyLicenseIterableFactory_Vtbl)).CreateInstance)(this, contentheader, fullyevaluated, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateInstance,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateInstance: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyLicenseIterableFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyLicenseManagement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeleteLicenses(this: *mut core::ffi::c_void, contentheader: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicenseManagement", "DeleteLicenses", 6usize, |this| ((**(this as *const *const IPlayReadyLicenseManagement_Vtbl)).DeleteLicenses)(this, contentheader, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeleteLicenses }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyLicenseManagement as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyLicenseSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateLAServiceRequest(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicenseSession", "CreateLAServiceRequest", 6usize, |this| ((**(this as *const *const IPlayReadyLicenseSession_Vtbl)).CreateLAServiceRequest)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConfigureMediaProtectionManager(this: *mut core::ffi::c_void, mpm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicenseSession", "ConfigureMediaProtectionManager", 7usize, |this| ((**(this as *const *const IPlayReadyLicenseSession_Vtbl)).ConfigureMediaProtectionManager)(this, mpm), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateLAServiceRequest, ConfigureMediaProtectionManager }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyLicenseSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPlayReadyLicenseSession2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateLicenseIterable(this: *mut core::ffi::c_void, contentheader: *mut core::ffi::c_void, fullyevaluated: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicenseSession2", "CreateLicenseIterable", 6usize, |this| ((**(this as *const *const IPlayReadyLicenseSession2_Vtbl)).CreateLicenseIterable)(this, contentheader, fullyevaluated, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateLicenseIterable,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateLicenseIterable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyLicenseSession2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPlayReadyLicenseSessionFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, configuration: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyLicenseSessionFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IPlayReadyLicenseSessionFactory_Vtbl)).CreateInstance)(this, configuration, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateInstance,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateInstance: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyLicenseSessionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyMeteringReportServiceRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MeteringCertificate(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyMeteringReportServiceRequest", "MeteringCertificate", 6usize, |this| ((**(this as *const *const IPlayReadyMeteringReportServiceRequest_Vtbl)).MeteringCertificate)(this, result_size__, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMeteringCertificate(this: *mut core::ffi::c_void, meteringCertBytes_array_size: u32, meteringcertbytes: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyMeteringReportServiceRequest", "SetMeteringCertificate", 7usize, |this| ((**(this as *const *const IPlayReadyMeteringReportServiceRequest_Vtbl)).SetMeteringCertificate)(this, meteringCertBytes_array_size, meteringcertbytes), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MeteringCertificate, SetMeteringCertificate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyMeteringReportServiceRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyRevocationServiceRequest_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyRevocationServiceRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPlayReadySecureStopIterableFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, publisherCertBytes_array_size: u32, publishercertbytes: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadySecureStopIterableFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IPlayReadySecureStopIterableFactory_Vtbl)).CreateInstance)(this, publisherCertBytes_array_size, publishercertbytes, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateInstance,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateInstance: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadySecureStopIterableFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadySecureStopServiceRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SessionID(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadySecureStopServiceRequest", "SessionID", 6usize, |this| ((**(this as *const *const IPlayReadySecureStopServiceRequest_Vtbl)).SessionID)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadySecureStopServiceRequest", "StartTime", 7usize, |this| ((**(this as *const *const IPlayReadySecureStopServiceRequest_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadySecureStopServiceRequest", "UpdateTime", 8usize, |this| ((**(this as *const *const IPlayReadySecureStopServiceRequest_Vtbl)).UpdateTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stopped(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadySecureStopServiceRequest", "Stopped", 9usize, |this| ((**(this as *const *const IPlayReadySecureStopServiceRequest_Vtbl)).Stopped)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublisherCertificate(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadySecureStopServiceRequest", "PublisherCertificate", 10usize, |this| ((**(this as *const *const IPlayReadySecureStopServiceRequest_Vtbl)).PublisherCertificate)(this, result_size__, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SessionID,
+            StartTime,
+            UpdateTime,
+            Stopped,
+            PublisherCertificate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadySecureStopServiceRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadySecureStopServiceRequestFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, publisherCertBytes_array_size: u32, publishercertbytes: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadySecureStopServiceRequestFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IPlayReadySecureStopServiceRequestFactory_Vtbl)).CreateInstance)(this, publisherCertBytes_array_size, publishercertbytes, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstanceFromSessionID(this: *mut core::ffi::c_void, sessionid: windows_core::GUID, publisherCertBytes_array_size: u32, publishercertbytes: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadySecureStopServiceRequestFactory", "CreateInstanceFromSessionID", 7usize, |this| ((**(this as *const *const IPlayReadySecureStopServiceRequestFactory_Vtbl)).CreateInstanceFromSessionID)(this, sessionid, publisherCertBytes_array_size, publishercertbytes, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance, CreateInstanceFromSessionID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadySecureStopServiceRequestFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyServiceRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyServiceRequest", "Uri", 6usize, |this| ((**(this as *const *const IPlayReadyServiceRequest_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyServiceRequest", "SetUri", 7usize, |this| ((**(this as *const *const IPlayReadyServiceRequest_Vtbl)).SetUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseCustomData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyServiceRequest", "ResponseCustomData", 8usize, |this| ((**(this as *const *const IPlayReadyServiceRequest_Vtbl)).ResponseCustomData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChallengeCustomData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyServiceRequest", "ChallengeCustomData", 9usize, |this| ((**(this as *const *const IPlayReadyServiceRequest_Vtbl)).ChallengeCustomData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChallengeCustomData(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyServiceRequest", "SetChallengeCustomData", 10usize, |this| ((**(this as *const *const IPlayReadyServiceRequest_Vtbl)).SetChallengeCustomData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginServiceRequest(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyServiceRequest", "BeginServiceRequest", 11usize, |this| ((**(this as *const *const IPlayReadyServiceRequest_Vtbl)).BeginServiceRequest)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextServiceRequest(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyServiceRequest", "NextServiceRequest", 12usize, |this| ((**(this as *const *const IPlayReadyServiceRequest_Vtbl)).NextServiceRequest)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateManualEnablingChallenge(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyServiceRequest", "GenerateManualEnablingChallenge", 13usize, |this| ((**(this as *const *const IPlayReadyServiceRequest_Vtbl)).GenerateManualEnablingChallenge)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessManualEnablingResponse(this: *mut core::ffi::c_void, responseBytes_array_size: u32, responsebytes: *const u8, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyServiceRequest", "ProcessManualEnablingResponse", 14usize, |this| ((**(this as *const *const IPlayReadyServiceRequest_Vtbl)).ProcessManualEnablingResponse)(this, responseBytes_array_size, responsebytes, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Uri,
+            SetUri,
+            ResponseCustomData,
+            ChallengeCustomData,
+            SetChallengeCustomData,
+            BeginServiceRequest,
+            NextServiceRequest,
+            GenerateManualEnablingChallenge,
+            ProcessManualEnablingResponse,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyServiceRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPlayReadySoapMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMessageBody(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadySoapMessage", "GetMessageBody", 6usize, |this| ((**(this as *const *const IPlayReadySoapMessage_Vtbl)).GetMessageBody)(this, result_size__, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MessageHeaders(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadySoapMessage", "MessageHeaders", 7usize, |this| ((**(this as *const *const IPlayReadySoapMessage_Vtbl)).MessageHeaders)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadySoapMessage", "Uri", 8usize, |this| ((**(this as *const *const IPlayReadySoapMessage_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMessageBody,
+            #[cfg(feature = "Foundation_Collections")]
+            MessageHeaders,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MessageHeaders: 0,
+            Uri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadySoapMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DomainJoinServiceRequestType(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics", "DomainJoinServiceRequestType", 6usize, |this| ((**(this as *const *const IPlayReadyStatics_Vtbl)).DomainJoinServiceRequestType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainLeaveServiceRequestType(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics", "DomainLeaveServiceRequestType", 7usize, |this| ((**(this as *const *const IPlayReadyStatics_Vtbl)).DomainLeaveServiceRequestType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IndividualizationServiceRequestType(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics", "IndividualizationServiceRequestType", 8usize, |this| ((**(this as *const *const IPlayReadyStatics_Vtbl)).IndividualizationServiceRequestType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LicenseAcquirerServiceRequestType(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics", "LicenseAcquirerServiceRequestType", 9usize, |this| ((**(this as *const *const IPlayReadyStatics_Vtbl)).LicenseAcquirerServiceRequestType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MeteringReportServiceRequestType(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics", "MeteringReportServiceRequestType", 10usize, |this| ((**(this as *const *const IPlayReadyStatics_Vtbl)).MeteringReportServiceRequestType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevocationServiceRequestType(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics", "RevocationServiceRequestType", 11usize, |this| ((**(this as *const *const IPlayReadyStatics_Vtbl)).RevocationServiceRequestType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaProtectionSystemId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics", "MediaProtectionSystemId", 12usize, |this| ((**(this as *const *const IPlayReadyStatics_Vtbl)).MediaProtectionSystemId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayReadySecurityVersion(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics", "PlayReadySecurityVersion", 13usize, |this| ((**(this as *const *const IPlayReadyStatics_Vtbl)).PlayReadySecurityVersion)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DomainJoinServiceRequestType,
+            DomainLeaveServiceRequestType,
+            IndividualizationServiceRequestType,
+            LicenseAcquirerServiceRequestType,
+            MeteringReportServiceRequestType,
+            RevocationServiceRequestType,
+            MediaProtectionSystemId,
+            PlayReadySecurityVersion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PlayReadyCertificateSecurityLevel(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics2", "PlayReadyCertificateSecurityLevel", 6usize, |this| ((**(this as *const *const IPlayReadyStatics2_Vtbl)).PlayReadyCertificateSecurityLevel)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PlayReadyCertificateSecurityLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SecureStopServiceRequestType(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics3", "SecureStopServiceRequestType", 6usize, |this| ((**(this as *const *const IPlayReadyStatics3_Vtbl)).SecureStopServiceRequestType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckSupportedHardware(this: *mut core::ffi::c_void, hwdrmfeature: PlayReadyHardwareDRMFeatures, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics3", "CheckSupportedHardware", 7usize, |this| ((**(this as *const *const IPlayReadyStatics3_Vtbl)).CheckSupportedHardware)(this, hwdrmfeature, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SecureStopServiceRequestType, CheckSupportedHardware }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InputTrustAuthorityToCreate(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics4", "InputTrustAuthorityToCreate", 6usize, |this| ((**(this as *const *const IPlayReadyStatics4_Vtbl)).InputTrustAuthorityToCreate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectionSystemId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics4", "ProtectionSystemId", 7usize, |this| ((**(this as *const *const IPlayReadyStatics4_Vtbl)).ProtectionSystemId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InputTrustAuthorityToCreate, ProtectionSystemId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayReadyStatics5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HardwareDRMDisabledAtTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics5", "HardwareDRMDisabledAtTime", 6usize, |this| ((**(this as *const *const IPlayReadyStatics5_Vtbl)).HardwareDRMDisabledAtTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareDRMDisabledUntilTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics5", "HardwareDRMDisabledUntilTime", 7usize, |this| ((**(this as *const *const IPlayReadyStatics5_Vtbl)).HardwareDRMDisabledUntilTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetHardwareDRMDisabled(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayReadyStatics5", "ResetHardwareDRMDisabled", 8usize, |this| ((**(this as *const *const IPlayReadyStatics5_Vtbl)).ResetHardwareDRMDisabled)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HardwareDRMDisabledAtTime,
+            HardwareDRMDisabledUntilTime,
+            ResetHardwareDRMDisabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyStatics5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -592,6 +592,7 @@ impl GraphicsTrustStatus {
 impl windows_core::TypeKind for GraphicsTrustStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GraphicsTrustStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GraphicsTrustStatus").field(&self.0).finish()
@@ -611,6 +612,7 @@ impl HdcpProtection {
 impl windows_core::TypeKind for HdcpProtection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HdcpProtection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HdcpProtection").field(&self.0).finish()
@@ -631,6 +633,7 @@ impl HdcpSetProtectionResult {
 impl windows_core::TypeKind for HdcpSetProtectionResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HdcpSetProtectionResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HdcpSetProtectionResult").field(&self.0).finish()
@@ -650,6 +653,7 @@ impl ProtectionCapabilityResult {
 impl windows_core::TypeKind for ProtectionCapabilityResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProtectionCapabilityResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProtectionCapabilityResult").field(&self.0).finish()
@@ -671,6 +675,7 @@ impl RenewalStatus {
 impl windows_core::TypeKind for RenewalStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RenewalStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RenewalStatus").field(&self.0).finish()
@@ -702,6 +707,7 @@ impl RevocationAndRenewalReasons {
 impl windows_core::TypeKind for RevocationAndRenewalReasons {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RevocationAndRenewalReasons {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RevocationAndRenewalReasons").field(&self.0).finish()
@@ -927,3 +933,5 @@ pub struct ServiceRequestedEventHandler_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,260 @@
+impl windows_core::imp::ProxyVtable for IComponentLoadFailedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Information(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentLoadFailedEventArgs", "Information", 6usize, |this| ((**(this as *const *const IComponentLoadFailedEventArgs_Vtbl)).Information)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Completion(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentLoadFailedEventArgs", "Completion", 7usize, |this| ((**(this as *const *const IComponentLoadFailedEventArgs_Vtbl)).Completion)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Information, Completion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComponentLoadFailedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComponentRenewalStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RenewSystemComponentsAsync(this: *mut core::ffi::c_void, information: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentRenewalStatics", "RenewSystemComponentsAsync", 6usize, |this| ((**(this as *const *const IComponentRenewalStatics_Vtbl)).RenewSystemComponentsAsync)(this, information, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RenewSystemComponentsAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComponentRenewalStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHdcpSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEffectiveProtectionAtLeast(this: *mut core::ffi::c_void, protection: HdcpProtection, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdcpSession", "IsEffectiveProtectionAtLeast", 6usize, |this| ((**(this as *const *const IHdcpSession_Vtbl)).IsEffectiveProtectionAtLeast)(this, protection, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEffectiveProtection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdcpSession", "GetEffectiveProtection", 7usize, |this| ((**(this as *const *const IHdcpSession_Vtbl)).GetEffectiveProtection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredMinProtectionAsync(this: *mut core::ffi::c_void, protection: HdcpProtection, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdcpSession", "SetDesiredMinProtectionAsync", 8usize, |this| ((**(this as *const *const IHdcpSession_Vtbl)).SetDesiredMinProtectionAsync)(this, protection, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectionChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdcpSession", "ProtectionChanged", 9usize, |this| ((**(this as *const *const IHdcpSession_Vtbl)).ProtectionChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProtectionChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHdcpSession", "RemoveProtectionChanged", 10usize, |this| ((**(this as *const *const IHdcpSession_Vtbl)).RemoveProtectionChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEffectiveProtectionAtLeast,
+            GetEffectiveProtection,
+            SetDesiredMinProtectionAsync,
+            ProtectionChanged,
+            RemoveProtectionChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHdcpSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaProtectionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServiceRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaProtectionManager", "ServiceRequested", 6usize, |this| ((**(this as *const *const IMediaProtectionManager_Vtbl)).ServiceRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveServiceRequested(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaProtectionManager", "RemoveServiceRequested", 7usize, |this| ((**(this as *const *const IMediaProtectionManager_Vtbl)).RemoveServiceRequested)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RebootNeeded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaProtectionManager", "RebootNeeded", 8usize, |this| ((**(this as *const *const IMediaProtectionManager_Vtbl)).RebootNeeded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRebootNeeded(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaProtectionManager", "RemoveRebootNeeded", 9usize, |this| ((**(this as *const *const IMediaProtectionManager_Vtbl)).RemoveRebootNeeded)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComponentLoadFailed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaProtectionManager", "ComponentLoadFailed", 10usize, |this| ((**(this as *const *const IMediaProtectionManager_Vtbl)).ComponentLoadFailed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveComponentLoadFailed(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaProtectionManager", "RemoveComponentLoadFailed", 11usize, |this| ((**(this as *const *const IMediaProtectionManager_Vtbl)).RemoveComponentLoadFailed)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaProtectionManager", "Properties", 12usize, |this| ((**(this as *const *const IMediaProtectionManager_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServiceRequested,
+            RemoveServiceRequested,
+            RebootNeeded,
+            RemoveRebootNeeded,
+            ComponentLoadFailed,
+            RemoveComponentLoadFailed,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaProtectionManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaProtectionPMPServer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaProtectionPMPServer", "Properties", 6usize, |this| ((**(this as *const *const IMediaProtectionPMPServer_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaProtectionPMPServer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaProtectionPMPServerFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreatePMPServer(this: *mut core::ffi::c_void, pproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaProtectionPMPServerFactory", "CreatePMPServer", 6usize, |this| ((**(this as *const *const IMediaProtectionPMPServerFactory_Vtbl)).CreatePMPServer)(this, pproperties, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreatePMPServer,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreatePMPServer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaProtectionPMPServerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaProtectionServiceCompletion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void, success: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaProtectionServiceCompletion", "Complete", 6usize, |this| ((**(this as *const *const IMediaProtectionServiceCompletion_Vtbl)).Complete)(this, success), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaProtectionServiceCompletion as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaProtectionServiceRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProtectionSystem(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaProtectionServiceRequest", "ProtectionSystem", 6usize, |this| ((**(this as *const *const IMediaProtectionServiceRequest_Vtbl)).ProtectionSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaProtectionServiceRequest", "Type", 7usize, |this| ((**(this as *const *const IMediaProtectionServiceRequest_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProtectionSystem, Type }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaProtectionServiceRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProtectionCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTypeSupported(this: *mut core::ffi::c_void, r#type: core::mem::MaybeUninit<windows_core::HSTRING>, keysystem: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut ProtectionCapabilityResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionCapabilities", "IsTypeSupported", 6usize, |this| ((**(this as *const *const IProtectionCapabilities_Vtbl)).IsTypeSupported)(this, r#type, keysystem, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsTypeSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectionCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRevocationAndRenewalInformation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Items(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRevocationAndRenewalInformation", "Items", 6usize, |this| ((**(this as *const *const IRevocationAndRenewalInformation_Vtbl)).Items)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Items,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Items: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRevocationAndRenewalInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRevocationAndRenewalItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reasons(this: *mut core::ffi::c_void, result__: *mut RevocationAndRenewalReasons) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRevocationAndRenewalItem", "Reasons", 6usize, |this| ((**(this as *const *const IRevocationAndRenewalItem_Vtbl)).Reasons)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeaderHash(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRevocationAndRenewalItem", "HeaderHash", 7usize, |this| ((**(this as *const *const IRevocationAndRenewalItem_Vtbl)).HeaderHash)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublicKeyHash(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRevocationAndRenewalItem", "PublicKeyHash", 8usize, |this| ((**(this as *const *const IRevocationAndRenewalItem_Vtbl)).PublicKeyHash)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRevocationAndRenewalItem", "Name", 9usize, |this| ((**(this as *const *const IRevocationAndRenewalItem_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenewalId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRevocationAndRenewalItem", "RenewalId", 10usize, |this| ((**(this as *const *const IRevocationAndRenewalItem_Vtbl)).RenewalId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reasons, HeaderHash, PublicKeyHash, Name, RenewalId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRevocationAndRenewalItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServiceRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const IServiceRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Completion(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceRequestedEventArgs", "Completion", 7usize, |this| ((**(this as *const *const IServiceRequestedEventArgs_Vtbl)).Completion)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, Completion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Media_Playback")]
+impl windows_core::imp::ProxyVtable for IServiceRequestedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Media_Playback")]
+        unsafe extern "system" fn MediaPlaybackItem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceRequestedEventArgs2", "MediaPlaybackItem", 6usize, |this| ((**(this as *const *const IServiceRequestedEventArgs2_Vtbl)).MediaPlaybackItem)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Media_Playback")]
+            MediaPlaybackItem,
+            #[cfg(not(feature = "Media_Playback"))]
+            MediaPlaybackItem: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceRequestedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
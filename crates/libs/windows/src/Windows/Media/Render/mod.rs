@@ -18,6 +18,7 @@ impl AudioRenderCategory {
 impl windows_core::TypeKind for AudioRenderCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioRenderCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioRenderCategory").field(&self.0).finish()
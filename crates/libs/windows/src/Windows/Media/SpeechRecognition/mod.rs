@@ -1529,6 +1529,7 @@ impl SpeechContinuousRecognitionMode {
 impl windows_core::TypeKind for SpeechContinuousRecognitionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechContinuousRecognitionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechContinuousRecognitionMode").field(&self.0).finish()
@@ -1552,6 +1553,7 @@ impl SpeechRecognitionAudioProblem {
 impl windows_core::TypeKind for SpeechRecognitionAudioProblem {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRecognitionAudioProblem {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRecognitionAudioProblem").field(&self.0).finish()
@@ -1572,6 +1574,7 @@ impl SpeechRecognitionConfidence {
 impl windows_core::TypeKind for SpeechRecognitionConfidence {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRecognitionConfidence {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRecognitionConfidence").field(&self.0).finish()
@@ -1591,6 +1594,7 @@ impl SpeechRecognitionConstraintProbability {
 impl windows_core::TypeKind for SpeechRecognitionConstraintProbability {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRecognitionConstraintProbability {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRecognitionConstraintProbability").field(&self.0).finish()
@@ -1611,6 +1615,7 @@ impl SpeechRecognitionConstraintType {
 impl windows_core::TypeKind for SpeechRecognitionConstraintType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRecognitionConstraintType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRecognitionConstraintType").field(&self.0).finish()
@@ -1638,6 +1643,7 @@ impl SpeechRecognitionResultStatus {
 impl windows_core::TypeKind for SpeechRecognitionResultStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRecognitionResultStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRecognitionResultStatus").field(&self.0).finish()
@@ -1657,6 +1663,7 @@ impl SpeechRecognitionScenario {
 impl windows_core::TypeKind for SpeechRecognitionScenario {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRecognitionScenario {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRecognitionScenario").field(&self.0).finish()
@@ -1680,6 +1687,7 @@ impl SpeechRecognizerState {
 impl windows_core::TypeKind for SpeechRecognizerState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRecognizerState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRecognizerState").field(&self.0).finish()
@@ -1690,3 +1698,5 @@ impl windows_core::RuntimeType for SpeechRecognizerState {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,707 @@
+impl windows_core::imp::ProxyVtable for ISpeechContinuousRecognitionCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut SpeechRecognitionResultStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechContinuousRecognitionCompletedEventArgs", "Status", 6usize, |this| ((**(this as *const *const ISpeechContinuousRecognitionCompletedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechContinuousRecognitionCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechContinuousRecognitionResultGeneratedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Result(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechContinuousRecognitionResultGeneratedEventArgs", "Result", 6usize, |this| ((**(this as *const *const ISpeechContinuousRecognitionResultGeneratedEventArgs_Vtbl)).Result)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Result }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechContinuousRecognitionResultGeneratedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechContinuousRecognitionSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AutoStopSilenceTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechContinuousRecognitionSession", "AutoStopSilenceTimeout", 6usize, |this| ((**(this as *const *const ISpeechContinuousRecognitionSession_Vtbl)).AutoStopSilenceTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoStopSilenceTimeout(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechContinuousRecognitionSession", "SetAutoStopSilenceTimeout", 7usize, |this| ((**(this as *const *const ISpeechContinuousRecognitionSession_Vtbl)).SetAutoStopSilenceTimeout)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechContinuousRecognitionSession", "StartAsync", 8usize, |this| ((**(this as *const *const ISpeechContinuousRecognitionSession_Vtbl)).StartAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartWithModeAsync(this: *mut core::ffi::c_void, mode: SpeechContinuousRecognitionMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechContinuousRecognitionSession", "StartWithModeAsync", 9usize, |this| ((**(this as *const *const ISpeechContinuousRecognitionSession_Vtbl)).StartWithModeAsync)(this, mode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechContinuousRecognitionSession", "StopAsync", 10usize, |this| ((**(this as *const *const ISpeechContinuousRecognitionSession_Vtbl)).StopAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechContinuousRecognitionSession", "CancelAsync", 11usize, |this| ((**(this as *const *const ISpeechContinuousRecognitionSession_Vtbl)).CancelAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PauseAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechContinuousRecognitionSession", "PauseAsync", 12usize, |this| ((**(this as *const *const ISpeechContinuousRecognitionSession_Vtbl)).PauseAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechContinuousRecognitionSession", "Resume", 13usize, |this| ((**(this as *const *const ISpeechContinuousRecognitionSession_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Completed(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechContinuousRecognitionSession", "Completed", 14usize, |this| ((**(this as *const *const ISpeechContinuousRecognitionSession_Vtbl)).Completed)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCompleted(this: *mut core::ffi::c_void, value: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechContinuousRecognitionSession", "RemoveCompleted", 15usize, |this| ((**(this as *const *const ISpeechContinuousRecognitionSession_Vtbl)).RemoveCompleted)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResultGenerated(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechContinuousRecognitionSession", "ResultGenerated", 16usize, |this| ((**(this as *const *const ISpeechContinuousRecognitionSession_Vtbl)).ResultGenerated)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveResultGenerated(this: *mut core::ffi::c_void, value: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechContinuousRecognitionSession", "RemoveResultGenerated", 17usize, |this| ((**(this as *const *const ISpeechContinuousRecognitionSession_Vtbl)).RemoveResultGenerated)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AutoStopSilenceTimeout,
+            SetAutoStopSilenceTimeout,
+            StartAsync,
+            StartWithModeAsync,
+            StopAsync,
+            CancelAsync,
+            PauseAsync,
+            Resume,
+            Completed,
+            RemoveCompleted,
+            ResultGenerated,
+            RemoveResultGenerated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechContinuousRecognitionSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechRecognitionCompilationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut SpeechRecognitionResultStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionCompilationResult", "Status", 6usize, |this| ((**(this as *const *const ISpeechRecognitionCompilationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognitionCompilationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechRecognitionConstraint_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionConstraint", "IsEnabled", 6usize, |this| ((**(this as *const *const ISpeechRecognitionConstraint_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionConstraint", "SetIsEnabled", 7usize, |this| ((**(this as *const *const ISpeechRecognitionConstraint_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tag(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionConstraint", "Tag", 8usize, |this| ((**(this as *const *const ISpeechRecognitionConstraint_Vtbl)).Tag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionConstraint", "SetTag", 9usize, |this| ((**(this as *const *const ISpeechRecognitionConstraint_Vtbl)).SetTag)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut SpeechRecognitionConstraintType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionConstraint", "Type", 10usize, |this| ((**(this as *const *const ISpeechRecognitionConstraint_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Probability(this: *mut core::ffi::c_void, result__: *mut SpeechRecognitionConstraintProbability) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionConstraint", "Probability", 11usize, |this| ((**(this as *const *const ISpeechRecognitionConstraint_Vtbl)).Probability)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProbability(this: *mut core::ffi::c_void, value: SpeechRecognitionConstraintProbability) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionConstraint", "SetProbability", 12usize, |this| ((**(this as *const *const ISpeechRecognitionConstraint_Vtbl)).SetProbability)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEnabled,
+            SetIsEnabled,
+            Tag,
+            SetTag,
+            Type,
+            Probability,
+            SetProbability,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognitionConstraint as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for ISpeechRecognitionGrammarFileConstraint_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn GrammarFile(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionGrammarFileConstraint", "GrammarFile", 6usize, |this| ((**(this as *const *const ISpeechRecognitionGrammarFileConstraint_Vtbl)).GrammarFile)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            GrammarFile,
+            #[cfg(not(feature = "Storage"))]
+            GrammarFile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognitionGrammarFileConstraint as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for ISpeechRecognitionGrammarFileConstraintFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionGrammarFileConstraintFactory", "Create", 6usize, |this| ((**(this as *const *const ISpeechRecognitionGrammarFileConstraintFactory_Vtbl)).Create)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CreateWithTag(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, tag: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionGrammarFileConstraintFactory", "CreateWithTag", 7usize, |this| ((**(this as *const *const ISpeechRecognitionGrammarFileConstraintFactory_Vtbl)).CreateWithTag)(this, file, tag, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            Create,
+            #[cfg(not(feature = "Storage"))]
+            Create: 0,
+            #[cfg(feature = "Storage")]
+            CreateWithTag,
+            #[cfg(not(feature = "Storage"))]
+            CreateWithTag: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognitionGrammarFileConstraintFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechRecognitionHypothesis_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionHypothesis", "Text", 6usize, |this| ((**(this as *const *const ISpeechRecognitionHypothesis_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Text }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognitionHypothesis as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechRecognitionHypothesisGeneratedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Hypothesis(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionHypothesisGeneratedEventArgs", "Hypothesis", 6usize, |this| ((**(this as *const *const ISpeechRecognitionHypothesisGeneratedEventArgs_Vtbl)).Hypothesis)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Hypothesis }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognitionHypothesisGeneratedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISpeechRecognitionListConstraint_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Commands(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionListConstraint", "Commands", 6usize, |this| ((**(this as *const *const ISpeechRecognitionListConstraint_Vtbl)).Commands)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Commands,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Commands: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognitionListConstraint as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISpeechRecognitionListConstraintFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, commands: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionListConstraintFactory", "Create", 6usize, |this| ((**(this as *const *const ISpeechRecognitionListConstraintFactory_Vtbl)).Create)(this, commands, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWithTag(this: *mut core::ffi::c_void, commands: *mut core::ffi::c_void, tag: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionListConstraintFactory", "CreateWithTag", 7usize, |this| ((**(this as *const *const ISpeechRecognitionListConstraintFactory_Vtbl)).CreateWithTag)(this, commands, tag, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Create,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWithTag,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWithTag: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognitionListConstraintFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechRecognitionQualityDegradingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Problem(this: *mut core::ffi::c_void, result__: *mut SpeechRecognitionAudioProblem) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionQualityDegradingEventArgs", "Problem", 6usize, |this| ((**(this as *const *const ISpeechRecognitionQualityDegradingEventArgs_Vtbl)).Problem)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Problem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognitionQualityDegradingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISpeechRecognitionResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut SpeechRecognitionResultStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionResult", "Status", 6usize, |this| ((**(this as *const *const ISpeechRecognitionResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionResult", "Text", 7usize, |this| ((**(this as *const *const ISpeechRecognitionResult_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Confidence(this: *mut core::ffi::c_void, result__: *mut SpeechRecognitionConfidence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionResult", "Confidence", 8usize, |this| ((**(this as *const *const ISpeechRecognitionResult_Vtbl)).Confidence)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SemanticInterpretation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionResult", "SemanticInterpretation", 9usize, |this| ((**(this as *const *const ISpeechRecognitionResult_Vtbl)).SemanticInterpretation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAlternates(this: *mut core::ffi::c_void, maxalternates: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionResult", "GetAlternates", 10usize, |this| ((**(this as *const *const ISpeechRecognitionResult_Vtbl)).GetAlternates)(this, maxalternates, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Constraint(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionResult", "Constraint", 11usize, |this| ((**(this as *const *const ISpeechRecognitionResult_Vtbl)).Constraint)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RulePath(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionResult", "RulePath", 12usize, |this| ((**(this as *const *const ISpeechRecognitionResult_Vtbl)).RulePath)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawConfidence(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionResult", "RawConfidence", 13usize, |this| ((**(this as *const *const ISpeechRecognitionResult_Vtbl)).RawConfidence)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            Text,
+            Confidence,
+            SemanticInterpretation,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAlternates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAlternates: 0,
+            Constraint,
+            #[cfg(feature = "Foundation_Collections")]
+            RulePath,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RulePath: 0,
+            RawConfidence,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognitionResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechRecognitionResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PhraseStartTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionResult2", "PhraseStartTime", 6usize, |this| ((**(this as *const *const ISpeechRecognitionResult2_Vtbl)).PhraseStartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhraseDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionResult2", "PhraseDuration", 7usize, |this| ((**(this as *const *const ISpeechRecognitionResult2_Vtbl)).PhraseDuration)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PhraseStartTime, PhraseDuration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognitionResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISpeechRecognitionSemanticInterpretation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionSemanticInterpretation", "Properties", 6usize, |this| ((**(this as *const *const ISpeechRecognitionSemanticInterpretation_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognitionSemanticInterpretation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechRecognitionTopicConstraint_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Scenario(this: *mut core::ffi::c_void, result__: *mut SpeechRecognitionScenario) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionTopicConstraint", "Scenario", 6usize, |this| ((**(this as *const *const ISpeechRecognitionTopicConstraint_Vtbl)).Scenario)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TopicHint(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionTopicConstraint", "TopicHint", 7usize, |this| ((**(this as *const *const ISpeechRecognitionTopicConstraint_Vtbl)).TopicHint)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Scenario, TopicHint }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognitionTopicConstraint as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechRecognitionTopicConstraintFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, scenario: SpeechRecognitionScenario, topichint: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionTopicConstraintFactory", "Create", 6usize, |this| ((**(this as *const *const ISpeechRecognitionTopicConstraintFactory_Vtbl)).Create)(this, scenario, topichint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithTag(this: *mut core::ffi::c_void, scenario: SpeechRecognitionScenario, topichint: core::mem::MaybeUninit<windows_core::HSTRING>, tag: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognitionTopicConstraintFactory", "CreateWithTag", 7usize, |this| ((**(this as *const *const ISpeechRecognitionTopicConstraintFactory_Vtbl)).CreateWithTag)(this, scenario, topichint, tag, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithTag }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognitionTopicConstraintFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechRecognitionVoiceCommandDefinitionConstraint_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognitionVoiceCommandDefinitionConstraint as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Globalization"))]
+impl windows_core::imp::ProxyVtable for ISpeechRecognizer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Globalization")]
+        unsafe extern "system" fn CurrentLanguage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "CurrentLanguage", 6usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).CurrentLanguage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Constraints(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "Constraints", 7usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).Constraints)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timeouts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "Timeouts", 8usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).Timeouts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UIOptions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "UIOptions", 9usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).UIOptions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompileConstraintsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "CompileConstraintsAsync", 10usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).CompileConstraintsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecognizeAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "RecognizeAsync", 11usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).RecognizeAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecognizeWithUIAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "RecognizeWithUIAsync", 12usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).RecognizeWithUIAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecognitionQualityDegrading(this: *mut core::ffi::c_void, speechrecognitionqualitydegradinghandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "RecognitionQualityDegrading", 13usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).RecognitionQualityDegrading)(this, speechrecognitionqualitydegradinghandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRecognitionQualityDegrading(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "RemoveRecognitionQualityDegrading", 14usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).RemoveRecognitionQualityDegrading)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateChanged(this: *mut core::ffi::c_void, statechangedhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "StateChanged", 15usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).StateChanged)(this, statechangedhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStateChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "RemoveStateChanged", 16usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).RemoveStateChanged)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Globalization")]
+            CurrentLanguage,
+            #[cfg(not(feature = "Globalization"))]
+            CurrentLanguage: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Constraints,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Constraints: 0,
+            Timeouts,
+            UIOptions,
+            CompileConstraintsAsync,
+            RecognizeAsync,
+            RecognizeWithUIAsync,
+            RecognitionQualityDegrading,
+            RemoveRecognitionQualityDegrading,
+            StateChanged,
+            RemoveStateChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognizer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechRecognizer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContinuousRecognitionSession(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer2", "ContinuousRecognitionSession", 6usize, |this| ((**(this as *const *const ISpeechRecognizer2_Vtbl)).ContinuousRecognitionSession)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut SpeechRecognizerState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer2", "State", 7usize, |this| ((**(this as *const *const ISpeechRecognizer2_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopRecognitionAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer2", "StopRecognitionAsync", 8usize, |this| ((**(this as *const *const ISpeechRecognizer2_Vtbl)).StopRecognitionAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HypothesisGenerated(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer2", "HypothesisGenerated", 9usize, |this| ((**(this as *const *const ISpeechRecognizer2_Vtbl)).HypothesisGenerated)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHypothesisGenerated(this: *mut core::ffi::c_void, value: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer2", "RemoveHypothesisGenerated", 10usize, |this| ((**(this as *const *const ISpeechRecognizer2_Vtbl)).RemoveHypothesisGenerated)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ContinuousRecognitionSession,
+            State,
+            StopRecognitionAsync,
+            HypothesisGenerated,
+            RemoveHypothesisGenerated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognizer2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Globalization")]
+impl windows_core::imp::ProxyVtable for ISpeechRecognizerFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Globalization")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, language: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerFactory", "Create", 6usize, |this| ((**(this as *const *const ISpeechRecognizerFactory_Vtbl)).Create)(this, language, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Globalization")]
+            Create,
+            #[cfg(not(feature = "Globalization"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognizerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechRecognizerStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut SpeechRecognizerState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerStateChangedEventArgs", "State", 6usize, |this| ((**(this as *const *const ISpeechRecognizerStateChangedEventArgs_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, State }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognizerStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Globalization"))]
+impl windows_core::imp::ProxyVtable for ISpeechRecognizerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Globalization")]
+        unsafe extern "system" fn SystemSpeechLanguage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerStatics", "SystemSpeechLanguage", 6usize, |this| ((**(this as *const *const ISpeechRecognizerStatics_Vtbl)).SystemSpeechLanguage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Globalization"))]
+        unsafe extern "system" fn SupportedTopicLanguages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerStatics", "SupportedTopicLanguages", 7usize, |this| ((**(this as *const *const ISpeechRecognizerStatics_Vtbl)).SupportedTopicLanguages)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Globalization"))]
+        unsafe extern "system" fn SupportedGrammarLanguages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerStatics", "SupportedGrammarLanguages", 8usize, |this| ((**(this as *const *const ISpeechRecognizerStatics_Vtbl)).SupportedGrammarLanguages)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Globalization")]
+            SystemSpeechLanguage,
+            #[cfg(not(feature = "Globalization"))]
+            SystemSpeechLanguage: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Globalization"))]
+            SupportedTopicLanguages,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Globalization")))]
+            SupportedTopicLanguages: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Globalization"))]
+            SupportedGrammarLanguages,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Globalization")))]
+            SupportedGrammarLanguages: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognizerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Globalization")]
+impl windows_core::imp::ProxyVtable for ISpeechRecognizerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Globalization")]
+        unsafe extern "system" fn TrySetSystemSpeechLanguageAsync(this: *mut core::ffi::c_void, speechlanguage: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerStatics2", "TrySetSystemSpeechLanguageAsync", 6usize, |this| ((**(this as *const *const ISpeechRecognizerStatics2_Vtbl)).TrySetSystemSpeechLanguageAsync)(this, speechlanguage, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Globalization")]
+            TrySetSystemSpeechLanguageAsync,
+            #[cfg(not(feature = "Globalization"))]
+            TrySetSystemSpeechLanguageAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognizerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechRecognizerTimeouts_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitialSilenceTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerTimeouts", "InitialSilenceTimeout", 6usize, |this| ((**(this as *const *const ISpeechRecognizerTimeouts_Vtbl)).InitialSilenceTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialSilenceTimeout(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerTimeouts", "SetInitialSilenceTimeout", 7usize, |this| ((**(this as *const *const ISpeechRecognizerTimeouts_Vtbl)).SetInitialSilenceTimeout)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndSilenceTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerTimeouts", "EndSilenceTimeout", 8usize, |this| ((**(this as *const *const ISpeechRecognizerTimeouts_Vtbl)).EndSilenceTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEndSilenceTimeout(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerTimeouts", "SetEndSilenceTimeout", 9usize, |this| ((**(this as *const *const ISpeechRecognizerTimeouts_Vtbl)).SetEndSilenceTimeout)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BabbleTimeout(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerTimeouts", "BabbleTimeout", 10usize, |this| ((**(this as *const *const ISpeechRecognizerTimeouts_Vtbl)).BabbleTimeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBabbleTimeout(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerTimeouts", "SetBabbleTimeout", 11usize, |this| ((**(this as *const *const ISpeechRecognizerTimeouts_Vtbl)).SetBabbleTimeout)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitialSilenceTimeout,
+            SetInitialSilenceTimeout,
+            EndSilenceTimeout,
+            SetEndSilenceTimeout,
+            BabbleTimeout,
+            SetBabbleTimeout,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognizerTimeouts as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechRecognizerUIOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExampleText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerUIOptions", "ExampleText", 6usize, |this| ((**(this as *const *const ISpeechRecognizerUIOptions_Vtbl)).ExampleText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExampleText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerUIOptions", "SetExampleText", 7usize, |this| ((**(this as *const *const ISpeechRecognizerUIOptions_Vtbl)).SetExampleText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudiblePrompt(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerUIOptions", "AudiblePrompt", 8usize, |this| ((**(this as *const *const ISpeechRecognizerUIOptions_Vtbl)).AudiblePrompt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudiblePrompt(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerUIOptions", "SetAudiblePrompt", 9usize, |this| ((**(this as *const *const ISpeechRecognizerUIOptions_Vtbl)).SetAudiblePrompt)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReadBackEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerUIOptions", "IsReadBackEnabled", 10usize, |this| ((**(this as *const *const ISpeechRecognizerUIOptions_Vtbl)).IsReadBackEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsReadBackEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerUIOptions", "SetIsReadBackEnabled", 11usize, |this| ((**(this as *const *const ISpeechRecognizerUIOptions_Vtbl)).SetIsReadBackEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowConfirmation(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerUIOptions", "ShowConfirmation", 12usize, |this| ((**(this as *const *const ISpeechRecognizerUIOptions_Vtbl)).ShowConfirmation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowConfirmation(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerUIOptions", "SetShowConfirmation", 13usize, |this| ((**(this as *const *const ISpeechRecognizerUIOptions_Vtbl)).SetShowConfirmation)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ExampleText,
+            SetExampleText,
+            AudiblePrompt,
+            SetAudiblePrompt,
+            IsReadBackEnabled,
+            SetIsReadBackEnabled,
+            ShowConfirmation,
+            SetShowConfirmation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognizerUIOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IVoiceCommandManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn InstallCommandSetsFromStorageFileAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandManager", "InstallCommandSetsFromStorageFileAsync", 6usize, |this| ((**(this as *const *const IVoiceCommandManager_Vtbl)).InstallCommandSetsFromStorageFileAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn InstalledCommandSets(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandManager", "InstalledCommandSets", 7usize, |this| ((**(this as *const *const IVoiceCommandManager_Vtbl)).InstalledCommandSets)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            InstallCommandSetsFromStorageFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            InstallCommandSetsFromStorageFileAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            InstalledCommandSets,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            InstalledCommandSets: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceCommandManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVoiceCommandSet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandSet", "Language", 6usize, |this| ((**(this as *const *const IVoiceCommandSet_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandSet", "Name", 7usize, |this| ((**(this as *const *const IVoiceCommandSet_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetPhraseListAsync(this: *mut core::ffi::c_void, phraselistname: core::mem::MaybeUninit<windows_core::HSTRING>, phraselist: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceCommandSet", "SetPhraseListAsync", 8usize, |this| ((**(this as *const *const IVoiceCommandSet_Vtbl)).SetPhraseListAsync)(this, phraselistname, phraselist, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Language,
+            Name,
+            #[cfg(feature = "Foundation_Collections")]
+            SetPhraseListAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetPhraseListAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceCommandSet as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -503,6 +503,7 @@ impl SpeechAppendedSilence {
 impl windows_core::TypeKind for SpeechAppendedSilence {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechAppendedSilence {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechAppendedSilence").field(&self.0).finish()
@@ -521,6 +522,7 @@ impl SpeechPunctuationSilence {
 impl windows_core::TypeKind for SpeechPunctuationSilence {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechPunctuationSilence {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechPunctuationSilence").field(&self.0).finish()
@@ -539,6 +541,7 @@ impl VoiceGender {
 impl windows_core::TypeKind for VoiceGender {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VoiceGender {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VoiceGender").field(&self.0).finish()
@@ -547,3 +550,5 @@ impl core::fmt::Debug for VoiceGender {
 impl windows_core::RuntimeType for VoiceGender {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.SpeechSynthesis.VoiceGender;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,208 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInstalledVoicesStatic_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AllVoices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstalledVoicesStatic", "AllVoices", 6usize, |this| ((**(this as *const *const IInstalledVoicesStatic_Vtbl)).AllVoices)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultVoice(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstalledVoicesStatic", "DefaultVoice", 7usize, |this| ((**(this as *const *const IInstalledVoicesStatic_Vtbl)).DefaultVoice)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AllVoices,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AllVoices: 0,
+            DefaultVoice,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInstalledVoicesStatic as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInstalledVoicesStatic2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TrySetDefaultVoiceAsync(this: *mut core::ffi::c_void, voice: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstalledVoicesStatic2", "TrySetDefaultVoiceAsync", 6usize, |this| ((**(this as *const *const IInstalledVoicesStatic2_Vtbl)).TrySetDefaultVoiceAsync)(this, voice, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TrySetDefaultVoiceAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInstalledVoicesStatic2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ISpeechSynthesisStream_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Markers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesisStream", "Markers", 6usize, |this| ((**(this as *const *const ISpeechSynthesisStream_Vtbl)).Markers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Markers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Markers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechSynthesisStream as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechSynthesizer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SynthesizeTextToStreamAsync(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizer", "SynthesizeTextToStreamAsync", 6usize, |this| ((**(this as *const *const ISpeechSynthesizer_Vtbl)).SynthesizeTextToStreamAsync)(this, text, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SynthesizeSsmlToStreamAsync(this: *mut core::ffi::c_void, ssml: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizer", "SynthesizeSsmlToStreamAsync", 7usize, |this| ((**(this as *const *const ISpeechSynthesizer_Vtbl)).SynthesizeSsmlToStreamAsync)(this, ssml, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVoice(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizer", "SetVoice", 8usize, |this| ((**(this as *const *const ISpeechSynthesizer_Vtbl)).SetVoice)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Voice(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizer", "Voice", 9usize, |this| ((**(this as *const *const ISpeechSynthesizer_Vtbl)).Voice)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SynthesizeTextToStreamAsync,
+            SynthesizeSsmlToStreamAsync,
+            SetVoice,
+            Voice,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechSynthesizer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechSynthesizer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Options(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizer2", "Options", 6usize, |this| ((**(this as *const *const ISpeechSynthesizer2_Vtbl)).Options)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Options }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechSynthesizer2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechSynthesizerOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IncludeWordBoundaryMetadata(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizerOptions", "IncludeWordBoundaryMetadata", 6usize, |this| ((**(this as *const *const ISpeechSynthesizerOptions_Vtbl)).IncludeWordBoundaryMetadata)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeWordBoundaryMetadata(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizerOptions", "SetIncludeWordBoundaryMetadata", 7usize, |this| ((**(this as *const *const ISpeechSynthesizerOptions_Vtbl)).SetIncludeWordBoundaryMetadata)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeSentenceBoundaryMetadata(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizerOptions", "IncludeSentenceBoundaryMetadata", 8usize, |this| ((**(this as *const *const ISpeechSynthesizerOptions_Vtbl)).IncludeSentenceBoundaryMetadata)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeSentenceBoundaryMetadata(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizerOptions", "SetIncludeSentenceBoundaryMetadata", 9usize, |this| ((**(this as *const *const ISpeechSynthesizerOptions_Vtbl)).SetIncludeSentenceBoundaryMetadata)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IncludeWordBoundaryMetadata,
+            SetIncludeWordBoundaryMetadata,
+            IncludeSentenceBoundaryMetadata,
+            SetIncludeSentenceBoundaryMetadata,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechSynthesizerOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechSynthesizerOptions2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AudioVolume(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizerOptions2", "AudioVolume", 6usize, |this| ((**(this as *const *const ISpeechSynthesizerOptions2_Vtbl)).AudioVolume)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioVolume(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizerOptions2", "SetAudioVolume", 7usize, |this| ((**(this as *const *const ISpeechSynthesizerOptions2_Vtbl)).SetAudioVolume)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpeakingRate(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizerOptions2", "SpeakingRate", 8usize, |this| ((**(this as *const *const ISpeechSynthesizerOptions2_Vtbl)).SpeakingRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpeakingRate(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizerOptions2", "SetSpeakingRate", 9usize, |this| ((**(this as *const *const ISpeechSynthesizerOptions2_Vtbl)).SetSpeakingRate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioPitch(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizerOptions2", "AudioPitch", 10usize, |this| ((**(this as *const *const ISpeechSynthesizerOptions2_Vtbl)).AudioPitch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioPitch(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizerOptions2", "SetAudioPitch", 11usize, |this| ((**(this as *const *const ISpeechSynthesizerOptions2_Vtbl)).SetAudioPitch)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AudioVolume,
+            SetAudioVolume,
+            SpeakingRate,
+            SetSpeakingRate,
+            AudioPitch,
+            SetAudioPitch,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechSynthesizerOptions2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechSynthesizerOptions3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppendedSilence(this: *mut core::ffi::c_void, result__: *mut SpeechAppendedSilence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizerOptions3", "AppendedSilence", 6usize, |this| ((**(this as *const *const ISpeechSynthesizerOptions3_Vtbl)).AppendedSilence)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppendedSilence(this: *mut core::ffi::c_void, value: SpeechAppendedSilence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizerOptions3", "SetAppendedSilence", 7usize, |this| ((**(this as *const *const ISpeechSynthesizerOptions3_Vtbl)).SetAppendedSilence)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PunctuationSilence(this: *mut core::ffi::c_void, result__: *mut SpeechPunctuationSilence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizerOptions3", "PunctuationSilence", 8usize, |this| ((**(this as *const *const ISpeechSynthesizerOptions3_Vtbl)).PunctuationSilence)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPunctuationSilence(this: *mut core::ffi::c_void, value: SpeechPunctuationSilence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechSynthesizerOptions3", "SetPunctuationSilence", 9usize, |this| ((**(this as *const *const ISpeechSynthesizerOptions3_Vtbl)).SetPunctuationSilence)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppendedSilence,
+            SetAppendedSilence,
+            PunctuationSilence,
+            SetPunctuationSilence,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechSynthesizerOptions3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVoiceInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceInformation", "DisplayName", 6usize, |this| ((**(this as *const *const IVoiceInformation_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceInformation", "Id", 7usize, |this| ((**(this as *const *const IVoiceInformation_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceInformation", "Language", 8usize, |this| ((**(this as *const *const IVoiceInformation_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceInformation", "Description", 9usize, |this| ((**(this as *const *const IVoiceInformation_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gender(this: *mut core::ffi::c_void, result__: *mut VoiceGender) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVoiceInformation", "Gender", 10usize, |this| ((**(this as *const *const IVoiceInformation_Vtbl)).Gender)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayName, Id, Language, Description, Gender }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVoiceInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
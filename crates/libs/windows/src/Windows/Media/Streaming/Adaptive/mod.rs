@@ -1502,6 +1502,7 @@ impl AdaptiveMediaSourceCreationStatus {
 impl windows_core::TypeKind for AdaptiveMediaSourceCreationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AdaptiveMediaSourceCreationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AdaptiveMediaSourceCreationStatus").field(&self.0).finish()
@@ -1527,6 +1528,7 @@ impl AdaptiveMediaSourceDiagnosticType {
 impl windows_core::TypeKind for AdaptiveMediaSourceDiagnosticType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AdaptiveMediaSourceDiagnosticType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AdaptiveMediaSourceDiagnosticType").field(&self.0).finish()
@@ -1550,6 +1552,7 @@ impl AdaptiveMediaSourceDownloadBitrateChangedReason {
 impl windows_core::TypeKind for AdaptiveMediaSourceDownloadBitrateChangedReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AdaptiveMediaSourceDownloadBitrateChangedReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AdaptiveMediaSourceDownloadBitrateChangedReason").field(&self.0).finish()
@@ -1572,6 +1575,7 @@ impl AdaptiveMediaSourceResourceType {
 impl windows_core::TypeKind for AdaptiveMediaSourceResourceType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AdaptiveMediaSourceResourceType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AdaptiveMediaSourceResourceType").field(&self.0).finish()
@@ -1580,3 +1584,5 @@ impl core::fmt::Debug for AdaptiveMediaSourceResourceType {
 impl windows_core::RuntimeType for AdaptiveMediaSourceResourceType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.Streaming.Adaptive.AdaptiveMediaSourceResourceType;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,778 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_Core"))]
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsLive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "IsLive", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).IsLive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredLiveOffset(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "DesiredLiveOffset", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).DesiredLiveOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredLiveOffset(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "SetDesiredLiveOffset", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).SetDesiredLiveOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialBitrate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "InitialBitrate", 9usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).InitialBitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialBitrate(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "SetInitialBitrate", 10usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).SetInitialBitrate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentDownloadBitrate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "CurrentDownloadBitrate", 11usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).CurrentDownloadBitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPlaybackBitrate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "CurrentPlaybackBitrate", 12usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).CurrentPlaybackBitrate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AvailableBitrates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "AvailableBitrates", 13usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).AvailableBitrates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredMinBitrate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "DesiredMinBitrate", 14usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).DesiredMinBitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredMinBitrate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "SetDesiredMinBitrate", 15usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).SetDesiredMinBitrate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredMaxBitrate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "DesiredMaxBitrate", 16usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).DesiredMaxBitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredMaxBitrate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "SetDesiredMaxBitrate", 17usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).SetDesiredMaxBitrate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioOnlyPlayback(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "AudioOnlyPlayback", 18usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).AudioOnlyPlayback)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InboundBitsPerSecond(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "InboundBitsPerSecond", 19usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).InboundBitsPerSecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InboundBitsPerSecondWindow(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "InboundBitsPerSecondWindow", 20usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).InboundBitsPerSecondWindow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInboundBitsPerSecondWindow(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "SetInboundBitsPerSecondWindow", 21usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).SetInboundBitsPerSecondWindow)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadBitrateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "DownloadBitrateChanged", 22usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).DownloadBitrateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDownloadBitrateChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "RemoveDownloadBitrateChanged", 23usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).RemoveDownloadBitrateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackBitrateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "PlaybackBitrateChanged", 24usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).PlaybackBitrateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePlaybackBitrateChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "RemovePlaybackBitrateChanged", 25usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).RemovePlaybackBitrateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "DownloadRequested", 26usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).DownloadRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDownloadRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "RemoveDownloadRequested", 27usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).RemoveDownloadRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "DownloadCompleted", 28usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).DownloadCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDownloadCompleted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "RemoveDownloadCompleted", 29usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).RemoveDownloadCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadFailed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "DownloadFailed", 30usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).DownloadFailed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDownloadFailed(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource", "RemoveDownloadFailed", 31usize, |this| ((**(this as *const *const IAdaptiveMediaSource_Vtbl)).RemoveDownloadFailed)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsLive,
+            DesiredLiveOffset,
+            SetDesiredLiveOffset,
+            InitialBitrate,
+            SetInitialBitrate,
+            CurrentDownloadBitrate,
+            CurrentPlaybackBitrate,
+            #[cfg(feature = "Foundation_Collections")]
+            AvailableBitrates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AvailableBitrates: 0,
+            DesiredMinBitrate,
+            SetDesiredMinBitrate,
+            DesiredMaxBitrate,
+            SetDesiredMaxBitrate,
+            AudioOnlyPlayback,
+            InboundBitsPerSecond,
+            InboundBitsPerSecondWindow,
+            SetInboundBitsPerSecondWindow,
+            DownloadBitrateChanged,
+            RemoveDownloadBitrateChanged,
+            PlaybackBitrateChanged,
+            RemovePlaybackBitrateChanged,
+            DownloadRequested,
+            RemoveDownloadRequested,
+            DownloadCompleted,
+            RemoveDownloadCompleted,
+            DownloadFailed,
+            RemoveDownloadFailed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSource2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdvancedSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource2", "AdvancedSettings", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSource2_Vtbl)).AdvancedSettings)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AdvancedSettings }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSource2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSource3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinLiveOffset(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource3", "MinLiveOffset", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSource3_Vtbl)).MinLiveOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSeekableWindowSize(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource3", "MaxSeekableWindowSize", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSource3_Vtbl)).MaxSeekableWindowSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredSeekableWindowSize(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource3", "DesiredSeekableWindowSize", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSource3_Vtbl)).DesiredSeekableWindowSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredSeekableWindowSize(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource3", "SetDesiredSeekableWindowSize", 9usize, |this| ((**(this as *const *const IAdaptiveMediaSource3_Vtbl)).SetDesiredSeekableWindowSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Diagnostics(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource3", "Diagnostics", 10usize, |this| ((**(this as *const *const IAdaptiveMediaSource3_Vtbl)).Diagnostics)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCorrelatedTimes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSource3", "GetCorrelatedTimes", 11usize, |this| ((**(this as *const *const IAdaptiveMediaSource3_Vtbl)).GetCorrelatedTimes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MinLiveOffset,
+            MaxSeekableWindowSize,
+            DesiredSeekableWindowSize,
+            SetDesiredSeekableWindowSize,
+            Diagnostics,
+            GetCorrelatedTimes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSource3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceAdvancedSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllSegmentsIndependent(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceAdvancedSettings", "AllSegmentsIndependent", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceAdvancedSettings_Vtbl)).AllSegmentsIndependent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllSegmentsIndependent(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceAdvancedSettings", "SetAllSegmentsIndependent", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceAdvancedSettings_Vtbl)).SetAllSegmentsIndependent)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredBitrateHeadroomRatio(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceAdvancedSettings", "DesiredBitrateHeadroomRatio", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSourceAdvancedSettings_Vtbl)).DesiredBitrateHeadroomRatio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredBitrateHeadroomRatio(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceAdvancedSettings", "SetDesiredBitrateHeadroomRatio", 9usize, |this| ((**(this as *const *const IAdaptiveMediaSourceAdvancedSettings_Vtbl)).SetDesiredBitrateHeadroomRatio)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitrateDowngradeTriggerRatio(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceAdvancedSettings", "BitrateDowngradeTriggerRatio", 10usize, |this| ((**(this as *const *const IAdaptiveMediaSourceAdvancedSettings_Vtbl)).BitrateDowngradeTriggerRatio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBitrateDowngradeTriggerRatio(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceAdvancedSettings", "SetBitrateDowngradeTriggerRatio", 11usize, |this| ((**(this as *const *const IAdaptiveMediaSourceAdvancedSettings_Vtbl)).SetBitrateDowngradeTriggerRatio)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AllSegmentsIndependent,
+            SetAllSegmentsIndependent,
+            DesiredBitrateHeadroomRatio,
+            SetDesiredBitrateHeadroomRatio,
+            BitrateDowngradeTriggerRatio,
+            SetBitrateDowngradeTriggerRatio,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceAdvancedSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceCorrelatedTimes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceCorrelatedTimes", "Position", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceCorrelatedTimes_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PresentationTimeStamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceCorrelatedTimes", "PresentationTimeStamp", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceCorrelatedTimes_Vtbl)).PresentationTimeStamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProgramDateTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceCorrelatedTimes", "ProgramDateTime", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSourceCorrelatedTimes_Vtbl)).ProgramDateTime)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Position, PresentationTimeStamp, ProgramDateTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceCorrelatedTimes as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Web_Http")]
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceCreationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut AdaptiveMediaSourceCreationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceCreationResult", "Status", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceCreationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceCreationResult", "MediaSource", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceCreationResult_Vtbl)).MediaSource)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Http")]
+        unsafe extern "system" fn HttpResponseMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceCreationResult", "HttpResponseMessage", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSourceCreationResult_Vtbl)).HttpResponseMessage)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            MediaSource,
+            #[cfg(feature = "Web_Http")]
+            HttpResponseMessage,
+            #[cfg(not(feature = "Web_Http"))]
+            HttpResponseMessage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceCreationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceCreationResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceCreationResult2", "ExtendedError", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceCreationResult2_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceCreationResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDiagnosticAvailableEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DiagnosticType(this: *mut core::ffi::c_void, result__: *mut AdaptiveMediaSourceDiagnosticType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDiagnosticAvailableEventArgs", "DiagnosticType", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDiagnosticAvailableEventArgs_Vtbl)).DiagnosticType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDiagnosticAvailableEventArgs", "RequestId", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDiagnosticAvailableEventArgs_Vtbl)).RequestId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDiagnosticAvailableEventArgs", "Position", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDiagnosticAvailableEventArgs_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SegmentId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDiagnosticAvailableEventArgs", "SegmentId", 9usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDiagnosticAvailableEventArgs_Vtbl)).SegmentId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceType(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDiagnosticAvailableEventArgs", "ResourceType", 10usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDiagnosticAvailableEventArgs_Vtbl)).ResourceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDiagnosticAvailableEventArgs", "ResourceUri", 11usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDiagnosticAvailableEventArgs_Vtbl)).ResourceUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceByteRangeOffset(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDiagnosticAvailableEventArgs", "ResourceByteRangeOffset", 12usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDiagnosticAvailableEventArgs_Vtbl)).ResourceByteRangeOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceByteRangeLength(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDiagnosticAvailableEventArgs", "ResourceByteRangeLength", 13usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDiagnosticAvailableEventArgs_Vtbl)).ResourceByteRangeLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bitrate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDiagnosticAvailableEventArgs", "Bitrate", 14usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDiagnosticAvailableEventArgs_Vtbl)).Bitrate)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DiagnosticType,
+            RequestId,
+            Position,
+            SegmentId,
+            ResourceType,
+            ResourceUri,
+            ResourceByteRangeOffset,
+            ResourceByteRangeLength,
+            Bitrate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDiagnosticAvailableEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDiagnosticAvailableEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDiagnosticAvailableEventArgs2", "ExtendedError", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDiagnosticAvailableEventArgs2_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDiagnosticAvailableEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDiagnosticAvailableEventArgs3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResourceDuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDiagnosticAvailableEventArgs3", "ResourceDuration", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDiagnosticAvailableEventArgs3_Vtbl)).ResourceDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceContentType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDiagnosticAvailableEventArgs3", "ResourceContentType", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDiagnosticAvailableEventArgs3_Vtbl)).ResourceContentType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ResourceDuration, ResourceContentType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDiagnosticAvailableEventArgs3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDiagnostics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DiagnosticAvailable(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDiagnostics", "DiagnosticAvailable", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDiagnostics_Vtbl)).DiagnosticAvailable)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDiagnosticAvailable(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDiagnostics", "RemoveDiagnosticAvailable", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDiagnostics_Vtbl)).RemoveDiagnosticAvailable)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DiagnosticAvailable, RemoveDiagnosticAvailable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDiagnostics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDownloadBitrateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OldValue(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadBitrateChangedEventArgs", "OldValue", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadBitrateChangedEventArgs_Vtbl)).OldValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewValue(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadBitrateChangedEventArgs", "NewValue", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadBitrateChangedEventArgs_Vtbl)).NewValue)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OldValue, NewValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDownloadBitrateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDownloadBitrateChangedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut AdaptiveMediaSourceDownloadBitrateChangedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadBitrateChangedEventArgs2", "Reason", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadBitrateChangedEventArgs2_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDownloadBitrateChangedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Web_Http")]
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDownloadCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResourceType(this: *mut core::ffi::c_void, result__: *mut AdaptiveMediaSourceResourceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadCompletedEventArgs", "ResourceType", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadCompletedEventArgs_Vtbl)).ResourceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadCompletedEventArgs", "ResourceUri", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadCompletedEventArgs_Vtbl)).ResourceUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceByteRangeOffset(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadCompletedEventArgs", "ResourceByteRangeOffset", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadCompletedEventArgs_Vtbl)).ResourceByteRangeOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceByteRangeLength(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadCompletedEventArgs", "ResourceByteRangeLength", 9usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadCompletedEventArgs_Vtbl)).ResourceByteRangeLength)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Http")]
+        unsafe extern "system" fn HttpResponseMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadCompletedEventArgs", "HttpResponseMessage", 10usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadCompletedEventArgs_Vtbl)).HttpResponseMessage)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ResourceType,
+            ResourceUri,
+            ResourceByteRangeOffset,
+            ResourceByteRangeLength,
+            #[cfg(feature = "Web_Http")]
+            HttpResponseMessage,
+            #[cfg(not(feature = "Web_Http"))]
+            HttpResponseMessage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDownloadCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDownloadCompletedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadCompletedEventArgs2", "RequestId", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadCompletedEventArgs2_Vtbl)).RequestId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Statistics(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadCompletedEventArgs2", "Statistics", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadCompletedEventArgs2_Vtbl)).Statistics)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadCompletedEventArgs2", "Position", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadCompletedEventArgs2_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestId, Statistics, Position }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDownloadCompletedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDownloadCompletedEventArgs3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResourceDuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadCompletedEventArgs3", "ResourceDuration", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadCompletedEventArgs3_Vtbl)).ResourceDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceContentType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadCompletedEventArgs3", "ResourceContentType", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadCompletedEventArgs3_Vtbl)).ResourceContentType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ResourceDuration, ResourceContentType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDownloadCompletedEventArgs3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Web_Http")]
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDownloadFailedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResourceType(this: *mut core::ffi::c_void, result__: *mut AdaptiveMediaSourceResourceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadFailedEventArgs", "ResourceType", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadFailedEventArgs_Vtbl)).ResourceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadFailedEventArgs", "ResourceUri", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadFailedEventArgs_Vtbl)).ResourceUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceByteRangeOffset(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadFailedEventArgs", "ResourceByteRangeOffset", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadFailedEventArgs_Vtbl)).ResourceByteRangeOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceByteRangeLength(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadFailedEventArgs", "ResourceByteRangeLength", 9usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadFailedEventArgs_Vtbl)).ResourceByteRangeLength)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Http")]
+        unsafe extern "system" fn HttpResponseMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadFailedEventArgs", "HttpResponseMessage", 10usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadFailedEventArgs_Vtbl)).HttpResponseMessage)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ResourceType,
+            ResourceUri,
+            ResourceByteRangeOffset,
+            ResourceByteRangeLength,
+            #[cfg(feature = "Web_Http")]
+            HttpResponseMessage,
+            #[cfg(not(feature = "Web_Http"))]
+            HttpResponseMessage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDownloadFailedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDownloadFailedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadFailedEventArgs2", "RequestId", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadFailedEventArgs2_Vtbl)).RequestId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadFailedEventArgs2", "ExtendedError", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadFailedEventArgs2_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Statistics(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadFailedEventArgs2", "Statistics", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadFailedEventArgs2_Vtbl)).Statistics)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadFailedEventArgs2", "Position", 9usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadFailedEventArgs2_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestId, ExtendedError, Statistics, Position }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDownloadFailedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDownloadFailedEventArgs3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResourceDuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadFailedEventArgs3", "ResourceDuration", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadFailedEventArgs3_Vtbl)).ResourceDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceContentType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadFailedEventArgs3", "ResourceContentType", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadFailedEventArgs3_Vtbl)).ResourceContentType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ResourceDuration, ResourceContentType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDownloadFailedEventArgs3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDownloadRequestedDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadRequestedDeferral", "Complete", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadRequestedDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDownloadRequestedDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDownloadRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResourceType(this: *mut core::ffi::c_void, result__: *mut AdaptiveMediaSourceResourceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadRequestedEventArgs", "ResourceType", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadRequestedEventArgs_Vtbl)).ResourceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadRequestedEventArgs", "ResourceUri", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadRequestedEventArgs_Vtbl)).ResourceUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceByteRangeOffset(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadRequestedEventArgs", "ResourceByteRangeOffset", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadRequestedEventArgs_Vtbl)).ResourceByteRangeOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceByteRangeLength(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadRequestedEventArgs", "ResourceByteRangeLength", 9usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadRequestedEventArgs_Vtbl)).ResourceByteRangeLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Result(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadRequestedEventArgs", "Result", 10usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadRequestedEventArgs_Vtbl)).Result)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadRequestedEventArgs", "GetDeferral", 11usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ResourceType,
+            ResourceUri,
+            ResourceByteRangeOffset,
+            ResourceByteRangeLength,
+            Result,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDownloadRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDownloadRequestedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadRequestedEventArgs2", "RequestId", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadRequestedEventArgs2_Vtbl)).RequestId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadRequestedEventArgs2", "Position", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadRequestedEventArgs2_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestId, Position }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDownloadRequestedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDownloadRequestedEventArgs3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResourceDuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadRequestedEventArgs3", "ResourceDuration", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadRequestedEventArgs3_Vtbl)).ResourceDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceContentType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadRequestedEventArgs3", "ResourceContentType", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadRequestedEventArgs3_Vtbl)).ResourceContentType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ResourceDuration, ResourceContentType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDownloadRequestedEventArgs3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDownloadResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResourceUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadResult", "ResourceUri", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadResult_Vtbl)).ResourceUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResourceUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadResult", "SetResourceUri", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadResult_Vtbl)).SetResourceUri)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn InputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadResult", "InputStream", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadResult_Vtbl)).InputStream)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetInputStream(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadResult", "SetInputStream", 9usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadResult_Vtbl)).SetInputStream)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Buffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadResult", "Buffer", 10usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadResult_Vtbl)).Buffer)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetBuffer(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadResult", "SetBuffer", 11usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadResult_Vtbl)).SetBuffer)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadResult", "ContentType", 12usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadResult_Vtbl)).ContentType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentType(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadResult", "SetContentType", 13usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadResult_Vtbl)).SetContentType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedStatus(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadResult", "ExtendedStatus", 14usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadResult_Vtbl)).ExtendedStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtendedStatus(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadResult", "SetExtendedStatus", 15usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadResult_Vtbl)).SetExtendedStatus)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ResourceUri,
+            SetResourceUri,
+            #[cfg(feature = "Storage_Streams")]
+            InputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            InputStream: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetInputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetInputStream: 0,
+            #[cfg(feature = "Storage_Streams")]
+            Buffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Buffer: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetBuffer: 0,
+            ContentType,
+            SetContentType,
+            ExtendedStatus,
+            SetExtendedStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDownloadResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDownloadResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResourceByteRangeOffset(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadResult2", "ResourceByteRangeOffset", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadResult2_Vtbl)).ResourceByteRangeOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResourceByteRangeOffset(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadResult2", "SetResourceByteRangeOffset", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadResult2_Vtbl)).SetResourceByteRangeOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceByteRangeLength(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadResult2", "ResourceByteRangeLength", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadResult2_Vtbl)).ResourceByteRangeLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResourceByteRangeLength(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadResult2", "SetResourceByteRangeLength", 9usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadResult2_Vtbl)).SetResourceByteRangeLength)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ResourceByteRangeOffset,
+            SetResourceByteRangeOffset,
+            ResourceByteRangeLength,
+            SetResourceByteRangeLength,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDownloadResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceDownloadStatistics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContentBytesReceivedCount(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadStatistics", "ContentBytesReceivedCount", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadStatistics_Vtbl)).ContentBytesReceivedCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeToHeadersReceived(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadStatistics", "TimeToHeadersReceived", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadStatistics_Vtbl)).TimeToHeadersReceived)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeToFirstByteReceived(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadStatistics", "TimeToFirstByteReceived", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadStatistics_Vtbl)).TimeToFirstByteReceived)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeToLastByteReceived(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceDownloadStatistics", "TimeToLastByteReceived", 9usize, |this| ((**(this as *const *const IAdaptiveMediaSourceDownloadStatistics_Vtbl)).TimeToLastByteReceived)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ContentBytesReceivedCount,
+            TimeToHeadersReceived,
+            TimeToFirstByteReceived,
+            TimeToLastByteReceived,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceDownloadStatistics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourcePlaybackBitrateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OldValue(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourcePlaybackBitrateChangedEventArgs", "OldValue", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourcePlaybackBitrateChangedEventArgs_Vtbl)).OldValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewValue(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourcePlaybackBitrateChangedEventArgs", "NewValue", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourcePlaybackBitrateChangedEventArgs_Vtbl)).NewValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioOnly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourcePlaybackBitrateChangedEventArgs", "AudioOnly", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSourcePlaybackBitrateChangedEventArgs_Vtbl)).AudioOnly)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OldValue, NewValue, AudioOnly }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourcePlaybackBitrateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_Streams", feature = "Web_Http"))]
+impl windows_core::imp::ProxyVtable for IAdaptiveMediaSourceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsContentTypeSupported(this: *mut core::ffi::c_void, contenttype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceStatics", "IsContentTypeSupported", 6usize, |this| ((**(this as *const *const IAdaptiveMediaSourceStatics_Vtbl)).IsContentTypeSupported)(this, contenttype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromUriAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceStatics", "CreateFromUriAsync", 7usize, |this| ((**(this as *const *const IAdaptiveMediaSourceStatics_Vtbl)).CreateFromUriAsync)(this, uri, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Http")]
+        unsafe extern "system" fn CreateFromUriWithDownloaderAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, httpclient: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceStatics", "CreateFromUriWithDownloaderAsync", 8usize, |this| ((**(this as *const *const IAdaptiveMediaSourceStatics_Vtbl)).CreateFromUriWithDownloaderAsync)(this, uri, httpclient, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromStreamAsync(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, contenttype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceStatics", "CreateFromStreamAsync", 9usize, |this| ((**(this as *const *const IAdaptiveMediaSourceStatics_Vtbl)).CreateFromStreamAsync)(this, stream, uri, contenttype, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "Web_Http"))]
+        unsafe extern "system" fn CreateFromStreamWithDownloaderAsync(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, contenttype: core::mem::MaybeUninit<windows_core::HSTRING>, httpclient: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveMediaSourceStatics", "CreateFromStreamWithDownloaderAsync", 10usize, |this| ((**(this as *const *const IAdaptiveMediaSourceStatics_Vtbl)).CreateFromStreamWithDownloaderAsync)(this, stream, uri, contenttype, httpclient, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsContentTypeSupported,
+            CreateFromUriAsync,
+            #[cfg(feature = "Web_Http")]
+            CreateFromUriWithDownloaderAsync,
+            #[cfg(not(feature = "Web_Http"))]
+            CreateFromUriWithDownloaderAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromStreamAsync: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "Web_Http"))]
+            CreateFromStreamWithDownloaderAsync,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "Web_Http")))]
+            CreateFromStreamWithDownloaderAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveMediaSourceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
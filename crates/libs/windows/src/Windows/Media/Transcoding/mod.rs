@@ -254,6 +254,7 @@ impl MediaVideoProcessingAlgorithm {
 impl windows_core::TypeKind for MediaVideoProcessingAlgorithm {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaVideoProcessingAlgorithm {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaVideoProcessingAlgorithm").field(&self.0).finish()
@@ -274,6 +275,7 @@ impl TranscodeFailureReason {
 impl windows_core::TypeKind for TranscodeFailureReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TranscodeFailureReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TranscodeFailureReason").field(&self.0).finish()
@@ -282,3 +284,5 @@ impl core::fmt::Debug for TranscodeFailureReason {
 impl windows_core::RuntimeType for TranscodeFailureReason {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.Transcoding.TranscodeFailureReason;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,134 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMediaTranscoder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTrimStartTime(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder", "SetTrimStartTime", 6usize, |this| ((**(this as *const *const IMediaTranscoder_Vtbl)).SetTrimStartTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrimStartTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder", "TrimStartTime", 7usize, |this| ((**(this as *const *const IMediaTranscoder_Vtbl)).TrimStartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrimStopTime(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder", "SetTrimStopTime", 8usize, |this| ((**(this as *const *const IMediaTranscoder_Vtbl)).SetTrimStopTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrimStopTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder", "TrimStopTime", 9usize, |this| ((**(this as *const *const IMediaTranscoder_Vtbl)).TrimStopTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlwaysReencode(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder", "SetAlwaysReencode", 10usize, |this| ((**(this as *const *const IMediaTranscoder_Vtbl)).SetAlwaysReencode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlwaysReencode(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder", "AlwaysReencode", 11usize, |this| ((**(this as *const *const IMediaTranscoder_Vtbl)).AlwaysReencode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHardwareAccelerationEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder", "SetHardwareAccelerationEnabled", 12usize, |this| ((**(this as *const *const IMediaTranscoder_Vtbl)).SetHardwareAccelerationEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareAccelerationEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder", "HardwareAccelerationEnabled", 13usize, |this| ((**(this as *const *const IMediaTranscoder_Vtbl)).HardwareAccelerationEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAudioEffect(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder", "AddAudioEffect", 14usize, |this| ((**(this as *const *const IMediaTranscoder_Vtbl)).AddAudioEffect)(this, activatableclassid), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AddAudioEffectWithSettings(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, effectrequired: bool, configuration: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder", "AddAudioEffectWithSettings", 15usize, |this| ((**(this as *const *const IMediaTranscoder_Vtbl)).AddAudioEffectWithSettings)(this, activatableclassid, effectrequired, configuration), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddVideoEffect(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder", "AddVideoEffect", 16usize, |this| ((**(this as *const *const IMediaTranscoder_Vtbl)).AddVideoEffect)(this, activatableclassid), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AddVideoEffectWithSettings(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, effectrequired: bool, configuration: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder", "AddVideoEffectWithSettings", 17usize, |this| ((**(this as *const *const IMediaTranscoder_Vtbl)).AddVideoEffectWithSettings)(this, activatableclassid, effectrequired, configuration), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearEffects(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder", "ClearEffects", 18usize, |this| ((**(this as *const *const IMediaTranscoder_Vtbl)).ClearEffects)(this), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_MediaProperties", feature = "Storage"))]
+        unsafe extern "system" fn PrepareFileTranscodeAsync(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, destination: *mut core::ffi::c_void, profile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder", "PrepareFileTranscodeAsync", 19usize, |this| ((**(this as *const *const IMediaTranscoder_Vtbl)).PrepareFileTranscodeAsync)(this, source, destination, profile, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Media_MediaProperties", feature = "Storage_Streams"))]
+        unsafe extern "system" fn PrepareStreamTranscodeAsync(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, destination: *mut core::ffi::c_void, profile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder", "PrepareStreamTranscodeAsync", 20usize, |this| ((**(this as *const *const IMediaTranscoder_Vtbl)).PrepareStreamTranscodeAsync)(this, source, destination, profile, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetTrimStartTime,
+            TrimStartTime,
+            SetTrimStopTime,
+            TrimStopTime,
+            SetAlwaysReencode,
+            AlwaysReencode,
+            SetHardwareAccelerationEnabled,
+            HardwareAccelerationEnabled,
+            AddAudioEffect,
+            #[cfg(feature = "Foundation_Collections")]
+            AddAudioEffectWithSettings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AddAudioEffectWithSettings: 0,
+            AddVideoEffect,
+            #[cfg(feature = "Foundation_Collections")]
+            AddVideoEffectWithSettings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AddVideoEffectWithSettings: 0,
+            ClearEffects,
+            #[cfg(all(feature = "Media_MediaProperties", feature = "Storage"))]
+            PrepareFileTranscodeAsync,
+            #[cfg(not(all(feature = "Media_MediaProperties", feature = "Storage")))]
+            PrepareFileTranscodeAsync: 0,
+            #[cfg(all(feature = "Media_MediaProperties", feature = "Storage_Streams"))]
+            PrepareStreamTranscodeAsync,
+            #[cfg(not(all(feature = "Media_MediaProperties", feature = "Storage_Streams")))]
+            PrepareStreamTranscodeAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaTranscoder as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Media_Core", feature = "Media_MediaProperties", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMediaTranscoder2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Media_Core", feature = "Media_MediaProperties", feature = "Storage_Streams"))]
+        unsafe extern "system" fn PrepareMediaStreamSourceTranscodeAsync(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, destination: *mut core::ffi::c_void, profile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder2", "PrepareMediaStreamSourceTranscodeAsync", 6usize, |this| ((**(this as *const *const IMediaTranscoder2_Vtbl)).PrepareMediaStreamSourceTranscodeAsync)(this, source, destination, profile, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoProcessingAlgorithm(this: *mut core::ffi::c_void, value: MediaVideoProcessingAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder2", "SetVideoProcessingAlgorithm", 7usize, |this| ((**(this as *const *const IMediaTranscoder2_Vtbl)).SetVideoProcessingAlgorithm)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoProcessingAlgorithm(this: *mut core::ffi::c_void, result__: *mut MediaVideoProcessingAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTranscoder2", "VideoProcessingAlgorithm", 8usize, |this| ((**(this as *const *const IMediaTranscoder2_Vtbl)).VideoProcessingAlgorithm)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Media_Core", feature = "Media_MediaProperties", feature = "Storage_Streams"))]
+            PrepareMediaStreamSourceTranscodeAsync,
+            #[cfg(not(all(feature = "Media_Core", feature = "Media_MediaProperties", feature = "Storage_Streams")))]
+            PrepareMediaStreamSourceTranscodeAsync: 0,
+            SetVideoProcessingAlgorithm,
+            VideoProcessingAlgorithm,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaTranscoder2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrepareTranscodeResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanTranscode(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrepareTranscodeResult", "CanTranscode", 6usize, |this| ((**(this as *const *const IPrepareTranscodeResult_Vtbl)).CanTranscode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FailureReason(this: *mut core::ffi::c_void, result__: *mut TranscodeFailureReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrepareTranscodeResult", "FailureReason", 7usize, |this| ((**(this as *const *const IPrepareTranscodeResult_Vtbl)).FailureReason)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TranscodeAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrepareTranscodeResult", "TranscodeAsync", 8usize, |this| ((**(this as *const *const IPrepareTranscodeResult_Vtbl)).TranscodeAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CanTranscode, FailureReason, TranscodeAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrepareTranscodeResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -2628,6 +2628,7 @@ impl AudioBufferAccessMode {
 impl windows_core::TypeKind for AudioBufferAccessMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioBufferAccessMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioBufferAccessMode").field(&self.0).finish()
@@ -2646,6 +2647,7 @@ impl AudioProcessing {
 impl windows_core::TypeKind for AudioProcessing {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioProcessing {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioProcessing").field(&self.0).finish()
@@ -2665,6 +2667,7 @@ impl MediaPlaybackAutoRepeatMode {
 impl windows_core::TypeKind for MediaPlaybackAutoRepeatMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaPlaybackAutoRepeatMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaPlaybackAutoRepeatMode").field(&self.0).finish()
@@ -2686,6 +2689,7 @@ impl MediaPlaybackStatus {
 impl windows_core::TypeKind for MediaPlaybackStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaPlaybackStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaPlaybackStatus").field(&self.0).finish()
@@ -2706,6 +2710,7 @@ impl MediaPlaybackType {
 impl windows_core::TypeKind for MediaPlaybackType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaPlaybackType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaPlaybackType").field(&self.0).finish()
@@ -2726,6 +2731,7 @@ impl MediaTimelineControllerState {
 impl windows_core::TypeKind for MediaTimelineControllerState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MediaTimelineControllerState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MediaTimelineControllerState").field(&self.0).finish()
@@ -2745,6 +2751,7 @@ impl SoundLevel {
 impl windows_core::TypeKind for SoundLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SoundLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SoundLevel").field(&self.0).finish()
@@ -2771,6 +2778,7 @@ impl SystemMediaTransportControlsButton {
 impl windows_core::TypeKind for SystemMediaTransportControlsButton {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SystemMediaTransportControlsButton {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SystemMediaTransportControlsButton").field(&self.0).finish()
@@ -2788,6 +2796,7 @@ impl SystemMediaTransportControlsProperty {
 impl windows_core::TypeKind for SystemMediaTransportControlsProperty {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SystemMediaTransportControlsProperty {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SystemMediaTransportControlsProperty").field(&self.0).finish()
@@ -2797,7 +2806,8 @@ impl windows_core::RuntimeType for SystemMediaTransportControlsProperty {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Media.SystemMediaTransportControlsProperty;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MediaTimeRange {
     pub Start: super::Foundation::TimeSpan,
     pub End: super::Foundation::TimeSpan,
@@ -2815,3 +2825,5 @@ impl Default for MediaTimeRange {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1380 @@
+impl windows_core::imp::ProxyVtable for IAudioBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Capacity(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioBuffer", "Capacity", 6usize, |this| ((**(this as *const *const IAudioBuffer_Vtbl)).Capacity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioBuffer", "Length", 7usize, |this| ((**(this as *const *const IAudioBuffer_Vtbl)).Length)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLength(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioBuffer", "SetLength", 8usize, |this| ((**(this as *const *const IAudioBuffer_Vtbl)).SetLength)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Capacity, Length, SetLength }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioBuffer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAudioFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LockBuffer(this: *mut core::ffi::c_void, mode: AudioBufferAccessMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFrame", "LockBuffer", 6usize, |this| ((**(this as *const *const IAudioFrame_Vtbl)).LockBuffer)(this, mode, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LockBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioFrameFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, capacity: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFrameFactory", "Create", 6usize, |this| ((**(this as *const *const IAudioFrameFactory_Vtbl)).Create)(this, capacity, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioFrameFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAutoRepeatModeChangeRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestedAutoRepeatMode(this: *mut core::ffi::c_void, result__: *mut MediaPlaybackAutoRepeatMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutoRepeatModeChangeRequestedEventArgs", "RequestedAutoRepeatMode", 6usize, |this| ((**(this as *const *const IAutoRepeatModeChangeRequestedEventArgs_Vtbl)).RequestedAutoRepeatMode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestedAutoRepeatMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAutoRepeatModeChangeRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImageDisplayProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageDisplayProperties", "Title", 6usize, |this| ((**(this as *const *const IImageDisplayProperties_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageDisplayProperties", "SetTitle", 7usize, |this| ((**(this as *const *const IImageDisplayProperties_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subtitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageDisplayProperties", "Subtitle", 8usize, |this| ((**(this as *const *const IImageDisplayProperties_Vtbl)).Subtitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubtitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageDisplayProperties", "SetSubtitle", 9usize, |this| ((**(this as *const *const IImageDisplayProperties_Vtbl)).SetSubtitle)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Title, SetTitle, Subtitle, SetSubtitle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageDisplayProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IMediaControl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SoundLevelChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "SoundLevelChanged", 6usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).SoundLevelChanged)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveSoundLevelChanged(this: *mut core::ffi::c_void, cookie: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RemoveSoundLevelChanged", 7usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RemoveSoundLevelChanged)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PlayPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "PlayPressed", 8usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).PlayPressed)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemovePlayPressed(this: *mut core::ffi::c_void, cookie: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RemovePlayPressed", 9usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RemovePlayPressed)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PausePressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "PausePressed", 10usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).PausePressed)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemovePausePressed(this: *mut core::ffi::c_void, cookie: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RemovePausePressed", 11usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RemovePausePressed)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn StopPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "StopPressed", 12usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).StopPressed)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveStopPressed(this: *mut core::ffi::c_void, cookie: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RemoveStopPressed", 13usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RemoveStopPressed)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PlayPauseTogglePressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "PlayPauseTogglePressed", 14usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).PlayPauseTogglePressed)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemovePlayPauseTogglePressed(this: *mut core::ffi::c_void, cookie: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RemovePlayPauseTogglePressed", 15usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RemovePlayPauseTogglePressed)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RecordPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RecordPressed", 16usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RecordPressed)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveRecordPressed(this: *mut core::ffi::c_void, cookie: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RemoveRecordPressed", 17usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RemoveRecordPressed)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn NextTrackPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "NextTrackPressed", 18usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).NextTrackPressed)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveNextTrackPressed(this: *mut core::ffi::c_void, cookie: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RemoveNextTrackPressed", 19usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RemoveNextTrackPressed)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PreviousTrackPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "PreviousTrackPressed", 20usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).PreviousTrackPressed)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemovePreviousTrackPressed(this: *mut core::ffi::c_void, cookie: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RemovePreviousTrackPressed", 21usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RemovePreviousTrackPressed)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn FastForwardPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "FastForwardPressed", 22usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).FastForwardPressed)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveFastForwardPressed(this: *mut core::ffi::c_void, cookie: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RemoveFastForwardPressed", 23usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RemoveFastForwardPressed)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RewindPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RewindPressed", 24usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RewindPressed)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveRewindPressed(this: *mut core::ffi::c_void, cookie: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RemoveRewindPressed", 25usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RemoveRewindPressed)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ChannelUpPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "ChannelUpPressed", 26usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).ChannelUpPressed)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveChannelUpPressed(this: *mut core::ffi::c_void, cookie: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RemoveChannelUpPressed", 27usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RemoveChannelUpPressed)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ChannelDownPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "ChannelDownPressed", 28usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).ChannelDownPressed)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveChannelDownPressed(this: *mut core::ffi::c_void, cookie: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RemoveChannelDownPressed", 29usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RemoveChannelDownPressed)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SoundLevel(this: *mut core::ffi::c_void, result__: *mut SoundLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "SoundLevel", 30usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).SoundLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetTrackName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "SetTrackName", 31usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).SetTrackName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TrackName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "TrackName", 32usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).TrackName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetArtistName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "SetArtistName", 33usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).SetArtistName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ArtistName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "ArtistName", 34usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).ArtistName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetIsPlaying(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "SetIsPlaying", 35usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).SetIsPlaying)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsPlaying(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "IsPlaying", 36usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).IsPlaying)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetAlbumArt(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "SetAlbumArt", 37usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).SetAlbumArt)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AlbumArt(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "AlbumArt", 38usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).AlbumArt)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            SoundLevelChanged,
+            #[cfg(not(feature = "deprecated"))]
+            SoundLevelChanged: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveSoundLevelChanged,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveSoundLevelChanged: 0,
+            #[cfg(feature = "deprecated")]
+            PlayPressed,
+            #[cfg(not(feature = "deprecated"))]
+            PlayPressed: 0,
+            #[cfg(feature = "deprecated")]
+            RemovePlayPressed,
+            #[cfg(not(feature = "deprecated"))]
+            RemovePlayPressed: 0,
+            #[cfg(feature = "deprecated")]
+            PausePressed,
+            #[cfg(not(feature = "deprecated"))]
+            PausePressed: 0,
+            #[cfg(feature = "deprecated")]
+            RemovePausePressed,
+            #[cfg(not(feature = "deprecated"))]
+            RemovePausePressed: 0,
+            #[cfg(feature = "deprecated")]
+            StopPressed,
+            #[cfg(not(feature = "deprecated"))]
+            StopPressed: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveStopPressed,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveStopPressed: 0,
+            #[cfg(feature = "deprecated")]
+            PlayPauseTogglePressed,
+            #[cfg(not(feature = "deprecated"))]
+            PlayPauseTogglePressed: 0,
+            #[cfg(feature = "deprecated")]
+            RemovePlayPauseTogglePressed,
+            #[cfg(not(feature = "deprecated"))]
+            RemovePlayPauseTogglePressed: 0,
+            #[cfg(feature = "deprecated")]
+            RecordPressed,
+            #[cfg(not(feature = "deprecated"))]
+            RecordPressed: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveRecordPressed,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveRecordPressed: 0,
+            #[cfg(feature = "deprecated")]
+            NextTrackPressed,
+            #[cfg(not(feature = "deprecated"))]
+            NextTrackPressed: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveNextTrackPressed,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveNextTrackPressed: 0,
+            #[cfg(feature = "deprecated")]
+            PreviousTrackPressed,
+            #[cfg(not(feature = "deprecated"))]
+            PreviousTrackPressed: 0,
+            #[cfg(feature = "deprecated")]
+            RemovePreviousTrackPressed,
+            #[cfg(not(feature = "deprecated"))]
+            RemovePreviousTrackPressed: 0,
+            #[cfg(feature = "deprecated")]
+            FastForwardPressed,
+            #[cfg(not(feature = "deprecated"))]
+            FastForwardPressed: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveFastForwardPressed,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveFastForwardPressed: 0,
+            #[cfg(feature = "deprecated")]
+            RewindPressed,
+            #[cfg(not(feature = "deprecated"))]
+            RewindPressed: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveRewindPressed,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveRewindPressed: 0,
+            #[cfg(feature = "deprecated")]
+            ChannelUpPressed,
+            #[cfg(not(feature = "deprecated"))]
+            ChannelUpPressed: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveChannelUpPressed,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveChannelUpPressed: 0,
+            #[cfg(feature = "deprecated")]
+            ChannelDownPressed,
+            #[cfg(not(feature = "deprecated"))]
+            ChannelDownPressed: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveChannelDownPressed,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveChannelDownPressed: 0,
+            #[cfg(feature = "deprecated")]
+            SoundLevel,
+            #[cfg(not(feature = "deprecated"))]
+            SoundLevel: 0,
+            #[cfg(feature = "deprecated")]
+            SetTrackName,
+            #[cfg(not(feature = "deprecated"))]
+            SetTrackName: 0,
+            #[cfg(feature = "deprecated")]
+            TrackName,
+            #[cfg(not(feature = "deprecated"))]
+            TrackName: 0,
+            #[cfg(feature = "deprecated")]
+            SetArtistName,
+            #[cfg(not(feature = "deprecated"))]
+            SetArtistName: 0,
+            #[cfg(feature = "deprecated")]
+            ArtistName,
+            #[cfg(not(feature = "deprecated"))]
+            ArtistName: 0,
+            #[cfg(feature = "deprecated")]
+            SetIsPlaying,
+            #[cfg(not(feature = "deprecated"))]
+            SetIsPlaying: 0,
+            #[cfg(feature = "deprecated")]
+            IsPlaying,
+            #[cfg(not(feature = "deprecated"))]
+            IsPlaying: 0,
+            #[cfg(feature = "deprecated")]
+            SetAlbumArt,
+            #[cfg(not(feature = "deprecated"))]
+            SetAlbumArt: 0,
+            #[cfg(feature = "deprecated")]
+            AlbumArt,
+            #[cfg(not(feature = "deprecated"))]
+            AlbumArt: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaExtension_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetProperties(this: *mut core::ffi::c_void, configuration: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaExtension", "SetProperties", 6usize, |this| ((**(this as *const *const IMediaExtension_Vtbl)).SetProperties)(this, configuration), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SetProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaExtension as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaExtensionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterSchemeHandler(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, scheme: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaExtensionManager", "RegisterSchemeHandler", 6usize, |this| ((**(this as *const *const IMediaExtensionManager_Vtbl)).RegisterSchemeHandler)(this, activatableclassid, scheme), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RegisterSchemeHandlerWithSettings(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, scheme: core::mem::MaybeUninit<windows_core::HSTRING>, configuration: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaExtensionManager", "RegisterSchemeHandlerWithSettings", 7usize, |this| ((**(this as *const *const IMediaExtensionManager_Vtbl)).RegisterSchemeHandlerWithSettings)(this, activatableclassid, scheme, configuration), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterByteStreamHandler(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, fileextension: core::mem::MaybeUninit<windows_core::HSTRING>, mimetype: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaExtensionManager", "RegisterByteStreamHandler", 8usize, |this| ((**(this as *const *const IMediaExtensionManager_Vtbl)).RegisterByteStreamHandler)(this, activatableclassid, fileextension, mimetype), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RegisterByteStreamHandlerWithSettings(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, fileextension: core::mem::MaybeUninit<windows_core::HSTRING>, mimetype: core::mem::MaybeUninit<windows_core::HSTRING>, configuration: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaExtensionManager", "RegisterByteStreamHandlerWithSettings", 9usize, |this| ((**(this as *const *const IMediaExtensionManager_Vtbl)).RegisterByteStreamHandlerWithSettings)(this, activatableclassid, fileextension, mimetype, configuration), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterAudioDecoder(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, inputsubtype: windows_core::GUID, outputsubtype: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaExtensionManager", "RegisterAudioDecoder", 10usize, |this| ((**(this as *const *const IMediaExtensionManager_Vtbl)).RegisterAudioDecoder)(this, activatableclassid, inputsubtype, outputsubtype), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RegisterAudioDecoderWithSettings(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, inputsubtype: windows_core::GUID, outputsubtype: windows_core::GUID, configuration: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaExtensionManager", "RegisterAudioDecoderWithSettings", 11usize, |this| ((**(this as *const *const IMediaExtensionManager_Vtbl)).RegisterAudioDecoderWithSettings)(this, activatableclassid, inputsubtype, outputsubtype, configuration), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterAudioEncoder(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, inputsubtype: windows_core::GUID, outputsubtype: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaExtensionManager", "RegisterAudioEncoder", 12usize, |this| ((**(this as *const *const IMediaExtensionManager_Vtbl)).RegisterAudioEncoder)(this, activatableclassid, inputsubtype, outputsubtype), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RegisterAudioEncoderWithSettings(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, inputsubtype: windows_core::GUID, outputsubtype: windows_core::GUID, configuration: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaExtensionManager", "RegisterAudioEncoderWithSettings", 13usize, |this| ((**(this as *const *const IMediaExtensionManager_Vtbl)).RegisterAudioEncoderWithSettings)(this, activatableclassid, inputsubtype, outputsubtype, configuration), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterVideoDecoder(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, inputsubtype: windows_core::GUID, outputsubtype: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaExtensionManager", "RegisterVideoDecoder", 14usize, |this| ((**(this as *const *const IMediaExtensionManager_Vtbl)).RegisterVideoDecoder)(this, activatableclassid, inputsubtype, outputsubtype), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RegisterVideoDecoderWithSettings(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, inputsubtype: windows_core::GUID, outputsubtype: windows_core::GUID, configuration: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaExtensionManager", "RegisterVideoDecoderWithSettings", 15usize, |this| ((**(this as *const *const IMediaExtensionManager_Vtbl)).RegisterVideoDecoderWithSettings)(this, activatableclassid, inputsubtype, outputsubtype, configuration), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterVideoEncoder(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, inputsubtype: windows_core::GUID, outputsubtype: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaExtensionManager", "RegisterVideoEncoder", 16usize, |this| ((**(this as *const *const IMediaExtensionManager_Vtbl)).RegisterVideoEncoder)(this, activatableclassid, inputsubtype, outputsubtype), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RegisterVideoEncoderWithSettings(this: *mut core::ffi::c_void, activatableclassid: core::mem::MaybeUninit<windows_core::HSTRING>, inputsubtype: windows_core::GUID, outputsubtype: windows_core::GUID, configuration: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaExtensionManager", "RegisterVideoEncoderWithSettings", 17usize, |this| ((**(this as *const *const IMediaExtensionManager_Vtbl)).RegisterVideoEncoderWithSettings)(this, activatableclassid, inputsubtype, outputsubtype, configuration), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterSchemeHandler,
+            #[cfg(feature = "Foundation_Collections")]
+            RegisterSchemeHandlerWithSettings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RegisterSchemeHandlerWithSettings: 0,
+            RegisterByteStreamHandler,
+            #[cfg(feature = "Foundation_Collections")]
+            RegisterByteStreamHandlerWithSettings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RegisterByteStreamHandlerWithSettings: 0,
+            RegisterAudioDecoder,
+            #[cfg(feature = "Foundation_Collections")]
+            RegisterAudioDecoderWithSettings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RegisterAudioDecoderWithSettings: 0,
+            RegisterAudioEncoder,
+            #[cfg(feature = "Foundation_Collections")]
+            RegisterAudioEncoderWithSettings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RegisterAudioEncoderWithSettings: 0,
+            RegisterVideoDecoder,
+            #[cfg(feature = "Foundation_Collections")]
+            RegisterVideoDecoderWithSettings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RegisterVideoDecoderWithSettings: 0,
+            RegisterVideoEncoder,
+            #[cfg(feature = "Foundation_Collections")]
+            RegisterVideoEncoderWithSettings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RegisterVideoEncoderWithSettings: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaExtensionManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_AppService", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IMediaExtensionManager2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_AppService")]
+        unsafe extern "system" fn RegisterMediaExtensionForAppService(this: *mut core::ffi::c_void, extension: *mut core::ffi::c_void, connection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaExtensionManager2", "RegisterMediaExtensionForAppService", 6usize, |this| ((**(this as *const *const IMediaExtensionManager2_Vtbl)).RegisterMediaExtensionForAppService)(this, extension, connection), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_AppService")]
+            RegisterMediaExtensionForAppService,
+            #[cfg(not(feature = "ApplicationModel_AppService"))]
+            RegisterMediaExtensionForAppService: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaExtensionManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrame", "Type", 6usize, |this| ((**(this as *const *const IMediaFrame_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReadOnly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrame", "IsReadOnly", 7usize, |this| ((**(this as *const *const IMediaFrame_Vtbl)).IsReadOnly)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRelativeTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrame", "SetRelativeTime", 8usize, |this| ((**(this as *const *const IMediaFrame_Vtbl)).SetRelativeTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RelativeTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrame", "RelativeTime", 9usize, |this| ((**(this as *const *const IMediaFrame_Vtbl)).RelativeTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSystemRelativeTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrame", "SetSystemRelativeTime", 10usize, |this| ((**(this as *const *const IMediaFrame_Vtbl)).SetSystemRelativeTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemRelativeTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrame", "SystemRelativeTime", 11usize, |this| ((**(this as *const *const IMediaFrame_Vtbl)).SystemRelativeTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrame", "SetDuration", 12usize, |this| ((**(this as *const *const IMediaFrame_Vtbl)).SetDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrame", "Duration", 13usize, |this| ((**(this as *const *const IMediaFrame_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsDiscontinuous(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrame", "SetIsDiscontinuous", 14usize, |this| ((**(this as *const *const IMediaFrame_Vtbl)).SetIsDiscontinuous)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDiscontinuous(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrame", "IsDiscontinuous", 15usize, |this| ((**(this as *const *const IMediaFrame_Vtbl)).IsDiscontinuous)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ExtendedProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFrame", "ExtendedProperties", 16usize, |this| ((**(this as *const *const IMediaFrame_Vtbl)).ExtendedProperties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            IsReadOnly,
+            SetRelativeTime,
+            RelativeTime,
+            SetSystemRelativeTime,
+            SystemRelativeTime,
+            SetDuration,
+            Duration,
+            SetIsDiscontinuous,
+            IsDiscontinuous,
+            #[cfg(feature = "Foundation_Collections")]
+            ExtendedProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ExtendedProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaMarker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Time(this: *mut core::ffi::c_void, result__: *mut super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaMarker", "Time", 6usize, |this| ((**(this as *const *const IMediaMarker_Vtbl)).Time)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaMarkerType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaMarker", "MediaMarkerType", 7usize, |this| ((**(this as *const *const IMediaMarker_Vtbl)).MediaMarkerType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaMarker", "Text", 8usize, |this| ((**(this as *const *const IMediaMarker_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Time, MediaMarkerType, Text }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaMarker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaMarkerTypesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Bookmark(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaMarkerTypesStatics", "Bookmark", 6usize, |this| ((**(this as *const *const IMediaMarkerTypesStatics_Vtbl)).Bookmark)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Bookmark }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaMarkerTypesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaMarkers_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Markers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaMarkers", "Markers", 6usize, |this| ((**(this as *const *const IMediaMarkers_Vtbl)).Markers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Markers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Markers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaMarkers as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMediaProcessingTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Arguments(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaProcessingTriggerDetails", "Arguments", 6usize, |this| ((**(this as *const *const IMediaProcessingTriggerDetails_Vtbl)).Arguments)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Arguments,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Arguments: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaProcessingTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaTimelineController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController", "Start", 6usize, |this| ((**(this as *const *const IMediaTimelineController_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController", "Resume", 7usize, |this| ((**(this as *const *const IMediaTimelineController_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController", "Pause", 8usize, |this| ((**(this as *const *const IMediaTimelineController_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController", "Position", 9usize, |this| ((**(this as *const *const IMediaTimelineController_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, value: super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController", "SetPosition", 10usize, |this| ((**(this as *const *const IMediaTimelineController_Vtbl)).SetPosition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClockRate(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController", "ClockRate", 11usize, |this| ((**(this as *const *const IMediaTimelineController_Vtbl)).ClockRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClockRate(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController", "SetClockRate", 12usize, |this| ((**(this as *const *const IMediaTimelineController_Vtbl)).SetClockRate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut MediaTimelineControllerState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController", "State", 13usize, |this| ((**(this as *const *const IMediaTimelineController_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionChanged(this: *mut core::ffi::c_void, positionchangedeventhandler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController", "PositionChanged", 14usize, |this| ((**(this as *const *const IMediaTimelineController_Vtbl)).PositionChanged)(this, positionchangedeventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePositionChanged(this: *mut core::ffi::c_void, eventcookie: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController", "RemovePositionChanged", 15usize, |this| ((**(this as *const *const IMediaTimelineController_Vtbl)).RemovePositionChanged)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateChanged(this: *mut core::ffi::c_void, statechangedeventhandler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController", "StateChanged", 16usize, |this| ((**(this as *const *const IMediaTimelineController_Vtbl)).StateChanged)(this, statechangedeventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStateChanged(this: *mut core::ffi::c_void, eventcookie: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController", "RemoveStateChanged", 17usize, |this| ((**(this as *const *const IMediaTimelineController_Vtbl)).RemoveStateChanged)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Start,
+            Resume,
+            Pause,
+            Position,
+            SetPosition,
+            ClockRate,
+            SetClockRate,
+            State,
+            PositionChanged,
+            RemovePositionChanged,
+            StateChanged,
+            RemoveStateChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaTimelineController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaTimelineController2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController2", "Duration", 6usize, |this| ((**(this as *const *const IMediaTimelineController2_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController2", "SetDuration", 7usize, |this| ((**(this as *const *const IMediaTimelineController2_Vtbl)).SetDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLoopingEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController2", "IsLoopingEnabled", 8usize, |this| ((**(this as *const *const IMediaTimelineController2_Vtbl)).IsLoopingEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsLoopingEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController2", "SetIsLoopingEnabled", 9usize, |this| ((**(this as *const *const IMediaTimelineController2_Vtbl)).SetIsLoopingEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Failed(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController2", "Failed", 10usize, |this| ((**(this as *const *const IMediaTimelineController2_Vtbl)).Failed)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFailed(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController2", "RemoveFailed", 11usize, |this| ((**(this as *const *const IMediaTimelineController2_Vtbl)).RemoveFailed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ended(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController2", "Ended", 12usize, |this| ((**(this as *const *const IMediaTimelineController2_Vtbl)).Ended)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnded(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineController2", "RemoveEnded", 13usize, |this| ((**(this as *const *const IMediaTimelineController2_Vtbl)).RemoveEnded)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Duration,
+            SetDuration,
+            IsLoopingEnabled,
+            SetIsLoopingEnabled,
+            Failed,
+            RemoveFailed,
+            Ended,
+            RemoveEnded,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaTimelineController2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaTimelineControllerFailedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTimelineControllerFailedEventArgs", "ExtendedError", 6usize, |this| ((**(this as *const *const IMediaTimelineControllerFailedEventArgs_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaTimelineControllerFailedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMusicDisplayProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicDisplayProperties", "Title", 6usize, |this| ((**(this as *const *const IMusicDisplayProperties_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicDisplayProperties", "SetTitle", 7usize, |this| ((**(this as *const *const IMusicDisplayProperties_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlbumArtist(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicDisplayProperties", "AlbumArtist", 8usize, |this| ((**(this as *const *const IMusicDisplayProperties_Vtbl)).AlbumArtist)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlbumArtist(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicDisplayProperties", "SetAlbumArtist", 9usize, |this| ((**(this as *const *const IMusicDisplayProperties_Vtbl)).SetAlbumArtist)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Artist(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicDisplayProperties", "Artist", 10usize, |this| ((**(this as *const *const IMusicDisplayProperties_Vtbl)).Artist)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetArtist(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicDisplayProperties", "SetArtist", 11usize, |this| ((**(this as *const *const IMusicDisplayProperties_Vtbl)).SetArtist)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Title,
+            SetTitle,
+            AlbumArtist,
+            SetAlbumArtist,
+            Artist,
+            SetArtist,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMusicDisplayProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMusicDisplayProperties2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AlbumTitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicDisplayProperties2", "AlbumTitle", 6usize, |this| ((**(this as *const *const IMusicDisplayProperties2_Vtbl)).AlbumTitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlbumTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicDisplayProperties2", "SetAlbumTitle", 7usize, |this| ((**(this as *const *const IMusicDisplayProperties2_Vtbl)).SetAlbumTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrackNumber(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicDisplayProperties2", "TrackNumber", 8usize, |this| ((**(this as *const *const IMusicDisplayProperties2_Vtbl)).TrackNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrackNumber(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicDisplayProperties2", "SetTrackNumber", 9usize, |this| ((**(this as *const *const IMusicDisplayProperties2_Vtbl)).SetTrackNumber)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Genres(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicDisplayProperties2", "Genres", 10usize, |this| ((**(this as *const *const IMusicDisplayProperties2_Vtbl)).Genres)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AlbumTitle,
+            SetAlbumTitle,
+            TrackNumber,
+            SetTrackNumber,
+            #[cfg(feature = "Foundation_Collections")]
+            Genres,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Genres: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMusicDisplayProperties2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMusicDisplayProperties3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AlbumTrackCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicDisplayProperties3", "AlbumTrackCount", 6usize, |this| ((**(this as *const *const IMusicDisplayProperties3_Vtbl)).AlbumTrackCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlbumTrackCount(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicDisplayProperties3", "SetAlbumTrackCount", 7usize, |this| ((**(this as *const *const IMusicDisplayProperties3_Vtbl)).SetAlbumTrackCount)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AlbumTrackCount, SetAlbumTrackCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMusicDisplayProperties3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlaybackPositionChangeRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestedPlaybackPosition(this: *mut core::ffi::c_void, result__: *mut super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaybackPositionChangeRequestedEventArgs", "RequestedPlaybackPosition", 6usize, |this| ((**(this as *const *const IPlaybackPositionChangeRequestedEventArgs_Vtbl)).RequestedPlaybackPosition)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestedPlaybackPosition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaybackPositionChangeRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlaybackRateChangeRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestedPlaybackRate(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaybackRateChangeRequestedEventArgs", "RequestedPlaybackRate", 6usize, |this| ((**(this as *const *const IPlaybackRateChangeRequestedEventArgs_Vtbl)).RequestedPlaybackRate)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestedPlaybackRate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaybackRateChangeRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IShuffleEnabledChangeRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestedShuffleEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShuffleEnabledChangeRequestedEventArgs", "RequestedShuffleEnabled", 6usize, |this| ((**(this as *const *const IShuffleEnabledChangeRequestedEventArgs_Vtbl)).RequestedShuffleEnabled)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestedShuffleEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShuffleEnabledChangeRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemMediaTransportControls_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PlaybackStatus(this: *mut core::ffi::c_void, result__: *mut MediaPlaybackStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "PlaybackStatus", 6usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).PlaybackStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlaybackStatus(this: *mut core::ffi::c_void, value: MediaPlaybackStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "SetPlaybackStatus", 7usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).SetPlaybackStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayUpdater(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "DisplayUpdater", 8usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).DisplayUpdater)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SoundLevel(this: *mut core::ffi::c_void, result__: *mut SoundLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "SoundLevel", 9usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).SoundLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "IsEnabled", 10usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "SetIsEnabled", 11usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPlayEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "IsPlayEnabled", 12usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).IsPlayEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsPlayEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "SetIsPlayEnabled", 13usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).SetIsPlayEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStopEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "IsStopEnabled", 14usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).IsStopEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsStopEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "SetIsStopEnabled", 15usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).SetIsStopEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPauseEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "IsPauseEnabled", 16usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).IsPauseEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsPauseEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "SetIsPauseEnabled", 17usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).SetIsPauseEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRecordEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "IsRecordEnabled", 18usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).IsRecordEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsRecordEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "SetIsRecordEnabled", 19usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).SetIsRecordEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFastForwardEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "IsFastForwardEnabled", 20usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).IsFastForwardEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsFastForwardEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "SetIsFastForwardEnabled", 21usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).SetIsFastForwardEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRewindEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "IsRewindEnabled", 22usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).IsRewindEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsRewindEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "SetIsRewindEnabled", 23usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).SetIsRewindEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPreviousEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "IsPreviousEnabled", 24usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).IsPreviousEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsPreviousEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "SetIsPreviousEnabled", 25usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).SetIsPreviousEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNextEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "IsNextEnabled", 26usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).IsNextEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsNextEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "SetIsNextEnabled", 27usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).SetIsNextEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsChannelUpEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "IsChannelUpEnabled", 28usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).IsChannelUpEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsChannelUpEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "SetIsChannelUpEnabled", 29usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).SetIsChannelUpEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsChannelDownEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "IsChannelDownEnabled", 30usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).IsChannelDownEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsChannelDownEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "SetIsChannelDownEnabled", 31usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).SetIsChannelDownEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "ButtonPressed", 32usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).ButtonPressed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveButtonPressed(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "RemoveButtonPressed", 33usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).RemoveButtonPressed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropertyChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "PropertyChanged", 34usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).PropertyChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePropertyChanged(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls", "RemovePropertyChanged", 35usize, |this| ((**(this as *const *const ISystemMediaTransportControls_Vtbl)).RemovePropertyChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PlaybackStatus,
+            SetPlaybackStatus,
+            DisplayUpdater,
+            SoundLevel,
+            IsEnabled,
+            SetIsEnabled,
+            IsPlayEnabled,
+            SetIsPlayEnabled,
+            IsStopEnabled,
+            SetIsStopEnabled,
+            IsPauseEnabled,
+            SetIsPauseEnabled,
+            IsRecordEnabled,
+            SetIsRecordEnabled,
+            IsFastForwardEnabled,
+            SetIsFastForwardEnabled,
+            IsRewindEnabled,
+            SetIsRewindEnabled,
+            IsPreviousEnabled,
+            SetIsPreviousEnabled,
+            IsNextEnabled,
+            SetIsNextEnabled,
+            IsChannelUpEnabled,
+            SetIsChannelUpEnabled,
+            IsChannelDownEnabled,
+            SetIsChannelDownEnabled,
+            ButtonPressed,
+            RemoveButtonPressed,
+            PropertyChanged,
+            RemovePropertyChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemMediaTransportControls as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemMediaTransportControls2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AutoRepeatMode(this: *mut core::ffi::c_void, result__: *mut MediaPlaybackAutoRepeatMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls2", "AutoRepeatMode", 6usize, |this| ((**(this as *const *const ISystemMediaTransportControls2_Vtbl)).AutoRepeatMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoRepeatMode(this: *mut core::ffi::c_void, value: MediaPlaybackAutoRepeatMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls2", "SetAutoRepeatMode", 7usize, |this| ((**(this as *const *const ISystemMediaTransportControls2_Vtbl)).SetAutoRepeatMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShuffleEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls2", "ShuffleEnabled", 8usize, |this| ((**(this as *const *const ISystemMediaTransportControls2_Vtbl)).ShuffleEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShuffleEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls2", "SetShuffleEnabled", 9usize, |this| ((**(this as *const *const ISystemMediaTransportControls2_Vtbl)).SetShuffleEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackRate(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls2", "PlaybackRate", 10usize, |this| ((**(this as *const *const ISystemMediaTransportControls2_Vtbl)).PlaybackRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlaybackRate(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls2", "SetPlaybackRate", 11usize, |this| ((**(this as *const *const ISystemMediaTransportControls2_Vtbl)).SetPlaybackRate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateTimelineProperties(this: *mut core::ffi::c_void, timelineproperties: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls2", "UpdateTimelineProperties", 12usize, |this| ((**(this as *const *const ISystemMediaTransportControls2_Vtbl)).UpdateTimelineProperties)(this, timelineproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackPositionChangeRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls2", "PlaybackPositionChangeRequested", 13usize, |this| ((**(this as *const *const ISystemMediaTransportControls2_Vtbl)).PlaybackPositionChangeRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePlaybackPositionChangeRequested(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls2", "RemovePlaybackPositionChangeRequested", 14usize, |this| ((**(this as *const *const ISystemMediaTransportControls2_Vtbl)).RemovePlaybackPositionChangeRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackRateChangeRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls2", "PlaybackRateChangeRequested", 15usize, |this| ((**(this as *const *const ISystemMediaTransportControls2_Vtbl)).PlaybackRateChangeRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePlaybackRateChangeRequested(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls2", "RemovePlaybackRateChangeRequested", 16usize, |this| ((**(this as *const *const ISystemMediaTransportControls2_Vtbl)).RemovePlaybackRateChangeRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShuffleEnabledChangeRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls2", "ShuffleEnabledChangeRequested", 17usize, |this| ((**(this as *const *const ISystemMediaTransportControls2_Vtbl)).ShuffleEnabledChangeRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveShuffleEnabledChangeRequested(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls2", "RemoveShuffleEnabledChangeRequested", 18usize, |this| ((**(this as *const *const ISystemMediaTransportControls2_Vtbl)).RemoveShuffleEnabledChangeRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoRepeatModeChangeRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls2", "AutoRepeatModeChangeRequested", 19usize, |this| ((**(this as *const *const ISystemMediaTransportControls2_Vtbl)).AutoRepeatModeChangeRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAutoRepeatModeChangeRequested(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControls2", "RemoveAutoRepeatModeChangeRequested", 20usize, |this| ((**(this as *const *const ISystemMediaTransportControls2_Vtbl)).RemoveAutoRepeatModeChangeRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AutoRepeatMode,
+            SetAutoRepeatMode,
+            ShuffleEnabled,
+            SetShuffleEnabled,
+            PlaybackRate,
+            SetPlaybackRate,
+            UpdateTimelineProperties,
+            PlaybackPositionChangeRequested,
+            RemovePlaybackPositionChangeRequested,
+            PlaybackRateChangeRequested,
+            RemovePlaybackRateChangeRequested,
+            ShuffleEnabledChangeRequested,
+            RemoveShuffleEnabledChangeRequested,
+            AutoRepeatModeChangeRequested,
+            RemoveAutoRepeatModeChangeRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemMediaTransportControls2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemMediaTransportControlsButtonPressedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Button(this: *mut core::ffi::c_void, result__: *mut SystemMediaTransportControlsButton) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsButtonPressedEventArgs", "Button", 6usize, |this| ((**(this as *const *const ISystemMediaTransportControlsButtonPressedEventArgs_Vtbl)).Button)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Button }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemMediaTransportControlsButtonPressedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISystemMediaTransportControlsDisplayUpdater_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut MediaPlaybackType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsDisplayUpdater", "Type", 6usize, |this| ((**(this as *const *const ISystemMediaTransportControlsDisplayUpdater_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, value: MediaPlaybackType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsDisplayUpdater", "SetType", 7usize, |this| ((**(this as *const *const ISystemMediaTransportControlsDisplayUpdater_Vtbl)).SetType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppMediaId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsDisplayUpdater", "AppMediaId", 8usize, |this| ((**(this as *const *const ISystemMediaTransportControlsDisplayUpdater_Vtbl)).AppMediaId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppMediaId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsDisplayUpdater", "SetAppMediaId", 9usize, |this| ((**(this as *const *const ISystemMediaTransportControlsDisplayUpdater_Vtbl)).SetAppMediaId)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsDisplayUpdater", "Thumbnail", 10usize, |this| ((**(this as *const *const ISystemMediaTransportControlsDisplayUpdater_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetThumbnail(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsDisplayUpdater", "SetThumbnail", 11usize, |this| ((**(this as *const *const ISystemMediaTransportControlsDisplayUpdater_Vtbl)).SetThumbnail)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MusicProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsDisplayUpdater", "MusicProperties", 12usize, |this| ((**(this as *const *const ISystemMediaTransportControlsDisplayUpdater_Vtbl)).MusicProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsDisplayUpdater", "VideoProperties", 13usize, |this| ((**(this as *const *const ISystemMediaTransportControlsDisplayUpdater_Vtbl)).VideoProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImageProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsDisplayUpdater", "ImageProperties", 14usize, |this| ((**(this as *const *const ISystemMediaTransportControlsDisplayUpdater_Vtbl)).ImageProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CopyFromFileAsync(this: *mut core::ffi::c_void, r#type: MediaPlaybackType, source: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsDisplayUpdater", "CopyFromFileAsync", 15usize, |this| ((**(this as *const *const ISystemMediaTransportControlsDisplayUpdater_Vtbl)).CopyFromFileAsync)(this, r#type, source, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsDisplayUpdater", "ClearAll", 16usize, |this| ((**(this as *const *const ISystemMediaTransportControlsDisplayUpdater_Vtbl)).ClearAll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsDisplayUpdater", "Update", 17usize, |this| ((**(this as *const *const ISystemMediaTransportControlsDisplayUpdater_Vtbl)).Update)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            SetType,
+            AppMediaId,
+            SetAppMediaId,
+            #[cfg(feature = "Storage_Streams")]
+            Thumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Thumbnail: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetThumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetThumbnail: 0,
+            MusicProperties,
+            VideoProperties,
+            ImageProperties,
+            #[cfg(feature = "Storage")]
+            CopyFromFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            CopyFromFileAsync: 0,
+            ClearAll,
+            Update,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemMediaTransportControlsDisplayUpdater as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemMediaTransportControlsPropertyChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Property(this: *mut core::ffi::c_void, result__: *mut SystemMediaTransportControlsProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsPropertyChangedEventArgs", "Property", 6usize, |this| ((**(this as *const *const ISystemMediaTransportControlsPropertyChangedEventArgs_Vtbl)).Property)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Property }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemMediaTransportControlsPropertyChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemMediaTransportControlsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const ISystemMediaTransportControlsStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemMediaTransportControlsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemMediaTransportControlsTimelineProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsTimelineProperties", "StartTime", 6usize, |this| ((**(this as *const *const ISystemMediaTransportControlsTimelineProperties_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, value: super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsTimelineProperties", "SetStartTime", 7usize, |this| ((**(this as *const *const ISystemMediaTransportControlsTimelineProperties_Vtbl)).SetStartTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndTime(this: *mut core::ffi::c_void, result__: *mut super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsTimelineProperties", "EndTime", 8usize, |this| ((**(this as *const *const ISystemMediaTransportControlsTimelineProperties_Vtbl)).EndTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEndTime(this: *mut core::ffi::c_void, value: super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsTimelineProperties", "SetEndTime", 9usize, |this| ((**(this as *const *const ISystemMediaTransportControlsTimelineProperties_Vtbl)).SetEndTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinSeekTime(this: *mut core::ffi::c_void, result__: *mut super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsTimelineProperties", "MinSeekTime", 10usize, |this| ((**(this as *const *const ISystemMediaTransportControlsTimelineProperties_Vtbl)).MinSeekTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinSeekTime(this: *mut core::ffi::c_void, value: super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsTimelineProperties", "SetMinSeekTime", 11usize, |this| ((**(this as *const *const ISystemMediaTransportControlsTimelineProperties_Vtbl)).SetMinSeekTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSeekTime(this: *mut core::ffi::c_void, result__: *mut super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsTimelineProperties", "MaxSeekTime", 12usize, |this| ((**(this as *const *const ISystemMediaTransportControlsTimelineProperties_Vtbl)).MaxSeekTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxSeekTime(this: *mut core::ffi::c_void, value: super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsTimelineProperties", "SetMaxSeekTime", 13usize, |this| ((**(this as *const *const ISystemMediaTransportControlsTimelineProperties_Vtbl)).SetMaxSeekTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsTimelineProperties", "Position", 14usize, |this| ((**(this as *const *const ISystemMediaTransportControlsTimelineProperties_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, value: super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsTimelineProperties", "SetPosition", 15usize, |this| ((**(this as *const *const ISystemMediaTransportControlsTimelineProperties_Vtbl)).SetPosition)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartTime,
+            SetStartTime,
+            EndTime,
+            SetEndTime,
+            MinSeekTime,
+            SetMinSeekTime,
+            MaxSeekTime,
+            SetMaxSeekTime,
+            Position,
+            SetPosition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemMediaTransportControlsTimelineProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoDisplayProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDisplayProperties", "Title", 6usize, |this| ((**(this as *const *const IVideoDisplayProperties_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDisplayProperties", "SetTitle", 7usize, |this| ((**(this as *const *const IVideoDisplayProperties_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subtitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDisplayProperties", "Subtitle", 8usize, |this| ((**(this as *const *const IVideoDisplayProperties_Vtbl)).Subtitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubtitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDisplayProperties", "SetSubtitle", 9usize, |this| ((**(this as *const *const IVideoDisplayProperties_Vtbl)).SetSubtitle)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Title, SetTitle, Subtitle, SetSubtitle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoDisplayProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVideoDisplayProperties2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Genres(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoDisplayProperties2", "Genres", 6usize, |this| ((**(this as *const *const IVideoDisplayProperties2_Vtbl)).Genres)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Genres,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Genres: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoDisplayProperties2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoEffectsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VideoStabilization(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoEffectsStatics", "VideoStabilization", 6usize, |this| ((**(this as *const *const IVideoEffectsStatics_Vtbl)).VideoStabilization)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, VideoStabilization }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoEffectsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX_Direct3D11", feature = "Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for IVideoFrame_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn SoftwareBitmap(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoFrame", "SoftwareBitmap", 6usize, |this| ((**(this as *const *const IVideoFrame_Vtbl)).SoftwareBitmap)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyToAsync(this: *mut core::ffi::c_void, frame: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoFrame", "CopyToAsync", 7usize, |this| ((**(this as *const *const IVideoFrame_Vtbl)).CopyToAsync)(this, frame, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn Direct3DSurface(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoFrame", "Direct3DSurface", 8usize, |this| ((**(this as *const *const IVideoFrame_Vtbl)).Direct3DSurface)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Imaging")]
+            SoftwareBitmap,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            SoftwareBitmap: 0,
+            CopyToAsync,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            Direct3DSurface,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            Direct3DSurface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for IVideoFrame2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn CopyToWithBoundsAsync(this: *mut core::ffi::c_void, frame: *mut core::ffi::c_void, sourcebounds: *mut core::ffi::c_void, destinationbounds: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoFrame2", "CopyToWithBoundsAsync", 6usize, |this| ((**(this as *const *const IVideoFrame2_Vtbl)).CopyToWithBoundsAsync)(this, frame, sourcebounds, destinationbounds, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Imaging")]
+            CopyToWithBoundsAsync,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            CopyToWithBoundsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoFrame2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for IVideoFrameFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, format: super::Graphics::Imaging::BitmapPixelFormat, width: i32, height: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoFrameFactory", "Create", 6usize, |this| ((**(this as *const *const IVideoFrameFactory_Vtbl)).Create)(this, format, width, height, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn CreateWithAlpha(this: *mut core::ffi::c_void, format: super::Graphics::Imaging::BitmapPixelFormat, width: i32, height: i32, alpha: super::Graphics::Imaging::BitmapAlphaMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoFrameFactory", "CreateWithAlpha", 7usize, |this| ((**(this as *const *const IVideoFrameFactory_Vtbl)).CreateWithAlpha)(this, format, width, height, alpha, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_Imaging")]
+            Create,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            Create: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            CreateWithAlpha,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            CreateWithAlpha: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoFrameFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Graphics_DirectX_Direct3D11", feature = "Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for IVideoFrameStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn CreateAsDirect3D11SurfaceBacked(this: *mut core::ffi::c_void, format: super::Graphics::DirectX::DirectXPixelFormat, width: i32, height: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoFrameStatics", "CreateAsDirect3D11SurfaceBacked", 6usize, |this| ((**(this as *const *const IVideoFrameStatics_Vtbl)).CreateAsDirect3D11SurfaceBacked)(this, format, width, height, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn CreateAsDirect3D11SurfaceBackedWithDevice(this: *mut core::ffi::c_void, format: super::Graphics::DirectX::DirectXPixelFormat, width: i32, height: i32, device: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoFrameStatics", "CreateAsDirect3D11SurfaceBackedWithDevice", 7usize, |this| ((**(this as *const *const IVideoFrameStatics_Vtbl)).CreateAsDirect3D11SurfaceBackedWithDevice)(this, format, width, height, device, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn CreateWithSoftwareBitmap(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoFrameStatics", "CreateWithSoftwareBitmap", 8usize, |this| ((**(this as *const *const IVideoFrameStatics_Vtbl)).CreateWithSoftwareBitmap)(this, bitmap, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+        unsafe extern "system" fn CreateWithDirect3D11Surface(this: *mut core::ffi::c_void, surface: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoFrameStatics", "CreateWithDirect3D11Surface", 9usize, |this| ((**(this as *const *const IVideoFrameStatics_Vtbl)).CreateWithDirect3D11Surface)(this, surface, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX")]
+            CreateAsDirect3D11SurfaceBacked,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            CreateAsDirect3D11SurfaceBacked: 0,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            CreateAsDirect3D11SurfaceBackedWithDevice,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            CreateAsDirect3D11SurfaceBackedWithDevice: 0,
+            #[cfg(feature = "Graphics_Imaging")]
+            CreateWithSoftwareBitmap,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            CreateWithSoftwareBitmap: 0,
+            #[cfg(feature = "Graphics_DirectX_Direct3D11")]
+            CreateWithDirect3D11Surface,
+            #[cfg(not(feature = "Graphics_DirectX_Direct3D11"))]
+            CreateWithDirect3D11Surface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoFrameStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,1070 @@
+#[cfg(all(feature = "Security_Credentials", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IBackgroundDownloader_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CreateDownload(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, resultfile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloader", "CreateDownload", 6usize, |this| ((**(this as *const *const IBackgroundDownloader_Vtbl)).CreateDownload)(this, uri, resultfile, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CreateDownloadFromFile(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, resultfile: *mut core::ffi::c_void, requestbodyfile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloader", "CreateDownloadFromFile", 7usize, |this| ((**(this as *const *const IBackgroundDownloader_Vtbl)).CreateDownloadFromFile)(this, uri, resultfile, requestbodyfile, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateDownloadAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, resultfile: *mut core::ffi::c_void, requestbodystream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloader", "CreateDownloadAsync", 8usize, |this| ((**(this as *const *const IBackgroundDownloader_Vtbl)).CreateDownloadAsync)(this, uri, resultfile, requestbodystream, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            CreateDownload,
+            #[cfg(not(feature = "Storage"))]
+            CreateDownload: 0,
+            #[cfg(feature = "Storage")]
+            CreateDownloadFromFile,
+            #[cfg(not(feature = "Storage"))]
+            CreateDownloadFromFile: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateDownloadAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateDownloadAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundDownloader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Notifications")]
+impl windows_core::imp::ProxyVtable for IBackgroundDownloader2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransferGroup(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloader2", "TransferGroup", 6usize, |this| ((**(this as *const *const IBackgroundDownloader2_Vtbl)).TransferGroup)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransferGroup(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloader2", "SetTransferGroup", 7usize, |this| ((**(this as *const *const IBackgroundDownloader2_Vtbl)).SetTransferGroup)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn SuccessToastNotification(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloader2", "SuccessToastNotification", 8usize, |this| ((**(this as *const *const IBackgroundDownloader2_Vtbl)).SuccessToastNotification)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn SetSuccessToastNotification(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloader2", "SetSuccessToastNotification", 9usize, |this| ((**(this as *const *const IBackgroundDownloader2_Vtbl)).SetSuccessToastNotification)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn FailureToastNotification(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloader2", "FailureToastNotification", 10usize, |this| ((**(this as *const *const IBackgroundDownloader2_Vtbl)).FailureToastNotification)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn SetFailureToastNotification(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloader2", "SetFailureToastNotification", 11usize, |this| ((**(this as *const *const IBackgroundDownloader2_Vtbl)).SetFailureToastNotification)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn SuccessTileNotification(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloader2", "SuccessTileNotification", 12usize, |this| ((**(this as *const *const IBackgroundDownloader2_Vtbl)).SuccessTileNotification)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn SetSuccessTileNotification(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloader2", "SetSuccessTileNotification", 13usize, |this| ((**(this as *const *const IBackgroundDownloader2_Vtbl)).SetSuccessTileNotification)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn FailureTileNotification(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloader2", "FailureTileNotification", 14usize, |this| ((**(this as *const *const IBackgroundDownloader2_Vtbl)).FailureTileNotification)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn SetFailureTileNotification(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloader2", "SetFailureTileNotification", 15usize, |this| ((**(this as *const *const IBackgroundDownloader2_Vtbl)).SetFailureTileNotification)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TransferGroup,
+            SetTransferGroup,
+            #[cfg(feature = "UI_Notifications")]
+            SuccessToastNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            SuccessToastNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            SetSuccessToastNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            SetSuccessToastNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            FailureToastNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            FailureToastNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            SetFailureToastNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            SetFailureToastNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            SuccessTileNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            SuccessTileNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            SetSuccessTileNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            SetSuccessTileNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            FailureTileNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            FailureTileNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            SetFailureTileNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            SetFailureTileNotification: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundDownloader2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundDownloader3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CompletionGroup(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloader3", "CompletionGroup", 6usize, |this| ((**(this as *const *const IBackgroundDownloader3_Vtbl)).CompletionGroup)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CompletionGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundDownloader3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundDownloaderFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithCompletionGroup(this: *mut core::ffi::c_void, completiongroup: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloaderFactory", "CreateWithCompletionGroup", 6usize, |this| ((**(this as *const *const IBackgroundDownloaderFactory_Vtbl)).CreateWithCompletionGroup)(this, completiongroup, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithCompletionGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundDownloaderFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBackgroundDownloaderStaticMethods_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCurrentDownloadsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloaderStaticMethods", "GetCurrentDownloadsAsync", 6usize, |this| ((**(this as *const *const IBackgroundDownloaderStaticMethods_Vtbl)).GetCurrentDownloadsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn GetCurrentDownloadsForGroupAsync(this: *mut core::ffi::c_void, group: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloaderStaticMethods", "GetCurrentDownloadsForGroupAsync", 7usize, |this| ((**(this as *const *const IBackgroundDownloaderStaticMethods_Vtbl)).GetCurrentDownloadsForGroupAsync)(this, group, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCurrentDownloadsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCurrentDownloadsAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            GetCurrentDownloadsForGroupAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            GetCurrentDownloadsForGroupAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundDownloaderStaticMethods as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBackgroundDownloaderStaticMethods2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCurrentDownloadsForTransferGroupAsync(this: *mut core::ffi::c_void, group: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloaderStaticMethods2", "GetCurrentDownloadsForTransferGroupAsync", 6usize, |this| ((**(this as *const *const IBackgroundDownloaderStaticMethods2_Vtbl)).GetCurrentDownloadsForTransferGroupAsync)(this, group, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCurrentDownloadsForTransferGroupAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCurrentDownloadsForTransferGroupAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundDownloaderStaticMethods2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IBackgroundDownloaderUserConsent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn RequestUnconstrainedDownloadsAsync(this: *mut core::ffi::c_void, operations: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundDownloaderUserConsent", "RequestUnconstrainedDownloadsAsync", 6usize, |this| ((**(this as *const *const IBackgroundDownloaderUserConsent_Vtbl)).RequestUnconstrainedDownloadsAsync)(this, operations, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            RequestUnconstrainedDownloadsAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            RequestUnconstrainedDownloadsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundDownloaderUserConsent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IBackgroundTransferBase_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRequestHeader(this: *mut core::ffi::c_void, headername: core::mem::MaybeUninit<windows_core::HSTRING>, headervalue: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferBase", "SetRequestHeader", 6usize, |this| ((**(this as *const *const IBackgroundTransferBase_Vtbl)).SetRequestHeader)(this, headername, headervalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn ServerCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferBase", "ServerCredential", 7usize, |this| ((**(this as *const *const IBackgroundTransferBase_Vtbl)).ServerCredential)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetServerCredential(this: *mut core::ffi::c_void, credential: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferBase", "SetServerCredential", 8usize, |this| ((**(this as *const *const IBackgroundTransferBase_Vtbl)).SetServerCredential)(this, credential), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn ProxyCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferBase", "ProxyCredential", 9usize, |this| ((**(this as *const *const IBackgroundTransferBase_Vtbl)).ProxyCredential)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetProxyCredential(this: *mut core::ffi::c_void, credential: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferBase", "SetProxyCredential", 10usize, |this| ((**(this as *const *const IBackgroundTransferBase_Vtbl)).SetProxyCredential)(this, credential), |result| Some(*result))
+        }
+        unsafe extern "system" fn Method(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferBase", "Method", 11usize, |this| ((**(this as *const *const IBackgroundTransferBase_Vtbl)).Method)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMethod(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferBase", "SetMethod", 12usize, |this| ((**(this as *const *const IBackgroundTransferBase_Vtbl)).SetMethod)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Group(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferBase", "Group", 13usize, |this| ((**(this as *const *const IBackgroundTransferBase_Vtbl)).Group)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetGroup(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferBase", "SetGroup", 14usize, |this| ((**(this as *const *const IBackgroundTransferBase_Vtbl)).SetGroup)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CostPolicy(this: *mut core::ffi::c_void, result__: *mut BackgroundTransferCostPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferBase", "CostPolicy", 15usize, |this| ((**(this as *const *const IBackgroundTransferBase_Vtbl)).CostPolicy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCostPolicy(this: *mut core::ffi::c_void, value: BackgroundTransferCostPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferBase", "SetCostPolicy", 16usize, |this| ((**(this as *const *const IBackgroundTransferBase_Vtbl)).SetCostPolicy)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetRequestHeader,
+            #[cfg(feature = "Security_Credentials")]
+            ServerCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            ServerCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetServerCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetServerCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            ProxyCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            ProxyCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetProxyCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetProxyCredential: 0,
+            Method,
+            SetMethod,
+            #[cfg(feature = "deprecated")]
+            Group,
+            #[cfg(not(feature = "deprecated"))]
+            Group: 0,
+            #[cfg(feature = "deprecated")]
+            SetGroup,
+            #[cfg(not(feature = "deprecated"))]
+            SetGroup: 0,
+            CostPolicy,
+            SetCostPolicy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTransferBase as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Background")]
+impl windows_core::imp::ProxyVtable for IBackgroundTransferCompletionGroup_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_Background")]
+        unsafe extern "system" fn Trigger(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferCompletionGroup", "Trigger", 6usize, |this| ((**(this as *const *const IBackgroundTransferCompletionGroup_Vtbl)).Trigger)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferCompletionGroup", "IsEnabled", 7usize, |this| ((**(this as *const *const IBackgroundTransferCompletionGroup_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferCompletionGroup", "Enable", 8usize, |this| ((**(this as *const *const IBackgroundTransferCompletionGroup_Vtbl)).Enable)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_Background")]
+            Trigger,
+            #[cfg(not(feature = "ApplicationModel_Background"))]
+            Trigger: 0,
+            IsEnabled,
+            Enable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTransferCompletionGroup as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBackgroundTransferCompletionGroupTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Downloads(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferCompletionGroupTriggerDetails", "Downloads", 6usize, |this| ((**(this as *const *const IBackgroundTransferCompletionGroupTriggerDetails_Vtbl)).Downloads)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Uploads(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferCompletionGroupTriggerDetails", "Uploads", 7usize, |this| ((**(this as *const *const IBackgroundTransferCompletionGroupTriggerDetails_Vtbl)).Uploads)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Downloads,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Downloads: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Uploads,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Uploads: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTransferCompletionGroupTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IBackgroundTransferContentPart_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetHeader(this: *mut core::ffi::c_void, headername: core::mem::MaybeUninit<windows_core::HSTRING>, headervalue: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferContentPart", "SetHeader", 6usize, |this| ((**(this as *const *const IBackgroundTransferContentPart_Vtbl)).SetHeader)(this, headername, headervalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferContentPart", "SetText", 7usize, |this| ((**(this as *const *const IBackgroundTransferContentPart_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SetFile(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferContentPart", "SetFile", 8usize, |this| ((**(this as *const *const IBackgroundTransferContentPart_Vtbl)).SetFile)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetHeader,
+            SetText,
+            #[cfg(feature = "Storage")]
+            SetFile,
+            #[cfg(not(feature = "Storage"))]
+            SetFile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTransferContentPart as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTransferContentPartFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferContentPartFactory", "CreateWithName", 6usize, |this| ((**(this as *const *const IBackgroundTransferContentPartFactory_Vtbl)).CreateWithName)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithNameAndFileName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, filename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferContentPartFactory", "CreateWithNameAndFileName", 7usize, |this| ((**(this as *const *const IBackgroundTransferContentPartFactory_Vtbl)).CreateWithNameAndFileName)(this, name, filename, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithName, CreateWithNameAndFileName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTransferContentPartFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Web")]
+impl windows_core::imp::ProxyVtable for IBackgroundTransferErrorStaticMethods_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Web")]
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, hresult: i32, result__: *mut super::super::Web::WebErrorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferErrorStaticMethods", "GetStatus", 6usize, |this| ((**(this as *const *const IBackgroundTransferErrorStaticMethods_Vtbl)).GetStatus)(this, hresult, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Web")]
+            GetStatus,
+            #[cfg(not(feature = "Web"))]
+            GetStatus: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTransferErrorStaticMethods as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTransferGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferGroup", "Name", 6usize, |this| ((**(this as *const *const IBackgroundTransferGroup_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferBehavior(this: *mut core::ffi::c_void, result__: *mut BackgroundTransferBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferGroup", "TransferBehavior", 7usize, |this| ((**(this as *const *const IBackgroundTransferGroup_Vtbl)).TransferBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransferBehavior(this: *mut core::ffi::c_void, value: BackgroundTransferBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferGroup", "SetTransferBehavior", 8usize, |this| ((**(this as *const *const IBackgroundTransferGroup_Vtbl)).SetTransferBehavior)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, TransferBehavior, SetTransferBehavior }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTransferGroup as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTransferGroupStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateGroup(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferGroupStatics", "CreateGroup", 6usize, |this| ((**(this as *const *const IBackgroundTransferGroupStatics_Vtbl)).CreateGroup)(this, name, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTransferGroupStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IBackgroundTransferOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Guid(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferOperation", "Guid", 6usize, |this| ((**(this as *const *const IBackgroundTransferOperation_Vtbl)).Guid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestedUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferOperation", "RequestedUri", 7usize, |this| ((**(this as *const *const IBackgroundTransferOperation_Vtbl)).RequestedUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Method(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferOperation", "Method", 8usize, |this| ((**(this as *const *const IBackgroundTransferOperation_Vtbl)).Method)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Group(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferOperation", "Group", 9usize, |this| ((**(this as *const *const IBackgroundTransferOperation_Vtbl)).Group)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CostPolicy(this: *mut core::ffi::c_void, result__: *mut BackgroundTransferCostPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferOperation", "CostPolicy", 10usize, |this| ((**(this as *const *const IBackgroundTransferOperation_Vtbl)).CostPolicy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCostPolicy(this: *mut core::ffi::c_void, value: BackgroundTransferCostPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferOperation", "SetCostPolicy", 11usize, |this| ((**(this as *const *const IBackgroundTransferOperation_Vtbl)).SetCostPolicy)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetResultStreamAt(this: *mut core::ffi::c_void, position: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferOperation", "GetResultStreamAt", 12usize, |this| ((**(this as *const *const IBackgroundTransferOperation_Vtbl)).GetResultStreamAt)(this, position, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResponseInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferOperation", "GetResponseInformation", 13usize, |this| ((**(this as *const *const IBackgroundTransferOperation_Vtbl)).GetResponseInformation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Guid,
+            RequestedUri,
+            Method,
+            #[cfg(feature = "deprecated")]
+            Group,
+            #[cfg(not(feature = "deprecated"))]
+            Group: 0,
+            CostPolicy,
+            SetCostPolicy,
+            #[cfg(feature = "Storage_Streams")]
+            GetResultStreamAt,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetResultStreamAt: 0,
+            GetResponseInformation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTransferOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundTransferOperationPriority_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, result__: *mut BackgroundTransferPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferOperationPriority", "Priority", 6usize, |this| ((**(this as *const *const IBackgroundTransferOperationPriority_Vtbl)).Priority)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, value: BackgroundTransferPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferOperationPriority", "SetPriority", 7usize, |this| ((**(this as *const *const IBackgroundTransferOperationPriority_Vtbl)).SetPriority)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Priority, SetPriority }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTransferOperationPriority as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBackgroundTransferRangesDownloadedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WasDownloadRestarted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferRangesDownloadedEventArgs", "WasDownloadRestarted", 6usize, |this| ((**(this as *const *const IBackgroundTransferRangesDownloadedEventArgs_Vtbl)).WasDownloadRestarted)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AddedRanges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferRangesDownloadedEventArgs", "AddedRanges", 7usize, |this| ((**(this as *const *const IBackgroundTransferRangesDownloadedEventArgs_Vtbl)).AddedRanges)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundTransferRangesDownloadedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IBackgroundTransferRangesDownloadedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            WasDownloadRestarted,
+            #[cfg(feature = "Foundation_Collections")]
+            AddedRanges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AddedRanges: 0,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundTransferRangesDownloadedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IBackgroundUploader_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CreateUpload(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, sourcefile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader", "CreateUpload", 6usize, |this| ((**(this as *const *const IBackgroundUploader_Vtbl)).CreateUpload)(this, uri, sourcefile, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateUploadFromStreamAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, sourcestream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader", "CreateUploadFromStreamAsync", 7usize, |this| ((**(this as *const *const IBackgroundUploader_Vtbl)).CreateUploadFromStreamAsync)(this, uri, sourcestream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateUploadWithFormDataAndAutoBoundaryAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, parts: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader", "CreateUploadWithFormDataAndAutoBoundaryAsync", 8usize, |this| ((**(this as *const *const IBackgroundUploader_Vtbl)).CreateUploadWithFormDataAndAutoBoundaryAsync)(this, uri, parts, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateUploadWithSubTypeAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, parts: *mut core::ffi::c_void, subtype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader", "CreateUploadWithSubTypeAsync", 9usize, |this| ((**(this as *const *const IBackgroundUploader_Vtbl)).CreateUploadWithSubTypeAsync)(this, uri, parts, subtype, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateUploadWithSubTypeAndBoundaryAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, parts: *mut core::ffi::c_void, subtype: core::mem::MaybeUninit<windows_core::HSTRING>, boundary: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader", "CreateUploadWithSubTypeAndBoundaryAsync", 10usize, |this| ((**(this as *const *const IBackgroundUploader_Vtbl)).CreateUploadWithSubTypeAndBoundaryAsync)(this, uri, parts, subtype, boundary, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            CreateUpload,
+            #[cfg(not(feature = "Storage"))]
+            CreateUpload: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateUploadFromStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateUploadFromStreamAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateUploadWithFormDataAndAutoBoundaryAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateUploadWithFormDataAndAutoBoundaryAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateUploadWithSubTypeAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateUploadWithSubTypeAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateUploadWithSubTypeAndBoundaryAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateUploadWithSubTypeAndBoundaryAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundUploader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Notifications")]
+impl windows_core::imp::ProxyVtable for IBackgroundUploader2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransferGroup(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader2", "TransferGroup", 6usize, |this| ((**(this as *const *const IBackgroundUploader2_Vtbl)).TransferGroup)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransferGroup(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader2", "SetTransferGroup", 7usize, |this| ((**(this as *const *const IBackgroundUploader2_Vtbl)).SetTransferGroup)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn SuccessToastNotification(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader2", "SuccessToastNotification", 8usize, |this| ((**(this as *const *const IBackgroundUploader2_Vtbl)).SuccessToastNotification)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn SetSuccessToastNotification(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader2", "SetSuccessToastNotification", 9usize, |this| ((**(this as *const *const IBackgroundUploader2_Vtbl)).SetSuccessToastNotification)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn FailureToastNotification(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader2", "FailureToastNotification", 10usize, |this| ((**(this as *const *const IBackgroundUploader2_Vtbl)).FailureToastNotification)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn SetFailureToastNotification(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader2", "SetFailureToastNotification", 11usize, |this| ((**(this as *const *const IBackgroundUploader2_Vtbl)).SetFailureToastNotification)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn SuccessTileNotification(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader2", "SuccessTileNotification", 12usize, |this| ((**(this as *const *const IBackgroundUploader2_Vtbl)).SuccessTileNotification)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn SetSuccessTileNotification(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader2", "SetSuccessTileNotification", 13usize, |this| ((**(this as *const *const IBackgroundUploader2_Vtbl)).SetSuccessTileNotification)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn FailureTileNotification(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader2", "FailureTileNotification", 14usize, |this| ((**(this as *const *const IBackgroundUploader2_Vtbl)).FailureTileNotification)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn SetFailureTileNotification(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader2", "SetFailureTileNotification", 15usize, |this| ((**(this as *const *const IBackgroundUploader2_Vtbl)).SetFailureTileNotification)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TransferGroup,
+            SetTransferGroup,
+            #[cfg(feature = "UI_Notifications")]
+            SuccessToastNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            SuccessToastNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            SetSuccessToastNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            SetSuccessToastNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            FailureToastNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            FailureToastNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            SetFailureToastNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            SetFailureToastNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            SuccessTileNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            SuccessTileNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            SetSuccessTileNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            SetSuccessTileNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            FailureTileNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            FailureTileNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            SetFailureTileNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            SetFailureTileNotification: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundUploader2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundUploader3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CompletionGroup(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploader3", "CompletionGroup", 6usize, |this| ((**(this as *const *const IBackgroundUploader3_Vtbl)).CompletionGroup)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CompletionGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundUploader3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundUploaderFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithCompletionGroup(this: *mut core::ffi::c_void, completiongroup: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploaderFactory", "CreateWithCompletionGroup", 6usize, |this| ((**(this as *const *const IBackgroundUploaderFactory_Vtbl)).CreateWithCompletionGroup)(this, completiongroup, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithCompletionGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundUploaderFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBackgroundUploaderStaticMethods_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCurrentUploadsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploaderStaticMethods", "GetCurrentUploadsAsync", 6usize, |this| ((**(this as *const *const IBackgroundUploaderStaticMethods_Vtbl)).GetCurrentUploadsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn GetCurrentUploadsForGroupAsync(this: *mut core::ffi::c_void, group: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploaderStaticMethods", "GetCurrentUploadsForGroupAsync", 7usize, |this| ((**(this as *const *const IBackgroundUploaderStaticMethods_Vtbl)).GetCurrentUploadsForGroupAsync)(this, group, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCurrentUploadsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCurrentUploadsAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            GetCurrentUploadsForGroupAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            GetCurrentUploadsForGroupAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundUploaderStaticMethods as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IBackgroundUploaderStaticMethods2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCurrentUploadsForTransferGroupAsync(this: *mut core::ffi::c_void, group: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploaderStaticMethods2", "GetCurrentUploadsForTransferGroupAsync", 6usize, |this| ((**(this as *const *const IBackgroundUploaderStaticMethods2_Vtbl)).GetCurrentUploadsForTransferGroupAsync)(this, group, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCurrentUploadsForTransferGroupAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCurrentUploadsForTransferGroupAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundUploaderStaticMethods2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IBackgroundUploaderUserConsent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn RequestUnconstrainedUploadsAsync(this: *mut core::ffi::c_void, operations: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundUploaderUserConsent", "RequestUnconstrainedUploadsAsync", 6usize, |this| ((**(this as *const *const IBackgroundUploaderUserConsent_Vtbl)).RequestUnconstrainedUploadsAsync)(this, operations, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            RequestUnconstrainedUploadsAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            RequestUnconstrainedUploadsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundUploaderUserConsent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContentPrefetcher_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ContentUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentPrefetcher", "ContentUris", 6usize, |this| ((**(this as *const *const IContentPrefetcher_Vtbl)).ContentUris)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIndirectContentUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentPrefetcher", "SetIndirectContentUri", 7usize, |this| ((**(this as *const *const IContentPrefetcher_Vtbl)).SetIndirectContentUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IndirectContentUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentPrefetcher", "IndirectContentUri", 8usize, |this| ((**(this as *const *const IContentPrefetcher_Vtbl)).IndirectContentUri)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ContentUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ContentUris: 0,
+            SetIndirectContentUri,
+            IndirectContentUri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContentPrefetcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContentPrefetcherTime_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LastSuccessfulPrefetchTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentPrefetcherTime", "LastSuccessfulPrefetchTime", 6usize, |this| ((**(this as *const *const IContentPrefetcherTime_Vtbl)).LastSuccessfulPrefetchTime)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LastSuccessfulPrefetchTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContentPrefetcherTime as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IDownloadOperation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn ResultFile(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation", "ResultFile", 6usize, |this| ((**(this as *const *const IDownloadOperation_Vtbl)).ResultFile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, result__: *mut BackgroundDownloadProgress) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation", "Progress", 7usize, |this| ((**(this as *const *const IDownloadOperation_Vtbl)).Progress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation", "StartAsync", 8usize, |this| ((**(this as *const *const IDownloadOperation_Vtbl)).StartAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation", "AttachAsync", 9usize, |this| ((**(this as *const *const IDownloadOperation_Vtbl)).AttachAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation", "Pause", 10usize, |this| ((**(this as *const *const IDownloadOperation_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation", "Resume", 11usize, |this| ((**(this as *const *const IDownloadOperation_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            ResultFile,
+            #[cfg(not(feature = "Storage"))]
+            ResultFile: 0,
+            Progress,
+            StartAsync,
+            AttachAsync,
+            Pause,
+            Resume,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDownloadOperation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransferGroup(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation2", "TransferGroup", 6usize, |this| ((**(this as *const *const IDownloadOperation2_Vtbl)).TransferGroup)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TransferGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadOperation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams", feature = "Web"))]
+impl windows_core::imp::ProxyVtable for IDownloadOperation3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsRandomAccessRequired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation3", "IsRandomAccessRequired", 6usize, |this| ((**(this as *const *const IDownloadOperation3_Vtbl)).IsRandomAccessRequired)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsRandomAccessRequired(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation3", "SetIsRandomAccessRequired", 7usize, |this| ((**(this as *const *const IDownloadOperation3_Vtbl)).SetIsRandomAccessRequired)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetResultRandomAccessStreamReference(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation3", "GetResultRandomAccessStreamReference", 8usize, |this| ((**(this as *const *const IDownloadOperation3_Vtbl)).GetResultRandomAccessStreamReference)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetDownloadedRanges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation3", "GetDownloadedRanges", 9usize, |this| ((**(this as *const *const IDownloadOperation3_Vtbl)).GetDownloadedRanges)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RangesDownloaded(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation3", "RangesDownloaded", 10usize, |this| ((**(this as *const *const IDownloadOperation3_Vtbl)).RangesDownloaded)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRangesDownloaded(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation3", "RemoveRangesDownloaded", 11usize, |this| ((**(this as *const *const IDownloadOperation3_Vtbl)).RemoveRangesDownloaded)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestedUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation3", "SetRequestedUri", 12usize, |this| ((**(this as *const *const IDownloadOperation3_Vtbl)).SetRequestedUri)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Web"))]
+        unsafe extern "system" fn RecoverableWebErrorStatuses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation3", "RecoverableWebErrorStatuses", 13usize, |this| ((**(this as *const *const IDownloadOperation3_Vtbl)).RecoverableWebErrorStatuses)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Web")]
+        unsafe extern "system" fn CurrentWebErrorStatus(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation3", "CurrentWebErrorStatus", 14usize, |this| ((**(this as *const *const IDownloadOperation3_Vtbl)).CurrentWebErrorStatus)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsRandomAccessRequired,
+            SetIsRandomAccessRequired,
+            #[cfg(feature = "Storage_Streams")]
+            GetResultRandomAccessStreamReference,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetResultRandomAccessStreamReference: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetDownloadedRanges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetDownloadedRanges: 0,
+            RangesDownloaded,
+            RemoveRangesDownloaded,
+            SetRequestedUri,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Web"))]
+            RecoverableWebErrorStatuses,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Web")))]
+            RecoverableWebErrorStatuses: 0,
+            #[cfg(feature = "Web")]
+            CurrentWebErrorStatus,
+            #[cfg(not(feature = "Web"))]
+            CurrentWebErrorStatus: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadOperation3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDownloadOperation4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MakeCurrentInTransferGroup(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation4", "MakeCurrentInTransferGroup", 6usize, |this| ((**(this as *const *const IDownloadOperation4_Vtbl)).MakeCurrentInTransferGroup)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MakeCurrentInTransferGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadOperation4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDownloadOperation5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRequestHeader(this: *mut core::ffi::c_void, headername: core::mem::MaybeUninit<windows_core::HSTRING>, headervalue: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation5", "SetRequestHeader", 6usize, |this| ((**(this as *const *const IDownloadOperation5_Vtbl)).SetRequestHeader)(this, headername, headervalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRequestHeader(this: *mut core::ffi::c_void, headername: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadOperation5", "RemoveRequestHeader", 7usize, |this| ((**(this as *const *const IDownloadOperation5_Vtbl)).RemoveRequestHeader)(this, headername), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetRequestHeader, RemoveRequestHeader }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadOperation5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IResponseInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsResumable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResponseInformation", "IsResumable", 6usize, |this| ((**(this as *const *const IResponseInformation_Vtbl)).IsResumable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActualUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResponseInformation", "ActualUri", 7usize, |this| ((**(this as *const *const IResponseInformation_Vtbl)).ActualUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResponseInformation", "StatusCode", 8usize, |this| ((**(this as *const *const IResponseInformation_Vtbl)).StatusCode)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Headers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResponseInformation", "Headers", 9usize, |this| ((**(this as *const *const IResponseInformation_Vtbl)).Headers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsResumable,
+            ActualUri,
+            StatusCode,
+            #[cfg(feature = "Foundation_Collections")]
+            Headers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Headers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResponseInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IUnconstrainedTransferRequestResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsUnconstrained(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnconstrainedTransferRequestResult", "IsUnconstrained", 6usize, |this| ((**(this as *const *const IUnconstrainedTransferRequestResult_Vtbl)).IsUnconstrained)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            IsUnconstrained,
+            #[cfg(not(feature = "deprecated"))]
+            IsUnconstrained: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUnconstrainedTransferRequestResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IUploadOperation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SourceFile(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUploadOperation", "SourceFile", 6usize, |this| ((**(this as *const *const IUploadOperation_Vtbl)).SourceFile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, result__: *mut BackgroundUploadProgress) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUploadOperation", "Progress", 7usize, |this| ((**(this as *const *const IUploadOperation_Vtbl)).Progress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUploadOperation", "StartAsync", 8usize, |this| ((**(this as *const *const IUploadOperation_Vtbl)).StartAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUploadOperation", "AttachAsync", 9usize, |this| ((**(this as *const *const IUploadOperation_Vtbl)).AttachAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            SourceFile,
+            #[cfg(not(feature = "Storage"))]
+            SourceFile: 0,
+            Progress,
+            StartAsync,
+            AttachAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUploadOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUploadOperation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransferGroup(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUploadOperation2", "TransferGroup", 6usize, |this| ((**(this as *const *const IUploadOperation2_Vtbl)).TransferGroup)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TransferGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUploadOperation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUploadOperation3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MakeCurrentInTransferGroup(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUploadOperation3", "MakeCurrentInTransferGroup", 6usize, |this| ((**(this as *const *const IUploadOperation3_Vtbl)).MakeCurrentInTransferGroup)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MakeCurrentInTransferGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUploadOperation3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUploadOperation4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRequestHeader(this: *mut core::ffi::c_void, headername: core::mem::MaybeUninit<windows_core::HSTRING>, headervalue: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUploadOperation4", "SetRequestHeader", 6usize, |this| ((**(this as *const *const IUploadOperation4_Vtbl)).SetRequestHeader)(this, headername, headervalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRequestHeader(this: *mut core::ffi::c_void, headername: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUploadOperation4", "RemoveRequestHeader", 7usize, |this| ((**(this as *const *const IUploadOperation4_Vtbl)).RemoveRequestHeader)(this, headername), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetRequestHeader, RemoveRequestHeader }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUploadOperation4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1916,6 +1916,7 @@ impl CellularApnAuthenticationType {
 impl windows_core::TypeKind for CellularApnAuthenticationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CellularApnAuthenticationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CellularApnAuthenticationType").field(&self.0).finish()
@@ -1936,6 +1937,7 @@ impl ConnectionProfileDeleteStatus {
 impl windows_core::TypeKind for ConnectionProfileDeleteStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ConnectionProfileDeleteStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ConnectionProfileDeleteStatus").field(&self.0).finish()
@@ -1956,6 +1958,7 @@ impl DataUsageGranularity {
 impl windows_core::TypeKind for DataUsageGranularity {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DataUsageGranularity {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DataUsageGranularity").field(&self.0).finish()
@@ -1975,6 +1978,7 @@ impl DomainAuthenticationKind {
 impl windows_core::TypeKind for DomainAuthenticationKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DomainAuthenticationKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DomainAuthenticationKind").field(&self.0).finish()
@@ -1994,6 +1998,7 @@ impl DomainConnectivityLevel {
 impl windows_core::TypeKind for DomainConnectivityLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DomainConnectivityLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DomainConnectivityLevel").field(&self.0).finish()
@@ -2025,6 +2030,7 @@ impl NetworkAuthenticationType {
 impl windows_core::TypeKind for NetworkAuthenticationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NetworkAuthenticationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NetworkAuthenticationType").field(&self.0).finish()
@@ -2045,6 +2051,7 @@ impl NetworkConnectivityLevel {
 impl windows_core::TypeKind for NetworkConnectivityLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NetworkConnectivityLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NetworkConnectivityLevel").field(&self.0).finish()
@@ -2065,6 +2072,7 @@ impl NetworkCostType {
 impl windows_core::TypeKind for NetworkCostType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NetworkCostType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NetworkCostType").field(&self.0).finish()
@@ -2093,6 +2101,7 @@ impl NetworkEncryptionType {
 impl windows_core::TypeKind for NetworkEncryptionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NetworkEncryptionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NetworkEncryptionType").field(&self.0).finish()
@@ -2112,6 +2121,7 @@ impl NetworkTypes {
 impl windows_core::TypeKind for NetworkTypes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NetworkTypes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NetworkTypes").field(&self.0).finish()
@@ -2164,6 +2174,7 @@ impl RoamingStates {
 impl windows_core::TypeKind for RoamingStates {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RoamingStates {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RoamingStates").field(&self.0).finish()
@@ -2216,6 +2227,7 @@ impl TriStates {
 impl windows_core::TypeKind for TriStates {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TriStates {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TriStates").field(&self.0).finish()
@@ -2249,6 +2261,7 @@ impl WwanDataClass {
 impl windows_core::TypeKind for WwanDataClass {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WwanDataClass {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WwanDataClass").field(&self.0).finish()
@@ -2303,6 +2316,7 @@ impl WwanNetworkIPKind {
 impl windows_core::TypeKind for WwanNetworkIPKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WwanNetworkIPKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WwanNetworkIPKind").field(&self.0).finish()
@@ -2326,6 +2340,7 @@ impl WwanNetworkRegistrationState {
 impl windows_core::TypeKind for WwanNetworkRegistrationState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WwanNetworkRegistrationState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WwanNetworkRegistrationState").field(&self.0).finish()
@@ -2335,7 +2350,8 @@ impl windows_core::RuntimeType for WwanNetworkRegistrationState {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Networking.Connectivity.WwanNetworkRegistrationState;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NetworkUsageStates {
     pub Roaming: TriStates,
     pub Shared: TriStates,
@@ -2411,3 +2427,5 @@ pub struct NetworkStatusChangedEventHandler_Vtbl {
     pub base__: windows_core::IUnknown_Vtbl,
     pub Invoke: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,975 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IAttributedNetworkUsage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BytesSent(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAttributedNetworkUsage", "BytesSent", 6usize, |this| ((**(this as *const *const IAttributedNetworkUsage_Vtbl)).BytesSent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BytesReceived(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAttributedNetworkUsage", "BytesReceived", 7usize, |this| ((**(this as *const *const IAttributedNetworkUsage_Vtbl)).BytesReceived)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttributionId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAttributedNetworkUsage", "AttributionId", 8usize, |this| ((**(this as *const *const IAttributedNetworkUsage_Vtbl)).AttributionId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttributionName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAttributedNetworkUsage", "AttributionName", 9usize, |this| ((**(this as *const *const IAttributedNetworkUsage_Vtbl)).AttributionName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AttributionThumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAttributedNetworkUsage", "AttributionThumbnail", 10usize, |this| ((**(this as *const *const IAttributedNetworkUsage_Vtbl)).AttributionThumbnail)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BytesSent,
+            BytesReceived,
+            AttributionId,
+            AttributionName,
+            #[cfg(feature = "Storage_Streams")]
+            AttributionThumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AttributionThumbnail: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAttributedNetworkUsage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICellularApnContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICellularApnContext", "ProviderId", 6usize, |this| ((**(this as *const *const ICellularApnContext_Vtbl)).ProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICellularApnContext", "SetProviderId", 7usize, |this| ((**(this as *const *const ICellularApnContext_Vtbl)).SetProviderId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccessPointName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICellularApnContext", "AccessPointName", 8usize, |this| ((**(this as *const *const ICellularApnContext_Vtbl)).AccessPointName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccessPointName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICellularApnContext", "SetAccessPointName", 9usize, |this| ((**(this as *const *const ICellularApnContext_Vtbl)).SetAccessPointName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICellularApnContext", "UserName", 10usize, |this| ((**(this as *const *const ICellularApnContext_Vtbl)).UserName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICellularApnContext", "SetUserName", 11usize, |this| ((**(this as *const *const ICellularApnContext_Vtbl)).SetUserName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Password(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICellularApnContext", "Password", 12usize, |this| ((**(this as *const *const ICellularApnContext_Vtbl)).Password)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPassword(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICellularApnContext", "SetPassword", 13usize, |this| ((**(this as *const *const ICellularApnContext_Vtbl)).SetPassword)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCompressionEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICellularApnContext", "IsCompressionEnabled", 14usize, |this| ((**(this as *const *const ICellularApnContext_Vtbl)).IsCompressionEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCompressionEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICellularApnContext", "SetIsCompressionEnabled", 15usize, |this| ((**(this as *const *const ICellularApnContext_Vtbl)).SetIsCompressionEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationType(this: *mut core::ffi::c_void, result__: *mut CellularApnAuthenticationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICellularApnContext", "AuthenticationType", 16usize, |this| ((**(this as *const *const ICellularApnContext_Vtbl)).AuthenticationType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationType(this: *mut core::ffi::c_void, value: CellularApnAuthenticationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICellularApnContext", "SetAuthenticationType", 17usize, |this| ((**(this as *const *const ICellularApnContext_Vtbl)).SetAuthenticationType)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProviderId,
+            SetProviderId,
+            AccessPointName,
+            SetAccessPointName,
+            UserName,
+            SetUserName,
+            Password,
+            SetPassword,
+            IsCompressionEnabled,
+            SetIsCompressionEnabled,
+            AuthenticationType,
+            SetAuthenticationType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICellularApnContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICellularApnContext2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProfileName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICellularApnContext2", "ProfileName", 6usize, |this| ((**(this as *const *const ICellularApnContext2_Vtbl)).ProfileName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProfileName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICellularApnContext2", "SetProfileName", 7usize, |this| ((**(this as *const *const ICellularApnContext2_Vtbl)).SetProfileName)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProfileName, SetProfileName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICellularApnContext2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConnectionCost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NetworkCostType(this: *mut core::ffi::c_void, result__: *mut NetworkCostType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionCost", "NetworkCostType", 6usize, |this| ((**(this as *const *const IConnectionCost_Vtbl)).NetworkCostType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Roaming(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionCost", "Roaming", 7usize, |this| ((**(this as *const *const IConnectionCost_Vtbl)).Roaming)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OverDataLimit(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionCost", "OverDataLimit", 8usize, |this| ((**(this as *const *const IConnectionCost_Vtbl)).OverDataLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApproachingDataLimit(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionCost", "ApproachingDataLimit", 9usize, |this| ((**(this as *const *const IConnectionCost_Vtbl)).ApproachingDataLimit)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NetworkCostType,
+            Roaming,
+            OverDataLimit,
+            ApproachingDataLimit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionCost as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConnectionCost2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BackgroundDataUsageRestricted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionCost2", "BackgroundDataUsageRestricted", 6usize, |this| ((**(this as *const *const IConnectionCost2_Vtbl)).BackgroundDataUsageRestricted)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BackgroundDataUsageRestricted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionCost2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IConnectionProfile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProfileName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile", "ProfileName", 6usize, |this| ((**(this as *const *const IConnectionProfile_Vtbl)).ProfileName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkConnectivityLevel(this: *mut core::ffi::c_void, result__: *mut NetworkConnectivityLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile", "GetNetworkConnectivityLevel", 7usize, |this| ((**(this as *const *const IConnectionProfile_Vtbl)).GetNetworkConnectivityLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetNetworkNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile", "GetNetworkNames", 8usize, |this| ((**(this as *const *const IConnectionProfile_Vtbl)).GetNetworkNames)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectionCost(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile", "GetConnectionCost", 9usize, |this| ((**(this as *const *const IConnectionProfile_Vtbl)).GetConnectionCost)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataPlanStatus(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile", "GetDataPlanStatus", 10usize, |this| ((**(this as *const *const IConnectionProfile_Vtbl)).GetDataPlanStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkAdapter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile", "NetworkAdapter", 11usize, |this| ((**(this as *const *const IConnectionProfile_Vtbl)).NetworkAdapter)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetLocalUsage(this: *mut core::ffi::c_void, starttime: super::super::Foundation::DateTime, endtime: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile", "GetLocalUsage", 12usize, |this| ((**(this as *const *const IConnectionProfile_Vtbl)).GetLocalUsage)(this, starttime, endtime, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetLocalUsagePerRoamingStates(this: *mut core::ffi::c_void, starttime: super::super::Foundation::DateTime, endtime: super::super::Foundation::DateTime, states: RoamingStates, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile", "GetLocalUsagePerRoamingStates", 13usize, |this| ((**(this as *const *const IConnectionProfile_Vtbl)).GetLocalUsagePerRoamingStates)(this, starttime, endtime, states, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkSecuritySettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile", "NetworkSecuritySettings", 14usize, |this| ((**(this as *const *const IConnectionProfile_Vtbl)).NetworkSecuritySettings)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProfileName,
+            GetNetworkConnectivityLevel,
+            #[cfg(feature = "Foundation_Collections")]
+            GetNetworkNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetNetworkNames: 0,
+            GetConnectionCost,
+            GetDataPlanStatus,
+            NetworkAdapter,
+            #[cfg(feature = "deprecated")]
+            GetLocalUsage,
+            #[cfg(not(feature = "deprecated"))]
+            GetLocalUsage: 0,
+            #[cfg(feature = "deprecated")]
+            GetLocalUsagePerRoamingStates,
+            #[cfg(not(feature = "deprecated"))]
+            GetLocalUsagePerRoamingStates: 0,
+            NetworkSecuritySettings,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionProfile as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IConnectionProfile2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsWwanConnectionProfile(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile2", "IsWwanConnectionProfile", 6usize, |this| ((**(this as *const *const IConnectionProfile2_Vtbl)).IsWwanConnectionProfile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWlanConnectionProfile(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile2", "IsWlanConnectionProfile", 7usize, |this| ((**(this as *const *const IConnectionProfile2_Vtbl)).IsWlanConnectionProfile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WwanConnectionProfileDetails(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile2", "WwanConnectionProfileDetails", 8usize, |this| ((**(this as *const *const IConnectionProfile2_Vtbl)).WwanConnectionProfileDetails)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WlanConnectionProfileDetails(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile2", "WlanConnectionProfileDetails", 9usize, |this| ((**(this as *const *const IConnectionProfile2_Vtbl)).WlanConnectionProfileDetails)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceProviderGuid(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile2", "ServiceProviderGuid", 10usize, |this| ((**(this as *const *const IConnectionProfile2_Vtbl)).ServiceProviderGuid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignalBars(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile2", "GetSignalBars", 11usize, |this| ((**(this as *const *const IConnectionProfile2_Vtbl)).GetSignalBars)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDomainConnectivityLevel(this: *mut core::ffi::c_void, result__: *mut DomainConnectivityLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile2", "GetDomainConnectivityLevel", 12usize, |this| ((**(this as *const *const IConnectionProfile2_Vtbl)).GetDomainConnectivityLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetNetworkUsageAsync(this: *mut core::ffi::c_void, starttime: super::super::Foundation::DateTime, endtime: super::super::Foundation::DateTime, granularity: DataUsageGranularity, states: NetworkUsageStates, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile2", "GetNetworkUsageAsync", 13usize, |this| ((**(this as *const *const IConnectionProfile2_Vtbl)).GetNetworkUsageAsync)(this, starttime, endtime, granularity, states, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetConnectivityIntervalsAsync(this: *mut core::ffi::c_void, starttime: super::super::Foundation::DateTime, endtime: super::super::Foundation::DateTime, states: NetworkUsageStates, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile2", "GetConnectivityIntervalsAsync", 14usize, |this| ((**(this as *const *const IConnectionProfile2_Vtbl)).GetConnectivityIntervalsAsync)(this, starttime, endtime, states, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsWwanConnectionProfile,
+            IsWlanConnectionProfile,
+            WwanConnectionProfileDetails,
+            WlanConnectionProfileDetails,
+            ServiceProviderGuid,
+            GetSignalBars,
+            GetDomainConnectivityLevel,
+            #[cfg(feature = "Foundation_Collections")]
+            GetNetworkUsageAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetNetworkUsageAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetConnectivityIntervalsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetConnectivityIntervalsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionProfile2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IConnectionProfile3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAttributedNetworkUsageAsync(this: *mut core::ffi::c_void, starttime: super::super::Foundation::DateTime, endtime: super::super::Foundation::DateTime, states: NetworkUsageStates, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile3", "GetAttributedNetworkUsageAsync", 6usize, |this| ((**(this as *const *const IConnectionProfile3_Vtbl)).GetAttributedNetworkUsageAsync)(this, starttime, endtime, states, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAttributedNetworkUsageAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAttributedNetworkUsageAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionProfile3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IConnectionProfile4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetProviderNetworkUsageAsync(this: *mut core::ffi::c_void, starttime: super::super::Foundation::DateTime, endtime: super::super::Foundation::DateTime, states: NetworkUsageStates, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile4", "GetProviderNetworkUsageAsync", 6usize, |this| ((**(this as *const *const IConnectionProfile4_Vtbl)).GetProviderNetworkUsageAsync)(this, starttime, endtime, states, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetProviderNetworkUsageAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetProviderNetworkUsageAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionProfile4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConnectionProfile5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanDelete(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile5", "CanDelete", 6usize, |this| ((**(this as *const *const IConnectionProfile5_Vtbl)).CanDelete)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryDeleteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile5", "TryDeleteAsync", 7usize, |this| ((**(this as *const *const IConnectionProfile5_Vtbl)).TryDeleteAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CanDelete, TryDeleteAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionProfile5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConnectionProfile6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDomainAuthenticatedBy(this: *mut core::ffi::c_void, kind: DomainAuthenticationKind, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfile6", "IsDomainAuthenticatedBy", 6usize, |this| ((**(this as *const *const IConnectionProfile6_Vtbl)).IsDomainAuthenticatedBy)(this, kind, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDomainAuthenticatedBy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionProfile6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConnectionProfileFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIsConnected(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter", "SetIsConnected", 6usize, |this| ((**(this as *const *const IConnectionProfileFilter_Vtbl)).SetIsConnected)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter", "IsConnected", 7usize, |this| ((**(this as *const *const IConnectionProfileFilter_Vtbl)).IsConnected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsWwanConnectionProfile(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter", "SetIsWwanConnectionProfile", 8usize, |this| ((**(this as *const *const IConnectionProfileFilter_Vtbl)).SetIsWwanConnectionProfile)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWwanConnectionProfile(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter", "IsWwanConnectionProfile", 9usize, |this| ((**(this as *const *const IConnectionProfileFilter_Vtbl)).IsWwanConnectionProfile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsWlanConnectionProfile(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter", "SetIsWlanConnectionProfile", 10usize, |this| ((**(this as *const *const IConnectionProfileFilter_Vtbl)).SetIsWlanConnectionProfile)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWlanConnectionProfile(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter", "IsWlanConnectionProfile", 11usize, |this| ((**(this as *const *const IConnectionProfileFilter_Vtbl)).IsWlanConnectionProfile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkCostType(this: *mut core::ffi::c_void, value: NetworkCostType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter", "SetNetworkCostType", 12usize, |this| ((**(this as *const *const IConnectionProfileFilter_Vtbl)).SetNetworkCostType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkCostType(this: *mut core::ffi::c_void, result__: *mut NetworkCostType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter", "NetworkCostType", 13usize, |this| ((**(this as *const *const IConnectionProfileFilter_Vtbl)).NetworkCostType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceProviderGuid(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter", "SetServiceProviderGuid", 14usize, |this| ((**(this as *const *const IConnectionProfileFilter_Vtbl)).SetServiceProviderGuid)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceProviderGuid(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter", "ServiceProviderGuid", 15usize, |this| ((**(this as *const *const IConnectionProfileFilter_Vtbl)).ServiceProviderGuid)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetIsConnected,
+            IsConnected,
+            SetIsWwanConnectionProfile,
+            IsWwanConnectionProfile,
+            SetIsWlanConnectionProfile,
+            IsWlanConnectionProfile,
+            SetNetworkCostType,
+            NetworkCostType,
+            SetServiceProviderGuid,
+            ServiceProviderGuid,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionProfileFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IConnectionProfileFilter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIsRoaming(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter2", "SetIsRoaming", 6usize, |this| ((**(this as *const *const IConnectionProfileFilter2_Vtbl)).SetIsRoaming)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRoaming(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter2", "IsRoaming", 7usize, |this| ((**(this as *const *const IConnectionProfileFilter2_Vtbl)).IsRoaming)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsOverDataLimit(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter2", "SetIsOverDataLimit", 8usize, |this| ((**(this as *const *const IConnectionProfileFilter2_Vtbl)).SetIsOverDataLimit)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOverDataLimit(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter2", "IsOverDataLimit", 9usize, |this| ((**(this as *const *const IConnectionProfileFilter2_Vtbl)).IsOverDataLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsBackgroundDataUsageRestricted(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter2", "SetIsBackgroundDataUsageRestricted", 10usize, |this| ((**(this as *const *const IConnectionProfileFilter2_Vtbl)).SetIsBackgroundDataUsageRestricted)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBackgroundDataUsageRestricted(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter2", "IsBackgroundDataUsageRestricted", 11usize, |this| ((**(this as *const *const IConnectionProfileFilter2_Vtbl)).IsBackgroundDataUsageRestricted)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn RawData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter2", "RawData", 12usize, |this| ((**(this as *const *const IConnectionProfileFilter2_Vtbl)).RawData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetIsRoaming,
+            IsRoaming,
+            SetIsOverDataLimit,
+            IsOverDataLimit,
+            SetIsBackgroundDataUsageRestricted,
+            IsBackgroundDataUsageRestricted,
+            #[cfg(feature = "Storage_Streams")]
+            RawData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            RawData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionProfileFilter2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConnectionProfileFilter3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPurposeGuid(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter3", "SetPurposeGuid", 6usize, |this| ((**(this as *const *const IConnectionProfileFilter3_Vtbl)).SetPurposeGuid)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PurposeGuid(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionProfileFilter3", "PurposeGuid", 7usize, |this| ((**(this as *const *const IConnectionProfileFilter3_Vtbl)).PurposeGuid)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPurposeGuid, PurposeGuid }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionProfileFilter3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConnectionSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectionProfile(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionSession", "ConnectionProfile", 6usize, |this| ((**(this as *const *const IConnectionSession_Vtbl)).ConnectionProfile)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectionProfile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConnectivityInterval_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectivityInterval", "StartTime", 6usize, |this| ((**(this as *const *const IConnectivityInterval_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectivityInterval", "ConnectionDuration", 7usize, |this| ((**(this as *const *const IConnectivityInterval_Vtbl)).ConnectionDuration)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartTime, ConnectionDuration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectivityInterval as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConnectivityManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcquireConnectionAsync(this: *mut core::ffi::c_void, cellularapncontext: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectivityManagerStatics", "AcquireConnectionAsync", 6usize, |this| ((**(this as *const *const IConnectivityManagerStatics_Vtbl)).AcquireConnectionAsync)(this, cellularapncontext, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddHttpRoutePolicy(this: *mut core::ffi::c_void, routepolicy: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectivityManagerStatics", "AddHttpRoutePolicy", 7usize, |this| ((**(this as *const *const IConnectivityManagerStatics_Vtbl)).AddHttpRoutePolicy)(this, routepolicy), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHttpRoutePolicy(this: *mut core::ffi::c_void, routepolicy: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectivityManagerStatics", "RemoveHttpRoutePolicy", 8usize, |this| ((**(this as *const *const IConnectivityManagerStatics_Vtbl)).RemoveHttpRoutePolicy)(this, routepolicy), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AcquireConnectionAsync,
+            AddHttpRoutePolicy,
+            RemoveHttpRoutePolicy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectivityManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataPlanStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DataPlanUsage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPlanStatus", "DataPlanUsage", 6usize, |this| ((**(this as *const *const IDataPlanStatus_Vtbl)).DataPlanUsage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataLimitInMegabytes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPlanStatus", "DataLimitInMegabytes", 7usize, |this| ((**(this as *const *const IDataPlanStatus_Vtbl)).DataLimitInMegabytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InboundBitsPerSecond(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPlanStatus", "InboundBitsPerSecond", 8usize, |this| ((**(this as *const *const IDataPlanStatus_Vtbl)).InboundBitsPerSecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutboundBitsPerSecond(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPlanStatus", "OutboundBitsPerSecond", 9usize, |this| ((**(this as *const *const IDataPlanStatus_Vtbl)).OutboundBitsPerSecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextBillingCycle(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPlanStatus", "NextBillingCycle", 10usize, |this| ((**(this as *const *const IDataPlanStatus_Vtbl)).NextBillingCycle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxTransferSizeInMegabytes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPlanStatus", "MaxTransferSizeInMegabytes", 11usize, |this| ((**(this as *const *const IDataPlanStatus_Vtbl)).MaxTransferSizeInMegabytes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DataPlanUsage,
+            DataLimitInMegabytes,
+            InboundBitsPerSecond,
+            OutboundBitsPerSecond,
+            NextBillingCycle,
+            MaxTransferSizeInMegabytes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPlanStatus as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataPlanUsage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MegabytesUsed(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPlanUsage", "MegabytesUsed", 6usize, |this| ((**(this as *const *const IDataPlanUsage_Vtbl)).MegabytesUsed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastSyncTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataPlanUsage", "LastSyncTime", 7usize, |this| ((**(this as *const *const IDataPlanUsage_Vtbl)).LastSyncTime)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MegabytesUsed, LastSyncTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataPlanUsage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IDataUsage_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn BytesSent(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataUsage", "BytesSent", 6usize, |this| ((**(this as *const *const IDataUsage_Vtbl)).BytesSent)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn BytesReceived(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataUsage", "BytesReceived", 7usize, |this| ((**(this as *const *const IDataUsage_Vtbl)).BytesReceived)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            BytesSent,
+            #[cfg(not(feature = "deprecated"))]
+            BytesSent: 0,
+            #[cfg(feature = "deprecated")]
+            BytesReceived,
+            #[cfg(not(feature = "deprecated"))]
+            BytesReceived: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataUsage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIPInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NetworkAdapter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIPInformation", "NetworkAdapter", 6usize, |this| ((**(this as *const *const IIPInformation_Vtbl)).NetworkAdapter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrefixLength(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIPInformation", "PrefixLength", 7usize, |this| ((**(this as *const *const IIPInformation_Vtbl)).PrefixLength)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NetworkAdapter, PrefixLength }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIPInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILanIdentifier_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InfrastructureId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanIdentifier", "InfrastructureId", 6usize, |this| ((**(this as *const *const ILanIdentifier_Vtbl)).InfrastructureId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PortId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanIdentifier", "PortId", 7usize, |this| ((**(this as *const *const ILanIdentifier_Vtbl)).PortId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkAdapterId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanIdentifier", "NetworkAdapterId", 8usize, |this| ((**(this as *const *const ILanIdentifier_Vtbl)).NetworkAdapterId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InfrastructureId, PortId, NetworkAdapterId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanIdentifier as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ILanIdentifierData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanIdentifierData", "Type", 6usize, |this| ((**(this as *const *const ILanIdentifierData_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanIdentifierData", "Value", 7usize, |this| ((**(this as *const *const ILanIdentifierData_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            #[cfg(feature = "Foundation_Collections")]
+            Value,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Value: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanIdentifierData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkAdapter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OutboundMaxBitsPerSecond(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkAdapter", "OutboundMaxBitsPerSecond", 6usize, |this| ((**(this as *const *const INetworkAdapter_Vtbl)).OutboundMaxBitsPerSecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InboundMaxBitsPerSecond(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkAdapter", "InboundMaxBitsPerSecond", 7usize, |this| ((**(this as *const *const INetworkAdapter_Vtbl)).InboundMaxBitsPerSecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IanaInterfaceType(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkAdapter", "IanaInterfaceType", 8usize, |this| ((**(this as *const *const INetworkAdapter_Vtbl)).IanaInterfaceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkItem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkAdapter", "NetworkItem", 9usize, |this| ((**(this as *const *const INetworkAdapter_Vtbl)).NetworkItem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkAdapterId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkAdapter", "NetworkAdapterId", 10usize, |this| ((**(this as *const *const INetworkAdapter_Vtbl)).NetworkAdapterId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectedProfileAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkAdapter", "GetConnectedProfileAsync", 11usize, |this| ((**(this as *const *const INetworkAdapter_Vtbl)).GetConnectedProfileAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OutboundMaxBitsPerSecond,
+            InboundMaxBitsPerSecond,
+            IanaInterfaceType,
+            NetworkItem,
+            NetworkAdapterId,
+            GetConnectedProfileAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkAdapter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for INetworkInformationStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetConnectionProfiles(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkInformationStatics", "GetConnectionProfiles", 6usize, |this| ((**(this as *const *const INetworkInformationStatics_Vtbl)).GetConnectionProfiles)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInternetConnectionProfile(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkInformationStatics", "GetInternetConnectionProfile", 7usize, |this| ((**(this as *const *const INetworkInformationStatics_Vtbl)).GetInternetConnectionProfile)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetLanIdentifiers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkInformationStatics", "GetLanIdentifiers", 8usize, |this| ((**(this as *const *const INetworkInformationStatics_Vtbl)).GetLanIdentifiers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetHostNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkInformationStatics", "GetHostNames", 9usize, |this| ((**(this as *const *const INetworkInformationStatics_Vtbl)).GetHostNames)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProxyConfigurationAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkInformationStatics", "GetProxyConfigurationAsync", 10usize, |this| ((**(this as *const *const INetworkInformationStatics_Vtbl)).GetProxyConfigurationAsync)(this, uri, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSortedEndpointPairs(this: *mut core::ffi::c_void, destinationlist: *mut core::ffi::c_void, sortoptions: super::HostNameSortOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkInformationStatics", "GetSortedEndpointPairs", 11usize, |this| ((**(this as *const *const INetworkInformationStatics_Vtbl)).GetSortedEndpointPairs)(this, destinationlist, sortoptions, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkStatusChanged(this: *mut core::ffi::c_void, networkstatushandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkInformationStatics", "NetworkStatusChanged", 12usize, |this| ((**(this as *const *const INetworkInformationStatics_Vtbl)).NetworkStatusChanged)(this, networkstatushandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNetworkStatusChanged(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkInformationStatics", "RemoveNetworkStatusChanged", 13usize, |this| ((**(this as *const *const INetworkInformationStatics_Vtbl)).RemoveNetworkStatusChanged)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetConnectionProfiles,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetConnectionProfiles: 0,
+            GetInternetConnectionProfile,
+            #[cfg(feature = "Foundation_Collections")]
+            GetLanIdentifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetLanIdentifiers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetHostNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetHostNames: 0,
+            GetProxyConfigurationAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSortedEndpointPairs,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSortedEndpointPairs: 0,
+            NetworkStatusChanged,
+            RemoveNetworkStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkInformationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for INetworkInformationStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindConnectionProfilesAsync(this: *mut core::ffi::c_void, pprofilefilter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkInformationStatics2", "FindConnectionProfilesAsync", 6usize, |this| ((**(this as *const *const INetworkInformationStatics2_Vtbl)).FindConnectionProfilesAsync)(this, pprofilefilter, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindConnectionProfilesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindConnectionProfilesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkInformationStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NetworkId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkItem", "NetworkId", 6usize, |this| ((**(this as *const *const INetworkItem_Vtbl)).NetworkId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkTypes(this: *mut core::ffi::c_void, result__: *mut NetworkTypes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkItem", "GetNetworkTypes", 7usize, |this| ((**(this as *const *const INetworkItem_Vtbl)).GetNetworkTypes)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NetworkId, GetNetworkTypes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkSecuritySettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NetworkAuthenticationType(this: *mut core::ffi::c_void, result__: *mut NetworkAuthenticationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkSecuritySettings", "NetworkAuthenticationType", 6usize, |this| ((**(this as *const *const INetworkSecuritySettings_Vtbl)).NetworkAuthenticationType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkEncryptionType(this: *mut core::ffi::c_void, result__: *mut NetworkEncryptionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkSecuritySettings", "NetworkEncryptionType", 7usize, |this| ((**(this as *const *const INetworkSecuritySettings_Vtbl)).NetworkEncryptionType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NetworkAuthenticationType, NetworkEncryptionType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkSecuritySettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkStateChangeEventDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasNewInternetConnectionProfile(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkStateChangeEventDetails", "HasNewInternetConnectionProfile", 6usize, |this| ((**(this as *const *const INetworkStateChangeEventDetails_Vtbl)).HasNewInternetConnectionProfile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasNewConnectionCost(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkStateChangeEventDetails", "HasNewConnectionCost", 7usize, |this| ((**(this as *const *const INetworkStateChangeEventDetails_Vtbl)).HasNewConnectionCost)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasNewNetworkConnectivityLevel(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkStateChangeEventDetails", "HasNewNetworkConnectivityLevel", 8usize, |this| ((**(this as *const *const INetworkStateChangeEventDetails_Vtbl)).HasNewNetworkConnectivityLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasNewDomainConnectivityLevel(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkStateChangeEventDetails", "HasNewDomainConnectivityLevel", 9usize, |this| ((**(this as *const *const INetworkStateChangeEventDetails_Vtbl)).HasNewDomainConnectivityLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasNewHostNameList(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkStateChangeEventDetails", "HasNewHostNameList", 10usize, |this| ((**(this as *const *const INetworkStateChangeEventDetails_Vtbl)).HasNewHostNameList)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasNewWwanRegistrationState(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkStateChangeEventDetails", "HasNewWwanRegistrationState", 11usize, |this| ((**(this as *const *const INetworkStateChangeEventDetails_Vtbl)).HasNewWwanRegistrationState)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HasNewInternetConnectionProfile,
+            HasNewConnectionCost,
+            HasNewNetworkConnectivityLevel,
+            HasNewDomainConnectivityLevel,
+            HasNewHostNameList,
+            HasNewWwanRegistrationState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkStateChangeEventDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkStateChangeEventDetails2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasNewTetheringOperationalState(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkStateChangeEventDetails2", "HasNewTetheringOperationalState", 6usize, |this| ((**(this as *const *const INetworkStateChangeEventDetails2_Vtbl)).HasNewTetheringOperationalState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasNewTetheringClientCount(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkStateChangeEventDetails2", "HasNewTetheringClientCount", 7usize, |this| ((**(this as *const *const INetworkStateChangeEventDetails2_Vtbl)).HasNewTetheringClientCount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HasNewTetheringOperationalState, HasNewTetheringClientCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkStateChangeEventDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkUsage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BytesSent(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkUsage", "BytesSent", 6usize, |this| ((**(this as *const *const INetworkUsage_Vtbl)).BytesSent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BytesReceived(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkUsage", "BytesReceived", 7usize, |this| ((**(this as *const *const INetworkUsage_Vtbl)).BytesReceived)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkUsage", "ConnectionDuration", 8usize, |this| ((**(this as *const *const INetworkUsage_Vtbl)).ConnectionDuration)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BytesSent, BytesReceived, ConnectionDuration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkUsage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProviderNetworkUsage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BytesSent(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderNetworkUsage", "BytesSent", 6usize, |this| ((**(this as *const *const IProviderNetworkUsage_Vtbl)).BytesSent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BytesReceived(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderNetworkUsage", "BytesReceived", 7usize, |this| ((**(this as *const *const IProviderNetworkUsage_Vtbl)).BytesReceived)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderNetworkUsage", "ProviderId", 8usize, |this| ((**(this as *const *const IProviderNetworkUsage_Vtbl)).ProviderId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BytesSent, BytesReceived, ProviderId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProviderNetworkUsage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IProxyConfiguration_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ProxyUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProxyConfiguration", "ProxyUris", 6usize, |this| ((**(this as *const *const IProxyConfiguration_Vtbl)).ProxyUris)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanConnectDirectly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProxyConfiguration", "CanConnectDirectly", 7usize, |this| ((**(this as *const *const IProxyConfiguration_Vtbl)).CanConnectDirectly)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ProxyUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ProxyUris: 0,
+            CanConnectDirectly,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProxyConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRoutePolicy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectionProfile(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRoutePolicy", "ConnectionProfile", 6usize, |this| ((**(this as *const *const IRoutePolicy_Vtbl)).ConnectionProfile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HostName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRoutePolicy", "HostName", 7usize, |this| ((**(this as *const *const IRoutePolicy_Vtbl)).HostName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HostNameType(this: *mut core::ffi::c_void, result__: *mut super::DomainNameType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRoutePolicy", "HostNameType", 8usize, |this| ((**(this as *const *const IRoutePolicy_Vtbl)).HostNameType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectionProfile, HostName, HostNameType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRoutePolicy as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRoutePolicyFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateRoutePolicy(this: *mut core::ffi::c_void, connectionprofile: *mut core::ffi::c_void, hostname: *mut core::ffi::c_void, r#type: super::DomainNameType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRoutePolicyFactory", "CreateRoutePolicy", 6usize, |this| ((**(this as *const *const IRoutePolicyFactory_Vtbl)).CreateRoutePolicy)(this, connectionprofile, hostname, r#type, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateRoutePolicy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRoutePolicyFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWlanConnectionProfileDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetConnectedSsid(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWlanConnectionProfileDetails", "GetConnectedSsid", 6usize, |this| ((**(this as *const *const IWlanConnectionProfileDetails_Vtbl)).GetConnectedSsid)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetConnectedSsid }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWlanConnectionProfileDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWwanConnectionProfileDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HomeProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWwanConnectionProfileDetails", "HomeProviderId", 6usize, |this| ((**(this as *const *const IWwanConnectionProfileDetails_Vtbl)).HomeProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccessPointName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWwanConnectionProfileDetails", "AccessPointName", 7usize, |this| ((**(this as *const *const IWwanConnectionProfileDetails_Vtbl)).AccessPointName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkRegistrationState(this: *mut core::ffi::c_void, result__: *mut WwanNetworkRegistrationState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWwanConnectionProfileDetails", "GetNetworkRegistrationState", 8usize, |this| ((**(this as *const *const IWwanConnectionProfileDetails_Vtbl)).GetNetworkRegistrationState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentDataClass(this: *mut core::ffi::c_void, result__: *mut WwanDataClass) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWwanConnectionProfileDetails", "GetCurrentDataClass", 9usize, |this| ((**(this as *const *const IWwanConnectionProfileDetails_Vtbl)).GetCurrentDataClass)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HomeProviderId,
+            AccessPointName,
+            GetNetworkRegistrationState,
+            GetCurrentDataClass,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWwanConnectionProfileDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWwanConnectionProfileDetails2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IPKind(this: *mut core::ffi::c_void, result__: *mut WwanNetworkIPKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWwanConnectionProfileDetails2", "IPKind", 6usize, |this| ((**(this as *const *const IWwanConnectionProfileDetails2_Vtbl)).IPKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PurposeGuids(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWwanConnectionProfileDetails2", "PurposeGuids", 7usize, |this| ((**(this as *const *const IWwanConnectionProfileDetails2_Vtbl)).PurposeGuids)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IPKind,
+            #[cfg(feature = "Foundation_Collections")]
+            PurposeGuids,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PurposeGuids: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWwanConnectionProfileDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
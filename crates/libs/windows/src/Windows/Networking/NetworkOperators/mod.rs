@@ -6232,6 +6232,7 @@ impl DataClasses {
 impl windows_core::TypeKind for DataClasses {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DataClasses {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DataClasses").field(&self.0).finish()
@@ -6284,6 +6285,7 @@ impl ESimAuthenticationPreference {
 impl windows_core::TypeKind for ESimAuthenticationPreference {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ESimAuthenticationPreference {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ESimAuthenticationPreference").field(&self.0).finish()
@@ -6303,6 +6305,7 @@ impl ESimDiscoverResultKind {
 impl windows_core::TypeKind for ESimDiscoverResultKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ESimDiscoverResultKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ESimDiscoverResultKind").field(&self.0).finish()
@@ -6348,6 +6351,7 @@ impl ESimOperationStatus {
 impl windows_core::TypeKind for ESimOperationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ESimOperationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ESimOperationStatus").field(&self.0).finish()
@@ -6367,6 +6371,7 @@ impl ESimProfileClass {
 impl windows_core::TypeKind for ESimProfileClass {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ESimProfileClass {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ESimProfileClass").field(&self.0).finish()
@@ -6391,6 +6396,7 @@ impl ESimProfileMetadataState {
 impl windows_core::TypeKind for ESimProfileMetadataState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ESimProfileMetadataState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ESimProfileMetadataState").field(&self.0).finish()
@@ -6411,6 +6417,7 @@ impl ESimProfileState {
 impl windows_core::TypeKind for ESimProfileState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ESimProfileState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ESimProfileState").field(&self.0).finish()
@@ -6431,6 +6438,7 @@ impl ESimState {
 impl windows_core::TypeKind for ESimState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ESimState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ESimState").field(&self.0).finish()
@@ -6452,6 +6460,7 @@ impl ESimWatcherStatus {
 impl windows_core::TypeKind for ESimWatcherStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ESimWatcherStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ESimWatcherStatus").field(&self.0).finish()
@@ -6475,6 +6484,7 @@ impl HotspotAuthenticationResponseCode {
 impl windows_core::TypeKind for HotspotAuthenticationResponseCode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HotspotAuthenticationResponseCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HotspotAuthenticationResponseCode").field(&self.0).finish()
@@ -6496,6 +6506,7 @@ impl MobileBroadbandAccountWatcherStatus {
 impl windows_core::TypeKind for MobileBroadbandAccountWatcherStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MobileBroadbandAccountWatcherStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MobileBroadbandAccountWatcherStatus").field(&self.0).finish()
@@ -6516,6 +6527,7 @@ impl MobileBroadbandDeviceType {
 impl windows_core::TypeKind for MobileBroadbandDeviceType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MobileBroadbandDeviceType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MobileBroadbandDeviceType").field(&self.0).finish()
@@ -6536,6 +6548,7 @@ impl MobileBroadbandModemStatus {
 impl windows_core::TypeKind for MobileBroadbandModemStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MobileBroadbandModemStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MobileBroadbandModemStatus").field(&self.0).finish()
@@ -6555,6 +6568,7 @@ impl MobileBroadbandPinFormat {
 impl windows_core::TypeKind for MobileBroadbandPinFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MobileBroadbandPinFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MobileBroadbandPinFormat").field(&self.0).finish()
@@ -6575,6 +6589,7 @@ impl MobileBroadbandPinLockState {
 impl windows_core::TypeKind for MobileBroadbandPinLockState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MobileBroadbandPinLockState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MobileBroadbandPinLockState").field(&self.0).finish()
@@ -6602,6 +6617,7 @@ impl MobileBroadbandPinType {
 impl windows_core::TypeKind for MobileBroadbandPinType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MobileBroadbandPinType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MobileBroadbandPinType").field(&self.0).finish()
@@ -6620,6 +6636,7 @@ impl MobileBroadbandRadioState {
 impl windows_core::TypeKind for MobileBroadbandRadioState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MobileBroadbandRadioState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MobileBroadbandRadioState").field(&self.0).finish()
@@ -6646,6 +6663,7 @@ impl MobileBroadbandSlotState {
 impl windows_core::TypeKind for MobileBroadbandSlotState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MobileBroadbandSlotState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MobileBroadbandSlotState").field(&self.0).finish()
@@ -6666,6 +6684,7 @@ impl MobileBroadbandUiccAppOperationStatus {
 impl windows_core::TypeKind for MobileBroadbandUiccAppOperationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MobileBroadbandUiccAppOperationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MobileBroadbandUiccAppOperationStatus").field(&self.0).finish()
@@ -6690,6 +6709,7 @@ impl NetworkDeviceStatus {
 impl windows_core::TypeKind for NetworkDeviceStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NetworkDeviceStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NetworkDeviceStatus").field(&self.0).finish()
@@ -6707,6 +6727,7 @@ impl NetworkOperatorDataUsageNotificationKind {
 impl windows_core::TypeKind for NetworkOperatorDataUsageNotificationKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NetworkOperatorDataUsageNotificationKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NetworkOperatorDataUsageNotificationKind").field(&self.0).finish()
@@ -6736,6 +6757,7 @@ impl NetworkOperatorEventMessageType {
 impl windows_core::TypeKind for NetworkOperatorEventMessageType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NetworkOperatorEventMessageType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NetworkOperatorEventMessageType").field(&self.0).finish()
@@ -6759,6 +6781,7 @@ impl NetworkRegistrationState {
 impl windows_core::TypeKind for NetworkRegistrationState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NetworkRegistrationState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NetworkRegistrationState").field(&self.0).finish()
@@ -6777,6 +6800,7 @@ impl ProfileMediaType {
 impl windows_core::TypeKind for ProfileMediaType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProfileMediaType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProfileMediaType").field(&self.0).finish()
@@ -6801,6 +6825,7 @@ impl TetheringCapability {
 impl windows_core::TypeKind for TetheringCapability {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TetheringCapability {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TetheringCapability").field(&self.0).finish()
@@ -6829,6 +6854,7 @@ impl TetheringOperationStatus {
 impl windows_core::TypeKind for TetheringOperationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TetheringOperationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TetheringOperationStatus").field(&self.0).finish()
@@ -6849,6 +6875,7 @@ impl TetheringOperationalState {
 impl windows_core::TypeKind for TetheringOperationalState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TetheringOperationalState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TetheringOperationalState").field(&self.0).finish()
@@ -6868,6 +6895,7 @@ impl TetheringWiFiAuthenticationKind {
 impl windows_core::TypeKind for TetheringWiFiAuthenticationKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TetheringWiFiAuthenticationKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TetheringWiFiAuthenticationKind").field(&self.0).finish()
@@ -6888,6 +6916,7 @@ impl TetheringWiFiBand {
 impl windows_core::TypeKind for TetheringWiFiBand {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TetheringWiFiBand {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TetheringWiFiBand").field(&self.0).finish()
@@ -6906,6 +6935,7 @@ impl TetheringWiFiPerformancePriority {
 impl windows_core::TypeKind for TetheringWiFiPerformancePriority {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TetheringWiFiPerformancePriority {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TetheringWiFiPerformancePriority").field(&self.0).finish()
@@ -6930,6 +6960,7 @@ impl UiccAccessCondition {
 impl windows_core::TypeKind for UiccAccessCondition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UiccAccessCondition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UiccAccessCondition").field(&self.0).finish()
@@ -6953,6 +6984,7 @@ impl UiccAppKind {
 impl windows_core::TypeKind for UiccAppKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UiccAppKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UiccAppKind").field(&self.0).finish()
@@ -6972,6 +7004,7 @@ impl UiccAppRecordKind {
 impl windows_core::TypeKind for UiccAppRecordKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UiccAppRecordKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UiccAppRecordKind").field(&self.0).finish()
@@ -6994,6 +7027,7 @@ impl UssdResultCode {
 impl windows_core::TypeKind for UssdResultCode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UssdResultCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UssdResultCode").field(&self.0).finish()
@@ -7003,7 +7037,8 @@ impl windows_core::RuntimeType for UssdResultCode {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Networking.NetworkOperators.UssdResultCode;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ESimProfileInstallProgress {
     pub TotalSizeInBytes: i32,
     pub InstalledSizeInBytes: i32,
@@ -7020,7 +7055,8 @@ impl Default for ESimProfileInstallProgress {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ProfileUsage {
     pub UsageInMegabytes: u32,
     pub LastSyncTime: super::super::Foundation::DateTime,
@@ -7036,3 +7072,5 @@ impl Default for ProfileUsage {
         unsafe { core::mem::zeroed() }
     }
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,3198 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IESim_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AvailableMemoryInBytes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim", "AvailableMemoryInBytes", 6usize, |this| ((**(this as *const *const IESim_Vtbl)).AvailableMemoryInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Eid(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim", "Eid", 7usize, |this| ((**(this as *const *const IESim_Vtbl)).Eid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirmwareVersion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim", "FirmwareVersion", 8usize, |this| ((**(this as *const *const IESim_Vtbl)).FirmwareVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MobileBroadbandModemDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim", "MobileBroadbandModemDeviceId", 9usize, |this| ((**(this as *const *const IESim_Vtbl)).MobileBroadbandModemDeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Policy(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim", "Policy", 10usize, |this| ((**(this as *const *const IESim_Vtbl)).Policy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut ESimState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim", "State", 11usize, |this| ((**(this as *const *const IESim_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetProfiles(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim", "GetProfiles", 12usize, |this| ((**(this as *const *const IESim_Vtbl)).GetProfiles)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteProfileAsync(this: *mut core::ffi::c_void, profileid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim", "DeleteProfileAsync", 13usize, |this| ((**(this as *const *const IESim_Vtbl)).DeleteProfileAsync)(this, profileid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadProfileMetadataAsync(this: *mut core::ffi::c_void, activationcode: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim", "DownloadProfileMetadataAsync", 14usize, |this| ((**(this as *const *const IESim_Vtbl)).DownloadProfileMetadataAsync)(this, activationcode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim", "ResetAsync", 15usize, |this| ((**(this as *const *const IESim_Vtbl)).ResetAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProfileChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim", "ProfileChanged", 16usize, |this| ((**(this as *const *const IESim_Vtbl)).ProfileChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProfileChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim", "RemoveProfileChanged", 17usize, |this| ((**(this as *const *const IESim_Vtbl)).RemoveProfileChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AvailableMemoryInBytes,
+            Eid,
+            FirmwareVersion,
+            MobileBroadbandModemDeviceId,
+            Policy,
+            State,
+            #[cfg(feature = "Foundation_Collections")]
+            GetProfiles,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetProfiles: 0,
+            DeleteProfileAsync,
+            DownloadProfileMetadataAsync,
+            ResetAsync,
+            ProfileChanged,
+            RemoveProfileChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESim as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESim2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Discover(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim2", "Discover", 6usize, |this| ((**(this as *const *const IESim2_Vtbl)).Discover)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscoverWithServerAddressAndMatchingId(this: *mut core::ffi::c_void, serveraddress: core::mem::MaybeUninit<windows_core::HSTRING>, matchingid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim2", "DiscoverWithServerAddressAndMatchingId", 7usize, |this| ((**(this as *const *const IESim2_Vtbl)).DiscoverWithServerAddressAndMatchingId)(this, serveraddress, matchingid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscoverAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim2", "DiscoverAsync", 8usize, |this| ((**(this as *const *const IESim2_Vtbl)).DiscoverAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscoverWithServerAddressAndMatchingIdAsync(this: *mut core::ffi::c_void, serveraddress: core::mem::MaybeUninit<windows_core::HSTRING>, matchingid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim2", "DiscoverWithServerAddressAndMatchingIdAsync", 9usize, |this| ((**(this as *const *const IESim2_Vtbl)).DiscoverWithServerAddressAndMatchingIdAsync)(this, serveraddress, matchingid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Discover,
+            DiscoverWithServerAddressAndMatchingId,
+            DiscoverAsync,
+            DiscoverWithServerAddressAndMatchingIdAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESim2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESim3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SlotIndex(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESim3", "SlotIndex", 6usize, |this| ((**(this as *const *const IESim3_Vtbl)).SlotIndex)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SlotIndex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESim3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESimAddedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ESim(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimAddedEventArgs", "ESim", 6usize, |this| ((**(this as *const *const IESimAddedEventArgs_Vtbl)).ESim)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ESim }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESimAddedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESimDiscoverEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MatchingId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimDiscoverEvent", "MatchingId", 6usize, |this| ((**(this as *const *const IESimDiscoverEvent_Vtbl)).MatchingId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RspServerAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimDiscoverEvent", "RspServerAddress", 7usize, |this| ((**(this as *const *const IESimDiscoverEvent_Vtbl)).RspServerAddress)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MatchingId, RspServerAddress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESimDiscoverEvent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IESimDiscoverResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Events(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimDiscoverResult", "Events", 6usize, |this| ((**(this as *const *const IESimDiscoverResult_Vtbl)).Events)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut ESimDiscoverResultKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimDiscoverResult", "Kind", 7usize, |this| ((**(this as *const *const IESimDiscoverResult_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProfileMetadata(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimDiscoverResult", "ProfileMetadata", 8usize, |this| ((**(this as *const *const IESimDiscoverResult_Vtbl)).ProfileMetadata)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Result(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimDiscoverResult", "Result", 9usize, |this| ((**(this as *const *const IESimDiscoverResult_Vtbl)).Result)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Events,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Events: 0,
+            Kind,
+            ProfileMetadata,
+            Result,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESimDiscoverResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESimDownloadProfileMetadataResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Result(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimDownloadProfileMetadataResult", "Result", 6usize, |this| ((**(this as *const *const IESimDownloadProfileMetadataResult_Vtbl)).Result)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProfileMetadata(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimDownloadProfileMetadataResult", "ProfileMetadata", 7usize, |this| ((**(this as *const *const IESimDownloadProfileMetadataResult_Vtbl)).ProfileMetadata)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Result, ProfileMetadata }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESimDownloadProfileMetadataResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESimManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServiceInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimManagerStatics", "ServiceInfo", 6usize, |this| ((**(this as *const *const IESimManagerStatics_Vtbl)).ServiceInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryCreateESimWatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimManagerStatics", "TryCreateESimWatcher", 7usize, |this| ((**(this as *const *const IESimManagerStatics_Vtbl)).TryCreateESimWatcher)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceInfoChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimManagerStatics", "ServiceInfoChanged", 8usize, |this| ((**(this as *const *const IESimManagerStatics_Vtbl)).ServiceInfoChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveServiceInfoChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimManagerStatics", "RemoveServiceInfoChanged", 9usize, |this| ((**(this as *const *const IESimManagerStatics_Vtbl)).RemoveServiceInfoChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServiceInfo,
+            TryCreateESimWatcher,
+            ServiceInfoChanged,
+            RemoveServiceInfoChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESimManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESimOperationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut ESimOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimOperationResult", "Status", 6usize, |this| ((**(this as *const *const IESimOperationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESimOperationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESimPolicy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShouldEnableManagingUi(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimPolicy", "ShouldEnableManagingUi", 6usize, |this| ((**(this as *const *const IESimPolicy_Vtbl)).ShouldEnableManagingUi)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShouldEnableManagingUi }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESimPolicy as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IESimProfile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Class(this: *mut core::ffi::c_void, result__: *mut ESimProfileClass) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfile", "Class", 6usize, |this| ((**(this as *const *const IESimProfile_Vtbl)).Class)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Nickname(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfile", "Nickname", 7usize, |this| ((**(this as *const *const IESimProfile_Vtbl)).Nickname)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Policy(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfile", "Policy", 8usize, |this| ((**(this as *const *const IESimProfile_Vtbl)).Policy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfile", "Id", 9usize, |this| ((**(this as *const *const IESimProfile_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ProviderIcon(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfile", "ProviderIcon", 10usize, |this| ((**(this as *const *const IESimProfile_Vtbl)).ProviderIcon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfile", "ProviderId", 11usize, |this| ((**(this as *const *const IESimProfile_Vtbl)).ProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfile", "ProviderName", 12usize, |this| ((**(this as *const *const IESimProfile_Vtbl)).ProviderName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut ESimProfileState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfile", "State", 13usize, |this| ((**(this as *const *const IESimProfile_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfile", "DisableAsync", 14usize, |this| ((**(this as *const *const IESimProfile_Vtbl)).DisableAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfile", "EnableAsync", 15usize, |this| ((**(this as *const *const IESimProfile_Vtbl)).EnableAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNicknameAsync(this: *mut core::ffi::c_void, newnickname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfile", "SetNicknameAsync", 16usize, |this| ((**(this as *const *const IESimProfile_Vtbl)).SetNicknameAsync)(this, newnickname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Class,
+            Nickname,
+            Policy,
+            Id,
+            #[cfg(feature = "Storage_Streams")]
+            ProviderIcon,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ProviderIcon: 0,
+            ProviderId,
+            ProviderName,
+            State,
+            DisableAsync,
+            EnableAsync,
+            SetNicknameAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESimProfile as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IESimProfileMetadata_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsConfirmationCodeRequired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfileMetadata", "IsConfirmationCodeRequired", 6usize, |this| ((**(this as *const *const IESimProfileMetadata_Vtbl)).IsConfirmationCodeRequired)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Policy(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfileMetadata", "Policy", 7usize, |this| ((**(this as *const *const IESimProfileMetadata_Vtbl)).Policy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfileMetadata", "Id", 8usize, |this| ((**(this as *const *const IESimProfileMetadata_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ProviderIcon(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfileMetadata", "ProviderIcon", 9usize, |this| ((**(this as *const *const IESimProfileMetadata_Vtbl)).ProviderIcon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfileMetadata", "ProviderId", 10usize, |this| ((**(this as *const *const IESimProfileMetadata_Vtbl)).ProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfileMetadata", "ProviderName", 11usize, |this| ((**(this as *const *const IESimProfileMetadata_Vtbl)).ProviderName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut ESimProfileMetadataState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfileMetadata", "State", 12usize, |this| ((**(this as *const *const IESimProfileMetadata_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DenyInstallAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfileMetadata", "DenyInstallAsync", 13usize, |this| ((**(this as *const *const IESimProfileMetadata_Vtbl)).DenyInstallAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConfirmInstallAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfileMetadata", "ConfirmInstallAsync", 14usize, |this| ((**(this as *const *const IESimProfileMetadata_Vtbl)).ConfirmInstallAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConfirmInstallWithConfirmationCodeAsync(this: *mut core::ffi::c_void, confirmationcode: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfileMetadata", "ConfirmInstallWithConfirmationCodeAsync", 15usize, |this| ((**(this as *const *const IESimProfileMetadata_Vtbl)).ConfirmInstallWithConfirmationCodeAsync)(this, confirmationcode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostponeInstallAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfileMetadata", "PostponeInstallAsync", 16usize, |this| ((**(this as *const *const IESimProfileMetadata_Vtbl)).PostponeInstallAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfileMetadata", "StateChanged", 17usize, |this| ((**(this as *const *const IESimProfileMetadata_Vtbl)).StateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfileMetadata", "RemoveStateChanged", 18usize, |this| ((**(this as *const *const IESimProfileMetadata_Vtbl)).RemoveStateChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsConfirmationCodeRequired,
+            Policy,
+            Id,
+            #[cfg(feature = "Storage_Streams")]
+            ProviderIcon,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ProviderIcon: 0,
+            ProviderId,
+            ProviderName,
+            State,
+            DenyInstallAsync,
+            ConfirmInstallAsync,
+            ConfirmInstallWithConfirmationCodeAsync,
+            PostponeInstallAsync,
+            StateChanged,
+            RemoveStateChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESimProfileMetadata as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESimProfilePolicy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanDelete(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfilePolicy", "CanDelete", 6usize, |this| ((**(this as *const *const IESimProfilePolicy_Vtbl)).CanDelete)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanDisable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfilePolicy", "CanDisable", 7usize, |this| ((**(this as *const *const IESimProfilePolicy_Vtbl)).CanDisable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsManagedByEnterprise(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimProfilePolicy", "IsManagedByEnterprise", 8usize, |this| ((**(this as *const *const IESimProfilePolicy_Vtbl)).IsManagedByEnterprise)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CanDelete, CanDisable, IsManagedByEnterprise }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESimProfilePolicy as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESimRemovedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ESim(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimRemovedEventArgs", "ESim", 6usize, |this| ((**(this as *const *const IESimRemovedEventArgs_Vtbl)).ESim)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ESim }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESimRemovedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESimServiceInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AuthenticationPreference(this: *mut core::ffi::c_void, result__: *mut ESimAuthenticationPreference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimServiceInfo", "AuthenticationPreference", 6usize, |this| ((**(this as *const *const IESimServiceInfo_Vtbl)).AuthenticationPreference)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsESimUiEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimServiceInfo", "IsESimUiEnabled", 7usize, |this| ((**(this as *const *const IESimServiceInfo_Vtbl)).IsESimUiEnabled)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AuthenticationPreference, IsESimUiEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESimServiceInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESimUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ESim(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimUpdatedEventArgs", "ESim", 6usize, |this| ((**(this as *const *const IESimUpdatedEventArgs_Vtbl)).ESim)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ESim }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESimUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESimWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut ESimWatcherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimWatcher", "Status", 6usize, |this| ((**(this as *const *const IESimWatcher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimWatcher", "Start", 7usize, |this| ((**(this as *const *const IESimWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimWatcher", "Stop", 8usize, |this| ((**(this as *const *const IESimWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Added(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimWatcher", "Added", 9usize, |this| ((**(this as *const *const IESimWatcher_Vtbl)).Added)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimWatcher", "RemoveAdded", 10usize, |this| ((**(this as *const *const IESimWatcher_Vtbl)).RemoveAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimWatcher", "EnumerationCompleted", 11usize, |this| ((**(this as *const *const IESimWatcher_Vtbl)).EnumerationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnumerationCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimWatcher", "RemoveEnumerationCompleted", 12usize, |this| ((**(this as *const *const IESimWatcher_Vtbl)).RemoveEnumerationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Removed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimWatcher", "Removed", 13usize, |this| ((**(this as *const *const IESimWatcher_Vtbl)).Removed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimWatcher", "RemoveRemoved", 14usize, |this| ((**(this as *const *const IESimWatcher_Vtbl)).RemoveRemoved)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stopped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimWatcher", "Stopped", 15usize, |this| ((**(this as *const *const IESimWatcher_Vtbl)).Stopped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStopped(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimWatcher", "RemoveStopped", 16usize, |this| ((**(this as *const *const IESimWatcher_Vtbl)).RemoveStopped)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Updated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimWatcher", "Updated", 17usize, |this| ((**(this as *const *const IESimWatcher_Vtbl)).Updated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESimWatcher", "RemoveUpdated", 18usize, |this| ((**(this as *const *const IESimWatcher_Vtbl)).RemoveUpdated)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            Start,
+            Stop,
+            Added,
+            RemoveAdded,
+            EnumerationCompleted,
+            RemoveEnumerationCompleted,
+            Removed,
+            RemoveRemoved,
+            Stopped,
+            RemoveStopped,
+            Updated,
+            RemoveUpdated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESimWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFdnAccessManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestUnlockAsync(this: *mut core::ffi::c_void, contactlistid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFdnAccessManagerStatics", "RequestUnlockAsync", 6usize, |this| ((**(this as *const *const IFdnAccessManagerStatics_Vtbl)).RequestUnlockAsync)(this, contactlistid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestUnlockAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFdnAccessManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Networking_Connectivity"))]
+impl windows_core::imp::ProxyVtable for IHotspotAuthenticationContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WirelessNetworkId(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotAuthenticationContext", "WirelessNetworkId", 6usize, |this| ((**(this as *const *const IHotspotAuthenticationContext_Vtbl)).WirelessNetworkId)(this, result_size__, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Connectivity")]
+        unsafe extern "system" fn NetworkAdapter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotAuthenticationContext", "NetworkAdapter", 7usize, |this| ((**(this as *const *const IHotspotAuthenticationContext_Vtbl)).NetworkAdapter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedirectMessageUrl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotAuthenticationContext", "RedirectMessageUrl", 8usize, |this| ((**(this as *const *const IHotspotAuthenticationContext_Vtbl)).RedirectMessageUrl)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn RedirectMessageXml(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotAuthenticationContext", "RedirectMessageXml", 9usize, |this| ((**(this as *const *const IHotspotAuthenticationContext_Vtbl)).RedirectMessageXml)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationUrl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotAuthenticationContext", "AuthenticationUrl", 10usize, |this| ((**(this as *const *const IHotspotAuthenticationContext_Vtbl)).AuthenticationUrl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IssueCredentials(this: *mut core::ffi::c_void, username: core::mem::MaybeUninit<windows_core::HSTRING>, password: core::mem::MaybeUninit<windows_core::HSTRING>, extraparameters: core::mem::MaybeUninit<windows_core::HSTRING>, markasmanualconnectonfailure: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotAuthenticationContext", "IssueCredentials", 11usize, |this| ((**(this as *const *const IHotspotAuthenticationContext_Vtbl)).IssueCredentials)(this, username, password, extraparameters, markasmanualconnectonfailure), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbortAuthentication(this: *mut core::ffi::c_void, markasmanual: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotAuthenticationContext", "AbortAuthentication", 12usize, |this| ((**(this as *const *const IHotspotAuthenticationContext_Vtbl)).AbortAuthentication)(this, markasmanual), |result| Some(*result))
+        }
+        unsafe extern "system" fn SkipAuthentication(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotAuthenticationContext", "SkipAuthentication", 13usize, |this| ((**(this as *const *const IHotspotAuthenticationContext_Vtbl)).SkipAuthentication)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TriggerAttentionRequired(this: *mut core::ffi::c_void, packagerelativeapplicationid: core::mem::MaybeUninit<windows_core::HSTRING>, applicationparameters: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotAuthenticationContext", "TriggerAttentionRequired", 14usize, |this| ((**(this as *const *const IHotspotAuthenticationContext_Vtbl)).TriggerAttentionRequired)(this, packagerelativeapplicationid, applicationparameters), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            WirelessNetworkId,
+            #[cfg(feature = "Networking_Connectivity")]
+            NetworkAdapter,
+            #[cfg(not(feature = "Networking_Connectivity"))]
+            NetworkAdapter: 0,
+            RedirectMessageUrl,
+            #[cfg(feature = "Data_Xml_Dom")]
+            RedirectMessageXml,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            RedirectMessageXml: 0,
+            AuthenticationUrl,
+            IssueCredentials,
+            AbortAuthentication,
+            SkipAuthentication,
+            TriggerAttentionRequired,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHotspotAuthenticationContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHotspotAuthenticationContext2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IssueCredentialsAsync(this: *mut core::ffi::c_void, username: core::mem::MaybeUninit<windows_core::HSTRING>, password: core::mem::MaybeUninit<windows_core::HSTRING>, extraparameters: core::mem::MaybeUninit<windows_core::HSTRING>, markasmanualconnectonfailure: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotAuthenticationContext2", "IssueCredentialsAsync", 6usize, |this| ((**(this as *const *const IHotspotAuthenticationContext2_Vtbl)).IssueCredentialsAsync)(this, username, password, extraparameters, markasmanualconnectonfailure, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IssueCredentialsAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHotspotAuthenticationContext2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHotspotAuthenticationContextStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryGetAuthenticationContext(this: *mut core::ffi::c_void, eventoken: core::mem::MaybeUninit<windows_core::HSTRING>, context: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotAuthenticationContextStatics", "TryGetAuthenticationContext", 6usize, |this| ((**(this as *const *const IHotspotAuthenticationContextStatics_Vtbl)).TryGetAuthenticationContext)(this, eventoken, context, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryGetAuthenticationContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHotspotAuthenticationContextStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHotspotAuthenticationEventDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventToken(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotAuthenticationEventDetails", "EventToken", 6usize, |this| ((**(this as *const *const IHotspotAuthenticationEventDetails_Vtbl)).EventToken)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EventToken }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHotspotAuthenticationEventDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IHotspotCredentialsAuthenticationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasNetworkErrorOccurred(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotCredentialsAuthenticationResult", "HasNetworkErrorOccurred", 6usize, |this| ((**(this as *const *const IHotspotCredentialsAuthenticationResult_Vtbl)).HasNetworkErrorOccurred)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseCode(this: *mut core::ffi::c_void, result__: *mut HotspotAuthenticationResponseCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotCredentialsAuthenticationResult", "ResponseCode", 7usize, |this| ((**(this as *const *const IHotspotCredentialsAuthenticationResult_Vtbl)).ResponseCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogoffUrl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotCredentialsAuthenticationResult", "LogoffUrl", 8usize, |this| ((**(this as *const *const IHotspotCredentialsAuthenticationResult_Vtbl)).LogoffUrl)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn AuthenticationReplyXml(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHotspotCredentialsAuthenticationResult", "AuthenticationReplyXml", 9usize, |this| ((**(this as *const *const IHotspotCredentialsAuthenticationResult_Vtbl)).AuthenticationReplyXml)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HasNetworkErrorOccurred,
+            ResponseCode,
+            LogoffUrl,
+            #[cfg(feature = "Data_Xml_Dom")]
+            AuthenticationReplyXml,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            AuthenticationReplyXml: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHotspotCredentialsAuthenticationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IKnownCSimFilePathsStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EFSpn(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownCSimFilePathsStatics", "EFSpn", 6usize, |this| ((**(this as *const *const IKnownCSimFilePathsStatics_Vtbl)).EFSpn)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Gid1(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownCSimFilePathsStatics", "Gid1", 7usize, |this| ((**(this as *const *const IKnownCSimFilePathsStatics_Vtbl)).Gid1)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Gid2(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownCSimFilePathsStatics", "Gid2", 8usize, |this| ((**(this as *const *const IKnownCSimFilePathsStatics_Vtbl)).Gid2)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            EFSpn,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EFSpn: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Gid1,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Gid1: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Gid2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Gid2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownCSimFilePathsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IKnownRuimFilePathsStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EFSpn(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRuimFilePathsStatics", "EFSpn", 6usize, |this| ((**(this as *const *const IKnownRuimFilePathsStatics_Vtbl)).EFSpn)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Gid1(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRuimFilePathsStatics", "Gid1", 7usize, |this| ((**(this as *const *const IKnownRuimFilePathsStatics_Vtbl)).Gid1)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Gid2(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRuimFilePathsStatics", "Gid2", 8usize, |this| ((**(this as *const *const IKnownRuimFilePathsStatics_Vtbl)).Gid2)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            EFSpn,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EFSpn: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Gid1,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Gid1: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Gid2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Gid2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownRuimFilePathsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IKnownSimFilePathsStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EFOns(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimFilePathsStatics", "EFOns", 6usize, |this| ((**(this as *const *const IKnownSimFilePathsStatics_Vtbl)).EFOns)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EFSpn(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimFilePathsStatics", "EFSpn", 7usize, |this| ((**(this as *const *const IKnownSimFilePathsStatics_Vtbl)).EFSpn)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Gid1(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimFilePathsStatics", "Gid1", 8usize, |this| ((**(this as *const *const IKnownSimFilePathsStatics_Vtbl)).Gid1)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Gid2(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownSimFilePathsStatics", "Gid2", 9usize, |this| ((**(this as *const *const IKnownSimFilePathsStatics_Vtbl)).Gid2)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            EFOns,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EFOns: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            EFSpn,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EFSpn: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Gid1,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Gid1: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Gid2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Gid2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownSimFilePathsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IKnownUSimFilePathsStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EFSpn(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownUSimFilePathsStatics", "EFSpn", 6usize, |this| ((**(this as *const *const IKnownUSimFilePathsStatics_Vtbl)).EFSpn)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EFOpl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownUSimFilePathsStatics", "EFOpl", 7usize, |this| ((**(this as *const *const IKnownUSimFilePathsStatics_Vtbl)).EFOpl)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EFPnn(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownUSimFilePathsStatics", "EFPnn", 8usize, |this| ((**(this as *const *const IKnownUSimFilePathsStatics_Vtbl)).EFPnn)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Gid1(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownUSimFilePathsStatics", "Gid1", 9usize, |this| ((**(this as *const *const IKnownUSimFilePathsStatics_Vtbl)).Gid1)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Gid2(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownUSimFilePathsStatics", "Gid2", 10usize, |this| ((**(this as *const *const IKnownUSimFilePathsStatics_Vtbl)).Gid2)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            EFSpn,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EFSpn: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            EFOpl,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EFOpl: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            EFPnn,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EFPnn: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Gid1,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Gid1: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Gid2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Gid2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownUSimFilePathsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandAccount_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NetworkAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccount", "NetworkAccountId", 6usize, |this| ((**(this as *const *const IMobileBroadbandAccount_Vtbl)).NetworkAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceProviderGuid(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccount", "ServiceProviderGuid", 7usize, |this| ((**(this as *const *const IMobileBroadbandAccount_Vtbl)).ServiceProviderGuid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceProviderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccount", "ServiceProviderName", 8usize, |this| ((**(this as *const *const IMobileBroadbandAccount_Vtbl)).ServiceProviderName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentNetwork(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccount", "CurrentNetwork", 9usize, |this| ((**(this as *const *const IMobileBroadbandAccount_Vtbl)).CurrentNetwork)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentDeviceInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccount", "CurrentDeviceInformation", 10usize, |this| ((**(this as *const *const IMobileBroadbandAccount_Vtbl)).CurrentDeviceInformation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NetworkAccountId,
+            ServiceProviderGuid,
+            ServiceProviderName,
+            CurrentNetwork,
+            CurrentDeviceInformation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandAccount as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Networking_Connectivity"))]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandAccount2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Networking_Connectivity"))]
+        unsafe extern "system" fn GetConnectionProfiles(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccount2", "GetConnectionProfiles", 6usize, |this| ((**(this as *const *const IMobileBroadbandAccount2_Vtbl)).GetConnectionProfiles)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Networking_Connectivity"))]
+            GetConnectionProfiles,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Networking_Connectivity")))]
+            GetConnectionProfiles: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandAccount2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandAccount3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccountExperienceUrl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccount3", "AccountExperienceUrl", 6usize, |this| ((**(this as *const *const IMobileBroadbandAccount3_Vtbl)).AccountExperienceUrl)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AccountExperienceUrl }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandAccount3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandAccountEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NetworkAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountEventArgs", "NetworkAccountId", 6usize, |this| ((**(this as *const *const IMobileBroadbandAccountEventArgs_Vtbl)).NetworkAccountId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NetworkAccountId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandAccountEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandAccountStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AvailableNetworkAccountIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountStatics", "AvailableNetworkAccountIds", 6usize, |this| ((**(this as *const *const IMobileBroadbandAccountStatics_Vtbl)).AvailableNetworkAccountIds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromNetworkAccountId(this: *mut core::ffi::c_void, networkaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountStatics", "CreateFromNetworkAccountId", 7usize, |this| ((**(this as *const *const IMobileBroadbandAccountStatics_Vtbl)).CreateFromNetworkAccountId)(this, networkaccountid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AvailableNetworkAccountIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AvailableNetworkAccountIds: 0,
+            CreateFromNetworkAccountId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandAccountStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandAccountUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NetworkAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountUpdatedEventArgs", "NetworkAccountId", 6usize, |this| ((**(this as *const *const IMobileBroadbandAccountUpdatedEventArgs_Vtbl)).NetworkAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasDeviceInformationChanged(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountUpdatedEventArgs", "HasDeviceInformationChanged", 7usize, |this| ((**(this as *const *const IMobileBroadbandAccountUpdatedEventArgs_Vtbl)).HasDeviceInformationChanged)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasNetworkChanged(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountUpdatedEventArgs", "HasNetworkChanged", 8usize, |this| ((**(this as *const *const IMobileBroadbandAccountUpdatedEventArgs_Vtbl)).HasNetworkChanged)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NetworkAccountId,
+            HasDeviceInformationChanged,
+            HasNetworkChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandAccountUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandAccountWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccountAdded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountWatcher", "AccountAdded", 6usize, |this| ((**(this as *const *const IMobileBroadbandAccountWatcher_Vtbl)).AccountAdded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAccountAdded(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountWatcher", "RemoveAccountAdded", 7usize, |this| ((**(this as *const *const IMobileBroadbandAccountWatcher_Vtbl)).RemoveAccountAdded)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccountUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountWatcher", "AccountUpdated", 8usize, |this| ((**(this as *const *const IMobileBroadbandAccountWatcher_Vtbl)).AccountUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAccountUpdated(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountWatcher", "RemoveAccountUpdated", 9usize, |this| ((**(this as *const *const IMobileBroadbandAccountWatcher_Vtbl)).RemoveAccountUpdated)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccountRemoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountWatcher", "AccountRemoved", 10usize, |this| ((**(this as *const *const IMobileBroadbandAccountWatcher_Vtbl)).AccountRemoved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAccountRemoved(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountWatcher", "RemoveAccountRemoved", 11usize, |this| ((**(this as *const *const IMobileBroadbandAccountWatcher_Vtbl)).RemoveAccountRemoved)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountWatcher", "EnumerationCompleted", 12usize, |this| ((**(this as *const *const IMobileBroadbandAccountWatcher_Vtbl)).EnumerationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnumerationCompleted(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountWatcher", "RemoveEnumerationCompleted", 13usize, |this| ((**(this as *const *const IMobileBroadbandAccountWatcher_Vtbl)).RemoveEnumerationCompleted)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stopped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountWatcher", "Stopped", 14usize, |this| ((**(this as *const *const IMobileBroadbandAccountWatcher_Vtbl)).Stopped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStopped(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountWatcher", "RemoveStopped", 15usize, |this| ((**(this as *const *const IMobileBroadbandAccountWatcher_Vtbl)).RemoveStopped)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut MobileBroadbandAccountWatcherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountWatcher", "Status", 16usize, |this| ((**(this as *const *const IMobileBroadbandAccountWatcher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountWatcher", "Start", 17usize, |this| ((**(this as *const *const IMobileBroadbandAccountWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAccountWatcher", "Stop", 18usize, |this| ((**(this as *const *const IMobileBroadbandAccountWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AccountAdded,
+            RemoveAccountAdded,
+            AccountUpdated,
+            RemoveAccountUpdated,
+            AccountRemoved,
+            RemoveAccountRemoved,
+            EnumerationCompleted,
+            RemoveEnumerationCompleted,
+            Stopped,
+            RemoveStopped,
+            Status,
+            Start,
+            Stop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandAccountWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandAntennaSar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AntennaIndex(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAntennaSar", "AntennaIndex", 6usize, |this| ((**(this as *const *const IMobileBroadbandAntennaSar_Vtbl)).AntennaIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SarBackoffIndex(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAntennaSar", "SarBackoffIndex", 7usize, |this| ((**(this as *const *const IMobileBroadbandAntennaSar_Vtbl)).SarBackoffIndex)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AntennaIndex, SarBackoffIndex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandAntennaSar as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandAntennaSarFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithIndex(this: *mut core::ffi::c_void, antennaindex: i32, sarbackoffindex: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandAntennaSarFactory", "CreateWithIndex", 6usize, |this| ((**(this as *const *const IMobileBroadbandAntennaSarFactory_Vtbl)).CreateWithIndex)(this, antennaindex, sarbackoffindex, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithIndex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandAntennaSarFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandCellCdma_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BaseStationId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellCdma", "BaseStationId", 6usize, |this| ((**(this as *const *const IMobileBroadbandCellCdma_Vtbl)).BaseStationId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BaseStationPNCode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellCdma", "BaseStationPNCode", 7usize, |this| ((**(this as *const *const IMobileBroadbandCellCdma_Vtbl)).BaseStationPNCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BaseStationLatitude(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellCdma", "BaseStationLatitude", 8usize, |this| ((**(this as *const *const IMobileBroadbandCellCdma_Vtbl)).BaseStationLatitude)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BaseStationLongitude(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellCdma", "BaseStationLongitude", 9usize, |this| ((**(this as *const *const IMobileBroadbandCellCdma_Vtbl)).BaseStationLongitude)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BaseStationLastBroadcastGpsTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellCdma", "BaseStationLastBroadcastGpsTime", 10usize, |this| ((**(this as *const *const IMobileBroadbandCellCdma_Vtbl)).BaseStationLastBroadcastGpsTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellCdma", "NetworkId", 11usize, |this| ((**(this as *const *const IMobileBroadbandCellCdma_Vtbl)).NetworkId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PilotSignalStrengthInDB(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellCdma", "PilotSignalStrengthInDB", 12usize, |this| ((**(this as *const *const IMobileBroadbandCellCdma_Vtbl)).PilotSignalStrengthInDB)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellCdma", "SystemId", 13usize, |this| ((**(this as *const *const IMobileBroadbandCellCdma_Vtbl)).SystemId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BaseStationId,
+            BaseStationPNCode,
+            BaseStationLatitude,
+            BaseStationLongitude,
+            BaseStationLastBroadcastGpsTime,
+            NetworkId,
+            PilotSignalStrengthInDB,
+            SystemId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandCellCdma as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandCellGsm_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BaseStationId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellGsm", "BaseStationId", 6usize, |this| ((**(this as *const *const IMobileBroadbandCellGsm_Vtbl)).BaseStationId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CellId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellGsm", "CellId", 7usize, |this| ((**(this as *const *const IMobileBroadbandCellGsm_Vtbl)).CellId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChannelNumber(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellGsm", "ChannelNumber", 8usize, |this| ((**(this as *const *const IMobileBroadbandCellGsm_Vtbl)).ChannelNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocationAreaCode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellGsm", "LocationAreaCode", 9usize, |this| ((**(this as *const *const IMobileBroadbandCellGsm_Vtbl)).LocationAreaCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellGsm", "ProviderId", 10usize, |this| ((**(this as *const *const IMobileBroadbandCellGsm_Vtbl)).ProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceivedSignalStrengthInDBm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellGsm", "ReceivedSignalStrengthInDBm", 11usize, |this| ((**(this as *const *const IMobileBroadbandCellGsm_Vtbl)).ReceivedSignalStrengthInDBm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimingAdvanceInBitPeriods(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellGsm", "TimingAdvanceInBitPeriods", 12usize, |this| ((**(this as *const *const IMobileBroadbandCellGsm_Vtbl)).TimingAdvanceInBitPeriods)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BaseStationId,
+            CellId,
+            ChannelNumber,
+            LocationAreaCode,
+            ProviderId,
+            ReceivedSignalStrengthInDBm,
+            TimingAdvanceInBitPeriods,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandCellGsm as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandCellLte_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CellId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellLte", "CellId", 6usize, |this| ((**(this as *const *const IMobileBroadbandCellLte_Vtbl)).CellId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChannelNumber(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellLte", "ChannelNumber", 7usize, |this| ((**(this as *const *const IMobileBroadbandCellLte_Vtbl)).ChannelNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhysicalCellId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellLte", "PhysicalCellId", 8usize, |this| ((**(this as *const *const IMobileBroadbandCellLte_Vtbl)).PhysicalCellId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellLte", "ProviderId", 9usize, |this| ((**(this as *const *const IMobileBroadbandCellLte_Vtbl)).ProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReferenceSignalReceivedPowerInDBm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellLte", "ReferenceSignalReceivedPowerInDBm", 10usize, |this| ((**(this as *const *const IMobileBroadbandCellLte_Vtbl)).ReferenceSignalReceivedPowerInDBm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReferenceSignalReceivedQualityInDBm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellLte", "ReferenceSignalReceivedQualityInDBm", 11usize, |this| ((**(this as *const *const IMobileBroadbandCellLte_Vtbl)).ReferenceSignalReceivedQualityInDBm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimingAdvanceInBitPeriods(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellLte", "TimingAdvanceInBitPeriods", 12usize, |this| ((**(this as *const *const IMobileBroadbandCellLte_Vtbl)).TimingAdvanceInBitPeriods)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrackingAreaCode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellLte", "TrackingAreaCode", 13usize, |this| ((**(this as *const *const IMobileBroadbandCellLte_Vtbl)).TrackingAreaCode)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CellId,
+            ChannelNumber,
+            PhysicalCellId,
+            ProviderId,
+            ReferenceSignalReceivedPowerInDBm,
+            ReferenceSignalReceivedQualityInDBm,
+            TimingAdvanceInBitPeriods,
+            TrackingAreaCode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandCellLte as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandCellNR_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CellId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellNR", "CellId", 6usize, |this| ((**(this as *const *const IMobileBroadbandCellNR_Vtbl)).CellId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChannelNumber(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellNR", "ChannelNumber", 7usize, |this| ((**(this as *const *const IMobileBroadbandCellNR_Vtbl)).ChannelNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhysicalCellId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellNR", "PhysicalCellId", 8usize, |this| ((**(this as *const *const IMobileBroadbandCellNR_Vtbl)).PhysicalCellId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellNR", "ProviderId", 9usize, |this| ((**(this as *const *const IMobileBroadbandCellNR_Vtbl)).ProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReferenceSignalReceivedPowerInDBm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellNR", "ReferenceSignalReceivedPowerInDBm", 10usize, |this| ((**(this as *const *const IMobileBroadbandCellNR_Vtbl)).ReferenceSignalReceivedPowerInDBm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReferenceSignalReceivedQualityInDBm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellNR", "ReferenceSignalReceivedQualityInDBm", 11usize, |this| ((**(this as *const *const IMobileBroadbandCellNR_Vtbl)).ReferenceSignalReceivedQualityInDBm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimingAdvanceInNanoseconds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellNR", "TimingAdvanceInNanoseconds", 12usize, |this| ((**(this as *const *const IMobileBroadbandCellNR_Vtbl)).TimingAdvanceInNanoseconds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrackingAreaCode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellNR", "TrackingAreaCode", 13usize, |this| ((**(this as *const *const IMobileBroadbandCellNR_Vtbl)).TrackingAreaCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalToNoiseRatioInDB(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellNR", "SignalToNoiseRatioInDB", 14usize, |this| ((**(this as *const *const IMobileBroadbandCellNR_Vtbl)).SignalToNoiseRatioInDB)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CellId,
+            ChannelNumber,
+            PhysicalCellId,
+            ProviderId,
+            ReferenceSignalReceivedPowerInDBm,
+            ReferenceSignalReceivedQualityInDBm,
+            TimingAdvanceInNanoseconds,
+            TrackingAreaCode,
+            SignalToNoiseRatioInDB,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandCellNR as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandCellTdscdma_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CellId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellTdscdma", "CellId", 6usize, |this| ((**(this as *const *const IMobileBroadbandCellTdscdma_Vtbl)).CellId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CellParameterId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellTdscdma", "CellParameterId", 7usize, |this| ((**(this as *const *const IMobileBroadbandCellTdscdma_Vtbl)).CellParameterId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChannelNumber(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellTdscdma", "ChannelNumber", 8usize, |this| ((**(this as *const *const IMobileBroadbandCellTdscdma_Vtbl)).ChannelNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocationAreaCode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellTdscdma", "LocationAreaCode", 9usize, |this| ((**(this as *const *const IMobileBroadbandCellTdscdma_Vtbl)).LocationAreaCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PathLossInDB(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellTdscdma", "PathLossInDB", 10usize, |this| ((**(this as *const *const IMobileBroadbandCellTdscdma_Vtbl)).PathLossInDB)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellTdscdma", "ProviderId", 11usize, |this| ((**(this as *const *const IMobileBroadbandCellTdscdma_Vtbl)).ProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceivedSignalCodePowerInDBm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellTdscdma", "ReceivedSignalCodePowerInDBm", 12usize, |this| ((**(this as *const *const IMobileBroadbandCellTdscdma_Vtbl)).ReceivedSignalCodePowerInDBm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimingAdvanceInBitPeriods(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellTdscdma", "TimingAdvanceInBitPeriods", 13usize, |this| ((**(this as *const *const IMobileBroadbandCellTdscdma_Vtbl)).TimingAdvanceInBitPeriods)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CellId,
+            CellParameterId,
+            ChannelNumber,
+            LocationAreaCode,
+            PathLossInDB,
+            ProviderId,
+            ReceivedSignalCodePowerInDBm,
+            TimingAdvanceInBitPeriods,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandCellTdscdma as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandCellUmts_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CellId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellUmts", "CellId", 6usize, |this| ((**(this as *const *const IMobileBroadbandCellUmts_Vtbl)).CellId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChannelNumber(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellUmts", "ChannelNumber", 7usize, |this| ((**(this as *const *const IMobileBroadbandCellUmts_Vtbl)).ChannelNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocationAreaCode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellUmts", "LocationAreaCode", 8usize, |this| ((**(this as *const *const IMobileBroadbandCellUmts_Vtbl)).LocationAreaCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PathLossInDB(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellUmts", "PathLossInDB", 9usize, |this| ((**(this as *const *const IMobileBroadbandCellUmts_Vtbl)).PathLossInDB)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrimaryScramblingCode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellUmts", "PrimaryScramblingCode", 10usize, |this| ((**(this as *const *const IMobileBroadbandCellUmts_Vtbl)).PrimaryScramblingCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellUmts", "ProviderId", 11usize, |this| ((**(this as *const *const IMobileBroadbandCellUmts_Vtbl)).ProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceivedSignalCodePowerInDBm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellUmts", "ReceivedSignalCodePowerInDBm", 12usize, |this| ((**(this as *const *const IMobileBroadbandCellUmts_Vtbl)).ReceivedSignalCodePowerInDBm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalToNoiseRatioInDB(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellUmts", "SignalToNoiseRatioInDB", 13usize, |this| ((**(this as *const *const IMobileBroadbandCellUmts_Vtbl)).SignalToNoiseRatioInDB)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CellId,
+            ChannelNumber,
+            LocationAreaCode,
+            PathLossInDB,
+            PrimaryScramblingCode,
+            ProviderId,
+            ReceivedSignalCodePowerInDBm,
+            SignalToNoiseRatioInDB,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandCellUmts as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandCellsInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn NeighboringCellsCdma(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellsInfo", "NeighboringCellsCdma", 6usize, |this| ((**(this as *const *const IMobileBroadbandCellsInfo_Vtbl)).NeighboringCellsCdma)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn NeighboringCellsGsm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellsInfo", "NeighboringCellsGsm", 7usize, |this| ((**(this as *const *const IMobileBroadbandCellsInfo_Vtbl)).NeighboringCellsGsm)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn NeighboringCellsLte(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellsInfo", "NeighboringCellsLte", 8usize, |this| ((**(this as *const *const IMobileBroadbandCellsInfo_Vtbl)).NeighboringCellsLte)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn NeighboringCellsTdscdma(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellsInfo", "NeighboringCellsTdscdma", 9usize, |this| ((**(this as *const *const IMobileBroadbandCellsInfo_Vtbl)).NeighboringCellsTdscdma)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn NeighboringCellsUmts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellsInfo", "NeighboringCellsUmts", 10usize, |this| ((**(this as *const *const IMobileBroadbandCellsInfo_Vtbl)).NeighboringCellsUmts)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ServingCellsCdma(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellsInfo", "ServingCellsCdma", 11usize, |this| ((**(this as *const *const IMobileBroadbandCellsInfo_Vtbl)).ServingCellsCdma)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ServingCellsGsm(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellsInfo", "ServingCellsGsm", 12usize, |this| ((**(this as *const *const IMobileBroadbandCellsInfo_Vtbl)).ServingCellsGsm)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ServingCellsLte(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellsInfo", "ServingCellsLte", 13usize, |this| ((**(this as *const *const IMobileBroadbandCellsInfo_Vtbl)).ServingCellsLte)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ServingCellsTdscdma(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellsInfo", "ServingCellsTdscdma", 14usize, |this| ((**(this as *const *const IMobileBroadbandCellsInfo_Vtbl)).ServingCellsTdscdma)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ServingCellsUmts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellsInfo", "ServingCellsUmts", 15usize, |this| ((**(this as *const *const IMobileBroadbandCellsInfo_Vtbl)).ServingCellsUmts)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            NeighboringCellsCdma,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            NeighboringCellsCdma: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            NeighboringCellsGsm,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            NeighboringCellsGsm: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            NeighboringCellsLte,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            NeighboringCellsLte: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            NeighboringCellsTdscdma,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            NeighboringCellsTdscdma: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            NeighboringCellsUmts,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            NeighboringCellsUmts: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ServingCellsCdma,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ServingCellsCdma: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ServingCellsGsm,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ServingCellsGsm: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ServingCellsLte,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ServingCellsLte: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ServingCellsTdscdma,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ServingCellsTdscdma: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ServingCellsUmts,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ServingCellsUmts: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandCellsInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandCellsInfo2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn NeighboringCellsNR(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellsInfo2", "NeighboringCellsNR", 6usize, |this| ((**(this as *const *const IMobileBroadbandCellsInfo2_Vtbl)).NeighboringCellsNR)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ServingCellsNR(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCellsInfo2", "ServingCellsNR", 7usize, |this| ((**(this as *const *const IMobileBroadbandCellsInfo2_Vtbl)).ServingCellsNR)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            NeighboringCellsNR,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            NeighboringCellsNR: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ServingCellsNR,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ServingCellsNR: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandCellsInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandCurrentSlotIndexChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentSlotIndex(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandCurrentSlotIndexChangedEventArgs", "CurrentSlotIndex", 6usize, |this| ((**(this as *const *const IMobileBroadbandCurrentSlotIndexChangedEventArgs_Vtbl)).CurrentSlotIndex)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CurrentSlotIndex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandCurrentSlotIndexChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Sms", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandDeviceInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NetworkDeviceStatus(this: *mut core::ffi::c_void, result__: *mut NetworkDeviceStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation", "NetworkDeviceStatus", 6usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation_Vtbl)).NetworkDeviceStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Manufacturer(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation", "Manufacturer", 7usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation_Vtbl)).Manufacturer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Model(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation", "Model", 8usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation_Vtbl)).Model)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirmwareInformation(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation", "FirmwareInformation", 9usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation_Vtbl)).FirmwareInformation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Sms")]
+        unsafe extern "system" fn CellularClass(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Sms::CellularClass) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation", "CellularClass", 10usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation_Vtbl)).CellularClass)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataClasses(this: *mut core::ffi::c_void, result__: *mut DataClasses) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation", "DataClasses", 11usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation_Vtbl)).DataClasses)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomDataClass(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation", "CustomDataClass", 12usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation_Vtbl)).CustomDataClass)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MobileEquipmentId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation", "MobileEquipmentId", 13usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation_Vtbl)).MobileEquipmentId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TelephoneNumbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation", "TelephoneNumbers", 14usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation_Vtbl)).TelephoneNumbers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubscriberId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation", "SubscriberId", 15usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation_Vtbl)).SubscriberId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SimIccId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation", "SimIccId", 16usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation_Vtbl)).SimIccId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceType(this: *mut core::ffi::c_void, result__: *mut MobileBroadbandDeviceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation", "DeviceType", 17usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation_Vtbl)).DeviceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation", "DeviceId", 18usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentRadioState(this: *mut core::ffi::c_void, result__: *mut MobileBroadbandRadioState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation", "CurrentRadioState", 19usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation_Vtbl)).CurrentRadioState)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NetworkDeviceStatus,
+            Manufacturer,
+            Model,
+            FirmwareInformation,
+            #[cfg(feature = "Devices_Sms")]
+            CellularClass,
+            #[cfg(not(feature = "Devices_Sms"))]
+            CellularClass: 0,
+            DataClasses,
+            CustomDataClass,
+            MobileEquipmentId,
+            #[cfg(feature = "Foundation_Collections")]
+            TelephoneNumbers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TelephoneNumbers: 0,
+            SubscriberId,
+            SimIccId,
+            DeviceType,
+            DeviceId,
+            CurrentRadioState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandDeviceInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandDeviceInformation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PinManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation2", "PinManager", 6usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation2_Vtbl)).PinManager)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Revision(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation2", "Revision", 7usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation2_Vtbl)).Revision)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SerialNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation2", "SerialNumber", 8usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation2_Vtbl)).SerialNumber)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PinManager, Revision, SerialNumber }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandDeviceInformation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandDeviceInformation3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SimSpn(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation3", "SimSpn", 6usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation3_Vtbl)).SimSpn)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SimPnn(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation3", "SimPnn", 7usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation3_Vtbl)).SimPnn)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SimGid1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation3", "SimGid1", 8usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation3_Vtbl)).SimGid1)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SimSpn, SimPnn, SimGid1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandDeviceInformation3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandDeviceInformation4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SlotManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceInformation4", "SlotManager", 6usize, |this| ((**(this as *const *const IMobileBroadbandDeviceInformation4_Vtbl)).SlotManager)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SlotManager }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandDeviceInformation4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandDeviceService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceServiceId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceService", "DeviceServiceId", 6usize, |this| ((**(this as *const *const IMobileBroadbandDeviceService_Vtbl)).DeviceServiceId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedCommands(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceService", "SupportedCommands", 7usize, |this| ((**(this as *const *const IMobileBroadbandDeviceService_Vtbl)).SupportedCommands)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenDataSession(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceService", "OpenDataSession", 8usize, |this| ((**(this as *const *const IMobileBroadbandDeviceService_Vtbl)).OpenDataSession)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenCommandSession(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceService", "OpenCommandSession", 9usize, |this| ((**(this as *const *const IMobileBroadbandDeviceService_Vtbl)).OpenCommandSession)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceServiceId,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedCommands,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedCommands: 0,
+            OpenDataSession,
+            OpenCommandSession,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandDeviceService as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandDeviceServiceCommandEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceCommandEventArgs", "DeviceId", 6usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceCommandEventArgs_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceServiceId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceCommandEventArgs", "DeviceServiceId", 7usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceCommandEventArgs_Vtbl)).DeviceServiceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceCommandEventArgs", "EventId", 8usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceCommandEventArgs_Vtbl)).EventId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ReceivedData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceCommandEventArgs", "ReceivedData", 9usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceCommandEventArgs_Vtbl)).ReceivedData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            DeviceServiceId,
+            EventId,
+            #[cfg(feature = "Storage_Streams")]
+            ReceivedData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ReceivedData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandDeviceServiceCommandEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandDeviceServiceCommandResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceCommandResult", "StatusCode", 6usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceCommandResult_Vtbl)).StatusCode)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ResponseData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceCommandResult", "ResponseData", 7usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceCommandResult_Vtbl)).ResponseData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StatusCode,
+            #[cfg(feature = "Storage_Streams")]
+            ResponseData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ResponseData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandDeviceServiceCommandResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandDeviceServiceCommandSession_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SendQueryCommandAsync(this: *mut core::ffi::c_void, commandid: u32, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceCommandSession", "SendQueryCommandAsync", 6usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceCommandSession_Vtbl)).SendQueryCommandAsync)(this, commandid, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SendSetCommandAsync(this: *mut core::ffi::c_void, commandid: u32, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceCommandSession", "SendSetCommandAsync", 7usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceCommandSession_Vtbl)).SendSetCommandAsync)(this, commandid, data, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseSession(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceCommandSession", "CloseSession", 8usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceCommandSession_Vtbl)).CloseSession)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            SendQueryCommandAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SendQueryCommandAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SendSetCommandAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SendSetCommandAsync: 0,
+            CloseSession,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandDeviceServiceCommandSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandDeviceServiceCommandSession2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CommandReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceCommandSession2", "CommandReceived", 6usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceCommandSession2_Vtbl)).CommandReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCommandReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceCommandSession2", "RemoveCommandReceived", 7usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceCommandSession2_Vtbl)).RemoveCommandReceived)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CommandReceived, RemoveCommandReceived }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandDeviceServiceCommandSession2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandDeviceServiceDataReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ReceivedData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceDataReceivedEventArgs", "ReceivedData", 6usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceDataReceivedEventArgs_Vtbl)).ReceivedData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            ReceivedData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ReceivedData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandDeviceServiceDataReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandDeviceServiceDataSession_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn WriteDataAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceDataSession", "WriteDataAsync", 6usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceDataSession_Vtbl)).WriteDataAsync)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseSession(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceDataSession", "CloseSession", 7usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceDataSession_Vtbl)).CloseSession)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataReceived(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceDataSession", "DataReceived", 8usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceDataSession_Vtbl)).DataReceived)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDataReceived(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceDataSession", "RemoveDataReceived", 9usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceDataSession_Vtbl)).RemoveDataReceived)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            WriteDataAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            WriteDataAsync: 0,
+            CloseSession,
+            DataReceived,
+            RemoveDataReceived,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandDeviceServiceDataSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandDeviceServiceInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceServiceId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceInformation", "DeviceServiceId", 6usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceInformation_Vtbl)).DeviceServiceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDataReadSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceInformation", "IsDataReadSupported", 7usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceInformation_Vtbl)).IsDataReadSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDataWriteSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceInformation", "IsDataWriteSupported", 8usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceInformation_Vtbl)).IsDataWriteSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceServiceId, IsDataReadSupported, IsDataWriteSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandDeviceServiceInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandDeviceServiceTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceTriggerDetails", "DeviceId", 6usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceTriggerDetails_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceServiceId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceTriggerDetails", "DeviceServiceId", 7usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceTriggerDetails_Vtbl)).DeviceServiceId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ReceivedData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceTriggerDetails", "ReceivedData", 8usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceTriggerDetails_Vtbl)).ReceivedData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceId,
+            DeviceServiceId,
+            #[cfg(feature = "Storage_Streams")]
+            ReceivedData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ReceivedData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandDeviceServiceTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandDeviceServiceTriggerDetails2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandDeviceServiceTriggerDetails2", "EventId", 6usize, |this| ((**(this as *const *const IMobileBroadbandDeviceServiceTriggerDetails2_Vtbl)).EventId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EventId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandDeviceServiceTriggerDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandModem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentAccount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem", "CurrentAccount", 6usize, |this| ((**(this as *const *const IMobileBroadbandModem_Vtbl)).CurrentAccount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem", "DeviceInformation", 7usize, |this| ((**(this as *const *const IMobileBroadbandModem_Vtbl)).DeviceInformation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxDeviceServiceCommandSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem", "MaxDeviceServiceCommandSizeInBytes", 8usize, |this| ((**(this as *const *const IMobileBroadbandModem_Vtbl)).MaxDeviceServiceCommandSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxDeviceServiceDataSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem", "MaxDeviceServiceDataSizeInBytes", 9usize, |this| ((**(this as *const *const IMobileBroadbandModem_Vtbl)).MaxDeviceServiceDataSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DeviceServices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem", "DeviceServices", 10usize, |this| ((**(this as *const *const IMobileBroadbandModem_Vtbl)).DeviceServices)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceService(this: *mut core::ffi::c_void, deviceserviceid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem", "GetDeviceService", 11usize, |this| ((**(this as *const *const IMobileBroadbandModem_Vtbl)).GetDeviceService)(this, deviceserviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsResetSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem", "IsResetSupported", 12usize, |this| ((**(this as *const *const IMobileBroadbandModem_Vtbl)).IsResetSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem", "ResetAsync", 13usize, |this| ((**(this as *const *const IMobileBroadbandModem_Vtbl)).ResetAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentConfigurationAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem", "GetCurrentConfigurationAsync", 14usize, |this| ((**(this as *const *const IMobileBroadbandModem_Vtbl)).GetCurrentConfigurationAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentNetwork(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem", "CurrentNetwork", 15usize, |this| ((**(this as *const *const IMobileBroadbandModem_Vtbl)).CurrentNetwork)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentAccount,
+            DeviceInformation,
+            MaxDeviceServiceCommandSizeInBytes,
+            MaxDeviceServiceDataSizeInBytes,
+            #[cfg(feature = "Foundation_Collections")]
+            DeviceServices,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DeviceServices: 0,
+            GetDeviceService,
+            IsResetSupported,
+            ResetAsync,
+            GetCurrentConfigurationAsync,
+            CurrentNetwork,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandModem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandModem2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIsPassthroughEnabledAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem2", "GetIsPassthroughEnabledAsync", 6usize, |this| ((**(this as *const *const IMobileBroadbandModem2_Vtbl)).GetIsPassthroughEnabledAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsPassthroughEnabledAsync(this: *mut core::ffi::c_void, value: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem2", "SetIsPassthroughEnabledAsync", 7usize, |this| ((**(this as *const *const IMobileBroadbandModem2_Vtbl)).SetIsPassthroughEnabledAsync)(this, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIsPassthroughEnabledAsync, SetIsPassthroughEnabledAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandModem2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandModem3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryGetPcoAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem3", "TryGetPcoAsync", 6usize, |this| ((**(this as *const *const IMobileBroadbandModem3_Vtbl)).TryGetPcoAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInEmergencyCallMode(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem3", "IsInEmergencyCallMode", 7usize, |this| ((**(this as *const *const IMobileBroadbandModem3_Vtbl)).IsInEmergencyCallMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInEmergencyCallModeChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem3", "IsInEmergencyCallModeChanged", 8usize, |this| ((**(this as *const *const IMobileBroadbandModem3_Vtbl)).IsInEmergencyCallModeChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveIsInEmergencyCallModeChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem3", "RemoveIsInEmergencyCallModeChanged", 9usize, |this| ((**(this as *const *const IMobileBroadbandModem3_Vtbl)).RemoveIsInEmergencyCallModeChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TryGetPcoAsync,
+            IsInEmergencyCallMode,
+            IsInEmergencyCallModeChanged,
+            RemoveIsInEmergencyCallModeChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandModem3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandModem4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIsPassthroughEnabledWithSlotIndexAsync(this: *mut core::ffi::c_void, value: bool, slotindex: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem4", "SetIsPassthroughEnabledWithSlotIndexAsync", 6usize, |this| ((**(this as *const *const IMobileBroadbandModem4_Vtbl)).SetIsPassthroughEnabledWithSlotIndexAsync)(this, value, slotindex, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsPassthroughEnabledWithSlotIndexAsync(this: *mut core::ffi::c_void, slotindex: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem4", "GetIsPassthroughEnabledWithSlotIndexAsync", 7usize, |this| ((**(this as *const *const IMobileBroadbandModem4_Vtbl)).GetIsPassthroughEnabledWithSlotIndexAsync)(this, slotindex, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsPassthroughEnabledWithSlotIndex(this: *mut core::ffi::c_void, value: bool, slotindex: i32, result__: *mut MobileBroadbandModemStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem4", "SetIsPassthroughEnabledWithSlotIndex", 8usize, |this| ((**(this as *const *const IMobileBroadbandModem4_Vtbl)).SetIsPassthroughEnabledWithSlotIndex)(this, value, slotindex, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsPassthroughEnabledWithSlotIndex(this: *mut core::ffi::c_void, slotindex: i32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModem4", "GetIsPassthroughEnabledWithSlotIndex", 9usize, |this| ((**(this as *const *const IMobileBroadbandModem4_Vtbl)).GetIsPassthroughEnabledWithSlotIndex)(this, slotindex, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetIsPassthroughEnabledWithSlotIndexAsync,
+            GetIsPassthroughEnabledWithSlotIndexAsync,
+            SetIsPassthroughEnabledWithSlotIndex,
+            GetIsPassthroughEnabledWithSlotIndex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandModem4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandModemConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uicc(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModemConfiguration", "Uicc", 6usize, |this| ((**(this as *const *const IMobileBroadbandModemConfiguration_Vtbl)).Uicc)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HomeProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModemConfiguration", "HomeProviderId", 7usize, |this| ((**(this as *const *const IMobileBroadbandModemConfiguration_Vtbl)).HomeProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HomeProviderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModemConfiguration", "HomeProviderName", 8usize, |this| ((**(this as *const *const IMobileBroadbandModemConfiguration_Vtbl)).HomeProviderName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uicc, HomeProviderId, HomeProviderName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandModemConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandModemConfiguration2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SarManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModemConfiguration2", "SarManager", 6usize, |this| ((**(this as *const *const IMobileBroadbandModemConfiguration2_Vtbl)).SarManager)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SarManager }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandModemConfiguration2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandModemIsolation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddAllowedHost(this: *mut core::ffi::c_void, host: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModemIsolation", "AddAllowedHost", 6usize, |this| ((**(this as *const *const IMobileBroadbandModemIsolation_Vtbl)).AddAllowedHost)(this, host), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAllowedHostRange(this: *mut core::ffi::c_void, first: *mut core::ffi::c_void, last: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModemIsolation", "AddAllowedHostRange", 7usize, |this| ((**(this as *const *const IMobileBroadbandModemIsolation_Vtbl)).AddAllowedHostRange)(this, first, last), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyConfigurationAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModemIsolation", "ApplyConfigurationAsync", 8usize, |this| ((**(this as *const *const IMobileBroadbandModemIsolation_Vtbl)).ApplyConfigurationAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearConfigurationAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModemIsolation", "ClearConfigurationAsync", 9usize, |this| ((**(this as *const *const IMobileBroadbandModemIsolation_Vtbl)).ClearConfigurationAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddAllowedHost,
+            AddAllowedHostRange,
+            ApplyConfigurationAsync,
+            ClearConfigurationAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandModemIsolation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandModemIsolationFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, modemdeviceid: core::mem::MaybeUninit<windows_core::HSTRING>, rulegroupid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModemIsolationFactory", "Create", 6usize, |this| ((**(this as *const *const IMobileBroadbandModemIsolationFactory_Vtbl)).Create)(this, modemdeviceid, rulegroupid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandModemIsolationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandModemStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModemStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IMobileBroadbandModemStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromId(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModemStatics", "FromId", 7usize, |this| ((**(this as *const *const IMobileBroadbandModemStatics_Vtbl)).FromId)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandModemStatics", "GetDefault", 8usize, |this| ((**(this as *const *const IMobileBroadbandModemStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, FromId, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandModemStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking_Connectivity")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandNetwork_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Networking_Connectivity")]
+        unsafe extern "system" fn NetworkAdapter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetwork", "NetworkAdapter", 6usize, |this| ((**(this as *const *const IMobileBroadbandNetwork_Vtbl)).NetworkAdapter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkRegistrationState(this: *mut core::ffi::c_void, result__: *mut NetworkRegistrationState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetwork", "NetworkRegistrationState", 7usize, |this| ((**(this as *const *const IMobileBroadbandNetwork_Vtbl)).NetworkRegistrationState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegistrationNetworkError(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetwork", "RegistrationNetworkError", 8usize, |this| ((**(this as *const *const IMobileBroadbandNetwork_Vtbl)).RegistrationNetworkError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PacketAttachNetworkError(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetwork", "PacketAttachNetworkError", 9usize, |this| ((**(this as *const *const IMobileBroadbandNetwork_Vtbl)).PacketAttachNetworkError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivationNetworkError(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetwork", "ActivationNetworkError", 10usize, |this| ((**(this as *const *const IMobileBroadbandNetwork_Vtbl)).ActivationNetworkError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccessPointName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetwork", "AccessPointName", 11usize, |this| ((**(this as *const *const IMobileBroadbandNetwork_Vtbl)).AccessPointName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisteredDataClass(this: *mut core::ffi::c_void, result__: *mut DataClasses) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetwork", "RegisteredDataClass", 12usize, |this| ((**(this as *const *const IMobileBroadbandNetwork_Vtbl)).RegisteredDataClass)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisteredProviderId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetwork", "RegisteredProviderId", 13usize, |this| ((**(this as *const *const IMobileBroadbandNetwork_Vtbl)).RegisteredProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisteredProviderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetwork", "RegisteredProviderName", 14usize, |this| ((**(this as *const *const IMobileBroadbandNetwork_Vtbl)).RegisteredProviderName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowConnectionUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetwork", "ShowConnectionUI", 15usize, |this| ((**(this as *const *const IMobileBroadbandNetwork_Vtbl)).ShowConnectionUI)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Networking_Connectivity")]
+            NetworkAdapter,
+            #[cfg(not(feature = "Networking_Connectivity"))]
+            NetworkAdapter: 0,
+            NetworkRegistrationState,
+            RegistrationNetworkError,
+            PacketAttachNetworkError,
+            ActivationNetworkError,
+            AccessPointName,
+            RegisteredDataClass,
+            RegisteredProviderId,
+            RegisteredProviderName,
+            ShowConnectionUI,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandNetwork as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandNetwork2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVoiceCallSupportAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetwork2", "GetVoiceCallSupportAsync", 6usize, |this| ((**(this as *const *const IMobileBroadbandNetwork2_Vtbl)).GetVoiceCallSupportAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RegistrationUiccApps(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetwork2", "RegistrationUiccApps", 7usize, |this| ((**(this as *const *const IMobileBroadbandNetwork2_Vtbl)).RegistrationUiccApps)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetVoiceCallSupportAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            RegistrationUiccApps,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RegistrationUiccApps: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandNetwork2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandNetwork3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCellsInfoAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetwork3", "GetCellsInfoAsync", 6usize, |this| ((**(this as *const *const IMobileBroadbandNetwork3_Vtbl)).GetCellsInfoAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCellsInfoAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandNetwork3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandNetworkRegistrationStateChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetworkRegistrationStateChange", "DeviceId", 6usize, |this| ((**(this as *const *const IMobileBroadbandNetworkRegistrationStateChange_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Network(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetworkRegistrationStateChange", "Network", 7usize, |this| ((**(this as *const *const IMobileBroadbandNetworkRegistrationStateChange_Vtbl)).Network)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId, Network }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandNetworkRegistrationStateChange as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandNetworkRegistrationStateChangeTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn NetworkRegistrationStateChanges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandNetworkRegistrationStateChangeTriggerDetails", "NetworkRegistrationStateChanges", 6usize, |this| ((**(this as *const *const IMobileBroadbandNetworkRegistrationStateChangeTriggerDetails_Vtbl)).NetworkRegistrationStateChanges)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            NetworkRegistrationStateChanges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            NetworkRegistrationStateChanges: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandNetworkRegistrationStateChangeTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandPco_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPco", "Data", 6usize, |this| ((**(this as *const *const IMobileBroadbandPco_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsComplete(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPco", "IsComplete", 7usize, |this| ((**(this as *const *const IMobileBroadbandPco_Vtbl)).IsComplete)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPco", "DeviceId", 8usize, |this| ((**(this as *const *const IMobileBroadbandPco_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Data,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Data: 0,
+            IsComplete,
+            DeviceId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandPco as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandPcoDataChangeTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UpdatedData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPcoDataChangeTriggerDetails", "UpdatedData", 6usize, |this| ((**(this as *const *const IMobileBroadbandPcoDataChangeTriggerDetails_Vtbl)).UpdatedData)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UpdatedData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandPcoDataChangeTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandPin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut MobileBroadbandPinType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPin", "Type", 6usize, |this| ((**(this as *const *const IMobileBroadbandPin_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockState(this: *mut core::ffi::c_void, result__: *mut MobileBroadbandPinLockState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPin", "LockState", 7usize, |this| ((**(this as *const *const IMobileBroadbandPin_Vtbl)).LockState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, result__: *mut MobileBroadbandPinFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPin", "Format", 8usize, |this| ((**(this as *const *const IMobileBroadbandPin_Vtbl)).Format)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPin", "Enabled", 9usize, |this| ((**(this as *const *const IMobileBroadbandPin_Vtbl)).Enabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxLength(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPin", "MaxLength", 10usize, |this| ((**(this as *const *const IMobileBroadbandPin_Vtbl)).MaxLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinLength(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPin", "MinLength", 11usize, |this| ((**(this as *const *const IMobileBroadbandPin_Vtbl)).MinLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttemptsRemaining(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPin", "AttemptsRemaining", 12usize, |this| ((**(this as *const *const IMobileBroadbandPin_Vtbl)).AttemptsRemaining)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableAsync(this: *mut core::ffi::c_void, currentpin: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPin", "EnableAsync", 13usize, |this| ((**(this as *const *const IMobileBroadbandPin_Vtbl)).EnableAsync)(this, currentpin, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableAsync(this: *mut core::ffi::c_void, currentpin: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPin", "DisableAsync", 14usize, |this| ((**(this as *const *const IMobileBroadbandPin_Vtbl)).DisableAsync)(this, currentpin, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnterAsync(this: *mut core::ffi::c_void, currentpin: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPin", "EnterAsync", 15usize, |this| ((**(this as *const *const IMobileBroadbandPin_Vtbl)).EnterAsync)(this, currentpin, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeAsync(this: *mut core::ffi::c_void, currentpin: core::mem::MaybeUninit<windows_core::HSTRING>, newpin: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPin", "ChangeAsync", 16usize, |this| ((**(this as *const *const IMobileBroadbandPin_Vtbl)).ChangeAsync)(this, currentpin, newpin, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnblockAsync(this: *mut core::ffi::c_void, pinunblockkey: core::mem::MaybeUninit<windows_core::HSTRING>, newpin: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPin", "UnblockAsync", 17usize, |this| ((**(this as *const *const IMobileBroadbandPin_Vtbl)).UnblockAsync)(this, pinunblockkey, newpin, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            LockState,
+            Format,
+            Enabled,
+            MaxLength,
+            MinLength,
+            AttemptsRemaining,
+            EnableAsync,
+            DisableAsync,
+            EnterAsync,
+            ChangeAsync,
+            UnblockAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandPin as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandPinLockStateChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPinLockStateChange", "DeviceId", 6usize, |this| ((**(this as *const *const IMobileBroadbandPinLockStateChange_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PinType(this: *mut core::ffi::c_void, result__: *mut MobileBroadbandPinType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPinLockStateChange", "PinType", 7usize, |this| ((**(this as *const *const IMobileBroadbandPinLockStateChange_Vtbl)).PinType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PinLockState(this: *mut core::ffi::c_void, result__: *mut MobileBroadbandPinLockState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPinLockStateChange", "PinLockState", 8usize, |this| ((**(this as *const *const IMobileBroadbandPinLockStateChange_Vtbl)).PinLockState)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId, PinType, PinLockState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandPinLockStateChange as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandPinLockStateChangeTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PinLockStateChanges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPinLockStateChangeTriggerDetails", "PinLockStateChanges", 6usize, |this| ((**(this as *const *const IMobileBroadbandPinLockStateChangeTriggerDetails_Vtbl)).PinLockStateChanges)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            PinLockStateChanges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PinLockStateChanges: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandPinLockStateChangeTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandPinManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedPins(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPinManager", "SupportedPins", 6usize, |this| ((**(this as *const *const IMobileBroadbandPinManager_Vtbl)).SupportedPins)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPin(this: *mut core::ffi::c_void, pintype: MobileBroadbandPinType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPinManager", "GetPin", 7usize, |this| ((**(this as *const *const IMobileBroadbandPinManager_Vtbl)).GetPin)(this, pintype, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedPins,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedPins: 0,
+            GetPin,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandPinManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandPinOperationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSuccessful(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPinOperationResult", "IsSuccessful", 6usize, |this| ((**(this as *const *const IMobileBroadbandPinOperationResult_Vtbl)).IsSuccessful)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttemptsRemaining(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandPinOperationResult", "AttemptsRemaining", 7usize, |this| ((**(this as *const *const IMobileBroadbandPinOperationResult_Vtbl)).AttemptsRemaining)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSuccessful, AttemptsRemaining }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandPinOperationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandRadioStateChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandRadioStateChange", "DeviceId", 6usize, |this| ((**(this as *const *const IMobileBroadbandRadioStateChange_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RadioState(this: *mut core::ffi::c_void, result__: *mut MobileBroadbandRadioState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandRadioStateChange", "RadioState", 7usize, |this| ((**(this as *const *const IMobileBroadbandRadioStateChange_Vtbl)).RadioState)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId, RadioState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandRadioStateChange as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandRadioStateChangeTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RadioStateChanges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandRadioStateChangeTriggerDetails", "RadioStateChanges", 6usize, |this| ((**(this as *const *const IMobileBroadbandRadioStateChangeTriggerDetails_Vtbl)).RadioStateChanges)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            RadioStateChanges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RadioStateChanges: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandRadioStateChangeTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandSarManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsBackoffEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSarManager", "IsBackoffEnabled", 6usize, |this| ((**(this as *const *const IMobileBroadbandSarManager_Vtbl)).IsBackoffEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWiFiHardwareIntegrated(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSarManager", "IsWiFiHardwareIntegrated", 7usize, |this| ((**(this as *const *const IMobileBroadbandSarManager_Vtbl)).IsWiFiHardwareIntegrated)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSarControlledByHardware(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSarManager", "IsSarControlledByHardware", 8usize, |this| ((**(this as *const *const IMobileBroadbandSarManager_Vtbl)).IsSarControlledByHardware)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Antennas(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSarManager", "Antennas", 9usize, |this| ((**(this as *const *const IMobileBroadbandSarManager_Vtbl)).Antennas)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HysteresisTimerPeriod(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSarManager", "HysteresisTimerPeriod", 10usize, |this| ((**(this as *const *const IMobileBroadbandSarManager_Vtbl)).HysteresisTimerPeriod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransmissionStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSarManager", "TransmissionStateChanged", 11usize, |this| ((**(this as *const *const IMobileBroadbandSarManager_Vtbl)).TransmissionStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTransmissionStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSarManager", "RemoveTransmissionStateChanged", 12usize, |this| ((**(this as *const *const IMobileBroadbandSarManager_Vtbl)).RemoveTransmissionStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableBackoffAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSarManager", "EnableBackoffAsync", 13usize, |this| ((**(this as *const *const IMobileBroadbandSarManager_Vtbl)).EnableBackoffAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableBackoffAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSarManager", "DisableBackoffAsync", 14usize, |this| ((**(this as *const *const IMobileBroadbandSarManager_Vtbl)).DisableBackoffAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetConfigurationAsync(this: *mut core::ffi::c_void, antennas: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSarManager", "SetConfigurationAsync", 15usize, |this| ((**(this as *const *const IMobileBroadbandSarManager_Vtbl)).SetConfigurationAsync)(this, antennas, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevertSarToHardwareControlAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSarManager", "RevertSarToHardwareControlAsync", 16usize, |this| ((**(this as *const *const IMobileBroadbandSarManager_Vtbl)).RevertSarToHardwareControlAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransmissionStateChangedHysteresisAsync(this: *mut core::ffi::c_void, timerperiod: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSarManager", "SetTransmissionStateChangedHysteresisAsync", 17usize, |this| ((**(this as *const *const IMobileBroadbandSarManager_Vtbl)).SetTransmissionStateChangedHysteresisAsync)(this, timerperiod, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsTransmittingAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSarManager", "GetIsTransmittingAsync", 18usize, |this| ((**(this as *const *const IMobileBroadbandSarManager_Vtbl)).GetIsTransmittingAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTransmissionStateMonitoring(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSarManager", "StartTransmissionStateMonitoring", 19usize, |this| ((**(this as *const *const IMobileBroadbandSarManager_Vtbl)).StartTransmissionStateMonitoring)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopTransmissionStateMonitoring(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSarManager", "StopTransmissionStateMonitoring", 20usize, |this| ((**(this as *const *const IMobileBroadbandSarManager_Vtbl)).StopTransmissionStateMonitoring)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsBackoffEnabled,
+            IsWiFiHardwareIntegrated,
+            IsSarControlledByHardware,
+            #[cfg(feature = "Foundation_Collections")]
+            Antennas,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Antennas: 0,
+            HysteresisTimerPeriod,
+            TransmissionStateChanged,
+            RemoveTransmissionStateChanged,
+            EnableBackoffAsync,
+            DisableBackoffAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            SetConfigurationAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetConfigurationAsync: 0,
+            RevertSarToHardwareControlAsync,
+            SetTransmissionStateChangedHysteresisAsync,
+            GetIsTransmittingAsync,
+            StartTransmissionStateMonitoring,
+            StopTransmissionStateMonitoring,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandSarManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandSlotInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Index(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSlotInfo", "Index", 6usize, |this| ((**(this as *const *const IMobileBroadbandSlotInfo_Vtbl)).Index)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut MobileBroadbandSlotState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSlotInfo", "State", 7usize, |this| ((**(this as *const *const IMobileBroadbandSlotInfo_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Index, State }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandSlotInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandSlotInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IccId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSlotInfo2", "IccId", 6usize, |this| ((**(this as *const *const IMobileBroadbandSlotInfo2_Vtbl)).IccId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IccId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandSlotInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandSlotInfoChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SlotInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSlotInfoChangedEventArgs", "SlotInfo", 6usize, |this| ((**(this as *const *const IMobileBroadbandSlotInfoChangedEventArgs_Vtbl)).SlotInfo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SlotInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandSlotInfoChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandSlotManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SlotInfos(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSlotManager", "SlotInfos", 6usize, |this| ((**(this as *const *const IMobileBroadbandSlotManager_Vtbl)).SlotInfos)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentSlotIndex(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSlotManager", "CurrentSlotIndex", 7usize, |this| ((**(this as *const *const IMobileBroadbandSlotManager_Vtbl)).CurrentSlotIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentSlot(this: *mut core::ffi::c_void, slotindex: i32, result__: *mut MobileBroadbandModemStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSlotManager", "SetCurrentSlot", 8usize, |this| ((**(this as *const *const IMobileBroadbandSlotManager_Vtbl)).SetCurrentSlot)(this, slotindex, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentSlotAsync(this: *mut core::ffi::c_void, slotindex: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSlotManager", "SetCurrentSlotAsync", 9usize, |this| ((**(this as *const *const IMobileBroadbandSlotManager_Vtbl)).SetCurrentSlotAsync)(this, slotindex, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SlotInfoChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSlotManager", "SlotInfoChanged", 10usize, |this| ((**(this as *const *const IMobileBroadbandSlotManager_Vtbl)).SlotInfoChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSlotInfoChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSlotManager", "RemoveSlotInfoChanged", 11usize, |this| ((**(this as *const *const IMobileBroadbandSlotManager_Vtbl)).RemoveSlotInfoChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentSlotIndexChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSlotManager", "CurrentSlotIndexChanged", 12usize, |this| ((**(this as *const *const IMobileBroadbandSlotManager_Vtbl)).CurrentSlotIndexChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCurrentSlotIndexChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandSlotManager", "RemoveCurrentSlotIndexChanged", 13usize, |this| ((**(this as *const *const IMobileBroadbandSlotManager_Vtbl)).RemoveCurrentSlotIndexChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SlotInfos,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SlotInfos: 0,
+            CurrentSlotIndex,
+            SetCurrentSlot,
+            SetCurrentSlotAsync,
+            SlotInfoChanged,
+            RemoveSlotInfoChanged,
+            CurrentSlotIndexChanged,
+            RemoveCurrentSlotIndexChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandSlotManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandTransmissionStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTransmitting(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandTransmissionStateChangedEventArgs", "IsTransmitting", 6usize, |this| ((**(this as *const *const IMobileBroadbandTransmissionStateChangedEventArgs_Vtbl)).IsTransmitting)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsTransmitting }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandTransmissionStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandUicc_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SimIccId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUicc", "SimIccId", 6usize, |this| ((**(this as *const *const IMobileBroadbandUicc_Vtbl)).SimIccId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUiccAppsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUicc", "GetUiccAppsAsync", 7usize, |this| ((**(this as *const *const IMobileBroadbandUicc_Vtbl)).GetUiccAppsAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SimIccId, GetUiccAppsAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandUicc as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandUiccApp_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUiccApp", "Id", 6usize, |this| ((**(this as *const *const IMobileBroadbandUiccApp_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut UiccAppKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUiccApp", "Kind", 7usize, |this| ((**(this as *const *const IMobileBroadbandUiccApp_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetRecordDetailsAsync(this: *mut core::ffi::c_void, uiccfilepath: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUiccApp", "GetRecordDetailsAsync", 8usize, |this| ((**(this as *const *const IMobileBroadbandUiccApp_Vtbl)).GetRecordDetailsAsync)(this, uiccfilepath, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadRecordAsync(this: *mut core::ffi::c_void, uiccfilepath: *mut core::ffi::c_void, recordindex: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUiccApp", "ReadRecordAsync", 9usize, |this| ((**(this as *const *const IMobileBroadbandUiccApp_Vtbl)).ReadRecordAsync)(this, uiccfilepath, recordindex, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Id,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Id: 0,
+            Kind,
+            #[cfg(feature = "Foundation_Collections")]
+            GetRecordDetailsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetRecordDetailsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadRecordAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadRecordAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandUiccApp as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandUiccAppReadRecordResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut MobileBroadbandUiccAppOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUiccAppReadRecordResult", "Status", 6usize, |this| ((**(this as *const *const IMobileBroadbandUiccAppReadRecordResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUiccAppReadRecordResult", "Data", 7usize, |this| ((**(this as *const *const IMobileBroadbandUiccAppReadRecordResult_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Storage_Streams")]
+            Data,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Data: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandUiccAppReadRecordResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMobileBroadbandUiccAppRecordDetailsResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut MobileBroadbandUiccAppOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUiccAppRecordDetailsResult", "Status", 6usize, |this| ((**(this as *const *const IMobileBroadbandUiccAppRecordDetailsResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut UiccAppRecordKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUiccAppRecordDetailsResult", "Kind", 7usize, |this| ((**(this as *const *const IMobileBroadbandUiccAppRecordDetailsResult_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUiccAppRecordDetailsResult", "RecordCount", 8usize, |this| ((**(this as *const *const IMobileBroadbandUiccAppRecordDetailsResult_Vtbl)).RecordCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordSize(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUiccAppRecordDetailsResult", "RecordSize", 9usize, |this| ((**(this as *const *const IMobileBroadbandUiccAppRecordDetailsResult_Vtbl)).RecordSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadAccessCondition(this: *mut core::ffi::c_void, result__: *mut UiccAccessCondition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUiccAppRecordDetailsResult", "ReadAccessCondition", 10usize, |this| ((**(this as *const *const IMobileBroadbandUiccAppRecordDetailsResult_Vtbl)).ReadAccessCondition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteAccessCondition(this: *mut core::ffi::c_void, result__: *mut UiccAccessCondition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUiccAppRecordDetailsResult", "WriteAccessCondition", 11usize, |this| ((**(this as *const *const IMobileBroadbandUiccAppRecordDetailsResult_Vtbl)).WriteAccessCondition)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            Kind,
+            RecordCount,
+            RecordSize,
+            ReadAccessCondition,
+            WriteAccessCondition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandUiccAppRecordDetailsResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMobileBroadbandUiccAppsResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut MobileBroadbandUiccAppOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUiccAppsResult", "Status", 6usize, |this| ((**(this as *const *const IMobileBroadbandUiccAppsResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UiccApps(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMobileBroadbandUiccAppsResult", "UiccApps", 7usize, |this| ((**(this as *const *const IMobileBroadbandUiccAppsResult_Vtbl)).UiccApps)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Foundation_Collections")]
+            UiccApps,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UiccApps: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMobileBroadbandUiccAppsResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkOperatorDataUsageTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotificationKind(this: *mut core::ffi::c_void, result__: *mut NetworkOperatorDataUsageNotificationKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorDataUsageTriggerDetails", "NotificationKind", 6usize, |this| ((**(this as *const *const INetworkOperatorDataUsageTriggerDetails_Vtbl)).NotificationKind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NotificationKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorDataUsageTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Sms")]
+impl windows_core::imp::ProxyVtable for INetworkOperatorNotificationEventDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotificationType(this: *mut core::ffi::c_void, result__: *mut NetworkOperatorEventMessageType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorNotificationEventDetails", "NotificationType", 6usize, |this| ((**(this as *const *const INetworkOperatorNotificationEventDetails_Vtbl)).NotificationType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorNotificationEventDetails", "NetworkAccountId", 7usize, |this| ((**(this as *const *const INetworkOperatorNotificationEventDetails_Vtbl)).NetworkAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncodingType(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorNotificationEventDetails", "EncodingType", 8usize, |this| ((**(this as *const *const INetworkOperatorNotificationEventDetails_Vtbl)).EncodingType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorNotificationEventDetails", "Message", 9usize, |this| ((**(this as *const *const INetworkOperatorNotificationEventDetails_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RuleId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorNotificationEventDetails", "RuleId", 10usize, |this| ((**(this as *const *const INetworkOperatorNotificationEventDetails_Vtbl)).RuleId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Sms")]
+        unsafe extern "system" fn SmsMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorNotificationEventDetails", "SmsMessage", 11usize, |this| ((**(this as *const *const INetworkOperatorNotificationEventDetails_Vtbl)).SmsMessage)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NotificationType,
+            NetworkAccountId,
+            EncodingType,
+            Message,
+            RuleId,
+            #[cfg(feature = "Devices_Sms")]
+            SmsMessage,
+            #[cfg(not(feature = "Devices_Sms"))]
+            SmsMessage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorNotificationEventDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkOperatorTetheringAccessPointConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Ssid(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringAccessPointConfiguration", "Ssid", 6usize, |this| ((**(this as *const *const INetworkOperatorTetheringAccessPointConfiguration_Vtbl)).Ssid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSsid(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringAccessPointConfiguration", "SetSsid", 7usize, |this| ((**(this as *const *const INetworkOperatorTetheringAccessPointConfiguration_Vtbl)).SetSsid)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Passphrase(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringAccessPointConfiguration", "Passphrase", 8usize, |this| ((**(this as *const *const INetworkOperatorTetheringAccessPointConfiguration_Vtbl)).Passphrase)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPassphrase(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringAccessPointConfiguration", "SetPassphrase", 9usize, |this| ((**(this as *const *const INetworkOperatorTetheringAccessPointConfiguration_Vtbl)).SetPassphrase)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Ssid, SetSsid, Passphrase, SetPassphrase }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorTetheringAccessPointConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkOperatorTetheringAccessPointConfiguration2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsBandSupported(this: *mut core::ffi::c_void, band: TetheringWiFiBand, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringAccessPointConfiguration2", "IsBandSupported", 6usize, |this| ((**(this as *const *const INetworkOperatorTetheringAccessPointConfiguration2_Vtbl)).IsBandSupported)(this, band, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBandSupportedAsync(this: *mut core::ffi::c_void, band: TetheringWiFiBand, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringAccessPointConfiguration2", "IsBandSupportedAsync", 7usize, |this| ((**(this as *const *const INetworkOperatorTetheringAccessPointConfiguration2_Vtbl)).IsBandSupportedAsync)(this, band, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Band(this: *mut core::ffi::c_void, result__: *mut TetheringWiFiBand) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringAccessPointConfiguration2", "Band", 8usize, |this| ((**(this as *const *const INetworkOperatorTetheringAccessPointConfiguration2_Vtbl)).Band)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBand(this: *mut core::ffi::c_void, value: TetheringWiFiBand) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringAccessPointConfiguration2", "SetBand", 9usize, |this| ((**(this as *const *const INetworkOperatorTetheringAccessPointConfiguration2_Vtbl)).SetBand)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsBandSupported, IsBandSupportedAsync, Band, SetBand }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorTetheringAccessPointConfiguration2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkOperatorTetheringAccessPointConfiguration3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAuthenticationKindSupported(this: *mut core::ffi::c_void, authenticationkind: TetheringWiFiAuthenticationKind, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringAccessPointConfiguration3", "IsAuthenticationKindSupported", 6usize, |this| ((**(this as *const *const INetworkOperatorTetheringAccessPointConfiguration3_Vtbl)).IsAuthenticationKindSupported)(this, authenticationkind, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAuthenticationKindSupportedAsync(this: *mut core::ffi::c_void, authenticationkind: TetheringWiFiAuthenticationKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringAccessPointConfiguration3", "IsAuthenticationKindSupportedAsync", 7usize, |this| ((**(this as *const *const INetworkOperatorTetheringAccessPointConfiguration3_Vtbl)).IsAuthenticationKindSupportedAsync)(this, authenticationkind, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationKind(this: *mut core::ffi::c_void, result__: *mut TetheringWiFiAuthenticationKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringAccessPointConfiguration3", "AuthenticationKind", 8usize, |this| ((**(this as *const *const INetworkOperatorTetheringAccessPointConfiguration3_Vtbl)).AuthenticationKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationKind(this: *mut core::ffi::c_void, value: TetheringWiFiAuthenticationKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringAccessPointConfiguration3", "SetAuthenticationKind", 9usize, |this| ((**(this as *const *const INetworkOperatorTetheringAccessPointConfiguration3_Vtbl)).SetAuthenticationKind)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsAuthenticationKindSupported,
+            IsAuthenticationKindSupportedAsync,
+            AuthenticationKind,
+            SetAuthenticationKind,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorTetheringAccessPointConfiguration3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for INetworkOperatorTetheringClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MacAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringClient", "MacAddress", 6usize, |this| ((**(this as *const *const INetworkOperatorTetheringClient_Vtbl)).MacAddress)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn HostNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringClient", "HostNames", 7usize, |this| ((**(this as *const *const INetworkOperatorTetheringClient_Vtbl)).HostNames)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MacAddress,
+            #[cfg(feature = "Foundation_Collections")]
+            HostNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            HostNames: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorTetheringClient as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for INetworkOperatorTetheringClientManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetTetheringClients(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringClientManager", "GetTetheringClients", 6usize, |this| ((**(this as *const *const INetworkOperatorTetheringClientManager_Vtbl)).GetTetheringClients)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetTetheringClients,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetTetheringClients: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorTetheringClientManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkOperatorTetheringEntitlementCheck_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AuthorizeTethering(this: *mut core::ffi::c_void, allow: bool, entitlementfailurereason: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringEntitlementCheck", "AuthorizeTethering", 6usize, |this| ((**(this as *const *const INetworkOperatorTetheringEntitlementCheck_Vtbl)).AuthorizeTethering)(this, allow, entitlementfailurereason), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AuthorizeTethering }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorTetheringEntitlementCheck as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkOperatorTetheringManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxClientCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManager", "MaxClientCount", 6usize, |this| ((**(this as *const *const INetworkOperatorTetheringManager_Vtbl)).MaxClientCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManager", "ClientCount", 7usize, |this| ((**(this as *const *const INetworkOperatorTetheringManager_Vtbl)).ClientCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TetheringOperationalState(this: *mut core::ffi::c_void, result__: *mut TetheringOperationalState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManager", "TetheringOperationalState", 8usize, |this| ((**(this as *const *const INetworkOperatorTetheringManager_Vtbl)).TetheringOperationalState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentAccessPointConfiguration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManager", "GetCurrentAccessPointConfiguration", 9usize, |this| ((**(this as *const *const INetworkOperatorTetheringManager_Vtbl)).GetCurrentAccessPointConfiguration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConfigureAccessPointAsync(this: *mut core::ffi::c_void, configuration: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManager", "ConfigureAccessPointAsync", 10usize, |this| ((**(this as *const *const INetworkOperatorTetheringManager_Vtbl)).ConfigureAccessPointAsync)(this, configuration, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTetheringAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManager", "StartTetheringAsync", 11usize, |this| ((**(this as *const *const INetworkOperatorTetheringManager_Vtbl)).StartTetheringAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopTetheringAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManager", "StopTetheringAsync", 12usize, |this| ((**(this as *const *const INetworkOperatorTetheringManager_Vtbl)).StopTetheringAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaxClientCount,
+            ClientCount,
+            TetheringOperationalState,
+            GetCurrentAccessPointConfiguration,
+            ConfigureAccessPointAsync,
+            StartTetheringAsync,
+            StopTetheringAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorTetheringManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkOperatorTetheringManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartTetheringAsync(this: *mut core::ffi::c_void, configuration: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManager2", "StartTetheringAsync", 6usize, |this| ((**(this as *const *const INetworkOperatorTetheringManager2_Vtbl)).StartTetheringAsync)(this, configuration, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartTetheringAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorTetheringManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkOperatorTetheringManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTetheringCapability(this: *mut core::ffi::c_void, networkaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut TetheringCapability) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManagerStatics", "GetTetheringCapability", 6usize, |this| ((**(this as *const *const INetworkOperatorTetheringManagerStatics_Vtbl)).GetTetheringCapability)(this, networkaccountid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromNetworkAccountId(this: *mut core::ffi::c_void, networkaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManagerStatics", "CreateFromNetworkAccountId", 7usize, |this| ((**(this as *const *const INetworkOperatorTetheringManagerStatics_Vtbl)).CreateFromNetworkAccountId)(this, networkaccountid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTetheringCapability, CreateFromNetworkAccountId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorTetheringManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking_Connectivity")]
+impl windows_core::imp::ProxyVtable for INetworkOperatorTetheringManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Networking_Connectivity")]
+        unsafe extern "system" fn GetTetheringCapabilityFromConnectionProfile(this: *mut core::ffi::c_void, profile: *mut core::ffi::c_void, result__: *mut TetheringCapability) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManagerStatics2", "GetTetheringCapabilityFromConnectionProfile", 6usize, |this| ((**(this as *const *const INetworkOperatorTetheringManagerStatics2_Vtbl)).GetTetheringCapabilityFromConnectionProfile)(this, profile, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Connectivity")]
+        unsafe extern "system" fn CreateFromConnectionProfile(this: *mut core::ffi::c_void, profile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManagerStatics2", "CreateFromConnectionProfile", 7usize, |this| ((**(this as *const *const INetworkOperatorTetheringManagerStatics2_Vtbl)).CreateFromConnectionProfile)(this, profile, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Networking_Connectivity")]
+            GetTetheringCapabilityFromConnectionProfile,
+            #[cfg(not(feature = "Networking_Connectivity"))]
+            GetTetheringCapabilityFromConnectionProfile: 0,
+            #[cfg(feature = "Networking_Connectivity")]
+            CreateFromConnectionProfile,
+            #[cfg(not(feature = "Networking_Connectivity"))]
+            CreateFromConnectionProfile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorTetheringManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking_Connectivity")]
+impl windows_core::imp::ProxyVtable for INetworkOperatorTetheringManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Networking_Connectivity")]
+        unsafe extern "system" fn CreateFromConnectionProfileWithTargetAdapter(this: *mut core::ffi::c_void, profile: *mut core::ffi::c_void, adapter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManagerStatics3", "CreateFromConnectionProfileWithTargetAdapter", 6usize, |this| ((**(this as *const *const INetworkOperatorTetheringManagerStatics3_Vtbl)).CreateFromConnectionProfileWithTargetAdapter)(this, profile, adapter, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Networking_Connectivity")]
+            CreateFromConnectionProfileWithTargetAdapter,
+            #[cfg(not(feature = "Networking_Connectivity"))]
+            CreateFromConnectionProfileWithTargetAdapter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorTetheringManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkOperatorTetheringManagerStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsNoConnectionsTimeoutEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManagerStatics4", "IsNoConnectionsTimeoutEnabled", 6usize, |this| ((**(this as *const *const INetworkOperatorTetheringManagerStatics4_Vtbl)).IsNoConnectionsTimeoutEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableNoConnectionsTimeout(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManagerStatics4", "EnableNoConnectionsTimeout", 7usize, |this| ((**(this as *const *const INetworkOperatorTetheringManagerStatics4_Vtbl)).EnableNoConnectionsTimeout)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableNoConnectionsTimeoutAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManagerStatics4", "EnableNoConnectionsTimeoutAsync", 8usize, |this| ((**(this as *const *const INetworkOperatorTetheringManagerStatics4_Vtbl)).EnableNoConnectionsTimeoutAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableNoConnectionsTimeout(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManagerStatics4", "DisableNoConnectionsTimeout", 9usize, |this| ((**(this as *const *const INetworkOperatorTetheringManagerStatics4_Vtbl)).DisableNoConnectionsTimeout)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableNoConnectionsTimeoutAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringManagerStatics4", "DisableNoConnectionsTimeoutAsync", 10usize, |this| ((**(this as *const *const INetworkOperatorTetheringManagerStatics4_Vtbl)).DisableNoConnectionsTimeoutAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsNoConnectionsTimeoutEnabled,
+            EnableNoConnectionsTimeout,
+            EnableNoConnectionsTimeoutAsync,
+            DisableNoConnectionsTimeout,
+            DisableNoConnectionsTimeoutAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorTetheringManagerStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkOperatorTetheringOperationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut TetheringOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringOperationResult", "Status", 6usize, |this| ((**(this as *const *const INetworkOperatorTetheringOperationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdditionalErrorMessage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringOperationResult", "AdditionalErrorMessage", 7usize, |this| ((**(this as *const *const INetworkOperatorTetheringOperationResult_Vtbl)).AdditionalErrorMessage)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, AdditionalErrorMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorTetheringOperationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkOperatorTetheringSessionAccessPointConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Ssid(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringSessionAccessPointConfiguration", "Ssid", 6usize, |this| ((**(this as *const *const INetworkOperatorTetheringSessionAccessPointConfiguration_Vtbl)).Ssid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSsid(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringSessionAccessPointConfiguration", "SetSsid", 7usize, |this| ((**(this as *const *const INetworkOperatorTetheringSessionAccessPointConfiguration_Vtbl)).SetSsid)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Passphrase(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringSessionAccessPointConfiguration", "Passphrase", 8usize, |this| ((**(this as *const *const INetworkOperatorTetheringSessionAccessPointConfiguration_Vtbl)).Passphrase)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPassphrase(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringSessionAccessPointConfiguration", "SetPassphrase", 9usize, |this| ((**(this as *const *const INetworkOperatorTetheringSessionAccessPointConfiguration_Vtbl)).SetPassphrase)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBandSupported(this: *mut core::ffi::c_void, band: TetheringWiFiBand, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringSessionAccessPointConfiguration", "IsBandSupported", 10usize, |this| ((**(this as *const *const INetworkOperatorTetheringSessionAccessPointConfiguration_Vtbl)).IsBandSupported)(this, band, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBandSupportedAsync(this: *mut core::ffi::c_void, band: TetheringWiFiBand, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringSessionAccessPointConfiguration", "IsBandSupportedAsync", 11usize, |this| ((**(this as *const *const INetworkOperatorTetheringSessionAccessPointConfiguration_Vtbl)).IsBandSupportedAsync)(this, band, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Band(this: *mut core::ffi::c_void, result__: *mut TetheringWiFiBand) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringSessionAccessPointConfiguration", "Band", 12usize, |this| ((**(this as *const *const INetworkOperatorTetheringSessionAccessPointConfiguration_Vtbl)).Band)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBand(this: *mut core::ffi::c_void, value: TetheringWiFiBand) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringSessionAccessPointConfiguration", "SetBand", 13usize, |this| ((**(this as *const *const INetworkOperatorTetheringSessionAccessPointConfiguration_Vtbl)).SetBand)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAuthenticationKindSupported(this: *mut core::ffi::c_void, authenticationkind: TetheringWiFiAuthenticationKind, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringSessionAccessPointConfiguration", "IsAuthenticationKindSupported", 14usize, |this| ((**(this as *const *const INetworkOperatorTetheringSessionAccessPointConfiguration_Vtbl)).IsAuthenticationKindSupported)(this, authenticationkind, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAuthenticationKindSupportedAsync(this: *mut core::ffi::c_void, authenticationkind: TetheringWiFiAuthenticationKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringSessionAccessPointConfiguration", "IsAuthenticationKindSupportedAsync", 15usize, |this| ((**(this as *const *const INetworkOperatorTetheringSessionAccessPointConfiguration_Vtbl)).IsAuthenticationKindSupportedAsync)(this, authenticationkind, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationKind(this: *mut core::ffi::c_void, result__: *mut TetheringWiFiAuthenticationKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringSessionAccessPointConfiguration", "AuthenticationKind", 16usize, |this| ((**(this as *const *const INetworkOperatorTetheringSessionAccessPointConfiguration_Vtbl)).AuthenticationKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationKind(this: *mut core::ffi::c_void, value: TetheringWiFiAuthenticationKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringSessionAccessPointConfiguration", "SetAuthenticationKind", 17usize, |this| ((**(this as *const *const INetworkOperatorTetheringSessionAccessPointConfiguration_Vtbl)).SetAuthenticationKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PerformancePriority(this: *mut core::ffi::c_void, result__: *mut TetheringWiFiPerformancePriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringSessionAccessPointConfiguration", "PerformancePriority", 18usize, |this| ((**(this as *const *const INetworkOperatorTetheringSessionAccessPointConfiguration_Vtbl)).PerformancePriority)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPerformancePriority(this: *mut core::ffi::c_void, value: TetheringWiFiPerformancePriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkOperatorTetheringSessionAccessPointConfiguration", "SetPerformancePriority", 19usize, |this| ((**(this as *const *const INetworkOperatorTetheringSessionAccessPointConfiguration_Vtbl)).SetPerformancePriority)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Ssid,
+            SetSsid,
+            Passphrase,
+            SetPassphrase,
+            IsBandSupported,
+            IsBandSupportedAsync,
+            Band,
+            SetBand,
+            IsAuthenticationKindSupported,
+            IsAuthenticationKindSupportedAsync,
+            AuthenticationKind,
+            SetAuthenticationKind,
+            PerformancePriority,
+            SetPerformancePriority,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkOperatorTetheringSessionAccessPointConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProvisionFromXmlDocumentResults_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllElementsProvisioned(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvisionFromXmlDocumentResults", "AllElementsProvisioned", 6usize, |this| ((**(this as *const *const IProvisionFromXmlDocumentResults_Vtbl)).AllElementsProvisioned)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProvisionResultsXml(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvisionFromXmlDocumentResults", "ProvisionResultsXml", 7usize, |this| ((**(this as *const *const IProvisionFromXmlDocumentResults_Vtbl)).ProvisionResultsXml)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AllElementsProvisioned, ProvisionResultsXml }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvisionFromXmlDocumentResults as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking_Connectivity")]
+impl windows_core::imp::ProxyVtable for IProvisionedProfile_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Networking_Connectivity")]
+        unsafe extern "system" fn UpdateCost(this: *mut core::ffi::c_void, value: super::Connectivity::NetworkCostType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvisionedProfile", "UpdateCost", 6usize, |this| ((**(this as *const *const IProvisionedProfile_Vtbl)).UpdateCost)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateUsage(this: *mut core::ffi::c_void, value: ProfileUsage) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvisionedProfile", "UpdateUsage", 7usize, |this| ((**(this as *const *const IProvisionedProfile_Vtbl)).UpdateUsage)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Networking_Connectivity")]
+            UpdateCost,
+            #[cfg(not(feature = "Networking_Connectivity"))]
+            UpdateCost: 0,
+            UpdateUsage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvisionedProfile as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProvisioningAgent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProvisionFromXmlDocumentAsync(this: *mut core::ffi::c_void, provisioningxmldocument: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvisioningAgent", "ProvisionFromXmlDocumentAsync", 6usize, |this| ((**(this as *const *const IProvisioningAgent_Vtbl)).ProvisionFromXmlDocumentAsync)(this, provisioningxmldocument, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProvisionedProfile(this: *mut core::ffi::c_void, mediatype: ProfileMediaType, profilename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvisioningAgent", "GetProvisionedProfile", 7usize, |this| ((**(this as *const *const IProvisioningAgent_Vtbl)).GetProvisionedProfile)(this, mediatype, profilename, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProvisionFromXmlDocumentAsync, GetProvisionedProfile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvisioningAgent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProvisioningAgentStaticMethods_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromNetworkAccountId(this: *mut core::ffi::c_void, networkaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvisioningAgentStaticMethods", "CreateFromNetworkAccountId", 6usize, |this| ((**(this as *const *const IProvisioningAgentStaticMethods_Vtbl)).CreateFromNetworkAccountId)(this, networkaccountid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromNetworkAccountId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvisioningAgentStaticMethods as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITetheringEntitlementCheckTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NetworkAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITetheringEntitlementCheckTriggerDetails", "NetworkAccountId", 6usize, |this| ((**(this as *const *const ITetheringEntitlementCheckTriggerDetails_Vtbl)).NetworkAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowTethering(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITetheringEntitlementCheckTriggerDetails", "AllowTethering", 7usize, |this| ((**(this as *const *const ITetheringEntitlementCheckTriggerDetails_Vtbl)).AllowTethering)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DenyTethering(this: *mut core::ffi::c_void, entitlementfailurereason: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITetheringEntitlementCheckTriggerDetails", "DenyTethering", 8usize, |this| ((**(this as *const *const ITetheringEntitlementCheckTriggerDetails_Vtbl)).DenyTethering)(this, entitlementfailurereason), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NetworkAccountId, AllowTethering, DenyTethering }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITetheringEntitlementCheckTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUssdMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DataCodingScheme(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUssdMessage", "DataCodingScheme", 6usize, |this| ((**(this as *const *const IUssdMessage_Vtbl)).DataCodingScheme)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataCodingScheme(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUssdMessage", "SetDataCodingScheme", 7usize, |this| ((**(this as *const *const IUssdMessage_Vtbl)).SetDataCodingScheme)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPayload(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUssdMessage", "GetPayload", 8usize, |this| ((**(this as *const *const IUssdMessage_Vtbl)).GetPayload)(this, result_size__, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPayload(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUssdMessage", "SetPayload", 9usize, |this| ((**(this as *const *const IUssdMessage_Vtbl)).SetPayload)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PayloadAsText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUssdMessage", "PayloadAsText", 10usize, |this| ((**(this as *const *const IUssdMessage_Vtbl)).PayloadAsText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPayloadAsText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUssdMessage", "SetPayloadAsText", 11usize, |this| ((**(this as *const *const IUssdMessage_Vtbl)).SetPayloadAsText)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DataCodingScheme,
+            SetDataCodingScheme,
+            GetPayload,
+            SetPayload,
+            PayloadAsText,
+            SetPayloadAsText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUssdMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUssdMessageFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMessage(this: *mut core::ffi::c_void, messagetext: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUssdMessageFactory", "CreateMessage", 6usize, |this| ((**(this as *const *const IUssdMessageFactory_Vtbl)).CreateMessage)(this, messagetext, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUssdMessageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUssdReply_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResultCode(this: *mut core::ffi::c_void, result__: *mut UssdResultCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUssdReply", "ResultCode", 6usize, |this| ((**(this as *const *const IUssdReply_Vtbl)).ResultCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUssdReply", "Message", 7usize, |this| ((**(this as *const *const IUssdReply_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ResultCode, Message }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUssdReply as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUssdSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendMessageAndGetReplyAsync(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUssdSession", "SendMessageAndGetReplyAsync", 6usize, |this| ((**(this as *const *const IUssdSession_Vtbl)).SendMessageAndGetReplyAsync)(this, message, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUssdSession", "Close", 7usize, |this| ((**(this as *const *const IUssdSession_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SendMessageAndGetReplyAsync, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUssdSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUssdSessionStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromNetworkAccountId(this: *mut core::ffi::c_void, networkaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUssdSessionStatics", "CreateFromNetworkAccountId", 6usize, |this| ((**(this as *const *const IUssdSessionStatics_Vtbl)).CreateFromNetworkAccountId)(this, networkaccountid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromNetworkInterfaceId(this: *mut core::ffi::c_void, networkinterfaceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUssdSessionStatics", "CreateFromNetworkInterfaceId", 7usize, |this| ((**(this as *const *const IUssdSessionStatics_Vtbl)).CreateFromNetworkInterfaceId)(this, networkinterfaceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromNetworkAccountId, CreateFromNetworkInterfaceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUssdSessionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
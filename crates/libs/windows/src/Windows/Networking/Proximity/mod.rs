@@ -778,6 +778,7 @@ impl PeerDiscoveryTypes {
 impl windows_core::TypeKind for PeerDiscoveryTypes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PeerDiscoveryTypes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PeerDiscoveryTypes").field(&self.0).finish()
@@ -830,6 +831,7 @@ impl PeerRole {
 impl windows_core::TypeKind for PeerRole {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PeerRole {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PeerRole").field(&self.0).finish()
@@ -852,6 +854,7 @@ impl PeerWatcherStatus {
 impl windows_core::TypeKind for PeerWatcherStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PeerWatcherStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PeerWatcherStatus").field(&self.0).finish()
@@ -874,6 +877,7 @@ impl TriggeredConnectState {
 impl windows_core::TypeKind for TriggeredConnectState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TriggeredConnectState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TriggeredConnectState").field(&self.0).finish()
@@ -1123,3 +1127,5 @@ pub struct MessageTransmittedHandler_Vtbl {
     pub base__: windows_core::IUnknown_Vtbl,
     pub Invoke: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, i64) -> windows_core::HRESULT,
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
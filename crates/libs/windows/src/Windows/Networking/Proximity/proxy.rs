@@ -0,0 +1,428 @@
+impl windows_core::imp::ProxyVtable for IConnectionRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PeerInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionRequestedEventArgs", "PeerInformation", 6usize, |this| ((**(this as *const *const IConnectionRequestedEventArgs_Vtbl)).PeerInformation)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PeerInformation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Networking_Sockets"))]
+impl windows_core::imp::ProxyVtable for IPeerFinderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowBluetooth(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "AllowBluetooth", 6usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).AllowBluetooth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowBluetooth(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "SetAllowBluetooth", 7usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).SetAllowBluetooth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowInfrastructure(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "AllowInfrastructure", 8usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).AllowInfrastructure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowInfrastructure(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "SetAllowInfrastructure", 9usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).SetAllowInfrastructure)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowWiFiDirect(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "AllowWiFiDirect", 10usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).AllowWiFiDirect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowWiFiDirect(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "SetAllowWiFiDirect", 11usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).SetAllowWiFiDirect)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "DisplayName", 12usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "SetDisplayName", 13usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedDiscoveryTypes(this: *mut core::ffi::c_void, result__: *mut PeerDiscoveryTypes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "SupportedDiscoveryTypes", 14usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).SupportedDiscoveryTypes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AlternateIdentities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "AlternateIdentities", 15usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).AlternateIdentities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "Start", 16usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartWithMessage(this: *mut core::ffi::c_void, peermessage: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "StartWithMessage", 17usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).StartWithMessage)(this, peermessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "Stop", 18usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TriggeredConnectionStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "TriggeredConnectionStateChanged", 19usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).TriggeredConnectionStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTriggeredConnectionStateChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "RemoveTriggeredConnectionStateChanged", 20usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).RemoveTriggeredConnectionStateChanged)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "ConnectionRequested", 21usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).ConnectionRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConnectionRequested(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "RemoveConnectionRequested", 22usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).RemoveConnectionRequested)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllPeersAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "FindAllPeersAsync", 23usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).FindAllPeersAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn ConnectAsync(this: *mut core::ffi::c_void, peerinformation: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics", "ConnectAsync", 24usize, |this| ((**(this as *const *const IPeerFinderStatics_Vtbl)).ConnectAsync)(this, peerinformation, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AllowBluetooth,
+            SetAllowBluetooth,
+            AllowInfrastructure,
+            SetAllowInfrastructure,
+            AllowWiFiDirect,
+            SetAllowWiFiDirect,
+            DisplayName,
+            SetDisplayName,
+            SupportedDiscoveryTypes,
+            #[cfg(feature = "Foundation_Collections")]
+            AlternateIdentities,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AlternateIdentities: 0,
+            Start,
+            StartWithMessage,
+            Stop,
+            TriggeredConnectionStateChanged,
+            RemoveTriggeredConnectionStateChanged,
+            ConnectionRequested,
+            RemoveConnectionRequested,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllPeersAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllPeersAsync: 0,
+            #[cfg(feature = "Networking_Sockets")]
+            ConnectAsync,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            ConnectAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPeerFinderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPeerFinderStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Role(this: *mut core::ffi::c_void, result__: *mut PeerRole) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics2", "Role", 6usize, |this| ((**(this as *const *const IPeerFinderStatics2_Vtbl)).Role)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRole(this: *mut core::ffi::c_void, value: PeerRole) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics2", "SetRole", 7usize, |this| ((**(this as *const *const IPeerFinderStatics2_Vtbl)).SetRole)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn DiscoveryData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics2", "DiscoveryData", 8usize, |this| ((**(this as *const *const IPeerFinderStatics2_Vtbl)).DiscoveryData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetDiscoveryData(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics2", "SetDiscoveryData", 9usize, |this| ((**(this as *const *const IPeerFinderStatics2_Vtbl)).SetDiscoveryData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerFinderStatics2", "CreateWatcher", 10usize, |this| ((**(this as *const *const IPeerFinderStatics2_Vtbl)).CreateWatcher)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Role,
+            SetRole,
+            #[cfg(feature = "Storage_Streams")]
+            DiscoveryData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            DiscoveryData: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetDiscoveryData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetDiscoveryData: 0,
+            CreateWatcher,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPeerFinderStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPeerInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerInformation", "DisplayName", 6usize, |this| ((**(this as *const *const IPeerInformation_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPeerInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPeerInformation3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerInformation3", "Id", 6usize, |this| ((**(this as *const *const IPeerInformation3_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn DiscoveryData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerInformation3", "DiscoveryData", 7usize, |this| ((**(this as *const *const IPeerInformation3_Vtbl)).DiscoveryData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            #[cfg(feature = "Storage_Streams")]
+            DiscoveryData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            DiscoveryData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPeerInformation3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPeerInformationWithHostAndService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HostName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerInformationWithHostAndService", "HostName", 6usize, |this| ((**(this as *const *const IPeerInformationWithHostAndService_Vtbl)).HostName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerInformationWithHostAndService", "ServiceName", 7usize, |this| ((**(this as *const *const IPeerInformationWithHostAndService_Vtbl)).ServiceName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HostName, ServiceName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPeerInformationWithHostAndService as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPeerWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Added(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerWatcher", "Added", 6usize, |this| ((**(this as *const *const IPeerWatcher_Vtbl)).Added)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerWatcher", "RemoveAdded", 7usize, |this| ((**(this as *const *const IPeerWatcher_Vtbl)).RemoveAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Removed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerWatcher", "Removed", 8usize, |this| ((**(this as *const *const IPeerWatcher_Vtbl)).Removed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerWatcher", "RemoveRemoved", 9usize, |this| ((**(this as *const *const IPeerWatcher_Vtbl)).RemoveRemoved)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Updated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerWatcher", "Updated", 10usize, |this| ((**(this as *const *const IPeerWatcher_Vtbl)).Updated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerWatcher", "RemoveUpdated", 11usize, |this| ((**(this as *const *const IPeerWatcher_Vtbl)).RemoveUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerWatcher", "EnumerationCompleted", 12usize, |this| ((**(this as *const *const IPeerWatcher_Vtbl)).EnumerationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnumerationCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerWatcher", "RemoveEnumerationCompleted", 13usize, |this| ((**(this as *const *const IPeerWatcher_Vtbl)).RemoveEnumerationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stopped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerWatcher", "Stopped", 14usize, |this| ((**(this as *const *const IPeerWatcher_Vtbl)).Stopped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStopped(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerWatcher", "RemoveStopped", 15usize, |this| ((**(this as *const *const IPeerWatcher_Vtbl)).RemoveStopped)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut PeerWatcherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerWatcher", "Status", 16usize, |this| ((**(this as *const *const IPeerWatcher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerWatcher", "Start", 17usize, |this| ((**(this as *const *const IPeerWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPeerWatcher", "Stop", 18usize, |this| ((**(this as *const *const IPeerWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Added,
+            RemoveAdded,
+            Removed,
+            RemoveRemoved,
+            Updated,
+            RemoveUpdated,
+            EnumerationCompleted,
+            RemoveEnumerationCompleted,
+            Stopped,
+            RemoveStopped,
+            Status,
+            Start,
+            Stop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPeerWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IProximityDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SubscribeForMessage(this: *mut core::ffi::c_void, messagetype: core::mem::MaybeUninit<windows_core::HSTRING>, messagereceivedhandler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "SubscribeForMessage", 6usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).SubscribeForMessage)(this, messagetype, messagereceivedhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublishMessage(this: *mut core::ffi::c_void, messagetype: core::mem::MaybeUninit<windows_core::HSTRING>, message: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "PublishMessage", 7usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).PublishMessage)(this, messagetype, message, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublishMessageWithCallback(this: *mut core::ffi::c_void, messagetype: core::mem::MaybeUninit<windows_core::HSTRING>, message: core::mem::MaybeUninit<windows_core::HSTRING>, messagetransmittedhandler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "PublishMessageWithCallback", 8usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).PublishMessageWithCallback)(this, messagetype, message, messagetransmittedhandler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn PublishBinaryMessage(this: *mut core::ffi::c_void, messagetype: core::mem::MaybeUninit<windows_core::HSTRING>, message: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "PublishBinaryMessage", 9usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).PublishBinaryMessage)(this, messagetype, message, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn PublishBinaryMessageWithCallback(this: *mut core::ffi::c_void, messagetype: core::mem::MaybeUninit<windows_core::HSTRING>, message: *mut core::ffi::c_void, messagetransmittedhandler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "PublishBinaryMessageWithCallback", 10usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).PublishBinaryMessageWithCallback)(this, messagetype, message, messagetransmittedhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublishUriMessage(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "PublishUriMessage", 11usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).PublishUriMessage)(this, message, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublishUriMessageWithCallback(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, messagetransmittedhandler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "PublishUriMessageWithCallback", 12usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).PublishUriMessageWithCallback)(this, message, messagetransmittedhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopSubscribingForMessage(this: *mut core::ffi::c_void, subscriptionid: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "StopSubscribingForMessage", 13usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).StopSubscribingForMessage)(this, subscriptionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopPublishingMessage(this: *mut core::ffi::c_void, messageid: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "StopPublishingMessage", 14usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).StopPublishingMessage)(this, messageid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceArrived(this: *mut core::ffi::c_void, arrivedhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "DeviceArrived", 15usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).DeviceArrived)(this, arrivedhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDeviceArrived(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "RemoveDeviceArrived", 16usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).RemoveDeviceArrived)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceDeparted(this: *mut core::ffi::c_void, departedhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "DeviceDeparted", 17usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).DeviceDeparted)(this, departedhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDeviceDeparted(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "RemoveDeviceDeparted", 18usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).RemoveDeviceDeparted)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxMessageBytes(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "MaxMessageBytes", 19usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).MaxMessageBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitsPerSecond(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "BitsPerSecond", 20usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).BitsPerSecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDevice", "DeviceId", 21usize, |this| ((**(this as *const *const IProximityDevice_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SubscribeForMessage,
+            PublishMessage,
+            PublishMessageWithCallback,
+            #[cfg(feature = "Storage_Streams")]
+            PublishBinaryMessage,
+            #[cfg(not(feature = "Storage_Streams"))]
+            PublishBinaryMessage: 0,
+            #[cfg(feature = "Storage_Streams")]
+            PublishBinaryMessageWithCallback,
+            #[cfg(not(feature = "Storage_Streams"))]
+            PublishBinaryMessageWithCallback: 0,
+            PublishUriMessage,
+            PublishUriMessageWithCallback,
+            StopSubscribingForMessage,
+            StopPublishingMessage,
+            DeviceArrived,
+            RemoveDeviceArrived,
+            DeviceDeparted,
+            RemoveDeviceDeparted,
+            MaxMessageBytes,
+            BitsPerSecond,
+            DeviceId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProximityDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProximityDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDeviceStatics", "GetDeviceSelector", 6usize, |this| ((**(this as *const *const IProximityDeviceStatics_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDeviceStatics", "GetDefault", 7usize, |this| ((**(this as *const *const IProximityDeviceStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromId(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityDeviceStatics", "FromId", 8usize, |this| ((**(this as *const *const IProximityDeviceStatics_Vtbl)).FromId)(this, deviceid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceSelector, GetDefault, FromId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProximityDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IProximityMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MessageType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityMessage", "MessageType", 6usize, |this| ((**(this as *const *const IProximityMessage_Vtbl)).MessageType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubscriptionId(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityMessage", "SubscriptionId", 7usize, |this| ((**(this as *const *const IProximityMessage_Vtbl)).SubscriptionId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityMessage", "Data", 8usize, |this| ((**(this as *const *const IProximityMessage_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataAsString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProximityMessage", "DataAsString", 9usize, |this| ((**(this as *const *const IProximityMessage_Vtbl)).DataAsString)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MessageType,
+            SubscriptionId,
+            #[cfg(feature = "Storage_Streams")]
+            Data,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Data: 0,
+            DataAsString,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProximityMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking_Sockets")]
+impl windows_core::imp::ProxyVtable for ITriggeredConnectionStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut TriggeredConnectState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITriggeredConnectionStateChangedEventArgs", "State", 6usize, |this| ((**(this as *const *const ITriggeredConnectionStateChangedEventArgs_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITriggeredConnectionStateChangedEventArgs", "Id", 7usize, |this| ((**(this as *const *const ITriggeredConnectionStateChangedEventArgs_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn Socket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITriggeredConnectionStateChangedEventArgs", "Socket", 8usize, |this| ((**(this as *const *const ITriggeredConnectionStateChangedEventArgs_Vtbl)).Socket)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            State,
+            Id,
+            #[cfg(feature = "Networking_Sockets")]
+            Socket,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            Socket: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITriggeredConnectionStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -486,6 +486,7 @@ impl PushNotificationType {
 impl windows_core::TypeKind for PushNotificationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PushNotificationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PushNotificationType").field(&self.0).finish()
@@ -494,3 +495,5 @@ impl core::fmt::Debug for PushNotificationType {
 impl windows_core::RuntimeType for PushNotificationType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Networking.PushNotifications.PushNotificationType;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,276 @@
+impl windows_core::imp::ProxyVtable for IPushNotificationChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannel", "Uri", 6usize, |this| ((**(this as *const *const IPushNotificationChannel_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannel", "ExpirationTime", 7usize, |this| ((**(this as *const *const IPushNotificationChannel_Vtbl)).ExpirationTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannel", "Close", 8usize, |this| ((**(this as *const *const IPushNotificationChannel_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushNotificationReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannel", "PushNotificationReceived", 9usize, |this| ((**(this as *const *const IPushNotificationChannel_Vtbl)).PushNotificationReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePushNotificationReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannel", "RemovePushNotificationReceived", 10usize, |this| ((**(this as *const *const IPushNotificationChannel_Vtbl)).RemovePushNotificationReceived)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Uri,
+            ExpirationTime,
+            Close,
+            PushNotificationReceived,
+            RemovePushNotificationReceived,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPushNotificationChannel as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IPushNotificationChannelManagerForUser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreatePushNotificationChannelForApplicationAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannelManagerForUser", "CreatePushNotificationChannelForApplicationAsync", 6usize, |this| ((**(this as *const *const IPushNotificationChannelManagerForUser_Vtbl)).CreatePushNotificationChannelForApplicationAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePushNotificationChannelForApplicationAsyncWithId(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannelManagerForUser", "CreatePushNotificationChannelForApplicationAsyncWithId", 7usize, |this| ((**(this as *const *const IPushNotificationChannelManagerForUser_Vtbl)).CreatePushNotificationChannelForApplicationAsyncWithId)(this, applicationid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePushNotificationChannelForSecondaryTileAsync(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannelManagerForUser", "CreatePushNotificationChannelForSecondaryTileAsync", 8usize, |this| ((**(this as *const *const IPushNotificationChannelManagerForUser_Vtbl)).CreatePushNotificationChannelForSecondaryTileAsync)(this, tileid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannelManagerForUser", "User", 9usize, |this| ((**(this as *const *const IPushNotificationChannelManagerForUser_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreatePushNotificationChannelForApplicationAsync,
+            CreatePushNotificationChannelForApplicationAsyncWithId,
+            CreatePushNotificationChannelForSecondaryTileAsync,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPushNotificationChannelManagerForUser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IPushNotificationChannelManagerForUser2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateRawPushNotificationChannelWithAlternateKeyForApplicationAsync(this: *mut core::ffi::c_void, appserverkey: *mut core::ffi::c_void, channelid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannelManagerForUser2", "CreateRawPushNotificationChannelWithAlternateKeyForApplicationAsync", 6usize, |this| ((**(this as *const *const IPushNotificationChannelManagerForUser2_Vtbl)).CreateRawPushNotificationChannelWithAlternateKeyForApplicationAsync)(this, appserverkey, channelid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateRawPushNotificationChannelWithAlternateKeyForApplicationAsyncWithId(this: *mut core::ffi::c_void, appserverkey: *mut core::ffi::c_void, channelid: core::mem::MaybeUninit<windows_core::HSTRING>, appid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannelManagerForUser2", "CreateRawPushNotificationChannelWithAlternateKeyForApplicationAsyncWithId", 7usize, |this| ((**(this as *const *const IPushNotificationChannelManagerForUser2_Vtbl)).CreateRawPushNotificationChannelWithAlternateKeyForApplicationAsyncWithId)(this, appserverkey, channelid, appid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateRawPushNotificationChannelWithAlternateKeyForApplicationAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateRawPushNotificationChannelWithAlternateKeyForApplicationAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateRawPushNotificationChannelWithAlternateKeyForApplicationAsyncWithId,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateRawPushNotificationChannelWithAlternateKeyForApplicationAsyncWithId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPushNotificationChannelManagerForUser2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPushNotificationChannelManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreatePushNotificationChannelForApplicationAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannelManagerStatics", "CreatePushNotificationChannelForApplicationAsync", 6usize, |this| ((**(this as *const *const IPushNotificationChannelManagerStatics_Vtbl)).CreatePushNotificationChannelForApplicationAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePushNotificationChannelForApplicationAsyncWithId(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannelManagerStatics", "CreatePushNotificationChannelForApplicationAsyncWithId", 7usize, |this| ((**(this as *const *const IPushNotificationChannelManagerStatics_Vtbl)).CreatePushNotificationChannelForApplicationAsyncWithId)(this, applicationid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePushNotificationChannelForSecondaryTileAsync(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannelManagerStatics", "CreatePushNotificationChannelForSecondaryTileAsync", 8usize, |this| ((**(this as *const *const IPushNotificationChannelManagerStatics_Vtbl)).CreatePushNotificationChannelForSecondaryTileAsync)(this, tileid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreatePushNotificationChannelForApplicationAsync,
+            CreatePushNotificationChannelForApplicationAsyncWithId,
+            CreatePushNotificationChannelForSecondaryTileAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPushNotificationChannelManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IPushNotificationChannelManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannelManagerStatics2", "GetForUser", 6usize, |this| ((**(this as *const *const IPushNotificationChannelManagerStatics2_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPushNotificationChannelManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPushNotificationChannelManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannelManagerStatics3", "GetDefault", 6usize, |this| ((**(this as *const *const IPushNotificationChannelManagerStatics3_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPushNotificationChannelManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPushNotificationChannelManagerStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChannelsRevoked(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannelManagerStatics4", "ChannelsRevoked", 6usize, |this| ((**(this as *const *const IPushNotificationChannelManagerStatics4_Vtbl)).ChannelsRevoked)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveChannelsRevoked(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationChannelManagerStatics4", "RemoveChannelsRevoked", 7usize, |this| ((**(this as *const *const IPushNotificationChannelManagerStatics4_Vtbl)).RemoveChannelsRevoked)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ChannelsRevoked, RemoveChannelsRevoked }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPushNotificationChannelManagerStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPushNotificationChannelsRevokedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPushNotificationChannelsRevokedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Notifications")]
+impl windows_core::imp::ProxyVtable for IPushNotificationReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCancel(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationReceivedEventArgs", "SetCancel", 6usize, |this| ((**(this as *const *const IPushNotificationReceivedEventArgs_Vtbl)).SetCancel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationReceivedEventArgs", "Cancel", 7usize, |this| ((**(this as *const *const IPushNotificationReceivedEventArgs_Vtbl)).Cancel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotificationType(this: *mut core::ffi::c_void, result__: *mut PushNotificationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationReceivedEventArgs", "NotificationType", 8usize, |this| ((**(this as *const *const IPushNotificationReceivedEventArgs_Vtbl)).NotificationType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn ToastNotification(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationReceivedEventArgs", "ToastNotification", 9usize, |this| ((**(this as *const *const IPushNotificationReceivedEventArgs_Vtbl)).ToastNotification)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn TileNotification(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationReceivedEventArgs", "TileNotification", 10usize, |this| ((**(this as *const *const IPushNotificationReceivedEventArgs_Vtbl)).TileNotification)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn BadgeNotification(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationReceivedEventArgs", "BadgeNotification", 11usize, |this| ((**(this as *const *const IPushNotificationReceivedEventArgs_Vtbl)).BadgeNotification)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawNotification(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPushNotificationReceivedEventArgs", "RawNotification", 12usize, |this| ((**(this as *const *const IPushNotificationReceivedEventArgs_Vtbl)).RawNotification)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetCancel,
+            Cancel,
+            NotificationType,
+            #[cfg(feature = "UI_Notifications")]
+            ToastNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            ToastNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            TileNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            TileNotification: 0,
+            #[cfg(feature = "UI_Notifications")]
+            BadgeNotification,
+            #[cfg(not(feature = "UI_Notifications"))]
+            BadgeNotification: 0,
+            RawNotification,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPushNotificationReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRawNotification_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawNotification", "Content", 6usize, |this| ((**(this as *const *const IRawNotification_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Content }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawNotification as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRawNotification2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Headers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawNotification2", "Headers", 6usize, |this| ((**(this as *const *const IRawNotification2_Vtbl)).Headers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChannelId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawNotification2", "ChannelId", 7usize, |this| ((**(this as *const *const IRawNotification2_Vtbl)).ChannelId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Headers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Headers: 0,
+            ChannelId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawNotification2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IRawNotification3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ContentBytes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawNotification3", "ContentBytes", 6usize, |this| ((**(this as *const *const IRawNotification3_Vtbl)).ContentBytes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            ContentBytes,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ContentBytes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawNotification3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -453,6 +453,7 @@ impl DnssdRegistrationStatus {
 impl windows_core::TypeKind for DnssdRegistrationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DnssdRegistrationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DnssdRegistrationStatus").field(&self.0).finish()
@@ -475,6 +476,7 @@ impl DnssdServiceWatcherStatus {
 impl windows_core::TypeKind for DnssdServiceWatcherStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DnssdServiceWatcherStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DnssdServiceWatcherStatus").field(&self.0).finish()
@@ -483,3 +485,5 @@ impl core::fmt::Debug for DnssdServiceWatcherStatus {
 impl windows_core::RuntimeType for DnssdServiceWatcherStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Networking.ServiceDiscovery.Dnssd.DnssdServiceWatcherStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
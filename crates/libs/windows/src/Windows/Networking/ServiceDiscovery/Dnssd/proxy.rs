@@ -0,0 +1,169 @@
+impl windows_core::imp::ProxyVtable for IDnssdRegistrationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut DnssdRegistrationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdRegistrationResult", "Status", 6usize, |this| ((**(this as *const *const IDnssdRegistrationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IPAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdRegistrationResult", "IPAddress", 7usize, |this| ((**(this as *const *const IDnssdRegistrationResult_Vtbl)).IPAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasInstanceNameChanged(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdRegistrationResult", "HasInstanceNameChanged", 8usize, |this| ((**(this as *const *const IDnssdRegistrationResult_Vtbl)).HasInstanceNameChanged)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, IPAddress, HasInstanceNameChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDnssdRegistrationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Networking_Connectivity", feature = "Networking_Sockets"))]
+impl windows_core::imp::ProxyVtable for IDnssdServiceInstance_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DnssdServiceInstanceName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstance", "DnssdServiceInstanceName", 6usize, |this| ((**(this as *const *const IDnssdServiceInstance_Vtbl)).DnssdServiceInstanceName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDnssdServiceInstanceName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstance", "SetDnssdServiceInstanceName", 7usize, |this| ((**(this as *const *const IDnssdServiceInstance_Vtbl)).SetDnssdServiceInstanceName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HostName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstance", "HostName", 8usize, |this| ((**(this as *const *const IDnssdServiceInstance_Vtbl)).HostName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHostName(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstance", "SetHostName", 9usize, |this| ((**(this as *const *const IDnssdServiceInstance_Vtbl)).SetHostName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Port(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstance", "Port", 10usize, |this| ((**(this as *const *const IDnssdServiceInstance_Vtbl)).Port)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPort(this: *mut core::ffi::c_void, value: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstance", "SetPort", 11usize, |this| ((**(this as *const *const IDnssdServiceInstance_Vtbl)).SetPort)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstance", "Priority", 12usize, |this| ((**(this as *const *const IDnssdServiceInstance_Vtbl)).Priority)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, value: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstance", "SetPriority", 13usize, |this| ((**(this as *const *const IDnssdServiceInstance_Vtbl)).SetPriority)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Weight(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstance", "Weight", 14usize, |this| ((**(this as *const *const IDnssdServiceInstance_Vtbl)).Weight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWeight(this: *mut core::ffi::c_void, value: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstance", "SetWeight", 15usize, |this| ((**(this as *const *const IDnssdServiceInstance_Vtbl)).SetWeight)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TextAttributes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstance", "TextAttributes", 16usize, |this| ((**(this as *const *const IDnssdServiceInstance_Vtbl)).TextAttributes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn RegisterStreamSocketListenerAsync1(this: *mut core::ffi::c_void, socket: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstance", "RegisterStreamSocketListenerAsync1", 17usize, |this| ((**(this as *const *const IDnssdServiceInstance_Vtbl)).RegisterStreamSocketListenerAsync1)(this, socket, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Networking_Connectivity", feature = "Networking_Sockets"))]
+        unsafe extern "system" fn RegisterStreamSocketListenerAsync2(this: *mut core::ffi::c_void, socket: *mut core::ffi::c_void, adapter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstance", "RegisterStreamSocketListenerAsync2", 18usize, |this| ((**(this as *const *const IDnssdServiceInstance_Vtbl)).RegisterStreamSocketListenerAsync2)(this, socket, adapter, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn RegisterDatagramSocketAsync1(this: *mut core::ffi::c_void, socket: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstance", "RegisterDatagramSocketAsync1", 19usize, |this| ((**(this as *const *const IDnssdServiceInstance_Vtbl)).RegisterDatagramSocketAsync1)(this, socket, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Networking_Connectivity", feature = "Networking_Sockets"))]
+        unsafe extern "system" fn RegisterDatagramSocketAsync2(this: *mut core::ffi::c_void, socket: *mut core::ffi::c_void, adapter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstance", "RegisterDatagramSocketAsync2", 20usize, |this| ((**(this as *const *const IDnssdServiceInstance_Vtbl)).RegisterDatagramSocketAsync2)(this, socket, adapter, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DnssdServiceInstanceName,
+            SetDnssdServiceInstanceName,
+            HostName,
+            SetHostName,
+            Port,
+            SetPort,
+            Priority,
+            SetPriority,
+            Weight,
+            SetWeight,
+            #[cfg(feature = "Foundation_Collections")]
+            TextAttributes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TextAttributes: 0,
+            #[cfg(feature = "Networking_Sockets")]
+            RegisterStreamSocketListenerAsync1,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            RegisterStreamSocketListenerAsync1: 0,
+            #[cfg(all(feature = "Networking_Connectivity", feature = "Networking_Sockets"))]
+            RegisterStreamSocketListenerAsync2,
+            #[cfg(not(all(feature = "Networking_Connectivity", feature = "Networking_Sockets")))]
+            RegisterStreamSocketListenerAsync2: 0,
+            #[cfg(feature = "Networking_Sockets")]
+            RegisterDatagramSocketAsync1,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            RegisterDatagramSocketAsync1: 0,
+            #[cfg(all(feature = "Networking_Connectivity", feature = "Networking_Sockets"))]
+            RegisterDatagramSocketAsync2,
+            #[cfg(not(all(feature = "Networking_Connectivity", feature = "Networking_Sockets")))]
+            RegisterDatagramSocketAsync2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDnssdServiceInstance as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDnssdServiceInstanceFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, dnssdserviceinstancename: core::mem::MaybeUninit<windows_core::HSTRING>, hostname: *mut core::ffi::c_void, port: u16, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceInstanceFactory", "Create", 6usize, |this| ((**(this as *const *const IDnssdServiceInstanceFactory_Vtbl)).Create)(this, dnssdserviceinstancename, hostname, port, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDnssdServiceInstanceFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDnssdServiceWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Added(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceWatcher", "Added", 6usize, |this| ((**(this as *const *const IDnssdServiceWatcher_Vtbl)).Added)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdded(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceWatcher", "RemoveAdded", 7usize, |this| ((**(this as *const *const IDnssdServiceWatcher_Vtbl)).RemoveAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceWatcher", "EnumerationCompleted", 8usize, |this| ((**(this as *const *const IDnssdServiceWatcher_Vtbl)).EnumerationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnumerationCompleted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceWatcher", "RemoveEnumerationCompleted", 9usize, |this| ((**(this as *const *const IDnssdServiceWatcher_Vtbl)).RemoveEnumerationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stopped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceWatcher", "Stopped", 10usize, |this| ((**(this as *const *const IDnssdServiceWatcher_Vtbl)).Stopped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStopped(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceWatcher", "RemoveStopped", 11usize, |this| ((**(this as *const *const IDnssdServiceWatcher_Vtbl)).RemoveStopped)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut DnssdServiceWatcherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceWatcher", "Status", 12usize, |this| ((**(this as *const *const IDnssdServiceWatcher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceWatcher", "Start", 13usize, |this| ((**(this as *const *const IDnssdServiceWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDnssdServiceWatcher", "Stop", 14usize, |this| ((**(this as *const *const IDnssdServiceWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Added,
+            RemoveAdded,
+            EnumerationCompleted,
+            RemoveEnumerationCompleted,
+            Stopped,
+            RemoveStopped,
+            Status,
+            Start,
+            Stop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDnssdServiceWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
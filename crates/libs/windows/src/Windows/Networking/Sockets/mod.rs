@@ -3628,6 +3628,7 @@ impl ControlChannelTriggerResetReason {
 impl windows_core::TypeKind for ControlChannelTriggerResetReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ControlChannelTriggerResetReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ControlChannelTriggerResetReason").field(&self.0).finish()
@@ -3646,6 +3647,7 @@ impl ControlChannelTriggerResourceType {
 impl windows_core::TypeKind for ControlChannelTriggerResourceType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ControlChannelTriggerResourceType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ControlChannelTriggerResourceType").field(&self.0).finish()
@@ -3669,6 +3671,7 @@ impl ControlChannelTriggerStatus {
 impl windows_core::TypeKind for ControlChannelTriggerStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ControlChannelTriggerStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ControlChannelTriggerStatus").field(&self.0).finish()
@@ -3687,6 +3690,7 @@ impl MessageWebSocketReceiveMode {
 impl windows_core::TypeKind for MessageWebSocketReceiveMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MessageWebSocketReceiveMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MessageWebSocketReceiveMode").field(&self.0).finish()
@@ -3705,6 +3709,7 @@ impl SocketActivityConnectedStandbyAction {
 impl windows_core::TypeKind for SocketActivityConnectedStandbyAction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SocketActivityConnectedStandbyAction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SocketActivityConnectedStandbyAction").field(&self.0).finish()
@@ -3725,6 +3730,7 @@ impl SocketActivityKind {
 impl windows_core::TypeKind for SocketActivityKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SocketActivityKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SocketActivityKind").field(&self.0).finish()
@@ -3746,6 +3752,7 @@ impl SocketActivityTriggerReason {
 impl windows_core::TypeKind for SocketActivityTriggerReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SocketActivityTriggerReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SocketActivityTriggerReason").field(&self.0).finish()
@@ -3793,6 +3800,7 @@ impl SocketErrorStatus {
 impl windows_core::TypeKind for SocketErrorStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SocketErrorStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SocketErrorStatus").field(&self.0).finish()
@@ -3811,6 +3819,7 @@ impl SocketMessageType {
 impl windows_core::TypeKind for SocketMessageType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SocketMessageType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SocketMessageType").field(&self.0).finish()
@@ -3838,6 +3847,7 @@ impl SocketProtectionLevel {
 impl windows_core::TypeKind for SocketProtectionLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SocketProtectionLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SocketProtectionLevel").field(&self.0).finish()
@@ -3856,6 +3866,7 @@ impl SocketQualityOfService {
 impl windows_core::TypeKind for SocketQualityOfService {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SocketQualityOfService {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SocketQualityOfService").field(&self.0).finish()
@@ -3875,6 +3886,7 @@ impl SocketSslErrorSeverity {
 impl windows_core::TypeKind for SocketSslErrorSeverity {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SocketSslErrorSeverity {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SocketSslErrorSeverity").field(&self.0).finish()
@@ -3884,7 +3896,8 @@ impl windows_core::RuntimeType for SocketSslErrorSeverity {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Networking.Sockets.SocketSslErrorSeverity;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BandwidthStatistics {
     pub OutboundBitsPerSecond: u64,
     pub InboundBitsPerSecond: u64,
@@ -3905,7 +3918,8 @@ impl Default for BandwidthStatistics {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RoundTripTimeStatistics {
     pub Variance: u32,
     pub Max: u32,
@@ -3925,3 +3939,5 @@ impl Default for RoundTripTimeStatistics {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
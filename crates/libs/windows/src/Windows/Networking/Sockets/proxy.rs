@@ -0,0 +1,1760 @@
+#[cfg(feature = "ApplicationModel_Background")]
+impl windows_core::imp::ProxyVtable for IControlChannelTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ControlChannelTriggerId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTrigger", "ControlChannelTriggerId", 6usize, |this| ((**(this as *const *const IControlChannelTrigger_Vtbl)).ControlChannelTriggerId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerKeepAliveIntervalInMinutes(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTrigger", "ServerKeepAliveIntervalInMinutes", 7usize, |this| ((**(this as *const *const IControlChannelTrigger_Vtbl)).ServerKeepAliveIntervalInMinutes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServerKeepAliveIntervalInMinutes(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTrigger", "SetServerKeepAliveIntervalInMinutes", 8usize, |this| ((**(this as *const *const IControlChannelTrigger_Vtbl)).SetServerKeepAliveIntervalInMinutes)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentKeepAliveIntervalInMinutes(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTrigger", "CurrentKeepAliveIntervalInMinutes", 9usize, |this| ((**(this as *const *const IControlChannelTrigger_Vtbl)).CurrentKeepAliveIntervalInMinutes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportObject(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTrigger", "TransportObject", 10usize, |this| ((**(this as *const *const IControlChannelTrigger_Vtbl)).TransportObject)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Background")]
+        unsafe extern "system" fn KeepAliveTrigger(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTrigger", "KeepAliveTrigger", 11usize, |this| ((**(this as *const *const IControlChannelTrigger_Vtbl)).KeepAliveTrigger)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Background")]
+        unsafe extern "system" fn PushNotificationTrigger(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTrigger", "PushNotificationTrigger", 12usize, |this| ((**(this as *const *const IControlChannelTrigger_Vtbl)).PushNotificationTrigger)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsingTransport(this: *mut core::ffi::c_void, transport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTrigger", "UsingTransport", 13usize, |this| ((**(this as *const *const IControlChannelTrigger_Vtbl)).UsingTransport)(this, transport), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForPushEnabled(this: *mut core::ffi::c_void, result__: *mut ControlChannelTriggerStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTrigger", "WaitForPushEnabled", 14usize, |this| ((**(this as *const *const IControlChannelTrigger_Vtbl)).WaitForPushEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecreaseNetworkKeepAliveInterval(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTrigger", "DecreaseNetworkKeepAliveInterval", 15usize, |this| ((**(this as *const *const IControlChannelTrigger_Vtbl)).DecreaseNetworkKeepAliveInterval)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushTransport(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTrigger", "FlushTransport", 16usize, |this| ((**(this as *const *const IControlChannelTrigger_Vtbl)).FlushTransport)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ControlChannelTriggerId,
+            ServerKeepAliveIntervalInMinutes,
+            SetServerKeepAliveIntervalInMinutes,
+            CurrentKeepAliveIntervalInMinutes,
+            TransportObject,
+            #[cfg(feature = "ApplicationModel_Background")]
+            KeepAliveTrigger,
+            #[cfg(not(feature = "ApplicationModel_Background"))]
+            KeepAliveTrigger: 0,
+            #[cfg(feature = "ApplicationModel_Background")]
+            PushNotificationTrigger,
+            #[cfg(not(feature = "ApplicationModel_Background"))]
+            PushNotificationTrigger: 0,
+            UsingTransport,
+            WaitForPushEnabled,
+            DecreaseNetworkKeepAliveInterval,
+            FlushTransport,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IControlChannelTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IControlChannelTrigger2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsWakeFromLowPowerSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTrigger2", "IsWakeFromLowPowerSupported", 6usize, |this| ((**(this as *const *const IControlChannelTrigger2_Vtbl)).IsWakeFromLowPowerSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsWakeFromLowPowerSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IControlChannelTrigger2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IControlChannelTriggerEventDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ControlChannelTrigger(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTriggerEventDetails", "ControlChannelTrigger", 6usize, |this| ((**(this as *const *const IControlChannelTriggerEventDetails_Vtbl)).ControlChannelTrigger)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ControlChannelTrigger }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IControlChannelTriggerEventDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IControlChannelTriggerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateControlChannelTrigger(this: *mut core::ffi::c_void, channelid: core::mem::MaybeUninit<windows_core::HSTRING>, serverkeepaliveintervalinminutes: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTriggerFactory", "CreateControlChannelTrigger", 6usize, |this| ((**(this as *const *const IControlChannelTriggerFactory_Vtbl)).CreateControlChannelTrigger)(this, channelid, serverkeepaliveintervalinminutes, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateControlChannelTriggerEx(this: *mut core::ffi::c_void, channelid: core::mem::MaybeUninit<windows_core::HSTRING>, serverkeepaliveintervalinminutes: u32, resourcerequesttype: ControlChannelTriggerResourceType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTriggerFactory", "CreateControlChannelTriggerEx", 7usize, |this| ((**(this as *const *const IControlChannelTriggerFactory_Vtbl)).CreateControlChannelTriggerEx)(this, channelid, serverkeepaliveintervalinminutes, resourcerequesttype, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateControlChannelTrigger, CreateControlChannelTriggerEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IControlChannelTriggerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IControlChannelTriggerResetEventDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResetReason(this: *mut core::ffi::c_void, result__: *mut ControlChannelTriggerResetReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTriggerResetEventDetails", "ResetReason", 6usize, |this| ((**(this as *const *const IControlChannelTriggerResetEventDetails_Vtbl)).ResetReason)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareSlotReset(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTriggerResetEventDetails", "HardwareSlotReset", 7usize, |this| ((**(this as *const *const IControlChannelTriggerResetEventDetails_Vtbl)).HardwareSlotReset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SoftwareSlotReset(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChannelTriggerResetEventDetails", "SoftwareSlotReset", 8usize, |this| ((**(this as *const *const IControlChannelTriggerResetEventDetails_Vtbl)).SoftwareSlotReset)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ResetReason, HardwareSlotReset, SoftwareSlotReset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IControlChannelTriggerResetEventDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IDatagramSocket_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Control(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket", "Control", 6usize, |this| ((**(this as *const *const IDatagramSocket_Vtbl)).Control)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Information(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket", "Information", 7usize, |this| ((**(this as *const *const IDatagramSocket_Vtbl)).Information)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn OutputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket", "OutputStream", 8usize, |this| ((**(this as *const *const IDatagramSocket_Vtbl)).OutputStream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectAsync(this: *mut core::ffi::c_void, remotehostname: *mut core::ffi::c_void, remoteservicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket", "ConnectAsync", 9usize, |this| ((**(this as *const *const IDatagramSocket_Vtbl)).ConnectAsync)(this, remotehostname, remoteservicename, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectWithEndpointPairAsync(this: *mut core::ffi::c_void, endpointpair: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket", "ConnectWithEndpointPairAsync", 10usize, |this| ((**(this as *const *const IDatagramSocket_Vtbl)).ConnectWithEndpointPairAsync)(this, endpointpair, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindServiceNameAsync(this: *mut core::ffi::c_void, localservicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket", "BindServiceNameAsync", 11usize, |this| ((**(this as *const *const IDatagramSocket_Vtbl)).BindServiceNameAsync)(this, localservicename, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindEndpointAsync(this: *mut core::ffi::c_void, localhostname: *mut core::ffi::c_void, localservicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket", "BindEndpointAsync", 12usize, |this| ((**(this as *const *const IDatagramSocket_Vtbl)).BindEndpointAsync)(this, localhostname, localservicename, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JoinMulticastGroup(this: *mut core::ffi::c_void, host: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket", "JoinMulticastGroup", 13usize, |this| ((**(this as *const *const IDatagramSocket_Vtbl)).JoinMulticastGroup)(this, host), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetOutputStreamAsync(this: *mut core::ffi::c_void, remotehostname: *mut core::ffi::c_void, remoteservicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket", "GetOutputStreamAsync", 14usize, |this| ((**(this as *const *const IDatagramSocket_Vtbl)).GetOutputStreamAsync)(this, remotehostname, remoteservicename, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetOutputStreamWithEndpointPairAsync(this: *mut core::ffi::c_void, endpointpair: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket", "GetOutputStreamWithEndpointPairAsync", 15usize, |this| ((**(this as *const *const IDatagramSocket_Vtbl)).GetOutputStreamWithEndpointPairAsync)(this, endpointpair, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageReceived(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket", "MessageReceived", 16usize, |this| ((**(this as *const *const IDatagramSocket_Vtbl)).MessageReceived)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMessageReceived(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket", "RemoveMessageReceived", 17usize, |this| ((**(this as *const *const IDatagramSocket_Vtbl)).RemoveMessageReceived)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Control,
+            Information,
+            #[cfg(feature = "Storage_Streams")]
+            OutputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            OutputStream: 0,
+            ConnectAsync,
+            ConnectWithEndpointPairAsync,
+            BindServiceNameAsync,
+            BindEndpointAsync,
+            JoinMulticastGroup,
+            #[cfg(feature = "Storage_Streams")]
+            GetOutputStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetOutputStreamAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            GetOutputStreamWithEndpointPairAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetOutputStreamWithEndpointPairAsync: 0,
+            MessageReceived,
+            RemoveMessageReceived,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDatagramSocket as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking_Connectivity")]
+impl windows_core::imp::ProxyVtable for IDatagramSocket2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Networking_Connectivity")]
+        unsafe extern "system" fn BindServiceNameAndAdapterAsync(this: *mut core::ffi::c_void, localservicename: core::mem::MaybeUninit<windows_core::HSTRING>, adapter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket2", "BindServiceNameAndAdapterAsync", 6usize, |this| ((**(this as *const *const IDatagramSocket2_Vtbl)).BindServiceNameAndAdapterAsync)(this, localservicename, adapter, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Networking_Connectivity")]
+            BindServiceNameAndAdapterAsync,
+            #[cfg(not(feature = "Networking_Connectivity"))]
+            BindServiceNameAndAdapterAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDatagramSocket2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDatagramSocket3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CancelIOAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket3", "CancelIOAsync", 6usize, |this| ((**(this as *const *const IDatagramSocket3_Vtbl)).CancelIOAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableTransferOwnership(this: *mut core::ffi::c_void, taskid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket3", "EnableTransferOwnership", 7usize, |this| ((**(this as *const *const IDatagramSocket3_Vtbl)).EnableTransferOwnership)(this, taskid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableTransferOwnershipWithConnectedStandbyAction(this: *mut core::ffi::c_void, taskid: windows_core::GUID, connectedstandbyaction: SocketActivityConnectedStandbyAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket3", "EnableTransferOwnershipWithConnectedStandbyAction", 8usize, |this| ((**(this as *const *const IDatagramSocket3_Vtbl)).EnableTransferOwnershipWithConnectedStandbyAction)(this, taskid, connectedstandbyaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferOwnership(this: *mut core::ffi::c_void, socketid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket3", "TransferOwnership", 9usize, |this| ((**(this as *const *const IDatagramSocket3_Vtbl)).TransferOwnership)(this, socketid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferOwnershipWithContext(this: *mut core::ffi::c_void, socketid: core::mem::MaybeUninit<windows_core::HSTRING>, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket3", "TransferOwnershipWithContext", 10usize, |this| ((**(this as *const *const IDatagramSocket3_Vtbl)).TransferOwnershipWithContext)(this, socketid, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferOwnershipWithContextAndKeepAliveTime(this: *mut core::ffi::c_void, socketid: core::mem::MaybeUninit<windows_core::HSTRING>, data: *mut core::ffi::c_void, keepalivetime: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocket3", "TransferOwnershipWithContextAndKeepAliveTime", 11usize, |this| ((**(this as *const *const IDatagramSocket3_Vtbl)).TransferOwnershipWithContextAndKeepAliveTime)(this, socketid, data, keepalivetime), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CancelIOAsync,
+            EnableTransferOwnership,
+            EnableTransferOwnershipWithConnectedStandbyAction,
+            TransferOwnership,
+            TransferOwnershipWithContext,
+            TransferOwnershipWithContextAndKeepAliveTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDatagramSocket3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDatagramSocketControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QualityOfService(this: *mut core::ffi::c_void, result__: *mut SocketQualityOfService) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketControl", "QualityOfService", 6usize, |this| ((**(this as *const *const IDatagramSocketControl_Vtbl)).QualityOfService)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQualityOfService(this: *mut core::ffi::c_void, value: SocketQualityOfService) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketControl", "SetQualityOfService", 7usize, |this| ((**(this as *const *const IDatagramSocketControl_Vtbl)).SetQualityOfService)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutboundUnicastHopLimit(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketControl", "OutboundUnicastHopLimit", 8usize, |this| ((**(this as *const *const IDatagramSocketControl_Vtbl)).OutboundUnicastHopLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutboundUnicastHopLimit(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketControl", "SetOutboundUnicastHopLimit", 9usize, |this| ((**(this as *const *const IDatagramSocketControl_Vtbl)).SetOutboundUnicastHopLimit)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QualityOfService,
+            SetQualityOfService,
+            OutboundUnicastHopLimit,
+            SetOutboundUnicastHopLimit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDatagramSocketControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDatagramSocketControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InboundBufferSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketControl2", "InboundBufferSizeInBytes", 6usize, |this| ((**(this as *const *const IDatagramSocketControl2_Vtbl)).InboundBufferSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInboundBufferSizeInBytes(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketControl2", "SetInboundBufferSizeInBytes", 7usize, |this| ((**(this as *const *const IDatagramSocketControl2_Vtbl)).SetInboundBufferSizeInBytes)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DontFragment(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketControl2", "DontFragment", 8usize, |this| ((**(this as *const *const IDatagramSocketControl2_Vtbl)).DontFragment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDontFragment(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketControl2", "SetDontFragment", 9usize, |this| ((**(this as *const *const IDatagramSocketControl2_Vtbl)).SetDontFragment)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InboundBufferSizeInBytes,
+            SetInboundBufferSizeInBytes,
+            DontFragment,
+            SetDontFragment,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDatagramSocketControl2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDatagramSocketControl3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MulticastOnly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketControl3", "MulticastOnly", 6usize, |this| ((**(this as *const *const IDatagramSocketControl3_Vtbl)).MulticastOnly)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMulticastOnly(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketControl3", "SetMulticastOnly", 7usize, |this| ((**(this as *const *const IDatagramSocketControl3_Vtbl)).SetMulticastOnly)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MulticastOnly, SetMulticastOnly }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDatagramSocketControl3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDatagramSocketInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LocalAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketInformation", "LocalAddress", 6usize, |this| ((**(this as *const *const IDatagramSocketInformation_Vtbl)).LocalAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalPort(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketInformation", "LocalPort", 7usize, |this| ((**(this as *const *const IDatagramSocketInformation_Vtbl)).LocalPort)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketInformation", "RemoteAddress", 8usize, |this| ((**(this as *const *const IDatagramSocketInformation_Vtbl)).RemoteAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemotePort(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketInformation", "RemotePort", 9usize, |this| ((**(this as *const *const IDatagramSocketInformation_Vtbl)).RemotePort)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LocalAddress, LocalPort, RemoteAddress, RemotePort }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDatagramSocketInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IDatagramSocketMessageReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoteAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketMessageReceivedEventArgs", "RemoteAddress", 6usize, |this| ((**(this as *const *const IDatagramSocketMessageReceivedEventArgs_Vtbl)).RemoteAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemotePort(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketMessageReceivedEventArgs", "RemotePort", 7usize, |this| ((**(this as *const *const IDatagramSocketMessageReceivedEventArgs_Vtbl)).RemotePort)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketMessageReceivedEventArgs", "LocalAddress", 8usize, |this| ((**(this as *const *const IDatagramSocketMessageReceivedEventArgs_Vtbl)).LocalAddress)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetDataReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketMessageReceivedEventArgs", "GetDataReader", 9usize, |this| ((**(this as *const *const IDatagramSocketMessageReceivedEventArgs_Vtbl)).GetDataReader)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetDataStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketMessageReceivedEventArgs", "GetDataStream", 10usize, |this| ((**(this as *const *const IDatagramSocketMessageReceivedEventArgs_Vtbl)).GetDataStream)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RemoteAddress,
+            RemotePort,
+            LocalAddress,
+            #[cfg(feature = "Storage_Streams")]
+            GetDataReader,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetDataReader: 0,
+            #[cfg(feature = "Storage_Streams")]
+            GetDataStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetDataStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDatagramSocketMessageReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDatagramSocketStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetEndpointPairsAsync(this: *mut core::ffi::c_void, remotehostname: *mut core::ffi::c_void, remoteservicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketStatics", "GetEndpointPairsAsync", 6usize, |this| ((**(this as *const *const IDatagramSocketStatics_Vtbl)).GetEndpointPairsAsync)(this, remotehostname, remoteservicename, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetEndpointPairsWithSortOptionsAsync(this: *mut core::ffi::c_void, remotehostname: *mut core::ffi::c_void, remoteservicename: core::mem::MaybeUninit<windows_core::HSTRING>, sortoptions: super::HostNameSortOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDatagramSocketStatics", "GetEndpointPairsWithSortOptionsAsync", 7usize, |this| ((**(this as *const *const IDatagramSocketStatics_Vtbl)).GetEndpointPairsWithSortOptionsAsync)(this, remotehostname, remoteservicename, sortoptions, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetEndpointPairsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetEndpointPairsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetEndpointPairsWithSortOptionsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetEndpointPairsWithSortOptionsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDatagramSocketStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMessageWebSocket_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Control(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocket", "Control", 6usize, |this| ((**(this as *const *const IMessageWebSocket_Vtbl)).Control)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Information(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocket", "Information", 7usize, |this| ((**(this as *const *const IMessageWebSocket_Vtbl)).Information)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageReceived(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocket", "MessageReceived", 8usize, |this| ((**(this as *const *const IMessageWebSocket_Vtbl)).MessageReceived)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMessageReceived(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocket", "RemoveMessageReceived", 9usize, |this| ((**(this as *const *const IMessageWebSocket_Vtbl)).RemoveMessageReceived)(this, eventcookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Control, Information, MessageReceived, RemoveMessageReceived }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMessageWebSocket as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMessageWebSocket2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServerCustomValidationRequested(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocket2", "ServerCustomValidationRequested", 6usize, |this| ((**(this as *const *const IMessageWebSocket2_Vtbl)).ServerCustomValidationRequested)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveServerCustomValidationRequested(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocket2", "RemoveServerCustomValidationRequested", 7usize, |this| ((**(this as *const *const IMessageWebSocket2_Vtbl)).RemoveServerCustomValidationRequested)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServerCustomValidationRequested,
+            RemoveServerCustomValidationRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMessageWebSocket2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMessageWebSocket3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SendNonfinalFrameAsync(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocket3", "SendNonfinalFrameAsync", 6usize, |this| ((**(this as *const *const IMessageWebSocket3_Vtbl)).SendNonfinalFrameAsync)(this, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SendFinalFrameAsync(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocket3", "SendFinalFrameAsync", 7usize, |this| ((**(this as *const *const IMessageWebSocket3_Vtbl)).SendFinalFrameAsync)(this, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            SendNonfinalFrameAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SendNonfinalFrameAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SendFinalFrameAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SendFinalFrameAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMessageWebSocket3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+impl windows_core::imp::ProxyVtable for IMessageWebSocketControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxMessageSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocketControl", "MaxMessageSize", 6usize, |this| ((**(this as *const *const IMessageWebSocketControl_Vtbl)).MaxMessageSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxMessageSize(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocketControl", "SetMaxMessageSize", 7usize, |this| ((**(this as *const *const IMessageWebSocketControl_Vtbl)).SetMaxMessageSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageType(this: *mut core::ffi::c_void, result__: *mut SocketMessageType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocketControl", "MessageType", 8usize, |this| ((**(this as *const *const IMessageWebSocketControl_Vtbl)).MessageType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageType(this: *mut core::ffi::c_void, value: SocketMessageType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocketControl", "SetMessageType", 9usize, |this| ((**(this as *const *const IMessageWebSocketControl_Vtbl)).SetMessageType)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaxMessageSize,
+            SetMaxMessageSize,
+            MessageType,
+            SetMessageType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMessageWebSocketControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Cryptography_Certificates")]
+impl windows_core::imp::ProxyVtable for IMessageWebSocketControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DesiredUnsolicitedPongInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocketControl2", "DesiredUnsolicitedPongInterval", 6usize, |this| ((**(this as *const *const IMessageWebSocketControl2_Vtbl)).DesiredUnsolicitedPongInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredUnsolicitedPongInterval(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocketControl2", "SetDesiredUnsolicitedPongInterval", 7usize, |this| ((**(this as *const *const IMessageWebSocketControl2_Vtbl)).SetDesiredUnsolicitedPongInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActualUnsolicitedPongInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocketControl2", "ActualUnsolicitedPongInterval", 8usize, |this| ((**(this as *const *const IMessageWebSocketControl2_Vtbl)).ActualUnsolicitedPongInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceiveMode(this: *mut core::ffi::c_void, result__: *mut MessageWebSocketReceiveMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocketControl2", "ReceiveMode", 9usize, |this| ((**(this as *const *const IMessageWebSocketControl2_Vtbl)).ReceiveMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReceiveMode(this: *mut core::ffi::c_void, value: MessageWebSocketReceiveMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocketControl2", "SetReceiveMode", 10usize, |this| ((**(this as *const *const IMessageWebSocketControl2_Vtbl)).SetReceiveMode)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn ClientCertificate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocketControl2", "ClientCertificate", 11usize, |this| ((**(this as *const *const IMessageWebSocketControl2_Vtbl)).ClientCertificate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn SetClientCertificate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocketControl2", "SetClientCertificate", 12usize, |this| ((**(this as *const *const IMessageWebSocketControl2_Vtbl)).SetClientCertificate)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DesiredUnsolicitedPongInterval,
+            SetDesiredUnsolicitedPongInterval,
+            ActualUnsolicitedPongInterval,
+            ReceiveMode,
+            SetReceiveMode,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            ClientCertificate,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            ClientCertificate: 0,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            SetClientCertificate,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            SetClientCertificate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMessageWebSocketControl2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMessageWebSocketMessageReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MessageType(this: *mut core::ffi::c_void, result__: *mut SocketMessageType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocketMessageReceivedEventArgs", "MessageType", 6usize, |this| ((**(this as *const *const IMessageWebSocketMessageReceivedEventArgs_Vtbl)).MessageType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetDataReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocketMessageReceivedEventArgs", "GetDataReader", 7usize, |this| ((**(this as *const *const IMessageWebSocketMessageReceivedEventArgs_Vtbl)).GetDataReader)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetDataStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocketMessageReceivedEventArgs", "GetDataStream", 8usize, |this| ((**(this as *const *const IMessageWebSocketMessageReceivedEventArgs_Vtbl)).GetDataStream)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MessageType,
+            #[cfg(feature = "Storage_Streams")]
+            GetDataReader,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetDataReader: 0,
+            #[cfg(feature = "Storage_Streams")]
+            GetDataStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetDataStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMessageWebSocketMessageReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMessageWebSocketMessageReceivedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsMessageComplete(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageWebSocketMessageReceivedEventArgs2", "IsMessageComplete", 6usize, |this| ((**(this as *const *const IMessageWebSocketMessageReceivedEventArgs2_Vtbl)).IsMessageComplete)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsMessageComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMessageWebSocketMessageReceivedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IServerMessageWebSocket_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MessageReceived(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerMessageWebSocket", "MessageReceived", 6usize, |this| ((**(this as *const *const IServerMessageWebSocket_Vtbl)).MessageReceived)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMessageReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerMessageWebSocket", "RemoveMessageReceived", 7usize, |this| ((**(this as *const *const IServerMessageWebSocket_Vtbl)).RemoveMessageReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Control(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerMessageWebSocket", "Control", 8usize, |this| ((**(this as *const *const IServerMessageWebSocket_Vtbl)).Control)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Information(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerMessageWebSocket", "Information", 9usize, |this| ((**(this as *const *const IServerMessageWebSocket_Vtbl)).Information)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn OutputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerMessageWebSocket", "OutputStream", 10usize, |this| ((**(this as *const *const IServerMessageWebSocket_Vtbl)).OutputStream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerMessageWebSocket", "Closed", 11usize, |this| ((**(this as *const *const IServerMessageWebSocket_Vtbl)).Closed)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerMessageWebSocket", "RemoveClosed", 12usize, |this| ((**(this as *const *const IServerMessageWebSocket_Vtbl)).RemoveClosed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseWithStatus(this: *mut core::ffi::c_void, code: u16, reason: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerMessageWebSocket", "CloseWithStatus", 13usize, |this| ((**(this as *const *const IServerMessageWebSocket_Vtbl)).CloseWithStatus)(this, code, reason), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MessageReceived,
+            RemoveMessageReceived,
+            Control,
+            Information,
+            #[cfg(feature = "Storage_Streams")]
+            OutputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            OutputStream: 0,
+            Closed,
+            RemoveClosed,
+            CloseWithStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServerMessageWebSocket as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServerMessageWebSocketControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MessageType(this: *mut core::ffi::c_void, result__: *mut SocketMessageType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerMessageWebSocketControl", "MessageType", 6usize, |this| ((**(this as *const *const IServerMessageWebSocketControl_Vtbl)).MessageType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageType(this: *mut core::ffi::c_void, value: SocketMessageType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerMessageWebSocketControl", "SetMessageType", 7usize, |this| ((**(this as *const *const IServerMessageWebSocketControl_Vtbl)).SetMessageType)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MessageType, SetMessageType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServerMessageWebSocketControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServerMessageWebSocketInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BandwidthStatistics(this: *mut core::ffi::c_void, result__: *mut BandwidthStatistics) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerMessageWebSocketInformation", "BandwidthStatistics", 6usize, |this| ((**(this as *const *const IServerMessageWebSocketInformation_Vtbl)).BandwidthStatistics)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Protocol(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerMessageWebSocketInformation", "Protocol", 7usize, |this| ((**(this as *const *const IServerMessageWebSocketInformation_Vtbl)).Protocol)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerMessageWebSocketInformation", "LocalAddress", 8usize, |this| ((**(this as *const *const IServerMessageWebSocketInformation_Vtbl)).LocalAddress)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BandwidthStatistics, Protocol, LocalAddress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServerMessageWebSocketInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IServerStreamWebSocket_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Information(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerStreamWebSocket", "Information", 6usize, |this| ((**(this as *const *const IServerStreamWebSocket_Vtbl)).Information)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn InputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerStreamWebSocket", "InputStream", 7usize, |this| ((**(this as *const *const IServerStreamWebSocket_Vtbl)).InputStream)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn OutputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerStreamWebSocket", "OutputStream", 8usize, |this| ((**(this as *const *const IServerStreamWebSocket_Vtbl)).OutputStream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerStreamWebSocket", "Closed", 9usize, |this| ((**(this as *const *const IServerStreamWebSocket_Vtbl)).Closed)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerStreamWebSocket", "RemoveClosed", 10usize, |this| ((**(this as *const *const IServerStreamWebSocket_Vtbl)).RemoveClosed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseWithStatus(this: *mut core::ffi::c_void, code: u16, reason: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerStreamWebSocket", "CloseWithStatus", 11usize, |this| ((**(this as *const *const IServerStreamWebSocket_Vtbl)).CloseWithStatus)(this, code, reason), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Information,
+            #[cfg(feature = "Storage_Streams")]
+            InputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            InputStream: 0,
+            #[cfg(feature = "Storage_Streams")]
+            OutputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            OutputStream: 0,
+            Closed,
+            RemoveClosed,
+            CloseWithStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServerStreamWebSocket as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServerStreamWebSocketInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BandwidthStatistics(this: *mut core::ffi::c_void, result__: *mut BandwidthStatistics) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerStreamWebSocketInformation", "BandwidthStatistics", 6usize, |this| ((**(this as *const *const IServerStreamWebSocketInformation_Vtbl)).BandwidthStatistics)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Protocol(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerStreamWebSocketInformation", "Protocol", 7usize, |this| ((**(this as *const *const IServerStreamWebSocketInformation_Vtbl)).Protocol)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerStreamWebSocketInformation", "LocalAddress", 8usize, |this| ((**(this as *const *const IServerStreamWebSocketInformation_Vtbl)).LocalAddress)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BandwidthStatistics, Protocol, LocalAddress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServerStreamWebSocketInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISocketActivityContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISocketActivityContext", "Data", 6usize, |this| ((**(this as *const *const ISocketActivityContext_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Data,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Data: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISocketActivityContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISocketActivityContextFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISocketActivityContextFactory", "Create", 6usize, |this| ((**(this as *const *const ISocketActivityContextFactory_Vtbl)).Create)(this, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Create,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISocketActivityContextFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISocketActivityInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TaskId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISocketActivityInformation", "TaskId", 6usize, |this| ((**(this as *const *const ISocketActivityInformation_Vtbl)).TaskId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISocketActivityInformation", "Id", 7usize, |this| ((**(this as *const *const ISocketActivityInformation_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SocketKind(this: *mut core::ffi::c_void, result__: *mut SocketActivityKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISocketActivityInformation", "SocketKind", 8usize, |this| ((**(this as *const *const ISocketActivityInformation_Vtbl)).SocketKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Context(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISocketActivityInformation", "Context", 9usize, |this| ((**(this as *const *const ISocketActivityInformation_Vtbl)).Context)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DatagramSocket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISocketActivityInformation", "DatagramSocket", 10usize, |this| ((**(this as *const *const ISocketActivityInformation_Vtbl)).DatagramSocket)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StreamSocket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISocketActivityInformation", "StreamSocket", 11usize, |this| ((**(this as *const *const ISocketActivityInformation_Vtbl)).StreamSocket)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StreamSocketListener(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISocketActivityInformation", "StreamSocketListener", 12usize, |this| ((**(this as *const *const ISocketActivityInformation_Vtbl)).StreamSocketListener)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TaskId,
+            Id,
+            SocketKind,
+            Context,
+            DatagramSocket,
+            StreamSocket,
+            StreamSocketListener,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISocketActivityInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISocketActivityInformationStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AllSockets(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISocketActivityInformationStatics", "AllSockets", 6usize, |this| ((**(this as *const *const ISocketActivityInformationStatics_Vtbl)).AllSockets)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AllSockets,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AllSockets: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISocketActivityInformationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISocketActivityTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut SocketActivityTriggerReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISocketActivityTriggerDetails", "Reason", 6usize, |this| ((**(this as *const *const ISocketActivityTriggerDetails_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SocketInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISocketActivityTriggerDetails", "SocketInformation", 7usize, |this| ((**(this as *const *const ISocketActivityTriggerDetails_Vtbl)).SocketInformation)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reason, SocketInformation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISocketActivityTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISocketErrorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, hresult: i32, result__: *mut SocketErrorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISocketErrorStatics", "GetStatus", 6usize, |this| ((**(this as *const *const ISocketErrorStatics_Vtbl)).GetStatus)(this, hresult, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISocketErrorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IStreamSocket_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Control(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket", "Control", 6usize, |this| ((**(this as *const *const IStreamSocket_Vtbl)).Control)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Information(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket", "Information", 7usize, |this| ((**(this as *const *const IStreamSocket_Vtbl)).Information)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn InputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket", "InputStream", 8usize, |this| ((**(this as *const *const IStreamSocket_Vtbl)).InputStream)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn OutputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket", "OutputStream", 9usize, |this| ((**(this as *const *const IStreamSocket_Vtbl)).OutputStream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectWithEndpointPairAsync(this: *mut core::ffi::c_void, endpointpair: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket", "ConnectWithEndpointPairAsync", 10usize, |this| ((**(this as *const *const IStreamSocket_Vtbl)).ConnectWithEndpointPairAsync)(this, endpointpair, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectAsync(this: *mut core::ffi::c_void, remotehostname: *mut core::ffi::c_void, remoteservicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket", "ConnectAsync", 11usize, |this| ((**(this as *const *const IStreamSocket_Vtbl)).ConnectAsync)(this, remotehostname, remoteservicename, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectWithEndpointPairAndProtectionLevelAsync(this: *mut core::ffi::c_void, endpointpair: *mut core::ffi::c_void, protectionlevel: SocketProtectionLevel, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket", "ConnectWithEndpointPairAndProtectionLevelAsync", 12usize, |this| ((**(this as *const *const IStreamSocket_Vtbl)).ConnectWithEndpointPairAndProtectionLevelAsync)(this, endpointpair, protectionlevel, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectWithProtectionLevelAsync(this: *mut core::ffi::c_void, remotehostname: *mut core::ffi::c_void, remoteservicename: core::mem::MaybeUninit<windows_core::HSTRING>, protectionlevel: SocketProtectionLevel, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket", "ConnectWithProtectionLevelAsync", 13usize, |this| ((**(this as *const *const IStreamSocket_Vtbl)).ConnectWithProtectionLevelAsync)(this, remotehostname, remoteservicename, protectionlevel, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpgradeToSslAsync(this: *mut core::ffi::c_void, protectionlevel: SocketProtectionLevel, validationhostname: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket", "UpgradeToSslAsync", 14usize, |this| ((**(this as *const *const IStreamSocket_Vtbl)).UpgradeToSslAsync)(this, protectionlevel, validationhostname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Control,
+            Information,
+            #[cfg(feature = "Storage_Streams")]
+            InputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            InputStream: 0,
+            #[cfg(feature = "Storage_Streams")]
+            OutputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            OutputStream: 0,
+            ConnectWithEndpointPairAsync,
+            ConnectAsync,
+            ConnectWithEndpointPairAndProtectionLevelAsync,
+            ConnectWithProtectionLevelAsync,
+            UpgradeToSslAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocket as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking_Connectivity")]
+impl windows_core::imp::ProxyVtable for IStreamSocket2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Networking_Connectivity")]
+        unsafe extern "system" fn ConnectWithProtectionLevelAndAdapterAsync(this: *mut core::ffi::c_void, remotehostname: *mut core::ffi::c_void, remoteservicename: core::mem::MaybeUninit<windows_core::HSTRING>, protectionlevel: SocketProtectionLevel, adapter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket2", "ConnectWithProtectionLevelAndAdapterAsync", 6usize, |this| ((**(this as *const *const IStreamSocket2_Vtbl)).ConnectWithProtectionLevelAndAdapterAsync)(this, remotehostname, remoteservicename, protectionlevel, adapter, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Networking_Connectivity")]
+            ConnectWithProtectionLevelAndAdapterAsync,
+            #[cfg(not(feature = "Networking_Connectivity"))]
+            ConnectWithProtectionLevelAndAdapterAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocket2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamSocket3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CancelIOAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket3", "CancelIOAsync", 6usize, |this| ((**(this as *const *const IStreamSocket3_Vtbl)).CancelIOAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableTransferOwnership(this: *mut core::ffi::c_void, taskid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket3", "EnableTransferOwnership", 7usize, |this| ((**(this as *const *const IStreamSocket3_Vtbl)).EnableTransferOwnership)(this, taskid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableTransferOwnershipWithConnectedStandbyAction(this: *mut core::ffi::c_void, taskid: windows_core::GUID, connectedstandbyaction: SocketActivityConnectedStandbyAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket3", "EnableTransferOwnershipWithConnectedStandbyAction", 8usize, |this| ((**(this as *const *const IStreamSocket3_Vtbl)).EnableTransferOwnershipWithConnectedStandbyAction)(this, taskid, connectedstandbyaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferOwnership(this: *mut core::ffi::c_void, socketid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket3", "TransferOwnership", 9usize, |this| ((**(this as *const *const IStreamSocket3_Vtbl)).TransferOwnership)(this, socketid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferOwnershipWithContext(this: *mut core::ffi::c_void, socketid: core::mem::MaybeUninit<windows_core::HSTRING>, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket3", "TransferOwnershipWithContext", 10usize, |this| ((**(this as *const *const IStreamSocket3_Vtbl)).TransferOwnershipWithContext)(this, socketid, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferOwnershipWithContextAndKeepAliveTime(this: *mut core::ffi::c_void, socketid: core::mem::MaybeUninit<windows_core::HSTRING>, data: *mut core::ffi::c_void, keepalivetime: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocket3", "TransferOwnershipWithContextAndKeepAliveTime", 11usize, |this| ((**(this as *const *const IStreamSocket3_Vtbl)).TransferOwnershipWithContextAndKeepAliveTime)(this, socketid, data, keepalivetime), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CancelIOAsync,
+            EnableTransferOwnership,
+            EnableTransferOwnershipWithConnectedStandbyAction,
+            TransferOwnership,
+            TransferOwnershipWithContext,
+            TransferOwnershipWithContextAndKeepAliveTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocket3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamSocketControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NoDelay(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl", "NoDelay", 6usize, |this| ((**(this as *const *const IStreamSocketControl_Vtbl)).NoDelay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNoDelay(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl", "SetNoDelay", 7usize, |this| ((**(this as *const *const IStreamSocketControl_Vtbl)).SetNoDelay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeepAlive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl", "KeepAlive", 8usize, |this| ((**(this as *const *const IStreamSocketControl_Vtbl)).KeepAlive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeepAlive(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl", "SetKeepAlive", 9usize, |this| ((**(this as *const *const IStreamSocketControl_Vtbl)).SetKeepAlive)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutboundBufferSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl", "OutboundBufferSizeInBytes", 10usize, |this| ((**(this as *const *const IStreamSocketControl_Vtbl)).OutboundBufferSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutboundBufferSizeInBytes(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl", "SetOutboundBufferSizeInBytes", 11usize, |this| ((**(this as *const *const IStreamSocketControl_Vtbl)).SetOutboundBufferSizeInBytes)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn QualityOfService(this: *mut core::ffi::c_void, result__: *mut SocketQualityOfService) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl", "QualityOfService", 12usize, |this| ((**(this as *const *const IStreamSocketControl_Vtbl)).QualityOfService)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQualityOfService(this: *mut core::ffi::c_void, value: SocketQualityOfService) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl", "SetQualityOfService", 13usize, |this| ((**(this as *const *const IStreamSocketControl_Vtbl)).SetQualityOfService)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutboundUnicastHopLimit(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl", "OutboundUnicastHopLimit", 14usize, |this| ((**(this as *const *const IStreamSocketControl_Vtbl)).OutboundUnicastHopLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutboundUnicastHopLimit(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl", "SetOutboundUnicastHopLimit", 15usize, |this| ((**(this as *const *const IStreamSocketControl_Vtbl)).SetOutboundUnicastHopLimit)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NoDelay,
+            SetNoDelay,
+            KeepAlive,
+            SetKeepAlive,
+            OutboundBufferSizeInBytes,
+            SetOutboundBufferSizeInBytes,
+            QualityOfService,
+            SetQualityOfService,
+            OutboundUnicastHopLimit,
+            SetOutboundUnicastHopLimit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocketControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+impl windows_core::imp::ProxyVtable for IStreamSocketControl2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn IgnorableServerCertificateErrors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl2", "IgnorableServerCertificateErrors", 6usize, |this| ((**(this as *const *const IStreamSocketControl2_Vtbl)).IgnorableServerCertificateErrors)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            IgnorableServerCertificateErrors,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            IgnorableServerCertificateErrors: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocketControl2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Cryptography_Certificates")]
+impl windows_core::imp::ProxyVtable for IStreamSocketControl3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SerializeConnectionAttempts(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl3", "SerializeConnectionAttempts", 6usize, |this| ((**(this as *const *const IStreamSocketControl3_Vtbl)).SerializeConnectionAttempts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSerializeConnectionAttempts(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl3", "SetSerializeConnectionAttempts", 7usize, |this| ((**(this as *const *const IStreamSocketControl3_Vtbl)).SetSerializeConnectionAttempts)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn ClientCertificate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl3", "ClientCertificate", 8usize, |this| ((**(this as *const *const IStreamSocketControl3_Vtbl)).ClientCertificate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn SetClientCertificate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl3", "SetClientCertificate", 9usize, |this| ((**(this as *const *const IStreamSocketControl3_Vtbl)).SetClientCertificate)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SerializeConnectionAttempts,
+            SetSerializeConnectionAttempts,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            ClientCertificate,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            ClientCertificate: 0,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            SetClientCertificate,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            SetClientCertificate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocketControl3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamSocketControl4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinProtectionLevel(this: *mut core::ffi::c_void, result__: *mut SocketProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl4", "MinProtectionLevel", 6usize, |this| ((**(this as *const *const IStreamSocketControl4_Vtbl)).MinProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinProtectionLevel(this: *mut core::ffi::c_void, value: SocketProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketControl4", "SetMinProtectionLevel", 7usize, |this| ((**(this as *const *const IStreamSocketControl4_Vtbl)).SetMinProtectionLevel)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MinProtectionLevel, SetMinProtectionLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocketControl4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IStreamSocketInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LocalAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketInformation", "LocalAddress", 6usize, |this| ((**(this as *const *const IStreamSocketInformation_Vtbl)).LocalAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalPort(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketInformation", "LocalPort", 7usize, |this| ((**(this as *const *const IStreamSocketInformation_Vtbl)).LocalPort)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteHostName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketInformation", "RemoteHostName", 8usize, |this| ((**(this as *const *const IStreamSocketInformation_Vtbl)).RemoteHostName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketInformation", "RemoteAddress", 9usize, |this| ((**(this as *const *const IStreamSocketInformation_Vtbl)).RemoteAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteServiceName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketInformation", "RemoteServiceName", 10usize, |this| ((**(this as *const *const IStreamSocketInformation_Vtbl)).RemoteServiceName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemotePort(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketInformation", "RemotePort", 11usize, |this| ((**(this as *const *const IStreamSocketInformation_Vtbl)).RemotePort)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoundTripTimeStatistics(this: *mut core::ffi::c_void, result__: *mut RoundTripTimeStatistics) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketInformation", "RoundTripTimeStatistics", 12usize, |this| ((**(this as *const *const IStreamSocketInformation_Vtbl)).RoundTripTimeStatistics)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BandwidthStatistics(this: *mut core::ffi::c_void, result__: *mut BandwidthStatistics) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketInformation", "BandwidthStatistics", 13usize, |this| ((**(this as *const *const IStreamSocketInformation_Vtbl)).BandwidthStatistics)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectionLevel(this: *mut core::ffi::c_void, result__: *mut SocketProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketInformation", "ProtectionLevel", 14usize, |this| ((**(this as *const *const IStreamSocketInformation_Vtbl)).ProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SessionKey(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketInformation", "SessionKey", 15usize, |this| ((**(this as *const *const IStreamSocketInformation_Vtbl)).SessionKey)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LocalAddress,
+            LocalPort,
+            RemoteHostName,
+            RemoteAddress,
+            RemoteServiceName,
+            RemotePort,
+            RoundTripTimeStatistics,
+            BandwidthStatistics,
+            ProtectionLevel,
+            #[cfg(feature = "Storage_Streams")]
+            SessionKey,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SessionKey: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocketInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+impl windows_core::imp::ProxyVtable for IStreamSocketInformation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServerCertificateErrorSeverity(this: *mut core::ffi::c_void, result__: *mut SocketSslErrorSeverity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketInformation2", "ServerCertificateErrorSeverity", 6usize, |this| ((**(this as *const *const IStreamSocketInformation2_Vtbl)).ServerCertificateErrorSeverity)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn ServerCertificateErrors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketInformation2", "ServerCertificateErrors", 7usize, |this| ((**(this as *const *const IStreamSocketInformation2_Vtbl)).ServerCertificateErrors)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn ServerCertificate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketInformation2", "ServerCertificate", 8usize, |this| ((**(this as *const *const IStreamSocketInformation2_Vtbl)).ServerCertificate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn ServerIntermediateCertificates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketInformation2", "ServerIntermediateCertificates", 9usize, |this| ((**(this as *const *const IStreamSocketInformation2_Vtbl)).ServerIntermediateCertificates)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServerCertificateErrorSeverity,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            ServerCertificateErrors,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            ServerCertificateErrors: 0,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            ServerCertificate,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            ServerCertificate: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            ServerIntermediateCertificates,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            ServerIntermediateCertificates: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocketInformation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamSocketListener_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Control(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListener", "Control", 6usize, |this| ((**(this as *const *const IStreamSocketListener_Vtbl)).Control)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Information(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListener", "Information", 7usize, |this| ((**(this as *const *const IStreamSocketListener_Vtbl)).Information)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindServiceNameAsync(this: *mut core::ffi::c_void, localservicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListener", "BindServiceNameAsync", 8usize, |this| ((**(this as *const *const IStreamSocketListener_Vtbl)).BindServiceNameAsync)(this, localservicename, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindEndpointAsync(this: *mut core::ffi::c_void, localhostname: *mut core::ffi::c_void, localservicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListener", "BindEndpointAsync", 9usize, |this| ((**(this as *const *const IStreamSocketListener_Vtbl)).BindEndpointAsync)(this, localhostname, localservicename, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionReceived(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListener", "ConnectionReceived", 10usize, |this| ((**(this as *const *const IStreamSocketListener_Vtbl)).ConnectionReceived)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConnectionReceived(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListener", "RemoveConnectionReceived", 11usize, |this| ((**(this as *const *const IStreamSocketListener_Vtbl)).RemoveConnectionReceived)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Control,
+            Information,
+            BindServiceNameAsync,
+            BindEndpointAsync,
+            ConnectionReceived,
+            RemoveConnectionReceived,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocketListener as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking_Connectivity")]
+impl windows_core::imp::ProxyVtable for IStreamSocketListener2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BindServiceNameWithProtectionLevelAsync(this: *mut core::ffi::c_void, localservicename: core::mem::MaybeUninit<windows_core::HSTRING>, protectionlevel: SocketProtectionLevel, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListener2", "BindServiceNameWithProtectionLevelAsync", 6usize, |this| ((**(this as *const *const IStreamSocketListener2_Vtbl)).BindServiceNameWithProtectionLevelAsync)(this, localservicename, protectionlevel, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Connectivity")]
+        unsafe extern "system" fn BindServiceNameWithProtectionLevelAndAdapterAsync(this: *mut core::ffi::c_void, localservicename: core::mem::MaybeUninit<windows_core::HSTRING>, protectionlevel: SocketProtectionLevel, adapter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListener2", "BindServiceNameWithProtectionLevelAndAdapterAsync", 7usize, |this| ((**(this as *const *const IStreamSocketListener2_Vtbl)).BindServiceNameWithProtectionLevelAndAdapterAsync)(this, localservicename, protectionlevel, adapter, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BindServiceNameWithProtectionLevelAsync,
+            #[cfg(feature = "Networking_Connectivity")]
+            BindServiceNameWithProtectionLevelAndAdapterAsync,
+            #[cfg(not(feature = "Networking_Connectivity"))]
+            BindServiceNameWithProtectionLevelAndAdapterAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocketListener2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamSocketListener3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CancelIOAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListener3", "CancelIOAsync", 6usize, |this| ((**(this as *const *const IStreamSocketListener3_Vtbl)).CancelIOAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableTransferOwnership(this: *mut core::ffi::c_void, taskid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListener3", "EnableTransferOwnership", 7usize, |this| ((**(this as *const *const IStreamSocketListener3_Vtbl)).EnableTransferOwnership)(this, taskid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableTransferOwnershipWithConnectedStandbyAction(this: *mut core::ffi::c_void, taskid: windows_core::GUID, connectedstandbyaction: SocketActivityConnectedStandbyAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListener3", "EnableTransferOwnershipWithConnectedStandbyAction", 8usize, |this| ((**(this as *const *const IStreamSocketListener3_Vtbl)).EnableTransferOwnershipWithConnectedStandbyAction)(this, taskid, connectedstandbyaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferOwnership(this: *mut core::ffi::c_void, socketid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListener3", "TransferOwnership", 9usize, |this| ((**(this as *const *const IStreamSocketListener3_Vtbl)).TransferOwnership)(this, socketid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferOwnershipWithContext(this: *mut core::ffi::c_void, socketid: core::mem::MaybeUninit<windows_core::HSTRING>, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListener3", "TransferOwnershipWithContext", 10usize, |this| ((**(this as *const *const IStreamSocketListener3_Vtbl)).TransferOwnershipWithContext)(this, socketid, data), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CancelIOAsync,
+            EnableTransferOwnership,
+            EnableTransferOwnershipWithConnectedStandbyAction,
+            TransferOwnership,
+            TransferOwnershipWithContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocketListener3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamSocketListenerConnectionReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Socket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListenerConnectionReceivedEventArgs", "Socket", 6usize, |this| ((**(this as *const *const IStreamSocketListenerConnectionReceivedEventArgs_Vtbl)).Socket)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Socket }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocketListenerConnectionReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamSocketListenerControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QualityOfService(this: *mut core::ffi::c_void, result__: *mut SocketQualityOfService) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListenerControl", "QualityOfService", 6usize, |this| ((**(this as *const *const IStreamSocketListenerControl_Vtbl)).QualityOfService)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQualityOfService(this: *mut core::ffi::c_void, value: SocketQualityOfService) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListenerControl", "SetQualityOfService", 7usize, |this| ((**(this as *const *const IStreamSocketListenerControl_Vtbl)).SetQualityOfService)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QualityOfService, SetQualityOfService }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocketListenerControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamSocketListenerControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NoDelay(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListenerControl2", "NoDelay", 6usize, |this| ((**(this as *const *const IStreamSocketListenerControl2_Vtbl)).NoDelay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNoDelay(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListenerControl2", "SetNoDelay", 7usize, |this| ((**(this as *const *const IStreamSocketListenerControl2_Vtbl)).SetNoDelay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeepAlive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListenerControl2", "KeepAlive", 8usize, |this| ((**(this as *const *const IStreamSocketListenerControl2_Vtbl)).KeepAlive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeepAlive(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListenerControl2", "SetKeepAlive", 9usize, |this| ((**(this as *const *const IStreamSocketListenerControl2_Vtbl)).SetKeepAlive)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutboundBufferSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListenerControl2", "OutboundBufferSizeInBytes", 10usize, |this| ((**(this as *const *const IStreamSocketListenerControl2_Vtbl)).OutboundBufferSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutboundBufferSizeInBytes(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListenerControl2", "SetOutboundBufferSizeInBytes", 11usize, |this| ((**(this as *const *const IStreamSocketListenerControl2_Vtbl)).SetOutboundBufferSizeInBytes)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutboundUnicastHopLimit(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListenerControl2", "OutboundUnicastHopLimit", 12usize, |this| ((**(this as *const *const IStreamSocketListenerControl2_Vtbl)).OutboundUnicastHopLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutboundUnicastHopLimit(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListenerControl2", "SetOutboundUnicastHopLimit", 13usize, |this| ((**(this as *const *const IStreamSocketListenerControl2_Vtbl)).SetOutboundUnicastHopLimit)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NoDelay,
+            SetNoDelay,
+            KeepAlive,
+            SetKeepAlive,
+            OutboundBufferSizeInBytes,
+            SetOutboundBufferSizeInBytes,
+            OutboundUnicastHopLimit,
+            SetOutboundUnicastHopLimit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocketListenerControl2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamSocketListenerInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LocalPort(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketListenerInformation", "LocalPort", 6usize, |this| ((**(this as *const *const IStreamSocketListenerInformation_Vtbl)).LocalPort)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LocalPort }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocketListenerInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStreamSocketStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetEndpointPairsAsync(this: *mut core::ffi::c_void, remotehostname: *mut core::ffi::c_void, remoteservicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketStatics", "GetEndpointPairsAsync", 6usize, |this| ((**(this as *const *const IStreamSocketStatics_Vtbl)).GetEndpointPairsAsync)(this, remotehostname, remoteservicename, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetEndpointPairsWithSortOptionsAsync(this: *mut core::ffi::c_void, remotehostname: *mut core::ffi::c_void, remoteservicename: core::mem::MaybeUninit<windows_core::HSTRING>, sortoptions: super::HostNameSortOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSocketStatics", "GetEndpointPairsWithSortOptionsAsync", 7usize, |this| ((**(this as *const *const IStreamSocketStatics_Vtbl)).GetEndpointPairsWithSortOptionsAsync)(this, remotehostname, remoteservicename, sortoptions, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetEndpointPairsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetEndpointPairsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetEndpointPairsWithSortOptionsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetEndpointPairsWithSortOptionsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSocketStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IStreamWebSocket_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Control(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamWebSocket", "Control", 6usize, |this| ((**(this as *const *const IStreamWebSocket_Vtbl)).Control)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Information(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamWebSocket", "Information", 7usize, |this| ((**(this as *const *const IStreamWebSocket_Vtbl)).Information)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn InputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamWebSocket", "InputStream", 8usize, |this| ((**(this as *const *const IStreamWebSocket_Vtbl)).InputStream)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Control,
+            Information,
+            #[cfg(feature = "Storage_Streams")]
+            InputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            InputStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamWebSocket as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IStreamWebSocket2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServerCustomValidationRequested(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamWebSocket2", "ServerCustomValidationRequested", 6usize, |this| ((**(this as *const *const IStreamWebSocket2_Vtbl)).ServerCustomValidationRequested)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveServerCustomValidationRequested(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamWebSocket2", "RemoveServerCustomValidationRequested", 7usize, |this| ((**(this as *const *const IStreamWebSocket2_Vtbl)).RemoveServerCustomValidationRequested)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServerCustomValidationRequested,
+            RemoveServerCustomValidationRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamWebSocket2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+impl windows_core::imp::ProxyVtable for IStreamWebSocketControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NoDelay(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamWebSocketControl", "NoDelay", 6usize, |this| ((**(this as *const *const IStreamWebSocketControl_Vtbl)).NoDelay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNoDelay(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamWebSocketControl", "SetNoDelay", 7usize, |this| ((**(this as *const *const IStreamWebSocketControl_Vtbl)).SetNoDelay)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NoDelay, SetNoDelay }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamWebSocketControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Cryptography_Certificates")]
+impl windows_core::imp::ProxyVtable for IStreamWebSocketControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DesiredUnsolicitedPongInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamWebSocketControl2", "DesiredUnsolicitedPongInterval", 6usize, |this| ((**(this as *const *const IStreamWebSocketControl2_Vtbl)).DesiredUnsolicitedPongInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredUnsolicitedPongInterval(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamWebSocketControl2", "SetDesiredUnsolicitedPongInterval", 7usize, |this| ((**(this as *const *const IStreamWebSocketControl2_Vtbl)).SetDesiredUnsolicitedPongInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActualUnsolicitedPongInterval(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamWebSocketControl2", "ActualUnsolicitedPongInterval", 8usize, |this| ((**(this as *const *const IStreamWebSocketControl2_Vtbl)).ActualUnsolicitedPongInterval)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn ClientCertificate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamWebSocketControl2", "ClientCertificate", 9usize, |this| ((**(this as *const *const IStreamWebSocketControl2_Vtbl)).ClientCertificate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn SetClientCertificate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamWebSocketControl2", "SetClientCertificate", 10usize, |this| ((**(this as *const *const IStreamWebSocketControl2_Vtbl)).SetClientCertificate)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DesiredUnsolicitedPongInterval,
+            SetDesiredUnsolicitedPongInterval,
+            ActualUnsolicitedPongInterval,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            ClientCertificate,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            ClientCertificate: 0,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            SetClientCertificate,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            SetClientCertificate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamWebSocketControl2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IWebSocket_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn OutputStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocket", "OutputStream", 6usize, |this| ((**(this as *const *const IWebSocket_Vtbl)).OutputStream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocket", "ConnectAsync", 7usize, |this| ((**(this as *const *const IWebSocket_Vtbl)).ConnectAsync)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestHeader(this: *mut core::ffi::c_void, headername: core::mem::MaybeUninit<windows_core::HSTRING>, headervalue: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocket", "SetRequestHeader", 8usize, |this| ((**(this as *const *const IWebSocket_Vtbl)).SetRequestHeader)(this, headername, headervalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocket", "Closed", 9usize, |this| ((**(this as *const *const IWebSocket_Vtbl)).Closed)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocket", "RemoveClosed", 10usize, |this| ((**(this as *const *const IWebSocket_Vtbl)).RemoveClosed)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseWithStatus(this: *mut core::ffi::c_void, code: u16, reason: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocket", "CloseWithStatus", 11usize, |this| ((**(this as *const *const IWebSocket_Vtbl)).CloseWithStatus)(this, code, reason), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            OutputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            OutputStream: 0,
+            ConnectAsync,
+            SetRequestHeader,
+            Closed,
+            RemoveClosed,
+            CloseWithStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebSocket as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebSocketClosedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Code(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketClosedEventArgs", "Code", 6usize, |this| ((**(this as *const *const IWebSocketClosedEventArgs_Vtbl)).Code)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketClosedEventArgs", "Reason", 7usize, |this| ((**(this as *const *const IWebSocketClosedEventArgs_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Code, Reason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebSocketClosedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+impl windows_core::imp::ProxyVtable for IWebSocketControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OutboundBufferSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketControl", "OutboundBufferSizeInBytes", 6usize, |this| ((**(this as *const *const IWebSocketControl_Vtbl)).OutboundBufferSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutboundBufferSizeInBytes(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketControl", "SetOutboundBufferSizeInBytes", 7usize, |this| ((**(this as *const *const IWebSocketControl_Vtbl)).SetOutboundBufferSizeInBytes)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn ServerCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketControl", "ServerCredential", 8usize, |this| ((**(this as *const *const IWebSocketControl_Vtbl)).ServerCredential)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetServerCredential(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketControl", "SetServerCredential", 9usize, |this| ((**(this as *const *const IWebSocketControl_Vtbl)).SetServerCredential)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn ProxyCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketControl", "ProxyCredential", 10usize, |this| ((**(this as *const *const IWebSocketControl_Vtbl)).ProxyCredential)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetProxyCredential(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketControl", "SetProxyCredential", 11usize, |this| ((**(this as *const *const IWebSocketControl_Vtbl)).SetProxyCredential)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedProtocols(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketControl", "SupportedProtocols", 12usize, |this| ((**(this as *const *const IWebSocketControl_Vtbl)).SupportedProtocols)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OutboundBufferSizeInBytes,
+            SetOutboundBufferSizeInBytes,
+            #[cfg(feature = "Security_Credentials")]
+            ServerCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            ServerCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetServerCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetServerCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            ProxyCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            ProxyCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetProxyCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetProxyCredential: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedProtocols,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedProtocols: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebSocketControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "Security_Cryptography_Certificates"))]
+impl windows_core::imp::ProxyVtable for IWebSocketControl2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn IgnorableServerCertificateErrors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketControl2", "IgnorableServerCertificateErrors", 6usize, |this| ((**(this as *const *const IWebSocketControl2_Vtbl)).IgnorableServerCertificateErrors)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            IgnorableServerCertificateErrors,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            IgnorableServerCertificateErrors: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebSocketControl2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Web")]
+impl windows_core::imp::ProxyVtable for IWebSocketErrorStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Web")]
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, hresult: i32, result__: *mut super::super::Web::WebErrorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketErrorStatics", "GetStatus", 6usize, |this| ((**(this as *const *const IWebSocketErrorStatics_Vtbl)).GetStatus)(this, hresult, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Web")]
+            GetStatus,
+            #[cfg(not(feature = "Web"))]
+            GetStatus: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebSocketErrorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebSocketInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LocalAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketInformation", "LocalAddress", 6usize, |this| ((**(this as *const *const IWebSocketInformation_Vtbl)).LocalAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BandwidthStatistics(this: *mut core::ffi::c_void, result__: *mut BandwidthStatistics) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketInformation", "BandwidthStatistics", 7usize, |this| ((**(this as *const *const IWebSocketInformation_Vtbl)).BandwidthStatistics)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Protocol(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketInformation", "Protocol", 8usize, |this| ((**(this as *const *const IWebSocketInformation_Vtbl)).Protocol)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LocalAddress, BandwidthStatistics, Protocol }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebSocketInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+impl windows_core::imp::ProxyVtable for IWebSocketInformation2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn ServerCertificate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketInformation2", "ServerCertificate", 6usize, |this| ((**(this as *const *const IWebSocketInformation2_Vtbl)).ServerCertificate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerCertificateErrorSeverity(this: *mut core::ffi::c_void, result__: *mut SocketSslErrorSeverity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketInformation2", "ServerCertificateErrorSeverity", 7usize, |this| ((**(this as *const *const IWebSocketInformation2_Vtbl)).ServerCertificateErrorSeverity)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn ServerCertificateErrors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketInformation2", "ServerCertificateErrors", 8usize, |this| ((**(this as *const *const IWebSocketInformation2_Vtbl)).ServerCertificateErrors)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn ServerIntermediateCertificates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketInformation2", "ServerIntermediateCertificates", 9usize, |this| ((**(this as *const *const IWebSocketInformation2_Vtbl)).ServerIntermediateCertificates)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            ServerCertificate,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            ServerCertificate: 0,
+            ServerCertificateErrorSeverity,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            ServerCertificateErrors,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            ServerCertificateErrors: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            ServerIntermediateCertificates,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            ServerIntermediateCertificates: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebSocketInformation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+impl windows_core::imp::ProxyVtable for IWebSocketServerCustomValidationRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn ServerCertificate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketServerCustomValidationRequestedEventArgs", "ServerCertificate", 6usize, |this| ((**(this as *const *const IWebSocketServerCustomValidationRequestedEventArgs_Vtbl)).ServerCertificate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerCertificateErrorSeverity(this: *mut core::ffi::c_void, result__: *mut SocketSslErrorSeverity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketServerCustomValidationRequestedEventArgs", "ServerCertificateErrorSeverity", 7usize, |this| ((**(this as *const *const IWebSocketServerCustomValidationRequestedEventArgs_Vtbl)).ServerCertificateErrorSeverity)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn ServerCertificateErrors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketServerCustomValidationRequestedEventArgs", "ServerCertificateErrors", 8usize, |this| ((**(this as *const *const IWebSocketServerCustomValidationRequestedEventArgs_Vtbl)).ServerCertificateErrors)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn ServerIntermediateCertificates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketServerCustomValidationRequestedEventArgs", "ServerIntermediateCertificates", 9usize, |this| ((**(this as *const *const IWebSocketServerCustomValidationRequestedEventArgs_Vtbl)).ServerIntermediateCertificates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reject(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketServerCustomValidationRequestedEventArgs", "Reject", 10usize, |this| ((**(this as *const *const IWebSocketServerCustomValidationRequestedEventArgs_Vtbl)).Reject)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebSocketServerCustomValidationRequestedEventArgs", "GetDeferral", 11usize, |this| ((**(this as *const *const IWebSocketServerCustomValidationRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            ServerCertificate,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            ServerCertificate: 0,
+            ServerCertificateErrorSeverity,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            ServerCertificateErrors,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            ServerCertificateErrors: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            ServerIntermediateCertificates,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            ServerIntermediateCertificates: 0,
+            Reject,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebSocketServerCustomValidationRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -3846,6 +3846,7 @@ impl VpnAppIdType {
 impl windows_core::TypeKind for VpnAppIdType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VpnAppIdType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VpnAppIdType").field(&self.0).finish()
@@ -3866,6 +3867,7 @@ impl VpnAuthenticationMethod {
 impl windows_core::TypeKind for VpnAuthenticationMethod {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VpnAuthenticationMethod {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VpnAuthenticationMethod").field(&self.0).finish()
@@ -3884,6 +3886,7 @@ impl VpnChannelActivityEventType {
 impl windows_core::TypeKind for VpnChannelActivityEventType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VpnChannelActivityEventType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VpnChannelActivityEventType").field(&self.0).finish()
@@ -3903,6 +3906,7 @@ impl VpnChannelRequestCredentialsOptions {
 impl windows_core::TypeKind for VpnChannelRequestCredentialsOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VpnChannelRequestCredentialsOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VpnChannelRequestCredentialsOptions").field(&self.0).finish()
@@ -3959,6 +3963,7 @@ impl VpnCredentialType {
 impl windows_core::TypeKind for VpnCredentialType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VpnCredentialType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VpnCredentialType").field(&self.0).finish()
@@ -3977,6 +3982,7 @@ impl VpnDataPathType {
 impl windows_core::TypeKind for VpnDataPathType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VpnDataPathType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VpnDataPathType").field(&self.0).finish()
@@ -3996,6 +4002,7 @@ impl VpnDomainNameType {
 impl windows_core::TypeKind for VpnDomainNameType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VpnDomainNameType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VpnDomainNameType").field(&self.0).finish()
@@ -4019,6 +4026,7 @@ impl VpnIPProtocol {
 impl windows_core::TypeKind for VpnIPProtocol {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VpnIPProtocol {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VpnIPProtocol").field(&self.0).finish()
@@ -4039,6 +4047,7 @@ impl VpnManagementConnectionStatus {
 impl windows_core::TypeKind for VpnManagementConnectionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VpnManagementConnectionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VpnManagementConnectionStatus").field(&self.0).finish()
@@ -4074,6 +4083,7 @@ impl VpnManagementErrorStatus {
 impl windows_core::TypeKind for VpnManagementErrorStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VpnManagementErrorStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VpnManagementErrorStatus").field(&self.0).finish()
@@ -4093,6 +4103,7 @@ impl VpnNativeProtocolType {
 impl windows_core::TypeKind for VpnNativeProtocolType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VpnNativeProtocolType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VpnNativeProtocolType").field(&self.0).finish()
@@ -4111,6 +4122,7 @@ impl VpnPacketBufferStatus {
 impl windows_core::TypeKind for VpnPacketBufferStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VpnPacketBufferStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VpnPacketBufferStatus").field(&self.0).finish()
@@ -4129,6 +4141,7 @@ impl VpnRoutingPolicyType {
 impl windows_core::TypeKind for VpnRoutingPolicyType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VpnRoutingPolicyType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VpnRoutingPolicyType").field(&self.0).finish()
@@ -4139,3 +4152,5 @@ impl windows_core::RuntimeType for VpnRoutingPolicyType {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1712 @@
+impl windows_core::imp::ProxyVtable for IVpnAppId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut VpnAppIdType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnAppId", "Type", 6usize, |this| ((**(this as *const *const IVpnAppId_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, value: VpnAppIdType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnAppId", "SetType", 7usize, |this| ((**(this as *const *const IVpnAppId_Vtbl)).SetType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnAppId", "Value", 8usize, |this| ((**(this as *const *const IVpnAppId_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnAppId", "SetValue", 9usize, |this| ((**(this as *const *const IVpnAppId_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Type, SetType, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnAppId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnAppIdFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, r#type: VpnAppIdType, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnAppIdFactory", "Create", 6usize, |this| ((**(this as *const *const IVpnAppIdFactory_Vtbl)).Create)(this, r#type, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnAppIdFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+impl windows_core::imp::ProxyVtable for IVpnChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AssociateTransport(this: *mut core::ffi::c_void, mainoutertunneltransport: *mut core::ffi::c_void, optionaloutertunneltransport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "AssociateTransport", 6usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).AssociateTransport)(this, mainoutertunneltransport, optionaloutertunneltransport), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, assignedclientipv4list: *mut core::ffi::c_void, assignedclientipv6list: *mut core::ffi::c_void, vpninterfaceid: *mut core::ffi::c_void, routescope: *mut core::ffi::c_void, namespacescope: *mut core::ffi::c_void, mtusize: u32, maxframesize: u32, optimizeforlowcostnetwork: bool, mainoutertunneltransport: *mut core::ffi::c_void, optionaloutertunneltransport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "Start", 7usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).Start)(this, assignedclientipv4list, assignedclientipv6list, vpninterfaceid, routescope, namespacescope, mtusize, maxframesize, optimizeforlowcostnetwork, mainoutertunneltransport, optionaloutertunneltransport), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "Stop", 8usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn RequestCredentials(this: *mut core::ffi::c_void, credtype: VpnCredentialType, isretry: bool, issinglesignoncredential: bool, certificate: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "RequestCredentials", 9usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).RequestCredentials)(this, credtype, isretry, issinglesignoncredential, certificate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestVpnPacketBuffer(this: *mut core::ffi::c_void, r#type: VpnDataPathType, vpnpacketbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "RequestVpnPacketBuffer", 10usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).RequestVpnPacketBuffer)(this, r#type, vpnpacketbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogDiagnosticMessage(this: *mut core::ffi::c_void, message: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "LogDiagnosticMessage", 11usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).LogDiagnosticMessage)(this, message), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "Id", 12usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "Configuration", 13usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).Configuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivityChange(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "ActivityChange", 14usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).ActivityChange)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveActivityChange(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "RemoveActivityChange", 15usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).RemoveActivityChange)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlugInContext(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "SetPlugInContext", 16usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).SetPlugInContext)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlugInContext(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "PlugInContext", 17usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).PlugInContext)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemHealth(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "SystemHealth", 18usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).SystemHealth)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestCustomPrompt(this: *mut core::ffi::c_void, customprompt: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "RequestCustomPrompt", 19usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).RequestCustomPrompt)(this, customprompt), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetErrorMessage(this: *mut core::ffi::c_void, message: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "SetErrorMessage", 20usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).SetErrorMessage)(this, message), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowedSslTlsVersions(this: *mut core::ffi::c_void, tunneltransport: *mut core::ffi::c_void, usetls12: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel", "SetAllowedSslTlsVersions", 21usize, |this| ((**(this as *const *const IVpnChannel_Vtbl)).SetAllowedSslTlsVersions)(this, tunneltransport, usetls12), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AssociateTransport,
+            #[cfg(feature = "Foundation_Collections")]
+            Start,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Start: 0,
+            Stop,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            RequestCredentials,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            RequestCredentials: 0,
+            RequestVpnPacketBuffer,
+            LogDiagnosticMessage,
+            Id,
+            Configuration,
+            ActivityChange,
+            RemoveActivityChange,
+            SetPlugInContext,
+            PlugInContext,
+            SystemHealth,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestCustomPrompt,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestCustomPrompt: 0,
+            SetErrorMessage,
+            SetAllowedSslTlsVersions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnChannel as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+impl windows_core::imp::ProxyVtable for IVpnChannel2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StartWithMainTransport(this: *mut core::ffi::c_void, assignedclientipv4list: *mut core::ffi::c_void, assignedclientipv6list: *mut core::ffi::c_void, vpninterfaceid: *mut core::ffi::c_void, assignedroutes: *mut core::ffi::c_void, assigneddomainname: *mut core::ffi::c_void, mtusize: u32, maxframesize: u32, reserved: bool, mainoutertunneltransport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel2", "StartWithMainTransport", 6usize, |this| ((**(this as *const *const IVpnChannel2_Vtbl)).StartWithMainTransport)(this, assignedclientipv4list, assignedclientipv6list, vpninterfaceid, assignedroutes, assigneddomainname, mtusize, maxframesize, reserved, mainoutertunneltransport), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StartExistingTransports(this: *mut core::ffi::c_void, assignedclientipv4list: *mut core::ffi::c_void, assignedclientipv6list: *mut core::ffi::c_void, vpninterfaceid: *mut core::ffi::c_void, assignedroutes: *mut core::ffi::c_void, assigneddomainname: *mut core::ffi::c_void, mtusize: u32, maxframesize: u32, reserved: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel2", "StartExistingTransports", 7usize, |this| ((**(this as *const *const IVpnChannel2_Vtbl)).StartExistingTransports)(this, assignedclientipv4list, assignedclientipv6list, vpninterfaceid, assignedroutes, assigneddomainname, mtusize, maxframesize, reserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivityStateChange(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel2", "ActivityStateChange", 8usize, |this| ((**(this as *const *const IVpnChannel2_Vtbl)).ActivityStateChange)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveActivityStateChange(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel2", "RemoveActivityStateChange", 9usize, |this| ((**(this as *const *const IVpnChannel2_Vtbl)).RemoveActivityStateChange)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVpnSendPacketBuffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel2", "GetVpnSendPacketBuffer", 10usize, |this| ((**(this as *const *const IVpnChannel2_Vtbl)).GetVpnSendPacketBuffer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVpnReceivePacketBuffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel2", "GetVpnReceivePacketBuffer", 11usize, |this| ((**(this as *const *const IVpnChannel2_Vtbl)).GetVpnReceivePacketBuffer)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestCustomPromptAsync(this: *mut core::ffi::c_void, custompromptelement: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel2", "RequestCustomPromptAsync", 12usize, |this| ((**(this as *const *const IVpnChannel2_Vtbl)).RequestCustomPromptAsync)(this, custompromptelement, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn RequestCredentialsWithCertificateAsync(this: *mut core::ffi::c_void, credtype: VpnCredentialType, credoptions: u32, certificate: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel2", "RequestCredentialsWithCertificateAsync", 13usize, |this| ((**(this as *const *const IVpnChannel2_Vtbl)).RequestCredentialsWithCertificateAsync)(this, credtype, credoptions, certificate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestCredentialsWithOptionsAsync(this: *mut core::ffi::c_void, credtype: VpnCredentialType, credoptions: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel2", "RequestCredentialsWithOptionsAsync", 14usize, |this| ((**(this as *const *const IVpnChannel2_Vtbl)).RequestCredentialsWithOptionsAsync)(this, credtype, credoptions, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestCredentialsSimpleAsync(this: *mut core::ffi::c_void, credtype: VpnCredentialType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel2", "RequestCredentialsSimpleAsync", 15usize, |this| ((**(this as *const *const IVpnChannel2_Vtbl)).RequestCredentialsSimpleAsync)(this, credtype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminateConnection(this: *mut core::ffi::c_void, message: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel2", "TerminateConnection", 16usize, |this| ((**(this as *const *const IVpnChannel2_Vtbl)).TerminateConnection)(this, message), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StartWithTrafficFilter(this: *mut core::ffi::c_void, assignedclientipv4list: *mut core::ffi::c_void, assignedclientipv6list: *mut core::ffi::c_void, vpninterfaceid: *mut core::ffi::c_void, assignedroutes: *mut core::ffi::c_void, assignednamespace: *mut core::ffi::c_void, mtusize: u32, maxframesize: u32, reserved: bool, mainoutertunneltransport: *mut core::ffi::c_void, optionaloutertunneltransport: *mut core::ffi::c_void, assignedtrafficfilters: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel2", "StartWithTrafficFilter", 17usize, |this| ((**(this as *const *const IVpnChannel2_Vtbl)).StartWithTrafficFilter)(this, assignedclientipv4list, assignedclientipv6list, vpninterfaceid, assignedroutes, assignednamespace, mtusize, maxframesize, reserved, mainoutertunneltransport, optionaloutertunneltransport, assignedtrafficfilters), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            StartWithMainTransport,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StartWithMainTransport: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            StartExistingTransports,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StartExistingTransports: 0,
+            ActivityStateChange,
+            RemoveActivityStateChange,
+            GetVpnSendPacketBuffer,
+            GetVpnReceivePacketBuffer,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestCustomPromptAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestCustomPromptAsync: 0,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            RequestCredentialsWithCertificateAsync,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            RequestCredentialsWithCertificateAsync: 0,
+            RequestCredentialsWithOptionsAsync,
+            RequestCredentialsSimpleAsync,
+            TerminateConnection,
+            #[cfg(feature = "Foundation_Collections")]
+            StartWithTrafficFilter,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StartWithTrafficFilter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnChannel2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Networking_Sockets"))]
+impl windows_core::imp::ProxyVtable for IVpnChannel4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddAndAssociateTransport(this: *mut core::ffi::c_void, transport: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel4", "AddAndAssociateTransport", 6usize, |this| ((**(this as *const *const IVpnChannel4_Vtbl)).AddAndAssociateTransport)(this, transport, context), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StartWithMultipleTransports(this: *mut core::ffi::c_void, assignedclientipv4addresses: *mut core::ffi::c_void, assignedclientipv6addresses: *mut core::ffi::c_void, vpninterfaceid: *mut core::ffi::c_void, assignedroutes: *mut core::ffi::c_void, assignednamespace: *mut core::ffi::c_void, mtusize: u32, maxframesize: u32, reserved: bool, transports: *mut core::ffi::c_void, assignedtrafficfilters: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel4", "StartWithMultipleTransports", 7usize, |this| ((**(this as *const *const IVpnChannel4_Vtbl)).StartWithMultipleTransports)(this, assignedclientipv4addresses, assignedclientipv6addresses, vpninterfaceid, assignedroutes, assignednamespace, mtusize, maxframesize, reserved, transports, assignedtrafficfilters), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplaceAndAssociateTransport(this: *mut core::ffi::c_void, transport: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel4", "ReplaceAndAssociateTransport", 8usize, |this| ((**(this as *const *const IVpnChannel4_Vtbl)).ReplaceAndAssociateTransport)(this, transport, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartReconnectingTransport(this: *mut core::ffi::c_void, transport: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel4", "StartReconnectingTransport", 9usize, |this| ((**(this as *const *const IVpnChannel4_Vtbl)).StartReconnectingTransport)(this, transport, context), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn GetSlotTypeForTransportContext(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void, result__: *mut super::Sockets::ControlChannelTriggerStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel4", "GetSlotTypeForTransportContext", 10usize, |this| ((**(this as *const *const IVpnChannel4_Vtbl)).GetSlotTypeForTransportContext)(this, context, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentRequestTransportContext(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel4", "CurrentRequestTransportContext", 11usize, |this| ((**(this as *const *const IVpnChannel4_Vtbl)).CurrentRequestTransportContext)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddAndAssociateTransport,
+            #[cfg(feature = "Foundation_Collections")]
+            StartWithMultipleTransports,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StartWithMultipleTransports: 0,
+            ReplaceAndAssociateTransport,
+            StartReconnectingTransport,
+            #[cfg(feature = "Networking_Sockets")]
+            GetSlotTypeForTransportContext,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            GetSlotTypeForTransportContext: 0,
+            CurrentRequestTransportContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnChannel4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnChannel5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppendVpnReceivePacketBuffer(this: *mut core::ffi::c_void, decapsulatedpacketbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel5", "AppendVpnReceivePacketBuffer", 6usize, |this| ((**(this as *const *const IVpnChannel5_Vtbl)).AppendVpnReceivePacketBuffer)(this, decapsulatedpacketbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendVpnSendPacketBuffer(this: *mut core::ffi::c_void, encapsulatedpacketbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel5", "AppendVpnSendPacketBuffer", 7usize, |this| ((**(this as *const *const IVpnChannel5_Vtbl)).AppendVpnSendPacketBuffer)(this, encapsulatedpacketbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushVpnReceivePacketBuffers(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel5", "FlushVpnReceivePacketBuffers", 8usize, |this| ((**(this as *const *const IVpnChannel5_Vtbl)).FlushVpnReceivePacketBuffers)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushVpnSendPacketBuffers(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel5", "FlushVpnSendPacketBuffers", 9usize, |this| ((**(this as *const *const IVpnChannel5_Vtbl)).FlushVpnSendPacketBuffers)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppendVpnReceivePacketBuffer,
+            AppendVpnSendPacketBuffer,
+            FlushVpnReceivePacketBuffers,
+            FlushVpnSendPacketBuffers,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnChannel5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnChannel6_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ActivateForeground(this: *mut core::ffi::c_void, packagerelativeappid: core::mem::MaybeUninit<windows_core::HSTRING>, sharedcontext: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannel6", "ActivateForeground", 6usize, |this| ((**(this as *const *const IVpnChannel6_Vtbl)).ActivateForeground)(this, packagerelativeappid, sharedcontext, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ActivateForeground,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ActivateForeground: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnChannel6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnChannelActivityEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut VpnChannelActivityEventType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannelActivityEventArgs", "Type", 6usize, |this| ((**(this as *const *const IVpnChannelActivityEventArgs_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Type }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnChannelActivityEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnChannelActivityStateChangedArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivityState(this: *mut core::ffi::c_void, result__: *mut VpnChannelActivityEventType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannelActivityStateChangedArgs", "ActivityState", 6usize, |this| ((**(this as *const *const IVpnChannelActivityStateChangedArgs_Vtbl)).ActivityState)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ActivityState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnChannelActivityStateChangedArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnChannelConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServerServiceName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannelConfiguration", "ServerServiceName", 6usize, |this| ((**(this as *const *const IVpnChannelConfiguration_Vtbl)).ServerServiceName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ServerHostNameList(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannelConfiguration", "ServerHostNameList", 7usize, |this| ((**(this as *const *const IVpnChannelConfiguration_Vtbl)).ServerHostNameList)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomField(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannelConfiguration", "CustomField", 8usize, |this| ((**(this as *const *const IVpnChannelConfiguration_Vtbl)).CustomField)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServerServiceName,
+            #[cfg(feature = "Foundation_Collections")]
+            ServerHostNameList,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ServerHostNameList: 0,
+            CustomField,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnChannelConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnChannelConfiguration2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ServerUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannelConfiguration2", "ServerUris", 6usize, |this| ((**(this as *const *const IVpnChannelConfiguration2_Vtbl)).ServerUris)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ServerUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ServerUris: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnChannelConfiguration2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnChannelStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProcessEventAsync(this: *mut core::ffi::c_void, thirdpartyplugin: *mut core::ffi::c_void, event: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnChannelStatics", "ProcessEventAsync", 6usize, |this| ((**(this as *const *const IVpnChannelStatics_Vtbl)).ProcessEventAsync)(this, thirdpartyplugin, event), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProcessEventAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnChannelStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Security_Credentials", feature = "Security_Cryptography_Certificates"))]
+impl windows_core::imp::ProxyVtable for IVpnCredential_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn PasskeyCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCredential", "PasskeyCredential", 6usize, |this| ((**(this as *const *const IVpnCredential_Vtbl)).PasskeyCredential)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn CertificateCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCredential", "CertificateCredential", 7usize, |this| ((**(this as *const *const IVpnCredential_Vtbl)).CertificateCredential)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdditionalPin(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCredential", "AdditionalPin", 8usize, |this| ((**(this as *const *const IVpnCredential_Vtbl)).AdditionalPin)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn OldPasswordCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCredential", "OldPasswordCredential", 9usize, |this| ((**(this as *const *const IVpnCredential_Vtbl)).OldPasswordCredential)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            PasskeyCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            PasskeyCredential: 0,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            CertificateCredential,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            CertificateCredential: 0,
+            AdditionalPin,
+            #[cfg(feature = "Security_Credentials")]
+            OldPasswordCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            OldPasswordCredential: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnCredential as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnCustomCheckBox_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetInitialCheckState(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomCheckBox", "SetInitialCheckState", 6usize, |this| ((**(this as *const *const IVpnCustomCheckBox_Vtbl)).SetInitialCheckState)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialCheckState(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomCheckBox", "InitialCheckState", 7usize, |this| ((**(this as *const *const IVpnCustomCheckBox_Vtbl)).InitialCheckState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Checked(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomCheckBox", "Checked", 8usize, |this| ((**(this as *const *const IVpnCustomCheckBox_Vtbl)).Checked)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetInitialCheckState, InitialCheckState, Checked }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnCustomCheckBox as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnCustomComboBox_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetOptionsText(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomComboBox", "SetOptionsText", 6usize, |this| ((**(this as *const *const IVpnCustomComboBox_Vtbl)).SetOptionsText)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn OptionsText(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomComboBox", "OptionsText", 7usize, |this| ((**(this as *const *const IVpnCustomComboBox_Vtbl)).OptionsText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Selected(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomComboBox", "Selected", 8usize, |this| ((**(this as *const *const IVpnCustomComboBox_Vtbl)).Selected)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SetOptionsText,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetOptionsText: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            OptionsText,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            OptionsText: 0,
+            Selected,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnCustomComboBox as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnCustomEditBox_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDefaultText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomEditBox", "SetDefaultText", 6usize, |this| ((**(this as *const *const IVpnCustomEditBox_Vtbl)).SetDefaultText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomEditBox", "DefaultText", 7usize, |this| ((**(this as *const *const IVpnCustomEditBox_Vtbl)).DefaultText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNoEcho(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomEditBox", "SetNoEcho", 8usize, |this| ((**(this as *const *const IVpnCustomEditBox_Vtbl)).SetNoEcho)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NoEcho(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomEditBox", "NoEcho", 9usize, |this| ((**(this as *const *const IVpnCustomEditBox_Vtbl)).NoEcho)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomEditBox", "Text", 10usize, |this| ((**(this as *const *const IVpnCustomEditBox_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDefaultText, DefaultText, SetNoEcho, NoEcho, Text }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnCustomEditBox as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnCustomErrorBox_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnCustomErrorBox as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnCustomPrompt_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPrompt", "SetLabel", 6usize, |this| ((**(this as *const *const IVpnCustomPrompt_Vtbl)).SetLabel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPrompt", "Label", 7usize, |this| ((**(this as *const *const IVpnCustomPrompt_Vtbl)).Label)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompulsory(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPrompt", "SetCompulsory", 8usize, |this| ((**(this as *const *const IVpnCustomPrompt_Vtbl)).SetCompulsory)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Compulsory(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPrompt", "Compulsory", 9usize, |this| ((**(this as *const *const IVpnCustomPrompt_Vtbl)).Compulsory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBordered(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPrompt", "SetBordered", 10usize, |this| ((**(this as *const *const IVpnCustomPrompt_Vtbl)).SetBordered)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bordered(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPrompt", "Bordered", 11usize, |this| ((**(this as *const *const IVpnCustomPrompt_Vtbl)).Bordered)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetLabel,
+            Label,
+            SetCompulsory,
+            Compulsory,
+            SetBordered,
+            Bordered,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnCustomPrompt as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnCustomPromptBooleanInput_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetInitialValue(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptBooleanInput", "SetInitialValue", 6usize, |this| ((**(this as *const *const IVpnCustomPromptBooleanInput_Vtbl)).SetInitialValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialValue(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptBooleanInput", "InitialValue", 7usize, |this| ((**(this as *const *const IVpnCustomPromptBooleanInput_Vtbl)).InitialValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptBooleanInput", "Value", 8usize, |this| ((**(this as *const *const IVpnCustomPromptBooleanInput_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetInitialValue, InitialValue, Value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnCustomPromptBooleanInput as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnCustomPromptElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptElement", "SetDisplayName", 6usize, |this| ((**(this as *const *const IVpnCustomPromptElement_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptElement", "DisplayName", 7usize, |this| ((**(this as *const *const IVpnCustomPromptElement_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompulsory(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptElement", "SetCompulsory", 8usize, |this| ((**(this as *const *const IVpnCustomPromptElement_Vtbl)).SetCompulsory)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Compulsory(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptElement", "Compulsory", 9usize, |this| ((**(this as *const *const IVpnCustomPromptElement_Vtbl)).Compulsory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEmphasized(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptElement", "SetEmphasized", 10usize, |this| ((**(this as *const *const IVpnCustomPromptElement_Vtbl)).SetEmphasized)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Emphasized(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptElement", "Emphasized", 11usize, |this| ((**(this as *const *const IVpnCustomPromptElement_Vtbl)).Emphasized)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDisplayName,
+            DisplayName,
+            SetCompulsory,
+            Compulsory,
+            SetEmphasized,
+            Emphasized,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnCustomPromptElement as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnCustomPromptOptionSelector_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Options(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptOptionSelector", "Options", 6usize, |this| ((**(this as *const *const IVpnCustomPromptOptionSelector_Vtbl)).Options)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedIndex(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptOptionSelector", "SelectedIndex", 7usize, |this| ((**(this as *const *const IVpnCustomPromptOptionSelector_Vtbl)).SelectedIndex)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Options,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Options: 0,
+            SelectedIndex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnCustomPromptOptionSelector as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnCustomPromptText_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptText", "SetText", 6usize, |this| ((**(this as *const *const IVpnCustomPromptText_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptText", "Text", 7usize, |this| ((**(this as *const *const IVpnCustomPromptText_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetText, Text }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnCustomPromptText as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnCustomPromptTextInput_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPlaceholderText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptTextInput", "SetPlaceholderText", 6usize, |this| ((**(this as *const *const IVpnCustomPromptTextInput_Vtbl)).SetPlaceholderText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaceholderText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptTextInput", "PlaceholderText", 7usize, |this| ((**(this as *const *const IVpnCustomPromptTextInput_Vtbl)).PlaceholderText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsTextHidden(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptTextInput", "SetIsTextHidden", 8usize, |this| ((**(this as *const *const IVpnCustomPromptTextInput_Vtbl)).SetIsTextHidden)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTextHidden(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptTextInput", "IsTextHidden", 9usize, |this| ((**(this as *const *const IVpnCustomPromptTextInput_Vtbl)).IsTextHidden)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomPromptTextInput", "Text", 10usize, |this| ((**(this as *const *const IVpnCustomPromptTextInput_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPlaceholderText,
+            PlaceholderText,
+            SetIsTextHidden,
+            IsTextHidden,
+            Text,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnCustomPromptTextInput as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnCustomTextBox_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDisplayText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomTextBox", "SetDisplayText", 6usize, |this| ((**(this as *const *const IVpnCustomTextBox_Vtbl)).SetDisplayText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnCustomTextBox", "DisplayText", 7usize, |this| ((**(this as *const *const IVpnCustomTextBox_Vtbl)).DisplayText)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDisplayText, DisplayText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnCustomTextBox as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnDomainNameAssignment_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DomainNameList(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnDomainNameAssignment", "DomainNameList", 6usize, |this| ((**(this as *const *const IVpnDomainNameAssignment_Vtbl)).DomainNameList)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProxyAutoConfigurationUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnDomainNameAssignment", "SetProxyAutoConfigurationUri", 7usize, |this| ((**(this as *const *const IVpnDomainNameAssignment_Vtbl)).SetProxyAutoConfigurationUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProxyAutoConfigurationUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnDomainNameAssignment", "ProxyAutoConfigurationUri", 8usize, |this| ((**(this as *const *const IVpnDomainNameAssignment_Vtbl)).ProxyAutoConfigurationUri)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            DomainNameList,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DomainNameList: 0,
+            SetProxyAutoConfigurationUri,
+            ProxyAutoConfigurationUri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnDomainNameAssignment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnDomainNameInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDomainName(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnDomainNameInfo", "SetDomainName", 6usize, |this| ((**(this as *const *const IVpnDomainNameInfo_Vtbl)).SetDomainName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnDomainNameInfo", "DomainName", 7usize, |this| ((**(this as *const *const IVpnDomainNameInfo_Vtbl)).DomainName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDomainNameType(this: *mut core::ffi::c_void, value: VpnDomainNameType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnDomainNameInfo", "SetDomainNameType", 8usize, |this| ((**(this as *const *const IVpnDomainNameInfo_Vtbl)).SetDomainNameType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainNameType(this: *mut core::ffi::c_void, result__: *mut VpnDomainNameType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnDomainNameInfo", "DomainNameType", 9usize, |this| ((**(this as *const *const IVpnDomainNameInfo_Vtbl)).DomainNameType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DnsServers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnDomainNameInfo", "DnsServers", 10usize, |this| ((**(this as *const *const IVpnDomainNameInfo_Vtbl)).DnsServers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn WebProxyServers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnDomainNameInfo", "WebProxyServers", 11usize, |this| ((**(this as *const *const IVpnDomainNameInfo_Vtbl)).WebProxyServers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDomainName,
+            DomainName,
+            SetDomainNameType,
+            DomainNameType,
+            #[cfg(feature = "Foundation_Collections")]
+            DnsServers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DnsServers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            WebProxyServers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            WebProxyServers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnDomainNameInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnDomainNameInfo2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn WebProxyUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnDomainNameInfo2", "WebProxyUris", 6usize, |this| ((**(this as *const *const IVpnDomainNameInfo2_Vtbl)).WebProxyUris)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            WebProxyUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            WebProxyUris: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnDomainNameInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnDomainNameInfoFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateVpnDomainNameInfo(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, nametype: VpnDomainNameType, dnsserverlist: *mut core::ffi::c_void, proxyserverlist: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnDomainNameInfoFactory", "CreateVpnDomainNameInfo", 6usize, |this| ((**(this as *const *const IVpnDomainNameInfoFactory_Vtbl)).CreateVpnDomainNameInfo)(this, name, nametype, dnsserverlist, proxyserverlist, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateVpnDomainNameInfo,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateVpnDomainNameInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnDomainNameInfoFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnForegroundActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProfileName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnForegroundActivatedEventArgs", "ProfileName", 6usize, |this| ((**(this as *const *const IVpnForegroundActivatedEventArgs_Vtbl)).ProfileName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SharedContext(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnForegroundActivatedEventArgs", "SharedContext", 7usize, |this| ((**(this as *const *const IVpnForegroundActivatedEventArgs_Vtbl)).SharedContext)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivationOperation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnForegroundActivatedEventArgs", "ActivationOperation", 8usize, |this| ((**(this as *const *const IVpnForegroundActivatedEventArgs_Vtbl)).ActivationOperation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProfileName,
+            #[cfg(feature = "Foundation_Collections")]
+            SharedContext,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SharedContext: 0,
+            ActivationOperation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnForegroundActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnForegroundActivationOperation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void, result: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnForegroundActivationOperation", "Complete", 6usize, |this| ((**(this as *const *const IVpnForegroundActivationOperation_Vtbl)).Complete)(this, result), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Complete,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Complete: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnForegroundActivationOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnInterfaceId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAddressInfo(this: *mut core::ffi::c_void, id_array_size: *mut u32, id: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnInterfaceId", "GetAddressInfo", 6usize, |this| ((**(this as *const *const IVpnInterfaceId_Vtbl)).GetAddressInfo)(this, id_array_size, id), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAddressInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnInterfaceId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnInterfaceIdFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateVpnInterfaceId(this: *mut core::ffi::c_void, address_array_size: u32, address: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnInterfaceIdFactory", "CreateVpnInterfaceId", 6usize, |this| ((**(this as *const *const IVpnInterfaceIdFactory_Vtbl)).CreateVpnInterfaceId)(this, address_array_size, address, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateVpnInterfaceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnInterfaceIdFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+impl windows_core::imp::ProxyVtable for IVpnManagementAgent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddProfileFromXmlAsync(this: *mut core::ffi::c_void, xml: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnManagementAgent", "AddProfileFromXmlAsync", 6usize, |this| ((**(this as *const *const IVpnManagementAgent_Vtbl)).AddProfileFromXmlAsync)(this, xml, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddProfileFromObjectAsync(this: *mut core::ffi::c_void, profile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnManagementAgent", "AddProfileFromObjectAsync", 7usize, |this| ((**(this as *const *const IVpnManagementAgent_Vtbl)).AddProfileFromObjectAsync)(this, profile, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateProfileFromXmlAsync(this: *mut core::ffi::c_void, xml: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnManagementAgent", "UpdateProfileFromXmlAsync", 8usize, |this| ((**(this as *const *const IVpnManagementAgent_Vtbl)).UpdateProfileFromXmlAsync)(this, xml, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateProfileFromObjectAsync(this: *mut core::ffi::c_void, profile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnManagementAgent", "UpdateProfileFromObjectAsync", 9usize, |this| ((**(this as *const *const IVpnManagementAgent_Vtbl)).UpdateProfileFromObjectAsync)(this, profile, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetProfilesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnManagementAgent", "GetProfilesAsync", 10usize, |this| ((**(this as *const *const IVpnManagementAgent_Vtbl)).GetProfilesAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteProfileAsync(this: *mut core::ffi::c_void, profile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnManagementAgent", "DeleteProfileAsync", 11usize, |this| ((**(this as *const *const IVpnManagementAgent_Vtbl)).DeleteProfileAsync)(this, profile, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectProfileAsync(this: *mut core::ffi::c_void, profile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnManagementAgent", "ConnectProfileAsync", 12usize, |this| ((**(this as *const *const IVpnManagementAgent_Vtbl)).ConnectProfileAsync)(this, profile, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn ConnectProfileWithPasswordCredentialAsync(this: *mut core::ffi::c_void, profile: *mut core::ffi::c_void, passwordcredential: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnManagementAgent", "ConnectProfileWithPasswordCredentialAsync", 13usize, |this| ((**(this as *const *const IVpnManagementAgent_Vtbl)).ConnectProfileWithPasswordCredentialAsync)(this, profile, passwordcredential, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectProfileAsync(this: *mut core::ffi::c_void, profile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnManagementAgent", "DisconnectProfileAsync", 14usize, |this| ((**(this as *const *const IVpnManagementAgent_Vtbl)).DisconnectProfileAsync)(this, profile, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddProfileFromXmlAsync,
+            AddProfileFromObjectAsync,
+            UpdateProfileFromXmlAsync,
+            UpdateProfileFromObjectAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetProfilesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetProfilesAsync: 0,
+            DeleteProfileAsync,
+            ConnectProfileAsync,
+            #[cfg(feature = "Security_Credentials")]
+            ConnectProfileWithPasswordCredentialAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            ConnectProfileWithPasswordCredentialAsync: 0,
+            DisconnectProfileAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnManagementAgent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnNamespaceAssignment_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetNamespaceList(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNamespaceAssignment", "SetNamespaceList", 6usize, |this| ((**(this as *const *const IVpnNamespaceAssignment_Vtbl)).SetNamespaceList)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn NamespaceList(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNamespaceAssignment", "NamespaceList", 7usize, |this| ((**(this as *const *const IVpnNamespaceAssignment_Vtbl)).NamespaceList)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProxyAutoConfigUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNamespaceAssignment", "SetProxyAutoConfigUri", 8usize, |this| ((**(this as *const *const IVpnNamespaceAssignment_Vtbl)).SetProxyAutoConfigUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProxyAutoConfigUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNamespaceAssignment", "ProxyAutoConfigUri", 9usize, |this| ((**(this as *const *const IVpnNamespaceAssignment_Vtbl)).ProxyAutoConfigUri)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SetNamespaceList,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetNamespaceList: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            NamespaceList,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            NamespaceList: 0,
+            SetProxyAutoConfigUri,
+            ProxyAutoConfigUri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnNamespaceAssignment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnNamespaceInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetNamespace(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNamespaceInfo", "SetNamespace", 6usize, |this| ((**(this as *const *const IVpnNamespaceInfo_Vtbl)).SetNamespace)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Namespace(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNamespaceInfo", "Namespace", 7usize, |this| ((**(this as *const *const IVpnNamespaceInfo_Vtbl)).Namespace)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetDnsServers(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNamespaceInfo", "SetDnsServers", 8usize, |this| ((**(this as *const *const IVpnNamespaceInfo_Vtbl)).SetDnsServers)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DnsServers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNamespaceInfo", "DnsServers", 9usize, |this| ((**(this as *const *const IVpnNamespaceInfo_Vtbl)).DnsServers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetWebProxyServers(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNamespaceInfo", "SetWebProxyServers", 10usize, |this| ((**(this as *const *const IVpnNamespaceInfo_Vtbl)).SetWebProxyServers)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn WebProxyServers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNamespaceInfo", "WebProxyServers", 11usize, |this| ((**(this as *const *const IVpnNamespaceInfo_Vtbl)).WebProxyServers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetNamespace,
+            Namespace,
+            #[cfg(feature = "Foundation_Collections")]
+            SetDnsServers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetDnsServers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            DnsServers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DnsServers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetWebProxyServers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetWebProxyServers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            WebProxyServers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            WebProxyServers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnNamespaceInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnNamespaceInfoFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateVpnNamespaceInfo(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, dnsserverlist: *mut core::ffi::c_void, proxyserverlist: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNamespaceInfoFactory", "CreateVpnNamespaceInfo", 6usize, |this| ((**(this as *const *const IVpnNamespaceInfoFactory_Vtbl)).CreateVpnNamespaceInfo)(this, name, dnsserverlist, proxyserverlist, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateVpnNamespaceInfo,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateVpnNamespaceInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnNamespaceInfoFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnNativeProfile_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Servers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNativeProfile", "Servers", 6usize, |this| ((**(this as *const *const IVpnNativeProfile_Vtbl)).Servers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoutingPolicyType(this: *mut core::ffi::c_void, result__: *mut VpnRoutingPolicyType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNativeProfile", "RoutingPolicyType", 7usize, |this| ((**(this as *const *const IVpnNativeProfile_Vtbl)).RoutingPolicyType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoutingPolicyType(this: *mut core::ffi::c_void, value: VpnRoutingPolicyType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNativeProfile", "SetRoutingPolicyType", 8usize, |this| ((**(this as *const *const IVpnNativeProfile_Vtbl)).SetRoutingPolicyType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NativeProtocolType(this: *mut core::ffi::c_void, result__: *mut VpnNativeProtocolType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNativeProfile", "NativeProtocolType", 9usize, |this| ((**(this as *const *const IVpnNativeProfile_Vtbl)).NativeProtocolType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNativeProtocolType(this: *mut core::ffi::c_void, value: VpnNativeProtocolType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNativeProfile", "SetNativeProtocolType", 10usize, |this| ((**(this as *const *const IVpnNativeProfile_Vtbl)).SetNativeProtocolType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserAuthenticationMethod(this: *mut core::ffi::c_void, result__: *mut VpnAuthenticationMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNativeProfile", "UserAuthenticationMethod", 11usize, |this| ((**(this as *const *const IVpnNativeProfile_Vtbl)).UserAuthenticationMethod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserAuthenticationMethod(this: *mut core::ffi::c_void, value: VpnAuthenticationMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNativeProfile", "SetUserAuthenticationMethod", 12usize, |this| ((**(this as *const *const IVpnNativeProfile_Vtbl)).SetUserAuthenticationMethod)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TunnelAuthenticationMethod(this: *mut core::ffi::c_void, result__: *mut VpnAuthenticationMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNativeProfile", "TunnelAuthenticationMethod", 13usize, |this| ((**(this as *const *const IVpnNativeProfile_Vtbl)).TunnelAuthenticationMethod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTunnelAuthenticationMethod(this: *mut core::ffi::c_void, value: VpnAuthenticationMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNativeProfile", "SetTunnelAuthenticationMethod", 14usize, |this| ((**(this as *const *const IVpnNativeProfile_Vtbl)).SetTunnelAuthenticationMethod)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EapConfiguration(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNativeProfile", "EapConfiguration", 15usize, |this| ((**(this as *const *const IVpnNativeProfile_Vtbl)).EapConfiguration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEapConfiguration(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNativeProfile", "SetEapConfiguration", 16usize, |this| ((**(this as *const *const IVpnNativeProfile_Vtbl)).SetEapConfiguration)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Servers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Servers: 0,
+            RoutingPolicyType,
+            SetRoutingPolicyType,
+            NativeProtocolType,
+            SetNativeProtocolType,
+            UserAuthenticationMethod,
+            SetUserAuthenticationMethod,
+            TunnelAuthenticationMethod,
+            SetTunnelAuthenticationMethod,
+            EapConfiguration,
+            SetEapConfiguration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnNativeProfile as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnNativeProfile2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequireVpnClientAppUI(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNativeProfile2", "RequireVpnClientAppUI", 6usize, |this| ((**(this as *const *const IVpnNativeProfile2_Vtbl)).RequireVpnClientAppUI)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequireVpnClientAppUI(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNativeProfile2", "SetRequireVpnClientAppUI", 7usize, |this| ((**(this as *const *const IVpnNativeProfile2_Vtbl)).SetRequireVpnClientAppUI)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionStatus(this: *mut core::ffi::c_void, result__: *mut VpnManagementConnectionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnNativeProfile2", "ConnectionStatus", 8usize, |this| ((**(this as *const *const IVpnNativeProfile2_Vtbl)).ConnectionStatus)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequireVpnClientAppUI,
+            SetRequireVpnClientAppUI,
+            ConnectionStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnNativeProfile2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IVpnPacketBuffer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Buffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBuffer", "Buffer", 6usize, |this| ((**(this as *const *const IVpnPacketBuffer_Vtbl)).Buffer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, value: VpnPacketBufferStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBuffer", "SetStatus", 7usize, |this| ((**(this as *const *const IVpnPacketBuffer_Vtbl)).SetStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut VpnPacketBufferStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBuffer", "Status", 8usize, |this| ((**(this as *const *const IVpnPacketBuffer_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransportAffinity(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBuffer", "SetTransportAffinity", 9usize, |this| ((**(this as *const *const IVpnPacketBuffer_Vtbl)).SetTransportAffinity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportAffinity(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBuffer", "TransportAffinity", 10usize, |this| ((**(this as *const *const IVpnPacketBuffer_Vtbl)).TransportAffinity)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Buffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Buffer: 0,
+            SetStatus,
+            Status,
+            SetTransportAffinity,
+            TransportAffinity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnPacketBuffer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnPacketBuffer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBuffer2", "AppId", 6usize, |this| ((**(this as *const *const IVpnPacketBuffer2_Vtbl)).AppId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AppId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnPacketBuffer2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnPacketBuffer3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTransportContext(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBuffer3", "SetTransportContext", 6usize, |this| ((**(this as *const *const IVpnPacketBuffer3_Vtbl)).SetTransportContext)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportContext(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBuffer3", "TransportContext", 7usize, |this| ((**(this as *const *const IVpnPacketBuffer3_Vtbl)).TransportContext)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTransportContext, TransportContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnPacketBuffer3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnPacketBufferFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateVpnPacketBuffer(this: *mut core::ffi::c_void, parentbuffer: *mut core::ffi::c_void, offset: u32, length: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBufferFactory", "CreateVpnPacketBuffer", 6usize, |this| ((**(this as *const *const IVpnPacketBufferFactory_Vtbl)).CreateVpnPacketBuffer)(this, parentbuffer, offset, length, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateVpnPacketBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnPacketBufferFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnPacketBufferList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, nextvpnpacketbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBufferList", "Append", 6usize, |this| ((**(this as *const *const IVpnPacketBufferList_Vtbl)).Append)(this, nextvpnpacketbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAtBegin(this: *mut core::ffi::c_void, nextvpnpacketbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBufferList", "AddAtBegin", 7usize, |this| ((**(this as *const *const IVpnPacketBufferList_Vtbl)).AddAtBegin)(this, nextvpnpacketbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAtEnd(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBufferList", "RemoveAtEnd", 8usize, |this| ((**(this as *const *const IVpnPacketBufferList_Vtbl)).RemoveAtEnd)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAtBegin(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBufferList", "RemoveAtBegin", 9usize, |this| ((**(this as *const *const IVpnPacketBufferList_Vtbl)).RemoveAtBegin)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBufferList", "Clear", 10usize, |this| ((**(this as *const *const IVpnPacketBufferList_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, value: VpnPacketBufferStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBufferList", "SetStatus", 11usize, |this| ((**(this as *const *const IVpnPacketBufferList_Vtbl)).SetStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut VpnPacketBufferStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBufferList", "Status", 12usize, |this| ((**(this as *const *const IVpnPacketBufferList_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBufferList", "Size", 13usize, |this| ((**(this as *const *const IVpnPacketBufferList_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Append,
+            AddAtBegin,
+            RemoveAtEnd,
+            RemoveAtBegin,
+            Clear,
+            SetStatus,
+            Status,
+            Size,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnPacketBufferList as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnPacketBufferList2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddLeadingPacket(this: *mut core::ffi::c_void, nextvpnpacketbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBufferList2", "AddLeadingPacket", 6usize, |this| ((**(this as *const *const IVpnPacketBufferList2_Vtbl)).AddLeadingPacket)(this, nextvpnpacketbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLeadingPacket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBufferList2", "RemoveLeadingPacket", 7usize, |this| ((**(this as *const *const IVpnPacketBufferList2_Vtbl)).RemoveLeadingPacket)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTrailingPacket(this: *mut core::ffi::c_void, nextvpnpacketbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBufferList2", "AddTrailingPacket", 8usize, |this| ((**(this as *const *const IVpnPacketBufferList2_Vtbl)).AddTrailingPacket)(this, nextvpnpacketbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTrailingPacket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPacketBufferList2", "RemoveTrailingPacket", 9usize, |this| ((**(this as *const *const IVpnPacketBufferList2_Vtbl)).RemoveTrailingPacket)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddLeadingPacket,
+            RemoveLeadingPacket,
+            AddTrailingPacket,
+            RemoveTrailingPacket,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnPacketBufferList2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IVpnPickedCredential_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn PasskeyCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPickedCredential", "PasskeyCredential", 6usize, |this| ((**(this as *const *const IVpnPickedCredential_Vtbl)).PasskeyCredential)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdditionalPin(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPickedCredential", "AdditionalPin", 7usize, |this| ((**(this as *const *const IVpnPickedCredential_Vtbl)).AdditionalPin)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn OldPasswordCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPickedCredential", "OldPasswordCredential", 8usize, |this| ((**(this as *const *const IVpnPickedCredential_Vtbl)).OldPasswordCredential)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            PasskeyCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            PasskeyCredential: 0,
+            AdditionalPin,
+            #[cfg(feature = "Security_Credentials")]
+            OldPasswordCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            OldPasswordCredential: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnPickedCredential as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnPlugIn_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, channel: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPlugIn", "Connect", 6usize, |this| ((**(this as *const *const IVpnPlugIn_Vtbl)).Connect)(this, channel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void, channel: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPlugIn", "Disconnect", 7usize, |this| ((**(this as *const *const IVpnPlugIn_Vtbl)).Disconnect)(this, channel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeepAlivePayload(this: *mut core::ffi::c_void, channel: *mut core::ffi::c_void, keepalivepacket: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPlugIn", "GetKeepAlivePayload", 8usize, |this| ((**(this as *const *const IVpnPlugIn_Vtbl)).GetKeepAlivePayload)(this, channel, keepalivepacket), |result| Some(*result))
+        }
+        unsafe extern "system" fn Encapsulate(this: *mut core::ffi::c_void, channel: *mut core::ffi::c_void, packets: *mut core::ffi::c_void, encapulatedpackets: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPlugIn", "Encapsulate", 9usize, |this| ((**(this as *const *const IVpnPlugIn_Vtbl)).Encapsulate)(this, channel, packets, encapulatedpackets), |result| Some(*result))
+        }
+        unsafe extern "system" fn Decapsulate(this: *mut core::ffi::c_void, channel: *mut core::ffi::c_void, encapbuffer: *mut core::ffi::c_void, decapsulatedpackets: *mut core::ffi::c_void, controlpacketstosend: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPlugIn", "Decapsulate", 10usize, |this| ((**(this as *const *const IVpnPlugIn_Vtbl)).Decapsulate)(this, channel, encapbuffer, decapsulatedpackets, controlpacketstosend), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Connect,
+            Disconnect,
+            GetKeepAlivePayload,
+            Encapsulate,
+            Decapsulate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnPlugIn as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnPlugInProfile_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ServerUris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPlugInProfile", "ServerUris", 6usize, |this| ((**(this as *const *const IVpnPlugInProfile_Vtbl)).ServerUris)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomConfiguration(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPlugInProfile", "CustomConfiguration", 7usize, |this| ((**(this as *const *const IVpnPlugInProfile_Vtbl)).CustomConfiguration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomConfiguration(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPlugInProfile", "SetCustomConfiguration", 8usize, |this| ((**(this as *const *const IVpnPlugInProfile_Vtbl)).SetCustomConfiguration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VpnPluginPackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPlugInProfile", "VpnPluginPackageFamilyName", 9usize, |this| ((**(this as *const *const IVpnPlugInProfile_Vtbl)).VpnPluginPackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVpnPluginPackageFamilyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPlugInProfile", "SetVpnPluginPackageFamilyName", 10usize, |this| ((**(this as *const *const IVpnPlugInProfile_Vtbl)).SetVpnPluginPackageFamilyName)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ServerUris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ServerUris: 0,
+            CustomConfiguration,
+            SetCustomConfiguration,
+            VpnPluginPackageFamilyName,
+            SetVpnPluginPackageFamilyName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnPlugInProfile as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnPlugInProfile2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequireVpnClientAppUI(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPlugInProfile2", "RequireVpnClientAppUI", 6usize, |this| ((**(this as *const *const IVpnPlugInProfile2_Vtbl)).RequireVpnClientAppUI)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequireVpnClientAppUI(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPlugInProfile2", "SetRequireVpnClientAppUI", 7usize, |this| ((**(this as *const *const IVpnPlugInProfile2_Vtbl)).SetRequireVpnClientAppUI)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionStatus(this: *mut core::ffi::c_void, result__: *mut VpnManagementConnectionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPlugInProfile2", "ConnectionStatus", 8usize, |this| ((**(this as *const *const IVpnPlugInProfile2_Vtbl)).ConnectionStatus)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequireVpnClientAppUI,
+            SetRequireVpnClientAppUI,
+            ConnectionStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnPlugInProfile2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnPlugInReconnectTransport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReconnectTransport(this: *mut core::ffi::c_void, channel: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnPlugInReconnectTransport", "ReconnectTransport", 6usize, |this| ((**(this as *const *const IVpnPlugInReconnectTransport_Vtbl)).ReconnectTransport)(this, channel, context), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReconnectTransport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnPlugInReconnectTransport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnProfile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProfileName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnProfile", "ProfileName", 6usize, |this| ((**(this as *const *const IVpnProfile_Vtbl)).ProfileName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProfileName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnProfile", "SetProfileName", 7usize, |this| ((**(this as *const *const IVpnProfile_Vtbl)).SetProfileName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AppTriggers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnProfile", "AppTriggers", 8usize, |this| ((**(this as *const *const IVpnProfile_Vtbl)).AppTriggers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Routes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnProfile", "Routes", 9usize, |this| ((**(this as *const *const IVpnProfile_Vtbl)).Routes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DomainNameInfoList(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnProfile", "DomainNameInfoList", 10usize, |this| ((**(this as *const *const IVpnProfile_Vtbl)).DomainNameInfoList)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TrafficFilters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnProfile", "TrafficFilters", 11usize, |this| ((**(this as *const *const IVpnProfile_Vtbl)).TrafficFilters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RememberCredentials(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnProfile", "RememberCredentials", 12usize, |this| ((**(this as *const *const IVpnProfile_Vtbl)).RememberCredentials)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRememberCredentials(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnProfile", "SetRememberCredentials", 13usize, |this| ((**(this as *const *const IVpnProfile_Vtbl)).SetRememberCredentials)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlwaysOn(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnProfile", "AlwaysOn", 14usize, |this| ((**(this as *const *const IVpnProfile_Vtbl)).AlwaysOn)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlwaysOn(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnProfile", "SetAlwaysOn", 15usize, |this| ((**(this as *const *const IVpnProfile_Vtbl)).SetAlwaysOn)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProfileName,
+            SetProfileName,
+            #[cfg(feature = "Foundation_Collections")]
+            AppTriggers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AppTriggers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Routes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Routes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            DomainNameInfoList,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DomainNameInfoList: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            TrafficFilters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TrafficFilters: 0,
+            RememberCredentials,
+            SetRememberCredentials,
+            AlwaysOn,
+            SetAlwaysOn,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnProfile as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnRoute_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAddress(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnRoute", "SetAddress", 6usize, |this| ((**(this as *const *const IVpnRoute_Vtbl)).SetAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnRoute", "Address", 7usize, |this| ((**(this as *const *const IVpnRoute_Vtbl)).Address)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrefixSize(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnRoute", "SetPrefixSize", 8usize, |this| ((**(this as *const *const IVpnRoute_Vtbl)).SetPrefixSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrefixSize(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnRoute", "PrefixSize", 9usize, |this| ((**(this as *const *const IVpnRoute_Vtbl)).PrefixSize)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAddress, Address, SetPrefixSize, PrefixSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnRoute as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnRouteAssignment_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetIpv4InclusionRoutes(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnRouteAssignment", "SetIpv4InclusionRoutes", 6usize, |this| ((**(this as *const *const IVpnRouteAssignment_Vtbl)).SetIpv4InclusionRoutes)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetIpv6InclusionRoutes(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnRouteAssignment", "SetIpv6InclusionRoutes", 7usize, |this| ((**(this as *const *const IVpnRouteAssignment_Vtbl)).SetIpv6InclusionRoutes)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Ipv4InclusionRoutes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnRouteAssignment", "Ipv4InclusionRoutes", 8usize, |this| ((**(this as *const *const IVpnRouteAssignment_Vtbl)).Ipv4InclusionRoutes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Ipv6InclusionRoutes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnRouteAssignment", "Ipv6InclusionRoutes", 9usize, |this| ((**(this as *const *const IVpnRouteAssignment_Vtbl)).Ipv6InclusionRoutes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetIpv4ExclusionRoutes(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnRouteAssignment", "SetIpv4ExclusionRoutes", 10usize, |this| ((**(this as *const *const IVpnRouteAssignment_Vtbl)).SetIpv4ExclusionRoutes)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetIpv6ExclusionRoutes(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnRouteAssignment", "SetIpv6ExclusionRoutes", 11usize, |this| ((**(this as *const *const IVpnRouteAssignment_Vtbl)).SetIpv6ExclusionRoutes)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Ipv4ExclusionRoutes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnRouteAssignment", "Ipv4ExclusionRoutes", 12usize, |this| ((**(this as *const *const IVpnRouteAssignment_Vtbl)).Ipv4ExclusionRoutes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Ipv6ExclusionRoutes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnRouteAssignment", "Ipv6ExclusionRoutes", 13usize, |this| ((**(this as *const *const IVpnRouteAssignment_Vtbl)).Ipv6ExclusionRoutes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExcludeLocalSubnets(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnRouteAssignment", "SetExcludeLocalSubnets", 14usize, |this| ((**(this as *const *const IVpnRouteAssignment_Vtbl)).SetExcludeLocalSubnets)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExcludeLocalSubnets(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnRouteAssignment", "ExcludeLocalSubnets", 15usize, |this| ((**(this as *const *const IVpnRouteAssignment_Vtbl)).ExcludeLocalSubnets)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SetIpv4InclusionRoutes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetIpv4InclusionRoutes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetIpv6InclusionRoutes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetIpv6InclusionRoutes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Ipv4InclusionRoutes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Ipv4InclusionRoutes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Ipv6InclusionRoutes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Ipv6InclusionRoutes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetIpv4ExclusionRoutes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetIpv4ExclusionRoutes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetIpv6ExclusionRoutes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetIpv6ExclusionRoutes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Ipv4ExclusionRoutes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Ipv4ExclusionRoutes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Ipv6ExclusionRoutes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Ipv6ExclusionRoutes: 0,
+            SetExcludeLocalSubnets,
+            ExcludeLocalSubnets,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnRouteAssignment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnRouteFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateVpnRoute(this: *mut core::ffi::c_void, address: *mut core::ffi::c_void, prefixsize: u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnRouteFactory", "CreateVpnRoute", 6usize, |this| ((**(this as *const *const IVpnRouteFactory_Vtbl)).CreateVpnRoute)(this, address, prefixsize, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateVpnRoute }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnRouteFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IVpnSystemHealth_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn StatementOfHealth(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnSystemHealth", "StatementOfHealth", 6usize, |this| ((**(this as *const *const IVpnSystemHealth_Vtbl)).StatementOfHealth)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            StatementOfHealth,
+            #[cfg(not(feature = "Storage_Streams"))]
+            StatementOfHealth: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnSystemHealth as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnTrafficFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilter", "AppId", 6usize, |this| ((**(this as *const *const IVpnTrafficFilter_Vtbl)).AppId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppId(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilter", "SetAppId", 7usize, |this| ((**(this as *const *const IVpnTrafficFilter_Vtbl)).SetAppId)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AppClaims(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilter", "AppClaims", 8usize, |this| ((**(this as *const *const IVpnTrafficFilter_Vtbl)).AppClaims)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Protocol(this: *mut core::ffi::c_void, result__: *mut VpnIPProtocol) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilter", "Protocol", 9usize, |this| ((**(this as *const *const IVpnTrafficFilter_Vtbl)).Protocol)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProtocol(this: *mut core::ffi::c_void, value: VpnIPProtocol) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilter", "SetProtocol", 10usize, |this| ((**(this as *const *const IVpnTrafficFilter_Vtbl)).SetProtocol)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn LocalPortRanges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilter", "LocalPortRanges", 11usize, |this| ((**(this as *const *const IVpnTrafficFilter_Vtbl)).LocalPortRanges)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RemotePortRanges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilter", "RemotePortRanges", 12usize, |this| ((**(this as *const *const IVpnTrafficFilter_Vtbl)).RemotePortRanges)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn LocalAddressRanges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilter", "LocalAddressRanges", 13usize, |this| ((**(this as *const *const IVpnTrafficFilter_Vtbl)).LocalAddressRanges)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RemoteAddressRanges(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilter", "RemoteAddressRanges", 14usize, |this| ((**(this as *const *const IVpnTrafficFilter_Vtbl)).RemoteAddressRanges)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoutingPolicyType(this: *mut core::ffi::c_void, result__: *mut VpnRoutingPolicyType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilter", "RoutingPolicyType", 15usize, |this| ((**(this as *const *const IVpnTrafficFilter_Vtbl)).RoutingPolicyType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoutingPolicyType(this: *mut core::ffi::c_void, value: VpnRoutingPolicyType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilter", "SetRoutingPolicyType", 16usize, |this| ((**(this as *const *const IVpnTrafficFilter_Vtbl)).SetRoutingPolicyType)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppId,
+            SetAppId,
+            #[cfg(feature = "Foundation_Collections")]
+            AppClaims,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AppClaims: 0,
+            Protocol,
+            SetProtocol,
+            #[cfg(feature = "Foundation_Collections")]
+            LocalPortRanges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            LocalPortRanges: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RemotePortRanges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RemotePortRanges: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            LocalAddressRanges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            LocalAddressRanges: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RemoteAddressRanges,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RemoteAddressRanges: 0,
+            RoutingPolicyType,
+            SetRoutingPolicyType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnTrafficFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVpnTrafficFilterAssignment_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TrafficFilterList(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilterAssignment", "TrafficFilterList", 6usize, |this| ((**(this as *const *const IVpnTrafficFilterAssignment_Vtbl)).TrafficFilterList)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowOutbound(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilterAssignment", "AllowOutbound", 7usize, |this| ((**(this as *const *const IVpnTrafficFilterAssignment_Vtbl)).AllowOutbound)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowOutbound(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilterAssignment", "SetAllowOutbound", 8usize, |this| ((**(this as *const *const IVpnTrafficFilterAssignment_Vtbl)).SetAllowOutbound)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowInbound(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilterAssignment", "AllowInbound", 9usize, |this| ((**(this as *const *const IVpnTrafficFilterAssignment_Vtbl)).AllowInbound)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowInbound(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilterAssignment", "SetAllowInbound", 10usize, |this| ((**(this as *const *const IVpnTrafficFilterAssignment_Vtbl)).SetAllowInbound)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            TrafficFilterList,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TrafficFilterList: 0,
+            AllowOutbound,
+            SetAllowOutbound,
+            AllowInbound,
+            SetAllowInbound,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnTrafficFilterAssignment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVpnTrafficFilterFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, appid: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVpnTrafficFilterFactory", "Create", 6usize, |this| ((**(this as *const *const IVpnTrafficFilterFactory_Vtbl)).Create)(this, appid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVpnTrafficFilterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
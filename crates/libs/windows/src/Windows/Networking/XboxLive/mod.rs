@@ -1003,6 +1003,7 @@ impl XboxLiveEndpointPairCreationBehaviors {
 impl windows_core::TypeKind for XboxLiveEndpointPairCreationBehaviors {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XboxLiveEndpointPairCreationBehaviors {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XboxLiveEndpointPairCreationBehaviors").field(&self.0).finish()
@@ -1061,6 +1062,7 @@ impl XboxLiveEndpointPairCreationStatus {
 impl windows_core::TypeKind for XboxLiveEndpointPairCreationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XboxLiveEndpointPairCreationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XboxLiveEndpointPairCreationStatus").field(&self.0).finish()
@@ -1084,6 +1086,7 @@ impl XboxLiveEndpointPairState {
 impl windows_core::TypeKind for XboxLiveEndpointPairState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XboxLiveEndpointPairState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XboxLiveEndpointPairState").field(&self.0).finish()
@@ -1103,6 +1106,7 @@ impl XboxLiveNetworkAccessKind {
 impl windows_core::TypeKind for XboxLiveNetworkAccessKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XboxLiveNetworkAccessKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XboxLiveNetworkAccessKind").field(&self.0).finish()
@@ -1131,6 +1135,7 @@ impl XboxLiveQualityOfServiceMeasurementStatus {
 impl windows_core::TypeKind for XboxLiveQualityOfServiceMeasurementStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XboxLiveQualityOfServiceMeasurementStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XboxLiveQualityOfServiceMeasurementStatus").field(&self.0).finish()
@@ -1156,6 +1161,7 @@ impl XboxLiveQualityOfServiceMetric {
 impl windows_core::TypeKind for XboxLiveQualityOfServiceMetric {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XboxLiveQualityOfServiceMetric {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XboxLiveQualityOfServiceMetric").field(&self.0).finish()
@@ -1175,6 +1181,7 @@ impl XboxLiveSocketKind {
 impl windows_core::TypeKind for XboxLiveSocketKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XboxLiveSocketKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XboxLiveSocketKind").field(&self.0).finish()
@@ -1183,3 +1190,5 @@ impl core::fmt::Debug for XboxLiveSocketKind {
 impl windows_core::RuntimeType for XboxLiveSocketKind {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Networking.XboxLive.XboxLiveSocketKind;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,525 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IXboxLiveDeviceAddress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SnapshotChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveDeviceAddress", "SnapshotChanged", 6usize, |this| ((**(this as *const *const IXboxLiveDeviceAddress_Vtbl)).SnapshotChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSnapshotChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveDeviceAddress", "RemoveSnapshotChanged", 7usize, |this| ((**(this as *const *const IXboxLiveDeviceAddress_Vtbl)).RemoveSnapshotChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSnapshotAsBase64(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveDeviceAddress", "GetSnapshotAsBase64", 8usize, |this| ((**(this as *const *const IXboxLiveDeviceAddress_Vtbl)).GetSnapshotAsBase64)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetSnapshotAsBuffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveDeviceAddress", "GetSnapshotAsBuffer", 9usize, |this| ((**(this as *const *const IXboxLiveDeviceAddress_Vtbl)).GetSnapshotAsBuffer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSnapshotAsBytes(this: *mut core::ffi::c_void, buffer_array_size: u32, buffer: *mut u8, byteswritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveDeviceAddress", "GetSnapshotAsBytes", 10usize, |this| ((**(this as *const *const IXboxLiveDeviceAddress_Vtbl)).GetSnapshotAsBytes)(this, buffer_array_size, buffer, byteswritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn Compare(this: *mut core::ffi::c_void, otherdeviceaddress: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveDeviceAddress", "Compare", 11usize, |this| ((**(this as *const *const IXboxLiveDeviceAddress_Vtbl)).Compare)(this, otherdeviceaddress, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsValid(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveDeviceAddress", "IsValid", 12usize, |this| ((**(this as *const *const IXboxLiveDeviceAddress_Vtbl)).IsValid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLocal(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveDeviceAddress", "IsLocal", 13usize, |this| ((**(this as *const *const IXboxLiveDeviceAddress_Vtbl)).IsLocal)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkAccessKind(this: *mut core::ffi::c_void, result__: *mut XboxLiveNetworkAccessKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveDeviceAddress", "NetworkAccessKind", 14usize, |this| ((**(this as *const *const IXboxLiveDeviceAddress_Vtbl)).NetworkAccessKind)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SnapshotChanged,
+            RemoveSnapshotChanged,
+            GetSnapshotAsBase64,
+            #[cfg(feature = "Storage_Streams")]
+            GetSnapshotAsBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetSnapshotAsBuffer: 0,
+            GetSnapshotAsBytes,
+            Compare,
+            IsValid,
+            IsLocal,
+            NetworkAccessKind,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXboxLiveDeviceAddress as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IXboxLiveDeviceAddressStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromSnapshotBase64(this: *mut core::ffi::c_void, base64: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveDeviceAddressStatics", "CreateFromSnapshotBase64", 6usize, |this| ((**(this as *const *const IXboxLiveDeviceAddressStatics_Vtbl)).CreateFromSnapshotBase64)(this, base64, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromSnapshotBuffer(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveDeviceAddressStatics", "CreateFromSnapshotBuffer", 7usize, |this| ((**(this as *const *const IXboxLiveDeviceAddressStatics_Vtbl)).CreateFromSnapshotBuffer)(this, buffer, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromSnapshotBytes(this: *mut core::ffi::c_void, buffer_array_size: u32, buffer: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveDeviceAddressStatics", "CreateFromSnapshotBytes", 8usize, |this| ((**(this as *const *const IXboxLiveDeviceAddressStatics_Vtbl)).CreateFromSnapshotBytes)(this, buffer_array_size, buffer, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocal(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveDeviceAddressStatics", "GetLocal", 9usize, |this| ((**(this as *const *const IXboxLiveDeviceAddressStatics_Vtbl)).GetLocal)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSnapshotBytesSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveDeviceAddressStatics", "MaxSnapshotBytesSize", 10usize, |this| ((**(this as *const *const IXboxLiveDeviceAddressStatics_Vtbl)).MaxSnapshotBytesSize)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromSnapshotBase64,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromSnapshotBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromSnapshotBuffer: 0,
+            CreateFromSnapshotBytes,
+            GetLocal,
+            MaxSnapshotBytesSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXboxLiveDeviceAddressStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXboxLiveEndpointPair_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPair", "StateChanged", 6usize, |this| ((**(this as *const *const IXboxLiveEndpointPair_Vtbl)).StateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPair", "RemoveStateChanged", 7usize, |this| ((**(this as *const *const IXboxLiveEndpointPair_Vtbl)).RemoveStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPair", "DeleteAsync", 8usize, |this| ((**(this as *const *const IXboxLiveEndpointPair_Vtbl)).DeleteAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRemoteSocketAddressBytes(this: *mut core::ffi::c_void, socketAddress_array_size: u32, socketaddress: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPair", "GetRemoteSocketAddressBytes", 9usize, |this| ((**(this as *const *const IXboxLiveEndpointPair_Vtbl)).GetRemoteSocketAddressBytes)(this, socketAddress_array_size, socketaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalSocketAddressBytes(this: *mut core::ffi::c_void, socketAddress_array_size: u32, socketaddress: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPair", "GetLocalSocketAddressBytes", 10usize, |this| ((**(this as *const *const IXboxLiveEndpointPair_Vtbl)).GetLocalSocketAddressBytes)(this, socketAddress_array_size, socketaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut XboxLiveEndpointPairState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPair", "State", 11usize, |this| ((**(this as *const *const IXboxLiveEndpointPair_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Template(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPair", "Template", 12usize, |this| ((**(this as *const *const IXboxLiveEndpointPair_Vtbl)).Template)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteDeviceAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPair", "RemoteDeviceAddress", 13usize, |this| ((**(this as *const *const IXboxLiveEndpointPair_Vtbl)).RemoteDeviceAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteHostName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPair", "RemoteHostName", 14usize, |this| ((**(this as *const *const IXboxLiveEndpointPair_Vtbl)).RemoteHostName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemotePort(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPair", "RemotePort", 15usize, |this| ((**(this as *const *const IXboxLiveEndpointPair_Vtbl)).RemotePort)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalHostName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPair", "LocalHostName", 16usize, |this| ((**(this as *const *const IXboxLiveEndpointPair_Vtbl)).LocalHostName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalPort(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPair", "LocalPort", 17usize, |this| ((**(this as *const *const IXboxLiveEndpointPair_Vtbl)).LocalPort)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StateChanged,
+            RemoveStateChanged,
+            DeleteAsync,
+            GetRemoteSocketAddressBytes,
+            GetLocalSocketAddressBytes,
+            State,
+            Template,
+            RemoteDeviceAddress,
+            RemoteHostName,
+            RemotePort,
+            LocalHostName,
+            LocalPort,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXboxLiveEndpointPair as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXboxLiveEndpointPairCreationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairCreationResult", "DeviceAddress", 6usize, |this| ((**(this as *const *const IXboxLiveEndpointPairCreationResult_Vtbl)).DeviceAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut XboxLiveEndpointPairCreationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairCreationResult", "Status", 7usize, |this| ((**(this as *const *const IXboxLiveEndpointPairCreationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsExistingPathEvaluation(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairCreationResult", "IsExistingPathEvaluation", 8usize, |this| ((**(this as *const *const IXboxLiveEndpointPairCreationResult_Vtbl)).IsExistingPathEvaluation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndpointPair(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairCreationResult", "EndpointPair", 9usize, |this| ((**(this as *const *const IXboxLiveEndpointPairCreationResult_Vtbl)).EndpointPair)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceAddress,
+            Status,
+            IsExistingPathEvaluation,
+            EndpointPair,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXboxLiveEndpointPairCreationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXboxLiveEndpointPairStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OldState(this: *mut core::ffi::c_void, result__: *mut XboxLiveEndpointPairState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairStateChangedEventArgs", "OldState", 6usize, |this| ((**(this as *const *const IXboxLiveEndpointPairStateChangedEventArgs_Vtbl)).OldState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewState(this: *mut core::ffi::c_void, result__: *mut XboxLiveEndpointPairState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairStateChangedEventArgs", "NewState", 7usize, |this| ((**(this as *const *const IXboxLiveEndpointPairStateChangedEventArgs_Vtbl)).NewState)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OldState, NewState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXboxLiveEndpointPairStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXboxLiveEndpointPairStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindEndpointPairBySocketAddressBytes(this: *mut core::ffi::c_void, localSocketAddress_array_size: u32, localsocketaddress: *const u8, remoteSocketAddress_array_size: u32, remotesocketaddress: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairStatics", "FindEndpointPairBySocketAddressBytes", 6usize, |this| ((**(this as *const *const IXboxLiveEndpointPairStatics_Vtbl)).FindEndpointPairBySocketAddressBytes)(this, localSocketAddress_array_size, localsocketaddress, remoteSocketAddress_array_size, remotesocketaddress, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindEndpointPairByHostNamesAndPorts(this: *mut core::ffi::c_void, localhostname: *mut core::ffi::c_void, localport: core::mem::MaybeUninit<windows_core::HSTRING>, remotehostname: *mut core::ffi::c_void, remoteport: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairStatics", "FindEndpointPairByHostNamesAndPorts", 7usize, |this| ((**(this as *const *const IXboxLiveEndpointPairStatics_Vtbl)).FindEndpointPairByHostNamesAndPorts)(this, localhostname, localport, remotehostname, remoteport, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FindEndpointPairBySocketAddressBytes,
+            FindEndpointPairByHostNamesAndPorts,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXboxLiveEndpointPairStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IXboxLiveEndpointPairTemplate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InboundEndpointPairCreated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairTemplate", "InboundEndpointPairCreated", 6usize, |this| ((**(this as *const *const IXboxLiveEndpointPairTemplate_Vtbl)).InboundEndpointPairCreated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveInboundEndpointPairCreated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairTemplate", "RemoveInboundEndpointPairCreated", 7usize, |this| ((**(this as *const *const IXboxLiveEndpointPairTemplate_Vtbl)).RemoveInboundEndpointPairCreated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEndpointPairDefaultAsync(this: *mut core::ffi::c_void, deviceaddress: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairTemplate", "CreateEndpointPairDefaultAsync", 8usize, |this| ((**(this as *const *const IXboxLiveEndpointPairTemplate_Vtbl)).CreateEndpointPairDefaultAsync)(this, deviceaddress, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEndpointPairWithBehaviorsAsync(this: *mut core::ffi::c_void, deviceaddress: *mut core::ffi::c_void, behaviors: XboxLiveEndpointPairCreationBehaviors, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairTemplate", "CreateEndpointPairWithBehaviorsAsync", 9usize, |this| ((**(this as *const *const IXboxLiveEndpointPairTemplate_Vtbl)).CreateEndpointPairWithBehaviorsAsync)(this, deviceaddress, behaviors, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEndpointPairForPortsDefaultAsync(this: *mut core::ffi::c_void, deviceaddress: *mut core::ffi::c_void, initiatorport: core::mem::MaybeUninit<windows_core::HSTRING>, acceptorport: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairTemplate", "CreateEndpointPairForPortsDefaultAsync", 10usize, |this| ((**(this as *const *const IXboxLiveEndpointPairTemplate_Vtbl)).CreateEndpointPairForPortsDefaultAsync)(this, deviceaddress, initiatorport, acceptorport, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEndpointPairForPortsWithBehaviorsAsync(this: *mut core::ffi::c_void, deviceaddress: *mut core::ffi::c_void, initiatorport: core::mem::MaybeUninit<windows_core::HSTRING>, acceptorport: core::mem::MaybeUninit<windows_core::HSTRING>, behaviors: XboxLiveEndpointPairCreationBehaviors, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairTemplate", "CreateEndpointPairForPortsWithBehaviorsAsync", 11usize, |this| ((**(this as *const *const IXboxLiveEndpointPairTemplate_Vtbl)).CreateEndpointPairForPortsWithBehaviorsAsync)(this, deviceaddress, initiatorport, acceptorport, behaviors, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairTemplate", "Name", 12usize, |this| ((**(this as *const *const IXboxLiveEndpointPairTemplate_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SocketKind(this: *mut core::ffi::c_void, result__: *mut XboxLiveSocketKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairTemplate", "SocketKind", 13usize, |this| ((**(this as *const *const IXboxLiveEndpointPairTemplate_Vtbl)).SocketKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitiatorBoundPortRangeLower(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairTemplate", "InitiatorBoundPortRangeLower", 14usize, |this| ((**(this as *const *const IXboxLiveEndpointPairTemplate_Vtbl)).InitiatorBoundPortRangeLower)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitiatorBoundPortRangeUpper(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairTemplate", "InitiatorBoundPortRangeUpper", 15usize, |this| ((**(this as *const *const IXboxLiveEndpointPairTemplate_Vtbl)).InitiatorBoundPortRangeUpper)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptorBoundPortRangeLower(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairTemplate", "AcceptorBoundPortRangeLower", 16usize, |this| ((**(this as *const *const IXboxLiveEndpointPairTemplate_Vtbl)).AcceptorBoundPortRangeLower)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptorBoundPortRangeUpper(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairTemplate", "AcceptorBoundPortRangeUpper", 17usize, |this| ((**(this as *const *const IXboxLiveEndpointPairTemplate_Vtbl)).AcceptorBoundPortRangeUpper)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EndpointPairs(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairTemplate", "EndpointPairs", 18usize, |this| ((**(this as *const *const IXboxLiveEndpointPairTemplate_Vtbl)).EndpointPairs)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InboundEndpointPairCreated,
+            RemoveInboundEndpointPairCreated,
+            CreateEndpointPairDefaultAsync,
+            CreateEndpointPairWithBehaviorsAsync,
+            CreateEndpointPairForPortsDefaultAsync,
+            CreateEndpointPairForPortsWithBehaviorsAsync,
+            Name,
+            SocketKind,
+            InitiatorBoundPortRangeLower,
+            InitiatorBoundPortRangeUpper,
+            AcceptorBoundPortRangeLower,
+            AcceptorBoundPortRangeUpper,
+            #[cfg(feature = "Foundation_Collections")]
+            EndpointPairs,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EndpointPairs: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXboxLiveEndpointPairTemplate as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IXboxLiveEndpointPairTemplateStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTemplateByName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairTemplateStatics", "GetTemplateByName", 6usize, |this| ((**(this as *const *const IXboxLiveEndpointPairTemplateStatics_Vtbl)).GetTemplateByName)(this, name, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Templates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveEndpointPairTemplateStatics", "Templates", 7usize, |this| ((**(this as *const *const IXboxLiveEndpointPairTemplateStatics_Vtbl)).Templates)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTemplateByName,
+            #[cfg(feature = "Foundation_Collections")]
+            Templates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Templates: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXboxLiveEndpointPairTemplateStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXboxLiveInboundEndpointPairCreatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EndpointPair(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveInboundEndpointPairCreatedEventArgs", "EndpointPair", 6usize, |this| ((**(this as *const *const IXboxLiveInboundEndpointPairCreatedEventArgs_Vtbl)).EndpointPair)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EndpointPair }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXboxLiveInboundEndpointPairCreatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IXboxLiveQualityOfServiceMeasurement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MeasureAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "MeasureAsync", 6usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).MeasureAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetMetricResultsForDevice(this: *mut core::ffi::c_void, deviceaddress: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "GetMetricResultsForDevice", 7usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).GetMetricResultsForDevice)(this, deviceaddress, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetMetricResultsForMetric(this: *mut core::ffi::c_void, metric: XboxLiveQualityOfServiceMetric, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "GetMetricResultsForMetric", 8usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).GetMetricResultsForMetric)(this, metric, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMetricResult(this: *mut core::ffi::c_void, deviceaddress: *mut core::ffi::c_void, metric: XboxLiveQualityOfServiceMetric, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "GetMetricResult", 9usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).GetMetricResult)(this, deviceaddress, metric, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivatePayloadResult(this: *mut core::ffi::c_void, deviceaddress: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "GetPrivatePayloadResult", 10usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).GetPrivatePayloadResult)(this, deviceaddress, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Metrics(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "Metrics", 11usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).Metrics)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DeviceAddresses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "DeviceAddresses", 12usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).DeviceAddresses)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShouldRequestPrivatePayloads(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "ShouldRequestPrivatePayloads", 13usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).ShouldRequestPrivatePayloads)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShouldRequestPrivatePayloads(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "SetShouldRequestPrivatePayloads", 14usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).SetShouldRequestPrivatePayloads)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeoutInMilliseconds(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "TimeoutInMilliseconds", 15usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).TimeoutInMilliseconds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimeoutInMilliseconds(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "SetTimeoutInMilliseconds", 16usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).SetTimeoutInMilliseconds)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfProbesToAttempt(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "NumberOfProbesToAttempt", 17usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).NumberOfProbesToAttempt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumberOfProbesToAttempt(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "SetNumberOfProbesToAttempt", 18usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).SetNumberOfProbesToAttempt)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfResultsPending(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "NumberOfResultsPending", 19usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).NumberOfResultsPending)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn MetricResults(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "MetricResults", 20usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).MetricResults)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PrivatePayloadResults(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurement", "PrivatePayloadResults", 21usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurement_Vtbl)).PrivatePayloadResults)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MeasureAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetMetricResultsForDevice,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetMetricResultsForDevice: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetMetricResultsForMetric,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetMetricResultsForMetric: 0,
+            GetMetricResult,
+            GetPrivatePayloadResult,
+            #[cfg(feature = "Foundation_Collections")]
+            Metrics,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Metrics: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            DeviceAddresses,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DeviceAddresses: 0,
+            ShouldRequestPrivatePayloads,
+            SetShouldRequestPrivatePayloads,
+            TimeoutInMilliseconds,
+            SetTimeoutInMilliseconds,
+            NumberOfProbesToAttempt,
+            SetNumberOfProbesToAttempt,
+            NumberOfResultsPending,
+            #[cfg(feature = "Foundation_Collections")]
+            MetricResults,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            MetricResults: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            PrivatePayloadResults,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PrivatePayloadResults: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXboxLiveQualityOfServiceMeasurement as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IXboxLiveQualityOfServiceMeasurementStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PublishPrivatePayloadBytes(this: *mut core::ffi::c_void, payload_array_size: u32, payload: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurementStatics", "PublishPrivatePayloadBytes", 6usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurementStatics_Vtbl)).PublishPrivatePayloadBytes)(this, payload_array_size, payload), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearPrivatePayload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurementStatics", "ClearPrivatePayload", 7usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurementStatics_Vtbl)).ClearPrivatePayload)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSimultaneousProbeConnections(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurementStatics", "MaxSimultaneousProbeConnections", 8usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurementStatics_Vtbl)).MaxSimultaneousProbeConnections)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxSimultaneousProbeConnections(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurementStatics", "SetMaxSimultaneousProbeConnections", 9usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurementStatics_Vtbl)).SetMaxSimultaneousProbeConnections)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSystemOutboundBandwidthConstrained(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurementStatics", "IsSystemOutboundBandwidthConstrained", 10usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurementStatics_Vtbl)).IsSystemOutboundBandwidthConstrained)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsSystemOutboundBandwidthConstrained(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurementStatics", "SetIsSystemOutboundBandwidthConstrained", 11usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurementStatics_Vtbl)).SetIsSystemOutboundBandwidthConstrained)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSystemInboundBandwidthConstrained(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurementStatics", "IsSystemInboundBandwidthConstrained", 12usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurementStatics_Vtbl)).IsSystemInboundBandwidthConstrained)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsSystemInboundBandwidthConstrained(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurementStatics", "SetIsSystemInboundBandwidthConstrained", 13usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurementStatics_Vtbl)).SetIsSystemInboundBandwidthConstrained)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn PublishedPrivatePayload(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurementStatics", "PublishedPrivatePayload", 14usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurementStatics_Vtbl)).PublishedPrivatePayload)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetPublishedPrivatePayload(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurementStatics", "SetPublishedPrivatePayload", 15usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurementStatics_Vtbl)).SetPublishedPrivatePayload)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPrivatePayloadSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMeasurementStatics", "MaxPrivatePayloadSize", 16usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMeasurementStatics_Vtbl)).MaxPrivatePayloadSize)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PublishPrivatePayloadBytes,
+            ClearPrivatePayload,
+            MaxSimultaneousProbeConnections,
+            SetMaxSimultaneousProbeConnections,
+            IsSystemOutboundBandwidthConstrained,
+            SetIsSystemOutboundBandwidthConstrained,
+            IsSystemInboundBandwidthConstrained,
+            SetIsSystemInboundBandwidthConstrained,
+            #[cfg(feature = "Storage_Streams")]
+            PublishedPrivatePayload,
+            #[cfg(not(feature = "Storage_Streams"))]
+            PublishedPrivatePayload: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetPublishedPrivatePayload,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetPublishedPrivatePayload: 0,
+            MaxPrivatePayloadSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXboxLiveQualityOfServiceMeasurementStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXboxLiveQualityOfServiceMetricResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut XboxLiveQualityOfServiceMeasurementStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMetricResult", "Status", 6usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMetricResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMetricResult", "DeviceAddress", 7usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMetricResult_Vtbl)).DeviceAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Metric(this: *mut core::ffi::c_void, result__: *mut XboxLiveQualityOfServiceMetric) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMetricResult", "Metric", 8usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMetricResult_Vtbl)).Metric)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServiceMetricResult", "Value", 9usize, |this| ((**(this as *const *const IXboxLiveQualityOfServiceMetricResult_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, DeviceAddress, Metric, Value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXboxLiveQualityOfServiceMetricResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IXboxLiveQualityOfServicePrivatePayloadResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut XboxLiveQualityOfServiceMeasurementStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServicePrivatePayloadResult", "Status", 6usize, |this| ((**(this as *const *const IXboxLiveQualityOfServicePrivatePayloadResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServicePrivatePayloadResult", "DeviceAddress", 7usize, |this| ((**(this as *const *const IXboxLiveQualityOfServicePrivatePayloadResult_Vtbl)).DeviceAddress)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXboxLiveQualityOfServicePrivatePayloadResult", "Value", 8usize, |this| ((**(this as *const *const IXboxLiveQualityOfServicePrivatePayloadResult_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            DeviceAddress,
+            #[cfg(feature = "Storage_Streams")]
+            Value,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Value: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXboxLiveQualityOfServicePrivatePayloadResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -263,6 +263,7 @@ impl DomainNameType {
 impl windows_core::TypeKind for DomainNameType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DomainNameType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DomainNameType").field(&self.0).finish()
@@ -281,6 +282,7 @@ impl HostNameSortOptions {
 impl windows_core::TypeKind for HostNameSortOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HostNameSortOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HostNameSortOptions").field(&self.0).finish()
@@ -334,6 +336,7 @@ impl HostNameType {
 impl windows_core::TypeKind for HostNameType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HostNameType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HostNameType").field(&self.0).finish()
@@ -342,3 +345,5 @@ impl core::fmt::Debug for HostNameType {
 impl windows_core::RuntimeType for HostNameType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Networking.HostNameType;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,119 @@
+impl windows_core::imp::ProxyVtable for IEndpointPair_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LocalHostName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEndpointPair", "LocalHostName", 6usize, |this| ((**(this as *const *const IEndpointPair_Vtbl)).LocalHostName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalHostName(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEndpointPair", "SetLocalHostName", 7usize, |this| ((**(this as *const *const IEndpointPair_Vtbl)).SetLocalHostName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalServiceName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEndpointPair", "LocalServiceName", 8usize, |this| ((**(this as *const *const IEndpointPair_Vtbl)).LocalServiceName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalServiceName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEndpointPair", "SetLocalServiceName", 9usize, |this| ((**(this as *const *const IEndpointPair_Vtbl)).SetLocalServiceName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteHostName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEndpointPair", "RemoteHostName", 10usize, |this| ((**(this as *const *const IEndpointPair_Vtbl)).RemoteHostName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteHostName(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEndpointPair", "SetRemoteHostName", 11usize, |this| ((**(this as *const *const IEndpointPair_Vtbl)).SetRemoteHostName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteServiceName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEndpointPair", "RemoteServiceName", 12usize, |this| ((**(this as *const *const IEndpointPair_Vtbl)).RemoteServiceName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteServiceName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEndpointPair", "SetRemoteServiceName", 13usize, |this| ((**(this as *const *const IEndpointPair_Vtbl)).SetRemoteServiceName)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LocalHostName,
+            SetLocalHostName,
+            LocalServiceName,
+            SetLocalServiceName,
+            RemoteHostName,
+            SetRemoteHostName,
+            RemoteServiceName,
+            SetRemoteServiceName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEndpointPair as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEndpointPairFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateEndpointPair(this: *mut core::ffi::c_void, localhostname: *mut core::ffi::c_void, localservicename: core::mem::MaybeUninit<windows_core::HSTRING>, remotehostname: *mut core::ffi::c_void, remoteservicename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEndpointPairFactory", "CreateEndpointPair", 6usize, |this| ((**(this as *const *const IEndpointPairFactory_Vtbl)).CreateEndpointPair)(this, localhostname, localservicename, remotehostname, remoteservicename, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateEndpointPair }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEndpointPairFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking_Connectivity")]
+impl windows_core::imp::ProxyVtable for IHostName_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Networking_Connectivity")]
+        unsafe extern "system" fn IPInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostName", "IPInformation", 6usize, |this| ((**(this as *const *const IHostName_Vtbl)).IPInformation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostName", "RawName", 7usize, |this| ((**(this as *const *const IHostName_Vtbl)).RawName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostName", "DisplayName", 8usize, |this| ((**(this as *const *const IHostName_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanonicalName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostName", "CanonicalName", 9usize, |this| ((**(this as *const *const IHostName_Vtbl)).CanonicalName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut HostNameType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostName", "Type", 10usize, |this| ((**(this as *const *const IHostName_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, hostname: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostName", "IsEqual", 11usize, |this| ((**(this as *const *const IHostName_Vtbl)).IsEqual)(this, hostname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Networking_Connectivity")]
+            IPInformation,
+            #[cfg(not(feature = "Networking_Connectivity"))]
+            IPInformation: 0,
+            RawName,
+            DisplayName,
+            CanonicalName,
+            Type,
+            IsEqual,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostName as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostNameFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateHostName(this: *mut core::ffi::c_void, hostname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostNameFactory", "CreateHostName", 6usize, |this| ((**(this as *const *const IHostNameFactory_Vtbl)).CreateHostName)(this, hostname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateHostName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostNameFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostNameStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Compare(this: *mut core::ffi::c_void, value1: core::mem::MaybeUninit<windows_core::HSTRING>, value2: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostNameStatics", "Compare", 6usize, |this| ((**(this as *const *const IHostNameStatics_Vtbl)).Compare)(this, value1, value2, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Compare }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostNameStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -24,3 +24,5 @@ impl CorePerceptionAutomation {
 impl windows_core::RuntimeName for CorePerceptionAutomation {
     const NAME: &'static str = "Windows.Perception.Automation.Core.CorePerceptionAutomation";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,12 @@
+impl windows_core::imp::ProxyVtable for ICorePerceptionAutomationStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetActivationFactoryProvider(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePerceptionAutomationStatics", "SetActivationFactoryProvider", 6usize, |this| ((**(this as *const *const ICorePerceptionAutomationStatics_Vtbl)).SetActivationFactoryProvider)(this, provider), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetActivationFactoryProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorePerceptionAutomationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
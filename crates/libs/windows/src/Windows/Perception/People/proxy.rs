@@ -0,0 +1,212 @@
+#[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for IEyesPose_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsCalibrationValid(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEyesPose", "IsCalibrationValid", 6usize, |this| ((**(this as *const *const IEyesPose_Vtbl)).IsCalibrationValid)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn Gaze(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEyesPose", "Gaze", 7usize, |this| ((**(this as *const *const IEyesPose_Vtbl)).Gaze)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateTimestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEyesPose", "UpdateTimestamp", 8usize, |this| ((**(this as *const *const IEyesPose_Vtbl)).UpdateTimestamp)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsCalibrationValid,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            Gaze,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            Gaze: 0,
+            UpdateTimestamp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEyesPose as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Input")]
+impl windows_core::imp::ProxyVtable for IEyesPoseStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEyesPoseStatics", "IsSupported", 6usize, |this| ((**(this as *const *const IEyesPoseStatics_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Input")]
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEyesPoseStatics", "RequestAccessAsync", 7usize, |this| ((**(this as *const *const IEyesPoseStatics_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupported,
+            #[cfg(feature = "UI_Input")]
+            RequestAccessAsync,
+            #[cfg(not(feature = "UI_Input"))]
+            RequestAccessAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEyesPoseStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Input_Spatial")]
+impl windows_core::imp::ProxyVtable for IHandMeshObserver_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Input_Spatial")]
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandMeshObserver", "Source", 6usize, |this| ((**(this as *const *const IHandMeshObserver_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TriangleIndexCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandMeshObserver", "TriangleIndexCount", 7usize, |this| ((**(this as *const *const IHandMeshObserver_Vtbl)).TriangleIndexCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VertexCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandMeshObserver", "VertexCount", 8usize, |this| ((**(this as *const *const IHandMeshObserver_Vtbl)).VertexCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTriangleIndices(this: *mut core::ffi::c_void, indices_array_size: u32, indices: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandMeshObserver", "GetTriangleIndices", 9usize, |this| ((**(this as *const *const IHandMeshObserver_Vtbl)).GetTriangleIndices)(this, indices_array_size, indices), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVertexStateForPose(this: *mut core::ffi::c_void, handpose: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandMeshObserver", "GetVertexStateForPose", 10usize, |this| ((**(this as *const *const IHandMeshObserver_Vtbl)).GetVertexStateForPose)(this, handpose, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NeutralPose(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandMeshObserver", "NeutralPose", 11usize, |this| ((**(this as *const *const IHandMeshObserver_Vtbl)).NeutralPose)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NeutralPoseVersion(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandMeshObserver", "NeutralPoseVersion", 12usize, |this| ((**(this as *const *const IHandMeshObserver_Vtbl)).NeutralPoseVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModelId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandMeshObserver", "ModelId", 13usize, |this| ((**(this as *const *const IHandMeshObserver_Vtbl)).ModelId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Input_Spatial")]
+            Source,
+            #[cfg(not(feature = "UI_Input_Spatial"))]
+            Source: 0,
+            TriangleIndexCount,
+            VertexCount,
+            GetTriangleIndices,
+            GetVertexStateForPose,
+            NeutralPose,
+            NeutralPoseVersion,
+            ModelId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHandMeshObserver as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for IHandMeshVertexState_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn CoordinateSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandMeshVertexState", "CoordinateSystem", 6usize, |this| ((**(this as *const *const IHandMeshVertexState_Vtbl)).CoordinateSystem)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn GetVertices(this: *mut core::ffi::c_void, vertices_array_size: u32, vertices: *mut HandMeshVertex) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandMeshVertexState", "GetVertices", 7usize, |this| ((**(this as *const *const IHandMeshVertexState_Vtbl)).GetVertices)(this, vertices_array_size, vertices), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateTimestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandMeshVertexState", "UpdateTimestamp", 8usize, |this| ((**(this as *const *const IHandMeshVertexState_Vtbl)).UpdateTimestamp)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Perception_Spatial")]
+            CoordinateSystem,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            CoordinateSystem: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            GetVertices,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            GetVertices: 0,
+            UpdateTimestamp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHandMeshVertexState as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for IHandPose_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn TryGetJoint(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, joint: HandJointKind, jointpose: *mut JointPose, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandPose", "TryGetJoint", 6usize, |this| ((**(this as *const *const IHandPose_Vtbl)).TryGetJoint)(this, coordinatesystem, joint, jointpose, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn TryGetJoints(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, joints_array_size: u32, joints: *const HandJointKind, jointPoses_array_size: u32, jointposes: *mut JointPose, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandPose", "TryGetJoints", 7usize, |this| ((**(this as *const *const IHandPose_Vtbl)).TryGetJoints)(this, coordinatesystem, joints_array_size, joints, jointPoses_array_size, jointposes, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn GetRelativeJoint(this: *mut core::ffi::c_void, joint: HandJointKind, referencejoint: HandJointKind, result__: *mut JointPose) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandPose", "GetRelativeJoint", 8usize, |this| ((**(this as *const *const IHandPose_Vtbl)).GetRelativeJoint)(this, joint, referencejoint, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn GetRelativeJoints(this: *mut core::ffi::c_void, joints_array_size: u32, joints: *const HandJointKind, referenceJoints_array_size: u32, referencejoints: *const HandJointKind, jointPoses_array_size: u32, jointposes: *mut JointPose) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandPose", "GetRelativeJoints", 9usize, |this| ((**(this as *const *const IHandPose_Vtbl)).GetRelativeJoints)(this, joints_array_size, joints, referenceJoints_array_size, referencejoints, jointPoses_array_size, jointposes), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            TryGetJoint,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            TryGetJoint: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            TryGetJoints,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            TryGetJoints: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            GetRelativeJoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            GetRelativeJoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            GetRelativeJoints,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            GetRelativeJoints: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHandPose as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IHeadPose_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeadPose", "Position", 6usize, |this| ((**(this as *const *const IHeadPose_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn ForwardDirection(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeadPose", "ForwardDirection", 7usize, |this| ((**(this as *const *const IHeadPose_Vtbl)).ForwardDirection)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn UpDirection(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeadPose", "UpDirection", 8usize, |this| ((**(this as *const *const IHeadPose_Vtbl)).UpDirection)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Position,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Position: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            ForwardDirection,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            ForwardDirection: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            UpDirection,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            UpDirection: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHeadPose as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
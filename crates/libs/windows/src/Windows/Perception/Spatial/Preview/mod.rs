@@ -158,3 +158,5 @@ impl SpatialGraphInteropPreview {
 impl windows_core::RuntimeName for SpatialGraphInteropPreview {
     const NAME: &'static str = "Windows.Perception.Spatial.Preview.SpatialGraphInteropPreview";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,96 @@
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialGraphInteropFrameOfReferencePreview_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CoordinateSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGraphInteropFrameOfReferencePreview", "CoordinateSystem", 6usize, |this| ((**(this as *const *const ISpatialGraphInteropFrameOfReferencePreview_Vtbl)).CoordinateSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NodeId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGraphInteropFrameOfReferencePreview", "NodeId", 7usize, |this| ((**(this as *const *const ISpatialGraphInteropFrameOfReferencePreview_Vtbl)).NodeId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CoordinateSystemToNodeTransform(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Matrix4x4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGraphInteropFrameOfReferencePreview", "CoordinateSystemToNodeTransform", 8usize, |this| ((**(this as *const *const ISpatialGraphInteropFrameOfReferencePreview_Vtbl)).CoordinateSystemToNodeTransform)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CoordinateSystem,
+            NodeId,
+            #[cfg(feature = "Foundation_Numerics")]
+            CoordinateSystemToNodeTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CoordinateSystemToNodeTransform: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialGraphInteropFrameOfReferencePreview as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialGraphInteropPreviewStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateCoordinateSystemForNode(this: *mut core::ffi::c_void, nodeid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGraphInteropPreviewStatics", "CreateCoordinateSystemForNode", 6usize, |this| ((**(this as *const *const ISpatialGraphInteropPreviewStatics_Vtbl)).CreateCoordinateSystemForNode)(this, nodeid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CreateCoordinateSystemForNodeWithPosition(this: *mut core::ffi::c_void, nodeid: windows_core::GUID, relativeposition: super::super::super::Foundation::Numerics::Vector3, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGraphInteropPreviewStatics", "CreateCoordinateSystemForNodeWithPosition", 7usize, |this| ((**(this as *const *const ISpatialGraphInteropPreviewStatics_Vtbl)).CreateCoordinateSystemForNodeWithPosition)(this, nodeid, relativeposition, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CreateCoordinateSystemForNodeWithPositionAndOrientation(this: *mut core::ffi::c_void, nodeid: windows_core::GUID, relativeposition: super::super::super::Foundation::Numerics::Vector3, relativeorientation: super::super::super::Foundation::Numerics::Quaternion, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGraphInteropPreviewStatics", "CreateCoordinateSystemForNodeWithPositionAndOrientation", 8usize, |this| ((**(this as *const *const ISpatialGraphInteropPreviewStatics_Vtbl)).CreateCoordinateSystemForNodeWithPositionAndOrientation)(this, nodeid, relativeposition, relativeorientation, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLocatorForNode(this: *mut core::ffi::c_void, nodeid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGraphInteropPreviewStatics", "CreateLocatorForNode", 9usize, |this| ((**(this as *const *const ISpatialGraphInteropPreviewStatics_Vtbl)).CreateLocatorForNode)(this, nodeid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateCoordinateSystemForNode,
+            #[cfg(feature = "Foundation_Numerics")]
+            CreateCoordinateSystemForNodeWithPosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CreateCoordinateSystemForNodeWithPosition: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            CreateCoordinateSystemForNodeWithPositionAndOrientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CreateCoordinateSystemForNodeWithPositionAndOrientation: 0,
+            CreateLocatorForNode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialGraphInteropPreviewStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialGraphInteropPreviewStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryCreateFrameOfReference(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGraphInteropPreviewStatics2", "TryCreateFrameOfReference", 6usize, |this| ((**(this as *const *const ISpatialGraphInteropPreviewStatics2_Vtbl)).TryCreateFrameOfReference)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryCreateFrameOfReferenceWithPosition(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, relativeposition: super::super::super::Foundation::Numerics::Vector3, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGraphInteropPreviewStatics2", "TryCreateFrameOfReferenceWithPosition", 7usize, |this| ((**(this as *const *const ISpatialGraphInteropPreviewStatics2_Vtbl)).TryCreateFrameOfReferenceWithPosition)(this, coordinatesystem, relativeposition, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryCreateFrameOfReferenceWithPositionAndOrientation(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, relativeposition: super::super::super::Foundation::Numerics::Vector3, relativeorientation: super::super::super::Foundation::Numerics::Quaternion, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGraphInteropPreviewStatics2", "TryCreateFrameOfReferenceWithPositionAndOrientation", 8usize, |this| ((**(this as *const *const ISpatialGraphInteropPreviewStatics2_Vtbl)).TryCreateFrameOfReferenceWithPositionAndOrientation)(this, coordinatesystem, relativeposition, relativeorientation, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TryCreateFrameOfReference,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryCreateFrameOfReferenceWithPosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryCreateFrameOfReferenceWithPosition: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryCreateFrameOfReferenceWithPositionAndOrientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryCreateFrameOfReferenceWithPositionAndOrientation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialGraphInteropPreviewStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -494,3 +494,5 @@ impl windows_core::RuntimeName for SpatialSurfaceObserver {
 }
 unsafe impl Send for SpatialSurfaceObserver {}
 unsafe impl Sync for SpatialSurfaceObserver {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,280 @@
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialSurfaceInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceInfo", "Id", 6usize, |this| ((**(this as *const *const ISpatialSurfaceInfo_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceInfo", "UpdateTime", 7usize, |this| ((**(this as *const *const ISpatialSurfaceInfo_Vtbl)).UpdateTime)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryGetBounds(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceInfo", "TryGetBounds", 8usize, |this| ((**(this as *const *const ISpatialSurfaceInfo_Vtbl)).TryGetBounds)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryComputeLatestMeshAsync(this: *mut core::ffi::c_void, maxtrianglespercubicmeter: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceInfo", "TryComputeLatestMeshAsync", 9usize, |this| ((**(this as *const *const ISpatialSurfaceInfo_Vtbl)).TryComputeLatestMeshAsync)(this, maxtrianglespercubicmeter, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryComputeLatestMeshWithOptionsAsync(this: *mut core::ffi::c_void, maxtrianglespercubicmeter: f64, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceInfo", "TryComputeLatestMeshWithOptionsAsync", 10usize, |this| ((**(this as *const *const ISpatialSurfaceInfo_Vtbl)).TryComputeLatestMeshWithOptionsAsync)(this, maxtrianglespercubicmeter, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            UpdateTime,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryGetBounds,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryGetBounds: 0,
+            TryComputeLatestMeshAsync,
+            TryComputeLatestMeshWithOptionsAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialSurfaceInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialSurfaceMesh_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SurfaceInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMesh", "SurfaceInfo", 6usize, |this| ((**(this as *const *const ISpatialSurfaceMesh_Vtbl)).SurfaceInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CoordinateSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMesh", "CoordinateSystem", 7usize, |this| ((**(this as *const *const ISpatialSurfaceMesh_Vtbl)).CoordinateSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TriangleIndices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMesh", "TriangleIndices", 8usize, |this| ((**(this as *const *const ISpatialSurfaceMesh_Vtbl)).TriangleIndices)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VertexPositions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMesh", "VertexPositions", 9usize, |this| ((**(this as *const *const ISpatialSurfaceMesh_Vtbl)).VertexPositions)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn VertexPositionScale(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMesh", "VertexPositionScale", 10usize, |this| ((**(this as *const *const ISpatialSurfaceMesh_Vtbl)).VertexPositionScale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VertexNormals(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMesh", "VertexNormals", 11usize, |this| ((**(this as *const *const ISpatialSurfaceMesh_Vtbl)).VertexNormals)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SurfaceInfo,
+            CoordinateSystem,
+            TriangleIndices,
+            VertexPositions,
+            #[cfg(feature = "Foundation_Numerics")]
+            VertexPositionScale,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            VertexPositionScale: 0,
+            VertexNormals,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialSurfaceMesh as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Graphics_DirectX", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ISpatialSurfaceMeshBuffer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMeshBuffer", "Format", 6usize, |this| ((**(this as *const *const ISpatialSurfaceMeshBuffer_Vtbl)).Format)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stride(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMeshBuffer", "Stride", 7usize, |this| ((**(this as *const *const ISpatialSurfaceMeshBuffer_Vtbl)).Stride)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ElementCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMeshBuffer", "ElementCount", 8usize, |this| ((**(this as *const *const ISpatialSurfaceMeshBuffer_Vtbl)).ElementCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMeshBuffer", "Data", 9usize, |this| ((**(this as *const *const ISpatialSurfaceMeshBuffer_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX")]
+            Format,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            Format: 0,
+            Stride,
+            ElementCount,
+            #[cfg(feature = "Storage_Streams")]
+            Data,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Data: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialSurfaceMeshBuffer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX")]
+impl windows_core::imp::ProxyVtable for ISpatialSurfaceMeshOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn VertexPositionFormat(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMeshOptions", "VertexPositionFormat", 6usize, |this| ((**(this as *const *const ISpatialSurfaceMeshOptions_Vtbl)).VertexPositionFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn SetVertexPositionFormat(this: *mut core::ffi::c_void, value: super::super::super::Graphics::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMeshOptions", "SetVertexPositionFormat", 7usize, |this| ((**(this as *const *const ISpatialSurfaceMeshOptions_Vtbl)).SetVertexPositionFormat)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn TriangleIndexFormat(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMeshOptions", "TriangleIndexFormat", 8usize, |this| ((**(this as *const *const ISpatialSurfaceMeshOptions_Vtbl)).TriangleIndexFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn SetTriangleIndexFormat(this: *mut core::ffi::c_void, value: super::super::super::Graphics::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMeshOptions", "SetTriangleIndexFormat", 9usize, |this| ((**(this as *const *const ISpatialSurfaceMeshOptions_Vtbl)).SetTriangleIndexFormat)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn VertexNormalFormat(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMeshOptions", "VertexNormalFormat", 10usize, |this| ((**(this as *const *const ISpatialSurfaceMeshOptions_Vtbl)).VertexNormalFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn SetVertexNormalFormat(this: *mut core::ffi::c_void, value: super::super::super::Graphics::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMeshOptions", "SetVertexNormalFormat", 11usize, |this| ((**(this as *const *const ISpatialSurfaceMeshOptions_Vtbl)).SetVertexNormalFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeVertexNormals(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMeshOptions", "IncludeVertexNormals", 12usize, |this| ((**(this as *const *const ISpatialSurfaceMeshOptions_Vtbl)).IncludeVertexNormals)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeVertexNormals(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMeshOptions", "SetIncludeVertexNormals", 13usize, |this| ((**(this as *const *const ISpatialSurfaceMeshOptions_Vtbl)).SetIncludeVertexNormals)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX")]
+            VertexPositionFormat,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            VertexPositionFormat: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            SetVertexPositionFormat,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            SetVertexPositionFormat: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            TriangleIndexFormat,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            TriangleIndexFormat: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            SetTriangleIndexFormat,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            SetTriangleIndexFormat: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            VertexNormalFormat,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            VertexNormalFormat: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            SetVertexNormalFormat,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            SetVertexNormalFormat: 0,
+            IncludeVertexNormals,
+            SetIncludeVertexNormals,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialSurfaceMeshOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX"))]
+impl windows_core::imp::ProxyVtable for ISpatialSurfaceMeshOptionsStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX"))]
+        unsafe extern "system" fn SupportedVertexPositionFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMeshOptionsStatics", "SupportedVertexPositionFormats", 6usize, |this| ((**(this as *const *const ISpatialSurfaceMeshOptionsStatics_Vtbl)).SupportedVertexPositionFormats)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX"))]
+        unsafe extern "system" fn SupportedTriangleIndexFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMeshOptionsStatics", "SupportedTriangleIndexFormats", 7usize, |this| ((**(this as *const *const ISpatialSurfaceMeshOptionsStatics_Vtbl)).SupportedTriangleIndexFormats)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX"))]
+        unsafe extern "system" fn SupportedVertexNormalFormats(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceMeshOptionsStatics", "SupportedVertexNormalFormats", 8usize, |this| ((**(this as *const *const ISpatialSurfaceMeshOptionsStatics_Vtbl)).SupportedVertexNormalFormats)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX"))]
+            SupportedVertexPositionFormats,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics_DirectX")))]
+            SupportedVertexPositionFormats: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX"))]
+            SupportedTriangleIndexFormats,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics_DirectX")))]
+            SupportedTriangleIndexFormats: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX"))]
+            SupportedVertexNormalFormats,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics_DirectX")))]
+            SupportedVertexNormalFormats: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialSurfaceMeshOptionsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISpatialSurfaceObserver_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetObservedSurfaces(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceObserver", "GetObservedSurfaces", 6usize, |this| ((**(this as *const *const ISpatialSurfaceObserver_Vtbl)).GetObservedSurfaces)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBoundingVolume(this: *mut core::ffi::c_void, bounds: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceObserver", "SetBoundingVolume", 7usize, |this| ((**(this as *const *const ISpatialSurfaceObserver_Vtbl)).SetBoundingVolume)(this, bounds), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetBoundingVolumes(this: *mut core::ffi::c_void, bounds: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceObserver", "SetBoundingVolumes", 8usize, |this| ((**(this as *const *const ISpatialSurfaceObserver_Vtbl)).SetBoundingVolumes)(this, bounds), |result| Some(*result))
+        }
+        unsafe extern "system" fn ObservedSurfacesChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceObserver", "ObservedSurfacesChanged", 9usize, |this| ((**(this as *const *const ISpatialSurfaceObserver_Vtbl)).ObservedSurfacesChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveObservedSurfacesChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceObserver", "RemoveObservedSurfacesChanged", 10usize, |this| ((**(this as *const *const ISpatialSurfaceObserver_Vtbl)).RemoveObservedSurfacesChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetObservedSurfaces,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetObservedSurfaces: 0,
+            SetBoundingVolume,
+            #[cfg(feature = "Foundation_Collections")]
+            SetBoundingVolumes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetBoundingVolumes: 0,
+            ObservedSurfacesChanged,
+            RemoveObservedSurfacesChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialSurfaceObserver as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialSurfaceObserverStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceObserverStatics", "RequestAccessAsync", 6usize, |this| ((**(this as *const *const ISpatialSurfaceObserverStatics_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestAccessAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialSurfaceObserverStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialSurfaceObserverStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialSurfaceObserverStatics2", "IsSupported", 6usize, |this| ((**(this as *const *const ISpatialSurfaceObserverStatics2_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialSurfaceObserverStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1585,6 +1585,7 @@ impl SpatialAnchorExportPurpose {
 impl windows_core::TypeKind for SpatialAnchorExportPurpose {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialAnchorExportPurpose {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialAnchorExportPurpose").field(&self.0).finish()
@@ -1607,6 +1608,7 @@ impl SpatialEntityWatcherStatus {
 impl windows_core::TypeKind for SpatialEntityWatcherStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialEntityWatcherStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialEntityWatcherStatus").field(&self.0).finish()
@@ -1628,6 +1630,7 @@ impl SpatialLocatability {
 impl windows_core::TypeKind for SpatialLocatability {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialLocatability {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialLocatability").field(&self.0).finish()
@@ -1646,6 +1649,7 @@ impl SpatialLookDirectionRange {
 impl windows_core::TypeKind for SpatialLookDirectionRange {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialLookDirectionRange {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialLookDirectionRange").field(&self.0).finish()
@@ -1664,6 +1668,7 @@ impl SpatialMovementRange {
 impl windows_core::TypeKind for SpatialMovementRange {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialMovementRange {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialMovementRange").field(&self.0).finish()
@@ -1684,6 +1689,7 @@ impl SpatialPerceptionAccessStatus {
 impl windows_core::TypeKind for SpatialPerceptionAccessStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialPerceptionAccessStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialPerceptionAccessStatus").field(&self.0).finish()
@@ -1694,7 +1700,8 @@ impl windows_core::RuntimeType for SpatialPerceptionAccessStatus {
 }
 #[repr(C)]
 #[cfg(feature = "Foundation_Numerics")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SpatialBoundingBox {
     pub Center: super::super::Foundation::Numerics::Vector3,
     pub Extents: super::super::Foundation::Numerics::Vector3,
@@ -1715,7 +1722,8 @@ impl Default for SpatialBoundingBox {
 }
 #[repr(C)]
 #[cfg(feature = "Foundation_Numerics")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SpatialBoundingFrustum {
     pub Near: super::super::Foundation::Numerics::Plane,
     pub Far: super::super::Foundation::Numerics::Plane,
@@ -1740,7 +1748,8 @@ impl Default for SpatialBoundingFrustum {
 }
 #[repr(C)]
 #[cfg(feature = "Foundation_Numerics")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SpatialBoundingOrientedBox {
     pub Center: super::super::Foundation::Numerics::Vector3,
     pub Extents: super::super::Foundation::Numerics::Vector3,
@@ -1762,7 +1771,8 @@ impl Default for SpatialBoundingOrientedBox {
 }
 #[repr(C)]
 #[cfg(feature = "Foundation_Numerics")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SpatialBoundingSphere {
     pub Center: super::super::Foundation::Numerics::Vector3,
     pub Radius: f32,
@@ -1783,7 +1793,8 @@ impl Default for SpatialBoundingSphere {
 }
 #[repr(C)]
 #[cfg(feature = "Foundation_Numerics")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SpatialRay {
     pub Origin: super::super::Foundation::Numerics::Vector3,
     pub Direction: super::super::Foundation::Numerics::Vector3,
@@ -1802,3 +1813,5 @@ impl Default for SpatialRay {
         unsafe { core::mem::zeroed() }
     }
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
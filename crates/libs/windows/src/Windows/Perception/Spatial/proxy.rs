@@ -0,0 +1,827 @@
+impl windows_core::imp::ProxyVtable for ISpatialAnchor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CoordinateSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchor", "CoordinateSystem", 6usize, |this| ((**(this as *const *const ISpatialAnchor_Vtbl)).CoordinateSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawCoordinateSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchor", "RawCoordinateSystem", 7usize, |this| ((**(this as *const *const ISpatialAnchor_Vtbl)).RawCoordinateSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawCoordinateSystemAdjusted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchor", "RawCoordinateSystemAdjusted", 8usize, |this| ((**(this as *const *const ISpatialAnchor_Vtbl)).RawCoordinateSystemAdjusted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRawCoordinateSystemAdjusted(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchor", "RemoveRawCoordinateSystemAdjusted", 9usize, |this| ((**(this as *const *const ISpatialAnchor_Vtbl)).RemoveRawCoordinateSystemAdjusted)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CoordinateSystem,
+            RawCoordinateSystem,
+            RawCoordinateSystemAdjusted,
+            RemoveRawCoordinateSystemAdjusted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAnchor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAnchor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemovedByUser(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchor2", "RemovedByUser", 6usize, |this| ((**(this as *const *const ISpatialAnchor2_Vtbl)).RemovedByUser)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RemovedByUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAnchor2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAnchorExportSufficiency_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsMinimallySufficient(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorExportSufficiency", "IsMinimallySufficient", 6usize, |this| ((**(this as *const *const ISpatialAnchorExportSufficiency_Vtbl)).IsMinimallySufficient)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SufficiencyLevel(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorExportSufficiency", "SufficiencyLevel", 7usize, |this| ((**(this as *const *const ISpatialAnchorExportSufficiency_Vtbl)).SufficiencyLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecommendedSufficiencyLevel(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorExportSufficiency", "RecommendedSufficiencyLevel", 8usize, |this| ((**(this as *const *const ISpatialAnchorExportSufficiency_Vtbl)).RecommendedSufficiencyLevel)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsMinimallySufficient,
+            SufficiencyLevel,
+            RecommendedSufficiencyLevel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAnchorExportSufficiency as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISpatialAnchorExporter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAnchorExportSufficiencyAsync(this: *mut core::ffi::c_void, anchor: *mut core::ffi::c_void, purpose: SpatialAnchorExportPurpose, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorExporter", "GetAnchorExportSufficiencyAsync", 6usize, |this| ((**(this as *const *const ISpatialAnchorExporter_Vtbl)).GetAnchorExportSufficiencyAsync)(this, anchor, purpose, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn TryExportAnchorAsync(this: *mut core::ffi::c_void, anchor: *mut core::ffi::c_void, purpose: SpatialAnchorExportPurpose, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorExporter", "TryExportAnchorAsync", 7usize, |this| ((**(this as *const *const ISpatialAnchorExporter_Vtbl)).TryExportAnchorAsync)(this, anchor, purpose, stream, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAnchorExportSufficiencyAsync,
+            #[cfg(feature = "Storage_Streams")]
+            TryExportAnchorAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            TryExportAnchorAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAnchorExporter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAnchorExporterStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorExporterStatics", "GetDefault", 6usize, |this| ((**(this as *const *const ISpatialAnchorExporterStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorExporterStatics", "RequestAccessAsync", 7usize, |this| ((**(this as *const *const ISpatialAnchorExporterStatics_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault, RequestAccessAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAnchorExporterStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAnchorManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestStoreAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorManagerStatics", "RequestStoreAsync", 6usize, |this| ((**(this as *const *const ISpatialAnchorManagerStatics_Vtbl)).RequestStoreAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestStoreAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAnchorManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialAnchorRawCoordinateSystemAdjustedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn OldRawCoordinateSystemToNewRawCoordinateSystemTransform(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Matrix4x4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorRawCoordinateSystemAdjustedEventArgs", "OldRawCoordinateSystemToNewRawCoordinateSystemTransform", 6usize, |this| ((**(this as *const *const ISpatialAnchorRawCoordinateSystemAdjustedEventArgs_Vtbl)).OldRawCoordinateSystemToNewRawCoordinateSystemTransform)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            OldRawCoordinateSystemToNewRawCoordinateSystemTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            OldRawCoordinateSystemToNewRawCoordinateSystemTransform: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAnchorRawCoordinateSystemAdjustedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialAnchorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryCreateRelativeTo(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorStatics", "TryCreateRelativeTo", 6usize, |this| ((**(this as *const *const ISpatialAnchorStatics_Vtbl)).TryCreateRelativeTo)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryCreateWithPositionRelativeTo(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, position: super::super::Foundation::Numerics::Vector3, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorStatics", "TryCreateWithPositionRelativeTo", 7usize, |this| ((**(this as *const *const ISpatialAnchorStatics_Vtbl)).TryCreateWithPositionRelativeTo)(this, coordinatesystem, position, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryCreateWithPositionAndOrientationRelativeTo(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, position: super::super::Foundation::Numerics::Vector3, orientation: super::super::Foundation::Numerics::Quaternion, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorStatics", "TryCreateWithPositionAndOrientationRelativeTo", 8usize, |this| ((**(this as *const *const ISpatialAnchorStatics_Vtbl)).TryCreateWithPositionAndOrientationRelativeTo)(this, coordinatesystem, position, orientation, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TryCreateRelativeTo,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryCreateWithPositionRelativeTo,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryCreateWithPositionRelativeTo: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryCreateWithPositionAndOrientationRelativeTo,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryCreateWithPositionAndOrientationRelativeTo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAnchorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISpatialAnchorStore_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAllSavedAnchors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorStore", "GetAllSavedAnchors", 6usize, |this| ((**(this as *const *const ISpatialAnchorStore_Vtbl)).GetAllSavedAnchors)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySave(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, anchor: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorStore", "TrySave", 7usize, |this| ((**(this as *const *const ISpatialAnchorStore_Vtbl)).TrySave)(this, id, anchor, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorStore", "Remove", 8usize, |this| ((**(this as *const *const ISpatialAnchorStore_Vtbl)).Remove)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorStore", "Clear", 9usize, |this| ((**(this as *const *const ISpatialAnchorStore_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAllSavedAnchors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAllSavedAnchors: 0,
+            TrySave,
+            Remove,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAnchorStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for ISpatialAnchorTransferManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn TryImportAnchorsAsync(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorTransferManagerStatics", "TryImportAnchorsAsync", 6usize, |this| ((**(this as *const *const ISpatialAnchorTransferManagerStatics_Vtbl)).TryImportAnchorsAsync)(this, stream, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn TryExportAnchorsAsync(this: *mut core::ffi::c_void, anchors: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorTransferManagerStatics", "TryExportAnchorsAsync", 7usize, |this| ((**(this as *const *const ISpatialAnchorTransferManagerStatics_Vtbl)).TryExportAnchorsAsync)(this, anchors, stream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAnchorTransferManagerStatics", "RequestAccessAsync", 8usize, |this| ((**(this as *const *const ISpatialAnchorTransferManagerStatics_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams", feature = "deprecated"))]
+            TryImportAnchorsAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams", feature = "deprecated")))]
+            TryImportAnchorsAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams", feature = "deprecated"))]
+            TryExportAnchorsAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams", feature = "deprecated")))]
+            TryExportAnchorsAsync: 0,
+            #[cfg(feature = "deprecated")]
+            RequestAccessAsync,
+            #[cfg(not(feature = "deprecated"))]
+            RequestAccessAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAnchorTransferManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialBoundingVolume_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialBoundingVolume as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialBoundingVolumeStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn FromBox(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, r#box: SpatialBoundingBox, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialBoundingVolumeStatics", "FromBox", 6usize, |this| ((**(this as *const *const ISpatialBoundingVolumeStatics_Vtbl)).FromBox)(this, coordinatesystem, r#box, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn FromOrientedBox(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, r#box: SpatialBoundingOrientedBox, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialBoundingVolumeStatics", "FromOrientedBox", 7usize, |this| ((**(this as *const *const ISpatialBoundingVolumeStatics_Vtbl)).FromOrientedBox)(this, coordinatesystem, r#box, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn FromSphere(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, sphere: SpatialBoundingSphere, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialBoundingVolumeStatics", "FromSphere", 8usize, |this| ((**(this as *const *const ISpatialBoundingVolumeStatics_Vtbl)).FromSphere)(this, coordinatesystem, sphere, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn FromFrustum(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, frustum: SpatialBoundingFrustum, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialBoundingVolumeStatics", "FromFrustum", 9usize, |this| ((**(this as *const *const ISpatialBoundingVolumeStatics_Vtbl)).FromFrustum)(this, coordinatesystem, frustum, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            FromBox,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            FromBox: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            FromOrientedBox,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            FromOrientedBox: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            FromSphere,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            FromSphere: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            FromFrustum,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            FromFrustum: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialBoundingVolumeStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialCoordinateSystem_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryGetTransformTo(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialCoordinateSystem", "TryGetTransformTo", 6usize, |this| ((**(this as *const *const ISpatialCoordinateSystem_Vtbl)).TryGetTransformTo)(this, target, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryGetTransformTo,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryGetTransformTo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialCoordinateSystem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISpatialEntity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntity", "Id", 6usize, |this| ((**(this as *const *const ISpatialEntity_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Anchor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntity", "Anchor", 7usize, |this| ((**(this as *const *const ISpatialEntity_Vtbl)).Anchor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntity", "Properties", 8usize, |this| ((**(this as *const *const ISpatialEntity_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            Anchor,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialEntity as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialEntityAddedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Entity(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityAddedEventArgs", "Entity", 6usize, |this| ((**(this as *const *const ISpatialEntityAddedEventArgs_Vtbl)).Entity)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Entity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialEntityAddedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISpatialEntityFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithSpatialAnchor(this: *mut core::ffi::c_void, spatialanchor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityFactory", "CreateWithSpatialAnchor", 6usize, |this| ((**(this as *const *const ISpatialEntityFactory_Vtbl)).CreateWithSpatialAnchor)(this, spatialanchor, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWithSpatialAnchorAndProperties(this: *mut core::ffi::c_void, spatialanchor: *mut core::ffi::c_void, propertyset: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityFactory", "CreateWithSpatialAnchorAndProperties", 7usize, |this| ((**(this as *const *const ISpatialEntityFactory_Vtbl)).CreateWithSpatialAnchorAndProperties)(this, spatialanchor, propertyset, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateWithSpatialAnchor,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWithSpatialAnchorAndProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWithSpatialAnchorAndProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialEntityFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialEntityRemovedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Entity(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityRemovedEventArgs", "Entity", 6usize, |this| ((**(this as *const *const ISpatialEntityRemovedEventArgs_Vtbl)).Entity)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Entity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialEntityRemovedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialEntityStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, entity: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityStore", "SaveAsync", 6usize, |this| ((**(this as *const *const ISpatialEntityStore_Vtbl)).SaveAsync)(this, entity, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAsync(this: *mut core::ffi::c_void, entity: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityStore", "RemoveAsync", 7usize, |this| ((**(this as *const *const ISpatialEntityStore_Vtbl)).RemoveAsync)(this, entity, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEntityWatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityStore", "CreateEntityWatcher", 8usize, |this| ((**(this as *const *const ISpatialEntityStore_Vtbl)).CreateEntityWatcher)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SaveAsync, RemoveAsync, CreateEntityWatcher }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialEntityStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System_RemoteSystems")]
+impl windows_core::imp::ProxyVtable for ISpatialEntityStoreStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityStoreStatics", "IsSupported", 6usize, |this| ((**(this as *const *const ISpatialEntityStoreStatics_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System_RemoteSystems")]
+        unsafe extern "system" fn TryGetForRemoteSystemSession(this: *mut core::ffi::c_void, session: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityStoreStatics", "TryGetForRemoteSystemSession", 7usize, |this| ((**(this as *const *const ISpatialEntityStoreStatics_Vtbl)).TryGetForRemoteSystemSession)(this, session, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupported,
+            #[cfg(feature = "System_RemoteSystems")]
+            TryGetForRemoteSystemSession,
+            #[cfg(not(feature = "System_RemoteSystems"))]
+            TryGetForRemoteSystemSession: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialEntityStoreStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialEntityUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Entity(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityUpdatedEventArgs", "Entity", 6usize, |this| ((**(this as *const *const ISpatialEntityUpdatedEventArgs_Vtbl)).Entity)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Entity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialEntityUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialEntityWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut SpatialEntityWatcherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityWatcher", "Status", 6usize, |this| ((**(this as *const *const ISpatialEntityWatcher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Added(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityWatcher", "Added", 7usize, |this| ((**(this as *const *const ISpatialEntityWatcher_Vtbl)).Added)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityWatcher", "RemoveAdded", 8usize, |this| ((**(this as *const *const ISpatialEntityWatcher_Vtbl)).RemoveAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Updated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityWatcher", "Updated", 9usize, |this| ((**(this as *const *const ISpatialEntityWatcher_Vtbl)).Updated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityWatcher", "RemoveUpdated", 10usize, |this| ((**(this as *const *const ISpatialEntityWatcher_Vtbl)).RemoveUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Removed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityWatcher", "Removed", 11usize, |this| ((**(this as *const *const ISpatialEntityWatcher_Vtbl)).Removed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityWatcher", "RemoveRemoved", 12usize, |this| ((**(this as *const *const ISpatialEntityWatcher_Vtbl)).RemoveRemoved)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityWatcher", "EnumerationCompleted", 13usize, |this| ((**(this as *const *const ISpatialEntityWatcher_Vtbl)).EnumerationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnumerationCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityWatcher", "RemoveEnumerationCompleted", 14usize, |this| ((**(this as *const *const ISpatialEntityWatcher_Vtbl)).RemoveEnumerationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityWatcher", "Start", 15usize, |this| ((**(this as *const *const ISpatialEntityWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialEntityWatcher", "Stop", 16usize, |this| ((**(this as *const *const ISpatialEntityWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            Added,
+            RemoveAdded,
+            Updated,
+            RemoveUpdated,
+            Removed,
+            RemoveRemoved,
+            EnumerationCompleted,
+            RemoveEnumerationCompleted,
+            Start,
+            Stop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialEntityWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialLocation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocation", "Position", 6usize, |this| ((**(this as *const *const ISpatialLocation_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocation", "Orientation", 7usize, |this| ((**(this as *const *const ISpatialLocation_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn AbsoluteLinearVelocity(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocation", "AbsoluteLinearVelocity", 8usize, |this| ((**(this as *const *const ISpatialLocation_Vtbl)).AbsoluteLinearVelocity)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn AbsoluteLinearAcceleration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocation", "AbsoluteLinearAcceleration", 9usize, |this| ((**(this as *const *const ISpatialLocation_Vtbl)).AbsoluteLinearAcceleration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "deprecated"))]
+        unsafe extern "system" fn AbsoluteAngularVelocity(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocation", "AbsoluteAngularVelocity", 10usize, |this| ((**(this as *const *const ISpatialLocation_Vtbl)).AbsoluteAngularVelocity)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "deprecated"))]
+        unsafe extern "system" fn AbsoluteAngularAcceleration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocation", "AbsoluteAngularAcceleration", 11usize, |this| ((**(this as *const *const ISpatialLocation_Vtbl)).AbsoluteAngularAcceleration)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Position,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Position: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Orientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Orientation: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            AbsoluteLinearVelocity,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            AbsoluteLinearVelocity: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            AbsoluteLinearAcceleration,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            AbsoluteLinearAcceleration: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "deprecated"))]
+            AbsoluteAngularVelocity,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "deprecated")))]
+            AbsoluteAngularVelocity: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "deprecated"))]
+            AbsoluteAngularAcceleration,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "deprecated")))]
+            AbsoluteAngularAcceleration: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialLocation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialLocation2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn AbsoluteAngularVelocityAxisAngle(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocation2", "AbsoluteAngularVelocityAxisAngle", 6usize, |this| ((**(this as *const *const ISpatialLocation2_Vtbl)).AbsoluteAngularVelocityAxisAngle)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn AbsoluteAngularAccelerationAxisAngle(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocation2", "AbsoluteAngularAccelerationAxisAngle", 7usize, |this| ((**(this as *const *const ISpatialLocation2_Vtbl)).AbsoluteAngularAccelerationAxisAngle)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            AbsoluteAngularVelocityAxisAngle,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            AbsoluteAngularVelocityAxisAngle: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            AbsoluteAngularAccelerationAxisAngle,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            AbsoluteAngularAccelerationAxisAngle: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialLocation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialLocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Locatability(this: *mut core::ffi::c_void, result__: *mut SpatialLocatability) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocator", "Locatability", 6usize, |this| ((**(this as *const *const ISpatialLocator_Vtbl)).Locatability)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocatabilityChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocator", "LocatabilityChanged", 7usize, |this| ((**(this as *const *const ISpatialLocator_Vtbl)).LocatabilityChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLocatabilityChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocator", "RemoveLocatabilityChanged", 8usize, |this| ((**(this as *const *const ISpatialLocator_Vtbl)).RemoveLocatabilityChanged)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionalTrackingDeactivating(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocator", "PositionalTrackingDeactivating", 9usize, |this| ((**(this as *const *const ISpatialLocator_Vtbl)).PositionalTrackingDeactivating)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePositionalTrackingDeactivating(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocator", "RemovePositionalTrackingDeactivating", 10usize, |this| ((**(this as *const *const ISpatialLocator_Vtbl)).RemovePositionalTrackingDeactivating)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryLocateAtTimestamp(this: *mut core::ffi::c_void, timestamp: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocator", "TryLocateAtTimestamp", 11usize, |this| ((**(this as *const *const ISpatialLocator_Vtbl)).TryLocateAtTimestamp)(this, timestamp, coordinatesystem, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAttachedFrameOfReferenceAtCurrentHeading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocator", "CreateAttachedFrameOfReferenceAtCurrentHeading", 12usize, |this| ((**(this as *const *const ISpatialLocator_Vtbl)).CreateAttachedFrameOfReferenceAtCurrentHeading)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CreateAttachedFrameOfReferenceAtCurrentHeadingWithPosition(this: *mut core::ffi::c_void, relativeposition: super::super::Foundation::Numerics::Vector3, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocator", "CreateAttachedFrameOfReferenceAtCurrentHeadingWithPosition", 13usize, |this| ((**(this as *const *const ISpatialLocator_Vtbl)).CreateAttachedFrameOfReferenceAtCurrentHeadingWithPosition)(this, relativeposition, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CreateAttachedFrameOfReferenceAtCurrentHeadingWithPositionAndOrientation(this: *mut core::ffi::c_void, relativeposition: super::super::Foundation::Numerics::Vector3, relativeorientation: super::super::Foundation::Numerics::Quaternion, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocator", "CreateAttachedFrameOfReferenceAtCurrentHeadingWithPositionAndOrientation", 14usize, |this| ((**(this as *const *const ISpatialLocator_Vtbl)).CreateAttachedFrameOfReferenceAtCurrentHeadingWithPositionAndOrientation)(this, relativeposition, relativeorientation, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CreateAttachedFrameOfReferenceAtCurrentHeadingWithPositionAndOrientationAndRelativeHeading(this: *mut core::ffi::c_void, relativeposition: super::super::Foundation::Numerics::Vector3, relativeorientation: super::super::Foundation::Numerics::Quaternion, relativeheadinginradians: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocator", "CreateAttachedFrameOfReferenceAtCurrentHeadingWithPositionAndOrientationAndRelativeHeading", 15usize, |this| ((**(this as *const *const ISpatialLocator_Vtbl)).CreateAttachedFrameOfReferenceAtCurrentHeadingWithPositionAndOrientationAndRelativeHeading)(this, relativeposition, relativeorientation, relativeheadinginradians, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStationaryFrameOfReferenceAtCurrentLocation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocator", "CreateStationaryFrameOfReferenceAtCurrentLocation", 16usize, |this| ((**(this as *const *const ISpatialLocator_Vtbl)).CreateStationaryFrameOfReferenceAtCurrentLocation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CreateStationaryFrameOfReferenceAtCurrentLocationWithPosition(this: *mut core::ffi::c_void, relativeposition: super::super::Foundation::Numerics::Vector3, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocator", "CreateStationaryFrameOfReferenceAtCurrentLocationWithPosition", 17usize, |this| ((**(this as *const *const ISpatialLocator_Vtbl)).CreateStationaryFrameOfReferenceAtCurrentLocationWithPosition)(this, relativeposition, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CreateStationaryFrameOfReferenceAtCurrentLocationWithPositionAndOrientation(this: *mut core::ffi::c_void, relativeposition: super::super::Foundation::Numerics::Vector3, relativeorientation: super::super::Foundation::Numerics::Quaternion, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocator", "CreateStationaryFrameOfReferenceAtCurrentLocationWithPositionAndOrientation", 18usize, |this| ((**(this as *const *const ISpatialLocator_Vtbl)).CreateStationaryFrameOfReferenceAtCurrentLocationWithPositionAndOrientation)(this, relativeposition, relativeorientation, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CreateStationaryFrameOfReferenceAtCurrentLocationWithPositionAndOrientationAndRelativeHeading(this: *mut core::ffi::c_void, relativeposition: super::super::Foundation::Numerics::Vector3, relativeorientation: super::super::Foundation::Numerics::Quaternion, relativeheadinginradians: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocator", "CreateStationaryFrameOfReferenceAtCurrentLocationWithPositionAndOrientationAndRelativeHeading", 19usize, |this| ((**(this as *const *const ISpatialLocator_Vtbl)).CreateStationaryFrameOfReferenceAtCurrentLocationWithPositionAndOrientationAndRelativeHeading)(this, relativeposition, relativeorientation, relativeheadinginradians, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Locatability,
+            LocatabilityChanged,
+            RemoveLocatabilityChanged,
+            PositionalTrackingDeactivating,
+            RemovePositionalTrackingDeactivating,
+            TryLocateAtTimestamp,
+            CreateAttachedFrameOfReferenceAtCurrentHeading,
+            #[cfg(feature = "Foundation_Numerics")]
+            CreateAttachedFrameOfReferenceAtCurrentHeadingWithPosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CreateAttachedFrameOfReferenceAtCurrentHeadingWithPosition: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            CreateAttachedFrameOfReferenceAtCurrentHeadingWithPositionAndOrientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CreateAttachedFrameOfReferenceAtCurrentHeadingWithPositionAndOrientation: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            CreateAttachedFrameOfReferenceAtCurrentHeadingWithPositionAndOrientationAndRelativeHeading,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CreateAttachedFrameOfReferenceAtCurrentHeadingWithPositionAndOrientationAndRelativeHeading: 0,
+            CreateStationaryFrameOfReferenceAtCurrentLocation,
+            #[cfg(feature = "Foundation_Numerics")]
+            CreateStationaryFrameOfReferenceAtCurrentLocationWithPosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CreateStationaryFrameOfReferenceAtCurrentLocationWithPosition: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            CreateStationaryFrameOfReferenceAtCurrentLocationWithPositionAndOrientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CreateStationaryFrameOfReferenceAtCurrentLocationWithPositionAndOrientation: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            CreateStationaryFrameOfReferenceAtCurrentLocationWithPositionAndOrientationAndRelativeHeading,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CreateStationaryFrameOfReferenceAtCurrentLocationWithPositionAndOrientationAndRelativeHeading: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialLocator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialLocatorAttachedFrameOfReference_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn RelativePosition(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocatorAttachedFrameOfReference", "RelativePosition", 6usize, |this| ((**(this as *const *const ISpatialLocatorAttachedFrameOfReference_Vtbl)).RelativePosition)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetRelativePosition(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocatorAttachedFrameOfReference", "SetRelativePosition", 7usize, |this| ((**(this as *const *const ISpatialLocatorAttachedFrameOfReference_Vtbl)).SetRelativePosition)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn RelativeOrientation(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocatorAttachedFrameOfReference", "RelativeOrientation", 8usize, |this| ((**(this as *const *const ISpatialLocatorAttachedFrameOfReference_Vtbl)).RelativeOrientation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetRelativeOrientation(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocatorAttachedFrameOfReference", "SetRelativeOrientation", 9usize, |this| ((**(this as *const *const ISpatialLocatorAttachedFrameOfReference_Vtbl)).SetRelativeOrientation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdjustHeading(this: *mut core::ffi::c_void, headingoffsetinradians: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocatorAttachedFrameOfReference", "AdjustHeading", 10usize, |this| ((**(this as *const *const ISpatialLocatorAttachedFrameOfReference_Vtbl)).AdjustHeading)(this, headingoffsetinradians), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStationaryCoordinateSystemAtTimestamp(this: *mut core::ffi::c_void, timestamp: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocatorAttachedFrameOfReference", "GetStationaryCoordinateSystemAtTimestamp", 11usize, |this| ((**(this as *const *const ISpatialLocatorAttachedFrameOfReference_Vtbl)).GetStationaryCoordinateSystemAtTimestamp)(this, timestamp, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetRelativeHeadingAtTimestamp(this: *mut core::ffi::c_void, timestamp: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocatorAttachedFrameOfReference", "TryGetRelativeHeadingAtTimestamp", 12usize, |this| ((**(this as *const *const ISpatialLocatorAttachedFrameOfReference_Vtbl)).TryGetRelativeHeadingAtTimestamp)(this, timestamp, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            RelativePosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            RelativePosition: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetRelativePosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetRelativePosition: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            RelativeOrientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            RelativeOrientation: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetRelativeOrientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetRelativeOrientation: 0,
+            AdjustHeading,
+            GetStationaryCoordinateSystemAtTimestamp,
+            TryGetRelativeHeadingAtTimestamp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialLocatorAttachedFrameOfReference as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialLocatorPositionalTrackingDeactivatingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Canceled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocatorPositionalTrackingDeactivatingEventArgs", "Canceled", 6usize, |this| ((**(this as *const *const ISpatialLocatorPositionalTrackingDeactivatingEventArgs_Vtbl)).Canceled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanceled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocatorPositionalTrackingDeactivatingEventArgs", "SetCanceled", 7usize, |this| ((**(this as *const *const ISpatialLocatorPositionalTrackingDeactivatingEventArgs_Vtbl)).SetCanceled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Canceled, SetCanceled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialLocatorPositionalTrackingDeactivatingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialLocatorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialLocatorStatics", "GetDefault", 6usize, |this| ((**(this as *const *const ISpatialLocatorStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialLocatorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialStageFrameOfReference_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CoordinateSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialStageFrameOfReference", "CoordinateSystem", 6usize, |this| ((**(this as *const *const ISpatialStageFrameOfReference_Vtbl)).CoordinateSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MovementRange(this: *mut core::ffi::c_void, result__: *mut SpatialMovementRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialStageFrameOfReference", "MovementRange", 7usize, |this| ((**(this as *const *const ISpatialStageFrameOfReference_Vtbl)).MovementRange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LookDirectionRange(this: *mut core::ffi::c_void, result__: *mut SpatialLookDirectionRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialStageFrameOfReference", "LookDirectionRange", 8usize, |this| ((**(this as *const *const ISpatialStageFrameOfReference_Vtbl)).LookDirectionRange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCoordinateSystemAtCurrentLocation(this: *mut core::ffi::c_void, locator: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialStageFrameOfReference", "GetCoordinateSystemAtCurrentLocation", 9usize, |this| ((**(this as *const *const ISpatialStageFrameOfReference_Vtbl)).GetCoordinateSystemAtCurrentLocation)(this, locator, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryGetMovementBounds(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialStageFrameOfReference", "TryGetMovementBounds", 10usize, |this| ((**(this as *const *const ISpatialStageFrameOfReference_Vtbl)).TryGetMovementBounds)(this, coordinatesystem, result_size__, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CoordinateSystem,
+            MovementRange,
+            LookDirectionRange,
+            GetCoordinateSystemAtCurrentLocation,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryGetMovementBounds,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryGetMovementBounds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialStageFrameOfReference as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialStageFrameOfReferenceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialStageFrameOfReferenceStatics", "Current", 6usize, |this| ((**(this as *const *const ISpatialStageFrameOfReferenceStatics_Vtbl)).Current)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialStageFrameOfReferenceStatics", "CurrentChanged", 7usize, |this| ((**(this as *const *const ISpatialStageFrameOfReferenceStatics_Vtbl)).CurrentChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCurrentChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialStageFrameOfReferenceStatics", "RemoveCurrentChanged", 8usize, |this| ((**(this as *const *const ISpatialStageFrameOfReferenceStatics_Vtbl)).RemoveCurrentChanged)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestNewStageAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialStageFrameOfReferenceStatics", "RequestNewStageAsync", 9usize, |this| ((**(this as *const *const ISpatialStageFrameOfReferenceStatics_Vtbl)).RequestNewStageAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Current,
+            CurrentChanged,
+            RemoveCurrentChanged,
+            RequestNewStageAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialStageFrameOfReferenceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialStationaryFrameOfReference_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CoordinateSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialStationaryFrameOfReference", "CoordinateSystem", 6usize, |this| ((**(this as *const *const ISpatialStationaryFrameOfReference_Vtbl)).CoordinateSystem)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CoordinateSystem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialStationaryFrameOfReference as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
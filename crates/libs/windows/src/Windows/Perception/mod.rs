@@ -108,3 +108,5 @@ impl PerceptionTimestampHelper {
 impl windows_core::RuntimeName for PerceptionTimestampHelper {
     const NAME: &'static str = "Windows.Perception.PerceptionTimestampHelper";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
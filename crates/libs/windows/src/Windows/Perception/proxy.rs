@@ -0,0 +1,51 @@
+impl windows_core::imp::ProxyVtable for IPerceptionTimestamp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TargetTime(this: *mut core::ffi::c_void, result__: *mut super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerceptionTimestamp", "TargetTime", 6usize, |this| ((**(this as *const *const IPerceptionTimestamp_Vtbl)).TargetTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PredictionAmount(this: *mut core::ffi::c_void, result__: *mut super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerceptionTimestamp", "PredictionAmount", 7usize, |this| ((**(this as *const *const IPerceptionTimestamp_Vtbl)).PredictionAmount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TargetTime, PredictionAmount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPerceptionTimestamp as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPerceptionTimestamp2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SystemRelativeTargetTime(this: *mut core::ffi::c_void, result__: *mut super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerceptionTimestamp2", "SystemRelativeTargetTime", 6usize, |this| ((**(this as *const *const IPerceptionTimestamp2_Vtbl)).SystemRelativeTargetTime)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SystemRelativeTargetTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPerceptionTimestamp2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPerceptionTimestampHelperStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromHistoricalTargetTime(this: *mut core::ffi::c_void, targettime: super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerceptionTimestampHelperStatics", "FromHistoricalTargetTime", 6usize, |this| ((**(this as *const *const IPerceptionTimestampHelperStatics_Vtbl)).FromHistoricalTargetTime)(this, targettime, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromHistoricalTargetTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPerceptionTimestampHelperStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPerceptionTimestampHelperStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromSystemRelativeTargetTime(this: *mut core::ffi::c_void, targettime: super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerceptionTimestampHelperStatics2", "FromSystemRelativeTargetTime", 6usize, |this| ((**(this as *const *const IPerceptionTimestampHelperStatics2_Vtbl)).FromSystemRelativeTargetTime)(this, targettime, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromSystemRelativeTargetTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPerceptionTimestampHelperStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -34,6 +34,7 @@ impl ApplicationProfileModes {
 impl windows_core::TypeKind for ApplicationProfileModes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ApplicationProfileModes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ApplicationProfileModes").field(&self.0).finish()
@@ -75,3 +76,5 @@ impl core::ops::Not for ApplicationProfileModes {
 impl windows_core::RuntimeType for ApplicationProfileModes {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Phone.ApplicationModel.ApplicationProfileModes;u4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,12 @@
+impl windows_core::imp::ProxyVtable for IApplicationProfileStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Modes(this: *mut core::ffi::c_void, result__: *mut ApplicationProfileModes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationProfileStatics", "Modes", 6usize, |this| ((**(this as *const *const IApplicationProfileStatics_Vtbl)).Modes)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Modes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationProfileStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
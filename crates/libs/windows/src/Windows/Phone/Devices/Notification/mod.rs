@@ -54,3 +54,5 @@ impl windows_core::RuntimeName for VibrationDevice {
 }
 unsafe impl Send for VibrationDevice {}
 unsafe impl Sync for VibrationDevice {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,27 @@
+impl windows_core::imp::ProxyVtable for IVibrationDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Vibrate(this: *mut core::ffi::c_void, duration: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVibrationDevice", "Vibrate", 6usize, |this| ((**(this as *const *const IVibrationDevice_Vtbl)).Vibrate)(this, duration), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVibrationDevice", "Cancel", 7usize, |this| ((**(this as *const *const IVibrationDevice_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Vibrate, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVibrationDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVibrationDeviceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVibrationDeviceStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IVibrationDeviceStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVibrationDeviceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -76,3 +76,5 @@ impl windows_core::RuntimeName for Battery {
 }
 unsafe impl Send for Battery {}
 unsafe impl Sync for Battery {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,39 @@
+impl windows_core::imp::ProxyVtable for IBattery_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemainingChargePercent(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBattery", "RemainingChargePercent", 6usize, |this| ((**(this as *const *const IBattery_Vtbl)).RemainingChargePercent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemainingDischargeTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBattery", "RemainingDischargeTime", 7usize, |this| ((**(this as *const *const IBattery_Vtbl)).RemainingDischargeTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemainingChargePercentChanged(this: *mut core::ffi::c_void, changehandler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBattery", "RemainingChargePercentChanged", 8usize, |this| ((**(this as *const *const IBattery_Vtbl)).RemainingChargePercentChanged)(this, changehandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemainingChargePercentChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBattery", "RemoveRemainingChargePercentChanged", 9usize, |this| ((**(this as *const *const IBattery_Vtbl)).RemoveRemainingChargePercentChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RemainingChargePercent,
+            RemainingDischargeTime,
+            RemainingChargePercentChanged,
+            RemoveRemainingChargePercentChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBattery as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBatteryStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBatteryStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IBatteryStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBatteryStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -367,6 +367,7 @@ impl EnterpriseEnrollmentStatus {
 impl windows_core::TypeKind for EnterpriseEnrollmentStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnterpriseEnrollmentStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnterpriseEnrollmentStatus").field(&self.0).finish()
@@ -387,6 +388,7 @@ impl EnterpriseStatus {
 impl windows_core::TypeKind for EnterpriseStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnterpriseStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnterpriseStatus").field(&self.0).finish()
@@ -395,3 +397,5 @@ impl core::fmt::Debug for EnterpriseStatus {
 impl windows_core::RuntimeType for EnterpriseStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Phone.Management.Deployment.EnterpriseStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
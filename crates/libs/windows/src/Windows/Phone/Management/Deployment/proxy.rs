@@ -0,0 +1,202 @@
+impl windows_core::imp::ProxyVtable for IEnterprise_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterprise", "Id", 6usize, |this| ((**(this as *const *const IEnterprise_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterprise", "Name", 7usize, |this| ((**(this as *const *const IEnterprise_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WorkplaceId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterprise", "WorkplaceId", 8usize, |this| ((**(this as *const *const IEnterprise_Vtbl)).WorkplaceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnrollmentValidFrom(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterprise", "EnrollmentValidFrom", 9usize, |this| ((**(this as *const *const IEnterprise_Vtbl)).EnrollmentValidFrom)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnrollmentValidTo(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterprise", "EnrollmentValidTo", 10usize, |this| ((**(this as *const *const IEnterprise_Vtbl)).EnrollmentValidTo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut EnterpriseStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterprise", "Status", 11usize, |this| ((**(this as *const *const IEnterprise_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            Name,
+            WorkplaceId,
+            EnrollmentValidFrom,
+            EnrollmentValidTo,
+            Status,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnterprise as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEnterpriseEnrollmentManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EnrolledEnterprises(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseEnrollmentManager", "EnrolledEnterprises", 6usize, |this| ((**(this as *const *const IEnterpriseEnrollmentManager_Vtbl)).EnrolledEnterprises)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentEnterprise(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseEnrollmentManager", "CurrentEnterprise", 7usize, |this| ((**(this as *const *const IEnterpriseEnrollmentManager_Vtbl)).CurrentEnterprise)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateEnterprisesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseEnrollmentManager", "ValidateEnterprisesAsync", 8usize, |this| ((**(this as *const *const IEnterpriseEnrollmentManager_Vtbl)).ValidateEnterprisesAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestEnrollmentAsync(this: *mut core::ffi::c_void, enrollmenttoken: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseEnrollmentManager", "RequestEnrollmentAsync", 9usize, |this| ((**(this as *const *const IEnterpriseEnrollmentManager_Vtbl)).RequestEnrollmentAsync)(this, enrollmenttoken, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestUnenrollmentAsync(this: *mut core::ffi::c_void, enterprise: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseEnrollmentManager", "RequestUnenrollmentAsync", 10usize, |this| ((**(this as *const *const IEnterpriseEnrollmentManager_Vtbl)).RequestUnenrollmentAsync)(this, enterprise, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            EnrolledEnterprises,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EnrolledEnterprises: 0,
+            CurrentEnterprise,
+            ValidateEnterprisesAsync,
+            RequestEnrollmentAsync,
+            RequestUnenrollmentAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnterpriseEnrollmentManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnterpriseEnrollmentResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnrolledEnterprise(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseEnrollmentResult", "EnrolledEnterprise", 6usize, |this| ((**(this as *const *const IEnterpriseEnrollmentResult_Vtbl)).EnrolledEnterprise)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut EnterpriseEnrollmentStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseEnrollmentResult", "Status", 7usize, |this| ((**(this as *const *const IEnterpriseEnrollmentResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnrolledEnterprise, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnterpriseEnrollmentResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IInstallationManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddPackageAsync(this: *mut core::ffi::c_void, title: core::mem::MaybeUninit<windows_core::HSTRING>, sourcelocation: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationManagerStatics", "AddPackageAsync", 6usize, |this| ((**(this as *const *const IInstallationManagerStatics_Vtbl)).AddPackageAsync)(this, title, sourcelocation, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPackagePreloadedAsync(this: *mut core::ffi::c_void, title: core::mem::MaybeUninit<windows_core::HSTRING>, sourcelocation: *mut core::ffi::c_void, instanceid: core::mem::MaybeUninit<windows_core::HSTRING>, offerid: core::mem::MaybeUninit<windows_core::HSTRING>, license: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationManagerStatics", "AddPackagePreloadedAsync", 7usize, |this| ((**(this as *const *const IInstallationManagerStatics_Vtbl)).AddPackagePreloadedAsync)(this, title, sourcelocation, instanceid, offerid, license, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetPendingPackageInstalls(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationManagerStatics", "GetPendingPackageInstalls", 8usize, |this| ((**(this as *const *const IInstallationManagerStatics_Vtbl)).GetPendingPackageInstalls)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesForCurrentPublisher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationManagerStatics", "FindPackagesForCurrentPublisher", 9usize, |this| ((**(this as *const *const IInstallationManagerStatics_Vtbl)).FindPackagesForCurrentPublisher)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationManagerStatics", "FindPackages", 10usize, |this| ((**(this as *const *const IInstallationManagerStatics_Vtbl)).FindPackages)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddPackageAsync,
+            AddPackagePreloadedAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetPendingPackageInstalls,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetPendingPackageInstalls: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesForCurrentPublisher,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesForCurrentPublisher: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackages,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackages: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInstallationManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections", feature = "Management_Deployment"))]
+impl windows_core::imp::ProxyVtable for IInstallationManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Management_Deployment")]
+        unsafe extern "system" fn RemovePackageAsync(this: *mut core::ffi::c_void, packagefullname: core::mem::MaybeUninit<windows_core::HSTRING>, removaloptions: super::super::super::Management::Deployment::RemovalOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationManagerStatics2", "RemovePackageAsync", 6usize, |this| ((**(this as *const *const IInstallationManagerStatics2_Vtbl)).RemovePackageAsync)(this, packagefullname, removaloptions, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Management_Deployment"))]
+        unsafe extern "system" fn RegisterPackageAsync(this: *mut core::ffi::c_void, manifesturi: *mut core::ffi::c_void, dependencypackageuris: *mut core::ffi::c_void, deploymentoptions: super::super::super::Management::Deployment::DeploymentOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationManagerStatics2", "RegisterPackageAsync", 7usize, |this| ((**(this as *const *const IInstallationManagerStatics2_Vtbl)).RegisterPackageAsync)(this, manifesturi, dependencypackageuris, deploymentoptions, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindPackagesByNamePublisher(this: *mut core::ffi::c_void, packagename: core::mem::MaybeUninit<windows_core::HSTRING>, packagepublisher: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationManagerStatics2", "FindPackagesByNamePublisher", 8usize, |this| ((**(this as *const *const IInstallationManagerStatics2_Vtbl)).FindPackagesByNamePublisher)(this, packagename, packagepublisher, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Management_Deployment")]
+            RemovePackageAsync,
+            #[cfg(not(feature = "Management_Deployment"))]
+            RemovePackageAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Management_Deployment"))]
+            RegisterPackageAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Management_Deployment")))]
+            RegisterPackageAsync: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindPackagesByNamePublisher,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindPackagesByNamePublisher: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInstallationManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Management_Deployment")]
+impl windows_core::imp::ProxyVtable for IPackageInstallResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProductId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageInstallResult", "ProductId", 6usize, |this| ((**(this as *const *const IPackageInstallResult_Vtbl)).ProductId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Management_Deployment")]
+        unsafe extern "system" fn InstallState(this: *mut core::ffi::c_void, result__: *mut super::super::super::Management::Deployment::PackageInstallState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageInstallResult", "InstallState", 7usize, |this| ((**(this as *const *const IPackageInstallResult_Vtbl)).InstallState)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProductId,
+            #[cfg(feature = "Management_Deployment")]
+            InstallState,
+            #[cfg(not(feature = "Management_Deployment"))]
+            InstallState: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageInstallResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPackageInstallResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ErrorText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageInstallResult2", "ErrorText", 6usize, |this| ((**(this as *const *const IPackageInstallResult2_Vtbl)).ErrorText)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ErrorText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageInstallResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -97,6 +97,7 @@ impl AudioRoutingEndpoint {
 impl windows_core::TypeKind for AudioRoutingEndpoint {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioRoutingEndpoint {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioRoutingEndpoint").field(&self.0).finish()
@@ -117,6 +118,7 @@ impl AvailableAudioRoutingEndpoints {
 impl windows_core::TypeKind for AvailableAudioRoutingEndpoints {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AvailableAudioRoutingEndpoints {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AvailableAudioRoutingEndpoints").field(&self.0).finish()
@@ -158,3 +160,5 @@ impl core::ops::Not for AvailableAudioRoutingEndpoints {
 impl windows_core::RuntimeType for AvailableAudioRoutingEndpoints {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Phone.Media.Devices.AvailableAudioRoutingEndpoints;u4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,43 @@
+impl windows_core::imp::ProxyVtable for IAudioRoutingManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAudioEndpoint(this: *mut core::ffi::c_void, result__: *mut AudioRoutingEndpoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioRoutingManager", "GetAudioEndpoint", 6usize, |this| ((**(this as *const *const IAudioRoutingManager_Vtbl)).GetAudioEndpoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioEndpoint(this: *mut core::ffi::c_void, endpoint: AudioRoutingEndpoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioRoutingManager", "SetAudioEndpoint", 7usize, |this| ((**(this as *const *const IAudioRoutingManager_Vtbl)).SetAudioEndpoint)(this, endpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioEndpointChanged(this: *mut core::ffi::c_void, endpointchangehandler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioRoutingManager", "AudioEndpointChanged", 8usize, |this| ((**(this as *const *const IAudioRoutingManager_Vtbl)).AudioEndpointChanged)(this, endpointchangehandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAudioEndpointChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioRoutingManager", "RemoveAudioEndpointChanged", 9usize, |this| ((**(this as *const *const IAudioRoutingManager_Vtbl)).RemoveAudioEndpointChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvailableAudioEndpoints(this: *mut core::ffi::c_void, result__: *mut AvailableAudioRoutingEndpoints) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioRoutingManager", "AvailableAudioEndpoints", 10usize, |this| ((**(this as *const *const IAudioRoutingManager_Vtbl)).AvailableAudioEndpoints)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAudioEndpoint,
+            SetAudioEndpoint,
+            AudioEndpointChanged,
+            RemoveAudioEndpointChanged,
+            AvailableAudioEndpoints,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioRoutingManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioRoutingManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioRoutingManagerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IAudioRoutingManagerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioRoutingManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -2084,6 +2084,7 @@ impl AccessoryNotificationType {
 impl windows_core::TypeKind for AccessoryNotificationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AccessoryNotificationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AccessoryNotificationType").field(&self.0).finish()
@@ -2140,6 +2141,7 @@ impl CalendarChangedEvent {
 impl windows_core::TypeKind for CalendarChangedEvent {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CalendarChangedEvent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CalendarChangedEvent").field(&self.0).finish()
@@ -2159,6 +2161,7 @@ impl PhoneCallAudioEndpoint {
 impl windows_core::TypeKind for PhoneCallAudioEndpoint {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallAudioEndpoint {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallAudioEndpoint").field(&self.0).finish()
@@ -2177,6 +2180,7 @@ impl PhoneCallDirection {
 impl windows_core::TypeKind for PhoneCallDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallDirection").field(&self.0).finish()
@@ -2198,6 +2202,7 @@ impl PhoneCallState {
 impl windows_core::TypeKind for PhoneCallState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallState").field(&self.0).finish()
@@ -2216,6 +2221,7 @@ impl PhoneCallTransport {
 impl windows_core::TypeKind for PhoneCallTransport {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneCallTransport {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneCallTransport").field(&self.0).finish()
@@ -2235,6 +2241,7 @@ impl PhoneLineRegistrationState {
 impl windows_core::TypeKind for PhoneLineRegistrationState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneLineRegistrationState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneLineRegistrationState").field(&self.0).finish()
@@ -2253,6 +2260,7 @@ impl PhoneMediaType {
 impl windows_core::TypeKind for PhoneMediaType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneMediaType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneMediaType").field(&self.0).finish()
@@ -2274,6 +2282,7 @@ impl PhoneNotificationType {
 impl windows_core::TypeKind for PhoneNotificationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhoneNotificationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhoneNotificationType").field(&self.0).finish()
@@ -2301,6 +2310,7 @@ impl PlaybackCapability {
 impl windows_core::TypeKind for PlaybackCapability {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlaybackCapability {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlaybackCapability").field(&self.0).finish()
@@ -2360,6 +2370,7 @@ impl PlaybackCommand {
 impl windows_core::TypeKind for PlaybackCommand {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlaybackCommand {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlaybackCommand").field(&self.0).finish()
@@ -2381,6 +2392,7 @@ impl PlaybackStatus {
 impl windows_core::TypeKind for PlaybackStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlaybackStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlaybackStatus").field(&self.0).finish()
@@ -2400,6 +2412,7 @@ impl ReminderState {
 impl windows_core::TypeKind for ReminderState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ReminderState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ReminderState").field(&self.0).finish()
@@ -2420,6 +2433,7 @@ impl VibrateState {
 impl windows_core::TypeKind for VibrateState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VibrateState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VibrateState").field(&self.0).finish()
@@ -2430,3 +2444,5 @@ impl windows_core::RuntimeType for VibrateState {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
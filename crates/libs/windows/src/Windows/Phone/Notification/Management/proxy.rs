@@ -0,0 +1,926 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IAccessoryManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterAccessoryApp(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "RegisterAccessoryApp", 6usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).RegisterAccessoryApp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextTriggerDetails(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "GetNextTriggerDetails", 7usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).GetNextTriggerDetails)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessTriggerDetails(this: *mut core::ffi::c_void, pdetails: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "ProcessTriggerDetails", 8usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).ProcessTriggerDetails)(this, pdetails), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PhoneLineDetails(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "PhoneLineDetails", 9usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).PhoneLineDetails)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPhoneLineDetails(this: *mut core::ffi::c_void, phoneline: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "GetPhoneLineDetails", 10usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).GetPhoneLineDetails)(this, phoneline, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptPhoneCall(this: *mut core::ffi::c_void, phonecallid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "AcceptPhoneCall", 11usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).AcceptPhoneCall)(this, phonecallid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptPhoneCallOnEndpoint(this: *mut core::ffi::c_void, phonecallid: u32, endpoint: PhoneCallAudioEndpoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "AcceptPhoneCallOnEndpoint", 12usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).AcceptPhoneCallOnEndpoint)(this, phonecallid, endpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptPhoneCallWithVideo(this: *mut core::ffi::c_void, phonecallid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "AcceptPhoneCallWithVideo", 13usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).AcceptPhoneCallWithVideo)(this, phonecallid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptPhoneCallWithVideoOnAudioEndpoint(this: *mut core::ffi::c_void, phonecallid: u32, endpoint: PhoneCallAudioEndpoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "AcceptPhoneCallWithVideoOnAudioEndpoint", 14usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).AcceptPhoneCallWithVideoOnAudioEndpoint)(this, phonecallid, endpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn RejectPhoneCall(this: *mut core::ffi::c_void, phonecallid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "RejectPhoneCall", 15usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).RejectPhoneCall)(this, phonecallid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RejectPhoneCallWithText(this: *mut core::ffi::c_void, phonecallid: u32, textresponseid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "RejectPhoneCallWithText", 16usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).RejectPhoneCallWithText)(this, phonecallid, textresponseid), |result| Some(*result))
+        }
+        unsafe extern "system" fn MakePhoneCall(this: *mut core::ffi::c_void, phoneline: windows_core::GUID, phonenumber: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "MakePhoneCall", 17usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).MakePhoneCall)(this, phoneline, phonenumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn MakePhoneCallOnAudioEndpoint(this: *mut core::ffi::c_void, phoneline: windows_core::GUID, phonenumber: core::mem::MaybeUninit<windows_core::HSTRING>, endpoint: PhoneCallAudioEndpoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "MakePhoneCallOnAudioEndpoint", 18usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).MakePhoneCallOnAudioEndpoint)(this, phoneline, phonenumber, endpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn MakePhoneCallWithVideo(this: *mut core::ffi::c_void, phoneline: windows_core::GUID, phonenumber: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "MakePhoneCallWithVideo", 19usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).MakePhoneCallWithVideo)(this, phoneline, phonenumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn MakePhoneCallWithVideoOnAudioEndpoint(this: *mut core::ffi::c_void, phoneline: windows_core::GUID, phonenumber: core::mem::MaybeUninit<windows_core::HSTRING>, endpoint: PhoneCallAudioEndpoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "MakePhoneCallWithVideoOnAudioEndpoint", 20usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).MakePhoneCallWithVideoOnAudioEndpoint)(this, phoneline, phonenumber, endpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwapPhoneCalls(this: *mut core::ffi::c_void, phonecallidtohold: u32, phonecallidonhold: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "SwapPhoneCalls", 21usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).SwapPhoneCalls)(this, phonecallidtohold, phonecallidonhold), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoldPhoneCall(this: *mut core::ffi::c_void, phonecallid: u32, holdcall: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "HoldPhoneCall", 22usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).HoldPhoneCall)(this, phonecallid, holdcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndPhoneCall(this: *mut core::ffi::c_void, phonecallid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "EndPhoneCall", 23usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).EndPhoneCall)(this, phonecallid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPhoneMute(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "SetPhoneMute", 24usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).SetPhoneMute)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhoneMute(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "PhoneMute", 25usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).PhoneMute)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPhoneCallAudioEndpoint(this: *mut core::ffi::c_void, value: PhoneCallAudioEndpoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "SetPhoneCallAudioEndpoint", 26usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).SetPhoneCallAudioEndpoint)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhoneCallAudioEndpoint(this: *mut core::ffi::c_void, result__: *mut PhoneCallAudioEndpoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "PhoneCallAudioEndpoint", 27usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).PhoneCallAudioEndpoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SnoozeAlarm(this: *mut core::ffi::c_void, alarmid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "SnoozeAlarm", 28usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).SnoozeAlarm)(this, alarmid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SnoozeAlarmForSpecifiedTime(this: *mut core::ffi::c_void, alarmid: windows_core::GUID, timespan: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "SnoozeAlarmForSpecifiedTime", 29usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).SnoozeAlarmForSpecifiedTime)(this, alarmid, timespan), |result| Some(*result))
+        }
+        unsafe extern "system" fn DismissAlarm(this: *mut core::ffi::c_void, alarmid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "DismissAlarm", 30usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).DismissAlarm)(this, alarmid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SnoozeReminder(this: *mut core::ffi::c_void, reminderid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "SnoozeReminder", 31usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).SnoozeReminder)(this, reminderid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SnoozeReminderForSpecifiedTime(this: *mut core::ffi::c_void, reminderid: windows_core::GUID, timespan: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "SnoozeReminderForSpecifiedTime", 32usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).SnoozeReminderForSpecifiedTime)(this, reminderid, timespan), |result| Some(*result))
+        }
+        unsafe extern "system" fn DismissReminder(this: *mut core::ffi::c_void, reminderid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "DismissReminder", 33usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).DismissReminder)(this, reminderid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMediaMetadata(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "GetMediaMetadata", 34usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).GetMediaMetadata)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaPlaybackCapabilities(this: *mut core::ffi::c_void, result__: *mut PlaybackCapability) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "MediaPlaybackCapabilities", 35usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).MediaPlaybackCapabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaPlaybackStatus(this: *mut core::ffi::c_void, result__: *mut PlaybackStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "MediaPlaybackStatus", 36usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).MediaPlaybackStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PerformMediaPlaybackCommand(this: *mut core::ffi::c_void, command: PlaybackCommand) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "PerformMediaPlaybackCommand", 37usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).PerformMediaPlaybackCommand)(this, command), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoNotDisturbEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "DoNotDisturbEnabled", 38usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).DoNotDisturbEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrivingModeEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "DrivingModeEnabled", 39usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).DrivingModeEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BatterySaverState(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "BatterySaverState", 40usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).BatterySaverState)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetApps(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "GetApps", 41usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).GetApps)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableNotificationsForApplication(this: *mut core::ffi::c_void, appid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "EnableNotificationsForApplication", 42usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).EnableNotificationsForApplication)(this, appid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableNotificationsForApplication(this: *mut core::ffi::c_void, appid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "DisableNotificationsForApplication", 43usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).DisableNotificationsForApplication)(this, appid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNotificationEnabledForApplication(this: *mut core::ffi::c_void, appid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "IsNotificationEnabledForApplication", 44usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).IsNotificationEnabledForApplication)(this, appid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnabledAccessoryNotificationTypes(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "GetEnabledAccessoryNotificationTypes", 45usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).GetEnabledAccessoryNotificationTypes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableAccessoryNotificationTypes(this: *mut core::ffi::c_void, accessorynotificationtypes: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "EnableAccessoryNotificationTypes", 46usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).EnableAccessoryNotificationTypes)(this, accessorynotificationtypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableAllAccessoryNotificationTypes(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "DisableAllAccessoryNotificationTypes", 47usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).DisableAllAccessoryNotificationTypes)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserConsent(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "GetUserConsent", 48usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).GetUserConsent)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetAppIcon(this: *mut core::ffi::c_void, appid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager", "GetAppIcon", 49usize, |this| ((**(this as *const *const IAccessoryManager_Vtbl)).GetAppIcon)(this, appid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterAccessoryApp,
+            GetNextTriggerDetails,
+            ProcessTriggerDetails,
+            #[cfg(feature = "Foundation_Collections")]
+            PhoneLineDetails,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PhoneLineDetails: 0,
+            GetPhoneLineDetails,
+            AcceptPhoneCall,
+            AcceptPhoneCallOnEndpoint,
+            AcceptPhoneCallWithVideo,
+            AcceptPhoneCallWithVideoOnAudioEndpoint,
+            RejectPhoneCall,
+            RejectPhoneCallWithText,
+            MakePhoneCall,
+            MakePhoneCallOnAudioEndpoint,
+            MakePhoneCallWithVideo,
+            MakePhoneCallWithVideoOnAudioEndpoint,
+            SwapPhoneCalls,
+            HoldPhoneCall,
+            EndPhoneCall,
+            SetPhoneMute,
+            PhoneMute,
+            SetPhoneCallAudioEndpoint,
+            PhoneCallAudioEndpoint,
+            SnoozeAlarm,
+            SnoozeAlarmForSpecifiedTime,
+            DismissAlarm,
+            SnoozeReminder,
+            SnoozeReminderForSpecifiedTime,
+            DismissReminder,
+            GetMediaMetadata,
+            MediaPlaybackCapabilities,
+            MediaPlaybackStatus,
+            PerformMediaPlaybackCommand,
+            DoNotDisturbEnabled,
+            DrivingModeEnabled,
+            BatterySaverState,
+            #[cfg(feature = "Foundation_Collections")]
+            GetApps,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetApps: 0,
+            EnableNotificationsForApplication,
+            DisableNotificationsForApplication,
+            IsNotificationEnabledForApplication,
+            GetEnabledAccessoryNotificationTypes,
+            EnableAccessoryNotificationTypes,
+            DisableAllAccessoryNotificationTypes,
+            GetUserConsent,
+            #[cfg(feature = "Storage_Streams")]
+            GetAppIcon,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetAppIcon: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccessoryManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAccessoryManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RingDevice(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager2", "RingDevice", 6usize, |this| ((**(this as *const *const IAccessoryManager2_Vtbl)).RingDevice)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SpeedDialList(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager2", "SpeedDialList", 7usize, |this| ((**(this as *const *const IAccessoryManager2_Vtbl)).SpeedDialList)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearToast(this: *mut core::ffi::c_void, instanceid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager2", "ClearToast", 8usize, |this| ((**(this as *const *const IAccessoryManager2_Vtbl)).ClearToast)(this, instanceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPhonePinLocked(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager2", "IsPhonePinLocked", 9usize, |this| ((**(this as *const *const IAccessoryManager2_Vtbl)).IsPhonePinLocked)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncreaseVolume(this: *mut core::ffi::c_void, step: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager2", "IncreaseVolume", 10usize, |this| ((**(this as *const *const IAccessoryManager2_Vtbl)).IncreaseVolume)(this, step), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecreaseVolume(this: *mut core::ffi::c_void, step: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager2", "DecreaseVolume", 11usize, |this| ((**(this as *const *const IAccessoryManager2_Vtbl)).DecreaseVolume)(this, step), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMute(this: *mut core::ffi::c_void, mute: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager2", "SetMute", 12usize, |this| ((**(this as *const *const IAccessoryManager2_Vtbl)).SetMute)(this, mute), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRingerVibrate(this: *mut core::ffi::c_void, ringer: bool, vibrate: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager2", "SetRingerVibrate", 13usize, |this| ((**(this as *const *const IAccessoryManager2_Vtbl)).SetRingerVibrate)(this, ringer, vibrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumeInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager2", "VolumeInfo", 14usize, |this| ((**(this as *const *const IAccessoryManager2_Vtbl)).VolumeInfo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAllEmailAccounts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager2", "GetAllEmailAccounts", 15usize, |this| ((**(this as *const *const IAccessoryManager2_Vtbl)).GetAllEmailAccounts)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetFolders(this: *mut core::ffi::c_void, emailaccount: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager2", "GetFolders", 16usize, |this| ((**(this as *const *const IAccessoryManager2_Vtbl)).GetFolders)(this, emailaccount, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableEmailNotificationEmailAccount(this: *mut core::ffi::c_void, emailaccount: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager2", "EnableEmailNotificationEmailAccount", 17usize, |this| ((**(this as *const *const IAccessoryManager2_Vtbl)).EnableEmailNotificationEmailAccount)(this, emailaccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableEmailNotificationEmailAccount(this: *mut core::ffi::c_void, emailaccount: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager2", "DisableEmailNotificationEmailAccount", 18usize, |this| ((**(this as *const *const IAccessoryManager2_Vtbl)).DisableEmailNotificationEmailAccount)(this, emailaccount), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EnableEmailNotificationFolderFilter(this: *mut core::ffi::c_void, emailaccount: core::mem::MaybeUninit<windows_core::HSTRING>, folders: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager2", "EnableEmailNotificationFolderFilter", 19usize, |this| ((**(this as *const *const IAccessoryManager2_Vtbl)).EnableEmailNotificationFolderFilter)(this, emailaccount, folders), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateEmailReadStatus(this: *mut core::ffi::c_void, messageentryid: *mut core::ffi::c_void, isread: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager2", "UpdateEmailReadStatus", 20usize, |this| ((**(this as *const *const IAccessoryManager2_Vtbl)).UpdateEmailReadStatus)(this, messageentryid, isread), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RingDevice,
+            #[cfg(feature = "Foundation_Collections")]
+            SpeedDialList,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SpeedDialList: 0,
+            ClearToast,
+            IsPhonePinLocked,
+            IncreaseVolume,
+            DecreaseVolume,
+            SetMute,
+            SetRingerVibrate,
+            VolumeInfo,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAllEmailAccounts,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAllEmailAccounts: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetFolders,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetFolders: 0,
+            EnableEmailNotificationEmailAccount,
+            DisableEmailNotificationEmailAccount,
+            #[cfg(feature = "Foundation_Collections")]
+            EnableEmailNotificationFolderFilter,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EnableEmailNotificationFolderFilter: 0,
+            UpdateEmailReadStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccessoryManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccessoryManager3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SnoozeAlarmByInstanceId(this: *mut core::ffi::c_void, instanceid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager3", "SnoozeAlarmByInstanceId", 6usize, |this| ((**(this as *const *const IAccessoryManager3_Vtbl)).SnoozeAlarmByInstanceId)(this, instanceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DismissAlarmByInstanceId(this: *mut core::ffi::c_void, instanceid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager3", "DismissAlarmByInstanceId", 7usize, |this| ((**(this as *const *const IAccessoryManager3_Vtbl)).DismissAlarmByInstanceId)(this, instanceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SnoozeReminderByInstanceId(this: *mut core::ffi::c_void, instanceid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager3", "SnoozeReminderByInstanceId", 8usize, |this| ((**(this as *const *const IAccessoryManager3_Vtbl)).SnoozeReminderByInstanceId)(this, instanceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DismissReminderByInstanceId(this: *mut core::ffi::c_void, instanceid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryManager3", "DismissReminderByInstanceId", 9usize, |this| ((**(this as *const *const IAccessoryManager3_Vtbl)).DismissReminderByInstanceId)(this, instanceid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SnoozeAlarmByInstanceId,
+            DismissAlarmByInstanceId,
+            SnoozeReminderByInstanceId,
+            DismissReminderByInstanceId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccessoryManager3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccessoryNotificationTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TimeCreated(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryNotificationTriggerDetails", "TimeCreated", 6usize, |this| ((**(this as *const *const IAccessoryNotificationTriggerDetails_Vtbl)).TimeCreated)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryNotificationTriggerDetails", "AppDisplayName", 7usize, |this| ((**(this as *const *const IAccessoryNotificationTriggerDetails_Vtbl)).AppDisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryNotificationTriggerDetails", "AppId", 8usize, |this| ((**(this as *const *const IAccessoryNotificationTriggerDetails_Vtbl)).AppId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccessoryNotificationType(this: *mut core::ffi::c_void, result__: *mut AccessoryNotificationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryNotificationTriggerDetails", "AccessoryNotificationType", 9usize, |this| ((**(this as *const *const IAccessoryNotificationTriggerDetails_Vtbl)).AccessoryNotificationType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartedProcessing(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryNotificationTriggerDetails", "StartedProcessing", 10usize, |this| ((**(this as *const *const IAccessoryNotificationTriggerDetails_Vtbl)).StartedProcessing)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartedProcessing(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessoryNotificationTriggerDetails", "SetStartedProcessing", 11usize, |this| ((**(this as *const *const IAccessoryNotificationTriggerDetails_Vtbl)).SetStartedProcessing)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TimeCreated,
+            AppDisplayName,
+            AppId,
+            AccessoryNotificationType,
+            StartedProcessing,
+            SetStartedProcessing,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccessoryNotificationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAlarmNotificationTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AlarmId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlarmNotificationTriggerDetails", "AlarmId", 6usize, |this| ((**(this as *const *const IAlarmNotificationTriggerDetails_Vtbl)).AlarmId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlarmNotificationTriggerDetails", "Title", 7usize, |this| ((**(this as *const *const IAlarmNotificationTriggerDetails_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlarmNotificationTriggerDetails", "Timestamp", 8usize, |this| ((**(this as *const *const IAlarmNotificationTriggerDetails_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReminderState(this: *mut core::ffi::c_void, result__: *mut ReminderState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlarmNotificationTriggerDetails", "ReminderState", 9usize, |this| ((**(this as *const *const IAlarmNotificationTriggerDetails_Vtbl)).ReminderState)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AlarmId, Title, Timestamp, ReminderState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAlarmNotificationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAlarmNotificationTriggerDetails2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstanceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlarmNotificationTriggerDetails2", "InstanceId", 6usize, |this| ((**(this as *const *const IAlarmNotificationTriggerDetails2_Vtbl)).InstanceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InstanceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAlarmNotificationTriggerDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppNotificationInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppNotificationInfo", "Id", 6usize, |this| ((**(this as *const *const IAppNotificationInfo_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppNotificationInfo", "Name", 7usize, |this| ((**(this as *const *const IAppNotificationInfo_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Name }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppNotificationInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBinaryId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBinaryId", "Id", 6usize, |this| ((**(this as *const *const IBinaryId_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBinaryId", "Length", 7usize, |this| ((**(this as *const *const IBinaryId_Vtbl)).Length)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Length }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBinaryId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICalendarChangedNotificationTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventType(this: *mut core::ffi::c_void, result__: *mut CalendarChangedEvent) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarChangedNotificationTriggerDetails", "EventType", 6usize, |this| ((**(this as *const *const ICalendarChangedNotificationTriggerDetails_Vtbl)).EventType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICalendarChangedNotificationTriggerDetails", "ItemId", 7usize, |this| ((**(this as *const *const ICalendarChangedNotificationTriggerDetails_Vtbl)).ItemId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EventType, ItemId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICalendarChangedNotificationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICortanaTileNotificationTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TileId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICortanaTileNotificationTriggerDetails", "TileId", 6usize, |this| ((**(this as *const *const ICortanaTileNotificationTriggerDetails_Vtbl)).TileId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICortanaTileNotificationTriggerDetails", "Content", 7usize, |this| ((**(this as *const *const ICortanaTileNotificationTriggerDetails_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LargeContent1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICortanaTileNotificationTriggerDetails", "LargeContent1", 8usize, |this| ((**(this as *const *const ICortanaTileNotificationTriggerDetails_Vtbl)).LargeContent1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LargeContent2(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICortanaTileNotificationTriggerDetails", "LargeContent2", 9usize, |this| ((**(this as *const *const ICortanaTileNotificationTriggerDetails_Vtbl)).LargeContent2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmphasizedText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICortanaTileNotificationTriggerDetails", "EmphasizedText", 10usize, |this| ((**(this as *const *const ICortanaTileNotificationTriggerDetails_Vtbl)).EmphasizedText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NonWrappedSmallContent1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICortanaTileNotificationTriggerDetails", "NonWrappedSmallContent1", 11usize, |this| ((**(this as *const *const ICortanaTileNotificationTriggerDetails_Vtbl)).NonWrappedSmallContent1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NonWrappedSmallContent2(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICortanaTileNotificationTriggerDetails", "NonWrappedSmallContent2", 12usize, |this| ((**(this as *const *const ICortanaTileNotificationTriggerDetails_Vtbl)).NonWrappedSmallContent2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NonWrappedSmallContent3(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICortanaTileNotificationTriggerDetails", "NonWrappedSmallContent3", 13usize, |this| ((**(this as *const *const ICortanaTileNotificationTriggerDetails_Vtbl)).NonWrappedSmallContent3)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NonWrappedSmallContent4(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICortanaTileNotificationTriggerDetails", "NonWrappedSmallContent4", 14usize, |this| ((**(this as *const *const ICortanaTileNotificationTriggerDetails_Vtbl)).NonWrappedSmallContent4)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICortanaTileNotificationTriggerDetails", "Source", 15usize, |this| ((**(this as *const *const ICortanaTileNotificationTriggerDetails_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TileId,
+            Content,
+            LargeContent1,
+            LargeContent2,
+            EmphasizedText,
+            NonWrappedSmallContent1,
+            NonWrappedSmallContent2,
+            NonWrappedSmallContent3,
+            NonWrappedSmallContent4,
+            Source,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICortanaTileNotificationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailAccountInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAccountInfo", "DisplayName", 6usize, |this| ((**(this as *const *const IEmailAccountInfo_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNotificationEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAccountInfo", "IsNotificationEnabled", 7usize, |this| ((**(this as *const *const IEmailAccountInfo_Vtbl)).IsNotificationEnabled)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayName, IsNotificationEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailAccountInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailFolderInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolderInfo", "DisplayName", 6usize, |this| ((**(this as *const *const IEmailFolderInfo_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNotificationEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailFolderInfo", "IsNotificationEnabled", 7usize, |this| ((**(this as *const *const IEmailFolderInfo_Vtbl)).IsNotificationEnabled)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayName, IsNotificationEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailFolderInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Email")]
+impl windows_core::imp::ProxyVtable for IEmailNotificationTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccountName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailNotificationTriggerDetails", "AccountName", 6usize, |this| ((**(this as *const *const IEmailNotificationTriggerDetails_Vtbl)).AccountName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentFolderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailNotificationTriggerDetails", "ParentFolderName", 7usize, |this| ((**(this as *const *const IEmailNotificationTriggerDetails_Vtbl)).ParentFolderName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailNotificationTriggerDetails", "SenderName", 8usize, |this| ((**(this as *const *const IEmailNotificationTriggerDetails_Vtbl)).SenderName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailNotificationTriggerDetails", "SenderAddress", 9usize, |this| ((**(this as *const *const IEmailNotificationTriggerDetails_Vtbl)).SenderAddress)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Email")]
+        unsafe extern "system" fn EmailMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailNotificationTriggerDetails", "EmailMessage", 10usize, |this| ((**(this as *const *const IEmailNotificationTriggerDetails_Vtbl)).EmailMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailNotificationTriggerDetails", "Timestamp", 11usize, |this| ((**(this as *const *const IEmailNotificationTriggerDetails_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AccountName,
+            ParentFolderName,
+            SenderName,
+            SenderAddress,
+            #[cfg(feature = "ApplicationModel_Email")]
+            EmailMessage,
+            #[cfg(not(feature = "ApplicationModel_Email"))]
+            EmailMessage: 0,
+            Timestamp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailNotificationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailNotificationTriggerDetails2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MessageEntryId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailNotificationTriggerDetails2", "MessageEntryId", 6usize, |this| ((**(this as *const *const IEmailNotificationTriggerDetails2_Vtbl)).MessageEntryId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MessageEntryId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailNotificationTriggerDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmailReadNotificationTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccountName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailReadNotificationTriggerDetails", "AccountName", 6usize, |this| ((**(this as *const *const IEmailReadNotificationTriggerDetails_Vtbl)).AccountName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentFolderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailReadNotificationTriggerDetails", "ParentFolderName", 7usize, |this| ((**(this as *const *const IEmailReadNotificationTriggerDetails_Vtbl)).ParentFolderName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageEntryId(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailReadNotificationTriggerDetails", "MessageEntryId", 8usize, |this| ((**(this as *const *const IEmailReadNotificationTriggerDetails_Vtbl)).MessageEntryId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRead(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailReadNotificationTriggerDetails", "IsRead", 9usize, |this| ((**(this as *const *const IEmailReadNotificationTriggerDetails_Vtbl)).IsRead)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AccountName, ParentFolderName, MessageEntryId, IsRead }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailReadNotificationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaControlsTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PlaybackStatus(this: *mut core::ffi::c_void, result__: *mut PlaybackStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControlsTriggerDetails", "PlaybackStatus", 6usize, |this| ((**(this as *const *const IMediaControlsTriggerDetails_Vtbl)).PlaybackStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaMetadata(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControlsTriggerDetails", "MediaMetadata", 7usize, |this| ((**(this as *const *const IMediaControlsTriggerDetails_Vtbl)).MediaMetadata)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PlaybackStatus, MediaMetadata }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaControlsTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMediaMetadata_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaMetadata", "Title", 6usize, |this| ((**(this as *const *const IMediaMetadata_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subtitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaMetadata", "Subtitle", 7usize, |this| ((**(this as *const *const IMediaMetadata_Vtbl)).Subtitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Artist(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaMetadata", "Artist", 8usize, |this| ((**(this as *const *const IMediaMetadata_Vtbl)).Artist)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Album(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaMetadata", "Album", 9usize, |this| ((**(this as *const *const IMediaMetadata_Vtbl)).Album)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Track(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaMetadata", "Track", 10usize, |this| ((**(this as *const *const IMediaMetadata_Vtbl)).Track)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaMetadata", "Duration", 11usize, |this| ((**(this as *const *const IMediaMetadata_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaMetadata", "Thumbnail", 12usize, |this| ((**(this as *const *const IMediaMetadata_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Title,
+            Subtitle,
+            Artist,
+            Album,
+            Track,
+            Duration,
+            #[cfg(feature = "Storage_Streams")]
+            Thumbnail,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Thumbnail: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaMetadata as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPhoneCallDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PhoneLine(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallDetails", "PhoneLine", 6usize, |this| ((**(this as *const *const IPhoneCallDetails_Vtbl)).PhoneLine)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallDetails", "CallId", 7usize, |this| ((**(this as *const *const IPhoneCallDetails_Vtbl)).CallId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallTransport(this: *mut core::ffi::c_void, result__: *mut PhoneCallTransport) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallDetails", "CallTransport", 8usize, |this| ((**(this as *const *const IPhoneCallDetails_Vtbl)).CallTransport)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallMediaType(this: *mut core::ffi::c_void, result__: *mut PhoneMediaType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallDetails", "CallMediaType", 9usize, |this| ((**(this as *const *const IPhoneCallDetails_Vtbl)).CallMediaType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallDirection(this: *mut core::ffi::c_void, result__: *mut PhoneCallDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallDetails", "CallDirection", 10usize, |this| ((**(this as *const *const IPhoneCallDetails_Vtbl)).CallDirection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut PhoneCallState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallDetails", "State", 11usize, |this| ((**(this as *const *const IPhoneCallDetails_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConferenceCallId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallDetails", "ConferenceCallId", 12usize, |this| ((**(this as *const *const IPhoneCallDetails_Vtbl)).ConferenceCallId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallDetails", "StartTime", 13usize, |this| ((**(this as *const *const IPhoneCallDetails_Vtbl)).StartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallDetails", "EndTime", 14usize, |this| ((**(this as *const *const IPhoneCallDetails_Vtbl)).EndTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhoneNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallDetails", "PhoneNumber", 15usize, |this| ((**(this as *const *const IPhoneCallDetails_Vtbl)).PhoneNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContactName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallDetails", "ContactName", 16usize, |this| ((**(this as *const *const IPhoneCallDetails_Vtbl)).ContactName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PresetTextResponses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneCallDetails", "PresetTextResponses", 17usize, |this| ((**(this as *const *const IPhoneCallDetails_Vtbl)).PresetTextResponses)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PhoneLine,
+            CallId,
+            CallTransport,
+            CallMediaType,
+            CallDirection,
+            State,
+            ConferenceCallId,
+            StartTime,
+            EndTime,
+            PhoneNumber,
+            ContactName,
+            #[cfg(feature = "Foundation_Collections")]
+            PresetTextResponses,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PresetTextResponses: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneCallDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneLineDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LineId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineDetails", "LineId", 6usize, |this| ((**(this as *const *const IPhoneLineDetails_Vtbl)).LineId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineDetails", "DisplayName", 7usize, |this| ((**(this as *const *const IPhoneLineDetails_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineDetails", "LineNumber", 8usize, |this| ((**(this as *const *const IPhoneLineDetails_Vtbl)).LineNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultOutgoingLine(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineDetails", "DefaultOutgoingLine", 9usize, |this| ((**(this as *const *const IPhoneLineDetails_Vtbl)).DefaultOutgoingLine)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VoicemailCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineDetails", "VoicemailCount", 10usize, |this| ((**(this as *const *const IPhoneLineDetails_Vtbl)).VoicemailCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegistrationState(this: *mut core::ffi::c_void, result__: *mut PhoneLineRegistrationState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineDetails", "RegistrationState", 11usize, |this| ((**(this as *const *const IPhoneLineDetails_Vtbl)).RegistrationState)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LineId,
+            DisplayName,
+            LineNumber,
+            DefaultOutgoingLine,
+            VoicemailCount,
+            RegistrationState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneLineDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneLineDetails2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MissedCallCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneLineDetails2", "MissedCallCount", 6usize, |this| ((**(this as *const *const IPhoneLineDetails2_Vtbl)).MissedCallCount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MissedCallCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneLineDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhoneNotificationTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PhoneNotificationType(this: *mut core::ffi::c_void, result__: *mut PhoneNotificationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNotificationTriggerDetails", "PhoneNotificationType", 6usize, |this| ((**(this as *const *const IPhoneNotificationTriggerDetails_Vtbl)).PhoneNotificationType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallDetails(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNotificationTriggerDetails", "CallDetails", 7usize, |this| ((**(this as *const *const IPhoneNotificationTriggerDetails_Vtbl)).CallDetails)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhoneLineChangedId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhoneNotificationTriggerDetails", "PhoneLineChangedId", 8usize, |this| ((**(this as *const *const IPhoneNotificationTriggerDetails_Vtbl)).PhoneLineChangedId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PhoneNotificationType, CallDetails, PhoneLineChangedId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhoneNotificationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Appointments")]
+impl windows_core::imp::ProxyVtable for IReminderNotificationTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReminderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReminderNotificationTriggerDetails", "ReminderId", 6usize, |this| ((**(this as *const *const IReminderNotificationTriggerDetails_Vtbl)).ReminderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReminderNotificationTriggerDetails", "Title", 7usize, |this| ((**(this as *const *const IReminderNotificationTriggerDetails_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReminderNotificationTriggerDetails", "Description", 8usize, |this| ((**(this as *const *const IReminderNotificationTriggerDetails_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Details(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReminderNotificationTriggerDetails", "Details", 9usize, |this| ((**(this as *const *const IReminderNotificationTriggerDetails_Vtbl)).Details)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReminderNotificationTriggerDetails", "Timestamp", 10usize, |this| ((**(this as *const *const IReminderNotificationTriggerDetails_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Appointments")]
+        unsafe extern "system" fn Appointment(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReminderNotificationTriggerDetails", "Appointment", 11usize, |this| ((**(this as *const *const IReminderNotificationTriggerDetails_Vtbl)).Appointment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReminderState(this: *mut core::ffi::c_void, result__: *mut ReminderState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReminderNotificationTriggerDetails", "ReminderState", 12usize, |this| ((**(this as *const *const IReminderNotificationTriggerDetails_Vtbl)).ReminderState)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReminderId,
+            Title,
+            Description,
+            Details,
+            Timestamp,
+            #[cfg(feature = "ApplicationModel_Appointments")]
+            Appointment,
+            #[cfg(not(feature = "ApplicationModel_Appointments"))]
+            Appointment: 0,
+            ReminderState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReminderNotificationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IReminderNotificationTriggerDetails2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstanceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReminderNotificationTriggerDetails2", "InstanceId", 6usize, |this| ((**(this as *const *const IReminderNotificationTriggerDetails2_Vtbl)).InstanceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InstanceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReminderNotificationTriggerDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeedDialEntry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PhoneNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeedDialEntry", "PhoneNumber", 6usize, |this| ((**(this as *const *const ISpeedDialEntry_Vtbl)).PhoneNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeedDialEntry", "NumberType", 7usize, |this| ((**(this as *const *const ISpeedDialEntry_Vtbl)).NumberType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContactName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeedDialEntry", "ContactName", 8usize, |this| ((**(this as *const *const ISpeedDialEntry_Vtbl)).ContactName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PhoneNumber, NumberType, ContactName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeedDialEntry as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextResponse_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextResponse", "Id", 6usize, |this| ((**(this as *const *const ITextResponse_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextResponse", "Content", 7usize, |this| ((**(this as *const *const ITextResponse_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Content }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextResponse as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotificationTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Text1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationTriggerDetails", "Text1", 6usize, |this| ((**(this as *const *const IToastNotificationTriggerDetails_Vtbl)).Text1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text2(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationTriggerDetails", "Text2", 7usize, |this| ((**(this as *const *const IToastNotificationTriggerDetails_Vtbl)).Text2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text3(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationTriggerDetails", "Text3", 8usize, |this| ((**(this as *const *const IToastNotificationTriggerDetails_Vtbl)).Text3)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text4(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationTriggerDetails", "Text4", 9usize, |this| ((**(this as *const *const IToastNotificationTriggerDetails_Vtbl)).Text4)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuppressPopup(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationTriggerDetails", "SuppressPopup", 10usize, |this| ((**(this as *const *const IToastNotificationTriggerDetails_Vtbl)).SuppressPopup)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Text1, Text2, Text3, Text4, SuppressPopup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotificationTriggerDetails2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstanceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationTriggerDetails2", "InstanceId", 6usize, |this| ((**(this as *const *const IToastNotificationTriggerDetails2_Vtbl)).InstanceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InstanceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationTriggerDetails2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVolumeInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SystemVolume(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVolumeInfo", "SystemVolume", 6usize, |this| ((**(this as *const *const IVolumeInfo_Vtbl)).SystemVolume)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallVolume(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVolumeInfo", "CallVolume", 7usize, |this| ((**(this as *const *const IVolumeInfo_Vtbl)).CallVolume)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaVolume(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVolumeInfo", "MediaVolume", 8usize, |this| ((**(this as *const *const IVolumeInfo_Vtbl)).MediaVolume)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMuted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVolumeInfo", "IsMuted", 9usize, |this| ((**(this as *const *const IVolumeInfo_Vtbl)).IsMuted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVibrateEnabled(this: *mut core::ffi::c_void, result__: *mut VibrateState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVolumeInfo", "IsVibrateEnabled", 10usize, |this| ((**(this as *const *const IVolumeInfo_Vtbl)).IsVibrateEnabled)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SystemVolume,
+            CallVolume,
+            MediaVolume,
+            IsMuted,
+            IsVibrateEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVolumeInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -112,3 +112,5 @@ impl MessagePartnerProvisioningManager {
 impl windows_core::RuntimeName for MessagePartnerProvisioningManager {
     const NAME: &'static str = "Windows.Phone.PersonalInformation.Provisioning.MessagePartnerProvisioningManager";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
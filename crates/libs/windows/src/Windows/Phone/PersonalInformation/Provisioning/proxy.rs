@@ -0,0 +1,64 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IContactPartnerProvisioningManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AssociateNetworkAccountAsync(this: *mut core::ffi::c_void, store: *mut core::ffi::c_void, networkname: core::mem::MaybeUninit<windows_core::HSTRING>, networkaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPartnerProvisioningManagerStatics", "AssociateNetworkAccountAsync", 6usize, |this| ((**(this as *const *const IContactPartnerProvisioningManagerStatics_Vtbl)).AssociateNetworkAccountAsync)(this, store, networkname, networkaccountid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ImportVcardToSystemAsync(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPartnerProvisioningManagerStatics", "ImportVcardToSystemAsync", 7usize, |this| ((**(this as *const *const IContactPartnerProvisioningManagerStatics_Vtbl)).ImportVcardToSystemAsync)(this, stream, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AssociateNetworkAccountAsync,
+            #[cfg(feature = "Storage_Streams")]
+            ImportVcardToSystemAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ImportVcardToSystemAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPartnerProvisioningManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactPartnerProvisioningManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AssociateSocialNetworkAccountAsync(this: *mut core::ffi::c_void, store: *mut core::ffi::c_void, networkname: core::mem::MaybeUninit<windows_core::HSTRING>, networkaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPartnerProvisioningManagerStatics2", "AssociateSocialNetworkAccountAsync", 6usize, |this| ((**(this as *const *const IContactPartnerProvisioningManagerStatics2_Vtbl)).AssociateSocialNetworkAccountAsync)(this, store, networkname, networkaccountid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AssociateSocialNetworkAccountAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPartnerProvisioningManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMessagePartnerProvisioningManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ImportSmsToSystemAsync(this: *mut core::ffi::c_void, incoming: bool, read: bool, body: core::mem::MaybeUninit<windows_core::HSTRING>, sender: core::mem::MaybeUninit<windows_core::HSTRING>, recipients: *mut core::ffi::c_void, deliverytime: super::super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessagePartnerProvisioningManagerStatics", "ImportSmsToSystemAsync", 6usize, |this| ((**(this as *const *const IMessagePartnerProvisioningManagerStatics_Vtbl)).ImportSmsToSystemAsync)(this, incoming, read, body, sender, recipients, deliverytime, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ImportMmsToSystemAsync(this: *mut core::ffi::c_void, incoming: bool, read: bool, subject: core::mem::MaybeUninit<windows_core::HSTRING>, sender: core::mem::MaybeUninit<windows_core::HSTRING>, recipients: *mut core::ffi::c_void, deliverytime: super::super::super::Foundation::DateTime, attachments: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessagePartnerProvisioningManagerStatics", "ImportMmsToSystemAsync", 7usize, |this| ((**(this as *const *const IMessagePartnerProvisioningManagerStatics_Vtbl)).ImportMmsToSystemAsync)(this, incoming, read, subject, sender, recipients, deliverytime, attachments, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ImportSmsToSystemAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ImportSmsToSystemAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ImportMmsToSystemAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ImportMmsToSystemAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMessagePartnerProvisioningManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
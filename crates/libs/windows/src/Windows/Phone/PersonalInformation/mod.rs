@@ -1303,6 +1303,7 @@ impl ContactChangeType {
 impl windows_core::TypeKind for ContactChangeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactChangeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactChangeType").field(&self.0).finish()
@@ -1322,6 +1323,7 @@ impl ContactQueryResultOrdering {
 impl windows_core::TypeKind for ContactQueryResultOrdering {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactQueryResultOrdering {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactQueryResultOrdering").field(&self.0).finish()
@@ -1340,6 +1342,7 @@ impl ContactStoreApplicationAccessMode {
 impl windows_core::TypeKind for ContactStoreApplicationAccessMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactStoreApplicationAccessMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactStoreApplicationAccessMode").field(&self.0).finish()
@@ -1358,6 +1361,7 @@ impl ContactStoreSystemAccessMode {
 impl windows_core::TypeKind for ContactStoreSystemAccessMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ContactStoreSystemAccessMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ContactStoreSystemAccessMode").field(&self.0).finish()
@@ -1376,6 +1380,7 @@ impl VCardFormat {
 impl windows_core::TypeKind for VCardFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VCardFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VCardFormat").field(&self.0).finish()
@@ -1386,3 +1391,5 @@ impl windows_core::RuntimeType for VCardFormat {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
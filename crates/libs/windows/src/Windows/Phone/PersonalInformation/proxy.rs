@@ -0,0 +1,572 @@
+impl windows_core::imp::ProxyVtable for IContactAddress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Country(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "Country", 6usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).Country)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCountry(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "SetCountry", 7usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).SetCountry)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Locality(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "Locality", 8usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).Locality)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocality(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "SetLocality", 9usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).SetLocality)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Region(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "Region", 10usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).Region)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRegion(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "SetRegion", 11usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).SetRegion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostalCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "PostalCode", 12usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).PostalCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPostalCode(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "SetPostalCode", 13usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).SetPostalCode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StreetAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "StreetAddress", 14usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).StreetAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreetAddress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAddress", "SetStreetAddress", 15usize, |this| ((**(this as *const *const IContactAddress_Vtbl)).SetStreetAddress)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Country,
+            SetCountry,
+            Locality,
+            SetLocality,
+            Region,
+            SetRegion,
+            PostalCode,
+            SetPostalCode,
+            StreetAddress,
+            SetStreetAddress,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAddress as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactChangeRecord_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeType(this: *mut core::ffi::c_void, result__: *mut ContactChangeType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactChangeRecord", "ChangeType", 6usize, |this| ((**(this as *const *const IContactChangeRecord_Vtbl)).ChangeType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevisionNumber(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactChangeRecord", "RevisionNumber", 7usize, |this| ((**(this as *const *const IContactChangeRecord_Vtbl)).RevisionNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactChangeRecord", "Id", 8usize, |this| ((**(this as *const *const IContactChangeRecord_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactChangeRecord", "RemoteId", 9usize, |this| ((**(this as *const *const IContactChangeRecord_Vtbl)).RemoteId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ChangeType, RevisionNumber, Id, RemoteId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactChangeRecord as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IContactInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "DisplayName", 6usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "SetDisplayName", 7usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "FamilyName", 8usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).FamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFamilyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "SetFamilyName", 9usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).SetFamilyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GivenName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "GivenName", 10usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).GivenName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGivenName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "SetGivenName", 11usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).SetGivenName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HonorificPrefix(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "HonorificPrefix", 12usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).HonorificPrefix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHonorificPrefix(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "SetHonorificPrefix", 13usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).SetHonorificPrefix)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HonorificSuffix(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "HonorificSuffix", 14usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).HonorificSuffix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHonorificSuffix(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "SetHonorificSuffix", 15usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).SetHonorificSuffix)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetDisplayPictureAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "GetDisplayPictureAsync", 16usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).GetDisplayPictureAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetDisplayPictureAsync(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "SetDisplayPictureAsync", 17usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).SetDisplayPictureAsync)(this, stream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn DisplayPicture(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "DisplayPicture", 18usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).DisplayPicture)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetPropertiesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "GetPropertiesAsync", 19usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).GetPropertiesAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ToVcardAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "ToVcardAsync", 20usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).ToVcardAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ToVcardWithOptionsAsync(this: *mut core::ffi::c_void, format: VCardFormat, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation", "ToVcardWithOptionsAsync", 21usize, |this| ((**(this as *const *const IContactInformation_Vtbl)).ToVcardWithOptionsAsync)(this, format, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayName,
+            SetDisplayName,
+            FamilyName,
+            SetFamilyName,
+            GivenName,
+            SetGivenName,
+            HonorificPrefix,
+            SetHonorificPrefix,
+            HonorificSuffix,
+            SetHonorificSuffix,
+            #[cfg(feature = "Storage_Streams")]
+            GetDisplayPictureAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetDisplayPictureAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetDisplayPictureAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetDisplayPictureAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            DisplayPicture,
+            #[cfg(not(feature = "Storage_Streams"))]
+            DisplayPicture: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetPropertiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetPropertiesAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ToVcardAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ToVcardAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ToVcardWithOptionsAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ToVcardWithOptionsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactInformation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayPictureDate(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation2", "DisplayPictureDate", 6usize, |this| ((**(this as *const *const IContactInformation2_Vtbl)).DisplayPictureDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayPictureDate(this: *mut core::ffi::c_void, returnvalue: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformation2", "SetDisplayPictureDate", 7usize, |this| ((**(this as *const *const IContactInformation2_Vtbl)).SetDisplayPictureDate)(this, returnvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayPictureDate, SetDisplayPictureDate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactInformation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IContactInformationStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ParseVcardAsync(this: *mut core::ffi::c_void, vcard: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactInformationStatics", "ParseVcardAsync", 6usize, |this| ((**(this as *const *const IContactInformationStatics_Vtbl)).ParseVcardAsync)(this, vcard, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            ParseVcardAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ParseVcardAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactInformationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactQueryOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DesiredFields(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryOptions", "DesiredFields", 6usize, |this| ((**(this as *const *const IContactQueryOptions_Vtbl)).DesiredFields)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OrderBy(this: *mut core::ffi::c_void, result__: *mut ContactQueryResultOrdering) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryOptions", "OrderBy", 7usize, |this| ((**(this as *const *const IContactQueryOptions_Vtbl)).OrderBy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOrderBy(this: *mut core::ffi::c_void, value: ContactQueryResultOrdering) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryOptions", "SetOrderBy", 8usize, |this| ((**(this as *const *const IContactQueryOptions_Vtbl)).SetOrderBy)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            DesiredFields,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DesiredFields: 0,
+            OrderBy,
+            SetOrderBy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactQueryOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactQueryResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetContactCountAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryResult", "GetContactCountAsync", 6usize, |this| ((**(this as *const *const IContactQueryResult_Vtbl)).GetContactCountAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetContactsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryResult", "GetContactsAsync", 7usize, |this| ((**(this as *const *const IContactQueryResult_Vtbl)).GetContactsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetContactsAsyncInRange(this: *mut core::ffi::c_void, startindex: u32, maxnumberofitems: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryResult", "GetContactsAsyncInRange", 8usize, |this| ((**(this as *const *const IContactQueryResult_Vtbl)).GetContactsAsyncInRange)(this, startindex, maxnumberofitems, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentQueryOptions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactQueryResult", "GetCurrentQueryOptions", 9usize, |this| ((**(this as *const *const IContactQueryResult_Vtbl)).GetCurrentQueryOptions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetContactCountAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetContactsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetContactsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetContactsAsyncInRange,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetContactsAsyncInRange: 0,
+            GetCurrentQueryOptions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactQueryResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContactStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindContactByRemoteIdAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore", "FindContactByRemoteIdAsync", 6usize, |this| ((**(this as *const *const IContactStore_Vtbl)).FindContactByRemoteIdAsync)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindContactByIdAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore", "FindContactByIdAsync", 7usize, |this| ((**(this as *const *const IContactStore_Vtbl)).FindContactByIdAsync)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteContactAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore", "DeleteContactAsync", 8usize, |this| ((**(this as *const *const IContactStore_Vtbl)).DeleteContactAsync)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateContactQueryDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore", "CreateContactQueryDefault", 9usize, |this| ((**(this as *const *const IContactStore_Vtbl)).CreateContactQueryDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateContactQueryWithOptions(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore", "CreateContactQueryWithOptions", 10usize, |this| ((**(this as *const *const IContactStore_Vtbl)).CreateContactQueryWithOptions)(this, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore", "DeleteAsync", 11usize, |this| ((**(this as *const *const IContactStore_Vtbl)).DeleteAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevisionNumber(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore", "RevisionNumber", 12usize, |this| ((**(this as *const *const IContactStore_Vtbl)).RevisionNumber)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetChangesAsync(this: *mut core::ffi::c_void, baserevisionnumber: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore", "GetChangesAsync", 13usize, |this| ((**(this as *const *const IContactStore_Vtbl)).GetChangesAsync)(this, baserevisionnumber, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn LoadExtendedPropertiesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore", "LoadExtendedPropertiesAsync", 14usize, |this| ((**(this as *const *const IContactStore_Vtbl)).LoadExtendedPropertiesAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SaveExtendedPropertiesAsync(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore", "SaveExtendedPropertiesAsync", 15usize, |this| ((**(this as *const *const IContactStore_Vtbl)).SaveExtendedPropertiesAsync)(this, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FindContactByRemoteIdAsync,
+            FindContactByIdAsync,
+            DeleteContactAsync,
+            CreateContactQueryDefault,
+            CreateContactQueryWithOptions,
+            DeleteAsync,
+            RevisionNumber,
+            #[cfg(feature = "Foundation_Collections")]
+            GetChangesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetChangesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            LoadExtendedPropertiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            LoadExtendedPropertiesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SaveExtendedPropertiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SaveExtendedPropertiesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactStore2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMeContactAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStore2", "CreateMeContactAsync", 6usize, |this| ((**(this as *const *const IContactStore2_Vtbl)).CreateMeContactAsync)(this, id, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMeContactAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactStore2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactStoreStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateOrOpenAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStoreStatics", "CreateOrOpenAsync", 6usize, |this| ((**(this as *const *const IContactStoreStatics_Vtbl)).CreateOrOpenAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOrOpenWithOptionsAsync(this: *mut core::ffi::c_void, access: ContactStoreSystemAccessMode, sharing: ContactStoreApplicationAccessMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactStoreStatics", "CreateOrOpenWithOptionsAsync", 7usize, |this| ((**(this as *const *const IContactStoreStatics_Vtbl)).CreateOrOpenWithOptionsAsync)(this, access, sharing, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateOrOpenAsync, CreateOrOpenWithOptionsAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactStoreStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKnownContactPropertiesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "DisplayName", 6usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "FamilyName", 7usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).FamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GivenName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "GivenName", 8usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).GivenName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HonorificPrefix(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "HonorificPrefix", 9usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).HonorificPrefix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HonorificSuffix(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "HonorificSuffix", 10usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).HonorificSuffix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdditionalName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "AdditionalName", 11usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).AdditionalName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "Address", 12usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).Address)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "OtherAddress", 13usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).OtherAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Email(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "Email", 14usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).Email)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WorkAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "WorkAddress", 15usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).WorkAddress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WorkTelephone(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "WorkTelephone", 16usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).WorkTelephone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JobTitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "JobTitle", 17usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).JobTitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Birthdate(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "Birthdate", 18usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).Birthdate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Anniversary(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "Anniversary", 19usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).Anniversary)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Telephone(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "Telephone", 20usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).Telephone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MobileTelephone(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "MobileTelephone", 21usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).MobileTelephone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Url(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "Url", 22usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).Url)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Notes(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "Notes", 23usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).Notes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WorkFax(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "WorkFax", 24usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).WorkFax)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Children(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "Children", 25usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).Children)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignificantOther(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "SignificantOther", 26usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).SignificantOther)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompanyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "CompanyName", 27usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).CompanyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompanyTelephone(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "CompanyTelephone", 28usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).CompanyTelephone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HomeFax(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "HomeFax", 29usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).HomeFax)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlternateTelephone(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "AlternateTelephone", 30usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).AlternateTelephone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Manager(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "Manager", 31usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).Manager)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Nickname(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "Nickname", 32usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).Nickname)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OfficeLocation(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "OfficeLocation", 33usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).OfficeLocation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WorkEmail(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "WorkEmail", 34usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).WorkEmail)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn YomiGivenName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "YomiGivenName", 35usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).YomiGivenName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn YomiFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "YomiFamilyName", 36usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).YomiFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn YomiCompanyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "YomiCompanyName", 37usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).YomiCompanyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherEmail(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "OtherEmail", 38usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).OtherEmail)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlternateMobileTelephone(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "AlternateMobileTelephone", 39usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).AlternateMobileTelephone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlternateWorkTelephone(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownContactPropertiesStatics", "AlternateWorkTelephone", 40usize, |this| ((**(this as *const *const IKnownContactPropertiesStatics_Vtbl)).AlternateWorkTelephone)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayName,
+            FamilyName,
+            GivenName,
+            HonorificPrefix,
+            HonorificSuffix,
+            AdditionalName,
+            Address,
+            OtherAddress,
+            Email,
+            WorkAddress,
+            WorkTelephone,
+            JobTitle,
+            Birthdate,
+            Anniversary,
+            Telephone,
+            MobileTelephone,
+            Url,
+            Notes,
+            WorkFax,
+            Children,
+            SignificantOther,
+            CompanyName,
+            CompanyTelephone,
+            HomeFax,
+            AlternateTelephone,
+            Manager,
+            Nickname,
+            OfficeLocation,
+            WorkEmail,
+            YomiGivenName,
+            YomiFamilyName,
+            YomiCompanyName,
+            OtherEmail,
+            AlternateMobileTelephone,
+            AlternateWorkTelephone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownContactPropertiesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IStoredContact_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Store(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoredContact", "Store", 6usize, |this| ((**(this as *const *const IStoredContact_Vtbl)).Store)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoredContact", "Id", 7usize, |this| ((**(this as *const *const IStoredContact_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoredContact", "RemoteId", 8usize, |this| ((**(this as *const *const IStoredContact_Vtbl)).RemoteId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoredContact", "SetRemoteId", 9usize, |this| ((**(this as *const *const IStoredContact_Vtbl)).SetRemoteId)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetExtendedPropertiesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoredContact", "GetExtendedPropertiesAsync", 10usize, |this| ((**(this as *const *const IStoredContact_Vtbl)).GetExtendedPropertiesAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoredContact", "SaveAsync", 11usize, |this| ((**(this as *const *const IStoredContact_Vtbl)).SaveAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplaceExistingContactAsync(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoredContact", "ReplaceExistingContactAsync", 12usize, |this| ((**(this as *const *const IStoredContact_Vtbl)).ReplaceExistingContactAsync)(this, id, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Store,
+            Id,
+            RemoteId,
+            SetRemoteId,
+            #[cfg(feature = "Foundation_Collections")]
+            GetExtendedPropertiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetExtendedPropertiesAsync: 0,
+            SaveAsync,
+            ReplaceExistingContactAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoredContact as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoredContactFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateStoredContact(this: *mut core::ffi::c_void, store: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoredContactFactory", "CreateStoredContact", 6usize, |this| ((**(this as *const *const IStoredContactFactory_Vtbl)).CreateStoredContact)(this, store, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStoredContactFromInformation(this: *mut core::ffi::c_void, store: *mut core::ffi::c_void, contact: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoredContactFactory", "CreateStoredContactFromInformation", 7usize, |this| ((**(this as *const *const IStoredContactFactory_Vtbl)).CreateStoredContactFromInformation)(this, store, contact, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateStoredContact, CreateStoredContactFromInformation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoredContactFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
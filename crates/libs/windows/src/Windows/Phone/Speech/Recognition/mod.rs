@@ -11,6 +11,7 @@ impl SpeechRecognitionUIStatus {
 impl windows_core::TypeKind for SpeechRecognitionUIStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRecognitionUIStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRecognitionUIStatus").field(&self.0).finish()
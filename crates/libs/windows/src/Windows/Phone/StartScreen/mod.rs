@@ -198,3 +198,5 @@ impl windows_core::RuntimeName for DualSimTile {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,123 @@
+impl windows_core::imp::ProxyVtable for IDualSimTile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDualSimTile", "SetDisplayName", 6usize, |this| ((**(this as *const *const IDualSimTile_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDualSimTile", "DisplayName", 7usize, |this| ((**(this as *const *const IDualSimTile_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPinnedToStart(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDualSimTile", "IsPinnedToStart", 8usize, |this| ((**(this as *const *const IDualSimTile_Vtbl)).IsPinnedToStart)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDualSimTile", "CreateAsync", 9usize, |this| ((**(this as *const *const IDualSimTile_Vtbl)).CreateAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDualSimTile", "UpdateAsync", 10usize, |this| ((**(this as *const *const IDualSimTile_Vtbl)).UpdateAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDualSimTile", "DeleteAsync", 11usize, |this| ((**(this as *const *const IDualSimTile_Vtbl)).DeleteAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDisplayName,
+            DisplayName,
+            IsPinnedToStart,
+            CreateAsync,
+            UpdateAsync,
+            DeleteAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDualSimTile as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Notifications")]
+impl windows_core::imp::ProxyVtable for IDualSimTileStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTileForSim2(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDualSimTileStatics", "GetTileForSim2", 6usize, |this| ((**(this as *const *const IDualSimTileStatics_Vtbl)).GetTileForSim2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateDisplayNameForSim1Async(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDualSimTileStatics", "UpdateDisplayNameForSim1Async", 7usize, |this| ((**(this as *const *const IDualSimTileStatics_Vtbl)).UpdateDisplayNameForSim1Async)(this, name, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn CreateTileUpdaterForSim1(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDualSimTileStatics", "CreateTileUpdaterForSim1", 8usize, |this| ((**(this as *const *const IDualSimTileStatics_Vtbl)).CreateTileUpdaterForSim1)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn CreateTileUpdaterForSim2(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDualSimTileStatics", "CreateTileUpdaterForSim2", 9usize, |this| ((**(this as *const *const IDualSimTileStatics_Vtbl)).CreateTileUpdaterForSim2)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn CreateBadgeUpdaterForSim1(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDualSimTileStatics", "CreateBadgeUpdaterForSim1", 10usize, |this| ((**(this as *const *const IDualSimTileStatics_Vtbl)).CreateBadgeUpdaterForSim1)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn CreateBadgeUpdaterForSim2(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDualSimTileStatics", "CreateBadgeUpdaterForSim2", 11usize, |this| ((**(this as *const *const IDualSimTileStatics_Vtbl)).CreateBadgeUpdaterForSim2)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn CreateToastNotifierForSim1(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDualSimTileStatics", "CreateToastNotifierForSim1", 12usize, |this| ((**(this as *const *const IDualSimTileStatics_Vtbl)).CreateToastNotifierForSim1)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn CreateToastNotifierForSim2(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDualSimTileStatics", "CreateToastNotifierForSim2", 13usize, |this| ((**(this as *const *const IDualSimTileStatics_Vtbl)).CreateToastNotifierForSim2)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTileForSim2,
+            UpdateDisplayNameForSim1Async,
+            #[cfg(feature = "UI_Notifications")]
+            CreateTileUpdaterForSim1,
+            #[cfg(not(feature = "UI_Notifications"))]
+            CreateTileUpdaterForSim1: 0,
+            #[cfg(feature = "UI_Notifications")]
+            CreateTileUpdaterForSim2,
+            #[cfg(not(feature = "UI_Notifications"))]
+            CreateTileUpdaterForSim2: 0,
+            #[cfg(feature = "UI_Notifications")]
+            CreateBadgeUpdaterForSim1,
+            #[cfg(not(feature = "UI_Notifications"))]
+            CreateBadgeUpdaterForSim1: 0,
+            #[cfg(feature = "UI_Notifications")]
+            CreateBadgeUpdaterForSim2,
+            #[cfg(not(feature = "UI_Notifications"))]
+            CreateBadgeUpdaterForSim2: 0,
+            #[cfg(feature = "UI_Notifications")]
+            CreateToastNotifierForSim1,
+            #[cfg(not(feature = "UI_Notifications"))]
+            CreateToastNotifierForSim1: 0,
+            #[cfg(feature = "UI_Notifications")]
+            CreateToastNotifierForSim2,
+            #[cfg(not(feature = "UI_Notifications"))]
+            CreateToastNotifierForSim2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDualSimTileStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Notifications")]
+impl windows_core::imp::ProxyVtable for IToastNotificationManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Notifications")]
+        unsafe extern "system" fn CreateToastNotifierForSecondaryTile(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerStatics3", "CreateToastNotifierForSecondaryTile", 6usize, |this| ((**(this as *const *const IToastNotificationManagerStatics3_Vtbl)).CreateToastNotifierForSecondaryTile)(this, tileid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Notifications")]
+            CreateToastNotifierForSecondaryTile,
+            #[cfg(not(feature = "UI_Notifications"))]
+            CreateToastNotifierForSecondaryTile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -68,6 +68,7 @@ impl PowerSavingMode {
 impl windows_core::TypeKind for PowerSavingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PowerSavingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PowerSavingMode").field(&self.0).finish()
@@ -76,3 +77,5 @@ impl core::fmt::Debug for PowerSavingMode {
 impl windows_core::RuntimeType for PowerSavingMode {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Phone.System.Power.PowerSavingMode;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,35 @@
+impl windows_core::imp::ProxyVtable for IPowerManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PowerSavingMode(this: *mut core::ffi::c_void, result__: *mut PowerSavingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "PowerSavingMode", 6usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).PowerSavingMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerSavingModeChanged(this: *mut core::ffi::c_void, changehandler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "PowerSavingModeChanged", 7usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).PowerSavingModeChanged)(this, changehandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePowerSavingModeChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "RemovePowerSavingModeChanged", 8usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).RemovePowerSavingModeChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PowerSavingMode,
+            PowerSavingModeChanged,
+            RemovePowerSavingModeChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPowerManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPowerManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PowerSavingModeEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics2", "PowerSavingModeEnabled", 6usize, |this| ((**(this as *const *const IPowerManagerStatics2_Vtbl)).PowerSavingModeEnabled)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PowerSavingModeEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPowerManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -35,3 +35,5 @@ impl RetailMode {
 impl windows_core::RuntimeName for RetailMode {
     const NAME: &'static str = "Windows.Phone.System.Profile.RetailMode";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,20 @@
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IRetailModeStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RetailModeEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRetailModeStatics", "RetailModeEnabled", 6usize, |this| ((**(this as *const *const IRetailModeStatics_Vtbl)).RetailModeEnabled)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            RetailModeEnabled,
+            #[cfg(not(feature = "deprecated"))]
+            RetailModeEnabled: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRetailModeStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
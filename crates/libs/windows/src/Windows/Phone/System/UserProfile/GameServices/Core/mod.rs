@@ -147,6 +147,7 @@ impl GameServiceGameOutcome {
 impl windows_core::TypeKind for GameServiceGameOutcome {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameServiceGameOutcome {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameServiceGameOutcome").field(&self.0).finish()
@@ -165,6 +166,7 @@ impl GameServiceScoreKind {
 impl windows_core::TypeKind for GameServiceScoreKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GameServiceScoreKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GameServiceScoreKind").field(&self.0).finish()
@@ -173,3 +175,5 @@ impl core::fmt::Debug for GameServiceScoreKind {
 impl windows_core::RuntimeType for GameServiceScoreKind {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Phone.System.UserProfile.GameServices.Core.GameServiceScoreKind;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,75 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IGameService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServiceUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameService", "ServiceUri", 6usize, |this| ((**(this as *const *const IGameService_Vtbl)).ServiceUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGamerProfileAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameService", "GetGamerProfileAsync", 7usize, |this| ((**(this as *const *const IGameService_Vtbl)).GetGamerProfileAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInstalledGameItemsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameService", "GetInstalledGameItemsAsync", 8usize, |this| ((**(this as *const *const IGameService_Vtbl)).GetInstalledGameItemsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPartnerTokenAsync(this: *mut core::ffi::c_void, audienceuri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameService", "GetPartnerTokenAsync", 9usize, |this| ((**(this as *const *const IGameService_Vtbl)).GetPartnerTokenAsync)(this, audienceuri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivilegesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameService", "GetPrivilegesAsync", 10usize, |this| ((**(this as *const *const IGameService_Vtbl)).GetPrivilegesAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GrantAchievement(this: *mut core::ffi::c_void, achievementid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameService", "GrantAchievement", 11usize, |this| ((**(this as *const *const IGameService_Vtbl)).GrantAchievement)(this, achievementid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GrantAvatarAward(this: *mut core::ffi::c_void, avatarawardid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameService", "GrantAvatarAward", 12usize, |this| ((**(this as *const *const IGameService_Vtbl)).GrantAvatarAward)(this, avatarawardid), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn PostResult(this: *mut core::ffi::c_void, gamevariant: u32, scorekind: GameServiceScoreKind, scorevalue: i64, gameoutcome: GameServiceGameOutcome, buffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameService", "PostResult", 13usize, |this| ((**(this as *const *const IGameService_Vtbl)).PostResult)(this, gamevariant, scorekind, scorevalue, gameoutcome, buffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServiceUri,
+            GetGamerProfileAsync,
+            GetInstalledGameItemsAsync,
+            GetPartnerTokenAsync,
+            GetPrivilegesAsync,
+            GrantAchievement,
+            GrantAvatarAward,
+            #[cfg(feature = "Storage_Streams")]
+            PostResult,
+            #[cfg(not(feature = "Storage_Streams"))]
+            PostResult: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameService as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameService2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifyPartnerTokenExpired(this: *mut core::ffi::c_void, audienceuri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameService2", "NotifyPartnerTokenExpired", 6usize, |this| ((**(this as *const *const IGameService2_Vtbl)).NotifyPartnerTokenExpired)(this, audienceuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAuthenticationStatus(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameService2", "GetAuthenticationStatus", 7usize, |this| ((**(this as *const *const IGameService2_Vtbl)).GetAuthenticationStatus)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NotifyPartnerTokenExpired, GetAuthenticationStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameService2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameServicePropertyCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyAsync(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameServicePropertyCollection", "GetPropertyAsync", 6usize, |this| ((**(this as *const *const IGameServicePropertyCollection_Vtbl)).GetPropertyAsync)(this, propertyname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPropertyAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameServicePropertyCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
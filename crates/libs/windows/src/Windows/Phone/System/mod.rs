@@ -47,3 +47,5 @@ impl SystemProtection {
 impl windows_core::RuntimeName for SystemProtection {
     const NAME: &'static str = "Windows.Phone.System.SystemProtection";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
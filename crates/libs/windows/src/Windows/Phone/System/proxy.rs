@@ -0,0 +1,24 @@
+impl windows_core::imp::ProxyVtable for ISystemProtectionStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScreenLocked(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemProtectionStatics", "ScreenLocked", 6usize, |this| ((**(this as *const *const ISystemProtectionStatics_Vtbl)).ScreenLocked)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ScreenLocked }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemProtectionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemProtectionUnlockStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestScreenUnlock(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemProtectionUnlockStatics", "RequestScreenUnlock", 6usize, |this| ((**(this as *const *const ISystemProtectionUnlockStatics_Vtbl)).RequestScreenUnlock)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestScreenUnlock }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemProtectionUnlockStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
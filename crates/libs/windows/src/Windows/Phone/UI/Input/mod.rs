@@ -150,3 +150,5 @@ impl HardwareButtons {
 impl windows_core::RuntimeName for HardwareButtons {
     const NAME: &'static str = "Windows.Phone.UI.Input.HardwareButtons";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
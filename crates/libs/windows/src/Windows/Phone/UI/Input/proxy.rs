@@ -0,0 +1,72 @@
+impl windows_core::imp::ProxyVtable for IBackPressedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackPressedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IBackPressedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackPressedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IBackPressedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackPressedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICameraEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHardwareButtonsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BackPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareButtonsStatics", "BackPressed", 6usize, |this| ((**(this as *const *const IHardwareButtonsStatics_Vtbl)).BackPressed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBackPressed(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareButtonsStatics", "RemoveBackPressed", 7usize, |this| ((**(this as *const *const IHardwareButtonsStatics_Vtbl)).RemoveBackPressed)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BackPressed, RemoveBackPressed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHardwareButtonsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHardwareButtonsStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CameraHalfPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareButtonsStatics2", "CameraHalfPressed", 6usize, |this| ((**(this as *const *const IHardwareButtonsStatics2_Vtbl)).CameraHalfPressed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCameraHalfPressed(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareButtonsStatics2", "RemoveCameraHalfPressed", 7usize, |this| ((**(this as *const *const IHardwareButtonsStatics2_Vtbl)).RemoveCameraHalfPressed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CameraPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareButtonsStatics2", "CameraPressed", 8usize, |this| ((**(this as *const *const IHardwareButtonsStatics2_Vtbl)).CameraPressed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCameraPressed(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareButtonsStatics2", "RemoveCameraPressed", 9usize, |this| ((**(this as *const *const IHardwareButtonsStatics2_Vtbl)).RemoveCameraPressed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CameraReleased(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareButtonsStatics2", "CameraReleased", 10usize, |this| ((**(this as *const *const IHardwareButtonsStatics2_Vtbl)).CameraReleased)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCameraReleased(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareButtonsStatics2", "RemoveCameraReleased", 11usize, |this| ((**(this as *const *const IHardwareButtonsStatics2_Vtbl)).RemoveCameraReleased)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CameraHalfPressed,
+            RemoveCameraHalfPressed,
+            CameraPressed,
+            RemoveCameraPressed,
+            CameraReleased,
+            RemoveCameraReleased,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHardwareButtonsStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -398,6 +398,7 @@ impl MicrosoftAccountMultiFactorAuthenticationType {
 impl windows_core::TypeKind for MicrosoftAccountMultiFactorAuthenticationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MicrosoftAccountMultiFactorAuthenticationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MicrosoftAccountMultiFactorAuthenticationType").field(&self.0).finish()
@@ -436,6 +437,7 @@ impl MicrosoftAccountMultiFactorServiceResponse {
 impl windows_core::TypeKind for MicrosoftAccountMultiFactorServiceResponse {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MicrosoftAccountMultiFactorServiceResponse {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MicrosoftAccountMultiFactorServiceResponse").field(&self.0).finish()
@@ -455,6 +457,7 @@ impl MicrosoftAccountMultiFactorSessionApprovalStatus {
 impl windows_core::TypeKind for MicrosoftAccountMultiFactorSessionApprovalStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MicrosoftAccountMultiFactorSessionApprovalStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MicrosoftAccountMultiFactorSessionApprovalStatus").field(&self.0).finish()
@@ -473,6 +476,7 @@ impl MicrosoftAccountMultiFactorSessionAuthenticationStatus {
 impl windows_core::TypeKind for MicrosoftAccountMultiFactorSessionAuthenticationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MicrosoftAccountMultiFactorSessionAuthenticationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MicrosoftAccountMultiFactorSessionAuthenticationStatus").field(&self.0).finish()
@@ -481,3 +485,5 @@ impl core::fmt::Debug for MicrosoftAccountMultiFactorSessionAuthenticationStatus
 impl windows_core::RuntimeType for MicrosoftAccountMultiFactorSessionAuthenticationStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Security.Authentication.Identity.Core.MicrosoftAccountMultiFactorSessionAuthenticationStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,188 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMicrosoftAccountMultiFactorAuthenticationManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOneTimePassCodeAsync(this: *mut core::ffi::c_void, useraccountid: core::mem::MaybeUninit<windows_core::HSTRING>, codelength: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorAuthenticationManager", "GetOneTimePassCodeAsync", 6usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorAuthenticationManager_Vtbl)).GetOneTimePassCodeAsync)(this, useraccountid, codelength, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDeviceAsync(this: *mut core::ffi::c_void, useraccountid: core::mem::MaybeUninit<windows_core::HSTRING>, authenticationtoken: core::mem::MaybeUninit<windows_core::HSTRING>, wnschannelid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorAuthenticationManager", "AddDeviceAsync", 7usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorAuthenticationManager_Vtbl)).AddDeviceAsync)(this, useraccountid, authenticationtoken, wnschannelid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDeviceAsync(this: *mut core::ffi::c_void, useraccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorAuthenticationManager", "RemoveDeviceAsync", 8usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorAuthenticationManager_Vtbl)).RemoveDeviceAsync)(this, useraccountid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateWnsChannelAsync(this: *mut core::ffi::c_void, useraccountid: core::mem::MaybeUninit<windows_core::HSTRING>, channeluri: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorAuthenticationManager", "UpdateWnsChannelAsync", 9usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorAuthenticationManager_Vtbl)).UpdateWnsChannelAsync)(this, useraccountid, channeluri, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSessionsAsync(this: *mut core::ffi::c_void, useraccountidlist: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorAuthenticationManager", "GetSessionsAsync", 10usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorAuthenticationManager_Vtbl)).GetSessionsAsync)(this, useraccountidlist, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSessionsAndUnregisteredAccountsAsync(this: *mut core::ffi::c_void, useraccountidlist: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorAuthenticationManager", "GetSessionsAndUnregisteredAccountsAsync", 11usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorAuthenticationManager_Vtbl)).GetSessionsAndUnregisteredAccountsAsync)(this, useraccountidlist, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApproveSessionUsingAuthSessionInfoAsync(this: *mut core::ffi::c_void, sessionauthentictionstatus: MicrosoftAccountMultiFactorSessionAuthenticationStatus, authenticationsessioninfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorAuthenticationManager", "ApproveSessionUsingAuthSessionInfoAsync", 12usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorAuthenticationManager_Vtbl)).ApproveSessionUsingAuthSessionInfoAsync)(this, sessionauthentictionstatus, authenticationsessioninfo, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApproveSessionAsync(this: *mut core::ffi::c_void, sessionauthentictionstatus: MicrosoftAccountMultiFactorSessionAuthenticationStatus, useraccountid: core::mem::MaybeUninit<windows_core::HSTRING>, sessionid: core::mem::MaybeUninit<windows_core::HSTRING>, sessionauthenticationtype: MicrosoftAccountMultiFactorAuthenticationType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorAuthenticationManager", "ApproveSessionAsync", 13usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorAuthenticationManager_Vtbl)).ApproveSessionAsync)(this, sessionauthentictionstatus, useraccountid, sessionid, sessionauthenticationtype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DenySessionUsingAuthSessionInfoAsync(this: *mut core::ffi::c_void, authenticationsessioninfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorAuthenticationManager", "DenySessionUsingAuthSessionInfoAsync", 14usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorAuthenticationManager_Vtbl)).DenySessionUsingAuthSessionInfoAsync)(this, authenticationsessioninfo, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DenySessionAsync(this: *mut core::ffi::c_void, useraccountid: core::mem::MaybeUninit<windows_core::HSTRING>, sessionid: core::mem::MaybeUninit<windows_core::HSTRING>, sessionauthenticationtype: MicrosoftAccountMultiFactorAuthenticationType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorAuthenticationManager", "DenySessionAsync", 15usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorAuthenticationManager_Vtbl)).DenySessionAsync)(this, useraccountid, sessionid, sessionauthenticationtype, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOneTimePassCodeAsync,
+            AddDeviceAsync,
+            RemoveDeviceAsync,
+            UpdateWnsChannelAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSessionsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSessionsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSessionsAndUnregisteredAccountsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSessionsAndUnregisteredAccountsAsync: 0,
+            ApproveSessionUsingAuthSessionInfoAsync,
+            ApproveSessionAsync,
+            DenySessionUsingAuthSessionInfoAsync,
+            DenySessionAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMicrosoftAccountMultiFactorAuthenticationManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMicrosoftAccountMultiFactorAuthenticatorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorAuthenticatorStatics", "Current", 6usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorAuthenticatorStatics_Vtbl)).Current)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Current }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMicrosoftAccountMultiFactorAuthenticatorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMicrosoftAccountMultiFactorGetSessionsResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Sessions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorGetSessionsResult", "Sessions", 6usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorGetSessionsResult_Vtbl)).Sessions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceResponse(this: *mut core::ffi::c_void, result__: *mut MicrosoftAccountMultiFactorServiceResponse) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorGetSessionsResult", "ServiceResponse", 7usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorGetSessionsResult_Vtbl)).ServiceResponse)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Sessions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Sessions: 0,
+            ServiceResponse,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMicrosoftAccountMultiFactorGetSessionsResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMicrosoftAccountMultiFactorOneTimeCodedInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Code(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorOneTimeCodedInfo", "Code", 6usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorOneTimeCodedInfo_Vtbl)).Code)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeInterval(this: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorOneTimeCodedInfo", "TimeInterval", 7usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorOneTimeCodedInfo_Vtbl)).TimeInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeToLive(this: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorOneTimeCodedInfo", "TimeToLive", 8usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorOneTimeCodedInfo_Vtbl)).TimeToLive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceResponse(this: *mut core::ffi::c_void, result__: *mut MicrosoftAccountMultiFactorServiceResponse) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorOneTimeCodedInfo", "ServiceResponse", 9usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorOneTimeCodedInfo_Vtbl)).ServiceResponse)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Code, TimeInterval, TimeToLive, ServiceResponse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMicrosoftAccountMultiFactorOneTimeCodedInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMicrosoftAccountMultiFactorSessionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserAccountId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorSessionInfo", "UserAccountId", 6usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorSessionInfo_Vtbl)).UserAccountId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorSessionInfo", "SessionId", 7usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorSessionInfo_Vtbl)).SessionId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplaySessionId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorSessionInfo", "DisplaySessionId", 8usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorSessionInfo_Vtbl)).DisplaySessionId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApprovalStatus(this: *mut core::ffi::c_void, result__: *mut MicrosoftAccountMultiFactorSessionApprovalStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorSessionInfo", "ApprovalStatus", 9usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorSessionInfo_Vtbl)).ApprovalStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationType(this: *mut core::ffi::c_void, result__: *mut MicrosoftAccountMultiFactorAuthenticationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorSessionInfo", "AuthenticationType", 10usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorSessionInfo_Vtbl)).AuthenticationType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorSessionInfo", "RequestTime", 11usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorSessionInfo_Vtbl)).RequestTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorSessionInfo", "ExpirationTime", 12usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorSessionInfo_Vtbl)).ExpirationTime)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UserAccountId,
+            SessionId,
+            DisplaySessionId,
+            ApprovalStatus,
+            AuthenticationType,
+            RequestTime,
+            ExpirationTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMicrosoftAccountMultiFactorSessionInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMicrosoftAccountMultiFactorUnregisteredAccountsAndSessionInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Sessions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorUnregisteredAccountsAndSessionInfo", "Sessions", 6usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorUnregisteredAccountsAndSessionInfo_Vtbl)).Sessions)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UnregisteredAccounts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorUnregisteredAccountsAndSessionInfo", "UnregisteredAccounts", 7usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorUnregisteredAccountsAndSessionInfo_Vtbl)).UnregisteredAccounts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceResponse(this: *mut core::ffi::c_void, result__: *mut MicrosoftAccountMultiFactorServiceResponse) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMicrosoftAccountMultiFactorUnregisteredAccountsAndSessionInfo", "ServiceResponse", 8usize, |this| ((**(this as *const *const IMicrosoftAccountMultiFactorUnregisteredAccountsAndSessionInfo_Vtbl)).ServiceResponse)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Sessions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Sessions: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            UnregisteredAccounts,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UnregisteredAccounts: 0,
+            ServiceResponse,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMicrosoftAccountMultiFactorUnregisteredAccountsAndSessionInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
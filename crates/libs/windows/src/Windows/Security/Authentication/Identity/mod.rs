@@ -124,3 +124,5 @@ impl windows_core::RuntimeName for EnterpriseKeyCredentialRegistrationManager {
 }
 unsafe impl Send for EnterpriseKeyCredentialRegistrationManager {}
 unsafe impl Sync for EnterpriseKeyCredentialRegistrationManager {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,56 @@
+impl windows_core::imp::ProxyVtable for IEnterpriseKeyCredentialRegistrationInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TenantId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseKeyCredentialRegistrationInfo", "TenantId", 6usize, |this| ((**(this as *const *const IEnterpriseKeyCredentialRegistrationInfo_Vtbl)).TenantId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TenantName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseKeyCredentialRegistrationInfo", "TenantName", 7usize, |this| ((**(this as *const *const IEnterpriseKeyCredentialRegistrationInfo_Vtbl)).TenantName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseKeyCredentialRegistrationInfo", "Subject", 8usize, |this| ((**(this as *const *const IEnterpriseKeyCredentialRegistrationInfo_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseKeyCredentialRegistrationInfo", "KeyId", 9usize, |this| ((**(this as *const *const IEnterpriseKeyCredentialRegistrationInfo_Vtbl)).KeyId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseKeyCredentialRegistrationInfo", "KeyName", 10usize, |this| ((**(this as *const *const IEnterpriseKeyCredentialRegistrationInfo_Vtbl)).KeyName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TenantId, TenantName, Subject, KeyId, KeyName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnterpriseKeyCredentialRegistrationInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEnterpriseKeyCredentialRegistrationManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetRegistrationsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseKeyCredentialRegistrationManager", "GetRegistrationsAsync", 6usize, |this| ((**(this as *const *const IEnterpriseKeyCredentialRegistrationManager_Vtbl)).GetRegistrationsAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetRegistrationsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetRegistrationsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnterpriseKeyCredentialRegistrationManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnterpriseKeyCredentialRegistrationManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseKeyCredentialRegistrationManagerStatics", "Current", 6usize, |this| ((**(this as *const *const IEnterpriseKeyCredentialRegistrationManagerStatics_Vtbl)).Current)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Current }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnterpriseKeyCredentialRegistrationManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
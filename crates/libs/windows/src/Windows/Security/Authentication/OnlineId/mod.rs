@@ -504,6 +504,27 @@ impl SignOutUserOperation {
         self.GetResults()
     }
 }
+#[cfg(feature = "std")]
+impl super::super::super::Foundation::Async for SignOutUserOperation {
+    type Output = ();
+    fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetCompleted(&super::super::super::Foundation::AsyncActionCompletedHandler::new(move |_sender, _args| {
+            handler();
+            Ok(())
+        }))
+    }
+    fn get_results(&self) -> windows_core::Result<Self::Output> {
+        self.GetResults()
+    }
+}
+#[cfg(feature = "std")]
+impl core::future::IntoFuture for SignOutUserOperation {
+    type Output = windows_core::Result<()>;
+    type IntoFuture = super::super::super::Foundation::AsyncFuture<Self>;
+    fn into_future(self) -> Self::IntoFuture {
+        super::super::super::Foundation::AsyncFuture::new(self)
+    }
+}
 unsafe impl Send for SignOutUserOperation {}
 unsafe impl Sync for SignOutUserOperation {}
 #[repr(transparent)]
@@ -587,6 +608,27 @@ impl UserAuthenticationOperation {
         self.GetResults()
     }
 }
+#[cfg(feature = "std")]
+impl super::super::super::Foundation::Async for UserAuthenticationOperation {
+    type Output = UserIdentity;
+    fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetCompleted(&super::super::super::Foundation::AsyncOperationCompletedHandler::new(move |_sender, _args| {
+            handler();
+            Ok(())
+        }))
+    }
+    fn get_results(&self) -> windows_core::Result<Self::Output> {
+        self.GetResults()
+    }
+}
+#[cfg(feature = "std")]
+impl core::future::IntoFuture for UserAuthenticationOperation {
+    type Output = windows_core::Result<UserIdentity>;
+    type IntoFuture = super::super::super::Foundation::AsyncFuture<Self>;
+    fn into_future(self) -> Self::IntoFuture {
+        super::super::super::Foundation::AsyncFuture::new(self)
+    }
+}
 unsafe impl Send for UserAuthenticationOperation {}
 unsafe impl Sync for UserAuthenticationOperation {}
 #[repr(transparent)]
@@ -675,6 +717,7 @@ impl CredentialPromptType {
 impl windows_core::TypeKind for CredentialPromptType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CredentialPromptType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CredentialPromptType").field(&self.0).finish()
@@ -694,6 +737,7 @@ impl OnlineIdSystemTicketStatus {
 impl windows_core::TypeKind for OnlineIdSystemTicketStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OnlineIdSystemTicketStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OnlineIdSystemTicketStatus").field(&self.0).finish()
@@ -702,3 +746,5 @@ impl core::fmt::Debug for OnlineIdSystemTicketStatus {
 impl windows_core::RuntimeType for OnlineIdSystemTicketStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Security.Authentication.OnlineId.OnlineIdSystemTicketStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
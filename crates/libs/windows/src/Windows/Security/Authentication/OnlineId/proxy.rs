@@ -0,0 +1,233 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IOnlineIdAuthenticator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AuthenticateUserAsync(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdAuthenticator", "AuthenticateUserAsync", 6usize, |this| ((**(this as *const *const IOnlineIdAuthenticator_Vtbl)).AuthenticateUserAsync)(this, request, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AuthenticateUserAsyncAdvanced(this: *mut core::ffi::c_void, requests: *mut core::ffi::c_void, credentialprompttype: CredentialPromptType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdAuthenticator", "AuthenticateUserAsyncAdvanced", 7usize, |this| ((**(this as *const *const IOnlineIdAuthenticator_Vtbl)).AuthenticateUserAsyncAdvanced)(this, requests, credentialprompttype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignOutUserAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdAuthenticator", "SignOutUserAsync", 8usize, |this| ((**(this as *const *const IOnlineIdAuthenticator_Vtbl)).SignOutUserAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationId(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdAuthenticator", "SetApplicationId", 9usize, |this| ((**(this as *const *const IOnlineIdAuthenticator_Vtbl)).SetApplicationId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdAuthenticator", "ApplicationId", 10usize, |this| ((**(this as *const *const IOnlineIdAuthenticator_Vtbl)).ApplicationId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanSignOut(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdAuthenticator", "CanSignOut", 11usize, |this| ((**(this as *const *const IOnlineIdAuthenticator_Vtbl)).CanSignOut)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticatedSafeCustomerId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdAuthenticator", "AuthenticatedSafeCustomerId", 12usize, |this| ((**(this as *const *const IOnlineIdAuthenticator_Vtbl)).AuthenticatedSafeCustomerId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AuthenticateUserAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            AuthenticateUserAsyncAdvanced,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AuthenticateUserAsyncAdvanced: 0,
+            SignOutUserAsync,
+            SetApplicationId,
+            ApplicationId,
+            CanSignOut,
+            AuthenticatedSafeCustomerId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOnlineIdAuthenticator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOnlineIdServiceTicket_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdServiceTicket", "Value", 6usize, |this| ((**(this as *const *const IOnlineIdServiceTicket_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdServiceTicket", "Request", 7usize, |this| ((**(this as *const *const IOnlineIdServiceTicket_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdServiceTicket", "ErrorCode", 8usize, |this| ((**(this as *const *const IOnlineIdServiceTicket_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Value, Request, ErrorCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOnlineIdServiceTicket as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOnlineIdServiceTicketRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Service(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdServiceTicketRequest", "Service", 6usize, |this| ((**(this as *const *const IOnlineIdServiceTicketRequest_Vtbl)).Service)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Policy(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdServiceTicketRequest", "Policy", 7usize, |this| ((**(this as *const *const IOnlineIdServiceTicketRequest_Vtbl)).Policy)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Service, Policy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOnlineIdServiceTicketRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOnlineIdServiceTicketRequestFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateOnlineIdServiceTicketRequest(this: *mut core::ffi::c_void, service: core::mem::MaybeUninit<windows_core::HSTRING>, policy: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdServiceTicketRequestFactory", "CreateOnlineIdServiceTicketRequest", 6usize, |this| ((**(this as *const *const IOnlineIdServiceTicketRequestFactory_Vtbl)).CreateOnlineIdServiceTicketRequest)(this, service, policy, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOnlineIdServiceTicketRequestAdvanced(this: *mut core::ffi::c_void, service: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdServiceTicketRequestFactory", "CreateOnlineIdServiceTicketRequestAdvanced", 7usize, |this| ((**(this as *const *const IOnlineIdServiceTicketRequestFactory_Vtbl)).CreateOnlineIdServiceTicketRequestAdvanced)(this, service, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateOnlineIdServiceTicketRequest,
+            CreateOnlineIdServiceTicketRequestAdvanced,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOnlineIdServiceTicketRequestFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IOnlineIdSystemAuthenticatorForUser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTicketAsync(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdSystemAuthenticatorForUser", "GetTicketAsync", 6usize, |this| ((**(this as *const *const IOnlineIdSystemAuthenticatorForUser_Vtbl)).GetTicketAsync)(this, request, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationId(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdSystemAuthenticatorForUser", "SetApplicationId", 7usize, |this| ((**(this as *const *const IOnlineIdSystemAuthenticatorForUser_Vtbl)).SetApplicationId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdSystemAuthenticatorForUser", "ApplicationId", 8usize, |this| ((**(this as *const *const IOnlineIdSystemAuthenticatorForUser_Vtbl)).ApplicationId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdSystemAuthenticatorForUser", "User", 9usize, |this| ((**(this as *const *const IOnlineIdSystemAuthenticatorForUser_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTicketAsync,
+            SetApplicationId,
+            ApplicationId,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOnlineIdSystemAuthenticatorForUser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IOnlineIdSystemAuthenticatorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Default(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdSystemAuthenticatorStatics", "Default", 6usize, |this| ((**(this as *const *const IOnlineIdSystemAuthenticatorStatics_Vtbl)).Default)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdSystemAuthenticatorStatics", "GetForUser", 7usize, |this| ((**(this as *const *const IOnlineIdSystemAuthenticatorStatics_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Default,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOnlineIdSystemAuthenticatorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOnlineIdSystemIdentity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Ticket(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdSystemIdentity", "Ticket", 6usize, |this| ((**(this as *const *const IOnlineIdSystemIdentity_Vtbl)).Ticket)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdSystemIdentity", "Id", 7usize, |this| ((**(this as *const *const IOnlineIdSystemIdentity_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Ticket, Id }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOnlineIdSystemIdentity as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOnlineIdSystemTicketResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Identity(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdSystemTicketResult", "Identity", 6usize, |this| ((**(this as *const *const IOnlineIdSystemTicketResult_Vtbl)).Identity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut OnlineIdSystemTicketStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdSystemTicketResult", "Status", 7usize, |this| ((**(this as *const *const IOnlineIdSystemTicketResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOnlineIdSystemTicketResult", "ExtendedError", 8usize, |this| ((**(this as *const *const IOnlineIdSystemTicketResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Identity, Status, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOnlineIdSystemTicketResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUserIdentity_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Tickets(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserIdentity", "Tickets", 6usize, |this| ((**(this as *const *const IUserIdentity_Vtbl)).Tickets)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserIdentity", "Id", 7usize, |this| ((**(this as *const *const IUserIdentity_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SafeCustomerId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserIdentity", "SafeCustomerId", 8usize, |this| ((**(this as *const *const IUserIdentity_Vtbl)).SafeCustomerId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignInName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserIdentity", "SignInName", 9usize, |this| ((**(this as *const *const IUserIdentity_Vtbl)).SignInName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserIdentity", "FirstName", 10usize, |this| ((**(this as *const *const IUserIdentity_Vtbl)).FirstName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserIdentity", "LastName", 11usize, |this| ((**(this as *const *const IUserIdentity_Vtbl)).LastName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBetaAccount(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserIdentity", "IsBetaAccount", 12usize, |this| ((**(this as *const *const IUserIdentity_Vtbl)).IsBetaAccount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConfirmedPC(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserIdentity", "IsConfirmedPC", 13usize, |this| ((**(this as *const *const IUserIdentity_Vtbl)).IsConfirmedPC)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Tickets,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Tickets: 0,
+            Id,
+            SafeCustomerId,
+            SignInName,
+            FirstName,
+            LastName,
+            IsBetaAccount,
+            IsConfirmedPC,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserIdentity as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -878,6 +878,7 @@ impl FindAllWebAccountsStatus {
 impl windows_core::TypeKind for FindAllWebAccountsStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FindAllWebAccountsStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FindAllWebAccountsStatus").field(&self.0).finish()
@@ -896,6 +897,7 @@ impl WebTokenRequestPromptType {
 impl windows_core::TypeKind for WebTokenRequestPromptType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebTokenRequestPromptType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebTokenRequestPromptType").field(&self.0).finish()
@@ -918,6 +920,7 @@ impl WebTokenRequestStatus {
 impl windows_core::TypeKind for WebTokenRequestStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebTokenRequestStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebTokenRequestStatus").field(&self.0).finish()
@@ -926,3 +929,5 @@ impl core::fmt::Debug for WebTokenRequestStatus {
 impl windows_core::RuntimeType for WebTokenRequestStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Security.Authentication.Web.Core.WebTokenRequestStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
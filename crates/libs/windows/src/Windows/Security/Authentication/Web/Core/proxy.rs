@@ -0,0 +1,509 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+impl windows_core::imp::ProxyVtable for IFindAllAccountsResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+        unsafe extern "system" fn Accounts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindAllAccountsResult", "Accounts", 6usize, |this| ((**(this as *const *const IFindAllAccountsResult_Vtbl)).Accounts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut FindAllWebAccountsStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindAllAccountsResult", "Status", 7usize, |this| ((**(this as *const *const IFindAllAccountsResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderError(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindAllAccountsResult", "ProviderError", 8usize, |this| ((**(this as *const *const IFindAllAccountsResult_Vtbl)).ProviderError)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+            Accounts,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Credentials")))]
+            Accounts: 0,
+            Status,
+            ProviderError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFindAllAccountsResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IWebAccountEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn Account(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountEventArgs", "Account", 6usize, |this| ((**(this as *const *const IWebAccountEventArgs_Vtbl)).Account)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            Account,
+            #[cfg(not(feature = "Security_Credentials"))]
+            Account: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAccountMonitor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Updated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountMonitor", "Updated", 6usize, |this| ((**(this as *const *const IWebAccountMonitor_Vtbl)).Updated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUpdated(this: *mut core::ffi::c_void, token: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountMonitor", "RemoveUpdated", 7usize, |this| ((**(this as *const *const IWebAccountMonitor_Vtbl)).RemoveUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Removed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountMonitor", "Removed", 8usize, |this| ((**(this as *const *const IWebAccountMonitor_Vtbl)).Removed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoved(this: *mut core::ffi::c_void, token: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountMonitor", "RemoveRemoved", 9usize, |this| ((**(this as *const *const IWebAccountMonitor_Vtbl)).RemoveRemoved)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultSignInAccountChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountMonitor", "DefaultSignInAccountChanged", 10usize, |this| ((**(this as *const *const IWebAccountMonitor_Vtbl)).DefaultSignInAccountChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDefaultSignInAccountChanged(this: *mut core::ffi::c_void, token: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountMonitor", "RemoveDefaultSignInAccountChanged", 11usize, |this| ((**(this as *const *const IWebAccountMonitor_Vtbl)).RemoveDefaultSignInAccountChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Updated,
+            RemoveUpdated,
+            Removed,
+            RemoveRemoved,
+            DefaultSignInAccountChanged,
+            RemoveDefaultSignInAccountChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountMonitor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAccountMonitor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccountPictureUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountMonitor2", "AccountPictureUpdated", 6usize, |this| ((**(this as *const *const IWebAccountMonitor2_Vtbl)).AccountPictureUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAccountPictureUpdated(this: *mut core::ffi::c_void, token: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountMonitor2", "RemoveAccountPictureUpdated", 7usize, |this| ((**(this as *const *const IWebAccountMonitor2_Vtbl)).RemoveAccountPictureUpdated)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AccountPictureUpdated, RemoveAccountPictureUpdated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountMonitor2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IWebAuthenticationCoreManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTokenSilentlyAsync(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerStatics", "GetTokenSilentlyAsync", 6usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerStatics_Vtbl)).GetTokenSilentlyAsync)(this, request, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn GetTokenSilentlyWithWebAccountAsync(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerStatics", "GetTokenSilentlyWithWebAccountAsync", 7usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerStatics_Vtbl)).GetTokenSilentlyWithWebAccountAsync)(this, request, webaccount, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestTokenAsync(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerStatics", "RequestTokenAsync", 8usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerStatics_Vtbl)).RequestTokenAsync)(this, request, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn RequestTokenWithWebAccountAsync(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerStatics", "RequestTokenWithWebAccountAsync", 9usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerStatics_Vtbl)).RequestTokenWithWebAccountAsync)(this, request, webaccount, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn FindAccountAsync(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, webaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerStatics", "FindAccountAsync", 10usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerStatics_Vtbl)).FindAccountAsync)(this, provider, webaccountid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn FindAccountProviderAsync(this: *mut core::ffi::c_void, webaccountproviderid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerStatics", "FindAccountProviderAsync", 11usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerStatics_Vtbl)).FindAccountProviderAsync)(this, webaccountproviderid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn FindAccountProviderWithAuthorityAsync(this: *mut core::ffi::c_void, webaccountproviderid: core::mem::MaybeUninit<windows_core::HSTRING>, authority: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerStatics", "FindAccountProviderWithAuthorityAsync", 12usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerStatics_Vtbl)).FindAccountProviderWithAuthorityAsync)(this, webaccountproviderid, authority, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTokenSilentlyAsync,
+            #[cfg(feature = "Security_Credentials")]
+            GetTokenSilentlyWithWebAccountAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            GetTokenSilentlyWithWebAccountAsync: 0,
+            RequestTokenAsync,
+            #[cfg(feature = "Security_Credentials")]
+            RequestTokenWithWebAccountAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            RequestTokenWithWebAccountAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            FindAccountAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            FindAccountAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            FindAccountProviderAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            FindAccountProviderAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            FindAccountProviderWithAuthorityAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            FindAccountProviderWithAuthorityAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAuthenticationCoreManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Security_Credentials", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IWebAuthenticationCoreManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Security_Credentials", feature = "System"))]
+        unsafe extern "system" fn FindAccountProviderWithAuthorityForUserAsync(this: *mut core::ffi::c_void, webaccountproviderid: core::mem::MaybeUninit<windows_core::HSTRING>, authority: core::mem::MaybeUninit<windows_core::HSTRING>, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerStatics2", "FindAccountProviderWithAuthorityForUserAsync", 6usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerStatics2_Vtbl)).FindAccountProviderWithAuthorityForUserAsync)(this, webaccountproviderid, authority, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Security_Credentials", feature = "System"))]
+            FindAccountProviderWithAuthorityForUserAsync,
+            #[cfg(not(all(feature = "Security_Credentials", feature = "System")))]
+            FindAccountProviderWithAuthorityForUserAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAuthenticationCoreManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+impl windows_core::imp::ProxyVtable for IWebAuthenticationCoreManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+        unsafe extern "system" fn CreateWebAccountMonitor(this: *mut core::ffi::c_void, webaccounts: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerStatics3", "CreateWebAccountMonitor", 6usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerStatics3_Vtbl)).CreateWebAccountMonitor)(this, webaccounts, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+            CreateWebAccountMonitor,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Credentials")))]
+            CreateWebAccountMonitor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAuthenticationCoreManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Security_Credentials", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IWebAuthenticationCoreManagerStatics4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn FindAllAccountsAsync(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerStatics4", "FindAllAccountsAsync", 6usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerStatics4_Vtbl)).FindAllAccountsAsync)(this, provider, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn FindAllAccountsWithClientIdAsync(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, clientid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerStatics4", "FindAllAccountsWithClientIdAsync", 7usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerStatics4_Vtbl)).FindAllAccountsWithClientIdAsync)(this, provider, clientid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn FindSystemAccountProviderAsync(this: *mut core::ffi::c_void, webaccountproviderid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerStatics4", "FindSystemAccountProviderAsync", 8usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerStatics4_Vtbl)).FindSystemAccountProviderAsync)(this, webaccountproviderid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn FindSystemAccountProviderWithAuthorityAsync(this: *mut core::ffi::c_void, webaccountproviderid: core::mem::MaybeUninit<windows_core::HSTRING>, authority: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerStatics4", "FindSystemAccountProviderWithAuthorityAsync", 9usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerStatics4_Vtbl)).FindSystemAccountProviderWithAuthorityAsync)(this, webaccountproviderid, authority, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Security_Credentials", feature = "System"))]
+        unsafe extern "system" fn FindSystemAccountProviderWithAuthorityForUserAsync(this: *mut core::ffi::c_void, webaccountproviderid: core::mem::MaybeUninit<windows_core::HSTRING>, authority: core::mem::MaybeUninit<windows_core::HSTRING>, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerStatics4", "FindSystemAccountProviderWithAuthorityForUserAsync", 10usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerStatics4_Vtbl)).FindSystemAccountProviderWithAuthorityForUserAsync)(this, webaccountproviderid, authority, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            FindAllAccountsAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            FindAllAccountsAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            FindAllAccountsWithClientIdAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            FindAllAccountsWithClientIdAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            FindSystemAccountProviderAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            FindSystemAccountProviderAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            FindSystemAccountProviderWithAuthorityAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            FindSystemAccountProviderWithAuthorityAsync: 0,
+            #[cfg(all(feature = "Security_Credentials", feature = "System"))]
+            FindSystemAccountProviderWithAuthorityForUserAsync,
+            #[cfg(not(all(feature = "Security_Credentials", feature = "System")))]
+            FindSystemAccountProviderWithAuthorityForUserAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAuthenticationCoreManagerStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWebProviderError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProviderError", "ErrorCode", 6usize, |this| ((**(this as *const *const IWebProviderError_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorMessage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProviderError", "ErrorMessage", 7usize, |this| ((**(this as *const *const IWebProviderError_Vtbl)).ErrorMessage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProviderError", "Properties", 8usize, |this| ((**(this as *const *const IWebProviderError_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ErrorCode,
+            ErrorMessage,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebProviderError as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebProviderErrorFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, errorcode: u32, errormessage: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProviderErrorFactory", "Create", 6usize, |this| ((**(this as *const *const IWebProviderErrorFactory_Vtbl)).Create)(this, errorcode, errormessage, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebProviderErrorFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+impl windows_core::imp::ProxyVtable for IWebTokenRequest_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn WebAccountProvider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequest", "WebAccountProvider", 6usize, |this| ((**(this as *const *const IWebTokenRequest_Vtbl)).WebAccountProvider)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scope(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequest", "Scope", 7usize, |this| ((**(this as *const *const IWebTokenRequest_Vtbl)).Scope)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequest", "ClientId", 8usize, |this| ((**(this as *const *const IWebTokenRequest_Vtbl)).ClientId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PromptType(this: *mut core::ffi::c_void, result__: *mut WebTokenRequestPromptType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequest", "PromptType", 9usize, |this| ((**(this as *const *const IWebTokenRequest_Vtbl)).PromptType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequest", "Properties", 10usize, |this| ((**(this as *const *const IWebTokenRequest_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            WebAccountProvider,
+            #[cfg(not(feature = "Security_Credentials"))]
+            WebAccountProvider: 0,
+            Scope,
+            ClientId,
+            PromptType,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebTokenRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWebTokenRequest2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AppProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequest2", "AppProperties", 6usize, |this| ((**(this as *const *const IWebTokenRequest2_Vtbl)).AppProperties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AppProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AppProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebTokenRequest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebTokenRequest3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CorrelationId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequest3", "CorrelationId", 6usize, |this| ((**(this as *const *const IWebTokenRequest3_Vtbl)).CorrelationId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCorrelationId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequest3", "SetCorrelationId", 7usize, |this| ((**(this as *const *const IWebTokenRequest3_Vtbl)).SetCorrelationId)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CorrelationId, SetCorrelationId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebTokenRequest3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IWebTokenRequestFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, scope: core::mem::MaybeUninit<windows_core::HSTRING>, clientid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequestFactory", "Create", 6usize, |this| ((**(this as *const *const IWebTokenRequestFactory_Vtbl)).Create)(this, provider, scope, clientid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn CreateWithPromptType(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, scope: core::mem::MaybeUninit<windows_core::HSTRING>, clientid: core::mem::MaybeUninit<windows_core::HSTRING>, prompttype: WebTokenRequestPromptType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequestFactory", "CreateWithPromptType", 7usize, |this| ((**(this as *const *const IWebTokenRequestFactory_Vtbl)).CreateWithPromptType)(this, provider, scope, clientid, prompttype, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn CreateWithProvider(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequestFactory", "CreateWithProvider", 8usize, |this| ((**(this as *const *const IWebTokenRequestFactory_Vtbl)).CreateWithProvider)(this, provider, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn CreateWithScope(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, scope: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequestFactory", "CreateWithScope", 9usize, |this| ((**(this as *const *const IWebTokenRequestFactory_Vtbl)).CreateWithScope)(this, provider, scope, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            Create,
+            #[cfg(not(feature = "Security_Credentials"))]
+            Create: 0,
+            #[cfg(feature = "Security_Credentials")]
+            CreateWithPromptType,
+            #[cfg(not(feature = "Security_Credentials"))]
+            CreateWithPromptType: 0,
+            #[cfg(feature = "Security_Credentials")]
+            CreateWithProvider,
+            #[cfg(not(feature = "Security_Credentials"))]
+            CreateWithProvider: 0,
+            #[cfg(feature = "Security_Credentials")]
+            CreateWithScope,
+            #[cfg(not(feature = "Security_Credentials"))]
+            CreateWithScope: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebTokenRequestFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWebTokenRequestResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ResponseData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequestResult", "ResponseData", 6usize, |this| ((**(this as *const *const IWebTokenRequestResult_Vtbl)).ResponseData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseStatus(this: *mut core::ffi::c_void, result__: *mut WebTokenRequestStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequestResult", "ResponseStatus", 7usize, |this| ((**(this as *const *const IWebTokenRequestResult_Vtbl)).ResponseStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseError(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequestResult", "ResponseError", 8usize, |this| ((**(this as *const *const IWebTokenRequestResult_Vtbl)).ResponseError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvalidateCacheAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenRequestResult", "InvalidateCacheAsync", 9usize, |this| ((**(this as *const *const IWebTokenRequestResult_Vtbl)).InvalidateCacheAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ResponseData,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ResponseData: 0,
+            ResponseStatus,
+            ResponseError,
+            InvalidateCacheAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebTokenRequestResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+impl windows_core::imp::ProxyVtable for IWebTokenResponse_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Token(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenResponse", "Token", 6usize, |this| ((**(this as *const *const IWebTokenResponse_Vtbl)).Token)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderError(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenResponse", "ProviderError", 7usize, |this| ((**(this as *const *const IWebTokenResponse_Vtbl)).ProviderError)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn WebAccount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenResponse", "WebAccount", 8usize, |this| ((**(this as *const *const IWebTokenResponse_Vtbl)).WebAccount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenResponse", "Properties", 9usize, |this| ((**(this as *const *const IWebTokenResponse_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Token,
+            ProviderError,
+            #[cfg(feature = "Security_Credentials")]
+            WebAccount,
+            #[cfg(not(feature = "Security_Credentials"))]
+            WebAccount: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebTokenResponse as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IWebTokenResponseFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithToken(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenResponseFactory", "CreateWithToken", 6usize, |this| ((**(this as *const *const IWebTokenResponseFactory_Vtbl)).CreateWithToken)(this, token, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn CreateWithTokenAndAccount(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>, webaccount: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenResponseFactory", "CreateWithTokenAndAccount", 7usize, |this| ((**(this as *const *const IWebTokenResponseFactory_Vtbl)).CreateWithTokenAndAccount)(this, token, webaccount, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn CreateWithTokenAccountAndError(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>, webaccount: *mut core::ffi::c_void, error: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebTokenResponseFactory", "CreateWithTokenAccountAndError", 8usize, |this| ((**(this as *const *const IWebTokenResponseFactory_Vtbl)).CreateWithTokenAccountAndError)(this, token, webaccount, error, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateWithToken,
+            #[cfg(feature = "Security_Credentials")]
+            CreateWithTokenAndAccount,
+            #[cfg(not(feature = "Security_Credentials"))]
+            CreateWithTokenAndAccount: 0,
+            #[cfg(feature = "Security_Credentials")]
+            CreateWithTokenAccountAndError,
+            #[cfg(not(feature = "Security_Credentials"))]
+            CreateWithTokenAccountAndError: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebTokenResponseFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
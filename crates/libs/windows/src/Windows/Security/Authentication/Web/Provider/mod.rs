@@ -1454,6 +1454,7 @@ impl WebAccountClientViewType {
 impl windows_core::TypeKind for WebAccountClientViewType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebAccountClientViewType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebAccountClientViewType").field(&self.0).finish()
@@ -1477,6 +1478,7 @@ impl WebAccountProviderOperationKind {
 impl windows_core::TypeKind for WebAccountProviderOperationKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebAccountProviderOperationKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebAccountProviderOperationKind").field(&self.0).finish()
@@ -1495,6 +1497,7 @@ impl WebAccountScope {
 impl windows_core::TypeKind for WebAccountScope {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebAccountScope {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebAccountScope").field(&self.0).finish()
@@ -1513,6 +1516,7 @@ impl WebAccountSelectionOptions {
 impl windows_core::TypeKind for WebAccountSelectionOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebAccountSelectionOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebAccountSelectionOptions").field(&self.0).finish()
@@ -1556,3 +1560,5 @@ impl windows_core::RuntimeType for WebAccountSelectionOptions {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
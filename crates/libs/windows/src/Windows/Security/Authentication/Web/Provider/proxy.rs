@@ -0,0 +1,669 @@
+impl windows_core::imp::ProxyVtable for IWebAccountClientView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ApplicationCallbackUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountClientView", "ApplicationCallbackUri", 6usize, |this| ((**(this as *const *const IWebAccountClientView_Vtbl)).ApplicationCallbackUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut WebAccountClientViewType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountClientView", "Type", 7usize, |this| ((**(this as *const *const IWebAccountClientView_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccountPairwiseId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountClientView", "AccountPairwiseId", 8usize, |this| ((**(this as *const *const IWebAccountClientView_Vtbl)).AccountPairwiseId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ApplicationCallbackUri, Type, AccountPairwiseId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountClientView as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAccountClientViewFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, viewtype: WebAccountClientViewType, applicationcallbackuri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountClientViewFactory", "Create", 6usize, |this| ((**(this as *const *const IWebAccountClientViewFactory_Vtbl)).Create)(this, viewtype, applicationcallbackuri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithPairwiseId(this: *mut core::ffi::c_void, viewtype: WebAccountClientViewType, applicationcallbackuri: *mut core::ffi::c_void, accountpairwiseid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountClientViewFactory", "CreateWithPairwiseId", 7usize, |this| ((**(this as *const *const IWebAccountClientViewFactory_Vtbl)).CreateWithPairwiseId)(this, viewtype, applicationcallbackuri, accountpairwiseid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithPairwiseId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountClientViewFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "Storage_Streams", feature = "Web_Http"))]
+impl windows_core::imp::ProxyVtable for IWebAccountManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+        unsafe extern "system" fn UpdateWebAccountPropertiesAsync(this: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, webaccountusername: core::mem::MaybeUninit<windows_core::HSTRING>, additionalproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics", "UpdateWebAccountPropertiesAsync", 6usize, |this| ((**(this as *const *const IWebAccountManagerStatics_Vtbl)).UpdateWebAccountPropertiesAsync)(this, webaccount, webaccountusername, additionalproperties, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+        unsafe extern "system" fn AddWebAccountAsync(this: *mut core::ffi::c_void, webaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, webaccountusername: core::mem::MaybeUninit<windows_core::HSTRING>, props: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics", "AddWebAccountAsync", 7usize, |this| ((**(this as *const *const IWebAccountManagerStatics_Vtbl)).AddWebAccountAsync)(this, webaccountid, webaccountusername, props, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn DeleteWebAccountAsync(this: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics", "DeleteWebAccountAsync", 8usize, |this| ((**(this as *const *const IWebAccountManagerStatics_Vtbl)).DeleteWebAccountAsync)(this, webaccount, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+        unsafe extern "system" fn FindAllProviderWebAccountsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics", "FindAllProviderWebAccountsAsync", 9usize, |this| ((**(this as *const *const IWebAccountManagerStatics_Vtbl)).FindAllProviderWebAccountsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Web_Http"))]
+        unsafe extern "system" fn PushCookiesAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, cookies: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics", "PushCookiesAsync", 10usize, |this| ((**(this as *const *const IWebAccountManagerStatics_Vtbl)).PushCookiesAsync)(this, uri, cookies, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetViewAsync(this: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, view: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics", "SetViewAsync", 11usize, |this| ((**(this as *const *const IWebAccountManagerStatics_Vtbl)).SetViewAsync)(this, webaccount, view, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn ClearViewAsync(this: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, applicationcallbackuri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics", "ClearViewAsync", 12usize, |this| ((**(this as *const *const IWebAccountManagerStatics_Vtbl)).ClearViewAsync)(this, webaccount, applicationcallbackuri, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+        unsafe extern "system" fn GetViewsAsync(this: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics", "GetViewsAsync", 13usize, |this| ((**(this as *const *const IWebAccountManagerStatics_Vtbl)).GetViewsAsync)(this, webaccount, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Security_Credentials", feature = "Storage_Streams"))]
+        unsafe extern "system" fn SetWebAccountPictureAsync(this: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, webaccountpicture: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics", "SetWebAccountPictureAsync", 14usize, |this| ((**(this as *const *const IWebAccountManagerStatics_Vtbl)).SetWebAccountPictureAsync)(this, webaccount, webaccountpicture, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn ClearWebAccountPictureAsync(this: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics", "ClearWebAccountPictureAsync", 15usize, |this| ((**(this as *const *const IWebAccountManagerStatics_Vtbl)).ClearWebAccountPictureAsync)(this, webaccount, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+            UpdateWebAccountPropertiesAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Credentials")))]
+            UpdateWebAccountPropertiesAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+            AddWebAccountAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Credentials")))]
+            AddWebAccountAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            DeleteWebAccountAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            DeleteWebAccountAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+            FindAllProviderWebAccountsAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Credentials")))]
+            FindAllProviderWebAccountsAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Web_Http"))]
+            PushCookiesAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Web_Http")))]
+            PushCookiesAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetViewAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetViewAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            ClearViewAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            ClearViewAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+            GetViewsAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Credentials")))]
+            GetViewsAsync: 0,
+            #[cfg(all(feature = "Security_Credentials", feature = "Storage_Streams"))]
+            SetWebAccountPictureAsync,
+            #[cfg(not(all(feature = "Security_Credentials", feature = "Storage_Streams")))]
+            SetWebAccountPictureAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            ClearWebAccountPictureAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            ClearWebAccountPictureAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAccountManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PullCookiesAsync(this: *mut core::ffi::c_void, uristring: core::mem::MaybeUninit<windows_core::HSTRING>, callerpfn: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics2", "PullCookiesAsync", 6usize, |this| ((**(this as *const *const IWebAccountManagerStatics2_Vtbl)).PullCookiesAsync)(this, uristring, callerpfn, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PullCookiesAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IWebAccountManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "System"))]
+        unsafe extern "system" fn FindAllProviderWebAccountsForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics3", "FindAllProviderWebAccountsForUserAsync", 6usize, |this| ((**(this as *const *const IWebAccountManagerStatics3_Vtbl)).FindAllProviderWebAccountsForUserAsync)(this, user, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "System"))]
+        unsafe extern "system" fn AddWebAccountForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, webaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, webaccountusername: core::mem::MaybeUninit<windows_core::HSTRING>, props: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics3", "AddWebAccountForUserAsync", 7usize, |this| ((**(this as *const *const IWebAccountManagerStatics3_Vtbl)).AddWebAccountForUserAsync)(this, user, webaccountid, webaccountusername, props, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "System"))]
+        unsafe extern "system" fn AddWebAccountWithScopeForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, webaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, webaccountusername: core::mem::MaybeUninit<windows_core::HSTRING>, props: *mut core::ffi::c_void, scope: WebAccountScope, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics3", "AddWebAccountWithScopeForUserAsync", 8usize, |this| ((**(this as *const *const IWebAccountManagerStatics3_Vtbl)).AddWebAccountWithScopeForUserAsync)(this, user, webaccountid, webaccountusername, props, scope, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "System"))]
+        unsafe extern "system" fn AddWebAccountWithScopeAndMapForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, webaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, webaccountusername: core::mem::MaybeUninit<windows_core::HSTRING>, props: *mut core::ffi::c_void, scope: WebAccountScope, peruserwebaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics3", "AddWebAccountWithScopeAndMapForUserAsync", 9usize, |this| ((**(this as *const *const IWebAccountManagerStatics3_Vtbl)).AddWebAccountWithScopeAndMapForUserAsync)(this, user, webaccountid, webaccountusername, props, scope, peruserwebaccountid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "System"))]
+            FindAllProviderWebAccountsForUserAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "System")))]
+            FindAllProviderWebAccountsForUserAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "System"))]
+            AddWebAccountForUserAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "System")))]
+            AddWebAccountForUserAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "System"))]
+            AddWebAccountWithScopeForUserAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "System")))]
+            AddWebAccountWithScopeForUserAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "System"))]
+            AddWebAccountWithScopeAndMapForUserAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "System")))]
+            AddWebAccountWithScopeAndMapForUserAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IWebAccountManagerStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InvalidateAppCacheForAllAccountsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics4", "InvalidateAppCacheForAllAccountsAsync", 6usize, |this| ((**(this as *const *const IWebAccountManagerStatics4_Vtbl)).InvalidateAppCacheForAllAccountsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn InvalidateAppCacheForAccountAsync(this: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountManagerStatics4", "InvalidateAppCacheForAccountAsync", 7usize, |this| ((**(this as *const *const IWebAccountManagerStatics4_Vtbl)).InvalidateAppCacheForAccountAsync)(this, webaccount, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InvalidateAppCacheForAllAccountsAsync,
+            #[cfg(feature = "Security_Credentials")]
+            InvalidateAppCacheForAccountAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            InvalidateAppCacheForAccountAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountManagerStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+impl windows_core::imp::ProxyVtable for IWebAccountMapManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+        unsafe extern "system" fn AddWebAccountWithScopeAndMapAsync(this: *mut core::ffi::c_void, webaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, webaccountusername: core::mem::MaybeUninit<windows_core::HSTRING>, props: *mut core::ffi::c_void, scope: WebAccountScope, peruserwebaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountMapManagerStatics", "AddWebAccountWithScopeAndMapAsync", 6usize, |this| ((**(this as *const *const IWebAccountMapManagerStatics_Vtbl)).AddWebAccountWithScopeAndMapAsync)(this, webaccountid, webaccountusername, props, scope, peruserwebaccountid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetPerAppToPerUserAccountAsync(this: *mut core::ffi::c_void, perappaccount: *mut core::ffi::c_void, peruserwebaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountMapManagerStatics", "SetPerAppToPerUserAccountAsync", 7usize, |this| ((**(this as *const *const IWebAccountMapManagerStatics_Vtbl)).SetPerAppToPerUserAccountAsync)(this, perappaccount, peruserwebaccountid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn GetPerUserFromPerAppAccountAsync(this: *mut core::ffi::c_void, perappaccount: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountMapManagerStatics", "GetPerUserFromPerAppAccountAsync", 8usize, |this| ((**(this as *const *const IWebAccountMapManagerStatics_Vtbl)).GetPerUserFromPerAppAccountAsync)(this, perappaccount, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn ClearPerUserFromPerAppAccountAsync(this: *mut core::ffi::c_void, perappaccount: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountMapManagerStatics", "ClearPerUserFromPerAppAccountAsync", 9usize, |this| ((**(this as *const *const IWebAccountMapManagerStatics_Vtbl)).ClearPerUserFromPerAppAccountAsync)(this, perappaccount, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+            AddWebAccountWithScopeAndMapAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Credentials")))]
+            AddWebAccountWithScopeAndMapAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetPerAppToPerUserAccountAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetPerAppToPerUserAccountAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            GetPerUserFromPerAppAccountAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            GetPerUserFromPerAppAccountAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            ClearPerUserFromPerAppAccountAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            ClearPerUserFromPerAppAccountAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountMapManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAccountProviderAddAccountOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportCompleted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderAddAccountOperation", "ReportCompleted", 6usize, |this| ((**(this as *const *const IWebAccountProviderAddAccountOperation_Vtbl)).ReportCompleted)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportCompleted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderAddAccountOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Authentication_Web_Core")]
+impl windows_core::imp::ProxyVtable for IWebAccountProviderBaseReportOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportCompleted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderBaseReportOperation", "ReportCompleted", 6usize, |this| ((**(this as *const *const IWebAccountProviderBaseReportOperation_Vtbl)).ReportCompleted)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Authentication_Web_Core")]
+        unsafe extern "system" fn ReportError(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderBaseReportOperation", "ReportError", 7usize, |this| ((**(this as *const *const IWebAccountProviderBaseReportOperation_Vtbl)).ReportError)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReportCompleted,
+            #[cfg(feature = "Security_Authentication_Web_Core")]
+            ReportError,
+            #[cfg(not(feature = "Security_Authentication_Web_Core"))]
+            ReportError: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderBaseReportOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IWebAccountProviderDeleteAccountOperation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn WebAccount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderDeleteAccountOperation", "WebAccount", 6usize, |this| ((**(this as *const *const IWebAccountProviderDeleteAccountOperation_Vtbl)).WebAccount)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            WebAccount,
+            #[cfg(not(feature = "Security_Credentials"))]
+            WebAccount: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderDeleteAccountOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IWebAccountProviderManageAccountOperation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn WebAccount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderManageAccountOperation", "WebAccount", 6usize, |this| ((**(this as *const *const IWebAccountProviderManageAccountOperation_Vtbl)).WebAccount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCompleted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderManageAccountOperation", "ReportCompleted", 7usize, |this| ((**(this as *const *const IWebAccountProviderManageAccountOperation_Vtbl)).ReportCompleted)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            WebAccount,
+            #[cfg(not(feature = "Security_Credentials"))]
+            WebAccount: 0,
+            ReportCompleted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderManageAccountOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAccountProviderOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut WebAccountProviderOperationKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderOperation", "Kind", 6usize, |this| ((**(this as *const *const IWebAccountProviderOperation_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Web_Http"))]
+impl windows_core::imp::ProxyVtable for IWebAccountProviderRetrieveCookiesOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Context(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderRetrieveCookiesOperation", "Context", 6usize, |this| ((**(this as *const *const IWebAccountProviderRetrieveCookiesOperation_Vtbl)).Context)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Web_Http"))]
+        unsafe extern "system" fn Cookies(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderRetrieveCookiesOperation", "Cookies", 7usize, |this| ((**(this as *const *const IWebAccountProviderRetrieveCookiesOperation_Vtbl)).Cookies)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUri(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderRetrieveCookiesOperation", "SetUri", 8usize, |this| ((**(this as *const *const IWebAccountProviderRetrieveCookiesOperation_Vtbl)).SetUri)(this, uri), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderRetrieveCookiesOperation", "Uri", 9usize, |this| ((**(this as *const *const IWebAccountProviderRetrieveCookiesOperation_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationCallbackUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderRetrieveCookiesOperation", "ApplicationCallbackUri", 10usize, |this| ((**(this as *const *const IWebAccountProviderRetrieveCookiesOperation_Vtbl)).ApplicationCallbackUri)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Context,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Web_Http"))]
+            Cookies,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Web_Http")))]
+            Cookies: 0,
+            SetUri,
+            Uri,
+            ApplicationCallbackUri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderRetrieveCookiesOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IWebAccountProviderSignOutAccountOperation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn WebAccount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderSignOutAccountOperation", "WebAccount", 6usize, |this| ((**(this as *const *const IWebAccountProviderSignOutAccountOperation_Vtbl)).WebAccount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationCallbackUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderSignOutAccountOperation", "ApplicationCallbackUri", 7usize, |this| ((**(this as *const *const IWebAccountProviderSignOutAccountOperation_Vtbl)).ApplicationCallbackUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderSignOutAccountOperation", "ClientId", 8usize, |this| ((**(this as *const *const IWebAccountProviderSignOutAccountOperation_Vtbl)).ClientId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            WebAccount,
+            #[cfg(not(feature = "Security_Credentials"))]
+            WebAccount: 0,
+            ApplicationCallbackUri,
+            ClientId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderSignOutAccountOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Authentication_Web_Core")]
+impl windows_core::imp::ProxyVtable for IWebAccountProviderSilentReportOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportUserInteractionRequired(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderSilentReportOperation", "ReportUserInteractionRequired", 6usize, |this| ((**(this as *const *const IWebAccountProviderSilentReportOperation_Vtbl)).ReportUserInteractionRequired)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Authentication_Web_Core")]
+        unsafe extern "system" fn ReportUserInteractionRequiredWithError(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderSilentReportOperation", "ReportUserInteractionRequiredWithError", 7usize, |this| ((**(this as *const *const IWebAccountProviderSilentReportOperation_Vtbl)).ReportUserInteractionRequiredWithError)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReportUserInteractionRequired,
+            #[cfg(feature = "Security_Authentication_Web_Core")]
+            ReportUserInteractionRequiredWithError,
+            #[cfg(not(feature = "Security_Authentication_Web_Core"))]
+            ReportUserInteractionRequiredWithError: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderSilentReportOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAccountProviderTokenObjects_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Operation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderTokenObjects", "Operation", 6usize, |this| ((**(this as *const *const IWebAccountProviderTokenObjects_Vtbl)).Operation)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Operation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderTokenObjects as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IWebAccountProviderTokenObjects2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderTokenObjects2", "User", 6usize, |this| ((**(this as *const *const IWebAccountProviderTokenObjects2_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderTokenObjects2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWebAccountProviderTokenOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProviderRequest(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderTokenOperation", "ProviderRequest", 6usize, |this| ((**(this as *const *const IWebAccountProviderTokenOperation_Vtbl)).ProviderRequest)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ProviderResponses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderTokenOperation", "ProviderResponses", 7usize, |this| ((**(this as *const *const IWebAccountProviderTokenOperation_Vtbl)).ProviderResponses)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCacheExpirationTime(this: *mut core::ffi::c_void, value: super::super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderTokenOperation", "SetCacheExpirationTime", 8usize, |this| ((**(this as *const *const IWebAccountProviderTokenOperation_Vtbl)).SetCacheExpirationTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CacheExpirationTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderTokenOperation", "CacheExpirationTime", 9usize, |this| ((**(this as *const *const IWebAccountProviderTokenOperation_Vtbl)).CacheExpirationTime)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProviderRequest,
+            #[cfg(feature = "Foundation_Collections")]
+            ProviderResponses,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ProviderResponses: 0,
+            SetCacheExpirationTime,
+            CacheExpirationTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderTokenOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Authentication_Web_Core")]
+impl windows_core::imp::ProxyVtable for IWebAccountProviderUIReportOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportUserCanceled(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderUIReportOperation", "ReportUserCanceled", 6usize, |this| ((**(this as *const *const IWebAccountProviderUIReportOperation_Vtbl)).ReportUserCanceled)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportUserCanceled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderUIReportOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+impl windows_core::imp::ProxyVtable for IWebAccountScopeManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+        unsafe extern "system" fn AddWebAccountWithScopeAsync(this: *mut core::ffi::c_void, webaccountid: core::mem::MaybeUninit<windows_core::HSTRING>, webaccountusername: core::mem::MaybeUninit<windows_core::HSTRING>, props: *mut core::ffi::c_void, scope: WebAccountScope, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountScopeManagerStatics", "AddWebAccountWithScopeAsync", 6usize, |this| ((**(this as *const *const IWebAccountScopeManagerStatics_Vtbl)).AddWebAccountWithScopeAsync)(this, webaccountid, webaccountusername, props, scope, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetScopeAsync(this: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, scope: WebAccountScope, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountScopeManagerStatics", "SetScopeAsync", 7usize, |this| ((**(this as *const *const IWebAccountScopeManagerStatics_Vtbl)).SetScopeAsync)(this, webaccount, scope, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn GetScope(this: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, result__: *mut WebAccountScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountScopeManagerStatics", "GetScope", 8usize, |this| ((**(this as *const *const IWebAccountScopeManagerStatics_Vtbl)).GetScope)(this, webaccount, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+            AddWebAccountWithScopeAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Credentials")))]
+            AddWebAccountWithScopeAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetScopeAsync,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetScopeAsync: 0,
+            #[cfg(feature = "Security_Credentials")]
+            GetScope,
+            #[cfg(not(feature = "Security_Credentials"))]
+            GetScope: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountScopeManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Authentication_Web_Core", feature = "Security_Credentials", feature = "Security_Cryptography_Core"))]
+impl windows_core::imp::ProxyVtable for IWebProviderTokenRequest_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Authentication_Web_Core")]
+        unsafe extern "system" fn ClientRequest(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProviderTokenRequest", "ClientRequest", 6usize, |this| ((**(this as *const *const IWebProviderTokenRequest_Vtbl)).ClientRequest)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+        unsafe extern "system" fn WebAccounts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProviderTokenRequest", "WebAccounts", 7usize, |this| ((**(this as *const *const IWebProviderTokenRequest_Vtbl)).WebAccounts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WebAccountSelectionOptions(this: *mut core::ffi::c_void, result__: *mut WebAccountSelectionOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProviderTokenRequest", "WebAccountSelectionOptions", 8usize, |this| ((**(this as *const *const IWebProviderTokenRequest_Vtbl)).WebAccountSelectionOptions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationCallbackUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProviderTokenRequest", "ApplicationCallbackUri", 9usize, |this| ((**(this as *const *const IWebProviderTokenRequest_Vtbl)).ApplicationCallbackUri)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Core")]
+        unsafe extern "system" fn GetApplicationTokenBindingKeyAsync(this: *mut core::ffi::c_void, keytype: super::TokenBindingKeyType, target: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProviderTokenRequest", "GetApplicationTokenBindingKeyAsync", 10usize, |this| ((**(this as *const *const IWebProviderTokenRequest_Vtbl)).GetApplicationTokenBindingKeyAsync)(this, keytype, target, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Authentication_Web_Core")]
+            ClientRequest,
+            #[cfg(not(feature = "Security_Authentication_Web_Core"))]
+            ClientRequest: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials"))]
+            WebAccounts,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Credentials")))]
+            WebAccounts: 0,
+            WebAccountSelectionOptions,
+            ApplicationCallbackUri,
+            #[cfg(feature = "Security_Cryptography_Core")]
+            GetApplicationTokenBindingKeyAsync,
+            #[cfg(not(feature = "Security_Cryptography_Core"))]
+            GetApplicationTokenBindingKeyAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebProviderTokenRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IWebProviderTokenRequest2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetApplicationTokenBindingKeyIdAsync(this: *mut core::ffi::c_void, keytype: super::TokenBindingKeyType, target: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProviderTokenRequest2", "GetApplicationTokenBindingKeyIdAsync", 6usize, |this| ((**(this as *const *const IWebProviderTokenRequest2_Vtbl)).GetApplicationTokenBindingKeyIdAsync)(this, keytype, target, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            GetApplicationTokenBindingKeyIdAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetApplicationTokenBindingKeyIdAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebProviderTokenRequest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebProviderTokenRequest3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ApplicationPackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProviderTokenRequest3", "ApplicationPackageFamilyName", 6usize, |this| ((**(this as *const *const IWebProviderTokenRequest3_Vtbl)).ApplicationPackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationProcessName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProviderTokenRequest3", "ApplicationProcessName", 7usize, |this| ((**(this as *const *const IWebProviderTokenRequest3_Vtbl)).ApplicationProcessName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckApplicationForCapabilityAsync(this: *mut core::ffi::c_void, capabilityname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProviderTokenRequest3", "CheckApplicationForCapabilityAsync", 8usize, |this| ((**(this as *const *const IWebProviderTokenRequest3_Vtbl)).CheckApplicationForCapabilityAsync)(this, capabilityname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ApplicationPackageFamilyName,
+            ApplicationProcessName,
+            CheckApplicationForCapabilityAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebProviderTokenRequest3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Authentication_Web_Core")]
+impl windows_core::imp::ProxyVtable for IWebProviderTokenResponse_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Authentication_Web_Core")]
+        unsafe extern "system" fn ClientResponse(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProviderTokenResponse", "ClientResponse", 6usize, |this| ((**(this as *const *const IWebProviderTokenResponse_Vtbl)).ClientResponse)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Authentication_Web_Core")]
+            ClientResponse,
+            #[cfg(not(feature = "Security_Authentication_Web_Core"))]
+            ClientResponse: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebProviderTokenResponse as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Authentication_Web_Core")]
+impl windows_core::imp::ProxyVtable for IWebProviderTokenResponseFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Authentication_Web_Core")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, webtokenresponse: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProviderTokenResponseFactory", "Create", 6usize, |this| ((**(this as *const *const IWebProviderTokenResponseFactory_Vtbl)).Create)(this, webtokenresponse, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Authentication_Web_Core")]
+            Create,
+            #[cfg(not(feature = "Security_Authentication_Web_Core"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebProviderTokenResponseFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
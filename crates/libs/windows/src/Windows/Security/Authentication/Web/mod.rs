@@ -169,6 +169,7 @@ impl TokenBindingKeyType {
 impl windows_core::TypeKind for TokenBindingKeyType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TokenBindingKeyType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TokenBindingKeyType").field(&self.0).finish()
@@ -190,6 +191,7 @@ impl WebAuthenticationOptions {
 impl windows_core::TypeKind for WebAuthenticationOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebAuthenticationOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebAuthenticationOptions").field(&self.0).finish()
@@ -242,6 +244,7 @@ impl WebAuthenticationStatus {
 impl windows_core::TypeKind for WebAuthenticationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebAuthenticationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebAuthenticationStatus").field(&self.0).finish()
@@ -250,3 +253,5 @@ impl core::fmt::Debug for WebAuthenticationStatus {
 impl windows_core::RuntimeType for WebAuthenticationStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Security.Authentication.Web.WebAuthenticationStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,77 @@
+impl windows_core::imp::ProxyVtable for IWebAuthenticationBrokerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AuthenticateWithCallbackUriAsync(this: *mut core::ffi::c_void, options: WebAuthenticationOptions, requesturi: *mut core::ffi::c_void, callbackuri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationBrokerStatics", "AuthenticateWithCallbackUriAsync", 6usize, |this| ((**(this as *const *const IWebAuthenticationBrokerStatics_Vtbl)).AuthenticateWithCallbackUriAsync)(this, options, requesturi, callbackuri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticateWithoutCallbackUriAsync(this: *mut core::ffi::c_void, options: WebAuthenticationOptions, requesturi: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationBrokerStatics", "AuthenticateWithoutCallbackUriAsync", 7usize, |this| ((**(this as *const *const IWebAuthenticationBrokerStatics_Vtbl)).AuthenticateWithoutCallbackUriAsync)(this, options, requesturi, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentApplicationCallbackUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationBrokerStatics", "GetCurrentApplicationCallbackUri", 8usize, |this| ((**(this as *const *const IWebAuthenticationBrokerStatics_Vtbl)).GetCurrentApplicationCallbackUri)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AuthenticateWithCallbackUriAsync,
+            AuthenticateWithoutCallbackUriAsync,
+            GetCurrentApplicationCallbackUri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAuthenticationBrokerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWebAuthenticationBrokerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AuthenticateAndContinue(this: *mut core::ffi::c_void, requesturi: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationBrokerStatics2", "AuthenticateAndContinue", 6usize, |this| ((**(this as *const *const IWebAuthenticationBrokerStatics2_Vtbl)).AuthenticateAndContinue)(this, requesturi), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticateWithCallbackUriAndContinue(this: *mut core::ffi::c_void, requesturi: *mut core::ffi::c_void, callbackuri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationBrokerStatics2", "AuthenticateWithCallbackUriAndContinue", 7usize, |this| ((**(this as *const *const IWebAuthenticationBrokerStatics2_Vtbl)).AuthenticateWithCallbackUriAndContinue)(this, requesturi, callbackuri), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AuthenticateWithCallbackUriContinuationDataAndOptionsAndContinue(this: *mut core::ffi::c_void, requesturi: *mut core::ffi::c_void, callbackuri: *mut core::ffi::c_void, continuationdata: *mut core::ffi::c_void, options: WebAuthenticationOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationBrokerStatics2", "AuthenticateWithCallbackUriContinuationDataAndOptionsAndContinue", 8usize, |this| ((**(this as *const *const IWebAuthenticationBrokerStatics2_Vtbl)).AuthenticateWithCallbackUriContinuationDataAndOptionsAndContinue)(this, requesturi, callbackuri, continuationdata, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticateSilentlyAsync(this: *mut core::ffi::c_void, requesturi: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationBrokerStatics2", "AuthenticateSilentlyAsync", 9usize, |this| ((**(this as *const *const IWebAuthenticationBrokerStatics2_Vtbl)).AuthenticateSilentlyAsync)(this, requesturi, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticateSilentlyWithOptionsAsync(this: *mut core::ffi::c_void, requesturi: *mut core::ffi::c_void, options: WebAuthenticationOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationBrokerStatics2", "AuthenticateSilentlyWithOptionsAsync", 10usize, |this| ((**(this as *const *const IWebAuthenticationBrokerStatics2_Vtbl)).AuthenticateSilentlyWithOptionsAsync)(this, requesturi, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AuthenticateAndContinue,
+            AuthenticateWithCallbackUriAndContinue,
+            #[cfg(feature = "Foundation_Collections")]
+            AuthenticateWithCallbackUriContinuationDataAndOptionsAndContinue,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AuthenticateWithCallbackUriContinuationDataAndOptionsAndContinue: 0,
+            AuthenticateSilentlyAsync,
+            AuthenticateSilentlyWithOptionsAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAuthenticationBrokerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAuthenticationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResponseData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationResult", "ResponseData", 6usize, |this| ((**(this as *const *const IWebAuthenticationResult_Vtbl)).ResponseData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseStatus(this: *mut core::ffi::c_void, result__: *mut WebAuthenticationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationResult", "ResponseStatus", 7usize, |this| ((**(this as *const *const IWebAuthenticationResult_Vtbl)).ResponseStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseErrorDetail(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationResult", "ResponseErrorDetail", 8usize, |this| ((**(this as *const *const IWebAuthenticationResult_Vtbl)).ResponseErrorDetail)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ResponseData, ResponseStatus, ResponseErrorDetail }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAuthenticationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
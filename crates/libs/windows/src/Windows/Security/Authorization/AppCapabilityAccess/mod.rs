@@ -198,6 +198,7 @@ impl AppCapabilityAccessStatus {
 impl windows_core::TypeKind for AppCapabilityAccessStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppCapabilityAccessStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppCapabilityAccessStatus").field(&self.0).finish()
@@ -206,3 +207,5 @@ impl core::fmt::Debug for AppCapabilityAccessStatus {
 impl windows_core::RuntimeType for AppCapabilityAccessStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Security.Authorization.AppCapabilityAccess.AppCapabilityAccessStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,102 @@
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IAppCapability_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CapabilityName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapability", "CapabilityName", 6usize, |this| ((**(this as *const *const IAppCapability_Vtbl)).CapabilityName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapability", "User", 7usize, |this| ((**(this as *const *const IAppCapability_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapability", "RequestAccessAsync", 8usize, |this| ((**(this as *const *const IAppCapability_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckAccess(this: *mut core::ffi::c_void, result__: *mut AppCapabilityAccessStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapability", "CheckAccess", 9usize, |this| ((**(this as *const *const IAppCapability_Vtbl)).CheckAccess)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccessChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapability", "AccessChanged", 10usize, |this| ((**(this as *const *const IAppCapability_Vtbl)).AccessChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAccessChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapability", "RemoveAccessChanged", 11usize, |this| ((**(this as *const *const IAppCapability_Vtbl)).RemoveAccessChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CapabilityName,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+            RequestAccessAsync,
+            CheckAccess,
+            AccessChanged,
+            RemoveAccessChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCapability as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCapability2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayMessage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapability2", "DisplayMessage", 6usize, |this| ((**(this as *const *const IAppCapability2_Vtbl)).DisplayMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayMessage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapability2", "SetDisplayMessage", 7usize, |this| ((**(this as *const *const IAppCapability2_Vtbl)).SetDisplayMessage)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayMessage, SetDisplayMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCapability2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppCapabilityAccessChangedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCapabilityAccessChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IAppCapabilityStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestAccessForCapabilitiesAsync(this: *mut core::ffi::c_void, capabilitynames: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapabilityStatics", "RequestAccessForCapabilitiesAsync", 6usize, |this| ((**(this as *const *const IAppCapabilityStatics_Vtbl)).RequestAccessForCapabilitiesAsync)(this, capabilitynames, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+        unsafe extern "system" fn RequestAccessForCapabilitiesForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, capabilitynames: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapabilityStatics", "RequestAccessForCapabilitiesForUserAsync", 7usize, |this| ((**(this as *const *const IAppCapabilityStatics_Vtbl)).RequestAccessForCapabilitiesForUserAsync)(this, user, capabilitynames, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, capabilityname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapabilityStatics", "Create", 8usize, |this| ((**(this as *const *const IAppCapabilityStatics_Vtbl)).Create)(this, capabilityname, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn CreateWithProcessIdForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, capabilityname: core::mem::MaybeUninit<windows_core::HSTRING>, pid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppCapabilityStatics", "CreateWithProcessIdForUser", 9usize, |this| ((**(this as *const *const IAppCapabilityStatics_Vtbl)).CreateWithProcessIdForUser)(this, user, capabilityname, pid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestAccessForCapabilitiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestAccessForCapabilitiesAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+            RequestAccessForCapabilitiesForUserAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "System")))]
+            RequestAccessForCapabilitiesForUserAsync: 0,
+            Create,
+            #[cfg(feature = "System")]
+            CreateWithProcessIdForUser,
+            #[cfg(not(feature = "System"))]
+            CreateWithProcessIdForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppCapabilityStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -345,6 +345,7 @@ impl AuthenticationProtocol {
 impl windows_core::TypeKind for AuthenticationProtocol {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AuthenticationProtocol {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AuthenticationProtocol").field(&self.0).finish()
@@ -364,6 +365,7 @@ impl CredentialSaveOption {
 impl windows_core::TypeKind for CredentialSaveOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CredentialSaveOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CredentialSaveOption").field(&self.0).finish()
@@ -387,6 +389,7 @@ impl UserConsentVerificationResult {
 impl windows_core::TypeKind for UserConsentVerificationResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserConsentVerificationResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserConsentVerificationResult").field(&self.0).finish()
@@ -408,6 +411,7 @@ impl UserConsentVerifierAvailability {
 impl windows_core::TypeKind for UserConsentVerifierAvailability {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserConsentVerifierAvailability {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserConsentVerifierAvailability").field(&self.0).finish()
@@ -416,3 +420,5 @@ impl core::fmt::Debug for UserConsentVerifierAvailability {
 impl windows_core::RuntimeType for UserConsentVerifierAvailability {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Security.Credentials.UI.UserConsentVerifierAvailability;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
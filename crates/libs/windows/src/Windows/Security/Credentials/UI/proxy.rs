@@ -0,0 +1,182 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ICredentialPickerOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCaption(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "SetCaption", 6usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).SetCaption)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Caption(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "Caption", 7usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).Caption)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "SetMessage", 8usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).SetMessage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "Message", 9usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetErrorCode(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "SetErrorCode", 10usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).SetErrorCode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "ErrorCode", 11usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "SetTargetName", 12usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).SetTargetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "TargetName", 13usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).TargetName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationProtocol(this: *mut core::ffi::c_void, value: AuthenticationProtocol) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "SetAuthenticationProtocol", 14usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).SetAuthenticationProtocol)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationProtocol(this: *mut core::ffi::c_void, result__: *mut AuthenticationProtocol) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "AuthenticationProtocol", 15usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).AuthenticationProtocol)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomAuthenticationProtocol(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "SetCustomAuthenticationProtocol", 16usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).SetCustomAuthenticationProtocol)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomAuthenticationProtocol(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "CustomAuthenticationProtocol", 17usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).CustomAuthenticationProtocol)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetPreviousCredential(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "SetPreviousCredential", 18usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).SetPreviousCredential)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn PreviousCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "PreviousCredential", 19usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).PreviousCredential)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlwaysDisplayDialog(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "SetAlwaysDisplayDialog", 20usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).SetAlwaysDisplayDialog)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlwaysDisplayDialog(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "AlwaysDisplayDialog", 21usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).AlwaysDisplayDialog)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCallerSavesCredential(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "SetCallerSavesCredential", 22usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).SetCallerSavesCredential)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallerSavesCredential(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "CallerSavesCredential", 23usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).CallerSavesCredential)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCredentialSaveOption(this: *mut core::ffi::c_void, value: CredentialSaveOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "SetCredentialSaveOption", 24usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).SetCredentialSaveOption)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CredentialSaveOption(this: *mut core::ffi::c_void, result__: *mut CredentialSaveOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerOptions", "CredentialSaveOption", 25usize, |this| ((**(this as *const *const ICredentialPickerOptions_Vtbl)).CredentialSaveOption)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetCaption,
+            Caption,
+            SetMessage,
+            Message,
+            SetErrorCode,
+            ErrorCode,
+            SetTargetName,
+            TargetName,
+            SetAuthenticationProtocol,
+            AuthenticationProtocol,
+            SetCustomAuthenticationProtocol,
+            CustomAuthenticationProtocol,
+            #[cfg(feature = "Storage_Streams")]
+            SetPreviousCredential,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetPreviousCredential: 0,
+            #[cfg(feature = "Storage_Streams")]
+            PreviousCredential,
+            #[cfg(not(feature = "Storage_Streams"))]
+            PreviousCredential: 0,
+            SetAlwaysDisplayDialog,
+            AlwaysDisplayDialog,
+            SetCallerSavesCredential,
+            CallerSavesCredential,
+            SetCredentialSaveOption,
+            CredentialSaveOption,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICredentialPickerOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ICredentialPickerResults_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerResults", "ErrorCode", 6usize, |this| ((**(this as *const *const ICredentialPickerResults_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CredentialSaveOption(this: *mut core::ffi::c_void, result__: *mut CredentialSaveOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerResults", "CredentialSaveOption", 7usize, |this| ((**(this as *const *const ICredentialPickerResults_Vtbl)).CredentialSaveOption)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CredentialSaved(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerResults", "CredentialSaved", 8usize, |this| ((**(this as *const *const ICredentialPickerResults_Vtbl)).CredentialSaved)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Credential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerResults", "Credential", 9usize, |this| ((**(this as *const *const ICredentialPickerResults_Vtbl)).Credential)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CredentialDomainName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerResults", "CredentialDomainName", 10usize, |this| ((**(this as *const *const ICredentialPickerResults_Vtbl)).CredentialDomainName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CredentialUserName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerResults", "CredentialUserName", 11usize, |this| ((**(this as *const *const ICredentialPickerResults_Vtbl)).CredentialUserName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CredentialPassword(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerResults", "CredentialPassword", 12usize, |this| ((**(this as *const *const ICredentialPickerResults_Vtbl)).CredentialPassword)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ErrorCode,
+            CredentialSaveOption,
+            CredentialSaved,
+            #[cfg(feature = "Storage_Streams")]
+            Credential,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Credential: 0,
+            CredentialDomainName,
+            CredentialUserName,
+            CredentialPassword,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICredentialPickerResults as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICredentialPickerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PickWithOptionsAsync(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerStatics", "PickWithOptionsAsync", 6usize, |this| ((**(this as *const *const ICredentialPickerStatics_Vtbl)).PickWithOptionsAsync)(this, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PickWithMessageAsync(this: *mut core::ffi::c_void, targetname: core::mem::MaybeUninit<windows_core::HSTRING>, message: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerStatics", "PickWithMessageAsync", 7usize, |this| ((**(this as *const *const ICredentialPickerStatics_Vtbl)).PickWithMessageAsync)(this, targetname, message, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PickWithCaptionAsync(this: *mut core::ffi::c_void, targetname: core::mem::MaybeUninit<windows_core::HSTRING>, message: core::mem::MaybeUninit<windows_core::HSTRING>, caption: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialPickerStatics", "PickWithCaptionAsync", 8usize, |this| ((**(this as *const *const ICredentialPickerStatics_Vtbl)).PickWithCaptionAsync)(this, targetname, message, caption, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PickWithOptionsAsync,
+            PickWithMessageAsync,
+            PickWithCaptionAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICredentialPickerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserConsentVerifierStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CheckAvailabilityAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserConsentVerifierStatics", "CheckAvailabilityAsync", 6usize, |this| ((**(this as *const *const IUserConsentVerifierStatics_Vtbl)).CheckAvailabilityAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestVerificationAsync(this: *mut core::ffi::c_void, message: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserConsentVerifierStatics", "RequestVerificationAsync", 7usize, |this| ((**(this as *const *const IUserConsentVerifierStatics_Vtbl)).RequestVerificationAsync)(this, message, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CheckAvailabilityAsync, RequestVerificationAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserConsentVerifierStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
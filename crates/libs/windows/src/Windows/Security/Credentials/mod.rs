@@ -920,6 +920,7 @@ impl KeyCredentialAttestationStatus {
 impl windows_core::TypeKind for KeyCredentialAttestationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KeyCredentialAttestationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KeyCredentialAttestationStatus").field(&self.0).finish()
@@ -938,6 +939,7 @@ impl KeyCredentialCreationOption {
 impl windows_core::TypeKind for KeyCredentialCreationOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KeyCredentialCreationOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KeyCredentialCreationOption").field(&self.0).finish()
@@ -961,6 +963,7 @@ impl KeyCredentialStatus {
 impl windows_core::TypeKind for KeyCredentialStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KeyCredentialStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KeyCredentialStatus").field(&self.0).finish()
@@ -981,6 +984,7 @@ impl WebAccountPictureSize {
 impl windows_core::TypeKind for WebAccountPictureSize {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebAccountPictureSize {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebAccountPictureSize").field(&self.0).finish()
@@ -1000,6 +1004,7 @@ impl WebAccountState {
 impl windows_core::TypeKind for WebAccountState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebAccountState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebAccountState").field(&self.0).finish()
@@ -1010,3 +1015,5 @@ impl windows_core::RuntimeType for WebAccountState {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
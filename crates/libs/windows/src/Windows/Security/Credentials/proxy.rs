@@ -0,0 +1,410 @@
+impl windows_core::imp::ProxyVtable for ICredentialFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreatePasswordCredential(this: *mut core::ffi::c_void, resource: core::mem::MaybeUninit<windows_core::HSTRING>, username: core::mem::MaybeUninit<windows_core::HSTRING>, password: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialFactory", "CreatePasswordCredential", 6usize, |this| ((**(this as *const *const ICredentialFactory_Vtbl)).CreatePasswordCredential)(this, resource, username, password, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreatePasswordCredential }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICredentialFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Security_Cryptography_Core", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IKeyCredential_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredential", "Name", 6usize, |this| ((**(this as *const *const IKeyCredential_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn RetrievePublicKeyWithDefaultBlobType(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredential", "RetrievePublicKeyWithDefaultBlobType", 7usize, |this| ((**(this as *const *const IKeyCredential_Vtbl)).RetrievePublicKeyWithDefaultBlobType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Security_Cryptography_Core", feature = "Storage_Streams"))]
+        unsafe extern "system" fn RetrievePublicKeyWithBlobType(this: *mut core::ffi::c_void, blobtype: super::Cryptography::Core::CryptographicPublicKeyBlobType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredential", "RetrievePublicKeyWithBlobType", 8usize, |this| ((**(this as *const *const IKeyCredential_Vtbl)).RetrievePublicKeyWithBlobType)(this, blobtype, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn RequestSignAsync(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredential", "RequestSignAsync", 9usize, |this| ((**(this as *const *const IKeyCredential_Vtbl)).RequestSignAsync)(this, data, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttestationAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredential", "GetAttestationAsync", 10usize, |this| ((**(this as *const *const IKeyCredential_Vtbl)).GetAttestationAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            #[cfg(feature = "Storage_Streams")]
+            RetrievePublicKeyWithDefaultBlobType,
+            #[cfg(not(feature = "Storage_Streams"))]
+            RetrievePublicKeyWithDefaultBlobType: 0,
+            #[cfg(all(feature = "Security_Cryptography_Core", feature = "Storage_Streams"))]
+            RetrievePublicKeyWithBlobType,
+            #[cfg(not(all(feature = "Security_Cryptography_Core", feature = "Storage_Streams")))]
+            RetrievePublicKeyWithBlobType: 0,
+            #[cfg(feature = "Storage_Streams")]
+            RequestSignAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            RequestSignAsync: 0,
+            GetAttestationAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyCredential as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IKeyCredentialAttestationResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CertificateChainBuffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredentialAttestationResult", "CertificateChainBuffer", 6usize, |this| ((**(this as *const *const IKeyCredentialAttestationResult_Vtbl)).CertificateChainBuffer)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AttestationBuffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredentialAttestationResult", "AttestationBuffer", 7usize, |this| ((**(this as *const *const IKeyCredentialAttestationResult_Vtbl)).AttestationBuffer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut KeyCredentialAttestationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredentialAttestationResult", "Status", 8usize, |this| ((**(this as *const *const IKeyCredentialAttestationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CertificateChainBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CertificateChainBuffer: 0,
+            #[cfg(feature = "Storage_Streams")]
+            AttestationBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AttestationBuffer: 0,
+            Status,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyCredentialAttestationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyCredentialManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupportedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredentialManagerStatics", "IsSupportedAsync", 6usize, |this| ((**(this as *const *const IKeyCredentialManagerStatics_Vtbl)).IsSupportedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenewAttestationAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredentialManagerStatics", "RenewAttestationAsync", 7usize, |this| ((**(this as *const *const IKeyCredentialManagerStatics_Vtbl)).RenewAttestationAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestCreateAsync(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, option: KeyCredentialCreationOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredentialManagerStatics", "RequestCreateAsync", 8usize, |this| ((**(this as *const *const IKeyCredentialManagerStatics_Vtbl)).RequestCreateAsync)(this, name, option, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenAsync(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredentialManagerStatics", "OpenAsync", 9usize, |this| ((**(this as *const *const IKeyCredentialManagerStatics_Vtbl)).OpenAsync)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredentialManagerStatics", "DeleteAsync", 10usize, |this| ((**(this as *const *const IKeyCredentialManagerStatics_Vtbl)).DeleteAsync)(this, name, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupportedAsync,
+            RenewAttestationAsync,
+            RequestCreateAsync,
+            OpenAsync,
+            DeleteAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyCredentialManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IKeyCredentialOperationResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Result(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredentialOperationResult", "Result", 6usize, |this| ((**(this as *const *const IKeyCredentialOperationResult_Vtbl)).Result)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut KeyCredentialStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredentialOperationResult", "Status", 7usize, |this| ((**(this as *const *const IKeyCredentialOperationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Result,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Result: 0,
+            Status,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyCredentialOperationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyCredentialRetrievalResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Credential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredentialRetrievalResult", "Credential", 6usize, |this| ((**(this as *const *const IKeyCredentialRetrievalResult_Vtbl)).Credential)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut KeyCredentialStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyCredentialRetrievalResult", "Status", 7usize, |this| ((**(this as *const *const IKeyCredentialRetrievalResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Credential, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyCredentialRetrievalResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPasswordCredential_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Resource(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPasswordCredential", "Resource", 6usize, |this| ((**(this as *const *const IPasswordCredential_Vtbl)).Resource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResource(this: *mut core::ffi::c_void, resource: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPasswordCredential", "SetResource", 7usize, |this| ((**(this as *const *const IPasswordCredential_Vtbl)).SetResource)(this, resource), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPasswordCredential", "UserName", 8usize, |this| ((**(this as *const *const IPasswordCredential_Vtbl)).UserName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserName(this: *mut core::ffi::c_void, username: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPasswordCredential", "SetUserName", 9usize, |this| ((**(this as *const *const IPasswordCredential_Vtbl)).SetUserName)(this, username), |result| Some(*result))
+        }
+        unsafe extern "system" fn Password(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPasswordCredential", "Password", 10usize, |this| ((**(this as *const *const IPasswordCredential_Vtbl)).Password)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPassword(this: *mut core::ffi::c_void, password: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPasswordCredential", "SetPassword", 11usize, |this| ((**(this as *const *const IPasswordCredential_Vtbl)).SetPassword)(this, password), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetrievePassword(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPasswordCredential", "RetrievePassword", 12usize, |this| ((**(this as *const *const IPasswordCredential_Vtbl)).RetrievePassword)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPasswordCredential", "Properties", 13usize, |this| ((**(this as *const *const IPasswordCredential_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Resource,
+            SetResource,
+            UserName,
+            SetUserName,
+            Password,
+            SetPassword,
+            RetrievePassword,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPasswordCredential as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPasswordVault_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, credential: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPasswordVault", "Add", 6usize, |this| ((**(this as *const *const IPasswordVault_Vtbl)).Add)(this, credential), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, credential: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPasswordVault", "Remove", 7usize, |this| ((**(this as *const *const IPasswordVault_Vtbl)).Remove)(this, credential), |result| Some(*result))
+        }
+        unsafe extern "system" fn Retrieve(this: *mut core::ffi::c_void, resource: core::mem::MaybeUninit<windows_core::HSTRING>, username: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPasswordVault", "Retrieve", 8usize, |this| ((**(this as *const *const IPasswordVault_Vtbl)).Retrieve)(this, resource, username, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllByResource(this: *mut core::ffi::c_void, resource: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPasswordVault", "FindAllByResource", 9usize, |this| ((**(this as *const *const IPasswordVault_Vtbl)).FindAllByResource)(this, resource, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllByUserName(this: *mut core::ffi::c_void, username: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPasswordVault", "FindAllByUserName", 10usize, |this| ((**(this as *const *const IPasswordVault_Vtbl)).FindAllByUserName)(this, username, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RetrieveAll(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPasswordVault", "RetrieveAll", 11usize, |this| ((**(this as *const *const IPasswordVault_Vtbl)).RetrieveAll)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Add,
+            Remove,
+            Retrieve,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllByResource,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllByResource: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllByUserName,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllByUserName: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RetrieveAll,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RetrieveAll: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPasswordVault as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAccount_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WebAccountProvider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccount", "WebAccountProvider", 6usize, |this| ((**(this as *const *const IWebAccount_Vtbl)).WebAccountProvider)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccount", "UserName", 7usize, |this| ((**(this as *const *const IWebAccount_Vtbl)).UserName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut WebAccountState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccount", "State", 8usize, |this| ((**(this as *const *const IWebAccount_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WebAccountProvider, UserName, State }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccount as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IWebAccount2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccount2", "Id", 6usize, |this| ((**(this as *const *const IWebAccount2_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccount2", "Properties", 7usize, |this| ((**(this as *const *const IWebAccount2_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetPictureAsync(this: *mut core::ffi::c_void, desizedsize: WebAccountPictureSize, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccount2", "GetPictureAsync", 8usize, |this| ((**(this as *const *const IWebAccount2_Vtbl)).GetPictureAsync)(this, desizedsize, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignOutAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccount2", "SignOutAsync", 9usize, |this| ((**(this as *const *const IWebAccount2_Vtbl)).SignOutAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignOutWithClientIdAsync(this: *mut core::ffi::c_void, clientid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccount2", "SignOutWithClientIdAsync", 10usize, |this| ((**(this as *const *const IWebAccount2_Vtbl)).SignOutWithClientIdAsync)(this, clientid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            #[cfg(feature = "Storage_Streams")]
+            GetPictureAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetPictureAsync: 0,
+            SignOutAsync,
+            SignOutWithClientIdAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccount2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAccountFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWebAccount(this: *mut core::ffi::c_void, webaccountprovider: *mut core::ffi::c_void, username: core::mem::MaybeUninit<windows_core::HSTRING>, state: WebAccountState, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountFactory", "CreateWebAccount", 6usize, |this| ((**(this as *const *const IWebAccountFactory_Vtbl)).CreateWebAccount)(this, webaccountprovider, username, state, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWebAccount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAccountProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProvider", "Id", 6usize, |this| ((**(this as *const *const IWebAccountProvider_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProvider", "DisplayName", 7usize, |this| ((**(this as *const *const IWebAccountProvider_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IconUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProvider", "IconUri", 8usize, |this| ((**(this as *const *const IWebAccountProvider_Vtbl)).IconUri)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            DisplayName,
+            #[cfg(feature = "deprecated")]
+            IconUri,
+            #[cfg(not(feature = "deprecated"))]
+            IconUri: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAccountProvider2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayPurpose(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProvider2", "DisplayPurpose", 6usize, |this| ((**(this as *const *const IWebAccountProvider2_Vtbl)).DisplayPurpose)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Authority(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProvider2", "Authority", 7usize, |this| ((**(this as *const *const IWebAccountProvider2_Vtbl)).Authority)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayPurpose, Authority }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProvider2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IWebAccountProvider3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProvider3", "User", 6usize, |this| ((**(this as *const *const IWebAccountProvider3_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProvider3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAccountProvider4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSystemProvider(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProvider4", "IsSystemProvider", 6usize, |this| ((**(this as *const *const IWebAccountProvider4_Vtbl)).IsSystemProvider)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSystemProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProvider4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAccountProviderFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWebAccountProvider(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, iconuri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderFactory", "CreateWebAccountProvider", 6usize, |this| ((**(this as *const *const IWebAccountProviderFactory_Vtbl)).CreateWebAccountProvider)(this, id, displayname, iconuri, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWebAccountProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -2525,6 +2525,7 @@ impl CertificateChainPolicy {
 impl windows_core::TypeKind for CertificateChainPolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CertificateChainPolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CertificateChainPolicy").field(&self.0).finish()
@@ -2555,6 +2556,7 @@ impl ChainValidationResult {
 impl windows_core::TypeKind for ChainValidationResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ChainValidationResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ChainValidationResult").field(&self.0).finish()
@@ -2576,6 +2578,7 @@ impl EnrollKeyUsages {
 impl windows_core::TypeKind for EnrollKeyUsages {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnrollKeyUsages {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnrollKeyUsages").field(&self.0).finish()
@@ -2627,6 +2630,7 @@ impl ExportOption {
 impl windows_core::TypeKind for ExportOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ExportOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ExportOption").field(&self.0).finish()
@@ -2645,6 +2649,7 @@ impl InstallOptions {
 impl windows_core::TypeKind for InstallOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InstallOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InstallOptions").field(&self.0).finish()
@@ -2698,6 +2703,7 @@ impl KeyProtectionLevel {
 impl windows_core::TypeKind for KeyProtectionLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KeyProtectionLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KeyProtectionLevel").field(&self.0).finish()
@@ -2717,6 +2723,7 @@ impl KeySize {
 impl windows_core::TypeKind for KeySize {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KeySize {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KeySize").field(&self.0).finish()
@@ -2738,6 +2745,7 @@ impl SignatureValidationResult {
 impl windows_core::TypeKind for SignatureValidationResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SignatureValidationResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SignatureValidationResult").field(&self.0).finish()
@@ -2746,3 +2754,5 @@ impl core::fmt::Debug for SignatureValidationResult {
 impl windows_core::RuntimeType for SignatureValidationResult {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Security.Cryptography.Certificates.SignatureValidationResult;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
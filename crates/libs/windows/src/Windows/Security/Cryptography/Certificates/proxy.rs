@@ -0,0 +1,1430 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ICertificate_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BuildChainAsync(this: *mut core::ffi::c_void, certificates: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate", "BuildChainAsync", 6usize, |this| ((**(this as *const *const ICertificate_Vtbl)).BuildChainAsync)(this, certificates, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BuildChainWithParametersAsync(this: *mut core::ffi::c_void, certificates: *mut core::ffi::c_void, parameters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate", "BuildChainWithParametersAsync", 7usize, |this| ((**(this as *const *const ICertificate_Vtbl)).BuildChainWithParametersAsync)(this, certificates, parameters, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SerialNumber(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate", "SerialNumber", 8usize, |this| ((**(this as *const *const ICertificate_Vtbl)).SerialNumber)(this, result_size__, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHashValue(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate", "GetHashValue", 9usize, |this| ((**(this as *const *const ICertificate_Vtbl)).GetHashValue)(this, result_size__, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHashValueWithAlgorithm(this: *mut core::ffi::c_void, hashalgorithmname: core::mem::MaybeUninit<windows_core::HSTRING>, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate", "GetHashValueWithAlgorithm", 10usize, |this| ((**(this as *const *const ICertificate_Vtbl)).GetHashValueWithAlgorithm)(this, hashalgorithmname, result_size__, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetCertificateBlob(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate", "GetCertificateBlob", 11usize, |this| ((**(this as *const *const ICertificate_Vtbl)).GetCertificateBlob)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate", "Subject", 12usize, |this| ((**(this as *const *const ICertificate_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Issuer(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate", "Issuer", 13usize, |this| ((**(this as *const *const ICertificate_Vtbl)).Issuer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasPrivateKey(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate", "HasPrivateKey", 14usize, |this| ((**(this as *const *const ICertificate_Vtbl)).HasPrivateKey)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStronglyProtected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate", "IsStronglyProtected", 15usize, |this| ((**(this as *const *const ICertificate_Vtbl)).IsStronglyProtected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidFrom(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate", "ValidFrom", 16usize, |this| ((**(this as *const *const ICertificate_Vtbl)).ValidFrom)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidTo(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate", "ValidTo", 17usize, |this| ((**(this as *const *const ICertificate_Vtbl)).ValidTo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EnhancedKeyUsages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate", "EnhancedKeyUsages", 18usize, |this| ((**(this as *const *const ICertificate_Vtbl)).EnhancedKeyUsages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFriendlyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate", "SetFriendlyName", 19usize, |this| ((**(this as *const *const ICertificate_Vtbl)).SetFriendlyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate", "FriendlyName", 20usize, |this| ((**(this as *const *const ICertificate_Vtbl)).FriendlyName)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            BuildChainAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BuildChainAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            BuildChainWithParametersAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BuildChainWithParametersAsync: 0,
+            SerialNumber,
+            GetHashValue,
+            GetHashValueWithAlgorithm,
+            #[cfg(feature = "Storage_Streams")]
+            GetCertificateBlob,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetCertificateBlob: 0,
+            Subject,
+            Issuer,
+            HasPrivateKey,
+            IsStronglyProtected,
+            ValidFrom,
+            ValidTo,
+            #[cfg(feature = "Foundation_Collections")]
+            EnhancedKeyUsages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EnhancedKeyUsages: 0,
+            SetFriendlyName,
+            FriendlyName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificate as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertificate2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSecurityDeviceBound(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate2", "IsSecurityDeviceBound", 6usize, |this| ((**(this as *const *const ICertificate2_Vtbl)).IsSecurityDeviceBound)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyUsages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate2", "KeyUsages", 7usize, |this| ((**(this as *const *const ICertificate2_Vtbl)).KeyUsages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyAlgorithmName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate2", "KeyAlgorithmName", 8usize, |this| ((**(this as *const *const ICertificate2_Vtbl)).KeyAlgorithmName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignatureAlgorithmName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate2", "SignatureAlgorithmName", 9usize, |this| ((**(this as *const *const ICertificate2_Vtbl)).SignatureAlgorithmName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignatureHashAlgorithmName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate2", "SignatureHashAlgorithmName", 10usize, |this| ((**(this as *const *const ICertificate2_Vtbl)).SignatureHashAlgorithmName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubjectAlternativeName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate2", "SubjectAlternativeName", 11usize, |this| ((**(this as *const *const ICertificate2_Vtbl)).SubjectAlternativeName)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSecurityDeviceBound,
+            KeyUsages,
+            KeyAlgorithmName,
+            SignatureAlgorithmName,
+            SignatureHashAlgorithmName,
+            SubjectAlternativeName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificate2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertificate3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPerUser(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate3", "IsPerUser", 6usize, |this| ((**(this as *const *const ICertificate3_Vtbl)).IsPerUser)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StoreName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate3", "StoreName", 7usize, |this| ((**(this as *const *const ICertificate3_Vtbl)).StoreName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyStorageProviderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificate3", "KeyStorageProviderName", 8usize, |this| ((**(this as *const *const ICertificate3_Vtbl)).KeyStorageProviderName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsPerUser, StoreName, KeyStorageProviderName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificate3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICertificateChain_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Validate(this: *mut core::ffi::c_void, result__: *mut ChainValidationResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateChain", "Validate", 6usize, |this| ((**(this as *const *const ICertificateChain_Vtbl)).Validate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateWithParameters(this: *mut core::ffi::c_void, parameter: *mut core::ffi::c_void, result__: *mut ChainValidationResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateChain", "ValidateWithParameters", 7usize, |this| ((**(this as *const *const ICertificateChain_Vtbl)).ValidateWithParameters)(this, parameter, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCertificates(this: *mut core::ffi::c_void, includeroot: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateChain", "GetCertificates", 8usize, |this| ((**(this as *const *const ICertificateChain_Vtbl)).GetCertificates)(this, includeroot, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Validate,
+            ValidateWithParameters,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCertificates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCertificates: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateChain as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertificateEnrollmentManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateRequestAsync(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentManagerStatics", "CreateRequestAsync", 6usize, |this| ((**(this as *const *const ICertificateEnrollmentManagerStatics_Vtbl)).CreateRequestAsync)(this, request, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallCertificateAsync(this: *mut core::ffi::c_void, certificate: core::mem::MaybeUninit<windows_core::HSTRING>, installoption: InstallOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentManagerStatics", "InstallCertificateAsync", 7usize, |this| ((**(this as *const *const ICertificateEnrollmentManagerStatics_Vtbl)).InstallCertificateAsync)(this, certificate, installoption, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportPfxDataAsync(this: *mut core::ffi::c_void, pfxdata: core::mem::MaybeUninit<windows_core::HSTRING>, password: core::mem::MaybeUninit<windows_core::HSTRING>, exportable: ExportOption, keyprotectionlevel: KeyProtectionLevel, installoption: InstallOptions, friendlyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentManagerStatics", "ImportPfxDataAsync", 8usize, |this| ((**(this as *const *const ICertificateEnrollmentManagerStatics_Vtbl)).ImportPfxDataAsync)(this, pfxdata, password, exportable, keyprotectionlevel, installoption, friendlyname, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateRequestAsync,
+            InstallCertificateAsync,
+            ImportPfxDataAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateEnrollmentManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertificateEnrollmentManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserCertificateEnrollmentManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentManagerStatics2", "UserCertificateEnrollmentManager", 6usize, |this| ((**(this as *const *const ICertificateEnrollmentManagerStatics2_Vtbl)).UserCertificateEnrollmentManager)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportPfxDataToKspAsync(this: *mut core::ffi::c_void, pfxdata: core::mem::MaybeUninit<windows_core::HSTRING>, password: core::mem::MaybeUninit<windows_core::HSTRING>, exportable: ExportOption, keyprotectionlevel: KeyProtectionLevel, installoption: InstallOptions, friendlyname: core::mem::MaybeUninit<windows_core::HSTRING>, keystorageprovider: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentManagerStatics2", "ImportPfxDataToKspAsync", 7usize, |this| ((**(this as *const *const ICertificateEnrollmentManagerStatics2_Vtbl)).ImportPfxDataToKspAsync)(this, pfxdata, password, exportable, keyprotectionlevel, installoption, friendlyname, keystorageprovider, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserCertificateEnrollmentManager, ImportPfxDataToKspAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateEnrollmentManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertificateEnrollmentManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ImportPfxDataToKspWithParametersAsync(this: *mut core::ffi::c_void, pfxdata: core::mem::MaybeUninit<windows_core::HSTRING>, password: core::mem::MaybeUninit<windows_core::HSTRING>, pfximportparameters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentManagerStatics3", "ImportPfxDataToKspWithParametersAsync", 6usize, |this| ((**(this as *const *const ICertificateEnrollmentManagerStatics3_Vtbl)).ImportPfxDataToKspWithParametersAsync)(this, pfxdata, password, pfximportparameters, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ImportPfxDataToKspWithParametersAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateEnrollmentManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertificateExtension_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ObjectId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateExtension", "ObjectId", 6usize, |this| ((**(this as *const *const ICertificateExtension_Vtbl)).ObjectId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetObjectId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateExtension", "SetObjectId", 7usize, |this| ((**(this as *const *const ICertificateExtension_Vtbl)).SetObjectId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCritical(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateExtension", "IsCritical", 8usize, |this| ((**(this as *const *const ICertificateExtension_Vtbl)).IsCritical)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCritical(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateExtension", "SetIsCritical", 9usize, |this| ((**(this as *const *const ICertificateExtension_Vtbl)).SetIsCritical)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncodeValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateExtension", "EncodeValue", 10usize, |this| ((**(this as *const *const ICertificateExtension_Vtbl)).EncodeValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateExtension", "Value", 11usize, |this| ((**(this as *const *const ICertificateExtension_Vtbl)).Value)(this, result_size__, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateExtension", "SetValue", 12usize, |this| ((**(this as *const *const ICertificateExtension_Vtbl)).SetValue)(this, value_array_size, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ObjectId,
+            SetObjectId,
+            IsCritical,
+            SetIsCritical,
+            EncodeValue,
+            Value,
+            SetValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateExtension as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ICertificateFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateCertificate(this: *mut core::ffi::c_void, certblob: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateFactory", "CreateCertificate", 6usize, |this| ((**(this as *const *const ICertificateFactory_Vtbl)).CreateCertificate)(this, certblob, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateCertificate,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateCertificate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertificateKeyUsages_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EncipherOnly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "EncipherOnly", 6usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).EncipherOnly)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEncipherOnly(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "SetEncipherOnly", 7usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).SetEncipherOnly)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CrlSign(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "CrlSign", 8usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).CrlSign)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCrlSign(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "SetCrlSign", 9usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).SetCrlSign)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyCertificateSign(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "KeyCertificateSign", 10usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).KeyCertificateSign)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeyCertificateSign(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "SetKeyCertificateSign", 11usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).SetKeyCertificateSign)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyAgreement(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "KeyAgreement", 12usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).KeyAgreement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeyAgreement(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "SetKeyAgreement", 13usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).SetKeyAgreement)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataEncipherment(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "DataEncipherment", 14usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).DataEncipherment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataEncipherment(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "SetDataEncipherment", 15usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).SetDataEncipherment)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyEncipherment(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "KeyEncipherment", 16usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).KeyEncipherment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeyEncipherment(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "SetKeyEncipherment", 17usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).SetKeyEncipherment)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NonRepudiation(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "NonRepudiation", 18usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).NonRepudiation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNonRepudiation(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "SetNonRepudiation", 19usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).SetNonRepudiation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DigitalSignature(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "DigitalSignature", 20usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).DigitalSignature)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDigitalSignature(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateKeyUsages", "SetDigitalSignature", 21usize, |this| ((**(this as *const *const ICertificateKeyUsages_Vtbl)).SetDigitalSignature)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EncipherOnly,
+            SetEncipherOnly,
+            CrlSign,
+            SetCrlSign,
+            KeyCertificateSign,
+            SetKeyCertificateSign,
+            KeyAgreement,
+            SetKeyAgreement,
+            DataEncipherment,
+            SetDataEncipherment,
+            KeyEncipherment,
+            SetKeyEncipherment,
+            NonRepudiation,
+            SetNonRepudiation,
+            DigitalSignature,
+            SetDigitalSignature,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateKeyUsages as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICertificateQuery_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EnhancedKeyUsages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateQuery", "EnhancedKeyUsages", 6usize, |this| ((**(this as *const *const ICertificateQuery_Vtbl)).EnhancedKeyUsages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IssuerName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateQuery", "IssuerName", 7usize, |this| ((**(this as *const *const ICertificateQuery_Vtbl)).IssuerName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIssuerName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateQuery", "SetIssuerName", 8usize, |this| ((**(this as *const *const ICertificateQuery_Vtbl)).SetIssuerName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateQuery", "FriendlyName", 9usize, |this| ((**(this as *const *const ICertificateQuery_Vtbl)).FriendlyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFriendlyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateQuery", "SetFriendlyName", 10usize, |this| ((**(this as *const *const ICertificateQuery_Vtbl)).SetFriendlyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Thumbprint(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateQuery", "Thumbprint", 11usize, |this| ((**(this as *const *const ICertificateQuery_Vtbl)).Thumbprint)(this, result_size__, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThumbprint(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateQuery", "SetThumbprint", 12usize, |this| ((**(this as *const *const ICertificateQuery_Vtbl)).SetThumbprint)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareOnly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateQuery", "HardwareOnly", 13usize, |this| ((**(this as *const *const ICertificateQuery_Vtbl)).HardwareOnly)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHardwareOnly(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateQuery", "SetHardwareOnly", 14usize, |this| ((**(this as *const *const ICertificateQuery_Vtbl)).SetHardwareOnly)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            EnhancedKeyUsages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EnhancedKeyUsages: 0,
+            IssuerName,
+            SetIssuerName,
+            FriendlyName,
+            SetFriendlyName,
+            Thumbprint,
+            SetThumbprint,
+            HardwareOnly,
+            SetHardwareOnly,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateQuery as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertificateQuery2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IncludeDuplicates(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateQuery2", "IncludeDuplicates", 6usize, |this| ((**(this as *const *const ICertificateQuery2_Vtbl)).IncludeDuplicates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeDuplicates(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateQuery2", "SetIncludeDuplicates", 7usize, |this| ((**(this as *const *const ICertificateQuery2_Vtbl)).SetIncludeDuplicates)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeExpiredCertificates(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateQuery2", "IncludeExpiredCertificates", 8usize, |this| ((**(this as *const *const ICertificateQuery2_Vtbl)).IncludeExpiredCertificates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeExpiredCertificates(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateQuery2", "SetIncludeExpiredCertificates", 9usize, |this| ((**(this as *const *const ICertificateQuery2_Vtbl)).SetIncludeExpiredCertificates)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StoreName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateQuery2", "StoreName", 10usize, |this| ((**(this as *const *const ICertificateQuery2_Vtbl)).StoreName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStoreName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateQuery2", "SetStoreName", 11usize, |this| ((**(this as *const *const ICertificateQuery2_Vtbl)).SetStoreName)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IncludeDuplicates,
+            SetIncludeDuplicates,
+            IncludeExpiredCertificates,
+            SetIncludeExpiredCertificates,
+            StoreName,
+            SetStoreName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateQuery2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertificateRequestProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "Subject", 6usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).Subject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubject(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "SetSubject", 7usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).SetSubject)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyAlgorithmName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "KeyAlgorithmName", 8usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).KeyAlgorithmName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeyAlgorithmName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "SetKeyAlgorithmName", 9usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).SetKeyAlgorithmName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeySize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "KeySize", 10usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).KeySize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeySize(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "SetKeySize", 11usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).SetKeySize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "FriendlyName", 12usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).FriendlyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFriendlyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "SetFriendlyName", 13usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).SetFriendlyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashAlgorithmName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "HashAlgorithmName", 14usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).HashAlgorithmName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHashAlgorithmName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "SetHashAlgorithmName", 15usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).SetHashAlgorithmName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Exportable(this: *mut core::ffi::c_void, result__: *mut ExportOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "Exportable", 16usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).Exportable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExportable(this: *mut core::ffi::c_void, value: ExportOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "SetExportable", 17usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).SetExportable)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyUsages(this: *mut core::ffi::c_void, result__: *mut EnrollKeyUsages) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "KeyUsages", 18usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).KeyUsages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeyUsages(this: *mut core::ffi::c_void, value: EnrollKeyUsages) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "SetKeyUsages", 19usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).SetKeyUsages)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyProtectionLevel(this: *mut core::ffi::c_void, result__: *mut KeyProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "KeyProtectionLevel", 20usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).KeyProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeyProtectionLevel(this: *mut core::ffi::c_void, value: KeyProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "SetKeyProtectionLevel", 21usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).SetKeyProtectionLevel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyStorageProviderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "KeyStorageProviderName", 22usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).KeyStorageProviderName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeyStorageProviderName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties", "SetKeyStorageProviderName", 23usize, |this| ((**(this as *const *const ICertificateRequestProperties_Vtbl)).SetKeyStorageProviderName)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Subject,
+            SetSubject,
+            KeyAlgorithmName,
+            SetKeyAlgorithmName,
+            KeySize,
+            SetKeySize,
+            FriendlyName,
+            SetFriendlyName,
+            HashAlgorithmName,
+            SetHashAlgorithmName,
+            Exportable,
+            SetExportable,
+            KeyUsages,
+            SetKeyUsages,
+            KeyProtectionLevel,
+            SetKeyProtectionLevel,
+            KeyStorageProviderName,
+            SetKeyStorageProviderName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateRequestProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertificateRequestProperties2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SmartcardReaderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties2", "SmartcardReaderName", 6usize, |this| ((**(this as *const *const ICertificateRequestProperties2_Vtbl)).SmartcardReaderName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSmartcardReaderName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties2", "SetSmartcardReaderName", 7usize, |this| ((**(this as *const *const ICertificateRequestProperties2_Vtbl)).SetSmartcardReaderName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SigningCertificate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties2", "SigningCertificate", 8usize, |this| ((**(this as *const *const ICertificateRequestProperties2_Vtbl)).SigningCertificate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSigningCertificate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties2", "SetSigningCertificate", 9usize, |this| ((**(this as *const *const ICertificateRequestProperties2_Vtbl)).SetSigningCertificate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttestationCredentialCertificate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties2", "AttestationCredentialCertificate", 10usize, |this| ((**(this as *const *const ICertificateRequestProperties2_Vtbl)).AttestationCredentialCertificate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttestationCredentialCertificate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties2", "SetAttestationCredentialCertificate", 11usize, |this| ((**(this as *const *const ICertificateRequestProperties2_Vtbl)).SetAttestationCredentialCertificate)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SmartcardReaderName,
+            SetSmartcardReaderName,
+            SigningCertificate,
+            SetSigningCertificate,
+            AttestationCredentialCertificate,
+            SetAttestationCredentialCertificate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateRequestProperties2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertificateRequestProperties3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurveName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties3", "CurveName", 6usize, |this| ((**(this as *const *const ICertificateRequestProperties3_Vtbl)).CurveName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurveName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties3", "SetCurveName", 7usize, |this| ((**(this as *const *const ICertificateRequestProperties3_Vtbl)).SetCurveName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurveParameters(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties3", "CurveParameters", 8usize, |this| ((**(this as *const *const ICertificateRequestProperties3_Vtbl)).CurveParameters)(this, result_size__, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurveParameters(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties3", "SetCurveParameters", 9usize, |this| ((**(this as *const *const ICertificateRequestProperties3_Vtbl)).SetCurveParameters)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainerNamePrefix(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties3", "ContainerNamePrefix", 10usize, |this| ((**(this as *const *const ICertificateRequestProperties3_Vtbl)).ContainerNamePrefix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContainerNamePrefix(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties3", "SetContainerNamePrefix", 11usize, |this| ((**(this as *const *const ICertificateRequestProperties3_Vtbl)).SetContainerNamePrefix)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainerName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties3", "ContainerName", 12usize, |this| ((**(this as *const *const ICertificateRequestProperties3_Vtbl)).ContainerName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContainerName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties3", "SetContainerName", 13usize, |this| ((**(this as *const *const ICertificateRequestProperties3_Vtbl)).SetContainerName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseExistingKey(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties3", "UseExistingKey", 14usize, |this| ((**(this as *const *const ICertificateRequestProperties3_Vtbl)).UseExistingKey)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseExistingKey(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties3", "SetUseExistingKey", 15usize, |this| ((**(this as *const *const ICertificateRequestProperties3_Vtbl)).SetUseExistingKey)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurveName,
+            SetCurveName,
+            CurveParameters,
+            SetCurveParameters,
+            ContainerNamePrefix,
+            SetContainerNamePrefix,
+            ContainerName,
+            SetContainerName,
+            UseExistingKey,
+            SetUseExistingKey,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateRequestProperties3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICertificateRequestProperties4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SuppressedDefaults(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties4", "SuppressedDefaults", 6usize, |this| ((**(this as *const *const ICertificateRequestProperties4_Vtbl)).SuppressedDefaults)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubjectAlternativeName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties4", "SubjectAlternativeName", 7usize, |this| ((**(this as *const *const ICertificateRequestProperties4_Vtbl)).SubjectAlternativeName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Extensions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateRequestProperties4", "Extensions", 8usize, |this| ((**(this as *const *const ICertificateRequestProperties4_Vtbl)).Extensions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SuppressedDefaults,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SuppressedDefaults: 0,
+            SubjectAlternativeName,
+            #[cfg(feature = "Foundation_Collections")]
+            Extensions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Extensions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateRequestProperties4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertificateStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, certificate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateStore", "Add", 6usize, |this| ((**(this as *const *const ICertificateStore_Vtbl)).Add)(this, certificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, certificate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateStore", "Delete", 7usize, |this| ((**(this as *const *const ICertificateStore_Vtbl)).Delete)(this, certificate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Add, Delete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertificateStore2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateStore2", "Name", 6usize, |this| ((**(this as *const *const ICertificateStore2_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateStore2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICertificateStoresStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateStoresStatics", "FindAllAsync", 6usize, |this| ((**(this as *const *const ICertificateStoresStatics_Vtbl)).FindAllAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllWithQueryAsync(this: *mut core::ffi::c_void, query: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateStoresStatics", "FindAllWithQueryAsync", 7usize, |this| ((**(this as *const *const ICertificateStoresStatics_Vtbl)).FindAllWithQueryAsync)(this, query, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrustedRootCertificationAuthorities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateStoresStatics", "TrustedRootCertificationAuthorities", 8usize, |this| ((**(this as *const *const ICertificateStoresStatics_Vtbl)).TrustedRootCertificationAuthorities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IntermediateCertificationAuthorities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateStoresStatics", "IntermediateCertificationAuthorities", 9usize, |this| ((**(this as *const *const ICertificateStoresStatics_Vtbl)).IntermediateCertificationAuthorities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStoreByName(this: *mut core::ffi::c_void, storename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateStoresStatics", "GetStoreByName", 10usize, |this| ((**(this as *const *const ICertificateStoresStatics_Vtbl)).GetStoreByName)(this, storename, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllWithQueryAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllWithQueryAsync: 0,
+            TrustedRootCertificationAuthorities,
+            IntermediateCertificationAuthorities,
+            GetStoreByName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateStoresStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertificateStoresStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUserStoreByName(this: *mut core::ffi::c_void, storename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateStoresStatics2", "GetUserStoreByName", 6usize, |this| ((**(this as *const *const ICertificateStoresStatics2_Vtbl)).GetUserStoreByName)(this, storename, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUserStoreByName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateStoresStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IChainBuildingParameters_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EnhancedKeyUsages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainBuildingParameters", "EnhancedKeyUsages", 6usize, |this| ((**(this as *const *const IChainBuildingParameters_Vtbl)).EnhancedKeyUsages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidationTimestamp(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainBuildingParameters", "ValidationTimestamp", 7usize, |this| ((**(this as *const *const IChainBuildingParameters_Vtbl)).ValidationTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValidationTimestamp(this: *mut core::ffi::c_void, value: super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainBuildingParameters", "SetValidationTimestamp", 8usize, |this| ((**(this as *const *const IChainBuildingParameters_Vtbl)).SetValidationTimestamp)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevocationCheckEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainBuildingParameters", "RevocationCheckEnabled", 9usize, |this| ((**(this as *const *const IChainBuildingParameters_Vtbl)).RevocationCheckEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRevocationCheckEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainBuildingParameters", "SetRevocationCheckEnabled", 10usize, |this| ((**(this as *const *const IChainBuildingParameters_Vtbl)).SetRevocationCheckEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkRetrievalEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainBuildingParameters", "NetworkRetrievalEnabled", 11usize, |this| ((**(this as *const *const IChainBuildingParameters_Vtbl)).NetworkRetrievalEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkRetrievalEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainBuildingParameters", "SetNetworkRetrievalEnabled", 12usize, |this| ((**(this as *const *const IChainBuildingParameters_Vtbl)).SetNetworkRetrievalEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthorityInformationAccessEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainBuildingParameters", "AuthorityInformationAccessEnabled", 13usize, |this| ((**(this as *const *const IChainBuildingParameters_Vtbl)).AuthorityInformationAccessEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthorityInformationAccessEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainBuildingParameters", "SetAuthorityInformationAccessEnabled", 14usize, |this| ((**(this as *const *const IChainBuildingParameters_Vtbl)).SetAuthorityInformationAccessEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentTimeValidationEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainBuildingParameters", "CurrentTimeValidationEnabled", 15usize, |this| ((**(this as *const *const IChainBuildingParameters_Vtbl)).CurrentTimeValidationEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentTimeValidationEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainBuildingParameters", "SetCurrentTimeValidationEnabled", 16usize, |this| ((**(this as *const *const IChainBuildingParameters_Vtbl)).SetCurrentTimeValidationEnabled)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ExclusiveTrustRoots(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainBuildingParameters", "ExclusiveTrustRoots", 17usize, |this| ((**(this as *const *const IChainBuildingParameters_Vtbl)).ExclusiveTrustRoots)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            EnhancedKeyUsages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EnhancedKeyUsages: 0,
+            ValidationTimestamp,
+            SetValidationTimestamp,
+            RevocationCheckEnabled,
+            SetRevocationCheckEnabled,
+            NetworkRetrievalEnabled,
+            SetNetworkRetrievalEnabled,
+            AuthorityInformationAccessEnabled,
+            SetAuthorityInformationAccessEnabled,
+            CurrentTimeValidationEnabled,
+            SetCurrentTimeValidationEnabled,
+            #[cfg(feature = "Foundation_Collections")]
+            ExclusiveTrustRoots,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ExclusiveTrustRoots: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChainBuildingParameters as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking")]
+impl windows_core::imp::ProxyVtable for IChainValidationParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CertificateChainPolicy(this: *mut core::ffi::c_void, result__: *mut CertificateChainPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainValidationParameters", "CertificateChainPolicy", 6usize, |this| ((**(this as *const *const IChainValidationParameters_Vtbl)).CertificateChainPolicy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCertificateChainPolicy(this: *mut core::ffi::c_void, value: CertificateChainPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainValidationParameters", "SetCertificateChainPolicy", 7usize, |this| ((**(this as *const *const IChainValidationParameters_Vtbl)).SetCertificateChainPolicy)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking")]
+        unsafe extern "system" fn ServerDnsName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainValidationParameters", "ServerDnsName", 8usize, |this| ((**(this as *const *const IChainValidationParameters_Vtbl)).ServerDnsName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking")]
+        unsafe extern "system" fn SetServerDnsName(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChainValidationParameters", "SetServerDnsName", 9usize, |this| ((**(this as *const *const IChainValidationParameters_Vtbl)).SetServerDnsName)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CertificateChainPolicy,
+            SetCertificateChainPolicy,
+            #[cfg(feature = "Networking")]
+            ServerDnsName,
+            #[cfg(not(feature = "Networking"))]
+            ServerDnsName: 0,
+            #[cfg(feature = "Networking")]
+            SetServerDnsName,
+            #[cfg(not(feature = "Networking"))]
+            SetServerDnsName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChainValidationParameters as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICmsAttachedSignature_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Certificates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsAttachedSignature", "Certificates", 6usize, |this| ((**(this as *const *const ICmsAttachedSignature_Vtbl)).Certificates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsAttachedSignature", "Content", 7usize, |this| ((**(this as *const *const ICmsAttachedSignature_Vtbl)).Content)(this, result_size__, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Signers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsAttachedSignature", "Signers", 8usize, |this| ((**(this as *const *const ICmsAttachedSignature_Vtbl)).Signers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerifySignature(this: *mut core::ffi::c_void, result__: *mut SignatureValidationResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsAttachedSignature", "VerifySignature", 9usize, |this| ((**(this as *const *const ICmsAttachedSignature_Vtbl)).VerifySignature)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Certificates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Certificates: 0,
+            Content,
+            #[cfg(feature = "Foundation_Collections")]
+            Signers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Signers: 0,
+            VerifySignature,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICmsAttachedSignature as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ICmsAttachedSignatureFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateCmsAttachedSignature(this: *mut core::ffi::c_void, inputblob: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsAttachedSignatureFactory", "CreateCmsAttachedSignature", 6usize, |this| ((**(this as *const *const ICmsAttachedSignatureFactory_Vtbl)).CreateCmsAttachedSignature)(this, inputblob, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateCmsAttachedSignature,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateCmsAttachedSignature: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICmsAttachedSignatureFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ICmsAttachedSignatureStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GenerateSignatureAsync(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, signers: *mut core::ffi::c_void, certificates: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsAttachedSignatureStatics", "GenerateSignatureAsync", 6usize, |this| ((**(this as *const *const ICmsAttachedSignatureStatics_Vtbl)).GenerateSignatureAsync)(this, data, signers, certificates, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            GenerateSignatureAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            GenerateSignatureAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICmsAttachedSignatureStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ICmsDetachedSignature_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Certificates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsDetachedSignature", "Certificates", 6usize, |this| ((**(this as *const *const ICmsDetachedSignature_Vtbl)).Certificates)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Signers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsDetachedSignature", "Signers", 7usize, |this| ((**(this as *const *const ICmsDetachedSignature_Vtbl)).Signers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn VerifySignatureAsync(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsDetachedSignature", "VerifySignatureAsync", 8usize, |this| ((**(this as *const *const ICmsDetachedSignature_Vtbl)).VerifySignatureAsync)(this, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Certificates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Certificates: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Signers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Signers: 0,
+            #[cfg(feature = "Storage_Streams")]
+            VerifySignatureAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            VerifySignatureAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICmsDetachedSignature as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ICmsDetachedSignatureFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateCmsDetachedSignature(this: *mut core::ffi::c_void, inputblob: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsDetachedSignatureFactory", "CreateCmsDetachedSignature", 6usize, |this| ((**(this as *const *const ICmsDetachedSignatureFactory_Vtbl)).CreateCmsDetachedSignature)(this, inputblob, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateCmsDetachedSignature,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateCmsDetachedSignature: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICmsDetachedSignatureFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ICmsDetachedSignatureStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GenerateSignatureAsync(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, signers: *mut core::ffi::c_void, certificates: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsDetachedSignatureStatics", "GenerateSignatureAsync", 6usize, |this| ((**(this as *const *const ICmsDetachedSignatureStatics_Vtbl)).GenerateSignatureAsync)(this, data, signers, certificates, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            GenerateSignatureAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            GenerateSignatureAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICmsDetachedSignatureStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICmsSignerInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Certificate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsSignerInfo", "Certificate", 6usize, |this| ((**(this as *const *const ICmsSignerInfo_Vtbl)).Certificate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCertificate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsSignerInfo", "SetCertificate", 7usize, |this| ((**(this as *const *const ICmsSignerInfo_Vtbl)).SetCertificate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashAlgorithmName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsSignerInfo", "HashAlgorithmName", 8usize, |this| ((**(this as *const *const ICmsSignerInfo_Vtbl)).HashAlgorithmName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHashAlgorithmName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsSignerInfo", "SetHashAlgorithmName", 9usize, |this| ((**(this as *const *const ICmsSignerInfo_Vtbl)).SetHashAlgorithmName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimestampInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsSignerInfo", "TimestampInfo", 10usize, |this| ((**(this as *const *const ICmsSignerInfo_Vtbl)).TimestampInfo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Certificate,
+            SetCertificate,
+            HashAlgorithmName,
+            SetHashAlgorithmName,
+            TimestampInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICmsSignerInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICmsTimestampInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SigningCertificate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsTimestampInfo", "SigningCertificate", 6usize, |this| ((**(this as *const *const ICmsTimestampInfo_Vtbl)).SigningCertificate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Certificates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsTimestampInfo", "Certificates", 7usize, |this| ((**(this as *const *const ICmsTimestampInfo_Vtbl)).Certificates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICmsTimestampInfo", "Timestamp", 8usize, |this| ((**(this as *const *const ICmsTimestampInfo_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SigningCertificate,
+            #[cfg(feature = "Foundation_Collections")]
+            Certificates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Certificates: 0,
+            Timestamp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICmsTimestampInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyAlgorithmNamesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Rsa(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyAlgorithmNamesStatics", "Rsa", 6usize, |this| ((**(this as *const *const IKeyAlgorithmNamesStatics_Vtbl)).Rsa)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Dsa(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyAlgorithmNamesStatics", "Dsa", 7usize, |this| ((**(this as *const *const IKeyAlgorithmNamesStatics_Vtbl)).Dsa)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ecdh256(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyAlgorithmNamesStatics", "Ecdh256", 8usize, |this| ((**(this as *const *const IKeyAlgorithmNamesStatics_Vtbl)).Ecdh256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ecdh384(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyAlgorithmNamesStatics", "Ecdh384", 9usize, |this| ((**(this as *const *const IKeyAlgorithmNamesStatics_Vtbl)).Ecdh384)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ecdh521(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyAlgorithmNamesStatics", "Ecdh521", 10usize, |this| ((**(this as *const *const IKeyAlgorithmNamesStatics_Vtbl)).Ecdh521)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ecdsa256(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyAlgorithmNamesStatics", "Ecdsa256", 11usize, |this| ((**(this as *const *const IKeyAlgorithmNamesStatics_Vtbl)).Ecdsa256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ecdsa384(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyAlgorithmNamesStatics", "Ecdsa384", 12usize, |this| ((**(this as *const *const IKeyAlgorithmNamesStatics_Vtbl)).Ecdsa384)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ecdsa521(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyAlgorithmNamesStatics", "Ecdsa521", 13usize, |this| ((**(this as *const *const IKeyAlgorithmNamesStatics_Vtbl)).Ecdsa521)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Rsa,
+            Dsa,
+            Ecdh256,
+            Ecdh384,
+            Ecdh521,
+            Ecdsa256,
+            Ecdsa384,
+            Ecdsa521,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyAlgorithmNamesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyAlgorithmNamesStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Ecdsa(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyAlgorithmNamesStatics2", "Ecdsa", 6usize, |this| ((**(this as *const *const IKeyAlgorithmNamesStatics2_Vtbl)).Ecdsa)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ecdh(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyAlgorithmNamesStatics2", "Ecdh", 7usize, |this| ((**(this as *const *const IKeyAlgorithmNamesStatics2_Vtbl)).Ecdh)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Ecdsa, Ecdh }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyAlgorithmNamesStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyAttestationHelperStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DecryptTpmAttestationCredentialAsync(this: *mut core::ffi::c_void, credential: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyAttestationHelperStatics", "DecryptTpmAttestationCredentialAsync", 6usize, |this| ((**(this as *const *const IKeyAttestationHelperStatics_Vtbl)).DecryptTpmAttestationCredentialAsync)(this, credential, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTpmAttestationCredentialId(this: *mut core::ffi::c_void, credential: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyAttestationHelperStatics", "GetTpmAttestationCredentialId", 7usize, |this| ((**(this as *const *const IKeyAttestationHelperStatics_Vtbl)).GetTpmAttestationCredentialId)(this, credential, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DecryptTpmAttestationCredentialAsync,
+            GetTpmAttestationCredentialId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyAttestationHelperStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyAttestationHelperStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DecryptTpmAttestationCredentialWithContainerNameAsync(this: *mut core::ffi::c_void, credential: core::mem::MaybeUninit<windows_core::HSTRING>, containername: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyAttestationHelperStatics2", "DecryptTpmAttestationCredentialWithContainerNameAsync", 6usize, |this| ((**(this as *const *const IKeyAttestationHelperStatics2_Vtbl)).DecryptTpmAttestationCredentialWithContainerNameAsync)(this, credential, containername, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DecryptTpmAttestationCredentialWithContainerNameAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyAttestationHelperStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyStorageProviderNamesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SoftwareKeyStorageProvider(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyStorageProviderNamesStatics", "SoftwareKeyStorageProvider", 6usize, |this| ((**(this as *const *const IKeyStorageProviderNamesStatics_Vtbl)).SoftwareKeyStorageProvider)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SmartcardKeyStorageProvider(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyStorageProviderNamesStatics", "SmartcardKeyStorageProvider", 7usize, |this| ((**(this as *const *const IKeyStorageProviderNamesStatics_Vtbl)).SmartcardKeyStorageProvider)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlatformKeyStorageProvider(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyStorageProviderNamesStatics", "PlatformKeyStorageProvider", 8usize, |this| ((**(this as *const *const IKeyStorageProviderNamesStatics_Vtbl)).PlatformKeyStorageProvider)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SoftwareKeyStorageProvider,
+            SmartcardKeyStorageProvider,
+            PlatformKeyStorageProvider,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyStorageProviderNamesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyStorageProviderNamesStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PassportKeyStorageProvider(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyStorageProviderNamesStatics2", "PassportKeyStorageProvider", 6usize, |this| ((**(this as *const *const IKeyStorageProviderNamesStatics2_Vtbl)).PassportKeyStorageProvider)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PassportKeyStorageProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyStorageProviderNamesStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPfxImportParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Exportable(this: *mut core::ffi::c_void, result__: *mut ExportOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPfxImportParameters", "Exportable", 6usize, |this| ((**(this as *const *const IPfxImportParameters_Vtbl)).Exportable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExportable(this: *mut core::ffi::c_void, value: ExportOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPfxImportParameters", "SetExportable", 7usize, |this| ((**(this as *const *const IPfxImportParameters_Vtbl)).SetExportable)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyProtectionLevel(this: *mut core::ffi::c_void, result__: *mut KeyProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPfxImportParameters", "KeyProtectionLevel", 8usize, |this| ((**(this as *const *const IPfxImportParameters_Vtbl)).KeyProtectionLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeyProtectionLevel(this: *mut core::ffi::c_void, value: KeyProtectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPfxImportParameters", "SetKeyProtectionLevel", 9usize, |this| ((**(this as *const *const IPfxImportParameters_Vtbl)).SetKeyProtectionLevel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallOptions(this: *mut core::ffi::c_void, result__: *mut InstallOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPfxImportParameters", "InstallOptions", 10usize, |this| ((**(this as *const *const IPfxImportParameters_Vtbl)).InstallOptions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInstallOptions(this: *mut core::ffi::c_void, value: InstallOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPfxImportParameters", "SetInstallOptions", 11usize, |this| ((**(this as *const *const IPfxImportParameters_Vtbl)).SetInstallOptions)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPfxImportParameters", "FriendlyName", 12usize, |this| ((**(this as *const *const IPfxImportParameters_Vtbl)).FriendlyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFriendlyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPfxImportParameters", "SetFriendlyName", 13usize, |this| ((**(this as *const *const IPfxImportParameters_Vtbl)).SetFriendlyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyStorageProviderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPfxImportParameters", "KeyStorageProviderName", 14usize, |this| ((**(this as *const *const IPfxImportParameters_Vtbl)).KeyStorageProviderName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeyStorageProviderName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPfxImportParameters", "SetKeyStorageProviderName", 15usize, |this| ((**(this as *const *const IPfxImportParameters_Vtbl)).SetKeyStorageProviderName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainerNamePrefix(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPfxImportParameters", "ContainerNamePrefix", 16usize, |this| ((**(this as *const *const IPfxImportParameters_Vtbl)).ContainerNamePrefix)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContainerNamePrefix(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPfxImportParameters", "SetContainerNamePrefix", 17usize, |this| ((**(this as *const *const IPfxImportParameters_Vtbl)).SetContainerNamePrefix)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReaderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPfxImportParameters", "ReaderName", 18usize, |this| ((**(this as *const *const IPfxImportParameters_Vtbl)).ReaderName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReaderName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPfxImportParameters", "SetReaderName", 19usize, |this| ((**(this as *const *const IPfxImportParameters_Vtbl)).SetReaderName)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Exportable,
+            SetExportable,
+            KeyProtectionLevel,
+            SetKeyProtectionLevel,
+            InstallOptions,
+            SetInstallOptions,
+            FriendlyName,
+            SetFriendlyName,
+            KeyStorageProviderName,
+            SetKeyStorageProviderName,
+            ContainerNamePrefix,
+            SetContainerNamePrefix,
+            ReaderName,
+            SetReaderName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPfxImportParameters as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStandardCertificateStoreNamesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Personal(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardCertificateStoreNamesStatics", "Personal", 6usize, |this| ((**(this as *const *const IStandardCertificateStoreNamesStatics_Vtbl)).Personal)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrustedRootCertificationAuthorities(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardCertificateStoreNamesStatics", "TrustedRootCertificationAuthorities", 7usize, |this| ((**(this as *const *const IStandardCertificateStoreNamesStatics_Vtbl)).TrustedRootCertificationAuthorities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IntermediateCertificationAuthorities(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStandardCertificateStoreNamesStatics", "IntermediateCertificationAuthorities", 8usize, |this| ((**(this as *const *const IStandardCertificateStoreNamesStatics_Vtbl)).IntermediateCertificationAuthorities)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Personal,
+            TrustedRootCertificationAuthorities,
+            IntermediateCertificationAuthorities,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStandardCertificateStoreNamesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISubjectAlternativeNameInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EmailName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubjectAlternativeNameInfo", "EmailName", 6usize, |this| ((**(this as *const *const ISubjectAlternativeNameInfo_Vtbl)).EmailName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn IPAddress(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubjectAlternativeNameInfo", "IPAddress", 7usize, |this| ((**(this as *const *const ISubjectAlternativeNameInfo_Vtbl)).IPAddress)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Url(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubjectAlternativeNameInfo", "Url", 8usize, |this| ((**(this as *const *const ISubjectAlternativeNameInfo_Vtbl)).Url)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DnsName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubjectAlternativeNameInfo", "DnsName", 9usize, |this| ((**(this as *const *const ISubjectAlternativeNameInfo_Vtbl)).DnsName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DistinguishedName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubjectAlternativeNameInfo", "DistinguishedName", 10usize, |this| ((**(this as *const *const ISubjectAlternativeNameInfo_Vtbl)).DistinguishedName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PrincipalName(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubjectAlternativeNameInfo", "PrincipalName", 11usize, |this| ((**(this as *const *const ISubjectAlternativeNameInfo_Vtbl)).PrincipalName)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            EmailName,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EmailName: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            IPAddress,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            IPAddress: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Url,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Url: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            DnsName,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DnsName: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            DistinguishedName,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DistinguishedName: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            PrincipalName,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PrincipalName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISubjectAlternativeNameInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISubjectAlternativeNameInfo2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn EmailNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubjectAlternativeNameInfo2", "EmailNames", 6usize, |this| ((**(this as *const *const ISubjectAlternativeNameInfo2_Vtbl)).EmailNames)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn IPAddresses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubjectAlternativeNameInfo2", "IPAddresses", 7usize, |this| ((**(this as *const *const ISubjectAlternativeNameInfo2_Vtbl)).IPAddresses)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Urls(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubjectAlternativeNameInfo2", "Urls", 8usize, |this| ((**(this as *const *const ISubjectAlternativeNameInfo2_Vtbl)).Urls)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DnsNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubjectAlternativeNameInfo2", "DnsNames", 9usize, |this| ((**(this as *const *const ISubjectAlternativeNameInfo2_Vtbl)).DnsNames)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DistinguishedNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubjectAlternativeNameInfo2", "DistinguishedNames", 10usize, |this| ((**(this as *const *const ISubjectAlternativeNameInfo2_Vtbl)).DistinguishedNames)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PrincipalNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubjectAlternativeNameInfo2", "PrincipalNames", 11usize, |this| ((**(this as *const *const ISubjectAlternativeNameInfo2_Vtbl)).PrincipalNames)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Extension(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubjectAlternativeNameInfo2", "Extension", 12usize, |this| ((**(this as *const *const ISubjectAlternativeNameInfo2_Vtbl)).Extension)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            EmailNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            EmailNames: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            IPAddresses,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            IPAddresses: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Urls,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Urls: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            DnsNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DnsNames: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            DistinguishedNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DistinguishedNames: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            PrincipalNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PrincipalNames: 0,
+            Extension,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISubjectAlternativeNameInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserCertificateEnrollmentManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateRequestAsync(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserCertificateEnrollmentManager", "CreateRequestAsync", 6usize, |this| ((**(this as *const *const IUserCertificateEnrollmentManager_Vtbl)).CreateRequestAsync)(this, request, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallCertificateAsync(this: *mut core::ffi::c_void, certificate: core::mem::MaybeUninit<windows_core::HSTRING>, installoption: InstallOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserCertificateEnrollmentManager", "InstallCertificateAsync", 7usize, |this| ((**(this as *const *const IUserCertificateEnrollmentManager_Vtbl)).InstallCertificateAsync)(this, certificate, installoption, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportPfxDataAsync(this: *mut core::ffi::c_void, pfxdata: core::mem::MaybeUninit<windows_core::HSTRING>, password: core::mem::MaybeUninit<windows_core::HSTRING>, exportable: ExportOption, keyprotectionlevel: KeyProtectionLevel, installoption: InstallOptions, friendlyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserCertificateEnrollmentManager", "ImportPfxDataAsync", 8usize, |this| ((**(this as *const *const IUserCertificateEnrollmentManager_Vtbl)).ImportPfxDataAsync)(this, pfxdata, password, exportable, keyprotectionlevel, installoption, friendlyname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportPfxDataToKspAsync(this: *mut core::ffi::c_void, pfxdata: core::mem::MaybeUninit<windows_core::HSTRING>, password: core::mem::MaybeUninit<windows_core::HSTRING>, exportable: ExportOption, keyprotectionlevel: KeyProtectionLevel, installoption: InstallOptions, friendlyname: core::mem::MaybeUninit<windows_core::HSTRING>, keystorageprovider: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserCertificateEnrollmentManager", "ImportPfxDataToKspAsync", 9usize, |this| ((**(this as *const *const IUserCertificateEnrollmentManager_Vtbl)).ImportPfxDataToKspAsync)(this, pfxdata, password, exportable, keyprotectionlevel, installoption, friendlyname, keystorageprovider, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateRequestAsync,
+            InstallCertificateAsync,
+            ImportPfxDataAsync,
+            ImportPfxDataToKspAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserCertificateEnrollmentManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserCertificateEnrollmentManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ImportPfxDataToKspWithParametersAsync(this: *mut core::ffi::c_void, pfxdata: core::mem::MaybeUninit<windows_core::HSTRING>, password: core::mem::MaybeUninit<windows_core::HSTRING>, pfximportparameters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserCertificateEnrollmentManager2", "ImportPfxDataToKspWithParametersAsync", 6usize, |this| ((**(this as *const *const IUserCertificateEnrollmentManager2_Vtbl)).ImportPfxDataToKspWithParametersAsync)(this, pfxdata, password, pfximportparameters, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ImportPfxDataToKspWithParametersAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserCertificateEnrollmentManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserCertificateStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAddAsync(this: *mut core::ffi::c_void, certificate: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserCertificateStore", "RequestAddAsync", 6usize, |this| ((**(this as *const *const IUserCertificateStore_Vtbl)).RequestAddAsync)(this, certificate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestDeleteAsync(this: *mut core::ffi::c_void, certificate: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserCertificateStore", "RequestDeleteAsync", 7usize, |this| ((**(this as *const *const IUserCertificateStore_Vtbl)).RequestDeleteAsync)(this, certificate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserCertificateStore", "Name", 8usize, |this| ((**(this as *const *const IUserCertificateStore_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestAddAsync, RequestDeleteAsync, Name }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserCertificateStore as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
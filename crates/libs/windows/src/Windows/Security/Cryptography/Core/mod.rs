@@ -2058,6 +2058,7 @@ impl Capi1KdfTargetAlgorithm {
 impl windows_core::TypeKind for Capi1KdfTargetAlgorithm {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Capi1KdfTargetAlgorithm {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Capi1KdfTargetAlgorithm").field(&self.0).finish()
@@ -2078,6 +2079,7 @@ impl CryptographicPadding {
 impl windows_core::TypeKind for CryptographicPadding {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CryptographicPadding {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CryptographicPadding").field(&self.0).finish()
@@ -2099,6 +2101,7 @@ impl CryptographicPrivateKeyBlobType {
 impl windows_core::TypeKind for CryptographicPrivateKeyBlobType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CryptographicPrivateKeyBlobType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CryptographicPrivateKeyBlobType").field(&self.0).finish()
@@ -2120,6 +2123,7 @@ impl CryptographicPublicKeyBlobType {
 impl windows_core::TypeKind for CryptographicPublicKeyBlobType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CryptographicPublicKeyBlobType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CryptographicPublicKeyBlobType").field(&self.0).finish()
@@ -2128,3 +2132,5 @@ impl core::fmt::Debug for CryptographicPublicKeyBlobType {
 impl windows_core::RuntimeType for CryptographicPublicKeyBlobType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Security.Cryptography.Core.CryptographicPublicKeyBlobType;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1155 @@
+impl windows_core::imp::ProxyVtable for IAsymmetricAlgorithmNamesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RsaPkcs1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "RsaPkcs1", 6usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).RsaPkcs1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsaOaepSha1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "RsaOaepSha1", 7usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).RsaOaepSha1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsaOaepSha256(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "RsaOaepSha256", 8usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).RsaOaepSha256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsaOaepSha384(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "RsaOaepSha384", 9usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).RsaOaepSha384)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsaOaepSha512(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "RsaOaepSha512", 10usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).RsaOaepSha512)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EcdsaP256Sha256(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "EcdsaP256Sha256", 11usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).EcdsaP256Sha256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EcdsaP384Sha384(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "EcdsaP384Sha384", 12usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).EcdsaP384Sha384)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EcdsaP521Sha512(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "EcdsaP521Sha512", 13usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).EcdsaP521Sha512)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DsaSha1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "DsaSha1", 14usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).DsaSha1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DsaSha256(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "DsaSha256", 15usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).DsaSha256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsaSignPkcs1Sha1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "RsaSignPkcs1Sha1", 16usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).RsaSignPkcs1Sha1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsaSignPkcs1Sha256(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "RsaSignPkcs1Sha256", 17usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).RsaSignPkcs1Sha256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsaSignPkcs1Sha384(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "RsaSignPkcs1Sha384", 18usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).RsaSignPkcs1Sha384)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsaSignPkcs1Sha512(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "RsaSignPkcs1Sha512", 19usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).RsaSignPkcs1Sha512)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsaSignPssSha1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "RsaSignPssSha1", 20usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).RsaSignPssSha1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsaSignPssSha256(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "RsaSignPssSha256", 21usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).RsaSignPssSha256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsaSignPssSha384(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "RsaSignPssSha384", 22usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).RsaSignPssSha384)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsaSignPssSha512(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics", "RsaSignPssSha512", 23usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics_Vtbl)).RsaSignPssSha512)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RsaPkcs1,
+            RsaOaepSha1,
+            RsaOaepSha256,
+            RsaOaepSha384,
+            RsaOaepSha512,
+            EcdsaP256Sha256,
+            EcdsaP384Sha384,
+            EcdsaP521Sha512,
+            DsaSha1,
+            DsaSha256,
+            RsaSignPkcs1Sha1,
+            RsaSignPkcs1Sha256,
+            RsaSignPkcs1Sha384,
+            RsaSignPkcs1Sha512,
+            RsaSignPssSha1,
+            RsaSignPssSha256,
+            RsaSignPssSha384,
+            RsaSignPssSha512,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAsymmetricAlgorithmNamesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAsymmetricAlgorithmNamesStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EcdsaSha256(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics2", "EcdsaSha256", 6usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics2_Vtbl)).EcdsaSha256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EcdsaSha384(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics2", "EcdsaSha384", 7usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics2_Vtbl)).EcdsaSha384)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EcdsaSha512(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricAlgorithmNamesStatics2", "EcdsaSha512", 8usize, |this| ((**(this as *const *const IAsymmetricAlgorithmNamesStatics2_Vtbl)).EcdsaSha512)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EcdsaSha256, EcdsaSha384, EcdsaSha512 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAsymmetricAlgorithmNamesStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IAsymmetricKeyAlgorithmProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AlgorithmName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricKeyAlgorithmProvider", "AlgorithmName", 6usize, |this| ((**(this as *const *const IAsymmetricKeyAlgorithmProvider_Vtbl)).AlgorithmName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateKeyPair(this: *mut core::ffi::c_void, keysize: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricKeyAlgorithmProvider", "CreateKeyPair", 7usize, |this| ((**(this as *const *const IAsymmetricKeyAlgorithmProvider_Vtbl)).CreateKeyPair)(this, keysize, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ImportDefaultPrivateKeyBlob(this: *mut core::ffi::c_void, keyblob: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricKeyAlgorithmProvider", "ImportDefaultPrivateKeyBlob", 8usize, |this| ((**(this as *const *const IAsymmetricKeyAlgorithmProvider_Vtbl)).ImportDefaultPrivateKeyBlob)(this, keyblob, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ImportKeyPairWithBlobType(this: *mut core::ffi::c_void, keyblob: *mut core::ffi::c_void, blobtype: CryptographicPrivateKeyBlobType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricKeyAlgorithmProvider", "ImportKeyPairWithBlobType", 9usize, |this| ((**(this as *const *const IAsymmetricKeyAlgorithmProvider_Vtbl)).ImportKeyPairWithBlobType)(this, keyblob, blobtype, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ImportDefaultPublicKeyBlob(this: *mut core::ffi::c_void, keyblob: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricKeyAlgorithmProvider", "ImportDefaultPublicKeyBlob", 10usize, |this| ((**(this as *const *const IAsymmetricKeyAlgorithmProvider_Vtbl)).ImportDefaultPublicKeyBlob)(this, keyblob, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ImportPublicKeyWithBlobType(this: *mut core::ffi::c_void, keyblob: *mut core::ffi::c_void, blobtype: CryptographicPublicKeyBlobType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricKeyAlgorithmProvider", "ImportPublicKeyWithBlobType", 11usize, |this| ((**(this as *const *const IAsymmetricKeyAlgorithmProvider_Vtbl)).ImportPublicKeyWithBlobType)(this, keyblob, blobtype, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AlgorithmName,
+            CreateKeyPair,
+            #[cfg(feature = "Storage_Streams")]
+            ImportDefaultPrivateKeyBlob,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ImportDefaultPrivateKeyBlob: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ImportKeyPairWithBlobType,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ImportKeyPairWithBlobType: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ImportDefaultPublicKeyBlob,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ImportDefaultPublicKeyBlob: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ImportPublicKeyWithBlobType,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ImportPublicKeyWithBlobType: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAsymmetricKeyAlgorithmProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAsymmetricKeyAlgorithmProvider2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateKeyPairWithCurveName(this: *mut core::ffi::c_void, curvename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricKeyAlgorithmProvider2", "CreateKeyPairWithCurveName", 6usize, |this| ((**(this as *const *const IAsymmetricKeyAlgorithmProvider2_Vtbl)).CreateKeyPairWithCurveName)(this, curvename, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateKeyPairWithCurveParameters(this: *mut core::ffi::c_void, parameters_array_size: u32, parameters: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricKeyAlgorithmProvider2", "CreateKeyPairWithCurveParameters", 7usize, |this| ((**(this as *const *const IAsymmetricKeyAlgorithmProvider2_Vtbl)).CreateKeyPairWithCurveParameters)(this, parameters_array_size, parameters, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateKeyPairWithCurveName, CreateKeyPairWithCurveParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAsymmetricKeyAlgorithmProvider2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAsymmetricKeyAlgorithmProviderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenAlgorithm(this: *mut core::ffi::c_void, algorithm: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsymmetricKeyAlgorithmProviderStatics", "OpenAlgorithm", 6usize, |this| ((**(this as *const *const IAsymmetricKeyAlgorithmProviderStatics_Vtbl)).OpenAlgorithm)(this, algorithm, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenAlgorithm }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAsymmetricKeyAlgorithmProviderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ICryptographicEngineStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Encrypt(this: *mut core::ffi::c_void, key: *mut core::ffi::c_void, data: *mut core::ffi::c_void, iv: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicEngineStatics", "Encrypt", 6usize, |this| ((**(this as *const *const ICryptographicEngineStatics_Vtbl)).Encrypt)(this, key, data, iv, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Decrypt(this: *mut core::ffi::c_void, key: *mut core::ffi::c_void, data: *mut core::ffi::c_void, iv: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicEngineStatics", "Decrypt", 7usize, |this| ((**(this as *const *const ICryptographicEngineStatics_Vtbl)).Decrypt)(this, key, data, iv, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn EncryptAndAuthenticate(this: *mut core::ffi::c_void, key: *mut core::ffi::c_void, data: *mut core::ffi::c_void, nonce: *mut core::ffi::c_void, authenticateddata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicEngineStatics", "EncryptAndAuthenticate", 8usize, |this| ((**(this as *const *const ICryptographicEngineStatics_Vtbl)).EncryptAndAuthenticate)(this, key, data, nonce, authenticateddata, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn DecryptAndAuthenticate(this: *mut core::ffi::c_void, key: *mut core::ffi::c_void, data: *mut core::ffi::c_void, nonce: *mut core::ffi::c_void, authenticationtag: *mut core::ffi::c_void, authenticateddata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicEngineStatics", "DecryptAndAuthenticate", 9usize, |this| ((**(this as *const *const ICryptographicEngineStatics_Vtbl)).DecryptAndAuthenticate)(this, key, data, nonce, authenticationtag, authenticateddata, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Sign(this: *mut core::ffi::c_void, key: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicEngineStatics", "Sign", 10usize, |this| ((**(this as *const *const ICryptographicEngineStatics_Vtbl)).Sign)(this, key, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn VerifySignature(this: *mut core::ffi::c_void, key: *mut core::ffi::c_void, data: *mut core::ffi::c_void, signature: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicEngineStatics", "VerifySignature", 11usize, |this| ((**(this as *const *const ICryptographicEngineStatics_Vtbl)).VerifySignature)(this, key, data, signature, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn DeriveKeyMaterial(this: *mut core::ffi::c_void, key: *mut core::ffi::c_void, parameters: *mut core::ffi::c_void, desiredkeysize: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicEngineStatics", "DeriveKeyMaterial", 12usize, |this| ((**(this as *const *const ICryptographicEngineStatics_Vtbl)).DeriveKeyMaterial)(this, key, parameters, desiredkeysize, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Encrypt,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Encrypt: 0,
+            #[cfg(feature = "Storage_Streams")]
+            Decrypt,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Decrypt: 0,
+            #[cfg(feature = "Storage_Streams")]
+            EncryptAndAuthenticate,
+            #[cfg(not(feature = "Storage_Streams"))]
+            EncryptAndAuthenticate: 0,
+            #[cfg(feature = "Storage_Streams")]
+            DecryptAndAuthenticate,
+            #[cfg(not(feature = "Storage_Streams"))]
+            DecryptAndAuthenticate: 0,
+            #[cfg(feature = "Storage_Streams")]
+            Sign,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Sign: 0,
+            #[cfg(feature = "Storage_Streams")]
+            VerifySignature,
+            #[cfg(not(feature = "Storage_Streams"))]
+            VerifySignature: 0,
+            #[cfg(feature = "Storage_Streams")]
+            DeriveKeyMaterial,
+            #[cfg(not(feature = "Storage_Streams"))]
+            DeriveKeyMaterial: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICryptographicEngineStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ICryptographicEngineStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SignHashedData(this: *mut core::ffi::c_void, key: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicEngineStatics2", "SignHashedData", 6usize, |this| ((**(this as *const *const ICryptographicEngineStatics2_Vtbl)).SignHashedData)(this, key, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn VerifySignatureWithHashInput(this: *mut core::ffi::c_void, key: *mut core::ffi::c_void, data: *mut core::ffi::c_void, signature: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicEngineStatics2", "VerifySignatureWithHashInput", 7usize, |this| ((**(this as *const *const ICryptographicEngineStatics2_Vtbl)).VerifySignatureWithHashInput)(this, key, data, signature, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn DecryptAsync(this: *mut core::ffi::c_void, key: *mut core::ffi::c_void, data: *mut core::ffi::c_void, iv: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicEngineStatics2", "DecryptAsync", 8usize, |this| ((**(this as *const *const ICryptographicEngineStatics2_Vtbl)).DecryptAsync)(this, key, data, iv, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SignAsync(this: *mut core::ffi::c_void, key: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicEngineStatics2", "SignAsync", 9usize, |this| ((**(this as *const *const ICryptographicEngineStatics2_Vtbl)).SignAsync)(this, key, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SignHashedDataAsync(this: *mut core::ffi::c_void, key: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicEngineStatics2", "SignHashedDataAsync", 10usize, |this| ((**(this as *const *const ICryptographicEngineStatics2_Vtbl)).SignHashedDataAsync)(this, key, data, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            SignHashedData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SignHashedData: 0,
+            #[cfg(feature = "Storage_Streams")]
+            VerifySignatureWithHashInput,
+            #[cfg(not(feature = "Storage_Streams"))]
+            VerifySignatureWithHashInput: 0,
+            #[cfg(feature = "Storage_Streams")]
+            DecryptAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            DecryptAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SignAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SignAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SignHashedDataAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SignHashedDataAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICryptographicEngineStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ICryptographicKey_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KeySize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicKey", "KeySize", 6usize, |this| ((**(this as *const *const ICryptographicKey_Vtbl)).KeySize)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ExportDefaultPrivateKeyBlobType(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicKey", "ExportDefaultPrivateKeyBlobType", 7usize, |this| ((**(this as *const *const ICryptographicKey_Vtbl)).ExportDefaultPrivateKeyBlobType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ExportPrivateKeyWithBlobType(this: *mut core::ffi::c_void, blobtype: CryptographicPrivateKeyBlobType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicKey", "ExportPrivateKeyWithBlobType", 8usize, |this| ((**(this as *const *const ICryptographicKey_Vtbl)).ExportPrivateKeyWithBlobType)(this, blobtype, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ExportDefaultPublicKeyBlobType(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicKey", "ExportDefaultPublicKeyBlobType", 9usize, |this| ((**(this as *const *const ICryptographicKey_Vtbl)).ExportDefaultPublicKeyBlobType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ExportPublicKeyWithBlobType(this: *mut core::ffi::c_void, blobtype: CryptographicPublicKeyBlobType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicKey", "ExportPublicKeyWithBlobType", 10usize, |this| ((**(this as *const *const ICryptographicKey_Vtbl)).ExportPublicKeyWithBlobType)(this, blobtype, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            KeySize,
+            #[cfg(feature = "Storage_Streams")]
+            ExportDefaultPrivateKeyBlobType,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ExportDefaultPrivateKeyBlobType: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ExportPrivateKeyWithBlobType,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ExportPrivateKeyWithBlobType: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ExportDefaultPublicKeyBlobType,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ExportDefaultPublicKeyBlobType: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ExportPublicKeyWithBlobType,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ExportPublicKeyWithBlobType: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICryptographicKey as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IEccCurveNamesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BrainpoolP160r1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "BrainpoolP160r1", 6usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).BrainpoolP160r1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrainpoolP160t1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "BrainpoolP160t1", 7usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).BrainpoolP160t1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrainpoolP192r1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "BrainpoolP192r1", 8usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).BrainpoolP192r1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrainpoolP192t1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "BrainpoolP192t1", 9usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).BrainpoolP192t1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrainpoolP224r1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "BrainpoolP224r1", 10usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).BrainpoolP224r1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrainpoolP224t1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "BrainpoolP224t1", 11usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).BrainpoolP224t1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrainpoolP256r1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "BrainpoolP256r1", 12usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).BrainpoolP256r1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrainpoolP256t1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "BrainpoolP256t1", 13usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).BrainpoolP256t1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrainpoolP320r1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "BrainpoolP320r1", 14usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).BrainpoolP320r1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrainpoolP320t1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "BrainpoolP320t1", 15usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).BrainpoolP320t1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrainpoolP384r1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "BrainpoolP384r1", 16usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).BrainpoolP384r1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrainpoolP384t1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "BrainpoolP384t1", 17usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).BrainpoolP384t1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrainpoolP512r1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "BrainpoolP512r1", 18usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).BrainpoolP512r1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrainpoolP512t1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "BrainpoolP512t1", 19usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).BrainpoolP512t1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Curve25519(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "Curve25519", 20usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).Curve25519)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ec192wapi(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "Ec192wapi", 21usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).Ec192wapi)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NistP192(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "NistP192", 22usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).NistP192)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NistP224(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "NistP224", 23usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).NistP224)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NistP256(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "NistP256", 24usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).NistP256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NistP384(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "NistP384", 25usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).NistP384)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NistP521(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "NistP521", 26usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).NistP521)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumsP256t1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "NumsP256t1", 27usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).NumsP256t1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumsP384t1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "NumsP384t1", 28usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).NumsP384t1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumsP512t1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "NumsP512t1", 29usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).NumsP512t1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecP160k1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "SecP160k1", 30usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).SecP160k1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecP160r1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "SecP160r1", 31usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).SecP160r1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecP160r2(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "SecP160r2", 32usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).SecP160r2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecP192k1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "SecP192k1", 33usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).SecP192k1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecP192r1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "SecP192r1", 34usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).SecP192r1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecP224k1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "SecP224k1", 35usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).SecP224k1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecP224r1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "SecP224r1", 36usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).SecP224r1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecP256k1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "SecP256k1", 37usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).SecP256k1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecP256r1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "SecP256r1", 38usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).SecP256r1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecP384r1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "SecP384r1", 39usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).SecP384r1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecP521r1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "SecP521r1", 40usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).SecP521r1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wtls7(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "Wtls7", 41usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).Wtls7)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wtls9(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "Wtls9", 42usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).Wtls9)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wtls12(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "Wtls12", 43usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).Wtls12)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn X962P192v1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "X962P192v1", 44usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).X962P192v1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn X962P192v2(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "X962P192v2", 45usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).X962P192v2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn X962P192v3(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "X962P192v3", 46usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).X962P192v3)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn X962P239v1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "X962P239v1", 47usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).X962P239v1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn X962P239v2(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "X962P239v2", 48usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).X962P239v2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn X962P239v3(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "X962P239v3", 49usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).X962P239v3)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn X962P256v1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "X962P256v1", 50usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).X962P256v1)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AllEccCurveNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEccCurveNamesStatics", "AllEccCurveNames", 51usize, |this| ((**(this as *const *const IEccCurveNamesStatics_Vtbl)).AllEccCurveNames)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BrainpoolP160r1,
+            BrainpoolP160t1,
+            BrainpoolP192r1,
+            BrainpoolP192t1,
+            BrainpoolP224r1,
+            BrainpoolP224t1,
+            BrainpoolP256r1,
+            BrainpoolP256t1,
+            BrainpoolP320r1,
+            BrainpoolP320t1,
+            BrainpoolP384r1,
+            BrainpoolP384t1,
+            BrainpoolP512r1,
+            BrainpoolP512t1,
+            Curve25519,
+            Ec192wapi,
+            NistP192,
+            NistP224,
+            NistP256,
+            NistP384,
+            NistP521,
+            NumsP256t1,
+            NumsP384t1,
+            NumsP512t1,
+            SecP160k1,
+            SecP160r1,
+            SecP160r2,
+            SecP192k1,
+            SecP192r1,
+            SecP224k1,
+            SecP224r1,
+            SecP256k1,
+            SecP256r1,
+            SecP384r1,
+            SecP521r1,
+            Wtls7,
+            Wtls9,
+            Wtls12,
+            X962P192v1,
+            X962P192v2,
+            X962P192v3,
+            X962P239v1,
+            X962P239v2,
+            X962P239v3,
+            X962P256v1,
+            #[cfg(feature = "Foundation_Collections")]
+            AllEccCurveNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AllEccCurveNames: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEccCurveNamesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IEncryptedAndAuthenticatedData_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn EncryptedData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEncryptedAndAuthenticatedData", "EncryptedData", 6usize, |this| ((**(this as *const *const IEncryptedAndAuthenticatedData_Vtbl)).EncryptedData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AuthenticationTag(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEncryptedAndAuthenticatedData", "AuthenticationTag", 7usize, |this| ((**(this as *const *const IEncryptedAndAuthenticatedData_Vtbl)).AuthenticationTag)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            EncryptedData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            EncryptedData: 0,
+            #[cfg(feature = "Storage_Streams")]
+            AuthenticationTag,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AuthenticationTag: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEncryptedAndAuthenticatedData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHashAlgorithmNamesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Md5(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHashAlgorithmNamesStatics", "Md5", 6usize, |this| ((**(this as *const *const IHashAlgorithmNamesStatics_Vtbl)).Md5)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sha1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHashAlgorithmNamesStatics", "Sha1", 7usize, |this| ((**(this as *const *const IHashAlgorithmNamesStatics_Vtbl)).Sha1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sha256(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHashAlgorithmNamesStatics", "Sha256", 8usize, |this| ((**(this as *const *const IHashAlgorithmNamesStatics_Vtbl)).Sha256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sha384(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHashAlgorithmNamesStatics", "Sha384", 9usize, |this| ((**(this as *const *const IHashAlgorithmNamesStatics_Vtbl)).Sha384)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sha512(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHashAlgorithmNamesStatics", "Sha512", 10usize, |this| ((**(this as *const *const IHashAlgorithmNamesStatics_Vtbl)).Sha512)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Md5, Sha1, Sha256, Sha384, Sha512 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHashAlgorithmNamesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IHashAlgorithmProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AlgorithmName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHashAlgorithmProvider", "AlgorithmName", 6usize, |this| ((**(this as *const *const IHashAlgorithmProvider_Vtbl)).AlgorithmName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashLength(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHashAlgorithmProvider", "HashLength", 7usize, |this| ((**(this as *const *const IHashAlgorithmProvider_Vtbl)).HashLength)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn HashData(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHashAlgorithmProvider", "HashData", 8usize, |this| ((**(this as *const *const IHashAlgorithmProvider_Vtbl)).HashData)(this, data, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateHash(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHashAlgorithmProvider", "CreateHash", 9usize, |this| ((**(this as *const *const IHashAlgorithmProvider_Vtbl)).CreateHash)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AlgorithmName,
+            HashLength,
+            #[cfg(feature = "Storage_Streams")]
+            HashData,
+            #[cfg(not(feature = "Storage_Streams"))]
+            HashData: 0,
+            CreateHash,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHashAlgorithmProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHashAlgorithmProviderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenAlgorithm(this: *mut core::ffi::c_void, algorithm: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHashAlgorithmProviderStatics", "OpenAlgorithm", 6usize, |this| ((**(this as *const *const IHashAlgorithmProviderStatics_Vtbl)).OpenAlgorithm)(this, algorithm, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenAlgorithm }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHashAlgorithmProviderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IHashComputation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHashComputation", "Append", 6usize, |this| ((**(this as *const *const IHashComputation_Vtbl)).Append)(this, data), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetValueAndReset(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHashComputation", "GetValueAndReset", 7usize, |this| ((**(this as *const *const IHashComputation_Vtbl)).GetValueAndReset)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Append,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Append: 0,
+            #[cfg(feature = "Storage_Streams")]
+            GetValueAndReset,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetValueAndReset: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHashComputation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyDerivationAlgorithmNamesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Pbkdf2Md5(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics", "Pbkdf2Md5", 6usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics_Vtbl)).Pbkdf2Md5)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pbkdf2Sha1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics", "Pbkdf2Sha1", 7usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics_Vtbl)).Pbkdf2Sha1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pbkdf2Sha256(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics", "Pbkdf2Sha256", 8usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics_Vtbl)).Pbkdf2Sha256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pbkdf2Sha384(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics", "Pbkdf2Sha384", 9usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics_Vtbl)).Pbkdf2Sha384)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pbkdf2Sha512(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics", "Pbkdf2Sha512", 10usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics_Vtbl)).Pbkdf2Sha512)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sp800108CtrHmacMd5(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics", "Sp800108CtrHmacMd5", 11usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics_Vtbl)).Sp800108CtrHmacMd5)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sp800108CtrHmacSha1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics", "Sp800108CtrHmacSha1", 12usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics_Vtbl)).Sp800108CtrHmacSha1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sp800108CtrHmacSha256(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics", "Sp800108CtrHmacSha256", 13usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics_Vtbl)).Sp800108CtrHmacSha256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sp800108CtrHmacSha384(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics", "Sp800108CtrHmacSha384", 14usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics_Vtbl)).Sp800108CtrHmacSha384)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sp800108CtrHmacSha512(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics", "Sp800108CtrHmacSha512", 15usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics_Vtbl)).Sp800108CtrHmacSha512)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sp80056aConcatMd5(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics", "Sp80056aConcatMd5", 16usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics_Vtbl)).Sp80056aConcatMd5)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sp80056aConcatSha1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics", "Sp80056aConcatSha1", 17usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics_Vtbl)).Sp80056aConcatSha1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sp80056aConcatSha256(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics", "Sp80056aConcatSha256", 18usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics_Vtbl)).Sp80056aConcatSha256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sp80056aConcatSha384(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics", "Sp80056aConcatSha384", 19usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics_Vtbl)).Sp80056aConcatSha384)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sp80056aConcatSha512(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics", "Sp80056aConcatSha512", 20usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics_Vtbl)).Sp80056aConcatSha512)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Pbkdf2Md5,
+            Pbkdf2Sha1,
+            Pbkdf2Sha256,
+            Pbkdf2Sha384,
+            Pbkdf2Sha512,
+            Sp800108CtrHmacMd5,
+            Sp800108CtrHmacSha1,
+            Sp800108CtrHmacSha256,
+            Sp800108CtrHmacSha384,
+            Sp800108CtrHmacSha512,
+            Sp80056aConcatMd5,
+            Sp80056aConcatSha1,
+            Sp80056aConcatSha256,
+            Sp80056aConcatSha384,
+            Sp80056aConcatSha512,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyDerivationAlgorithmNamesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyDerivationAlgorithmNamesStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CapiKdfMd5(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics2", "CapiKdfMd5", 6usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics2_Vtbl)).CapiKdfMd5)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CapiKdfSha1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics2", "CapiKdfSha1", 7usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics2_Vtbl)).CapiKdfSha1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CapiKdfSha256(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics2", "CapiKdfSha256", 8usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics2_Vtbl)).CapiKdfSha256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CapiKdfSha384(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics2", "CapiKdfSha384", 9usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics2_Vtbl)).CapiKdfSha384)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CapiKdfSha512(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmNamesStatics2", "CapiKdfSha512", 10usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmNamesStatics2_Vtbl)).CapiKdfSha512)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CapiKdfMd5,
+            CapiKdfSha1,
+            CapiKdfSha256,
+            CapiKdfSha384,
+            CapiKdfSha512,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyDerivationAlgorithmNamesStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IKeyDerivationAlgorithmProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AlgorithmName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmProvider", "AlgorithmName", 6usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmProvider_Vtbl)).AlgorithmName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateKey(this: *mut core::ffi::c_void, keymaterial: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmProvider", "CreateKey", 7usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmProvider_Vtbl)).CreateKey)(this, keymaterial, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AlgorithmName,
+            #[cfg(feature = "Storage_Streams")]
+            CreateKey,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateKey: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyDerivationAlgorithmProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyDerivationAlgorithmProviderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenAlgorithm(this: *mut core::ffi::c_void, algorithm: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationAlgorithmProviderStatics", "OpenAlgorithm", 6usize, |this| ((**(this as *const *const IKeyDerivationAlgorithmProviderStatics_Vtbl)).OpenAlgorithm)(this, algorithm, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenAlgorithm }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyDerivationAlgorithmProviderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IKeyDerivationParameters_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn KdfGenericBinary(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationParameters", "KdfGenericBinary", 6usize, |this| ((**(this as *const *const IKeyDerivationParameters_Vtbl)).KdfGenericBinary)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetKdfGenericBinary(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationParameters", "SetKdfGenericBinary", 7usize, |this| ((**(this as *const *const IKeyDerivationParameters_Vtbl)).SetKdfGenericBinary)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IterationCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationParameters", "IterationCount", 8usize, |this| ((**(this as *const *const IKeyDerivationParameters_Vtbl)).IterationCount)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            KdfGenericBinary,
+            #[cfg(not(feature = "Storage_Streams"))]
+            KdfGenericBinary: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetKdfGenericBinary,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetKdfGenericBinary: 0,
+            IterationCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyDerivationParameters as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyDerivationParameters2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Capi1KdfTargetAlgorithm(this: *mut core::ffi::c_void, result__: *mut Capi1KdfTargetAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationParameters2", "Capi1KdfTargetAlgorithm", 6usize, |this| ((**(this as *const *const IKeyDerivationParameters2_Vtbl)).Capi1KdfTargetAlgorithm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCapi1KdfTargetAlgorithm(this: *mut core::ffi::c_void, value: Capi1KdfTargetAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationParameters2", "SetCapi1KdfTargetAlgorithm", 7usize, |this| ((**(this as *const *const IKeyDerivationParameters2_Vtbl)).SetCapi1KdfTargetAlgorithm)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Capi1KdfTargetAlgorithm, SetCapi1KdfTargetAlgorithm }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyDerivationParameters2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IKeyDerivationParametersStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn BuildForPbkdf2(this: *mut core::ffi::c_void, pbkdf2salt: *mut core::ffi::c_void, iterationcount: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationParametersStatics", "BuildForPbkdf2", 6usize, |this| ((**(this as *const *const IKeyDerivationParametersStatics_Vtbl)).BuildForPbkdf2)(this, pbkdf2salt, iterationcount, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn BuildForSP800108(this: *mut core::ffi::c_void, label: *mut core::ffi::c_void, context: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationParametersStatics", "BuildForSP800108", 7usize, |this| ((**(this as *const *const IKeyDerivationParametersStatics_Vtbl)).BuildForSP800108)(this, label, context, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn BuildForSP80056a(this: *mut core::ffi::c_void, algorithmid: *mut core::ffi::c_void, partyuinfo: *mut core::ffi::c_void, partyvinfo: *mut core::ffi::c_void, supppubinfo: *mut core::ffi::c_void, suppprivinfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationParametersStatics", "BuildForSP80056a", 8usize, |this| ((**(this as *const *const IKeyDerivationParametersStatics_Vtbl)).BuildForSP80056a)(this, algorithmid, partyuinfo, partyvinfo, supppubinfo, suppprivinfo, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            BuildForPbkdf2,
+            #[cfg(not(feature = "Storage_Streams"))]
+            BuildForPbkdf2: 0,
+            #[cfg(feature = "Storage_Streams")]
+            BuildForSP800108,
+            #[cfg(not(feature = "Storage_Streams"))]
+            BuildForSP800108: 0,
+            #[cfg(feature = "Storage_Streams")]
+            BuildForSP80056a,
+            #[cfg(not(feature = "Storage_Streams"))]
+            BuildForSP80056a: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyDerivationParametersStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyDerivationParametersStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BuildForCapi1Kdf(this: *mut core::ffi::c_void, capi1kdftargetalgorithm: Capi1KdfTargetAlgorithm, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyDerivationParametersStatics2", "BuildForCapi1Kdf", 6usize, |this| ((**(this as *const *const IKeyDerivationParametersStatics2_Vtbl)).BuildForCapi1Kdf)(this, capi1kdftargetalgorithm, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BuildForCapi1Kdf }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyDerivationParametersStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMacAlgorithmNamesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HmacMd5(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMacAlgorithmNamesStatics", "HmacMd5", 6usize, |this| ((**(this as *const *const IMacAlgorithmNamesStatics_Vtbl)).HmacMd5)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HmacSha1(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMacAlgorithmNamesStatics", "HmacSha1", 7usize, |this| ((**(this as *const *const IMacAlgorithmNamesStatics_Vtbl)).HmacSha1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HmacSha256(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMacAlgorithmNamesStatics", "HmacSha256", 8usize, |this| ((**(this as *const *const IMacAlgorithmNamesStatics_Vtbl)).HmacSha256)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HmacSha384(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMacAlgorithmNamesStatics", "HmacSha384", 9usize, |this| ((**(this as *const *const IMacAlgorithmNamesStatics_Vtbl)).HmacSha384)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HmacSha512(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMacAlgorithmNamesStatics", "HmacSha512", 10usize, |this| ((**(this as *const *const IMacAlgorithmNamesStatics_Vtbl)).HmacSha512)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AesCmac(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMacAlgorithmNamesStatics", "AesCmac", 11usize, |this| ((**(this as *const *const IMacAlgorithmNamesStatics_Vtbl)).AesCmac)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HmacMd5,
+            HmacSha1,
+            HmacSha256,
+            HmacSha384,
+            HmacSha512,
+            AesCmac,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMacAlgorithmNamesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMacAlgorithmProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AlgorithmName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMacAlgorithmProvider", "AlgorithmName", 6usize, |this| ((**(this as *const *const IMacAlgorithmProvider_Vtbl)).AlgorithmName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MacLength(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMacAlgorithmProvider", "MacLength", 7usize, |this| ((**(this as *const *const IMacAlgorithmProvider_Vtbl)).MacLength)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateKey(this: *mut core::ffi::c_void, keymaterial: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMacAlgorithmProvider", "CreateKey", 8usize, |this| ((**(this as *const *const IMacAlgorithmProvider_Vtbl)).CreateKey)(this, keymaterial, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AlgorithmName,
+            MacLength,
+            #[cfg(feature = "Storage_Streams")]
+            CreateKey,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateKey: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMacAlgorithmProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IMacAlgorithmProvider2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateHash(this: *mut core::ffi::c_void, keymaterial: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMacAlgorithmProvider2", "CreateHash", 6usize, |this| ((**(this as *const *const IMacAlgorithmProvider2_Vtbl)).CreateHash)(this, keymaterial, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateHash,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateHash: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMacAlgorithmProvider2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMacAlgorithmProviderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenAlgorithm(this: *mut core::ffi::c_void, algorithm: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMacAlgorithmProviderStatics", "OpenAlgorithm", 6usize, |this| ((**(this as *const *const IMacAlgorithmProviderStatics_Vtbl)).OpenAlgorithm)(this, algorithm, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenAlgorithm }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMacAlgorithmProviderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Cryptography_Certificates")]
+impl windows_core::imp::ProxyVtable for IPersistedKeyProviderStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn OpenKeyPairFromCertificateAsync(this: *mut core::ffi::c_void, certificate: *mut core::ffi::c_void, hashalgorithmname: core::mem::MaybeUninit<windows_core::HSTRING>, padding: CryptographicPadding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistedKeyProviderStatics", "OpenKeyPairFromCertificateAsync", 6usize, |this| ((**(this as *const *const IPersistedKeyProviderStatics_Vtbl)).OpenKeyPairFromCertificateAsync)(this, certificate, hashalgorithmname, padding, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn OpenPublicKeyFromCertificate(this: *mut core::ffi::c_void, certificate: *mut core::ffi::c_void, hashalgorithmname: core::mem::MaybeUninit<windows_core::HSTRING>, padding: CryptographicPadding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistedKeyProviderStatics", "OpenPublicKeyFromCertificate", 7usize, |this| ((**(this as *const *const IPersistedKeyProviderStatics_Vtbl)).OpenPublicKeyFromCertificate)(this, certificate, hashalgorithmname, padding, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            OpenKeyPairFromCertificateAsync,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            OpenKeyPairFromCertificateAsync: 0,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            OpenPublicKeyFromCertificate,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            OpenPublicKeyFromCertificate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistedKeyProviderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISymmetricAlgorithmNamesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DesCbc(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "DesCbc", 6usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).DesCbc)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesEcb(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "DesEcb", 7usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).DesEcb)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TripleDesCbc(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "TripleDesCbc", 8usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).TripleDesCbc)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TripleDesEcb(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "TripleDesEcb", 9usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).TripleDesEcb)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rc2Cbc(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "Rc2Cbc", 10usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).Rc2Cbc)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rc2Ecb(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "Rc2Ecb", 11usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).Rc2Ecb)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AesCbc(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "AesCbc", 12usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).AesCbc)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AesEcb(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "AesEcb", 13usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).AesEcb)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AesGcm(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "AesGcm", 14usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).AesGcm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AesCcm(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "AesCcm", 15usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).AesCcm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AesCbcPkcs7(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "AesCbcPkcs7", 16usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).AesCbcPkcs7)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AesEcbPkcs7(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "AesEcbPkcs7", 17usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).AesEcbPkcs7)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesCbcPkcs7(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "DesCbcPkcs7", 18usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).DesCbcPkcs7)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesEcbPkcs7(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "DesEcbPkcs7", 19usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).DesEcbPkcs7)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TripleDesCbcPkcs7(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "TripleDesCbcPkcs7", 20usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).TripleDesCbcPkcs7)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TripleDesEcbPkcs7(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "TripleDesEcbPkcs7", 21usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).TripleDesEcbPkcs7)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rc2CbcPkcs7(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "Rc2CbcPkcs7", 22usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).Rc2CbcPkcs7)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rc2EcbPkcs7(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "Rc2EcbPkcs7", 23usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).Rc2EcbPkcs7)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rc4(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricAlgorithmNamesStatics", "Rc4", 24usize, |this| ((**(this as *const *const ISymmetricAlgorithmNamesStatics_Vtbl)).Rc4)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DesCbc,
+            DesEcb,
+            TripleDesCbc,
+            TripleDesEcb,
+            Rc2Cbc,
+            Rc2Ecb,
+            AesCbc,
+            AesEcb,
+            AesGcm,
+            AesCcm,
+            AesCbcPkcs7,
+            AesEcbPkcs7,
+            DesCbcPkcs7,
+            DesEcbPkcs7,
+            TripleDesCbcPkcs7,
+            TripleDesEcbPkcs7,
+            Rc2CbcPkcs7,
+            Rc2EcbPkcs7,
+            Rc4,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISymmetricAlgorithmNamesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISymmetricKeyAlgorithmProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AlgorithmName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricKeyAlgorithmProvider", "AlgorithmName", 6usize, |this| ((**(this as *const *const ISymmetricKeyAlgorithmProvider_Vtbl)).AlgorithmName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlockLength(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricKeyAlgorithmProvider", "BlockLength", 7usize, |this| ((**(this as *const *const ISymmetricKeyAlgorithmProvider_Vtbl)).BlockLength)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateSymmetricKey(this: *mut core::ffi::c_void, keymaterial: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricKeyAlgorithmProvider", "CreateSymmetricKey", 8usize, |this| ((**(this as *const *const ISymmetricKeyAlgorithmProvider_Vtbl)).CreateSymmetricKey)(this, keymaterial, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AlgorithmName,
+            BlockLength,
+            #[cfg(feature = "Storage_Streams")]
+            CreateSymmetricKey,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateSymmetricKey: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISymmetricKeyAlgorithmProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISymmetricKeyAlgorithmProviderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenAlgorithm(this: *mut core::ffi::c_void, algorithm: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISymmetricKeyAlgorithmProviderStatics", "OpenAlgorithm", 6usize, |this| ((**(this as *const *const ISymmetricKeyAlgorithmProviderStatics_Vtbl)).OpenAlgorithm)(this, algorithm, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenAlgorithm }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISymmetricKeyAlgorithmProviderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -113,3 +113,5 @@ impl windows_core::RuntimeName for DataProtectionProvider {
 }
 unsafe impl Send for DataProtectionProvider {}
 unsafe impl Sync for DataProtectionProvider {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
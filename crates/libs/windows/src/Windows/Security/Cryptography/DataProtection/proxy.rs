@@ -0,0 +1,56 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IDataProtectionProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ProtectAsync(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProtectionProvider", "ProtectAsync", 6usize, |this| ((**(this as *const *const IDataProtectionProvider_Vtbl)).ProtectAsync)(this, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn UnprotectAsync(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProtectionProvider", "UnprotectAsync", 7usize, |this| ((**(this as *const *const IDataProtectionProvider_Vtbl)).UnprotectAsync)(this, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ProtectStreamAsync(this: *mut core::ffi::c_void, src: *mut core::ffi::c_void, dest: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProtectionProvider", "ProtectStreamAsync", 8usize, |this| ((**(this as *const *const IDataProtectionProvider_Vtbl)).ProtectStreamAsync)(this, src, dest, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn UnprotectStreamAsync(this: *mut core::ffi::c_void, src: *mut core::ffi::c_void, dest: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProtectionProvider", "UnprotectStreamAsync", 9usize, |this| ((**(this as *const *const IDataProtectionProvider_Vtbl)).UnprotectStreamAsync)(this, src, dest, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            ProtectAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ProtectAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            UnprotectAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            UnprotectAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ProtectStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ProtectStreamAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            UnprotectStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            UnprotectStreamAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataProtectionProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataProtectionProviderFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateOverloadExplicit(this: *mut core::ffi::c_void, protectiondescriptor: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProtectionProviderFactory", "CreateOverloadExplicit", 6usize, |this| ((**(this as *const *const IDataProtectionProviderFactory_Vtbl)).CreateOverloadExplicit)(this, protectiondescriptor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateOverloadExplicit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataProtectionProviderFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
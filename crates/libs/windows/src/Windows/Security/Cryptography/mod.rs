@@ -164,6 +164,7 @@ impl BinaryStringEncoding {
 impl windows_core::TypeKind for BinaryStringEncoding {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BinaryStringEncoding {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BinaryStringEncoding").field(&self.0).finish()
@@ -172,3 +173,5 @@ impl core::fmt::Debug for BinaryStringEncoding {
 impl windows_core::RuntimeType for BinaryStringEncoding {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Security.Cryptography.BinaryStringEncoding;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,96 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ICryptographicBufferStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Compare(this: *mut core::ffi::c_void, object1: *mut core::ffi::c_void, object2: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicBufferStatics", "Compare", 6usize, |this| ((**(this as *const *const ICryptographicBufferStatics_Vtbl)).Compare)(this, object1, object2, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GenerateRandom(this: *mut core::ffi::c_void, length: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicBufferStatics", "GenerateRandom", 7usize, |this| ((**(this as *const *const ICryptographicBufferStatics_Vtbl)).GenerateRandom)(this, length, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateRandomNumber(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicBufferStatics", "GenerateRandomNumber", 8usize, |this| ((**(this as *const *const ICryptographicBufferStatics_Vtbl)).GenerateRandomNumber)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromByteArray(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicBufferStatics", "CreateFromByteArray", 9usize, |this| ((**(this as *const *const ICryptographicBufferStatics_Vtbl)).CreateFromByteArray)(this, value_array_size, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CopyToByteArray(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicBufferStatics", "CopyToByteArray", 10usize, |this| ((**(this as *const *const ICryptographicBufferStatics_Vtbl)).CopyToByteArray)(this, buffer, value_array_size, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn DecodeFromHexString(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicBufferStatics", "DecodeFromHexString", 11usize, |this| ((**(this as *const *const ICryptographicBufferStatics_Vtbl)).DecodeFromHexString)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn EncodeToHexString(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicBufferStatics", "EncodeToHexString", 12usize, |this| ((**(this as *const *const ICryptographicBufferStatics_Vtbl)).EncodeToHexString)(this, buffer, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn DecodeFromBase64String(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicBufferStatics", "DecodeFromBase64String", 13usize, |this| ((**(this as *const *const ICryptographicBufferStatics_Vtbl)).DecodeFromBase64String)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn EncodeToBase64String(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicBufferStatics", "EncodeToBase64String", 14usize, |this| ((**(this as *const *const ICryptographicBufferStatics_Vtbl)).EncodeToBase64String)(this, buffer, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ConvertStringToBinary(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, encoding: BinaryStringEncoding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicBufferStatics", "ConvertStringToBinary", 15usize, |this| ((**(this as *const *const ICryptographicBufferStatics_Vtbl)).ConvertStringToBinary)(this, value, encoding, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ConvertBinaryToString(this: *mut core::ffi::c_void, encoding: BinaryStringEncoding, buffer: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptographicBufferStatics", "ConvertBinaryToString", 16usize, |this| ((**(this as *const *const ICryptographicBufferStatics_Vtbl)).ConvertBinaryToString)(this, encoding, buffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Compare,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Compare: 0,
+            #[cfg(feature = "Storage_Streams")]
+            GenerateRandom,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GenerateRandom: 0,
+            GenerateRandomNumber,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromByteArray,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromByteArray: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CopyToByteArray,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CopyToByteArray: 0,
+            #[cfg(feature = "Storage_Streams")]
+            DecodeFromHexString,
+            #[cfg(not(feature = "Storage_Streams"))]
+            DecodeFromHexString: 0,
+            #[cfg(feature = "Storage_Streams")]
+            EncodeToHexString,
+            #[cfg(not(feature = "Storage_Streams"))]
+            EncodeToHexString: 0,
+            #[cfg(feature = "Storage_Streams")]
+            DecodeFromBase64String,
+            #[cfg(not(feature = "Storage_Streams"))]
+            DecodeFromBase64String: 0,
+            #[cfg(feature = "Storage_Streams")]
+            EncodeToBase64String,
+            #[cfg(not(feature = "Storage_Streams"))]
+            EncodeToBase64String: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ConvertStringToBinary,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ConvertStringToBinary: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ConvertBinaryToString,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ConvertBinaryToString: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICryptographicBufferStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
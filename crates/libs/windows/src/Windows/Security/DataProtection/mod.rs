@@ -267,6 +267,7 @@ impl UserDataAvailability {
 impl windows_core::TypeKind for UserDataAvailability {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataAvailability {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataAvailability").field(&self.0).finish()
@@ -285,6 +286,7 @@ impl UserDataBufferUnprotectStatus {
 impl windows_core::TypeKind for UserDataBufferUnprotectStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataBufferUnprotectStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataBufferUnprotectStatus").field(&self.0).finish()
@@ -304,6 +306,7 @@ impl UserDataStorageItemProtectionStatus {
 impl windows_core::TypeKind for UserDataStorageItemProtectionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserDataStorageItemProtectionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserDataStorageItemProtectionStatus").field(&self.0).finish()
@@ -312,3 +315,5 @@ impl core::fmt::Debug for UserDataStorageItemProtectionStatus {
 impl windows_core::RuntimeType for UserDataStorageItemProtectionStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Security.DataProtection.UserDataStorageItemProtectionStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,128 @@
+impl windows_core::imp::ProxyVtable for IUserDataAvailabilityStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataAvailabilityStateChangedEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const IUserDataAvailabilityStateChangedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataAvailabilityStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IUserDataBufferUnprotectResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut UserDataBufferUnprotectStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataBufferUnprotectResult", "Status", 6usize, |this| ((**(this as *const *const IUserDataBufferUnprotectResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn UnprotectedBuffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataBufferUnprotectResult", "UnprotectedBuffer", 7usize, |this| ((**(this as *const *const IUserDataBufferUnprotectResult_Vtbl)).UnprotectedBuffer)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Storage_Streams")]
+            UnprotectedBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            UnprotectedBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataBufferUnprotectResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IUserDataProtectionManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn ProtectStorageItemAsync(this: *mut core::ffi::c_void, storageitem: *mut core::ffi::c_void, availability: UserDataAvailability, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataProtectionManager", "ProtectStorageItemAsync", 6usize, |this| ((**(this as *const *const IUserDataProtectionManager_Vtbl)).ProtectStorageItemAsync)(this, storageitem, availability, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn GetStorageItemProtectionInfoAsync(this: *mut core::ffi::c_void, storageitem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataProtectionManager", "GetStorageItemProtectionInfoAsync", 7usize, |this| ((**(this as *const *const IUserDataProtectionManager_Vtbl)).GetStorageItemProtectionInfoAsync)(this, storageitem, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ProtectBufferAsync(this: *mut core::ffi::c_void, unprotectedbuffer: *mut core::ffi::c_void, availability: UserDataAvailability, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataProtectionManager", "ProtectBufferAsync", 8usize, |this| ((**(this as *const *const IUserDataProtectionManager_Vtbl)).ProtectBufferAsync)(this, unprotectedbuffer, availability, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn UnprotectBufferAsync(this: *mut core::ffi::c_void, protectedbuffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataProtectionManager", "UnprotectBufferAsync", 9usize, |this| ((**(this as *const *const IUserDataProtectionManager_Vtbl)).UnprotectBufferAsync)(this, protectedbuffer, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsContinuedDataAvailabilityExpected(this: *mut core::ffi::c_void, availability: UserDataAvailability, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataProtectionManager", "IsContinuedDataAvailabilityExpected", 10usize, |this| ((**(this as *const *const IUserDataProtectionManager_Vtbl)).IsContinuedDataAvailabilityExpected)(this, availability, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataAvailabilityStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataProtectionManager", "DataAvailabilityStateChanged", 11usize, |this| ((**(this as *const *const IUserDataProtectionManager_Vtbl)).DataAvailabilityStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDataAvailabilityStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataProtectionManager", "RemoveDataAvailabilityStateChanged", 12usize, |this| ((**(this as *const *const IUserDataProtectionManager_Vtbl)).RemoveDataAvailabilityStateChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            ProtectStorageItemAsync,
+            #[cfg(not(feature = "Storage"))]
+            ProtectStorageItemAsync: 0,
+            #[cfg(feature = "Storage")]
+            GetStorageItemProtectionInfoAsync,
+            #[cfg(not(feature = "Storage"))]
+            GetStorageItemProtectionInfoAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ProtectBufferAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ProtectBufferAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            UnprotectBufferAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            UnprotectBufferAsync: 0,
+            IsContinuedDataAvailabilityExpected,
+            DataAvailabilityStateChanged,
+            RemoveDataAvailabilityStateChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataProtectionManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IUserDataProtectionManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryGetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataProtectionManagerStatics", "TryGetDefault", 6usize, |this| ((**(this as *const *const IUserDataProtectionManagerStatics_Vtbl)).TryGetDefault)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn TryGetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataProtectionManagerStatics", "TryGetForUser", 7usize, |this| ((**(this as *const *const IUserDataProtectionManagerStatics_Vtbl)).TryGetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TryGetDefault,
+            #[cfg(feature = "System")]
+            TryGetForUser,
+            #[cfg(not(feature = "System"))]
+            TryGetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataProtectionManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataStorageItemProtectionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Availability(this: *mut core::ffi::c_void, result__: *mut UserDataAvailability) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataStorageItemProtectionInfo", "Availability", 6usize, |this| ((**(this as *const *const IUserDataStorageItemProtectionInfo_Vtbl)).Availability)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Availability }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataStorageItemProtectionInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1498,6 +1498,7 @@ impl DataProtectionStatus {
 impl windows_core::TypeKind for DataProtectionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DataProtectionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DataProtectionStatus").field(&self.0).finish()
@@ -1518,6 +1519,7 @@ impl EnforcementLevel {
 impl windows_core::TypeKind for EnforcementLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnforcementLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnforcementLevel").field(&self.0).finish()
@@ -1546,6 +1548,7 @@ impl FileProtectionStatus {
 impl windows_core::TypeKind for FileProtectionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FileProtectionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FileProtectionStatus").field(&self.0).finish()
@@ -1570,6 +1573,7 @@ impl ProtectedImportExportStatus {
 impl windows_core::TypeKind for ProtectedImportExportStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProtectedImportExportStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProtectedImportExportStatus").field(&self.0).finish()
@@ -1590,6 +1594,7 @@ impl ProtectionPolicyAuditAction {
 impl windows_core::TypeKind for ProtectionPolicyAuditAction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProtectionPolicyAuditAction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProtectionPolicyAuditAction").field(&self.0).finish()
@@ -1609,6 +1614,7 @@ impl ProtectionPolicyEvaluationResult {
 impl windows_core::TypeKind for ProtectionPolicyEvaluationResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProtectionPolicyEvaluationResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProtectionPolicyEvaluationResult").field(&self.0).finish()
@@ -1627,6 +1633,7 @@ impl ProtectionPolicyRequestAccessBehavior {
 impl windows_core::TypeKind for ProtectionPolicyRequestAccessBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProtectionPolicyRequestAccessBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProtectionPolicyRequestAccessBehavior").field(&self.0).finish()
@@ -1635,3 +1642,5 @@ impl core::fmt::Debug for ProtectionPolicyRequestAccessBehavior {
 impl windows_core::RuntimeType for ProtectionPolicyRequestAccessBehavior {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Security.EnterpriseData.ProtectionPolicyRequestAccessBehavior;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,809 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IBufferProtectUnprotectResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Buffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBufferProtectUnprotectResult", "Buffer", 6usize, |this| ((**(this as *const *const IBufferProtectUnprotectResult_Vtbl)).Buffer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectionInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBufferProtectUnprotectResult", "ProtectionInfo", 7usize, |this| ((**(this as *const *const IBufferProtectUnprotectResult_Vtbl)).ProtectionInfo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Buffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Buffer: 0,
+            ProtectionInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBufferProtectUnprotectResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataProtectionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut DataProtectionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProtectionInfo", "Status", 6usize, |this| ((**(this as *const *const IDataProtectionInfo_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Identity(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProtectionInfo", "Identity", 7usize, |this| ((**(this as *const *const IDataProtectionInfo_Vtbl)).Identity)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Identity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataProtectionInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IDataProtectionManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ProtectAsync(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProtectionManagerStatics", "ProtectAsync", 6usize, |this| ((**(this as *const *const IDataProtectionManagerStatics_Vtbl)).ProtectAsync)(this, data, identity, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn UnprotectAsync(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProtectionManagerStatics", "UnprotectAsync", 7usize, |this| ((**(this as *const *const IDataProtectionManagerStatics_Vtbl)).UnprotectAsync)(this, data, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ProtectStreamAsync(this: *mut core::ffi::c_void, unprotectedstream: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, protectedstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProtectionManagerStatics", "ProtectStreamAsync", 8usize, |this| ((**(this as *const *const IDataProtectionManagerStatics_Vtbl)).ProtectStreamAsync)(this, unprotectedstream, identity, protectedstream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn UnprotectStreamAsync(this: *mut core::ffi::c_void, protectedstream: *mut core::ffi::c_void, unprotectedstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProtectionManagerStatics", "UnprotectStreamAsync", 9usize, |this| ((**(this as *const *const IDataProtectionManagerStatics_Vtbl)).UnprotectStreamAsync)(this, protectedstream, unprotectedstream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetProtectionInfoAsync(this: *mut core::ffi::c_void, protecteddata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProtectionManagerStatics", "GetProtectionInfoAsync", 10usize, |this| ((**(this as *const *const IDataProtectionManagerStatics_Vtbl)).GetProtectionInfoAsync)(this, protecteddata, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetStreamProtectionInfoAsync(this: *mut core::ffi::c_void, protectedstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataProtectionManagerStatics", "GetStreamProtectionInfoAsync", 11usize, |this| ((**(this as *const *const IDataProtectionManagerStatics_Vtbl)).GetStreamProtectionInfoAsync)(this, protectedstream, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            ProtectAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ProtectAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            UnprotectAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            UnprotectAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ProtectStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ProtectStreamAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            UnprotectStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            UnprotectStreamAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            GetProtectionInfoAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetProtectionInfoAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            GetStreamProtectionInfoAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetStreamProtectionInfoAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataProtectionManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileProtectionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut FileProtectionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionInfo", "Status", 6usize, |this| ((**(this as *const *const IFileProtectionInfo_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRoamable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionInfo", "IsRoamable", 7usize, |this| ((**(this as *const *const IFileProtectionInfo_Vtbl)).IsRoamable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Identity(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionInfo", "Identity", 8usize, |this| ((**(this as *const *const IFileProtectionInfo_Vtbl)).Identity)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, IsRoamable, Identity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileProtectionInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileProtectionInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsProtectWhileOpenSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionInfo2", "IsProtectWhileOpenSupported", 6usize, |this| ((**(this as *const *const IFileProtectionInfo2_Vtbl)).IsProtectWhileOpenSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsProtectWhileOpenSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileProtectionInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IFileProtectionManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn ProtectAsync(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionManagerStatics", "ProtectAsync", 6usize, |this| ((**(this as *const *const IFileProtectionManagerStatics_Vtbl)).ProtectAsync)(this, target, identity, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CopyProtectionAsync(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, target: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionManagerStatics", "CopyProtectionAsync", 7usize, |this| ((**(this as *const *const IFileProtectionManagerStatics_Vtbl)).CopyProtectionAsync)(this, source, target, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn GetProtectionInfoAsync(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionManagerStatics", "GetProtectionInfoAsync", 8usize, |this| ((**(this as *const *const IFileProtectionManagerStatics_Vtbl)).GetProtectionInfoAsync)(this, source, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SaveFileAsContainerAsync(this: *mut core::ffi::c_void, protectedfile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionManagerStatics", "SaveFileAsContainerAsync", 9usize, |this| ((**(this as *const *const IFileProtectionManagerStatics_Vtbl)).SaveFileAsContainerAsync)(this, protectedfile, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LoadFileFromContainerAsync(this: *mut core::ffi::c_void, containerfile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionManagerStatics", "LoadFileFromContainerAsync", 10usize, |this| ((**(this as *const *const IFileProtectionManagerStatics_Vtbl)).LoadFileFromContainerAsync)(this, containerfile, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LoadFileFromContainerWithTargetAsync(this: *mut core::ffi::c_void, containerfile: *mut core::ffi::c_void, target: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionManagerStatics", "LoadFileFromContainerWithTargetAsync", 11usize, |this| ((**(this as *const *const IFileProtectionManagerStatics_Vtbl)).LoadFileFromContainerWithTargetAsync)(this, containerfile, target, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn CreateProtectedAndOpenAsync(this: *mut core::ffi::c_void, parentfolder: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, identity: core::mem::MaybeUninit<windows_core::HSTRING>, collisionoption: super::super::Storage::CreationCollisionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionManagerStatics", "CreateProtectedAndOpenAsync", 12usize, |this| ((**(this as *const *const IFileProtectionManagerStatics_Vtbl)).CreateProtectedAndOpenAsync)(this, parentfolder, desiredname, identity, collisionoption, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            ProtectAsync,
+            #[cfg(not(feature = "Storage"))]
+            ProtectAsync: 0,
+            #[cfg(feature = "Storage")]
+            CopyProtectionAsync,
+            #[cfg(not(feature = "Storage"))]
+            CopyProtectionAsync: 0,
+            #[cfg(feature = "Storage")]
+            GetProtectionInfoAsync,
+            #[cfg(not(feature = "Storage"))]
+            GetProtectionInfoAsync: 0,
+            #[cfg(feature = "Storage")]
+            SaveFileAsContainerAsync,
+            #[cfg(not(feature = "Storage"))]
+            SaveFileAsContainerAsync: 0,
+            #[cfg(feature = "Storage")]
+            LoadFileFromContainerAsync,
+            #[cfg(not(feature = "Storage"))]
+            LoadFileFromContainerAsync: 0,
+            #[cfg(feature = "Storage")]
+            LoadFileFromContainerWithTargetAsync,
+            #[cfg(not(feature = "Storage"))]
+            LoadFileFromContainerWithTargetAsync: 0,
+            #[cfg(feature = "Storage")]
+            CreateProtectedAndOpenAsync,
+            #[cfg(not(feature = "Storage"))]
+            CreateProtectedAndOpenAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileProtectionManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IFileProtectionManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn IsContainerAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionManagerStatics2", "IsContainerAsync", 6usize, |this| ((**(this as *const *const IFileProtectionManagerStatics2_Vtbl)).IsContainerAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LoadFileFromContainerWithTargetAndNameCollisionOptionAsync(this: *mut core::ffi::c_void, containerfile: *mut core::ffi::c_void, target: *mut core::ffi::c_void, collisionoption: super::super::Storage::NameCollisionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionManagerStatics2", "LoadFileFromContainerWithTargetAndNameCollisionOptionAsync", 7usize, |this| ((**(this as *const *const IFileProtectionManagerStatics2_Vtbl)).LoadFileFromContainerWithTargetAndNameCollisionOptionAsync)(this, containerfile, target, collisionoption, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn SaveFileAsContainerWithSharingAsync(this: *mut core::ffi::c_void, protectedfile: *mut core::ffi::c_void, sharedwithidentities: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionManagerStatics2", "SaveFileAsContainerWithSharingAsync", 8usize, |this| ((**(this as *const *const IFileProtectionManagerStatics2_Vtbl)).SaveFileAsContainerWithSharingAsync)(this, protectedfile, sharedwithidentities, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            IsContainerAsync,
+            #[cfg(not(feature = "Storage"))]
+            IsContainerAsync: 0,
+            #[cfg(feature = "Storage")]
+            LoadFileFromContainerWithTargetAndNameCollisionOptionAsync,
+            #[cfg(not(feature = "Storage"))]
+            LoadFileFromContainerWithTargetAndNameCollisionOptionAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            SaveFileAsContainerWithSharingAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            SaveFileAsContainerWithSharingAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileProtectionManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IFileProtectionManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn UnprotectAsync(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionManagerStatics3", "UnprotectAsync", 6usize, |this| ((**(this as *const *const IFileProtectionManagerStatics3_Vtbl)).UnprotectAsync)(this, target, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn UnprotectWithOptionsAsync(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileProtectionManagerStatics3", "UnprotectWithOptionsAsync", 7usize, |this| ((**(this as *const *const IFileProtectionManagerStatics3_Vtbl)).UnprotectWithOptionsAsync)(this, target, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            UnprotectAsync,
+            #[cfg(not(feature = "Storage"))]
+            UnprotectAsync: 0,
+            #[cfg(feature = "Storage")]
+            UnprotectWithOptionsAsync,
+            #[cfg(not(feature = "Storage"))]
+            UnprotectWithOptionsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileProtectionManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IFileRevocationManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Storage", feature = "deprecated"))]
+        unsafe extern "system" fn ProtectAsync(this: *mut core::ffi::c_void, storageitem: *mut core::ffi::c_void, enterpriseidentity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileRevocationManagerStatics", "ProtectAsync", 6usize, |this| ((**(this as *const *const IFileRevocationManagerStatics_Vtbl)).ProtectAsync)(this, storageitem, enterpriseidentity, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage", feature = "deprecated"))]
+        unsafe extern "system" fn CopyProtectionAsync(this: *mut core::ffi::c_void, sourcestorageitem: *mut core::ffi::c_void, targetstorageitem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileRevocationManagerStatics", "CopyProtectionAsync", 7usize, |this| ((**(this as *const *const IFileRevocationManagerStatics_Vtbl)).CopyProtectionAsync)(this, sourcestorageitem, targetstorageitem, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Revoke(this: *mut core::ffi::c_void, enterpriseidentity: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileRevocationManagerStatics", "Revoke", 8usize, |this| ((**(this as *const *const IFileRevocationManagerStatics_Vtbl)).Revoke)(this, enterpriseidentity), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage", feature = "deprecated"))]
+        unsafe extern "system" fn GetStatusAsync(this: *mut core::ffi::c_void, storageitem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileRevocationManagerStatics", "GetStatusAsync", 9usize, |this| ((**(this as *const *const IFileRevocationManagerStatics_Vtbl)).GetStatusAsync)(this, storageitem, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Storage", feature = "deprecated"))]
+            ProtectAsync,
+            #[cfg(not(all(feature = "Storage", feature = "deprecated")))]
+            ProtectAsync: 0,
+            #[cfg(all(feature = "Storage", feature = "deprecated"))]
+            CopyProtectionAsync,
+            #[cfg(not(all(feature = "Storage", feature = "deprecated")))]
+            CopyProtectionAsync: 0,
+            #[cfg(feature = "deprecated")]
+            Revoke,
+            #[cfg(not(feature = "deprecated"))]
+            Revoke: 0,
+            #[cfg(all(feature = "Storage", feature = "deprecated"))]
+            GetStatusAsync,
+            #[cfg(not(all(feature = "Storage", feature = "deprecated")))]
+            GetStatusAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileRevocationManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileUnprotectOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAudit(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileUnprotectOptions", "SetAudit", 6usize, |this| ((**(this as *const *const IFileUnprotectOptions_Vtbl)).SetAudit)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Audit(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileUnprotectOptions", "Audit", 7usize, |this| ((**(this as *const *const IFileUnprotectOptions_Vtbl)).Audit)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAudit, Audit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileUnprotectOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileUnprotectOptionsFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, audit: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileUnprotectOptionsFactory", "Create", 6usize, |this| ((**(this as *const *const IFileUnprotectOptionsFactory_Vtbl)).Create)(this, audit, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileUnprotectOptionsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IProtectedAccessResumedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Identities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectedAccessResumedEventArgs", "Identities", 6usize, |this| ((**(this as *const *const IProtectedAccessResumedEventArgs_Vtbl)).Identities)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Identities,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Identities: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectedAccessResumedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IProtectedAccessSuspendingEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Identities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectedAccessSuspendingEventArgs", "Identities", 6usize, |this| ((**(this as *const *const IProtectedAccessSuspendingEventArgs_Vtbl)).Identities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectedAccessSuspendingEventArgs", "Deadline", 7usize, |this| ((**(this as *const *const IProtectedAccessSuspendingEventArgs_Vtbl)).Deadline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectedAccessSuspendingEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IProtectedAccessSuspendingEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Identities,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Identities: 0,
+            Deadline,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectedAccessSuspendingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IProtectedContainerExportResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut ProtectedImportExportStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectedContainerExportResult", "Status", 6usize, |this| ((**(this as *const *const IProtectedContainerExportResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn File(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectedContainerExportResult", "File", 7usize, |this| ((**(this as *const *const IProtectedContainerExportResult_Vtbl)).File)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Storage")]
+            File,
+            #[cfg(not(feature = "Storage"))]
+            File: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectedContainerExportResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IProtectedContainerImportResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut ProtectedImportExportStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectedContainerImportResult", "Status", 6usize, |this| ((**(this as *const *const IProtectedContainerImportResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn File(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectedContainerImportResult", "File", 7usize, |this| ((**(this as *const *const IProtectedContainerImportResult_Vtbl)).File)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Storage")]
+            File,
+            #[cfg(not(feature = "Storage"))]
+            File: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectedContainerImportResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IProtectedContentRevokedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Identities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectedContentRevokedEventArgs", "Identities", 6usize, |this| ((**(this as *const *const IProtectedContentRevokedEventArgs_Vtbl)).Identities)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Identities,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Identities: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectedContentRevokedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IProtectedFileCreateResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn File(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectedFileCreateResult", "File", 6usize, |this| ((**(this as *const *const IProtectedFileCreateResult_Vtbl)).File)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Stream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectedFileCreateResult", "Stream", 7usize, |this| ((**(this as *const *const IProtectedFileCreateResult_Vtbl)).Stream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectionInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectedFileCreateResult", "ProtectionInfo", 8usize, |this| ((**(this as *const *const IProtectedFileCreateResult_Vtbl)).ProtectionInfo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            File,
+            #[cfg(not(feature = "Storage"))]
+            File: 0,
+            #[cfg(feature = "Storage_Streams")]
+            Stream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Stream: 0,
+            ProtectionInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectedFileCreateResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProtectionPolicyAuditInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAction(this: *mut core::ffi::c_void, value: ProtectionPolicyAuditAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyAuditInfo", "SetAction", 6usize, |this| ((**(this as *const *const IProtectionPolicyAuditInfo_Vtbl)).SetAction)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Action(this: *mut core::ffi::c_void, result__: *mut ProtectionPolicyAuditAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyAuditInfo", "Action", 7usize, |this| ((**(this as *const *const IProtectionPolicyAuditInfo_Vtbl)).Action)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyAuditInfo", "SetDataDescription", 8usize, |this| ((**(this as *const *const IProtectionPolicyAuditInfo_Vtbl)).SetDataDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyAuditInfo", "DataDescription", 9usize, |this| ((**(this as *const *const IProtectionPolicyAuditInfo_Vtbl)).DataDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyAuditInfo", "SetSourceDescription", 10usize, |this| ((**(this as *const *const IProtectionPolicyAuditInfo_Vtbl)).SetSourceDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyAuditInfo", "SourceDescription", 11usize, |this| ((**(this as *const *const IProtectionPolicyAuditInfo_Vtbl)).SourceDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyAuditInfo", "SetTargetDescription", 12usize, |this| ((**(this as *const *const IProtectionPolicyAuditInfo_Vtbl)).SetTargetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyAuditInfo", "TargetDescription", 13usize, |this| ((**(this as *const *const IProtectionPolicyAuditInfo_Vtbl)).TargetDescription)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAction,
+            Action,
+            SetDataDescription,
+            DataDescription,
+            SetSourceDescription,
+            SourceDescription,
+            SetTargetDescription,
+            TargetDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectionPolicyAuditInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProtectionPolicyAuditInfoFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, action: ProtectionPolicyAuditAction, datadescription: core::mem::MaybeUninit<windows_core::HSTRING>, sourcedescription: core::mem::MaybeUninit<windows_core::HSTRING>, targetdescription: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyAuditInfoFactory", "Create", 6usize, |this| ((**(this as *const *const IProtectionPolicyAuditInfoFactory_Vtbl)).Create)(this, action, datadescription, sourcedescription, targetdescription, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithActionAndDataDescription(this: *mut core::ffi::c_void, action: ProtectionPolicyAuditAction, datadescription: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyAuditInfoFactory", "CreateWithActionAndDataDescription", 7usize, |this| ((**(this as *const *const IProtectionPolicyAuditInfoFactory_Vtbl)).CreateWithActionAndDataDescription)(this, action, datadescription, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithActionAndDataDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectionPolicyAuditInfoFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProtectionPolicyManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIdentity(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManager", "SetIdentity", 6usize, |this| ((**(this as *const *const IProtectionPolicyManager_Vtbl)).SetIdentity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Identity(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManager", "Identity", 7usize, |this| ((**(this as *const *const IProtectionPolicyManager_Vtbl)).Identity)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetIdentity, Identity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectionPolicyManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProtectionPolicyManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetShowEnterpriseIndicator(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManager2", "SetShowEnterpriseIndicator", 6usize, |this| ((**(this as *const *const IProtectionPolicyManager2_Vtbl)).SetShowEnterpriseIndicator)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowEnterpriseIndicator(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManager2", "ShowEnterpriseIndicator", 7usize, |this| ((**(this as *const *const IProtectionPolicyManager2_Vtbl)).ShowEnterpriseIndicator)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetShowEnterpriseIndicator, ShowEnterpriseIndicator }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectionPolicyManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking")]
+impl windows_core::imp::ProxyVtable for IProtectionPolicyManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsIdentityManaged(this: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics", "IsIdentityManaged", 6usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics_Vtbl)).IsIdentityManaged)(this, identity, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryApplyProcessUIPolicy(this: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics", "TryApplyProcessUIPolicy", 7usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics_Vtbl)).TryApplyProcessUIPolicy)(this, identity, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearProcessUIPolicy(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics", "ClearProcessUIPolicy", 8usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics_Vtbl)).ClearProcessUIPolicy)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCurrentThreadNetworkContext(this: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics", "CreateCurrentThreadNetworkContext", 9usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics_Vtbl)).CreateCurrentThreadNetworkContext)(this, identity, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking")]
+        unsafe extern "system" fn GetPrimaryManagedIdentityForNetworkEndpointAsync(this: *mut core::ffi::c_void, endpointhost: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics", "GetPrimaryManagedIdentityForNetworkEndpointAsync", 10usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics_Vtbl)).GetPrimaryManagedIdentityForNetworkEndpointAsync)(this, endpointhost, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevokeContent(this: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics", "RevokeContent", 11usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics_Vtbl)).RevokeContent)(this, identity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics", "GetForCurrentView", 12usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectedAccessSuspending(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics", "ProtectedAccessSuspending", 13usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics_Vtbl)).ProtectedAccessSuspending)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProtectedAccessSuspending(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics", "RemoveProtectedAccessSuspending", 14usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics_Vtbl)).RemoveProtectedAccessSuspending)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectedAccessResumed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics", "ProtectedAccessResumed", 15usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics_Vtbl)).ProtectedAccessResumed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProtectedAccessResumed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics", "RemoveProtectedAccessResumed", 16usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics_Vtbl)).RemoveProtectedAccessResumed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectedContentRevoked(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics", "ProtectedContentRevoked", 17usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics_Vtbl)).ProtectedContentRevoked)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProtectedContentRevoked(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics", "RemoveProtectedContentRevoked", 18usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics_Vtbl)).RemoveProtectedContentRevoked)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckAccess(this: *mut core::ffi::c_void, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, targetidentity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut ProtectionPolicyEvaluationResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics", "CheckAccess", 19usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics_Vtbl)).CheckAccess)(this, sourceidentity, targetidentity, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, targetidentity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics", "RequestAccessAsync", 20usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics_Vtbl)).RequestAccessAsync)(this, sourceidentity, targetidentity, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsIdentityManaged,
+            TryApplyProcessUIPolicy,
+            ClearProcessUIPolicy,
+            CreateCurrentThreadNetworkContext,
+            #[cfg(feature = "Networking")]
+            GetPrimaryManagedIdentityForNetworkEndpointAsync,
+            #[cfg(not(feature = "Networking"))]
+            GetPrimaryManagedIdentityForNetworkEndpointAsync: 0,
+            RevokeContent,
+            GetForCurrentView,
+            ProtectedAccessSuspending,
+            RemoveProtectedAccessSuspending,
+            ProtectedAccessResumed,
+            RemoveProtectedAccessResumed,
+            ProtectedContentRevoked,
+            RemoveProtectedContentRevoked,
+            CheckAccess,
+            RequestAccessAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectionPolicyManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProtectionPolicyManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasContentBeenRevokedSince(this: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, since: super::super::Foundation::DateTime, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics2", "HasContentBeenRevokedSince", 6usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics2_Vtbl)).HasContentBeenRevokedSince)(this, identity, since, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckAccessForApp(this: *mut core::ffi::c_void, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, apppackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut ProtectionPolicyEvaluationResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics2", "CheckAccessForApp", 7usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics2_Vtbl)).CheckAccessForApp)(this, sourceidentity, apppackagefamilyname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessForAppAsync(this: *mut core::ffi::c_void, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, apppackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics2", "RequestAccessForAppAsync", 8usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics2_Vtbl)).RequestAccessForAppAsync)(this, sourceidentity, apppackagefamilyname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnforcementLevel(this: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut EnforcementLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics2", "GetEnforcementLevel", 9usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics2_Vtbl)).GetEnforcementLevel)(this, identity, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUserDecryptionAllowed(this: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics2", "IsUserDecryptionAllowed", 10usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics2_Vtbl)).IsUserDecryptionAllowed)(this, identity, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsProtectionUnderLockRequired(this: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics2", "IsProtectionUnderLockRequired", 11usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics2_Vtbl)).IsProtectionUnderLockRequired)(this, identity, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics2", "PolicyChanged", 12usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics2_Vtbl)).PolicyChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePolicyChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics2", "RemovePolicyChanged", 13usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics2_Vtbl)).RemovePolicyChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsProtectionEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics2", "IsProtectionEnabled", 14usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics2_Vtbl)).IsProtectionEnabled)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HasContentBeenRevokedSince,
+            CheckAccessForApp,
+            RequestAccessForAppAsync,
+            GetEnforcementLevel,
+            IsUserDecryptionAllowed,
+            IsProtectionUnderLockRequired,
+            PolicyChanged,
+            RemovePolicyChanged,
+            IsProtectionEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectionPolicyManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProtectionPolicyManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAccessWithAuditingInfoAsync(this: *mut core::ffi::c_void, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, targetidentity: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics3", "RequestAccessWithAuditingInfoAsync", 6usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics3_Vtbl)).RequestAccessWithAuditingInfoAsync)(this, sourceidentity, targetidentity, auditinfo, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessWithMessageAsync(this: *mut core::ffi::c_void, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, targetidentity: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfo: *mut core::ffi::c_void, messagefromapp: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics3", "RequestAccessWithMessageAsync", 7usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics3_Vtbl)).RequestAccessWithMessageAsync)(this, sourceidentity, targetidentity, auditinfo, messagefromapp, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessForAppWithAuditingInfoAsync(this: *mut core::ffi::c_void, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, apppackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics3", "RequestAccessForAppWithAuditingInfoAsync", 8usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics3_Vtbl)).RequestAccessForAppWithAuditingInfoAsync)(this, sourceidentity, apppackagefamilyname, auditinfo, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessForAppWithMessageAsync(this: *mut core::ffi::c_void, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, apppackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfo: *mut core::ffi::c_void, messagefromapp: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics3", "RequestAccessForAppWithMessageAsync", 9usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics3_Vtbl)).RequestAccessForAppWithMessageAsync)(this, sourceidentity, apppackagefamilyname, auditinfo, messagefromapp, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogAuditEvent(this: *mut core::ffi::c_void, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, targetidentity: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics3", "LogAuditEvent", 10usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics3_Vtbl)).LogAuditEvent)(this, sourceidentity, targetidentity, auditinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestAccessWithAuditingInfoAsync,
+            RequestAccessWithMessageAsync,
+            RequestAccessForAppWithAuditingInfoAsync,
+            RequestAccessForAppWithMessageAsync,
+            LogAuditEvent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectionPolicyManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IProtectionPolicyManagerStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsRoamableProtectionEnabled(this: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics4", "IsRoamableProtectionEnabled", 6usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics4_Vtbl)).IsRoamableProtectionEnabled)(this, identity, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessWithBehaviorAsync(this: *mut core::ffi::c_void, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, targetidentity: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfo: *mut core::ffi::c_void, messagefromapp: core::mem::MaybeUninit<windows_core::HSTRING>, behavior: ProtectionPolicyRequestAccessBehavior, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics4", "RequestAccessWithBehaviorAsync", 7usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics4_Vtbl)).RequestAccessWithBehaviorAsync)(this, sourceidentity, targetidentity, auditinfo, messagefromapp, behavior, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessForAppWithBehaviorAsync(this: *mut core::ffi::c_void, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, apppackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfo: *mut core::ffi::c_void, messagefromapp: core::mem::MaybeUninit<windows_core::HSTRING>, behavior: ProtectionPolicyRequestAccessBehavior, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics4", "RequestAccessForAppWithBehaviorAsync", 8usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics4_Vtbl)).RequestAccessForAppWithBehaviorAsync)(this, sourceidentity, apppackagefamilyname, auditinfo, messagefromapp, behavior, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn RequestAccessToFilesForAppAsync(this: *mut core::ffi::c_void, sourceitemlist: *mut core::ffi::c_void, apppackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics4", "RequestAccessToFilesForAppAsync", 9usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics4_Vtbl)).RequestAccessToFilesForAppAsync)(this, sourceitemlist, apppackagefamilyname, auditinfo, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn RequestAccessToFilesForAppWithMessageAndBehaviorAsync(this: *mut core::ffi::c_void, sourceitemlist: *mut core::ffi::c_void, apppackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfo: *mut core::ffi::c_void, messagefromapp: core::mem::MaybeUninit<windows_core::HSTRING>, behavior: ProtectionPolicyRequestAccessBehavior, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics4", "RequestAccessToFilesForAppWithMessageAndBehaviorAsync", 10usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics4_Vtbl)).RequestAccessToFilesForAppWithMessageAndBehaviorAsync)(this, sourceitemlist, apppackagefamilyname, auditinfo, messagefromapp, behavior, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn RequestAccessToFilesForProcessAsync(this: *mut core::ffi::c_void, sourceitemlist: *mut core::ffi::c_void, processid: u32, auditinfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics4", "RequestAccessToFilesForProcessAsync", 11usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics4_Vtbl)).RequestAccessToFilesForProcessAsync)(this, sourceitemlist, processid, auditinfo, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn RequestAccessToFilesForProcessWithMessageAndBehaviorAsync(this: *mut core::ffi::c_void, sourceitemlist: *mut core::ffi::c_void, processid: u32, auditinfo: *mut core::ffi::c_void, messagefromapp: core::mem::MaybeUninit<windows_core::HSTRING>, behavior: ProtectionPolicyRequestAccessBehavior, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics4", "RequestAccessToFilesForProcessWithMessageAndBehaviorAsync", 12usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics4_Vtbl)).RequestAccessToFilesForProcessWithMessageAndBehaviorAsync)(this, sourceitemlist, processid, auditinfo, messagefromapp, behavior, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn IsFileProtectionRequiredAsync(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics4", "IsFileProtectionRequiredAsync", 13usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics4_Vtbl)).IsFileProtectionRequiredAsync)(this, target, identity, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn IsFileProtectionRequiredForNewFileAsync(this: *mut core::ffi::c_void, parentfolder: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics4", "IsFileProtectionRequiredForNewFileAsync", 14usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics4_Vtbl)).IsFileProtectionRequiredForNewFileAsync)(this, parentfolder, identity, desiredname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrimaryManagedIdentity(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics4", "PrimaryManagedIdentity", 15usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics4_Vtbl)).PrimaryManagedIdentity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrimaryManagedIdentityForIdentity(this: *mut core::ffi::c_void, identity: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerStatics4", "GetPrimaryManagedIdentityForIdentity", 16usize, |this| ((**(this as *const *const IProtectionPolicyManagerStatics4_Vtbl)).GetPrimaryManagedIdentityForIdentity)(this, identity, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsRoamableProtectionEnabled,
+            RequestAccessWithBehaviorAsync,
+            RequestAccessForAppWithBehaviorAsync,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            RequestAccessToFilesForAppAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            RequestAccessToFilesForAppAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            RequestAccessToFilesForAppWithMessageAndBehaviorAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            RequestAccessToFilesForAppWithMessageAndBehaviorAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            RequestAccessToFilesForProcessAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            RequestAccessToFilesForProcessAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            RequestAccessToFilesForProcessWithMessageAndBehaviorAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            RequestAccessToFilesForProcessWithMessageAndBehaviorAsync: 0,
+            #[cfg(feature = "Storage")]
+            IsFileProtectionRequiredAsync,
+            #[cfg(not(feature = "Storage"))]
+            IsFileProtectionRequiredAsync: 0,
+            #[cfg(feature = "Storage")]
+            IsFileProtectionRequiredForNewFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            IsFileProtectionRequiredForNewFileAsync: 0,
+            PrimaryManagedIdentity,
+            GetPrimaryManagedIdentityForIdentity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectionPolicyManagerStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IThreadNetworkContext_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IThreadNetworkContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -376,6 +376,7 @@ impl EasDisallowConvenienceLogonResult {
 impl windows_core::TypeKind for EasDisallowConvenienceLogonResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EasDisallowConvenienceLogonResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EasDisallowConvenienceLogonResult").field(&self.0).finish()
@@ -395,6 +396,7 @@ impl EasEncryptionProviderType {
 impl windows_core::TypeKind for EasEncryptionProviderType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EasEncryptionProviderType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EasEncryptionProviderType").field(&self.0).finish()
@@ -416,6 +418,7 @@ impl EasMaxInactivityTimeLockResult {
 impl windows_core::TypeKind for EasMaxInactivityTimeLockResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EasMaxInactivityTimeLockResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EasMaxInactivityTimeLockResult").field(&self.0).finish()
@@ -437,6 +440,7 @@ impl EasMaxPasswordFailedAttemptsResult {
 impl windows_core::TypeKind for EasMaxPasswordFailedAttemptsResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EasMaxPasswordFailedAttemptsResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EasMaxPasswordFailedAttemptsResult").field(&self.0).finish()
@@ -468,6 +472,7 @@ impl EasMinPasswordComplexCharactersResult {
 impl windows_core::TypeKind for EasMinPasswordComplexCharactersResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EasMinPasswordComplexCharactersResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EasMinPasswordComplexCharactersResult").field(&self.0).finish()
@@ -499,6 +504,7 @@ impl EasMinPasswordLengthResult {
 impl windows_core::TypeKind for EasMinPasswordLengthResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EasMinPasswordLengthResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EasMinPasswordLengthResult").field(&self.0).finish()
@@ -524,6 +530,7 @@ impl EasPasswordExpirationResult {
 impl windows_core::TypeKind for EasPasswordExpirationResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EasPasswordExpirationResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EasPasswordExpirationResult").field(&self.0).finish()
@@ -545,6 +552,7 @@ impl EasPasswordHistoryResult {
 impl windows_core::TypeKind for EasPasswordHistoryResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EasPasswordHistoryResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EasPasswordHistoryResult").field(&self.0).finish()
@@ -580,6 +588,7 @@ impl EasRequireEncryptionResult {
 impl windows_core::TypeKind for EasRequireEncryptionResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EasRequireEncryptionResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EasRequireEncryptionResult").field(&self.0).finish()
@@ -588,3 +597,5 @@ impl core::fmt::Debug for EasRequireEncryptionResult {
 impl windows_core::RuntimeType for EasRequireEncryptionResult {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Security.ExchangeActiveSyncProvisioning.EasRequireEncryptionResult;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,192 @@
+impl windows_core::imp::ProxyVtable for IEasClientDeviceInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientDeviceInformation", "Id", 6usize, |this| ((**(this as *const *const IEasClientDeviceInformation_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OperatingSystem(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientDeviceInformation", "OperatingSystem", 7usize, |this| ((**(this as *const *const IEasClientDeviceInformation_Vtbl)).OperatingSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientDeviceInformation", "FriendlyName", 8usize, |this| ((**(this as *const *const IEasClientDeviceInformation_Vtbl)).FriendlyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemManufacturer(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientDeviceInformation", "SystemManufacturer", 9usize, |this| ((**(this as *const *const IEasClientDeviceInformation_Vtbl)).SystemManufacturer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemProductName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientDeviceInformation", "SystemProductName", 10usize, |this| ((**(this as *const *const IEasClientDeviceInformation_Vtbl)).SystemProductName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemSku(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientDeviceInformation", "SystemSku", 11usize, |this| ((**(this as *const *const IEasClientDeviceInformation_Vtbl)).SystemSku)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            OperatingSystem,
+            FriendlyName,
+            SystemManufacturer,
+            SystemProductName,
+            SystemSku,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEasClientDeviceInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEasClientDeviceInformation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SystemHardwareVersion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientDeviceInformation2", "SystemHardwareVersion", 6usize, |this| ((**(this as *const *const IEasClientDeviceInformation2_Vtbl)).SystemHardwareVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemFirmwareVersion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientDeviceInformation2", "SystemFirmwareVersion", 7usize, |this| ((**(this as *const *const IEasClientDeviceInformation2_Vtbl)).SystemFirmwareVersion)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SystemHardwareVersion, SystemFirmwareVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEasClientDeviceInformation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEasClientSecurityPolicy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequireEncryption(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "RequireEncryption", 6usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).RequireEncryption)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequireEncryption(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "SetRequireEncryption", 7usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).SetRequireEncryption)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinPasswordLength(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "MinPasswordLength", 8usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).MinPasswordLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinPasswordLength(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "SetMinPasswordLength", 9usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).SetMinPasswordLength)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisallowConvenienceLogon(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "DisallowConvenienceLogon", 10usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).DisallowConvenienceLogon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisallowConvenienceLogon(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "SetDisallowConvenienceLogon", 11usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).SetDisallowConvenienceLogon)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinPasswordComplexCharacters(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "MinPasswordComplexCharacters", 12usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).MinPasswordComplexCharacters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinPasswordComplexCharacters(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "SetMinPasswordComplexCharacters", 13usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).SetMinPasswordComplexCharacters)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PasswordExpiration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "PasswordExpiration", 14usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).PasswordExpiration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPasswordExpiration(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "SetPasswordExpiration", 15usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).SetPasswordExpiration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PasswordHistory(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "PasswordHistory", 16usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).PasswordHistory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPasswordHistory(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "SetPasswordHistory", 17usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).SetPasswordHistory)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPasswordFailedAttempts(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "MaxPasswordFailedAttempts", 18usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).MaxPasswordFailedAttempts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxPasswordFailedAttempts(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "SetMaxPasswordFailedAttempts", 19usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).SetMaxPasswordFailedAttempts)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxInactivityTimeLock(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "MaxInactivityTimeLock", 20usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).MaxInactivityTimeLock)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxInactivityTimeLock(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "SetMaxInactivityTimeLock", 21usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).SetMaxInactivityTimeLock)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckCompliance(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "CheckCompliance", 22usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).CheckCompliance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasClientSecurityPolicy", "ApplyAsync", 23usize, |this| ((**(this as *const *const IEasClientSecurityPolicy_Vtbl)).ApplyAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequireEncryption,
+            SetRequireEncryption,
+            MinPasswordLength,
+            SetMinPasswordLength,
+            DisallowConvenienceLogon,
+            SetDisallowConvenienceLogon,
+            MinPasswordComplexCharacters,
+            SetMinPasswordComplexCharacters,
+            PasswordExpiration,
+            SetPasswordExpiration,
+            PasswordHistory,
+            SetPasswordHistory,
+            MaxPasswordFailedAttempts,
+            SetMaxPasswordFailedAttempts,
+            MaxInactivityTimeLock,
+            SetMaxInactivityTimeLock,
+            CheckCompliance,
+            ApplyAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEasClientSecurityPolicy as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEasComplianceResults_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Compliant(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasComplianceResults", "Compliant", 6usize, |this| ((**(this as *const *const IEasComplianceResults_Vtbl)).Compliant)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequireEncryptionResult(this: *mut core::ffi::c_void, result__: *mut EasRequireEncryptionResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasComplianceResults", "RequireEncryptionResult", 7usize, |this| ((**(this as *const *const IEasComplianceResults_Vtbl)).RequireEncryptionResult)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinPasswordLengthResult(this: *mut core::ffi::c_void, result__: *mut EasMinPasswordLengthResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasComplianceResults", "MinPasswordLengthResult", 8usize, |this| ((**(this as *const *const IEasComplianceResults_Vtbl)).MinPasswordLengthResult)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisallowConvenienceLogonResult(this: *mut core::ffi::c_void, result__: *mut EasDisallowConvenienceLogonResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasComplianceResults", "DisallowConvenienceLogonResult", 9usize, |this| ((**(this as *const *const IEasComplianceResults_Vtbl)).DisallowConvenienceLogonResult)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinPasswordComplexCharactersResult(this: *mut core::ffi::c_void, result__: *mut EasMinPasswordComplexCharactersResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasComplianceResults", "MinPasswordComplexCharactersResult", 10usize, |this| ((**(this as *const *const IEasComplianceResults_Vtbl)).MinPasswordComplexCharactersResult)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PasswordExpirationResult(this: *mut core::ffi::c_void, result__: *mut EasPasswordExpirationResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasComplianceResults", "PasswordExpirationResult", 11usize, |this| ((**(this as *const *const IEasComplianceResults_Vtbl)).PasswordExpirationResult)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PasswordHistoryResult(this: *mut core::ffi::c_void, result__: *mut EasPasswordHistoryResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasComplianceResults", "PasswordHistoryResult", 12usize, |this| ((**(this as *const *const IEasComplianceResults_Vtbl)).PasswordHistoryResult)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPasswordFailedAttemptsResult(this: *mut core::ffi::c_void, result__: *mut EasMaxPasswordFailedAttemptsResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasComplianceResults", "MaxPasswordFailedAttemptsResult", 13usize, |this| ((**(this as *const *const IEasComplianceResults_Vtbl)).MaxPasswordFailedAttemptsResult)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxInactivityTimeLockResult(this: *mut core::ffi::c_void, result__: *mut EasMaxInactivityTimeLockResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasComplianceResults", "MaxInactivityTimeLockResult", 14usize, |this| ((**(this as *const *const IEasComplianceResults_Vtbl)).MaxInactivityTimeLockResult)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Compliant,
+            RequireEncryptionResult,
+            MinPasswordLengthResult,
+            DisallowConvenienceLogonResult,
+            MinPasswordComplexCharactersResult,
+            PasswordExpirationResult,
+            PasswordHistoryResult,
+            MaxPasswordFailedAttemptsResult,
+            MaxInactivityTimeLockResult,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEasComplianceResults as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEasComplianceResults2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EncryptionProviderType(this: *mut core::ffi::c_void, result__: *mut EasEncryptionProviderType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEasComplianceResults2", "EncryptionProviderType", 6usize, |this| ((**(this as *const *const IEasComplianceResults2_Vtbl)).EncryptionProviderType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EncryptionProviderType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEasComplianceResults2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1962,6 +1962,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentActivator {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentActivator {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentActivator").field(&self.0).finish()
@@ -1987,6 +1988,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentAllowedClipboardFormat
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentAllowedClipboardFormats {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentAllowedClipboardFormats").field(&self.0).finish()
@@ -2052,6 +2054,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentAvailablePrinters {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentAvailablePrinters {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentAvailablePrinters").field(&self.0).finish()
@@ -2115,6 +2118,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentClipboardCopyPasteDire
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentClipboardCopyPasteDirections {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentClipboardCopyPasteDirections").field(&self.0).finish()
@@ -2178,6 +2182,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentCreateStatus {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentCreateStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentCreateStatus").field(&self.0).finish()
@@ -2201,6 +2206,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentCreationPriority {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentCreationPriority {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentCreationPriority").field(&self.0).finish()
@@ -2227,6 +2233,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentHostError {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentHostError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentHostError").field(&self.0).finish()
@@ -2254,6 +2261,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentLaunchFileStatus {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentLaunchFileStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentLaunchFileStatus").field(&self.0).finish()
@@ -2280,6 +2288,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentOwnerRegistrationStatu
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentOwnerRegistrationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentOwnerRegistrationStatus").field(&self.0).finish()
@@ -2304,6 +2313,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentPostMessageStatus {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentPostMessageStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentPostMessageStatus").field(&self.0).finish()
@@ -2328,6 +2338,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentProcessState {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentProcessState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentProcessState").field(&self.0).finish()
@@ -2356,6 +2367,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentProgressState {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentProgressState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentProgressState").field(&self.0).finish()
@@ -2383,6 +2395,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentShareFileStatus {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentShareFileStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentShareFileStatus").field(&self.0).finish()
@@ -2409,6 +2422,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentShareFolderStatus {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentShareFolderStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentShareFolderStatus").field(&self.0).finish()
@@ -2436,6 +2450,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentSignInProgress {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentSignInProgress {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentSignInProgress").field(&self.0).finish()
@@ -2462,6 +2477,7 @@ impl windows_core::TypeKind for IsolatedWindowsEnvironmentStartProcessStatus {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolatedWindowsEnvironmentStartProcessStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolatedWindowsEnvironmentStartProcessStatus").field(&self.0).finish()
@@ -2472,7 +2488,8 @@ impl windows_core::RuntimeType for IsolatedWindowsEnvironmentStartProcessStatus
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Security.Isolation.IsolatedWindowsEnvironmentStartProcessStatus;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IsolatedWindowsEnvironmentCreateProgress {
     pub State: IsolatedWindowsEnvironmentProgressState,
     pub PercentComplete: u32,
@@ -2628,3 +2645,5 @@ pub struct MessageReceivedCallback_Vtbl {
     #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
     Invoke: usize,
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
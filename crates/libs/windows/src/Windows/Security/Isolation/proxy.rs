@@ -0,0 +1,1124 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironment_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment", "Id", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn StartProcessSilentlyAsync(this: *mut core::ffi::c_void, hostexepath: core::mem::MaybeUninit<windows_core::HSTRING>, arguments: core::mem::MaybeUninit<windows_core::HSTRING>, activator: IsolatedWindowsEnvironmentActivator, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment", "StartProcessSilentlyAsync", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment_Vtbl)).StartProcessSilentlyAsync)(this, hostexepath, arguments, activator, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn StartProcessSilentlyWithTelemetryAsync(this: *mut core::ffi::c_void, hostexepath: core::mem::MaybeUninit<windows_core::HSTRING>, arguments: core::mem::MaybeUninit<windows_core::HSTRING>, activator: IsolatedWindowsEnvironmentActivator, telemetryparameters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment", "StartProcessSilentlyWithTelemetryAsync", 8usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment_Vtbl)).StartProcessSilentlyWithTelemetryAsync)(this, hostexepath, arguments, activator, telemetryparameters, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ShareFolderAsync(this: *mut core::ffi::c_void, hostfolder: core::mem::MaybeUninit<windows_core::HSTRING>, requestoptions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment", "ShareFolderAsync", 9usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment_Vtbl)).ShareFolderAsync)(this, hostfolder, requestoptions, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ShareFolderWithTelemetryAsync(this: *mut core::ffi::c_void, hostfolder: core::mem::MaybeUninit<windows_core::HSTRING>, requestoptions: *mut core::ffi::c_void, telemetryparameters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment", "ShareFolderWithTelemetryAsync", 10usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment_Vtbl)).ShareFolderWithTelemetryAsync)(this, hostfolder, requestoptions, telemetryparameters, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn LaunchFileWithUIAsync(this: *mut core::ffi::c_void, appexepath: core::mem::MaybeUninit<windows_core::HSTRING>, argumentstemplate: core::mem::MaybeUninit<windows_core::HSTRING>, filepath: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment", "LaunchFileWithUIAsync", 11usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment_Vtbl)).LaunchFileWithUIAsync)(this, appexepath, argumentstemplate, filepath, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn LaunchFileWithUIAndTelemetryAsync(this: *mut core::ffi::c_void, appexepath: core::mem::MaybeUninit<windows_core::HSTRING>, argumentstemplate: core::mem::MaybeUninit<windows_core::HSTRING>, filepath: core::mem::MaybeUninit<windows_core::HSTRING>, telemetryparameters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment", "LaunchFileWithUIAndTelemetryAsync", 12usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment_Vtbl)).LaunchFileWithUIAndTelemetryAsync)(this, appexepath, argumentstemplate, filepath, telemetryparameters, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TerminateAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment", "TerminateAsync", 13usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment_Vtbl)).TerminateAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TerminateWithTelemetryAsync(this: *mut core::ffi::c_void, telemetryparameters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment", "TerminateWithTelemetryAsync", 14usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment_Vtbl)).TerminateWithTelemetryAsync)(this, telemetryparameters, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn RegisterMessageReceiver(this: *mut core::ffi::c_void, receiverid: windows_core::GUID, messagereceivedcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment", "RegisterMessageReceiver", 15usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment_Vtbl)).RegisterMessageReceiver)(this, receiverid, messagereceivedcallback), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn UnregisterMessageReceiver(this: *mut core::ffi::c_void, receiverid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment", "UnregisterMessageReceiver", 16usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment_Vtbl)).UnregisterMessageReceiver)(this, receiverid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Id,
+            #[cfg(not(feature = "deprecated"))]
+            Id: 0,
+            #[cfg(feature = "deprecated")]
+            StartProcessSilentlyAsync,
+            #[cfg(not(feature = "deprecated"))]
+            StartProcessSilentlyAsync: 0,
+            #[cfg(feature = "deprecated")]
+            StartProcessSilentlyWithTelemetryAsync,
+            #[cfg(not(feature = "deprecated"))]
+            StartProcessSilentlyWithTelemetryAsync: 0,
+            #[cfg(feature = "deprecated")]
+            ShareFolderAsync,
+            #[cfg(not(feature = "deprecated"))]
+            ShareFolderAsync: 0,
+            #[cfg(feature = "deprecated")]
+            ShareFolderWithTelemetryAsync,
+            #[cfg(not(feature = "deprecated"))]
+            ShareFolderWithTelemetryAsync: 0,
+            #[cfg(feature = "deprecated")]
+            LaunchFileWithUIAsync,
+            #[cfg(not(feature = "deprecated"))]
+            LaunchFileWithUIAsync: 0,
+            #[cfg(feature = "deprecated")]
+            LaunchFileWithUIAndTelemetryAsync,
+            #[cfg(not(feature = "deprecated"))]
+            LaunchFileWithUIAndTelemetryAsync: 0,
+            #[cfg(feature = "deprecated")]
+            TerminateAsync,
+            #[cfg(not(feature = "deprecated"))]
+            TerminateAsync: 0,
+            #[cfg(feature = "deprecated")]
+            TerminateWithTelemetryAsync,
+            #[cfg(not(feature = "deprecated"))]
+            TerminateWithTelemetryAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            RegisterMessageReceiver,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            RegisterMessageReceiver: 0,
+            #[cfg(feature = "deprecated")]
+            UnregisterMessageReceiver,
+            #[cfg(not(feature = "deprecated"))]
+            UnregisterMessageReceiver: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironment2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn PostMessageToReceiverAsync(this: *mut core::ffi::c_void, receiverid: windows_core::GUID, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment2", "PostMessageToReceiverAsync", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment2_Vtbl)).PostMessageToReceiverAsync)(this, receiverid, message, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn PostMessageToReceiverWithTelemetryAsync(this: *mut core::ffi::c_void, receiverid: windows_core::GUID, message: *mut core::ffi::c_void, telemetryparameters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment2", "PostMessageToReceiverWithTelemetryAsync", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment2_Vtbl)).PostMessageToReceiverWithTelemetryAsync)(this, receiverid, message, telemetryparameters, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            PostMessageToReceiverAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            PostMessageToReceiverAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            PostMessageToReceiverWithTelemetryAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            PostMessageToReceiverWithTelemetryAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironment2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironment3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetUserInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment3", "GetUserInfo", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment3_Vtbl)).GetUserInfo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ShareFileAsync(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::HSTRING>, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment3", "ShareFileAsync", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment3_Vtbl)).ShareFileAsync)(this, filepath, options, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ShareFileWithTelemetryAsync(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::HSTRING>, options: *mut core::ffi::c_void, telemetryparameters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment3", "ShareFileWithTelemetryAsync", 8usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment3_Vtbl)).ShareFileWithTelemetryAsync)(this, filepath, options, telemetryparameters, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            GetUserInfo,
+            #[cfg(not(feature = "deprecated"))]
+            GetUserInfo: 0,
+            #[cfg(feature = "deprecated")]
+            ShareFileAsync,
+            #[cfg(not(feature = "deprecated"))]
+            ShareFileAsync: 0,
+            #[cfg(feature = "deprecated")]
+            ShareFileWithTelemetryAsync,
+            #[cfg(not(feature = "deprecated"))]
+            ShareFileWithTelemetryAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironment3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironment4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ChangePriority(this: *mut core::ffi::c_void, priority: IsolatedWindowsEnvironmentCreationPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironment4", "ChangePriority", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironment4_Vtbl)).ChangePriority)(this, priority), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            ChangePriority,
+            #[cfg(not(feature = "deprecated"))]
+            ChangePriority: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironment4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentCreateResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut IsolatedWindowsEnvironmentCreateStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentCreateResult", "Status", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentCreateResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentCreateResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentCreateResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Environment(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentCreateResult", "Environment", 8usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentCreateResult_Vtbl)).Environment)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Status,
+            #[cfg(not(feature = "deprecated"))]
+            Status: 0,
+            #[cfg(feature = "deprecated")]
+            ExtendedError,
+            #[cfg(not(feature = "deprecated"))]
+            ExtendedError: 0,
+            #[cfg(feature = "deprecated")]
+            Environment,
+            #[cfg(not(feature = "deprecated"))]
+            Environment: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentCreateResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentCreateResult2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ChangeCreationPriority(this: *mut core::ffi::c_void, priority: IsolatedWindowsEnvironmentCreationPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentCreateResult2", "ChangeCreationPriority", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentCreateResult2_Vtbl)).ChangeCreationPriority)(this, priority), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            ChangeCreationPriority,
+            #[cfg(not(feature = "deprecated"))]
+            ChangeCreationPriority: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentCreateResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreateAsync(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentFactory", "CreateAsync", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentFactory_Vtbl)).CreateAsync)(this, options, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreateWithTelemetryAsync(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, telemetryparameters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentFactory", "CreateWithTelemetryAsync", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentFactory_Vtbl)).CreateWithTelemetryAsync)(this, options, telemetryparameters, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetById(this: *mut core::ffi::c_void, environmentid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentFactory", "GetById", 8usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentFactory_Vtbl)).GetById)(this, environmentid, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn FindByOwnerId(this: *mut core::ffi::c_void, environmentownerid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentFactory", "FindByOwnerId", 9usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentFactory_Vtbl)).FindByOwnerId)(this, environmentownerid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CreateAsync,
+            #[cfg(not(feature = "deprecated"))]
+            CreateAsync: 0,
+            #[cfg(feature = "deprecated")]
+            CreateWithTelemetryAsync,
+            #[cfg(not(feature = "deprecated"))]
+            CreateWithTelemetryAsync: 0,
+            #[cfg(feature = "deprecated")]
+            GetById,
+            #[cfg(not(feature = "deprecated"))]
+            GetById: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            FindByOwnerId,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            FindByOwnerId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentFile_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentFile", "Id", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentFile_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn HostPath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentFile", "HostPath", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentFile_Vtbl)).HostPath)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentFile", "Close", 8usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentFile_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Id,
+            #[cfg(not(feature = "deprecated"))]
+            Id: 0,
+            #[cfg(feature = "deprecated")]
+            HostPath,
+            #[cfg(not(feature = "deprecated"))]
+            HostPath: 0,
+            #[cfg(feature = "deprecated")]
+            Close,
+            #[cfg(not(feature = "deprecated"))]
+            Close: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentFile as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentFile2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GuestPath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentFile2", "GuestPath", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentFile2_Vtbl)).GuestPath)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsReadOnly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentFile2", "IsReadOnly", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentFile2_Vtbl)).IsReadOnly)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            GuestPath,
+            #[cfg(not(feature = "deprecated"))]
+            GuestPath: 0,
+            #[cfg(feature = "deprecated")]
+            IsReadOnly,
+            #[cfg(not(feature = "deprecated"))]
+            IsReadOnly: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentFile2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentHostStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsReady(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentHostStatics", "IsReady", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentHostStatics_Vtbl)).IsReady)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn HostErrors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentHostStatics", "HostErrors", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentHostStatics_Vtbl)).HostErrors)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            IsReady,
+            #[cfg(not(feature = "deprecated"))]
+            IsReady: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            HostErrors,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            HostErrors: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentHostStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentLaunchFileResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut IsolatedWindowsEnvironmentLaunchFileStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentLaunchFileResult", "Status", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentLaunchFileResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentLaunchFileResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentLaunchFileResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn File(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentLaunchFileResult", "File", 8usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentLaunchFileResult_Vtbl)).File)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Status,
+            #[cfg(not(feature = "deprecated"))]
+            Status: 0,
+            #[cfg(feature = "deprecated")]
+            ExtendedError,
+            #[cfg(not(feature = "deprecated"))]
+            ExtendedError: 0,
+            #[cfg(feature = "deprecated")]
+            File,
+            #[cfg(not(feature = "deprecated"))]
+            File: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentLaunchFileResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn EnvironmentOwnerId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "EnvironmentOwnerId", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).EnvironmentOwnerId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetEnvironmentOwnerId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "SetEnvironmentOwnerId", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).SetEnvironmentOwnerId)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AllowedClipboardFormats(this: *mut core::ffi::c_void, result__: *mut IsolatedWindowsEnvironmentAllowedClipboardFormats) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "AllowedClipboardFormats", 8usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).AllowedClipboardFormats)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetAllowedClipboardFormats(this: *mut core::ffi::c_void, value: IsolatedWindowsEnvironmentAllowedClipboardFormats) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "SetAllowedClipboardFormats", 9usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).SetAllowedClipboardFormats)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ClipboardCopyPasteDirections(this: *mut core::ffi::c_void, result__: *mut IsolatedWindowsEnvironmentClipboardCopyPasteDirections) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "ClipboardCopyPasteDirections", 10usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).ClipboardCopyPasteDirections)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetClipboardCopyPasteDirections(this: *mut core::ffi::c_void, value: IsolatedWindowsEnvironmentClipboardCopyPasteDirections) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "SetClipboardCopyPasteDirections", 11usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).SetClipboardCopyPasteDirections)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AvailablePrinters(this: *mut core::ffi::c_void, result__: *mut IsolatedWindowsEnvironmentAvailablePrinters) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "AvailablePrinters", 12usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).AvailablePrinters)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetAvailablePrinters(this: *mut core::ffi::c_void, value: IsolatedWindowsEnvironmentAvailablePrinters) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "SetAvailablePrinters", 13usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).SetAvailablePrinters)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SharedHostFolderPath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "SharedHostFolderPath", 14usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).SharedHostFolderPath)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SharedFolderNameInEnvironment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "SharedFolderNameInEnvironment", 15usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).SharedFolderNameInEnvironment)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ShareHostFolderForUntrustedItems(this: *mut core::ffi::c_void, sharedhostfolderpath: core::mem::MaybeUninit<windows_core::HSTRING>, sharefoldernameinenvironment: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "ShareHostFolderForUntrustedItems", 16usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).ShareHostFolderForUntrustedItems)(this, sharedhostfolderpath, sharefoldernameinenvironment), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PersistUserProfile(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "PersistUserProfile", 17usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).PersistUserProfile)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetPersistUserProfile(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "SetPersistUserProfile", 18usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).SetPersistUserProfile)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AllowGraphicsHardwareAcceleration(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "AllowGraphicsHardwareAcceleration", 19usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).AllowGraphicsHardwareAcceleration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetAllowGraphicsHardwareAcceleration(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "SetAllowGraphicsHardwareAcceleration", 20usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).SetAllowGraphicsHardwareAcceleration)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AllowCameraAndMicrophoneAccess(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "AllowCameraAndMicrophoneAccess", 21usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).AllowCameraAndMicrophoneAccess)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetAllowCameraAndMicrophoneAccess(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions", "SetAllowCameraAndMicrophoneAccess", 22usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions_Vtbl)).SetAllowCameraAndMicrophoneAccess)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            EnvironmentOwnerId,
+            #[cfg(not(feature = "deprecated"))]
+            EnvironmentOwnerId: 0,
+            #[cfg(feature = "deprecated")]
+            SetEnvironmentOwnerId,
+            #[cfg(not(feature = "deprecated"))]
+            SetEnvironmentOwnerId: 0,
+            #[cfg(feature = "deprecated")]
+            AllowedClipboardFormats,
+            #[cfg(not(feature = "deprecated"))]
+            AllowedClipboardFormats: 0,
+            #[cfg(feature = "deprecated")]
+            SetAllowedClipboardFormats,
+            #[cfg(not(feature = "deprecated"))]
+            SetAllowedClipboardFormats: 0,
+            #[cfg(feature = "deprecated")]
+            ClipboardCopyPasteDirections,
+            #[cfg(not(feature = "deprecated"))]
+            ClipboardCopyPasteDirections: 0,
+            #[cfg(feature = "deprecated")]
+            SetClipboardCopyPasteDirections,
+            #[cfg(not(feature = "deprecated"))]
+            SetClipboardCopyPasteDirections: 0,
+            #[cfg(feature = "deprecated")]
+            AvailablePrinters,
+            #[cfg(not(feature = "deprecated"))]
+            AvailablePrinters: 0,
+            #[cfg(feature = "deprecated")]
+            SetAvailablePrinters,
+            #[cfg(not(feature = "deprecated"))]
+            SetAvailablePrinters: 0,
+            #[cfg(feature = "deprecated")]
+            SharedHostFolderPath,
+            #[cfg(not(feature = "deprecated"))]
+            SharedHostFolderPath: 0,
+            #[cfg(feature = "deprecated")]
+            SharedFolderNameInEnvironment,
+            #[cfg(not(feature = "deprecated"))]
+            SharedFolderNameInEnvironment: 0,
+            #[cfg(feature = "deprecated")]
+            ShareHostFolderForUntrustedItems,
+            #[cfg(not(feature = "deprecated"))]
+            ShareHostFolderForUntrustedItems: 0,
+            #[cfg(feature = "deprecated")]
+            PersistUserProfile,
+            #[cfg(not(feature = "deprecated"))]
+            PersistUserProfile: 0,
+            #[cfg(feature = "deprecated")]
+            SetPersistUserProfile,
+            #[cfg(not(feature = "deprecated"))]
+            SetPersistUserProfile: 0,
+            #[cfg(feature = "deprecated")]
+            AllowGraphicsHardwareAcceleration,
+            #[cfg(not(feature = "deprecated"))]
+            AllowGraphicsHardwareAcceleration: 0,
+            #[cfg(feature = "deprecated")]
+            SetAllowGraphicsHardwareAcceleration,
+            #[cfg(not(feature = "deprecated"))]
+            SetAllowGraphicsHardwareAcceleration: 0,
+            #[cfg(feature = "deprecated")]
+            AllowCameraAndMicrophoneAccess,
+            #[cfg(not(feature = "deprecated"))]
+            AllowCameraAndMicrophoneAccess: 0,
+            #[cfg(feature = "deprecated")]
+            SetAllowCameraAndMicrophoneAccess,
+            #[cfg(not(feature = "deprecated"))]
+            SetAllowCameraAndMicrophoneAccess: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentOptions2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn WindowAnnotationOverride(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions2", "WindowAnnotationOverride", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions2_Vtbl)).WindowAnnotationOverride)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetWindowAnnotationOverride(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions2", "SetWindowAnnotationOverride", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions2_Vtbl)).SetWindowAnnotationOverride)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            WindowAnnotationOverride,
+            #[cfg(not(feature = "deprecated"))]
+            WindowAnnotationOverride: 0,
+            #[cfg(feature = "deprecated")]
+            SetWindowAnnotationOverride,
+            #[cfg(not(feature = "deprecated"))]
+            SetWindowAnnotationOverride: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentOptions2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentOptions3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AllowedClipboardFormatsToEnvironment(this: *mut core::ffi::c_void, result__: *mut IsolatedWindowsEnvironmentAllowedClipboardFormats) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions3", "AllowedClipboardFormatsToEnvironment", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions3_Vtbl)).AllowedClipboardFormatsToEnvironment)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetAllowedClipboardFormatsToEnvironment(this: *mut core::ffi::c_void, value: IsolatedWindowsEnvironmentAllowedClipboardFormats) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions3", "SetAllowedClipboardFormatsToEnvironment", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions3_Vtbl)).SetAllowedClipboardFormatsToEnvironment)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AllowedClipboardFormatsToHost(this: *mut core::ffi::c_void, result__: *mut IsolatedWindowsEnvironmentAllowedClipboardFormats) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions3", "AllowedClipboardFormatsToHost", 8usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions3_Vtbl)).AllowedClipboardFormatsToHost)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetAllowedClipboardFormatsToHost(this: *mut core::ffi::c_void, value: IsolatedWindowsEnvironmentAllowedClipboardFormats) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions3", "SetAllowedClipboardFormatsToHost", 9usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions3_Vtbl)).SetAllowedClipboardFormatsToHost)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreationPriority(this: *mut core::ffi::c_void, result__: *mut IsolatedWindowsEnvironmentCreationPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions3", "CreationPriority", 10usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions3_Vtbl)).CreationPriority)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetCreationPriority(this: *mut core::ffi::c_void, value: IsolatedWindowsEnvironmentCreationPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOptions3", "SetCreationPriority", 11usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOptions3_Vtbl)).SetCreationPriority)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            AllowedClipboardFormatsToEnvironment,
+            #[cfg(not(feature = "deprecated"))]
+            AllowedClipboardFormatsToEnvironment: 0,
+            #[cfg(feature = "deprecated")]
+            SetAllowedClipboardFormatsToEnvironment,
+            #[cfg(not(feature = "deprecated"))]
+            SetAllowedClipboardFormatsToEnvironment: 0,
+            #[cfg(feature = "deprecated")]
+            AllowedClipboardFormatsToHost,
+            #[cfg(not(feature = "deprecated"))]
+            AllowedClipboardFormatsToHost: 0,
+            #[cfg(feature = "deprecated")]
+            SetAllowedClipboardFormatsToHost,
+            #[cfg(not(feature = "deprecated"))]
+            SetAllowedClipboardFormatsToHost: 0,
+            #[cfg(feature = "deprecated")]
+            CreationPriority,
+            #[cfg(not(feature = "deprecated"))]
+            CreationPriority: 0,
+            #[cfg(feature = "deprecated")]
+            SetCreationPriority,
+            #[cfg(not(feature = "deprecated"))]
+            SetCreationPriority: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentOptions3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentOwnerRegistrationData_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn ShareableFolders(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOwnerRegistrationData", "ShareableFolders", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOwnerRegistrationData_Vtbl)).ShareableFolders)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn ProcessesRunnableAsSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOwnerRegistrationData", "ProcessesRunnableAsSystem", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOwnerRegistrationData_Vtbl)).ProcessesRunnableAsSystem)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn ProcessesRunnableAsUser(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOwnerRegistrationData", "ProcessesRunnableAsUser", 8usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOwnerRegistrationData_Vtbl)).ProcessesRunnableAsUser)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn ActivationFileExtensions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOwnerRegistrationData", "ActivationFileExtensions", 9usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOwnerRegistrationData_Vtbl)).ActivationFileExtensions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            ShareableFolders,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            ShareableFolders: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            ProcessesRunnableAsSystem,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            ProcessesRunnableAsSystem: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            ProcessesRunnableAsUser,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            ProcessesRunnableAsUser: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            ActivationFileExtensions,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            ActivationFileExtensions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentOwnerRegistrationData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentOwnerRegistrationResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut IsolatedWindowsEnvironmentOwnerRegistrationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOwnerRegistrationResult", "Status", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOwnerRegistrationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOwnerRegistrationResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOwnerRegistrationResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Status,
+            #[cfg(not(feature = "deprecated"))]
+            Status: 0,
+            #[cfg(feature = "deprecated")]
+            ExtendedError,
+            #[cfg(not(feature = "deprecated"))]
+            ExtendedError: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentOwnerRegistrationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentOwnerRegistrationStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void, ownername: core::mem::MaybeUninit<windows_core::HSTRING>, ownerregistrationdata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOwnerRegistrationStatics", "Register", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOwnerRegistrationStatics_Vtbl)).Register)(this, ownername, ownerregistrationdata, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Unregister(this: *mut core::ffi::c_void, ownername: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentOwnerRegistrationStatics", "Unregister", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentOwnerRegistrationStatics_Vtbl)).Unregister)(this, ownername), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Register,
+            #[cfg(not(feature = "deprecated"))]
+            Register: 0,
+            #[cfg(feature = "deprecated")]
+            Unregister,
+            #[cfg(not(feature = "deprecated"))]
+            Unregister: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentOwnerRegistrationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentPostMessageResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut IsolatedWindowsEnvironmentPostMessageStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentPostMessageResult", "Status", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentPostMessageResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentPostMessageResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentPostMessageResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Status,
+            #[cfg(not(feature = "deprecated"))]
+            Status: 0,
+            #[cfg(feature = "deprecated")]
+            ExtendedError,
+            #[cfg(not(feature = "deprecated"))]
+            ExtendedError: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentPostMessageResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentProcess_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut IsolatedWindowsEnvironmentProcessState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentProcess", "State", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentProcess_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ExitCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentProcess", "ExitCode", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentProcess_Vtbl)).ExitCode)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn WaitForExit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentProcess", "WaitForExit", 8usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentProcess_Vtbl)).WaitForExit)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn WaitForExitWithTimeout(this: *mut core::ffi::c_void, timeoutmilliseconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentProcess", "WaitForExitWithTimeout", 9usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentProcess_Vtbl)).WaitForExitWithTimeout)(this, timeoutmilliseconds), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn WaitForExitAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentProcess", "WaitForExitAsync", 10usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentProcess_Vtbl)).WaitForExitAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            State,
+            #[cfg(not(feature = "deprecated"))]
+            State: 0,
+            #[cfg(feature = "deprecated")]
+            ExitCode,
+            #[cfg(not(feature = "deprecated"))]
+            ExitCode: 0,
+            #[cfg(feature = "deprecated")]
+            WaitForExit,
+            #[cfg(not(feature = "deprecated"))]
+            WaitForExit: 0,
+            #[cfg(feature = "deprecated")]
+            WaitForExitWithTimeout,
+            #[cfg(not(feature = "deprecated"))]
+            WaitForExitWithTimeout: 0,
+            #[cfg(feature = "deprecated")]
+            WaitForExitAsync,
+            #[cfg(not(feature = "deprecated"))]
+            WaitForExitAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentProcess as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentShareFileRequestOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AllowWrite(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentShareFileRequestOptions", "AllowWrite", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentShareFileRequestOptions_Vtbl)).AllowWrite)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetAllowWrite(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentShareFileRequestOptions", "SetAllowWrite", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentShareFileRequestOptions_Vtbl)).SetAllowWrite)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            AllowWrite,
+            #[cfg(not(feature = "deprecated"))]
+            AllowWrite: 0,
+            #[cfg(feature = "deprecated")]
+            SetAllowWrite,
+            #[cfg(not(feature = "deprecated"))]
+            SetAllowWrite: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentShareFileRequestOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentShareFileResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut IsolatedWindowsEnvironmentShareFileStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentShareFileResult", "Status", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentShareFileResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentShareFileResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentShareFileResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn File(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentShareFileResult", "File", 8usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentShareFileResult_Vtbl)).File)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Status,
+            #[cfg(not(feature = "deprecated"))]
+            Status: 0,
+            #[cfg(feature = "deprecated")]
+            ExtendedError,
+            #[cfg(not(feature = "deprecated"))]
+            ExtendedError: 0,
+            #[cfg(feature = "deprecated")]
+            File,
+            #[cfg(not(feature = "deprecated"))]
+            File: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentShareFileResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentShareFolderRequestOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AllowWrite(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentShareFolderRequestOptions", "AllowWrite", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentShareFolderRequestOptions_Vtbl)).AllowWrite)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetAllowWrite(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentShareFolderRequestOptions", "SetAllowWrite", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentShareFolderRequestOptions_Vtbl)).SetAllowWrite)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            AllowWrite,
+            #[cfg(not(feature = "deprecated"))]
+            AllowWrite: 0,
+            #[cfg(feature = "deprecated")]
+            SetAllowWrite,
+            #[cfg(not(feature = "deprecated"))]
+            SetAllowWrite: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentShareFolderRequestOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentShareFolderResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut IsolatedWindowsEnvironmentShareFolderStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentShareFolderResult", "Status", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentShareFolderResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentShareFolderResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentShareFolderResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Status,
+            #[cfg(not(feature = "deprecated"))]
+            Status: 0,
+            #[cfg(feature = "deprecated")]
+            ExtendedError,
+            #[cfg(not(feature = "deprecated"))]
+            ExtendedError: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentShareFolderResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentStartProcessResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut IsolatedWindowsEnvironmentStartProcessStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentStartProcessResult", "Status", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentStartProcessResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentStartProcessResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentStartProcessResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Process(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentStartProcessResult", "Process", 8usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentStartProcessResult_Vtbl)).Process)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Status,
+            #[cfg(not(feature = "deprecated"))]
+            Status: 0,
+            #[cfg(feature = "deprecated")]
+            ExtendedError,
+            #[cfg(not(feature = "deprecated"))]
+            ExtendedError: 0,
+            #[cfg(feature = "deprecated")]
+            Process,
+            #[cfg(not(feature = "deprecated"))]
+            Process: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentStartProcessResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentTelemetryParameters_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CorrelationId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentTelemetryParameters", "CorrelationId", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentTelemetryParameters_Vtbl)).CorrelationId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetCorrelationId(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentTelemetryParameters", "SetCorrelationId", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentTelemetryParameters_Vtbl)).SetCorrelationId)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CorrelationId,
+            #[cfg(not(feature = "deprecated"))]
+            CorrelationId: 0,
+            #[cfg(feature = "deprecated")]
+            SetCorrelationId,
+            #[cfg(not(feature = "deprecated"))]
+            SetCorrelationId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentTelemetryParameters as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentUserInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn EnvironmentUserSid(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentUserInfo", "EnvironmentUserSid", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentUserInfo_Vtbl)).EnvironmentUserSid)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn EnvironmentUserName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentUserInfo", "EnvironmentUserName", 7usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentUserInfo_Vtbl)).EnvironmentUserName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TryWaitForSignInAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentUserInfo", "TryWaitForSignInAsync", 8usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentUserInfo_Vtbl)).TryWaitForSignInAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            EnvironmentUserSid,
+            #[cfg(not(feature = "deprecated"))]
+            EnvironmentUserSid: 0,
+            #[cfg(feature = "deprecated")]
+            EnvironmentUserName,
+            #[cfg(not(feature = "deprecated"))]
+            EnvironmentUserName: 0,
+            #[cfg(feature = "deprecated")]
+            TryWaitForSignInAsync,
+            #[cfg(not(feature = "deprecated"))]
+            TryWaitForSignInAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentUserInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsEnvironmentUserInfo2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TryWaitForSignInWithProgressAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsEnvironmentUserInfo2", "TryWaitForSignInWithProgressAsync", 6usize, |this| ((**(this as *const *const IIsolatedWindowsEnvironmentUserInfo2_Vtbl)).TryWaitForSignInWithProgressAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            TryWaitForSignInWithProgressAsync,
+            #[cfg(not(feature = "deprecated"))]
+            TryWaitForSignInWithProgressAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsEnvironmentUserInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsHostMessengerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn PostMessageToReceiver(this: *mut core::ffi::c_void, receiverid: windows_core::GUID, message: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsHostMessengerStatics", "PostMessageToReceiver", 6usize, |this| ((**(this as *const *const IIsolatedWindowsHostMessengerStatics_Vtbl)).PostMessageToReceiver)(this, receiverid, message), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetFileId(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsHostMessengerStatics", "GetFileId", 7usize, |this| ((**(this as *const *const IIsolatedWindowsHostMessengerStatics_Vtbl)).GetFileId)(this, filepath, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            PostMessageToReceiver,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            PostMessageToReceiver: 0,
+            #[cfg(feature = "deprecated")]
+            GetFileId,
+            #[cfg(not(feature = "deprecated"))]
+            GetFileId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsHostMessengerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IIsolatedWindowsHostMessengerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn RegisterHostMessageReceiver(this: *mut core::ffi::c_void, receiverid: windows_core::GUID, hostmessagereceivedcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsHostMessengerStatics2", "RegisterHostMessageReceiver", 6usize, |this| ((**(this as *const *const IIsolatedWindowsHostMessengerStatics2_Vtbl)).RegisterHostMessageReceiver)(this, receiverid, hostmessagereceivedcallback), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn UnregisterHostMessageReceiver(this: *mut core::ffi::c_void, receiverid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedWindowsHostMessengerStatics2", "UnregisterHostMessageReceiver", 7usize, |this| ((**(this as *const *const IIsolatedWindowsHostMessengerStatics2_Vtbl)).UnregisterHostMessageReceiver)(this, receiverid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            RegisterHostMessageReceiver,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            RegisterHostMessageReceiver: 0,
+            #[cfg(feature = "deprecated")]
+            UnregisterHostMessageReceiver,
+            #[cfg(not(feature = "deprecated"))]
+            UnregisterHostMessageReceiver: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedWindowsHostMessengerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1205,6 +1205,7 @@ impl GuidanceAudioMeasurementSystem {
 impl windows_core::TypeKind for GuidanceAudioMeasurementSystem {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GuidanceAudioMeasurementSystem {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GuidanceAudioMeasurementSystem").field(&self.0).finish()
@@ -1227,6 +1228,7 @@ impl GuidanceAudioNotificationKind {
 impl windows_core::TypeKind for GuidanceAudioNotificationKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GuidanceAudioNotificationKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GuidanceAudioNotificationKind").field(&self.0).finish()
@@ -1250,6 +1252,7 @@ impl GuidanceAudioNotifications {
 impl windows_core::TypeKind for GuidanceAudioNotifications {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GuidanceAudioNotifications {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GuidanceAudioNotifications").field(&self.0).finish()
@@ -1310,6 +1313,7 @@ impl GuidanceLaneMarkers {
 impl windows_core::TypeKind for GuidanceLaneMarkers {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GuidanceLaneMarkers {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GuidanceLaneMarkers").field(&self.0).finish()
@@ -1407,6 +1411,7 @@ impl GuidanceManeuverKind {
 impl windows_core::TypeKind for GuidanceManeuverKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GuidanceManeuverKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GuidanceManeuverKind").field(&self.0).finish()
@@ -1427,6 +1432,7 @@ impl GuidanceMode {
 impl windows_core::TypeKind for GuidanceMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GuidanceMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GuidanceMode").field(&self.0).finish()
@@ -1435,3 +1441,5 @@ impl core::fmt::Debug for GuidanceMode {
 impl windows_core::RuntimeType for GuidanceMode {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Services.Maps.Guidance.GuidanceMode;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
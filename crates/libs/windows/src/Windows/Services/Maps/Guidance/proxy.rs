@@ -0,0 +1,640 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGuidanceAudioNotificationRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AudioNotification(this: *mut core::ffi::c_void, result__: *mut GuidanceAudioNotificationKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceAudioNotificationRequestedEventArgs", "AudioNotification", 6usize, |this| ((**(this as *const *const IGuidanceAudioNotificationRequestedEventArgs_Vtbl)).AudioNotification)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AudioFilePaths(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceAudioNotificationRequestedEventArgs", "AudioFilePaths", 7usize, |this| ((**(this as *const *const IGuidanceAudioNotificationRequestedEventArgs_Vtbl)).AudioFilePaths)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceAudioNotificationRequestedEventArgs", "AudioText", 8usize, |this| ((**(this as *const *const IGuidanceAudioNotificationRequestedEventArgs_Vtbl)).AudioText)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AudioNotification,
+            #[cfg(feature = "Foundation_Collections")]
+            AudioFilePaths,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AudioFilePaths: 0,
+            AudioText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceAudioNotificationRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGuidanceLaneInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LaneMarkers(this: *mut core::ffi::c_void, result__: *mut GuidanceLaneMarkers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceLaneInfo", "LaneMarkers", 6usize, |this| ((**(this as *const *const IGuidanceLaneInfo_Vtbl)).LaneMarkers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOnRoute(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceLaneInfo", "IsOnRoute", 7usize, |this| ((**(this as *const *const IGuidanceLaneInfo_Vtbl)).IsOnRoute)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LaneMarkers, IsOnRoute }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceLaneInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for IGuidanceManeuver_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn StartLocation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceManeuver", "StartLocation", 6usize, |this| ((**(this as *const *const IGuidanceManeuver_Vtbl)).StartLocation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DistanceFromRouteStart(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceManeuver", "DistanceFromRouteStart", 7usize, |this| ((**(this as *const *const IGuidanceManeuver_Vtbl)).DistanceFromRouteStart)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DistanceFromPreviousManeuver(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceManeuver", "DistanceFromPreviousManeuver", 8usize, |this| ((**(this as *const *const IGuidanceManeuver_Vtbl)).DistanceFromPreviousManeuver)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DepartureRoadName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceManeuver", "DepartureRoadName", 9usize, |this| ((**(this as *const *const IGuidanceManeuver_Vtbl)).DepartureRoadName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextRoadName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceManeuver", "NextRoadName", 10usize, |this| ((**(this as *const *const IGuidanceManeuver_Vtbl)).NextRoadName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DepartureShortRoadName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceManeuver", "DepartureShortRoadName", 11usize, |this| ((**(this as *const *const IGuidanceManeuver_Vtbl)).DepartureShortRoadName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextShortRoadName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceManeuver", "NextShortRoadName", 12usize, |this| ((**(this as *const *const IGuidanceManeuver_Vtbl)).NextShortRoadName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut GuidanceManeuverKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceManeuver", "Kind", 13usize, |this| ((**(this as *const *const IGuidanceManeuver_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAngle(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceManeuver", "StartAngle", 14usize, |this| ((**(this as *const *const IGuidanceManeuver_Vtbl)).StartAngle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndAngle(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceManeuver", "EndAngle", 15usize, |this| ((**(this as *const *const IGuidanceManeuver_Vtbl)).EndAngle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoadSignpost(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceManeuver", "RoadSignpost", 16usize, |this| ((**(this as *const *const IGuidanceManeuver_Vtbl)).RoadSignpost)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstructionText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceManeuver", "InstructionText", 17usize, |this| ((**(this as *const *const IGuidanceManeuver_Vtbl)).InstructionText)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            StartLocation,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            StartLocation: 0,
+            DistanceFromRouteStart,
+            DistanceFromPreviousManeuver,
+            DepartureRoadName,
+            NextRoadName,
+            DepartureShortRoadName,
+            NextShortRoadName,
+            Kind,
+            StartAngle,
+            EndAngle,
+            RoadSignpost,
+            InstructionText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceManeuver as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for IGuidanceMapMatchedCoordinate_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn Location(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceMapMatchedCoordinate", "Location", 6usize, |this| ((**(this as *const *const IGuidanceMapMatchedCoordinate_Vtbl)).Location)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentHeading(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceMapMatchedCoordinate", "CurrentHeading", 7usize, |this| ((**(this as *const *const IGuidanceMapMatchedCoordinate_Vtbl)).CurrentHeading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentSpeed(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceMapMatchedCoordinate", "CurrentSpeed", 8usize, |this| ((**(this as *const *const IGuidanceMapMatchedCoordinate_Vtbl)).CurrentSpeed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOnStreet(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceMapMatchedCoordinate", "IsOnStreet", 9usize, |this| ((**(this as *const *const IGuidanceMapMatchedCoordinate_Vtbl)).IsOnStreet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Road(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceMapMatchedCoordinate", "Road", 10usize, |this| ((**(this as *const *const IGuidanceMapMatchedCoordinate_Vtbl)).Road)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            Location,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            Location: 0,
+            CurrentHeading,
+            CurrentSpeed,
+            IsOnStreet,
+            Road,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceMapMatchedCoordinate as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for IGuidanceNavigator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartNavigating(this: *mut core::ffi::c_void, route: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "StartNavigating", 6usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).StartNavigating)(this, route), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartSimulating(this: *mut core::ffi::c_void, route: *mut core::ffi::c_void, speedinmeterspersecond: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "StartSimulating", 7usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).StartSimulating)(this, route, speedinmeterspersecond), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTracking(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "StartTracking", 8usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).StartTracking)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "Pause", 9usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "Resume", 10usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "Stop", 11usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RepeatLastAudioNotification(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "RepeatLastAudioNotification", 12usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).RepeatLastAudioNotification)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioMeasurementSystem(this: *mut core::ffi::c_void, result__: *mut GuidanceAudioMeasurementSystem) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "AudioMeasurementSystem", 13usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).AudioMeasurementSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioMeasurementSystem(this: *mut core::ffi::c_void, value: GuidanceAudioMeasurementSystem) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "SetAudioMeasurementSystem", 14usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).SetAudioMeasurementSystem)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioNotifications(this: *mut core::ffi::c_void, result__: *mut GuidanceAudioNotifications) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "AudioNotifications", 15usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).AudioNotifications)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioNotifications(this: *mut core::ffi::c_void, value: GuidanceAudioNotifications) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "SetAudioNotifications", 16usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).SetAudioNotifications)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GuidanceUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "GuidanceUpdated", 17usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).GuidanceUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGuidanceUpdated(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "RemoveGuidanceUpdated", 18usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).RemoveGuidanceUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationReached(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "DestinationReached", 19usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).DestinationReached)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDestinationReached(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "RemoveDestinationReached", 20usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).RemoveDestinationReached)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rerouting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "Rerouting", 21usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).Rerouting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRerouting(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "RemoveRerouting", 22usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).RemoveRerouting)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rerouted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "Rerouted", 23usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).Rerouted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRerouted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "RemoveRerouted", 24usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).RemoveRerouted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RerouteFailed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "RerouteFailed", 25usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).RerouteFailed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRerouteFailed(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "RemoveRerouteFailed", 26usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).RemoveRerouteFailed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserLocationLost(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "UserLocationLost", 27usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).UserLocationLost)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUserLocationLost(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "RemoveUserLocationLost", 28usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).RemoveUserLocationLost)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserLocationRestored(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "UserLocationRestored", 29usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).UserLocationRestored)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUserLocationRestored(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "RemoveUserLocationRestored", 30usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).RemoveUserLocationRestored)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGuidanceVoice(this: *mut core::ffi::c_void, voiceid: i32, voicefolder: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "SetGuidanceVoice", 31usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).SetGuidanceVoice)(this, voiceid, voicefolder), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn UpdateUserLocation(this: *mut core::ffi::c_void, userlocation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "UpdateUserLocation", 32usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).UpdateUserLocation)(this, userlocation), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn UpdateUserLocationWithPositionOverride(this: *mut core::ffi::c_void, userlocation: *mut core::ffi::c_void, positionoverride: super::super::super::Devices::Geolocation::BasicGeoposition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator", "UpdateUserLocationWithPositionOverride", 33usize, |this| ((**(this as *const *const IGuidanceNavigator_Vtbl)).UpdateUserLocationWithPositionOverride)(this, userlocation, positionoverride), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartNavigating,
+            StartSimulating,
+            StartTracking,
+            Pause,
+            Resume,
+            Stop,
+            RepeatLastAudioNotification,
+            AudioMeasurementSystem,
+            SetAudioMeasurementSystem,
+            AudioNotifications,
+            SetAudioNotifications,
+            GuidanceUpdated,
+            RemoveGuidanceUpdated,
+            DestinationReached,
+            RemoveDestinationReached,
+            Rerouting,
+            RemoveRerouting,
+            Rerouted,
+            RemoveRerouted,
+            RerouteFailed,
+            RemoveRerouteFailed,
+            UserLocationLost,
+            RemoveUserLocationLost,
+            UserLocationRestored,
+            RemoveUserLocationRestored,
+            SetGuidanceVoice,
+            #[cfg(feature = "Devices_Geolocation")]
+            UpdateUserLocation,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            UpdateUserLocation: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            UpdateUserLocationWithPositionOverride,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            UpdateUserLocationWithPositionOverride: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceNavigator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGuidanceNavigator2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AudioNotificationRequested(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator2", "AudioNotificationRequested", 6usize, |this| ((**(this as *const *const IGuidanceNavigator2_Vtbl)).AudioNotificationRequested)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAudioNotificationRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator2", "RemoveAudioNotificationRequested", 7usize, |this| ((**(this as *const *const IGuidanceNavigator2_Vtbl)).RemoveAudioNotificationRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGuidanceAudioMuted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator2", "IsGuidanceAudioMuted", 8usize, |this| ((**(this as *const *const IGuidanceNavigator2_Vtbl)).IsGuidanceAudioMuted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsGuidanceAudioMuted(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigator2", "SetIsGuidanceAudioMuted", 9usize, |this| ((**(this as *const *const IGuidanceNavigator2_Vtbl)).SetIsGuidanceAudioMuted)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AudioNotificationRequested,
+            RemoveAudioNotificationRequested,
+            IsGuidanceAudioMuted,
+            SetIsGuidanceAudioMuted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceNavigator2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGuidanceNavigatorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigatorStatics", "GetCurrent", 6usize, |this| ((**(this as *const *const IGuidanceNavigatorStatics_Vtbl)).GetCurrent)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceNavigatorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGuidanceNavigatorStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UseAppProvidedVoice(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceNavigatorStatics2", "UseAppProvidedVoice", 6usize, |this| ((**(this as *const *const IGuidanceNavigatorStatics2_Vtbl)).UseAppProvidedVoice)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UseAppProvidedVoice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceNavigatorStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGuidanceReroutedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Route(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceReroutedEventArgs", "Route", 6usize, |this| ((**(this as *const *const IGuidanceReroutedEventArgs_Vtbl)).Route)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Route }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceReroutedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for IGuidanceRoadSegment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RoadName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoadSegment", "RoadName", 6usize, |this| ((**(this as *const *const IGuidanceRoadSegment_Vtbl)).RoadName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShortRoadName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoadSegment", "ShortRoadName", 7usize, |this| ((**(this as *const *const IGuidanceRoadSegment_Vtbl)).ShortRoadName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpeedLimit(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoadSegment", "SpeedLimit", 8usize, |this| ((**(this as *const *const IGuidanceRoadSegment_Vtbl)).SpeedLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TravelTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoadSegment", "TravelTime", 9usize, |this| ((**(this as *const *const IGuidanceRoadSegment_Vtbl)).TravelTime)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoadSegment", "Path", 10usize, |this| ((**(this as *const *const IGuidanceRoadSegment_Vtbl)).Path)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoadSegment", "Id", 11usize, |this| ((**(this as *const *const IGuidanceRoadSegment_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHighway(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoadSegment", "IsHighway", 12usize, |this| ((**(this as *const *const IGuidanceRoadSegment_Vtbl)).IsHighway)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTunnel(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoadSegment", "IsTunnel", 13usize, |this| ((**(this as *const *const IGuidanceRoadSegment_Vtbl)).IsTunnel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTollRoad(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoadSegment", "IsTollRoad", 14usize, |this| ((**(this as *const *const IGuidanceRoadSegment_Vtbl)).IsTollRoad)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RoadName,
+            ShortRoadName,
+            SpeedLimit,
+            TravelTime,
+            #[cfg(feature = "Devices_Geolocation")]
+            Path,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            Path: 0,
+            Id,
+            IsHighway,
+            IsTunnel,
+            IsTollRoad,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceRoadSegment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGuidanceRoadSegment2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsScenic(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoadSegment2", "IsScenic", 6usize, |this| ((**(this as *const *const IGuidanceRoadSegment2_Vtbl)).IsScenic)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsScenic }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceRoadSegment2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "UI"))]
+impl windows_core::imp::ProxyVtable for IGuidanceRoadSignpost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExitNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoadSignpost", "ExitNumber", 6usize, |this| ((**(this as *const *const IGuidanceRoadSignpost_Vtbl)).ExitNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Exit(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoadSignpost", "Exit", 7usize, |this| ((**(this as *const *const IGuidanceRoadSignpost_Vtbl)).Exit)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn BackgroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoadSignpost", "BackgroundColor", 8usize, |this| ((**(this as *const *const IGuidanceRoadSignpost_Vtbl)).BackgroundColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn ForegroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoadSignpost", "ForegroundColor", 9usize, |this| ((**(this as *const *const IGuidanceRoadSignpost_Vtbl)).ForegroundColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ExitDirections(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoadSignpost", "ExitDirections", 10usize, |this| ((**(this as *const *const IGuidanceRoadSignpost_Vtbl)).ExitDirections)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ExitNumber,
+            Exit,
+            #[cfg(feature = "UI")]
+            BackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            BackgroundColor: 0,
+            #[cfg(feature = "UI")]
+            ForegroundColor,
+            #[cfg(not(feature = "UI"))]
+            ForegroundColor: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ExitDirections,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ExitDirections: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceRoadSignpost as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Geolocation", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IGuidanceRoute_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoute", "Duration", 6usize, |this| ((**(this as *const *const IGuidanceRoute_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Distance(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoute", "Distance", 7usize, |this| ((**(this as *const *const IGuidanceRoute_Vtbl)).Distance)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Maneuvers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoute", "Maneuvers", 8usize, |this| ((**(this as *const *const IGuidanceRoute_Vtbl)).Maneuvers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn BoundingBox(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoute", "BoundingBox", 9usize, |this| ((**(this as *const *const IGuidanceRoute_Vtbl)).BoundingBox)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoute", "Path", 10usize, |this| ((**(this as *const *const IGuidanceRoute_Vtbl)).Path)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RoadSegments(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoute", "RoadSegments", 11usize, |this| ((**(this as *const *const IGuidanceRoute_Vtbl)).RoadSegments)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertToMapRoute(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRoute", "ConvertToMapRoute", 12usize, |this| ((**(this as *const *const IGuidanceRoute_Vtbl)).ConvertToMapRoute)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Duration,
+            Distance,
+            #[cfg(feature = "Foundation_Collections")]
+            Maneuvers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Maneuvers: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            BoundingBox,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            BoundingBox: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            Path,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            Path: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RoadSegments,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RoadSegments: 0,
+            ConvertToMapRoute,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceRoute as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGuidanceRouteStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanCreateFromMapRoute(this: *mut core::ffi::c_void, maproute: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRouteStatics", "CanCreateFromMapRoute", 6usize, |this| ((**(this as *const *const IGuidanceRouteStatics_Vtbl)).CanCreateFromMapRoute)(this, maproute, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryCreateFromMapRoute(this: *mut core::ffi::c_void, maproute: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceRouteStatics", "TryCreateFromMapRoute", 7usize, |this| ((**(this as *const *const IGuidanceRouteStatics_Vtbl)).TryCreateFromMapRoute)(this, maproute, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CanCreateFromMapRoute, TryCreateFromMapRoute }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceRouteStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGuidanceTelemetryCollector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceTelemetryCollector", "Enabled", 6usize, |this| ((**(this as *const *const IGuidanceTelemetryCollector_Vtbl)).Enabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceTelemetryCollector", "SetEnabled", 7usize, |this| ((**(this as *const *const IGuidanceTelemetryCollector_Vtbl)).SetEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearLocalData(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceTelemetryCollector", "ClearLocalData", 8usize, |this| ((**(this as *const *const IGuidanceTelemetryCollector_Vtbl)).ClearLocalData)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpeedTrigger(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceTelemetryCollector", "SpeedTrigger", 9usize, |this| ((**(this as *const *const IGuidanceTelemetryCollector_Vtbl)).SpeedTrigger)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpeedTrigger(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceTelemetryCollector", "SetSpeedTrigger", 10usize, |this| ((**(this as *const *const IGuidanceTelemetryCollector_Vtbl)).SetSpeedTrigger)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UploadFrequency(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceTelemetryCollector", "UploadFrequency", 11usize, |this| ((**(this as *const *const IGuidanceTelemetryCollector_Vtbl)).UploadFrequency)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUploadFrequency(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceTelemetryCollector", "SetUploadFrequency", 12usize, |this| ((**(this as *const *const IGuidanceTelemetryCollector_Vtbl)).SetUploadFrequency)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Enabled,
+            SetEnabled,
+            ClearLocalData,
+            SpeedTrigger,
+            SetSpeedTrigger,
+            UploadFrequency,
+            SetUploadFrequency,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceTelemetryCollector as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGuidanceTelemetryCollectorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceTelemetryCollectorStatics", "GetCurrent", 6usize, |this| ((**(this as *const *const IGuidanceTelemetryCollectorStatics_Vtbl)).GetCurrent)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceTelemetryCollectorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGuidanceUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut GuidanceMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceUpdatedEventArgs", "Mode", 6usize, |this| ((**(this as *const *const IGuidanceUpdatedEventArgs_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextManeuver(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceUpdatedEventArgs", "NextManeuver", 7usize, |this| ((**(this as *const *const IGuidanceUpdatedEventArgs_Vtbl)).NextManeuver)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextManeuverDistance(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceUpdatedEventArgs", "NextManeuverDistance", 8usize, |this| ((**(this as *const *const IGuidanceUpdatedEventArgs_Vtbl)).NextManeuverDistance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AfterNextManeuver(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceUpdatedEventArgs", "AfterNextManeuver", 9usize, |this| ((**(this as *const *const IGuidanceUpdatedEventArgs_Vtbl)).AfterNextManeuver)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AfterNextManeuverDistance(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceUpdatedEventArgs", "AfterNextManeuverDistance", 10usize, |this| ((**(this as *const *const IGuidanceUpdatedEventArgs_Vtbl)).AfterNextManeuverDistance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DistanceToDestination(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceUpdatedEventArgs", "DistanceToDestination", 11usize, |this| ((**(this as *const *const IGuidanceUpdatedEventArgs_Vtbl)).DistanceToDestination)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ElapsedDistance(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceUpdatedEventArgs", "ElapsedDistance", 12usize, |this| ((**(this as *const *const IGuidanceUpdatedEventArgs_Vtbl)).ElapsedDistance)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ElapsedTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceUpdatedEventArgs", "ElapsedTime", 13usize, |this| ((**(this as *const *const IGuidanceUpdatedEventArgs_Vtbl)).ElapsedTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeToDestination(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceUpdatedEventArgs", "TimeToDestination", 14usize, |this| ((**(this as *const *const IGuidanceUpdatedEventArgs_Vtbl)).TimeToDestination)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoadName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceUpdatedEventArgs", "RoadName", 15usize, |this| ((**(this as *const *const IGuidanceUpdatedEventArgs_Vtbl)).RoadName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Route(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceUpdatedEventArgs", "Route", 16usize, |this| ((**(this as *const *const IGuidanceUpdatedEventArgs_Vtbl)).Route)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentLocation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceUpdatedEventArgs", "CurrentLocation", 17usize, |this| ((**(this as *const *const IGuidanceUpdatedEventArgs_Vtbl)).CurrentLocation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNewManeuver(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceUpdatedEventArgs", "IsNewManeuver", 18usize, |this| ((**(this as *const *const IGuidanceUpdatedEventArgs_Vtbl)).IsNewManeuver)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn LaneInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuidanceUpdatedEventArgs", "LaneInfo", 19usize, |this| ((**(this as *const *const IGuidanceUpdatedEventArgs_Vtbl)).LaneInfo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Mode,
+            NextManeuver,
+            NextManeuverDistance,
+            AfterNextManeuver,
+            AfterNextManeuverDistance,
+            DistanceToDestination,
+            ElapsedDistance,
+            ElapsedTime,
+            TimeToDestination,
+            RoadName,
+            Route,
+            CurrentLocation,
+            IsNewManeuver,
+            #[cfg(feature = "Foundation_Collections")]
+            LaneInfo,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            LaneInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuidanceUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
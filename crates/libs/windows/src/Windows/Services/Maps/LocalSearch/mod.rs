@@ -423,6 +423,7 @@ impl LocalLocationFinderStatus {
 impl windows_core::TypeKind for LocalLocationFinderStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LocalLocationFinderStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LocalLocationFinderStatus").field(&self.0).finish()
@@ -431,3 +432,5 @@ impl core::fmt::Debug for LocalLocationFinderStatus {
 impl windows_core::RuntimeType for LocalLocationFinderStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Services.Maps.LocalSearch.LocalLocationFinderStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,217 @@
+impl windows_core::imp::ProxyVtable for ILocalCategoriesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BankAndCreditUnions(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalCategoriesStatics", "BankAndCreditUnions", 6usize, |this| ((**(this as *const *const ILocalCategoriesStatics_Vtbl)).BankAndCreditUnions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EatDrink(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalCategoriesStatics", "EatDrink", 7usize, |this| ((**(this as *const *const ILocalCategoriesStatics_Vtbl)).EatDrink)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hospitals(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalCategoriesStatics", "Hospitals", 8usize, |this| ((**(this as *const *const ILocalCategoriesStatics_Vtbl)).Hospitals)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HotelsAndMotels(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalCategoriesStatics", "HotelsAndMotels", 9usize, |this| ((**(this as *const *const ILocalCategoriesStatics_Vtbl)).HotelsAndMotels)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn All(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalCategoriesStatics", "All", 10usize, |this| ((**(this as *const *const ILocalCategoriesStatics_Vtbl)).All)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parking(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalCategoriesStatics", "Parking", 11usize, |this| ((**(this as *const *const ILocalCategoriesStatics_Vtbl)).Parking)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeeDo(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalCategoriesStatics", "SeeDo", 12usize, |this| ((**(this as *const *const ILocalCategoriesStatics_Vtbl)).SeeDo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shop(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalCategoriesStatics", "Shop", 13usize, |this| ((**(this as *const *const ILocalCategoriesStatics_Vtbl)).Shop)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BankAndCreditUnions,
+            EatDrink,
+            Hospitals,
+            HotelsAndMotels,
+            All,
+            Parking,
+            SeeDo,
+            Shop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocalCategoriesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for ILocalLocation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocation", "Address", 6usize, |this| ((**(this as *const *const ILocalLocation_Vtbl)).Address)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Identifier(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocation", "Identifier", 7usize, |this| ((**(this as *const *const ILocalLocation_Vtbl)).Identifier)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocation", "Description", 8usize, |this| ((**(this as *const *const ILocalLocation_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocation", "DisplayName", 9usize, |this| ((**(this as *const *const ILocalLocation_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn Point(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocation", "Point", 10usize, |this| ((**(this as *const *const ILocalLocation_Vtbl)).Point)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhoneNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocation", "PhoneNumber", 11usize, |this| ((**(this as *const *const ILocalLocation_Vtbl)).PhoneNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataAttribution(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocation", "DataAttribution", 12usize, |this| ((**(this as *const *const ILocalLocation_Vtbl)).DataAttribution)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Address,
+            Identifier,
+            Description,
+            DisplayName,
+            #[cfg(feature = "Devices_Geolocation")]
+            Point,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            Point: 0,
+            PhoneNumber,
+            DataAttribution,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocalLocation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ILocalLocation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Category(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocation2", "Category", 6usize, |this| ((**(this as *const *const ILocalLocation2_Vtbl)).Category)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RatingInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocation2", "RatingInfo", 7usize, |this| ((**(this as *const *const ILocalLocation2_Vtbl)).RatingInfo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn HoursOfOperation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocation2", "HoursOfOperation", 8usize, |this| ((**(this as *const *const ILocalLocation2_Vtbl)).HoursOfOperation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Category,
+            RatingInfo,
+            #[cfg(feature = "Foundation_Collections")]
+            HoursOfOperation,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            HoursOfOperation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocalLocation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ILocalLocationFinderResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn LocalLocations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocationFinderResult", "LocalLocations", 6usize, |this| ((**(this as *const *const ILocalLocationFinderResult_Vtbl)).LocalLocations)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut LocalLocationFinderStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocationFinderResult", "Status", 7usize, |this| ((**(this as *const *const ILocalLocationFinderResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            LocalLocations,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            LocalLocations: 0,
+            Status,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocalLocationFinderResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for ILocalLocationFinderStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn FindLocalLocationsAsync(this: *mut core::ffi::c_void, searchterm: core::mem::MaybeUninit<windows_core::HSTRING>, searcharea: *mut core::ffi::c_void, localcategory: core::mem::MaybeUninit<windows_core::HSTRING>, maxresults: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocationFinderStatics", "FindLocalLocationsAsync", 6usize, |this| ((**(this as *const *const ILocalLocationFinderStatics_Vtbl)).FindLocalLocationsAsync)(this, searchterm, searcharea, localcategory, maxresults, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            FindLocalLocationsAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            FindLocalLocationsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocalLocationFinderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Globalization")]
+impl windows_core::imp::ProxyVtable for ILocalLocationHoursOfOperationItem_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Globalization")]
+        unsafe extern "system" fn Day(this: *mut core::ffi::c_void, result__: *mut super::super::super::Globalization::DayOfWeek) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocationHoursOfOperationItem", "Day", 6usize, |this| ((**(this as *const *const ILocalLocationHoursOfOperationItem_Vtbl)).Day)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocationHoursOfOperationItem", "Start", 7usize, |this| ((**(this as *const *const ILocalLocationHoursOfOperationItem_Vtbl)).Start)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Span(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocationHoursOfOperationItem", "Span", 8usize, |this| ((**(this as *const *const ILocalLocationHoursOfOperationItem_Vtbl)).Span)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Globalization")]
+            Day,
+            #[cfg(not(feature = "Globalization"))]
+            Day: 0,
+            Start,
+            Span,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocalLocationHoursOfOperationItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILocalLocationRatingInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AggregateRating(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocationRatingInfo", "AggregateRating", 6usize, |this| ((**(this as *const *const ILocalLocationRatingInfo_Vtbl)).AggregateRating)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RatingCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocationRatingInfo", "RatingCount", 7usize, |this| ((**(this as *const *const ILocalLocationRatingInfo_Vtbl)).RatingCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderIdentifier(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocalLocationRatingInfo", "ProviderIdentifier", 8usize, |this| ((**(this as *const *const ILocalLocationRatingInfo_Vtbl)).ProviderIdentifier)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AggregateRating, RatingCount, ProviderIdentifier }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocalLocationRatingInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlaceInfoHelperStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromLocalLocation(this: *mut core::ffi::c_void, location: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfoHelperStatics", "CreateFromLocalLocation", 6usize, |this| ((**(this as *const *const IPlaceInfoHelperStatics_Vtbl)).CreateFromLocalLocation)(this, location, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromLocalLocation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaceInfoHelperStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
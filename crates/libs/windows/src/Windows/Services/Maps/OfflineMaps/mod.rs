@@ -227,6 +227,7 @@ impl OfflineMapPackageQueryStatus {
 impl windows_core::TypeKind for OfflineMapPackageQueryStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OfflineMapPackageQueryStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OfflineMapPackageQueryStatus").field(&self.0).finish()
@@ -247,6 +248,7 @@ impl OfflineMapPackageStartDownloadStatus {
 impl windows_core::TypeKind for OfflineMapPackageStartDownloadStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OfflineMapPackageStartDownloadStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OfflineMapPackageStartDownloadStatus").field(&self.0).finish()
@@ -267,6 +269,7 @@ impl OfflineMapPackageStatus {
 impl windows_core::TypeKind for OfflineMapPackageStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OfflineMapPackageStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OfflineMapPackageStatus").field(&self.0).finish()
@@ -275,3 +278,5 @@ impl core::fmt::Debug for OfflineMapPackageStatus {
 impl windows_core::RuntimeType for OfflineMapPackageStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Services.Maps.OfflineMaps.OfflineMapPackageStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,111 @@
+impl windows_core::imp::ProxyVtable for IOfflineMapPackage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut OfflineMapPackageStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineMapPackage", "Status", 6usize, |this| ((**(this as *const *const IOfflineMapPackage_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineMapPackage", "DisplayName", 7usize, |this| ((**(this as *const *const IOfflineMapPackage_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnclosingRegionName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineMapPackage", "EnclosingRegionName", 8usize, |this| ((**(this as *const *const IOfflineMapPackage_Vtbl)).EnclosingRegionName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EstimatedSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineMapPackage", "EstimatedSizeInBytes", 9usize, |this| ((**(this as *const *const IOfflineMapPackage_Vtbl)).EstimatedSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineMapPackage", "RemoveStatusChanged", 10usize, |this| ((**(this as *const *const IOfflineMapPackage_Vtbl)).RemoveStatusChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusChanged(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineMapPackage", "StatusChanged", 11usize, |this| ((**(this as *const *const IOfflineMapPackage_Vtbl)).StatusChanged)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStartDownloadAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineMapPackage", "RequestStartDownloadAsync", 12usize, |this| ((**(this as *const *const IOfflineMapPackage_Vtbl)).RequestStartDownloadAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            DisplayName,
+            EnclosingRegionName,
+            EstimatedSizeInBytes,
+            RemoveStatusChanged,
+            StatusChanged,
+            RequestStartDownloadAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineMapPackage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IOfflineMapPackageQueryResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut OfflineMapPackageQueryStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineMapPackageQueryResult", "Status", 6usize, |this| ((**(this as *const *const IOfflineMapPackageQueryResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Packages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineMapPackageQueryResult", "Packages", 7usize, |this| ((**(this as *const *const IOfflineMapPackageQueryResult_Vtbl)).Packages)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Foundation_Collections")]
+            Packages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Packages: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineMapPackageQueryResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineMapPackageStartDownloadResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut OfflineMapPackageStartDownloadStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineMapPackageStartDownloadResult", "Status", 6usize, |this| ((**(this as *const *const IOfflineMapPackageStartDownloadResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineMapPackageStartDownloadResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for IOfflineMapPackageStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn FindPackagesAsync(this: *mut core::ffi::c_void, querypoint: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineMapPackageStatics", "FindPackagesAsync", 6usize, |this| ((**(this as *const *const IOfflineMapPackageStatics_Vtbl)).FindPackagesAsync)(this, querypoint, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn FindPackagesInBoundingBoxAsync(this: *mut core::ffi::c_void, queryboundingbox: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineMapPackageStatics", "FindPackagesInBoundingBoxAsync", 7usize, |this| ((**(this as *const *const IOfflineMapPackageStatics_Vtbl)).FindPackagesInBoundingBoxAsync)(this, queryboundingbox, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn FindPackagesInGeocircleAsync(this: *mut core::ffi::c_void, querycircle: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineMapPackageStatics", "FindPackagesInGeocircleAsync", 8usize, |this| ((**(this as *const *const IOfflineMapPackageStatics_Vtbl)).FindPackagesInGeocircleAsync)(this, querycircle, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            FindPackagesAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            FindPackagesAsync: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            FindPackagesInBoundingBoxAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            FindPackagesInBoundingBoxAsync: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            FindPackagesInGeocircleAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            FindPackagesInGeocircleAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineMapPackageStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1676,6 +1676,7 @@ impl ManeuverWarningKind {
 impl windows_core::TypeKind for ManeuverWarningKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ManeuverWarningKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ManeuverWarningKind").field(&self.0).finish()
@@ -1697,6 +1698,7 @@ impl ManeuverWarningSeverity {
 impl windows_core::TypeKind for ManeuverWarningSeverity {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ManeuverWarningSeverity {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ManeuverWarningSeverity").field(&self.0).finish()
@@ -1715,6 +1717,7 @@ impl MapLocationDesiredAccuracy {
 impl windows_core::TypeKind for MapLocationDesiredAccuracy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MapLocationDesiredAccuracy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MapLocationDesiredAccuracy").field(&self.0).finish()
@@ -1738,6 +1741,7 @@ impl MapLocationFinderStatus {
 impl windows_core::TypeKind for MapLocationFinderStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MapLocationFinderStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MapLocationFinderStatus").field(&self.0).finish()
@@ -1757,6 +1761,7 @@ impl MapManeuverNotices {
 impl windows_core::TypeKind for MapManeuverNotices {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MapManeuverNotices {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MapManeuverNotices").field(&self.0).finish()
@@ -1816,6 +1821,7 @@ impl MapRouteFinderStatus {
 impl windows_core::TypeKind for MapRouteFinderStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MapRouteFinderStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MapRouteFinderStatus").field(&self.0).finish()
@@ -1857,6 +1863,7 @@ impl MapRouteManeuverKind {
 impl windows_core::TypeKind for MapRouteManeuverKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MapRouteManeuverKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MapRouteManeuverKind").field(&self.0).finish()
@@ -1877,6 +1884,7 @@ impl MapRouteOptimization {
 impl windows_core::TypeKind for MapRouteOptimization {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MapRouteOptimization {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MapRouteOptimization").field(&self.0).finish()
@@ -1900,6 +1908,7 @@ impl MapRouteRestrictions {
 impl windows_core::TypeKind for MapRouteRestrictions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MapRouteRestrictions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MapRouteRestrictions").field(&self.0).finish()
@@ -1951,6 +1960,7 @@ impl MapServiceDataUsagePreference {
 impl windows_core::TypeKind for MapServiceDataUsagePreference {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MapServiceDataUsagePreference {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MapServiceDataUsagePreference").field(&self.0).finish()
@@ -1972,6 +1982,7 @@ impl TrafficCongestion {
 impl windows_core::TypeKind for TrafficCongestion {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TrafficCongestion {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TrafficCongestion").field(&self.0).finish()
@@ -1990,6 +2001,7 @@ impl WaypointKind {
 impl windows_core::TypeKind for WaypointKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WaypointKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WaypointKind").field(&self.0).finish()
@@ -1998,3 +2010,5 @@ impl core::fmt::Debug for WaypointKind {
 impl windows_core::RuntimeType for WaypointKind {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Services.Maps.WaypointKind;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,917 @@
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for IEnhancedWaypoint_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn Point(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedWaypoint", "Point", 6usize, |this| ((**(this as *const *const IEnhancedWaypoint_Vtbl)).Point)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut WaypointKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedWaypoint", "Kind", 7usize, |this| ((**(this as *const *const IEnhancedWaypoint_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            Point,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            Point: 0,
+            Kind,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnhancedWaypoint as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for IEnhancedWaypointFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, point: *mut core::ffi::c_void, kind: WaypointKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedWaypointFactory", "Create", 6usize, |this| ((**(this as *const *const IEnhancedWaypointFactory_Vtbl)).Create)(this, point, kind, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            Create,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnhancedWaypointFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IManeuverWarning_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut ManeuverWarningKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManeuverWarning", "Kind", 6usize, |this| ((**(this as *const *const IManeuverWarning_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Severity(this: *mut core::ffi::c_void, result__: *mut ManeuverWarningSeverity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManeuverWarning", "Severity", 7usize, |this| ((**(this as *const *const IManeuverWarning_Vtbl)).Severity)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind, Severity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IManeuverWarning as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapAddress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BuildingName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress", "BuildingName", 6usize, |this| ((**(this as *const *const IMapAddress_Vtbl)).BuildingName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuildingFloor(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress", "BuildingFloor", 7usize, |this| ((**(this as *const *const IMapAddress_Vtbl)).BuildingFloor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuildingRoom(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress", "BuildingRoom", 8usize, |this| ((**(this as *const *const IMapAddress_Vtbl)).BuildingRoom)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuildingWing(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress", "BuildingWing", 9usize, |this| ((**(this as *const *const IMapAddress_Vtbl)).BuildingWing)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StreetNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress", "StreetNumber", 10usize, |this| ((**(this as *const *const IMapAddress_Vtbl)).StreetNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Street(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress", "Street", 11usize, |this| ((**(this as *const *const IMapAddress_Vtbl)).Street)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Neighborhood(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress", "Neighborhood", 12usize, |this| ((**(this as *const *const IMapAddress_Vtbl)).Neighborhood)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn District(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress", "District", 13usize, |this| ((**(this as *const *const IMapAddress_Vtbl)).District)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Town(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress", "Town", 14usize, |this| ((**(this as *const *const IMapAddress_Vtbl)).Town)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Region(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress", "Region", 15usize, |this| ((**(this as *const *const IMapAddress_Vtbl)).Region)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegionCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress", "RegionCode", 16usize, |this| ((**(this as *const *const IMapAddress_Vtbl)).RegionCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Country(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress", "Country", 17usize, |this| ((**(this as *const *const IMapAddress_Vtbl)).Country)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CountryCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress", "CountryCode", 18usize, |this| ((**(this as *const *const IMapAddress_Vtbl)).CountryCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress", "PostCode", 19usize, |this| ((**(this as *const *const IMapAddress_Vtbl)).PostCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Continent(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress", "Continent", 20usize, |this| ((**(this as *const *const IMapAddress_Vtbl)).Continent)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BuildingName,
+            BuildingFloor,
+            BuildingRoom,
+            BuildingWing,
+            StreetNumber,
+            Street,
+            Neighborhood,
+            District,
+            Town,
+            Region,
+            RegionCode,
+            Country,
+            CountryCode,
+            PostCode,
+            Continent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapAddress as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapAddress2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FormattedAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapAddress2", "FormattedAddress", 6usize, |this| ((**(this as *const *const IMapAddress2_Vtbl)).FormattedAddress)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FormattedAddress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapAddress2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for IMapLocation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn Point(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapLocation", "Point", 6usize, |this| ((**(this as *const *const IMapLocation_Vtbl)).Point)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapLocation", "DisplayName", 7usize, |this| ((**(this as *const *const IMapLocation_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapLocation", "Description", 8usize, |this| ((**(this as *const *const IMapLocation_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapLocation", "Address", 9usize, |this| ((**(this as *const *const IMapLocation_Vtbl)).Address)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            Point,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            Point: 0,
+            DisplayName,
+            Description,
+            Address,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapLocation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMapLocationFinderResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Locations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapLocationFinderResult", "Locations", 6usize, |this| ((**(this as *const *const IMapLocationFinderResult_Vtbl)).Locations)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut MapLocationFinderStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapLocationFinderResult", "Status", 7usize, |this| ((**(this as *const *const IMapLocationFinderResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Locations,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Locations: 0,
+            Status,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapLocationFinderResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for IMapLocationFinderStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn FindLocationsAtAsync(this: *mut core::ffi::c_void, querypoint: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapLocationFinderStatics", "FindLocationsAtAsync", 6usize, |this| ((**(this as *const *const IMapLocationFinderStatics_Vtbl)).FindLocationsAtAsync)(this, querypoint, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn FindLocationsAsync(this: *mut core::ffi::c_void, searchtext: core::mem::MaybeUninit<windows_core::HSTRING>, referencepoint: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapLocationFinderStatics", "FindLocationsAsync", 7usize, |this| ((**(this as *const *const IMapLocationFinderStatics_Vtbl)).FindLocationsAsync)(this, searchtext, referencepoint, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn FindLocationsWithMaxCountAsync(this: *mut core::ffi::c_void, searchtext: core::mem::MaybeUninit<windows_core::HSTRING>, referencepoint: *mut core::ffi::c_void, maxcount: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapLocationFinderStatics", "FindLocationsWithMaxCountAsync", 8usize, |this| ((**(this as *const *const IMapLocationFinderStatics_Vtbl)).FindLocationsWithMaxCountAsync)(this, searchtext, referencepoint, maxcount, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            FindLocationsAtAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            FindLocationsAtAsync: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            FindLocationsAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            FindLocationsAsync: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            FindLocationsWithMaxCountAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            FindLocationsWithMaxCountAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapLocationFinderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for IMapLocationFinderStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn FindLocationsAtWithAccuracyAsync(this: *mut core::ffi::c_void, querypoint: *mut core::ffi::c_void, accuracy: MapLocationDesiredAccuracy, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapLocationFinderStatics2", "FindLocationsAtWithAccuracyAsync", 6usize, |this| ((**(this as *const *const IMapLocationFinderStatics2_Vtbl)).FindLocationsAtWithAccuracyAsync)(this, querypoint, accuracy, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            FindLocationsAtWithAccuracyAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            FindLocationsAtWithAccuracyAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapLocationFinderStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowDownloadedMapsUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapManagerStatics", "ShowDownloadedMapsUI", 6usize, |this| ((**(this as *const *const IMapManagerStatics_Vtbl)).ShowDownloadedMapsUI)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowMapsUpdateUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapManagerStatics", "ShowMapsUpdateUI", 7usize, |this| ((**(this as *const *const IMapManagerStatics_Vtbl)).ShowMapsUpdateUI)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowDownloadedMapsUI, ShowMapsUpdateUI }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Geolocation", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IMapRoute_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn BoundingBox(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRoute", "BoundingBox", 6usize, |this| ((**(this as *const *const IMapRoute_Vtbl)).BoundingBox)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LengthInMeters(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRoute", "LengthInMeters", 7usize, |this| ((**(this as *const *const IMapRoute_Vtbl)).LengthInMeters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EstimatedDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRoute", "EstimatedDuration", 8usize, |this| ((**(this as *const *const IMapRoute_Vtbl)).EstimatedDuration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRoute", "Path", 9usize, |this| ((**(this as *const *const IMapRoute_Vtbl)).Path)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Legs(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRoute", "Legs", 10usize, |this| ((**(this as *const *const IMapRoute_Vtbl)).Legs)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTrafficBased(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRoute", "IsTrafficBased", 11usize, |this| ((**(this as *const *const IMapRoute_Vtbl)).IsTrafficBased)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            BoundingBox,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            BoundingBox: 0,
+            LengthInMeters,
+            EstimatedDuration,
+            #[cfg(feature = "Devices_Geolocation")]
+            Path,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            Path: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Legs,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Legs: 0,
+            IsTrafficBased,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRoute as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapRoute2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ViolatedRestrictions(this: *mut core::ffi::c_void, result__: *mut MapRouteRestrictions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRoute2", "ViolatedRestrictions", 6usize, |this| ((**(this as *const *const IMapRoute2_Vtbl)).ViolatedRestrictions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasBlockedRoads(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRoute2", "HasBlockedRoads", 7usize, |this| ((**(this as *const *const IMapRoute2_Vtbl)).HasBlockedRoads)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ViolatedRestrictions, HasBlockedRoads }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRoute2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapRoute3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DurationWithoutTraffic(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRoute3", "DurationWithoutTraffic", 6usize, |this| ((**(this as *const *const IMapRoute3_Vtbl)).DurationWithoutTraffic)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrafficCongestion(this: *mut core::ffi::c_void, result__: *mut TrafficCongestion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRoute3", "TrafficCongestion", 7usize, |this| ((**(this as *const *const IMapRoute3_Vtbl)).TrafficCongestion)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DurationWithoutTraffic, TrafficCongestion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRoute3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapRoute4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsScenic(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRoute4", "IsScenic", 6usize, |this| ((**(this as *const *const IMapRoute4_Vtbl)).IsScenic)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsScenic }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRoute4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapRouteDrivingOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxAlternateRouteCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteDrivingOptions", "MaxAlternateRouteCount", 6usize, |this| ((**(this as *const *const IMapRouteDrivingOptions_Vtbl)).MaxAlternateRouteCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxAlternateRouteCount(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteDrivingOptions", "SetMaxAlternateRouteCount", 7usize, |this| ((**(this as *const *const IMapRouteDrivingOptions_Vtbl)).SetMaxAlternateRouteCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialHeading(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteDrivingOptions", "InitialHeading", 8usize, |this| ((**(this as *const *const IMapRouteDrivingOptions_Vtbl)).InitialHeading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialHeading(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteDrivingOptions", "SetInitialHeading", 9usize, |this| ((**(this as *const *const IMapRouteDrivingOptions_Vtbl)).SetInitialHeading)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RouteOptimization(this: *mut core::ffi::c_void, result__: *mut MapRouteOptimization) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteDrivingOptions", "RouteOptimization", 10usize, |this| ((**(this as *const *const IMapRouteDrivingOptions_Vtbl)).RouteOptimization)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRouteOptimization(this: *mut core::ffi::c_void, value: MapRouteOptimization) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteDrivingOptions", "SetRouteOptimization", 11usize, |this| ((**(this as *const *const IMapRouteDrivingOptions_Vtbl)).SetRouteOptimization)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RouteRestrictions(this: *mut core::ffi::c_void, result__: *mut MapRouteRestrictions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteDrivingOptions", "RouteRestrictions", 12usize, |this| ((**(this as *const *const IMapRouteDrivingOptions_Vtbl)).RouteRestrictions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRouteRestrictions(this: *mut core::ffi::c_void, value: MapRouteRestrictions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteDrivingOptions", "SetRouteRestrictions", 13usize, |this| ((**(this as *const *const IMapRouteDrivingOptions_Vtbl)).SetRouteRestrictions)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaxAlternateRouteCount,
+            SetMaxAlternateRouteCount,
+            InitialHeading,
+            SetInitialHeading,
+            RouteOptimization,
+            SetRouteOptimization,
+            RouteRestrictions,
+            SetRouteRestrictions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRouteDrivingOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapRouteDrivingOptions2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DepartureTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteDrivingOptions2", "DepartureTime", 6usize, |this| ((**(this as *const *const IMapRouteDrivingOptions2_Vtbl)).DepartureTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDepartureTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteDrivingOptions2", "SetDepartureTime", 7usize, |this| ((**(this as *const *const IMapRouteDrivingOptions2_Vtbl)).SetDepartureTime)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DepartureTime, SetDepartureTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRouteDrivingOptions2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapRouteFinderResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Route(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderResult", "Route", 6usize, |this| ((**(this as *const *const IMapRouteFinderResult_Vtbl)).Route)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut MapRouteFinderStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderResult", "Status", 7usize, |this| ((**(this as *const *const IMapRouteFinderResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Route, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRouteFinderResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMapRouteFinderResult2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AlternateRoutes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderResult2", "AlternateRoutes", 6usize, |this| ((**(this as *const *const IMapRouteFinderResult2_Vtbl)).AlternateRoutes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AlternateRoutes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AlternateRoutes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRouteFinderResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Geolocation", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IMapRouteFinderStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn GetDrivingRouteAsync(this: *mut core::ffi::c_void, startpoint: *mut core::ffi::c_void, endpoint: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderStatics", "GetDrivingRouteAsync", 6usize, |this| ((**(this as *const *const IMapRouteFinderStatics_Vtbl)).GetDrivingRouteAsync)(this, startpoint, endpoint, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn GetDrivingRouteWithOptimizationAsync(this: *mut core::ffi::c_void, startpoint: *mut core::ffi::c_void, endpoint: *mut core::ffi::c_void, optimization: MapRouteOptimization, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderStatics", "GetDrivingRouteWithOptimizationAsync", 7usize, |this| ((**(this as *const *const IMapRouteFinderStatics_Vtbl)).GetDrivingRouteWithOptimizationAsync)(this, startpoint, endpoint, optimization, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn GetDrivingRouteWithOptimizationAndRestrictionsAsync(this: *mut core::ffi::c_void, startpoint: *mut core::ffi::c_void, endpoint: *mut core::ffi::c_void, optimization: MapRouteOptimization, restrictions: MapRouteRestrictions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderStatics", "GetDrivingRouteWithOptimizationAndRestrictionsAsync", 8usize, |this| ((**(this as *const *const IMapRouteFinderStatics_Vtbl)).GetDrivingRouteWithOptimizationAndRestrictionsAsync)(this, startpoint, endpoint, optimization, restrictions, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn GetDrivingRouteWithOptimizationRestrictionsAndHeadingAsync(this: *mut core::ffi::c_void, startpoint: *mut core::ffi::c_void, endpoint: *mut core::ffi::c_void, optimization: MapRouteOptimization, restrictions: MapRouteRestrictions, headingindegrees: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderStatics", "GetDrivingRouteWithOptimizationRestrictionsAndHeadingAsync", 9usize, |this| ((**(this as *const *const IMapRouteFinderStatics_Vtbl)).GetDrivingRouteWithOptimizationRestrictionsAndHeadingAsync)(this, startpoint, endpoint, optimization, restrictions, headingindegrees, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Geolocation", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetDrivingRouteFromWaypointsAsync(this: *mut core::ffi::c_void, waypoints: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderStatics", "GetDrivingRouteFromWaypointsAsync", 10usize, |this| ((**(this as *const *const IMapRouteFinderStatics_Vtbl)).GetDrivingRouteFromWaypointsAsync)(this, waypoints, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Geolocation", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetDrivingRouteFromWaypointsAndOptimizationAsync(this: *mut core::ffi::c_void, waypoints: *mut core::ffi::c_void, optimization: MapRouteOptimization, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderStatics", "GetDrivingRouteFromWaypointsAndOptimizationAsync", 11usize, |this| ((**(this as *const *const IMapRouteFinderStatics_Vtbl)).GetDrivingRouteFromWaypointsAndOptimizationAsync)(this, waypoints, optimization, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Geolocation", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetDrivingRouteFromWaypointsOptimizationAndRestrictionsAsync(this: *mut core::ffi::c_void, waypoints: *mut core::ffi::c_void, optimization: MapRouteOptimization, restrictions: MapRouteRestrictions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderStatics", "GetDrivingRouteFromWaypointsOptimizationAndRestrictionsAsync", 12usize, |this| ((**(this as *const *const IMapRouteFinderStatics_Vtbl)).GetDrivingRouteFromWaypointsOptimizationAndRestrictionsAsync)(this, waypoints, optimization, restrictions, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Geolocation", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetDrivingRouteFromWaypointsOptimizationRestrictionsAndHeadingAsync(this: *mut core::ffi::c_void, waypoints: *mut core::ffi::c_void, optimization: MapRouteOptimization, restrictions: MapRouteRestrictions, headingindegrees: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderStatics", "GetDrivingRouteFromWaypointsOptimizationRestrictionsAndHeadingAsync", 13usize, |this| ((**(this as *const *const IMapRouteFinderStatics_Vtbl)).GetDrivingRouteFromWaypointsOptimizationRestrictionsAndHeadingAsync)(this, waypoints, optimization, restrictions, headingindegrees, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn GetWalkingRouteAsync(this: *mut core::ffi::c_void, startpoint: *mut core::ffi::c_void, endpoint: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderStatics", "GetWalkingRouteAsync", 14usize, |this| ((**(this as *const *const IMapRouteFinderStatics_Vtbl)).GetWalkingRouteAsync)(this, startpoint, endpoint, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Devices_Geolocation", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetWalkingRouteFromWaypointsAsync(this: *mut core::ffi::c_void, waypoints: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderStatics", "GetWalkingRouteFromWaypointsAsync", 15usize, |this| ((**(this as *const *const IMapRouteFinderStatics_Vtbl)).GetWalkingRouteFromWaypointsAsync)(this, waypoints, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            GetDrivingRouteAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            GetDrivingRouteAsync: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            GetDrivingRouteWithOptimizationAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            GetDrivingRouteWithOptimizationAsync: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            GetDrivingRouteWithOptimizationAndRestrictionsAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            GetDrivingRouteWithOptimizationAndRestrictionsAsync: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            GetDrivingRouteWithOptimizationRestrictionsAndHeadingAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            GetDrivingRouteWithOptimizationRestrictionsAndHeadingAsync: 0,
+            #[cfg(all(feature = "Devices_Geolocation", feature = "Foundation_Collections"))]
+            GetDrivingRouteFromWaypointsAsync,
+            #[cfg(not(all(feature = "Devices_Geolocation", feature = "Foundation_Collections")))]
+            GetDrivingRouteFromWaypointsAsync: 0,
+            #[cfg(all(feature = "Devices_Geolocation", feature = "Foundation_Collections"))]
+            GetDrivingRouteFromWaypointsAndOptimizationAsync,
+            #[cfg(not(all(feature = "Devices_Geolocation", feature = "Foundation_Collections")))]
+            GetDrivingRouteFromWaypointsAndOptimizationAsync: 0,
+            #[cfg(all(feature = "Devices_Geolocation", feature = "Foundation_Collections"))]
+            GetDrivingRouteFromWaypointsOptimizationAndRestrictionsAsync,
+            #[cfg(not(all(feature = "Devices_Geolocation", feature = "Foundation_Collections")))]
+            GetDrivingRouteFromWaypointsOptimizationAndRestrictionsAsync: 0,
+            #[cfg(all(feature = "Devices_Geolocation", feature = "Foundation_Collections"))]
+            GetDrivingRouteFromWaypointsOptimizationRestrictionsAndHeadingAsync,
+            #[cfg(not(all(feature = "Devices_Geolocation", feature = "Foundation_Collections")))]
+            GetDrivingRouteFromWaypointsOptimizationRestrictionsAndHeadingAsync: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            GetWalkingRouteAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            GetWalkingRouteAsync: 0,
+            #[cfg(all(feature = "Devices_Geolocation", feature = "Foundation_Collections"))]
+            GetWalkingRouteFromWaypointsAsync,
+            #[cfg(not(all(feature = "Devices_Geolocation", feature = "Foundation_Collections")))]
+            GetWalkingRouteFromWaypointsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRouteFinderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for IMapRouteFinderStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn GetDrivingRouteWithOptionsAsync(this: *mut core::ffi::c_void, startpoint: *mut core::ffi::c_void, endpoint: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderStatics2", "GetDrivingRouteWithOptionsAsync", 6usize, |this| ((**(this as *const *const IMapRouteFinderStatics2_Vtbl)).GetDrivingRouteWithOptionsAsync)(this, startpoint, endpoint, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            GetDrivingRouteWithOptionsAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            GetDrivingRouteWithOptionsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRouteFinderStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMapRouteFinderStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetDrivingRouteFromEnhancedWaypointsAsync(this: *mut core::ffi::c_void, waypoints: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderStatics3", "GetDrivingRouteFromEnhancedWaypointsAsync", 6usize, |this| ((**(this as *const *const IMapRouteFinderStatics3_Vtbl)).GetDrivingRouteFromEnhancedWaypointsAsync)(this, waypoints, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetDrivingRouteFromEnhancedWaypointsWithOptionsAsync(this: *mut core::ffi::c_void, waypoints: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteFinderStatics3", "GetDrivingRouteFromEnhancedWaypointsWithOptionsAsync", 7usize, |this| ((**(this as *const *const IMapRouteFinderStatics3_Vtbl)).GetDrivingRouteFromEnhancedWaypointsWithOptionsAsync)(this, waypoints, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetDrivingRouteFromEnhancedWaypointsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetDrivingRouteFromEnhancedWaypointsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetDrivingRouteFromEnhancedWaypointsWithOptionsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetDrivingRouteFromEnhancedWaypointsWithOptionsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRouteFinderStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Geolocation", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IMapRouteLeg_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn BoundingBox(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteLeg", "BoundingBox", 6usize, |this| ((**(this as *const *const IMapRouteLeg_Vtbl)).BoundingBox)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteLeg", "Path", 7usize, |this| ((**(this as *const *const IMapRouteLeg_Vtbl)).Path)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LengthInMeters(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteLeg", "LengthInMeters", 8usize, |this| ((**(this as *const *const IMapRouteLeg_Vtbl)).LengthInMeters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EstimatedDuration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteLeg", "EstimatedDuration", 9usize, |this| ((**(this as *const *const IMapRouteLeg_Vtbl)).EstimatedDuration)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Maneuvers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteLeg", "Maneuvers", 10usize, |this| ((**(this as *const *const IMapRouteLeg_Vtbl)).Maneuvers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            BoundingBox,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            BoundingBox: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            Path,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            Path: 0,
+            LengthInMeters,
+            EstimatedDuration,
+            #[cfg(feature = "Foundation_Collections")]
+            Maneuvers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Maneuvers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRouteLeg as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapRouteLeg2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DurationWithoutTraffic(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteLeg2", "DurationWithoutTraffic", 6usize, |this| ((**(this as *const *const IMapRouteLeg2_Vtbl)).DurationWithoutTraffic)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrafficCongestion(this: *mut core::ffi::c_void, result__: *mut TrafficCongestion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteLeg2", "TrafficCongestion", 7usize, |this| ((**(this as *const *const IMapRouteLeg2_Vtbl)).TrafficCongestion)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DurationWithoutTraffic, TrafficCongestion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRouteLeg2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for IMapRouteManeuver_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn StartingPoint(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteManeuver", "StartingPoint", 6usize, |this| ((**(this as *const *const IMapRouteManeuver_Vtbl)).StartingPoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LengthInMeters(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteManeuver", "LengthInMeters", 7usize, |this| ((**(this as *const *const IMapRouteManeuver_Vtbl)).LengthInMeters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstructionText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteManeuver", "InstructionText", 8usize, |this| ((**(this as *const *const IMapRouteManeuver_Vtbl)).InstructionText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut MapRouteManeuverKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteManeuver", "Kind", 9usize, |this| ((**(this as *const *const IMapRouteManeuver_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExitNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteManeuver", "ExitNumber", 10usize, |this| ((**(this as *const *const IMapRouteManeuver_Vtbl)).ExitNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManeuverNotices(this: *mut core::ffi::c_void, result__: *mut MapManeuverNotices) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteManeuver", "ManeuverNotices", 11usize, |this| ((**(this as *const *const IMapRouteManeuver_Vtbl)).ManeuverNotices)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            StartingPoint,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            StartingPoint: 0,
+            LengthInMeters,
+            InstructionText,
+            Kind,
+            ExitNumber,
+            ManeuverNotices,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRouteManeuver as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapRouteManeuver2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartHeading(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteManeuver2", "StartHeading", 6usize, |this| ((**(this as *const *const IMapRouteManeuver2_Vtbl)).StartHeading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndHeading(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteManeuver2", "EndHeading", 7usize, |this| ((**(this as *const *const IMapRouteManeuver2_Vtbl)).EndHeading)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StreetName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteManeuver2", "StreetName", 8usize, |this| ((**(this as *const *const IMapRouteManeuver2_Vtbl)).StreetName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartHeading, EndHeading, StreetName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRouteManeuver2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMapRouteManeuver3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Warnings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapRouteManeuver3", "Warnings", 6usize, |this| ((**(this as *const *const IMapRouteManeuver3_Vtbl)).Warnings)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Warnings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Warnings: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapRouteManeuver3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapServiceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetServiceToken(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapServiceStatics", "SetServiceToken", 6usize, |this| ((**(this as *const *const IMapServiceStatics_Vtbl)).SetServiceToken)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceToken(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapServiceStatics", "ServiceToken", 7usize, |this| ((**(this as *const *const IMapServiceStatics_Vtbl)).ServiceToken)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetServiceToken, ServiceToken }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapServiceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapServiceStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WorldViewRegionCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapServiceStatics2", "WorldViewRegionCode", 6usize, |this| ((**(this as *const *const IMapServiceStatics2_Vtbl)).WorldViewRegionCode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WorldViewRegionCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapServiceStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapServiceStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DataAttributions(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapServiceStatics3", "DataAttributions", 6usize, |this| ((**(this as *const *const IMapServiceStatics3_Vtbl)).DataAttributions)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DataAttributions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapServiceStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapServiceStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDataUsagePreference(this: *mut core::ffi::c_void, value: MapServiceDataUsagePreference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapServiceStatics4", "SetDataUsagePreference", 6usize, |this| ((**(this as *const *const IMapServiceStatics4_Vtbl)).SetDataUsagePreference)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataUsagePreference(this: *mut core::ffi::c_void, result__: *mut MapServiceDataUsagePreference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapServiceStatics4", "DataUsagePreference", 7usize, |this| ((**(this as *const *const IMapServiceStatics4_Vtbl)).DataUsagePreference)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDataUsagePreference, DataUsagePreference }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapServiceStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Geolocation", feature = "UI_Popups"))]
+impl windows_core::imp::ProxyVtable for IPlaceInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfo", "Show", 6usize, |this| ((**(this as *const *const IPlaceInfo_Vtbl)).Show)(this, selection), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowWithPreferredPlacement(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::super::UI::Popups::Placement) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfo", "ShowWithPreferredPlacement", 7usize, |this| ((**(this as *const *const IPlaceInfo_Vtbl)).ShowWithPreferredPlacement)(this, selection, preferredplacement), |result| Some(*result))
+        }
+        unsafe extern "system" fn Identifier(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfo", "Identifier", 8usize, |this| ((**(this as *const *const IPlaceInfo_Vtbl)).Identifier)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfo", "DisplayName", 9usize, |this| ((**(this as *const *const IPlaceInfo_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfo", "DisplayAddress", 10usize, |this| ((**(this as *const *const IPlaceInfo_Vtbl)).DisplayAddress)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn Geoshape(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfo", "Geoshape", 11usize, |this| ((**(this as *const *const IPlaceInfo_Vtbl)).Geoshape)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Show,
+            #[cfg(feature = "UI_Popups")]
+            ShowWithPreferredPlacement,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowWithPreferredPlacement: 0,
+            Identifier,
+            DisplayName,
+            DisplayAddress,
+            #[cfg(feature = "Devices_Geolocation")]
+            Geoshape,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            Geoshape: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaceInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlaceInfoCreateOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfoCreateOptions", "SetDisplayName", 6usize, |this| ((**(this as *const *const IPlaceInfoCreateOptions_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfoCreateOptions", "DisplayName", 7usize, |this| ((**(this as *const *const IPlaceInfoCreateOptions_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayAddress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfoCreateOptions", "SetDisplayAddress", 8usize, |this| ((**(this as *const *const IPlaceInfoCreateOptions_Vtbl)).SetDisplayAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayAddress(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfoCreateOptions", "DisplayAddress", 9usize, |this| ((**(this as *const *const IPlaceInfoCreateOptions_Vtbl)).DisplayAddress)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDisplayName,
+            DisplayName,
+            SetDisplayAddress,
+            DisplayAddress,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaceInfoCreateOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for IPlaceInfoStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, referencepoint: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfoStatics", "Create", 6usize, |this| ((**(this as *const *const IPlaceInfoStatics_Vtbl)).Create)(this, referencepoint, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn CreateWithGeopointAndOptions(this: *mut core::ffi::c_void, referencepoint: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfoStatics", "CreateWithGeopointAndOptions", 7usize, |this| ((**(this as *const *const IPlaceInfoStatics_Vtbl)).CreateWithGeopointAndOptions)(this, referencepoint, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromIdentifier(this: *mut core::ffi::c_void, identifier: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfoStatics", "CreateFromIdentifier", 8usize, |this| ((**(this as *const *const IPlaceInfoStatics_Vtbl)).CreateFromIdentifier)(this, identifier, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn CreateFromIdentifierWithOptions(this: *mut core::ffi::c_void, identifier: core::mem::MaybeUninit<windows_core::HSTRING>, defaultpoint: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfoStatics", "CreateFromIdentifierWithOptions", 9usize, |this| ((**(this as *const *const IPlaceInfoStatics_Vtbl)).CreateFromIdentifierWithOptions)(this, identifier, defaultpoint, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromMapLocation(this: *mut core::ffi::c_void, location: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfoStatics", "CreateFromMapLocation", 10usize, |this| ((**(this as *const *const IPlaceInfoStatics_Vtbl)).CreateFromMapLocation)(this, location, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsShowSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfoStatics", "IsShowSupported", 11usize, |this| ((**(this as *const *const IPlaceInfoStatics_Vtbl)).IsShowSupported)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            Create,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            Create: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            CreateWithGeopointAndOptions,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            CreateWithGeopointAndOptions: 0,
+            CreateFromIdentifier,
+            #[cfg(feature = "Devices_Geolocation")]
+            CreateFromIdentifierWithOptions,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            CreateFromIdentifierWithOptions: 0,
+            CreateFromMapLocation,
+            IsShowSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaceInfoStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlaceInfoStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromAddress(this: *mut core::ffi::c_void, displayaddress: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfoStatics2", "CreateFromAddress", 6usize, |this| ((**(this as *const *const IPlaceInfoStatics2_Vtbl)).CreateFromAddress)(this, displayaddress, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromAddressWithName(this: *mut core::ffi::c_void, displayaddress: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaceInfoStatics2", "CreateFromAddressWithName", 7usize, |this| ((**(this as *const *const IPlaceInfoStatics2_Vtbl)).CreateFromAddressWithName)(this, displayaddress, displayname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromAddress, CreateFromAddressWithName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaceInfoStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
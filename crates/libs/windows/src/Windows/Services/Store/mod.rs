@@ -2755,6 +2755,7 @@ impl StoreCanLicenseStatus {
 impl windows_core::TypeKind for StoreCanLicenseStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StoreCanLicenseStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StoreCanLicenseStatus").field(&self.0).finish()
@@ -2775,6 +2776,7 @@ impl StoreConsumableStatus {
 impl windows_core::TypeKind for StoreConsumableStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StoreConsumableStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StoreConsumableStatus").field(&self.0).finish()
@@ -2797,6 +2799,7 @@ impl StoreDurationUnit {
 impl windows_core::TypeKind for StoreDurationUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StoreDurationUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StoreDurationUnit").field(&self.0).finish()
@@ -2822,6 +2825,7 @@ impl StorePackageUpdateState {
 impl windows_core::TypeKind for StorePackageUpdateState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorePackageUpdateState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorePackageUpdateState").field(&self.0).finish()
@@ -2843,6 +2847,7 @@ impl StorePurchaseStatus {
 impl windows_core::TypeKind for StorePurchaseStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorePurchaseStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorePurchaseStatus").field(&self.0).finish()
@@ -2874,6 +2879,7 @@ impl StoreQueueItemExtendedState {
 impl windows_core::TypeKind for StoreQueueItemExtendedState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StoreQueueItemExtendedState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StoreQueueItemExtendedState").field(&self.0).finish()
@@ -2893,6 +2899,7 @@ impl StoreQueueItemKind {
 impl windows_core::TypeKind for StoreQueueItemKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StoreQueueItemKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StoreQueueItemKind").field(&self.0).finish()
@@ -2914,6 +2921,7 @@ impl StoreQueueItemState {
 impl windows_core::TypeKind for StoreQueueItemState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StoreQueueItemState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StoreQueueItemState").field(&self.0).finish()
@@ -2934,6 +2942,7 @@ impl StoreRateAndReviewStatus {
 impl windows_core::TypeKind for StoreRateAndReviewStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StoreRateAndReviewStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StoreRateAndReviewStatus").field(&self.0).finish()
@@ -2955,6 +2964,7 @@ impl StoreUninstallStorePackageStatus {
 impl windows_core::TypeKind for StoreUninstallStorePackageStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StoreUninstallStorePackageStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StoreUninstallStorePackageStatus").field(&self.0).finish()
@@ -2964,7 +2974,8 @@ impl windows_core::RuntimeType for StoreUninstallStorePackageStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Services.Store.StoreUninstallStorePackageStatus;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct StorePackageUpdateStatus {
     pub PackageFamilyName: windows_core::HSTRING,
     pub PackageDownloadSizeInBytes: u64,
@@ -2984,3 +2995,5 @@ impl Default for StorePackageUpdateStatus {
         unsafe { core::mem::zeroed() }
     }
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1360 @@
+impl windows_core::imp::ProxyVtable for IStoreAcquireLicenseResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StorePackageLicense(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAcquireLicenseResult", "StorePackageLicense", 6usize, |this| ((**(this as *const *const IStoreAcquireLicenseResult_Vtbl)).StorePackageLicense)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAcquireLicenseResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IStoreAcquireLicenseResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StorePackageLicense, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreAcquireLicenseResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStoreAppLicense_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SkuStoreId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAppLicense", "SkuStoreId", 6usize, |this| ((**(this as *const *const IStoreAppLicense_Vtbl)).SkuStoreId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsActive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAppLicense", "IsActive", 7usize, |this| ((**(this as *const *const IStoreAppLicense_Vtbl)).IsActive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTrial(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAppLicense", "IsTrial", 8usize, |this| ((**(this as *const *const IStoreAppLicense_Vtbl)).IsTrial)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationDate(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAppLicense", "ExpirationDate", 9usize, |this| ((**(this as *const *const IStoreAppLicense_Vtbl)).ExpirationDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedJsonData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAppLicense", "ExtendedJsonData", 10usize, |this| ((**(this as *const *const IStoreAppLicense_Vtbl)).ExtendedJsonData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AddOnLicenses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAppLicense", "AddOnLicenses", 11usize, |this| ((**(this as *const *const IStoreAppLicense_Vtbl)).AddOnLicenses)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrialTimeRemaining(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAppLicense", "TrialTimeRemaining", 12usize, |this| ((**(this as *const *const IStoreAppLicense_Vtbl)).TrialTimeRemaining)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTrialOwnedByThisUser(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAppLicense", "IsTrialOwnedByThisUser", 13usize, |this| ((**(this as *const *const IStoreAppLicense_Vtbl)).IsTrialOwnedByThisUser)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrialUniqueId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAppLicense", "TrialUniqueId", 14usize, |this| ((**(this as *const *const IStoreAppLicense_Vtbl)).TrialUniqueId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SkuStoreId,
+            IsActive,
+            IsTrial,
+            ExpirationDate,
+            ExtendedJsonData,
+            #[cfg(feature = "Foundation_Collections")]
+            AddOnLicenses,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AddOnLicenses: 0,
+            TrialTimeRemaining,
+            IsTrialOwnedByThisUser,
+            TrialUniqueId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreAppLicense as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreAppLicense2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDiscLicense(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAppLicense2", "IsDiscLicense", 6usize, |this| ((**(this as *const *const IStoreAppLicense2_Vtbl)).IsDiscLicense)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDiscLicense }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreAppLicense2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreAvailability_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StoreId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAvailability", "StoreId", 6usize, |this| ((**(this as *const *const IStoreAvailability_Vtbl)).StoreId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndDate(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAvailability", "EndDate", 7usize, |this| ((**(this as *const *const IStoreAvailability_Vtbl)).EndDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Price(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAvailability", "Price", 8usize, |this| ((**(this as *const *const IStoreAvailability_Vtbl)).Price)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedJsonData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAvailability", "ExtendedJsonData", 9usize, |this| ((**(this as *const *const IStoreAvailability_Vtbl)).ExtendedJsonData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPurchaseAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAvailability", "RequestPurchaseAsync", 10usize, |this| ((**(this as *const *const IStoreAvailability_Vtbl)).RequestPurchaseAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPurchaseWithPurchasePropertiesAsync(this: *mut core::ffi::c_void, storepurchaseproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreAvailability", "RequestPurchaseWithPurchasePropertiesAsync", 11usize, |this| ((**(this as *const *const IStoreAvailability_Vtbl)).RequestPurchaseWithPurchasePropertiesAsync)(this, storepurchaseproperties, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StoreId,
+            EndDate,
+            Price,
+            ExtendedJsonData,
+            RequestPurchaseAsync,
+            RequestPurchaseWithPurchasePropertiesAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreAvailability as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreCanAcquireLicenseResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreCanAcquireLicenseResult", "ExtendedError", 6usize, |this| ((**(this as *const *const IStoreCanAcquireLicenseResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LicensableSku(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreCanAcquireLicenseResult", "LicensableSku", 7usize, |this| ((**(this as *const *const IStoreCanAcquireLicenseResult_Vtbl)).LicensableSku)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut StoreCanLicenseStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreCanAcquireLicenseResult", "Status", 8usize, |this| ((**(this as *const *const IStoreCanAcquireLicenseResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError, LicensableSku, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreCanAcquireLicenseResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreCollectionData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTrial(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreCollectionData", "IsTrial", 6usize, |this| ((**(this as *const *const IStoreCollectionData_Vtbl)).IsTrial)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CampaignId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreCollectionData", "CampaignId", 7usize, |this| ((**(this as *const *const IStoreCollectionData_Vtbl)).CampaignId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeveloperOfferId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreCollectionData", "DeveloperOfferId", 8usize, |this| ((**(this as *const *const IStoreCollectionData_Vtbl)).DeveloperOfferId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcquiredDate(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreCollectionData", "AcquiredDate", 9usize, |this| ((**(this as *const *const IStoreCollectionData_Vtbl)).AcquiredDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartDate(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreCollectionData", "StartDate", 10usize, |this| ((**(this as *const *const IStoreCollectionData_Vtbl)).StartDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndDate(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreCollectionData", "EndDate", 11usize, |this| ((**(this as *const *const IStoreCollectionData_Vtbl)).EndDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrialTimeRemaining(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreCollectionData", "TrialTimeRemaining", 12usize, |this| ((**(this as *const *const IStoreCollectionData_Vtbl)).TrialTimeRemaining)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedJsonData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreCollectionData", "ExtendedJsonData", 13usize, |this| ((**(this as *const *const IStoreCollectionData_Vtbl)).ExtendedJsonData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsTrial,
+            CampaignId,
+            DeveloperOfferId,
+            AcquiredDate,
+            StartDate,
+            EndDate,
+            TrialTimeRemaining,
+            ExtendedJsonData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreCollectionData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreConsumableResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut StoreConsumableStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreConsumableResult", "Status", 6usize, |this| ((**(this as *const *const IStoreConsumableResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrackingId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreConsumableResult", "TrackingId", 7usize, |this| ((**(this as *const *const IStoreConsumableResult_Vtbl)).TrackingId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BalanceRemaining(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreConsumableResult", "BalanceRemaining", 8usize, |this| ((**(this as *const *const IStoreConsumableResult_Vtbl)).BalanceRemaining)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreConsumableResult", "ExtendedError", 9usize, |this| ((**(this as *const *const IStoreConsumableResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, TrackingId, BalanceRemaining, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreConsumableResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IStoreContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "User", 6usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OfflineLicensesChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "OfflineLicensesChanged", 7usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).OfflineLicensesChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOfflineLicensesChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "RemoveOfflineLicensesChanged", 8usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).RemoveOfflineLicensesChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCustomerPurchaseIdAsync(this: *mut core::ffi::c_void, serviceticket: core::mem::MaybeUninit<windows_core::HSTRING>, publisheruserid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "GetCustomerPurchaseIdAsync", 9usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).GetCustomerPurchaseIdAsync)(this, serviceticket, publisheruserid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCustomerCollectionsIdAsync(this: *mut core::ffi::c_void, serviceticket: core::mem::MaybeUninit<windows_core::HSTRING>, publisheruserid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "GetCustomerCollectionsIdAsync", 10usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).GetCustomerCollectionsIdAsync)(this, serviceticket, publisheruserid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppLicenseAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "GetAppLicenseAsync", 11usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).GetAppLicenseAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStoreProductForCurrentAppAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "GetStoreProductForCurrentAppAsync", 12usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).GetStoreProductForCurrentAppAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetStoreProductsAsync(this: *mut core::ffi::c_void, productkinds: *mut core::ffi::c_void, storeids: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "GetStoreProductsAsync", 13usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).GetStoreProductsAsync)(this, productkinds, storeids, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAssociatedStoreProductsAsync(this: *mut core::ffi::c_void, productkinds: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "GetAssociatedStoreProductsAsync", 14usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).GetAssociatedStoreProductsAsync)(this, productkinds, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAssociatedStoreProductsWithPagingAsync(this: *mut core::ffi::c_void, productkinds: *mut core::ffi::c_void, maxitemstoretrieveperpage: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "GetAssociatedStoreProductsWithPagingAsync", 15usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).GetAssociatedStoreProductsWithPagingAsync)(this, productkinds, maxitemstoretrieveperpage, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetUserCollectionAsync(this: *mut core::ffi::c_void, productkinds: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "GetUserCollectionAsync", 16usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).GetUserCollectionAsync)(this, productkinds, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetUserCollectionWithPagingAsync(this: *mut core::ffi::c_void, productkinds: *mut core::ffi::c_void, maxitemstoretrieveperpage: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "GetUserCollectionWithPagingAsync", 17usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).GetUserCollectionWithPagingAsync)(this, productkinds, maxitemstoretrieveperpage, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportConsumableFulfillmentAsync(this: *mut core::ffi::c_void, productstoreid: core::mem::MaybeUninit<windows_core::HSTRING>, quantity: u32, trackingid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "ReportConsumableFulfillmentAsync", 18usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).ReportConsumableFulfillmentAsync)(this, productstoreid, quantity, trackingid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConsumableBalanceRemainingAsync(this: *mut core::ffi::c_void, productstoreid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "GetConsumableBalanceRemainingAsync", 19usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).GetConsumableBalanceRemainingAsync)(this, productstoreid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn AcquireStoreLicenseForOptionalPackageAsync(this: *mut core::ffi::c_void, optionalpackage: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "AcquireStoreLicenseForOptionalPackageAsync", 20usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).AcquireStoreLicenseForOptionalPackageAsync)(this, optionalpackage, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPurchaseAsync(this: *mut core::ffi::c_void, storeid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "RequestPurchaseAsync", 21usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).RequestPurchaseAsync)(this, storeid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPurchaseWithPurchasePropertiesAsync(this: *mut core::ffi::c_void, storeid: core::mem::MaybeUninit<windows_core::HSTRING>, storepurchaseproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "RequestPurchaseWithPurchasePropertiesAsync", 22usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).RequestPurchaseWithPurchasePropertiesAsync)(this, storeid, storepurchaseproperties, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAppAndOptionalStorePackageUpdatesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "GetAppAndOptionalStorePackageUpdatesAsync", 23usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).GetAppAndOptionalStorePackageUpdatesAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestDownloadStorePackageUpdatesAsync(this: *mut core::ffi::c_void, storepackageupdates: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "RequestDownloadStorePackageUpdatesAsync", 24usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).RequestDownloadStorePackageUpdatesAsync)(this, storepackageupdates, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestDownloadAndInstallStorePackageUpdatesAsync(this: *mut core::ffi::c_void, storepackageupdates: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "RequestDownloadAndInstallStorePackageUpdatesAsync", 25usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).RequestDownloadAndInstallStorePackageUpdatesAsync)(this, storepackageupdates, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestDownloadAndInstallStorePackagesAsync(this: *mut core::ffi::c_void, storeids: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext", "RequestDownloadAndInstallStorePackagesAsync", 26usize, |this| ((**(this as *const *const IStoreContext_Vtbl)).RequestDownloadAndInstallStorePackagesAsync)(this, storeids, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+            OfflineLicensesChanged,
+            RemoveOfflineLicensesChanged,
+            GetCustomerPurchaseIdAsync,
+            GetCustomerCollectionsIdAsync,
+            GetAppLicenseAsync,
+            GetStoreProductForCurrentAppAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetStoreProductsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetStoreProductsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAssociatedStoreProductsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAssociatedStoreProductsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAssociatedStoreProductsWithPagingAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAssociatedStoreProductsWithPagingAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetUserCollectionAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetUserCollectionAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetUserCollectionWithPagingAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetUserCollectionWithPagingAsync: 0,
+            ReportConsumableFulfillmentAsync,
+            GetConsumableBalanceRemainingAsync,
+            #[cfg(feature = "ApplicationModel")]
+            AcquireStoreLicenseForOptionalPackageAsync,
+            #[cfg(not(feature = "ApplicationModel"))]
+            AcquireStoreLicenseForOptionalPackageAsync: 0,
+            RequestPurchaseAsync,
+            RequestPurchaseWithPurchasePropertiesAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAppAndOptionalStorePackageUpdatesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAppAndOptionalStorePackageUpdatesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestDownloadStorePackageUpdatesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestDownloadStorePackageUpdatesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestDownloadAndInstallStorePackageUpdatesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestDownloadAndInstallStorePackageUpdatesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestDownloadAndInstallStorePackagesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestDownloadAndInstallStorePackagesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IStoreContext2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindStoreProductForPackageAsync(this: *mut core::ffi::c_void, productkinds: *mut core::ffi::c_void, package: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext2", "FindStoreProductForPackageAsync", 6usize, |this| ((**(this as *const *const IStoreContext2_Vtbl)).FindStoreProductForPackageAsync)(this, productkinds, package, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindStoreProductForPackageAsync,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindStoreProductForPackageAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreContext2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IStoreContext3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanSilentlyDownloadStorePackageUpdates(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext3", "CanSilentlyDownloadStorePackageUpdates", 6usize, |this| ((**(this as *const *const IStoreContext3_Vtbl)).CanSilentlyDownloadStorePackageUpdates)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TrySilentDownloadStorePackageUpdatesAsync(this: *mut core::ffi::c_void, storepackageupdates: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext3", "TrySilentDownloadStorePackageUpdatesAsync", 7usize, |this| ((**(this as *const *const IStoreContext3_Vtbl)).TrySilentDownloadStorePackageUpdatesAsync)(this, storepackageupdates, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TrySilentDownloadAndInstallStorePackageUpdatesAsync(this: *mut core::ffi::c_void, storepackageupdates: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext3", "TrySilentDownloadAndInstallStorePackageUpdatesAsync", 8usize, |this| ((**(this as *const *const IStoreContext3_Vtbl)).TrySilentDownloadAndInstallStorePackageUpdatesAsync)(this, storepackageupdates, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn CanAcquireStoreLicenseForOptionalPackageAsync(this: *mut core::ffi::c_void, optionalpackage: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext3", "CanAcquireStoreLicenseForOptionalPackageAsync", 9usize, |this| ((**(this as *const *const IStoreContext3_Vtbl)).CanAcquireStoreLicenseForOptionalPackageAsync)(this, optionalpackage, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanAcquireStoreLicenseAsync(this: *mut core::ffi::c_void, productstoreid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext3", "CanAcquireStoreLicenseAsync", 10usize, |this| ((**(this as *const *const IStoreContext3_Vtbl)).CanAcquireStoreLicenseAsync)(this, productstoreid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetStoreProductsWithOptionsAsync(this: *mut core::ffi::c_void, productkinds: *mut core::ffi::c_void, storeids: *mut core::ffi::c_void, storeproductoptions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext3", "GetStoreProductsWithOptionsAsync", 11usize, |this| ((**(this as *const *const IStoreContext3_Vtbl)).GetStoreProductsWithOptionsAsync)(this, productkinds, storeids, storeproductoptions, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAssociatedStoreQueueItemsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext3", "GetAssociatedStoreQueueItemsAsync", 12usize, |this| ((**(this as *const *const IStoreContext3_Vtbl)).GetAssociatedStoreQueueItemsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetStoreQueueItemsAsync(this: *mut core::ffi::c_void, storeids: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext3", "GetStoreQueueItemsAsync", 13usize, |this| ((**(this as *const *const IStoreContext3_Vtbl)).GetStoreQueueItemsAsync)(this, storeids, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestDownloadAndInstallStorePackagesWithInstallOptionsAsync(this: *mut core::ffi::c_void, storeids: *mut core::ffi::c_void, storepackageinstalloptions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext3", "RequestDownloadAndInstallStorePackagesWithInstallOptionsAsync", 14usize, |this| ((**(this as *const *const IStoreContext3_Vtbl)).RequestDownloadAndInstallStorePackagesWithInstallOptionsAsync)(this, storeids, storepackageinstalloptions, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DownloadAndInstallStorePackagesAsync(this: *mut core::ffi::c_void, storeids: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext3", "DownloadAndInstallStorePackagesAsync", 15usize, |this| ((**(this as *const *const IStoreContext3_Vtbl)).DownloadAndInstallStorePackagesAsync)(this, storeids, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn RequestUninstallStorePackageAsync(this: *mut core::ffi::c_void, package: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext3", "RequestUninstallStorePackageAsync", 16usize, |this| ((**(this as *const *const IStoreContext3_Vtbl)).RequestUninstallStorePackageAsync)(this, package, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestUninstallStorePackageByStoreIdAsync(this: *mut core::ffi::c_void, storeid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext3", "RequestUninstallStorePackageByStoreIdAsync", 17usize, |this| ((**(this as *const *const IStoreContext3_Vtbl)).RequestUninstallStorePackageByStoreIdAsync)(this, storeid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn UninstallStorePackageAsync(this: *mut core::ffi::c_void, package: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext3", "UninstallStorePackageAsync", 18usize, |this| ((**(this as *const *const IStoreContext3_Vtbl)).UninstallStorePackageAsync)(this, package, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UninstallStorePackageByStoreIdAsync(this: *mut core::ffi::c_void, storeid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext3", "UninstallStorePackageByStoreIdAsync", 19usize, |this| ((**(this as *const *const IStoreContext3_Vtbl)).UninstallStorePackageByStoreIdAsync)(this, storeid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanSilentlyDownloadStorePackageUpdates,
+            #[cfg(feature = "Foundation_Collections")]
+            TrySilentDownloadStorePackageUpdatesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TrySilentDownloadStorePackageUpdatesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            TrySilentDownloadAndInstallStorePackageUpdatesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TrySilentDownloadAndInstallStorePackageUpdatesAsync: 0,
+            #[cfg(feature = "ApplicationModel")]
+            CanAcquireStoreLicenseForOptionalPackageAsync,
+            #[cfg(not(feature = "ApplicationModel"))]
+            CanAcquireStoreLicenseForOptionalPackageAsync: 0,
+            CanAcquireStoreLicenseAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetStoreProductsWithOptionsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetStoreProductsWithOptionsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAssociatedStoreQueueItemsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAssociatedStoreQueueItemsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetStoreQueueItemsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetStoreQueueItemsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestDownloadAndInstallStorePackagesWithInstallOptionsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestDownloadAndInstallStorePackagesWithInstallOptionsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            DownloadAndInstallStorePackagesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DownloadAndInstallStorePackagesAsync: 0,
+            #[cfg(feature = "ApplicationModel")]
+            RequestUninstallStorePackageAsync,
+            #[cfg(not(feature = "ApplicationModel"))]
+            RequestUninstallStorePackageAsync: 0,
+            RequestUninstallStorePackageByStoreIdAsync,
+            #[cfg(feature = "ApplicationModel")]
+            UninstallStorePackageAsync,
+            #[cfg(not(feature = "ApplicationModel"))]
+            UninstallStorePackageAsync: 0,
+            UninstallStorePackageByStoreIdAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreContext3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStoreContext4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestRateAndReviewAppAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext4", "RequestRateAndReviewAppAsync", 6usize, |this| ((**(this as *const *const IStoreContext4_Vtbl)).RequestRateAndReviewAppAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetInstallOrderForAssociatedStoreQueueItemsAsync(this: *mut core::ffi::c_void, items: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext4", "SetInstallOrderForAssociatedStoreQueueItemsAsync", 7usize, |this| ((**(this as *const *const IStoreContext4_Vtbl)).SetInstallOrderForAssociatedStoreQueueItemsAsync)(this, items, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestRateAndReviewAppAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            SetInstallOrderForAssociatedStoreQueueItemsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetInstallOrderForAssociatedStoreQueueItemsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreContext4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStoreContext5_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetUserPurchaseHistoryAsync(this: *mut core::ffi::c_void, productkinds: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext5", "GetUserPurchaseHistoryAsync", 6usize, |this| ((**(this as *const *const IStoreContext5_Vtbl)).GetUserPurchaseHistoryAsync)(this, productkinds, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAssociatedStoreProductsByInAppOfferTokenAsync(this: *mut core::ffi::c_void, inappoffertokens: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext5", "GetAssociatedStoreProductsByInAppOfferTokenAsync", 7usize, |this| ((**(this as *const *const IStoreContext5_Vtbl)).GetAssociatedStoreProductsByInAppOfferTokenAsync)(this, inappoffertokens, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPurchaseByInAppOfferTokenAsync(this: *mut core::ffi::c_void, inappoffertoken: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContext5", "RequestPurchaseByInAppOfferTokenAsync", 8usize, |this| ((**(this as *const *const IStoreContext5_Vtbl)).RequestPurchaseByInAppOfferTokenAsync)(this, inappoffertoken, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetUserPurchaseHistoryAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetUserPurchaseHistoryAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAssociatedStoreProductsByInAppOfferTokenAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAssociatedStoreProductsByInAppOfferTokenAsync: 0,
+            RequestPurchaseByInAppOfferTokenAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreContext5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IStoreContextStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContextStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IStoreContextStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreContextStatics", "GetForUser", 7usize, |this| ((**(this as *const *const IStoreContextStatics_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDefault,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreContextStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreImage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreImage", "Uri", 6usize, |this| ((**(this as *const *const IStoreImage_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImagePurposeTag(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreImage", "ImagePurposeTag", 7usize, |this| ((**(this as *const *const IStoreImage_Vtbl)).ImagePurposeTag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreImage", "Width", 8usize, |this| ((**(this as *const *const IStoreImage_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreImage", "Height", 9usize, |this| ((**(this as *const *const IStoreImage_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Caption(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreImage", "Caption", 10usize, |this| ((**(this as *const *const IStoreImage_Vtbl)).Caption)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uri, ImagePurposeTag, Width, Height, Caption }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreImage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreLicense_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SkuStoreId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreLicense", "SkuStoreId", 6usize, |this| ((**(this as *const *const IStoreLicense_Vtbl)).SkuStoreId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsActive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreLicense", "IsActive", 7usize, |this| ((**(this as *const *const IStoreLicense_Vtbl)).IsActive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationDate(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreLicense", "ExpirationDate", 8usize, |this| ((**(this as *const *const IStoreLicense_Vtbl)).ExpirationDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedJsonData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreLicense", "ExtendedJsonData", 9usize, |this| ((**(this as *const *const IStoreLicense_Vtbl)).ExtendedJsonData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InAppOfferToken(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreLicense", "InAppOfferToken", 10usize, |this| ((**(this as *const *const IStoreLicense_Vtbl)).InAppOfferToken)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SkuStoreId,
+            IsActive,
+            ExpirationDate,
+            ExtendedJsonData,
+            InAppOfferToken,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreLicense as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorePackageInstallOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowForcedAppRestart(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePackageInstallOptions", "AllowForcedAppRestart", 6usize, |this| ((**(this as *const *const IStorePackageInstallOptions_Vtbl)).AllowForcedAppRestart)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowForcedAppRestart(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePackageInstallOptions", "SetAllowForcedAppRestart", 7usize, |this| ((**(this as *const *const IStorePackageInstallOptions_Vtbl)).SetAllowForcedAppRestart)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AllowForcedAppRestart, SetAllowForcedAppRestart }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorePackageInstallOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel")]
+impl windows_core::imp::ProxyVtable for IStorePackageLicense_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LicenseLost(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePackageLicense", "LicenseLost", 6usize, |this| ((**(this as *const *const IStorePackageLicense_Vtbl)).LicenseLost)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLicenseLost(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePackageLicense", "RemoveLicenseLost", 7usize, |this| ((**(this as *const *const IStorePackageLicense_Vtbl)).RemoveLicenseLost)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePackageLicense", "Package", 8usize, |this| ((**(this as *const *const IStorePackageLicense_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsValid(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePackageLicense", "IsValid", 9usize, |this| ((**(this as *const *const IStorePackageLicense_Vtbl)).IsValid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseLicense(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePackageLicense", "ReleaseLicense", 10usize, |this| ((**(this as *const *const IStorePackageLicense_Vtbl)).ReleaseLicense)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LicenseLost,
+            RemoveLicenseLost,
+            #[cfg(feature = "ApplicationModel")]
+            Package,
+            #[cfg(not(feature = "ApplicationModel"))]
+            Package: 0,
+            IsValid,
+            ReleaseLicense,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorePackageLicense as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel")]
+impl windows_core::imp::ProxyVtable for IStorePackageUpdate_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn Package(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePackageUpdate", "Package", 6usize, |this| ((**(this as *const *const IStorePackageUpdate_Vtbl)).Package)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mandatory(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePackageUpdate", "Mandatory", 7usize, |this| ((**(this as *const *const IStorePackageUpdate_Vtbl)).Mandatory)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel")]
+            Package,
+            #[cfg(not(feature = "ApplicationModel"))]
+            Package: 0,
+            Mandatory,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorePackageUpdate as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorePackageUpdateResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OverallState(this: *mut core::ffi::c_void, result__: *mut StorePackageUpdateState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePackageUpdateResult", "OverallState", 6usize, |this| ((**(this as *const *const IStorePackageUpdateResult_Vtbl)).OverallState)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StorePackageUpdateStatuses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePackageUpdateResult", "StorePackageUpdateStatuses", 7usize, |this| ((**(this as *const *const IStorePackageUpdateResult_Vtbl)).StorePackageUpdateStatuses)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OverallState,
+            #[cfg(feature = "Foundation_Collections")]
+            StorePackageUpdateStatuses,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StorePackageUpdateStatuses: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorePackageUpdateResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorePackageUpdateResult2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StoreQueueItems(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePackageUpdateResult2", "StoreQueueItems", 6usize, |this| ((**(this as *const *const IStorePackageUpdateResult2_Vtbl)).StoreQueueItems)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            StoreQueueItems,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StoreQueueItems: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorePackageUpdateResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorePrice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FormattedBasePrice(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePrice", "FormattedBasePrice", 6usize, |this| ((**(this as *const *const IStorePrice_Vtbl)).FormattedBasePrice)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormattedPrice(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePrice", "FormattedPrice", 7usize, |this| ((**(this as *const *const IStorePrice_Vtbl)).FormattedPrice)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOnSale(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePrice", "IsOnSale", 8usize, |this| ((**(this as *const *const IStorePrice_Vtbl)).IsOnSale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaleEndDate(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePrice", "SaleEndDate", 9usize, |this| ((**(this as *const *const IStorePrice_Vtbl)).SaleEndDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrencyCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePrice", "CurrencyCode", 10usize, |this| ((**(this as *const *const IStorePrice_Vtbl)).CurrencyCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormattedRecurrencePrice(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePrice", "FormattedRecurrencePrice", 11usize, |this| ((**(this as *const *const IStorePrice_Vtbl)).FormattedRecurrencePrice)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FormattedBasePrice,
+            FormattedPrice,
+            IsOnSale,
+            SaleEndDate,
+            CurrencyCode,
+            FormattedRecurrencePrice,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorePrice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorePrice2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UnformattedBasePrice(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePrice2", "UnformattedBasePrice", 6usize, |this| ((**(this as *const *const IStorePrice2_Vtbl)).UnformattedBasePrice)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnformattedPrice(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePrice2", "UnformattedPrice", 7usize, |this| ((**(this as *const *const IStorePrice2_Vtbl)).UnformattedPrice)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnformattedRecurrencePrice(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePrice2", "UnformattedRecurrencePrice", 8usize, |this| ((**(this as *const *const IStorePrice2_Vtbl)).UnformattedRecurrencePrice)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UnformattedBasePrice,
+            UnformattedPrice,
+            UnformattedRecurrencePrice,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorePrice2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStoreProduct_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StoreId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "StoreId", 6usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).StoreId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "Language", 7usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "Title", 8usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "Description", 9usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProductKind(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "ProductKind", 10usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).ProductKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasDigitalDownload(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "HasDigitalDownload", 11usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).HasDigitalDownload)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Keywords(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "Keywords", 12usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).Keywords)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Images(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "Images", 13usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).Images)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Videos(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "Videos", 14usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).Videos)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Skus(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "Skus", 15usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).Skus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInUserCollection(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "IsInUserCollection", 16usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).IsInUserCollection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Price(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "Price", 17usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).Price)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedJsonData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "ExtendedJsonData", 18usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).ExtendedJsonData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LinkUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "LinkUri", 19usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).LinkUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsAnySkuInstalledAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "GetIsAnySkuInstalledAsync", 20usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).GetIsAnySkuInstalledAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPurchaseAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "RequestPurchaseAsync", 21usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).RequestPurchaseAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPurchaseWithPurchasePropertiesAsync(this: *mut core::ffi::c_void, storepurchaseproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "RequestPurchaseWithPurchasePropertiesAsync", 22usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).RequestPurchaseWithPurchasePropertiesAsync)(this, storepurchaseproperties, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InAppOfferToken(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProduct", "InAppOfferToken", 23usize, |this| ((**(this as *const *const IStoreProduct_Vtbl)).InAppOfferToken)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StoreId,
+            Language,
+            Title,
+            Description,
+            ProductKind,
+            HasDigitalDownload,
+            #[cfg(feature = "Foundation_Collections")]
+            Keywords,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Keywords: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Images,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Images: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Videos,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Videos: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Skus,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Skus: 0,
+            IsInUserCollection,
+            Price,
+            ExtendedJsonData,
+            LinkUri,
+            GetIsAnySkuInstalledAsync,
+            RequestPurchaseAsync,
+            RequestPurchaseWithPurchasePropertiesAsync,
+            InAppOfferToken,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreProduct as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStoreProductOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ActionFilters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProductOptions", "ActionFilters", 6usize, |this| ((**(this as *const *const IStoreProductOptions_Vtbl)).ActionFilters)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ActionFilters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ActionFilters: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreProductOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStoreProductPagedQueryResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Products(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProductPagedQueryResult", "Products", 6usize, |this| ((**(this as *const *const IStoreProductPagedQueryResult_Vtbl)).Products)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasMoreResults(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProductPagedQueryResult", "HasMoreResults", 7usize, |this| ((**(this as *const *const IStoreProductPagedQueryResult_Vtbl)).HasMoreResults)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProductPagedQueryResult", "ExtendedError", 8usize, |this| ((**(this as *const *const IStoreProductPagedQueryResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProductPagedQueryResult", "GetNextAsync", 9usize, |this| ((**(this as *const *const IStoreProductPagedQueryResult_Vtbl)).GetNextAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Products,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Products: 0,
+            HasMoreResults,
+            ExtendedError,
+            GetNextAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreProductPagedQueryResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStoreProductQueryResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Products(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProductQueryResult", "Products", 6usize, |this| ((**(this as *const *const IStoreProductQueryResult_Vtbl)).Products)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProductQueryResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IStoreProductQueryResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Products,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Products: 0,
+            ExtendedError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreProductQueryResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreProductResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Product(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProductResult", "Product", 6usize, |this| ((**(this as *const *const IStoreProductResult_Vtbl)).Product)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreProductResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IStoreProductResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Product, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreProductResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorePurchaseProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePurchaseProperties", "Name", 6usize, |this| ((**(this as *const *const IStorePurchaseProperties_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePurchaseProperties", "SetName", 7usize, |this| ((**(this as *const *const IStorePurchaseProperties_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedJsonData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePurchaseProperties", "ExtendedJsonData", 8usize, |this| ((**(this as *const *const IStorePurchaseProperties_Vtbl)).ExtendedJsonData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtendedJsonData(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePurchaseProperties", "SetExtendedJsonData", 9usize, |this| ((**(this as *const *const IStorePurchaseProperties_Vtbl)).SetExtendedJsonData)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, SetName, ExtendedJsonData, SetExtendedJsonData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorePurchaseProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorePurchasePropertiesFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePurchasePropertiesFactory", "Create", 6usize, |this| ((**(this as *const *const IStorePurchasePropertiesFactory_Vtbl)).Create)(this, name, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorePurchasePropertiesFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorePurchaseResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut StorePurchaseStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePurchaseResult", "Status", 6usize, |this| ((**(this as *const *const IStorePurchaseResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorePurchaseResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IStorePurchaseResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorePurchaseResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreQueueItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProductId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItem", "ProductId", 6usize, |this| ((**(this as *const *const IStoreQueueItem_Vtbl)).ProductId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItem", "PackageFamilyName", 7usize, |this| ((**(this as *const *const IStoreQueueItem_Vtbl)).PackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallKind(this: *mut core::ffi::c_void, result__: *mut StoreQueueItemKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItem", "InstallKind", 8usize, |this| ((**(this as *const *const IStoreQueueItem_Vtbl)).InstallKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentStatus(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItem", "GetCurrentStatus", 9usize, |this| ((**(this as *const *const IStoreQueueItem_Vtbl)).GetCurrentStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Completed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItem", "Completed", 10usize, |this| ((**(this as *const *const IStoreQueueItem_Vtbl)).Completed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItem", "RemoveCompleted", 11usize, |this| ((**(this as *const *const IStoreQueueItem_Vtbl)).RemoveCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItem", "StatusChanged", 12usize, |this| ((**(this as *const *const IStoreQueueItem_Vtbl)).StatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItem", "RemoveStatusChanged", 13usize, |this| ((**(this as *const *const IStoreQueueItem_Vtbl)).RemoveStatusChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProductId,
+            PackageFamilyName,
+            InstallKind,
+            GetCurrentStatus,
+            Completed,
+            RemoveCompleted,
+            StatusChanged,
+            RemoveStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreQueueItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreQueueItem2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CancelInstallAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItem2", "CancelInstallAsync", 6usize, |this| ((**(this as *const *const IStoreQueueItem2_Vtbl)).CancelInstallAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PauseInstallAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItem2", "PauseInstallAsync", 7usize, |this| ((**(this as *const *const IStoreQueueItem2_Vtbl)).PauseInstallAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeInstallAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItem2", "ResumeInstallAsync", 8usize, |this| ((**(this as *const *const IStoreQueueItem2_Vtbl)).ResumeInstallAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CancelInstallAsync, PauseInstallAsync, ResumeInstallAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreQueueItem2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreQueueItemCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItemCompletedEventArgs", "Status", 6usize, |this| ((**(this as *const *const IStoreQueueItemCompletedEventArgs_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreQueueItemCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreQueueItemStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PackageInstallState(this: *mut core::ffi::c_void, result__: *mut StoreQueueItemState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItemStatus", "PackageInstallState", 6usize, |this| ((**(this as *const *const IStoreQueueItemStatus_Vtbl)).PackageInstallState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageInstallExtendedState(this: *mut core::ffi::c_void, result__: *mut StoreQueueItemExtendedState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItemStatus", "PackageInstallExtendedState", 7usize, |this| ((**(this as *const *const IStoreQueueItemStatus_Vtbl)).PackageInstallExtendedState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateStatus(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<StorePackageUpdateStatus>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItemStatus", "UpdateStatus", 8usize, |this| ((**(this as *const *const IStoreQueueItemStatus_Vtbl)).UpdateStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreQueueItemStatus", "ExtendedError", 9usize, |this| ((**(this as *const *const IStoreQueueItemStatus_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PackageInstallState,
+            PackageInstallExtendedState,
+            UpdateStatus,
+            ExtendedError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreQueueItemStatus as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreRateAndReviewResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreRateAndReviewResult", "ExtendedError", 6usize, |this| ((**(this as *const *const IStoreRateAndReviewResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedJsonData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreRateAndReviewResult", "ExtendedJsonData", 7usize, |this| ((**(this as *const *const IStoreRateAndReviewResult_Vtbl)).ExtendedJsonData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WasUpdated(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreRateAndReviewResult", "WasUpdated", 8usize, |this| ((**(this as *const *const IStoreRateAndReviewResult_Vtbl)).WasUpdated)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut StoreRateAndReviewStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreRateAndReviewResult", "Status", 9usize, |this| ((**(this as *const *const IStoreRateAndReviewResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError, ExtendedJsonData, WasUpdated, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreRateAndReviewResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreRequestHelperStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendRequestAsync(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void, requestkind: u32, parametersasjson: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreRequestHelperStatics", "SendRequestAsync", 6usize, |this| ((**(this as *const *const IStoreRequestHelperStatics_Vtbl)).SendRequestAsync)(this, context, requestkind, parametersasjson, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SendRequestAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreRequestHelperStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreSendRequestResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Response(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSendRequestResult", "Response", 6usize, |this| ((**(this as *const *const IStoreSendRequestResult_Vtbl)).Response)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSendRequestResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IStoreSendRequestResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Response, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreSendRequestResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Web_Http")]
+impl windows_core::imp::ProxyVtable for IStoreSendRequestResult2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Web_Http")]
+        unsafe extern "system" fn HttpStatusCode(this: *mut core::ffi::c_void, result__: *mut super::super::Web::Http::HttpStatusCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSendRequestResult2", "HttpStatusCode", 6usize, |this| ((**(this as *const *const IStoreSendRequestResult2_Vtbl)).HttpStatusCode)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Web_Http")]
+            HttpStatusCode,
+            #[cfg(not(feature = "Web_Http"))]
+            HttpStatusCode: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreSendRequestResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStoreSku_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StoreId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "StoreId", 6usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).StoreId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "Language", 7usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "Title", 8usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "Description", 9usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTrial(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "IsTrial", 10usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).IsTrial)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomDeveloperData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "CustomDeveloperData", 11usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).CustomDeveloperData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Images(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "Images", 12usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).Images)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Videos(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "Videos", 13usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).Videos)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Availabilities(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "Availabilities", 14usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).Availabilities)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Price(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "Price", 15usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).Price)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedJsonData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "ExtendedJsonData", 16usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).ExtendedJsonData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInUserCollection(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "IsInUserCollection", 17usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).IsInUserCollection)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BundledSkus(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "BundledSkus", 18usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).BundledSkus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CollectionData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "CollectionData", 19usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).CollectionData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsInstalledAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "GetIsInstalledAsync", 20usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).GetIsInstalledAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPurchaseAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "RequestPurchaseAsync", 21usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).RequestPurchaseAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPurchaseWithPurchasePropertiesAsync(this: *mut core::ffi::c_void, storepurchaseproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "RequestPurchaseWithPurchasePropertiesAsync", 22usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).RequestPurchaseWithPurchasePropertiesAsync)(this, storepurchaseproperties, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSubscription(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "IsSubscription", 23usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).IsSubscription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubscriptionInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSku", "SubscriptionInfo", 24usize, |this| ((**(this as *const *const IStoreSku_Vtbl)).SubscriptionInfo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StoreId,
+            Language,
+            Title,
+            Description,
+            IsTrial,
+            CustomDeveloperData,
+            #[cfg(feature = "Foundation_Collections")]
+            Images,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Images: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Videos,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Videos: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Availabilities,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Availabilities: 0,
+            Price,
+            ExtendedJsonData,
+            IsInUserCollection,
+            #[cfg(feature = "Foundation_Collections")]
+            BundledSkus,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BundledSkus: 0,
+            CollectionData,
+            GetIsInstalledAsync,
+            RequestPurchaseAsync,
+            RequestPurchaseWithPurchasePropertiesAsync,
+            IsSubscription,
+            SubscriptionInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreSku as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreSubscriptionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BillingPeriod(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSubscriptionInfo", "BillingPeriod", 6usize, |this| ((**(this as *const *const IStoreSubscriptionInfo_Vtbl)).BillingPeriod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BillingPeriodUnit(this: *mut core::ffi::c_void, result__: *mut StoreDurationUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSubscriptionInfo", "BillingPeriodUnit", 7usize, |this| ((**(this as *const *const IStoreSubscriptionInfo_Vtbl)).BillingPeriodUnit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasTrialPeriod(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSubscriptionInfo", "HasTrialPeriod", 8usize, |this| ((**(this as *const *const IStoreSubscriptionInfo_Vtbl)).HasTrialPeriod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrialPeriod(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSubscriptionInfo", "TrialPeriod", 9usize, |this| ((**(this as *const *const IStoreSubscriptionInfo_Vtbl)).TrialPeriod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrialPeriodUnit(this: *mut core::ffi::c_void, result__: *mut StoreDurationUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreSubscriptionInfo", "TrialPeriodUnit", 10usize, |this| ((**(this as *const *const IStoreSubscriptionInfo_Vtbl)).TrialPeriodUnit)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BillingPeriod,
+            BillingPeriodUnit,
+            HasTrialPeriod,
+            TrialPeriod,
+            TrialPeriodUnit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreSubscriptionInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreUninstallStorePackageResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreUninstallStorePackageResult", "ExtendedError", 6usize, |this| ((**(this as *const *const IStoreUninstallStorePackageResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut StoreUninstallStorePackageStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreUninstallStorePackageResult", "Status", 7usize, |this| ((**(this as *const *const IStoreUninstallStorePackageResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreUninstallStorePackageResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStoreVideo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreVideo", "Uri", 6usize, |this| ((**(this as *const *const IStoreVideo_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoPurposeTag(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreVideo", "VideoPurposeTag", 7usize, |this| ((**(this as *const *const IStoreVideo_Vtbl)).VideoPurposeTag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreVideo", "Width", 8usize, |this| ((**(this as *const *const IStoreVideo_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreVideo", "Height", 9usize, |this| ((**(this as *const *const IStoreVideo_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Caption(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreVideo", "Caption", 10usize, |this| ((**(this as *const *const IStoreVideo_Vtbl)).Caption)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreviewImage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStoreVideo", "PreviewImage", 11usize, |this| ((**(this as *const *const IStoreVideo_Vtbl)).PreviewImage)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uri, VideoPurposeTag, Width, Height, Caption, PreviewImage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStoreVideo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -970,6 +970,7 @@ impl TargetedContentAppInstallationState {
 impl windows_core::TypeKind for TargetedContentAppInstallationState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TargetedContentAppInstallationState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TargetedContentAppInstallationState").field(&self.0).finish()
@@ -989,6 +990,7 @@ impl TargetedContentAvailability {
 impl windows_core::TypeKind for TargetedContentAvailability {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TargetedContentAvailability {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TargetedContentAvailability").field(&self.0).finish()
@@ -1018,6 +1020,7 @@ impl TargetedContentInteraction {
 impl windows_core::TypeKind for TargetedContentInteraction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TargetedContentInteraction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TargetedContentInteraction").field(&self.0).finish()
@@ -1037,6 +1040,7 @@ impl TargetedContentObjectKind {
 impl windows_core::TypeKind for TargetedContentObjectKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TargetedContentObjectKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TargetedContentObjectKind").field(&self.0).finish()
@@ -1067,6 +1071,7 @@ impl TargetedContentValueKind {
 impl windows_core::TypeKind for TargetedContentValueKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TargetedContentValueKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TargetedContentValueKind").field(&self.0).finish()
@@ -1075,3 +1080,5 @@ impl core::fmt::Debug for TargetedContentValueKind {
 impl windows_core::RuntimeType for TargetedContentValueKind {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Services.TargetedContent.TargetedContentValueKind;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
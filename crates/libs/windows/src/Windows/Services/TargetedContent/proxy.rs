@@ -0,0 +1,445 @@
+impl windows_core::imp::ProxyVtable for ITargetedContentAction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InvokeAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentAction", "InvokeAsync", 6usize, |this| ((**(this as *const *const ITargetedContentAction_Vtbl)).InvokeAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InvokeAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetedContentAction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetedContentAvailabilityChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentAvailabilityChangedEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const ITargetedContentAvailabilityChangedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetedContentAvailabilityChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetedContentChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentChangedEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const ITargetedContentChangedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasPreviousContentExpired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentChangedEventArgs", "HasPreviousContentExpired", 7usize, |this| ((**(this as *const *const ITargetedContentChangedEventArgs_Vtbl)).HasPreviousContentExpired)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral, HasPreviousContentExpired }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetedContentChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITargetedContentCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentCollection", "Id", 6usize, |this| ((**(this as *const *const ITargetedContentCollection_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInteraction(this: *mut core::ffi::c_void, interaction: TargetedContentInteraction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentCollection", "ReportInteraction", 7usize, |this| ((**(this as *const *const ITargetedContentCollection_Vtbl)).ReportInteraction)(this, interaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCustomInteraction(this: *mut core::ffi::c_void, custominteractionname: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentCollection", "ReportCustomInteraction", 8usize, |this| ((**(this as *const *const ITargetedContentCollection_Vtbl)).ReportCustomInteraction)(this, custominteractionname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentCollection", "Path", 9usize, |this| ((**(this as *const *const ITargetedContentCollection_Vtbl)).Path)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentCollection", "Properties", 10usize, |this| ((**(this as *const *const ITargetedContentCollection_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Collections(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentCollection", "Collections", 11usize, |this| ((**(this as *const *const ITargetedContentCollection_Vtbl)).Collections)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Items(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentCollection", "Items", 12usize, |this| ((**(this as *const *const ITargetedContentCollection_Vtbl)).Items)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            ReportInteraction,
+            ReportCustomInteraction,
+            Path,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Collections,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Collections: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Items,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Items: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetedContentCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetedContentContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentContainer", "Id", 6usize, |this| ((**(this as *const *const ITargetedContentContainer_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentContainer", "Timestamp", 7usize, |this| ((**(this as *const *const ITargetedContentContainer_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Availability(this: *mut core::ffi::c_void, result__: *mut TargetedContentAvailability) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentContainer", "Availability", 8usize, |this| ((**(this as *const *const ITargetedContentContainer_Vtbl)).Availability)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentContainer", "Content", 9usize, |this| ((**(this as *const *const ITargetedContentContainer_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectSingleObject(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentContainer", "SelectSingleObject", 10usize, |this| ((**(this as *const *const ITargetedContentContainer_Vtbl)).SelectSingleObject)(this, path, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Timestamp, Availability, Content, SelectSingleObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetedContentContainer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetedContentContainerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAsync(this: *mut core::ffi::c_void, contentid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentContainerStatics", "GetAsync", 6usize, |this| ((**(this as *const *const ITargetedContentContainerStatics_Vtbl)).GetAsync)(this, contentid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetedContentContainerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITargetedContentImage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentImage", "Height", 6usize, |this| ((**(this as *const *const ITargetedContentImage_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentImage", "Width", 7usize, |this| ((**(this as *const *const ITargetedContentImage_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Height, Width }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetedContentImage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITargetedContentItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentItem", "Path", 6usize, |this| ((**(this as *const *const ITargetedContentItem_Vtbl)).Path)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInteraction(this: *mut core::ffi::c_void, interaction: TargetedContentInteraction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentItem", "ReportInteraction", 7usize, |this| ((**(this as *const *const ITargetedContentItem_Vtbl)).ReportInteraction)(this, interaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCustomInteraction(this: *mut core::ffi::c_void, custominteractionname: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentItem", "ReportCustomInteraction", 8usize, |this| ((**(this as *const *const ITargetedContentItem_Vtbl)).ReportCustomInteraction)(this, custominteractionname), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentItem", "State", 9usize, |this| ((**(this as *const *const ITargetedContentItem_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentItem", "Properties", 10usize, |this| ((**(this as *const *const ITargetedContentItem_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Collections(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentItem", "Collections", 11usize, |this| ((**(this as *const *const ITargetedContentItem_Vtbl)).Collections)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Path,
+            ReportInteraction,
+            ReportCustomInteraction,
+            State,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Collections,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Collections: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetedContentItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetedContentItemState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShouldDisplay(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentItemState", "ShouldDisplay", 6usize, |this| ((**(this as *const *const ITargetedContentItemState_Vtbl)).ShouldDisplay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppInstallationState(this: *mut core::ffi::c_void, result__: *mut TargetedContentAppInstallationState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentItemState", "AppInstallationState", 7usize, |this| ((**(this as *const *const ITargetedContentItemState_Vtbl)).AppInstallationState)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShouldDisplay, AppInstallationState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetedContentItemState as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetedContentObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ObjectKind(this: *mut core::ffi::c_void, result__: *mut TargetedContentObjectKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentObject", "ObjectKind", 6usize, |this| ((**(this as *const *const ITargetedContentObject_Vtbl)).ObjectKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Collection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentObject", "Collection", 7usize, |this| ((**(this as *const *const ITargetedContentObject_Vtbl)).Collection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentObject", "Item", 8usize, |this| ((**(this as *const *const ITargetedContentObject_Vtbl)).Item)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentObject", "Value", 9usize, |this| ((**(this as *const *const ITargetedContentObject_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ObjectKind, Collection, Item, Value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetedContentObject as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetedContentStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentStateChangedEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const ITargetedContentStateChangedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetedContentStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetedContentSubscription_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscription", "Id", 6usize, |this| ((**(this as *const *const ITargetedContentSubscription_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentContainerAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscription", "GetContentContainerAsync", 7usize, |this| ((**(this as *const *const ITargetedContentSubscription_Vtbl)).GetContentContainerAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscription", "ContentChanged", 8usize, |this| ((**(this as *const *const ITargetedContentSubscription_Vtbl)).ContentChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveContentChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscription", "RemoveContentChanged", 9usize, |this| ((**(this as *const *const ITargetedContentSubscription_Vtbl)).RemoveContentChanged)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvailabilityChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscription", "AvailabilityChanged", 10usize, |this| ((**(this as *const *const ITargetedContentSubscription_Vtbl)).AvailabilityChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAvailabilityChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscription", "RemoveAvailabilityChanged", 11usize, |this| ((**(this as *const *const ITargetedContentSubscription_Vtbl)).RemoveAvailabilityChanged)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscription", "StateChanged", 12usize, |this| ((**(this as *const *const ITargetedContentSubscription_Vtbl)).StateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStateChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscription", "RemoveStateChanged", 13usize, |this| ((**(this as *const *const ITargetedContentSubscription_Vtbl)).RemoveStateChanged)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            GetContentContainerAsync,
+            ContentChanged,
+            RemoveContentChanged,
+            AvailabilityChanged,
+            RemoveAvailabilityChanged,
+            StateChanged,
+            RemoveStateChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetedContentSubscription as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITargetedContentSubscriptionOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SubscriptionId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscriptionOptions", "SubscriptionId", 6usize, |this| ((**(this as *const *const ITargetedContentSubscriptionOptions_Vtbl)).SubscriptionId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowPartialContentAvailability(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscriptionOptions", "AllowPartialContentAvailability", 7usize, |this| ((**(this as *const *const ITargetedContentSubscriptionOptions_Vtbl)).AllowPartialContentAvailability)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowPartialContentAvailability(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscriptionOptions", "SetAllowPartialContentAvailability", 8usize, |this| ((**(this as *const *const ITargetedContentSubscriptionOptions_Vtbl)).SetAllowPartialContentAvailability)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CloudQueryParameters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscriptionOptions", "CloudQueryParameters", 9usize, |this| ((**(this as *const *const ITargetedContentSubscriptionOptions_Vtbl)).CloudQueryParameters)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn LocalFilters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscriptionOptions", "LocalFilters", 10usize, |this| ((**(this as *const *const ITargetedContentSubscriptionOptions_Vtbl)).LocalFilters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscriptionOptions", "Update", 11usize, |this| ((**(this as *const *const ITargetedContentSubscriptionOptions_Vtbl)).Update)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SubscriptionId,
+            AllowPartialContentAvailability,
+            SetAllowPartialContentAvailability,
+            #[cfg(feature = "Foundation_Collections")]
+            CloudQueryParameters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CloudQueryParameters: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            LocalFilters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            LocalFilters: 0,
+            Update,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetedContentSubscriptionOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetedContentSubscriptionStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAsync(this: *mut core::ffi::c_void, subscriptionid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscriptionStatics", "GetAsync", 6usize, |this| ((**(this as *const *const ITargetedContentSubscriptionStatics_Vtbl)).GetAsync)(this, subscriptionid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptions(this: *mut core::ffi::c_void, subscriptionid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentSubscriptionStatics", "GetOptions", 7usize, |this| ((**(this as *const *const ITargetedContentSubscriptionStatics_Vtbl)).GetOptions)(this, subscriptionid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAsync, GetOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetedContentSubscriptionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ITargetedContentValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ValueKind(this: *mut core::ffi::c_void, result__: *mut TargetedContentValueKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "ValueKind", 6usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).ValueKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "Path", 7usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).Path)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn String(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "String", 8usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).String)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "Uri", 9usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Number(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "Number", 10usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).Number)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Boolean(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "Boolean", 11usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).Boolean)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn File(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "File", 12usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).File)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImageFile(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "ImageFile", 13usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).ImageFile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Action(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "Action", 14usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).Action)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Strings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "Strings", 15usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).Strings)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Uris(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "Uris", 16usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).Uris)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Numbers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "Numbers", 17usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).Numbers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Booleans(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "Booleans", 18usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).Booleans)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn Files(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "Files", 19usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).Files)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ImageFiles(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "ImageFiles", 20usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).ImageFiles)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Actions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetedContentValue", "Actions", 21usize, |this| ((**(this as *const *const ITargetedContentValue_Vtbl)).Actions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ValueKind,
+            Path,
+            String,
+            Uri,
+            Number,
+            Boolean,
+            #[cfg(feature = "Storage_Streams")]
+            File,
+            #[cfg(not(feature = "Storage_Streams"))]
+            File: 0,
+            ImageFile,
+            Action,
+            #[cfg(feature = "Foundation_Collections")]
+            Strings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Strings: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Uris,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Uris: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Numbers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Numbers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Booleans,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Booleans: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            Files,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            Files: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ImageFiles,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ImageFiles: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Actions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Actions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetedContentValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
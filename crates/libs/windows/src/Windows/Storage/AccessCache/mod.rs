@@ -669,6 +669,7 @@ impl AccessCacheOptions {
 impl windows_core::TypeKind for AccessCacheOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AccessCacheOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AccessCacheOptions").field(&self.0).finish()
@@ -720,6 +721,7 @@ impl RecentStorageItemVisibility {
 impl windows_core::TypeKind for RecentStorageItemVisibility {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RecentStorageItemVisibility {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RecentStorageItemVisibility").field(&self.0).finish()
@@ -729,7 +731,8 @@ impl windows_core::RuntimeType for RecentStorageItemVisibility {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Storage.AccessCache.RecentStorageItemVisibility;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AccessListEntry {
     pub Token: windows_core::HSTRING,
     pub Metadata: windows_core::HSTRING,
@@ -747,3 +750,5 @@ impl Default for AccessListEntry {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,171 @@
+impl windows_core::imp::ProxyVtable for IItemRemovedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemovedEntry(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<AccessListEntry>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IItemRemovedEventArgs", "RemovedEntry", 6usize, |this| ((**(this as *const *const IItemRemovedEventArgs_Vtbl)).RemovedEntry)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RemovedEntry }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IItemRemovedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageApplicationPermissionsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FutureAccessList(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageApplicationPermissionsStatics", "FutureAccessList", 6usize, |this| ((**(this as *const *const IStorageApplicationPermissionsStatics_Vtbl)).FutureAccessList)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MostRecentlyUsedList(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageApplicationPermissionsStatics", "MostRecentlyUsedList", 7usize, |this| ((**(this as *const *const IStorageApplicationPermissionsStatics_Vtbl)).MostRecentlyUsedList)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FutureAccessList, MostRecentlyUsedList }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageApplicationPermissionsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IStorageApplicationPermissionsStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetFutureAccessListForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageApplicationPermissionsStatics2", "GetFutureAccessListForUser", 6usize, |this| ((**(this as *const *const IStorageApplicationPermissionsStatics2_Vtbl)).GetFutureAccessListForUser)(this, user, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetMostRecentlyUsedListForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageApplicationPermissionsStatics2", "GetMostRecentlyUsedListForUser", 7usize, |this| ((**(this as *const *const IStorageApplicationPermissionsStatics2_Vtbl)).GetMostRecentlyUsedListForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetFutureAccessListForUser,
+            #[cfg(not(feature = "System"))]
+            GetFutureAccessListForUser: 0,
+            #[cfg(feature = "System")]
+            GetMostRecentlyUsedListForUser,
+            #[cfg(not(feature = "System"))]
+            GetMostRecentlyUsedListForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageApplicationPermissionsStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageItemAccessList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddOverloadDefaultMetadata(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "AddOverloadDefaultMetadata", 6usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).AddOverloadDefaultMetadata)(this, file, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, metadata: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "Add", 7usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).Add)(this, file, metadata, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddOrReplaceOverloadDefaultMetadata(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>, file: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "AddOrReplaceOverloadDefaultMetadata", 8usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).AddOrReplaceOverloadDefaultMetadata)(this, token, file), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddOrReplace(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>, file: *mut core::ffi::c_void, metadata: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "AddOrReplace", 9usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).AddOrReplace)(this, token, file, metadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemAsync(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "GetItemAsync", 10usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).GetItemAsync)(this, token, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileAsync(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "GetFileAsync", 11usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).GetFileAsync)(this, token, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFolderAsync(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "GetFolderAsync", 12usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).GetFolderAsync)(this, token, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemWithOptionsAsync(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>, options: AccessCacheOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "GetItemWithOptionsAsync", 13usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).GetItemWithOptionsAsync)(this, token, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileWithOptionsAsync(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>, options: AccessCacheOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "GetFileWithOptionsAsync", 14usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).GetFileWithOptionsAsync)(this, token, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFolderWithOptionsAsync(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>, options: AccessCacheOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "GetFolderWithOptionsAsync", 15usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).GetFolderWithOptionsAsync)(this, token, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "Remove", 16usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).Remove)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainsItem(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "ContainsItem", 17usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).ContainsItem)(this, token, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "Clear", 18usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckAccess(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "CheckAccess", 19usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).CheckAccess)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Entries(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "Entries", 20usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).Entries)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaximumItemsAllowed(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemAccessList", "MaximumItemsAllowed", 21usize, |this| ((**(this as *const *const IStorageItemAccessList_Vtbl)).MaximumItemsAllowed)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddOverloadDefaultMetadata,
+            Add,
+            AddOrReplaceOverloadDefaultMetadata,
+            AddOrReplace,
+            GetItemAsync,
+            GetFileAsync,
+            GetFolderAsync,
+            GetItemWithOptionsAsync,
+            GetFileWithOptionsAsync,
+            GetFolderWithOptionsAsync,
+            Remove,
+            ContainsItem,
+            Clear,
+            CheckAccess,
+            #[cfg(feature = "Foundation_Collections")]
+            Entries,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Entries: 0,
+            MaximumItemsAllowed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageItemAccessList as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageItemMostRecentlyUsedList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ItemRemoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemMostRecentlyUsedList", "ItemRemoved", 6usize, |this| ((**(this as *const *const IStorageItemMostRecentlyUsedList_Vtbl)).ItemRemoved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItemRemoved(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemMostRecentlyUsedList", "RemoveItemRemoved", 7usize, |this| ((**(this as *const *const IStorageItemMostRecentlyUsedList_Vtbl)).RemoveItemRemoved)(this, eventcookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ItemRemoved, RemoveItemRemoved }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageItemMostRecentlyUsedList as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageItemMostRecentlyUsedList2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddWithMetadataAndVisibility(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, metadata: core::mem::MaybeUninit<windows_core::HSTRING>, visibility: RecentStorageItemVisibility, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemMostRecentlyUsedList2", "AddWithMetadataAndVisibility", 6usize, |this| ((**(this as *const *const IStorageItemMostRecentlyUsedList2_Vtbl)).AddWithMetadataAndVisibility)(this, file, metadata, visibility, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddOrReplaceWithMetadataAndVisibility(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>, file: *mut core::ffi::c_void, metadata: core::mem::MaybeUninit<windows_core::HSTRING>, visibility: RecentStorageItemVisibility) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemMostRecentlyUsedList2", "AddOrReplaceWithMetadataAndVisibility", 7usize, |this| ((**(this as *const *const IStorageItemMostRecentlyUsedList2_Vtbl)).AddOrReplaceWithMetadataAndVisibility)(this, token, file, metadata, visibility), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddWithMetadataAndVisibility,
+            AddOrReplaceWithMetadataAndVisibility,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageItemMostRecentlyUsedList2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1171,3 +1171,5 @@ impl windows_core::RuntimeName for FolderInformation {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
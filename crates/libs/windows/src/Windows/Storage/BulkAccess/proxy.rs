@@ -0,0 +1,192 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IFileInformationFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetItemsAsync(this: *mut core::ffi::c_void, startindex: u32, maxitemstoretrieve: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileInformationFactory", "GetItemsAsync", 6usize, |this| ((**(this as *const *const IFileInformationFactory_Vtbl)).GetItemsAsync)(this, startindex, maxitemstoretrieve, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetItemsAsyncDefaultStartAndCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileInformationFactory", "GetItemsAsyncDefaultStartAndCount", 7usize, |this| ((**(this as *const *const IFileInformationFactory_Vtbl)).GetItemsAsyncDefaultStartAndCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetFilesAsync(this: *mut core::ffi::c_void, startindex: u32, maxitemstoretrieve: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileInformationFactory", "GetFilesAsync", 8usize, |this| ((**(this as *const *const IFileInformationFactory_Vtbl)).GetFilesAsync)(this, startindex, maxitemstoretrieve, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetFilesAsyncDefaultStartAndCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileInformationFactory", "GetFilesAsyncDefaultStartAndCount", 9usize, |this| ((**(this as *const *const IFileInformationFactory_Vtbl)).GetFilesAsyncDefaultStartAndCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetFoldersAsync(this: *mut core::ffi::c_void, startindex: u32, maxitemstoretrieve: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileInformationFactory", "GetFoldersAsync", 10usize, |this| ((**(this as *const *const IFileInformationFactory_Vtbl)).GetFoldersAsync)(this, startindex, maxitemstoretrieve, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetFoldersAsyncDefaultStartAndCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileInformationFactory", "GetFoldersAsyncDefaultStartAndCount", 11usize, |this| ((**(this as *const *const IFileInformationFactory_Vtbl)).GetFoldersAsyncDefaultStartAndCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVirtualizedItemsVector(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileInformationFactory", "GetVirtualizedItemsVector", 12usize, |this| ((**(this as *const *const IFileInformationFactory_Vtbl)).GetVirtualizedItemsVector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVirtualizedFilesVector(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileInformationFactory", "GetVirtualizedFilesVector", 13usize, |this| ((**(this as *const *const IFileInformationFactory_Vtbl)).GetVirtualizedFilesVector)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVirtualizedFoldersVector(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileInformationFactory", "GetVirtualizedFoldersVector", 14usize, |this| ((**(this as *const *const IFileInformationFactory_Vtbl)).GetVirtualizedFoldersVector)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetItemsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetItemsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetItemsAsyncDefaultStartAndCount,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetItemsAsyncDefaultStartAndCount: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetFilesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetFilesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetFilesAsyncDefaultStartAndCount,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetFilesAsyncDefaultStartAndCount: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetFoldersAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetFoldersAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetFoldersAsyncDefaultStartAndCount,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetFoldersAsyncDefaultStartAndCount: 0,
+            GetVirtualizedItemsVector,
+            GetVirtualizedFilesVector,
+            GetVirtualizedFoldersVector,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileInformationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Search"))]
+impl windows_core::imp::ProxyVtable for IFileInformationFactoryFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Search"))]
+        unsafe extern "system" fn CreateWithMode(this: *mut core::ffi::c_void, queryresult: *mut core::ffi::c_void, mode: super::FileProperties::ThumbnailMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileInformationFactoryFactory", "CreateWithMode", 6usize, |this| ((**(this as *const *const IFileInformationFactoryFactory_Vtbl)).CreateWithMode)(this, queryresult, mode, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Search"))]
+        unsafe extern "system" fn CreateWithModeAndSize(this: *mut core::ffi::c_void, queryresult: *mut core::ffi::c_void, mode: super::FileProperties::ThumbnailMode, requestedthumbnailsize: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileInformationFactoryFactory", "CreateWithModeAndSize", 7usize, |this| ((**(this as *const *const IFileInformationFactoryFactory_Vtbl)).CreateWithModeAndSize)(this, queryresult, mode, requestedthumbnailsize, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Search"))]
+        unsafe extern "system" fn CreateWithModeAndSizeAndOptions(this: *mut core::ffi::c_void, queryresult: *mut core::ffi::c_void, mode: super::FileProperties::ThumbnailMode, requestedthumbnailsize: u32, thumbnailoptions: super::FileProperties::ThumbnailOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileInformationFactoryFactory", "CreateWithModeAndSizeAndOptions", 8usize, |this| ((**(this as *const *const IFileInformationFactoryFactory_Vtbl)).CreateWithModeAndSizeAndOptions)(this, queryresult, mode, requestedthumbnailsize, thumbnailoptions, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Search"))]
+        unsafe extern "system" fn CreateWithModeAndSizeAndOptionsAndFlags(this: *mut core::ffi::c_void, queryresult: *mut core::ffi::c_void, mode: super::FileProperties::ThumbnailMode, requestedthumbnailsize: u32, thumbnailoptions: super::FileProperties::ThumbnailOptions, delayload: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileInformationFactoryFactory", "CreateWithModeAndSizeAndOptionsAndFlags", 9usize, |this| ((**(this as *const *const IFileInformationFactoryFactory_Vtbl)).CreateWithModeAndSizeAndOptionsAndFlags)(this, queryresult, mode, requestedthumbnailsize, thumbnailoptions, delayload, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Search"))]
+            CreateWithMode,
+            #[cfg(not(all(feature = "Storage_FileProperties", feature = "Storage_Search")))]
+            CreateWithMode: 0,
+            #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Search"))]
+            CreateWithModeAndSize,
+            #[cfg(not(all(feature = "Storage_FileProperties", feature = "Storage_Search")))]
+            CreateWithModeAndSize: 0,
+            #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Search"))]
+            CreateWithModeAndSizeAndOptions,
+            #[cfg(not(all(feature = "Storage_FileProperties", feature = "Storage_Search")))]
+            CreateWithModeAndSizeAndOptions: 0,
+            #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Search"))]
+            CreateWithModeAndSizeAndOptionsAndFlags,
+            #[cfg(not(all(feature = "Storage_FileProperties", feature = "Storage_Search")))]
+            CreateWithModeAndSizeAndOptionsAndFlags: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileInformationFactoryFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IStorageItemInformation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_FileProperties")]
+        unsafe extern "system" fn MusicProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemInformation", "MusicProperties", 6usize, |this| ((**(this as *const *const IStorageItemInformation_Vtbl)).MusicProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_FileProperties")]
+        unsafe extern "system" fn VideoProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemInformation", "VideoProperties", 7usize, |this| ((**(this as *const *const IStorageItemInformation_Vtbl)).VideoProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_FileProperties")]
+        unsafe extern "system" fn ImageProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemInformation", "ImageProperties", 8usize, |this| ((**(this as *const *const IStorageItemInformation_Vtbl)).ImageProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_FileProperties")]
+        unsafe extern "system" fn DocumentProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemInformation", "DocumentProperties", 9usize, |this| ((**(this as *const *const IStorageItemInformation_Vtbl)).DocumentProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_FileProperties")]
+        unsafe extern "system" fn BasicProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemInformation", "BasicProperties", 10usize, |this| ((**(this as *const *const IStorageItemInformation_Vtbl)).BasicProperties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+        unsafe extern "system" fn Thumbnail(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemInformation", "Thumbnail", 11usize, |this| ((**(this as *const *const IStorageItemInformation_Vtbl)).Thumbnail)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThumbnailUpdated(this: *mut core::ffi::c_void, changedhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemInformation", "ThumbnailUpdated", 12usize, |this| ((**(this as *const *const IStorageItemInformation_Vtbl)).ThumbnailUpdated)(this, changedhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveThumbnailUpdated(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemInformation", "RemoveThumbnailUpdated", 13usize, |this| ((**(this as *const *const IStorageItemInformation_Vtbl)).RemoveThumbnailUpdated)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropertiesUpdated(this: *mut core::ffi::c_void, changedhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemInformation", "PropertiesUpdated", 14usize, |this| ((**(this as *const *const IStorageItemInformation_Vtbl)).PropertiesUpdated)(this, changedhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePropertiesUpdated(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemInformation", "RemovePropertiesUpdated", 15usize, |this| ((**(this as *const *const IStorageItemInformation_Vtbl)).RemovePropertiesUpdated)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_FileProperties")]
+            MusicProperties,
+            #[cfg(not(feature = "Storage_FileProperties"))]
+            MusicProperties: 0,
+            #[cfg(feature = "Storage_FileProperties")]
+            VideoProperties,
+            #[cfg(not(feature = "Storage_FileProperties"))]
+            VideoProperties: 0,
+            #[cfg(feature = "Storage_FileProperties")]
+            ImageProperties,
+            #[cfg(not(feature = "Storage_FileProperties"))]
+            ImageProperties: 0,
+            #[cfg(feature = "Storage_FileProperties")]
+            DocumentProperties,
+            #[cfg(not(feature = "Storage_FileProperties"))]
+            DocumentProperties: 0,
+            #[cfg(feature = "Storage_FileProperties")]
+            BasicProperties,
+            #[cfg(not(feature = "Storage_FileProperties"))]
+            BasicProperties: 0,
+            #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+            Thumbnail,
+            #[cfg(not(all(feature = "Storage_FileProperties", feature = "Storage_Streams")))]
+            Thumbnail: 0,
+            ThumbnailUpdated,
+            RemoveThumbnailUpdated,
+            PropertiesUpdated,
+            RemovePropertiesUpdated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageItemInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
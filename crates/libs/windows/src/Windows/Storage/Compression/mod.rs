@@ -206,6 +206,7 @@ impl CompressAlgorithm {
 impl windows_core::TypeKind for CompressAlgorithm {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompressAlgorithm {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompressAlgorithm").field(&self.0).finish()
@@ -214,3 +215,5 @@ impl core::fmt::Debug for CompressAlgorithm {
 impl windows_core::RuntimeType for CompressAlgorithm {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Storage.Compression.CompressAlgorithm;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,92 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ICompressor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FinishAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompressor", "FinishAsync", 6usize, |this| ((**(this as *const *const ICompressor_Vtbl)).FinishAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn DetachStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompressor", "DetachStream", 7usize, |this| ((**(this as *const *const ICompressor_Vtbl)).DetachStream)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FinishAsync,
+            #[cfg(feature = "Storage_Streams")]
+            DetachStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            DetachStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompressor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ICompressorFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateCompressor(this: *mut core::ffi::c_void, underlyingstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompressorFactory", "CreateCompressor", 6usize, |this| ((**(this as *const *const ICompressorFactory_Vtbl)).CreateCompressor)(this, underlyingstream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateCompressorEx(this: *mut core::ffi::c_void, underlyingstream: *mut core::ffi::c_void, algorithm: CompressAlgorithm, blocksize: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompressorFactory", "CreateCompressorEx", 7usize, |this| ((**(this as *const *const ICompressorFactory_Vtbl)).CreateCompressorEx)(this, underlyingstream, algorithm, blocksize, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateCompressor,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateCompressor: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateCompressorEx,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateCompressorEx: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompressorFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IDecompressor_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn DetachStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDecompressor", "DetachStream", 6usize, |this| ((**(this as *const *const IDecompressor_Vtbl)).DetachStream)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            DetachStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            DetachStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDecompressor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IDecompressorFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateDecompressor(this: *mut core::ffi::c_void, underlyingstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDecompressorFactory", "CreateDecompressor", 6usize, |this| ((**(this as *const *const IDecompressorFactory_Vtbl)).CreateDecompressor)(this, underlyingstream, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateDecompressor,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateDecompressor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDecompressorFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
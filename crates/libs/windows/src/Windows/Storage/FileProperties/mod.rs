@@ -1226,6 +1226,7 @@ impl PhotoOrientation {
 impl windows_core::TypeKind for PhotoOrientation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhotoOrientation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhotoOrientation").field(&self.0).finish()
@@ -1248,6 +1249,7 @@ impl PropertyPrefetchOptions {
 impl windows_core::TypeKind for PropertyPrefetchOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PropertyPrefetchOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PropertyPrefetchOptions").field(&self.0).finish()
@@ -1303,6 +1305,7 @@ impl ThumbnailMode {
 impl windows_core::TypeKind for ThumbnailMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ThumbnailMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ThumbnailMode").field(&self.0).finish()
@@ -1323,6 +1326,7 @@ impl ThumbnailOptions {
 impl windows_core::TypeKind for ThumbnailOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ThumbnailOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ThumbnailOptions").field(&self.0).finish()
@@ -1374,6 +1378,7 @@ impl ThumbnailType {
 impl windows_core::TypeKind for ThumbnailType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ThumbnailType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ThumbnailType").field(&self.0).finish()
@@ -1394,6 +1399,7 @@ impl VideoOrientation {
 impl windows_core::TypeKind for VideoOrientation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VideoOrientation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VideoOrientation").field(&self.0).finish()
@@ -1404,3 +1410,5 @@ impl windows_core::RuntimeType for VideoOrientation {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
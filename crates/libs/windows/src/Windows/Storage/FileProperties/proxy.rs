@@ -0,0 +1,517 @@
+impl windows_core::imp::ProxyVtable for IBasicProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicProperties", "Size", 6usize, |this| ((**(this as *const *const IBasicProperties_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DateModified(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicProperties", "DateModified", 7usize, |this| ((**(this as *const *const IBasicProperties_Vtbl)).DateModified)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemDate(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicProperties", "ItemDate", 8usize, |this| ((**(this as *const *const IBasicProperties_Vtbl)).ItemDate)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Size, DateModified, ItemDate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBasicProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDocumentProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Author(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDocumentProperties", "Author", 6usize, |this| ((**(this as *const *const IDocumentProperties_Vtbl)).Author)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDocumentProperties", "Title", 7usize, |this| ((**(this as *const *const IDocumentProperties_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDocumentProperties", "SetTitle", 8usize, |this| ((**(this as *const *const IDocumentProperties_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Keywords(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDocumentProperties", "Keywords", 9usize, |this| ((**(this as *const *const IDocumentProperties_Vtbl)).Keywords)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Comment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDocumentProperties", "Comment", 10usize, |this| ((**(this as *const *const IDocumentProperties_Vtbl)).Comment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComment(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDocumentProperties", "SetComment", 11usize, |this| ((**(this as *const *const IDocumentProperties_Vtbl)).SetComment)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Author,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Author: 0,
+            Title,
+            SetTitle,
+            #[cfg(feature = "Foundation_Collections")]
+            Keywords,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Keywords: 0,
+            Comment,
+            SetComment,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDocumentProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Geolocation")]
+impl windows_core::imp::ProxyVtable for IGeotagHelperStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn GetGeotagAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeotagHelperStatics", "GetGeotagAsync", 6usize, |this| ((**(this as *const *const IGeotagHelperStatics_Vtbl)).GetGeotagAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn SetGeotagFromGeolocatorAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, geolocator: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeotagHelperStatics", "SetGeotagFromGeolocatorAsync", 7usize, |this| ((**(this as *const *const IGeotagHelperStatics_Vtbl)).SetGeotagFromGeolocatorAsync)(this, file, geolocator, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Geolocation")]
+        unsafe extern "system" fn SetGeotagAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, geopoint: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeotagHelperStatics", "SetGeotagAsync", 8usize, |this| ((**(this as *const *const IGeotagHelperStatics_Vtbl)).SetGeotagAsync)(this, file, geopoint, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Geolocation")]
+            GetGeotagAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            GetGeotagAsync: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            SetGeotagFromGeolocatorAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            SetGeotagFromGeolocatorAsync: 0,
+            #[cfg(feature = "Devices_Geolocation")]
+            SetGeotagAsync,
+            #[cfg(not(feature = "Devices_Geolocation"))]
+            SetGeotagAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeotagHelperStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IImageProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Rating(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "Rating", 6usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).Rating)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRating(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "SetRating", 7usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).SetRating)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Keywords(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "Keywords", 8usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).Keywords)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DateTaken(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "DateTaken", 9usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).DateTaken)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDateTaken(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "SetDateTaken", 10usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).SetDateTaken)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "Width", 11usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "Height", 12usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "Title", 13usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "SetTitle", 14usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Latitude(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "Latitude", 15usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).Latitude)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Longitude(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "Longitude", 16usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).Longitude)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CameraManufacturer(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "CameraManufacturer", 17usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).CameraManufacturer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCameraManufacturer(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "SetCameraManufacturer", 18usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).SetCameraManufacturer)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CameraModel(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "CameraModel", 19usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).CameraModel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCameraModel(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "SetCameraModel", 20usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).SetCameraModel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut PhotoOrientation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "Orientation", 21usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PeopleNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageProperties", "PeopleNames", 22usize, |this| ((**(this as *const *const IImageProperties_Vtbl)).PeopleNames)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Rating,
+            SetRating,
+            #[cfg(feature = "Foundation_Collections")]
+            Keywords,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Keywords: 0,
+            DateTaken,
+            SetDateTaken,
+            Width,
+            Height,
+            Title,
+            SetTitle,
+            Latitude,
+            Longitude,
+            CameraManufacturer,
+            SetCameraManufacturer,
+            CameraModel,
+            SetCameraModel,
+            Orientation,
+            #[cfg(feature = "Foundation_Collections")]
+            PeopleNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PeopleNames: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMusicProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Album(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "Album", 6usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).Album)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlbum(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "SetAlbum", 7usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).SetAlbum)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Artist(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "Artist", 8usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).Artist)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetArtist(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "SetArtist", 9usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).SetArtist)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Genre(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "Genre", 10usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).Genre)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrackNumber(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "TrackNumber", 11usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).TrackNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrackNumber(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "SetTrackNumber", 12usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).SetTrackNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "Title", 13usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "SetTitle", 14usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rating(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "Rating", 15usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).Rating)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRating(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "SetRating", 16usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).SetRating)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "Duration", 17usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bitrate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "Bitrate", 18usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).Bitrate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlbumArtist(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "AlbumArtist", 19usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).AlbumArtist)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlbumArtist(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "SetAlbumArtist", 20usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).SetAlbumArtist)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Composers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "Composers", 21usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).Composers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Conductors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "Conductors", 22usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).Conductors)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subtitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "Subtitle", 23usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).Subtitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubtitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "SetSubtitle", 24usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).SetSubtitle)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Producers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "Producers", 25usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).Producers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Publisher(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "Publisher", 26usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).Publisher)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPublisher(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "SetPublisher", 27usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).SetPublisher)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Writers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "Writers", 28usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).Writers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Year(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "Year", 29usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).Year)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetYear(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMusicProperties", "SetYear", 30usize, |this| ((**(this as *const *const IMusicProperties_Vtbl)).SetYear)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Album,
+            SetAlbum,
+            Artist,
+            SetArtist,
+            #[cfg(feature = "Foundation_Collections")]
+            Genre,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Genre: 0,
+            TrackNumber,
+            SetTrackNumber,
+            Title,
+            SetTitle,
+            Rating,
+            SetRating,
+            Duration,
+            Bitrate,
+            AlbumArtist,
+            SetAlbumArtist,
+            #[cfg(feature = "Foundation_Collections")]
+            Composers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Composers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Conductors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Conductors: 0,
+            Subtitle,
+            SetSubtitle,
+            #[cfg(feature = "Foundation_Collections")]
+            Producers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Producers: 0,
+            Publisher,
+            SetPublisher,
+            #[cfg(feature = "Foundation_Collections")]
+            Writers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Writers: 0,
+            Year,
+            SetYear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMusicProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageItemContentProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMusicPropertiesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemContentProperties", "GetMusicPropertiesAsync", 6usize, |this| ((**(this as *const *const IStorageItemContentProperties_Vtbl)).GetMusicPropertiesAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoPropertiesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemContentProperties", "GetVideoPropertiesAsync", 7usize, |this| ((**(this as *const *const IStorageItemContentProperties_Vtbl)).GetVideoPropertiesAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImagePropertiesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemContentProperties", "GetImagePropertiesAsync", 8usize, |this| ((**(this as *const *const IStorageItemContentProperties_Vtbl)).GetImagePropertiesAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocumentPropertiesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemContentProperties", "GetDocumentPropertiesAsync", 9usize, |this| ((**(this as *const *const IStorageItemContentProperties_Vtbl)).GetDocumentPropertiesAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMusicPropertiesAsync,
+            GetVideoPropertiesAsync,
+            GetImagePropertiesAsync,
+            GetDocumentPropertiesAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageItemContentProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageItemExtraProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RetrievePropertiesAsync(this: *mut core::ffi::c_void, propertiestoretrieve: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemExtraProperties", "RetrievePropertiesAsync", 6usize, |this| ((**(this as *const *const IStorageItemExtraProperties_Vtbl)).RetrievePropertiesAsync)(this, propertiestoretrieve, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SavePropertiesAsync(this: *mut core::ffi::c_void, propertiestosave: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemExtraProperties", "SavePropertiesAsync", 7usize, |this| ((**(this as *const *const IStorageItemExtraProperties_Vtbl)).SavePropertiesAsync)(this, propertiestosave, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SavePropertiesAsyncOverloadDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemExtraProperties", "SavePropertiesAsyncOverloadDefault", 8usize, |this| ((**(this as *const *const IStorageItemExtraProperties_Vtbl)).SavePropertiesAsyncOverloadDefault)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            RetrievePropertiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RetrievePropertiesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SavePropertiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SavePropertiesAsync: 0,
+            SavePropertiesAsyncOverloadDefault,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageItemExtraProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IThumbnailProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OriginalWidth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThumbnailProperties", "OriginalWidth", 6usize, |this| ((**(this as *const *const IThumbnailProperties_Vtbl)).OriginalWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OriginalHeight(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThumbnailProperties", "OriginalHeight", 7usize, |this| ((**(this as *const *const IThumbnailProperties_Vtbl)).OriginalHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReturnedSmallerCachedSize(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThumbnailProperties", "ReturnedSmallerCachedSize", 8usize, |this| ((**(this as *const *const IThumbnailProperties_Vtbl)).ReturnedSmallerCachedSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut ThumbnailType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThumbnailProperties", "Type", 9usize, |this| ((**(this as *const *const IThumbnailProperties_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OriginalWidth,
+            OriginalHeight,
+            ReturnedSmallerCachedSize,
+            Type,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IThumbnailProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVideoProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Rating(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Rating", 6usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Rating)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRating(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "SetRating", 7usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).SetRating)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Keywords(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Keywords", 8usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Keywords)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Width", 9usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Height", 10usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Height)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Duration", 11usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Latitude(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Latitude", 12usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Latitude)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Longitude(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Longitude", 13usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Longitude)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Title", 14usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "SetTitle", 15usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subtitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Subtitle", 16usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Subtitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubtitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "SetSubtitle", 17usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).SetSubtitle)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Producers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Producers", 18usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Producers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Publisher(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Publisher", 19usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Publisher)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPublisher(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "SetPublisher", 20usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).SetPublisher)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Writers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Writers", 21usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Writers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Year(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Year", 22usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Year)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetYear(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "SetYear", 23usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).SetYear)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bitrate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Bitrate", 24usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Bitrate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Directors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Directors", 25usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Directors)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut VideoOrientation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProperties", "Orientation", 26usize, |this| ((**(this as *const *const IVideoProperties_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Rating,
+            SetRating,
+            #[cfg(feature = "Foundation_Collections")]
+            Keywords,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Keywords: 0,
+            Width,
+            Height,
+            Duration,
+            Latitude,
+            Longitude,
+            Title,
+            SetTitle,
+            Subtitle,
+            SetSubtitle,
+            #[cfg(feature = "Foundation_Collections")]
+            Producers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Producers: 0,
+            Publisher,
+            SetPublisher,
+            #[cfg(feature = "Foundation_Collections")]
+            Writers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Writers: 0,
+            Year,
+            SetYear,
+            Bitrate,
+            #[cfg(feature = "Foundation_Collections")]
+            Directors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Directors: 0,
+            Orientation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -518,6 +518,7 @@ impl AddFileResult {
 impl windows_core::TypeKind for AddFileResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AddFileResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AddFileResult").field(&self.0).finish()
@@ -536,6 +537,7 @@ impl FileSelectionMode {
 impl windows_core::TypeKind for FileSelectionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FileSelectionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FileSelectionMode").field(&self.0).finish()
@@ -555,6 +557,7 @@ impl SetFileNameResult {
 impl windows_core::TypeKind for SetFileNameResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SetFileNameResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SetFileNameResult").field(&self.0).finish()
@@ -563,3 +566,5 @@ impl core::fmt::Debug for SetFileNameResult {
 impl windows_core::RuntimeType for SetFileNameResult {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Storage.Pickers.Provider.SetFileNameResult;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
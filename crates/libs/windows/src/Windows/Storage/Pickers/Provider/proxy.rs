@@ -0,0 +1,236 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IFileOpenPickerUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddFile(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, file: *mut core::ffi::c_void, result__: *mut AddFileResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerUI", "AddFile", 6usize, |this| ((**(this as *const *const IFileOpenPickerUI_Vtbl)).AddFile)(this, id, file, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFile(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerUI", "RemoveFile", 7usize, |this| ((**(this as *const *const IFileOpenPickerUI_Vtbl)).RemoveFile)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainsFile(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerUI", "ContainsFile", 8usize, |this| ((**(this as *const *const IFileOpenPickerUI_Vtbl)).ContainsFile)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanAddFile(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerUI", "CanAddFile", 9usize, |this| ((**(this as *const *const IFileOpenPickerUI_Vtbl)).CanAddFile)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AllowedFileTypes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerUI", "AllowedFileTypes", 10usize, |this| ((**(this as *const *const IFileOpenPickerUI_Vtbl)).AllowedFileTypes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectionMode(this: *mut core::ffi::c_void, result__: *mut FileSelectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerUI", "SelectionMode", 11usize, |this| ((**(this as *const *const IFileOpenPickerUI_Vtbl)).SelectionMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SettingsIdentifier(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerUI", "SettingsIdentifier", 12usize, |this| ((**(this as *const *const IFileOpenPickerUI_Vtbl)).SettingsIdentifier)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerUI", "Title", 13usize, |this| ((**(this as *const *const IFileOpenPickerUI_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerUI", "SetTitle", 14usize, |this| ((**(this as *const *const IFileOpenPickerUI_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn FileRemoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerUI", "FileRemoved", 15usize, |this| ((**(this as *const *const IFileOpenPickerUI_Vtbl)).FileRemoved)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveFileRemoved(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerUI", "RemoveFileRemoved", 16usize, |this| ((**(this as *const *const IFileOpenPickerUI_Vtbl)).RemoveFileRemoved)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Closing(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerUI", "Closing", 17usize, |this| ((**(this as *const *const IFileOpenPickerUI_Vtbl)).Closing)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosing(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerUI", "RemoveClosing", 18usize, |this| ((**(this as *const *const IFileOpenPickerUI_Vtbl)).RemoveClosing)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddFile,
+            RemoveFile,
+            ContainsFile,
+            CanAddFile,
+            #[cfg(feature = "Foundation_Collections")]
+            AllowedFileTypes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AllowedFileTypes: 0,
+            SelectionMode,
+            SettingsIdentifier,
+            Title,
+            SetTitle,
+            #[cfg(feature = "deprecated")]
+            FileRemoved,
+            #[cfg(not(feature = "deprecated"))]
+            FileRemoved: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveFileRemoved,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveFileRemoved: 0,
+            Closing,
+            RemoveClosing,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileOpenPickerUI as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IFileRemovedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileRemovedEventArgs", "Id", 6usize, |this| ((**(this as *const *const IFileRemovedEventArgs_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Id,
+            #[cfg(not(feature = "deprecated"))]
+            Id: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileRemovedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IFileSavePickerUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePickerUI", "Title", 6usize, |this| ((**(this as *const *const IFileSavePickerUI_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePickerUI", "SetTitle", 7usize, |this| ((**(this as *const *const IFileSavePickerUI_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AllowedFileTypes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePickerUI", "AllowedFileTypes", 8usize, |this| ((**(this as *const *const IFileSavePickerUI_Vtbl)).AllowedFileTypes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SettingsIdentifier(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePickerUI", "SettingsIdentifier", 9usize, |this| ((**(this as *const *const IFileSavePickerUI_Vtbl)).SettingsIdentifier)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePickerUI", "FileName", 10usize, |this| ((**(this as *const *const IFileSavePickerUI_Vtbl)).FileName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySetFileName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut SetFileNameResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePickerUI", "TrySetFileName", 11usize, |this| ((**(this as *const *const IFileSavePickerUI_Vtbl)).TrySetFileName)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileNameChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePickerUI", "FileNameChanged", 12usize, |this| ((**(this as *const *const IFileSavePickerUI_Vtbl)).FileNameChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFileNameChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePickerUI", "RemoveFileNameChanged", 13usize, |this| ((**(this as *const *const IFileSavePickerUI_Vtbl)).RemoveFileNameChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetFileRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePickerUI", "TargetFileRequested", 14usize, |this| ((**(this as *const *const IFileSavePickerUI_Vtbl)).TargetFileRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTargetFileRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePickerUI", "RemoveTargetFileRequested", 15usize, |this| ((**(this as *const *const IFileSavePickerUI_Vtbl)).RemoveTargetFileRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Title,
+            SetTitle,
+            #[cfg(feature = "Foundation_Collections")]
+            AllowedFileTypes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AllowedFileTypes: 0,
+            SettingsIdentifier,
+            FileName,
+            TrySetFileName,
+            FileNameChanged,
+            RemoveFileNameChanged,
+            TargetFileRequested,
+            RemoveTargetFileRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSavePickerUI as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPickerClosingDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPickerClosingDeferral", "Complete", 6usize, |this| ((**(this as *const *const IPickerClosingDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPickerClosingDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPickerClosingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClosingOperation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPickerClosingEventArgs", "ClosingOperation", 6usize, |this| ((**(this as *const *const IPickerClosingEventArgs_Vtbl)).ClosingOperation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCanceled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPickerClosingEventArgs", "IsCanceled", 7usize, |this| ((**(this as *const *const IPickerClosingEventArgs_Vtbl)).IsCanceled)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ClosingOperation, IsCanceled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPickerClosingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPickerClosingOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPickerClosingOperation", "GetDeferral", 6usize, |this| ((**(this as *const *const IPickerClosingOperation_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPickerClosingOperation", "Deadline", 7usize, |this| ((**(this as *const *const IPickerClosingOperation_Vtbl)).Deadline)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral, Deadline }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPickerClosingOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetFileRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TargetFile(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFileRequest", "TargetFile", 6usize, |this| ((**(this as *const *const ITargetFileRequest_Vtbl)).TargetFile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetFile(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFileRequest", "SetTargetFile", 7usize, |this| ((**(this as *const *const ITargetFileRequest_Vtbl)).SetTargetFile)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFileRequest", "GetDeferral", 8usize, |this| ((**(this as *const *const ITargetFileRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TargetFile, SetTargetFile, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetFileRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetFileRequestDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFileRequestDeferral", "Complete", 6usize, |this| ((**(this as *const *const ITargetFileRequestDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetFileRequestDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetFileRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFileRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const ITargetFileRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetFileRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
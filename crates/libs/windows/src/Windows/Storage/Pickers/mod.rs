@@ -991,6 +991,7 @@ impl PickerLocationId {
 impl windows_core::TypeKind for PickerLocationId {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PickerLocationId {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PickerLocationId").field(&self.0).finish()
@@ -1009,6 +1010,7 @@ impl PickerViewMode {
 impl windows_core::TypeKind for PickerViewMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PickerViewMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PickerViewMode").field(&self.0).finish()
@@ -1017,3 +1019,5 @@ impl core::fmt::Debug for PickerViewMode {
 impl windows_core::RuntimeType for PickerViewMode {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Storage.Pickers.PickerViewMode;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
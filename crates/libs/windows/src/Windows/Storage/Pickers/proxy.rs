@@ -0,0 +1,450 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IFileOpenPicker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ViewMode(this: *mut core::ffi::c_void, result__: *mut PickerViewMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPicker", "ViewMode", 6usize, |this| ((**(this as *const *const IFileOpenPicker_Vtbl)).ViewMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewMode(this: *mut core::ffi::c_void, value: PickerViewMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPicker", "SetViewMode", 7usize, |this| ((**(this as *const *const IFileOpenPicker_Vtbl)).SetViewMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SettingsIdentifier(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPicker", "SettingsIdentifier", 8usize, |this| ((**(this as *const *const IFileOpenPicker_Vtbl)).SettingsIdentifier)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSettingsIdentifier(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPicker", "SetSettingsIdentifier", 9usize, |this| ((**(this as *const *const IFileOpenPicker_Vtbl)).SetSettingsIdentifier)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuggestedStartLocation(this: *mut core::ffi::c_void, result__: *mut PickerLocationId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPicker", "SuggestedStartLocation", 10usize, |this| ((**(this as *const *const IFileOpenPicker_Vtbl)).SuggestedStartLocation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuggestedStartLocation(this: *mut core::ffi::c_void, value: PickerLocationId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPicker", "SetSuggestedStartLocation", 11usize, |this| ((**(this as *const *const IFileOpenPicker_Vtbl)).SetSuggestedStartLocation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitButtonText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPicker", "CommitButtonText", 12usize, |this| ((**(this as *const *const IFileOpenPicker_Vtbl)).CommitButtonText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCommitButtonText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPicker", "SetCommitButtonText", 13usize, |this| ((**(this as *const *const IFileOpenPicker_Vtbl)).SetCommitButtonText)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FileTypeFilter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPicker", "FileTypeFilter", 14usize, |this| ((**(this as *const *const IFileOpenPicker_Vtbl)).FileTypeFilter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PickSingleFileAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPicker", "PickSingleFileAsync", 15usize, |this| ((**(this as *const *const IFileOpenPicker_Vtbl)).PickSingleFileAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PickMultipleFilesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPicker", "PickMultipleFilesAsync", 16usize, |this| ((**(this as *const *const IFileOpenPicker_Vtbl)).PickMultipleFilesAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ViewMode,
+            SetViewMode,
+            SettingsIdentifier,
+            SetSettingsIdentifier,
+            SuggestedStartLocation,
+            SetSuggestedStartLocation,
+            CommitButtonText,
+            SetCommitButtonText,
+            #[cfg(feature = "Foundation_Collections")]
+            FileTypeFilter,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FileTypeFilter: 0,
+            PickSingleFileAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            PickMultipleFilesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PickMultipleFilesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileOpenPicker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IFileOpenPicker2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn ContinuationData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPicker2", "ContinuationData", 6usize, |this| ((**(this as *const *const IFileOpenPicker2_Vtbl)).ContinuationData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PickSingleFileAndContinue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPicker2", "PickSingleFileAndContinue", 7usize, |this| ((**(this as *const *const IFileOpenPicker2_Vtbl)).PickSingleFileAndContinue)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PickMultipleFilesAndContinue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPicker2", "PickMultipleFilesAndContinue", 8usize, |this| ((**(this as *const *const IFileOpenPicker2_Vtbl)).PickMultipleFilesAndContinue)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            ContinuationData,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            ContinuationData: 0,
+            #[cfg(feature = "deprecated")]
+            PickSingleFileAndContinue,
+            #[cfg(not(feature = "deprecated"))]
+            PickSingleFileAndContinue: 0,
+            #[cfg(feature = "deprecated")]
+            PickMultipleFilesAndContinue,
+            #[cfg(not(feature = "deprecated"))]
+            PickMultipleFilesAndContinue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileOpenPicker2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IFileOpenPicker3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPicker3", "User", 6usize, |this| ((**(this as *const *const IFileOpenPicker3_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileOpenPicker3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileOpenPickerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ResumePickSingleFileAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerStatics", "ResumePickSingleFileAsync", 6usize, |this| ((**(this as *const *const IFileOpenPickerStatics_Vtbl)).ResumePickSingleFileAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            ResumePickSingleFileAsync,
+            #[cfg(not(feature = "deprecated"))]
+            ResumePickSingleFileAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileOpenPickerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IFileOpenPickerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn CreateForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerStatics2", "CreateForUser", 6usize, |this| ((**(this as *const *const IFileOpenPickerStatics2_Vtbl)).CreateForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            CreateForUser,
+            #[cfg(not(feature = "System"))]
+            CreateForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileOpenPickerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileOpenPickerWithOperationId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PickSingleFileAsync(this: *mut core::ffi::c_void, pickeroperationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileOpenPickerWithOperationId", "PickSingleFileAsync", 6usize, |this| ((**(this as *const *const IFileOpenPickerWithOperationId_Vtbl)).PickSingleFileAsync)(this, pickeroperationid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PickSingleFileAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileOpenPickerWithOperationId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IFileSavePicker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SettingsIdentifier(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker", "SettingsIdentifier", 6usize, |this| ((**(this as *const *const IFileSavePicker_Vtbl)).SettingsIdentifier)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSettingsIdentifier(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker", "SetSettingsIdentifier", 7usize, |this| ((**(this as *const *const IFileSavePicker_Vtbl)).SetSettingsIdentifier)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuggestedStartLocation(this: *mut core::ffi::c_void, result__: *mut PickerLocationId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker", "SuggestedStartLocation", 8usize, |this| ((**(this as *const *const IFileSavePicker_Vtbl)).SuggestedStartLocation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuggestedStartLocation(this: *mut core::ffi::c_void, value: PickerLocationId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker", "SetSuggestedStartLocation", 9usize, |this| ((**(this as *const *const IFileSavePicker_Vtbl)).SetSuggestedStartLocation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitButtonText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker", "CommitButtonText", 10usize, |this| ((**(this as *const *const IFileSavePicker_Vtbl)).CommitButtonText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCommitButtonText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker", "SetCommitButtonText", 11usize, |this| ((**(this as *const *const IFileSavePicker_Vtbl)).SetCommitButtonText)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FileTypeChoices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker", "FileTypeChoices", 12usize, |this| ((**(this as *const *const IFileSavePicker_Vtbl)).FileTypeChoices)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultFileExtension(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker", "DefaultFileExtension", 13usize, |this| ((**(this as *const *const IFileSavePicker_Vtbl)).DefaultFileExtension)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultFileExtension(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker", "SetDefaultFileExtension", 14usize, |this| ((**(this as *const *const IFileSavePicker_Vtbl)).SetDefaultFileExtension)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuggestedSaveFile(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker", "SuggestedSaveFile", 15usize, |this| ((**(this as *const *const IFileSavePicker_Vtbl)).SuggestedSaveFile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuggestedSaveFile(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker", "SetSuggestedSaveFile", 16usize, |this| ((**(this as *const *const IFileSavePicker_Vtbl)).SetSuggestedSaveFile)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuggestedFileName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker", "SuggestedFileName", 17usize, |this| ((**(this as *const *const IFileSavePicker_Vtbl)).SuggestedFileName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuggestedFileName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker", "SetSuggestedFileName", 18usize, |this| ((**(this as *const *const IFileSavePicker_Vtbl)).SetSuggestedFileName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PickSaveFileAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker", "PickSaveFileAsync", 19usize, |this| ((**(this as *const *const IFileSavePicker_Vtbl)).PickSaveFileAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SettingsIdentifier,
+            SetSettingsIdentifier,
+            SuggestedStartLocation,
+            SetSuggestedStartLocation,
+            CommitButtonText,
+            SetCommitButtonText,
+            #[cfg(feature = "Foundation_Collections")]
+            FileTypeChoices,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FileTypeChoices: 0,
+            DefaultFileExtension,
+            SetDefaultFileExtension,
+            SuggestedSaveFile,
+            SetSuggestedSaveFile,
+            SuggestedFileName,
+            SetSuggestedFileName,
+            PickSaveFileAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSavePicker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IFileSavePicker2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ContinuationData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker2", "ContinuationData", 6usize, |this| ((**(this as *const *const IFileSavePicker2_Vtbl)).ContinuationData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PickSaveFileAndContinue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker2", "PickSaveFileAndContinue", 7usize, |this| ((**(this as *const *const IFileSavePicker2_Vtbl)).PickSaveFileAndContinue)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ContinuationData,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ContinuationData: 0,
+            #[cfg(feature = "deprecated")]
+            PickSaveFileAndContinue,
+            #[cfg(not(feature = "deprecated"))]
+            PickSaveFileAndContinue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSavePicker2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileSavePicker3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnterpriseId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker3", "EnterpriseId", 6usize, |this| ((**(this as *const *const IFileSavePicker3_Vtbl)).EnterpriseId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnterpriseId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker3", "SetEnterpriseId", 7usize, |this| ((**(this as *const *const IFileSavePicker3_Vtbl)).SetEnterpriseId)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnterpriseId, SetEnterpriseId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSavePicker3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IFileSavePicker4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePicker4", "User", 6usize, |this| ((**(this as *const *const IFileSavePicker4_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSavePicker4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IFileSavePickerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn CreateForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSavePickerStatics", "CreateForUser", 6usize, |this| ((**(this as *const *const IFileSavePickerStatics_Vtbl)).CreateForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            CreateForUser,
+            #[cfg(not(feature = "System"))]
+            CreateForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSavePickerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IFolderPicker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ViewMode(this: *mut core::ffi::c_void, result__: *mut PickerViewMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderPicker", "ViewMode", 6usize, |this| ((**(this as *const *const IFolderPicker_Vtbl)).ViewMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewMode(this: *mut core::ffi::c_void, value: PickerViewMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderPicker", "SetViewMode", 7usize, |this| ((**(this as *const *const IFolderPicker_Vtbl)).SetViewMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SettingsIdentifier(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderPicker", "SettingsIdentifier", 8usize, |this| ((**(this as *const *const IFolderPicker_Vtbl)).SettingsIdentifier)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSettingsIdentifier(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderPicker", "SetSettingsIdentifier", 9usize, |this| ((**(this as *const *const IFolderPicker_Vtbl)).SetSettingsIdentifier)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuggestedStartLocation(this: *mut core::ffi::c_void, result__: *mut PickerLocationId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderPicker", "SuggestedStartLocation", 10usize, |this| ((**(this as *const *const IFolderPicker_Vtbl)).SuggestedStartLocation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuggestedStartLocation(this: *mut core::ffi::c_void, value: PickerLocationId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderPicker", "SetSuggestedStartLocation", 11usize, |this| ((**(this as *const *const IFolderPicker_Vtbl)).SetSuggestedStartLocation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitButtonText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderPicker", "CommitButtonText", 12usize, |this| ((**(this as *const *const IFolderPicker_Vtbl)).CommitButtonText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCommitButtonText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderPicker", "SetCommitButtonText", 13usize, |this| ((**(this as *const *const IFolderPicker_Vtbl)).SetCommitButtonText)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FileTypeFilter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderPicker", "FileTypeFilter", 14usize, |this| ((**(this as *const *const IFolderPicker_Vtbl)).FileTypeFilter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PickSingleFolderAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderPicker", "PickSingleFolderAsync", 15usize, |this| ((**(this as *const *const IFolderPicker_Vtbl)).PickSingleFolderAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ViewMode,
+            SetViewMode,
+            SettingsIdentifier,
+            SetSettingsIdentifier,
+            SuggestedStartLocation,
+            SetSuggestedStartLocation,
+            CommitButtonText,
+            SetCommitButtonText,
+            #[cfg(feature = "Foundation_Collections")]
+            FileTypeFilter,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FileTypeFilter: 0,
+            PickSingleFolderAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFolderPicker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IFolderPicker2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ContinuationData(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderPicker2", "ContinuationData", 6usize, |this| ((**(this as *const *const IFolderPicker2_Vtbl)).ContinuationData)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn PickFolderAndContinue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderPicker2", "PickFolderAndContinue", 7usize, |this| ((**(this as *const *const IFolderPicker2_Vtbl)).PickFolderAndContinue)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ContinuationData,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ContinuationData: 0,
+            #[cfg(feature = "deprecated")]
+            PickFolderAndContinue,
+            #[cfg(not(feature = "deprecated"))]
+            PickFolderAndContinue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFolderPicker2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IFolderPicker3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderPicker3", "User", 6usize, |this| ((**(this as *const *const IFolderPicker3_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFolderPicker3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IFolderPickerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn CreateForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderPickerStatics", "CreateForUser", 6usize, |this| ((**(this as *const *const IFolderPickerStatics_Vtbl)).CreateForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            CreateForUser,
+            #[cfg(not(feature = "System"))]
+            CreateForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFolderPickerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1803,6 +1803,7 @@ impl CachedFileOptions {
 impl windows_core::TypeKind for CachedFileOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CachedFileOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CachedFileOptions").field(&self.0).finish()
@@ -1854,6 +1855,7 @@ impl CachedFileTarget {
 impl windows_core::TypeKind for CachedFileTarget {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CachedFileTarget {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CachedFileTarget").field(&self.0).finish()
@@ -1876,6 +1878,7 @@ impl FileUpdateStatus {
 impl windows_core::TypeKind for FileUpdateStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FileUpdateStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FileUpdateStatus").field(&self.0).finish()
@@ -1894,6 +1897,7 @@ impl ReadActivationMode {
 impl windows_core::TypeKind for ReadActivationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ReadActivationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ReadActivationMode").field(&self.0).finish()
@@ -1912,6 +1916,7 @@ impl StorageProviderHardlinkPolicy {
 impl windows_core::TypeKind for StorageProviderHardlinkPolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorageProviderHardlinkPolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorageProviderHardlinkPolicy").field(&self.0).finish()
@@ -1965,6 +1970,7 @@ impl StorageProviderHydrationPolicy {
 impl windows_core::TypeKind for StorageProviderHydrationPolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorageProviderHydrationPolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorageProviderHydrationPolicy").field(&self.0).finish()
@@ -1986,6 +1992,7 @@ impl StorageProviderHydrationPolicyModifier {
 impl windows_core::TypeKind for StorageProviderHydrationPolicyModifier {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorageProviderHydrationPolicyModifier {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorageProviderHydrationPolicyModifier").field(&self.0).finish()
@@ -2047,6 +2054,7 @@ impl StorageProviderInSyncPolicy {
 impl windows_core::TypeKind for StorageProviderInSyncPolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorageProviderInSyncPolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorageProviderInSyncPolicy").field(&self.0).finish()
@@ -2099,6 +2107,7 @@ impl StorageProviderKnownFolderSyncStatus {
 impl windows_core::TypeKind for StorageProviderKnownFolderSyncStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorageProviderKnownFolderSyncStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorageProviderKnownFolderSyncStatus").field(&self.0).finish()
@@ -2117,6 +2126,7 @@ impl StorageProviderPopulationPolicy {
 impl windows_core::TypeKind for StorageProviderPopulationPolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorageProviderPopulationPolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorageProviderPopulationPolicy").field(&self.0).finish()
@@ -2135,6 +2145,7 @@ impl StorageProviderProtectionMode {
 impl windows_core::TypeKind for StorageProviderProtectionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorageProviderProtectionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorageProviderProtectionMode").field(&self.0).finish()
@@ -2157,6 +2168,7 @@ impl StorageProviderState {
 impl windows_core::TypeKind for StorageProviderState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorageProviderState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorageProviderState").field(&self.0).finish()
@@ -2176,6 +2188,7 @@ impl StorageProviderUICommandState {
 impl windows_core::TypeKind for StorageProviderUICommandState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorageProviderUICommandState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorageProviderUICommandState").field(&self.0).finish()
@@ -2195,6 +2208,7 @@ impl StorageProviderUriSourceStatus {
 impl windows_core::TypeKind for StorageProviderUriSourceStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorageProviderUriSourceStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorageProviderUriSourceStatus").field(&self.0).finish()
@@ -2215,6 +2229,7 @@ impl UIStatus {
 impl windows_core::TypeKind for UIStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIStatus").field(&self.0).finish()
@@ -2234,6 +2249,7 @@ impl WriteActivationMode {
 impl windows_core::TypeKind for WriteActivationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WriteActivationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WriteActivationMode").field(&self.0).finish()
@@ -2304,3 +2320,5 @@ pub struct StorageProviderKnownFolderSyncRequestedHandler_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
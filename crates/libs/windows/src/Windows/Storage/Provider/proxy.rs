@@ -0,0 +1,900 @@
+impl windows_core::imp::ProxyVtable for ICachedFileUpdaterStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetUpdateInformation(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, contentid: core::mem::MaybeUninit<windows_core::HSTRING>, readmode: ReadActivationMode, writemode: WriteActivationMode, options: CachedFileOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileUpdaterStatics", "SetUpdateInformation", 6usize, |this| ((**(this as *const *const ICachedFileUpdaterStatics_Vtbl)).SetUpdateInformation)(this, file, contentid, readmode, writemode, options), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetUpdateInformation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICachedFileUpdaterStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICachedFileUpdaterUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileUpdaterUI", "Title", 6usize, |this| ((**(this as *const *const ICachedFileUpdaterUI_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileUpdaterUI", "SetTitle", 7usize, |this| ((**(this as *const *const ICachedFileUpdaterUI_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateTarget(this: *mut core::ffi::c_void, result__: *mut CachedFileTarget) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileUpdaterUI", "UpdateTarget", 8usize, |this| ((**(this as *const *const ICachedFileUpdaterUI_Vtbl)).UpdateTarget)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileUpdateRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileUpdaterUI", "FileUpdateRequested", 9usize, |this| ((**(this as *const *const ICachedFileUpdaterUI_Vtbl)).FileUpdateRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFileUpdateRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileUpdaterUI", "RemoveFileUpdateRequested", 10usize, |this| ((**(this as *const *const ICachedFileUpdaterUI_Vtbl)).RemoveFileUpdateRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn UIRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileUpdaterUI", "UIRequested", 11usize, |this| ((**(this as *const *const ICachedFileUpdaterUI_Vtbl)).UIRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUIRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileUpdaterUI", "RemoveUIRequested", 12usize, |this| ((**(this as *const *const ICachedFileUpdaterUI_Vtbl)).RemoveUIRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn UIStatus(this: *mut core::ffi::c_void, result__: *mut UIStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileUpdaterUI", "UIStatus", 13usize, |this| ((**(this as *const *const ICachedFileUpdaterUI_Vtbl)).UIStatus)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Title,
+            SetTitle,
+            UpdateTarget,
+            FileUpdateRequested,
+            RemoveFileUpdateRequested,
+            UIRequested,
+            RemoveUIRequested,
+            UIStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICachedFileUpdaterUI as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICachedFileUpdaterUI2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UpdateRequest(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileUpdaterUI2", "UpdateRequest", 6usize, |this| ((**(this as *const *const ICachedFileUpdaterUI2_Vtbl)).UpdateRequest)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileUpdaterUI2", "GetDeferral", 7usize, |this| ((**(this as *const *const ICachedFileUpdaterUI2_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UpdateRequest, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICachedFileUpdaterUI2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileUpdateRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContentId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileUpdateRequest", "ContentId", 6usize, |this| ((**(this as *const *const IFileUpdateRequest_Vtbl)).ContentId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn File(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileUpdateRequest", "File", 7usize, |this| ((**(this as *const *const IFileUpdateRequest_Vtbl)).File)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut FileUpdateStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileUpdateRequest", "Status", 8usize, |this| ((**(this as *const *const IFileUpdateRequest_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, value: FileUpdateStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileUpdateRequest", "SetStatus", 9usize, |this| ((**(this as *const *const IFileUpdateRequest_Vtbl)).SetStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileUpdateRequest", "GetDeferral", 10usize, |this| ((**(this as *const *const IFileUpdateRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateLocalFile(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileUpdateRequest", "UpdateLocalFile", 11usize, |this| ((**(this as *const *const IFileUpdateRequest_Vtbl)).UpdateLocalFile)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ContentId,
+            File,
+            Status,
+            SetStatus,
+            GetDeferral,
+            UpdateLocalFile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileUpdateRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileUpdateRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserInputNeededMessage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileUpdateRequest2", "UserInputNeededMessage", 6usize, |this| ((**(this as *const *const IFileUpdateRequest2_Vtbl)).UserInputNeededMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserInputNeededMessage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileUpdateRequest2", "SetUserInputNeededMessage", 7usize, |this| ((**(this as *const *const IFileUpdateRequest2_Vtbl)).SetUserInputNeededMessage)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserInputNeededMessage, SetUserInputNeededMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileUpdateRequest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileUpdateRequestDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileUpdateRequestDeferral", "Complete", 6usize, |this| ((**(this as *const *const IFileUpdateRequestDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileUpdateRequestDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileUpdateRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileUpdateRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const IFileUpdateRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileUpdateRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderFileTypeInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FileExtension(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderFileTypeInfo", "FileExtension", 6usize, |this| ((**(this as *const *const IStorageProviderFileTypeInfo_Vtbl)).FileExtension)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IconResource(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderFileTypeInfo", "IconResource", 7usize, |this| ((**(this as *const *const IStorageProviderFileTypeInfo_Vtbl)).IconResource)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FileExtension, IconResource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderFileTypeInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderFileTypeInfoFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, fileextension: core::mem::MaybeUninit<windows_core::HSTRING>, iconresource: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderFileTypeInfoFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IStorageProviderFileTypeInfoFactory_Vtbl)).CreateInstance)(this, fileextension, iconresource, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderFileTypeInfoFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderGetContentInfoForPathResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut StorageProviderUriSourceStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderGetContentInfoForPathResult", "Status", 6usize, |this| ((**(this as *const *const IStorageProviderGetContentInfoForPathResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, value: StorageProviderUriSourceStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderGetContentInfoForPathResult", "SetStatus", 7usize, |this| ((**(this as *const *const IStorageProviderGetContentInfoForPathResult_Vtbl)).SetStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentUri(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderGetContentInfoForPathResult", "ContentUri", 8usize, |this| ((**(this as *const *const IStorageProviderGetContentInfoForPathResult_Vtbl)).ContentUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentUri(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderGetContentInfoForPathResult", "SetContentUri", 9usize, |this| ((**(this as *const *const IStorageProviderGetContentInfoForPathResult_Vtbl)).SetContentUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderGetContentInfoForPathResult", "ContentId", 10usize, |this| ((**(this as *const *const IStorageProviderGetContentInfoForPathResult_Vtbl)).ContentId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderGetContentInfoForPathResult", "SetContentId", 11usize, |this| ((**(this as *const *const IStorageProviderGetContentInfoForPathResult_Vtbl)).SetContentId)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            SetStatus,
+            ContentUri,
+            SetContentUri,
+            ContentId,
+            SetContentId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderGetContentInfoForPathResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderGetPathForContentUriResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut StorageProviderUriSourceStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderGetPathForContentUriResult", "Status", 6usize, |this| ((**(this as *const *const IStorageProviderGetPathForContentUriResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, value: StorageProviderUriSourceStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderGetPathForContentUriResult", "SetStatus", 7usize, |this| ((**(this as *const *const IStorageProviderGetPathForContentUriResult_Vtbl)).SetStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderGetPathForContentUriResult", "Path", 8usize, |this| ((**(this as *const *const IStorageProviderGetPathForContentUriResult_Vtbl)).Path)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPath(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderGetPathForContentUriResult", "SetPath", 9usize, |this| ((**(this as *const *const IStorageProviderGetPathForContentUriResult_Vtbl)).SetPath)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, SetStatus, Path, SetPath }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderGetPathForContentUriResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageProviderItemPropertiesStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetAsync(this: *mut core::ffi::c_void, item: *mut core::ffi::c_void, itemproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderItemPropertiesStatics", "SetAsync", 6usize, |this| ((**(this as *const *const IStorageProviderItemPropertiesStatics_Vtbl)).SetAsync)(this, item, itemproperties, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SetAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderItemPropertiesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderItemProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderItemProperty", "SetId", 6usize, |this| ((**(this as *const *const IStorageProviderItemProperty_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderItemProperty", "Id", 7usize, |this| ((**(this as *const *const IStorageProviderItemProperty_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderItemProperty", "SetValue", 8usize, |this| ((**(this as *const *const IStorageProviderItemProperty_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderItemProperty", "Value", 9usize, |this| ((**(this as *const *const IStorageProviderItemProperty_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIconResource(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderItemProperty", "SetIconResource", 10usize, |this| ((**(this as *const *const IStorageProviderItemProperty_Vtbl)).SetIconResource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IconResource(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderItemProperty", "IconResource", 11usize, |this| ((**(this as *const *const IStorageProviderItemProperty_Vtbl)).IconResource)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetId, Id, SetValue, Value, SetIconResource, IconResource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderItemProperty as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderItemPropertyDefinition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderItemPropertyDefinition", "Id", 6usize, |this| ((**(this as *const *const IStorageProviderItemPropertyDefinition_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderItemPropertyDefinition", "SetId", 7usize, |this| ((**(this as *const *const IStorageProviderItemPropertyDefinition_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayNameResource(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderItemPropertyDefinition", "DisplayNameResource", 8usize, |this| ((**(this as *const *const IStorageProviderItemPropertyDefinition_Vtbl)).DisplayNameResource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayNameResource(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderItemPropertyDefinition", "SetDisplayNameResource", 9usize, |this| ((**(this as *const *const IStorageProviderItemPropertyDefinition_Vtbl)).SetDisplayNameResource)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, SetId, DisplayNameResource, SetDisplayNameResource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderItemPropertyDefinition as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageProviderItemPropertySource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetItemProperties(this: *mut core::ffi::c_void, itempath: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderItemPropertySource", "GetItemProperties", 6usize, |this| ((**(this as *const *const IStorageProviderItemPropertySource_Vtbl)).GetItemProperties)(this, itempath, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetItemProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetItemProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderItemPropertySource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderKnownFolderEntry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KnownFolderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderKnownFolderEntry", "KnownFolderId", 6usize, |this| ((**(this as *const *const IStorageProviderKnownFolderEntry_Vtbl)).KnownFolderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKnownFolderId(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderKnownFolderEntry", "SetKnownFolderId", 7usize, |this| ((**(this as *const *const IStorageProviderKnownFolderEntry_Vtbl)).SetKnownFolderId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut StorageProviderKnownFolderSyncStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderKnownFolderEntry", "Status", 8usize, |this| ((**(this as *const *const IStorageProviderKnownFolderEntry_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, value: StorageProviderKnownFolderSyncStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderKnownFolderEntry", "SetStatus", 9usize, |this| ((**(this as *const *const IStorageProviderKnownFolderEntry_Vtbl)).SetStatus)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KnownFolderId, SetKnownFolderId, Status, SetStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderKnownFolderEntry as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageProviderKnownFolderSyncInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProviderDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderKnownFolderSyncInfo", "ProviderDisplayName", 6usize, |this| ((**(this as *const *const IStorageProviderKnownFolderSyncInfo_Vtbl)).ProviderDisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderKnownFolderSyncInfo", "SetProviderDisplayName", 7usize, |this| ((**(this as *const *const IStorageProviderKnownFolderSyncInfo_Vtbl)).SetProviderDisplayName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn KnownFolderEntries(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderKnownFolderSyncInfo", "KnownFolderEntries", 8usize, |this| ((**(this as *const *const IStorageProviderKnownFolderSyncInfo_Vtbl)).KnownFolderEntries)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncRequested(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderKnownFolderSyncInfo", "SyncRequested", 9usize, |this| ((**(this as *const *const IStorageProviderKnownFolderSyncInfo_Vtbl)).SyncRequested)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncRequested(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderKnownFolderSyncInfo", "SetSyncRequested", 10usize, |this| ((**(this as *const *const IStorageProviderKnownFolderSyncInfo_Vtbl)).SetSyncRequested)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProviderDisplayName,
+            SetProviderDisplayName,
+            #[cfg(feature = "Foundation_Collections")]
+            KnownFolderEntries,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            KnownFolderEntries: 0,
+            SyncRequested,
+            SetSyncRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderKnownFolderSyncInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderKnownFolderSyncInfoSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetKnownFolderSyncInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderKnownFolderSyncInfoSource", "GetKnownFolderSyncInfo", 6usize, |this| ((**(this as *const *const IStorageProviderKnownFolderSyncInfoSource_Vtbl)).GetKnownFolderSyncInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KnownFolderSyncInfoChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderKnownFolderSyncInfoSource", "KnownFolderSyncInfoChanged", 7usize, |this| ((**(this as *const *const IStorageProviderKnownFolderSyncInfoSource_Vtbl)).KnownFolderSyncInfoChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveKnownFolderSyncInfoChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderKnownFolderSyncInfoSource", "RemoveKnownFolderSyncInfoChanged", 8usize, |this| ((**(this as *const *const IStorageProviderKnownFolderSyncInfoSource_Vtbl)).RemoveKnownFolderSyncInfoChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetKnownFolderSyncInfo,
+            KnownFolderSyncInfoChanged,
+            RemoveKnownFolderSyncInfoChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderKnownFolderSyncInfoSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderKnownFolderSyncInfoSourceFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetKnownFolderSyncInfoSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderKnownFolderSyncInfoSourceFactory", "GetKnownFolderSyncInfoSource", 6usize, |this| ((**(this as *const *const IStorageProviderKnownFolderSyncInfoSourceFactory_Vtbl)).GetKnownFolderSyncInfoSource)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetKnownFolderSyncInfoSource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderKnownFolderSyncInfoSourceFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageProviderKnownFolderSyncRequestArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn KnownFolders(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderKnownFolderSyncRequestArgs", "KnownFolders", 6usize, |this| ((**(this as *const *const IStorageProviderKnownFolderSyncRequestArgs_Vtbl)).KnownFolders)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderKnownFolderSyncRequestArgs", "Source", 7usize, |this| ((**(this as *const *const IStorageProviderKnownFolderSyncRequestArgs_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            KnownFolders,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            KnownFolders: 0,
+            Source,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderKnownFolderSyncRequestArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderMoreInfoUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderMoreInfoUI", "Message", 6usize, |this| ((**(this as *const *const IStorageProviderMoreInfoUI_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderMoreInfoUI", "SetMessage", 7usize, |this| ((**(this as *const *const IStorageProviderMoreInfoUI_Vtbl)).SetMessage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Command(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderMoreInfoUI", "Command", 8usize, |this| ((**(this as *const *const IStorageProviderMoreInfoUI_Vtbl)).Command)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCommand(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderMoreInfoUI", "SetCommand", 9usize, |this| ((**(this as *const *const IStorageProviderMoreInfoUI_Vtbl)).SetCommand)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Message, SetMessage, Command, SetCommand }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderMoreInfoUI as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderPropertyCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPropertySupported(this: *mut core::ffi::c_void, propertycanonicalname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderPropertyCapabilities", "IsPropertySupported", 6usize, |this| ((**(this as *const *const IStorageProviderPropertyCapabilities_Vtbl)).IsPropertySupported)(this, propertycanonicalname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsPropertySupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderPropertyCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for IStorageProviderQuotaUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QuotaTotalInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderQuotaUI", "QuotaTotalInBytes", 6usize, |this| ((**(this as *const *const IStorageProviderQuotaUI_Vtbl)).QuotaTotalInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuotaTotalInBytes(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderQuotaUI", "SetQuotaTotalInBytes", 7usize, |this| ((**(this as *const *const IStorageProviderQuotaUI_Vtbl)).SetQuotaTotalInBytes)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuotaUsedInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderQuotaUI", "QuotaUsedInBytes", 8usize, |this| ((**(this as *const *const IStorageProviderQuotaUI_Vtbl)).QuotaUsedInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuotaUsedInBytes(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderQuotaUI", "SetQuotaUsedInBytes", 9usize, |this| ((**(this as *const *const IStorageProviderQuotaUI_Vtbl)).SetQuotaUsedInBytes)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuotaUsedLabel(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderQuotaUI", "QuotaUsedLabel", 10usize, |this| ((**(this as *const *const IStorageProviderQuotaUI_Vtbl)).QuotaUsedLabel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuotaUsedLabel(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderQuotaUI", "SetQuotaUsedLabel", 11usize, |this| ((**(this as *const *const IStorageProviderQuotaUI_Vtbl)).SetQuotaUsedLabel)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn QuotaUsedColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderQuotaUI", "QuotaUsedColor", 12usize, |this| ((**(this as *const *const IStorageProviderQuotaUI_Vtbl)).QuotaUsedColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetQuotaUsedColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderQuotaUI", "SetQuotaUsedColor", 13usize, |this| ((**(this as *const *const IStorageProviderQuotaUI_Vtbl)).SetQuotaUsedColor)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QuotaTotalInBytes,
+            SetQuotaTotalInBytes,
+            QuotaUsedInBytes,
+            SetQuotaUsedInBytes,
+            QuotaUsedLabel,
+            SetQuotaUsedLabel,
+            #[cfg(feature = "UI")]
+            QuotaUsedColor,
+            #[cfg(not(feature = "UI"))]
+            QuotaUsedColor: 0,
+            #[cfg(feature = "UI")]
+            SetQuotaUsedColor,
+            #[cfg(not(feature = "UI"))]
+            SetQuotaUsedColor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderQuotaUI as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageProviderStatusUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProviderState(this: *mut core::ffi::c_void, result__: *mut StorageProviderState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "ProviderState", 6usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).ProviderState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderState(this: *mut core::ffi::c_void, value: StorageProviderState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "SetProviderState", 7usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).SetProviderState)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderStateLabel(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "ProviderStateLabel", 8usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).ProviderStateLabel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderStateLabel(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "SetProviderStateLabel", 9usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).SetProviderStateLabel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderStateIcon(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "ProviderStateIcon", 10usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).ProviderStateIcon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderStateIcon(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "SetProviderStateIcon", 11usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).SetProviderStateIcon)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncStatusCommand(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "SyncStatusCommand", 12usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).SyncStatusCommand)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncStatusCommand(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "SetSyncStatusCommand", 13usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).SetSyncStatusCommand)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuotaUI(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "QuotaUI", 14usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).QuotaUI)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuotaUI(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "SetQuotaUI", 15usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).SetQuotaUI)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoreInfoUI(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "MoreInfoUI", 16usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).MoreInfoUI)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMoreInfoUI(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "SetMoreInfoUI", 17usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).SetMoreInfoUI)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderPrimaryCommand(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "ProviderPrimaryCommand", 18usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).ProviderPrimaryCommand)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderPrimaryCommand(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "SetProviderPrimaryCommand", 19usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).SetProviderPrimaryCommand)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ProviderSecondaryCommands(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "ProviderSecondaryCommands", 20usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).ProviderSecondaryCommands)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetProviderSecondaryCommands(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUI", "SetProviderSecondaryCommands", 21usize, |this| ((**(this as *const *const IStorageProviderStatusUI_Vtbl)).SetProviderSecondaryCommands)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProviderState,
+            SetProviderState,
+            ProviderStateLabel,
+            SetProviderStateLabel,
+            ProviderStateIcon,
+            SetProviderStateIcon,
+            SyncStatusCommand,
+            SetSyncStatusCommand,
+            QuotaUI,
+            SetQuotaUI,
+            MoreInfoUI,
+            SetMoreInfoUI,
+            ProviderPrimaryCommand,
+            SetProviderPrimaryCommand,
+            #[cfg(feature = "Foundation_Collections")]
+            ProviderSecondaryCommands,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ProviderSecondaryCommands: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetProviderSecondaryCommands,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetProviderSecondaryCommands: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderStatusUI as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderStatusUISource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatusUI(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUISource", "GetStatusUI", 6usize, |this| ((**(this as *const *const IStorageProviderStatusUISource_Vtbl)).GetStatusUI)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusUIChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUISource", "StatusUIChanged", 7usize, |this| ((**(this as *const *const IStorageProviderStatusUISource_Vtbl)).StatusUIChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStatusUIChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUISource", "RemoveStatusUIChanged", 8usize, |this| ((**(this as *const *const IStorageProviderStatusUISource_Vtbl)).RemoveStatusUIChanged)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStatusUI, StatusUIChanged, RemoveStatusUIChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderStatusUISource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderStatusUISourceFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatusUISource(this: *mut core::ffi::c_void, syncrootid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderStatusUISourceFactory", "GetStatusUISource", 6usize, |this| ((**(this as *const *const IStorageProviderStatusUISourceFactory_Vtbl)).GetStatusUISource)(this, syncrootid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStatusUISource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderStatusUISourceFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IStorageProviderSyncRootInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "Id", 6usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "SetId", 7usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Context(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "Context", 8usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).Context)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetContext(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "SetContext", 9usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).SetContext)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "Path", 10usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).Path)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPath(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "SetPath", 11usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).SetPath)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayNameResource(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "DisplayNameResource", 12usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).DisplayNameResource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayNameResource(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "SetDisplayNameResource", 13usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).SetDisplayNameResource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IconResource(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "IconResource", 14usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).IconResource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIconResource(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "SetIconResource", 15usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).SetIconResource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HydrationPolicy(this: *mut core::ffi::c_void, result__: *mut StorageProviderHydrationPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "HydrationPolicy", 16usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).HydrationPolicy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHydrationPolicy(this: *mut core::ffi::c_void, value: StorageProviderHydrationPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "SetHydrationPolicy", 17usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).SetHydrationPolicy)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HydrationPolicyModifier(this: *mut core::ffi::c_void, result__: *mut StorageProviderHydrationPolicyModifier) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "HydrationPolicyModifier", 18usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).HydrationPolicyModifier)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHydrationPolicyModifier(this: *mut core::ffi::c_void, value: StorageProviderHydrationPolicyModifier) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "SetHydrationPolicyModifier", 19usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).SetHydrationPolicyModifier)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PopulationPolicy(this: *mut core::ffi::c_void, result__: *mut StorageProviderPopulationPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "PopulationPolicy", 20usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).PopulationPolicy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPopulationPolicy(this: *mut core::ffi::c_void, value: StorageProviderPopulationPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "SetPopulationPolicy", 21usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).SetPopulationPolicy)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InSyncPolicy(this: *mut core::ffi::c_void, result__: *mut StorageProviderInSyncPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "InSyncPolicy", 22usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).InSyncPolicy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInSyncPolicy(this: *mut core::ffi::c_void, value: StorageProviderInSyncPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "SetInSyncPolicy", 23usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).SetInSyncPolicy)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardlinkPolicy(this: *mut core::ffi::c_void, result__: *mut StorageProviderHardlinkPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "HardlinkPolicy", 24usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).HardlinkPolicy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHardlinkPolicy(this: *mut core::ffi::c_void, value: StorageProviderHardlinkPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "SetHardlinkPolicy", 25usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).SetHardlinkPolicy)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowSiblingsAsGroup(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "ShowSiblingsAsGroup", 26usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).ShowSiblingsAsGroup)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowSiblingsAsGroup(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "SetShowSiblingsAsGroup", 27usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).SetShowSiblingsAsGroup)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "Version", 28usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).Version)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVersion(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "SetVersion", 29usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).SetVersion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectionMode(this: *mut core::ffi::c_void, result__: *mut StorageProviderProtectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "ProtectionMode", 30usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).ProtectionMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProtectionMode(this: *mut core::ffi::c_void, value: StorageProviderProtectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "SetProtectionMode", 31usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).SetProtectionMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowPinning(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "AllowPinning", 32usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).AllowPinning)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowPinning(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "SetAllowPinning", 33usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).SetAllowPinning)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StorageProviderItemPropertyDefinitions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "StorageProviderItemPropertyDefinitions", 34usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).StorageProviderItemPropertyDefinitions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecycleBinUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "RecycleBinUri", 35usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).RecycleBinUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecycleBinUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo", "SetRecycleBinUri", 36usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo_Vtbl)).SetRecycleBinUri)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            SetId,
+            #[cfg(feature = "Storage_Streams")]
+            Context,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Context: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetContext,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetContext: 0,
+            Path,
+            SetPath,
+            DisplayNameResource,
+            SetDisplayNameResource,
+            IconResource,
+            SetIconResource,
+            HydrationPolicy,
+            SetHydrationPolicy,
+            HydrationPolicyModifier,
+            SetHydrationPolicyModifier,
+            PopulationPolicy,
+            SetPopulationPolicy,
+            InSyncPolicy,
+            SetInSyncPolicy,
+            HardlinkPolicy,
+            SetHardlinkPolicy,
+            ShowSiblingsAsGroup,
+            SetShowSiblingsAsGroup,
+            Version,
+            SetVersion,
+            ProtectionMode,
+            SetProtectionMode,
+            AllowPinning,
+            SetAllowPinning,
+            #[cfg(feature = "Foundation_Collections")]
+            StorageProviderItemPropertyDefinitions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StorageProviderItemPropertyDefinitions: 0,
+            RecycleBinUri,
+            SetRecycleBinUri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderSyncRootInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderSyncRootInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProviderId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo2", "ProviderId", 6usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo2_Vtbl)).ProviderId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderId(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo2", "SetProviderId", 7usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo2_Vtbl)).SetProviderId)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProviderId, SetProviderId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderSyncRootInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageProviderSyncRootInfo3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FallbackFileTypeInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootInfo3", "FallbackFileTypeInfo", 6usize, |this| ((**(this as *const *const IStorageProviderSyncRootInfo3_Vtbl)).FallbackFileTypeInfo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FallbackFileTypeInfo,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FallbackFileTypeInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderSyncRootInfo3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageProviderSyncRootManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void, syncrootinformation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootManagerStatics", "Register", 6usize, |this| ((**(this as *const *const IStorageProviderSyncRootManagerStatics_Vtbl)).Register)(this, syncrootinformation), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unregister(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootManagerStatics", "Unregister", 7usize, |this| ((**(this as *const *const IStorageProviderSyncRootManagerStatics_Vtbl)).Unregister)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSyncRootInformationForFolder(this: *mut core::ffi::c_void, folder: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootManagerStatics", "GetSyncRootInformationForFolder", 8usize, |this| ((**(this as *const *const IStorageProviderSyncRootManagerStatics_Vtbl)).GetSyncRootInformationForFolder)(this, folder, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSyncRootInformationForId(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootManagerStatics", "GetSyncRootInformationForId", 9usize, |this| ((**(this as *const *const IStorageProviderSyncRootManagerStatics_Vtbl)).GetSyncRootInformationForId)(this, id, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCurrentSyncRoots(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootManagerStatics", "GetCurrentSyncRoots", 10usize, |this| ((**(this as *const *const IStorageProviderSyncRootManagerStatics_Vtbl)).GetCurrentSyncRoots)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Register,
+            Unregister,
+            GetSyncRootInformationForFolder,
+            GetSyncRootInformationForId,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCurrentSyncRoots,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCurrentSyncRoots: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderSyncRootManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderSyncRootManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderSyncRootManagerStatics2", "IsSupported", 6usize, |this| ((**(this as *const *const IStorageProviderSyncRootManagerStatics2_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderSyncRootManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderUICommand_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderUICommand", "Label", 6usize, |this| ((**(this as *const *const IStorageProviderUICommand_Vtbl)).Label)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderUICommand", "Description", 7usize, |this| ((**(this as *const *const IStorageProviderUICommand_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Icon(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderUICommand", "Icon", 8usize, |this| ((**(this as *const *const IStorageProviderUICommand_Vtbl)).Icon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut StorageProviderUICommandState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderUICommand", "State", 9usize, |this| ((**(this as *const *const IStorageProviderUICommand_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderUICommand", "Invoke", 10usize, |this| ((**(this as *const *const IStorageProviderUICommand_Vtbl)).Invoke)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Label, Description, Icon, State, Invoke }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderUICommand as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProviderUriSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPathForContentUri(this: *mut core::ffi::c_void, contenturi: core::mem::MaybeUninit<windows_core::HSTRING>, result: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderUriSource", "GetPathForContentUri", 6usize, |this| ((**(this as *const *const IStorageProviderUriSource_Vtbl)).GetPathForContentUri)(this, contenturi, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentInfoForPath(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::HSTRING>, result: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProviderUriSource", "GetContentInfoForPath", 7usize, |this| ((**(this as *const *const IStorageProviderUriSource_Vtbl)).GetContentInfoForPath)(this, path, result), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPathForContentUri, GetContentInfoForPath }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProviderUriSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1585,6 +1585,7 @@ impl CommonFileQuery {
 impl windows_core::TypeKind for CommonFileQuery {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CommonFileQuery {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CommonFileQuery").field(&self.0).finish()
@@ -1614,6 +1615,7 @@ impl CommonFolderQuery {
 impl windows_core::TypeKind for CommonFolderQuery {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CommonFolderQuery {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CommonFolderQuery").field(&self.0).finish()
@@ -1633,6 +1635,7 @@ impl DateStackOption {
 impl windows_core::TypeKind for DateStackOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DateStackOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DateStackOption").field(&self.0).finish()
@@ -1651,6 +1654,7 @@ impl FolderDepth {
 impl windows_core::TypeKind for FolderDepth {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FolderDepth {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FolderDepth").field(&self.0).finish()
@@ -1671,6 +1675,7 @@ impl IndexedState {
 impl windows_core::TypeKind for IndexedState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IndexedState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IndexedState").field(&self.0).finish()
@@ -1691,6 +1696,7 @@ impl IndexerOption {
 impl windows_core::TypeKind for IndexerOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IndexerOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IndexerOption").field(&self.0).finish()
@@ -1700,7 +1706,8 @@ impl windows_core::RuntimeType for IndexerOption {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Storage.Search.IndexerOption;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SortEntry {
     pub PropertyName: windows_core::HSTRING,
     pub AscendingOrder: bool,
@@ -1718,3 +1725,5 @@ impl Default for SortEntry {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
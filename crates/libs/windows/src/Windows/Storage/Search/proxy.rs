@@ -0,0 +1,649 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContentIndexer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddAsync(this: *mut core::ffi::c_void, indexablecontent: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexer", "AddAsync", 6usize, |this| ((**(this as *const *const IContentIndexer_Vtbl)).AddAsync)(this, indexablecontent, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateAsync(this: *mut core::ffi::c_void, indexablecontent: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexer", "UpdateAsync", 7usize, |this| ((**(this as *const *const IContentIndexer_Vtbl)).UpdateAsync)(this, indexablecontent, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, contentid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexer", "DeleteAsync", 8usize, |this| ((**(this as *const *const IContentIndexer_Vtbl)).DeleteAsync)(this, contentid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DeleteMultipleAsync(this: *mut core::ffi::c_void, contentids: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexer", "DeleteMultipleAsync", 9usize, |this| ((**(this as *const *const IContentIndexer_Vtbl)).DeleteMultipleAsync)(this, contentids, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAllAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexer", "DeleteAllAsync", 10usize, |this| ((**(this as *const *const IContentIndexer_Vtbl)).DeleteAllAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RetrievePropertiesAsync(this: *mut core::ffi::c_void, contentid: core::mem::MaybeUninit<windows_core::HSTRING>, propertiestoretrieve: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexer", "RetrievePropertiesAsync", 11usize, |this| ((**(this as *const *const IContentIndexer_Vtbl)).RetrievePropertiesAsync)(this, contentid, propertiestoretrieve, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Revision(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexer", "Revision", 12usize, |this| ((**(this as *const *const IContentIndexer_Vtbl)).Revision)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddAsync,
+            UpdateAsync,
+            DeleteAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            DeleteMultipleAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DeleteMultipleAsync: 0,
+            DeleteAllAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            RetrievePropertiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RetrievePropertiesAsync: 0,
+            Revision,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContentIndexer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContentIndexerQuery_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCountAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexerQuery", "GetCountAsync", 6usize, |this| ((**(this as *const *const IContentIndexerQuery_Vtbl)).GetCountAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetPropertiesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexerQuery", "GetPropertiesAsync", 7usize, |this| ((**(this as *const *const IContentIndexerQuery_Vtbl)).GetPropertiesAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetPropertiesRangeAsync(this: *mut core::ffi::c_void, startindex: u32, maxitems: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexerQuery", "GetPropertiesRangeAsync", 8usize, |this| ((**(this as *const *const IContentIndexerQuery_Vtbl)).GetPropertiesRangeAsync)(this, startindex, maxitems, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexerQuery", "GetAsync", 9usize, |this| ((**(this as *const *const IContentIndexerQuery_Vtbl)).GetAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetRangeAsync(this: *mut core::ffi::c_void, startindex: u32, maxitems: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexerQuery", "GetRangeAsync", 10usize, |this| ((**(this as *const *const IContentIndexerQuery_Vtbl)).GetRangeAsync)(this, startindex, maxitems, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryFolder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexerQuery", "QueryFolder", 11usize, |this| ((**(this as *const *const IContentIndexerQuery_Vtbl)).QueryFolder)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCountAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetPropertiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetPropertiesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetPropertiesRangeAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetPropertiesRangeAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetRangeAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetRangeAsync: 0,
+            QueryFolder,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContentIndexerQuery as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IContentIndexerQueryOperations_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateQueryWithSortOrderAndLanguage(this: *mut core::ffi::c_void, searchfilter: core::mem::MaybeUninit<windows_core::HSTRING>, propertiestoretrieve: *mut core::ffi::c_void, sortorder: *mut core::ffi::c_void, searchfilterlanguage: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexerQueryOperations", "CreateQueryWithSortOrderAndLanguage", 6usize, |this| ((**(this as *const *const IContentIndexerQueryOperations_Vtbl)).CreateQueryWithSortOrderAndLanguage)(this, searchfilter, propertiestoretrieve, sortorder, searchfilterlanguage, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateQueryWithSortOrder(this: *mut core::ffi::c_void, searchfilter: core::mem::MaybeUninit<windows_core::HSTRING>, propertiestoretrieve: *mut core::ffi::c_void, sortorder: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexerQueryOperations", "CreateQueryWithSortOrder", 7usize, |this| ((**(this as *const *const IContentIndexerQueryOperations_Vtbl)).CreateQueryWithSortOrder)(this, searchfilter, propertiestoretrieve, sortorder, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateQuery(this: *mut core::ffi::c_void, searchfilter: core::mem::MaybeUninit<windows_core::HSTRING>, propertiestoretrieve: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexerQueryOperations", "CreateQuery", 8usize, |this| ((**(this as *const *const IContentIndexerQueryOperations_Vtbl)).CreateQuery)(this, searchfilter, propertiestoretrieve, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateQueryWithSortOrderAndLanguage,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateQueryWithSortOrderAndLanguage: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateQueryWithSortOrder,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateQueryWithSortOrder: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateQuery,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateQuery: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContentIndexerQueryOperations as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContentIndexerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIndexerWithName(this: *mut core::ffi::c_void, indexname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexerStatics", "GetIndexerWithName", 6usize, |this| ((**(this as *const *const IContentIndexerStatics_Vtbl)).GetIndexerWithName)(this, indexname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndexer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentIndexerStatics", "GetIndexer", 7usize, |this| ((**(this as *const *const IContentIndexerStatics_Vtbl)).GetIndexer)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIndexerWithName, GetIndexer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContentIndexerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IIndexableContent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexableContent", "Id", 6usize, |this| ((**(this as *const *const IIndexableContent_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexableContent", "SetId", 7usize, |this| ((**(this as *const *const IIndexableContent_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexableContent", "Properties", 8usize, |this| ((**(this as *const *const IIndexableContent_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Stream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexableContent", "Stream", 9usize, |this| ((**(this as *const *const IIndexableContent_Vtbl)).Stream)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetStream(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexableContent", "SetStream", 10usize, |this| ((**(this as *const *const IIndexableContent_Vtbl)).SetStream)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StreamContentType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexableContent", "StreamContentType", 11usize, |this| ((**(this as *const *const IIndexableContent_Vtbl)).StreamContentType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamContentType(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexableContent", "SetStreamContentType", 12usize, |this| ((**(this as *const *const IIndexableContent_Vtbl)).SetStreamContentType)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            SetId,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            #[cfg(feature = "Storage_Streams")]
+            Stream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Stream: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetStream: 0,
+            StreamContentType,
+            SetStreamContentType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIndexableContent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_FileProperties"))]
+impl windows_core::imp::ProxyVtable for IQueryOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FileTypeFilter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "FileTypeFilter", 6usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).FileTypeFilter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FolderDepth(this: *mut core::ffi::c_void, result__: *mut FolderDepth) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "FolderDepth", 7usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).FolderDepth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFolderDepth(this: *mut core::ffi::c_void, value: FolderDepth) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "SetFolderDepth", 8usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).SetFolderDepth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationSearchFilter(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "ApplicationSearchFilter", 9usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).ApplicationSearchFilter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationSearchFilter(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "SetApplicationSearchFilter", 10usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).SetApplicationSearchFilter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserSearchFilter(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "UserSearchFilter", 11usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).UserSearchFilter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserSearchFilter(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "SetUserSearchFilter", 12usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).SetUserSearchFilter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "Language", 13usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "SetLanguage", 14usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).SetLanguage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IndexerOption(this: *mut core::ffi::c_void, result__: *mut IndexerOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "IndexerOption", 15usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).IndexerOption)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIndexerOption(this: *mut core::ffi::c_void, value: IndexerOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "SetIndexerOption", 16usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).SetIndexerOption)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SortOrder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "SortOrder", 17usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).SortOrder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GroupPropertyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "GroupPropertyName", 18usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).GroupPropertyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DateStackOption(this: *mut core::ffi::c_void, result__: *mut DateStackOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "DateStackOption", 19usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).DateStackOption)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveToString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "SaveToString", 20usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).SaveToString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadFromString(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "LoadFromString", 21usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).LoadFromString)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_FileProperties")]
+        unsafe extern "system" fn SetThumbnailPrefetch(this: *mut core::ffi::c_void, mode: super::FileProperties::ThumbnailMode, requestedsize: u32, options: super::FileProperties::ThumbnailOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "SetThumbnailPrefetch", 22usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).SetThumbnailPrefetch)(this, mode, requestedsize, options), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_FileProperties"))]
+        unsafe extern "system" fn SetPropertyPrefetch(this: *mut core::ffi::c_void, options: super::FileProperties::PropertyPrefetchOptions, propertiestoretrieve: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptions", "SetPropertyPrefetch", 23usize, |this| ((**(this as *const *const IQueryOptions_Vtbl)).SetPropertyPrefetch)(this, options, propertiestoretrieve), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FileTypeFilter,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FileTypeFilter: 0,
+            FolderDepth,
+            SetFolderDepth,
+            ApplicationSearchFilter,
+            SetApplicationSearchFilter,
+            UserSearchFilter,
+            SetUserSearchFilter,
+            Language,
+            SetLanguage,
+            IndexerOption,
+            SetIndexerOption,
+            #[cfg(feature = "Foundation_Collections")]
+            SortOrder,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SortOrder: 0,
+            GroupPropertyName,
+            DateStackOption,
+            SaveToString,
+            LoadFromString,
+            #[cfg(feature = "Storage_FileProperties")]
+            SetThumbnailPrefetch,
+            #[cfg(not(feature = "Storage_FileProperties"))]
+            SetThumbnailPrefetch: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_FileProperties"))]
+            SetPropertyPrefetch,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_FileProperties")))]
+            SetPropertyPrefetch: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQueryOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IQueryOptionsFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateCommonFileQuery(this: *mut core::ffi::c_void, query: CommonFileQuery, filetypefilter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptionsFactory", "CreateCommonFileQuery", 6usize, |this| ((**(this as *const *const IQueryOptionsFactory_Vtbl)).CreateCommonFileQuery)(this, query, filetypefilter, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCommonFolderQuery(this: *mut core::ffi::c_void, query: CommonFolderQuery, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptionsFactory", "CreateCommonFolderQuery", 7usize, |this| ((**(this as *const *const IQueryOptionsFactory_Vtbl)).CreateCommonFolderQuery)(this, query, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateCommonFileQuery,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateCommonFileQuery: 0,
+            CreateCommonFolderQuery,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQueryOptionsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IQueryOptionsWithProviderFilter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StorageProviderIdFilter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOptionsWithProviderFilter", "StorageProviderIdFilter", 6usize, |this| ((**(this as *const *const IQueryOptionsWithProviderFilter_Vtbl)).StorageProviderIdFilter)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            StorageProviderIdFilter,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StorageProviderIdFilter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQueryOptionsWithProviderFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageFileQueryResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetFilesAsync(this: *mut core::ffi::c_void, startindex: u32, maxnumberofitems: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFileQueryResult", "GetFilesAsync", 6usize, |this| ((**(this as *const *const IStorageFileQueryResult_Vtbl)).GetFilesAsync)(this, startindex, maxnumberofitems, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetFilesAsyncDefaultStartAndCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFileQueryResult", "GetFilesAsyncDefaultStartAndCount", 7usize, |this| ((**(this as *const *const IStorageFileQueryResult_Vtbl)).GetFilesAsyncDefaultStartAndCount)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetFilesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetFilesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetFilesAsyncDefaultStartAndCount,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetFilesAsyncDefaultStartAndCount: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageFileQueryResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Text", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for IStorageFileQueryResult2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Data_Text", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn GetMatchingPropertiesWithRanges(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFileQueryResult2", "GetMatchingPropertiesWithRanges", 6usize, |this| ((**(this as *const *const IStorageFileQueryResult2_Vtbl)).GetMatchingPropertiesWithRanges)(this, file, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Data_Text", feature = "Foundation_Collections"))]
+            GetMatchingPropertiesWithRanges,
+            #[cfg(not(all(feature = "Data_Text", feature = "Foundation_Collections")))]
+            GetMatchingPropertiesWithRanges: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageFileQueryResult2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageFolderQueryOperations_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIndexedStateAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "GetIndexedStateAsync", 6usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).GetIndexedStateAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFileQueryOverloadDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "CreateFileQueryOverloadDefault", 7usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).CreateFileQueryOverloadDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFileQuery(this: *mut core::ffi::c_void, query: CommonFileQuery, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "CreateFileQuery", 8usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).CreateFileQuery)(this, query, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFileQueryWithOptions(this: *mut core::ffi::c_void, queryoptions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "CreateFileQueryWithOptions", 9usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).CreateFileQueryWithOptions)(this, queryoptions, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFolderQueryOverloadDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "CreateFolderQueryOverloadDefault", 10usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).CreateFolderQueryOverloadDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFolderQuery(this: *mut core::ffi::c_void, query: CommonFolderQuery, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "CreateFolderQuery", 11usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).CreateFolderQuery)(this, query, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFolderQueryWithOptions(this: *mut core::ffi::c_void, queryoptions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "CreateFolderQueryWithOptions", 12usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).CreateFolderQueryWithOptions)(this, queryoptions, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateItemQuery(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "CreateItemQuery", 13usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).CreateItemQuery)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateItemQueryWithOptions(this: *mut core::ffi::c_void, queryoptions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "CreateItemQueryWithOptions", 14usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).CreateItemQueryWithOptions)(this, queryoptions, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetFilesAsync(this: *mut core::ffi::c_void, query: CommonFileQuery, startindex: u32, maxitemstoretrieve: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "GetFilesAsync", 15usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).GetFilesAsync)(this, query, startindex, maxitemstoretrieve, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetFilesAsyncOverloadDefaultStartAndCount(this: *mut core::ffi::c_void, query: CommonFileQuery, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "GetFilesAsyncOverloadDefaultStartAndCount", 16usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).GetFilesAsyncOverloadDefaultStartAndCount)(this, query, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetFoldersAsync(this: *mut core::ffi::c_void, query: CommonFolderQuery, startindex: u32, maxitemstoretrieve: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "GetFoldersAsync", 17usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).GetFoldersAsync)(this, query, startindex, maxitemstoretrieve, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetFoldersAsyncOverloadDefaultStartAndCount(this: *mut core::ffi::c_void, query: CommonFolderQuery, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "GetFoldersAsyncOverloadDefaultStartAndCount", 18usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).GetFoldersAsyncOverloadDefaultStartAndCount)(this, query, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetItemsAsync(this: *mut core::ffi::c_void, startindex: u32, maxitemstoretrieve: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "GetItemsAsync", 19usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).GetItemsAsync)(this, startindex, maxitemstoretrieve, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AreQueryOptionsSupported(this: *mut core::ffi::c_void, queryoptions: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "AreQueryOptionsSupported", 20usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).AreQueryOptionsSupported)(this, queryoptions, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCommonFolderQuerySupported(this: *mut core::ffi::c_void, query: CommonFolderQuery, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "IsCommonFolderQuerySupported", 21usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).IsCommonFolderQuerySupported)(this, query, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCommonFileQuerySupported(this: *mut core::ffi::c_void, query: CommonFileQuery, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryOperations", "IsCommonFileQuerySupported", 22usize, |this| ((**(this as *const *const IStorageFolderQueryOperations_Vtbl)).IsCommonFileQuerySupported)(this, query, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetIndexedStateAsync,
+            CreateFileQueryOverloadDefault,
+            CreateFileQuery,
+            CreateFileQueryWithOptions,
+            CreateFolderQueryOverloadDefault,
+            CreateFolderQuery,
+            CreateFolderQueryWithOptions,
+            CreateItemQuery,
+            CreateItemQueryWithOptions,
+            #[cfg(feature = "Foundation_Collections")]
+            GetFilesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetFilesAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetFilesAsyncOverloadDefaultStartAndCount,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetFilesAsyncOverloadDefaultStartAndCount: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetFoldersAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetFoldersAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetFoldersAsyncOverloadDefaultStartAndCount,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetFoldersAsyncOverloadDefaultStartAndCount: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetItemsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetItemsAsync: 0,
+            AreQueryOptionsSupported,
+            IsCommonFolderQuerySupported,
+            IsCommonFileQuerySupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageFolderQueryOperations as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageFolderQueryResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetFoldersAsync(this: *mut core::ffi::c_void, startindex: u32, maxnumberofitems: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryResult", "GetFoldersAsync", 6usize, |this| ((**(this as *const *const IStorageFolderQueryResult_Vtbl)).GetFoldersAsync)(this, startindex, maxnumberofitems, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetFoldersAsyncDefaultStartAndCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderQueryResult", "GetFoldersAsyncDefaultStartAndCount", 7usize, |this| ((**(this as *const *const IStorageFolderQueryResult_Vtbl)).GetFoldersAsyncDefaultStartAndCount)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetFoldersAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetFoldersAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetFoldersAsyncDefaultStartAndCount,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetFoldersAsyncDefaultStartAndCount: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageFolderQueryResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageItemQueryResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetItemsAsync(this: *mut core::ffi::c_void, startindex: u32, maxnumberofitems: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemQueryResult", "GetItemsAsync", 6usize, |this| ((**(this as *const *const IStorageItemQueryResult_Vtbl)).GetItemsAsync)(this, startindex, maxnumberofitems, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetItemsAsyncDefaultStartAndCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemQueryResult", "GetItemsAsyncDefaultStartAndCount", 7usize, |this| ((**(this as *const *const IStorageItemQueryResult_Vtbl)).GetItemsAsyncDefaultStartAndCount)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetItemsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetItemsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetItemsAsyncDefaultStartAndCount,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetItemsAsyncDefaultStartAndCount: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageItemQueryResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageLibraryChangeTrackerTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Folder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChangeTrackerTriggerDetails", "Folder", 6usize, |this| ((**(this as *const *const IStorageLibraryChangeTrackerTriggerDetails_Vtbl)).Folder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeTracker(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChangeTrackerTriggerDetails", "ChangeTracker", 7usize, |this| ((**(this as *const *const IStorageLibraryChangeTrackerTriggerDetails_Vtbl)).ChangeTracker)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Folder, ChangeTracker }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibraryChangeTrackerTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageLibraryContentChangedTriggerDetails_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Folder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryContentChangedTriggerDetails", "Folder", 6usize, |this| ((**(this as *const *const IStorageLibraryContentChangedTriggerDetails_Vtbl)).Folder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateModifiedSinceQuery(this: *mut core::ffi::c_void, lastquerytime: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryContentChangedTriggerDetails", "CreateModifiedSinceQuery", 7usize, |this| ((**(this as *const *const IStorageLibraryContentChangedTriggerDetails_Vtbl)).CreateModifiedSinceQuery)(this, lastquerytime, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Folder, CreateModifiedSinceQuery }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibraryContentChangedTriggerDetails as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageQueryResultBase_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItemCountAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageQueryResultBase", "GetItemCountAsync", 6usize, |this| ((**(this as *const *const IStorageQueryResultBase_Vtbl)).GetItemCountAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Folder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageQueryResultBase", "Folder", 7usize, |this| ((**(this as *const *const IStorageQueryResultBase_Vtbl)).Folder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageQueryResultBase", "ContentsChanged", 8usize, |this| ((**(this as *const *const IStorageQueryResultBase_Vtbl)).ContentsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveContentsChanged(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageQueryResultBase", "RemoveContentsChanged", 9usize, |this| ((**(this as *const *const IStorageQueryResultBase_Vtbl)).RemoveContentsChanged)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn OptionsChanged(this: *mut core::ffi::c_void, changedhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageQueryResultBase", "OptionsChanged", 10usize, |this| ((**(this as *const *const IStorageQueryResultBase_Vtbl)).OptionsChanged)(this, changedhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOptionsChanged(this: *mut core::ffi::c_void, eventcookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageQueryResultBase", "RemoveOptionsChanged", 11usize, |this| ((**(this as *const *const IStorageQueryResultBase_Vtbl)).RemoveOptionsChanged)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindStartIndexAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageQueryResultBase", "FindStartIndexAsync", 12usize, |this| ((**(this as *const *const IStorageQueryResultBase_Vtbl)).FindStartIndexAsync)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentQueryOptions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageQueryResultBase", "GetCurrentQueryOptions", 13usize, |this| ((**(this as *const *const IStorageQueryResultBase_Vtbl)).GetCurrentQueryOptions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyNewQueryOptions(this: *mut core::ffi::c_void, newqueryoptions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageQueryResultBase", "ApplyNewQueryOptions", 14usize, |this| ((**(this as *const *const IStorageQueryResultBase_Vtbl)).ApplyNewQueryOptions)(this, newqueryoptions), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetItemCountAsync,
+            Folder,
+            ContentsChanged,
+            RemoveContentsChanged,
+            OptionsChanged,
+            RemoveOptionsChanged,
+            FindStartIndexAsync,
+            GetCurrentQueryOptions,
+            ApplyNewQueryOptions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageQueryResultBase as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IValueAndLanguage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueAndLanguage", "Language", 6usize, |this| ((**(this as *const *const IValueAndLanguage_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueAndLanguage", "SetLanguage", 7usize, |this| ((**(this as *const *const IValueAndLanguage_Vtbl)).SetLanguage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueAndLanguage", "Value", 8usize, |this| ((**(this as *const *const IValueAndLanguage_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueAndLanguage", "SetValue", 9usize, |this| ((**(this as *const *const IValueAndLanguage_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Language, SetLanguage, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IValueAndLanguage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
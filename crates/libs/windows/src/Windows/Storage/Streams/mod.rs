@@ -1325,6 +1325,27 @@ impl DataReaderLoadOperation {
         self.GetResults()
     }
 }
+#[cfg(feature = "std")]
+impl super::super::Foundation::Async for DataReaderLoadOperation {
+    type Output = u32;
+    fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetCompleted(&super::super::Foundation::AsyncOperationCompletedHandler::new(move |_sender, _args| {
+            handler();
+            Ok(())
+        }))
+    }
+    fn get_results(&self) -> windows_core::Result<Self::Output> {
+        self.GetResults()
+    }
+}
+#[cfg(feature = "std")]
+impl core::future::IntoFuture for DataReaderLoadOperation {
+    type Output = windows_core::Result<u32>;
+    type IntoFuture = super::super::Foundation::AsyncFuture<Self>;
+    fn into_future(self) -> Self::IntoFuture {
+        super::super::Foundation::AsyncFuture::new(self)
+    }
+}
 unsafe impl Send for DataReaderLoadOperation {}
 unsafe impl Sync for DataReaderLoadOperation {}
 #[repr(transparent)]
@@ -1593,6 +1614,27 @@ impl DataWriterStoreOperation {
         self.GetResults()
     }
 }
+#[cfg(feature = "std")]
+impl super::super::Foundation::Async for DataWriterStoreOperation {
+    type Output = u32;
+    fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> windows_core::Result<()> {
+        self.SetCompleted(&super::super::Foundation::AsyncOperationCompletedHandler::new(move |_sender, _args| {
+            handler();
+            Ok(())
+        }))
+    }
+    fn get_results(&self) -> windows_core::Result<Self::Output> {
+        self.GetResults()
+    }
+}
+#[cfg(feature = "std")]
+impl core::future::IntoFuture for DataWriterStoreOperation {
+    type Output = windows_core::Result<u32>;
+    type IntoFuture = super::super::Foundation::AsyncFuture<Self>;
+    fn into_future(self) -> Self::IntoFuture {
+        super::super::Foundation::AsyncFuture::new(self)
+    }
+}
 unsafe impl Send for DataWriterStoreOperation {}
 unsafe impl Sync for DataWriterStoreOperation {}
 #[repr(transparent)]
@@ -2247,6 +2289,7 @@ impl ByteOrder {
 impl windows_core::TypeKind for ByteOrder {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ByteOrder {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ByteOrder").field(&self.0).finish()
@@ -2268,6 +2311,7 @@ impl FileOpenDisposition {
 impl windows_core::TypeKind for FileOpenDisposition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FileOpenDisposition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FileOpenDisposition").field(&self.0).finish()
@@ -2287,6 +2331,7 @@ impl InputStreamOptions {
 impl windows_core::TypeKind for InputStreamOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InputStreamOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InputStreamOptions").field(&self.0).finish()
@@ -2339,6 +2384,7 @@ impl UnicodeEncoding {
 impl windows_core::TypeKind for UnicodeEncoding {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UnicodeEncoding {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UnicodeEncoding").field(&self.0).finish()
@@ -2349,3 +2395,5 @@ impl windows_core::RuntimeType for UnicodeEncoding {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
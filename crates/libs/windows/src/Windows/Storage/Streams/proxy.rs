@@ -0,0 +1,556 @@
+impl windows_core::imp::ProxyVtable for IBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Capacity(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBuffer", "Capacity", 6usize, |this| ((**(this as *const *const IBuffer_Vtbl)).Capacity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBuffer", "Length", 7usize, |this| ((**(this as *const *const IBuffer_Vtbl)).Length)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLength(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBuffer", "SetLength", 8usize, |this| ((**(this as *const *const IBuffer_Vtbl)).SetLength)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Capacity, Length, SetLength }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBuffer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBufferFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, capacity: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBufferFactory", "Create", 6usize, |this| ((**(this as *const *const IBufferFactory_Vtbl)).Create)(this, capacity, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBufferFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBufferStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateCopyFromMemoryBuffer(this: *mut core::ffi::c_void, input: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBufferStatics", "CreateCopyFromMemoryBuffer", 6usize, |this| ((**(this as *const *const IBufferStatics_Vtbl)).CreateCopyFromMemoryBuffer)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMemoryBufferOverIBuffer(this: *mut core::ffi::c_void, input: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBufferStatics", "CreateMemoryBufferOverIBuffer", 7usize, |this| ((**(this as *const *const IBufferStatics_Vtbl)).CreateMemoryBufferOverIBuffer)(this, input, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateCopyFromMemoryBuffer, CreateMemoryBufferOverIBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBufferStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContentTypeProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContentType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentTypeProvider", "ContentType", 6usize, |this| ((**(this as *const *const IContentTypeProvider_Vtbl)).ContentType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContentType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContentTypeProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UnconsumedBufferLength(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "UnconsumedBufferLength", 6usize, |this| ((**(this as *const *const IDataReader_Vtbl)).UnconsumedBufferLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnicodeEncoding(this: *mut core::ffi::c_void, result__: *mut UnicodeEncoding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "UnicodeEncoding", 7usize, |this| ((**(this as *const *const IDataReader_Vtbl)).UnicodeEncoding)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnicodeEncoding(this: *mut core::ffi::c_void, value: UnicodeEncoding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "SetUnicodeEncoding", 8usize, |this| ((**(this as *const *const IDataReader_Vtbl)).SetUnicodeEncoding)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ByteOrder(this: *mut core::ffi::c_void, result__: *mut ByteOrder) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ByteOrder", 9usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ByteOrder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetByteOrder(this: *mut core::ffi::c_void, value: ByteOrder) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "SetByteOrder", 10usize, |this| ((**(this as *const *const IDataReader_Vtbl)).SetByteOrder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputStreamOptions(this: *mut core::ffi::c_void, result__: *mut InputStreamOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "InputStreamOptions", 11usize, |this| ((**(this as *const *const IDataReader_Vtbl)).InputStreamOptions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputStreamOptions(this: *mut core::ffi::c_void, value: InputStreamOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "SetInputStreamOptions", 12usize, |this| ((**(this as *const *const IDataReader_Vtbl)).SetInputStreamOptions)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadByte(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadByte", 13usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadByte)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadBytes(this: *mut core::ffi::c_void, value_array_size: u32, value: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadBytes", 14usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadBytes)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadBuffer(this: *mut core::ffi::c_void, length: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadBuffer", 15usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadBuffer)(this, length, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadBoolean(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadBoolean", 16usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadBoolean)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadGuid(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadGuid", 17usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadGuid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadInt16(this: *mut core::ffi::c_void, result__: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadInt16", 18usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadInt16)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadInt32(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadInt32", 19usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadInt32)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadInt64(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadInt64", 20usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadInt64)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadUInt16(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadUInt16", 21usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadUInt16)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadUInt32(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadUInt32", 22usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadUInt32)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadUInt64(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadUInt64", 23usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadUInt64)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadSingle(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadSingle", 24usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadSingle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadDouble(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadDouble", 25usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadDouble)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadString(this: *mut core::ffi::c_void, codeunitcount: u32, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadString", 26usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadString)(this, codeunitcount, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadDateTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadDateTime", 27usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadDateTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadTimeSpan(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "ReadTimeSpan", 28usize, |this| ((**(this as *const *const IDataReader_Vtbl)).ReadTimeSpan)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadAsync(this: *mut core::ffi::c_void, count: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "LoadAsync", 29usize, |this| ((**(this as *const *const IDataReader_Vtbl)).LoadAsync)(this, count, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetachBuffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "DetachBuffer", 30usize, |this| ((**(this as *const *const IDataReader_Vtbl)).DetachBuffer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetachStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReader", "DetachStream", 31usize, |this| ((**(this as *const *const IDataReader_Vtbl)).DetachStream)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UnconsumedBufferLength,
+            UnicodeEncoding,
+            SetUnicodeEncoding,
+            ByteOrder,
+            SetByteOrder,
+            InputStreamOptions,
+            SetInputStreamOptions,
+            ReadByte,
+            ReadBytes,
+            ReadBuffer,
+            ReadBoolean,
+            ReadGuid,
+            ReadInt16,
+            ReadInt32,
+            ReadInt64,
+            ReadUInt16,
+            ReadUInt32,
+            ReadUInt64,
+            ReadSingle,
+            ReadDouble,
+            ReadString,
+            ReadDateTime,
+            ReadTimeSpan,
+            LoadAsync,
+            DetachBuffer,
+            DetachStream,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataReaderFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDataReader(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReaderFactory", "CreateDataReader", 6usize, |this| ((**(this as *const *const IDataReaderFactory_Vtbl)).CreateDataReader)(this, inputstream, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateDataReader }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataReaderFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataReaderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromBuffer(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataReaderStatics", "FromBuffer", 6usize, |this| ((**(this as *const *const IDataReaderStatics_Vtbl)).FromBuffer)(this, buffer, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataReaderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataWriter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UnstoredBufferLength(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "UnstoredBufferLength", 6usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).UnstoredBufferLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnicodeEncoding(this: *mut core::ffi::c_void, result__: *mut UnicodeEncoding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "UnicodeEncoding", 7usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).UnicodeEncoding)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnicodeEncoding(this: *mut core::ffi::c_void, value: UnicodeEncoding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "SetUnicodeEncoding", 8usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).SetUnicodeEncoding)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ByteOrder(this: *mut core::ffi::c_void, result__: *mut ByteOrder) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "ByteOrder", 9usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).ByteOrder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetByteOrder(this: *mut core::ffi::c_void, value: ByteOrder) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "SetByteOrder", 10usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).SetByteOrder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteByte(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteByte", 11usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteByte)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteBytes(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteBytes", 12usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteBytes)(this, value_array_size, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteBuffer(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteBuffer", 13usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteBuffer)(this, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteBufferRange(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, start: u32, count: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteBufferRange", 14usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteBufferRange)(this, buffer, start, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteBoolean(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteBoolean", 15usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteBoolean)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteGuid(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteGuid", 16usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteGuid)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteInt16(this: *mut core::ffi::c_void, value: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteInt16", 17usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteInt16)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteInt32(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteInt32", 18usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteInt32)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteInt64(this: *mut core::ffi::c_void, value: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteInt64", 19usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteInt64)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteUInt16(this: *mut core::ffi::c_void, value: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteUInt16", 20usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteUInt16)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteUInt32(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteUInt32", 21usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteUInt32)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteUInt64(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteUInt64", 22usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteUInt64)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteSingle(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteSingle", 23usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteSingle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteDouble(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteDouble", 24usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteDouble)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteDateTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteDateTime", 25usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteDateTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteTimeSpan(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteTimeSpan", 26usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteTimeSpan)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteString(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "WriteString", 27usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).WriteString)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MeasureString(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "MeasureString", 28usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).MeasureString)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StoreAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "StoreAsync", 29usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).StoreAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "FlushAsync", 30usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).FlushAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetachBuffer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "DetachBuffer", 31usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).DetachBuffer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetachStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriter", "DetachStream", 32usize, |this| ((**(this as *const *const IDataWriter_Vtbl)).DetachStream)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UnstoredBufferLength,
+            UnicodeEncoding,
+            SetUnicodeEncoding,
+            ByteOrder,
+            SetByteOrder,
+            WriteByte,
+            WriteBytes,
+            WriteBuffer,
+            WriteBufferRange,
+            WriteBoolean,
+            WriteGuid,
+            WriteInt16,
+            WriteInt32,
+            WriteInt64,
+            WriteUInt16,
+            WriteUInt32,
+            WriteUInt64,
+            WriteSingle,
+            WriteDouble,
+            WriteDateTime,
+            WriteTimeSpan,
+            WriteString,
+            MeasureString,
+            StoreAsync,
+            FlushAsync,
+            DetachBuffer,
+            DetachStream,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataWriter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataWriterFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDataWriter(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataWriterFactory", "CreateDataWriter", 6usize, |this| ((**(this as *const *const IDataWriterFactory_Vtbl)).CreateDataWriter)(this, outputstream, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateDataWriter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataWriterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IFileRandomAccessStreamStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenAsync(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::HSTRING>, accessmode: super::FileAccessMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileRandomAccessStreamStatics", "OpenAsync", 6usize, |this| ((**(this as *const *const IFileRandomAccessStreamStatics_Vtbl)).OpenAsync)(this, filepath, accessmode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenWithOptionsAsync(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::HSTRING>, accessmode: super::FileAccessMode, sharingoptions: super::StorageOpenOptions, opendisposition: FileOpenDisposition, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileRandomAccessStreamStatics", "OpenWithOptionsAsync", 7usize, |this| ((**(this as *const *const IFileRandomAccessStreamStatics_Vtbl)).OpenWithOptionsAsync)(this, filepath, accessmode, sharingoptions, opendisposition, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenTransactedWriteAsync(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileRandomAccessStreamStatics", "OpenTransactedWriteAsync", 8usize, |this| ((**(this as *const *const IFileRandomAccessStreamStatics_Vtbl)).OpenTransactedWriteAsync)(this, filepath, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenTransactedWriteWithOptionsAsync(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::HSTRING>, openoptions: super::StorageOpenOptions, opendisposition: FileOpenDisposition, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileRandomAccessStreamStatics", "OpenTransactedWriteWithOptionsAsync", 9usize, |this| ((**(this as *const *const IFileRandomAccessStreamStatics_Vtbl)).OpenTransactedWriteWithOptionsAsync)(this, filepath, openoptions, opendisposition, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn OpenForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::HSTRING>, accessmode: super::FileAccessMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileRandomAccessStreamStatics", "OpenForUserAsync", 10usize, |this| ((**(this as *const *const IFileRandomAccessStreamStatics_Vtbl)).OpenForUserAsync)(this, user, filepath, accessmode, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn OpenForUserWithOptionsAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::HSTRING>, accessmode: super::FileAccessMode, sharingoptions: super::StorageOpenOptions, opendisposition: FileOpenDisposition, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileRandomAccessStreamStatics", "OpenForUserWithOptionsAsync", 11usize, |this| ((**(this as *const *const IFileRandomAccessStreamStatics_Vtbl)).OpenForUserWithOptionsAsync)(this, user, filepath, accessmode, sharingoptions, opendisposition, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn OpenTransactedWriteForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileRandomAccessStreamStatics", "OpenTransactedWriteForUserAsync", 12usize, |this| ((**(this as *const *const IFileRandomAccessStreamStatics_Vtbl)).OpenTransactedWriteForUserAsync)(this, user, filepath, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn OpenTransactedWriteForUserWithOptionsAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::HSTRING>, openoptions: super::StorageOpenOptions, opendisposition: FileOpenDisposition, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileRandomAccessStreamStatics", "OpenTransactedWriteForUserWithOptionsAsync", 13usize, |this| ((**(this as *const *const IFileRandomAccessStreamStatics_Vtbl)).OpenTransactedWriteForUserWithOptionsAsync)(this, user, filepath, openoptions, opendisposition, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OpenAsync,
+            OpenWithOptionsAsync,
+            OpenTransactedWriteAsync,
+            OpenTransactedWriteWithOptionsAsync,
+            #[cfg(feature = "System")]
+            OpenForUserAsync,
+            #[cfg(not(feature = "System"))]
+            OpenForUserAsync: 0,
+            #[cfg(feature = "System")]
+            OpenForUserWithOptionsAsync,
+            #[cfg(not(feature = "System"))]
+            OpenForUserWithOptionsAsync: 0,
+            #[cfg(feature = "System")]
+            OpenTransactedWriteForUserAsync,
+            #[cfg(not(feature = "System"))]
+            OpenTransactedWriteForUserAsync: 0,
+            #[cfg(feature = "System")]
+            OpenTransactedWriteForUserWithOptionsAsync,
+            #[cfg(not(feature = "System"))]
+            OpenTransactedWriteForUserWithOptionsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileRandomAccessStreamStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInputStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadAsync(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, count: u32, options: InputStreamOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputStream", "ReadAsync", 6usize, |this| ((**(this as *const *const IInputStream_Vtbl)).ReadAsync)(this, buffer, count, options, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReadAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputStream as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInputStreamReference_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenSequentialReadAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputStreamReference", "OpenSequentialReadAsync", 6usize, |this| ((**(this as *const *const IInputStreamReference_Vtbl)).OpenSequentialReadAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenSequentialReadAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputStreamReference as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOutputStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WriteAsync(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOutputStream", "WriteAsync", 6usize, |this| ((**(this as *const *const IOutputStream_Vtbl)).WriteAsync)(this, buffer, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOutputStream", "FlushAsync", 7usize, |this| ((**(this as *const *const IOutputStream_Vtbl)).FlushAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WriteAsync, FlushAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOutputStream as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPropertySetSerializer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, propertyset: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySetSerializer", "Serialize", 6usize, |this| ((**(this as *const *const IPropertySetSerializer_Vtbl)).Serialize)(this, propertyset, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Deserialize(this: *mut core::ffi::c_void, propertyset: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySetSerializer", "Deserialize", 7usize, |this| ((**(this as *const *const IPropertySetSerializer_Vtbl)).Deserialize)(this, propertyset, buffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Serialize,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Serialize: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Deserialize,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Deserialize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertySetSerializer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRandomAccessStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStream", "Size", 6usize, |this| ((**(this as *const *const IRandomAccessStream_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStream", "SetSize", 7usize, |this| ((**(this as *const *const IRandomAccessStream_Vtbl)).SetSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputStreamAt(this: *mut core::ffi::c_void, position: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStream", "GetInputStreamAt", 8usize, |this| ((**(this as *const *const IRandomAccessStream_Vtbl)).GetInputStreamAt)(this, position, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputStreamAt(this: *mut core::ffi::c_void, position: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStream", "GetOutputStreamAt", 9usize, |this| ((**(this as *const *const IRandomAccessStream_Vtbl)).GetOutputStreamAt)(this, position, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStream", "Position", 10usize, |this| ((**(this as *const *const IRandomAccessStream_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, position: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStream", "Seek", 11usize, |this| ((**(this as *const *const IRandomAccessStream_Vtbl)).Seek)(this, position), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloneStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStream", "CloneStream", 12usize, |this| ((**(this as *const *const IRandomAccessStream_Vtbl)).CloneStream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanRead(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStream", "CanRead", 13usize, |this| ((**(this as *const *const IRandomAccessStream_Vtbl)).CanRead)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanWrite(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStream", "CanWrite", 14usize, |this| ((**(this as *const *const IRandomAccessStream_Vtbl)).CanWrite)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Size,
+            SetSize,
+            GetInputStreamAt,
+            GetOutputStreamAt,
+            Position,
+            Seek,
+            CloneStream,
+            CanRead,
+            CanWrite,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRandomAccessStream as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRandomAccessStreamReference_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenReadAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStreamReference", "OpenReadAsync", 6usize, |this| ((**(this as *const *const IRandomAccessStreamReference_Vtbl)).OpenReadAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenReadAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRandomAccessStreamReference as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRandomAccessStreamReferenceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromFile(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStreamReferenceStatics", "CreateFromFile", 6usize, |this| ((**(this as *const *const IRandomAccessStreamReferenceStatics_Vtbl)).CreateFromFile)(this, file, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromUri(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStreamReferenceStatics", "CreateFromUri", 7usize, |this| ((**(this as *const *const IRandomAccessStreamReferenceStatics_Vtbl)).CreateFromUri)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromStream(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStreamReferenceStatics", "CreateFromStream", 8usize, |this| ((**(this as *const *const IRandomAccessStreamReferenceStatics_Vtbl)).CreateFromStream)(this, stream, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromFile, CreateFromUri, CreateFromStream }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRandomAccessStreamReferenceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRandomAccessStreamStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CopyAsync(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, destination: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStreamStatics", "CopyAsync", 6usize, |this| ((**(this as *const *const IRandomAccessStreamStatics_Vtbl)).CopyAsync)(this, source, destination, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopySizeAsync(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, destination: *mut core::ffi::c_void, bytestocopy: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStreamStatics", "CopySizeAsync", 7usize, |this| ((**(this as *const *const IRandomAccessStreamStatics_Vtbl)).CopySizeAsync)(this, source, destination, bytestocopy, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyAndCloseAsync(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, destination: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStreamStatics", "CopyAndCloseAsync", 8usize, |this| ((**(this as *const *const IRandomAccessStreamStatics_Vtbl)).CopyAndCloseAsync)(this, source, destination, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CopyAsync, CopySizeAsync, CopyAndCloseAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRandomAccessStreamStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRandomAccessStreamWithContentType_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRandomAccessStreamWithContentType as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
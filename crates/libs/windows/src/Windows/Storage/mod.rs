@@ -5037,6 +5037,7 @@ impl ApplicationDataCreateDisposition {
 impl windows_core::TypeKind for ApplicationDataCreateDisposition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ApplicationDataCreateDisposition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ApplicationDataCreateDisposition").field(&self.0).finish()
@@ -5058,6 +5059,7 @@ impl ApplicationDataLocality {
 impl windows_core::TypeKind for ApplicationDataLocality {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ApplicationDataLocality {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ApplicationDataLocality").field(&self.0).finish()
@@ -5078,6 +5080,7 @@ impl CreationCollisionOption {
 impl windows_core::TypeKind for CreationCollisionOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CreationCollisionOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CreationCollisionOption").field(&self.0).finish()
@@ -5096,6 +5099,7 @@ impl FileAccessMode {
 impl windows_core::TypeKind for FileAccessMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FileAccessMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FileAccessMode").field(&self.0).finish()
@@ -5118,6 +5122,7 @@ impl FileAttributes {
 impl windows_core::TypeKind for FileAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FileAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FileAttributes").field(&self.0).finish()
@@ -5184,6 +5189,7 @@ impl KnownFolderId {
 impl windows_core::TypeKind for KnownFolderId {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KnownFolderId {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KnownFolderId").field(&self.0).finish()
@@ -5206,6 +5212,7 @@ impl KnownFoldersAccessStatus {
 impl windows_core::TypeKind for KnownFoldersAccessStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KnownFoldersAccessStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KnownFoldersAccessStatus").field(&self.0).finish()
@@ -5226,6 +5233,7 @@ impl KnownLibraryId {
 impl windows_core::TypeKind for KnownLibraryId {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KnownLibraryId {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KnownLibraryId").field(&self.0).finish()
@@ -5245,6 +5253,7 @@ impl NameCollisionOption {
 impl windows_core::TypeKind for NameCollisionOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NameCollisionOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NameCollisionOption").field(&self.0).finish()
@@ -5263,6 +5272,7 @@ impl StorageDeleteOption {
 impl windows_core::TypeKind for StorageDeleteOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorageDeleteOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorageDeleteOption").field(&self.0).finish()
@@ -5282,6 +5292,7 @@ impl StorageItemTypes {
 impl windows_core::TypeKind for StorageItemTypes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorageItemTypes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorageItemTypes").field(&self.0).finish()
@@ -5341,6 +5352,7 @@ impl StorageLibraryChangeType {
 impl windows_core::TypeKind for StorageLibraryChangeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorageLibraryChangeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorageLibraryChangeType").field(&self.0).finish()
@@ -5360,6 +5372,7 @@ impl StorageOpenOptions {
 impl windows_core::TypeKind for StorageOpenOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StorageOpenOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StorageOpenOptions").field(&self.0).finish()
@@ -5412,6 +5425,7 @@ impl StreamedFileFailureMode {
 impl windows_core::TypeKind for StreamedFileFailureMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StreamedFileFailureMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StreamedFileFailureMode").field(&self.0).finish()
@@ -5552,3 +5566,5 @@ pub struct StreamedFileDataRequestedHandler_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1933 @@
+impl windows_core::imp::ProxyVtable for IAppDataPaths_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cookies(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDataPaths", "Cookies", 6usize, |this| ((**(this as *const *const IAppDataPaths_Vtbl)).Cookies)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Desktop(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDataPaths", "Desktop", 7usize, |this| ((**(this as *const *const IAppDataPaths_Vtbl)).Desktop)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Documents(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDataPaths", "Documents", 8usize, |this| ((**(this as *const *const IAppDataPaths_Vtbl)).Documents)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Favorites(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDataPaths", "Favorites", 9usize, |this| ((**(this as *const *const IAppDataPaths_Vtbl)).Favorites)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn History(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDataPaths", "History", 10usize, |this| ((**(this as *const *const IAppDataPaths_Vtbl)).History)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InternetCache(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDataPaths", "InternetCache", 11usize, |this| ((**(this as *const *const IAppDataPaths_Vtbl)).InternetCache)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalAppData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDataPaths", "LocalAppData", 12usize, |this| ((**(this as *const *const IAppDataPaths_Vtbl)).LocalAppData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProgramData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDataPaths", "ProgramData", 13usize, |this| ((**(this as *const *const IAppDataPaths_Vtbl)).ProgramData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoamingAppData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDataPaths", "RoamingAppData", 14usize, |this| ((**(this as *const *const IAppDataPaths_Vtbl)).RoamingAppData)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Cookies,
+            Desktop,
+            Documents,
+            Favorites,
+            History,
+            InternetCache,
+            LocalAppData,
+            ProgramData,
+            RoamingAppData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppDataPaths as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IAppDataPathsStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDataPathsStatics", "GetForUser", 6usize, |this| ((**(this as *const *const IAppDataPathsStatics_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDataPathsStatics", "GetDefault", 7usize, |this| ((**(this as *const *const IAppDataPathsStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+            GetDefault,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppDataPathsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData", "Version", 6usize, |this| ((**(this as *const *const IApplicationData_Vtbl)).Version)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVersionAsync(this: *mut core::ffi::c_void, desiredversion: u32, handler: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData", "SetVersionAsync", 7usize, |this| ((**(this as *const *const IApplicationData_Vtbl)).SetVersionAsync)(this, desiredversion, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearAllAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData", "ClearAllAsync", 8usize, |this| ((**(this as *const *const IApplicationData_Vtbl)).ClearAllAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearAsync(this: *mut core::ffi::c_void, locality: ApplicationDataLocality, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData", "ClearAsync", 9usize, |this| ((**(this as *const *const IApplicationData_Vtbl)).ClearAsync)(this, locality, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData", "LocalSettings", 10usize, |this| ((**(this as *const *const IApplicationData_Vtbl)).LocalSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoamingSettings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData", "RoamingSettings", 11usize, |this| ((**(this as *const *const IApplicationData_Vtbl)).RoamingSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalFolder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData", "LocalFolder", 12usize, |this| ((**(this as *const *const IApplicationData_Vtbl)).LocalFolder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoamingFolder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData", "RoamingFolder", 13usize, |this| ((**(this as *const *const IApplicationData_Vtbl)).RoamingFolder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TemporaryFolder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData", "TemporaryFolder", 14usize, |this| ((**(this as *const *const IApplicationData_Vtbl)).TemporaryFolder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData", "DataChanged", 15usize, |this| ((**(this as *const *const IApplicationData_Vtbl)).DataChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDataChanged(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData", "RemoveDataChanged", 16usize, |this| ((**(this as *const *const IApplicationData_Vtbl)).RemoveDataChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalDataChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData", "SignalDataChanged", 17usize, |this| ((**(this as *const *const IApplicationData_Vtbl)).SignalDataChanged)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoamingStorageQuota(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData", "RoamingStorageQuota", 18usize, |this| ((**(this as *const *const IApplicationData_Vtbl)).RoamingStorageQuota)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Version,
+            SetVersionAsync,
+            ClearAllAsync,
+            ClearAsync,
+            LocalSettings,
+            RoamingSettings,
+            LocalFolder,
+            RoamingFolder,
+            TemporaryFolder,
+            DataChanged,
+            RemoveDataChanged,
+            SignalDataChanged,
+            RoamingStorageQuota,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationData2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LocalCacheFolder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData2", "LocalCacheFolder", 6usize, |this| ((**(this as *const *const IApplicationData2_Vtbl)).LocalCacheFolder)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LocalCacheFolder }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationData2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationData3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPublisherCacheFolder(this: *mut core::ffi::c_void, foldername: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData3", "GetPublisherCacheFolder", 6usize, |this| ((**(this as *const *const IApplicationData3_Vtbl)).GetPublisherCacheFolder)(this, foldername, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearPublisherCacheFolderAsync(this: *mut core::ffi::c_void, foldername: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData3", "ClearPublisherCacheFolderAsync", 7usize, |this| ((**(this as *const *const IApplicationData3_Vtbl)).ClearPublisherCacheFolderAsync)(this, foldername, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SharedLocalFolder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationData3", "SharedLocalFolder", 8usize, |this| ((**(this as *const *const IApplicationData3_Vtbl)).SharedLocalFolder)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPublisherCacheFolder,
+            ClearPublisherCacheFolderAsync,
+            SharedLocalFolder,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationData3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IApplicationDataContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDataContainer", "Name", 6usize, |this| ((**(this as *const *const IApplicationDataContainer_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Locality(this: *mut core::ffi::c_void, result__: *mut ApplicationDataLocality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDataContainer", "Locality", 7usize, |this| ((**(this as *const *const IApplicationDataContainer_Vtbl)).Locality)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Values(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDataContainer", "Values", 8usize, |this| ((**(this as *const *const IApplicationDataContainer_Vtbl)).Values)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Containers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDataContainer", "Containers", 9usize, |this| ((**(this as *const *const IApplicationDataContainer_Vtbl)).Containers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateContainer(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, disposition: ApplicationDataCreateDisposition, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDataContainer", "CreateContainer", 10usize, |this| ((**(this as *const *const IApplicationDataContainer_Vtbl)).CreateContainer)(this, name, disposition, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteContainer(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDataContainer", "DeleteContainer", 11usize, |this| ((**(this as *const *const IApplicationDataContainer_Vtbl)).DeleteContainer)(this, name), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Locality,
+            #[cfg(feature = "Foundation_Collections")]
+            Values,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Values: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Containers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Containers: 0,
+            CreateContainer,
+            DeleteContainer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationDataContainer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationDataStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDataStatics", "Current", 6usize, |this| ((**(this as *const *const IApplicationDataStatics_Vtbl)).Current)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Current }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationDataStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IApplicationDataStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDataStatics2", "GetForUserAsync", 6usize, |this| ((**(this as *const *const IApplicationDataStatics2_Vtbl)).GetForUserAsync)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetForUserAsync,
+            #[cfg(not(feature = "System"))]
+            GetForUserAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationDataStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Provider")]
+impl windows_core::imp::ProxyVtable for ICachedFileManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeferUpdates(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileManagerStatics", "DeferUpdates", 6usize, |this| ((**(this as *const *const ICachedFileManagerStatics_Vtbl)).DeferUpdates)(this, file), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Provider")]
+        unsafe extern "system" fn CompleteUpdatesAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICachedFileManagerStatics", "CompleteUpdatesAsync", 7usize, |this| ((**(this as *const *const ICachedFileManagerStatics_Vtbl)).CompleteUpdatesAsync)(this, file, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeferUpdates,
+            #[cfg(feature = "Storage_Provider")]
+            CompleteUpdatesAsync,
+            #[cfg(not(feature = "Storage_Provider"))]
+            CompleteUpdatesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICachedFileManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDownloadsFolderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFileAsync(this: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadsFolderStatics", "CreateFileAsync", 6usize, |this| ((**(this as *const *const IDownloadsFolderStatics_Vtbl)).CreateFileAsync)(this, desiredname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFolderAsync(this: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadsFolderStatics", "CreateFolderAsync", 7usize, |this| ((**(this as *const *const IDownloadsFolderStatics_Vtbl)).CreateFolderAsync)(this, desiredname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFileWithCollisionOptionAsync(this: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, option: CreationCollisionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadsFolderStatics", "CreateFileWithCollisionOptionAsync", 8usize, |this| ((**(this as *const *const IDownloadsFolderStatics_Vtbl)).CreateFileWithCollisionOptionAsync)(this, desiredname, option, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFolderWithCollisionOptionAsync(this: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, option: CreationCollisionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadsFolderStatics", "CreateFolderWithCollisionOptionAsync", 9usize, |this| ((**(this as *const *const IDownloadsFolderStatics_Vtbl)).CreateFolderWithCollisionOptionAsync)(this, desiredname, option, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFileAsync,
+            CreateFolderAsync,
+            CreateFileWithCollisionOptionAsync,
+            CreateFolderWithCollisionOptionAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadsFolderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IDownloadsFolderStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn CreateFileForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadsFolderStatics2", "CreateFileForUserAsync", 6usize, |this| ((**(this as *const *const IDownloadsFolderStatics2_Vtbl)).CreateFileForUserAsync)(this, user, desiredname, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn CreateFolderForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadsFolderStatics2", "CreateFolderForUserAsync", 7usize, |this| ((**(this as *const *const IDownloadsFolderStatics2_Vtbl)).CreateFolderForUserAsync)(this, user, desiredname, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn CreateFileForUserWithCollisionOptionAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, option: CreationCollisionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadsFolderStatics2", "CreateFileForUserWithCollisionOptionAsync", 8usize, |this| ((**(this as *const *const IDownloadsFolderStatics2_Vtbl)).CreateFileForUserWithCollisionOptionAsync)(this, user, desiredname, option, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn CreateFolderForUserWithCollisionOptionAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, option: CreationCollisionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadsFolderStatics2", "CreateFolderForUserWithCollisionOptionAsync", 9usize, |this| ((**(this as *const *const IDownloadsFolderStatics2_Vtbl)).CreateFolderForUserWithCollisionOptionAsync)(this, user, desiredname, option, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            CreateFileForUserAsync,
+            #[cfg(not(feature = "System"))]
+            CreateFileForUserAsync: 0,
+            #[cfg(feature = "System")]
+            CreateFolderForUserAsync,
+            #[cfg(not(feature = "System"))]
+            CreateFolderForUserAsync: 0,
+            #[cfg(feature = "System")]
+            CreateFileForUserWithCollisionOptionAsync,
+            #[cfg(not(feature = "System"))]
+            CreateFileForUserWithCollisionOptionAsync: 0,
+            #[cfg(feature = "System")]
+            CreateFolderForUserWithCollisionOptionAsync,
+            #[cfg(not(feature = "System"))]
+            CreateFolderForUserWithCollisionOptionAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadsFolderStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IFileIOStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadTextAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIOStatics", "ReadTextAsync", 6usize, |this| ((**(this as *const *const IFileIOStatics_Vtbl)).ReadTextAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ReadTextWithEncodingAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, encoding: Streams::UnicodeEncoding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIOStatics", "ReadTextWithEncodingAsync", 7usize, |this| ((**(this as *const *const IFileIOStatics_Vtbl)).ReadTextWithEncodingAsync)(this, file, encoding, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteTextAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, contents: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIOStatics", "WriteTextAsync", 8usize, |this| ((**(this as *const *const IFileIOStatics_Vtbl)).WriteTextAsync)(this, file, contents, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn WriteTextWithEncodingAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, contents: core::mem::MaybeUninit<windows_core::HSTRING>, encoding: Streams::UnicodeEncoding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIOStatics", "WriteTextWithEncodingAsync", 9usize, |this| ((**(this as *const *const IFileIOStatics_Vtbl)).WriteTextWithEncodingAsync)(this, file, contents, encoding, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendTextAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, contents: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIOStatics", "AppendTextAsync", 10usize, |this| ((**(this as *const *const IFileIOStatics_Vtbl)).AppendTextAsync)(this, file, contents, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AppendTextWithEncodingAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, contents: core::mem::MaybeUninit<windows_core::HSTRING>, encoding: Streams::UnicodeEncoding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIOStatics", "AppendTextWithEncodingAsync", 11usize, |this| ((**(this as *const *const IFileIOStatics_Vtbl)).AppendTextWithEncodingAsync)(this, file, contents, encoding, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadLinesAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIOStatics", "ReadLinesAsync", 12usize, |this| ((**(this as *const *const IFileIOStatics_Vtbl)).ReadLinesAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn ReadLinesWithEncodingAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, encoding: Streams::UnicodeEncoding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIOStatics", "ReadLinesWithEncodingAsync", 13usize, |this| ((**(this as *const *const IFileIOStatics_Vtbl)).ReadLinesWithEncodingAsync)(this, file, encoding, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn WriteLinesAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, lines: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIOStatics", "WriteLinesAsync", 14usize, |this| ((**(this as *const *const IFileIOStatics_Vtbl)).WriteLinesAsync)(this, file, lines, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn WriteLinesWithEncodingAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, lines: *mut core::ffi::c_void, encoding: Streams::UnicodeEncoding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIOStatics", "WriteLinesWithEncodingAsync", 15usize, |this| ((**(this as *const *const IFileIOStatics_Vtbl)).WriteLinesWithEncodingAsync)(this, file, lines, encoding, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AppendLinesAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, lines: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIOStatics", "AppendLinesAsync", 16usize, |this| ((**(this as *const *const IFileIOStatics_Vtbl)).AppendLinesAsync)(this, file, lines, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn AppendLinesWithEncodingAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, lines: *mut core::ffi::c_void, encoding: Streams::UnicodeEncoding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIOStatics", "AppendLinesWithEncodingAsync", 17usize, |this| ((**(this as *const *const IFileIOStatics_Vtbl)).AppendLinesWithEncodingAsync)(this, file, lines, encoding, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ReadBufferAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIOStatics", "ReadBufferAsync", 18usize, |this| ((**(this as *const *const IFileIOStatics_Vtbl)).ReadBufferAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn WriteBufferAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIOStatics", "WriteBufferAsync", 19usize, |this| ((**(this as *const *const IFileIOStatics_Vtbl)).WriteBufferAsync)(this, file, buffer, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteBytesAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, buffer_array_size: u32, buffer: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIOStatics", "WriteBytesAsync", 20usize, |this| ((**(this as *const *const IFileIOStatics_Vtbl)).WriteBytesAsync)(this, file, buffer_array_size, buffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReadTextAsync,
+            #[cfg(feature = "Storage_Streams")]
+            ReadTextWithEncodingAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ReadTextWithEncodingAsync: 0,
+            WriteTextAsync,
+            #[cfg(feature = "Storage_Streams")]
+            WriteTextWithEncodingAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            WriteTextWithEncodingAsync: 0,
+            AppendTextAsync,
+            #[cfg(feature = "Storage_Streams")]
+            AppendTextWithEncodingAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AppendTextWithEncodingAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadLinesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadLinesAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            ReadLinesWithEncodingAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            ReadLinesWithEncodingAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            WriteLinesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            WriteLinesAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            WriteLinesWithEncodingAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            WriteLinesWithEncodingAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            AppendLinesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AppendLinesAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            AppendLinesWithEncodingAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            AppendLinesWithEncodingAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ReadBufferAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ReadBufferAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            WriteBufferAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            WriteBufferAsync: 0,
+            WriteBytesAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileIOStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKnownFoldersCameraRollStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CameraRoll(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersCameraRollStatics", "CameraRoll", 6usize, |this| ((**(this as *const *const IKnownFoldersCameraRollStatics_Vtbl)).CameraRoll)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CameraRoll }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownFoldersCameraRollStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKnownFoldersPlaylistsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Playlists(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersPlaylistsStatics", "Playlists", 6usize, |this| ((**(this as *const *const IKnownFoldersPlaylistsStatics_Vtbl)).Playlists)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Playlists }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownFoldersPlaylistsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKnownFoldersSavedPicturesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SavedPictures(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersSavedPicturesStatics", "SavedPictures", 6usize, |this| ((**(this as *const *const IKnownFoldersSavedPicturesStatics_Vtbl)).SavedPictures)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SavedPictures }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownFoldersSavedPicturesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKnownFoldersStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MusicLibrary(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersStatics", "MusicLibrary", 6usize, |this| ((**(this as *const *const IKnownFoldersStatics_Vtbl)).MusicLibrary)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PicturesLibrary(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersStatics", "PicturesLibrary", 7usize, |this| ((**(this as *const *const IKnownFoldersStatics_Vtbl)).PicturesLibrary)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideosLibrary(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersStatics", "VideosLibrary", 8usize, |this| ((**(this as *const *const IKnownFoldersStatics_Vtbl)).VideosLibrary)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentsLibrary(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersStatics", "DocumentsLibrary", 9usize, |this| ((**(this as *const *const IKnownFoldersStatics_Vtbl)).DocumentsLibrary)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HomeGroup(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersStatics", "HomeGroup", 10usize, |this| ((**(this as *const *const IKnownFoldersStatics_Vtbl)).HomeGroup)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovableDevices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersStatics", "RemovableDevices", 11usize, |this| ((**(this as *const *const IKnownFoldersStatics_Vtbl)).RemovableDevices)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaServerDevices(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersStatics", "MediaServerDevices", 12usize, |this| ((**(this as *const *const IKnownFoldersStatics_Vtbl)).MediaServerDevices)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MusicLibrary,
+            PicturesLibrary,
+            VideosLibrary,
+            DocumentsLibrary,
+            HomeGroup,
+            RemovableDevices,
+            MediaServerDevices,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownFoldersStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKnownFoldersStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Objects3D(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersStatics2", "Objects3D", 6usize, |this| ((**(this as *const *const IKnownFoldersStatics2_Vtbl)).Objects3D)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppCaptures(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersStatics2", "AppCaptures", 7usize, |this| ((**(this as *const *const IKnownFoldersStatics2_Vtbl)).AppCaptures)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordedCalls(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersStatics2", "RecordedCalls", 8usize, |this| ((**(this as *const *const IKnownFoldersStatics2_Vtbl)).RecordedCalls)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Objects3D, AppCaptures, RecordedCalls }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownFoldersStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IKnownFoldersStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetFolderForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, folderid: KnownFolderId, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersStatics3", "GetFolderForUserAsync", 6usize, |this| ((**(this as *const *const IKnownFoldersStatics3_Vtbl)).GetFolderForUserAsync)(this, user, folderid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetFolderForUserAsync,
+            #[cfg(not(feature = "System"))]
+            GetFolderForUserAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownFoldersStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IKnownFoldersStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, folderid: KnownFolderId, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersStatics4", "RequestAccessAsync", 6usize, |this| ((**(this as *const *const IKnownFoldersStatics4_Vtbl)).RequestAccessAsync)(this, folderid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn RequestAccessForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, folderid: KnownFolderId, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersStatics4", "RequestAccessForUserAsync", 7usize, |this| ((**(this as *const *const IKnownFoldersStatics4_Vtbl)).RequestAccessForUserAsync)(this, user, folderid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFolderAsync(this: *mut core::ffi::c_void, folderid: KnownFolderId, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownFoldersStatics4", "GetFolderAsync", 8usize, |this| ((**(this as *const *const IKnownFoldersStatics4_Vtbl)).GetFolderAsync)(this, folderid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestAccessAsync,
+            #[cfg(feature = "System")]
+            RequestAccessForUserAsync,
+            #[cfg(not(feature = "System"))]
+            RequestAccessForUserAsync: 0,
+            GetFolderAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownFoldersStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IPathIOStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadTextAsync(this: *mut core::ffi::c_void, absolutepath: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathIOStatics", "ReadTextAsync", 6usize, |this| ((**(this as *const *const IPathIOStatics_Vtbl)).ReadTextAsync)(this, absolutepath, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ReadTextWithEncodingAsync(this: *mut core::ffi::c_void, absolutepath: core::mem::MaybeUninit<windows_core::HSTRING>, encoding: Streams::UnicodeEncoding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathIOStatics", "ReadTextWithEncodingAsync", 7usize, |this| ((**(this as *const *const IPathIOStatics_Vtbl)).ReadTextWithEncodingAsync)(this, absolutepath, encoding, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteTextAsync(this: *mut core::ffi::c_void, absolutepath: core::mem::MaybeUninit<windows_core::HSTRING>, contents: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathIOStatics", "WriteTextAsync", 8usize, |this| ((**(this as *const *const IPathIOStatics_Vtbl)).WriteTextAsync)(this, absolutepath, contents, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn WriteTextWithEncodingAsync(this: *mut core::ffi::c_void, absolutepath: core::mem::MaybeUninit<windows_core::HSTRING>, contents: core::mem::MaybeUninit<windows_core::HSTRING>, encoding: Streams::UnicodeEncoding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathIOStatics", "WriteTextWithEncodingAsync", 9usize, |this| ((**(this as *const *const IPathIOStatics_Vtbl)).WriteTextWithEncodingAsync)(this, absolutepath, contents, encoding, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendTextAsync(this: *mut core::ffi::c_void, absolutepath: core::mem::MaybeUninit<windows_core::HSTRING>, contents: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathIOStatics", "AppendTextAsync", 10usize, |this| ((**(this as *const *const IPathIOStatics_Vtbl)).AppendTextAsync)(this, absolutepath, contents, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn AppendTextWithEncodingAsync(this: *mut core::ffi::c_void, absolutepath: core::mem::MaybeUninit<windows_core::HSTRING>, contents: core::mem::MaybeUninit<windows_core::HSTRING>, encoding: Streams::UnicodeEncoding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathIOStatics", "AppendTextWithEncodingAsync", 11usize, |this| ((**(this as *const *const IPathIOStatics_Vtbl)).AppendTextWithEncodingAsync)(this, absolutepath, contents, encoding, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadLinesAsync(this: *mut core::ffi::c_void, absolutepath: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathIOStatics", "ReadLinesAsync", 12usize, |this| ((**(this as *const *const IPathIOStatics_Vtbl)).ReadLinesAsync)(this, absolutepath, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn ReadLinesWithEncodingAsync(this: *mut core::ffi::c_void, absolutepath: core::mem::MaybeUninit<windows_core::HSTRING>, encoding: Streams::UnicodeEncoding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathIOStatics", "ReadLinesWithEncodingAsync", 13usize, |this| ((**(this as *const *const IPathIOStatics_Vtbl)).ReadLinesWithEncodingAsync)(this, absolutepath, encoding, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn WriteLinesAsync(this: *mut core::ffi::c_void, absolutepath: core::mem::MaybeUninit<windows_core::HSTRING>, lines: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathIOStatics", "WriteLinesAsync", 14usize, |this| ((**(this as *const *const IPathIOStatics_Vtbl)).WriteLinesAsync)(this, absolutepath, lines, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn WriteLinesWithEncodingAsync(this: *mut core::ffi::c_void, absolutepath: core::mem::MaybeUninit<windows_core::HSTRING>, lines: *mut core::ffi::c_void, encoding: Streams::UnicodeEncoding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathIOStatics", "WriteLinesWithEncodingAsync", 15usize, |this| ((**(this as *const *const IPathIOStatics_Vtbl)).WriteLinesWithEncodingAsync)(this, absolutepath, lines, encoding, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AppendLinesAsync(this: *mut core::ffi::c_void, absolutepath: core::mem::MaybeUninit<windows_core::HSTRING>, lines: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathIOStatics", "AppendLinesAsync", 16usize, |this| ((**(this as *const *const IPathIOStatics_Vtbl)).AppendLinesAsync)(this, absolutepath, lines, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+        unsafe extern "system" fn AppendLinesWithEncodingAsync(this: *mut core::ffi::c_void, absolutepath: core::mem::MaybeUninit<windows_core::HSTRING>, lines: *mut core::ffi::c_void, encoding: Streams::UnicodeEncoding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathIOStatics", "AppendLinesWithEncodingAsync", 17usize, |this| ((**(this as *const *const IPathIOStatics_Vtbl)).AppendLinesWithEncodingAsync)(this, absolutepath, lines, encoding, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ReadBufferAsync(this: *mut core::ffi::c_void, absolutepath: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathIOStatics", "ReadBufferAsync", 18usize, |this| ((**(this as *const *const IPathIOStatics_Vtbl)).ReadBufferAsync)(this, absolutepath, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn WriteBufferAsync(this: *mut core::ffi::c_void, absolutepath: core::mem::MaybeUninit<windows_core::HSTRING>, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathIOStatics", "WriteBufferAsync", 19usize, |this| ((**(this as *const *const IPathIOStatics_Vtbl)).WriteBufferAsync)(this, absolutepath, buffer, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteBytesAsync(this: *mut core::ffi::c_void, absolutepath: core::mem::MaybeUninit<windows_core::HSTRING>, buffer_array_size: u32, buffer: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathIOStatics", "WriteBytesAsync", 20usize, |this| ((**(this as *const *const IPathIOStatics_Vtbl)).WriteBytesAsync)(this, absolutepath, buffer_array_size, buffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReadTextAsync,
+            #[cfg(feature = "Storage_Streams")]
+            ReadTextWithEncodingAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ReadTextWithEncodingAsync: 0,
+            WriteTextAsync,
+            #[cfg(feature = "Storage_Streams")]
+            WriteTextWithEncodingAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            WriteTextWithEncodingAsync: 0,
+            AppendTextAsync,
+            #[cfg(feature = "Storage_Streams")]
+            AppendTextWithEncodingAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            AppendTextWithEncodingAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadLinesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadLinesAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            ReadLinesWithEncodingAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            ReadLinesWithEncodingAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            WriteLinesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            WriteLinesAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            WriteLinesWithEncodingAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            WriteLinesWithEncodingAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            AppendLinesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AppendLinesAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+            AppendLinesWithEncodingAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage_Streams")))]
+            AppendLinesWithEncodingAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ReadBufferAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ReadBufferAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            WriteBufferAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            WriteBufferAsync: 0,
+            WriteBytesAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPathIOStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISetVersionDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISetVersionDeferral", "Complete", 6usize, |this| ((**(this as *const *const ISetVersionDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISetVersionDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISetVersionRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentVersion(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISetVersionRequest", "CurrentVersion", 6usize, |this| ((**(this as *const *const ISetVersionRequest_Vtbl)).CurrentVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredVersion(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISetVersionRequest", "DesiredVersion", 7usize, |this| ((**(this as *const *const ISetVersionRequest_Vtbl)).DesiredVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISetVersionRequest", "GetDeferral", 8usize, |this| ((**(this as *const *const ISetVersionRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CurrentVersion, DesiredVersion, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISetVersionRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IStorageFile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FileType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFile", "FileType", 6usize, |this| ((**(this as *const *const IStorageFile_Vtbl)).FileType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFile", "ContentType", 7usize, |this| ((**(this as *const *const IStorageFile_Vtbl)).ContentType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn OpenAsync(this: *mut core::ffi::c_void, accessmode: FileAccessMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFile", "OpenAsync", 8usize, |this| ((**(this as *const *const IStorageFile_Vtbl)).OpenAsync)(this, accessmode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenTransactedWriteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFile", "OpenTransactedWriteAsync", 9usize, |this| ((**(this as *const *const IStorageFile_Vtbl)).OpenTransactedWriteAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyOverloadDefaultNameAndOptions(this: *mut core::ffi::c_void, destinationfolder: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFile", "CopyOverloadDefaultNameAndOptions", 10usize, |this| ((**(this as *const *const IStorageFile_Vtbl)).CopyOverloadDefaultNameAndOptions)(this, destinationfolder, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyOverloadDefaultOptions(this: *mut core::ffi::c_void, destinationfolder: *mut core::ffi::c_void, desirednewname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFile", "CopyOverloadDefaultOptions", 11usize, |this| ((**(this as *const *const IStorageFile_Vtbl)).CopyOverloadDefaultOptions)(this, destinationfolder, desirednewname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyOverload(this: *mut core::ffi::c_void, destinationfolder: *mut core::ffi::c_void, desirednewname: core::mem::MaybeUninit<windows_core::HSTRING>, option: NameCollisionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFile", "CopyOverload", 12usize, |this| ((**(this as *const *const IStorageFile_Vtbl)).CopyOverload)(this, destinationfolder, desirednewname, option, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyAndReplaceAsync(this: *mut core::ffi::c_void, filetoreplace: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFile", "CopyAndReplaceAsync", 13usize, |this| ((**(this as *const *const IStorageFile_Vtbl)).CopyAndReplaceAsync)(this, filetoreplace, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveOverloadDefaultNameAndOptions(this: *mut core::ffi::c_void, destinationfolder: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFile", "MoveOverloadDefaultNameAndOptions", 14usize, |this| ((**(this as *const *const IStorageFile_Vtbl)).MoveOverloadDefaultNameAndOptions)(this, destinationfolder, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveOverloadDefaultOptions(this: *mut core::ffi::c_void, destinationfolder: *mut core::ffi::c_void, desirednewname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFile", "MoveOverloadDefaultOptions", 15usize, |this| ((**(this as *const *const IStorageFile_Vtbl)).MoveOverloadDefaultOptions)(this, destinationfolder, desirednewname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveOverload(this: *mut core::ffi::c_void, destinationfolder: *mut core::ffi::c_void, desirednewname: core::mem::MaybeUninit<windows_core::HSTRING>, option: NameCollisionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFile", "MoveOverload", 16usize, |this| ((**(this as *const *const IStorageFile_Vtbl)).MoveOverload)(this, destinationfolder, desirednewname, option, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveAndReplaceAsync(this: *mut core::ffi::c_void, filetoreplace: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFile", "MoveAndReplaceAsync", 17usize, |this| ((**(this as *const *const IStorageFile_Vtbl)).MoveAndReplaceAsync)(this, filetoreplace, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FileType,
+            ContentType,
+            #[cfg(feature = "Storage_Streams")]
+            OpenAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            OpenAsync: 0,
+            OpenTransactedWriteAsync,
+            CopyOverloadDefaultNameAndOptions,
+            CopyOverloadDefaultOptions,
+            CopyOverload,
+            CopyAndReplaceAsync,
+            MoveOverloadDefaultNameAndOptions,
+            MoveOverloadDefaultOptions,
+            MoveOverload,
+            MoveAndReplaceAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageFile as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IStorageFile2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn OpenWithOptionsAsync(this: *mut core::ffi::c_void, accessmode: FileAccessMode, options: StorageOpenOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFile2", "OpenWithOptionsAsync", 6usize, |this| ((**(this as *const *const IStorageFile2_Vtbl)).OpenWithOptionsAsync)(this, accessmode, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenTransactedWriteWithOptionsAsync(this: *mut core::ffi::c_void, options: StorageOpenOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFile2", "OpenTransactedWriteWithOptionsAsync", 7usize, |this| ((**(this as *const *const IStorageFile2_Vtbl)).OpenTransactedWriteWithOptionsAsync)(this, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            OpenWithOptionsAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            OpenWithOptionsAsync: 0,
+            OpenTransactedWriteWithOptionsAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageFile2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageFilePropertiesWithAvailability_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAvailable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFilePropertiesWithAvailability", "IsAvailable", 6usize, |this| ((**(this as *const *const IStorageFilePropertiesWithAvailability_Vtbl)).IsAvailable)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsAvailable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageFilePropertiesWithAvailability as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IStorageFileStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFileFromPathAsync(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFileStatics", "GetFileFromPathAsync", 6usize, |this| ((**(this as *const *const IStorageFileStatics_Vtbl)).GetFileFromPathAsync)(this, path, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileFromApplicationUriAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFileStatics", "GetFileFromApplicationUriAsync", 7usize, |this| ((**(this as *const *const IStorageFileStatics_Vtbl)).GetFileFromApplicationUriAsync)(this, uri, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateStreamedFileAsync(this: *mut core::ffi::c_void, displaynamewithextension: core::mem::MaybeUninit<windows_core::HSTRING>, datarequested: *mut core::ffi::c_void, thumbnail: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFileStatics", "CreateStreamedFileAsync", 8usize, |this| ((**(this as *const *const IStorageFileStatics_Vtbl)).CreateStreamedFileAsync)(this, displaynamewithextension, datarequested, thumbnail, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ReplaceWithStreamedFileAsync(this: *mut core::ffi::c_void, filetoreplace: *mut core::ffi::c_void, datarequested: *mut core::ffi::c_void, thumbnail: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFileStatics", "ReplaceWithStreamedFileAsync", 9usize, |this| ((**(this as *const *const IStorageFileStatics_Vtbl)).ReplaceWithStreamedFileAsync)(this, filetoreplace, datarequested, thumbnail, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateStreamedFileFromUriAsync(this: *mut core::ffi::c_void, displaynamewithextension: core::mem::MaybeUninit<windows_core::HSTRING>, uri: *mut core::ffi::c_void, thumbnail: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFileStatics", "CreateStreamedFileFromUriAsync", 10usize, |this| ((**(this as *const *const IStorageFileStatics_Vtbl)).CreateStreamedFileFromUriAsync)(this, displaynamewithextension, uri, thumbnail, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ReplaceWithStreamedFileFromUriAsync(this: *mut core::ffi::c_void, filetoreplace: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, thumbnail: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFileStatics", "ReplaceWithStreamedFileFromUriAsync", 11usize, |this| ((**(this as *const *const IStorageFileStatics_Vtbl)).ReplaceWithStreamedFileFromUriAsync)(this, filetoreplace, uri, thumbnail, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFileFromPathAsync,
+            GetFileFromApplicationUriAsync,
+            #[cfg(feature = "Storage_Streams")]
+            CreateStreamedFileAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateStreamedFileAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ReplaceWithStreamedFileAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ReplaceWithStreamedFileAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateStreamedFileFromUriAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateStreamedFileFromUriAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ReplaceWithStreamedFileFromUriAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ReplaceWithStreamedFileFromUriAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageFileStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IStorageFileStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetFileFromPathForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFileStatics2", "GetFileFromPathForUserAsync", 6usize, |this| ((**(this as *const *const IStorageFileStatics2_Vtbl)).GetFileFromPathForUserAsync)(this, user, path, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetFileFromPathForUserAsync,
+            #[cfg(not(feature = "System"))]
+            GetFileFromPathForUserAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageFileStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_FileProperties"))]
+impl windows_core::imp::ProxyVtable for IStorageFolder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFileAsyncOverloadDefaultOptions(this: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolder", "CreateFileAsyncOverloadDefaultOptions", 6usize, |this| ((**(this as *const *const IStorageFolder_Vtbl)).CreateFileAsyncOverloadDefaultOptions)(this, desiredname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFileAsync(this: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, options: CreationCollisionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolder", "CreateFileAsync", 7usize, |this| ((**(this as *const *const IStorageFolder_Vtbl)).CreateFileAsync)(this, desiredname, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFolderAsyncOverloadDefaultOptions(this: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolder", "CreateFolderAsyncOverloadDefaultOptions", 8usize, |this| ((**(this as *const *const IStorageFolder_Vtbl)).CreateFolderAsyncOverloadDefaultOptions)(this, desiredname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFolderAsync(this: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, options: CreationCollisionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolder", "CreateFolderAsync", 9usize, |this| ((**(this as *const *const IStorageFolder_Vtbl)).CreateFolderAsync)(this, desiredname, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileAsync(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolder", "GetFileAsync", 10usize, |this| ((**(this as *const *const IStorageFolder_Vtbl)).GetFileAsync)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFolderAsync(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolder", "GetFolderAsync", 11usize, |this| ((**(this as *const *const IStorageFolder_Vtbl)).GetFolderAsync)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemAsync(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolder", "GetItemAsync", 12usize, |this| ((**(this as *const *const IStorageFolder_Vtbl)).GetItemAsync)(this, name, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetFilesAsyncOverloadDefaultOptionsStartAndCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolder", "GetFilesAsyncOverloadDefaultOptionsStartAndCount", 13usize, |this| ((**(this as *const *const IStorageFolder_Vtbl)).GetFilesAsyncOverloadDefaultOptionsStartAndCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetFoldersAsyncOverloadDefaultOptionsStartAndCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolder", "GetFoldersAsyncOverloadDefaultOptionsStartAndCount", 14usize, |this| ((**(this as *const *const IStorageFolder_Vtbl)).GetFoldersAsyncOverloadDefaultOptionsStartAndCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetItemsAsyncOverloadDefaultStartAndCount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolder", "GetItemsAsyncOverloadDefaultStartAndCount", 15usize, |this| ((**(this as *const *const IStorageFolder_Vtbl)).GetItemsAsyncOverloadDefaultStartAndCount)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFileAsyncOverloadDefaultOptions,
+            CreateFileAsync,
+            CreateFolderAsyncOverloadDefaultOptions,
+            CreateFolderAsync,
+            GetFileAsync,
+            GetFolderAsync,
+            GetItemAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetFilesAsyncOverloadDefaultOptionsStartAndCount,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetFilesAsyncOverloadDefaultOptionsStartAndCount: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetFoldersAsyncOverloadDefaultOptionsStartAndCount,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetFoldersAsyncOverloadDefaultOptionsStartAndCount: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetItemsAsyncOverloadDefaultStartAndCount,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetItemsAsyncOverloadDefaultStartAndCount: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageFolder as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageFolder2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryGetItemAsync(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolder2", "TryGetItemAsync", 6usize, |this| ((**(this as *const *const IStorageFolder2_Vtbl)).TryGetItemAsync)(this, name, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryGetItemAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageFolder2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageFolder3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryGetChangeTracker(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolder3", "TryGetChangeTracker", 6usize, |this| ((**(this as *const *const IStorageFolder3_Vtbl)).TryGetChangeTracker)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryGetChangeTracker }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageFolder3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageFolderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFolderFromPathAsync(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderStatics", "GetFolderFromPathAsync", 6usize, |this| ((**(this as *const *const IStorageFolderStatics_Vtbl)).GetFolderFromPathAsync)(this, path, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFolderFromPathAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageFolderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IStorageFolderStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetFolderFromPathForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderStatics2", "GetFolderFromPathForUserAsync", 6usize, |this| ((**(this as *const *const IStorageFolderStatics2_Vtbl)).GetFolderFromPathForUserAsync)(this, user, path, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetFolderFromPathForUserAsync,
+            #[cfg(not(feature = "System"))]
+            GetFolderFromPathForUserAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageFolderStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_FileProperties")]
+impl windows_core::imp::ProxyVtable for IStorageItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RenameAsyncOverloadDefaultOptions(this: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItem", "RenameAsyncOverloadDefaultOptions", 6usize, |this| ((**(this as *const *const IStorageItem_Vtbl)).RenameAsyncOverloadDefaultOptions)(this, desiredname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenameAsync(this: *mut core::ffi::c_void, desiredname: core::mem::MaybeUninit<windows_core::HSTRING>, option: NameCollisionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItem", "RenameAsync", 7usize, |this| ((**(this as *const *const IStorageItem_Vtbl)).RenameAsync)(this, desiredname, option, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAsyncOverloadDefaultOptions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItem", "DeleteAsyncOverloadDefaultOptions", 8usize, |this| ((**(this as *const *const IStorageItem_Vtbl)).DeleteAsyncOverloadDefaultOptions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, option: StorageDeleteOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItem", "DeleteAsync", 9usize, |this| ((**(this as *const *const IStorageItem_Vtbl)).DeleteAsync)(this, option, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_FileProperties")]
+        unsafe extern "system" fn GetBasicPropertiesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItem", "GetBasicPropertiesAsync", 10usize, |this| ((**(this as *const *const IStorageItem_Vtbl)).GetBasicPropertiesAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItem", "Name", 11usize, |this| ((**(this as *const *const IStorageItem_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItem", "Path", 12usize, |this| ((**(this as *const *const IStorageItem_Vtbl)).Path)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Attributes(this: *mut core::ffi::c_void, result__: *mut FileAttributes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItem", "Attributes", 13usize, |this| ((**(this as *const *const IStorageItem_Vtbl)).Attributes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DateCreated(this: *mut core::ffi::c_void, result__: *mut super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItem", "DateCreated", 14usize, |this| ((**(this as *const *const IStorageItem_Vtbl)).DateCreated)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOfType(this: *mut core::ffi::c_void, r#type: StorageItemTypes, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItem", "IsOfType", 15usize, |this| ((**(this as *const *const IStorageItem_Vtbl)).IsOfType)(this, r#type, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RenameAsyncOverloadDefaultOptions,
+            RenameAsync,
+            DeleteAsyncOverloadDefaultOptions,
+            DeleteAsync,
+            #[cfg(feature = "Storage_FileProperties")]
+            GetBasicPropertiesAsync,
+            #[cfg(not(feature = "Storage_FileProperties"))]
+            GetBasicPropertiesAsync: 0,
+            Name,
+            Path,
+            Attributes,
+            DateCreated,
+            IsOfType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_FileProperties")]
+impl windows_core::imp::ProxyVtable for IStorageItem2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetParentAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItem2", "GetParentAsync", 6usize, |this| ((**(this as *const *const IStorageItem2_Vtbl)).GetParentAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, item: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItem2", "IsEqual", 7usize, |this| ((**(this as *const *const IStorageItem2_Vtbl)).IsEqual)(this, item, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetParentAsync, IsEqual }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageItem2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IStorageItemProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GetThumbnailAsyncOverloadDefaultSizeDefaultOptions(this: *mut core::ffi::c_void, mode: FileProperties::ThumbnailMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemProperties", "GetThumbnailAsyncOverloadDefaultSizeDefaultOptions", 6usize, |this| ((**(this as *const *const IStorageItemProperties_Vtbl)).GetThumbnailAsyncOverloadDefaultSizeDefaultOptions)(this, mode, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GetThumbnailAsyncOverloadDefaultOptions(this: *mut core::ffi::c_void, mode: FileProperties::ThumbnailMode, requestedsize: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemProperties", "GetThumbnailAsyncOverloadDefaultOptions", 7usize, |this| ((**(this as *const *const IStorageItemProperties_Vtbl)).GetThumbnailAsyncOverloadDefaultOptions)(this, mode, requestedsize, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GetThumbnailAsync(this: *mut core::ffi::c_void, mode: FileProperties::ThumbnailMode, requestedsize: u32, options: FileProperties::ThumbnailOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemProperties", "GetThumbnailAsync", 8usize, |this| ((**(this as *const *const IStorageItemProperties_Vtbl)).GetThumbnailAsync)(this, mode, requestedsize, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemProperties", "DisplayName", 9usize, |this| ((**(this as *const *const IStorageItemProperties_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemProperties", "DisplayType", 10usize, |this| ((**(this as *const *const IStorageItemProperties_Vtbl)).DisplayType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FolderRelativeId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemProperties", "FolderRelativeId", 11usize, |this| ((**(this as *const *const IStorageItemProperties_Vtbl)).FolderRelativeId)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_FileProperties")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemProperties", "Properties", 12usize, |this| ((**(this as *const *const IStorageItemProperties_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+            GetThumbnailAsyncOverloadDefaultSizeDefaultOptions,
+            #[cfg(not(all(feature = "Storage_FileProperties", feature = "Storage_Streams")))]
+            GetThumbnailAsyncOverloadDefaultSizeDefaultOptions: 0,
+            #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+            GetThumbnailAsyncOverloadDefaultOptions,
+            #[cfg(not(all(feature = "Storage_FileProperties", feature = "Storage_Streams")))]
+            GetThumbnailAsyncOverloadDefaultOptions: 0,
+            #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+            GetThumbnailAsync,
+            #[cfg(not(all(feature = "Storage_FileProperties", feature = "Storage_Streams")))]
+            GetThumbnailAsync: 0,
+            DisplayName,
+            DisplayType,
+            FolderRelativeId,
+            #[cfg(feature = "Storage_FileProperties")]
+            Properties,
+            #[cfg(not(feature = "Storage_FileProperties"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageItemProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IStorageItemProperties2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GetScaledImageAsThumbnailAsyncOverloadDefaultSizeDefaultOptions(this: *mut core::ffi::c_void, mode: FileProperties::ThumbnailMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemProperties2", "GetScaledImageAsThumbnailAsyncOverloadDefaultSizeDefaultOptions", 6usize, |this| ((**(this as *const *const IStorageItemProperties2_Vtbl)).GetScaledImageAsThumbnailAsyncOverloadDefaultSizeDefaultOptions)(this, mode, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GetScaledImageAsThumbnailAsyncOverloadDefaultOptions(this: *mut core::ffi::c_void, mode: FileProperties::ThumbnailMode, requestedsize: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemProperties2", "GetScaledImageAsThumbnailAsyncOverloadDefaultOptions", 7usize, |this| ((**(this as *const *const IStorageItemProperties2_Vtbl)).GetScaledImageAsThumbnailAsyncOverloadDefaultOptions)(this, mode, requestedsize, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+        unsafe extern "system" fn GetScaledImageAsThumbnailAsync(this: *mut core::ffi::c_void, mode: FileProperties::ThumbnailMode, requestedsize: u32, options: FileProperties::ThumbnailOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemProperties2", "GetScaledImageAsThumbnailAsync", 8usize, |this| ((**(this as *const *const IStorageItemProperties2_Vtbl)).GetScaledImageAsThumbnailAsync)(this, mode, requestedsize, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+            GetScaledImageAsThumbnailAsyncOverloadDefaultSizeDefaultOptions,
+            #[cfg(not(all(feature = "Storage_FileProperties", feature = "Storage_Streams")))]
+            GetScaledImageAsThumbnailAsyncOverloadDefaultSizeDefaultOptions: 0,
+            #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+            GetScaledImageAsThumbnailAsyncOverloadDefaultOptions,
+            #[cfg(not(all(feature = "Storage_FileProperties", feature = "Storage_Streams")))]
+            GetScaledImageAsThumbnailAsyncOverloadDefaultOptions: 0,
+            #[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+            GetScaledImageAsThumbnailAsync,
+            #[cfg(not(all(feature = "Storage_FileProperties", feature = "Storage_Streams")))]
+            GetScaledImageAsThumbnailAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageItemProperties2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IStorageItemPropertiesWithProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Provider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemPropertiesWithProvider", "Provider", 6usize, |this| ((**(this as *const *const IStorageItemPropertiesWithProvider_Vtbl)).Provider)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Provider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageItemPropertiesWithProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageLibrary_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAddFolderAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibrary", "RequestAddFolderAsync", 6usize, |this| ((**(this as *const *const IStorageLibrary_Vtbl)).RequestAddFolderAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestRemoveFolderAsync(this: *mut core::ffi::c_void, folder: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibrary", "RequestRemoveFolderAsync", 7usize, |this| ((**(this as *const *const IStorageLibrary_Vtbl)).RequestRemoveFolderAsync)(this, folder, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Folders(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibrary", "Folders", 8usize, |this| ((**(this as *const *const IStorageLibrary_Vtbl)).Folders)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveFolder(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibrary", "SaveFolder", 9usize, |this| ((**(this as *const *const IStorageLibrary_Vtbl)).SaveFolder)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefinitionChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibrary", "DefinitionChanged", 10usize, |this| ((**(this as *const *const IStorageLibrary_Vtbl)).DefinitionChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDefinitionChanged(this: *mut core::ffi::c_void, eventcookie: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibrary", "RemoveDefinitionChanged", 11usize, |this| ((**(this as *const *const IStorageLibrary_Vtbl)).RemoveDefinitionChanged)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestAddFolderAsync,
+            RequestRemoveFolderAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            Folders,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Folders: 0,
+            SaveFolder,
+            DefinitionChanged,
+            RemoveDefinitionChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibrary as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageLibrary2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeTracker(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibrary2", "ChangeTracker", 6usize, |this| ((**(this as *const *const IStorageLibrary2_Vtbl)).ChangeTracker)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ChangeTracker }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibrary2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageLibrary3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AreFolderSuggestionsAvailableAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibrary3", "AreFolderSuggestionsAvailableAsync", 6usize, |this| ((**(this as *const *const IStorageLibrary3_Vtbl)).AreFolderSuggestionsAvailableAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AreFolderSuggestionsAvailableAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibrary3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageLibraryChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeType(this: *mut core::ffi::c_void, result__: *mut StorageLibraryChangeType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChange", "ChangeType", 6usize, |this| ((**(this as *const *const IStorageLibraryChange_Vtbl)).ChangeType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChange", "Path", 7usize, |this| ((**(this as *const *const IStorageLibraryChange_Vtbl)).Path)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreviousPath(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChange", "PreviousPath", 8usize, |this| ((**(this as *const *const IStorageLibraryChange_Vtbl)).PreviousPath)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOfType(this: *mut core::ffi::c_void, r#type: StorageItemTypes, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChange", "IsOfType", 9usize, |this| ((**(this as *const *const IStorageLibraryChange_Vtbl)).IsOfType)(this, r#type, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStorageItemAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChange", "GetStorageItemAsync", 10usize, |this| ((**(this as *const *const IStorageLibraryChange_Vtbl)).GetStorageItemAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChangeType,
+            Path,
+            PreviousPath,
+            IsOfType,
+            GetStorageItemAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibraryChange as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IStorageLibraryChangeReader_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReadBatchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChangeReader", "ReadBatchAsync", 6usize, |this| ((**(this as *const *const IStorageLibraryChangeReader_Vtbl)).ReadBatchAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptChangesAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChangeReader", "AcceptChangesAsync", 7usize, |this| ((**(this as *const *const IStorageLibraryChangeReader_Vtbl)).AcceptChangesAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ReadBatchAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReadBatchAsync: 0,
+            AcceptChangesAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibraryChangeReader as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageLibraryChangeReader2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLastChangeId(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChangeReader2", "GetLastChangeId", 6usize, |this| ((**(this as *const *const IStorageLibraryChangeReader2_Vtbl)).GetLastChangeId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLastChangeId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibraryChangeReader2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageLibraryChangeTracker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetChangeReader(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChangeTracker", "GetChangeReader", 6usize, |this| ((**(this as *const *const IStorageLibraryChangeTracker_Vtbl)).GetChangeReader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChangeTracker", "Enable", 7usize, |this| ((**(this as *const *const IStorageLibraryChangeTracker_Vtbl)).Enable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChangeTracker", "Reset", 8usize, |this| ((**(this as *const *const IStorageLibraryChangeTracker_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetChangeReader, Enable, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibraryChangeTracker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageLibraryChangeTracker2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableWithOptions(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChangeTracker2", "EnableWithOptions", 6usize, |this| ((**(this as *const *const IStorageLibraryChangeTracker2_Vtbl)).EnableWithOptions)(this, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChangeTracker2", "Disable", 7usize, |this| ((**(this as *const *const IStorageLibraryChangeTracker2_Vtbl)).Disable)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnableWithOptions, Disable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibraryChangeTracker2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageLibraryChangeTrackerOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TrackChangeDetails(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChangeTrackerOptions", "TrackChangeDetails", 6usize, |this| ((**(this as *const *const IStorageLibraryChangeTrackerOptions_Vtbl)).TrackChangeDetails)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrackChangeDetails(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryChangeTrackerOptions", "SetTrackChangeDetails", 7usize, |this| ((**(this as *const *const IStorageLibraryChangeTrackerOptions_Vtbl)).SetTrackChangeDetails)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TrackChangeDetails, SetTrackChangeDetails }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibraryChangeTrackerOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageLibraryLastChangeId_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibraryLastChangeId as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageLibraryLastChangeIdStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Unknown(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryLastChangeIdStatics", "Unknown", 6usize, |this| ((**(this as *const *const IStorageLibraryLastChangeIdStatics_Vtbl)).Unknown)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Unknown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibraryLastChangeIdStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageLibraryStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLibraryAsync(this: *mut core::ffi::c_void, libraryid: KnownLibraryId, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryStatics", "GetLibraryAsync", 6usize, |this| ((**(this as *const *const IStorageLibraryStatics_Vtbl)).GetLibraryAsync)(this, libraryid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLibraryAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibraryStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IStorageLibraryStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetLibraryForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, libraryid: KnownLibraryId, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageLibraryStatics2", "GetLibraryForUserAsync", 6usize, |this| ((**(this as *const *const IStorageLibraryStatics2_Vtbl)).GetLibraryForUserAsync)(this, user, libraryid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetLibraryForUserAsync,
+            #[cfg(not(feature = "System"))]
+            GetLibraryForUserAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageLibraryStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProvider", "Id", 6usize, |this| ((**(this as *const *const IStorageProvider_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProvider", "DisplayName", 7usize, |this| ((**(this as *const *const IStorageProvider_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, DisplayName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageProvider2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPropertySupportedForPartialFileAsync(this: *mut core::ffi::c_void, propertycanonicalname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageProvider2", "IsPropertySupportedForPartialFileAsync", 6usize, |this| ((**(this as *const *const IStorageProvider2_Vtbl)).IsPropertySupportedForPartialFileAsync)(this, propertycanonicalname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsPropertySupportedForPartialFileAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageProvider2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IStorageStreamTransaction_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Stream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageStreamTransaction", "Stream", 6usize, |this| ((**(this as *const *const IStorageStreamTransaction_Vtbl)).Stream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageStreamTransaction", "CommitAsync", 7usize, |this| ((**(this as *const *const IStorageStreamTransaction_Vtbl)).CommitAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Stream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Stream: 0,
+            CommitAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageStreamTransaction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamedFileDataRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FailAndClose(this: *mut core::ffi::c_void, failuremode: StreamedFileFailureMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamedFileDataRequest", "FailAndClose", 6usize, |this| ((**(this as *const *const IStreamedFileDataRequest_Vtbl)).FailAndClose)(this, failuremode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FailAndClose }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamedFileDataRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemAudioProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EncodingBitrate(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemAudioProperties", "EncodingBitrate", 6usize, |this| ((**(this as *const *const ISystemAudioProperties_Vtbl)).EncodingBitrate)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EncodingBitrate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemAudioProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemDataPaths_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Fonts(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "Fonts", 6usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).Fonts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProgramData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "ProgramData", 7usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).ProgramData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Public(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "Public", 8usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).Public)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublicDesktop(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "PublicDesktop", 9usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).PublicDesktop)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublicDocuments(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "PublicDocuments", 10usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).PublicDocuments)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublicDownloads(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "PublicDownloads", 11usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).PublicDownloads)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublicMusic(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "PublicMusic", 12usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).PublicMusic)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublicPictures(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "PublicPictures", 13usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).PublicPictures)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublicVideos(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "PublicVideos", 14usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).PublicVideos)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn System(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "System", 15usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).System)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemHost(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "SystemHost", 16usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).SystemHost)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemX86(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "SystemX86", 17usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).SystemX86)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemX64(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "SystemX64", 18usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).SystemX64)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemArm(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "SystemArm", 19usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).SystemArm)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserProfiles(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "UserProfiles", 20usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).UserProfiles)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Windows(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPaths", "Windows", 21usize, |this| ((**(this as *const *const ISystemDataPaths_Vtbl)).Windows)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Fonts,
+            ProgramData,
+            Public,
+            PublicDesktop,
+            PublicDocuments,
+            PublicDownloads,
+            PublicMusic,
+            PublicPictures,
+            PublicVideos,
+            System,
+            SystemHost,
+            SystemX86,
+            SystemX64,
+            SystemArm,
+            UserProfiles,
+            Windows,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemDataPaths as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemDataPathsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDataPathsStatics", "GetDefault", 6usize, |this| ((**(this as *const *const ISystemDataPathsStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemDataPathsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemGPSProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LatitudeDecimal(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemGPSProperties", "LatitudeDecimal", 6usize, |this| ((**(this as *const *const ISystemGPSProperties_Vtbl)).LatitudeDecimal)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LongitudeDecimal(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemGPSProperties", "LongitudeDecimal", 7usize, |this| ((**(this as *const *const ISystemGPSProperties_Vtbl)).LongitudeDecimal)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LatitudeDecimal, LongitudeDecimal }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemGPSProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemImageProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HorizontalSize(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemImageProperties", "HorizontalSize", 6usize, |this| ((**(this as *const *const ISystemImageProperties_Vtbl)).HorizontalSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerticalSize(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemImageProperties", "VerticalSize", 7usize, |this| ((**(this as *const *const ISystemImageProperties_Vtbl)).VerticalSize)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HorizontalSize, VerticalSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemImageProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemMediaProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaProperties", "Duration", 6usize, |this| ((**(this as *const *const ISystemMediaProperties_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Producer(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaProperties", "Producer", 7usize, |this| ((**(this as *const *const ISystemMediaProperties_Vtbl)).Producer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Publisher(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaProperties", "Publisher", 8usize, |this| ((**(this as *const *const ISystemMediaProperties_Vtbl)).Publisher)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubTitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaProperties", "SubTitle", 9usize, |this| ((**(this as *const *const ISystemMediaProperties_Vtbl)).SubTitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Writer(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaProperties", "Writer", 10usize, |this| ((**(this as *const *const ISystemMediaProperties_Vtbl)).Writer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Year(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaProperties", "Year", 11usize, |this| ((**(this as *const *const ISystemMediaProperties_Vtbl)).Year)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Duration, Producer, Publisher, SubTitle, Writer, Year }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemMediaProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemMusicProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AlbumArtist(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMusicProperties", "AlbumArtist", 6usize, |this| ((**(this as *const *const ISystemMusicProperties_Vtbl)).AlbumArtist)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlbumTitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMusicProperties", "AlbumTitle", 7usize, |this| ((**(this as *const *const ISystemMusicProperties_Vtbl)).AlbumTitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Artist(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMusicProperties", "Artist", 8usize, |this| ((**(this as *const *const ISystemMusicProperties_Vtbl)).Artist)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Composer(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMusicProperties", "Composer", 9usize, |this| ((**(this as *const *const ISystemMusicProperties_Vtbl)).Composer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Conductor(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMusicProperties", "Conductor", 10usize, |this| ((**(this as *const *const ISystemMusicProperties_Vtbl)).Conductor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayArtist(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMusicProperties", "DisplayArtist", 11usize, |this| ((**(this as *const *const ISystemMusicProperties_Vtbl)).DisplayArtist)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Genre(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMusicProperties", "Genre", 12usize, |this| ((**(this as *const *const ISystemMusicProperties_Vtbl)).Genre)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrackNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMusicProperties", "TrackNumber", 13usize, |this| ((**(this as *const *const ISystemMusicProperties_Vtbl)).TrackNumber)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AlbumArtist,
+            AlbumTitle,
+            Artist,
+            Composer,
+            Conductor,
+            DisplayArtist,
+            Genre,
+            TrackNumber,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemMusicProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemPhotoProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CameraManufacturer(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemPhotoProperties", "CameraManufacturer", 6usize, |this| ((**(this as *const *const ISystemPhotoProperties_Vtbl)).CameraManufacturer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CameraModel(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemPhotoProperties", "CameraModel", 7usize, |this| ((**(this as *const *const ISystemPhotoProperties_Vtbl)).CameraModel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DateTaken(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemPhotoProperties", "DateTaken", 8usize, |this| ((**(this as *const *const ISystemPhotoProperties_Vtbl)).DateTaken)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemPhotoProperties", "Orientation", 9usize, |this| ((**(this as *const *const ISystemPhotoProperties_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PeopleNames(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemPhotoProperties", "PeopleNames", 10usize, |this| ((**(this as *const *const ISystemPhotoProperties_Vtbl)).PeopleNames)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CameraManufacturer,
+            CameraModel,
+            DateTaken,
+            Orientation,
+            PeopleNames,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemPhotoProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Author(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemProperties", "Author", 6usize, |this| ((**(this as *const *const ISystemProperties_Vtbl)).Author)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Comment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemProperties", "Comment", 7usize, |this| ((**(this as *const *const ISystemProperties_Vtbl)).Comment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemNameDisplay(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemProperties", "ItemNameDisplay", 8usize, |this| ((**(this as *const *const ISystemProperties_Vtbl)).ItemNameDisplay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Keywords(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemProperties", "Keywords", 9usize, |this| ((**(this as *const *const ISystemProperties_Vtbl)).Keywords)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rating(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemProperties", "Rating", 10usize, |this| ((**(this as *const *const ISystemProperties_Vtbl)).Rating)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemProperties", "Title", 11usize, |this| ((**(this as *const *const ISystemProperties_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Audio(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemProperties", "Audio", 12usize, |this| ((**(this as *const *const ISystemProperties_Vtbl)).Audio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GPS(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemProperties", "GPS", 13usize, |this| ((**(this as *const *const ISystemProperties_Vtbl)).GPS)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Media(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemProperties", "Media", 14usize, |this| ((**(this as *const *const ISystemProperties_Vtbl)).Media)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Music(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemProperties", "Music", 15usize, |this| ((**(this as *const *const ISystemProperties_Vtbl)).Music)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Photo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemProperties", "Photo", 16usize, |this| ((**(this as *const *const ISystemProperties_Vtbl)).Photo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Video(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemProperties", "Video", 17usize, |this| ((**(this as *const *const ISystemProperties_Vtbl)).Video)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Image(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemProperties", "Image", 18usize, |this| ((**(this as *const *const ISystemProperties_Vtbl)).Image)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Author,
+            Comment,
+            ItemNameDisplay,
+            Keywords,
+            Rating,
+            Title,
+            Audio,
+            GPS,
+            Media,
+            Music,
+            Photo,
+            Video,
+            Image,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemVideoProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Director(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemVideoProperties", "Director", 6usize, |this| ((**(this as *const *const ISystemVideoProperties_Vtbl)).Director)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameHeight(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemVideoProperties", "FrameHeight", 7usize, |this| ((**(this as *const *const ISystemVideoProperties_Vtbl)).FrameHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameWidth(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemVideoProperties", "FrameWidth", 8usize, |this| ((**(this as *const *const ISystemVideoProperties_Vtbl)).FrameWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemVideoProperties", "Orientation", 9usize, |this| ((**(this as *const *const ISystemVideoProperties_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalBitrate(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemVideoProperties", "TotalBitrate", 10usize, |this| ((**(this as *const *const ISystemVideoProperties_Vtbl)).TotalBitrate)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Director, FrameHeight, FrameWidth, Orientation, TotalBitrate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemVideoProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDataPaths_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CameraRoll(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "CameraRoll", 6usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).CameraRoll)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cookies(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "Cookies", 7usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).Cookies)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Desktop(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "Desktop", 8usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).Desktop)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Documents(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "Documents", 9usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).Documents)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Downloads(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "Downloads", 10usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).Downloads)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Favorites(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "Favorites", 11usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).Favorites)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn History(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "History", 12usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).History)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InternetCache(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "InternetCache", 13usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).InternetCache)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalAppData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "LocalAppData", 14usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).LocalAppData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalAppDataLow(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "LocalAppDataLow", 15usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).LocalAppDataLow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Music(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "Music", 16usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).Music)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pictures(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "Pictures", 17usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).Pictures)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Profile(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "Profile", 18usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).Profile)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Recent(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "Recent", 19usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).Recent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoamingAppData(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "RoamingAppData", 20usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).RoamingAppData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SavedPictures(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "SavedPictures", 21usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).SavedPictures)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Screenshots(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "Screenshots", 22usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).Screenshots)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Templates(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "Templates", 23usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).Templates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Videos(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPaths", "Videos", 24usize, |this| ((**(this as *const *const IUserDataPaths_Vtbl)).Videos)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CameraRoll,
+            Cookies,
+            Desktop,
+            Documents,
+            Downloads,
+            Favorites,
+            History,
+            InternetCache,
+            LocalAppData,
+            LocalAppDataLow,
+            Music,
+            Pictures,
+            Profile,
+            Recent,
+            RoamingAppData,
+            SavedPictures,
+            Screenshots,
+            Templates,
+            Videos,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataPaths as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IUserDataPathsStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPathsStatics", "GetForUser", 6usize, |this| ((**(this as *const *const IUserDataPathsStatics_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDataPathsStatics", "GetDefault", 7usize, |this| ((**(this as *const *const IUserDataPathsStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+            GetDefault,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDataPathsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -300,6 +300,7 @@ impl DevicePortalConnectionClosedReason {
 impl windows_core::TypeKind for DevicePortalConnectionClosedReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DevicePortalConnectionClosedReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DevicePortalConnectionClosedReason").field(&self.0).finish()
@@ -308,3 +309,5 @@ impl core::fmt::Debug for DevicePortalConnectionClosedReason {
 impl windows_core::RuntimeType for DevicePortalConnectionClosedReason {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.System.Diagnostics.DevicePortal.DevicePortalConnectionClosedReason;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
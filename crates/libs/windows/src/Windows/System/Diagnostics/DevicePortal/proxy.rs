@@ -0,0 +1,161 @@
+impl windows_core::imp::ProxyVtable for IDevicePortalConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalConnection", "Closed", 6usize, |this| ((**(this as *const *const IDevicePortalConnection_Vtbl)).Closed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalConnection", "RemoveClosed", 7usize, |this| ((**(this as *const *const IDevicePortalConnection_Vtbl)).RemoveClosed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalConnection", "RequestReceived", 8usize, |this| ((**(this as *const *const IDevicePortalConnection_Vtbl)).RequestReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRequestReceived(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalConnection", "RemoveRequestReceived", 9usize, |this| ((**(this as *const *const IDevicePortalConnection_Vtbl)).RemoveRequestReceived)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Closed, RemoveClosed, RequestReceived, RemoveRequestReceived }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePortalConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDevicePortalConnectionClosedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut DevicePortalConnectionClosedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalConnectionClosedEventArgs", "Reason", 6usize, |this| ((**(this as *const *const IDevicePortalConnectionClosedEventArgs_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePortalConnectionClosedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Web_Http")]
+impl windows_core::imp::ProxyVtable for IDevicePortalConnectionRequestReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Web_Http")]
+        unsafe extern "system" fn RequestMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalConnectionRequestReceivedEventArgs", "RequestMessage", 6usize, |this| ((**(this as *const *const IDevicePortalConnectionRequestReceivedEventArgs_Vtbl)).RequestMessage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Http")]
+        unsafe extern "system" fn ResponseMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalConnectionRequestReceivedEventArgs", "ResponseMessage", 7usize, |this| ((**(this as *const *const IDevicePortalConnectionRequestReceivedEventArgs_Vtbl)).ResponseMessage)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Web_Http")]
+            RequestMessage,
+            #[cfg(not(feature = "Web_Http"))]
+            RequestMessage: 0,
+            #[cfg(feature = "Web_Http")]
+            ResponseMessage,
+            #[cfg(not(feature = "Web_Http"))]
+            ResponseMessage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePortalConnectionRequestReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_AppService")]
+impl windows_core::imp::ProxyVtable for IDevicePortalConnectionStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_AppService")]
+        unsafe extern "system" fn GetForAppServiceConnection(this: *mut core::ffi::c_void, appserviceconnection: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalConnectionStatics", "GetForAppServiceConnection", 6usize, |this| ((**(this as *const *const IDevicePortalConnectionStatics_Vtbl)).GetForAppServiceConnection)(this, appserviceconnection, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_AppService")]
+            GetForAppServiceConnection,
+            #[cfg(not(feature = "ApplicationModel_AppService"))]
+            GetForAppServiceConnection: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePortalConnectionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Networking_Sockets", feature = "Web_Http"))]
+impl windows_core::imp::ProxyVtable for IDevicePortalWebSocketConnection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Networking_Sockets", feature = "Web_Http"))]
+        unsafe extern "system" fn GetServerMessageWebSocketForRequest(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalWebSocketConnection", "GetServerMessageWebSocketForRequest", 6usize, |this| ((**(this as *const *const IDevicePortalWebSocketConnection_Vtbl)).GetServerMessageWebSocketForRequest)(this, request, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Networking_Sockets", feature = "Web_Http"))]
+        unsafe extern "system" fn GetServerMessageWebSocketForRequest2(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, messagetype: super::super::super::Networking::Sockets::SocketMessageType, protocol: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalWebSocketConnection", "GetServerMessageWebSocketForRequest2", 7usize, |this| ((**(this as *const *const IDevicePortalWebSocketConnection_Vtbl)).GetServerMessageWebSocketForRequest2)(this, request, messagetype, protocol, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Networking_Sockets", feature = "Web_Http"))]
+        unsafe extern "system" fn GetServerMessageWebSocketForRequest3(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, messagetype: super::super::super::Networking::Sockets::SocketMessageType, protocol: core::mem::MaybeUninit<windows_core::HSTRING>, outboundbuffersizeinbytes: u32, maxmessagesize: u32, receivemode: super::super::super::Networking::Sockets::MessageWebSocketReceiveMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalWebSocketConnection", "GetServerMessageWebSocketForRequest3", 8usize, |this| ((**(this as *const *const IDevicePortalWebSocketConnection_Vtbl)).GetServerMessageWebSocketForRequest3)(this, request, messagetype, protocol, outboundbuffersizeinbytes, maxmessagesize, receivemode, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Networking_Sockets", feature = "Web_Http"))]
+        unsafe extern "system" fn GetServerStreamWebSocketForRequest(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalWebSocketConnection", "GetServerStreamWebSocketForRequest", 9usize, |this| ((**(this as *const *const IDevicePortalWebSocketConnection_Vtbl)).GetServerStreamWebSocketForRequest)(this, request, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Networking_Sockets", feature = "Web_Http"))]
+        unsafe extern "system" fn GetServerStreamWebSocketForRequest2(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, protocol: core::mem::MaybeUninit<windows_core::HSTRING>, outboundbuffersizeinbytes: u32, nodelay: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalWebSocketConnection", "GetServerStreamWebSocketForRequest2", 10usize, |this| ((**(this as *const *const IDevicePortalWebSocketConnection_Vtbl)).GetServerStreamWebSocketForRequest2)(this, request, protocol, outboundbuffersizeinbytes, nodelay, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Networking_Sockets", feature = "Web_Http"))]
+            GetServerMessageWebSocketForRequest,
+            #[cfg(not(all(feature = "Networking_Sockets", feature = "Web_Http")))]
+            GetServerMessageWebSocketForRequest: 0,
+            #[cfg(all(feature = "Networking_Sockets", feature = "Web_Http"))]
+            GetServerMessageWebSocketForRequest2,
+            #[cfg(not(all(feature = "Networking_Sockets", feature = "Web_Http")))]
+            GetServerMessageWebSocketForRequest2: 0,
+            #[cfg(all(feature = "Networking_Sockets", feature = "Web_Http"))]
+            GetServerMessageWebSocketForRequest3,
+            #[cfg(not(all(feature = "Networking_Sockets", feature = "Web_Http")))]
+            GetServerMessageWebSocketForRequest3: 0,
+            #[cfg(all(feature = "Networking_Sockets", feature = "Web_Http"))]
+            GetServerStreamWebSocketForRequest,
+            #[cfg(not(all(feature = "Networking_Sockets", feature = "Web_Http")))]
+            GetServerStreamWebSocketForRequest: 0,
+            #[cfg(all(feature = "Networking_Sockets", feature = "Web_Http"))]
+            GetServerStreamWebSocketForRequest2,
+            #[cfg(not(all(feature = "Networking_Sockets", feature = "Web_Http")))]
+            GetServerStreamWebSocketForRequest2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePortalWebSocketConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDevicePortalWebSocketConnectionRequestReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsWebSocketUpgradeRequest(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalWebSocketConnectionRequestReceivedEventArgs", "IsWebSocketUpgradeRequest", 6usize, |this| ((**(this as *const *const IDevicePortalWebSocketConnectionRequestReceivedEventArgs_Vtbl)).IsWebSocketUpgradeRequest)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn WebSocketProtocolsRequested(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalWebSocketConnectionRequestReceivedEventArgs", "WebSocketProtocolsRequested", 7usize, |this| ((**(this as *const *const IDevicePortalWebSocketConnectionRequestReceivedEventArgs_Vtbl)).WebSocketProtocolsRequested)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDevicePortalWebSocketConnectionRequestReceivedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IDevicePortalWebSocketConnectionRequestReceivedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsWebSocketUpgradeRequest,
+            #[cfg(feature = "Foundation_Collections")]
+            WebSocketProtocolsRequested,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            WebSocketProtocolsRequested: 0,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDevicePortalWebSocketConnectionRequestReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
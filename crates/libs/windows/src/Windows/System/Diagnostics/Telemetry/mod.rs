@@ -138,6 +138,7 @@ impl PlatformTelemetryRegistrationStatus {
 impl windows_core::TypeKind for PlatformTelemetryRegistrationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlatformTelemetryRegistrationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlatformTelemetryRegistrationStatus").field(&self.0).finish()
@@ -146,3 +147,5 @@ impl core::fmt::Debug for PlatformTelemetryRegistrationStatus {
 impl windows_core::RuntimeType for PlatformTelemetryRegistrationStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.System.Diagnostics.Telemetry.PlatformTelemetryRegistrationStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,54 @@
+impl windows_core::imp::ProxyVtable for IPlatformTelemetryClientStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformTelemetryClientStatics", "Register", 6usize, |this| ((**(this as *const *const IPlatformTelemetryClientStatics_Vtbl)).Register)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterWithSettings(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, settings: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformTelemetryClientStatics", "RegisterWithSettings", 7usize, |this| ((**(this as *const *const IPlatformTelemetryClientStatics_Vtbl)).RegisterWithSettings)(this, id, settings, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Register, RegisterWithSettings }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlatformTelemetryClientStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlatformTelemetryRegistrationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut PlatformTelemetryRegistrationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformTelemetryRegistrationResult", "Status", 6usize, |this| ((**(this as *const *const IPlatformTelemetryRegistrationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlatformTelemetryRegistrationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlatformTelemetryRegistrationSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StorageSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformTelemetryRegistrationSettings", "StorageSize", 6usize, |this| ((**(this as *const *const IPlatformTelemetryRegistrationSettings_Vtbl)).StorageSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStorageSize(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformTelemetryRegistrationSettings", "SetStorageSize", 7usize, |this| ((**(this as *const *const IPlatformTelemetryRegistrationSettings_Vtbl)).SetStorageSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UploadQuotaSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformTelemetryRegistrationSettings", "UploadQuotaSize", 8usize, |this| ((**(this as *const *const IPlatformTelemetryRegistrationSettings_Vtbl)).UploadQuotaSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUploadQuotaSize(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformTelemetryRegistrationSettings", "SetUploadQuotaSize", 9usize, |this| ((**(this as *const *const IPlatformTelemetryRegistrationSettings_Vtbl)).SetUploadQuotaSize)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StorageSize,
+            SetStorageSize,
+            UploadQuotaSize,
+            SetUploadQuotaSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlatformTelemetryRegistrationSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -215,6 +215,7 @@ impl PlatformDiagnosticActionState {
 impl windows_core::TypeKind for PlatformDiagnosticActionState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlatformDiagnosticActionState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlatformDiagnosticActionState").field(&self.0).finish()
@@ -233,6 +234,7 @@ impl PlatformDiagnosticEscalationType {
 impl windows_core::TypeKind for PlatformDiagnosticEscalationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlatformDiagnosticEscalationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlatformDiagnosticEscalationType").field(&self.0).finish()
@@ -252,6 +254,7 @@ impl PlatformDiagnosticEventBufferLatencies {
 impl windows_core::TypeKind for PlatformDiagnosticEventBufferLatencies {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlatformDiagnosticEventBufferLatencies {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlatformDiagnosticEventBufferLatencies").field(&self.0).finish()
@@ -303,6 +306,7 @@ impl PlatformDiagnosticTracePriority {
 impl windows_core::TypeKind for PlatformDiagnosticTracePriority {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlatformDiagnosticTracePriority {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlatformDiagnosticTracePriority").field(&self.0).finish()
@@ -322,6 +326,7 @@ impl PlatformDiagnosticTraceSlotState {
 impl windows_core::TypeKind for PlatformDiagnosticTraceSlotState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlatformDiagnosticTraceSlotState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlatformDiagnosticTraceSlotState").field(&self.0).finish()
@@ -341,6 +346,7 @@ impl PlatformDiagnosticTraceSlotType {
 impl windows_core::TypeKind for PlatformDiagnosticTraceSlotType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlatformDiagnosticTraceSlotType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlatformDiagnosticTraceSlotType").field(&self.0).finish()
@@ -349,3 +355,5 @@ impl core::fmt::Debug for PlatformDiagnosticTraceSlotType {
 impl windows_core::RuntimeType for PlatformDiagnosticTraceSlotType {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.System.Diagnostics.TraceReporting.PlatformDiagnosticTraceSlotType;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
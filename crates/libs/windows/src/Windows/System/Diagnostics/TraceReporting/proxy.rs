@@ -0,0 +1,106 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPlatformDiagnosticActionsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsScenarioEnabled(this: *mut core::ffi::c_void, scenarioid: windows_core::GUID, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticActionsStatics", "IsScenarioEnabled", 6usize, |this| ((**(this as *const *const IPlatformDiagnosticActionsStatics_Vtbl)).IsScenarioEnabled)(this, scenarioid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TryEscalateScenario(this: *mut core::ffi::c_void, scenarioid: windows_core::GUID, escalationtype: PlatformDiagnosticEscalationType, outputdirectory: core::mem::MaybeUninit<windows_core::HSTRING>, timestampoutputdirectory: bool, forceescalationupload: bool, triggers: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticActionsStatics", "TryEscalateScenario", 7usize, |this| ((**(this as *const *const IPlatformDiagnosticActionsStatics_Vtbl)).TryEscalateScenario)(this, scenarioid, escalationtype, outputdirectory, timestampoutputdirectory, forceescalationupload, triggers, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadLatestSettingsForNamespace(this: *mut core::ffi::c_void, partner: core::mem::MaybeUninit<windows_core::HSTRING>, feature: core::mem::MaybeUninit<windows_core::HSTRING>, isscenarionamespace: bool, downloadovercostednetwork: bool, downloadoverbattery: bool, result__: *mut PlatformDiagnosticActionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticActionsStatics", "DownloadLatestSettingsForNamespace", 8usize, |this| ((**(this as *const *const IPlatformDiagnosticActionsStatics_Vtbl)).DownloadLatestSettingsForNamespace)(this, partner, feature, isscenarionamespace, downloadovercostednetwork, downloadoverbattery, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetActiveScenarioList(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticActionsStatics", "GetActiveScenarioList", 9usize, |this| ((**(this as *const *const IPlatformDiagnosticActionsStatics_Vtbl)).GetActiveScenarioList)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceUpload(this: *mut core::ffi::c_void, latency: PlatformDiagnosticEventBufferLatencies, uploadovercostednetwork: bool, uploadoverbattery: bool, result__: *mut PlatformDiagnosticActionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticActionsStatics", "ForceUpload", 10usize, |this| ((**(this as *const *const IPlatformDiagnosticActionsStatics_Vtbl)).ForceUpload)(this, latency, uploadovercostednetwork, uploadoverbattery, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTraceRunning(this: *mut core::ffi::c_void, slottype: PlatformDiagnosticTraceSlotType, scenarioid: windows_core::GUID, traceprofilehash: u64, result__: *mut PlatformDiagnosticTraceSlotState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticActionsStatics", "IsTraceRunning", 11usize, |this| ((**(this as *const *const IPlatformDiagnosticActionsStatics_Vtbl)).IsTraceRunning)(this, slottype, scenarioid, traceprofilehash, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveTraceRuntime(this: *mut core::ffi::c_void, slottype: PlatformDiagnosticTraceSlotType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticActionsStatics", "GetActiveTraceRuntime", 12usize, |this| ((**(this as *const *const IPlatformDiagnosticActionsStatics_Vtbl)).GetActiveTraceRuntime)(this, slottype, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetKnownTraceList(this: *mut core::ffi::c_void, slottype: PlatformDiagnosticTraceSlotType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticActionsStatics", "GetKnownTraceList", 13usize, |this| ((**(this as *const *const IPlatformDiagnosticActionsStatics_Vtbl)).GetKnownTraceList)(this, slottype, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsScenarioEnabled,
+            #[cfg(feature = "Foundation_Collections")]
+            TryEscalateScenario,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TryEscalateScenario: 0,
+            DownloadLatestSettingsForNamespace,
+            #[cfg(feature = "Foundation_Collections")]
+            GetActiveScenarioList,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetActiveScenarioList: 0,
+            ForceUpload,
+            IsTraceRunning,
+            GetActiveTraceRuntime,
+            #[cfg(feature = "Foundation_Collections")]
+            GetKnownTraceList,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetKnownTraceList: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlatformDiagnosticActionsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlatformDiagnosticTraceInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScenarioId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticTraceInfo", "ScenarioId", 6usize, |this| ((**(this as *const *const IPlatformDiagnosticTraceInfo_Vtbl)).ScenarioId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProfileHash(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticTraceInfo", "ProfileHash", 7usize, |this| ((**(this as *const *const IPlatformDiagnosticTraceInfo_Vtbl)).ProfileHash)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsExclusive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticTraceInfo", "IsExclusive", 8usize, |this| ((**(this as *const *const IPlatformDiagnosticTraceInfo_Vtbl)).IsExclusive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAutoLogger(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticTraceInfo", "IsAutoLogger", 9usize, |this| ((**(this as *const *const IPlatformDiagnosticTraceInfo_Vtbl)).IsAutoLogger)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxTraceDurationFileTime(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticTraceInfo", "MaxTraceDurationFileTime", 10usize, |this| ((**(this as *const *const IPlatformDiagnosticTraceInfo_Vtbl)).MaxTraceDurationFileTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, result__: *mut PlatformDiagnosticTracePriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticTraceInfo", "Priority", 11usize, |this| ((**(this as *const *const IPlatformDiagnosticTraceInfo_Vtbl)).Priority)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ScenarioId,
+            ProfileHash,
+            IsExclusive,
+            IsAutoLogger,
+            MaxTraceDurationFileTime,
+            Priority,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlatformDiagnosticTraceInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlatformDiagnosticTraceRuntimeInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RuntimeFileTime(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticTraceRuntimeInfo", "RuntimeFileTime", 6usize, |this| ((**(this as *const *const IPlatformDiagnosticTraceRuntimeInfo_Vtbl)).RuntimeFileTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EtwRuntimeFileTime(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticTraceRuntimeInfo", "EtwRuntimeFileTime", 7usize, |this| ((**(this as *const *const IPlatformDiagnosticTraceRuntimeInfo_Vtbl)).EtwRuntimeFileTime)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RuntimeFileTime, EtwRuntimeFileTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlatformDiagnosticTraceRuntimeInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
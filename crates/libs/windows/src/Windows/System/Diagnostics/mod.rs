@@ -917,6 +917,7 @@ impl DiagnosticActionState {
 impl windows_core::TypeKind for DiagnosticActionState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DiagnosticActionState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DiagnosticActionState").field(&self.0).finish()
@@ -925,3 +926,5 @@ impl core::fmt::Debug for DiagnosticActionState {
 impl windows_core::RuntimeType for DiagnosticActionState {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.System.Diagnostics.DiagnosticActionState;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,425 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IDiagnosticActionResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiagnosticActionResult", "ExtendedError", 6usize, |this| ((**(this as *const *const IDiagnosticActionResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Results(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiagnosticActionResult", "Results", 7usize, |this| ((**(this as *const *const IDiagnosticActionResult_Vtbl)).Results)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ExtendedError,
+            #[cfg(feature = "Foundation_Collections")]
+            Results,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Results: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiagnosticActionResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Json")]
+impl windows_core::imp::ProxyVtable for IDiagnosticInvoker_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Json")]
+        unsafe extern "system" fn RunDiagnosticActionAsync(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiagnosticInvoker", "RunDiagnosticActionAsync", 6usize, |this| ((**(this as *const *const IDiagnosticInvoker_Vtbl)).RunDiagnosticActionAsync)(this, context, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Json")]
+            RunDiagnosticActionAsync,
+            #[cfg(not(feature = "Data_Json"))]
+            RunDiagnosticActionAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiagnosticInvoker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDiagnosticInvoker2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RunDiagnosticActionFromStringAsync(this: *mut core::ffi::c_void, context: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiagnosticInvoker2", "RunDiagnosticActionFromStringAsync", 6usize, |this| ((**(this as *const *const IDiagnosticInvoker2_Vtbl)).RunDiagnosticActionFromStringAsync)(this, context, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RunDiagnosticActionFromStringAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiagnosticInvoker2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDiagnosticInvokerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiagnosticInvokerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IDiagnosticInvokerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiagnosticInvokerStatics", "GetForUser", 7usize, |this| ((**(this as *const *const IDiagnosticInvokerStatics_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiagnosticInvokerStatics", "IsSupported", 8usize, |this| ((**(this as *const *const IDiagnosticInvokerStatics_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault, GetForUser, IsSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiagnosticInvokerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessCpuUsage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessCpuUsage", "GetReport", 6usize, |this| ((**(this as *const *const IProcessCpuUsage_Vtbl)).GetReport)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetReport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessCpuUsage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessCpuUsageReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KernelTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessCpuUsageReport", "KernelTime", 6usize, |this| ((**(this as *const *const IProcessCpuUsageReport_Vtbl)).KernelTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessCpuUsageReport", "UserTime", 7usize, |this| ((**(this as *const *const IProcessCpuUsageReport_Vtbl)).UserTime)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KernelTime, UserTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessCpuUsageReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessDiagnosticInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProcessId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiagnosticInfo", "ProcessId", 6usize, |this| ((**(this as *const *const IProcessDiagnosticInfo_Vtbl)).ProcessId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecutableFileName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiagnosticInfo", "ExecutableFileName", 7usize, |this| ((**(this as *const *const IProcessDiagnosticInfo_Vtbl)).ExecutableFileName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiagnosticInfo", "Parent", 8usize, |this| ((**(this as *const *const IProcessDiagnosticInfo_Vtbl)).Parent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessStartTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiagnosticInfo", "ProcessStartTime", 9usize, |this| ((**(this as *const *const IProcessDiagnosticInfo_Vtbl)).ProcessStartTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiskUsage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiagnosticInfo", "DiskUsage", 10usize, |this| ((**(this as *const *const IProcessDiagnosticInfo_Vtbl)).DiskUsage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MemoryUsage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiagnosticInfo", "MemoryUsage", 11usize, |this| ((**(this as *const *const IProcessDiagnosticInfo_Vtbl)).MemoryUsage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CpuUsage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiagnosticInfo", "CpuUsage", 12usize, |this| ((**(this as *const *const IProcessDiagnosticInfo_Vtbl)).CpuUsage)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProcessId,
+            ExecutableFileName,
+            Parent,
+            ProcessStartTime,
+            DiskUsage,
+            MemoryUsage,
+            CpuUsage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessDiagnosticInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IProcessDiagnosticInfo2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAppDiagnosticInfos(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiagnosticInfo2", "GetAppDiagnosticInfos", 6usize, |this| ((**(this as *const *const IProcessDiagnosticInfo2_Vtbl)).GetAppDiagnosticInfos)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPackaged(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiagnosticInfo2", "IsPackaged", 7usize, |this| ((**(this as *const *const IProcessDiagnosticInfo2_Vtbl)).IsPackaged)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAppDiagnosticInfos,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAppDiagnosticInfos: 0,
+            IsPackaged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessDiagnosticInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IProcessDiagnosticInfoStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetForProcesses(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiagnosticInfoStatics", "GetForProcesses", 6usize, |this| ((**(this as *const *const IProcessDiagnosticInfoStatics_Vtbl)).GetForProcesses)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForCurrentProcess(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiagnosticInfoStatics", "GetForCurrentProcess", 7usize, |this| ((**(this as *const *const IProcessDiagnosticInfoStatics_Vtbl)).GetForCurrentProcess)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetForProcesses,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetForProcesses: 0,
+            GetForCurrentProcess,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessDiagnosticInfoStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessDiagnosticInfoStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryGetForProcessId(this: *mut core::ffi::c_void, processid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiagnosticInfoStatics2", "TryGetForProcessId", 6usize, |this| ((**(this as *const *const IProcessDiagnosticInfoStatics2_Vtbl)).TryGetForProcessId)(this, processid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryGetForProcessId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessDiagnosticInfoStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessDiskUsage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiskUsage", "GetReport", 6usize, |this| ((**(this as *const *const IProcessDiskUsage_Vtbl)).GetReport)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetReport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessDiskUsage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessDiskUsageReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadOperationCount(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiskUsageReport", "ReadOperationCount", 6usize, |this| ((**(this as *const *const IProcessDiskUsageReport_Vtbl)).ReadOperationCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteOperationCount(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiskUsageReport", "WriteOperationCount", 7usize, |this| ((**(this as *const *const IProcessDiskUsageReport_Vtbl)).WriteOperationCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherOperationCount(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiskUsageReport", "OtherOperationCount", 8usize, |this| ((**(this as *const *const IProcessDiskUsageReport_Vtbl)).OtherOperationCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BytesReadCount(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiskUsageReport", "BytesReadCount", 9usize, |this| ((**(this as *const *const IProcessDiskUsageReport_Vtbl)).BytesReadCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BytesWrittenCount(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiskUsageReport", "BytesWrittenCount", 10usize, |this| ((**(this as *const *const IProcessDiskUsageReport_Vtbl)).BytesWrittenCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherBytesCount(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDiskUsageReport", "OtherBytesCount", 11usize, |this| ((**(this as *const *const IProcessDiskUsageReport_Vtbl)).OtherBytesCount)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReadOperationCount,
+            WriteOperationCount,
+            OtherOperationCount,
+            BytesReadCount,
+            BytesWrittenCount,
+            OtherBytesCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessDiskUsageReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessMemoryUsage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessMemoryUsage", "GetReport", 6usize, |this| ((**(this as *const *const IProcessMemoryUsage_Vtbl)).GetReport)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetReport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessMemoryUsage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessMemoryUsageReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NonPagedPoolSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessMemoryUsageReport", "NonPagedPoolSizeInBytes", 6usize, |this| ((**(this as *const *const IProcessMemoryUsageReport_Vtbl)).NonPagedPoolSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageFaultCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessMemoryUsageReport", "PageFaultCount", 7usize, |this| ((**(this as *const *const IProcessMemoryUsageReport_Vtbl)).PageFaultCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageFileSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessMemoryUsageReport", "PageFileSizeInBytes", 8usize, |this| ((**(this as *const *const IProcessMemoryUsageReport_Vtbl)).PageFileSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PagedPoolSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessMemoryUsageReport", "PagedPoolSizeInBytes", 9usize, |this| ((**(this as *const *const IProcessMemoryUsageReport_Vtbl)).PagedPoolSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PeakNonPagedPoolSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessMemoryUsageReport", "PeakNonPagedPoolSizeInBytes", 10usize, |this| ((**(this as *const *const IProcessMemoryUsageReport_Vtbl)).PeakNonPagedPoolSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PeakPageFileSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessMemoryUsageReport", "PeakPageFileSizeInBytes", 11usize, |this| ((**(this as *const *const IProcessMemoryUsageReport_Vtbl)).PeakPageFileSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PeakPagedPoolSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessMemoryUsageReport", "PeakPagedPoolSizeInBytes", 12usize, |this| ((**(this as *const *const IProcessMemoryUsageReport_Vtbl)).PeakPagedPoolSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PeakVirtualMemorySizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessMemoryUsageReport", "PeakVirtualMemorySizeInBytes", 13usize, |this| ((**(this as *const *const IProcessMemoryUsageReport_Vtbl)).PeakVirtualMemorySizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PeakWorkingSetSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessMemoryUsageReport", "PeakWorkingSetSizeInBytes", 14usize, |this| ((**(this as *const *const IProcessMemoryUsageReport_Vtbl)).PeakWorkingSetSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivatePageCount(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessMemoryUsageReport", "PrivatePageCount", 15usize, |this| ((**(this as *const *const IProcessMemoryUsageReport_Vtbl)).PrivatePageCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VirtualMemorySizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessMemoryUsageReport", "VirtualMemorySizeInBytes", 16usize, |this| ((**(this as *const *const IProcessMemoryUsageReport_Vtbl)).VirtualMemorySizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WorkingSetSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessMemoryUsageReport", "WorkingSetSizeInBytes", 17usize, |this| ((**(this as *const *const IProcessMemoryUsageReport_Vtbl)).WorkingSetSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NonPagedPoolSizeInBytes,
+            PageFaultCount,
+            PageFileSizeInBytes,
+            PagedPoolSizeInBytes,
+            PeakNonPagedPoolSizeInBytes,
+            PeakPageFileSizeInBytes,
+            PeakPagedPoolSizeInBytes,
+            PeakVirtualMemorySizeInBytes,
+            PeakWorkingSetSizeInBytes,
+            PrivatePageCount,
+            VirtualMemorySizeInBytes,
+            WorkingSetSizeInBytes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessMemoryUsageReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemCpuUsage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemCpuUsage", "GetReport", 6usize, |this| ((**(this as *const *const ISystemCpuUsage_Vtbl)).GetReport)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetReport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemCpuUsage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemCpuUsageReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KernelTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemCpuUsageReport", "KernelTime", 6usize, |this| ((**(this as *const *const ISystemCpuUsageReport_Vtbl)).KernelTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemCpuUsageReport", "UserTime", 7usize, |this| ((**(this as *const *const ISystemCpuUsageReport_Vtbl)).UserTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IdleTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemCpuUsageReport", "IdleTime", 8usize, |this| ((**(this as *const *const ISystemCpuUsageReport_Vtbl)).IdleTime)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KernelTime, UserTime, IdleTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemCpuUsageReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemDiagnosticInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MemoryUsage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDiagnosticInfo", "MemoryUsage", 6usize, |this| ((**(this as *const *const ISystemDiagnosticInfo_Vtbl)).MemoryUsage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CpuUsage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDiagnosticInfo", "CpuUsage", 7usize, |this| ((**(this as *const *const ISystemDiagnosticInfo_Vtbl)).CpuUsage)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MemoryUsage, CpuUsage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemDiagnosticInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemDiagnosticInfoStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDiagnosticInfoStatics", "GetForCurrentSystem", 6usize, |this| ((**(this as *const *const ISystemDiagnosticInfoStatics_Vtbl)).GetForCurrentSystem)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentSystem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemDiagnosticInfoStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemDiagnosticInfoStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsArchitectureSupported(this: *mut core::ffi::c_void, r#type: super::ProcessorArchitecture, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDiagnosticInfoStatics2", "IsArchitectureSupported", 6usize, |this| ((**(this as *const *const ISystemDiagnosticInfoStatics2_Vtbl)).IsArchitectureSupported)(this, r#type, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredArchitecture(this: *mut core::ffi::c_void, result__: *mut super::ProcessorArchitecture) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemDiagnosticInfoStatics2", "PreferredArchitecture", 7usize, |this| ((**(this as *const *const ISystemDiagnosticInfoStatics2_Vtbl)).PreferredArchitecture)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsArchitectureSupported, PreferredArchitecture }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemDiagnosticInfoStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemMemoryUsage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMemoryUsage", "GetReport", 6usize, |this| ((**(this as *const *const ISystemMemoryUsage_Vtbl)).GetReport)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetReport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemMemoryUsage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemMemoryUsageReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TotalPhysicalSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMemoryUsageReport", "TotalPhysicalSizeInBytes", 6usize, |this| ((**(this as *const *const ISystemMemoryUsageReport_Vtbl)).TotalPhysicalSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvailableSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMemoryUsageReport", "AvailableSizeInBytes", 7usize, |this| ((**(this as *const *const ISystemMemoryUsageReport_Vtbl)).AvailableSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommittedSizeInBytes(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMemoryUsageReport", "CommittedSizeInBytes", 8usize, |this| ((**(this as *const *const ISystemMemoryUsageReport_Vtbl)).CommittedSizeInBytes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TotalPhysicalSizeInBytes,
+            AvailableSizeInBytes,
+            CommittedSizeInBytes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemMemoryUsageReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -39,3 +39,5 @@ unsafe impl windows_core::Interface for DisplayRequest {
 impl windows_core::RuntimeName for DisplayRequest {
     const NAME: &'static str = "Windows.System.Display.DisplayRequest";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
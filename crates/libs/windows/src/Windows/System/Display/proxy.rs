@@ -0,0 +1,15 @@
+impl windows_core::imp::ProxyVtable for IDisplayRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestActive(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayRequest", "RequestActive", 6usize, |this| ((**(this as *const *const IDisplayRequest_Vtbl)).RequestActive)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestRelease(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayRequest", "RequestRelease", 7usize, |this| ((**(this as *const *const IDisplayRequest_Vtbl)).RequestRelease)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestActive, RequestRelease }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
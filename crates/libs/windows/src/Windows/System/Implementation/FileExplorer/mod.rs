@@ -152,3 +152,5 @@ unsafe impl Send for SysStorageProviderEventReceivedEventArgs {}
 unsafe impl Sync for SysStorageProviderEventReceivedEventArgs {}
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,74 @@
+impl windows_core::imp::ProxyVtable for ISysStorageProviderEventReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Json(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISysStorageProviderEventReceivedEventArgs", "Json", 6usize, |this| ((**(this as *const *const ISysStorageProviderEventReceivedEventArgs_Vtbl)).Json)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Json }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISysStorageProviderEventReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISysStorageProviderEventReceivedEventArgsFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, json: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISysStorageProviderEventReceivedEventArgsFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const ISysStorageProviderEventReceivedEventArgsFactory_Vtbl)).CreateInstance)(this, json, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISysStorageProviderEventReceivedEventArgsFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISysStorageProviderEventSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISysStorageProviderEventSource", "EventReceived", 6usize, |this| ((**(this as *const *const ISysStorageProviderEventSource_Vtbl)).EventReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEventReceived(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISysStorageProviderEventSource", "RemoveEventReceived", 7usize, |this| ((**(this as *const *const ISysStorageProviderEventSource_Vtbl)).RemoveEventReceived)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EventReceived, RemoveEventReceived }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISysStorageProviderEventSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISysStorageProviderHandlerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHttpRequestProvider(this: *mut core::ffi::c_void, syncrootid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISysStorageProviderHandlerFactory", "GetHttpRequestProvider", 6usize, |this| ((**(this as *const *const ISysStorageProviderHandlerFactory_Vtbl)).GetHttpRequestProvider)(this, syncrootid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventSource(this: *mut core::ffi::c_void, syncrootid: core::mem::MaybeUninit<windows_core::HSTRING>, eventname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISysStorageProviderHandlerFactory", "GetEventSource", 7usize, |this| ((**(this as *const *const ISysStorageProviderHandlerFactory_Vtbl)).GetEventSource)(this, syncrootid, eventname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetHttpRequestProvider, GetEventSource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISysStorageProviderHandlerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Web_Http")]
+impl windows_core::imp::ProxyVtable for ISysStorageProviderHttpRequestProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Web_Http")]
+        unsafe extern "system" fn SendRequestAsync(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISysStorageProviderHttpRequestProvider", "SendRequestAsync", 6usize, |this| ((**(this as *const *const ISysStorageProviderHttpRequestProvider_Vtbl)).SendRequestAsync)(this, request, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Web_Http")]
+            SendRequestAsync,
+            #[cfg(not(feature = "Web_Http"))]
+            SendRequestAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISysStorageProviderHttpRequestProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
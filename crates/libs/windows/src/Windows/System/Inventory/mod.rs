@@ -88,3 +88,5 @@ impl windows_core::RuntimeName for InstalledDesktopApp {
 }
 unsafe impl Send for InstalledDesktopApp {}
 unsafe impl Sync for InstalledDesktopApp {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
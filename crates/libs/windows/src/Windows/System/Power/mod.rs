@@ -428,6 +428,7 @@ impl BatteryStatus {
 impl windows_core::TypeKind for BatteryStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BatteryStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BatteryStatus").field(&self.0).finish()
@@ -447,6 +448,7 @@ impl EnergySaverStatus {
 impl windows_core::TypeKind for EnergySaverStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnergySaverStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnergySaverStatus").field(&self.0).finish()
@@ -466,6 +468,7 @@ impl PowerSupplyStatus {
 impl windows_core::TypeKind for PowerSupplyStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PowerSupplyStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PowerSupplyStatus").field(&self.0).finish()
@@ -474,3 +477,5 @@ impl core::fmt::Debug for PowerSupplyStatus {
 impl windows_core::RuntimeType for PowerSupplyStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.System.Power.PowerSupplyStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,271 @@
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IBackgroundEnergyManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn LowUsageLevel(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundEnergyManagerStatics", "LowUsageLevel", 6usize, |this| ((**(this as *const *const IBackgroundEnergyManagerStatics_Vtbl)).LowUsageLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn NearMaxAcceptableUsageLevel(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundEnergyManagerStatics", "NearMaxAcceptableUsageLevel", 7usize, |this| ((**(this as *const *const IBackgroundEnergyManagerStatics_Vtbl)).NearMaxAcceptableUsageLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn MaxAcceptableUsageLevel(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundEnergyManagerStatics", "MaxAcceptableUsageLevel", 8usize, |this| ((**(this as *const *const IBackgroundEnergyManagerStatics_Vtbl)).MaxAcceptableUsageLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ExcessiveUsageLevel(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundEnergyManagerStatics", "ExcessiveUsageLevel", 9usize, |this| ((**(this as *const *const IBackgroundEnergyManagerStatics_Vtbl)).ExcessiveUsageLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn NearTerminationUsageLevel(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundEnergyManagerStatics", "NearTerminationUsageLevel", 10usize, |this| ((**(this as *const *const IBackgroundEnergyManagerStatics_Vtbl)).NearTerminationUsageLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TerminationUsageLevel(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundEnergyManagerStatics", "TerminationUsageLevel", 11usize, |this| ((**(this as *const *const IBackgroundEnergyManagerStatics_Vtbl)).TerminationUsageLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RecentEnergyUsage(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundEnergyManagerStatics", "RecentEnergyUsage", 12usize, |this| ((**(this as *const *const IBackgroundEnergyManagerStatics_Vtbl)).RecentEnergyUsage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RecentEnergyUsageLevel(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundEnergyManagerStatics", "RecentEnergyUsageLevel", 13usize, |this| ((**(this as *const *const IBackgroundEnergyManagerStatics_Vtbl)).RecentEnergyUsageLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RecentEnergyUsageIncreased(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundEnergyManagerStatics", "RecentEnergyUsageIncreased", 14usize, |this| ((**(this as *const *const IBackgroundEnergyManagerStatics_Vtbl)).RecentEnergyUsageIncreased)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveRecentEnergyUsageIncreased(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundEnergyManagerStatics", "RemoveRecentEnergyUsageIncreased", 15usize, |this| ((**(this as *const *const IBackgroundEnergyManagerStatics_Vtbl)).RemoveRecentEnergyUsageIncreased)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RecentEnergyUsageReturnedToLow(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundEnergyManagerStatics", "RecentEnergyUsageReturnedToLow", 16usize, |this| ((**(this as *const *const IBackgroundEnergyManagerStatics_Vtbl)).RecentEnergyUsageReturnedToLow)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveRecentEnergyUsageReturnedToLow(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundEnergyManagerStatics", "RemoveRecentEnergyUsageReturnedToLow", 17usize, |this| ((**(this as *const *const IBackgroundEnergyManagerStatics_Vtbl)).RemoveRecentEnergyUsageReturnedToLow)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            LowUsageLevel,
+            #[cfg(not(feature = "deprecated"))]
+            LowUsageLevel: 0,
+            #[cfg(feature = "deprecated")]
+            NearMaxAcceptableUsageLevel,
+            #[cfg(not(feature = "deprecated"))]
+            NearMaxAcceptableUsageLevel: 0,
+            #[cfg(feature = "deprecated")]
+            MaxAcceptableUsageLevel,
+            #[cfg(not(feature = "deprecated"))]
+            MaxAcceptableUsageLevel: 0,
+            #[cfg(feature = "deprecated")]
+            ExcessiveUsageLevel,
+            #[cfg(not(feature = "deprecated"))]
+            ExcessiveUsageLevel: 0,
+            #[cfg(feature = "deprecated")]
+            NearTerminationUsageLevel,
+            #[cfg(not(feature = "deprecated"))]
+            NearTerminationUsageLevel: 0,
+            #[cfg(feature = "deprecated")]
+            TerminationUsageLevel,
+            #[cfg(not(feature = "deprecated"))]
+            TerminationUsageLevel: 0,
+            #[cfg(feature = "deprecated")]
+            RecentEnergyUsage,
+            #[cfg(not(feature = "deprecated"))]
+            RecentEnergyUsage: 0,
+            #[cfg(feature = "deprecated")]
+            RecentEnergyUsageLevel,
+            #[cfg(not(feature = "deprecated"))]
+            RecentEnergyUsageLevel: 0,
+            #[cfg(feature = "deprecated")]
+            RecentEnergyUsageIncreased,
+            #[cfg(not(feature = "deprecated"))]
+            RecentEnergyUsageIncreased: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveRecentEnergyUsageIncreased,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveRecentEnergyUsageIncreased: 0,
+            #[cfg(feature = "deprecated")]
+            RecentEnergyUsageReturnedToLow,
+            #[cfg(not(feature = "deprecated"))]
+            RecentEnergyUsageReturnedToLow: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveRecentEnergyUsageReturnedToLow,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveRecentEnergyUsageReturnedToLow: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundEnergyManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IForegroundEnergyManagerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn LowUsageLevel(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForegroundEnergyManagerStatics", "LowUsageLevel", 6usize, |this| ((**(this as *const *const IForegroundEnergyManagerStatics_Vtbl)).LowUsageLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn NearMaxAcceptableUsageLevel(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForegroundEnergyManagerStatics", "NearMaxAcceptableUsageLevel", 7usize, |this| ((**(this as *const *const IForegroundEnergyManagerStatics_Vtbl)).NearMaxAcceptableUsageLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn MaxAcceptableUsageLevel(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForegroundEnergyManagerStatics", "MaxAcceptableUsageLevel", 8usize, |this| ((**(this as *const *const IForegroundEnergyManagerStatics_Vtbl)).MaxAcceptableUsageLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ExcessiveUsageLevel(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForegroundEnergyManagerStatics", "ExcessiveUsageLevel", 9usize, |this| ((**(this as *const *const IForegroundEnergyManagerStatics_Vtbl)).ExcessiveUsageLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RecentEnergyUsage(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForegroundEnergyManagerStatics", "RecentEnergyUsage", 10usize, |this| ((**(this as *const *const IForegroundEnergyManagerStatics_Vtbl)).RecentEnergyUsage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RecentEnergyUsageLevel(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForegroundEnergyManagerStatics", "RecentEnergyUsageLevel", 11usize, |this| ((**(this as *const *const IForegroundEnergyManagerStatics_Vtbl)).RecentEnergyUsageLevel)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RecentEnergyUsageIncreased(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForegroundEnergyManagerStatics", "RecentEnergyUsageIncreased", 12usize, |this| ((**(this as *const *const IForegroundEnergyManagerStatics_Vtbl)).RecentEnergyUsageIncreased)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveRecentEnergyUsageIncreased(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForegroundEnergyManagerStatics", "RemoveRecentEnergyUsageIncreased", 13usize, |this| ((**(this as *const *const IForegroundEnergyManagerStatics_Vtbl)).RemoveRecentEnergyUsageIncreased)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RecentEnergyUsageReturnedToLow(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForegroundEnergyManagerStatics", "RecentEnergyUsageReturnedToLow", 14usize, |this| ((**(this as *const *const IForegroundEnergyManagerStatics_Vtbl)).RecentEnergyUsageReturnedToLow)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveRecentEnergyUsageReturnedToLow(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForegroundEnergyManagerStatics", "RemoveRecentEnergyUsageReturnedToLow", 15usize, |this| ((**(this as *const *const IForegroundEnergyManagerStatics_Vtbl)).RemoveRecentEnergyUsageReturnedToLow)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            LowUsageLevel,
+            #[cfg(not(feature = "deprecated"))]
+            LowUsageLevel: 0,
+            #[cfg(feature = "deprecated")]
+            NearMaxAcceptableUsageLevel,
+            #[cfg(not(feature = "deprecated"))]
+            NearMaxAcceptableUsageLevel: 0,
+            #[cfg(feature = "deprecated")]
+            MaxAcceptableUsageLevel,
+            #[cfg(not(feature = "deprecated"))]
+            MaxAcceptableUsageLevel: 0,
+            #[cfg(feature = "deprecated")]
+            ExcessiveUsageLevel,
+            #[cfg(not(feature = "deprecated"))]
+            ExcessiveUsageLevel: 0,
+            #[cfg(feature = "deprecated")]
+            RecentEnergyUsage,
+            #[cfg(not(feature = "deprecated"))]
+            RecentEnergyUsage: 0,
+            #[cfg(feature = "deprecated")]
+            RecentEnergyUsageLevel,
+            #[cfg(not(feature = "deprecated"))]
+            RecentEnergyUsageLevel: 0,
+            #[cfg(feature = "deprecated")]
+            RecentEnergyUsageIncreased,
+            #[cfg(not(feature = "deprecated"))]
+            RecentEnergyUsageIncreased: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveRecentEnergyUsageIncreased,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveRecentEnergyUsageIncreased: 0,
+            #[cfg(feature = "deprecated")]
+            RecentEnergyUsageReturnedToLow,
+            #[cfg(not(feature = "deprecated"))]
+            RecentEnergyUsageReturnedToLow: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveRecentEnergyUsageReturnedToLow,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveRecentEnergyUsageReturnedToLow: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IForegroundEnergyManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPowerManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnergySaverStatus(this: *mut core::ffi::c_void, result__: *mut EnergySaverStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "EnergySaverStatus", 6usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).EnergySaverStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnergySaverStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "EnergySaverStatusChanged", 7usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).EnergySaverStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnergySaverStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "RemoveEnergySaverStatusChanged", 8usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).RemoveEnergySaverStatusChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn BatteryStatus(this: *mut core::ffi::c_void, result__: *mut BatteryStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "BatteryStatus", 9usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).BatteryStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BatteryStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "BatteryStatusChanged", 10usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).BatteryStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBatteryStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "RemoveBatteryStatusChanged", 11usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).RemoveBatteryStatusChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerSupplyStatus(this: *mut core::ffi::c_void, result__: *mut PowerSupplyStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "PowerSupplyStatus", 12usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).PowerSupplyStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowerSupplyStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "PowerSupplyStatusChanged", 13usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).PowerSupplyStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePowerSupplyStatusChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "RemovePowerSupplyStatusChanged", 14usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).RemovePowerSupplyStatusChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemainingChargePercent(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "RemainingChargePercent", 15usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).RemainingChargePercent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemainingChargePercentChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "RemainingChargePercentChanged", 16usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).RemainingChargePercentChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemainingChargePercentChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "RemoveRemainingChargePercentChanged", 17usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).RemoveRemainingChargePercentChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemainingDischargeTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "RemainingDischargeTime", 18usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).RemainingDischargeTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemainingDischargeTimeChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "RemainingDischargeTimeChanged", 19usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).RemainingDischargeTimeChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemainingDischargeTimeChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerManagerStatics", "RemoveRemainingDischargeTimeChanged", 20usize, |this| ((**(this as *const *const IPowerManagerStatics_Vtbl)).RemoveRemainingDischargeTimeChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnergySaverStatus,
+            EnergySaverStatusChanged,
+            RemoveEnergySaverStatusChanged,
+            BatteryStatus,
+            BatteryStatusChanged,
+            RemoveBatteryStatusChanged,
+            PowerSupplyStatus,
+            PowerSupplyStatusChanged,
+            RemovePowerSupplyStatusChanged,
+            RemainingChargePercent,
+            RemainingChargePercentChanged,
+            RemoveRemainingChargePercentChanged,
+            RemainingDischargeTime,
+            RemainingDischargeTimeChanged,
+            RemoveRemainingDischargeTimeChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPowerManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
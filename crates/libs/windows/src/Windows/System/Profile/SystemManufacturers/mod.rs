@@ -209,3 +209,5 @@ impl SystemSupportInfo {
 impl windows_core::RuntimeName for SystemSupportInfo {
     const NAME: &'static str = "Windows.System.Profile.SystemManufacturers.SystemSupportInfo";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
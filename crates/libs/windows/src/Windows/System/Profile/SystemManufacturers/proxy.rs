@@ -0,0 +1,96 @@
+impl windows_core::imp::ProxyVtable for IOemSupportInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SupportLink(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOemSupportInfo", "SupportLink", 6usize, |this| ((**(this as *const *const IOemSupportInfo_Vtbl)).SupportLink)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportAppLink(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOemSupportInfo", "SupportAppLink", 7usize, |this| ((**(this as *const *const IOemSupportInfo_Vtbl)).SupportAppLink)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportProvider(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOemSupportInfo", "SupportProvider", 8usize, |this| ((**(this as *const *const IOemSupportInfo_Vtbl)).SupportProvider)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SupportLink, SupportAppLink, SupportProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOemSupportInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmbiosInformationStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SerialNumber(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmbiosInformationStatics", "SerialNumber", 6usize, |this| ((**(this as *const *const ISmbiosInformationStatics_Vtbl)).SerialNumber)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SerialNumber }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmbiosInformationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemSupportDeviceInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OperatingSystem(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemSupportDeviceInfo", "OperatingSystem", 6usize, |this| ((**(this as *const *const ISystemSupportDeviceInfo_Vtbl)).OperatingSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemSupportDeviceInfo", "FriendlyName", 7usize, |this| ((**(this as *const *const ISystemSupportDeviceInfo_Vtbl)).FriendlyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemManufacturer(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemSupportDeviceInfo", "SystemManufacturer", 8usize, |this| ((**(this as *const *const ISystemSupportDeviceInfo_Vtbl)).SystemManufacturer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemProductName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemSupportDeviceInfo", "SystemProductName", 9usize, |this| ((**(this as *const *const ISystemSupportDeviceInfo_Vtbl)).SystemProductName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemSku(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemSupportDeviceInfo", "SystemSku", 10usize, |this| ((**(this as *const *const ISystemSupportDeviceInfo_Vtbl)).SystemSku)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemHardwareVersion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemSupportDeviceInfo", "SystemHardwareVersion", 11usize, |this| ((**(this as *const *const ISystemSupportDeviceInfo_Vtbl)).SystemHardwareVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemFirmwareVersion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemSupportDeviceInfo", "SystemFirmwareVersion", 12usize, |this| ((**(this as *const *const ISystemSupportDeviceInfo_Vtbl)).SystemFirmwareVersion)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OperatingSystem,
+            FriendlyName,
+            SystemManufacturer,
+            SystemProductName,
+            SystemSku,
+            SystemHardwareVersion,
+            SystemFirmwareVersion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemSupportDeviceInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemSupportInfoStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LocalSystemEdition(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemSupportInfoStatics", "LocalSystemEdition", 6usize, |this| ((**(this as *const *const ISystemSupportInfoStatics_Vtbl)).LocalSystemEdition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OemSupportInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemSupportInfoStatics", "OemSupportInfo", 7usize, |this| ((**(this as *const *const ISystemSupportInfoStatics_Vtbl)).OemSupportInfo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LocalSystemEdition, OemSupportInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemSupportInfoStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemSupportInfoStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LocalDeviceInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemSupportInfoStatics2", "LocalDeviceInfo", 6usize, |this| ((**(this as *const *const ISystemSupportInfoStatics2_Vtbl)).LocalDeviceInfo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LocalDeviceInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemSupportInfoStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
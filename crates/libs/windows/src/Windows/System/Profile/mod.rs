@@ -877,6 +877,7 @@ impl PlatformAutomaticAppSignInPolicy {
 impl windows_core::TypeKind for PlatformAutomaticAppSignInPolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlatformAutomaticAppSignInPolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlatformAutomaticAppSignInPolicy").field(&self.0).finish()
@@ -897,6 +898,7 @@ impl PlatformDataCollectionLevel {
 impl windows_core::TypeKind for PlatformDataCollectionLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlatformDataCollectionLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlatformDataCollectionLevel").field(&self.0).finish()
@@ -917,6 +919,7 @@ impl SystemIdentificationSource {
 impl windows_core::TypeKind for SystemIdentificationSource {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SystemIdentificationSource {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SystemIdentificationSource").field(&self.0).finish()
@@ -936,6 +939,7 @@ impl SystemOutOfBoxExperienceState {
 impl windows_core::TypeKind for SystemOutOfBoxExperienceState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SystemOutOfBoxExperienceState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SystemOutOfBoxExperienceState").field(&self.0).finish()
@@ -954,6 +958,7 @@ impl UnsupportedAppRequirementReasons {
 impl windows_core::TypeKind for UnsupportedAppRequirementReasons {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UnsupportedAppRequirementReasons {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UnsupportedAppRequirementReasons").field(&self.0).finish()
@@ -995,3 +1000,5 @@ impl core::ops::Not for UnsupportedAppRequirementReasons {
 impl windows_core::RuntimeType for UnsupportedAppRequirementReasons {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.System.Profile.UnsupportedAppRequirementReasons;u4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,466 @@
+impl windows_core::imp::ProxyVtable for IAnalyticsInfoStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VersionInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalyticsInfoStatics", "VersionInfo", 6usize, |this| ((**(this as *const *const IAnalyticsInfoStatics_Vtbl)).VersionInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceForm(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalyticsInfoStatics", "DeviceForm", 7usize, |this| ((**(this as *const *const IAnalyticsInfoStatics_Vtbl)).DeviceForm)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, VersionInfo, DeviceForm }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnalyticsInfoStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAnalyticsInfoStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetSystemPropertiesAsync(this: *mut core::ffi::c_void, attributenames: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalyticsInfoStatics2", "GetSystemPropertiesAsync", 6usize, |this| ((**(this as *const *const IAnalyticsInfoStatics2_Vtbl)).GetSystemPropertiesAsync)(this, attributenames, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetSystemPropertiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetSystemPropertiesAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnalyticsInfoStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAnalyticsVersionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceFamily(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalyticsVersionInfo", "DeviceFamily", 6usize, |this| ((**(this as *const *const IAnalyticsVersionInfo_Vtbl)).DeviceFamily)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceFamilyVersion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalyticsVersionInfo", "DeviceFamilyVersion", 7usize, |this| ((**(this as *const *const IAnalyticsVersionInfo_Vtbl)).DeviceFamilyVersion)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceFamily, DeviceFamilyVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnalyticsVersionInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAnalyticsVersionInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProductName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalyticsVersionInfo2", "ProductName", 6usize, |this| ((**(this as *const *const IAnalyticsVersionInfo2_Vtbl)).ProductName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProductName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnalyticsVersionInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppApplicabilityStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetUnsupportedAppRequirements(this: *mut core::ffi::c_void, capabilities: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppApplicabilityStatics", "GetUnsupportedAppRequirements", 6usize, |this| ((**(this as *const *const IAppApplicabilityStatics_Vtbl)).GetUnsupportedAppRequirements)(this, capabilities, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetUnsupportedAppRequirements,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetUnsupportedAppRequirements: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppApplicabilityStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEducationSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEducationEnvironment(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEducationSettingsStatics", "IsEducationEnvironment", 6usize, |this| ((**(this as *const *const IEducationSettingsStatics_Vtbl)).IsEducationEnvironment)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsEducationEnvironment }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEducationSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IHardwareIdentificationStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetPackageSpecificToken(this: *mut core::ffi::c_void, nonce: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareIdentificationStatics", "GetPackageSpecificToken", 6usize, |this| ((**(this as *const *const IHardwareIdentificationStatics_Vtbl)).GetPackageSpecificToken)(this, nonce, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            GetPackageSpecificToken,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetPackageSpecificToken: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHardwareIdentificationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IHardwareToken_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareToken", "Id", 6usize, |this| ((**(this as *const *const IHardwareToken_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Signature(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareToken", "Signature", 7usize, |this| ((**(this as *const *const IHardwareToken_Vtbl)).Signature)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Certificate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareToken", "Certificate", 8usize, |this| ((**(this as *const *const IHardwareToken_Vtbl)).Certificate)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Id,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Id: 0,
+            #[cfg(feature = "Storage_Streams")]
+            Signature,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Signature: 0,
+            #[cfg(feature = "Storage_Streams")]
+            Certificate,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Certificate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHardwareToken as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKnownRetailInfoPropertiesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RetailAccessCode(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "RetailAccessCode", 6usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).RetailAccessCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManufacturerName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "ManufacturerName", 7usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).ManufacturerName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModelName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "ModelName", 8usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).ModelName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayModelName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "DisplayModelName", 9usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).DisplayModelName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Price(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "Price", 10usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).Price)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFeatured(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "IsFeatured", 11usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).IsFeatured)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormFactor(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "FormFactor", 12usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).FormFactor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScreenSize(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "ScreenSize", 13usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).ScreenSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Weight(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "Weight", 14usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).Weight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "DisplayDescription", 15usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).DisplayDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BatteryLifeDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "BatteryLifeDescription", 16usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).BatteryLifeDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessorDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "ProcessorDescription", 17usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).ProcessorDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Memory(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "Memory", 18usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).Memory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StorageDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "StorageDescription", 19usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).StorageDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GraphicsDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "GraphicsDescription", 20usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).GraphicsDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrontCameraDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "FrontCameraDescription", 21usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).FrontCameraDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RearCameraDescription(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "RearCameraDescription", 22usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).RearCameraDescription)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasNfc(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "HasNfc", 23usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).HasNfc)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasSdSlot(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "HasSdSlot", 24usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).HasSdSlot)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasOpticalDrive(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "HasOpticalDrive", 25usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).HasOpticalDrive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOfficeInstalled(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "IsOfficeInstalled", 26usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).IsOfficeInstalled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WindowsEdition(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRetailInfoPropertiesStatics", "WindowsEdition", 27usize, |this| ((**(this as *const *const IKnownRetailInfoPropertiesStatics_Vtbl)).WindowsEdition)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RetailAccessCode,
+            ManufacturerName,
+            ModelName,
+            DisplayModelName,
+            Price,
+            IsFeatured,
+            FormFactor,
+            ScreenSize,
+            Weight,
+            DisplayDescription,
+            BatteryLifeDescription,
+            ProcessorDescription,
+            Memory,
+            StorageDescription,
+            GraphicsDescription,
+            FrontCameraDescription,
+            RearCameraDescription,
+            HasNfc,
+            HasSdSlot,
+            HasOpticalDrive,
+            IsOfficeInstalled,
+            WindowsEdition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownRetailInfoPropertiesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlatformAutomaticAppSignInManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Policy(this: *mut core::ffi::c_void, result__: *mut PlatformAutomaticAppSignInPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformAutomaticAppSignInManagerStatics", "Policy", 6usize, |this| ((**(this as *const *const IPlatformAutomaticAppSignInManagerStatics_Vtbl)).Policy)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Policy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlatformAutomaticAppSignInManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlatformDiagnosticsAndUsageDataSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CollectionLevel(this: *mut core::ffi::c_void, result__: *mut PlatformDataCollectionLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticsAndUsageDataSettingsStatics", "CollectionLevel", 6usize, |this| ((**(this as *const *const IPlatformDiagnosticsAndUsageDataSettingsStatics_Vtbl)).CollectionLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CollectionLevelChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticsAndUsageDataSettingsStatics", "CollectionLevelChanged", 7usize, |this| ((**(this as *const *const IPlatformDiagnosticsAndUsageDataSettingsStatics_Vtbl)).CollectionLevelChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCollectionLevelChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticsAndUsageDataSettingsStatics", "RemoveCollectionLevelChanged", 8usize, |this| ((**(this as *const *const IPlatformDiagnosticsAndUsageDataSettingsStatics_Vtbl)).RemoveCollectionLevelChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanCollectDiagnostics(this: *mut core::ffi::c_void, level: PlatformDataCollectionLevel, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlatformDiagnosticsAndUsageDataSettingsStatics", "CanCollectDiagnostics", 9usize, |this| ((**(this as *const *const IPlatformDiagnosticsAndUsageDataSettingsStatics_Vtbl)).CanCollectDiagnostics)(this, level, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CollectionLevel,
+            CollectionLevelChanged,
+            RemoveCollectionLevelChanged,
+            CanCollectDiagnostics,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlatformDiagnosticsAndUsageDataSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRetailInfoStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDemoModeEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRetailInfoStatics", "IsDemoModeEnabled", 6usize, |this| ((**(this as *const *const IRetailInfoStatics_Vtbl)).IsDemoModeEnabled)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRetailInfoStatics", "Properties", 7usize, |this| ((**(this as *const *const IRetailInfoStatics_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsDemoModeEnabled,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRetailInfoStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISharedModeSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedModeSettingsStatics", "IsEnabled", 6usize, |this| ((**(this as *const *const ISharedModeSettingsStatics_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISharedModeSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISharedModeSettingsStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShouldAvoidLocalStorage(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedModeSettingsStatics2", "ShouldAvoidLocalStorage", 6usize, |this| ((**(this as *const *const ISharedModeSettingsStatics2_Vtbl)).ShouldAvoidLocalStorage)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShouldAvoidLocalStorage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISharedModeSettingsStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISmartAppControlPolicyStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartAppControlPolicyStatics", "IsEnabled", 6usize, |this| ((**(this as *const *const ISmartAppControlPolicyStatics_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Changed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartAppControlPolicyStatics", "Changed", 7usize, |this| ((**(this as *const *const ISmartAppControlPolicyStatics_Vtbl)).Changed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmartAppControlPolicyStatics", "RemoveChanged", 8usize, |this| ((**(this as *const *const ISmartAppControlPolicyStatics_Vtbl)).RemoveChanged)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsEnabled, Changed, RemoveChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmartAppControlPolicyStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ISystemIdentificationInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemIdentificationInfo", "Id", 6usize, |this| ((**(this as *const *const ISystemIdentificationInfo_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut SystemIdentificationSource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemIdentificationInfo", "Source", 7usize, |this| ((**(this as *const *const ISystemIdentificationInfo_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            Id,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Id: 0,
+            Source,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemIdentificationInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemIdentificationStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSystemIdForPublisher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemIdentificationStatics", "GetSystemIdForPublisher", 6usize, |this| ((**(this as *const *const ISystemIdentificationStatics_Vtbl)).GetSystemIdForPublisher)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSystemIdForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemIdentificationStatics", "GetSystemIdForUser", 7usize, |this| ((**(this as *const *const ISystemIdentificationStatics_Vtbl)).GetSystemIdForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSystemIdForPublisher, GetSystemIdForUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemIdentificationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemSetupInfoStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OutOfBoxExperienceState(this: *mut core::ffi::c_void, result__: *mut SystemOutOfBoxExperienceState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemSetupInfoStatics", "OutOfBoxExperienceState", 6usize, |this| ((**(this as *const *const ISystemSetupInfoStatics_Vtbl)).OutOfBoxExperienceState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutOfBoxExperienceStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemSetupInfoStatics", "OutOfBoxExperienceStateChanged", 7usize, |this| ((**(this as *const *const ISystemSetupInfoStatics_Vtbl)).OutOfBoxExperienceStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOutOfBoxExperienceStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemSetupInfoStatics", "RemoveOutOfBoxExperienceStateChanged", 8usize, |this| ((**(this as *const *const ISystemSetupInfoStatics_Vtbl)).RemoveOutOfBoxExperienceStateChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OutOfBoxExperienceState,
+            OutOfBoxExperienceStateChanged,
+            RemoveOutOfBoxExperienceStateChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemSetupInfoStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUnsupportedAppRequirement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Requirement(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnsupportedAppRequirement", "Requirement", 6usize, |this| ((**(this as *const *const IUnsupportedAppRequirement_Vtbl)).Requirement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reasons(this: *mut core::ffi::c_void, result__: *mut UnsupportedAppRequirementReasons) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnsupportedAppRequirement", "Reasons", 7usize, |this| ((**(this as *const *const IUnsupportedAppRequirement_Vtbl)).Reasons)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Requirement, Reasons }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUnsupportedAppRequirement as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsIntegrityPolicyStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsIntegrityPolicyStatics", "IsEnabled", 6usize, |this| ((**(this as *const *const IWindowsIntegrityPolicyStatics_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabledForTrial(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsIntegrityPolicyStatics", "IsEnabledForTrial", 7usize, |this| ((**(this as *const *const IWindowsIntegrityPolicyStatics_Vtbl)).IsEnabledForTrial)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanDisable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsIntegrityPolicyStatics", "CanDisable", 8usize, |this| ((**(this as *const *const IWindowsIntegrityPolicyStatics_Vtbl)).CanDisable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDisableSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsIntegrityPolicyStatics", "IsDisableSupported", 9usize, |this| ((**(this as *const *const IWindowsIntegrityPolicyStatics_Vtbl)).IsDisableSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsIntegrityPolicyStatics", "PolicyChanged", 10usize, |this| ((**(this as *const *const IWindowsIntegrityPolicyStatics_Vtbl)).PolicyChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePolicyChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsIntegrityPolicyStatics", "RemovePolicyChanged", 11usize, |this| ((**(this as *const *const IWindowsIntegrityPolicyStatics_Vtbl)).RemovePolicyChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEnabled,
+            IsEnabledForTrial,
+            CanDisable,
+            IsDisableSupported,
+            PolicyChanged,
+            RemovePolicyChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsIntegrityPolicyStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -129,6 +129,7 @@ impl RemoteKeyEventAttributes {
 impl windows_core::TypeKind for RemoteKeyEventAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteKeyEventAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteKeyEventAttributes").field(&self.0).finish()
@@ -180,6 +181,7 @@ impl RemoteTextConnectionOptions {
 impl windows_core::TypeKind for RemoteTextConnectionOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteTextConnectionOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteTextConnectionOptions").field(&self.0).finish()
@@ -287,3 +289,5 @@ pub struct RemoteTextConnectionDataHandler_Vtbl {
     pub base__: windows_core::IUnknown_Vtbl,
     pub Invoke: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *const u8, *mut bool) -> windows_core::HRESULT,
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
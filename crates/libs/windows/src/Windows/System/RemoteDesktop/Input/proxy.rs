@@ -0,0 +1,67 @@
+impl windows_core::imp::ProxyVtable for IRemoteTextConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteTextConnection", "IsEnabled", 6usize, |this| ((**(this as *const *const IRemoteTextConnection_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteTextConnection", "SetIsEnabled", 7usize, |this| ((**(this as *const *const IRemoteTextConnection_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterThread(this: *mut core::ffi::c_void, threadid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteTextConnection", "RegisterThread", 8usize, |this| ((**(this as *const *const IRemoteTextConnection_Vtbl)).RegisterThread)(this, threadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterThread(this: *mut core::ffi::c_void, threadid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteTextConnection", "UnregisterThread", 9usize, |this| ((**(this as *const *const IRemoteTextConnection_Vtbl)).UnregisterThread)(this, threadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportDataReceived(this: *mut core::ffi::c_void, pduData_array_size: u32, pdudata: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteTextConnection", "ReportDataReceived", 10usize, |this| ((**(this as *const *const IRemoteTextConnection_Vtbl)).ReportDataReceived)(this, pduData_array_size, pdudata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEnabled,
+            SetIsEnabled,
+            RegisterThread,
+            UnregisterThread,
+            ReportDataReceived,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteTextConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteTextConnection2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportPredictedKeyEvent(this: *mut core::ffi::c_void, scancode: u16, attributes: RemoteKeyEventAttributes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteTextConnection2", "ReportPredictedKeyEvent", 6usize, |this| ((**(this as *const *const IRemoteTextConnection2_Vtbl)).ReportPredictedKeyEvent)(this, scancode, attributes), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportPredictedKeyEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteTextConnection2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteTextConnectionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, connectionid: windows_core::GUID, pduforwarder: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteTextConnectionFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IRemoteTextConnectionFactory_Vtbl)).CreateInstance)(this, connectionid, pduforwarder, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteTextConnectionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteTextConnectionFactory2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, connectionid: windows_core::GUID, pduforwarder: *mut core::ffi::c_void, options: RemoteTextConnectionOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteTextConnectionFactory2", "CreateInstance", 6usize, |this| ((**(this as *const *const IRemoteTextConnectionFactory2_Vtbl)).CreateInstance)(this, connectionid, pduforwarder, options, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteTextConnectionFactory2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
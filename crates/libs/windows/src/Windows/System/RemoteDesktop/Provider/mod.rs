@@ -317,6 +317,7 @@ impl RemoteDesktopConnectionStatus {
 impl windows_core::TypeKind for RemoteDesktopConnectionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteDesktopConnectionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteDesktopConnectionStatus").field(&self.0).finish()
@@ -338,6 +339,7 @@ impl RemoteDesktopLocalAction {
 impl windows_core::TypeKind for RemoteDesktopLocalAction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteDesktopLocalAction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteDesktopLocalAction").field(&self.0).finish()
@@ -346,3 +348,5 @@ impl core::fmt::Debug for RemoteDesktopLocalAction {
 impl windows_core::RuntimeType for RemoteDesktopLocalAction {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.System.RemoteDesktop.Provider.RemoteDesktopLocalAction;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,156 @@
+impl windows_core::imp::ProxyVtable for IPerformLocalActionRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Action(this: *mut core::ffi::c_void, result__: *mut RemoteDesktopLocalAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerformLocalActionRequestedEventArgs", "Action", 6usize, |this| ((**(this as *const *const IPerformLocalActionRequestedEventArgs_Vtbl)).Action)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Action }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPerformLocalActionRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteDesktopConnectionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetConnectionStatus(this: *mut core::ffi::c_void, value: RemoteDesktopConnectionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopConnectionInfo", "SetConnectionStatus", 6usize, |this| ((**(this as *const *const IRemoteDesktopConnectionInfo_Vtbl)).SetConnectionStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwitchToLocalSession(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopConnectionInfo", "SwitchToLocalSession", 7usize, |this| ((**(this as *const *const IRemoteDesktopConnectionInfo_Vtbl)).SwitchToLocalSession)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetConnectionStatus, SwitchToLocalSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDesktopConnectionInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteDesktopConnectionInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PerformLocalActionFromRemote(this: *mut core::ffi::c_void, action: RemoteDesktopLocalAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopConnectionInfo2", "PerformLocalActionFromRemote", 6usize, |this| ((**(this as *const *const IRemoteDesktopConnectionInfo2_Vtbl)).PerformLocalActionFromRemote)(this, action), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PerformLocalActionFromRemote }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDesktopConnectionInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI")]
+impl windows_core::imp::ProxyVtable for IRemoteDesktopConnectionInfoStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn GetForLaunchUri(this: *mut core::ffi::c_void, launchuri: *mut core::ffi::c_void, windowid: super::super::super::UI::WindowId, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopConnectionInfoStatics", "GetForLaunchUri", 6usize, |this| ((**(this as *const *const IRemoteDesktopConnectionInfoStatics_Vtbl)).GetForLaunchUri)(this, launchuri, windowid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI")]
+            GetForLaunchUri,
+            #[cfg(not(feature = "UI"))]
+            GetForLaunchUri: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDesktopConnectionInfoStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteDesktopConnectionRemoteInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportSwitched(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopConnectionRemoteInfo", "ReportSwitched", 6usize, |this| ((**(this as *const *const IRemoteDesktopConnectionRemoteInfo_Vtbl)).ReportSwitched)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwitchToLocalSessionRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopConnectionRemoteInfo", "SwitchToLocalSessionRequested", 7usize, |this| ((**(this as *const *const IRemoteDesktopConnectionRemoteInfo_Vtbl)).SwitchToLocalSessionRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSwitchToLocalSessionRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopConnectionRemoteInfo", "RemoveSwitchToLocalSessionRequested", 8usize, |this| ((**(this as *const *const IRemoteDesktopConnectionRemoteInfo_Vtbl)).RemoveSwitchToLocalSessionRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PerformLocalActionRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopConnectionRemoteInfo", "PerformLocalActionRequested", 9usize, |this| ((**(this as *const *const IRemoteDesktopConnectionRemoteInfo_Vtbl)).PerformLocalActionRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePerformLocalActionRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopConnectionRemoteInfo", "RemovePerformLocalActionRequested", 10usize, |this| ((**(this as *const *const IRemoteDesktopConnectionRemoteInfo_Vtbl)).RemovePerformLocalActionRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReportSwitched,
+            SwitchToLocalSessionRequested,
+            RemoveSwitchToLocalSessionRequested,
+            PerformLocalActionRequested,
+            RemovePerformLocalActionRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDesktopConnectionRemoteInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteDesktopConnectionRemoteInfoStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSwitchSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopConnectionRemoteInfoStatics", "IsSwitchSupported", 6usize, |this| ((**(this as *const *const IRemoteDesktopConnectionRemoteInfoStatics_Vtbl)).IsSwitchSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForLaunchUri(this: *mut core::ffi::c_void, launchuri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopConnectionRemoteInfoStatics", "GetForLaunchUri", 7usize, |this| ((**(this as *const *const IRemoteDesktopConnectionRemoteInfoStatics_Vtbl)).GetForLaunchUri)(this, launchuri, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSwitchSupported, GetForLaunchUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDesktopConnectionRemoteInfoStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteDesktopInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopInfo", "DisplayName", 6usize, |this| ((**(this as *const *const IRemoteDesktopInfo_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopInfo", "Id", 7usize, |this| ((**(this as *const *const IRemoteDesktopInfo_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayName, Id }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDesktopInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteDesktopInfoFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopInfoFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IRemoteDesktopInfoFactory_Vtbl)).CreateInstance)(this, id, displayname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDesktopInfoFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRemoteDesktopRegistrarStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DesktopInfos(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopRegistrarStatics", "DesktopInfos", 6usize, |this| ((**(this as *const *const IRemoteDesktopRegistrarStatics_Vtbl)).DesktopInfos)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSwitchToLocalSessionEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopRegistrarStatics", "IsSwitchToLocalSessionEnabled", 7usize, |this| ((**(this as *const *const IRemoteDesktopRegistrarStatics_Vtbl)).IsSwitchToLocalSessionEnabled)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            DesktopInfos,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DesktopInfos: 0,
+            IsSwitchToLocalSessionEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDesktopRegistrarStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -28,3 +28,5 @@ impl InteractiveSession {
 impl windows_core::RuntimeName for InteractiveSession {
     const NAME: &'static str = "Windows.System.RemoteDesktop.InteractiveSession";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
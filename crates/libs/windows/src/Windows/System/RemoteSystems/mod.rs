@@ -2616,6 +2616,7 @@ impl RemoteSystemAccessStatus {
 impl windows_core::TypeKind for RemoteSystemAccessStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteSystemAccessStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteSystemAccessStatus").field(&self.0).finish()
@@ -2634,6 +2635,7 @@ impl RemoteSystemAuthorizationKind {
 impl windows_core::TypeKind for RemoteSystemAuthorizationKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteSystemAuthorizationKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteSystemAuthorizationKind").field(&self.0).finish()
@@ -2654,6 +2656,7 @@ impl RemoteSystemDiscoveryType {
 impl windows_core::TypeKind for RemoteSystemDiscoveryType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteSystemDiscoveryType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteSystemDiscoveryType").field(&self.0).finish()
@@ -2675,6 +2678,7 @@ impl RemoteSystemPlatform {
 impl windows_core::TypeKind for RemoteSystemPlatform {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteSystemPlatform {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteSystemPlatform").field(&self.0).finish()
@@ -2694,6 +2698,7 @@ impl RemoteSystemSessionCreationStatus {
 impl windows_core::TypeKind for RemoteSystemSessionCreationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteSystemSessionCreationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteSystemSessionCreationStatus").field(&self.0).finish()
@@ -2713,6 +2718,7 @@ impl RemoteSystemSessionDisconnectedReason {
 impl windows_core::TypeKind for RemoteSystemSessionDisconnectedReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteSystemSessionDisconnectedReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteSystemSessionDisconnectedReason").field(&self.0).finish()
@@ -2734,6 +2740,7 @@ impl RemoteSystemSessionJoinStatus {
 impl windows_core::TypeKind for RemoteSystemSessionJoinStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteSystemSessionJoinStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteSystemSessionJoinStatus").field(&self.0).finish()
@@ -2752,6 +2759,7 @@ impl RemoteSystemSessionMessageChannelReliability {
 impl windows_core::TypeKind for RemoteSystemSessionMessageChannelReliability {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteSystemSessionMessageChannelReliability {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteSystemSessionMessageChannelReliability").field(&self.0).finish()
@@ -2774,6 +2782,7 @@ impl RemoteSystemSessionParticipantWatcherStatus {
 impl windows_core::TypeKind for RemoteSystemSessionParticipantWatcherStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteSystemSessionParticipantWatcherStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteSystemSessionParticipantWatcherStatus").field(&self.0).finish()
@@ -2796,6 +2805,7 @@ impl RemoteSystemSessionWatcherStatus {
 impl windows_core::TypeKind for RemoteSystemSessionWatcherStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteSystemSessionWatcherStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteSystemSessionWatcherStatus").field(&self.0).finish()
@@ -2816,6 +2826,7 @@ impl RemoteSystemStatus {
 impl windows_core::TypeKind for RemoteSystemStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteSystemStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteSystemStatus").field(&self.0).finish()
@@ -2834,6 +2845,7 @@ impl RemoteSystemStatusType {
 impl windows_core::TypeKind for RemoteSystemStatusType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteSystemStatusType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteSystemStatusType").field(&self.0).finish()
@@ -2853,6 +2865,7 @@ impl RemoteSystemWatcherError {
 impl windows_core::TypeKind for RemoteSystemWatcherError {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteSystemWatcherError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteSystemWatcherError").field(&self.0).finish()
@@ -2863,3 +2876,5 @@ impl windows_core::RuntimeType for RemoteSystemWatcherError {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1235 @@
+impl windows_core::imp::ProxyVtable for IKnownRemoteSystemCapabilitiesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppService(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRemoteSystemCapabilitiesStatics", "AppService", 6usize, |this| ((**(this as *const *const IKnownRemoteSystemCapabilitiesStatics_Vtbl)).AppService)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchUri(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRemoteSystemCapabilitiesStatics", "LaunchUri", 7usize, |this| ((**(this as *const *const IKnownRemoteSystemCapabilitiesStatics_Vtbl)).LaunchUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteSession(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRemoteSystemCapabilitiesStatics", "RemoteSession", 8usize, |this| ((**(this as *const *const IKnownRemoteSystemCapabilitiesStatics_Vtbl)).RemoteSession)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpatialEntity(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownRemoteSystemCapabilitiesStatics", "SpatialEntity", 9usize, |this| ((**(this as *const *const IKnownRemoteSystemCapabilitiesStatics_Vtbl)).SpatialEntity)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AppService, LaunchUri, RemoteSession, SpatialEntity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownRemoteSystemCapabilitiesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystem", "DisplayName", 6usize, |this| ((**(this as *const *const IRemoteSystem_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystem", "Id", 7usize, |this| ((**(this as *const *const IRemoteSystem_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystem", "Kind", 8usize, |this| ((**(this as *const *const IRemoteSystem_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut RemoteSystemStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystem", "Status", 9usize, |this| ((**(this as *const *const IRemoteSystem_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAvailableByProximity(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystem", "IsAvailableByProximity", 10usize, |this| ((**(this as *const *const IRemoteSystem_Vtbl)).IsAvailableByProximity)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayName, Id, Kind, Status, IsAvailableByProximity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystem2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAvailableBySpatialProximity(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystem2", "IsAvailableBySpatialProximity", 6usize, |this| ((**(this as *const *const IRemoteSystem2_Vtbl)).IsAvailableBySpatialProximity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCapabilitySupportedAsync(this: *mut core::ffi::c_void, capabilityname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystem2", "GetCapabilitySupportedAsync", 7usize, |this| ((**(this as *const *const IRemoteSystem2_Vtbl)).GetCapabilitySupportedAsync)(this, capabilityname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsAvailableBySpatialProximity, GetCapabilitySupportedAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystem2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystem3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ManufacturerDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystem3", "ManufacturerDisplayName", 6usize, |this| ((**(this as *const *const IRemoteSystem3_Vtbl)).ManufacturerDisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModelDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystem3", "ModelDisplayName", 7usize, |this| ((**(this as *const *const IRemoteSystem3_Vtbl)).ModelDisplayName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ManufacturerDisplayName, ModelDisplayName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystem3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystem4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Platform(this: *mut core::ffi::c_void, result__: *mut RemoteSystemPlatform) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystem4", "Platform", 6usize, |this| ((**(this as *const *const IRemoteSystem4_Vtbl)).Platform)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Platform }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystem4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRemoteSystem5_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Apps(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystem5", "Apps", 6usize, |this| ((**(this as *const *const IRemoteSystem5_Vtbl)).Apps)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Apps,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Apps: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystem5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystem6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystem6", "User", 6usize, |this| ((**(this as *const *const IRemoteSystem6_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, User }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystem6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemAddedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoteSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemAddedEventArgs", "RemoteSystem", 6usize, |this| ((**(this as *const *const IRemoteSystemAddedEventArgs_Vtbl)).RemoteSystem)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RemoteSystem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemAddedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRemoteSystemApp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemApp", "Id", 6usize, |this| ((**(this as *const *const IRemoteSystemApp_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemApp", "DisplayName", 7usize, |this| ((**(this as *const *const IRemoteSystemApp_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAvailableByProximity(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemApp", "IsAvailableByProximity", 8usize, |this| ((**(this as *const *const IRemoteSystemApp_Vtbl)).IsAvailableByProximity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAvailableBySpatialProximity(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemApp", "IsAvailableBySpatialProximity", 9usize, |this| ((**(this as *const *const IRemoteSystemApp_Vtbl)).IsAvailableBySpatialProximity)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Attributes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemApp", "Attributes", 10usize, |this| ((**(this as *const *const IRemoteSystemApp_Vtbl)).Attributes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            DisplayName,
+            IsAvailableByProximity,
+            IsAvailableBySpatialProximity,
+            #[cfg(feature = "Foundation_Collections")]
+            Attributes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Attributes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemApp as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemApp2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemApp2", "User", 6usize, |this| ((**(this as *const *const IRemoteSystemApp2_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionToken(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemApp2", "ConnectionToken", 7usize, |this| ((**(this as *const *const IRemoteSystemApp2_Vtbl)).ConnectionToken)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, User, ConnectionToken }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemApp2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRemoteSystemAppRegistration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemAppRegistration", "User", 6usize, |this| ((**(this as *const *const IRemoteSystemAppRegistration_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Attributes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemAppRegistration", "Attributes", 7usize, |this| ((**(this as *const *const IRemoteSystemAppRegistration_Vtbl)).Attributes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemAppRegistration", "SaveAsync", 8usize, |this| ((**(this as *const *const IRemoteSystemAppRegistration_Vtbl)).SaveAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            User,
+            #[cfg(feature = "Foundation_Collections")]
+            Attributes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Attributes: 0,
+            SaveAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemAppRegistration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemAppRegistrationStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemAppRegistrationStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IRemoteSystemAppRegistrationStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemAppRegistrationStatics", "GetForUser", 7usize, |this| ((**(this as *const *const IRemoteSystemAppRegistrationStatics_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault, GetForUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemAppRegistrationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemAuthorizationKindFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoteSystemAuthorizationKind(this: *mut core::ffi::c_void, result__: *mut RemoteSystemAuthorizationKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemAuthorizationKindFilter", "RemoteSystemAuthorizationKind", 6usize, |this| ((**(this as *const *const IRemoteSystemAuthorizationKindFilter_Vtbl)).RemoteSystemAuthorizationKind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RemoteSystemAuthorizationKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemAuthorizationKindFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemAuthorizationKindFilterFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, remotesystemauthorizationkind: RemoteSystemAuthorizationKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemAuthorizationKindFilterFactory", "Create", 6usize, |this| ((**(this as *const *const IRemoteSystemAuthorizationKindFilterFactory_Vtbl)).Create)(this, remotesystemauthorizationkind, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemAuthorizationKindFilterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemConnectionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsProximal(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemConnectionInfo", "IsProximal", 6usize, |this| ((**(this as *const *const IRemoteSystemConnectionInfo_Vtbl)).IsProximal)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsProximal }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemConnectionInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_AppService")]
+impl windows_core::imp::ProxyVtable for IRemoteSystemConnectionInfoStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_AppService")]
+        unsafe extern "system" fn TryCreateFromAppServiceConnection(this: *mut core::ffi::c_void, connection: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemConnectionInfoStatics", "TryCreateFromAppServiceConnection", 6usize, |this| ((**(this as *const *const IRemoteSystemConnectionInfoStatics_Vtbl)).TryCreateFromAppServiceConnection)(this, connection, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_AppService")]
+            TryCreateFromAppServiceConnection,
+            #[cfg(not(feature = "ApplicationModel_AppService"))]
+            TryCreateFromAppServiceConnection: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemConnectionInfoStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemConnectionRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoteSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemConnectionRequest", "RemoteSystem", 6usize, |this| ((**(this as *const *const IRemoteSystemConnectionRequest_Vtbl)).RemoteSystem)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RemoteSystem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemConnectionRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemConnectionRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoteSystemApp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemConnectionRequest2", "RemoteSystemApp", 6usize, |this| ((**(this as *const *const IRemoteSystemConnectionRequest2_Vtbl)).RemoteSystemApp)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RemoteSystemApp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemConnectionRequest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemConnectionRequest3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectionToken(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemConnectionRequest3", "ConnectionToken", 6usize, |this| ((**(this as *const *const IRemoteSystemConnectionRequest3_Vtbl)).ConnectionToken)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectionToken }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemConnectionRequest3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemConnectionRequestFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, remotesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemConnectionRequestFactory", "Create", 6usize, |this| ((**(this as *const *const IRemoteSystemConnectionRequestFactory_Vtbl)).Create)(this, remotesystem, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemConnectionRequestFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemConnectionRequestStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateForApp(this: *mut core::ffi::c_void, remotesystemapp: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemConnectionRequestStatics", "CreateForApp", 6usize, |this| ((**(this as *const *const IRemoteSystemConnectionRequestStatics_Vtbl)).CreateForApp)(this, remotesystemapp, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateForApp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemConnectionRequestStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemConnectionRequestStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromConnectionToken(this: *mut core::ffi::c_void, connectiontoken: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemConnectionRequestStatics2", "CreateFromConnectionToken", 6usize, |this| ((**(this as *const *const IRemoteSystemConnectionRequestStatics2_Vtbl)).CreateFromConnectionToken)(this, connectiontoken, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromConnectionTokenForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, connectiontoken: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemConnectionRequestStatics2", "CreateFromConnectionTokenForUser", 7usize, |this| ((**(this as *const *const IRemoteSystemConnectionRequestStatics2_Vtbl)).CreateFromConnectionTokenForUser)(this, user, connectiontoken, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromConnectionToken, CreateFromConnectionTokenForUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemConnectionRequestStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemDiscoveryTypeFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoteSystemDiscoveryType(this: *mut core::ffi::c_void, result__: *mut RemoteSystemDiscoveryType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemDiscoveryTypeFilter", "RemoteSystemDiscoveryType", 6usize, |this| ((**(this as *const *const IRemoteSystemDiscoveryTypeFilter_Vtbl)).RemoteSystemDiscoveryType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RemoteSystemDiscoveryType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemDiscoveryTypeFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemDiscoveryTypeFilterFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, discoverytype: RemoteSystemDiscoveryType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemDiscoveryTypeFilterFactory", "Create", 6usize, |this| ((**(this as *const *const IRemoteSystemDiscoveryTypeFilterFactory_Vtbl)).Create)(this, discoverytype, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemDiscoveryTypeFilterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemEnumerationCompletedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemEnumerationCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemFilter_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRemoteSystemKindFilter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RemoteSystemKinds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemKindFilter", "RemoteSystemKinds", 6usize, |this| ((**(this as *const *const IRemoteSystemKindFilter_Vtbl)).RemoteSystemKinds)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            RemoteSystemKinds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RemoteSystemKinds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemKindFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRemoteSystemKindFilterFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, remotesystemkinds: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemKindFilterFactory", "Create", 6usize, |this| ((**(this as *const *const IRemoteSystemKindFilterFactory_Vtbl)).Create)(this, remotesystemkinds, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Create,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemKindFilterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemKindStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Phone(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemKindStatics", "Phone", 6usize, |this| ((**(this as *const *const IRemoteSystemKindStatics_Vtbl)).Phone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hub(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemKindStatics", "Hub", 7usize, |this| ((**(this as *const *const IRemoteSystemKindStatics_Vtbl)).Hub)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Holographic(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemKindStatics", "Holographic", 8usize, |this| ((**(this as *const *const IRemoteSystemKindStatics_Vtbl)).Holographic)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Desktop(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemKindStatics", "Desktop", 9usize, |this| ((**(this as *const *const IRemoteSystemKindStatics_Vtbl)).Desktop)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Xbox(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemKindStatics", "Xbox", 10usize, |this| ((**(this as *const *const IRemoteSystemKindStatics_Vtbl)).Xbox)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Phone, Hub, Holographic, Desktop, Xbox }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemKindStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemKindStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Iot(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemKindStatics2", "Iot", 6usize, |this| ((**(this as *const *const IRemoteSystemKindStatics2_Vtbl)).Iot)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tablet(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemKindStatics2", "Tablet", 7usize, |this| ((**(this as *const *const IRemoteSystemKindStatics2_Vtbl)).Tablet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Laptop(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemKindStatics2", "Laptop", 8usize, |this| ((**(this as *const *const IRemoteSystemKindStatics2_Vtbl)).Laptop)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Iot, Tablet, Laptop }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemKindStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemRemovedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoteSystemId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemRemovedEventArgs", "RemoteSystemId", 6usize, |this| ((**(this as *const *const IRemoteSystemRemovedEventArgs_Vtbl)).RemoteSystemId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RemoteSystemId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemRemovedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSession", "Id", 6usize, |this| ((**(this as *const *const IRemoteSystemSession_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSession", "DisplayName", 7usize, |this| ((**(this as *const *const IRemoteSystemSession_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControllerDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSession", "ControllerDisplayName", 8usize, |this| ((**(this as *const *const IRemoteSystemSession_Vtbl)).ControllerDisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSession", "Disconnected", 9usize, |this| ((**(this as *const *const IRemoteSystemSession_Vtbl)).Disconnected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDisconnected(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSession", "RemoveDisconnected", 10usize, |this| ((**(this as *const *const IRemoteSystemSession_Vtbl)).RemoveDisconnected)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateParticipantWatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSession", "CreateParticipantWatcher", 11usize, |this| ((**(this as *const *const IRemoteSystemSession_Vtbl)).CreateParticipantWatcher)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendInvitationAsync(this: *mut core::ffi::c_void, invitee: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSession", "SendInvitationAsync", 12usize, |this| ((**(this as *const *const IRemoteSystemSession_Vtbl)).SendInvitationAsync)(this, invitee, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            DisplayName,
+            ControllerDisplayName,
+            Disconnected,
+            RemoveDisconnected,
+            CreateParticipantWatcher,
+            SendInvitationAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionAddedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SessionInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionAddedEventArgs", "SessionInfo", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionAddedEventArgs_Vtbl)).SessionInfo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SessionInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionAddedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn JoinRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionController", "JoinRequested", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionController_Vtbl)).JoinRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveJoinRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionController", "RemoveJoinRequested", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionController_Vtbl)).RemoveJoinRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveParticipantAsync(this: *mut core::ffi::c_void, pparticipant: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionController", "RemoveParticipantAsync", 8usize, |this| ((**(this as *const *const IRemoteSystemSessionController_Vtbl)).RemoveParticipantAsync)(this, pparticipant, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSessionAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionController", "CreateSessionAsync", 9usize, |this| ((**(this as *const *const IRemoteSystemSessionController_Vtbl)).CreateSessionAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            JoinRequested,
+            RemoveJoinRequested,
+            RemoveParticipantAsync,
+            CreateSessionAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionControllerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateController(this: *mut core::ffi::c_void, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionControllerFactory", "CreateController", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionControllerFactory_Vtbl)).CreateController)(this, displayname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateControllerWithSessionOptions(this: *mut core::ffi::c_void, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionControllerFactory", "CreateControllerWithSessionOptions", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionControllerFactory_Vtbl)).CreateControllerWithSessionOptions)(this, displayname, options, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateController, CreateControllerWithSessionOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionControllerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionCreationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut RemoteSystemSessionCreationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionCreationResult", "Status", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionCreationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionCreationResult", "Session", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionCreationResult_Vtbl)).Session)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Session }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionCreationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionDisconnectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut RemoteSystemSessionDisconnectedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionDisconnectedEventArgs", "Reason", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionDisconnectedEventArgs_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionDisconnectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionInfo", "DisplayName", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionInfo_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControllerDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionInfo", "ControllerDisplayName", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionInfo_Vtbl)).ControllerDisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn JoinAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionInfo", "JoinAsync", 8usize, |this| ((**(this as *const *const IRemoteSystemSessionInfo_Vtbl)).JoinAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayName, ControllerDisplayName, JoinAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionInvitation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Sender(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionInvitation", "Sender", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionInvitation_Vtbl)).Sender)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionInvitation", "SessionInfo", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionInvitation_Vtbl)).SessionInfo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Sender, SessionInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionInvitation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionInvitationListener_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InvitationReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionInvitationListener", "InvitationReceived", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionInvitationListener_Vtbl)).InvitationReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveInvitationReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionInvitationListener", "RemoveInvitationReceived", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionInvitationListener_Vtbl)).RemoveInvitationReceived)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InvitationReceived, RemoveInvitationReceived }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionInvitationListener as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionInvitationReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Invitation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionInvitationReceivedEventArgs", "Invitation", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionInvitationReceivedEventArgs_Vtbl)).Invitation)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Invitation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionInvitationReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionJoinRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Participant(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionJoinRequest", "Participant", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionJoinRequest_Vtbl)).Participant)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Accept(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionJoinRequest", "Accept", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionJoinRequest_Vtbl)).Accept)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Participant, Accept }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionJoinRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionJoinRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn JoinRequest(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionJoinRequestedEventArgs", "JoinRequest", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionJoinRequestedEventArgs_Vtbl)).JoinRequest)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionJoinRequestedEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionJoinRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, JoinRequest, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionJoinRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionJoinResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut RemoteSystemSessionJoinStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionJoinResult", "Status", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionJoinResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionJoinResult", "Session", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionJoinResult_Vtbl)).Session)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Session }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionJoinResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionMessageChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionMessageChannel", "Session", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionMessageChannel_Vtbl)).Session)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BroadcastValueSetAsync(this: *mut core::ffi::c_void, messagedata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionMessageChannel", "BroadcastValueSetAsync", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionMessageChannel_Vtbl)).BroadcastValueSetAsync)(this, messagedata, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SendValueSetAsync(this: *mut core::ffi::c_void, messagedata: *mut core::ffi::c_void, participant: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionMessageChannel", "SendValueSetAsync", 8usize, |this| ((**(this as *const *const IRemoteSystemSessionMessageChannel_Vtbl)).SendValueSetAsync)(this, messagedata, participant, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SendValueSetToParticipantsAsync(this: *mut core::ffi::c_void, messagedata: *mut core::ffi::c_void, participants: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionMessageChannel", "SendValueSetToParticipantsAsync", 9usize, |this| ((**(this as *const *const IRemoteSystemSessionMessageChannel_Vtbl)).SendValueSetToParticipantsAsync)(this, messagedata, participants, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValueSetReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionMessageChannel", "ValueSetReceived", 10usize, |this| ((**(this as *const *const IRemoteSystemSessionMessageChannel_Vtbl)).ValueSetReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveValueSetReceived(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionMessageChannel", "RemoveValueSetReceived", 11usize, |this| ((**(this as *const *const IRemoteSystemSessionMessageChannel_Vtbl)).RemoveValueSetReceived)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Session,
+            #[cfg(feature = "Foundation_Collections")]
+            BroadcastValueSetAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BroadcastValueSetAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SendValueSetAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SendValueSetAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SendValueSetToParticipantsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SendValueSetToParticipantsAsync: 0,
+            ValueSetReceived,
+            RemoveValueSetReceived,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionMessageChannel as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionMessageChannelFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, session: *mut core::ffi::c_void, channelname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionMessageChannelFactory", "Create", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionMessageChannelFactory_Vtbl)).Create)(this, session, channelname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithReliability(this: *mut core::ffi::c_void, session: *mut core::ffi::c_void, channelname: core::mem::MaybeUninit<windows_core::HSTRING>, reliability: RemoteSystemSessionMessageChannelReliability, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionMessageChannelFactory", "CreateWithReliability", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionMessageChannelFactory_Vtbl)).CreateWithReliability)(this, session, channelname, reliability, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithReliability }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionMessageChannelFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsInviteOnly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionOptions", "IsInviteOnly", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionOptions_Vtbl)).IsInviteOnly)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsInviteOnly(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionOptions", "SetIsInviteOnly", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionOptions_Vtbl)).SetIsInviteOnly)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsInviteOnly, SetIsInviteOnly }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Networking"))]
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionParticipant_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoteSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionParticipant", "RemoteSystem", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionParticipant_Vtbl)).RemoteSystem)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Networking"))]
+        unsafe extern "system" fn GetHostNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionParticipant", "GetHostNames", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionParticipant_Vtbl)).GetHostNames)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RemoteSystem,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Networking"))]
+            GetHostNames,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Networking")))]
+            GetHostNames: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionParticipant as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionParticipantAddedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Participant(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionParticipantAddedEventArgs", "Participant", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionParticipantAddedEventArgs_Vtbl)).Participant)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Participant }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionParticipantAddedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionParticipantRemovedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Participant(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionParticipantRemovedEventArgs", "Participant", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionParticipantRemovedEventArgs_Vtbl)).Participant)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Participant }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionParticipantRemovedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionParticipantWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionParticipantWatcher", "Start", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionParticipantWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionParticipantWatcher", "Stop", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionParticipantWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut RemoteSystemSessionParticipantWatcherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionParticipantWatcher", "Status", 8usize, |this| ((**(this as *const *const IRemoteSystemSessionParticipantWatcher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Added(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionParticipantWatcher", "Added", 9usize, |this| ((**(this as *const *const IRemoteSystemSessionParticipantWatcher_Vtbl)).Added)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionParticipantWatcher", "RemoveAdded", 10usize, |this| ((**(this as *const *const IRemoteSystemSessionParticipantWatcher_Vtbl)).RemoveAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Removed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionParticipantWatcher", "Removed", 11usize, |this| ((**(this as *const *const IRemoteSystemSessionParticipantWatcher_Vtbl)).Removed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionParticipantWatcher", "RemoveRemoved", 12usize, |this| ((**(this as *const *const IRemoteSystemSessionParticipantWatcher_Vtbl)).RemoveRemoved)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionParticipantWatcher", "EnumerationCompleted", 13usize, |this| ((**(this as *const *const IRemoteSystemSessionParticipantWatcher_Vtbl)).EnumerationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnumerationCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionParticipantWatcher", "RemoveEnumerationCompleted", 14usize, |this| ((**(this as *const *const IRemoteSystemSessionParticipantWatcher_Vtbl)).RemoveEnumerationCompleted)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Start,
+            Stop,
+            Status,
+            Added,
+            RemoveAdded,
+            Removed,
+            RemoveRemoved,
+            EnumerationCompleted,
+            RemoveEnumerationCompleted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionParticipantWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionRemovedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SessionInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionRemovedEventArgs", "SessionInfo", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionRemovedEventArgs_Vtbl)).SessionInfo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SessionInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionRemovedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionStatics", "CreateWatcher", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionStatics_Vtbl)).CreateWatcher)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWatcher }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SessionInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionUpdatedEventArgs", "SessionInfo", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionUpdatedEventArgs_Vtbl)).SessionInfo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SessionInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionValueSetReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Sender(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionValueSetReceivedEventArgs", "Sender", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionValueSetReceivedEventArgs_Vtbl)).Sender)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionValueSetReceivedEventArgs", "Message", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionValueSetReceivedEventArgs_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Sender,
+            #[cfg(feature = "Foundation_Collections")]
+            Message,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Message: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionValueSetReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemSessionWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionWatcher", "Start", 6usize, |this| ((**(this as *const *const IRemoteSystemSessionWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionWatcher", "Stop", 7usize, |this| ((**(this as *const *const IRemoteSystemSessionWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut RemoteSystemSessionWatcherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionWatcher", "Status", 8usize, |this| ((**(this as *const *const IRemoteSystemSessionWatcher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Added(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionWatcher", "Added", 9usize, |this| ((**(this as *const *const IRemoteSystemSessionWatcher_Vtbl)).Added)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionWatcher", "RemoveAdded", 10usize, |this| ((**(this as *const *const IRemoteSystemSessionWatcher_Vtbl)).RemoveAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Updated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionWatcher", "Updated", 11usize, |this| ((**(this as *const *const IRemoteSystemSessionWatcher_Vtbl)).Updated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionWatcher", "RemoveUpdated", 12usize, |this| ((**(this as *const *const IRemoteSystemSessionWatcher_Vtbl)).RemoveUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Removed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionWatcher", "Removed", 13usize, |this| ((**(this as *const *const IRemoteSystemSessionWatcher_Vtbl)).Removed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemSessionWatcher", "RemoveRemoved", 14usize, |this| ((**(this as *const *const IRemoteSystemSessionWatcher_Vtbl)).RemoveRemoved)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Start,
+            Stop,
+            Status,
+            Added,
+            RemoveAdded,
+            Updated,
+            RemoveUpdated,
+            Removed,
+            RemoveRemoved,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemSessionWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Networking"))]
+impl windows_core::imp::ProxyVtable for IRemoteSystemStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Networking")]
+        unsafe extern "system" fn FindByHostNameAsync(this: *mut core::ffi::c_void, hostname: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemStatics", "FindByHostNameAsync", 6usize, |this| ((**(this as *const *const IRemoteSystemStatics_Vtbl)).FindByHostNameAsync)(this, hostname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemStatics", "CreateWatcher", 7usize, |this| ((**(this as *const *const IRemoteSystemStatics_Vtbl)).CreateWatcher)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWatcherWithFilters(this: *mut core::ffi::c_void, filters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemStatics", "CreateWatcherWithFilters", 8usize, |this| ((**(this as *const *const IRemoteSystemStatics_Vtbl)).CreateWatcherWithFilters)(this, filters, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemStatics", "RequestAccessAsync", 9usize, |this| ((**(this as *const *const IRemoteSystemStatics_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Networking")]
+            FindByHostNameAsync,
+            #[cfg(not(feature = "Networking"))]
+            FindByHostNameAsync: 0,
+            CreateWatcher,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWatcherWithFilters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWatcherWithFilters: 0,
+            RequestAccessAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAuthorizationKindEnabled(this: *mut core::ffi::c_void, kind: RemoteSystemAuthorizationKind, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemStatics2", "IsAuthorizationKindEnabled", 6usize, |this| ((**(this as *const *const IRemoteSystemStatics2_Vtbl)).IsAuthorizationKindEnabled)(this, kind, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsAuthorizationKindEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRemoteSystemStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWatcherForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemStatics3", "CreateWatcherForUser", 6usize, |this| ((**(this as *const *const IRemoteSystemStatics3_Vtbl)).CreateWatcherForUser)(this, user, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateWatcherWithFiltersForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, filters: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemStatics3", "CreateWatcherWithFiltersForUser", 7usize, |this| ((**(this as *const *const IRemoteSystemStatics3_Vtbl)).CreateWatcherWithFiltersForUser)(this, user, filters, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateWatcherForUser,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateWatcherWithFiltersForUser,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateWatcherWithFiltersForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemStatusTypeFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoteSystemStatusType(this: *mut core::ffi::c_void, result__: *mut RemoteSystemStatusType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemStatusTypeFilter", "RemoteSystemStatusType", 6usize, |this| ((**(this as *const *const IRemoteSystemStatusTypeFilter_Vtbl)).RemoteSystemStatusType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RemoteSystemStatusType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemStatusTypeFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemStatusTypeFilterFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, remotesystemstatustype: RemoteSystemStatusType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemStatusTypeFilterFactory", "Create", 6usize, |this| ((**(this as *const *const IRemoteSystemStatusTypeFilterFactory_Vtbl)).Create)(this, remotesystemstatustype, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemStatusTypeFilterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoteSystem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemUpdatedEventArgs", "RemoteSystem", 6usize, |this| ((**(this as *const *const IRemoteSystemUpdatedEventArgs_Vtbl)).RemoteSystem)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RemoteSystem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWatcher", "Start", 6usize, |this| ((**(this as *const *const IRemoteSystemWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWatcher", "Stop", 7usize, |this| ((**(this as *const *const IRemoteSystemWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteSystemAdded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWatcher", "RemoteSystemAdded", 8usize, |this| ((**(this as *const *const IRemoteSystemWatcher_Vtbl)).RemoteSystemAdded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoteSystemAdded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWatcher", "RemoveRemoteSystemAdded", 9usize, |this| ((**(this as *const *const IRemoteSystemWatcher_Vtbl)).RemoveRemoteSystemAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteSystemUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWatcher", "RemoteSystemUpdated", 10usize, |this| ((**(this as *const *const IRemoteSystemWatcher_Vtbl)).RemoteSystemUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoteSystemUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWatcher", "RemoveRemoteSystemUpdated", 11usize, |this| ((**(this as *const *const IRemoteSystemWatcher_Vtbl)).RemoveRemoteSystemUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteSystemRemoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWatcher", "RemoteSystemRemoved", 12usize, |this| ((**(this as *const *const IRemoteSystemWatcher_Vtbl)).RemoteSystemRemoved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoteSystemRemoved(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWatcher", "RemoveRemoteSystemRemoved", 13usize, |this| ((**(this as *const *const IRemoteSystemWatcher_Vtbl)).RemoveRemoteSystemRemoved)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Start,
+            Stop,
+            RemoteSystemAdded,
+            RemoveRemoteSystemAdded,
+            RemoteSystemUpdated,
+            RemoveRemoteSystemUpdated,
+            RemoteSystemRemoved,
+            RemoveRemoteSystemRemoved,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemWatcher2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumerationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWatcher2", "EnumerationCompleted", 6usize, |this| ((**(this as *const *const IRemoteSystemWatcher2_Vtbl)).EnumerationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnumerationCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWatcher2", "RemoveEnumerationCompleted", 7usize, |this| ((**(this as *const *const IRemoteSystemWatcher2_Vtbl)).RemoveEnumerationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorOccurred(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWatcher2", "ErrorOccurred", 8usize, |this| ((**(this as *const *const IRemoteSystemWatcher2_Vtbl)).ErrorOccurred)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveErrorOccurred(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWatcher2", "RemoveErrorOccurred", 9usize, |this| ((**(this as *const *const IRemoteSystemWatcher2_Vtbl)).RemoveErrorOccurred)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumerationCompleted,
+            RemoveEnumerationCompleted,
+            ErrorOccurred,
+            RemoveErrorOccurred,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemWatcher2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemWatcher3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWatcher3", "User", 6usize, |this| ((**(this as *const *const IRemoteSystemWatcher3_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, User }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemWatcher3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemWatcherErrorOccurredEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, result__: *mut RemoteSystemWatcherError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWatcherErrorOccurredEventArgs", "Error", 6usize, |this| ((**(this as *const *const IRemoteSystemWatcherErrorOccurredEventArgs_Vtbl)).Error)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Error }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemWatcherErrorOccurredEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IRemoteSystemWebAccountFilter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn Account(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWebAccountFilter", "Account", 6usize, |this| ((**(this as *const *const IRemoteSystemWebAccountFilter_Vtbl)).Account)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            Account,
+            #[cfg(not(feature = "Security_Credentials"))]
+            Account: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemWebAccountFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IRemoteSystemWebAccountFilterFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, account: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemWebAccountFilterFactory", "Create", 6usize, |this| ((**(this as *const *const IRemoteSystemWebAccountFilterFactory_Vtbl)).Create)(this, account, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            Create,
+            #[cfg(not(feature = "Security_Credentials"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemWebAccountFilterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,77 @@
+impl windows_core::imp::ProxyVtable for IPreallocatedWorkItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RunAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPreallocatedWorkItem", "RunAsync", 6usize, |this| ((**(this as *const *const IPreallocatedWorkItem_Vtbl)).RunAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RunAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPreallocatedWorkItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPreallocatedWorkItemFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWorkItem(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPreallocatedWorkItemFactory", "CreateWorkItem", 6usize, |this| ((**(this as *const *const IPreallocatedWorkItemFactory_Vtbl)).CreateWorkItem)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWorkItemWithPriority(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, priority: super::WorkItemPriority, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPreallocatedWorkItemFactory", "CreateWorkItemWithPriority", 7usize, |this| ((**(this as *const *const IPreallocatedWorkItemFactory_Vtbl)).CreateWorkItemWithPriority)(this, handler, priority, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWorkItemWithPriorityAndOptions(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, priority: super::WorkItemPriority, options: super::WorkItemOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPreallocatedWorkItemFactory", "CreateWorkItemWithPriorityAndOptions", 8usize, |this| ((**(this as *const *const IPreallocatedWorkItemFactory_Vtbl)).CreateWorkItemWithPriorityAndOptions)(this, handler, priority, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateWorkItem,
+            CreateWorkItemWithPriority,
+            CreateWorkItemWithPriorityAndOptions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPreallocatedWorkItemFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISignalNotifier_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignalNotifier", "Enable", 6usize, |this| ((**(this as *const *const ISignalNotifier_Vtbl)).Enable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignalNotifier", "Terminate", 7usize, |this| ((**(this as *const *const ISignalNotifier_Vtbl)).Terminate)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Enable, Terminate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISignalNotifier as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISignalNotifierStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AttachToEvent(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, handler: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignalNotifierStatics", "AttachToEvent", 6usize, |this| ((**(this as *const *const ISignalNotifierStatics_Vtbl)).AttachToEvent)(this, name, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachToEventWithTimeout(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, handler: *mut core::ffi::c_void, timeout: super::super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignalNotifierStatics", "AttachToEventWithTimeout", 7usize, |this| ((**(this as *const *const ISignalNotifierStatics_Vtbl)).AttachToEventWithTimeout)(this, name, handler, timeout, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachToSemaphore(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, handler: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignalNotifierStatics", "AttachToSemaphore", 8usize, |this| ((**(this as *const *const ISignalNotifierStatics_Vtbl)).AttachToSemaphore)(this, name, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachToSemaphoreWithTimeout(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, handler: *mut core::ffi::c_void, timeout: super::super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignalNotifierStatics", "AttachToSemaphoreWithTimeout", 9usize, |this| ((**(this as *const *const ISignalNotifierStatics_Vtbl)).AttachToSemaphoreWithTimeout)(this, name, handler, timeout, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AttachToEvent,
+            AttachToEventWithTimeout,
+            AttachToSemaphore,
+            AttachToSemaphoreWithTimeout,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISignalNotifierStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -161,6 +161,7 @@ impl WorkItemOptions {
 impl windows_core::TypeKind for WorkItemOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WorkItemOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WorkItemOptions").field(&self.0).finish()
@@ -213,6 +214,7 @@ impl WorkItemPriority {
 impl windows_core::TypeKind for WorkItemPriority {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WorkItemPriority {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WorkItemPriority").field(&self.0).finish()
@@ -401,3 +403,5 @@ pub struct WorkItemHandler_Vtbl {
     pub base__: windows_core::IUnknown_Vtbl,
     pub Invoke: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,68 @@
+impl windows_core::imp::ProxyVtable for IThreadPoolStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RunAsync(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolStatics", "RunAsync", 6usize, |this| ((**(this as *const *const IThreadPoolStatics_Vtbl)).RunAsync)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunWithPriorityAsync(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, priority: WorkItemPriority, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolStatics", "RunWithPriorityAsync", 7usize, |this| ((**(this as *const *const IThreadPoolStatics_Vtbl)).RunWithPriorityAsync)(this, handler, priority, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunWithPriorityAndOptionsAsync(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, priority: WorkItemPriority, options: WorkItemOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolStatics", "RunWithPriorityAndOptionsAsync", 8usize, |this| ((**(this as *const *const IThreadPoolStatics_Vtbl)).RunWithPriorityAndOptionsAsync)(this, handler, priority, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RunAsync,
+            RunWithPriorityAsync,
+            RunWithPriorityAndOptionsAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IThreadPoolStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IThreadPoolTimer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Period(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolTimer", "Period", 6usize, |this| ((**(this as *const *const IThreadPoolTimer_Vtbl)).Period)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delay(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolTimer", "Delay", 7usize, |this| ((**(this as *const *const IThreadPoolTimer_Vtbl)).Delay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolTimer", "Cancel", 8usize, |this| ((**(this as *const *const IThreadPoolTimer_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Period, Delay, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IThreadPoolTimer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IThreadPoolTimerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreatePeriodicTimer(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, period: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolTimerStatics", "CreatePeriodicTimer", 6usize, |this| ((**(this as *const *const IThreadPoolTimerStatics_Vtbl)).CreatePeriodicTimer)(this, handler, period, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTimer(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, delay: super::super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolTimerStatics", "CreateTimer", 7usize, |this| ((**(this as *const *const IThreadPoolTimerStatics_Vtbl)).CreateTimer)(this, handler, delay, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePeriodicTimerWithCompletion(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, period: super::super::Foundation::TimeSpan, destroyed: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolTimerStatics", "CreatePeriodicTimerWithCompletion", 8usize, |this| ((**(this as *const *const IThreadPoolTimerStatics_Vtbl)).CreatePeriodicTimerWithCompletion)(this, handler, period, destroyed, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTimerWithCompletion(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, delay: super::super::Foundation::TimeSpan, destroyed: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolTimerStatics", "CreateTimerWithCompletion", 9usize, |this| ((**(this as *const *const IThreadPoolTimerStatics_Vtbl)).CreateTimerWithCompletion)(this, handler, delay, destroyed, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreatePeriodicTimer,
+            CreateTimer,
+            CreatePeriodicTimerWithCompletion,
+            CreateTimerWithCompletion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IThreadPoolTimerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
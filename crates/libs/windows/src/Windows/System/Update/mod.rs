@@ -337,6 +337,7 @@ impl SystemUpdateAttentionRequiredReason {
 impl windows_core::TypeKind for SystemUpdateAttentionRequiredReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SystemUpdateAttentionRequiredReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SystemUpdateAttentionRequiredReason").field(&self.0).finish()
@@ -362,6 +363,7 @@ impl SystemUpdateItemState {
 impl windows_core::TypeKind for SystemUpdateItemState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SystemUpdateItemState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SystemUpdateItemState").field(&self.0).finish()
@@ -390,6 +392,7 @@ impl SystemUpdateManagerState {
 impl windows_core::TypeKind for SystemUpdateManagerState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SystemUpdateManagerState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SystemUpdateManagerState").field(&self.0).finish()
@@ -408,6 +411,7 @@ impl SystemUpdateStartInstallAction {
 impl windows_core::TypeKind for SystemUpdateStartInstallAction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SystemUpdateStartInstallAction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SystemUpdateStartInstallAction").field(&self.0).finish()
@@ -416,3 +420,5 @@ impl core::fmt::Debug for SystemUpdateStartInstallAction {
 impl windows_core::RuntimeType for SystemUpdateStartInstallAction {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.System.Update.SystemUpdateStartInstallAction;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
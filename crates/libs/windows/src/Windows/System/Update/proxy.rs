@@ -0,0 +1,177 @@
+impl windows_core::imp::ProxyVtable for ISystemUpdateItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut SystemUpdateItemState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateItem", "State", 6usize, |this| ((**(this as *const *const ISystemUpdateItem_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateItem", "Title", 7usize, |this| ((**(this as *const *const ISystemUpdateItem_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateItem", "Description", 8usize, |this| ((**(this as *const *const ISystemUpdateItem_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateItem", "Id", 9usize, |this| ((**(this as *const *const ISystemUpdateItem_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Revision(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateItem", "Revision", 10usize, |this| ((**(this as *const *const ISystemUpdateItem_Vtbl)).Revision)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadProgress(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateItem", "DownloadProgress", 11usize, |this| ((**(this as *const *const ISystemUpdateItem_Vtbl)).DownloadProgress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallProgress(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateItem", "InstallProgress", 12usize, |this| ((**(this as *const *const ISystemUpdateItem_Vtbl)).InstallProgress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateItem", "ExtendedError", 13usize, |this| ((**(this as *const *const ISystemUpdateItem_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            State,
+            Title,
+            Description,
+            Id,
+            Revision,
+            DownloadProgress,
+            InstallProgress,
+            ExtendedError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemUpdateItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemUpdateLastErrorInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut SystemUpdateManagerState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateLastErrorInfo", "State", 6usize, |this| ((**(this as *const *const ISystemUpdateLastErrorInfo_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateLastErrorInfo", "ExtendedError", 7usize, |this| ((**(this as *const *const ISystemUpdateLastErrorInfo_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInteractive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateLastErrorInfo", "IsInteractive", 8usize, |this| ((**(this as *const *const ISystemUpdateLastErrorInfo_Vtbl)).IsInteractive)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, State, ExtendedError, IsInteractive }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemUpdateLastErrorInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISystemUpdateManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "IsSupported", 6usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut SystemUpdateManagerState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "State", 7usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "StateChanged", 8usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).StateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStateChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "RemoveStateChanged", 9usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).RemoveStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadProgress(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "DownloadProgress", 10usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).DownloadProgress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallProgress(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "InstallProgress", 11usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).InstallProgress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserActiveHoursStart(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "UserActiveHoursStart", 12usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).UserActiveHoursStart)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserActiveHoursEnd(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "UserActiveHoursEnd", 13usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).UserActiveHoursEnd)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserActiveHoursMax(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "UserActiveHoursMax", 14usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).UserActiveHoursMax)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySetUserActiveHours(this: *mut core::ffi::c_void, start: super::super::Foundation::TimeSpan, end: super::super::Foundation::TimeSpan, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "TrySetUserActiveHours", 15usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).TrySetUserActiveHours)(this, start, end, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastUpdateCheckTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "LastUpdateCheckTime", 16usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).LastUpdateCheckTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastUpdateInstallTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "LastUpdateInstallTime", 17usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).LastUpdateInstallTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastErrorInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "LastErrorInfo", 18usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).LastErrorInfo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAutomaticRebootBlockIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "GetAutomaticRebootBlockIds", 19usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).GetAutomaticRebootBlockIds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlockAutomaticRebootAsync(this: *mut core::ffi::c_void, lockid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "BlockAutomaticRebootAsync", 20usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).BlockAutomaticRebootAsync)(this, lockid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnblockAutomaticRebootAsync(this: *mut core::ffi::c_void, lockid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "UnblockAutomaticRebootAsync", 21usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).UnblockAutomaticRebootAsync)(this, lockid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "ExtendedError", 22usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetUpdateItems(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "GetUpdateItems", 23usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).GetUpdateItems)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttentionRequiredReason(this: *mut core::ffi::c_void, result__: *mut SystemUpdateAttentionRequiredReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "AttentionRequiredReason", 24usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).AttentionRequiredReason)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlightRing(this: *mut core::ffi::c_void, flightring: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "SetFlightRing", 25usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).SetFlightRing)(this, flightring, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlightRing(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "GetFlightRing", 26usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).GetFlightRing)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartInstall(this: *mut core::ffi::c_void, action: SystemUpdateStartInstallAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "StartInstall", 27usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).StartInstall)(this, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn RebootToCompleteInstall(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "RebootToCompleteInstall", 28usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).RebootToCompleteInstall)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartCancelUpdates(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemUpdateManagerStatics", "StartCancelUpdates", 29usize, |this| ((**(this as *const *const ISystemUpdateManagerStatics_Vtbl)).StartCancelUpdates)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupported,
+            State,
+            StateChanged,
+            RemoveStateChanged,
+            DownloadProgress,
+            InstallProgress,
+            UserActiveHoursStart,
+            UserActiveHoursEnd,
+            UserActiveHoursMax,
+            TrySetUserActiveHours,
+            LastUpdateCheckTime,
+            LastUpdateInstallTime,
+            LastErrorInfo,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAutomaticRebootBlockIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAutomaticRebootBlockIds: 0,
+            BlockAutomaticRebootAsync,
+            UnblockAutomaticRebootAsync,
+            ExtendedError,
+            #[cfg(feature = "Foundation_Collections")]
+            GetUpdateItems,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetUpdateItems: 0,
+            AttentionRequiredReason,
+            SetFlightRing,
+            GetFlightRing,
+            StartInstall,
+            RebootToCompleteInstall,
+            StartCancelUpdates,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemUpdateManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -982,6 +982,7 @@ impl windows_core::TypeKind for AccountPictureKind {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AccountPictureKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AccountPictureKind").field(&self.0).finish()
@@ -1009,6 +1010,7 @@ impl windows_core::TypeKind for SetAccountPictureResult {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SetAccountPictureResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SetAccountPictureResult").field(&self.0).finish()
@@ -1029,6 +1031,7 @@ impl SetImageFeedResult {
 impl windows_core::TypeKind for SetImageFeedResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SetImageFeedResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SetImageFeedResult").field(&self.0).finish()
@@ -1037,3 +1040,5 @@ impl core::fmt::Debug for SetImageFeedResult {
 impl windows_core::RuntimeType for SetImageFeedResult {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.System.UserProfile.SetImageFeedResult;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,504 @@
+impl windows_core::imp::ProxyVtable for IAdvertisingManagerForUser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdvertisingId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvertisingManagerForUser", "AdvertisingId", 6usize, |this| ((**(this as *const *const IAdvertisingManagerForUser_Vtbl)).AdvertisingId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvertisingManagerForUser", "User", 7usize, |this| ((**(this as *const *const IAdvertisingManagerForUser_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AdvertisingId, User }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvertisingManagerForUser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdvertisingManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdvertisingId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvertisingManagerStatics", "AdvertisingId", 6usize, |this| ((**(this as *const *const IAdvertisingManagerStatics_Vtbl)).AdvertisingId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AdvertisingId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvertisingManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdvertisingManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvertisingManagerStatics2", "GetForUser", 6usize, |this| ((**(this as *const *const IAdvertisingManagerStatics2_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvertisingManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAssignedAccessSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssignedAccessSettings", "IsEnabled", 6usize, |this| ((**(this as *const *const IAssignedAccessSettings_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSingleAppKioskMode(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssignedAccessSettings", "IsSingleAppKioskMode", 7usize, |this| ((**(this as *const *const IAssignedAccessSettings_Vtbl)).IsSingleAppKioskMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssignedAccessSettings", "User", 8usize, |this| ((**(this as *const *const IAssignedAccessSettings_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsEnabled, IsSingleAppKioskMode, User }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAssignedAccessSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAssignedAccessSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssignedAccessSettingsStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IAssignedAccessSettingsStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssignedAccessSettingsStatics", "GetForUser", 7usize, |this| ((**(this as *const *const IAssignedAccessSettingsStatics_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault, GetForUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAssignedAccessSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDiagnosticsSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanUseDiagnosticsToTailorExperiences(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiagnosticsSettings", "CanUseDiagnosticsToTailorExperiences", 6usize, |this| ((**(this as *const *const IDiagnosticsSettings_Vtbl)).CanUseDiagnosticsToTailorExperiences)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiagnosticsSettings", "User", 7usize, |this| ((**(this as *const *const IDiagnosticsSettings_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CanUseDiagnosticsToTailorExperiences, User }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiagnosticsSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDiagnosticsSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiagnosticsSettingsStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IDiagnosticsSettingsStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiagnosticsSettingsStatics", "GetForUser", 7usize, |this| ((**(this as *const *const IDiagnosticsSettingsStatics_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault, GetForUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiagnosticsSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IFirstSignInSettings_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFirstSignInSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFirstSignInSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFirstSignInSettingsStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IFirstSignInSettingsStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFirstSignInSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Globalization"))]
+impl windows_core::imp::ProxyVtable for IGlobalizationPreferencesForUser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesForUser", "User", 6usize, |this| ((**(this as *const *const IGlobalizationPreferencesForUser_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Calendars(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesForUser", "Calendars", 7usize, |this| ((**(this as *const *const IGlobalizationPreferencesForUser_Vtbl)).Calendars)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Clocks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesForUser", "Clocks", 8usize, |this| ((**(this as *const *const IGlobalizationPreferencesForUser_Vtbl)).Clocks)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Currencies(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesForUser", "Currencies", 9usize, |this| ((**(this as *const *const IGlobalizationPreferencesForUser_Vtbl)).Currencies)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Languages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesForUser", "Languages", 10usize, |this| ((**(this as *const *const IGlobalizationPreferencesForUser_Vtbl)).Languages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HomeGeographicRegion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesForUser", "HomeGeographicRegion", 11usize, |this| ((**(this as *const *const IGlobalizationPreferencesForUser_Vtbl)).HomeGeographicRegion)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Globalization")]
+        unsafe extern "system" fn WeekStartsOn(this: *mut core::ffi::c_void, result__: *mut super::super::Globalization::DayOfWeek) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesForUser", "WeekStartsOn", 12usize, |this| ((**(this as *const *const IGlobalizationPreferencesForUser_Vtbl)).WeekStartsOn)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            User,
+            #[cfg(feature = "Foundation_Collections")]
+            Calendars,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Calendars: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Clocks,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Clocks: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Currencies,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Currencies: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Languages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Languages: 0,
+            HomeGeographicRegion,
+            #[cfg(feature = "Globalization")]
+            WeekStartsOn,
+            #[cfg(not(feature = "Globalization"))]
+            WeekStartsOn: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGlobalizationPreferencesForUser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Globalization"))]
+impl windows_core::imp::ProxyVtable for IGlobalizationPreferencesStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Calendars(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesStatics", "Calendars", 6usize, |this| ((**(this as *const *const IGlobalizationPreferencesStatics_Vtbl)).Calendars)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Clocks(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesStatics", "Clocks", 7usize, |this| ((**(this as *const *const IGlobalizationPreferencesStatics_Vtbl)).Clocks)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Currencies(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesStatics", "Currencies", 8usize, |this| ((**(this as *const *const IGlobalizationPreferencesStatics_Vtbl)).Currencies)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Languages(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesStatics", "Languages", 9usize, |this| ((**(this as *const *const IGlobalizationPreferencesStatics_Vtbl)).Languages)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HomeGeographicRegion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesStatics", "HomeGeographicRegion", 10usize, |this| ((**(this as *const *const IGlobalizationPreferencesStatics_Vtbl)).HomeGeographicRegion)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Globalization")]
+        unsafe extern "system" fn WeekStartsOn(this: *mut core::ffi::c_void, result__: *mut super::super::Globalization::DayOfWeek) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesStatics", "WeekStartsOn", 11usize, |this| ((**(this as *const *const IGlobalizationPreferencesStatics_Vtbl)).WeekStartsOn)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Calendars,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Calendars: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Clocks,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Clocks: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Currencies,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Currencies: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Languages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Languages: 0,
+            HomeGeographicRegion,
+            #[cfg(feature = "Globalization")]
+            WeekStartsOn,
+            #[cfg(not(feature = "Globalization"))]
+            WeekStartsOn: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGlobalizationPreferencesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGlobalizationPreferencesStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TrySetHomeGeographicRegion(this: *mut core::ffi::c_void, region: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesStatics2", "TrySetHomeGeographicRegion", 6usize, |this| ((**(this as *const *const IGlobalizationPreferencesStatics2_Vtbl)).TrySetHomeGeographicRegion)(this, region, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TrySetLanguages(this: *mut core::ffi::c_void, languagetags: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesStatics2", "TrySetLanguages", 7usize, |this| ((**(this as *const *const IGlobalizationPreferencesStatics2_Vtbl)).TrySetLanguages)(this, languagetags, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TrySetHomeGeographicRegion,
+            #[cfg(feature = "Foundation_Collections")]
+            TrySetLanguages,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TrySetLanguages: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGlobalizationPreferencesStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGlobalizationPreferencesStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalizationPreferencesStatics3", "GetForUser", 6usize, |this| ((**(this as *const *const IGlobalizationPreferencesStatics3_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGlobalizationPreferencesStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILockScreenImageFeedStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestSetImageFeedAsync(this: *mut core::ffi::c_void, syndicationfeeduri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenImageFeedStatics", "RequestSetImageFeedAsync", 6usize, |this| ((**(this as *const *const ILockScreenImageFeedStatics_Vtbl)).RequestSetImageFeedAsync)(this, syndicationfeeduri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryRemoveImageFeed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenImageFeedStatics", "TryRemoveImageFeed", 7usize, |this| ((**(this as *const *const ILockScreenImageFeedStatics_Vtbl)).TryRemoveImageFeed)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestSetImageFeedAsync, TryRemoveImageFeed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILockScreenImageFeedStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ILockScreenStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OriginalImageFile(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenStatics", "OriginalImageFile", 6usize, |this| ((**(this as *const *const ILockScreenStatics_Vtbl)).OriginalImageFile)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetImageStream(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenStatics", "GetImageStream", 7usize, |this| ((**(this as *const *const ILockScreenStatics_Vtbl)).GetImageStream)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn SetImageFileAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenStatics", "SetImageFileAsync", 8usize, |this| ((**(this as *const *const ILockScreenStatics_Vtbl)).SetImageFileAsync)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetImageStreamAsync(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockScreenStatics", "SetImageStreamAsync", 9usize, |this| ((**(this as *const *const ILockScreenStatics_Vtbl)).SetImageStreamAsync)(this, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OriginalImageFile,
+            #[cfg(feature = "Storage_Streams")]
+            GetImageStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetImageStream: 0,
+            #[cfg(feature = "Storage")]
+            SetImageFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            SetImageFileAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetImageStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetImageStreamAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILockScreenStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for IUserInformationStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AccountPictureChangeEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInformationStatics", "AccountPictureChangeEnabled", 6usize, |this| ((**(this as *const *const IUserInformationStatics_Vtbl)).AccountPictureChangeEnabled)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn NameAccessAllowed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInformationStatics", "NameAccessAllowed", 7usize, |this| ((**(this as *const *const IUserInformationStatics_Vtbl)).NameAccessAllowed)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage", feature = "deprecated"))]
+        unsafe extern "system" fn GetAccountPicture(this: *mut core::ffi::c_void, kind: AccountPictureKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInformationStatics", "GetAccountPicture", 8usize, |this| ((**(this as *const *const IUserInformationStatics_Vtbl)).GetAccountPicture)(this, kind, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage", feature = "deprecated"))]
+        unsafe extern "system" fn SetAccountPictureAsync(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInformationStatics", "SetAccountPictureAsync", 9usize, |this| ((**(this as *const *const IUserInformationStatics_Vtbl)).SetAccountPictureAsync)(this, image, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage", feature = "deprecated"))]
+        unsafe extern "system" fn SetAccountPicturesAsync(this: *mut core::ffi::c_void, smallimage: *mut core::ffi::c_void, largeimage: *mut core::ffi::c_void, video: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInformationStatics", "SetAccountPicturesAsync", 10usize, |this| ((**(this as *const *const IUserInformationStatics_Vtbl)).SetAccountPicturesAsync)(this, smallimage, largeimage, video, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn SetAccountPictureFromStreamAsync(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInformationStatics", "SetAccountPictureFromStreamAsync", 11usize, |this| ((**(this as *const *const IUserInformationStatics_Vtbl)).SetAccountPictureFromStreamAsync)(this, image, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+        unsafe extern "system" fn SetAccountPicturesFromStreamsAsync(this: *mut core::ffi::c_void, smallimage: *mut core::ffi::c_void, largeimage: *mut core::ffi::c_void, video: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInformationStatics", "SetAccountPicturesFromStreamsAsync", 12usize, |this| ((**(this as *const *const IUserInformationStatics_Vtbl)).SetAccountPicturesFromStreamsAsync)(this, smallimage, largeimage, video, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn AccountPictureChanged(this: *mut core::ffi::c_void, changehandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInformationStatics", "AccountPictureChanged", 13usize, |this| ((**(this as *const *const IUserInformationStatics_Vtbl)).AccountPictureChanged)(this, changehandler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveAccountPictureChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInformationStatics", "RemoveAccountPictureChanged", 14usize, |this| ((**(this as *const *const IUserInformationStatics_Vtbl)).RemoveAccountPictureChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetDisplayNameAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInformationStatics", "GetDisplayNameAsync", 15usize, |this| ((**(this as *const *const IUserInformationStatics_Vtbl)).GetDisplayNameAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetFirstNameAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInformationStatics", "GetFirstNameAsync", 16usize, |this| ((**(this as *const *const IUserInformationStatics_Vtbl)).GetFirstNameAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetLastNameAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInformationStatics", "GetLastNameAsync", 17usize, |this| ((**(this as *const *const IUserInformationStatics_Vtbl)).GetLastNameAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetPrincipalNameAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInformationStatics", "GetPrincipalNameAsync", 18usize, |this| ((**(this as *const *const IUserInformationStatics_Vtbl)).GetPrincipalNameAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetSessionInitiationProtocolUriAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInformationStatics", "GetSessionInitiationProtocolUriAsync", 19usize, |this| ((**(this as *const *const IUserInformationStatics_Vtbl)).GetSessionInitiationProtocolUriAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetDomainNameAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInformationStatics", "GetDomainNameAsync", 20usize, |this| ((**(this as *const *const IUserInformationStatics_Vtbl)).GetDomainNameAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            AccountPictureChangeEnabled,
+            #[cfg(not(feature = "deprecated"))]
+            AccountPictureChangeEnabled: 0,
+            #[cfg(feature = "deprecated")]
+            NameAccessAllowed,
+            #[cfg(not(feature = "deprecated"))]
+            NameAccessAllowed: 0,
+            #[cfg(all(feature = "Storage", feature = "deprecated"))]
+            GetAccountPicture,
+            #[cfg(not(all(feature = "Storage", feature = "deprecated")))]
+            GetAccountPicture: 0,
+            #[cfg(all(feature = "Storage", feature = "deprecated"))]
+            SetAccountPictureAsync,
+            #[cfg(not(all(feature = "Storage", feature = "deprecated")))]
+            SetAccountPictureAsync: 0,
+            #[cfg(all(feature = "Storage", feature = "deprecated"))]
+            SetAccountPicturesAsync,
+            #[cfg(not(all(feature = "Storage", feature = "deprecated")))]
+            SetAccountPicturesAsync: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            SetAccountPictureFromStreamAsync,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            SetAccountPictureFromStreamAsync: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "deprecated"))]
+            SetAccountPicturesFromStreamsAsync,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "deprecated")))]
+            SetAccountPicturesFromStreamsAsync: 0,
+            #[cfg(feature = "deprecated")]
+            AccountPictureChanged,
+            #[cfg(not(feature = "deprecated"))]
+            AccountPictureChanged: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveAccountPictureChanged,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveAccountPictureChanged: 0,
+            #[cfg(feature = "deprecated")]
+            GetDisplayNameAsync,
+            #[cfg(not(feature = "deprecated"))]
+            GetDisplayNameAsync: 0,
+            #[cfg(feature = "deprecated")]
+            GetFirstNameAsync,
+            #[cfg(not(feature = "deprecated"))]
+            GetFirstNameAsync: 0,
+            #[cfg(feature = "deprecated")]
+            GetLastNameAsync,
+            #[cfg(not(feature = "deprecated"))]
+            GetLastNameAsync: 0,
+            #[cfg(feature = "deprecated")]
+            GetPrincipalNameAsync,
+            #[cfg(not(feature = "deprecated"))]
+            GetPrincipalNameAsync: 0,
+            #[cfg(feature = "deprecated")]
+            GetSessionInitiationProtocolUriAsync,
+            #[cfg(not(feature = "deprecated"))]
+            GetSessionInitiationProtocolUriAsync: 0,
+            #[cfg(feature = "deprecated")]
+            GetDomainNameAsync,
+            #[cfg(not(feature = "deprecated"))]
+            GetDomainNameAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserInformationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for IUserProfilePersonalizationSettings_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn TrySetLockScreenImageAsync(this: *mut core::ffi::c_void, imagefile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserProfilePersonalizationSettings", "TrySetLockScreenImageAsync", 6usize, |this| ((**(this as *const *const IUserProfilePersonalizationSettings_Vtbl)).TrySetLockScreenImageAsync)(this, imagefile, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn TrySetWallpaperImageAsync(this: *mut core::ffi::c_void, imagefile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserProfilePersonalizationSettings", "TrySetWallpaperImageAsync", 7usize, |this| ((**(this as *const *const IUserProfilePersonalizationSettings_Vtbl)).TrySetWallpaperImageAsync)(this, imagefile, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            TrySetLockScreenImageAsync,
+            #[cfg(not(feature = "Storage"))]
+            TrySetLockScreenImageAsync: 0,
+            #[cfg(feature = "Storage")]
+            TrySetWallpaperImageAsync,
+            #[cfg(not(feature = "Storage"))]
+            TrySetWallpaperImageAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserProfilePersonalizationSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserProfilePersonalizationSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserProfilePersonalizationSettingsStatics", "Current", 6usize, |this| ((**(this as *const *const IUserProfilePersonalizationSettingsStatics_Vtbl)).Current)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserProfilePersonalizationSettingsStatics", "IsSupported", 7usize, |this| ((**(this as *const *const IUserProfilePersonalizationSettingsStatics_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Current, IsSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserProfilePersonalizationSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -3955,6 +3955,7 @@ impl AppDiagnosticInfoWatcherStatus {
 impl windows_core::TypeKind for AppDiagnosticInfoWatcherStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppDiagnosticInfoWatcherStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppDiagnosticInfoWatcherStatus").field(&self.0).finish()
@@ -3975,6 +3976,7 @@ impl AppMemoryUsageLevel {
 impl windows_core::TypeKind for AppMemoryUsageLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppMemoryUsageLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppMemoryUsageLevel").field(&self.0).finish()
@@ -3994,6 +3996,7 @@ impl AppResourceGroupEnergyQuotaState {
 impl windows_core::TypeKind for AppResourceGroupEnergyQuotaState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppResourceGroupEnergyQuotaState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppResourceGroupEnergyQuotaState").field(&self.0).finish()
@@ -4015,6 +4018,7 @@ impl AppResourceGroupExecutionState {
 impl windows_core::TypeKind for AppResourceGroupExecutionState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppResourceGroupExecutionState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppResourceGroupExecutionState").field(&self.0).finish()
@@ -4037,6 +4041,7 @@ impl AppResourceGroupInfoWatcherStatus {
 impl windows_core::TypeKind for AppResourceGroupInfoWatcherStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppResourceGroupInfoWatcherStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppResourceGroupInfoWatcherStatus").field(&self.0).finish()
@@ -4056,6 +4061,7 @@ impl AutoUpdateTimeZoneStatus {
 impl windows_core::TypeKind for AutoUpdateTimeZoneStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AutoUpdateTimeZoneStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AutoUpdateTimeZoneStatus").field(&self.0).finish()
@@ -4076,6 +4082,7 @@ impl DiagnosticAccessStatus {
 impl windows_core::TypeKind for DiagnosticAccessStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DiagnosticAccessStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DiagnosticAccessStatus").field(&self.0).finish()
@@ -4095,6 +4102,7 @@ impl DispatcherQueuePriority {
 impl windows_core::TypeKind for DispatcherQueuePriority {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DispatcherQueuePriority {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DispatcherQueuePriority").field(&self.0).finish()
@@ -4116,6 +4124,7 @@ impl LaunchFileStatus {
 impl windows_core::TypeKind for LaunchFileStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LaunchFileStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LaunchFileStatus").field(&self.0).finish()
@@ -4137,6 +4146,7 @@ impl LaunchQuerySupportStatus {
 impl windows_core::TypeKind for LaunchQuerySupportStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LaunchQuerySupportStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LaunchQuerySupportStatus").field(&self.0).finish()
@@ -4155,6 +4165,7 @@ impl LaunchQuerySupportType {
 impl windows_core::TypeKind for LaunchQuerySupportType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LaunchQuerySupportType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LaunchQuerySupportType").field(&self.0).finish()
@@ -4175,6 +4186,7 @@ impl LaunchUriStatus {
 impl windows_core::TypeKind for LaunchUriStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LaunchUriStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LaunchUriStatus").field(&self.0).finish()
@@ -4193,6 +4205,7 @@ impl PowerState {
 impl windows_core::TypeKind for PowerState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PowerState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PowerState").field(&self.0).finish()
@@ -4216,6 +4229,7 @@ impl ProcessorArchitecture {
 impl windows_core::TypeKind for ProcessorArchitecture {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProcessorArchitecture {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProcessorArchitecture").field(&self.0).finish()
@@ -4240,6 +4254,7 @@ impl RemoteLaunchUriStatus {
 impl windows_core::TypeKind for RemoteLaunchUriStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteLaunchUriStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteLaunchUriStatus").field(&self.0).finish()
@@ -4258,6 +4273,7 @@ impl ShutdownKind {
 impl windows_core::TypeKind for ShutdownKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ShutdownKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ShutdownKind").field(&self.0).finish()
@@ -4277,6 +4293,7 @@ impl UserAgeConsentGroup {
 impl windows_core::TypeKind for UserAgeConsentGroup {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserAgeConsentGroup {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserAgeConsentGroup").field(&self.0).finish()
@@ -4298,6 +4315,7 @@ impl UserAgeConsentResult {
 impl windows_core::TypeKind for UserAgeConsentResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserAgeConsentResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserAgeConsentResult").field(&self.0).finish()
@@ -4317,6 +4335,7 @@ impl UserAuthenticationStatus {
 impl windows_core::TypeKind for UserAuthenticationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserAuthenticationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserAuthenticationStatus").field(&self.0).finish()
@@ -4337,6 +4356,7 @@ impl UserPictureSize {
 impl windows_core::TypeKind for UserPictureSize {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserPictureSize {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserPictureSize").field(&self.0).finish()
@@ -4358,6 +4378,7 @@ impl UserType {
 impl windows_core::TypeKind for UserType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserType").field(&self.0).finish()
@@ -4380,6 +4401,7 @@ impl UserWatcherStatus {
 impl windows_core::TypeKind for UserWatcherStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserWatcherStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserWatcherStatus").field(&self.0).finish()
@@ -4398,6 +4420,7 @@ impl UserWatcherUpdateKind {
 impl windows_core::TypeKind for UserWatcherUpdateKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserWatcherUpdateKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserWatcherUpdateKind").field(&self.0).finish()
@@ -4586,6 +4609,7 @@ impl VirtualKey {
 impl windows_core::TypeKind for VirtualKey {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VirtualKey {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VirtualKey").field(&self.0).finish()
@@ -4607,6 +4631,7 @@ impl VirtualKeyModifiers {
 impl windows_core::TypeKind for VirtualKeyModifiers {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VirtualKeyModifiers {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VirtualKeyModifiers").field(&self.0).finish()
@@ -4705,5 +4730,8 @@ pub struct DispatcherQueueHandler_Vtbl {
     pub base__: windows_core::IUnknown_Vtbl,
     pub Invoke: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
 }
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "System/DispatcherQueue.rs"));
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,2069 @@
+impl windows_core::imp::ProxyVtable for IAppActivationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppActivationResult", "ExtendedError", 6usize, |this| ((**(this as *const *const IAppActivationResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppResourceGroupInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppActivationResult", "AppResourceGroupInfo", 7usize, |this| ((**(this as *const *const IAppActivationResult_Vtbl)).AppResourceGroupInfo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError, AppResourceGroupInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppActivationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel")]
+impl windows_core::imp::ProxyVtable for IAppDiagnosticInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn AppInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfo", "AppInfo", 6usize, |this| ((**(this as *const *const IAppDiagnosticInfo_Vtbl)).AppInfo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel")]
+            AppInfo,
+            #[cfg(not(feature = "ApplicationModel"))]
+            AppInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppDiagnosticInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppDiagnosticInfo2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetResourceGroups(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfo2", "GetResourceGroups", 6usize, |this| ((**(this as *const *const IAppDiagnosticInfo2_Vtbl)).GetResourceGroups)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateResourceGroupWatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfo2", "CreateResourceGroupWatcher", 7usize, |this| ((**(this as *const *const IAppDiagnosticInfo2_Vtbl)).CreateResourceGroupWatcher)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetResourceGroups,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetResourceGroups: 0,
+            CreateResourceGroupWatcher,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppDiagnosticInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppDiagnosticInfo3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LaunchAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfo3", "LaunchAsync", 6usize, |this| ((**(this as *const *const IAppDiagnosticInfo3_Vtbl)).LaunchAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LaunchAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppDiagnosticInfo3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppDiagnosticInfoStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestInfoAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoStatics", "RequestInfoAsync", 6usize, |this| ((**(this as *const *const IAppDiagnosticInfoStatics_Vtbl)).RequestInfoAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestInfoAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestInfoAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppDiagnosticInfoStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppDiagnosticInfoStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoStatics2", "CreateWatcher", 6usize, |this| ((**(this as *const *const IAppDiagnosticInfoStatics2_Vtbl)).CreateWatcher)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoStatics2", "RequestAccessAsync", 7usize, |this| ((**(this as *const *const IAppDiagnosticInfoStatics2_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestInfoForPackageAsync(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoStatics2", "RequestInfoForPackageAsync", 8usize, |this| ((**(this as *const *const IAppDiagnosticInfoStatics2_Vtbl)).RequestInfoForPackageAsync)(this, packagefamilyname, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestInfoForAppAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoStatics2", "RequestInfoForAppAsync", 9usize, |this| ((**(this as *const *const IAppDiagnosticInfoStatics2_Vtbl)).RequestInfoForAppAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RequestInfoForAppUserModelId(this: *mut core::ffi::c_void, appusermodelid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoStatics2", "RequestInfoForAppUserModelId", 10usize, |this| ((**(this as *const *const IAppDiagnosticInfoStatics2_Vtbl)).RequestInfoForAppUserModelId)(this, appusermodelid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateWatcher,
+            RequestAccessAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestInfoForPackageAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestInfoForPackageAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestInfoForAppAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestInfoForAppAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            RequestInfoForAppUserModelId,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RequestInfoForAppUserModelId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppDiagnosticInfoStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppDiagnosticInfoWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Added(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoWatcher", "Added", 6usize, |this| ((**(this as *const *const IAppDiagnosticInfoWatcher_Vtbl)).Added)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdded(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoWatcher", "RemoveAdded", 7usize, |this| ((**(this as *const *const IAppDiagnosticInfoWatcher_Vtbl)).RemoveAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Removed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoWatcher", "Removed", 8usize, |this| ((**(this as *const *const IAppDiagnosticInfoWatcher_Vtbl)).Removed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoved(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoWatcher", "RemoveRemoved", 9usize, |this| ((**(this as *const *const IAppDiagnosticInfoWatcher_Vtbl)).RemoveRemoved)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoWatcher", "EnumerationCompleted", 10usize, |this| ((**(this as *const *const IAppDiagnosticInfoWatcher_Vtbl)).EnumerationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnumerationCompleted(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoWatcher", "RemoveEnumerationCompleted", 11usize, |this| ((**(this as *const *const IAppDiagnosticInfoWatcher_Vtbl)).RemoveEnumerationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stopped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoWatcher", "Stopped", 12usize, |this| ((**(this as *const *const IAppDiagnosticInfoWatcher_Vtbl)).Stopped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStopped(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoWatcher", "RemoveStopped", 13usize, |this| ((**(this as *const *const IAppDiagnosticInfoWatcher_Vtbl)).RemoveStopped)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut AppDiagnosticInfoWatcherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoWatcher", "Status", 14usize, |this| ((**(this as *const *const IAppDiagnosticInfoWatcher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoWatcher", "Start", 15usize, |this| ((**(this as *const *const IAppDiagnosticInfoWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoWatcher", "Stop", 16usize, |this| ((**(this as *const *const IAppDiagnosticInfoWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Added,
+            RemoveAdded,
+            Removed,
+            RemoveRemoved,
+            EnumerationCompleted,
+            RemoveEnumerationCompleted,
+            Stopped,
+            RemoveStopped,
+            Status,
+            Start,
+            Stop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppDiagnosticInfoWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppDiagnosticInfoWatcherEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppDiagnosticInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDiagnosticInfoWatcherEventArgs", "AppDiagnosticInfo", 6usize, |this| ((**(this as *const *const IAppDiagnosticInfoWatcherEventArgs_Vtbl)).AppDiagnosticInfo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AppDiagnosticInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppDiagnosticInfoWatcherEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppExecutionStateChangeResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppExecutionStateChangeResult", "ExtendedError", 6usize, |this| ((**(this as *const *const IAppExecutionStateChangeResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppExecutionStateChangeResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppMemoryReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrivateCommitUsage(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppMemoryReport", "PrivateCommitUsage", 6usize, |this| ((**(this as *const *const IAppMemoryReport_Vtbl)).PrivateCommitUsage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PeakPrivateCommitUsage(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppMemoryReport", "PeakPrivateCommitUsage", 7usize, |this| ((**(this as *const *const IAppMemoryReport_Vtbl)).PeakPrivateCommitUsage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalCommitUsage(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppMemoryReport", "TotalCommitUsage", 8usize, |this| ((**(this as *const *const IAppMemoryReport_Vtbl)).TotalCommitUsage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalCommitLimit(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppMemoryReport", "TotalCommitLimit", 9usize, |this| ((**(this as *const *const IAppMemoryReport_Vtbl)).TotalCommitLimit)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PrivateCommitUsage,
+            PeakPrivateCommitUsage,
+            TotalCommitUsage,
+            TotalCommitLimit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppMemoryReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppMemoryReport2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExpectedTotalCommitLimit(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppMemoryReport2", "ExpectedTotalCommitLimit", 6usize, |this| ((**(this as *const *const IAppMemoryReport2_Vtbl)).ExpectedTotalCommitLimit)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExpectedTotalCommitLimit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppMemoryReport2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppMemoryUsageLimitChangingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OldLimit(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppMemoryUsageLimitChangingEventArgs", "OldLimit", 6usize, |this| ((**(this as *const *const IAppMemoryUsageLimitChangingEventArgs_Vtbl)).OldLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewLimit(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppMemoryUsageLimitChangingEventArgs", "NewLimit", 7usize, |this| ((**(this as *const *const IAppMemoryUsageLimitChangingEventArgs_Vtbl)).NewLimit)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OldLimit, NewLimit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppMemoryUsageLimitChangingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppResourceGroupBackgroundTaskReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TaskId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupBackgroundTaskReport", "TaskId", 6usize, |this| ((**(this as *const *const IAppResourceGroupBackgroundTaskReport_Vtbl)).TaskId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupBackgroundTaskReport", "Name", 7usize, |this| ((**(this as *const *const IAppResourceGroupBackgroundTaskReport_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Trigger(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupBackgroundTaskReport", "Trigger", 8usize, |this| ((**(this as *const *const IAppResourceGroupBackgroundTaskReport_Vtbl)).Trigger)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EntryPoint(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupBackgroundTaskReport", "EntryPoint", 9usize, |this| ((**(this as *const *const IAppResourceGroupBackgroundTaskReport_Vtbl)).EntryPoint)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TaskId, Name, Trigger, EntryPoint }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppResourceGroupBackgroundTaskReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "System_Diagnostics"))]
+impl windows_core::imp::ProxyVtable for IAppResourceGroupInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstanceId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfo", "InstanceId", 6usize, |this| ((**(this as *const *const IAppResourceGroupInfo_Vtbl)).InstanceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsShared(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfo", "IsShared", 7usize, |this| ((**(this as *const *const IAppResourceGroupInfo_Vtbl)).IsShared)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetBackgroundTaskReports(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfo", "GetBackgroundTaskReports", 8usize, |this| ((**(this as *const *const IAppResourceGroupInfo_Vtbl)).GetBackgroundTaskReports)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMemoryReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfo", "GetMemoryReport", 9usize, |this| ((**(this as *const *const IAppResourceGroupInfo_Vtbl)).GetMemoryReport)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "System_Diagnostics"))]
+        unsafe extern "system" fn GetProcessDiagnosticInfos(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfo", "GetProcessDiagnosticInfos", 10usize, |this| ((**(this as *const *const IAppResourceGroupInfo_Vtbl)).GetProcessDiagnosticInfos)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStateReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfo", "GetStateReport", 11usize, |this| ((**(this as *const *const IAppResourceGroupInfo_Vtbl)).GetStateReport)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InstanceId,
+            IsShared,
+            #[cfg(feature = "Foundation_Collections")]
+            GetBackgroundTaskReports,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetBackgroundTaskReports: 0,
+            GetMemoryReport,
+            #[cfg(all(feature = "Foundation_Collections", feature = "System_Diagnostics"))]
+            GetProcessDiagnosticInfos,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "System_Diagnostics")))]
+            GetProcessDiagnosticInfos: 0,
+            GetStateReport,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppResourceGroupInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppResourceGroupInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartSuspendAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfo2", "StartSuspendAsync", 6usize, |this| ((**(this as *const *const IAppResourceGroupInfo2_Vtbl)).StartSuspendAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartResumeAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfo2", "StartResumeAsync", 7usize, |this| ((**(this as *const *const IAppResourceGroupInfo2_Vtbl)).StartResumeAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTerminateAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfo2", "StartTerminateAsync", 8usize, |this| ((**(this as *const *const IAppResourceGroupInfo2_Vtbl)).StartTerminateAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartSuspendAsync, StartResumeAsync, StartTerminateAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppResourceGroupInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppResourceGroupInfoWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Added(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcher", "Added", 6usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcher_Vtbl)).Added)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdded(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcher", "RemoveAdded", 7usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcher_Vtbl)).RemoveAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Removed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcher", "Removed", 8usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcher_Vtbl)).Removed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoved(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcher", "RemoveRemoved", 9usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcher_Vtbl)).RemoveRemoved)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcher", "EnumerationCompleted", 10usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcher_Vtbl)).EnumerationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnumerationCompleted(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcher", "RemoveEnumerationCompleted", 11usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcher_Vtbl)).RemoveEnumerationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stopped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcher", "Stopped", 12usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcher_Vtbl)).Stopped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStopped(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcher", "RemoveStopped", 13usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcher_Vtbl)).RemoveStopped)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecutionStateChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcher", "ExecutionStateChanged", 14usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcher_Vtbl)).ExecutionStateChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveExecutionStateChanged(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcher", "RemoveExecutionStateChanged", 15usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcher_Vtbl)).RemoveExecutionStateChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut AppResourceGroupInfoWatcherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcher", "Status", 16usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcher", "Start", 17usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcher", "Stop", 18usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Added,
+            RemoveAdded,
+            Removed,
+            RemoveRemoved,
+            EnumerationCompleted,
+            RemoveEnumerationCompleted,
+            Stopped,
+            RemoveStopped,
+            ExecutionStateChanged,
+            RemoveExecutionStateChanged,
+            Status,
+            Start,
+            Stop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppResourceGroupInfoWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppResourceGroupInfoWatcherEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AppDiagnosticInfos(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcherEventArgs", "AppDiagnosticInfos", 6usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcherEventArgs_Vtbl)).AppDiagnosticInfos)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppResourceGroupInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcherEventArgs", "AppResourceGroupInfo", 7usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcherEventArgs_Vtbl)).AppResourceGroupInfo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AppDiagnosticInfos,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AppDiagnosticInfos: 0,
+            AppResourceGroupInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppResourceGroupInfoWatcherEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppResourceGroupInfoWatcherExecutionStateChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AppDiagnosticInfos(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcherExecutionStateChangedEventArgs", "AppDiagnosticInfos", 6usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcherExecutionStateChangedEventArgs_Vtbl)).AppDiagnosticInfos)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppResourceGroupInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupInfoWatcherExecutionStateChangedEventArgs", "AppResourceGroupInfo", 7usize, |this| ((**(this as *const *const IAppResourceGroupInfoWatcherExecutionStateChangedEventArgs_Vtbl)).AppResourceGroupInfo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AppDiagnosticInfos,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AppDiagnosticInfos: 0,
+            AppResourceGroupInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppResourceGroupInfoWatcherExecutionStateChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppResourceGroupMemoryReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CommitUsageLimit(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupMemoryReport", "CommitUsageLimit", 6usize, |this| ((**(this as *const *const IAppResourceGroupMemoryReport_Vtbl)).CommitUsageLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitUsageLevel(this: *mut core::ffi::c_void, result__: *mut AppMemoryUsageLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupMemoryReport", "CommitUsageLevel", 7usize, |this| ((**(this as *const *const IAppResourceGroupMemoryReport_Vtbl)).CommitUsageLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivateCommitUsage(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupMemoryReport", "PrivateCommitUsage", 8usize, |this| ((**(this as *const *const IAppResourceGroupMemoryReport_Vtbl)).PrivateCommitUsage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalCommitUsage(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupMemoryReport", "TotalCommitUsage", 9usize, |this| ((**(this as *const *const IAppResourceGroupMemoryReport_Vtbl)).TotalCommitUsage)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CommitUsageLimit,
+            CommitUsageLevel,
+            PrivateCommitUsage,
+            TotalCommitUsage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppResourceGroupMemoryReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppResourceGroupStateReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExecutionState(this: *mut core::ffi::c_void, result__: *mut AppResourceGroupExecutionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupStateReport", "ExecutionState", 6usize, |this| ((**(this as *const *const IAppResourceGroupStateReport_Vtbl)).ExecutionState)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnergyQuotaState(this: *mut core::ffi::c_void, result__: *mut AppResourceGroupEnergyQuotaState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppResourceGroupStateReport", "EnergyQuotaState", 7usize, |this| ((**(this as *const *const IAppResourceGroupStateReport_Vtbl)).EnergyQuotaState)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExecutionState, EnergyQuotaState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppResourceGroupStateReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppUriHandlerHost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerHost", "Name", 6usize, |this| ((**(this as *const *const IAppUriHandlerHost_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerHost", "SetName", 7usize, |this| ((**(this as *const *const IAppUriHandlerHost_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, SetName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppUriHandlerHost as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppUriHandlerHost2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerHost2", "IsEnabled", 6usize, |this| ((**(this as *const *const IAppUriHandlerHost2_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerHost2", "SetIsEnabled", 7usize, |this| ((**(this as *const *const IAppUriHandlerHost2_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsEnabled, SetIsEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppUriHandlerHost2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppUriHandlerHostFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerHostFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IAppUriHandlerHostFactory_Vtbl)).CreateInstance)(this, name, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppUriHandlerHostFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppUriHandlerRegistration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerRegistration", "Name", 6usize, |this| ((**(this as *const *const IAppUriHandlerRegistration_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerRegistration", "User", 7usize, |this| ((**(this as *const *const IAppUriHandlerRegistration_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAppAddedHostsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerRegistration", "GetAppAddedHostsAsync", 8usize, |this| ((**(this as *const *const IAppUriHandlerRegistration_Vtbl)).GetAppAddedHostsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetAppAddedHostsAsync(this: *mut core::ffi::c_void, hosts: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerRegistration", "SetAppAddedHostsAsync", 9usize, |this| ((**(this as *const *const IAppUriHandlerRegistration_Vtbl)).SetAppAddedHostsAsync)(this, hosts, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            User,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAppAddedHostsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAppAddedHostsAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SetAppAddedHostsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetAppAddedHostsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppUriHandlerRegistration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppUriHandlerRegistration2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetAllHosts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerRegistration2", "GetAllHosts", 6usize, |this| ((**(this as *const *const IAppUriHandlerRegistration2_Vtbl)).GetAllHosts)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UpdateHosts(this: *mut core::ffi::c_void, hosts: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerRegistration2", "UpdateHosts", 7usize, |this| ((**(this as *const *const IAppUriHandlerRegistration2_Vtbl)).UpdateHosts)(this, hosts), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerRegistration2", "PackageFamilyName", 8usize, |this| ((**(this as *const *const IAppUriHandlerRegistration2_Vtbl)).PackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetAllHosts,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetAllHosts: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            UpdateHosts,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UpdateHosts: 0,
+            PackageFamilyName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppUriHandlerRegistration2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppUriHandlerRegistrationManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerRegistrationManager", "User", 6usize, |this| ((**(this as *const *const IAppUriHandlerRegistrationManager_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetRegistration(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerRegistrationManager", "TryGetRegistration", 7usize, |this| ((**(this as *const *const IAppUriHandlerRegistrationManager_Vtbl)).TryGetRegistration)(this, name, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, User, TryGetRegistration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppUriHandlerRegistrationManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppUriHandlerRegistrationManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerRegistrationManager2", "PackageFamilyName", 6usize, |this| ((**(this as *const *const IAppUriHandlerRegistrationManager2_Vtbl)).PackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PackageFamilyName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppUriHandlerRegistrationManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppUriHandlerRegistrationManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerRegistrationManagerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IAppUriHandlerRegistrationManagerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerRegistrationManagerStatics", "GetForUser", 7usize, |this| ((**(this as *const *const IAppUriHandlerRegistrationManagerStatics_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault, GetForUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppUriHandlerRegistrationManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppUriHandlerRegistrationManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForPackage(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerRegistrationManagerStatics2", "GetForPackage", 6usize, |this| ((**(this as *const *const IAppUriHandlerRegistrationManagerStatics2_Vtbl)).GetForPackage)(this, packagefamilyname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForPackageForUser(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppUriHandlerRegistrationManagerStatics2", "GetForPackageForUser", 7usize, |this| ((**(this as *const *const IAppUriHandlerRegistrationManagerStatics2_Vtbl)).GetForPackageForUser)(this, packagefamilyname, user, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForPackage, GetForPackageForUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppUriHandlerRegistrationManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDateTimeSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSystemDateTime(this: *mut core::ffi::c_void, utcdatetime: super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDateTimeSettingsStatics", "SetSystemDateTime", 6usize, |this| ((**(this as *const *const IDateTimeSettingsStatics_Vtbl)).SetSystemDateTime)(this, utcdatetime), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSystemDateTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDateTimeSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDispatcherQueue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTimer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueue", "CreateTimer", 6usize, |this| ((**(this as *const *const IDispatcherQueue_Vtbl)).CreateTimer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryEnqueue(this: *mut core::ffi::c_void, callback: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueue", "TryEnqueue", 7usize, |this| ((**(this as *const *const IDispatcherQueue_Vtbl)).TryEnqueue)(this, callback, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryEnqueueWithPriority(this: *mut core::ffi::c_void, priority: DispatcherQueuePriority, callback: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueue", "TryEnqueueWithPriority", 8usize, |this| ((**(this as *const *const IDispatcherQueue_Vtbl)).TryEnqueueWithPriority)(this, priority, callback, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutdownStarting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueue", "ShutdownStarting", 9usize, |this| ((**(this as *const *const IDispatcherQueue_Vtbl)).ShutdownStarting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveShutdownStarting(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueue", "RemoveShutdownStarting", 10usize, |this| ((**(this as *const *const IDispatcherQueue_Vtbl)).RemoveShutdownStarting)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutdownCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueue", "ShutdownCompleted", 11usize, |this| ((**(this as *const *const IDispatcherQueue_Vtbl)).ShutdownCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveShutdownCompleted(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueue", "RemoveShutdownCompleted", 12usize, |this| ((**(this as *const *const IDispatcherQueue_Vtbl)).RemoveShutdownCompleted)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateTimer,
+            TryEnqueue,
+            TryEnqueueWithPriority,
+            ShutdownStarting,
+            RemoveShutdownStarting,
+            ShutdownCompleted,
+            RemoveShutdownCompleted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDispatcherQueue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDispatcherQueue2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasThreadAccess(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueue2", "HasThreadAccess", 6usize, |this| ((**(this as *const *const IDispatcherQueue2_Vtbl)).HasThreadAccess)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HasThreadAccess }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDispatcherQueue2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDispatcherQueueController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DispatcherQueue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueueController", "DispatcherQueue", 6usize, |this| ((**(this as *const *const IDispatcherQueueController_Vtbl)).DispatcherQueue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutdownQueueAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueueController", "ShutdownQueueAsync", 7usize, |this| ((**(this as *const *const IDispatcherQueueController_Vtbl)).ShutdownQueueAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DispatcherQueue, ShutdownQueueAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDispatcherQueueController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDispatcherQueueControllerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateOnDedicatedThread(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueueControllerStatics", "CreateOnDedicatedThread", 6usize, |this| ((**(this as *const *const IDispatcherQueueControllerStatics_Vtbl)).CreateOnDedicatedThread)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateOnDedicatedThread }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDispatcherQueueControllerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDispatcherQueueShutdownStartingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueueShutdownStartingEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const IDispatcherQueueShutdownStartingEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDispatcherQueueShutdownStartingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDispatcherQueueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentThread(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueueStatics", "GetForCurrentThread", 6usize, |this| ((**(this as *const *const IDispatcherQueueStatics_Vtbl)).GetForCurrentThread)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentThread }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDispatcherQueueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDispatcherQueueTimer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Interval(this: *mut core::ffi::c_void, result__: *mut super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueueTimer", "Interval", 6usize, |this| ((**(this as *const *const IDispatcherQueueTimer_Vtbl)).Interval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterval(this: *mut core::ffi::c_void, value: super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueueTimer", "SetInterval", 7usize, |this| ((**(this as *const *const IDispatcherQueueTimer_Vtbl)).SetInterval)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRunning(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueueTimer", "IsRunning", 8usize, |this| ((**(this as *const *const IDispatcherQueueTimer_Vtbl)).IsRunning)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRepeating(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueueTimer", "IsRepeating", 9usize, |this| ((**(this as *const *const IDispatcherQueueTimer_Vtbl)).IsRepeating)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsRepeating(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueueTimer", "SetIsRepeating", 10usize, |this| ((**(this as *const *const IDispatcherQueueTimer_Vtbl)).SetIsRepeating)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueueTimer", "Start", 11usize, |this| ((**(this as *const *const IDispatcherQueueTimer_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueueTimer", "Stop", 12usize, |this| ((**(this as *const *const IDispatcherQueueTimer_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tick(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueueTimer", "Tick", 13usize, |this| ((**(this as *const *const IDispatcherQueueTimer_Vtbl)).Tick)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTick(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatcherQueueTimer", "RemoveTick", 14usize, |this| ((**(this as *const *const IDispatcherQueueTimer_Vtbl)).RemoveTick)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Interval,
+            SetInterval,
+            IsRunning,
+            IsRepeating,
+            SetIsRepeating,
+            Start,
+            Stop,
+            Tick,
+            RemoveTick,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDispatcherQueueTimer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for IFolderLauncherOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+        unsafe extern "system" fn ItemsToSelect(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderLauncherOptions", "ItemsToSelect", 6usize, |this| ((**(this as *const *const IFolderLauncherOptions_Vtbl)).ItemsToSelect)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Storage"))]
+            ItemsToSelect,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Storage")))]
+            ItemsToSelect: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFolderLauncherOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKnownUserPropertiesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownUserPropertiesStatics", "DisplayName", 6usize, |this| ((**(this as *const *const IKnownUserPropertiesStatics_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownUserPropertiesStatics", "FirstName", 7usize, |this| ((**(this as *const *const IKnownUserPropertiesStatics_Vtbl)).FirstName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownUserPropertiesStatics", "LastName", 8usize, |this| ((**(this as *const *const IKnownUserPropertiesStatics_Vtbl)).LastName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownUserPropertiesStatics", "ProviderName", 9usize, |this| ((**(this as *const *const IKnownUserPropertiesStatics_Vtbl)).ProviderName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccountName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownUserPropertiesStatics", "AccountName", 10usize, |this| ((**(this as *const *const IKnownUserPropertiesStatics_Vtbl)).AccountName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GuestHost(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownUserPropertiesStatics", "GuestHost", 11usize, |this| ((**(this as *const *const IKnownUserPropertiesStatics_Vtbl)).GuestHost)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrincipalName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownUserPropertiesStatics", "PrincipalName", 12usize, |this| ((**(this as *const *const IKnownUserPropertiesStatics_Vtbl)).PrincipalName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownUserPropertiesStatics", "DomainName", 13usize, |this| ((**(this as *const *const IKnownUserPropertiesStatics_Vtbl)).DomainName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionInitiationProtocolUri(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownUserPropertiesStatics", "SessionInitiationProtocolUri", 14usize, |this| ((**(this as *const *const IKnownUserPropertiesStatics_Vtbl)).SessionInitiationProtocolUri)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayName,
+            FirstName,
+            LastName,
+            ProviderName,
+            AccountName,
+            GuestHost,
+            PrincipalName,
+            DomainName,
+            SessionInitiationProtocolUri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownUserPropertiesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKnownUserPropertiesStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AgeEnforcementRegion(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownUserPropertiesStatics2", "AgeEnforcementRegion", 6usize, |this| ((**(this as *const *const IKnownUserPropertiesStatics2_Vtbl)).AgeEnforcementRegion)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AgeEnforcementRegion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownUserPropertiesStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ILaunchUriResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut LaunchUriStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILaunchUriResult", "Status", 6usize, |this| ((**(this as *const *const ILaunchUriResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Result(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILaunchUriResult", "Result", 7usize, |this| ((**(this as *const *const ILaunchUriResult_Vtbl)).Result)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Foundation_Collections")]
+            Result,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Result: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILaunchUriResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILauncherOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TreatAsUntrusted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions", "TreatAsUntrusted", 6usize, |this| ((**(this as *const *const ILauncherOptions_Vtbl)).TreatAsUntrusted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTreatAsUntrusted(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions", "SetTreatAsUntrusted", 7usize, |this| ((**(this as *const *const ILauncherOptions_Vtbl)).SetTreatAsUntrusted)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayApplicationPicker(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions", "DisplayApplicationPicker", 8usize, |this| ((**(this as *const *const ILauncherOptions_Vtbl)).DisplayApplicationPicker)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayApplicationPicker(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions", "SetDisplayApplicationPicker", 9usize, |this| ((**(this as *const *const ILauncherOptions_Vtbl)).SetDisplayApplicationPicker)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UI(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions", "UI", 10usize, |this| ((**(this as *const *const ILauncherOptions_Vtbl)).UI)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredApplicationPackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions", "PreferredApplicationPackageFamilyName", 11usize, |this| ((**(this as *const *const ILauncherOptions_Vtbl)).PreferredApplicationPackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredApplicationPackageFamilyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions", "SetPreferredApplicationPackageFamilyName", 12usize, |this| ((**(this as *const *const ILauncherOptions_Vtbl)).SetPreferredApplicationPackageFamilyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredApplicationDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions", "PreferredApplicationDisplayName", 13usize, |this| ((**(this as *const *const ILauncherOptions_Vtbl)).PreferredApplicationDisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredApplicationDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions", "SetPreferredApplicationDisplayName", 14usize, |this| ((**(this as *const *const ILauncherOptions_Vtbl)).SetPreferredApplicationDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FallbackUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions", "FallbackUri", 15usize, |this| ((**(this as *const *const ILauncherOptions_Vtbl)).FallbackUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFallbackUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions", "SetFallbackUri", 16usize, |this| ((**(this as *const *const ILauncherOptions_Vtbl)).SetFallbackUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions", "ContentType", 17usize, |this| ((**(this as *const *const ILauncherOptions_Vtbl)).ContentType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentType(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions", "SetContentType", 18usize, |this| ((**(this as *const *const ILauncherOptions_Vtbl)).SetContentType)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TreatAsUntrusted,
+            SetTreatAsUntrusted,
+            DisplayApplicationPicker,
+            SetDisplayApplicationPicker,
+            UI,
+            PreferredApplicationPackageFamilyName,
+            SetPreferredApplicationPackageFamilyName,
+            PreferredApplicationDisplayName,
+            SetPreferredApplicationDisplayName,
+            FallbackUri,
+            SetFallbackUri,
+            ContentType,
+            SetContentType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILauncherOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Search")]
+impl windows_core::imp::ProxyVtable for ILauncherOptions2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TargetApplicationPackageFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions2", "TargetApplicationPackageFamilyName", 6usize, |this| ((**(this as *const *const ILauncherOptions2_Vtbl)).TargetApplicationPackageFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetApplicationPackageFamilyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions2", "SetTargetApplicationPackageFamilyName", 7usize, |this| ((**(this as *const *const ILauncherOptions2_Vtbl)).SetTargetApplicationPackageFamilyName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Search")]
+        unsafe extern "system" fn NeighboringFilesQuery(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions2", "NeighboringFilesQuery", 8usize, |this| ((**(this as *const *const ILauncherOptions2_Vtbl)).NeighboringFilesQuery)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Search")]
+        unsafe extern "system" fn SetNeighboringFilesQuery(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions2", "SetNeighboringFilesQuery", 9usize, |this| ((**(this as *const *const ILauncherOptions2_Vtbl)).SetNeighboringFilesQuery)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TargetApplicationPackageFamilyName,
+            SetTargetApplicationPackageFamilyName,
+            #[cfg(feature = "Storage_Search")]
+            NeighboringFilesQuery,
+            #[cfg(not(feature = "Storage_Search"))]
+            NeighboringFilesQuery: 0,
+            #[cfg(feature = "Storage_Search")]
+            SetNeighboringFilesQuery,
+            #[cfg(not(feature = "Storage_Search"))]
+            SetNeighboringFilesQuery: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILauncherOptions2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILauncherOptions3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IgnoreAppUriHandlers(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions3", "IgnoreAppUriHandlers", 6usize, |this| ((**(this as *const *const ILauncherOptions3_Vtbl)).IgnoreAppUriHandlers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIgnoreAppUriHandlers(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions3", "SetIgnoreAppUriHandlers", 7usize, |this| ((**(this as *const *const ILauncherOptions3_Vtbl)).SetIgnoreAppUriHandlers)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IgnoreAppUriHandlers, SetIgnoreAppUriHandlers }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILauncherOptions3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILauncherOptions4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LimitPickerToCurrentAppAndAppUriHandlers(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions4", "LimitPickerToCurrentAppAndAppUriHandlers", 6usize, |this| ((**(this as *const *const ILauncherOptions4_Vtbl)).LimitPickerToCurrentAppAndAppUriHandlers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLimitPickerToCurrentAppAndAppUriHandlers(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherOptions4", "SetLimitPickerToCurrentAppAndAppUriHandlers", 7usize, |this| ((**(this as *const *const ILauncherOptions4_Vtbl)).SetLimitPickerToCurrentAppAndAppUriHandlers)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LimitPickerToCurrentAppAndAppUriHandlers,
+            SetLimitPickerToCurrentAppAndAppUriHandlers,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILauncherOptions4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for ILauncherStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LaunchFileAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics", "LaunchFileAsync", 6usize, |this| ((**(this as *const *const ILauncherStatics_Vtbl)).LaunchFileAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LaunchFileWithOptionsAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics", "LaunchFileWithOptionsAsync", 7usize, |this| ((**(this as *const *const ILauncherStatics_Vtbl)).LaunchFileWithOptionsAsync)(this, file, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchUriAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics", "LaunchUriAsync", 8usize, |this| ((**(this as *const *const ILauncherStatics_Vtbl)).LaunchUriAsync)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchUriWithOptionsAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics", "LaunchUriWithOptionsAsync", 9usize, |this| ((**(this as *const *const ILauncherStatics_Vtbl)).LaunchUriWithOptionsAsync)(this, uri, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            LaunchFileAsync,
+            #[cfg(not(feature = "Storage"))]
+            LaunchFileAsync: 0,
+            #[cfg(feature = "Storage")]
+            LaunchFileWithOptionsAsync,
+            #[cfg(not(feature = "Storage"))]
+            LaunchFileWithOptionsAsync: 0,
+            LaunchUriAsync,
+            LaunchUriWithOptionsAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILauncherStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections", feature = "Storage"))]
+impl windows_core::imp::ProxyVtable for ILauncherStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LaunchUriForResultsAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics2", "LaunchUriForResultsAsync", 6usize, |this| ((**(this as *const *const ILauncherStatics2_Vtbl)).LaunchUriForResultsAsync)(this, uri, options, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn LaunchUriForResultsWithDataAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, options: *mut core::ffi::c_void, inputdata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics2", "LaunchUriForResultsWithDataAsync", 7usize, |this| ((**(this as *const *const ILauncherStatics2_Vtbl)).LaunchUriForResultsWithDataAsync)(this, uri, options, inputdata, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn LaunchUriWithDataAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, options: *mut core::ffi::c_void, inputdata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics2", "LaunchUriWithDataAsync", 8usize, |this| ((**(this as *const *const ILauncherStatics2_Vtbl)).LaunchUriWithDataAsync)(this, uri, options, inputdata, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryUriSupportAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, launchquerysupporttype: LaunchQuerySupportType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics2", "QueryUriSupportAsync", 9usize, |this| ((**(this as *const *const ILauncherStatics2_Vtbl)).QueryUriSupportAsync)(this, uri, launchquerysupporttype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryUriSupportWithPackageFamilyNameAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, launchquerysupporttype: LaunchQuerySupportType, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics2", "QueryUriSupportWithPackageFamilyNameAsync", 10usize, |this| ((**(this as *const *const ILauncherStatics2_Vtbl)).QueryUriSupportWithPackageFamilyNameAsync)(this, uri, launchquerysupporttype, packagefamilyname, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn QueryFileSupportAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics2", "QueryFileSupportAsync", 11usize, |this| ((**(this as *const *const ILauncherStatics2_Vtbl)).QueryFileSupportAsync)(this, file, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn QueryFileSupportWithPackageFamilyNameAsync(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics2", "QueryFileSupportWithPackageFamilyNameAsync", 12usize, |this| ((**(this as *const *const ILauncherStatics2_Vtbl)).QueryFileSupportWithPackageFamilyNameAsync)(this, file, packagefamilyname, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindUriSchemeHandlersAsync(this: *mut core::ffi::c_void, scheme: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics2", "FindUriSchemeHandlersAsync", 13usize, |this| ((**(this as *const *const ILauncherStatics2_Vtbl)).FindUriSchemeHandlersAsync)(this, scheme, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindUriSchemeHandlersWithLaunchUriTypeAsync(this: *mut core::ffi::c_void, scheme: core::mem::MaybeUninit<windows_core::HSTRING>, launchquerysupporttype: LaunchQuerySupportType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics2", "FindUriSchemeHandlersWithLaunchUriTypeAsync", 14usize, |this| ((**(this as *const *const ILauncherStatics2_Vtbl)).FindUriSchemeHandlersWithLaunchUriTypeAsync)(this, scheme, launchquerysupporttype, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindFileHandlersAsync(this: *mut core::ffi::c_void, extension: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics2", "FindFileHandlersAsync", 15usize, |this| ((**(this as *const *const ILauncherStatics2_Vtbl)).FindFileHandlersAsync)(this, extension, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LaunchUriForResultsAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            LaunchUriForResultsWithDataAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            LaunchUriForResultsWithDataAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            LaunchUriWithDataAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            LaunchUriWithDataAsync: 0,
+            QueryUriSupportAsync,
+            QueryUriSupportWithPackageFamilyNameAsync,
+            #[cfg(feature = "Storage")]
+            QueryFileSupportAsync,
+            #[cfg(not(feature = "Storage"))]
+            QueryFileSupportAsync: 0,
+            #[cfg(feature = "Storage")]
+            QueryFileSupportWithPackageFamilyNameAsync,
+            #[cfg(not(feature = "Storage"))]
+            QueryFileSupportWithPackageFamilyNameAsync: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindUriSchemeHandlersAsync,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindUriSchemeHandlersAsync: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindUriSchemeHandlersWithLaunchUriTypeAsync,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindUriSchemeHandlersWithLaunchUriTypeAsync: 0,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindFileHandlersAsync,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindFileHandlersAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILauncherStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage")]
+impl windows_core::imp::ProxyVtable for ILauncherStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LaunchFolderAsync(this: *mut core::ffi::c_void, folder: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics3", "LaunchFolderAsync", 6usize, |this| ((**(this as *const *const ILauncherStatics3_Vtbl)).LaunchFolderAsync)(this, folder, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage")]
+        unsafe extern "system" fn LaunchFolderWithOptionsAsync(this: *mut core::ffi::c_void, folder: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics3", "LaunchFolderWithOptionsAsync", 7usize, |this| ((**(this as *const *const ILauncherStatics3_Vtbl)).LaunchFolderWithOptionsAsync)(this, folder, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage")]
+            LaunchFolderAsync,
+            #[cfg(not(feature = "Storage"))]
+            LaunchFolderAsync: 0,
+            #[cfg(feature = "Storage")]
+            LaunchFolderWithOptionsAsync,
+            #[cfg(not(feature = "Storage"))]
+            LaunchFolderWithOptionsAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILauncherStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for ILauncherStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryAppUriSupportAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics4", "QueryAppUriSupportAsync", 6usize, |this| ((**(this as *const *const ILauncherStatics4_Vtbl)).QueryAppUriSupportAsync)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryAppUriSupportWithPackageFamilyNameAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics4", "QueryAppUriSupportWithPackageFamilyNameAsync", 7usize, |this| ((**(this as *const *const ILauncherStatics4_Vtbl)).QueryAppUriSupportWithPackageFamilyNameAsync)(this, uri, packagefamilyname, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+        unsafe extern "system" fn FindAppUriHandlersAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics4", "FindAppUriHandlersAsync", 8usize, |this| ((**(this as *const *const ILauncherStatics4_Vtbl)).FindAppUriHandlersAsync)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchUriForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics4", "LaunchUriForUserAsync", 9usize, |this| ((**(this as *const *const ILauncherStatics4_Vtbl)).LaunchUriForUserAsync)(this, user, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchUriWithOptionsForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics4", "LaunchUriWithOptionsForUserAsync", 10usize, |this| ((**(this as *const *const ILauncherStatics4_Vtbl)).LaunchUriWithOptionsForUserAsync)(this, user, uri, options, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn LaunchUriWithDataForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, options: *mut core::ffi::c_void, inputdata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics4", "LaunchUriWithDataForUserAsync", 11usize, |this| ((**(this as *const *const ILauncherStatics4_Vtbl)).LaunchUriWithDataForUserAsync)(this, user, uri, options, inputdata, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchUriForResultsForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics4", "LaunchUriForResultsForUserAsync", 12usize, |this| ((**(this as *const *const ILauncherStatics4_Vtbl)).LaunchUriForResultsForUserAsync)(this, user, uri, options, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn LaunchUriForResultsWithDataForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, options: *mut core::ffi::c_void, inputdata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics4", "LaunchUriForResultsWithDataForUserAsync", 13usize, |this| ((**(this as *const *const ILauncherStatics4_Vtbl)).LaunchUriForResultsWithDataForUserAsync)(this, user, uri, options, inputdata, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryAppUriSupportAsync,
+            QueryAppUriSupportWithPackageFamilyNameAsync,
+            #[cfg(all(feature = "ApplicationModel", feature = "Foundation_Collections"))]
+            FindAppUriHandlersAsync,
+            #[cfg(not(all(feature = "ApplicationModel", feature = "Foundation_Collections")))]
+            FindAppUriHandlersAsync: 0,
+            LaunchUriForUserAsync,
+            LaunchUriWithOptionsForUserAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            LaunchUriWithDataForUserAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            LaunchUriWithDataForUserAsync: 0,
+            LaunchUriForResultsForUserAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            LaunchUriForResultsWithDataForUserAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            LaunchUriForResultsWithDataForUserAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILauncherStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILauncherStatics5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LaunchFolderPathAsync(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics5", "LaunchFolderPathAsync", 6usize, |this| ((**(this as *const *const ILauncherStatics5_Vtbl)).LaunchFolderPathAsync)(this, path, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchFolderPathWithOptionsAsync(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::HSTRING>, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics5", "LaunchFolderPathWithOptionsAsync", 7usize, |this| ((**(this as *const *const ILauncherStatics5_Vtbl)).LaunchFolderPathWithOptionsAsync)(this, path, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchFolderPathForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics5", "LaunchFolderPathForUserAsync", 8usize, |this| ((**(this as *const *const ILauncherStatics5_Vtbl)).LaunchFolderPathForUserAsync)(this, user, path, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchFolderPathWithOptionsForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::HSTRING>, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherStatics5", "LaunchFolderPathWithOptionsForUserAsync", 9usize, |this| ((**(this as *const *const ILauncherStatics5_Vtbl)).LaunchFolderPathWithOptionsForUserAsync)(this, user, path, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LaunchFolderPathAsync,
+            LaunchFolderPathWithOptionsAsync,
+            LaunchFolderPathForUserAsync,
+            LaunchFolderPathWithOptionsForUserAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILauncherStatics5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Popups")]
+impl windows_core::imp::ProxyVtable for ILauncherUIOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InvocationPoint(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherUIOptions", "InvocationPoint", 6usize, |this| ((**(this as *const *const ILauncherUIOptions_Vtbl)).InvocationPoint)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInvocationPoint(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherUIOptions", "SetInvocationPoint", 7usize, |this| ((**(this as *const *const ILauncherUIOptions_Vtbl)).SetInvocationPoint)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectionRect(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherUIOptions", "SelectionRect", 8usize, |this| ((**(this as *const *const ILauncherUIOptions_Vtbl)).SelectionRect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelectionRect(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherUIOptions", "SetSelectionRect", 9usize, |this| ((**(this as *const *const ILauncherUIOptions_Vtbl)).SetSelectionRect)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn PreferredPlacement(this: *mut core::ffi::c_void, result__: *mut super::UI::Popups::Placement) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherUIOptions", "PreferredPlacement", 10usize, |this| ((**(this as *const *const ILauncherUIOptions_Vtbl)).PreferredPlacement)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn SetPreferredPlacement(this: *mut core::ffi::c_void, value: super::UI::Popups::Placement) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherUIOptions", "SetPreferredPlacement", 11usize, |this| ((**(this as *const *const ILauncherUIOptions_Vtbl)).SetPreferredPlacement)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InvocationPoint,
+            SetInvocationPoint,
+            SelectionRect,
+            SetSelectionRect,
+            #[cfg(feature = "UI_Popups")]
+            PreferredPlacement,
+            #[cfg(not(feature = "UI_Popups"))]
+            PreferredPlacement: 0,
+            #[cfg(feature = "UI_Popups")]
+            SetPreferredPlacement,
+            #[cfg(not(feature = "UI_Popups"))]
+            SetPreferredPlacement: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILauncherUIOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_ViewManagement")]
+impl windows_core::imp::ProxyVtable for ILauncherViewOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_ViewManagement")]
+        unsafe extern "system" fn DesiredRemainingView(this: *mut core::ffi::c_void, result__: *mut super::UI::ViewManagement::ViewSizePreference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherViewOptions", "DesiredRemainingView", 6usize, |this| ((**(this as *const *const ILauncherViewOptions_Vtbl)).DesiredRemainingView)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_ViewManagement")]
+        unsafe extern "system" fn SetDesiredRemainingView(this: *mut core::ffi::c_void, value: super::UI::ViewManagement::ViewSizePreference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILauncherViewOptions", "SetDesiredRemainingView", 7usize, |this| ((**(this as *const *const ILauncherViewOptions_Vtbl)).SetDesiredRemainingView)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_ViewManagement")]
+            DesiredRemainingView,
+            #[cfg(not(feature = "UI_ViewManagement"))]
+            DesiredRemainingView: 0,
+            #[cfg(feature = "UI_ViewManagement")]
+            SetDesiredRemainingView,
+            #[cfg(not(feature = "UI_ViewManagement"))]
+            SetDesiredRemainingView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILauncherViewOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMemoryManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppMemoryUsage(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryManagerStatics", "AppMemoryUsage", 6usize, |this| ((**(this as *const *const IMemoryManagerStatics_Vtbl)).AppMemoryUsage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppMemoryUsageLimit(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryManagerStatics", "AppMemoryUsageLimit", 7usize, |this| ((**(this as *const *const IMemoryManagerStatics_Vtbl)).AppMemoryUsageLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppMemoryUsageLevel(this: *mut core::ffi::c_void, result__: *mut AppMemoryUsageLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryManagerStatics", "AppMemoryUsageLevel", 8usize, |this| ((**(this as *const *const IMemoryManagerStatics_Vtbl)).AppMemoryUsageLevel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppMemoryUsageIncreased(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryManagerStatics", "AppMemoryUsageIncreased", 9usize, |this| ((**(this as *const *const IMemoryManagerStatics_Vtbl)).AppMemoryUsageIncreased)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAppMemoryUsageIncreased(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryManagerStatics", "RemoveAppMemoryUsageIncreased", 10usize, |this| ((**(this as *const *const IMemoryManagerStatics_Vtbl)).RemoveAppMemoryUsageIncreased)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppMemoryUsageDecreased(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryManagerStatics", "AppMemoryUsageDecreased", 11usize, |this| ((**(this as *const *const IMemoryManagerStatics_Vtbl)).AppMemoryUsageDecreased)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAppMemoryUsageDecreased(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryManagerStatics", "RemoveAppMemoryUsageDecreased", 12usize, |this| ((**(this as *const *const IMemoryManagerStatics_Vtbl)).RemoveAppMemoryUsageDecreased)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppMemoryUsageLimitChanging(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryManagerStatics", "AppMemoryUsageLimitChanging", 13usize, |this| ((**(this as *const *const IMemoryManagerStatics_Vtbl)).AppMemoryUsageLimitChanging)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAppMemoryUsageLimitChanging(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryManagerStatics", "RemoveAppMemoryUsageLimitChanging", 14usize, |this| ((**(this as *const *const IMemoryManagerStatics_Vtbl)).RemoveAppMemoryUsageLimitChanging)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppMemoryUsage,
+            AppMemoryUsageLimit,
+            AppMemoryUsageLevel,
+            AppMemoryUsageIncreased,
+            RemoveAppMemoryUsageIncreased,
+            AppMemoryUsageDecreased,
+            RemoveAppMemoryUsageDecreased,
+            AppMemoryUsageLimitChanging,
+            RemoveAppMemoryUsageLimitChanging,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMemoryManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMemoryManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAppMemoryReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryManagerStatics2", "GetAppMemoryReport", 6usize, |this| ((**(this as *const *const IMemoryManagerStatics2_Vtbl)).GetAppMemoryReport)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProcessMemoryReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryManagerStatics2", "GetProcessMemoryReport", 7usize, |this| ((**(this as *const *const IMemoryManagerStatics2_Vtbl)).GetProcessMemoryReport)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAppMemoryReport, GetProcessMemoryReport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMemoryManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMemoryManagerStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TrySetAppMemoryUsageLimit(this: *mut core::ffi::c_void, value: u64, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryManagerStatics3", "TrySetAppMemoryUsageLimit", 6usize, |this| ((**(this as *const *const IMemoryManagerStatics3_Vtbl)).TrySetAppMemoryUsageLimit)(this, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TrySetAppMemoryUsageLimit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMemoryManagerStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMemoryManagerStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExpectedAppMemoryUsageLimit(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryManagerStatics4", "ExpectedAppMemoryUsageLimit", 6usize, |this| ((**(this as *const *const IMemoryManagerStatics4_Vtbl)).ExpectedAppMemoryUsageLimit)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExpectedAppMemoryUsageLimit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMemoryManagerStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IProcessLauncherOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn StandardInput(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessLauncherOptions", "StandardInput", 6usize, |this| ((**(this as *const *const IProcessLauncherOptions_Vtbl)).StandardInput)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetStandardInput(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessLauncherOptions", "SetStandardInput", 7usize, |this| ((**(this as *const *const IProcessLauncherOptions_Vtbl)).SetStandardInput)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn StandardOutput(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessLauncherOptions", "StandardOutput", 8usize, |this| ((**(this as *const *const IProcessLauncherOptions_Vtbl)).StandardOutput)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetStandardOutput(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessLauncherOptions", "SetStandardOutput", 9usize, |this| ((**(this as *const *const IProcessLauncherOptions_Vtbl)).SetStandardOutput)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn StandardError(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessLauncherOptions", "StandardError", 10usize, |this| ((**(this as *const *const IProcessLauncherOptions_Vtbl)).StandardError)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetStandardError(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessLauncherOptions", "SetStandardError", 11usize, |this| ((**(this as *const *const IProcessLauncherOptions_Vtbl)).SetStandardError)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WorkingDirectory(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessLauncherOptions", "WorkingDirectory", 12usize, |this| ((**(this as *const *const IProcessLauncherOptions_Vtbl)).WorkingDirectory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWorkingDirectory(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessLauncherOptions", "SetWorkingDirectory", 13usize, |this| ((**(this as *const *const IProcessLauncherOptions_Vtbl)).SetWorkingDirectory)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            StandardInput,
+            #[cfg(not(feature = "Storage_Streams"))]
+            StandardInput: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetStandardInput,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetStandardInput: 0,
+            #[cfg(feature = "Storage_Streams")]
+            StandardOutput,
+            #[cfg(not(feature = "Storage_Streams"))]
+            StandardOutput: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetStandardOutput,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetStandardOutput: 0,
+            #[cfg(feature = "Storage_Streams")]
+            StandardError,
+            #[cfg(not(feature = "Storage_Streams"))]
+            StandardError: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetStandardError,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetStandardError: 0,
+            WorkingDirectory,
+            SetWorkingDirectory,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessLauncherOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessLauncherResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExitCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessLauncherResult", "ExitCode", 6usize, |this| ((**(this as *const *const IProcessLauncherResult_Vtbl)).ExitCode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExitCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessLauncherResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessLauncherStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RunToCompletionAsync(this: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::HSTRING>, args: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessLauncherStatics", "RunToCompletionAsync", 6usize, |this| ((**(this as *const *const IProcessLauncherStatics_Vtbl)).RunToCompletionAsync)(this, filename, args, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunToCompletionAsyncWithOptions(this: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::HSTRING>, args: core::mem::MaybeUninit<windows_core::HSTRING>, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessLauncherStatics", "RunToCompletionAsyncWithOptions", 7usize, |this| ((**(this as *const *const IProcessLauncherStatics_Vtbl)).RunToCompletionAsyncWithOptions)(this, filename, args, options, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RunToCompletionAsync, RunToCompletionAsyncWithOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessLauncherStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessMemoryReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrivateWorkingSetUsage(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessMemoryReport", "PrivateWorkingSetUsage", 6usize, |this| ((**(this as *const *const IProcessMemoryReport_Vtbl)).PrivateWorkingSetUsage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalWorkingSetUsage(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessMemoryReport", "TotalWorkingSetUsage", 7usize, |this| ((**(this as *const *const IProcessMemoryReport_Vtbl)).TotalWorkingSetUsage)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrivateWorkingSetUsage, TotalWorkingSetUsage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessMemoryReport as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IProtocolForResultsOperation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ReportCompleted(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtocolForResultsOperation", "ReportCompleted", 6usize, |this| ((**(this as *const *const IProtocolForResultsOperation_Vtbl)).ReportCompleted)(this, data), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ReportCompleted,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ReportCompleted: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtocolForResultsOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRemoteLauncherOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FallbackUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteLauncherOptions", "FallbackUri", 6usize, |this| ((**(this as *const *const IRemoteLauncherOptions_Vtbl)).FallbackUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFallbackUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteLauncherOptions", "SetFallbackUri", 7usize, |this| ((**(this as *const *const IRemoteLauncherOptions_Vtbl)).SetFallbackUri)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PreferredAppIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteLauncherOptions", "PreferredAppIds", 8usize, |this| ((**(this as *const *const IRemoteLauncherOptions_Vtbl)).PreferredAppIds)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FallbackUri,
+            SetFallbackUri,
+            #[cfg(feature = "Foundation_Collections")]
+            PreferredAppIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PreferredAppIds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteLauncherOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "System_RemoteSystems"))]
+impl windows_core::imp::ProxyVtable for IRemoteLauncherStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System_RemoteSystems")]
+        unsafe extern "system" fn LaunchUriAsync(this: *mut core::ffi::c_void, remotesystemconnectionrequest: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteLauncherStatics", "LaunchUriAsync", 6usize, |this| ((**(this as *const *const IRemoteLauncherStatics_Vtbl)).LaunchUriAsync)(this, remotesystemconnectionrequest, uri, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System_RemoteSystems")]
+        unsafe extern "system" fn LaunchUriWithOptionsAsync(this: *mut core::ffi::c_void, remotesystemconnectionrequest: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteLauncherStatics", "LaunchUriWithOptionsAsync", 7usize, |this| ((**(this as *const *const IRemoteLauncherStatics_Vtbl)).LaunchUriWithOptionsAsync)(this, remotesystemconnectionrequest, uri, options, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "System_RemoteSystems"))]
+        unsafe extern "system" fn LaunchUriWithDataAsync(this: *mut core::ffi::c_void, remotesystemconnectionrequest: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, options: *mut core::ffi::c_void, inputdata: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteLauncherStatics", "LaunchUriWithDataAsync", 8usize, |this| ((**(this as *const *const IRemoteLauncherStatics_Vtbl)).LaunchUriWithDataAsync)(this, remotesystemconnectionrequest, uri, options, inputdata, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System_RemoteSystems")]
+            LaunchUriAsync,
+            #[cfg(not(feature = "System_RemoteSystems"))]
+            LaunchUriAsync: 0,
+            #[cfg(feature = "System_RemoteSystems")]
+            LaunchUriWithOptionsAsync,
+            #[cfg(not(feature = "System_RemoteSystems"))]
+            LaunchUriWithOptionsAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "System_RemoteSystems"))]
+            LaunchUriWithDataAsync,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "System_RemoteSystems")))]
+            LaunchUriWithDataAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteLauncherStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IShutdownManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginShutdown(this: *mut core::ffi::c_void, shutdownkind: ShutdownKind, timeout: super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShutdownManagerStatics", "BeginShutdown", 6usize, |this| ((**(this as *const *const IShutdownManagerStatics_Vtbl)).BeginShutdown)(this, shutdownkind, timeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelShutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShutdownManagerStatics", "CancelShutdown", 7usize, |this| ((**(this as *const *const IShutdownManagerStatics_Vtbl)).CancelShutdown)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginShutdown, CancelShutdown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShutdownManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IShutdownManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPowerStateSupported(this: *mut core::ffi::c_void, powerstate: PowerState, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShutdownManagerStatics2", "IsPowerStateSupported", 6usize, |this| ((**(this as *const *const IShutdownManagerStatics2_Vtbl)).IsPowerStateSupported)(this, powerstate, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnterPowerState(this: *mut core::ffi::c_void, powerstate: PowerState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShutdownManagerStatics2", "EnterPowerState", 7usize, |this| ((**(this as *const *const IShutdownManagerStatics2_Vtbl)).EnterPowerState)(this, powerstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnterPowerStateWithTimeSpan(this: *mut core::ffi::c_void, powerstate: PowerState, wakeupafter: super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShutdownManagerStatics2", "EnterPowerStateWithTimeSpan", 8usize, |this| ((**(this as *const *const IShutdownManagerStatics2_Vtbl)).EnterPowerStateWithTimeSpan)(this, powerstate, wakeupafter), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsPowerStateSupported,
+            EnterPowerState,
+            EnterPowerStateWithTimeSpan,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShutdownManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITimeZoneSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentTimeZoneDisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimeZoneSettingsStatics", "CurrentTimeZoneDisplayName", 6usize, |this| ((**(this as *const *const ITimeZoneSettingsStatics_Vtbl)).CurrentTimeZoneDisplayName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SupportedTimeZoneDisplayNames(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimeZoneSettingsStatics", "SupportedTimeZoneDisplayNames", 7usize, |this| ((**(this as *const *const ITimeZoneSettingsStatics_Vtbl)).SupportedTimeZoneDisplayNames)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanChangeTimeZone(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimeZoneSettingsStatics", "CanChangeTimeZone", 8usize, |this| ((**(this as *const *const ITimeZoneSettingsStatics_Vtbl)).CanChangeTimeZone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeTimeZoneByDisplayName(this: *mut core::ffi::c_void, timezonedisplayname: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimeZoneSettingsStatics", "ChangeTimeZoneByDisplayName", 9usize, |this| ((**(this as *const *const ITimeZoneSettingsStatics_Vtbl)).ChangeTimeZoneByDisplayName)(this, timezonedisplayname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentTimeZoneDisplayName,
+            #[cfg(feature = "Foundation_Collections")]
+            SupportedTimeZoneDisplayNames,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SupportedTimeZoneDisplayNames: 0,
+            CanChangeTimeZone,
+            ChangeTimeZoneByDisplayName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimeZoneSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimeZoneSettingsStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AutoUpdateTimeZoneAsync(this: *mut core::ffi::c_void, timeout: super::Foundation::TimeSpan, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimeZoneSettingsStatics2", "AutoUpdateTimeZoneAsync", 6usize, |this| ((**(this as *const *const ITimeZoneSettingsStatics2_Vtbl)).AutoUpdateTimeZoneAsync)(this, timeout, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AutoUpdateTimeZoneAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimeZoneSettingsStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IUser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NonRoamableId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUser", "NonRoamableId", 6usize, |this| ((**(this as *const *const IUser_Vtbl)).NonRoamableId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationStatus(this: *mut core::ffi::c_void, result__: *mut UserAuthenticationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUser", "AuthenticationStatus", 7usize, |this| ((**(this as *const *const IUser_Vtbl)).AuthenticationStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut UserType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUser", "Type", 8usize, |this| ((**(this as *const *const IUser_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyAsync(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUser", "GetPropertyAsync", 9usize, |this| ((**(this as *const *const IUser_Vtbl)).GetPropertyAsync)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetPropertiesAsync(this: *mut core::ffi::c_void, values: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUser", "GetPropertiesAsync", 10usize, |this| ((**(this as *const *const IUser_Vtbl)).GetPropertiesAsync)(this, values, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetPictureAsync(this: *mut core::ffi::c_void, desiredsize: UserPictureSize, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUser", "GetPictureAsync", 11usize, |this| ((**(this as *const *const IUser_Vtbl)).GetPictureAsync)(this, desiredsize, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NonRoamableId,
+            AuthenticationStatus,
+            Type,
+            GetPropertyAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetPropertiesAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetPropertiesAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            GetPictureAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetPictureAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUser2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CheckUserAgeConsentGroupAsync(this: *mut core::ffi::c_void, consentgroup: UserAgeConsentGroup, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUser2", "CheckUserAgeConsentGroupAsync", 6usize, |this| ((**(this as *const *const IUser2_Vtbl)).CheckUserAgeConsentGroupAsync)(this, consentgroup, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CheckUserAgeConsentGroupAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUser2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserAuthenticationStatusChangeDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserAuthenticationStatusChangeDeferral", "Complete", 6usize, |this| ((**(this as *const *const IUserAuthenticationStatusChangeDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserAuthenticationStatusChangeDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserAuthenticationStatusChangingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserAuthenticationStatusChangingEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const IUserAuthenticationStatusChangingEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserAuthenticationStatusChangingEventArgs", "User", 7usize, |this| ((**(this as *const *const IUserAuthenticationStatusChangingEventArgs_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewStatus(this: *mut core::ffi::c_void, result__: *mut UserAuthenticationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserAuthenticationStatusChangingEventArgs", "NewStatus", 8usize, |this| ((**(this as *const *const IUserAuthenticationStatusChangingEventArgs_Vtbl)).NewStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentStatus(this: *mut core::ffi::c_void, result__: *mut UserAuthenticationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserAuthenticationStatusChangingEventArgs", "CurrentStatus", 9usize, |this| ((**(this as *const *const IUserAuthenticationStatusChangingEventArgs_Vtbl)).CurrentStatus)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral, User, NewStatus, CurrentStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserAuthenticationStatusChangingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserChangedEventArgs", "User", 6usize, |this| ((**(this as *const *const IUserChangedEventArgs_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, User }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUserChangedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ChangedPropertyKinds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserChangedEventArgs2", "ChangedPropertyKinds", 6usize, |this| ((**(this as *const *const IUserChangedEventArgs2_Vtbl)).ChangedPropertyKinds)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ChangedPropertyKinds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ChangedPropertyKinds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserChangedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDeviceAssociationChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDeviceAssociationChangedEventArgs", "DeviceId", 6usize, |this| ((**(this as *const *const IUserDeviceAssociationChangedEventArgs_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewUser(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDeviceAssociationChangedEventArgs", "NewUser", 7usize, |this| ((**(this as *const *const IUserDeviceAssociationChangedEventArgs_Vtbl)).NewUser)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OldUser(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDeviceAssociationChangedEventArgs", "OldUser", 8usize, |this| ((**(this as *const *const IUserDeviceAssociationChangedEventArgs_Vtbl)).OldUser)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId, NewUser, OldUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDeviceAssociationChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDeviceAssociationStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindUserFromDeviceId(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDeviceAssociationStatics", "FindUserFromDeviceId", 6usize, |this| ((**(this as *const *const IUserDeviceAssociationStatics_Vtbl)).FindUserFromDeviceId)(this, deviceid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserDeviceAssociationChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDeviceAssociationStatics", "UserDeviceAssociationChanged", 7usize, |this| ((**(this as *const *const IUserDeviceAssociationStatics_Vtbl)).UserDeviceAssociationChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUserDeviceAssociationChanged(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDeviceAssociationStatics", "RemoveUserDeviceAssociationChanged", 8usize, |this| ((**(this as *const *const IUserDeviceAssociationStatics_Vtbl)).RemoveUserDeviceAssociationChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FindUserFromDeviceId,
+            UserDeviceAssociationChanged,
+            RemoveUserDeviceAssociationChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDeviceAssociationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserPicker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowGuestAccounts(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserPicker", "AllowGuestAccounts", 6usize, |this| ((**(this as *const *const IUserPicker_Vtbl)).AllowGuestAccounts)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowGuestAccounts(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserPicker", "SetAllowGuestAccounts", 7usize, |this| ((**(this as *const *const IUserPicker_Vtbl)).SetAllowGuestAccounts)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuggestedSelectedUser(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserPicker", "SuggestedSelectedUser", 8usize, |this| ((**(this as *const *const IUserPicker_Vtbl)).SuggestedSelectedUser)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuggestedSelectedUser(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserPicker", "SetSuggestedSelectedUser", 9usize, |this| ((**(this as *const *const IUserPicker_Vtbl)).SetSuggestedSelectedUser)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PickSingleUserAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserPicker", "PickSingleUserAsync", 10usize, |this| ((**(this as *const *const IUserPicker_Vtbl)).PickSingleUserAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AllowGuestAccounts,
+            SetAllowGuestAccounts,
+            SuggestedSelectedUser,
+            SetSuggestedSelectedUser,
+            PickSingleUserAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserPicker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserPickerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserPickerStatics", "IsSupported", 6usize, |this| ((**(this as *const *const IUserPickerStatics_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserPickerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUserStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserStatics", "CreateWatcher", 6usize, |this| ((**(this as *const *const IUserStatics_Vtbl)).CreateWatcher)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserStatics", "FindAllAsync", 7usize, |this| ((**(this as *const *const IUserStatics_Vtbl)).FindAllAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn FindAllAsyncByType(this: *mut core::ffi::c_void, r#type: UserType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserStatics", "FindAllAsyncByType", 8usize, |this| ((**(this as *const *const IUserStatics_Vtbl)).FindAllAsyncByType)(this, r#type, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+        unsafe extern "system" fn FindAllAsyncByTypeAndStatus(this: *mut core::ffi::c_void, r#type: UserType, status: UserAuthenticationStatus, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserStatics", "FindAllAsyncByTypeAndStatus", 9usize, |this| ((**(this as *const *const IUserStatics_Vtbl)).FindAllAsyncByTypeAndStatus)(this, r#type, status, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFromId(this: *mut core::ffi::c_void, nonroamableid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserStatics", "GetFromId", 10usize, |this| ((**(this as *const *const IUserStatics_Vtbl)).GetFromId)(this, nonroamableid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateWatcher,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsync: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            FindAllAsyncByType,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            FindAllAsyncByType: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "deprecated"))]
+            FindAllAsyncByTypeAndStatus,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "deprecated")))]
+            FindAllAsyncByTypeAndStatus: 0,
+            GetFromId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserStatics2", "GetDefault", 6usize, |this| ((**(this as *const *const IUserStatics2_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut UserWatcherStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "Status", 6usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "Start", 7usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "Stop", 8usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Added(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "Added", 9usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).Added)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdded(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "RemoveAdded", 10usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).RemoveAdded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Removed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "Removed", 11usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).Removed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRemoved(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "RemoveRemoved", 12usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).RemoveRemoved)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Updated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "Updated", 13usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).Updated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUpdated(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "RemoveUpdated", 14usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).RemoveUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationStatusChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "AuthenticationStatusChanged", 15usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).AuthenticationStatusChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAuthenticationStatusChanged(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "RemoveAuthenticationStatusChanged", 16usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).RemoveAuthenticationStatusChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationStatusChanging(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "AuthenticationStatusChanging", 17usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).AuthenticationStatusChanging)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAuthenticationStatusChanging(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "RemoveAuthenticationStatusChanging", 18usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).RemoveAuthenticationStatusChanging)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "EnumerationCompleted", 19usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).EnumerationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnumerationCompleted(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "RemoveEnumerationCompleted", 20usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).RemoveEnumerationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stopped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "Stopped", 21usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).Stopped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStopped(this: *mut core::ffi::c_void, token: super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserWatcher", "RemoveStopped", 22usize, |this| ((**(this as *const *const IUserWatcher_Vtbl)).RemoveStopped)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            Start,
+            Stop,
+            Added,
+            RemoveAdded,
+            Removed,
+            RemoveRemoved,
+            Updated,
+            RemoveUpdated,
+            AuthenticationStatusChanged,
+            RemoveAuthenticationStatusChanged,
+            AuthenticationStatusChanging,
+            RemoveAuthenticationStatusChanging,
+            EnumerationCompleted,
+            RemoveEnumerationCompleted,
+            Stopped,
+            RemoveStopped,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserWatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
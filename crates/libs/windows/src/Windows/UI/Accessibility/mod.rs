@@ -97,3 +97,5 @@ impl windows_core::RuntimeName for ScreenReaderService {
 }
 unsafe impl Send for ScreenReaderService {}
 unsafe impl Sync for ScreenReaderService {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
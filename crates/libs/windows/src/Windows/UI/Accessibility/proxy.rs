@@ -0,0 +1,38 @@
+impl windows_core::imp::ProxyVtable for IScreenReaderPositionChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScreenPositionInRawPixels(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScreenReaderPositionChangedEventArgs", "ScreenPositionInRawPixels", 6usize, |this| ((**(this as *const *const IScreenReaderPositionChangedEventArgs_Vtbl)).ScreenPositionInRawPixels)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReadingText(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScreenReaderPositionChangedEventArgs", "IsReadingText", 7usize, |this| ((**(this as *const *const IScreenReaderPositionChangedEventArgs_Vtbl)).IsReadingText)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ScreenPositionInRawPixels, IsReadingText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScreenReaderPositionChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScreenReaderService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentScreenReaderPosition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScreenReaderService", "CurrentScreenReaderPosition", 6usize, |this| ((**(this as *const *const IScreenReaderService_Vtbl)).CurrentScreenReaderPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScreenReaderPositionChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScreenReaderService", "ScreenReaderPositionChanged", 7usize, |this| ((**(this as *const *const IScreenReaderService_Vtbl)).ScreenReaderPositionChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveScreenReaderPositionChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScreenReaderService", "RemoveScreenReaderPositionChanged", 8usize, |this| ((**(this as *const *const IScreenReaderService_Vtbl)).RemoveScreenReaderPositionChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentScreenReaderPosition,
+            ScreenReaderPositionChanged,
+            RemoveScreenReaderPositionChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScreenReaderService as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -871,6 +871,7 @@ impl windows_core::TypeKind for SettingsEdgeLocation {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SettingsEdgeLocation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SettingsEdgeLocation").field(&self.0).finish()
@@ -894,6 +895,7 @@ impl SupportedWebAccountActions {
 impl windows_core::TypeKind for SupportedWebAccountActions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SupportedWebAccountActions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SupportedWebAccountActions").field(&self.0).finish()
@@ -948,6 +950,7 @@ impl WebAccountAction {
 impl windows_core::TypeKind for WebAccountAction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebAccountAction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebAccountAction").field(&self.0).finish()
@@ -1137,3 +1140,5 @@ pub struct WebAccountProviderCommandInvokedHandler_Vtbl {
     pub base__: windows_core::IUnknown_Vtbl,
     pub Invoke: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
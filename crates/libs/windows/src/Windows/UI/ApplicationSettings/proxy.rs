@@ -0,0 +1,461 @@
+impl windows_core::imp::ProxyVtable for IAccountsSettingsPane_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccountCommandsRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPane", "AccountCommandsRequested", 6usize, |this| ((**(this as *const *const IAccountsSettingsPane_Vtbl)).AccountCommandsRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAccountCommandsRequested(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPane", "RemoveAccountCommandsRequested", 7usize, |this| ((**(this as *const *const IAccountsSettingsPane_Vtbl)).RemoveAccountCommandsRequested)(this, cookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AccountCommandsRequested, RemoveAccountCommandsRequested }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccountsSettingsPane as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "UI_Popups"))]
+impl windows_core::imp::ProxyVtable for IAccountsSettingsPaneCommandsRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn WebAccountProviderCommands(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneCommandsRequestedEventArgs", "WebAccountProviderCommands", 6usize, |this| ((**(this as *const *const IAccountsSettingsPaneCommandsRequestedEventArgs_Vtbl)).WebAccountProviderCommands)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn WebAccountCommands(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneCommandsRequestedEventArgs", "WebAccountCommands", 7usize, |this| ((**(this as *const *const IAccountsSettingsPaneCommandsRequestedEventArgs_Vtbl)).WebAccountCommands)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CredentialCommands(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneCommandsRequestedEventArgs", "CredentialCommands", 8usize, |this| ((**(this as *const *const IAccountsSettingsPaneCommandsRequestedEventArgs_Vtbl)).CredentialCommands)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "UI_Popups"))]
+        unsafe extern "system" fn Commands(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneCommandsRequestedEventArgs", "Commands", 9usize, |this| ((**(this as *const *const IAccountsSettingsPaneCommandsRequestedEventArgs_Vtbl)).Commands)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeaderText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneCommandsRequestedEventArgs", "HeaderText", 10usize, |this| ((**(this as *const *const IAccountsSettingsPaneCommandsRequestedEventArgs_Vtbl)).HeaderText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHeaderText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneCommandsRequestedEventArgs", "SetHeaderText", 11usize, |this| ((**(this as *const *const IAccountsSettingsPaneCommandsRequestedEventArgs_Vtbl)).SetHeaderText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneCommandsRequestedEventArgs", "GetDeferral", 12usize, |this| ((**(this as *const *const IAccountsSettingsPaneCommandsRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            WebAccountProviderCommands,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            WebAccountProviderCommands: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            WebAccountCommands,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            WebAccountCommands: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CredentialCommands,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CredentialCommands: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "UI_Popups"))]
+            Commands,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "UI_Popups")))]
+            Commands: 0,
+            HeaderText,
+            SetHeaderText,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccountsSettingsPaneCommandsRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IAccountsSettingsPaneCommandsRequestedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneCommandsRequestedEventArgs2", "User", 6usize, |this| ((**(this as *const *const IAccountsSettingsPaneCommandsRequestedEventArgs2_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccountsSettingsPaneCommandsRequestedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccountsSettingsPaneEventDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneEventDeferral", "Complete", 6usize, |this| ((**(this as *const *const IAccountsSettingsPaneEventDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccountsSettingsPaneEventDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccountsSettingsPaneStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IAccountsSettingsPaneStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneStatics", "Show", 7usize, |this| ((**(this as *const *const IAccountsSettingsPaneStatics_Vtbl)).Show)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView, Show }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccountsSettingsPaneStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccountsSettingsPaneStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowManageAccountsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneStatics2", "ShowManageAccountsAsync", 6usize, |this| ((**(this as *const *const IAccountsSettingsPaneStatics2_Vtbl)).ShowManageAccountsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAddAccountAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneStatics2", "ShowAddAccountAsync", 7usize, |this| ((**(this as *const *const IAccountsSettingsPaneStatics2_Vtbl)).ShowAddAccountAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowManageAccountsAsync, ShowAddAccountAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccountsSettingsPaneStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IAccountsSettingsPaneStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ShowManageAccountsForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneStatics3", "ShowManageAccountsForUserAsync", 6usize, |this| ((**(this as *const *const IAccountsSettingsPaneStatics3_Vtbl)).ShowManageAccountsForUserAsync)(this, user, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn ShowAddAccountForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneStatics3", "ShowAddAccountForUserAsync", 7usize, |this| ((**(this as *const *const IAccountsSettingsPaneStatics3_Vtbl)).ShowAddAccountForUserAsync)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            ShowManageAccountsForUserAsync,
+            #[cfg(not(feature = "System"))]
+            ShowManageAccountsForUserAsync: 0,
+            #[cfg(feature = "System")]
+            ShowAddAccountForUserAsync,
+            #[cfg(not(feature = "System"))]
+            ShowAddAccountForUserAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccountsSettingsPaneStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for ICredentialCommand_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn PasswordCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialCommand", "PasswordCredential", 6usize, |this| ((**(this as *const *const ICredentialCommand_Vtbl)).PasswordCredential)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CredentialDeleted(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialCommand", "CredentialDeleted", 7usize, |this| ((**(this as *const *const ICredentialCommand_Vtbl)).CredentialDeleted)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            PasswordCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            PasswordCredential: 0,
+            CredentialDeleted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICredentialCommand as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for ICredentialCommandFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn CreateCredentialCommand(this: *mut core::ffi::c_void, passwordcredential: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialCommandFactory", "CreateCredentialCommand", 6usize, |this| ((**(this as *const *const ICredentialCommandFactory_Vtbl)).CreateCredentialCommand)(this, passwordcredential, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn CreateCredentialCommandWithHandler(this: *mut core::ffi::c_void, passwordcredential: *mut core::ffi::c_void, deleted: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICredentialCommandFactory", "CreateCredentialCommandWithHandler", 7usize, |this| ((**(this as *const *const ICredentialCommandFactory_Vtbl)).CreateCredentialCommandWithHandler)(this, passwordcredential, deleted, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            CreateCredentialCommand,
+            #[cfg(not(feature = "Security_Credentials"))]
+            CreateCredentialCommand: 0,
+            #[cfg(feature = "Security_Credentials")]
+            CreateCredentialCommandWithHandler,
+            #[cfg(not(feature = "Security_Credentials"))]
+            CreateCredentialCommandWithHandler: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICredentialCommandFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Popups")]
+impl windows_core::imp::ProxyVtable for ISettingsCommandFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn CreateSettingsCommand(this: *mut core::ffi::c_void, settingscommandid: *mut core::ffi::c_void, label: core::mem::MaybeUninit<windows_core::HSTRING>, handler: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsCommandFactory", "CreateSettingsCommand", 6usize, |this| ((**(this as *const *const ISettingsCommandFactory_Vtbl)).CreateSettingsCommand)(this, settingscommandid, label, handler, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Popups")]
+            CreateSettingsCommand,
+            #[cfg(not(feature = "UI_Popups"))]
+            CreateSettingsCommand: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISettingsCommandFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Popups")]
+impl windows_core::imp::ProxyVtable for ISettingsCommandStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn AccountsCommand(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsCommandStatics", "AccountsCommand", 6usize, |this| ((**(this as *const *const ISettingsCommandStatics_Vtbl)).AccountsCommand)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Popups")]
+            AccountsCommand,
+            #[cfg(not(feature = "UI_Popups"))]
+            AccountsCommand: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISettingsCommandStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISettingsPane_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CommandsRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsPane", "CommandsRequested", 6usize, |this| ((**(this as *const *const ISettingsPane_Vtbl)).CommandsRequested)(this, handler, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn RemoveCommandsRequested(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsPane", "RemoveCommandsRequested", 7usize, |this| ((**(this as *const *const ISettingsPane_Vtbl)).RemoveCommandsRequested)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CommandsRequested,
+            #[cfg(not(feature = "deprecated"))]
+            CommandsRequested: 0,
+            #[cfg(feature = "deprecated")]
+            RemoveCommandsRequested,
+            #[cfg(not(feature = "deprecated"))]
+            RemoveCommandsRequested: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISettingsPane as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "UI_Popups", feature = "deprecated"))]
+impl windows_core::imp::ProxyVtable for ISettingsPaneCommandsRequest_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "UI_Popups", feature = "deprecated"))]
+        unsafe extern "system" fn ApplicationCommands(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsPaneCommandsRequest", "ApplicationCommands", 6usize, |this| ((**(this as *const *const ISettingsPaneCommandsRequest_Vtbl)).ApplicationCommands)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "UI_Popups", feature = "deprecated"))]
+            ApplicationCommands,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "UI_Popups", feature = "deprecated")))]
+            ApplicationCommands: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISettingsPaneCommandsRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISettingsPaneCommandsRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsPaneCommandsRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const ISettingsPaneCommandsRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Request,
+            #[cfg(not(feature = "deprecated"))]
+            Request: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISettingsPaneCommandsRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for ISettingsPaneStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsPaneStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const ISettingsPaneStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsPaneStatics", "Show", 7usize, |this| ((**(this as *const *const ISettingsPaneStatics_Vtbl)).Show)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Edge(this: *mut core::ffi::c_void, result__: *mut SettingsEdgeLocation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsPaneStatics", "Edge", 8usize, |this| ((**(this as *const *const ISettingsPaneStatics_Vtbl)).Edge)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            GetForCurrentView,
+            #[cfg(not(feature = "deprecated"))]
+            GetForCurrentView: 0,
+            #[cfg(feature = "deprecated")]
+            Show,
+            #[cfg(not(feature = "deprecated"))]
+            Show: 0,
+            #[cfg(feature = "deprecated")]
+            Edge,
+            #[cfg(not(feature = "deprecated"))]
+            Edge: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISettingsPaneStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IWebAccountCommand_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn WebAccount(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountCommand", "WebAccount", 6usize, |this| ((**(this as *const *const IWebAccountCommand_Vtbl)).WebAccount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invoked(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountCommand", "Invoked", 7usize, |this| ((**(this as *const *const IWebAccountCommand_Vtbl)).Invoked)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Actions(this: *mut core::ffi::c_void, result__: *mut SupportedWebAccountActions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountCommand", "Actions", 8usize, |this| ((**(this as *const *const IWebAccountCommand_Vtbl)).Actions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            WebAccount,
+            #[cfg(not(feature = "Security_Credentials"))]
+            WebAccount: 0,
+            Invoked,
+            Actions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountCommand as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IWebAccountCommandFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn CreateWebAccountCommand(this: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, invoked: *mut core::ffi::c_void, actions: SupportedWebAccountActions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountCommandFactory", "CreateWebAccountCommand", 6usize, |this| ((**(this as *const *const IWebAccountCommandFactory_Vtbl)).CreateWebAccountCommand)(this, webaccount, invoked, actions, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            CreateWebAccountCommand,
+            #[cfg(not(feature = "Security_Credentials"))]
+            CreateWebAccountCommand: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountCommandFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAccountInvokedArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Action(this: *mut core::ffi::c_void, result__: *mut WebAccountAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountInvokedArgs", "Action", 6usize, |this| ((**(this as *const *const IWebAccountInvokedArgs_Vtbl)).Action)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Action }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountInvokedArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IWebAccountProviderCommand_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn WebAccountProvider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderCommand", "WebAccountProvider", 6usize, |this| ((**(this as *const *const IWebAccountProviderCommand_Vtbl)).WebAccountProvider)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invoked(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderCommand", "Invoked", 7usize, |this| ((**(this as *const *const IWebAccountProviderCommand_Vtbl)).Invoked)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            WebAccountProvider,
+            #[cfg(not(feature = "Security_Credentials"))]
+            WebAccountProvider: 0,
+            Invoked,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderCommand as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IWebAccountProviderCommandFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn CreateWebAccountProviderCommand(this: *mut core::ffi::c_void, webaccountprovider: *mut core::ffi::c_void, invoked: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAccountProviderCommandFactory", "CreateWebAccountProviderCommand", 6usize, |this| ((**(this as *const *const IWebAccountProviderCommandFactory_Vtbl)).CreateWebAccountProviderCommand)(this, webaccountprovider, invoked, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            CreateWebAccountProviderCommand,
+            #[cfg(not(feature = "Security_Credentials"))]
+            CreateWebAccountProviderCommand: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAccountProviderCommandFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
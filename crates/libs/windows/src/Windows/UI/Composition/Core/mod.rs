@@ -73,3 +73,5 @@ impl windows_core::RuntimeName for CompositorController {
 }
 unsafe impl Send for CompositorController {}
 unsafe impl Sync for CompositorController {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,31 @@
+impl windows_core::imp::ProxyVtable for ICompositorController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Compositor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorController", "Compositor", 6usize, |this| ((**(this as *const *const ICompositorController_Vtbl)).Compositor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorController", "Commit", 7usize, |this| ((**(this as *const *const ICompositorController_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnsurePreviousCommitCompletedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorController", "EnsurePreviousCommitCompletedAsync", 8usize, |this| ((**(this as *const *const ICompositorController_Vtbl)).EnsurePreviousCommitCompletedAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitNeeded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorController", "CommitNeeded", 9usize, |this| ((**(this as *const *const ICompositorController_Vtbl)).CommitNeeded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCommitNeeded(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorController", "RemoveCommitNeeded", 10usize, |this| ((**(this as *const *const ICompositorController_Vtbl)).RemoveCommitNeeded)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Compositor,
+            Commit,
+            EnsurePreviousCommitCompletedAsync,
+            CommitNeeded,
+            RemoveCommitNeeded,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositorController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -153,3 +153,5 @@ impl windows_core::RuntimeName for DesktopWindowTarget {
 }
 unsafe impl Send for DesktopWindowTarget {}
 unsafe impl Sync for DesktopWindowTarget {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,12 @@
+impl windows_core::imp::ProxyVtable for IDesktopWindowTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTopmost(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDesktopWindowTarget", "IsTopmost", 6usize, |this| ((**(this as *const *const IDesktopWindowTarget_Vtbl)).IsTopmost)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsTopmost }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDesktopWindowTarget as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
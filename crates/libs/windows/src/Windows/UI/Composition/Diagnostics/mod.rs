@@ -130,6 +130,7 @@ impl CompositionDebugOverdrawContentKinds {
 impl windows_core::TypeKind for CompositionDebugOverdrawContentKinds {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionDebugOverdrawContentKinds {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionDebugOverdrawContentKinds").field(&self.0).finish()
@@ -171,3 +172,5 @@ impl core::ops::Not for CompositionDebugOverdrawContentKinds {
 impl windows_core::RuntimeType for CompositionDebugOverdrawContentKinds {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.Composition.Diagnostics.CompositionDebugOverdrawContentKinds;u4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,45 @@
+impl windows_core::imp::ProxyVtable for ICompositionDebugHeatMaps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Hide(this: *mut core::ffi::c_void, subtree: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDebugHeatMaps", "Hide", 6usize, |this| ((**(this as *const *const ICompositionDebugHeatMaps_Vtbl)).Hide)(this, subtree), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowMemoryUsage(this: *mut core::ffi::c_void, subtree: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDebugHeatMaps", "ShowMemoryUsage", 7usize, |this| ((**(this as *const *const ICompositionDebugHeatMaps_Vtbl)).ShowMemoryUsage)(this, subtree), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowOverdraw(this: *mut core::ffi::c_void, subtree: *mut core::ffi::c_void, contentkinds: CompositionDebugOverdrawContentKinds) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDebugHeatMaps", "ShowOverdraw", 8usize, |this| ((**(this as *const *const ICompositionDebugHeatMaps_Vtbl)).ShowOverdraw)(this, subtree, contentkinds), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowRedraw(this: *mut core::ffi::c_void, subtree: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDebugHeatMaps", "ShowRedraw", 9usize, |this| ((**(this as *const *const ICompositionDebugHeatMaps_Vtbl)).ShowRedraw)(this, subtree), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Hide, ShowMemoryUsage, ShowOverdraw, ShowRedraw }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionDebugHeatMaps as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionDebugSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HeatMaps(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDebugSettings", "HeatMaps", 6usize, |this| ((**(this as *const *const ICompositionDebugSettings_Vtbl)).HeatMaps)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HeatMaps }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionDebugSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionDebugSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryGetSettings(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDebugSettingsStatics", "TryGetSettings", 6usize, |this| ((**(this as *const *const ICompositionDebugSettingsStatics_Vtbl)).TryGetSettings)(this, compositor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryGetSettings }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionDebugSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -153,6 +153,7 @@ impl SceneLightingEffectReflectanceModel {
 impl windows_core::TypeKind for SceneLightingEffectReflectanceModel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SceneLightingEffectReflectanceModel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SceneLightingEffectReflectanceModel").field(&self.0).finish()
@@ -161,3 +162,5 @@ impl core::fmt::Debug for SceneLightingEffectReflectanceModel {
 impl windows_core::RuntimeType for SceneLightingEffectReflectanceModel {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.Composition.Effects.SceneLightingEffectReflectanceModel;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
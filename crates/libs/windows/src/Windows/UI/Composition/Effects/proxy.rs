@@ -0,0 +1,75 @@
+#[cfg(feature = "Graphics_Effects")]
+impl windows_core::imp::ProxyVtable for ISceneLightingEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AmbientAmount(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneLightingEffect", "AmbientAmount", 6usize, |this| ((**(this as *const *const ISceneLightingEffect_Vtbl)).AmbientAmount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAmbientAmount(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneLightingEffect", "SetAmbientAmount", 7usize, |this| ((**(this as *const *const ISceneLightingEffect_Vtbl)).SetAmbientAmount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiffuseAmount(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneLightingEffect", "DiffuseAmount", 8usize, |this| ((**(this as *const *const ISceneLightingEffect_Vtbl)).DiffuseAmount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDiffuseAmount(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneLightingEffect", "SetDiffuseAmount", 9usize, |this| ((**(this as *const *const ISceneLightingEffect_Vtbl)).SetDiffuseAmount)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Effects")]
+        unsafe extern "system" fn NormalMapSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneLightingEffect", "NormalMapSource", 10usize, |this| ((**(this as *const *const ISceneLightingEffect_Vtbl)).NormalMapSource)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Effects")]
+        unsafe extern "system" fn SetNormalMapSource(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneLightingEffect", "SetNormalMapSource", 11usize, |this| ((**(this as *const *const ISceneLightingEffect_Vtbl)).SetNormalMapSource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpecularAmount(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneLightingEffect", "SpecularAmount", 12usize, |this| ((**(this as *const *const ISceneLightingEffect_Vtbl)).SpecularAmount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpecularAmount(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneLightingEffect", "SetSpecularAmount", 13usize, |this| ((**(this as *const *const ISceneLightingEffect_Vtbl)).SetSpecularAmount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpecularShine(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneLightingEffect", "SpecularShine", 14usize, |this| ((**(this as *const *const ISceneLightingEffect_Vtbl)).SpecularShine)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpecularShine(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneLightingEffect", "SetSpecularShine", 15usize, |this| ((**(this as *const *const ISceneLightingEffect_Vtbl)).SetSpecularShine)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AmbientAmount,
+            SetAmbientAmount,
+            DiffuseAmount,
+            SetDiffuseAmount,
+            #[cfg(feature = "Graphics_Effects")]
+            NormalMapSource,
+            #[cfg(not(feature = "Graphics_Effects"))]
+            NormalMapSource: 0,
+            #[cfg(feature = "Graphics_Effects")]
+            SetNormalMapSource,
+            #[cfg(not(feature = "Graphics_Effects"))]
+            SetNormalMapSource: 0,
+            SpecularAmount,
+            SetSpecularAmount,
+            SpecularShine,
+            SetSpecularShine,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneLightingEffect as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneLightingEffect2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReflectanceModel(this: *mut core::ffi::c_void, result__: *mut SceneLightingEffectReflectanceModel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneLightingEffect2", "ReflectanceModel", 6usize, |this| ((**(this as *const *const ISceneLightingEffect2_Vtbl)).ReflectanceModel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReflectanceModel(this: *mut core::ffi::c_void, value: SceneLightingEffectReflectanceModel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneLightingEffect2", "SetReflectanceModel", 7usize, |this| ((**(this as *const *const ISceneLightingEffect2_Vtbl)).SetReflectanceModel)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReflectanceModel, SetReflectanceModel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneLightingEffect2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -3145,6 +3145,7 @@ impl InteractionBindingAxisModes {
 impl windows_core::TypeKind for InteractionBindingAxisModes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InteractionBindingAxisModes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InteractionBindingAxisModes").field(&self.0).finish()
@@ -3197,6 +3198,7 @@ impl InteractionChainingMode {
 impl windows_core::TypeKind for InteractionChainingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InteractionChainingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InteractionChainingMode").field(&self.0).finish()
@@ -3216,6 +3218,7 @@ impl InteractionSourceMode {
 impl windows_core::TypeKind for InteractionSourceMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InteractionSourceMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InteractionSourceMode").field(&self.0).finish()
@@ -3234,6 +3237,7 @@ impl InteractionSourceRedirectionMode {
 impl windows_core::TypeKind for InteractionSourceRedirectionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InteractionSourceRedirectionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InteractionSourceRedirectionMode").field(&self.0).finish()
@@ -3252,6 +3256,7 @@ impl InteractionTrackerClampingOption {
 impl windows_core::TypeKind for InteractionTrackerClampingOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InteractionTrackerClampingOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InteractionTrackerClampingOption").field(&self.0).finish()
@@ -3270,6 +3275,7 @@ impl InteractionTrackerPositionUpdateOption {
 impl windows_core::TypeKind for InteractionTrackerPositionUpdateOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InteractionTrackerPositionUpdateOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InteractionTrackerPositionUpdateOption").field(&self.0).finish()
@@ -3290,6 +3296,7 @@ impl VisualInteractionSourceRedirectionMode {
 impl windows_core::TypeKind for VisualInteractionSourceRedirectionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VisualInteractionSourceRedirectionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VisualInteractionSourceRedirectionMode").field(&self.0).finish()
@@ -3300,3 +3307,5 @@ impl windows_core::RuntimeType for VisualInteractionSourceRedirectionMode {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
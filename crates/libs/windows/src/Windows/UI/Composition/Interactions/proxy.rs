@@ -0,0 +1,1052 @@
+impl windows_core::imp::ProxyVtable for ICompositionConditionalValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Condition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionConditionalValue", "Condition", 6usize, |this| ((**(this as *const *const ICompositionConditionalValue_Vtbl)).Condition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCondition(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionConditionalValue", "SetCondition", 7usize, |this| ((**(this as *const *const ICompositionConditionalValue_Vtbl)).SetCondition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionConditionalValue", "Value", 8usize, |this| ((**(this as *const *const ICompositionConditionalValue_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionConditionalValue", "SetValue", 9usize, |this| ((**(this as *const *const ICompositionConditionalValue_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Condition, SetCondition, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionConditionalValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionConditionalValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionConditionalValueStatics", "Create", 6usize, |this| ((**(this as *const *const ICompositionConditionalValueStatics_Vtbl)).Create)(this, compositor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionConditionalValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionInteractionSource_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionInteractionSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionInteractionSourceCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionInteractionSourceCollection", "Count", 6usize, |this| ((**(this as *const *const ICompositionInteractionSourceCollection_Vtbl)).Count)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionInteractionSourceCollection", "Add", 7usize, |this| ((**(this as *const *const ICompositionInteractionSourceCollection_Vtbl)).Add)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionInteractionSourceCollection", "Remove", 8usize, |this| ((**(this as *const *const ICompositionInteractionSourceCollection_Vtbl)).Remove)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionInteractionSourceCollection", "RemoveAll", 9usize, |this| ((**(this as *const *const ICompositionInteractionSourceCollection_Vtbl)).RemoveAll)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, Add, Remove, RemoveAll }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionInteractionSourceCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionSourceConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PositionXSourceMode(this: *mut core::ffi::c_void, result__: *mut InteractionSourceRedirectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionSourceConfiguration", "PositionXSourceMode", 6usize, |this| ((**(this as *const *const IInteractionSourceConfiguration_Vtbl)).PositionXSourceMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPositionXSourceMode(this: *mut core::ffi::c_void, value: InteractionSourceRedirectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionSourceConfiguration", "SetPositionXSourceMode", 7usize, |this| ((**(this as *const *const IInteractionSourceConfiguration_Vtbl)).SetPositionXSourceMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionYSourceMode(this: *mut core::ffi::c_void, result__: *mut InteractionSourceRedirectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionSourceConfiguration", "PositionYSourceMode", 8usize, |this| ((**(this as *const *const IInteractionSourceConfiguration_Vtbl)).PositionYSourceMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPositionYSourceMode(this: *mut core::ffi::c_void, value: InteractionSourceRedirectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionSourceConfiguration", "SetPositionYSourceMode", 9usize, |this| ((**(this as *const *const IInteractionSourceConfiguration_Vtbl)).SetPositionYSourceMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleSourceMode(this: *mut core::ffi::c_void, result__: *mut InteractionSourceRedirectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionSourceConfiguration", "ScaleSourceMode", 10usize, |this| ((**(this as *const *const IInteractionSourceConfiguration_Vtbl)).ScaleSourceMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaleSourceMode(this: *mut core::ffi::c_void, value: InteractionSourceRedirectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionSourceConfiguration", "SetScaleSourceMode", 11usize, |this| ((**(this as *const *const IInteractionSourceConfiguration_Vtbl)).SetScaleSourceMode)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PositionXSourceMode,
+            SetPositionXSourceMode,
+            PositionYSourceMode,
+            SetPositionYSourceMode,
+            ScaleSourceMode,
+            SetScaleSourceMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionSourceConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Foundation_Numerics"))]
+impl windows_core::imp::ProxyVtable for IInteractionTracker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSources(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "InteractionSources", 6usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).InteractionSources)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPositionRoundingSuggested(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "IsPositionRoundingSuggested", 7usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).IsPositionRoundingSuggested)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn MaxPosition(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "MaxPosition", 8usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).MaxPosition)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetMaxPosition(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "SetMaxPosition", 9usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).SetMaxPosition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxScale(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "MaxScale", 10usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).MaxScale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxScale(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "SetMaxScale", 11usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).SetMaxScale)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn MinPosition(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "MinPosition", 12usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).MinPosition)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetMinPosition(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "SetMinPosition", 13usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).SetMinPosition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinScale(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "MinScale", 14usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).MinScale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinScale(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "SetMinScale", 15usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).SetMinScale)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn NaturalRestingPosition(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "NaturalRestingPosition", 16usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).NaturalRestingPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NaturalRestingScale(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "NaturalRestingScale", 17usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).NaturalRestingScale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Owner(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "Owner", 18usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).Owner)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "Position", 19usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn PositionInertiaDecayRate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "PositionInertiaDecayRate", 20usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).PositionInertiaDecayRate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetPositionInertiaDecayRate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "SetPositionInertiaDecayRate", 21usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).SetPositionInertiaDecayRate)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn PositionVelocityInPixelsPerSecond(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "PositionVelocityInPixelsPerSecond", 22usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).PositionVelocityInPixelsPerSecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scale(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "Scale", 23usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).Scale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleInertiaDecayRate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "ScaleInertiaDecayRate", 24usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).ScaleInertiaDecayRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaleInertiaDecayRate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "SetScaleInertiaDecayRate", 25usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).SetScaleInertiaDecayRate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleVelocityInPercentPerSecond(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "ScaleVelocityInPercentPerSecond", 26usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).ScaleVelocityInPercentPerSecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdjustPositionXIfGreaterThanThreshold(this: *mut core::ffi::c_void, adjustment: f32, positionthreshold: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "AdjustPositionXIfGreaterThanThreshold", 27usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).AdjustPositionXIfGreaterThanThreshold)(this, adjustment, positionthreshold), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdjustPositionYIfGreaterThanThreshold(this: *mut core::ffi::c_void, adjustment: f32, positionthreshold: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "AdjustPositionYIfGreaterThanThreshold", 28usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).AdjustPositionYIfGreaterThanThreshold)(this, adjustment, positionthreshold), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ConfigurePositionXInertiaModifiers(this: *mut core::ffi::c_void, modifiers: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "ConfigurePositionXInertiaModifiers", 29usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).ConfigurePositionXInertiaModifiers)(this, modifiers), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ConfigurePositionYInertiaModifiers(this: *mut core::ffi::c_void, modifiers: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "ConfigurePositionYInertiaModifiers", 30usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).ConfigurePositionYInertiaModifiers)(this, modifiers), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ConfigureScaleInertiaModifiers(this: *mut core::ffi::c_void, modifiers: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "ConfigureScaleInertiaModifiers", 31usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).ConfigureScaleInertiaModifiers)(this, modifiers), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryUpdatePosition(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Numerics::Vector3, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "TryUpdatePosition", 32usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).TryUpdatePosition)(this, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryUpdatePositionBy(this: *mut core::ffi::c_void, amount: super::super::super::Foundation::Numerics::Vector3, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "TryUpdatePositionBy", 33usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).TryUpdatePositionBy)(this, amount, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryUpdatePositionWithAnimation(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "TryUpdatePositionWithAnimation", 34usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).TryUpdatePositionWithAnimation)(this, animation, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryUpdatePositionWithAdditionalVelocity(this: *mut core::ffi::c_void, velocityinpixelspersecond: super::super::super::Foundation::Numerics::Vector3, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "TryUpdatePositionWithAdditionalVelocity", 35usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).TryUpdatePositionWithAdditionalVelocity)(this, velocityinpixelspersecond, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryUpdateScale(this: *mut core::ffi::c_void, value: f32, centerpoint: super::super::super::Foundation::Numerics::Vector3, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "TryUpdateScale", 36usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).TryUpdateScale)(this, value, centerpoint, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryUpdateScaleWithAnimation(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void, centerpoint: super::super::super::Foundation::Numerics::Vector3, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "TryUpdateScaleWithAnimation", 37usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).TryUpdateScaleWithAnimation)(this, animation, centerpoint, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryUpdateScaleWithAdditionalVelocity(this: *mut core::ffi::c_void, velocityinpercentpersecond: f32, centerpoint: super::super::super::Foundation::Numerics::Vector3, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker", "TryUpdateScaleWithAdditionalVelocity", 38usize, |this| ((**(this as *const *const IInteractionTracker_Vtbl)).TryUpdateScaleWithAdditionalVelocity)(this, velocityinpercentpersecond, centerpoint, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InteractionSources,
+            IsPositionRoundingSuggested,
+            #[cfg(feature = "Foundation_Numerics")]
+            MaxPosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            MaxPosition: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetMaxPosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetMaxPosition: 0,
+            MaxScale,
+            SetMaxScale,
+            #[cfg(feature = "Foundation_Numerics")]
+            MinPosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            MinPosition: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetMinPosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetMinPosition: 0,
+            MinScale,
+            SetMinScale,
+            #[cfg(feature = "Foundation_Numerics")]
+            NaturalRestingPosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            NaturalRestingPosition: 0,
+            NaturalRestingScale,
+            Owner,
+            #[cfg(feature = "Foundation_Numerics")]
+            Position,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Position: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            PositionInertiaDecayRate,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            PositionInertiaDecayRate: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetPositionInertiaDecayRate,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetPositionInertiaDecayRate: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            PositionVelocityInPixelsPerSecond,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            PositionVelocityInPixelsPerSecond: 0,
+            Scale,
+            ScaleInertiaDecayRate,
+            SetScaleInertiaDecayRate,
+            ScaleVelocityInPercentPerSecond,
+            AdjustPositionXIfGreaterThanThreshold,
+            AdjustPositionYIfGreaterThanThreshold,
+            #[cfg(feature = "Foundation_Collections")]
+            ConfigurePositionXInertiaModifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ConfigurePositionXInertiaModifiers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ConfigurePositionYInertiaModifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ConfigurePositionYInertiaModifiers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ConfigureScaleInertiaModifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ConfigureScaleInertiaModifiers: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryUpdatePosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryUpdatePosition: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryUpdatePositionBy,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryUpdatePositionBy: 0,
+            TryUpdatePositionWithAnimation,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryUpdatePositionWithAdditionalVelocity,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryUpdatePositionWithAdditionalVelocity: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryUpdateScale,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryUpdateScale: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryUpdateScaleWithAnimation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryUpdateScaleWithAnimation: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryUpdateScaleWithAdditionalVelocity,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryUpdateScaleWithAdditionalVelocity: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTracker as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInteractionTracker2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ConfigureCenterPointXInertiaModifiers(this: *mut core::ffi::c_void, conditionalvalues: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker2", "ConfigureCenterPointXInertiaModifiers", 6usize, |this| ((**(this as *const *const IInteractionTracker2_Vtbl)).ConfigureCenterPointXInertiaModifiers)(this, conditionalvalues), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ConfigureCenterPointYInertiaModifiers(this: *mut core::ffi::c_void, conditionalvalues: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker2", "ConfigureCenterPointYInertiaModifiers", 7usize, |this| ((**(this as *const *const IInteractionTracker2_Vtbl)).ConfigureCenterPointYInertiaModifiers)(this, conditionalvalues), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ConfigureCenterPointXInertiaModifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ConfigureCenterPointXInertiaModifiers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ConfigureCenterPointYInertiaModifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ConfigureCenterPointYInertiaModifiers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTracker2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInteractionTracker3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ConfigureVector2PositionInertiaModifiers(this: *mut core::ffi::c_void, modifiers: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker3", "ConfigureVector2PositionInertiaModifiers", 6usize, |this| ((**(this as *const *const IInteractionTracker3_Vtbl)).ConfigureVector2PositionInertiaModifiers)(this, modifiers), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            ConfigureVector2PositionInertiaModifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ConfigureVector2PositionInertiaModifiers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTracker3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IInteractionTracker4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryUpdatePositionWithOption(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Numerics::Vector3, option: InteractionTrackerClampingOption, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker4", "TryUpdatePositionWithOption", 6usize, |this| ((**(this as *const *const IInteractionTracker4_Vtbl)).TryUpdatePositionWithOption)(this, value, option, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryUpdatePositionByWithOption(this: *mut core::ffi::c_void, amount: super::super::super::Foundation::Numerics::Vector3, option: InteractionTrackerClampingOption, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker4", "TryUpdatePositionByWithOption", 7usize, |this| ((**(this as *const *const IInteractionTracker4_Vtbl)).TryUpdatePositionByWithOption)(this, amount, option, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInertiaFromImpulse(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker4", "IsInertiaFromImpulse", 8usize, |this| ((**(this as *const *const IInteractionTracker4_Vtbl)).IsInertiaFromImpulse)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryUpdatePositionWithOption,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryUpdatePositionWithOption: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryUpdatePositionByWithOption,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryUpdatePositionByWithOption: 0,
+            IsInertiaFromImpulse,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTracker4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IInteractionTracker5_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryUpdatePositionWithOption(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Numerics::Vector3, option: InteractionTrackerClampingOption, posupdateoption: InteractionTrackerPositionUpdateOption, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTracker5", "TryUpdatePositionWithOption", 6usize, |this| ((**(this as *const *const IInteractionTracker5_Vtbl)).TryUpdatePositionWithOption)(this, value, option, posupdateoption, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryUpdatePositionWithOption,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryUpdatePositionWithOption: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTracker5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerCustomAnimationStateEnteredArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerCustomAnimationStateEnteredArgs", "RequestId", 6usize, |this| ((**(this as *const *const IInteractionTrackerCustomAnimationStateEnteredArgs_Vtbl)).RequestId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerCustomAnimationStateEnteredArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerCustomAnimationStateEnteredArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsFromBinding(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerCustomAnimationStateEnteredArgs2", "IsFromBinding", 6usize, |this| ((**(this as *const *const IInteractionTrackerCustomAnimationStateEnteredArgs2_Vtbl)).IsFromBinding)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsFromBinding }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerCustomAnimationStateEnteredArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerIdleStateEnteredArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerIdleStateEnteredArgs", "RequestId", 6usize, |this| ((**(this as *const *const IInteractionTrackerIdleStateEnteredArgs_Vtbl)).RequestId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerIdleStateEnteredArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerIdleStateEnteredArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsFromBinding(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerIdleStateEnteredArgs2", "IsFromBinding", 6usize, |this| ((**(this as *const *const IInteractionTrackerIdleStateEnteredArgs2_Vtbl)).IsFromBinding)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsFromBinding }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerIdleStateEnteredArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerInertiaModifier_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerInertiaModifier as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerInertiaModifierFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerInertiaModifierFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerInertiaMotion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Condition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaMotion", "Condition", 6usize, |this| ((**(this as *const *const IInteractionTrackerInertiaMotion_Vtbl)).Condition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCondition(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaMotion", "SetCondition", 7usize, |this| ((**(this as *const *const IInteractionTrackerInertiaMotion_Vtbl)).SetCondition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Motion(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaMotion", "Motion", 8usize, |this| ((**(this as *const *const IInteractionTrackerInertiaMotion_Vtbl)).Motion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMotion(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaMotion", "SetMotion", 9usize, |this| ((**(this as *const *const IInteractionTrackerInertiaMotion_Vtbl)).SetMotion)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Condition, SetCondition, Motion, SetMotion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerInertiaMotion as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerInertiaMotionStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaMotionStatics", "Create", 6usize, |this| ((**(this as *const *const IInteractionTrackerInertiaMotionStatics_Vtbl)).Create)(this, compositor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerInertiaMotionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerInertiaNaturalMotion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Condition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaNaturalMotion", "Condition", 6usize, |this| ((**(this as *const *const IInteractionTrackerInertiaNaturalMotion_Vtbl)).Condition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCondition(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaNaturalMotion", "SetCondition", 7usize, |this| ((**(this as *const *const IInteractionTrackerInertiaNaturalMotion_Vtbl)).SetCondition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NaturalMotion(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaNaturalMotion", "NaturalMotion", 8usize, |this| ((**(this as *const *const IInteractionTrackerInertiaNaturalMotion_Vtbl)).NaturalMotion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNaturalMotion(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaNaturalMotion", "SetNaturalMotion", 9usize, |this| ((**(this as *const *const IInteractionTrackerInertiaNaturalMotion_Vtbl)).SetNaturalMotion)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Condition, SetCondition, NaturalMotion, SetNaturalMotion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerInertiaNaturalMotion as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerInertiaNaturalMotionStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaNaturalMotionStatics", "Create", 6usize, |this| ((**(this as *const *const IInteractionTrackerInertiaNaturalMotionStatics_Vtbl)).Create)(this, compositor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerInertiaNaturalMotionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerInertiaRestingValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Condition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaRestingValue", "Condition", 6usize, |this| ((**(this as *const *const IInteractionTrackerInertiaRestingValue_Vtbl)).Condition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCondition(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaRestingValue", "SetCondition", 7usize, |this| ((**(this as *const *const IInteractionTrackerInertiaRestingValue_Vtbl)).SetCondition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestingValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaRestingValue", "RestingValue", 8usize, |this| ((**(this as *const *const IInteractionTrackerInertiaRestingValue_Vtbl)).RestingValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRestingValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaRestingValue", "SetRestingValue", 9usize, |this| ((**(this as *const *const IInteractionTrackerInertiaRestingValue_Vtbl)).SetRestingValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Condition, SetCondition, RestingValue, SetRestingValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerInertiaRestingValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerInertiaRestingValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaRestingValueStatics", "Create", 6usize, |this| ((**(this as *const *const IInteractionTrackerInertiaRestingValueStatics_Vtbl)).Create)(this, compositor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerInertiaRestingValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IInteractionTrackerInertiaStateEnteredArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn ModifiedRestingPosition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaStateEnteredArgs", "ModifiedRestingPosition", 6usize, |this| ((**(this as *const *const IInteractionTrackerInertiaStateEnteredArgs_Vtbl)).ModifiedRestingPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModifiedRestingScale(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaStateEnteredArgs", "ModifiedRestingScale", 7usize, |this| ((**(this as *const *const IInteractionTrackerInertiaStateEnteredArgs_Vtbl)).ModifiedRestingScale)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn NaturalRestingPosition(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaStateEnteredArgs", "NaturalRestingPosition", 8usize, |this| ((**(this as *const *const IInteractionTrackerInertiaStateEnteredArgs_Vtbl)).NaturalRestingPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NaturalRestingScale(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaStateEnteredArgs", "NaturalRestingScale", 9usize, |this| ((**(this as *const *const IInteractionTrackerInertiaStateEnteredArgs_Vtbl)).NaturalRestingScale)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn PositionVelocityInPixelsPerSecond(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaStateEnteredArgs", "PositionVelocityInPixelsPerSecond", 10usize, |this| ((**(this as *const *const IInteractionTrackerInertiaStateEnteredArgs_Vtbl)).PositionVelocityInPixelsPerSecond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaStateEnteredArgs", "RequestId", 11usize, |this| ((**(this as *const *const IInteractionTrackerInertiaStateEnteredArgs_Vtbl)).RequestId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleVelocityInPercentPerSecond(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaStateEnteredArgs", "ScaleVelocityInPercentPerSecond", 12usize, |this| ((**(this as *const *const IInteractionTrackerInertiaStateEnteredArgs_Vtbl)).ScaleVelocityInPercentPerSecond)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            ModifiedRestingPosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            ModifiedRestingPosition: 0,
+            ModifiedRestingScale,
+            #[cfg(feature = "Foundation_Numerics")]
+            NaturalRestingPosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            NaturalRestingPosition: 0,
+            NaturalRestingScale,
+            #[cfg(feature = "Foundation_Numerics")]
+            PositionVelocityInPixelsPerSecond,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            PositionVelocityInPixelsPerSecond: 0,
+            RequestId,
+            ScaleVelocityInPercentPerSecond,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerInertiaStateEnteredArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerInertiaStateEnteredArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsInertiaFromImpulse(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaStateEnteredArgs2", "IsInertiaFromImpulse", 6usize, |this| ((**(this as *const *const IInteractionTrackerInertiaStateEnteredArgs2_Vtbl)).IsInertiaFromImpulse)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsInertiaFromImpulse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerInertiaStateEnteredArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerInertiaStateEnteredArgs3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsFromBinding(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInertiaStateEnteredArgs3", "IsFromBinding", 6usize, |this| ((**(this as *const *const IInteractionTrackerInertiaStateEnteredArgs3_Vtbl)).IsFromBinding)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsFromBinding }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerInertiaStateEnteredArgs3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerInteractingStateEnteredArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInteractingStateEnteredArgs", "RequestId", 6usize, |this| ((**(this as *const *const IInteractionTrackerInteractingStateEnteredArgs_Vtbl)).RequestId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerInteractingStateEnteredArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerInteractingStateEnteredArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsFromBinding(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerInteractingStateEnteredArgs2", "IsFromBinding", 6usize, |this| ((**(this as *const *const IInteractionTrackerInteractingStateEnteredArgs2_Vtbl)).IsFromBinding)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsFromBinding }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerInteractingStateEnteredArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerOwner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CustomAnimationStateEntered(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, args: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerOwner", "CustomAnimationStateEntered", 6usize, |this| ((**(this as *const *const IInteractionTrackerOwner_Vtbl)).CustomAnimationStateEntered)(this, sender, args), |result| Some(*result))
+        }
+        unsafe extern "system" fn IdleStateEntered(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, args: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerOwner", "IdleStateEntered", 7usize, |this| ((**(this as *const *const IInteractionTrackerOwner_Vtbl)).IdleStateEntered)(this, sender, args), |result| Some(*result))
+        }
+        unsafe extern "system" fn InertiaStateEntered(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, args: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerOwner", "InertiaStateEntered", 8usize, |this| ((**(this as *const *const IInteractionTrackerOwner_Vtbl)).InertiaStateEntered)(this, sender, args), |result| Some(*result))
+        }
+        unsafe extern "system" fn InteractingStateEntered(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, args: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerOwner", "InteractingStateEntered", 9usize, |this| ((**(this as *const *const IInteractionTrackerOwner_Vtbl)).InteractingStateEntered)(this, sender, args), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestIgnored(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, args: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerOwner", "RequestIgnored", 10usize, |this| ((**(this as *const *const IInteractionTrackerOwner_Vtbl)).RequestIgnored)(this, sender, args), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValuesChanged(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, args: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerOwner", "ValuesChanged", 11usize, |this| ((**(this as *const *const IInteractionTrackerOwner_Vtbl)).ValuesChanged)(this, sender, args), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CustomAnimationStateEntered,
+            IdleStateEntered,
+            InertiaStateEntered,
+            InteractingStateEntered,
+            RequestIgnored,
+            ValuesChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerOwner as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerRequestIgnoredArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerRequestIgnoredArgs", "RequestId", 6usize, |this| ((**(this as *const *const IInteractionTrackerRequestIgnoredArgs_Vtbl)).RequestId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerRequestIgnoredArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerStatics", "Create", 6usize, |this| ((**(this as *const *const IInteractionTrackerStatics_Vtbl)).Create)(this, compositor, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithOwner(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, owner: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerStatics", "CreateWithOwner", 7usize, |this| ((**(this as *const *const IInteractionTrackerStatics_Vtbl)).CreateWithOwner)(this, compositor, owner, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithOwner }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBindingMode(this: *mut core::ffi::c_void, boundtracker1: *mut core::ffi::c_void, boundtracker2: *mut core::ffi::c_void, axismode: InteractionBindingAxisModes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerStatics2", "SetBindingMode", 6usize, |this| ((**(this as *const *const IInteractionTrackerStatics2_Vtbl)).SetBindingMode)(this, boundtracker1, boundtracker2, axismode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBindingMode(this: *mut core::ffi::c_void, boundtracker1: *mut core::ffi::c_void, boundtracker2: *mut core::ffi::c_void, result__: *mut InteractionBindingAxisModes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerStatics2", "GetBindingMode", 7usize, |this| ((**(this as *const *const IInteractionTrackerStatics2_Vtbl)).GetBindingMode)(this, boundtracker1, boundtracker2, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetBindingMode, GetBindingMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IInteractionTrackerValuesChangedArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerValuesChangedArgs", "Position", 6usize, |this| ((**(this as *const *const IInteractionTrackerValuesChangedArgs_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerValuesChangedArgs", "RequestId", 7usize, |this| ((**(this as *const *const IInteractionTrackerValuesChangedArgs_Vtbl)).RequestId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scale(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerValuesChangedArgs", "Scale", 8usize, |this| ((**(this as *const *const IInteractionTrackerValuesChangedArgs_Vtbl)).Scale)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Position,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Position: 0,
+            RequestId,
+            Scale,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerValuesChangedArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerVector2InertiaModifier_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerVector2InertiaModifier as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerVector2InertiaModifierFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerVector2InertiaModifierFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerVector2InertiaNaturalMotion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Condition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerVector2InertiaNaturalMotion", "Condition", 6usize, |this| ((**(this as *const *const IInteractionTrackerVector2InertiaNaturalMotion_Vtbl)).Condition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCondition(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerVector2InertiaNaturalMotion", "SetCondition", 7usize, |this| ((**(this as *const *const IInteractionTrackerVector2InertiaNaturalMotion_Vtbl)).SetCondition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NaturalMotion(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerVector2InertiaNaturalMotion", "NaturalMotion", 8usize, |this| ((**(this as *const *const IInteractionTrackerVector2InertiaNaturalMotion_Vtbl)).NaturalMotion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNaturalMotion(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerVector2InertiaNaturalMotion", "SetNaturalMotion", 9usize, |this| ((**(this as *const *const IInteractionTrackerVector2InertiaNaturalMotion_Vtbl)).SetNaturalMotion)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Condition, SetCondition, NaturalMotion, SetNaturalMotion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerVector2InertiaNaturalMotion as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInteractionTrackerVector2InertiaNaturalMotionStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInteractionTrackerVector2InertiaNaturalMotionStatics", "Create", 6usize, |this| ((**(this as *const *const IInteractionTrackerVector2InertiaNaturalMotionStatics_Vtbl)).Create)(this, compositor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInteractionTrackerVector2InertiaNaturalMotionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Input")]
+impl windows_core::imp::ProxyVtable for IVisualInteractionSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPositionXRailsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "IsPositionXRailsEnabled", 6usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).IsPositionXRailsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsPositionXRailsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "SetIsPositionXRailsEnabled", 7usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).SetIsPositionXRailsEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPositionYRailsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "IsPositionYRailsEnabled", 8usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).IsPositionYRailsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsPositionYRailsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "SetIsPositionYRailsEnabled", 9usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).SetIsPositionYRailsEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManipulationRedirectionMode(this: *mut core::ffi::c_void, result__: *mut VisualInteractionSourceRedirectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "ManipulationRedirectionMode", 10usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).ManipulationRedirectionMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetManipulationRedirectionMode(this: *mut core::ffi::c_void, value: VisualInteractionSourceRedirectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "SetManipulationRedirectionMode", 11usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).SetManipulationRedirectionMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionXChainingMode(this: *mut core::ffi::c_void, result__: *mut InteractionChainingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "PositionXChainingMode", 12usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).PositionXChainingMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPositionXChainingMode(this: *mut core::ffi::c_void, value: InteractionChainingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "SetPositionXChainingMode", 13usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).SetPositionXChainingMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionXSourceMode(this: *mut core::ffi::c_void, result__: *mut InteractionSourceMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "PositionXSourceMode", 14usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).PositionXSourceMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPositionXSourceMode(this: *mut core::ffi::c_void, value: InteractionSourceMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "SetPositionXSourceMode", 15usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).SetPositionXSourceMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionYChainingMode(this: *mut core::ffi::c_void, result__: *mut InteractionChainingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "PositionYChainingMode", 16usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).PositionYChainingMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPositionYChainingMode(this: *mut core::ffi::c_void, value: InteractionChainingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "SetPositionYChainingMode", 17usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).SetPositionYChainingMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionYSourceMode(this: *mut core::ffi::c_void, result__: *mut InteractionSourceMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "PositionYSourceMode", 18usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).PositionYSourceMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPositionYSourceMode(this: *mut core::ffi::c_void, value: InteractionSourceMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "SetPositionYSourceMode", 19usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).SetPositionYSourceMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleChainingMode(this: *mut core::ffi::c_void, result__: *mut InteractionChainingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "ScaleChainingMode", 20usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).ScaleChainingMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaleChainingMode(this: *mut core::ffi::c_void, value: InteractionChainingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "SetScaleChainingMode", 21usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).SetScaleChainingMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleSourceMode(this: *mut core::ffi::c_void, result__: *mut InteractionSourceMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "ScaleSourceMode", 22usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).ScaleSourceMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaleSourceMode(this: *mut core::ffi::c_void, value: InteractionSourceMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "SetScaleSourceMode", 23usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).SetScaleSourceMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "Source", 24usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Input")]
+        unsafe extern "system" fn TryRedirectForManipulation(this: *mut core::ffi::c_void, pointerpoint: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource", "TryRedirectForManipulation", 25usize, |this| ((**(this as *const *const IVisualInteractionSource_Vtbl)).TryRedirectForManipulation)(this, pointerpoint), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsPositionXRailsEnabled,
+            SetIsPositionXRailsEnabled,
+            IsPositionYRailsEnabled,
+            SetIsPositionYRailsEnabled,
+            ManipulationRedirectionMode,
+            SetManipulationRedirectionMode,
+            PositionXChainingMode,
+            SetPositionXChainingMode,
+            PositionXSourceMode,
+            SetPositionXSourceMode,
+            PositionYChainingMode,
+            SetPositionYChainingMode,
+            PositionYSourceMode,
+            SetPositionYSourceMode,
+            ScaleChainingMode,
+            SetScaleChainingMode,
+            ScaleSourceMode,
+            SetScaleSourceMode,
+            Source,
+            #[cfg(feature = "UI_Input")]
+            TryRedirectForManipulation,
+            #[cfg(not(feature = "UI_Input"))]
+            TryRedirectForManipulation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisualInteractionSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Foundation_Numerics"))]
+impl windows_core::imp::ProxyVtable for IVisualInteractionSource2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn DeltaPosition(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource2", "DeltaPosition", 6usize, |this| ((**(this as *const *const IVisualInteractionSource2_Vtbl)).DeltaPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeltaScale(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource2", "DeltaScale", 7usize, |this| ((**(this as *const *const IVisualInteractionSource2_Vtbl)).DeltaScale)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource2", "Position", 8usize, |this| ((**(this as *const *const IVisualInteractionSource2_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn PositionVelocity(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource2", "PositionVelocity", 9usize, |this| ((**(this as *const *const IVisualInteractionSource2_Vtbl)).PositionVelocity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scale(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource2", "Scale", 10usize, |this| ((**(this as *const *const IVisualInteractionSource2_Vtbl)).Scale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleVelocity(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource2", "ScaleVelocity", 11usize, |this| ((**(this as *const *const IVisualInteractionSource2_Vtbl)).ScaleVelocity)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ConfigureCenterPointXModifiers(this: *mut core::ffi::c_void, conditionalvalues: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource2", "ConfigureCenterPointXModifiers", 12usize, |this| ((**(this as *const *const IVisualInteractionSource2_Vtbl)).ConfigureCenterPointXModifiers)(this, conditionalvalues), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ConfigureCenterPointYModifiers(this: *mut core::ffi::c_void, conditionalvalues: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource2", "ConfigureCenterPointYModifiers", 13usize, |this| ((**(this as *const *const IVisualInteractionSource2_Vtbl)).ConfigureCenterPointYModifiers)(this, conditionalvalues), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ConfigureDeltaPositionXModifiers(this: *mut core::ffi::c_void, conditionalvalues: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource2", "ConfigureDeltaPositionXModifiers", 14usize, |this| ((**(this as *const *const IVisualInteractionSource2_Vtbl)).ConfigureDeltaPositionXModifiers)(this, conditionalvalues), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ConfigureDeltaPositionYModifiers(this: *mut core::ffi::c_void, conditionalvalues: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource2", "ConfigureDeltaPositionYModifiers", 15usize, |this| ((**(this as *const *const IVisualInteractionSource2_Vtbl)).ConfigureDeltaPositionYModifiers)(this, conditionalvalues), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ConfigureDeltaScaleModifiers(this: *mut core::ffi::c_void, conditionalvalues: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource2", "ConfigureDeltaScaleModifiers", 16usize, |this| ((**(this as *const *const IVisualInteractionSource2_Vtbl)).ConfigureDeltaScaleModifiers)(this, conditionalvalues), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            DeltaPosition,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            DeltaPosition: 0,
+            DeltaScale,
+            #[cfg(feature = "Foundation_Numerics")]
+            Position,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Position: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            PositionVelocity,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            PositionVelocity: 0,
+            Scale,
+            ScaleVelocity,
+            #[cfg(feature = "Foundation_Collections")]
+            ConfigureCenterPointXModifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ConfigureCenterPointXModifiers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ConfigureCenterPointYModifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ConfigureCenterPointYModifiers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ConfigureDeltaPositionXModifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ConfigureDeltaPositionXModifiers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ConfigureDeltaPositionYModifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ConfigureDeltaPositionYModifiers: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ConfigureDeltaScaleModifiers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ConfigureDeltaScaleModifiers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisualInteractionSource2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVisualInteractionSource3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PointerWheelConfig(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSource3", "PointerWheelConfig", 6usize, |this| ((**(this as *const *const IVisualInteractionSource3_Vtbl)).PointerWheelConfig)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PointerWheelConfig }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisualInteractionSource3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVisualInteractionSourceObjectFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisualInteractionSourceObjectFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVisualInteractionSourceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSourceStatics", "Create", 6usize, |this| ((**(this as *const *const IVisualInteractionSourceStatics_Vtbl)).Create)(this, source, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisualInteractionSourceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVisualInteractionSourceStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromIVisualElement(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSourceStatics2", "CreateFromIVisualElement", 6usize, |this| ((**(this as *const *const IVisualInteractionSourceStatics2_Vtbl)).CreateFromIVisualElement)(this, source, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromIVisualElement }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisualInteractionSourceStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -3757,6 +3757,7 @@ impl SceneAlphaMode {
 impl windows_core::TypeKind for SceneAlphaMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SceneAlphaMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SceneAlphaMode").field(&self.0).finish()
@@ -3780,6 +3781,7 @@ impl SceneAttributeSemantic {
 impl windows_core::TypeKind for SceneAttributeSemantic {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SceneAttributeSemantic {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SceneAttributeSemantic").field(&self.0).finish()
@@ -3797,6 +3799,7 @@ impl SceneComponentType {
 impl windows_core::TypeKind for SceneComponentType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SceneComponentType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SceneComponentType").field(&self.0).finish()
@@ -3816,6 +3819,7 @@ impl SceneWrappingMode {
 impl windows_core::TypeKind for SceneWrappingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SceneWrappingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SceneWrappingMode").field(&self.0).finish()
@@ -3824,3 +3828,5 @@ impl core::fmt::Debug for SceneWrappingMode {
 impl windows_core::RuntimeType for SceneWrappingMode {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.Composition.Scenes.SceneWrappingMode;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
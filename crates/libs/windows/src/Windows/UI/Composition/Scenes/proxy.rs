@@ -0,0 +1,633 @@
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISceneBoundingBox_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Center(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneBoundingBox", "Center", 6usize, |this| ((**(this as *const *const ISceneBoundingBox_Vtbl)).Center)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Extents(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneBoundingBox", "Extents", 7usize, |this| ((**(this as *const *const ISceneBoundingBox_Vtbl)).Extents)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Max(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneBoundingBox", "Max", 8usize, |this| ((**(this as *const *const ISceneBoundingBox_Vtbl)).Max)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Min(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneBoundingBox", "Min", 9usize, |this| ((**(this as *const *const ISceneBoundingBox_Vtbl)).Min)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneBoundingBox", "Size", 10usize, |this| ((**(this as *const *const ISceneBoundingBox_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Center,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Center: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Extents,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Extents: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Max,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Max: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Min,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Min: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Size,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Size: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneBoundingBox as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneComponent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ComponentType(this: *mut core::ffi::c_void, result__: *mut SceneComponentType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneComponent", "ComponentType", 6usize, |this| ((**(this as *const *const ISceneComponent_Vtbl)).ComponentType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ComponentType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneComponent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneComponentCollection_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneComponentCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneComponentFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneComponentFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneMaterial_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneMaterial as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneMaterialFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneMaterialFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneMaterialInput_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneMaterialInput as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneMaterialInputFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneMaterialInputFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX")]
+impl windows_core::imp::ProxyVtable for ISceneMesh_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Bounds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMesh", "Bounds", 6usize, |this| ((**(this as *const *const ISceneMesh_Vtbl)).Bounds)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn PrimitiveTopology(this: *mut core::ffi::c_void, result__: *mut super::super::super::Graphics::DirectX::DirectXPrimitiveTopology) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMesh", "PrimitiveTopology", 7usize, |this| ((**(this as *const *const ISceneMesh_Vtbl)).PrimitiveTopology)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn SetPrimitiveTopology(this: *mut core::ffi::c_void, value: super::super::super::Graphics::DirectX::DirectXPrimitiveTopology) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMesh", "SetPrimitiveTopology", 8usize, |this| ((**(this as *const *const ISceneMesh_Vtbl)).SetPrimitiveTopology)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn FillMeshAttribute(this: *mut core::ffi::c_void, semantic: SceneAttributeSemantic, format: super::super::super::Graphics::DirectX::DirectXPixelFormat, memory: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMesh", "FillMeshAttribute", 9usize, |this| ((**(this as *const *const ISceneMesh_Vtbl)).FillMeshAttribute)(this, semantic, format, memory), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Bounds,
+            #[cfg(feature = "Graphics_DirectX")]
+            PrimitiveTopology,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            PrimitiveTopology: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            SetPrimitiveTopology,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            SetPrimitiveTopology: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            FillMeshAttribute,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            FillMeshAttribute: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneMesh as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneMeshMaterialAttributeMap_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneMeshMaterialAttributeMap as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneMeshRendererComponent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Material(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMeshRendererComponent", "Material", 6usize, |this| ((**(this as *const *const ISceneMeshRendererComponent_Vtbl)).Material)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaterial(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMeshRendererComponent", "SetMaterial", 7usize, |this| ((**(this as *const *const ISceneMeshRendererComponent_Vtbl)).SetMaterial)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mesh(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMeshRendererComponent", "Mesh", 8usize, |this| ((**(this as *const *const ISceneMeshRendererComponent_Vtbl)).Mesh)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMesh(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMeshRendererComponent", "SetMesh", 9usize, |this| ((**(this as *const *const ISceneMeshRendererComponent_Vtbl)).SetMesh)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UVMappings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMeshRendererComponent", "UVMappings", 10usize, |this| ((**(this as *const *const ISceneMeshRendererComponent_Vtbl)).UVMappings)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Material, SetMaterial, Mesh, SetMesh, UVMappings }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneMeshRendererComponent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneMeshRendererComponentStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMeshRendererComponentStatics", "Create", 6usize, |this| ((**(this as *const *const ISceneMeshRendererComponentStatics_Vtbl)).Create)(this, compositor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneMeshRendererComponentStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneMeshStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMeshStatics", "Create", 6usize, |this| ((**(this as *const *const ISceneMeshStatics_Vtbl)).Create)(this, compositor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneMeshStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISceneMetallicRoughnessMaterial_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BaseColorInput(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMetallicRoughnessMaterial", "BaseColorInput", 6usize, |this| ((**(this as *const *const ISceneMetallicRoughnessMaterial_Vtbl)).BaseColorInput)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBaseColorInput(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMetallicRoughnessMaterial", "SetBaseColorInput", 7usize, |this| ((**(this as *const *const ISceneMetallicRoughnessMaterial_Vtbl)).SetBaseColorInput)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn BaseColorFactor(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMetallicRoughnessMaterial", "BaseColorFactor", 8usize, |this| ((**(this as *const *const ISceneMetallicRoughnessMaterial_Vtbl)).BaseColorFactor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetBaseColorFactor(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Numerics::Vector4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMetallicRoughnessMaterial", "SetBaseColorFactor", 9usize, |this| ((**(this as *const *const ISceneMetallicRoughnessMaterial_Vtbl)).SetBaseColorFactor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MetallicFactor(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMetallicRoughnessMaterial", "MetallicFactor", 10usize, |this| ((**(this as *const *const ISceneMetallicRoughnessMaterial_Vtbl)).MetallicFactor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMetallicFactor(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMetallicRoughnessMaterial", "SetMetallicFactor", 11usize, |this| ((**(this as *const *const ISceneMetallicRoughnessMaterial_Vtbl)).SetMetallicFactor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MetallicRoughnessInput(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMetallicRoughnessMaterial", "MetallicRoughnessInput", 12usize, |this| ((**(this as *const *const ISceneMetallicRoughnessMaterial_Vtbl)).MetallicRoughnessInput)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMetallicRoughnessInput(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMetallicRoughnessMaterial", "SetMetallicRoughnessInput", 13usize, |this| ((**(this as *const *const ISceneMetallicRoughnessMaterial_Vtbl)).SetMetallicRoughnessInput)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoughnessFactor(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMetallicRoughnessMaterial", "RoughnessFactor", 14usize, |this| ((**(this as *const *const ISceneMetallicRoughnessMaterial_Vtbl)).RoughnessFactor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoughnessFactor(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMetallicRoughnessMaterial", "SetRoughnessFactor", 15usize, |this| ((**(this as *const *const ISceneMetallicRoughnessMaterial_Vtbl)).SetRoughnessFactor)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BaseColorInput,
+            SetBaseColorInput,
+            #[cfg(feature = "Foundation_Numerics")]
+            BaseColorFactor,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            BaseColorFactor: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetBaseColorFactor,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetBaseColorFactor: 0,
+            MetallicFactor,
+            SetMetallicFactor,
+            MetallicRoughnessInput,
+            SetMetallicRoughnessInput,
+            RoughnessFactor,
+            SetRoughnessFactor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneMetallicRoughnessMaterial as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneMetallicRoughnessMaterialStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneMetallicRoughnessMaterialStatics", "Create", 6usize, |this| ((**(this as *const *const ISceneMetallicRoughnessMaterialStatics_Vtbl)).Create)(this, compositor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneMetallicRoughnessMaterialStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISceneModelTransform_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneModelTransform", "Orientation", 6usize, |this| ((**(this as *const *const ISceneModelTransform_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetOrientation(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneModelTransform", "SetOrientation", 7usize, |this| ((**(this as *const *const ISceneModelTransform_Vtbl)).SetOrientation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationAngle(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneModelTransform", "RotationAngle", 8usize, |this| ((**(this as *const *const ISceneModelTransform_Vtbl)).RotationAngle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotationAngle(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneModelTransform", "SetRotationAngle", 9usize, |this| ((**(this as *const *const ISceneModelTransform_Vtbl)).SetRotationAngle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationAngleInDegrees(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneModelTransform", "RotationAngleInDegrees", 10usize, |this| ((**(this as *const *const ISceneModelTransform_Vtbl)).RotationAngleInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotationAngleInDegrees(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneModelTransform", "SetRotationAngleInDegrees", 11usize, |this| ((**(this as *const *const ISceneModelTransform_Vtbl)).SetRotationAngleInDegrees)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn RotationAxis(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneModelTransform", "RotationAxis", 12usize, |this| ((**(this as *const *const ISceneModelTransform_Vtbl)).RotationAxis)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetRotationAxis(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneModelTransform", "SetRotationAxis", 13usize, |this| ((**(this as *const *const ISceneModelTransform_Vtbl)).SetRotationAxis)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Scale(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneModelTransform", "Scale", 14usize, |this| ((**(this as *const *const ISceneModelTransform_Vtbl)).Scale)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetScale(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneModelTransform", "SetScale", 15usize, |this| ((**(this as *const *const ISceneModelTransform_Vtbl)).SetScale)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Translation(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneModelTransform", "Translation", 16usize, |this| ((**(this as *const *const ISceneModelTransform_Vtbl)).Translation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetTranslation(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneModelTransform", "SetTranslation", 17usize, |this| ((**(this as *const *const ISceneModelTransform_Vtbl)).SetTranslation)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Orientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Orientation: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetOrientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetOrientation: 0,
+            RotationAngle,
+            SetRotationAngle,
+            RotationAngleInDegrees,
+            SetRotationAngleInDegrees,
+            #[cfg(feature = "Foundation_Numerics")]
+            RotationAxis,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            RotationAxis: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetRotationAxis,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetRotationAxis: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Scale,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Scale: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetScale,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetScale: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Translation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Translation: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetTranslation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetTranslation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneModelTransform as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISceneNode_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Children(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneNode", "Children", 6usize, |this| ((**(this as *const *const ISceneNode_Vtbl)).Children)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Components(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneNode", "Components", 7usize, |this| ((**(this as *const *const ISceneNode_Vtbl)).Components)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneNode", "Parent", 8usize, |this| ((**(this as *const *const ISceneNode_Vtbl)).Parent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Transform(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneNode", "Transform", 9usize, |this| ((**(this as *const *const ISceneNode_Vtbl)).Transform)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFirstComponentOfType(this: *mut core::ffi::c_void, value: SceneComponentType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneNode", "FindFirstComponentOfType", 10usize, |this| ((**(this as *const *const ISceneNode_Vtbl)).FindFirstComponentOfType)(this, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Children,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Children: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Components,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Components: 0,
+            Parent,
+            Transform,
+            FindFirstComponentOfType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneNode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneNodeCollection_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneNodeCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneNodeStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneNodeStatics", "Create", 6usize, |this| ((**(this as *const *const ISceneNodeStatics_Vtbl)).Create)(this, compositor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneNodeStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneObject_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneObject as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneObjectFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneObjectFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IScenePbrMaterial_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AlphaCutoff(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "AlphaCutoff", 6usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).AlphaCutoff)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlphaCutoff(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "SetAlphaCutoff", 7usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).SetAlphaCutoff)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlphaMode(this: *mut core::ffi::c_void, result__: *mut SceneAlphaMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "AlphaMode", 8usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).AlphaMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlphaMode(this: *mut core::ffi::c_void, value: SceneAlphaMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "SetAlphaMode", 9usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).SetAlphaMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmissiveInput(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "EmissiveInput", 10usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).EmissiveInput)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEmissiveInput(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "SetEmissiveInput", 11usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).SetEmissiveInput)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn EmissiveFactor(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "EmissiveFactor", 12usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).EmissiveFactor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetEmissiveFactor(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "SetEmissiveFactor", 13usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).SetEmissiveFactor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDoubleSided(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "IsDoubleSided", 14usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).IsDoubleSided)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsDoubleSided(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "SetIsDoubleSided", 15usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).SetIsDoubleSided)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NormalInput(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "NormalInput", 16usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).NormalInput)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNormalInput(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "SetNormalInput", 17usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).SetNormalInput)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NormalScale(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "NormalScale", 18usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).NormalScale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNormalScale(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "SetNormalScale", 19usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).SetNormalScale)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OcclusionInput(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "OcclusionInput", 20usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).OcclusionInput)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOcclusionInput(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "SetOcclusionInput", 21usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).SetOcclusionInput)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OcclusionStrength(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "OcclusionStrength", 22usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).OcclusionStrength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOcclusionStrength(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScenePbrMaterial", "SetOcclusionStrength", 23usize, |this| ((**(this as *const *const IScenePbrMaterial_Vtbl)).SetOcclusionStrength)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AlphaCutoff,
+            SetAlphaCutoff,
+            AlphaMode,
+            SetAlphaMode,
+            EmissiveInput,
+            SetEmissiveInput,
+            #[cfg(feature = "Foundation_Numerics")]
+            EmissiveFactor,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            EmissiveFactor: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetEmissiveFactor,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetEmissiveFactor: 0,
+            IsDoubleSided,
+            SetIsDoubleSided,
+            NormalInput,
+            SetNormalInput,
+            NormalScale,
+            SetNormalScale,
+            OcclusionInput,
+            SetOcclusionInput,
+            OcclusionStrength,
+            SetOcclusionStrength,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScenePbrMaterial as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScenePbrMaterialFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScenePbrMaterialFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneRendererComponent_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneRendererComponent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneRendererComponentFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneRendererComponentFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneSurfaceMaterialInput_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BitmapInterpolationMode(this: *mut core::ffi::c_void, result__: *mut super::CompositionBitmapInterpolationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneSurfaceMaterialInput", "BitmapInterpolationMode", 6usize, |this| ((**(this as *const *const ISceneSurfaceMaterialInput_Vtbl)).BitmapInterpolationMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBitmapInterpolationMode(this: *mut core::ffi::c_void, value: super::CompositionBitmapInterpolationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneSurfaceMaterialInput", "SetBitmapInterpolationMode", 7usize, |this| ((**(this as *const *const ISceneSurfaceMaterialInput_Vtbl)).SetBitmapInterpolationMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Surface(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneSurfaceMaterialInput", "Surface", 8usize, |this| ((**(this as *const *const ISceneSurfaceMaterialInput_Vtbl)).Surface)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSurface(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneSurfaceMaterialInput", "SetSurface", 9usize, |this| ((**(this as *const *const ISceneSurfaceMaterialInput_Vtbl)).SetSurface)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WrappingUMode(this: *mut core::ffi::c_void, result__: *mut SceneWrappingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneSurfaceMaterialInput", "WrappingUMode", 10usize, |this| ((**(this as *const *const ISceneSurfaceMaterialInput_Vtbl)).WrappingUMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWrappingUMode(this: *mut core::ffi::c_void, value: SceneWrappingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneSurfaceMaterialInput", "SetWrappingUMode", 11usize, |this| ((**(this as *const *const ISceneSurfaceMaterialInput_Vtbl)).SetWrappingUMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WrappingVMode(this: *mut core::ffi::c_void, result__: *mut SceneWrappingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneSurfaceMaterialInput", "WrappingVMode", 12usize, |this| ((**(this as *const *const ISceneSurfaceMaterialInput_Vtbl)).WrappingVMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWrappingVMode(this: *mut core::ffi::c_void, value: SceneWrappingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneSurfaceMaterialInput", "SetWrappingVMode", 13usize, |this| ((**(this as *const *const ISceneSurfaceMaterialInput_Vtbl)).SetWrappingVMode)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BitmapInterpolationMode,
+            SetBitmapInterpolationMode,
+            Surface,
+            SetSurface,
+            WrappingUMode,
+            SetWrappingUMode,
+            WrappingVMode,
+            SetWrappingVMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneSurfaceMaterialInput as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneSurfaceMaterialInputStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneSurfaceMaterialInputStatics", "Create", 6usize, |this| ((**(this as *const *const ISceneSurfaceMaterialInputStatics_Vtbl)).Create)(this, compositor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneSurfaceMaterialInputStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneVisual_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Root(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneVisual", "Root", 6usize, |this| ((**(this as *const *const ISceneVisual_Vtbl)).Root)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoot(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneVisual", "SetRoot", 7usize, |this| ((**(this as *const *const ISceneVisual_Vtbl)).SetRoot)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Root, SetRoot }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneVisual as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceneVisualStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceneVisualStatics", "Create", 6usize, |this| ((**(this as *const *const ISceneVisualStatics_Vtbl)).Create)(this, compositor, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceneVisualStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
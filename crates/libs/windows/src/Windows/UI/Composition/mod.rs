@@ -26177,6 +26177,7 @@ impl AnimationControllerProgressBehavior {
 impl windows_core::TypeKind for AnimationControllerProgressBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AnimationControllerProgressBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AnimationControllerProgressBehavior").field(&self.0).finish()
@@ -26195,6 +26196,7 @@ impl AnimationDelayBehavior {
 impl windows_core::TypeKind for AnimationDelayBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AnimationDelayBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AnimationDelayBehavior").field(&self.0).finish()
@@ -26215,6 +26217,7 @@ impl AnimationDirection {
 impl windows_core::TypeKind for AnimationDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AnimationDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AnimationDirection").field(&self.0).finish()
@@ -26233,6 +26236,7 @@ impl AnimationIterationBehavior {
 impl windows_core::TypeKind for AnimationIterationBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AnimationIterationBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AnimationIterationBehavior").field(&self.0).finish()
@@ -26253,6 +26257,7 @@ impl AnimationPropertyAccessMode {
 impl windows_core::TypeKind for AnimationPropertyAccessMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AnimationPropertyAccessMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AnimationPropertyAccessMode").field(&self.0).finish()
@@ -26272,6 +26277,7 @@ impl AnimationStopBehavior {
 impl windows_core::TypeKind for AnimationStopBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AnimationStopBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AnimationStopBehavior").field(&self.0).finish()
@@ -26291,6 +26297,7 @@ impl CompositionBackfaceVisibility {
 impl windows_core::TypeKind for CompositionBackfaceVisibility {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionBackfaceVisibility {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionBackfaceVisibility").field(&self.0).finish()
@@ -26312,6 +26319,7 @@ impl CompositionBatchTypes {
 impl windows_core::TypeKind for CompositionBatchTypes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionBatchTypes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionBatchTypes").field(&self.0).finish()
@@ -26371,6 +26379,7 @@ impl CompositionBitmapInterpolationMode {
 impl windows_core::TypeKind for CompositionBitmapInterpolationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionBitmapInterpolationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionBitmapInterpolationMode").field(&self.0).finish()
@@ -26390,6 +26399,7 @@ impl CompositionBorderMode {
 impl windows_core::TypeKind for CompositionBorderMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionBorderMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionBorderMode").field(&self.0).finish()
@@ -26411,6 +26421,7 @@ impl CompositionColorSpace {
 impl windows_core::TypeKind for CompositionColorSpace {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionColorSpace {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionColorSpace").field(&self.0).finish()
@@ -26431,6 +26442,7 @@ impl CompositionCompositeMode {
 impl windows_core::TypeKind for CompositionCompositeMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionCompositeMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionCompositeMode").field(&self.0).finish()
@@ -26449,6 +26461,7 @@ impl CompositionDropShadowSourcePolicy {
 impl windows_core::TypeKind for CompositionDropShadowSourcePolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionDropShadowSourcePolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionDropShadowSourcePolicy").field(&self.0).finish()
@@ -26468,6 +26481,7 @@ impl CompositionEasingFunctionMode {
 impl windows_core::TypeKind for CompositionEasingFunctionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionEasingFunctionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionEasingFunctionMode").field(&self.0).finish()
@@ -26488,6 +26502,7 @@ impl CompositionEffectFactoryLoadStatus {
 impl windows_core::TypeKind for CompositionEffectFactoryLoadStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionEffectFactoryLoadStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionEffectFactoryLoadStatus").field(&self.0).finish()
@@ -26507,6 +26522,7 @@ impl CompositionGetValueStatus {
 impl windows_core::TypeKind for CompositionGetValueStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionGetValueStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionGetValueStatus").field(&self.0).finish()
@@ -26526,6 +26542,7 @@ impl CompositionGradientExtendMode {
 impl windows_core::TypeKind for CompositionGradientExtendMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionGradientExtendMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionGradientExtendMode").field(&self.0).finish()
@@ -26544,6 +26561,7 @@ impl CompositionMappingMode {
 impl windows_core::TypeKind for CompositionMappingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionMappingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionMappingMode").field(&self.0).finish()
@@ -26564,6 +26582,7 @@ impl CompositionStretch {
 impl windows_core::TypeKind for CompositionStretch {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionStretch {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionStretch").field(&self.0).finish()
@@ -26584,6 +26603,7 @@ impl CompositionStrokeCap {
 impl windows_core::TypeKind for CompositionStrokeCap {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionStrokeCap {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionStrokeCap").field(&self.0).finish()
@@ -26604,6 +26624,7 @@ impl CompositionStrokeLineJoin {
 impl windows_core::TypeKind for CompositionStrokeLineJoin {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionStrokeLineJoin {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionStrokeLineJoin").field(&self.0).finish()
@@ -26613,7 +26634,8 @@ impl windows_core::RuntimeType for CompositionStrokeLineJoin {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.Composition.CompositionStrokeLineJoin;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct InkTrailPoint {
     pub Point: super::super::Foundation::Point,
     pub Radius: f32,
@@ -26631,3 +26653,5 @@ impl Default for InkTrailPoint {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
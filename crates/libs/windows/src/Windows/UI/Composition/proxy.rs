@@ -0,0 +1,5263 @@
+impl windows_core::imp::ProxyVtable for IAmbientLight_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAmbientLight", "Color", 6usize, |this| ((**(this as *const *const IAmbientLight_Vtbl)).Color)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, value: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAmbientLight", "SetColor", 7usize, |this| ((**(this as *const *const IAmbientLight_Vtbl)).SetColor)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Color, SetColor }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAmbientLight as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAmbientLight2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Intensity(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAmbientLight2", "Intensity", 6usize, |this| ((**(this as *const *const IAmbientLight2_Vtbl)).Intensity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIntensity(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAmbientLight2", "SetIntensity", 7usize, |this| ((**(this as *const *const IAmbientLight2_Vtbl)).SetIntensity)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Intensity, SetIntensity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAmbientLight2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAnimationController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PlaybackRate(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationController", "PlaybackRate", 6usize, |this| ((**(this as *const *const IAnimationController_Vtbl)).PlaybackRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlaybackRate(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationController", "SetPlaybackRate", 7usize, |this| ((**(this as *const *const IAnimationController_Vtbl)).SetPlaybackRate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationController", "Progress", 8usize, |this| ((**(this as *const *const IAnimationController_Vtbl)).Progress)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProgress(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationController", "SetProgress", 9usize, |this| ((**(this as *const *const IAnimationController_Vtbl)).SetProgress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProgressBehavior(this: *mut core::ffi::c_void, result__: *mut AnimationControllerProgressBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationController", "ProgressBehavior", 10usize, |this| ((**(this as *const *const IAnimationController_Vtbl)).ProgressBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProgressBehavior(this: *mut core::ffi::c_void, value: AnimationControllerProgressBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationController", "SetProgressBehavior", 11usize, |this| ((**(this as *const *const IAnimationController_Vtbl)).SetProgressBehavior)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationController", "Pause", 12usize, |this| ((**(this as *const *const IAnimationController_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationController", "Resume", 13usize, |this| ((**(this as *const *const IAnimationController_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PlaybackRate,
+            SetPlaybackRate,
+            Progress,
+            SetProgress,
+            ProgressBehavior,
+            SetProgressBehavior,
+            Pause,
+            Resume,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnimationController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAnimationControllerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxPlaybackRate(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationControllerStatics", "MaxPlaybackRate", 6usize, |this| ((**(this as *const *const IAnimationControllerStatics_Vtbl)).MaxPlaybackRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinPlaybackRate(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationControllerStatics", "MinPlaybackRate", 7usize, |this| ((**(this as *const *const IAnimationControllerStatics_Vtbl)).MinPlaybackRate)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MaxPlaybackRate, MinPlaybackRate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnimationControllerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAnimationObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PopulatePropertyInfo(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, propertyinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationObject", "PopulatePropertyInfo", 6usize, |this| ((**(this as *const *const IAnimationObject_Vtbl)).PopulatePropertyInfo)(this, propertyname, propertyinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PopulatePropertyInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnimationObject as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAnimationPropertyInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccessMode(this: *mut core::ffi::c_void, result__: *mut AnimationPropertyAccessMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationPropertyInfo", "AccessMode", 6usize, |this| ((**(this as *const *const IAnimationPropertyInfo_Vtbl)).AccessMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccessMode(this: *mut core::ffi::c_void, value: AnimationPropertyAccessMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationPropertyInfo", "SetAccessMode", 7usize, |this| ((**(this as *const *const IAnimationPropertyInfo_Vtbl)).SetAccessMode)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AccessMode, SetAccessMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnimationPropertyInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAnimationPropertyInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetResolvedCompositionObject(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationPropertyInfo2", "GetResolvedCompositionObject", 6usize, |this| ((**(this as *const *const IAnimationPropertyInfo2_Vtbl)).GetResolvedCompositionObject)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResolvedCompositionObjectProperty(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationPropertyInfo2", "GetResolvedCompositionObjectProperty", 7usize, |this| ((**(this as *const *const IAnimationPropertyInfo2_Vtbl)).GetResolvedCompositionObjectProperty)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetResolvedCompositionObject,
+            GetResolvedCompositionObjectProperty,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnimationPropertyInfo2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackEasingFunction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut CompositionEasingFunctionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackEasingFunction", "Mode", 6usize, |this| ((**(this as *const *const IBackEasingFunction_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Amplitude(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackEasingFunction", "Amplitude", 7usize, |this| ((**(this as *const *const IBackEasingFunction_Vtbl)).Amplitude)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Mode, Amplitude }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackEasingFunction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBooleanKeyFrameAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InsertKeyFrame(this: *mut core::ffi::c_void, normalizedprogresskey: f32, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBooleanKeyFrameAnimation", "InsertKeyFrame", 6usize, |this| ((**(this as *const *const IBooleanKeyFrameAnimation_Vtbl)).InsertKeyFrame)(this, normalizedprogresskey, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InsertKeyFrame }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBooleanKeyFrameAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBounceEasingFunction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut CompositionEasingFunctionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBounceEasingFunction", "Mode", 6usize, |this| ((**(this as *const *const IBounceEasingFunction_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bounces(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBounceEasingFunction", "Bounces", 7usize, |this| ((**(this as *const *const IBounceEasingFunction_Vtbl)).Bounces)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bounciness(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBounceEasingFunction", "Bounciness", 8usize, |this| ((**(this as *const *const IBounceEasingFunction_Vtbl)).Bounciness)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Mode, Bounces, Bounciness }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBounceEasingFunction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBounceScalarNaturalMotionAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Acceleration(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBounceScalarNaturalMotionAnimation", "Acceleration", 6usize, |this| ((**(this as *const *const IBounceScalarNaturalMotionAnimation_Vtbl)).Acceleration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAcceleration(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBounceScalarNaturalMotionAnimation", "SetAcceleration", 7usize, |this| ((**(this as *const *const IBounceScalarNaturalMotionAnimation_Vtbl)).SetAcceleration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Restitution(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBounceScalarNaturalMotionAnimation", "Restitution", 8usize, |this| ((**(this as *const *const IBounceScalarNaturalMotionAnimation_Vtbl)).Restitution)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRestitution(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBounceScalarNaturalMotionAnimation", "SetRestitution", 9usize, |this| ((**(this as *const *const IBounceScalarNaturalMotionAnimation_Vtbl)).SetRestitution)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Acceleration, SetAcceleration, Restitution, SetRestitution }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBounceScalarNaturalMotionAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBounceVector2NaturalMotionAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Acceleration(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBounceVector2NaturalMotionAnimation", "Acceleration", 6usize, |this| ((**(this as *const *const IBounceVector2NaturalMotionAnimation_Vtbl)).Acceleration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAcceleration(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBounceVector2NaturalMotionAnimation", "SetAcceleration", 7usize, |this| ((**(this as *const *const IBounceVector2NaturalMotionAnimation_Vtbl)).SetAcceleration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Restitution(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBounceVector2NaturalMotionAnimation", "Restitution", 8usize, |this| ((**(this as *const *const IBounceVector2NaturalMotionAnimation_Vtbl)).Restitution)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRestitution(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBounceVector2NaturalMotionAnimation", "SetRestitution", 9usize, |this| ((**(this as *const *const IBounceVector2NaturalMotionAnimation_Vtbl)).SetRestitution)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Acceleration, SetAcceleration, Restitution, SetRestitution }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBounceVector2NaturalMotionAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBounceVector3NaturalMotionAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Acceleration(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBounceVector3NaturalMotionAnimation", "Acceleration", 6usize, |this| ((**(this as *const *const IBounceVector3NaturalMotionAnimation_Vtbl)).Acceleration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAcceleration(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBounceVector3NaturalMotionAnimation", "SetAcceleration", 7usize, |this| ((**(this as *const *const IBounceVector3NaturalMotionAnimation_Vtbl)).SetAcceleration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Restitution(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBounceVector3NaturalMotionAnimation", "Restitution", 8usize, |this| ((**(this as *const *const IBounceVector3NaturalMotionAnimation_Vtbl)).Restitution)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRestitution(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBounceVector3NaturalMotionAnimation", "SetRestitution", 9usize, |this| ((**(this as *const *const IBounceVector3NaturalMotionAnimation_Vtbl)).SetRestitution)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Acceleration, SetAcceleration, Restitution, SetRestitution }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBounceVector3NaturalMotionAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICircleEasingFunction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut CompositionEasingFunctionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICircleEasingFunction", "Mode", 6usize, |this| ((**(this as *const *const ICircleEasingFunction_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Mode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICircleEasingFunction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IColorKeyFrameAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InterpolationColorSpace(this: *mut core::ffi::c_void, result__: *mut CompositionColorSpace) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorKeyFrameAnimation", "InterpolationColorSpace", 6usize, |this| ((**(this as *const *const IColorKeyFrameAnimation_Vtbl)).InterpolationColorSpace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterpolationColorSpace(this: *mut core::ffi::c_void, value: CompositionColorSpace) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorKeyFrameAnimation", "SetInterpolationColorSpace", 7usize, |this| ((**(this as *const *const IColorKeyFrameAnimation_Vtbl)).SetInterpolationColorSpace)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertKeyFrame(this: *mut core::ffi::c_void, normalizedprogresskey: f32, value: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorKeyFrameAnimation", "InsertKeyFrame", 8usize, |this| ((**(this as *const *const IColorKeyFrameAnimation_Vtbl)).InsertKeyFrame)(this, normalizedprogresskey, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertKeyFrameWithEasingFunction(this: *mut core::ffi::c_void, normalizedprogresskey: f32, value: super::Color, easingfunction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorKeyFrameAnimation", "InsertKeyFrameWithEasingFunction", 9usize, |this| ((**(this as *const *const IColorKeyFrameAnimation_Vtbl)).InsertKeyFrameWithEasingFunction)(this, normalizedprogresskey, value, easingfunction), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InterpolationColorSpace,
+            SetInterpolationColorSpace,
+            InsertKeyFrame,
+            InsertKeyFrameWithEasingFunction,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IColorKeyFrameAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICompositionAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClearAllParameters(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation", "ClearAllParameters", 6usize, |this| ((**(this as *const *const ICompositionAnimation_Vtbl)).ClearAllParameters)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearParameter(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation", "ClearParameter", 7usize, |this| ((**(this as *const *const ICompositionAnimation_Vtbl)).ClearParameter)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorParameter(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation", "SetColorParameter", 8usize, |this| ((**(this as *const *const ICompositionAnimation_Vtbl)).SetColorParameter)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetMatrix3x2Parameter(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation", "SetMatrix3x2Parameter", 9usize, |this| ((**(this as *const *const ICompositionAnimation_Vtbl)).SetMatrix3x2Parameter)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetMatrix4x4Parameter(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::super::Foundation::Numerics::Matrix4x4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation", "SetMatrix4x4Parameter", 10usize, |this| ((**(this as *const *const ICompositionAnimation_Vtbl)).SetMatrix4x4Parameter)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetQuaternionParameter(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation", "SetQuaternionParameter", 11usize, |this| ((**(this as *const *const ICompositionAnimation_Vtbl)).SetQuaternionParameter)(this, key, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReferenceParameter(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>, compositionobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation", "SetReferenceParameter", 12usize, |this| ((**(this as *const *const ICompositionAnimation_Vtbl)).SetReferenceParameter)(this, key, compositionobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScalarParameter(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation", "SetScalarParameter", 13usize, |this| ((**(this as *const *const ICompositionAnimation_Vtbl)).SetScalarParameter)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetVector2Parameter(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation", "SetVector2Parameter", 14usize, |this| ((**(this as *const *const ICompositionAnimation_Vtbl)).SetVector2Parameter)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetVector3Parameter(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation", "SetVector3Parameter", 15usize, |this| ((**(this as *const *const ICompositionAnimation_Vtbl)).SetVector3Parameter)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetVector4Parameter(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::super::Foundation::Numerics::Vector4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation", "SetVector4Parameter", 16usize, |this| ((**(this as *const *const ICompositionAnimation_Vtbl)).SetVector4Parameter)(this, key, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ClearAllParameters,
+            ClearParameter,
+            SetColorParameter,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetMatrix3x2Parameter,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetMatrix3x2Parameter: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetMatrix4x4Parameter,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetMatrix4x4Parameter: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetQuaternionParameter,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetQuaternionParameter: 0,
+            SetReferenceParameter,
+            SetScalarParameter,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetVector2Parameter,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetVector2Parameter: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetVector3Parameter,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetVector3Parameter: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetVector4Parameter,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetVector4Parameter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionAnimation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBooleanParameter(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation2", "SetBooleanParameter", 6usize, |this| ((**(this as *const *const ICompositionAnimation2_Vtbl)).SetBooleanParameter)(this, key, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Target(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation2", "Target", 7usize, |this| ((**(this as *const *const ICompositionAnimation2_Vtbl)).Target)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTarget(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation2", "SetTarget", 8usize, |this| ((**(this as *const *const ICompositionAnimation2_Vtbl)).SetTarget)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetBooleanParameter, Target, SetTarget }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionAnimation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICompositionAnimation3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn InitialValueExpressions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation3", "InitialValueExpressions", 6usize, |this| ((**(this as *const *const ICompositionAnimation3_Vtbl)).InitialValueExpressions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            InitialValueExpressions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            InitialValueExpressions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionAnimation3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionAnimation4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetExpressionReferenceParameter(this: *mut core::ffi::c_void, parametername: core::mem::MaybeUninit<windows_core::HSTRING>, source: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimation4", "SetExpressionReferenceParameter", 6usize, |this| ((**(this as *const *const ICompositionAnimation4_Vtbl)).SetExpressionReferenceParameter)(this, parametername, source), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetExpressionReferenceParameter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionAnimation4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionAnimationBase_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionAnimationBase as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionAnimationFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionAnimationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionAnimationGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimationGroup", "Count", 6usize, |this| ((**(this as *const *const ICompositionAnimationGroup_Vtbl)).Count)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimationGroup", "Add", 7usize, |this| ((**(this as *const *const ICompositionAnimationGroup_Vtbl)).Add)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimationGroup", "Remove", 8usize, |this| ((**(this as *const *const ICompositionAnimationGroup_Vtbl)).Remove)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionAnimationGroup", "RemoveAll", 9usize, |this| ((**(this as *const *const ICompositionAnimationGroup_Vtbl)).RemoveAll)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, Add, Remove, RemoveAll }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionAnimationGroup as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionBackdropBrush_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionBackdropBrush as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionBatchCompletedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionBatchCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionBrush_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionBrush as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionBrushFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionBrushFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AreEffectsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionCapabilities", "AreEffectsSupported", 6usize, |this| ((**(this as *const *const ICompositionCapabilities_Vtbl)).AreEffectsSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AreEffectsFast(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionCapabilities", "AreEffectsFast", 7usize, |this| ((**(this as *const *const ICompositionCapabilities_Vtbl)).AreEffectsFast)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Changed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionCapabilities", "Changed", 8usize, |this| ((**(this as *const *const ICompositionCapabilities_Vtbl)).Changed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionCapabilities", "RemoveChanged", 9usize, |this| ((**(this as *const *const ICompositionCapabilities_Vtbl)).RemoveChanged)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AreEffectsSupported, AreEffectsFast, Changed, RemoveChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionCapabilities as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionCapabilitiesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionCapabilitiesStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const ICompositionCapabilitiesStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionCapabilitiesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionClip_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionClip as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICompositionClip2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn AnchorPoint(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionClip2", "AnchorPoint", 6usize, |this| ((**(this as *const *const ICompositionClip2_Vtbl)).AnchorPoint)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetAnchorPoint(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionClip2", "SetAnchorPoint", 7usize, |this| ((**(this as *const *const ICompositionClip2_Vtbl)).SetAnchorPoint)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CenterPoint(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionClip2", "CenterPoint", 8usize, |this| ((**(this as *const *const ICompositionClip2_Vtbl)).CenterPoint)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetCenterPoint(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionClip2", "SetCenterPoint", 9usize, |this| ((**(this as *const *const ICompositionClip2_Vtbl)).SetCenterPoint)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionClip2", "Offset", 10usize, |this| ((**(this as *const *const ICompositionClip2_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionClip2", "SetOffset", 11usize, |this| ((**(this as *const *const ICompositionClip2_Vtbl)).SetOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationAngle(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionClip2", "RotationAngle", 12usize, |this| ((**(this as *const *const ICompositionClip2_Vtbl)).RotationAngle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotationAngle(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionClip2", "SetRotationAngle", 13usize, |this| ((**(this as *const *const ICompositionClip2_Vtbl)).SetRotationAngle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationAngleInDegrees(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionClip2", "RotationAngleInDegrees", 14usize, |this| ((**(this as *const *const ICompositionClip2_Vtbl)).RotationAngleInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotationAngleInDegrees(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionClip2", "SetRotationAngleInDegrees", 15usize, |this| ((**(this as *const *const ICompositionClip2_Vtbl)).SetRotationAngleInDegrees)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Scale(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionClip2", "Scale", 16usize, |this| ((**(this as *const *const ICompositionClip2_Vtbl)).Scale)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetScale(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionClip2", "SetScale", 17usize, |this| ((**(this as *const *const ICompositionClip2_Vtbl)).SetScale)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TransformMatrix(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionClip2", "TransformMatrix", 18usize, |this| ((**(this as *const *const ICompositionClip2_Vtbl)).TransformMatrix)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetTransformMatrix(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionClip2", "SetTransformMatrix", 19usize, |this| ((**(this as *const *const ICompositionClip2_Vtbl)).SetTransformMatrix)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            AnchorPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            AnchorPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetAnchorPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetAnchorPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            CenterPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CenterPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetCenterPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetCenterPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Offset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Offset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetOffset: 0,
+            RotationAngle,
+            SetRotationAngle,
+            RotationAngleInDegrees,
+            SetRotationAngleInDegrees,
+            #[cfg(feature = "Foundation_Numerics")]
+            Scale,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Scale: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetScale,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetScale: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TransformMatrix,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TransformMatrix: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetTransformMatrix,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetTransformMatrix: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionClip2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionClipFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionClipFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionColorBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionColorBrush", "Color", 6usize, |this| ((**(this as *const *const ICompositionColorBrush_Vtbl)).Color)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, value: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionColorBrush", "SetColor", 7usize, |this| ((**(this as *const *const ICompositionColorBrush_Vtbl)).SetColor)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Color, SetColor }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionColorBrush as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionColorGradientStop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionColorGradientStop", "Color", 6usize, |this| ((**(this as *const *const ICompositionColorGradientStop_Vtbl)).Color)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, value: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionColorGradientStop", "SetColor", 7usize, |this| ((**(this as *const *const ICompositionColorGradientStop_Vtbl)).SetColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionColorGradientStop", "Offset", 8usize, |this| ((**(this as *const *const ICompositionColorGradientStop_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionColorGradientStop", "SetOffset", 9usize, |this| ((**(this as *const *const ICompositionColorGradientStop_Vtbl)).SetOffset)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Color, SetColor, Offset, SetOffset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionColorGradientStop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionColorGradientStopCollection_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionColorGradientStopCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionCommitBatch_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsActive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionCommitBatch", "IsActive", 6usize, |this| ((**(this as *const *const ICompositionCommitBatch_Vtbl)).IsActive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionCommitBatch", "IsEnded", 7usize, |this| ((**(this as *const *const ICompositionCommitBatch_Vtbl)).IsEnded)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Completed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionCommitBatch", "Completed", 8usize, |this| ((**(this as *const *const ICompositionCommitBatch_Vtbl)).Completed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionCommitBatch", "RemoveCompleted", 9usize, |this| ((**(this as *const *const ICompositionCommitBatch_Vtbl)).RemoveCompleted)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsActive, IsEnded, Completed, RemoveCompleted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionCommitBatch as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICompositionContainerShape_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Shapes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionContainerShape", "Shapes", 6usize, |this| ((**(this as *const *const ICompositionContainerShape_Vtbl)).Shapes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Shapes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Shapes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionContainerShape as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX")]
+impl windows_core::imp::ProxyVtable for ICompositionDrawingSurface_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn AlphaMode(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::DirectX::DirectXAlphaMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurface", "AlphaMode", 6usize, |this| ((**(this as *const *const ICompositionDrawingSurface_Vtbl)).AlphaMode)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn PixelFormat(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurface", "PixelFormat", 7usize, |this| ((**(this as *const *const ICompositionDrawingSurface_Vtbl)).PixelFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurface", "Size", 8usize, |this| ((**(this as *const *const ICompositionDrawingSurface_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX")]
+            AlphaMode,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            AlphaMode: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            PixelFormat,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            PixelFormat: 0,
+            Size,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionDrawingSurface as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics")]
+impl windows_core::imp::ProxyVtable for ICompositionDrawingSurface2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn SizeInt32(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::SizeInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurface2", "SizeInt32", 6usize, |this| ((**(this as *const *const ICompositionDrawingSurface2_Vtbl)).SizeInt32)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn Resize(this: *mut core::ffi::c_void, sizepixels: super::super::Graphics::SizeInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurface2", "Resize", 7usize, |this| ((**(this as *const *const ICompositionDrawingSurface2_Vtbl)).Resize)(this, sizepixels), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn Scroll(this: *mut core::ffi::c_void, offset: super::super::Graphics::PointInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurface2", "Scroll", 8usize, |this| ((**(this as *const *const ICompositionDrawingSurface2_Vtbl)).Scroll)(this, offset), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn ScrollRect(this: *mut core::ffi::c_void, offset: super::super::Graphics::PointInt32, scrollrect: super::super::Graphics::RectInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurface2", "ScrollRect", 9usize, |this| ((**(this as *const *const ICompositionDrawingSurface2_Vtbl)).ScrollRect)(this, offset, scrollrect), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn ScrollWithClip(this: *mut core::ffi::c_void, offset: super::super::Graphics::PointInt32, cliprect: super::super::Graphics::RectInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurface2", "ScrollWithClip", 10usize, |this| ((**(this as *const *const ICompositionDrawingSurface2_Vtbl)).ScrollWithClip)(this, offset, cliprect), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn ScrollRectWithClip(this: *mut core::ffi::c_void, offset: super::super::Graphics::PointInt32, cliprect: super::super::Graphics::RectInt32, scrollrect: super::super::Graphics::RectInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurface2", "ScrollRectWithClip", 11usize, |this| ((**(this as *const *const ICompositionDrawingSurface2_Vtbl)).ScrollRectWithClip)(this, offset, cliprect, scrollrect), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics")]
+            SizeInt32,
+            #[cfg(not(feature = "Graphics"))]
+            SizeInt32: 0,
+            #[cfg(feature = "Graphics")]
+            Resize,
+            #[cfg(not(feature = "Graphics"))]
+            Resize: 0,
+            #[cfg(feature = "Graphics")]
+            Scroll,
+            #[cfg(not(feature = "Graphics"))]
+            Scroll: 0,
+            #[cfg(feature = "Graphics")]
+            ScrollRect,
+            #[cfg(not(feature = "Graphics"))]
+            ScrollRect: 0,
+            #[cfg(feature = "Graphics")]
+            ScrollWithClip,
+            #[cfg(not(feature = "Graphics"))]
+            ScrollWithClip: 0,
+            #[cfg(feature = "Graphics")]
+            ScrollRectWithClip,
+            #[cfg(not(feature = "Graphics"))]
+            ScrollRectWithClip: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionDrawingSurface2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionDrawingSurfaceFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionDrawingSurfaceFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionEasingFunction_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionEasingFunction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionEasingFunctionFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionEasingFunctionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICompositionEasingFunctionStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CreateCubicBezierEasingFunction(this: *mut core::ffi::c_void, owner: *mut core::ffi::c_void, controlpoint1: super::super::Foundation::Numerics::Vector2, controlpoint2: super::super::Foundation::Numerics::Vector2, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEasingFunctionStatics", "CreateCubicBezierEasingFunction", 6usize, |this| ((**(this as *const *const ICompositionEasingFunctionStatics_Vtbl)).CreateCubicBezierEasingFunction)(this, owner, controlpoint1, controlpoint2, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLinearEasingFunction(this: *mut core::ffi::c_void, owner: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEasingFunctionStatics", "CreateLinearEasingFunction", 7usize, |this| ((**(this as *const *const ICompositionEasingFunctionStatics_Vtbl)).CreateLinearEasingFunction)(this, owner, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStepEasingFunction(this: *mut core::ffi::c_void, owner: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEasingFunctionStatics", "CreateStepEasingFunction", 8usize, |this| ((**(this as *const *const ICompositionEasingFunctionStatics_Vtbl)).CreateStepEasingFunction)(this, owner, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStepEasingFunctionWithStepCount(this: *mut core::ffi::c_void, owner: *mut core::ffi::c_void, stepcount: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEasingFunctionStatics", "CreateStepEasingFunctionWithStepCount", 9usize, |this| ((**(this as *const *const ICompositionEasingFunctionStatics_Vtbl)).CreateStepEasingFunctionWithStepCount)(this, owner, stepcount, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBackEasingFunction(this: *mut core::ffi::c_void, owner: *mut core::ffi::c_void, mode: CompositionEasingFunctionMode, amplitude: f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEasingFunctionStatics", "CreateBackEasingFunction", 10usize, |this| ((**(this as *const *const ICompositionEasingFunctionStatics_Vtbl)).CreateBackEasingFunction)(this, owner, mode, amplitude, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBounceEasingFunction(this: *mut core::ffi::c_void, owner: *mut core::ffi::c_void, mode: CompositionEasingFunctionMode, bounces: i32, bounciness: f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEasingFunctionStatics", "CreateBounceEasingFunction", 11usize, |this| ((**(this as *const *const ICompositionEasingFunctionStatics_Vtbl)).CreateBounceEasingFunction)(this, owner, mode, bounces, bounciness, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCircleEasingFunction(this: *mut core::ffi::c_void, owner: *mut core::ffi::c_void, mode: CompositionEasingFunctionMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEasingFunctionStatics", "CreateCircleEasingFunction", 12usize, |this| ((**(this as *const *const ICompositionEasingFunctionStatics_Vtbl)).CreateCircleEasingFunction)(this, owner, mode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateElasticEasingFunction(this: *mut core::ffi::c_void, owner: *mut core::ffi::c_void, mode: CompositionEasingFunctionMode, oscillations: i32, springiness: f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEasingFunctionStatics", "CreateElasticEasingFunction", 13usize, |this| ((**(this as *const *const ICompositionEasingFunctionStatics_Vtbl)).CreateElasticEasingFunction)(this, owner, mode, oscillations, springiness, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateExponentialEasingFunction(this: *mut core::ffi::c_void, owner: *mut core::ffi::c_void, mode: CompositionEasingFunctionMode, exponent: f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEasingFunctionStatics", "CreateExponentialEasingFunction", 14usize, |this| ((**(this as *const *const ICompositionEasingFunctionStatics_Vtbl)).CreateExponentialEasingFunction)(this, owner, mode, exponent, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePowerEasingFunction(this: *mut core::ffi::c_void, owner: *mut core::ffi::c_void, mode: CompositionEasingFunctionMode, power: f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEasingFunctionStatics", "CreatePowerEasingFunction", 15usize, |this| ((**(this as *const *const ICompositionEasingFunctionStatics_Vtbl)).CreatePowerEasingFunction)(this, owner, mode, power, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSineEasingFunction(this: *mut core::ffi::c_void, owner: *mut core::ffi::c_void, mode: CompositionEasingFunctionMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEasingFunctionStatics", "CreateSineEasingFunction", 16usize, |this| ((**(this as *const *const ICompositionEasingFunctionStatics_Vtbl)).CreateSineEasingFunction)(this, owner, mode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            CreateCubicBezierEasingFunction,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CreateCubicBezierEasingFunction: 0,
+            CreateLinearEasingFunction,
+            CreateStepEasingFunction,
+            CreateStepEasingFunctionWithStepCount,
+            CreateBackEasingFunction,
+            CreateBounceEasingFunction,
+            CreateCircleEasingFunction,
+            CreateElasticEasingFunction,
+            CreateExponentialEasingFunction,
+            CreatePowerEasingFunction,
+            CreateSineEasingFunction,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionEasingFunctionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionEffectBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSourceParameter(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEffectBrush", "GetSourceParameter", 6usize, |this| ((**(this as *const *const ICompositionEffectBrush_Vtbl)).GetSourceParameter)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceParameter(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, source: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEffectBrush", "SetSourceParameter", 7usize, |this| ((**(this as *const *const ICompositionEffectBrush_Vtbl)).SetSourceParameter)(this, name, source), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSourceParameter, SetSourceParameter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionEffectBrush as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionEffectFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateBrush(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEffectFactory", "CreateBrush", 6usize, |this| ((**(this as *const *const ICompositionEffectFactory_Vtbl)).CreateBrush)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEffectFactory", "ExtendedError", 7usize, |this| ((**(this as *const *const ICompositionEffectFactory_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadStatus(this: *mut core::ffi::c_void, result__: *mut CompositionEffectFactoryLoadStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEffectFactory", "LoadStatus", 8usize, |this| ((**(this as *const *const ICompositionEffectFactory_Vtbl)).LoadStatus)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateBrush, ExtendedError, LoadStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionEffectFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionEffectSourceParameter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEffectSourceParameter", "Name", 6usize, |this| ((**(this as *const *const ICompositionEffectSourceParameter_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionEffectSourceParameter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionEffectSourceParameterFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEffectSourceParameterFactory", "Create", 6usize, |this| ((**(this as *const *const ICompositionEffectSourceParameterFactory_Vtbl)).Create)(this, name, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionEffectSourceParameterFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICompositionEllipseGeometry_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Center(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEllipseGeometry", "Center", 6usize, |this| ((**(this as *const *const ICompositionEllipseGeometry_Vtbl)).Center)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetCenter(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEllipseGeometry", "SetCenter", 7usize, |this| ((**(this as *const *const ICompositionEllipseGeometry_Vtbl)).SetCenter)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Radius(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEllipseGeometry", "Radius", 8usize, |this| ((**(this as *const *const ICompositionEllipseGeometry_Vtbl)).Radius)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetRadius(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionEllipseGeometry", "SetRadius", 9usize, |this| ((**(this as *const *const ICompositionEllipseGeometry_Vtbl)).SetRadius)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Center,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Center: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetCenter,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetCenter: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Radius,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Radius: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetRadius,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetRadius: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionEllipseGeometry as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionGeometricClip_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Geometry(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGeometricClip", "Geometry", 6usize, |this| ((**(this as *const *const ICompositionGeometricClip_Vtbl)).Geometry)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGeometry(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGeometricClip", "SetGeometry", 7usize, |this| ((**(this as *const *const ICompositionGeometricClip_Vtbl)).SetGeometry)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ViewBox(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGeometricClip", "ViewBox", 8usize, |this| ((**(this as *const *const ICompositionGeometricClip_Vtbl)).ViewBox)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewBox(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGeometricClip", "SetViewBox", 9usize, |this| ((**(this as *const *const ICompositionGeometricClip_Vtbl)).SetViewBox)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Geometry, SetGeometry, ViewBox, SetViewBox }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionGeometricClip as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionGeometry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TrimEnd(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGeometry", "TrimEnd", 6usize, |this| ((**(this as *const *const ICompositionGeometry_Vtbl)).TrimEnd)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrimEnd(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGeometry", "SetTrimEnd", 7usize, |this| ((**(this as *const *const ICompositionGeometry_Vtbl)).SetTrimEnd)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrimOffset(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGeometry", "TrimOffset", 8usize, |this| ((**(this as *const *const ICompositionGeometry_Vtbl)).TrimOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrimOffset(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGeometry", "SetTrimOffset", 9usize, |this| ((**(this as *const *const ICompositionGeometry_Vtbl)).SetTrimOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrimStart(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGeometry", "TrimStart", 10usize, |this| ((**(this as *const *const ICompositionGeometry_Vtbl)).TrimStart)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrimStart(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGeometry", "SetTrimStart", 11usize, |this| ((**(this as *const *const ICompositionGeometry_Vtbl)).SetTrimStart)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TrimEnd,
+            SetTrimEnd,
+            TrimOffset,
+            SetTrimOffset,
+            TrimStart,
+            SetTrimStart,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionGeometry as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionGeometryFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionGeometryFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICompositionGradientBrush_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn AnchorPoint(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "AnchorPoint", 6usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).AnchorPoint)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetAnchorPoint(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "SetAnchorPoint", 7usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).SetAnchorPoint)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CenterPoint(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "CenterPoint", 8usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).CenterPoint)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetCenterPoint(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "SetCenterPoint", 9usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).SetCenterPoint)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorStops(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "ColorStops", 10usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).ColorStops)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendMode(this: *mut core::ffi::c_void, result__: *mut CompositionGradientExtendMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "ExtendMode", 11usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).ExtendMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtendMode(this: *mut core::ffi::c_void, value: CompositionGradientExtendMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "SetExtendMode", 12usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).SetExtendMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InterpolationSpace(this: *mut core::ffi::c_void, result__: *mut CompositionColorSpace) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "InterpolationSpace", 13usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).InterpolationSpace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterpolationSpace(this: *mut core::ffi::c_void, value: CompositionColorSpace) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "SetInterpolationSpace", 14usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).SetInterpolationSpace)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "Offset", 15usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "SetOffset", 16usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).SetOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationAngle(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "RotationAngle", 17usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).RotationAngle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotationAngle(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "SetRotationAngle", 18usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).SetRotationAngle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationAngleInDegrees(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "RotationAngleInDegrees", 19usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).RotationAngleInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotationAngleInDegrees(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "SetRotationAngleInDegrees", 20usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).SetRotationAngleInDegrees)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Scale(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "Scale", 21usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).Scale)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetScale(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "SetScale", 22usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).SetScale)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TransformMatrix(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "TransformMatrix", 23usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).TransformMatrix)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetTransformMatrix(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush", "SetTransformMatrix", 24usize, |this| ((**(this as *const *const ICompositionGradientBrush_Vtbl)).SetTransformMatrix)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            AnchorPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            AnchorPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetAnchorPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetAnchorPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            CenterPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CenterPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetCenterPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetCenterPoint: 0,
+            ColorStops,
+            ExtendMode,
+            SetExtendMode,
+            InterpolationSpace,
+            SetInterpolationSpace,
+            #[cfg(feature = "Foundation_Numerics")]
+            Offset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Offset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetOffset: 0,
+            RotationAngle,
+            SetRotationAngle,
+            RotationAngleInDegrees,
+            SetRotationAngleInDegrees,
+            #[cfg(feature = "Foundation_Numerics")]
+            Scale,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Scale: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetScale,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetScale: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TransformMatrix,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TransformMatrix: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetTransformMatrix,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetTransformMatrix: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionGradientBrush as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionGradientBrush2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MappingMode(this: *mut core::ffi::c_void, result__: *mut CompositionMappingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush2", "MappingMode", 6usize, |this| ((**(this as *const *const ICompositionGradientBrush2_Vtbl)).MappingMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMappingMode(this: *mut core::ffi::c_void, value: CompositionMappingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGradientBrush2", "SetMappingMode", 7usize, |this| ((**(this as *const *const ICompositionGradientBrush2_Vtbl)).SetMappingMode)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MappingMode, SetMappingMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionGradientBrush2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionGradientBrushFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionGradientBrushFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX")]
+impl windows_core::imp::ProxyVtable for ICompositionGraphicsDevice_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn CreateDrawingSurface(this: *mut core::ffi::c_void, sizepixels: super::super::Foundation::Size, pixelformat: super::super::Graphics::DirectX::DirectXPixelFormat, alphamode: super::super::Graphics::DirectX::DirectXAlphaMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGraphicsDevice", "CreateDrawingSurface", 6usize, |this| ((**(this as *const *const ICompositionGraphicsDevice_Vtbl)).CreateDrawingSurface)(this, sizepixels, pixelformat, alphamode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenderingDeviceReplaced(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGraphicsDevice", "RenderingDeviceReplaced", 7usize, |this| ((**(this as *const *const ICompositionGraphicsDevice_Vtbl)).RenderingDeviceReplaced)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRenderingDeviceReplaced(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGraphicsDevice", "RemoveRenderingDeviceReplaced", 8usize, |this| ((**(this as *const *const ICompositionGraphicsDevice_Vtbl)).RemoveRenderingDeviceReplaced)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX")]
+            CreateDrawingSurface,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            CreateDrawingSurface: 0,
+            RenderingDeviceReplaced,
+            RemoveRenderingDeviceReplaced,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionGraphicsDevice as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX")]
+impl windows_core::imp::ProxyVtable for ICompositionGraphicsDevice2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn CreateDrawingSurface2(this: *mut core::ffi::c_void, sizepixels: super::super::Graphics::SizeInt32, pixelformat: super::super::Graphics::DirectX::DirectXPixelFormat, alphamode: super::super::Graphics::DirectX::DirectXAlphaMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGraphicsDevice2", "CreateDrawingSurface2", 6usize, |this| ((**(this as *const *const ICompositionGraphicsDevice2_Vtbl)).CreateDrawingSurface2)(this, sizepixels, pixelformat, alphamode, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn CreateVirtualDrawingSurface(this: *mut core::ffi::c_void, sizepixels: super::super::Graphics::SizeInt32, pixelformat: super::super::Graphics::DirectX::DirectXPixelFormat, alphamode: super::super::Graphics::DirectX::DirectXAlphaMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGraphicsDevice2", "CreateVirtualDrawingSurface", 7usize, |this| ((**(this as *const *const ICompositionGraphicsDevice2_Vtbl)).CreateVirtualDrawingSurface)(this, sizepixels, pixelformat, alphamode, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX")]
+            CreateDrawingSurface2,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            CreateDrawingSurface2: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            CreateVirtualDrawingSurface,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            CreateVirtualDrawingSurface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionGraphicsDevice2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX")]
+impl windows_core::imp::ProxyVtable for ICompositionGraphicsDevice3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn CreateMipmapSurface(this: *mut core::ffi::c_void, sizepixels: super::super::Graphics::SizeInt32, pixelformat: super::super::Graphics::DirectX::DirectXPixelFormat, alphamode: super::super::Graphics::DirectX::DirectXAlphaMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGraphicsDevice3", "CreateMipmapSurface", 6usize, |this| ((**(this as *const *const ICompositionGraphicsDevice3_Vtbl)).CreateMipmapSurface)(this, sizepixels, pixelformat, alphamode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Trim(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGraphicsDevice3", "Trim", 7usize, |this| ((**(this as *const *const ICompositionGraphicsDevice3_Vtbl)).Trim)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX")]
+            CreateMipmapSurface,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            CreateMipmapSurface: 0,
+            Trim,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionGraphicsDevice3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX")]
+impl windows_core::imp::ProxyVtable for ICompositionGraphicsDevice4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn CaptureAsync(this: *mut core::ffi::c_void, capturevisual: *mut core::ffi::c_void, size: super::super::Graphics::SizeInt32, pixelformat: super::super::Graphics::DirectX::DirectXPixelFormat, alphamode: super::super::Graphics::DirectX::DirectXAlphaMode, sdrboost: f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGraphicsDevice4", "CaptureAsync", 6usize, |this| ((**(this as *const *const ICompositionGraphicsDevice4_Vtbl)).CaptureAsync)(this, capturevisual, size, pixelformat, alphamode, sdrboost, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics_DirectX")]
+            CaptureAsync,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            CaptureAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionGraphicsDevice4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionLight_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Targets(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionLight", "Targets", 6usize, |this| ((**(this as *const *const ICompositionLight_Vtbl)).Targets)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Targets }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionLight as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionLight2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExclusionsFromTargets(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionLight2", "ExclusionsFromTargets", 6usize, |this| ((**(this as *const *const ICompositionLight2_Vtbl)).ExclusionsFromTargets)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExclusionsFromTargets }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionLight2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionLight3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionLight3", "IsEnabled", 6usize, |this| ((**(this as *const *const ICompositionLight3_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionLight3", "SetIsEnabled", 7usize, |this| ((**(this as *const *const ICompositionLight3_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsEnabled, SetIsEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionLight3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionLightFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionLightFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICompositionLineGeometry_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionLineGeometry", "Start", 6usize, |this| ((**(this as *const *const ICompositionLineGeometry_Vtbl)).Start)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetStart(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionLineGeometry", "SetStart", 7usize, |this| ((**(this as *const *const ICompositionLineGeometry_Vtbl)).SetStart)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionLineGeometry", "End", 8usize, |this| ((**(this as *const *const ICompositionLineGeometry_Vtbl)).End)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetEnd(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionLineGeometry", "SetEnd", 9usize, |this| ((**(this as *const *const ICompositionLineGeometry_Vtbl)).SetEnd)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Start,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Start: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetStart,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetStart: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            End,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            End: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetEnd,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetEnd: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionLineGeometry as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICompositionLinearGradientBrush_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn EndPoint(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionLinearGradientBrush", "EndPoint", 6usize, |this| ((**(this as *const *const ICompositionLinearGradientBrush_Vtbl)).EndPoint)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetEndPoint(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionLinearGradientBrush", "SetEndPoint", 7usize, |this| ((**(this as *const *const ICompositionLinearGradientBrush_Vtbl)).SetEndPoint)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn StartPoint(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionLinearGradientBrush", "StartPoint", 8usize, |this| ((**(this as *const *const ICompositionLinearGradientBrush_Vtbl)).StartPoint)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetStartPoint(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionLinearGradientBrush", "SetStartPoint", 9usize, |this| ((**(this as *const *const ICompositionLinearGradientBrush_Vtbl)).SetStartPoint)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            EndPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            EndPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetEndPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetEndPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            StartPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            StartPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetStartPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetStartPoint: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionLinearGradientBrush as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionMaskBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mask(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionMaskBrush", "Mask", 6usize, |this| ((**(this as *const *const ICompositionMaskBrush_Vtbl)).Mask)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMask(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionMaskBrush", "SetMask", 7usize, |this| ((**(this as *const *const ICompositionMaskBrush_Vtbl)).SetMask)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionMaskBrush", "Source", 8usize, |this| ((**(this as *const *const ICompositionMaskBrush_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionMaskBrush", "SetSource", 9usize, |this| ((**(this as *const *const ICompositionMaskBrush_Vtbl)).SetSource)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Mask, SetMask, Source, SetSource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionMaskBrush as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX")]
+impl windows_core::imp::ProxyVtable for ICompositionMipmapSurface_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LevelCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionMipmapSurface", "LevelCount", 6usize, |this| ((**(this as *const *const ICompositionMipmapSurface_Vtbl)).LevelCount)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn AlphaMode(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::DirectX::DirectXAlphaMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionMipmapSurface", "AlphaMode", 7usize, |this| ((**(this as *const *const ICompositionMipmapSurface_Vtbl)).AlphaMode)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn PixelFormat(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::DirectX::DirectXPixelFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionMipmapSurface", "PixelFormat", 8usize, |this| ((**(this as *const *const ICompositionMipmapSurface_Vtbl)).PixelFormat)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn SizeInt32(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::SizeInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionMipmapSurface", "SizeInt32", 9usize, |this| ((**(this as *const *const ICompositionMipmapSurface_Vtbl)).SizeInt32)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDrawingSurfaceForLevel(this: *mut core::ffi::c_void, level: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionMipmapSurface", "GetDrawingSurfaceForLevel", 10usize, |this| ((**(this as *const *const ICompositionMipmapSurface_Vtbl)).GetDrawingSurfaceForLevel)(this, level, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LevelCount,
+            #[cfg(feature = "Graphics_DirectX")]
+            AlphaMode,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            AlphaMode: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            PixelFormat,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            PixelFormat: 0,
+            #[cfg(feature = "Graphics")]
+            SizeInt32,
+            #[cfg(not(feature = "Graphics"))]
+            SizeInt32: 0,
+            GetDrawingSurfaceForLevel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionMipmapSurface as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionNineGridBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BottomInset(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "BottomInset", 6usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).BottomInset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottomInset(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "SetBottomInset", 7usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).SetBottomInset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BottomInsetScale(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "BottomInsetScale", 8usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).BottomInsetScale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottomInsetScale(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "SetBottomInsetScale", 9usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).SetBottomInsetScale)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCenterHollow(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "IsCenterHollow", 10usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).IsCenterHollow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCenterHollow(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "SetIsCenterHollow", 11usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).SetIsCenterHollow)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeftInset(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "LeftInset", 12usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).LeftInset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLeftInset(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "SetLeftInset", 13usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).SetLeftInset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeftInsetScale(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "LeftInsetScale", 14usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).LeftInsetScale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLeftInsetScale(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "SetLeftInsetScale", 15usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).SetLeftInsetScale)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RightInset(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "RightInset", 16usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).RightInset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRightInset(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "SetRightInset", 17usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).SetRightInset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RightInsetScale(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "RightInsetScale", 18usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).RightInsetScale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRightInsetScale(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "SetRightInsetScale", 19usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).SetRightInsetScale)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "Source", 20usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "SetSource", 21usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).SetSource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TopInset(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "TopInset", 22usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).TopInset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTopInset(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "SetTopInset", 23usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).SetTopInset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TopInsetScale(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "TopInsetScale", 24usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).TopInsetScale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTopInsetScale(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "SetTopInsetScale", 25usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).SetTopInsetScale)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInsets(this: *mut core::ffi::c_void, inset: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "SetInsets", 26usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).SetInsets)(this, inset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInsetsWithValues(this: *mut core::ffi::c_void, left: f32, top: f32, right: f32, bottom: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "SetInsetsWithValues", 27usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).SetInsetsWithValues)(this, left, top, right, bottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInsetScales(this: *mut core::ffi::c_void, scale: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "SetInsetScales", 28usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).SetInsetScales)(this, scale), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInsetScalesWithValues(this: *mut core::ffi::c_void, left: f32, top: f32, right: f32, bottom: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionNineGridBrush", "SetInsetScalesWithValues", 29usize, |this| ((**(this as *const *const ICompositionNineGridBrush_Vtbl)).SetInsetScalesWithValues)(this, left, top, right, bottom), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BottomInset,
+            SetBottomInset,
+            BottomInsetScale,
+            SetBottomInsetScale,
+            IsCenterHollow,
+            SetIsCenterHollow,
+            LeftInset,
+            SetLeftInset,
+            LeftInsetScale,
+            SetLeftInsetScale,
+            RightInset,
+            SetRightInset,
+            RightInsetScale,
+            SetRightInsetScale,
+            Source,
+            SetSource,
+            TopInset,
+            SetTopInset,
+            TopInsetScale,
+            SetTopInsetScale,
+            SetInsets,
+            SetInsetsWithValues,
+            SetInsetScales,
+            SetInsetScalesWithValues,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionNineGridBrush as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Core")]
+impl windows_core::imp::ProxyVtable for ICompositionObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Compositor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObject", "Compositor", 6usize, |this| ((**(this as *const *const ICompositionObject_Vtbl)).Compositor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn Dispatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObject", "Dispatcher", 7usize, |this| ((**(this as *const *const ICompositionObject_Vtbl)).Dispatcher)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObject", "Properties", 8usize, |this| ((**(this as *const *const ICompositionObject_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAnimation(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObject", "StartAnimation", 9usize, |this| ((**(this as *const *const ICompositionObject_Vtbl)).StartAnimation)(this, propertyname, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAnimation(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObject", "StopAnimation", 10usize, |this| ((**(this as *const *const ICompositionObject_Vtbl)).StopAnimation)(this, propertyname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Compositor,
+            #[cfg(feature = "UI_Core")]
+            Dispatcher,
+            #[cfg(not(feature = "UI_Core"))]
+            Dispatcher: 0,
+            Properties,
+            StartAnimation,
+            StopAnimation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionObject as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionObject2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Comment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObject2", "Comment", 6usize, |this| ((**(this as *const *const ICompositionObject2_Vtbl)).Comment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComment(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObject2", "SetComment", 7usize, |this| ((**(this as *const *const ICompositionObject2_Vtbl)).SetComment)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImplicitAnimations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObject2", "ImplicitAnimations", 8usize, |this| ((**(this as *const *const ICompositionObject2_Vtbl)).ImplicitAnimations)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetImplicitAnimations(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObject2", "SetImplicitAnimations", 9usize, |this| ((**(this as *const *const ICompositionObject2_Vtbl)).SetImplicitAnimations)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAnimationGroup(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObject2", "StartAnimationGroup", 10usize, |this| ((**(this as *const *const ICompositionObject2_Vtbl)).StartAnimationGroup)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAnimationGroup(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObject2", "StopAnimationGroup", 11usize, |this| ((**(this as *const *const ICompositionObject2_Vtbl)).StopAnimationGroup)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Comment,
+            SetComment,
+            ImplicitAnimations,
+            SetImplicitAnimations,
+            StartAnimationGroup,
+            StopAnimationGroup,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionObject2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for ICompositionObject3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn DispatcherQueue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObject3", "DispatcherQueue", 6usize, |this| ((**(this as *const *const ICompositionObject3_Vtbl)).DispatcherQueue)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            DispatcherQueue,
+            #[cfg(not(feature = "System"))]
+            DispatcherQueue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionObject3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionObject4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryGetAnimationController(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObject4", "TryGetAnimationController", 6usize, |this| ((**(this as *const *const ICompositionObject4_Vtbl)).TryGetAnimationController)(this, propertyname, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryGetAnimationController }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionObject4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionObject5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartAnimationWithController(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, animation: *mut core::ffi::c_void, animationcontroller: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObject5", "StartAnimationWithController", 6usize, |this| ((**(this as *const *const ICompositionObject5_Vtbl)).StartAnimationWithController)(this, propertyname, animation, animationcontroller), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartAnimationWithController }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionObject5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionObjectFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionObjectFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionObjectStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartAnimationWithIAnimationObject(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObjectStatics", "StartAnimationWithIAnimationObject", 6usize, |this| ((**(this as *const *const ICompositionObjectStatics_Vtbl)).StartAnimationWithIAnimationObject)(this, target, propertyname, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAnimationGroupWithIAnimationObject(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionObjectStatics", "StartAnimationGroupWithIAnimationObject", 7usize, |this| ((**(this as *const *const ICompositionObjectStatics_Vtbl)).StartAnimationGroupWithIAnimationObject)(this, target, animation), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartAnimationWithIAnimationObject,
+            StartAnimationGroupWithIAnimationObject,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionObjectStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionPath_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionPath as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics")]
+impl windows_core::imp::ProxyVtable for ICompositionPathFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPathFactory", "Create", 6usize, |this| ((**(this as *const *const ICompositionPathFactory_Vtbl)).Create)(this, source, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics")]
+            Create,
+            #[cfg(not(feature = "Graphics"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionPathFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionPathGeometry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPathGeometry", "Path", 6usize, |this| ((**(this as *const *const ICompositionPathGeometry_Vtbl)).Path)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPath(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPathGeometry", "SetPath", 7usize, |this| ((**(this as *const *const ICompositionPathGeometry_Vtbl)).SetPath)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Path, SetPath }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionPathGeometry as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionProjectedShadow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BlurRadiusMultiplier(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadow", "BlurRadiusMultiplier", 6usize, |this| ((**(this as *const *const ICompositionProjectedShadow_Vtbl)).BlurRadiusMultiplier)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlurRadiusMultiplier(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadow", "SetBlurRadiusMultiplier", 7usize, |this| ((**(this as *const *const ICompositionProjectedShadow_Vtbl)).SetBlurRadiusMultiplier)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Casters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadow", "Casters", 8usize, |this| ((**(this as *const *const ICompositionProjectedShadow_Vtbl)).Casters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LightSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadow", "LightSource", 9usize, |this| ((**(this as *const *const ICompositionProjectedShadow_Vtbl)).LightSource)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLightSource(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadow", "SetLightSource", 10usize, |this| ((**(this as *const *const ICompositionProjectedShadow_Vtbl)).SetLightSource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBlurRadius(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadow", "MaxBlurRadius", 11usize, |this| ((**(this as *const *const ICompositionProjectedShadow_Vtbl)).MaxBlurRadius)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxBlurRadius(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadow", "SetMaxBlurRadius", 12usize, |this| ((**(this as *const *const ICompositionProjectedShadow_Vtbl)).SetMaxBlurRadius)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinBlurRadius(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadow", "MinBlurRadius", 13usize, |this| ((**(this as *const *const ICompositionProjectedShadow_Vtbl)).MinBlurRadius)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinBlurRadius(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadow", "SetMinBlurRadius", 14usize, |this| ((**(this as *const *const ICompositionProjectedShadow_Vtbl)).SetMinBlurRadius)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Receivers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadow", "Receivers", 15usize, |this| ((**(this as *const *const ICompositionProjectedShadow_Vtbl)).Receivers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BlurRadiusMultiplier,
+            SetBlurRadiusMultiplier,
+            Casters,
+            LightSource,
+            SetLightSource,
+            MaxBlurRadius,
+            SetMaxBlurRadius,
+            MinBlurRadius,
+            SetMinBlurRadius,
+            Receivers,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionProjectedShadow as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionProjectedShadowCaster_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Brush(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowCaster", "Brush", 6usize, |this| ((**(this as *const *const ICompositionProjectedShadowCaster_Vtbl)).Brush)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBrush(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowCaster", "SetBrush", 7usize, |this| ((**(this as *const *const ICompositionProjectedShadowCaster_Vtbl)).SetBrush)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CastingVisual(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowCaster", "CastingVisual", 8usize, |this| ((**(this as *const *const ICompositionProjectedShadowCaster_Vtbl)).CastingVisual)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCastingVisual(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowCaster", "SetCastingVisual", 9usize, |this| ((**(this as *const *const ICompositionProjectedShadowCaster_Vtbl)).SetCastingVisual)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Brush, SetBrush, CastingVisual, SetCastingVisual }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionProjectedShadowCaster as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionProjectedShadowCasterCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowCasterCollection", "Count", 6usize, |this| ((**(this as *const *const ICompositionProjectedShadowCasterCollection_Vtbl)).Count)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAbove(this: *mut core::ffi::c_void, newcaster: *mut core::ffi::c_void, reference: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowCasterCollection", "InsertAbove", 7usize, |this| ((**(this as *const *const ICompositionProjectedShadowCasterCollection_Vtbl)).InsertAbove)(this, newcaster, reference), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAtBottom(this: *mut core::ffi::c_void, newcaster: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowCasterCollection", "InsertAtBottom", 8usize, |this| ((**(this as *const *const ICompositionProjectedShadowCasterCollection_Vtbl)).InsertAtBottom)(this, newcaster), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAtTop(this: *mut core::ffi::c_void, newcaster: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowCasterCollection", "InsertAtTop", 9usize, |this| ((**(this as *const *const ICompositionProjectedShadowCasterCollection_Vtbl)).InsertAtTop)(this, newcaster), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertBelow(this: *mut core::ffi::c_void, newcaster: *mut core::ffi::c_void, reference: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowCasterCollection", "InsertBelow", 10usize, |this| ((**(this as *const *const ICompositionProjectedShadowCasterCollection_Vtbl)).InsertBelow)(this, newcaster, reference), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, caster: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowCasterCollection", "Remove", 11usize, |this| ((**(this as *const *const ICompositionProjectedShadowCasterCollection_Vtbl)).Remove)(this, caster), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowCasterCollection", "RemoveAll", 12usize, |this| ((**(this as *const *const ICompositionProjectedShadowCasterCollection_Vtbl)).RemoveAll)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            InsertAbove,
+            InsertAtBottom,
+            InsertAtTop,
+            InsertBelow,
+            Remove,
+            RemoveAll,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionProjectedShadowCasterCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionProjectedShadowCasterCollectionStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxRespectedCasters(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowCasterCollectionStatics", "MaxRespectedCasters", 6usize, |this| ((**(this as *const *const ICompositionProjectedShadowCasterCollectionStatics_Vtbl)).MaxRespectedCasters)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MaxRespectedCasters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionProjectedShadowCasterCollectionStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionProjectedShadowReceiver_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReceivingVisual(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowReceiver", "ReceivingVisual", 6usize, |this| ((**(this as *const *const ICompositionProjectedShadowReceiver_Vtbl)).ReceivingVisual)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReceivingVisual(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowReceiver", "SetReceivingVisual", 7usize, |this| ((**(this as *const *const ICompositionProjectedShadowReceiver_Vtbl)).SetReceivingVisual)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReceivingVisual, SetReceivingVisual }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionProjectedShadowReceiver as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionProjectedShadowReceiverUnorderedCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowReceiverUnorderedCollection", "Add", 6usize, |this| ((**(this as *const *const ICompositionProjectedShadowReceiverUnorderedCollection_Vtbl)).Add)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowReceiverUnorderedCollection", "Count", 7usize, |this| ((**(this as *const *const ICompositionProjectedShadowReceiverUnorderedCollection_Vtbl)).Count)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowReceiverUnorderedCollection", "Remove", 8usize, |this| ((**(this as *const *const ICompositionProjectedShadowReceiverUnorderedCollection_Vtbl)).Remove)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionProjectedShadowReceiverUnorderedCollection", "RemoveAll", 9usize, |this| ((**(this as *const *const ICompositionProjectedShadowReceiverUnorderedCollection_Vtbl)).RemoveAll)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Add, Count, Remove, RemoveAll }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionProjectedShadowReceiverUnorderedCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICompositionPropertySet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InsertColor(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "InsertColor", 6usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).InsertColor)(this, propertyname, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InsertMatrix3x2(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "InsertMatrix3x2", 7usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).InsertMatrix3x2)(this, propertyname, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InsertMatrix4x4(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::super::Foundation::Numerics::Matrix4x4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "InsertMatrix4x4", 8usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).InsertMatrix4x4)(this, propertyname, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InsertQuaternion(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "InsertQuaternion", 9usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).InsertQuaternion)(this, propertyname, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertScalar(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "InsertScalar", 10usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).InsertScalar)(this, propertyname, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InsertVector2(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "InsertVector2", 11usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).InsertVector2)(this, propertyname, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InsertVector3(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "InsertVector3", 12usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).InsertVector3)(this, propertyname, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InsertVector4(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: super::super::Foundation::Numerics::Vector4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "InsertVector4", 13usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).InsertVector4)(this, propertyname, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetColor(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: *mut super::Color, result__: *mut CompositionGetValueStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "TryGetColor", 14usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).TryGetColor)(this, propertyname, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryGetMatrix3x2(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: *mut super::super::Foundation::Numerics::Matrix3x2, result__: *mut CompositionGetValueStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "TryGetMatrix3x2", 15usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).TryGetMatrix3x2)(this, propertyname, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryGetMatrix4x4(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: *mut super::super::Foundation::Numerics::Matrix4x4, result__: *mut CompositionGetValueStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "TryGetMatrix4x4", 16usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).TryGetMatrix4x4)(this, propertyname, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryGetQuaternion(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: *mut super::super::Foundation::Numerics::Quaternion, result__: *mut CompositionGetValueStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "TryGetQuaternion", 17usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).TryGetQuaternion)(this, propertyname, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetScalar(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: *mut f32, result__: *mut CompositionGetValueStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "TryGetScalar", 18usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).TryGetScalar)(this, propertyname, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryGetVector2(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: *mut super::super::Foundation::Numerics::Vector2, result__: *mut CompositionGetValueStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "TryGetVector2", 19usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).TryGetVector2)(this, propertyname, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryGetVector3(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: *mut super::super::Foundation::Numerics::Vector3, result__: *mut CompositionGetValueStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "TryGetVector3", 20usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).TryGetVector3)(this, propertyname, value, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TryGetVector4(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: *mut super::super::Foundation::Numerics::Vector4, result__: *mut CompositionGetValueStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet", "TryGetVector4", 21usize, |this| ((**(this as *const *const ICompositionPropertySet_Vtbl)).TryGetVector4)(this, propertyname, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InsertColor,
+            #[cfg(feature = "Foundation_Numerics")]
+            InsertMatrix3x2,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InsertMatrix3x2: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            InsertMatrix4x4,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InsertMatrix4x4: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            InsertQuaternion,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InsertQuaternion: 0,
+            InsertScalar,
+            #[cfg(feature = "Foundation_Numerics")]
+            InsertVector2,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InsertVector2: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            InsertVector3,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InsertVector3: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            InsertVector4,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InsertVector4: 0,
+            TryGetColor,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryGetMatrix3x2,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryGetMatrix3x2: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryGetMatrix4x4,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryGetMatrix4x4: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryGetQuaternion,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryGetQuaternion: 0,
+            TryGetScalar,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryGetVector2,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryGetVector2: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryGetVector3,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryGetVector3: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TryGetVector4,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TryGetVector4: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionPropertySet as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionPropertySet2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InsertBoolean(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet2", "InsertBoolean", 6usize, |this| ((**(this as *const *const ICompositionPropertySet2_Vtbl)).InsertBoolean)(this, propertyname, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetBoolean(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::HSTRING>, value: *mut bool, result__: *mut CompositionGetValueStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionPropertySet2", "TryGetBoolean", 7usize, |this| ((**(this as *const *const ICompositionPropertySet2_Vtbl)).TryGetBoolean)(this, propertyname, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InsertBoolean, TryGetBoolean }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionPropertySet2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICompositionRadialGradientBrush_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn EllipseCenter(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRadialGradientBrush", "EllipseCenter", 6usize, |this| ((**(this as *const *const ICompositionRadialGradientBrush_Vtbl)).EllipseCenter)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetEllipseCenter(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRadialGradientBrush", "SetEllipseCenter", 7usize, |this| ((**(this as *const *const ICompositionRadialGradientBrush_Vtbl)).SetEllipseCenter)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn EllipseRadius(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRadialGradientBrush", "EllipseRadius", 8usize, |this| ((**(this as *const *const ICompositionRadialGradientBrush_Vtbl)).EllipseRadius)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetEllipseRadius(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRadialGradientBrush", "SetEllipseRadius", 9usize, |this| ((**(this as *const *const ICompositionRadialGradientBrush_Vtbl)).SetEllipseRadius)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn GradientOriginOffset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRadialGradientBrush", "GradientOriginOffset", 10usize, |this| ((**(this as *const *const ICompositionRadialGradientBrush_Vtbl)).GradientOriginOffset)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetGradientOriginOffset(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRadialGradientBrush", "SetGradientOriginOffset", 11usize, |this| ((**(this as *const *const ICompositionRadialGradientBrush_Vtbl)).SetGradientOriginOffset)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            EllipseCenter,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            EllipseCenter: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetEllipseCenter,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetEllipseCenter: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            EllipseRadius,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            EllipseRadius: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetEllipseRadius,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetEllipseRadius: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            GradientOriginOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            GradientOriginOffset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetGradientOriginOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetGradientOriginOffset: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionRadialGradientBrush as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICompositionRectangleGeometry_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRectangleGeometry", "Offset", 6usize, |this| ((**(this as *const *const ICompositionRectangleGeometry_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRectangleGeometry", "SetOffset", 7usize, |this| ((**(this as *const *const ICompositionRectangleGeometry_Vtbl)).SetOffset)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRectangleGeometry", "Size", 8usize, |this| ((**(this as *const *const ICompositionRectangleGeometry_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRectangleGeometry", "SetSize", 9usize, |this| ((**(this as *const *const ICompositionRectangleGeometry_Vtbl)).SetSize)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Offset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Offset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetOffset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Size,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Size: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetSize,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetSize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionRectangleGeometry as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICompositionRoundedRectangleGeometry_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CornerRadius(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRoundedRectangleGeometry", "CornerRadius", 6usize, |this| ((**(this as *const *const ICompositionRoundedRectangleGeometry_Vtbl)).CornerRadius)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetCornerRadius(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRoundedRectangleGeometry", "SetCornerRadius", 7usize, |this| ((**(this as *const *const ICompositionRoundedRectangleGeometry_Vtbl)).SetCornerRadius)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRoundedRectangleGeometry", "Offset", 8usize, |this| ((**(this as *const *const ICompositionRoundedRectangleGeometry_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRoundedRectangleGeometry", "SetOffset", 9usize, |this| ((**(this as *const *const ICompositionRoundedRectangleGeometry_Vtbl)).SetOffset)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRoundedRectangleGeometry", "Size", 10usize, |this| ((**(this as *const *const ICompositionRoundedRectangleGeometry_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionRoundedRectangleGeometry", "SetSize", 11usize, |this| ((**(this as *const *const ICompositionRoundedRectangleGeometry_Vtbl)).SetSize)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            CornerRadius,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CornerRadius: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetCornerRadius,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetCornerRadius: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Offset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Offset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetOffset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Size,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Size: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetSize,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetSize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionRoundedRectangleGeometry as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionScopedBatch_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsActive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionScopedBatch", "IsActive", 6usize, |this| ((**(this as *const *const ICompositionScopedBatch_Vtbl)).IsActive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionScopedBatch", "IsEnded", 7usize, |this| ((**(this as *const *const ICompositionScopedBatch_Vtbl)).IsEnded)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionScopedBatch", "End", 8usize, |this| ((**(this as *const *const ICompositionScopedBatch_Vtbl)).End)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionScopedBatch", "Resume", 9usize, |this| ((**(this as *const *const ICompositionScopedBatch_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Suspend(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionScopedBatch", "Suspend", 10usize, |this| ((**(this as *const *const ICompositionScopedBatch_Vtbl)).Suspend)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Completed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionScopedBatch", "Completed", 11usize, |this| ((**(this as *const *const ICompositionScopedBatch_Vtbl)).Completed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionScopedBatch", "RemoveCompleted", 12usize, |this| ((**(this as *const *const ICompositionScopedBatch_Vtbl)).RemoveCompleted)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsActive,
+            IsEnded,
+            End,
+            Resume,
+            Suspend,
+            Completed,
+            RemoveCompleted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionScopedBatch as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionShadow_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionShadow as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionShadowFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionShadowFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICompositionShape_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CenterPoint(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionShape", "CenterPoint", 6usize, |this| ((**(this as *const *const ICompositionShape_Vtbl)).CenterPoint)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetCenterPoint(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionShape", "SetCenterPoint", 7usize, |this| ((**(this as *const *const ICompositionShape_Vtbl)).SetCenterPoint)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionShape", "Offset", 8usize, |this| ((**(this as *const *const ICompositionShape_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionShape", "SetOffset", 9usize, |this| ((**(this as *const *const ICompositionShape_Vtbl)).SetOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationAngle(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionShape", "RotationAngle", 10usize, |this| ((**(this as *const *const ICompositionShape_Vtbl)).RotationAngle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotationAngle(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionShape", "SetRotationAngle", 11usize, |this| ((**(this as *const *const ICompositionShape_Vtbl)).SetRotationAngle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationAngleInDegrees(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionShape", "RotationAngleInDegrees", 12usize, |this| ((**(this as *const *const ICompositionShape_Vtbl)).RotationAngleInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotationAngleInDegrees(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionShape", "SetRotationAngleInDegrees", 13usize, |this| ((**(this as *const *const ICompositionShape_Vtbl)).SetRotationAngleInDegrees)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Scale(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionShape", "Scale", 14usize, |this| ((**(this as *const *const ICompositionShape_Vtbl)).Scale)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetScale(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionShape", "SetScale", 15usize, |this| ((**(this as *const *const ICompositionShape_Vtbl)).SetScale)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TransformMatrix(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionShape", "TransformMatrix", 16usize, |this| ((**(this as *const *const ICompositionShape_Vtbl)).TransformMatrix)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetTransformMatrix(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionShape", "SetTransformMatrix", 17usize, |this| ((**(this as *const *const ICompositionShape_Vtbl)).SetTransformMatrix)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            CenterPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CenterPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetCenterPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetCenterPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Offset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Offset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetOffset: 0,
+            RotationAngle,
+            SetRotationAngle,
+            RotationAngleInDegrees,
+            SetRotationAngleInDegrees,
+            #[cfg(feature = "Foundation_Numerics")]
+            Scale,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Scale: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetScale,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetScale: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TransformMatrix,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TransformMatrix: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetTransformMatrix,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetTransformMatrix: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionShape as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionShapeFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionShapeFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICompositionSpriteShape_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FillBrush(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "FillBrush", 6usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).FillBrush)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFillBrush(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "SetFillBrush", 7usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).SetFillBrush)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Geometry(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "Geometry", 8usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).Geometry)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGeometry(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "SetGeometry", 9usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).SetGeometry)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStrokeNonScaling(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "IsStrokeNonScaling", 10usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).IsStrokeNonScaling)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsStrokeNonScaling(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "SetIsStrokeNonScaling", 11usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).SetIsStrokeNonScaling)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrokeBrush(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "StrokeBrush", 12usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).StrokeBrush)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeBrush(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "SetStrokeBrush", 13usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).SetStrokeBrush)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StrokeDashArray(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "StrokeDashArray", 14usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).StrokeDashArray)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrokeDashCap(this: *mut core::ffi::c_void, result__: *mut CompositionStrokeCap) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "StrokeDashCap", 15usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).StrokeDashCap)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeDashCap(this: *mut core::ffi::c_void, value: CompositionStrokeCap) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "SetStrokeDashCap", 16usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).SetStrokeDashCap)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrokeDashOffset(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "StrokeDashOffset", 17usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).StrokeDashOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeDashOffset(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "SetStrokeDashOffset", 18usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).SetStrokeDashOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrokeEndCap(this: *mut core::ffi::c_void, result__: *mut CompositionStrokeCap) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "StrokeEndCap", 19usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).StrokeEndCap)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeEndCap(this: *mut core::ffi::c_void, value: CompositionStrokeCap) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "SetStrokeEndCap", 20usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).SetStrokeEndCap)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrokeLineJoin(this: *mut core::ffi::c_void, result__: *mut CompositionStrokeLineJoin) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "StrokeLineJoin", 21usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).StrokeLineJoin)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeLineJoin(this: *mut core::ffi::c_void, value: CompositionStrokeLineJoin) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "SetStrokeLineJoin", 22usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).SetStrokeLineJoin)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrokeMiterLimit(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "StrokeMiterLimit", 23usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).StrokeMiterLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeMiterLimit(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "SetStrokeMiterLimit", 24usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).SetStrokeMiterLimit)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrokeStartCap(this: *mut core::ffi::c_void, result__: *mut CompositionStrokeCap) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "StrokeStartCap", 25usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).StrokeStartCap)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeStartCap(this: *mut core::ffi::c_void, value: CompositionStrokeCap) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "SetStrokeStartCap", 26usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).SetStrokeStartCap)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrokeThickness(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "StrokeThickness", 27usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).StrokeThickness)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeThickness(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSpriteShape", "SetStrokeThickness", 28usize, |this| ((**(this as *const *const ICompositionSpriteShape_Vtbl)).SetStrokeThickness)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FillBrush,
+            SetFillBrush,
+            Geometry,
+            SetGeometry,
+            IsStrokeNonScaling,
+            SetIsStrokeNonScaling,
+            StrokeBrush,
+            SetStrokeBrush,
+            #[cfg(feature = "Foundation_Collections")]
+            StrokeDashArray,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StrokeDashArray: 0,
+            StrokeDashCap,
+            SetStrokeDashCap,
+            StrokeDashOffset,
+            SetStrokeDashOffset,
+            StrokeEndCap,
+            SetStrokeEndCap,
+            StrokeLineJoin,
+            SetStrokeLineJoin,
+            StrokeMiterLimit,
+            SetStrokeMiterLimit,
+            StrokeStartCap,
+            SetStrokeStartCap,
+            StrokeThickness,
+            SetStrokeThickness,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionSpriteShape as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionSupportsSystemBackdrop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SystemBackdrop(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSupportsSystemBackdrop", "SystemBackdrop", 6usize, |this| ((**(this as *const *const ICompositionSupportsSystemBackdrop_Vtbl)).SystemBackdrop)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSystemBackdrop(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSupportsSystemBackdrop", "SetSystemBackdrop", 7usize, |this| ((**(this as *const *const ICompositionSupportsSystemBackdrop_Vtbl)).SetSystemBackdrop)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SystemBackdrop, SetSystemBackdrop }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionSupportsSystemBackdrop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionSurface_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionSurface as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionSurfaceBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BitmapInterpolationMode(this: *mut core::ffi::c_void, result__: *mut CompositionBitmapInterpolationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush", "BitmapInterpolationMode", 6usize, |this| ((**(this as *const *const ICompositionSurfaceBrush_Vtbl)).BitmapInterpolationMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBitmapInterpolationMode(this: *mut core::ffi::c_void, value: CompositionBitmapInterpolationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush", "SetBitmapInterpolationMode", 7usize, |this| ((**(this as *const *const ICompositionSurfaceBrush_Vtbl)).SetBitmapInterpolationMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HorizontalAlignmentRatio(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush", "HorizontalAlignmentRatio", 8usize, |this| ((**(this as *const *const ICompositionSurfaceBrush_Vtbl)).HorizontalAlignmentRatio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHorizontalAlignmentRatio(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush", "SetHorizontalAlignmentRatio", 9usize, |this| ((**(this as *const *const ICompositionSurfaceBrush_Vtbl)).SetHorizontalAlignmentRatio)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stretch(this: *mut core::ffi::c_void, result__: *mut CompositionStretch) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush", "Stretch", 10usize, |this| ((**(this as *const *const ICompositionSurfaceBrush_Vtbl)).Stretch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStretch(this: *mut core::ffi::c_void, value: CompositionStretch) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush", "SetStretch", 11usize, |this| ((**(this as *const *const ICompositionSurfaceBrush_Vtbl)).SetStretch)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Surface(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush", "Surface", 12usize, |this| ((**(this as *const *const ICompositionSurfaceBrush_Vtbl)).Surface)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSurface(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush", "SetSurface", 13usize, |this| ((**(this as *const *const ICompositionSurfaceBrush_Vtbl)).SetSurface)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerticalAlignmentRatio(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush", "VerticalAlignmentRatio", 14usize, |this| ((**(this as *const *const ICompositionSurfaceBrush_Vtbl)).VerticalAlignmentRatio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVerticalAlignmentRatio(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush", "SetVerticalAlignmentRatio", 15usize, |this| ((**(this as *const *const ICompositionSurfaceBrush_Vtbl)).SetVerticalAlignmentRatio)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BitmapInterpolationMode,
+            SetBitmapInterpolationMode,
+            HorizontalAlignmentRatio,
+            SetHorizontalAlignmentRatio,
+            Stretch,
+            SetStretch,
+            Surface,
+            SetSurface,
+            VerticalAlignmentRatio,
+            SetVerticalAlignmentRatio,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionSurfaceBrush as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICompositionSurfaceBrush2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn AnchorPoint(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush2", "AnchorPoint", 6usize, |this| ((**(this as *const *const ICompositionSurfaceBrush2_Vtbl)).AnchorPoint)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetAnchorPoint(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush2", "SetAnchorPoint", 7usize, |this| ((**(this as *const *const ICompositionSurfaceBrush2_Vtbl)).SetAnchorPoint)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CenterPoint(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush2", "CenterPoint", 8usize, |this| ((**(this as *const *const ICompositionSurfaceBrush2_Vtbl)).CenterPoint)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetCenterPoint(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush2", "SetCenterPoint", 9usize, |this| ((**(this as *const *const ICompositionSurfaceBrush2_Vtbl)).SetCenterPoint)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush2", "Offset", 10usize, |this| ((**(this as *const *const ICompositionSurfaceBrush2_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush2", "SetOffset", 11usize, |this| ((**(this as *const *const ICompositionSurfaceBrush2_Vtbl)).SetOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationAngle(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush2", "RotationAngle", 12usize, |this| ((**(this as *const *const ICompositionSurfaceBrush2_Vtbl)).RotationAngle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotationAngle(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush2", "SetRotationAngle", 13usize, |this| ((**(this as *const *const ICompositionSurfaceBrush2_Vtbl)).SetRotationAngle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationAngleInDegrees(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush2", "RotationAngleInDegrees", 14usize, |this| ((**(this as *const *const ICompositionSurfaceBrush2_Vtbl)).RotationAngleInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotationAngleInDegrees(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush2", "SetRotationAngleInDegrees", 15usize, |this| ((**(this as *const *const ICompositionSurfaceBrush2_Vtbl)).SetRotationAngleInDegrees)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Scale(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush2", "Scale", 16usize, |this| ((**(this as *const *const ICompositionSurfaceBrush2_Vtbl)).Scale)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetScale(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush2", "SetScale", 17usize, |this| ((**(this as *const *const ICompositionSurfaceBrush2_Vtbl)).SetScale)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TransformMatrix(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush2", "TransformMatrix", 18usize, |this| ((**(this as *const *const ICompositionSurfaceBrush2_Vtbl)).TransformMatrix)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetTransformMatrix(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush2", "SetTransformMatrix", 19usize, |this| ((**(this as *const *const ICompositionSurfaceBrush2_Vtbl)).SetTransformMatrix)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            AnchorPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            AnchorPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetAnchorPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetAnchorPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            CenterPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CenterPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetCenterPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetCenterPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Offset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Offset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetOffset: 0,
+            RotationAngle,
+            SetRotationAngle,
+            RotationAngleInDegrees,
+            SetRotationAngleInDegrees,
+            #[cfg(feature = "Foundation_Numerics")]
+            Scale,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Scale: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetScale,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetScale: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TransformMatrix,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TransformMatrix: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetTransformMatrix,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetTransformMatrix: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionSurfaceBrush2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionSurfaceBrush3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SnapToPixels(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush3", "SnapToPixels", 6usize, |this| ((**(this as *const *const ICompositionSurfaceBrush3_Vtbl)).SnapToPixels)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSnapToPixels(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceBrush3", "SetSnapToPixels", 7usize, |this| ((**(this as *const *const ICompositionSurfaceBrush3_Vtbl)).SetSnapToPixels)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SnapToPixels, SetSnapToPixels }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionSurfaceBrush3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionSurfaceFacade_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRealSurface(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionSurfaceFacade", "GetRealSurface", 6usize, |this| ((**(this as *const *const ICompositionSurfaceFacade_Vtbl)).GetRealSurface)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRealSurface }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionSurfaceFacade as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Root(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionTarget", "Root", 6usize, |this| ((**(this as *const *const ICompositionTarget_Vtbl)).Root)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoot(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionTarget", "SetRoot", 7usize, |this| ((**(this as *const *const ICompositionTarget_Vtbl)).SetRoot)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Root, SetRoot }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionTarget as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionTargetFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionTargetFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_DirectX")]
+impl windows_core::imp::ProxyVtable for ICompositionTexture_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn SourceRect(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::RectInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionTexture", "SourceRect", 6usize, |this| ((**(this as *const *const ICompositionTexture_Vtbl)).SourceRect)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn SetSourceRect(this: *mut core::ffi::c_void, value: super::super::Graphics::RectInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionTexture", "SetSourceRect", 7usize, |this| ((**(this as *const *const ICompositionTexture_Vtbl)).SetSourceRect)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn AlphaMode(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::DirectX::DirectXAlphaMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionTexture", "AlphaMode", 8usize, |this| ((**(this as *const *const ICompositionTexture_Vtbl)).AlphaMode)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn SetAlphaMode(this: *mut core::ffi::c_void, value: super::super::Graphics::DirectX::DirectXAlphaMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionTexture", "SetAlphaMode", 9usize, |this| ((**(this as *const *const ICompositionTexture_Vtbl)).SetAlphaMode)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn ColorSpace(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::DirectX::DirectXColorSpace) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionTexture", "ColorSpace", 10usize, |this| ((**(this as *const *const ICompositionTexture_Vtbl)).ColorSpace)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_DirectX")]
+        unsafe extern "system" fn SetColorSpace(this: *mut core::ffi::c_void, value: super::super::Graphics::DirectX::DirectXColorSpace) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionTexture", "SetColorSpace", 11usize, |this| ((**(this as *const *const ICompositionTexture_Vtbl)).SetColorSpace)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics")]
+            SourceRect,
+            #[cfg(not(feature = "Graphics"))]
+            SourceRect: 0,
+            #[cfg(feature = "Graphics")]
+            SetSourceRect,
+            #[cfg(not(feature = "Graphics"))]
+            SetSourceRect: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            AlphaMode,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            AlphaMode: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            SetAlphaMode,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            SetAlphaMode: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            ColorSpace,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            ColorSpace: 0,
+            #[cfg(feature = "Graphics_DirectX")]
+            SetColorSpace,
+            #[cfg(not(feature = "Graphics_DirectX"))]
+            SetColorSpace: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionTexture as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionTextureFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionTextureFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionTransform_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionTransform as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionTransformFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionTransformFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICompositionViewBox_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HorizontalAlignmentRatio(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionViewBox", "HorizontalAlignmentRatio", 6usize, |this| ((**(this as *const *const ICompositionViewBox_Vtbl)).HorizontalAlignmentRatio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHorizontalAlignmentRatio(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionViewBox", "SetHorizontalAlignmentRatio", 7usize, |this| ((**(this as *const *const ICompositionViewBox_Vtbl)).SetHorizontalAlignmentRatio)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionViewBox", "Offset", 8usize, |this| ((**(this as *const *const ICompositionViewBox_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionViewBox", "SetOffset", 9usize, |this| ((**(this as *const *const ICompositionViewBox_Vtbl)).SetOffset)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionViewBox", "Size", 10usize, |this| ((**(this as *const *const ICompositionViewBox_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionViewBox", "SetSize", 11usize, |this| ((**(this as *const *const ICompositionViewBox_Vtbl)).SetSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stretch(this: *mut core::ffi::c_void, result__: *mut CompositionStretch) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionViewBox", "Stretch", 12usize, |this| ((**(this as *const *const ICompositionViewBox_Vtbl)).Stretch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStretch(this: *mut core::ffi::c_void, value: CompositionStretch) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionViewBox", "SetStretch", 13usize, |this| ((**(this as *const *const ICompositionViewBox_Vtbl)).SetStretch)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerticalAlignmentRatio(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionViewBox", "VerticalAlignmentRatio", 14usize, |this| ((**(this as *const *const ICompositionViewBox_Vtbl)).VerticalAlignmentRatio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVerticalAlignmentRatio(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionViewBox", "SetVerticalAlignmentRatio", 15usize, |this| ((**(this as *const *const ICompositionViewBox_Vtbl)).SetVerticalAlignmentRatio)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HorizontalAlignmentRatio,
+            SetHorizontalAlignmentRatio,
+            #[cfg(feature = "Foundation_Numerics")]
+            Offset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Offset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetOffset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Size,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Size: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetSize,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetSize: 0,
+            Stretch,
+            SetStretch,
+            VerticalAlignmentRatio,
+            SetVerticalAlignmentRatio,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionViewBox as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics")]
+impl windows_core::imp::ProxyVtable for ICompositionVirtualDrawingSurface_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Graphics")]
+        unsafe extern "system" fn Trim(this: *mut core::ffi::c_void, rects_array_size: u32, rects: *const super::super::Graphics::RectInt32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionVirtualDrawingSurface", "Trim", 6usize, |this| ((**(this as *const *const ICompositionVirtualDrawingSurface_Vtbl)).Trim)(this, rects_array_size, rects), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Graphics")]
+            Trim,
+            #[cfg(not(feature = "Graphics"))]
+            Trim: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionVirtualDrawingSurface as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionVirtualDrawingSurfaceFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionVirtualDrawingSurfaceFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICompositionVisualSurface_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SourceVisual(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionVisualSurface", "SourceVisual", 6usize, |this| ((**(this as *const *const ICompositionVisualSurface_Vtbl)).SourceVisual)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceVisual(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionVisualSurface", "SetSourceVisual", 7usize, |this| ((**(this as *const *const ICompositionVisualSurface_Vtbl)).SetSourceVisual)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SourceOffset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionVisualSurface", "SourceOffset", 8usize, |this| ((**(this as *const *const ICompositionVisualSurface_Vtbl)).SourceOffset)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetSourceOffset(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionVisualSurface", "SetSourceOffset", 9usize, |this| ((**(this as *const *const ICompositionVisualSurface_Vtbl)).SetSourceOffset)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SourceSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionVisualSurface", "SourceSize", 10usize, |this| ((**(this as *const *const ICompositionVisualSurface_Vtbl)).SourceSize)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetSourceSize(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionVisualSurface", "SetSourceSize", 11usize, |this| ((**(this as *const *const ICompositionVisualSurface_Vtbl)).SetSourceSize)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SourceVisual,
+            SetSourceVisual,
+            #[cfg(feature = "Foundation_Numerics")]
+            SourceOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SourceOffset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetSourceOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetSourceOffset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SourceSize,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SourceSize: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetSourceSize,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetSourceSize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionVisualSurface as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Foundation_Numerics", feature = "Graphics_Effects"))]
+impl windows_core::imp::ProxyVtable for ICompositor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateColorKeyFrameAnimation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateColorKeyFrameAnimation", 6usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateColorKeyFrameAnimation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateColorBrush(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateColorBrush", 7usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateColorBrush)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateColorBrushWithColor(this: *mut core::ffi::c_void, color: super::Color, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateColorBrushWithColor", 8usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateColorBrushWithColor)(this, color, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateContainerVisual(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateContainerVisual", 9usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateContainerVisual)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CreateCubicBezierEasingFunction(this: *mut core::ffi::c_void, controlpoint1: super::super::Foundation::Numerics::Vector2, controlpoint2: super::super::Foundation::Numerics::Vector2, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateCubicBezierEasingFunction", 10usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateCubicBezierEasingFunction)(this, controlpoint1, controlpoint2, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Effects")]
+        unsafe extern "system" fn CreateEffectFactory(this: *mut core::ffi::c_void, graphicseffect: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateEffectFactory", 11usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateEffectFactory)(this, graphicseffect, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Effects"))]
+        unsafe extern "system" fn CreateEffectFactoryWithProperties(this: *mut core::ffi::c_void, graphicseffect: *mut core::ffi::c_void, animatableproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateEffectFactoryWithProperties", 12usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateEffectFactoryWithProperties)(this, graphicseffect, animatableproperties, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateExpressionAnimation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateExpressionAnimation", 13usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateExpressionAnimation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateExpressionAnimationWithExpression(this: *mut core::ffi::c_void, expression: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateExpressionAnimationWithExpression", 14usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateExpressionAnimationWithExpression)(this, expression, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInsetClip(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateInsetClip", 15usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateInsetClip)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInsetClipWithInsets(this: *mut core::ffi::c_void, leftinset: f32, topinset: f32, rightinset: f32, bottominset: f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateInsetClipWithInsets", 16usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateInsetClipWithInsets)(this, leftinset, topinset, rightinset, bottominset, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLinearEasingFunction(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateLinearEasingFunction", 17usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateLinearEasingFunction)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePropertySet(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreatePropertySet", 18usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreatePropertySet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateQuaternionKeyFrameAnimation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateQuaternionKeyFrameAnimation", 19usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateQuaternionKeyFrameAnimation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateScalarKeyFrameAnimation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateScalarKeyFrameAnimation", 20usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateScalarKeyFrameAnimation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateScopedBatch(this: *mut core::ffi::c_void, batchtype: CompositionBatchTypes, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateScopedBatch", 21usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateScopedBatch)(this, batchtype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSpriteVisual(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateSpriteVisual", 22usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateSpriteVisual)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSurfaceBrush(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateSurfaceBrush", 23usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateSurfaceBrush)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSurfaceBrushWithSurface(this: *mut core::ffi::c_void, surface: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateSurfaceBrushWithSurface", 24usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateSurfaceBrushWithSurface)(this, surface, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTargetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateTargetForCurrentView", 25usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateTargetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVector2KeyFrameAnimation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateVector2KeyFrameAnimation", 26usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateVector2KeyFrameAnimation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVector3KeyFrameAnimation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateVector3KeyFrameAnimation", 27usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateVector3KeyFrameAnimation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVector4KeyFrameAnimation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "CreateVector4KeyFrameAnimation", 28usize, |this| ((**(this as *const *const ICompositor_Vtbl)).CreateVector4KeyFrameAnimation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCommitBatch(this: *mut core::ffi::c_void, batchtype: CompositionBatchTypes, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor", "GetCommitBatch", 29usize, |this| ((**(this as *const *const ICompositor_Vtbl)).GetCommitBatch)(this, batchtype, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateColorKeyFrameAnimation,
+            CreateColorBrush,
+            CreateColorBrushWithColor,
+            CreateContainerVisual,
+            #[cfg(feature = "Foundation_Numerics")]
+            CreateCubicBezierEasingFunction,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CreateCubicBezierEasingFunction: 0,
+            #[cfg(feature = "Graphics_Effects")]
+            CreateEffectFactory,
+            #[cfg(not(feature = "Graphics_Effects"))]
+            CreateEffectFactory: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Graphics_Effects"))]
+            CreateEffectFactoryWithProperties,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Graphics_Effects")))]
+            CreateEffectFactoryWithProperties: 0,
+            CreateExpressionAnimation,
+            CreateExpressionAnimationWithExpression,
+            CreateInsetClip,
+            CreateInsetClipWithInsets,
+            CreateLinearEasingFunction,
+            CreatePropertySet,
+            CreateQuaternionKeyFrameAnimation,
+            CreateScalarKeyFrameAnimation,
+            CreateScopedBatch,
+            CreateSpriteVisual,
+            CreateSurfaceBrush,
+            CreateSurfaceBrushWithSurface,
+            CreateTargetForCurrentView,
+            CreateVector2KeyFrameAnimation,
+            CreateVector3KeyFrameAnimation,
+            CreateVector4KeyFrameAnimation,
+            GetCommitBatch,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAmbientLight(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor2", "CreateAmbientLight", 6usize, |this| ((**(this as *const *const ICompositor2_Vtbl)).CreateAmbientLight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAnimationGroup(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor2", "CreateAnimationGroup", 7usize, |this| ((**(this as *const *const ICompositor2_Vtbl)).CreateAnimationGroup)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBackdropBrush(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor2", "CreateBackdropBrush", 8usize, |this| ((**(this as *const *const ICompositor2_Vtbl)).CreateBackdropBrush)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDistantLight(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor2", "CreateDistantLight", 9usize, |this| ((**(this as *const *const ICompositor2_Vtbl)).CreateDistantLight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDropShadow(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor2", "CreateDropShadow", 10usize, |this| ((**(this as *const *const ICompositor2_Vtbl)).CreateDropShadow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateImplicitAnimationCollection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor2", "CreateImplicitAnimationCollection", 11usize, |this| ((**(this as *const *const ICompositor2_Vtbl)).CreateImplicitAnimationCollection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLayerVisual(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor2", "CreateLayerVisual", 12usize, |this| ((**(this as *const *const ICompositor2_Vtbl)).CreateLayerVisual)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMaskBrush(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor2", "CreateMaskBrush", 13usize, |this| ((**(this as *const *const ICompositor2_Vtbl)).CreateMaskBrush)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNineGridBrush(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor2", "CreateNineGridBrush", 14usize, |this| ((**(this as *const *const ICompositor2_Vtbl)).CreateNineGridBrush)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePointLight(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor2", "CreatePointLight", 15usize, |this| ((**(this as *const *const ICompositor2_Vtbl)).CreatePointLight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSpotLight(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor2", "CreateSpotLight", 16usize, |this| ((**(this as *const *const ICompositor2_Vtbl)).CreateSpotLight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStepEasingFunction(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor2", "CreateStepEasingFunction", 17usize, |this| ((**(this as *const *const ICompositor2_Vtbl)).CreateStepEasingFunction)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStepEasingFunctionWithStepCount(this: *mut core::ffi::c_void, stepcount: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor2", "CreateStepEasingFunctionWithStepCount", 18usize, |this| ((**(this as *const *const ICompositor2_Vtbl)).CreateStepEasingFunctionWithStepCount)(this, stepcount, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateAmbientLight,
+            CreateAnimationGroup,
+            CreateBackdropBrush,
+            CreateDistantLight,
+            CreateDropShadow,
+            CreateImplicitAnimationCollection,
+            CreateLayerVisual,
+            CreateMaskBrush,
+            CreateNineGridBrush,
+            CreatePointLight,
+            CreateSpotLight,
+            CreateStepEasingFunction,
+            CreateStepEasingFunctionWithStepCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositor2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositor3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateHostBackdropBrush(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor3", "CreateHostBackdropBrush", 6usize, |this| ((**(this as *const *const ICompositor3_Vtbl)).CreateHostBackdropBrush)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateHostBackdropBrush }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositor3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositor4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateColorGradientStop(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor4", "CreateColorGradientStop", 6usize, |this| ((**(this as *const *const ICompositor4_Vtbl)).CreateColorGradientStop)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateColorGradientStopWithOffsetAndColor(this: *mut core::ffi::c_void, offset: f32, color: super::Color, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor4", "CreateColorGradientStopWithOffsetAndColor", 7usize, |this| ((**(this as *const *const ICompositor4_Vtbl)).CreateColorGradientStopWithOffsetAndColor)(this, offset, color, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLinearGradientBrush(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor4", "CreateLinearGradientBrush", 8usize, |this| ((**(this as *const *const ICompositor4_Vtbl)).CreateLinearGradientBrush)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSpringScalarAnimation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor4", "CreateSpringScalarAnimation", 9usize, |this| ((**(this as *const *const ICompositor4_Vtbl)).CreateSpringScalarAnimation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSpringVector2Animation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor4", "CreateSpringVector2Animation", 10usize, |this| ((**(this as *const *const ICompositor4_Vtbl)).CreateSpringVector2Animation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSpringVector3Animation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor4", "CreateSpringVector3Animation", 11usize, |this| ((**(this as *const *const ICompositor4_Vtbl)).CreateSpringVector3Animation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateColorGradientStop,
+            CreateColorGradientStopWithOffsetAndColor,
+            CreateLinearGradientBrush,
+            CreateSpringScalarAnimation,
+            CreateSpringVector2Animation,
+            CreateSpringVector3Animation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositor4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositor5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Comment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "Comment", 6usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).Comment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComment(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "SetComment", 7usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).SetComment)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GlobalPlaybackRate(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "GlobalPlaybackRate", 8usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).GlobalPlaybackRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGlobalPlaybackRate(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "SetGlobalPlaybackRate", 9usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).SetGlobalPlaybackRate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBounceScalarAnimation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "CreateBounceScalarAnimation", 10usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).CreateBounceScalarAnimation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBounceVector2Animation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "CreateBounceVector2Animation", 11usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).CreateBounceVector2Animation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBounceVector3Animation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "CreateBounceVector3Animation", 12usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).CreateBounceVector3Animation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateContainerShape(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "CreateContainerShape", 13usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).CreateContainerShape)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEllipseGeometry(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "CreateEllipseGeometry", 14usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).CreateEllipseGeometry)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLineGeometry(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "CreateLineGeometry", 15usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).CreateLineGeometry)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePathGeometry(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "CreatePathGeometry", 16usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).CreatePathGeometry)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePathGeometryWithPath(this: *mut core::ffi::c_void, path: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "CreatePathGeometryWithPath", 17usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).CreatePathGeometryWithPath)(this, path, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePathKeyFrameAnimation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "CreatePathKeyFrameAnimation", 18usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).CreatePathKeyFrameAnimation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRectangleGeometry(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "CreateRectangleGeometry", 19usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).CreateRectangleGeometry)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRoundedRectangleGeometry(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "CreateRoundedRectangleGeometry", 20usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).CreateRoundedRectangleGeometry)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateShapeVisual(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "CreateShapeVisual", 21usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).CreateShapeVisual)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSpriteShape(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "CreateSpriteShape", 22usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).CreateSpriteShape)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSpriteShapeWithGeometry(this: *mut core::ffi::c_void, geometry: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "CreateSpriteShapeWithGeometry", 23usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).CreateSpriteShapeWithGeometry)(this, geometry, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateViewBox(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "CreateViewBox", 24usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).CreateViewBox)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestCommitAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor5", "RequestCommitAsync", 25usize, |this| ((**(this as *const *const ICompositor5_Vtbl)).RequestCommitAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Comment,
+            SetComment,
+            GlobalPlaybackRate,
+            SetGlobalPlaybackRate,
+            CreateBounceScalarAnimation,
+            CreateBounceVector2Animation,
+            CreateBounceVector3Animation,
+            CreateContainerShape,
+            CreateEllipseGeometry,
+            CreateLineGeometry,
+            CreatePathGeometry,
+            CreatePathGeometryWithPath,
+            CreatePathKeyFrameAnimation,
+            CreateRectangleGeometry,
+            CreateRoundedRectangleGeometry,
+            CreateShapeVisual,
+            CreateSpriteShape,
+            CreateSpriteShapeWithGeometry,
+            CreateViewBox,
+            RequestCommitAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositor5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositor6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateGeometricClip(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor6", "CreateGeometricClip", 6usize, |this| ((**(this as *const *const ICompositor6_Vtbl)).CreateGeometricClip)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGeometricClipWithGeometry(this: *mut core::ffi::c_void, geometry: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor6", "CreateGeometricClipWithGeometry", 7usize, |this| ((**(this as *const *const ICompositor6_Vtbl)).CreateGeometricClipWithGeometry)(this, geometry, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRedirectVisual(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor6", "CreateRedirectVisual", 8usize, |this| ((**(this as *const *const ICompositor6_Vtbl)).CreateRedirectVisual)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRedirectVisualWithSourceVisual(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor6", "CreateRedirectVisualWithSourceVisual", 9usize, |this| ((**(this as *const *const ICompositor6_Vtbl)).CreateRedirectVisualWithSourceVisual)(this, source, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBooleanKeyFrameAnimation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor6", "CreateBooleanKeyFrameAnimation", 10usize, |this| ((**(this as *const *const ICompositor6_Vtbl)).CreateBooleanKeyFrameAnimation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateGeometricClip,
+            CreateGeometricClipWithGeometry,
+            CreateRedirectVisual,
+            CreateRedirectVisualWithSourceVisual,
+            CreateBooleanKeyFrameAnimation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositor6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "System"))]
+impl windows_core::imp::ProxyVtable for ICompositor7_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn DispatcherQueue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor7", "DispatcherQueue", 6usize, |this| ((**(this as *const *const ICompositor7_Vtbl)).DispatcherQueue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAnimationPropertyInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor7", "CreateAnimationPropertyInfo", 7usize, |this| ((**(this as *const *const ICompositor7_Vtbl)).CreateAnimationPropertyInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRectangleClip(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor7", "CreateRectangleClip", 8usize, |this| ((**(this as *const *const ICompositor7_Vtbl)).CreateRectangleClip)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRectangleClipWithSides(this: *mut core::ffi::c_void, left: f32, top: f32, right: f32, bottom: f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor7", "CreateRectangleClipWithSides", 9usize, |this| ((**(this as *const *const ICompositor7_Vtbl)).CreateRectangleClipWithSides)(this, left, top, right, bottom, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CreateRectangleClipWithSidesAndRadius(this: *mut core::ffi::c_void, left: f32, top: f32, right: f32, bottom: f32, topleftradius: super::super::Foundation::Numerics::Vector2, toprightradius: super::super::Foundation::Numerics::Vector2, bottomrightradius: super::super::Foundation::Numerics::Vector2, bottomleftradius: super::super::Foundation::Numerics::Vector2, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor7", "CreateRectangleClipWithSidesAndRadius", 10usize, |this| ((**(this as *const *const ICompositor7_Vtbl)).CreateRectangleClipWithSidesAndRadius)(this, left, top, right, bottom, topleftradius, toprightradius, bottomrightradius, bottomleftradius, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            DispatcherQueue,
+            #[cfg(not(feature = "System"))]
+            DispatcherQueue: 0,
+            CreateAnimationPropertyInfo,
+            CreateRectangleClip,
+            CreateRectangleClipWithSides,
+            #[cfg(feature = "Foundation_Numerics")]
+            CreateRectangleClipWithSidesAndRadius,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CreateRectangleClipWithSidesAndRadius: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositor7 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositor8_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAnimationController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositor8", "CreateAnimationController", 6usize, |this| ((**(this as *const *const ICompositor8_Vtbl)).CreateAnimationController)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateAnimationController }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositor8 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxGlobalPlaybackRate(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorStatics", "MaxGlobalPlaybackRate", 6usize, |this| ((**(this as *const *const ICompositorStatics_Vtbl)).MaxGlobalPlaybackRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinGlobalPlaybackRate(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorStatics", "MinGlobalPlaybackRate", 7usize, |this| ((**(this as *const *const ICompositorStatics_Vtbl)).MinGlobalPlaybackRate)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MaxGlobalPlaybackRate, MinGlobalPlaybackRate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositorWithBlurredWallpaperBackdropBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryCreateBlurredWallpaperBackdropBrush(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorWithBlurredWallpaperBackdropBrush", "TryCreateBlurredWallpaperBackdropBrush", 6usize, |this| ((**(this as *const *const ICompositorWithBlurredWallpaperBackdropBrush_Vtbl)).TryCreateBlurredWallpaperBackdropBrush)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryCreateBlurredWallpaperBackdropBrush }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositorWithBlurredWallpaperBackdropBrush as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositorWithProjectedShadow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateProjectedShadowCaster(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorWithProjectedShadow", "CreateProjectedShadowCaster", 6usize, |this| ((**(this as *const *const ICompositorWithProjectedShadow_Vtbl)).CreateProjectedShadowCaster)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateProjectedShadow(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorWithProjectedShadow", "CreateProjectedShadow", 7usize, |this| ((**(this as *const *const ICompositorWithProjectedShadow_Vtbl)).CreateProjectedShadow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateProjectedShadowReceiver(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorWithProjectedShadow", "CreateProjectedShadowReceiver", 8usize, |this| ((**(this as *const *const ICompositorWithProjectedShadow_Vtbl)).CreateProjectedShadowReceiver)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateProjectedShadowCaster,
+            CreateProjectedShadow,
+            CreateProjectedShadowReceiver,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositorWithProjectedShadow as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositorWithRadialGradient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateRadialGradientBrush(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorWithRadialGradient", "CreateRadialGradientBrush", 6usize, |this| ((**(this as *const *const ICompositorWithRadialGradient_Vtbl)).CreateRadialGradientBrush)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateRadialGradientBrush }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositorWithRadialGradient as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositorWithVisualSurface_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateVisualSurface(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorWithVisualSurface", "CreateVisualSurface", 6usize, |this| ((**(this as *const *const ICompositorWithVisualSurface_Vtbl)).CreateVisualSurface)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateVisualSurface }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositorWithVisualSurface as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContainerVisual_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Children(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContainerVisual", "Children", 6usize, |this| ((**(this as *const *const IContainerVisual_Vtbl)).Children)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Children }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContainerVisual as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContainerVisualFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContainerVisualFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICubicBezierEasingFunction_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn ControlPoint1(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICubicBezierEasingFunction", "ControlPoint1", 6usize, |this| ((**(this as *const *const ICubicBezierEasingFunction_Vtbl)).ControlPoint1)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn ControlPoint2(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICubicBezierEasingFunction", "ControlPoint2", 7usize, |this| ((**(this as *const *const ICubicBezierEasingFunction_Vtbl)).ControlPoint2)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            ControlPoint1,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            ControlPoint1: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            ControlPoint2,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            ControlPoint2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICubicBezierEasingFunction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDelegatedInkTrailVisual_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddTrailPoints(this: *mut core::ffi::c_void, inkPoints_array_size: u32, inkpoints: *const InkTrailPoint, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDelegatedInkTrailVisual", "AddTrailPoints", 6usize, |this| ((**(this as *const *const IDelegatedInkTrailVisual_Vtbl)).AddTrailPoints)(this, inkPoints_array_size, inkpoints, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTrailPointsWithPrediction(this: *mut core::ffi::c_void, inkPoints_array_size: u32, inkpoints: *const InkTrailPoint, predictedInkPoints_array_size: u32, predictedinkpoints: *const InkTrailPoint, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDelegatedInkTrailVisual", "AddTrailPointsWithPrediction", 7usize, |this| ((**(this as *const *const IDelegatedInkTrailVisual_Vtbl)).AddTrailPointsWithPrediction)(this, inkPoints_array_size, inkpoints, predictedInkPoints_array_size, predictedinkpoints, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTrailPoints(this: *mut core::ffi::c_void, generationid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDelegatedInkTrailVisual", "RemoveTrailPoints", 8usize, |this| ((**(this as *const *const IDelegatedInkTrailVisual_Vtbl)).RemoveTrailPoints)(this, generationid), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartNewTrail(this: *mut core::ffi::c_void, color: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDelegatedInkTrailVisual", "StartNewTrail", 9usize, |this| ((**(this as *const *const IDelegatedInkTrailVisual_Vtbl)).StartNewTrail)(this, color), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddTrailPoints,
+            AddTrailPointsWithPrediction,
+            RemoveTrailPoints,
+            StartNewTrail,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDelegatedInkTrailVisual as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDelegatedInkTrailVisualStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDelegatedInkTrailVisualStatics", "Create", 6usize, |this| ((**(this as *const *const IDelegatedInkTrailVisualStatics_Vtbl)).Create)(this, compositor, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateForSwapChain(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void, swapchain: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDelegatedInkTrailVisualStatics", "CreateForSwapChain", 7usize, |this| ((**(this as *const *const IDelegatedInkTrailVisualStatics_Vtbl)).CreateForSwapChain)(this, compositor, swapchain, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateForSwapChain }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDelegatedInkTrailVisualStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IDistantLight_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistantLight", "Color", 6usize, |this| ((**(this as *const *const IDistantLight_Vtbl)).Color)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, value: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistantLight", "SetColor", 7usize, |this| ((**(this as *const *const IDistantLight_Vtbl)).SetColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CoordinateSpace(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistantLight", "CoordinateSpace", 8usize, |this| ((**(this as *const *const IDistantLight_Vtbl)).CoordinateSpace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCoordinateSpace(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistantLight", "SetCoordinateSpace", 9usize, |this| ((**(this as *const *const IDistantLight_Vtbl)).SetCoordinateSpace)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Direction(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistantLight", "Direction", 10usize, |this| ((**(this as *const *const IDistantLight_Vtbl)).Direction)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetDirection(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistantLight", "SetDirection", 11usize, |this| ((**(this as *const *const IDistantLight_Vtbl)).SetDirection)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Color,
+            SetColor,
+            CoordinateSpace,
+            SetCoordinateSpace,
+            #[cfg(feature = "Foundation_Numerics")]
+            Direction,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Direction: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetDirection,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetDirection: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDistantLight as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDistantLight2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Intensity(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistantLight2", "Intensity", 6usize, |this| ((**(this as *const *const IDistantLight2_Vtbl)).Intensity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIntensity(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistantLight2", "SetIntensity", 7usize, |this| ((**(this as *const *const IDistantLight2_Vtbl)).SetIntensity)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Intensity, SetIntensity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDistantLight2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IDropShadow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BlurRadius(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropShadow", "BlurRadius", 6usize, |this| ((**(this as *const *const IDropShadow_Vtbl)).BlurRadius)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlurRadius(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropShadow", "SetBlurRadius", 7usize, |this| ((**(this as *const *const IDropShadow_Vtbl)).SetBlurRadius)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropShadow", "Color", 8usize, |this| ((**(this as *const *const IDropShadow_Vtbl)).Color)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, value: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropShadow", "SetColor", 9usize, |this| ((**(this as *const *const IDropShadow_Vtbl)).SetColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mask(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropShadow", "Mask", 10usize, |this| ((**(this as *const *const IDropShadow_Vtbl)).Mask)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMask(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropShadow", "SetMask", 11usize, |this| ((**(this as *const *const IDropShadow_Vtbl)).SetMask)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropShadow", "Offset", 12usize, |this| ((**(this as *const *const IDropShadow_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropShadow", "SetOffset", 13usize, |this| ((**(this as *const *const IDropShadow_Vtbl)).SetOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Opacity(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropShadow", "Opacity", 14usize, |this| ((**(this as *const *const IDropShadow_Vtbl)).Opacity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpacity(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropShadow", "SetOpacity", 15usize, |this| ((**(this as *const *const IDropShadow_Vtbl)).SetOpacity)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BlurRadius,
+            SetBlurRadius,
+            Color,
+            SetColor,
+            Mask,
+            SetMask,
+            #[cfg(feature = "Foundation_Numerics")]
+            Offset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Offset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetOffset: 0,
+            Opacity,
+            SetOpacity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDropShadow as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDropShadow2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SourcePolicy(this: *mut core::ffi::c_void, result__: *mut CompositionDropShadowSourcePolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropShadow2", "SourcePolicy", 6usize, |this| ((**(this as *const *const IDropShadow2_Vtbl)).SourcePolicy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourcePolicy(this: *mut core::ffi::c_void, value: CompositionDropShadowSourcePolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropShadow2", "SetSourcePolicy", 7usize, |this| ((**(this as *const *const IDropShadow2_Vtbl)).SetSourcePolicy)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SourcePolicy, SetSourcePolicy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDropShadow2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IElasticEasingFunction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut CompositionEasingFunctionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IElasticEasingFunction", "Mode", 6usize, |this| ((**(this as *const *const IElasticEasingFunction_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Oscillations(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IElasticEasingFunction", "Oscillations", 7usize, |this| ((**(this as *const *const IElasticEasingFunction_Vtbl)).Oscillations)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Springiness(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IElasticEasingFunction", "Springiness", 8usize, |this| ((**(this as *const *const IElasticEasingFunction_Vtbl)).Springiness)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Mode, Oscillations, Springiness }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IElasticEasingFunction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IExponentialEasingFunction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut CompositionEasingFunctionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExponentialEasingFunction", "Mode", 6usize, |this| ((**(this as *const *const IExponentialEasingFunction_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Exponent(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExponentialEasingFunction", "Exponent", 7usize, |this| ((**(this as *const *const IExponentialEasingFunction_Vtbl)).Exponent)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Mode, Exponent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExponentialEasingFunction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IExpressionAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Expression(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExpressionAnimation", "Expression", 6usize, |this| ((**(this as *const *const IExpressionAnimation_Vtbl)).Expression)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExpression(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExpressionAnimation", "SetExpression", 7usize, |this| ((**(this as *const *const IExpressionAnimation_Vtbl)).SetExpression)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Expression, SetExpression }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExpressionAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImplicitAnimationCollection_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImplicitAnimationCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInsetClip_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BottomInset(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInsetClip", "BottomInset", 6usize, |this| ((**(this as *const *const IInsetClip_Vtbl)).BottomInset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottomInset(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInsetClip", "SetBottomInset", 7usize, |this| ((**(this as *const *const IInsetClip_Vtbl)).SetBottomInset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeftInset(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInsetClip", "LeftInset", 8usize, |this| ((**(this as *const *const IInsetClip_Vtbl)).LeftInset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLeftInset(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInsetClip", "SetLeftInset", 9usize, |this| ((**(this as *const *const IInsetClip_Vtbl)).SetLeftInset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RightInset(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInsetClip", "RightInset", 10usize, |this| ((**(this as *const *const IInsetClip_Vtbl)).RightInset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRightInset(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInsetClip", "SetRightInset", 11usize, |this| ((**(this as *const *const IInsetClip_Vtbl)).SetRightInset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TopInset(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInsetClip", "TopInset", 12usize, |this| ((**(this as *const *const IInsetClip_Vtbl)).TopInset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTopInset(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInsetClip", "SetTopInset", 13usize, |this| ((**(this as *const *const IInsetClip_Vtbl)).SetTopInset)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BottomInset,
+            SetBottomInset,
+            LeftInset,
+            SetLeftInset,
+            RightInset,
+            SetRightInset,
+            TopInset,
+            SetTopInset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInsetClip as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyFrameAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DelayTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation", "DelayTime", 6usize, |this| ((**(this as *const *const IKeyFrameAnimation_Vtbl)).DelayTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelayTime(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation", "SetDelayTime", 7usize, |this| ((**(this as *const *const IKeyFrameAnimation_Vtbl)).SetDelayTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation", "Duration", 8usize, |this| ((**(this as *const *const IKeyFrameAnimation_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation", "SetDuration", 9usize, |this| ((**(this as *const *const IKeyFrameAnimation_Vtbl)).SetDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IterationBehavior(this: *mut core::ffi::c_void, result__: *mut AnimationIterationBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation", "IterationBehavior", 10usize, |this| ((**(this as *const *const IKeyFrameAnimation_Vtbl)).IterationBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIterationBehavior(this: *mut core::ffi::c_void, value: AnimationIterationBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation", "SetIterationBehavior", 11usize, |this| ((**(this as *const *const IKeyFrameAnimation_Vtbl)).SetIterationBehavior)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IterationCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation", "IterationCount", 12usize, |this| ((**(this as *const *const IKeyFrameAnimation_Vtbl)).IterationCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIterationCount(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation", "SetIterationCount", 13usize, |this| ((**(this as *const *const IKeyFrameAnimation_Vtbl)).SetIterationCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyFrameCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation", "KeyFrameCount", 14usize, |this| ((**(this as *const *const IKeyFrameAnimation_Vtbl)).KeyFrameCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopBehavior(this: *mut core::ffi::c_void, result__: *mut AnimationStopBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation", "StopBehavior", 15usize, |this| ((**(this as *const *const IKeyFrameAnimation_Vtbl)).StopBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStopBehavior(this: *mut core::ffi::c_void, value: AnimationStopBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation", "SetStopBehavior", 16usize, |this| ((**(this as *const *const IKeyFrameAnimation_Vtbl)).SetStopBehavior)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertExpressionKeyFrame(this: *mut core::ffi::c_void, normalizedprogresskey: f32, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation", "InsertExpressionKeyFrame", 17usize, |this| ((**(this as *const *const IKeyFrameAnimation_Vtbl)).InsertExpressionKeyFrame)(this, normalizedprogresskey, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertExpressionKeyFrameWithEasingFunction(this: *mut core::ffi::c_void, normalizedprogresskey: f32, value: core::mem::MaybeUninit<windows_core::HSTRING>, easingfunction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation", "InsertExpressionKeyFrameWithEasingFunction", 18usize, |this| ((**(this as *const *const IKeyFrameAnimation_Vtbl)).InsertExpressionKeyFrameWithEasingFunction)(this, normalizedprogresskey, value, easingfunction), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DelayTime,
+            SetDelayTime,
+            Duration,
+            SetDuration,
+            IterationBehavior,
+            SetIterationBehavior,
+            IterationCount,
+            SetIterationCount,
+            KeyFrameCount,
+            StopBehavior,
+            SetStopBehavior,
+            InsertExpressionKeyFrame,
+            InsertExpressionKeyFrameWithEasingFunction,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyFrameAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyFrameAnimation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Direction(this: *mut core::ffi::c_void, result__: *mut AnimationDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation2", "Direction", 6usize, |this| ((**(this as *const *const IKeyFrameAnimation2_Vtbl)).Direction)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDirection(this: *mut core::ffi::c_void, value: AnimationDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation2", "SetDirection", 7usize, |this| ((**(this as *const *const IKeyFrameAnimation2_Vtbl)).SetDirection)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Direction, SetDirection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyFrameAnimation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyFrameAnimation3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DelayBehavior(this: *mut core::ffi::c_void, result__: *mut AnimationDelayBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation3", "DelayBehavior", 6usize, |this| ((**(this as *const *const IKeyFrameAnimation3_Vtbl)).DelayBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelayBehavior(this: *mut core::ffi::c_void, value: AnimationDelayBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyFrameAnimation3", "SetDelayBehavior", 7usize, |this| ((**(this as *const *const IKeyFrameAnimation3_Vtbl)).SetDelayBehavior)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DelayBehavior, SetDelayBehavior }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyFrameAnimation3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyFrameAnimationFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyFrameAnimationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILayerVisual_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Effect(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayerVisual", "Effect", 6usize, |this| ((**(this as *const *const ILayerVisual_Vtbl)).Effect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEffect(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayerVisual", "SetEffect", 7usize, |this| ((**(this as *const *const ILayerVisual_Vtbl)).SetEffect)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Effect, SetEffect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILayerVisual as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILayerVisual2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Shadow(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayerVisual2", "Shadow", 6usize, |this| ((**(this as *const *const ILayerVisual2_Vtbl)).Shadow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShadow(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayerVisual2", "SetShadow", 7usize, |this| ((**(this as *const *const ILayerVisual2_Vtbl)).SetShadow)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Shadow, SetShadow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILayerVisual2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILinearEasingFunction_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILinearEasingFunction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INaturalMotionAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DelayBehavior(this: *mut core::ffi::c_void, result__: *mut AnimationDelayBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INaturalMotionAnimation", "DelayBehavior", 6usize, |this| ((**(this as *const *const INaturalMotionAnimation_Vtbl)).DelayBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelayBehavior(this: *mut core::ffi::c_void, value: AnimationDelayBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INaturalMotionAnimation", "SetDelayBehavior", 7usize, |this| ((**(this as *const *const INaturalMotionAnimation_Vtbl)).SetDelayBehavior)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DelayTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INaturalMotionAnimation", "DelayTime", 8usize, |this| ((**(this as *const *const INaturalMotionAnimation_Vtbl)).DelayTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelayTime(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INaturalMotionAnimation", "SetDelayTime", 9usize, |this| ((**(this as *const *const INaturalMotionAnimation_Vtbl)).SetDelayTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopBehavior(this: *mut core::ffi::c_void, result__: *mut AnimationStopBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INaturalMotionAnimation", "StopBehavior", 10usize, |this| ((**(this as *const *const INaturalMotionAnimation_Vtbl)).StopBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStopBehavior(this: *mut core::ffi::c_void, value: AnimationStopBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INaturalMotionAnimation", "SetStopBehavior", 11usize, |this| ((**(this as *const *const INaturalMotionAnimation_Vtbl)).SetStopBehavior)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DelayBehavior,
+            SetDelayBehavior,
+            DelayTime,
+            SetDelayTime,
+            StopBehavior,
+            SetStopBehavior,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INaturalMotionAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INaturalMotionAnimationFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INaturalMotionAnimationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPathKeyFrameAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InsertKeyFrame(this: *mut core::ffi::c_void, normalizedprogresskey: f32, path: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathKeyFrameAnimation", "InsertKeyFrame", 6usize, |this| ((**(this as *const *const IPathKeyFrameAnimation_Vtbl)).InsertKeyFrame)(this, normalizedprogresskey, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertKeyFrameWithEasingFunction(this: *mut core::ffi::c_void, normalizedprogresskey: f32, path: *mut core::ffi::c_void, easingfunction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPathKeyFrameAnimation", "InsertKeyFrameWithEasingFunction", 7usize, |this| ((**(this as *const *const IPathKeyFrameAnimation_Vtbl)).InsertKeyFrameWithEasingFunction)(this, normalizedprogresskey, path, easingfunction), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InsertKeyFrame, InsertKeyFrameWithEasingFunction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPathKeyFrameAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IPointLight_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight", "Color", 6usize, |this| ((**(this as *const *const IPointLight_Vtbl)).Color)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, value: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight", "SetColor", 7usize, |this| ((**(this as *const *const IPointLight_Vtbl)).SetColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConstantAttenuation(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight", "ConstantAttenuation", 8usize, |this| ((**(this as *const *const IPointLight_Vtbl)).ConstantAttenuation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConstantAttenuation(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight", "SetConstantAttenuation", 9usize, |this| ((**(this as *const *const IPointLight_Vtbl)).SetConstantAttenuation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CoordinateSpace(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight", "CoordinateSpace", 10usize, |this| ((**(this as *const *const IPointLight_Vtbl)).CoordinateSpace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCoordinateSpace(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight", "SetCoordinateSpace", 11usize, |this| ((**(this as *const *const IPointLight_Vtbl)).SetCoordinateSpace)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LinearAttenuation(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight", "LinearAttenuation", 12usize, |this| ((**(this as *const *const IPointLight_Vtbl)).LinearAttenuation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLinearAttenuation(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight", "SetLinearAttenuation", 13usize, |this| ((**(this as *const *const IPointLight_Vtbl)).SetLinearAttenuation)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight", "Offset", 14usize, |this| ((**(this as *const *const IPointLight_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight", "SetOffset", 15usize, |this| ((**(this as *const *const IPointLight_Vtbl)).SetOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuadraticAttenuation(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight", "QuadraticAttenuation", 16usize, |this| ((**(this as *const *const IPointLight_Vtbl)).QuadraticAttenuation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuadraticAttenuation(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight", "SetQuadraticAttenuation", 17usize, |this| ((**(this as *const *const IPointLight_Vtbl)).SetQuadraticAttenuation)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Color,
+            SetColor,
+            ConstantAttenuation,
+            SetConstantAttenuation,
+            CoordinateSpace,
+            SetCoordinateSpace,
+            LinearAttenuation,
+            SetLinearAttenuation,
+            #[cfg(feature = "Foundation_Numerics")]
+            Offset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Offset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetOffset: 0,
+            QuadraticAttenuation,
+            SetQuadraticAttenuation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPointLight as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPointLight2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Intensity(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight2", "Intensity", 6usize, |this| ((**(this as *const *const IPointLight2_Vtbl)).Intensity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIntensity(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight2", "SetIntensity", 7usize, |this| ((**(this as *const *const IPointLight2_Vtbl)).SetIntensity)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Intensity, SetIntensity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPointLight2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPointLight3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinAttenuationCutoff(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight3", "MinAttenuationCutoff", 6usize, |this| ((**(this as *const *const IPointLight3_Vtbl)).MinAttenuationCutoff)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinAttenuationCutoff(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight3", "SetMinAttenuationCutoff", 7usize, |this| ((**(this as *const *const IPointLight3_Vtbl)).SetMinAttenuationCutoff)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxAttenuationCutoff(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight3", "MaxAttenuationCutoff", 8usize, |this| ((**(this as *const *const IPointLight3_Vtbl)).MaxAttenuationCutoff)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxAttenuationCutoff(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointLight3", "SetMaxAttenuationCutoff", 9usize, |this| ((**(this as *const *const IPointLight3_Vtbl)).SetMaxAttenuationCutoff)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MinAttenuationCutoff,
+            SetMinAttenuationCutoff,
+            MaxAttenuationCutoff,
+            SetMaxAttenuationCutoff,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPointLight3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPowerEasingFunction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut CompositionEasingFunctionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerEasingFunction", "Mode", 6usize, |this| ((**(this as *const *const IPowerEasingFunction_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Power(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPowerEasingFunction", "Power", 7usize, |this| ((**(this as *const *const IPowerEasingFunction_Vtbl)).Power)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Mode, Power }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPowerEasingFunction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IQuaternionKeyFrameAnimation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InsertKeyFrame(this: *mut core::ffi::c_void, normalizedprogresskey: f32, value: super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuaternionKeyFrameAnimation", "InsertKeyFrame", 6usize, |this| ((**(this as *const *const IQuaternionKeyFrameAnimation_Vtbl)).InsertKeyFrame)(this, normalizedprogresskey, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InsertKeyFrameWithEasingFunction(this: *mut core::ffi::c_void, normalizedprogresskey: f32, value: super::super::Foundation::Numerics::Quaternion, easingfunction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuaternionKeyFrameAnimation", "InsertKeyFrameWithEasingFunction", 7usize, |this| ((**(this as *const *const IQuaternionKeyFrameAnimation_Vtbl)).InsertKeyFrameWithEasingFunction)(this, normalizedprogresskey, value, easingfunction), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            InsertKeyFrame,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InsertKeyFrame: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            InsertKeyFrameWithEasingFunction,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InsertKeyFrameWithEasingFunction: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQuaternionKeyFrameAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IRectangleClip_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Bottom(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "Bottom", 6usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).Bottom)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottom(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "SetBottom", 7usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).SetBottom)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn BottomLeftRadius(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "BottomLeftRadius", 8usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).BottomLeftRadius)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetBottomLeftRadius(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "SetBottomLeftRadius", 9usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).SetBottomLeftRadius)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn BottomRightRadius(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "BottomRightRadius", 10usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).BottomRightRadius)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetBottomRightRadius(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "SetBottomRightRadius", 11usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).SetBottomRightRadius)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Left(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "Left", 12usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).Left)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLeft(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "SetLeft", 13usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).SetLeft)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Right(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "Right", 14usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).Right)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRight(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "SetRight", 15usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).SetRight)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Top(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "Top", 16usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).Top)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTop(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "SetTop", 17usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).SetTop)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TopLeftRadius(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "TopLeftRadius", 18usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).TopLeftRadius)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetTopLeftRadius(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "SetTopLeftRadius", 19usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).SetTopLeftRadius)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TopRightRadius(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "TopRightRadius", 20usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).TopRightRadius)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetTopRightRadius(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRectangleClip", "SetTopRightRadius", 21usize, |this| ((**(this as *const *const IRectangleClip_Vtbl)).SetTopRightRadius)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Bottom,
+            SetBottom,
+            #[cfg(feature = "Foundation_Numerics")]
+            BottomLeftRadius,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            BottomLeftRadius: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetBottomLeftRadius,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetBottomLeftRadius: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            BottomRightRadius,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            BottomRightRadius: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetBottomRightRadius,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetBottomRightRadius: 0,
+            Left,
+            SetLeft,
+            Right,
+            SetRight,
+            Top,
+            SetTop,
+            #[cfg(feature = "Foundation_Numerics")]
+            TopLeftRadius,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TopLeftRadius: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetTopLeftRadius,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetTopLeftRadius: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TopRightRadius,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TopRightRadius: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetTopRightRadius,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetTopRightRadius: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRectangleClip as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRedirectVisual_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRedirectVisual", "Source", 6usize, |this| ((**(this as *const *const IRedirectVisual_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRedirectVisual", "SetSource", 7usize, |this| ((**(this as *const *const IRedirectVisual_Vtbl)).SetSource)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Source, SetSource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRedirectVisual as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRenderingDeviceReplacedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GraphicsDevice(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRenderingDeviceReplacedEventArgs", "GraphicsDevice", 6usize, |this| ((**(this as *const *const IRenderingDeviceReplacedEventArgs_Vtbl)).GraphicsDevice)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GraphicsDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRenderingDeviceReplacedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScalarKeyFrameAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InsertKeyFrame(this: *mut core::ffi::c_void, normalizedprogresskey: f32, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScalarKeyFrameAnimation", "InsertKeyFrame", 6usize, |this| ((**(this as *const *const IScalarKeyFrameAnimation_Vtbl)).InsertKeyFrame)(this, normalizedprogresskey, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertKeyFrameWithEasingFunction(this: *mut core::ffi::c_void, normalizedprogresskey: f32, value: f32, easingfunction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScalarKeyFrameAnimation", "InsertKeyFrameWithEasingFunction", 7usize, |this| ((**(this as *const *const IScalarKeyFrameAnimation_Vtbl)).InsertKeyFrameWithEasingFunction)(this, normalizedprogresskey, value, easingfunction), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InsertKeyFrame, InsertKeyFrameWithEasingFunction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScalarKeyFrameAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScalarNaturalMotionAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FinalValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScalarNaturalMotionAnimation", "FinalValue", 6usize, |this| ((**(this as *const *const IScalarNaturalMotionAnimation_Vtbl)).FinalValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFinalValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScalarNaturalMotionAnimation", "SetFinalValue", 7usize, |this| ((**(this as *const *const IScalarNaturalMotionAnimation_Vtbl)).SetFinalValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScalarNaturalMotionAnimation", "InitialValue", 8usize, |this| ((**(this as *const *const IScalarNaturalMotionAnimation_Vtbl)).InitialValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScalarNaturalMotionAnimation", "SetInitialValue", 9usize, |this| ((**(this as *const *const IScalarNaturalMotionAnimation_Vtbl)).SetInitialValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialVelocity(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScalarNaturalMotionAnimation", "InitialVelocity", 10usize, |this| ((**(this as *const *const IScalarNaturalMotionAnimation_Vtbl)).InitialVelocity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialVelocity(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScalarNaturalMotionAnimation", "SetInitialVelocity", 11usize, |this| ((**(this as *const *const IScalarNaturalMotionAnimation_Vtbl)).SetInitialVelocity)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FinalValue,
+            SetFinalValue,
+            InitialValue,
+            SetInitialValue,
+            InitialVelocity,
+            SetInitialVelocity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScalarNaturalMotionAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScalarNaturalMotionAnimationFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScalarNaturalMotionAnimationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IShapeVisual_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Shapes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShapeVisual", "Shapes", 6usize, |this| ((**(this as *const *const IShapeVisual_Vtbl)).Shapes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ViewBox(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShapeVisual", "ViewBox", 7usize, |this| ((**(this as *const *const IShapeVisual_Vtbl)).ViewBox)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewBox(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShapeVisual", "SetViewBox", 8usize, |this| ((**(this as *const *const IShapeVisual_Vtbl)).SetViewBox)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Shapes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Shapes: 0,
+            ViewBox,
+            SetViewBox,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShapeVisual as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISineEasingFunction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut CompositionEasingFunctionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISineEasingFunction", "Mode", 6usize, |this| ((**(this as *const *const ISineEasingFunction_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Mode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISineEasingFunction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpotLight_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConstantAttenuation(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "ConstantAttenuation", 6usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).ConstantAttenuation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConstantAttenuation(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "SetConstantAttenuation", 7usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).SetConstantAttenuation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CoordinateSpace(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "CoordinateSpace", 8usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).CoordinateSpace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCoordinateSpace(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "SetCoordinateSpace", 9usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).SetCoordinateSpace)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Direction(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "Direction", 10usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).Direction)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetDirection(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "SetDirection", 11usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).SetDirection)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InnerConeAngle(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "InnerConeAngle", 12usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).InnerConeAngle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInnerConeAngle(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "SetInnerConeAngle", 13usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).SetInnerConeAngle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InnerConeAngleInDegrees(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "InnerConeAngleInDegrees", 14usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).InnerConeAngleInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInnerConeAngleInDegrees(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "SetInnerConeAngleInDegrees", 15usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).SetInnerConeAngleInDegrees)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InnerConeColor(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "InnerConeColor", 16usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).InnerConeColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInnerConeColor(this: *mut core::ffi::c_void, value: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "SetInnerConeColor", 17usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).SetInnerConeColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LinearAttenuation(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "LinearAttenuation", 18usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).LinearAttenuation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLinearAttenuation(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "SetLinearAttenuation", 19usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).SetLinearAttenuation)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "Offset", 20usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "SetOffset", 21usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).SetOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OuterConeAngle(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "OuterConeAngle", 22usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).OuterConeAngle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOuterConeAngle(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "SetOuterConeAngle", 23usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).SetOuterConeAngle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OuterConeAngleInDegrees(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "OuterConeAngleInDegrees", 24usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).OuterConeAngleInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOuterConeAngleInDegrees(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "SetOuterConeAngleInDegrees", 25usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).SetOuterConeAngleInDegrees)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OuterConeColor(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "OuterConeColor", 26usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).OuterConeColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOuterConeColor(this: *mut core::ffi::c_void, value: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "SetOuterConeColor", 27usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).SetOuterConeColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuadraticAttenuation(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "QuadraticAttenuation", 28usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).QuadraticAttenuation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuadraticAttenuation(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight", "SetQuadraticAttenuation", 29usize, |this| ((**(this as *const *const ISpotLight_Vtbl)).SetQuadraticAttenuation)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConstantAttenuation,
+            SetConstantAttenuation,
+            CoordinateSpace,
+            SetCoordinateSpace,
+            #[cfg(feature = "Foundation_Numerics")]
+            Direction,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Direction: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetDirection,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetDirection: 0,
+            InnerConeAngle,
+            SetInnerConeAngle,
+            InnerConeAngleInDegrees,
+            SetInnerConeAngleInDegrees,
+            InnerConeColor,
+            SetInnerConeColor,
+            LinearAttenuation,
+            SetLinearAttenuation,
+            #[cfg(feature = "Foundation_Numerics")]
+            Offset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Offset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetOffset: 0,
+            OuterConeAngle,
+            SetOuterConeAngle,
+            OuterConeAngleInDegrees,
+            SetOuterConeAngleInDegrees,
+            OuterConeColor,
+            SetOuterConeColor,
+            QuadraticAttenuation,
+            SetQuadraticAttenuation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpotLight as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpotLight2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InnerConeIntensity(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight2", "InnerConeIntensity", 6usize, |this| ((**(this as *const *const ISpotLight2_Vtbl)).InnerConeIntensity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInnerConeIntensity(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight2", "SetInnerConeIntensity", 7usize, |this| ((**(this as *const *const ISpotLight2_Vtbl)).SetInnerConeIntensity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn OuterConeIntensity(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight2", "OuterConeIntensity", 8usize, |this| ((**(this as *const *const ISpotLight2_Vtbl)).OuterConeIntensity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOuterConeIntensity(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight2", "SetOuterConeIntensity", 9usize, |this| ((**(this as *const *const ISpotLight2_Vtbl)).SetOuterConeIntensity)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InnerConeIntensity,
+            SetInnerConeIntensity,
+            OuterConeIntensity,
+            SetOuterConeIntensity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpotLight2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpotLight3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinAttenuationCutoff(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight3", "MinAttenuationCutoff", 6usize, |this| ((**(this as *const *const ISpotLight3_Vtbl)).MinAttenuationCutoff)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinAttenuationCutoff(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight3", "SetMinAttenuationCutoff", 7usize, |this| ((**(this as *const *const ISpotLight3_Vtbl)).SetMinAttenuationCutoff)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxAttenuationCutoff(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight3", "MaxAttenuationCutoff", 8usize, |this| ((**(this as *const *const ISpotLight3_Vtbl)).MaxAttenuationCutoff)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxAttenuationCutoff(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpotLight3", "SetMaxAttenuationCutoff", 9usize, |this| ((**(this as *const *const ISpotLight3_Vtbl)).SetMaxAttenuationCutoff)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MinAttenuationCutoff,
+            SetMinAttenuationCutoff,
+            MaxAttenuationCutoff,
+            SetMaxAttenuationCutoff,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpotLight3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpringScalarNaturalMotionAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DampingRatio(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpringScalarNaturalMotionAnimation", "DampingRatio", 6usize, |this| ((**(this as *const *const ISpringScalarNaturalMotionAnimation_Vtbl)).DampingRatio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDampingRatio(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpringScalarNaturalMotionAnimation", "SetDampingRatio", 7usize, |this| ((**(this as *const *const ISpringScalarNaturalMotionAnimation_Vtbl)).SetDampingRatio)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Period(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpringScalarNaturalMotionAnimation", "Period", 8usize, |this| ((**(this as *const *const ISpringScalarNaturalMotionAnimation_Vtbl)).Period)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPeriod(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpringScalarNaturalMotionAnimation", "SetPeriod", 9usize, |this| ((**(this as *const *const ISpringScalarNaturalMotionAnimation_Vtbl)).SetPeriod)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DampingRatio, SetDampingRatio, Period, SetPeriod }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpringScalarNaturalMotionAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpringVector2NaturalMotionAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DampingRatio(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpringVector2NaturalMotionAnimation", "DampingRatio", 6usize, |this| ((**(this as *const *const ISpringVector2NaturalMotionAnimation_Vtbl)).DampingRatio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDampingRatio(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpringVector2NaturalMotionAnimation", "SetDampingRatio", 7usize, |this| ((**(this as *const *const ISpringVector2NaturalMotionAnimation_Vtbl)).SetDampingRatio)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Period(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpringVector2NaturalMotionAnimation", "Period", 8usize, |this| ((**(this as *const *const ISpringVector2NaturalMotionAnimation_Vtbl)).Period)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPeriod(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpringVector2NaturalMotionAnimation", "SetPeriod", 9usize, |this| ((**(this as *const *const ISpringVector2NaturalMotionAnimation_Vtbl)).SetPeriod)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DampingRatio, SetDampingRatio, Period, SetPeriod }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpringVector2NaturalMotionAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpringVector3NaturalMotionAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DampingRatio(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpringVector3NaturalMotionAnimation", "DampingRatio", 6usize, |this| ((**(this as *const *const ISpringVector3NaturalMotionAnimation_Vtbl)).DampingRatio)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDampingRatio(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpringVector3NaturalMotionAnimation", "SetDampingRatio", 7usize, |this| ((**(this as *const *const ISpringVector3NaturalMotionAnimation_Vtbl)).SetDampingRatio)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Period(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpringVector3NaturalMotionAnimation", "Period", 8usize, |this| ((**(this as *const *const ISpringVector3NaturalMotionAnimation_Vtbl)).Period)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPeriod(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpringVector3NaturalMotionAnimation", "SetPeriod", 9usize, |this| ((**(this as *const *const ISpringVector3NaturalMotionAnimation_Vtbl)).SetPeriod)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DampingRatio, SetDampingRatio, Period, SetPeriod }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpringVector3NaturalMotionAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpriteVisual_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Brush(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpriteVisual", "Brush", 6usize, |this| ((**(this as *const *const ISpriteVisual_Vtbl)).Brush)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBrush(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpriteVisual", "SetBrush", 7usize, |this| ((**(this as *const *const ISpriteVisual_Vtbl)).SetBrush)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Brush, SetBrush }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpriteVisual as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpriteVisual2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Shadow(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpriteVisual2", "Shadow", 6usize, |this| ((**(this as *const *const ISpriteVisual2_Vtbl)).Shadow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShadow(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpriteVisual2", "SetShadow", 7usize, |this| ((**(this as *const *const ISpriteVisual2_Vtbl)).SetShadow)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Shadow, SetShadow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpriteVisual2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStepEasingFunction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FinalStep(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStepEasingFunction", "FinalStep", 6usize, |this| ((**(this as *const *const IStepEasingFunction_Vtbl)).FinalStep)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFinalStep(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStepEasingFunction", "SetFinalStep", 7usize, |this| ((**(this as *const *const IStepEasingFunction_Vtbl)).SetFinalStep)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialStep(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStepEasingFunction", "InitialStep", 8usize, |this| ((**(this as *const *const IStepEasingFunction_Vtbl)).InitialStep)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialStep(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStepEasingFunction", "SetInitialStep", 9usize, |this| ((**(this as *const *const IStepEasingFunction_Vtbl)).SetInitialStep)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFinalStepSingleFrame(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStepEasingFunction", "IsFinalStepSingleFrame", 10usize, |this| ((**(this as *const *const IStepEasingFunction_Vtbl)).IsFinalStepSingleFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsFinalStepSingleFrame(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStepEasingFunction", "SetIsFinalStepSingleFrame", 11usize, |this| ((**(this as *const *const IStepEasingFunction_Vtbl)).SetIsFinalStepSingleFrame)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInitialStepSingleFrame(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStepEasingFunction", "IsInitialStepSingleFrame", 12usize, |this| ((**(this as *const *const IStepEasingFunction_Vtbl)).IsInitialStepSingleFrame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsInitialStepSingleFrame(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStepEasingFunction", "SetIsInitialStepSingleFrame", 13usize, |this| ((**(this as *const *const IStepEasingFunction_Vtbl)).SetIsInitialStepSingleFrame)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StepCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStepEasingFunction", "StepCount", 14usize, |this| ((**(this as *const *const IStepEasingFunction_Vtbl)).StepCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStepCount(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStepEasingFunction", "SetStepCount", 15usize, |this| ((**(this as *const *const IStepEasingFunction_Vtbl)).SetStepCount)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FinalStep,
+            SetFinalStep,
+            InitialStep,
+            SetInitialStep,
+            IsFinalStepSingleFrame,
+            SetIsFinalStepSingleFrame,
+            IsInitialStepSingleFrame,
+            SetIsInitialStepSingleFrame,
+            StepCount,
+            SetStepCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStepEasingFunction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IVector2KeyFrameAnimation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InsertKeyFrame(this: *mut core::ffi::c_void, normalizedprogresskey: f32, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector2KeyFrameAnimation", "InsertKeyFrame", 6usize, |this| ((**(this as *const *const IVector2KeyFrameAnimation_Vtbl)).InsertKeyFrame)(this, normalizedprogresskey, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InsertKeyFrameWithEasingFunction(this: *mut core::ffi::c_void, normalizedprogresskey: f32, value: super::super::Foundation::Numerics::Vector2, easingfunction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector2KeyFrameAnimation", "InsertKeyFrameWithEasingFunction", 7usize, |this| ((**(this as *const *const IVector2KeyFrameAnimation_Vtbl)).InsertKeyFrameWithEasingFunction)(this, normalizedprogresskey, value, easingfunction), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            InsertKeyFrame,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InsertKeyFrame: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            InsertKeyFrameWithEasingFunction,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InsertKeyFrameWithEasingFunction: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVector2KeyFrameAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IVector2NaturalMotionAnimation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn FinalValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector2NaturalMotionAnimation", "FinalValue", 6usize, |this| ((**(this as *const *const IVector2NaturalMotionAnimation_Vtbl)).FinalValue)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetFinalValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector2NaturalMotionAnimation", "SetFinalValue", 7usize, |this| ((**(this as *const *const IVector2NaturalMotionAnimation_Vtbl)).SetFinalValue)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InitialValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector2NaturalMotionAnimation", "InitialValue", 8usize, |this| ((**(this as *const *const IVector2NaturalMotionAnimation_Vtbl)).InitialValue)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetInitialValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector2NaturalMotionAnimation", "SetInitialValue", 9usize, |this| ((**(this as *const *const IVector2NaturalMotionAnimation_Vtbl)).SetInitialValue)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InitialVelocity(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector2NaturalMotionAnimation", "InitialVelocity", 10usize, |this| ((**(this as *const *const IVector2NaturalMotionAnimation_Vtbl)).InitialVelocity)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetInitialVelocity(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector2NaturalMotionAnimation", "SetInitialVelocity", 11usize, |this| ((**(this as *const *const IVector2NaturalMotionAnimation_Vtbl)).SetInitialVelocity)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            FinalValue,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            FinalValue: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetFinalValue,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetFinalValue: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            InitialValue,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InitialValue: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetInitialValue,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetInitialValue: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            InitialVelocity,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InitialVelocity: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetInitialVelocity,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetInitialVelocity: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVector2NaturalMotionAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVector2NaturalMotionAnimationFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVector2NaturalMotionAnimationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IVector3KeyFrameAnimation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InsertKeyFrame(this: *mut core::ffi::c_void, normalizedprogresskey: f32, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector3KeyFrameAnimation", "InsertKeyFrame", 6usize, |this| ((**(this as *const *const IVector3KeyFrameAnimation_Vtbl)).InsertKeyFrame)(this, normalizedprogresskey, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InsertKeyFrameWithEasingFunction(this: *mut core::ffi::c_void, normalizedprogresskey: f32, value: super::super::Foundation::Numerics::Vector3, easingfunction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector3KeyFrameAnimation", "InsertKeyFrameWithEasingFunction", 7usize, |this| ((**(this as *const *const IVector3KeyFrameAnimation_Vtbl)).InsertKeyFrameWithEasingFunction)(this, normalizedprogresskey, value, easingfunction), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            InsertKeyFrame,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InsertKeyFrame: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            InsertKeyFrameWithEasingFunction,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InsertKeyFrameWithEasingFunction: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVector3KeyFrameAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IVector3NaturalMotionAnimation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn FinalValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector3NaturalMotionAnimation", "FinalValue", 6usize, |this| ((**(this as *const *const IVector3NaturalMotionAnimation_Vtbl)).FinalValue)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetFinalValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector3NaturalMotionAnimation", "SetFinalValue", 7usize, |this| ((**(this as *const *const IVector3NaturalMotionAnimation_Vtbl)).SetFinalValue)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InitialValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector3NaturalMotionAnimation", "InitialValue", 8usize, |this| ((**(this as *const *const IVector3NaturalMotionAnimation_Vtbl)).InitialValue)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetInitialValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector3NaturalMotionAnimation", "SetInitialValue", 9usize, |this| ((**(this as *const *const IVector3NaturalMotionAnimation_Vtbl)).SetInitialValue)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InitialVelocity(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector3NaturalMotionAnimation", "InitialVelocity", 10usize, |this| ((**(this as *const *const IVector3NaturalMotionAnimation_Vtbl)).InitialVelocity)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetInitialVelocity(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector3NaturalMotionAnimation", "SetInitialVelocity", 11usize, |this| ((**(this as *const *const IVector3NaturalMotionAnimation_Vtbl)).SetInitialVelocity)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            FinalValue,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            FinalValue: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetFinalValue,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetFinalValue: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            InitialValue,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InitialValue: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetInitialValue,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetInitialValue: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            InitialVelocity,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InitialVelocity: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetInitialVelocity,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetInitialVelocity: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVector3NaturalMotionAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVector3NaturalMotionAnimationFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVector3NaturalMotionAnimationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IVector4KeyFrameAnimation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InsertKeyFrame(this: *mut core::ffi::c_void, normalizedprogresskey: f32, value: super::super::Foundation::Numerics::Vector4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector4KeyFrameAnimation", "InsertKeyFrame", 6usize, |this| ((**(this as *const *const IVector4KeyFrameAnimation_Vtbl)).InsertKeyFrame)(this, normalizedprogresskey, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn InsertKeyFrameWithEasingFunction(this: *mut core::ffi::c_void, normalizedprogresskey: f32, value: super::super::Foundation::Numerics::Vector4, easingfunction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVector4KeyFrameAnimation", "InsertKeyFrameWithEasingFunction", 7usize, |this| ((**(this as *const *const IVector4KeyFrameAnimation_Vtbl)).InsertKeyFrameWithEasingFunction)(this, normalizedprogresskey, value, easingfunction), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            InsertKeyFrame,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InsertKeyFrame: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            InsertKeyFrameWithEasingFunction,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            InsertKeyFrameWithEasingFunction: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVector4KeyFrameAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IVisual_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn AnchorPoint(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "AnchorPoint", 6usize, |this| ((**(this as *const *const IVisual_Vtbl)).AnchorPoint)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetAnchorPoint(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetAnchorPoint", 7usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetAnchorPoint)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackfaceVisibility(this: *mut core::ffi::c_void, result__: *mut CompositionBackfaceVisibility) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "BackfaceVisibility", 8usize, |this| ((**(this as *const *const IVisual_Vtbl)).BackfaceVisibility)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackfaceVisibility(this: *mut core::ffi::c_void, value: CompositionBackfaceVisibility) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetBackfaceVisibility", 9usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetBackfaceVisibility)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BorderMode(this: *mut core::ffi::c_void, result__: *mut CompositionBorderMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "BorderMode", 10usize, |this| ((**(this as *const *const IVisual_Vtbl)).BorderMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBorderMode(this: *mut core::ffi::c_void, value: CompositionBorderMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetBorderMode", 11usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetBorderMode)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CenterPoint(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "CenterPoint", 12usize, |this| ((**(this as *const *const IVisual_Vtbl)).CenterPoint)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetCenterPoint(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetCenterPoint", 13usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetCenterPoint)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clip(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "Clip", 14usize, |this| ((**(this as *const *const IVisual_Vtbl)).Clip)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClip(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetClip", 15usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetClip)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompositeMode(this: *mut core::ffi::c_void, result__: *mut CompositionCompositeMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "CompositeMode", 16usize, |this| ((**(this as *const *const IVisual_Vtbl)).CompositeMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompositeMode(this: *mut core::ffi::c_void, value: CompositionCompositeMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetCompositeMode", 17usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetCompositeMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "IsVisible", 18usize, |this| ((**(this as *const *const IVisual_Vtbl)).IsVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetIsVisible", 19usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetIsVisible)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "Offset", 20usize, |this| ((**(this as *const *const IVisual_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetOffset", 21usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetOffset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Opacity(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "Opacity", 22usize, |this| ((**(this as *const *const IVisual_Vtbl)).Opacity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpacity(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetOpacity", 23usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetOpacity)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "Orientation", 24usize, |this| ((**(this as *const *const IVisual_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetOrientation(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetOrientation", 25usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetOrientation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "Parent", 26usize, |this| ((**(this as *const *const IVisual_Vtbl)).Parent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationAngle(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "RotationAngle", 27usize, |this| ((**(this as *const *const IVisual_Vtbl)).RotationAngle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotationAngle(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetRotationAngle", 28usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetRotationAngle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationAngleInDegrees(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "RotationAngleInDegrees", 29usize, |this| ((**(this as *const *const IVisual_Vtbl)).RotationAngleInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotationAngleInDegrees(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetRotationAngleInDegrees", 30usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetRotationAngleInDegrees)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn RotationAxis(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "RotationAxis", 31usize, |this| ((**(this as *const *const IVisual_Vtbl)).RotationAxis)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetRotationAxis(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetRotationAxis", 32usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetRotationAxis)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Scale(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "Scale", 33usize, |this| ((**(this as *const *const IVisual_Vtbl)).Scale)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetScale(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetScale", 34usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetScale)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "Size", 35usize, |this| ((**(this as *const *const IVisual_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetSize", 36usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetSize)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn TransformMatrix(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Matrix4x4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "TransformMatrix", 37usize, |this| ((**(this as *const *const IVisual_Vtbl)).TransformMatrix)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetTransformMatrix(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Matrix4x4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual", "SetTransformMatrix", 38usize, |this| ((**(this as *const *const IVisual_Vtbl)).SetTransformMatrix)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            AnchorPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            AnchorPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetAnchorPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetAnchorPoint: 0,
+            BackfaceVisibility,
+            SetBackfaceVisibility,
+            BorderMode,
+            SetBorderMode,
+            #[cfg(feature = "Foundation_Numerics")]
+            CenterPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CenterPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetCenterPoint,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetCenterPoint: 0,
+            Clip,
+            SetClip,
+            CompositeMode,
+            SetCompositeMode,
+            IsVisible,
+            SetIsVisible,
+            #[cfg(feature = "Foundation_Numerics")]
+            Offset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Offset: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetOffset: 0,
+            Opacity,
+            SetOpacity,
+            #[cfg(feature = "Foundation_Numerics")]
+            Orientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Orientation: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetOrientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetOrientation: 0,
+            Parent,
+            RotationAngle,
+            SetRotationAngle,
+            RotationAngleInDegrees,
+            SetRotationAngleInDegrees,
+            #[cfg(feature = "Foundation_Numerics")]
+            RotationAxis,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            RotationAxis: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetRotationAxis,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetRotationAxis: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Scale,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Scale: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetScale,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetScale: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Size,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Size: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetSize,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetSize: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            TransformMatrix,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            TransformMatrix: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetTransformMatrix,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetTransformMatrix: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisual as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IVisual2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParentForTransform(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual2", "ParentForTransform", 6usize, |this| ((**(this as *const *const IVisual2_Vtbl)).ParentForTransform)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParentForTransform(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual2", "SetParentForTransform", 7usize, |this| ((**(this as *const *const IVisual2_Vtbl)).SetParentForTransform)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn RelativeOffsetAdjustment(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual2", "RelativeOffsetAdjustment", 8usize, |this| ((**(this as *const *const IVisual2_Vtbl)).RelativeOffsetAdjustment)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetRelativeOffsetAdjustment(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual2", "SetRelativeOffsetAdjustment", 9usize, |this| ((**(this as *const *const IVisual2_Vtbl)).SetRelativeOffsetAdjustment)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn RelativeSizeAdjustment(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual2", "RelativeSizeAdjustment", 10usize, |this| ((**(this as *const *const IVisual2_Vtbl)).RelativeSizeAdjustment)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetRelativeSizeAdjustment(this: *mut core::ffi::c_void, value: super::super::Foundation::Numerics::Vector2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual2", "SetRelativeSizeAdjustment", 11usize, |this| ((**(this as *const *const IVisual2_Vtbl)).SetRelativeSizeAdjustment)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ParentForTransform,
+            SetParentForTransform,
+            #[cfg(feature = "Foundation_Numerics")]
+            RelativeOffsetAdjustment,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            RelativeOffsetAdjustment: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetRelativeOffsetAdjustment,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetRelativeOffsetAdjustment: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            RelativeSizeAdjustment,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            RelativeSizeAdjustment: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetRelativeSizeAdjustment,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetRelativeSizeAdjustment: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisual2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVisual3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsHitTestVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual3", "IsHitTestVisible", 6usize, |this| ((**(this as *const *const IVisual3_Vtbl)).IsHitTestVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsHitTestVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual3", "SetIsHitTestVisible", 7usize, |this| ((**(this as *const *const IVisual3_Vtbl)).SetIsHitTestVisible)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsHitTestVisible, SetIsHitTestVisible }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisual3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVisual4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPixelSnappingEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual4", "IsPixelSnappingEnabled", 6usize, |this| ((**(this as *const *const IVisual4_Vtbl)).IsPixelSnappingEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsPixelSnappingEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisual4", "SetIsPixelSnappingEnabled", 7usize, |this| ((**(this as *const *const IVisual4_Vtbl)).SetIsPixelSnappingEnabled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsPixelSnappingEnabled, SetIsPixelSnappingEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisual4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVisualCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualCollection", "Count", 6usize, |this| ((**(this as *const *const IVisualCollection_Vtbl)).Count)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAbove(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, sibling: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualCollection", "InsertAbove", 7usize, |this| ((**(this as *const *const IVisualCollection_Vtbl)).InsertAbove)(this, newchild, sibling), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAtBottom(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualCollection", "InsertAtBottom", 8usize, |this| ((**(this as *const *const IVisualCollection_Vtbl)).InsertAtBottom)(this, newchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAtTop(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualCollection", "InsertAtTop", 9usize, |this| ((**(this as *const *const IVisualCollection_Vtbl)).InsertAtTop)(this, newchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertBelow(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, sibling: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualCollection", "InsertBelow", 10usize, |this| ((**(this as *const *const IVisualCollection_Vtbl)).InsertBelow)(this, newchild, sibling), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, child: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualCollection", "Remove", 11usize, |this| ((**(this as *const *const IVisualCollection_Vtbl)).Remove)(this, child), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualCollection", "RemoveAll", 12usize, |this| ((**(this as *const *const IVisualCollection_Vtbl)).RemoveAll)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            InsertAbove,
+            InsertAtBottom,
+            InsertAtTop,
+            InsertBelow,
+            Remove,
+            RemoveAll,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisualCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVisualElement_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisualElement as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVisualElement2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVisualInternal(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualElement2", "GetVisualInternal", 6usize, |this| ((**(this as *const *const IVisualElement2_Vtbl)).GetVisualInternal)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetVisualInternal }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisualElement2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVisualFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisualFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVisualUnorderedCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualUnorderedCollection", "Count", 6usize, |this| ((**(this as *const *const IVisualUnorderedCollection_Vtbl)).Count)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, newvisual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualUnorderedCollection", "Add", 7usize, |this| ((**(this as *const *const IVisualUnorderedCollection_Vtbl)).Add)(this, newvisual), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, visual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualUnorderedCollection", "Remove", 8usize, |this| ((**(this as *const *const IVisualUnorderedCollection_Vtbl)).Remove)(this, visual), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualUnorderedCollection", "RemoveAll", 9usize, |this| ((**(this as *const *const IVisualUnorderedCollection_Vtbl)).RemoveAll)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, Add, Remove, RemoveAll }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisualUnorderedCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -477,6 +477,7 @@ impl AnimationEffect {
 impl windows_core::TypeKind for AnimationEffect {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AnimationEffect {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AnimationEffect").field(&self.0).finish()
@@ -513,6 +514,7 @@ impl AnimationEffectTarget {
 impl windows_core::TypeKind for AnimationEffectTarget {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AnimationEffectTarget {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AnimationEffectTarget").field(&self.0).finish()
@@ -532,6 +534,7 @@ impl PropertyAnimationType {
 impl windows_core::TypeKind for PropertyAnimationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PropertyAnimationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PropertyAnimationType").field(&self.0).finish()
@@ -542,3 +545,5 @@ impl windows_core::RuntimeType for PropertyAnimationType {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
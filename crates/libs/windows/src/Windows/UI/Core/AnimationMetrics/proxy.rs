@@ -0,0 +1,118 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAnimationDescription_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Animations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationDescription", "Animations", 6usize, |this| ((**(this as *const *const IAnimationDescription_Vtbl)).Animations)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StaggerDelay(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationDescription", "StaggerDelay", 7usize, |this| ((**(this as *const *const IAnimationDescription_Vtbl)).StaggerDelay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StaggerDelayFactor(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationDescription", "StaggerDelayFactor", 8usize, |this| ((**(this as *const *const IAnimationDescription_Vtbl)).StaggerDelayFactor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DelayLimit(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationDescription", "DelayLimit", 9usize, |this| ((**(this as *const *const IAnimationDescription_Vtbl)).DelayLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZOrder(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationDescription", "ZOrder", 10usize, |this| ((**(this as *const *const IAnimationDescription_Vtbl)).ZOrder)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Animations,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Animations: 0,
+            StaggerDelay,
+            StaggerDelayFactor,
+            DelayLimit,
+            ZOrder,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnimationDescription as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAnimationDescriptionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, effect: AnimationEffect, target: AnimationEffectTarget, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnimationDescriptionFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IAnimationDescriptionFactory_Vtbl)).CreateInstance)(this, effect, target, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnimationDescriptionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpacityAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitialOpacity(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpacityAnimation", "InitialOpacity", 6usize, |this| ((**(this as *const *const IOpacityAnimation_Vtbl)).InitialOpacity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinalOpacity(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpacityAnimation", "FinalOpacity", 7usize, |this| ((**(this as *const *const IOpacityAnimation_Vtbl)).FinalOpacity)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitialOpacity, FinalOpacity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpacityAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut PropertyAnimationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyAnimation", "Type", 6usize, |this| ((**(this as *const *const IPropertyAnimation_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delay(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyAnimation", "Delay", 7usize, |this| ((**(this as *const *const IPropertyAnimation_Vtbl)).Delay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyAnimation", "Duration", 8usize, |this| ((**(this as *const *const IPropertyAnimation_Vtbl)).Duration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Control1(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyAnimation", "Control1", 9usize, |this| ((**(this as *const *const IPropertyAnimation_Vtbl)).Control1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Control2(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyAnimation", "Control2", 10usize, |this| ((**(this as *const *const IPropertyAnimation_Vtbl)).Control2)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Type, Delay, Duration, Control1, Control2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScaleAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitialScaleX(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScaleAnimation", "InitialScaleX", 6usize, |this| ((**(this as *const *const IScaleAnimation_Vtbl)).InitialScaleX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialScaleY(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScaleAnimation", "InitialScaleY", 7usize, |this| ((**(this as *const *const IScaleAnimation_Vtbl)).InitialScaleY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinalScaleX(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScaleAnimation", "FinalScaleX", 8usize, |this| ((**(this as *const *const IScaleAnimation_Vtbl)).FinalScaleX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinalScaleY(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScaleAnimation", "FinalScaleY", 9usize, |this| ((**(this as *const *const IScaleAnimation_Vtbl)).FinalScaleY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NormalizedOrigin(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScaleAnimation", "NormalizedOrigin", 10usize, |this| ((**(this as *const *const IScaleAnimation_Vtbl)).NormalizedOrigin)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitialScaleX,
+            InitialScaleY,
+            FinalScaleX,
+            FinalScaleY,
+            NormalizedOrigin,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScaleAnimation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
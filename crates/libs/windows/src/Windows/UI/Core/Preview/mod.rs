@@ -160,3 +160,5 @@ impl windows_core::RuntimeName for SystemNavigationManagerPreview {
 }
 unsafe impl Send for SystemNavigationManagerPreview {}
 unsafe impl Sync for SystemNavigationManagerPreview {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
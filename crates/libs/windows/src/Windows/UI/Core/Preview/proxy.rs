@@ -0,0 +1,72 @@
+impl windows_core::imp::ProxyVtable for ICoreAppWindowPreview_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreAppWindowPreview as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_WindowManagement")]
+impl windows_core::imp::ProxyVtable for ICoreAppWindowPreviewStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_WindowManagement")]
+        unsafe extern "system" fn GetIdFromWindow(this: *mut core::ffi::c_void, window: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAppWindowPreviewStatics", "GetIdFromWindow", 6usize, |this| ((**(this as *const *const ICoreAppWindowPreviewStatics_Vtbl)).GetIdFromWindow)(this, window, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_WindowManagement")]
+            GetIdFromWindow,
+            #[cfg(not(feature = "UI_WindowManagement"))]
+            GetIdFromWindow: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreAppWindowPreviewStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemNavigationCloseRequestedPreviewEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemNavigationCloseRequestedPreviewEventArgs", "Handled", 6usize, |this| ((**(this as *const *const ISystemNavigationCloseRequestedPreviewEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemNavigationCloseRequestedPreviewEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const ISystemNavigationCloseRequestedPreviewEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemNavigationCloseRequestedPreviewEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const ISystemNavigationCloseRequestedPreviewEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemNavigationCloseRequestedPreviewEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemNavigationManagerPreview_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CloseRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemNavigationManagerPreview", "CloseRequested", 6usize, |this| ((**(this as *const *const ISystemNavigationManagerPreview_Vtbl)).CloseRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCloseRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemNavigationManagerPreview", "RemoveCloseRequested", 7usize, |this| ((**(this as *const *const ISystemNavigationManagerPreview_Vtbl)).RemoveCloseRequested)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CloseRequested, RemoveCloseRequested }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemNavigationManagerPreview as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemNavigationManagerPreviewStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemNavigationManagerPreviewStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const ISystemNavigationManagerPreviewStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemNavigationManagerPreviewStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
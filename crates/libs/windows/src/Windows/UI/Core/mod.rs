@@ -3782,6 +3782,7 @@ impl AppViewBackButtonVisibility {
 impl windows_core::TypeKind for AppViewBackButtonVisibility {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppViewBackButtonVisibility {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppViewBackButtonVisibility").field(&self.0).finish()
@@ -3807,6 +3808,7 @@ impl CoreAcceleratorKeyEventType {
 impl windows_core::TypeKind for CoreAcceleratorKeyEventType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreAcceleratorKeyEventType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreAcceleratorKeyEventType").field(&self.0).finish()
@@ -3839,6 +3841,7 @@ impl CoreCursorType {
 impl windows_core::TypeKind for CoreCursorType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreCursorType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreCursorType").field(&self.0).finish()
@@ -3859,6 +3862,7 @@ impl CoreDispatcherPriority {
 impl windows_core::TypeKind for CoreDispatcherPriority {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreDispatcherPriority {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreDispatcherPriority").field(&self.0).finish()
@@ -3881,6 +3885,7 @@ impl CoreIndependentInputFilters {
 impl windows_core::TypeKind for CoreIndependentInputFilters {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreIndependentInputFilters {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreIndependentInputFilters").field(&self.0).finish()
@@ -3934,6 +3939,7 @@ impl CoreInputDeviceTypes {
 impl windows_core::TypeKind for CoreInputDeviceTypes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreInputDeviceTypes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreInputDeviceTypes").field(&self.0).finish()
@@ -3987,6 +3993,7 @@ impl CoreProcessEventsOption {
 impl windows_core::TypeKind for CoreProcessEventsOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreProcessEventsOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreProcessEventsOption").field(&self.0).finish()
@@ -4005,6 +4012,7 @@ impl CoreProximityEvaluationScore {
 impl windows_core::TypeKind for CoreProximityEvaluationScore {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreProximityEvaluationScore {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreProximityEvaluationScore").field(&self.0).finish()
@@ -4024,6 +4032,7 @@ impl CoreVirtualKeyStates {
 impl windows_core::TypeKind for CoreVirtualKeyStates {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreVirtualKeyStates {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreVirtualKeyStates").field(&self.0).finish()
@@ -4077,6 +4086,7 @@ impl CoreWindowActivationMode {
 impl windows_core::TypeKind for CoreWindowActivationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreWindowActivationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreWindowActivationMode").field(&self.0).finish()
@@ -4096,6 +4106,7 @@ impl CoreWindowActivationState {
 impl windows_core::TypeKind for CoreWindowActivationState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreWindowActivationState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreWindowActivationState").field(&self.0).finish()
@@ -4114,6 +4125,7 @@ impl CoreWindowFlowDirection {
 impl windows_core::TypeKind for CoreWindowFlowDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreWindowFlowDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreWindowFlowDirection").field(&self.0).finish()
@@ -4123,7 +4135,8 @@ impl windows_core::RuntimeType for CoreWindowFlowDirection {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.Core.CoreWindowFlowDirection;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CorePhysicalKeyStatus {
     pub RepeatCount: u32,
     pub ScanCode: u32,
@@ -4144,7 +4157,8 @@ impl Default for CorePhysicalKeyStatus {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CoreProximityEvaluation {
     pub Score: i32,
     pub AdjustedPoint: super::super::Foundation::Point,
@@ -4279,3 +4293,5 @@ pub struct IdleDispatchedHandler_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1422 @@
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IAcceleratorKeyEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventType(this: *mut core::ffi::c_void, result__: *mut CoreAcceleratorKeyEventType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAcceleratorKeyEventArgs", "EventType", 6usize, |this| ((**(this as *const *const IAcceleratorKeyEventArgs_Vtbl)).EventType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn VirtualKey(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAcceleratorKeyEventArgs", "VirtualKey", 7usize, |this| ((**(this as *const *const IAcceleratorKeyEventArgs_Vtbl)).VirtualKey)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyStatus(this: *mut core::ffi::c_void, result__: *mut CorePhysicalKeyStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAcceleratorKeyEventArgs", "KeyStatus", 8usize, |this| ((**(this as *const *const IAcceleratorKeyEventArgs_Vtbl)).KeyStatus)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EventType,
+            #[cfg(feature = "System")]
+            VirtualKey,
+            #[cfg(not(feature = "System"))]
+            VirtualKey: 0,
+            KeyStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAcceleratorKeyEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAcceleratorKeyEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAcceleratorKeyEventArgs2", "DeviceId", 6usize, |this| ((**(this as *const *const IAcceleratorKeyEventArgs2_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAcceleratorKeyEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAutomationProviderRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AutomationProvider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomationProviderRequestedEventArgs", "AutomationProvider", 6usize, |this| ((**(this as *const *const IAutomationProviderRequestedEventArgs_Vtbl)).AutomationProvider)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutomationProvider(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomationProviderRequestedEventArgs", "SetAutomationProvider", 7usize, |this| ((**(this as *const *const IAutomationProviderRequestedEventArgs_Vtbl)).SetAutomationProvider)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AutomationProvider, SetAutomationProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAutomationProviderRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackRequestedEventArgs", "Handled", 6usize, |this| ((**(this as *const *const IBackRequestedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackRequestedEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const IBackRequestedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICharacterReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KeyCode(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICharacterReceivedEventArgs", "KeyCode", 6usize, |this| ((**(this as *const *const ICharacterReceivedEventArgs_Vtbl)).KeyCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyStatus(this: *mut core::ffi::c_void, result__: *mut CorePhysicalKeyStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICharacterReceivedEventArgs", "KeyStatus", 7usize, |this| ((**(this as *const *const ICharacterReceivedEventArgs_Vtbl)).KeyStatus)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KeyCode, KeyStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICharacterReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClosestInteractiveBoundsRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PointerPosition(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosestInteractiveBoundsRequestedEventArgs", "PointerPosition", 6usize, |this| ((**(this as *const *const IClosestInteractiveBoundsRequestedEventArgs_Vtbl)).PointerPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchBounds(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosestInteractiveBoundsRequestedEventArgs", "SearchBounds", 7usize, |this| ((**(this as *const *const IClosestInteractiveBoundsRequestedEventArgs_Vtbl)).SearchBounds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClosestInteractiveBounds(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosestInteractiveBoundsRequestedEventArgs", "ClosestInteractiveBounds", 8usize, |this| ((**(this as *const *const IClosestInteractiveBoundsRequestedEventArgs_Vtbl)).ClosestInteractiveBounds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClosestInteractiveBounds(this: *mut core::ffi::c_void, value: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClosestInteractiveBoundsRequestedEventArgs", "SetClosestInteractiveBounds", 9usize, |this| ((**(this as *const *const IClosestInteractiveBoundsRequestedEventArgs_Vtbl)).SetClosestInteractiveBounds)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PointerPosition,
+            SearchBounds,
+            ClosestInteractiveBounds,
+            SetClosestInteractiveBounds,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClosestInteractiveBoundsRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreAcceleratorKeys_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcceleratorKeyActivated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAcceleratorKeys", "AcceleratorKeyActivated", 6usize, |this| ((**(this as *const *const ICoreAcceleratorKeys_Vtbl)).AcceleratorKeyActivated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAcceleratorKeyActivated(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAcceleratorKeys", "RemoveAcceleratorKeyActivated", 7usize, |this| ((**(this as *const *const ICoreAcceleratorKeys_Vtbl)).RemoveAcceleratorKeyActivated)(this, cookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AcceleratorKeyActivated, RemoveAcceleratorKeyActivated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreAcceleratorKeys as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreClosestInteractiveBoundsRequested_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClosestInteractiveBoundsRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreClosestInteractiveBoundsRequested", "ClosestInteractiveBoundsRequested", 6usize, |this| ((**(this as *const *const ICoreClosestInteractiveBoundsRequested_Vtbl)).ClosestInteractiveBoundsRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosestInteractiveBoundsRequested(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreClosestInteractiveBoundsRequested", "RemoveClosestInteractiveBoundsRequested", 7usize, |this| ((**(this as *const *const ICoreClosestInteractiveBoundsRequested_Vtbl)).RemoveClosestInteractiveBoundsRequested)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ClosestInteractiveBoundsRequested,
+            RemoveClosestInteractiveBoundsRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreClosestInteractiveBoundsRequested as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreComponentFocusable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasFocus(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreComponentFocusable", "HasFocus", 6usize, |this| ((**(this as *const *const ICoreComponentFocusable_Vtbl)).HasFocus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GotFocus(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreComponentFocusable", "GotFocus", 7usize, |this| ((**(this as *const *const ICoreComponentFocusable_Vtbl)).GotFocus)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGotFocus(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreComponentFocusable", "RemoveGotFocus", 8usize, |this| ((**(this as *const *const ICoreComponentFocusable_Vtbl)).RemoveGotFocus)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn LostFocus(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreComponentFocusable", "LostFocus", 9usize, |this| ((**(this as *const *const ICoreComponentFocusable_Vtbl)).LostFocus)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLostFocus(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreComponentFocusable", "RemoveLostFocus", 10usize, |this| ((**(this as *const *const ICoreComponentFocusable_Vtbl)).RemoveLostFocus)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HasFocus,
+            GotFocus,
+            RemoveGotFocus,
+            LostFocus,
+            RemoveLostFocus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreComponentFocusable as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreCursor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreCursor", "Id", 6usize, |this| ((**(this as *const *const ICoreCursor_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut CoreCursorType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreCursor", "Type", 7usize, |this| ((**(this as *const *const ICoreCursor_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Type }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreCursor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreCursorFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateCursor(this: *mut core::ffi::c_void, r#type: CoreCursorType, id: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreCursorFactory", "CreateCursor", 6usize, |this| ((**(this as *const *const ICoreCursorFactory_Vtbl)).CreateCursor)(this, r#type, id, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateCursor }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreCursorFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreDispatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasThreadAccess(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDispatcher", "HasThreadAccess", 6usize, |this| ((**(this as *const *const ICoreDispatcher_Vtbl)).HasThreadAccess)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessEvents(this: *mut core::ffi::c_void, options: CoreProcessEventsOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDispatcher", "ProcessEvents", 7usize, |this| ((**(this as *const *const ICoreDispatcher_Vtbl)).ProcessEvents)(this, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunAsync(this: *mut core::ffi::c_void, priority: CoreDispatcherPriority, agilecallback: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDispatcher", "RunAsync", 8usize, |this| ((**(this as *const *const ICoreDispatcher_Vtbl)).RunAsync)(this, priority, agilecallback, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunIdleAsync(this: *mut core::ffi::c_void, agilecallback: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDispatcher", "RunIdleAsync", 9usize, |this| ((**(this as *const *const ICoreDispatcher_Vtbl)).RunIdleAsync)(this, agilecallback, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HasThreadAccess, ProcessEvents, RunAsync, RunIdleAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreDispatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreDispatcher2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryRunAsync(this: *mut core::ffi::c_void, priority: CoreDispatcherPriority, agilecallback: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDispatcher2", "TryRunAsync", 6usize, |this| ((**(this as *const *const ICoreDispatcher2_Vtbl)).TryRunAsync)(this, priority, agilecallback, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryRunIdleAsync(this: *mut core::ffi::c_void, agilecallback: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDispatcher2", "TryRunIdleAsync", 7usize, |this| ((**(this as *const *const ICoreDispatcher2_Vtbl)).TryRunIdleAsync)(this, agilecallback, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryRunAsync, TryRunIdleAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreDispatcher2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreDispatcherWithTaskPriority_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentPriority(this: *mut core::ffi::c_void, result__: *mut CoreDispatcherPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDispatcherWithTaskPriority", "CurrentPriority", 6usize, |this| ((**(this as *const *const ICoreDispatcherWithTaskPriority_Vtbl)).CurrentPriority)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentPriority(this: *mut core::ffi::c_void, value: CoreDispatcherPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDispatcherWithTaskPriority", "SetCurrentPriority", 7usize, |this| ((**(this as *const *const ICoreDispatcherWithTaskPriority_Vtbl)).SetCurrentPriority)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShouldYield(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDispatcherWithTaskPriority", "ShouldYield", 8usize, |this| ((**(this as *const *const ICoreDispatcherWithTaskPriority_Vtbl)).ShouldYield)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShouldYieldToPriority(this: *mut core::ffi::c_void, priority: CoreDispatcherPriority, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDispatcherWithTaskPriority", "ShouldYieldToPriority", 9usize, |this| ((**(this as *const *const ICoreDispatcherWithTaskPriority_Vtbl)).ShouldYieldToPriority)(this, priority, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopProcessEvents(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreDispatcherWithTaskPriority", "StopProcessEvents", 10usize, |this| ((**(this as *const *const ICoreDispatcherWithTaskPriority_Vtbl)).StopProcessEvents)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentPriority,
+            SetCurrentPriority,
+            ShouldYield,
+            ShouldYieldToPriority,
+            StopProcessEvents,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreDispatcherWithTaskPriority as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreIndependentInputSourceController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTransparentForUncontrolledInput(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreIndependentInputSourceController", "IsTransparentForUncontrolledInput", 6usize, |this| ((**(this as *const *const ICoreIndependentInputSourceController_Vtbl)).IsTransparentForUncontrolledInput)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsTransparentForUncontrolledInput(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreIndependentInputSourceController", "SetIsTransparentForUncontrolledInput", 7usize, |this| ((**(this as *const *const ICoreIndependentInputSourceController_Vtbl)).SetIsTransparentForUncontrolledInput)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPalmRejectionEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreIndependentInputSourceController", "IsPalmRejectionEnabled", 8usize, |this| ((**(this as *const *const ICoreIndependentInputSourceController_Vtbl)).IsPalmRejectionEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsPalmRejectionEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreIndependentInputSourceController", "SetIsPalmRejectionEnabled", 9usize, |this| ((**(this as *const *const ICoreIndependentInputSourceController_Vtbl)).SetIsPalmRejectionEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreIndependentInputSourceController", "Source", 10usize, |this| ((**(this as *const *const ICoreIndependentInputSourceController_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetControlledInput(this: *mut core::ffi::c_void, inputtypes: CoreInputDeviceTypes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreIndependentInputSourceController", "SetControlledInput", 11usize, |this| ((**(this as *const *const ICoreIndependentInputSourceController_Vtbl)).SetControlledInput)(this, inputtypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetControlledInputWithFilters(this: *mut core::ffi::c_void, inputtypes: CoreInputDeviceTypes, required: CoreIndependentInputFilters, excluded: CoreIndependentInputFilters) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreIndependentInputSourceController", "SetControlledInputWithFilters", 12usize, |this| ((**(this as *const *const ICoreIndependentInputSourceController_Vtbl)).SetControlledInputWithFilters)(this, inputtypes, required, excluded), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsTransparentForUncontrolledInput,
+            SetIsTransparentForUncontrolledInput,
+            IsPalmRejectionEnabled,
+            SetIsPalmRejectionEnabled,
+            Source,
+            SetControlledInput,
+            SetControlledInputWithFilters,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreIndependentInputSourceController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Composition")]
+impl windows_core::imp::ProxyVtable for ICoreIndependentInputSourceControllerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Composition")]
+        unsafe extern "system" fn CreateForVisual(this: *mut core::ffi::c_void, visual: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreIndependentInputSourceControllerStatics", "CreateForVisual", 6usize, |this| ((**(this as *const *const ICoreIndependentInputSourceControllerStatics_Vtbl)).CreateForVisual)(this, visual, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Composition")]
+        unsafe extern "system" fn CreateForIVisualElement(this: *mut core::ffi::c_void, visualelement: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreIndependentInputSourceControllerStatics", "CreateForIVisualElement", 7usize, |this| ((**(this as *const *const ICoreIndependentInputSourceControllerStatics_Vtbl)).CreateForIVisualElement)(this, visualelement, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Composition")]
+            CreateForVisual,
+            #[cfg(not(feature = "UI_Composition"))]
+            CreateForVisual: 0,
+            #[cfg(feature = "UI_Composition")]
+            CreateForIVisualElement,
+            #[cfg(not(feature = "UI_Composition"))]
+            CreateForIVisualElement: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreIndependentInputSourceControllerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreInputSourceBase_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Dispatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputSourceBase", "Dispatcher", 6usize, |this| ((**(this as *const *const ICoreInputSourceBase_Vtbl)).Dispatcher)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInputEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputSourceBase", "IsInputEnabled", 7usize, |this| ((**(this as *const *const ICoreInputSourceBase_Vtbl)).IsInputEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsInputEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputSourceBase", "SetIsInputEnabled", 8usize, |this| ((**(this as *const *const ICoreInputSourceBase_Vtbl)).SetIsInputEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputEnabled(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputSourceBase", "InputEnabled", 9usize, |this| ((**(this as *const *const ICoreInputSourceBase_Vtbl)).InputEnabled)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveInputEnabled(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputSourceBase", "RemoveInputEnabled", 10usize, |this| ((**(this as *const *const ICoreInputSourceBase_Vtbl)).RemoveInputEnabled)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Dispatcher,
+            IsInputEnabled,
+            SetIsInputEnabled,
+            InputEnabled,
+            RemoveInputEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputSourceBase as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for ICoreKeyboardInputSource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetCurrentKeyState(this: *mut core::ffi::c_void, virtualkey: super::super::System::VirtualKey, result__: *mut CoreVirtualKeyStates) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreKeyboardInputSource", "GetCurrentKeyState", 6usize, |this| ((**(this as *const *const ICoreKeyboardInputSource_Vtbl)).GetCurrentKeyState)(this, virtualkey, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CharacterReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreKeyboardInputSource", "CharacterReceived", 7usize, |this| ((**(this as *const *const ICoreKeyboardInputSource_Vtbl)).CharacterReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCharacterReceived(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreKeyboardInputSource", "RemoveCharacterReceived", 8usize, |this| ((**(this as *const *const ICoreKeyboardInputSource_Vtbl)).RemoveCharacterReceived)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyDown(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreKeyboardInputSource", "KeyDown", 9usize, |this| ((**(this as *const *const ICoreKeyboardInputSource_Vtbl)).KeyDown)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveKeyDown(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreKeyboardInputSource", "RemoveKeyDown", 10usize, |this| ((**(this as *const *const ICoreKeyboardInputSource_Vtbl)).RemoveKeyDown)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyUp(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreKeyboardInputSource", "KeyUp", 11usize, |this| ((**(this as *const *const ICoreKeyboardInputSource_Vtbl)).KeyUp)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveKeyUp(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreKeyboardInputSource", "RemoveKeyUp", 12usize, |this| ((**(this as *const *const ICoreKeyboardInputSource_Vtbl)).RemoveKeyUp)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetCurrentKeyState,
+            #[cfg(not(feature = "System"))]
+            GetCurrentKeyState: 0,
+            CharacterReceived,
+            RemoveCharacterReceived,
+            KeyDown,
+            RemoveKeyDown,
+            KeyUp,
+            RemoveKeyUp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreKeyboardInputSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreKeyboardInputSource2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentKeyEventDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreKeyboardInputSource2", "GetCurrentKeyEventDeviceId", 6usize, |this| ((**(this as *const *const ICoreKeyboardInputSource2_Vtbl)).GetCurrentKeyEventDeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrentKeyEventDeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreKeyboardInputSource2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorePointerInputSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReleasePointerCapture(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "ReleasePointerCapture", 6usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).ReleasePointerCapture)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPointerCapture(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "SetPointerCapture", 7usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).SetPointerCapture)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasCapture(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "HasCapture", 8usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).HasCapture)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerPosition(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "PointerPosition", 9usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).PointerPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerCursor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "PointerCursor", 10usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).PointerCursor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPointerCursor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "SetPointerCursor", 11usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).SetPointerCursor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerCaptureLost(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "PointerCaptureLost", 12usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).PointerCaptureLost)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerCaptureLost(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "RemovePointerCaptureLost", 13usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).RemovePointerCaptureLost)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerEntered(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "PointerEntered", 14usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).PointerEntered)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerEntered(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "RemovePointerEntered", 15usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).RemovePointerEntered)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerExited(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "PointerExited", 16usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).PointerExited)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerExited(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "RemovePointerExited", 17usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).RemovePointerExited)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerMoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "PointerMoved", 18usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).PointerMoved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerMoved(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "RemovePointerMoved", 19usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).RemovePointerMoved)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "PointerPressed", 20usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).PointerPressed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerPressed(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "RemovePointerPressed", 21usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).RemovePointerPressed)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerReleased(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "PointerReleased", 22usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).PointerReleased)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerReleased(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "RemovePointerReleased", 23usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).RemovePointerReleased)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerWheelChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "PointerWheelChanged", 24usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).PointerWheelChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerWheelChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource", "RemovePointerWheelChanged", 25usize, |this| ((**(this as *const *const ICorePointerInputSource_Vtbl)).RemovePointerWheelChanged)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReleasePointerCapture,
+            SetPointerCapture,
+            HasCapture,
+            PointerPosition,
+            PointerCursor,
+            SetPointerCursor,
+            PointerCaptureLost,
+            RemovePointerCaptureLost,
+            PointerEntered,
+            RemovePointerEntered,
+            PointerExited,
+            RemovePointerExited,
+            PointerMoved,
+            RemovePointerMoved,
+            PointerPressed,
+            RemovePointerPressed,
+            PointerReleased,
+            RemovePointerReleased,
+            PointerWheelChanged,
+            RemovePointerWheelChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorePointerInputSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for ICorePointerInputSource2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn DispatcherQueue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerInputSource2", "DispatcherQueue", 6usize, |this| ((**(this as *const *const ICorePointerInputSource2_Vtbl)).DispatcherQueue)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            DispatcherQueue,
+            #[cfg(not(feature = "System"))]
+            DispatcherQueue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorePointerInputSource2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorePointerRedirector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PointerRoutedAway(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerRedirector", "PointerRoutedAway", 6usize, |this| ((**(this as *const *const ICorePointerRedirector_Vtbl)).PointerRoutedAway)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerRoutedAway(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerRedirector", "RemovePointerRoutedAway", 7usize, |this| ((**(this as *const *const ICorePointerRedirector_Vtbl)).RemovePointerRoutedAway)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerRoutedTo(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerRedirector", "PointerRoutedTo", 8usize, |this| ((**(this as *const *const ICorePointerRedirector_Vtbl)).PointerRoutedTo)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerRoutedTo(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerRedirector", "RemovePointerRoutedTo", 9usize, |this| ((**(this as *const *const ICorePointerRedirector_Vtbl)).RemovePointerRoutedTo)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerRoutedReleased(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerRedirector", "PointerRoutedReleased", 10usize, |this| ((**(this as *const *const ICorePointerRedirector_Vtbl)).PointerRoutedReleased)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerRoutedReleased(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorePointerRedirector", "RemovePointerRoutedReleased", 11usize, |this| ((**(this as *const *const ICorePointerRedirector_Vtbl)).RemovePointerRoutedReleased)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PointerRoutedAway,
+            RemovePointerRoutedAway,
+            PointerRoutedTo,
+            RemovePointerRoutedTo,
+            PointerRoutedReleased,
+            RemovePointerRoutedReleased,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorePointerRedirector as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTouchHitTesting_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TouchHitTesting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTouchHitTesting", "TouchHitTesting", 6usize, |this| ((**(this as *const *const ICoreTouchHitTesting_Vtbl)).TouchHitTesting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTouchHitTesting(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTouchHitTesting", "RemoveTouchHitTesting", 7usize, |this| ((**(this as *const *const ICoreTouchHitTesting_Vtbl)).RemoveTouchHitTesting)(this, cookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TouchHitTesting, RemoveTouchHitTesting }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTouchHitTesting as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+impl windows_core::imp::ProxyVtable for ICoreWindow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AutomationHostProvider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "AutomationHostProvider", 6usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).AutomationHostProvider)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bounds(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "Bounds", 7usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).Bounds)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CustomProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "CustomProperties", 8usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).CustomProperties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Dispatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "Dispatcher", 9usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).Dispatcher)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlowDirection(this: *mut core::ffi::c_void, result__: *mut CoreWindowFlowDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "FlowDirection", 10usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).FlowDirection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlowDirection(this: *mut core::ffi::c_void, value: CoreWindowFlowDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "SetFlowDirection", 11usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).SetFlowDirection)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInputEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "IsInputEnabled", 12usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).IsInputEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsInputEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "SetIsInputEnabled", 13usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).SetIsInputEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerCursor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "PointerCursor", 14usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).PointerCursor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPointerCursor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "SetPointerCursor", 15usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).SetPointerCursor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerPosition(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "PointerPosition", 16usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).PointerPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Visible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "Visible", 17usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).Visible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "Activate", 18usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).Activate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "Close", 19usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetAsyncKeyState(this: *mut core::ffi::c_void, virtualkey: super::super::System::VirtualKey, result__: *mut CoreVirtualKeyStates) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "GetAsyncKeyState", 20usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).GetAsyncKeyState)(this, virtualkey, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetKeyState(this: *mut core::ffi::c_void, virtualkey: super::super::System::VirtualKey, result__: *mut CoreVirtualKeyStates) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "GetKeyState", 21usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).GetKeyState)(this, virtualkey, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleasePointerCapture(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "ReleasePointerCapture", 22usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).ReleasePointerCapture)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPointerCapture(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "SetPointerCapture", 23usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).SetPointerCapture)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Activated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "Activated", 24usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).Activated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveActivated(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemoveActivated", 25usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemoveActivated)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutomationProviderRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "AutomationProviderRequested", 26usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).AutomationProviderRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAutomationProviderRequested(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemoveAutomationProviderRequested", 27usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemoveAutomationProviderRequested)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn CharacterReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "CharacterReceived", 28usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).CharacterReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCharacterReceived(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemoveCharacterReceived", 29usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemoveCharacterReceived)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "Closed", 30usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).Closed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemoveClosed", 31usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemoveClosed)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputEnabled(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "InputEnabled", 32usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).InputEnabled)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveInputEnabled(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemoveInputEnabled", 33usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemoveInputEnabled)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyDown(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "KeyDown", 34usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).KeyDown)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveKeyDown(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemoveKeyDown", 35usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemoveKeyDown)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyUp(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "KeyUp", 36usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).KeyUp)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveKeyUp(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemoveKeyUp", 37usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemoveKeyUp)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerCaptureLost(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "PointerCaptureLost", 38usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).PointerCaptureLost)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerCaptureLost(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemovePointerCaptureLost", 39usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemovePointerCaptureLost)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerEntered(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "PointerEntered", 40usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).PointerEntered)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerEntered(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemovePointerEntered", 41usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemovePointerEntered)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerExited(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "PointerExited", 42usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).PointerExited)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerExited(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemovePointerExited", 43usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemovePointerExited)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerMoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "PointerMoved", 44usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).PointerMoved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerMoved(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemovePointerMoved", 45usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemovePointerMoved)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "PointerPressed", 46usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).PointerPressed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerPressed(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemovePointerPressed", 47usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemovePointerPressed)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerReleased(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "PointerReleased", 48usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).PointerReleased)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerReleased(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemovePointerReleased", 49usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemovePointerReleased)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn TouchHitTesting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "TouchHitTesting", 50usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).TouchHitTesting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTouchHitTesting(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemoveTouchHitTesting", 51usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemoveTouchHitTesting)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerWheelChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "PointerWheelChanged", 52usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).PointerWheelChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerWheelChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemovePointerWheelChanged", 53usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemovePointerWheelChanged)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "SizeChanged", 54usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).SizeChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSizeChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemoveSizeChanged", 55usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemoveSizeChanged)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn VisibilityChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "VisibilityChanged", 56usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).VisibilityChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVisibilityChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow", "RemoveVisibilityChanged", 57usize, |this| ((**(this as *const *const ICoreWindow_Vtbl)).RemoveVisibilityChanged)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AutomationHostProvider,
+            Bounds,
+            #[cfg(feature = "Foundation_Collections")]
+            CustomProperties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CustomProperties: 0,
+            Dispatcher,
+            FlowDirection,
+            SetFlowDirection,
+            IsInputEnabled,
+            SetIsInputEnabled,
+            PointerCursor,
+            SetPointerCursor,
+            PointerPosition,
+            Visible,
+            Activate,
+            Close,
+            #[cfg(feature = "System")]
+            GetAsyncKeyState,
+            #[cfg(not(feature = "System"))]
+            GetAsyncKeyState: 0,
+            #[cfg(feature = "System")]
+            GetKeyState,
+            #[cfg(not(feature = "System"))]
+            GetKeyState: 0,
+            ReleasePointerCapture,
+            SetPointerCapture,
+            Activated,
+            RemoveActivated,
+            AutomationProviderRequested,
+            RemoveAutomationProviderRequested,
+            CharacterReceived,
+            RemoveCharacterReceived,
+            Closed,
+            RemoveClosed,
+            InputEnabled,
+            RemoveInputEnabled,
+            KeyDown,
+            RemoveKeyDown,
+            KeyUp,
+            RemoveKeyUp,
+            PointerCaptureLost,
+            RemovePointerCaptureLost,
+            PointerEntered,
+            RemovePointerEntered,
+            PointerExited,
+            RemovePointerExited,
+            PointerMoved,
+            RemovePointerMoved,
+            PointerPressed,
+            RemovePointerPressed,
+            PointerReleased,
+            RemovePointerReleased,
+            TouchHitTesting,
+            RemoveTouchHitTesting,
+            PointerWheelChanged,
+            RemovePointerWheelChanged,
+            SizeChanged,
+            RemoveSizeChanged,
+            VisibilityChanged,
+            RemoveVisibilityChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindow as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWindow2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPointerPosition(this: *mut core::ffi::c_void, value: super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow2", "SetPointerPosition", 6usize, |this| ((**(this as *const *const ICoreWindow2_Vtbl)).SetPointerPosition)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPointerPosition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindow2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWindow3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClosestInteractiveBoundsRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow3", "ClosestInteractiveBoundsRequested", 6usize, |this| ((**(this as *const *const ICoreWindow3_Vtbl)).ClosestInteractiveBoundsRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosestInteractiveBoundsRequested(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow3", "RemoveClosestInteractiveBoundsRequested", 7usize, |this| ((**(this as *const *const ICoreWindow3_Vtbl)).RemoveClosestInteractiveBoundsRequested)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentKeyEventDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow3", "GetCurrentKeyEventDeviceId", 8usize, |this| ((**(this as *const *const ICoreWindow3_Vtbl)).GetCurrentKeyEventDeviceId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ClosestInteractiveBoundsRequested,
+            RemoveClosestInteractiveBoundsRequested,
+            GetCurrentKeyEventDeviceId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindow3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWindow4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResizeStarted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow4", "ResizeStarted", 6usize, |this| ((**(this as *const *const ICoreWindow4_Vtbl)).ResizeStarted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveResizeStarted(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow4", "RemoveResizeStarted", 7usize, |this| ((**(this as *const *const ICoreWindow4_Vtbl)).RemoveResizeStarted)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResizeCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow4", "ResizeCompleted", 8usize, |this| ((**(this as *const *const ICoreWindow4_Vtbl)).ResizeCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveResizeCompleted(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow4", "RemoveResizeCompleted", 9usize, |this| ((**(this as *const *const ICoreWindow4_Vtbl)).RemoveResizeCompleted)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ResizeStarted,
+            RemoveResizeStarted,
+            ResizeCompleted,
+            RemoveResizeCompleted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindow4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for ICoreWindow5_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn DispatcherQueue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow5", "DispatcherQueue", 6usize, |this| ((**(this as *const *const ICoreWindow5_Vtbl)).DispatcherQueue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivationMode(this: *mut core::ffi::c_void, result__: *mut CoreWindowActivationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindow5", "ActivationMode", 7usize, |this| ((**(this as *const *const ICoreWindow5_Vtbl)).ActivationMode)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            DispatcherQueue,
+            #[cfg(not(feature = "System"))]
+            DispatcherQueue: 0,
+            ActivationMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindow5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "UI_Popups"))]
+impl windows_core::imp::ProxyVtable for ICoreWindowDialog_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Showing(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "Showing", 6usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).Showing)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveShowing(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "RemoveShowing", 7usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).RemoveShowing)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "MaxSize", 8usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).MaxSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "MinSize", 9usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).MinSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "Title", 10usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "SetTitle", 11usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInteractionDelayed(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "IsInteractionDelayed", 12usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).IsInteractionDelayed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsInteractionDelayed(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "SetIsInteractionDelayed", 13usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).SetIsInteractionDelayed)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "UI_Popups"))]
+        unsafe extern "system" fn Commands(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "Commands", 14usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).Commands)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultCommandIndex(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "DefaultCommandIndex", 15usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).DefaultCommandIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultCommandIndex(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "SetDefaultCommandIndex", 16usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).SetDefaultCommandIndex)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelCommandIndex(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "CancelCommandIndex", 17usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).CancelCommandIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCancelCommandIndex(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "SetCancelCommandIndex", 18usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).SetCancelCommandIndex)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn BackButtonCommand(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "BackButtonCommand", 19usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).BackButtonCommand)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn SetBackButtonCommand(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "SetBackButtonCommand", 20usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).SetBackButtonCommand)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialog", "ShowAsync", 21usize, |this| ((**(this as *const *const ICoreWindowDialog_Vtbl)).ShowAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Showing,
+            RemoveShowing,
+            MaxSize,
+            MinSize,
+            Title,
+            SetTitle,
+            IsInteractionDelayed,
+            SetIsInteractionDelayed,
+            #[cfg(all(feature = "Foundation_Collections", feature = "UI_Popups"))]
+            Commands,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "UI_Popups")))]
+            Commands: 0,
+            DefaultCommandIndex,
+            SetDefaultCommandIndex,
+            CancelCommandIndex,
+            SetCancelCommandIndex,
+            #[cfg(feature = "UI_Popups")]
+            BackButtonCommand,
+            #[cfg(not(feature = "UI_Popups"))]
+            BackButtonCommand: 0,
+            #[cfg(feature = "UI_Popups")]
+            SetBackButtonCommand,
+            #[cfg(not(feature = "UI_Popups"))]
+            SetBackButtonCommand: 0,
+            #[cfg(feature = "UI_Popups")]
+            ShowAsync,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindowDialog as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWindowDialogFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithTitle(this: *mut core::ffi::c_void, title: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowDialogFactory", "CreateWithTitle", 6usize, |this| ((**(this as *const *const ICoreWindowDialogFactory_Vtbl)).CreateWithTitle)(this, title, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithTitle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindowDialogFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWindowEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowEventArgs", "Handled", 6usize, |this| ((**(this as *const *const ICoreWindowEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowEventArgs", "SetHandled", 7usize, |this| ((**(this as *const *const ICoreWindowEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handled, SetHandled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindowEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "UI_Popups"))]
+impl windows_core::imp::ProxyVtable for ICoreWindowFlyout_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Showing(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyout", "Showing", 6usize, |this| ((**(this as *const *const ICoreWindowFlyout_Vtbl)).Showing)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveShowing(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyout", "RemoveShowing", 7usize, |this| ((**(this as *const *const ICoreWindowFlyout_Vtbl)).RemoveShowing)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyout", "MaxSize", 8usize, |this| ((**(this as *const *const ICoreWindowFlyout_Vtbl)).MaxSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyout", "MinSize", 9usize, |this| ((**(this as *const *const ICoreWindowFlyout_Vtbl)).MinSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyout", "Title", 10usize, |this| ((**(this as *const *const ICoreWindowFlyout_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyout", "SetTitle", 11usize, |this| ((**(this as *const *const ICoreWindowFlyout_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInteractionDelayed(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyout", "IsInteractionDelayed", 12usize, |this| ((**(this as *const *const ICoreWindowFlyout_Vtbl)).IsInteractionDelayed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsInteractionDelayed(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyout", "SetIsInteractionDelayed", 13usize, |this| ((**(this as *const *const ICoreWindowFlyout_Vtbl)).SetIsInteractionDelayed)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "UI_Popups"))]
+        unsafe extern "system" fn Commands(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyout", "Commands", 14usize, |this| ((**(this as *const *const ICoreWindowFlyout_Vtbl)).Commands)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultCommandIndex(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyout", "DefaultCommandIndex", 15usize, |this| ((**(this as *const *const ICoreWindowFlyout_Vtbl)).DefaultCommandIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultCommandIndex(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyout", "SetDefaultCommandIndex", 16usize, |this| ((**(this as *const *const ICoreWindowFlyout_Vtbl)).SetDefaultCommandIndex)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn BackButtonCommand(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyout", "BackButtonCommand", 17usize, |this| ((**(this as *const *const ICoreWindowFlyout_Vtbl)).BackButtonCommand)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn SetBackButtonCommand(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyout", "SetBackButtonCommand", 18usize, |this| ((**(this as *const *const ICoreWindowFlyout_Vtbl)).SetBackButtonCommand)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn ShowAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyout", "ShowAsync", 19usize, |this| ((**(this as *const *const ICoreWindowFlyout_Vtbl)).ShowAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Showing,
+            RemoveShowing,
+            MaxSize,
+            MinSize,
+            Title,
+            SetTitle,
+            IsInteractionDelayed,
+            SetIsInteractionDelayed,
+            #[cfg(all(feature = "Foundation_Collections", feature = "UI_Popups"))]
+            Commands,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "UI_Popups")))]
+            Commands: 0,
+            DefaultCommandIndex,
+            SetDefaultCommandIndex,
+            #[cfg(feature = "UI_Popups")]
+            BackButtonCommand,
+            #[cfg(not(feature = "UI_Popups"))]
+            BackButtonCommand: 0,
+            #[cfg(feature = "UI_Popups")]
+            SetBackButtonCommand,
+            #[cfg(not(feature = "UI_Popups"))]
+            SetBackButtonCommand: 0,
+            #[cfg(feature = "UI_Popups")]
+            ShowAsync,
+            #[cfg(not(feature = "UI_Popups"))]
+            ShowAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindowFlyout as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWindowFlyoutFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, position: super::super::Foundation::Point, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyoutFactory", "Create", 6usize, |this| ((**(this as *const *const ICoreWindowFlyoutFactory_Vtbl)).Create)(this, position, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithTitle(this: *mut core::ffi::c_void, position: super::super::Foundation::Point, title: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowFlyoutFactory", "CreateWithTitle", 7usize, |this| ((**(this as *const *const ICoreWindowFlyoutFactory_Vtbl)).CreateWithTitle)(this, position, title, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithTitle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindowFlyoutFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWindowPopupShowingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDesiredSize(this: *mut core::ffi::c_void, value: super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowPopupShowingEventArgs", "SetDesiredSize", 6usize, |this| ((**(this as *const *const ICoreWindowPopupShowingEventArgs_Vtbl)).SetDesiredSize)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDesiredSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindowPopupShowingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWindowResizeManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifyLayoutCompleted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowResizeManager", "NotifyLayoutCompleted", 6usize, |this| ((**(this as *const *const ICoreWindowResizeManager_Vtbl)).NotifyLayoutCompleted)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NotifyLayoutCompleted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindowResizeManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWindowResizeManagerLayoutCapability_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetShouldWaitForLayoutCompletion(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowResizeManagerLayoutCapability", "SetShouldWaitForLayoutCompletion", 6usize, |this| ((**(this as *const *const ICoreWindowResizeManagerLayoutCapability_Vtbl)).SetShouldWaitForLayoutCompletion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShouldWaitForLayoutCompletion(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowResizeManagerLayoutCapability", "ShouldWaitForLayoutCompletion", 7usize, |this| ((**(this as *const *const ICoreWindowResizeManagerLayoutCapability_Vtbl)).ShouldWaitForLayoutCompletion)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetShouldWaitForLayoutCompletion,
+            ShouldWaitForLayoutCompletion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindowResizeManagerLayoutCapability as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWindowResizeManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowResizeManagerStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const ICoreWindowResizeManagerStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindowResizeManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWindowStatic_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentThread(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowStatic", "GetForCurrentThread", 6usize, |this| ((**(this as *const *const ICoreWindowStatic_Vtbl)).GetForCurrentThread)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentThread }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindowStatic as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWindowWithContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UIContext(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowWithContext", "UIContext", 6usize, |this| ((**(this as *const *const ICoreWindowWithContext_Vtbl)).UIContext)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UIContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindowWithContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIdleDispatchedHandlerArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDispatcherIdle(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdleDispatchedHandlerArgs", "IsDispatcherIdle", 6usize, |this| ((**(this as *const *const IIdleDispatchedHandlerArgs_Vtbl)).IsDispatcherIdle)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDispatcherIdle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIdleDispatchedHandlerArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInitializeWithCoreWindow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, window: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInitializeWithCoreWindow", "Initialize", 6usize, |this| ((**(this as *const *const IInitializeWithCoreWindow_Vtbl)).Initialize)(this, window), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInitializeWithCoreWindow as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInputEnabledEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InputEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputEnabledEventArgs", "InputEnabled", 6usize, |this| ((**(this as *const *const IInputEnabledEventArgs_Vtbl)).InputEnabled)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InputEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputEnabledEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IKeyEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn VirtualKey(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyEventArgs", "VirtualKey", 6usize, |this| ((**(this as *const *const IKeyEventArgs_Vtbl)).VirtualKey)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyStatus(this: *mut core::ffi::c_void, result__: *mut CorePhysicalKeyStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyEventArgs", "KeyStatus", 7usize, |this| ((**(this as *const *const IKeyEventArgs_Vtbl)).KeyStatus)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            VirtualKey,
+            #[cfg(not(feature = "System"))]
+            VirtualKey: 0,
+            KeyStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyEventArgs2", "DeviceId", 6usize, |this| ((**(this as *const *const IKeyEventArgs2_Vtbl)).DeviceId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "System", feature = "UI_Input"))]
+impl windows_core::imp::ProxyVtable for IPointerEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Input")]
+        unsafe extern "system" fn CurrentPoint(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerEventArgs", "CurrentPoint", 6usize, |this| ((**(this as *const *const IPointerEventArgs_Vtbl)).CurrentPoint)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn KeyModifiers(this: *mut core::ffi::c_void, result__: *mut super::super::System::VirtualKeyModifiers) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerEventArgs", "KeyModifiers", 7usize, |this| ((**(this as *const *const IPointerEventArgs_Vtbl)).KeyModifiers)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "UI_Input"))]
+        unsafe extern "system" fn GetIntermediatePoints(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerEventArgs", "GetIntermediatePoints", 8usize, |this| ((**(this as *const *const IPointerEventArgs_Vtbl)).GetIntermediatePoints)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Input")]
+            CurrentPoint,
+            #[cfg(not(feature = "UI_Input"))]
+            CurrentPoint: 0,
+            #[cfg(feature = "System")]
+            KeyModifiers,
+            #[cfg(not(feature = "System"))]
+            KeyModifiers: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "UI_Input"))]
+            GetIntermediatePoints,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "UI_Input")))]
+            GetIntermediatePoints: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPointerEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemNavigationManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BackRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemNavigationManager", "BackRequested", 6usize, |this| ((**(this as *const *const ISystemNavigationManager_Vtbl)).BackRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBackRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemNavigationManager", "RemoveBackRequested", 7usize, |this| ((**(this as *const *const ISystemNavigationManager_Vtbl)).RemoveBackRequested)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BackRequested, RemoveBackRequested }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemNavigationManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemNavigationManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppViewBackButtonVisibility(this: *mut core::ffi::c_void, result__: *mut AppViewBackButtonVisibility) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemNavigationManager2", "AppViewBackButtonVisibility", 6usize, |this| ((**(this as *const *const ISystemNavigationManager2_Vtbl)).AppViewBackButtonVisibility)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppViewBackButtonVisibility(this: *mut core::ffi::c_void, value: AppViewBackButtonVisibility) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemNavigationManager2", "SetAppViewBackButtonVisibility", 7usize, |this| ((**(this as *const *const ISystemNavigationManager2_Vtbl)).SetAppViewBackButtonVisibility)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AppViewBackButtonVisibility, SetAppViewBackButtonVisibility }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemNavigationManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemNavigationManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemNavigationManagerStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const ISystemNavigationManagerStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemNavigationManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITouchHitTestingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProximityEvaluation(this: *mut core::ffi::c_void, result__: *mut CoreProximityEvaluation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITouchHitTestingEventArgs", "ProximityEvaluation", 6usize, |this| ((**(this as *const *const ITouchHitTestingEventArgs_Vtbl)).ProximityEvaluation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProximityEvaluation(this: *mut core::ffi::c_void, value: CoreProximityEvaluation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITouchHitTestingEventArgs", "SetProximityEvaluation", 7usize, |this| ((**(this as *const *const ITouchHitTestingEventArgs_Vtbl)).SetProximityEvaluation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Point(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITouchHitTestingEventArgs", "Point", 8usize, |this| ((**(this as *const *const ITouchHitTestingEventArgs_Vtbl)).Point)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BoundingBox(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITouchHitTestingEventArgs", "BoundingBox", 9usize, |this| ((**(this as *const *const ITouchHitTestingEventArgs_Vtbl)).BoundingBox)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EvaluateProximityToRect(this: *mut core::ffi::c_void, controlboundingbox: super::super::Foundation::Rect, result__: *mut CoreProximityEvaluation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITouchHitTestingEventArgs", "EvaluateProximityToRect", 10usize, |this| ((**(this as *const *const ITouchHitTestingEventArgs_Vtbl)).EvaluateProximityToRect)(this, controlboundingbox, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EvaluateProximityToPolygon(this: *mut core::ffi::c_void, controlVertices_array_size: u32, controlvertices: *const super::super::Foundation::Point, result__: *mut CoreProximityEvaluation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITouchHitTestingEventArgs", "EvaluateProximityToPolygon", 11usize, |this| ((**(this as *const *const ITouchHitTestingEventArgs_Vtbl)).EvaluateProximityToPolygon)(this, controlVertices_array_size, controlvertices, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProximityEvaluation,
+            SetProximityEvaluation,
+            Point,
+            BoundingBox,
+            EvaluateProximityToRect,
+            EvaluateProximityToPolygon,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITouchHitTestingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVisibilityChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Visible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisibilityChangedEventArgs", "Visible", 6usize, |this| ((**(this as *const *const IVisibilityChangedEventArgs_Vtbl)).Visible)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Visible }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisibilityChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WindowActivationState(this: *mut core::ffi::c_void, result__: *mut CoreWindowActivationState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowActivatedEventArgs", "WindowActivationState", 6usize, |this| ((**(this as *const *const IWindowActivatedEventArgs_Vtbl)).WindowActivationState)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WindowActivationState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowSizeChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowSizeChangedEventArgs", "Size", 6usize, |this| ((**(this as *const *const IWindowSizeChangedEventArgs_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Size }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowSizeChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -91,3 +91,5 @@ impl windows_core::RuntimeName for RadialControllerIndependentInputSource {
 }
 unsafe impl Send for RadialControllerIndependentInputSource {}
 unsafe impl Sync for RadialControllerIndependentInputSource {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,64 @@
+#[cfg(feature = "UI_Core")]
+impl windows_core::imp::ProxyVtable for IRadialControllerIndependentInputSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Controller(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerIndependentInputSource", "Controller", 6usize, |this| ((**(this as *const *const IRadialControllerIndependentInputSource_Vtbl)).Controller)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn Dispatcher(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerIndependentInputSource", "Dispatcher", 7usize, |this| ((**(this as *const *const IRadialControllerIndependentInputSource_Vtbl)).Dispatcher)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Controller,
+            #[cfg(feature = "UI_Core")]
+            Dispatcher,
+            #[cfg(not(feature = "UI_Core"))]
+            Dispatcher: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerIndependentInputSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IRadialControllerIndependentInputSource2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn DispatcherQueue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerIndependentInputSource2", "DispatcherQueue", 6usize, |this| ((**(this as *const *const IRadialControllerIndependentInputSource2_Vtbl)).DispatcherQueue)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            DispatcherQueue,
+            #[cfg(not(feature = "System"))]
+            DispatcherQueue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerIndependentInputSource2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Core")]
+impl windows_core::imp::ProxyVtable for IRadialControllerIndependentInputSourceStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_Core")]
+        unsafe extern "system" fn CreateForView(this: *mut core::ffi::c_void, view: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerIndependentInputSourceStatics", "CreateForView", 6usize, |this| ((**(this as *const *const IRadialControllerIndependentInputSourceStatics_Vtbl)).CreateForView)(this, view, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_Core")]
+            CreateForView,
+            #[cfg(not(feature = "ApplicationModel_Core"))]
+            CreateForView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerIndependentInputSourceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
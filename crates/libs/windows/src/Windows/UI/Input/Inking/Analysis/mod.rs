@@ -1096,6 +1096,7 @@ impl InkAnalysisDrawingKind {
 impl windows_core::TypeKind for InkAnalysisDrawingKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkAnalysisDrawingKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkAnalysisDrawingKind").field(&self.0).finish()
@@ -1121,6 +1122,7 @@ impl InkAnalysisNodeKind {
 impl windows_core::TypeKind for InkAnalysisNodeKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkAnalysisNodeKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkAnalysisNodeKind").field(&self.0).finish()
@@ -1139,6 +1141,7 @@ impl InkAnalysisStatus {
 impl windows_core::TypeKind for InkAnalysisStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkAnalysisStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkAnalysisStatus").field(&self.0).finish()
@@ -1158,6 +1161,7 @@ impl InkAnalysisStrokeKind {
 impl windows_core::TypeKind for InkAnalysisStrokeKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkAnalysisStrokeKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkAnalysisStrokeKind").field(&self.0).finish()
@@ -1168,3 +1172,5 @@ impl windows_core::RuntimeType for InkAnalysisStrokeKind {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
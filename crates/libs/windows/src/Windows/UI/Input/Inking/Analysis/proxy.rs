@@ -0,0 +1,280 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkAnalysisInkBullet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RecognizedText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisInkBullet", "RecognizedText", 6usize, |this| ((**(this as *const *const IInkAnalysisInkBullet_Vtbl)).RecognizedText)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RecognizedText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkAnalysisInkBullet as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkAnalysisInkDrawing_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DrawingKind(this: *mut core::ffi::c_void, result__: *mut InkAnalysisDrawingKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisInkDrawing", "DrawingKind", 6usize, |this| ((**(this as *const *const IInkAnalysisInkDrawing_Vtbl)).DrawingKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Center(this: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisInkDrawing", "Center", 7usize, |this| ((**(this as *const *const IInkAnalysisInkDrawing_Vtbl)).Center)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Points(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisInkDrawing", "Points", 8usize, |this| ((**(this as *const *const IInkAnalysisInkDrawing_Vtbl)).Points)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DrawingKind,
+            Center,
+            #[cfg(feature = "Foundation_Collections")]
+            Points,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Points: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkAnalysisInkDrawing as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkAnalysisInkWord_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RecognizedText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisInkWord", "RecognizedText", 6usize, |this| ((**(this as *const *const IInkAnalysisInkWord_Vtbl)).RecognizedText)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn TextAlternates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisInkWord", "TextAlternates", 7usize, |this| ((**(this as *const *const IInkAnalysisInkWord_Vtbl)).TextAlternates)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RecognizedText,
+            #[cfg(feature = "Foundation_Collections")]
+            TextAlternates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            TextAlternates: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkAnalysisInkWord as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkAnalysisLine_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RecognizedText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisLine", "RecognizedText", 6usize, |this| ((**(this as *const *const IInkAnalysisLine_Vtbl)).RecognizedText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IndentLevel(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisLine", "IndentLevel", 7usize, |this| ((**(this as *const *const IInkAnalysisLine_Vtbl)).IndentLevel)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RecognizedText, IndentLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkAnalysisLine as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkAnalysisListItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RecognizedText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisListItem", "RecognizedText", 6usize, |this| ((**(this as *const *const IInkAnalysisListItem_Vtbl)).RecognizedText)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RecognizedText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkAnalysisListItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkAnalysisNode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisNode", "Id", 6usize, |this| ((**(this as *const *const IInkAnalysisNode_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut InkAnalysisNodeKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisNode", "Kind", 7usize, |this| ((**(this as *const *const IInkAnalysisNode_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BoundingRect(this: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisNode", "BoundingRect", 8usize, |this| ((**(this as *const *const IInkAnalysisNode_Vtbl)).BoundingRect)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RotatedBoundingRect(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisNode", "RotatedBoundingRect", 9usize, |this| ((**(this as *const *const IInkAnalysisNode_Vtbl)).RotatedBoundingRect)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Children(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisNode", "Children", 10usize, |this| ((**(this as *const *const IInkAnalysisNode_Vtbl)).Children)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisNode", "Parent", 11usize, |this| ((**(this as *const *const IInkAnalysisNode_Vtbl)).Parent)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetStrokeIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisNode", "GetStrokeIds", 12usize, |this| ((**(this as *const *const IInkAnalysisNode_Vtbl)).GetStrokeIds)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            Kind,
+            BoundingRect,
+            #[cfg(feature = "Foundation_Collections")]
+            RotatedBoundingRect,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RotatedBoundingRect: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Children,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Children: 0,
+            Parent,
+            #[cfg(feature = "Foundation_Collections")]
+            GetStrokeIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetStrokeIds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkAnalysisNode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkAnalysisParagraph_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RecognizedText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisParagraph", "RecognizedText", 6usize, |this| ((**(this as *const *const IInkAnalysisParagraph_Vtbl)).RecognizedText)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RecognizedText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkAnalysisParagraph as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkAnalysisResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut InkAnalysisStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisResult", "Status", 6usize, |this| ((**(this as *const *const IInkAnalysisResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkAnalysisResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkAnalysisRoot_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RecognizedText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisRoot", "RecognizedText", 6usize, |this| ((**(this as *const *const IInkAnalysisRoot_Vtbl)).RecognizedText)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindNodes(this: *mut core::ffi::c_void, nodekind: InkAnalysisNodeKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisRoot", "FindNodes", 7usize, |this| ((**(this as *const *const IInkAnalysisRoot_Vtbl)).FindNodes)(this, nodekind, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RecognizedText,
+            #[cfg(feature = "Foundation_Collections")]
+            FindNodes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindNodes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkAnalysisRoot as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkAnalysisWritingRegion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RecognizedText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalysisWritingRegion", "RecognizedText", 6usize, |this| ((**(this as *const *const IInkAnalysisWritingRegion_Vtbl)).RecognizedText)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RecognizedText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkAnalysisWritingRegion as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkAnalyzer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AnalysisRoot(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalyzer", "AnalysisRoot", 6usize, |this| ((**(this as *const *const IInkAnalyzer_Vtbl)).AnalysisRoot)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAnalyzing(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalyzer", "IsAnalyzing", 7usize, |this| ((**(this as *const *const IInkAnalyzer_Vtbl)).IsAnalyzing)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDataForStroke(this: *mut core::ffi::c_void, stroke: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalyzer", "AddDataForStroke", 8usize, |this| ((**(this as *const *const IInkAnalyzer_Vtbl)).AddDataForStroke)(this, stroke), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AddDataForStrokes(this: *mut core::ffi::c_void, strokes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalyzer", "AddDataForStrokes", 9usize, |this| ((**(this as *const *const IInkAnalyzer_Vtbl)).AddDataForStrokes)(this, strokes), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearDataForAllStrokes(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalyzer", "ClearDataForAllStrokes", 10usize, |this| ((**(this as *const *const IInkAnalyzer_Vtbl)).ClearDataForAllStrokes)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDataForStroke(this: *mut core::ffi::c_void, strokeid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalyzer", "RemoveDataForStroke", 11usize, |this| ((**(this as *const *const IInkAnalyzer_Vtbl)).RemoveDataForStroke)(this, strokeid), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RemoveDataForStrokes(this: *mut core::ffi::c_void, strokeids: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalyzer", "RemoveDataForStrokes", 12usize, |this| ((**(this as *const *const IInkAnalyzer_Vtbl)).RemoveDataForStrokes)(this, strokeids), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplaceDataForStroke(this: *mut core::ffi::c_void, stroke: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalyzer", "ReplaceDataForStroke", 13usize, |this| ((**(this as *const *const IInkAnalyzer_Vtbl)).ReplaceDataForStroke)(this, stroke), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeDataKind(this: *mut core::ffi::c_void, strokeid: u32, strokekind: InkAnalysisStrokeKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalyzer", "SetStrokeDataKind", 14usize, |this| ((**(this as *const *const IInkAnalyzer_Vtbl)).SetStrokeDataKind)(this, strokeid, strokekind), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnalyzeAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalyzer", "AnalyzeAsync", 15usize, |this| ((**(this as *const *const IInkAnalyzer_Vtbl)).AnalyzeAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AnalysisRoot,
+            IsAnalyzing,
+            AddDataForStroke,
+            #[cfg(feature = "Foundation_Collections")]
+            AddDataForStrokes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AddDataForStrokes: 0,
+            ClearDataForAllStrokes,
+            RemoveDataForStroke,
+            #[cfg(feature = "Foundation_Collections")]
+            RemoveDataForStrokes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RemoveDataForStrokes: 0,
+            ReplaceDataForStroke,
+            SetStrokeDataKind,
+            AnalyzeAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkAnalyzer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkAnalyzerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAnalyzer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkAnalyzerFactory", "CreateAnalyzer", 6usize, |this| ((**(this as *const *const IInkAnalyzerFactory_Vtbl)).CreateAnalyzer)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateAnalyzer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkAnalyzerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -603,6 +603,7 @@ impl CoreWetStrokeDisposition {
 impl windows_core::TypeKind for CoreWetStrokeDisposition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreWetStrokeDisposition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreWetStrokeDisposition").field(&self.0).finish()
@@ -611,3 +612,5 @@ impl core::fmt::Debug for CoreWetStrokeDisposition {
 impl windows_core::RuntimeType for CoreWetStrokeDisposition {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.Input.Inking.Core.CoreWetStrokeDisposition;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
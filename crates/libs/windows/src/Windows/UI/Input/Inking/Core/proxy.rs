@@ -0,0 +1,331 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Foundation_Numerics"))]
+impl windows_core::imp::ProxyVtable for ICoreIncrementalInkStroke_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AppendInkPoints(this: *mut core::ffi::c_void, inkpoints: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreIncrementalInkStroke", "AppendInkPoints", 6usize, |this| ((**(this as *const *const ICoreIncrementalInkStroke_Vtbl)).AppendInkPoints)(this, inkpoints, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInkStroke(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreIncrementalInkStroke", "CreateInkStroke", 7usize, |this| ((**(this as *const *const ICoreIncrementalInkStroke_Vtbl)).CreateInkStroke)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawingAttributes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreIncrementalInkStroke", "DrawingAttributes", 8usize, |this| ((**(this as *const *const ICoreIncrementalInkStroke_Vtbl)).DrawingAttributes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn PointTransform(this: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreIncrementalInkStroke", "PointTransform", 9usize, |this| ((**(this as *const *const ICoreIncrementalInkStroke_Vtbl)).PointTransform)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BoundingRect(this: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreIncrementalInkStroke", "BoundingRect", 10usize, |this| ((**(this as *const *const ICoreIncrementalInkStroke_Vtbl)).BoundingRect)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AppendInkPoints,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AppendInkPoints: 0,
+            CreateInkStroke,
+            DrawingAttributes,
+            #[cfg(feature = "Foundation_Numerics")]
+            PointTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            PointTransform: 0,
+            BoundingRect,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreIncrementalInkStroke as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ICoreIncrementalInkStrokeFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, drawingattributes: *mut core::ffi::c_void, pointtransform: super::super::super::super::Foundation::Numerics::Matrix3x2, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreIncrementalInkStrokeFactory", "Create", 6usize, |this| ((**(this as *const *const ICoreIncrementalInkStrokeFactory_Vtbl)).Create)(this, drawingattributes, pointtransform, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Create,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreIncrementalInkStrokeFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Core")]
+impl windows_core::imp::ProxyVtable for ICoreInkIndependentInputSource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn PointerEntering(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource", "PointerEntering", 6usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource_Vtbl)).PointerEntering)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerEntering(this: *mut core::ffi::c_void, cookie: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource", "RemovePointerEntering", 7usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource_Vtbl)).RemovePointerEntering)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn PointerHovering(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource", "PointerHovering", 8usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource_Vtbl)).PointerHovering)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerHovering(this: *mut core::ffi::c_void, cookie: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource", "RemovePointerHovering", 9usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource_Vtbl)).RemovePointerHovering)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn PointerExiting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource", "PointerExiting", 10usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource_Vtbl)).PointerExiting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerExiting(this: *mut core::ffi::c_void, cookie: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource", "RemovePointerExiting", 11usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource_Vtbl)).RemovePointerExiting)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn PointerPressing(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource", "PointerPressing", 12usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource_Vtbl)).PointerPressing)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerPressing(this: *mut core::ffi::c_void, cookie: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource", "RemovePointerPressing", 13usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource_Vtbl)).RemovePointerPressing)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn PointerMoving(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource", "PointerMoving", 14usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource_Vtbl)).PointerMoving)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerMoving(this: *mut core::ffi::c_void, cookie: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource", "RemovePointerMoving", 15usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource_Vtbl)).RemovePointerMoving)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn PointerReleasing(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource", "PointerReleasing", 16usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource_Vtbl)).PointerReleasing)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerReleasing(this: *mut core::ffi::c_void, cookie: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource", "RemovePointerReleasing", 17usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource_Vtbl)).RemovePointerReleasing)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn PointerLost(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource", "PointerLost", 18usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource_Vtbl)).PointerLost)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerLost(this: *mut core::ffi::c_void, cookie: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource", "RemovePointerLost", 19usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource_Vtbl)).RemovePointerLost)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn InkPresenter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource", "InkPresenter", 20usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource_Vtbl)).InkPresenter)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Core")]
+            PointerEntering,
+            #[cfg(not(feature = "UI_Core"))]
+            PointerEntering: 0,
+            RemovePointerEntering,
+            #[cfg(feature = "UI_Core")]
+            PointerHovering,
+            #[cfg(not(feature = "UI_Core"))]
+            PointerHovering: 0,
+            RemovePointerHovering,
+            #[cfg(feature = "UI_Core")]
+            PointerExiting,
+            #[cfg(not(feature = "UI_Core"))]
+            PointerExiting: 0,
+            RemovePointerExiting,
+            #[cfg(feature = "UI_Core")]
+            PointerPressing,
+            #[cfg(not(feature = "UI_Core"))]
+            PointerPressing: 0,
+            RemovePointerPressing,
+            #[cfg(feature = "UI_Core")]
+            PointerMoving,
+            #[cfg(not(feature = "UI_Core"))]
+            PointerMoving: 0,
+            RemovePointerMoving,
+            #[cfg(feature = "UI_Core")]
+            PointerReleasing,
+            #[cfg(not(feature = "UI_Core"))]
+            PointerReleasing: 0,
+            RemovePointerReleasing,
+            #[cfg(feature = "UI_Core")]
+            PointerLost,
+            #[cfg(not(feature = "UI_Core"))]
+            PointerLost: 0,
+            RemovePointerLost,
+            InkPresenter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInkIndependentInputSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Core")]
+impl windows_core::imp::ProxyVtable for ICoreInkIndependentInputSource2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn PointerCursor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource2", "PointerCursor", 6usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource2_Vtbl)).PointerCursor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn SetPointerCursor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSource2", "SetPointerCursor", 7usize, |this| ((**(this as *const *const ICoreInkIndependentInputSource2_Vtbl)).SetPointerCursor)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Core")]
+            PointerCursor,
+            #[cfg(not(feature = "UI_Core"))]
+            PointerCursor: 0,
+            #[cfg(feature = "UI_Core")]
+            SetPointerCursor,
+            #[cfg(not(feature = "UI_Core"))]
+            SetPointerCursor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInkIndependentInputSource2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreInkIndependentInputSourceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, inkpresenter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkIndependentInputSourceStatics", "Create", 6usize, |this| ((**(this as *const *const ICoreInkIndependentInputSourceStatics_Vtbl)).Create)(this, inkpresenter, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInkIndependentInputSourceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Composition")]
+impl windows_core::imp::ProxyVtable for ICoreInkPresenterHost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InkPresenter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkPresenterHost", "InkPresenter", 6usize, |this| ((**(this as *const *const ICoreInkPresenterHost_Vtbl)).InkPresenter)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Composition")]
+        unsafe extern "system" fn RootVisual(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkPresenterHost", "RootVisual", 7usize, |this| ((**(this as *const *const ICoreInkPresenterHost_Vtbl)).RootVisual)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Composition")]
+        unsafe extern "system" fn SetRootVisual(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInkPresenterHost", "SetRootVisual", 8usize, |this| ((**(this as *const *const ICoreInkPresenterHost_Vtbl)).SetRootVisual)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InkPresenter,
+            #[cfg(feature = "UI_Composition")]
+            RootVisual,
+            #[cfg(not(feature = "UI_Composition"))]
+            RootVisual: 0,
+            #[cfg(feature = "UI_Composition")]
+            SetRootVisual,
+            #[cfg(not(feature = "UI_Composition"))]
+            SetRootVisual: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInkPresenterHost as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICoreWetStrokeUpdateEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn NewInkPoints(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateEventArgs", "NewInkPoints", 6usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateEventArgs_Vtbl)).NewInkPoints)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateEventArgs", "PointerId", 7usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateEventArgs_Vtbl)).PointerId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disposition(this: *mut core::ffi::c_void, result__: *mut CoreWetStrokeDisposition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateEventArgs", "Disposition", 8usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateEventArgs_Vtbl)).Disposition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisposition(this: *mut core::ffi::c_void, value: CoreWetStrokeDisposition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateEventArgs", "SetDisposition", 9usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateEventArgs_Vtbl)).SetDisposition)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            NewInkPoints,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            NewInkPoints: 0,
+            PointerId,
+            Disposition,
+            SetDisposition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWetStrokeUpdateEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWetStrokeUpdateSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WetStrokeStarting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateSource", "WetStrokeStarting", 6usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateSource_Vtbl)).WetStrokeStarting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveWetStrokeStarting(this: *mut core::ffi::c_void, cookie: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateSource", "RemoveWetStrokeStarting", 7usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateSource_Vtbl)).RemoveWetStrokeStarting)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn WetStrokeContinuing(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateSource", "WetStrokeContinuing", 8usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateSource_Vtbl)).WetStrokeContinuing)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveWetStrokeContinuing(this: *mut core::ffi::c_void, cookie: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateSource", "RemoveWetStrokeContinuing", 9usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateSource_Vtbl)).RemoveWetStrokeContinuing)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn WetStrokeStopping(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateSource", "WetStrokeStopping", 10usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateSource_Vtbl)).WetStrokeStopping)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveWetStrokeStopping(this: *mut core::ffi::c_void, cookie: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateSource", "RemoveWetStrokeStopping", 11usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateSource_Vtbl)).RemoveWetStrokeStopping)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn WetStrokeCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateSource", "WetStrokeCompleted", 12usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateSource_Vtbl)).WetStrokeCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveWetStrokeCompleted(this: *mut core::ffi::c_void, cookie: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateSource", "RemoveWetStrokeCompleted", 13usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateSource_Vtbl)).RemoveWetStrokeCompleted)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn WetStrokeCanceled(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateSource", "WetStrokeCanceled", 14usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateSource_Vtbl)).WetStrokeCanceled)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveWetStrokeCanceled(this: *mut core::ffi::c_void, cookie: super::super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateSource", "RemoveWetStrokeCanceled", 15usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateSource_Vtbl)).RemoveWetStrokeCanceled)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn InkPresenter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateSource", "InkPresenter", 16usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateSource_Vtbl)).InkPresenter)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            WetStrokeStarting,
+            RemoveWetStrokeStarting,
+            WetStrokeContinuing,
+            RemoveWetStrokeContinuing,
+            WetStrokeStopping,
+            RemoveWetStrokeStopping,
+            WetStrokeCompleted,
+            RemoveWetStrokeCompleted,
+            WetStrokeCanceled,
+            RemoveWetStrokeCanceled,
+            InkPresenter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWetStrokeUpdateSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWetStrokeUpdateSourceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, inkpresenter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWetStrokeUpdateSourceStatics", "Create", 6usize, |this| ((**(this as *const *const ICoreWetStrokeUpdateSourceStatics_Vtbl)).Create)(this, inkpresenter, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWetStrokeUpdateSourceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
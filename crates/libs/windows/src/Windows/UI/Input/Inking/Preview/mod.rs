@@ -71,3 +71,5 @@ impl windows_core::RuntimeName for PalmRejectionDelayZonePreview {
 }
 unsafe impl Send for PalmRejectionDelayZonePreview {}
 unsafe impl Sync for PalmRejectionDelayZonePreview {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,35 @@
+impl windows_core::imp::ProxyVtable for IPalmRejectionDelayZonePreview_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPalmRejectionDelayZonePreview as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Composition")]
+impl windows_core::imp::ProxyVtable for IPalmRejectionDelayZonePreviewStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Composition")]
+        unsafe extern "system" fn CreateForVisual(this: *mut core::ffi::c_void, inputpanelvisual: *mut core::ffi::c_void, inputpanelrect: super::super::super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPalmRejectionDelayZonePreviewStatics", "CreateForVisual", 6usize, |this| ((**(this as *const *const IPalmRejectionDelayZonePreviewStatics_Vtbl)).CreateForVisual)(this, inputpanelvisual, inputpanelrect, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Composition")]
+        unsafe extern "system" fn CreateForVisualWithViewportClip(this: *mut core::ffi::c_void, inputpanelvisual: *mut core::ffi::c_void, inputpanelrect: super::super::super::super::Foundation::Rect, viewportvisual: *mut core::ffi::c_void, viewportrect: super::super::super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPalmRejectionDelayZonePreviewStatics", "CreateForVisualWithViewportClip", 7usize, |this| ((**(this as *const *const IPalmRejectionDelayZonePreviewStatics_Vtbl)).CreateForVisualWithViewportClip)(this, inputpanelvisual, inputpanelrect, viewportvisual, viewportrect, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Composition")]
+            CreateForVisual,
+            #[cfg(not(feature = "UI_Composition"))]
+            CreateForVisual: 0,
+            #[cfg(feature = "UI_Composition")]
+            CreateForVisualWithViewportClip,
+            #[cfg(not(feature = "UI_Composition"))]
+            CreateForVisualWithViewportClip: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPalmRejectionDelayZonePreviewStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -2952,6 +2952,7 @@ impl HandwritingLineHeight {
 impl windows_core::TypeKind for HandwritingLineHeight {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HandwritingLineHeight {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HandwritingLineHeight").field(&self.0).finish()
@@ -2970,6 +2971,7 @@ impl InkDrawingAttributesKind {
 impl windows_core::TypeKind for InkDrawingAttributesKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkDrawingAttributesKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkDrawingAttributesKind").field(&self.0).finish()
@@ -2989,6 +2991,7 @@ impl InkHighContrastAdjustment {
 impl windows_core::TypeKind for InkHighContrastAdjustment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkHighContrastAdjustment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkHighContrastAdjustment").field(&self.0).finish()
@@ -3008,6 +3011,7 @@ impl InkInputProcessingMode {
 impl windows_core::TypeKind for InkInputProcessingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkInputProcessingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkInputProcessingMode").field(&self.0).finish()
@@ -3026,6 +3030,7 @@ impl InkInputRightDragAction {
 impl windows_core::TypeKind for InkInputRightDragAction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkInputRightDragAction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkInputRightDragAction").field(&self.0).finish()
@@ -3045,6 +3050,7 @@ impl InkManipulationMode {
 impl windows_core::TypeKind for InkManipulationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkManipulationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkManipulationMode").field(&self.0).finish()
@@ -3063,6 +3069,7 @@ impl InkPersistenceFormat {
 impl windows_core::TypeKind for InkPersistenceFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkPersistenceFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkPersistenceFormat").field(&self.0).finish()
@@ -3081,6 +3088,7 @@ impl InkPresenterPredefinedConfiguration {
 impl windows_core::TypeKind for InkPresenterPredefinedConfiguration {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkPresenterPredefinedConfiguration {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkPresenterPredefinedConfiguration").field(&self.0).finish()
@@ -3100,6 +3108,7 @@ impl InkPresenterStencilKind {
 impl windows_core::TypeKind for InkPresenterStencilKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkPresenterStencilKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkPresenterStencilKind").field(&self.0).finish()
@@ -3119,6 +3128,7 @@ impl InkRecognitionTarget {
 impl windows_core::TypeKind for InkRecognitionTarget {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkRecognitionTarget {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkRecognitionTarget").field(&self.0).finish()
@@ -3137,6 +3147,7 @@ impl PenHandedness {
 impl windows_core::TypeKind for PenHandedness {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PenHandedness {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PenHandedness").field(&self.0).finish()
@@ -3155,6 +3166,7 @@ impl PenTipShape {
 impl windows_core::TypeKind for PenTipShape {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PenTipShape {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PenTipShape").field(&self.0).finish()
@@ -3165,3 +3177,5 @@ impl windows_core::RuntimeType for PenTipShape {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1420 @@
+impl windows_core::imp::ProxyVtable for IInkDrawingAttributes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, result__: *mut super::super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "Color", 6usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).Color)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, value: super::super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "SetColor", 7usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).SetColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PenTip(this: *mut core::ffi::c_void, result__: *mut PenTipShape) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "PenTip", 8usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).PenTip)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPenTip(this: *mut core::ffi::c_void, value: PenTipShape) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "SetPenTip", 9usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).SetPenTip)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "Size", 10usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "SetSize", 11usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).SetSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IgnorePressure(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "IgnorePressure", 12usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).IgnorePressure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIgnorePressure(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "SetIgnorePressure", 13usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).SetIgnorePressure)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FitToCurve(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "FitToCurve", 14usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).FitToCurve)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFitToCurve(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "SetFitToCurve", 15usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).SetFitToCurve)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Color,
+            SetColor,
+            PenTip,
+            SetPenTip,
+            Size,
+            SetSize,
+            IgnorePressure,
+            SetIgnorePressure,
+            FitToCurve,
+            SetFitToCurve,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkDrawingAttributes as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IInkDrawingAttributes2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn PenTipTransform(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes2", "PenTipTransform", 6usize, |this| ((**(this as *const *const IInkDrawingAttributes2_Vtbl)).PenTipTransform)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetPenTipTransform(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes2", "SetPenTipTransform", 7usize, |this| ((**(this as *const *const IInkDrawingAttributes2_Vtbl)).SetPenTipTransform)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawAsHighlighter(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes2", "DrawAsHighlighter", 8usize, |this| ((**(this as *const *const IInkDrawingAttributes2_Vtbl)).DrawAsHighlighter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDrawAsHighlighter(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes2", "SetDrawAsHighlighter", 9usize, |this| ((**(this as *const *const IInkDrawingAttributes2_Vtbl)).SetDrawAsHighlighter)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            PenTipTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            PenTipTransform: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetPenTipTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetPenTipTransform: 0,
+            DrawAsHighlighter,
+            SetDrawAsHighlighter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkDrawingAttributes2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkDrawingAttributes3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut InkDrawingAttributesKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes3", "Kind", 6usize, |this| ((**(this as *const *const IInkDrawingAttributes3_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PencilProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes3", "PencilProperties", 7usize, |this| ((**(this as *const *const IInkDrawingAttributes3_Vtbl)).PencilProperties)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind, PencilProperties }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkDrawingAttributes3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkDrawingAttributes4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IgnoreTilt(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes4", "IgnoreTilt", 6usize, |this| ((**(this as *const *const IInkDrawingAttributes4_Vtbl)).IgnoreTilt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIgnoreTilt(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes4", "SetIgnoreTilt", 7usize, |this| ((**(this as *const *const IInkDrawingAttributes4_Vtbl)).SetIgnoreTilt)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IgnoreTilt, SetIgnoreTilt }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkDrawingAttributes4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkDrawingAttributes5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ModelerAttributes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes5", "ModelerAttributes", 6usize, |this| ((**(this as *const *const IInkDrawingAttributes5_Vtbl)).ModelerAttributes)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ModelerAttributes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkDrawingAttributes5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkDrawingAttributesPencilProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Opacity(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributesPencilProperties", "Opacity", 6usize, |this| ((**(this as *const *const IInkDrawingAttributesPencilProperties_Vtbl)).Opacity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpacity(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributesPencilProperties", "SetOpacity", 7usize, |this| ((**(this as *const *const IInkDrawingAttributesPencilProperties_Vtbl)).SetOpacity)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Opacity, SetOpacity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkDrawingAttributesPencilProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkDrawingAttributesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateForPencil(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributesStatics", "CreateForPencil", 6usize, |this| ((**(this as *const *const IInkDrawingAttributesStatics_Vtbl)).CreateForPencil)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateForPencil }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkDrawingAttributesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkInputConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPrimaryBarrelButtonInputEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkInputConfiguration", "IsPrimaryBarrelButtonInputEnabled", 6usize, |this| ((**(this as *const *const IInkInputConfiguration_Vtbl)).IsPrimaryBarrelButtonInputEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsPrimaryBarrelButtonInputEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkInputConfiguration", "SetIsPrimaryBarrelButtonInputEnabled", 7usize, |this| ((**(this as *const *const IInkInputConfiguration_Vtbl)).SetIsPrimaryBarrelButtonInputEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEraserInputEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkInputConfiguration", "IsEraserInputEnabled", 8usize, |this| ((**(this as *const *const IInkInputConfiguration_Vtbl)).IsEraserInputEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEraserInputEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkInputConfiguration", "SetIsEraserInputEnabled", 9usize, |this| ((**(this as *const *const IInkInputConfiguration_Vtbl)).SetIsEraserInputEnabled)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsPrimaryBarrelButtonInputEnabled,
+            SetIsPrimaryBarrelButtonInputEnabled,
+            IsEraserInputEnabled,
+            SetIsEraserInputEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkInputConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkInputConfiguration2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPenHapticFeedbackEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkInputConfiguration2", "IsPenHapticFeedbackEnabled", 6usize, |this| ((**(this as *const *const IInkInputConfiguration2_Vtbl)).IsPenHapticFeedbackEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsPenHapticFeedbackEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkInputConfiguration2", "SetIsPenHapticFeedbackEnabled", 7usize, |this| ((**(this as *const *const IInkInputConfiguration2_Vtbl)).SetIsPenHapticFeedbackEnabled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsPenHapticFeedbackEnabled, SetIsPenHapticFeedbackEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkInputConfiguration2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkInputProcessingConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut InkInputProcessingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkInputProcessingConfiguration", "Mode", 6usize, |this| ((**(this as *const *const IInkInputProcessingConfiguration_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, value: InkInputProcessingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkInputProcessingConfiguration", "SetMode", 7usize, |this| ((**(this as *const *const IInkInputProcessingConfiguration_Vtbl)).SetMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RightDragAction(this: *mut core::ffi::c_void, result__: *mut InkInputRightDragAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkInputProcessingConfiguration", "RightDragAction", 8usize, |this| ((**(this as *const *const IInkInputProcessingConfiguration_Vtbl)).RightDragAction)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRightDragAction(this: *mut core::ffi::c_void, value: InkInputRightDragAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkInputProcessingConfiguration", "SetRightDragAction", 9usize, |this| ((**(this as *const *const IInkInputProcessingConfiguration_Vtbl)).SetRightDragAction)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Mode, SetMode, RightDragAction, SetRightDragAction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkInputProcessingConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IInkManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, result__: *mut InkManipulationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkManager", "Mode", 6usize, |this| ((**(this as *const *const IInkManager_Vtbl)).Mode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, value: InkManipulationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkManager", "SetMode", 7usize, |this| ((**(this as *const *const IInkManager_Vtbl)).SetMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessPointerDown(this: *mut core::ffi::c_void, pointerpoint: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkManager", "ProcessPointerDown", 8usize, |this| ((**(this as *const *const IInkManager_Vtbl)).ProcessPointerDown)(this, pointerpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessPointerUpdate(this: *mut core::ffi::c_void, pointerpoint: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkManager", "ProcessPointerUpdate", 9usize, |this| ((**(this as *const *const IInkManager_Vtbl)).ProcessPointerUpdate)(this, pointerpoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessPointerUp(this: *mut core::ffi::c_void, pointerpoint: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkManager", "ProcessPointerUp", 10usize, |this| ((**(this as *const *const IInkManager_Vtbl)).ProcessPointerUp)(this, pointerpoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultDrawingAttributes(this: *mut core::ffi::c_void, drawingattributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkManager", "SetDefaultDrawingAttributes", 11usize, |this| ((**(this as *const *const IInkManager_Vtbl)).SetDefaultDrawingAttributes)(this, drawingattributes), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RecognizeAsync2(this: *mut core::ffi::c_void, recognitiontarget: InkRecognitionTarget, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkManager", "RecognizeAsync2", 12usize, |this| ((**(this as *const *const IInkManager_Vtbl)).RecognizeAsync2)(this, recognitiontarget, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Mode,
+            SetMode,
+            ProcessPointerDown,
+            ProcessPointerUpdate,
+            ProcessPointerUp,
+            SetDefaultDrawingAttributes,
+            #[cfg(feature = "Foundation_Collections")]
+            RecognizeAsync2,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RecognizeAsync2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkModelerAttributes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PredictionTime(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkModelerAttributes", "PredictionTime", 6usize, |this| ((**(this as *const *const IInkModelerAttributes_Vtbl)).PredictionTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPredictionTime(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkModelerAttributes", "SetPredictionTime", 7usize, |this| ((**(this as *const *const IInkModelerAttributes_Vtbl)).SetPredictionTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScalingFactor(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkModelerAttributes", "ScalingFactor", 8usize, |this| ((**(this as *const *const IInkModelerAttributes_Vtbl)).ScalingFactor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScalingFactor(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkModelerAttributes", "SetScalingFactor", 9usize, |this| ((**(this as *const *const IInkModelerAttributes_Vtbl)).SetScalingFactor)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PredictionTime,
+            SetPredictionTime,
+            ScalingFactor,
+            SetScalingFactor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkModelerAttributes as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkModelerAttributes2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UseVelocityBasedPressure(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkModelerAttributes2", "UseVelocityBasedPressure", 6usize, |this| ((**(this as *const *const IInkModelerAttributes2_Vtbl)).UseVelocityBasedPressure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseVelocityBasedPressure(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkModelerAttributes2", "SetUseVelocityBasedPressure", 7usize, |this| ((**(this as *const *const IInkModelerAttributes2_Vtbl)).SetUseVelocityBasedPressure)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UseVelocityBasedPressure, SetUseVelocityBasedPressure }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkModelerAttributes2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkPoint_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPoint", "Position", 6usize, |this| ((**(this as *const *const IInkPoint_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pressure(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPoint", "Pressure", 7usize, |this| ((**(this as *const *const IInkPoint_Vtbl)).Pressure)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Position, Pressure }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkPoint as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkPoint2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TiltX(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPoint2", "TiltX", 6usize, |this| ((**(this as *const *const IInkPoint2_Vtbl)).TiltX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TiltY(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPoint2", "TiltY", 7usize, |this| ((**(this as *const *const IInkPoint2_Vtbl)).TiltY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPoint2", "Timestamp", 8usize, |this| ((**(this as *const *const IInkPoint2_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TiltX, TiltY, Timestamp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkPoint2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkPointFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInkPoint(this: *mut core::ffi::c_void, position: super::super::super::Foundation::Point, pressure: f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPointFactory", "CreateInkPoint", 6usize, |this| ((**(this as *const *const IInkPointFactory_Vtbl)).CreateInkPoint)(this, position, pressure, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInkPoint }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkPointFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkPointFactory2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInkPointWithTiltAndTimestamp(this: *mut core::ffi::c_void, position: super::super::super::Foundation::Point, pressure: f32, tiltx: f32, tilty: f32, timestamp: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPointFactory2", "CreateInkPointWithTiltAndTimestamp", 6usize, |this| ((**(this as *const *const IInkPointFactory2_Vtbl)).CreateInkPointWithTiltAndTimestamp)(this, position, pressure, tiltx, tilty, timestamp, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInkPointWithTiltAndTimestamp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkPointFactory2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Core")]
+impl windows_core::imp::ProxyVtable for IInkPresenter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsInputEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "IsInputEnabled", 6usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).IsInputEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsInputEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "SetIsInputEnabled", 7usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).SetIsInputEnabled)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn InputDeviceTypes(this: *mut core::ffi::c_void, result__: *mut super::super::Core::CoreInputDeviceTypes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "InputDeviceTypes", 8usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).InputDeviceTypes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn SetInputDeviceTypes(this: *mut core::ffi::c_void, value: super::super::Core::CoreInputDeviceTypes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "SetInputDeviceTypes", 9usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).SetInputDeviceTypes)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnprocessedInput(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "UnprocessedInput", 10usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).UnprocessedInput)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrokeInput(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "StrokeInput", 11usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).StrokeInput)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputProcessingConfiguration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "InputProcessingConfiguration", 12usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).InputProcessingConfiguration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrokeContainer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "StrokeContainer", 13usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).StrokeContainer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeContainer(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "SetStrokeContainer", 14usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).SetStrokeContainer)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyDefaultDrawingAttributes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "CopyDefaultDrawingAttributes", 15usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).CopyDefaultDrawingAttributes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateDefaultDrawingAttributes(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "UpdateDefaultDrawingAttributes", 16usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).UpdateDefaultDrawingAttributes)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivateCustomDrying(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "ActivateCustomDrying", 17usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).ActivateCustomDrying)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPredefinedConfiguration(this: *mut core::ffi::c_void, value: InkPresenterPredefinedConfiguration) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "SetPredefinedConfiguration", 18usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).SetPredefinedConfiguration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrokesCollected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "StrokesCollected", 19usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).StrokesCollected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStrokesCollected(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "RemoveStrokesCollected", 20usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).RemoveStrokesCollected)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrokesErased(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "StrokesErased", 21usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).StrokesErased)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStrokesErased(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter", "RemoveStrokesErased", 22usize, |this| ((**(this as *const *const IInkPresenter_Vtbl)).RemoveStrokesErased)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsInputEnabled,
+            SetIsInputEnabled,
+            #[cfg(feature = "UI_Core")]
+            InputDeviceTypes,
+            #[cfg(not(feature = "UI_Core"))]
+            InputDeviceTypes: 0,
+            #[cfg(feature = "UI_Core")]
+            SetInputDeviceTypes,
+            #[cfg(not(feature = "UI_Core"))]
+            SetInputDeviceTypes: 0,
+            UnprocessedInput,
+            StrokeInput,
+            InputProcessingConfiguration,
+            StrokeContainer,
+            SetStrokeContainer,
+            CopyDefaultDrawingAttributes,
+            UpdateDefaultDrawingAttributes,
+            ActivateCustomDrying,
+            SetPredefinedConfiguration,
+            StrokesCollected,
+            RemoveStrokesCollected,
+            StrokesErased,
+            RemoveStrokesErased,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkPresenter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Core")]
+impl windows_core::imp::ProxyVtable for IInkPresenter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HighContrastAdjustment(this: *mut core::ffi::c_void, result__: *mut InkHighContrastAdjustment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter2", "HighContrastAdjustment", 6usize, |this| ((**(this as *const *const IInkPresenter2_Vtbl)).HighContrastAdjustment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHighContrastAdjustment(this: *mut core::ffi::c_void, value: InkHighContrastAdjustment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter2", "SetHighContrastAdjustment", 7usize, |this| ((**(this as *const *const IInkPresenter2_Vtbl)).SetHighContrastAdjustment)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HighContrastAdjustment, SetHighContrastAdjustment }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkPresenter2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkPresenter3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InputConfiguration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenter3", "InputConfiguration", 6usize, |this| ((**(this as *const *const IInkPresenter3_Vtbl)).InputConfiguration)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InputConfiguration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkPresenter3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IInkPresenterProtractor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AreTickMarksVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterProtractor", "AreTickMarksVisible", 6usize, |this| ((**(this as *const *const IInkPresenterProtractor_Vtbl)).AreTickMarksVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAreTickMarksVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterProtractor", "SetAreTickMarksVisible", 7usize, |this| ((**(this as *const *const IInkPresenterProtractor_Vtbl)).SetAreTickMarksVisible)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AreRaysVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterProtractor", "AreRaysVisible", 8usize, |this| ((**(this as *const *const IInkPresenterProtractor_Vtbl)).AreRaysVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAreRaysVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterProtractor", "SetAreRaysVisible", 9usize, |this| ((**(this as *const *const IInkPresenterProtractor_Vtbl)).SetAreRaysVisible)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCenterMarkerVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterProtractor", "IsCenterMarkerVisible", 10usize, |this| ((**(this as *const *const IInkPresenterProtractor_Vtbl)).IsCenterMarkerVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCenterMarkerVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterProtractor", "SetIsCenterMarkerVisible", 11usize, |this| ((**(this as *const *const IInkPresenterProtractor_Vtbl)).SetIsCenterMarkerVisible)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAngleReadoutVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterProtractor", "IsAngleReadoutVisible", 12usize, |this| ((**(this as *const *const IInkPresenterProtractor_Vtbl)).IsAngleReadoutVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsAngleReadoutVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterProtractor", "SetIsAngleReadoutVisible", 13usize, |this| ((**(this as *const *const IInkPresenterProtractor_Vtbl)).SetIsAngleReadoutVisible)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsResizable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterProtractor", "IsResizable", 14usize, |this| ((**(this as *const *const IInkPresenterProtractor_Vtbl)).IsResizable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsResizable(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterProtractor", "SetIsResizable", 15usize, |this| ((**(this as *const *const IInkPresenterProtractor_Vtbl)).SetIsResizable)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Radius(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterProtractor", "Radius", 16usize, |this| ((**(this as *const *const IInkPresenterProtractor_Vtbl)).Radius)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRadius(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterProtractor", "SetRadius", 17usize, |this| ((**(this as *const *const IInkPresenterProtractor_Vtbl)).SetRadius)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccentColor(this: *mut core::ffi::c_void, result__: *mut super::super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterProtractor", "AccentColor", 18usize, |this| ((**(this as *const *const IInkPresenterProtractor_Vtbl)).AccentColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccentColor(this: *mut core::ffi::c_void, value: super::super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterProtractor", "SetAccentColor", 19usize, |this| ((**(this as *const *const IInkPresenterProtractor_Vtbl)).SetAccentColor)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AreTickMarksVisible,
+            SetAreTickMarksVisible,
+            AreRaysVisible,
+            SetAreRaysVisible,
+            IsCenterMarkerVisible,
+            SetIsCenterMarkerVisible,
+            IsAngleReadoutVisible,
+            SetIsAngleReadoutVisible,
+            IsResizable,
+            SetIsResizable,
+            Radius,
+            SetRadius,
+            AccentColor,
+            SetAccentColor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkPresenterProtractor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkPresenterProtractorFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, inkpresenter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterProtractorFactory", "Create", 6usize, |this| ((**(this as *const *const IInkPresenterProtractorFactory_Vtbl)).Create)(this, inkpresenter, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkPresenterProtractorFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IInkPresenterRuler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterRuler", "Length", 6usize, |this| ((**(this as *const *const IInkPresenterRuler_Vtbl)).Length)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLength(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterRuler", "SetLength", 7usize, |this| ((**(this as *const *const IInkPresenterRuler_Vtbl)).SetLength)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterRuler", "Width", 8usize, |this| ((**(this as *const *const IInkPresenterRuler_Vtbl)).Width)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWidth(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterRuler", "SetWidth", 9usize, |this| ((**(this as *const *const IInkPresenterRuler_Vtbl)).SetWidth)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Length, SetLength, Width, SetWidth }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkPresenterRuler as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkPresenterRuler2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AreTickMarksVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterRuler2", "AreTickMarksVisible", 6usize, |this| ((**(this as *const *const IInkPresenterRuler2_Vtbl)).AreTickMarksVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAreTickMarksVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterRuler2", "SetAreTickMarksVisible", 7usize, |this| ((**(this as *const *const IInkPresenterRuler2_Vtbl)).SetAreTickMarksVisible)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCompassVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterRuler2", "IsCompassVisible", 8usize, |this| ((**(this as *const *const IInkPresenterRuler2_Vtbl)).IsCompassVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsCompassVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterRuler2", "SetIsCompassVisible", 9usize, |this| ((**(this as *const *const IInkPresenterRuler2_Vtbl)).SetIsCompassVisible)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AreTickMarksVisible,
+            SetAreTickMarksVisible,
+            IsCompassVisible,
+            SetIsCompassVisible,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkPresenterRuler2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkPresenterRulerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, inkpresenter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterRulerFactory", "Create", 6usize, |this| ((**(this as *const *const IInkPresenterRulerFactory_Vtbl)).Create)(this, inkpresenter, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkPresenterRulerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IInkPresenterStencil_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut InkPresenterStencilKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterStencil", "Kind", 6usize, |this| ((**(this as *const *const IInkPresenterStencil_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterStencil", "IsVisible", 7usize, |this| ((**(this as *const *const IInkPresenterStencil_Vtbl)).IsVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterStencil", "SetIsVisible", 8usize, |this| ((**(this as *const *const IInkPresenterStencil_Vtbl)).SetIsVisible)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterStencil", "BackgroundColor", 9usize, |this| ((**(this as *const *const IInkPresenterStencil_Vtbl)).BackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackgroundColor(this: *mut core::ffi::c_void, value: super::super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterStencil", "SetBackgroundColor", 10usize, |this| ((**(this as *const *const IInkPresenterStencil_Vtbl)).SetBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForegroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterStencil", "ForegroundColor", 11usize, |this| ((**(this as *const *const IInkPresenterStencil_Vtbl)).ForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForegroundColor(this: *mut core::ffi::c_void, value: super::super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterStencil", "SetForegroundColor", 12usize, |this| ((**(this as *const *const IInkPresenterStencil_Vtbl)).SetForegroundColor)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Transform(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterStencil", "Transform", 13usize, |this| ((**(this as *const *const IInkPresenterStencil_Vtbl)).Transform)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetTransform(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterStencil", "SetTransform", 14usize, |this| ((**(this as *const *const IInkPresenterStencil_Vtbl)).SetTransform)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Kind,
+            IsVisible,
+            SetIsVisible,
+            BackgroundColor,
+            SetBackgroundColor,
+            ForegroundColor,
+            SetForegroundColor,
+            #[cfg(feature = "Foundation_Numerics")]
+            Transform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Transform: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetTransform: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkPresenterStencil as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkRecognitionResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BoundingRect(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionResult", "BoundingRect", 6usize, |this| ((**(this as *const *const IInkRecognitionResult_Vtbl)).BoundingRect)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetTextCandidates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionResult", "GetTextCandidates", 7usize, |this| ((**(this as *const *const IInkRecognitionResult_Vtbl)).GetTextCandidates)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetStrokes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionResult", "GetStrokes", 8usize, |this| ((**(this as *const *const IInkRecognitionResult_Vtbl)).GetStrokes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BoundingRect,
+            #[cfg(feature = "Foundation_Collections")]
+            GetTextCandidates,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetTextCandidates: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetStrokes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetStrokes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkRecognitionResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkRecognizer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizer", "Name", 6usize, |this| ((**(this as *const *const IInkRecognizer_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkRecognizer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkRecognizerContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDefaultRecognizer(this: *mut core::ffi::c_void, recognizer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContainer", "SetDefaultRecognizer", 6usize, |this| ((**(this as *const *const IInkRecognizerContainer_Vtbl)).SetDefaultRecognizer)(this, recognizer), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn RecognizeAsync(this: *mut core::ffi::c_void, strokecollection: *mut core::ffi::c_void, recognitiontarget: InkRecognitionTarget, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContainer", "RecognizeAsync", 7usize, |this| ((**(this as *const *const IInkRecognizerContainer_Vtbl)).RecognizeAsync)(this, strokecollection, recognitiontarget, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetRecognizers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContainer", "GetRecognizers", 8usize, |this| ((**(this as *const *const IInkRecognizerContainer_Vtbl)).GetRecognizers)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDefaultRecognizer,
+            #[cfg(feature = "Foundation_Collections")]
+            RecognizeAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            RecognizeAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetRecognizers,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetRecognizers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkRecognizerContainer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkStroke_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DrawingAttributes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke", "DrawingAttributes", 6usize, |this| ((**(this as *const *const IInkStroke_Vtbl)).DrawingAttributes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDrawingAttributes(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke", "SetDrawingAttributes", 7usize, |this| ((**(this as *const *const IInkStroke_Vtbl)).SetDrawingAttributes)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BoundingRect(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke", "BoundingRect", 8usize, |this| ((**(this as *const *const IInkStroke_Vtbl)).BoundingRect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Selected(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke", "Selected", 9usize, |this| ((**(this as *const *const IInkStroke_Vtbl)).Selected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelected(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke", "SetSelected", 10usize, |this| ((**(this as *const *const IInkStroke_Vtbl)).SetSelected)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Recognized(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke", "Recognized", 11usize, |this| ((**(this as *const *const IInkStroke_Vtbl)).Recognized)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetRenderingSegments(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke", "GetRenderingSegments", 12usize, |this| ((**(this as *const *const IInkStroke_Vtbl)).GetRenderingSegments)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke", "Clone", 13usize, |this| ((**(this as *const *const IInkStroke_Vtbl)).Clone)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DrawingAttributes,
+            SetDrawingAttributes,
+            BoundingRect,
+            Selected,
+            SetSelected,
+            Recognized,
+            #[cfg(feature = "Foundation_Collections")]
+            GetRenderingSegments,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetRenderingSegments: 0,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStroke as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Foundation_Numerics"))]
+impl windows_core::imp::ProxyVtable for IInkStroke2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn PointTransform(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke2", "PointTransform", 6usize, |this| ((**(this as *const *const IInkStroke2_Vtbl)).PointTransform)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetPointTransform(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke2", "SetPointTransform", 7usize, |this| ((**(this as *const *const IInkStroke2_Vtbl)).SetPointTransform)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetInkPoints(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke2", "GetInkPoints", 8usize, |this| ((**(this as *const *const IInkStroke2_Vtbl)).GetInkPoints)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            PointTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            PointTransform: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetPointTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetPointTransform: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetInkPoints,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetInkPoints: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStroke2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkStroke3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke3", "Id", 6usize, |this| ((**(this as *const *const IInkStroke3_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrokeStartedTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke3", "StrokeStartedTime", 7usize, |this| ((**(this as *const *const IInkStroke3_Vtbl)).StrokeStartedTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeStartedTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke3", "SetStrokeStartedTime", 8usize, |this| ((**(this as *const *const IInkStroke3_Vtbl)).SetStrokeStartedTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrokeDuration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke3", "StrokeDuration", 9usize, |this| ((**(this as *const *const IInkStroke3_Vtbl)).StrokeDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeDuration(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke3", "SetStrokeDuration", 10usize, |this| ((**(this as *const *const IInkStroke3_Vtbl)).SetStrokeDuration)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            StrokeStartedTime,
+            SetStrokeStartedTime,
+            StrokeDuration,
+            SetStrokeDuration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStroke3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkStroke4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PointerId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStroke4", "PointerId", 6usize, |this| ((**(this as *const *const IInkStroke4_Vtbl)).PointerId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PointerId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStroke4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkStrokeBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginStroke(this: *mut core::ffi::c_void, pointerpoint: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeBuilder", "BeginStroke", 6usize, |this| ((**(this as *const *const IInkStrokeBuilder_Vtbl)).BeginStroke)(this, pointerpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendToStroke(this: *mut core::ffi::c_void, pointerpoint: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeBuilder", "AppendToStroke", 7usize, |this| ((**(this as *const *const IInkStrokeBuilder_Vtbl)).AppendToStroke)(this, pointerpoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndStroke(this: *mut core::ffi::c_void, pointerpoint: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeBuilder", "EndStroke", 8usize, |this| ((**(this as *const *const IInkStrokeBuilder_Vtbl)).EndStroke)(this, pointerpoint, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateStroke(this: *mut core::ffi::c_void, points: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeBuilder", "CreateStroke", 9usize, |this| ((**(this as *const *const IInkStrokeBuilder_Vtbl)).CreateStroke)(this, points, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultDrawingAttributes(this: *mut core::ffi::c_void, drawingattributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeBuilder", "SetDefaultDrawingAttributes", 10usize, |this| ((**(this as *const *const IInkStrokeBuilder_Vtbl)).SetDefaultDrawingAttributes)(this, drawingattributes), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BeginStroke,
+            AppendToStroke,
+            EndStroke,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateStroke,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateStroke: 0,
+            SetDefaultDrawingAttributes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStrokeBuilder as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Foundation_Numerics"))]
+impl windows_core::imp::ProxyVtable for IInkStrokeBuilder2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Foundation_Numerics"))]
+        unsafe extern "system" fn CreateStrokeFromInkPoints(this: *mut core::ffi::c_void, inkpoints: *mut core::ffi::c_void, transform: super::super::super::Foundation::Numerics::Matrix3x2, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeBuilder2", "CreateStrokeFromInkPoints", 6usize, |this| ((**(this as *const *const IInkStrokeBuilder2_Vtbl)).CreateStrokeFromInkPoints)(this, inkpoints, transform, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Foundation_Numerics"))]
+            CreateStrokeFromInkPoints,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Foundation_Numerics")))]
+            CreateStrokeFromInkPoints: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStrokeBuilder2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Foundation_Numerics"))]
+impl windows_core::imp::ProxyVtable for IInkStrokeBuilder3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "Foundation_Numerics"))]
+        unsafe extern "system" fn CreateStrokeFromInkPoints(this: *mut core::ffi::c_void, inkpoints: *mut core::ffi::c_void, transform: super::super::super::Foundation::Numerics::Matrix3x2, strokestartedtime: *mut core::ffi::c_void, strokeduration: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeBuilder3", "CreateStrokeFromInkPoints", 6usize, |this| ((**(this as *const *const IInkStrokeBuilder3_Vtbl)).CreateStrokeFromInkPoints)(this, inkpoints, transform, strokestartedtime, strokeduration, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Foundation_Numerics"))]
+            CreateStrokeFromInkPoints,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Foundation_Numerics")))]
+            CreateStrokeFromInkPoints: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStrokeBuilder3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IInkStrokeContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BoundingRect(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer", "BoundingRect", 6usize, |this| ((**(this as *const *const IInkStrokeContainer_Vtbl)).BoundingRect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStroke(this: *mut core::ffi::c_void, stroke: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer", "AddStroke", 7usize, |this| ((**(this as *const *const IInkStrokeContainer_Vtbl)).AddStroke)(this, stroke), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteSelected(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer", "DeleteSelected", 8usize, |this| ((**(this as *const *const IInkStrokeContainer_Vtbl)).DeleteSelected)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveSelected(this: *mut core::ffi::c_void, translation: super::super::super::Foundation::Point, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer", "MoveSelected", 9usize, |this| ((**(this as *const *const IInkStrokeContainer_Vtbl)).MoveSelected)(this, translation, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SelectWithPolyLine(this: *mut core::ffi::c_void, polyline: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer", "SelectWithPolyLine", 10usize, |this| ((**(this as *const *const IInkStrokeContainer_Vtbl)).SelectWithPolyLine)(this, polyline, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectWithLine(this: *mut core::ffi::c_void, from: super::super::super::Foundation::Point, to: super::super::super::Foundation::Point, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer", "SelectWithLine", 11usize, |this| ((**(this as *const *const IInkStrokeContainer_Vtbl)).SelectWithLine)(this, from, to, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopySelectedToClipboard(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer", "CopySelectedToClipboard", 12usize, |this| ((**(this as *const *const IInkStrokeContainer_Vtbl)).CopySelectedToClipboard)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PasteFromClipboard(this: *mut core::ffi::c_void, position: super::super::super::Foundation::Point, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer", "PasteFromClipboard", 13usize, |this| ((**(this as *const *const IInkStrokeContainer_Vtbl)).PasteFromClipboard)(this, position, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanPasteFromClipboard(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer", "CanPasteFromClipboard", 14usize, |this| ((**(this as *const *const IInkStrokeContainer_Vtbl)).CanPasteFromClipboard)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn LoadAsync(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer", "LoadAsync", 15usize, |this| ((**(this as *const *const IInkStrokeContainer_Vtbl)).LoadAsync)(this, inputstream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer", "SaveAsync", 16usize, |this| ((**(this as *const *const IInkStrokeContainer_Vtbl)).SaveAsync)(this, outputstream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UpdateRecognitionResults(this: *mut core::ffi::c_void, recognitionresults: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer", "UpdateRecognitionResults", 17usize, |this| ((**(this as *const *const IInkStrokeContainer_Vtbl)).UpdateRecognitionResults)(this, recognitionresults), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetStrokes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer", "GetStrokes", 18usize, |this| ((**(this as *const *const IInkStrokeContainer_Vtbl)).GetStrokes)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetRecognitionResults(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer", "GetRecognitionResults", 19usize, |this| ((**(this as *const *const IInkStrokeContainer_Vtbl)).GetRecognitionResults)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BoundingRect,
+            AddStroke,
+            DeleteSelected,
+            MoveSelected,
+            #[cfg(feature = "Foundation_Collections")]
+            SelectWithPolyLine,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SelectWithPolyLine: 0,
+            SelectWithLine,
+            CopySelectedToClipboard,
+            PasteFromClipboard,
+            CanPasteFromClipboard,
+            #[cfg(feature = "Storage_Streams")]
+            LoadAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            LoadAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SaveAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SaveAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            UpdateRecognitionResults,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UpdateRecognitionResults: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetStrokes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetStrokes: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetRecognitionResults,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetRecognitionResults: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStrokeContainer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkStrokeContainer2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AddStrokes(this: *mut core::ffi::c_void, strokes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer2", "AddStrokes", 6usize, |this| ((**(this as *const *const IInkStrokeContainer2_Vtbl)).AddStrokes)(this, strokes), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer2", "Clear", 7usize, |this| ((**(this as *const *const IInkStrokeContainer2_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            AddStrokes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AddStrokes: 0,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStrokeContainer2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IInkStrokeContainer3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SaveWithFormatAsync(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, inkpersistenceformat: InkPersistenceFormat, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer3", "SaveWithFormatAsync", 6usize, |this| ((**(this as *const *const IInkStrokeContainer3_Vtbl)).SaveWithFormatAsync)(this, outputstream, inkpersistenceformat, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrokeById(this: *mut core::ffi::c_void, id: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeContainer3", "GetStrokeById", 7usize, |this| ((**(this as *const *const IInkStrokeContainer3_Vtbl)).GetStrokeById)(this, id, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            SaveWithFormatAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SaveWithFormatAsync: 0,
+            GetStrokeById,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStrokeContainer3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Core")]
+impl windows_core::imp::ProxyVtable for IInkStrokeInput_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn StrokeStarted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeInput", "StrokeStarted", 6usize, |this| ((**(this as *const *const IInkStrokeInput_Vtbl)).StrokeStarted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStrokeStarted(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeInput", "RemoveStrokeStarted", 7usize, |this| ((**(this as *const *const IInkStrokeInput_Vtbl)).RemoveStrokeStarted)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn StrokeContinued(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeInput", "StrokeContinued", 8usize, |this| ((**(this as *const *const IInkStrokeInput_Vtbl)).StrokeContinued)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStrokeContinued(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeInput", "RemoveStrokeContinued", 9usize, |this| ((**(this as *const *const IInkStrokeInput_Vtbl)).RemoveStrokeContinued)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn StrokeEnded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeInput", "StrokeEnded", 10usize, |this| ((**(this as *const *const IInkStrokeInput_Vtbl)).StrokeEnded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStrokeEnded(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeInput", "RemoveStrokeEnded", 11usize, |this| ((**(this as *const *const IInkStrokeInput_Vtbl)).RemoveStrokeEnded)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn StrokeCanceled(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeInput", "StrokeCanceled", 12usize, |this| ((**(this as *const *const IInkStrokeInput_Vtbl)).StrokeCanceled)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStrokeCanceled(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeInput", "RemoveStrokeCanceled", 13usize, |this| ((**(this as *const *const IInkStrokeInput_Vtbl)).RemoveStrokeCanceled)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn InkPresenter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeInput", "InkPresenter", 14usize, |this| ((**(this as *const *const IInkStrokeInput_Vtbl)).InkPresenter)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Core")]
+            StrokeStarted,
+            #[cfg(not(feature = "UI_Core"))]
+            StrokeStarted: 0,
+            RemoveStrokeStarted,
+            #[cfg(feature = "UI_Core")]
+            StrokeContinued,
+            #[cfg(not(feature = "UI_Core"))]
+            StrokeContinued: 0,
+            RemoveStrokeContinued,
+            #[cfg(feature = "UI_Core")]
+            StrokeEnded,
+            #[cfg(not(feature = "UI_Core"))]
+            StrokeEnded: 0,
+            RemoveStrokeEnded,
+            #[cfg(feature = "UI_Core")]
+            StrokeCanceled,
+            #[cfg(not(feature = "UI_Core"))]
+            StrokeCanceled: 0,
+            RemoveStrokeCanceled,
+            InkPresenter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStrokeInput as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkStrokeRenderingSegment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeRenderingSegment", "Position", 6usize, |this| ((**(this as *const *const IInkStrokeRenderingSegment_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BezierControlPoint1(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeRenderingSegment", "BezierControlPoint1", 7usize, |this| ((**(this as *const *const IInkStrokeRenderingSegment_Vtbl)).BezierControlPoint1)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BezierControlPoint2(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeRenderingSegment", "BezierControlPoint2", 8usize, |this| ((**(this as *const *const IInkStrokeRenderingSegment_Vtbl)).BezierControlPoint2)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pressure(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeRenderingSegment", "Pressure", 9usize, |this| ((**(this as *const *const IInkStrokeRenderingSegment_Vtbl)).Pressure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TiltX(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeRenderingSegment", "TiltX", 10usize, |this| ((**(this as *const *const IInkStrokeRenderingSegment_Vtbl)).TiltX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TiltY(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeRenderingSegment", "TiltY", 11usize, |this| ((**(this as *const *const IInkStrokeRenderingSegment_Vtbl)).TiltY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Twist(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeRenderingSegment", "Twist", 12usize, |this| ((**(this as *const *const IInkStrokeRenderingSegment_Vtbl)).Twist)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Position,
+            BezierControlPoint1,
+            BezierControlPoint2,
+            Pressure,
+            TiltX,
+            TiltY,
+            Twist,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStrokeRenderingSegment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkStrokesCollectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Strokes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokesCollectedEventArgs", "Strokes", 6usize, |this| ((**(this as *const *const IInkStrokesCollectedEventArgs_Vtbl)).Strokes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Strokes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Strokes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStrokesCollectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkStrokesErasedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Strokes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokesErasedEventArgs", "Strokes", 6usize, |this| ((**(this as *const *const IInkStrokesErasedEventArgs_Vtbl)).Strokes)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Strokes,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Strokes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStrokesErasedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInkSynchronizer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn BeginDry(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkSynchronizer", "BeginDry", 6usize, |this| ((**(this as *const *const IInkSynchronizer_Vtbl)).BeginDry)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndDry(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkSynchronizer", "EndDry", 7usize, |this| ((**(this as *const *const IInkSynchronizer_Vtbl)).EndDry)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            BeginDry,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            BeginDry: 0,
+            EndDry,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkSynchronizer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Core")]
+impl windows_core::imp::ProxyVtable for IInkUnprocessedInput_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn PointerEntered(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkUnprocessedInput", "PointerEntered", 6usize, |this| ((**(this as *const *const IInkUnprocessedInput_Vtbl)).PointerEntered)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerEntered(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkUnprocessedInput", "RemovePointerEntered", 7usize, |this| ((**(this as *const *const IInkUnprocessedInput_Vtbl)).RemovePointerEntered)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn PointerHovered(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkUnprocessedInput", "PointerHovered", 8usize, |this| ((**(this as *const *const IInkUnprocessedInput_Vtbl)).PointerHovered)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerHovered(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkUnprocessedInput", "RemovePointerHovered", 9usize, |this| ((**(this as *const *const IInkUnprocessedInput_Vtbl)).RemovePointerHovered)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn PointerExited(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkUnprocessedInput", "PointerExited", 10usize, |this| ((**(this as *const *const IInkUnprocessedInput_Vtbl)).PointerExited)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerExited(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkUnprocessedInput", "RemovePointerExited", 11usize, |this| ((**(this as *const *const IInkUnprocessedInput_Vtbl)).RemovePointerExited)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn PointerPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkUnprocessedInput", "PointerPressed", 12usize, |this| ((**(this as *const *const IInkUnprocessedInput_Vtbl)).PointerPressed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerPressed(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkUnprocessedInput", "RemovePointerPressed", 13usize, |this| ((**(this as *const *const IInkUnprocessedInput_Vtbl)).RemovePointerPressed)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn PointerMoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkUnprocessedInput", "PointerMoved", 14usize, |this| ((**(this as *const *const IInkUnprocessedInput_Vtbl)).PointerMoved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerMoved(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkUnprocessedInput", "RemovePointerMoved", 15usize, |this| ((**(this as *const *const IInkUnprocessedInput_Vtbl)).RemovePointerMoved)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn PointerReleased(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkUnprocessedInput", "PointerReleased", 16usize, |this| ((**(this as *const *const IInkUnprocessedInput_Vtbl)).PointerReleased)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerReleased(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkUnprocessedInput", "RemovePointerReleased", 17usize, |this| ((**(this as *const *const IInkUnprocessedInput_Vtbl)).RemovePointerReleased)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn PointerLost(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkUnprocessedInput", "PointerLost", 18usize, |this| ((**(this as *const *const IInkUnprocessedInput_Vtbl)).PointerLost)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePointerLost(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkUnprocessedInput", "RemovePointerLost", 19usize, |this| ((**(this as *const *const IInkUnprocessedInput_Vtbl)).RemovePointerLost)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn InkPresenter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkUnprocessedInput", "InkPresenter", 20usize, |this| ((**(this as *const *const IInkUnprocessedInput_Vtbl)).InkPresenter)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Core")]
+            PointerEntered,
+            #[cfg(not(feature = "UI_Core"))]
+            PointerEntered: 0,
+            RemovePointerEntered,
+            #[cfg(feature = "UI_Core")]
+            PointerHovered,
+            #[cfg(not(feature = "UI_Core"))]
+            PointerHovered: 0,
+            RemovePointerHovered,
+            #[cfg(feature = "UI_Core")]
+            PointerExited,
+            #[cfg(not(feature = "UI_Core"))]
+            PointerExited: 0,
+            RemovePointerExited,
+            #[cfg(feature = "UI_Core")]
+            PointerPressed,
+            #[cfg(not(feature = "UI_Core"))]
+            PointerPressed: 0,
+            RemovePointerPressed,
+            #[cfg(feature = "UI_Core")]
+            PointerMoved,
+            #[cfg(not(feature = "UI_Core"))]
+            PointerMoved: 0,
+            RemovePointerMoved,
+            #[cfg(feature = "UI_Core")]
+            PointerReleased,
+            #[cfg(not(feature = "UI_Core"))]
+            PointerReleased: 0,
+            RemovePointerReleased,
+            #[cfg(feature = "UI_Core")]
+            PointerLost,
+            #[cfg(not(feature = "UI_Core"))]
+            PointerLost: 0,
+            RemovePointerLost,
+            InkPresenter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkUnprocessedInput as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPenAndInkSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsHandwritingDirectlyIntoTextFieldEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenAndInkSettings", "IsHandwritingDirectlyIntoTextFieldEnabled", 6usize, |this| ((**(this as *const *const IPenAndInkSettings_Vtbl)).IsHandwritingDirectlyIntoTextFieldEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PenHandedness(this: *mut core::ffi::c_void, result__: *mut PenHandedness) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenAndInkSettings", "PenHandedness", 7usize, |this| ((**(this as *const *const IPenAndInkSettings_Vtbl)).PenHandedness)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandwritingLineHeight(this: *mut core::ffi::c_void, result__: *mut HandwritingLineHeight) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenAndInkSettings", "HandwritingLineHeight", 8usize, |this| ((**(this as *const *const IPenAndInkSettings_Vtbl)).HandwritingLineHeight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FontFamilyName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenAndInkSettings", "FontFamilyName", 9usize, |this| ((**(this as *const *const IPenAndInkSettings_Vtbl)).FontFamilyName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserConsentsToHandwritingTelemetryCollection(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenAndInkSettings", "UserConsentsToHandwritingTelemetryCollection", 10usize, |this| ((**(this as *const *const IPenAndInkSettings_Vtbl)).UserConsentsToHandwritingTelemetryCollection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTouchHandwritingEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenAndInkSettings", "IsTouchHandwritingEnabled", 11usize, |this| ((**(this as *const *const IPenAndInkSettings_Vtbl)).IsTouchHandwritingEnabled)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsHandwritingDirectlyIntoTextFieldEnabled,
+            PenHandedness,
+            HandwritingLineHeight,
+            FontFamilyName,
+            UserConsentsToHandwritingTelemetryCollection,
+            IsTouchHandwritingEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenAndInkSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPenAndInkSettings2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPenHandedness(this: *mut core::ffi::c_void, value: PenHandedness) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenAndInkSettings2", "SetPenHandedness", 6usize, |this| ((**(this as *const *const IPenAndInkSettings2_Vtbl)).SetPenHandedness)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPenHandedness }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenAndInkSettings2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPenAndInkSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenAndInkSettingsStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IPenAndInkSettingsStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenAndInkSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -714,6 +714,7 @@ impl InjectedInputButtonChangeKind {
 impl windows_core::TypeKind for InjectedInputButtonChangeKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InjectedInputButtonChangeKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InjectedInputButtonChangeKind").field(&self.0).finish()
@@ -735,6 +736,7 @@ impl InjectedInputKeyOptions {
 impl windows_core::TypeKind for InjectedInputKeyOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InjectedInputKeyOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InjectedInputKeyOptions").field(&self.0).finish()
@@ -799,6 +801,7 @@ impl InjectedInputMouseOptions {
 impl windows_core::TypeKind for InjectedInputMouseOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InjectedInputMouseOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InjectedInputMouseOptions").field(&self.0).finish()
@@ -852,6 +855,7 @@ impl InjectedInputPenButtons {
 impl windows_core::TypeKind for InjectedInputPenButtons {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InjectedInputPenButtons {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InjectedInputPenButtons").field(&self.0).finish()
@@ -906,6 +910,7 @@ impl InjectedInputPenParameters {
 impl windows_core::TypeKind for InjectedInputPenParameters {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InjectedInputPenParameters {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InjectedInputPenParameters").field(&self.0).finish()
@@ -968,6 +973,7 @@ impl InjectedInputPointerOptions {
 impl windows_core::TypeKind for InjectedInputPointerOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InjectedInputPointerOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InjectedInputPointerOptions").field(&self.0).finish()
@@ -1020,6 +1026,7 @@ impl InjectedInputShortcut {
 impl windows_core::TypeKind for InjectedInputShortcut {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InjectedInputShortcut {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InjectedInputShortcut").field(&self.0).finish()
@@ -1040,6 +1047,7 @@ impl InjectedInputTouchParameters {
 impl windows_core::TypeKind for InjectedInputTouchParameters {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InjectedInputTouchParameters {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InjectedInputTouchParameters").field(&self.0).finish()
@@ -1092,6 +1100,7 @@ impl InjectedInputVisualizationMode {
 impl windows_core::TypeKind for InjectedInputVisualizationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InjectedInputVisualizationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InjectedInputVisualizationMode").field(&self.0).finish()
@@ -1101,7 +1110,8 @@ impl windows_core::RuntimeType for InjectedInputVisualizationMode {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.Input.Preview.Injection.InjectedInputVisualizationMode;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct InjectedInputPoint {
     pub PositionX: i32,
     pub PositionY: i32,
@@ -1118,7 +1128,8 @@ impl Default for InjectedInputPoint {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct InjectedInputPointerInfo {
     pub PointerId: u32,
     pub PointerOptions: InjectedInputPointerOptions,
@@ -1138,7 +1149,8 @@ impl Default for InjectedInputPointerInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct InjectedInputRectangle {
     pub Left: i32,
     pub Top: i32,
@@ -1156,3 +1168,5 @@ impl Default for InjectedInputRectangle {
         unsafe { core::mem::zeroed() }
     }
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,408 @@
+#[cfg(feature = "Gaming_Input")]
+impl windows_core::imp::ProxyVtable for IInjectedInputGamepadInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Gaming_Input")]
+        unsafe extern "system" fn Buttons(this: *mut core::ffi::c_void, result__: *mut super::super::super::super::Gaming::Input::GamepadButtons) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputGamepadInfo", "Buttons", 6usize, |this| ((**(this as *const *const IInjectedInputGamepadInfo_Vtbl)).Buttons)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Gaming_Input")]
+        unsafe extern "system" fn SetButtons(this: *mut core::ffi::c_void, value: super::super::super::super::Gaming::Input::GamepadButtons) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputGamepadInfo", "SetButtons", 7usize, |this| ((**(this as *const *const IInjectedInputGamepadInfo_Vtbl)).SetButtons)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeftThumbstickX(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputGamepadInfo", "LeftThumbstickX", 8usize, |this| ((**(this as *const *const IInjectedInputGamepadInfo_Vtbl)).LeftThumbstickX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLeftThumbstickX(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputGamepadInfo", "SetLeftThumbstickX", 9usize, |this| ((**(this as *const *const IInjectedInputGamepadInfo_Vtbl)).SetLeftThumbstickX)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeftThumbstickY(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputGamepadInfo", "LeftThumbstickY", 10usize, |this| ((**(this as *const *const IInjectedInputGamepadInfo_Vtbl)).LeftThumbstickY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLeftThumbstickY(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputGamepadInfo", "SetLeftThumbstickY", 11usize, |this| ((**(this as *const *const IInjectedInputGamepadInfo_Vtbl)).SetLeftThumbstickY)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeftTrigger(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputGamepadInfo", "LeftTrigger", 12usize, |this| ((**(this as *const *const IInjectedInputGamepadInfo_Vtbl)).LeftTrigger)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLeftTrigger(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputGamepadInfo", "SetLeftTrigger", 13usize, |this| ((**(this as *const *const IInjectedInputGamepadInfo_Vtbl)).SetLeftTrigger)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RightThumbstickX(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputGamepadInfo", "RightThumbstickX", 14usize, |this| ((**(this as *const *const IInjectedInputGamepadInfo_Vtbl)).RightThumbstickX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRightThumbstickX(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputGamepadInfo", "SetRightThumbstickX", 15usize, |this| ((**(this as *const *const IInjectedInputGamepadInfo_Vtbl)).SetRightThumbstickX)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RightThumbstickY(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputGamepadInfo", "RightThumbstickY", 16usize, |this| ((**(this as *const *const IInjectedInputGamepadInfo_Vtbl)).RightThumbstickY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRightThumbstickY(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputGamepadInfo", "SetRightThumbstickY", 17usize, |this| ((**(this as *const *const IInjectedInputGamepadInfo_Vtbl)).SetRightThumbstickY)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RightTrigger(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputGamepadInfo", "RightTrigger", 18usize, |this| ((**(this as *const *const IInjectedInputGamepadInfo_Vtbl)).RightTrigger)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRightTrigger(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputGamepadInfo", "SetRightTrigger", 19usize, |this| ((**(this as *const *const IInjectedInputGamepadInfo_Vtbl)).SetRightTrigger)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Gaming_Input")]
+            Buttons,
+            #[cfg(not(feature = "Gaming_Input"))]
+            Buttons: 0,
+            #[cfg(feature = "Gaming_Input")]
+            SetButtons,
+            #[cfg(not(feature = "Gaming_Input"))]
+            SetButtons: 0,
+            LeftThumbstickX,
+            SetLeftThumbstickX,
+            LeftThumbstickY,
+            SetLeftThumbstickY,
+            LeftTrigger,
+            SetLeftTrigger,
+            RightThumbstickX,
+            SetRightThumbstickX,
+            RightThumbstickY,
+            SetRightThumbstickY,
+            RightTrigger,
+            SetRightTrigger,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInjectedInputGamepadInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Gaming_Input")]
+impl windows_core::imp::ProxyVtable for IInjectedInputGamepadInfoFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Gaming_Input")]
+        unsafe extern "system" fn CreateInstanceFromGamepadReading(this: *mut core::ffi::c_void, reading: super::super::super::super::Gaming::Input::GamepadReading, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputGamepadInfoFactory", "CreateInstanceFromGamepadReading", 6usize, |this| ((**(this as *const *const IInjectedInputGamepadInfoFactory_Vtbl)).CreateInstanceFromGamepadReading)(this, reading, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Gaming_Input")]
+            CreateInstanceFromGamepadReading,
+            #[cfg(not(feature = "Gaming_Input"))]
+            CreateInstanceFromGamepadReading: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInjectedInputGamepadInfoFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInjectedInputKeyboardInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KeyOptions(this: *mut core::ffi::c_void, result__: *mut InjectedInputKeyOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputKeyboardInfo", "KeyOptions", 6usize, |this| ((**(this as *const *const IInjectedInputKeyboardInfo_Vtbl)).KeyOptions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeyOptions(this: *mut core::ffi::c_void, value: InjectedInputKeyOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputKeyboardInfo", "SetKeyOptions", 7usize, |this| ((**(this as *const *const IInjectedInputKeyboardInfo_Vtbl)).SetKeyOptions)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScanCode(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputKeyboardInfo", "ScanCode", 8usize, |this| ((**(this as *const *const IInjectedInputKeyboardInfo_Vtbl)).ScanCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScanCode(this: *mut core::ffi::c_void, value: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputKeyboardInfo", "SetScanCode", 9usize, |this| ((**(this as *const *const IInjectedInputKeyboardInfo_Vtbl)).SetScanCode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VirtualKey(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputKeyboardInfo", "VirtualKey", 10usize, |this| ((**(this as *const *const IInjectedInputKeyboardInfo_Vtbl)).VirtualKey)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVirtualKey(this: *mut core::ffi::c_void, value: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputKeyboardInfo", "SetVirtualKey", 11usize, |this| ((**(this as *const *const IInjectedInputKeyboardInfo_Vtbl)).SetVirtualKey)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            KeyOptions,
+            SetKeyOptions,
+            ScanCode,
+            SetScanCode,
+            VirtualKey,
+            SetVirtualKey,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInjectedInputKeyboardInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInjectedInputMouseInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MouseOptions(this: *mut core::ffi::c_void, result__: *mut InjectedInputMouseOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputMouseInfo", "MouseOptions", 6usize, |this| ((**(this as *const *const IInjectedInputMouseInfo_Vtbl)).MouseOptions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMouseOptions(this: *mut core::ffi::c_void, value: InjectedInputMouseOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputMouseInfo", "SetMouseOptions", 7usize, |this| ((**(this as *const *const IInjectedInputMouseInfo_Vtbl)).SetMouseOptions)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MouseData(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputMouseInfo", "MouseData", 8usize, |this| ((**(this as *const *const IInjectedInputMouseInfo_Vtbl)).MouseData)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMouseData(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputMouseInfo", "SetMouseData", 9usize, |this| ((**(this as *const *const IInjectedInputMouseInfo_Vtbl)).SetMouseData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeltaY(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputMouseInfo", "DeltaY", 10usize, |this| ((**(this as *const *const IInjectedInputMouseInfo_Vtbl)).DeltaY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeltaY(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputMouseInfo", "SetDeltaY", 11usize, |this| ((**(this as *const *const IInjectedInputMouseInfo_Vtbl)).SetDeltaY)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeltaX(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputMouseInfo", "DeltaX", 12usize, |this| ((**(this as *const *const IInjectedInputMouseInfo_Vtbl)).DeltaX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeltaX(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputMouseInfo", "SetDeltaX", 13usize, |this| ((**(this as *const *const IInjectedInputMouseInfo_Vtbl)).SetDeltaX)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeOffsetInMilliseconds(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputMouseInfo", "TimeOffsetInMilliseconds", 14usize, |this| ((**(this as *const *const IInjectedInputMouseInfo_Vtbl)).TimeOffsetInMilliseconds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimeOffsetInMilliseconds(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputMouseInfo", "SetTimeOffsetInMilliseconds", 15usize, |this| ((**(this as *const *const IInjectedInputMouseInfo_Vtbl)).SetTimeOffsetInMilliseconds)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MouseOptions,
+            SetMouseOptions,
+            MouseData,
+            SetMouseData,
+            DeltaY,
+            SetDeltaY,
+            DeltaX,
+            SetDeltaX,
+            TimeOffsetInMilliseconds,
+            SetTimeOffsetInMilliseconds,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInjectedInputMouseInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInjectedInputPenInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PointerInfo(this: *mut core::ffi::c_void, result__: *mut InjectedInputPointerInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputPenInfo", "PointerInfo", 6usize, |this| ((**(this as *const *const IInjectedInputPenInfo_Vtbl)).PointerInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPointerInfo(this: *mut core::ffi::c_void, value: InjectedInputPointerInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputPenInfo", "SetPointerInfo", 7usize, |this| ((**(this as *const *const IInjectedInputPenInfo_Vtbl)).SetPointerInfo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PenButtons(this: *mut core::ffi::c_void, result__: *mut InjectedInputPenButtons) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputPenInfo", "PenButtons", 8usize, |this| ((**(this as *const *const IInjectedInputPenInfo_Vtbl)).PenButtons)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPenButtons(this: *mut core::ffi::c_void, value: InjectedInputPenButtons) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputPenInfo", "SetPenButtons", 9usize, |this| ((**(this as *const *const IInjectedInputPenInfo_Vtbl)).SetPenButtons)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PenParameters(this: *mut core::ffi::c_void, result__: *mut InjectedInputPenParameters) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputPenInfo", "PenParameters", 10usize, |this| ((**(this as *const *const IInjectedInputPenInfo_Vtbl)).PenParameters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPenParameters(this: *mut core::ffi::c_void, value: InjectedInputPenParameters) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputPenInfo", "SetPenParameters", 11usize, |this| ((**(this as *const *const IInjectedInputPenInfo_Vtbl)).SetPenParameters)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pressure(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputPenInfo", "Pressure", 12usize, |this| ((**(this as *const *const IInjectedInputPenInfo_Vtbl)).Pressure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPressure(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputPenInfo", "SetPressure", 13usize, |this| ((**(this as *const *const IInjectedInputPenInfo_Vtbl)).SetPressure)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rotation(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputPenInfo", "Rotation", 14usize, |this| ((**(this as *const *const IInjectedInputPenInfo_Vtbl)).Rotation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotation(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputPenInfo", "SetRotation", 15usize, |this| ((**(this as *const *const IInjectedInputPenInfo_Vtbl)).SetRotation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TiltX(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputPenInfo", "TiltX", 16usize, |this| ((**(this as *const *const IInjectedInputPenInfo_Vtbl)).TiltX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTiltX(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputPenInfo", "SetTiltX", 17usize, |this| ((**(this as *const *const IInjectedInputPenInfo_Vtbl)).SetTiltX)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TiltY(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputPenInfo", "TiltY", 18usize, |this| ((**(this as *const *const IInjectedInputPenInfo_Vtbl)).TiltY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTiltY(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputPenInfo", "SetTiltY", 19usize, |this| ((**(this as *const *const IInjectedInputPenInfo_Vtbl)).SetTiltY)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PointerInfo,
+            SetPointerInfo,
+            PenButtons,
+            SetPenButtons,
+            PenParameters,
+            SetPenParameters,
+            Pressure,
+            SetPressure,
+            Rotation,
+            SetRotation,
+            TiltX,
+            SetTiltX,
+            TiltY,
+            SetTiltY,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInjectedInputPenInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInjectedInputTouchInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut InjectedInputRectangle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputTouchInfo", "Contact", 6usize, |this| ((**(this as *const *const IInjectedInputTouchInfo_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContact(this: *mut core::ffi::c_void, value: InjectedInputRectangle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputTouchInfo", "SetContact", 7usize, |this| ((**(this as *const *const IInjectedInputTouchInfo_Vtbl)).SetContact)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputTouchInfo", "Orientation", 8usize, |this| ((**(this as *const *const IInjectedInputTouchInfo_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOrientation(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputTouchInfo", "SetOrientation", 9usize, |this| ((**(this as *const *const IInjectedInputTouchInfo_Vtbl)).SetOrientation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerInfo(this: *mut core::ffi::c_void, result__: *mut InjectedInputPointerInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputTouchInfo", "PointerInfo", 10usize, |this| ((**(this as *const *const IInjectedInputTouchInfo_Vtbl)).PointerInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPointerInfo(this: *mut core::ffi::c_void, value: InjectedInputPointerInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputTouchInfo", "SetPointerInfo", 11usize, |this| ((**(this as *const *const IInjectedInputTouchInfo_Vtbl)).SetPointerInfo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pressure(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputTouchInfo", "Pressure", 12usize, |this| ((**(this as *const *const IInjectedInputTouchInfo_Vtbl)).Pressure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPressure(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputTouchInfo", "SetPressure", 13usize, |this| ((**(this as *const *const IInjectedInputTouchInfo_Vtbl)).SetPressure)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TouchParameters(this: *mut core::ffi::c_void, result__: *mut InjectedInputTouchParameters) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputTouchInfo", "TouchParameters", 14usize, |this| ((**(this as *const *const IInjectedInputTouchInfo_Vtbl)).TouchParameters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTouchParameters(this: *mut core::ffi::c_void, value: InjectedInputTouchParameters) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInjectedInputTouchInfo", "SetTouchParameters", 15usize, |this| ((**(this as *const *const IInjectedInputTouchInfo_Vtbl)).SetTouchParameters)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Contact,
+            SetContact,
+            Orientation,
+            SetOrientation,
+            PointerInfo,
+            SetPointerInfo,
+            Pressure,
+            SetPressure,
+            TouchParameters,
+            SetTouchParameters,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInjectedInputTouchInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInputInjector_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn InjectKeyboardInput(this: *mut core::ffi::c_void, input: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputInjector", "InjectKeyboardInput", 6usize, |this| ((**(this as *const *const IInputInjector_Vtbl)).InjectKeyboardInput)(this, input), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn InjectMouseInput(this: *mut core::ffi::c_void, input: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputInjector", "InjectMouseInput", 7usize, |this| ((**(this as *const *const IInputInjector_Vtbl)).InjectMouseInput)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeTouchInjection(this: *mut core::ffi::c_void, visualmode: InjectedInputVisualizationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputInjector", "InitializeTouchInjection", 8usize, |this| ((**(this as *const *const IInputInjector_Vtbl)).InitializeTouchInjection)(this, visualmode), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn InjectTouchInput(this: *mut core::ffi::c_void, input: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputInjector", "InjectTouchInput", 9usize, |this| ((**(this as *const *const IInputInjector_Vtbl)).InjectTouchInput)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn UninitializeTouchInjection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputInjector", "UninitializeTouchInjection", 10usize, |this| ((**(this as *const *const IInputInjector_Vtbl)).UninitializeTouchInjection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializePenInjection(this: *mut core::ffi::c_void, visualmode: InjectedInputVisualizationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputInjector", "InitializePenInjection", 11usize, |this| ((**(this as *const *const IInputInjector_Vtbl)).InitializePenInjection)(this, visualmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn InjectPenInput(this: *mut core::ffi::c_void, input: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputInjector", "InjectPenInput", 12usize, |this| ((**(this as *const *const IInputInjector_Vtbl)).InjectPenInput)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn UninitializePenInjection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputInjector", "UninitializePenInjection", 13usize, |this| ((**(this as *const *const IInputInjector_Vtbl)).UninitializePenInjection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InjectShortcut(this: *mut core::ffi::c_void, shortcut: InjectedInputShortcut) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputInjector", "InjectShortcut", 14usize, |this| ((**(this as *const *const IInputInjector_Vtbl)).InjectShortcut)(this, shortcut), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            InjectKeyboardInput,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            InjectKeyboardInput: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            InjectMouseInput,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            InjectMouseInput: 0,
+            InitializeTouchInjection,
+            #[cfg(feature = "Foundation_Collections")]
+            InjectTouchInput,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            InjectTouchInput: 0,
+            UninitializeTouchInjection,
+            InitializePenInjection,
+            InjectPenInput,
+            UninitializePenInjection,
+            InjectShortcut,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputInjector as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IInputInjector2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeGamepadInjection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputInjector2", "InitializeGamepadInjection", 6usize, |this| ((**(this as *const *const IInputInjector2_Vtbl)).InitializeGamepadInjection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InjectGamepadInput(this: *mut core::ffi::c_void, input: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputInjector2", "InjectGamepadInput", 7usize, |this| ((**(this as *const *const IInputInjector2_Vtbl)).InjectGamepadInput)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn UninitializeGamepadInjection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputInjector2", "UninitializeGamepadInjection", 8usize, |this| ((**(this as *const *const IInputInjector2_Vtbl)).UninitializeGamepadInjection)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeGamepadInjection,
+            InjectGamepadInput,
+            UninitializeGamepadInjection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputInjector2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInputInjectorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryCreate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputInjectorStatics", "TryCreate", 6usize, |this| ((**(this as *const *const IInputInjectorStatics_Vtbl)).TryCreate)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryCreate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputInjectorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInputInjectorStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryCreateForAppBroadcastOnly(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputInjectorStatics2", "TryCreateForAppBroadcastOnly", 6usize, |this| ((**(this as *const *const IInputInjectorStatics2_Vtbl)).TryCreateForAppBroadcastOnly)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryCreateForAppBroadcastOnly }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputInjectorStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -33,3 +33,5 @@ impl InputActivationListenerPreview {
 impl windows_core::RuntimeName for InputActivationListenerPreview {
     const NAME: &'static str = "Windows.UI.Input.Preview.InputActivationListenerPreview";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,20 @@
+#[cfg(feature = "UI_WindowManagement")]
+impl windows_core::imp::ProxyVtable for IInputActivationListenerPreviewStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_WindowManagement")]
+        unsafe extern "system" fn CreateForApplicationWindow(this: *mut core::ffi::c_void, window: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputActivationListenerPreviewStatics", "CreateForApplicationWindow", 6usize, |this| ((**(this as *const *const IInputActivationListenerPreviewStatics_Vtbl)).CreateForApplicationWindow)(this, window, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_WindowManagement")]
+            CreateForApplicationWindow,
+            #[cfg(not(feature = "UI_WindowManagement"))]
+            CreateForApplicationWindow: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputActivationListenerPreviewStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -2208,6 +2208,7 @@ impl SpatialGestureSettings {
 impl windows_core::TypeKind for SpatialGestureSettings {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialGestureSettings {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialGestureSettings").field(&self.0).finish()
@@ -2263,6 +2264,7 @@ impl SpatialInteractionPressKind {
 impl windows_core::TypeKind for SpatialInteractionPressKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialInteractionPressKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialInteractionPressKind").field(&self.0).finish()
@@ -2282,6 +2284,7 @@ impl SpatialInteractionSourceHandedness {
 impl windows_core::TypeKind for SpatialInteractionSourceHandedness {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialInteractionSourceHandedness {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialInteractionSourceHandedness").field(&self.0).finish()
@@ -2302,6 +2305,7 @@ impl SpatialInteractionSourceKind {
 impl windows_core::TypeKind for SpatialInteractionSourceKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialInteractionSourceKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialInteractionSourceKind").field(&self.0).finish()
@@ -2320,6 +2324,7 @@ impl SpatialInteractionSourcePositionAccuracy {
 impl windows_core::TypeKind for SpatialInteractionSourcePositionAccuracy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialInteractionSourcePositionAccuracy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialInteractionSourcePositionAccuracy").field(&self.0).finish()
@@ -2328,3 +2333,5 @@ impl core::fmt::Debug for SpatialInteractionSourcePositionAccuracy {
 impl windows_core::RuntimeType for SpatialInteractionSourcePositionAccuracy {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.Input.Spatial.SpatialInteractionSourcePositionAccuracy;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1192 @@
+impl windows_core::imp::ProxyVtable for ISpatialGestureRecognizer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RecognitionStarted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RecognitionStarted", 6usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RecognitionStarted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRecognitionStarted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RemoveRecognitionStarted", 7usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RemoveRecognitionStarted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecognitionEnded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RecognitionEnded", 8usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RecognitionEnded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRecognitionEnded(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RemoveRecognitionEnded", 9usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RemoveRecognitionEnded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tapped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "Tapped", 10usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).Tapped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTapped(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RemoveTapped", 11usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RemoveTapped)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoldStarted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "HoldStarted", 12usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).HoldStarted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHoldStarted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RemoveHoldStarted", 13usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RemoveHoldStarted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoldCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "HoldCompleted", 14usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).HoldCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHoldCompleted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RemoveHoldCompleted", 15usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RemoveHoldCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoldCanceled(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "HoldCanceled", 16usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).HoldCanceled)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHoldCanceled(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RemoveHoldCanceled", 17usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RemoveHoldCanceled)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManipulationStarted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "ManipulationStarted", 18usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).ManipulationStarted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveManipulationStarted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RemoveManipulationStarted", 19usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RemoveManipulationStarted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManipulationUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "ManipulationUpdated", 20usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).ManipulationUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveManipulationUpdated(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RemoveManipulationUpdated", 21usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RemoveManipulationUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManipulationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "ManipulationCompleted", 22usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).ManipulationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveManipulationCompleted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RemoveManipulationCompleted", 23usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RemoveManipulationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManipulationCanceled(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "ManipulationCanceled", 24usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).ManipulationCanceled)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveManipulationCanceled(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RemoveManipulationCanceled", 25usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RemoveManipulationCanceled)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn NavigationStarted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "NavigationStarted", 26usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).NavigationStarted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNavigationStarted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RemoveNavigationStarted", 27usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RemoveNavigationStarted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn NavigationUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "NavigationUpdated", 28usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).NavigationUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNavigationUpdated(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RemoveNavigationUpdated", 29usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RemoveNavigationUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn NavigationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "NavigationCompleted", 30usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).NavigationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNavigationCompleted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RemoveNavigationCompleted", 31usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RemoveNavigationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn NavigationCanceled(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "NavigationCanceled", 32usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).NavigationCanceled)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNavigationCanceled(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "RemoveNavigationCanceled", 33usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).RemoveNavigationCanceled)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CaptureInteraction(this: *mut core::ffi::c_void, interaction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "CaptureInteraction", 34usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).CaptureInteraction)(this, interaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelPendingGestures(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "CancelPendingGestures", 35usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).CancelPendingGestures)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySetGestureSettings(this: *mut core::ffi::c_void, settings: SpatialGestureSettings, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "TrySetGestureSettings", 36usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).TrySetGestureSettings)(this, settings, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GestureSettings(this: *mut core::ffi::c_void, result__: *mut SpatialGestureSettings) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizer", "GestureSettings", 37usize, |this| ((**(this as *const *const ISpatialGestureRecognizer_Vtbl)).GestureSettings)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RecognitionStarted,
+            RemoveRecognitionStarted,
+            RecognitionEnded,
+            RemoveRecognitionEnded,
+            Tapped,
+            RemoveTapped,
+            HoldStarted,
+            RemoveHoldStarted,
+            HoldCompleted,
+            RemoveHoldCompleted,
+            HoldCanceled,
+            RemoveHoldCanceled,
+            ManipulationStarted,
+            RemoveManipulationStarted,
+            ManipulationUpdated,
+            RemoveManipulationUpdated,
+            ManipulationCompleted,
+            RemoveManipulationCompleted,
+            ManipulationCanceled,
+            RemoveManipulationCanceled,
+            NavigationStarted,
+            RemoveNavigationStarted,
+            NavigationUpdated,
+            RemoveNavigationUpdated,
+            NavigationCompleted,
+            RemoveNavigationCompleted,
+            NavigationCanceled,
+            RemoveNavigationCanceled,
+            CaptureInteraction,
+            CancelPendingGestures,
+            TrySetGestureSettings,
+            GestureSettings,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialGestureRecognizer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialGestureRecognizerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, settings: SpatialGestureSettings, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialGestureRecognizerFactory", "Create", 6usize, |this| ((**(this as *const *const ISpatialGestureRecognizerFactory_Vtbl)).Create)(this, settings, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialGestureRecognizerFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialHoldCanceledEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSourceKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialHoldCanceledEventArgs", "InteractionSourceKind", 6usize, |this| ((**(this as *const *const ISpatialHoldCanceledEventArgs_Vtbl)).InteractionSourceKind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InteractionSourceKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialHoldCanceledEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialHoldCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSourceKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialHoldCompletedEventArgs", "InteractionSourceKind", 6usize, |this| ((**(this as *const *const ISpatialHoldCompletedEventArgs_Vtbl)).InteractionSourceKind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InteractionSourceKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialHoldCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_Spatial")]
+impl windows_core::imp::ProxyVtable for ISpatialHoldStartedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSourceKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialHoldStartedEventArgs", "InteractionSourceKind", 6usize, |this| ((**(this as *const *const ISpatialHoldStartedEventArgs_Vtbl)).InteractionSourceKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn TryGetPointerPose(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialHoldStartedEventArgs", "TryGetPointerPose", 7usize, |this| ((**(this as *const *const ISpatialHoldStartedEventArgs_Vtbl)).TryGetPointerPose)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InteractionSourceKind,
+            #[cfg(feature = "Perception_Spatial")]
+            TryGetPointerPose,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            TryGetPointerPose: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialHoldStartedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialInteraction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SourceState(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteraction", "SourceState", 6usize, |this| ((**(this as *const *const ISpatialInteraction_Vtbl)).SourceState)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SourceState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteraction as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Haptics")]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasTouchpad(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionController", "HasTouchpad", 6usize, |this| ((**(this as *const *const ISpatialInteractionController_Vtbl)).HasTouchpad)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasThumbstick(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionController", "HasThumbstick", 7usize, |this| ((**(this as *const *const ISpatialInteractionController_Vtbl)).HasThumbstick)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Haptics")]
+        unsafe extern "system" fn SimpleHapticsController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionController", "SimpleHapticsController", 8usize, |this| ((**(this as *const *const ISpatialInteractionController_Vtbl)).SimpleHapticsController)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VendorId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionController", "VendorId", 9usize, |this| ((**(this as *const *const ISpatialInteractionController_Vtbl)).VendorId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProductId(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionController", "ProductId", 10usize, |this| ((**(this as *const *const ISpatialInteractionController_Vtbl)).ProductId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionController", "Version", 11usize, |this| ((**(this as *const *const ISpatialInteractionController_Vtbl)).Version)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HasTouchpad,
+            HasThumbstick,
+            #[cfg(feature = "Devices_Haptics")]
+            SimpleHapticsController,
+            #[cfg(not(feature = "Devices_Haptics"))]
+            SimpleHapticsController: 0,
+            VendorId,
+            ProductId,
+            Version,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Haptics", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionController2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn TryGetRenderableModelAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionController2", "TryGetRenderableModelAsync", 6usize, |this| ((**(this as *const *const ISpatialInteractionController2_Vtbl)).TryGetRenderableModelAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            TryGetRenderableModelAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            TryGetRenderableModelAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionController2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Haptics", feature = "Devices_Power", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionController3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Power")]
+        unsafe extern "system" fn TryGetBatteryReport(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionController3", "TryGetBatteryReport", 6usize, |this| ((**(this as *const *const ISpatialInteractionController3_Vtbl)).TryGetBatteryReport)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Power")]
+            TryGetBatteryReport,
+            #[cfg(not(feature = "Devices_Power"))]
+            TryGetBatteryReport: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionController3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialInteractionControllerProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTouchpadTouched(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionControllerProperties", "IsTouchpadTouched", 6usize, |this| ((**(this as *const *const ISpatialInteractionControllerProperties_Vtbl)).IsTouchpadTouched)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTouchpadPressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionControllerProperties", "IsTouchpadPressed", 7usize, |this| ((**(this as *const *const ISpatialInteractionControllerProperties_Vtbl)).IsTouchpadPressed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsThumbstickPressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionControllerProperties", "IsThumbstickPressed", 8usize, |this| ((**(this as *const *const ISpatialInteractionControllerProperties_Vtbl)).IsThumbstickPressed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThumbstickX(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionControllerProperties", "ThumbstickX", 9usize, |this| ((**(this as *const *const ISpatialInteractionControllerProperties_Vtbl)).ThumbstickX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThumbstickY(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionControllerProperties", "ThumbstickY", 10usize, |this| ((**(this as *const *const ISpatialInteractionControllerProperties_Vtbl)).ThumbstickY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TouchpadX(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionControllerProperties", "TouchpadX", 11usize, |this| ((**(this as *const *const ISpatialInteractionControllerProperties_Vtbl)).TouchpadX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TouchpadY(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionControllerProperties", "TouchpadY", 12usize, |this| ((**(this as *const *const ISpatialInteractionControllerProperties_Vtbl)).TouchpadY)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsTouchpadTouched,
+            IsTouchpadPressed,
+            IsThumbstickPressed,
+            ThumbstickX,
+            ThumbstickY,
+            TouchpadX,
+            TouchpadY,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionControllerProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_Spatial")]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionDetectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSourceKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionDetectedEventArgs", "InteractionSourceKind", 6usize, |this| ((**(this as *const *const ISpatialInteractionDetectedEventArgs_Vtbl)).InteractionSourceKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn TryGetPointerPose(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionDetectedEventArgs", "TryGetPointerPose", 7usize, |this| ((**(this as *const *const ISpatialInteractionDetectedEventArgs_Vtbl)).TryGetPointerPose)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Interaction(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionDetectedEventArgs", "Interaction", 8usize, |this| ((**(this as *const *const ISpatialInteractionDetectedEventArgs_Vtbl)).Interaction)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InteractionSourceKind,
+            #[cfg(feature = "Perception_Spatial")]
+            TryGetPointerPose,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            TryGetPointerPose: 0,
+            Interaction,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionDetectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_Spatial")]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionDetectedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSource(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionDetectedEventArgs2", "InteractionSource", 6usize, |this| ((**(this as *const *const ISpatialInteractionDetectedEventArgs2_Vtbl)).InteractionSource)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InteractionSource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionDetectedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Perception"))]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SourceDetected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManager", "SourceDetected", 6usize, |this| ((**(this as *const *const ISpatialInteractionManager_Vtbl)).SourceDetected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSourceDetected(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManager", "RemoveSourceDetected", 7usize, |this| ((**(this as *const *const ISpatialInteractionManager_Vtbl)).RemoveSourceDetected)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceLost(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManager", "SourceLost", 8usize, |this| ((**(this as *const *const ISpatialInteractionManager_Vtbl)).SourceLost)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSourceLost(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManager", "RemoveSourceLost", 9usize, |this| ((**(this as *const *const ISpatialInteractionManager_Vtbl)).RemoveSourceLost)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManager", "SourceUpdated", 10usize, |this| ((**(this as *const *const ISpatialInteractionManager_Vtbl)).SourceUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSourceUpdated(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManager", "RemoveSourceUpdated", 11usize, |this| ((**(this as *const *const ISpatialInteractionManager_Vtbl)).RemoveSourceUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourcePressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManager", "SourcePressed", 12usize, |this| ((**(this as *const *const ISpatialInteractionManager_Vtbl)).SourcePressed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSourcePressed(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManager", "RemoveSourcePressed", 13usize, |this| ((**(this as *const *const ISpatialInteractionManager_Vtbl)).RemoveSourcePressed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceReleased(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManager", "SourceReleased", 14usize, |this| ((**(this as *const *const ISpatialInteractionManager_Vtbl)).SourceReleased)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSourceReleased(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManager", "RemoveSourceReleased", 15usize, |this| ((**(this as *const *const ISpatialInteractionManager_Vtbl)).RemoveSourceReleased)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn InteractionDetected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManager", "InteractionDetected", 16usize, |this| ((**(this as *const *const ISpatialInteractionManager_Vtbl)).InteractionDetected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveInteractionDetected(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManager", "RemoveInteractionDetected", 17usize, |this| ((**(this as *const *const ISpatialInteractionManager_Vtbl)).RemoveInteractionDetected)(this, token), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Perception"))]
+        unsafe extern "system" fn GetDetectedSourcesAtTimestamp(this: *mut core::ffi::c_void, timestamp: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManager", "GetDetectedSourcesAtTimestamp", 18usize, |this| ((**(this as *const *const ISpatialInteractionManager_Vtbl)).GetDetectedSourcesAtTimestamp)(this, timestamp, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SourceDetected,
+            RemoveSourceDetected,
+            SourceLost,
+            RemoveSourceLost,
+            SourceUpdated,
+            RemoveSourceUpdated,
+            SourcePressed,
+            RemoveSourcePressed,
+            SourceReleased,
+            RemoveSourceReleased,
+            InteractionDetected,
+            RemoveInteractionDetected,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Perception"))]
+            GetDetectedSourcesAtTimestamp,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Perception")))]
+            GetDetectedSourcesAtTimestamp: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialInteractionManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManagerStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const ISpatialInteractionManagerStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialInteractionManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSourceKindSupported(this: *mut core::ffi::c_void, kind: SpatialInteractionSourceKind, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManagerStatics2", "IsSourceKindSupported", 6usize, |this| ((**(this as *const *const ISpatialInteractionManagerStatics2_Vtbl)).IsSourceKindSupported)(this, kind, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSourceKindSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialInteractionSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSource", "Id", 6usize, |this| ((**(this as *const *const ISpatialInteractionSource_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSource", "Kind", 7usize, |this| ((**(this as *const *const ISpatialInteractionSource_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Kind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception")]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionSource2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPointingSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSource2", "IsPointingSupported", 6usize, |this| ((**(this as *const *const ISpatialInteractionSource2_Vtbl)).IsPointingSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMenuSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSource2", "IsMenuSupported", 7usize, |this| ((**(this as *const *const ISpatialInteractionSource2_Vtbl)).IsMenuSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGraspSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSource2", "IsGraspSupported", 8usize, |this| ((**(this as *const *const ISpatialInteractionSource2_Vtbl)).IsGraspSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Controller(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSource2", "Controller", 9usize, |this| ((**(this as *const *const ISpatialInteractionSource2_Vtbl)).Controller)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception")]
+        unsafe extern "system" fn TryGetStateAtTimestamp(this: *mut core::ffi::c_void, timestamp: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSource2", "TryGetStateAtTimestamp", 10usize, |this| ((**(this as *const *const ISpatialInteractionSource2_Vtbl)).TryGetStateAtTimestamp)(this, timestamp, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsPointingSupported,
+            IsMenuSupported,
+            IsGraspSupported,
+            Controller,
+            #[cfg(feature = "Perception")]
+            TryGetStateAtTimestamp,
+            #[cfg(not(feature = "Perception"))]
+            TryGetStateAtTimestamp: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionSource2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception")]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionSource3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handedness(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceHandedness) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSource3", "Handedness", 6usize, |this| ((**(this as *const *const ISpatialInteractionSource3_Vtbl)).Handedness)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handedness }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionSource3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_People")]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionSource4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Perception_People")]
+        unsafe extern "system" fn TryCreateHandMeshObserver(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSource4", "TryCreateHandMeshObserver", 6usize, |this| ((**(this as *const *const ISpatialInteractionSource4_Vtbl)).TryCreateHandMeshObserver)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_People")]
+        unsafe extern "system" fn TryCreateHandMeshObserverAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSource4", "TryCreateHandMeshObserverAsync", 7usize, |this| ((**(this as *const *const ISpatialInteractionSource4_Vtbl)).TryCreateHandMeshObserverAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Perception_People")]
+            TryCreateHandMeshObserver,
+            #[cfg(not(feature = "Perception_People"))]
+            TryCreateHandMeshObserver: 0,
+            #[cfg(feature = "Perception_People")]
+            TryCreateHandMeshObserverAsync,
+            #[cfg(not(feature = "Perception_People"))]
+            TryCreateHandMeshObserverAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionSource4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialInteractionSourceEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceEventArgs", "State", 6usize, |this| ((**(this as *const *const ISpatialInteractionSourceEventArgs_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, State }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionSourceEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialInteractionSourceEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PressKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionPressKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceEventArgs2", "PressKind", 6usize, |this| ((**(this as *const *const ISpatialInteractionSourceEventArgs2_Vtbl)).PressKind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PressKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionSourceEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionSourceLocation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceLocation", "Position", 6usize, |this| ((**(this as *const *const ISpatialInteractionSourceLocation_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Velocity(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceLocation", "Velocity", 7usize, |this| ((**(this as *const *const ISpatialInteractionSourceLocation_Vtbl)).Velocity)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Position,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Position: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Velocity,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Velocity: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionSourceLocation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionSourceLocation2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceLocation2", "Orientation", 6usize, |this| ((**(this as *const *const ISpatialInteractionSourceLocation2_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Orientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Orientation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionSourceLocation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionSourceLocation3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PositionAccuracy(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourcePositionAccuracy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceLocation3", "PositionAccuracy", 6usize, |this| ((**(this as *const *const ISpatialInteractionSourceLocation3_Vtbl)).PositionAccuracy)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn AngularVelocity(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceLocation3", "AngularVelocity", 7usize, |this| ((**(this as *const *const ISpatialInteractionSourceLocation3_Vtbl)).AngularVelocity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourcePointerPose(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceLocation3", "SourcePointerPose", 8usize, |this| ((**(this as *const *const ISpatialInteractionSourceLocation3_Vtbl)).SourcePointerPose)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PositionAccuracy,
+            #[cfg(feature = "Foundation_Numerics")]
+            AngularVelocity,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            AngularVelocity: 0,
+            SourcePointerPose,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionSourceLocation3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionSourceProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn TryGetSourceLossMitigationDirection(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceProperties", "TryGetSourceLossMitigationDirection", 6usize, |this| ((**(this as *const *const ISpatialInteractionSourceProperties_Vtbl)).TryGetSourceLossMitigationDirection)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceLossRisk(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceProperties", "SourceLossRisk", 7usize, |this| ((**(this as *const *const ISpatialInteractionSourceProperties_Vtbl)).SourceLossRisk)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn TryGetLocation(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceProperties", "TryGetLocation", 8usize, |this| ((**(this as *const *const ISpatialInteractionSourceProperties_Vtbl)).TryGetLocation)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            TryGetSourceLossMitigationDirection,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            TryGetSourceLossMitigationDirection: 0,
+            SourceLossRisk,
+            #[cfg(feature = "Perception_Spatial")]
+            TryGetLocation,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            TryGetLocation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionSourceProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_Spatial")]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionSourceState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceState", "Source", 6usize, |this| ((**(this as *const *const ISpatialInteractionSourceState_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceState", "Properties", 7usize, |this| ((**(this as *const *const ISpatialInteractionSourceState_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceState", "IsPressed", 8usize, |this| ((**(this as *const *const ISpatialInteractionSourceState_Vtbl)).IsPressed)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception")]
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceState", "Timestamp", 9usize, |this| ((**(this as *const *const ISpatialInteractionSourceState_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn TryGetPointerPose(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceState", "TryGetPointerPose", 10usize, |this| ((**(this as *const *const ISpatialInteractionSourceState_Vtbl)).TryGetPointerPose)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Source,
+            Properties,
+            IsPressed,
+            #[cfg(feature = "Perception")]
+            Timestamp,
+            #[cfg(not(feature = "Perception"))]
+            Timestamp: 0,
+            #[cfg(feature = "Perception_Spatial")]
+            TryGetPointerPose,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            TryGetPointerPose: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionSourceState as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_Spatial")]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionSourceState2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSelectPressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceState2", "IsSelectPressed", 6usize, |this| ((**(this as *const *const ISpatialInteractionSourceState2_Vtbl)).IsSelectPressed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMenuPressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceState2", "IsMenuPressed", 7usize, |this| ((**(this as *const *const ISpatialInteractionSourceState2_Vtbl)).IsMenuPressed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGrasped(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceState2", "IsGrasped", 8usize, |this| ((**(this as *const *const ISpatialInteractionSourceState2_Vtbl)).IsGrasped)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectPressedValue(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceState2", "SelectPressedValue", 9usize, |this| ((**(this as *const *const ISpatialInteractionSourceState2_Vtbl)).SelectPressedValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControllerProperties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceState2", "ControllerProperties", 10usize, |this| ((**(this as *const *const ISpatialInteractionSourceState2_Vtbl)).ControllerProperties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSelectPressed,
+            IsMenuPressed,
+            IsGrasped,
+            SelectPressedValue,
+            ControllerProperties,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionSourceState2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Perception_People", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for ISpatialInteractionSourceState3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Perception_People")]
+        unsafe extern "system" fn TryGetHandPose(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionSourceState3", "TryGetHandPose", 6usize, |this| ((**(this as *const *const ISpatialInteractionSourceState3_Vtbl)).TryGetHandPose)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Perception_People")]
+            TryGetHandPose,
+            #[cfg(not(feature = "Perception_People"))]
+            TryGetHandPose: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionSourceState3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialManipulationCanceledEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSourceKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialManipulationCanceledEventArgs", "InteractionSourceKind", 6usize, |this| ((**(this as *const *const ISpatialManipulationCanceledEventArgs_Vtbl)).InteractionSourceKind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InteractionSourceKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialManipulationCanceledEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_Spatial")]
+impl windows_core::imp::ProxyVtable for ISpatialManipulationCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSourceKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialManipulationCompletedEventArgs", "InteractionSourceKind", 6usize, |this| ((**(this as *const *const ISpatialManipulationCompletedEventArgs_Vtbl)).InteractionSourceKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn TryGetCumulativeDelta(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialManipulationCompletedEventArgs", "TryGetCumulativeDelta", 7usize, |this| ((**(this as *const *const ISpatialManipulationCompletedEventArgs_Vtbl)).TryGetCumulativeDelta)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InteractionSourceKind,
+            #[cfg(feature = "Perception_Spatial")]
+            TryGetCumulativeDelta,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            TryGetCumulativeDelta: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialManipulationCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialManipulationDelta_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Translation(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialManipulationDelta", "Translation", 6usize, |this| ((**(this as *const *const ISpatialManipulationDelta_Vtbl)).Translation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Translation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Translation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialManipulationDelta as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_Spatial")]
+impl windows_core::imp::ProxyVtable for ISpatialManipulationStartedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSourceKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialManipulationStartedEventArgs", "InteractionSourceKind", 6usize, |this| ((**(this as *const *const ISpatialManipulationStartedEventArgs_Vtbl)).InteractionSourceKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn TryGetPointerPose(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialManipulationStartedEventArgs", "TryGetPointerPose", 7usize, |this| ((**(this as *const *const ISpatialManipulationStartedEventArgs_Vtbl)).TryGetPointerPose)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InteractionSourceKind,
+            #[cfg(feature = "Perception_Spatial")]
+            TryGetPointerPose,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            TryGetPointerPose: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialManipulationStartedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_Spatial")]
+impl windows_core::imp::ProxyVtable for ISpatialManipulationUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSourceKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialManipulationUpdatedEventArgs", "InteractionSourceKind", 6usize, |this| ((**(this as *const *const ISpatialManipulationUpdatedEventArgs_Vtbl)).InteractionSourceKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn TryGetCumulativeDelta(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialManipulationUpdatedEventArgs", "TryGetCumulativeDelta", 7usize, |this| ((**(this as *const *const ISpatialManipulationUpdatedEventArgs_Vtbl)).TryGetCumulativeDelta)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InteractionSourceKind,
+            #[cfg(feature = "Perception_Spatial")]
+            TryGetCumulativeDelta,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            TryGetCumulativeDelta: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialManipulationUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialNavigationCanceledEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSourceKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialNavigationCanceledEventArgs", "InteractionSourceKind", 6usize, |this| ((**(this as *const *const ISpatialNavigationCanceledEventArgs_Vtbl)).InteractionSourceKind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InteractionSourceKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialNavigationCanceledEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialNavigationCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSourceKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialNavigationCompletedEventArgs", "InteractionSourceKind", 6usize, |this| ((**(this as *const *const ISpatialNavigationCompletedEventArgs_Vtbl)).InteractionSourceKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn NormalizedOffset(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialNavigationCompletedEventArgs", "NormalizedOffset", 7usize, |this| ((**(this as *const *const ISpatialNavigationCompletedEventArgs_Vtbl)).NormalizedOffset)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InteractionSourceKind,
+            #[cfg(feature = "Foundation_Numerics")]
+            NormalizedOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            NormalizedOffset: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialNavigationCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_Spatial")]
+impl windows_core::imp::ProxyVtable for ISpatialNavigationStartedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSourceKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialNavigationStartedEventArgs", "InteractionSourceKind", 6usize, |this| ((**(this as *const *const ISpatialNavigationStartedEventArgs_Vtbl)).InteractionSourceKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn TryGetPointerPose(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialNavigationStartedEventArgs", "TryGetPointerPose", 7usize, |this| ((**(this as *const *const ISpatialNavigationStartedEventArgs_Vtbl)).TryGetPointerPose)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNavigatingX(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialNavigationStartedEventArgs", "IsNavigatingX", 8usize, |this| ((**(this as *const *const ISpatialNavigationStartedEventArgs_Vtbl)).IsNavigatingX)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNavigatingY(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialNavigationStartedEventArgs", "IsNavigatingY", 9usize, |this| ((**(this as *const *const ISpatialNavigationStartedEventArgs_Vtbl)).IsNavigatingY)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNavigatingZ(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialNavigationStartedEventArgs", "IsNavigatingZ", 10usize, |this| ((**(this as *const *const ISpatialNavigationStartedEventArgs_Vtbl)).IsNavigatingZ)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InteractionSourceKind,
+            #[cfg(feature = "Perception_Spatial")]
+            TryGetPointerPose,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            TryGetPointerPose: 0,
+            IsNavigatingX,
+            IsNavigatingY,
+            IsNavigatingZ,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialNavigationStartedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialNavigationUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSourceKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialNavigationUpdatedEventArgs", "InteractionSourceKind", 6usize, |this| ((**(this as *const *const ISpatialNavigationUpdatedEventArgs_Vtbl)).InteractionSourceKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn NormalizedOffset(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialNavigationUpdatedEventArgs", "NormalizedOffset", 7usize, |this| ((**(this as *const *const ISpatialNavigationUpdatedEventArgs_Vtbl)).NormalizedOffset)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InteractionSourceKind,
+            #[cfg(feature = "Foundation_Numerics")]
+            NormalizedOffset,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            NormalizedOffset: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialNavigationUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialPointerInteractionSourcePose_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialPointerInteractionSourcePose", "Position", 6usize, |this| ((**(this as *const *const ISpatialPointerInteractionSourcePose_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn ForwardDirection(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialPointerInteractionSourcePose", "ForwardDirection", 7usize, |this| ((**(this as *const *const ISpatialPointerInteractionSourcePose_Vtbl)).ForwardDirection)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn UpDirection(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Vector3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialPointerInteractionSourcePose", "UpDirection", 8usize, |this| ((**(this as *const *const ISpatialPointerInteractionSourcePose_Vtbl)).UpDirection)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Position,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Position: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            ForwardDirection,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            ForwardDirection: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            UpDirection,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            UpDirection: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialPointerInteractionSourcePose as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ISpatialPointerInteractionSourcePose2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Numerics::Quaternion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialPointerInteractionSourcePose2", "Orientation", 6usize, |this| ((**(this as *const *const ISpatialPointerInteractionSourcePose2_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionAccuracy(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourcePositionAccuracy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialPointerInteractionSourcePose2", "PositionAccuracy", 7usize, |this| ((**(this as *const *const ISpatialPointerInteractionSourcePose2_Vtbl)).PositionAccuracy)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            Orientation,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Orientation: 0,
+            PositionAccuracy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialPointerInteractionSourcePose2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_People")]
+impl windows_core::imp::ProxyVtable for ISpatialPointerPose_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Perception")]
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialPointerPose", "Timestamp", 6usize, |this| ((**(this as *const *const ISpatialPointerPose_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_People")]
+        unsafe extern "system" fn Head(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialPointerPose", "Head", 7usize, |this| ((**(this as *const *const ISpatialPointerPose_Vtbl)).Head)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Perception")]
+            Timestamp,
+            #[cfg(not(feature = "Perception"))]
+            Timestamp: 0,
+            #[cfg(feature = "Perception_People")]
+            Head,
+            #[cfg(not(feature = "Perception_People"))]
+            Head: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialPointerPose as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_People")]
+impl windows_core::imp::ProxyVtable for ISpatialPointerPose2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryGetInteractionSourcePose(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialPointerPose2", "TryGetInteractionSourcePose", 6usize, |this| ((**(this as *const *const ISpatialPointerPose2_Vtbl)).TryGetInteractionSourcePose)(this, source, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryGetInteractionSourcePose }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialPointerPose2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_People")]
+impl windows_core::imp::ProxyVtable for ISpatialPointerPose3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Perception_People")]
+        unsafe extern "system" fn Eyes(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialPointerPose3", "Eyes", 6usize, |this| ((**(this as *const *const ISpatialPointerPose3_Vtbl)).Eyes)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHeadCapturedBySystem(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialPointerPose3", "IsHeadCapturedBySystem", 7usize, |this| ((**(this as *const *const ISpatialPointerPose3_Vtbl)).IsHeadCapturedBySystem)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Perception_People")]
+            Eyes,
+            #[cfg(not(feature = "Perception_People"))]
+            Eyes: 0,
+            IsHeadCapturedBySystem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialPointerPose3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_Spatial")]
+impl windows_core::imp::ProxyVtable for ISpatialPointerPoseStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn TryGetAtTimestamp(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, timestamp: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialPointerPoseStatics", "TryGetAtTimestamp", 6usize, |this| ((**(this as *const *const ISpatialPointerPoseStatics_Vtbl)).TryGetAtTimestamp)(this, coordinatesystem, timestamp, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Perception_Spatial")]
+            TryGetAtTimestamp,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            TryGetAtTimestamp: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialPointerPoseStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialRecognitionEndedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSourceKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialRecognitionEndedEventArgs", "InteractionSourceKind", 6usize, |this| ((**(this as *const *const ISpatialRecognitionEndedEventArgs_Vtbl)).InteractionSourceKind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InteractionSourceKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialRecognitionEndedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_Spatial")]
+impl windows_core::imp::ProxyVtable for ISpatialRecognitionStartedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSourceKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialRecognitionStartedEventArgs", "InteractionSourceKind", 6usize, |this| ((**(this as *const *const ISpatialRecognitionStartedEventArgs_Vtbl)).InteractionSourceKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn TryGetPointerPose(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialRecognitionStartedEventArgs", "TryGetPointerPose", 7usize, |this| ((**(this as *const *const ISpatialRecognitionStartedEventArgs_Vtbl)).TryGetPointerPose)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGesturePossible(this: *mut core::ffi::c_void, gesture: SpatialGestureSettings, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialRecognitionStartedEventArgs", "IsGesturePossible", 8usize, |this| ((**(this as *const *const ISpatialRecognitionStartedEventArgs_Vtbl)).IsGesturePossible)(this, gesture, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InteractionSourceKind,
+            #[cfg(feature = "Perception_Spatial")]
+            TryGetPointerPose,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            TryGetPointerPose: 0,
+            IsGesturePossible,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialRecognitionStartedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Perception_Spatial")]
+impl windows_core::imp::ProxyVtable for ISpatialTappedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InteractionSourceKind(this: *mut core::ffi::c_void, result__: *mut SpatialInteractionSourceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialTappedEventArgs", "InteractionSourceKind", 6usize, |this| ((**(this as *const *const ISpatialTappedEventArgs_Vtbl)).InteractionSourceKind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Perception_Spatial")]
+        unsafe extern "system" fn TryGetPointerPose(this: *mut core::ffi::c_void, coordinatesystem: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialTappedEventArgs", "TryGetPointerPose", 7usize, |this| ((**(this as *const *const ISpatialTappedEventArgs_Vtbl)).TryGetPointerPose)(this, coordinatesystem, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TapCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialTappedEventArgs", "TapCount", 8usize, |this| ((**(this as *const *const ISpatialTappedEventArgs_Vtbl)).TapCount)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InteractionSourceKind,
+            #[cfg(feature = "Perception_Spatial")]
+            TryGetPointerPose,
+            #[cfg(not(feature = "Perception_Spatial"))]
+            TryGetPointerPose: 0,
+            TapCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialTappedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
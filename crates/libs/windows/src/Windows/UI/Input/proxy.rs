@@ -0,0 +1,1917 @@
+impl windows_core::imp::ProxyVtable for IAttachableInputObject_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAttachableInputObject as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAttachableInputObjectFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAttachableInputObjectFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Input")]
+impl windows_core::imp::ProxyVtable for ICrossSlidingEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Input")]
+        unsafe extern "system" fn PointerDeviceType(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Input::PointerDeviceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrossSlidingEventArgs", "PointerDeviceType", 6usize, |this| ((**(this as *const *const ICrossSlidingEventArgs_Vtbl)).PointerDeviceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrossSlidingEventArgs", "Position", 7usize, |this| ((**(this as *const *const ICrossSlidingEventArgs_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CrossSlidingState(this: *mut core::ffi::c_void, result__: *mut CrossSlidingState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrossSlidingEventArgs", "CrossSlidingState", 8usize, |this| ((**(this as *const *const ICrossSlidingEventArgs_Vtbl)).CrossSlidingState)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Input")]
+            PointerDeviceType,
+            #[cfg(not(feature = "Devices_Input"))]
+            PointerDeviceType: 0,
+            Position,
+            CrossSlidingState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICrossSlidingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICrossSlidingEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrossSlidingEventArgs2", "ContactCount", 6usize, |this| ((**(this as *const *const ICrossSlidingEventArgs2_Vtbl)).ContactCount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContactCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICrossSlidingEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Input")]
+impl windows_core::imp::ProxyVtable for IDraggingEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Input")]
+        unsafe extern "system" fn PointerDeviceType(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Input::PointerDeviceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDraggingEventArgs", "PointerDeviceType", 6usize, |this| ((**(this as *const *const IDraggingEventArgs_Vtbl)).PointerDeviceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDraggingEventArgs", "Position", 7usize, |this| ((**(this as *const *const IDraggingEventArgs_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DraggingState(this: *mut core::ffi::c_void, result__: *mut DraggingState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDraggingEventArgs", "DraggingState", 8usize, |this| ((**(this as *const *const IDraggingEventArgs_Vtbl)).DraggingState)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Input")]
+            PointerDeviceType,
+            #[cfg(not(feature = "Devices_Input"))]
+            PointerDeviceType: 0,
+            Position,
+            DraggingState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDraggingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDraggingEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDraggingEventArgs2", "ContactCount", 6usize, |this| ((**(this as *const *const IDraggingEventArgs2_Vtbl)).ContactCount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContactCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDraggingEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEdgeGesture_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Starting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEdgeGesture", "Starting", 6usize, |this| ((**(this as *const *const IEdgeGesture_Vtbl)).Starting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStarting(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEdgeGesture", "RemoveStarting", 7usize, |this| ((**(this as *const *const IEdgeGesture_Vtbl)).RemoveStarting)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Completed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEdgeGesture", "Completed", 8usize, |this| ((**(this as *const *const IEdgeGesture_Vtbl)).Completed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEdgeGesture", "RemoveCompleted", 9usize, |this| ((**(this as *const *const IEdgeGesture_Vtbl)).RemoveCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Canceled(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEdgeGesture", "Canceled", 10usize, |this| ((**(this as *const *const IEdgeGesture_Vtbl)).Canceled)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCanceled(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEdgeGesture", "RemoveCanceled", 11usize, |this| ((**(this as *const *const IEdgeGesture_Vtbl)).RemoveCanceled)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Starting,
+            RemoveStarting,
+            Completed,
+            RemoveCompleted,
+            Canceled,
+            RemoveCanceled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEdgeGesture as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEdgeGestureEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut EdgeGestureKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEdgeGestureEventArgs", "Kind", 6usize, |this| ((**(this as *const *const IEdgeGestureEventArgs_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEdgeGestureEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEdgeGestureStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEdgeGestureStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IEdgeGestureStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEdgeGestureStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IGestureRecognizer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GestureSettings(this: *mut core::ffi::c_void, result__: *mut GestureSettings) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "GestureSettings", 6usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).GestureSettings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGestureSettings(this: *mut core::ffi::c_void, value: GestureSettings) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetGestureSettings", 7usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetGestureSettings)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInertial(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "IsInertial", 8usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).IsInertial)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsActive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "IsActive", 9usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).IsActive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowGestureFeedback(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "ShowGestureFeedback", 10usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).ShowGestureFeedback)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowGestureFeedback(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetShowGestureFeedback", 11usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetShowGestureFeedback)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PivotCenter(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "PivotCenter", 12usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).PivotCenter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPivotCenter(this: *mut core::ffi::c_void, value: super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetPivotCenter", 13usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetPivotCenter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PivotRadius(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "PivotRadius", 14usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).PivotRadius)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPivotRadius(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetPivotRadius", 15usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetPivotRadius)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InertiaTranslationDeceleration(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "InertiaTranslationDeceleration", 16usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).InertiaTranslationDeceleration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInertiaTranslationDeceleration(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetInertiaTranslationDeceleration", 17usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetInertiaTranslationDeceleration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InertiaRotationDeceleration(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "InertiaRotationDeceleration", 18usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).InertiaRotationDeceleration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInertiaRotationDeceleration(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetInertiaRotationDeceleration", 19usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetInertiaRotationDeceleration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InertiaExpansionDeceleration(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "InertiaExpansionDeceleration", 20usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).InertiaExpansionDeceleration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInertiaExpansionDeceleration(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetInertiaExpansionDeceleration", 21usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetInertiaExpansionDeceleration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InertiaTranslationDisplacement(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "InertiaTranslationDisplacement", 22usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).InertiaTranslationDisplacement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInertiaTranslationDisplacement(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetInertiaTranslationDisplacement", 23usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetInertiaTranslationDisplacement)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InertiaRotationAngle(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "InertiaRotationAngle", 24usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).InertiaRotationAngle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInertiaRotationAngle(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetInertiaRotationAngle", 25usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetInertiaRotationAngle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InertiaExpansion(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "InertiaExpansion", 26usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).InertiaExpansion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInertiaExpansion(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetInertiaExpansion", 27usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetInertiaExpansion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManipulationExact(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "ManipulationExact", 28usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).ManipulationExact)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetManipulationExact(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetManipulationExact", 29usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetManipulationExact)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CrossSlideThresholds(this: *mut core::ffi::c_void, result__: *mut CrossSlideThresholds) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "CrossSlideThresholds", 30usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).CrossSlideThresholds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCrossSlideThresholds(this: *mut core::ffi::c_void, value: CrossSlideThresholds) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetCrossSlideThresholds", 31usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetCrossSlideThresholds)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CrossSlideHorizontally(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "CrossSlideHorizontally", 32usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).CrossSlideHorizontally)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCrossSlideHorizontally(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetCrossSlideHorizontally", 33usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetCrossSlideHorizontally)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CrossSlideExact(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "CrossSlideExact", 34usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).CrossSlideExact)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCrossSlideExact(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetCrossSlideExact", 35usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetCrossSlideExact)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoProcessInertia(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "AutoProcessInertia", 36usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).AutoProcessInertia)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoProcessInertia(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetAutoProcessInertia", 37usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetAutoProcessInertia)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MouseWheelParameters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "MouseWheelParameters", 38usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).MouseWheelParameters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanBeDoubleTap(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "CanBeDoubleTap", 39usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).CanBeDoubleTap)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessDownEvent(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "ProcessDownEvent", 40usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).ProcessDownEvent)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ProcessMoveEvents(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "ProcessMoveEvents", 41usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).ProcessMoveEvents)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessUpEvent(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "ProcessUpEvent", 42usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).ProcessUpEvent)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessMouseWheelEvent(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, isshiftkeydown: bool, iscontrolkeydown: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "ProcessMouseWheelEvent", 43usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).ProcessMouseWheelEvent)(this, value, isshiftkeydown, iscontrolkeydown), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessInertia(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "ProcessInertia", 44usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).ProcessInertia)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompleteGesture(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "CompleteGesture", 45usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).CompleteGesture)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tapped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "Tapped", 46usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).Tapped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTapped(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "RemoveTapped", 47usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).RemoveTapped)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RightTapped(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "RightTapped", 48usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).RightTapped)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRightTapped(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "RemoveRightTapped", 49usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).RemoveRightTapped)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Holding(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "Holding", 50usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).Holding)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHolding(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "RemoveHolding", 51usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).RemoveHolding)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Dragging(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "Dragging", 52usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).Dragging)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDragging(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "RemoveDragging", 53usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).RemoveDragging)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManipulationStarted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "ManipulationStarted", 54usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).ManipulationStarted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveManipulationStarted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "RemoveManipulationStarted", 55usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).RemoveManipulationStarted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManipulationUpdated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "ManipulationUpdated", 56usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).ManipulationUpdated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveManipulationUpdated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "RemoveManipulationUpdated", 57usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).RemoveManipulationUpdated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManipulationInertiaStarting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "ManipulationInertiaStarting", 58usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).ManipulationInertiaStarting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveManipulationInertiaStarting(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "RemoveManipulationInertiaStarting", 59usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).RemoveManipulationInertiaStarting)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManipulationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "ManipulationCompleted", 60usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).ManipulationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveManipulationCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "RemoveManipulationCompleted", 61usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).RemoveManipulationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CrossSliding(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "CrossSliding", 62usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).CrossSliding)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCrossSliding(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "RemoveCrossSliding", 63usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).RemoveCrossSliding)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GestureSettings,
+            SetGestureSettings,
+            IsInertial,
+            IsActive,
+            ShowGestureFeedback,
+            SetShowGestureFeedback,
+            PivotCenter,
+            SetPivotCenter,
+            PivotRadius,
+            SetPivotRadius,
+            InertiaTranslationDeceleration,
+            SetInertiaTranslationDeceleration,
+            InertiaRotationDeceleration,
+            SetInertiaRotationDeceleration,
+            InertiaExpansionDeceleration,
+            SetInertiaExpansionDeceleration,
+            InertiaTranslationDisplacement,
+            SetInertiaTranslationDisplacement,
+            InertiaRotationAngle,
+            SetInertiaRotationAngle,
+            InertiaExpansion,
+            SetInertiaExpansion,
+            ManipulationExact,
+            SetManipulationExact,
+            CrossSlideThresholds,
+            SetCrossSlideThresholds,
+            CrossSlideHorizontally,
+            SetCrossSlideHorizontally,
+            CrossSlideExact,
+            SetCrossSlideExact,
+            AutoProcessInertia,
+            SetAutoProcessInertia,
+            MouseWheelParameters,
+            CanBeDoubleTap,
+            ProcessDownEvent,
+            #[cfg(feature = "Foundation_Collections")]
+            ProcessMoveEvents,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ProcessMoveEvents: 0,
+            ProcessUpEvent,
+            ProcessMouseWheelEvent,
+            ProcessInertia,
+            CompleteGesture,
+            Tapped,
+            RemoveTapped,
+            RightTapped,
+            RemoveRightTapped,
+            Holding,
+            RemoveHolding,
+            Dragging,
+            RemoveDragging,
+            ManipulationStarted,
+            RemoveManipulationStarted,
+            ManipulationUpdated,
+            RemoveManipulationUpdated,
+            ManipulationInertiaStarting,
+            RemoveManipulationInertiaStarting,
+            ManipulationCompleted,
+            RemoveManipulationCompleted,
+            CrossSliding,
+            RemoveCrossSliding,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGestureRecognizer as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGestureRecognizer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TapMinContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "TapMinContactCount", 6usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).TapMinContactCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTapMinContactCount(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "SetTapMinContactCount", 7usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).SetTapMinContactCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TapMaxContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "TapMaxContactCount", 8usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).TapMaxContactCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTapMaxContactCount(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "SetTapMaxContactCount", 9usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).SetTapMaxContactCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoldMinContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "HoldMinContactCount", 10usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).HoldMinContactCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHoldMinContactCount(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "SetHoldMinContactCount", 11usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).SetHoldMinContactCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoldMaxContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "HoldMaxContactCount", 12usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).HoldMaxContactCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHoldMaxContactCount(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "SetHoldMaxContactCount", 13usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).SetHoldMaxContactCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoldRadius(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "HoldRadius", 14usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).HoldRadius)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHoldRadius(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "SetHoldRadius", 15usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).SetHoldRadius)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoldStartDelay(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "HoldStartDelay", 16usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).HoldStartDelay)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHoldStartDelay(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "SetHoldStartDelay", 17usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).SetHoldStartDelay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TranslationMinContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "TranslationMinContactCount", 18usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).TranslationMinContactCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTranslationMinContactCount(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "SetTranslationMinContactCount", 19usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).SetTranslationMinContactCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TranslationMaxContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "TranslationMaxContactCount", 20usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).TranslationMaxContactCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTranslationMaxContactCount(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer2", "SetTranslationMaxContactCount", 21usize, |this| ((**(this as *const *const IGestureRecognizer2_Vtbl)).SetTranslationMaxContactCount)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TapMinContactCount,
+            SetTapMinContactCount,
+            TapMaxContactCount,
+            SetTapMaxContactCount,
+            HoldMinContactCount,
+            SetHoldMinContactCount,
+            HoldMaxContactCount,
+            SetHoldMaxContactCount,
+            HoldRadius,
+            SetHoldRadius,
+            HoldStartDelay,
+            SetHoldStartDelay,
+            TranslationMinContactCount,
+            SetTranslationMinContactCount,
+            TranslationMaxContactCount,
+            SetTranslationMaxContactCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGestureRecognizer2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Input")]
+impl windows_core::imp::ProxyVtable for IHoldingEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Input")]
+        unsafe extern "system" fn PointerDeviceType(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Input::PointerDeviceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHoldingEventArgs", "PointerDeviceType", 6usize, |this| ((**(this as *const *const IHoldingEventArgs_Vtbl)).PointerDeviceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHoldingEventArgs", "Position", 7usize, |this| ((**(this as *const *const IHoldingEventArgs_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoldingState(this: *mut core::ffi::c_void, result__: *mut HoldingState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHoldingEventArgs", "HoldingState", 8usize, |this| ((**(this as *const *const IHoldingEventArgs_Vtbl)).HoldingState)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Input")]
+            PointerDeviceType,
+            #[cfg(not(feature = "Devices_Input"))]
+            PointerDeviceType: 0,
+            Position,
+            HoldingState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHoldingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHoldingEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHoldingEventArgs2", "ContactCount", 6usize, |this| ((**(this as *const *const IHoldingEventArgs2_Vtbl)).ContactCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHoldingEventArgs2", "CurrentContactCount", 7usize, |this| ((**(this as *const *const IHoldingEventArgs2_Vtbl)).CurrentContactCount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContactCount, CurrentContactCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHoldingEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInputActivationListener_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut InputActivationState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputActivationListener", "State", 6usize, |this| ((**(this as *const *const IInputActivationListener_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputActivationChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputActivationListener", "InputActivationChanged", 7usize, |this| ((**(this as *const *const IInputActivationListener_Vtbl)).InputActivationChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveInputActivationChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputActivationListener", "RemoveInputActivationChanged", 8usize, |this| ((**(this as *const *const IInputActivationListener_Vtbl)).RemoveInputActivationChanged)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, State, InputActivationChanged, RemoveInputActivationChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputActivationListener as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInputActivationListenerActivationChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut InputActivationState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputActivationListenerActivationChangedEventArgs", "State", 6usize, |this| ((**(this as *const *const IInputActivationListenerActivationChangedEventArgs_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, State }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputActivationListenerActivationChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Core")]
+impl windows_core::imp::ProxyVtable for IKeyboardDeliveryInterceptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsInterceptionEnabledWhenInForeground(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyboardDeliveryInterceptor", "IsInterceptionEnabledWhenInForeground", 6usize, |this| ((**(this as *const *const IKeyboardDeliveryInterceptor_Vtbl)).IsInterceptionEnabledWhenInForeground)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsInterceptionEnabledWhenInForeground(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyboardDeliveryInterceptor", "SetIsInterceptionEnabledWhenInForeground", 7usize, |this| ((**(this as *const *const IKeyboardDeliveryInterceptor_Vtbl)).SetIsInterceptionEnabledWhenInForeground)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn KeyDown(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyboardDeliveryInterceptor", "KeyDown", 8usize, |this| ((**(this as *const *const IKeyboardDeliveryInterceptor_Vtbl)).KeyDown)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveKeyDown(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyboardDeliveryInterceptor", "RemoveKeyDown", 9usize, |this| ((**(this as *const *const IKeyboardDeliveryInterceptor_Vtbl)).RemoveKeyDown)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn KeyUp(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyboardDeliveryInterceptor", "KeyUp", 10usize, |this| ((**(this as *const *const IKeyboardDeliveryInterceptor_Vtbl)).KeyUp)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveKeyUp(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyboardDeliveryInterceptor", "RemoveKeyUp", 11usize, |this| ((**(this as *const *const IKeyboardDeliveryInterceptor_Vtbl)).RemoveKeyUp)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsInterceptionEnabledWhenInForeground,
+            SetIsInterceptionEnabledWhenInForeground,
+            #[cfg(feature = "UI_Core")]
+            KeyDown,
+            #[cfg(not(feature = "UI_Core"))]
+            KeyDown: 0,
+            RemoveKeyDown,
+            #[cfg(feature = "UI_Core")]
+            KeyUp,
+            #[cfg(not(feature = "UI_Core"))]
+            KeyUp: 0,
+            RemoveKeyUp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyboardDeliveryInterceptor as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKeyboardDeliveryInterceptorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKeyboardDeliveryInterceptorStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IKeyboardDeliveryInterceptorStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKeyboardDeliveryInterceptorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Input")]
+impl windows_core::imp::ProxyVtable for IManipulationCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Input")]
+        unsafe extern "system" fn PointerDeviceType(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Input::PointerDeviceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationCompletedEventArgs", "PointerDeviceType", 6usize, |this| ((**(this as *const *const IManipulationCompletedEventArgs_Vtbl)).PointerDeviceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationCompletedEventArgs", "Position", 7usize, |this| ((**(this as *const *const IManipulationCompletedEventArgs_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cumulative(this: *mut core::ffi::c_void, result__: *mut ManipulationDelta) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationCompletedEventArgs", "Cumulative", 8usize, |this| ((**(this as *const *const IManipulationCompletedEventArgs_Vtbl)).Cumulative)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Velocities(this: *mut core::ffi::c_void, result__: *mut ManipulationVelocities) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationCompletedEventArgs", "Velocities", 9usize, |this| ((**(this as *const *const IManipulationCompletedEventArgs_Vtbl)).Velocities)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Input")]
+            PointerDeviceType,
+            #[cfg(not(feature = "Devices_Input"))]
+            PointerDeviceType: 0,
+            Position,
+            Cumulative,
+            Velocities,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IManipulationCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IManipulationCompletedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationCompletedEventArgs2", "ContactCount", 6usize, |this| ((**(this as *const *const IManipulationCompletedEventArgs2_Vtbl)).ContactCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationCompletedEventArgs2", "CurrentContactCount", 7usize, |this| ((**(this as *const *const IManipulationCompletedEventArgs2_Vtbl)).CurrentContactCount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContactCount, CurrentContactCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IManipulationCompletedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Input")]
+impl windows_core::imp::ProxyVtable for IManipulationInertiaStartingEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Input")]
+        unsafe extern "system" fn PointerDeviceType(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Input::PointerDeviceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationInertiaStartingEventArgs", "PointerDeviceType", 6usize, |this| ((**(this as *const *const IManipulationInertiaStartingEventArgs_Vtbl)).PointerDeviceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationInertiaStartingEventArgs", "Position", 7usize, |this| ((**(this as *const *const IManipulationInertiaStartingEventArgs_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delta(this: *mut core::ffi::c_void, result__: *mut ManipulationDelta) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationInertiaStartingEventArgs", "Delta", 8usize, |this| ((**(this as *const *const IManipulationInertiaStartingEventArgs_Vtbl)).Delta)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cumulative(this: *mut core::ffi::c_void, result__: *mut ManipulationDelta) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationInertiaStartingEventArgs", "Cumulative", 9usize, |this| ((**(this as *const *const IManipulationInertiaStartingEventArgs_Vtbl)).Cumulative)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Velocities(this: *mut core::ffi::c_void, result__: *mut ManipulationVelocities) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationInertiaStartingEventArgs", "Velocities", 10usize, |this| ((**(this as *const *const IManipulationInertiaStartingEventArgs_Vtbl)).Velocities)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Input")]
+            PointerDeviceType,
+            #[cfg(not(feature = "Devices_Input"))]
+            PointerDeviceType: 0,
+            Position,
+            Delta,
+            Cumulative,
+            Velocities,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IManipulationInertiaStartingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IManipulationInertiaStartingEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationInertiaStartingEventArgs2", "ContactCount", 6usize, |this| ((**(this as *const *const IManipulationInertiaStartingEventArgs2_Vtbl)).ContactCount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContactCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IManipulationInertiaStartingEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Input")]
+impl windows_core::imp::ProxyVtable for IManipulationStartedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Input")]
+        unsafe extern "system" fn PointerDeviceType(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Input::PointerDeviceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationStartedEventArgs", "PointerDeviceType", 6usize, |this| ((**(this as *const *const IManipulationStartedEventArgs_Vtbl)).PointerDeviceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationStartedEventArgs", "Position", 7usize, |this| ((**(this as *const *const IManipulationStartedEventArgs_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cumulative(this: *mut core::ffi::c_void, result__: *mut ManipulationDelta) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationStartedEventArgs", "Cumulative", 8usize, |this| ((**(this as *const *const IManipulationStartedEventArgs_Vtbl)).Cumulative)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Input")]
+            PointerDeviceType,
+            #[cfg(not(feature = "Devices_Input"))]
+            PointerDeviceType: 0,
+            Position,
+            Cumulative,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IManipulationStartedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IManipulationStartedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationStartedEventArgs2", "ContactCount", 6usize, |this| ((**(this as *const *const IManipulationStartedEventArgs2_Vtbl)).ContactCount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContactCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IManipulationStartedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Input")]
+impl windows_core::imp::ProxyVtable for IManipulationUpdatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Input")]
+        unsafe extern "system" fn PointerDeviceType(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Input::PointerDeviceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationUpdatedEventArgs", "PointerDeviceType", 6usize, |this| ((**(this as *const *const IManipulationUpdatedEventArgs_Vtbl)).PointerDeviceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationUpdatedEventArgs", "Position", 7usize, |this| ((**(this as *const *const IManipulationUpdatedEventArgs_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delta(this: *mut core::ffi::c_void, result__: *mut ManipulationDelta) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationUpdatedEventArgs", "Delta", 8usize, |this| ((**(this as *const *const IManipulationUpdatedEventArgs_Vtbl)).Delta)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cumulative(this: *mut core::ffi::c_void, result__: *mut ManipulationDelta) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationUpdatedEventArgs", "Cumulative", 9usize, |this| ((**(this as *const *const IManipulationUpdatedEventArgs_Vtbl)).Cumulative)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Velocities(this: *mut core::ffi::c_void, result__: *mut ManipulationVelocities) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationUpdatedEventArgs", "Velocities", 10usize, |this| ((**(this as *const *const IManipulationUpdatedEventArgs_Vtbl)).Velocities)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Input")]
+            PointerDeviceType,
+            #[cfg(not(feature = "Devices_Input"))]
+            PointerDeviceType: 0,
+            Position,
+            Delta,
+            Cumulative,
+            Velocities,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IManipulationUpdatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IManipulationUpdatedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationUpdatedEventArgs2", "ContactCount", 6usize, |this| ((**(this as *const *const IManipulationUpdatedEventArgs2_Vtbl)).ContactCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationUpdatedEventArgs2", "CurrentContactCount", 7usize, |this| ((**(this as *const *const IManipulationUpdatedEventArgs2_Vtbl)).CurrentContactCount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContactCount, CurrentContactCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IManipulationUpdatedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMouseWheelParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CharTranslation(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseWheelParameters", "CharTranslation", 6usize, |this| ((**(this as *const *const IMouseWheelParameters_Vtbl)).CharTranslation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCharTranslation(this: *mut core::ffi::c_void, value: super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseWheelParameters", "SetCharTranslation", 7usize, |this| ((**(this as *const *const IMouseWheelParameters_Vtbl)).SetCharTranslation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeltaScale(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseWheelParameters", "DeltaScale", 8usize, |this| ((**(this as *const *const IMouseWheelParameters_Vtbl)).DeltaScale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeltaScale(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseWheelParameters", "SetDeltaScale", 9usize, |this| ((**(this as *const *const IMouseWheelParameters_Vtbl)).SetDeltaScale)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeltaRotationAngle(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseWheelParameters", "DeltaRotationAngle", 10usize, |this| ((**(this as *const *const IMouseWheelParameters_Vtbl)).DeltaRotationAngle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeltaRotationAngle(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseWheelParameters", "SetDeltaRotationAngle", 11usize, |this| ((**(this as *const *const IMouseWheelParameters_Vtbl)).SetDeltaRotationAngle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageTranslation(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseWheelParameters", "PageTranslation", 12usize, |this| ((**(this as *const *const IMouseWheelParameters_Vtbl)).PageTranslation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPageTranslation(this: *mut core::ffi::c_void, value: super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMouseWheelParameters", "SetPageTranslation", 13usize, |this| ((**(this as *const *const IMouseWheelParameters_Vtbl)).SetPageTranslation)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CharTranslation,
+            SetCharTranslation,
+            DeltaScale,
+            SetDeltaScale,
+            DeltaRotationAngle,
+            SetDeltaRotationAngle,
+            PageTranslation,
+            SetPageTranslation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMouseWheelParameters as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Input")]
+impl windows_core::imp::ProxyVtable for IPointerPoint_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Input")]
+        unsafe extern "system" fn PointerDevice(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPoint", "PointerDevice", 6usize, |this| ((**(this as *const *const IPointerPoint_Vtbl)).PointerDevice)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPoint", "Position", 7usize, |this| ((**(this as *const *const IPointerPoint_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawPosition(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPoint", "RawPosition", 8usize, |this| ((**(this as *const *const IPointerPoint_Vtbl)).RawPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPoint", "PointerId", 9usize, |this| ((**(this as *const *const IPointerPoint_Vtbl)).PointerId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPoint", "FrameId", 10usize, |this| ((**(this as *const *const IPointerPoint_Vtbl)).FrameId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPoint", "Timestamp", 11usize, |this| ((**(this as *const *const IPointerPoint_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInContact(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPoint", "IsInContact", 12usize, |this| ((**(this as *const *const IPointerPoint_Vtbl)).IsInContact)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPoint", "Properties", 13usize, |this| ((**(this as *const *const IPointerPoint_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Input")]
+            PointerDevice,
+            #[cfg(not(feature = "Devices_Input"))]
+            PointerDevice: 0,
+            Position,
+            RawPosition,
+            PointerId,
+            FrameId,
+            Timestamp,
+            IsInContact,
+            Properties,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPointerPoint as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPointerPointProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Pressure(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "Pressure", 6usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).Pressure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInverted(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "IsInverted", 7usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).IsInverted)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEraser(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "IsEraser", 8usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).IsEraser)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "Orientation", 9usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn XTilt(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "XTilt", 10usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).XTilt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn YTilt(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "YTilt", 11usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).YTilt)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Twist(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "Twist", 12usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).Twist)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContactRect(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "ContactRect", 13usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).ContactRect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContactRectRaw(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "ContactRectRaw", 14usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).ContactRectRaw)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TouchConfidence(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "TouchConfidence", 15usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).TouchConfidence)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLeftButtonPressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "IsLeftButtonPressed", 16usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).IsLeftButtonPressed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRightButtonPressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "IsRightButtonPressed", 17usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).IsRightButtonPressed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMiddleButtonPressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "IsMiddleButtonPressed", 18usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).IsMiddleButtonPressed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MouseWheelDelta(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "MouseWheelDelta", 19usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).MouseWheelDelta)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHorizontalMouseWheel(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "IsHorizontalMouseWheel", 20usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).IsHorizontalMouseWheel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPrimary(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "IsPrimary", 21usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).IsPrimary)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInRange(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "IsInRange", 22usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).IsInRange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCanceled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "IsCanceled", 23usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).IsCanceled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBarrelButtonPressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "IsBarrelButtonPressed", 24usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).IsBarrelButtonPressed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsXButton1Pressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "IsXButton1Pressed", 25usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).IsXButton1Pressed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsXButton2Pressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "IsXButton2Pressed", 26usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).IsXButton2Pressed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerUpdateKind(this: *mut core::ffi::c_void, result__: *mut PointerUpdateKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "PointerUpdateKind", 27usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).PointerUpdateKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasUsage(this: *mut core::ffi::c_void, usagepage: u32, usageid: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "HasUsage", 28usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).HasUsage)(this, usagepage, usageid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUsageValue(this: *mut core::ffi::c_void, usagepage: u32, usageid: u32, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties", "GetUsageValue", 29usize, |this| ((**(this as *const *const IPointerPointProperties_Vtbl)).GetUsageValue)(this, usagepage, usageid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Pressure,
+            IsInverted,
+            IsEraser,
+            Orientation,
+            XTilt,
+            YTilt,
+            Twist,
+            ContactRect,
+            ContactRectRaw,
+            TouchConfidence,
+            IsLeftButtonPressed,
+            IsRightButtonPressed,
+            IsMiddleButtonPressed,
+            MouseWheelDelta,
+            IsHorizontalMouseWheel,
+            IsPrimary,
+            IsInRange,
+            IsCanceled,
+            IsBarrelButtonPressed,
+            IsXButton1Pressed,
+            IsXButton2Pressed,
+            PointerUpdateKind,
+            HasUsage,
+            GetUsageValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPointerPointProperties as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPointerPointProperties2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ZDistance(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointProperties2", "ZDistance", 6usize, |this| ((**(this as *const *const IPointerPointProperties2_Vtbl)).ZDistance)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ZDistance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPointerPointProperties2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPointerPointStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentPoint(this: *mut core::ffi::c_void, pointerid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointStatics", "GetCurrentPoint", 6usize, |this| ((**(this as *const *const IPointerPointStatics_Vtbl)).GetCurrentPoint)(this, pointerid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetIntermediatePoints(this: *mut core::ffi::c_void, pointerid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointStatics", "GetIntermediatePoints", 7usize, |this| ((**(this as *const *const IPointerPointStatics_Vtbl)).GetIntermediatePoints)(this, pointerid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPointTransformed(this: *mut core::ffi::c_void, pointerid: u32, transform: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointStatics", "GetCurrentPointTransformed", 8usize, |this| ((**(this as *const *const IPointerPointStatics_Vtbl)).GetCurrentPointTransformed)(this, pointerid, transform, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetIntermediatePointsTransformed(this: *mut core::ffi::c_void, pointerid: u32, transform: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointStatics", "GetIntermediatePointsTransformed", 9usize, |this| ((**(this as *const *const IPointerPointStatics_Vtbl)).GetIntermediatePointsTransformed)(this, pointerid, transform, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentPoint,
+            #[cfg(feature = "Foundation_Collections")]
+            GetIntermediatePoints,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetIntermediatePoints: 0,
+            GetCurrentPointTransformed,
+            #[cfg(feature = "Foundation_Collections")]
+            GetIntermediatePointsTransformed,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetIntermediatePointsTransformed: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPointerPointStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPointerPointTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Inverse(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointTransform", "Inverse", 6usize, |this| ((**(this as *const *const IPointerPointTransform_Vtbl)).Inverse)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryTransform(this: *mut core::ffi::c_void, inpoint: super::super::Foundation::Point, outpoint: *mut super::super::Foundation::Point, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointTransform", "TryTransform", 7usize, |this| ((**(this as *const *const IPointerPointTransform_Vtbl)).TryTransform)(this, inpoint, outpoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransformBounds(this: *mut core::ffi::c_void, rect: super::super::Foundation::Rect, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerPointTransform", "TransformBounds", 8usize, |this| ((**(this as *const *const IPointerPointTransform_Vtbl)).TransformBounds)(this, rect, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Inverse, TryTransform, TransformBounds }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPointerPointTransform as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPointerVisualizationSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIsContactFeedbackEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerVisualizationSettings", "SetIsContactFeedbackEnabled", 6usize, |this| ((**(this as *const *const IPointerVisualizationSettings_Vtbl)).SetIsContactFeedbackEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsContactFeedbackEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerVisualizationSettings", "IsContactFeedbackEnabled", 7usize, |this| ((**(this as *const *const IPointerVisualizationSettings_Vtbl)).IsContactFeedbackEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsBarrelButtonFeedbackEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerVisualizationSettings", "SetIsBarrelButtonFeedbackEnabled", 8usize, |this| ((**(this as *const *const IPointerVisualizationSettings_Vtbl)).SetIsBarrelButtonFeedbackEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBarrelButtonFeedbackEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerVisualizationSettings", "IsBarrelButtonFeedbackEnabled", 9usize, |this| ((**(this as *const *const IPointerVisualizationSettings_Vtbl)).IsBarrelButtonFeedbackEnabled)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetIsContactFeedbackEnabled,
+            IsContactFeedbackEnabled,
+            SetIsBarrelButtonFeedbackEnabled,
+            IsBarrelButtonFeedbackEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPointerVisualizationSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPointerVisualizationSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerVisualizationSettingsStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IPointerVisualizationSettingsStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPointerVisualizationSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Menu(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "Menu", 6usize, |this| ((**(this as *const *const IRadialController_Vtbl)).Menu)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationResolutionInDegrees(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "RotationResolutionInDegrees", 7usize, |this| ((**(this as *const *const IRadialController_Vtbl)).RotationResolutionInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotationResolutionInDegrees(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "SetRotationResolutionInDegrees", 8usize, |this| ((**(this as *const *const IRadialController_Vtbl)).SetRotationResolutionInDegrees)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseAutomaticHapticFeedback(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "UseAutomaticHapticFeedback", 9usize, |this| ((**(this as *const *const IRadialController_Vtbl)).UseAutomaticHapticFeedback)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseAutomaticHapticFeedback(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "SetUseAutomaticHapticFeedback", 10usize, |this| ((**(this as *const *const IRadialController_Vtbl)).SetUseAutomaticHapticFeedback)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScreenContactStarted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "ScreenContactStarted", 11usize, |this| ((**(this as *const *const IRadialController_Vtbl)).ScreenContactStarted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveScreenContactStarted(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "RemoveScreenContactStarted", 12usize, |this| ((**(this as *const *const IRadialController_Vtbl)).RemoveScreenContactStarted)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScreenContactEnded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "ScreenContactEnded", 13usize, |this| ((**(this as *const *const IRadialController_Vtbl)).ScreenContactEnded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveScreenContactEnded(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "RemoveScreenContactEnded", 14usize, |this| ((**(this as *const *const IRadialController_Vtbl)).RemoveScreenContactEnded)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScreenContactContinued(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "ScreenContactContinued", 15usize, |this| ((**(this as *const *const IRadialController_Vtbl)).ScreenContactContinued)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveScreenContactContinued(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "RemoveScreenContactContinued", 16usize, |this| ((**(this as *const *const IRadialController_Vtbl)).RemoveScreenContactContinued)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControlLost(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "ControlLost", 17usize, |this| ((**(this as *const *const IRadialController_Vtbl)).ControlLost)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveControlLost(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "RemoveControlLost", 18usize, |this| ((**(this as *const *const IRadialController_Vtbl)).RemoveControlLost)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "RotationChanged", 19usize, |this| ((**(this as *const *const IRadialController_Vtbl)).RotationChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRotationChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "RemoveRotationChanged", 20usize, |this| ((**(this as *const *const IRadialController_Vtbl)).RemoveRotationChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonClicked(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "ButtonClicked", 21usize, |this| ((**(this as *const *const IRadialController_Vtbl)).ButtonClicked)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveButtonClicked(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "RemoveButtonClicked", 22usize, |this| ((**(this as *const *const IRadialController_Vtbl)).RemoveButtonClicked)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControlAcquired(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "ControlAcquired", 23usize, |this| ((**(this as *const *const IRadialController_Vtbl)).ControlAcquired)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveControlAcquired(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController", "RemoveControlAcquired", 24usize, |this| ((**(this as *const *const IRadialController_Vtbl)).RemoveControlAcquired)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Menu,
+            RotationResolutionInDegrees,
+            SetRotationResolutionInDegrees,
+            UseAutomaticHapticFeedback,
+            SetUseAutomaticHapticFeedback,
+            ScreenContactStarted,
+            RemoveScreenContactStarted,
+            ScreenContactEnded,
+            RemoveScreenContactEnded,
+            ScreenContactContinued,
+            RemoveScreenContactContinued,
+            ControlLost,
+            RemoveControlLost,
+            RotationChanged,
+            RemoveRotationChanged,
+            ButtonClicked,
+            RemoveButtonClicked,
+            ControlAcquired,
+            RemoveControlAcquired,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialController2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ButtonPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController2", "ButtonPressed", 6usize, |this| ((**(this as *const *const IRadialController2_Vtbl)).ButtonPressed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveButtonPressed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController2", "RemoveButtonPressed", 7usize, |this| ((**(this as *const *const IRadialController2_Vtbl)).RemoveButtonPressed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonHolding(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController2", "ButtonHolding", 8usize, |this| ((**(this as *const *const IRadialController2_Vtbl)).ButtonHolding)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveButtonHolding(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController2", "RemoveButtonHolding", 9usize, |this| ((**(this as *const *const IRadialController2_Vtbl)).RemoveButtonHolding)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonReleased(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController2", "ButtonReleased", 10usize, |this| ((**(this as *const *const IRadialController2_Vtbl)).ButtonReleased)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveButtonReleased(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialController2", "RemoveButtonReleased", 11usize, |this| ((**(this as *const *const IRadialController2_Vtbl)).RemoveButtonReleased)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ButtonPressed,
+            RemoveButtonPressed,
+            ButtonHolding,
+            RemoveButtonHolding,
+            ButtonReleased,
+            RemoveButtonReleased,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialController2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialControllerButtonClickedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerButtonClickedEventArgs", "Contact", 6usize, |this| ((**(this as *const *const IRadialControllerButtonClickedEventArgs_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Contact }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerButtonClickedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Haptics")]
+impl windows_core::imp::ProxyVtable for IRadialControllerButtonClickedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Haptics")]
+        unsafe extern "system" fn SimpleHapticsController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerButtonClickedEventArgs2", "SimpleHapticsController", 6usize, |this| ((**(this as *const *const IRadialControllerButtonClickedEventArgs2_Vtbl)).SimpleHapticsController)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Haptics")]
+            SimpleHapticsController,
+            #[cfg(not(feature = "Devices_Haptics"))]
+            SimpleHapticsController: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerButtonClickedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Haptics")]
+impl windows_core::imp::ProxyVtable for IRadialControllerButtonHoldingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerButtonHoldingEventArgs", "Contact", 6usize, |this| ((**(this as *const *const IRadialControllerButtonHoldingEventArgs_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Haptics")]
+        unsafe extern "system" fn SimpleHapticsController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerButtonHoldingEventArgs", "SimpleHapticsController", 7usize, |this| ((**(this as *const *const IRadialControllerButtonHoldingEventArgs_Vtbl)).SimpleHapticsController)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Contact,
+            #[cfg(feature = "Devices_Haptics")]
+            SimpleHapticsController,
+            #[cfg(not(feature = "Devices_Haptics"))]
+            SimpleHapticsController: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerButtonHoldingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Haptics")]
+impl windows_core::imp::ProxyVtable for IRadialControllerButtonPressedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerButtonPressedEventArgs", "Contact", 6usize, |this| ((**(this as *const *const IRadialControllerButtonPressedEventArgs_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Haptics")]
+        unsafe extern "system" fn SimpleHapticsController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerButtonPressedEventArgs", "SimpleHapticsController", 7usize, |this| ((**(this as *const *const IRadialControllerButtonPressedEventArgs_Vtbl)).SimpleHapticsController)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Contact,
+            #[cfg(feature = "Devices_Haptics")]
+            SimpleHapticsController,
+            #[cfg(not(feature = "Devices_Haptics"))]
+            SimpleHapticsController: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerButtonPressedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Haptics")]
+impl windows_core::imp::ProxyVtable for IRadialControllerButtonReleasedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerButtonReleasedEventArgs", "Contact", 6usize, |this| ((**(this as *const *const IRadialControllerButtonReleasedEventArgs_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Haptics")]
+        unsafe extern "system" fn SimpleHapticsController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerButtonReleasedEventArgs", "SimpleHapticsController", 7usize, |this| ((**(this as *const *const IRadialControllerButtonReleasedEventArgs_Vtbl)).SimpleHapticsController)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Contact,
+            #[cfg(feature = "Devices_Haptics")]
+            SimpleHapticsController,
+            #[cfg(not(feature = "Devices_Haptics"))]
+            SimpleHapticsController: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerButtonReleasedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRadialControllerConfiguration_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SetDefaultMenuItems(this: *mut core::ffi::c_void, buttons: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerConfiguration", "SetDefaultMenuItems", 6usize, |this| ((**(this as *const *const IRadialControllerConfiguration_Vtbl)).SetDefaultMenuItems)(this, buttons), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetToDefaultMenuItems(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerConfiguration", "ResetToDefaultMenuItems", 7usize, |this| ((**(this as *const *const IRadialControllerConfiguration_Vtbl)).ResetToDefaultMenuItems)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySelectDefaultMenuItem(this: *mut core::ffi::c_void, r#type: RadialControllerSystemMenuItemKind, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerConfiguration", "TrySelectDefaultMenuItem", 8usize, |this| ((**(this as *const *const IRadialControllerConfiguration_Vtbl)).TrySelectDefaultMenuItem)(this, r#type, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            SetDefaultMenuItems,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SetDefaultMenuItems: 0,
+            ResetToDefaultMenuItems,
+            TrySelectDefaultMenuItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialControllerConfiguration2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetActiveControllerWhenMenuIsSuppressed(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerConfiguration2", "SetActiveControllerWhenMenuIsSuppressed", 6usize, |this| ((**(this as *const *const IRadialControllerConfiguration2_Vtbl)).SetActiveControllerWhenMenuIsSuppressed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActiveControllerWhenMenuIsSuppressed(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerConfiguration2", "ActiveControllerWhenMenuIsSuppressed", 7usize, |this| ((**(this as *const *const IRadialControllerConfiguration2_Vtbl)).ActiveControllerWhenMenuIsSuppressed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsMenuSuppressed(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerConfiguration2", "SetIsMenuSuppressed", 8usize, |this| ((**(this as *const *const IRadialControllerConfiguration2_Vtbl)).SetIsMenuSuppressed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMenuSuppressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerConfiguration2", "IsMenuSuppressed", 9usize, |this| ((**(this as *const *const IRadialControllerConfiguration2_Vtbl)).IsMenuSuppressed)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetActiveControllerWhenMenuIsSuppressed,
+            ActiveControllerWhenMenuIsSuppressed,
+            SetIsMenuSuppressed,
+            IsMenuSuppressed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerConfiguration2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialControllerConfigurationStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerConfigurationStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IRadialControllerConfigurationStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerConfigurationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialControllerConfigurationStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAppController(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerConfigurationStatics2", "SetAppController", 6usize, |this| ((**(this as *const *const IRadialControllerConfigurationStatics2_Vtbl)).SetAppController)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerConfigurationStatics2", "AppController", 7usize, |this| ((**(this as *const *const IRadialControllerConfigurationStatics2_Vtbl)).AppController)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsAppControllerEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerConfigurationStatics2", "SetIsAppControllerEnabled", 8usize, |this| ((**(this as *const *const IRadialControllerConfigurationStatics2_Vtbl)).SetIsAppControllerEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAppControllerEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerConfigurationStatics2", "IsAppControllerEnabled", 9usize, |this| ((**(this as *const *const IRadialControllerConfigurationStatics2_Vtbl)).IsAppControllerEnabled)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAppController,
+            AppController,
+            SetIsAppControllerEnabled,
+            IsAppControllerEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerConfigurationStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialControllerControlAcquiredEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerControlAcquiredEventArgs", "Contact", 6usize, |this| ((**(this as *const *const IRadialControllerControlAcquiredEventArgs_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Contact }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerControlAcquiredEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Haptics")]
+impl windows_core::imp::ProxyVtable for IRadialControllerControlAcquiredEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsButtonPressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerControlAcquiredEventArgs2", "IsButtonPressed", 6usize, |this| ((**(this as *const *const IRadialControllerControlAcquiredEventArgs2_Vtbl)).IsButtonPressed)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Haptics")]
+        unsafe extern "system" fn SimpleHapticsController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerControlAcquiredEventArgs2", "SimpleHapticsController", 7usize, |this| ((**(this as *const *const IRadialControllerControlAcquiredEventArgs2_Vtbl)).SimpleHapticsController)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsButtonPressed,
+            #[cfg(feature = "Devices_Haptics")]
+            SimpleHapticsController,
+            #[cfg(not(feature = "Devices_Haptics"))]
+            SimpleHapticsController: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerControlAcquiredEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IRadialControllerMenu_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Items(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerMenu", "Items", 6usize, |this| ((**(this as *const *const IRadialControllerMenu_Vtbl)).Items)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerMenu", "IsEnabled", 7usize, |this| ((**(this as *const *const IRadialControllerMenu_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerMenu", "SetIsEnabled", 8usize, |this| ((**(this as *const *const IRadialControllerMenu_Vtbl)).SetIsEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelectedMenuItem(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerMenu", "GetSelectedMenuItem", 9usize, |this| ((**(this as *const *const IRadialControllerMenu_Vtbl)).GetSelectedMenuItem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectMenuItem(this: *mut core::ffi::c_void, menuitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerMenu", "SelectMenuItem", 10usize, |this| ((**(this as *const *const IRadialControllerMenu_Vtbl)).SelectMenuItem)(this, menuitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySelectPreviouslySelectedMenuItem(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerMenu", "TrySelectPreviouslySelectedMenuItem", 11usize, |this| ((**(this as *const *const IRadialControllerMenu_Vtbl)).TrySelectPreviouslySelectedMenuItem)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Items,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Items: 0,
+            IsEnabled,
+            SetIsEnabled,
+            GetSelectedMenuItem,
+            SelectMenuItem,
+            TrySelectPreviouslySelectedMenuItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerMenu as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialControllerMenuItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerMenuItem", "DisplayText", 6usize, |this| ((**(this as *const *const IRadialControllerMenuItem_Vtbl)).DisplayText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tag(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerMenuItem", "Tag", 7usize, |this| ((**(this as *const *const IRadialControllerMenuItem_Vtbl)).Tag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerMenuItem", "SetTag", 8usize, |this| ((**(this as *const *const IRadialControllerMenuItem_Vtbl)).SetTag)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invoked(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerMenuItem", "Invoked", 9usize, |this| ((**(this as *const *const IRadialControllerMenuItem_Vtbl)).Invoked)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveInvoked(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerMenuItem", "RemoveInvoked", 10usize, |this| ((**(this as *const *const IRadialControllerMenuItem_Vtbl)).RemoveInvoked)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayText, Tag, SetTag, Invoked, RemoveInvoked }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerMenuItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IRadialControllerMenuItemStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromIcon(this: *mut core::ffi::c_void, displaytext: core::mem::MaybeUninit<windows_core::HSTRING>, icon: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerMenuItemStatics", "CreateFromIcon", 6usize, |this| ((**(this as *const *const IRadialControllerMenuItemStatics_Vtbl)).CreateFromIcon)(this, displaytext, icon, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromKnownIcon(this: *mut core::ffi::c_void, displaytext: core::mem::MaybeUninit<windows_core::HSTRING>, value: RadialControllerMenuKnownIcon, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerMenuItemStatics", "CreateFromKnownIcon", 7usize, |this| ((**(this as *const *const IRadialControllerMenuItemStatics_Vtbl)).CreateFromKnownIcon)(this, displaytext, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromIcon,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromIcon: 0,
+            CreateFromKnownIcon,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerMenuItemStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialControllerMenuItemStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromFontGlyph(this: *mut core::ffi::c_void, displaytext: core::mem::MaybeUninit<windows_core::HSTRING>, glyph: core::mem::MaybeUninit<windows_core::HSTRING>, fontfamily: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerMenuItemStatics2", "CreateFromFontGlyph", 6usize, |this| ((**(this as *const *const IRadialControllerMenuItemStatics2_Vtbl)).CreateFromFontGlyph)(this, displaytext, glyph, fontfamily, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromFontGlyphWithUri(this: *mut core::ffi::c_void, displaytext: core::mem::MaybeUninit<windows_core::HSTRING>, glyph: core::mem::MaybeUninit<windows_core::HSTRING>, fontfamily: core::mem::MaybeUninit<windows_core::HSTRING>, fonturi: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerMenuItemStatics2", "CreateFromFontGlyphWithUri", 7usize, |this| ((**(this as *const *const IRadialControllerMenuItemStatics2_Vtbl)).CreateFromFontGlyphWithUri)(this, displaytext, glyph, fontfamily, fonturi, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromFontGlyph, CreateFromFontGlyphWithUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerMenuItemStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialControllerRotationChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RotationDeltaInDegrees(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerRotationChangedEventArgs", "RotationDeltaInDegrees", 6usize, |this| ((**(this as *const *const IRadialControllerRotationChangedEventArgs_Vtbl)).RotationDeltaInDegrees)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerRotationChangedEventArgs", "Contact", 7usize, |this| ((**(this as *const *const IRadialControllerRotationChangedEventArgs_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RotationDeltaInDegrees, Contact }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerRotationChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Haptics")]
+impl windows_core::imp::ProxyVtable for IRadialControllerRotationChangedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsButtonPressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerRotationChangedEventArgs2", "IsButtonPressed", 6usize, |this| ((**(this as *const *const IRadialControllerRotationChangedEventArgs2_Vtbl)).IsButtonPressed)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Haptics")]
+        unsafe extern "system" fn SimpleHapticsController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerRotationChangedEventArgs2", "SimpleHapticsController", 7usize, |this| ((**(this as *const *const IRadialControllerRotationChangedEventArgs2_Vtbl)).SimpleHapticsController)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsButtonPressed,
+            #[cfg(feature = "Devices_Haptics")]
+            SimpleHapticsController,
+            #[cfg(not(feature = "Devices_Haptics"))]
+            SimpleHapticsController: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerRotationChangedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialControllerScreenContact_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Bounds(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerScreenContact", "Bounds", 6usize, |this| ((**(this as *const *const IRadialControllerScreenContact_Vtbl)).Bounds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerScreenContact", "Position", 7usize, |this| ((**(this as *const *const IRadialControllerScreenContact_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Bounds, Position }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerScreenContact as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialControllerScreenContactContinuedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerScreenContactContinuedEventArgs", "Contact", 6usize, |this| ((**(this as *const *const IRadialControllerScreenContactContinuedEventArgs_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Contact }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerScreenContactContinuedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Haptics")]
+impl windows_core::imp::ProxyVtable for IRadialControllerScreenContactContinuedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsButtonPressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerScreenContactContinuedEventArgs2", "IsButtonPressed", 6usize, |this| ((**(this as *const *const IRadialControllerScreenContactContinuedEventArgs2_Vtbl)).IsButtonPressed)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Haptics")]
+        unsafe extern "system" fn SimpleHapticsController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerScreenContactContinuedEventArgs2", "SimpleHapticsController", 7usize, |this| ((**(this as *const *const IRadialControllerScreenContactContinuedEventArgs2_Vtbl)).SimpleHapticsController)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsButtonPressed,
+            #[cfg(feature = "Devices_Haptics")]
+            SimpleHapticsController,
+            #[cfg(not(feature = "Devices_Haptics"))]
+            SimpleHapticsController: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerScreenContactContinuedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Haptics")]
+impl windows_core::imp::ProxyVtable for IRadialControllerScreenContactEndedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsButtonPressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerScreenContactEndedEventArgs", "IsButtonPressed", 6usize, |this| ((**(this as *const *const IRadialControllerScreenContactEndedEventArgs_Vtbl)).IsButtonPressed)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Haptics")]
+        unsafe extern "system" fn SimpleHapticsController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerScreenContactEndedEventArgs", "SimpleHapticsController", 7usize, |this| ((**(this as *const *const IRadialControllerScreenContactEndedEventArgs_Vtbl)).SimpleHapticsController)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsButtonPressed,
+            #[cfg(feature = "Devices_Haptics")]
+            SimpleHapticsController,
+            #[cfg(not(feature = "Devices_Haptics"))]
+            SimpleHapticsController: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerScreenContactEndedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialControllerScreenContactStartedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Contact(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerScreenContactStartedEventArgs", "Contact", 6usize, |this| ((**(this as *const *const IRadialControllerScreenContactStartedEventArgs_Vtbl)).Contact)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Contact }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerScreenContactStartedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Haptics")]
+impl windows_core::imp::ProxyVtable for IRadialControllerScreenContactStartedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsButtonPressed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerScreenContactStartedEventArgs2", "IsButtonPressed", 6usize, |this| ((**(this as *const *const IRadialControllerScreenContactStartedEventArgs2_Vtbl)).IsButtonPressed)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Devices_Haptics")]
+        unsafe extern "system" fn SimpleHapticsController(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerScreenContactStartedEventArgs2", "SimpleHapticsController", 7usize, |this| ((**(this as *const *const IRadialControllerScreenContactStartedEventArgs2_Vtbl)).SimpleHapticsController)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsButtonPressed,
+            #[cfg(feature = "Devices_Haptics")]
+            SimpleHapticsController,
+            #[cfg(not(feature = "Devices_Haptics"))]
+            SimpleHapticsController: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerScreenContactStartedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialControllerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerStatics", "IsSupported", 6usize, |this| ((**(this as *const *const IRadialControllerStatics_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerStatics", "CreateForCurrentView", 7usize, |this| ((**(this as *const *const IRadialControllerStatics_Vtbl)).CreateForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSupported, CreateForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Input")]
+impl windows_core::imp::ProxyVtable for IRightTappedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Input")]
+        unsafe extern "system" fn PointerDeviceType(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Input::PointerDeviceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRightTappedEventArgs", "PointerDeviceType", 6usize, |this| ((**(this as *const *const IRightTappedEventArgs_Vtbl)).PointerDeviceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRightTappedEventArgs", "Position", 7usize, |this| ((**(this as *const *const IRightTappedEventArgs_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Input")]
+            PointerDeviceType,
+            #[cfg(not(feature = "Devices_Input"))]
+            PointerDeviceType: 0,
+            Position,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRightTappedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRightTappedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRightTappedEventArgs2", "ContactCount", 6usize, |this| ((**(this as *const *const IRightTappedEventArgs2_Vtbl)).ContactCount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContactCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRightTappedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemButtonEventController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SystemFunctionButtonPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemButtonEventController", "SystemFunctionButtonPressed", 6usize, |this| ((**(this as *const *const ISystemButtonEventController_Vtbl)).SystemFunctionButtonPressed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSystemFunctionButtonPressed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemButtonEventController", "RemoveSystemFunctionButtonPressed", 7usize, |this| ((**(this as *const *const ISystemButtonEventController_Vtbl)).RemoveSystemFunctionButtonPressed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemFunctionButtonReleased(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemButtonEventController", "SystemFunctionButtonReleased", 8usize, |this| ((**(this as *const *const ISystemButtonEventController_Vtbl)).SystemFunctionButtonReleased)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSystemFunctionButtonReleased(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemButtonEventController", "RemoveSystemFunctionButtonReleased", 9usize, |this| ((**(this as *const *const ISystemButtonEventController_Vtbl)).RemoveSystemFunctionButtonReleased)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemFunctionLockChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemButtonEventController", "SystemFunctionLockChanged", 10usize, |this| ((**(this as *const *const ISystemButtonEventController_Vtbl)).SystemFunctionLockChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSystemFunctionLockChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemButtonEventController", "RemoveSystemFunctionLockChanged", 11usize, |this| ((**(this as *const *const ISystemButtonEventController_Vtbl)).RemoveSystemFunctionLockChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemFunctionLockIndicatorChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemButtonEventController", "SystemFunctionLockIndicatorChanged", 12usize, |this| ((**(this as *const *const ISystemButtonEventController_Vtbl)).SystemFunctionLockIndicatorChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSystemFunctionLockIndicatorChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemButtonEventController", "RemoveSystemFunctionLockIndicatorChanged", 13usize, |this| ((**(this as *const *const ISystemButtonEventController_Vtbl)).RemoveSystemFunctionLockIndicatorChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SystemFunctionButtonPressed,
+            RemoveSystemFunctionButtonPressed,
+            SystemFunctionButtonReleased,
+            RemoveSystemFunctionButtonReleased,
+            SystemFunctionLockChanged,
+            RemoveSystemFunctionLockChanged,
+            SystemFunctionLockIndicatorChanged,
+            RemoveSystemFunctionLockIndicatorChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemButtonEventController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for ISystemButtonEventControllerStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn CreateForDispatcherQueue(this: *mut core::ffi::c_void, queue: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemButtonEventControllerStatics", "CreateForDispatcherQueue", 6usize, |this| ((**(this as *const *const ISystemButtonEventControllerStatics_Vtbl)).CreateForDispatcherQueue)(this, queue, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            CreateForDispatcherQueue,
+            #[cfg(not(feature = "System"))]
+            CreateForDispatcherQueue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemButtonEventControllerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemFunctionButtonEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemFunctionButtonEventArgs", "Timestamp", 6usize, |this| ((**(this as *const *const ISystemFunctionButtonEventArgs_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemFunctionButtonEventArgs", "Handled", 7usize, |this| ((**(this as *const *const ISystemFunctionButtonEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemFunctionButtonEventArgs", "SetHandled", 8usize, |this| ((**(this as *const *const ISystemFunctionButtonEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, Handled, SetHandled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemFunctionButtonEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemFunctionLockChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemFunctionLockChangedEventArgs", "Timestamp", 6usize, |this| ((**(this as *const *const ISystemFunctionLockChangedEventArgs_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLocked(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemFunctionLockChangedEventArgs", "IsLocked", 7usize, |this| ((**(this as *const *const ISystemFunctionLockChangedEventArgs_Vtbl)).IsLocked)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemFunctionLockChangedEventArgs", "Handled", 8usize, |this| ((**(this as *const *const ISystemFunctionLockChangedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemFunctionLockChangedEventArgs", "SetHandled", 9usize, |this| ((**(this as *const *const ISystemFunctionLockChangedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, IsLocked, Handled, SetHandled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemFunctionLockChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemFunctionLockIndicatorChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemFunctionLockIndicatorChangedEventArgs", "Timestamp", 6usize, |this| ((**(this as *const *const ISystemFunctionLockIndicatorChangedEventArgs_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIndicatorOn(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemFunctionLockIndicatorChangedEventArgs", "IsIndicatorOn", 7usize, |this| ((**(this as *const *const ISystemFunctionLockIndicatorChangedEventArgs_Vtbl)).IsIndicatorOn)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemFunctionLockIndicatorChangedEventArgs", "Handled", 8usize, |this| ((**(this as *const *const ISystemFunctionLockIndicatorChangedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemFunctionLockIndicatorChangedEventArgs", "SetHandled", 9usize, |this| ((**(this as *const *const ISystemFunctionLockIndicatorChangedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, IsIndicatorOn, Handled, SetHandled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemFunctionLockIndicatorChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Devices_Input")]
+impl windows_core::imp::ProxyVtable for ITappedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Input")]
+        unsafe extern "system" fn PointerDeviceType(this: *mut core::ffi::c_void, result__: *mut super::super::Devices::Input::PointerDeviceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITappedEventArgs", "PointerDeviceType", 6usize, |this| ((**(this as *const *const ITappedEventArgs_Vtbl)).PointerDeviceType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITappedEventArgs", "Position", 7usize, |this| ((**(this as *const *const ITappedEventArgs_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TapCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITappedEventArgs", "TapCount", 8usize, |this| ((**(this as *const *const ITappedEventArgs_Vtbl)).TapCount)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Input")]
+            PointerDeviceType,
+            #[cfg(not(feature = "Devices_Input"))]
+            PointerDeviceType: 0,
+            Position,
+            TapCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITappedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITappedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContactCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITappedEventArgs2", "ContactCount", 6usize, |this| ((**(this as *const *const ITappedEventArgs2_Vtbl)).ContactCount)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContactCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITappedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
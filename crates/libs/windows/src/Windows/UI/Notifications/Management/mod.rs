@@ -117,6 +117,7 @@ impl UserNotificationListenerAccessStatus {
 impl windows_core::TypeKind for UserNotificationListenerAccessStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserNotificationListenerAccessStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserNotificationListenerAccessStatus").field(&self.0).finish()
@@ -125,3 +126,5 @@ impl core::fmt::Debug for UserNotificationListenerAccessStatus {
 impl windows_core::RuntimeType for UserNotificationListenerAccessStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.Notifications.Management.UserNotificationListenerAccessStatus;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,60 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IUserNotificationListener_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAccessAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotificationListener", "RequestAccessAsync", 6usize, |this| ((**(this as *const *const IUserNotificationListener_Vtbl)).RequestAccessAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccessStatus(this: *mut core::ffi::c_void, result__: *mut UserNotificationListenerAccessStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotificationListener", "GetAccessStatus", 7usize, |this| ((**(this as *const *const IUserNotificationListener_Vtbl)).GetAccessStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotificationChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotificationListener", "NotificationChanged", 8usize, |this| ((**(this as *const *const IUserNotificationListener_Vtbl)).NotificationChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNotificationChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotificationListener", "RemoveNotificationChanged", 9usize, |this| ((**(this as *const *const IUserNotificationListener_Vtbl)).RemoveNotificationChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetNotificationsAsync(this: *mut core::ffi::c_void, kinds: super::NotificationKinds, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotificationListener", "GetNotificationsAsync", 10usize, |this| ((**(this as *const *const IUserNotificationListener_Vtbl)).GetNotificationsAsync)(this, kinds, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNotification(this: *mut core::ffi::c_void, notificationid: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotificationListener", "GetNotification", 11usize, |this| ((**(this as *const *const IUserNotificationListener_Vtbl)).GetNotification)(this, notificationid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearNotifications(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotificationListener", "ClearNotifications", 12usize, |this| ((**(this as *const *const IUserNotificationListener_Vtbl)).ClearNotifications)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNotification(this: *mut core::ffi::c_void, notificationid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotificationListener", "RemoveNotification", 13usize, |this| ((**(this as *const *const IUserNotificationListener_Vtbl)).RemoveNotification)(this, notificationid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestAccessAsync,
+            GetAccessStatus,
+            NotificationChanged,
+            RemoveNotificationChanged,
+            #[cfg(feature = "Foundation_Collections")]
+            GetNotificationsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetNotificationsAsync: 0,
+            GetNotification,
+            ClearNotifications,
+            RemoveNotification,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserNotificationListener as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserNotificationListenerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotificationListenerStatics", "Current", 6usize, |this| ((**(this as *const *const IUserNotificationListenerStatics_Vtbl)).Current)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Current }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserNotificationListenerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -21,3 +21,5 @@ impl ToastOcclusionManagerPreview {
 impl windows_core::RuntimeName for ToastOcclusionManagerPreview {
     const NAME: &'static str = "Windows.UI.Notifications.Preview.ToastOcclusionManagerPreview";
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,12 @@
+impl windows_core::imp::ProxyVtable for IToastOcclusionManagerPreviewStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetToastWindowMargin(this: *mut core::ffi::c_void, appwindowid: super::super::WindowId, margin: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastOcclusionManagerPreviewStatics", "SetToastWindowMargin", 6usize, |this| ((**(this as *const *const IToastOcclusionManagerPreviewStatics_Vtbl)).SetToastWindowMargin)(this, appwindowid, margin), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetToastWindowMargin }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastOcclusionManagerPreviewStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
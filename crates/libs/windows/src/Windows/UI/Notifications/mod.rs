@@ -3195,6 +3195,7 @@ impl AdaptiveNotificationContentKind {
 impl windows_core::TypeKind for AdaptiveNotificationContentKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AdaptiveNotificationContentKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AdaptiveNotificationContentKind").field(&self.0).finish()
@@ -3213,6 +3214,7 @@ impl BadgeTemplateType {
 impl windows_core::TypeKind for BadgeTemplateType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BadgeTemplateType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BadgeTemplateType").field(&self.0).finish()
@@ -3231,6 +3233,7 @@ impl NotificationKinds {
 impl windows_core::TypeKind for NotificationKinds {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NotificationKinds {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NotificationKinds").field(&self.0).finish()
@@ -3282,6 +3285,7 @@ impl NotificationMirroring {
 impl windows_core::TypeKind for NotificationMirroring {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NotificationMirroring {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NotificationMirroring").field(&self.0).finish()
@@ -3303,6 +3307,7 @@ impl NotificationSetting {
 impl windows_core::TypeKind for NotificationSetting {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NotificationSetting {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NotificationSetting").field(&self.0).finish()
@@ -3322,6 +3327,7 @@ impl NotificationUpdateResult {
 impl windows_core::TypeKind for NotificationUpdateResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NotificationUpdateResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NotificationUpdateResult").field(&self.0).finish()
@@ -3343,6 +3349,7 @@ impl PeriodicUpdateRecurrence {
 impl windows_core::TypeKind for PeriodicUpdateRecurrence {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PeriodicUpdateRecurrence {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PeriodicUpdateRecurrence").field(&self.0).finish()
@@ -3360,6 +3367,7 @@ impl TileFlyoutTemplateType {
 impl windows_core::TypeKind for TileFlyoutTemplateType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TileFlyoutTemplateType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TileFlyoutTemplateType").field(&self.0).finish()
@@ -3502,6 +3510,7 @@ impl TileTemplateType {
 impl windows_core::TypeKind for TileTemplateType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TileTemplateType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TileTemplateType").field(&self.0).finish()
@@ -3521,6 +3530,7 @@ impl ToastDismissalReason {
 impl windows_core::TypeKind for ToastDismissalReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ToastDismissalReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ToastDismissalReason").field(&self.0).finish()
@@ -3541,6 +3551,7 @@ impl ToastHistoryChangedType {
 impl windows_core::TypeKind for ToastHistoryChangedType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ToastHistoryChangedType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ToastHistoryChangedType").field(&self.0).finish()
@@ -3560,6 +3571,7 @@ impl ToastNotificationMode {
 impl windows_core::TypeKind for ToastNotificationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ToastNotificationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ToastNotificationMode").field(&self.0).finish()
@@ -3578,6 +3590,7 @@ impl ToastNotificationPriority {
 impl windows_core::TypeKind for ToastNotificationPriority {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ToastNotificationPriority {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ToastNotificationPriority").field(&self.0).finish()
@@ -3602,6 +3615,7 @@ impl ToastTemplateType {
 impl windows_core::TypeKind for ToastTemplateType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ToastTemplateType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ToastTemplateType").field(&self.0).finish()
@@ -3620,6 +3634,7 @@ impl UserNotificationChangedKind {
 impl windows_core::TypeKind for UserNotificationChangedKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserNotificationChangedKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserNotificationChangedKind").field(&self.0).finish()
@@ -3628,5 +3643,8 @@ impl core::fmt::Debug for UserNotificationChangedKind {
 impl windows_core::RuntimeType for UserNotificationChangedKind {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.Notifications.UserNotificationChangedKind;i4)");
 }
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "UI/Notifications/Toast.rs"));
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1802 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAdaptiveNotificationContent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut AdaptiveNotificationContentKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveNotificationContent", "Kind", 6usize, |this| ((**(this as *const *const IAdaptiveNotificationContent_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Hints(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveNotificationContent", "Hints", 7usize, |this| ((**(this as *const *const IAdaptiveNotificationContent_Vtbl)).Hints)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Kind,
+            #[cfg(feature = "Foundation_Collections")]
+            Hints,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Hints: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveNotificationContent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveNotificationText_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveNotificationText", "Text", 6usize, |this| ((**(this as *const *const IAdaptiveNotificationText_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveNotificationText", "SetText", 7usize, |this| ((**(this as *const *const IAdaptiveNotificationText_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveNotificationText", "Language", 8usize, |this| ((**(this as *const *const IAdaptiveNotificationText_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveNotificationText", "SetLanguage", 9usize, |this| ((**(this as *const *const IAdaptiveNotificationText_Vtbl)).SetLanguage)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Text, SetText, Language, SetLanguage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveNotificationText as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IBadgeNotification_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeNotification", "Content", 6usize, |this| ((**(this as *const *const IBadgeNotification_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExpirationTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeNotification", "SetExpirationTime", 7usize, |this| ((**(this as *const *const IBadgeNotification_Vtbl)).SetExpirationTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeNotification", "ExpirationTime", 8usize, |this| ((**(this as *const *const IBadgeNotification_Vtbl)).ExpirationTime)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            Content,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            Content: 0,
+            SetExpirationTime,
+            ExpirationTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBadgeNotification as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IBadgeNotificationFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn CreateBadgeNotification(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeNotificationFactory", "CreateBadgeNotification", 6usize, |this| ((**(this as *const *const IBadgeNotificationFactory_Vtbl)).CreateBadgeNotification)(this, content, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            CreateBadgeNotification,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            CreateBadgeNotification: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBadgeNotificationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IBadgeUpdateManagerForUser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateBadgeUpdaterForApplication(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeUpdateManagerForUser", "CreateBadgeUpdaterForApplication", 6usize, |this| ((**(this as *const *const IBadgeUpdateManagerForUser_Vtbl)).CreateBadgeUpdaterForApplication)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBadgeUpdaterForApplicationWithId(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeUpdateManagerForUser", "CreateBadgeUpdaterForApplicationWithId", 7usize, |this| ((**(this as *const *const IBadgeUpdateManagerForUser_Vtbl)).CreateBadgeUpdaterForApplicationWithId)(this, applicationid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBadgeUpdaterForSecondaryTile(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeUpdateManagerForUser", "CreateBadgeUpdaterForSecondaryTile", 8usize, |this| ((**(this as *const *const IBadgeUpdateManagerForUser_Vtbl)).CreateBadgeUpdaterForSecondaryTile)(this, tileid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeUpdateManagerForUser", "User", 9usize, |this| ((**(this as *const *const IBadgeUpdateManagerForUser_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateBadgeUpdaterForApplication,
+            CreateBadgeUpdaterForApplicationWithId,
+            CreateBadgeUpdaterForSecondaryTile,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBadgeUpdateManagerForUser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IBadgeUpdateManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateBadgeUpdaterForApplication(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeUpdateManagerStatics", "CreateBadgeUpdaterForApplication", 6usize, |this| ((**(this as *const *const IBadgeUpdateManagerStatics_Vtbl)).CreateBadgeUpdaterForApplication)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBadgeUpdaterForApplicationWithId(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeUpdateManagerStatics", "CreateBadgeUpdaterForApplicationWithId", 7usize, |this| ((**(this as *const *const IBadgeUpdateManagerStatics_Vtbl)).CreateBadgeUpdaterForApplicationWithId)(this, applicationid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBadgeUpdaterForSecondaryTile(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeUpdateManagerStatics", "CreateBadgeUpdaterForSecondaryTile", 8usize, |this| ((**(this as *const *const IBadgeUpdateManagerStatics_Vtbl)).CreateBadgeUpdaterForSecondaryTile)(this, tileid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn GetTemplateContent(this: *mut core::ffi::c_void, r#type: BadgeTemplateType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeUpdateManagerStatics", "GetTemplateContent", 9usize, |this| ((**(this as *const *const IBadgeUpdateManagerStatics_Vtbl)).GetTemplateContent)(this, r#type, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateBadgeUpdaterForApplication,
+            CreateBadgeUpdaterForApplicationWithId,
+            CreateBadgeUpdaterForSecondaryTile,
+            #[cfg(feature = "Data_Xml_Dom")]
+            GetTemplateContent,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            GetTemplateContent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBadgeUpdateManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IBadgeUpdateManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeUpdateManagerStatics2", "GetForUser", 6usize, |this| ((**(this as *const *const IBadgeUpdateManagerStatics2_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBadgeUpdateManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBadgeUpdater_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, notification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeUpdater", "Update", 6usize, |this| ((**(this as *const *const IBadgeUpdater_Vtbl)).Update)(this, notification), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeUpdater", "Clear", 7usize, |this| ((**(this as *const *const IBadgeUpdater_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartPeriodicUpdate(this: *mut core::ffi::c_void, badgecontent: *mut core::ffi::c_void, requestedinterval: PeriodicUpdateRecurrence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeUpdater", "StartPeriodicUpdate", 8usize, |this| ((**(this as *const *const IBadgeUpdater_Vtbl)).StartPeriodicUpdate)(this, badgecontent, requestedinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartPeriodicUpdateAtTime(this: *mut core::ffi::c_void, badgecontent: *mut core::ffi::c_void, starttime: super::super::Foundation::DateTime, requestedinterval: PeriodicUpdateRecurrence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeUpdater", "StartPeriodicUpdateAtTime", 9usize, |this| ((**(this as *const *const IBadgeUpdater_Vtbl)).StartPeriodicUpdateAtTime)(this, badgecontent, starttime, requestedinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopPeriodicUpdate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBadgeUpdater", "StopPeriodicUpdate", 10usize, |this| ((**(this as *const *const IBadgeUpdater_Vtbl)).StopPeriodicUpdate)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Update,
+            Clear,
+            StartPeriodicUpdate,
+            StartPeriodicUpdateAtTime,
+            StopPeriodicUpdate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBadgeUpdater as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKnownAdaptiveNotificationHintsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Style(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationHintsStatics", "Style", 6usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationHintsStatics_Vtbl)).Style)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wrap(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationHintsStatics", "Wrap", 7usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationHintsStatics_Vtbl)).Wrap)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxLines(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationHintsStatics", "MaxLines", 8usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationHintsStatics_Vtbl)).MaxLines)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinLines(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationHintsStatics", "MinLines", 9usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationHintsStatics_Vtbl)).MinLines)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextStacking(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationHintsStatics", "TextStacking", 10usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationHintsStatics_Vtbl)).TextStacking)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Align(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationHintsStatics", "Align", 11usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationHintsStatics_Vtbl)).Align)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Style, Wrap, MaxLines, MinLines, TextStacking, Align }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownAdaptiveNotificationHintsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKnownAdaptiveNotificationTextStylesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Caption(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "Caption", 6usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).Caption)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Body(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "Body", 7usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).Body)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Base(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "Base", 8usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).Base)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subtitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "Subtitle", 9usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).Subtitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "Title", 10usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subheader(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "Subheader", 11usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).Subheader)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Header(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "Header", 12usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).Header)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TitleNumeral(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "TitleNumeral", 13usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).TitleNumeral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubheaderNumeral(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "SubheaderNumeral", 14usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).SubheaderNumeral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeaderNumeral(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "HeaderNumeral", 15usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).HeaderNumeral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CaptionSubtle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "CaptionSubtle", 16usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).CaptionSubtle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BodySubtle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "BodySubtle", 17usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).BodySubtle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BaseSubtle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "BaseSubtle", 18usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).BaseSubtle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubtitleSubtle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "SubtitleSubtle", 19usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).SubtitleSubtle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TitleSubtle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "TitleSubtle", 20usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).TitleSubtle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubheaderSubtle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "SubheaderSubtle", 21usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).SubheaderSubtle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubheaderNumeralSubtle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "SubheaderNumeralSubtle", 22usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).SubheaderNumeralSubtle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeaderSubtle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "HeaderSubtle", 23usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).HeaderSubtle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeaderNumeralSubtle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownAdaptiveNotificationTextStylesStatics", "HeaderNumeralSubtle", 24usize, |this| ((**(this as *const *const IKnownAdaptiveNotificationTextStylesStatics_Vtbl)).HeaderNumeralSubtle)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Caption,
+            Body,
+            Base,
+            Subtitle,
+            Title,
+            Subheader,
+            Header,
+            TitleNumeral,
+            SubheaderNumeral,
+            HeaderNumeral,
+            CaptionSubtle,
+            BodySubtle,
+            BaseSubtle,
+            SubtitleSubtle,
+            TitleSubtle,
+            SubheaderSubtle,
+            SubheaderNumeralSubtle,
+            HeaderSubtle,
+            HeaderNumeralSubtle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownAdaptiveNotificationTextStylesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKnownNotificationBindingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ToastGeneric(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnownNotificationBindingsStatics", "ToastGeneric", 6usize, |this| ((**(this as *const *const IKnownNotificationBindingsStatics_Vtbl)).ToastGeneric)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ToastGeneric }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnownNotificationBindingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INotification_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExpirationTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotification", "ExpirationTime", 6usize, |this| ((**(this as *const *const INotification_Vtbl)).ExpirationTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExpirationTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotification", "SetExpirationTime", 7usize, |this| ((**(this as *const *const INotification_Vtbl)).SetExpirationTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Visual(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotification", "Visual", 8usize, |this| ((**(this as *const *const INotification_Vtbl)).Visual)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVisual(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotification", "SetVisual", 9usize, |this| ((**(this as *const *const INotification_Vtbl)).SetVisual)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExpirationTime, SetExpirationTime, Visual, SetVisual }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INotification as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for INotificationBinding_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Template(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationBinding", "Template", 6usize, |this| ((**(this as *const *const INotificationBinding_Vtbl)).Template)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTemplate(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationBinding", "SetTemplate", 7usize, |this| ((**(this as *const *const INotificationBinding_Vtbl)).SetTemplate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationBinding", "Language", 8usize, |this| ((**(this as *const *const INotificationBinding_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationBinding", "SetLanguage", 9usize, |this| ((**(this as *const *const INotificationBinding_Vtbl)).SetLanguage)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Hints(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationBinding", "Hints", 10usize, |this| ((**(this as *const *const INotificationBinding_Vtbl)).Hints)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetTextElements(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationBinding", "GetTextElements", 11usize, |this| ((**(this as *const *const INotificationBinding_Vtbl)).GetTextElements)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Template,
+            SetTemplate,
+            Language,
+            SetLanguage,
+            #[cfg(feature = "Foundation_Collections")]
+            Hints,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Hints: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetTextElements,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetTextElements: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INotificationBinding as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for INotificationData_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Values(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationData", "Values", 6usize, |this| ((**(this as *const *const INotificationData_Vtbl)).Values)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SequenceNumber(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationData", "SequenceNumber", 7usize, |this| ((**(this as *const *const INotificationData_Vtbl)).SequenceNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSequenceNumber(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationData", "SetSequenceNumber", 8usize, |this| ((**(this as *const *const INotificationData_Vtbl)).SetSequenceNumber)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Values,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Values: 0,
+            SequenceNumber,
+            SetSequenceNumber,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INotificationData as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for INotificationDataFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateNotificationDataWithValuesAndSequenceNumber(this: *mut core::ffi::c_void, initialvalues: *mut core::ffi::c_void, sequencenumber: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationDataFactory", "CreateNotificationDataWithValuesAndSequenceNumber", 6usize, |this| ((**(this as *const *const INotificationDataFactory_Vtbl)).CreateNotificationDataWithValuesAndSequenceNumber)(this, initialvalues, sequencenumber, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CreateNotificationDataWithValues(this: *mut core::ffi::c_void, initialvalues: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationDataFactory", "CreateNotificationDataWithValues", 7usize, |this| ((**(this as *const *const INotificationDataFactory_Vtbl)).CreateNotificationDataWithValues)(this, initialvalues, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateNotificationDataWithValuesAndSequenceNumber,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateNotificationDataWithValuesAndSequenceNumber: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            CreateNotificationDataWithValues,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CreateNotificationDataWithValues: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INotificationDataFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for INotificationVisual_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationVisual", "Language", 6usize, |this| ((**(this as *const *const INotificationVisual_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationVisual", "SetLanguage", 7usize, |this| ((**(this as *const *const INotificationVisual_Vtbl)).SetLanguage)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Bindings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationVisual", "Bindings", 8usize, |this| ((**(this as *const *const INotificationVisual_Vtbl)).Bindings)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBinding(this: *mut core::ffi::c_void, templatename: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationVisual", "GetBinding", 9usize, |this| ((**(this as *const *const INotificationVisual_Vtbl)).GetBinding)(this, templatename, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Language,
+            SetLanguage,
+            #[cfg(feature = "Foundation_Collections")]
+            Bindings,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Bindings: 0,
+            GetBinding,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INotificationVisual as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IScheduledTileNotification_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledTileNotification", "Content", 6usize, |this| ((**(this as *const *const IScheduledTileNotification_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeliveryTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledTileNotification", "DeliveryTime", 7usize, |this| ((**(this as *const *const IScheduledTileNotification_Vtbl)).DeliveryTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExpirationTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledTileNotification", "SetExpirationTime", 8usize, |this| ((**(this as *const *const IScheduledTileNotification_Vtbl)).SetExpirationTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledTileNotification", "ExpirationTime", 9usize, |this| ((**(this as *const *const IScheduledTileNotification_Vtbl)).ExpirationTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledTileNotification", "SetTag", 10usize, |this| ((**(this as *const *const IScheduledTileNotification_Vtbl)).SetTag)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tag(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledTileNotification", "Tag", 11usize, |this| ((**(this as *const *const IScheduledTileNotification_Vtbl)).Tag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledTileNotification", "SetId", 12usize, |this| ((**(this as *const *const IScheduledTileNotification_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledTileNotification", "Id", 13usize, |this| ((**(this as *const *const IScheduledTileNotification_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            Content,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            Content: 0,
+            DeliveryTime,
+            SetExpirationTime,
+            ExpirationTime,
+            SetTag,
+            Tag,
+            SetId,
+            Id,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScheduledTileNotification as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IScheduledTileNotificationFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn CreateScheduledTileNotification(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, deliverytime: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledTileNotificationFactory", "CreateScheduledTileNotification", 6usize, |this| ((**(this as *const *const IScheduledTileNotificationFactory_Vtbl)).CreateScheduledTileNotification)(this, content, deliverytime, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            CreateScheduledTileNotification,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            CreateScheduledTileNotification: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScheduledTileNotificationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IScheduledToastNotification_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification", "Content", 6usize, |this| ((**(this as *const *const IScheduledToastNotification_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeliveryTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification", "DeliveryTime", 7usize, |this| ((**(this as *const *const IScheduledToastNotification_Vtbl)).DeliveryTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SnoozeInterval(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification", "SnoozeInterval", 8usize, |this| ((**(this as *const *const IScheduledToastNotification_Vtbl)).SnoozeInterval)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaximumSnoozeCount(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification", "MaximumSnoozeCount", 9usize, |this| ((**(this as *const *const IScheduledToastNotification_Vtbl)).MaximumSnoozeCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification", "SetId", 10usize, |this| ((**(this as *const *const IScheduledToastNotification_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification", "Id", 11usize, |this| ((**(this as *const *const IScheduledToastNotification_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            Content,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            Content: 0,
+            DeliveryTime,
+            SnoozeInterval,
+            MaximumSnoozeCount,
+            SetId,
+            Id,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScheduledToastNotification as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScheduledToastNotification2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification2", "SetTag", 6usize, |this| ((**(this as *const *const IScheduledToastNotification2_Vtbl)).SetTag)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tag(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification2", "Tag", 7usize, |this| ((**(this as *const *const IScheduledToastNotification2_Vtbl)).Tag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroup(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification2", "SetGroup", 8usize, |this| ((**(this as *const *const IScheduledToastNotification2_Vtbl)).SetGroup)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Group(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification2", "Group", 9usize, |this| ((**(this as *const *const IScheduledToastNotification2_Vtbl)).Group)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuppressPopup(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification2", "SetSuppressPopup", 10usize, |this| ((**(this as *const *const IScheduledToastNotification2_Vtbl)).SetSuppressPopup)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuppressPopup(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification2", "SuppressPopup", 11usize, |this| ((**(this as *const *const IScheduledToastNotification2_Vtbl)).SuppressPopup)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetTag,
+            Tag,
+            SetGroup,
+            Group,
+            SetSuppressPopup,
+            SuppressPopup,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScheduledToastNotification2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScheduledToastNotification3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotificationMirroring(this: *mut core::ffi::c_void, result__: *mut NotificationMirroring) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification3", "NotificationMirroring", 6usize, |this| ((**(this as *const *const IScheduledToastNotification3_Vtbl)).NotificationMirroring)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotificationMirroring(this: *mut core::ffi::c_void, value: NotificationMirroring) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification3", "SetNotificationMirroring", 7usize, |this| ((**(this as *const *const IScheduledToastNotification3_Vtbl)).SetNotificationMirroring)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification3", "RemoteId", 8usize, |this| ((**(this as *const *const IScheduledToastNotification3_Vtbl)).RemoteId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification3", "SetRemoteId", 9usize, |this| ((**(this as *const *const IScheduledToastNotification3_Vtbl)).SetRemoteId)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NotificationMirroring,
+            SetNotificationMirroring,
+            RemoteId,
+            SetRemoteId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScheduledToastNotification3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScheduledToastNotification4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExpirationTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification4", "ExpirationTime", 6usize, |this| ((**(this as *const *const IScheduledToastNotification4_Vtbl)).ExpirationTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExpirationTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotification4", "SetExpirationTime", 7usize, |this| ((**(this as *const *const IScheduledToastNotification4_Vtbl)).SetExpirationTime)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExpirationTime, SetExpirationTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScheduledToastNotification4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IScheduledToastNotificationFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn CreateScheduledToastNotification(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, deliverytime: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotificationFactory", "CreateScheduledToastNotification", 6usize, |this| ((**(this as *const *const IScheduledToastNotificationFactory_Vtbl)).CreateScheduledToastNotification)(this, content, deliverytime, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn CreateScheduledToastNotificationRecurring(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, deliverytime: super::super::Foundation::DateTime, snoozeinterval: super::super::Foundation::TimeSpan, maximumsnoozecount: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotificationFactory", "CreateScheduledToastNotificationRecurring", 7usize, |this| ((**(this as *const *const IScheduledToastNotificationFactory_Vtbl)).CreateScheduledToastNotificationRecurring)(this, content, deliverytime, snoozeinterval, maximumsnoozecount, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            CreateScheduledToastNotification,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            CreateScheduledToastNotification: 0,
+            #[cfg(feature = "Data_Xml_Dom")]
+            CreateScheduledToastNotificationRecurring,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            CreateScheduledToastNotificationRecurring: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScheduledToastNotificationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScheduledToastNotificationShowingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotificationShowingEventArgs", "Cancel", 6usize, |this| ((**(this as *const *const IScheduledToastNotificationShowingEventArgs_Vtbl)).Cancel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCancel(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotificationShowingEventArgs", "SetCancel", 7usize, |this| ((**(this as *const *const IScheduledToastNotificationShowingEventArgs_Vtbl)).SetCancel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScheduledToastNotification(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotificationShowingEventArgs", "ScheduledToastNotification", 8usize, |this| ((**(this as *const *const IScheduledToastNotificationShowingEventArgs_Vtbl)).ScheduledToastNotification)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledToastNotificationShowingEventArgs", "GetDeferral", 9usize, |this| ((**(this as *const *const IScheduledToastNotificationShowingEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cancel, SetCancel, ScheduledToastNotification, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScheduledToastNotificationShowingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IShownTileNotification_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Arguments(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShownTileNotification", "Arguments", 6usize, |this| ((**(this as *const *const IShownTileNotification_Vtbl)).Arguments)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Arguments }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShownTileNotification as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for ITileFlyoutNotification_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileFlyoutNotification", "Content", 6usize, |this| ((**(this as *const *const ITileFlyoutNotification_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExpirationTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileFlyoutNotification", "SetExpirationTime", 7usize, |this| ((**(this as *const *const ITileFlyoutNotification_Vtbl)).SetExpirationTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileFlyoutNotification", "ExpirationTime", 8usize, |this| ((**(this as *const *const ITileFlyoutNotification_Vtbl)).ExpirationTime)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            Content,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            Content: 0,
+            SetExpirationTime,
+            ExpirationTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITileFlyoutNotification as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for ITileFlyoutNotificationFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn CreateTileFlyoutNotification(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileFlyoutNotificationFactory", "CreateTileFlyoutNotification", 6usize, |this| ((**(this as *const *const ITileFlyoutNotificationFactory_Vtbl)).CreateTileFlyoutNotification)(this, content, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            CreateTileFlyoutNotification,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            CreateTileFlyoutNotification: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITileFlyoutNotificationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for ITileFlyoutUpdateManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTileFlyoutUpdaterForApplication(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileFlyoutUpdateManagerStatics", "CreateTileFlyoutUpdaterForApplication", 6usize, |this| ((**(this as *const *const ITileFlyoutUpdateManagerStatics_Vtbl)).CreateTileFlyoutUpdaterForApplication)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTileFlyoutUpdaterForApplicationWithId(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileFlyoutUpdateManagerStatics", "CreateTileFlyoutUpdaterForApplicationWithId", 7usize, |this| ((**(this as *const *const ITileFlyoutUpdateManagerStatics_Vtbl)).CreateTileFlyoutUpdaterForApplicationWithId)(this, applicationid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTileFlyoutUpdaterForSecondaryTile(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileFlyoutUpdateManagerStatics", "CreateTileFlyoutUpdaterForSecondaryTile", 8usize, |this| ((**(this as *const *const ITileFlyoutUpdateManagerStatics_Vtbl)).CreateTileFlyoutUpdaterForSecondaryTile)(this, tileid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn GetTemplateContent(this: *mut core::ffi::c_void, r#type: TileFlyoutTemplateType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileFlyoutUpdateManagerStatics", "GetTemplateContent", 9usize, |this| ((**(this as *const *const ITileFlyoutUpdateManagerStatics_Vtbl)).GetTemplateContent)(this, r#type, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateTileFlyoutUpdaterForApplication,
+            CreateTileFlyoutUpdaterForApplicationWithId,
+            CreateTileFlyoutUpdaterForSecondaryTile,
+            #[cfg(feature = "Data_Xml_Dom")]
+            GetTemplateContent,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            GetTemplateContent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITileFlyoutUpdateManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITileFlyoutUpdater_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, notification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileFlyoutUpdater", "Update", 6usize, |this| ((**(this as *const *const ITileFlyoutUpdater_Vtbl)).Update)(this, notification), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileFlyoutUpdater", "Clear", 7usize, |this| ((**(this as *const *const ITileFlyoutUpdater_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartPeriodicUpdate(this: *mut core::ffi::c_void, tileflyoutcontent: *mut core::ffi::c_void, requestedinterval: PeriodicUpdateRecurrence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileFlyoutUpdater", "StartPeriodicUpdate", 8usize, |this| ((**(this as *const *const ITileFlyoutUpdater_Vtbl)).StartPeriodicUpdate)(this, tileflyoutcontent, requestedinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartPeriodicUpdateAtTime(this: *mut core::ffi::c_void, tileflyoutcontent: *mut core::ffi::c_void, starttime: super::super::Foundation::DateTime, requestedinterval: PeriodicUpdateRecurrence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileFlyoutUpdater", "StartPeriodicUpdateAtTime", 9usize, |this| ((**(this as *const *const ITileFlyoutUpdater_Vtbl)).StartPeriodicUpdateAtTime)(this, tileflyoutcontent, starttime, requestedinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopPeriodicUpdate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileFlyoutUpdater", "StopPeriodicUpdate", 10usize, |this| ((**(this as *const *const ITileFlyoutUpdater_Vtbl)).StopPeriodicUpdate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setting(this: *mut core::ffi::c_void, result__: *mut NotificationSetting) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileFlyoutUpdater", "Setting", 11usize, |this| ((**(this as *const *const ITileFlyoutUpdater_Vtbl)).Setting)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Update,
+            Clear,
+            StartPeriodicUpdate,
+            StartPeriodicUpdateAtTime,
+            StopPeriodicUpdate,
+            Setting,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITileFlyoutUpdater as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for ITileNotification_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileNotification", "Content", 6usize, |this| ((**(this as *const *const ITileNotification_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExpirationTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileNotification", "SetExpirationTime", 7usize, |this| ((**(this as *const *const ITileNotification_Vtbl)).SetExpirationTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileNotification", "ExpirationTime", 8usize, |this| ((**(this as *const *const ITileNotification_Vtbl)).ExpirationTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileNotification", "SetTag", 9usize, |this| ((**(this as *const *const ITileNotification_Vtbl)).SetTag)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tag(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileNotification", "Tag", 10usize, |this| ((**(this as *const *const ITileNotification_Vtbl)).Tag)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            Content,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            Content: 0,
+            SetExpirationTime,
+            ExpirationTime,
+            SetTag,
+            Tag,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITileNotification as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for ITileNotificationFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn CreateTileNotification(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileNotificationFactory", "CreateTileNotification", 6usize, |this| ((**(this as *const *const ITileNotificationFactory_Vtbl)).CreateTileNotification)(this, content, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            CreateTileNotification,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            CreateTileNotification: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITileNotificationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for ITileUpdateManagerForUser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTileUpdaterForApplication(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdateManagerForUser", "CreateTileUpdaterForApplication", 6usize, |this| ((**(this as *const *const ITileUpdateManagerForUser_Vtbl)).CreateTileUpdaterForApplication)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTileUpdaterForApplicationWithId(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdateManagerForUser", "CreateTileUpdaterForApplicationWithId", 7usize, |this| ((**(this as *const *const ITileUpdateManagerForUser_Vtbl)).CreateTileUpdaterForApplicationWithId)(this, applicationid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTileUpdaterForSecondaryTile(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdateManagerForUser", "CreateTileUpdaterForSecondaryTile", 8usize, |this| ((**(this as *const *const ITileUpdateManagerForUser_Vtbl)).CreateTileUpdaterForSecondaryTile)(this, tileid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdateManagerForUser", "User", 9usize, |this| ((**(this as *const *const ITileUpdateManagerForUser_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateTileUpdaterForApplication,
+            CreateTileUpdaterForApplicationWithId,
+            CreateTileUpdaterForSecondaryTile,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITileUpdateManagerForUser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for ITileUpdateManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTileUpdaterForApplication(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdateManagerStatics", "CreateTileUpdaterForApplication", 6usize, |this| ((**(this as *const *const ITileUpdateManagerStatics_Vtbl)).CreateTileUpdaterForApplication)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTileUpdaterForApplicationWithId(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdateManagerStatics", "CreateTileUpdaterForApplicationWithId", 7usize, |this| ((**(this as *const *const ITileUpdateManagerStatics_Vtbl)).CreateTileUpdaterForApplicationWithId)(this, applicationid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTileUpdaterForSecondaryTile(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdateManagerStatics", "CreateTileUpdaterForSecondaryTile", 8usize, |this| ((**(this as *const *const ITileUpdateManagerStatics_Vtbl)).CreateTileUpdaterForSecondaryTile)(this, tileid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn GetTemplateContent(this: *mut core::ffi::c_void, r#type: TileTemplateType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdateManagerStatics", "GetTemplateContent", 9usize, |this| ((**(this as *const *const ITileUpdateManagerStatics_Vtbl)).GetTemplateContent)(this, r#type, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateTileUpdaterForApplication,
+            CreateTileUpdaterForApplicationWithId,
+            CreateTileUpdaterForSecondaryTile,
+            #[cfg(feature = "Data_Xml_Dom")]
+            GetTemplateContent,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            GetTemplateContent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITileUpdateManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for ITileUpdateManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdateManagerStatics2", "GetForUser", 6usize, |this| ((**(this as *const *const ITileUpdateManagerStatics2_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITileUpdateManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ITileUpdater_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, notification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdater", "Update", 6usize, |this| ((**(this as *const *const ITileUpdater_Vtbl)).Update)(this, notification), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdater", "Clear", 7usize, |this| ((**(this as *const *const ITileUpdater_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableNotificationQueue(this: *mut core::ffi::c_void, enable: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdater", "EnableNotificationQueue", 8usize, |this| ((**(this as *const *const ITileUpdater_Vtbl)).EnableNotificationQueue)(this, enable), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setting(this: *mut core::ffi::c_void, result__: *mut NotificationSetting) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdater", "Setting", 9usize, |this| ((**(this as *const *const ITileUpdater_Vtbl)).Setting)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddToSchedule(this: *mut core::ffi::c_void, scheduledtile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdater", "AddToSchedule", 10usize, |this| ((**(this as *const *const ITileUpdater_Vtbl)).AddToSchedule)(this, scheduledtile), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFromSchedule(this: *mut core::ffi::c_void, scheduledtile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdater", "RemoveFromSchedule", 11usize, |this| ((**(this as *const *const ITileUpdater_Vtbl)).RemoveFromSchedule)(this, scheduledtile), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetScheduledTileNotifications(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdater", "GetScheduledTileNotifications", 12usize, |this| ((**(this as *const *const ITileUpdater_Vtbl)).GetScheduledTileNotifications)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartPeriodicUpdate(this: *mut core::ffi::c_void, tilecontent: *mut core::ffi::c_void, requestedinterval: PeriodicUpdateRecurrence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdater", "StartPeriodicUpdate", 13usize, |this| ((**(this as *const *const ITileUpdater_Vtbl)).StartPeriodicUpdate)(this, tilecontent, requestedinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartPeriodicUpdateAtTime(this: *mut core::ffi::c_void, tilecontent: *mut core::ffi::c_void, starttime: super::super::Foundation::DateTime, requestedinterval: PeriodicUpdateRecurrence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdater", "StartPeriodicUpdateAtTime", 14usize, |this| ((**(this as *const *const ITileUpdater_Vtbl)).StartPeriodicUpdateAtTime)(this, tilecontent, starttime, requestedinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopPeriodicUpdate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdater", "StopPeriodicUpdate", 15usize, |this| ((**(this as *const *const ITileUpdater_Vtbl)).StopPeriodicUpdate)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StartPeriodicUpdateBatch(this: *mut core::ffi::c_void, tilecontents: *mut core::ffi::c_void, requestedinterval: PeriodicUpdateRecurrence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdater", "StartPeriodicUpdateBatch", 16usize, |this| ((**(this as *const *const ITileUpdater_Vtbl)).StartPeriodicUpdateBatch)(this, tilecontents, requestedinterval), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn StartPeriodicUpdateBatchAtTime(this: *mut core::ffi::c_void, tilecontents: *mut core::ffi::c_void, starttime: super::super::Foundation::DateTime, requestedinterval: PeriodicUpdateRecurrence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdater", "StartPeriodicUpdateBatchAtTime", 17usize, |this| ((**(this as *const *const ITileUpdater_Vtbl)).StartPeriodicUpdateBatchAtTime)(this, tilecontents, starttime, requestedinterval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Update,
+            Clear,
+            EnableNotificationQueue,
+            Setting,
+            AddToSchedule,
+            RemoveFromSchedule,
+            #[cfg(feature = "Foundation_Collections")]
+            GetScheduledTileNotifications,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetScheduledTileNotifications: 0,
+            StartPeriodicUpdate,
+            StartPeriodicUpdateAtTime,
+            StopPeriodicUpdate,
+            #[cfg(feature = "Foundation_Collections")]
+            StartPeriodicUpdateBatch,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StartPeriodicUpdateBatch: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            StartPeriodicUpdateBatchAtTime,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            StartPeriodicUpdateBatchAtTime: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITileUpdater as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITileUpdater2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableNotificationQueueForSquare150x150(this: *mut core::ffi::c_void, enable: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdater2", "EnableNotificationQueueForSquare150x150", 6usize, |this| ((**(this as *const *const ITileUpdater2_Vtbl)).EnableNotificationQueueForSquare150x150)(this, enable), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableNotificationQueueForWide310x150(this: *mut core::ffi::c_void, enable: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdater2", "EnableNotificationQueueForWide310x150", 7usize, |this| ((**(this as *const *const ITileUpdater2_Vtbl)).EnableNotificationQueueForWide310x150)(this, enable), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableNotificationQueueForSquare310x310(this: *mut core::ffi::c_void, enable: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileUpdater2", "EnableNotificationQueueForSquare310x310", 8usize, |this| ((**(this as *const *const ITileUpdater2_Vtbl)).EnableNotificationQueueForSquare310x310)(this, enable), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnableNotificationQueueForSquare150x150,
+            EnableNotificationQueueForWide310x150,
+            EnableNotificationQueueForSquare310x310,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITileUpdater2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastActivatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Arguments(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastActivatedEventArgs", "Arguments", 6usize, |this| ((**(this as *const *const IToastActivatedEventArgs_Vtbl)).Arguments)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Arguments }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastActivatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IToastActivatedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UserInput(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastActivatedEventArgs2", "UserInput", 6usize, |this| ((**(this as *const *const IToastActivatedEventArgs2_Vtbl)).UserInput)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            UserInput,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UserInput: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastActivatedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastCollection", "Id", 6usize, |this| ((**(this as *const *const IToastCollection_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastCollection", "DisplayName", 7usize, |this| ((**(this as *const *const IToastCollection_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastCollection", "SetDisplayName", 8usize, |this| ((**(this as *const *const IToastCollection_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchArgs(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastCollection", "LaunchArgs", 9usize, |this| ((**(this as *const *const IToastCollection_Vtbl)).LaunchArgs)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLaunchArgs(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastCollection", "SetLaunchArgs", 10usize, |this| ((**(this as *const *const IToastCollection_Vtbl)).SetLaunchArgs)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Icon(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastCollection", "Icon", 11usize, |this| ((**(this as *const *const IToastCollection_Vtbl)).Icon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIcon(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastCollection", "SetIcon", 12usize, |this| ((**(this as *const *const IToastCollection_Vtbl)).SetIcon)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            DisplayName,
+            SetDisplayName,
+            LaunchArgs,
+            SetLaunchArgs,
+            Icon,
+            SetIcon,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastCollectionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, collectionid: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, launchargs: core::mem::MaybeUninit<windows_core::HSTRING>, iconuri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastCollectionFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IToastCollectionFactory_Vtbl)).CreateInstance)(this, collectionid, displayname, launchargs, iconuri, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastCollectionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IToastCollectionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SaveToastCollectionAsync(this: *mut core::ffi::c_void, collection: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastCollectionManager", "SaveToastCollectionAsync", 6usize, |this| ((**(this as *const *const IToastCollectionManager_Vtbl)).SaveToastCollectionAsync)(this, collection, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllToastCollectionsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastCollectionManager", "FindAllToastCollectionsAsync", 7usize, |this| ((**(this as *const *const IToastCollectionManager_Vtbl)).FindAllToastCollectionsAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetToastCollectionAsync(this: *mut core::ffi::c_void, collectionid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastCollectionManager", "GetToastCollectionAsync", 8usize, |this| ((**(this as *const *const IToastCollectionManager_Vtbl)).GetToastCollectionAsync)(this, collectionid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveToastCollectionAsync(this: *mut core::ffi::c_void, collectionid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastCollectionManager", "RemoveToastCollectionAsync", 9usize, |this| ((**(this as *const *const IToastCollectionManager_Vtbl)).RemoveToastCollectionAsync)(this, collectionid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllToastCollectionsAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastCollectionManager", "RemoveAllToastCollectionsAsync", 10usize, |this| ((**(this as *const *const IToastCollectionManager_Vtbl)).RemoveAllToastCollectionsAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastCollectionManager", "User", 11usize, |this| ((**(this as *const *const IToastCollectionManager_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastCollectionManager", "AppId", 12usize, |this| ((**(this as *const *const IToastCollectionManager_Vtbl)).AppId)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SaveToastCollectionAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllToastCollectionsAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllToastCollectionsAsync: 0,
+            GetToastCollectionAsync,
+            RemoveToastCollectionAsync,
+            RemoveAllToastCollectionsAsync,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+            AppId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastCollectionManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastDismissedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut ToastDismissalReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastDismissedEventArgs", "Reason", 6usize, |this| ((**(this as *const *const IToastDismissedEventArgs_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastDismissedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastFailedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastFailedEventArgs", "ErrorCode", 6usize, |this| ((**(this as *const *const IToastFailedEventArgs_Vtbl)).ErrorCode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ErrorCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastFailedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IToastNotification_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification", "Content", 6usize, |this| ((**(this as *const *const IToastNotification_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExpirationTime(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification", "SetExpirationTime", 7usize, |this| ((**(this as *const *const IToastNotification_Vtbl)).SetExpirationTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationTime(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification", "ExpirationTime", 8usize, |this| ((**(this as *const *const IToastNotification_Vtbl)).ExpirationTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Dismissed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification", "Dismissed", 9usize, |this| ((**(this as *const *const IToastNotification_Vtbl)).Dismissed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDismissed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification", "RemoveDismissed", 10usize, |this| ((**(this as *const *const IToastNotification_Vtbl)).RemoveDismissed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Activated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification", "Activated", 11usize, |this| ((**(this as *const *const IToastNotification_Vtbl)).Activated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveActivated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification", "RemoveActivated", 12usize, |this| ((**(this as *const *const IToastNotification_Vtbl)).RemoveActivated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Failed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification", "Failed", 13usize, |this| ((**(this as *const *const IToastNotification_Vtbl)).Failed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFailed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification", "RemoveFailed", 14usize, |this| ((**(this as *const *const IToastNotification_Vtbl)).RemoveFailed)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            Content,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            Content: 0,
+            SetExpirationTime,
+            ExpirationTime,
+            Dismissed,
+            RemoveDismissed,
+            Activated,
+            RemoveActivated,
+            Failed,
+            RemoveFailed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotification as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotification2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification2", "SetTag", 6usize, |this| ((**(this as *const *const IToastNotification2_Vtbl)).SetTag)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tag(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification2", "Tag", 7usize, |this| ((**(this as *const *const IToastNotification2_Vtbl)).Tag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroup(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification2", "SetGroup", 8usize, |this| ((**(this as *const *const IToastNotification2_Vtbl)).SetGroup)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Group(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification2", "Group", 9usize, |this| ((**(this as *const *const IToastNotification2_Vtbl)).Group)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuppressPopup(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification2", "SetSuppressPopup", 10usize, |this| ((**(this as *const *const IToastNotification2_Vtbl)).SetSuppressPopup)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuppressPopup(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification2", "SuppressPopup", 11usize, |this| ((**(this as *const *const IToastNotification2_Vtbl)).SuppressPopup)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetTag,
+            Tag,
+            SetGroup,
+            Group,
+            SetSuppressPopup,
+            SuppressPopup,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotification2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotification3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotificationMirroring(this: *mut core::ffi::c_void, result__: *mut NotificationMirroring) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification3", "NotificationMirroring", 6usize, |this| ((**(this as *const *const IToastNotification3_Vtbl)).NotificationMirroring)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotificationMirroring(this: *mut core::ffi::c_void, value: NotificationMirroring) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification3", "SetNotificationMirroring", 7usize, |this| ((**(this as *const *const IToastNotification3_Vtbl)).SetNotificationMirroring)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification3", "RemoteId", 8usize, |this| ((**(this as *const *const IToastNotification3_Vtbl)).RemoteId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification3", "SetRemoteId", 9usize, |this| ((**(this as *const *const IToastNotification3_Vtbl)).SetRemoteId)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NotificationMirroring,
+            SetNotificationMirroring,
+            RemoteId,
+            SetRemoteId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotification3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotification4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification4", "Data", 6usize, |this| ((**(this as *const *const IToastNotification4_Vtbl)).Data)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification4", "SetData", 7usize, |this| ((**(this as *const *const IToastNotification4_Vtbl)).SetData)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, result__: *mut ToastNotificationPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification4", "Priority", 8usize, |this| ((**(this as *const *const IToastNotification4_Vtbl)).Priority)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, value: ToastNotificationPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification4", "SetPriority", 9usize, |this| ((**(this as *const *const IToastNotification4_Vtbl)).SetPriority)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Data, SetData, Priority, SetPriority }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotification4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotification6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExpiresOnReboot(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification6", "ExpiresOnReboot", 6usize, |this| ((**(this as *const *const IToastNotification6_Vtbl)).ExpiresOnReboot)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExpiresOnReboot(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotification6", "SetExpiresOnReboot", 7usize, |this| ((**(this as *const *const IToastNotification6_Vtbl)).SetExpiresOnReboot)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExpiresOnReboot, SetExpiresOnReboot }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotification6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IToastNotificationActionTriggerDetail_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Argument(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationActionTriggerDetail", "Argument", 6usize, |this| ((**(this as *const *const IToastNotificationActionTriggerDetail_Vtbl)).Argument)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn UserInput(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationActionTriggerDetail", "UserInput", 7usize, |this| ((**(this as *const *const IToastNotificationActionTriggerDetail_Vtbl)).UserInput)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Argument,
+            #[cfg(feature = "Foundation_Collections")]
+            UserInput,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            UserInput: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationActionTriggerDetail as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IToastNotificationFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn CreateToastNotification(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationFactory", "CreateToastNotification", 6usize, |this| ((**(this as *const *const IToastNotificationFactory_Vtbl)).CreateToastNotification)(this, content, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Data_Xml_Dom")]
+            CreateToastNotification,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            CreateToastNotification: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotificationHistory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoveGroup(this: *mut core::ffi::c_void, group: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationHistory", "RemoveGroup", 6usize, |this| ((**(this as *const *const IToastNotificationHistory_Vtbl)).RemoveGroup)(this, group), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGroupWithId(this: *mut core::ffi::c_void, group: core::mem::MaybeUninit<windows_core::HSTRING>, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationHistory", "RemoveGroupWithId", 7usize, |this| ((**(this as *const *const IToastNotificationHistory_Vtbl)).RemoveGroupWithId)(this, group, applicationid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGroupedTagWithId(this: *mut core::ffi::c_void, tag: core::mem::MaybeUninit<windows_core::HSTRING>, group: core::mem::MaybeUninit<windows_core::HSTRING>, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationHistory", "RemoveGroupedTagWithId", 8usize, |this| ((**(this as *const *const IToastNotificationHistory_Vtbl)).RemoveGroupedTagWithId)(this, tag, group, applicationid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGroupedTag(this: *mut core::ffi::c_void, tag: core::mem::MaybeUninit<windows_core::HSTRING>, group: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationHistory", "RemoveGroupedTag", 9usize, |this| ((**(this as *const *const IToastNotificationHistory_Vtbl)).RemoveGroupedTag)(this, tag, group), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, tag: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationHistory", "Remove", 10usize, |this| ((**(this as *const *const IToastNotificationHistory_Vtbl)).Remove)(this, tag), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationHistory", "Clear", 11usize, |this| ((**(this as *const *const IToastNotificationHistory_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearWithId(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationHistory", "ClearWithId", 12usize, |this| ((**(this as *const *const IToastNotificationHistory_Vtbl)).ClearWithId)(this, applicationid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RemoveGroup,
+            RemoveGroupWithId,
+            RemoveGroupedTagWithId,
+            RemoveGroupedTag,
+            Remove,
+            Clear,
+            ClearWithId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationHistory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IToastNotificationHistory2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetHistory(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationHistory2", "GetHistory", 6usize, |this| ((**(this as *const *const IToastNotificationHistory2_Vtbl)).GetHistory)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetHistoryWithId(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationHistory2", "GetHistoryWithId", 7usize, |this| ((**(this as *const *const IToastNotificationHistory2_Vtbl)).GetHistoryWithId)(this, applicationid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            GetHistory,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetHistory: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            GetHistoryWithId,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetHistoryWithId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationHistory2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotificationHistoryChangedTriggerDetail_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeType(this: *mut core::ffi::c_void, result__: *mut ToastHistoryChangedType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationHistoryChangedTriggerDetail", "ChangeType", 6usize, |this| ((**(this as *const *const IToastNotificationHistoryChangedTriggerDetail_Vtbl)).ChangeType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ChangeType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationHistoryChangedTriggerDetail as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotificationHistoryChangedTriggerDetail2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CollectionId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationHistoryChangedTriggerDetail2", "CollectionId", 6usize, |this| ((**(this as *const *const IToastNotificationHistoryChangedTriggerDetail2_Vtbl)).CollectionId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CollectionId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationHistoryChangedTriggerDetail2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IToastNotificationManagerForUser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateToastNotifier(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerForUser", "CreateToastNotifier", 6usize, |this| ((**(this as *const *const IToastNotificationManagerForUser_Vtbl)).CreateToastNotifier)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateToastNotifierWithId(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerForUser", "CreateToastNotifierWithId", 7usize, |this| ((**(this as *const *const IToastNotificationManagerForUser_Vtbl)).CreateToastNotifierWithId)(this, applicationid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn History(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerForUser", "History", 8usize, |this| ((**(this as *const *const IToastNotificationManagerForUser_Vtbl)).History)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerForUser", "User", 9usize, |this| ((**(this as *const *const IToastNotificationManagerForUser_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateToastNotifier,
+            CreateToastNotifierWithId,
+            History,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationManagerForUser as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotificationManagerForUser2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetToastNotifierForToastCollectionIdAsync(this: *mut core::ffi::c_void, collectionid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerForUser2", "GetToastNotifierForToastCollectionIdAsync", 6usize, |this| ((**(this as *const *const IToastNotificationManagerForUser2_Vtbl)).GetToastNotifierForToastCollectionIdAsync)(this, collectionid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHistoryForToastCollectionIdAsync(this: *mut core::ffi::c_void, collectionid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerForUser2", "GetHistoryForToastCollectionIdAsync", 7usize, |this| ((**(this as *const *const IToastNotificationManagerForUser2_Vtbl)).GetHistoryForToastCollectionIdAsync)(this, collectionid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetToastCollectionManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerForUser2", "GetToastCollectionManager", 8usize, |this| ((**(this as *const *const IToastNotificationManagerForUser2_Vtbl)).GetToastCollectionManager)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetToastCollectionManagerWithAppId(this: *mut core::ffi::c_void, appid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerForUser2", "GetToastCollectionManagerWithAppId", 9usize, |this| ((**(this as *const *const IToastNotificationManagerForUser2_Vtbl)).GetToastCollectionManagerWithAppId)(this, appid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetToastNotifierForToastCollectionIdAsync,
+            GetHistoryForToastCollectionIdAsync,
+            GetToastCollectionManager,
+            GetToastCollectionManagerWithAppId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationManagerForUser2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotificationManagerForUser3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotificationMode(this: *mut core::ffi::c_void, result__: *mut ToastNotificationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerForUser3", "NotificationMode", 6usize, |this| ((**(this as *const *const IToastNotificationManagerForUser3_Vtbl)).NotificationMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotificationModeChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerForUser3", "NotificationModeChanged", 7usize, |this| ((**(this as *const *const IToastNotificationManagerForUser3_Vtbl)).NotificationModeChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNotificationModeChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerForUser3", "RemoveNotificationModeChanged", 8usize, |this| ((**(this as *const *const IToastNotificationManagerForUser3_Vtbl)).RemoveNotificationModeChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NotificationMode,
+            NotificationModeChanged,
+            RemoveNotificationModeChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationManagerForUser3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Data_Xml_Dom")]
+impl windows_core::imp::ProxyVtable for IToastNotificationManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateToastNotifier(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerStatics", "CreateToastNotifier", 6usize, |this| ((**(this as *const *const IToastNotificationManagerStatics_Vtbl)).CreateToastNotifier)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateToastNotifierWithId(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerStatics", "CreateToastNotifierWithId", 7usize, |this| ((**(this as *const *const IToastNotificationManagerStatics_Vtbl)).CreateToastNotifierWithId)(this, applicationid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn GetTemplateContent(this: *mut core::ffi::c_void, r#type: ToastTemplateType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerStatics", "GetTemplateContent", 8usize, |this| ((**(this as *const *const IToastNotificationManagerStatics_Vtbl)).GetTemplateContent)(this, r#type, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateToastNotifier,
+            CreateToastNotifierWithId,
+            #[cfg(feature = "Data_Xml_Dom")]
+            GetTemplateContent,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            GetTemplateContent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotificationManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn History(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerStatics2", "History", 6usize, |this| ((**(this as *const *const IToastNotificationManagerStatics2_Vtbl)).History)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, History }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IToastNotificationManagerStatics4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerStatics4", "GetForUser", 6usize, |this| ((**(this as *const *const IToastNotificationManagerStatics4_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConfigureNotificationMirroring(this: *mut core::ffi::c_void, value: NotificationMirroring) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerStatics4", "ConfigureNotificationMirroring", 7usize, |this| ((**(this as *const *const IToastNotificationManagerStatics4_Vtbl)).ConfigureNotificationMirroring)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+            ConfigureNotificationMirroring,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationManagerStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotificationManagerStatics5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotificationManagerStatics5", "GetDefault", 6usize, |this| ((**(this as *const *const IToastNotificationManagerStatics5_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotificationManagerStatics5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IToastNotifier_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, notification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotifier", "Show", 6usize, |this| ((**(this as *const *const IToastNotifier_Vtbl)).Show)(this, notification), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hide(this: *mut core::ffi::c_void, notification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotifier", "Hide", 7usize, |this| ((**(this as *const *const IToastNotifier_Vtbl)).Hide)(this, notification), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setting(this: *mut core::ffi::c_void, result__: *mut NotificationSetting) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotifier", "Setting", 8usize, |this| ((**(this as *const *const IToastNotifier_Vtbl)).Setting)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddToSchedule(this: *mut core::ffi::c_void, scheduledtoast: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotifier", "AddToSchedule", 9usize, |this| ((**(this as *const *const IToastNotifier_Vtbl)).AddToSchedule)(this, scheduledtoast), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFromSchedule(this: *mut core::ffi::c_void, scheduledtoast: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotifier", "RemoveFromSchedule", 10usize, |this| ((**(this as *const *const IToastNotifier_Vtbl)).RemoveFromSchedule)(this, scheduledtoast), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetScheduledToastNotifications(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotifier", "GetScheduledToastNotifications", 11usize, |this| ((**(this as *const *const IToastNotifier_Vtbl)).GetScheduledToastNotifications)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Show,
+            Hide,
+            Setting,
+            AddToSchedule,
+            RemoveFromSchedule,
+            #[cfg(feature = "Foundation_Collections")]
+            GetScheduledToastNotifications,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetScheduledToastNotifications: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotifier as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotifier2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UpdateWithTagAndGroup(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, tag: core::mem::MaybeUninit<windows_core::HSTRING>, group: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut NotificationUpdateResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotifier2", "UpdateWithTagAndGroup", 6usize, |this| ((**(this as *const *const IToastNotifier2_Vtbl)).UpdateWithTagAndGroup)(this, data, tag, group, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateWithTag(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, tag: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut NotificationUpdateResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotifier2", "UpdateWithTag", 7usize, |this| ((**(this as *const *const IToastNotifier2_Vtbl)).UpdateWithTag)(this, data, tag, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UpdateWithTagAndGroup, UpdateWithTag }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotifier2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToastNotifier3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScheduledToastNotificationShowing(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotifier3", "ScheduledToastNotificationShowing", 6usize, |this| ((**(this as *const *const IToastNotifier3_Vtbl)).ScheduledToastNotificationShowing)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveScheduledToastNotificationShowing(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToastNotifier3", "RemoveScheduledToastNotificationShowing", 7usize, |this| ((**(this as *const *const IToastNotifier3_Vtbl)).RemoveScheduledToastNotificationShowing)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ScheduledToastNotificationShowing,
+            RemoveScheduledToastNotificationShowing,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToastNotifier3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel")]
+impl windows_core::imp::ProxyVtable for IUserNotification_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Notification(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotification", "Notification", 6usize, |this| ((**(this as *const *const IUserNotification_Vtbl)).Notification)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn AppInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotification", "AppInfo", 7usize, |this| ((**(this as *const *const IUserNotification_Vtbl)).AppInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotification", "Id", 8usize, |this| ((**(this as *const *const IUserNotification_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreationTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotification", "CreationTime", 9usize, |this| ((**(this as *const *const IUserNotification_Vtbl)).CreationTime)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Notification,
+            #[cfg(feature = "ApplicationModel")]
+            AppInfo,
+            #[cfg(not(feature = "ApplicationModel"))]
+            AppInfo: 0,
+            Id,
+            CreationTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserNotification as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserNotificationChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeKind(this: *mut core::ffi::c_void, result__: *mut UserNotificationChangedKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotificationChangedEventArgs", "ChangeKind", 6usize, |this| ((**(this as *const *const IUserNotificationChangedEventArgs_Vtbl)).ChangeKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserNotificationId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserNotificationChangedEventArgs", "UserNotificationId", 7usize, |this| ((**(this as *const *const IUserNotificationChangedEventArgs_Vtbl)).UserNotificationId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ChangeKind, UserNotificationId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserNotificationChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
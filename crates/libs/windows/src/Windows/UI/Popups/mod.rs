@@ -444,6 +444,7 @@ impl MessageDialogOptions {
 impl windows_core::TypeKind for MessageDialogOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MessageDialogOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MessageDialogOptions").field(&self.0).finish()
@@ -498,6 +499,7 @@ impl Placement {
 impl windows_core::TypeKind for Placement {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Placement {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Placement").field(&self.0).finish()
@@ -568,3 +570,5 @@ pub struct UICommandInvokedHandler_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
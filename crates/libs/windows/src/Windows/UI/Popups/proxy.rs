@@ -0,0 +1,156 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IMessageDialog_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageDialog", "Title", 6usize, |this| ((**(this as *const *const IMessageDialog_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageDialog", "SetTitle", 7usize, |this| ((**(this as *const *const IMessageDialog_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Commands(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageDialog", "Commands", 8usize, |this| ((**(this as *const *const IMessageDialog_Vtbl)).Commands)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultCommandIndex(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageDialog", "DefaultCommandIndex", 9usize, |this| ((**(this as *const *const IMessageDialog_Vtbl)).DefaultCommandIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultCommandIndex(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageDialog", "SetDefaultCommandIndex", 10usize, |this| ((**(this as *const *const IMessageDialog_Vtbl)).SetDefaultCommandIndex)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelCommandIndex(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageDialog", "CancelCommandIndex", 11usize, |this| ((**(this as *const *const IMessageDialog_Vtbl)).CancelCommandIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCancelCommandIndex(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageDialog", "SetCancelCommandIndex", 12usize, |this| ((**(this as *const *const IMessageDialog_Vtbl)).SetCancelCommandIndex)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageDialog", "Content", 13usize, |this| ((**(this as *const *const IMessageDialog_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContent(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageDialog", "SetContent", 14usize, |this| ((**(this as *const *const IMessageDialog_Vtbl)).SetContent)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageDialog", "ShowAsync", 15usize, |this| ((**(this as *const *const IMessageDialog_Vtbl)).ShowAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Options(this: *mut core::ffi::c_void, result__: *mut MessageDialogOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageDialog", "Options", 16usize, |this| ((**(this as *const *const IMessageDialog_Vtbl)).Options)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOptions(this: *mut core::ffi::c_void, value: MessageDialogOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageDialog", "SetOptions", 17usize, |this| ((**(this as *const *const IMessageDialog_Vtbl)).SetOptions)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Title,
+            SetTitle,
+            #[cfg(feature = "Foundation_Collections")]
+            Commands,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Commands: 0,
+            DefaultCommandIndex,
+            SetDefaultCommandIndex,
+            CancelCommandIndex,
+            SetCancelCommandIndex,
+            Content,
+            SetContent,
+            ShowAsync,
+            Options,
+            SetOptions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMessageDialog as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMessageDialogFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, content: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageDialogFactory", "Create", 6usize, |this| ((**(this as *const *const IMessageDialogFactory_Vtbl)).Create)(this, content, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithTitle(this: *mut core::ffi::c_void, content: core::mem::MaybeUninit<windows_core::HSTRING>, title: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageDialogFactory", "CreateWithTitle", 7usize, |this| ((**(this as *const *const IMessageDialogFactory_Vtbl)).CreateWithTitle)(this, content, title, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithTitle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMessageDialogFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IPopupMenu_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Commands(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPopupMenu", "Commands", 6usize, |this| ((**(this as *const *const IPopupMenu_Vtbl)).Commands)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAsync(this: *mut core::ffi::c_void, invocationpoint: super::super::Foundation::Point, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPopupMenu", "ShowAsync", 7usize, |this| ((**(this as *const *const IPopupMenu_Vtbl)).ShowAsync)(this, invocationpoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAsyncWithRect(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPopupMenu", "ShowAsyncWithRect", 8usize, |this| ((**(this as *const *const IPopupMenu_Vtbl)).ShowAsyncWithRect)(this, selection, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAsyncWithRectAndPlacement(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: Placement, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPopupMenu", "ShowAsyncWithRectAndPlacement", 9usize, |this| ((**(this as *const *const IPopupMenu_Vtbl)).ShowAsyncWithRectAndPlacement)(this, selection, preferredplacement, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Commands,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Commands: 0,
+            ShowAsync,
+            ShowAsyncWithRect,
+            ShowAsyncWithRectAndPlacement,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPopupMenu as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUICommand_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICommand", "Label", 6usize, |this| ((**(this as *const *const IUICommand_Vtbl)).Label)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICommand", "SetLabel", 7usize, |this| ((**(this as *const *const IUICommand_Vtbl)).SetLabel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invoked(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICommand", "Invoked", 8usize, |this| ((**(this as *const *const IUICommand_Vtbl)).Invoked)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInvoked(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICommand", "SetInvoked", 9usize, |this| ((**(this as *const *const IUICommand_Vtbl)).SetInvoked)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICommand", "Id", 10usize, |this| ((**(this as *const *const IUICommand_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICommand", "SetId", 11usize, |this| ((**(this as *const *const IUICommand_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Label, SetLabel, Invoked, SetInvoked, Id, SetId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUICommand as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUICommandFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, label: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICommandFactory", "Create", 6usize, |this| ((**(this as *const *const IUICommandFactory_Vtbl)).Create)(this, label, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithHandler(this: *mut core::ffi::c_void, label: core::mem::MaybeUninit<windows_core::HSTRING>, action: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICommandFactory", "CreateWithHandler", 7usize, |this| ((**(this as *const *const IUICommandFactory_Vtbl)).CreateWithHandler)(this, label, action, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithHandlerAndId(this: *mut core::ffi::c_void, label: core::mem::MaybeUninit<windows_core::HSTRING>, action: *mut core::ffi::c_void, commandid: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICommandFactory", "CreateWithHandlerAndId", 8usize, |this| ((**(this as *const *const IUICommandFactory_Vtbl)).CreateWithHandlerAndId)(this, label, action, commandid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, CreateWithHandler, CreateWithHandlerAndId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUICommandFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1311,6 +1311,7 @@ impl SecurityAppKind {
 impl windows_core::TypeKind for SecurityAppKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SecurityAppKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SecurityAppKind").field(&self.0).finish()
@@ -1329,6 +1330,7 @@ impl SecurityAppState {
 impl windows_core::TypeKind for SecurityAppState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SecurityAppState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SecurityAppState").field(&self.0).finish()
@@ -1349,6 +1351,7 @@ impl SecurityAppSubstatus {
 impl windows_core::TypeKind for SecurityAppSubstatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SecurityAppSubstatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SecurityAppSubstatus").field(&self.0).finish()
@@ -1368,6 +1371,7 @@ impl ShareWindowCommand {
 impl windows_core::TypeKind for ShareWindowCommand {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ShareWindowCommand {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ShareWindowCommand").field(&self.0).finish()
@@ -1378,3 +1382,5 @@ impl windows_core::RuntimeType for ShareWindowCommand {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
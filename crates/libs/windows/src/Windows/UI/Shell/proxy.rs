@@ -0,0 +1,556 @@
+impl windows_core::imp::ProxyVtable for IAdaptiveCard_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ToJson(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveCard", "ToJson", 6usize, |this| ((**(this as *const *const IAdaptiveCard_Vtbl)).ToJson)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ToJson }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveCard as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdaptiveCardBuilderStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAdaptiveCardFromJson(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdaptiveCardBuilderStatics", "CreateAdaptiveCardFromJson", 6usize, |this| ((**(this as *const *const IAdaptiveCardBuilderStatics_Vtbl)).CreateAdaptiveCardFromJson)(this, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateAdaptiveCardFromJson }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdaptiveCardBuilderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFocusSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSession", "Id", 6usize, |this| ((**(this as *const *const IFocusSession_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSession", "End", 7usize, |this| ((**(this as *const *const IFocusSession_Vtbl)).End)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, End }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFocusSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFocusSessionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsFocusActive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSessionManager", "IsFocusActive", 6usize, |this| ((**(this as *const *const IFocusSessionManager_Vtbl)).IsFocusActive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSession(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSessionManager", "GetSession", 7usize, |this| ((**(this as *const *const IFocusSessionManager_Vtbl)).GetSession)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryStartFocusSession(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSessionManager", "TryStartFocusSession", 8usize, |this| ((**(this as *const *const IFocusSessionManager_Vtbl)).TryStartFocusSession)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryStartFocusSession2(this: *mut core::ffi::c_void, endtime: super::super::Foundation::DateTime, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSessionManager", "TryStartFocusSession2", 9usize, |this| ((**(this as *const *const IFocusSessionManager_Vtbl)).TryStartFocusSession2)(this, endtime, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeactivateFocus(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSessionManager", "DeactivateFocus", 10usize, |this| ((**(this as *const *const IFocusSessionManager_Vtbl)).DeactivateFocus)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFocusActiveChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSessionManager", "IsFocusActiveChanged", 11usize, |this| ((**(this as *const *const IFocusSessionManager_Vtbl)).IsFocusActiveChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveIsFocusActiveChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSessionManager", "RemoveIsFocusActiveChanged", 12usize, |this| ((**(this as *const *const IFocusSessionManager_Vtbl)).RemoveIsFocusActiveChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsFocusActive,
+            GetSession,
+            TryStartFocusSession,
+            TryStartFocusSession2,
+            DeactivateFocus,
+            IsFocusActiveChanged,
+            RemoveIsFocusActiveChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFocusSessionManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFocusSessionManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSessionManagerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IFocusSessionManagerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFocusSessionManagerStatics", "IsSupported", 7usize, |this| ((**(this as *const *const IFocusSessionManagerStatics_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault, IsSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFocusSessionManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISecurityAppManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void, kind: SecurityAppKind, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, detailsuri: *mut core::ffi::c_void, registerperuser: bool, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityAppManager", "Register", 6usize, |this| ((**(this as *const *const ISecurityAppManager_Vtbl)).Register)(this, kind, displayname, detailsuri, registerperuser, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unregister(this: *mut core::ffi::c_void, kind: SecurityAppKind, guidregistration: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityAppManager", "Unregister", 7usize, |this| ((**(this as *const *const ISecurityAppManager_Vtbl)).Unregister)(this, kind, guidregistration), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateState(this: *mut core::ffi::c_void, kind: SecurityAppKind, guidregistration: windows_core::GUID, state: SecurityAppState, substatus: SecurityAppSubstatus, detailsuri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityAppManager", "UpdateState", 8usize, |this| ((**(this as *const *const ISecurityAppManager_Vtbl)).UpdateState)(this, kind, guidregistration, state, substatus, detailsuri), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Register, Unregister, UpdateState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecurityAppManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IShareWindowCommandEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WindowId(this: *mut core::ffi::c_void, result__: *mut super::WindowId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareWindowCommandEventArgs", "WindowId", 6usize, |this| ((**(this as *const *const IShareWindowCommandEventArgs_Vtbl)).WindowId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Command(this: *mut core::ffi::c_void, result__: *mut ShareWindowCommand) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareWindowCommandEventArgs", "Command", 7usize, |this| ((**(this as *const *const IShareWindowCommandEventArgs_Vtbl)).Command)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCommand(this: *mut core::ffi::c_void, value: ShareWindowCommand) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareWindowCommandEventArgs", "SetCommand", 8usize, |this| ((**(this as *const *const IShareWindowCommandEventArgs_Vtbl)).SetCommand)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WindowId, Command, SetCommand }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareWindowCommandEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IShareWindowCommandSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareWindowCommandSource", "Start", 6usize, |this| ((**(this as *const *const IShareWindowCommandSource_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareWindowCommandSource", "Stop", 7usize, |this| ((**(this as *const *const IShareWindowCommandSource_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportCommandChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareWindowCommandSource", "ReportCommandChanged", 8usize, |this| ((**(this as *const *const IShareWindowCommandSource_Vtbl)).ReportCommandChanged)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommandRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareWindowCommandSource", "CommandRequested", 9usize, |this| ((**(this as *const *const IShareWindowCommandSource_Vtbl)).CommandRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCommandRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareWindowCommandSource", "RemoveCommandRequested", 10usize, |this| ((**(this as *const *const IShareWindowCommandSource_Vtbl)).RemoveCommandRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommandInvoked(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareWindowCommandSource", "CommandInvoked", 11usize, |this| ((**(this as *const *const IShareWindowCommandSource_Vtbl)).CommandInvoked)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCommandInvoked(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareWindowCommandSource", "RemoveCommandInvoked", 12usize, |this| ((**(this as *const *const IShareWindowCommandSource_Vtbl)).RemoveCommandInvoked)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Start,
+            Stop,
+            ReportCommandChanged,
+            CommandRequested,
+            RemoveCommandRequested,
+            CommandInvoked,
+            RemoveCommandInvoked,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareWindowCommandSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IShareWindowCommandSourceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareWindowCommandSourceStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IShareWindowCommandSourceStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareWindowCommandSourceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Core")]
+impl windows_core::imp::ProxyVtable for ITaskbarManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskbarManager", "IsSupported", 6usize, |this| ((**(this as *const *const ITaskbarManager_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPinningAllowed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskbarManager", "IsPinningAllowed", 7usize, |this| ((**(this as *const *const ITaskbarManager_Vtbl)).IsPinningAllowed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCurrentAppPinnedAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskbarManager", "IsCurrentAppPinnedAsync", 8usize, |this| ((**(this as *const *const ITaskbarManager_Vtbl)).IsCurrentAppPinnedAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Core")]
+        unsafe extern "system" fn IsAppListEntryPinnedAsync(this: *mut core::ffi::c_void, applistentry: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskbarManager", "IsAppListEntryPinnedAsync", 9usize, |this| ((**(this as *const *const ITaskbarManager_Vtbl)).IsAppListEntryPinnedAsync)(this, applistentry, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPinCurrentAppAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskbarManager", "RequestPinCurrentAppAsync", 10usize, |this| ((**(this as *const *const ITaskbarManager_Vtbl)).RequestPinCurrentAppAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Core")]
+        unsafe extern "system" fn RequestPinAppListEntryAsync(this: *mut core::ffi::c_void, applistentry: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskbarManager", "RequestPinAppListEntryAsync", 11usize, |this| ((**(this as *const *const ITaskbarManager_Vtbl)).RequestPinAppListEntryAsync)(this, applistentry, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupported,
+            IsPinningAllowed,
+            IsCurrentAppPinnedAsync,
+            #[cfg(feature = "ApplicationModel_Core")]
+            IsAppListEntryPinnedAsync,
+            #[cfg(not(feature = "ApplicationModel_Core"))]
+            IsAppListEntryPinnedAsync: 0,
+            RequestPinCurrentAppAsync,
+            #[cfg(feature = "ApplicationModel_Core")]
+            RequestPinAppListEntryAsync,
+            #[cfg(not(feature = "ApplicationModel_Core"))]
+            RequestPinAppListEntryAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskbarManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Core", feature = "UI_StartScreen"))]
+impl windows_core::imp::ProxyVtable for ITaskbarManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSecondaryTilePinnedAsync(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskbarManager2", "IsSecondaryTilePinnedAsync", 6usize, |this| ((**(this as *const *const ITaskbarManager2_Vtbl)).IsSecondaryTilePinnedAsync)(this, tileid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_StartScreen")]
+        unsafe extern "system" fn RequestPinSecondaryTileAsync(this: *mut core::ffi::c_void, secondarytile: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskbarManager2", "RequestPinSecondaryTileAsync", 7usize, |this| ((**(this as *const *const ITaskbarManager2_Vtbl)).RequestPinSecondaryTileAsync)(this, secondarytile, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryUnpinSecondaryTileAsync(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskbarManager2", "TryUnpinSecondaryTileAsync", 8usize, |this| ((**(this as *const *const ITaskbarManager2_Vtbl)).TryUnpinSecondaryTileAsync)(this, tileid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSecondaryTilePinnedAsync,
+            #[cfg(feature = "UI_StartScreen")]
+            RequestPinSecondaryTileAsync,
+            #[cfg(not(feature = "UI_StartScreen"))]
+            RequestPinSecondaryTileAsync: 0,
+            TryUnpinSecondaryTileAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskbarManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITaskbarManagerDesktopAppSupportStatics_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskbarManagerDesktopAppSupportStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITaskbarManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskbarManagerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const ITaskbarManagerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskbarManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowTab_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Tag(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTab", "Tag", 6usize, |this| ((**(this as *const *const IWindowTab_Vtbl)).Tag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTab", "SetTag", 7usize, |this| ((**(this as *const *const IWindowTab_Vtbl)).SetTag)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTab", "Title", 8usize, |this| ((**(this as *const *const IWindowTab_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTab", "SetTitle", 9usize, |this| ((**(this as *const *const IWindowTab_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Icon(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTab", "Icon", 10usize, |this| ((**(this as *const *const IWindowTab_Vtbl)).Icon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIcon(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTab", "SetIcon", 11usize, |this| ((**(this as *const *const IWindowTab_Vtbl)).SetIcon)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TreatAsSecondaryTileId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTab", "TreatAsSecondaryTileId", 12usize, |this| ((**(this as *const *const IWindowTab_Vtbl)).TreatAsSecondaryTileId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTreatAsSecondaryTileId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTab", "SetTreatAsSecondaryTileId", 13usize, |this| ((**(this as *const *const IWindowTab_Vtbl)).SetTreatAsSecondaryTileId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Group(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTab", "Group", 14usize, |this| ((**(this as *const *const IWindowTab_Vtbl)).Group)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroup(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTab", "SetGroup", 15usize, |this| ((**(this as *const *const IWindowTab_Vtbl)).SetGroup)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportThumbnailAvailable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTab", "ReportThumbnailAvailable", 16usize, |this| ((**(this as *const *const IWindowTab_Vtbl)).ReportThumbnailAvailable)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Tag,
+            SetTag,
+            Title,
+            SetTitle,
+            Icon,
+            SetIcon,
+            TreatAsSecondaryTileId,
+            SetTreatAsSecondaryTileId,
+            Group,
+            SetGroup,
+            ReportThumbnailAvailable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowTab as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowTabCloseRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Tab(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabCloseRequestedEventArgs", "Tab", 6usize, |this| ((**(this as *const *const IWindowTabCloseRequestedEventArgs_Vtbl)).Tab)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Tab }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowTabCloseRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowTabCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MoveTab(this: *mut core::ffi::c_void, tab: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabCollection", "MoveTab", 6usize, |this| ((**(this as *const *const IWindowTabCollection_Vtbl)).MoveTab)(this, tab, index), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MoveTab }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowTabCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowTabGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabGroup", "Title", 6usize, |this| ((**(this as *const *const IWindowTabGroup_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabGroup", "SetTitle", 7usize, |this| ((**(this as *const *const IWindowTabGroup_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Icon(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabGroup", "Icon", 8usize, |this| ((**(this as *const *const IWindowTabGroup_Vtbl)).Icon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIcon(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabGroup", "SetIcon", 9usize, |this| ((**(this as *const *const IWindowTabGroup_Vtbl)).SetIcon)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Title, SetTitle, Icon, SetIcon }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowTabGroup as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowTabIcon_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowTabIcon as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IWindowTabIconStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromFontGlyph(this: *mut core::ffi::c_void, glyph: core::mem::MaybeUninit<windows_core::HSTRING>, fontfamily: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabIconStatics", "CreateFromFontGlyph", 6usize, |this| ((**(this as *const *const IWindowTabIconStatics_Vtbl)).CreateFromFontGlyph)(this, glyph, fontfamily, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromFontGlyphWithUri(this: *mut core::ffi::c_void, glyph: core::mem::MaybeUninit<windows_core::HSTRING>, fontfamily: core::mem::MaybeUninit<windows_core::HSTRING>, fonturi: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabIconStatics", "CreateFromFontGlyphWithUri", 7usize, |this| ((**(this as *const *const IWindowTabIconStatics_Vtbl)).CreateFromFontGlyphWithUri)(this, glyph, fontfamily, fonturi, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromImage(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabIconStatics", "CreateFromImage", 8usize, |this| ((**(this as *const *const IWindowTabIconStatics_Vtbl)).CreateFromImage)(this, image, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromFontGlyph,
+            CreateFromFontGlyphWithUri,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromImage,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromImage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowTabIconStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowTabManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Tabs(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabManager", "Tabs", 6usize, |this| ((**(this as *const *const IWindowTabManager_Vtbl)).Tabs)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActiveTab(this: *mut core::ffi::c_void, tab: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabManager", "SetActiveTab", 7usize, |this| ((**(this as *const *const IWindowTabManager_Vtbl)).SetActiveTab)(this, tab), |result| Some(*result))
+        }
+        unsafe extern "system" fn TabSwitchRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabManager", "TabSwitchRequested", 8usize, |this| ((**(this as *const *const IWindowTabManager_Vtbl)).TabSwitchRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTabSwitchRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabManager", "RemoveTabSwitchRequested", 9usize, |this| ((**(this as *const *const IWindowTabManager_Vtbl)).RemoveTabSwitchRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TabCloseRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabManager", "TabCloseRequested", 10usize, |this| ((**(this as *const *const IWindowTabManager_Vtbl)).TabCloseRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTabCloseRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabManager", "RemoveTabCloseRequested", 11usize, |this| ((**(this as *const *const IWindowTabManager_Vtbl)).RemoveTabCloseRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TabTearOutRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabManager", "TabTearOutRequested", 12usize, |this| ((**(this as *const *const IWindowTabManager_Vtbl)).TabTearOutRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTabTearOutRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabManager", "RemoveTabTearOutRequested", 13usize, |this| ((**(this as *const *const IWindowTabManager_Vtbl)).RemoveTabTearOutRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TabThumbnailRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabManager", "TabThumbnailRequested", 14usize, |this| ((**(this as *const *const IWindowTabManager_Vtbl)).TabThumbnailRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTabThumbnailRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabManager", "RemoveTabThumbnailRequested", 15usize, |this| ((**(this as *const *const IWindowTabManager_Vtbl)).RemoveTabThumbnailRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Tabs,
+            SetActiveTab,
+            TabSwitchRequested,
+            RemoveTabSwitchRequested,
+            TabCloseRequested,
+            RemoveTabCloseRequested,
+            TabTearOutRequested,
+            RemoveTabTearOutRequested,
+            TabThumbnailRequested,
+            RemoveTabThumbnailRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowTabManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowTabManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, id: super::WindowId, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabManagerStatics", "GetForWindow", 6usize, |this| ((**(this as *const *const IWindowTabManagerStatics_Vtbl)).GetForWindow)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabManagerStatics", "IsSupported", 7usize, |this| ((**(this as *const *const IWindowTabManagerStatics_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTabTearOutSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabManagerStatics", "IsTabTearOutSupported", 8usize, |this| ((**(this as *const *const IWindowTabManagerStatics_Vtbl)).IsTabTearOutSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForWindow, IsSupported, IsTabTearOutSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowTabManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowTabSwitchRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Tab(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabSwitchRequestedEventArgs", "Tab", 6usize, |this| ((**(this as *const *const IWindowTabSwitchRequestedEventArgs_Vtbl)).Tab)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Tab }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowTabSwitchRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowTabTearOutRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Tab(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabTearOutRequestedEventArgs", "Tab", 6usize, |this| ((**(this as *const *const IWindowTabTearOutRequestedEventArgs_Vtbl)).Tab)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WindowId(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabTearOutRequestedEventArgs", "WindowId", 7usize, |this| ((**(this as *const *const IWindowTabTearOutRequestedEventArgs_Vtbl)).WindowId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWindowId(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabTearOutRequestedEventArgs", "SetWindowId", 8usize, |this| ((**(this as *const *const IWindowTabTearOutRequestedEventArgs_Vtbl)).SetWindowId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabTearOutRequestedEventArgs", "GetDeferral", 9usize, |this| ((**(this as *const *const IWindowTabTearOutRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Tab, WindowId, SetWindowId, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowTabTearOutRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Graphics_Imaging", feature = "Storage_Streams"))]
+impl windows_core::imp::ProxyVtable for IWindowTabThumbnailRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Tab(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabThumbnailRequestedEventArgs", "Tab", 6usize, |this| ((**(this as *const *const IWindowTabThumbnailRequestedEventArgs_Vtbl)).Tab)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Imaging")]
+        unsafe extern "system" fn RequestedSize(this: *mut core::ffi::c_void, result__: *mut super::super::Graphics::Imaging::BitmapSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabThumbnailRequestedEventArgs", "RequestedSize", 7usize, |this| ((**(this as *const *const IWindowTabThumbnailRequestedEventArgs_Vtbl)).RequestedSize)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Image(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabThumbnailRequestedEventArgs", "Image", 8usize, |this| ((**(this as *const *const IWindowTabThumbnailRequestedEventArgs_Vtbl)).Image)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetImage(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabThumbnailRequestedEventArgs", "SetImage", 9usize, |this| ((**(this as *const *const IWindowTabThumbnailRequestedEventArgs_Vtbl)).SetImage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabThumbnailRequestedEventArgs", "GetDeferral", 10usize, |this| ((**(this as *const *const IWindowTabThumbnailRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCompositedOnWindow(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowTabThumbnailRequestedEventArgs", "IsCompositedOnWindow", 11usize, |this| ((**(this as *const *const IWindowTabThumbnailRequestedEventArgs_Vtbl)).IsCompositedOnWindow)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Tab,
+            #[cfg(feature = "Graphics_Imaging")]
+            RequestedSize,
+            #[cfg(not(feature = "Graphics_Imaging"))]
+            RequestedSize: 0,
+            #[cfg(feature = "Storage_Streams")]
+            Image,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Image: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetImage,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetImage: 0,
+            GetDeferral,
+            IsCompositedOnWindow,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowTabThumbnailRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1352,6 +1352,7 @@ impl ForegroundText {
 impl windows_core::TypeKind for ForegroundText {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ForegroundText {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ForegroundText").field(&self.0).finish()
@@ -1370,6 +1371,7 @@ impl JumpListItemKind {
 impl windows_core::TypeKind for JumpListItemKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for JumpListItemKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("JumpListItemKind").field(&self.0).finish()
@@ -1389,6 +1391,7 @@ impl JumpListSystemGroupKind {
 impl windows_core::TypeKind for JumpListSystemGroupKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for JumpListSystemGroupKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("JumpListSystemGroupKind").field(&self.0).finish()
@@ -1407,6 +1410,7 @@ impl TileMixedRealityModelActivationBehavior {
 impl windows_core::TypeKind for TileMixedRealityModelActivationBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TileMixedRealityModelActivationBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TileMixedRealityModelActivationBehavior").field(&self.0).finish()
@@ -1427,6 +1431,7 @@ impl TileOptions {
 impl windows_core::TypeKind for TileOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TileOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TileOptions").field(&self.0).finish()
@@ -1484,6 +1489,7 @@ impl TileSize {
 impl windows_core::TypeKind for TileSize {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TileSize {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TileSize").field(&self.0).finish()
@@ -1492,3 +1498,5 @@ impl core::fmt::Debug for TileSize {
 impl windows_core::RuntimeType for TileSize {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.StartScreen.TileSize;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
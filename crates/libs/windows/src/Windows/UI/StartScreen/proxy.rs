@@ -0,0 +1,788 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IJumpList_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Items(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpList", "Items", 6usize, |this| ((**(this as *const *const IJumpList_Vtbl)).Items)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemGroupKind(this: *mut core::ffi::c_void, result__: *mut JumpListSystemGroupKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpList", "SystemGroupKind", 7usize, |this| ((**(this as *const *const IJumpList_Vtbl)).SystemGroupKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSystemGroupKind(this: *mut core::ffi::c_void, value: JumpListSystemGroupKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpList", "SetSystemGroupKind", 8usize, |this| ((**(this as *const *const IJumpList_Vtbl)).SetSystemGroupKind)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpList", "SaveAsync", 9usize, |this| ((**(this as *const *const IJumpList_Vtbl)).SaveAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Items,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Items: 0,
+            SystemGroupKind,
+            SetSystemGroupKind,
+            SaveAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJumpList as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJumpListItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut JumpListItemKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpListItem", "Kind", 6usize, |this| ((**(this as *const *const IJumpListItem_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Arguments(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpListItem", "Arguments", 7usize, |this| ((**(this as *const *const IJumpListItem_Vtbl)).Arguments)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovedByUser(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpListItem", "RemovedByUser", 8usize, |this| ((**(this as *const *const IJumpListItem_Vtbl)).RemovedByUser)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpListItem", "Description", 9usize, |this| ((**(this as *const *const IJumpListItem_Vtbl)).Description)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpListItem", "SetDescription", 10usize, |this| ((**(this as *const *const IJumpListItem_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpListItem", "DisplayName", 11usize, |this| ((**(this as *const *const IJumpListItem_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpListItem", "SetDisplayName", 12usize, |this| ((**(this as *const *const IJumpListItem_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GroupName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpListItem", "GroupName", 13usize, |this| ((**(this as *const *const IJumpListItem_Vtbl)).GroupName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroupName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpListItem", "SetGroupName", 14usize, |this| ((**(this as *const *const IJumpListItem_Vtbl)).SetGroupName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Logo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpListItem", "Logo", 15usize, |this| ((**(this as *const *const IJumpListItem_Vtbl)).Logo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpListItem", "SetLogo", 16usize, |this| ((**(this as *const *const IJumpListItem_Vtbl)).SetLogo)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Kind,
+            Arguments,
+            RemovedByUser,
+            Description,
+            SetDescription,
+            DisplayName,
+            SetDisplayName,
+            GroupName,
+            SetGroupName,
+            Logo,
+            SetLogo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJumpListItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJumpListItemStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithArguments(this: *mut core::ffi::c_void, arguments: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpListItemStatics", "CreateWithArguments", 6usize, |this| ((**(this as *const *const IJumpListItemStatics_Vtbl)).CreateWithArguments)(this, arguments, displayname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSeparator(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpListItemStatics", "CreateSeparator", 7usize, |this| ((**(this as *const *const IJumpListItemStatics_Vtbl)).CreateSeparator)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithArguments, CreateSeparator }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJumpListItemStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJumpListStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadCurrentAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpListStatics", "LoadCurrentAsync", 6usize, |this| ((**(this as *const *const IJumpListStatics_Vtbl)).LoadCurrentAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJumpListStatics", "IsSupported", 7usize, |this| ((**(this as *const *const IJumpListStatics_Vtbl)).IsSupported)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LoadCurrentAsync, IsSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJumpListStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Popups")]
+impl windows_core::imp::ProxyVtable for ISecondaryTile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTileId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "SetTileId", 6usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).SetTileId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TileId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "TileId", 7usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).TileId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetArguments(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "SetArguments", 8usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).SetArguments)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Arguments(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "Arguments", 9usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).Arguments)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetShortName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "SetShortName", 10usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).SetShortName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ShortName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "ShortName", 11usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).ShortName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "SetDisplayName", 12usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).SetDisplayName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "DisplayName", 13usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).DisplayName)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetLogo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "SetLogo", 14usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).SetLogo)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Logo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "Logo", 15usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).Logo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetSmallLogo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "SetSmallLogo", 16usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).SetSmallLogo)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SmallLogo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "SmallLogo", 17usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).SmallLogo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetWideLogo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "SetWideLogo", 18usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).SetWideLogo)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn WideLogo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "WideLogo", 19usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).WideLogo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLockScreenBadgeLogo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "SetLockScreenBadgeLogo", 20usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).SetLockScreenBadgeLogo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockScreenBadgeLogo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "LockScreenBadgeLogo", 21usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).LockScreenBadgeLogo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLockScreenDisplayBadgeAndTileText(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "SetLockScreenDisplayBadgeAndTileText", 22usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).SetLockScreenDisplayBadgeAndTileText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockScreenDisplayBadgeAndTileText(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "LockScreenDisplayBadgeAndTileText", 23usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).LockScreenDisplayBadgeAndTileText)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetTileOptions(this: *mut core::ffi::c_void, value: TileOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "SetTileOptions", 24usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).SetTileOptions)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TileOptions(this: *mut core::ffi::c_void, result__: *mut TileOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "TileOptions", 25usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).TileOptions)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetForegroundText(this: *mut core::ffi::c_void, value: ForegroundText) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "SetForegroundText", 26usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).SetForegroundText)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn ForegroundText(this: *mut core::ffi::c_void, result__: *mut ForegroundText) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "ForegroundText", 27usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).ForegroundText)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetBackgroundColor(this: *mut core::ffi::c_void, value: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "SetBackgroundColor", 28usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).SetBackgroundColor)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn BackgroundColor(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "BackgroundColor", 29usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).BackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestCreateAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "RequestCreateAsync", 30usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).RequestCreateAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestCreateAsyncWithPoint(this: *mut core::ffi::c_void, invocationpoint: super::super::Foundation::Point, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "RequestCreateAsyncWithPoint", 31usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).RequestCreateAsyncWithPoint)(this, invocationpoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestCreateAsyncWithRect(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "RequestCreateAsyncWithRect", 32usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).RequestCreateAsyncWithRect)(this, selection, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn RequestCreateAsyncWithRectAndPlacement(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::Popups::Placement, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "RequestCreateAsyncWithRectAndPlacement", 33usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).RequestCreateAsyncWithRectAndPlacement)(this, selection, preferredplacement, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestDeleteAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "RequestDeleteAsync", 34usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).RequestDeleteAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestDeleteAsyncWithPoint(this: *mut core::ffi::c_void, invocationpoint: super::super::Foundation::Point, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "RequestDeleteAsyncWithPoint", 35usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).RequestDeleteAsyncWithPoint)(this, invocationpoint, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestDeleteAsyncWithRect(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "RequestDeleteAsyncWithRect", 36usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).RequestDeleteAsyncWithRect)(this, selection, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn RequestDeleteAsyncWithRectAndPlacement(this: *mut core::ffi::c_void, selection: super::super::Foundation::Rect, preferredplacement: super::Popups::Placement, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "RequestDeleteAsyncWithRectAndPlacement", 37usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).RequestDeleteAsyncWithRectAndPlacement)(this, selection, preferredplacement, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile", "UpdateAsync", 38usize, |this| ((**(this as *const *const ISecondaryTile_Vtbl)).UpdateAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetTileId,
+            TileId,
+            SetArguments,
+            Arguments,
+            #[cfg(feature = "deprecated")]
+            SetShortName,
+            #[cfg(not(feature = "deprecated"))]
+            SetShortName: 0,
+            #[cfg(feature = "deprecated")]
+            ShortName,
+            #[cfg(not(feature = "deprecated"))]
+            ShortName: 0,
+            SetDisplayName,
+            DisplayName,
+            #[cfg(feature = "deprecated")]
+            SetLogo,
+            #[cfg(not(feature = "deprecated"))]
+            SetLogo: 0,
+            #[cfg(feature = "deprecated")]
+            Logo,
+            #[cfg(not(feature = "deprecated"))]
+            Logo: 0,
+            #[cfg(feature = "deprecated")]
+            SetSmallLogo,
+            #[cfg(not(feature = "deprecated"))]
+            SetSmallLogo: 0,
+            #[cfg(feature = "deprecated")]
+            SmallLogo,
+            #[cfg(not(feature = "deprecated"))]
+            SmallLogo: 0,
+            #[cfg(feature = "deprecated")]
+            SetWideLogo,
+            #[cfg(not(feature = "deprecated"))]
+            SetWideLogo: 0,
+            #[cfg(feature = "deprecated")]
+            WideLogo,
+            #[cfg(not(feature = "deprecated"))]
+            WideLogo: 0,
+            SetLockScreenBadgeLogo,
+            LockScreenBadgeLogo,
+            SetLockScreenDisplayBadgeAndTileText,
+            LockScreenDisplayBadgeAndTileText,
+            #[cfg(feature = "deprecated")]
+            SetTileOptions,
+            #[cfg(not(feature = "deprecated"))]
+            SetTileOptions: 0,
+            #[cfg(feature = "deprecated")]
+            TileOptions,
+            #[cfg(not(feature = "deprecated"))]
+            TileOptions: 0,
+            #[cfg(feature = "deprecated")]
+            SetForegroundText,
+            #[cfg(not(feature = "deprecated"))]
+            SetForegroundText: 0,
+            #[cfg(feature = "deprecated")]
+            ForegroundText,
+            #[cfg(not(feature = "deprecated"))]
+            ForegroundText: 0,
+            #[cfg(feature = "deprecated")]
+            SetBackgroundColor,
+            #[cfg(not(feature = "deprecated"))]
+            SetBackgroundColor: 0,
+            #[cfg(feature = "deprecated")]
+            BackgroundColor,
+            #[cfg(not(feature = "deprecated"))]
+            BackgroundColor: 0,
+            RequestCreateAsync,
+            RequestCreateAsyncWithPoint,
+            RequestCreateAsyncWithRect,
+            #[cfg(feature = "UI_Popups")]
+            RequestCreateAsyncWithRectAndPlacement,
+            #[cfg(not(feature = "UI_Popups"))]
+            RequestCreateAsyncWithRectAndPlacement: 0,
+            RequestDeleteAsync,
+            RequestDeleteAsyncWithPoint,
+            RequestDeleteAsyncWithRect,
+            #[cfg(feature = "UI_Popups")]
+            RequestDeleteAsyncWithRectAndPlacement,
+            #[cfg(not(feature = "UI_Popups"))]
+            RequestDeleteAsyncWithRectAndPlacement: 0,
+            UpdateAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecondaryTile as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Popups")]
+impl windows_core::imp::ProxyVtable for ISecondaryTile2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPhoneticName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile2", "SetPhoneticName", 6usize, |this| ((**(this as *const *const ISecondaryTile2_Vtbl)).SetPhoneticName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhoneticName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile2", "PhoneticName", 7usize, |this| ((**(this as *const *const ISecondaryTile2_Vtbl)).PhoneticName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VisualElements(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile2", "VisualElements", 8usize, |this| ((**(this as *const *const ISecondaryTile2_Vtbl)).VisualElements)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoamingEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile2", "SetRoamingEnabled", 9usize, |this| ((**(this as *const *const ISecondaryTile2_Vtbl)).SetRoamingEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoamingEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile2", "RoamingEnabled", 10usize, |this| ((**(this as *const *const ISecondaryTile2_Vtbl)).RoamingEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VisualElementsRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile2", "VisualElementsRequested", 11usize, |this| ((**(this as *const *const ISecondaryTile2_Vtbl)).VisualElementsRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVisualElementsRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTile2", "RemoveVisualElementsRequested", 12usize, |this| ((**(this as *const *const ISecondaryTile2_Vtbl)).RemoveVisualElementsRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPhoneticName,
+            PhoneticName,
+            VisualElements,
+            SetRoamingEnabled,
+            RoamingEnabled,
+            VisualElementsRequested,
+            RemoveVisualElementsRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecondaryTile2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISecondaryTileFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreateTile(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, shortname: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, arguments: core::mem::MaybeUninit<windows_core::HSTRING>, tileoptions: TileOptions, logoreference: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileFactory", "CreateTile", 6usize, |this| ((**(this as *const *const ISecondaryTileFactory_Vtbl)).CreateTile)(this, tileid, shortname, displayname, arguments, tileoptions, logoreference, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn CreateWideTile(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, shortname: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, arguments: core::mem::MaybeUninit<windows_core::HSTRING>, tileoptions: TileOptions, logoreference: *mut core::ffi::c_void, widelogoreference: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileFactory", "CreateWideTile", 7usize, |this| ((**(this as *const *const ISecondaryTileFactory_Vtbl)).CreateWideTile)(this, tileid, shortname, displayname, arguments, tileoptions, logoreference, widelogoreference, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithId(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileFactory", "CreateWithId", 8usize, |this| ((**(this as *const *const ISecondaryTileFactory_Vtbl)).CreateWithId)(this, tileid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            CreateTile,
+            #[cfg(not(feature = "deprecated"))]
+            CreateTile: 0,
+            #[cfg(feature = "deprecated")]
+            CreateWideTile,
+            #[cfg(not(feature = "deprecated"))]
+            CreateWideTile: 0,
+            CreateWithId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecondaryTileFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISecondaryTileFactory2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMinimalTile(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, displayname: core::mem::MaybeUninit<windows_core::HSTRING>, arguments: core::mem::MaybeUninit<windows_core::HSTRING>, square150x150logo: *mut core::ffi::c_void, desiredsize: TileSize, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileFactory2", "CreateMinimalTile", 6usize, |this| ((**(this as *const *const ISecondaryTileFactory2_Vtbl)).CreateMinimalTile)(this, tileid, displayname, arguments, square150x150logo, desiredsize, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMinimalTile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecondaryTileFactory2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ISecondaryTileStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Exists(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileStatics", "Exists", 6usize, |this| ((**(this as *const *const ISecondaryTileStatics_Vtbl)).Exists)(this, tileid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileStatics", "FindAllAsync", 7usize, |this| ((**(this as *const *const ISecondaryTileStatics_Vtbl)).FindAllAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllForApplicationAsync(this: *mut core::ffi::c_void, applicationid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileStatics", "FindAllForApplicationAsync", 8usize, |this| ((**(this as *const *const ISecondaryTileStatics_Vtbl)).FindAllForApplicationAsync)(this, applicationid, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllForPackageAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileStatics", "FindAllForPackageAsync", 9usize, |this| ((**(this as *const *const ISecondaryTileStatics_Vtbl)).FindAllForPackageAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Exists,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllForApplicationAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllForApplicationAsync: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllForPackageAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllForPackageAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecondaryTileStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISecondaryTileVisualElements_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetSquare30x30Logo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "SetSquare30x30Logo", 6usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).SetSquare30x30Logo)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Square30x30Logo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "Square30x30Logo", 7usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).Square30x30Logo)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetSquare70x70Logo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "SetSquare70x70Logo", 8usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).SetSquare70x70Logo)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Square70x70Logo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "Square70x70Logo", 9usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).Square70x70Logo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSquare150x150Logo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "SetSquare150x150Logo", 10usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).SetSquare150x150Logo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Square150x150Logo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "Square150x150Logo", 11usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).Square150x150Logo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWide310x150Logo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "SetWide310x150Logo", 12usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).SetWide310x150Logo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wide310x150Logo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "Wide310x150Logo", 13usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).Wide310x150Logo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSquare310x310Logo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "SetSquare310x310Logo", 14usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).SetSquare310x310Logo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Square310x310Logo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "Square310x310Logo", 15usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).Square310x310Logo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForegroundText(this: *mut core::ffi::c_void, value: ForegroundText) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "SetForegroundText", 16usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).SetForegroundText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForegroundText(this: *mut core::ffi::c_void, result__: *mut ForegroundText) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "ForegroundText", 17usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).ForegroundText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackgroundColor(this: *mut core::ffi::c_void, value: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "SetBackgroundColor", 18usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).SetBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundColor(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "BackgroundColor", 19usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).BackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowNameOnSquare150x150Logo(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "SetShowNameOnSquare150x150Logo", 20usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).SetShowNameOnSquare150x150Logo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowNameOnSquare150x150Logo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "ShowNameOnSquare150x150Logo", 21usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).ShowNameOnSquare150x150Logo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowNameOnWide310x150Logo(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "SetShowNameOnWide310x150Logo", 22usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).SetShowNameOnWide310x150Logo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowNameOnWide310x150Logo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "ShowNameOnWide310x150Logo", 23usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).ShowNameOnWide310x150Logo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowNameOnSquare310x310Logo(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "SetShowNameOnSquare310x310Logo", 24usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).SetShowNameOnSquare310x310Logo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowNameOnSquare310x310Logo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements", "ShowNameOnSquare310x310Logo", 25usize, |this| ((**(this as *const *const ISecondaryTileVisualElements_Vtbl)).ShowNameOnSquare310x310Logo)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            SetSquare30x30Logo,
+            #[cfg(not(feature = "deprecated"))]
+            SetSquare30x30Logo: 0,
+            #[cfg(feature = "deprecated")]
+            Square30x30Logo,
+            #[cfg(not(feature = "deprecated"))]
+            Square30x30Logo: 0,
+            #[cfg(feature = "deprecated")]
+            SetSquare70x70Logo,
+            #[cfg(not(feature = "deprecated"))]
+            SetSquare70x70Logo: 0,
+            #[cfg(feature = "deprecated")]
+            Square70x70Logo,
+            #[cfg(not(feature = "deprecated"))]
+            Square70x70Logo: 0,
+            SetSquare150x150Logo,
+            Square150x150Logo,
+            SetWide310x150Logo,
+            Wide310x150Logo,
+            SetSquare310x310Logo,
+            Square310x310Logo,
+            SetForegroundText,
+            ForegroundText,
+            SetBackgroundColor,
+            BackgroundColor,
+            SetShowNameOnSquare150x150Logo,
+            ShowNameOnSquare150x150Logo,
+            SetShowNameOnWide310x150Logo,
+            ShowNameOnWide310x150Logo,
+            SetShowNameOnSquare310x310Logo,
+            ShowNameOnSquare310x310Logo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecondaryTileVisualElements as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISecondaryTileVisualElements2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSquare71x71Logo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements2", "SetSquare71x71Logo", 6usize, |this| ((**(this as *const *const ISecondaryTileVisualElements2_Vtbl)).SetSquare71x71Logo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Square71x71Logo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements2", "Square71x71Logo", 7usize, |this| ((**(this as *const *const ISecondaryTileVisualElements2_Vtbl)).Square71x71Logo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSquare71x71Logo, Square71x71Logo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecondaryTileVisualElements2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISecondaryTileVisualElements3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSquare44x44Logo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements3", "SetSquare44x44Logo", 6usize, |this| ((**(this as *const *const ISecondaryTileVisualElements3_Vtbl)).SetSquare44x44Logo)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Square44x44Logo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements3", "Square44x44Logo", 7usize, |this| ((**(this as *const *const ISecondaryTileVisualElements3_Vtbl)).Square44x44Logo)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSquare44x44Logo, Square44x44Logo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecondaryTileVisualElements3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISecondaryTileVisualElements4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MixedRealityModel(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecondaryTileVisualElements4", "MixedRealityModel", 6usize, |this| ((**(this as *const *const ISecondaryTileVisualElements4_Vtbl)).MixedRealityModel)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MixedRealityModel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecondaryTileVisualElements4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Core", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IStartScreenManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStartScreenManager", "User", 6usize, |this| ((**(this as *const *const IStartScreenManager_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Core")]
+        unsafe extern "system" fn SupportsAppListEntry(this: *mut core::ffi::c_void, applistentry: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStartScreenManager", "SupportsAppListEntry", 7usize, |this| ((**(this as *const *const IStartScreenManager_Vtbl)).SupportsAppListEntry)(this, applistentry, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Core")]
+        unsafe extern "system" fn ContainsAppListEntryAsync(this: *mut core::ffi::c_void, applistentry: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStartScreenManager", "ContainsAppListEntryAsync", 8usize, |this| ((**(this as *const *const IStartScreenManager_Vtbl)).ContainsAppListEntryAsync)(this, applistentry, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Core")]
+        unsafe extern "system" fn RequestAddAppListEntryAsync(this: *mut core::ffi::c_void, applistentry: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStartScreenManager", "RequestAddAppListEntryAsync", 9usize, |this| ((**(this as *const *const IStartScreenManager_Vtbl)).RequestAddAppListEntryAsync)(this, applistentry, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+            #[cfg(feature = "ApplicationModel_Core")]
+            SupportsAppListEntry,
+            #[cfg(not(feature = "ApplicationModel_Core"))]
+            SupportsAppListEntry: 0,
+            #[cfg(feature = "ApplicationModel_Core")]
+            ContainsAppListEntryAsync,
+            #[cfg(not(feature = "ApplicationModel_Core"))]
+            ContainsAppListEntryAsync: 0,
+            #[cfg(feature = "ApplicationModel_Core")]
+            RequestAddAppListEntryAsync,
+            #[cfg(not(feature = "ApplicationModel_Core"))]
+            RequestAddAppListEntryAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStartScreenManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Core", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IStartScreenManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContainsSecondaryTileAsync(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStartScreenManager2", "ContainsSecondaryTileAsync", 6usize, |this| ((**(this as *const *const IStartScreenManager2_Vtbl)).ContainsSecondaryTileAsync)(this, tileid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryRemoveSecondaryTileAsync(this: *mut core::ffi::c_void, tileid: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStartScreenManager2", "TryRemoveSecondaryTileAsync", 7usize, |this| ((**(this as *const *const IStartScreenManager2_Vtbl)).TryRemoveSecondaryTileAsync)(this, tileid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContainsSecondaryTileAsync, TryRemoveSecondaryTileAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStartScreenManager2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IStartScreenManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStartScreenManagerStatics", "GetDefault", 6usize, |this| ((**(this as *const *const IStartScreenManagerStatics_Vtbl)).GetDefault)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn GetForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStartScreenManagerStatics", "GetForUser", 7usize, |this| ((**(this as *const *const IStartScreenManagerStatics_Vtbl)).GetForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDefault,
+            #[cfg(feature = "System")]
+            GetForUser,
+            #[cfg(not(feature = "System"))]
+            GetForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStartScreenManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+impl windows_core::imp::ProxyVtable for ITileMixedRealityModel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileMixedRealityModel", "SetUri", 6usize, |this| ((**(this as *const *const ITileMixedRealityModel_Vtbl)).SetUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileMixedRealityModel", "Uri", 7usize, |this| ((**(this as *const *const ITileMixedRealityModel_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn SetBoundingBox(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileMixedRealityModel", "SetBoundingBox", 8usize, |this| ((**(this as *const *const ITileMixedRealityModel_Vtbl)).SetBoundingBox)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+        unsafe extern "system" fn BoundingBox(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileMixedRealityModel", "BoundingBox", 9usize, |this| ((**(this as *const *const ITileMixedRealityModel_Vtbl)).BoundingBox)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetUri,
+            Uri,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            SetBoundingBox,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            SetBoundingBox: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Perception_Spatial"))]
+            BoundingBox,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Perception_Spatial")))]
+            BoundingBox: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITileMixedRealityModel as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITileMixedRealityModel2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetActivationBehavior(this: *mut core::ffi::c_void, value: TileMixedRealityModelActivationBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileMixedRealityModel2", "SetActivationBehavior", 6usize, |this| ((**(this as *const *const ITileMixedRealityModel2_Vtbl)).SetActivationBehavior)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivationBehavior(this: *mut core::ffi::c_void, result__: *mut TileMixedRealityModelActivationBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITileMixedRealityModel2", "ActivationBehavior", 7usize, |this| ((**(this as *const *const ITileMixedRealityModel2_Vtbl)).ActivationBehavior)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetActivationBehavior, ActivationBehavior }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITileMixedRealityModel2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IVisualElementsRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VisualElements(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualElementsRequest", "VisualElements", 6usize, |this| ((**(this as *const *const IVisualElementsRequest_Vtbl)).VisualElements)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AlternateVisualElements(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualElementsRequest", "AlternateVisualElements", 7usize, |this| ((**(this as *const *const IVisualElementsRequest_Vtbl)).AlternateVisualElements)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualElementsRequest", "Deadline", 8usize, |this| ((**(this as *const *const IVisualElementsRequest_Vtbl)).Deadline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualElementsRequest", "GetDeferral", 9usize, |this| ((**(this as *const *const IVisualElementsRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            VisualElements,
+            #[cfg(feature = "Foundation_Collections")]
+            AlternateVisualElements,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AlternateVisualElements: 0,
+            Deadline,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisualElementsRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVisualElementsRequestDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualElementsRequestDeferral", "Complete", 6usize, |this| ((**(this as *const *const IVisualElementsRequestDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisualElementsRequestDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVisualElementsRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualElementsRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const IVisualElementsRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisualElementsRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
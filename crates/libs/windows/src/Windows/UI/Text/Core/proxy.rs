@@ -0,0 +1,535 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICoreTextCompositionCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsCanceled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextCompositionCompletedEventArgs", "IsCanceled", 6usize, |this| ((**(this as *const *const ICoreTextCompositionCompletedEventArgs_Vtbl)).IsCanceled)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn CompositionSegments(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextCompositionCompletedEventArgs", "CompositionSegments", 7usize, |this| ((**(this as *const *const ICoreTextCompositionCompletedEventArgs_Vtbl)).CompositionSegments)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextCompositionCompletedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const ICoreTextCompositionCompletedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsCanceled,
+            #[cfg(feature = "Foundation_Collections")]
+            CompositionSegments,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            CompositionSegments: 0,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextCompositionCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTextCompositionSegment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PreconversionString(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextCompositionSegment", "PreconversionString", 6usize, |this| ((**(this as *const *const ICoreTextCompositionSegment_Vtbl)).PreconversionString)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Range(this: *mut core::ffi::c_void, result__: *mut CoreTextRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextCompositionSegment", "Range", 7usize, |this| ((**(this as *const *const ICoreTextCompositionSegment_Vtbl)).Range)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PreconversionString, Range }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextCompositionSegment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTextCompositionStartedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsCanceled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextCompositionStartedEventArgs", "IsCanceled", 6usize, |this| ((**(this as *const *const ICoreTextCompositionStartedEventArgs_Vtbl)).IsCanceled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextCompositionStartedEventArgs", "GetDeferral", 7usize, |this| ((**(this as *const *const ICoreTextCompositionStartedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsCanceled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextCompositionStartedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTextEditContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "Name", 6usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "SetName", 7usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputScope(this: *mut core::ffi::c_void, result__: *mut CoreTextInputScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "InputScope", 8usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).InputScope)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputScope(this: *mut core::ffi::c_void, value: CoreTextInputScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "SetInputScope", 9usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).SetInputScope)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReadOnly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "IsReadOnly", 10usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).IsReadOnly)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsReadOnly(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "SetIsReadOnly", 11usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).SetIsReadOnly)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputPaneDisplayPolicy(this: *mut core::ffi::c_void, result__: *mut CoreTextInputPaneDisplayPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "InputPaneDisplayPolicy", 12usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).InputPaneDisplayPolicy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputPaneDisplayPolicy(this: *mut core::ffi::c_void, value: CoreTextInputPaneDisplayPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "SetInputPaneDisplayPolicy", 13usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).SetInputPaneDisplayPolicy)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "TextRequested", 14usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).TextRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTextRequested(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "RemoveTextRequested", 15usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).RemoveTextRequested)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectionRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "SelectionRequested", 16usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).SelectionRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSelectionRequested(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "RemoveSelectionRequested", 17usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).RemoveSelectionRequested)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn LayoutRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "LayoutRequested", 18usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).LayoutRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLayoutRequested(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "RemoveLayoutRequested", 19usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).RemoveLayoutRequested)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextUpdating(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "TextUpdating", 20usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).TextUpdating)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTextUpdating(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "RemoveTextUpdating", 21usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).RemoveTextUpdating)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectionUpdating(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "SelectionUpdating", 22usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).SelectionUpdating)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSelectionUpdating(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "RemoveSelectionUpdating", 23usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).RemoveSelectionUpdating)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatUpdating(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "FormatUpdating", 24usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).FormatUpdating)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFormatUpdating(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "RemoveFormatUpdating", 25usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).RemoveFormatUpdating)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompositionStarted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "CompositionStarted", 26usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).CompositionStarted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCompositionStarted(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "RemoveCompositionStarted", 27usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).RemoveCompositionStarted)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompositionCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "CompositionCompleted", 28usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).CompositionCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCompositionCompleted(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "RemoveCompositionCompleted", 29usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).RemoveCompositionCompleted)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn FocusRemoved(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "FocusRemoved", 30usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).FocusRemoved)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFocusRemoved(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "RemoveFocusRemoved", 31usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).RemoveFocusRemoved)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyFocusEnter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "NotifyFocusEnter", 32usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).NotifyFocusEnter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyFocusLeave(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "NotifyFocusLeave", 33usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).NotifyFocusLeave)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyTextChanged(this: *mut core::ffi::c_void, modifiedrange: CoreTextRange, newlength: i32, newselection: CoreTextRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "NotifyTextChanged", 34usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).NotifyTextChanged)(this, modifiedrange, newlength, newselection), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifySelectionChanged(this: *mut core::ffi::c_void, selection: CoreTextRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "NotifySelectionChanged", 35usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).NotifySelectionChanged)(this, selection), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyLayoutChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext", "NotifyLayoutChanged", 36usize, |this| ((**(this as *const *const ICoreTextEditContext_Vtbl)).NotifyLayoutChanged)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            InputScope,
+            SetInputScope,
+            IsReadOnly,
+            SetIsReadOnly,
+            InputPaneDisplayPolicy,
+            SetInputPaneDisplayPolicy,
+            TextRequested,
+            RemoveTextRequested,
+            SelectionRequested,
+            RemoveSelectionRequested,
+            LayoutRequested,
+            RemoveLayoutRequested,
+            TextUpdating,
+            RemoveTextUpdating,
+            SelectionUpdating,
+            RemoveSelectionUpdating,
+            FormatUpdating,
+            RemoveFormatUpdating,
+            CompositionStarted,
+            RemoveCompositionStarted,
+            CompositionCompleted,
+            RemoveCompositionCompleted,
+            FocusRemoved,
+            RemoveFocusRemoved,
+            NotifyFocusEnter,
+            NotifyFocusLeave,
+            NotifyTextChanged,
+            NotifySelectionChanged,
+            NotifyLayoutChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextEditContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTextEditContext2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifyFocusLeaveCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext2", "NotifyFocusLeaveCompleted", 6usize, |this| ((**(this as *const *const ICoreTextEditContext2_Vtbl)).NotifyFocusLeaveCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNotifyFocusLeaveCompleted(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextEditContext2", "RemoveNotifyFocusLeaveCompleted", 7usize, |this| ((**(this as *const *const ICoreTextEditContext2_Vtbl)).RemoveNotifyFocusLeaveCompleted)(this, cookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NotifyFocusLeaveCompleted, RemoveNotifyFocusLeaveCompleted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextEditContext2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_ViewManagement")]
+impl windows_core::imp::ProxyVtable for ICoreTextFormatUpdatingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Range(this: *mut core::ffi::c_void, result__: *mut CoreTextRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextFormatUpdatingEventArgs", "Range", 6usize, |this| ((**(this as *const *const ICoreTextFormatUpdatingEventArgs_Vtbl)).Range)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_ViewManagement")]
+        unsafe extern "system" fn TextColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextFormatUpdatingEventArgs", "TextColor", 7usize, |this| ((**(this as *const *const ICoreTextFormatUpdatingEventArgs_Vtbl)).TextColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_ViewManagement")]
+        unsafe extern "system" fn BackgroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextFormatUpdatingEventArgs", "BackgroundColor", 8usize, |this| ((**(this as *const *const ICoreTextFormatUpdatingEventArgs_Vtbl)).BackgroundColor)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_ViewManagement")]
+        unsafe extern "system" fn UnderlineColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextFormatUpdatingEventArgs", "UnderlineColor", 9usize, |this| ((**(this as *const *const ICoreTextFormatUpdatingEventArgs_Vtbl)).UnderlineColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnderlineType(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextFormatUpdatingEventArgs", "UnderlineType", 10usize, |this| ((**(this as *const *const ICoreTextFormatUpdatingEventArgs_Vtbl)).UnderlineType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut CoreTextFormatUpdatingReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextFormatUpdatingEventArgs", "Reason", 11usize, |this| ((**(this as *const *const ICoreTextFormatUpdatingEventArgs_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Result(this: *mut core::ffi::c_void, result__: *mut CoreTextFormatUpdatingResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextFormatUpdatingEventArgs", "Result", 12usize, |this| ((**(this as *const *const ICoreTextFormatUpdatingEventArgs_Vtbl)).Result)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResult(this: *mut core::ffi::c_void, value: CoreTextFormatUpdatingResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextFormatUpdatingEventArgs", "SetResult", 13usize, |this| ((**(this as *const *const ICoreTextFormatUpdatingEventArgs_Vtbl)).SetResult)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCanceled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextFormatUpdatingEventArgs", "IsCanceled", 14usize, |this| ((**(this as *const *const ICoreTextFormatUpdatingEventArgs_Vtbl)).IsCanceled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextFormatUpdatingEventArgs", "GetDeferral", 15usize, |this| ((**(this as *const *const ICoreTextFormatUpdatingEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Range,
+            #[cfg(feature = "UI_ViewManagement")]
+            TextColor,
+            #[cfg(not(feature = "UI_ViewManagement"))]
+            TextColor: 0,
+            #[cfg(feature = "UI_ViewManagement")]
+            BackgroundColor,
+            #[cfg(not(feature = "UI_ViewManagement"))]
+            BackgroundColor: 0,
+            #[cfg(feature = "UI_ViewManagement")]
+            UnderlineColor,
+            #[cfg(not(feature = "UI_ViewManagement"))]
+            UnderlineColor: 0,
+            UnderlineType,
+            Reason,
+            Result,
+            SetResult,
+            IsCanceled,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextFormatUpdatingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTextLayoutBounds_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TextBounds(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextLayoutBounds", "TextBounds", 6usize, |this| ((**(this as *const *const ICoreTextLayoutBounds_Vtbl)).TextBounds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextBounds(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextLayoutBounds", "SetTextBounds", 7usize, |this| ((**(this as *const *const ICoreTextLayoutBounds_Vtbl)).SetTextBounds)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControlBounds(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextLayoutBounds", "ControlBounds", 8usize, |this| ((**(this as *const *const ICoreTextLayoutBounds_Vtbl)).ControlBounds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetControlBounds(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextLayoutBounds", "SetControlBounds", 9usize, |this| ((**(this as *const *const ICoreTextLayoutBounds_Vtbl)).SetControlBounds)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TextBounds, SetTextBounds, ControlBounds, SetControlBounds }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextLayoutBounds as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTextLayoutRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Range(this: *mut core::ffi::c_void, result__: *mut CoreTextRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextLayoutRequest", "Range", 6usize, |this| ((**(this as *const *const ICoreTextLayoutRequest_Vtbl)).Range)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LayoutBounds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextLayoutRequest", "LayoutBounds", 7usize, |this| ((**(this as *const *const ICoreTextLayoutRequest_Vtbl)).LayoutBounds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCanceled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextLayoutRequest", "IsCanceled", 8usize, |this| ((**(this as *const *const ICoreTextLayoutRequest_Vtbl)).IsCanceled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextLayoutRequest", "GetDeferral", 9usize, |this| ((**(this as *const *const ICoreTextLayoutRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Range, LayoutBounds, IsCanceled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextLayoutRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTextLayoutRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LayoutBoundsVisualPixels(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextLayoutRequest2", "LayoutBoundsVisualPixels", 6usize, |this| ((**(this as *const *const ICoreTextLayoutRequest2_Vtbl)).LayoutBoundsVisualPixels)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LayoutBoundsVisualPixels }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextLayoutRequest2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTextLayoutRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextLayoutRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const ICoreTextLayoutRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextLayoutRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTextSelectionRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Selection(this: *mut core::ffi::c_void, result__: *mut CoreTextRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextSelectionRequest", "Selection", 6usize, |this| ((**(this as *const *const ICoreTextSelectionRequest_Vtbl)).Selection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelection(this: *mut core::ffi::c_void, value: CoreTextRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextSelectionRequest", "SetSelection", 7usize, |this| ((**(this as *const *const ICoreTextSelectionRequest_Vtbl)).SetSelection)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCanceled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextSelectionRequest", "IsCanceled", 8usize, |this| ((**(this as *const *const ICoreTextSelectionRequest_Vtbl)).IsCanceled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextSelectionRequest", "GetDeferral", 9usize, |this| ((**(this as *const *const ICoreTextSelectionRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Selection, SetSelection, IsCanceled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextSelectionRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTextSelectionRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextSelectionRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const ICoreTextSelectionRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextSelectionRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTextSelectionUpdatingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Selection(this: *mut core::ffi::c_void, result__: *mut CoreTextRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextSelectionUpdatingEventArgs", "Selection", 6usize, |this| ((**(this as *const *const ICoreTextSelectionUpdatingEventArgs_Vtbl)).Selection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Result(this: *mut core::ffi::c_void, result__: *mut CoreTextSelectionUpdatingResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextSelectionUpdatingEventArgs", "Result", 7usize, |this| ((**(this as *const *const ICoreTextSelectionUpdatingEventArgs_Vtbl)).Result)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResult(this: *mut core::ffi::c_void, value: CoreTextSelectionUpdatingResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextSelectionUpdatingEventArgs", "SetResult", 8usize, |this| ((**(this as *const *const ICoreTextSelectionUpdatingEventArgs_Vtbl)).SetResult)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCanceled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextSelectionUpdatingEventArgs", "IsCanceled", 9usize, |this| ((**(this as *const *const ICoreTextSelectionUpdatingEventArgs_Vtbl)).IsCanceled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextSelectionUpdatingEventArgs", "GetDeferral", 10usize, |this| ((**(this as *const *const ICoreTextSelectionUpdatingEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Selection, Result, SetResult, IsCanceled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextSelectionUpdatingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Globalization")]
+impl windows_core::imp::ProxyVtable for ICoreTextServicesManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Globalization")]
+        unsafe extern "system" fn InputLanguage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextServicesManager", "InputLanguage", 6usize, |this| ((**(this as *const *const ICoreTextServicesManager_Vtbl)).InputLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputLanguageChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextServicesManager", "InputLanguageChanged", 7usize, |this| ((**(this as *const *const ICoreTextServicesManager_Vtbl)).InputLanguageChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveInputLanguageChanged(this: *mut core::ffi::c_void, cookie: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextServicesManager", "RemoveInputLanguageChanged", 8usize, |this| ((**(this as *const *const ICoreTextServicesManager_Vtbl)).RemoveInputLanguageChanged)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEditContext(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextServicesManager", "CreateEditContext", 9usize, |this| ((**(this as *const *const ICoreTextServicesManager_Vtbl)).CreateEditContext)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Globalization")]
+            InputLanguage,
+            #[cfg(not(feature = "Globalization"))]
+            InputLanguage: 0,
+            InputLanguageChanged,
+            RemoveInputLanguageChanged,
+            CreateEditContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextServicesManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTextServicesManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextServicesManagerStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const ICoreTextServicesManagerStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextServicesManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTextServicesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HiddenCharacter(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextServicesStatics", "HiddenCharacter", 6usize, |this| ((**(this as *const *const ICoreTextServicesStatics_Vtbl)).HiddenCharacter)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HiddenCharacter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextServicesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTextTextRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Range(this: *mut core::ffi::c_void, result__: *mut CoreTextRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextTextRequest", "Range", 6usize, |this| ((**(this as *const *const ICoreTextTextRequest_Vtbl)).Range)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextTextRequest", "Text", 7usize, |this| ((**(this as *const *const ICoreTextTextRequest_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextTextRequest", "SetText", 8usize, |this| ((**(this as *const *const ICoreTextTextRequest_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCanceled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextTextRequest", "IsCanceled", 9usize, |this| ((**(this as *const *const ICoreTextTextRequest_Vtbl)).IsCanceled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextTextRequest", "GetDeferral", 10usize, |this| ((**(this as *const *const ICoreTextTextRequest_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Range, Text, SetText, IsCanceled, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextTextRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreTextTextRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextTextRequestedEventArgs", "Request", 6usize, |this| ((**(this as *const *const ICoreTextTextRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextTextRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Globalization")]
+impl windows_core::imp::ProxyVtable for ICoreTextTextUpdatingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Range(this: *mut core::ffi::c_void, result__: *mut CoreTextRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextTextUpdatingEventArgs", "Range", 6usize, |this| ((**(this as *const *const ICoreTextTextUpdatingEventArgs_Vtbl)).Range)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextTextUpdatingEventArgs", "Text", 7usize, |this| ((**(this as *const *const ICoreTextTextUpdatingEventArgs_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewSelection(this: *mut core::ffi::c_void, result__: *mut CoreTextRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextTextUpdatingEventArgs", "NewSelection", 8usize, |this| ((**(this as *const *const ICoreTextTextUpdatingEventArgs_Vtbl)).NewSelection)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Globalization")]
+        unsafe extern "system" fn InputLanguage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextTextUpdatingEventArgs", "InputLanguage", 9usize, |this| ((**(this as *const *const ICoreTextTextUpdatingEventArgs_Vtbl)).InputLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Result(this: *mut core::ffi::c_void, result__: *mut CoreTextTextUpdatingResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextTextUpdatingEventArgs", "Result", 10usize, |this| ((**(this as *const *const ICoreTextTextUpdatingEventArgs_Vtbl)).Result)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResult(this: *mut core::ffi::c_void, value: CoreTextTextUpdatingResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextTextUpdatingEventArgs", "SetResult", 11usize, |this| ((**(this as *const *const ICoreTextTextUpdatingEventArgs_Vtbl)).SetResult)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCanceled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextTextUpdatingEventArgs", "IsCanceled", 12usize, |this| ((**(this as *const *const ICoreTextTextUpdatingEventArgs_Vtbl)).IsCanceled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreTextTextUpdatingEventArgs", "GetDeferral", 13usize, |this| ((**(this as *const *const ICoreTextTextUpdatingEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Range,
+            Text,
+            NewSelection,
+            #[cfg(feature = "Globalization")]
+            InputLanguage,
+            #[cfg(not(feature = "Globalization"))]
+            InputLanguage: 0,
+            Result,
+            SetResult,
+            IsCanceled,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreTextTextUpdatingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -2607,6 +2607,7 @@ impl CaretType {
 impl windows_core::TypeKind for CaretType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CaretType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CaretType").field(&self.0).finish()
@@ -2626,6 +2627,7 @@ impl FindOptions {
 impl windows_core::TypeKind for FindOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FindOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FindOptions").field(&self.0).finish()
@@ -2685,6 +2687,7 @@ impl FontStretch {
 impl windows_core::TypeKind for FontStretch {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FontStretch {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FontStretch").field(&self.0).finish()
@@ -2704,6 +2707,7 @@ impl FontStyle {
 impl windows_core::TypeKind for FontStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FontStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FontStyle").field(&self.0).finish()
@@ -2724,6 +2728,7 @@ impl FormatEffect {
 impl windows_core::TypeKind for FormatEffect {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FormatEffect {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FormatEffect").field(&self.0).finish()
@@ -2743,6 +2748,7 @@ impl HorizontalCharacterAlignment {
 impl windows_core::TypeKind for HorizontalCharacterAlignment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HorizontalCharacterAlignment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HorizontalCharacterAlignment").field(&self.0).finish()
@@ -2761,6 +2767,7 @@ impl LetterCase {
 impl windows_core::TypeKind for LetterCase {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LetterCase {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LetterCase").field(&self.0).finish()
@@ -2785,6 +2792,7 @@ impl LineSpacingRule {
 impl windows_core::TypeKind for LineSpacingRule {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LineSpacingRule {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LineSpacingRule").field(&self.0).finish()
@@ -2810,6 +2818,7 @@ impl LinkType {
 impl windows_core::TypeKind for LinkType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LinkType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LinkType").field(&self.0).finish()
@@ -2830,6 +2839,7 @@ impl MarkerAlignment {
 impl windows_core::TypeKind for MarkerAlignment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MarkerAlignment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MarkerAlignment").field(&self.0).finish()
@@ -2853,6 +2863,7 @@ impl MarkerStyle {
 impl windows_core::TypeKind for MarkerStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MarkerStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MarkerStyle").field(&self.0).finish()
@@ -2894,6 +2905,7 @@ impl MarkerType {
 impl windows_core::TypeKind for MarkerType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MarkerType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MarkerType").field(&self.0).finish()
@@ -2915,6 +2927,7 @@ impl ParagraphAlignment {
 impl windows_core::TypeKind for ParagraphAlignment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ParagraphAlignment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ParagraphAlignment").field(&self.0).finish()
@@ -2943,6 +2956,7 @@ impl ParagraphStyle {
 impl windows_core::TypeKind for ParagraphStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ParagraphStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ParagraphStyle").field(&self.0).finish()
@@ -2967,6 +2981,7 @@ impl PointOptions {
 impl windows_core::TypeKind for PointOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PointOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PointOptions").field(&self.0).finish()
@@ -3021,6 +3036,7 @@ impl RangeGravity {
 impl windows_core::TypeKind for RangeGravity {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RangeGravity {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RangeGravity").field(&self.0).finish()
@@ -3039,6 +3055,7 @@ impl RichEditMathMode {
 impl windows_core::TypeKind for RichEditMathMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RichEditMathMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RichEditMathMode").field(&self.0).finish()
@@ -3060,6 +3077,7 @@ impl SelectionOptions {
 impl windows_core::TypeKind for SelectionOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SelectionOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SelectionOptions").field(&self.0).finish()
@@ -3114,6 +3132,7 @@ impl SelectionType {
 impl windows_core::TypeKind for SelectionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SelectionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SelectionType").field(&self.0).finish()
@@ -3135,6 +3154,7 @@ impl TabAlignment {
 impl windows_core::TypeKind for TabAlignment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TabAlignment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TabAlignment").field(&self.0).finish()
@@ -3157,6 +3177,7 @@ impl TabLeader {
 impl windows_core::TypeKind for TabLeader {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TabLeader {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TabLeader").field(&self.0).finish()
@@ -3176,6 +3197,7 @@ impl TextDecorations {
 impl windows_core::TypeKind for TextDecorations {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TextDecorations {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TextDecorations").field(&self.0).finish()
@@ -3234,6 +3256,7 @@ impl TextGetOptions {
 impl windows_core::TypeKind for TextGetOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TextGetOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TextGetOptions").field(&self.0).finish()
@@ -3316,6 +3339,7 @@ impl TextRangeUnit {
 impl windows_core::TypeKind for TextRangeUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TextRangeUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TextRangeUnit").field(&self.0).finish()
@@ -3396,6 +3420,7 @@ impl TextScript {
 impl windows_core::TypeKind for TextScript {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TextScript {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TextScript").field(&self.0).finish()
@@ -3419,6 +3444,7 @@ impl TextSetOptions {
 impl windows_core::TypeKind for TextSetOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TextSetOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TextSetOptions").field(&self.0).finish()
@@ -3488,6 +3514,7 @@ impl UnderlineType {
 impl windows_core::TypeKind for UnderlineType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UnderlineType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UnderlineType").field(&self.0).finish()
@@ -3507,6 +3534,7 @@ impl VerticalCharacterAlignment {
 impl windows_core::TypeKind for VerticalCharacterAlignment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VerticalCharacterAlignment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VerticalCharacterAlignment").field(&self.0).finish()
@@ -3516,7 +3544,8 @@ impl windows_core::RuntimeType for VerticalCharacterAlignment {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.Text.VerticalCharacterAlignment;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FontWeight {
     pub Weight: u16,
 }
@@ -3533,3 +3562,5 @@ impl Default for FontWeight {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1054 @@
+impl windows_core::imp::ProxyVtable for IContentLinkInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentLinkInfo", "Id", 6usize, |this| ((**(this as *const *const IContentLinkInfo_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentLinkInfo", "SetId", 7usize, |this| ((**(this as *const *const IContentLinkInfo_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentLinkInfo", "DisplayText", 8usize, |this| ((**(this as *const *const IContentLinkInfo_Vtbl)).DisplayText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentLinkInfo", "SetDisplayText", 9usize, |this| ((**(this as *const *const IContentLinkInfo_Vtbl)).SetDisplayText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecondaryText(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentLinkInfo", "SecondaryText", 10usize, |this| ((**(this as *const *const IContentLinkInfo_Vtbl)).SecondaryText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecondaryText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentLinkInfo", "SetSecondaryText", 11usize, |this| ((**(this as *const *const IContentLinkInfo_Vtbl)).SetSecondaryText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentLinkInfo", "Uri", 12usize, |this| ((**(this as *const *const IContentLinkInfo_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentLinkInfo", "SetUri", 13usize, |this| ((**(this as *const *const IContentLinkInfo_Vtbl)).SetUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LinkContentKind(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentLinkInfo", "LinkContentKind", 14usize, |this| ((**(this as *const *const IContentLinkInfo_Vtbl)).LinkContentKind)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLinkContentKind(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentLinkInfo", "SetLinkContentKind", 15usize, |this| ((**(this as *const *const IContentLinkInfo_Vtbl)).SetLinkContentKind)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            SetId,
+            DisplayText,
+            SetDisplayText,
+            SecondaryText,
+            SetSecondaryText,
+            Uri,
+            SetUri,
+            LinkContentKind,
+            SetLinkContentKind,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContentLinkInfo as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFontWeights_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFontWeights as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFontWeightsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Black(this: *mut core::ffi::c_void, result__: *mut FontWeight) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFontWeightsStatics", "Black", 6usize, |this| ((**(this as *const *const IFontWeightsStatics_Vtbl)).Black)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bold(this: *mut core::ffi::c_void, result__: *mut FontWeight) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFontWeightsStatics", "Bold", 7usize, |this| ((**(this as *const *const IFontWeightsStatics_Vtbl)).Bold)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtraBlack(this: *mut core::ffi::c_void, result__: *mut FontWeight) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFontWeightsStatics", "ExtraBlack", 8usize, |this| ((**(this as *const *const IFontWeightsStatics_Vtbl)).ExtraBlack)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtraBold(this: *mut core::ffi::c_void, result__: *mut FontWeight) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFontWeightsStatics", "ExtraBold", 9usize, |this| ((**(this as *const *const IFontWeightsStatics_Vtbl)).ExtraBold)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtraLight(this: *mut core::ffi::c_void, result__: *mut FontWeight) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFontWeightsStatics", "ExtraLight", 10usize, |this| ((**(this as *const *const IFontWeightsStatics_Vtbl)).ExtraLight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Light(this: *mut core::ffi::c_void, result__: *mut FontWeight) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFontWeightsStatics", "Light", 11usize, |this| ((**(this as *const *const IFontWeightsStatics_Vtbl)).Light)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Medium(this: *mut core::ffi::c_void, result__: *mut FontWeight) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFontWeightsStatics", "Medium", 12usize, |this| ((**(this as *const *const IFontWeightsStatics_Vtbl)).Medium)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Normal(this: *mut core::ffi::c_void, result__: *mut FontWeight) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFontWeightsStatics", "Normal", 13usize, |this| ((**(this as *const *const IFontWeightsStatics_Vtbl)).Normal)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SemiBold(this: *mut core::ffi::c_void, result__: *mut FontWeight) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFontWeightsStatics", "SemiBold", 14usize, |this| ((**(this as *const *const IFontWeightsStatics_Vtbl)).SemiBold)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SemiLight(this: *mut core::ffi::c_void, result__: *mut FontWeight) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFontWeightsStatics", "SemiLight", 15usize, |this| ((**(this as *const *const IFontWeightsStatics_Vtbl)).SemiLight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Thin(this: *mut core::ffi::c_void, result__: *mut FontWeight) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFontWeightsStatics", "Thin", 16usize, |this| ((**(this as *const *const IFontWeightsStatics_Vtbl)).Thin)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Black,
+            Bold,
+            ExtraBlack,
+            ExtraBold,
+            ExtraLight,
+            Light,
+            Medium,
+            Normal,
+            SemiBold,
+            SemiLight,
+            Thin,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFontWeightsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRichEditTextRange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContentLinkInfo(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditTextRange", "ContentLinkInfo", 6usize, |this| ((**(this as *const *const IRichEditTextRange_Vtbl)).ContentLinkInfo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentLinkInfo(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditTextRange", "SetContentLinkInfo", 7usize, |this| ((**(this as *const *const IRichEditTextRange_Vtbl)).SetContentLinkInfo)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContentLinkInfo, SetContentLinkInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRichEditTextRange as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextCharacterFormat_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllCaps(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "AllCaps", 6usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).AllCaps)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllCaps(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetAllCaps", 7usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetAllCaps)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundColor(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "BackgroundColor", 8usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).BackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackgroundColor(this: *mut core::ffi::c_void, value: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetBackgroundColor", 9usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bold(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "Bold", 10usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).Bold)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBold(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetBold", 11usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetBold)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FontStretch(this: *mut core::ffi::c_void, result__: *mut FontStretch) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "FontStretch", 12usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).FontStretch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontStretch(this: *mut core::ffi::c_void, value: FontStretch) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetFontStretch", 13usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetFontStretch)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FontStyle(this: *mut core::ffi::c_void, result__: *mut FontStyle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "FontStyle", 14usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).FontStyle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontStyle(this: *mut core::ffi::c_void, value: FontStyle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetFontStyle", 15usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetFontStyle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForegroundColor(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "ForegroundColor", 16usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).ForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForegroundColor(this: *mut core::ffi::c_void, value: super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetForegroundColor", 17usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetForegroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hidden(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "Hidden", 18usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).Hidden)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHidden(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetHidden", 19usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetHidden)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Italic(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "Italic", 20usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).Italic)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetItalic(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetItalic", 21usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetItalic)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kerning(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "Kerning", 22usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).Kerning)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKerning(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetKerning", 23usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetKerning)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LanguageTag(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "LanguageTag", 24usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).LanguageTag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguageTag(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetLanguageTag", 25usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetLanguageTag)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LinkType(this: *mut core::ffi::c_void, result__: *mut LinkType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "LinkType", 26usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).LinkType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "Name", 27usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetName", 28usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Outline(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "Outline", 29usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).Outline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutline(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetOutline", 30usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetOutline)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "Position", 31usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).Position)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetPosition", 32usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetPosition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectedText(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "ProtectedText", 33usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).ProtectedText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProtectedText(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetProtectedText", 34usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetProtectedText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "Size", 35usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetSize", 36usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SmallCaps(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SmallCaps", 37usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SmallCaps)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSmallCaps(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetSmallCaps", 38usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetSmallCaps)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Spacing(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "Spacing", 39usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).Spacing)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpacing(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetSpacing", 40usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetSpacing)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Strikethrough(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "Strikethrough", 41usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).Strikethrough)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrikethrough(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetStrikethrough", 42usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetStrikethrough)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subscript(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "Subscript", 43usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).Subscript)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubscript(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetSubscript", 44usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetSubscript)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Superscript(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "Superscript", 45usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).Superscript)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuperscript(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetSuperscript", 46usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetSuperscript)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextScript(this: *mut core::ffi::c_void, result__: *mut TextScript) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "TextScript", 47usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).TextScript)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextScript(this: *mut core::ffi::c_void, value: TextScript) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetTextScript", 48usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetTextScript)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Underline(this: *mut core::ffi::c_void, result__: *mut UnderlineType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "Underline", 49usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).Underline)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnderline(this: *mut core::ffi::c_void, value: UnderlineType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetUnderline", 50usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetUnderline)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Weight(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "Weight", 51usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).Weight)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWeight(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetWeight", 52usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetWeight)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClone(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "SetClone", 53usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).SetClone)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClone(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "GetClone", 54usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).GetClone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextCharacterFormat", "IsEqual", 55usize, |this| ((**(this as *const *const ITextCharacterFormat_Vtbl)).IsEqual)(this, format, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AllCaps,
+            SetAllCaps,
+            BackgroundColor,
+            SetBackgroundColor,
+            Bold,
+            SetBold,
+            FontStretch,
+            SetFontStretch,
+            FontStyle,
+            SetFontStyle,
+            ForegroundColor,
+            SetForegroundColor,
+            Hidden,
+            SetHidden,
+            Italic,
+            SetItalic,
+            Kerning,
+            SetKerning,
+            LanguageTag,
+            SetLanguageTag,
+            LinkType,
+            Name,
+            SetName,
+            Outline,
+            SetOutline,
+            Position,
+            SetPosition,
+            ProtectedText,
+            SetProtectedText,
+            Size,
+            SetSize,
+            SmallCaps,
+            SetSmallCaps,
+            Spacing,
+            SetSpacing,
+            Strikethrough,
+            SetStrikethrough,
+            Subscript,
+            SetSubscript,
+            Superscript,
+            SetSuperscript,
+            TextScript,
+            SetTextScript,
+            Underline,
+            SetUnderline,
+            Weight,
+            SetWeight,
+            SetClone,
+            GetClone,
+            IsEqual,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextCharacterFormat as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextConstantsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AutoColor(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextConstantsStatics", "AutoColor", 6usize, |this| ((**(this as *const *const ITextConstantsStatics_Vtbl)).AutoColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinUnitCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextConstantsStatics", "MinUnitCount", 7usize, |this| ((**(this as *const *const ITextConstantsStatics_Vtbl)).MinUnitCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxUnitCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextConstantsStatics", "MaxUnitCount", 8usize, |this| ((**(this as *const *const ITextConstantsStatics_Vtbl)).MaxUnitCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UndefinedColor(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextConstantsStatics", "UndefinedColor", 9usize, |this| ((**(this as *const *const ITextConstantsStatics_Vtbl)).UndefinedColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UndefinedFloatValue(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextConstantsStatics", "UndefinedFloatValue", 10usize, |this| ((**(this as *const *const ITextConstantsStatics_Vtbl)).UndefinedFloatValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UndefinedInt32Value(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextConstantsStatics", "UndefinedInt32Value", 11usize, |this| ((**(this as *const *const ITextConstantsStatics_Vtbl)).UndefinedInt32Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UndefinedFontStretch(this: *mut core::ffi::c_void, result__: *mut FontStretch) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextConstantsStatics", "UndefinedFontStretch", 12usize, |this| ((**(this as *const *const ITextConstantsStatics_Vtbl)).UndefinedFontStretch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UndefinedFontStyle(this: *mut core::ffi::c_void, result__: *mut FontStyle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextConstantsStatics", "UndefinedFontStyle", 13usize, |this| ((**(this as *const *const ITextConstantsStatics_Vtbl)).UndefinedFontStyle)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AutoColor,
+            MinUnitCount,
+            MaxUnitCount,
+            UndefinedColor,
+            UndefinedFloatValue,
+            UndefinedInt32Value,
+            UndefinedFontStretch,
+            UndefinedFontStyle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextConstantsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITextDocument_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CaretType(this: *mut core::ffi::c_void, result__: *mut CaretType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "CaretType", 6usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).CaretType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCaretType(this: *mut core::ffi::c_void, value: CaretType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "SetCaretType", 7usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).SetCaretType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultTabStop(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "DefaultTabStop", 8usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).DefaultTabStop)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultTabStop(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "SetDefaultTabStop", 9usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).SetDefaultTabStop)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Selection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "Selection", 10usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).Selection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UndoLimit(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "UndoLimit", 11usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).UndoLimit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUndoLimit(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "SetUndoLimit", 12usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).SetUndoLimit)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanCopy(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "CanCopy", 13usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).CanCopy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanPaste(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "CanPaste", 14usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).CanPaste)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanRedo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "CanRedo", 15usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).CanRedo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanUndo(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "CanUndo", 16usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).CanUndo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyDisplayUpdates(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "ApplyDisplayUpdates", 17usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).ApplyDisplayUpdates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BatchDisplayUpdates(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "BatchDisplayUpdates", 18usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).BatchDisplayUpdates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginUndoGroup(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "BeginUndoGroup", 19usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).BeginUndoGroup)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndUndoGroup(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "EndUndoGroup", 20usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).EndUndoGroup)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultCharacterFormat(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "GetDefaultCharacterFormat", 21usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).GetDefaultCharacterFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultParagraphFormat(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "GetDefaultParagraphFormat", 22usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).GetDefaultParagraphFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRange(this: *mut core::ffi::c_void, startposition: i32, endposition: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "GetRange", 23usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).GetRange)(this, startposition, endposition, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRangeFromPoint(this: *mut core::ffi::c_void, point: super::super::Foundation::Point, options: PointOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "GetRangeFromPoint", 24usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).GetRangeFromPoint)(this, point, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "GetText", 25usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).GetText)(this, options, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn LoadFromStream(this: *mut core::ffi::c_void, options: TextSetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "LoadFromStream", 26usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).LoadFromStream)(this, options, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Redo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "Redo", 27usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).Redo)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SaveToStream(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "SaveToStream", 28usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).SaveToStream)(this, options, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultCharacterFormat(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "SetDefaultCharacterFormat", 29usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).SetDefaultCharacterFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultParagraphFormat(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "SetDefaultParagraphFormat", 30usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).SetDefaultParagraphFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, options: TextSetOptions, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "SetText", 31usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).SetText)(this, options, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Undo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "Undo", 32usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).Undo)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CaretType,
+            SetCaretType,
+            DefaultTabStop,
+            SetDefaultTabStop,
+            Selection,
+            UndoLimit,
+            SetUndoLimit,
+            CanCopy,
+            CanPaste,
+            CanRedo,
+            CanUndo,
+            ApplyDisplayUpdates,
+            BatchDisplayUpdates,
+            BeginUndoGroup,
+            EndUndoGroup,
+            GetDefaultCharacterFormat,
+            GetDefaultParagraphFormat,
+            GetRange,
+            GetRangeFromPoint,
+            GetText,
+            #[cfg(feature = "Storage_Streams")]
+            LoadFromStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            LoadFromStream: 0,
+            Redo,
+            #[cfg(feature = "Storage_Streams")]
+            SaveToStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SaveToStream: 0,
+            SetDefaultCharacterFormat,
+            SetDefaultParagraphFormat,
+            SetText,
+            Undo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextDocument as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextDocument2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AlignmentIncludesTrailingWhitespace(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "AlignmentIncludesTrailingWhitespace", 6usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).AlignmentIncludesTrailingWhitespace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlignmentIncludesTrailingWhitespace(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "SetAlignmentIncludesTrailingWhitespace", 7usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).SetAlignmentIncludesTrailingWhitespace)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IgnoreTrailingCharacterSpacing(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "IgnoreTrailingCharacterSpacing", 8usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).IgnoreTrailingCharacterSpacing)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIgnoreTrailingCharacterSpacing(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "SetIgnoreTrailingCharacterSpacing", 9usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).SetIgnoreTrailingCharacterSpacing)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AlignmentIncludesTrailingWhitespace,
+            SetAlignmentIncludesTrailingWhitespace,
+            IgnoreTrailingCharacterSpacing,
+            SetIgnoreTrailingCharacterSpacing,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextDocument2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextDocument3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClearUndoRedoHistory(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument3", "ClearUndoRedoHistory", 6usize, |this| ((**(this as *const *const ITextDocument3_Vtbl)).ClearUndoRedoHistory)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ClearUndoRedoHistory }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextDocument3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextDocument4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMath(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument4", "SetMath", 6usize, |this| ((**(this as *const *const ITextDocument4_Vtbl)).SetMath)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMath(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument4", "GetMath", 7usize, |this| ((**(this as *const *const ITextDocument4_Vtbl)).GetMath)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMathMode(this: *mut core::ffi::c_void, mode: RichEditMathMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument4", "SetMathMode", 8usize, |this| ((**(this as *const *const ITextDocument4_Vtbl)).SetMathMode)(this, mode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMath, GetMath, SetMathMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextDocument4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextParagraphFormat_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Alignment(this: *mut core::ffi::c_void, result__: *mut ParagraphAlignment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "Alignment", 6usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).Alignment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlignment(this: *mut core::ffi::c_void, value: ParagraphAlignment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetAlignment", 7usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetAlignment)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstLineIndent(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "FirstLineIndent", 8usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).FirstLineIndent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeepTogether(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "KeepTogether", 9usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).KeepTogether)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeepTogether(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetKeepTogether", 10usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetKeepTogether)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeepWithNext(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "KeepWithNext", 11usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).KeepWithNext)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeepWithNext(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetKeepWithNext", 12usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetKeepWithNext)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeftIndent(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "LeftIndent", 13usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).LeftIndent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineSpacing(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "LineSpacing", 14usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).LineSpacing)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineSpacingRule(this: *mut core::ffi::c_void, result__: *mut LineSpacingRule) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "LineSpacingRule", 15usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).LineSpacingRule)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ListAlignment(this: *mut core::ffi::c_void, result__: *mut MarkerAlignment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "ListAlignment", 16usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).ListAlignment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetListAlignment(this: *mut core::ffi::c_void, value: MarkerAlignment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetListAlignment", 17usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetListAlignment)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ListLevelIndex(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "ListLevelIndex", 18usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).ListLevelIndex)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetListLevelIndex(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetListLevelIndex", 19usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetListLevelIndex)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ListStart(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "ListStart", 20usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).ListStart)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetListStart(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetListStart", 21usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetListStart)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ListStyle(this: *mut core::ffi::c_void, result__: *mut MarkerStyle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "ListStyle", 22usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).ListStyle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetListStyle(this: *mut core::ffi::c_void, value: MarkerStyle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetListStyle", 23usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetListStyle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ListTab(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "ListTab", 24usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).ListTab)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetListTab(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetListTab", 25usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetListTab)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ListType(this: *mut core::ffi::c_void, result__: *mut MarkerType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "ListType", 26usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).ListType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetListType(this: *mut core::ffi::c_void, value: MarkerType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetListType", 27usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetListType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NoLineNumber(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "NoLineNumber", 28usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).NoLineNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNoLineNumber(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetNoLineNumber", 29usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetNoLineNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageBreakBefore(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "PageBreakBefore", 30usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).PageBreakBefore)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPageBreakBefore(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetPageBreakBefore", 31usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetPageBreakBefore)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RightIndent(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "RightIndent", 32usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).RightIndent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRightIndent(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetRightIndent", 33usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetRightIndent)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RightToLeft(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "RightToLeft", 34usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).RightToLeft)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRightToLeft(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetRightToLeft", 35usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetRightToLeft)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Style(this: *mut core::ffi::c_void, result__: *mut ParagraphStyle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "Style", 36usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).Style)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStyle(this: *mut core::ffi::c_void, value: ParagraphStyle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetStyle", 37usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetStyle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpaceAfter(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SpaceAfter", 38usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SpaceAfter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpaceAfter(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetSpaceAfter", 39usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetSpaceAfter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpaceBefore(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SpaceBefore", 40usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SpaceBefore)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpaceBefore(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetSpaceBefore", 41usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetSpaceBefore)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WidowControl(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "WidowControl", 42usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).WidowControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWidowControl(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetWidowControl", 43usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetWidowControl)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TabCount(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "TabCount", 44usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).TabCount)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTab(this: *mut core::ffi::c_void, position: f32, align: TabAlignment, leader: TabLeader) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "AddTab", 45usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).AddTab)(this, position, align, leader), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearAllTabs(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "ClearAllTabs", 46usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).ClearAllTabs)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteTab(this: *mut core::ffi::c_void, position: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "DeleteTab", 47usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).DeleteTab)(this, position), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClone(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "GetClone", 48usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).GetClone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTab(this: *mut core::ffi::c_void, index: i32, position: *mut f32, align: *mut TabAlignment, leader: *mut TabLeader) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "GetTab", 49usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).GetTab)(this, index, position, align, leader), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "IsEqual", 50usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).IsEqual)(this, format, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClone(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetClone", 51usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetClone)(this, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIndents(this: *mut core::ffi::c_void, start: f32, left: f32, right: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetIndents", 52usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetIndents)(this, start, left, right), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLineSpacing(this: *mut core::ffi::c_void, rule: LineSpacingRule, spacing: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextParagraphFormat", "SetLineSpacing", 53usize, |this| ((**(this as *const *const ITextParagraphFormat_Vtbl)).SetLineSpacing)(this, rule, spacing), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Alignment,
+            SetAlignment,
+            FirstLineIndent,
+            KeepTogether,
+            SetKeepTogether,
+            KeepWithNext,
+            SetKeepWithNext,
+            LeftIndent,
+            LineSpacing,
+            LineSpacingRule,
+            ListAlignment,
+            SetListAlignment,
+            ListLevelIndex,
+            SetListLevelIndex,
+            ListStart,
+            SetListStart,
+            ListStyle,
+            SetListStyle,
+            ListTab,
+            SetListTab,
+            ListType,
+            SetListType,
+            NoLineNumber,
+            SetNoLineNumber,
+            PageBreakBefore,
+            SetPageBreakBefore,
+            RightIndent,
+            SetRightIndent,
+            RightToLeft,
+            SetRightToLeft,
+            Style,
+            SetStyle,
+            SpaceAfter,
+            SetSpaceAfter,
+            SpaceBefore,
+            SetSpaceBefore,
+            WidowControl,
+            SetWidowControl,
+            TabCount,
+            AddTab,
+            ClearAllTabs,
+            DeleteTab,
+            GetClone,
+            GetTab,
+            IsEqual,
+            SetClone,
+            SetIndents,
+            SetLineSpacing,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextParagraphFormat as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITextRange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Character(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Character", 6usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Character)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCharacter(this: *mut core::ffi::c_void, value: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetCharacter", 7usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetCharacter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CharacterFormat(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "CharacterFormat", 8usize, |this| ((**(this as *const *const ITextRange_Vtbl)).CharacterFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCharacterFormat(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetCharacterFormat", 9usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetCharacterFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormattedText(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "FormattedText", 10usize, |this| ((**(this as *const *const ITextRange_Vtbl)).FormattedText)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormattedText(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetFormattedText", 11usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetFormattedText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndPosition(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "EndPosition", 12usize, |this| ((**(this as *const *const ITextRange_Vtbl)).EndPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEndPosition(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetEndPosition", 13usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetEndPosition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gravity(this: *mut core::ffi::c_void, result__: *mut RangeGravity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Gravity", 14usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Gravity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGravity(this: *mut core::ffi::c_void, value: RangeGravity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetGravity", 15usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetGravity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Length", 16usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Length)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Link(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Link", 17usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Link)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLink(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetLink", 18usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetLink)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParagraphFormat(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "ParagraphFormat", 19usize, |this| ((**(this as *const *const ITextRange_Vtbl)).ParagraphFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParagraphFormat(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetParagraphFormat", 20usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetParagraphFormat)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartPosition(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "StartPosition", 21usize, |this| ((**(this as *const *const ITextRange_Vtbl)).StartPosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartPosition(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetStartPosition", 22usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetStartPosition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StoryLength(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "StoryLength", 23usize, |this| ((**(this as *const *const ITextRange_Vtbl)).StoryLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Text", 24usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetText", 25usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanPaste(this: *mut core::ffi::c_void, format: i32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "CanPaste", 26usize, |this| ((**(this as *const *const ITextRange_Vtbl)).CanPaste)(this, format, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeCase(this: *mut core::ffi::c_void, value: LetterCase) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "ChangeCase", 27usize, |this| ((**(this as *const *const ITextRange_Vtbl)).ChangeCase)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Collapse(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Collapse", 28usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Collapse)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Copy", 29usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Copy)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cut(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Cut", 30usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Cut)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Delete", 31usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Delete)(this, unit, count, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndOf(this: *mut core::ffi::c_void, unit: TextRangeUnit, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "EndOf", 32usize, |this| ((**(this as *const *const ITextRange_Vtbl)).EndOf)(this, unit, extend, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Expand(this: *mut core::ffi::c_void, unit: TextRangeUnit, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Expand", 33usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Expand)(this, unit, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>, scanlength: i32, options: FindOptions, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "FindText", 34usize, |this| ((**(this as *const *const ITextRange_Vtbl)).FindText)(this, value, scanlength, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCharacterUtf32(this: *mut core::ffi::c_void, value: *mut u32, offset: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetCharacterUtf32", 35usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetCharacterUtf32)(this, value, offset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClone(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetClone", 36usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetClone)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndex(this: *mut core::ffi::c_void, unit: TextRangeUnit, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetIndex", 37usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetIndex)(this, unit, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPoint(this: *mut core::ffi::c_void, horizontalalign: HorizontalCharacterAlignment, verticalalign: VerticalCharacterAlignment, options: PointOptions, point: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetPoint", 38usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetPoint)(this, horizontalalign, verticalalign, options, point), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRect(this: *mut core::ffi::c_void, options: PointOptions, rect: *mut super::super::Foundation::Rect, hit: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetRect", 39usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetRect)(this, options, rect, hit), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetText", 40usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetText)(this, options, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetTextViaStream(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetTextViaStream", 41usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetTextViaStream)(this, options, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InRange(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "InRange", 42usize, |this| ((**(this as *const *const ITextRange_Vtbl)).InRange)(this, range, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn InsertImage(this: *mut core::ffi::c_void, width: i32, height: i32, ascent: i32, verticalalign: VerticalCharacterAlignment, alternatetext: core::mem::MaybeUninit<windows_core::HSTRING>, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "InsertImage", 43usize, |this| ((**(this as *const *const ITextRange_Vtbl)).InsertImage)(this, width, height, ascent, verticalalign, alternatetext, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InStory(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "InStory", 44usize, |this| ((**(this as *const *const ITextRange_Vtbl)).InStory)(this, range, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "IsEqual", 45usize, |this| ((**(this as *const *const ITextRange_Vtbl)).IsEqual)(this, range, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Move", 46usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Move)(this, unit, count, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveEnd(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "MoveEnd", 47usize, |this| ((**(this as *const *const ITextRange_Vtbl)).MoveEnd)(this, unit, count, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveStart(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "MoveStart", 48usize, |this| ((**(this as *const *const ITextRange_Vtbl)).MoveStart)(this, unit, count, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Paste(this: *mut core::ffi::c_void, format: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Paste", 49usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Paste)(this, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScrollIntoView(this: *mut core::ffi::c_void, value: PointOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "ScrollIntoView", 50usize, |this| ((**(this as *const *const ITextRange_Vtbl)).ScrollIntoView)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MatchSelection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "MatchSelection", 51usize, |this| ((**(this as *const *const ITextRange_Vtbl)).MatchSelection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIndex(this: *mut core::ffi::c_void, unit: TextRangeUnit, index: i32, extend: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetIndex", 52usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetIndex)(this, unit, index, extend), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPoint(this: *mut core::ffi::c_void, point: super::super::Foundation::Point, options: PointOptions, extend: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetPoint", 53usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetPoint)(this, point, options, extend), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRange(this: *mut core::ffi::c_void, startposition: i32, endposition: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetRange", 54usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetRange)(this, startposition, endposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText2(this: *mut core::ffi::c_void, options: TextSetOptions, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetText2", 55usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetText2)(this, options, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetTextViaStream(this: *mut core::ffi::c_void, options: TextSetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetTextViaStream", 56usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetTextViaStream)(this, options, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartOf(this: *mut core::ffi::c_void, unit: TextRangeUnit, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "StartOf", 57usize, |this| ((**(this as *const *const ITextRange_Vtbl)).StartOf)(this, unit, extend, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Character,
+            SetCharacter,
+            CharacterFormat,
+            SetCharacterFormat,
+            FormattedText,
+            SetFormattedText,
+            EndPosition,
+            SetEndPosition,
+            Gravity,
+            SetGravity,
+            Length,
+            Link,
+            SetLink,
+            ParagraphFormat,
+            SetParagraphFormat,
+            StartPosition,
+            SetStartPosition,
+            StoryLength,
+            Text,
+            SetText,
+            CanPaste,
+            ChangeCase,
+            Collapse,
+            Copy,
+            Cut,
+            Delete,
+            EndOf,
+            Expand,
+            FindText,
+            GetCharacterUtf32,
+            GetClone,
+            GetIndex,
+            GetPoint,
+            GetRect,
+            GetText,
+            #[cfg(feature = "Storage_Streams")]
+            GetTextViaStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetTextViaStream: 0,
+            InRange,
+            #[cfg(feature = "Storage_Streams")]
+            InsertImage,
+            #[cfg(not(feature = "Storage_Streams"))]
+            InsertImage: 0,
+            InStory,
+            IsEqual,
+            Move,
+            MoveEnd,
+            MoveStart,
+            Paste,
+            ScrollIntoView,
+            MatchSelection,
+            SetIndex,
+            SetPoint,
+            SetRange,
+            SetText2,
+            #[cfg(feature = "Storage_Streams")]
+            SetTextViaStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetTextViaStream: 0,
+            StartOf,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextRange as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for ITextSelection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Options(this: *mut core::ffi::c_void, result__: *mut SelectionOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "Options", 6usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).Options)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOptions(this: *mut core::ffi::c_void, value: SelectionOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "SetOptions", 7usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).SetOptions)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut SelectionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "Type", 8usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndKey(this: *mut core::ffi::c_void, unit: TextRangeUnit, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "EndKey", 9usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).EndKey)(this, unit, extend, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HomeKey(this: *mut core::ffi::c_void, unit: TextRangeUnit, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "HomeKey", 10usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).HomeKey)(this, unit, extend, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveDown(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "MoveDown", 11usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).MoveDown)(this, unit, count, extend, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveLeft(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "MoveLeft", 12usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).MoveLeft)(this, unit, count, extend, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveRight(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "MoveRight", 13usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).MoveRight)(this, unit, count, extend, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveUp(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "MoveUp", 14usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).MoveUp)(this, unit, count, extend, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TypeText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "TypeText", 15usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).TypeText)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Options,
+            SetOptions,
+            Type,
+            EndKey,
+            HomeKey,
+            MoveDown,
+            MoveLeft,
+            MoveRight,
+            MoveUp,
+            TypeText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextSelection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -558,6 +558,7 @@ impl AutomationRemoteOperationStatus {
 impl windows_core::TypeKind for AutomationRemoteOperationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AutomationRemoteOperationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AutomationRemoteOperationStatus").field(&self.0).finish()
@@ -567,7 +568,8 @@ impl windows_core::RuntimeType for AutomationRemoteOperationStatus {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.UIAutomation.Core.AutomationRemoteOperationStatus;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AutomationAnnotationTypeRegistration {
     pub LocalId: i32,
 }
@@ -583,7 +585,8 @@ impl Default for AutomationAnnotationTypeRegistration {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AutomationRemoteOperationOperandId {
     pub Value: i32,
 }
@@ -600,3 +603,5 @@ impl Default for AutomationRemoteOperationOperandId {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,239 @@
+impl windows_core::imp::ProxyVtable for IAutomationRemoteOperationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, result__: *mut AutomationRemoteOperationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomationRemoteOperationResult", "Status", 6usize, |this| ((**(this as *const *const IAutomationRemoteOperationResult_Vtbl)).Status)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomationRemoteOperationResult", "ExtendedError", 7usize, |this| ((**(this as *const *const IAutomationRemoteOperationResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorLocation(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomationRemoteOperationResult", "ErrorLocation", 8usize, |this| ((**(this as *const *const IAutomationRemoteOperationResult_Vtbl)).ErrorLocation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasOperand(this: *mut core::ffi::c_void, operandid: AutomationRemoteOperationOperandId, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomationRemoteOperationResult", "HasOperand", 9usize, |this| ((**(this as *const *const IAutomationRemoteOperationResult_Vtbl)).HasOperand)(this, operandid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOperand(this: *mut core::ffi::c_void, operandid: AutomationRemoteOperationOperandId, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomationRemoteOperationResult", "GetOperand", 10usize, |this| ((**(this as *const *const IAutomationRemoteOperationResult_Vtbl)).GetOperand)(this, operandid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, ExtendedError, ErrorLocation, HasOperand, GetOperand }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAutomationRemoteOperationResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreAutomationConnectionBoundObjectProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsComThreadingRequired(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAutomationConnectionBoundObjectProvider", "IsComThreadingRequired", 6usize, |this| ((**(this as *const *const ICoreAutomationConnectionBoundObjectProvider_Vtbl)).IsComThreadingRequired)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsComThreadingRequired }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreAutomationConnectionBoundObjectProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreAutomationRegistrarStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterAnnotationType(this: *mut core::ffi::c_void, guid: windows_core::GUID, result__: *mut AutomationAnnotationTypeRegistration) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAutomationRegistrarStatics", "RegisterAnnotationType", 6usize, |this| ((**(this as *const *const ICoreAutomationRegistrarStatics_Vtbl)).RegisterAnnotationType)(this, guid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterAnnotationType(this: *mut core::ffi::c_void, registration: AutomationAnnotationTypeRegistration) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAutomationRegistrarStatics", "UnregisterAnnotationType", 7usize, |this| ((**(this as *const *const ICoreAutomationRegistrarStatics_Vtbl)).UnregisterAnnotationType)(this, registration), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterAnnotationType, UnregisterAnnotationType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreAutomationRegistrarStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreAutomationRemoteOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsOpcodeSupported(this: *mut core::ffi::c_void, opcode: u32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAutomationRemoteOperation", "IsOpcodeSupported", 6usize, |this| ((**(this as *const *const ICoreAutomationRemoteOperation_Vtbl)).IsOpcodeSupported)(this, opcode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportElement(this: *mut core::ffi::c_void, operandid: AutomationRemoteOperationOperandId, element: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAutomationRemoteOperation", "ImportElement", 7usize, |this| ((**(this as *const *const ICoreAutomationRemoteOperation_Vtbl)).ImportElement)(this, operandid, element), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportTextRange(this: *mut core::ffi::c_void, operandid: AutomationRemoteOperationOperandId, textrange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAutomationRemoteOperation", "ImportTextRange", 8usize, |this| ((**(this as *const *const ICoreAutomationRemoteOperation_Vtbl)).ImportTextRange)(this, operandid, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddToResults(this: *mut core::ffi::c_void, operandid: AutomationRemoteOperationOperandId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAutomationRemoteOperation", "AddToResults", 9usize, |this| ((**(this as *const *const ICoreAutomationRemoteOperation_Vtbl)).AddToResults)(this, operandid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Execute(this: *mut core::ffi::c_void, bytecodeBuffer_array_size: u32, bytecodebuffer: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAutomationRemoteOperation", "Execute", 10usize, |this| ((**(this as *const *const ICoreAutomationRemoteOperation_Vtbl)).Execute)(this, bytecodeBuffer_array_size, bytecodebuffer, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsOpcodeSupported,
+            ImportElement,
+            ImportTextRange,
+            AddToResults,
+            Execute,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreAutomationRemoteOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreAutomationRemoteOperation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ImportConnectionBoundObject(this: *mut core::ffi::c_void, operandid: AutomationRemoteOperationOperandId, connectionboundobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAutomationRemoteOperation2", "ImportConnectionBoundObject", 6usize, |this| ((**(this as *const *const ICoreAutomationRemoteOperation2_Vtbl)).ImportConnectionBoundObject)(this, operandid, connectionboundobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ImportConnectionBoundObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreAutomationRemoteOperation2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreAutomationRemoteOperationContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOperand(this: *mut core::ffi::c_void, id: AutomationRemoteOperationOperandId, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAutomationRemoteOperationContext", "GetOperand", 6usize, |this| ((**(this as *const *const ICoreAutomationRemoteOperationContext_Vtbl)).GetOperand)(this, id, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOperand(this: *mut core::ffi::c_void, id: AutomationRemoteOperationOperandId, operand: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAutomationRemoteOperationContext", "SetOperand", 7usize, |this| ((**(this as *const *const ICoreAutomationRemoteOperationContext_Vtbl)).SetOperand)(this, id, operand), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOperand2(this: *mut core::ffi::c_void, id: AutomationRemoteOperationOperandId, operand: *mut core::ffi::c_void, operandinterfaceid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAutomationRemoteOperationContext", "SetOperand2", 8usize, |this| ((**(this as *const *const ICoreAutomationRemoteOperationContext_Vtbl)).SetOperand2)(this, id, operand, operandinterfaceid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOperand, SetOperand, SetOperand2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreAutomationRemoteOperationContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreAutomationRemoteOperationExtensionProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CallExtension(this: *mut core::ffi::c_void, extensionid: windows_core::GUID, context: *mut core::ffi::c_void, operandIds_array_size: u32, operandids: *const AutomationRemoteOperationOperandId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAutomationRemoteOperationExtensionProvider", "CallExtension", 6usize, |this| ((**(this as *const *const ICoreAutomationRemoteOperationExtensionProvider_Vtbl)).CallExtension)(this, extensionid, context, operandIds_array_size, operandids), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsExtensionSupported(this: *mut core::ffi::c_void, extensionid: windows_core::GUID, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreAutomationRemoteOperationExtensionProvider", "IsExtensionSupported", 7usize, |this| ((**(this as *const *const ICoreAutomationRemoteOperationExtensionProvider_Vtbl)).IsExtensionSupported)(this, extensionid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CallExtension, IsExtensionSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreAutomationRemoteOperationExtensionProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteAutomationClientSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationClientSession", "Start", 6usize, |this| ((**(this as *const *const IRemoteAutomationClientSession_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationClientSession", "Stop", 7usize, |this| ((**(this as *const *const IRemoteAutomationClientSession_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWindowAsync(this: *mut core::ffi::c_void, remotewindowid: u64, remoteprocessid: u32, parentautomationelement: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationClientSession", "CreateWindowAsync", 8usize, |this| ((**(this as *const *const IRemoteAutomationClientSession_Vtbl)).CreateWindowAsync)(this, remotewindowid, remoteprocessid, parentautomationelement, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationClientSession", "SessionId", 9usize, |this| ((**(this as *const *const IRemoteAutomationClientSession_Vtbl)).SessionId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationClientSession", "ConnectionRequested", 10usize, |this| ((**(this as *const *const IRemoteAutomationClientSession_Vtbl)).ConnectionRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConnectionRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationClientSession", "RemoveConnectionRequested", 11usize, |this| ((**(this as *const *const IRemoteAutomationClientSession_Vtbl)).RemoveConnectionRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationClientSession", "Disconnected", 12usize, |this| ((**(this as *const *const IRemoteAutomationClientSession_Vtbl)).Disconnected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDisconnected(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationClientSession", "RemoveDisconnected", 13usize, |this| ((**(this as *const *const IRemoteAutomationClientSession_Vtbl)).RemoveDisconnected)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Start,
+            Stop,
+            CreateWindowAsync,
+            SessionId,
+            ConnectionRequested,
+            RemoveConnectionRequested,
+            Disconnected,
+            RemoveDisconnected,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteAutomationClientSession as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteAutomationClientSessionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationClientSessionFactory", "CreateInstance", 6usize, |this| ((**(this as *const *const IRemoteAutomationClientSessionFactory_Vtbl)).CreateInstance)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstance2(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, sessionid: windows_core::GUID, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationClientSessionFactory", "CreateInstance2", 7usize, |this| ((**(this as *const *const IRemoteAutomationClientSessionFactory_Vtbl)).CreateInstance2)(this, name, sessionid, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance, CreateInstance2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteAutomationClientSessionFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteAutomationConnectionRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LocalPipeName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationConnectionRequestedEventArgs", "LocalPipeName", 6usize, |this| ((**(this as *const *const IRemoteAutomationConnectionRequestedEventArgs_Vtbl)).LocalPipeName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteProcessId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationConnectionRequestedEventArgs", "RemoteProcessId", 7usize, |this| ((**(this as *const *const IRemoteAutomationConnectionRequestedEventArgs_Vtbl)).RemoteProcessId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LocalPipeName, RemoteProcessId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteAutomationConnectionRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteAutomationDisconnectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LocalPipeName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationDisconnectedEventArgs", "LocalPipeName", 6usize, |this| ((**(this as *const *const IRemoteAutomationDisconnectedEventArgs_Vtbl)).LocalPipeName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LocalPipeName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteAutomationDisconnectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteAutomationServerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportSession(this: *mut core::ffi::c_void, sessionid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationServerStatics", "ReportSession", 6usize, |this| ((**(this as *const *const IRemoteAutomationServerStatics_Vtbl)).ReportSession)(this, sessionid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteAutomationServerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteAutomationWindow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AutomationProvider(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationWindow", "AutomationProvider", 6usize, |this| ((**(this as *const *const IRemoteAutomationWindow_Vtbl)).AutomationProvider)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteAutomationWindow", "UnregisterAsync", 7usize, |this| ((**(this as *const *const IRemoteAutomationWindow_Vtbl)).UnregisterAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AutomationProvider, UnregisterAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteAutomationWindow as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
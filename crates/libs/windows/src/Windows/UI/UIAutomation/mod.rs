@@ -163,3 +163,5 @@ impl windows_core::RuntimeName for AutomationTextRange {
 }
 unsafe impl Send for AutomationTextRange {}
 unsafe impl Sync for AutomationTextRange {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,55 @@
+impl windows_core::imp::ProxyVtable for IAutomationConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsRemoteSystem(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomationConnection", "IsRemoteSystem", 6usize, |this| ((**(this as *const *const IAutomationConnection_Vtbl)).IsRemoteSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppUserModelId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomationConnection", "AppUserModelId", 7usize, |this| ((**(this as *const *const IAutomationConnection_Vtbl)).AppUserModelId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecutableFileName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomationConnection", "ExecutableFileName", 8usize, |this| ((**(this as *const *const IAutomationConnection_Vtbl)).ExecutableFileName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsRemoteSystem, AppUserModelId, ExecutableFileName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAutomationConnection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAutomationConnectionBoundObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomationConnectionBoundObject", "Connection", 6usize, |this| ((**(this as *const *const IAutomationConnectionBoundObject_Vtbl)).Connection)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Connection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAutomationConnectionBoundObject as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAutomationElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsRemoteSystem(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomationElement", "IsRemoteSystem", 6usize, |this| ((**(this as *const *const IAutomationElement_Vtbl)).IsRemoteSystem)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppUserModelId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomationElement", "AppUserModelId", 7usize, |this| ((**(this as *const *const IAutomationElement_Vtbl)).AppUserModelId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecutableFileName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomationElement", "ExecutableFileName", 8usize, |this| ((**(this as *const *const IAutomationElement_Vtbl)).ExecutableFileName)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsRemoteSystem, AppUserModelId, ExecutableFileName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAutomationElement as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAutomationTextRange_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAutomationTextRange as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -841,6 +841,7 @@ impl CoreInputViewKind {
 impl windows_core::TypeKind for CoreInputViewKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreInputViewKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreInputViewKind").field(&self.0).finish()
@@ -860,6 +861,7 @@ impl CoreInputViewOcclusionKind {
 impl windows_core::TypeKind for CoreInputViewOcclusionKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreInputViewOcclusionKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreInputViewOcclusionKind").field(&self.0).finish()
@@ -880,6 +882,7 @@ impl CoreInputViewXYFocusTransferDirection {
 impl windows_core::TypeKind for CoreInputViewXYFocusTransferDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoreInputViewXYFocusTransferDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoreInputViewXYFocusTransferDirection").field(&self.0).finish()
@@ -888,3 +891,5 @@ impl core::fmt::Debug for CoreInputViewXYFocusTransferDirection {
 impl windows_core::RuntimeType for CoreInputViewXYFocusTransferDirection {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.ViewManagement.Core.CoreInputViewXYFocusTransferDirection;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,438 @@
+impl windows_core::imp::ProxyVtable for ICoreFrameworkInputView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrimaryViewAnimationStarting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFrameworkInputView", "PrimaryViewAnimationStarting", 6usize, |this| ((**(this as *const *const ICoreFrameworkInputView_Vtbl)).PrimaryViewAnimationStarting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePrimaryViewAnimationStarting(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFrameworkInputView", "RemovePrimaryViewAnimationStarting", 7usize, |this| ((**(this as *const *const ICoreFrameworkInputView_Vtbl)).RemovePrimaryViewAnimationStarting)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn OcclusionsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFrameworkInputView", "OcclusionsChanged", 8usize, |this| ((**(this as *const *const ICoreFrameworkInputView_Vtbl)).OcclusionsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOcclusionsChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFrameworkInputView", "RemoveOcclusionsChanged", 9usize, |this| ((**(this as *const *const ICoreFrameworkInputView_Vtbl)).RemoveOcclusionsChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PrimaryViewAnimationStarting,
+            RemovePrimaryViewAnimationStarting,
+            OcclusionsChanged,
+            RemoveOcclusionsChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreFrameworkInputView as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICoreFrameworkInputViewAnimationStartingEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Occlusions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFrameworkInputViewAnimationStartingEventArgs", "Occlusions", 6usize, |this| ((**(this as *const *const ICoreFrameworkInputViewAnimationStartingEventArgs_Vtbl)).Occlusions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameworkAnimationRecommended(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFrameworkInputViewAnimationStartingEventArgs", "FrameworkAnimationRecommended", 7usize, |this| ((**(this as *const *const ICoreFrameworkInputViewAnimationStartingEventArgs_Vtbl)).FrameworkAnimationRecommended)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnimationDuration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFrameworkInputViewAnimationStartingEventArgs", "AnimationDuration", 8usize, |this| ((**(this as *const *const ICoreFrameworkInputViewAnimationStartingEventArgs_Vtbl)).AnimationDuration)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Occlusions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Occlusions: 0,
+            FrameworkAnimationRecommended,
+            AnimationDuration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreFrameworkInputViewAnimationStartingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICoreFrameworkInputViewOcclusionsChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Occlusions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFrameworkInputViewOcclusionsChangedEventArgs", "Occlusions", 6usize, |this| ((**(this as *const *const ICoreFrameworkInputViewOcclusionsChangedEventArgs_Vtbl)).Occlusions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFrameworkInputViewOcclusionsChangedEventArgs", "Handled", 7usize, |this| ((**(this as *const *const ICoreFrameworkInputViewOcclusionsChangedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Occlusions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Occlusions: 0,
+            Handled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreFrameworkInputViewOcclusionsChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreFrameworkInputViewStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForUIContext(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFrameworkInputViewStatics", "GetForUIContext", 6usize, |this| ((**(this as *const *const ICoreFrameworkInputViewStatics_Vtbl)).GetForUIContext)(this, context, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFrameworkInputViewStatics", "GetForCurrentView", 7usize, |this| ((**(this as *const *const ICoreFrameworkInputViewStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForUIContext, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreFrameworkInputViewStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICoreInputView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OcclusionsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView", "OcclusionsChanged", 6usize, |this| ((**(this as *const *const ICoreInputView_Vtbl)).OcclusionsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOcclusionsChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView", "RemoveOcclusionsChanged", 7usize, |this| ((**(this as *const *const ICoreInputView_Vtbl)).RemoveOcclusionsChanged)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCoreInputViewOcclusions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView", "GetCoreInputViewOcclusions", 8usize, |this| ((**(this as *const *const ICoreInputView_Vtbl)).GetCoreInputViewOcclusions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryShowPrimaryView(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView", "TryShowPrimaryView", 9usize, |this| ((**(this as *const *const ICoreInputView_Vtbl)).TryShowPrimaryView)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryHidePrimaryView(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView", "TryHidePrimaryView", 10usize, |this| ((**(this as *const *const ICoreInputView_Vtbl)).TryHidePrimaryView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OcclusionsChanged,
+            RemoveOcclusionsChanged,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCoreInputViewOcclusions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCoreInputViewOcclusions: 0,
+            TryShowPrimaryView,
+            TryHidePrimaryView,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputView as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreInputView2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn XYFocusTransferringFromPrimaryView(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView2", "XYFocusTransferringFromPrimaryView", 6usize, |this| ((**(this as *const *const ICoreInputView2_Vtbl)).XYFocusTransferringFromPrimaryView)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveXYFocusTransferringFromPrimaryView(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView2", "RemoveXYFocusTransferringFromPrimaryView", 7usize, |this| ((**(this as *const *const ICoreInputView2_Vtbl)).RemoveXYFocusTransferringFromPrimaryView)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn XYFocusTransferredToPrimaryView(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView2", "XYFocusTransferredToPrimaryView", 8usize, |this| ((**(this as *const *const ICoreInputView2_Vtbl)).XYFocusTransferredToPrimaryView)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveXYFocusTransferredToPrimaryView(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView2", "RemoveXYFocusTransferredToPrimaryView", 9usize, |this| ((**(this as *const *const ICoreInputView2_Vtbl)).RemoveXYFocusTransferredToPrimaryView)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryTransferXYFocusToPrimaryView(this: *mut core::ffi::c_void, origin: super::super::super::Foundation::Rect, direction: CoreInputViewXYFocusTransferDirection, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView2", "TryTransferXYFocusToPrimaryView", 10usize, |this| ((**(this as *const *const ICoreInputView2_Vtbl)).TryTransferXYFocusToPrimaryView)(this, origin, direction, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            XYFocusTransferringFromPrimaryView,
+            RemoveXYFocusTransferringFromPrimaryView,
+            XYFocusTransferredToPrimaryView,
+            RemoveXYFocusTransferredToPrimaryView,
+            TryTransferXYFocusToPrimaryView,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputView2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreInputView3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryShow(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView3", "TryShow", 6usize, |this| ((**(this as *const *const ICoreInputView3_Vtbl)).TryShow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryShowWithKind(this: *mut core::ffi::c_void, r#type: CoreInputViewKind, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView3", "TryShowWithKind", 7usize, |this| ((**(this as *const *const ICoreInputView3_Vtbl)).TryShowWithKind)(this, r#type, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryHide(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView3", "TryHide", 8usize, |this| ((**(this as *const *const ICoreInputView3_Vtbl)).TryHide)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryShow, TryShowWithKind, TryHide }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputView3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreInputView4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrimaryViewShowing(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView4", "PrimaryViewShowing", 6usize, |this| ((**(this as *const *const ICoreInputView4_Vtbl)).PrimaryViewShowing)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePrimaryViewShowing(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView4", "RemovePrimaryViewShowing", 7usize, |this| ((**(this as *const *const ICoreInputView4_Vtbl)).RemovePrimaryViewShowing)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrimaryViewHiding(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView4", "PrimaryViewHiding", 8usize, |this| ((**(this as *const *const ICoreInputView4_Vtbl)).PrimaryViewHiding)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePrimaryViewHiding(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView4", "RemovePrimaryViewHiding", 9usize, |this| ((**(this as *const *const ICoreInputView4_Vtbl)).RemovePrimaryViewHiding)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PrimaryViewShowing,
+            RemovePrimaryViewShowing,
+            PrimaryViewHiding,
+            RemovePrimaryViewHiding,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputView4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreInputView5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsKindSupported(this: *mut core::ffi::c_void, r#type: CoreInputViewKind, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView5", "IsKindSupported", 6usize, |this| ((**(this as *const *const ICoreInputView5_Vtbl)).IsKindSupported)(this, r#type, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedKindsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView5", "SupportedKindsChanged", 7usize, |this| ((**(this as *const *const ICoreInputView5_Vtbl)).SupportedKindsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSupportedKindsChanged(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView5", "RemoveSupportedKindsChanged", 8usize, |this| ((**(this as *const *const ICoreInputView5_Vtbl)).RemoveSupportedKindsChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrimaryViewAnimationStarting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView5", "PrimaryViewAnimationStarting", 9usize, |this| ((**(this as *const *const ICoreInputView5_Vtbl)).PrimaryViewAnimationStarting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePrimaryViewAnimationStarting(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputView5", "RemovePrimaryViewAnimationStarting", 10usize, |this| ((**(this as *const *const ICoreInputView5_Vtbl)).RemovePrimaryViewAnimationStarting)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsKindSupported,
+            SupportedKindsChanged,
+            RemoveSupportedKindsChanged,
+            PrimaryViewAnimationStarting,
+            RemovePrimaryViewAnimationStarting,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputView5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICoreInputViewAnimationStartingEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Occlusions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewAnimationStartingEventArgs", "Occlusions", 6usize, |this| ((**(this as *const *const ICoreInputViewAnimationStartingEventArgs_Vtbl)).Occlusions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewAnimationStartingEventArgs", "Handled", 7usize, |this| ((**(this as *const *const ICoreInputViewAnimationStartingEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewAnimationStartingEventArgs", "SetHandled", 8usize, |this| ((**(this as *const *const ICoreInputViewAnimationStartingEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnimationDuration(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewAnimationStartingEventArgs", "AnimationDuration", 9usize, |this| ((**(this as *const *const ICoreInputViewAnimationStartingEventArgs_Vtbl)).AnimationDuration)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Occlusions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Occlusions: 0,
+            Handled,
+            SetHandled,
+            AnimationDuration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputViewAnimationStartingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreInputViewHidingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryCancel(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewHidingEventArgs", "TryCancel", 6usize, |this| ((**(this as *const *const ICoreInputViewHidingEventArgs_Vtbl)).TryCancel)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryCancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputViewHidingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreInputViewOcclusion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OccludingRect(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewOcclusion", "OccludingRect", 6usize, |this| ((**(this as *const *const ICoreInputViewOcclusion_Vtbl)).OccludingRect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OcclusionKind(this: *mut core::ffi::c_void, result__: *mut CoreInputViewOcclusionKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewOcclusion", "OcclusionKind", 7usize, |this| ((**(this as *const *const ICoreInputViewOcclusion_Vtbl)).OcclusionKind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OccludingRect, OcclusionKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputViewOcclusion as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for ICoreInputViewOcclusionsChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Occlusions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewOcclusionsChangedEventArgs", "Occlusions", 6usize, |this| ((**(this as *const *const ICoreInputViewOcclusionsChangedEventArgs_Vtbl)).Occlusions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewOcclusionsChangedEventArgs", "Handled", 7usize, |this| ((**(this as *const *const ICoreInputViewOcclusionsChangedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewOcclusionsChangedEventArgs", "SetHandled", 8usize, |this| ((**(this as *const *const ICoreInputViewOcclusionsChangedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Occlusions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Occlusions: 0,
+            Handled,
+            SetHandled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputViewOcclusionsChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreInputViewShowingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryCancel(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewShowingEventArgs", "TryCancel", 6usize, |this| ((**(this as *const *const ICoreInputViewShowingEventArgs_Vtbl)).TryCancel)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryCancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputViewShowingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreInputViewStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const ICoreInputViewStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputViewStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreInputViewStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForUIContext(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewStatics2", "GetForUIContext", 6usize, |this| ((**(this as *const *const ICoreInputViewStatics2_Vtbl)).GetForUIContext)(this, context, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForUIContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputViewStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreInputViewTransferringXYFocusEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Origin(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewTransferringXYFocusEventArgs", "Origin", 6usize, |this| ((**(this as *const *const ICoreInputViewTransferringXYFocusEventArgs_Vtbl)).Origin)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Direction(this: *mut core::ffi::c_void, result__: *mut CoreInputViewXYFocusTransferDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewTransferringXYFocusEventArgs", "Direction", 7usize, |this| ((**(this as *const *const ICoreInputViewTransferringXYFocusEventArgs_Vtbl)).Direction)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransferHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewTransferringXYFocusEventArgs", "SetTransferHandled", 8usize, |this| ((**(this as *const *const ICoreInputViewTransferringXYFocusEventArgs_Vtbl)).SetTransferHandled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferHandled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewTransferringXYFocusEventArgs", "TransferHandled", 9usize, |this| ((**(this as *const *const ICoreInputViewTransferringXYFocusEventArgs_Vtbl)).TransferHandled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeepPrimaryViewVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewTransferringXYFocusEventArgs", "SetKeepPrimaryViewVisible", 10usize, |this| ((**(this as *const *const ICoreInputViewTransferringXYFocusEventArgs_Vtbl)).SetKeepPrimaryViewVisible)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeepPrimaryViewVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputViewTransferringXYFocusEventArgs", "KeepPrimaryViewVisible", 11usize, |this| ((**(this as *const *const ICoreInputViewTransferringXYFocusEventArgs_Vtbl)).KeepPrimaryViewVisible)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Origin,
+            Direction,
+            SetTransferHandled,
+            TransferHandled,
+            SetKeepPrimaryViewVisible,
+            KeepPrimaryViewVisible,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputViewTransferringXYFocusEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUISettingsController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAdvancedEffectsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettingsController", "SetAdvancedEffectsEnabled", 6usize, |this| ((**(this as *const *const IUISettingsController_Vtbl)).SetAdvancedEffectsEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAnimationsEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettingsController", "SetAnimationsEnabled", 7usize, |this| ((**(this as *const *const IUISettingsController_Vtbl)).SetAnimationsEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoHideScrollBars(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettingsController", "SetAutoHideScrollBars", 8usize, |this| ((**(this as *const *const IUISettingsController_Vtbl)).SetAutoHideScrollBars)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageDuration(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettingsController", "SetMessageDuration", 9usize, |this| ((**(this as *const *const IUISettingsController_Vtbl)).SetMessageDuration)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextScaleFactor(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettingsController", "SetTextScaleFactor", 10usize, |this| ((**(this as *const *const IUISettingsController_Vtbl)).SetTextScaleFactor)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAdvancedEffectsEnabled,
+            SetAnimationsEnabled,
+            SetAutoHideScrollBars,
+            SetMessageDuration,
+            SetTextScaleFactor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUISettingsController as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUISettingsControllerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestDefaultAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettingsControllerStatics", "RequestDefaultAsync", 6usize, |this| ((**(this as *const *const IUISettingsControllerStatics_Vtbl)).RequestDefaultAsync)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestDefaultAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUISettingsControllerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -2225,6 +2225,7 @@ impl ApplicationViewBoundsMode {
 impl windows_core::TypeKind for ApplicationViewBoundsMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ApplicationViewBoundsMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ApplicationViewBoundsMode").field(&self.0).finish()
@@ -2243,6 +2244,7 @@ impl ApplicationViewMode {
 impl windows_core::TypeKind for ApplicationViewMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ApplicationViewMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ApplicationViewMode").field(&self.0).finish()
@@ -2261,6 +2263,7 @@ impl ApplicationViewOrientation {
 impl windows_core::TypeKind for ApplicationViewOrientation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ApplicationViewOrientation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ApplicationViewOrientation").field(&self.0).finish()
@@ -2285,6 +2288,7 @@ impl windows_core::TypeKind for ApplicationViewState {
     type TypeKind = windows_core::CopyType;
 }
 #[cfg(feature = "deprecated")]
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ApplicationViewState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ApplicationViewState").field(&self.0).finish()
@@ -2305,6 +2309,7 @@ impl ApplicationViewSwitchingOptions {
 impl windows_core::TypeKind for ApplicationViewSwitchingOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ApplicationViewSwitchingOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ApplicationViewSwitchingOptions").field(&self.0).finish()
@@ -2359,6 +2364,7 @@ impl ApplicationViewWindowingMode {
 impl windows_core::TypeKind for ApplicationViewWindowingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ApplicationViewWindowingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ApplicationViewWindowingMode").field(&self.0).finish()
@@ -2377,6 +2383,7 @@ impl FullScreenSystemOverlayMode {
 impl windows_core::TypeKind for FullScreenSystemOverlayMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FullScreenSystemOverlayMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FullScreenSystemOverlayMode").field(&self.0).finish()
@@ -2395,6 +2402,7 @@ impl HandPreference {
 impl windows_core::TypeKind for HandPreference {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HandPreference {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HandPreference").field(&self.0).finish()
@@ -2413,6 +2421,7 @@ impl ScreenCaptureDisabledBehavior {
 impl windows_core::TypeKind for ScreenCaptureDisabledBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ScreenCaptureDisabledBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ScreenCaptureDisabledBehavior").field(&self.0).finish()
@@ -2439,6 +2448,7 @@ impl UIColorType {
 impl windows_core::TypeKind for UIColorType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIColorType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIColorType").field(&self.0).finish()
@@ -2481,6 +2491,7 @@ impl UIElementType {
 impl windows_core::TypeKind for UIElementType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIElementType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIElementType").field(&self.0).finish()
@@ -2499,6 +2510,7 @@ impl UserInteractionMode {
 impl windows_core::TypeKind for UserInteractionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UserInteractionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UserInteractionMode").field(&self.0).finish()
@@ -2522,6 +2534,7 @@ impl ViewSizePreference {
 impl windows_core::TypeKind for ViewSizePreference {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ViewSizePreference {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ViewSizePreference").field(&self.0).finish()
@@ -2530,3 +2543,5 @@ impl core::fmt::Debug for ViewSizePreference {
 impl windows_core::RuntimeType for ViewSizePreference {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.ViewManagement.ViewSizePreference;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1227 @@
+impl windows_core::imp::ProxyVtable for IAccessibilitySettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HighContrast(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibilitySettings", "HighContrast", 6usize, |this| ((**(this as *const *const IAccessibilitySettings_Vtbl)).HighContrast)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HighContrastScheme(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibilitySettings", "HighContrastScheme", 7usize, |this| ((**(this as *const *const IAccessibilitySettings_Vtbl)).HighContrastScheme)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HighContrastChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibilitySettings", "HighContrastChanged", 8usize, |this| ((**(this as *const *const IAccessibilitySettings_Vtbl)).HighContrastChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHighContrastChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibilitySettings", "RemoveHighContrastChanged", 9usize, |this| ((**(this as *const *const IAccessibilitySettings_Vtbl)).RemoveHighContrastChanged)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HighContrast,
+            HighContrastScheme,
+            HighContrastChanged,
+            RemoveHighContrastChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccessibilitySettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActivationViewSwitcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowAsStandaloneAsync(this: *mut core::ffi::c_void, viewid: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationViewSwitcher", "ShowAsStandaloneAsync", 6usize, |this| ((**(this as *const *const IActivationViewSwitcher_Vtbl)).ShowAsStandaloneAsync)(this, viewid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAsStandaloneWithSizePreferenceAsync(this: *mut core::ffi::c_void, viewid: i32, sizepreference: ViewSizePreference, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationViewSwitcher", "ShowAsStandaloneWithSizePreferenceAsync", 7usize, |this| ((**(this as *const *const IActivationViewSwitcher_Vtbl)).ShowAsStandaloneWithSizePreferenceAsync)(this, viewid, sizepreference, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsViewPresentedOnActivationVirtualDesktop(this: *mut core::ffi::c_void, viewid: i32, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationViewSwitcher", "IsViewPresentedOnActivationVirtualDesktop", 8usize, |this| ((**(this as *const *const IActivationViewSwitcher_Vtbl)).IsViewPresentedOnActivationVirtualDesktop)(this, viewid, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ShowAsStandaloneAsync,
+            ShowAsStandaloneWithSizePreferenceAsync,
+            IsViewPresentedOnActivationVirtualDesktop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivationViewSwitcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Orientation(this: *mut core::ffi::c_void, result__: *mut ApplicationViewOrientation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView", "Orientation", 6usize, |this| ((**(this as *const *const IApplicationView_Vtbl)).Orientation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdjacentToLeftDisplayEdge(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView", "AdjacentToLeftDisplayEdge", 7usize, |this| ((**(this as *const *const IApplicationView_Vtbl)).AdjacentToLeftDisplayEdge)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdjacentToRightDisplayEdge(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView", "AdjacentToRightDisplayEdge", 8usize, |this| ((**(this as *const *const IApplicationView_Vtbl)).AdjacentToRightDisplayEdge)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn IsFullScreen(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView", "IsFullScreen", 9usize, |this| ((**(this as *const *const IApplicationView_Vtbl)).IsFullScreen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOnLockScreen(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView", "IsOnLockScreen", 10usize, |this| ((**(this as *const *const IApplicationView_Vtbl)).IsOnLockScreen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsScreenCaptureEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView", "IsScreenCaptureEnabled", 11usize, |this| ((**(this as *const *const IApplicationView_Vtbl)).IsScreenCaptureEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsScreenCaptureEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView", "SetIsScreenCaptureEnabled", 12usize, |this| ((**(this as *const *const IApplicationView_Vtbl)).SetIsScreenCaptureEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView", "SetTitle", 13usize, |this| ((**(this as *const *const IApplicationView_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView", "Title", 14usize, |this| ((**(this as *const *const IApplicationView_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView", "Id", 15usize, |this| ((**(this as *const *const IApplicationView_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Consolidated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView", "Consolidated", 16usize, |this| ((**(this as *const *const IApplicationView_Vtbl)).Consolidated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConsolidated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView", "RemoveConsolidated", 17usize, |this| ((**(this as *const *const IApplicationView_Vtbl)).RemoveConsolidated)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Orientation,
+            AdjacentToLeftDisplayEdge,
+            AdjacentToRightDisplayEdge,
+            #[cfg(feature = "deprecated")]
+            IsFullScreen,
+            #[cfg(not(feature = "deprecated"))]
+            IsFullScreen: 0,
+            IsOnLockScreen,
+            IsScreenCaptureEnabled,
+            SetIsScreenCaptureEnabled,
+            SetTitle,
+            Title,
+            Id,
+            Consolidated,
+            RemoveConsolidated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationView as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationView2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SuppressSystemOverlays(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView2", "SuppressSystemOverlays", 6usize, |this| ((**(this as *const *const IApplicationView2_Vtbl)).SuppressSystemOverlays)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn SetSuppressSystemOverlays(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView2", "SetSuppressSystemOverlays", 7usize, |this| ((**(this as *const *const IApplicationView2_Vtbl)).SetSuppressSystemOverlays)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VisibleBounds(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView2", "VisibleBounds", 8usize, |this| ((**(this as *const *const IApplicationView2_Vtbl)).VisibleBounds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn VisibleBoundsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView2", "VisibleBoundsChanged", 9usize, |this| ((**(this as *const *const IApplicationView2_Vtbl)).VisibleBoundsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVisibleBoundsChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView2", "RemoveVisibleBoundsChanged", 10usize, |this| ((**(this as *const *const IApplicationView2_Vtbl)).RemoveVisibleBoundsChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredBoundsMode(this: *mut core::ffi::c_void, boundsmode: ApplicationViewBoundsMode, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView2", "SetDesiredBoundsMode", 11usize, |this| ((**(this as *const *const IApplicationView2_Vtbl)).SetDesiredBoundsMode)(this, boundsmode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredBoundsMode(this: *mut core::ffi::c_void, result__: *mut ApplicationViewBoundsMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView2", "DesiredBoundsMode", 12usize, |this| ((**(this as *const *const IApplicationView2_Vtbl)).DesiredBoundsMode)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            SuppressSystemOverlays,
+            #[cfg(not(feature = "deprecated"))]
+            SuppressSystemOverlays: 0,
+            #[cfg(feature = "deprecated")]
+            SetSuppressSystemOverlays,
+            #[cfg(not(feature = "deprecated"))]
+            SetSuppressSystemOverlays: 0,
+            VisibleBounds,
+            VisibleBoundsChanged,
+            RemoveVisibleBoundsChanged,
+            SetDesiredBoundsMode,
+            DesiredBoundsMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationView2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationView3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TitleBar(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView3", "TitleBar", 6usize, |this| ((**(this as *const *const IApplicationView3_Vtbl)).TitleBar)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FullScreenSystemOverlayMode(this: *mut core::ffi::c_void, result__: *mut FullScreenSystemOverlayMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView3", "FullScreenSystemOverlayMode", 7usize, |this| ((**(this as *const *const IApplicationView3_Vtbl)).FullScreenSystemOverlayMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFullScreenSystemOverlayMode(this: *mut core::ffi::c_void, value: FullScreenSystemOverlayMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView3", "SetFullScreenSystemOverlayMode", 8usize, |this| ((**(this as *const *const IApplicationView3_Vtbl)).SetFullScreenSystemOverlayMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFullScreenMode(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView3", "IsFullScreenMode", 9usize, |this| ((**(this as *const *const IApplicationView3_Vtbl)).IsFullScreenMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryEnterFullScreenMode(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView3", "TryEnterFullScreenMode", 10usize, |this| ((**(this as *const *const IApplicationView3_Vtbl)).TryEnterFullScreenMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExitFullScreenMode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView3", "ExitFullScreenMode", 11usize, |this| ((**(this as *const *const IApplicationView3_Vtbl)).ExitFullScreenMode)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowStandardSystemOverlays(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView3", "ShowStandardSystemOverlays", 12usize, |this| ((**(this as *const *const IApplicationView3_Vtbl)).ShowStandardSystemOverlays)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryResizeView(this: *mut core::ffi::c_void, value: super::super::Foundation::Size, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView3", "TryResizeView", 13usize, |this| ((**(this as *const *const IApplicationView3_Vtbl)).TryResizeView)(this, value, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredMinSize(this: *mut core::ffi::c_void, minsize: super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView3", "SetPreferredMinSize", 14usize, |this| ((**(this as *const *const IApplicationView3_Vtbl)).SetPreferredMinSize)(this, minsize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TitleBar,
+            FullScreenSystemOverlayMode,
+            SetFullScreenSystemOverlayMode,
+            IsFullScreenMode,
+            TryEnterFullScreenMode,
+            ExitFullScreenMode,
+            ShowStandardSystemOverlays,
+            TryResizeView,
+            SetPreferredMinSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationView3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationView4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ViewMode(this: *mut core::ffi::c_void, result__: *mut ApplicationViewMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView4", "ViewMode", 6usize, |this| ((**(this as *const *const IApplicationView4_Vtbl)).ViewMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsViewModeSupported(this: *mut core::ffi::c_void, viewmode: ApplicationViewMode, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView4", "IsViewModeSupported", 7usize, |this| ((**(this as *const *const IApplicationView4_Vtbl)).IsViewModeSupported)(this, viewmode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryEnterViewModeAsync(this: *mut core::ffi::c_void, viewmode: ApplicationViewMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView4", "TryEnterViewModeAsync", 8usize, |this| ((**(this as *const *const IApplicationView4_Vtbl)).TryEnterViewModeAsync)(this, viewmode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryEnterViewModeWithPreferencesAsync(this: *mut core::ffi::c_void, viewmode: ApplicationViewMode, viewmodepreferences: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView4", "TryEnterViewModeWithPreferencesAsync", 9usize, |this| ((**(this as *const *const IApplicationView4_Vtbl)).TryEnterViewModeWithPreferencesAsync)(this, viewmode, viewmodepreferences, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryConsolidateAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView4", "TryConsolidateAsync", 10usize, |this| ((**(this as *const *const IApplicationView4_Vtbl)).TryConsolidateAsync)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ViewMode,
+            IsViewModeSupported,
+            TryEnterViewModeAsync,
+            TryEnterViewModeWithPreferencesAsync,
+            TryConsolidateAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationView4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationView7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PersistedStateId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView7", "PersistedStateId", 6usize, |this| ((**(this as *const *const IApplicationView7_Vtbl)).PersistedStateId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPersistedStateId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView7", "SetPersistedStateId", 7usize, |this| ((**(this as *const *const IApplicationView7_Vtbl)).SetPersistedStateId)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PersistedStateId, SetPersistedStateId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationView7 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "UI_WindowManagement"))]
+impl windows_core::imp::ProxyVtable for IApplicationView9_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_WindowManagement")]
+        unsafe extern "system" fn WindowingEnvironment(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView9", "WindowingEnvironment", 6usize, |this| ((**(this as *const *const IApplicationView9_Vtbl)).WindowingEnvironment)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "UI_WindowManagement"))]
+        unsafe extern "system" fn GetDisplayRegions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationView9", "GetDisplayRegions", 7usize, |this| ((**(this as *const *const IApplicationView9_Vtbl)).GetDisplayRegions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_WindowManagement")]
+            WindowingEnvironment,
+            #[cfg(not(feature = "UI_WindowManagement"))]
+            WindowingEnvironment: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "UI_WindowManagement"))]
+            GetDisplayRegions,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "UI_WindowManagement")))]
+            GetDisplayRegions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationView9 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationViewConsolidatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsUserInitiated(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewConsolidatedEventArgs", "IsUserInitiated", 6usize, |this| ((**(this as *const *const IApplicationViewConsolidatedEventArgs_Vtbl)).IsUserInitiated)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsUserInitiated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewConsolidatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationViewConsolidatedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAppInitiated(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewConsolidatedEventArgs2", "IsAppInitiated", 6usize, |this| ((**(this as *const *const IApplicationViewConsolidatedEventArgs2_Vtbl)).IsAppInitiated)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsAppInitiated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewConsolidatedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IApplicationViewFullscreenStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TryUnsnapToFullscreen(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewFullscreenStatics", "TryUnsnapToFullscreen", 6usize, |this| ((**(this as *const *const IApplicationViewFullscreenStatics_Vtbl)).TryUnsnapToFullscreen)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            TryUnsnapToFullscreen,
+            #[cfg(not(feature = "deprecated"))]
+            TryUnsnapToFullscreen: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewFullscreenStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Core")]
+impl windows_core::imp::ProxyVtable for IApplicationViewInteropStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn GetApplicationViewIdForWindow(this: *mut core::ffi::c_void, window: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewInteropStatics", "GetApplicationViewIdForWindow", 6usize, |this| ((**(this as *const *const IApplicationViewInteropStatics_Vtbl)).GetApplicationViewIdForWindow)(this, window, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Core")]
+            GetApplicationViewIdForWindow,
+            #[cfg(not(feature = "UI_Core"))]
+            GetApplicationViewIdForWindow: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewInteropStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationViewScaling_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewScaling as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationViewScalingStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisableLayoutScaling(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewScalingStatics", "DisableLayoutScaling", 6usize, |this| ((**(this as *const *const IApplicationViewScalingStatics_Vtbl)).DisableLayoutScaling)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySetDisableLayoutScaling(this: *mut core::ffi::c_void, disablelayoutscaling: bool, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewScalingStatics", "TrySetDisableLayoutScaling", 7usize, |this| ((**(this as *const *const IApplicationViewScalingStatics_Vtbl)).TrySetDisableLayoutScaling)(this, disablelayoutscaling, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisableLayoutScaling, TrySetDisableLayoutScaling }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewScalingStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "deprecated")]
+impl windows_core::imp::ProxyVtable for IApplicationViewStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut ApplicationViewState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewStatics", "Value", 6usize, |this| ((**(this as *const *const IApplicationViewStatics_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "deprecated")]
+        unsafe extern "system" fn TryUnsnap(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewStatics", "TryUnsnap", 7usize, |this| ((**(this as *const *const IApplicationViewStatics_Vtbl)).TryUnsnap)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "deprecated")]
+            Value,
+            #[cfg(not(feature = "deprecated"))]
+            Value: 0,
+            #[cfg(feature = "deprecated")]
+            TryUnsnap,
+            #[cfg(not(feature = "deprecated"))]
+            TryUnsnap: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationViewStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewStatics2", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IApplicationViewStatics2_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminateAppOnFinalViewClose(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewStatics2", "TerminateAppOnFinalViewClose", 7usize, |this| ((**(this as *const *const IApplicationViewStatics2_Vtbl)).TerminateAppOnFinalViewClose)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTerminateAppOnFinalViewClose(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewStatics2", "SetTerminateAppOnFinalViewClose", 8usize, |this| ((**(this as *const *const IApplicationViewStatics2_Vtbl)).SetTerminateAppOnFinalViewClose)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetForCurrentView,
+            TerminateAppOnFinalViewClose,
+            SetTerminateAppOnFinalViewClose,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationViewStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PreferredLaunchWindowingMode(this: *mut core::ffi::c_void, result__: *mut ApplicationViewWindowingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewStatics3", "PreferredLaunchWindowingMode", 6usize, |this| ((**(this as *const *const IApplicationViewStatics3_Vtbl)).PreferredLaunchWindowingMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredLaunchWindowingMode(this: *mut core::ffi::c_void, value: ApplicationViewWindowingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewStatics3", "SetPreferredLaunchWindowingMode", 7usize, |this| ((**(this as *const *const IApplicationViewStatics3_Vtbl)).SetPreferredLaunchWindowingMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredLaunchViewSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewStatics3", "PreferredLaunchViewSize", 8usize, |this| ((**(this as *const *const IApplicationViewStatics3_Vtbl)).PreferredLaunchViewSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredLaunchViewSize(this: *mut core::ffi::c_void, value: super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewStatics3", "SetPreferredLaunchViewSize", 9usize, |this| ((**(this as *const *const IApplicationViewStatics3_Vtbl)).SetPreferredLaunchViewSize)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PreferredLaunchWindowingMode,
+            SetPreferredLaunchWindowingMode,
+            PreferredLaunchViewSize,
+            SetPreferredLaunchViewSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationViewStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClearAllPersistedState(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewStatics4", "ClearAllPersistedState", 6usize, |this| ((**(this as *const *const IApplicationViewStatics4_Vtbl)).ClearAllPersistedState)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearPersistedState(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewStatics4", "ClearPersistedState", 7usize, |this| ((**(this as *const *const IApplicationViewStatics4_Vtbl)).ClearPersistedState)(this, key), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ClearAllPersistedState, ClearPersistedState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationViewSwitcherStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisableShowingMainViewOnActivation(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewSwitcherStatics", "DisableShowingMainViewOnActivation", 6usize, |this| ((**(this as *const *const IApplicationViewSwitcherStatics_Vtbl)).DisableShowingMainViewOnActivation)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryShowAsStandaloneAsync(this: *mut core::ffi::c_void, viewid: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewSwitcherStatics", "TryShowAsStandaloneAsync", 7usize, |this| ((**(this as *const *const IApplicationViewSwitcherStatics_Vtbl)).TryShowAsStandaloneAsync)(this, viewid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryShowAsStandaloneWithSizePreferenceAsync(this: *mut core::ffi::c_void, viewid: i32, sizepreference: ViewSizePreference, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewSwitcherStatics", "TryShowAsStandaloneWithSizePreferenceAsync", 8usize, |this| ((**(this as *const *const IApplicationViewSwitcherStatics_Vtbl)).TryShowAsStandaloneWithSizePreferenceAsync)(this, viewid, sizepreference, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryShowAsStandaloneWithAnchorViewAndSizePreferenceAsync(this: *mut core::ffi::c_void, viewid: i32, sizepreference: ViewSizePreference, anchorviewid: i32, anchorsizepreference: ViewSizePreference, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewSwitcherStatics", "TryShowAsStandaloneWithAnchorViewAndSizePreferenceAsync", 9usize, |this| ((**(this as *const *const IApplicationViewSwitcherStatics_Vtbl)).TryShowAsStandaloneWithAnchorViewAndSizePreferenceAsync)(this, viewid, sizepreference, anchorviewid, anchorsizepreference, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwitchAsync(this: *mut core::ffi::c_void, viewid: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewSwitcherStatics", "SwitchAsync", 10usize, |this| ((**(this as *const *const IApplicationViewSwitcherStatics_Vtbl)).SwitchAsync)(this, viewid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwitchFromViewAsync(this: *mut core::ffi::c_void, toviewid: i32, fromviewid: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewSwitcherStatics", "SwitchFromViewAsync", 11usize, |this| ((**(this as *const *const IApplicationViewSwitcherStatics_Vtbl)).SwitchFromViewAsync)(this, toviewid, fromviewid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwitchFromViewWithOptionsAsync(this: *mut core::ffi::c_void, toviewid: i32, fromviewid: i32, options: ApplicationViewSwitchingOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewSwitcherStatics", "SwitchFromViewWithOptionsAsync", 12usize, |this| ((**(this as *const *const IApplicationViewSwitcherStatics_Vtbl)).SwitchFromViewWithOptionsAsync)(this, toviewid, fromviewid, options, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrepareForCustomAnimatedSwitchAsync(this: *mut core::ffi::c_void, toviewid: i32, fromviewid: i32, options: ApplicationViewSwitchingOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewSwitcherStatics", "PrepareForCustomAnimatedSwitchAsync", 13usize, |this| ((**(this as *const *const IApplicationViewSwitcherStatics_Vtbl)).PrepareForCustomAnimatedSwitchAsync)(this, toviewid, fromviewid, options, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisableShowingMainViewOnActivation,
+            TryShowAsStandaloneAsync,
+            TryShowAsStandaloneWithSizePreferenceAsync,
+            TryShowAsStandaloneWithAnchorViewAndSizePreferenceAsync,
+            SwitchAsync,
+            SwitchFromViewAsync,
+            SwitchFromViewWithOptionsAsync,
+            PrepareForCustomAnimatedSwitchAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewSwitcherStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationViewSwitcherStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisableSystemViewActivationPolicy(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewSwitcherStatics2", "DisableSystemViewActivationPolicy", 6usize, |this| ((**(this as *const *const IApplicationViewSwitcherStatics2_Vtbl)).DisableSystemViewActivationPolicy)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisableSystemViewActivationPolicy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewSwitcherStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationViewSwitcherStatics3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryShowAsViewModeAsync(this: *mut core::ffi::c_void, viewid: i32, viewmode: ApplicationViewMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewSwitcherStatics3", "TryShowAsViewModeAsync", 6usize, |this| ((**(this as *const *const IApplicationViewSwitcherStatics3_Vtbl)).TryShowAsViewModeAsync)(this, viewid, viewmode, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryShowAsViewModeWithPreferencesAsync(this: *mut core::ffi::c_void, viewid: i32, viewmode: ApplicationViewMode, viewmodepreferences: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewSwitcherStatics3", "TryShowAsViewModeWithPreferencesAsync", 7usize, |this| ((**(this as *const *const IApplicationViewSwitcherStatics3_Vtbl)).TryShowAsViewModeWithPreferencesAsync)(this, viewid, viewmode, viewmodepreferences, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TryShowAsViewModeAsync,
+            TryShowAsViewModeWithPreferencesAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewSwitcherStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationViewTitleBar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetForegroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "SetForegroundColor", 6usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).SetForegroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForegroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "ForegroundColor", 7usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).ForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackgroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "SetBackgroundColor", 8usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).SetBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "BackgroundColor", 9usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).BackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonForegroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "SetButtonForegroundColor", 10usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).SetButtonForegroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonForegroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "ButtonForegroundColor", 11usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).ButtonForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonBackgroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "SetButtonBackgroundColor", 12usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).SetButtonBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonBackgroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "ButtonBackgroundColor", 13usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).ButtonBackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonHoverForegroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "SetButtonHoverForegroundColor", 14usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).SetButtonHoverForegroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonHoverForegroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "ButtonHoverForegroundColor", 15usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).ButtonHoverForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonHoverBackgroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "SetButtonHoverBackgroundColor", 16usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).SetButtonHoverBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonHoverBackgroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "ButtonHoverBackgroundColor", 17usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).ButtonHoverBackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonPressedForegroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "SetButtonPressedForegroundColor", 18usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).SetButtonPressedForegroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonPressedForegroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "ButtonPressedForegroundColor", 19usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).ButtonPressedForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonPressedBackgroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "SetButtonPressedBackgroundColor", 20usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).SetButtonPressedBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonPressedBackgroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "ButtonPressedBackgroundColor", 21usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).ButtonPressedBackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInactiveForegroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "SetInactiveForegroundColor", 22usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).SetInactiveForegroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InactiveForegroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "InactiveForegroundColor", 23usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).InactiveForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInactiveBackgroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "SetInactiveBackgroundColor", 24usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).SetInactiveBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InactiveBackgroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "InactiveBackgroundColor", 25usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).InactiveBackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonInactiveForegroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "SetButtonInactiveForegroundColor", 26usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).SetButtonInactiveForegroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonInactiveForegroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "ButtonInactiveForegroundColor", 27usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).ButtonInactiveForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonInactiveBackgroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "SetButtonInactiveBackgroundColor", 28usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).SetButtonInactiveBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonInactiveBackgroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTitleBar", "ButtonInactiveBackgroundColor", 29usize, |this| ((**(this as *const *const IApplicationViewTitleBar_Vtbl)).ButtonInactiveBackgroundColor)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetForegroundColor,
+            ForegroundColor,
+            SetBackgroundColor,
+            BackgroundColor,
+            SetButtonForegroundColor,
+            ButtonForegroundColor,
+            SetButtonBackgroundColor,
+            ButtonBackgroundColor,
+            SetButtonHoverForegroundColor,
+            ButtonHoverForegroundColor,
+            SetButtonHoverBackgroundColor,
+            ButtonHoverBackgroundColor,
+            SetButtonPressedForegroundColor,
+            ButtonPressedForegroundColor,
+            SetButtonPressedBackgroundColor,
+            ButtonPressedBackgroundColor,
+            SetInactiveForegroundColor,
+            InactiveForegroundColor,
+            SetInactiveBackgroundColor,
+            InactiveBackgroundColor,
+            SetButtonInactiveForegroundColor,
+            ButtonInactiveForegroundColor,
+            SetButtonInactiveBackgroundColor,
+            ButtonInactiveBackgroundColor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewTitleBar as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationViewTransferContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ViewId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTransferContext", "ViewId", 6usize, |this| ((**(this as *const *const IApplicationViewTransferContext_Vtbl)).ViewId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewId(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTransferContext", "SetViewId", 7usize, |this| ((**(this as *const *const IApplicationViewTransferContext_Vtbl)).SetViewId)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ViewId, SetViewId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewTransferContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationViewTransferContextStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DataPackageFormatId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewTransferContextStatics", "DataPackageFormatId", 6usize, |this| ((**(this as *const *const IApplicationViewTransferContextStatics_Vtbl)).DataPackageFormatId)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DataPackageFormatId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewTransferContextStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationViewWithContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UIContext(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationViewWithContext", "UIContext", 6usize, |this| ((**(this as *const *const IApplicationViewWithContext_Vtbl)).UIContext)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UIContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationViewWithContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInputPane_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Showing(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPane", "Showing", 6usize, |this| ((**(this as *const *const IInputPane_Vtbl)).Showing)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveShowing(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPane", "RemoveShowing", 7usize, |this| ((**(this as *const *const IInputPane_Vtbl)).RemoveShowing)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hiding(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPane", "Hiding", 8usize, |this| ((**(this as *const *const IInputPane_Vtbl)).Hiding)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHiding(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPane", "RemoveHiding", 9usize, |this| ((**(this as *const *const IInputPane_Vtbl)).RemoveHiding)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn OccludedRect(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPane", "OccludedRect", 10usize, |this| ((**(this as *const *const IInputPane_Vtbl)).OccludedRect)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Showing, RemoveShowing, Hiding, RemoveHiding, OccludedRect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputPane as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInputPane2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryShow(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPane2", "TryShow", 6usize, |this| ((**(this as *const *const IInputPane2_Vtbl)).TryShow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryHide(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPane2", "TryHide", 7usize, |this| ((**(this as *const *const IInputPane2_Vtbl)).TryHide)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryShow, TryHide }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputPane2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInputPaneControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Visible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPaneControl", "Visible", 6usize, |this| ((**(this as *const *const IInputPaneControl_Vtbl)).Visible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPaneControl", "SetVisible", 7usize, |this| ((**(this as *const *const IInputPaneControl_Vtbl)).SetVisible)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Visible, SetVisible }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputPaneControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInputPaneStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPaneStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IInputPaneStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputPaneStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInputPaneStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForUIContext(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPaneStatics2", "GetForUIContext", 6usize, |this| ((**(this as *const *const IInputPaneStatics2_Vtbl)).GetForUIContext)(this, context, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForUIContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputPaneStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInputPaneVisibilityEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OccludedRect(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPaneVisibilityEventArgs", "OccludedRect", 6usize, |this| ((**(this as *const *const IInputPaneVisibilityEventArgs_Vtbl)).OccludedRect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnsuredFocusedElementInView(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPaneVisibilityEventArgs", "SetEnsuredFocusedElementInView", 7usize, |this| ((**(this as *const *const IInputPaneVisibilityEventArgs_Vtbl)).SetEnsuredFocusedElementInView)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnsuredFocusedElementInView(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPaneVisibilityEventArgs", "EnsuredFocusedElementInView", 8usize, |this| ((**(this as *const *const IInputPaneVisibilityEventArgs_Vtbl)).EnsuredFocusedElementInView)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OccludedRect,
+            SetEnsuredFocusedElementInView,
+            EnsuredFocusedElementInView,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputPaneVisibilityEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProjectionManagerStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartProjectingAsync(this: *mut core::ffi::c_void, projectionviewid: i32, anchorviewid: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProjectionManagerStatics", "StartProjectingAsync", 6usize, |this| ((**(this as *const *const IProjectionManagerStatics_Vtbl)).StartProjectingAsync)(this, projectionviewid, anchorviewid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwapDisplaysForViewsAsync(this: *mut core::ffi::c_void, projectionviewid: i32, anchorviewid: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProjectionManagerStatics", "SwapDisplaysForViewsAsync", 7usize, |this| ((**(this as *const *const IProjectionManagerStatics_Vtbl)).SwapDisplaysForViewsAsync)(this, projectionviewid, anchorviewid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopProjectingAsync(this: *mut core::ffi::c_void, projectionviewid: i32, anchorviewid: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProjectionManagerStatics", "StopProjectingAsync", 8usize, |this| ((**(this as *const *const IProjectionManagerStatics_Vtbl)).StopProjectingAsync)(this, projectionviewid, anchorviewid, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProjectionDisplayAvailable(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProjectionManagerStatics", "ProjectionDisplayAvailable", 9usize, |this| ((**(this as *const *const IProjectionManagerStatics_Vtbl)).ProjectionDisplayAvailable)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProjectionDisplayAvailableChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProjectionManagerStatics", "ProjectionDisplayAvailableChanged", 10usize, |this| ((**(this as *const *const IProjectionManagerStatics_Vtbl)).ProjectionDisplayAvailableChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProjectionDisplayAvailableChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProjectionManagerStatics", "RemoveProjectionDisplayAvailableChanged", 11usize, |this| ((**(this as *const *const IProjectionManagerStatics_Vtbl)).RemoveProjectionDisplayAvailableChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartProjectingAsync,
+            SwapDisplaysForViewsAsync,
+            StopProjectingAsync,
+            ProjectionDisplayAvailable,
+            ProjectionDisplayAvailableChanged,
+            RemoveProjectionDisplayAvailableChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProjectionManagerStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Devices_Enumeration", feature = "UI_Popups"))]
+impl windows_core::imp::ProxyVtable for IProjectionManagerStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Devices_Enumeration")]
+        unsafe extern "system" fn StartProjectingWithDeviceInfoAsync(this: *mut core::ffi::c_void, projectionviewid: i32, anchorviewid: i32, displaydeviceinfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProjectionManagerStatics2", "StartProjectingWithDeviceInfoAsync", 6usize, |this| ((**(this as *const *const IProjectionManagerStatics2_Vtbl)).StartProjectingWithDeviceInfoAsync)(this, projectionviewid, anchorviewid, displaydeviceinfo, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStartProjectingAsync(this: *mut core::ffi::c_void, projectionviewid: i32, anchorviewid: i32, selection: super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProjectionManagerStatics2", "RequestStartProjectingAsync", 7usize, |this| ((**(this as *const *const IProjectionManagerStatics2_Vtbl)).RequestStartProjectingAsync)(this, projectionviewid, anchorviewid, selection, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Popups")]
+        unsafe extern "system" fn RequestStartProjectingWithPlacementAsync(this: *mut core::ffi::c_void, projectionviewid: i32, anchorviewid: i32, selection: super::super::Foundation::Rect, prefferedplacement: super::Popups::Placement, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProjectionManagerStatics2", "RequestStartProjectingWithPlacementAsync", 8usize, |this| ((**(this as *const *const IProjectionManagerStatics2_Vtbl)).RequestStartProjectingWithPlacementAsync)(this, projectionviewid, anchorviewid, selection, prefferedplacement, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSelector(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProjectionManagerStatics2", "GetDeviceSelector", 9usize, |this| ((**(this as *const *const IProjectionManagerStatics2_Vtbl)).GetDeviceSelector)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Devices_Enumeration")]
+            StartProjectingWithDeviceInfoAsync,
+            #[cfg(not(feature = "Devices_Enumeration"))]
+            StartProjectingWithDeviceInfoAsync: 0,
+            RequestStartProjectingAsync,
+            #[cfg(feature = "UI_Popups")]
+            RequestStartProjectingWithPlacementAsync,
+            #[cfg(not(feature = "UI_Popups"))]
+            RequestStartProjectingWithPlacementAsync: 0,
+            GetDeviceSelector,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProjectionManagerStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStatusBar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBar", "ShowAsync", 6usize, |this| ((**(this as *const *const IStatusBar_Vtbl)).ShowAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HideAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBar", "HideAsync", 7usize, |this| ((**(this as *const *const IStatusBar_Vtbl)).HideAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundOpacity(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBar", "BackgroundOpacity", 8usize, |this| ((**(this as *const *const IStatusBar_Vtbl)).BackgroundOpacity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackgroundOpacity(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBar", "SetBackgroundOpacity", 9usize, |this| ((**(this as *const *const IStatusBar_Vtbl)).SetBackgroundOpacity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForegroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBar", "ForegroundColor", 10usize, |this| ((**(this as *const *const IStatusBar_Vtbl)).ForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForegroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBar", "SetForegroundColor", 11usize, |this| ((**(this as *const *const IStatusBar_Vtbl)).SetForegroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBar", "BackgroundColor", 12usize, |this| ((**(this as *const *const IStatusBar_Vtbl)).BackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackgroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBar", "SetBackgroundColor", 13usize, |this| ((**(this as *const *const IStatusBar_Vtbl)).SetBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProgressIndicator(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBar", "ProgressIndicator", 14usize, |this| ((**(this as *const *const IStatusBar_Vtbl)).ProgressIndicator)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OccludedRect(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBar", "OccludedRect", 15usize, |this| ((**(this as *const *const IStatusBar_Vtbl)).OccludedRect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Showing(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBar", "Showing", 16usize, |this| ((**(this as *const *const IStatusBar_Vtbl)).Showing)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveShowing(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBar", "RemoveShowing", 17usize, |this| ((**(this as *const *const IStatusBar_Vtbl)).RemoveShowing)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hiding(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBar", "Hiding", 18usize, |this| ((**(this as *const *const IStatusBar_Vtbl)).Hiding)(this, eventhandler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveHiding(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBar", "RemoveHiding", 19usize, |this| ((**(this as *const *const IStatusBar_Vtbl)).RemoveHiding)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ShowAsync,
+            HideAsync,
+            BackgroundOpacity,
+            SetBackgroundOpacity,
+            ForegroundColor,
+            SetForegroundColor,
+            BackgroundColor,
+            SetBackgroundColor,
+            ProgressIndicator,
+            OccludedRect,
+            Showing,
+            RemoveShowing,
+            Hiding,
+            RemoveHiding,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStatusBar as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStatusBarProgressIndicator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBarProgressIndicator", "ShowAsync", 6usize, |this| ((**(this as *const *const IStatusBarProgressIndicator_Vtbl)).ShowAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HideAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBarProgressIndicator", "HideAsync", 7usize, |this| ((**(this as *const *const IStatusBarProgressIndicator_Vtbl)).HideAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBarProgressIndicator", "Text", 8usize, |this| ((**(this as *const *const IStatusBarProgressIndicator_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBarProgressIndicator", "SetText", 9usize, |this| ((**(this as *const *const IStatusBarProgressIndicator_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProgressValue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBarProgressIndicator", "ProgressValue", 10usize, |this| ((**(this as *const *const IStatusBarProgressIndicator_Vtbl)).ProgressValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProgressValue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBarProgressIndicator", "SetProgressValue", 11usize, |this| ((**(this as *const *const IStatusBarProgressIndicator_Vtbl)).SetProgressValue)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ShowAsync,
+            HideAsync,
+            Text,
+            SetText,
+            ProgressValue,
+            SetProgressValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStatusBarProgressIndicator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStatusBarStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStatusBarStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IStatusBarStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStatusBarStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUISettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HandPreference(this: *mut core::ffi::c_void, result__: *mut HandPreference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings", "HandPreference", 6usize, |this| ((**(this as *const *const IUISettings_Vtbl)).HandPreference)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CursorSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings", "CursorSize", 7usize, |this| ((**(this as *const *const IUISettings_Vtbl)).CursorSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScrollBarSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings", "ScrollBarSize", 8usize, |this| ((**(this as *const *const IUISettings_Vtbl)).ScrollBarSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScrollBarArrowSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings", "ScrollBarArrowSize", 9usize, |this| ((**(this as *const *const IUISettings_Vtbl)).ScrollBarArrowSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScrollBarThumbBoxSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings", "ScrollBarThumbBoxSize", 10usize, |this| ((**(this as *const *const IUISettings_Vtbl)).ScrollBarThumbBoxSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageDuration(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings", "MessageDuration", 11usize, |this| ((**(this as *const *const IUISettings_Vtbl)).MessageDuration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnimationsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings", "AnimationsEnabled", 12usize, |this| ((**(this as *const *const IUISettings_Vtbl)).AnimationsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CaretBrowsingEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings", "CaretBrowsingEnabled", 13usize, |this| ((**(this as *const *const IUISettings_Vtbl)).CaretBrowsingEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CaretBlinkRate(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings", "CaretBlinkRate", 14usize, |this| ((**(this as *const *const IUISettings_Vtbl)).CaretBlinkRate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CaretWidth(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings", "CaretWidth", 15usize, |this| ((**(this as *const *const IUISettings_Vtbl)).CaretWidth)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoubleClickTime(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings", "DoubleClickTime", 16usize, |this| ((**(this as *const *const IUISettings_Vtbl)).DoubleClickTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MouseHoverTime(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings", "MouseHoverTime", 17usize, |this| ((**(this as *const *const IUISettings_Vtbl)).MouseHoverTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UIElementColor(this: *mut core::ffi::c_void, desiredelement: UIElementType, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings", "UIElementColor", 18usize, |this| ((**(this as *const *const IUISettings_Vtbl)).UIElementColor)(this, desiredelement, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HandPreference,
+            CursorSize,
+            ScrollBarSize,
+            ScrollBarArrowSize,
+            ScrollBarThumbBoxSize,
+            MessageDuration,
+            AnimationsEnabled,
+            CaretBrowsingEnabled,
+            CaretBlinkRate,
+            CaretWidth,
+            DoubleClickTime,
+            MouseHoverTime,
+            UIElementColor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUISettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUISettings2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TextScaleFactor(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings2", "TextScaleFactor", 6usize, |this| ((**(this as *const *const IUISettings2_Vtbl)).TextScaleFactor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextScaleFactorChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings2", "TextScaleFactorChanged", 7usize, |this| ((**(this as *const *const IUISettings2_Vtbl)).TextScaleFactorChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTextScaleFactorChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings2", "RemoveTextScaleFactorChanged", 8usize, |this| ((**(this as *const *const IUISettings2_Vtbl)).RemoveTextScaleFactorChanged)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TextScaleFactor,
+            TextScaleFactorChanged,
+            RemoveTextScaleFactorChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUISettings2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUISettings3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetColorValue(this: *mut core::ffi::c_void, desiredcolor: UIColorType, result__: *mut super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings3", "GetColorValue", 6usize, |this| ((**(this as *const *const IUISettings3_Vtbl)).GetColorValue)(this, desiredcolor, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorValuesChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings3", "ColorValuesChanged", 7usize, |this| ((**(this as *const *const IUISettings3_Vtbl)).ColorValuesChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveColorValuesChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings3", "RemoveColorValuesChanged", 8usize, |this| ((**(this as *const *const IUISettings3_Vtbl)).RemoveColorValuesChanged)(this, cookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetColorValue, ColorValuesChanged, RemoveColorValuesChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUISettings3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUISettings4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdvancedEffectsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings4", "AdvancedEffectsEnabled", 6usize, |this| ((**(this as *const *const IUISettings4_Vtbl)).AdvancedEffectsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdvancedEffectsEnabledChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings4", "AdvancedEffectsEnabledChanged", 7usize, |this| ((**(this as *const *const IUISettings4_Vtbl)).AdvancedEffectsEnabledChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAdvancedEffectsEnabledChanged(this: *mut core::ffi::c_void, cookie: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings4", "RemoveAdvancedEffectsEnabledChanged", 8usize, |this| ((**(this as *const *const IUISettings4_Vtbl)).RemoveAdvancedEffectsEnabledChanged)(this, cookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AdvancedEffectsEnabled,
+            AdvancedEffectsEnabledChanged,
+            RemoveAdvancedEffectsEnabledChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUISettings4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUISettings5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AutoHideScrollBars(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings5", "AutoHideScrollBars", 6usize, |this| ((**(this as *const *const IUISettings5_Vtbl)).AutoHideScrollBars)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoHideScrollBarsChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings5", "AutoHideScrollBarsChanged", 7usize, |this| ((**(this as *const *const IUISettings5_Vtbl)).AutoHideScrollBarsChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAutoHideScrollBarsChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings5", "RemoveAutoHideScrollBarsChanged", 8usize, |this| ((**(this as *const *const IUISettings5_Vtbl)).RemoveAutoHideScrollBarsChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AutoHideScrollBars,
+            AutoHideScrollBarsChanged,
+            RemoveAutoHideScrollBarsChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUISettings5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUISettings6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AnimationsEnabledChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings6", "AnimationsEnabledChanged", 6usize, |this| ((**(this as *const *const IUISettings6_Vtbl)).AnimationsEnabledChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAnimationsEnabledChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings6", "RemoveAnimationsEnabledChanged", 7usize, |this| ((**(this as *const *const IUISettings6_Vtbl)).RemoveAnimationsEnabledChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageDurationChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings6", "MessageDurationChanged", 8usize, |this| ((**(this as *const *const IUISettings6_Vtbl)).MessageDurationChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMessageDurationChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISettings6", "RemoveMessageDurationChanged", 9usize, |this| ((**(this as *const *const IUISettings6_Vtbl)).RemoveMessageDurationChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AnimationsEnabledChanged,
+            RemoveAnimationsEnabledChanged,
+            MessageDurationChanged,
+            RemoveMessageDurationChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUISettings6 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUISettingsAnimationsEnabledChangedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUISettingsAnimationsEnabledChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUISettingsAutoHideScrollBarsChangedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUISettingsAutoHideScrollBarsChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUISettingsMessageDurationChangedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUISettingsMessageDurationChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIViewSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserInteractionMode(this: *mut core::ffi::c_void, result__: *mut UserInteractionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIViewSettings", "UserInteractionMode", 6usize, |this| ((**(this as *const *const IUIViewSettings_Vtbl)).UserInteractionMode)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserInteractionMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIViewSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIViewSettingsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIViewSettingsStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IUIViewSettingsStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIViewSettingsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IViewModePreferences_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ViewSizePreference(this: *mut core::ffi::c_void, result__: *mut ViewSizePreference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewModePreferences", "ViewSizePreference", 6usize, |this| ((**(this as *const *const IViewModePreferences_Vtbl)).ViewSizePreference)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewSizePreference(this: *mut core::ffi::c_void, value: ViewSizePreference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewModePreferences", "SetViewSizePreference", 7usize, |this| ((**(this as *const *const IViewModePreferences_Vtbl)).SetViewSizePreference)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewModePreferences", "CustomSize", 8usize, |this| ((**(this as *const *const IViewModePreferences_Vtbl)).CustomSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomSize(this: *mut core::ffi::c_void, value: super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewModePreferences", "SetCustomSize", 9usize, |this| ((**(this as *const *const IViewModePreferences_Vtbl)).SetCustomSize)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ViewSizePreference,
+            SetViewSizePreference,
+            CustomSize,
+            SetCustomSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IViewModePreferences as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IViewModePreferencesStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDefault(this: *mut core::ffi::c_void, mode: ApplicationViewMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewModePreferencesStatics", "CreateDefault", 6usize, |this| ((**(this as *const *const IViewModePreferencesStatics_Vtbl)).CreateDefault)(this, mode, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IViewModePreferencesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -634,6 +634,7 @@ impl WebUICommandBarClosedDisplayMode {
 impl windows_core::TypeKind for WebUICommandBarClosedDisplayMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebUICommandBarClosedDisplayMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebUICommandBarClosedDisplayMode").field(&self.0).finish()
@@ -818,3 +819,5 @@ pub struct SizeChangedEventHandler_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,288 @@
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWebUICommandBar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Visible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "Visible", 6usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).Visible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "SetVisible", 7usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).SetVisible)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Opacity(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "Opacity", 8usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).Opacity)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpacity(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "SetOpacity", 9usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).SetOpacity)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForegroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "ForegroundColor", 10usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).ForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForegroundColor(this: *mut core::ffi::c_void, value: super::super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "SetForegroundColor", 11usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).SetForegroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "BackgroundColor", 12usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).BackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackgroundColor(this: *mut core::ffi::c_void, value: super::super::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "SetBackgroundColor", 13usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).SetBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClosedDisplayMode(this: *mut core::ffi::c_void, result__: *mut WebUICommandBarClosedDisplayMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "ClosedDisplayMode", 14usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).ClosedDisplayMode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClosedDisplayMode(this: *mut core::ffi::c_void, value: WebUICommandBarClosedDisplayMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "SetClosedDisplayMode", 15usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).SetClosedDisplayMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOpen(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "IsOpen", 16usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).IsOpen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsOpen(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "SetIsOpen", 17usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).SetIsOpen)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "Size", 18usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn PrimaryCommands(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "PrimaryCommands", 19usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).PrimaryCommands)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SecondaryCommands(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "SecondaryCommands", 20usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).SecondaryCommands)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MenuOpened(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "MenuOpened", 21usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).MenuOpened)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMenuOpened(this: *mut core::ffi::c_void, value: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "RemoveMenuOpened", 22usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).RemoveMenuOpened)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MenuClosed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "MenuClosed", 23usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).MenuClosed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMenuClosed(this: *mut core::ffi::c_void, value: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "RemoveMenuClosed", 24usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).RemoveMenuClosed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "SizeChanged", 25usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).SizeChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSizeChanged(this: *mut core::ffi::c_void, value: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBar", "RemoveSizeChanged", 26usize, |this| ((**(this as *const *const IWebUICommandBar_Vtbl)).RemoveSizeChanged)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Visible,
+            SetVisible,
+            Opacity,
+            SetOpacity,
+            ForegroundColor,
+            SetForegroundColor,
+            BackgroundColor,
+            SetBackgroundColor,
+            ClosedDisplayMode,
+            SetClosedDisplayMode,
+            IsOpen,
+            SetIsOpen,
+            Size,
+            #[cfg(feature = "Foundation_Collections")]
+            PrimaryCommands,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            PrimaryCommands: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            SecondaryCommands,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SecondaryCommands: 0,
+            MenuOpened,
+            RemoveMenuOpened,
+            MenuClosed,
+            RemoveMenuClosed,
+            SizeChanged,
+            RemoveSizeChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUICommandBar as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUICommandBarBitmapIcon_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarBitmapIcon", "Uri", 6usize, |this| ((**(this as *const *const IWebUICommandBarBitmapIcon_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarBitmapIcon", "SetUri", 7usize, |this| ((**(this as *const *const IWebUICommandBarBitmapIcon_Vtbl)).SetUri)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uri, SetUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUICommandBarBitmapIcon as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUICommandBarBitmapIconFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarBitmapIconFactory", "Create", 6usize, |this| ((**(this as *const *const IWebUICommandBarBitmapIconFactory_Vtbl)).Create)(this, uri, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUICommandBarBitmapIconFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUICommandBarConfirmationButton_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarConfirmationButton", "Text", 6usize, |this| ((**(this as *const *const IWebUICommandBarConfirmationButton_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarConfirmationButton", "SetText", 7usize, |this| ((**(this as *const *const IWebUICommandBarConfirmationButton_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemInvoked(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarConfirmationButton", "ItemInvoked", 8usize, |this| ((**(this as *const *const IWebUICommandBarConfirmationButton_Vtbl)).ItemInvoked)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItemInvoked(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarConfirmationButton", "RemoveItemInvoked", 9usize, |this| ((**(this as *const *const IWebUICommandBarConfirmationButton_Vtbl)).RemoveItemInvoked)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Text, SetText, ItemInvoked, RemoveItemInvoked }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUICommandBarConfirmationButton as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUICommandBarElement_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUICommandBarElement as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUICommandBarIcon_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUICommandBarIcon as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUICommandBarIconButton_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarIconButton", "Enabled", 6usize, |this| ((**(this as *const *const IWebUICommandBarIconButton_Vtbl)).Enabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarIconButton", "SetEnabled", 7usize, |this| ((**(this as *const *const IWebUICommandBarIconButton_Vtbl)).SetEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarIconButton", "Label", 8usize, |this| ((**(this as *const *const IWebUICommandBarIconButton_Vtbl)).Label)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarIconButton", "SetLabel", 9usize, |this| ((**(this as *const *const IWebUICommandBarIconButton_Vtbl)).SetLabel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsToggleButton(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarIconButton", "IsToggleButton", 10usize, |this| ((**(this as *const *const IWebUICommandBarIconButton_Vtbl)).IsToggleButton)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsToggleButton(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarIconButton", "SetIsToggleButton", 11usize, |this| ((**(this as *const *const IWebUICommandBarIconButton_Vtbl)).SetIsToggleButton)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsChecked(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarIconButton", "IsChecked", 12usize, |this| ((**(this as *const *const IWebUICommandBarIconButton_Vtbl)).IsChecked)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsChecked(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarIconButton", "SetIsChecked", 13usize, |this| ((**(this as *const *const IWebUICommandBarIconButton_Vtbl)).SetIsChecked)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Icon(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarIconButton", "Icon", 14usize, |this| ((**(this as *const *const IWebUICommandBarIconButton_Vtbl)).Icon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIcon(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarIconButton", "SetIcon", 15usize, |this| ((**(this as *const *const IWebUICommandBarIconButton_Vtbl)).SetIcon)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemInvoked(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarIconButton", "ItemInvoked", 16usize, |this| ((**(this as *const *const IWebUICommandBarIconButton_Vtbl)).ItemInvoked)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItemInvoked(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarIconButton", "RemoveItemInvoked", 17usize, |this| ((**(this as *const *const IWebUICommandBarIconButton_Vtbl)).RemoveItemInvoked)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Enabled,
+            SetEnabled,
+            Label,
+            SetLabel,
+            IsToggleButton,
+            SetIsToggleButton,
+            IsChecked,
+            SetIsChecked,
+            Icon,
+            SetIcon,
+            ItemInvoked,
+            RemoveItemInvoked,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUICommandBarIconButton as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUICommandBarItemInvokedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPrimaryCommand(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarItemInvokedEventArgs", "IsPrimaryCommand", 6usize, |this| ((**(this as *const *const IWebUICommandBarItemInvokedEventArgs_Vtbl)).IsPrimaryCommand)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsPrimaryCommand }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUICommandBarItemInvokedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUICommandBarSizeChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarSizeChangedEventArgs", "Size", 6usize, |this| ((**(this as *const *const IWebUICommandBarSizeChangedEventArgs_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Size }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUICommandBarSizeChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUICommandBarStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForCurrentView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarStatics", "GetForCurrentView", 6usize, |this| ((**(this as *const *const IWebUICommandBarStatics_Vtbl)).GetForCurrentView)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForCurrentView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUICommandBarStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUICommandBarSymbolIcon_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Symbol(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarSymbolIcon", "Symbol", 6usize, |this| ((**(this as *const *const IWebUICommandBarSymbolIcon_Vtbl)).Symbol)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSymbol(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarSymbolIcon", "SetSymbol", 7usize, |this| ((**(this as *const *const IWebUICommandBarSymbolIcon_Vtbl)).SetSymbol)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Symbol, SetSymbol }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUICommandBarSymbolIcon as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUICommandBarSymbolIconFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, symbol: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUICommandBarSymbolIconFactory", "Create", 6usize, |this| ((**(this as *const *const IWebUICommandBarSymbolIconFactory_Vtbl)).Create)(this, symbol, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUICommandBarSymbolIconFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
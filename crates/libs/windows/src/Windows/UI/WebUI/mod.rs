@@ -5131,6 +5131,7 @@ impl PrintContent {
 impl windows_core::TypeKind for PrintContent {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PrintContent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PrintContent").field(&self.0).finish()
@@ -5617,3 +5618,5 @@ pub struct SuspendingEventHandler_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,426 @@
+impl windows_core::imp::ProxyVtable for IActivatedDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivatedDeferral", "Complete", 6usize, |this| ((**(this as *const *const IActivatedDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivatedDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActivatedEventArgsDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivatedOperation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivatedEventArgsDeferral", "ActivatedOperation", 6usize, |this| ((**(this as *const *const IActivatedEventArgsDeferral_Vtbl)).ActivatedOperation)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ActivatedOperation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivatedEventArgsDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActivatedOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivatedOperation", "GetDeferral", 6usize, |this| ((**(this as *const *const IActivatedOperation_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivatedOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Graphics_Printing")]
+impl windows_core::imp::ProxyVtable for IHtmlPrintDocumentSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut PrintContent) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "Content", 6usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContent(this: *mut core::ffi::c_void, value: PrintContent) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "SetContent", 7usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).SetContent)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeftMargin(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "LeftMargin", 8usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).LeftMargin)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLeftMargin(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "SetLeftMargin", 9usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).SetLeftMargin)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TopMargin(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "TopMargin", 10usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).TopMargin)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTopMargin(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "SetTopMargin", 11usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).SetTopMargin)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RightMargin(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "RightMargin", 12usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).RightMargin)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRightMargin(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "SetRightMargin", 13usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).SetRightMargin)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BottomMargin(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "BottomMargin", 14usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).BottomMargin)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottomMargin(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "SetBottomMargin", 15usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).SetBottomMargin)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableHeaderFooter(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "EnableHeaderFooter", 16usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).EnableHeaderFooter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableHeaderFooter(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "SetEnableHeaderFooter", 17usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).SetEnableHeaderFooter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShrinkToFit(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "ShrinkToFit", 18usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).ShrinkToFit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShrinkToFit(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "SetShrinkToFit", 19usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).SetShrinkToFit)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PercentScale(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "PercentScale", 20usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).PercentScale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPercentScale(this: *mut core::ffi::c_void, scalepercent: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "SetPercentScale", 21usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).SetPercentScale)(this, scalepercent), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageRange(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "PageRange", 22usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).PageRange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySetPageRange(this: *mut core::ffi::c_void, strpagerange: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHtmlPrintDocumentSource", "TrySetPageRange", 23usize, |this| ((**(this as *const *const IHtmlPrintDocumentSource_Vtbl)).TrySetPageRange)(this, strpagerange, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Content,
+            SetContent,
+            LeftMargin,
+            SetLeftMargin,
+            TopMargin,
+            SetTopMargin,
+            RightMargin,
+            SetRightMargin,
+            BottomMargin,
+            SetBottomMargin,
+            EnableHeaderFooter,
+            SetEnableHeaderFooter,
+            ShrinkToFit,
+            SetShrinkToFit,
+            PercentScale,
+            SetPercentScale,
+            PageRange,
+            TrySetPageRange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHtmlPrintDocumentSource as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Activation")]
+impl windows_core::imp::ProxyVtable for INewWebUIViewCreatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WebUIView(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INewWebUIViewCreatedEventArgs", "WebUIView", 6usize, |this| ((**(this as *const *const INewWebUIViewCreatedEventArgs_Vtbl)).WebUIView)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Activation")]
+        unsafe extern "system" fn ActivatedEventArgs(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INewWebUIViewCreatedEventArgs", "ActivatedEventArgs", 7usize, |this| ((**(this as *const *const INewWebUIViewCreatedEventArgs_Vtbl)).ActivatedEventArgs)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasPendingNavigate(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INewWebUIViewCreatedEventArgs", "HasPendingNavigate", 8usize, |this| ((**(this as *const *const INewWebUIViewCreatedEventArgs_Vtbl)).HasPendingNavigate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INewWebUIViewCreatedEventArgs", "GetDeferral", 9usize, |this| ((**(this as *const *const INewWebUIViewCreatedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            WebUIView,
+            #[cfg(feature = "ApplicationModel_Activation")]
+            ActivatedEventArgs,
+            #[cfg(not(feature = "ApplicationModel_Activation"))]
+            ActivatedEventArgs: 0,
+            HasPendingNavigate,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INewWebUIViewCreatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Activation")]
+impl windows_core::imp::ProxyVtable for IWebUIActivationStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_Activation")]
+        unsafe extern "system" fn Activated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics", "Activated", 6usize, |this| ((**(this as *const *const IWebUIActivationStatics_Vtbl)).Activated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveActivated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics", "RemoveActivated", 7usize, |this| ((**(this as *const *const IWebUIActivationStatics_Vtbl)).RemoveActivated)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn Suspending(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics", "Suspending", 8usize, |this| ((**(this as *const *const IWebUIActivationStatics_Vtbl)).Suspending)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSuspending(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics", "RemoveSuspending", 9usize, |this| ((**(this as *const *const IWebUIActivationStatics_Vtbl)).RemoveSuspending)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resuming(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics", "Resuming", 10usize, |this| ((**(this as *const *const IWebUIActivationStatics_Vtbl)).Resuming)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveResuming(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics", "RemoveResuming", 11usize, |this| ((**(this as *const *const IWebUIActivationStatics_Vtbl)).RemoveResuming)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Navigated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics", "Navigated", 12usize, |this| ((**(this as *const *const IWebUIActivationStatics_Vtbl)).Navigated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNavigated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics", "RemoveNavigated", 13usize, |this| ((**(this as *const *const IWebUIActivationStatics_Vtbl)).RemoveNavigated)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_Activation")]
+            Activated,
+            #[cfg(not(feature = "ApplicationModel_Activation"))]
+            Activated: 0,
+            RemoveActivated,
+            #[cfg(feature = "ApplicationModel")]
+            Suspending,
+            #[cfg(not(feature = "ApplicationModel"))]
+            Suspending: 0,
+            RemoveSuspending,
+            Resuming,
+            RemoveResuming,
+            Navigated,
+            RemoveNavigated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUIActivationStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel")]
+impl windows_core::imp::ProxyVtable for IWebUIActivationStatics2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn LeavingBackground(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics2", "LeavingBackground", 6usize, |this| ((**(this as *const *const IWebUIActivationStatics2_Vtbl)).LeavingBackground)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLeavingBackground(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics2", "RemoveLeavingBackground", 7usize, |this| ((**(this as *const *const IWebUIActivationStatics2_Vtbl)).RemoveLeavingBackground)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel")]
+        unsafe extern "system" fn EnteredBackground(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics2", "EnteredBackground", 8usize, |this| ((**(this as *const *const IWebUIActivationStatics2_Vtbl)).EnteredBackground)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnteredBackground(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics2", "RemoveEnteredBackground", 9usize, |this| ((**(this as *const *const IWebUIActivationStatics2_Vtbl)).RemoveEnteredBackground)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnablePrelaunch(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics2", "EnablePrelaunch", 10usize, |this| ((**(this as *const *const IWebUIActivationStatics2_Vtbl)).EnablePrelaunch)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel")]
+            LeavingBackground,
+            #[cfg(not(feature = "ApplicationModel"))]
+            LeavingBackground: 0,
+            RemoveLeavingBackground,
+            #[cfg(feature = "ApplicationModel")]
+            EnteredBackground,
+            #[cfg(not(feature = "ApplicationModel"))]
+            EnteredBackground: 0,
+            RemoveEnteredBackground,
+            EnablePrelaunch,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUIActivationStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "ApplicationModel_Core", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IWebUIActivationStatics3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "ApplicationModel_Core")]
+        unsafe extern "system" fn RequestRestartAsync(this: *mut core::ffi::c_void, launcharguments: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics3", "RequestRestartAsync", 6usize, |this| ((**(this as *const *const IWebUIActivationStatics3_Vtbl)).RequestRestartAsync)(this, launcharguments, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "ApplicationModel_Core", feature = "System"))]
+        unsafe extern "system" fn RequestRestartForUserAsync(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, launcharguments: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics3", "RequestRestartForUserAsync", 7usize, |this| ((**(this as *const *const IWebUIActivationStatics3_Vtbl)).RequestRestartForUserAsync)(this, user, launcharguments, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "ApplicationModel_Core")]
+            RequestRestartAsync,
+            #[cfg(not(feature = "ApplicationModel_Core"))]
+            RequestRestartAsync: 0,
+            #[cfg(all(feature = "ApplicationModel_Core", feature = "System"))]
+            RequestRestartForUserAsync,
+            #[cfg(not(all(feature = "ApplicationModel_Core", feature = "System")))]
+            RequestRestartForUserAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUIActivationStatics3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Activation")]
+impl windows_core::imp::ProxyVtable for IWebUIActivationStatics4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NewWebUIViewCreated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics4", "NewWebUIViewCreated", 6usize, |this| ((**(this as *const *const IWebUIActivationStatics4_Vtbl)).NewWebUIViewCreated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNewWebUIViewCreated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics4", "RemoveNewWebUIViewCreated", 7usize, |this| ((**(this as *const *const IWebUIActivationStatics4_Vtbl)).RemoveNewWebUIViewCreated)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Activation")]
+        unsafe extern "system" fn BackgroundActivated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics4", "BackgroundActivated", 8usize, |this| ((**(this as *const *const IWebUIActivationStatics4_Vtbl)).BackgroundActivated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBackgroundActivated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIActivationStatics4", "RemoveBackgroundActivated", 9usize, |this| ((**(this as *const *const IWebUIActivationStatics4_Vtbl)).RemoveBackgroundActivated)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NewWebUIViewCreated,
+            RemoveNewWebUIViewCreated,
+            #[cfg(feature = "ApplicationModel_Activation")]
+            BackgroundActivated,
+            #[cfg(not(feature = "ApplicationModel_Activation"))]
+            BackgroundActivated: 0,
+            RemoveBackgroundActivated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUIActivationStatics4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUIBackgroundTaskInstance_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Succeeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIBackgroundTaskInstance", "Succeeded", 6usize, |this| ((**(this as *const *const IWebUIBackgroundTaskInstance_Vtbl)).Succeeded)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSucceeded(this: *mut core::ffi::c_void, succeeded: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIBackgroundTaskInstance", "SetSucceeded", 7usize, |this| ((**(this as *const *const IWebUIBackgroundTaskInstance_Vtbl)).SetSucceeded)(this, succeeded), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Succeeded, SetSucceeded }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUIBackgroundTaskInstance as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUIBackgroundTaskInstanceStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIBackgroundTaskInstanceStatics", "Current", 6usize, |this| ((**(this as *const *const IWebUIBackgroundTaskInstanceStatics_Vtbl)).Current)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Current }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUIBackgroundTaskInstanceStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUINavigatedDeferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUINavigatedDeferral", "Complete", 6usize, |this| ((**(this as *const *const IWebUINavigatedDeferral_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUINavigatedDeferral as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUINavigatedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NavigatedOperation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUINavigatedEventArgs", "NavigatedOperation", 6usize, |this| ((**(this as *const *const IWebUINavigatedEventArgs_Vtbl)).NavigatedOperation)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NavigatedOperation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUINavigatedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUINavigatedOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUINavigatedOperation", "GetDeferral", 6usize, |this| ((**(this as *const *const IWebUINavigatedOperation_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUINavigatedOperation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "ApplicationModel_Activation")]
+impl windows_core::imp::ProxyVtable for IWebUIView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ApplicationViewId(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIView", "ApplicationViewId", 6usize, |this| ((**(this as *const *const IWebUIView_Vtbl)).ApplicationViewId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIView", "Closed", 7usize, |this| ((**(this as *const *const IWebUIView_Vtbl)).Closed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIView", "RemoveClosed", 8usize, |this| ((**(this as *const *const IWebUIView_Vtbl)).RemoveClosed)(this, token), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_Activation")]
+        unsafe extern "system" fn Activated(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIView", "Activated", 9usize, |this| ((**(this as *const *const IWebUIView_Vtbl)).Activated)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveActivated(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIView", "RemoveActivated", 10usize, |this| ((**(this as *const *const IWebUIView_Vtbl)).RemoveActivated)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn IgnoreApplicationContentUriRulesNavigationRestrictions(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIView", "IgnoreApplicationContentUriRulesNavigationRestrictions", 11usize, |this| ((**(this as *const *const IWebUIView_Vtbl)).IgnoreApplicationContentUriRulesNavigationRestrictions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIgnoreApplicationContentUriRulesNavigationRestrictions(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIView", "SetIgnoreApplicationContentUriRulesNavigationRestrictions", 12usize, |this| ((**(this as *const *const IWebUIView_Vtbl)).SetIgnoreApplicationContentUriRulesNavigationRestrictions)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ApplicationViewId,
+            Closed,
+            RemoveClosed,
+            #[cfg(feature = "ApplicationModel_Activation")]
+            Activated,
+            #[cfg(not(feature = "ApplicationModel_Activation"))]
+            Activated: 0,
+            RemoveActivated,
+            IgnoreApplicationContentUriRulesNavigationRestrictions,
+            SetIgnoreApplicationContentUriRulesNavigationRestrictions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUIView as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebUIViewStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIViewStatics", "CreateAsync", 6usize, |this| ((**(this as *const *const IWebUIViewStatics_Vtbl)).CreateAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithUriAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebUIViewStatics", "CreateWithUriAsync", 7usize, |this| ((**(this as *const *const IWebUIViewStatics_Vtbl)).CreateWithUriAsync)(this, uri, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateAsync, CreateWithUriAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebUIViewStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
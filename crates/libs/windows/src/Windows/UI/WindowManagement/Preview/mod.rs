@@ -44,3 +44,5 @@ impl windows_core::RuntimeName for WindowManagementPreview {
 }
 unsafe impl Send for WindowManagementPreview {}
 unsafe impl Sync for WindowManagementPreview {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
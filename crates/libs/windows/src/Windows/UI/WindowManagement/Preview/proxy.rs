@@ -0,0 +1,19 @@
+impl windows_core::imp::ProxyVtable for IWindowManagementPreview_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowManagementPreview as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowManagementPreviewStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPreferredMinSize(this: *mut core::ffi::c_void, window: *mut core::ffi::c_void, preferredframeminsize: super::super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowManagementPreviewStatics", "SetPreferredMinSize", 6usize, |this| ((**(this as *const *const IWindowManagementPreviewStatics_Vtbl)).SetPreferredMinSize)(this, window, preferredframeminsize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPreferredMinSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowManagementPreviewStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1425,6 +1425,7 @@ impl AppWindowClosedReason {
 impl windows_core::TypeKind for AppWindowClosedReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppWindowClosedReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppWindowClosedReason").field(&self.0).finish()
@@ -1443,6 +1444,7 @@ impl AppWindowFrameStyle {
 impl windows_core::TypeKind for AppWindowFrameStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppWindowFrameStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppWindowFrameStyle").field(&self.0).finish()
@@ -1462,6 +1464,7 @@ impl AppWindowPresentationKind {
 impl windows_core::TypeKind for AppWindowPresentationKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppWindowPresentationKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppWindowPresentationKind").field(&self.0).finish()
@@ -1480,6 +1483,7 @@ impl AppWindowTitleBarVisibility {
 impl windows_core::TypeKind for AppWindowTitleBarVisibility {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppWindowTitleBarVisibility {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppWindowTitleBarVisibility").field(&self.0).finish()
@@ -1499,6 +1503,7 @@ impl WindowingEnvironmentKind {
 impl windows_core::TypeKind for WindowingEnvironmentKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WindowingEnvironmentKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WindowingEnvironmentKind").field(&self.0).finish()
@@ -1507,3 +1512,5 @@ impl core::fmt::Debug for WindowingEnvironmentKind {
 impl windows_core::RuntimeType for WindowingEnvironmentKind {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.UI.WindowManagement.WindowingEnvironmentKind;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
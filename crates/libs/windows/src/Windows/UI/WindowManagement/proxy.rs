@@ -0,0 +1,664 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+impl windows_core::imp::ProxyVtable for IAppWindow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "Content", 6usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn DispatcherQueue(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "DispatcherQueue", 7usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).DispatcherQueue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Frame(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "Frame", 8usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).Frame)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "IsVisible", 9usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).IsVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PersistedStateId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "PersistedStateId", 10usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).PersistedStateId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPersistedStateId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "SetPersistedStateId", 11usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).SetPersistedStateId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Presenter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "Presenter", 12usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).Presenter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "Title", 13usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "SetTitle", 14usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TitleBar(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "TitleBar", 15usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).TitleBar)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UIContext(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "UIContext", 16usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).UIContext)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WindowingEnvironment(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "WindowingEnvironment", 17usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).WindowingEnvironment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "CloseAsync", 18usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).CloseAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPlacement(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "GetPlacement", 19usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).GetPlacement)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetDisplayRegions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "GetDisplayRegions", 20usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).GetDisplayRegions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestMoveToDisplayRegion(this: *mut core::ffi::c_void, displayregion: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "RequestMoveToDisplayRegion", 21usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).RequestMoveToDisplayRegion)(this, displayregion), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestMoveAdjacentToCurrentView(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "RequestMoveAdjacentToCurrentView", 22usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).RequestMoveAdjacentToCurrentView)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestMoveAdjacentToWindow(this: *mut core::ffi::c_void, anchorwindow: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "RequestMoveAdjacentToWindow", 23usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).RequestMoveAdjacentToWindow)(this, anchorwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestMoveRelativeToWindowContent(this: *mut core::ffi::c_void, anchorwindow: *mut core::ffi::c_void, contentoffset: super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "RequestMoveRelativeToWindowContent", 24usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).RequestMoveRelativeToWindowContent)(this, anchorwindow, contentoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestMoveRelativeToCurrentViewContent(this: *mut core::ffi::c_void, contentoffset: super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "RequestMoveRelativeToCurrentViewContent", 25usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).RequestMoveRelativeToCurrentViewContent)(this, contentoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestMoveRelativeToDisplayRegion(this: *mut core::ffi::c_void, displayregion: *mut core::ffi::c_void, displayregionoffset: super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "RequestMoveRelativeToDisplayRegion", 26usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).RequestMoveRelativeToDisplayRegion)(this, displayregion, displayregionoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestSize(this: *mut core::ffi::c_void, framesize: super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "RequestSize", 27usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).RequestSize)(this, framesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryShowAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "TryShowAsync", 28usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).TryShowAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Changed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "Changed", 29usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).Changed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "RemoveChanged", 30usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).RemoveChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn Closed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "Closed", 31usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).Closed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClosed(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "RemoveClosed", 32usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).RemoveClosed)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "CloseRequested", 33usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).CloseRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCloseRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindow", "RemoveCloseRequested", 34usize, |this| ((**(this as *const *const IAppWindow_Vtbl)).RemoveCloseRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Content,
+            #[cfg(feature = "System")]
+            DispatcherQueue,
+            #[cfg(not(feature = "System"))]
+            DispatcherQueue: 0,
+            Frame,
+            IsVisible,
+            PersistedStateId,
+            SetPersistedStateId,
+            Presenter,
+            Title,
+            SetTitle,
+            TitleBar,
+            UIContext,
+            WindowingEnvironment,
+            CloseAsync,
+            GetPlacement,
+            #[cfg(feature = "Foundation_Collections")]
+            GetDisplayRegions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetDisplayRegions: 0,
+            RequestMoveToDisplayRegion,
+            RequestMoveAdjacentToCurrentView,
+            RequestMoveAdjacentToWindow,
+            RequestMoveRelativeToWindowContent,
+            RequestMoveRelativeToCurrentViewContent,
+            RequestMoveRelativeToDisplayRegion,
+            RequestSize,
+            TryShowAsync,
+            Changed,
+            RemoveChanged,
+            Closed,
+            RemoveClosed,
+            CloseRequested,
+            RemoveCloseRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppWindow as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppWindowChangedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DidAvailableWindowPresentationsChange(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowChangedEventArgs", "DidAvailableWindowPresentationsChange", 6usize, |this| ((**(this as *const *const IAppWindowChangedEventArgs_Vtbl)).DidAvailableWindowPresentationsChange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DidDisplayRegionsChange(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowChangedEventArgs", "DidDisplayRegionsChange", 7usize, |this| ((**(this as *const *const IAppWindowChangedEventArgs_Vtbl)).DidDisplayRegionsChange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DidFrameChange(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowChangedEventArgs", "DidFrameChange", 8usize, |this| ((**(this as *const *const IAppWindowChangedEventArgs_Vtbl)).DidFrameChange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DidSizeChange(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowChangedEventArgs", "DidSizeChange", 9usize, |this| ((**(this as *const *const IAppWindowChangedEventArgs_Vtbl)).DidSizeChange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DidTitleBarChange(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowChangedEventArgs", "DidTitleBarChange", 10usize, |this| ((**(this as *const *const IAppWindowChangedEventArgs_Vtbl)).DidTitleBarChange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DidVisibilityChange(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowChangedEventArgs", "DidVisibilityChange", 11usize, |this| ((**(this as *const *const IAppWindowChangedEventArgs_Vtbl)).DidVisibilityChange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DidWindowingEnvironmentChange(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowChangedEventArgs", "DidWindowingEnvironmentChange", 12usize, |this| ((**(this as *const *const IAppWindowChangedEventArgs_Vtbl)).DidWindowingEnvironmentChange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DidWindowPresentationChange(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowChangedEventArgs", "DidWindowPresentationChange", 13usize, |this| ((**(this as *const *const IAppWindowChangedEventArgs_Vtbl)).DidWindowPresentationChange)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DidAvailableWindowPresentationsChange,
+            DidDisplayRegionsChange,
+            DidFrameChange,
+            DidSizeChange,
+            DidTitleBarChange,
+            DidVisibilityChange,
+            DidWindowingEnvironmentChange,
+            DidWindowPresentationChange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppWindowChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppWindowCloseRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowCloseRequestedEventArgs", "Cancel", 6usize, |this| ((**(this as *const *const IAppWindowCloseRequestedEventArgs_Vtbl)).Cancel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCancel(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowCloseRequestedEventArgs", "SetCancel", 7usize, |this| ((**(this as *const *const IAppWindowCloseRequestedEventArgs_Vtbl)).SetCancel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowCloseRequestedEventArgs", "GetDeferral", 8usize, |this| ((**(this as *const *const IAppWindowCloseRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cancel, SetCancel, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppWindowCloseRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppWindowClosedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut AppWindowClosedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowClosedEventArgs", "Reason", 6usize, |this| ((**(this as *const *const IAppWindowClosedEventArgs_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppWindowClosedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "UI_Composition"))]
+impl windows_core::imp::ProxyVtable for IAppWindowFrame_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Collections", feature = "UI_Composition"))]
+        unsafe extern "system" fn DragRegionVisuals(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowFrame", "DragRegionVisuals", 6usize, |this| ((**(this as *const *const IAppWindowFrame_Vtbl)).DragRegionVisuals)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Collections", feature = "UI_Composition"))]
+            DragRegionVisuals,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "UI_Composition")))]
+            DragRegionVisuals: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppWindowFrame as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppWindowFrameStyle_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFrameStyle(this: *mut core::ffi::c_void, result__: *mut AppWindowFrameStyle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowFrameStyle", "GetFrameStyle", 6usize, |this| ((**(this as *const *const IAppWindowFrameStyle_Vtbl)).GetFrameStyle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrameStyle(this: *mut core::ffi::c_void, framestyle: AppWindowFrameStyle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowFrameStyle", "SetFrameStyle", 7usize, |this| ((**(this as *const *const IAppWindowFrameStyle_Vtbl)).SetFrameStyle)(this, framestyle), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFrameStyle, SetFrameStyle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppWindowFrameStyle as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppWindowPlacement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayRegion(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowPlacement", "DisplayRegion", 6usize, |this| ((**(this as *const *const IAppWindowPlacement_Vtbl)).DisplayRegion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Offset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowPlacement", "Offset", 7usize, |this| ((**(this as *const *const IAppWindowPlacement_Vtbl)).Offset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowPlacement", "Size", 8usize, |this| ((**(this as *const *const IAppWindowPlacement_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayRegion, Offset, Size }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppWindowPlacement as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppWindowPresentationConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut AppWindowPresentationKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowPresentationConfiguration", "Kind", 6usize, |this| ((**(this as *const *const IAppWindowPresentationConfiguration_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Kind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppWindowPresentationConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppWindowPresentationConfigurationFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppWindowPresentationConfigurationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppWindowPresenter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetConfiguration(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowPresenter", "GetConfiguration", 6usize, |this| ((**(this as *const *const IAppWindowPresenter_Vtbl)).GetConfiguration)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPresentationSupported(this: *mut core::ffi::c_void, presentationkind: AppWindowPresentationKind, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowPresenter", "IsPresentationSupported", 7usize, |this| ((**(this as *const *const IAppWindowPresenter_Vtbl)).IsPresentationSupported)(this, presentationkind, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPresentation(this: *mut core::ffi::c_void, configuration: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowPresenter", "RequestPresentation", 8usize, |this| ((**(this as *const *const IAppWindowPresenter_Vtbl)).RequestPresentation)(this, configuration, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPresentationByKind(this: *mut core::ffi::c_void, presentationkind: AppWindowPresentationKind, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowPresenter", "RequestPresentationByKind", 9usize, |this| ((**(this as *const *const IAppWindowPresenter_Vtbl)).RequestPresentationByKind)(this, presentationkind, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetConfiguration,
+            IsPresentationSupported,
+            RequestPresentation,
+            RequestPresentationByKind,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppWindowPresenter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppWindowStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryCreateAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowStatics", "TryCreateAsync", 6usize, |this| ((**(this as *const *const IAppWindowStatics_Vtbl)).TryCreateAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearAllPersistedState(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowStatics", "ClearAllPersistedState", 7usize, |this| ((**(this as *const *const IAppWindowStatics_Vtbl)).ClearAllPersistedState)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearPersistedState(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowStatics", "ClearPersistedState", 8usize, |this| ((**(this as *const *const IAppWindowStatics_Vtbl)).ClearPersistedState)(this, key), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryCreateAsync, ClearAllPersistedState, ClearPersistedState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppWindowStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IAppWindowTitleBar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BackgroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "BackgroundColor", 6usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).BackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackgroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "SetBackgroundColor", 7usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).SetBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonBackgroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "ButtonBackgroundColor", 8usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).ButtonBackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonBackgroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "SetButtonBackgroundColor", 9usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).SetButtonBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonForegroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "ButtonForegroundColor", 10usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).ButtonForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonForegroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "SetButtonForegroundColor", 11usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).SetButtonForegroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonHoverBackgroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "ButtonHoverBackgroundColor", 12usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).ButtonHoverBackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonHoverBackgroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "SetButtonHoverBackgroundColor", 13usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).SetButtonHoverBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonHoverForegroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "ButtonHoverForegroundColor", 14usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).ButtonHoverForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonHoverForegroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "SetButtonHoverForegroundColor", 15usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).SetButtonHoverForegroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonInactiveBackgroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "ButtonInactiveBackgroundColor", 16usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).ButtonInactiveBackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonInactiveBackgroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "SetButtonInactiveBackgroundColor", 17usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).SetButtonInactiveBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonInactiveForegroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "ButtonInactiveForegroundColor", 18usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).ButtonInactiveForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonInactiveForegroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "SetButtonInactiveForegroundColor", 19usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).SetButtonInactiveForegroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonPressedBackgroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "ButtonPressedBackgroundColor", 20usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).ButtonPressedBackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonPressedBackgroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "SetButtonPressedBackgroundColor", 21usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).SetButtonPressedBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonPressedForegroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "ButtonPressedForegroundColor", 22usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).ButtonPressedForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonPressedForegroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "SetButtonPressedForegroundColor", 23usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).SetButtonPressedForegroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendsContentIntoTitleBar(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "ExtendsContentIntoTitleBar", 24usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).ExtendsContentIntoTitleBar)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtendsContentIntoTitleBar(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "SetExtendsContentIntoTitleBar", 25usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).SetExtendsContentIntoTitleBar)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForegroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "ForegroundColor", 26usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).ForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForegroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "SetForegroundColor", 27usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).SetForegroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InactiveBackgroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "InactiveBackgroundColor", 28usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).InactiveBackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInactiveBackgroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "SetInactiveBackgroundColor", 29usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).SetInactiveBackgroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InactiveForegroundColor(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "InactiveForegroundColor", 30usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).InactiveForegroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInactiveForegroundColor(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "SetInactiveForegroundColor", 31usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).SetInactiveForegroundColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "IsVisible", 32usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).IsVisible)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetTitleBarOcclusions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBar", "GetTitleBarOcclusions", 33usize, |this| ((**(this as *const *const IAppWindowTitleBar_Vtbl)).GetTitleBarOcclusions)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BackgroundColor,
+            SetBackgroundColor,
+            ButtonBackgroundColor,
+            SetButtonBackgroundColor,
+            ButtonForegroundColor,
+            SetButtonForegroundColor,
+            ButtonHoverBackgroundColor,
+            SetButtonHoverBackgroundColor,
+            ButtonHoverForegroundColor,
+            SetButtonHoverForegroundColor,
+            ButtonInactiveBackgroundColor,
+            SetButtonInactiveBackgroundColor,
+            ButtonInactiveForegroundColor,
+            SetButtonInactiveForegroundColor,
+            ButtonPressedBackgroundColor,
+            SetButtonPressedBackgroundColor,
+            ButtonPressedForegroundColor,
+            SetButtonPressedForegroundColor,
+            ExtendsContentIntoTitleBar,
+            SetExtendsContentIntoTitleBar,
+            ForegroundColor,
+            SetForegroundColor,
+            InactiveBackgroundColor,
+            SetInactiveBackgroundColor,
+            InactiveForegroundColor,
+            SetInactiveForegroundColor,
+            IsVisible,
+            #[cfg(feature = "Foundation_Collections")]
+            GetTitleBarOcclusions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetTitleBarOcclusions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppWindowTitleBar as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppWindowTitleBarOcclusion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OccludingRect(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBarOcclusion", "OccludingRect", 6usize, |this| ((**(this as *const *const IAppWindowTitleBarOcclusion_Vtbl)).OccludingRect)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OccludingRect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppWindowTitleBarOcclusion as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppWindowTitleBarVisibility_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPreferredVisibility(this: *mut core::ffi::c_void, result__: *mut AppWindowTitleBarVisibility) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBarVisibility", "GetPreferredVisibility", 6usize, |this| ((**(this as *const *const IAppWindowTitleBarVisibility_Vtbl)).GetPreferredVisibility)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredVisibility(this: *mut core::ffi::c_void, visibilitymode: AppWindowTitleBarVisibility) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppWindowTitleBarVisibility", "SetPreferredVisibility", 7usize, |this| ((**(this as *const *const IAppWindowTitleBarVisibility_Vtbl)).SetPreferredVisibility)(this, visibilitymode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPreferredVisibility, SetPreferredVisibility }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppWindowTitleBarVisibility as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompactOverlayPresentationConfiguration_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompactOverlayPresentationConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDefaultPresentationConfiguration_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDefaultPresentationConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayRegion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayMonitorDeviceId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayRegion", "DisplayMonitorDeviceId", 6usize, |this| ((**(this as *const *const IDisplayRegion_Vtbl)).DisplayMonitorDeviceId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayRegion", "IsVisible", 7usize, |this| ((**(this as *const *const IDisplayRegion_Vtbl)).IsVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WorkAreaOffset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayRegion", "WorkAreaOffset", 8usize, |this| ((**(this as *const *const IDisplayRegion_Vtbl)).WorkAreaOffset)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WorkAreaSize(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayRegion", "WorkAreaSize", 9usize, |this| ((**(this as *const *const IDisplayRegion_Vtbl)).WorkAreaSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WindowingEnvironment(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayRegion", "WindowingEnvironment", 10usize, |this| ((**(this as *const *const IDisplayRegion_Vtbl)).WindowingEnvironment)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Changed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayRegion", "Changed", 11usize, |this| ((**(this as *const *const IDisplayRegion_Vtbl)).Changed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayRegion", "RemoveChanged", 12usize, |this| ((**(this as *const *const IDisplayRegion_Vtbl)).RemoveChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayMonitorDeviceId,
+            IsVisible,
+            WorkAreaOffset,
+            WorkAreaSize,
+            WindowingEnvironment,
+            Changed,
+            RemoveChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayRegion as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFullScreenPresentationConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsExclusive(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenPresentationConfiguration", "IsExclusive", 6usize, |this| ((**(this as *const *const IFullScreenPresentationConfiguration_Vtbl)).IsExclusive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsExclusive(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenPresentationConfiguration", "SetIsExclusive", 7usize, |this| ((**(this as *const *const IFullScreenPresentationConfiguration_Vtbl)).SetIsExclusive)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsExclusive, SetIsExclusive }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFullScreenPresentationConfiguration as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWindowServicesStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllTopLevelWindowIds(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowServicesStatics", "FindAllTopLevelWindowIds", 6usize, |this| ((**(this as *const *const IWindowServicesStatics_Vtbl)).FindAllTopLevelWindowIds)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllTopLevelWindowIds,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllTopLevelWindowIds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowServicesStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWindowingEnvironment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowingEnvironment", "IsEnabled", 6usize, |this| ((**(this as *const *const IWindowingEnvironment_Vtbl)).IsEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Kind(this: *mut core::ffi::c_void, result__: *mut WindowingEnvironmentKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowingEnvironment", "Kind", 7usize, |this| ((**(this as *const *const IWindowingEnvironment_Vtbl)).Kind)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetDisplayRegions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowingEnvironment", "GetDisplayRegions", 8usize, |this| ((**(this as *const *const IWindowingEnvironment_Vtbl)).GetDisplayRegions)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Changed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowingEnvironment", "Changed", 9usize, |this| ((**(this as *const *const IWindowingEnvironment_Vtbl)).Changed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowingEnvironment", "RemoveChanged", 10usize, |this| ((**(this as *const *const IWindowingEnvironment_Vtbl)).RemoveChanged)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEnabled,
+            Kind,
+            #[cfg(feature = "Foundation_Collections")]
+            GetDisplayRegions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetDisplayRegions: 0,
+            Changed,
+            RemoveChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowingEnvironment as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowingEnvironmentAddedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WindowingEnvironment(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowingEnvironmentAddedEventArgs", "WindowingEnvironment", 6usize, |this| ((**(this as *const *const IWindowingEnvironmentAddedEventArgs_Vtbl)).WindowingEnvironment)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WindowingEnvironment }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowingEnvironmentAddedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowingEnvironmentChangedEventArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowingEnvironmentChangedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowingEnvironmentRemovedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WindowingEnvironment(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowingEnvironmentRemovedEventArgs", "WindowingEnvironment", 6usize, |this| ((**(this as *const *const IWindowingEnvironmentRemovedEventArgs_Vtbl)).WindowingEnvironment)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WindowingEnvironment }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowingEnvironmentRemovedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWindowingEnvironmentStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAll(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowingEnvironmentStatics", "FindAll", 6usize, |this| ((**(this as *const *const IWindowingEnvironmentStatics_Vtbl)).FindAll)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn FindAllWithKind(this: *mut core::ffi::c_void, kind: WindowingEnvironmentKind, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowingEnvironmentStatics", "FindAllWithKind", 7usize, |this| ((**(this as *const *const IWindowingEnvironmentStatics_Vtbl)).FindAllWithKind)(this, kind, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAll,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAll: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            FindAllWithKind,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            FindAllWithKind: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowingEnvironmentStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
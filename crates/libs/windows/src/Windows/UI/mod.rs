@@ -1178,7 +1178,8 @@ impl windows_core::RuntimeName for UIContext {
 unsafe impl Send for UIContext {}
 unsafe impl Sync for UIContext {}
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Color {
     pub A: u8,
     pub R: u8,
@@ -1197,7 +1198,8 @@ impl Default for Color {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WindowId {
     pub Value: u64,
 }
@@ -1212,3 +1214,5 @@ impl Default for WindowId {
         unsafe { core::mem::zeroed() }
     }
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,632 @@
+impl windows_core::imp::ProxyVtable for IColorHelper_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IColorHelper as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IColorHelperStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FromArgb(this: *mut core::ffi::c_void, a: u8, r: u8, g: u8, b: u8, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorHelperStatics", "FromArgb", 6usize, |this| ((**(this as *const *const IColorHelperStatics_Vtbl)).FromArgb)(this, a, r, g, b, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FromArgb }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IColorHelperStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IColorHelperStatics2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ToDisplayName(this: *mut core::ffi::c_void, color: Color, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorHelperStatics2", "ToDisplayName", 6usize, |this| ((**(this as *const *const IColorHelperStatics2_Vtbl)).ToDisplayName)(this, color, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ToDisplayName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IColorHelperStatics2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IColors_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IColors as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IColorsStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AliceBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "AliceBlue", 6usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).AliceBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AntiqueWhite(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "AntiqueWhite", 7usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).AntiqueWhite)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Aqua(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Aqua", 8usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Aqua)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Aquamarine(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Aquamarine", 9usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Aquamarine)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Azure(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Azure", 10usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Azure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Beige(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Beige", 11usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Beige)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bisque(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Bisque", 12usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Bisque)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Black(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Black", 13usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Black)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlanchedAlmond(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "BlanchedAlmond", 14usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).BlanchedAlmond)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Blue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Blue", 15usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Blue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlueViolet(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "BlueViolet", 16usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).BlueViolet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Brown(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Brown", 17usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Brown)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BurlyWood(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "BurlyWood", 18usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).BurlyWood)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CadetBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "CadetBlue", 19usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).CadetBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Chartreuse(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Chartreuse", 20usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Chartreuse)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Chocolate(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Chocolate", 21usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Chocolate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Coral(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Coral", 22usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Coral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CornflowerBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "CornflowerBlue", 23usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).CornflowerBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cornsilk(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Cornsilk", 24usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Cornsilk)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Crimson(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Crimson", 25usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Crimson)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cyan(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Cyan", 26usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Cyan)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkBlue", 27usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkCyan(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkCyan", 28usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkCyan)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkGoldenrod(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkGoldenrod", 29usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkGoldenrod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkGray(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkGray", 30usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkGray)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkGreen(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkGreen", 31usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkGreen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkKhaki(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkKhaki", 32usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkKhaki)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkMagenta(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkMagenta", 33usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkMagenta)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkOliveGreen(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkOliveGreen", 34usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkOliveGreen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkOrange(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkOrange", 35usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkOrange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkOrchid(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkOrchid", 36usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkOrchid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkRed(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkRed", 37usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkRed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkSalmon(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkSalmon", 38usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkSalmon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkSeaGreen(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkSeaGreen", 39usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkSeaGreen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkSlateBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkSlateBlue", 40usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkSlateBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkSlateGray(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkSlateGray", 41usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkSlateGray)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkTurquoise(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkTurquoise", 42usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkTurquoise)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DarkViolet(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DarkViolet", 43usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DarkViolet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeepPink(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DeepPink", 44usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DeepPink)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeepSkyBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DeepSkyBlue", 45usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DeepSkyBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DimGray(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DimGray", 46usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DimGray)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DodgerBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "DodgerBlue", 47usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).DodgerBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Firebrick(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Firebrick", 48usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Firebrick)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn FloralWhite(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "FloralWhite", 49usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).FloralWhite)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForestGreen(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "ForestGreen", 50usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).ForestGreen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Fuchsia(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Fuchsia", 51usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Fuchsia)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gainsboro(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Gainsboro", 52usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Gainsboro)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GhostWhite(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "GhostWhite", 53usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).GhostWhite)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gold(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Gold", 54usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Gold)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Goldenrod(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Goldenrod", 55usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Goldenrod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Gray(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Gray", 56usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Gray)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Green(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Green", 57usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Green)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GreenYellow(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "GreenYellow", 58usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).GreenYellow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Honeydew(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Honeydew", 59usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Honeydew)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn HotPink(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "HotPink", 60usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).HotPink)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IndianRed(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "IndianRed", 61usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).IndianRed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Indigo(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Indigo", 62usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Indigo)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ivory(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Ivory", 63usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Ivory)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Khaki(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Khaki", 64usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Khaki)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Lavender(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Lavender", 65usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Lavender)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LavenderBlush(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LavenderBlush", 66usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LavenderBlush)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LawnGreen(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LawnGreen", 67usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LawnGreen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LemonChiffon(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LemonChiffon", 68usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LemonChiffon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LightBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LightBlue", 69usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LightBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LightCoral(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LightCoral", 70usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LightCoral)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LightCyan(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LightCyan", 71usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LightCyan)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LightGoldenrodYellow(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LightGoldenrodYellow", 72usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LightGoldenrodYellow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LightGreen(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LightGreen", 73usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LightGreen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LightGray(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LightGray", 74usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LightGray)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LightPink(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LightPink", 75usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LightPink)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LightSalmon(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LightSalmon", 76usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LightSalmon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LightSeaGreen(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LightSeaGreen", 77usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LightSeaGreen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LightSkyBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LightSkyBlue", 78usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LightSkyBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LightSlateGray(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LightSlateGray", 79usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LightSlateGray)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LightSteelBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LightSteelBlue", 80usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LightSteelBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LightYellow(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LightYellow", 81usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LightYellow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Lime(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Lime", 82usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Lime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LimeGreen(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "LimeGreen", 83usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).LimeGreen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Linen(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Linen", 84usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Linen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Magenta(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Magenta", 85usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Magenta)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Maroon(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Maroon", 86usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Maroon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediumAquamarine(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "MediumAquamarine", 87usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).MediumAquamarine)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediumBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "MediumBlue", 88usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).MediumBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediumOrchid(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "MediumOrchid", 89usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).MediumOrchid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediumPurple(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "MediumPurple", 90usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).MediumPurple)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediumSeaGreen(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "MediumSeaGreen", 91usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).MediumSeaGreen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediumSlateBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "MediumSlateBlue", 92usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).MediumSlateBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediumSpringGreen(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "MediumSpringGreen", 93usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).MediumSpringGreen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediumTurquoise(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "MediumTurquoise", 94usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).MediumTurquoise)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediumVioletRed(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "MediumVioletRed", 95usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).MediumVioletRed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MidnightBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "MidnightBlue", 96usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).MidnightBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MintCream(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "MintCream", 97usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).MintCream)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MistyRose(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "MistyRose", 98usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).MistyRose)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Moccasin(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Moccasin", 99usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Moccasin)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NavajoWhite(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "NavajoWhite", 100usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).NavajoWhite)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Navy(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Navy", 101usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Navy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OldLace(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "OldLace", 102usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).OldLace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Olive(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Olive", 103usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Olive)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OliveDrab(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "OliveDrab", 104usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).OliveDrab)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Orange(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Orange", 105usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Orange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn OrangeRed(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "OrangeRed", 106usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).OrangeRed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Orchid(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Orchid", 107usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Orchid)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PaleGoldenrod(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "PaleGoldenrod", 108usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).PaleGoldenrod)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PaleGreen(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "PaleGreen", 109usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).PaleGreen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PaleTurquoise(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "PaleTurquoise", 110usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).PaleTurquoise)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PaleVioletRed(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "PaleVioletRed", 111usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).PaleVioletRed)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PapayaWhip(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "PapayaWhip", 112usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).PapayaWhip)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PeachPuff(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "PeachPuff", 113usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).PeachPuff)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Peru(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Peru", 114usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Peru)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pink(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Pink", 115usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Pink)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Plum(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Plum", 116usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Plum)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PowderBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "PowderBlue", 117usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).PowderBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Purple(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Purple", 118usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Purple)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Red(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Red", 119usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Red)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RosyBrown(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "RosyBrown", 120usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).RosyBrown)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoyalBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "RoyalBlue", 121usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).RoyalBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaddleBrown(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "SaddleBrown", 122usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).SaddleBrown)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Salmon(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Salmon", 123usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Salmon)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SandyBrown(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "SandyBrown", 124usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).SandyBrown)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeaGreen(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "SeaGreen", 125usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).SeaGreen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeaShell(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "SeaShell", 126usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).SeaShell)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sienna(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Sienna", 127usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Sienna)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Silver(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Silver", 128usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Silver)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SkyBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "SkyBlue", 129usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).SkyBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SlateBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "SlateBlue", 130usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).SlateBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SlateGray(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "SlateGray", 131usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).SlateGray)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Snow(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Snow", 132usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Snow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpringGreen(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "SpringGreen", 133usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).SpringGreen)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SteelBlue(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "SteelBlue", 134usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).SteelBlue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tan(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Tan", 135usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Tan)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Teal(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Teal", 136usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Teal)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Thistle(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Thistle", 137usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Thistle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tomato(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Tomato", 138usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Tomato)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Transparent(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Transparent", 139usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Transparent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Turquoise(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Turquoise", 140usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Turquoise)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Violet(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Violet", 141usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Violet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wheat(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Wheat", 142usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Wheat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn White(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "White", 143usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).White)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WhiteSmoke(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "WhiteSmoke", 144usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).WhiteSmoke)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Yellow(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "Yellow", 145usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).Yellow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn YellowGreen(this: *mut core::ffi::c_void, result__: *mut Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColorsStatics", "YellowGreen", 146usize, |this| ((**(this as *const *const IColorsStatics_Vtbl)).YellowGreen)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AliceBlue,
+            AntiqueWhite,
+            Aqua,
+            Aquamarine,
+            Azure,
+            Beige,
+            Bisque,
+            Black,
+            BlanchedAlmond,
+            Blue,
+            BlueViolet,
+            Brown,
+            BurlyWood,
+            CadetBlue,
+            Chartreuse,
+            Chocolate,
+            Coral,
+            CornflowerBlue,
+            Cornsilk,
+            Crimson,
+            Cyan,
+            DarkBlue,
+            DarkCyan,
+            DarkGoldenrod,
+            DarkGray,
+            DarkGreen,
+            DarkKhaki,
+            DarkMagenta,
+            DarkOliveGreen,
+            DarkOrange,
+            DarkOrchid,
+            DarkRed,
+            DarkSalmon,
+            DarkSeaGreen,
+            DarkSlateBlue,
+            DarkSlateGray,
+            DarkTurquoise,
+            DarkViolet,
+            DeepPink,
+            DeepSkyBlue,
+            DimGray,
+            DodgerBlue,
+            Firebrick,
+            FloralWhite,
+            ForestGreen,
+            Fuchsia,
+            Gainsboro,
+            GhostWhite,
+            Gold,
+            Goldenrod,
+            Gray,
+            Green,
+            GreenYellow,
+            Honeydew,
+            HotPink,
+            IndianRed,
+            Indigo,
+            Ivory,
+            Khaki,
+            Lavender,
+            LavenderBlush,
+            LawnGreen,
+            LemonChiffon,
+            LightBlue,
+            LightCoral,
+            LightCyan,
+            LightGoldenrodYellow,
+            LightGreen,
+            LightGray,
+            LightPink,
+            LightSalmon,
+            LightSeaGreen,
+            LightSkyBlue,
+            LightSlateGray,
+            LightSteelBlue,
+            LightYellow,
+            Lime,
+            LimeGreen,
+            Linen,
+            Magenta,
+            Maroon,
+            MediumAquamarine,
+            MediumBlue,
+            MediumOrchid,
+            MediumPurple,
+            MediumSeaGreen,
+            MediumSlateBlue,
+            MediumSpringGreen,
+            MediumTurquoise,
+            MediumVioletRed,
+            MidnightBlue,
+            MintCream,
+            MistyRose,
+            Moccasin,
+            NavajoWhite,
+            Navy,
+            OldLace,
+            Olive,
+            OliveDrab,
+            Orange,
+            OrangeRed,
+            Orchid,
+            PaleGoldenrod,
+            PaleGreen,
+            PaleTurquoise,
+            PaleVioletRed,
+            PapayaWhip,
+            PeachPuff,
+            Peru,
+            Pink,
+            Plum,
+            PowderBlue,
+            Purple,
+            Red,
+            RosyBrown,
+            RoyalBlue,
+            SaddleBrown,
+            Salmon,
+            SandyBrown,
+            SeaGreen,
+            SeaShell,
+            Sienna,
+            Silver,
+            SkyBlue,
+            SlateBlue,
+            SlateGray,
+            Snow,
+            SpringGreen,
+            SteelBlue,
+            Tan,
+            Teal,
+            Thistle,
+            Tomato,
+            Transparent,
+            Turquoise,
+            Violet,
+            Wheat,
+            White,
+            WhiteSmoke,
+            Yellow,
+            YellowGreen,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IColorsStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIContentRoot_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UIContext(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIContentRoot", "UIContext", 6usize, |this| ((**(this as *const *const IUIContentRoot_Vtbl)).UIContext)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UIContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIContentRoot as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIContext_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIContext as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -97,6 +97,7 @@ pub struct IO_PRIORITY_HINT(pub i32);
 impl windows_core::TypeKind for IO_PRIORITY_HINT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IO_PRIORITY_HINT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IO_PRIORITY_HINT").field(&self.0).finish()
@@ -108,6 +109,7 @@ pub struct KSPIN_LOCK_QUEUE_NUMBER(pub i32);
 impl windows_core::TypeKind for KSPIN_LOCK_QUEUE_NUMBER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPIN_LOCK_QUEUE_NUMBER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPIN_LOCK_QUEUE_NUMBER").field(&self.0).finish()
@@ -119,6 +121,7 @@ pub struct OBJECT_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for OBJECT_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OBJECT_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OBJECT_INFORMATION_CLASS").field(&self.0).finish()
@@ -130,6 +133,7 @@ pub struct POOL_TYPE(pub i32);
 impl windows_core::TypeKind for POOL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POOL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POOL_TYPE").field(&self.0).finish()
@@ -242,7 +246,8 @@ impl Default for DEVICE_OBJECT_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVOBJ_EXTENSION {
     pub Type: i16,
     pub Size: u16,
@@ -330,7 +335,8 @@ impl Default for DISPATCHER_HEADER_0_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPATCHER_HEADER_0_1 {
     pub Type: u8,
     pub Signalling: u8,
@@ -385,11 +391,33 @@ impl Default for DISPATCHER_HEADER_0_2_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPATCHER_HEADER_0_2_0_0 {
     pub _bitfield: u8,
 }
 #[cfg(feature = "Win32_System_Kernel")]
+impl DISPATCHER_HEADER_0_2_0_0 {
+    pub const fn Absolute(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_Absolute(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Wake(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Wake(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn EncodedTolerableDelay(&self) -> u8 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_EncodedTolerableDelay(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+}
+#[cfg(feature = "Win32_System_Kernel")]
 impl windows_core::TypeKind for DISPATCHER_HEADER_0_2_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -418,11 +446,39 @@ impl Default for DISPATCHER_HEADER_0_2_1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPATCHER_HEADER_0_2_1_0 {
     pub _bitfield: u8,
 }
 #[cfg(feature = "Win32_System_Kernel")]
+impl DISPATCHER_HEADER_0_2_1_0 {
+    pub const fn Index(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_Index(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Processor(&self) -> u8 {
+        (self._bitfield >> 1) & 31
+    }
+    pub fn set_Processor(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(31 << 1)) | ((value & 31) << 1);
+    }
+    pub const fn Inserted(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Inserted(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Expired(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Expired(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
+#[cfg(feature = "Win32_System_Kernel")]
 impl windows_core::TypeKind for DISPATCHER_HEADER_0_2_1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -470,11 +526,57 @@ impl Default for DISPATCHER_HEADER_0_3_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPATCHER_HEADER_0_3_0_0 {
     pub _bitfield: u8,
 }
 #[cfg(feature = "Win32_System_Kernel")]
+impl DISPATCHER_HEADER_0_3_0_0 {
+    pub const fn Timer2Inserted(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_Timer2Inserted(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Timer2Expiring(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Timer2Expiring(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Timer2CancelPending(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Timer2CancelPending(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Timer2SetPending(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Timer2SetPending(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Timer2Running(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Timer2Running(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Timer2Disabled(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Timer2Disabled(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Timer2ReservedFlags(&self) -> u8 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_Timer2ReservedFlags(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+}
+#[cfg(feature = "Win32_System_Kernel")]
 impl windows_core::TypeKind for DISPATCHER_HEADER_0_3_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -522,11 +624,33 @@ impl Default for DISPATCHER_HEADER_0_4_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPATCHER_HEADER_0_4_0_0 {
     pub _bitfield: u8,
 }
 #[cfg(feature = "Win32_System_Kernel")]
+impl DISPATCHER_HEADER_0_4_0_0 {
+    pub const fn Abandoned(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_Abandoned(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DisableIncrement(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DisableIncrement(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn QueueReservedControlFlags(&self) -> u8 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_QueueReservedControlFlags(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+}
+#[cfg(feature = "Win32_System_Kernel")]
 impl windows_core::TypeKind for DISPATCHER_HEADER_0_4_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -574,11 +698,63 @@ impl Default for DISPATCHER_HEADER_0_5_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPATCHER_HEADER_0_5_0_0 {
     pub _bitfield: u8,
 }
 #[cfg(feature = "Win32_System_Kernel")]
+impl DISPATCHER_HEADER_0_5_0_0 {
+    pub const fn CycleProfiling(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_CycleProfiling(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CounterProfiling(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CounterProfiling(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn GroupScheduling(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_GroupScheduling(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AffinitySet(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AffinitySet(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Tagged(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Tagged(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn EnergyProfiling(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_EnergyProfiling(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn SchedulerAssist(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_SchedulerAssist(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Instrumented(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Instrumented(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
+#[cfg(feature = "Win32_System_Kernel")]
 impl windows_core::TypeKind for DISPATCHER_HEADER_0_5_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -606,7 +782,8 @@ impl Default for DISPATCHER_HEADER_0_5_1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPATCHER_HEADER_0_6 {
     pub MutantType: u8,
     pub MutantSize: u8,
@@ -624,7 +801,8 @@ impl Default for DISPATCHER_HEADER_0_6 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMA_COMMON_BUFFER_VECTOR(pub isize);
 impl Default for DMA_COMMON_BUFFER_VECTOR {
     fn default() -> Self {
@@ -636,7 +814,8 @@ impl windows_core::TypeKind for DMA_COMMON_BUFFER_VECTOR {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRIVER_EXTENSION {
     pub DriverObject: *mut DRIVER_OBJECT,
     pub AddDevice: DRIVER_ADD_DEVICE,
@@ -655,7 +834,8 @@ impl Default for DRIVER_EXTENSION {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRIVER_OBJECT {
     pub Type: i16,
     pub Size: i16,
@@ -684,7 +864,8 @@ impl Default for DRIVER_OBJECT {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ECP_HEADER(pub isize);
 impl Default for ECP_HEADER {
     fn default() -> Self {
@@ -695,7 +876,8 @@ impl windows_core::TypeKind for ECP_HEADER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ECP_LIST(pub isize);
 impl Default for ECP_LIST {
     fn default() -> Self {
@@ -752,7 +934,8 @@ impl Default for ERESOURCE_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ERESOURCE_0_0 {
     pub ReservedLowFlags: u8,
     pub WaiterPriority: u8,
@@ -786,7 +969,8 @@ impl Default for ERESOURCE_1 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAST_IO_DISPATCH {
     pub SizeOfFastIoDispatch: u32,
     pub FastIoCheckIfPossible: FAST_IO_CHECK_IF_POSSIBLE,
@@ -893,7 +1077,8 @@ impl Default for FILE_OBJECT {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IOMMU_DMA_DEVICE(pub isize);
 impl Default for IOMMU_DMA_DEVICE {
     fn default() -> Self {
@@ -904,7 +1089,8 @@ impl windows_core::TypeKind for IOMMU_DMA_DEVICE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IOMMU_DMA_DOMAIN(pub isize);
 impl Default for IOMMU_DMA_DOMAIN {
     fn default() -> Self {
@@ -915,7 +1101,8 @@ impl windows_core::TypeKind for IOMMU_DMA_DOMAIN {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_COMPLETION_CONTEXT {
     pub Port: *mut core::ffi::c_void,
     pub Key: *mut core::ffi::c_void,
@@ -931,7 +1118,8 @@ impl Default for IO_COMPLETION_CONTEXT {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_System_SystemServices", feature = "Win32_Security"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_SECURITY_CONTEXT {
     pub SecurityQos: *mut super::super::Win32::Security::SECURITY_QUALITY_OF_SERVICE,
     pub AccessState: *mut ACCESS_STATE,
@@ -1028,7 +1216,8 @@ impl Default for IO_STACK_LOCATION_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_0 {
     pub SecurityContext: *mut IO_SECURITY_CONTEXT,
     pub Options: u32,
@@ -1048,7 +1237,8 @@ impl Default for IO_STACK_LOCATION_0_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_1 {
     pub SecurityContext: *mut IO_SECURITY_CONTEXT,
     pub Options: u32,
@@ -1068,7 +1258,8 @@ impl Default for IO_STACK_LOCATION_0_1 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_2 {
     pub SecurityContext: *mut IO_SECURITY_CONTEXT,
     pub Options: u32,
@@ -1088,7 +1279,8 @@ impl Default for IO_STACK_LOCATION_0_2 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_3 {
     pub Capabilities: *mut super::System::SystemServices::DEVICE_CAPABILITIES,
 }
@@ -1104,7 +1296,8 @@ impl Default for IO_STACK_LOCATION_0_3 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_4 {
     pub OutputBufferLength: u32,
     pub InputBufferLength: u32,
@@ -1123,7 +1316,8 @@ impl Default for IO_STACK_LOCATION_0_4 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_5 {
     pub OutputBufferLength: u32,
     pub InputBufferLength: u32,
@@ -1142,7 +1336,8 @@ impl Default for IO_STACK_LOCATION_0_5 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_6 {
     pub IoResourceRequirementList: *mut super::System::SystemServices::IO_RESOURCE_REQUIREMENTS_LIST,
 }
@@ -1176,7 +1371,8 @@ impl Default for IO_STACK_LOCATION_0_7 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_8 {
     pub Vpb: *mut VPB,
     pub DeviceObject: *mut DEVICE_OBJECT,
@@ -1193,7 +1389,8 @@ impl Default for IO_STACK_LOCATION_0_8 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_9 {
     pub Length: u32,
     pub CompletionFilter: u32,
@@ -1211,7 +1408,8 @@ impl Default for IO_STACK_LOCATION_0_9 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_10 {
     pub Length: u32,
     pub CompletionFilter: u32,
@@ -1228,7 +1426,8 @@ impl Default for IO_STACK_LOCATION_0_10 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_11 {
     pub Argument1: *mut core::ffi::c_void,
     pub Argument2: *mut core::ffi::c_void,
@@ -1247,7 +1446,8 @@ impl Default for IO_STACK_LOCATION_0_11 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_12 {
     pub PowerSequence: *mut super::System::SystemServices::POWER_SEQUENCE,
 }
@@ -1299,7 +1499,8 @@ impl Default for IO_STACK_LOCATION_0_13_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_14 {
     pub Type: super::System::SystemServices::DEVICE_RELATION_TYPE,
 }
@@ -1315,7 +1516,8 @@ impl Default for IO_STACK_LOCATION_0_14 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_15 {
     pub DeviceTextType: super::System::SystemServices::DEVICE_TEXT_TYPE,
     pub LocaleId: u32,
@@ -1332,7 +1534,8 @@ impl Default for IO_STACK_LOCATION_0_15 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_16 {
     pub Length: u32,
     pub FileName: *mut super::super::Win32::Foundation::UNICODE_STRING,
@@ -1351,7 +1554,8 @@ impl Default for IO_STACK_LOCATION_0_16 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_17 {
     pub Length: u32,
     pub EaList: *mut core::ffi::c_void,
@@ -1370,7 +1574,8 @@ impl Default for IO_STACK_LOCATION_0_17 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_18 {
     pub Length: u32,
     pub FileInformationClass: super::Storage::FileSystem::FILE_INFORMATION_CLASS,
@@ -1387,7 +1592,8 @@ impl Default for IO_STACK_LOCATION_0_18 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_19 {
     pub IdType: super::System::SystemServices::BUS_QUERY_ID_TYPE,
 }
@@ -1403,7 +1609,8 @@ impl Default for IO_STACK_LOCATION_0_19 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_20 {
     pub InterfaceType: *const windows_core::GUID,
     pub Size: u16,
@@ -1423,7 +1630,8 @@ impl Default for IO_STACK_LOCATION_0_20 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_21 {
     pub Length: u32,
     pub StartSid: super::super::Win32::Security::PSID,
@@ -1442,7 +1650,8 @@ impl Default for IO_STACK_LOCATION_0_21 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_22 {
     pub SecurityInformation: u32,
     pub Length: u32,
@@ -1459,7 +1668,8 @@ impl Default for IO_STACK_LOCATION_0_22 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_23 {
     pub Length: u32,
     pub FsInformationClass: super::Storage::FileSystem::FS_INFORMATION_CLASS,
@@ -1476,7 +1686,8 @@ impl Default for IO_STACK_LOCATION_0_23 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_24 {
     pub WhichSpace: u32,
     pub Buffer: *mut core::ffi::c_void,
@@ -1513,7 +1724,8 @@ impl Default for IO_STACK_LOCATION_0_25 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_26 {
     pub Srb: *mut _SCSI_REQUEST_BLOCK,
 }
@@ -1529,7 +1741,8 @@ impl Default for IO_STACK_LOCATION_0_26 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_27 {
     pub Length: u32,
 }
@@ -1582,7 +1795,8 @@ impl Default for IO_STACK_LOCATION_0_28_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_28_0_0 {
     pub ReplaceIfExists: super::super::Win32::Foundation::BOOLEAN,
     pub AdvanceOnly: super::super::Win32::Foundation::BOOLEAN,
@@ -1599,7 +1813,8 @@ impl Default for IO_STACK_LOCATION_0_28_0_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_29 {
     pub Lock: super::super::Win32::Foundation::BOOLEAN,
 }
@@ -1615,7 +1830,8 @@ impl Default for IO_STACK_LOCATION_0_29 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_30 {
     pub Length: u32,
 }
@@ -1631,7 +1847,8 @@ impl Default for IO_STACK_LOCATION_0_30 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_31 {
     pub SecurityInformation: u32,
     pub SecurityDescriptor: super::super::Win32::Security::PSECURITY_DESCRIPTOR,
@@ -1648,7 +1865,8 @@ impl Default for IO_STACK_LOCATION_0_31 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_32 {
     pub Length: u32,
     pub FsInformationClass: super::Storage::FileSystem::FS_INFORMATION_CLASS,
@@ -1665,7 +1883,8 @@ impl Default for IO_STACK_LOCATION_0_32 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_33 {
     pub AllocatedResources: *mut super::System::SystemServices::CM_RESOURCE_LIST,
     pub AllocatedResourcesTranslated: *mut super::System::SystemServices::CM_RESOURCE_LIST,
@@ -1682,7 +1901,8 @@ impl Default for IO_STACK_LOCATION_0_33 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_34 {
     pub InPath: super::super::Win32::Foundation::BOOLEAN,
     pub Reserved: [super::super::Win32::Foundation::BOOLEAN; 3],
@@ -1700,7 +1920,8 @@ impl Default for IO_STACK_LOCATION_0_34 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_35 {
     pub Vpb: *mut VPB,
     pub DeviceObject: *mut DEVICE_OBJECT,
@@ -1717,7 +1938,8 @@ impl Default for IO_STACK_LOCATION_0_35 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_36 {
     pub ProviderId: usize,
     pub DataPath: *mut core::ffi::c_void,
@@ -1736,7 +1958,8 @@ impl Default for IO_STACK_LOCATION_0_36 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STACK_LOCATION_0_37 {
     pub PowerState: super::super::Win32::System::Power::SYSTEM_POWER_STATE,
 }
@@ -1964,7 +2187,8 @@ impl Default for IRP_3_0_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IRP_3_0_0_0 {
     pub DriverContext: [*mut core::ffi::c_void; 4],
 }
@@ -2014,7 +2238,8 @@ impl Default for IRP_3_0_1_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KDEVICE_QUEUE {
     pub Type: i16,
     pub Size: i16,
@@ -2074,7 +2299,8 @@ impl Default for KDPC_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KDPC_0_0 {
     pub Type: u8,
     pub Importance: u8,
@@ -2091,7 +2317,8 @@ impl Default for KDPC_0_0 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KENLISTMENT(pub isize);
 impl Default for KENLISTMENT {
     fn default() -> Self {
@@ -2118,7 +2345,8 @@ impl Default for KEVENT {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KGDT(pub isize);
 impl Default for KGDT {
     fn default() -> Self {
@@ -2129,7 +2357,8 @@ impl windows_core::TypeKind for KGDT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KIDT(pub isize);
 impl Default for KIDT {
     fn default() -> Self {
@@ -2178,11 +2407,27 @@ impl Default for KMUTANT_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KMUTANT_0_0 {
     pub _bitfield: u8,
 }
 #[cfg(feature = "Win32_System_Kernel")]
+impl KMUTANT_0_0 {
+    pub const fn Abandoned(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_Abandoned(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Spare1(&self) -> u8 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_Spare1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+}
+#[cfg(feature = "Win32_System_Kernel")]
 impl windows_core::TypeKind for KMUTANT_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2193,7 +2438,8 @@ impl Default for KMUTANT_0_0 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KPCR(pub isize);
 impl Default for KPCR {
     fn default() -> Self {
@@ -2204,7 +2450,8 @@ impl windows_core::TypeKind for KPCR {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KPRCB(pub isize);
 impl Default for KPRCB {
     fn default() -> Self {
@@ -2235,7 +2482,8 @@ impl Default for KQUEUE {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KRESOURCEMANAGER(pub isize);
 impl Default for KRESOURCEMANAGER {
     fn default() -> Self {
@@ -2246,7 +2494,8 @@ impl windows_core::TypeKind for KRESOURCEMANAGER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KTM(pub isize);
 impl Default for KTM {
     fn default() -> Self {
@@ -2257,7 +2506,8 @@ impl windows_core::TypeKind for KTM {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KTRANSACTION(pub isize);
 impl Default for KTRANSACTION {
     fn default() -> Self {
@@ -2268,7 +2518,8 @@ impl windows_core::TypeKind for KTRANSACTION {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KTSS(pub isize);
 impl Default for KTSS {
     fn default() -> Self {
@@ -2319,7 +2570,8 @@ impl Default for KWAIT_BLOCK_0 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LOADER_PARAMETER_BLOCK(pub isize);
 impl Default for LOADER_PARAMETER_BLOCK {
     fn default() -> Self {
@@ -2330,7 +2582,8 @@ impl windows_core::TypeKind for LOADER_PARAMETER_BLOCK {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MDL {
     pub Next: *mut MDL,
     pub Size: i16,
@@ -2350,7 +2603,8 @@ impl Default for MDL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OBJECT_ATTRIBUTES {
     pub Length: u32,
     pub RootDirectory: super::super::Win32::Foundation::HANDLE,
@@ -2368,7 +2622,8 @@ impl Default for OBJECT_ATTRIBUTES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OBJECT_ATTRIBUTES32 {
     pub Length: u32,
     pub RootDirectory: u32,
@@ -2386,7 +2641,8 @@ impl Default for OBJECT_ATTRIBUTES32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OBJECT_ATTRIBUTES64 {
     pub Length: u32,
     pub RootDirectory: u64,
@@ -2404,7 +2660,8 @@ impl Default for OBJECT_ATTRIBUTES64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OBJECT_NAME_INFORMATION {
     pub Name: super::super::Win32::Foundation::UNICODE_STRING,
 }
@@ -2445,10 +2702,37 @@ impl Default for OWNER_ENTRY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OWNER_ENTRY_0_0 {
     pub _bitfield: u32,
 }
+impl OWNER_ENTRY_0_0 {
+    pub const fn IoPriorityBoosted(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_IoPriorityBoosted(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn OwnerReferenced(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_OwnerReferenced(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn IoQoSPriorityBoosted(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_IoQoSPriorityBoosted(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn OwnerCount(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_OwnerCount(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for OWNER_ENTRY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2458,7 +2742,8 @@ impl Default for OWNER_ENTRY_0_0 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PAFFINITY_TOKEN(pub isize);
 impl Default for PAFFINITY_TOKEN {
     fn default() -> Self {
@@ -2469,7 +2754,8 @@ impl windows_core::TypeKind for PAFFINITY_TOKEN {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PBUS_HANDLER(pub isize);
 impl Default for PBUS_HANDLER {
     fn default() -> Self {
@@ -2480,7 +2766,8 @@ impl windows_core::TypeKind for PBUS_HANDLER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCALLBACK_OBJECT(pub isize);
 impl Default for PCALLBACK_OBJECT {
     fn default() -> Self {
@@ -2491,7 +2778,8 @@ impl windows_core::TypeKind for PCALLBACK_OBJECT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDEVICE_HANDLER_OBJECT(pub isize);
 impl Default for PDEVICE_HANDLER_OBJECT {
     fn default() -> Self {
@@ -2502,7 +2790,8 @@ impl windows_core::TypeKind for PDEVICE_HANDLER_OBJECT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEJOB(pub isize);
 impl Default for PEJOB {
     fn default() -> Self {
@@ -2513,7 +2802,8 @@ impl windows_core::TypeKind for PEJOB {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEPROCESS(pub isize);
 impl Default for PEPROCESS {
     fn default() -> Self {
@@ -2524,7 +2814,8 @@ impl windows_core::TypeKind for PEPROCESS {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PESILO(pub isize);
 impl Default for PESILO {
     fn default() -> Self {
@@ -2535,7 +2826,8 @@ impl windows_core::TypeKind for PESILO {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PETHREAD(pub isize);
 impl Default for PETHREAD {
     fn default() -> Self {
@@ -2546,7 +2838,8 @@ impl windows_core::TypeKind for PETHREAD {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEX_RUNDOWN_REF_CACHE_AWARE(pub isize);
 impl Default for PEX_RUNDOWN_REF_CACHE_AWARE {
     fn default() -> Self {
@@ -2557,7 +2850,8 @@ impl windows_core::TypeKind for PEX_RUNDOWN_REF_CACHE_AWARE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEX_TIMER(pub isize);
 impl Default for PEX_TIMER {
     fn default() -> Self {
@@ -2568,7 +2862,8 @@ impl windows_core::TypeKind for PEX_TIMER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PIO_REMOVE_LOCK_TRACKING_BLOCK(pub isize);
 impl Default for PIO_REMOVE_LOCK_TRACKING_BLOCK {
     fn default() -> Self {
@@ -2579,7 +2874,8 @@ impl windows_core::TypeKind for PIO_REMOVE_LOCK_TRACKING_BLOCK {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PIO_TIMER(pub isize);
 impl Default for PIO_TIMER {
     fn default() -> Self {
@@ -2590,7 +2886,8 @@ impl windows_core::TypeKind for PIO_TIMER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PIO_WORKITEM(pub isize);
 impl Default for PIO_WORKITEM {
     fn default() -> Self {
@@ -2601,7 +2898,8 @@ impl windows_core::TypeKind for PIO_WORKITEM {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PKINTERRUPT(pub isize);
 impl Default for PKINTERRUPT {
     fn default() -> Self {
@@ -2612,7 +2910,8 @@ impl windows_core::TypeKind for PKINTERRUPT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PKPROCESS(pub isize);
 impl Default for PKPROCESS {
     fn default() -> Self {
@@ -2623,7 +2922,8 @@ impl windows_core::TypeKind for PKPROCESS {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PKTHREAD(pub isize);
 impl Default for PKTHREAD {
     fn default() -> Self {
@@ -2634,7 +2934,8 @@ impl windows_core::TypeKind for PKTHREAD {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PNOTIFY_SYNC(pub isize);
 impl Default for PNOTIFY_SYNC {
     fn default() -> Self {
@@ -2645,7 +2946,8 @@ impl windows_core::TypeKind for PNOTIFY_SYNC {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POBJECT_TYPE(pub isize);
 impl Default for POBJECT_TYPE {
     fn default() -> Self {
@@ -2656,7 +2958,8 @@ impl windows_core::TypeKind for POBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POHANDLE(pub *mut core::ffi::c_void);
 impl POHANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -2672,7 +2975,8 @@ impl windows_core::TypeKind for POHANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PPCW_BUFFER(pub isize);
 impl Default for PPCW_BUFFER {
     fn default() -> Self {
@@ -2683,7 +2987,8 @@ impl windows_core::TypeKind for PPCW_BUFFER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PPCW_INSTANCE(pub isize);
 impl Default for PPCW_INSTANCE {
     fn default() -> Self {
@@ -2694,7 +2999,8 @@ impl windows_core::TypeKind for PPCW_INSTANCE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PPCW_REGISTRATION(pub isize);
 impl Default for PPCW_REGISTRATION {
     fn default() -> Self {
@@ -2705,7 +3011,8 @@ impl windows_core::TypeKind for PPCW_REGISTRATION {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PRKPROCESS(pub isize);
 impl Default for PRKPROCESS {
     fn default() -> Self {
@@ -2716,7 +3023,8 @@ impl windows_core::TypeKind for PRKPROCESS {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PRKTHREAD(pub isize);
 impl Default for PRKTHREAD {
     fn default() -> Self {
@@ -2727,7 +3035,8 @@ impl windows_core::TypeKind for PRKTHREAD {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PSILO_MONITOR(pub isize);
 impl Default for PSILO_MONITOR {
     fn default() -> Self {
@@ -2738,7 +3047,8 @@ impl windows_core::TypeKind for PSILO_MONITOR {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RTL_SPLAY_LINKS {
     pub Parent: *mut RTL_SPLAY_LINKS,
     pub LeftChild: *mut RTL_SPLAY_LINKS,
@@ -2753,7 +3063,8 @@ impl Default for RTL_SPLAY_LINKS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SECTION_OBJECT_POINTERS {
     pub DataSectionObject: *mut core::ffi::c_void,
     pub SharedCacheMap: *mut core::ffi::c_void,
@@ -2769,7 +3080,8 @@ impl Default for SECTION_OBJECT_POINTERS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Security")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SECURITY_SUBJECT_CONTEXT {
     pub ClientToken: *mut core::ffi::c_void,
     pub ImpersonationLevel: super::super::Win32::Security::SECURITY_IMPERSONATION_LEVEL,
@@ -2787,7 +3099,8 @@ impl Default for SECURITY_SUBJECT_CONTEXT {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SspiAsyncContext(pub isize);
 impl Default for SspiAsyncContext {
     fn default() -> Self {
@@ -2799,7 +3112,8 @@ impl windows_core::TypeKind for SspiAsyncContext {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TARGET_DEVICE_CUSTOM_NOTIFICATION {
     pub Version: u16,
     pub Size: u16,
@@ -2820,7 +3134,8 @@ impl Default for TARGET_DEVICE_CUSTOM_NOTIFICATION {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Storage_FileSystem", feature = "Wdk_System_SystemServices", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VPB {
     pub Type: i16,
     pub Size: i16,
@@ -2844,7 +3159,8 @@ impl Default for VPB {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WORK_QUEUE_ITEM {
     pub List: super::super::Win32::System::Kernel::LIST_ENTRY,
     pub WorkerRoutine: PWORKER_THREAD_ROUTINE,
@@ -2861,7 +3177,8 @@ impl Default for WORK_QUEUE_ITEM {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _DEVICE_OBJECT_POWER_EXTENSION(pub isize);
 impl Default for _DEVICE_OBJECT_POWER_EXTENSION {
     fn default() -> Self {
@@ -2872,7 +3189,8 @@ impl windows_core::TypeKind for _DEVICE_OBJECT_POWER_EXTENSION {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _IORING_OBJECT(pub isize);
 impl Default for _IORING_OBJECT {
     fn default() -> Self {
@@ -2883,7 +3201,8 @@ impl windows_core::TypeKind for _IORING_OBJECT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _SCSI_REQUEST_BLOCK(pub isize);
 impl Default for _SCSI_REQUEST_BLOCK {
     fn default() -> Self {
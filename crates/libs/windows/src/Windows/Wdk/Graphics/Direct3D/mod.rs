@@ -2674,6 +2674,7 @@ pub struct D3DDDIFORMAT(pub u32);
 impl windows_core::TypeKind for D3DDDIFORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDIFORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDIFORMAT").field(&self.0).finish()
@@ -2685,6 +2686,7 @@ pub struct D3DDDIGPUVIRTUALADDRESS_RESERVATION_TYPE(pub i32);
 impl windows_core::TypeKind for D3DDDIGPUVIRTUALADDRESS_RESERVATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDIGPUVIRTUALADDRESS_RESERVATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDIGPUVIRTUALADDRESS_RESERVATION_TYPE").field(&self.0).finish()
@@ -2696,6 +2698,7 @@ pub struct D3DDDIMULTISAMPLE_TYPE(pub i32);
 impl windows_core::TypeKind for D3DDDIMULTISAMPLE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDIMULTISAMPLE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDIMULTISAMPLE_TYPE").field(&self.0).finish()
@@ -2707,6 +2710,7 @@ pub struct D3DDDI_COLOR_SPACE_TYPE(pub i32);
 impl windows_core::TypeKind for D3DDDI_COLOR_SPACE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_COLOR_SPACE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_COLOR_SPACE_TYPE").field(&self.0).finish()
@@ -2718,6 +2722,7 @@ pub struct D3DDDI_DOORBELLSTATUS(pub i32);
 impl windows_core::TypeKind for D3DDDI_DOORBELLSTATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_DOORBELLSTATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_DOORBELLSTATUS").field(&self.0).finish()
@@ -2729,6 +2734,7 @@ pub struct D3DDDI_DRIVERESCAPETYPE(pub i32);
 impl windows_core::TypeKind for D3DDDI_DRIVERESCAPETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_DRIVERESCAPETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_DRIVERESCAPETYPE").field(&self.0).finish()
@@ -2740,6 +2746,7 @@ pub struct D3DDDI_FLIPINTERVAL_TYPE(pub i32);
 impl windows_core::TypeKind for D3DDDI_FLIPINTERVAL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_FLIPINTERVAL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_FLIPINTERVAL_TYPE").field(&self.0).finish()
@@ -2751,6 +2758,7 @@ pub struct D3DDDI_GAMMARAMP_TYPE(pub i32);
 impl windows_core::TypeKind for D3DDDI_GAMMARAMP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_GAMMARAMP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_GAMMARAMP_TYPE").field(&self.0).finish()
@@ -2762,6 +2770,7 @@ pub struct D3DDDI_HDR_METADATA_TYPE(pub i32);
 impl windows_core::TypeKind for D3DDDI_HDR_METADATA_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_HDR_METADATA_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_HDR_METADATA_TYPE").field(&self.0).finish()
@@ -2773,6 +2782,7 @@ pub struct D3DDDI_OFFER_PRIORITY(pub i32);
 impl windows_core::TypeKind for D3DDDI_OFFER_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_OFFER_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_OFFER_PRIORITY").field(&self.0).finish()
@@ -2784,6 +2794,7 @@ pub struct D3DDDI_OUTPUT_WIRE_COLOR_SPACE_TYPE(pub i32);
 impl windows_core::TypeKind for D3DDDI_OUTPUT_WIRE_COLOR_SPACE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_OUTPUT_WIRE_COLOR_SPACE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_OUTPUT_WIRE_COLOR_SPACE_TYPE").field(&self.0).finish()
@@ -2795,6 +2806,7 @@ pub struct D3DDDI_PAGINGQUEUE_PRIORITY(pub i32);
 impl windows_core::TypeKind for D3DDDI_PAGINGQUEUE_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_PAGINGQUEUE_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_PAGINGQUEUE_PRIORITY").field(&self.0).finish()
@@ -2806,6 +2818,7 @@ pub struct D3DDDI_POOL(pub i32);
 impl windows_core::TypeKind for D3DDDI_POOL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_POOL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_POOL").field(&self.0).finish()
@@ -2817,6 +2830,7 @@ pub struct D3DDDI_QUERYREGISTRY_STATUS(pub i32);
 impl windows_core::TypeKind for D3DDDI_QUERYREGISTRY_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_QUERYREGISTRY_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_QUERYREGISTRY_STATUS").field(&self.0).finish()
@@ -2828,6 +2842,7 @@ pub struct D3DDDI_QUERYREGISTRY_TYPE(pub i32);
 impl windows_core::TypeKind for D3DDDI_QUERYREGISTRY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_QUERYREGISTRY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_QUERYREGISTRY_TYPE").field(&self.0).finish()
@@ -2839,6 +2854,7 @@ pub struct D3DDDI_RECLAIM_RESULT(pub i32);
 impl windows_core::TypeKind for D3DDDI_RECLAIM_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_RECLAIM_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_RECLAIM_RESULT").field(&self.0).finish()
@@ -2850,6 +2866,7 @@ pub struct D3DDDI_ROTATION(pub i32);
 impl windows_core::TypeKind for D3DDDI_ROTATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_ROTATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_ROTATION").field(&self.0).finish()
@@ -2861,6 +2878,7 @@ pub struct D3DDDI_SCANLINEORDERING(pub i32);
 impl windows_core::TypeKind for D3DDDI_SCANLINEORDERING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_SCANLINEORDERING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_SCANLINEORDERING").field(&self.0).finish()
@@ -2872,6 +2890,7 @@ pub struct D3DDDI_SYNCHRONIZATIONOBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for D3DDDI_SYNCHRONIZATIONOBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_SYNCHRONIZATIONOBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_SYNCHRONIZATIONOBJECT_TYPE").field(&self.0).finish()
@@ -2883,6 +2902,7 @@ pub struct D3DDDI_UPDATEGPUVIRTUALADDRESS_OPERATION_TYPE(pub i32);
 impl windows_core::TypeKind for D3DDDI_UPDATEGPUVIRTUALADDRESS_OPERATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_UPDATEGPUVIRTUALADDRESS_OPERATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_UPDATEGPUVIRTUALADDRESS_OPERATION_TYPE").field(&self.0).finish()
@@ -2894,6 +2914,7 @@ pub struct D3DDDI_VIDEO_SIGNAL_SCANLINE_ORDERING(pub i32);
 impl windows_core::TypeKind for D3DDDI_VIDEO_SIGNAL_SCANLINE_ORDERING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DDDI_VIDEO_SIGNAL_SCANLINE_ORDERING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DDDI_VIDEO_SIGNAL_SCANLINE_ORDERING").field(&self.0).finish()
@@ -2905,6 +2926,7 @@ pub struct D3DHAL_DP2OPERATION(pub i32);
 impl windows_core::TypeKind for D3DHAL_DP2OPERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DHAL_DP2OPERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DHAL_DP2OPERATION").field(&self.0).finish()
@@ -2916,6 +2938,7 @@ pub struct D3DKMDT_COLORSPACE_TRANSFORM_STAGE_CONTROL(pub i32);
 impl windows_core::TypeKind for D3DKMDT_COLORSPACE_TRANSFORM_STAGE_CONTROL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_COLORSPACE_TRANSFORM_STAGE_CONTROL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_COLORSPACE_TRANSFORM_STAGE_CONTROL").field(&self.0).finish()
@@ -2927,6 +2950,7 @@ pub struct D3DKMDT_COLOR_BASIS(pub i32);
 impl windows_core::TypeKind for D3DKMDT_COLOR_BASIS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_COLOR_BASIS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_COLOR_BASIS").field(&self.0).finish()
@@ -2938,6 +2962,7 @@ pub struct D3DKMDT_COMPUTE_PREEMPTION_GRANULARITY(pub i32);
 impl windows_core::TypeKind for D3DKMDT_COMPUTE_PREEMPTION_GRANULARITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_COMPUTE_PREEMPTION_GRANULARITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_COMPUTE_PREEMPTION_GRANULARITY").field(&self.0).finish()
@@ -2949,6 +2974,7 @@ pub struct D3DKMDT_ENUMCOFUNCMODALITY_PIVOT_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMDT_ENUMCOFUNCMODALITY_PIVOT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_ENUMCOFUNCMODALITY_PIVOT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_ENUMCOFUNCMODALITY_PIVOT_TYPE").field(&self.0).finish()
@@ -2960,6 +2986,7 @@ pub struct D3DKMDT_GDISURFACETYPE(pub i32);
 impl windows_core::TypeKind for D3DKMDT_GDISURFACETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_GDISURFACETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_GDISURFACETYPE").field(&self.0).finish()
@@ -2971,6 +2998,7 @@ pub struct D3DKMDT_GRAPHICS_PREEMPTION_GRANULARITY(pub i32);
 impl windows_core::TypeKind for D3DKMDT_GRAPHICS_PREEMPTION_GRANULARITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_GRAPHICS_PREEMPTION_GRANULARITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_GRAPHICS_PREEMPTION_GRANULARITY").field(&self.0).finish()
@@ -2982,6 +3010,7 @@ pub struct D3DKMDT_GTFCOMPLIANCE(pub i32);
 impl windows_core::TypeKind for D3DKMDT_GTFCOMPLIANCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_GTFCOMPLIANCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_GTFCOMPLIANCE").field(&self.0).finish()
@@ -2993,6 +3022,7 @@ pub struct D3DKMDT_MODE_PREFERENCE(pub i32);
 impl windows_core::TypeKind for D3DKMDT_MODE_PREFERENCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_MODE_PREFERENCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_MODE_PREFERENCE").field(&self.0).finish()
@@ -3004,6 +3034,7 @@ pub struct D3DKMDT_MODE_PRUNING_REASON(pub i32);
 impl windows_core::TypeKind for D3DKMDT_MODE_PRUNING_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_MODE_PRUNING_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_MODE_PRUNING_REASON").field(&self.0).finish()
@@ -3015,6 +3046,7 @@ pub struct D3DKMDT_MONITOR_CAPABILITIES_ORIGIN(pub i32);
 impl windows_core::TypeKind for D3DKMDT_MONITOR_CAPABILITIES_ORIGIN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_MONITOR_CAPABILITIES_ORIGIN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_MONITOR_CAPABILITIES_ORIGIN").field(&self.0).finish()
@@ -3026,6 +3058,7 @@ pub struct D3DKMDT_MONITOR_CONNECTIVITY_CHECKS(pub i32);
 impl windows_core::TypeKind for D3DKMDT_MONITOR_CONNECTIVITY_CHECKS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_MONITOR_CONNECTIVITY_CHECKS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_MONITOR_CONNECTIVITY_CHECKS").field(&self.0).finish()
@@ -3037,6 +3070,7 @@ pub struct D3DKMDT_MONITOR_DESCRIPTOR_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMDT_MONITOR_DESCRIPTOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_MONITOR_DESCRIPTOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_MONITOR_DESCRIPTOR_TYPE").field(&self.0).finish()
@@ -3048,6 +3082,7 @@ pub struct D3DKMDT_MONITOR_FREQUENCY_RANGE_CONSTRAINT(pub i32);
 impl windows_core::TypeKind for D3DKMDT_MONITOR_FREQUENCY_RANGE_CONSTRAINT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_MONITOR_FREQUENCY_RANGE_CONSTRAINT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_MONITOR_FREQUENCY_RANGE_CONSTRAINT").field(&self.0).finish()
@@ -3059,6 +3094,7 @@ pub struct D3DKMDT_MONITOR_ORIENTATION(pub i32);
 impl windows_core::TypeKind for D3DKMDT_MONITOR_ORIENTATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_MONITOR_ORIENTATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_MONITOR_ORIENTATION").field(&self.0).finish()
@@ -3070,6 +3106,7 @@ pub struct D3DKMDT_MONITOR_ORIENTATION_AWARENESS(pub i32);
 impl windows_core::TypeKind for D3DKMDT_MONITOR_ORIENTATION_AWARENESS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_MONITOR_ORIENTATION_AWARENESS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_MONITOR_ORIENTATION_AWARENESS").field(&self.0).finish()
@@ -3081,6 +3118,7 @@ pub struct D3DKMDT_MONITOR_TIMING_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMDT_MONITOR_TIMING_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_MONITOR_TIMING_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_MONITOR_TIMING_TYPE").field(&self.0).finish()
@@ -3092,6 +3130,7 @@ pub struct D3DKMDT_PIXEL_VALUE_ACCESS_MODE(pub i32);
 impl windows_core::TypeKind for D3DKMDT_PIXEL_VALUE_ACCESS_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_PIXEL_VALUE_ACCESS_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_PIXEL_VALUE_ACCESS_MODE").field(&self.0).finish()
@@ -3103,6 +3142,7 @@ pub struct D3DKMDT_STANDARDALLOCATION_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMDT_STANDARDALLOCATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_STANDARDALLOCATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_STANDARDALLOCATION_TYPE").field(&self.0).finish()
@@ -3114,6 +3154,7 @@ pub struct D3DKMDT_TEXT_RENDERING_FORMAT(pub i32);
 impl windows_core::TypeKind for D3DKMDT_TEXT_RENDERING_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_TEXT_RENDERING_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_TEXT_RENDERING_FORMAT").field(&self.0).finish()
@@ -3125,6 +3166,7 @@ pub struct D3DKMDT_VIDEO_OUTPUT_TECHNOLOGY(pub i32);
 impl windows_core::TypeKind for D3DKMDT_VIDEO_OUTPUT_TECHNOLOGY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_VIDEO_OUTPUT_TECHNOLOGY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_VIDEO_OUTPUT_TECHNOLOGY").field(&self.0).finish()
@@ -3136,6 +3178,7 @@ pub struct D3DKMDT_VIDEO_SIGNAL_STANDARD(pub i32);
 impl windows_core::TypeKind for D3DKMDT_VIDEO_SIGNAL_STANDARD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_VIDEO_SIGNAL_STANDARD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_VIDEO_SIGNAL_STANDARD").field(&self.0).finish()
@@ -3147,6 +3190,7 @@ pub struct D3DKMDT_VIDPN_PRESENT_PATH_CONTENT(pub i32);
 impl windows_core::TypeKind for D3DKMDT_VIDPN_PRESENT_PATH_CONTENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_VIDPN_PRESENT_PATH_CONTENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_VIDPN_PRESENT_PATH_CONTENT").field(&self.0).finish()
@@ -3158,6 +3202,7 @@ pub struct D3DKMDT_VIDPN_PRESENT_PATH_COPYPROTECTION_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMDT_VIDPN_PRESENT_PATH_COPYPROTECTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_VIDPN_PRESENT_PATH_COPYPROTECTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_VIDPN_PRESENT_PATH_COPYPROTECTION_TYPE").field(&self.0).finish()
@@ -3169,6 +3214,7 @@ pub struct D3DKMDT_VIDPN_PRESENT_PATH_IMPORTANCE(pub i32);
 impl windows_core::TypeKind for D3DKMDT_VIDPN_PRESENT_PATH_IMPORTANCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_VIDPN_PRESENT_PATH_IMPORTANCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_VIDPN_PRESENT_PATH_IMPORTANCE").field(&self.0).finish()
@@ -3180,6 +3226,7 @@ pub struct D3DKMDT_VIDPN_PRESENT_PATH_ROTATION(pub i32);
 impl windows_core::TypeKind for D3DKMDT_VIDPN_PRESENT_PATH_ROTATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_VIDPN_PRESENT_PATH_ROTATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_VIDPN_PRESENT_PATH_ROTATION").field(&self.0).finish()
@@ -3191,6 +3238,7 @@ pub struct D3DKMDT_VIDPN_PRESENT_PATH_SCALING(pub i32);
 impl windows_core::TypeKind for D3DKMDT_VIDPN_PRESENT_PATH_SCALING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_VIDPN_PRESENT_PATH_SCALING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_VIDPN_PRESENT_PATH_SCALING").field(&self.0).finish()
@@ -3202,6 +3250,7 @@ pub struct D3DKMDT_VIDPN_SOURCE_MODE_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMDT_VIDPN_SOURCE_MODE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMDT_VIDPN_SOURCE_MODE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMDT_VIDPN_SOURCE_MODE_TYPE").field(&self.0).finish()
@@ -3213,6 +3262,7 @@ pub struct D3DKMT_ACTIVATE_SPECIFIC_DIAG_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_ACTIVATE_SPECIFIC_DIAG_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_ACTIVATE_SPECIFIC_DIAG_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_ACTIVATE_SPECIFIC_DIAG_TYPE").field(&self.0).finish()
@@ -3224,6 +3274,7 @@ pub struct D3DKMT_ADAPTER_VERIFIER_OPTION_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_ADAPTER_VERIFIER_OPTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_ADAPTER_VERIFIER_OPTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_ADAPTER_VERIFIER_OPTION_TYPE").field(&self.0).finish()
@@ -3235,6 +3286,7 @@ pub struct D3DKMT_ALLOCATIONRESIDENCYSTATUS(pub i32);
 impl windows_core::TypeKind for D3DKMT_ALLOCATIONRESIDENCYSTATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_ALLOCATIONRESIDENCYSTATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_ALLOCATIONRESIDENCYSTATUS").field(&self.0).finish()
@@ -3246,6 +3298,7 @@ pub struct D3DKMT_AUXILIARYPRESENTINFO_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_AUXILIARYPRESENTINFO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_AUXILIARYPRESENTINFO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_AUXILIARYPRESENTINFO_TYPE").field(&self.0).finish()
@@ -3257,6 +3310,7 @@ pub struct D3DKMT_BRIGHTNESS_INFO_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_BRIGHTNESS_INFO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_BRIGHTNESS_INFO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_BRIGHTNESS_INFO_TYPE").field(&self.0).finish()
@@ -3268,6 +3322,7 @@ pub struct D3DKMT_CANCEL_PRESENTS_OPERATION(pub i32);
 impl windows_core::TypeKind for D3DKMT_CANCEL_PRESENTS_OPERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_CANCEL_PRESENTS_OPERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_CANCEL_PRESENTS_OPERATION").field(&self.0).finish()
@@ -3279,6 +3334,7 @@ pub struct D3DKMT_CLIENTHINT(pub i32);
 impl windows_core::TypeKind for D3DKMT_CLIENTHINT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_CLIENTHINT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_CLIENTHINT").field(&self.0).finish()
@@ -3290,6 +3346,7 @@ pub struct D3DKMT_CROSSADAPTERRESOURCE_SUPPORT_TIER(pub i32);
 impl windows_core::TypeKind for D3DKMT_CROSSADAPTERRESOURCE_SUPPORT_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_CROSSADAPTERRESOURCE_SUPPORT_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_CROSSADAPTERRESOURCE_SUPPORT_TIER").field(&self.0).finish()
@@ -3301,6 +3358,7 @@ pub struct D3DKMT_DEFRAG_ESCAPE_OPERATION(pub i32);
 impl windows_core::TypeKind for D3DKMT_DEFRAG_ESCAPE_OPERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_DEFRAG_ESCAPE_OPERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_DEFRAG_ESCAPE_OPERATION").field(&self.0).finish()
@@ -3312,6 +3370,7 @@ pub struct D3DKMT_DEVICEESCAPE_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_DEVICEESCAPE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_DEVICEESCAPE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_DEVICEESCAPE_TYPE").field(&self.0).finish()
@@ -3323,6 +3382,7 @@ pub struct D3DKMT_DEVICEEXECUTION_STATE(pub i32);
 impl windows_core::TypeKind for D3DKMT_DEVICEEXECUTION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_DEVICEEXECUTION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_DEVICEEXECUTION_STATE").field(&self.0).finish()
@@ -3334,6 +3394,7 @@ pub struct D3DKMT_DEVICESTATE_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_DEVICESTATE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_DEVICESTATE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_DEVICESTATE_TYPE").field(&self.0).finish()
@@ -3345,6 +3406,7 @@ pub struct D3DKMT_DEVICE_ERROR_REASON(pub i32);
 impl windows_core::TypeKind for D3DKMT_DEVICE_ERROR_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_DEVICE_ERROR_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_DEVICE_ERROR_REASON").field(&self.0).finish()
@@ -3356,6 +3418,7 @@ pub struct D3DKMT_DMMESCAPETYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_DMMESCAPETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_DMMESCAPETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_DMMESCAPETYPE").field(&self.0).finish()
@@ -3367,6 +3430,7 @@ pub struct D3DKMT_DRIVERVERSION(pub i32);
 impl windows_core::TypeKind for D3DKMT_DRIVERVERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_DRIVERVERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_DRIVERVERSION").field(&self.0).finish()
@@ -3378,6 +3442,7 @@ pub struct D3DKMT_ESCAPETYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_ESCAPETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_ESCAPETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_ESCAPETYPE").field(&self.0).finish()
@@ -3389,6 +3454,7 @@ pub struct D3DKMT_ESCAPE_PFN_CONTROL_COMMAND(pub i32);
 impl windows_core::TypeKind for D3DKMT_ESCAPE_PFN_CONTROL_COMMAND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_ESCAPE_PFN_CONTROL_COMMAND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_ESCAPE_PFN_CONTROL_COMMAND").field(&self.0).finish()
@@ -3400,6 +3466,7 @@ pub struct D3DKMT_ESCAPE_VIRTUAL_REFRESH_RATE_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_ESCAPE_VIRTUAL_REFRESH_RATE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_ESCAPE_VIRTUAL_REFRESH_RATE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_ESCAPE_VIRTUAL_REFRESH_RATE_TYPE").field(&self.0).finish()
@@ -3411,6 +3478,7 @@ pub struct D3DKMT_FLIPMODEL_INDEPENDENT_FLIP_STAGE(pub i32);
 impl windows_core::TypeKind for D3DKMT_FLIPMODEL_INDEPENDENT_FLIP_STAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_FLIPMODEL_INDEPENDENT_FLIP_STAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_FLIPMODEL_INDEPENDENT_FLIP_STAGE").field(&self.0).finish()
@@ -3422,6 +3490,7 @@ pub struct D3DKMT_GPU_PREFERENCE_QUERY_STATE(pub i32);
 impl windows_core::TypeKind for D3DKMT_GPU_PREFERENCE_QUERY_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_GPU_PREFERENCE_QUERY_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_GPU_PREFERENCE_QUERY_STATE").field(&self.0).finish()
@@ -3433,6 +3502,7 @@ pub struct D3DKMT_GPU_PREFERENCE_QUERY_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_GPU_PREFERENCE_QUERY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_GPU_PREFERENCE_QUERY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_GPU_PREFERENCE_QUERY_TYPE").field(&self.0).finish()
@@ -3444,6 +3514,7 @@ pub struct D3DKMT_MEMORY_SEGMENT_GROUP(pub i32);
 impl windows_core::TypeKind for D3DKMT_MEMORY_SEGMENT_GROUP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_MEMORY_SEGMENT_GROUP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_MEMORY_SEGMENT_GROUP").field(&self.0).finish()
@@ -3455,6 +3526,7 @@ pub struct D3DKMT_MIRACAST_DEVICE_STATUS(pub i32);
 impl windows_core::TypeKind for D3DKMT_MIRACAST_DEVICE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_MIRACAST_DEVICE_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_MIRACAST_DEVICE_STATUS").field(&self.0).finish()
@@ -3466,6 +3538,7 @@ pub struct D3DKMT_MIRACAST_DISPLAY_DEVICE_STATE(pub i32);
 impl windows_core::TypeKind for D3DKMT_MIRACAST_DISPLAY_DEVICE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_MIRACAST_DISPLAY_DEVICE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_MIRACAST_DISPLAY_DEVICE_STATE").field(&self.0).finish()
@@ -3477,6 +3550,7 @@ pub struct D3DKMT_MIRACAST_DRIVER_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_MIRACAST_DRIVER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_MIRACAST_DRIVER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_MIRACAST_DRIVER_TYPE").field(&self.0).finish()
@@ -3488,6 +3562,7 @@ pub struct D3DKMT_MULTIPLANE_OVERLAY_BLEND(pub i32);
 impl windows_core::TypeKind for D3DKMT_MULTIPLANE_OVERLAY_BLEND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_MULTIPLANE_OVERLAY_BLEND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_MULTIPLANE_OVERLAY_BLEND").field(&self.0).finish()
@@ -3499,6 +3574,7 @@ pub struct D3DKMT_MULTIPLANE_OVERLAY_FLAGS(pub i32);
 impl windows_core::TypeKind for D3DKMT_MULTIPLANE_OVERLAY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_MULTIPLANE_OVERLAY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_MULTIPLANE_OVERLAY_FLAGS").field(&self.0).finish()
@@ -3510,6 +3586,7 @@ pub struct D3DKMT_MULTIPLANE_OVERLAY_STEREO_FORMAT(pub i32);
 impl windows_core::TypeKind for D3DKMT_MULTIPLANE_OVERLAY_STEREO_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_MULTIPLANE_OVERLAY_STEREO_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_MULTIPLANE_OVERLAY_STEREO_FORMAT").field(&self.0).finish()
@@ -3521,6 +3598,7 @@ pub struct D3DKMT_MULTIPLANE_OVERLAY_VIDEO_FRAME_FORMAT(pub i32);
 impl windows_core::TypeKind for D3DKMT_MULTIPLANE_OVERLAY_VIDEO_FRAME_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_MULTIPLANE_OVERLAY_VIDEO_FRAME_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_MULTIPLANE_OVERLAY_VIDEO_FRAME_FORMAT").field(&self.0).finish()
@@ -3532,6 +3610,7 @@ pub struct D3DKMT_MULTIPLANE_OVERLAY_YCbCr_FLAGS(pub i32);
 impl windows_core::TypeKind for D3DKMT_MULTIPLANE_OVERLAY_YCbCr_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_MULTIPLANE_OVERLAY_YCbCr_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_MULTIPLANE_OVERLAY_YCbCr_FLAGS").field(&self.0).finish()
@@ -3543,6 +3622,7 @@ pub struct D3DKMT_OFFER_PRIORITY(pub i32);
 impl windows_core::TypeKind for D3DKMT_OFFER_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_OFFER_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_OFFER_PRIORITY").field(&self.0).finish()
@@ -3554,6 +3634,7 @@ pub struct D3DKMT_OUTDUPL_POINTER_SHAPE_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_OUTDUPL_POINTER_SHAPE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_OUTDUPL_POINTER_SHAPE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_OUTDUPL_POINTER_SHAPE_TYPE").field(&self.0).finish()
@@ -3565,6 +3646,7 @@ pub struct D3DKMT_OUTPUTDUPL_METADATATYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_OUTPUTDUPL_METADATATYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_OUTPUTDUPL_METADATATYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_OUTPUTDUPL_METADATATYPE").field(&self.0).finish()
@@ -3576,6 +3658,7 @@ pub struct D3DKMT_PNP_KEY_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_PNP_KEY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_PNP_KEY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_PNP_KEY_TYPE").field(&self.0).finish()
@@ -3587,6 +3670,7 @@ pub struct D3DKMT_PRESENT_MODEL(pub i32);
 impl windows_core::TypeKind for D3DKMT_PRESENT_MODEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_PRESENT_MODEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_PRESENT_MODEL").field(&self.0).finish()
@@ -3598,6 +3682,7 @@ pub struct D3DKMT_PROCESS_VERIFIER_OPTION_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_PROCESS_VERIFIER_OPTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_PROCESS_VERIFIER_OPTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_PROCESS_VERIFIER_OPTION_TYPE").field(&self.0).finish()
@@ -3609,6 +3694,7 @@ pub struct D3DKMT_PROTECTED_SESSION_STATUS(pub i32);
 impl windows_core::TypeKind for D3DKMT_PROTECTED_SESSION_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_PROTECTED_SESSION_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_PROTECTED_SESSION_STATUS").field(&self.0).finish()
@@ -3620,6 +3706,7 @@ pub struct D3DKMT_QUERYRESULT_PREEMPTION_ATTEMPT_RESULT(pub i32);
 impl windows_core::TypeKind for D3DKMT_QUERYRESULT_PREEMPTION_ATTEMPT_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_QUERYRESULT_PREEMPTION_ATTEMPT_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_QUERYRESULT_PREEMPTION_ATTEMPT_RESULT").field(&self.0).finish()
@@ -3631,6 +3718,7 @@ pub struct D3DKMT_QUERYSTATISTICS_ALLOCATION_PRIORITY_CLASS(pub i32);
 impl windows_core::TypeKind for D3DKMT_QUERYSTATISTICS_ALLOCATION_PRIORITY_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_QUERYSTATISTICS_ALLOCATION_PRIORITY_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_QUERYSTATISTICS_ALLOCATION_PRIORITY_CLASS").field(&self.0).finish()
@@ -3642,6 +3730,7 @@ pub struct D3DKMT_QUERYSTATISTICS_DMA_PACKET_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_QUERYSTATISTICS_DMA_PACKET_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_QUERYSTATISTICS_DMA_PACKET_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_QUERYSTATISTICS_DMA_PACKET_TYPE").field(&self.0).finish()
@@ -3653,6 +3742,7 @@ pub struct D3DKMT_QUERYSTATISTICS_QUEUE_PACKET_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_QUERYSTATISTICS_QUEUE_PACKET_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_QUERYSTATISTICS_QUEUE_PACKET_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_QUERYSTATISTICS_QUEUE_PACKET_TYPE").field(&self.0).finish()
@@ -3664,6 +3754,7 @@ pub struct D3DKMT_QUERYSTATISTICS_SEGMENT_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_QUERYSTATISTICS_SEGMENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_QUERYSTATISTICS_SEGMENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_QUERYSTATISTICS_SEGMENT_TYPE").field(&self.0).finish()
@@ -3675,6 +3766,7 @@ pub struct D3DKMT_QUERYSTATISTICS_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_QUERYSTATISTICS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_QUERYSTATISTICS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_QUERYSTATISTICS_TYPE").field(&self.0).finish()
@@ -3686,6 +3778,7 @@ pub struct D3DKMT_QUEUEDLIMIT_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_QUEUEDLIMIT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_QUEUEDLIMIT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_QUEUEDLIMIT_TYPE").field(&self.0).finish()
@@ -3697,6 +3790,7 @@ pub struct D3DKMT_SCHEDULINGPRIORITYCLASS(pub i32);
 impl windows_core::TypeKind for D3DKMT_SCHEDULINGPRIORITYCLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_SCHEDULINGPRIORITYCLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_SCHEDULINGPRIORITYCLASS").field(&self.0).finish()
@@ -3708,6 +3802,7 @@ pub struct D3DKMT_STANDARDALLOCATIONTYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_STANDARDALLOCATIONTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_STANDARDALLOCATIONTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_STANDARDALLOCATIONTYPE").field(&self.0).finish()
@@ -3719,6 +3814,7 @@ pub struct D3DKMT_TDRDBGCTRLTYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_TDRDBGCTRLTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_TDRDBGCTRLTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_TDRDBGCTRLTYPE").field(&self.0).finish()
@@ -3730,6 +3826,7 @@ pub struct D3DKMT_VAD_ESCAPE_COMMAND(pub i32);
 impl windows_core::TypeKind for D3DKMT_VAD_ESCAPE_COMMAND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_VAD_ESCAPE_COMMAND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_VAD_ESCAPE_COMMAND").field(&self.0).finish()
@@ -3741,6 +3838,7 @@ pub struct D3DKMT_VERIFIER_OPTION_MODE(pub i32);
 impl windows_core::TypeKind for D3DKMT_VERIFIER_OPTION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_VERIFIER_OPTION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_VERIFIER_OPTION_MODE").field(&self.0).finish()
@@ -3752,6 +3850,7 @@ pub struct D3DKMT_VIDMMESCAPETYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_VIDMMESCAPETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_VIDMMESCAPETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_VIDMMESCAPETYPE").field(&self.0).finish()
@@ -3763,6 +3862,7 @@ pub struct D3DKMT_VIDPNSOURCEOWNER_TYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_VIDPNSOURCEOWNER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_VIDPNSOURCEOWNER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_VIDPNSOURCEOWNER_TYPE").field(&self.0).finish()
@@ -3774,6 +3874,7 @@ pub struct D3DKMT_VIDSCHESCAPETYPE(pub i32);
 impl windows_core::TypeKind for D3DKMT_VIDSCHESCAPETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DKMT_VIDSCHESCAPETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DKMT_VIDSCHESCAPETYPE").field(&self.0).finish()
@@ -3785,6 +3886,7 @@ pub struct D3DNTHAL_DP2OPERATION(pub i32);
 impl windows_core::TypeKind for D3DNTHAL_DP2OPERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DNTHAL_DP2OPERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DNTHAL_DP2OPERATION").field(&self.0).finish()
@@ -3796,6 +3898,7 @@ pub struct D3DVSD_TOKENTYPE(pub i32);
 impl windows_core::TypeKind for D3DVSD_TOKENTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DVSD_TOKENTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DVSD_TOKENTYPE").field(&self.0).finish()
@@ -3807,6 +3910,7 @@ pub struct DISPLAYID_DETAILED_TIMING_TYPE_I_ASPECT_RATIO(pub i32);
 impl windows_core::TypeKind for DISPLAYID_DETAILED_TIMING_TYPE_I_ASPECT_RATIO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPLAYID_DETAILED_TIMING_TYPE_I_ASPECT_RATIO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPLAYID_DETAILED_TIMING_TYPE_I_ASPECT_RATIO").field(&self.0).finish()
@@ -3818,6 +3922,7 @@ pub struct DISPLAYID_DETAILED_TIMING_TYPE_I_SCANNING_MODE(pub i32);
 impl windows_core::TypeKind for DISPLAYID_DETAILED_TIMING_TYPE_I_SCANNING_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPLAYID_DETAILED_TIMING_TYPE_I_SCANNING_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPLAYID_DETAILED_TIMING_TYPE_I_SCANNING_MODE").field(&self.0).finish()
@@ -3829,6 +3934,7 @@ pub struct DISPLAYID_DETAILED_TIMING_TYPE_I_STEREO_MODE(pub i32);
 impl windows_core::TypeKind for DISPLAYID_DETAILED_TIMING_TYPE_I_STEREO_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPLAYID_DETAILED_TIMING_TYPE_I_STEREO_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPLAYID_DETAILED_TIMING_TYPE_I_STEREO_MODE").field(&self.0).finish()
@@ -3840,6 +3946,7 @@ pub struct DISPLAYID_DETAILED_TIMING_TYPE_I_SYNC_POLARITY(pub i32);
 impl windows_core::TypeKind for DISPLAYID_DETAILED_TIMING_TYPE_I_SYNC_POLARITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPLAYID_DETAILED_TIMING_TYPE_I_SYNC_POLARITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPLAYID_DETAILED_TIMING_TYPE_I_SYNC_POLARITY").field(&self.0).finish()
@@ -3851,6 +3958,7 @@ pub struct DXGKDT_OPM_DVI_CHARACTERISTICS(pub i32);
 impl windows_core::TypeKind for DXGKDT_OPM_DVI_CHARACTERISTICS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKDT_OPM_DVI_CHARACTERISTICS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKDT_OPM_DVI_CHARACTERISTICS").field(&self.0).finish()
@@ -3862,6 +3970,7 @@ pub struct DXGKMDT_CERTIFICATE_TYPE(pub i32);
 impl windows_core::TypeKind for DXGKMDT_CERTIFICATE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_CERTIFICATE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_CERTIFICATE_TYPE").field(&self.0).finish()
@@ -3873,6 +3982,7 @@ pub struct DXGKMDT_OPM_ACP_PROTECTION_LEVEL(pub i32);
 impl windows_core::TypeKind for DXGKMDT_OPM_ACP_PROTECTION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_OPM_ACP_PROTECTION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_OPM_ACP_PROTECTION_LEVEL").field(&self.0).finish()
@@ -3884,6 +3994,7 @@ pub struct DXGKMDT_OPM_BUS_TYPE_AND_IMPLEMENTATION(pub i32);
 impl windows_core::TypeKind for DXGKMDT_OPM_BUS_TYPE_AND_IMPLEMENTATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_OPM_BUS_TYPE_AND_IMPLEMENTATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_OPM_BUS_TYPE_AND_IMPLEMENTATION").field(&self.0).finish()
@@ -3895,6 +4006,7 @@ pub struct DXGKMDT_OPM_CGMSA(pub i32);
 impl windows_core::TypeKind for DXGKMDT_OPM_CGMSA {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_OPM_CGMSA {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_OPM_CGMSA").field(&self.0).finish()
@@ -3906,6 +4018,7 @@ pub struct DXGKMDT_OPM_CONNECTOR_TYPE(pub i32);
 impl windows_core::TypeKind for DXGKMDT_OPM_CONNECTOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_OPM_CONNECTOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_OPM_CONNECTOR_TYPE").field(&self.0).finish()
@@ -3917,6 +4030,7 @@ pub struct DXGKMDT_OPM_DPCP_PROTECTION_LEVEL(pub i32);
 impl windows_core::TypeKind for DXGKMDT_OPM_DPCP_PROTECTION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_OPM_DPCP_PROTECTION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_OPM_DPCP_PROTECTION_LEVEL").field(&self.0).finish()
@@ -3928,6 +4042,7 @@ pub struct DXGKMDT_OPM_HDCP_FLAG(pub i32);
 impl windows_core::TypeKind for DXGKMDT_OPM_HDCP_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_OPM_HDCP_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_OPM_HDCP_FLAG").field(&self.0).finish()
@@ -3939,6 +4054,7 @@ pub struct DXGKMDT_OPM_HDCP_PROTECTION_LEVEL(pub i32);
 impl windows_core::TypeKind for DXGKMDT_OPM_HDCP_PROTECTION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_OPM_HDCP_PROTECTION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_OPM_HDCP_PROTECTION_LEVEL").field(&self.0).finish()
@@ -3950,6 +4066,7 @@ pub struct DXGKMDT_OPM_IMAGE_ASPECT_RATIO_EN300294(pub i32);
 impl windows_core::TypeKind for DXGKMDT_OPM_IMAGE_ASPECT_RATIO_EN300294 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_OPM_IMAGE_ASPECT_RATIO_EN300294 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_OPM_IMAGE_ASPECT_RATIO_EN300294").field(&self.0).finish()
@@ -3961,6 +4078,7 @@ pub struct DXGKMDT_OPM_INTERLEAVE_FORMAT(pub i32);
 impl windows_core::TypeKind for DXGKMDT_OPM_INTERLEAVE_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_OPM_INTERLEAVE_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_OPM_INTERLEAVE_FORMAT").field(&self.0).finish()
@@ -3972,6 +4090,7 @@ pub struct DXGKMDT_OPM_OUTPUT_HARDWARE_PROTECTION(pub i32);
 impl windows_core::TypeKind for DXGKMDT_OPM_OUTPUT_HARDWARE_PROTECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_OPM_OUTPUT_HARDWARE_PROTECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_OPM_OUTPUT_HARDWARE_PROTECTION").field(&self.0).finish()
@@ -3983,6 +4102,7 @@ pub struct DXGKMDT_OPM_PROTECTION_STANDARD(pub i32);
 impl windows_core::TypeKind for DXGKMDT_OPM_PROTECTION_STANDARD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_OPM_PROTECTION_STANDARD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_OPM_PROTECTION_STANDARD").field(&self.0).finish()
@@ -3994,6 +4114,7 @@ pub struct DXGKMDT_OPM_PROTECTION_TYPE(pub i32);
 impl windows_core::TypeKind for DXGKMDT_OPM_PROTECTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_OPM_PROTECTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_OPM_PROTECTION_TYPE").field(&self.0).finish()
@@ -4005,6 +4126,7 @@ pub struct DXGKMDT_OPM_STATUS(pub i32);
 impl windows_core::TypeKind for DXGKMDT_OPM_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_OPM_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_OPM_STATUS").field(&self.0).finish()
@@ -4016,6 +4138,7 @@ pub struct DXGKMDT_OPM_TYPE_ENFORCEMENT_HDCP_PROTECTION_LEVEL(pub i32);
 impl windows_core::TypeKind for DXGKMDT_OPM_TYPE_ENFORCEMENT_HDCP_PROTECTION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_OPM_TYPE_ENFORCEMENT_HDCP_PROTECTION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_OPM_TYPE_ENFORCEMENT_HDCP_PROTECTION_LEVEL").field(&self.0).finish()
@@ -4027,6 +4150,7 @@ pub struct DXGKMDT_OPM_VIDEO_OUTPUT_SEMANTICS(pub i32);
 impl windows_core::TypeKind for DXGKMDT_OPM_VIDEO_OUTPUT_SEMANTICS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMDT_OPM_VIDEO_OUTPUT_SEMANTICS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMDT_OPM_VIDEO_OUTPUT_SEMANTICS").field(&self.0).finish()
@@ -4038,6 +4162,7 @@ pub struct DXGKMT_MULTIPLANE_OVERLAY_STEREO_FLIP_MODE(pub i32);
 impl windows_core::TypeKind for DXGKMT_MULTIPLANE_OVERLAY_STEREO_FLIP_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMT_MULTIPLANE_OVERLAY_STEREO_FLIP_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMT_MULTIPLANE_OVERLAY_STEREO_FLIP_MODE").field(&self.0).finish()
@@ -4049,6 +4174,7 @@ pub struct DXGKMT_MULTIPLANE_OVERLAY_STRETCH_QUALITY(pub i32);
 impl windows_core::TypeKind for DXGKMT_MULTIPLANE_OVERLAY_STRETCH_QUALITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMT_MULTIPLANE_OVERLAY_STRETCH_QUALITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMT_MULTIPLANE_OVERLAY_STRETCH_QUALITY").field(&self.0).finish()
@@ -4060,6 +4186,7 @@ pub struct DXGKMT_POWER_SHARED_TYPE(pub i32);
 impl windows_core::TypeKind for DXGKMT_POWER_SHARED_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKMT_POWER_SHARED_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKMT_POWER_SHARED_TYPE").field(&self.0).finish()
@@ -4071,6 +4198,7 @@ pub struct DXGKVGPU_ESCAPE_TYPE(pub i32);
 impl windows_core::TypeKind for DXGKVGPU_ESCAPE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGKVGPU_ESCAPE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGKVGPU_ESCAPE_TYPE").field(&self.0).finish()
@@ -4082,6 +4210,7 @@ pub struct DXGK_BACKLIGHT_OPTIMIZATION_LEVEL(pub i32);
 impl windows_core::TypeKind for DXGK_BACKLIGHT_OPTIMIZATION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGK_BACKLIGHT_OPTIMIZATION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGK_BACKLIGHT_OPTIMIZATION_LEVEL").field(&self.0).finish()
@@ -4093,6 +4222,7 @@ pub struct DXGK_CHILD_DEVICE_HPD_AWARENESS(pub i32);
 impl windows_core::TypeKind for DXGK_CHILD_DEVICE_HPD_AWARENESS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGK_CHILD_DEVICE_HPD_AWARENESS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGK_CHILD_DEVICE_HPD_AWARENESS").field(&self.0).finish()
@@ -4104,6 +4234,7 @@ pub struct DXGK_DISPLAY_DESCRIPTOR_TYPE(pub u8);
 impl windows_core::TypeKind for DXGK_DISPLAY_DESCRIPTOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGK_DISPLAY_DESCRIPTOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGK_DISPLAY_DESCRIPTOR_TYPE").field(&self.0).finish()
@@ -4115,6 +4246,7 @@ pub struct DXGK_DISPLAY_TECHNOLOGY(pub u8);
 impl windows_core::TypeKind for DXGK_DISPLAY_TECHNOLOGY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGK_DISPLAY_TECHNOLOGY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGK_DISPLAY_TECHNOLOGY").field(&self.0).finish()
@@ -4126,6 +4258,7 @@ pub struct DXGK_DISPLAY_USAGE(pub u8);
 impl windows_core::TypeKind for DXGK_DISPLAY_USAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGK_DISPLAY_USAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGK_DISPLAY_USAGE").field(&self.0).finish()
@@ -4137,6 +4270,7 @@ pub struct DXGK_ENGINE_TYPE(pub i32);
 impl windows_core::TypeKind for DXGK_ENGINE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGK_ENGINE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGK_ENGINE_TYPE").field(&self.0).finish()
@@ -4148,6 +4282,7 @@ pub struct DXGK_GENERAL_ERROR_CODE(pub i32);
 impl windows_core::TypeKind for DXGK_GENERAL_ERROR_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGK_GENERAL_ERROR_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGK_GENERAL_ERROR_CODE").field(&self.0).finish()
@@ -4159,6 +4294,7 @@ pub struct DXGK_MIRACAST_CHUNK_TYPE(pub i32);
 impl windows_core::TypeKind for DXGK_MIRACAST_CHUNK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGK_MIRACAST_CHUNK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGK_MIRACAST_CHUNK_TYPE").field(&self.0).finish()
@@ -4170,6 +4306,7 @@ pub struct DXGK_PAGE_FAULT_FLAGS(pub i32);
 impl windows_core::TypeKind for DXGK_PAGE_FAULT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGK_PAGE_FAULT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGK_PAGE_FAULT_FLAGS").field(&self.0).finish()
@@ -4181,6 +4318,7 @@ pub struct DXGK_PTE_PAGE_SIZE(pub i32);
 impl windows_core::TypeKind for DXGK_PTE_PAGE_SIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGK_PTE_PAGE_SIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGK_PTE_PAGE_SIZE").field(&self.0).finish()
@@ -4192,6 +4330,7 @@ pub struct DXGK_RENDER_PIPELINE_STAGE(pub i32);
 impl windows_core::TypeKind for DXGK_RENDER_PIPELINE_STAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGK_RENDER_PIPELINE_STAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGK_RENDER_PIPELINE_STAGE").field(&self.0).finish()
@@ -4203,6 +4342,7 @@ pub struct KMTQUERYADAPTERINFOTYPE(pub i32);
 impl windows_core::TypeKind for KMTQUERYADAPTERINFOTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KMTQUERYADAPTERINFOTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KMTQUERYADAPTERINFOTYPE").field(&self.0).finish()
@@ -4214,6 +4354,7 @@ pub struct KMTUMDVERSION(pub i32);
 impl windows_core::TypeKind for KMTUMDVERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KMTUMDVERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KMTUMDVERSION").field(&self.0).finish()
@@ -4225,6 +4366,7 @@ pub struct KMT_DISPLAY_UMD_VERSION(pub i32);
 impl windows_core::TypeKind for KMT_DISPLAY_UMD_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KMT_DISPLAY_UMD_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KMT_DISPLAY_UMD_VERSION").field(&self.0).finish()
@@ -4236,6 +4378,7 @@ pub struct OUTPUTDUPL_CONTEXT_DEBUG_STATUS(pub i32);
 impl windows_core::TypeKind for OUTPUTDUPL_CONTEXT_DEBUG_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OUTPUTDUPL_CONTEXT_DEBUG_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OUTPUTDUPL_CONTEXT_DEBUG_STATUS").field(&self.0).finish()
@@ -4243,7 +4386,8 @@ impl core::fmt::Debug for OUTPUTDUPL_CONTEXT_DEBUG_STATUS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DCAPS8 {
     pub DeviceType: super::super::super::Win32::Graphics::Direct3D9::D3DDEVTYPE,
     pub AdapterOrdinal: u32,
@@ -4388,10 +4532,37 @@ impl Default for D3DDDICB_DESTROYALLOCATION2FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDICB_DESTROYALLOCATION2FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDICB_DESTROYALLOCATION2FLAGS_0_0 {
+    pub const fn AssumeNotInUse(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_AssumeNotInUse(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn SynchronousDestroy(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_SynchronousDestroy(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 2)) | ((value & 536870911) << 2);
+    }
+    pub const fn SystemUseOnly(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_SystemUseOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for D3DDDICB_DESTROYALLOCATION2FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4428,10 +4599,19 @@ impl Default for D3DDDICB_LOCK2FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDICB_LOCK2FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDICB_LOCK2FLAGS_0_0 {
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = value;
+    }
+}
 impl windows_core::TypeKind for D3DDDICB_LOCK2FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4468,10 +4648,85 @@ impl Default for D3DDDICB_LOCKFLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDICB_LOCKFLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDICB_LOCKFLAGS_0_0 {
+    pub const fn ReadOnly(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ReadOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn WriteOnly(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_WriteOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn DonotWait(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_DonotWait(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn IgnoreSync(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_IgnoreSync(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn LockEntire(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_LockEntire(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn DonotEvict(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_DonotEvict(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn AcquireAperture(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_AcquireAperture(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Discard(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Discard(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn NoExistingReference(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_NoExistingReference(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn UseAlternateVA(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_UseAlternateVA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn IgnoreReadSync(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_IgnoreReadSync(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 11) & 2097151
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2097151 << 11)) | ((value & 2097151) << 11);
+    }
+}
 impl windows_core::TypeKind for D3DDDICB_LOCKFLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4508,10 +4763,43 @@ impl Default for D3DDDICB_SIGNALFLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDICB_SIGNALFLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDICB_SIGNALFLAGS_0_0 {
+    pub const fn SignalAtSubmission(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_SignalAtSubmission(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn EnqueueCpuEvent(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_EnqueueCpuEvent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn AllowFenceRewind(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_AllowFenceRewind(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 3)) | ((value & 268435455) << 3);
+    }
+    pub const fn DXGK_SIGNAL_FLAG_INTERNAL0(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_DXGK_SIGNAL_FLAG_INTERNAL0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for D3DDDICB_SIGNALFLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4548,10 +4836,49 @@ impl Default for D3DDDIGPUVIRTUALADDRESS_PROTECTION_TYPE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDIGPUVIRTUALADDRESS_PROTECTION_TYPE_0_0 {
     pub _bitfield: u64,
 }
+impl D3DDDIGPUVIRTUALADDRESS_PROTECTION_TYPE_0_0 {
+    pub const fn Write(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_Write(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Execute(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Execute(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Zero(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Zero(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn NoAccess(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_NoAccess(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn SystemUseOnly(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_SystemUseOnly(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 5) & 576460752303423487
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(576460752303423487 << 5)) | ((value & 576460752303423487) << 5);
+    }
+}
 impl windows_core::TypeKind for D3DDDIGPUVIRTUALADDRESS_PROTECTION_TYPE_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4561,7 +4888,8 @@ impl Default for D3DDDIGPUVIRTUALADDRESS_PROTECTION_TYPE_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDIRECT {
     pub left: i32,
     pub top: i32,
@@ -4609,10 +4937,31 @@ impl Default for D3DDDI_ALLOCATIONINFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_ALLOCATIONINFO_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_ALLOCATIONINFO_0_0 {
+    pub const fn Primary(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Primary(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Stereo(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Stereo(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_ALLOCATIONINFO_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4685,10 +5034,37 @@ impl Default for D3DDDI_ALLOCATIONINFO2_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_ALLOCATIONINFO2_2_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_ALLOCATIONINFO2_2_0 {
+    pub const fn Primary(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Primary(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Stereo(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Stereo(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn OverridePriority(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_OverridePriority(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_ALLOCATIONINFO2_2_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4726,10 +5102,37 @@ impl Default for D3DDDI_ALLOCATIONLIST_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_ALLOCATIONLIST_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_ALLOCATIONLIST_0_0 {
+    pub const fn WriteOperation(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_WriteOperation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DoNotRetireInstance(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DoNotRetireInstance(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn OfferPriority(&self) -> u32 {
+        (self._bitfield >> 2) & 7
+    }
+    pub fn set_OfferPriority(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 2)) | ((value & 7) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 5) & 134217727
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(134217727 << 5)) | ((value & 134217727) << 5);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_ALLOCATIONLIST_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4766,10 +5169,55 @@ impl Default for D3DDDI_CREATECONTEXTFLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_CREATECONTEXTFLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_CREATECONTEXTFLAGS_0_0 {
+    pub const fn NullRendering(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_NullRendering(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn InitialData(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_InitialData(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn DisableGpuTimeout(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_DisableGpuTimeout(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn SynchronizationOnly(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_SynchronizationOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn HwQueueSupported(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_HwQueueSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn NoKmdAccess(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_NoKmdAccess(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 6) & 67108863
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(67108863 << 6)) | ((value & 67108863) << 6);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_CREATECONTEXTFLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4806,10 +5254,19 @@ impl Default for D3DDDI_CREATEHWCONTEXTFLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_CREATEHWCONTEXTFLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_CREATEHWCONTEXTFLAGS_0_0 {
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = value;
+    }
+}
 impl windows_core::TypeKind for D3DDDI_CREATEHWCONTEXTFLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4846,10 +5303,49 @@ impl Default for D3DDDI_CREATEHWQUEUEFLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_CREATEHWQUEUEFLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_CREATEHWQUEUEFLAGS_0_0 {
+    pub const fn DisableGpuTimeout(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_DisableGpuTimeout(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NoBroadcastSignal(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NoBroadcastSignal(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn NoBroadcastWait(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_NoBroadcastWait(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn NoKmdAccess(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_NoKmdAccess(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn UserModeSubmission(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_UserModeSubmission(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 5) & 134217727
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(134217727 << 5)) | ((value & 134217727) << 5);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_CREATEHWQUEUEFLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4878,7 +5374,8 @@ impl Default for D3DDDI_CREATENATIVEFENCEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_DESTROYPAGINGQUEUE {
     pub hPagingQueue: u32,
 }
@@ -4891,7 +5388,8 @@ impl Default for D3DDDI_DESTROYPAGINGQUEUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_DRIVERESCAPE_CPUEVENTUSAGE {
     pub EscapeType: D3DDDI_DRIVERESCAPETYPE,
     pub hSyncObject: u32,
@@ -4907,7 +5405,8 @@ impl Default for D3DDDI_DRIVERESCAPE_CPUEVENTUSAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_DRIVERESCAPE_TRANSLATEALLOCATIONEHANDLE {
     pub EscapeType: D3DDDI_DRIVERESCAPETYPE,
     pub hAllocation: u32,
@@ -4921,7 +5420,8 @@ impl Default for D3DDDI_DRIVERESCAPE_TRANSLATEALLOCATIONEHANDLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_DRIVERESCAPE_TRANSLATERESOURCEHANDLE {
     pub EscapeType: D3DDDI_DRIVERESCAPETYPE,
     pub hResource: u32,
@@ -4935,7 +5435,8 @@ impl Default for D3DDDI_DRIVERESCAPE_TRANSLATERESOURCEHANDLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_DXGI_RGB {
     pub Red: f32,
     pub Green: f32,
@@ -4977,10 +5478,67 @@ impl Default for D3DDDI_ESCAPEFLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_ESCAPEFLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_ESCAPEFLAGS_0_0 {
+    pub const fn HardwareAccess(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_HardwareAccess(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DeviceStatusQuery(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DeviceStatusQuery(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ChangeFrameLatency(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ChangeFrameLatency(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn NoAdapterSynchronization(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_NoAdapterSynchronization(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn VirtualMachineData(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_VirtualMachineData(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn DriverKnownEscape(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_DriverKnownEscape(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn DriverCommonEscape(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_DriverCommonEscape(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 8) & 16777215
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(16777215 << 8)) | ((value & 16777215) << 8);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_ESCAPEFLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5017,10 +5575,31 @@ impl Default for D3DDDI_EVICT_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_EVICT_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_EVICT_FLAGS_0_0 {
+    pub const fn EvictOnlyIfNecessary(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_EvictOnlyIfNecessary(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NotWrittenTo(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NotWrittenTo(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_EVICT_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5030,7 +5609,8 @@ impl Default for D3DDDI_EVICT_FLAGS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_GAMMA_RAMP_DXGI_1 {
     pub Scale: D3DDDI_DXGI_RGB,
     pub Offset: D3DDDI_DXGI_RGB,
@@ -5045,7 +5625,8 @@ impl Default for D3DDDI_GAMMA_RAMP_DXGI_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_GAMMA_RAMP_RGB256x3x16 {
     pub Red: [u16; 256],
     pub Green: [u16; 256],
@@ -5060,7 +5641,8 @@ impl Default for D3DDDI_GAMMA_RAMP_RGB256x3x16 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_GETRESOURCEPRESENTPRIVATEDRIVERDATA {
     pub hResource: u32,
     pub PrivateDriverDataSize: u32,
@@ -5075,7 +5657,8 @@ impl Default for D3DDDI_GETRESOURCEPRESENTPRIVATEDRIVERDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_HDR_METADATA_HDR10 {
     pub RedPrimary: [u16; 2],
     pub GreenPrimary: [u16; 2],
@@ -5095,7 +5678,8 @@ impl Default for D3DDDI_HDR_METADATA_HDR10 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_HDR_METADATA_HDR10PLUS {
     pub Data: [u8; 72],
 }
@@ -5108,7 +5692,8 @@ impl Default for D3DDDI_HDR_METADATA_HDR10PLUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_KERNELOVERLAYINFO {
     pub hAllocation: u32,
     pub DstRect: D3DDDIRECT,
@@ -5171,10 +5756,31 @@ impl Default for D3DDDI_MAKERESIDENT_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_MAKERESIDENT_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_MAKERESIDENT_FLAGS_0_0 {
+    pub const fn CantTrimFurther(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_CantTrimFurther(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MustSucceed(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MustSucceed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_MAKERESIDENT_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5209,7 +5815,8 @@ impl Default for D3DDDI_MAPGPUVIRTUALADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_MULTISAMPLINGMETHOD {
     pub NumSamples: u32,
     pub NumQualityLevels: u32,
@@ -5223,7 +5830,8 @@ impl Default for D3DDDI_MULTISAMPLINGMETHOD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_NATIVEFENCEMAPPING {
     pub CurrentValueCpuVa: *mut core::ffi::c_void,
     pub CurrentValueGpuVa: u64,
@@ -5265,10 +5873,25 @@ impl Default for D3DDDI_OFFER_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_OFFER_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_OFFER_FLAGS_0_0 {
+    pub const fn AllowDecommit(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_AllowDecommit(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_OFFER_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5278,7 +5901,8 @@ impl Default for D3DDDI_OFFER_FLAGS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_OPENALLOCATIONINFO {
     pub hAllocation: u32,
     pub pPrivateDriverData: *const core::ffi::c_void,
@@ -5293,7 +5917,8 @@ impl Default for D3DDDI_OPENALLOCATIONINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_OPENALLOCATIONINFO2 {
     pub hAllocation: u32,
     pub pPrivateDriverData: *const core::ffi::c_void,
@@ -5342,10 +5967,25 @@ impl Default for D3DDDI_PATCHLOCATIONLIST_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_PATCHLOCATIONLIST_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_PATCHLOCATIONLIST_0_0 {
+    pub const fn SlotId(&self) -> u32 {
+        self._bitfield & 16777215
+    }
+    pub fn set_SlotId(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !16777215) | (value & 16777215);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_PATCHLOCATIONLIST_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5382,10 +6022,31 @@ impl Default for D3DDDI_QUERYREGISTRY_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_QUERYREGISTRY_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_QUERYREGISTRY_FLAGS_0_0 {
+    pub const fn TranslatePath(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_TranslatePath(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MutableValue(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MutableValue(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_QUERYREGISTRY_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5431,7 +6092,8 @@ impl Default for D3DDDI_QUERYREGISTRY_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_RATIONAL {
     pub Numerator: u32,
     pub Denominator: u32,
@@ -5549,10 +6211,205 @@ impl Default for D3DDDI_RESOURCEFLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_RESOURCEFLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_RESOURCEFLAGS_0_0 {
+    pub const fn RenderTarget(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_RenderTarget(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ZBuffer(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ZBuffer(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Dynamic(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Dynamic(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn HintStatic(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_HintStatic(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn AutogenMipmap(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_AutogenMipmap(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn DMap(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_DMap(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn WriteOnly(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_WriteOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn NotLockable(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_NotLockable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Points(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_Points(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn RtPatches(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_RtPatches(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn NPatches(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_NPatches(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn SharedResource(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_SharedResource(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn DiscardRenderTarget(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_DiscardRenderTarget(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn Video(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_Video(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn CaptureBuffer(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_CaptureBuffer(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn Primary(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_Primary(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn Texture(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_Texture(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn CubeMap(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_CubeMap(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn Volume(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_Volume(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn VertexBuffer(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_VertexBuffer(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn IndexBuffer(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_IndexBuffer(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn DecodeRenderTarget(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_DecodeRenderTarget(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn DecodeCompressedBuffer(&self) -> u32 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_DecodeCompressedBuffer(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn VideoProcessRenderTarget(&self) -> u32 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_VideoProcessRenderTarget(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn CpuOptimized(&self) -> u32 {
+        (self._bitfield >> 24) & 1
+    }
+    pub fn set_CpuOptimized(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 24)) | ((value & 1) << 24);
+    }
+    pub const fn MightDrawFromLocked(&self) -> u32 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_MightDrawFromLocked(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn Overlay(&self) -> u32 {
+        (self._bitfield >> 26) & 1
+    }
+    pub fn set_Overlay(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 26)) | ((value & 1) << 26);
+    }
+    pub const fn MatchGdiPrimary(&self) -> u32 {
+        (self._bitfield >> 27) & 1
+    }
+    pub fn set_MatchGdiPrimary(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 27)) | ((value & 1) << 27);
+    }
+    pub const fn InterlacedRefresh(&self) -> u32 {
+        (self._bitfield >> 28) & 1
+    }
+    pub fn set_InterlacedRefresh(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 28)) | ((value & 1) << 28);
+    }
+    pub const fn TextApi(&self) -> u32 {
+        (self._bitfield >> 29) & 1
+    }
+    pub fn set_TextApi(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 29)) | ((value & 1) << 29);
+    }
+    pub const fn RestrictedContent(&self) -> u32 {
+        (self._bitfield >> 30) & 1
+    }
+    pub fn set_RestrictedContent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 30)) | ((value & 1) << 30);
+    }
+    pub const fn RestrictSharedAccess(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_RestrictSharedAccess(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_RESOURCEFLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5589,10 +6446,43 @@ impl Default for D3DDDI_RESOURCEFLAGS2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_RESOURCEFLAGS2_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_RESOURCEFLAGS2_0_0 {
+    pub const fn VideoEncoder(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_VideoEncoder(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn UserMemory(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_UserMemory(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn CrossAdapter(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CrossAdapter(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn IsDisplayable(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_IsDisplayable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_RESOURCEFLAGS2_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5629,10 +6519,79 @@ impl Default for D3DDDI_SEGMENTPREFERENCE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_SEGMENTPREFERENCE_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_SEGMENTPREFERENCE_0_0 {
+    pub const fn SegmentId0(&self) -> u32 {
+        self._bitfield & 31
+    }
+    pub fn set_SegmentId0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn Direction0(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Direction0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn SegmentId1(&self) -> u32 {
+        (self._bitfield >> 6) & 31
+    }
+    pub fn set_SegmentId1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 6)) | ((value & 31) << 6);
+    }
+    pub const fn Direction1(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_Direction1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn SegmentId2(&self) -> u32 {
+        (self._bitfield >> 12) & 31
+    }
+    pub fn set_SegmentId2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 12)) | ((value & 31) << 12);
+    }
+    pub const fn Direction2(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_Direction2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn SegmentId3(&self) -> u32 {
+        (self._bitfield >> 18) & 31
+    }
+    pub fn set_SegmentId3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 18)) | ((value & 31) << 18);
+    }
+    pub const fn Direction3(&self) -> u32 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_Direction3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn SegmentId4(&self) -> u32 {
+        (self._bitfield >> 24) & 31
+    }
+    pub fn set_SegmentId4(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 24)) | ((value & 31) << 24);
+    }
+    pub const fn Direction4(&self) -> u32 {
+        (self._bitfield >> 29) & 1
+    }
+    pub fn set_Direction4(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 29)) | ((value & 1) << 29);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 30) & 3
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 30)) | ((value & 3) << 30);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_SEGMENTPREFERENCE_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5642,7 +6601,8 @@ impl Default for D3DDDI_SEGMENTPREFERENCE_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_SURFACEINFO {
     pub Width: u32,
     pub Height: u32,
@@ -5689,7 +6649,8 @@ impl Default for D3DDDI_SYNCHRONIZATIONOBJECTINFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_SYNCHRONIZATIONOBJECTINFO_0_0 {
     pub Reserved: [u32; 16],
 }
@@ -5702,7 +6663,8 @@ impl Default for D3DDDI_SYNCHRONIZATIONOBJECTINFO_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_SYNCHRONIZATIONOBJECTINFO_0_1 {
     pub MaxCount: u32,
     pub InitialCount: u32,
@@ -5716,7 +6678,8 @@ impl Default for D3DDDI_SYNCHRONIZATIONOBJECTINFO_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_SYNCHRONIZATIONOBJECTINFO_0_2 {
     pub InitialState: super::super::super::Win32::Foundation::BOOL,
 }
@@ -5764,7 +6727,8 @@ impl Default for D3DDDI_SYNCHRONIZATIONOBJECTINFO2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_SYNCHRONIZATIONOBJECTINFO2_0_0 {
     pub Event: super::super::super::Win32::Foundation::HANDLE,
 }
@@ -5777,7 +6741,8 @@ impl Default for D3DDDI_SYNCHRONIZATIONOBJECTINFO2_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_SYNCHRONIZATIONOBJECTINFO2_0_1 {
     pub FenceValue: u64,
 }
@@ -5790,7 +6755,8 @@ impl Default for D3DDDI_SYNCHRONIZATIONOBJECTINFO2_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_SYNCHRONIZATIONOBJECTINFO2_0_2 {
     pub InitialFenceValue: u64,
     pub FenceValueCPUVirtualAddress: *mut core::ffi::c_void,
@@ -5807,7 +6773,8 @@ impl Default for D3DDDI_SYNCHRONIZATIONOBJECTINFO2_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_SYNCHRONIZATIONOBJECTINFO2_0_3 {
     pub hAdapter: u32,
     pub VidPnTargetId: u32,
@@ -5826,7 +6793,8 @@ impl Default for D3DDDI_SYNCHRONIZATIONOBJECTINFO2_0_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_SYNCHRONIZATIONOBJECTINFO2_0_4 {
     pub Reserved: [u64; 8],
 }
@@ -5839,7 +6807,8 @@ impl Default for D3DDDI_SYNCHRONIZATIONOBJECTINFO2_0_4 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_SYNCHRONIZATIONOBJECTINFO2_0_5 {
     pub MaxCount: u32,
     pub InitialCount: u32,
@@ -5853,7 +6822,8 @@ impl Default for D3DDDI_SYNCHRONIZATIONOBJECTINFO2_0_5 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_SYNCHRONIZATIONOBJECTINFO2_0_6 {
     pub InitialState: super::super::super::Win32::Foundation::BOOL,
 }
@@ -5893,10 +6863,85 @@ impl Default for D3DDDI_SYNCHRONIZATIONOBJECT_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_SYNCHRONIZATIONOBJECT_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_SYNCHRONIZATIONOBJECT_FLAGS_0_0 {
+    pub const fn Shared(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Shared(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NtSecuritySharing(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NtSecuritySharing(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn CrossAdapter(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CrossAdapter(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn TopOfPipeline(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_TopOfPipeline(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn NoSignal(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_NoSignal(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn NoWait(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_NoWait(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn NoSignalMaxValueOnTdr(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_NoSignalMaxValueOnTdr(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn NoGPUAccess(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_NoGPUAccess(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn SignalByKmd(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_SignalByKmd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn LocalMemoryPreferred(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_LocalMemoryPreferred(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 10) & 2097151
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2097151 << 10)) | ((value & 2097151) << 10);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_SYNCHRONIZATIONOBJECT_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5933,10 +6978,37 @@ impl Default for D3DDDI_TRIMRESIDENCYSET_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_TRIMRESIDENCYSET_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_TRIMRESIDENCYSET_FLAGS_0_0 {
+    pub const fn PeriodicTrim(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_PeriodicTrim(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn RestartPeriodicTrim(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_RestartPeriodicTrim(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn TrimToBudget(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_TrimToBudget(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_TRIMRESIDENCYSET_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5979,10 +7051,43 @@ impl Default for D3DDDI_UPDATEALLOCPROPERTY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_UPDATEALLOCPROPERTY_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_UPDATEALLOCPROPERTY_0_0 {
+    pub const fn SetAccessedPhysically(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_SetAccessedPhysically(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn SetSupportedSegmentSet(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_SetSupportedSegmentSet(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn SetPreferredSegment(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_SetPreferredSegment(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn SetUnmoveable(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_SetUnmoveable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_UPDATEALLOCPROPERTY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6019,10 +7124,31 @@ impl Default for D3DDDI_UPDATEALLOCPROPERTY_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_UPDATEALLOCPROPERTY_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_UPDATEALLOCPROPERTY_FLAGS_0_0 {
+    pub const fn AccessedPhysically(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_AccessedPhysically(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Unmoveable(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Unmoveable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_UPDATEALLOCPROPERTY_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6062,7 +7188,8 @@ impl Default for D3DDDI_UPDATEGPUVIRTUALADDRESS_OPERATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_UPDATEGPUVIRTUALADDRESS_OPERATION_0_0 {
     pub SourceAddress: u64,
     pub SizeInBytes: u64,
@@ -6096,7 +7223,8 @@ impl Default for D3DDDI_UPDATEGPUVIRTUALADDRESS_OPERATION_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_UPDATEGPUVIRTUALADDRESS_OPERATION_0_2 {
     pub BaseAddress: u64,
     pub SizeInBytes: u64,
@@ -6155,10 +7283,25 @@ impl Default for D3DDDI_WAITFORSYNCHRONIZATIONOBJECTFROMCPU_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDDI_WAITFORSYNCHRONIZATIONOBJECTFROMCPU_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DDDI_WAITFORSYNCHRONIZATIONOBJECTFROMCPU_FLAGS_0_0 {
+    pub const fn WaitAny(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_WaitAny(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DDDI_WAITFORSYNCHRONIZATIONOBJECTFROMCPU_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6169,7 +7312,8 @@ impl Default for D3DDDI_WAITFORSYNCHRONIZATIONOBJECTFROMCPU_FLAGS_0_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDEVICEDESC_V1 {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -6197,7 +7341,8 @@ impl Default for D3DDEVICEDESC_V1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDEVICEDESC_V2 {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -6233,7 +7378,8 @@ impl Default for D3DDEVICEDESC_V2 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DDEVICEDESC_V3 {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -6281,7 +7427,8 @@ impl Default for D3DDEVICEDESC_V3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DGPU_PHYSICAL_ADDRESS {
     pub SegmentId: u32,
     pub Padding: u32,
@@ -6297,7 +7444,8 @@ impl Default for D3DGPU_PHYSICAL_ADDRESS {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_CALLBACKS {
     pub dwSize: u32,
     pub ContextCreate: LPD3DHAL_CONTEXTCREATECB,
@@ -6347,7 +7495,8 @@ impl Default for D3DHAL_CALLBACKS {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_CALLBACKS2 {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -6369,7 +7518,8 @@ impl Default for D3DHAL_CALLBACKS2 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_CALLBACKS3 {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -6390,7 +7540,8 @@ impl Default for D3DHAL_CALLBACKS3 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_CLEAR2DATA {
     pub dwhContext: usize,
     pub dwFlags: u32,
@@ -6413,7 +7564,8 @@ impl Default for D3DHAL_CLEAR2DATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_CLEARDATA {
     pub dwhContext: usize,
     pub dwFlags: u32,
@@ -6434,7 +7586,8 @@ impl Default for D3DHAL_CLEARDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_CLIPPEDTRIANGLEFAN {
     pub FirstVertexOffset: u32,
     pub dwEdgeFlags: u32,
@@ -6553,7 +7706,8 @@ impl Default for D3DHAL_CONTEXTCREATEDATA_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_CONTEXTDESTROYALLDATA {
     pub dwPID: u32,
     pub ddrval: windows_core::HRESULT,
@@ -6567,7 +7721,8 @@ impl Default for D3DHAL_CONTEXTDESTROYALLDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_CONTEXTDESTROYDATA {
     pub dwhContext: usize,
     pub ddrval: windows_core::HRESULT,
@@ -6581,7 +7736,8 @@ impl Default for D3DHAL_CONTEXTDESTROYDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_D3DDX6EXTENDEDCAPS {
     pub dwSize: u32,
     pub dwMinTextureWidth: u32,
@@ -6615,7 +7771,8 @@ impl Default for D3DHAL_D3DDX6EXTENDEDCAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_D3DEXTENDEDCAPS {
     pub dwSize: u32,
     pub dwMinTextureWidth: u32,
@@ -6659,7 +7816,8 @@ impl Default for D3DHAL_D3DEXTENDEDCAPS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2ADDDIRTYBOX {
     pub dwSurface: u32,
     pub DirtyBox: super::super::super::Win32::Graphics::Direct3D9::D3DBOX,
@@ -6675,7 +7833,8 @@ impl Default for D3DHAL_DP2ADDDIRTYBOX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2ADDDIRTYRECT {
     pub dwSurface: u32,
     pub rDirtyArea: super::super::super::Win32::Foundation::RECTL,
@@ -6689,7 +7848,8 @@ impl Default for D3DHAL_DP2ADDDIRTYRECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2BLT {
     pub dwSource: u32,
     pub rSource: super::super::super::Win32::Foundation::RECTL,
@@ -6709,7 +7869,8 @@ impl Default for D3DHAL_DP2BLT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2BUFFERBLT {
     pub dwDDDestSurface: u32,
     pub dwDDSrcSurface: u32,
@@ -6728,7 +7889,8 @@ impl Default for D3DHAL_DP2BUFFERBLT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2CLEAR {
     pub dwFlags: u32,
     pub dwFillColor: u32,
@@ -6745,7 +7907,8 @@ impl Default for D3DHAL_DP2CLEAR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2COLORFILL {
     pub dwSurface: u32,
     pub rRect: super::super::super::Win32::Foundation::RECTL,
@@ -6790,7 +7953,8 @@ impl Default for D3DHAL_DP2COMMAND_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2COMPOSERECTS {
     pub SrcSurfaceHandle: u32,
     pub DstSurfaceHandle: u32,
@@ -6812,7 +7976,8 @@ impl Default for D3DHAL_DP2COMPOSERECTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2CREATELIGHT {
     pub dwIndex: u32,
 }
@@ -6825,7 +7990,8 @@ impl Default for D3DHAL_DP2CREATELIGHT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2CREATEPIXELSHADER {
     pub dwHandle: u32,
     pub dwCodeSize: u32,
@@ -6840,7 +8006,8 @@ impl Default for D3DHAL_DP2CREATEPIXELSHADER {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2CREATEQUERY {
     pub dwQueryID: u32,
     pub QueryType: super::super::super::Win32::Graphics::Direct3D9::D3DQUERYTYPE,
@@ -6856,7 +8023,8 @@ impl Default for D3DHAL_DP2CREATEQUERY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2CREATEVERTEXSHADER {
     pub dwHandle: u32,
     pub dwDeclSize: u32,
@@ -6871,7 +8039,8 @@ impl Default for D3DHAL_DP2CREATEVERTEXSHADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2CREATEVERTEXSHADERDECL {
     pub dwHandle: u32,
     pub dwNumVertexElements: u32,
@@ -6885,7 +8054,8 @@ impl Default for D3DHAL_DP2CREATEVERTEXSHADERDECL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2CREATEVERTEXSHADERFUNC {
     pub dwHandle: u32,
     pub dwSize: u32,
@@ -6899,7 +8069,8 @@ impl Default for D3DHAL_DP2CREATEVERTEXSHADERFUNC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2DELETEQUERY {
     pub dwQueryID: u32,
 }
@@ -6913,7 +8084,8 @@ impl Default for D3DHAL_DP2DELETEQUERY {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2DRAWINDEXEDPRIMITIVE {
     pub primType: super::super::super::Win32::Graphics::Direct3D9::D3DPRIMITIVETYPE,
     pub BaseVertexIndex: i32,
@@ -6934,7 +8106,8 @@ impl Default for D3DHAL_DP2DRAWINDEXEDPRIMITIVE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2DRAWINDEXEDPRIMITIVE2 {
     pub primType: super::super::super::Win32::Graphics::Direct3D9::D3DPRIMITIVETYPE,
     pub BaseVertexOffset: i32,
@@ -6955,7 +8128,8 @@ impl Default for D3DHAL_DP2DRAWINDEXEDPRIMITIVE2 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2DRAWPRIMITIVE {
     pub primType: super::super::super::Win32::Graphics::Direct3D9::D3DPRIMITIVETYPE,
     pub VStart: u32,
@@ -6973,7 +8147,8 @@ impl Default for D3DHAL_DP2DRAWPRIMITIVE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2DRAWPRIMITIVE2 {
     pub primType: super::super::super::Win32::Graphics::Direct3D9::D3DPRIMITIVETYPE,
     pub FirstVertexOffset: u32,
@@ -6990,7 +8165,8 @@ impl Default for D3DHAL_DP2DRAWPRIMITIVE2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2DRAWRECTPATCH {
     pub Handle: u32,
     pub Flags: u32,
@@ -7004,7 +8180,8 @@ impl Default for D3DHAL_DP2DRAWRECTPATCH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2DRAWTRIPATCH {
     pub Handle: u32,
     pub Flags: u32,
@@ -7018,7 +8195,8 @@ impl Default for D3DHAL_DP2DRAWTRIPATCH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2EXT {
     pub dwExtToken: u32,
     pub dwSize: u32,
@@ -7033,7 +8211,8 @@ impl Default for D3DHAL_DP2EXT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2GENERATEMIPSUBLEVELS {
     pub hSurface: u32,
     pub Filter: super::super::super::Win32::Graphics::Direct3D9::D3DTEXTUREFILTERTYPE,
@@ -7049,7 +8228,8 @@ impl Default for D3DHAL_DP2GENERATEMIPSUBLEVELS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2INDEXEDLINELIST {
     pub wV1: u16,
     pub wV2: u16,
@@ -7063,7 +8243,8 @@ impl Default for D3DHAL_DP2INDEXEDLINELIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2INDEXEDLINESTRIP {
     pub wV: [u16; 2],
 }
@@ -7076,7 +8257,8 @@ impl Default for D3DHAL_DP2INDEXEDLINESTRIP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2INDEXEDTRIANGLEFAN {
     pub wV: [u16; 3],
 }
@@ -7089,7 +8271,8 @@ impl Default for D3DHAL_DP2INDEXEDTRIANGLEFAN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2INDEXEDTRIANGLELIST {
     pub wV1: u16,
     pub wV2: u16,
@@ -7105,7 +8288,8 @@ impl Default for D3DHAL_DP2INDEXEDTRIANGLELIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2INDEXEDTRIANGLELIST2 {
     pub wV1: u16,
     pub wV2: u16,
@@ -7120,7 +8304,8 @@ impl Default for D3DHAL_DP2INDEXEDTRIANGLELIST2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2INDEXEDTRIANGLESTRIP {
     pub wV: [u16; 3],
 }
@@ -7133,7 +8318,8 @@ impl Default for D3DHAL_DP2INDEXEDTRIANGLESTRIP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2ISSUEQUERY {
     pub dwQueryID: u32,
     pub dwFlags: u32,
@@ -7147,7 +8333,8 @@ impl Default for D3DHAL_DP2ISSUEQUERY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2LINELIST {
     pub wVStart: u16,
 }
@@ -7160,7 +8347,8 @@ impl Default for D3DHAL_DP2LINELIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2LINESTRIP {
     pub wVStart: u16,
 }
@@ -7174,7 +8362,8 @@ impl Default for D3DHAL_DP2LINESTRIP {
 }
 #[repr(C)]
 #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct3D9"))]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2MULTIPLYTRANSFORM {
     pub xfrmType: super::super::super::Win32::Graphics::Direct3D9::D3DTRANSFORMSTATETYPE,
     pub matrix: super::super::super::Foundation::Numerics::Matrix4x4,
@@ -7190,7 +8379,8 @@ impl Default for D3DHAL_DP2MULTIPLYTRANSFORM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2PIXELSHADER {
     pub dwHandle: u32,
 }
@@ -7203,7 +8393,8 @@ impl Default for D3DHAL_DP2PIXELSHADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2POINTS {
     pub wCount: u16,
     pub wVStart: u16,
@@ -7251,7 +8442,8 @@ impl Default for D3DHAL_DP2RENDERSTATE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2RESPONSE {
     pub bCommand: u8,
     pub bReserved: u8,
@@ -7267,7 +8459,8 @@ impl Default for D3DHAL_DP2RESPONSE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2RESPONSEQUERY {
     pub dwQueryID: u32,
     pub dwSize: u32,
@@ -7281,7 +8474,8 @@ impl Default for D3DHAL_DP2RESPONSEQUERY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETCLIPPLANE {
     pub dwIndex: u32,
     pub plane: [f32; 4],
@@ -7295,7 +8489,8 @@ impl Default for D3DHAL_DP2SETCLIPPLANE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETCONVOLUTIONKERNELMONO {
     pub dwWidth: u32,
     pub dwHeight: u32,
@@ -7310,7 +8505,8 @@ impl Default for D3DHAL_DP2SETCONVOLUTIONKERNELMONO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETDEPTHSTENCIL {
     pub hZBuffer: u32,
 }
@@ -7323,7 +8519,8 @@ impl Default for D3DHAL_DP2SETDEPTHSTENCIL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETINDICES {
     pub dwVBHandle: u32,
     pub dwStride: u32,
@@ -7337,7 +8534,8 @@ impl Default for D3DHAL_DP2SETINDICES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETLIGHT {
     pub dwIndex: u32,
     pub dwDataType: u32,
@@ -7351,7 +8549,8 @@ impl Default for D3DHAL_DP2SETLIGHT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETPALETTE {
     pub dwPaletteHandle: u32,
     pub dwPaletteFlags: u32,
@@ -7366,7 +8565,8 @@ impl Default for D3DHAL_DP2SETPALETTE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETPIXELSHADERCONST {
     pub dwRegister: u32,
     pub dwCount: u32,
@@ -7380,7 +8580,8 @@ impl Default for D3DHAL_DP2SETPIXELSHADERCONST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETPRIORITY {
     pub dwDDSurface: u32,
     pub dwPriority: u32,
@@ -7394,7 +8595,8 @@ impl Default for D3DHAL_DP2SETPRIORITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETRENDERTARGET {
     pub hRenderTarget: u32,
     pub hZBuffer: u32,
@@ -7408,7 +8610,8 @@ impl Default for D3DHAL_DP2SETRENDERTARGET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETRENDERTARGET2 {
     pub RTIndex: u32,
     pub hRenderTarget: u32,
@@ -7422,7 +8625,8 @@ impl Default for D3DHAL_DP2SETRENDERTARGET2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETSTREAMSOURCE {
     pub dwStream: u32,
     pub dwVBHandle: u32,
@@ -7437,7 +8641,8 @@ impl Default for D3DHAL_DP2SETSTREAMSOURCE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETSTREAMSOURCE2 {
     pub dwStream: u32,
     pub dwVBHandle: u32,
@@ -7453,7 +8658,8 @@ impl Default for D3DHAL_DP2SETSTREAMSOURCE2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETSTREAMSOURCEFREQ {
     pub dwStream: u32,
     pub dwDivider: u32,
@@ -7467,7 +8673,8 @@ impl Default for D3DHAL_DP2SETSTREAMSOURCEFREQ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETSTREAMSOURCEUM {
     pub dwStream: u32,
     pub dwStride: u32,
@@ -7481,7 +8688,8 @@ impl Default for D3DHAL_DP2SETSTREAMSOURCEUM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETTEXLOD {
     pub dwDDSurface: u32,
     pub dwLOD: u32,
@@ -7496,7 +8704,8 @@ impl Default for D3DHAL_DP2SETTEXLOD {
 }
 #[repr(C)]
 #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct3D9"))]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETTRANSFORM {
     pub xfrmType: super::super::super::Win32::Graphics::Direct3D9::D3DTRANSFORMSTATETYPE,
     pub matrix: super::super::super::Foundation::Numerics::Matrix4x4,
@@ -7512,7 +8721,8 @@ impl Default for D3DHAL_DP2SETTRANSFORM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SETVERTEXSHADERCONST {
     pub dwRegister: u32,
     pub dwCount: u32,
@@ -7526,7 +8736,8 @@ impl Default for D3DHAL_DP2SETVERTEXSHADERCONST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2STARTVERTEX {
     pub wVStart: u16,
 }
@@ -7540,7 +8751,8 @@ impl Default for D3DHAL_DP2STARTVERTEX {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2STATESET {
     pub dwOperation: u32,
     pub dwParam: u32,
@@ -7557,7 +8769,8 @@ impl Default for D3DHAL_DP2STATESET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2SURFACEBLT {
     pub dwSource: u32,
     pub rSource: super::super::super::Win32::Foundation::RECTL,
@@ -7576,7 +8789,8 @@ impl Default for D3DHAL_DP2SURFACEBLT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2TEXBLT {
     pub dwDDDestSurface: u32,
     pub dwDDSrcSurface: u32,
@@ -7593,7 +8807,8 @@ impl Default for D3DHAL_DP2TEXBLT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2TEXTURESTAGESTATE {
     pub wStage: u16,
     pub TSState: u16,
@@ -7608,7 +8823,8 @@ impl Default for D3DHAL_DP2TEXTURESTAGESTATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2TRIANGLEFAN {
     pub wVStart: u16,
 }
@@ -7621,7 +8837,8 @@ impl Default for D3DHAL_DP2TRIANGLEFAN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2TRIANGLEFAN_IMM {
     pub dwEdgeFlags: u32,
 }
@@ -7634,7 +8851,8 @@ impl Default for D3DHAL_DP2TRIANGLEFAN_IMM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2TRIANGLELIST {
     pub wVStart: u16,
 }
@@ -7647,7 +8865,8 @@ impl Default for D3DHAL_DP2TRIANGLELIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2TRIANGLESTRIP {
     pub wVStart: u16,
 }
@@ -7660,7 +8879,8 @@ impl Default for D3DHAL_DP2TRIANGLESTRIP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2UPDATEPALETTE {
     pub dwPaletteHandle: u32,
     pub wStartIndex: u16,
@@ -7675,7 +8895,8 @@ impl Default for D3DHAL_DP2UPDATEPALETTE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2VERTEXSHADER {
     pub dwHandle: u32,
 }
@@ -7688,7 +8909,8 @@ impl Default for D3DHAL_DP2VERTEXSHADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2VIEWPORTINFO {
     pub dwX: u32,
     pub dwY: u32,
@@ -7705,7 +8927,8 @@ impl Default for D3DHAL_DP2VIEWPORTINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2VOLUMEBLT {
     pub dwDDDestSurface: u32,
     pub dwDDSrcSurface: u32,
@@ -7726,7 +8949,8 @@ impl Default for D3DHAL_DP2VOLUMEBLT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2WINFO {
     pub dvWNear: f32,
     pub dvWFar: f32,
@@ -7740,7 +8964,8 @@ impl Default for D3DHAL_DP2WINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DP2ZRANGE {
     pub dvMinZ: f32,
     pub dvMaxZ: f32,
@@ -7835,7 +9060,8 @@ impl Default for D3DHAL_DRAWONEPRIMITIVEDATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DRAWPRIMCOUNTS {
     pub wNumStateChanges: u16,
     pub wPrimitiveType: u16,
@@ -7914,7 +9140,8 @@ impl Default for D3DHAL_DRAWPRIMITIVES2DATA_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_DRAWPRIMITIVESDATA {
     pub dwhContext: usize,
     pub dwFlags: u32,
@@ -7951,7 +9178,8 @@ impl Default for D3DHAL_GETSTATEDATA {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_DirectDraw"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_GLOBALDRIVERDATA {
     pub dwSize: u32,
     pub hwCaps: D3DDEVICEDESC_V1,
@@ -7972,7 +9200,8 @@ impl Default for D3DHAL_GLOBALDRIVERDATA {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_DirectDraw"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_RENDERPRIMITIVEDATA {
     pub dwhContext: usize,
     pub dwOffset: u32,
@@ -8001,7 +9230,8 @@ impl Default for D3DHAL_RENDERPRIMITIVEDATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_DirectDraw")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_RENDERSTATEDATA {
     pub dwhContext: usize,
     pub dwOffset: u32,
@@ -8026,7 +9256,8 @@ impl Default for D3DHAL_RENDERSTATEDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_SCENECAPTUREDATA {
     pub dwhContext: usize,
     pub dwFlag: u32,
@@ -8110,7 +9341,8 @@ impl Default for D3DHAL_SETRENDERTARGETDATA_1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_DirectDraw")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_TEXTURECREATEDATA {
     pub dwhContext: usize,
     pub lpDDS: core::mem::ManuallyDrop<Option<super::super::super::Win32::Graphics::DirectDraw::IDirectDrawSurface>>,
@@ -8134,7 +9366,8 @@ impl Default for D3DHAL_TEXTURECREATEDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_TEXTUREDESTROYDATA {
     pub dwhContext: usize,
     pub dwHandle: u32,
@@ -8149,7 +9382,8 @@ impl Default for D3DHAL_TEXTUREDESTROYDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_TEXTUREGETSURFDATA {
     pub dwhContext: usize,
     pub lpDDS: usize,
@@ -8165,7 +9399,8 @@ impl Default for D3DHAL_TEXTUREGETSURFDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_TEXTURESWAPDATA {
     pub dwhContext: usize,
     pub dwHandle1: u32,
@@ -8181,7 +9416,8 @@ impl Default for D3DHAL_TEXTURESWAPDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DHAL_VALIDATETEXTURESTAGESTATEDATA {
     pub dwhContext: usize,
     pub dwFlags: u32,
@@ -8198,7 +9434,8 @@ impl Default for D3DHAL_VALIDATETEXTURESTAGESTATEDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_2DREGION {
     pub cx: u32,
     pub cy: u32,
@@ -8212,7 +9449,8 @@ impl Default for D3DKMDT_2DREGION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_3x4_COLORSPACE_TRANSFORM {
     pub ColorMatrix3x4: [f32; 12],
     pub ScalarMultiplier: f32,
@@ -8227,7 +9465,8 @@ impl Default for D3DKMDT_3x4_COLORSPACE_TRANSFORM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_COLORSPACE_TRANSFORM_MATRIX_V2 {
     pub StageControlLookupTable1DDegamma: D3DKMDT_COLORSPACE_TRANSFORM_STAGE_CONTROL,
     pub LookupTable1DDegamma: [D3DDDI_DXGI_RGB; 4096],
@@ -8245,7 +9484,8 @@ impl Default for D3DKMDT_COLORSPACE_TRANSFORM_MATRIX_V2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_COLOR_COEFF_DYNAMIC_RANGES {
     pub FirstChannel: u32,
     pub SecondChannel: u32,
@@ -8261,11 +9501,74 @@ impl Default for D3DKMDT_COLOR_COEFF_DYNAMIC_RANGES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_DISPLAYMODE_FLAGS {
     pub _bitfield1: u32,
     pub _bitfield2: u32,
 }
+impl D3DKMDT_DISPLAYMODE_FLAGS {
+    pub const fn ValidatedAgainstMonitorCaps(&self) -> u32 {
+        self._bitfield1 & 1
+    }
+    pub fn set_ValidatedAgainstMonitorCaps(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !1) | (value & 1);
+    }
+    pub const fn RoundedFakeMode(&self) -> u32 {
+        (self._bitfield1 >> 1) & 1
+    }
+    pub fn set_RoundedFakeMode(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Anonymous(&self) -> u32 {
+        (self._bitfield1 >> 2) & 0
+    }
+    pub fn set_Anonymous(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(0 << 2)) | ((value & 0) << 2);
+    }
+    pub const fn ModePruningReason(&self) -> u32 {
+        (self._bitfield1 >> 2) & 15
+    }
+    pub fn set_ModePruningReason(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(15 << 2)) | ((value & 15) << 2);
+    }
+    pub const fn Stereo(&self) -> u32 {
+        (self._bitfield1 >> 6) & 1
+    }
+    pub fn set_Stereo(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn AdvancedScanCapable(&self) -> u32 {
+        (self._bitfield1 >> 7) & 1
+    }
+    pub fn set_AdvancedScanCapable(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn PreferredTiming(&self) -> u32 {
+        (self._bitfield1 >> 8) & 1
+    }
+    pub fn set_PreferredTiming(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn PhysicalModeSupported(&self) -> u32 {
+        (self._bitfield1 >> 9) & 1
+    }
+    pub fn set_PhysicalModeSupported(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn VirtualRefreshRate(&self) -> u32 {
+        (self._bitfield1 >> 10) & 1
+    }
+    pub fn set_VirtualRefreshRate(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield2 & 8388607
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !8388607) | (value & 8388607);
+    }
+}
 impl windows_core::TypeKind for D3DKMDT_DISPLAYMODE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -8275,7 +9578,8 @@ impl Default for D3DKMDT_DISPLAYMODE_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_FREQUENCY_RANGE {
     pub MinVSyncFreq: D3DDDI_RATIONAL,
     pub MaxVSyncFreq: D3DDDI_RATIONAL,
@@ -8368,10 +9672,19 @@ impl Default for D3DKMDT_GDISURFACEFLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_GDISURFACEFLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMDT_GDISURFACEFLAGS_0_0 {
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = value;
+    }
+}
 impl windows_core::TypeKind for D3DKMDT_GDISURFACEFLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -8381,7 +9694,8 @@ impl Default for D3DKMDT_GDISURFACEFLAGS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_GRAPHICS_RENDERING_FORMAT {
     pub PrimSurfSize: D3DKMDT_2DREGION,
     pub VisibleRegionSize: D3DKMDT_2DREGION,
@@ -8399,7 +9713,8 @@ impl Default for D3DKMDT_GRAPHICS_RENDERING_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_MONITOR_DESCRIPTOR {
     pub Id: u32,
     pub Type: D3DKMDT_MONITOR_DESCRIPTOR_TYPE,
@@ -8464,7 +9779,8 @@ impl Default for D3DKMDT_MONITOR_SOURCE_MODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_PALETTEDATA {
     pub Red: u8,
     pub Green: u8,
@@ -8480,7 +9796,8 @@ impl Default for D3DKMDT_PALETTEDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_PREEMPTION_CAPS {
     pub GraphicsPreemptionGranularity: D3DKMDT_GRAPHICS_PREEMPTION_GRANULARITY,
     pub ComputePreemptionGranularity: D3DKMDT_COMPUTE_PREEMPTION_GRANULARITY,
@@ -8494,7 +9811,8 @@ impl Default for D3DKMDT_PREEMPTION_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_SHADOWSURFACEDATA {
     pub Width: u32,
     pub Height: u32,
@@ -8510,7 +9828,8 @@ impl Default for D3DKMDT_SHADOWSURFACEDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_SHAREDPRIMARYSURFACEDATA {
     pub Width: u32,
     pub Height: u32,
@@ -8527,7 +9846,8 @@ impl Default for D3DKMDT_SHAREDPRIMARYSURFACEDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_STAGINGSURFACEDATA {
     pub Width: u32,
     pub Height: u32,
@@ -8542,7 +9862,8 @@ impl Default for D3DKMDT_STAGINGSURFACEDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_VIDEO_PRESENT_SOURCE {
     pub Id: u32,
     pub dwReserved: u32,
@@ -8556,7 +9877,8 @@ impl Default for D3DKMDT_VIDEO_PRESENT_SOURCE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_VIDEO_PRESENT_TARGET {
     pub Id: u32,
     pub VideoOutputTechnology: D3DKMDT_VIDEO_OUTPUT_TECHNOLOGY,
@@ -8606,10 +9928,31 @@ impl Default for D3DKMDT_VIDEO_SIGNAL_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_VIDEO_SIGNAL_INFO_0_0 {
     pub _bitfield: i32,
 }
+impl D3DKMDT_VIDEO_SIGNAL_INFO_0_0 {
+    pub const fn ScanLineOrdering(&self) -> i32 {
+        self._bitfield & 7
+    }
+    pub fn set_ScanLineOrdering(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn VSyncFreqDivider(&self) -> i32 {
+        (self._bitfield >> 3) & 63
+    }
+    pub fn set_VSyncFreqDivider(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(63 << 3)) | ((value & 63) << 3);
+    }
+    pub const fn Reserved(&self) -> i32 {
+        (self._bitfield >> 9) & 8388607
+    }
+    pub fn set_Reserved(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(8388607 << 9)) | ((value & 8388607) << 9);
+    }
+}
 impl windows_core::TypeKind for D3DKMDT_VIDEO_SIGNAL_INFO_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -8619,10 +9962,55 @@ impl Default for D3DKMDT_VIDEO_SIGNAL_INFO_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_VIDPN_HW_CAPABILITY {
     pub _bitfield: u32,
 }
+impl D3DKMDT_VIDPN_HW_CAPABILITY {
+    pub const fn DriverRotation(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_DriverRotation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DriverScaling(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DriverScaling(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn DriverCloning(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_DriverCloning(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn DriverColorConvert(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_DriverColorConvert(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn DriverLinkedAdapaterOutput(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_DriverLinkedAdapaterOutput(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn DriverRemoteDisplay(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_DriverRemoteDisplay(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 6) & 67108863
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(67108863 << 6)) | ((value & 67108863) << 6);
+    }
+}
 impl windows_core::TypeKind for D3DKMDT_VIDPN_HW_CAPABILITY {
     type TypeKind = windows_core::CopyType;
 }
@@ -8655,7 +10043,8 @@ impl Default for D3DKMDT_VIDPN_PRESENT_PATH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_VIDPN_PRESENT_PATH_COPYPROTECTION {
     pub CopyProtectionType: D3DKMDT_VIDPN_PRESENT_PATH_COPYPROTECTION_TYPE,
     pub APSTriggerBits: u32,
@@ -8671,10 +10060,37 @@ impl Default for D3DKMDT_VIDPN_PRESENT_PATH_COPYPROTECTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_VIDPN_PRESENT_PATH_COPYPROTECTION_SUPPORT {
     pub _bitfield: u32,
 }
+impl D3DKMDT_VIDPN_PRESENT_PATH_COPYPROTECTION_SUPPORT {
+    pub const fn NoProtection(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_NoProtection(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MacroVisionApsTrigger(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MacroVisionApsTrigger(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MacroVisionFull(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_MacroVisionFull(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for D3DKMDT_VIDPN_PRESENT_PATH_COPYPROTECTION_SUPPORT {
     type TypeKind = windows_core::CopyType;
 }
@@ -8684,10 +10100,61 @@ impl Default for D3DKMDT_VIDPN_PRESENT_PATH_COPYPROTECTION_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_VIDPN_PRESENT_PATH_ROTATION_SUPPORT {
     pub _bitfield: u32,
 }
+impl D3DKMDT_VIDPN_PRESENT_PATH_ROTATION_SUPPORT {
+    pub const fn Identity(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Identity(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Rotate90(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Rotate90(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Rotate180(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Rotate180(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Rotate270(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Rotate270(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Offset0(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Offset0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Offset90(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Offset90(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Offset180(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Offset180(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Offset270(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Offset270(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for D3DKMDT_VIDPN_PRESENT_PATH_ROTATION_SUPPORT {
     type TypeKind = windows_core::CopyType;
 }
@@ -8697,10 +10164,43 @@ impl Default for D3DKMDT_VIDPN_PRESENT_PATH_ROTATION_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_VIDPN_PRESENT_PATH_SCALING_SUPPORT {
     pub _bitfield: u32,
 }
+impl D3DKMDT_VIDPN_PRESENT_PATH_SCALING_SUPPORT {
+    pub const fn Identity(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Identity(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Centered(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Centered(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Stretched(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Stretched(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AspectRatioCenteredMax(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AspectRatioCenteredMax(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Custom(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Custom(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+}
 impl windows_core::TypeKind for D3DKMDT_VIDPN_PRESENT_PATH_SCALING_SUPPORT {
     type TypeKind = windows_core::CopyType;
 }
@@ -8710,7 +10210,8 @@ impl Default for D3DKMDT_VIDPN_PRESENT_PATH_SCALING_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_VIDPN_PRESENT_PATH_TRANSFORMATION {
     pub Scaling: D3DKMDT_VIDPN_PRESENT_PATH_SCALING,
     pub ScalingSupport: D3DKMDT_VIDPN_PRESENT_PATH_SCALING_SUPPORT,
@@ -8785,10 +10286,25 @@ impl Default for D3DKMDT_VIDPN_TARGET_MODE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_VIDPN_TARGET_MODE_0_0 {
     pub _bitfield: i32,
 }
+impl D3DKMDT_VIDPN_TARGET_MODE_0_0 {
+    pub const fn Preference(&self) -> i32 {
+        self._bitfield & 3
+    }
+    pub fn set_Preference(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn Anonymous(&self) -> i32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Anonymous(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for D3DKMDT_VIDPN_TARGET_MODE_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -8798,7 +10314,8 @@ impl Default for D3DKMDT_VIDPN_TARGET_MODE_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_VIRTUALGPUSURFACEDATA {
     pub Size: u64,
     pub Alignment: u32,
@@ -8828,10 +10345,49 @@ impl Default for D3DKMDT_WIRE_FORMAT_AND_PREFERENCE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMDT_WIRE_FORMAT_AND_PREFERENCE_0 {
     pub _bitfield: i32,
 }
+impl D3DKMDT_WIRE_FORMAT_AND_PREFERENCE_0 {
+    pub const fn Preference(&self) -> i32 {
+        self._bitfield & 3
+    }
+    pub fn set_Preference(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn Rgb(&self) -> i32 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_Rgb(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+    pub const fn YCbCr444(&self) -> i32 {
+        (self._bitfield >> 8) & 63
+    }
+    pub fn set_YCbCr444(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(63 << 8)) | ((value & 63) << 8);
+    }
+    pub const fn YCbCr422(&self) -> i32 {
+        (self._bitfield >> 14) & 63
+    }
+    pub fn set_YCbCr422(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(63 << 14)) | ((value & 63) << 14);
+    }
+    pub const fn YCbCr420(&self) -> i32 {
+        (self._bitfield >> 20) & 63
+    }
+    pub fn set_YCbCr420(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(63 << 20)) | ((value & 63) << 20);
+    }
+    pub const fn Intensity(&self) -> i32 {
+        (self._bitfield >> 26) & 63
+    }
+    pub fn set_Intensity(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(63 << 26)) | ((value & 63) << 26);
+    }
+}
 impl windows_core::TypeKind for D3DKMDT_WIRE_FORMAT_AND_PREFERENCE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -8841,7 +10397,8 @@ impl Default for D3DKMDT_WIRE_FORMAT_AND_PREFERENCE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ACQUIREKEYEDMUTEX {
     pub hKeyedMutex: u32,
     pub Key: u64,
@@ -8857,7 +10414,8 @@ impl Default for D3DKMT_ACQUIREKEYEDMUTEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ACQUIREKEYEDMUTEX2 {
     pub hKeyedMutex: u32,
     pub Key: u64,
@@ -8875,7 +10433,8 @@ impl Default for D3DKMT_ACQUIREKEYEDMUTEX2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ACTIVATE_SPECIFIC_DIAG_ESCAPE {
     pub Type: D3DKMT_ACTIVATE_SPECIFIC_DIAG_TYPE,
     pub Activate: super::super::super::Win32::Foundation::BOOL,
@@ -8889,7 +10448,8 @@ impl Default for D3DKMT_ACTIVATE_SPECIFIC_DIAG_ESCAPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ADAPTERADDRESS {
     pub BusNumber: u32,
     pub DeviceNumber: u32,
@@ -8904,7 +10464,8 @@ impl Default for D3DKMT_ADAPTERADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ADAPTERINFO {
     pub hAdapter: u32,
     pub AdapterLuid: super::super::super::Win32::Foundation::LUID,
@@ -8920,7 +10481,8 @@ impl Default for D3DKMT_ADAPTERINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ADAPTERREGISTRYINFO {
     pub AdapterString: [u16; 260],
     pub BiosString: [u16; 260],
@@ -8963,10 +10525,103 @@ impl Default for D3DKMT_ADAPTERTYPE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ADAPTERTYPE_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_ADAPTERTYPE_0_0 {
+    pub const fn RenderSupported(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_RenderSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DisplaySupported(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DisplaySupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn SoftwareDevice(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_SoftwareDevice(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn PostDevice(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_PostDevice(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn HybridDiscrete(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_HybridDiscrete(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn HybridIntegrated(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_HybridIntegrated(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn IndirectDisplayDevice(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_IndirectDisplayDevice(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Paravirtualized(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Paravirtualized(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn ACGSupported(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_ACGSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn SupportSetTimingsFromVidPn(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_SupportSetTimingsFromVidPn(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Detachable(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_Detachable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn ComputeOnly(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_ComputeOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn Prototype(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_Prototype(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn RuntimePowerManagement(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_RuntimePowerManagement(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 14) & 262143
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(262143 << 14)) | ((value & 262143) << 14);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_ADAPTERTYPE_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -8976,7 +10631,8 @@ impl Default for D3DKMT_ADAPTERTYPE_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ADAPTER_PERFDATA {
     pub PhysicalAdapterIndex: u32,
     pub MemoryFrequency: u64,
@@ -8998,7 +10654,8 @@ impl Default for D3DKMT_ADAPTER_PERFDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ADAPTER_PERFDATACAPS {
     pub PhysicalAdapterIndex: u32,
     pub MaxMemoryBandwidth: u64,
@@ -9059,10 +10716,139 @@ impl Default for D3DKMT_ADAPTER_VERIFIER_VIDMM_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ADAPTER_VERIFIER_VIDMM_FLAGS_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_ADAPTER_VERIFIER_VIDMM_FLAGS_0 {
+    pub const fn AlwaysRepatch(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_AlwaysRepatch(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn FailSharedPrimary(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_FailSharedPrimary(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn FailProbeAndLock(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_FailProbeAndLock(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AlwaysDiscardOffer(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AlwaysDiscardOffer(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn NeverDiscardOffer(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_NeverDiscardOffer(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn ForceComplexLock(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_ForceComplexLock(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn NeverPrepatch(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_NeverPrepatch(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn ExpectPreparationFailure(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_ExpectPreparationFailure(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn TakeSplitPoint(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_TakeSplitPoint(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn FailAcquireSwizzlingRange(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_FailAcquireSwizzlingRange(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn PagingPathLockSubrange(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_PagingPathLockSubrange(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn PagingPathLockMinrange(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_PagingPathLockMinrange(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn FailVaRotation(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_FailVaRotation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn NoDemotion(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_NoDemotion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn FailDefragPass(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_FailDefragPass(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn AlwaysProcessOfferList(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_AlwaysProcessOfferList(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn AlwaysDecommitOffer(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_AlwaysDecommitOffer(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn NeverMoveDefrag(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_NeverMoveDefrag(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn AlwaysRelocateDisplayableResources(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_AlwaysRelocateDisplayableResources(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn AlwaysFailGrowVPRMoves(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_AlwaysFailGrowVPRMoves(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 20) & 4095
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4095 << 20)) | ((value & 4095) << 20);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_ADAPTER_VERIFIER_VIDMM_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -9072,7 +10858,8 @@ impl Default for D3DKMT_ADAPTER_VERIFIER_VIDMM_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ADAPTER_VERIFIER_VIDMM_TRIM_INTERVAL {
     pub MinimumTrimInterval: u64,
     pub MaximumTrimInterval: u64,
@@ -9087,7 +10874,8 @@ impl Default for D3DKMT_ADAPTER_VERIFIER_VIDMM_TRIM_INTERVAL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ADJUSTFULLSCREENGAMMA {
     pub hAdapter: u32,
     pub VidPnSourceId: u32,
@@ -9103,7 +10891,8 @@ impl Default for D3DKMT_ADJUSTFULLSCREENGAMMA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_AUXILIARYPRESENTINFO {
     pub size: u32,
     pub r#type: D3DKMT_AUXILIARYPRESENTINFO_TYPE,
@@ -9117,7 +10906,8 @@ impl Default for D3DKMT_AUXILIARYPRESENTINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_BDDFALLBACK_CTL {
     pub ForceBddHeadlessNextFallback: super::super::super::Win32::Foundation::BOOLEAN,
 }
@@ -9130,7 +10920,8 @@ impl Default for D3DKMT_BDDFALLBACK_CTL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_BLOCKLIST_INFO {
     pub Size: u32,
     pub BlockList: [u16; 1],
@@ -9144,7 +10935,8 @@ impl Default for D3DKMT_BLOCKLIST_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_BLTMODEL_PRESENTHISTORYTOKEN {
     pub hLogicalSurface: u64,
     pub hPhysicalSurface: u64,
@@ -9197,7 +10989,8 @@ impl Default for D3DKMT_BRIGHTNESS_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_BRIGHTNESS_POSSIBLE_LEVELS {
     pub LevelCount: u8,
     pub BrightnessLevels: [u8; 256],
@@ -9211,7 +11004,8 @@ impl Default for D3DKMT_BRIGHTNESS_POSSIBLE_LEVELS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_BUDGETCHANGENOTIFICATION {
     pub Context: *mut core::ffi::c_void,
     pub Budget: u64,
@@ -9271,10 +11065,25 @@ impl Default for D3DKMT_CANCEL_PRESENTS_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CANCEL_PRESENTS_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_CANCEL_PRESENTS_FLAGS_0_0 {
+    pub const fn NewVSyncInterruptState(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_NewVSyncInterruptState(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_CANCEL_PRESENTS_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -9285,7 +11094,8 @@ impl Default for D3DKMT_CANCEL_PRESENTS_FLAGS_0_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CHANGESURFACEPOINTER {
     pub hDC: super::super::super::Win32::Graphics::Gdi::HDC,
     pub hBitmap: super::super::super::Win32::Foundation::HANDLE,
@@ -9305,7 +11115,8 @@ impl Default for D3DKMT_CHANGESURFACEPOINTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CHANGEVIDEOMEMORYRESERVATION {
     pub hProcess: super::super::super::Win32::Foundation::HANDLE,
     pub hAdapter: u32,
@@ -9322,7 +11133,8 @@ impl Default for D3DKMT_CHANGEVIDEOMEMORYRESERVATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CHECKMONITORPOWERSTATE {
     pub hAdapter: u32,
     pub VidPnSourceId: u32,
@@ -9391,7 +11203,8 @@ impl Default for D3DKMT_CHECKMULTIPLANEOVERLAYSUPPORT3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CHECKOCCLUSION {
     pub hWindow: super::super::super::Win32::Foundation::HWND,
 }
@@ -9404,7 +11217,8 @@ impl Default for D3DKMT_CHECKOCCLUSION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CHECKSHAREDRESOURCEACCESS {
     pub hResource: u32,
     pub ClientPid: u32,
@@ -9418,7 +11232,8 @@ impl Default for D3DKMT_CHECKSHAREDRESOURCEACCESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CHECKVIDPNEXCLUSIVEOWNERSHIP {
     pub hAdapter: u32,
     pub VidPnSourceId: u32,
@@ -9432,7 +11247,8 @@ impl Default for D3DKMT_CHECKVIDPNEXCLUSIVEOWNERSHIP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CHECK_MULTIPLANE_OVERLAY_PLANE {
     pub hResource: u32,
     pub CompSurfaceLuid: super::super::super::Win32::Foundation::LUID,
@@ -9448,7 +11264,8 @@ impl Default for D3DKMT_CHECK_MULTIPLANE_OVERLAY_PLANE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CHECK_MULTIPLANE_OVERLAY_PLANE2 {
     pub LayerIndex: u32,
     pub hResource: u32,
@@ -9465,7 +11282,8 @@ impl Default for D3DKMT_CHECK_MULTIPLANE_OVERLAY_PLANE2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CHECK_MULTIPLANE_OVERLAY_PLANE3 {
     pub LayerIndex: u32,
     pub hResource: u32,
@@ -9509,10 +11327,31 @@ impl Default for D3DKMT_CHECK_MULTIPLANE_OVERLAY_SUPPORT_RETURN_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CHECK_MULTIPLANE_OVERLAY_SUPPORT_RETURN_INFO_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_CHECK_MULTIPLANE_OVERLAY_SUPPORT_RETURN_INFO_0_0 {
+    pub const fn FailingPlane(&self) -> u32 {
+        self._bitfield & 15
+    }
+    pub fn set_FailingPlane(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn TryAgain(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_TryAgain(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 5) & 134217727
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(134217727 << 5)) | ((value & 134217727) << 5);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_CHECK_MULTIPLANE_OVERLAY_SUPPORT_RETURN_INFO_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -9522,7 +11361,8 @@ impl Default for D3DKMT_CHECK_MULTIPLANE_OVERLAY_SUPPORT_RETURN_INFO_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CLOSEADAPTER {
     pub hAdapter: u32,
 }
@@ -9535,7 +11375,8 @@ impl Default for D3DKMT_CLOSEADAPTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_COMPOSITION_PRESENTHISTORYTOKEN {
     pub hPrivateData: u64,
 }
@@ -9548,7 +11389,8 @@ impl Default for D3DKMT_COMPOSITION_PRESENTHISTORYTOKEN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CONFIGURESHAREDRESOURCE {
     pub hDevice: u32,
     pub hResource: u32,
@@ -9606,10 +11448,19 @@ impl Default for D3DKMT_CONNECT_DOORBELL_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CONNECT_DOORBELL_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_CONNECT_DOORBELL_FLAGS_0_0 {
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = value;
+    }
+}
 impl windows_core::TypeKind for D3DKMT_CONNECT_DOORBELL_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -9619,7 +11470,8 @@ impl Default for D3DKMT_CONNECT_DOORBELL_FLAGS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CPDRIVERNAME {
     pub ContentProtectionFileName: [u16; 260],
 }
@@ -9683,10 +11535,151 @@ impl Default for D3DKMT_CREATEALLOCATION_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CREATEALLOCATIONFLAGS {
     pub _bitfield: u32,
 }
+impl D3DKMT_CREATEALLOCATIONFLAGS {
+    pub const fn CreateResource(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_CreateResource(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CreateShared(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CreateShared(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn NonSecure(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_NonSecure(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn CreateProtected(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_CreateProtected(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn RestrictSharedAccess(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_RestrictSharedAccess(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn ExistingSysMem(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_ExistingSysMem(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn NtSecuritySharing(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_NtSecuritySharing(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn ReadOnly(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_ReadOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn CreateWriteCombined(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_CreateWriteCombined(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn CreateCached(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_CreateCached(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn SwapChainBackBuffer(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_SwapChainBackBuffer(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn CrossAdapter(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_CrossAdapter(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn OpenCrossAdapter(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_OpenCrossAdapter(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn PartialSharedCreation(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_PartialSharedCreation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn Zeroed(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_Zeroed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn WriteWatch(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_WriteWatch(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn StandardAllocation(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_StandardAllocation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn ExistingSection(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_ExistingSection(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn AllowNotZeroed(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_AllowNotZeroed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn PhysicallyContiguous(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_PhysicallyContiguous(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn NoKmdAccess(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_NoKmdAccess(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn SharedDisplayable(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_SharedDisplayable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 22) & 1023
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1023 << 22)) | ((value & 1023) << 22);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_CREATEALLOCATIONFLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -9744,7 +11737,8 @@ impl Default for D3DKMT_CREATECONTEXTVIRTUAL {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CREATEDCFROMMEMORY {
     pub pMemory: *mut core::ffi::c_void,
     pub Format: D3DDDIFORMAT,
@@ -9802,10 +11796,43 @@ impl Default for D3DKMT_CREATEDEVICE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CREATEDEVICEFLAGS {
     pub _bitfield: u32,
 }
+impl D3DKMT_CREATEDEVICEFLAGS {
+    pub const fn LegacyMode(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_LegacyMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn RequestVSync(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_RequestVSync(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn DisableGpuTimeout(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_DisableGpuTimeout(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn TestDevice(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_TestDevice(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_CREATEDEVICEFLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -9854,7 +11881,8 @@ impl Default for D3DKMT_CREATEHWQUEUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CREATEKEYEDMUTEX {
     pub InitialValue: u64,
     pub hSharedHandle: u32,
@@ -9914,10 +11942,25 @@ impl Default for D3DKMT_CREATEKEYEDMUTEX2_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CREATEKEYEDMUTEX2_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_CREATEKEYEDMUTEX2_FLAGS_0_0 {
+    pub const fn NtSecuritySharing(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_NtSecuritySharing(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_CREATEKEYEDMUTEX2_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -9941,7 +11984,8 @@ impl Default for D3DKMT_CREATENATIVEFENCE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CREATEOVERLAY {
     pub VidPnSourceId: u32,
     pub hDevice: u32,
@@ -9957,7 +12001,8 @@ impl Default for D3DKMT_CREATEOVERLAY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CREATEPAGINGQUEUE {
     pub hDevice: u32,
     pub Priority: D3DDDI_PAGINGQUEUE_PRIORITY,
@@ -9975,7 +12020,8 @@ impl Default for D3DKMT_CREATEPAGINGQUEUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CREATEPROTECTEDSESSION {
     pub hDevice: u32,
     pub hSyncObject: u32,
@@ -10049,10 +12095,19 @@ impl Default for D3DKMT_CREATESTANDARDALLOCATIONFLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CREATESTANDARDALLOCATIONFLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_CREATESTANDARDALLOCATIONFLAGS_0_0 {
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = value;
+    }
+}
 impl windows_core::TypeKind for D3DKMT_CREATESTANDARDALLOCATIONFLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -10062,7 +12117,8 @@ impl Default for D3DKMT_CREATESTANDARDALLOCATIONFLAGS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CREATESYNCFILE {
     pub hDevice: u32,
     pub hMonitoredFence: u32,
@@ -10156,10 +12212,31 @@ impl Default for D3DKMT_CREATE_DOORBELL_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CREATE_DOORBELL_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_CREATE_DOORBELL_FLAGS_0_0 {
+    pub const fn RequireSecondaryCpuVA(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_RequireSecondaryCpuVA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ResizeRingBufferOperation(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ResizeRingBufferOperation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_CREATE_DOORBELL_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -10187,7 +12264,8 @@ impl Default for D3DKMT_CREATE_OUTPUTDUPL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CROSSADAPTERRESOURCE_SUPPORT {
     pub SupportTier: D3DKMT_CROSSADAPTERRESOURCE_SUPPORT_TIER,
 }
@@ -10200,7 +12278,8 @@ impl Default for D3DKMT_CROSSADAPTERRESOURCE_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_CURRENTDISPLAYMODE {
     pub VidPnSourceId: u32,
     pub DisplayMode: D3DKMT_DISPLAYMODE,
@@ -10214,7 +12293,8 @@ impl Default for D3DKMT_CURRENTDISPLAYMODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DEBUG_SNAPSHOT_ESCAPE {
     pub Length: u32,
     pub Buffer: [u8; 1],
@@ -10228,7 +12308,8 @@ impl Default for D3DKMT_DEBUG_SNAPSHOT_ESCAPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DESTROYALLOCATION {
     pub hDevice: u32,
     pub hResource: u32,
@@ -10261,7 +12342,8 @@ impl Default for D3DKMT_DESTROYALLOCATION2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DESTROYCONTEXT {
     pub hContext: u32,
 }
@@ -10275,7 +12357,8 @@ impl Default for D3DKMT_DESTROYCONTEXT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DESTROYDCFROMMEMORY {
     pub hDc: super::super::super::Win32::Graphics::Gdi::HDC,
     pub hBitmap: super::super::super::Win32::Foundation::HANDLE,
@@ -10291,7 +12374,8 @@ impl Default for D3DKMT_DESTROYDCFROMMEMORY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DESTROYDEVICE {
     pub hDevice: u32,
 }
@@ -10304,7 +12388,8 @@ impl Default for D3DKMT_DESTROYDEVICE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DESTROYHWCONTEXT {
     pub hHwContext: u32,
 }
@@ -10317,7 +12402,8 @@ impl Default for D3DKMT_DESTROYHWCONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DESTROYHWQUEUE {
     pub hHwQueue: u32,
 }
@@ -10330,7 +12416,8 @@ impl Default for D3DKMT_DESTROYHWQUEUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DESTROYKEYEDMUTEX {
     pub hKeyedMutex: u32,
 }
@@ -10343,7 +12430,8 @@ impl Default for D3DKMT_DESTROYKEYEDMUTEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DESTROYOVERLAY {
     pub hDevice: u32,
     pub hOverlay: u32,
@@ -10357,7 +12445,8 @@ impl Default for D3DKMT_DESTROYOVERLAY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DESTROYPROTECTEDSESSION {
     pub hHandle: u32,
 }
@@ -10370,7 +12459,8 @@ impl Default for D3DKMT_DESTROYPROTECTEDSESSION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DESTROYSYNCHRONIZATIONOBJECT {
     pub hSyncObject: u32,
 }
@@ -10383,7 +12473,8 @@ impl Default for D3DKMT_DESTROYSYNCHRONIZATIONOBJECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DESTROY_DOORBELL {
     pub hHwQueue: u32,
 }
@@ -10396,7 +12487,8 @@ impl Default for D3DKMT_DESTROY_DOORBELL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DESTROY_OUTPUTDUPL {
     pub hAdapter: u32,
     pub VidPnSourceId: u32,
@@ -10429,7 +12521,8 @@ impl Default for D3DKMT_DEVICEPAGEFAULT_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DEVICEPRESENT_QUEUE_STATE {
     pub VidPnSourceId: u32,
     pub bQueuedPresentLimitReached: super::super::super::Win32::Foundation::BOOLEAN,
@@ -10443,7 +12536,8 @@ impl Default for D3DKMT_DEVICEPRESENT_QUEUE_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DEVICEPRESENT_STATE {
     pub VidPnSourceId: u32,
     pub PresentStats: D3DKMT_PRESENT_STATS,
@@ -10457,7 +12551,8 @@ impl Default for D3DKMT_DEVICEPRESENT_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DEVICEPRESENT_STATE_DWM {
     pub VidPnSourceId: u32,
     pub PresentStatsDWM: D3DKMT_PRESENT_STATS_DWM,
@@ -10498,10 +12593,25 @@ impl Default for D3DKMT_DEVICERESET_STATE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DEVICERESET_STATE_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_DEVICERESET_STATE_0_0 {
+    pub const fn DesktopSwitched(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_DesktopSwitched(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_DEVICERESET_STATE_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -10538,7 +12648,8 @@ impl Default for D3DKMT_DEVICE_ESCAPE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DEVICE_ESCAPE_0_0 {
     pub hPrimaryAllocation: u32,
     pub VidPnSourceId: u32,
@@ -10552,7 +12663,8 @@ impl Default for D3DKMT_DEVICE_ESCAPE_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DEVICE_IDS {
     pub VendorID: u32,
     pub DeviceID: u32,
@@ -10570,7 +12682,8 @@ impl Default for D3DKMT_DEVICE_IDS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DIRECTFLIP_SUPPORT {
     pub Supported: super::super::super::Win32::Foundation::BOOL,
 }
@@ -10583,7 +12696,8 @@ impl Default for D3DKMT_DIRECTFLIP_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DIRTYREGIONS {
     pub NumRects: u32,
     pub Rects: [super::super::super::Win32::Foundation::RECT; 16],
@@ -10597,7 +12711,8 @@ impl Default for D3DKMT_DIRTYREGIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DISPLAYMODE {
     pub Width: u32,
     pub Height: u32,
@@ -10618,7 +12733,8 @@ impl Default for D3DKMT_DISPLAYMODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DISPLAYMODELIST {
     pub VidPnSourceId: u32,
     pub ModeCount: u32,
@@ -10664,6 +12780,20 @@ impl Default for D3DKMT_DISPLAY_CAPS_0 {
 pub struct D3DKMT_DISPLAY_CAPS_0_0 {
     pub _bitfield: u64,
 }
+impl D3DKMT_DISPLAY_CAPS_0_0 {
+    pub const fn PreferPhysicallyContiguous(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_PreferPhysicallyContiguous(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 1) & 9223372036854775807
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(9223372036854775807 << 1)) | ((value & 9223372036854775807) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_DISPLAY_CAPS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -10673,7 +12803,8 @@ impl Default for D3DKMT_DISPLAY_CAPS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DISPLAY_UMD_FILENAMEINFO {
     pub Version: KMT_DISPLAY_UMD_VERSION,
     pub UmdFileName: [u16; 260],
@@ -10687,7 +12818,8 @@ impl Default for D3DKMT_DISPLAY_UMD_FILENAMEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DLIST_DRIVER_NAME {
     pub DListFileName: [u16; 260],
 }
@@ -10700,7 +12832,8 @@ impl Default for D3DKMT_DLIST_DRIVER_NAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DMM_ESCAPE {
     pub Type: D3DKMT_DMMESCAPETYPE,
     pub ProvidedBufferSize: usize,
@@ -10716,7 +12849,8 @@ impl Default for D3DKMT_DMM_ESCAPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DOD_SET_DIRTYRECT_MODE {
     pub bForceFullScreenDirty: super::super::super::Win32::Foundation::BOOL,
 }
@@ -10760,6 +12894,26 @@ impl Default for D3DKMT_DRIVERCAPS_EXT_0 {
 pub struct D3DKMT_DRIVERCAPS_EXT_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_DRIVERCAPS_EXT_0_0 {
+    pub const fn VirtualModeSupport(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_VirtualModeSupport(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Usb4MonitorSupport(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Usb4MonitorSupport(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_DRIVERCAPS_EXT_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -10769,7 +12923,8 @@ impl Default for D3DKMT_DRIVERCAPS_EXT_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_DRIVER_DESCRIPTION {
     pub DriverDescription: [u16; 4096],
 }
@@ -10782,7 +12937,8 @@ impl Default for D3DKMT_DRIVER_DESCRIPTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ENUMADAPTERS {
     pub NumAdapters: u32,
     pub Adapters: [D3DKMT_ADAPTERINFO; 16],
@@ -10796,7 +12952,8 @@ impl Default for D3DKMT_ENUMADAPTERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ENUMADAPTERS2 {
     pub NumAdapters: u32,
     pub pAdapters: *mut D3DKMT_ADAPTERINFO,
@@ -10839,10 +12996,31 @@ impl Default for D3DKMT_ENUMADAPTERS_FILTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ENUMADAPTERS_FILTER_0 {
     pub _bitfield: u64,
 }
+impl D3DKMT_ENUMADAPTERS_FILTER_0 {
+    pub const fn IncludeComputeOnly(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_IncludeComputeOnly(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn IncludeDisplayOnly(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_IncludeDisplayOnly(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 2) & 4611686018427387903
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(4611686018427387903 << 2)) | ((value & 4611686018427387903) << 2);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_ENUMADAPTERS_FILTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -10871,7 +13049,8 @@ impl Default for D3DKMT_ESCAPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ESCAPE_VIRTUAL_REFRESH_RATE {
     pub Type: D3DKMT_ESCAPE_VIRTUAL_REFRESH_RATE_TYPE,
     pub VidPnSourceId: u32,
@@ -10948,10 +13127,25 @@ impl Default for D3DKMT_EVICTION_CRITERIA_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_EVICTION_CRITERIA_0_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_EVICTION_CRITERIA_0_0_0 {
+    pub const fn Primary(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Primary(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_EVICTION_CRITERIA_0_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -10961,7 +13155,8 @@ impl Default for D3DKMT_EVICTION_CRITERIA_0_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_FENCE_PRESENTHISTORYTOKEN {
     pub Key: u64,
 }
@@ -10974,10 +13169,25 @@ impl Default for D3DKMT_FENCE_PRESENTHISTORYTOKEN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_FLIPINFOFLAGS {
     pub _bitfield: u32,
 }
+impl D3DKMT_FLIPINFOFLAGS {
+    pub const fn FlipInterval(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_FlipInterval(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_FLIPINFOFLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -10987,7 +13197,8 @@ impl Default for D3DKMT_FLIPINFOFLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_FLIPMANAGER_AUXILIARYPRESENTINFO {
     pub auxiliaryPresentInfo: D3DKMT_AUXILIARYPRESENTINFO,
     pub flipManagerTracingId: u32,
@@ -11038,10 +13249,37 @@ impl Default for D3DKMT_FLIPMANAGER_PRESENTHISTORYTOKEN_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_FLIPMANAGER_PRESENTHISTORYTOKEN_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_FLIPMANAGER_PRESENTHISTORYTOKEN_0_0 {
+    pub const fn Discard(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Discard(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PresentAt(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PresentAt(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn hPrivateDataIsPointer(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_hPrivateDataIsPointer(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_FLIPMANAGER_PRESENTHISTORYTOKEN_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -11163,10 +13401,163 @@ impl Default for D3DKMT_FLIPMODEL_PRESENTHISTORYTOKENFLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_FLIPMODEL_PRESENTHISTORYTOKENFLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_FLIPMODEL_PRESENTHISTORYTOKENFLAGS_0_0 {
+    pub const fn Video(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Video(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn RestrictedContent(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_RestrictedContent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ClipToView(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ClipToView(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn StereoPreferRight(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_StereoPreferRight(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn TemporaryMono(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_TemporaryMono(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn FlipRestart(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_FlipRestart(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn HDRMetaDataChanged(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_HDRMetaDataChanged(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn AlphaMode(&self) -> u32 {
+        (self._bitfield >> 7) & 3
+    }
+    pub fn set_AlphaMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 7)) | ((value & 3) << 7);
+    }
+    pub const fn SignalLimitOnTokenCompletion(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_SignalLimitOnTokenCompletion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn YCbCrFlags(&self) -> u32 {
+        (self._bitfield >> 10) & 7
+    }
+    pub fn set_YCbCrFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 10)) | ((value & 7) << 10);
+    }
+    pub const fn IndependentFlip(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_IndependentFlip(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn IndependentFlipStage(&self) -> u32 {
+        (self._bitfield >> 14) & 3
+    }
+    pub fn set_IndependentFlipStage(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 14)) | ((value & 3) << 14);
+    }
+    pub const fn IndependentFlipReleaseCount(&self) -> u32 {
+        (self._bitfield >> 16) & 3
+    }
+    pub fn set_IndependentFlipReleaseCount(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 16)) | ((value & 3) << 16);
+    }
+    pub const fn IndependentFlipForceNotifyDwm(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_IndependentFlipForceNotifyDwm(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn UseCustomDuration(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_UseCustomDuration(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn IndependentFlipRequestDwmConfirm(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_IndependentFlipRequestDwmConfirm(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn IndependentFlipCandidate(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_IndependentFlipCandidate(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn IndependentFlipCheckNeeded(&self) -> u32 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_IndependentFlipCheckNeeded(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn IndependentFlipTrueImmediate(&self) -> u32 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_IndependentFlipTrueImmediate(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn IndependentFlipRequestDwmExit(&self) -> u32 {
+        (self._bitfield >> 24) & 1
+    }
+    pub fn set_IndependentFlipRequestDwmExit(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 24)) | ((value & 1) << 24);
+    }
+    pub const fn CompSurfaceNotifiedEarly(&self) -> u32 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_CompSurfaceNotifiedEarly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn IndependentFlipDoNotFlip(&self) -> u32 {
+        (self._bitfield >> 26) & 1
+    }
+    pub fn set_IndependentFlipDoNotFlip(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 26)) | ((value & 1) << 26);
+    }
+    pub const fn RequirePairedToken(&self) -> u32 {
+        (self._bitfield >> 27) & 1
+    }
+    pub fn set_RequirePairedToken(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 27)) | ((value & 1) << 27);
+    }
+    pub const fn VariableRefreshOverrideEligible(&self) -> u32 {
+        (self._bitfield >> 28) & 1
+    }
+    pub fn set_VariableRefreshOverrideEligible(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 28)) | ((value & 1) << 28);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 29) & 7
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 29)) | ((value & 7) << 29);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_FLIPMODEL_PRESENTHISTORYTOKENFLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -11176,7 +13567,8 @@ impl Default for D3DKMT_FLIPMODEL_PRESENTHISTORYTOKENFLAGS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_FLIPOVERLAY {
     pub hDevice: u32,
     pub hOverlay: u32,
@@ -11193,7 +13585,8 @@ impl Default for D3DKMT_FLIPOVERLAY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_FLIPQUEUEINFO {
     pub MaxHardwareFlipQueueLength: u32,
     pub MaxSoftwareFlipQueueLength: u32,
@@ -11208,7 +13601,8 @@ impl Default for D3DKMT_FLIPQUEUEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_FLUSHHEAPTRANSITIONS {
     pub hAdapter: u32,
 }
@@ -11221,7 +13615,8 @@ impl Default for D3DKMT_FLUSHHEAPTRANSITIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_FREEGPUVIRTUALADDRESS {
     pub hAdapter: u32,
     pub BaseAddress: u64,
@@ -11236,7 +13631,8 @@ impl Default for D3DKMT_FREEGPUVIRTUALADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GDIMODEL_PRESENTHISTORYTOKEN {
     pub hLogicalSurface: u64,
     pub hPhysicalSurface: u64,
@@ -11253,7 +13649,8 @@ impl Default for D3DKMT_GDIMODEL_PRESENTHISTORYTOKEN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GDIMODEL_SYSMEM_PRESENTHISTORYTOKEN {
     pub hlsurf: u64,
     pub dwDirtyFlags: u32,
@@ -11268,7 +13665,8 @@ impl Default for D3DKMT_GDIMODEL_SYSMEM_PRESENTHISTORYTOKEN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GETALLOCATIONPRIORITY {
     pub hDevice: u32,
     pub hResource: u32,
@@ -11285,7 +13683,8 @@ impl Default for D3DKMT_GETALLOCATIONPRIORITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GETCONTEXTINPROCESSSCHEDULINGPRIORITY {
     pub hContext: u32,
     pub Priority: i32,
@@ -11299,7 +13698,8 @@ impl Default for D3DKMT_GETCONTEXTINPROCESSSCHEDULINGPRIORITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GETCONTEXTSCHEDULINGPRIORITY {
     pub hContext: u32,
     pub Priority: i32,
@@ -11346,7 +13746,8 @@ impl Default for D3DKMT_GETDEVICESTATE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GETDISPLAYMODELIST {
     pub hAdapter: u32,
     pub VidPnSourceId: u32,
@@ -11362,7 +13763,8 @@ impl Default for D3DKMT_GETDISPLAYMODELIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GETMULTISAMPLEMETHODLIST {
     pub hAdapter: u32,
     pub VidPnSourceId: u32,
@@ -11381,7 +13783,8 @@ impl Default for D3DKMT_GETMULTISAMPLEMETHODLIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GETOVERLAYSTATE {
     pub hDevice: u32,
     pub hOverlay: u32,
@@ -11396,7 +13799,8 @@ impl Default for D3DKMT_GETOVERLAYSTATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GETPRESENTHISTORY {
     pub hAdapter: u32,
     pub ProvidedSize: u32,
@@ -11413,7 +13817,8 @@ impl Default for D3DKMT_GETPRESENTHISTORY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GETPROCESSDEVICEREMOVALSUPPORT {
     pub hProcess: super::super::super::Win32::Foundation::HANDLE,
     pub AdapterLuid: super::super::super::Win32::Foundation::LUID,
@@ -11428,7 +13833,8 @@ impl Default for D3DKMT_GETPROCESSDEVICEREMOVALSUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GETRUNTIMEDATA {
     pub hAdapter: u32,
     pub hGlobalShare: u32,
@@ -11444,7 +13850,8 @@ impl Default for D3DKMT_GETRUNTIMEDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GETSCANLINE {
     pub hAdapter: u32,
     pub VidPnSourceId: u32,
@@ -11460,7 +13867,8 @@ impl Default for D3DKMT_GETSCANLINE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GETSHAREDPRIMARYHANDLE {
     pub hAdapter: u32,
     pub VidPnSourceId: u32,
@@ -11475,7 +13883,8 @@ impl Default for D3DKMT_GETSHAREDPRIMARYHANDLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GETSHAREDRESOURCEADAPTERLUID {
     pub hGlobalShare: u32,
     pub hNtHandle: super::super::super::Win32::Foundation::HANDLE,
@@ -11490,7 +13899,8 @@ impl Default for D3DKMT_GETSHAREDRESOURCEADAPTERLUID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GETVERTICALBLANKEVENT {
     pub hAdapter: u32,
     pub hDevice: u32,
@@ -11506,7 +13916,8 @@ impl Default for D3DKMT_GETVERTICALBLANKEVENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GET_DEVICE_VIDPN_OWNERSHIP_INFO {
     pub hDevice: u32,
     pub bFailedDwmAcquireVidPn: super::super::super::Win32::Foundation::BOOLEAN,
@@ -11520,7 +13931,8 @@ impl Default for D3DKMT_GET_DEVICE_VIDPN_OWNERSHIP_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GET_GPUMMU_CAPS {
     pub PhysicalAdapterIndex: u32,
     pub GpuMmuCaps: DXGK_ESCAPE_GPUMMUCAPS,
@@ -11554,7 +13966,8 @@ impl Default for D3DKMT_GET_MULTIPLANE_OVERLAY_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GET_POST_COMPOSITION_CAPS {
     pub hAdapter: u32,
     pub VidPnSourceId: u32,
@@ -11589,7 +14002,8 @@ impl Default for D3DKMT_GET_PTE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GET_SEGMENT_CAPS {
     pub PhysicalAdapterIndex: u32,
     pub NumSegments: u32,
@@ -11632,10 +14046,37 @@ impl Default for D3DKMT_GPUMMU_CAPS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GPUMMU_CAPS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_GPUMMU_CAPS_0_0 {
+    pub const fn ReadOnlyMemorySupported(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ReadOnlyMemorySupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NoExecuteMemorySupported(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NoExecuteMemorySupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn CacheCoherentMemorySupported(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CacheCoherentMemorySupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_GPUMMU_CAPS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -11645,7 +14086,8 @@ impl Default for D3DKMT_GPUMMU_CAPS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_GPUVERSION {
     pub PhysicalAdapterIndex: u32,
     pub BiosVersion: [u16; 32],
@@ -11660,7 +14102,8 @@ impl Default for D3DKMT_GPUVERSION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_HISTORY_BUFFER_STATUS {
     pub Enabled: super::super::super::Win32::Foundation::BOOLEAN,
     pub Reserved: u32,
@@ -11674,7 +14117,8 @@ impl Default for D3DKMT_HISTORY_BUFFER_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_HWDRM_SUPPORT {
     pub Supported: super::super::super::Win32::Foundation::BOOLEAN,
 }
@@ -11687,7 +14131,8 @@ impl Default for D3DKMT_HWDRM_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_HYBRID_DLIST_DLL_SUPPORT {
     pub Supported: super::super::super::Win32::Foundation::BOOL,
 }
@@ -11700,7 +14145,8 @@ impl Default for D3DKMT_HYBRID_DLIST_DLL_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_HYBRID_LIST {
     pub State: D3DKMT_GPU_PREFERENCE_QUERY_STATE,
     pub AdapterLuid: super::super::super::Win32::Foundation::LUID,
@@ -11716,7 +14162,8 @@ impl Default for D3DKMT_HYBRID_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_INDEPENDENTFLIP_SECONDARY_SUPPORT {
     pub Supported: super::super::super::Win32::Foundation::BOOL,
 }
@@ -11729,7 +14176,8 @@ impl Default for D3DKMT_INDEPENDENTFLIP_SECONDARY_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_INDEPENDENTFLIP_SUPPORT {
     pub Supported: super::super::super::Win32::Foundation::BOOL,
 }
@@ -11742,7 +14190,8 @@ impl Default for D3DKMT_INDEPENDENTFLIP_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_INVALIDATEACTIVEVIDPN {
     pub hAdapter: u32,
     pub pPrivateDriverData: *mut core::ffi::c_void,
@@ -11757,7 +14206,8 @@ impl Default for D3DKMT_INVALIDATEACTIVEVIDPN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_INVALIDATECACHE {
     pub hDevice: u32,
     pub hAllocation: u32,
@@ -11773,7 +14223,8 @@ impl Default for D3DKMT_INVALIDATECACHE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_ISBADDRIVERFORHWPROTECTIONDISABLED {
     pub Disabled: super::super::super::Win32::Foundation::BOOL,
 }
@@ -11786,7 +14237,8 @@ impl Default for D3DKMT_ISBADDRIVERFORHWPROTECTIONDISABLED {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_KMD_DRIVER_VERSION {
     pub DriverVersion: i64,
 }
@@ -11835,7 +14287,8 @@ impl Default for D3DKMT_LOCK2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MARKDEVICEASERROR {
     pub hDevice: u32,
     pub Reason: D3DKMT_DEVICE_ERROR_REASON,
@@ -11849,7 +14302,8 @@ impl Default for D3DKMT_MARKDEVICEASERROR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MIRACASTCOMPANIONDRIVERNAME {
     pub MiracastCompanionDriverName: [u16; 260],
 }
@@ -11877,7 +14331,8 @@ impl Default for D3DKMT_MIRACAST_CHUNK_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MIRACAST_DISPLAY_DEVICE_CAPS {
     pub HdcpSupported: super::super::super::Win32::Foundation::BOOLEAN,
     pub DefaultControlPort: u32,
@@ -11892,7 +14347,8 @@ impl Default for D3DKMT_MIRACAST_DISPLAY_DEVICE_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MIRACAST_DISPLAY_DEVICE_STATUS {
     pub State: D3DKMT_MIRACAST_DISPLAY_DEVICE_STATE,
 }
@@ -11905,7 +14361,8 @@ impl Default for D3DKMT_MIRACAST_DISPLAY_DEVICE_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MIRACAST_DISPLAY_STOP_SESSIONS {
     pub AdapterLuid: super::super::super::Win32::Foundation::LUID,
     pub TargetId: u32,
@@ -11920,7 +14377,8 @@ impl Default for D3DKMT_MIRACAST_DISPLAY_STOP_SESSIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MOVE_RECT {
     pub SourcePoint: super::super::super::Win32::Foundation::POINT,
     pub DestRect: super::super::super::Win32::Foundation::RECT,
@@ -11934,7 +14392,8 @@ impl Default for D3DKMT_MOVE_RECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MPO3DDI_SUPPORT {
     pub Supported: super::super::super::Win32::Foundation::BOOL,
 }
@@ -11947,7 +14406,8 @@ impl Default for D3DKMT_MPO3DDI_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MPOKERNELCAPS_SUPPORT {
     pub Supported: super::super::super::Win32::Foundation::BOOL,
 }
@@ -11960,7 +14420,8 @@ impl Default for D3DKMT_MPOKERNELCAPS_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MULTIPLANEOVERLAY_DECODE_SUPPORT {
     pub Supported: super::super::super::Win32::Foundation::BOOL,
 }
@@ -11973,7 +14434,8 @@ impl Default for D3DKMT_MULTIPLANEOVERLAY_DECODE_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MULTIPLANEOVERLAY_HUD_SUPPORT {
     pub VidPnSourceId: u32,
     pub Update: super::super::super::Win32::Foundation::BOOL,
@@ -11989,7 +14451,8 @@ impl Default for D3DKMT_MULTIPLANEOVERLAY_HUD_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MULTIPLANEOVERLAY_SECONDARY_SUPPORT {
     pub Supported: super::super::super::Win32::Foundation::BOOL,
 }
@@ -12002,7 +14465,8 @@ impl Default for D3DKMT_MULTIPLANEOVERLAY_SECONDARY_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MULTIPLANEOVERLAY_STRETCH_SUPPORT {
     pub VidPnSourceId: u32,
     pub Update: super::super::super::Win32::Foundation::BOOL,
@@ -12017,7 +14481,8 @@ impl Default for D3DKMT_MULTIPLANEOVERLAY_STRETCH_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MULTIPLANEOVERLAY_SUPPORT {
     pub Supported: super::super::super::Win32::Foundation::BOOL,
 }
@@ -12030,7 +14495,8 @@ impl Default for D3DKMT_MULTIPLANEOVERLAY_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MULTIPLANE_OVERLAY {
     pub LayerIndex: u32,
     pub Enabled: super::super::super::Win32::Foundation::BOOL,
@@ -12046,7 +14512,8 @@ impl Default for D3DKMT_MULTIPLANE_OVERLAY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MULTIPLANE_OVERLAY2 {
     pub LayerIndex: u32,
     pub Enabled: super::super::super::Win32::Foundation::BOOL,
@@ -12087,7 +14554,8 @@ impl Default for D3DKMT_MULTIPLANE_OVERLAY3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MULTIPLANE_OVERLAY_ATTRIBUTES {
     pub Flags: u32,
     pub SrcRect: super::super::super::Win32::Foundation::RECT,
@@ -12114,7 +14582,8 @@ impl Default for D3DKMT_MULTIPLANE_OVERLAY_ATTRIBUTES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MULTIPLANE_OVERLAY_ATTRIBUTES2 {
     pub Flags: u32,
     pub SrcRect: super::super::super::Win32::Foundation::RECT,
@@ -12142,7 +14611,8 @@ impl Default for D3DKMT_MULTIPLANE_OVERLAY_ATTRIBUTES2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MULTIPLANE_OVERLAY_ATTRIBUTES3 {
     pub Flags: u32,
     pub SrcRect: super::super::super::Win32::Foundation::RECT,
@@ -12192,10 +14662,91 @@ impl Default for D3DKMT_MULTIPLANE_OVERLAY_CAPS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MULTIPLANE_OVERLAY_CAPS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_MULTIPLANE_OVERLAY_CAPS_0_0 {
+    pub const fn Rotation(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Rotation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn RotationWithoutIndependentFlip(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_RotationWithoutIndependentFlip(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn VerticalFlip(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_VerticalFlip(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn HorizontalFlip(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_HorizontalFlip(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn StretchRGB(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_StretchRGB(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn StretchYUV(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_StretchYUV(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn BilinearFilter(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_BilinearFilter(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn HighFilter(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_HighFilter(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Shared(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_Shared(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Immediate(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_Immediate(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Plane0ForVirtualModeOnly(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_Plane0ForVirtualModeOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Version3DDISupport(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_Version3DDISupport(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 12) & 1048575
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1048575 << 12)) | ((value & 1048575) << 12);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_MULTIPLANE_OVERLAY_CAPS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -12248,10 +14799,31 @@ impl Default for D3DKMT_MULTIPLANE_OVERLAY_POST_COMPOSITION_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MULTIPLANE_OVERLAY_POST_COMPOSITION_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_MULTIPLANE_OVERLAY_POST_COMPOSITION_FLAGS_0_0 {
+    pub const fn VerticalFlip(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_VerticalFlip(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn HorizontalFlip(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_HorizontalFlip(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_MULTIPLANE_OVERLAY_POST_COMPOSITION_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -12275,7 +14847,8 @@ impl Default for D3DKMT_MULTIPLANE_OVERLAY_POST_COMPOSITION_WITH_SOURCE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_MULTISAMPLEMETHOD {
     pub NumSamples: u32,
     pub NumQualityLevels: u32,
@@ -12304,7 +14877,8 @@ impl Default for D3DKMT_NODEMETADATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_NODE_PERFDATA {
     pub NodeOrdinal: u32,
     pub PhysicalAdapterIndex: u32,
@@ -12366,10 +14940,19 @@ impl Default for D3DKMT_NOTIFY_WORK_SUBMISSION_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_NOTIFY_WORK_SUBMISSION_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_NOTIFY_WORK_SUBMISSION_FLAGS_0_0 {
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = value;
+    }
+}
 impl windows_core::TypeKind for D3DKMT_NOTIFY_WORK_SUBMISSION_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -12424,10 +15007,31 @@ impl Default for D3DKMT_OFFER_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OFFER_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_OFFER_FLAGS_0_0 {
+    pub const fn OfferImmediately(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_OfferImmediately(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn AllowDecommit(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_AllowDecommit(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_OFFER_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -12437,7 +15041,8 @@ impl Default for D3DKMT_OFFER_FLAGS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OPENADAPTERFROMDEVICENAME {
     pub pDeviceName: windows_core::PCWSTR,
     pub hAdapter: u32,
@@ -12452,7 +15057,8 @@ impl Default for D3DKMT_OPENADAPTERFROMDEVICENAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OPENADAPTERFROMGDIDISPLAYNAME {
     pub DeviceName: [u16; 32],
     pub hAdapter: u32,
@@ -12469,7 +15075,8 @@ impl Default for D3DKMT_OPENADAPTERFROMGDIDISPLAYNAME {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OPENADAPTERFROMHDC {
     pub hDc: super::super::super::Win32::Graphics::Gdi::HDC,
     pub hAdapter: u32,
@@ -12487,7 +15094,8 @@ impl Default for D3DKMT_OPENADAPTERFROMHDC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OPENADAPTERFROMLUID {
     pub AdapterLuid: super::super::super::Win32::Foundation::LUID,
     pub hAdapter: u32,
@@ -12501,7 +15109,8 @@ impl Default for D3DKMT_OPENADAPTERFROMLUID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OPENGLINFO {
     pub UmdOpenGlIcdFileName: [u16; 260],
     pub Version: u32,
@@ -12516,7 +15125,8 @@ impl Default for D3DKMT_OPENGLINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OPENKEYEDMUTEX {
     pub hSharedHandle: u32,
     pub hKeyedMutex: u32,
@@ -12530,7 +15140,8 @@ impl Default for D3DKMT_OPENKEYEDMUTEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OPENKEYEDMUTEX2 {
     pub hSharedHandle: u32,
     pub hKeyedMutex: u32,
@@ -12546,7 +15157,8 @@ impl Default for D3DKMT_OPENKEYEDMUTEX2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OPENKEYEDMUTEXFROMNTHANDLE {
     pub hNtHandle: super::super::super::Win32::Foundation::HANDLE,
     pub hKeyedMutex: u32,
@@ -12581,7 +15193,8 @@ impl Default for D3DKMT_OPENNATIVEFENCEFROMNTHANDLE {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OPENNTHANDLEFROMNAME {
     pub dwDesiredAccess: u32,
     pub pObjAttrib: *mut super::super::Foundation::OBJECT_ATTRIBUTES,
@@ -12598,7 +15211,8 @@ impl Default for D3DKMT_OPENNTHANDLEFROMNAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OPENPROTECTEDSESSIONFROMNTHANDLE {
     pub hNtHandle: super::super::super::Win32::Foundation::HANDLE,
     pub hHandle: u32,
@@ -12649,7 +15263,8 @@ impl Default for D3DKMT_OPENRESOURCE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OPENRESOURCEFROMNTHANDLE {
     pub hDevice: u32,
     pub hNtHandle: super::super::super::Win32::Foundation::HANDLE,
@@ -12676,7 +15291,8 @@ impl Default for D3DKMT_OPENRESOURCEFROMNTHANDLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OPENSYNCHRONIZATIONOBJECT {
     pub hSharedHandle: u32,
     pub hSyncObject: u32,
@@ -12691,7 +15307,8 @@ impl Default for D3DKMT_OPENSYNCHRONIZATIONOBJECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OPENSYNCOBJECTFROMNTHANDLE {
     pub hNtHandle: super::super::super::Win32::Foundation::HANDLE,
     pub hSyncObject: u32,
@@ -12736,7 +15353,8 @@ impl Default for D3DKMT_OPENSYNCOBJECTFROMNTHANDLE2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OPENSYNCOBJECTFROMNTHANDLE2_0_0 {
     pub FenceValueCPUVirtualAddress: *mut core::ffi::c_void,
     pub FenceValueGPUVirtualAddress: u64,
@@ -12752,7 +15370,8 @@ impl Default for D3DKMT_OPENSYNCOBJECTFROMNTHANDLE2_0_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OPENSYNCOBJECTNTHANDLEFROMNAME {
     pub dwDesiredAccess: u32,
     pub pObjAttrib: *mut super::super::Foundation::OBJECT_ATTRIBUTES,
@@ -12769,7 +15388,8 @@ impl Default for D3DKMT_OPENSYNCOBJECTNTHANDLEFROMNAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OUTDUPL_POINTER_SHAPE_INFO {
     pub Type: D3DKMT_OUTDUPL_POINTER_SHAPE_TYPE,
     pub Width: u32,
@@ -12786,7 +15406,8 @@ impl Default for D3DKMT_OUTDUPL_POINTER_SHAPE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OUTPUTDUPLCONTEXTSCOUNT {
     pub VidPnSourceId: u32,
     pub OutputDuplicationCount: u32,
@@ -12827,10 +15448,25 @@ impl Default for D3DKMT_OUTPUTDUPLCREATIONFLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OUTPUTDUPLCREATIONFLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_OUTPUTDUPLCREATIONFLAGS_0_0 {
+    pub const fn CompositionUiCaptureOnly(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_CompositionUiCaptureOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_OUTPUTDUPLCREATIONFLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -12885,11 +15521,44 @@ impl Default for D3DKMT_OUTPUTDUPLPRESENTFLAGS_0 {
     fn default() -> Self {
         unsafe { core::mem::zeroed() }
     }
-}
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct D3DKMT_OUTPUTDUPLPRESENTFLAGS_0_0 {
-    pub _bitfield: u32,
+}
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct D3DKMT_OUTPUTDUPLPRESENTFLAGS_0_0 {
+    pub _bitfield: u32,
+}
+impl D3DKMT_OUTPUTDUPLPRESENTFLAGS_0_0 {
+    pub const fn ProtectedContentBlankedOut(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ProtectedContentBlankedOut(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn RemoteSession(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_RemoteSession(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn FullScreenPresent(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_FullScreenPresent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn PresentIndirect(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_PresentIndirect(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
 }
 impl windows_core::TypeKind for D3DKMT_OUTPUTDUPLPRESENTFLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
@@ -12919,7 +15588,8 @@ impl Default for D3DKMT_OUTPUTDUPLPRESENTTOHWQUEUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OUTPUTDUPL_FRAMEINFO {
     pub LastPresentTime: i64,
     pub LastMouseUpdateTime: i64,
@@ -12939,7 +15609,8 @@ impl Default for D3DKMT_OUTPUTDUPL_FRAMEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OUTPUTDUPL_GET_FRAMEINFO {
     pub hAdapter: u32,
     pub VidPnSourceId: u32,
@@ -12954,7 +15625,8 @@ impl Default for D3DKMT_OUTPUTDUPL_GET_FRAMEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OUTPUTDUPL_GET_POINTER_SHAPE_DATA {
     pub hAdapter: u32,
     pub VidPnSourceId: u32,
@@ -12972,7 +15644,8 @@ impl Default for D3DKMT_OUTPUTDUPL_GET_POINTER_SHAPE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OUTPUTDUPL_KEYEDMUTEX {
     pub hSharedSurfaceNt: super::super::super::Win32::Foundation::HANDLE,
 }
@@ -12985,7 +15658,8 @@ impl Default for D3DKMT_OUTPUTDUPL_KEYEDMUTEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OUTPUTDUPL_METADATA {
     pub hAdapter: u32,
     pub VidPnSourceId: u32,
@@ -13003,7 +15677,8 @@ impl Default for D3DKMT_OUTPUTDUPL_METADATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OUTPUTDUPL_POINTER_POSITION {
     pub Position: super::super::super::Win32::Foundation::POINT,
     pub Visible: super::super::super::Win32::Foundation::BOOL,
@@ -13017,7 +15692,8 @@ impl Default for D3DKMT_OUTPUTDUPL_POINTER_POSITION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OUTPUTDUPL_RELEASE_FRAME {
     pub hAdapter: u32,
     pub VidPnSourceId: u32,
@@ -13032,7 +15708,8 @@ impl Default for D3DKMT_OUTPUTDUPL_RELEASE_FRAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_OUTPUTDUPL_SNAPSHOT {
     pub Size: u32,
     pub SessionProcessCount: u32,
@@ -13051,7 +15728,8 @@ impl Default for D3DKMT_OUTPUTDUPL_SNAPSHOT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PAGE_TABLE_LEVEL_DESC {
     pub IndexBitCount: u32,
     pub IndexMask: u64,
@@ -13068,7 +15746,8 @@ impl Default for D3DKMT_PAGE_TABLE_LEVEL_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PANELFITTER_SUPPORT {
     pub Supported: super::super::super::Win32::Foundation::BOOL,
 }
@@ -13081,7 +15760,8 @@ impl Default for D3DKMT_PANELFITTER_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PARAVIRTUALIZATION {
     pub SecureContainer: super::super::super::Win32::Foundation::BOOLEAN,
 }
@@ -13094,7 +15774,8 @@ impl Default for D3DKMT_PARAVIRTUALIZATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PHYSICAL_ADAPTER_COUNT {
     pub Count: u32,
 }
@@ -13107,7 +15788,8 @@ impl Default for D3DKMT_PHYSICAL_ADAPTER_COUNT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PINDIRECTFLIPRESOURCES {
     pub hDevice: u32,
     pub ResourceCount: u32,
@@ -13149,10 +15831,25 @@ impl Default for D3DKMT_PLANE_SPECIFIC_INPUT_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PLANE_SPECIFIC_INPUT_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_PLANE_SPECIFIC_INPUT_FLAGS_0_0 {
+    pub const fn Enabled(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Enabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_PLANE_SPECIFIC_INPUT_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -13189,10 +15886,25 @@ impl Default for D3DKMT_PLANE_SPECIFIC_OUTPUT_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PLANE_SPECIFIC_OUTPUT_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_PLANE_SPECIFIC_OUTPUT_FLAGS_0_0 {
+    pub const fn FlipConvertedToImmediate(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_FlipConvertedToImmediate(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_PLANE_SPECIFIC_OUTPUT_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -13202,11 +15914,50 @@ impl Default for D3DKMT_PLANE_SPECIFIC_OUTPUT_FLAGS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_POLLDISPLAYCHILDREN {
     pub hAdapter: u32,
     pub _bitfield: u32,
 }
+impl D3DKMT_POLLDISPLAYCHILDREN {
+    pub const fn NonDestructiveOnly(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_NonDestructiveOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn SynchronousPolling(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_SynchronousPolling(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn DisableModeReset(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_DisableModeReset(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn PollAllAdapters(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_PollAllAdapters(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn PollInterruptible(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_PollInterruptible(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 5) & 134217727
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(134217727 << 5)) | ((value & 134217727) << 5);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_POLLDISPLAYCHILDREN {
     type TypeKind = windows_core::CopyType;
 }
@@ -13308,10 +16059,199 @@ impl Default for D3DKMT_PRESENTFLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PRESENTFLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_PRESENTFLAGS_0_0 {
+    pub const fn Blt(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Blt(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ColorFill(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ColorFill(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Flip(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Flip(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn FlipDoNotFlip(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_FlipDoNotFlip(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn FlipDoNotWait(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_FlipDoNotWait(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn FlipRestart(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_FlipRestart(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn DstRectValid(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_DstRectValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn SrcRectValid(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_SrcRectValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn RestrictVidPnSource(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_RestrictVidPnSource(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn SrcColorKey(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_SrcColorKey(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn DstColorKey(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_DstColorKey(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn LinearToSrgb(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_LinearToSrgb(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn PresentCountValid(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_PresentCountValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn Rotate(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_Rotate(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn PresentToBitmap(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_PresentToBitmap(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn RedirectedFlip(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_RedirectedFlip(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn RedirectedBlt(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_RedirectedBlt(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn FlipStereo(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_FlipStereo(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn FlipStereoTemporaryMono(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_FlipStereoTemporaryMono(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn FlipStereoPreferRight(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_FlipStereoPreferRight(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn BltStereoUseRight(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_BltStereoUseRight(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn PresentHistoryTokenOnly(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_PresentHistoryTokenOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn PresentRegionsValid(&self) -> u32 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_PresentRegionsValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn PresentDDA(&self) -> u32 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_PresentDDA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn ProtectedContentBlankedOut(&self) -> u32 {
+        (self._bitfield >> 24) & 1
+    }
+    pub fn set_ProtectedContentBlankedOut(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 24)) | ((value & 1) << 24);
+    }
+    pub const fn RemoteSession(&self) -> u32 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_RemoteSession(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn CrossAdapter(&self) -> u32 {
+        (self._bitfield >> 26) & 1
+    }
+    pub fn set_CrossAdapter(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 26)) | ((value & 1) << 26);
+    }
+    pub const fn DurationValid(&self) -> u32 {
+        (self._bitfield >> 27) & 1
+    }
+    pub fn set_DurationValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 27)) | ((value & 1) << 27);
+    }
+    pub const fn PresentIndirect(&self) -> u32 {
+        (self._bitfield >> 28) & 1
+    }
+    pub fn set_PresentIndirect(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 28)) | ((value & 1) << 28);
+    }
+    pub const fn PresentHMD(&self) -> u32 {
+        (self._bitfield >> 29) & 1
+    }
+    pub fn set_PresentHMD(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 29)) | ((value & 1) << 29);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 30) & 3
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 30)) | ((value & 3) << 30);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_PRESENTFLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -13484,10 +16424,85 @@ impl Default for D3DKMT_PRESENT_MULTIPLANE_OVERLAY_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PRESENT_MULTIPLANE_OVERLAY_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_PRESENT_MULTIPLANE_OVERLAY_FLAGS_0_0 {
+    pub const fn FlipStereo(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_FlipStereo(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn FlipStereoTemporaryMono(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_FlipStereoTemporaryMono(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn FlipStereoPreferRight(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_FlipStereoPreferRight(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn FlipDoNotWait(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_FlipDoNotWait(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn FlipDoNotFlip(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_FlipDoNotFlip(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn FlipRestart(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_FlipRestart(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn DurationValid(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_DurationValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn HDRMetaDataValid(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_HDRMetaDataValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn HMD(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_HMD(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn TrueImmediate(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_TrueImmediate(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn FromDDisplay(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_FromDDisplay(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 11) & 2097151
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2097151 << 11)) | ((value & 2097151) << 11);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_PRESENT_MULTIPLANE_OVERLAY_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -13544,10 +16559,19 @@ impl Default for D3DKMT_PRESENT_REDIRECTED_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PRESENT_REDIRECTED_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_PRESENT_REDIRECTED_FLAGS_0_0 {
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = value;
+    }
+}
 impl windows_core::TypeKind for D3DKMT_PRESENT_REDIRECTED_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -13557,7 +16581,8 @@ impl Default for D3DKMT_PRESENT_REDIRECTED_FLAGS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PRESENT_RGNS {
     pub DirtyRectCount: u32,
     pub pDirtyRects: *const super::super::super::Win32::Foundation::RECT,
@@ -13573,7 +16598,8 @@ impl Default for D3DKMT_PRESENT_RGNS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PRESENT_STATS {
     pub PresentCount: u32,
     pub PresentRefreshCount: u32,
@@ -13590,7 +16616,8 @@ impl Default for D3DKMT_PRESENT_STATS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PRESENT_STATS_DWM {
     pub PresentCount: u32,
     pub PresentRefreshCount: u32,
@@ -13608,7 +16635,8 @@ impl Default for D3DKMT_PRESENT_STATS_DWM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PRESENT_STATS_DWM2 {
     pub cbSize: u32,
     pub PresentCount: u32,
@@ -13673,10 +16701,43 @@ impl Default for D3DKMT_PROCESS_VERIFIER_VIDMM_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PROCESS_VERIFIER_VIDMM_FLAGS_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_PROCESS_VERIFIER_VIDMM_FLAGS_0 {
+    pub const fn ForceSynchronousEvict(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ForceSynchronousEvict(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NeverDeferEvictions(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NeverDeferEvictions(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn AlwaysFailCommitOnReclaim(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_AlwaysFailCommitOnReclaim(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AlwaysPlaceInDemotedLocation(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AlwaysPlaceInDemotedLocation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_PROCESS_VERIFIER_VIDMM_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -13686,7 +16747,8 @@ impl Default for D3DKMT_PROCESS_VERIFIER_VIDMM_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_PROCESS_VERIFIER_VIDMM_RESTRICT_BUDGET {
     pub LocalBudget: u64,
     pub NonLocalBudget: u64,
@@ -13700,7 +16762,8 @@ impl Default for D3DKMT_PROCESS_VERIFIER_VIDMM_RESTRICT_BUDGET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYADAPTERINFO {
     pub hAdapter: u32,
     pub Type: KMTQUERYADAPTERINFOTYPE,
@@ -13716,7 +16779,8 @@ impl Default for D3DKMT_QUERYADAPTERINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYALLOCATIONRESIDENCY {
     pub hDevice: u32,
     pub hResource: u32,
@@ -13779,10 +16843,25 @@ impl Default for D3DKMT_QUERYFSEBLOCKFLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYFSEBLOCKFLAGS_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_QUERYFSEBLOCKFLAGS_0 {
+    pub const fn Blocked(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Blocked(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_QUERYFSEBLOCKFLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -13792,7 +16871,8 @@ impl Default for D3DKMT_QUERYFSEBLOCKFLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYPROCESSOFFERINFO {
     pub cbSize: u32,
     pub hProcess: super::super::super::Win32::Foundation::HANDLE,
@@ -13808,7 +16888,8 @@ impl Default for D3DKMT_QUERYPROCESSOFFERINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYPROTECTEDSESSIONINFOFROMNTHANDLE {
     pub hNtHandle: super::super::super::Win32::Foundation::HANDLE,
     pub pPrivateDriverData: *const core::ffi::c_void,
@@ -13825,7 +16906,8 @@ impl Default for D3DKMT_QUERYPROTECTEDSESSIONINFOFROMNTHANDLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYPROTECTEDSESSIONSTATUS {
     pub hHandle: u32,
     pub Status: D3DKMT_PROTECTED_SESSION_STATUS,
@@ -13839,7 +16921,8 @@ impl Default for D3DKMT_QUERYPROTECTEDSESSIONSTATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYREMOTEVIDPNSOURCEFROMGDIDISPLAYNAME {
     pub DeviceName: [u16; 32],
     pub VidPnSourceId: u32,
@@ -13853,7 +16936,8 @@ impl Default for D3DKMT_QUERYREMOTEVIDPNSOURCEFROMGDIDISPLAYNAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYRESOURCEINFO {
     pub hDevice: u32,
     pub hGlobalShare: u32,
@@ -13872,7 +16956,8 @@ impl Default for D3DKMT_QUERYRESOURCEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYRESOURCEINFOFROMNTHANDLE {
     pub hDevice: u32,
     pub hNtHandle: super::super::super::Win32::Foundation::HANDLE,
@@ -13994,10 +17079,31 @@ impl Default for D3DKMT_QUERYSTATISTICS_ADAPTER_INFORMATION_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_ADAPTER_INFORMATION_FLAGS_0_0 {
     pub _bitfield: u64,
 }
+impl D3DKMT_QUERYSTATISTICS_ADAPTER_INFORMATION_FLAGS_0_0 {
+    pub const fn NumberOfMemoryGroups(&self) -> u64 {
+        self._bitfield & 3
+    }
+    pub fn set_NumberOfMemoryGroups(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn SupportsDemotion(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_SupportsDemotion(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 3) & 2305843009213693951
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(2305843009213693951 << 3)) | ((value & 2305843009213693951) << 3);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_QUERYSTATISTICS_ADAPTER_INFORMATION_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -14007,7 +17113,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_ADAPTER_INFORMATION_FLAGS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_COMMITMENT_DATA {
     pub TotalBytesEvictedFromProcess: u64,
     pub BytesBySegmentPreference: [u64; 5],
@@ -14021,7 +17128,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_COMMITMENT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_COUNTER {
     pub Count: u32,
     pub Bytes: u64,
@@ -14035,7 +17143,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_COUNTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_DMA_BUFFER {
     pub Size: D3DKMT_QUERYSTATISTICS_COUNTER,
     pub AllocationListBytes: u32,
@@ -14050,7 +17159,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_DMA_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_DMA_PACKET_TYPE_INFORMATION {
     pub PacketSubmited: u32,
     pub PacketCompleted: u32,
@@ -14066,7 +17176,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_DMA_PACKET_TYPE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_MEMORY {
     pub TotalBytesEvicted: u64,
     pub AllocsCommitted: u32,
@@ -14081,7 +17192,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_MEMORY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_MEMORY_USAGE {
     pub AllocatedBytes: u64,
     pub FreeBytes: u64,
@@ -14098,7 +17210,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_MEMORY_USAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_NODE_INFORMATION {
     pub GlobalInformation: D3DKMT_QUERYSTATISTICS_PROCESS_NODE_INFORMATION,
     pub SystemInformation: D3DKMT_QUERYSTATISTICS_PROCESS_NODE_INFORMATION,
@@ -14114,7 +17227,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_NODE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_PACKET_INFORMATION {
     pub QueuePacket: [D3DKMT_QUERYSTATISTICS_QUEUE_PACKET_TYPE_INFORMATION; 8],
     pub DmaPacket: [D3DKMT_QUERYSTATISTICS_DMA_PACKET_TYPE_INFORMATION; 4],
@@ -14128,7 +17242,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_PACKET_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_PHYSICAL_ADAPTER_INFORMATION {
     pub AdapterPerfData: D3DKMT_ADAPTER_PERFDATA,
     pub AdapterPerfDataCaps: D3DKMT_ADAPTER_PERFDATACAPS,
@@ -14143,7 +17258,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_PHYSICAL_ADAPTER_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_POLICY {
     pub PreferApertureForRead: [u64; 5],
     pub PreferAperture: [u64; 5],
@@ -14160,7 +17276,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_POLICY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_PREEMPTION_INFORMATION {
     pub PreemptionCounter: [u32; 16],
 }
@@ -14173,7 +17290,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_PREEMPTION_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_PROCESS_ADAPTER_INFORMATION {
     pub NbSegments: u32,
     pub NodeCount: u32,
@@ -14194,7 +17312,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_PROCESS_ADAPTER_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_PROCESS_INFORMATION {
     pub NodeCount: u32,
     pub VidPnSourceCount: u32,
@@ -14210,7 +17329,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_PROCESS_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_PROCESS_INTERFERENCE_COUNTERS {
     pub InterferenceCount: [u64; 9],
 }
@@ -14223,7 +17343,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_PROCESS_INTERFERENCE_COUNTERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_PROCESS_NODE_INFORMATION {
     pub RunningTime: i64,
     pub ContextSwitch: u32,
@@ -14240,7 +17361,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_PROCESS_NODE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_PROCESS_SEGMENT_GROUP_INFORMATION {
     pub Budget: u64,
     pub Requested: u64,
@@ -14256,7 +17378,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_PROCESS_SEGMENT_GROUP_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_PROCESS_SEGMENT_INFORMATION {
     pub BytesCommitted: u64,
     pub MaximumWorkingSet: u64,
@@ -14276,7 +17399,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_PROCESS_SEGMENT_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_PROCESS_SEGMENT_POLICY {
     pub UseMRU: u64,
 }
@@ -14289,7 +17413,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_PROCESS_SEGMENT_POLICY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_PROCESS_VIDPNSOURCE_INFORMATION {
     pub Frame: u32,
     pub CancelledFrame: u32,
@@ -14310,7 +17435,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_PROCESS_VIDPNSOURCE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_QUERY_ADAPTER2 {
     pub PhysicalAdapterIndex: u16,
 }
@@ -14323,7 +17449,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_QUERY_ADAPTER2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_QUERY_ADAPTER_INFORMATION2 {
     pub PhysicalAdapterIndex: u16,
 }
@@ -14336,7 +17463,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_QUERY_ADAPTER_INFORMATION2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_QUERY_NODE {
     pub NodeId: u32,
 }
@@ -14349,7 +17477,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_QUERY_NODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_QUERY_NODE2 {
     pub PhysicalAdapterIndex: u16,
     pub NodeOrdinal: u16,
@@ -14363,7 +17492,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_QUERY_NODE2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_QUERY_PHYSICAL_ADAPTER {
     pub PhysicalAdapterIndex: u32,
 }
@@ -14376,7 +17506,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_QUERY_PHYSICAL_ADAPTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_QUERY_PROCESS_SEGMENT_GROUP2 {
     pub PhysicalAdapterIndex: u16,
     pub SegmentGroup: u16,
@@ -14390,7 +17521,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_QUERY_PROCESS_SEGMENT_GROUP2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_QUERY_SEGMENT {
     pub SegmentId: u32,
 }
@@ -14403,7 +17535,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_QUERY_SEGMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_QUERY_SEGMENT2 {
     pub PhysicalAdapterIndex: u16,
     pub SegmentId: u16,
@@ -14417,7 +17550,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_QUERY_SEGMENT2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_QUERY_SEGMENT_GROUP_USAGE {
     pub PhysicalAdapterIndex: u16,
     pub SegmentGroup: u16,
@@ -14431,7 +17565,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_QUERY_SEGMENT_GROUP_USAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_QUERY_SEGMENT_USAGE {
     pub PhysicalAdapterIndex: u16,
     pub SegmentId: u16,
@@ -14445,7 +17580,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_QUERY_SEGMENT_USAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_QUERY_VIDPNSOURCE {
     pub VidPnSourceId: u32,
 }
@@ -14458,7 +17594,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_QUERY_VIDPNSOURCE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_QUEUE_PACKET_TYPE_INFORMATION {
     pub PacketSubmited: u32,
     pub PacketCompleted: u32,
@@ -14497,7 +17634,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_RESULT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_SEGMENT_INFORMATION {
     pub CommitLimit: u64,
     pub BytesCommitted: u64,
@@ -14519,10 +17657,37 @@ impl Default for D3DKMT_QUERYSTATISTICS_SEGMENT_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_SEGMENT_INFORMATION_0 {
     pub _bitfield: u64,
 }
+impl D3DKMT_QUERYSTATISTICS_SEGMENT_INFORMATION_0 {
+    pub const fn PreservedDuringStandby(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_PreservedDuringStandby(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PreservedDuringHibernate(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PreservedDuringHibernate(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn PartiallyPreservedDuringHibernate(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_PartiallyPreservedDuringHibernate(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 3) & 2305843009213693951
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(2305843009213693951 << 3)) | ((value & 2305843009213693951) << 3);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_QUERYSTATISTICS_SEGMENT_INFORMATION_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -14532,10 +17697,37 @@ impl Default for D3DKMT_QUERYSTATISTICS_SEGMENT_INFORMATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_SEGMENT_INFORMATION_1 {
     pub _bitfield: u64,
 }
+impl D3DKMT_QUERYSTATISTICS_SEGMENT_INFORMATION_1 {
+    pub const fn SystemMemory(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_SystemMemory(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PopulatedByReservedDDRByFirmware(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PopulatedByReservedDDRByFirmware(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn SegmentType(&self) -> u64 {
+        (self._bitfield >> 2) & 15
+    }
+    pub fn set_SegmentType(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(15 << 2)) | ((value & 15) << 2);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 6) & 288230376151711743
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(288230376151711743 << 6)) | ((value & 288230376151711743) << 6);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_QUERYSTATISTICS_SEGMENT_INFORMATION_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -14545,7 +17737,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_SEGMENT_INFORMATION_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_SYSTEM_MEMORY {
     pub BytesAllocated: u64,
     pub BytesReserved: u64,
@@ -14568,7 +17761,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_SYSTEM_MEMORY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_VIDEO_MEMORY {
     pub AllocsCommitted: u32,
     pub AllocsResidentInP: [D3DKMT_QUERYSTATISTICS_COUNTER; 5],
@@ -14584,7 +17778,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_VIDEO_MEMORY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATISTICS_VIDPNSOURCE_INFORMATION {
     pub GlobalInformation: D3DKMT_QUERYSTATISTICS_PROCESS_VIDPNSOURCE_INFORMATION,
     pub SystemInformation: D3DKMT_QUERYSTATISTICS_PROCESS_VIDPNSOURCE_INFORMATION,
@@ -14599,7 +17794,8 @@ impl Default for D3DKMT_QUERYSTATISTICS_VIDPNSOURCE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATSTICS_ALLOCATIONS {
     pub Created: D3DKMT_QUERYSTATISTICS_COUNTER,
     pub Destroyed: D3DKMT_QUERYSTATISTICS_COUNTER,
@@ -14618,7 +17814,8 @@ impl Default for D3DKMT_QUERYSTATSTICS_ALLOCATIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATSTICS_LOCKS {
     pub NbLocks: u32,
     pub NbLocksWaitFlag: u32,
@@ -14637,7 +17834,8 @@ impl Default for D3DKMT_QUERYSTATSTICS_LOCKS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATSTICS_PAGING_FAULT {
     pub Faults: D3DKMT_QUERYSTATISTICS_COUNTER,
     pub FaultsFirstTimeAccess: D3DKMT_QUERYSTATISTICS_COUNTER,
@@ -14667,7 +17865,8 @@ impl Default for D3DKMT_QUERYSTATSTICS_PAGING_FAULT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATSTICS_PAGING_TRANSFER {
     pub BytesFilled: u64,
     pub BytesDiscarded: u64,
@@ -14687,7 +17886,8 @@ impl Default for D3DKMT_QUERYSTATSTICS_PAGING_TRANSFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATSTICS_PREPRATION {
     pub BroadcastStall: u32,
     pub NbDMAPrepared: u32,
@@ -14704,7 +17904,8 @@ impl Default for D3DKMT_QUERYSTATSTICS_PREPRATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATSTICS_REFERENCE_DMA_BUFFER {
     pub NbCall: u32,
     pub NbAllocationsReferenced: u32,
@@ -14730,7 +17931,8 @@ impl Default for D3DKMT_QUERYSTATSTICS_REFERENCE_DMA_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATSTICS_RENAMING {
     pub NbAllocationsRenamed: u32,
     pub NbAllocationsShrinked: u32,
@@ -14751,7 +17953,8 @@ impl Default for D3DKMT_QUERYSTATSTICS_RENAMING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATSTICS_SWIZZLING_RANGE {
     pub NbRangesAcquired: u32,
     pub NbRangesReleased: u32,
@@ -14765,7 +17968,8 @@ impl Default for D3DKMT_QUERYSTATSTICS_SWIZZLING_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYSTATSTICS_TERMINATIONS {
     pub TerminatedShared: D3DKMT_QUERYSTATISTICS_COUNTER,
     pub TerminatedNonShared: D3DKMT_QUERYSTATISTICS_COUNTER,
@@ -14781,7 +17985,8 @@ impl Default for D3DKMT_QUERYSTATSTICS_TERMINATIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYVIDEOMEMORYINFO {
     pub hProcess: super::super::super::Win32::Foundation::HANDLE,
     pub hAdapter: u32,
@@ -14801,7 +18006,8 @@ impl Default for D3DKMT_QUERYVIDEOMEMORYINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERYVIDPNEXCLUSIVEOWNERSHIP {
     pub hProcess: super::super::super::Win32::Foundation::HANDLE,
     pub hWindow: super::super::super::Win32::Foundation::HWND,
@@ -14818,7 +18024,8 @@ impl Default for D3DKMT_QUERYVIDPNEXCLUSIVEOWNERSHIP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERY_ADAPTER_UNIQUE_GUID {
     pub AdapterUniqueGUID: [u16; 40],
 }
@@ -14831,7 +18038,8 @@ impl Default for D3DKMT_QUERY_ADAPTER_UNIQUE_GUID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERY_DEVICE_IDS {
     pub PhysicalAdapterIndex: u32,
     pub DeviceIds: D3DKMT_DEVICE_IDS,
@@ -14859,7 +18067,8 @@ impl Default for D3DKMT_QUERY_GPUMMU_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERY_MIRACAST_DRIVER_TYPE {
     pub MiracastDriverType: D3DKMT_MIRACAST_DRIVER_TYPE,
 }
@@ -14872,7 +18081,8 @@ impl Default for D3DKMT_QUERY_MIRACAST_DRIVER_TYPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERY_PHYSICAL_ADAPTER_PNP_KEY {
     pub PhysicalAdapterIndex: u32,
     pub PnPKeyType: D3DKMT_PNP_KEY_TYPE,
@@ -14888,7 +18098,8 @@ impl Default for D3DKMT_QUERY_PHYSICAL_ADAPTER_PNP_KEY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_QUERY_SCANOUT_CAPS {
     pub VidPnSourceId: u32,
     pub Caps: u32,
@@ -14902,7 +18113,8 @@ impl Default for D3DKMT_QUERY_SCANOUT_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_RECLAIMALLOCATIONS {
     pub hDevice: u32,
     pub pResources: *mut u32,
@@ -14951,7 +18163,8 @@ impl Default for D3DKMT_RECLAIMALLOCATIONS2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_REGISTERBUDGETCHANGENOTIFICATION {
     pub hDevice: u32,
     pub Callback: PFND3DKMT_BUDGETCHANGENOTIFICATIONCALLBACK,
@@ -14967,7 +18180,8 @@ impl Default for D3DKMT_REGISTERBUDGETCHANGENOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_REGISTERTRIMNOTIFICATION {
     pub AdapterLuid: super::super::super::Win32::Foundation::LUID,
     pub hDevice: u32,
@@ -14984,7 +18198,8 @@ impl Default for D3DKMT_REGISTERTRIMNOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_RELEASEKEYEDMUTEX {
     pub hKeyedMutex: u32,
     pub Key: u64,
@@ -14999,7 +18214,8 @@ impl Default for D3DKMT_RELEASEKEYEDMUTEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_RELEASEKEYEDMUTEX2 {
     pub hKeyedMutex: u32,
     pub Key: u64,
@@ -15061,10 +18277,61 @@ impl Default for D3DKMT_RENDER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_RENDERFLAGS {
     pub _bitfield: u32,
 }
+impl D3DKMT_RENDERFLAGS {
+    pub const fn ResizeCommandBuffer(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ResizeCommandBuffer(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ResizeAllocationList(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ResizeAllocationList(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ResizePatchLocationList(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ResizePatchLocationList(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn NullRendering(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_NullRendering(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn PresentRedirected(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_PresentRedirected(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn RenderKm(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_RenderKm(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn RenderKmReadback(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_RenderKmReadback(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 7) & 33554431
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(33554431 << 7)) | ((value & 33554431) << 7);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_RENDERFLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -15074,7 +18341,8 @@ impl Default for D3DKMT_RENDERFLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_REQUEST_MACHINE_CRASH_ESCAPE {
     pub Param1: usize,
     pub Param2: usize,
@@ -15089,7 +18357,8 @@ impl Default for D3DKMT_REQUEST_MACHINE_CRASH_ESCAPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SCATTERBLT {
     pub hLogicalSurfaceDestination: u64,
     pub hDestinationCompSurfDWM: i64,
@@ -15106,7 +18375,8 @@ impl Default for D3DKMT_SCATTERBLT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SCATTERBLTS {
     pub NumBlts: u32,
     pub Blts: [D3DKMT_SCATTERBLT; 12],
@@ -15120,7 +18390,8 @@ impl Default for D3DKMT_SCATTERBLTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SEGMENTGROUPSIZEINFO {
     pub PhysicalAdapterIndex: u32,
     pub LegacyInfo: D3DKMT_SEGMENTSIZEINFO,
@@ -15137,7 +18408,8 @@ impl Default for D3DKMT_SEGMENTGROUPSIZEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SEGMENTSIZEINFO {
     pub DedicatedVideoMemorySize: u64,
     pub DedicatedSystemMemorySize: u64,
@@ -15152,7 +18424,8 @@ impl Default for D3DKMT_SEGMENTSIZEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SEGMENT_CAPS {
     pub Size: u64,
     pub PageSize: u32,
@@ -15170,7 +18443,8 @@ impl Default for D3DKMT_SEGMENT_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SETALLOCATIONPRIORITY {
     pub hDevice: u32,
     pub hResource: u32,
@@ -15187,7 +18461,8 @@ impl Default for D3DKMT_SETALLOCATIONPRIORITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SETCONTEXTINPROCESSSCHEDULINGPRIORITY {
     pub hContext: u32,
     pub Priority: i32,
@@ -15201,7 +18476,8 @@ impl Default for D3DKMT_SETCONTEXTINPROCESSSCHEDULINGPRIORITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SETCONTEXTSCHEDULINGPRIORITY {
     pub hContext: u32,
     pub Priority: i32,
@@ -15215,7 +18491,8 @@ impl Default for D3DKMT_SETCONTEXTSCHEDULINGPRIORITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SETDISPLAYMODE {
     pub hDevice: u32,
     pub hPrimaryAllocation: u32,
@@ -15233,11 +18510,26 @@ impl Default for D3DKMT_SETDISPLAYMODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SETDISPLAYMODE_FLAGS {
     pub _bitfield1: u8,
     pub _bitfield2: u32,
 }
+impl D3DKMT_SETDISPLAYMODE_FLAGS {
+    pub const fn PreserveVidPn(&self) -> u8 {
+        self._bitfield1 & 1
+    }
+    pub fn set_PreserveVidPn(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield2 & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !2147483647) | (value & 2147483647);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_SETDISPLAYMODE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -15247,7 +18539,8 @@ impl Default for D3DKMT_SETDISPLAYMODE_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SETDISPLAYPRIVATEDRIVERFORMAT {
     pub hDevice: u32,
     pub VidPnSourceId: u32,
@@ -15292,10 +18585,25 @@ impl Default for D3DKMT_SETFSEBLOCKFLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SETFSEBLOCKFLAGS_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_SETFSEBLOCKFLAGS_0 {
+    pub const fn Block(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Block(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_SETFSEBLOCKFLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -15336,7 +18644,8 @@ impl Default for D3DKMT_SETGAMMARAMP_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SETHWPROTECTIONTEARDOWNRECOVERY {
     pub hAdapter: u32,
     pub Recovered: super::super::super::Win32::Foundation::BOOL,
@@ -15379,7 +18688,8 @@ impl Default for D3DKMT_SETQUEUEDLIMIT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SETQUEUEDLIMIT_0_0 {
     pub VidPnSourceId: u32,
     pub QueuedPendingFlipLimit: u32,
@@ -15393,7 +18703,8 @@ impl Default for D3DKMT_SETQUEUEDLIMIT_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SETSTABLEPOWERSTATE {
     pub hAdapter: u32,
     pub Enabled: super::super::super::Win32::Foundation::BOOL,
@@ -15407,7 +18718,8 @@ impl Default for D3DKMT_SETSTABLEPOWERSTATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SETSYNCREFRESHCOUNTWAITTARGET {
     pub hAdapter: u32,
     pub hDevice: u32,
@@ -15423,7 +18735,8 @@ impl Default for D3DKMT_SETSYNCREFRESHCOUNTWAITTARGET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SETVIDPNSOURCEHWPROTECTION {
     pub hAdapter: u32,
     pub VidPnSourceId: u32,
@@ -15438,7 +18751,8 @@ impl Default for D3DKMT_SETVIDPNSOURCEHWPROTECTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SETVIDPNSOURCEOWNER {
     pub hDevice: u32,
     pub pType: *const D3DKMT_VIDPNSOURCEOWNER_TYPE,
@@ -15512,7 +18826,8 @@ impl Default for D3DKMT_SET_COLORSPACE_TRANSFORM_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SHAREDPRIMARYLOCKNOTIFICATION {
     pub AdapterLuid: super::super::super::Win32::Foundation::LUID,
     pub VidPnSourceId: u32,
@@ -15527,7 +18842,8 @@ impl Default for D3DKMT_SHAREDPRIMARYLOCKNOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SHAREDPRIMARYUNLOCKNOTIFICATION {
     pub AdapterLuid: super::super::super::Win32::Foundation::LUID,
     pub VidPnSourceId: u32,
@@ -15541,7 +18857,8 @@ impl Default for D3DKMT_SHAREDPRIMARYUNLOCKNOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SHAREOBJECTWITHHOST {
     pub hDevice: u32,
     pub hObject: u32,
@@ -15607,7 +18924,8 @@ impl Default for D3DKMT_SIGNALSYNCHRONIZATIONOBJECT2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SIGNALSYNCHRONIZATIONOBJECT2_0_0 {
     pub FenceValue: u64,
 }
@@ -15701,7 +19019,8 @@ impl Default for D3DKMT_SIGNALSYNCHRONIZATIONOBJECTFROMGPU2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_STANDARDALLOCATION_EXISTINGHEAP {
     pub Size: usize,
 }
@@ -15714,7 +19033,8 @@ impl Default for D3DKMT_STANDARDALLOCATION_EXISTINGHEAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SUBMITCOMMAND {
     pub Commands: u64,
     pub CommandLength: u32,
@@ -15738,10 +19058,37 @@ impl Default for D3DKMT_SUBMITCOMMAND {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SUBMITCOMMANDFLAGS {
     pub _bitfield: u32,
 }
+impl D3DKMT_SUBMITCOMMANDFLAGS {
+    pub const fn NullRendering(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_NullRendering(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PresentRedirected(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PresentRedirected(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn NoKmdAccess(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_NoKmdAccess(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_SUBMITCOMMANDFLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -15751,7 +19098,8 @@ impl Default for D3DKMT_SUBMITCOMMANDFLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SUBMITCOMMANDTOHWQUEUE {
     pub hHwQueue: u32,
     pub HwQueueProgressFenceId: u64,
@@ -15818,7 +19166,8 @@ impl Default for D3DKMT_SUBMITSIGNALSYNCOBJECTSTOHWQUEUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SUBMITWAITFORSYNCOBJECTSTOHWQUEUE {
     pub hHwQueue: u32,
     pub ObjectCount: u32,
@@ -15834,7 +19183,8 @@ impl Default for D3DKMT_SUBMITWAITFORSYNCOBJECTSTOHWQUEUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_SURFACECOMPLETE_PRESENTHISTORYTOKEN {
     pub hLogicalSurface: u64,
 }
@@ -15935,10 +19285,31 @@ impl Default for D3DKMT_TRIMPROCESSCOMMITMENT_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_TRIMPROCESSCOMMITMENT_FLAGS_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_TRIMPROCESSCOMMITMENT_FLAGS_0 {
+    pub const fn Lazy(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Lazy(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn OnlyRepurposed(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_OnlyRepurposed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_TRIMPROCESSCOMMITMENT_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -15948,7 +19319,8 @@ impl Default for D3DKMT_TRIMPROCESSCOMMITMENT_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_UMDFILENAMEINFO {
     pub Version: KMTUMDVERSION,
     pub UmdFileName: [u16; 260],
@@ -15962,7 +19334,8 @@ impl Default for D3DKMT_UMDFILENAMEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_UMD_DRIVER_VERSION {
     pub DriverVersion: i64,
 }
@@ -15975,7 +19348,8 @@ impl Default for D3DKMT_UMD_DRIVER_VERSION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_UNLOCK {
     pub hDevice: u32,
     pub NumAllocations: u32,
@@ -15990,7 +19364,8 @@ impl Default for D3DKMT_UNLOCK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_UNLOCK2 {
     pub hDevice: u32,
     pub hAllocation: u32,
@@ -16004,7 +19379,8 @@ impl Default for D3DKMT_UNLOCK2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_UNPINDIRECTFLIPRESOURCES {
     pub hDevice: u32,
     pub ResourceCount: u32,
@@ -16019,7 +19395,8 @@ impl Default for D3DKMT_UNPINDIRECTFLIPRESOURCES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_UNREGISTERBUDGETCHANGENOTIFICATION {
     pub Handle: *mut core::ffi::c_void,
 }
@@ -16032,7 +19409,8 @@ impl Default for D3DKMT_UNREGISTERBUDGETCHANGENOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_UNREGISTERTRIMNOTIFICATION {
     pub Handle: *mut core::ffi::c_void,
     pub Callback: PFND3DKMT_TRIMNOTIFICATIONCALLBACK,
@@ -16081,10 +19459,25 @@ impl Default for D3DKMT_UPDATEGPUVIRTUALADDRESS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_UPDATEGPUVIRTUALADDRESS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_UPDATEGPUVIRTUALADDRESS_0_0 {
+    pub const fn DoNotWait(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_DoNotWait(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_UPDATEGPUVIRTUALADDRESS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -16094,7 +19487,8 @@ impl Default for D3DKMT_UPDATEGPUVIRTUALADDRESS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_UPDATEOVERLAY {
     pub hDevice: u32,
     pub hOverlay: u32,
@@ -16109,7 +19503,8 @@ impl Default for D3DKMT_UPDATEOVERLAY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VAD_DESC {
     pub VadIndex: u32,
     pub VadAddress: u64,
@@ -16127,7 +19522,8 @@ impl Default for D3DKMT_VAD_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VA_RANGE_DESC {
     pub VadAddress: u64,
     pub VaRangeIndex: u32,
@@ -16149,7 +19545,8 @@ impl Default for D3DKMT_VA_RANGE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VGPUINTERFACEID {
     pub VirtualGpuIntefaceId: [u16; 260],
 }
@@ -16203,7 +19600,8 @@ impl Default for D3DKMT_VIDMM_ESCAPE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIDMM_ESCAPE_0_0 {
     pub Operation: D3DKMT_DEFRAG_ESCAPE_OPERATION,
     pub SegmentId: u32,
@@ -16221,7 +19619,8 @@ impl Default for D3DKMT_VIDMM_ESCAPE_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIDMM_ESCAPE_0_1 {
     pub hPagingQueue: u32,
     pub PhysicalAdapterIndex: u32,
@@ -16237,7 +19636,8 @@ impl Default for D3DKMT_VIDMM_ESCAPE_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIDMM_ESCAPE_0_2 {
     pub TimerValue: i64,
 }
@@ -16250,7 +19650,8 @@ impl Default for D3DKMT_VIDMM_ESCAPE_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIDMM_ESCAPE_0_3 {
     pub NtHandle: u64,
 }
@@ -16263,7 +19664,8 @@ impl Default for D3DKMT_VIDMM_ESCAPE_0_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIDMM_ESCAPE_0_4 {
     pub ResourceHandle: u32,
     pub AllocationHandle: u32,
@@ -16278,7 +19680,8 @@ impl Default for D3DKMT_VIDMM_ESCAPE_0_4 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIDMM_ESCAPE_0_5 {
     pub NumBytesToTrim: u64,
 }
@@ -16324,7 +19727,8 @@ impl Default for D3DKMT_VIDMM_ESCAPE_0_6_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIDMM_ESCAPE_0_6_0_0 {
     pub NumVads: u32,
 }
@@ -16337,7 +19741,8 @@ impl Default for D3DKMT_VIDMM_ESCAPE_0_6_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIDMM_ESCAPE_0_7 {
     pub hProcess: super::super::super::Win32::Foundation::HANDLE,
 }
@@ -16350,7 +19755,8 @@ impl Default for D3DKMT_VIDMM_ESCAPE_0_7 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIDMM_ESCAPE_0_8 {
     pub LocalMemoryBudget: u64,
     pub SystemMemoryBudget: u64,
@@ -16391,10 +19797,109 @@ impl Default for D3DKMT_VIDMM_ESCAPE_0_9_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIDMM_ESCAPE_0_9_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_VIDMM_ESCAPE_0_9_0_0 {
+    pub const fn ProbeAndLock(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ProbeAndLock(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn SplitPoint(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_SplitPoint(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn NoDemotion(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_NoDemotion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn SwizzlingAperture(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_SwizzlingAperture(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn PagingPathLockSubRange(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_PagingPathLockSubRange(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PagingPathLockMinRange(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_PagingPathLockMinRange(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn ComplexLock(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_ComplexLock(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn FailVARotation(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_FailVARotation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn NoWriteCombined(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_NoWriteCombined(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn NoPrePatching(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_NoPrePatching(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn AlwaysRepatch(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_AlwaysRepatch(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn ExpectPreparationFailure(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_ExpectPreparationFailure(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn FailUserModeVAMapping(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_FailUserModeVAMapping(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn NeverDiscardOfferedAllocation(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_NeverDiscardOfferedAllocation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn AlwaysDiscardOfferedAllocation(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_AlwaysDiscardOfferedAllocation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 15) & 131071
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(131071 << 15)) | ((value & 131071) << 15);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_VIDMM_ESCAPE_0_9_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -16404,7 +19909,8 @@ impl Default for D3DKMT_VIDMM_ESCAPE_0_9_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIDMM_ESCAPE_0_10 {
     pub MinTrimInterval: u32,
     pub MaxTrimInterval: u32,
@@ -16419,7 +19925,8 @@ impl Default for D3DKMT_VIDMM_ESCAPE_0_10 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIDMM_ESCAPE_0_11 {
     pub hProcess: super::super::super::Win32::Foundation::HANDLE,
     pub bAllowWakeOnSubmission: super::super::super::Win32::Foundation::BOOL,
@@ -16433,7 +19940,8 @@ impl Default for D3DKMT_VIDMM_ESCAPE_0_11 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIDMM_ESCAPE_0_12 {
     pub SegmentId: u32,
 }
@@ -16446,7 +19954,8 @@ impl Default for D3DKMT_VIDMM_ESCAPE_0_12 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIDMM_ESCAPE_0_13 {
     pub bFlush: super::super::super::Win32::Foundation::BOOL,
 }
@@ -16484,11 +19993,38 @@ impl Default for D3DKMT_VIDPNSOURCEOWNER_FLAGS_0 {
     fn default() -> Self {
         unsafe { core::mem::zeroed() }
     }
-}
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct D3DKMT_VIDPNSOURCEOWNER_FLAGS_0_0 {
-    pub _bitfield: u32,
+}
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct D3DKMT_VIDPNSOURCEOWNER_FLAGS_0_0 {
+    pub _bitfield: u32,
+}
+impl D3DKMT_VIDPNSOURCEOWNER_FLAGS_0_0 {
+    pub const fn AllowOutputDuplication(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_AllowOutputDuplication(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DisableDWMVirtualMode(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DisableDWMVirtualMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn UseNtHandles(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_UseNtHandles(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
 }
 impl windows_core::TypeKind for D3DKMT_VIDPNSOURCEOWNER_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
@@ -16561,7 +20097,8 @@ impl Default for D3DKMT_VIDSCH_ESCAPE_0_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIDSCH_ESCAPE_0_1 {
     pub Count: u32,
     pub Time: u32,
@@ -16575,10 +20112,25 @@ impl Default for D3DKMT_VIDSCH_ESCAPE_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIRTUALADDRESSFLAGS {
     pub _bitfield: u32,
 }
+impl D3DKMT_VIRTUALADDRESSFLAGS {
+    pub const fn VirtualAddressSupported(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_VirtualAddressSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_VIRTUALADDRESSFLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -16588,7 +20140,8 @@ impl Default for D3DKMT_VIRTUALADDRESSFLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_VIRTUALADDRESSINFO {
     pub VirtualAddressFlags: D3DKMT_VIRTUALADDRESSFLAGS,
 }
@@ -16601,7 +20154,8 @@ impl Default for D3DKMT_VIRTUALADDRESSINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_WAITFORIDLE {
     pub hDevice: u32,
 }
@@ -16614,7 +20168,8 @@ impl Default for D3DKMT_WAITFORIDLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_WAITFORSYNCHRONIZATIONOBJECT {
     pub hContext: u32,
     pub ObjectCount: u32,
@@ -16659,7 +20214,8 @@ impl Default for D3DKMT_WAITFORSYNCHRONIZATIONOBJECT2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_WAITFORSYNCHRONIZATIONOBJECT2_0_0 {
     pub FenceValue: u64,
 }
@@ -16721,7 +20277,8 @@ impl Default for D3DKMT_WAITFORSYNCHRONIZATIONOBJECTFROMGPU_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_WAITFORVERTICALBLANKEVENT {
     pub hAdapter: u32,
     pub hDevice: u32,
@@ -16736,7 +20293,8 @@ impl Default for D3DKMT_WAITFORVERTICALBLANKEVENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_WAITFORVERTICALBLANKEVENT2 {
     pub hAdapter: u32,
     pub hDevice: u32,
@@ -16781,10 +20339,79 @@ impl Default for D3DKMT_WDDM_1_2_CAPS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_WDDM_1_2_CAPS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_WDDM_1_2_CAPS_0_0 {
+    pub const fn SupportNonVGA(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_SupportNonVGA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn SupportSmoothRotation(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_SupportSmoothRotation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn SupportPerEngineTDR(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_SupportPerEngineTDR(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn SupportKernelModeCommandBuffer(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_SupportKernelModeCommandBuffer(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn SupportCCD(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_SupportCCD(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn SupportSoftwareDeviceBitmaps(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_SupportSoftwareDeviceBitmaps(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn SupportGammaRamp(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_SupportGammaRamp(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn SupportHWCursor(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_SupportHWCursor(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn SupportHWVSync(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_SupportHWVSync(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn SupportSurpriseRemovalInHibernation(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_SupportSurpriseRemovalInHibernation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 10) & 4194303
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4194303 << 10)) | ((value & 4194303) << 10);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_WDDM_1_2_CAPS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -16825,6 +20452,50 @@ impl Default for D3DKMT_WDDM_1_3_CAPS_0 {
 pub struct D3DKMT_WDDM_1_3_CAPS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_WDDM_1_3_CAPS_0_0 {
+    pub const fn SupportMiracast(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_SupportMiracast(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn IsHybridIntegratedGPU(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_IsHybridIntegratedGPU(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn IsHybridDiscreteGPU(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_IsHybridDiscreteGPU(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn SupportPowerManagementPStates(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_SupportPowerManagementPStates(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn SupportVirtualModes(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_SupportVirtualModes(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn SupportCrossAdapterResource(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_SupportCrossAdapterResource(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 6) & 67108863
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(67108863 << 6)) | ((value & 67108863) << 6);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_WDDM_1_3_CAPS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -16865,6 +20536,50 @@ impl Default for D3DKMT_WDDM_2_0_CAPS_0 {
 pub struct D3DKMT_WDDM_2_0_CAPS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_WDDM_2_0_CAPS_0_0 {
+    pub const fn Support64BitAtomics(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Support64BitAtomics(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn GpuMmuSupported(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_GpuMmuSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn IoMmuSupported(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_IoMmuSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn FlipOverwriteSupported(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_FlipOverwriteSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn SupportContextlessPresent(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_SupportContextlessPresent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn SupportSurpriseRemoval(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_SupportSurpriseRemoval(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 6) & 67108863
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(67108863 << 6)) | ((value & 67108863) << 6);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_WDDM_2_0_CAPS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -16905,6 +20620,38 @@ impl Default for D3DKMT_WDDM_2_7_CAPS_0 {
 pub struct D3DKMT_WDDM_2_7_CAPS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_WDDM_2_7_CAPS_0_0 {
+    pub const fn HwSchSupported(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_HwSchSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn HwSchEnabled(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_HwSchEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn HwSchEnabledByDefault(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_HwSchEnabledByDefault(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn IndependentVidPnVSyncControl(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_IndependentVidPnVSyncControl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_WDDM_2_7_CAPS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -16945,6 +20692,32 @@ impl Default for D3DKMT_WDDM_2_9_CAPS_0 {
 pub struct D3DKMT_WDDM_2_9_CAPS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_WDDM_2_9_CAPS_0_0 {
+    pub const fn HwSchSupportState(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_HwSchSupportState(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn HwSchEnabled(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_HwSchEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn SelfRefreshMemorySupported(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_SelfRefreshMemorySupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_WDDM_2_9_CAPS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -16985,6 +20758,32 @@ impl Default for D3DKMT_WDDM_3_0_CAPS_0 {
 pub struct D3DKMT_WDDM_3_0_CAPS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_WDDM_3_0_CAPS_0_0 {
+    pub const fn HwFlipQueueSupportState(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_HwFlipQueueSupportState(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn HwFlipQueueEnabled(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_HwFlipQueueEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn DisplayableSupported(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_DisplayableSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_WDDM_3_0_CAPS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -17025,6 +20824,20 @@ impl Default for D3DKMT_WDDM_3_1_CAPS_0 {
 pub struct D3DKMT_WDDM_3_1_CAPS_0_0 {
     pub _bitfield: u32,
 }
+impl D3DKMT_WDDM_3_1_CAPS_0_0 {
+    pub const fn NativeGpuFenceSupported(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_NativeGpuFenceSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_WDDM_3_1_CAPS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -17034,10 +20847,25 @@ impl Default for D3DKMT_WDDM_3_1_CAPS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_WORKINGSETFLAGS {
     pub _bitfield: u32,
 }
+impl D3DKMT_WORKINGSETFLAGS {
+    pub const fn UseDefault(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_UseDefault(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for D3DKMT_WORKINGSETFLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -17047,7 +20875,8 @@ impl Default for D3DKMT_WORKINGSETFLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_WORKINGSETINFO {
     pub Flags: D3DKMT_WORKINGSETFLAGS,
     pub MinimumWorkingSetPercentile: u32,
@@ -17062,7 +20891,8 @@ impl Default for D3DKMT_WORKINGSETINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_WSAUMDIMAGENAME {
     pub WsaUmdImageName: [u16; 260],
 }
@@ -17075,7 +20905,8 @@ impl Default for D3DKMT_WSAUMDIMAGENAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DKMT_XBOX {
     pub IsXBOX: super::super::super::Win32::Foundation::BOOL,
 }
@@ -17088,7 +20919,8 @@ impl Default for D3DKMT_XBOX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DLINEPATTERN {
     pub wRepeatFactor: u16,
     pub wLinePattern: u16,
@@ -17103,7 +20935,8 @@ impl Default for D3DLINEPATTERN {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTDEVICEDESC_V3 {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -17152,7 +20985,8 @@ impl Default for D3DNTDEVICEDESC_V3 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHALDEVICEDESC_V1 {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -17180,7 +21014,8 @@ impl Default for D3DNTHALDEVICEDESC_V1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHALDEVICEDESC_V2 {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -17216,7 +21051,8 @@ impl Default for D3DNTHALDEVICEDESC_V2 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_DirectDraw")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_CALLBACKS {
     pub dwSize: u32,
     pub ContextCreate: LPD3DNTHAL_CONTEXTCREATECB,
@@ -17266,7 +21102,8 @@ impl Default for D3DNTHAL_CALLBACKS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_DirectDraw")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_CALLBACKS2 {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -17288,7 +21125,8 @@ impl Default for D3DNTHAL_CALLBACKS2 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_DirectDraw"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_CALLBACKS3 {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -17309,7 +21147,8 @@ impl Default for D3DNTHAL_CALLBACKS3 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_CLEAR2DATA {
     pub dwhContext: usize,
     pub dwFlags: u32,
@@ -17331,7 +21170,8 @@ impl Default for D3DNTHAL_CLEAR2DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_CLIPPEDTRIANGLEFAN {
     pub FirstVertexOffset: u32,
     pub dwEdgeFlags: u32,
@@ -17418,7 +21258,8 @@ impl Default for D3DNTHAL_CONTEXTCREATEDATA_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_CONTEXTDESTROYALLDATA {
     pub dwPID: u32,
     pub ddrval: windows_core::HRESULT,
@@ -17432,7 +21273,8 @@ impl Default for D3DNTHAL_CONTEXTDESTROYALLDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_CONTEXTDESTROYDATA {
     pub dwhContext: usize,
     pub ddrval: windows_core::HRESULT,
@@ -17446,7 +21288,8 @@ impl Default for D3DNTHAL_CONTEXTDESTROYDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_D3DDX6EXTENDEDCAPS {
     pub dwSize: u32,
     pub dwMinTextureWidth: u32,
@@ -17480,7 +21323,8 @@ impl Default for D3DNTHAL_D3DDX6EXTENDEDCAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_D3DEXTENDEDCAPS {
     pub dwSize: u32,
     pub dwMinTextureWidth: u32,
@@ -17524,7 +21368,8 @@ impl Default for D3DNTHAL_D3DEXTENDEDCAPS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2ADDDIRTYBOX {
     pub dwSurface: u32,
     pub DirtyBox: super::super::super::Win32::Graphics::Direct3D9::D3DBOX,
@@ -17540,7 +21385,8 @@ impl Default for D3DNTHAL_DP2ADDDIRTYBOX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2ADDDIRTYRECT {
     pub dwSurface: u32,
     pub rDirtyArea: super::super::super::Win32::Foundation::RECTL,
@@ -17554,7 +21400,8 @@ impl Default for D3DNTHAL_DP2ADDDIRTYRECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2BLT {
     pub dwSource: u32,
     pub rSource: super::super::super::Win32::Foundation::RECTL,
@@ -17574,7 +21421,8 @@ impl Default for D3DNTHAL_DP2BLT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2BUFFERBLT {
     pub dwDDDestSurface: u32,
     pub dwDDSrcSurface: u32,
@@ -17593,7 +21441,8 @@ impl Default for D3DNTHAL_DP2BUFFERBLT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2CLEAR {
     pub dwFlags: u32,
     pub dwFillColor: u32,
@@ -17610,7 +21459,8 @@ impl Default for D3DNTHAL_DP2CLEAR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2COLORFILL {
     pub dwSurface: u32,
     pub rRect: super::super::super::Win32::Foundation::RECTL,
@@ -17655,7 +21505,8 @@ impl Default for D3DNTHAL_DP2COMMAND_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2COMPOSERECTS {
     pub SrcSurfaceHandle: u32,
     pub DstSurfaceHandle: u32,
@@ -17677,7 +21528,8 @@ impl Default for D3DNTHAL_DP2COMPOSERECTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2CREATELIGHT {
     pub dwIndex: u32,
 }
@@ -17690,7 +21542,8 @@ impl Default for D3DNTHAL_DP2CREATELIGHT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2CREATEPIXELSHADER {
     pub dwHandle: u32,
     pub dwCodeSize: u32,
@@ -17705,7 +21558,8 @@ impl Default for D3DNTHAL_DP2CREATEPIXELSHADER {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2CREATEQUERY {
     pub dwQueryID: u32,
     pub QueryType: super::super::super::Win32::Graphics::Direct3D9::D3DQUERYTYPE,
@@ -17721,7 +21575,8 @@ impl Default for D3DNTHAL_DP2CREATEQUERY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2CREATEVERTEXSHADER {
     pub dwHandle: u32,
     pub dwDeclSize: u32,
@@ -17736,7 +21591,8 @@ impl Default for D3DNTHAL_DP2CREATEVERTEXSHADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2CREATEVERTEXSHADERDECL {
     pub dwHandle: u32,
     pub dwNumVertexElements: u32,
@@ -17750,7 +21606,8 @@ impl Default for D3DNTHAL_DP2CREATEVERTEXSHADERDECL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2CREATEVERTEXSHADERFUNC {
     pub dwHandle: u32,
     pub dwSize: u32,
@@ -17764,7 +21621,8 @@ impl Default for D3DNTHAL_DP2CREATEVERTEXSHADERFUNC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2DELETEQUERY {
     pub dwQueryID: u32,
 }
@@ -17778,7 +21636,8 @@ impl Default for D3DNTHAL_DP2DELETEQUERY {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2DRAWINDEXEDPRIMITIVE {
     pub primType: super::super::super::Win32::Graphics::Direct3D9::D3DPRIMITIVETYPE,
     pub BaseVertexIndex: i32,
@@ -17799,7 +21658,8 @@ impl Default for D3DNTHAL_DP2DRAWINDEXEDPRIMITIVE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2DRAWINDEXEDPRIMITIVE2 {
     pub primType: super::super::super::Win32::Graphics::Direct3D9::D3DPRIMITIVETYPE,
     pub BaseVertexOffset: i32,
@@ -17820,7 +21680,8 @@ impl Default for D3DNTHAL_DP2DRAWINDEXEDPRIMITIVE2 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2DRAWPRIMITIVE {
     pub primType: super::super::super::Win32::Graphics::Direct3D9::D3DPRIMITIVETYPE,
     pub VStart: u32,
@@ -17838,7 +21699,8 @@ impl Default for D3DNTHAL_DP2DRAWPRIMITIVE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2DRAWPRIMITIVE2 {
     pub primType: super::super::super::Win32::Graphics::Direct3D9::D3DPRIMITIVETYPE,
     pub FirstVertexOffset: u32,
@@ -17855,7 +21717,8 @@ impl Default for D3DNTHAL_DP2DRAWPRIMITIVE2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2DRAWRECTPATCH {
     pub Handle: u32,
     pub Flags: u32,
@@ -17869,7 +21732,8 @@ impl Default for D3DNTHAL_DP2DRAWRECTPATCH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2DRAWTRIPATCH {
     pub Handle: u32,
     pub Flags: u32,
@@ -17883,7 +21747,8 @@ impl Default for D3DNTHAL_DP2DRAWTRIPATCH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2EXT {
     pub dwExtToken: u32,
     pub dwSize: u32,
@@ -17898,7 +21763,8 @@ impl Default for D3DNTHAL_DP2EXT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2GENERATEMIPSUBLEVELS {
     pub hSurface: u32,
     pub Filter: super::super::super::Win32::Graphics::Direct3D9::D3DTEXTUREFILTERTYPE,
@@ -17914,7 +21780,8 @@ impl Default for D3DNTHAL_DP2GENERATEMIPSUBLEVELS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2INDEXEDLINELIST {
     pub wV1: u16,
     pub wV2: u16,
@@ -17928,7 +21795,8 @@ impl Default for D3DNTHAL_DP2INDEXEDLINELIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2INDEXEDLINESTRIP {
     pub wV: [u16; 2],
 }
@@ -17941,7 +21809,8 @@ impl Default for D3DNTHAL_DP2INDEXEDLINESTRIP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2INDEXEDTRIANGLEFAN {
     pub wV: [u16; 3],
 }
@@ -17954,7 +21823,8 @@ impl Default for D3DNTHAL_DP2INDEXEDTRIANGLEFAN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2INDEXEDTRIANGLELIST {
     pub wV1: u16,
     pub wV2: u16,
@@ -17970,7 +21840,8 @@ impl Default for D3DNTHAL_DP2INDEXEDTRIANGLELIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2INDEXEDTRIANGLELIST2 {
     pub wV1: u16,
     pub wV2: u16,
@@ -17985,7 +21856,8 @@ impl Default for D3DNTHAL_DP2INDEXEDTRIANGLELIST2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2INDEXEDTRIANGLESTRIP {
     pub wV: [u16; 3],
 }
@@ -17998,7 +21870,8 @@ impl Default for D3DNTHAL_DP2INDEXEDTRIANGLESTRIP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2ISSUEQUERY {
     pub dwQueryID: u32,
     pub dwFlags: u32,
@@ -18012,7 +21885,8 @@ impl Default for D3DNTHAL_DP2ISSUEQUERY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2LINELIST {
     pub wVStart: u16,
 }
@@ -18025,7 +21899,8 @@ impl Default for D3DNTHAL_DP2LINELIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2LINESTRIP {
     pub wVStart: u16,
 }
@@ -18039,7 +21914,8 @@ impl Default for D3DNTHAL_DP2LINESTRIP {
 }
 #[repr(C)]
 #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct3D9"))]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2MULTIPLYTRANSFORM {
     pub xfrmType: super::super::super::Win32::Graphics::Direct3D9::D3DTRANSFORMSTATETYPE,
     pub matrix: super::super::super::Foundation::Numerics::Matrix4x4,
@@ -18055,7 +21931,8 @@ impl Default for D3DNTHAL_DP2MULTIPLYTRANSFORM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2PIXELSHADER {
     pub dwHandle: u32,
 }
@@ -18068,7 +21945,8 @@ impl Default for D3DNTHAL_DP2PIXELSHADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2POINTS {
     pub wCount: u16,
     pub wVStart: u16,
@@ -18116,7 +21994,8 @@ impl Default for D3DNTHAL_DP2RENDERSTATE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2RESPONSE {
     pub bCommand: u8,
     pub bReserved: u8,
@@ -18132,7 +22011,8 @@ impl Default for D3DNTHAL_DP2RESPONSE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2RESPONSEQUERY {
     pub dwQueryID: u32,
     pub dwSize: u32,
@@ -18146,7 +22026,8 @@ impl Default for D3DNTHAL_DP2RESPONSEQUERY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETCLIPPLANE {
     pub dwIndex: u32,
     pub plane: [f32; 4],
@@ -18160,7 +22041,8 @@ impl Default for D3DNTHAL_DP2SETCLIPPLANE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETCONVOLUTIONKERNELMONO {
     pub dwWidth: u32,
     pub dwHeight: u32,
@@ -18175,7 +22057,8 @@ impl Default for D3DNTHAL_DP2SETCONVOLUTIONKERNELMONO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETDEPTHSTENCIL {
     pub hZBuffer: u32,
 }
@@ -18188,7 +22071,8 @@ impl Default for D3DNTHAL_DP2SETDEPTHSTENCIL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETINDICES {
     pub dwVBHandle: u32,
     pub dwStride: u32,
@@ -18230,7 +22114,8 @@ impl Default for D3DNTHAL_DP2SETLIGHT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETPALETTE {
     pub dwPaletteHandle: u32,
     pub dwPaletteFlags: u32,
@@ -18245,7 +22130,8 @@ impl Default for D3DNTHAL_DP2SETPALETTE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETPIXELSHADERCONST {
     pub dwRegister: u32,
     pub dwCount: u32,
@@ -18259,7 +22145,8 @@ impl Default for D3DNTHAL_DP2SETPIXELSHADERCONST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETPRIORITY {
     pub dwDDDestSurface: u32,
     pub dwPriority: u32,
@@ -18273,7 +22160,8 @@ impl Default for D3DNTHAL_DP2SETPRIORITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETRENDERTARGET {
     pub hRenderTarget: u32,
     pub hZBuffer: u32,
@@ -18287,7 +22175,8 @@ impl Default for D3DNTHAL_DP2SETRENDERTARGET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETRENDERTARGET2 {
     pub RTIndex: u32,
     pub hRenderTarget: u32,
@@ -18301,7 +22190,8 @@ impl Default for D3DNTHAL_DP2SETRENDERTARGET2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETSTREAMSOURCE {
     pub dwStream: u32,
     pub dwVBHandle: u32,
@@ -18316,7 +22206,8 @@ impl Default for D3DNTHAL_DP2SETSTREAMSOURCE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETSTREAMSOURCE2 {
     pub dwStream: u32,
     pub dwVBHandle: u32,
@@ -18332,7 +22223,8 @@ impl Default for D3DNTHAL_DP2SETSTREAMSOURCE2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETSTREAMSOURCEFREQ {
     pub dwStream: u32,
     pub dwDivider: u32,
@@ -18346,7 +22238,8 @@ impl Default for D3DNTHAL_DP2SETSTREAMSOURCEFREQ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETSTREAMSOURCEUM {
     pub dwStream: u32,
     pub dwStride: u32,
@@ -18360,7 +22253,8 @@ impl Default for D3DNTHAL_DP2SETSTREAMSOURCEUM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETTEXLOD {
     pub dwDDSurface: u32,
     pub dwLOD: u32,
@@ -18375,7 +22269,8 @@ impl Default for D3DNTHAL_DP2SETTEXLOD {
 }
 #[repr(C)]
 #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct3D9"))]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETTRANSFORM {
     pub xfrmType: super::super::super::Win32::Graphics::Direct3D9::D3DTRANSFORMSTATETYPE,
     pub matrix: super::super::super::Foundation::Numerics::Matrix4x4,
@@ -18391,7 +22286,8 @@ impl Default for D3DNTHAL_DP2SETTRANSFORM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SETVERTEXSHADERCONST {
     pub dwRegister: u32,
     pub dwCount: u32,
@@ -18405,7 +22301,8 @@ impl Default for D3DNTHAL_DP2SETVERTEXSHADERCONST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2STARTVERTEX {
     pub wVStart: u16,
 }
@@ -18419,7 +22316,8 @@ impl Default for D3DNTHAL_DP2STARTVERTEX {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2STATESET {
     pub dwOperation: u32,
     pub dwParam: u32,
@@ -18436,7 +22334,8 @@ impl Default for D3DNTHAL_DP2STATESET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2SURFACEBLT {
     pub dwSource: u32,
     pub rSource: super::super::super::Win32::Foundation::RECTL,
@@ -18455,7 +22354,8 @@ impl Default for D3DNTHAL_DP2SURFACEBLT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2TEXBLT {
     pub dwDDDestSurface: u32,
     pub dwDDSrcSurface: u32,
@@ -18472,7 +22372,8 @@ impl Default for D3DNTHAL_DP2TEXBLT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2TEXTURESTAGESTATE {
     pub wStage: u16,
     pub TSState: u16,
@@ -18487,7 +22388,8 @@ impl Default for D3DNTHAL_DP2TEXTURESTAGESTATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2TRIANGLEFAN {
     pub wVStart: u16,
 }
@@ -18500,7 +22402,8 @@ impl Default for D3DNTHAL_DP2TRIANGLEFAN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2TRIANGLEFAN_IMM {
     pub dwEdgeFlags: u32,
 }
@@ -18513,7 +22416,8 @@ impl Default for D3DNTHAL_DP2TRIANGLEFAN_IMM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2TRIANGLELIST {
     pub wVStart: u16,
 }
@@ -18526,7 +22430,8 @@ impl Default for D3DNTHAL_DP2TRIANGLELIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2TRIANGLESTRIP {
     pub wVStart: u16,
 }
@@ -18539,7 +22444,8 @@ impl Default for D3DNTHAL_DP2TRIANGLESTRIP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2UPDATEPALETTE {
     pub dwPaletteHandle: u32,
     pub wStartIndex: u16,
@@ -18554,7 +22460,8 @@ impl Default for D3DNTHAL_DP2UPDATEPALETTE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2VERTEXSHADER {
     pub dwHandle: u32,
 }
@@ -18567,7 +22474,8 @@ impl Default for D3DNTHAL_DP2VERTEXSHADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2VIEWPORTINFO {
     pub dwX: u32,
     pub dwY: u32,
@@ -18584,7 +22492,8 @@ impl Default for D3DNTHAL_DP2VIEWPORTINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2VOLUMEBLT {
     pub dwDDDestSurface: u32,
     pub dwDDSrcSurface: u32,
@@ -18605,7 +22514,8 @@ impl Default for D3DNTHAL_DP2VOLUMEBLT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2WINFO {
     pub dvWNear: f32,
     pub dvWFar: f32,
@@ -18619,7 +22529,8 @@ impl Default for D3DNTHAL_DP2WINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_DP2ZRANGE {
     pub dvMinZ: f32,
     pub dvMaxZ: f32,
@@ -18697,7 +22608,8 @@ impl Default for D3DNTHAL_DRAWPRIMITIVES2DATA_1 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_DirectDraw"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_GLOBALDRIVERDATA {
     pub dwSize: u32,
     pub hwCaps: D3DNTHALDEVICEDESC_V1,
@@ -18717,7 +22629,8 @@ impl Default for D3DNTHAL_GLOBALDRIVERDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_SCENECAPTUREDATA {
     pub dwhContext: usize,
     pub dwFlag: u32,
@@ -18733,7 +22646,8 @@ impl Default for D3DNTHAL_SCENECAPTUREDATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_DirectDraw")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_SETRENDERTARGETDATA {
     pub dwhContext: usize,
     pub lpDDS: *mut super::super::super::Win32::Graphics::DirectDraw::DD_SURFACE_LOCAL,
@@ -18751,7 +22665,8 @@ impl Default for D3DNTHAL_SETRENDERTARGETDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_TEXTURECREATEDATA {
     pub dwhContext: usize,
     pub hDDS: super::super::super::Win32::Foundation::HANDLE,
@@ -18767,7 +22682,8 @@ impl Default for D3DNTHAL_TEXTURECREATEDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_TEXTUREDESTROYDATA {
     pub dwhContext: usize,
     pub dwHandle: usize,
@@ -18782,7 +22698,8 @@ impl Default for D3DNTHAL_TEXTUREDESTROYDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_TEXTUREGETSURFDATA {
     pub dwhContext: usize,
     pub hDDS: super::super::super::Win32::Foundation::HANDLE,
@@ -18798,7 +22715,8 @@ impl Default for D3DNTHAL_TEXTUREGETSURFDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_TEXTURESWAPDATA {
     pub dwhContext: usize,
     pub dwHandle1: usize,
@@ -18814,7 +22732,8 @@ impl Default for D3DNTHAL_TEXTURESWAPDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DNTHAL_VALIDATETEXTURESTAGESTATEDATA {
     pub dwhContext: usize,
     pub dwFlags: u32,
@@ -18831,7 +22750,8 @@ impl Default for D3DNTHAL_VALIDATETEXTURESTAGESTATEDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDNT_DEFERRED_AGP_AWARE_DATA {
     pub gdi2: DDNT_GETDRIVERINFO2DATA,
 }
@@ -18844,7 +22764,8 @@ impl Default for DDNT_DEFERRED_AGP_AWARE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDNT_DXVERSION {
     pub gdi2: DDNT_GETDRIVERINFO2DATA,
     pub dwDXVersion: u32,
@@ -18859,7 +22780,8 @@ impl Default for DDNT_DXVERSION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDNT_FREE_DEFERRED_AGP_DATA {
     pub gdi2: DDNT_GETDRIVERINFO2DATA,
     pub dwProcessId: u32,
@@ -18873,7 +22795,8 @@ impl Default for DDNT_FREE_DEFERRED_AGP_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDNT_GETADAPTERGROUPDATA {
     pub gdi2: DDNT_GETDRIVERINFO2DATA,
     pub ulUniqueAdapterGroupId: usize,
@@ -18889,7 +22812,8 @@ impl Default for DDNT_GETADAPTERGROUPDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDNT_GETD3DQUERYCOUNTDATA {
     pub gdi2: DDNT_GETDRIVERINFO2DATA,
     pub dwNumQueries: u32,
@@ -18937,7 +22861,8 @@ impl Default for DDNT_GETD3DQUERYDATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDNT_GETDDIVERSIONDATA {
     pub gdi2: DDNT_GETDRIVERINFO2DATA,
     pub dwDXVersion: u32,
@@ -18952,7 +22877,8 @@ impl Default for DDNT_GETDDIVERSIONDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDNT_GETDRIVERINFO2DATA {
     pub dwReserved: u32,
     pub dwMagic: u32,
@@ -18968,7 +22894,8 @@ impl Default for DDNT_GETDRIVERINFO2DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDNT_GETEXTENDEDMODECOUNTDATA {
     pub gdi2: DDNT_GETDRIVERINFO2DATA,
     pub dwModeCount: u32,
@@ -18984,7 +22911,8 @@ impl Default for DDNT_GETEXTENDEDMODECOUNTDATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDNT_GETEXTENDEDMODEDATA {
     pub gdi2: DDNT_GETDRIVERINFO2DATA,
     pub dwModeIndex: u32,
@@ -19001,7 +22929,8 @@ impl Default for DDNT_GETEXTENDEDMODEDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDNT_GETFORMATCOUNTDATA {
     pub gdi2: DDNT_GETDRIVERINFO2DATA,
     pub dwFormatCount: u32,
@@ -19035,7 +22964,8 @@ impl Default for DDNT_GETFORMATDATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDNT_MULTISAMPLEQUALITYLEVELSDATA {
     pub gdi2: DDNT_GETDRIVERINFO2DATA,
     pub Format: super::super::super::Win32::Graphics::Direct3D9::D3DFORMAT,
@@ -19043,6 +22973,21 @@ pub struct DDNT_MULTISAMPLEQUALITYLEVELSDATA {
     pub QualityLevels: u32,
 }
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl DDNT_MULTISAMPLEQUALITYLEVELSDATA {
+    pub const fn bFlip(&self) -> i32 {
+        self._bitfield & 1
+    }
+    pub fn set_bFlip(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MSType(&self) -> i32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_MSType(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
 impl windows_core::TypeKind for DDNT_MULTISAMPLEQUALITYLEVELSDATA {
     type TypeKind = windows_core::CopyType;
 }
@@ -19053,7 +22998,8 @@ impl Default for DDNT_MULTISAMPLEQUALITYLEVELSDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DD_DEFERRED_AGP_AWARE_DATA {
     pub gdi2: DD_GETDRIVERINFO2DATA,
 }
@@ -19066,7 +23012,8 @@ impl Default for DD_DEFERRED_AGP_AWARE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DD_DXVERSION {
     pub gdi2: DD_GETDRIVERINFO2DATA,
     pub dwDXVersion: u32,
@@ -19081,7 +23028,8 @@ impl Default for DD_DXVERSION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DD_FREE_DEFERRED_AGP_DATA {
     pub gdi2: DD_GETDRIVERINFO2DATA,
     pub dwProcessId: u32,
@@ -19095,7 +23043,8 @@ impl Default for DD_FREE_DEFERRED_AGP_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DD_GETADAPTERGROUPDATA {
     pub gdi2: DD_GETDRIVERINFO2DATA,
     pub ulUniqueAdapterGroupId: usize,
@@ -19111,7 +23060,8 @@ impl Default for DD_GETADAPTERGROUPDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DD_GETD3DQUERYCOUNTDATA {
     pub gdi2: DD_GETDRIVERINFO2DATA,
     pub dwNumQueries: u32,
@@ -19159,7 +23109,8 @@ impl Default for DD_GETD3DQUERYDATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DD_GETDDIVERSIONDATA {
     pub gdi2: DD_GETDRIVERINFO2DATA,
     pub dwDXVersion: u32,
@@ -19174,7 +23125,8 @@ impl Default for DD_GETDDIVERSIONDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DD_GETDRIVERINFO2DATA {
     pub dwReserved: u32,
     pub dwMagic: u32,
@@ -19190,7 +23142,8 @@ impl Default for DD_GETDRIVERINFO2DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DD_GETEXTENDEDMODECOUNTDATA {
     pub gdi2: DD_GETDRIVERINFO2DATA,
     pub dwModeCount: u32,
@@ -19206,7 +23159,8 @@ impl Default for DD_GETEXTENDEDMODECOUNTDATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DD_GETEXTENDEDMODEDATA {
     pub gdi2: DD_GETDRIVERINFO2DATA,
     pub dwModeIndex: u32,
@@ -19223,7 +23177,8 @@ impl Default for DD_GETEXTENDEDMODEDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DD_GETFORMATCOUNTDATA {
     pub gdi2: DD_GETDRIVERINFO2DATA,
     pub dwFormatCount: u32,
@@ -19257,7 +23212,8 @@ impl Default for DD_GETFORMATDATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DD_MULTISAMPLEQUALITYLEVELSDATA {
     pub gdi2: DD_GETDRIVERINFO2DATA,
     pub Format: super::super::super::Win32::Graphics::Direct3D9::D3DFORMAT,
@@ -19265,6 +23221,21 @@ pub struct DD_MULTISAMPLEQUALITYLEVELSDATA {
     pub QualityLevels: u32,
 }
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl DD_MULTISAMPLEQUALITYLEVELSDATA {
+    pub const fn bFlip(&self) -> i32 {
+        self._bitfield & 1
+    }
+    pub fn set_bFlip(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MSType(&self) -> i32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_MSType(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
 impl windows_core::TypeKind for DD_MULTISAMPLEQUALITYLEVELSDATA {
     type TypeKind = windows_core::CopyType;
 }
@@ -19300,6 +23271,44 @@ impl Default for DISPLAYID_DETAILED_TIMING_TYPE_I {
 pub struct DISPLAYID_DETAILED_TIMING_TYPE_I_0 {
     pub _bitfield: u32,
 }
+impl DISPLAYID_DETAILED_TIMING_TYPE_I_0 {
+    pub const fn PixelClock(&self) -> u32 {
+        self._bitfield & 16777215
+    }
+    pub fn set_PixelClock(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !16777215) | (value & 16777215);
+    }
+    pub const fn AspectRatio(&self) -> u32 {
+        (self._bitfield >> 24) & 7
+    }
+    pub fn set_AspectRatio(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 24)) | ((value & 7) << 24);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 27) & 1
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 27)) | ((value & 1) << 27);
+    }
+    pub const fn ScanningType(&self) -> u32 {
+        (self._bitfield >> 28) & 1
+    }
+    pub fn set_ScanningType(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 28)) | ((value & 1) << 28);
+    }
+    pub const fn StereoMode(&self) -> u32 {
+        (self._bitfield >> 29) & 3
+    }
+    pub fn set_StereoMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 29)) | ((value & 3) << 29);
+    }
+    pub const fn PreferredTiming(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_PreferredTiming(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for DISPLAYID_DETAILED_TIMING_TYPE_I_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19313,6 +23322,20 @@ impl Default for DISPLAYID_DETAILED_TIMING_TYPE_I_0 {
 pub struct DISPLAYID_DETAILED_TIMING_TYPE_I_1 {
     pub _bitfield: u16,
 }
+impl DISPLAYID_DETAILED_TIMING_TYPE_I_1 {
+    pub const fn HorizontalFrontPorch(&self) -> u16 {
+        self._bitfield & 32767
+    }
+    pub fn set_HorizontalFrontPorch(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !32767) | (value & 32767);
+    }
+    pub const fn HorizontalSyncPolarity(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_HorizontalSyncPolarity(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for DISPLAYID_DETAILED_TIMING_TYPE_I_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19326,6 +23349,20 @@ impl Default for DISPLAYID_DETAILED_TIMING_TYPE_I_1 {
 pub struct DISPLAYID_DETAILED_TIMING_TYPE_I_2 {
     pub _bitfield: u16,
 }
+impl DISPLAYID_DETAILED_TIMING_TYPE_I_2 {
+    pub const fn VerticalFrontPorch(&self) -> u16 {
+        self._bitfield & 32767
+    }
+    pub fn set_VerticalFrontPorch(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !32767) | (value & 32767);
+    }
+    pub const fn VerticalSyncPolarity(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_VerticalSyncPolarity(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for DISPLAYID_DETAILED_TIMING_TYPE_I_2 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19335,7 +23372,8 @@ impl Default for DISPLAYID_DETAILED_TIMING_TYPE_I_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGKARG_SETPALETTE {
     pub VidPnSourceId: u32,
     pub FirstEntry: u32,
@@ -19464,7 +23502,8 @@ impl Default for DXGKMDT_OPM_CREATE_VIDEO_OUTPUT_FOR_TARGET_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGKMDT_OPM_ENCRYPTED_PARAMETERS {
     pub abEncryptedParameters: [u8; 256],
 }
@@ -19495,7 +23534,8 @@ impl Default for DXGKMDT_OPM_GET_INFO_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGKMDT_OPM_HDCP_KEY_SELECTION_VECTOR {
     pub abKeySelectionVector: [u8; 5],
 }
@@ -19508,7 +23548,8 @@ impl Default for DXGKMDT_OPM_HDCP_KEY_SELECTION_VECTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGKMDT_OPM_OMAC {
     pub abOMAC: [u8; 16],
 }
@@ -19536,7 +23577,8 @@ impl Default for DXGKMDT_OPM_OUTPUT_ID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGKMDT_OPM_RANDOM_NUMBER {
     pub abRandomNumber: [u8; 16],
 }
@@ -19632,7 +23674,8 @@ impl Default for DXGKMDT_OPM_STANDARD_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGKVGPU_ESCAPE_HEAD {
     pub Luid: GPUP_DRIVER_ESCAPE_INPUT,
     pub Type: DXGKVGPU_ESCAPE_TYPE,
@@ -19646,7 +23689,8 @@ impl Default for DXGKVGPU_ESCAPE_HEAD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGKVGPU_ESCAPE_INITIALIZE {
     pub Header: DXGKVGPU_ESCAPE_HEAD,
     pub VmGuid: windows_core::GUID,
@@ -19689,10 +23733,19 @@ impl Default for DXGKVGPU_ESCAPE_PAUSE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGKVGPU_ESCAPE_PAUSE_0_0 {
     pub _bitfield: u32,
 }
+impl DXGKVGPU_ESCAPE_PAUSE_0_0 {
+    pub const fn GuestVmRunning(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_GuestVmRunning(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+}
 impl windows_core::TypeKind for DXGKVGPU_ESCAPE_PAUSE_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19702,7 +23755,8 @@ impl Default for DXGKVGPU_ESCAPE_PAUSE_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGKVGPU_ESCAPE_POWERTRANSITIONCOMPLETE {
     pub Header: DXGKVGPU_ESCAPE_HEAD,
     pub PowerState: u32,
@@ -19716,7 +23770,8 @@ impl Default for DXGKVGPU_ESCAPE_POWERTRANSITIONCOMPLETE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGKVGPU_ESCAPE_READ_PCI_CONFIG {
     pub Header: DXGKVGPU_ESCAPE_HEAD,
     pub Offset: u32,
@@ -19731,7 +23786,8 @@ impl Default for DXGKVGPU_ESCAPE_READ_PCI_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGKVGPU_ESCAPE_READ_VGPU_TYPE {
     pub Header: DXGKVGPU_ESCAPE_HEAD,
 }
@@ -19744,7 +23800,8 @@ impl Default for DXGKVGPU_ESCAPE_READ_VGPU_TYPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGKVGPU_ESCAPE_RELEASE {
     pub Header: DXGKVGPU_ESCAPE_HEAD,
 }
@@ -19757,7 +23814,8 @@ impl Default for DXGKVGPU_ESCAPE_RELEASE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGKVGPU_ESCAPE_RESUME {
     pub Header: DXGKVGPU_ESCAPE_HEAD,
     pub DeviceLuid: super::super::super::Win32::Foundation::LUID,
@@ -19772,7 +23830,8 @@ impl Default for DXGKVGPU_ESCAPE_RESUME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGKVGPU_ESCAPE_WRITE_PCI_CONFIG {
     pub Header: DXGKVGPU_ESCAPE_HEAD,
     pub Offset: u32,
@@ -19871,6 +23930,32 @@ impl Default for DXGK_BRIGHTNESS_CAPS_0 {
 pub struct DXGK_BRIGHTNESS_CAPS_0_0 {
     pub _bitfield: u32,
 }
+impl DXGK_BRIGHTNESS_CAPS_0_0 {
+    pub const fn SmoothBrightness(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_SmoothBrightness(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn AdaptiveBrightness(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_AdaptiveBrightness(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn NitsBrightness(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_NitsBrightness(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for DXGK_BRIGHTNESS_CAPS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19960,6 +24045,32 @@ impl Default for DXGK_BRIGHTNESS_SENSOR_DATA_0 {
 pub struct DXGK_BRIGHTNESS_SENSOR_DATA_0_0 {
     pub _bitfield: u32,
 }
+impl DXGK_BRIGHTNESS_SENSOR_DATA_0_0 {
+    pub const fn AlsReadingValid(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_AlsReadingValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ChromaticityValid(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ChromaticityValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ColorTemperatureValid(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ColorTemperatureValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for DXGK_BRIGHTNESS_SENSOR_DATA_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20029,6 +24140,20 @@ impl Default for DXGK_BRIGHTNESS_STATE_0 {
 pub struct DXGK_BRIGHTNESS_STATE_0_0 {
     pub _bitfield: u32,
 }
+impl DXGK_BRIGHTNESS_STATE_0_0 {
+    pub const fn SmoothBrightness(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_SmoothBrightness(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for DXGK_BRIGHTNESS_STATE_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20038,7 +24163,8 @@ impl Default for DXGK_BRIGHTNESS_STATE_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGK_DISPLAY_INFORMATION {
     pub Width: u32,
     pub Height: u32,
@@ -20057,7 +24183,8 @@ impl Default for DXGK_DISPLAY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGK_ESCAPE_GPUMMUCAPS {
     pub ReadOnlyMemorySupported: super::super::super::Win32::Foundation::BOOLEAN,
     pub NoExecuteMemorySupported: super::super::super::Win32::Foundation::BOOLEAN,
@@ -20110,6 +24237,20 @@ impl Default for DXGK_FAULT_ERROR_CODE_0 {
 pub struct DXGK_FAULT_ERROR_CODE_0_0 {
     pub _bitfield: u32,
 }
+impl DXGK_FAULT_ERROR_CODE_0_0 {
+    pub const fn IsDeviceSpecificCode(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_IsDeviceSpecificCode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn GeneralErrorCode(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_GeneralErrorCode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for DXGK_FAULT_ERROR_CODE_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20123,6 +24264,20 @@ impl Default for DXGK_FAULT_ERROR_CODE_0_0 {
 pub struct DXGK_FAULT_ERROR_CODE_0_1 {
     pub _bitfield: u32,
 }
+impl DXGK_FAULT_ERROR_CODE_0_1 {
+    pub const fn IsDeviceSpecificCodeReservedBit(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_IsDeviceSpecificCodeReservedBit(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DeviceSpecificCode(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_DeviceSpecificCode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for DXGK_FAULT_ERROR_CODE_0_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20179,6 +24334,20 @@ impl Default for DXGK_GPUCLOCKDATA_FLAGS_0 {
 pub struct DXGK_GPUCLOCKDATA_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl DXGK_GPUCLOCKDATA_FLAGS_0_0 {
+    pub const fn ContextManagementProcessor(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ContextManagementProcessor(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for DXGK_GPUCLOCKDATA_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20203,7 +24372,8 @@ impl Default for DXGK_GPUVERSION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Power")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGK_GRAPHICSPOWER_REGISTER_INPUT_V_1_2 {
     pub Version: u32,
     pub PrivateHandle: *mut core::ffi::c_void,
@@ -20224,7 +24394,8 @@ impl Default for DXGK_GRAPHICSPOWER_REGISTER_INPUT_V_1_2 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Power")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGK_GRAPHICSPOWER_REGISTER_OUTPUT {
     pub DeviceHandle: *mut core::ffi::c_void,
     pub InitialGrfxPowerState: super::super::super::Win32::System::Power::DEVICE_POWER_STATE,
@@ -20256,10 +24427,25 @@ impl Default for DXGK_MIRACAST_CHUNK_ID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGK_MIRACAST_CHUNK_ID_0 {
     pub _bitfield: u64,
 }
+impl DXGK_MIRACAST_CHUNK_ID_0 {
+    pub const fn FrameNumber(&self) -> u64 {
+        self._bitfield & 1099511627775
+    }
+    pub fn set_FrameNumber(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1099511627775) | (value & 1099511627775);
+    }
+    pub const fn PartNumber(&self) -> u64 {
+        (self._bitfield >> 40) & 16777215
+    }
+    pub fn set_PartNumber(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(16777215 << 40)) | ((value & 16777215) << 40);
+    }
+}
 impl windows_core::TypeKind for DXGK_MIRACAST_CHUNK_ID_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20303,6 +24489,86 @@ impl Default for DXGK_MONITORLINKINFO_CAPABILITIES {
 pub struct DXGK_MONITORLINKINFO_CAPABILITIES_0 {
     pub _bitfield: u32,
 }
+impl DXGK_MONITORLINKINFO_CAPABILITIES_0 {
+    pub const fn Stereo(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Stereo(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn WideColorSpace(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_WideColorSpace(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn HighColorSpace(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_HighColorSpace(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn DynamicColorSpace(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_DynamicColorSpace(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn DynamicBitsPerColorChannel(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_DynamicBitsPerColorChannel(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn DynamicColorEncodingFormat(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_DynamicColorEncodingFormat(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn DedicatedTimingGeneration(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_DedicatedTimingGeneration(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn TargetIndependentPrimary(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_TargetIndependentPrimary(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn SyncLockIdentical(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_SyncLockIdentical(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Hdr10Plus(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_Hdr10Plus(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn DolbyVisionLowLatency(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_DolbyVisionLowLatency(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn VariableRefresh(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_VariableRefresh(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 12) & 1048575
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1048575 << 12)) | ((value & 1048575) << 12);
+    }
+}
 impl windows_core::TypeKind for DXGK_MONITORLINKINFO_CAPABILITIES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20330,6 +24596,20 @@ impl Default for DXGK_MONITORLINKINFO_USAGEHINTS {
 pub struct DXGK_MONITORLINKINFO_USAGEHINTS_0 {
     pub _bitfield: u32,
 }
+impl DXGK_MONITORLINKINFO_USAGEHINTS_0 {
+    pub const fn Hidden(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Hidden(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for DXGK_MONITORLINKINFO_USAGEHINTS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20387,6 +24667,44 @@ impl Default for DXGK_NODEMETADATA_FLAGS_0 {
 pub struct DXGK_NODEMETADATA_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl DXGK_NODEMETADATA_FLAGS_0_0 {
+    pub const fn ContextSchedulingSupported(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ContextSchedulingSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn RingBufferFenceRelease(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_RingBufferFenceRelease(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn SupportTrackedWorkload(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_SupportTrackedWorkload(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn UserModeSubmission(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_UserModeSubmission(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 4095
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4095 << 4)) | ((value & 4095) << 4);
+    }
+    pub const fn MaxInFlightHwQueueBuffers(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_MaxInFlightHwQueueBuffers(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for DXGK_NODEMETADATA_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20443,10 +24761,79 @@ impl Default for DXGK_PTE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGK_PTE_0_0 {
     pub _bitfield: u64,
 }
+impl DXGK_PTE_0_0 {
+    pub const fn Valid(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_Valid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Zero(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Zero(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn CacheCoherent(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CacheCoherent(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ReadOnly(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ReadOnly(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn NoExecute(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_NoExecute(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Segment(&self) -> u64 {
+        (self._bitfield >> 5) & 31
+    }
+    pub fn set_Segment(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(31 << 5)) | ((value & 31) << 5);
+    }
+    pub const fn LargePage(&self) -> u64 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_LargePage(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn PhysicalAdapterIndex(&self) -> u64 {
+        (self._bitfield >> 11) & 63
+    }
+    pub fn set_PhysicalAdapterIndex(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(63 << 11)) | ((value & 63) << 11);
+    }
+    pub const fn PageTablePageSize(&self) -> u64 {
+        (self._bitfield >> 17) & 3
+    }
+    pub fn set_PageTablePageSize(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(3 << 17)) | ((value & 3) << 17);
+    }
+    pub const fn SystemReserved0(&self) -> u64 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_SystemReserved0(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 20) & 17592186044415
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(17592186044415 << 20)) | ((value & 17592186044415) << 20);
+    }
+}
 impl windows_core::TypeKind for DXGK_PTE_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20485,7 +24872,8 @@ impl Default for DXGK_TARGETMODE_DETAIL_TIMING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GPUP_DRIVER_ESCAPE_INPUT {
     pub vfLUID: super::super::super::Win32::Foundation::LUID,
 }
@@ -20498,7 +24886,8 @@ impl Default for GPUP_DRIVER_ESCAPE_INPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OUTPUTDUPL_CONTEXT_DEBUG_INFO {
     pub Status: OUTPUTDUPL_CONTEXT_DEBUG_STATUS,
     pub ProcessID: super::super::super::Win32::Foundation::HANDLE,
@@ -20516,7 +24905,8 @@ impl Default for OUTPUTDUPL_CONTEXT_DEBUG_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _NT_D3DLINEPATTERN {
     pub wRepeatFactor: u16,
     pub wLinePattern: u16,
@@ -2870,6 +2870,7 @@ pub struct NDIS_802_11_AUTHENTICATION_MODE(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_AUTHENTICATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_AUTHENTICATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_AUTHENTICATION_MODE").field(&self.0).finish()
@@ -2881,6 +2882,7 @@ pub struct NDIS_802_11_MEDIA_STREAM_MODE(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_MEDIA_STREAM_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_MEDIA_STREAM_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_MEDIA_STREAM_MODE").field(&self.0).finish()
@@ -2892,6 +2894,7 @@ pub struct NDIS_802_11_NETWORK_INFRASTRUCTURE(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_NETWORK_INFRASTRUCTURE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_NETWORK_INFRASTRUCTURE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_NETWORK_INFRASTRUCTURE").field(&self.0).finish()
@@ -2903,6 +2906,7 @@ pub struct NDIS_802_11_NETWORK_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_NETWORK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_NETWORK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_NETWORK_TYPE").field(&self.0).finish()
@@ -2914,6 +2918,7 @@ pub struct NDIS_802_11_POWER_MODE(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_POWER_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_POWER_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_POWER_MODE").field(&self.0).finish()
@@ -2925,6 +2930,7 @@ pub struct NDIS_802_11_PRIVACY_FILTER(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_PRIVACY_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_PRIVACY_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_PRIVACY_FILTER").field(&self.0).finish()
@@ -2936,6 +2942,7 @@ pub struct NDIS_802_11_RADIO_STATUS(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_RADIO_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_RADIO_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_RADIO_STATUS").field(&self.0).finish()
@@ -2947,6 +2954,7 @@ pub struct NDIS_802_11_RELOAD_DEFAULTS(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_RELOAD_DEFAULTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_RELOAD_DEFAULTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_RELOAD_DEFAULTS").field(&self.0).finish()
@@ -2958,6 +2966,7 @@ pub struct NDIS_802_11_STATUS_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_STATUS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_STATUS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_STATUS_TYPE").field(&self.0).finish()
@@ -2969,6 +2978,7 @@ pub struct NDIS_802_11_WEP_STATUS(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_WEP_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_WEP_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_WEP_STATUS").field(&self.0).finish()
@@ -2980,6 +2990,7 @@ pub struct NDIS_802_5_RING_STATE(pub i32);
 impl windows_core::TypeKind for NDIS_802_5_RING_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_5_RING_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_5_RING_STATE").field(&self.0).finish()
@@ -2991,6 +3002,7 @@ pub struct NDIS_CLASS_ID(pub i32);
 impl windows_core::TypeKind for NDIS_CLASS_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_CLASS_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_CLASS_ID").field(&self.0).finish()
@@ -3002,6 +3014,7 @@ pub struct NDIS_DEVICE_PNP_EVENT(pub i32);
 impl windows_core::TypeKind for NDIS_DEVICE_PNP_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_DEVICE_PNP_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_DEVICE_PNP_EVENT").field(&self.0).finish()
@@ -3013,6 +3026,7 @@ pub struct NDIS_DEVICE_POWER_STATE(pub i32);
 impl windows_core::TypeKind for NDIS_DEVICE_POWER_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_DEVICE_POWER_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_DEVICE_POWER_STATE").field(&self.0).finish()
@@ -3024,6 +3038,7 @@ pub struct NDIS_ENVIRONMENT_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_ENVIRONMENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_ENVIRONMENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_ENVIRONMENT_TYPE").field(&self.0).finish()
@@ -3035,6 +3050,7 @@ pub struct NDIS_FDDI_ATTACHMENT_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_FDDI_ATTACHMENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_FDDI_ATTACHMENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_FDDI_ATTACHMENT_TYPE").field(&self.0).finish()
@@ -3046,6 +3062,7 @@ pub struct NDIS_FDDI_LCONNECTION_STATE(pub i32);
 impl windows_core::TypeKind for NDIS_FDDI_LCONNECTION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_FDDI_LCONNECTION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_FDDI_LCONNECTION_STATE").field(&self.0).finish()
@@ -3057,6 +3074,7 @@ pub struct NDIS_FDDI_RING_MGT_STATE(pub i32);
 impl windows_core::TypeKind for NDIS_FDDI_RING_MGT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_FDDI_RING_MGT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_FDDI_RING_MGT_STATE").field(&self.0).finish()
@@ -3068,6 +3086,7 @@ pub struct NDIS_HARDWARE_STATUS(pub i32);
 impl windows_core::TypeKind for NDIS_HARDWARE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_HARDWARE_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_HARDWARE_STATUS").field(&self.0).finish()
@@ -3079,6 +3098,7 @@ pub struct NDIS_INTERFACE_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_INTERFACE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_INTERFACE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_INTERFACE_TYPE").field(&self.0).finish()
@@ -3090,6 +3110,7 @@ pub struct NDIS_INTERRUPT_MODERATION(pub i32);
 impl windows_core::TypeKind for NDIS_INTERRUPT_MODERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_INTERRUPT_MODERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_INTERRUPT_MODERATION").field(&self.0).finish()
@@ -3101,6 +3122,7 @@ pub struct NDIS_MEDIA_STATE(pub i32);
 impl windows_core::TypeKind for NDIS_MEDIA_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_MEDIA_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_MEDIA_STATE").field(&self.0).finish()
@@ -3112,6 +3134,7 @@ pub struct NDIS_MEDIUM(pub i32);
 impl windows_core::TypeKind for NDIS_MEDIUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_MEDIUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_MEDIUM").field(&self.0).finish()
@@ -3123,6 +3146,7 @@ pub struct NDIS_NETWORK_CHANGE_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_NETWORK_CHANGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_NETWORK_CHANGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_NETWORK_CHANGE_TYPE").field(&self.0).finish()
@@ -3134,6 +3158,7 @@ pub struct NDIS_PARAMETER_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_PARAMETER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_PARAMETER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_PARAMETER_TYPE").field(&self.0).finish()
@@ -3145,6 +3170,7 @@ pub struct NDIS_PER_PACKET_INFO(pub i32);
 impl windows_core::TypeKind for NDIS_PER_PACKET_INFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_PER_PACKET_INFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_PER_PACKET_INFO").field(&self.0).finish()
@@ -3156,6 +3182,7 @@ pub struct NDIS_PHYSICAL_MEDIUM(pub i32);
 impl windows_core::TypeKind for NDIS_PHYSICAL_MEDIUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_PHYSICAL_MEDIUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_PHYSICAL_MEDIUM").field(&self.0).finish()
@@ -3167,6 +3194,7 @@ pub struct NDIS_PORT_AUTHORIZATION_STATE(pub i32);
 impl windows_core::TypeKind for NDIS_PORT_AUTHORIZATION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_PORT_AUTHORIZATION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_PORT_AUTHORIZATION_STATE").field(&self.0).finish()
@@ -3178,6 +3206,7 @@ pub struct NDIS_PORT_CONTROL_STATE(pub i32);
 impl windows_core::TypeKind for NDIS_PORT_CONTROL_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_PORT_CONTROL_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_PORT_CONTROL_STATE").field(&self.0).finish()
@@ -3189,6 +3218,7 @@ pub struct NDIS_PORT_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_PORT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_PORT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_PORT_TYPE").field(&self.0).finish()
@@ -3200,6 +3230,7 @@ pub struct NDIS_POWER_PROFILE(pub i32);
 impl windows_core::TypeKind for NDIS_POWER_PROFILE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_POWER_PROFILE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_POWER_PROFILE").field(&self.0).finish()
@@ -3211,6 +3242,7 @@ pub struct NDIS_PROCESSOR_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_PROCESSOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_PROCESSOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_PROCESSOR_TYPE").field(&self.0).finish()
@@ -3222,6 +3254,7 @@ pub struct NDIS_PROCESSOR_VENDOR(pub i32);
 impl windows_core::TypeKind for NDIS_PROCESSOR_VENDOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_PROCESSOR_VENDOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_PROCESSOR_VENDOR").field(&self.0).finish()
@@ -3233,6 +3266,7 @@ pub struct NDIS_REQUEST_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_REQUEST_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_REQUEST_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_REQUEST_TYPE").field(&self.0).finish()
@@ -3244,6 +3278,7 @@ pub struct NDIS_SUPPORTED_PAUSE_FUNCTIONS(pub i32);
 impl windows_core::TypeKind for NDIS_SUPPORTED_PAUSE_FUNCTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_SUPPORTED_PAUSE_FUNCTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_SUPPORTED_PAUSE_FUNCTIONS").field(&self.0).finish()
@@ -3255,6 +3290,7 @@ pub struct NDIS_WAN_HEADER_FORMAT(pub i32);
 impl windows_core::TypeKind for NDIS_WAN_HEADER_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_WAN_HEADER_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_WAN_HEADER_FORMAT").field(&self.0).finish()
@@ -3266,6 +3302,7 @@ pub struct NDIS_WAN_MEDIUM_SUBTYPE(pub i32);
 impl windows_core::TypeKind for NDIS_WAN_MEDIUM_SUBTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_WAN_MEDIUM_SUBTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_WAN_MEDIUM_SUBTYPE").field(&self.0).finish()
@@ -3277,6 +3314,7 @@ pub struct NDIS_WAN_QUALITY(pub i32);
 impl windows_core::TypeKind for NDIS_WAN_QUALITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_WAN_QUALITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_WAN_QUALITY").field(&self.0).finish()
@@ -3288,6 +3326,7 @@ pub struct OFFLOAD_CONF_ALGO(pub i32);
 impl windows_core::TypeKind for OFFLOAD_CONF_ALGO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLOAD_CONF_ALGO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLOAD_CONF_ALGO").field(&self.0).finish()
@@ -3299,6 +3338,7 @@ pub struct OFFLOAD_INTEGRITY_ALGO(pub i32);
 impl windows_core::TypeKind for OFFLOAD_INTEGRITY_ALGO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLOAD_INTEGRITY_ALGO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLOAD_INTEGRITY_ALGO").field(&self.0).finish()
@@ -3310,6 +3350,7 @@ pub struct OFFLOAD_OPERATION_E(pub i32);
 impl windows_core::TypeKind for OFFLOAD_OPERATION_E {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLOAD_OPERATION_E {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLOAD_OPERATION_E").field(&self.0).finish()
@@ -3321,13 +3362,15 @@ pub struct UDP_ENCAP_TYPE(pub i32);
 impl windows_core::TypeKind for UDP_ENCAP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDP_ENCAP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDP_ENCAP_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BINARY_DATA {
     pub Length: u16,
     pub Buffer: *mut core::ffi::c_void,
@@ -3341,7 +3384,8 @@ impl Default for BINARY_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BSSID_INFO {
     pub BSSID: [u8; 6],
     pub PMKID: [u8; 16],
@@ -3355,7 +3399,8 @@ impl Default for BSSID_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CO_ADDRESS {
     pub AddressSize: u32,
     pub Address: [u8; 1],
@@ -3369,7 +3414,8 @@ impl Default for CO_ADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CO_ADDRESS_FAMILY {
     pub AddressFamily: u32,
     pub MajorVersion: u32,
@@ -3384,7 +3430,8 @@ impl Default for CO_ADDRESS_FAMILY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CO_ADDRESS_LIST {
     pub NumberOfAddressesAvailable: u32,
     pub NumberOfAddresses: u32,
@@ -3400,7 +3447,8 @@ impl Default for CO_ADDRESS_LIST {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CO_CALL_MANAGER_PARAMETERS {
     pub Transmit: super::super::super::Win32::Networking::WinSock::FLOWSPEC,
     pub Receive: super::super::super::Win32::Networking::WinSock::FLOWSPEC,
@@ -3417,7 +3465,8 @@ impl Default for CO_CALL_MANAGER_PARAMETERS {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CO_CALL_PARAMETERS(pub isize);
 impl Default for CO_CALL_PARAMETERS {
     fn default() -> Self {
@@ -3428,7 +3477,8 @@ impl windows_core::TypeKind for CO_CALL_PARAMETERS {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CO_MEDIA_PARAMETERS(pub isize);
 impl Default for CO_MEDIA_PARAMETERS {
     fn default() -> Self {
@@ -3439,7 +3489,8 @@ impl windows_core::TypeKind for CO_MEDIA_PARAMETERS {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CO_PVC {
     pub NdisAfHandle: *mut core::ffi::c_void,
     pub PvcParameters: CO_SPECIFIC_PARAMETERS,
@@ -3453,7 +3504,8 @@ impl Default for CO_PVC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CO_SAP {
     pub SapType: u32,
     pub SapLength: u32,
@@ -3468,7 +3520,8 @@ impl Default for CO_SAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CO_SPECIFIC_PARAMETERS {
     pub ParamType: u32,
     pub Length: u32,
@@ -3483,7 +3536,8 @@ impl Default for CO_SPECIFIC_PARAMETERS {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ETH_FILTER(pub isize);
 impl Default for ETH_FILTER {
     fn default() -> Self {
@@ -3524,7 +3578,8 @@ impl Default for FILTERDBS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GEN_GET_NETCARD_TIME {
     pub ReadTime: u64,
 }
@@ -3537,7 +3592,8 @@ impl Default for GEN_GET_NETCARD_TIME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GEN_GET_TIME_CAPS {
     pub Flags: u32,
     pub ClockPrecision: u32,
@@ -3551,7 +3607,8 @@ impl Default for GEN_GET_TIME_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LOCK_STATE {
     pub LockState: u16,
     pub OldIrql: u8,
@@ -3565,7 +3622,8 @@ impl Default for LOCK_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MEDIA_SPECIFIC_INFORMATION {
     pub NextEntryOffset: u32,
     pub ClassId: NDIS_CLASS_ID,
@@ -3581,7 +3639,8 @@ impl Default for MEDIA_SPECIFIC_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_AI_REQFI {
     pub Capabilities: u16,
     pub ListenInterval: u16,
@@ -3596,7 +3655,8 @@ impl Default for NDIS_802_11_AI_REQFI {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_AI_RESFI {
     pub Capabilities: u16,
     pub StatusCode: u16,
@@ -3611,7 +3671,8 @@ impl Default for NDIS_802_11_AI_RESFI {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_ASSOCIATION_INFORMATION {
     pub Length: u32,
     pub AvailableRequestFixedIEs: u16,
@@ -3632,7 +3693,8 @@ impl Default for NDIS_802_11_ASSOCIATION_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_AUTHENTICATION_ENCRYPTION {
     pub AuthModeSupported: NDIS_802_11_AUTHENTICATION_MODE,
     pub EncryptStatusSupported: NDIS_802_11_WEP_STATUS,
@@ -3646,7 +3708,8 @@ impl Default for NDIS_802_11_AUTHENTICATION_ENCRYPTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_AUTHENTICATION_EVENT {
     pub Status: NDIS_802_11_STATUS_INDICATION,
     pub Request: [NDIS_802_11_AUTHENTICATION_REQUEST; 1],
@@ -3660,7 +3723,8 @@ impl Default for NDIS_802_11_AUTHENTICATION_EVENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_AUTHENTICATION_REQUEST {
     pub Length: u32,
     pub Bssid: [u8; 6],
@@ -3675,7 +3739,8 @@ impl Default for NDIS_802_11_AUTHENTICATION_REQUEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_BSSID_LIST {
     pub NumberOfItems: u32,
     pub Bssid: [NDIS_WLAN_BSSID; 1],
@@ -3689,7 +3754,8 @@ impl Default for NDIS_802_11_BSSID_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_BSSID_LIST_EX {
     pub NumberOfItems: u32,
     pub Bssid: [NDIS_WLAN_BSSID_EX; 1],
@@ -3703,7 +3769,8 @@ impl Default for NDIS_802_11_BSSID_LIST_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_CAPABILITY {
     pub Length: u32,
     pub Version: u32,
@@ -3720,7 +3787,8 @@ impl Default for NDIS_802_11_CAPABILITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_CONFIGURATION {
     pub Length: u32,
     pub BeaconPeriod: u32,
@@ -3737,7 +3805,8 @@ impl Default for NDIS_802_11_CONFIGURATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_CONFIGURATION_FH {
     pub Length: u32,
     pub HopPattern: u32,
@@ -3753,7 +3822,8 @@ impl Default for NDIS_802_11_CONFIGURATION_FH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_FIXED_IEs {
     pub Timestamp: [u8; 8],
     pub BeaconInterval: u16,
@@ -3768,7 +3838,8 @@ impl Default for NDIS_802_11_FIXED_IEs {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_KEY {
     pub Length: u32,
     pub KeyIndex: u32,
@@ -3786,7 +3857,8 @@ impl Default for NDIS_802_11_KEY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_NETWORK_TYPE_LIST {
     pub NumberOfItems: u32,
     pub NetworkType: [NDIS_802_11_NETWORK_TYPE; 1],
@@ -3800,7 +3872,8 @@ impl Default for NDIS_802_11_NETWORK_TYPE_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_NON_BCAST_SSID_LIST {
     pub NumberOfItems: u32,
     pub Non_Bcast_Ssid: [NDIS_802_11_SSID; 1],
@@ -3814,7 +3887,8 @@ impl Default for NDIS_802_11_NON_BCAST_SSID_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_PMKID {
     pub Length: u32,
     pub BSSIDInfoCount: u32,
@@ -3829,7 +3903,8 @@ impl Default for NDIS_802_11_PMKID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_PMKID_CANDIDATE_LIST {
     pub Version: u32,
     pub NumCandidates: u32,
@@ -3844,7 +3919,8 @@ impl Default for NDIS_802_11_PMKID_CANDIDATE_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_REMOVE_KEY {
     pub Length: u32,
     pub KeyIndex: u32,
@@ -3859,7 +3935,8 @@ impl Default for NDIS_802_11_REMOVE_KEY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_SSID {
     pub SsidLength: u32,
     pub Ssid: [u8; 32],
@@ -3873,7 +3950,8 @@ impl Default for NDIS_802_11_SSID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_STATISTICS {
     pub Length: u32,
     pub TransmittedFragmentCount: i64,
@@ -3910,7 +3988,8 @@ impl Default for NDIS_802_11_STATISTICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_STATUS_INDICATION {
     pub StatusType: NDIS_802_11_STATUS_TYPE,
 }
@@ -3952,7 +4031,8 @@ impl Default for NDIS_802_11_TEST_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_VARIABLE_IEs {
     pub ElementID: u8,
     pub Length: u8,
@@ -3967,7 +4047,8 @@ impl Default for NDIS_802_11_VARIABLE_IEs {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_WEP {
     pub Length: u32,
     pub KeyIndex: u32,
@@ -3983,7 +4064,8 @@ impl Default for NDIS_802_11_WEP {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_AF_LIST(pub isize);
 impl Default for NDIS_AF_LIST {
     fn default() -> Self {
@@ -3994,7 +4076,8 @@ impl windows_core::TypeKind for NDIS_AF_LIST {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_CALL_MANAGER_CHARACTERISTICS(pub isize);
 impl Default for NDIS_CALL_MANAGER_CHARACTERISTICS {
     fn default() -> Self {
@@ -4034,7 +4117,8 @@ impl Default for NDIS_CONFIGURATION_PARAMETER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_CO_DEVICE_PROFILE {
     pub DeviceDescription: NDIS_VAR_DATA_DESC,
     pub DevSpecificInfo: NDIS_VAR_DATA_DESC,
@@ -4074,7 +4158,8 @@ impl Default for NDIS_CO_DEVICE_PROFILE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_CO_LINK_SPEED {
     pub Outbound: u32,
     pub Inbound: u32,
@@ -4109,7 +4194,8 @@ impl Default for NDIS_DMA_BLOCK {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_System_SystemServices")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_DMA_DESCRIPTION {
     pub DemandMode: super::super::super::Win32::Foundation::BOOLEAN,
     pub AutoInitialize: super::super::super::Win32::Foundation::BOOLEAN,
@@ -4176,7 +4262,8 @@ impl Default for NDIS_GUID_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_HARDWARE_CROSSTIMESTAMP {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u32,
@@ -4193,7 +4280,8 @@ impl Default for NDIS_HARDWARE_CROSSTIMESTAMP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_INTERRUPT_MODERATION_PARAMETERS {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u32,
@@ -4208,7 +4296,8 @@ impl Default for NDIS_INTERRUPT_MODERATION_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IPSEC_OFFLOAD_V1 {
     pub Supported: NDIS_IPSEC_OFFLOAD_V1_2,
     pub IPv4AH: NDIS_IPSEC_OFFLOAD_V1_0,
@@ -4223,10 +4312,49 @@ impl Default for NDIS_IPSEC_OFFLOAD_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IPSEC_OFFLOAD_V1_0 {
     pub _bitfield: u32,
 }
+impl NDIS_IPSEC_OFFLOAD_V1_0 {
+    pub const fn Md5(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_Md5(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn Sha_1(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_Sha_1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn Transport(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_Transport(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn Tunnel(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_Tunnel(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn Send(&self) -> u32 {
+        (self._bitfield >> 8) & 3
+    }
+    pub fn set_Send(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 8)) | ((value & 3) << 8);
+    }
+    pub const fn Receive(&self) -> u32 {
+        (self._bitfield >> 10) & 3
+    }
+    pub fn set_Receive(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 10)) | ((value & 3) << 10);
+    }
+}
 impl windows_core::TypeKind for NDIS_IPSEC_OFFLOAD_V1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4236,10 +4364,61 @@ impl Default for NDIS_IPSEC_OFFLOAD_V1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IPSEC_OFFLOAD_V1_1 {
     pub _bitfield: u32,
 }
+impl NDIS_IPSEC_OFFLOAD_V1_1 {
+    pub const fn Des(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_Des(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn TripleDes(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_TripleDes(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn NullEsp(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_NullEsp(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn Transport(&self) -> u32 {
+        (self._bitfield >> 8) & 3
+    }
+    pub fn set_Transport(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 8)) | ((value & 3) << 8);
+    }
+    pub const fn Tunnel(&self) -> u32 {
+        (self._bitfield >> 10) & 3
+    }
+    pub fn set_Tunnel(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 10)) | ((value & 3) << 10);
+    }
+    pub const fn Send(&self) -> u32 {
+        (self._bitfield >> 12) & 3
+    }
+    pub fn set_Send(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 12)) | ((value & 3) << 12);
+    }
+    pub const fn Receive(&self) -> u32 {
+        (self._bitfield >> 14) & 3
+    }
+    pub fn set_Receive(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 14)) | ((value & 3) << 14);
+    }
+}
 impl windows_core::TypeKind for NDIS_IPSEC_OFFLOAD_V1_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4249,7 +4428,8 @@ impl Default for NDIS_IPSEC_OFFLOAD_V1_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IPSEC_OFFLOAD_V1_2 {
     pub Encapsulation: u32,
     pub AhEspCombined: u32,
@@ -4267,7 +4447,8 @@ impl Default for NDIS_IPSEC_OFFLOAD_V1_2 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_NetworkManagement_Ndis")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IP_OPER_STATE {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u32,
@@ -4285,7 +4466,8 @@ impl Default for NDIS_IP_OPER_STATE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_NetworkManagement_Ndis")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IP_OPER_STATUS {
     pub AddressFamily: u32,
     pub OperationalStatus: super::super::super::Win32::NetworkManagement::Ndis::NET_IF_OPER_STATUS,
@@ -4303,7 +4485,8 @@ impl Default for NDIS_IP_OPER_STATUS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_NetworkManagement_Ndis")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IP_OPER_STATUS_INFO {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u32,
@@ -4321,7 +4504,8 @@ impl Default for NDIS_IP_OPER_STATUS_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IRDA_PACKET_INFO {
     pub ExtraBOFs: u32,
     pub MinTurnAroundTime: u32,
@@ -4336,7 +4520,8 @@ impl Default for NDIS_IRDA_PACKET_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_NetworkManagement_Ndis")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_LINK_PARAMETERS {
     pub Header: NDIS_OBJECT_HEADER,
     pub MediaDuplexState: super::super::super::Win32::NetworkManagement::Ndis::NET_IF_MEDIA_DUPLEX_STATE,
@@ -4356,7 +4541,8 @@ impl Default for NDIS_LINK_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_LINK_SPEED {
     pub XmitLinkSpeed: u64,
     pub RcvLinkSpeed: u64,
@@ -4371,7 +4557,8 @@ impl Default for NDIS_LINK_SPEED {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_NetworkManagement_Ndis")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_LINK_STATE {
     pub Header: NDIS_OBJECT_HEADER,
     pub MediaConnectState: super::super::super::Win32::NetworkManagement::Ndis::NET_IF_MEDIA_CONNECT_STATE,
@@ -4392,7 +4579,8 @@ impl Default for NDIS_LINK_STATE {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_MINIPORT_BLOCK(pub isize);
 impl Default for NDIS_MINIPORT_BLOCK {
     fn default() -> Self {
@@ -4424,7 +4612,8 @@ impl Default for NDIS_MINIPORT_TIMER {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_M_DRIVER_BLOCK(pub isize);
 impl Default for NDIS_M_DRIVER_BLOCK {
     fn default() -> Self {
@@ -4435,7 +4624,8 @@ impl windows_core::TypeKind for NDIS_M_DRIVER_BLOCK {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_OBJECT_HEADER {
     pub Type: u8,
     pub Revision: u8,
@@ -4450,7 +4640,8 @@ impl Default for NDIS_OBJECT_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_OFFLOAD {
     pub Header: NDIS_OBJECT_HEADER,
     pub Checksum: NDIS_TCP_IP_CHECKSUM_OFFLOAD,
@@ -4468,7 +4659,8 @@ impl Default for NDIS_OFFLOAD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_OFFLOAD_PARAMETERS {
     pub Header: NDIS_OBJECT_HEADER,
     pub IPv4Checksum: u8,
@@ -4493,7 +4685,8 @@ impl Default for NDIS_OFFLOAD_PARAMETERS {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_OPEN_BLOCK(pub isize);
 impl Default for NDIS_OPEN_BLOCK {
     fn default() -> Self {
@@ -4505,7 +4698,8 @@ impl windows_core::TypeKind for NDIS_OPEN_BLOCK {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_NetworkManagement_Ndis")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_OPER_STATE {
     pub Header: NDIS_OBJECT_HEADER,
     pub OperationalStatus: super::super::super::Win32::NetworkManagement::Ndis::NET_IF_OPER_STATUS,
@@ -4549,10 +4743,37 @@ impl Default for NDIS_PACKET_8021Q_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PACKET_8021Q_INFO_0_0 {
     pub _bitfield: u32,
 }
+impl NDIS_PACKET_8021Q_INFO_0_0 {
+    pub const fn UserPriority(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_UserPriority(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn CanonicalFormatId(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_CanonicalFormatId(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn VlanId(&self) -> u32 {
+        (self._bitfield >> 4) & 4095
+    }
+    pub fn set_VlanId(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4095 << 4)) | ((value & 4095) << 4);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NDIS_PACKET_8021Q_INFO_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4562,7 +4783,8 @@ impl Default for NDIS_PACKET_8021Q_INFO_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PCI_DEVICE_CUSTOM_PROPERTIES {
     pub Header: NDIS_OBJECT_HEADER,
     pub DeviceType: u32,
@@ -4587,7 +4809,8 @@ impl Default for NDIS_PCI_DEVICE_CUSTOM_PROPERTIES {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PD_COUNTER_HANDLE(pub *mut core::ffi::c_void);
 impl NDIS_PD_COUNTER_HANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -4603,7 +4826,8 @@ impl windows_core::TypeKind for NDIS_PD_COUNTER_HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PD_FILTER_HANDLE(pub *mut core::ffi::c_void);
 impl NDIS_PD_FILTER_HANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -4619,7 +4843,8 @@ impl windows_core::TypeKind for NDIS_PD_FILTER_HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PD_PROVIDER_HANDLE(pub *mut core::ffi::c_void);
 impl NDIS_PD_PROVIDER_HANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -4635,7 +4860,8 @@ impl windows_core::TypeKind for NDIS_PD_PROVIDER_HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PHYSICAL_ADDRESS_UNIT {
     pub PhysicalAddress: i64,
     pub Length: u32,
@@ -4649,7 +4875,8 @@ impl Default for NDIS_PHYSICAL_ADDRESS_UNIT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PM_PACKET_PATTERN {
     pub Priority: u32,
     pub Reserved: u32,
@@ -4667,7 +4894,8 @@ impl Default for NDIS_PM_PACKET_PATTERN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PM_WAKE_UP_CAPABILITIES {
     pub MinMagicPacketWakeUp: NDIS_DEVICE_POWER_STATE,
     pub MinPatternWakeUp: NDIS_DEVICE_POWER_STATE,
@@ -4682,7 +4910,8 @@ impl Default for NDIS_PM_WAKE_UP_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PNP_CAPABILITIES {
     pub Flags: u32,
     pub WakeUpCapabilities: NDIS_PM_WAKE_UP_CAPABILITIES,
@@ -4696,7 +4925,8 @@ impl Default for NDIS_PNP_CAPABILITIES {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_POLL_HANDLE(pub *mut core::ffi::c_void);
 impl NDIS_POLL_HANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -4713,7 +4943,8 @@ impl windows_core::TypeKind for NDIS_POLL_HANDLE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_NetworkManagement_Ndis")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PORT {
     pub Next: *mut NDIS_PORT,
     pub NdisReserved: *mut core::ffi::c_void,
@@ -4733,7 +4964,8 @@ impl Default for NDIS_PORT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_NetworkManagement_Ndis")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PORT_ARRAY {
     pub Header: NDIS_OBJECT_HEADER,
     pub NumberOfPorts: u32,
@@ -4752,7 +4984,8 @@ impl Default for NDIS_PORT_ARRAY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PORT_AUTHENTICATION_PARAMETERS {
     pub Header: NDIS_OBJECT_HEADER,
     pub SendControlState: NDIS_PORT_CONTROL_STATE,
@@ -4770,7 +5003,8 @@ impl Default for NDIS_PORT_AUTHENTICATION_PARAMETERS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_NetworkManagement_Ndis")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PORT_CHARACTERISTICS {
     pub Header: NDIS_OBJECT_HEADER,
     pub PortNumber: u32,
@@ -4797,7 +5031,8 @@ impl Default for NDIS_PORT_CHARACTERISTICS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_NetworkManagement_Ndis")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PORT_STATE {
     pub Header: NDIS_OBJECT_HEADER,
     pub MediaConnectState: super::super::super::Win32::NetworkManagement::Ndis::NET_IF_MEDIA_CONNECT_STATE,
@@ -4821,7 +5056,8 @@ impl Default for NDIS_PORT_STATE {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PROTOCOL_BLOCK(pub isize);
 impl Default for NDIS_PROTOCOL_BLOCK {
     fn default() -> Self {
@@ -4832,7 +5068,8 @@ impl windows_core::TypeKind for NDIS_PROTOCOL_BLOCK {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_RECEIVE_HASH_PARAMETERS {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u32,
@@ -4849,7 +5086,8 @@ impl Default for NDIS_RECEIVE_HASH_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_RECEIVE_SCALE_CAPABILITIES {
     pub Header: NDIS_OBJECT_HEADER,
     pub CapabilitiesFlags: u32,
@@ -4865,7 +5103,8 @@ impl Default for NDIS_RECEIVE_SCALE_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_RECEIVE_SCALE_PARAMETERS {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u16,
@@ -4913,7 +5152,8 @@ impl Default for NDIS_RW_LOCK_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_RW_LOCK_0_0 {
     pub SpinLock: usize,
     pub Context: *mut core::ffi::c_void,
@@ -4942,7 +5182,8 @@ impl Default for NDIS_RW_LOCK_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_RW_LOCK_1_0 {
     pub RefCountLock: usize,
     pub SharedRefCount: u32,
@@ -4971,7 +5212,8 @@ impl Default for NDIS_RW_LOCK_REFCOUNT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_SPIN_LOCK {
     pub SpinLock: usize,
     pub OldIrql: u8,
@@ -4985,7 +5227,8 @@ impl Default for NDIS_SPIN_LOCK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_STATISTICS_INFO {
     pub Header: NDIS_OBJECT_HEADER,
     pub SupportedStatistics: u32,
@@ -5017,7 +5260,8 @@ impl Default for NDIS_STATISTICS_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_STATISTICS_VALUE {
     pub Oid: u32,
     pub DataLength: u32,
@@ -5032,7 +5276,8 @@ impl Default for NDIS_STATISTICS_VALUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_STATISTICS_VALUE_EX {
     pub Oid: u32,
     pub DataLength: u32,
@@ -5048,7 +5293,8 @@ impl Default for NDIS_STATISTICS_VALUE_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_CONNECTION_OFFLOAD {
     pub Header: NDIS_OBJECT_HEADER,
     pub Encapsulation: u32,
@@ -5056,6 +5302,32 @@ pub struct NDIS_TCP_CONNECTION_OFFLOAD {
     pub TcpConnectionOffloadCapacity: u32,
     pub Flags: u32,
 }
+impl NDIS_TCP_CONNECTION_OFFLOAD {
+    pub const fn SupportIPv4(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_SupportIPv4(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn SupportIPv6(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_SupportIPv6(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn SupportIPv6ExtensionHeaders(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_SupportIPv6ExtensionHeaders(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn SupportSack(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_SupportSack(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_CONNECTION_OFFLOAD {
     type TypeKind = windows_core::CopyType;
 }
@@ -5065,7 +5337,8 @@ impl Default for NDIS_TCP_CONNECTION_OFFLOAD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_IP_CHECKSUM_OFFLOAD {
     pub IPv4Transmit: NDIS_TCP_IP_CHECKSUM_OFFLOAD_1,
     pub IPv4Receive: NDIS_TCP_IP_CHECKSUM_OFFLOAD_0,
@@ -5081,11 +5354,44 @@ impl Default for NDIS_TCP_IP_CHECKSUM_OFFLOAD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_IP_CHECKSUM_OFFLOAD_0 {
     pub Encapsulation: u32,
     pub _bitfield: u32,
 }
+impl NDIS_TCP_IP_CHECKSUM_OFFLOAD_0 {
+    pub const fn IpOptionsSupported(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_IpOptionsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn TcpOptionsSupported(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_TcpOptionsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn TcpChecksum(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_TcpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn UdpChecksum(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_UdpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn IpChecksum(&self) -> u32 {
+        (self._bitfield >> 8) & 3
+    }
+    pub fn set_IpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 8)) | ((value & 3) << 8);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_IP_CHECKSUM_OFFLOAD_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5095,11 +5401,44 @@ impl Default for NDIS_TCP_IP_CHECKSUM_OFFLOAD_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_IP_CHECKSUM_OFFLOAD_1 {
     pub Encapsulation: u32,
     pub _bitfield: u32,
 }
+impl NDIS_TCP_IP_CHECKSUM_OFFLOAD_1 {
+    pub const fn IpOptionsSupported(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_IpOptionsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn TcpOptionsSupported(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_TcpOptionsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn TcpChecksum(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_TcpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn UdpChecksum(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_UdpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn IpChecksum(&self) -> u32 {
+        (self._bitfield >> 8) & 3
+    }
+    pub fn set_IpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 8)) | ((value & 3) << 8);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_IP_CHECKSUM_OFFLOAD_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5109,11 +5448,38 @@ impl Default for NDIS_TCP_IP_CHECKSUM_OFFLOAD_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_IP_CHECKSUM_OFFLOAD_2 {
     pub Encapsulation: u32,
     pub _bitfield: u32,
 }
+impl NDIS_TCP_IP_CHECKSUM_OFFLOAD_2 {
+    pub const fn IpExtensionHeadersSupported(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_IpExtensionHeadersSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn TcpOptionsSupported(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_TcpOptionsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn TcpChecksum(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_TcpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn UdpChecksum(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_UdpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_IP_CHECKSUM_OFFLOAD_2 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5123,11 +5489,38 @@ impl Default for NDIS_TCP_IP_CHECKSUM_OFFLOAD_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_IP_CHECKSUM_OFFLOAD_3 {
     pub Encapsulation: u32,
     pub _bitfield: u32,
 }
+impl NDIS_TCP_IP_CHECKSUM_OFFLOAD_3 {
+    pub const fn IpExtensionHeadersSupported(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_IpExtensionHeadersSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn TcpOptionsSupported(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_TcpOptionsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn TcpChecksum(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_TcpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn UdpChecksum(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_UdpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_IP_CHECKSUM_OFFLOAD_3 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5165,10 +5558,55 @@ impl Default for NDIS_TCP_IP_CHECKSUM_PACKET_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_IP_CHECKSUM_PACKET_INFO_0_0 {
     pub _bitfield: u32,
 }
+impl NDIS_TCP_IP_CHECKSUM_PACKET_INFO_0_0 {
+    pub const fn NdisPacketTcpChecksumFailed(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_NdisPacketTcpChecksumFailed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NdisPacketUdpChecksumFailed(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NdisPacketUdpChecksumFailed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn NdisPacketIpChecksumFailed(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_NdisPacketIpChecksumFailed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn NdisPacketTcpChecksumSucceeded(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_NdisPacketTcpChecksumSucceeded(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn NdisPacketUdpChecksumSucceeded(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_NdisPacketUdpChecksumSucceeded(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn NdisPacketIpChecksumSucceeded(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_NdisPacketIpChecksumSucceeded(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn NdisPacketLoopback(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_NdisPacketLoopback(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_IP_CHECKSUM_PACKET_INFO_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5178,10 +5616,43 @@ impl Default for NDIS_TCP_IP_CHECKSUM_PACKET_INFO_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_IP_CHECKSUM_PACKET_INFO_0_1 {
     pub _bitfield: u32,
 }
+impl NDIS_TCP_IP_CHECKSUM_PACKET_INFO_0_1 {
+    pub const fn NdisPacketChecksumV4(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_NdisPacketChecksumV4(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NdisPacketChecksumV6(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NdisPacketChecksumV6(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn NdisPacketTcpChecksum(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_NdisPacketTcpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn NdisPacketUdpChecksum(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_NdisPacketUdpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn NdisPacketIpChecksum(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_NdisPacketIpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_IP_CHECKSUM_PACKET_INFO_0_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5191,7 +5662,8 @@ impl Default for NDIS_TCP_IP_CHECKSUM_PACKET_INFO_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_LARGE_SEND_OFFLOAD_V1 {
     pub IPv4: NDIS_TCP_LARGE_SEND_OFFLOAD_V1_0,
 }
@@ -5204,13 +5676,28 @@ impl Default for NDIS_TCP_LARGE_SEND_OFFLOAD_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_LARGE_SEND_OFFLOAD_V1_0 {
     pub Encapsulation: u32,
     pub MaxOffLoadSize: u32,
     pub MinSegmentCount: u32,
     pub _bitfield: u32,
 }
+impl NDIS_TCP_LARGE_SEND_OFFLOAD_V1_0 {
+    pub const fn TcpOptions(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_TcpOptions(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn IpOptions(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_IpOptions(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_LARGE_SEND_OFFLOAD_V1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5220,7 +5707,8 @@ impl Default for NDIS_TCP_LARGE_SEND_OFFLOAD_V1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_LARGE_SEND_OFFLOAD_V2 {
     pub IPv4: NDIS_TCP_LARGE_SEND_OFFLOAD_V2_0,
     pub IPv6: NDIS_TCP_LARGE_SEND_OFFLOAD_V2_1,
@@ -5234,7 +5722,8 @@ impl Default for NDIS_TCP_LARGE_SEND_OFFLOAD_V2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_LARGE_SEND_OFFLOAD_V2_0 {
     pub Encapsulation: u32,
     pub MaxOffLoadSize: u32,
@@ -5249,13 +5738,28 @@ impl Default for NDIS_TCP_LARGE_SEND_OFFLOAD_V2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_LARGE_SEND_OFFLOAD_V2_1 {
     pub Encapsulation: u32,
     pub MaxOffLoadSize: u32,
     pub MinSegmentCount: u32,
     pub _bitfield: u32,
 }
+impl NDIS_TCP_LARGE_SEND_OFFLOAD_V2_1 {
+    pub const fn IpExtensionHeadersSupported(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_IpExtensionHeadersSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn TcpOptionsSupported(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_TcpOptionsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_LARGE_SEND_OFFLOAD_V2_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5265,7 +5769,8 @@ impl Default for NDIS_TCP_LARGE_SEND_OFFLOAD_V2_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TIMEOUT_DPC_REQUEST_CAPABILITIES {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u32,
@@ -5298,7 +5803,8 @@ impl Default for NDIS_TIMER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TIMESTAMP_CAPABILITIES {
     pub Header: NDIS_OBJECT_HEADER,
     pub HardwareClockFrequencyHz: u64,
@@ -5316,7 +5822,8 @@ impl Default for NDIS_TIMESTAMP_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TIMESTAMP_CAPABILITY_FLAGS {
     pub PtpV2OverUdpIPv4EventMsgReceiveHw: super::super::super::Win32::Foundation::BOOLEAN,
     pub PtpV2OverUdpIPv4AllMsgReceiveHw: super::super::super::Win32::Foundation::BOOLEAN,
@@ -5342,7 +5849,8 @@ impl Default for NDIS_TIMESTAMP_CAPABILITY_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_VAR_DATA_DESC {
     pub Length: u16,
     pub MaximumLength: u16,
@@ -5357,7 +5865,8 @@ impl Default for NDIS_VAR_DATA_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WAN_FRAGMENT {
     pub RemoteAddress: [u8; 6],
     pub LocalAddress: [u8; 6],
@@ -5371,7 +5880,8 @@ impl Default for NDIS_WAN_FRAGMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WAN_GET_STATS {
     pub LocalAddress: [u8; 6],
     pub BytesSent: u32,
@@ -5398,7 +5908,8 @@ impl Default for NDIS_WAN_GET_STATS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WAN_LINE_DOWN {
     pub RemoteAddress: [u8; 6],
     pub LocalAddress: [u8; 6],
@@ -5412,7 +5923,8 @@ impl Default for NDIS_WAN_LINE_DOWN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WAN_LINE_UP {
     pub LinkSpeed: u32,
     pub MaximumTotalSize: u32,
@@ -5434,7 +5946,8 @@ impl Default for NDIS_WAN_LINE_UP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WAN_PROTOCOL_CAPS {
     pub Flags: u32,
     pub Reserved: u32,
@@ -5448,7 +5961,8 @@ impl Default for NDIS_WAN_PROTOCOL_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WLAN_BSSID {
     pub Length: u32,
     pub MacAddress: [u8; 6],
@@ -5470,7 +5984,8 @@ impl Default for NDIS_WLAN_BSSID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WLAN_BSSID_EX {
     pub Length: u32,
     pub MacAddress: [u8; 6],
@@ -5537,7 +6052,8 @@ impl Default for NDIS_WMI_EVENT_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_IPSEC_OFFLOAD_V1 {
     pub Supported: NDIS_WMI_IPSEC_OFFLOAD_V1_2,
     pub IPv4AH: NDIS_WMI_IPSEC_OFFLOAD_V1_0,
@@ -5552,7 +6068,8 @@ impl Default for NDIS_WMI_IPSEC_OFFLOAD_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_IPSEC_OFFLOAD_V1_0 {
     pub Md5: u32,
     pub Sha_1: u32,
@@ -5570,7 +6087,8 @@ impl Default for NDIS_WMI_IPSEC_OFFLOAD_V1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_IPSEC_OFFLOAD_V1_1 {
     pub Des: u32,
     pub Reserved: u32,
@@ -5590,7 +6108,8 @@ impl Default for NDIS_WMI_IPSEC_OFFLOAD_V1_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_IPSEC_OFFLOAD_V1_2 {
     pub Encapsulation: u32,
     pub AhEspCombined: u32,
@@ -5628,7 +6147,8 @@ impl Default for NDIS_WMI_METHOD_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_OFFLOAD {
     pub Header: NDIS_OBJECT_HEADER,
     pub Checksum: NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD,
@@ -5646,7 +6166,8 @@ impl Default for NDIS_WMI_OFFLOAD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_OUTPUT_INFO {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u32,
@@ -5683,7 +6204,8 @@ impl Default for NDIS_WMI_SET_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_CONNECTION_OFFLOAD {
     pub Header: NDIS_OBJECT_HEADER,
     pub Encapsulation: u32,
@@ -5703,7 +6225,8 @@ impl Default for NDIS_WMI_TCP_CONNECTION_OFFLOAD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD {
     pub IPv4Transmit: NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_1,
     pub IPv4Receive: NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_0,
@@ -5719,7 +6242,8 @@ impl Default for NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_0 {
     pub Encapsulation: u32,
     pub IpOptionsSupported: u32,
@@ -5737,7 +6261,8 @@ impl Default for NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_1 {
     pub Encapsulation: u32,
     pub IpOptionsSupported: u32,
@@ -5755,7 +6280,8 @@ impl Default for NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_2 {
     pub Encapsulation: u32,
     pub IpExtensionHeadersSupported: u32,
@@ -5772,7 +6298,8 @@ impl Default for NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_3 {
     pub Encapsulation: u32,
     pub IpExtensionHeadersSupported: u32,
@@ -5789,7 +6316,8 @@ impl Default for NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V1 {
     pub IPv4: NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V1_0,
 }
@@ -5802,7 +6330,8 @@ impl Default for NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V1_0 {
     pub Encapsulation: u32,
     pub MaxOffLoadSize: u32,
@@ -5819,7 +6348,8 @@ impl Default for NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2 {
     pub IPv4: NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2_0,
     pub IPv6: NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2_1,
@@ -5833,7 +6363,8 @@ impl Default for NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2_0 {
     pub Encapsulation: u32,
     pub MaxOffLoadSize: u32,
@@ -5848,7 +6379,8 @@ impl Default for NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2_1 {
     pub Encapsulation: u32,
     pub MaxOffLoadSize: u32,
@@ -5865,7 +6397,8 @@ impl Default for NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WORK_ITEM {
     pub Context: *mut core::ffi::c_void,
     pub Routine: NDIS_PROC,
@@ -5880,7 +6413,8 @@ impl Default for NDIS_WORK_ITEM {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WRAPPER_HANDLE(pub isize);
 impl Default for NDIS_WRAPPER_HANDLE {
     fn default() -> Self {
@@ -5891,7 +6425,8 @@ impl windows_core::TypeKind for NDIS_WRAPPER_HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NETWORK_ADDRESS {
     pub AddressLength: u16,
     pub AddressType: u16,
@@ -5906,7 +6441,8 @@ impl Default for NETWORK_ADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NETWORK_ADDRESS_IP {
     pub sin_port: u16,
     pub IN_ADDR: u32,
@@ -5921,7 +6457,8 @@ impl Default for NETWORK_ADDRESS_IP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NETWORK_ADDRESS_IP6 {
     pub sin6_port: u16,
     pub sin6_flowinfo: u32,
@@ -5937,7 +6474,8 @@ impl Default for NETWORK_ADDRESS_IP6 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NETWORK_ADDRESS_IPX {
     pub NetworkAddress: u32,
     pub NodeAddress: [u8; 6],
@@ -5952,7 +6490,8 @@ impl Default for NETWORK_ADDRESS_IPX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NETWORK_ADDRESS_LIST {
     pub AddressCount: i32,
     pub AddressType: u16,
@@ -5967,7 +6506,8 @@ impl Default for NETWORK_ADDRESS_LIST {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NULL_FILTER(pub isize);
 impl Default for NULL_FILTER {
     fn default() -> Self {
@@ -5978,7 +6518,8 @@ impl windows_core::TypeKind for NULL_FILTER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OFFLOAD_ALGO_INFO {
     pub algoIdentifier: u32,
     pub algoKeylen: u32,
@@ -5993,7 +6534,8 @@ impl Default for OFFLOAD_ALGO_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OFFLOAD_IPSEC_ADD_SA {
     pub SrcAddr: u32,
     pub SrcMask: u32,
@@ -6020,7 +6562,8 @@ impl Default for OFFLOAD_IPSEC_ADD_SA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OFFLOAD_IPSEC_ADD_UDPESP_SA {
     pub SrcAddr: u32,
     pub SrcMask: u32,
@@ -6049,7 +6592,8 @@ impl Default for OFFLOAD_IPSEC_ADD_UDPESP_SA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OFFLOAD_IPSEC_DELETE_SA {
     pub OffloadHandle: super::super::super::Win32::Foundation::HANDLE,
 }
@@ -6062,7 +6606,8 @@ impl Default for OFFLOAD_IPSEC_DELETE_SA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OFFLOAD_IPSEC_DELETE_UDPESP_SA {
     pub OffloadHandle: super::super::super::Win32::Foundation::HANDLE,
     pub EncapTypeEntryOffldHandle: super::super::super::Win32::Foundation::HANDLE,
@@ -6076,7 +6621,8 @@ impl Default for OFFLOAD_IPSEC_DELETE_UDPESP_SA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OFFLOAD_IPSEC_UDPESP_ENCAPTYPE_ENTRY {
     pub UdpEncapType: UDP_ENCAP_TYPE,
     pub DstEncapPort: u16,
@@ -6090,7 +6636,8 @@ impl Default for OFFLOAD_IPSEC_UDPESP_ENCAPTYPE_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OFFLOAD_SECURITY_ASSOCIATION {
     pub Operation: OFFLOAD_OPERATION_E,
     pub SPI: u32,
@@ -6107,7 +6654,8 @@ impl Default for OFFLOAD_SECURITY_ASSOCIATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PMKID_CANDIDATE {
     pub BSSID: [u8; 6],
     pub Flags: u32,
@@ -6121,7 +6669,8 @@ impl Default for PMKID_CANDIDATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REFERENCE {
     pub SpinLock: usize,
     pub ReferenceCount: u16,
@@ -6136,7 +6685,8 @@ impl Default for REFERENCE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSPORT_HEADER_OFFSET {
     pub ProtocolType: u16,
     pub HeaderOffset: u16,
@@ -6150,7 +6700,8 @@ impl Default for TRANSPORT_HEADER_OFFSET {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TR_FILTER(pub isize);
 impl Default for TR_FILTER {
     fn default() -> Self {
@@ -6161,7 +6712,8 @@ impl windows_core::TypeKind for TR_FILTER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VAR_STRING {
     pub ulTotalSize: u32,
     pub ulNeededSize: u32,
@@ -52,6 +52,7 @@ pub struct SECTION_INHERIT(pub i32);
 impl windows_core::TypeKind for SECTION_INHERIT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SECTION_INHERIT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SECTION_INHERIT").field(&self.0).finish()
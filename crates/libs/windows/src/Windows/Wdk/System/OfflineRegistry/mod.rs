@@ -203,7 +203,8 @@ pub unsafe fn ORStart() -> super::super::super::Win32::Foundation::WIN32_ERROR {
     ORStart()
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ORHKEY(pub *mut core::ffi::c_void);
 impl ORHKEY {
     pub fn is_invalid(&self) -> bool {
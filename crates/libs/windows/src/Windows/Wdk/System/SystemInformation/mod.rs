@@ -45,6 +45,7 @@ pub struct SYSTEM_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for SYSTEM_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSTEM_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSTEM_INFORMATION_CLASS").field(&self.0).finish()
@@ -10043,6 +10043,7 @@ pub struct ALTERNATIVE_ARCHITECTURE_TYPE(pub i32);
 impl windows_core::TypeKind for ALTERNATIVE_ARCHITECTURE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ALTERNATIVE_ARCHITECTURE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ALTERNATIVE_ARCHITECTURE_TYPE").field(&self.0).finish()
@@ -10054,6 +10055,7 @@ pub struct ARBITER_ACTION(pub i32);
 impl windows_core::TypeKind for ARBITER_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ARBITER_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ARBITER_ACTION").field(&self.0).finish()
@@ -10065,6 +10067,7 @@ pub struct ARBITER_REQUEST_SOURCE(pub i32);
 impl windows_core::TypeKind for ARBITER_REQUEST_SOURCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ARBITER_REQUEST_SOURCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ARBITER_REQUEST_SOURCE").field(&self.0).finish()
@@ -10076,6 +10079,7 @@ pub struct ARBITER_RESULT(pub i32);
 impl windows_core::TypeKind for ARBITER_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ARBITER_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ARBITER_RESULT").field(&self.0).finish()
@@ -10087,6 +10091,7 @@ pub struct BDCB_CALLBACK_TYPE(pub i32);
 impl windows_core::TypeKind for BDCB_CALLBACK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDCB_CALLBACK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDCB_CALLBACK_TYPE").field(&self.0).finish()
@@ -10098,6 +10103,7 @@ pub struct BDCB_CLASSIFICATION(pub i32);
 impl windows_core::TypeKind for BDCB_CLASSIFICATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDCB_CLASSIFICATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDCB_CLASSIFICATION").field(&self.0).finish()
@@ -10109,6 +10115,7 @@ pub struct BDCB_STATUS_UPDATE_TYPE(pub i32);
 impl windows_core::TypeKind for BDCB_STATUS_UPDATE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDCB_STATUS_UPDATE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDCB_STATUS_UPDATE_TYPE").field(&self.0).finish()
@@ -10120,6 +10127,7 @@ pub struct BOUND_CALLBACK_STATUS(pub i32);
 impl windows_core::TypeKind for BOUND_CALLBACK_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BOUND_CALLBACK_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BOUND_CALLBACK_STATUS").field(&self.0).finish()
@@ -10131,6 +10139,7 @@ pub struct BUS_DATA_TYPE(pub i32);
 impl windows_core::TypeKind for BUS_DATA_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BUS_DATA_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BUS_DATA_TYPE").field(&self.0).finish()
@@ -10142,6 +10151,7 @@ pub struct BUS_QUERY_ID_TYPE(pub i32);
 impl windows_core::TypeKind for BUS_QUERY_ID_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BUS_QUERY_ID_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BUS_QUERY_ID_TYPE").field(&self.0).finish()
@@ -10153,6 +10163,7 @@ pub struct CM_SHARE_DISPOSITION(pub i32);
 impl windows_core::TypeKind for CM_SHARE_DISPOSITION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_SHARE_DISPOSITION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_SHARE_DISPOSITION").field(&self.0).finish()
@@ -10164,6 +10175,7 @@ pub struct CONFIGURATION_TYPE(pub i32);
 impl windows_core::TypeKind for CONFIGURATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONFIGURATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONFIGURATION_TYPE").field(&self.0).finish()
@@ -10175,6 +10187,7 @@ pub struct CREATE_FILE_TYPE(pub i32);
 impl windows_core::TypeKind for CREATE_FILE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREATE_FILE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREATE_FILE_TYPE").field(&self.0).finish()
@@ -10186,6 +10199,7 @@ pub struct D3COLD_LAST_TRANSITION_STATUS(pub i32);
 impl windows_core::TypeKind for D3COLD_LAST_TRANSITION_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3COLD_LAST_TRANSITION_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3COLD_LAST_TRANSITION_STATUS").field(&self.0).finish()
@@ -10197,6 +10211,7 @@ pub struct DEVICE_DIRECTORY_TYPE(pub i32);
 impl windows_core::TypeKind for DEVICE_DIRECTORY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVICE_DIRECTORY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVICE_DIRECTORY_TYPE").field(&self.0).finish()
@@ -10208,6 +10223,7 @@ pub struct DEVICE_INSTALL_STATE(pub i32);
 impl windows_core::TypeKind for DEVICE_INSTALL_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVICE_INSTALL_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVICE_INSTALL_STATE").field(&self.0).finish()
@@ -10219,6 +10235,7 @@ pub struct DEVICE_REGISTRY_PROPERTY(pub i32);
 impl windows_core::TypeKind for DEVICE_REGISTRY_PROPERTY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVICE_REGISTRY_PROPERTY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVICE_REGISTRY_PROPERTY").field(&self.0).finish()
@@ -10230,6 +10247,7 @@ pub struct DEVICE_RELATION_TYPE(pub i32);
 impl windows_core::TypeKind for DEVICE_RELATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVICE_RELATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVICE_RELATION_TYPE").field(&self.0).finish()
@@ -10241,6 +10259,7 @@ pub struct DEVICE_REMOVAL_POLICY(pub i32);
 impl windows_core::TypeKind for DEVICE_REMOVAL_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVICE_REMOVAL_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVICE_REMOVAL_POLICY").field(&self.0).finish()
@@ -10252,6 +10271,7 @@ pub struct DEVICE_RESET_TYPE(pub i32);
 impl windows_core::TypeKind for DEVICE_RESET_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVICE_RESET_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVICE_RESET_TYPE").field(&self.0).finish()
@@ -10263,6 +10283,7 @@ pub struct DEVICE_TEXT_TYPE(pub i32);
 impl windows_core::TypeKind for DEVICE_TEXT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVICE_TEXT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVICE_TEXT_TYPE").field(&self.0).finish()
@@ -10274,6 +10295,7 @@ pub struct DEVICE_USAGE_NOTIFICATION_TYPE(pub i32);
 impl windows_core::TypeKind for DEVICE_USAGE_NOTIFICATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVICE_USAGE_NOTIFICATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVICE_USAGE_NOTIFICATION_TYPE").field(&self.0).finish()
@@ -10285,6 +10307,7 @@ pub struct DEVICE_WAKE_DEPTH(pub i32);
 impl windows_core::TypeKind for DEVICE_WAKE_DEPTH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVICE_WAKE_DEPTH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVICE_WAKE_DEPTH").field(&self.0).finish()
@@ -10296,6 +10319,7 @@ pub struct DIRECTORY_NOTIFY_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for DIRECTORY_NOTIFY_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTORY_NOTIFY_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTORY_NOTIFY_INFORMATION_CLASS").field(&self.0).finish()
@@ -10307,6 +10331,7 @@ pub struct DMA_COMMON_BUFFER_EXTENDED_CONFIGURATION_ACCESS_TYPE(pub i32);
 impl windows_core::TypeKind for DMA_COMMON_BUFFER_EXTENDED_CONFIGURATION_ACCESS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DMA_COMMON_BUFFER_EXTENDED_CONFIGURATION_ACCESS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DMA_COMMON_BUFFER_EXTENDED_CONFIGURATION_ACCESS_TYPE").field(&self.0).finish()
@@ -10318,6 +10343,7 @@ pub struct DMA_COMMON_BUFFER_EXTENDED_CONFIGURATION_TYPE(pub i32);
 impl windows_core::TypeKind for DMA_COMMON_BUFFER_EXTENDED_CONFIGURATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DMA_COMMON_BUFFER_EXTENDED_CONFIGURATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DMA_COMMON_BUFFER_EXTENDED_CONFIGURATION_TYPE").field(&self.0).finish()
@@ -10329,6 +10355,7 @@ pub struct DMA_COMPLETION_STATUS(pub i32);
 impl windows_core::TypeKind for DMA_COMPLETION_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DMA_COMPLETION_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DMA_COMPLETION_STATUS").field(&self.0).finish()
@@ -10340,6 +10367,7 @@ pub struct DMA_SPEED(pub i32);
 impl windows_core::TypeKind for DMA_SPEED {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DMA_SPEED {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DMA_SPEED").field(&self.0).finish()
@@ -10351,6 +10379,7 @@ pub struct DMA_WIDTH(pub i32);
 impl windows_core::TypeKind for DMA_WIDTH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DMA_WIDTH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DMA_WIDTH").field(&self.0).finish()
@@ -10362,6 +10391,7 @@ pub struct DOMAIN_CONFIGURATION_ARCH(pub i32);
 impl windows_core::TypeKind for DOMAIN_CONFIGURATION_ARCH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DOMAIN_CONFIGURATION_ARCH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DOMAIN_CONFIGURATION_ARCH").field(&self.0).finish()
@@ -10373,6 +10403,7 @@ pub struct DRIVER_DIRECTORY_TYPE(pub i32);
 impl windows_core::TypeKind for DRIVER_DIRECTORY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRIVER_DIRECTORY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRIVER_DIRECTORY_TYPE").field(&self.0).finish()
@@ -10384,6 +10415,7 @@ pub struct DRIVER_REGKEY_TYPE(pub i32);
 impl windows_core::TypeKind for DRIVER_REGKEY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRIVER_REGKEY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRIVER_REGKEY_TYPE").field(&self.0).finish()
@@ -10395,6 +10427,7 @@ pub struct DRIVER_RUNTIME_INIT_FLAGS(pub i32);
 impl windows_core::TypeKind for DRIVER_RUNTIME_INIT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRIVER_RUNTIME_INIT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRIVER_RUNTIME_INIT_FLAGS").field(&self.0).finish()
@@ -10406,6 +10439,7 @@ pub struct EXTENDED_AGP_REGISTER(pub i32);
 impl windows_core::TypeKind for EXTENDED_AGP_REGISTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EXTENDED_AGP_REGISTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EXTENDED_AGP_REGISTER").field(&self.0).finish()
@@ -10417,6 +10451,7 @@ pub struct EX_POOL_PRIORITY(pub i32);
 impl windows_core::TypeKind for EX_POOL_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EX_POOL_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EX_POOL_PRIORITY").field(&self.0).finish()
@@ -10428,6 +10463,7 @@ pub struct FAULT_INFORMATION_ARCH(pub i32);
 impl windows_core::TypeKind for FAULT_INFORMATION_ARCH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAULT_INFORMATION_ARCH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAULT_INFORMATION_ARCH").field(&self.0).finish()
@@ -10439,6 +10475,7 @@ pub struct FAULT_INFORMATION_ARM64_TYPE(pub i32);
 impl windows_core::TypeKind for FAULT_INFORMATION_ARM64_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAULT_INFORMATION_ARM64_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAULT_INFORMATION_ARM64_TYPE").field(&self.0).finish()
@@ -10450,6 +10487,7 @@ pub struct HAL_APIC_DESTINATION_MODE(pub i32);
 impl windows_core::TypeKind for HAL_APIC_DESTINATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HAL_APIC_DESTINATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HAL_APIC_DESTINATION_MODE").field(&self.0).finish()
@@ -10461,6 +10499,7 @@ pub struct HAL_DISPLAY_BIOS_INFORMATION(pub i32);
 impl windows_core::TypeKind for HAL_DISPLAY_BIOS_INFORMATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HAL_DISPLAY_BIOS_INFORMATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HAL_DISPLAY_BIOS_INFORMATION").field(&self.0).finish()
@@ -10472,6 +10511,7 @@ pub struct HAL_DMA_CRASH_DUMP_REGISTER_TYPE(pub i32);
 impl windows_core::TypeKind for HAL_DMA_CRASH_DUMP_REGISTER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HAL_DMA_CRASH_DUMP_REGISTER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HAL_DMA_CRASH_DUMP_REGISTER_TYPE").field(&self.0).finish()
@@ -10483,6 +10523,7 @@ pub struct HAL_QUERY_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for HAL_QUERY_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HAL_QUERY_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HAL_QUERY_INFORMATION_CLASS").field(&self.0).finish()
@@ -10494,6 +10535,7 @@ pub struct HAL_SET_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for HAL_SET_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HAL_SET_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HAL_SET_INFORMATION_CLASS").field(&self.0).finish()
@@ -10505,6 +10547,7 @@ pub struct HARDWARE_COUNTER_TYPE(pub i32);
 impl windows_core::TypeKind for HARDWARE_COUNTER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HARDWARE_COUNTER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HARDWARE_COUNTER_TYPE").field(&self.0).finish()
@@ -10516,6 +10559,7 @@ pub struct INTEL_CACHE_TYPE(pub i32);
 impl windows_core::TypeKind for INTEL_CACHE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INTEL_CACHE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INTEL_CACHE_TYPE").field(&self.0).finish()
@@ -10527,6 +10571,7 @@ pub struct INTERFACE_TYPE(pub i32);
 impl windows_core::TypeKind for INTERFACE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INTERFACE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INTERFACE_TYPE").field(&self.0).finish()
@@ -10538,6 +10583,7 @@ pub struct INTERLOCKED_RESULT(pub i32);
 impl windows_core::TypeKind for INTERLOCKED_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INTERLOCKED_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INTERLOCKED_RESULT").field(&self.0).finish()
@@ -10549,6 +10595,7 @@ pub struct IOMMU_DEVICE_CREATION_CONFIGURATION_TYPE(pub i32);
 impl windows_core::TypeKind for IOMMU_DEVICE_CREATION_CONFIGURATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IOMMU_DEVICE_CREATION_CONFIGURATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IOMMU_DEVICE_CREATION_CONFIGURATION_TYPE").field(&self.0).finish()
@@ -10560,6 +10607,7 @@ pub struct IOMMU_DMA_DOMAIN_TYPE(pub i32);
 impl windows_core::TypeKind for IOMMU_DMA_DOMAIN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IOMMU_DMA_DOMAIN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IOMMU_DMA_DOMAIN_TYPE").field(&self.0).finish()
@@ -10571,6 +10619,7 @@ pub struct IOMMU_DMA_LOGICAL_ALLOCATOR_TYPE(pub i32);
 impl windows_core::TypeKind for IOMMU_DMA_LOGICAL_ALLOCATOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IOMMU_DMA_LOGICAL_ALLOCATOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IOMMU_DMA_LOGICAL_ALLOCATOR_TYPE").field(&self.0).finish()
@@ -10582,6 +10631,7 @@ pub struct IOMMU_MAP_PHYSICAL_ADDRESS_TYPE(pub i32);
 impl windows_core::TypeKind for IOMMU_MAP_PHYSICAL_ADDRESS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IOMMU_MAP_PHYSICAL_ADDRESS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IOMMU_MAP_PHYSICAL_ADDRESS_TYPE").field(&self.0).finish()
@@ -10593,6 +10643,7 @@ pub struct IO_ACCESS_MODE(pub i32);
 impl windows_core::TypeKind for IO_ACCESS_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IO_ACCESS_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IO_ACCESS_MODE").field(&self.0).finish()
@@ -10604,6 +10655,7 @@ pub struct IO_ACCESS_TYPE(pub i32);
 impl windows_core::TypeKind for IO_ACCESS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IO_ACCESS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IO_ACCESS_TYPE").field(&self.0).finish()
@@ -10615,6 +10667,7 @@ pub struct IO_ALLOCATION_ACTION(pub i32);
 impl windows_core::TypeKind for IO_ALLOCATION_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IO_ALLOCATION_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IO_ALLOCATION_ACTION").field(&self.0).finish()
@@ -10626,6 +10679,7 @@ pub struct IO_COMPLETION_ROUTINE_RESULT(pub i32);
 impl windows_core::TypeKind for IO_COMPLETION_ROUTINE_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IO_COMPLETION_ROUTINE_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IO_COMPLETION_ROUTINE_RESULT").field(&self.0).finish()
@@ -10637,6 +10691,7 @@ pub struct IO_CONTAINER_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for IO_CONTAINER_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IO_CONTAINER_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IO_CONTAINER_INFORMATION_CLASS").field(&self.0).finish()
@@ -10648,6 +10703,7 @@ pub struct IO_CONTAINER_NOTIFICATION_CLASS(pub i32);
 impl windows_core::TypeKind for IO_CONTAINER_NOTIFICATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IO_CONTAINER_NOTIFICATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IO_CONTAINER_NOTIFICATION_CLASS").field(&self.0).finish()
@@ -10659,6 +10715,7 @@ pub struct IO_NOTIFICATION_EVENT_CATEGORY(pub i32);
 impl windows_core::TypeKind for IO_NOTIFICATION_EVENT_CATEGORY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IO_NOTIFICATION_EVENT_CATEGORY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IO_NOTIFICATION_EVENT_CATEGORY").field(&self.0).finish()
@@ -10670,6 +10727,7 @@ pub struct IO_PAGING_PRIORITY(pub i32);
 impl windows_core::TypeKind for IO_PAGING_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IO_PAGING_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IO_PAGING_PRIORITY").field(&self.0).finish()
@@ -10681,6 +10739,7 @@ pub struct IO_QUERY_DEVICE_DATA_FORMAT(pub i32);
 impl windows_core::TypeKind for IO_QUERY_DEVICE_DATA_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IO_QUERY_DEVICE_DATA_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IO_QUERY_DEVICE_DATA_FORMAT").field(&self.0).finish()
@@ -10692,6 +10751,7 @@ pub struct IO_SESSION_EVENT(pub i32);
 impl windows_core::TypeKind for IO_SESSION_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IO_SESSION_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IO_SESSION_EVENT").field(&self.0).finish()
@@ -10703,6 +10763,7 @@ pub struct IO_SESSION_STATE(pub i32);
 impl windows_core::TypeKind for IO_SESSION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IO_SESSION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IO_SESSION_STATE").field(&self.0).finish()
@@ -10714,6 +10775,7 @@ pub struct IRQ_DEVICE_POLICY(pub i32);
 impl windows_core::TypeKind for IRQ_DEVICE_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IRQ_DEVICE_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IRQ_DEVICE_POLICY").field(&self.0).finish()
@@ -10725,6 +10787,7 @@ pub struct IRQ_GROUP_POLICY(pub i32);
 impl windows_core::TypeKind for IRQ_GROUP_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IRQ_GROUP_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IRQ_GROUP_POLICY").field(&self.0).finish()
@@ -10736,6 +10799,7 @@ pub struct IRQ_PRIORITY(pub i32);
 impl windows_core::TypeKind for IRQ_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IRQ_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IRQ_PRIORITY").field(&self.0).finish()
@@ -10747,6 +10811,7 @@ pub struct KBUGCHECK_BUFFER_DUMP_STATE(pub i32);
 impl windows_core::TypeKind for KBUGCHECK_BUFFER_DUMP_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KBUGCHECK_BUFFER_DUMP_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KBUGCHECK_BUFFER_DUMP_STATE").field(&self.0).finish()
@@ -10758,6 +10823,7 @@ pub struct KBUGCHECK_CALLBACK_REASON(pub i32);
 impl windows_core::TypeKind for KBUGCHECK_CALLBACK_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KBUGCHECK_CALLBACK_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KBUGCHECK_CALLBACK_REASON").field(&self.0).finish()
@@ -10769,6 +10835,7 @@ pub struct KBUGCHECK_DUMP_IO_TYPE(pub i32);
 impl windows_core::TypeKind for KBUGCHECK_DUMP_IO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KBUGCHECK_DUMP_IO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KBUGCHECK_DUMP_IO_TYPE").field(&self.0).finish()
@@ -10780,6 +10847,7 @@ pub struct KDPC_IMPORTANCE(pub i32);
 impl windows_core::TypeKind for KDPC_IMPORTANCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KDPC_IMPORTANCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KDPC_IMPORTANCE").field(&self.0).finish()
@@ -10791,6 +10859,7 @@ pub struct KD_CALLBACK_ACTION(pub i32);
 impl windows_core::TypeKind for KD_CALLBACK_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KD_CALLBACK_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KD_CALLBACK_ACTION").field(&self.0).finish()
@@ -10802,6 +10871,7 @@ pub struct KD_NAMESPACE_ENUM(pub i32);
 impl windows_core::TypeKind for KD_NAMESPACE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KD_NAMESPACE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KD_NAMESPACE_ENUM").field(&self.0).finish()
@@ -10813,6 +10883,7 @@ pub struct KD_OPTION(pub i32);
 impl windows_core::TypeKind for KD_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KD_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KD_OPTION").field(&self.0).finish()
@@ -10824,6 +10895,7 @@ pub struct KE_PROCESSOR_CHANGE_NOTIFY_STATE(pub i32);
 impl windows_core::TypeKind for KE_PROCESSOR_CHANGE_NOTIFY_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KE_PROCESSOR_CHANGE_NOTIFY_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KE_PROCESSOR_CHANGE_NOTIFY_STATE").field(&self.0).finish()
@@ -10835,6 +10907,7 @@ pub struct KINTERRUPT_MODE(pub i32);
 impl windows_core::TypeKind for KINTERRUPT_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KINTERRUPT_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KINTERRUPT_MODE").field(&self.0).finish()
@@ -10846,6 +10919,7 @@ pub struct KINTERRUPT_POLARITY(pub i32);
 impl windows_core::TypeKind for KINTERRUPT_POLARITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KINTERRUPT_POLARITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KINTERRUPT_POLARITY").field(&self.0).finish()
@@ -10857,6 +10931,7 @@ pub struct KPROFILE_SOURCE(pub i32);
 impl windows_core::TypeKind for KPROFILE_SOURCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KPROFILE_SOURCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KPROFILE_SOURCE").field(&self.0).finish()
@@ -10868,6 +10943,7 @@ pub struct KWAIT_REASON(pub i32);
 impl windows_core::TypeKind for KWAIT_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KWAIT_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KWAIT_REASON").field(&self.0).finish()
@@ -10879,6 +10955,7 @@ pub struct LOCK_OPERATION(pub i32);
 impl windows_core::TypeKind for LOCK_OPERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LOCK_OPERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LOCK_OPERATION").field(&self.0).finish()
@@ -10890,6 +10967,7 @@ pub struct MCA_EXCEPTION_TYPE(pub i32);
 impl windows_core::TypeKind for MCA_EXCEPTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MCA_EXCEPTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MCA_EXCEPTION_TYPE").field(&self.0).finish()
@@ -10901,6 +10979,7 @@ pub struct MEMORY_CACHING_TYPE(pub i32);
 impl windows_core::TypeKind for MEMORY_CACHING_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEMORY_CACHING_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEMORY_CACHING_TYPE").field(&self.0).finish()
@@ -10912,6 +10991,7 @@ pub struct MEMORY_CACHING_TYPE_ORIG(pub i32);
 impl windows_core::TypeKind for MEMORY_CACHING_TYPE_ORIG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEMORY_CACHING_TYPE_ORIG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEMORY_CACHING_TYPE_ORIG").field(&self.0).finish()
@@ -10923,6 +11003,7 @@ pub struct MEM_DEDICATED_ATTRIBUTE_TYPE(pub i32);
 impl windows_core::TypeKind for MEM_DEDICATED_ATTRIBUTE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEM_DEDICATED_ATTRIBUTE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEM_DEDICATED_ATTRIBUTE_TYPE").field(&self.0).finish()
@@ -10934,6 +11015,7 @@ pub struct MEM_SECTION_EXTENDED_PARAMETER_TYPE(pub i32);
 impl windows_core::TypeKind for MEM_SECTION_EXTENDED_PARAMETER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEM_SECTION_EXTENDED_PARAMETER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEM_SECTION_EXTENDED_PARAMETER_TYPE").field(&self.0).finish()
@@ -10945,6 +11027,7 @@ pub struct MM_MDL_PAGE_CONTENTS_STATE(pub i32);
 impl windows_core::TypeKind for MM_MDL_PAGE_CONTENTS_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MM_MDL_PAGE_CONTENTS_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MM_MDL_PAGE_CONTENTS_STATE").field(&self.0).finish()
@@ -10956,6 +11039,7 @@ pub struct MM_PAGE_PRIORITY(pub i32);
 impl windows_core::TypeKind for MM_PAGE_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MM_PAGE_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MM_PAGE_PRIORITY").field(&self.0).finish()
@@ -10967,6 +11051,7 @@ pub struct MM_ROTATE_DIRECTION(pub i32);
 impl windows_core::TypeKind for MM_ROTATE_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MM_ROTATE_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MM_ROTATE_DIRECTION").field(&self.0).finish()
@@ -10978,6 +11063,7 @@ pub struct MM_SYSTEMSIZE(pub i32);
 impl windows_core::TypeKind for MM_SYSTEMSIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MM_SYSTEMSIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MM_SYSTEMSIZE").field(&self.0).finish()
@@ -10989,6 +11075,7 @@ pub struct MODE(pub i32);
 impl windows_core::TypeKind for MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MODE").field(&self.0).finish()
@@ -11000,6 +11087,7 @@ pub struct NPEM_CONTROL_STANDARD_CONTROL_BIT(pub i32);
 impl windows_core::TypeKind for NPEM_CONTROL_STANDARD_CONTROL_BIT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NPEM_CONTROL_STANDARD_CONTROL_BIT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NPEM_CONTROL_STANDARD_CONTROL_BIT").field(&self.0).finish()
@@ -11011,6 +11099,7 @@ pub struct OB_PREOP_CALLBACK_STATUS(pub i32);
 impl windows_core::TypeKind for OB_PREOP_CALLBACK_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OB_PREOP_CALLBACK_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OB_PREOP_CALLBACK_STATUS").field(&self.0).finish()
@@ -11022,6 +11111,7 @@ pub struct PARTITION_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for PARTITION_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PARTITION_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PARTITION_INFORMATION_CLASS").field(&self.0).finish()
@@ -11033,6 +11123,7 @@ pub struct PCI_ACS_BIT(pub i32);
 impl windows_core::TypeKind for PCI_ACS_BIT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_ACS_BIT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_ACS_BIT").field(&self.0).finish()
@@ -11044,6 +11135,7 @@ pub struct PCI_BUS_WIDTH(pub i32);
 impl windows_core::TypeKind for PCI_BUS_WIDTH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_BUS_WIDTH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_BUS_WIDTH").field(&self.0).finish()
@@ -11055,6 +11147,7 @@ pub struct PCI_DEVICE_D3COLD_STATE_REASON(pub i32);
 impl windows_core::TypeKind for PCI_DEVICE_D3COLD_STATE_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_DEVICE_D3COLD_STATE_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_DEVICE_D3COLD_STATE_REASON").field(&self.0).finish()
@@ -11066,6 +11159,7 @@ pub struct PCI_EXPRESS_ASPM_CONTROL(pub i32);
 impl windows_core::TypeKind for PCI_EXPRESS_ASPM_CONTROL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_EXPRESS_ASPM_CONTROL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_EXPRESS_ASPM_CONTROL").field(&self.0).finish()
@@ -11077,6 +11171,7 @@ pub struct PCI_EXPRESS_ASPM_SUPPORT(pub i32);
 impl windows_core::TypeKind for PCI_EXPRESS_ASPM_SUPPORT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_EXPRESS_ASPM_SUPPORT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_EXPRESS_ASPM_SUPPORT").field(&self.0).finish()
@@ -11088,6 +11183,7 @@ pub struct PCI_EXPRESS_CARD_PRESENCE(pub i32);
 impl windows_core::TypeKind for PCI_EXPRESS_CARD_PRESENCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_EXPRESS_CARD_PRESENCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_EXPRESS_CARD_PRESENCE").field(&self.0).finish()
@@ -11099,6 +11195,7 @@ pub struct PCI_EXPRESS_DEVICE_TYPE(pub i32);
 impl windows_core::TypeKind for PCI_EXPRESS_DEVICE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_EXPRESS_DEVICE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_EXPRESS_DEVICE_TYPE").field(&self.0).finish()
@@ -11110,6 +11207,7 @@ pub struct PCI_EXPRESS_INDICATOR_STATE(pub i32);
 impl windows_core::TypeKind for PCI_EXPRESS_INDICATOR_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_EXPRESS_INDICATOR_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_EXPRESS_INDICATOR_STATE").field(&self.0).finish()
@@ -11121,6 +11219,7 @@ pub struct PCI_EXPRESS_L0s_EXIT_LATENCY(pub i32);
 impl windows_core::TypeKind for PCI_EXPRESS_L0s_EXIT_LATENCY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_EXPRESS_L0s_EXIT_LATENCY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_EXPRESS_L0s_EXIT_LATENCY").field(&self.0).finish()
@@ -11132,6 +11231,7 @@ pub struct PCI_EXPRESS_L1_EXIT_LATENCY(pub i32);
 impl windows_core::TypeKind for PCI_EXPRESS_L1_EXIT_LATENCY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_EXPRESS_L1_EXIT_LATENCY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_EXPRESS_L1_EXIT_LATENCY").field(&self.0).finish()
@@ -11143,6 +11243,7 @@ pub struct PCI_EXPRESS_LINK_SUBSTATE(pub i32);
 impl windows_core::TypeKind for PCI_EXPRESS_LINK_SUBSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_EXPRESS_LINK_SUBSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_EXPRESS_LINK_SUBSTATE").field(&self.0).finish()
@@ -11154,6 +11255,7 @@ pub struct PCI_EXPRESS_MAX_PAYLOAD_SIZE(pub i32);
 impl windows_core::TypeKind for PCI_EXPRESS_MAX_PAYLOAD_SIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_EXPRESS_MAX_PAYLOAD_SIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_EXPRESS_MAX_PAYLOAD_SIZE").field(&self.0).finish()
@@ -11165,6 +11267,7 @@ pub struct PCI_EXPRESS_MRL_STATE(pub i32);
 impl windows_core::TypeKind for PCI_EXPRESS_MRL_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_EXPRESS_MRL_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_EXPRESS_MRL_STATE").field(&self.0).finish()
@@ -11176,6 +11279,7 @@ pub struct PCI_EXPRESS_POWER_STATE(pub i32);
 impl windows_core::TypeKind for PCI_EXPRESS_POWER_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_EXPRESS_POWER_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_EXPRESS_POWER_STATE").field(&self.0).finish()
@@ -11187,6 +11291,7 @@ pub struct PCI_EXPRESS_RCB(pub i32);
 impl windows_core::TypeKind for PCI_EXPRESS_RCB {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_EXPRESS_RCB {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_EXPRESS_RCB").field(&self.0).finish()
@@ -11198,6 +11303,7 @@ pub struct PCI_HARDWARE_INTERFACE(pub i32);
 impl windows_core::TypeKind for PCI_HARDWARE_INTERFACE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCI_HARDWARE_INTERFACE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCI_HARDWARE_INTERFACE").field(&self.0).finish()
@@ -11209,6 +11315,7 @@ pub struct PCW_CALLBACK_TYPE(pub i32);
 impl windows_core::TypeKind for PCW_CALLBACK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCW_CALLBACK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCW_CALLBACK_TYPE").field(&self.0).finish()
@@ -11220,6 +11327,7 @@ pub struct PCW_REGISTRATION_FLAGS(pub i32);
 impl windows_core::TypeKind for PCW_REGISTRATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCW_REGISTRATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCW_REGISTRATION_FLAGS").field(&self.0).finish()
@@ -11231,6 +11339,7 @@ pub struct PHYSICAL_COUNTER_RESOURCE_DESCRIPTOR_TYPE(pub i32);
 impl windows_core::TypeKind for PHYSICAL_COUNTER_RESOURCE_DESCRIPTOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PHYSICAL_COUNTER_RESOURCE_DESCRIPTOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PHYSICAL_COUNTER_RESOURCE_DESCRIPTOR_TYPE").field(&self.0).finish()
@@ -11242,6 +11351,7 @@ pub struct POOL_EXTENDED_PARAMETER_TYPE(pub i32);
 impl windows_core::TypeKind for POOL_EXTENDED_PARAMETER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POOL_EXTENDED_PARAMETER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POOL_EXTENDED_PARAMETER_TYPE").field(&self.0).finish()
@@ -11253,6 +11363,7 @@ pub struct POWER_MONITOR_REQUEST_REASON(pub i32);
 impl windows_core::TypeKind for POWER_MONITOR_REQUEST_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POWER_MONITOR_REQUEST_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POWER_MONITOR_REQUEST_REASON").field(&self.0).finish()
@@ -11264,6 +11375,7 @@ pub struct POWER_MONITOR_REQUEST_TYPE(pub i32);
 impl windows_core::TypeKind for POWER_MONITOR_REQUEST_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POWER_MONITOR_REQUEST_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POWER_MONITOR_REQUEST_TYPE").field(&self.0).finish()
@@ -11275,6 +11387,7 @@ pub struct POWER_PLATFORM_ROLE(pub i32);
 impl windows_core::TypeKind for POWER_PLATFORM_ROLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POWER_PLATFORM_ROLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POWER_PLATFORM_ROLE").field(&self.0).finish()
@@ -11286,6 +11399,7 @@ pub struct POWER_STATE_TYPE(pub i32);
 impl windows_core::TypeKind for POWER_STATE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POWER_STATE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POWER_STATE_TYPE").field(&self.0).finish()
@@ -11297,6 +11411,7 @@ pub struct POWER_USER_PRESENCE_TYPE(pub i32);
 impl windows_core::TypeKind for POWER_USER_PRESENCE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POWER_USER_PRESENCE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POWER_USER_PRESENCE_TYPE").field(&self.0).finish()
@@ -11308,6 +11423,7 @@ pub struct PO_FX_PERF_STATE_TYPE(pub i32);
 impl windows_core::TypeKind for PO_FX_PERF_STATE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PO_FX_PERF_STATE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PO_FX_PERF_STATE_TYPE").field(&self.0).finish()
@@ -11319,6 +11435,7 @@ pub struct PO_FX_PERF_STATE_UNIT(pub i32);
 impl windows_core::TypeKind for PO_FX_PERF_STATE_UNIT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PO_FX_PERF_STATE_UNIT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PO_FX_PERF_STATE_UNIT").field(&self.0).finish()
@@ -11330,6 +11447,7 @@ pub struct PO_THERMAL_REQUEST_TYPE(pub i32);
 impl windows_core::TypeKind for PO_THERMAL_REQUEST_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PO_THERMAL_REQUEST_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PO_THERMAL_REQUEST_TYPE").field(&self.0).finish()
@@ -11341,6 +11459,7 @@ pub struct PSCREATEPROCESSNOTIFYTYPE(pub i32);
 impl windows_core::TypeKind for PSCREATEPROCESSNOTIFYTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PSCREATEPROCESSNOTIFYTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PSCREATEPROCESSNOTIFYTYPE").field(&self.0).finish()
@@ -11352,6 +11471,7 @@ pub struct PSCREATETHREADNOTIFYTYPE(pub i32);
 impl windows_core::TypeKind for PSCREATETHREADNOTIFYTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PSCREATETHREADNOTIFYTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PSCREATETHREADNOTIFYTYPE").field(&self.0).finish()
@@ -11363,6 +11483,7 @@ pub struct PSHED_PI_ERR_READING_PCIE_OVERRIDES(pub i32);
 impl windows_core::TypeKind for PSHED_PI_ERR_READING_PCIE_OVERRIDES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PSHED_PI_ERR_READING_PCIE_OVERRIDES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PSHED_PI_ERR_READING_PCIE_OVERRIDES").field(&self.0).finish()
@@ -11374,6 +11495,7 @@ pub struct REG_NOTIFY_CLASS(pub i32);
 impl windows_core::TypeKind for REG_NOTIFY_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REG_NOTIFY_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REG_NOTIFY_CLASS").field(&self.0).finish()
@@ -11385,6 +11507,7 @@ pub struct RESOURCE_TRANSLATION_DIRECTION(pub i32);
 impl windows_core::TypeKind for RESOURCE_TRANSLATION_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RESOURCE_TRANSLATION_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RESOURCE_TRANSLATION_DIRECTION").field(&self.0).finish()
@@ -11396,6 +11519,7 @@ pub struct RTL_GENERIC_COMPARE_RESULTS(pub i32);
 impl windows_core::TypeKind for RTL_GENERIC_COMPARE_RESULTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTL_GENERIC_COMPARE_RESULTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTL_GENERIC_COMPARE_RESULTS").field(&self.0).finish()
@@ -11407,6 +11531,7 @@ pub struct SECURITY_OPERATION_CODE(pub i32);
 impl windows_core::TypeKind for SECURITY_OPERATION_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SECURITY_OPERATION_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SECURITY_OPERATION_CODE").field(&self.0).finish()
@@ -11418,6 +11543,7 @@ pub struct SE_IMAGE_TYPE(pub i32);
 impl windows_core::TypeKind for SE_IMAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SE_IMAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SE_IMAGE_TYPE").field(&self.0).finish()
@@ -11429,6 +11555,7 @@ pub struct SE_IMAGE_VERIFICATION_CALLBACK_TYPE(pub i32);
 impl windows_core::TypeKind for SE_IMAGE_VERIFICATION_CALLBACK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SE_IMAGE_VERIFICATION_CALLBACK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SE_IMAGE_VERIFICATION_CALLBACK_TYPE").field(&self.0).finish()
@@ -11440,6 +11567,7 @@ pub struct SOC_SUBSYSTEM_TYPE(pub i32);
 impl windows_core::TypeKind for SOC_SUBSYSTEM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SOC_SUBSYSTEM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SOC_SUBSYSTEM_TYPE").field(&self.0).finish()
@@ -11451,6 +11579,7 @@ pub struct STATE_LOCATION_TYPE(pub i32);
 impl windows_core::TypeKind for STATE_LOCATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STATE_LOCATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STATE_LOCATION_TYPE").field(&self.0).finish()
@@ -11462,6 +11591,7 @@ pub struct SUBSYSTEM_INFORMATION_TYPE(pub i32);
 impl windows_core::TypeKind for SUBSYSTEM_INFORMATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SUBSYSTEM_INFORMATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SUBSYSTEM_INFORMATION_TYPE").field(&self.0).finish()
@@ -11473,6 +11603,7 @@ pub struct SYSTEM_FIRMWARE_TABLE_ACTION(pub i32);
 impl windows_core::TypeKind for SYSTEM_FIRMWARE_TABLE_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSTEM_FIRMWARE_TABLE_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSTEM_FIRMWARE_TABLE_ACTION").field(&self.0).finish()
@@ -11484,6 +11615,7 @@ pub struct SYSTEM_POWER_CONDITION(pub i32);
 impl windows_core::TypeKind for SYSTEM_POWER_CONDITION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSTEM_POWER_CONDITION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSTEM_POWER_CONDITION").field(&self.0).finish()
@@ -11495,6 +11627,7 @@ pub struct TABLE_SEARCH_RESULT(pub i32);
 impl windows_core::TypeKind for TABLE_SEARCH_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TABLE_SEARCH_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TABLE_SEARCH_RESULT").field(&self.0).finish()
@@ -11506,6 +11639,7 @@ pub struct TRACE_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for TRACE_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRACE_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRACE_INFORMATION_CLASS").field(&self.0).finish()
@@ -11517,6 +11651,7 @@ pub struct WHEAP_DPC_ERROR_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for WHEAP_DPC_ERROR_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEAP_DPC_ERROR_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEAP_DPC_ERROR_EVENT_TYPE").field(&self.0).finish()
@@ -11528,6 +11663,7 @@ pub struct WHEAP_PFA_OFFLINE_DECISION_TYPE(pub i32);
 impl windows_core::TypeKind for WHEAP_PFA_OFFLINE_DECISION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEAP_PFA_OFFLINE_DECISION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEAP_PFA_OFFLINE_DECISION_TYPE").field(&self.0).finish()
@@ -11539,6 +11675,7 @@ pub struct WHEA_BUGCHECK_RECOVERY_LOG_TYPE(pub i32);
 impl windows_core::TypeKind for WHEA_BUGCHECK_RECOVERY_LOG_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_BUGCHECK_RECOVERY_LOG_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_BUGCHECK_RECOVERY_LOG_TYPE").field(&self.0).finish()
@@ -11550,6 +11687,7 @@ pub struct WHEA_CPU_VENDOR(pub i32);
 impl windows_core::TypeKind for WHEA_CPU_VENDOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_CPU_VENDOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_CPU_VENDOR").field(&self.0).finish()
@@ -11561,6 +11699,7 @@ pub struct WHEA_ERROR_PACKET_DATA_FORMAT(pub i32);
 impl windows_core::TypeKind for WHEA_ERROR_PACKET_DATA_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_ERROR_PACKET_DATA_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_ERROR_PACKET_DATA_FORMAT").field(&self.0).finish()
@@ -11572,6 +11711,7 @@ pub struct WHEA_ERROR_SEVERITY(pub i32);
 impl windows_core::TypeKind for WHEA_ERROR_SEVERITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_ERROR_SEVERITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_ERROR_SEVERITY").field(&self.0).finish()
@@ -11583,6 +11723,7 @@ pub struct WHEA_ERROR_TYPE(pub i32);
 impl windows_core::TypeKind for WHEA_ERROR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_ERROR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_ERROR_TYPE").field(&self.0).finish()
@@ -11594,6 +11735,7 @@ pub struct WHEA_EVENT_LOG_ENTRY_ID(pub i32);
 impl windows_core::TypeKind for WHEA_EVENT_LOG_ENTRY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_EVENT_LOG_ENTRY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_EVENT_LOG_ENTRY_ID").field(&self.0).finish()
@@ -11605,6 +11747,7 @@ pub struct WHEA_EVENT_LOG_ENTRY_TYPE(pub i32);
 impl windows_core::TypeKind for WHEA_EVENT_LOG_ENTRY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_EVENT_LOG_ENTRY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_EVENT_LOG_ENTRY_TYPE").field(&self.0).finish()
@@ -11616,6 +11759,7 @@ pub struct WHEA_PCIEXPRESS_DEVICE_TYPE(pub i32);
 impl windows_core::TypeKind for WHEA_PCIEXPRESS_DEVICE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_PCIEXPRESS_DEVICE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_PCIEXPRESS_DEVICE_TYPE").field(&self.0).finish()
@@ -11627,6 +11771,7 @@ pub struct WHEA_PCI_RECOVERY_SIGNAL(pub i32);
 impl windows_core::TypeKind for WHEA_PCI_RECOVERY_SIGNAL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_PCI_RECOVERY_SIGNAL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_PCI_RECOVERY_SIGNAL").field(&self.0).finish()
@@ -11638,6 +11783,7 @@ pub struct WHEA_PCI_RECOVERY_STATUS(pub i32);
 impl windows_core::TypeKind for WHEA_PCI_RECOVERY_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_PCI_RECOVERY_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_PCI_RECOVERY_STATUS").field(&self.0).finish()
@@ -11649,6 +11795,7 @@ pub struct WHEA_PFA_REMOVE_TRIGGER(pub i32);
 impl windows_core::TypeKind for WHEA_PFA_REMOVE_TRIGGER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_PFA_REMOVE_TRIGGER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_PFA_REMOVE_TRIGGER").field(&self.0).finish()
@@ -11660,6 +11807,7 @@ pub struct WHEA_PSHED_PLUGIN_ENABLE_NOTIFY_ERRORS(pub i32);
 impl windows_core::TypeKind for WHEA_PSHED_PLUGIN_ENABLE_NOTIFY_ERRORS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_PSHED_PLUGIN_ENABLE_NOTIFY_ERRORS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_PSHED_PLUGIN_ENABLE_NOTIFY_ERRORS").field(&self.0).finish()
@@ -11671,6 +11819,7 @@ pub struct WHEA_RAW_DATA_FORMAT(pub i32);
 impl windows_core::TypeKind for WHEA_RAW_DATA_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_RAW_DATA_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_RAW_DATA_FORMAT").field(&self.0).finish()
@@ -11682,6 +11831,7 @@ pub struct WHEA_RECOVERY_CONTEXT_ERROR_TYPE(pub i32);
 impl windows_core::TypeKind for WHEA_RECOVERY_CONTEXT_ERROR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_RECOVERY_CONTEXT_ERROR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_RECOVERY_CONTEXT_ERROR_TYPE").field(&self.0).finish()
@@ -11693,6 +11843,7 @@ pub struct WHEA_RECOVERY_FAILURE_REASON(pub i32);
 impl windows_core::TypeKind for WHEA_RECOVERY_FAILURE_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_RECOVERY_FAILURE_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_RECOVERY_FAILURE_REASON").field(&self.0).finish()
@@ -11704,6 +11855,7 @@ pub struct WHEA_RECOVERY_TYPE(pub i32);
 impl windows_core::TypeKind for WHEA_RECOVERY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_RECOVERY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_RECOVERY_TYPE").field(&self.0).finish()
@@ -11715,6 +11867,7 @@ pub struct WHEA_THROTTLE_TYPE(pub i32);
 impl windows_core::TypeKind for WHEA_THROTTLE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_THROTTLE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_THROTTLE_TYPE").field(&self.0).finish()
@@ -11726,13 +11879,15 @@ pub struct WORK_QUEUE_TYPE(pub i32);
 impl windows_core::TypeKind for WORK_QUEUE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WORK_QUEUE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WORK_QUEUE_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ACPI_DEBUGGING_DEVICE_IN_USE {
     pub NameSpacePathLength: u32,
     pub NameSpacePath: [u16; 1],
@@ -11747,7 +11902,8 @@ impl Default for ACPI_DEBUGGING_DEVICE_IN_USE {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ACPI_INTERFACE_STANDARD {
     pub Size: u16,
     pub Version: u16,
@@ -11773,7 +11929,8 @@ impl Default for ACPI_INTERFACE_STANDARD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ACPI_INTERFACE_STANDARD2 {
     pub Size: u16,
     pub Version: u16,
@@ -11797,7 +11954,8 @@ impl Default for ACPI_INTERFACE_STANDARD2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AGP_TARGET_BUS_INTERFACE_STANDARD {
     pub Size: u16,
     pub Version: u16,
@@ -11831,7 +11989,8 @@ impl Default for AMD_L1_CACHE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMD_L1_CACHE_INFO_0 {
     pub LineSize: u8,
     pub LinesPerTag: u8,
@@ -11861,12 +12020,27 @@ impl Default for AMD_L2_CACHE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMD_L2_CACHE_INFO_0 {
     pub LineSize: u8,
     pub _bitfield: u8,
     pub Size: u16,
 }
+impl AMD_L2_CACHE_INFO_0 {
+    pub const fn LinesPerTag(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_LinesPerTag(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Associativity(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Associativity(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for AMD_L2_CACHE_INFO_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -11890,12 +12064,39 @@ impl Default for AMD_L3_CACHE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMD_L3_CACHE_INFO_0 {
     pub LineSize: u8,
     pub _bitfield1: u8,
     pub _bitfield2: u16,
 }
+impl AMD_L3_CACHE_INFO_0 {
+    pub const fn LinesPerTag(&self) -> u8 {
+        self._bitfield1 & 15
+    }
+    pub fn set_LinesPerTag(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !15) | (value & 15);
+    }
+    pub const fn Associativity(&self) -> u8 {
+        (self._bitfield1 >> 4) & 15
+    }
+    pub fn set_Associativity(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        self._bitfield2 & 3
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield2 = (self._bitfield2 & !3) | (value & 3);
+    }
+    pub const fn Size(&self) -> u16 {
+        (self._bitfield2 >> 2) & 16383
+    }
+    pub fn set_Size(&mut self, value: u16) {
+        self._bitfield2 = (self._bitfield2 & !(16383 << 2)) | ((value & 16383) << 2);
+    }
+}
 impl windows_core::TypeKind for AMD_L3_CACHE_INFO_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -11906,7 +12107,8 @@ impl Default for AMD_L3_CACHE_INFO_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ARBITER_ADD_RESERVED_PARAMETERS {
     pub ReserveDevice: *mut super::super::Foundation::DEVICE_OBJECT,
 }
@@ -11922,7 +12124,8 @@ impl Default for ARBITER_ADD_RESERVED_PARAMETERS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ARBITER_BOOT_ALLOCATION_PARAMETERS {
     pub ArbitrationList: *mut super::super::super::Win32::System::Kernel::LIST_ENTRY,
 }
@@ -11938,7 +12141,8 @@ impl Default for ARBITER_BOOT_ALLOCATION_PARAMETERS {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ARBITER_CONFLICT_INFO {
     pub OwningObject: *mut super::super::Foundation::DEVICE_OBJECT,
     pub Start: u64,
@@ -11956,7 +12160,8 @@ impl Default for ARBITER_CONFLICT_INFO {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ARBITER_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -11978,7 +12183,8 @@ impl Default for ARBITER_INTERFACE {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ARBITER_LIST_ENTRY {
     pub ListEntry: super::super::super::Win32::System::Kernel::LIST_ENTRY,
     pub AlternativeCount: u32,
@@ -12043,7 +12249,8 @@ impl Default for ARBITER_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ARBITER_QUERY_ALLOCATED_RESOURCES_PARAMETERS {
     pub AllocatedResources: *mut *mut CM_PARTIAL_RESOURCE_LIST,
 }
@@ -12057,7 +12264,8 @@ impl Default for ARBITER_QUERY_ALLOCATED_RESOURCES_PARAMETERS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ARBITER_QUERY_ARBITRATE_PARAMETERS {
     pub ArbitrationList: *mut super::super::super::Win32::System::Kernel::LIST_ENTRY,
 }
@@ -12073,7 +12281,8 @@ impl Default for ARBITER_QUERY_ARBITRATE_PARAMETERS {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ARBITER_QUERY_CONFLICT_PARAMETERS {
     pub PhysicalDeviceObject: *mut super::super::Foundation::DEVICE_OBJECT,
     pub ConflictingResource: *mut IO_RESOURCE_DESCRIPTOR,
@@ -12092,7 +12301,8 @@ impl Default for ARBITER_QUERY_CONFLICT_PARAMETERS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ARBITER_RETEST_ALLOCATION_PARAMETERS {
     pub ArbitrationList: *mut super::super::super::Win32::System::Kernel::LIST_ENTRY,
     pub AllocateFromCount: u32,
@@ -12110,7 +12320,8 @@ impl Default for ARBITER_RETEST_ALLOCATION_PARAMETERS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ARBITER_TEST_ALLOCATION_PARAMETERS {
     pub ArbitrationList: *mut super::super::super::Win32::System::Kernel::LIST_ENTRY,
     pub AllocateFromCount: u32,
@@ -12172,7 +12383,8 @@ impl Default for ARM64_NT_CONTEXT_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Diagnostics_Debug")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ARM64_NT_CONTEXT_0_0 {
     pub X0: u64,
     pub X1: u64,
@@ -12217,7 +12429,8 @@ impl Default for ARM64_NT_CONTEXT_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDCB_IMAGE_INFORMATION {
     pub Classification: BDCB_CLASSIFICATION,
     pub ImageFlags: u32,
@@ -12241,7 +12454,8 @@ impl Default for BDCB_IMAGE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDCB_STATUS_UPDATE_CONTEXT {
     pub StatusType: BDCB_STATUS_UPDATE_TYPE,
 }
@@ -12254,7 +12468,8 @@ impl Default for BDCB_STATUS_UPDATE_CONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BOOTDISK_INFORMATION {
     pub BootPartitionOffset: i64,
     pub SystemPartitionOffset: i64,
@@ -12270,7 +12485,8 @@ impl Default for BOOTDISK_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BOOTDISK_INFORMATION_EX {
     pub BootPartitionOffset: i64,
     pub SystemPartitionOffset: i64,
@@ -12305,7 +12521,8 @@ impl Default for BOOTDISK_INFORMATION_LITE {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BUS_INTERFACE_STANDARD {
     pub Size: u16,
     pub Version: u16,
@@ -12328,7 +12545,8 @@ impl Default for BUS_INTERFACE_STANDARD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BUS_RESOURCE_UPDATE_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -12360,10 +12578,25 @@ impl Default for BUS_SPECIFIC_RESET_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BUS_SPECIFIC_RESET_FLAGS_0 {
     pub _bitfield: u64,
 }
+impl BUS_SPECIFIC_RESET_FLAGS_0 {
+    pub const fn KeepStackReset(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_KeepStackReset(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 1) & 9223372036854775807
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(9223372036854775807 << 1)) | ((value & 9223372036854775807) << 1);
+    }
+}
 impl windows_core::TypeKind for BUS_SPECIFIC_RESET_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -12374,7 +12607,8 @@ impl Default for BUS_SPECIFIC_RESET_FLAGS_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_Storage_FileSystem", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLFS_MGMT_CLIENT_REGISTRATION {
     pub Version: u32,
     pub AdvanceTailCallback: PCLFS_CLIENT_ADVANCE_TAIL_CALLBACK,
@@ -12396,7 +12630,8 @@ impl Default for CLFS_MGMT_CLIENT_REGISTRATION {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CMC_DRIVER_INFO {
     pub ExceptionCallback: PDRIVER_CMC_EXCEPTION_CALLBACK,
     pub DpcCallback: super::super::Foundation::PKDEFERRED_ROUTINE,
@@ -12413,7 +12648,8 @@ impl Default for CMC_DRIVER_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_COMPONENT_INFORMATION {
     pub Flags: DEVICE_FLAGS,
     pub Version: u32,
@@ -12429,7 +12665,8 @@ impl Default for CM_COMPONENT_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_DISK_GEOMETRY_DEVICE_DATA {
     pub BytesPerSector: u32,
     pub NumberOfCylinders: u32,
@@ -12490,7 +12727,8 @@ impl Default for CM_EISA_SLOT_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_FLOPPY_DEVICE_DATA {
     pub Version: u16,
     pub Revision: u16,
@@ -12552,7 +12790,8 @@ impl Default for CM_INT13_DRIVE_PARAMETER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_KEYBOARD_DEVICE_DATA {
     pub Version: u16,
     pub Revision: u16,
@@ -12586,7 +12825,8 @@ impl Default for CM_MCA_POS_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_MONITOR_DEVICE_DATA {
     pub Version: u16,
     pub Revision: u16,
@@ -12667,7 +12907,8 @@ impl Default for CM_PARTIAL_RESOURCE_DESCRIPTOR_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_PARTIAL_RESOURCE_DESCRIPTOR_0_0 {
     pub Start: u32,
     pub Length: u32,
@@ -12682,7 +12923,8 @@ impl Default for CM_PARTIAL_RESOURCE_DESCRIPTOR_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_PARTIAL_RESOURCE_DESCRIPTOR_0_1 {
     pub Class: u8,
     pub Type: u8,
@@ -12700,7 +12942,8 @@ impl Default for CM_PARTIAL_RESOURCE_DESCRIPTOR_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_PARTIAL_RESOURCE_DESCRIPTOR_0_2 {
     pub Data: [u32; 3],
 }
@@ -12713,7 +12956,8 @@ impl Default for CM_PARTIAL_RESOURCE_DESCRIPTOR_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_PARTIAL_RESOURCE_DESCRIPTOR_0_3 {
     pub DataSize: u32,
     pub Reserved1: u32,
@@ -12728,7 +12972,8 @@ impl Default for CM_PARTIAL_RESOURCE_DESCRIPTOR_0_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_PARTIAL_RESOURCE_DESCRIPTOR_0_4 {
     pub Channel: u32,
     pub RequestLine: u32,
@@ -12746,7 +12991,8 @@ impl Default for CM_PARTIAL_RESOURCE_DESCRIPTOR_0_4 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_PARTIAL_RESOURCE_DESCRIPTOR_0_5 {
     pub Channel: u32,
     pub Port: u32,
@@ -12775,7 +13021,8 @@ impl Default for CM_PARTIAL_RESOURCE_DESCRIPTOR_0_6 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_PARTIAL_RESOURCE_DESCRIPTOR_0_7 {
     pub Level: u32,
     pub Vector: u32,
@@ -12873,7 +13120,8 @@ impl Default for CM_PARTIAL_RESOURCE_DESCRIPTOR_0_12_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_PARTIAL_RESOURCE_DESCRIPTOR_0_12_0_0 {
     pub Reserved: u16,
     pub MessageCount: u16,
@@ -12889,7 +13137,8 @@ impl Default for CM_PARTIAL_RESOURCE_DESCRIPTOR_0_12_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_PARTIAL_RESOURCE_DESCRIPTOR_0_12_0_1 {
     pub Level: u32,
     pub Vector: u32,
@@ -12934,7 +13183,8 @@ impl Default for CM_PARTIAL_RESOURCE_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_PCCARD_DEVICE_DATA {
     pub Flags: u8,
     pub ErrorCode: u8,
@@ -12996,7 +13246,8 @@ impl Default for CM_PNP_BIOS_INSTALLATION_CHECK {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Power")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_POWER_DATA {
     pub PD_Size: u32,
     pub PD_MostRecentPowerState: super::super::super::Win32::System::Power::DEVICE_POWER_STATE,
@@ -13032,7 +13283,8 @@ impl Default for CM_RESOURCE_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_ROM_BLOCK {
     pub Address: u32,
     pub Size: u32,
@@ -13046,7 +13298,8 @@ impl Default for CM_ROM_BLOCK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_SCSI_DEVICE_DATA {
     pub Version: u16,
     pub Revision: u16,
@@ -13061,7 +13314,8 @@ impl Default for CM_SCSI_DEVICE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_SERIAL_DEVICE_DATA {
     pub Version: u16,
     pub Revision: u16,
@@ -13076,7 +13330,8 @@ impl Default for CM_SERIAL_DEVICE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_SONIC_DEVICE_DATA {
     pub Version: u16,
     pub Revision: u16,
@@ -13092,7 +13347,8 @@ impl Default for CM_SONIC_DEVICE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_VIDEO_DEVICE_DATA {
     pub Version: u16,
     pub Revision: u16,
@@ -13107,7 +13363,8 @@ impl Default for CM_VIDEO_DEVICE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONFIGURATION_INFORMATION {
     pub DiskCount: u32,
     pub FloppyCount: u32,
@@ -13131,7 +13388,8 @@ impl Default for CONFIGURATION_INFORMATION {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Win32_System_Kernel"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONTROLLER_OBJECT {
     pub Type: i16,
     pub Size: i16,
@@ -13180,7 +13438,8 @@ impl Default for COUNTED_REASON_CONTEXT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COUNTED_REASON_CONTEXT_0_0 {
     pub ResourceFileName: super::super::super::Win32::Foundation::UNICODE_STRING,
     pub ResourceReasonId: u16,
@@ -13197,7 +13456,8 @@ impl Default for COUNTED_REASON_CONTEXT_0_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CPE_DRIVER_INFO {
     pub ExceptionCallback: PDRIVER_CPE_EXCEPTION_CALLBACK,
     pub DpcCallback: super::super::Foundation::PKDEFERRED_ROUTINE,
@@ -13214,7 +13474,8 @@ impl Default for CPE_DRIVER_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CRASHDUMP_FUNCTIONS_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -13232,7 +13493,8 @@ impl Default for CRASHDUMP_FUNCTIONS_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CREATE_USER_PROCESS_ECP_CONTEXT {
     pub Size: u16,
     pub Reserved: u16,
@@ -13247,7 +13509,8 @@ impl Default for CREATE_USER_PROCESS_ECP_CONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3COLD_AUX_POWER_AND_TIMING_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -13267,7 +13530,8 @@ impl Default for D3COLD_AUX_POWER_AND_TIMING_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3COLD_SUPPORT_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -13363,7 +13627,8 @@ impl Default for DEBUG_DEVICE_ADDRESS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEBUG_DEVICE_ADDRESS_0_0 {
     pub BitWidth: u8,
     pub AccessSize: u8,
@@ -13427,10 +13692,43 @@ impl Default for DEBUG_DEVICE_DESCRIPTOR_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEBUG_DEVICE_DESCRIPTOR_0_0 {
     pub _bitfield: u8,
 }
+impl DEBUG_DEVICE_DESCRIPTOR_0_0 {
+    pub const fn DbgHalScratchAllocated(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_DbgHalScratchAllocated(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DbgBarsMapped(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DbgBarsMapped(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn DbgScratchAllocated(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_DbgScratchAllocated(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn DbgUncachedMemory(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_DbgUncachedMemory(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn DbgSynthetic(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_DbgSynthetic(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+}
 impl windows_core::TypeKind for DEBUG_DEVICE_DESCRIPTOR_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -13440,7 +13738,8 @@ impl Default for DEBUG_DEVICE_DESCRIPTOR_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEBUG_EFI_IOMMU_DATA {
     pub PciIoProtocolHandle: *mut core::ffi::c_void,
     pub Mapping: *mut core::ffi::c_void,
@@ -13454,7 +13753,8 @@ impl Default for DEBUG_EFI_IOMMU_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEBUG_MEMORY_REQUIREMENTS {
     pub Start: i64,
     pub MaxEnd: i64,
@@ -13472,7 +13772,8 @@ impl Default for DEBUG_MEMORY_REQUIREMENTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEBUG_TRANSPORT_DATA {
     pub HwContextSize: u32,
     pub SharedVisibleDataSize: u32,
@@ -13518,10 +13819,31 @@ impl Default for DEVICE_BUS_SPECIFIC_RESET_TYPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVICE_BUS_SPECIFIC_RESET_TYPE_0 {
     pub _bitfield: u64,
 }
+impl DEVICE_BUS_SPECIFIC_RESET_TYPE_0 {
+    pub const fn FunctionLevelDeviceReset(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_FunctionLevelDeviceReset(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PlatformLevelDeviceReset(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PlatformLevelDeviceReset(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 2) & 4611686018427387903
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(4611686018427387903 << 2)) | ((value & 4611686018427387903) << 2);
+    }
+}
 impl windows_core::TypeKind for DEVICE_BUS_SPECIFIC_RESET_TYPE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -13531,10 +13853,37 @@ impl Default for DEVICE_BUS_SPECIFIC_RESET_TYPE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVICE_BUS_SPECIFIC_RESET_TYPE_1 {
     pub _bitfield: u64,
 }
+impl DEVICE_BUS_SPECIFIC_RESET_TYPE_1 {
+    pub const fn FunctionLevelDeviceReset(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_FunctionLevelDeviceReset(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PlatformLevelDeviceReset(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PlatformLevelDeviceReset(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn SecondaryBusReset(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_SecondaryBusReset(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 3) & 2305843009213693951
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(2305843009213693951 << 3)) | ((value & 2305843009213693951) << 3);
+    }
+}
 impl windows_core::TypeKind for DEVICE_BUS_SPECIFIC_RESET_TYPE_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -13545,7 +13894,8 @@ impl Default for DEVICE_BUS_SPECIFIC_RESET_TYPE_1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Power")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVICE_CAPABILITIES {
     pub Size: u16,
     pub Version: u16,
@@ -13560,6 +13910,153 @@ pub struct DEVICE_CAPABILITIES {
     pub D3Latency: u32,
 }
 #[cfg(feature = "Win32_System_Power")]
+impl DEVICE_CAPABILITIES {
+    pub const fn DeviceD1(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_DeviceD1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DeviceD2(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DeviceD2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn LockSupported(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_LockSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn EjectSupported(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_EjectSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Removable(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Removable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn DockDevice(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_DockDevice(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn UniqueID(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_UniqueID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn SilentInstall(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_SilentInstall(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn RawDeviceOK(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_RawDeviceOK(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn SurpriseRemovalOK(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_SurpriseRemovalOK(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn WakeFromD0(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_WakeFromD0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn WakeFromD1(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_WakeFromD1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn WakeFromD2(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_WakeFromD2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn WakeFromD3(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_WakeFromD3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn HardwareDisabled(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_HardwareDisabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn NonDynamic(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_NonDynamic(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn WarmEjectSupported(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_WarmEjectSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn NoDisplayInUI(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_NoDisplayInUI(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn WakeFromInterrupt(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_WakeFromInterrupt(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn SecureDevice(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_SecureDevice(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn ChildOfVgaEnabledBridge(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_ChildOfVgaEnabledBridge(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn DecodeIoOnBoot(&self) -> u32 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_DecodeIoOnBoot(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 23) & 511
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(511 << 23)) | ((value & 511) << 23);
+    }
+}
+#[cfg(feature = "Win32_System_Power")]
 impl windows_core::TypeKind for DEVICE_CAPABILITIES {
     type TypeKind = windows_core::CopyType;
 }
@@ -13570,7 +14067,8 @@ impl Default for DEVICE_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVICE_DESCRIPTION {
     pub Version: u32,
     pub Master: super::super::super::Win32::Foundation::BOOLEAN,
@@ -13602,7 +14100,8 @@ impl Default for DEVICE_DESCRIPTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVICE_FAULT_CONFIGURATION {
     pub FaultHandler: PIOMMU_DEVICE_FAULT_HANDLER,
     pub FaultContext: *mut core::ffi::c_void,
@@ -13616,10 +14115,55 @@ impl Default for DEVICE_FAULT_CONFIGURATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVICE_FLAGS {
     pub _bitfield: u32,
 }
+impl DEVICE_FLAGS {
+    pub const fn Failed(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Failed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ReadOnly(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ReadOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Removable(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Removable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ConsoleIn(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ConsoleIn(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ConsoleOut(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_ConsoleOut(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Input(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Input(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Output(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Output(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+}
 impl windows_core::TypeKind for DEVICE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -13629,7 +14173,8 @@ impl Default for DEVICE_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVICE_INTERFACE_CHANGE_NOTIFICATION {
     pub Version: u16,
     pub Size: u16,
@@ -13647,7 +14192,8 @@ impl Default for DEVICE_INTERFACE_CHANGE_NOTIFICATION {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVICE_RELATIONS {
     pub Count: u32,
     pub Objects: [*mut super::super::Foundation::DEVICE_OBJECT; 1],
@@ -13663,7 +14209,8 @@ impl Default for DEVICE_RELATIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVICE_RESET_INTERFACE_STANDARD {
     pub Size: u16,
     pub Version: u16,
@@ -13700,10 +14247,31 @@ impl Default for DEVICE_RESET_STATUS_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVICE_RESET_STATUS_FLAGS_0 {
     pub _bitfield: u64,
 }
+impl DEVICE_RESET_STATUS_FLAGS_0 {
+    pub const fn KeepStackReset(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_KeepStackReset(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn RecoveringFromBusError(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_RecoveringFromBusError(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 2) & 4611686018427387903
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(4611686018427387903 << 2)) | ((value & 4611686018427387903) << 2);
+    }
+}
 impl windows_core::TypeKind for DEVICE_RESET_STATUS_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -13741,7 +14309,8 @@ impl Default for DISK_SIGNATURE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISK_SIGNATURE_0_0 {
     pub DiskId: windows_core::GUID,
 }
@@ -13754,7 +14323,8 @@ impl Default for DISK_SIGNATURE_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISK_SIGNATURE_0_1 {
     pub Signature: u32,
     pub CheckSum: u32,
@@ -13769,7 +14339,8 @@ impl Default for DISK_SIGNATURE_0_1 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMA_ADAPTER {
     pub Version: u16,
     pub Size: u16,
@@ -13814,7 +14385,8 @@ impl Default for DMA_ADAPTER_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMA_ADAPTER_INFO_CRASHDUMP {
     pub DeviceDescription: DEVICE_DESCRIPTION,
     pub DeviceIdSize: usize,
@@ -13829,7 +14401,8 @@ impl Default for DMA_ADAPTER_INFO_CRASHDUMP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMA_ADAPTER_INFO_V1 {
     pub ReadDmaCounterAvailable: u32,
     pub ScatterGatherLimit: u32,
@@ -13876,7 +14449,8 @@ impl Default for DMA_COMMON_BUFFER_EXTENDED_CONFIGURATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMA_COMMON_BUFFER_EXTENDED_CONFIGURATION_0_0 {
     pub MinimumAddress: i64,
     pub MaximumAddress: i64,
@@ -13890,7 +14464,8 @@ impl Default for DMA_COMMON_BUFFER_EXTENDED_CONFIGURATION_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMA_COMMON_BUFFER_EXTENDED_CONFIGURATION_0_1 {
     pub Offset: u64,
     pub Length: u32,
@@ -13904,10 +14479,37 @@ impl Default for DMA_COMMON_BUFFER_EXTENDED_CONFIGURATION_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMA_CONFIGURATION_BYTE0 {
     pub _bitfield: u8,
 }
+impl DMA_CONFIGURATION_BYTE0 {
+    pub const fn Channel(&self) -> u8 {
+        self._bitfield & 7
+    }
+    pub fn set_Channel(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 3) & 7
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 3)) | ((value & 7) << 3);
+    }
+    pub const fn Shared(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Shared(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn MoreEntries(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_MoreEntries(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for DMA_CONFIGURATION_BYTE0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -13917,10 +14519,37 @@ impl Default for DMA_CONFIGURATION_BYTE0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMA_CONFIGURATION_BYTE1 {
     pub _bitfield: u8,
 }
+impl DMA_CONFIGURATION_BYTE1 {
+    pub const fn Reserved0(&self) -> u8 {
+        self._bitfield & 3
+    }
+    pub fn set_Reserved0(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn TransferSize(&self) -> u8 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_TransferSize(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn Timing(&self) -> u8 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_Timing(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn Reserved1(&self) -> u8 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_Reserved1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+}
 impl windows_core::TypeKind for DMA_CONFIGURATION_BYTE1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -13930,7 +14559,8 @@ impl Default for DMA_CONFIGURATION_BYTE1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMA_IOMMU_INTERFACE {
     pub Version: u32,
     pub CreateDomain: PIOMMU_DOMAIN_CREATE,
@@ -13985,7 +14615,8 @@ impl Default for DMA_IOMMU_INTERFACE_EX_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMA_IOMMU_INTERFACE_V1 {
     pub CreateDomain: PIOMMU_DOMAIN_CREATE,
     pub DeleteDomain: PIOMMU_DOMAIN_DELETE,
@@ -14010,7 +14641,8 @@ impl Default for DMA_IOMMU_INTERFACE_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMA_IOMMU_INTERFACE_V2 {
     pub CreateDomainEx: PIOMMU_DOMAIN_CREATE_EX,
     pub DeleteDomain: PIOMMU_DOMAIN_DELETE,
@@ -14045,7 +14677,8 @@ impl Default for DMA_IOMMU_INTERFACE_V2 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMA_OPERATIONS {
     pub Size: u32,
     pub PutDmaAdapter: PPUT_DMA_ADAPTER,
@@ -14127,7 +14760,8 @@ impl Default for DMA_TRANSFER_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMA_TRANSFER_INFO_V1 {
     pub MapRegisterCount: u32,
     pub ScatterGatherElementCount: u32,
@@ -14142,7 +14776,8 @@ impl Default for DMA_TRANSFER_INFO_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMA_TRANSFER_INFO_V2 {
     pub MapRegisterCount: u32,
     pub ScatterGatherElementCount: u32,
@@ -14186,7 +14821,8 @@ impl Default for DOMAIN_CONFIGURATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DOMAIN_CONFIGURATION_ARM64 {
     pub Ttbr0: i64,
     pub Ttbr1: i64,
@@ -14206,7 +14842,8 @@ impl Default for DOMAIN_CONFIGURATION_ARM64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DOMAIN_CONFIGURATION_X64 {
     pub FirstLevelPageTableRoot: i64,
     pub TranslationEnabled: super::super::super::Win32::Foundation::BOOLEAN,
@@ -14220,7 +14857,8 @@ impl Default for DOMAIN_CONFIGURATION_X64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DPC_WATCHDOG_GLOBAL_TRIAGE_BLOCK {
     pub Signature: u32,
     pub Revision: u16,
@@ -14237,7 +14875,8 @@ impl Default for DPC_WATCHDOG_GLOBAL_TRIAGE_BLOCK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRIVER_VERIFIER_THUNK_PAIRS {
     pub PristineRoutine: PDRIVER_VERIFIER_THUNK_ROUTINE,
     pub NewRoutine: PDRIVER_VERIFIER_THUNK_ROUTINE,
@@ -14266,7 +14905,8 @@ impl Default for EFI_ACPI_RAS_SIGNAL_TABLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EISA_DMA_CONFIGURATION {
     pub ConfigurationByte0: DMA_CONFIGURATION_BYTE0,
     pub ConfigurationByte1: DMA_CONFIGURATION_BYTE1,
@@ -14280,7 +14920,8 @@ impl Default for EISA_DMA_CONFIGURATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EISA_IRQ_CONFIGURATION {
     pub ConfigurationByte: EISA_IRQ_DESCRIPTOR,
     pub Reserved: u8,
@@ -14294,10 +14935,43 @@ impl Default for EISA_IRQ_CONFIGURATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EISA_IRQ_DESCRIPTOR {
     pub _bitfield: u8,
 }
+impl EISA_IRQ_DESCRIPTOR {
+    pub const fn Interrupt(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_Interrupt(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn LevelTriggered(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_LevelTriggered(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Shared(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Shared(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn MoreEntries(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_MoreEntries(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for EISA_IRQ_DESCRIPTOR {
     type TypeKind = windows_core::CopyType;
 }
@@ -14324,10 +14998,55 @@ impl Default for EISA_MEMORY_CONFIGURATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EISA_MEMORY_TYPE {
     pub _bitfield: u8,
 }
+impl EISA_MEMORY_TYPE {
+    pub const fn ReadWrite(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_ReadWrite(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Cached(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Cached(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved0(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Reserved0(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Type(&self) -> u8 {
+        (self._bitfield >> 3) & 3
+    }
+    pub fn set_Type(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 3)) | ((value & 3) << 3);
+    }
+    pub const fn Shared(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Shared(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved1(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Reserved1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn MoreEntries(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_MoreEntries(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for EISA_MEMORY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
@@ -14351,10 +15070,37 @@ impl Default for EISA_PORT_CONFIGURATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EISA_PORT_DESCRIPTOR {
     pub _bitfield: u8,
 }
+impl EISA_PORT_DESCRIPTOR {
+    pub const fn NumberPorts(&self) -> u8 {
+        self._bitfield & 31
+    }
+    pub fn set_NumberPorts(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Shared(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Shared(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn MoreEntries(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_MoreEntries(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for EISA_PORT_DESCRIPTOR {
     type TypeKind = windows_core::CopyType;
 }
@@ -14364,7 +15110,8 @@ impl Default for EISA_PORT_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ETW_TRACE_SESSION_SETTINGS {
     pub Version: u32,
     pub BufferSize: u32,
@@ -14384,7 +15131,8 @@ impl Default for ETW_TRACE_SESSION_SETTINGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EXTENDED_CREATE_INFORMATION {
     pub ExtendedCreateFlags: i64,
     pub EaBuffer: *mut core::ffi::c_void,
@@ -14399,7 +15147,8 @@ impl Default for EXTENDED_CREATE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EXTENDED_CREATE_INFORMATION_32 {
     pub ExtendedCreateFlags: i64,
     pub EaBuffer: *mut core::ffi::c_void,
@@ -14414,7 +15163,8 @@ impl Default for EXTENDED_CREATE_INFORMATION_32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EXT_DELETE_PARAMETERS {
     pub Version: u32,
     pub Reserved: u32,
@@ -14493,7 +15243,8 @@ impl Default for FAULT_INFORMATION_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAULT_INFORMATION_ARM64 {
     pub DomainHandle: *mut core::ffi::c_void,
     pub FaultAddress: *mut core::ffi::c_void,
@@ -14514,10 +15265,43 @@ impl Default for FAULT_INFORMATION_ARM64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAULT_INFORMATION_ARM64_FLAGS {
     pub _bitfield: u32,
 }
+impl FAULT_INFORMATION_ARM64_FLAGS {
+    pub const fn WriteNotRead(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_WriteNotRead(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn InstructionNotData(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_InstructionNotData(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Privileged(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Privileged(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn FaultAddressValid(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_FaultAddressValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for FAULT_INFORMATION_ARM64_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -14527,7 +15311,8 @@ impl Default for FAULT_INFORMATION_ARM64_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAULT_INFORMATION_X64 {
     pub DomainHandle: *mut core::ffi::c_void,
     pub FaultAddress: *mut core::ffi::c_void,
@@ -14545,10 +15330,25 @@ impl Default for FAULT_INFORMATION_X64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAULT_INFORMATION_X64_FLAGS {
     pub _bitfield: u32,
 }
+impl FAULT_INFORMATION_X64_FLAGS {
+    pub const fn FaultAddressValid(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_FaultAddressValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for FAULT_INFORMATION_X64_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -14558,7 +15358,8 @@ impl Default for FAULT_INFORMATION_X64_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_ATTRIBUTE_TAG_INFORMATION {
     pub FileAttributes: u32,
     pub ReparseTag: u32,
@@ -14572,7 +15373,8 @@ impl Default for FILE_ATTRIBUTE_TAG_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_END_OF_FILE_INFORMATION {
     pub EndOfFile: i64,
 }
@@ -14585,7 +15387,8 @@ impl Default for FILE_END_OF_FILE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_FS_DEVICE_INFORMATION {
     pub DeviceType: u32,
     pub Characteristics: u32,
@@ -14599,7 +15402,8 @@ impl Default for FILE_FS_DEVICE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_FS_FULL_SIZE_INFORMATION {
     pub TotalAllocationUnits: i64,
     pub CallerAvailableAllocationUnits: i64,
@@ -14616,7 +15420,8 @@ impl Default for FILE_FS_FULL_SIZE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_FS_FULL_SIZE_INFORMATION_EX {
     pub ActualTotalAllocationUnits: u64,
     pub ActualAvailableAllocationUnits: u64,
@@ -14641,7 +15446,8 @@ impl Default for FILE_FS_FULL_SIZE_INFORMATION_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_FS_LABEL_INFORMATION {
     pub VolumeLabelLength: u32,
     pub VolumeLabel: [u16; 1],
@@ -14655,7 +15461,8 @@ impl Default for FILE_FS_LABEL_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_FS_METADATA_SIZE_INFORMATION {
     pub TotalMetadataAllocationUnits: i64,
     pub SectorsPerAllocationUnit: u32,
@@ -14670,7 +15477,8 @@ impl Default for FILE_FS_METADATA_SIZE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_FS_OBJECTID_INFORMATION {
     pub ObjectId: [u8; 16],
     pub ExtendedInfo: [u8; 48],
@@ -14684,7 +15492,8 @@ impl Default for FILE_FS_OBJECTID_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_FS_SIZE_INFORMATION {
     pub TotalAllocationUnits: i64,
     pub AvailableAllocationUnits: i64,
@@ -14700,7 +15509,8 @@ impl Default for FILE_FS_SIZE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_FS_VOLUME_INFORMATION {
     pub VolumeCreationTime: i64,
     pub VolumeSerialNumber: u32,
@@ -14717,7 +15527,8 @@ impl Default for FILE_FS_VOLUME_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_IOSTATUSBLOCK_RANGE_INFORMATION {
     pub IoStatusBlockRange: *mut u8,
     pub Length: u32,
@@ -14731,7 +15542,8 @@ impl Default for FILE_IOSTATUSBLOCK_RANGE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_IO_COMPLETION_NOTIFICATION_INFORMATION {
     pub Flags: u32,
 }
@@ -14745,7 +15557,8 @@ impl Default for FILE_IO_COMPLETION_NOTIFICATION_INFORMATION {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_IO_PRIORITY_HINT_INFORMATION {
     pub PriorityHint: super::super::Foundation::IO_PRIORITY_HINT,
 }
@@ -14761,7 +15574,8 @@ impl Default for FILE_IO_PRIORITY_HINT_INFORMATION {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_IO_PRIORITY_HINT_INFORMATION_EX {
     pub PriorityHint: super::super::Foundation::IO_PRIORITY_HINT,
     pub BoostOutstanding: super::super::super::Win32::Foundation::BOOLEAN,
@@ -14777,7 +15591,8 @@ impl Default for FILE_IO_PRIORITY_HINT_INFORMATION_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_IS_REMOTE_DEVICE_INFORMATION {
     pub IsRemote: super::super::super::Win32::Foundation::BOOLEAN,
 }
@@ -14818,7 +15633,8 @@ impl Default for FILE_MEMORY_PARTITION_INFORMATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_MEMORY_PARTITION_INFORMATION_0_0 {
     pub NoCrossPartitionAccess: u8,
     pub Spare: [u8; 3],
@@ -14832,7 +15648,8 @@ impl Default for FILE_MEMORY_PARTITION_INFORMATION_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_NUMA_NODE_INFORMATION {
     pub NodeNumber: u16,
 }
@@ -14845,7 +15662,8 @@ impl Default for FILE_NUMA_NODE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_PROCESS_IDS_USING_FILE_INFORMATION {
     pub NumberOfProcessIdsInList: u32,
     pub ProcessIdList: [usize; 1],
@@ -14859,7 +15677,8 @@ impl Default for FILE_PROCESS_IDS_USING_FILE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_SFIO_RESERVE_INFORMATION {
     pub RequestsPerPeriod: u32,
     pub Period: u32,
@@ -14877,7 +15696,8 @@ impl Default for FILE_SFIO_RESERVE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_SFIO_VOLUME_INFORMATION {
     pub MaximumRequestsPerPeriod: u32,
     pub MinimumPeriod: u32,
@@ -14892,7 +15712,8 @@ impl Default for FILE_SFIO_VOLUME_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_STANDARD_INFORMATION_EX {
     pub AllocationSize: i64,
     pub EndOfFile: i64,
@@ -14911,7 +15732,8 @@ impl Default for FILE_STANDARD_INFORMATION_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_VALID_DATA_LENGTH_INFORMATION {
     pub ValidDataLength: i64,
 }
@@ -14924,7 +15746,8 @@ impl Default for FILE_VALID_DATA_LENGTH_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FLOATING_SAVE_AREA {
     pub ControlWord: u32,
     pub StatusWord: u32,
@@ -14945,7 +15768,8 @@ impl Default for FLOATING_SAVE_AREA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FPGA_CONTROL_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -14966,7 +15790,8 @@ impl Default for FPGA_CONTROL_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FUNCTION_LEVEL_DEVICE_RESET_PARAMETERS {
     pub Size: u32,
     pub DeviceResetCompletion: PDEVICE_RESET_COMPLETION,
@@ -14981,7 +15806,8 @@ impl Default for FUNCTION_LEVEL_DEVICE_RESET_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HAL_AMLI_BAD_IO_ADDRESS_LIST {
     pub BadAddrBegin: u32,
     pub BadAddrSize: u32,
@@ -14997,7 +15823,8 @@ impl Default for HAL_AMLI_BAD_IO_ADDRESS_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HAL_BUS_INFORMATION {
     pub BusType: INTERFACE_TYPE,
     pub ConfigurationType: BUS_DATA_TYPE,
@@ -15014,7 +15841,8 @@ impl Default for HAL_BUS_INFORMATION {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HAL_CALLBACKS {
     pub SetSystemInformation: super::super::Foundation::PCALLBACK_OBJECT,
     pub BusCheck: super::super::Foundation::PCALLBACK_OBJECT,
@@ -15031,7 +15859,8 @@ impl Default for HAL_CALLBACKS {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Ioctl", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HAL_DISPATCH {
     pub Version: u32,
     pub HalQuerySystemInformation: pHalQuerySystemInformation,
@@ -15069,7 +15898,8 @@ impl Default for HAL_DISPATCH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HAL_ERROR_INFO {
     pub Version: u32,
     pub InitMaxSize: u32,
@@ -15108,7 +15938,8 @@ impl Default for HAL_ERROR_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HAL_MCA_INTERFACE {
     pub Lock: PHALMCAINTERFACELOCK,
     pub Unlock: PHALMCAINTERFACEUNLOCK,
@@ -15123,7 +15954,8 @@ impl Default for HAL_MCA_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HAL_PLATFORM_INFORMATION {
     pub PlatformFlags: u32,
 }
@@ -15136,7 +15968,8 @@ impl Default for HAL_PLATFORM_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HAL_POWER_INFORMATION {
     pub TBD: u32,
 }
@@ -15149,7 +15982,8 @@ impl Default for HAL_POWER_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HAL_PROCESSOR_FEATURE {
     pub UsableFeatureBits: u32,
 }
@@ -15162,7 +15996,8 @@ impl Default for HAL_PROCESSOR_FEATURE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HAL_PROCESSOR_SPEED_INFORMATION {
     pub ProcessorSpeed: u32,
 }
@@ -15175,7 +16010,8 @@ impl Default for HAL_PROCESSOR_SPEED_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HARDWARE_COUNTER {
     pub Type: HARDWARE_COUNTER_TYPE,
     pub Reserved: u32,
@@ -15190,7 +16026,8 @@ impl Default for HARDWARE_COUNTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HWPROFILE_CHANGE_NOTIFICATION {
     pub Version: u16,
     pub Size: u16,
@@ -15236,10 +16073,67 @@ impl Default for IMAGE_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_INFO_0_0 {
     pub _bitfield: u32,
 }
+impl IMAGE_INFO_0_0 {
+    pub const fn ImageAddressingMode(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_ImageAddressingMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn SystemModeImage(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_SystemModeImage(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn ImageMappedToAllPids(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_ImageMappedToAllPids(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn ExtendedInfoPresent(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_ExtendedInfoPresent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn MachineTypeMismatch(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_MachineTypeMismatch(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn ImageSignatureLevel(&self) -> u32 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_ImageSignatureLevel(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+    pub const fn ImageSignatureType(&self) -> u32 {
+        (self._bitfield >> 16) & 7
+    }
+    pub fn set_ImageSignatureType(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 16)) | ((value & 7) << 16);
+    }
+    pub const fn ImagePartialMap(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_ImagePartialMap(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 20) & 4095
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4095 << 20)) | ((value & 4095) << 20);
+    }
+}
 impl windows_core::TypeKind for IMAGE_INFO_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -15268,7 +16162,8 @@ impl Default for IMAGE_INFO_EX {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Security")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INITIAL_PRIVILEGE_SET {
     pub PrivilegeCount: u32,
     pub Control: u32,
@@ -15285,7 +16180,8 @@ impl Default for INITIAL_PRIVILEGE_SET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INPUT_MAPPING_ELEMENT {
     pub InputMappingId: u32,
 }
@@ -15312,10 +16208,55 @@ impl Default for INTEL_CACHE_INFO_EAX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INTEL_CACHE_INFO_EAX_0 {
     pub _bitfield: i32,
 }
+impl INTEL_CACHE_INFO_EAX_0 {
+    pub const fn Type(&self) -> i32 {
+        self._bitfield & 31
+    }
+    pub fn set_Type(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn Level(&self) -> i32 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_Level(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn SelfInitializing(&self) -> i32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_SelfInitializing(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn FullyAssociative(&self) -> i32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_FullyAssociative(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Reserved(&self) -> i32 {
+        (self._bitfield >> 10) & 15
+    }
+    pub fn set_Reserved(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(15 << 10)) | ((value & 15) << 10);
+    }
+    pub const fn ThreadsSharing(&self) -> i32 {
+        (self._bitfield >> 14) & 4095
+    }
+    pub fn set_ThreadsSharing(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(4095 << 14)) | ((value & 4095) << 14);
+    }
+    pub const fn ProcessorCores(&self) -> i32 {
+        (self._bitfield >> 26) & 63
+    }
+    pub fn set_ProcessorCores(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(63 << 26)) | ((value & 63) << 26);
+    }
+}
 impl windows_core::TypeKind for INTEL_CACHE_INFO_EAX_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -15339,10 +16280,31 @@ impl Default for INTEL_CACHE_INFO_EBX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INTEL_CACHE_INFO_EBX_0 {
     pub _bitfield: u32,
 }
+impl INTEL_CACHE_INFO_EBX_0 {
+    pub const fn LineSize(&self) -> u32 {
+        self._bitfield & 4095
+    }
+    pub fn set_LineSize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !4095) | (value & 4095);
+    }
+    pub const fn Partitions(&self) -> u32 {
+        (self._bitfield >> 12) & 1023
+    }
+    pub fn set_Partitions(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1023 << 12)) | ((value & 1023) << 12);
+    }
+    pub const fn Associativity(&self) -> u32 {
+        (self._bitfield >> 22) & 1023
+    }
+    pub fn set_Associativity(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1023 << 22)) | ((value & 1023) << 22);
+    }
+}
 impl windows_core::TypeKind for INTEL_CACHE_INFO_EBX_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -15352,7 +16314,8 @@ impl Default for INTEL_CACHE_INFO_EBX_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -15404,7 +16367,8 @@ impl Default for IOMMU_DEVICE_CREATION_CONFIGURATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IOMMU_DEVICE_CREATION_CONFIGURATION_ACPI {
     pub InputMappingBase: u32,
     pub MappingsCount: u32,
@@ -15432,12 +16396,21 @@ impl Default for IOMMU_DMA_DOMAIN_CREATION_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IOMMU_DMA_DOMAIN_CREATION_FLAGS_0 {
     pub _bitfield: u64,
 }
-impl windows_core::TypeKind for IOMMU_DMA_DOMAIN_CREATION_FLAGS_0 {
-    type TypeKind = windows_core::CopyType;
+impl IOMMU_DMA_DOMAIN_CREATION_FLAGS_0 {
+    pub const fn Reserved(&self) -> u64 {
+        self._bitfield
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = value;
+    }
+}
+impl windows_core::TypeKind for IOMMU_DMA_DOMAIN_CREATION_FLAGS_0 {
+    type TypeKind = windows_core::CopyType;
 }
 impl Default for IOMMU_DMA_DOMAIN_CREATION_FLAGS_0 {
     fn default() -> Self {
@@ -15445,7 +16418,8 @@ impl Default for IOMMU_DMA_DOMAIN_CREATION_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IOMMU_DMA_LOGICAL_ADDRESS_TOKEN {
     pub LogicalAddressBase: u64,
     pub Size: usize,
@@ -15459,7 +16433,8 @@ impl Default for IOMMU_DMA_LOGICAL_ADDRESS_TOKEN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IOMMU_DMA_LOGICAL_ADDRESS_TOKEN_MAPPED_SEGMENT {
     pub OwningToken: *mut IOMMU_DMA_LOGICAL_ADDRESS_TOKEN,
     pub Offset: usize,
@@ -15501,7 +16476,8 @@ impl Default for IOMMU_DMA_LOGICAL_ALLOCATOR_CONFIG_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IOMMU_DMA_LOGICAL_ALLOCATOR_CONFIG_0_0 {
     pub AddressWidth: u32,
 }
@@ -15514,7 +16490,8 @@ impl Default for IOMMU_DMA_LOGICAL_ALLOCATOR_CONFIG_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IOMMU_DMA_RESERVED_REGION {
     pub RegionNext: *mut IOMMU_DMA_RESERVED_REGION,
     pub Base: u64,
@@ -15558,10 +16535,25 @@ impl Default for IOMMU_INTERFACE_STATE_CHANGE_FIELDS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IOMMU_INTERFACE_STATE_CHANGE_FIELDS_0 {
     pub _bitfield: u32,
 }
+impl IOMMU_INTERFACE_STATE_CHANGE_FIELDS_0 {
+    pub const fn AvailableDomainTypes(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_AvailableDomainTypes(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for IOMMU_INTERFACE_STATE_CHANGE_FIELDS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -15607,7 +16599,8 @@ impl Default for IOMMU_MAP_PHYSICAL_ADDRESS_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IOMMU_MAP_PHYSICAL_ADDRESS_0_0 {
     pub Base: i64,
     pub Size: usize,
@@ -15624,7 +16617,8 @@ impl Default for IOMMU_MAP_PHYSICAL_ADDRESS_0_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IOMMU_MAP_PHYSICAL_ADDRESS_0_1 {
     pub Mdl: *mut super::super::Foundation::MDL,
 }
@@ -15640,7 +16634,8 @@ impl Default for IOMMU_MAP_PHYSICAL_ADDRESS_0_1 {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IOMMU_MAP_PHYSICAL_ADDRESS_0_2 {
     pub PageFrame: *mut u32,
     pub NumberOfPages: usize,
@@ -15687,10 +16682,49 @@ impl Default for IO_ATTRIBUTION_INFORMATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_ATTRIBUTION_INFORMATION_0_0 {
     pub _bitfield: u32,
 }
+impl IO_ATTRIBUTION_INFORMATION_0_0 {
+    pub const fn MajorCode(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_MajorCode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn IoStart(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_IoStart(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn QueueOnly(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_QueueOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn IoFailed(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_IoFailed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn VirtualDevice(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_VirtualDevice(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn Spare(&self) -> u32 {
+        (self._bitfield >> 12) & 1048575
+    }
+    pub fn set_Spare(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1048575 << 12)) | ((value & 1048575) << 12);
+    }
+}
 impl windows_core::TypeKind for IO_ATTRIBUTION_INFORMATION_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -15701,7 +16735,8 @@ impl Default for IO_ATTRIBUTION_INFORMATION_0_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_CONNECT_INTERRUPT_FULLY_SPECIFIED_PARAMETERS {
     pub PhysicalDeviceObject: *mut super::super::Foundation::DEVICE_OBJECT,
     pub InterruptObject: *mut super::super::Foundation::PKINTERRUPT,
@@ -15729,7 +16764,8 @@ impl Default for IO_CONNECT_INTERRUPT_FULLY_SPECIFIED_PARAMETERS {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_CONNECT_INTERRUPT_LINE_BASED_PARAMETERS {
     pub PhysicalDeviceObject: *mut super::super::Foundation::DEVICE_OBJECT,
     pub InterruptObject: *mut super::super::Foundation::PKINTERRUPT,
@@ -15827,7 +16863,8 @@ impl Default for IO_CONNECT_INTERRUPT_PARAMETERS_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_CSQ {
     pub Type: u32,
     pub CsqInsertIrp: PIO_CSQ_INSERT_IRP,
@@ -15850,7 +16887,8 @@ impl Default for IO_CSQ {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_CSQ_IRP_CONTEXT {
     pub Type: u32,
     pub Irp: *mut super::super::Foundation::IRP,
@@ -15903,7 +16941,8 @@ impl Default for IO_DISCONNECT_INTERRUPT_PARAMETERS_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_DRIVER_CREATE_CONTEXT {
     pub Size: i16,
     pub ExtraCreateParameter: *mut isize,
@@ -15922,7 +16961,8 @@ impl Default for IO_DRIVER_CREATE_CONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_ERROR_LOG_MESSAGE {
     pub Type: u16,
     pub Size: u16,
@@ -15940,7 +16980,8 @@ impl Default for IO_ERROR_LOG_MESSAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_ERROR_LOG_PACKET {
     pub MajorFunctionCode: u8,
     pub RetryCount: u8,
@@ -15966,7 +17007,8 @@ impl Default for IO_ERROR_LOG_PACKET {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_FOEXT_SHADOW_FILE {
     pub BackingFileObject: *mut super::super::Foundation::FILE_OBJECT,
     pub BackingFltInstance: *mut core::ffi::c_void,
@@ -16018,11 +17060,27 @@ impl Default for IO_FOEXT_SILO_PARAMETERS_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_FOEXT_SILO_PARAMETERS_0_0 {
     pub _bitfield: u32,
 }
 #[cfg(feature = "Wdk_Foundation")]
+impl IO_FOEXT_SILO_PARAMETERS_0_0 {
+    pub const fn HasHardReference(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_HasHardReference(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn SpareFlags(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_SpareFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
+#[cfg(feature = "Wdk_Foundation")]
 impl windows_core::TypeKind for IO_FOEXT_SILO_PARAMETERS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -16034,7 +17092,8 @@ impl Default for IO_FOEXT_SILO_PARAMETERS_0_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_INTERRUPT_MESSAGE_INFO {
     pub UnifiedIrql: u8,
     pub MessageCount: u32,
@@ -16052,7 +17111,8 @@ impl Default for IO_INTERRUPT_MESSAGE_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_INTERRUPT_MESSAGE_INFO_ENTRY {
     pub MessageAddress: i64,
     pub TargetProcessorSet: usize,
@@ -16110,7 +17170,8 @@ impl Default for IO_REMOVE_LOCK_COMMON_BLOCK {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Win32_System_Kernel"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_REMOVE_LOCK_DBG_BLOCK {
     pub Signature: i32,
     pub HighWatermark: u32,
@@ -16213,7 +17274,8 @@ impl Default for IO_RESOURCE_DESCRIPTOR_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_RESOURCE_DESCRIPTOR_0_0 {
     pub Length: u32,
     pub MinBusNumber: u32,
@@ -16229,7 +17291,8 @@ impl Default for IO_RESOURCE_DESCRIPTOR_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_RESOURCE_DESCRIPTOR_0_1 {
     pub Priority: u32,
     pub Reserved1: u32,
@@ -16244,7 +17307,8 @@ impl Default for IO_RESOURCE_DESCRIPTOR_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_RESOURCE_DESCRIPTOR_0_2 {
     pub Class: u8,
     pub Type: u8,
@@ -16262,7 +17326,8 @@ impl Default for IO_RESOURCE_DESCRIPTOR_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_RESOURCE_DESCRIPTOR_0_3 {
     pub Data: [u32; 3],
 }
@@ -16275,7 +17340,8 @@ impl Default for IO_RESOURCE_DESCRIPTOR_0_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_RESOURCE_DESCRIPTOR_0_4 {
     pub RequestLine: u32,
     pub Reserved: u32,
@@ -16291,7 +17357,8 @@ impl Default for IO_RESOURCE_DESCRIPTOR_0_4 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_RESOURCE_DESCRIPTOR_0_5 {
     pub MinimumChannel: u32,
     pub MaximumChannel: u32,
@@ -16305,7 +17372,8 @@ impl Default for IO_RESOURCE_DESCRIPTOR_0_5 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_RESOURCE_DESCRIPTOR_0_6 {
     pub Length: u32,
     pub Alignment: u32,
@@ -16321,7 +17389,8 @@ impl Default for IO_RESOURCE_DESCRIPTOR_0_6 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_RESOURCE_DESCRIPTOR_0_7 {
     pub MinimumVector: u32,
     pub MaximumVector: u32,
@@ -16338,7 +17407,8 @@ impl Default for IO_RESOURCE_DESCRIPTOR_0_7 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_RESOURCE_DESCRIPTOR_0_8 {
     pub Length40: u32,
     pub Alignment40: u32,
@@ -16354,7 +17424,8 @@ impl Default for IO_RESOURCE_DESCRIPTOR_0_8 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_RESOURCE_DESCRIPTOR_0_9 {
     pub Length48: u32,
     pub Alignment48: u32,
@@ -16370,7 +17441,8 @@ impl Default for IO_RESOURCE_DESCRIPTOR_0_9 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_RESOURCE_DESCRIPTOR_0_10 {
     pub Length64: u32,
     pub Alignment64: u32,
@@ -16386,7 +17458,8 @@ impl Default for IO_RESOURCE_DESCRIPTOR_0_10 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_RESOURCE_DESCRIPTOR_0_11 {
     pub Length: u32,
     pub Alignment: u32,
@@ -16402,7 +17475,8 @@ impl Default for IO_RESOURCE_DESCRIPTOR_0_11 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_RESOURCE_DESCRIPTOR_0_12 {
     pub Length: u32,
     pub Alignment: u32,
@@ -16453,7 +17527,8 @@ impl Default for IO_RESOURCE_REQUIREMENTS_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_SESSION_CONNECT_INFO {
     pub SessionId: u32,
     pub LocalSession: super::super::super::Win32::Foundation::BOOLEAN,
@@ -16467,7 +17542,8 @@ impl Default for IO_SESSION_CONNECT_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_SESSION_STATE_INFORMATION {
     pub SessionId: u32,
     pub SessionState: IO_SESSION_STATE,
@@ -16482,7 +17558,8 @@ impl Default for IO_SESSION_STATE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_SESSION_STATE_NOTIFICATION {
     pub Size: u32,
     pub Flags: u32,
@@ -16499,7 +17576,8 @@ impl Default for IO_SESSION_STATE_NOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_STATUS_BLOCK32 {
     pub Status: super::super::super::Win32::Foundation::NTSTATUS,
     pub Information: u32,
@@ -16541,7 +17619,8 @@ impl Default for IO_STATUS_BLOCK64_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KADDRESS_RANGE {
     pub Address: *mut core::ffi::c_void,
     pub Size: usize,
@@ -16555,7 +17634,8 @@ impl Default for KADDRESS_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KADDRESS_RANGE_DESCRIPTOR {
     pub AddressRanges: *const KADDRESS_RANGE,
     pub AddressRangeCount: usize,
@@ -16570,7 +17650,8 @@ impl Default for KADDRESS_RANGE_DESCRIPTOR {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KAPC {
     pub Type: u8,
     pub AllFlags: u8,
@@ -16598,7 +17679,8 @@ impl Default for KAPC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KBUGCHECK_ADD_PAGES {
     pub Context: *mut core::ffi::c_void,
     pub Flags: u32,
@@ -16616,7 +17698,8 @@ impl Default for KBUGCHECK_ADD_PAGES {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KBUGCHECK_CALLBACK_RECORD {
     pub Entry: super::super::super::Win32::System::Kernel::LIST_ENTRY,
     pub CallbackRoutine: PKBUGCHECK_CALLBACK_ROUTINE,
@@ -16637,7 +17720,8 @@ impl Default for KBUGCHECK_CALLBACK_RECORD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KBUGCHECK_DUMP_IO {
     pub Offset: u64,
     pub Buffer: *mut core::ffi::c_void,
@@ -16654,7 +17738,8 @@ impl Default for KBUGCHECK_DUMP_IO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KBUGCHECK_REASON_CALLBACK_RECORD {
     pub Entry: super::super::super::Win32::System::Kernel::LIST_ENTRY,
     pub CallbackRoutine: PKBUGCHECK_REASON_CALLBACK_ROUTINE,
@@ -16674,7 +17759,8 @@ impl Default for KBUGCHECK_REASON_CALLBACK_RECORD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KBUGCHECK_REMOVE_PAGES {
     pub Context: *mut core::ffi::c_void,
     pub Flags: u32,
@@ -16691,7 +17777,8 @@ impl Default for KBUGCHECK_REMOVE_PAGES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KBUGCHECK_SECONDARY_DUMP_DATA {
     pub InBuffer: *mut core::ffi::c_void,
     pub InBufferLength: u32,
@@ -16709,7 +17796,8 @@ impl Default for KBUGCHECK_SECONDARY_DUMP_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KBUGCHECK_SECONDARY_DUMP_DATA_EX {
     pub InBuffer: *mut core::ffi::c_void,
     pub InBufferLength: u32,
@@ -16736,7 +17824,8 @@ impl Default for KBUGCHECK_SECONDARY_DUMP_DATA_EX {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KBUGCHECK_TRIAGE_DUMP_DATA {
     pub DataArray: *mut KTRIAGE_DUMP_DATA_ARRAY,
     pub Flags: u32,
@@ -16759,7 +17848,8 @@ impl Default for KBUGCHECK_TRIAGE_DUMP_DATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KDEVICE_QUEUE_ENTRY {
     pub DeviceListEntry: super::super::super::Win32::System::Kernel::LIST_ENTRY,
     pub SortKey: u32,
@@ -16776,7 +17866,8 @@ impl Default for KDEVICE_QUEUE_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KDPC_WATCHDOG_INFORMATION {
     pub DpcTimeLimit: u32,
     pub DpcTimeCount: u32,
@@ -16793,7 +17884,8 @@ impl Default for KDPC_WATCHDOG_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KERNEL_SOFT_RESTART_NOTIFICATION {
     pub Version: u16,
     pub Size: u16,
@@ -16808,7 +17900,8 @@ impl Default for KERNEL_SOFT_RESTART_NOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KERNEL_USER_TIMES {
     pub CreateTime: i64,
     pub ExitTime: i64,
@@ -16824,7 +17917,8 @@ impl Default for KERNEL_USER_TIMES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_BASIC_INFORMATION {
     pub LastWriteTime: i64,
     pub TitleIndex: u32,
@@ -16840,7 +17934,8 @@ impl Default for KEY_BASIC_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_CACHED_INFORMATION {
     pub LastWriteTime: i64,
     pub TitleIndex: u32,
@@ -16860,7 +17955,8 @@ impl Default for KEY_CACHED_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_CONTROL_FLAGS_INFORMATION {
     pub ControlFlags: u32,
 }
@@ -16873,7 +17969,8 @@ impl Default for KEY_CONTROL_FLAGS_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_FULL_INFORMATION {
     pub LastWriteTime: i64,
     pub TitleIndex: u32,
@@ -16896,10 +17993,43 @@ impl Default for KEY_FULL_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_LAYER_INFORMATION {
     pub _bitfield: u32,
 }
+impl KEY_LAYER_INFORMATION {
+    pub const fn IsTombstone(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_IsTombstone(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn IsSupersedeLocal(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_IsSupersedeLocal(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn IsSupersedeTree(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_IsSupersedeTree(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ClassIsInherited(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ClassIsInherited(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for KEY_LAYER_INFORMATION {
     type TypeKind = windows_core::CopyType;
 }
@@ -16909,7 +18039,8 @@ impl Default for KEY_LAYER_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_NAME_INFORMATION {
     pub NameLength: u32,
     pub Name: [u16; 1],
@@ -16923,7 +18054,8 @@ impl Default for KEY_NAME_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_NODE_INFORMATION {
     pub LastWriteTime: i64,
     pub TitleIndex: u32,
@@ -16941,10 +18073,37 @@ impl Default for KEY_NODE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_SET_VIRTUALIZATION_INFORMATION {
     pub _bitfield: u32,
 }
+impl KEY_SET_VIRTUALIZATION_INFORMATION {
+    pub const fn VirtualTarget(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_VirtualTarget(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn VirtualStore(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_VirtualStore(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn VirtualSource(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_VirtualSource(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for KEY_SET_VIRTUALIZATION_INFORMATION {
     type TypeKind = windows_core::CopyType;
 }
@@ -16954,10 +18113,25 @@ impl Default for KEY_SET_VIRTUALIZATION_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_TRUST_INFORMATION {
     pub _bitfield: u32,
 }
+impl KEY_TRUST_INFORMATION {
+    pub const fn TrustedKey(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_TrustedKey(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for KEY_TRUST_INFORMATION {
     type TypeKind = windows_core::CopyType;
 }
@@ -16967,7 +18141,8 @@ impl Default for KEY_TRUST_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_VALUE_BASIC_INFORMATION {
     pub TitleIndex: u32,
     pub Type: u32,
@@ -16983,7 +18158,8 @@ impl Default for KEY_VALUE_BASIC_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_VALUE_FULL_INFORMATION {
     pub TitleIndex: u32,
     pub Type: u32,
@@ -17001,10 +18177,25 @@ impl Default for KEY_VALUE_FULL_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_VALUE_LAYER_INFORMATION {
     pub _bitfield: u32,
 }
+impl KEY_VALUE_LAYER_INFORMATION {
+    pub const fn IsTombstone(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_IsTombstone(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for KEY_VALUE_LAYER_INFORMATION {
     type TypeKind = windows_core::CopyType;
 }
@@ -17014,7 +18205,8 @@ impl Default for KEY_VALUE_LAYER_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_VALUE_PARTIAL_INFORMATION {
     pub TitleIndex: u32,
     pub Type: u32,
@@ -17030,7 +18222,8 @@ impl Default for KEY_VALUE_PARTIAL_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_VALUE_PARTIAL_INFORMATION_ALIGN64 {
     pub Type: u32,
     pub DataLength: u32,
@@ -17045,10 +18238,49 @@ impl Default for KEY_VALUE_PARTIAL_INFORMATION_ALIGN64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_VIRTUALIZATION_INFORMATION {
     pub _bitfield: u32,
 }
+impl KEY_VIRTUALIZATION_INFORMATION {
+    pub const fn VirtualizationCandidate(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_VirtualizationCandidate(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn VirtualizationEnabled(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_VirtualizationEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn VirtualTarget(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_VirtualTarget(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn VirtualStore(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_VirtualStore(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn VirtualSource(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_VirtualSource(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 5) & 134217727
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(134217727 << 5)) | ((value & 134217727) << 5);
+    }
+}
 impl windows_core::TypeKind for KEY_VIRTUALIZATION_INFORMATION {
     type TypeKind = windows_core::CopyType;
 }
@@ -17058,7 +18290,8 @@ impl Default for KEY_VIRTUALIZATION_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_WOW64_FLAGS_INFORMATION {
     pub UserFlags: u32,
 }
@@ -17071,7 +18304,8 @@ impl Default for KEY_WOW64_FLAGS_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KEY_WRITE_TIME_INFORMATION {
     pub LastWriteTime: i64,
 }
@@ -17085,7 +18319,8 @@ impl Default for KEY_WRITE_TIME_INFORMATION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KE_PROCESSOR_CHANGE_NOTIFY_CONTEXT {
     pub State: KE_PROCESSOR_CHANGE_NOTIFY_STATE,
     pub NtNumber: u32,
@@ -17103,7 +18338,8 @@ impl Default for KE_PROCESSOR_CHANGE_NOTIFY_CONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KFLOATING_SAVE {
     pub ControlWord: u32,
     pub StatusWord: u32,
@@ -17139,7 +18375,8 @@ impl Default for KGATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KLOCK_QUEUE_HANDLE {
     pub LockQueue: KSPIN_LOCK_QUEUE,
     pub OldIrql: u8,
@@ -17170,7 +18407,8 @@ impl Default for KSEMAPHORE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KSPIN_LOCK_QUEUE {
     pub Next: *mut KSPIN_LOCK_QUEUE,
     pub Lock: *mut usize,
@@ -17184,7 +18422,8 @@ impl Default for KSPIN_LOCK_QUEUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KSYSTEM_TIME {
     pub LowPart: u32,
     pub High1Time: i32,
@@ -17220,7 +18459,8 @@ impl Default for KTIMER {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KTRIAGE_DUMP_DATA_ARRAY {
     pub List: super::super::super::Win32::System::Kernel::LIST_ENTRY,
     pub NumBlocksUsed: u32,
@@ -17355,11 +18595,39 @@ impl Default for KUSER_SHARED_DATA_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Diagnostics_Debug", feature = "Win32_System_Kernel"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KUSER_SHARED_DATA_0_0 {
     pub _bitfield: u8,
 }
 #[cfg(all(feature = "Win32_System_Diagnostics_Debug", feature = "Win32_System_Kernel"))]
+impl KUSER_SHARED_DATA_0_0 {
+    pub const fn NXSupportPolicy(&self) -> u8 {
+        self._bitfield & 3
+    }
+    pub fn set_NXSupportPolicy(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn SEHValidationPolicy(&self) -> u8 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_SEHValidationPolicy(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn CurDirDevicesSkippedForDlls(&self) -> u8 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_CurDirDevicesSkippedForDlls(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+}
+#[cfg(all(feature = "Win32_System_Diagnostics_Debug", feature = "Win32_System_Kernel"))]
 impl windows_core::TypeKind for KUSER_SHARED_DATA_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -17404,11 +18672,87 @@ impl Default for KUSER_SHARED_DATA_2 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Diagnostics_Debug", feature = "Win32_System_Kernel"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KUSER_SHARED_DATA_2_0 {
     pub _bitfield: u32,
 }
 #[cfg(all(feature = "Win32_System_Diagnostics_Debug", feature = "Win32_System_Kernel"))]
+impl KUSER_SHARED_DATA_2_0 {
+    pub const fn DbgErrorPortPresent(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_DbgErrorPortPresent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DbgElevationEnabled(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DbgElevationEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn DbgVirtEnabled(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_DbgVirtEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn DbgInstallerDetectEnabled(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_DbgInstallerDetectEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn DbgLkgEnabled(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_DbgLkgEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn DbgDynProcessorEnabled(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_DbgDynProcessorEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn DbgConsoleBrokerEnabled(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_DbgConsoleBrokerEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn DbgSecureBootEnabled(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_DbgSecureBootEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn DbgMultiSessionSku(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_DbgMultiSessionSku(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn DbgMultiUsersInSessionSku(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_DbgMultiUsersInSessionSku(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn DbgStateSeparationEnabled(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_DbgStateSeparationEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn SpareBits(&self) -> u32 {
+        (self._bitfield >> 11) & 2097151
+    }
+    pub fn set_SpareBits(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2097151 << 11)) | ((value & 2097151) << 11);
+    }
+}
+#[cfg(all(feature = "Win32_System_Diagnostics_Debug", feature = "Win32_System_Kernel"))]
 impl windows_core::TypeKind for KUSER_SHARED_DATA_2_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -17438,7 +18782,8 @@ impl Default for KUSER_SHARED_DATA_3 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Diagnostics_Debug", feature = "Win32_System_Kernel"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KUSER_SHARED_DATA_3_0 {
     pub ReservedTickCountOverlay: [u32; 3],
     pub TickCountPad: [u32; 1],
@@ -17472,7 +18817,8 @@ impl Default for KUSER_SHARED_DATA_4 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Diagnostics_Debug", feature = "Win32_System_Kernel"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KUSER_SHARED_DATA_4_0 {
     pub QpcBypassEnabled: u8,
     pub QpcShift: u8,
@@ -17488,7 +18834,8 @@ impl Default for KUSER_SHARED_DATA_4_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KWAIT_CHAIN {
     pub Head: *mut core::ffi::c_void,
 }
@@ -17501,7 +18848,8 @@ impl Default for KWAIT_CHAIN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LEGACY_BUS_INFORMATION {
     pub BusTypeGuid: windows_core::GUID,
     pub LegacyBusType: INTERFACE_TYPE,
@@ -17516,7 +18864,8 @@ impl Default for LEGACY_BUS_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LINK_SHARE_ACCESS {
     pub OpenCount: u32,
     pub Deleters: u32,
@@ -17561,7 +18910,8 @@ impl Default for LOADER_PARTITION_INFORMATION_EX_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MAILSLOT_CREATE_PARAMETERS {
     pub MailslotQuota: u32,
     pub MaximumMessageSize: u32,
@@ -17577,7 +18927,8 @@ impl Default for MAILSLOT_CREATE_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MAP_REGISTER_ENTRY {
     pub MapRegister: *mut core::ffi::c_void,
     pub WriteToDevice: super::super::super::Win32::Foundation::BOOLEAN,
@@ -17592,7 +18943,8 @@ impl Default for MAP_REGISTER_ENTRY {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MCA_DRIVER_INFO {
     pub ExceptionCallback: isize,
     pub DpcCallback: super::super::Foundation::PKDEFERRED_ROUTINE,
@@ -17661,7 +19013,8 @@ impl Default for MCA_EXCEPTION_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MCA_EXCEPTION_0_1 {
     pub Address: u64,
     pub Type: u64,
@@ -17693,6 +19046,74 @@ impl Default for MCG_CAP {
 pub struct MCG_CAP_0 {
     pub _bitfield: u64,
 }
+impl MCG_CAP_0 {
+    pub const fn CountField(&self) -> u64 {
+        self._bitfield & 255
+    }
+    pub fn set_CountField(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn ControlMsrPresent(&self) -> u64 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_ControlMsrPresent(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn ExtendedMsrsPresent(&self) -> u64 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_ExtendedMsrsPresent(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn SignalingExtensionPresent(&self) -> u64 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_SignalingExtensionPresent(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn ThresholdErrorStatusPresent(&self) -> u64 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_ThresholdErrorStatusPresent(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+    pub const fn ExtendedRegisterCount(&self) -> u64 {
+        (self._bitfield >> 16) & 255
+    }
+    pub fn set_ExtendedRegisterCount(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 16)) | ((value & 255) << 16);
+    }
+    pub const fn SoftwareErrorRecoverySupported(&self) -> u64 {
+        (self._bitfield >> 24) & 1
+    }
+    pub fn set_SoftwareErrorRecoverySupported(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 24)) | ((value & 1) << 24);
+    }
+    pub const fn EnhancedMachineCheckCapability(&self) -> u64 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_EnhancedMachineCheckCapability(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn ExtendedErrorLogging(&self) -> u64 {
+        (self._bitfield >> 26) & 1
+    }
+    pub fn set_ExtendedErrorLogging(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 26)) | ((value & 1) << 26);
+    }
+    pub const fn LocalMachineCheckException(&self) -> u64 {
+        (self._bitfield >> 27) & 1
+    }
+    pub fn set_LocalMachineCheckException(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 27)) | ((value & 1) << 27);
+    }
+}
 impl windows_core::TypeKind for MCG_CAP_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -17721,6 +19142,38 @@ pub struct MCG_STATUS_0 {
     pub _bitfield: u32,
     pub Reserved2: u32,
 }
+impl MCG_STATUS_0 {
+    pub const fn RestartIpValid(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_RestartIpValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ErrorIpValid(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ErrorIpValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MachineCheckInProgress(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_MachineCheckInProgress(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn LocalMceValid(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_LocalMceValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for MCG_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -17744,7 +19197,8 @@ impl Default for MCI_ADDR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MCI_ADDR_0 {
     pub Address: u32,
     pub Reserved: u32,
@@ -17772,12 +19226,63 @@ impl Default for MCI_STATS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MCI_STATS_0 {
     pub McaCod: u16,
     pub MsCod: u16,
     pub _bitfield: u32,
 }
+impl MCI_STATS_0 {
+    pub const fn OtherInfo(&self) -> u32 {
+        self._bitfield & 33554431
+    }
+    pub fn set_OtherInfo(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !33554431) | (value & 33554431);
+    }
+    pub const fn Damage(&self) -> u32 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_Damage(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn AddressValid(&self) -> u32 {
+        (self._bitfield >> 26) & 1
+    }
+    pub fn set_AddressValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 26)) | ((value & 1) << 26);
+    }
+    pub const fn MiscValid(&self) -> u32 {
+        (self._bitfield >> 27) & 1
+    }
+    pub fn set_MiscValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 27)) | ((value & 1) << 27);
+    }
+    pub const fn Enabled(&self) -> u32 {
+        (self._bitfield >> 28) & 1
+    }
+    pub fn set_Enabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 28)) | ((value & 1) << 28);
+    }
+    pub const fn UnCorrected(&self) -> u32 {
+        (self._bitfield >> 29) & 1
+    }
+    pub fn set_UnCorrected(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 29)) | ((value & 1) << 29);
+    }
+    pub const fn OverFlow(&self) -> u32 {
+        (self._bitfield >> 30) & 1
+    }
+    pub fn set_OverFlow(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 30)) | ((value & 1) << 30);
+    }
+    pub const fn Valid(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_Valid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for MCI_STATS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -17807,6 +19312,86 @@ impl Default for MCI_STATUS {
 pub struct MCI_STATUS_AMD_BITS {
     pub _bitfield: u64,
 }
+impl MCI_STATUS_AMD_BITS {
+    pub const fn McaErrorCode(&self) -> u64 {
+        self._bitfield & 65535
+    }
+    pub fn set_McaErrorCode(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn ModelErrorCode(&self) -> u64 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_ModelErrorCode(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+    pub const fn ImplementationSpecific2(&self) -> u64 {
+        (self._bitfield >> 32) & 2047
+    }
+    pub fn set_ImplementationSpecific2(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(2047 << 32)) | ((value & 2047) << 32);
+    }
+    pub const fn Poison(&self) -> u64 {
+        (self._bitfield >> 43) & 1
+    }
+    pub fn set_Poison(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 43)) | ((value & 1) << 43);
+    }
+    pub const fn Deferred(&self) -> u64 {
+        (self._bitfield >> 44) & 1
+    }
+    pub fn set_Deferred(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 44)) | ((value & 1) << 44);
+    }
+    pub const fn ImplementationSpecific1(&self) -> u64 {
+        (self._bitfield >> 45) & 4095
+    }
+    pub fn set_ImplementationSpecific1(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(4095 << 45)) | ((value & 4095) << 45);
+    }
+    pub const fn ContextCorrupt(&self) -> u64 {
+        (self._bitfield >> 57) & 1
+    }
+    pub fn set_ContextCorrupt(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 57)) | ((value & 1) << 57);
+    }
+    pub const fn AddressValid(&self) -> u64 {
+        (self._bitfield >> 58) & 1
+    }
+    pub fn set_AddressValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 58)) | ((value & 1) << 58);
+    }
+    pub const fn MiscValid(&self) -> u64 {
+        (self._bitfield >> 59) & 1
+    }
+    pub fn set_MiscValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 59)) | ((value & 1) << 59);
+    }
+    pub const fn ErrorEnabled(&self) -> u64 {
+        (self._bitfield >> 60) & 1
+    }
+    pub fn set_ErrorEnabled(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 60)) | ((value & 1) << 60);
+    }
+    pub const fn UncorrectedError(&self) -> u64 {
+        (self._bitfield >> 61) & 1
+    }
+    pub fn set_UncorrectedError(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 61)) | ((value & 1) << 61);
+    }
+    pub const fn StatusOverFlow(&self) -> u64 {
+        (self._bitfield >> 62) & 1
+    }
+    pub fn set_StatusOverFlow(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 62)) | ((value & 1) << 62);
+    }
+    pub const fn Valid(&self) -> u64 {
+        (self._bitfield >> 63) & 1
+    }
+    pub fn set_Valid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 63)) | ((value & 1) << 63);
+    }
+}
 impl windows_core::TypeKind for MCI_STATUS_AMD_BITS {
     type TypeKind = windows_core::CopyType;
 }
@@ -17820,6 +19405,68 @@ impl Default for MCI_STATUS_AMD_BITS {
 pub struct MCI_STATUS_BITS_COMMON {
     pub _bitfield: u64,
 }
+impl MCI_STATUS_BITS_COMMON {
+    pub const fn McaErrorCode(&self) -> u64 {
+        self._bitfield & 65535
+    }
+    pub fn set_McaErrorCode(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn ModelErrorCode(&self) -> u64 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_ModelErrorCode(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 32) & 33554431
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(33554431 << 32)) | ((value & 33554431) << 32);
+    }
+    pub const fn ContextCorrupt(&self) -> u64 {
+        (self._bitfield >> 57) & 1
+    }
+    pub fn set_ContextCorrupt(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 57)) | ((value & 1) << 57);
+    }
+    pub const fn AddressValid(&self) -> u64 {
+        (self._bitfield >> 58) & 1
+    }
+    pub fn set_AddressValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 58)) | ((value & 1) << 58);
+    }
+    pub const fn MiscValid(&self) -> u64 {
+        (self._bitfield >> 59) & 1
+    }
+    pub fn set_MiscValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 59)) | ((value & 1) << 59);
+    }
+    pub const fn ErrorEnabled(&self) -> u64 {
+        (self._bitfield >> 60) & 1
+    }
+    pub fn set_ErrorEnabled(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 60)) | ((value & 1) << 60);
+    }
+    pub const fn UncorrectedError(&self) -> u64 {
+        (self._bitfield >> 61) & 1
+    }
+    pub fn set_UncorrectedError(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 61)) | ((value & 1) << 61);
+    }
+    pub const fn StatusOverFlow(&self) -> u64 {
+        (self._bitfield >> 62) & 1
+    }
+    pub fn set_StatusOverFlow(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 62)) | ((value & 1) << 62);
+    }
+    pub const fn Valid(&self) -> u64 {
+        (self._bitfield >> 63) & 1
+    }
+    pub fn set_Valid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 63)) | ((value & 1) << 63);
+    }
+}
 impl windows_core::TypeKind for MCI_STATUS_BITS_COMMON {
     type TypeKind = windows_core::CopyType;
 }
@@ -17833,6 +19480,98 @@ impl Default for MCI_STATUS_BITS_COMMON {
 pub struct MCI_STATUS_INTEL_BITS {
     pub _bitfield: u64,
 }
+impl MCI_STATUS_INTEL_BITS {
+    pub const fn McaErrorCode(&self) -> u64 {
+        self._bitfield & 65535
+    }
+    pub fn set_McaErrorCode(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn ModelErrorCode(&self) -> u64 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_ModelErrorCode(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+    pub const fn OtherInfo(&self) -> u64 {
+        (self._bitfield >> 32) & 31
+    }
+    pub fn set_OtherInfo(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(31 << 32)) | ((value & 31) << 32);
+    }
+    pub const fn FirmwareUpdateError(&self) -> u64 {
+        (self._bitfield >> 37) & 1
+    }
+    pub fn set_FirmwareUpdateError(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 37)) | ((value & 1) << 37);
+    }
+    pub const fn CorrectedErrorCount(&self) -> u64 {
+        (self._bitfield >> 38) & 32767
+    }
+    pub fn set_CorrectedErrorCount(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(32767 << 38)) | ((value & 32767) << 38);
+    }
+    pub const fn ThresholdErrorStatus(&self) -> u64 {
+        (self._bitfield >> 53) & 3
+    }
+    pub fn set_ThresholdErrorStatus(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(3 << 53)) | ((value & 3) << 53);
+    }
+    pub const fn ActionRequired(&self) -> u64 {
+        (self._bitfield >> 55) & 1
+    }
+    pub fn set_ActionRequired(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 55)) | ((value & 1) << 55);
+    }
+    pub const fn Signalling(&self) -> u64 {
+        (self._bitfield >> 56) & 1
+    }
+    pub fn set_Signalling(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 56)) | ((value & 1) << 56);
+    }
+    pub const fn ContextCorrupt(&self) -> u64 {
+        (self._bitfield >> 57) & 1
+    }
+    pub fn set_ContextCorrupt(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 57)) | ((value & 1) << 57);
+    }
+    pub const fn AddressValid(&self) -> u64 {
+        (self._bitfield >> 58) & 1
+    }
+    pub fn set_AddressValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 58)) | ((value & 1) << 58);
+    }
+    pub const fn MiscValid(&self) -> u64 {
+        (self._bitfield >> 59) & 1
+    }
+    pub fn set_MiscValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 59)) | ((value & 1) << 59);
+    }
+    pub const fn ErrorEnabled(&self) -> u64 {
+        (self._bitfield >> 60) & 1
+    }
+    pub fn set_ErrorEnabled(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 60)) | ((value & 1) << 60);
+    }
+    pub const fn UncorrectedError(&self) -> u64 {
+        (self._bitfield >> 61) & 1
+    }
+    pub fn set_UncorrectedError(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 61)) | ((value & 1) << 61);
+    }
+    pub const fn StatusOverFlow(&self) -> u64 {
+        (self._bitfield >> 62) & 1
+    }
+    pub fn set_StatusOverFlow(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 62)) | ((value & 1) << 62);
+    }
+    pub const fn Valid(&self) -> u64 {
+        (self._bitfield >> 63) & 1
+    }
+    pub fn set_Valid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 63)) | ((value & 1) << 63);
+    }
+}
 impl windows_core::TypeKind for MCI_STATUS_INTEL_BITS {
     type TypeKind = windows_core::CopyType;
 }
@@ -17842,7 +19581,8 @@ impl Default for MCI_STATUS_INTEL_BITS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MEMORY_PARTITION_DEDICATED_MEMORY_OPEN_INFORMATION {
     pub DedicatedMemoryTypeId: u64,
     pub HandleAttributes: u32,
@@ -17885,7 +19625,8 @@ impl Default for MM_COPY_ADDRESS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MM_PHYSICAL_ADDRESS_LIST {
     pub PhysicalAddress: i64,
     pub NumberOfBytes: usize,
@@ -17917,7 +19658,8 @@ impl Default for MU_TELEMETRY_SECTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NAMED_PIPE_CREATE_PARAMETERS {
     pub NamedPipeType: u32,
     pub ReadMode: u32,
@@ -17951,24 +19693,106 @@ impl Default for NPEM_CAPABILITY_STANDARD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NPEM_CAPABILITY_STANDARD_0 {
     pub _bitfield: u32,
 }
-impl windows_core::TypeKind for NPEM_CAPABILITY_STANDARD_0 {
-    type TypeKind = windows_core::CopyType;
-}
-impl Default for NPEM_CAPABILITY_STANDARD_0 {
-    fn default() -> Self {
-        unsafe { core::mem::zeroed() }
+impl NPEM_CAPABILITY_STANDARD_0 {
+    pub const fn Capable(&self) -> u32 {
+        self._bitfield & 1
     }
-}
-#[repr(C)]
-#[derive(Clone, Copy, Debug)]
-pub struct NPEM_CONTROL_INTERFACE {
-    pub Size: u16,
-    pub Version: u16,
-    pub Context: *mut core::ffi::c_void,
+    pub fn set_Capable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ResetCapable(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ResetCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn OkCapable(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_OkCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn LocateCapable(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_LocateCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn FailCapable(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_FailCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn RebuildCapable(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_RebuildCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn PFACapable(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_PFACapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn HotSpareCapable(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_HotSpareCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn InACriticalArrayCapable(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_InACriticalArrayCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn InAFailedArrayCapable(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_InAFailedArrayCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn InvalidDeviceTypeCapable(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_InvalidDeviceTypeCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn DisabledCapable(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_DisabledCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 12) & 1048575
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1048575 << 12)) | ((value & 1048575) << 12);
+    }
+}
+impl windows_core::TypeKind for NPEM_CAPABILITY_STANDARD_0 {
+    type TypeKind = windows_core::CopyType;
+}
+impl Default for NPEM_CAPABILITY_STANDARD_0 {
+    fn default() -> Self {
+        unsafe { core::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct NPEM_CONTROL_INTERFACE {
+    pub Size: u16,
+    pub Version: u16,
+    pub Context: *mut core::ffi::c_void,
     pub InterfaceReference: PINTERFACE_REFERENCE,
     pub InterfaceDereference: PINTERFACE_DEREFERENCE,
     pub SetNpemSupportState: PNPEM_CONTROL_ENABLE_DISABLE,
@@ -18018,7 +19842,8 @@ impl Default for NT_TIB32_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OBJECT_HANDLE_INFORMATION {
     pub HandleAttributes: u32,
     pub GrantedAccess: u32,
@@ -18033,7 +19858,8 @@ impl Default for OBJECT_HANDLE_INFORMATION {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OB_CALLBACK_REGISTRATION {
     pub Version: u16,
     pub OperationRegistrationCount: u16,
@@ -18053,7 +19879,8 @@ impl Default for OB_CALLBACK_REGISTRATION {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OB_OPERATION_REGISTRATION {
     pub ObjectType: *mut super::super::Foundation::POBJECT_TYPE,
     pub Operations: u32,
@@ -18071,7 +19898,8 @@ impl Default for OB_OPERATION_REGISTRATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OB_POST_CREATE_HANDLE_INFORMATION {
     pub GrantedAccess: u32,
 }
@@ -18084,7 +19912,8 @@ impl Default for OB_POST_CREATE_HANDLE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OB_POST_DUPLICATE_HANDLE_INFORMATION {
     pub GrantedAccess: u32,
 }
@@ -18137,11 +19966,27 @@ impl Default for OB_POST_OPERATION_INFORMATION_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OB_POST_OPERATION_INFORMATION_0_0 {
     pub _bitfield: u32,
 }
 #[cfg(feature = "Wdk_Foundation")]
+impl OB_POST_OPERATION_INFORMATION_0_0 {
+    pub const fn KernelHandle(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_KernelHandle(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
+#[cfg(feature = "Wdk_Foundation")]
 impl windows_core::TypeKind for OB_POST_OPERATION_INFORMATION_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -18166,7 +20011,8 @@ impl Default for OB_POST_OPERATION_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OB_PRE_CREATE_HANDLE_INFORMATION {
     pub DesiredAccess: u32,
     pub OriginalDesiredAccess: u32,
@@ -18180,7 +20026,8 @@ impl Default for OB_PRE_CREATE_HANDLE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OB_PRE_DUPLICATE_HANDLE_INFORMATION {
     pub DesiredAccess: u32,
     pub OriginalDesiredAccess: u32,
@@ -18235,11 +20082,27 @@ impl Default for OB_PRE_OPERATION_INFORMATION_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OB_PRE_OPERATION_INFORMATION_0_0 {
     pub _bitfield: u32,
 }
 #[cfg(feature = "Wdk_Foundation")]
+impl OB_PRE_OPERATION_INFORMATION_0_0 {
+    pub const fn KernelHandle(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_KernelHandle(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
+#[cfg(feature = "Wdk_Foundation")]
 impl windows_core::TypeKind for OB_PRE_OPERATION_INFORMATION_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -18264,7 +20127,8 @@ impl Default for OB_PRE_OPERATION_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PAGE_PRIORITY_INFORMATION {
     pub PagePriority: u32,
 }
@@ -18334,10 +20198,91 @@ impl Default for PCIX_BRIDGE_CAPABILITY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCIX_BRIDGE_CAPABILITY_0_0 {
     pub _bitfield: u32,
 }
+impl PCIX_BRIDGE_CAPABILITY_0_0 {
+    pub const fn FunctionNumber(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_FunctionNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn DeviceNumber(&self) -> u32 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_DeviceNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn BusNumber(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_BusNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn Device64Bit(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_Device64Bit(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn Device133MHzCapable(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_Device133MHzCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn SplitCompletionDiscarded(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_SplitCompletionDiscarded(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn UnexpectedSplitCompletion(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_UnexpectedSplitCompletion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn SplitCompletionOverrun(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_SplitCompletionOverrun(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn SplitRequestDelayed(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_SplitRequestDelayed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 22) & 127
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(127 << 22)) | ((value & 127) << 22);
+    }
+    pub const fn DIMCapable(&self) -> u32 {
+        (self._bitfield >> 29) & 1
+    }
+    pub fn set_DIMCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 29)) | ((value & 1) << 29);
+    }
+    pub const fn Device266MHzCapable(&self) -> u32 {
+        (self._bitfield >> 30) & 1
+    }
+    pub fn set_Device266MHzCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 30)) | ((value & 1) << 30);
+    }
+    pub const fn Device533MHzCapable(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_Device533MHzCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for PCIX_BRIDGE_CAPABILITY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -18361,10 +20306,97 @@ impl Default for PCIX_BRIDGE_CAPABILITY_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCIX_BRIDGE_CAPABILITY_1_0 {
     pub _bitfield: u32,
 }
+impl PCIX_BRIDGE_CAPABILITY_1_0 {
+    pub const fn SelectSecondaryRegisters(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_SelectSecondaryRegisters(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ErrorPresentInOtherBank(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ErrorPresentInOtherBank(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn AdditionalCorrectableError(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_AdditionalCorrectableError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AdditionalUncorrectableError(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AdditionalUncorrectableError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ErrorPhase(&self) -> u32 {
+        (self._bitfield >> 4) & 7
+    }
+    pub fn set_ErrorPhase(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 4)) | ((value & 7) << 4);
+    }
+    pub const fn ErrorCorrected(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_ErrorCorrected(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Syndrome(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_Syndrome(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn ErrorFirstCommand(&self) -> u32 {
+        (self._bitfield >> 16) & 15
+    }
+    pub fn set_ErrorFirstCommand(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 16)) | ((value & 15) << 16);
+    }
+    pub const fn ErrorSecondCommand(&self) -> u32 {
+        (self._bitfield >> 20) & 15
+    }
+    pub fn set_ErrorSecondCommand(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 20)) | ((value & 15) << 20);
+    }
+    pub const fn ErrorUpperAttributes(&self) -> u32 {
+        (self._bitfield >> 24) & 15
+    }
+    pub fn set_ErrorUpperAttributes(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 24)) | ((value & 15) << 24);
+    }
+    pub const fn ControlUpdateEnable(&self) -> u32 {
+        (self._bitfield >> 28) & 1
+    }
+    pub fn set_ControlUpdateEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 28)) | ((value & 1) << 28);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 29) & 1
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 29)) | ((value & 1) << 29);
+    }
+    pub const fn DisableSingleBitCorrection(&self) -> u32 {
+        (self._bitfield >> 30) & 1
+    }
+    pub fn set_DisableSingleBitCorrection(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 30)) | ((value & 1) << 30);
+    }
+    pub const fn EccMode(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_EccMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for PCIX_BRIDGE_CAPABILITY_1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -18388,10 +20420,79 @@ impl Default for PCIX_BRIDGE_CAPABILITY_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCIX_BRIDGE_CAPABILITY_2_0 {
     pub _bitfield: u16,
 }
+impl PCIX_BRIDGE_CAPABILITY_2_0 {
+    pub const fn Bus64Bit(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_Bus64Bit(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Bus133MHzCapable(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Bus133MHzCapable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn SplitCompletionDiscarded(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_SplitCompletionDiscarded(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn UnexpectedSplitCompletion(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_UnexpectedSplitCompletion(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn SplitCompletionOverrun(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_SplitCompletionOverrun(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn SplitRequestDelayed(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_SplitRequestDelayed(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn BusModeFrequency(&self) -> u16 {
+        (self._bitfield >> 6) & 15
+    }
+    pub fn set_BusModeFrequency(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 6)) | ((value & 15) << 6);
+    }
+    pub const fn Rsvd(&self) -> u16 {
+        (self._bitfield >> 10) & 3
+    }
+    pub fn set_Rsvd(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 10)) | ((value & 3) << 10);
+    }
+    pub const fn Version(&self) -> u16 {
+        (self._bitfield >> 12) & 3
+    }
+    pub fn set_Version(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 12)) | ((value & 3) << 12);
+    }
+    pub const fn Bus266MHzCapable(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_Bus266MHzCapable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn Bus533MHzCapable(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_Bus533MHzCapable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for PCIX_BRIDGE_CAPABILITY_2_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -18432,10 +20533,31 @@ impl Default for PCI_ADVANCED_FEATURES_CAPABILITY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_ADVANCED_FEATURES_CAPABILITY_0_0 {
     pub _bitfield: u8,
 }
+impl PCI_ADVANCED_FEATURES_CAPABILITY_0_0 {
+    pub const fn FunctionLevelResetSupported(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_FunctionLevelResetSupported(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn TransactionsPendingSupported(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_TransactionsPendingSupported(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Rsvd(&self) -> u8 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_Rsvd(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+}
 impl windows_core::TypeKind for PCI_ADVANCED_FEATURES_CAPABILITY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -18459,10 +20581,25 @@ impl Default for PCI_ADVANCED_FEATURES_CAPABILITY_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_ADVANCED_FEATURES_CAPABILITY_1_0 {
     pub _bitfield: u8,
 }
+impl PCI_ADVANCED_FEATURES_CAPABILITY_1_0 {
+    pub const fn InitiateFunctionLevelReset(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_InitiateFunctionLevelReset(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Rsvd(&self) -> u8 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_Rsvd(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+}
 impl windows_core::TypeKind for PCI_ADVANCED_FEATURES_CAPABILITY_1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -18486,10 +20623,25 @@ impl Default for PCI_ADVANCED_FEATURES_CAPABILITY_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_ADVANCED_FEATURES_CAPABILITY_2_0 {
     pub _bitfield: u8,
 }
+impl PCI_ADVANCED_FEATURES_CAPABILITY_2_0 {
+    pub const fn TransactionsPending(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_TransactionsPending(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Rsvd(&self) -> u8 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_Rsvd(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+}
 impl windows_core::TypeKind for PCI_ADVANCED_FEATURES_CAPABILITY_2_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -18499,10 +20651,31 @@ impl Default for PCI_ADVANCED_FEATURES_CAPABILITY_2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_AGP_APERTURE_PAGE_SIZE {
     pub _bitfield: u16,
 }
+impl PCI_AGP_APERTURE_PAGE_SIZE {
+    pub const fn PageSizeMask(&self) -> u16 {
+        self._bitfield & 2047
+    }
+    pub fn set_PageSizeMask(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !2047) | (value & 2047);
+    }
+    pub const fn Rsvd1(&self) -> u16 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_Rsvd1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn PageSizeSelect(&self) -> u16 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_PageSizeSelect(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+}
 impl windows_core::TypeKind for PCI_AGP_APERTURE_PAGE_SIZE {
     type TypeKind = windows_core::CopyType;
 }
@@ -18512,13 +20685,34 @@ impl Default for PCI_AGP_APERTURE_PAGE_SIZE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_AGP_CAPABILITY {
     pub Header: PCI_CAPABILITIES_HEADER,
     pub _bitfield: u16,
     pub AGPStatus: PCI_AGP_CAPABILITY_1,
     pub AGPCommand: PCI_AGP_CAPABILITY_0,
 }
+impl PCI_AGP_CAPABILITY {
+    pub const fn Minor(&self) -> u16 {
+        self._bitfield & 15
+    }
+    pub fn set_Minor(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Major(&self) -> u16 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Major(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn Rsvd1(&self) -> u16 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_Rsvd1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+}
 impl windows_core::TypeKind for PCI_AGP_CAPABILITY {
     type TypeKind = windows_core::CopyType;
 }
@@ -18528,10 +20722,85 @@ impl Default for PCI_AGP_CAPABILITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_AGP_CAPABILITY_0 {
     pub _bitfield: u32,
 }
+impl PCI_AGP_CAPABILITY_0 {
+    pub const fn Rate(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_Rate(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Rsvd1(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Rsvd1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn FastWriteEnable(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_FastWriteEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn FourGBEnable(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_FourGBEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Gart64(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Gart64(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn AGPEnable(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_AGPEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn SBAEnable(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_SBAEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn CalibrationCycle(&self) -> u32 {
+        (self._bitfield >> 10) & 7
+    }
+    pub fn set_CalibrationCycle(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 10)) | ((value & 7) << 10);
+    }
+    pub const fn AsyncReqSize(&self) -> u32 {
+        (self._bitfield >> 13) & 7
+    }
+    pub fn set_AsyncReqSize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 13)) | ((value & 7) << 13);
+    }
+    pub const fn Rsvd3(&self) -> u32 {
+        (self._bitfield >> 16) & 255
+    }
+    pub fn set_Rsvd3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 16)) | ((value & 255) << 16);
+    }
+    pub const fn RequestQueueDepth(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_RequestQueueDepth(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for PCI_AGP_CAPABILITY_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -18541,10 +20810,97 @@ impl Default for PCI_AGP_CAPABILITY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_AGP_CAPABILITY_1 {
     pub _bitfield: u32,
 }
+impl PCI_AGP_CAPABILITY_1 {
+    pub const fn Rate(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_Rate(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Agp3Mode(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Agp3Mode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn FastWrite(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_FastWrite(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn FourGB(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_FourGB(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn HostTransDisable(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_HostTransDisable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Gart64(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Gart64(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn ITA_Coherent(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_ITA_Coherent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn SideBandAddressing(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_SideBandAddressing(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn CalibrationCycle(&self) -> u32 {
+        (self._bitfield >> 10) & 7
+    }
+    pub fn set_CalibrationCycle(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 10)) | ((value & 7) << 10);
+    }
+    pub const fn AsyncRequestSize(&self) -> u32 {
+        (self._bitfield >> 13) & 7
+    }
+    pub fn set_AsyncRequestSize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 13)) | ((value & 7) << 13);
+    }
+    pub const fn Rsvd1(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_Rsvd1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn Isoch(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_Isoch(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 18) & 63
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 18)) | ((value & 63) << 18);
+    }
+    pub const fn RequestQueueDepthMaximum(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_RequestQueueDepthMaximum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for PCI_AGP_CAPABILITY_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -18554,10 +20910,43 @@ impl Default for PCI_AGP_CAPABILITY_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_AGP_CONTROL {
     pub _bitfield: u32,
 }
+impl PCI_AGP_CONTROL {
+    pub const fn Rsvd1(&self) -> u32 {
+        self._bitfield & 127
+    }
+    pub fn set_Rsvd1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !127) | (value & 127);
+    }
+    pub const fn GTLB_Enable(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_GTLB_Enable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn AP_Enable(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_AP_Enable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn CAL_Disable(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_CAL_Disable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 10) & 4194303
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4194303 << 10)) | ((value & 4194303) << 10);
+    }
+}
 impl windows_core::TypeKind for PCI_AGP_CONTROL {
     type TypeKind = windows_core::CopyType;
 }
@@ -18567,7 +20956,8 @@ impl Default for PCI_AGP_CONTROL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_AGP_EXTENDED_CAPABILITY {
     pub IsochStatus: PCI_AGP_ISOCH_STATUS,
     pub AgpControl: PCI_AGP_CONTROL,
@@ -18586,10 +20976,31 @@ impl Default for PCI_AGP_EXTENDED_CAPABILITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_AGP_ISOCH_COMMAND {
     pub _bitfield: u16,
 }
+impl PCI_AGP_ISOCH_COMMAND {
+    pub const fn Rsvd1(&self) -> u16 {
+        self._bitfield & 63
+    }
+    pub fn set_Rsvd1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !63) | (value & 63);
+    }
+    pub const fn Isoch_Y(&self) -> u16 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_Isoch_Y(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn Isoch_N(&self) -> u16 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_Isoch_N(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+}
 impl windows_core::TypeKind for PCI_AGP_ISOCH_COMMAND {
     type TypeKind = windows_core::CopyType;
 }
@@ -18599,10 +21010,49 @@ impl Default for PCI_AGP_ISOCH_COMMAND {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_AGP_ISOCH_STATUS {
     pub _bitfield: u32,
 }
+impl PCI_AGP_ISOCH_STATUS {
+    pub const fn ErrorCode(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_ErrorCode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn Rsvd1(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Rsvd1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Isoch_L(&self) -> u32 {
+        (self._bitfield >> 3) & 7
+    }
+    pub fn set_Isoch_L(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 3)) | ((value & 7) << 3);
+    }
+    pub const fn Isoch_Y(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_Isoch_Y(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn Isoch_N(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_Isoch_N(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for PCI_AGP_ISOCH_STATUS {
     type TypeKind = windows_core::CopyType;
 }
@@ -18612,7 +21062,8 @@ impl Default for PCI_AGP_ISOCH_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_ATS_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -18631,7 +21082,8 @@ impl Default for PCI_ATS_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_BUS_INTERFACE_STANDARD {
     pub Size: u16,
     pub Version: u16,
@@ -18655,7 +21107,8 @@ impl Default for PCI_BUS_INTERFACE_STANDARD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_CAPABILITIES_HEADER {
     pub CapabilityID: u8,
     pub Next: u8,
@@ -18723,7 +21176,8 @@ impl Default for PCI_COMMON_HEADER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_COMMON_HEADER_0_0 {
     pub BaseAddresses: [u32; 6],
     pub CIS: u32,
@@ -18747,7 +21201,8 @@ impl Default for PCI_COMMON_HEADER_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_COMMON_HEADER_0_1 {
     pub BaseAddresses: [u32; 2],
     pub PrimaryBus: u8,
@@ -18781,7 +21236,8 @@ impl Default for PCI_COMMON_HEADER_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_COMMON_HEADER_0_2 {
     pub SocketRegistersBaseAddress: u32,
     pub CapabilitiesPtr: u8,
@@ -18805,7 +21261,8 @@ impl Default for PCI_COMMON_HEADER_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_COMMON_HEADER_0_2_0 {
     pub Base: u32,
     pub Limit: u32,
@@ -18819,7 +21276,8 @@ impl Default for PCI_COMMON_HEADER_0_2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_DEBUGGING_DEVICE_IN_USE {
     pub Segment: u16,
     pub Bus: u32,
@@ -18834,7 +21292,8 @@ impl Default for PCI_DEBUGGING_DEVICE_IN_USE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_DEVICE_PRESENCE_PARAMETERS {
     pub Size: u32,
     pub Flags: u32,
@@ -18856,7 +21315,8 @@ impl Default for PCI_DEVICE_PRESENCE_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_DEVICE_PRESENT_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -18905,15 +21365,72 @@ impl Default for PCI_EXPRESS_ACS_CAPABILITY_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_ACS_CAPABILITY_REGISTER_0 {
     pub _bitfield: u16,
 }
-impl windows_core::TypeKind for PCI_EXPRESS_ACS_CAPABILITY_REGISTER_0 {
-    type TypeKind = windows_core::CopyType;
-}
-impl Default for PCI_EXPRESS_ACS_CAPABILITY_REGISTER_0 {
-    fn default() -> Self {
+impl PCI_EXPRESS_ACS_CAPABILITY_REGISTER_0 {
+    pub const fn SourceValidation(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_SourceValidation(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn TranslationBlocking(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_TranslationBlocking(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn RequestRedirect(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_RequestRedirect(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn CompletionRedirect(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_CompletionRedirect(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn UpstreamForwarding(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_UpstreamForwarding(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn EgressControl(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_EgressControl(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn DirectTranslation(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_DirectTranslation(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn EnhancedCapability(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_EnhancedCapability(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn EgressControlVectorSize(&self) -> u16 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_EgressControlVectorSize(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+}
+impl windows_core::TypeKind for PCI_EXPRESS_ACS_CAPABILITY_REGISTER_0 {
+    type TypeKind = windows_core::CopyType;
+}
+impl Default for PCI_EXPRESS_ACS_CAPABILITY_REGISTER_0 {
+    fn default() -> Self {
         unsafe { core::mem::zeroed() }
     }
 }
@@ -18932,10 +21449,85 @@ impl Default for PCI_EXPRESS_ACS_CONTROL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_ACS_CONTROL_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_ACS_CONTROL_0 {
+    pub const fn SourceValidation(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_SourceValidation(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn TranslationBlocking(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_TranslationBlocking(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn RequestRedirect(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_RequestRedirect(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn CompletionRedirect(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_CompletionRedirect(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn UpstreamForwarding(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_UpstreamForwarding(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn EgressControl(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_EgressControl(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn DirectTranslation(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_DirectTranslation(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn IoBlocking(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_IoBlocking(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn DspMemoryControl(&self) -> u16 {
+        (self._bitfield >> 8) & 3
+    }
+    pub fn set_DspMemoryControl(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 8)) | ((value & 3) << 8);
+    }
+    pub const fn UspMemoryControl(&self) -> u16 {
+        (self._bitfield >> 10) & 3
+    }
+    pub fn set_UspMemoryControl(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 10)) | ((value & 3) << 10);
+    }
+    pub const fn UnclaimedRedirect(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_UnclaimedRedirect(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 13) & 7
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 13)) | ((value & 7) << 13);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_ACS_CONTROL_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -18959,10 +21551,67 @@ impl Default for PCI_EXPRESS_AER_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_AER_CAPABILITIES_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_AER_CAPABILITIES_0 {
+    pub const fn FirstErrorPointer(&self) -> u32 {
+        self._bitfield & 31
+    }
+    pub fn set_FirstErrorPointer(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn ECRCGenerationCapable(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_ECRCGenerationCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn ECRCGenerationEnable(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_ECRCGenerationEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn ECRCCheckCapable(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_ECRCCheckCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn ECRCCheckEnable(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_ECRCCheckEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn MultipleHeaderRecordingCapable(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_MultipleHeaderRecordingCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn MultipleHeaderRecordingEnable(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_MultipleHeaderRecordingEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn TlpPrefixLogPresent(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_TlpPrefixLogPresent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 12) & 1048575
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1048575 << 12)) | ((value & 1048575) << 12);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_AER_CAPABILITIES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -18997,7 +21646,8 @@ impl Default for PCI_EXPRESS_AER_CAPABILITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_ARI_CAPABILITY {
     pub Header: PCI_EXPRESS_ENHANCED_CAPABILITY_HEADER,
     pub Capability: PCI_EXPRESS_ARI_CAPABILITY_REGISTER,
@@ -19012,10 +21662,37 @@ impl Default for PCI_EXPRESS_ARI_CAPABILITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_ARI_CAPABILITY_REGISTER {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_ARI_CAPABILITY_REGISTER {
+    pub const fn MfvcFunctionGroupsCapability(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_MfvcFunctionGroupsCapability(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn AcsFunctionGroupsCapability(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_AcsFunctionGroupsCapability(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+    pub const fn NextFunctionNumber(&self) -> u16 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_NextFunctionNumber(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_ARI_CAPABILITY_REGISTER {
     type TypeKind = windows_core::CopyType;
 }
@@ -19025,10 +21702,43 @@ impl Default for PCI_EXPRESS_ARI_CAPABILITY_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_ARI_CONTROL_REGISTER {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_ARI_CONTROL_REGISTER {
+    pub const fn MfvcFunctionGroupsEnable(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_MfvcFunctionGroupsEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn AcsFunctionGroupsEnable(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_AcsFunctionGroupsEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved1(&self) -> u16 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_Reserved1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn FunctionGroup(&self) -> u16 {
+        (self._bitfield >> 4) & 7
+    }
+    pub fn set_FunctionGroup(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 4)) | ((value & 7) << 4);
+    }
+    pub const fn Reserved2(&self) -> u16 {
+        (self._bitfield >> 7) & 511
+    }
+    pub fn set_Reserved2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(511 << 7)) | ((value & 511) << 7);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_ARI_CONTROL_REGISTER {
     type TypeKind = windows_core::CopyType;
 }
@@ -19053,10 +21763,43 @@ impl Default for PCI_EXPRESS_ATS_CAPABILITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_ATS_CAPABILITY_REGISTER {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_ATS_CAPABILITY_REGISTER {
+    pub const fn InvalidateQueueDepth(&self) -> u16 {
+        self._bitfield & 31
+    }
+    pub fn set_InvalidateQueueDepth(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn PageAlignedRequest(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_PageAlignedRequest(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn GlobalInvalidateSupported(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_GlobalInvalidateSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn RelaxedOrderingSupported(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_RelaxedOrderingSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_ATS_CAPABILITY_REGISTER {
     type TypeKind = windows_core::CopyType;
 }
@@ -19080,10 +21823,31 @@ impl Default for PCI_EXPRESS_ATS_CONTROL_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_ATS_CONTROL_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_ATS_CONTROL_REGISTER_0 {
+    pub const fn SmallestTransactionUnit(&self) -> u16 {
+        self._bitfield & 31
+    }
+    pub fn set_SmallestTransactionUnit(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 5) & 1023
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1023 << 5)) | ((value & 1023) << 5);
+    }
+    pub const fn Enable(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_Enable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_ATS_CONTROL_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19132,10 +21896,43 @@ impl Default for PCI_EXPRESS_CAPABILITIES_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_CAPABILITIES_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_CAPABILITIES_REGISTER_0 {
+    pub const fn CapabilityVersion(&self) -> u16 {
+        self._bitfield & 15
+    }
+    pub fn set_CapabilityVersion(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn DeviceType(&self) -> u16 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_DeviceType(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn SlotImplemented(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_SlotImplemented(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn InterruptMessageNumber(&self) -> u16 {
+        (self._bitfield >> 9) & 31
+    }
+    pub fn set_InterruptMessageNumber(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(31 << 9)) | ((value & 31) << 9);
+    }
+    pub const fn Rsvd(&self) -> u16 {
+        (self._bitfield >> 14) & 3
+    }
+    pub fn set_Rsvd(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 14)) | ((value & 3) << 14);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_CAPABILITIES_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19191,10 +21988,79 @@ impl Default for PCI_EXPRESS_CORRECTABLE_ERROR_MASK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_CORRECTABLE_ERROR_MASK_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_CORRECTABLE_ERROR_MASK_0 {
+    pub const fn ReceiverError(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ReceiverError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 1) & 31
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 1)) | ((value & 31) << 1);
+    }
+    pub const fn BadTLP(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_BadTLP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn BadDLLP(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_BadDLLP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn ReplayNumRollover(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_ReplayNumRollover(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 9) & 7
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 9)) | ((value & 7) << 9);
+    }
+    pub const fn ReplayTimerTimeout(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_ReplayTimerTimeout(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn AdvisoryNonFatalError(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_AdvisoryNonFatalError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn CorrectedInternalError(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_CorrectedInternalError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn HeaderLogOverflow(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_HeaderLogOverflow(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn Reserved3(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Reserved3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_CORRECTABLE_ERROR_MASK_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19218,10 +22084,79 @@ impl Default for PCI_EXPRESS_CORRECTABLE_ERROR_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_CORRECTABLE_ERROR_STATUS_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_CORRECTABLE_ERROR_STATUS_0 {
+    pub const fn ReceiverError(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ReceiverError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 1) & 31
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 1)) | ((value & 31) << 1);
+    }
+    pub const fn BadTLP(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_BadTLP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn BadDLLP(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_BadDLLP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn ReplayNumRollover(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_ReplayNumRollover(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 9) & 7
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 9)) | ((value & 7) << 9);
+    }
+    pub const fn ReplayTimerTimeout(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_ReplayTimerTimeout(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn AdvisoryNonFatalError(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_AdvisoryNonFatalError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn CorrectedInternalError(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_CorrectedInternalError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn HeaderLogOverflow(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_HeaderLogOverflow(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn Reserved3(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Reserved3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_CORRECTABLE_ERROR_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19261,10 +22196,61 @@ impl Default for PCI_EXPRESS_CXL_DVSEC_CAPABILITY_REGISTER_V11 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_CXL_DVSEC_CAPABILITY_REGISTER_V11_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_CXL_DVSEC_CAPABILITY_REGISTER_V11_0 {
+    pub const fn CacheCapable(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_CacheCapable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn IoCapable(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_IoCapable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MemCapable(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_MemCapable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn MemHwInitMode(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_MemHwInitMode(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn HdmCount(&self) -> u16 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_HdmCount(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn Reserved0(&self) -> u16 {
+        (self._bitfield >> 6) & 255
+    }
+    pub fn set_Reserved0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 6)) | ((value & 255) << 6);
+    }
+    pub const fn ViralCapable(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_ViralCapable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn Reserved1(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_Reserved1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_CXL_DVSEC_CAPABILITY_REGISTER_V11_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19318,10 +22304,67 @@ impl Default for PCI_EXPRESS_CXL_DVSEC_CONTROL_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_CXL_DVSEC_CONTROL_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_CXL_DVSEC_CONTROL_REGISTER_0 {
+    pub const fn CacheEnable(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_CacheEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn IoEnable(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_IoEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MemEnable(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_MemEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn CacheSFCoverage(&self) -> u16 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_CacheSFCoverage(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn CacheSFGranularity(&self) -> u16 {
+        (self._bitfield >> 8) & 7
+    }
+    pub fn set_CacheSFGranularity(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 8)) | ((value & 7) << 8);
+    }
+    pub const fn CacheCleanEviction(&self) -> u16 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_CacheCleanEviction(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn Reserved0(&self) -> u16 {
+        (self._bitfield >> 12) & 3
+    }
+    pub fn set_Reserved0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 12)) | ((value & 3) << 12);
+    }
+    pub const fn ViralEnable(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_ViralEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn Reserved1(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_Reserved1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_CXL_DVSEC_CONTROL_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19345,10 +22388,25 @@ impl Default for PCI_EXPRESS_CXL_DVSEC_LOCK_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_CXL_DVSEC_LOCK_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_CXL_DVSEC_LOCK_REGISTER_0 {
+    pub const fn ConfigLock(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_ConfigLock(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 1) & 32767
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(32767 << 1)) | ((value & 32767) << 1);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_CXL_DVSEC_LOCK_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19358,7 +22416,8 @@ impl Default for PCI_EXPRESS_CXL_DVSEC_LOCK_REGISTER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_CXL_DVSEC_RANGE_BASE_HIGH_REGISTER {
     pub MemBaseHigh: u32,
 }
@@ -19385,10 +22444,25 @@ impl Default for PCI_EXPRESS_CXL_DVSEC_RANGE_BASE_LOW_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_CXL_DVSEC_RANGE_BASE_LOW_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_CXL_DVSEC_RANGE_BASE_LOW_REGISTER_0 {
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !268435455) | (value & 268435455);
+    }
+    pub const fn MemBaseLow(&self) -> u32 {
+        (self._bitfield >> 28) & 15
+    }
+    pub fn set_MemBaseLow(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 28)) | ((value & 15) << 28);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_CXL_DVSEC_RANGE_BASE_LOW_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19398,7 +22472,8 @@ impl Default for PCI_EXPRESS_CXL_DVSEC_RANGE_BASE_LOW_REGISTER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_CXL_DVSEC_RANGE_SIZE_HIGH_REGISTER {
     pub MemSizeHigh: u32,
 }
@@ -19425,10 +22500,55 @@ impl Default for PCI_EXPRESS_CXL_DVSEC_RANGE_SIZE_LOW_REGISTER_V11 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_CXL_DVSEC_RANGE_SIZE_LOW_REGISTER_V11_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_CXL_DVSEC_RANGE_SIZE_LOW_REGISTER_V11_0 {
+    pub const fn MemInfoValid(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_MemInfoValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MemActive(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MemActive(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MediaType(&self) -> u32 {
+        (self._bitfield >> 2) & 7
+    }
+    pub fn set_MediaType(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 2)) | ((value & 7) << 2);
+    }
+    pub const fn MemClass(&self) -> u32 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_MemClass(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn DesiredInterleave(&self) -> u32 {
+        (self._bitfield >> 8) & 7
+    }
+    pub fn set_DesiredInterleave(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 8)) | ((value & 7) << 8);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 11) & 131071
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(131071 << 11)) | ((value & 131071) << 11);
+    }
+    pub const fn MemSizeLow(&self) -> u32 {
+        (self._bitfield >> 28) & 15
+    }
+    pub fn set_MemSizeLow(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 28)) | ((value & 15) << 28);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_CXL_DVSEC_RANGE_SIZE_LOW_REGISTER_V11_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19452,10 +22572,31 @@ impl Default for PCI_EXPRESS_CXL_DVSEC_STATUS_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_CXL_DVSEC_STATUS_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_CXL_DVSEC_STATUS_REGISTER_0 {
+    pub const fn Reserved0(&self) -> u16 {
+        self._bitfield & 16383
+    }
+    pub fn set_Reserved0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !16383) | (value & 16383);
+    }
+    pub const fn ViralStatus(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_ViralStatus(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn Reserved1(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_Reserved1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_CXL_DVSEC_STATUS_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19495,10 +22636,31 @@ impl Default for PCI_EXPRESS_DESIGNATED_VENDOR_SPECIFIC_HEADER_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DESIGNATED_VENDOR_SPECIFIC_HEADER_1_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_DESIGNATED_VENDOR_SPECIFIC_HEADER_1_0 {
+    pub const fn DvsecVendorId(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_DvsecVendorId(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn DvsecVersion(&self) -> u32 {
+        (self._bitfield >> 16) & 15
+    }
+    pub fn set_DvsecVersion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 16)) | ((value & 15) << 16);
+    }
+    pub const fn DvsecLength(&self) -> u32 {
+        (self._bitfield >> 20) & 4095
+    }
+    pub fn set_DvsecLength(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4095 << 20)) | ((value & 4095) << 20);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DESIGNATED_VENDOR_SPECIFIC_HEADER_1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19522,10 +22684,19 @@ impl Default for PCI_EXPRESS_DESIGNATED_VENDOR_SPECIFIC_HEADER_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DESIGNATED_VENDOR_SPECIFIC_HEADER_2_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_DESIGNATED_VENDOR_SPECIFIC_HEADER_2_0 {
+    pub const fn DvsecId(&self) -> u16 {
+        self._bitfield
+    }
+    pub fn set_DvsecId(&mut self, value: u16) {
+        self._bitfield = value;
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DESIGNATED_VENDOR_SPECIFIC_HEADER_2_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19549,37 +22720,211 @@ impl Default for PCI_EXPRESS_DEVICE_CAPABILITIES_2_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DEVICE_CAPABILITIES_2_REGISTER_0 {
     pub _bitfield: u32,
 }
-impl windows_core::TypeKind for PCI_EXPRESS_DEVICE_CAPABILITIES_2_REGISTER_0 {
-    type TypeKind = windows_core::CopyType;
-}
-impl Default for PCI_EXPRESS_DEVICE_CAPABILITIES_2_REGISTER_0 {
-    fn default() -> Self {
-        unsafe { core::mem::zeroed() }
+impl PCI_EXPRESS_DEVICE_CAPABILITIES_2_REGISTER_0 {
+    pub const fn CompletionTimeoutRangesSupported(&self) -> u32 {
+        self._bitfield & 15
+    }
+    pub fn set_CompletionTimeoutRangesSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn CompletionTimeoutDisableSupported(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_CompletionTimeoutDisableSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn AriForwardingSupported(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_AriForwardingSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn AtomicOpRoutingSupported(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_AtomicOpRoutingSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn AtomicOpCompleterSupported32Bit(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_AtomicOpCompleterSupported32Bit(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn AtomicOpCompleterSupported64Bit(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_AtomicOpCompleterSupported64Bit(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn CASCompleterSupported128Bit(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_CASCompleterSupported128Bit(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn NoROEnabledPRPRPassing(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_NoROEnabledPRPRPassing(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn LTRMechanismSupported(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_LTRMechanismSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn TPHCompleterSupported(&self) -> u32 {
+        (self._bitfield >> 12) & 3
+    }
+    pub fn set_TPHCompleterSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 12)) | ((value & 3) << 12);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 14) & 15
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 14)) | ((value & 15) << 14);
+    }
+    pub const fn OBFFSupported(&self) -> u32 {
+        (self._bitfield >> 18) & 3
+    }
+    pub fn set_OBFFSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 18)) | ((value & 3) << 18);
+    }
+    pub const fn ExtendedFmtFieldSuported(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_ExtendedFmtFieldSuported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn EndEndTLPPrefixSupported(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_EndEndTLPPrefixSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn MaxEndEndTLPPrefixes(&self) -> u32 {
+        (self._bitfield >> 22) & 3
+    }
+    pub fn set_MaxEndEndTLPPrefixes(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 22)) | ((value & 3) << 22);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
+impl windows_core::TypeKind for PCI_EXPRESS_DEVICE_CAPABILITIES_2_REGISTER_0 {
+    type TypeKind = windows_core::CopyType;
+}
+impl Default for PCI_EXPRESS_DEVICE_CAPABILITIES_2_REGISTER_0 {
+    fn default() -> Self {
+        unsafe { core::mem::zeroed() }
     }
 }
-#[repr(C)]
-#[derive(Clone, Copy)]
-pub union PCI_EXPRESS_DEVICE_CAPABILITIES_REGISTER {
-    pub Anonymous: PCI_EXPRESS_DEVICE_CAPABILITIES_REGISTER_0,
-    pub AsULONG: u32,
-}
-impl windows_core::TypeKind for PCI_EXPRESS_DEVICE_CAPABILITIES_REGISTER {
-    type TypeKind = windows_core::CopyType;
-}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union PCI_EXPRESS_DEVICE_CAPABILITIES_REGISTER {
+    pub Anonymous: PCI_EXPRESS_DEVICE_CAPABILITIES_REGISTER_0,
+    pub AsULONG: u32,
+}
+impl windows_core::TypeKind for PCI_EXPRESS_DEVICE_CAPABILITIES_REGISTER {
+    type TypeKind = windows_core::CopyType;
+}
 impl Default for PCI_EXPRESS_DEVICE_CAPABILITIES_REGISTER {
     fn default() -> Self {
         unsafe { core::mem::zeroed() }
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DEVICE_CAPABILITIES_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_DEVICE_CAPABILITIES_REGISTER_0 {
+    pub const fn MaxPayloadSizeSupported(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_MaxPayloadSizeSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn PhantomFunctionsSupported(&self) -> u32 {
+        (self._bitfield >> 3) & 3
+    }
+    pub fn set_PhantomFunctionsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 3)) | ((value & 3) << 3);
+    }
+    pub const fn ExtendedTagSupported(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_ExtendedTagSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn L0sAcceptableLatency(&self) -> u32 {
+        (self._bitfield >> 6) & 7
+    }
+    pub fn set_L0sAcceptableLatency(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 6)) | ((value & 7) << 6);
+    }
+    pub const fn L1AcceptableLatency(&self) -> u32 {
+        (self._bitfield >> 9) & 7
+    }
+    pub fn set_L1AcceptableLatency(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 9)) | ((value & 7) << 9);
+    }
+    pub const fn Undefined(&self) -> u32 {
+        (self._bitfield >> 12) & 7
+    }
+    pub fn set_Undefined(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 12)) | ((value & 7) << 12);
+    }
+    pub const fn RoleBasedErrorReporting(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_RoleBasedErrorReporting(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn Rsvd1(&self) -> u32 {
+        (self._bitfield >> 16) & 3
+    }
+    pub fn set_Rsvd1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 16)) | ((value & 3) << 16);
+    }
+    pub const fn CapturedSlotPowerLimit(&self) -> u32 {
+        (self._bitfield >> 18) & 255
+    }
+    pub fn set_CapturedSlotPowerLimit(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 18)) | ((value & 255) << 18);
+    }
+    pub const fn CapturedSlotPowerLimitScale(&self) -> u32 {
+        (self._bitfield >> 26) & 3
+    }
+    pub fn set_CapturedSlotPowerLimitScale(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 26)) | ((value & 3) << 26);
+    }
+    pub const fn FunctionLevelResetCapability(&self) -> u32 {
+        (self._bitfield >> 28) & 1
+    }
+    pub fn set_FunctionLevelResetCapability(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 28)) | ((value & 1) << 28);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 29) & 7
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 29)) | ((value & 7) << 29);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DEVICE_CAPABILITIES_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19603,10 +22948,79 @@ impl Default for PCI_EXPRESS_DEVICE_CONTROL_2_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DEVICE_CONTROL_2_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_DEVICE_CONTROL_2_REGISTER_0 {
+    pub const fn CompletionTimeoutValue(&self) -> u16 {
+        self._bitfield & 15
+    }
+    pub fn set_CompletionTimeoutValue(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn CompletionTimeoutDisable(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_CompletionTimeoutDisable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn AriForwardingEnable(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_AriForwardingEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn AtomicOpRequesterEnable(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_AtomicOpRequesterEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn AtomicOpEgresBlocking(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_AtomicOpEgresBlocking(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn IDORequestEnable(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_IDORequestEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn IDOCompletionEnable(&self) -> u16 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_IDOCompletionEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn LTRMechanismEnable(&self) -> u16 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_LTRMechanismEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Rsvd(&self) -> u16 {
+        (self._bitfield >> 11) & 3
+    }
+    pub fn set_Rsvd(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 11)) | ((value & 3) << 11);
+    }
+    pub const fn OBFFEnable(&self) -> u16 {
+        (self._bitfield >> 13) & 3
+    }
+    pub fn set_OBFFEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 13)) | ((value & 3) << 13);
+    }
+    pub const fn EndEndTLPPrefixBlocking(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_EndEndTLPPrefixBlocking(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DEVICE_CONTROL_2_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19631,10 +23045,85 @@ impl Default for PCI_EXPRESS_DEVICE_CONTROL_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DEVICE_CONTROL_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_DEVICE_CONTROL_REGISTER_0 {
+    pub const fn CorrectableErrorEnable(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_CorrectableErrorEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NonFatalErrorEnable(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NonFatalErrorEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn FatalErrorEnable(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_FatalErrorEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn UnsupportedRequestErrorEnable(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_UnsupportedRequestErrorEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn EnableRelaxedOrder(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_EnableRelaxedOrder(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn MaxPayloadSize(&self) -> u16 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_MaxPayloadSize(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn ExtendedTagEnable(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_ExtendedTagEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn PhantomFunctionsEnable(&self) -> u16 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_PhantomFunctionsEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn AuxPowerEnable(&self) -> u16 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_AuxPowerEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn NoSnoopEnable(&self) -> u16 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_NoSnoopEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn MaxReadRequestSize(&self) -> u16 {
+        (self._bitfield >> 12) & 7
+    }
+    pub fn set_MaxReadRequestSize(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 12)) | ((value & 7) << 12);
+    }
+    pub const fn BridgeConfigRetryEnable(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_BridgeConfigRetryEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DEVICE_CONTROL_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19644,10 +23133,25 @@ impl Default for PCI_EXPRESS_DEVICE_CONTROL_REGISTER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DEVICE_CONTROL_REGISTER_1 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_DEVICE_CONTROL_REGISTER_1 {
+    pub const fn Anonymous(&self) -> u16 {
+        self._bitfield & 32767
+    }
+    pub fn set_Anonymous(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !32767) | (value & 32767);
+    }
+    pub const fn InitiateFunctionLevelReset(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_InitiateFunctionLevelReset(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DEVICE_CONTROL_REGISTER_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19671,10 +23175,19 @@ impl Default for PCI_EXPRESS_DEVICE_STATUS_2_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DEVICE_STATUS_2_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_DEVICE_STATUS_2_REGISTER_0 {
+    pub const fn Rsvd(&self) -> u16 {
+        self._bitfield
+    }
+    pub fn set_Rsvd(&mut self, value: u16) {
+        self._bitfield = value;
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DEVICE_STATUS_2_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19698,10 +23211,55 @@ impl Default for PCI_EXPRESS_DEVICE_STATUS_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DEVICE_STATUS_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_DEVICE_STATUS_REGISTER_0 {
+    pub const fn CorrectableErrorDetected(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_CorrectableErrorDetected(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NonFatalErrorDetected(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NonFatalErrorDetected(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn FatalErrorDetected(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_FatalErrorDetected(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn UnsupportedRequestDetected(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_UnsupportedRequestDetected(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn AuxPowerDetected(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_AuxPowerDetected(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn TransactionsPending(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_TransactionsPending(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Rsvd(&self) -> u16 {
+        (self._bitfield >> 6) & 1023
+    }
+    pub fn set_Rsvd(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1023 << 6)) | ((value & 1023) << 6);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DEVICE_STATUS_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19750,10 +23308,55 @@ impl Default for PCI_EXPRESS_DPC_CAPS_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DPC_CAPS_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_DPC_CAPS_REGISTER_0 {
+    pub const fn InterruptMsgNumber(&self) -> u16 {
+        self._bitfield & 31
+    }
+    pub fn set_InterruptMsgNumber(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn RpExtensionsForDpc(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_RpExtensionsForDpc(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn PoisonedTlpEgressBlockingSupported(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_PoisonedTlpEgressBlockingSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn DpcSoftwareTriggeringSupported(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_DpcSoftwareTriggeringSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn RpPioLogSize(&self) -> u16 {
+        (self._bitfield >> 8) & 15
+    }
+    pub fn set_RpPioLogSize(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 8)) | ((value & 15) << 8);
+    }
+    pub const fn DlActiveErrCorSignalingSupported(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_DlActiveErrCorSignalingSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 13) & 7
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 13)) | ((value & 7) << 13);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DPC_CAPS_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19777,10 +23380,61 @@ impl Default for PCI_EXPRESS_DPC_CONTROL_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DPC_CONTROL_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_DPC_CONTROL_REGISTER_0 {
+    pub const fn TriggerEnable(&self) -> u16 {
+        self._bitfield & 3
+    }
+    pub fn set_TriggerEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn CompletionControl(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CompletionControl(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn InterruptEnable(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_InterruptEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ErrCorEnable(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_ErrCorEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PoisonedTlpEgressBlockingEnable(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_PoisonedTlpEgressBlockingEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn SoftwareTrigger(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_SoftwareTrigger(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn DlActiveErrCorEnable(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_DlActiveErrCorEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DPC_CONTROL_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19804,10 +23458,31 @@ impl Default for PCI_EXPRESS_DPC_ERROR_SOURCE_ID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DPC_ERROR_SOURCE_ID_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_DPC_ERROR_SOURCE_ID_0 {
+    pub const fn Function(&self) -> u16 {
+        self._bitfield & 7
+    }
+    pub fn set_Function(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Device(&self) -> u16 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Device(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn Bus(&self) -> u16 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_Bus(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DPC_ERROR_SOURCE_ID_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19831,10 +23506,85 @@ impl Default for PCI_EXPRESS_DPC_RP_PIO_EXCEPTION_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DPC_RP_PIO_EXCEPTION_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_DPC_RP_PIO_EXCEPTION_REGISTER_0 {
+    pub const fn CfgURCpl(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_CfgURCpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CfgCACpl(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CfgCACpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn CfgCTO(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CfgCTO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn IoURCpl(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_IoURCpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn IoCACpl(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_IoCACpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn IoCTO(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_IoCTO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 11) & 31
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 11)) | ((value & 31) << 11);
+    }
+    pub const fn MemURCpl(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_MemURCpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn MemCACpl(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_MemCACpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn MemCTO(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_MemCTO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn Reserved3(&self) -> u32 {
+        (self._bitfield >> 19) & 8191
+    }
+    pub fn set_Reserved3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8191 << 19)) | ((value & 8191) << 19);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DPC_RP_PIO_EXCEPTION_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19844,7 +23594,8 @@ impl Default for PCI_EXPRESS_DPC_RP_PIO_EXCEPTION_REGISTER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DPC_RP_PIO_HEADERLOG_REGISTER {
     pub PioHeaderLogRegister: [u32; 4],
 }
@@ -19884,10 +23635,85 @@ impl Default for PCI_EXPRESS_DPC_RP_PIO_MASK_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DPC_RP_PIO_MASK_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_DPC_RP_PIO_MASK_REGISTER_0 {
+    pub const fn CfgURCpl(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_CfgURCpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CfgCACpl(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CfgCACpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn CfgCTO(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CfgCTO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn IoURCpl(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_IoURCpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn IoCACpl(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_IoCACpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn IoCTO(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_IoCTO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 11) & 31
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 11)) | ((value & 31) << 11);
+    }
+    pub const fn MemURCpl(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_MemURCpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn MemCACpl(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_MemCACpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn MemCTO(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_MemCTO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn Reserved3(&self) -> u32 {
+        (self._bitfield >> 19) & 8191
+    }
+    pub fn set_Reserved3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8191 << 19)) | ((value & 8191) << 19);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DPC_RP_PIO_MASK_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19911,10 +23737,85 @@ impl Default for PCI_EXPRESS_DPC_RP_PIO_SEVERITY_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DPC_RP_PIO_SEVERITY_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_DPC_RP_PIO_SEVERITY_REGISTER_0 {
+    pub const fn CfgURCpl(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_CfgURCpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CfgCACpl(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CfgCACpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn CfgCTO(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CfgCTO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn IoURCpl(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_IoURCpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn IoCACpl(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_IoCACpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn IoCTO(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_IoCTO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 11) & 31
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 11)) | ((value & 31) << 11);
+    }
+    pub const fn MemURCpl(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_MemURCpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn MemCACpl(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_MemCACpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn MemCTO(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_MemCTO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn Reserved3(&self) -> u32 {
+        (self._bitfield >> 19) & 8191
+    }
+    pub fn set_Reserved3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8191 << 19)) | ((value & 8191) << 19);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DPC_RP_PIO_SEVERITY_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19938,10 +23839,91 @@ impl Default for PCI_EXPRESS_DPC_RP_PIO_STATUS_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DPC_RP_PIO_STATUS_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_DPC_RP_PIO_STATUS_REGISTER_0 {
+    pub const fn CfgURCpl(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_CfgURCpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CfgCACpl(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CfgCACpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn CfgCTO(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CfgCTO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn IoURCpl(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_IoURCpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn IoCACpl(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_IoCACpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn IoCTO(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_IoCTO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 11) & 31
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 11)) | ((value & 31) << 11);
+    }
+    pub const fn MemURCpl(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_MemURCpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn MemCACpl(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_MemCACpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn MemCTO(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_MemCTO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn Reserved3(&self) -> u32 {
+        (self._bitfield >> 19) & 4095
+    }
+    pub fn set_Reserved3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4095 << 19)) | ((value & 4095) << 19);
+    }
+    pub const fn Reserved4(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_Reserved4(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DPC_RP_PIO_STATUS_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19965,10 +23947,85 @@ impl Default for PCI_EXPRESS_DPC_RP_PIO_SYSERR_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DPC_RP_PIO_SYSERR_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_DPC_RP_PIO_SYSERR_REGISTER_0 {
+    pub const fn CfgURCpl(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_CfgURCpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CfgCACpl(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CfgCACpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn CfgCTO(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CfgCTO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn IoURCpl(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_IoURCpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn IoCACpl(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_IoCACpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn IoCTO(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_IoCTO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 11) & 31
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 11)) | ((value & 31) << 11);
+    }
+    pub const fn MemURCpl(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_MemURCpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn MemCACpl(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_MemCACpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn MemCTO(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_MemCTO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn Reserved3(&self) -> u32 {
+        (self._bitfield >> 19) & 8191
+    }
+    pub fn set_Reserved3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8191 << 19)) | ((value & 8191) << 19);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DPC_RP_PIO_SYSERR_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -19978,7 +24035,8 @@ impl Default for PCI_EXPRESS_DPC_RP_PIO_SYSERR_REGISTER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DPC_RP_PIO_TLPPREFIXLOG_REGISTER {
     pub PioTlpPrefixLogRegister: [u32; 4],
 }
@@ -20005,10 +24063,61 @@ impl Default for PCI_EXPRESS_DPC_STATUS_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_DPC_STATUS_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_DPC_STATUS_REGISTER_0 {
+    pub const fn TriggerStatus(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_TriggerStatus(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn TriggerReason(&self) -> u16 {
+        (self._bitfield >> 1) & 3
+    }
+    pub fn set_TriggerReason(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 1)) | ((value & 3) << 1);
+    }
+    pub const fn InterruptStatus(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_InterruptStatus(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn RpBusy(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_RpBusy(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn TriggerReasonExtension(&self) -> u16 {
+        (self._bitfield >> 5) & 3
+    }
+    pub fn set_TriggerReasonExtension(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 5)) | ((value & 3) << 5);
+    }
+    pub const fn Reserved1(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Reserved1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn PioFirstErrPointer(&self) -> u16 {
+        (self._bitfield >> 8) & 31
+    }
+    pub fn set_PioFirstErrPointer(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(31 << 8)) | ((value & 31) << 8);
+    }
+    pub const fn Reserved2(&self) -> u16 {
+        (self._bitfield >> 13) & 7
+    }
+    pub fn set_Reserved2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 13)) | ((value & 7) << 13);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_DPC_STATUS_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20018,11 +24127,26 @@ impl Default for PCI_EXPRESS_DPC_STATUS_REGISTER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_ENHANCED_CAPABILITY_HEADER {
     pub CapabilityID: u16,
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_ENHANCED_CAPABILITY_HEADER {
+    pub const fn Version(&self) -> u16 {
+        self._bitfield & 15
+    }
+    pub fn set_Version(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Next(&self) -> u16 {
+        (self._bitfield >> 4) & 4095
+    }
+    pub fn set_Next(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(4095 << 4)) | ((value & 4095) << 4);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_ENHANCED_CAPABILITY_HEADER {
     type TypeKind = windows_core::CopyType;
 }
@@ -20044,12 +24168,51 @@ impl Default for PCI_EXPRESS_ERROR_SOURCE_ID {
     fn default() -> Self {
         unsafe { core::mem::zeroed() }
     }
-}
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct PCI_EXPRESS_ERROR_SOURCE_ID_0 {
-    pub _bitfield1: u16,
-    pub _bitfield2: u16,
+}
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct PCI_EXPRESS_ERROR_SOURCE_ID_0 {
+    pub _bitfield1: u16,
+    pub _bitfield2: u16,
+}
+impl PCI_EXPRESS_ERROR_SOURCE_ID_0 {
+    pub const fn CorrectableSourceIdFun(&self) -> u16 {
+        self._bitfield1 & 7
+    }
+    pub fn set_CorrectableSourceIdFun(&mut self, value: u16) {
+        self._bitfield1 = (self._bitfield1 & !7) | (value & 7);
+    }
+    pub const fn CorrectableSourceIdDev(&self) -> u16 {
+        (self._bitfield1 >> 3) & 31
+    }
+    pub fn set_CorrectableSourceIdDev(&mut self, value: u16) {
+        self._bitfield1 = (self._bitfield1 & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn CorrectableSourceIdBus(&self) -> u16 {
+        (self._bitfield1 >> 8) & 255
+    }
+    pub fn set_CorrectableSourceIdBus(&mut self, value: u16) {
+        self._bitfield1 = (self._bitfield1 & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn UncorrectableSourceIdFun(&self) -> u16 {
+        self._bitfield2 & 7
+    }
+    pub fn set_UncorrectableSourceIdFun(&mut self, value: u16) {
+        self._bitfield2 = (self._bitfield2 & !7) | (value & 7);
+    }
+    pub const fn UncorrectableSourceIdDev(&self) -> u16 {
+        (self._bitfield2 >> 3) & 31
+    }
+    pub fn set_UncorrectableSourceIdDev(&mut self, value: u16) {
+        self._bitfield2 = (self._bitfield2 & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn UncorrectableSourceIdBus(&self) -> u16 {
+        (self._bitfield2 >> 8) & 255
+    }
+    pub fn set_UncorrectableSourceIdBus(&mut self, value: u16) {
+        self._bitfield2 = (self._bitfield2 & !(255 << 8)) | ((value & 255) << 8);
+    }
 }
 impl windows_core::TypeKind for PCI_EXPRESS_ERROR_SOURCE_ID_0 {
     type TypeKind = windows_core::CopyType;
@@ -20060,7 +24223,8 @@ impl Default for PCI_EXPRESS_ERROR_SOURCE_ID_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_EVENT_COLLECTOR_ENDPOINT_ASSOCIATION_CAPABILITY {
     pub Header: PCI_EXPRESS_ENHANCED_CAPABILITY_HEADER,
     pub AssociationBitmap: u32,
@@ -20088,10 +24252,79 @@ impl Default for PCI_EXPRESS_L1_PM_SS_CAPABILITIES_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_L1_PM_SS_CAPABILITIES_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_L1_PM_SS_CAPABILITIES_REGISTER_0 {
+    pub const fn PciPmL12Supported(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_PciPmL12Supported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PciPmL11Supported(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PciPmL11Supported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn AspmL12Supported(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_AspmL12Supported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AspmL11Supported(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AspmL11Supported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn L1PmSsSupported(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_L1PmSsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn PortCommonModeRestoreTime(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_PortCommonModeRestoreTime(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn PortTPowerOnScale(&self) -> u32 {
+        (self._bitfield >> 16) & 3
+    }
+    pub fn set_PortTPowerOnScale(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 16)) | ((value & 3) << 16);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn PortTPowerOnValue(&self) -> u32 {
+        (self._bitfield >> 19) & 31
+    }
+    pub fn set_PortTPowerOnValue(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 19)) | ((value & 31) << 19);
+    }
+    pub const fn Rsvd3(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_Rsvd3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_L1_PM_SS_CAPABILITIES_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20131,10 +24364,67 @@ impl Default for PCI_EXPRESS_L1_PM_SS_CONTROL_1_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_L1_PM_SS_CONTROL_1_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_L1_PM_SS_CONTROL_1_REGISTER_0 {
+    pub const fn PciPmL12Enabled(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_PciPmL12Enabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PciPmL11Enabled(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PciPmL11Enabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn AspmL12Enabled(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_AspmL12Enabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AspmL11Enabled(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AspmL11Enabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn CommonModeRestoreTime(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_CommonModeRestoreTime(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn LtrL12ThresholdValue(&self) -> u32 {
+        (self._bitfield >> 16) & 1023
+    }
+    pub fn set_LtrL12ThresholdValue(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1023 << 16)) | ((value & 1023) << 16);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 26) & 7
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 26)) | ((value & 7) << 26);
+    }
+    pub const fn LtrL12ThresholdScale(&self) -> u32 {
+        (self._bitfield >> 29) & 7
+    }
+    pub fn set_LtrL12ThresholdScale(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 29)) | ((value & 7) << 29);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_L1_PM_SS_CONTROL_1_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20158,10 +24448,37 @@ impl Default for PCI_EXPRESS_L1_PM_SS_CONTROL_2_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_L1_PM_SS_CONTROL_2_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_L1_PM_SS_CONTROL_2_REGISTER_0 {
+    pub const fn TPowerOnScale(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_TPowerOnScale(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn TPowerOnValue(&self) -> u32 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_TPowerOnValue(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 8) & 16777215
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(16777215 << 8)) | ((value & 16777215) << 8);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_L1_PM_SS_CONTROL_2_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20171,7 +24488,8 @@ impl Default for PCI_EXPRESS_L1_PM_SS_CONTROL_2_REGISTER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_LANE_ERROR_STATUS {
     pub LaneBitmap: u32,
 }
@@ -20198,10 +24516,31 @@ impl Default for PCI_EXPRESS_LINK_CAPABILITIES_2_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_LINK_CAPABILITIES_2_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_LINK_CAPABILITIES_2_REGISTER_0 {
+    pub const fn Rsvd0(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Rsvd0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn SupportedLinkSpeedsVector(&self) -> u32 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_SupportedLinkSpeedsVector(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+    pub const fn Rsvd8_31(&self) -> u32 {
+        (self._bitfield >> 8) & 16777215
+    }
+    pub fn set_Rsvd8_31(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(16777215 << 8)) | ((value & 16777215) << 8);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_LINK_CAPABILITIES_2_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20225,10 +24564,85 @@ impl Default for PCI_EXPRESS_LINK_CAPABILITIES_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_LINK_CAPABILITIES_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_LINK_CAPABILITIES_REGISTER_0 {
+    pub const fn MaximumLinkSpeed(&self) -> u32 {
+        self._bitfield & 15
+    }
+    pub fn set_MaximumLinkSpeed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn MaximumLinkWidth(&self) -> u32 {
+        (self._bitfield >> 4) & 63
+    }
+    pub fn set_MaximumLinkWidth(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 4)) | ((value & 63) << 4);
+    }
+    pub const fn ActiveStatePMSupport(&self) -> u32 {
+        (self._bitfield >> 10) & 3
+    }
+    pub fn set_ActiveStatePMSupport(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 10)) | ((value & 3) << 10);
+    }
+    pub const fn L0sExitLatency(&self) -> u32 {
+        (self._bitfield >> 12) & 7
+    }
+    pub fn set_L0sExitLatency(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 12)) | ((value & 7) << 12);
+    }
+    pub const fn L1ExitLatency(&self) -> u32 {
+        (self._bitfield >> 15) & 7
+    }
+    pub fn set_L1ExitLatency(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 15)) | ((value & 7) << 15);
+    }
+    pub const fn ClockPowerManagement(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_ClockPowerManagement(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn SurpriseDownErrorReportingCapable(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_SurpriseDownErrorReportingCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn DataLinkLayerActiveReportingCapable(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_DataLinkLayerActiveReportingCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn LinkBandwidthNotificationCapability(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_LinkBandwidthNotificationCapability(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn AspmOptionalityCompliance(&self) -> u32 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_AspmOptionalityCompliance(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn PortNumber(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_PortNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_LINK_CAPABILITIES_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20265,10 +24679,31 @@ impl Default for PCI_EXPRESS_LINK_CONTROL3_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_LINK_CONTROL3_0_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_LINK_CONTROL3_0_0 {
+    pub const fn PerformEq(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_PerformEq(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn LinkEqIntEn(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_LinkEqIntEn(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_LINK_CONTROL3_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20292,10 +24727,25 @@ impl Default for PCI_EXPRESS_LINK_CONTROL_2_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_LINK_CONTROL_2_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_LINK_CONTROL_2_REGISTER_0 {
+    pub const fn TargetLinkSpeed(&self) -> u16 {
+        self._bitfield & 15
+    }
+    pub fn set_TargetLinkSpeed(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Rsvd4_15(&self) -> u16 {
+        (self._bitfield >> 4) & 4095
+    }
+    pub fn set_Rsvd4_15(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(4095 << 4)) | ((value & 4095) << 4);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_LINK_CONTROL_2_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20319,10 +24769,67 @@ impl Default for PCI_EXPRESS_LINK_CONTROL_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_LINK_CONTROL_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_LINK_CONTROL_REGISTER_0 {
+    pub const fn ActiveStatePMControl(&self) -> u16 {
+        self._bitfield & 3
+    }
+    pub fn set_ActiveStatePMControl(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn Rsvd1(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Rsvd1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ReadCompletionBoundary(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ReadCompletionBoundary(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn LinkDisable(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_LinkDisable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn RetrainLink(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_RetrainLink(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn CommonClockConfig(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_CommonClockConfig(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn ExtendedSynch(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_ExtendedSynch(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn EnableClockPowerManagement(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_EnableClockPowerManagement(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Rsvd2(&self) -> u16 {
+        (self._bitfield >> 9) & 127
+    }
+    pub fn set_Rsvd2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(127 << 9)) | ((value & 127) << 9);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_LINK_CONTROL_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20332,7 +24839,8 @@ impl Default for PCI_EXPRESS_LINK_CONTROL_REGISTER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_LINK_QUIESCENT_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -20365,10 +24873,19 @@ impl Default for PCI_EXPRESS_LINK_STATUS_2_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_LINK_STATUS_2_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_LINK_STATUS_2_REGISTER_0 {
+    pub const fn Rsvd0_15(&self) -> u16 {
+        self._bitfield
+    }
+    pub fn set_Rsvd0_15(&mut self, value: u16) {
+        self._bitfield = value;
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_LINK_STATUS_2_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20392,10 +24909,55 @@ impl Default for PCI_EXPRESS_LINK_STATUS_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_LINK_STATUS_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_LINK_STATUS_REGISTER_0 {
+    pub const fn LinkSpeed(&self) -> u16 {
+        self._bitfield & 15
+    }
+    pub fn set_LinkSpeed(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn LinkWidth(&self) -> u16 {
+        (self._bitfield >> 4) & 63
+    }
+    pub fn set_LinkWidth(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(63 << 4)) | ((value & 63) << 4);
+    }
+    pub const fn Undefined(&self) -> u16 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_Undefined(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn LinkTraining(&self) -> u16 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_LinkTraining(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn SlotClockConfig(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_SlotClockConfig(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn DataLinkLayerActive(&self) -> u16 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_DataLinkLayerActive(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn Rsvd(&self) -> u16 {
+        (self._bitfield >> 14) & 3
+    }
+    pub fn set_Rsvd(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 14)) | ((value & 3) << 14);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_LINK_STATUS_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20433,10 +24995,61 @@ impl Default for PCI_EXPRESS_LTR_MAX_LATENCY_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_LTR_MAX_LATENCY_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_LTR_MAX_LATENCY_REGISTER_0 {
+    pub const fn MaxSnoopLatencyValue(&self) -> u32 {
+        self._bitfield & 1023
+    }
+    pub fn set_MaxSnoopLatencyValue(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1023) | (value & 1023);
+    }
+    pub const fn MaxSnoopLatencyScale(&self) -> u32 {
+        (self._bitfield >> 10) & 7
+    }
+    pub fn set_MaxSnoopLatencyScale(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 10)) | ((value & 7) << 10);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 13) & 3
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 13)) | ((value & 3) << 13);
+    }
+    pub const fn MaxSnoopRequirement(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_MaxSnoopRequirement(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn MaxNoSnoopLatencyValue(&self) -> u32 {
+        (self._bitfield >> 16) & 1023
+    }
+    pub fn set_MaxNoSnoopLatencyValue(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1023 << 16)) | ((value & 1023) << 16);
+    }
+    pub const fn MaxNoSnoopLatencyScale(&self) -> u32 {
+        (self._bitfield >> 26) & 7
+    }
+    pub fn set_MaxNoSnoopLatencyScale(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 26)) | ((value & 7) << 26);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 29) & 3
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 29)) | ((value & 3) << 29);
+    }
+    pub const fn MaxNoSnoopRequirement(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_MaxNoSnoopRequirement(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_LTR_MAX_LATENCY_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20476,10 +25089,97 @@ impl Default for PCI_EXPRESS_NPEM_CAPABILITY_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_NPEM_CAPABILITY_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_NPEM_CAPABILITY_REGISTER_0 {
+    pub const fn Capable(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Capable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ResetCapable(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ResetCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn OkCapable(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_OkCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn LocateCapable(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_LocateCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn FailCapable(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_FailCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn RebuildCapable(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_RebuildCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn PFACapable(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_PFACapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn HotSpareCapable(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_HotSpareCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn InACriticalArrayCapable(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_InACriticalArrayCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn InAFailedArrayCapable(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_InAFailedArrayCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn InvalidDeviceTypeCapable(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_InvalidDeviceTypeCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn DisabledCapable(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_DisabledCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 12) & 4095
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4095 << 12)) | ((value & 4095) << 12);
+    }
+    pub const fn EnclosureSpecificCapabilities(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_EnclosureSpecificCapabilities(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_NPEM_CAPABILITY_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20503,10 +25203,97 @@ impl Default for PCI_EXPRESS_NPEM_CONTROL_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_NPEM_CONTROL_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_NPEM_CONTROL_REGISTER_0 {
+    pub const fn Enable(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Enable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn InitiateReset(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_InitiateReset(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn OkControl(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_OkControl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn LocateControl(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_LocateControl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn FailControl(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_FailControl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn RebuildControl(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_RebuildControl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn PFAControl(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_PFAControl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn HotSpareControl(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_HotSpareControl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn InACriticalArrayControl(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_InACriticalArrayControl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn InAFailedArrayControl(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_InAFailedArrayControl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn InvalidDeviceTypeControl(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_InvalidDeviceTypeControl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn DisabledControl(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_DisabledControl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 12) & 4095
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4095 << 12)) | ((value & 4095) << 12);
+    }
+    pub const fn EnclosureSpecificControls(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_EnclosureSpecificControls(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_NPEM_CONTROL_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20530,10 +25317,31 @@ impl Default for PCI_EXPRESS_NPEM_STATUS_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_NPEM_STATUS_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_NPEM_STATUS_REGISTER_0 {
+    pub const fn CommandCompleted(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_CommandCompleted(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 1) & 8388607
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8388607 << 1)) | ((value & 8388607) << 1);
+    }
+    pub const fn EnclosureSpecificStatus(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_EnclosureSpecificStatus(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_NPEM_STATUS_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20572,10 +25380,49 @@ impl Default for PCI_EXPRESS_PASID_CAPABILITY_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_PASID_CAPABILITY_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_PASID_CAPABILITY_REGISTER_0 {
+    pub const fn Rsvd(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_Rsvd(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ExecutePermissionSupported(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ExecutePermissionSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn PrivilegedModeSupported(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_PrivilegedModeSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Rsvd2(&self) -> u16 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Rsvd2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn MaxPASIDWidth(&self) -> u16 {
+        (self._bitfield >> 8) & 31
+    }
+    pub fn set_MaxPASIDWidth(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(31 << 8)) | ((value & 31) << 8);
+    }
+    pub const fn Rsvd3(&self) -> u16 {
+        (self._bitfield >> 13) & 7
+    }
+    pub fn set_Rsvd3(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 13)) | ((value & 7) << 13);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_PASID_CAPABILITY_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20599,10 +25446,37 @@ impl Default for PCI_EXPRESS_PASID_CONTROL_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_PASID_CONTROL_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_PASID_CONTROL_REGISTER_0 {
+    pub const fn PASIDEnable(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_PASIDEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ExecutePermissionEnable(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ExecutePermissionEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn PrivilegedModeEnable(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_PrivilegedModeEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Rsvd(&self) -> u16 {
+        (self._bitfield >> 3) & 8191
+    }
+    pub fn set_Rsvd(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(8191 << 3)) | ((value & 8191) << 3);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_PASID_CONTROL_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20624,11 +25498,32 @@ impl Default for PCI_EXPRESS_PME_REQUESTOR_ID {
     fn default() -> Self {
         unsafe { core::mem::zeroed() }
     }
-}
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct PCI_EXPRESS_PME_REQUESTOR_ID_0 {
-    pub _bitfield: u16,
+}
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct PCI_EXPRESS_PME_REQUESTOR_ID_0 {
+    pub _bitfield: u16,
+}
+impl PCI_EXPRESS_PME_REQUESTOR_ID_0 {
+    pub const fn FunctionNumber(&self) -> u16 {
+        self._bitfield & 7
+    }
+    pub fn set_FunctionNumber(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn DeviceNumber(&self) -> u16 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_DeviceNumber(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn BusNumber(&self) -> u16 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_BusNumber(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
 }
 impl windows_core::TypeKind for PCI_EXPRESS_PME_REQUESTOR_ID_0 {
     type TypeKind = windows_core::CopyType;
@@ -20670,10 +25565,31 @@ impl Default for PCI_EXPRESS_PRI_CONTROL_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_PRI_CONTROL_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_PRI_CONTROL_REGISTER_0 {
+    pub const fn Enable(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_Enable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reset(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Reset(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Rsvd(&self) -> u16 {
+        (self._bitfield >> 2) & 16383
+    }
+    pub fn set_Rsvd(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(16383 << 2)) | ((value & 16383) << 2);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_PRI_CONTROL_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20697,10 +25613,49 @@ impl Default for PCI_EXPRESS_PRI_STATUS_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_PRI_STATUS_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_PRI_STATUS_REGISTER_0 {
+    pub const fn ResponseFailure(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_ResponseFailure(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn UnexpectedPageRequestGroupIndex(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_UnexpectedPageRequestGroupIndex(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Rsvd(&self) -> u16 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_Rsvd(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+    pub const fn Stopped(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_Stopped(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Rsvd2(&self) -> u16 {
+        (self._bitfield >> 9) & 63
+    }
+    pub fn set_Rsvd2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(63 << 9)) | ((value & 63) << 9);
+    }
+    pub const fn PrgResponsePasidRequired(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_PrgResponsePasidRequired(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_PRI_STATUS_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20739,10 +25694,49 @@ impl Default for PCI_EXPRESS_PTM_CAPABILITY_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_PTM_CAPABILITY_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_PTM_CAPABILITY_REGISTER_0 {
+    pub const fn RequesterCapable(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_RequesterCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ResponderCapable(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ResponderCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn RootCapable(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_RootCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn LocalGranularity(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_LocalGranularity(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_PTM_CAPABILITY_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20766,10 +25760,43 @@ impl Default for PCI_EXPRESS_PTM_CONTROL_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_PTM_CONTROL_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_PTM_CONTROL_REGISTER_0 {
+    pub const fn Enable(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Enable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn RootSelect(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_RootSelect(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+    pub const fn EffectiveGranularity(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_EffectiveGranularity(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_PTM_CONTROL_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20807,10 +25834,31 @@ impl Default for PCI_EXPRESS_RESIZABLE_BAR_CAPABILITY_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_RESIZABLE_BAR_CAPABILITY_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_RESIZABLE_BAR_CAPABILITY_REGISTER_0 {
+    pub const fn Rsvd(&self) -> u32 {
+        self._bitfield & 15
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn SizesSupported(&self) -> u32 {
+        (self._bitfield >> 4) & 1048575
+    }
+    pub fn set_SizesSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1048575 << 4)) | ((value & 1048575) << 4);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_RESIZABLE_BAR_CAPABILITY_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20834,10 +25882,43 @@ impl Default for PCI_EXPRESS_RESIZABLE_BAR_CONTROL_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_RESIZABLE_BAR_CONTROL_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_RESIZABLE_BAR_CONTROL_REGISTER_0 {
+    pub const fn BarIndex(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_BarIndex(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 3) & 3
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 3)) | ((value & 3) << 3);
+    }
+    pub const fn NumberOfResizableBars(&self) -> u32 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_NumberOfResizableBars(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn BarSize(&self) -> u32 {
+        (self._bitfield >> 8) & 63
+    }
+    pub fn set_BarSize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 8)) | ((value & 63) << 8);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 14) & 262143
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(262143 << 14)) | ((value & 262143) << 14);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_RESIZABLE_BAR_CONTROL_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20898,10 +25979,25 @@ impl Default for PCI_EXPRESS_ROOT_CAPABILITIES_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_ROOT_CAPABILITIES_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_ROOT_CAPABILITIES_REGISTER_0 {
+    pub const fn CRSSoftwareVisibility(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_CRSSoftwareVisibility(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Rsvd(&self) -> u16 {
+        (self._bitfield >> 1) & 32767
+    }
+    pub fn set_Rsvd(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(32767 << 1)) | ((value & 32767) << 1);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_ROOT_CAPABILITIES_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20925,10 +26021,49 @@ impl Default for PCI_EXPRESS_ROOT_CONTROL_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_ROOT_CONTROL_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_ROOT_CONTROL_REGISTER_0 {
+    pub const fn CorrectableSerrEnable(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_CorrectableSerrEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NonFatalSerrEnable(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NonFatalSerrEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn FatalSerrEnable(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_FatalSerrEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn PMEInterruptEnable(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_PMEInterruptEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn CRSSoftwareVisibilityEnable(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_CRSSoftwareVisibilityEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Rsvd(&self) -> u16 {
+        (self._bitfield >> 5) & 2047
+    }
+    pub fn set_Rsvd(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(2047 << 5)) | ((value & 2047) << 5);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_ROOT_CONTROL_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20952,10 +26087,37 @@ impl Default for PCI_EXPRESS_ROOT_ERROR_COMMAND {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_ROOT_ERROR_COMMAND_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_ROOT_ERROR_COMMAND_0 {
+    pub const fn CorrectableErrorReportingEnable(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_CorrectableErrorReportingEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NonFatalErrorReportingEnable(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NonFatalErrorReportingEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn FatalErrorReportingEnable(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_FatalErrorReportingEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_ROOT_ERROR_COMMAND_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20979,10 +26141,67 @@ impl Default for PCI_EXPRESS_ROOT_ERROR_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_ROOT_ERROR_STATUS_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_ROOT_ERROR_STATUS_0 {
+    pub const fn CorrectableErrorReceived(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_CorrectableErrorReceived(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MultipleCorrectableErrorsReceived(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MultipleCorrectableErrorsReceived(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn UncorrectableErrorReceived(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_UncorrectableErrorReceived(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn MultipleUncorrectableErrorsReceived(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_MultipleUncorrectableErrorsReceived(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn FirstUncorrectableFatal(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_FirstUncorrectableFatal(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn NonFatalErrorMessagesReceived(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_NonFatalErrorMessagesReceived(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn FatalErrorMessagesReceived(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_FatalErrorMessagesReceived(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 7) & 1048575
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1048575 << 7)) | ((value & 1048575) << 7);
+    }
+    pub const fn AdvancedErrorInterruptMessageNumber(&self) -> u32 {
+        (self._bitfield >> 27) & 31
+    }
+    pub fn set_AdvancedErrorInterruptMessageNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 27)) | ((value & 31) << 27);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_ROOT_ERROR_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -20992,7 +26211,8 @@ impl Default for PCI_EXPRESS_ROOT_ERROR_STATUS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_ROOT_PORT_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -21025,10 +26245,37 @@ impl Default for PCI_EXPRESS_ROOT_STATUS_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_ROOT_STATUS_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_ROOT_STATUS_REGISTER_0 {
+    pub const fn PMERequestorId(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_PMERequestorId(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn PMEStatus(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_PMEStatus(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn PMEPending(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_PMEPending(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 18) & 16383
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(16383 << 18)) | ((value & 16383) << 18);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_ROOT_STATUS_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21067,10 +26314,25 @@ impl Default for PCI_EXPRESS_SEC_AER_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_SEC_AER_CAPABILITIES_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_SEC_AER_CAPABILITIES_0 {
+    pub const fn SecondaryUncorrectableFirstErrorPtr(&self) -> u32 {
+        self._bitfield & 31
+    }
+    pub fn set_SecondaryUncorrectableFirstErrorPtr(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 5) & 134217727
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(134217727 << 5)) | ((value & 134217727) << 5);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_SEC_AER_CAPABILITIES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21094,10 +26356,103 @@ impl Default for PCI_EXPRESS_SEC_UNCORRECTABLE_ERROR_MASK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_SEC_UNCORRECTABLE_ERROR_MASK_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_SEC_UNCORRECTABLE_ERROR_MASK_0 {
+    pub const fn TargetAbortOnSplitCompletion(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_TargetAbortOnSplitCompletion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MasterAbortOnSplitCompletion(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MasterAbortOnSplitCompletion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ReceivedTargetAbort(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ReceivedTargetAbort(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ReceivedMasterAbort(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ReceivedMasterAbort(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn RsvdZ(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_RsvdZ(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn UnexpectedSplitCompletionError(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_UnexpectedSplitCompletionError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn UncorrectableSplitCompletion(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_UncorrectableSplitCompletion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn UncorrectableDataError(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_UncorrectableDataError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn UncorrectableAttributeError(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_UncorrectableAttributeError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn UncorrectableAddressError(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_UncorrectableAddressError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn DelayedTransactionDiscardTimerExpired(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_DelayedTransactionDiscardTimerExpired(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn PERRAsserted(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_PERRAsserted(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn SERRAsserted(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_SERRAsserted(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn InternalBridgeError(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_InternalBridgeError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 14) & 262143
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(262143 << 14)) | ((value & 262143) << 14);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_SEC_UNCORRECTABLE_ERROR_MASK_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21121,10 +26476,103 @@ impl Default for PCI_EXPRESS_SEC_UNCORRECTABLE_ERROR_SEVERITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_SEC_UNCORRECTABLE_ERROR_SEVERITY_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_SEC_UNCORRECTABLE_ERROR_SEVERITY_0 {
+    pub const fn TargetAbortOnSplitCompletion(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_TargetAbortOnSplitCompletion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MasterAbortOnSplitCompletion(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MasterAbortOnSplitCompletion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ReceivedTargetAbort(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ReceivedTargetAbort(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ReceivedMasterAbort(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ReceivedMasterAbort(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn RsvdZ(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_RsvdZ(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn UnexpectedSplitCompletionError(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_UnexpectedSplitCompletionError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn UncorrectableSplitCompletion(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_UncorrectableSplitCompletion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn UncorrectableDataError(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_UncorrectableDataError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn UncorrectableAttributeError(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_UncorrectableAttributeError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn UncorrectableAddressError(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_UncorrectableAddressError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn DelayedTransactionDiscardTimerExpired(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_DelayedTransactionDiscardTimerExpired(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn PERRAsserted(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_PERRAsserted(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn SERRAsserted(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_SERRAsserted(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn InternalBridgeError(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_InternalBridgeError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 14) & 262143
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(262143 << 14)) | ((value & 262143) << 14);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_SEC_UNCORRECTABLE_ERROR_SEVERITY_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21148,10 +26596,103 @@ impl Default for PCI_EXPRESS_SEC_UNCORRECTABLE_ERROR_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_SEC_UNCORRECTABLE_ERROR_STATUS_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_SEC_UNCORRECTABLE_ERROR_STATUS_0 {
+    pub const fn TargetAbortOnSplitCompletion(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_TargetAbortOnSplitCompletion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MasterAbortOnSplitCompletion(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MasterAbortOnSplitCompletion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ReceivedTargetAbort(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ReceivedTargetAbort(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ReceivedMasterAbort(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ReceivedMasterAbort(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn RsvdZ(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_RsvdZ(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn UnexpectedSplitCompletionError(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_UnexpectedSplitCompletionError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn UncorrectableSplitCompletion(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_UncorrectableSplitCompletion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn UncorrectableDataError(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_UncorrectableDataError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn UncorrectableAttributeError(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_UncorrectableAttributeError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn UncorrectableAddressError(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_UncorrectableAddressError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn DelayedTransactionDiscardTimerExpired(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_DelayedTransactionDiscardTimerExpired(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn PERRAsserted(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_PERRAsserted(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn SERRAsserted(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_SERRAsserted(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn InternalBridgeError(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_InternalBridgeError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 14) & 262143
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(262143 << 14)) | ((value & 262143) << 14);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_SEC_UNCORRECTABLE_ERROR_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21161,7 +26702,8 @@ impl Default for PCI_EXPRESS_SEC_UNCORRECTABLE_ERROR_STATUS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_SERIAL_NUMBER_CAPABILITY {
     pub Header: PCI_EXPRESS_ENHANCED_CAPABILITY_HEADER,
     pub LowSerialNumber: u32,
@@ -21174,25 +26716,100 @@ impl Default for PCI_EXPRESS_SERIAL_NUMBER_CAPABILITY {
     fn default() -> Self {
         unsafe { core::mem::zeroed() }
     }
-}
-#[repr(C)]
-#[derive(Clone, Copy)]
-pub union PCI_EXPRESS_SLOT_CAPABILITIES_REGISTER {
-    pub Anonymous: PCI_EXPRESS_SLOT_CAPABILITIES_REGISTER_0,
-    pub AsULONG: u32,
-}
-impl windows_core::TypeKind for PCI_EXPRESS_SLOT_CAPABILITIES_REGISTER {
-    type TypeKind = windows_core::CopyType;
-}
-impl Default for PCI_EXPRESS_SLOT_CAPABILITIES_REGISTER {
-    fn default() -> Self {
-        unsafe { core::mem::zeroed() }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union PCI_EXPRESS_SLOT_CAPABILITIES_REGISTER {
+    pub Anonymous: PCI_EXPRESS_SLOT_CAPABILITIES_REGISTER_0,
+    pub AsULONG: u32,
+}
+impl windows_core::TypeKind for PCI_EXPRESS_SLOT_CAPABILITIES_REGISTER {
+    type TypeKind = windows_core::CopyType;
+}
+impl Default for PCI_EXPRESS_SLOT_CAPABILITIES_REGISTER {
+    fn default() -> Self {
+        unsafe { core::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct PCI_EXPRESS_SLOT_CAPABILITIES_REGISTER_0 {
+    pub _bitfield: u32,
+}
+impl PCI_EXPRESS_SLOT_CAPABILITIES_REGISTER_0 {
+    pub const fn AttentionButtonPresent(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_AttentionButtonPresent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PowerControllerPresent(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PowerControllerPresent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MRLSensorPresent(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_MRLSensorPresent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AttentionIndicatorPresent(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AttentionIndicatorPresent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn PowerIndicatorPresent(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_PowerIndicatorPresent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn HotPlugSurprise(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_HotPlugSurprise(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn HotPlugCapable(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_HotPlugCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn SlotPowerLimit(&self) -> u32 {
+        (self._bitfield >> 7) & 255
+    }
+    pub fn set_SlotPowerLimit(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 7)) | ((value & 255) << 7);
+    }
+    pub const fn SlotPowerLimitScale(&self) -> u32 {
+        (self._bitfield >> 15) & 3
+    }
+    pub fn set_SlotPowerLimitScale(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 15)) | ((value & 3) << 15);
+    }
+    pub const fn ElectromechanicalLockPresent(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_ElectromechanicalLockPresent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn NoCommandCompletedSupport(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_NoCommandCompletedSupport(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn PhysicalSlotNumber(&self) -> u32 {
+        (self._bitfield >> 19) & 8191
+    }
+    pub fn set_PhysicalSlotNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8191 << 19)) | ((value & 8191) << 19);
     }
-}
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct PCI_EXPRESS_SLOT_CAPABILITIES_REGISTER_0 {
-    pub _bitfield: u32,
 }
 impl windows_core::TypeKind for PCI_EXPRESS_SLOT_CAPABILITIES_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
@@ -21217,10 +26834,85 @@ impl Default for PCI_EXPRESS_SLOT_CONTROL_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_SLOT_CONTROL_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_SLOT_CONTROL_REGISTER_0 {
+    pub const fn AttentionButtonEnable(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_AttentionButtonEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PowerFaultDetectEnable(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PowerFaultDetectEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MRLSensorEnable(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_MRLSensorEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn PresenceDetectEnable(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_PresenceDetectEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn CommandCompletedEnable(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_CommandCompletedEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn HotPlugInterruptEnable(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_HotPlugInterruptEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn AttentionIndicatorControl(&self) -> u16 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_AttentionIndicatorControl(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn PowerIndicatorControl(&self) -> u16 {
+        (self._bitfield >> 8) & 3
+    }
+    pub fn set_PowerIndicatorControl(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 8)) | ((value & 3) << 8);
+    }
+    pub const fn PowerControllerControl(&self) -> u16 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_PowerControllerControl(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn ElectromechanicalLockControl(&self) -> u16 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_ElectromechanicalLockControl(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn DataLinkStateChangeEnable(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_DataLinkStateChangeEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn Rsvd(&self) -> u16 {
+        (self._bitfield >> 13) & 7
+    }
+    pub fn set_Rsvd(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 13)) | ((value & 7) << 13);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_SLOT_CONTROL_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21244,10 +26936,73 @@ impl Default for PCI_EXPRESS_SLOT_STATUS_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_SLOT_STATUS_REGISTER_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_SLOT_STATUS_REGISTER_0 {
+    pub const fn AttentionButtonPressed(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_AttentionButtonPressed(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PowerFaultDetected(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PowerFaultDetected(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MRLSensorChanged(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_MRLSensorChanged(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn PresenceDetectChanged(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_PresenceDetectChanged(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn CommandCompleted(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_CommandCompleted(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn MRLSensorState(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_MRLSensorState(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn PresenceDetectState(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_PresenceDetectState(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn ElectromechanicalLockEngaged(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_ElectromechanicalLockEngaged(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn DataLinkStateChanged(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_DataLinkStateChanged(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Rsvd(&self) -> u16 {
+        (self._bitfield >> 9) & 127
+    }
+    pub fn set_Rsvd(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(127 << 9)) | ((value & 127) << 9);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_SLOT_STATUS_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21300,10 +27055,31 @@ impl Default for PCI_EXPRESS_SRIOV_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_SRIOV_CAPS_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_SRIOV_CAPS_0 {
+    pub const fn VFMigrationCapable(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_VFMigrationCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 1) & 1048575
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1048575 << 1)) | ((value & 1048575) << 1);
+    }
+    pub const fn VFMigrationInterruptNumber(&self) -> u32 {
+        (self._bitfield >> 21) & 2047
+    }
+    pub fn set_VFMigrationInterruptNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2047 << 21)) | ((value & 2047) << 21);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_SRIOV_CAPS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21327,10 +27103,49 @@ impl Default for PCI_EXPRESS_SRIOV_CONTROL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_SRIOV_CONTROL_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_SRIOV_CONTROL_0 {
+    pub const fn VFEnable(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_VFEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn VFMigrationEnable(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_VFMigrationEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn VFMigrationInterruptEnable(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_VFMigrationInterruptEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn VFMemorySpaceEnable(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_VFMemorySpaceEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ARICapableHierarchy(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_ARICapableHierarchy(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved1(&self) -> u16 {
+        (self._bitfield >> 5) & 2047
+    }
+    pub fn set_Reserved1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(2047 << 5)) | ((value & 2047) << 5);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_SRIOV_CONTROL_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21354,10 +27169,25 @@ impl Default for PCI_EXPRESS_SRIOV_MIGRATION_STATE_ARRAY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_SRIOV_MIGRATION_STATE_ARRAY_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_SRIOV_MIGRATION_STATE_ARRAY_0 {
+    pub const fn VFMigrationStateBIR(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_VFMigrationStateBIR(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn VFMigrationStateOffset(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_VFMigrationStateOffset(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_SRIOV_MIGRATION_STATE_ARRAY_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21381,10 +27211,25 @@ impl Default for PCI_EXPRESS_SRIOV_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_SRIOV_STATUS_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_SRIOV_STATUS_0 {
+    pub const fn VFMigrationStatus(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_VFMigrationStatus(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved1(&self) -> u16 {
+        (self._bitfield >> 1) & 32767
+    }
+    pub fn set_Reserved1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(32767 << 1)) | ((value & 32767) << 1);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_SRIOV_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21423,10 +27268,67 @@ impl Default for PCI_EXPRESS_TPH_REQUESTER_CAPABILITY_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_TPH_REQUESTER_CAPABILITY_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_TPH_REQUESTER_CAPABILITY_REGISTER_0 {
+    pub const fn NoStModeSupported(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_NoStModeSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn InteruptVectorModeSupported(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_InteruptVectorModeSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn DeviceSpecificModeSupported(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_DeviceSpecificModeSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn ExtendedTPHRequesterSupported(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_ExtendedTPHRequesterSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn StTableLocation(&self) -> u32 {
+        (self._bitfield >> 9) & 3
+    }
+    pub fn set_StTableLocation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 9)) | ((value & 3) << 9);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 11) & 31
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 11)) | ((value & 31) << 11);
+    }
+    pub const fn StTableSize(&self) -> u32 {
+        (self._bitfield >> 16) & 2047
+    }
+    pub fn set_StTableSize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2047 << 16)) | ((value & 2047) << 16);
+    }
+    pub const fn Rsvd3(&self) -> u32 {
+        (self._bitfield >> 27) & 31
+    }
+    pub fn set_Rsvd3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 27)) | ((value & 31) << 27);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_TPH_REQUESTER_CAPABILITY_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21450,10 +27352,37 @@ impl Default for PCI_EXPRESS_TPH_REQUESTER_CONTROL_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_TPH_REQUESTER_CONTROL_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_TPH_REQUESTER_CONTROL_REGISTER_0 {
+    pub const fn StModeSelect(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_StModeSelect(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Rsvd(&self) -> u32 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Rsvd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn TphRequesterEnable(&self) -> u32 {
+        (self._bitfield >> 8) & 3
+    }
+    pub fn set_TphRequesterEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 8)) | ((value & 3) << 8);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 10) & 4194303
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4194303 << 10)) | ((value & 4194303) << 10);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_TPH_REQUESTER_CONTROL_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21477,10 +27406,25 @@ impl Default for PCI_EXPRESS_TPH_ST_TABLE_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_TPH_ST_TABLE_ENTRY_0 {
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_TPH_ST_TABLE_ENTRY_0 {
+    pub const fn LowerEntry(&self) -> u16 {
+        self._bitfield & 255
+    }
+    pub fn set_LowerEntry(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn UpperEntry(&self) -> u16 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_UpperEntry(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_TPH_ST_TABLE_ENTRY_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21504,10 +27448,133 @@ impl Default for PCI_EXPRESS_UNCORRECTABLE_ERROR_MASK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_UNCORRECTABLE_ERROR_MASK_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_UNCORRECTABLE_ERROR_MASK_0 {
+    pub const fn Undefined(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Undefined(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 1) & 7
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 1)) | ((value & 7) << 1);
+    }
+    pub const fn DataLinkProtocolError(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_DataLinkProtocolError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn SurpriseDownError(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_SurpriseDownError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 6) & 63
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 6)) | ((value & 63) << 6);
+    }
+    pub const fn PoisonedTLP(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_PoisonedTLP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn FlowControlProtocolError(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_FlowControlProtocolError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn CompletionTimeout(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_CompletionTimeout(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn CompleterAbort(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_CompleterAbort(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn UnexpectedCompletion(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_UnexpectedCompletion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn ReceiverOverflow(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_ReceiverOverflow(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn MalformedTLP(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_MalformedTLP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn ECRCError(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_ECRCError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn UnsupportedRequestError(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_UnsupportedRequestError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn AcsViolation(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_AcsViolation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn UncorrectableInternalError(&self) -> u32 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_UncorrectableInternalError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn MCBlockedTlp(&self) -> u32 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_MCBlockedTlp(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn AtomicOpEgressBlocked(&self) -> u32 {
+        (self._bitfield >> 24) & 1
+    }
+    pub fn set_AtomicOpEgressBlocked(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 24)) | ((value & 1) << 24);
+    }
+    pub const fn TlpPrefixBlocked(&self) -> u32 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_TlpPrefixBlocked(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn Reserved3(&self) -> u32 {
+        (self._bitfield >> 26) & 63
+    }
+    pub fn set_Reserved3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 26)) | ((value & 63) << 26);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_UNCORRECTABLE_ERROR_MASK_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21531,10 +27598,133 @@ impl Default for PCI_EXPRESS_UNCORRECTABLE_ERROR_SEVERITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_UNCORRECTABLE_ERROR_SEVERITY_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_UNCORRECTABLE_ERROR_SEVERITY_0 {
+    pub const fn Undefined(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Undefined(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 1) & 7
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 1)) | ((value & 7) << 1);
+    }
+    pub const fn DataLinkProtocolError(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_DataLinkProtocolError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn SurpriseDownError(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_SurpriseDownError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 6) & 63
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 6)) | ((value & 63) << 6);
+    }
+    pub const fn PoisonedTLP(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_PoisonedTLP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn FlowControlProtocolError(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_FlowControlProtocolError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn CompletionTimeout(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_CompletionTimeout(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn CompleterAbort(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_CompleterAbort(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn UnexpectedCompletion(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_UnexpectedCompletion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn ReceiverOverflow(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_ReceiverOverflow(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn MalformedTLP(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_MalformedTLP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn ECRCError(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_ECRCError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn UnsupportedRequestError(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_UnsupportedRequestError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn AcsViolation(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_AcsViolation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn UncorrectableInternalError(&self) -> u32 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_UncorrectableInternalError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn MCBlockedTlp(&self) -> u32 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_MCBlockedTlp(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn AtomicOpEgressBlocked(&self) -> u32 {
+        (self._bitfield >> 24) & 1
+    }
+    pub fn set_AtomicOpEgressBlocked(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 24)) | ((value & 1) << 24);
+    }
+    pub const fn TlpPrefixBlocked(&self) -> u32 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_TlpPrefixBlocked(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn Reserved3(&self) -> u32 {
+        (self._bitfield >> 26) & 63
+    }
+    pub fn set_Reserved3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 26)) | ((value & 63) << 26);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_UNCORRECTABLE_ERROR_SEVERITY_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21558,10 +27748,133 @@ impl Default for PCI_EXPRESS_UNCORRECTABLE_ERROR_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_UNCORRECTABLE_ERROR_STATUS_0 {
     pub _bitfield: u32,
 }
+impl PCI_EXPRESS_UNCORRECTABLE_ERROR_STATUS_0 {
+    pub const fn Undefined(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Undefined(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 1) & 7
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 1)) | ((value & 7) << 1);
+    }
+    pub const fn DataLinkProtocolError(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_DataLinkProtocolError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn SurpriseDownError(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_SurpriseDownError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 6) & 63
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 6)) | ((value & 63) << 6);
+    }
+    pub const fn PoisonedTLP(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_PoisonedTLP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn FlowControlProtocolError(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_FlowControlProtocolError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn CompletionTimeout(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_CompletionTimeout(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn CompleterAbort(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_CompleterAbort(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn UnexpectedCompletion(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_UnexpectedCompletion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn ReceiverOverflow(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_ReceiverOverflow(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn MalformedTLP(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_MalformedTLP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn ECRCError(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_ECRCError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn UnsupportedRequestError(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_UnsupportedRequestError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn AcsViolation(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_AcsViolation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn UncorrectableInternalError(&self) -> u32 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_UncorrectableInternalError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn MCBlockedTlp(&self) -> u32 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_MCBlockedTlp(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn AtomicOpEgressBlocked(&self) -> u32 {
+        (self._bitfield >> 24) & 1
+    }
+    pub fn set_AtomicOpEgressBlocked(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 24)) | ((value & 1) << 24);
+    }
+    pub const fn TlpPrefixBlocked(&self) -> u32 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_TlpPrefixBlocked(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn Reserved3(&self) -> u32 {
+        (self._bitfield >> 26) & 63
+    }
+    pub fn set_Reserved3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 26)) | ((value & 63) << 26);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_UNCORRECTABLE_ERROR_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21571,12 +27884,27 @@ impl Default for PCI_EXPRESS_UNCORRECTABLE_ERROR_STATUS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_EXPRESS_VENDOR_SPECIFIC_CAPABILITY {
     pub Header: PCI_EXPRESS_ENHANCED_CAPABILITY_HEADER,
     pub VsecId: u16,
     pub _bitfield: u16,
 }
+impl PCI_EXPRESS_VENDOR_SPECIFIC_CAPABILITY {
+    pub const fn VsecRev(&self) -> u16 {
+        self._bitfield & 15
+    }
+    pub fn set_VsecRev(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn VsecLength(&self) -> u16 {
+        (self._bitfield >> 4) & 4095
+    }
+    pub fn set_VsecLength(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(4095 << 4)) | ((value & 4095) << 4);
+    }
+}
 impl windows_core::TypeKind for PCI_EXPRESS_VENDOR_SPECIFIC_CAPABILITY {
     type TypeKind = windows_core::CopyType;
 }
@@ -21604,7 +27932,8 @@ impl Default for PCI_EXPRESS_VIRTUAL_CHANNEL_CAPABILITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_FIRMWARE_BUS_CAPS {
     pub Type: u16,
     pub Length: u16,
@@ -21624,10 +27953,43 @@ impl Default for PCI_FIRMWARE_BUS_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_FIRMWARE_BUS_CAPS_0 {
     pub _bitfield: u8,
 }
+impl PCI_FIRMWARE_BUS_CAPS_0 {
+    pub const fn SixtyFourBitDevice(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_SixtyFourBitDevice(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PciXMode1EccCapable(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PciXMode1EccCapable(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn DeviceIdMessagingCapable(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_DeviceIdMessagingCapable(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ObffWakeSignalCapable(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ObffWakeSignalCapable(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved1(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Reserved1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for PCI_FIRMWARE_BUS_CAPS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21637,7 +27999,8 @@ impl Default for PCI_FIRMWARE_BUS_CAPS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_FIRMWARE_BUS_CAPS_RETURN_BUFFER {
     pub Version: u16,
     pub Status: u16,
@@ -21667,10 +28030,73 @@ impl Default for PCI_FPB_CAPABILITIES_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_FPB_CAPABILITIES_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_FPB_CAPABILITIES_REGISTER_0 {
+    pub const fn RidDecodeMechanismSupported(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_RidDecodeMechanismSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MemLowDecodeMechanismSupported(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MemLowDecodeMechanismSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MemHighDecodeMechanismSupported(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_MemHighDecodeMechanismSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn NumSecDev(&self) -> u32 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_NumSecDev(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn RidVectorSizeSupported(&self) -> u32 {
+        (self._bitfield >> 8) & 7
+    }
+    pub fn set_RidVectorSizeSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 8)) | ((value & 7) << 8);
+    }
+    pub const fn Rsvd0(&self) -> u32 {
+        (self._bitfield >> 11) & 31
+    }
+    pub fn set_Rsvd0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 11)) | ((value & 31) << 11);
+    }
+    pub const fn MemLowVectorSizeSupported(&self) -> u32 {
+        (self._bitfield >> 16) & 7
+    }
+    pub fn set_MemLowVectorSizeSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 16)) | ((value & 7) << 16);
+    }
+    pub const fn Rsvd1(&self) -> u32 {
+        (self._bitfield >> 19) & 31
+    }
+    pub fn set_Rsvd1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 19)) | ((value & 31) << 19);
+    }
+    pub const fn MemHighVectorSizeSupported(&self) -> u32 {
+        (self._bitfield >> 24) & 7
+    }
+    pub fn set_MemHighVectorSizeSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 24)) | ((value & 7) << 24);
+    }
+    pub const fn Rsvd2(&self) -> u32 {
+        (self._bitfield >> 27) & 31
+    }
+    pub fn set_Rsvd2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 27)) | ((value & 31) << 27);
+    }
+}
 impl windows_core::TypeKind for PCI_FPB_CAPABILITIES_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21701,7 +28127,8 @@ impl Default for PCI_FPB_CAPABILITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_FPB_CAPABILITY_HEADER {
     pub Header: PCI_CAPABILITIES_HEADER,
     pub Reserved: u16,
@@ -21729,10 +28156,43 @@ impl Default for PCI_FPB_MEM_HIGH_VECTOR_CONTROL1_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_FPB_MEM_HIGH_VECTOR_CONTROL1_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_FPB_MEM_HIGH_VECTOR_CONTROL1_REGISTER_0 {
+    pub const fn MemHighDecodeMechanismEnable(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_MemHighDecodeMechanismEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Rsvd0(&self) -> u32 {
+        (self._bitfield >> 1) & 7
+    }
+    pub fn set_Rsvd0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 1)) | ((value & 7) << 1);
+    }
+    pub const fn MemHighVectorGranularity(&self) -> u32 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_MemHighVectorGranularity(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn Rsvd1(&self) -> u32 {
+        (self._bitfield >> 8) & 1048575
+    }
+    pub fn set_Rsvd1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1048575 << 8)) | ((value & 1048575) << 8);
+    }
+    pub const fn MemHighVectorStartLower(&self) -> u32 {
+        (self._bitfield >> 28) & 15
+    }
+    pub fn set_MemHighVectorStartLower(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 28)) | ((value & 15) << 28);
+    }
+}
 impl windows_core::TypeKind for PCI_FPB_MEM_HIGH_VECTOR_CONTROL1_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21742,7 +28202,8 @@ impl Default for PCI_FPB_MEM_HIGH_VECTOR_CONTROL1_REGISTER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_FPB_MEM_HIGH_VECTOR_CONTROL2_REGISTER {
     pub MemHighVectorStartUpper: u32,
 }
@@ -21769,10 +28230,43 @@ impl Default for PCI_FPB_MEM_LOW_VECTOR_CONTROL_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_FPB_MEM_LOW_VECTOR_CONTROL_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_FPB_MEM_LOW_VECTOR_CONTROL_REGISTER_0 {
+    pub const fn MemLowDecodeMechanismEnable(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_MemLowDecodeMechanismEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Rsvd0(&self) -> u32 {
+        (self._bitfield >> 1) & 7
+    }
+    pub fn set_Rsvd0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 1)) | ((value & 7) << 1);
+    }
+    pub const fn MemLowVectorGranularity(&self) -> u32 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_MemLowVectorGranularity(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn Rsvd1(&self) -> u32 {
+        (self._bitfield >> 8) & 4095
+    }
+    pub fn set_Rsvd1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4095 << 8)) | ((value & 4095) << 8);
+    }
+    pub const fn MemLowVectorStart(&self) -> u32 {
+        (self._bitfield >> 20) & 4095
+    }
+    pub fn set_MemLowVectorStart(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4095 << 20)) | ((value & 4095) << 20);
+    }
+}
 impl windows_core::TypeKind for PCI_FPB_MEM_LOW_VECTOR_CONTROL_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21796,10 +28290,43 @@ impl Default for PCI_FPB_RID_VECTOR_CONTROL1_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_FPB_RID_VECTOR_CONTROL1_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_FPB_RID_VECTOR_CONTROL1_REGISTER_0 {
+    pub const fn RidDecodeMechanismEnable(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_RidDecodeMechanismEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Rsvd0(&self) -> u32 {
+        (self._bitfield >> 1) & 7
+    }
+    pub fn set_Rsvd0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 1)) | ((value & 7) << 1);
+    }
+    pub const fn RidVectorGranularity(&self) -> u32 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_RidVectorGranularity(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn Rsvd1(&self) -> u32 {
+        (self._bitfield >> 8) & 2047
+    }
+    pub fn set_Rsvd1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2047 << 8)) | ((value & 2047) << 8);
+    }
+    pub const fn RidVectorStart(&self) -> u32 {
+        (self._bitfield >> 19) & 8191
+    }
+    pub fn set_RidVectorStart(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8191 << 19)) | ((value & 8191) << 19);
+    }
+}
 impl windows_core::TypeKind for PCI_FPB_RID_VECTOR_CONTROL1_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21823,10 +28350,31 @@ impl Default for PCI_FPB_RID_VECTOR_CONTROL2_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_FPB_RID_VECTOR_CONTROL2_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_FPB_RID_VECTOR_CONTROL2_REGISTER_0 {
+    pub const fn Rsvd0(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_Rsvd0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn RidSecondaryStart(&self) -> u32 {
+        (self._bitfield >> 3) & 8191
+    }
+    pub fn set_RidSecondaryStart(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8191 << 3)) | ((value & 8191) << 3);
+    }
+    pub const fn Rsvd1(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Rsvd1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for PCI_FPB_RID_VECTOR_CONTROL2_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21850,10 +28398,37 @@ impl Default for PCI_FPB_VECTOR_ACCESS_CONTROL_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_FPB_VECTOR_ACCESS_CONTROL_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl PCI_FPB_VECTOR_ACCESS_CONTROL_REGISTER_0 {
+    pub const fn VectorAccessOffset(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_VectorAccessOffset(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn Rsvd0(&self) -> u32 {
+        (self._bitfield >> 8) & 63
+    }
+    pub fn set_Rsvd0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 8)) | ((value & 63) << 8);
+    }
+    pub const fn VectorSelect(&self) -> u32 {
+        (self._bitfield >> 14) & 3
+    }
+    pub fn set_VectorSelect(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 14)) | ((value & 3) << 14);
+    }
+    pub const fn Rsvd1(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Rsvd1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for PCI_FPB_VECTOR_ACCESS_CONTROL_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21863,7 +28438,8 @@ impl Default for PCI_FPB_VECTOR_ACCESS_CONTROL_REGISTER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_FPB_VECTOR_ACCESS_DATA_REGISTER {
     pub VectorAccessData: u32,
 }
@@ -21876,7 +28452,8 @@ impl Default for PCI_FPB_VECTOR_ACCESS_DATA_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_MSIX_TABLE_CONFIG_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -21898,11 +28475,44 @@ impl Default for PCI_MSIX_TABLE_CONFIG_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_PMC {
     pub _bitfield: u8,
     pub Support: PCI_PMC_0,
 }
+impl PCI_PMC {
+    pub const fn Version(&self) -> u8 {
+        self._bitfield & 7
+    }
+    pub fn set_Version(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn PMEClock(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_PMEClock(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Rsvd1(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Rsvd1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn DeviceSpecificInitialization(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_DeviceSpecificInitialization(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Rsvd2(&self) -> u8 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_Rsvd2(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+}
 impl windows_core::TypeKind for PCI_PMC {
     type TypeKind = windows_core::CopyType;
 }
@@ -21912,10 +28522,61 @@ impl Default for PCI_PMC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_PMC_0 {
     pub _bitfield: u8,
 }
+impl PCI_PMC_0 {
+    pub const fn Rsvd2(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_Rsvd2(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn D1(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_D1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn D2(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_D2(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn PMED0(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_PMED0(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn PMED1(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_PMED1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PMED2(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_PMED2(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn PMED3Hot(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_PMED3Hot(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn PMED3Cold(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_PMED3Cold(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for PCI_PMC_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -21925,10 +28586,61 @@ impl Default for PCI_PMC_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_PMCSR {
     pub _bitfield: u16,
 }
+impl PCI_PMCSR {
+    pub const fn PowerState(&self) -> u16 {
+        self._bitfield & 3
+    }
+    pub fn set_PowerState(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn Rsvd1(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Rsvd1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn NoSoftReset(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_NoSoftReset(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Rsvd2(&self) -> u16 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Rsvd2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn PMEEnable(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_PMEEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn DataSelect(&self) -> u16 {
+        (self._bitfield >> 9) & 15
+    }
+    pub fn set_DataSelect(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 9)) | ((value & 15) << 9);
+    }
+    pub const fn DataScale(&self) -> u16 {
+        (self._bitfield >> 13) & 3
+    }
+    pub fn set_DataScale(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 13)) | ((value & 3) << 13);
+    }
+    pub const fn PMEStatus(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_PMEStatus(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for PCI_PMCSR {
     type TypeKind = windows_core::CopyType;
 }
@@ -21938,10 +28650,31 @@ impl Default for PCI_PMCSR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_PMCSR_BSE {
     pub _bitfield: u8,
 }
+impl PCI_PMCSR_BSE {
+    pub const fn Rsvd1(&self) -> u8 {
+        self._bitfield & 63
+    }
+    pub fn set_Rsvd1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !63) | (value & 63);
+    }
+    pub const fn D3HotSupportsStopClock(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_D3HotSupportsStopClock(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn BusPowerClockControlEnabled(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_BusPowerClockControlEnabled(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for PCI_PMCSR_BSE {
     type TypeKind = windows_core::CopyType;
 }
@@ -22027,7 +28760,8 @@ impl Default for PCI_ROOT_BUS_HARDWARE_CAPABILITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_ROOT_BUS_HARDWARE_CAPABILITY_0 {
     pub BusCapabilitiesFound: super::super::super::Win32::Foundation::BOOLEAN,
     pub CurrentSpeedAndMode: u32,
@@ -22071,10 +28805,55 @@ impl Default for PCI_ROOT_BUS_OSC_CONTROL_FIELD_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_ROOT_BUS_OSC_CONTROL_FIELD_0_0 {
     pub _bitfield: u32,
 }
+impl PCI_ROOT_BUS_OSC_CONTROL_FIELD_0_0 {
+    pub const fn ExpressNativeHotPlug(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ExpressNativeHotPlug(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ShpcNativeHotPlug(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ShpcNativeHotPlug(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ExpressNativePME(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ExpressNativePME(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ExpressAdvancedErrorReporting(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ExpressAdvancedErrorReporting(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ExpressCapabilityStructure(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_ExpressCapabilityStructure(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn LatencyToleranceReporting(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_LatencyToleranceReporting(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 6) & 67108863
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(67108863 << 6)) | ((value & 67108863) << 6);
+    }
+}
 impl windows_core::TypeKind for PCI_ROOT_BUS_OSC_CONTROL_FIELD_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -22111,10 +28890,61 @@ impl Default for PCI_ROOT_BUS_OSC_SUPPORT_FIELD_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_ROOT_BUS_OSC_SUPPORT_FIELD_0_0 {
     pub _bitfield: u32,
 }
+impl PCI_ROOT_BUS_OSC_SUPPORT_FIELD_0_0 {
+    pub const fn ExtendedConfigOpRegions(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ExtendedConfigOpRegions(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ActiveStatePowerManagement(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ActiveStatePowerManagement(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ClockPowerManagement(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ClockPowerManagement(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn SegmentGroups(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_SegmentGroups(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn MessageSignaledInterrupts(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_MessageSignaledInterrupts(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn OptimizedBufferFlushAndFill(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_OptimizedBufferFlushAndFill(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn AspmOptionality(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_AspmOptionality(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 7) & 33554431
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(33554431 << 7)) | ((value & 33554431) << 7);
+    }
+}
 impl windows_core::TypeKind for PCI_ROOT_BUS_OSC_SUPPORT_FIELD_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -22124,7 +28954,8 @@ impl Default for PCI_ROOT_BUS_OSC_SUPPORT_FIELD_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_SECURITY_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -22142,7 +28973,8 @@ impl Default for PCI_SECURITY_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_SECURITY_INTERFACE2 {
     pub Size: u16,
     pub Version: u16,
@@ -22189,10 +29021,31 @@ impl Default for PCI_SEGMENT_BUS_NUMBER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_SEGMENT_BUS_NUMBER_0_0 {
     pub _bitfield: u32,
 }
+impl PCI_SEGMENT_BUS_NUMBER_0_0 {
+    pub const fn BusNumber(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_BusNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn SegmentNumber(&self) -> u32 {
+        (self._bitfield >> 8) & 65535
+    }
+    pub fn set_SegmentNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 8)) | ((value & 65535) << 8);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for PCI_SEGMENT_BUS_NUMBER_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -22229,10 +29082,31 @@ impl Default for PCI_SLOT_NUMBER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_SLOT_NUMBER_0_0 {
     pub _bitfield: u32,
 }
+impl PCI_SLOT_NUMBER_0_0 {
+    pub const fn DeviceNumber(&self) -> u32 {
+        self._bitfield & 31
+    }
+    pub fn set_DeviceNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn FunctionNumber(&self) -> u32 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_FunctionNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 8) & 16777215
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(16777215 << 8)) | ((value & 16777215) << 8);
+    }
+}
 impl windows_core::TypeKind for PCI_SLOT_NUMBER_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -22242,7 +29116,8 @@ impl Default for PCI_SLOT_NUMBER_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_SUBSYSTEM_IDS_CAPABILITY {
     pub Header: PCI_CAPABILITIES_HEADER,
     pub Reserved: u16,
@@ -22258,7 +29133,8 @@ impl Default for PCI_SUBSYSTEM_IDS_CAPABILITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_VENDOR_SPECIFIC_CAPABILITY {
     pub Header: PCI_CAPABILITIES_HEADER,
     pub VscLength: u8,
@@ -22273,7 +29149,8 @@ impl Default for PCI_VENDOR_SPECIFIC_CAPABILITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCI_VIRTUALIZATION_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -22323,39 +29200,159 @@ impl Default for PCI_X_CAPABILITY_0 {
     fn default() -> Self {
         unsafe { core::mem::zeroed() }
     }
-}
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct PCI_X_CAPABILITY_0_0 {
-    pub _bitfield: u16,
-}
-impl windows_core::TypeKind for PCI_X_CAPABILITY_0_0 {
-    type TypeKind = windows_core::CopyType;
-}
-impl Default for PCI_X_CAPABILITY_0_0 {
-    fn default() -> Self {
-        unsafe { core::mem::zeroed() }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct PCI_X_CAPABILITY_0_0 {
+    pub _bitfield: u16,
+}
+impl PCI_X_CAPABILITY_0_0 {
+    pub const fn DataParityErrorRecoveryEnable(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_DataParityErrorRecoveryEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn EnableRelaxedOrdering(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_EnableRelaxedOrdering(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MaxMemoryReadByteCount(&self) -> u16 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_MaxMemoryReadByteCount(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn MaxOutstandingSplitTransactions(&self) -> u16 {
+        (self._bitfield >> 4) & 7
+    }
+    pub fn set_MaxOutstandingSplitTransactions(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 4)) | ((value & 7) << 4);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 7) & 511
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(511 << 7)) | ((value & 511) << 7);
+    }
+}
+impl windows_core::TypeKind for PCI_X_CAPABILITY_0_0 {
+    type TypeKind = windows_core::CopyType;
+}
+impl Default for PCI_X_CAPABILITY_0_0 {
+    fn default() -> Self {
+        unsafe { core::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union PCI_X_CAPABILITY_1 {
+    pub bits: PCI_X_CAPABILITY_1_0,
+    pub AsULONG: u32,
+}
+impl windows_core::TypeKind for PCI_X_CAPABILITY_1 {
+    type TypeKind = windows_core::CopyType;
+}
+impl Default for PCI_X_CAPABILITY_1 {
+    fn default() -> Self {
+        unsafe { core::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct PCI_X_CAPABILITY_1_0 {
+    pub _bitfield: u32,
+}
+impl PCI_X_CAPABILITY_1_0 {
+    pub const fn FunctionNumber(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_FunctionNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn DeviceNumber(&self) -> u32 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_DeviceNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn BusNumber(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_BusNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn Device64Bit(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_Device64Bit(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn Capable133MHz(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_Capable133MHz(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn SplitCompletionDiscarded(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_SplitCompletionDiscarded(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn UnexpectedSplitCompletion(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_UnexpectedSplitCompletion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn DeviceComplexity(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_DeviceComplexity(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn DesignedMaxMemoryReadByteCount(&self) -> u32 {
+        (self._bitfield >> 21) & 3
+    }
+    pub fn set_DesignedMaxMemoryReadByteCount(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 21)) | ((value & 3) << 21);
+    }
+    pub const fn DesignedMaxOutstandingSplitTransactions(&self) -> u32 {
+        (self._bitfield >> 23) & 7
+    }
+    pub fn set_DesignedMaxOutstandingSplitTransactions(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 23)) | ((value & 7) << 23);
+    }
+    pub const fn DesignedMaxCumulativeReadSize(&self) -> u32 {
+        (self._bitfield >> 26) & 7
+    }
+    pub fn set_DesignedMaxCumulativeReadSize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 26)) | ((value & 7) << 26);
+    }
+    pub const fn ReceivedSplitCompletionErrorMessage(&self) -> u32 {
+        (self._bitfield >> 29) & 1
+    }
+    pub fn set_ReceivedSplitCompletionErrorMessage(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 29)) | ((value & 1) << 29);
+    }
+    pub const fn CapablePCIX266(&self) -> u32 {
+        (self._bitfield >> 30) & 1
+    }
+    pub fn set_CapablePCIX266(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 30)) | ((value & 1) << 30);
+    }
+    pub const fn CapablePCIX533(&self) -> u32 {
+        (self._bitfield >> 31) & 1
     }
-}
-#[repr(C)]
-#[derive(Clone, Copy)]
-pub union PCI_X_CAPABILITY_1 {
-    pub bits: PCI_X_CAPABILITY_1_0,
-    pub AsULONG: u32,
-}
-impl windows_core::TypeKind for PCI_X_CAPABILITY_1 {
-    type TypeKind = windows_core::CopyType;
-}
-impl Default for PCI_X_CAPABILITY_1 {
-    fn default() -> Self {
-        unsafe { core::mem::zeroed() }
+    pub fn set_CapablePCIX533(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
     }
 }
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct PCI_X_CAPABILITY_1_0 {
-    pub _bitfield: u32,
-}
 impl windows_core::TypeKind for PCI_X_CAPABILITY_1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -22384,7 +29381,8 @@ impl Default for PCW_CALLBACK_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCW_COUNTER_DESCRIPTOR {
     pub Id: u16,
     pub StructIndex: u16,
@@ -22400,7 +29398,8 @@ impl Default for PCW_COUNTER_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCW_COUNTER_INFORMATION {
     pub CounterMask: u64,
     pub InstanceMask: *mut super::super::super::Win32::Foundation::UNICODE_STRING,
@@ -22414,7 +29413,8 @@ impl Default for PCW_COUNTER_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCW_DATA {
     pub Data: *const core::ffi::c_void,
     pub Size: u32,
@@ -22429,7 +29429,8 @@ impl Default for PCW_DATA {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Win32_System_Kernel"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCW_MASK_INFORMATION {
     pub CounterMask: u64,
     pub InstanceMask: *mut super::super::super::Win32::Foundation::UNICODE_STRING,
@@ -22449,7 +29450,8 @@ impl Default for PCW_MASK_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCW_REGISTRATION_INFORMATION {
     pub Version: u32,
     pub Name: *mut super::super::super::Win32::Foundation::UNICODE_STRING,
@@ -22468,7 +29470,8 @@ impl Default for PCW_REGISTRATION_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PHYSICAL_COUNTER_EVENT_BUFFER_CONFIGURATION {
     pub OverflowHandler: PPHYSICAL_COUNTER_EVENT_BUFFER_OVERFLOW_HANDLER,
     pub CustomEventBufferEntrySize: u32,
@@ -22515,7 +29518,8 @@ impl Default for PHYSICAL_COUNTER_RESOURCE_DESCRIPTOR_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PHYSICAL_COUNTER_RESOURCE_DESCRIPTOR_0_0 {
     pub Begin: u32,
     pub End: u32,
@@ -22543,7 +29547,8 @@ impl Default for PHYSICAL_COUNTER_RESOURCE_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PHYSICAL_MEMORY_RANGE {
     pub BaseAddress: i64,
     pub NumberOfBytes: i64,
@@ -22557,7 +29562,8 @@ impl Default for PHYSICAL_MEMORY_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PLUGPLAY_NOTIFICATION_HEADER {
     pub Version: u16,
     pub Size: u16,
@@ -22572,7 +29578,8 @@ impl Default for PLUGPLAY_NOTIFICATION_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PM_DISPATCH_TABLE {
     pub Signature: u32,
     pub Version: u32,
@@ -22587,7 +29594,8 @@ impl Default for PM_DISPATCH_TABLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PNP_BUS_INFORMATION {
     pub BusTypeGuid: windows_core::GUID,
     pub LegacyBusType: INTERFACE_TYPE,
@@ -22602,7 +29610,8 @@ impl Default for PNP_BUS_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PNP_EXTENDED_ADDRESS_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -22620,7 +29629,8 @@ impl Default for PNP_EXTENDED_ADDRESS_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PNP_LOCATION_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -22638,7 +29648,8 @@ impl Default for PNP_LOCATION_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PNP_REPLACE_DRIVER_INTERFACE {
     pub Size: u32,
     pub Version: u32,
@@ -22663,7 +29674,8 @@ impl Default for PNP_REPLACE_DRIVER_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PNP_REPLACE_MEMORY_LIST {
     pub AllocatedCount: u32,
     pub Count: u32,
@@ -22679,7 +29691,8 @@ impl Default for PNP_REPLACE_MEMORY_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PNP_REPLACE_MEMORY_LIST_0 {
     pub Address: i64,
     pub Length: u64,
@@ -22693,7 +29706,8 @@ impl Default for PNP_REPLACE_MEMORY_LIST_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PNP_REPLACE_PARAMETERS {
     pub Size: u32,
     pub Version: u32,
@@ -22714,7 +29728,8 @@ impl Default for PNP_REPLACE_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PNP_REPLACE_PROCESSOR_LIST {
     pub Affinity: *mut usize,
     pub GroupCount: u32,
@@ -22731,7 +29746,8 @@ impl Default for PNP_REPLACE_PROCESSOR_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PNP_REPLACE_PROCESSOR_LIST_V1 {
     pub AffinityMask: usize,
     pub AllocatedCount: u32,
@@ -22747,7 +29763,8 @@ impl Default for PNP_REPLACE_PROCESSOR_LIST_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POOLED_USAGE_AND_LIMITS {
     pub PeakPagedPoolUsage: usize,
     pub PagedPoolUsage: usize,
@@ -22768,7 +29785,8 @@ impl Default for POOLED_USAGE_AND_LIMITS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POOL_CREATE_EXTENDED_PARAMS {
     pub Version: u32,
 }
@@ -22795,10 +29813,31 @@ impl Default for POOL_EXTENDED_PARAMETER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POOL_EXTENDED_PARAMETER_0 {
     pub _bitfield: u64,
 }
+impl POOL_EXTENDED_PARAMETER_0 {
+    pub const fn Type(&self) -> u64 {
+        self._bitfield & 255
+    }
+    pub fn set_Type(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn Optional(&self) -> u64 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_Optional(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 9) & 36028797018963967
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(36028797018963967 << 9)) | ((value & 36028797018963967) << 9);
+    }
+}
 impl windows_core::TypeKind for POOL_EXTENDED_PARAMETER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -22825,7 +29864,8 @@ impl Default for POOL_EXTENDED_PARAMETER_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POOL_EXTENDED_PARAMS_SECURE_POOL {
     pub SecurePoolHandle: super::super::super::Win32::Foundation::HANDLE,
     pub Buffer: *mut core::ffi::c_void,
@@ -22841,7 +29881,8 @@ impl Default for POOL_EXTENDED_PARAMS_SECURE_POOL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POWER_MONITOR_INVOCATION {
     pub Console: super::super::super::Win32::Foundation::BOOLEAN,
     pub RequestReason: POWER_MONITOR_REQUEST_REASON,
@@ -22855,7 +29896,8 @@ impl Default for POWER_MONITOR_INVOCATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POWER_PLATFORM_INFORMATION {
     pub AoAc: super::super::super::Win32::Foundation::BOOLEAN,
 }
@@ -22868,7 +29910,8 @@ impl Default for POWER_PLATFORM_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POWER_SEQUENCE {
     pub SequenceD1: u32,
     pub SequenceD2: u32,
@@ -22883,7 +29926,8 @@ impl Default for POWER_SEQUENCE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POWER_SESSION_CONNECT {
     pub Connected: super::super::super::Win32::Foundation::BOOLEAN,
     pub Console: super::super::super::Win32::Foundation::BOOLEAN,
@@ -22897,7 +29941,8 @@ impl Default for POWER_SESSION_CONNECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POWER_SESSION_RIT_STATE {
     pub Active: super::super::super::Win32::Foundation::BOOLEAN,
     pub LastInputTime: u64,
@@ -22911,7 +29956,8 @@ impl Default for POWER_SESSION_RIT_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POWER_SESSION_TIMEOUTS {
     pub InputTimeout: u32,
     pub DisplayTimeout: u32,
@@ -22925,7 +29971,8 @@ impl Default for POWER_SESSION_TIMEOUTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POWER_SESSION_WINLOGON {
     pub SessionId: u32,
     pub Console: super::super::super::Win32::Foundation::BOOLEAN,
@@ -22957,7 +30004,8 @@ impl Default for POWER_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POWER_THROTTLING_PROCESS_STATE {
     pub Version: u32,
     pub ControlMask: u32,
@@ -22972,7 +30020,8 @@ impl Default for POWER_THROTTLING_PROCESS_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POWER_THROTTLING_THREAD_STATE {
     pub Version: u32,
     pub ControlMask: u32,
@@ -22987,7 +30036,8 @@ impl Default for POWER_THROTTLING_THREAD_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PO_FX_COMPONENT_IDLE_STATE {
     pub TransitionLatency: u64,
     pub ResidencyRequirement: u64,
@@ -23047,7 +30097,8 @@ impl Default for PO_FX_COMPONENT_PERF_SET_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PO_FX_COMPONENT_PERF_SET_0_0 {
     pub Count: u32,
     pub States: *mut PO_FX_PERF_STATE,
@@ -23061,7 +30112,8 @@ impl Default for PO_FX_COMPONENT_PERF_SET_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PO_FX_COMPONENT_PERF_SET_0_1 {
     pub Minimum: u64,
     pub Maximum: u64,
@@ -23075,7 +30127,8 @@ impl Default for PO_FX_COMPONENT_PERF_SET_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PO_FX_COMPONENT_V1 {
     pub Id: windows_core::GUID,
     pub IdleStateCount: u32,
@@ -23091,7 +30144,8 @@ impl Default for PO_FX_COMPONENT_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PO_FX_COMPONENT_V2 {
     pub Id: windows_core::GUID,
     pub Flags: u64,
@@ -23110,7 +30164,8 @@ impl Default for PO_FX_COMPONENT_V2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PO_FX_DEVICE_V1 {
     pub Version: u32,
     pub ComponentCount: u32,
@@ -23132,7 +30187,8 @@ impl Default for PO_FX_DEVICE_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PO_FX_DEVICE_V2 {
     pub Version: u32,
     pub Flags: u64,
@@ -23155,7 +30211,8 @@ impl Default for PO_FX_DEVICE_V2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PO_FX_DEVICE_V3 {
     pub Version: u32,
     pub Flags: u64,
@@ -23181,7 +30238,8 @@ impl Default for PO_FX_DEVICE_V3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PO_FX_PERF_STATE {
     pub Value: u64,
     pub Context: *mut core::ffi::c_void,
@@ -23223,7 +30281,8 @@ impl Default for PO_FX_PERF_STATE_CHANGE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_ACCESS_TOKEN {
     pub Token: super::super::super::Win32::Foundation::HANDLE,
     pub Thread: super::super::super::Win32::Foundation::HANDLE,
@@ -23264,7 +30323,8 @@ impl Default for PROCESS_DEVICEMAP_INFORMATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_DEVICEMAP_INFORMATION_0_0 {
     pub DriveMap: u32,
     pub DriveType: [u8; 32],
@@ -23278,7 +30338,8 @@ impl Default for PROCESS_DEVICEMAP_INFORMATION_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_DEVICEMAP_INFORMATION_0_1 {
     pub DirectoryHandle: super::super::super::Win32::Foundation::HANDLE,
 }
@@ -23319,7 +30380,8 @@ impl Default for PROCESS_DEVICEMAP_INFORMATION_EX_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_DEVICEMAP_INFORMATION_EX_0_0 {
     pub DriveMap: u32,
     pub DriveType: [u8; 32],
@@ -23333,7 +30395,8 @@ impl Default for PROCESS_DEVICEMAP_INFORMATION_EX_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_DEVICEMAP_INFORMATION_EX_0_1 {
     pub DirectoryHandle: super::super::super::Win32::Foundation::HANDLE,
 }
@@ -23346,7 +30409,8 @@ impl Default for PROCESS_DEVICEMAP_INFORMATION_EX_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_EXCEPTION_PORT {
     pub ExceptionPortHandle: super::super::super::Win32::Foundation::HANDLE,
     pub StateFlags: u32,
@@ -23396,11 +30460,75 @@ impl Default for PROCESS_EXTENDED_BASIC_INFORMATION_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Kernel", feature = "Win32_System_Threading"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_EXTENDED_BASIC_INFORMATION_0_0 {
     pub _bitfield: u32,
 }
 #[cfg(all(feature = "Win32_System_Kernel", feature = "Win32_System_Threading"))]
+impl PROCESS_EXTENDED_BASIC_INFORMATION_0_0 {
+    pub const fn IsProtectedProcess(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_IsProtectedProcess(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn IsWow64Process(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_IsWow64Process(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn IsProcessDeleting(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_IsProcessDeleting(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn IsCrossSessionCreate(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_IsCrossSessionCreate(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn IsFrozen(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_IsFrozen(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn IsBackground(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_IsBackground(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn IsStronglyNamed(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_IsStronglyNamed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn IsSecureProcess(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_IsSecureProcess(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn IsSubsystemProcess(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_IsSubsystemProcess(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn SpareBits(&self) -> u32 {
+        (self._bitfield >> 9) & 8388607
+    }
+    pub fn set_SpareBits(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8388607 << 9)) | ((value & 8388607) << 9);
+    }
+}
+#[cfg(all(feature = "Win32_System_Kernel", feature = "Win32_System_Threading"))]
 impl windows_core::TypeKind for PROCESS_EXTENDED_BASIC_INFORMATION_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -23411,7 +30539,8 @@ impl Default for PROCESS_EXTENDED_BASIC_INFORMATION_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_HANDLE_TRACING_ENABLE {
     pub Flags: u32,
 }
@@ -23424,7 +30553,8 @@ impl Default for PROCESS_HANDLE_TRACING_ENABLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_HANDLE_TRACING_ENABLE_EX {
     pub Flags: u32,
     pub TotalSlots: u32,
@@ -23439,7 +30569,8 @@ impl Default for PROCESS_HANDLE_TRACING_ENABLE_EX {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_WindowsProgramming")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_HANDLE_TRACING_ENTRY {
     pub Handle: super::super::super::Win32::Foundation::HANDLE,
     pub ClientId: super::super::super::Win32::System::WindowsProgramming::CLIENT_ID,
@@ -23458,7 +30589,8 @@ impl Default for PROCESS_HANDLE_TRACING_ENTRY {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_WindowsProgramming")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_HANDLE_TRACING_QUERY {
     pub Handle: super::super::super::Win32::Foundation::HANDLE,
     pub TotalTraces: u32,
@@ -23475,7 +30607,8 @@ impl Default for PROCESS_HANDLE_TRACING_QUERY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_KEEPALIVE_COUNT_INFORMATION {
     pub WakeCount: u32,
     pub NoWakeCount: u32,
@@ -23489,7 +30622,8 @@ impl Default for PROCESS_KEEPALIVE_COUNT_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MEMBERSHIP_INFORMATION {
     pub ServerSiloId: u32,
 }
@@ -23502,7 +30636,8 @@ impl Default for PROCESS_MEMBERSHIP_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_REVOKE_FILE_HANDLES_INFORMATION {
     pub TargetDevicePath: super::super::super::Win32::Foundation::UNICODE_STRING,
 }
@@ -23515,7 +30650,8 @@ impl Default for PROCESS_REVOKE_FILE_HANDLES_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_SESSION_INFORMATION {
     pub SessionId: u32,
 }
@@ -23528,7 +30664,8 @@ impl Default for PROCESS_SESSION_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_SYSCALL_PROVIDER_INFORMATION {
     pub ProviderId: windows_core::GUID,
     pub Level: u8,
@@ -23542,7 +30679,8 @@ impl Default for PROCESS_SYSCALL_PROVIDER_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_WS_WATCH_INFORMATION {
     pub FaultingPc: *mut core::ffi::c_void,
     pub FaultingVa: *mut core::ffi::c_void,
@@ -23597,11 +30735,33 @@ impl Default for PS_CREATE_NOTIFY_INFO_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power", feature = "Win32_System_WindowsProgramming"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PS_CREATE_NOTIFY_INFO_0_0 {
     pub _bitfield: u32,
 }
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power", feature = "Win32_System_WindowsProgramming"))]
+impl PS_CREATE_NOTIFY_INFO_0_0 {
+    pub const fn FileOpenNameAvailable(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_FileOpenNameAvailable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn IsSubsystemProcess(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_IsSubsystemProcess(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
+#[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power", feature = "Win32_System_WindowsProgramming"))]
 impl windows_core::TypeKind for PS_CREATE_NOTIFY_INFO_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -23612,7 +30772,8 @@ impl Default for PS_CREATE_NOTIFY_INFO_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PTM_CONTROL_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -23633,7 +30794,8 @@ impl Default for PTM_CONTROL_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REENUMERATE_SELF_INTERFACE_STANDARD {
     pub Size: u16,
     pub Version: u16,
@@ -23651,7 +30813,8 @@ impl Default for REENUMERATE_SELF_INTERFACE_STANDARD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_CALLBACK_CONTEXT_CLEANUP_INFORMATION {
     pub Object: *mut core::ffi::c_void,
     pub ObjectContext: *mut core::ffi::c_void,
@@ -23666,7 +30829,8 @@ impl Default for REG_CALLBACK_CONTEXT_CLEANUP_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_CREATE_KEY_INFORMATION {
     pub CompleteName: *mut super::super::super::Win32::Foundation::UNICODE_STRING,
     pub RootObject: *mut core::ffi::c_void,
@@ -23693,7 +30857,8 @@ impl Default for REG_CREATE_KEY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_CREATE_KEY_INFORMATION_V1 {
     pub CompleteName: *mut super::super::super::Win32::Foundation::UNICODE_STRING,
     pub RootObject: *mut core::ffi::c_void,
@@ -23724,7 +30889,8 @@ impl Default for REG_CREATE_KEY_INFORMATION_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_DELETE_KEY_INFORMATION {
     pub Object: *mut core::ffi::c_void,
     pub CallContext: *mut core::ffi::c_void,
@@ -23740,7 +30906,8 @@ impl Default for REG_DELETE_KEY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_DELETE_VALUE_KEY_INFORMATION {
     pub Object: *mut core::ffi::c_void,
     pub ValueName: *mut super::super::super::Win32::Foundation::UNICODE_STRING,
@@ -23757,7 +30924,8 @@ impl Default for REG_DELETE_VALUE_KEY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_KEY_HANDLE_CLOSE_INFORMATION {
     pub Object: *mut core::ffi::c_void,
     pub CallContext: *mut core::ffi::c_void,
@@ -23773,7 +30941,8 @@ impl Default for REG_KEY_HANDLE_CLOSE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_LOAD_KEY_INFORMATION {
     pub Object: *mut core::ffi::c_void,
     pub KeyName: *mut super::super::super::Win32::Foundation::UNICODE_STRING,
@@ -23796,7 +30965,8 @@ impl Default for REG_LOAD_KEY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_LOAD_KEY_INFORMATION_V2 {
     pub Object: *mut core::ffi::c_void,
     pub KeyName: *mut super::super::super::Win32::Foundation::UNICODE_STRING,
@@ -23820,7 +30990,8 @@ impl Default for REG_LOAD_KEY_INFORMATION_V2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_POST_CREATE_KEY_INFORMATION {
     pub CompleteName: *mut super::super::super::Win32::Foundation::UNICODE_STRING,
     pub Object: *mut core::ffi::c_void,
@@ -23835,7 +31006,8 @@ impl Default for REG_POST_CREATE_KEY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_POST_OPERATION_INFORMATION {
     pub Object: *mut core::ffi::c_void,
     pub Status: super::super::super::Win32::Foundation::NTSTATUS,
@@ -23854,7 +31026,8 @@ impl Default for REG_POST_OPERATION_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_PRE_CREATE_KEY_INFORMATION {
     pub CompleteName: *mut super::super::super::Win32::Foundation::UNICODE_STRING,
 }
@@ -23868,7 +31041,8 @@ impl Default for REG_PRE_CREATE_KEY_INFORMATION {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_QUERY_KEY_NAME {
     pub Object: *mut core::ffi::c_void,
     pub ObjectNameInfo: *mut super::super::Foundation::OBJECT_NAME_INFORMATION,
@@ -23890,7 +31064,8 @@ impl Default for REG_QUERY_KEY_NAME {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Security")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_QUERY_KEY_SECURITY_INFORMATION {
     pub Object: *mut core::ffi::c_void,
     pub SecurityInformation: *mut u32,
@@ -23911,7 +31086,8 @@ impl Default for REG_QUERY_KEY_SECURITY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_RENAME_KEY_INFORMATION {
     pub Object: *mut core::ffi::c_void,
     pub NewName: *mut super::super::super::Win32::Foundation::UNICODE_STRING,
@@ -23928,7 +31104,8 @@ impl Default for REG_RENAME_KEY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_REPLACE_KEY_INFORMATION {
     pub Object: *mut core::ffi::c_void,
     pub OldFileName: *mut super::super::super::Win32::Foundation::UNICODE_STRING,
@@ -23946,7 +31123,8 @@ impl Default for REG_REPLACE_KEY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_RESTORE_KEY_INFORMATION {
     pub Object: *mut core::ffi::c_void,
     pub FileHandle: super::super::super::Win32::Foundation::HANDLE,
@@ -23964,7 +31142,8 @@ impl Default for REG_RESTORE_KEY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_SAVE_KEY_INFORMATION {
     pub Object: *mut core::ffi::c_void,
     pub FileHandle: super::super::super::Win32::Foundation::HANDLE,
@@ -23982,7 +31161,8 @@ impl Default for REG_SAVE_KEY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_SAVE_MERGED_KEY_INFORMATION {
     pub Object: *mut core::ffi::c_void,
     pub FileHandle: super::super::super::Win32::Foundation::HANDLE,
@@ -24002,7 +31182,8 @@ impl Default for REG_SAVE_MERGED_KEY_INFORMATION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Security")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_SET_KEY_SECURITY_INFORMATION {
     pub Object: *mut core::ffi::c_void,
     pub SecurityInformation: *mut u32,
@@ -24022,7 +31203,8 @@ impl Default for REG_SET_KEY_SECURITY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_SET_VALUE_KEY_INFORMATION {
     pub Object: *mut core::ffi::c_void,
     pub ValueName: *mut super::super::super::Win32::Foundation::UNICODE_STRING,
@@ -24043,7 +31225,8 @@ impl Default for REG_SET_VALUE_KEY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_UNLOAD_KEY_INFORMATION {
     pub Object: *mut core::ffi::c_void,
     pub UserEvent: *mut core::ffi::c_void,
@@ -24061,7 +31244,8 @@ impl Default for REG_UNLOAD_KEY_INFORMATION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESOURCE_HASH_ENTRY {
     pub ListEntry: super::super::super::Win32::System::Kernel::LIST_ENTRY,
     pub Address: *mut core::ffi::c_void,
@@ -24080,7 +31264,8 @@ impl Default for RESOURCE_HASH_ENTRY {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESOURCE_PERFORMANCE_DATA {
     pub ActiveResourceCount: u32,
     pub TotalResourceCount: u32,
@@ -24105,7 +31290,8 @@ impl Default for RESOURCE_PERFORMANCE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RTL_AVL_TABLE {
     pub BalancedRoot: RTL_BALANCED_LINKS,
     pub OrderedPointer: *mut core::ffi::c_void,
@@ -24128,7 +31314,8 @@ impl Default for RTL_AVL_TABLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RTL_BALANCED_LINKS {
     pub Parent: *mut RTL_BALANCED_LINKS,
     pub LeftChild: *mut RTL_BALANCED_LINKS,
@@ -24145,7 +31332,8 @@ impl Default for RTL_BALANCED_LINKS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RTL_BITMAP {
     pub SizeOfBitMap: u32,
     pub Buffer: *mut u32,
@@ -24159,7 +31347,8 @@ impl Default for RTL_BITMAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RTL_BITMAP_RUN {
     pub StartingIndex: u32,
     pub NumberOfBits: u32,
@@ -24173,7 +31362,8 @@ impl Default for RTL_BITMAP_RUN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RTL_DYNAMIC_HASH_TABLE {
     pub Flags: u32,
     pub Shift: u32,
@@ -24195,7 +31385,8 @@ impl Default for RTL_DYNAMIC_HASH_TABLE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RTL_DYNAMIC_HASH_TABLE_CONTEXT {
     pub ChainHead: *mut super::super::super::Win32::System::Kernel::LIST_ENTRY,
     pub PrevLinkage: *mut super::super::super::Win32::System::Kernel::LIST_ENTRY,
@@ -24213,7 +31404,8 @@ impl Default for RTL_DYNAMIC_HASH_TABLE_CONTEXT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RTL_DYNAMIC_HASH_TABLE_ENTRY {
     pub Linkage: super::super::super::Win32::System::Kernel::LIST_ENTRY,
     pub Signature: usize,
@@ -24265,7 +31457,8 @@ impl Default for RTL_DYNAMIC_HASH_TABLE_ENUMERATOR_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Win32_System_Kernel"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RTL_GENERIC_TABLE {
     pub TableRoot: *mut super::super::Foundation::RTL_SPLAY_LINKS,
     pub InsertOrderList: super::super::super::Win32::System::Kernel::LIST_ENTRY,
@@ -24288,7 +31481,8 @@ impl Default for RTL_GENERIC_TABLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RTL_QUERY_REGISTRY_TABLE {
     pub QueryRoutine: PRTL_QUERY_REGISTRY_ROUTINE,
     pub Flags: u32,
@@ -24307,7 +31501,8 @@ impl Default for RTL_QUERY_REGISTRY_TABLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCATTER_GATHER_ELEMENT {
     pub Address: i64,
     pub Length: u32,
@@ -24322,7 +31517,8 @@ impl Default for SCATTER_GATHER_ELEMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCATTER_GATHER_LIST {
     pub NumberOfElements: u32,
     pub Reserved: usize,
@@ -24337,7 +31533,8 @@ impl Default for SCATTER_GATHER_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SDEV_IDENTIFIER_INTERFACE {
     pub InterfaceHeader: INTERFACE,
     pub GetIdentifier: PGET_SDEV_IDENTIFIER,
@@ -24352,7 +31549,8 @@ impl Default for SDEV_IDENTIFIER_INTERFACE {
 }
 #[repr(C)]
 #[cfg(feature = "Wdk_Foundation")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SECURE_DRIVER_INTERFACE {
     pub InterfaceHeader: INTERFACE,
     pub ProcessReference: PSECURE_DRIVER_PROCESS_REFERENCE,
@@ -24370,7 +31568,8 @@ impl Default for SECURE_DRIVER_INTERFACE {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SECURITY_CONTEXT_TRACKING_MODE(pub u8);
 impl Default for SECURITY_CONTEXT_TRACKING_MODE {
     fn default() -> Self {
@@ -24381,7 +31580,8 @@ impl windows_core::TypeKind for SECURITY_CONTEXT_TRACKING_MODE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SHARE_ACCESS {
     pub OpenCount: u32,
     pub Readers: u32,
@@ -24454,7 +31654,8 @@ impl Default for SILO_MONITOR_REGISTRATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOC_SUBSYSTEM_FAILURE_DETAILS {
     pub SubsysType: SOC_SUBSYSTEM_TYPE,
     pub FirmwareVersion: u64,
@@ -24471,7 +31672,8 @@ impl Default for SOC_SUBSYSTEM_FAILURE_DETAILS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYSTEM_FIRMWARE_TABLE_HANDLER {
     pub ProviderSignature: u32,
     pub Register: super::super::super::Win32::Foundation::BOOLEAN,
@@ -24487,7 +31689,8 @@ impl Default for SYSTEM_FIRMWARE_TABLE_HANDLER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYSTEM_FIRMWARE_TABLE_INFORMATION {
     pub ProviderSignature: u32,
     pub Action: SYSTEM_FIRMWARE_TABLE_ACTION,
@@ -24531,10 +31734,67 @@ impl Default for SYSTEM_POWER_STATE_CONTEXT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYSTEM_POWER_STATE_CONTEXT_0_0 {
     pub _bitfield: u32,
 }
+impl SYSTEM_POWER_STATE_CONTEXT_0_0 {
+    pub const fn Reserved1(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn TargetSystemState(&self) -> u32 {
+        (self._bitfield >> 8) & 15
+    }
+    pub fn set_TargetSystemState(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 8)) | ((value & 15) << 8);
+    }
+    pub const fn EffectiveSystemState(&self) -> u32 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_EffectiveSystemState(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+    pub const fn CurrentSystemState(&self) -> u32 {
+        (self._bitfield >> 16) & 15
+    }
+    pub fn set_CurrentSystemState(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 16)) | ((value & 15) << 16);
+    }
+    pub const fn IgnoreHibernationPath(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_IgnoreHibernationPath(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn PseudoTransition(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_PseudoTransition(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn KernelSoftReboot(&self) -> u32 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_KernelSoftReboot(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn DirectedDripsTransition(&self) -> u32 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_DirectedDripsTransition(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for SYSTEM_POWER_STATE_CONTEXT_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -24545,7 +31805,8 @@ impl Default for SYSTEM_POWER_STATE_CONTEXT_0_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TARGET_DEVICE_REMOVAL_NOTIFICATION {
     pub Version: u16,
     pub Size: u16,
@@ -24563,7 +31824,8 @@ impl Default for TARGET_DEVICE_REMOVAL_NOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TIMER_SET_COALESCABLE_TIMER_INFO {
     pub DueTime: i64,
     pub TimerApcRoutine: PTIMER_APC_ROUTINE,
@@ -24582,7 +31844,8 @@ impl Default for TIMER_SET_COALESCABLE_TIMER_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TIME_FIELDS {
     pub Year: i16,
     pub Month: i16,
@@ -24603,7 +31866,8 @@ impl Default for TIME_FIELDS {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSLATOR_INTERFACE {
     pub Size: u16,
     pub Version: u16,
@@ -24624,7 +31888,8 @@ impl Default for TRANSLATOR_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TXN_PARAMETER_BLOCK {
     pub Length: u16,
     pub TxFsContext: u16,
@@ -24653,10 +31918,55 @@ impl Default for VIRTUAL_CHANNEL_CAPABILITIES1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIRTUAL_CHANNEL_CAPABILITIES1_0 {
     pub _bitfield: u32,
 }
+impl VIRTUAL_CHANNEL_CAPABILITIES1_0 {
+    pub const fn ExtendedVCCount(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_ExtendedVCCount(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn RsvdP1(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_RsvdP1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn LowPriorityExtendedVCCount(&self) -> u32 {
+        (self._bitfield >> 4) & 7
+    }
+    pub fn set_LowPriorityExtendedVCCount(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 4)) | ((value & 7) << 4);
+    }
+    pub const fn RsvdP2(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_RsvdP2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn ReferenceClock(&self) -> u32 {
+        (self._bitfield >> 8) & 3
+    }
+    pub fn set_ReferenceClock(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 8)) | ((value & 3) << 8);
+    }
+    pub const fn PortArbitrationTableEntrySize(&self) -> u32 {
+        (self._bitfield >> 10) & 3
+    }
+    pub fn set_PortArbitrationTableEntrySize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 10)) | ((value & 3) << 10);
+    }
+    pub const fn RsvdP3(&self) -> u32 {
+        (self._bitfield >> 12) & 1048575
+    }
+    pub fn set_RsvdP3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1048575 << 12)) | ((value & 1048575) << 12);
+    }
+}
 impl windows_core::TypeKind for VIRTUAL_CHANNEL_CAPABILITIES1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -24680,10 +31990,31 @@ impl Default for VIRTUAL_CHANNEL_CAPABILITIES2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIRTUAL_CHANNEL_CAPABILITIES2_0 {
     pub _bitfield: u32,
 }
+impl VIRTUAL_CHANNEL_CAPABILITIES2_0 {
+    pub const fn VCArbitrationCapability(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_VCArbitrationCapability(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn RsvdP(&self) -> u32 {
+        (self._bitfield >> 8) & 65535
+    }
+    pub fn set_RsvdP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 8)) | ((value & 65535) << 8);
+    }
+    pub const fn VCArbitrationTableOffset(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_VCArbitrationTableOffset(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for VIRTUAL_CHANNEL_CAPABILITIES2_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -24707,10 +32038,31 @@ impl Default for VIRTUAL_CHANNEL_CONTROL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIRTUAL_CHANNEL_CONTROL_0 {
     pub _bitfield: u16,
 }
+impl VIRTUAL_CHANNEL_CONTROL_0 {
+    pub const fn LoadVCArbitrationTable(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_LoadVCArbitrationTable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn VCArbitrationSelect(&self) -> u16 {
+        (self._bitfield >> 1) & 7
+    }
+    pub fn set_VCArbitrationSelect(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 1)) | ((value & 7) << 1);
+    }
+    pub const fn RsvdP(&self) -> u16 {
+        (self._bitfield >> 4) & 4095
+    }
+    pub fn set_RsvdP(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(4095 << 4)) | ((value & 4095) << 4);
+    }
+}
 impl windows_core::TypeKind for VIRTUAL_CHANNEL_CONTROL_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -24734,10 +32086,25 @@ impl Default for VIRTUAL_CHANNEL_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIRTUAL_CHANNEL_STATUS_0 {
     pub _bitfield: u16,
 }
+impl VIRTUAL_CHANNEL_STATUS_0 {
+    pub const fn VCArbitrationTableStatus(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_VCArbitrationTableStatus(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn RsvdZ(&self) -> u16 {
+        (self._bitfield >> 1) & 32767
+    }
+    pub fn set_RsvdZ(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(32767 << 1)) | ((value & 32767) << 1);
+    }
+}
 impl windows_core::TypeKind for VIRTUAL_CHANNEL_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -24777,10 +32144,55 @@ impl Default for VIRTUAL_RESOURCE_CAPABILITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIRTUAL_RESOURCE_CAPABILITY_0 {
     pub _bitfield: u32,
 }
+impl VIRTUAL_RESOURCE_CAPABILITY_0 {
+    pub const fn PortArbitrationCapability(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_PortArbitrationCapability(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn RsvdP1(&self) -> u32 {
+        (self._bitfield >> 8) & 63
+    }
+    pub fn set_RsvdP1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 8)) | ((value & 63) << 8);
+    }
+    pub const fn Undefined(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_Undefined(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn RejectSnoopTransactions(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_RejectSnoopTransactions(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn MaximumTimeSlots(&self) -> u32 {
+        (self._bitfield >> 16) & 127
+    }
+    pub fn set_MaximumTimeSlots(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(127 << 16)) | ((value & 127) << 16);
+    }
+    pub const fn RsvdP2(&self) -> u32 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_RsvdP2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn PortArbitrationTableOffset(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_PortArbitrationTableOffset(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for VIRTUAL_RESOURCE_CAPABILITY_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -24804,10 +32216,61 @@ impl Default for VIRTUAL_RESOURCE_CONTROL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIRTUAL_RESOURCE_CONTROL_0 {
     pub _bitfield: u32,
 }
+impl VIRTUAL_RESOURCE_CONTROL_0 {
+    pub const fn TcVcMap(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_TcVcMap(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn RsvdP1(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_RsvdP1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn LoadPortArbitrationTable(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_LoadPortArbitrationTable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn PortArbitrationSelect(&self) -> u32 {
+        (self._bitfield >> 17) & 7
+    }
+    pub fn set_PortArbitrationSelect(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 17)) | ((value & 7) << 17);
+    }
+    pub const fn RsvdP2(&self) -> u32 {
+        (self._bitfield >> 20) & 15
+    }
+    pub fn set_RsvdP2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 20)) | ((value & 15) << 20);
+    }
+    pub const fn VcID(&self) -> u32 {
+        (self._bitfield >> 24) & 7
+    }
+    pub fn set_VcID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 24)) | ((value & 7) << 24);
+    }
+    pub const fn RsvdP3(&self) -> u32 {
+        (self._bitfield >> 27) & 15
+    }
+    pub fn set_RsvdP3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 27)) | ((value & 15) << 27);
+    }
+    pub const fn VcEnable(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_VcEnable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for VIRTUAL_RESOURCE_CONTROL_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -24831,10 +32294,31 @@ impl Default for VIRTUAL_RESOURCE_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIRTUAL_RESOURCE_STATUS_0 {
     pub _bitfield: u16,
 }
+impl VIRTUAL_RESOURCE_STATUS_0 {
+    pub const fn PortArbitrationTableStatus(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_PortArbitrationTableStatus(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn VcNegotiationPending(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_VcNegotiationPending(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn RsvdZ(&self) -> u16 {
+        (self._bitfield >> 2) & 16383
+    }
+    pub fn set_RsvdZ(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(16383 << 2)) | ((value & 16383) << 2);
+    }
+}
 impl windows_core::TypeKind for VIRTUAL_RESOURCE_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -24844,7 +32328,8 @@ impl Default for VIRTUAL_RESOURCE_STATUS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VM_COUNTERS {
     pub PeakVirtualSize: usize,
     pub VirtualSize: usize,
@@ -24867,7 +32352,8 @@ impl Default for VM_COUNTERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VM_COUNTERS_EX {
     pub PeakVirtualSize: usize,
     pub VirtualSize: usize,
@@ -24891,7 +32377,8 @@ impl Default for VM_COUNTERS_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VM_COUNTERS_EX2 {
     pub CountersEx: VM_COUNTERS_EX,
     pub PrivateWorkingSetSize: usize,
@@ -24946,13 +32433,47 @@ impl Default for WAIT_CONTEXT_BLOCK_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WAIT_CONTEXT_BLOCK_0_0 {
     pub DmaWaitEntry: super::super::super::Win32::System::Kernel::LIST_ENTRY,
     pub NumberOfChannels: u32,
     pub _bitfield: u32,
 }
 #[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
+impl WAIT_CONTEXT_BLOCK_0_0 {
+    pub const fn SyncCallback(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_SyncCallback(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DmaContext(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DmaContext(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ZeroMapRegisters(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ZeroMapRegisters(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 511
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(511 << 3)) | ((value & 511) << 3);
+    }
+    pub const fn NumberOfRemapPages(&self) -> u32 {
+        (self._bitfield >> 12) & 1048575
+    }
+    pub fn set_NumberOfRemapPages(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1048575 << 12)) | ((value & 1048575) << 12);
+    }
+}
+#[cfg(all(feature = "Wdk_Foundation", feature = "Wdk_Storage_FileSystem", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_Kernel", feature = "Win32_System_Power"))]
 impl windows_core::TypeKind for WAIT_CONTEXT_BLOCK_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -24963,7 +32484,8 @@ impl Default for WAIT_CONTEXT_BLOCK_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA128A {
     pub Low: u64,
     pub High: i64,
@@ -25922,6 +33444,86 @@ pub struct WHEA_ARM_BUS_ERROR {
     pub _bitfield5: u8,
     pub _bitfield6: u32,
 }
+impl WHEA_ARM_BUS_ERROR {
+    pub const fn TransactionType(&self) -> u8 {
+        self._bitfield1 & 3
+    }
+    pub fn set_TransactionType(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !3) | (value & 3);
+    }
+    pub const fn Operation(&self) -> u8 {
+        (self._bitfield1 >> 2) & 15
+    }
+    pub fn set_Operation(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !(15 << 2)) | ((value & 15) << 2);
+    }
+    pub const fn Level(&self) -> u8 {
+        self._bitfield2 & 7
+    }
+    pub fn set_Level(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !7) | (value & 7);
+    }
+    pub const fn ProcessorContextCorrupt(&self) -> u8 {
+        (self._bitfield2 >> 3) & 1
+    }
+    pub fn set_ProcessorContextCorrupt(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Corrected(&self) -> u8 {
+        (self._bitfield2 >> 4) & 1
+    }
+    pub fn set_Corrected(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PrecisePC(&self) -> u8 {
+        (self._bitfield2 >> 5) & 1
+    }
+    pub fn set_PrecisePC(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn RestartablePC(&self) -> u8 {
+        (self._bitfield2 >> 6) & 1
+    }
+    pub fn set_RestartablePC(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn ParticipationType(&self) -> u8 {
+        self._bitfield3 & 3
+    }
+    pub fn set_ParticipationType(&mut self, value: u8) {
+        self._bitfield3 = (self._bitfield3 & !3) | (value & 3);
+    }
+    pub const fn TimeOut(&self) -> u8 {
+        (self._bitfield3 >> 2) & 1
+    }
+    pub fn set_TimeOut(&mut self, value: u8) {
+        self._bitfield3 = (self._bitfield3 & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AddressSpace(&self) -> u8 {
+        (self._bitfield3 >> 3) & 3
+    }
+    pub fn set_AddressSpace(&mut self, value: u8) {
+        self._bitfield3 = (self._bitfield3 & !(3 << 3)) | ((value & 3) << 3);
+    }
+    pub const fn MemoryAccessAttributes(&self) -> u16 {
+        self._bitfield4 & 511
+    }
+    pub fn set_MemoryAccessAttributes(&mut self, value: u16) {
+        self._bitfield4 = (self._bitfield4 & !511) | (value & 511);
+    }
+    pub const fn AccessMode(&self) -> u8 {
+        self._bitfield5 & 1
+    }
+    pub fn set_AccessMode(&mut self, value: u8) {
+        self._bitfield5 = (self._bitfield5 & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield6 & 1048575
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield6 = (self._bitfield6 & !1048575) | (value & 1048575);
+    }
+}
 impl windows_core::TypeKind for WHEA_ARM_BUS_ERROR {
     type TypeKind = windows_core::CopyType;
 }
@@ -25949,6 +33551,86 @@ impl Default for WHEA_ARM_BUS_ERROR_VALID_BITS {
 pub struct WHEA_ARM_BUS_ERROR_VALID_BITS_0 {
     pub _bitfield: u16,
 }
+impl WHEA_ARM_BUS_ERROR_VALID_BITS_0 {
+    pub const fn TransactionType(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_TransactionType(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Operation(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Operation(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Level(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Level(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ProcessorContextCorrupt(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ProcessorContextCorrupt(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Corrected(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Corrected(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PrecisePC(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_PrecisePC(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn RestartablePC(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_RestartablePC(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn ParticipationType(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_ParticipationType(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Timeout(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_Timeout(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn AddressSpace(&self) -> u16 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_AddressSpace(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn MemoryAttributes(&self) -> u16 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_MemoryAttributes(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn AccessMode(&self) -> u16 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_AccessMode(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+}
 impl windows_core::TypeKind for WHEA_ARM_BUS_ERROR_VALID_BITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -25965,6 +33647,56 @@ pub struct WHEA_ARM_CACHE_ERROR {
     pub _bitfield2: u8,
     pub _bitfield3: u64,
 }
+impl WHEA_ARM_CACHE_ERROR {
+    pub const fn TransactionType(&self) -> u8 {
+        self._bitfield1 & 3
+    }
+    pub fn set_TransactionType(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !3) | (value & 3);
+    }
+    pub const fn Operation(&self) -> u8 {
+        (self._bitfield1 >> 2) & 15
+    }
+    pub fn set_Operation(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !(15 << 2)) | ((value & 15) << 2);
+    }
+    pub const fn Level(&self) -> u8 {
+        self._bitfield2 & 7
+    }
+    pub fn set_Level(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !7) | (value & 7);
+    }
+    pub const fn ProcessorContextCorrupt(&self) -> u8 {
+        (self._bitfield2 >> 3) & 1
+    }
+    pub fn set_ProcessorContextCorrupt(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Corrected(&self) -> u8 {
+        (self._bitfield2 >> 4) & 1
+    }
+    pub fn set_Corrected(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PrecisePC(&self) -> u8 {
+        (self._bitfield2 >> 5) & 1
+    }
+    pub fn set_PrecisePC(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn RestartablePC(&self) -> u8 {
+        (self._bitfield2 >> 6) & 1
+    }
+    pub fn set_RestartablePC(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        self._bitfield3 & 34359738367
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield3 = (self._bitfield3 & !34359738367) | (value & 34359738367);
+    }
+}
 impl windows_core::TypeKind for WHEA_ARM_CACHE_ERROR {
     type TypeKind = windows_core::CopyType;
 }
@@ -25992,6 +33724,56 @@ impl Default for WHEA_ARM_CACHE_ERROR_VALID_BITS {
 pub struct WHEA_ARM_CACHE_ERROR_VALID_BITS_0 {
     pub _bitfield: u16,
 }
+impl WHEA_ARM_CACHE_ERROR_VALID_BITS_0 {
+    pub const fn TransactionType(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_TransactionType(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Operation(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Operation(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Level(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Level(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ProcessorContextCorrupt(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ProcessorContextCorrupt(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Corrected(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Corrected(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PrecisePC(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_PrecisePC(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn RestartablePC(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_RestartablePC(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 7) & 511
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(511 << 7)) | ((value & 511) << 7);
+    }
+}
 impl windows_core::TypeKind for WHEA_ARM_CACHE_ERROR_VALID_BITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -26065,6 +33847,32 @@ impl Default for WHEA_ARM_PROCESSOR_ERROR_CONTEXT_INFORMATION_HEADER_FLAGS {
 pub struct WHEA_ARM_PROCESSOR_ERROR_CONTEXT_INFORMATION_HEADER_FLAGS_0 {
     pub _bitfield: u32,
 }
+impl WHEA_ARM_PROCESSOR_ERROR_CONTEXT_INFORMATION_HEADER_FLAGS_0 {
+    pub const fn ExceptionLevel(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ExceptionLevel(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NonSecure(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NonSecure(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn AArch64(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_AArch64(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for WHEA_ARM_PROCESSOR_ERROR_CONTEXT_INFORMATION_HEADER_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -26113,6 +33921,44 @@ impl Default for WHEA_ARM_PROCESSOR_ERROR_INFORMATION_VALID_BITS {
 pub struct WHEA_ARM_PROCESSOR_ERROR_INFORMATION_VALID_BITS_0 {
     pub _bitfield: u16,
 }
+impl WHEA_ARM_PROCESSOR_ERROR_INFORMATION_VALID_BITS_0 {
+    pub const fn MultipleError(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_MultipleError(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Flags(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Flags(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ErrorInformation(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ErrorInformation(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn VirtualFaultAddress(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_VirtualFaultAddress(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn PhysicalFaultAddress(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_PhysicalFaultAddress(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 5) & 2047
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(2047 << 5)) | ((value & 2047) << 5);
+    }
+}
 impl windows_core::TypeKind for WHEA_ARM_PROCESSOR_ERROR_INFORMATION_VALID_BITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -26163,6 +34009,38 @@ impl Default for WHEA_ARM_PROCESSOR_ERROR_SECTION_VALID_BITS {
 pub struct WHEA_ARM_PROCESSOR_ERROR_SECTION_VALID_BITS_0 {
     pub _bitfield: u32,
 }
+impl WHEA_ARM_PROCESSOR_ERROR_SECTION_VALID_BITS_0 {
+    pub const fn MPIDR(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_MPIDR(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn AffinityLevel(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_AffinityLevel(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn RunningState(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_RunningState(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn VendorSpecificInfo(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_VendorSpecificInfo(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for WHEA_ARM_PROCESSOR_ERROR_SECTION_VALID_BITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -26179,6 +34057,56 @@ pub struct WHEA_ARM_TLB_ERROR {
     pub _bitfield2: u8,
     pub _bitfield3: u64,
 }
+impl WHEA_ARM_TLB_ERROR {
+    pub const fn TransactionType(&self) -> u8 {
+        self._bitfield1 & 3
+    }
+    pub fn set_TransactionType(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !3) | (value & 3);
+    }
+    pub const fn Operation(&self) -> u8 {
+        (self._bitfield1 >> 2) & 15
+    }
+    pub fn set_Operation(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !(15 << 2)) | ((value & 15) << 2);
+    }
+    pub const fn Level(&self) -> u8 {
+        self._bitfield2 & 7
+    }
+    pub fn set_Level(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !7) | (value & 7);
+    }
+    pub const fn ProcessorContextCorrupt(&self) -> u8 {
+        (self._bitfield2 >> 3) & 1
+    }
+    pub fn set_ProcessorContextCorrupt(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Corrected(&self) -> u8 {
+        (self._bitfield2 >> 4) & 1
+    }
+    pub fn set_Corrected(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PrecisePC(&self) -> u8 {
+        (self._bitfield2 >> 5) & 1
+    }
+    pub fn set_PrecisePC(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn RestartablePC(&self) -> u8 {
+        (self._bitfield2 >> 6) & 1
+    }
+    pub fn set_RestartablePC(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        self._bitfield3 & 68719476735
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield3 = (self._bitfield3 & !68719476735) | (value & 68719476735);
+    }
+}
 impl windows_core::TypeKind for WHEA_ARM_TLB_ERROR {
     type TypeKind = windows_core::CopyType;
 }
@@ -26206,6 +34134,56 @@ impl Default for WHEA_ARM_TLB_ERROR_VALID_BITS {
 pub struct WHEA_ARM_TLB_ERROR_VALID_BITS_0 {
     pub _bitfield: u16,
 }
+impl WHEA_ARM_TLB_ERROR_VALID_BITS_0 {
+    pub const fn TransactionType(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_TransactionType(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Operation(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Operation(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Level(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Level(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ProcessorContextCorrupt(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ProcessorContextCorrupt(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Corrected(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Corrected(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PrecisePC(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_PrecisePC(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn RestartablePC(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_RestartablePC(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 7) & 511
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(511 << 7)) | ((value & 511) << 7);
+    }
+}
 impl windows_core::TypeKind for WHEA_ARM_TLB_ERROR_VALID_BITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -26276,10 +34254,121 @@ impl Default for WHEA_ERROR_INJECTION_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_ERROR_INJECTION_CAPABILITIES_0 {
     pub _bitfield: u32,
 }
+impl WHEA_ERROR_INJECTION_CAPABILITIES_0 {
+    pub const fn ProcessorCorrectable(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ProcessorCorrectable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ProcessorUncorrectableNonFatal(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ProcessorUncorrectableNonFatal(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ProcessorUncorrectableFatal(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ProcessorUncorrectableFatal(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn MemoryCorrectable(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_MemoryCorrectable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn MemoryUncorrectableNonFatal(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_MemoryUncorrectableNonFatal(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn MemoryUncorrectableFatal(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_MemoryUncorrectableFatal(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn PCIExpressCorrectable(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_PCIExpressCorrectable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn PCIExpressUncorrectableNonFatal(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_PCIExpressUncorrectableNonFatal(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn PCIExpressUncorrectableFatal(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_PCIExpressUncorrectableFatal(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn PlatformCorrectable(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_PlatformCorrectable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn PlatformUncorrectableNonFatal(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_PlatformUncorrectableNonFatal(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn PlatformUncorrectableFatal(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_PlatformUncorrectableFatal(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn IA64Corrected(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_IA64Corrected(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn IA64Recoverable(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_IA64Recoverable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn IA64Fatal(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_IA64Fatal(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn IA64RecoverableCache(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_IA64RecoverableCache(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn IA64RecoverableRegFile(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_IA64RecoverableRegFile(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 17) & 32767
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(32767 << 17)) | ((value & 32767) << 17);
+    }
+}
 impl windows_core::TypeKind for WHEA_ERROR_INJECTION_CAPABILITIES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -26307,6 +34396,68 @@ impl Default for WHEA_ERROR_PACKET_FLAGS {
 pub struct WHEA_ERROR_PACKET_FLAGS_0 {
     pub _bitfield: u32,
 }
+impl WHEA_ERROR_PACKET_FLAGS_0 {
+    pub const fn PreviousError(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_PreviousError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CriticalEvent(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CriticalEvent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn HypervisorError(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_HypervisorError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Simulated(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Simulated(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn PlatformPfaControl(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_PlatformPfaControl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PlatformDirectedOffline(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_PlatformDirectedOffline(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn AddressTranslationRequired(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_AddressTranslationRequired(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn AddressTranslationCompleted(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_AddressTranslationCompleted(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn RecoveryOptional(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_RecoveryOptional(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 9) & 8388607
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8388607 << 9)) | ((value & 8388607) << 9);
+    }
+}
 impl windows_core::TypeKind for WHEA_ERROR_PACKET_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -26489,6 +34640,68 @@ impl Default for WHEA_ERROR_RECORD_HEADER_FLAGS {
 pub struct WHEA_ERROR_RECORD_HEADER_FLAGS_0 {
     pub _bitfield: u32,
 }
+impl WHEA_ERROR_RECORD_HEADER_FLAGS_0 {
+    pub const fn Recovered(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Recovered(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PreviousError(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PreviousError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Simulated(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Simulated(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn DeviceDriver(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_DeviceDriver(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn CriticalEvent(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_CriticalEvent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PersistPfn(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_PersistPfn(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn SectionsTruncated(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_SectionsTruncated(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn RecoveryInProgress(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_RecoveryInProgress(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Throttle(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_Throttle(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 9) & 8388607
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8388607 << 9)) | ((value & 8388607) << 9);
+    }
+}
 impl windows_core::TypeKind for WHEA_ERROR_RECORD_HEADER_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -26516,6 +34729,32 @@ impl Default for WHEA_ERROR_RECORD_HEADER_VALIDBITS {
 pub struct WHEA_ERROR_RECORD_HEADER_VALIDBITS_0 {
     pub _bitfield: u32,
 }
+impl WHEA_ERROR_RECORD_HEADER_VALIDBITS_0 {
+    pub const fn PlatformId(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_PlatformId(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Timestamp(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Timestamp(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn PartitionId(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_PartitionId(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for WHEA_ERROR_RECORD_HEADER_VALIDBITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -26565,6 +34804,62 @@ impl Default for WHEA_ERROR_RECORD_SECTION_DESCRIPTOR_FLAGS {
 pub struct WHEA_ERROR_RECORD_SECTION_DESCRIPTOR_FLAGS_0 {
     pub _bitfield: u32,
 }
+impl WHEA_ERROR_RECORD_SECTION_DESCRIPTOR_FLAGS_0 {
+    pub const fn Primary(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Primary(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ContainmentWarning(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ContainmentWarning(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reset(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Reset(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ThresholdExceeded(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ThresholdExceeded(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ResourceNotAvailable(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_ResourceNotAvailable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn LatentError(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_LatentError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Propagated(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Propagated(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn FruTextByPlugin(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_FruTextByPlugin(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 8) & 16777215
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(16777215 << 8)) | ((value & 16777215) << 8);
+    }
+}
 impl windows_core::TypeKind for WHEA_ERROR_RECORD_SECTION_DESCRIPTOR_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -26588,10 +34883,31 @@ impl Default for WHEA_ERROR_RECORD_SECTION_DESCRIPTOR_VALIDBITS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_ERROR_RECORD_SECTION_DESCRIPTOR_VALIDBITS_0 {
     pub _bitfield: u8,
 }
+impl WHEA_ERROR_RECORD_SECTION_DESCRIPTOR_VALIDBITS_0 {
+    pub const fn FRUId(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_FRUId(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn FRUText(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_FRUText(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+}
 impl windows_core::TypeKind for WHEA_ERROR_RECORD_SECTION_DESCRIPTOR_VALIDBITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -26620,7 +34936,8 @@ impl Default for WHEA_ERROR_RECOVERY_INFO_SECTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_ERROR_SOURCE_CONFIGURATION {
     pub Flags: u32,
     pub Correct: WHEA_ERROR_SOURCE_CORRECT,
@@ -26640,7 +34957,8 @@ impl Default for WHEA_ERROR_SOURCE_CONFIGURATION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Diagnostics_Debug")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_ERROR_SOURCE_OVERRIDE_SETTINGS {
     pub Type: super::super::super::Win32::System::Diagnostics::Debug::WHEA_ERROR_SOURCE_TYPE,
     pub MaxRawDataLength: u32,
@@ -26676,6 +34994,68 @@ impl Default for WHEA_ERROR_STATUS {
 pub struct WHEA_ERROR_STATUS_0 {
     pub _bitfield: u64,
 }
+impl WHEA_ERROR_STATUS_0 {
+    pub const fn Reserved1(&self) -> u64 {
+        self._bitfield & 255
+    }
+    pub fn set_Reserved1(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn ErrorType(&self) -> u64 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_ErrorType(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn Address(&self) -> u64 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_Address(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn Control(&self) -> u64 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_Control(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn Data(&self) -> u64 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_Data(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn Responder(&self) -> u64 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_Responder(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn Requester(&self) -> u64 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_Requester(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn FirstError(&self) -> u64 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_FirstError(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn Overflow(&self) -> u64 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_Overflow(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn Reserved2(&self) -> u64 {
+        (self._bitfield >> 23) & 2199023255551
+    }
+    pub fn set_Reserved2(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(2199023255551 << 23)) | ((value & 2199023255551) << 23);
+    }
+}
 impl windows_core::TypeKind for WHEA_ERROR_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -26730,6 +35110,56 @@ impl Default for WHEA_EVENT_LOG_ENTRY_FLAGS {
 pub struct WHEA_EVENT_LOG_ENTRY_FLAGS_0 {
     pub _bitfield: u32,
 }
+impl WHEA_EVENT_LOG_ENTRY_FLAGS_0 {
+    pub const fn Reserved1(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn LogInternalEtw(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_LogInternalEtw(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn LogBlackbox(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_LogBlackbox(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn LogSel(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_LogSel(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn RawSel(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_RawSel(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn NoFormat(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_NoFormat(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Driver(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Driver(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 7) & 33554431
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(33554431 << 7)) | ((value & 33554431) << 7);
+    }
+}
 impl windows_core::TypeKind for WHEA_EVENT_LOG_ENTRY_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -26817,11 +35247,49 @@ impl Default for WHEA_GENERIC_ERROR_BLOCKSTATUS {
     fn default() -> Self {
         unsafe { core::mem::zeroed() }
     }
-}
-#[repr(C, packed(1))]
-#[derive(Clone, Copy)]
-pub struct WHEA_GENERIC_ERROR_BLOCKSTATUS_0 {
-    pub _bitfield: u32,
+}
+#[repr(C, packed(1))]
+#[derive(Clone, Copy)]
+pub struct WHEA_GENERIC_ERROR_BLOCKSTATUS_0 {
+    pub _bitfield: u32,
+}
+impl WHEA_GENERIC_ERROR_BLOCKSTATUS_0 {
+    pub const fn UncorrectableError(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_UncorrectableError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CorrectableError(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CorrectableError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MultipleUncorrectableErrors(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_MultipleUncorrectableErrors(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn MultipleCorrectableErrors(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_MultipleCorrectableErrors(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ErrorDataEntryCount(&self) -> u32 {
+        (self._bitfield >> 4) & 1023
+    }
+    pub fn set_ErrorDataEntryCount(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1023 << 4)) | ((value & 1023) << 4);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 14) & 262143
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(262143 << 14)) | ((value & 262143) << 14);
+    }
 }
 impl windows_core::TypeKind for WHEA_GENERIC_ERROR_BLOCKSTATUS_0 {
     type TypeKind = windows_core::CopyType;
@@ -26889,10 +35357,37 @@ impl Default for WHEA_IN_USE_PAGE_NOTIFY_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_IN_USE_PAGE_NOTIFY_FLAGS_0 {
     pub _bitfield: u8,
 }
+impl WHEA_IN_USE_PAGE_NOTIFY_FLAGS_0 {
+    pub const fn PlatformDirected(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_PlatformDirected(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 1) & 31
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(31 << 1)) | ((value & 31) << 1);
+    }
+    pub const fn NotifyAllOfflines(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_NotifyAllOfflines(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn PageOfflined(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_PageOfflined(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for WHEA_IN_USE_PAGE_NOTIFY_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -26965,6 +35460,38 @@ impl Default for WHEA_MEMORY_CORRECTABLE_ERROR_SECTION_VALIDBITS {
 pub struct WHEA_MEMORY_CORRECTABLE_ERROR_SECTION_VALIDBITS_0 {
     pub _bitfield: u64,
 }
+impl WHEA_MEMORY_CORRECTABLE_ERROR_SECTION_VALIDBITS_0 {
+    pub const fn SocketId(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_SocketId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ChannelId(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ChannelId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn DimmSlot(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_DimmSlot(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn CorrectableErrorCount(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_CorrectableErrorCount(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 4) & 1152921504606846975
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1152921504606846975 << 4)) | ((value & 1152921504606846975) << 4);
+    }
+}
 impl windows_core::TypeKind for WHEA_MEMORY_CORRECTABLE_ERROR_SECTION_VALIDBITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -27024,6 +35551,146 @@ impl Default for WHEA_MEMORY_ERROR_SECTION_VALIDBITS {
 pub struct WHEA_MEMORY_ERROR_SECTION_VALIDBITS_0 {
     pub _bitfield: u64,
 }
+impl WHEA_MEMORY_ERROR_SECTION_VALIDBITS_0 {
+    pub const fn ErrorStatus(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_ErrorStatus(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PhysicalAddress(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PhysicalAddress(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn PhysicalAddressMask(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_PhysicalAddressMask(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Node(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Node(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Card(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Card(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Module(&self) -> u64 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Module(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Bank(&self) -> u64 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Bank(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Device(&self) -> u64 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Device(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Row(&self) -> u64 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_Row(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Column(&self) -> u64 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_Column(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn BitPosition(&self) -> u64 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_BitPosition(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn RequesterId(&self) -> u64 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_RequesterId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn ResponderId(&self) -> u64 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_ResponderId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn TargetId(&self) -> u64 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_TargetId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn ErrorType(&self) -> u64 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_ErrorType(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn RankNumber(&self) -> u64 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_RankNumber(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn CardHandle(&self) -> u64 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_CardHandle(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn ModuleHandle(&self) -> u64 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_ModuleHandle(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn ExtendedRow(&self) -> u64 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_ExtendedRow(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn BankGroup(&self) -> u64 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_BankGroup(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn BankAddress(&self) -> u64 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_BankAddress(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn ChipIdentification(&self) -> u64 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_ChipIdentification(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 22) & 4398046511103
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(4398046511103 << 22)) | ((value & 4398046511103) << 22);
+    }
+}
 impl windows_core::TypeKind for WHEA_MEMORY_ERROR_SECTION_VALIDBITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -27094,6 +35761,20 @@ impl Default for WHEA_NMI_ERROR_SECTION_FLAGS {
 pub struct WHEA_NMI_ERROR_SECTION_FLAGS_0 {
     pub _bitfield: u32,
 }
+impl WHEA_NMI_ERROR_SECTION_FLAGS_0 {
+    pub const fn HypervisorError(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_HypervisorError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for WHEA_NMI_ERROR_SECTION_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -27201,6 +35882,62 @@ pub struct WHEA_PCIEXPRESS_DEVICE_ID {
     pub _bitfield2: u32,
     pub _bitfield3: u32,
 }
+impl WHEA_PCIEXPRESS_DEVICE_ID {
+    pub const fn ClassCode(&self) -> u32 {
+        self._bitfield1 & 16777215
+    }
+    pub fn set_ClassCode(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !16777215) | (value & 16777215);
+    }
+    pub const fn FunctionNumber(&self) -> u32 {
+        (self._bitfield1 >> 24) & 255
+    }
+    pub fn set_FunctionNumber(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(255 << 24)) | ((value & 255) << 24);
+    }
+    pub const fn DeviceNumber(&self) -> u32 {
+        self._bitfield2 & 255
+    }
+    pub fn set_DeviceNumber(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !255) | (value & 255);
+    }
+    pub const fn Segment(&self) -> u32 {
+        (self._bitfield2 >> 8) & 65535
+    }
+    pub fn set_Segment(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(65535 << 8)) | ((value & 65535) << 8);
+    }
+    pub const fn PrimaryBusNumber(&self) -> u32 {
+        (self._bitfield2 >> 24) & 255
+    }
+    pub fn set_PrimaryBusNumber(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(255 << 24)) | ((value & 255) << 24);
+    }
+    pub const fn SecondaryBusNumber(&self) -> u32 {
+        self._bitfield3 & 255
+    }
+    pub fn set_SecondaryBusNumber(&mut self, value: u32) {
+        self._bitfield3 = (self._bitfield3 & !255) | (value & 255);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield3 >> 8) & 7
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield3 = (self._bitfield3 & !(7 << 8)) | ((value & 7) << 8);
+    }
+    pub const fn SlotNumber(&self) -> u32 {
+        (self._bitfield3 >> 11) & 8191
+    }
+    pub fn set_SlotNumber(&mut self, value: u32) {
+        self._bitfield3 = (self._bitfield3 & !(8191 << 11)) | ((value & 8191) << 11);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield3 >> 24) & 255
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield3 = (self._bitfield3 & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for WHEA_PCIEXPRESS_DEVICE_ID {
     type TypeKind = windows_core::CopyType;
 }
@@ -27250,6 +35987,62 @@ impl Default for WHEA_PCIEXPRESS_ERROR_SECTION_VALIDBITS {
 pub struct WHEA_PCIEXPRESS_ERROR_SECTION_VALIDBITS_0 {
     pub _bitfield: u64,
 }
+impl WHEA_PCIEXPRESS_ERROR_SECTION_VALIDBITS_0 {
+    pub const fn PortType(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_PortType(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Version(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Version(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn CommandStatus(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CommandStatus(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn DeviceId(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_DeviceId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn DeviceSerialNumber(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_DeviceSerialNumber(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn BridgeControlStatus(&self) -> u64 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_BridgeControlStatus(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn ExpressCapability(&self) -> u64 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_ExpressCapability(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn AerInfo(&self) -> u64 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_AerInfo(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 8) & 72057594037927935
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(72057594037927935 << 8)) | ((value & 72057594037927935) << 8);
+    }
+}
 impl windows_core::TypeKind for WHEA_PCIEXPRESS_ERROR_SECTION_VALIDBITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -27338,6 +36131,50 @@ impl Default for WHEA_PCIE_CORRECTABLE_ERROR_DEVICES_VALIDBITS {
 pub struct WHEA_PCIE_CORRECTABLE_ERROR_DEVICES_VALIDBITS_0 {
     pub _bitfield: u64,
 }
+impl WHEA_PCIE_CORRECTABLE_ERROR_DEVICES_VALIDBITS_0 {
+    pub const fn Segment(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_Segment(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Bus(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Bus(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Device(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Device(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Function(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Function(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Mask(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Mask(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn CorrectableErrorCount(&self) -> u64 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_CorrectableErrorCount(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 6) & 288230376151711743
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(288230376151711743 << 6)) | ((value & 288230376151711743) << 6);
+    }
+}
 impl windows_core::TypeKind for WHEA_PCIE_CORRECTABLE_ERROR_DEVICES_VALIDBITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -27393,6 +36230,26 @@ impl Default for WHEA_PCIXBUS_COMMAND {
 pub struct WHEA_PCIXBUS_COMMAND_0 {
     pub _bitfield: u64,
 }
+impl WHEA_PCIXBUS_COMMAND_0 {
+    pub const fn Command(&self) -> u64 {
+        self._bitfield & 72057594037927935
+    }
+    pub fn set_Command(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !72057594037927935) | (value & 72057594037927935);
+    }
+    pub const fn PCIXCommand(&self) -> u64 {
+        (self._bitfield >> 56) & 1
+    }
+    pub fn set_PCIXCommand(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 56)) | ((value & 1) << 56);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 57) & 127
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(127 << 57)) | ((value & 127) << 57);
+    }
+}
 impl windows_core::TypeKind for WHEA_PCIXBUS_COMMAND_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -27443,6 +36300,68 @@ impl Default for WHEA_PCIXBUS_ERROR_SECTION_VALIDBITS {
 pub struct WHEA_PCIXBUS_ERROR_SECTION_VALIDBITS_0 {
     pub _bitfield: u64,
 }
+impl WHEA_PCIXBUS_ERROR_SECTION_VALIDBITS_0 {
+    pub const fn ErrorStatus(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_ErrorStatus(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ErrorType(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ErrorType(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn BusId(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_BusId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn BusAddress(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_BusAddress(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn BusData(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_BusData(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn BusCommand(&self) -> u64 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_BusCommand(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn RequesterId(&self) -> u64 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_RequesterId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn CompleterId(&self) -> u64 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_CompleterId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn TargetId(&self) -> u64 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_TargetId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 9) & 36028797018963967
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(36028797018963967 << 9)) | ((value & 36028797018963967) << 9);
+    }
+}
 impl windows_core::TypeKind for WHEA_PCIXBUS_ERROR_SECTION_VALIDBITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -27466,7 +36385,8 @@ impl Default for WHEA_PCIXBUS_ID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_PCIXBUS_ID_0 {
     pub BusNumber: u8,
     pub BusSegment: u8,
@@ -27516,6 +36436,44 @@ impl Default for WHEA_PCIXDEVICE_ERROR_SECTION_VALIDBITS {
 pub struct WHEA_PCIXDEVICE_ERROR_SECTION_VALIDBITS_0 {
     pub _bitfield: u64,
 }
+impl WHEA_PCIXDEVICE_ERROR_SECTION_VALIDBITS_0 {
+    pub const fn ErrorStatus(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_ErrorStatus(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn IdInfo(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_IdInfo(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MemoryNumber(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_MemoryNumber(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn IoNumber(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_IoNumber(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn RegisterDataPairs(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_RegisterDataPairs(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 5) & 576460752303423487
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(576460752303423487 << 5)) | ((value & 576460752303423487) << 5);
+    }
+}
 impl windows_core::TypeKind for WHEA_PCIXDEVICE_ERROR_SECTION_VALIDBITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -27533,6 +36491,44 @@ pub struct WHEA_PCIXDEVICE_ID {
     pub _bitfield2: u32,
     pub Reserved2: u32,
 }
+impl WHEA_PCIXDEVICE_ID {
+    pub const fn ClassCode(&self) -> u32 {
+        self._bitfield1 & 16777215
+    }
+    pub fn set_ClassCode(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !16777215) | (value & 16777215);
+    }
+    pub const fn FunctionNumber(&self) -> u32 {
+        (self._bitfield1 >> 24) & 255
+    }
+    pub fn set_FunctionNumber(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(255 << 24)) | ((value & 255) << 24);
+    }
+    pub const fn DeviceNumber(&self) -> u32 {
+        self._bitfield2 & 255
+    }
+    pub fn set_DeviceNumber(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !255) | (value & 255);
+    }
+    pub const fn BusNumber(&self) -> u32 {
+        (self._bitfield2 >> 8) & 255
+    }
+    pub fn set_BusNumber(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn SegmentNumber(&self) -> u32 {
+        (self._bitfield2 >> 16) & 255
+    }
+    pub fn set_SegmentNumber(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(255 << 16)) | ((value & 255) << 16);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield2 >> 24) & 255
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for WHEA_PCIXDEVICE_ID {
     type TypeKind = windows_core::CopyType;
 }
@@ -27556,7 +36552,8 @@ impl Default for WHEA_PCIXDEVICE_REGISTER_PAIR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_PCI_RECOVERY_SECTION {
     pub SignalType: u8,
     pub RecoveryAttempted: super::super::super::Win32::Foundation::BOOLEAN,
@@ -27589,6 +36586,44 @@ impl Default for WHEA_PERSISTENCE_INFO {
 pub struct WHEA_PERSISTENCE_INFO_0 {
     pub _bitfield: u64,
 }
+impl WHEA_PERSISTENCE_INFO_0 {
+    pub const fn Signature(&self) -> u64 {
+        self._bitfield & 65535
+    }
+    pub fn set_Signature(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn Length(&self) -> u64 {
+        (self._bitfield >> 16) & 16777215
+    }
+    pub fn set_Length(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(16777215 << 16)) | ((value & 16777215) << 16);
+    }
+    pub const fn Identifier(&self) -> u64 {
+        (self._bitfield >> 40) & 65535
+    }
+    pub fn set_Identifier(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(65535 << 40)) | ((value & 65535) << 40);
+    }
+    pub const fn Attributes(&self) -> u64 {
+        (self._bitfield >> 56) & 3
+    }
+    pub fn set_Attributes(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(3 << 56)) | ((value & 3) << 56);
+    }
+    pub const fn DoNotLog(&self) -> u64 {
+        (self._bitfield >> 58) & 1
+    }
+    pub fn set_DoNotLog(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 58)) | ((value & 1) << 58);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 59) & 31
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(31 << 59)) | ((value & 31) << 59);
+    }
+}
 impl windows_core::TypeKind for WHEA_PERSISTENCE_INFO_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -27634,6 +36669,32 @@ impl Default for WHEA_PMEM_ERROR_SECTION_VALIDBITS {
 pub struct WHEA_PMEM_ERROR_SECTION_VALIDBITS_0 {
     pub _bitfield: u64,
 }
+impl WHEA_PMEM_ERROR_SECTION_VALIDBITS_0 {
+    pub const fn ErrorStatus(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_ErrorStatus(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NFITHandle(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NFITHandle(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn LocationInfo(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_LocationInfo(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 3) & 2305843009213693951
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(2305843009213693951 << 3)) | ((value & 2305843009213693951) << 3);
+    }
+}
 impl windows_core::TypeKind for WHEA_PMEM_ERROR_SECTION_VALIDBITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -27677,6 +36738,56 @@ pub struct WHEA_PROCESSOR_FAMILY_INFO_0 {
     pub _bitfield: u32,
     pub NativeModelId: u32,
 }
+impl WHEA_PROCESSOR_FAMILY_INFO_0 {
+    pub const fn Stepping(&self) -> u32 {
+        self._bitfield & 15
+    }
+    pub fn set_Stepping(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Model(&self) -> u32 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Model(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn Family(&self) -> u32 {
+        (self._bitfield >> 8) & 15
+    }
+    pub fn set_Family(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 8)) | ((value & 15) << 8);
+    }
+    pub const fn ProcessorType(&self) -> u32 {
+        (self._bitfield >> 12) & 3
+    }
+    pub fn set_ProcessorType(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 12)) | ((value & 3) << 12);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 14) & 3
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 14)) | ((value & 3) << 14);
+    }
+    pub const fn ExtendedModel(&self) -> u32 {
+        (self._bitfield >> 16) & 15
+    }
+    pub fn set_ExtendedModel(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 16)) | ((value & 15) << 16);
+    }
+    pub const fn ExtendedFamily(&self) -> u32 {
+        (self._bitfield >> 20) & 255
+    }
+    pub fn set_ExtendedFamily(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 20)) | ((value & 255) << 20);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 28) & 15
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 28)) | ((value & 15) << 28);
+    }
+}
 impl windows_core::TypeKind for WHEA_PROCESSOR_FAMILY_INFO_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -27725,11 +36836,103 @@ impl Default for WHEA_PROCESSOR_GENERIC_ERROR_SECTION_VALIDBITS {
     fn default() -> Self {
         unsafe { core::mem::zeroed() }
     }
-}
-#[repr(C, packed(1))]
-#[derive(Clone, Copy)]
-pub struct WHEA_PROCESSOR_GENERIC_ERROR_SECTION_VALIDBITS_0 {
-    pub _bitfield: u64,
+}
+#[repr(C, packed(1))]
+#[derive(Clone, Copy)]
+pub struct WHEA_PROCESSOR_GENERIC_ERROR_SECTION_VALIDBITS_0 {
+    pub _bitfield: u64,
+}
+impl WHEA_PROCESSOR_GENERIC_ERROR_SECTION_VALIDBITS_0 {
+    pub const fn ProcessorType(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_ProcessorType(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn InstructionSet(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_InstructionSet(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ErrorType(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ErrorType(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Operation(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Operation(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Flags(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Flags(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Level(&self) -> u64 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Level(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn CPUVersion(&self) -> u64 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_CPUVersion(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn CPUBrandString(&self) -> u64 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_CPUBrandString(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn ProcessorId(&self) -> u64 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_ProcessorId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn TargetAddress(&self) -> u64 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_TargetAddress(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn RequesterId(&self) -> u64 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_RequesterId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn ResponderId(&self) -> u64 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_ResponderId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn InstructionPointer(&self) -> u64 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_InstructionPointer(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn NativeModelId(&self) -> u64 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_NativeModelId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 14) & 1125899906842623
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1125899906842623 << 14)) | ((value & 1125899906842623) << 14);
+    }
 }
 impl windows_core::TypeKind for WHEA_PROCESSOR_GENERIC_ERROR_SECTION_VALIDBITS_0 {
     type TypeKind = windows_core::CopyType;
@@ -27769,7 +36972,8 @@ impl Default for WHEA_PSHED_PI_TRACE_EVENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Diagnostics_Debug")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_PSHED_PLUGIN_CALLBACKS {
     pub GetAllErrorSources: PSHED_PI_GET_ALL_ERROR_SOURCES,
     pub Reserved: *mut core::ffi::c_void,
@@ -27893,7 +37097,8 @@ impl Default for WHEA_PSHED_PLUGIN_PLATFORM_SUPPORT_EVENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Diagnostics_Debug")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_PSHED_PLUGIN_REGISTRATION_PACKET_V1 {
     pub Length: u32,
     pub Version: u32,
@@ -27914,7 +37119,8 @@ impl Default for WHEA_PSHED_PLUGIN_REGISTRATION_PACKET_V1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Diagnostics_Debug")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_PSHED_PLUGIN_REGISTRATION_PACKET_V2 {
     pub Length: u32,
     pub Version: u32,
@@ -27968,6 +37174,44 @@ pub struct WHEA_RECOVERY_ACTION_0 {
     pub _bitfield1: u32,
     pub _bitfield2: u32,
 }
+impl WHEA_RECOVERY_ACTION_0 {
+    pub const fn NoneAttempted(&self) -> u32 {
+        self._bitfield1 & 1
+    }
+    pub fn set_NoneAttempted(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !1) | (value & 1);
+    }
+    pub const fn TerminateProcess(&self) -> u32 {
+        (self._bitfield1 >> 1) & 1
+    }
+    pub fn set_TerminateProcess(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ForwardedToVm(&self) -> u32 {
+        (self._bitfield1 >> 2) & 1
+    }
+    pub fn set_ForwardedToVm(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn MarkPageBad(&self) -> u32 {
+        (self._bitfield1 >> 3) & 1
+    }
+    pub fn set_MarkPageBad(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn PoisonNotPresent(&self) -> u32 {
+        (self._bitfield1 >> 4) & 1
+    }
+    pub fn set_PoisonNotPresent(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield2 & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !268435455) | (value & 268435455);
+    }
+}
 impl windows_core::TypeKind for WHEA_RECOVERY_ACTION_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -28007,7 +37251,8 @@ impl Default for WHEA_RECOVERY_CONTEXT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_RECOVERY_CONTEXT_0_0 {
     pub Address: usize,
     pub Consumed: super::super::super::Win32::Foundation::BOOLEAN,
@@ -28025,7 +37270,8 @@ impl Default for WHEA_RECOVERY_CONTEXT_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_RECOVERY_CONTEXT_0_1 {
     pub PmemErrInfo: usize,
 }
@@ -28065,10 +37311,19 @@ impl Default for WHEA_REPORT_HW_ERROR_DEVICE_DRIVER_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_REPORT_HW_ERROR_DEVICE_DRIVER_FLAGS_0 {
     pub _bitfield: u32,
 }
+impl WHEA_REPORT_HW_ERROR_DEVICE_DRIVER_FLAGS_0 {
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = value;
+    }
+}
 impl windows_core::TypeKind for WHEA_REPORT_HW_ERROR_DEVICE_DRIVER_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -28092,7 +37347,8 @@ impl Default for WHEA_REVISION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_REVISION_0 {
     pub MinorRevision: u8,
     pub MajorRevision: u8,
@@ -28184,7 +37440,8 @@ impl Default for WHEA_SEL_BUGCHECK_RECOVERY_STATUS_PHASE1_EVENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_SEL_BUGCHECK_RECOVERY_STATUS_PHASE1_EVENT_0 {
     pub DumpPolicy: u8,
     pub Reserved: [u8; 3],
@@ -28393,6 +37650,62 @@ impl Default for WHEA_TIMESTAMP {
 pub struct WHEA_TIMESTAMP_0 {
     pub _bitfield: u64,
 }
+impl WHEA_TIMESTAMP_0 {
+    pub const fn Seconds(&self) -> u64 {
+        self._bitfield & 255
+    }
+    pub fn set_Seconds(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn Minutes(&self) -> u64 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_Minutes(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn Hours(&self) -> u64 {
+        (self._bitfield >> 16) & 255
+    }
+    pub fn set_Hours(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 16)) | ((value & 255) << 16);
+    }
+    pub const fn Precise(&self) -> u64 {
+        (self._bitfield >> 24) & 1
+    }
+    pub fn set_Precise(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 24)) | ((value & 1) << 24);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 25) & 127
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(127 << 25)) | ((value & 127) << 25);
+    }
+    pub const fn Day(&self) -> u64 {
+        (self._bitfield >> 32) & 255
+    }
+    pub fn set_Day(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 32)) | ((value & 255) << 32);
+    }
+    pub const fn Month(&self) -> u64 {
+        (self._bitfield >> 40) & 255
+    }
+    pub fn set_Month(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 40)) | ((value & 255) << 40);
+    }
+    pub const fn Year(&self) -> u64 {
+        (self._bitfield >> 48) & 255
+    }
+    pub fn set_Year(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 48)) | ((value & 255) << 48);
+    }
+    pub const fn Century(&self) -> u64 {
+        (self._bitfield >> 56) & 255
+    }
+    pub fn set_Century(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 56)) | ((value & 255) << 56);
+    }
+}
 impl windows_core::TypeKind for WHEA_TIMESTAMP_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -28402,7 +37715,8 @@ impl Default for WHEA_TIMESTAMP_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHEA_X64_REGISTER_STATE {
     pub Rax: u64,
     pub Rbx: u64,
@@ -28504,6 +37818,152 @@ impl Default for WHEA_XPF_BUS_CHECK {
 pub struct WHEA_XPF_BUS_CHECK_0 {
     pub _bitfield: u64,
 }
+impl WHEA_XPF_BUS_CHECK_0 {
+    pub const fn TransactionTypeValid(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_TransactionTypeValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn OperationValid(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_OperationValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn LevelValid(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_LevelValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ProcessorContextCorruptValid(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ProcessorContextCorruptValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn UncorrectedValid(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_UncorrectedValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PreciseIPValid(&self) -> u64 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_PreciseIPValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn RestartableIPValid(&self) -> u64 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_RestartableIPValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn OverflowValid(&self) -> u64 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_OverflowValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn ParticipationValid(&self) -> u64 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_ParticipationValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn TimeoutValid(&self) -> u64 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_TimeoutValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn AddressSpaceValid(&self) -> u64 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_AddressSpaceValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn ReservedValid(&self) -> u64 {
+        (self._bitfield >> 11) & 31
+    }
+    pub fn set_ReservedValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(31 << 11)) | ((value & 31) << 11);
+    }
+    pub const fn TransactionType(&self) -> u64 {
+        (self._bitfield >> 16) & 3
+    }
+    pub fn set_TransactionType(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(3 << 16)) | ((value & 3) << 16);
+    }
+    pub const fn Operation(&self) -> u64 {
+        (self._bitfield >> 18) & 15
+    }
+    pub fn set_Operation(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(15 << 18)) | ((value & 15) << 18);
+    }
+    pub const fn Level(&self) -> u64 {
+        (self._bitfield >> 22) & 7
+    }
+    pub fn set_Level(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(7 << 22)) | ((value & 7) << 22);
+    }
+    pub const fn ProcessorContextCorrupt(&self) -> u64 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_ProcessorContextCorrupt(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn Uncorrected(&self) -> u64 {
+        (self._bitfield >> 26) & 1
+    }
+    pub fn set_Uncorrected(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 26)) | ((value & 1) << 26);
+    }
+    pub const fn PreciseIP(&self) -> u64 {
+        (self._bitfield >> 27) & 1
+    }
+    pub fn set_PreciseIP(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 27)) | ((value & 1) << 27);
+    }
+    pub const fn RestartableIP(&self) -> u64 {
+        (self._bitfield >> 28) & 1
+    }
+    pub fn set_RestartableIP(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 28)) | ((value & 1) << 28);
+    }
+    pub const fn Overflow(&self) -> u64 {
+        (self._bitfield >> 29) & 1
+    }
+    pub fn set_Overflow(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 29)) | ((value & 1) << 29);
+    }
+    pub const fn Participation(&self) -> u64 {
+        (self._bitfield >> 30) & 3
+    }
+    pub fn set_Participation(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(3 << 30)) | ((value & 3) << 30);
+    }
+    pub const fn Timeout(&self) -> u64 {
+        (self._bitfield >> 32) & 1
+    }
+    pub fn set_Timeout(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 32)) | ((value & 1) << 32);
+    }
+    pub const fn AddressSpace(&self) -> u64 {
+        (self._bitfield >> 33) & 3
+    }
+    pub fn set_AddressSpace(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(3 << 33)) | ((value & 3) << 33);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 35) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(536870911 << 35)) | ((value & 536870911) << 35);
+    }
+}
 impl windows_core::TypeKind for WHEA_XPF_BUS_CHECK_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -28531,6 +37991,116 @@ impl Default for WHEA_XPF_CACHE_CHECK {
 pub struct WHEA_XPF_CACHE_CHECK_0 {
     pub _bitfield: u64,
 }
+impl WHEA_XPF_CACHE_CHECK_0 {
+    pub const fn TransactionTypeValid(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_TransactionTypeValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn OperationValid(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_OperationValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn LevelValid(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_LevelValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ProcessorContextCorruptValid(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ProcessorContextCorruptValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn UncorrectedValid(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_UncorrectedValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PreciseIPValid(&self) -> u64 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_PreciseIPValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn RestartableIPValid(&self) -> u64 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_RestartableIPValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn OverflowValid(&self) -> u64 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_OverflowValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn ReservedValid(&self) -> u64 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_ReservedValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn TransactionType(&self) -> u64 {
+        (self._bitfield >> 16) & 3
+    }
+    pub fn set_TransactionType(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(3 << 16)) | ((value & 3) << 16);
+    }
+    pub const fn Operation(&self) -> u64 {
+        (self._bitfield >> 18) & 15
+    }
+    pub fn set_Operation(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(15 << 18)) | ((value & 15) << 18);
+    }
+    pub const fn Level(&self) -> u64 {
+        (self._bitfield >> 22) & 7
+    }
+    pub fn set_Level(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(7 << 22)) | ((value & 7) << 22);
+    }
+    pub const fn ProcessorContextCorrupt(&self) -> u64 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_ProcessorContextCorrupt(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn Uncorrected(&self) -> u64 {
+        (self._bitfield >> 26) & 1
+    }
+    pub fn set_Uncorrected(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 26)) | ((value & 1) << 26);
+    }
+    pub const fn PreciseIP(&self) -> u64 {
+        (self._bitfield >> 27) & 1
+    }
+    pub fn set_PreciseIP(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 27)) | ((value & 1) << 27);
+    }
+    pub const fn RestartableIP(&self) -> u64 {
+        (self._bitfield >> 28) & 1
+    }
+    pub fn set_RestartableIP(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 28)) | ((value & 1) << 28);
+    }
+    pub const fn Overflow(&self) -> u64 {
+        (self._bitfield >> 29) & 1
+    }
+    pub fn set_Overflow(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 29)) | ((value & 1) << 29);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 30) & 17179869183
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(17179869183 << 30)) | ((value & 17179869183) << 30);
+    }
+}
 impl windows_core::TypeKind for WHEA_XPF_CACHE_CHECK_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -28615,6 +38185,92 @@ impl Default for WHEA_XPF_MS_CHECK {
 pub struct WHEA_XPF_MS_CHECK_0 {
     pub _bitfield: u64,
 }
+impl WHEA_XPF_MS_CHECK_0 {
+    pub const fn ErrorTypeValid(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_ErrorTypeValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ProcessorContextCorruptValid(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ProcessorContextCorruptValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn UncorrectedValid(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_UncorrectedValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn PreciseIPValid(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_PreciseIPValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn RestartableIPValid(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_RestartableIPValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn OverflowValid(&self) -> u64 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_OverflowValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn ReservedValue(&self) -> u64 {
+        (self._bitfield >> 6) & 1023
+    }
+    pub fn set_ReservedValue(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1023 << 6)) | ((value & 1023) << 6);
+    }
+    pub const fn ErrorType(&self) -> u64 {
+        (self._bitfield >> 16) & 7
+    }
+    pub fn set_ErrorType(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(7 << 16)) | ((value & 7) << 16);
+    }
+    pub const fn ProcessorContextCorrupt(&self) -> u64 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_ProcessorContextCorrupt(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn Uncorrected(&self) -> u64 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_Uncorrected(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn PreciseIP(&self) -> u64 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_PreciseIP(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn RestartableIP(&self) -> u64 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_RestartableIP(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn Overflow(&self) -> u64 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_Overflow(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 24) & 1099511627775
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1099511627775 << 24)) | ((value & 1099511627775) << 24);
+    }
+}
 impl windows_core::TypeKind for WHEA_XPF_MS_CHECK_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -28658,6 +38314,38 @@ impl Default for WHEA_XPF_PROCESSOR_ERROR_SECTION_VALIDBITS {
 pub struct WHEA_XPF_PROCESSOR_ERROR_SECTION_VALIDBITS_0 {
     pub _bitfield: u64,
 }
+impl WHEA_XPF_PROCESSOR_ERROR_SECTION_VALIDBITS_0 {
+    pub const fn LocalAPICId(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_LocalAPICId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CpuId(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CpuId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ProcInfoCount(&self) -> u64 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_ProcInfoCount(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+    pub const fn ContextInfoCount(&self) -> u64 {
+        (self._bitfield >> 8) & 63
+    }
+    pub fn set_ContextInfoCount(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(63 << 8)) | ((value & 63) << 8);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 14) & 1125899906842623
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1125899906842623 << 14)) | ((value & 1125899906842623) << 14);
+    }
+}
 impl windows_core::TypeKind for WHEA_XPF_PROCESSOR_ERROR_SECTION_VALIDBITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -28721,6 +38409,44 @@ impl Default for WHEA_XPF_PROCINFO_VALIDBITS {
 pub struct WHEA_XPF_PROCINFO_VALIDBITS_0 {
     pub _bitfield: u64,
 }
+impl WHEA_XPF_PROCINFO_VALIDBITS_0 {
+    pub const fn CheckInfo(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_CheckInfo(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn TargetId(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_TargetId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn RequesterId(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_RequesterId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ResponderId(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ResponderId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn InstructionPointer(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_InstructionPointer(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 5) & 576460752303423487
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(576460752303423487 << 5)) | ((value & 576460752303423487) << 5);
+    }
+}
 impl windows_core::TypeKind for WHEA_XPF_PROCINFO_VALIDBITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -28748,6 +38474,116 @@ impl Default for WHEA_XPF_TLB_CHECK {
 pub struct WHEA_XPF_TLB_CHECK_0 {
     pub _bitfield: u64,
 }
+impl WHEA_XPF_TLB_CHECK_0 {
+    pub const fn TransactionTypeValid(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_TransactionTypeValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn OperationValid(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_OperationValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn LevelValid(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_LevelValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ProcessorContextCorruptValid(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ProcessorContextCorruptValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn UncorrectedValid(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_UncorrectedValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PreciseIPValid(&self) -> u64 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_PreciseIPValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn RestartableIPValid(&self) -> u64 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_RestartableIPValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn OverflowValid(&self) -> u64 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_OverflowValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn ReservedValid(&self) -> u64 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_ReservedValid(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn TransactionType(&self) -> u64 {
+        (self._bitfield >> 16) & 3
+    }
+    pub fn set_TransactionType(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(3 << 16)) | ((value & 3) << 16);
+    }
+    pub const fn Operation(&self) -> u64 {
+        (self._bitfield >> 18) & 15
+    }
+    pub fn set_Operation(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(15 << 18)) | ((value & 15) << 18);
+    }
+    pub const fn Level(&self) -> u64 {
+        (self._bitfield >> 22) & 7
+    }
+    pub fn set_Level(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(7 << 22)) | ((value & 7) << 22);
+    }
+    pub const fn ProcessorContextCorrupt(&self) -> u64 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_ProcessorContextCorrupt(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn Uncorrected(&self) -> u64 {
+        (self._bitfield >> 26) & 1
+    }
+    pub fn set_Uncorrected(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 26)) | ((value & 1) << 26);
+    }
+    pub const fn PreciseIP(&self) -> u64 {
+        (self._bitfield >> 27) & 1
+    }
+    pub fn set_PreciseIP(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 27)) | ((value & 1) << 27);
+    }
+    pub const fn RestartableIP(&self) -> u64 {
+        (self._bitfield >> 28) & 1
+    }
+    pub fn set_RestartableIP(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 28)) | ((value & 1) << 28);
+    }
+    pub const fn Overflow(&self) -> u64 {
+        (self._bitfield >> 29) & 1
+    }
+    pub fn set_Overflow(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 29)) | ((value & 1) << 29);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 30) & 17179869183
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(17179869183 << 30)) | ((value & 17179869183) << 30);
+    }
+}
 impl windows_core::TypeKind for WHEA_XPF_TLB_CHECK_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -28782,6 +38618,26 @@ impl Default for XPF_RECOVERY_INFO {
 pub struct XPF_RECOVERY_INFO_0 {
     pub _bitfield: u32,
 }
+impl XPF_RECOVERY_INFO_0 {
+    pub const fn RecoveryAttempted(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_RecoveryAttempted(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn HvHandled(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_HvHandled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for XPF_RECOVERY_INFO_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -28795,6 +38651,80 @@ impl Default for XPF_RECOVERY_INFO_0 {
 pub struct XPF_RECOVERY_INFO_1 {
     pub _bitfield: u32,
 }
+impl XPF_RECOVERY_INFO_1 {
+    pub const fn NotSupported(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_NotSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Overflow(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Overflow(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ContextCorrupt(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ContextCorrupt(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn RestartIpErrorIpNotValid(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_RestartIpErrorIpNotValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn NoRecoveryContext(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_NoRecoveryContext(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn MiscOrAddrNotValid(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_MiscOrAddrNotValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn InvalidAddressMode(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_InvalidAddressMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn HighIrql(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_HighIrql(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn InterruptsDisabled(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_InterruptsDisabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn SwapBusy(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_SwapBusy(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn StackOverflow(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_StackOverflow(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 11) & 2097151
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2097151 << 11)) | ((value & 2097151) << 11);
+    }
+}
 impl windows_core::TypeKind for XPF_RECOVERY_INFO_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -28805,7 +38735,8 @@ impl Default for XPF_RECOVERY_INFO_1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Diagnostics_Debug")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XSAVE_FORMAT {
     pub ControlWord: u16,
     pub StatusWord: u16,
@@ -28836,7 +38767,8 @@ impl Default for XSAVE_FORMAT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Diagnostics_Debug")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XSTATE_CONTEXT {
     pub Mask: u64,
     pub Length: u32,
@@ -28891,7 +38823,8 @@ impl Default for XSTATE_SAVE_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Diagnostics_Debug")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XSTATE_SAVE_0_0 {
     pub Reserved1: i64,
     pub Reserved2: u32,
@@ -28913,7 +38846,8 @@ impl Default for XSTATE_SAVE_0_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ZONE_HEADER {
     pub FreeList: super::super::super::Win32::System::Kernel::SINGLE_LIST_ENTRY,
     pub SegmentList: super::super::super::Win32::System::Kernel::SINGLE_LIST_ENTRY,
@@ -28932,7 +38866,8 @@ impl Default for ZONE_HEADER {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ZONE_SEGMENT_HEADER {
     pub SegmentList: super::super::super::Win32::System::Kernel::SINGLE_LIST_ENTRY,
     pub Reserved: *mut core::ffi::c_void,
@@ -28948,7 +38883,8 @@ impl Default for ZONE_SEGMENT_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _EXT_SET_PARAMETERS_V0 {
     pub Version: u32,
     pub Reserved: u32,
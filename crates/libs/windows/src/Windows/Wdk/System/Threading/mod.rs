@@ -306,6 +306,7 @@ pub struct PROCESSINFOCLASS(pub i32);
 impl windows_core::TypeKind for PROCESSINFOCLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROCESSINFOCLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROCESSINFOCLASS").field(&self.0).finish()
@@ -317,6 +318,7 @@ pub struct THREADINFOCLASS(pub i32);
 impl windows_core::TypeKind for THREADINFOCLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THREADINFOCLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THREADINFOCLASS").field(&self.0).finish()
@@ -328,6 +330,7 @@ pub struct TIMER_SET_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for TIMER_SET_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TIMER_SET_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TIMER_SET_INFORMATION_CLASS").field(&self.0).finish()
@@ -746,3 +746,5 @@ impl windows_core::RuntimeName for Workspace {
 }
 unsafe impl Send for Workspace {}
 unsafe impl Sync for Workspace {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
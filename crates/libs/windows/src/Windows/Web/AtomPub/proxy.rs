@@ -0,0 +1,204 @@
+#[cfg(all(feature = "Security_Credentials", feature = "Storage_Streams", feature = "Web_Syndication"))]
+impl windows_core::imp::ProxyVtable for IAtomPubClient_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Web_Syndication")]
+        unsafe extern "system" fn RetrieveServiceDocumentAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtomPubClient", "RetrieveServiceDocumentAsync", 6usize, |this| ((**(this as *const *const IAtomPubClient_Vtbl)).RetrieveServiceDocumentAsync)(this, uri, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "Web_Syndication"))]
+        unsafe extern "system" fn RetrieveMediaResourceAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtomPubClient", "RetrieveMediaResourceAsync", 7usize, |this| ((**(this as *const *const IAtomPubClient_Vtbl)).RetrieveMediaResourceAsync)(this, uri, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Syndication")]
+        unsafe extern "system" fn RetrieveResourceAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtomPubClient", "RetrieveResourceAsync", 8usize, |this| ((**(this as *const *const IAtomPubClient_Vtbl)).RetrieveResourceAsync)(this, uri, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Syndication")]
+        unsafe extern "system" fn CreateResourceAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, description: core::mem::MaybeUninit<windows_core::HSTRING>, item: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtomPubClient", "CreateResourceAsync", 9usize, |this| ((**(this as *const *const IAtomPubClient_Vtbl)).CreateResourceAsync)(this, uri, description, item, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "Web_Syndication"))]
+        unsafe extern "system" fn CreateMediaResourceAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, mediatype: core::mem::MaybeUninit<windows_core::HSTRING>, description: core::mem::MaybeUninit<windows_core::HSTRING>, mediastream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtomPubClient", "CreateMediaResourceAsync", 10usize, |this| ((**(this as *const *const IAtomPubClient_Vtbl)).CreateMediaResourceAsync)(this, uri, mediatype, description, mediastream, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Storage_Streams", feature = "Web_Syndication"))]
+        unsafe extern "system" fn UpdateMediaResourceAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, mediatype: core::mem::MaybeUninit<windows_core::HSTRING>, mediastream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtomPubClient", "UpdateMediaResourceAsync", 11usize, |this| ((**(this as *const *const IAtomPubClient_Vtbl)).UpdateMediaResourceAsync)(this, uri, mediatype, mediastream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Syndication")]
+        unsafe extern "system" fn UpdateResourceAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, item: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtomPubClient", "UpdateResourceAsync", 12usize, |this| ((**(this as *const *const IAtomPubClient_Vtbl)).UpdateResourceAsync)(this, uri, item, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Syndication")]
+        unsafe extern "system" fn UpdateResourceItemAsync(this: *mut core::ffi::c_void, item: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtomPubClient", "UpdateResourceItemAsync", 13usize, |this| ((**(this as *const *const IAtomPubClient_Vtbl)).UpdateResourceItemAsync)(this, item, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Syndication")]
+        unsafe extern "system" fn DeleteResourceAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtomPubClient", "DeleteResourceAsync", 14usize, |this| ((**(this as *const *const IAtomPubClient_Vtbl)).DeleteResourceAsync)(this, uri, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Syndication")]
+        unsafe extern "system" fn DeleteResourceItemAsync(this: *mut core::ffi::c_void, item: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtomPubClient", "DeleteResourceItemAsync", 15usize, |this| ((**(this as *const *const IAtomPubClient_Vtbl)).DeleteResourceItemAsync)(this, item, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelAsyncOperations(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtomPubClient", "CancelAsyncOperations", 16usize, |this| ((**(this as *const *const IAtomPubClient_Vtbl)).CancelAsyncOperations)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Web_Syndication")]
+            RetrieveServiceDocumentAsync,
+            #[cfg(not(feature = "Web_Syndication"))]
+            RetrieveServiceDocumentAsync: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "Web_Syndication"))]
+            RetrieveMediaResourceAsync,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "Web_Syndication")))]
+            RetrieveMediaResourceAsync: 0,
+            #[cfg(feature = "Web_Syndication")]
+            RetrieveResourceAsync,
+            #[cfg(not(feature = "Web_Syndication"))]
+            RetrieveResourceAsync: 0,
+            #[cfg(feature = "Web_Syndication")]
+            CreateResourceAsync,
+            #[cfg(not(feature = "Web_Syndication"))]
+            CreateResourceAsync: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "Web_Syndication"))]
+            CreateMediaResourceAsync,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "Web_Syndication")))]
+            CreateMediaResourceAsync: 0,
+            #[cfg(all(feature = "Storage_Streams", feature = "Web_Syndication"))]
+            UpdateMediaResourceAsync,
+            #[cfg(not(all(feature = "Storage_Streams", feature = "Web_Syndication")))]
+            UpdateMediaResourceAsync: 0,
+            #[cfg(feature = "Web_Syndication")]
+            UpdateResourceAsync,
+            #[cfg(not(feature = "Web_Syndication"))]
+            UpdateResourceAsync: 0,
+            #[cfg(feature = "Web_Syndication")]
+            UpdateResourceItemAsync,
+            #[cfg(not(feature = "Web_Syndication"))]
+            UpdateResourceItemAsync: 0,
+            #[cfg(feature = "Web_Syndication")]
+            DeleteResourceAsync,
+            #[cfg(not(feature = "Web_Syndication"))]
+            DeleteResourceAsync: 0,
+            #[cfg(feature = "Web_Syndication")]
+            DeleteResourceItemAsync,
+            #[cfg(not(feature = "Web_Syndication"))]
+            DeleteResourceItemAsync: 0,
+            CancelAsyncOperations,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAtomPubClient as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for IAtomPubClientFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn CreateAtomPubClientWithCredentials(this: *mut core::ffi::c_void, servercredential: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtomPubClientFactory", "CreateAtomPubClientWithCredentials", 6usize, |this| ((**(this as *const *const IAtomPubClientFactory_Vtbl)).CreateAtomPubClientWithCredentials)(this, servercredential, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            CreateAtomPubClientWithCredentials,
+            #[cfg(not(feature = "Security_Credentials"))]
+            CreateAtomPubClientWithCredentials: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAtomPubClientFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections", feature = "Web_Syndication"))]
+impl windows_core::imp::ProxyVtable for IResourceCollection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Web_Syndication")]
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceCollection", "Title", 6usize, |this| ((**(this as *const *const IResourceCollection_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceCollection", "Uri", 7usize, |this| ((**(this as *const *const IResourceCollection_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Web_Syndication"))]
+        unsafe extern "system" fn Categories(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceCollection", "Categories", 8usize, |this| ((**(this as *const *const IResourceCollection_Vtbl)).Categories)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Accepts(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceCollection", "Accepts", 9usize, |this| ((**(this as *const *const IResourceCollection_Vtbl)).Accepts)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Web_Syndication")]
+            Title,
+            #[cfg(not(feature = "Web_Syndication"))]
+            Title: 0,
+            Uri,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Web_Syndication"))]
+            Categories,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Web_Syndication")))]
+            Categories: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Accepts,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Accepts: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections", feature = "Web_Syndication"))]
+impl windows_core::imp::ProxyVtable for IServiceDocument_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Workspaces(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceDocument", "Workspaces", 6usize, |this| ((**(this as *const *const IServiceDocument_Vtbl)).Workspaces)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Workspaces,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Workspaces: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceDocument as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections", feature = "Web_Syndication"))]
+impl windows_core::imp::ProxyVtable for IWorkspace_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Web_Syndication")]
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspace", "Title", 6usize, |this| ((**(this as *const *const IWorkspace_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Collections(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspace", "Collections", 7usize, |this| ((**(this as *const *const IWorkspace_Vtbl)).Collections)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Web_Syndication")]
+            Title,
+            #[cfg(not(feature = "Web_Syndication"))]
+            Title: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Collections,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Collections: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkspace as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
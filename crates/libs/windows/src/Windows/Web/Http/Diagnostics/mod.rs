@@ -500,6 +500,7 @@ impl HttpDiagnosticRequestInitiator {
 impl windows_core::TypeKind for HttpDiagnosticRequestInitiator {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HttpDiagnosticRequestInitiator {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HttpDiagnosticRequestInitiator").field(&self.0).finish()
@@ -508,3 +509,5 @@ impl core::fmt::Debug for HttpDiagnosticRequestInitiator {
 impl windows_core::RuntimeType for HttpDiagnosticRequestInitiator {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Web.Http.Diagnostics.HttpDiagnosticRequestInitiator;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,234 @@
+impl windows_core::imp::ProxyVtable for IHttpDiagnosticProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProvider", "Start", 6usize, |this| ((**(this as *const *const IHttpDiagnosticProvider_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProvider", "Stop", 7usize, |this| ((**(this as *const *const IHttpDiagnosticProvider_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestSent(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProvider", "RequestSent", 8usize, |this| ((**(this as *const *const IHttpDiagnosticProvider_Vtbl)).RequestSent)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRequestSent(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProvider", "RemoveRequestSent", 9usize, |this| ((**(this as *const *const IHttpDiagnosticProvider_Vtbl)).RemoveRequestSent)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseReceived(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProvider", "ResponseReceived", 10usize, |this| ((**(this as *const *const IHttpDiagnosticProvider_Vtbl)).ResponseReceived)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveResponseReceived(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProvider", "RemoveResponseReceived", 11usize, |this| ((**(this as *const *const IHttpDiagnosticProvider_Vtbl)).RemoveResponseReceived)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestResponseCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProvider", "RequestResponseCompleted", 12usize, |this| ((**(this as *const *const IHttpDiagnosticProvider_Vtbl)).RequestResponseCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRequestResponseCompleted(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProvider", "RemoveRequestResponseCompleted", 13usize, |this| ((**(this as *const *const IHttpDiagnosticProvider_Vtbl)).RemoveRequestResponseCompleted)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Start,
+            Stop,
+            RequestSent,
+            RemoveRequestSent,
+            ResponseReceived,
+            RemoveResponseReceived,
+            RequestResponseCompleted,
+            RemoveRequestResponseCompleted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpDiagnosticProvider as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHttpDiagnosticProviderRequestResponseCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivityId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseCompletedEventArgs", "ActivityId", 6usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseCompletedEventArgs_Vtbl)).ActivityId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamps(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseCompletedEventArgs", "Timestamps", 7usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseCompletedEventArgs_Vtbl)).Timestamps)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestedUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseCompletedEventArgs", "RequestedUri", 8usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseCompletedEventArgs_Vtbl)).RequestedUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseCompletedEventArgs", "ProcessId", 9usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseCompletedEventArgs_Vtbl)).ProcessId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThreadId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseCompletedEventArgs", "ThreadId", 10usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseCompletedEventArgs_Vtbl)).ThreadId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initiator(this: *mut core::ffi::c_void, result__: *mut HttpDiagnosticRequestInitiator) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseCompletedEventArgs", "Initiator", 11usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseCompletedEventArgs_Vtbl)).Initiator)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SourceLocations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseCompletedEventArgs", "SourceLocations", 12usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseCompletedEventArgs_Vtbl)).SourceLocations)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ActivityId,
+            Timestamps,
+            RequestedUri,
+            ProcessId,
+            ThreadId,
+            Initiator,
+            #[cfg(feature = "Foundation_Collections")]
+            SourceLocations,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SourceLocations: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpDiagnosticProviderRequestResponseCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpDiagnosticProviderRequestResponseTimestamps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CacheCheckedTimestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseTimestamps", "CacheCheckedTimestamp", 6usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseTimestamps_Vtbl)).CacheCheckedTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionInitiatedTimestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseTimestamps", "ConnectionInitiatedTimestamp", 7usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseTimestamps_Vtbl)).ConnectionInitiatedTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NameResolvedTimestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseTimestamps", "NameResolvedTimestamp", 8usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseTimestamps_Vtbl)).NameResolvedTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SslNegotiatedTimestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseTimestamps", "SslNegotiatedTimestamp", 9usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseTimestamps_Vtbl)).SslNegotiatedTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionCompletedTimestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseTimestamps", "ConnectionCompletedTimestamp", 10usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseTimestamps_Vtbl)).ConnectionCompletedTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestSentTimestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseTimestamps", "RequestSentTimestamp", 11usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseTimestamps_Vtbl)).RequestSentTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestCompletedTimestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseTimestamps", "RequestCompletedTimestamp", 12usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseTimestamps_Vtbl)).RequestCompletedTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseReceivedTimestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseTimestamps", "ResponseReceivedTimestamp", 13usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseTimestamps_Vtbl)).ResponseReceivedTimestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseCompletedTimestamp(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestResponseTimestamps", "ResponseCompletedTimestamp", 14usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestResponseTimestamps_Vtbl)).ResponseCompletedTimestamp)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CacheCheckedTimestamp,
+            ConnectionInitiatedTimestamp,
+            NameResolvedTimestamp,
+            SslNegotiatedTimestamp,
+            ConnectionCompletedTimestamp,
+            RequestSentTimestamp,
+            RequestCompletedTimestamp,
+            ResponseReceivedTimestamp,
+            ResponseCompletedTimestamp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpDiagnosticProviderRequestResponseTimestamps as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHttpDiagnosticProviderRequestSentEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestSentEventArgs", "Timestamp", 6usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestSentEventArgs_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivityId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestSentEventArgs", "ActivityId", 7usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestSentEventArgs_Vtbl)).ActivityId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestSentEventArgs", "Message", 8usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestSentEventArgs_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestSentEventArgs", "ProcessId", 9usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestSentEventArgs_Vtbl)).ProcessId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThreadId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestSentEventArgs", "ThreadId", 10usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestSentEventArgs_Vtbl)).ThreadId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initiator(this: *mut core::ffi::c_void, result__: *mut HttpDiagnosticRequestInitiator) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestSentEventArgs", "Initiator", 11usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestSentEventArgs_Vtbl)).Initiator)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn SourceLocations(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderRequestSentEventArgs", "SourceLocations", 12usize, |this| ((**(this as *const *const IHttpDiagnosticProviderRequestSentEventArgs_Vtbl)).SourceLocations)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Timestamp,
+            ActivityId,
+            Message,
+            ProcessId,
+            ThreadId,
+            Initiator,
+            #[cfg(feature = "Foundation_Collections")]
+            SourceLocations,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            SourceLocations: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpDiagnosticProviderRequestSentEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpDiagnosticProviderResponseReceivedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderResponseReceivedEventArgs", "Timestamp", 6usize, |this| ((**(this as *const *const IHttpDiagnosticProviderResponseReceivedEventArgs_Vtbl)).Timestamp)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivityId(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderResponseReceivedEventArgs", "ActivityId", 7usize, |this| ((**(this as *const *const IHttpDiagnosticProviderResponseReceivedEventArgs_Vtbl)).ActivityId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderResponseReceivedEventArgs", "Message", 8usize, |this| ((**(this as *const *const IHttpDiagnosticProviderResponseReceivedEventArgs_Vtbl)).Message)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Timestamp, ActivityId, Message }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpDiagnosticProviderResponseReceivedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System_Diagnostics")]
+impl windows_core::imp::ProxyVtable for IHttpDiagnosticProviderStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System_Diagnostics")]
+        unsafe extern "system" fn CreateFromProcessDiagnosticInfo(this: *mut core::ffi::c_void, processdiagnosticinfo: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticProviderStatics", "CreateFromProcessDiagnosticInfo", 6usize, |this| ((**(this as *const *const IHttpDiagnosticProviderStatics_Vtbl)).CreateFromProcessDiagnosticInfo)(this, processdiagnosticinfo, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System_Diagnostics")]
+            CreateFromProcessDiagnosticInfo,
+            #[cfg(not(feature = "System_Diagnostics"))]
+            CreateFromProcessDiagnosticInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpDiagnosticProviderStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpDiagnosticSourceLocation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SourceUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticSourceLocation", "SourceUri", 6usize, |this| ((**(this as *const *const IHttpDiagnosticSourceLocation_Vtbl)).SourceUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineNumber(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticSourceLocation", "LineNumber", 7usize, |this| ((**(this as *const *const IHttpDiagnosticSourceLocation_Vtbl)).LineNumber)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColumnNumber(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDiagnosticSourceLocation", "ColumnNumber", 8usize, |this| ((**(this as *const *const IHttpDiagnosticSourceLocation_Vtbl)).ColumnNumber)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SourceUri, LineNumber, ColumnNumber }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpDiagnosticSourceLocation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
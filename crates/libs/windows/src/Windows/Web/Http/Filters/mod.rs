@@ -521,6 +521,7 @@ impl HttpCacheReadBehavior {
 impl windows_core::TypeKind for HttpCacheReadBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HttpCacheReadBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HttpCacheReadBehavior").field(&self.0).finish()
@@ -539,6 +540,7 @@ impl HttpCacheWriteBehavior {
 impl windows_core::TypeKind for HttpCacheWriteBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HttpCacheWriteBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HttpCacheWriteBehavior").field(&self.0).finish()
@@ -557,6 +559,7 @@ impl HttpCookieUsageBehavior {
 impl windows_core::TypeKind for HttpCookieUsageBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HttpCookieUsageBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HttpCookieUsageBehavior").field(&self.0).finish()
@@ -567,3 +570,5 @@ impl windows_core::RuntimeType for HttpCookieUsageBehavior {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
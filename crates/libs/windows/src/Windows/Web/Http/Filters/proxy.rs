@@ -0,0 +1,304 @@
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Credentials", feature = "Security_Cryptography_Certificates"))]
+impl windows_core::imp::ProxyVtable for IHttpBaseProtocolFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowAutoRedirect(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "AllowAutoRedirect", 6usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).AllowAutoRedirect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowAutoRedirect(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "SetAllowAutoRedirect", 7usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).SetAllowAutoRedirect)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowUI(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "AllowUI", 8usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).AllowUI)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowUI(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "SetAllowUI", 9usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).SetAllowUI)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutomaticDecompression(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "AutomaticDecompression", 10usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).AutomaticDecompression)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutomaticDecompression(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "SetAutomaticDecompression", 11usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).SetAutomaticDecompression)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CacheControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "CacheControl", 12usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).CacheControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CookieManager(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "CookieManager", 13usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).CookieManager)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn ClientCertificate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "ClientCertificate", 14usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).ClientCertificate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn SetClientCertificate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "SetClientCertificate", 15usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).SetClientCertificate)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn IgnorableServerCertificateErrors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "IgnorableServerCertificateErrors", 16usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).IgnorableServerCertificateErrors)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxConnectionsPerServer(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "MaxConnectionsPerServer", 17usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).MaxConnectionsPerServer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxConnectionsPerServer(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "SetMaxConnectionsPerServer", 18usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).SetMaxConnectionsPerServer)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn ProxyCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "ProxyCredential", 19usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).ProxyCredential)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetProxyCredential(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "SetProxyCredential", 20usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).SetProxyCredential)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn ServerCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "ServerCredential", 21usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).ServerCredential)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetServerCredential(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "SetServerCredential", 22usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).SetServerCredential)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseProxy(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "UseProxy", 23usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).UseProxy)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseProxy(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter", "SetUseProxy", 24usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter_Vtbl)).SetUseProxy)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AllowAutoRedirect,
+            SetAllowAutoRedirect,
+            AllowUI,
+            SetAllowUI,
+            AutomaticDecompression,
+            SetAutomaticDecompression,
+            CacheControl,
+            CookieManager,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            ClientCertificate,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            ClientCertificate: 0,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            SetClientCertificate,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            SetClientCertificate: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            IgnorableServerCertificateErrors,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            IgnorableServerCertificateErrors: 0,
+            MaxConnectionsPerServer,
+            SetMaxConnectionsPerServer,
+            #[cfg(feature = "Security_Credentials")]
+            ProxyCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            ProxyCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetProxyCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetProxyCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            ServerCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            ServerCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetServerCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetServerCredential: 0,
+            UseProxy,
+            SetUseProxy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpBaseProtocolFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpBaseProtocolFilter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxVersion(this: *mut core::ffi::c_void, result__: *mut super::HttpVersion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter2", "MaxVersion", 6usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter2_Vtbl)).MaxVersion)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxVersion(this: *mut core::ffi::c_void, value: super::HttpVersion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter2", "SetMaxVersion", 7usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter2_Vtbl)).SetMaxVersion)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MaxVersion, SetMaxVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpBaseProtocolFilter2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpBaseProtocolFilter3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CookieUsageBehavior(this: *mut core::ffi::c_void, result__: *mut HttpCookieUsageBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter3", "CookieUsageBehavior", 6usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter3_Vtbl)).CookieUsageBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCookieUsageBehavior(this: *mut core::ffi::c_void, value: HttpCookieUsageBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter3", "SetCookieUsageBehavior", 7usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter3_Vtbl)).SetCookieUsageBehavior)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CookieUsageBehavior, SetCookieUsageBehavior }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpBaseProtocolFilter3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpBaseProtocolFilter4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServerCustomValidationRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter4", "ServerCustomValidationRequested", 6usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter4_Vtbl)).ServerCustomValidationRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveServerCustomValidationRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter4", "RemoveServerCustomValidationRequested", 7usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter4_Vtbl)).RemoveServerCustomValidationRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearAuthenticationCache(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter4", "ClearAuthenticationCache", 8usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter4_Vtbl)).ClearAuthenticationCache)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServerCustomValidationRequested,
+            RemoveServerCustomValidationRequested,
+            ClearAuthenticationCache,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpBaseProtocolFilter4 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IHttpBaseProtocolFilter5_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilter5", "User", 6usize, |this| ((**(this as *const *const IHttpBaseProtocolFilter5_Vtbl)).User)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            User,
+            #[cfg(not(feature = "System"))]
+            User: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpBaseProtocolFilter5 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "System")]
+impl windows_core::imp::ProxyVtable for IHttpBaseProtocolFilterStatics_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn CreateForUser(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBaseProtocolFilterStatics", "CreateForUser", 6usize, |this| ((**(this as *const *const IHttpBaseProtocolFilterStatics_Vtbl)).CreateForUser)(this, user, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "System")]
+            CreateForUser,
+            #[cfg(not(feature = "System"))]
+            CreateForUser: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpBaseProtocolFilterStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpCacheControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadBehavior(this: *mut core::ffi::c_void, result__: *mut HttpCacheReadBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCacheControl", "ReadBehavior", 6usize, |this| ((**(this as *const *const IHttpCacheControl_Vtbl)).ReadBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReadBehavior(this: *mut core::ffi::c_void, value: HttpCacheReadBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCacheControl", "SetReadBehavior", 7usize, |this| ((**(this as *const *const IHttpCacheControl_Vtbl)).SetReadBehavior)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteBehavior(this: *mut core::ffi::c_void, result__: *mut HttpCacheWriteBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCacheControl", "WriteBehavior", 8usize, |this| ((**(this as *const *const IHttpCacheControl_Vtbl)).WriteBehavior)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWriteBehavior(this: *mut core::ffi::c_void, value: HttpCacheWriteBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCacheControl", "SetWriteBehavior", 9usize, |this| ((**(this as *const *const IHttpCacheControl_Vtbl)).SetWriteBehavior)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReadBehavior,
+            SetReadBehavior,
+            WriteBehavior,
+            SetWriteBehavior,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpCacheControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendRequestAsync(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpFilter", "SendRequestAsync", 6usize, |this| ((**(this as *const *const IHttpFilter_Vtbl)).SendRequestAsync)(this, request, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SendRequestAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpFilter as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Networking_Sockets", feature = "Security_Cryptography_Certificates"))]
+impl windows_core::imp::ProxyVtable for IHttpServerCustomValidationRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpServerCustomValidationRequestedEventArgs", "RequestMessage", 6usize, |this| ((**(this as *const *const IHttpServerCustomValidationRequestedEventArgs_Vtbl)).RequestMessage)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn ServerCertificate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpServerCustomValidationRequestedEventArgs", "ServerCertificate", 7usize, |this| ((**(this as *const *const IHttpServerCustomValidationRequestedEventArgs_Vtbl)).ServerCertificate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn ServerCertificateErrorSeverity(this: *mut core::ffi::c_void, result__: *mut super::super::super::Networking::Sockets::SocketSslErrorSeverity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpServerCustomValidationRequestedEventArgs", "ServerCertificateErrorSeverity", 8usize, |this| ((**(this as *const *const IHttpServerCustomValidationRequestedEventArgs_Vtbl)).ServerCertificateErrorSeverity)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn ServerCertificateErrors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpServerCustomValidationRequestedEventArgs", "ServerCertificateErrors", 9usize, |this| ((**(this as *const *const IHttpServerCustomValidationRequestedEventArgs_Vtbl)).ServerCertificateErrors)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn ServerIntermediateCertificates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpServerCustomValidationRequestedEventArgs", "ServerIntermediateCertificates", 10usize, |this| ((**(this as *const *const IHttpServerCustomValidationRequestedEventArgs_Vtbl)).ServerIntermediateCertificates)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reject(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpServerCustomValidationRequestedEventArgs", "Reject", 11usize, |this| ((**(this as *const *const IHttpServerCustomValidationRequestedEventArgs_Vtbl)).Reject)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpServerCustomValidationRequestedEventArgs", "GetDeferral", 12usize, |this| ((**(this as *const *const IHttpServerCustomValidationRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestMessage,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            ServerCertificate,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            ServerCertificate: 0,
+            #[cfg(feature = "Networking_Sockets")]
+            ServerCertificateErrorSeverity,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            ServerCertificateErrorSeverity: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            ServerCertificateErrors,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            ServerCertificateErrors: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            ServerIntermediateCertificates,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            ServerIntermediateCertificates: 0,
+            Reject,
+            GetDeferral,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpServerCustomValidationRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
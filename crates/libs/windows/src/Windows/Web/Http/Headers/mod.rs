@@ -4884,3 +4884,5 @@ impl IntoIterator for &HttpTransferCodingHeaderValueCollection {
 }
 unsafe impl Send for HttpTransferCodingHeaderValueCollection {}
 unsafe impl Sync for HttpTransferCodingHeaderValueCollection {}
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1436 @@
+impl windows_core::imp::ProxyVtable for IHttpCacheDirectiveHeaderValueCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MaxAge(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCacheDirectiveHeaderValueCollection", "MaxAge", 6usize, |this| ((**(this as *const *const IHttpCacheDirectiveHeaderValueCollection_Vtbl)).MaxAge)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxAge(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCacheDirectiveHeaderValueCollection", "SetMaxAge", 7usize, |this| ((**(this as *const *const IHttpCacheDirectiveHeaderValueCollection_Vtbl)).SetMaxAge)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxStale(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCacheDirectiveHeaderValueCollection", "MaxStale", 8usize, |this| ((**(this as *const *const IHttpCacheDirectiveHeaderValueCollection_Vtbl)).MaxStale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxStale(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCacheDirectiveHeaderValueCollection", "SetMaxStale", 9usize, |this| ((**(this as *const *const IHttpCacheDirectiveHeaderValueCollection_Vtbl)).SetMaxStale)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinFresh(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCacheDirectiveHeaderValueCollection", "MinFresh", 10usize, |this| ((**(this as *const *const IHttpCacheDirectiveHeaderValueCollection_Vtbl)).MinFresh)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinFresh(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCacheDirectiveHeaderValueCollection", "SetMinFresh", 11usize, |this| ((**(this as *const *const IHttpCacheDirectiveHeaderValueCollection_Vtbl)).SetMinFresh)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SharedMaxAge(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCacheDirectiveHeaderValueCollection", "SharedMaxAge", 12usize, |this| ((**(this as *const *const IHttpCacheDirectiveHeaderValueCollection_Vtbl)).SharedMaxAge)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSharedMaxAge(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCacheDirectiveHeaderValueCollection", "SetSharedMaxAge", 13usize, |this| ((**(this as *const *const IHttpCacheDirectiveHeaderValueCollection_Vtbl)).SetSharedMaxAge)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCacheDirectiveHeaderValueCollection", "ParseAdd", 14usize, |this| ((**(this as *const *const IHttpCacheDirectiveHeaderValueCollection_Vtbl)).ParseAdd)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCacheDirectiveHeaderValueCollection", "TryParseAdd", 15usize, |this| ((**(this as *const *const IHttpCacheDirectiveHeaderValueCollection_Vtbl)).TryParseAdd)(this, input, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MaxAge,
+            SetMaxAge,
+            MaxStale,
+            SetMaxStale,
+            MinFresh,
+            SetMinFresh,
+            SharedMaxAge,
+            SetSharedMaxAge,
+            ParseAdd,
+            TryParseAdd,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpCacheDirectiveHeaderValueCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHttpChallengeHeaderValue_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Parameters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpChallengeHeaderValue", "Parameters", 6usize, |this| ((**(this as *const *const IHttpChallengeHeaderValue_Vtbl)).Parameters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scheme(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpChallengeHeaderValue", "Scheme", 7usize, |this| ((**(this as *const *const IHttpChallengeHeaderValue_Vtbl)).Scheme)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Token(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpChallengeHeaderValue", "Token", 8usize, |this| ((**(this as *const *const IHttpChallengeHeaderValue_Vtbl)).Token)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Parameters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Parameters: 0,
+            Scheme,
+            Token,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpChallengeHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpChallengeHeaderValueCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpChallengeHeaderValueCollection", "ParseAdd", 6usize, |this| ((**(this as *const *const IHttpChallengeHeaderValueCollection_Vtbl)).ParseAdd)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpChallengeHeaderValueCollection", "TryParseAdd", 7usize, |this| ((**(this as *const *const IHttpChallengeHeaderValueCollection_Vtbl)).TryParseAdd)(this, input, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseAdd, TryParseAdd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpChallengeHeaderValueCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpChallengeHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromScheme(this: *mut core::ffi::c_void, scheme: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpChallengeHeaderValueFactory", "CreateFromScheme", 6usize, |this| ((**(this as *const *const IHttpChallengeHeaderValueFactory_Vtbl)).CreateFromScheme)(this, scheme, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromSchemeWithToken(this: *mut core::ffi::c_void, scheme: core::mem::MaybeUninit<windows_core::HSTRING>, token: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpChallengeHeaderValueFactory", "CreateFromSchemeWithToken", 7usize, |this| ((**(this as *const *const IHttpChallengeHeaderValueFactory_Vtbl)).CreateFromSchemeWithToken)(this, scheme, token, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromScheme, CreateFromSchemeWithToken }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpChallengeHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpChallengeHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpChallengeHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpChallengeHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, challengeheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpChallengeHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpChallengeHeaderValueStatics_Vtbl)).TryParse)(this, input, challengeheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpChallengeHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpConnectionOptionHeaderValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Token(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpConnectionOptionHeaderValue", "Token", 6usize, |this| ((**(this as *const *const IHttpConnectionOptionHeaderValue_Vtbl)).Token)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Token }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpConnectionOptionHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpConnectionOptionHeaderValueCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpConnectionOptionHeaderValueCollection", "ParseAdd", 6usize, |this| ((**(this as *const *const IHttpConnectionOptionHeaderValueCollection_Vtbl)).ParseAdd)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpConnectionOptionHeaderValueCollection", "TryParseAdd", 7usize, |this| ((**(this as *const *const IHttpConnectionOptionHeaderValueCollection_Vtbl)).TryParseAdd)(this, input, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseAdd, TryParseAdd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpConnectionOptionHeaderValueCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpConnectionOptionHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, token: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpConnectionOptionHeaderValueFactory", "Create", 6usize, |this| ((**(this as *const *const IHttpConnectionOptionHeaderValueFactory_Vtbl)).Create)(this, token, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpConnectionOptionHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpConnectionOptionHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpConnectionOptionHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpConnectionOptionHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, connectionoptionheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpConnectionOptionHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpConnectionOptionHeaderValueStatics_Vtbl)).TryParse)(this, input, connectionoptionheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpConnectionOptionHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpContentCodingHeaderValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContentCoding(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentCodingHeaderValue", "ContentCoding", 6usize, |this| ((**(this as *const *const IHttpContentCodingHeaderValue_Vtbl)).ContentCoding)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContentCoding }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContentCodingHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpContentCodingHeaderValueCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentCodingHeaderValueCollection", "ParseAdd", 6usize, |this| ((**(this as *const *const IHttpContentCodingHeaderValueCollection_Vtbl)).ParseAdd)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentCodingHeaderValueCollection", "TryParseAdd", 7usize, |this| ((**(this as *const *const IHttpContentCodingHeaderValueCollection_Vtbl)).TryParseAdd)(this, input, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseAdd, TryParseAdd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContentCodingHeaderValueCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpContentCodingHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, contentcoding: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentCodingHeaderValueFactory", "Create", 6usize, |this| ((**(this as *const *const IHttpContentCodingHeaderValueFactory_Vtbl)).Create)(this, contentcoding, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContentCodingHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpContentCodingHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentCodingHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpContentCodingHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, contentcodingheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentCodingHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpContentCodingHeaderValueStatics_Vtbl)).TryParse)(this, input, contentcodingheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContentCodingHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpContentCodingWithQualityHeaderValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContentCoding(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentCodingWithQualityHeaderValue", "ContentCoding", 6usize, |this| ((**(this as *const *const IHttpContentCodingWithQualityHeaderValue_Vtbl)).ContentCoding)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Quality(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentCodingWithQualityHeaderValue", "Quality", 7usize, |this| ((**(this as *const *const IHttpContentCodingWithQualityHeaderValue_Vtbl)).Quality)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContentCoding, Quality }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContentCodingWithQualityHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpContentCodingWithQualityHeaderValueCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentCodingWithQualityHeaderValueCollection", "ParseAdd", 6usize, |this| ((**(this as *const *const IHttpContentCodingWithQualityHeaderValueCollection_Vtbl)).ParseAdd)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentCodingWithQualityHeaderValueCollection", "TryParseAdd", 7usize, |this| ((**(this as *const *const IHttpContentCodingWithQualityHeaderValueCollection_Vtbl)).TryParseAdd)(this, input, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseAdd, TryParseAdd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContentCodingWithQualityHeaderValueCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpContentCodingWithQualityHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromValue(this: *mut core::ffi::c_void, contentcoding: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentCodingWithQualityHeaderValueFactory", "CreateFromValue", 6usize, |this| ((**(this as *const *const IHttpContentCodingWithQualityHeaderValueFactory_Vtbl)).CreateFromValue)(this, contentcoding, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromValueWithQuality(this: *mut core::ffi::c_void, contentcoding: core::mem::MaybeUninit<windows_core::HSTRING>, quality: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentCodingWithQualityHeaderValueFactory", "CreateFromValueWithQuality", 7usize, |this| ((**(this as *const *const IHttpContentCodingWithQualityHeaderValueFactory_Vtbl)).CreateFromValueWithQuality)(this, contentcoding, quality, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromValue, CreateFromValueWithQuality }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContentCodingWithQualityHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpContentCodingWithQualityHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentCodingWithQualityHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpContentCodingWithQualityHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, contentcodingwithqualityheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentCodingWithQualityHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpContentCodingWithQualityHeaderValueStatics_Vtbl)).TryParse)(this, input, contentcodingwithqualityheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContentCodingWithQualityHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHttpContentDispositionHeaderValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DispositionType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentDispositionHeaderValue", "DispositionType", 6usize, |this| ((**(this as *const *const IHttpContentDispositionHeaderValue_Vtbl)).DispositionType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDispositionType(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentDispositionHeaderValue", "SetDispositionType", 7usize, |this| ((**(this as *const *const IHttpContentDispositionHeaderValue_Vtbl)).SetDispositionType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentDispositionHeaderValue", "FileName", 8usize, |this| ((**(this as *const *const IHttpContentDispositionHeaderValue_Vtbl)).FileName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentDispositionHeaderValue", "SetFileName", 9usize, |this| ((**(this as *const *const IHttpContentDispositionHeaderValue_Vtbl)).SetFileName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileNameStar(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentDispositionHeaderValue", "FileNameStar", 10usize, |this| ((**(this as *const *const IHttpContentDispositionHeaderValue_Vtbl)).FileNameStar)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileNameStar(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentDispositionHeaderValue", "SetFileNameStar", 11usize, |this| ((**(this as *const *const IHttpContentDispositionHeaderValue_Vtbl)).SetFileNameStar)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentDispositionHeaderValue", "Name", 12usize, |this| ((**(this as *const *const IHttpContentDispositionHeaderValue_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentDispositionHeaderValue", "SetName", 13usize, |this| ((**(this as *const *const IHttpContentDispositionHeaderValue_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Parameters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentDispositionHeaderValue", "Parameters", 14usize, |this| ((**(this as *const *const IHttpContentDispositionHeaderValue_Vtbl)).Parameters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentDispositionHeaderValue", "Size", 15usize, |this| ((**(this as *const *const IHttpContentDispositionHeaderValue_Vtbl)).Size)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentDispositionHeaderValue", "SetSize", 16usize, |this| ((**(this as *const *const IHttpContentDispositionHeaderValue_Vtbl)).SetSize)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DispositionType,
+            SetDispositionType,
+            FileName,
+            SetFileName,
+            FileNameStar,
+            SetFileNameStar,
+            Name,
+            SetName,
+            #[cfg(feature = "Foundation_Collections")]
+            Parameters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Parameters: 0,
+            Size,
+            SetSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContentDispositionHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpContentDispositionHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, dispositiontype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentDispositionHeaderValueFactory", "Create", 6usize, |this| ((**(this as *const *const IHttpContentDispositionHeaderValueFactory_Vtbl)).Create)(this, dispositiontype, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContentDispositionHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpContentDispositionHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentDispositionHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpContentDispositionHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, contentdispositionheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentDispositionHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpContentDispositionHeaderValueStatics_Vtbl)).TryParse)(this, input, contentdispositionheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContentDispositionHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IHttpContentHeaderCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContentDisposition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "ContentDisposition", 6usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).ContentDisposition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentDisposition(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "SetContentDisposition", 7usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).SetContentDisposition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentEncoding(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "ContentEncoding", 8usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).ContentEncoding)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentLanguage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "ContentLanguage", 9usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).ContentLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentLength(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "ContentLength", 10usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).ContentLength)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentLength(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "SetContentLength", 11usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).SetContentLength)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentLocation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "ContentLocation", 12usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).ContentLocation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentLocation(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "SetContentLocation", 13usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).SetContentLocation)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ContentMD5(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "ContentMD5", 14usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).ContentMD5)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn SetContentMD5(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "SetContentMD5", 15usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).SetContentMD5)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentRange(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "ContentRange", 16usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).ContentRange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentRange(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "SetContentRange", 17usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).SetContentRange)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentType(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "ContentType", 18usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).ContentType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentType(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "SetContentType", 19usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).SetContentType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Expires(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "Expires", 20usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).Expires)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExpires(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "SetExpires", 21usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).SetExpires)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastModified(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "LastModified", 22usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).LastModified)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastModified(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "SetLastModified", 23usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).SetLastModified)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "Append", 24usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).Append)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryAppendWithoutValidation(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentHeaderCollection", "TryAppendWithoutValidation", 25usize, |this| ((**(this as *const *const IHttpContentHeaderCollection_Vtbl)).TryAppendWithoutValidation)(this, name, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ContentDisposition,
+            SetContentDisposition,
+            ContentEncoding,
+            ContentLanguage,
+            ContentLength,
+            SetContentLength,
+            ContentLocation,
+            SetContentLocation,
+            #[cfg(feature = "Storage_Streams")]
+            ContentMD5,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ContentMD5: 0,
+            #[cfg(feature = "Storage_Streams")]
+            SetContentMD5,
+            #[cfg(not(feature = "Storage_Streams"))]
+            SetContentMD5: 0,
+            ContentRange,
+            SetContentRange,
+            ContentType,
+            SetContentType,
+            Expires,
+            SetExpires,
+            LastModified,
+            SetLastModified,
+            Append,
+            TryAppendWithoutValidation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContentHeaderCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpContentRangeHeaderValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FirstBytePosition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentRangeHeaderValue", "FirstBytePosition", 6usize, |this| ((**(this as *const *const IHttpContentRangeHeaderValue_Vtbl)).FirstBytePosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastBytePosition(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentRangeHeaderValue", "LastBytePosition", 7usize, |this| ((**(this as *const *const IHttpContentRangeHeaderValue_Vtbl)).LastBytePosition)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentRangeHeaderValue", "Length", 8usize, |this| ((**(this as *const *const IHttpContentRangeHeaderValue_Vtbl)).Length)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unit(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentRangeHeaderValue", "Unit", 9usize, |this| ((**(this as *const *const IHttpContentRangeHeaderValue_Vtbl)).Unit)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnit(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentRangeHeaderValue", "SetUnit", 10usize, |this| ((**(this as *const *const IHttpContentRangeHeaderValue_Vtbl)).SetUnit)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FirstBytePosition, LastBytePosition, Length, Unit, SetUnit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContentRangeHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpContentRangeHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromLength(this: *mut core::ffi::c_void, length: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentRangeHeaderValueFactory", "CreateFromLength", 6usize, |this| ((**(this as *const *const IHttpContentRangeHeaderValueFactory_Vtbl)).CreateFromLength)(this, length, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromRange(this: *mut core::ffi::c_void, from: u64, to: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentRangeHeaderValueFactory", "CreateFromRange", 7usize, |this| ((**(this as *const *const IHttpContentRangeHeaderValueFactory_Vtbl)).CreateFromRange)(this, from, to, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromRangeWithLength(this: *mut core::ffi::c_void, from: u64, to: u64, length: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentRangeHeaderValueFactory", "CreateFromRangeWithLength", 8usize, |this| ((**(this as *const *const IHttpContentRangeHeaderValueFactory_Vtbl)).CreateFromRangeWithLength)(this, from, to, length, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromLength, CreateFromRange, CreateFromRangeWithLength }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContentRangeHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpContentRangeHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentRangeHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpContentRangeHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, contentrangeheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContentRangeHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpContentRangeHeaderValueStatics_Vtbl)).TryParse)(this, input, contentrangeheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContentRangeHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpCookiePairHeaderValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookiePairHeaderValue", "Name", 6usize, |this| ((**(this as *const *const IHttpCookiePairHeaderValue_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookiePairHeaderValue", "Value", 7usize, |this| ((**(this as *const *const IHttpCookiePairHeaderValue_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookiePairHeaderValue", "SetValue", 8usize, |this| ((**(this as *const *const IHttpCookiePairHeaderValue_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpCookiePairHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpCookiePairHeaderValueCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookiePairHeaderValueCollection", "ParseAdd", 6usize, |this| ((**(this as *const *const IHttpCookiePairHeaderValueCollection_Vtbl)).ParseAdd)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookiePairHeaderValueCollection", "TryParseAdd", 7usize, |this| ((**(this as *const *const IHttpCookiePairHeaderValueCollection_Vtbl)).TryParseAdd)(this, input, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseAdd, TryParseAdd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpCookiePairHeaderValueCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpCookiePairHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookiePairHeaderValueFactory", "CreateFromName", 6usize, |this| ((**(this as *const *const IHttpCookiePairHeaderValueFactory_Vtbl)).CreateFromName)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromNameWithValue(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookiePairHeaderValueFactory", "CreateFromNameWithValue", 7usize, |this| ((**(this as *const *const IHttpCookiePairHeaderValueFactory_Vtbl)).CreateFromNameWithValue)(this, name, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromName, CreateFromNameWithValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpCookiePairHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpCookiePairHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookiePairHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpCookiePairHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, cookiepairheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookiePairHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpCookiePairHeaderValueStatics_Vtbl)).TryParse)(this, input, cookiepairheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpCookiePairHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHttpCredentialsHeaderValue_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Parameters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCredentialsHeaderValue", "Parameters", 6usize, |this| ((**(this as *const *const IHttpCredentialsHeaderValue_Vtbl)).Parameters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scheme(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCredentialsHeaderValue", "Scheme", 7usize, |this| ((**(this as *const *const IHttpCredentialsHeaderValue_Vtbl)).Scheme)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Token(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCredentialsHeaderValue", "Token", 8usize, |this| ((**(this as *const *const IHttpCredentialsHeaderValue_Vtbl)).Token)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Parameters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Parameters: 0,
+            Scheme,
+            Token,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpCredentialsHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpCredentialsHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromScheme(this: *mut core::ffi::c_void, scheme: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCredentialsHeaderValueFactory", "CreateFromScheme", 6usize, |this| ((**(this as *const *const IHttpCredentialsHeaderValueFactory_Vtbl)).CreateFromScheme)(this, scheme, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromSchemeWithToken(this: *mut core::ffi::c_void, scheme: core::mem::MaybeUninit<windows_core::HSTRING>, token: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCredentialsHeaderValueFactory", "CreateFromSchemeWithToken", 7usize, |this| ((**(this as *const *const IHttpCredentialsHeaderValueFactory_Vtbl)).CreateFromSchemeWithToken)(this, scheme, token, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromScheme, CreateFromSchemeWithToken }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpCredentialsHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpCredentialsHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCredentialsHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpCredentialsHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, credentialsheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCredentialsHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpCredentialsHeaderValueStatics_Vtbl)).TryParse)(this, input, credentialsheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpCredentialsHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpDateOrDeltaHeaderValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Date(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDateOrDeltaHeaderValue", "Date", 6usize, |this| ((**(this as *const *const IHttpDateOrDeltaHeaderValue_Vtbl)).Date)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delta(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDateOrDeltaHeaderValue", "Delta", 7usize, |this| ((**(this as *const *const IHttpDateOrDeltaHeaderValue_Vtbl)).Delta)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Date, Delta }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpDateOrDeltaHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpDateOrDeltaHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDateOrDeltaHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpDateOrDeltaHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, dateordeltaheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpDateOrDeltaHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpDateOrDeltaHeaderValueStatics_Vtbl)).TryParse)(this, input, dateordeltaheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpDateOrDeltaHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHttpExpectationHeaderValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpExpectationHeaderValue", "Name", 6usize, |this| ((**(this as *const *const IHttpExpectationHeaderValue_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpExpectationHeaderValue", "Value", 7usize, |this| ((**(this as *const *const IHttpExpectationHeaderValue_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpExpectationHeaderValue", "SetValue", 8usize, |this| ((**(this as *const *const IHttpExpectationHeaderValue_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Parameters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpExpectationHeaderValue", "Parameters", 9usize, |this| ((**(this as *const *const IHttpExpectationHeaderValue_Vtbl)).Parameters)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Value,
+            SetValue,
+            #[cfg(feature = "Foundation_Collections")]
+            Parameters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Parameters: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpExpectationHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpExpectationHeaderValueCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpExpectationHeaderValueCollection", "ParseAdd", 6usize, |this| ((**(this as *const *const IHttpExpectationHeaderValueCollection_Vtbl)).ParseAdd)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpExpectationHeaderValueCollection", "TryParseAdd", 7usize, |this| ((**(this as *const *const IHttpExpectationHeaderValueCollection_Vtbl)).TryParseAdd)(this, input, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseAdd, TryParseAdd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpExpectationHeaderValueCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpExpectationHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpExpectationHeaderValueFactory", "CreateFromName", 6usize, |this| ((**(this as *const *const IHttpExpectationHeaderValueFactory_Vtbl)).CreateFromName)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromNameWithValue(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpExpectationHeaderValueFactory", "CreateFromNameWithValue", 7usize, |this| ((**(this as *const *const IHttpExpectationHeaderValueFactory_Vtbl)).CreateFromNameWithValue)(this, name, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromName, CreateFromNameWithValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpExpectationHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpExpectationHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpExpectationHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpExpectationHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, expectationheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpExpectationHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpExpectationHeaderValueStatics_Vtbl)).TryParse)(this, input, expectationheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpExpectationHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpLanguageHeaderValueCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpLanguageHeaderValueCollection", "ParseAdd", 6usize, |this| ((**(this as *const *const IHttpLanguageHeaderValueCollection_Vtbl)).ParseAdd)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpLanguageHeaderValueCollection", "TryParseAdd", 7usize, |this| ((**(this as *const *const IHttpLanguageHeaderValueCollection_Vtbl)).TryParseAdd)(this, input, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseAdd, TryParseAdd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpLanguageHeaderValueCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpLanguageRangeWithQualityHeaderValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LanguageRange(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpLanguageRangeWithQualityHeaderValue", "LanguageRange", 6usize, |this| ((**(this as *const *const IHttpLanguageRangeWithQualityHeaderValue_Vtbl)).LanguageRange)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Quality(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpLanguageRangeWithQualityHeaderValue", "Quality", 7usize, |this| ((**(this as *const *const IHttpLanguageRangeWithQualityHeaderValue_Vtbl)).Quality)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LanguageRange, Quality }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpLanguageRangeWithQualityHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpLanguageRangeWithQualityHeaderValueCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpLanguageRangeWithQualityHeaderValueCollection", "ParseAdd", 6usize, |this| ((**(this as *const *const IHttpLanguageRangeWithQualityHeaderValueCollection_Vtbl)).ParseAdd)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpLanguageRangeWithQualityHeaderValueCollection", "TryParseAdd", 7usize, |this| ((**(this as *const *const IHttpLanguageRangeWithQualityHeaderValueCollection_Vtbl)).TryParseAdd)(this, input, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseAdd, TryParseAdd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpLanguageRangeWithQualityHeaderValueCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpLanguageRangeWithQualityHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromLanguageRange(this: *mut core::ffi::c_void, languagerange: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpLanguageRangeWithQualityHeaderValueFactory", "CreateFromLanguageRange", 6usize, |this| ((**(this as *const *const IHttpLanguageRangeWithQualityHeaderValueFactory_Vtbl)).CreateFromLanguageRange)(this, languagerange, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromLanguageRangeWithQuality(this: *mut core::ffi::c_void, languagerange: core::mem::MaybeUninit<windows_core::HSTRING>, quality: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpLanguageRangeWithQualityHeaderValueFactory", "CreateFromLanguageRangeWithQuality", 7usize, |this| ((**(this as *const *const IHttpLanguageRangeWithQualityHeaderValueFactory_Vtbl)).CreateFromLanguageRangeWithQuality)(this, languagerange, quality, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromLanguageRange, CreateFromLanguageRangeWithQuality }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpLanguageRangeWithQualityHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpLanguageRangeWithQualityHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpLanguageRangeWithQualityHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpLanguageRangeWithQualityHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, languagerangewithqualityheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpLanguageRangeWithQualityHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpLanguageRangeWithQualityHeaderValueStatics_Vtbl)).TryParse)(this, input, languagerangewithqualityheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpLanguageRangeWithQualityHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHttpMediaTypeHeaderValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CharSet(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeHeaderValue", "CharSet", 6usize, |this| ((**(this as *const *const IHttpMediaTypeHeaderValue_Vtbl)).CharSet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCharSet(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeHeaderValue", "SetCharSet", 7usize, |this| ((**(this as *const *const IHttpMediaTypeHeaderValue_Vtbl)).SetCharSet)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeHeaderValue", "MediaType", 8usize, |this| ((**(this as *const *const IHttpMediaTypeHeaderValue_Vtbl)).MediaType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaType(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeHeaderValue", "SetMediaType", 9usize, |this| ((**(this as *const *const IHttpMediaTypeHeaderValue_Vtbl)).SetMediaType)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Parameters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeHeaderValue", "Parameters", 10usize, |this| ((**(this as *const *const IHttpMediaTypeHeaderValue_Vtbl)).Parameters)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CharSet,
+            SetCharSet,
+            MediaType,
+            SetMediaType,
+            #[cfg(feature = "Foundation_Collections")]
+            Parameters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Parameters: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpMediaTypeHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpMediaTypeHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, mediatype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeHeaderValueFactory", "Create", 6usize, |this| ((**(this as *const *const IHttpMediaTypeHeaderValueFactory_Vtbl)).Create)(this, mediatype, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpMediaTypeHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpMediaTypeHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpMediaTypeHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, mediatypeheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpMediaTypeHeaderValueStatics_Vtbl)).TryParse)(this, input, mediatypeheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpMediaTypeHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHttpMediaTypeWithQualityHeaderValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CharSet(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeWithQualityHeaderValue", "CharSet", 6usize, |this| ((**(this as *const *const IHttpMediaTypeWithQualityHeaderValue_Vtbl)).CharSet)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCharSet(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeWithQualityHeaderValue", "SetCharSet", 7usize, |this| ((**(this as *const *const IHttpMediaTypeWithQualityHeaderValue_Vtbl)).SetCharSet)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeWithQualityHeaderValue", "MediaType", 8usize, |this| ((**(this as *const *const IHttpMediaTypeWithQualityHeaderValue_Vtbl)).MediaType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaType(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeWithQualityHeaderValue", "SetMediaType", 9usize, |this| ((**(this as *const *const IHttpMediaTypeWithQualityHeaderValue_Vtbl)).SetMediaType)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Parameters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeWithQualityHeaderValue", "Parameters", 10usize, |this| ((**(this as *const *const IHttpMediaTypeWithQualityHeaderValue_Vtbl)).Parameters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Quality(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeWithQualityHeaderValue", "Quality", 11usize, |this| ((**(this as *const *const IHttpMediaTypeWithQualityHeaderValue_Vtbl)).Quality)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuality(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeWithQualityHeaderValue", "SetQuality", 12usize, |this| ((**(this as *const *const IHttpMediaTypeWithQualityHeaderValue_Vtbl)).SetQuality)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CharSet,
+            SetCharSet,
+            MediaType,
+            SetMediaType,
+            #[cfg(feature = "Foundation_Collections")]
+            Parameters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Parameters: 0,
+            Quality,
+            SetQuality,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpMediaTypeWithQualityHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpMediaTypeWithQualityHeaderValueCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeWithQualityHeaderValueCollection", "ParseAdd", 6usize, |this| ((**(this as *const *const IHttpMediaTypeWithQualityHeaderValueCollection_Vtbl)).ParseAdd)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeWithQualityHeaderValueCollection", "TryParseAdd", 7usize, |this| ((**(this as *const *const IHttpMediaTypeWithQualityHeaderValueCollection_Vtbl)).TryParseAdd)(this, input, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseAdd, TryParseAdd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpMediaTypeWithQualityHeaderValueCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpMediaTypeWithQualityHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromMediaType(this: *mut core::ffi::c_void, mediatype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeWithQualityHeaderValueFactory", "CreateFromMediaType", 6usize, |this| ((**(this as *const *const IHttpMediaTypeWithQualityHeaderValueFactory_Vtbl)).CreateFromMediaType)(this, mediatype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromMediaTypeWithQuality(this: *mut core::ffi::c_void, mediatype: core::mem::MaybeUninit<windows_core::HSTRING>, quality: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeWithQualityHeaderValueFactory", "CreateFromMediaTypeWithQuality", 7usize, |this| ((**(this as *const *const IHttpMediaTypeWithQualityHeaderValueFactory_Vtbl)).CreateFromMediaTypeWithQuality)(this, mediatype, quality, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromMediaType, CreateFromMediaTypeWithQuality }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpMediaTypeWithQualityHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpMediaTypeWithQualityHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeWithQualityHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpMediaTypeWithQualityHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, mediatypewithqualityheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMediaTypeWithQualityHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpMediaTypeWithQualityHeaderValueStatics_Vtbl)).TryParse)(this, input, mediatypewithqualityheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpMediaTypeWithQualityHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpMethodHeaderValueCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMethodHeaderValueCollection", "ParseAdd", 6usize, |this| ((**(this as *const *const IHttpMethodHeaderValueCollection_Vtbl)).ParseAdd)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMethodHeaderValueCollection", "TryParseAdd", 7usize, |this| ((**(this as *const *const IHttpMethodHeaderValueCollection_Vtbl)).TryParseAdd)(this, input, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseAdd, TryParseAdd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpMethodHeaderValueCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpNameValueHeaderValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpNameValueHeaderValue", "Name", 6usize, |this| ((**(this as *const *const IHttpNameValueHeaderValue_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpNameValueHeaderValue", "Value", 7usize, |this| ((**(this as *const *const IHttpNameValueHeaderValue_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpNameValueHeaderValue", "SetValue", 8usize, |this| ((**(this as *const *const IHttpNameValueHeaderValue_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpNameValueHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpNameValueHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpNameValueHeaderValueFactory", "CreateFromName", 6usize, |this| ((**(this as *const *const IHttpNameValueHeaderValueFactory_Vtbl)).CreateFromName)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromNameWithValue(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpNameValueHeaderValueFactory", "CreateFromNameWithValue", 7usize, |this| ((**(this as *const *const IHttpNameValueHeaderValueFactory_Vtbl)).CreateFromNameWithValue)(this, name, value, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromName, CreateFromNameWithValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpNameValueHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpNameValueHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpNameValueHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpNameValueHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, namevalueheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpNameValueHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpNameValueHeaderValueStatics_Vtbl)).TryParse)(this, input, namevalueheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpNameValueHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpProductHeaderValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpProductHeaderValue", "Name", 6usize, |this| ((**(this as *const *const IHttpProductHeaderValue_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpProductHeaderValue", "Version", 7usize, |this| ((**(this as *const *const IHttpProductHeaderValue_Vtbl)).Version)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, Version }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpProductHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpProductHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromName(this: *mut core::ffi::c_void, productname: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpProductHeaderValueFactory", "CreateFromName", 6usize, |this| ((**(this as *const *const IHttpProductHeaderValueFactory_Vtbl)).CreateFromName)(this, productname, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromNameWithVersion(this: *mut core::ffi::c_void, productname: core::mem::MaybeUninit<windows_core::HSTRING>, productversion: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpProductHeaderValueFactory", "CreateFromNameWithVersion", 7usize, |this| ((**(this as *const *const IHttpProductHeaderValueFactory_Vtbl)).CreateFromNameWithVersion)(this, productname, productversion, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromName, CreateFromNameWithVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpProductHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpProductHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpProductHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpProductHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, productheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpProductHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpProductHeaderValueStatics_Vtbl)).TryParse)(this, input, productheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpProductHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpProductInfoHeaderValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Product(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpProductInfoHeaderValue", "Product", 6usize, |this| ((**(this as *const *const IHttpProductInfoHeaderValue_Vtbl)).Product)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Comment(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpProductInfoHeaderValue", "Comment", 7usize, |this| ((**(this as *const *const IHttpProductInfoHeaderValue_Vtbl)).Comment)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Product, Comment }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpProductInfoHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpProductInfoHeaderValueCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpProductInfoHeaderValueCollection", "ParseAdd", 6usize, |this| ((**(this as *const *const IHttpProductInfoHeaderValueCollection_Vtbl)).ParseAdd)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpProductInfoHeaderValueCollection", "TryParseAdd", 7usize, |this| ((**(this as *const *const IHttpProductInfoHeaderValueCollection_Vtbl)).TryParseAdd)(this, input, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseAdd, TryParseAdd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpProductInfoHeaderValueCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpProductInfoHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromComment(this: *mut core::ffi::c_void, productcomment: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpProductInfoHeaderValueFactory", "CreateFromComment", 6usize, |this| ((**(this as *const *const IHttpProductInfoHeaderValueFactory_Vtbl)).CreateFromComment)(this, productcomment, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromNameWithVersion(this: *mut core::ffi::c_void, productname: core::mem::MaybeUninit<windows_core::HSTRING>, productversion: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpProductInfoHeaderValueFactory", "CreateFromNameWithVersion", 7usize, |this| ((**(this as *const *const IHttpProductInfoHeaderValueFactory_Vtbl)).CreateFromNameWithVersion)(this, productname, productversion, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromComment, CreateFromNameWithVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpProductInfoHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpProductInfoHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpProductInfoHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpProductInfoHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, productinfoheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpProductInfoHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpProductInfoHeaderValueStatics_Vtbl)).TryParse)(this, input, productinfoheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpProductInfoHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Networking")]
+impl windows_core::imp::ProxyVtable for IHttpRequestHeaderCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Accept(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "Accept", 6usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).Accept)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptEncoding(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "AcceptEncoding", 7usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).AcceptEncoding)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptLanguage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "AcceptLanguage", 8usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).AcceptLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Authorization(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "Authorization", 9usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).Authorization)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthorization(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "SetAuthorization", 10usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).SetAuthorization)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CacheControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "CacheControl", 11usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).CacheControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "Connection", 12usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).Connection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cookie(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "Cookie", 13usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).Cookie)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Date(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "Date", 14usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).Date)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "SetDate", 15usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).SetDate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Expect(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "Expect", 16usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).Expect)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn From(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "From", 17usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).From)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrom(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "SetFrom", 18usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).SetFrom)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking")]
+        unsafe extern "system" fn Host(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "Host", 19usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).Host)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking")]
+        unsafe extern "system" fn SetHost(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "SetHost", 20usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).SetHost)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IfModifiedSince(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "IfModifiedSince", 21usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).IfModifiedSince)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIfModifiedSince(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "SetIfModifiedSince", 22usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).SetIfModifiedSince)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IfUnmodifiedSince(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "IfUnmodifiedSince", 23usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).IfUnmodifiedSince)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIfUnmodifiedSince(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "SetIfUnmodifiedSince", 24usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).SetIfUnmodifiedSince)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxForwards(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "MaxForwards", 25usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).MaxForwards)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxForwards(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "SetMaxForwards", 26usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).SetMaxForwards)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProxyAuthorization(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "ProxyAuthorization", 27usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).ProxyAuthorization)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProxyAuthorization(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "SetProxyAuthorization", 28usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).SetProxyAuthorization)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Referer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "Referer", 29usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).Referer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReferer(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "SetReferer", 30usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).SetReferer)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferEncoding(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "TransferEncoding", 31usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).TransferEncoding)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserAgent(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "UserAgent", 32usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).UserAgent)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "Append", 33usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).Append)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryAppendWithoutValidation(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestHeaderCollection", "TryAppendWithoutValidation", 34usize, |this| ((**(this as *const *const IHttpRequestHeaderCollection_Vtbl)).TryAppendWithoutValidation)(this, name, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Accept,
+            AcceptEncoding,
+            AcceptLanguage,
+            Authorization,
+            SetAuthorization,
+            CacheControl,
+            Connection,
+            Cookie,
+            Date,
+            SetDate,
+            Expect,
+            From,
+            SetFrom,
+            #[cfg(feature = "Networking")]
+            Host,
+            #[cfg(not(feature = "Networking"))]
+            Host: 0,
+            #[cfg(feature = "Networking")]
+            SetHost,
+            #[cfg(not(feature = "Networking"))]
+            SetHost: 0,
+            IfModifiedSince,
+            SetIfModifiedSince,
+            IfUnmodifiedSince,
+            SetIfUnmodifiedSince,
+            MaxForwards,
+            SetMaxForwards,
+            ProxyAuthorization,
+            SetProxyAuthorization,
+            Referer,
+            SetReferer,
+            TransferEncoding,
+            UserAgent,
+            Append,
+            TryAppendWithoutValidation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpRequestHeaderCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpResponseHeaderCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Age(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "Age", 6usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).Age)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAge(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "SetAge", 7usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).SetAge)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Allow(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "Allow", 8usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).Allow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CacheControl(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "CacheControl", 9usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).CacheControl)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connection(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "Connection", 10usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).Connection)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Date(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "Date", 11usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).Date)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDate(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "SetDate", 12usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).SetDate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Location(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "Location", 13usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).Location)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocation(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "SetLocation", 14usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).SetLocation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProxyAuthenticate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "ProxyAuthenticate", 15usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).ProxyAuthenticate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetryAfter(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "RetryAfter", 16usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).RetryAfter)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRetryAfter(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "SetRetryAfter", 17usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).SetRetryAfter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferEncoding(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "TransferEncoding", 18usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).TransferEncoding)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WwwAuthenticate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "WwwAuthenticate", 19usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).WwwAuthenticate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "Append", 20usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).Append)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryAppendWithoutValidation(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseHeaderCollection", "TryAppendWithoutValidation", 21usize, |this| ((**(this as *const *const IHttpResponseHeaderCollection_Vtbl)).TryAppendWithoutValidation)(this, name, value, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Age,
+            SetAge,
+            Allow,
+            CacheControl,
+            Connection,
+            Date,
+            SetDate,
+            Location,
+            SetLocation,
+            ProxyAuthenticate,
+            RetryAfter,
+            SetRetryAfter,
+            TransferEncoding,
+            WwwAuthenticate,
+            Append,
+            TryAppendWithoutValidation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpResponseHeaderCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHttpTransferCodingHeaderValue_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Parameters(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpTransferCodingHeaderValue", "Parameters", 6usize, |this| ((**(this as *const *const IHttpTransferCodingHeaderValue_Vtbl)).Parameters)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpTransferCodingHeaderValue", "Value", 7usize, |this| ((**(this as *const *const IHttpTransferCodingHeaderValue_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Parameters,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Parameters: 0,
+            Value,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpTransferCodingHeaderValue as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpTransferCodingHeaderValueCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpTransferCodingHeaderValueCollection", "ParseAdd", 6usize, |this| ((**(this as *const *const IHttpTransferCodingHeaderValueCollection_Vtbl)).ParseAdd)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParseAdd(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpTransferCodingHeaderValueCollection", "TryParseAdd", 7usize, |this| ((**(this as *const *const IHttpTransferCodingHeaderValueCollection_Vtbl)).TryParseAdd)(this, input, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseAdd, TryParseAdd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpTransferCodingHeaderValueCollection as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpTransferCodingHeaderValueFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpTransferCodingHeaderValueFactory", "Create", 6usize, |this| ((**(this as *const *const IHttpTransferCodingHeaderValueFactory_Vtbl)).Create)(this, input, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpTransferCodingHeaderValueFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpTransferCodingHeaderValueStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpTransferCodingHeaderValueStatics", "Parse", 6usize, |this| ((**(this as *const *const IHttpTransferCodingHeaderValueStatics_Vtbl)).Parse)(this, input, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryParse(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::HSTRING>, transfercodingheadervalue: *mut *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpTransferCodingHeaderValueStatics", "TryParse", 7usize, |this| ((**(this as *const *const IHttpTransferCodingHeaderValueStatics_Vtbl)).TryParse)(this, input, transfercodingheadervalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Parse, TryParse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpTransferCodingHeaderValueStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
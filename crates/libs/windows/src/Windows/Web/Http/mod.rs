@@ -2424,6 +2424,7 @@ impl HttpCompletionOption {
 impl windows_core::TypeKind for HttpCompletionOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HttpCompletionOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HttpCompletionOption").field(&self.0).finish()
@@ -2450,6 +2451,7 @@ impl HttpProgressStage {
 impl windows_core::TypeKind for HttpProgressStage {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HttpProgressStage {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HttpProgressStage").field(&self.0).finish()
@@ -2469,6 +2471,7 @@ impl HttpResponseMessageSource {
 impl windows_core::TypeKind for HttpResponseMessageSource {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HttpResponseMessageSource {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HttpResponseMessageSource").field(&self.0).finish()
@@ -2543,6 +2546,7 @@ impl HttpStatusCode {
 impl windows_core::TypeKind for HttpStatusCode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HttpStatusCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HttpStatusCode").field(&self.0).finish()
@@ -2563,6 +2567,7 @@ impl HttpVersion {
 impl windows_core::TypeKind for HttpVersion {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HttpVersion {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HttpVersion").field(&self.0).finish()
@@ -2572,7 +2577,8 @@ impl windows_core::RuntimeType for HttpVersion {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Web.Http.HttpVersion;i4)");
 }
 #[repr(C)]
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HttpProgress {
     pub Stage: HttpProgressStage,
     pub BytesSent: u64,
@@ -2594,3 +2600,5 @@ impl Default for HttpProgress {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
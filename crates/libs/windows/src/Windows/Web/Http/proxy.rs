@@ -0,0 +1,859 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IHttpBufferContentFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromBuffer(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBufferContentFactory", "CreateFromBuffer", 6usize, |this| ((**(this as *const *const IHttpBufferContentFactory_Vtbl)).CreateFromBuffer)(this, content, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromBufferWithOffset(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, offset: u32, count: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpBufferContentFactory", "CreateFromBufferWithOffset", 7usize, |this| ((**(this as *const *const IHttpBufferContentFactory_Vtbl)).CreateFromBufferWithOffset)(this, content, offset, count, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromBuffer,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromBuffer: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromBufferWithOffset,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromBufferWithOffset: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpBufferContentFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_Streams", feature = "Web_Http_Headers"))]
+impl windows_core::imp::ProxyVtable for IHttpClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient", "DeleteAsync", 6usize, |this| ((**(this as *const *const IHttpClient_Vtbl)).DeleteAsync)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient", "GetAsync", 7usize, |this| ((**(this as *const *const IHttpClient_Vtbl)).GetAsync)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWithOptionAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, completionoption: HttpCompletionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient", "GetWithOptionAsync", 8usize, |this| ((**(this as *const *const IHttpClient_Vtbl)).GetWithOptionAsync)(this, uri, completionoption, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetBufferAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient", "GetBufferAsync", 9usize, |this| ((**(this as *const *const IHttpClient_Vtbl)).GetBufferAsync)(this, uri, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn GetInputStreamAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient", "GetInputStreamAsync", 10usize, |this| ((**(this as *const *const IHttpClient_Vtbl)).GetInputStreamAsync)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient", "GetStringAsync", 11usize, |this| ((**(this as *const *const IHttpClient_Vtbl)).GetStringAsync)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, content: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient", "PostAsync", 12usize, |this| ((**(this as *const *const IHttpClient_Vtbl)).PostAsync)(this, uri, content, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, content: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient", "PutAsync", 13usize, |this| ((**(this as *const *const IHttpClient_Vtbl)).PutAsync)(this, uri, content, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendRequestAsync(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient", "SendRequestAsync", 14usize, |this| ((**(this as *const *const IHttpClient_Vtbl)).SendRequestAsync)(this, request, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendRequestWithOptionAsync(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, completionoption: HttpCompletionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient", "SendRequestWithOptionAsync", 15usize, |this| ((**(this as *const *const IHttpClient_Vtbl)).SendRequestWithOptionAsync)(this, request, completionoption, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Http_Headers")]
+        unsafe extern "system" fn DefaultRequestHeaders(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient", "DefaultRequestHeaders", 16usize, |this| ((**(this as *const *const IHttpClient_Vtbl)).DefaultRequestHeaders)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeleteAsync,
+            GetAsync,
+            GetWithOptionAsync,
+            #[cfg(feature = "Storage_Streams")]
+            GetBufferAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetBufferAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            GetInputStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            GetInputStreamAsync: 0,
+            GetStringAsync,
+            PostAsync,
+            PutAsync,
+            SendRequestAsync,
+            SendRequestWithOptionAsync,
+            #[cfg(feature = "Web_Http_Headers")]
+            DefaultRequestHeaders,
+            #[cfg(not(feature = "Web_Http_Headers"))]
+            DefaultRequestHeaders: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpClient as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpClient2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryDeleteAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient2", "TryDeleteAsync", 6usize, |this| ((**(this as *const *const IHttpClient2_Vtbl)).TryDeleteAsync)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient2", "TryGetAsync", 7usize, |this| ((**(this as *const *const IHttpClient2_Vtbl)).TryGetAsync)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetAsync2(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, completionoption: HttpCompletionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient2", "TryGetAsync2", 8usize, |this| ((**(this as *const *const IHttpClient2_Vtbl)).TryGetAsync2)(this, uri, completionoption, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetBufferAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient2", "TryGetBufferAsync", 9usize, |this| ((**(this as *const *const IHttpClient2_Vtbl)).TryGetBufferAsync)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetInputStreamAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient2", "TryGetInputStreamAsync", 10usize, |this| ((**(this as *const *const IHttpClient2_Vtbl)).TryGetInputStreamAsync)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetStringAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient2", "TryGetStringAsync", 11usize, |this| ((**(this as *const *const IHttpClient2_Vtbl)).TryGetStringAsync)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryPostAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, content: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient2", "TryPostAsync", 12usize, |this| ((**(this as *const *const IHttpClient2_Vtbl)).TryPostAsync)(this, uri, content, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryPutAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, content: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient2", "TryPutAsync", 13usize, |this| ((**(this as *const *const IHttpClient2_Vtbl)).TryPutAsync)(this, uri, content, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySendRequestAsync(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient2", "TrySendRequestAsync", 14usize, |this| ((**(this as *const *const IHttpClient2_Vtbl)).TrySendRequestAsync)(this, request, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrySendRequestAsync2(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, completionoption: HttpCompletionOption, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient2", "TrySendRequestAsync2", 15usize, |this| ((**(this as *const *const IHttpClient2_Vtbl)).TrySendRequestAsync2)(this, request, completionoption, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TryDeleteAsync,
+            TryGetAsync,
+            TryGetAsync2,
+            TryGetBufferAsync,
+            TryGetInputStreamAsync,
+            TryGetStringAsync,
+            TryPostAsync,
+            TryPutAsync,
+            TrySendRequestAsync,
+            TrySendRequestAsync2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpClient2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpClient3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DefaultPrivacyAnnotation(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient3", "DefaultPrivacyAnnotation", 6usize, |this| ((**(this as *const *const IHttpClient3_Vtbl)).DefaultPrivacyAnnotation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultPrivacyAnnotation(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClient3", "SetDefaultPrivacyAnnotation", 7usize, |this| ((**(this as *const *const IHttpClient3_Vtbl)).SetDefaultPrivacyAnnotation)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DefaultPrivacyAnnotation, SetDefaultPrivacyAnnotation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpClient3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Web_Http_Filters")]
+impl windows_core::imp::ProxyVtable for IHttpClientFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Web_Http_Filters")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, filter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpClientFactory", "Create", 6usize, |this| ((**(this as *const *const IHttpClientFactory_Vtbl)).Create)(this, filter, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Web_Http_Filters")]
+            Create,
+            #[cfg(not(feature = "Web_Http_Filters"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpClientFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Storage_Streams", feature = "Web_Http_Headers"))]
+impl windows_core::imp::ProxyVtable for IHttpContent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Web_Http_Headers")]
+        unsafe extern "system" fn Headers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContent", "Headers", 6usize, |this| ((**(this as *const *const IHttpContent_Vtbl)).Headers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BufferAllAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContent", "BufferAllAsync", 7usize, |this| ((**(this as *const *const IHttpContent_Vtbl)).BufferAllAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ReadAsBufferAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContent", "ReadAsBufferAsync", 8usize, |this| ((**(this as *const *const IHttpContent_Vtbl)).ReadAsBufferAsync)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn ReadAsInputStreamAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContent", "ReadAsInputStreamAsync", 9usize, |this| ((**(this as *const *const IHttpContent_Vtbl)).ReadAsInputStreamAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadAsStringAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContent", "ReadAsStringAsync", 10usize, |this| ((**(this as *const *const IHttpContent_Vtbl)).ReadAsStringAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryComputeLength(this: *mut core::ffi::c_void, length: *mut u64, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContent", "TryComputeLength", 11usize, |this| ((**(this as *const *const IHttpContent_Vtbl)).TryComputeLength)(this, length, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn WriteToStreamAsync(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpContent", "WriteToStreamAsync", 12usize, |this| ((**(this as *const *const IHttpContent_Vtbl)).WriteToStreamAsync)(this, outputstream, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Web_Http_Headers")]
+            Headers,
+            #[cfg(not(feature = "Web_Http_Headers"))]
+            Headers: 0,
+            BufferAllAsync,
+            #[cfg(feature = "Storage_Streams")]
+            ReadAsBufferAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ReadAsBufferAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            ReadAsInputStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            ReadAsInputStreamAsync: 0,
+            ReadAsStringAsync,
+            TryComputeLength,
+            #[cfg(feature = "Storage_Streams")]
+            WriteToStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            WriteToStreamAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpContent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpCookie_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookie", "Name", 6usize, |this| ((**(this as *const *const IHttpCookie_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Domain(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookie", "Domain", 7usize, |this| ((**(this as *const *const IHttpCookie_Vtbl)).Domain)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookie", "Path", 8usize, |this| ((**(this as *const *const IHttpCookie_Vtbl)).Path)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Expires(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookie", "Expires", 9usize, |this| ((**(this as *const *const IHttpCookie_Vtbl)).Expires)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExpires(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookie", "SetExpires", 10usize, |this| ((**(this as *const *const IHttpCookie_Vtbl)).SetExpires)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HttpOnly(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookie", "HttpOnly", 11usize, |this| ((**(this as *const *const IHttpCookie_Vtbl)).HttpOnly)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHttpOnly(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookie", "SetHttpOnly", 12usize, |this| ((**(this as *const *const IHttpCookie_Vtbl)).SetHttpOnly)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Secure(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookie", "Secure", 13usize, |this| ((**(this as *const *const IHttpCookie_Vtbl)).Secure)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecure(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookie", "SetSecure", 14usize, |this| ((**(this as *const *const IHttpCookie_Vtbl)).SetSecure)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookie", "Value", 15usize, |this| ((**(this as *const *const IHttpCookie_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookie", "SetValue", 16usize, |this| ((**(this as *const *const IHttpCookie_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Domain,
+            Path,
+            Expires,
+            SetExpires,
+            HttpOnly,
+            SetHttpOnly,
+            Secure,
+            SetSecure,
+            Value,
+            SetValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpCookie as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpCookieFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, domain: core::mem::MaybeUninit<windows_core::HSTRING>, path: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookieFactory", "Create", 6usize, |this| ((**(this as *const *const IHttpCookieFactory_Vtbl)).Create)(this, name, domain, path, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpCookieFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHttpCookieManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCookie(this: *mut core::ffi::c_void, cookie: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookieManager", "SetCookie", 6usize, |this| ((**(this as *const *const IHttpCookieManager_Vtbl)).SetCookie)(this, cookie, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCookieWithThirdParty(this: *mut core::ffi::c_void, cookie: *mut core::ffi::c_void, thirdparty: bool, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookieManager", "SetCookieWithThirdParty", 7usize, |this| ((**(this as *const *const IHttpCookieManager_Vtbl)).SetCookieWithThirdParty)(this, cookie, thirdparty, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteCookie(this: *mut core::ffi::c_void, cookie: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookieManager", "DeleteCookie", 8usize, |this| ((**(this as *const *const IHttpCookieManager_Vtbl)).DeleteCookie)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetCookies(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpCookieManager", "GetCookies", 9usize, |this| ((**(this as *const *const IHttpCookieManager_Vtbl)).GetCookies)(this, uri, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetCookie,
+            SetCookieWithThirdParty,
+            DeleteCookie,
+            #[cfg(feature = "Foundation_Collections")]
+            GetCookies,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetCookies: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpCookieManager as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IHttpFormUrlEncodedContentFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpFormUrlEncodedContentFactory", "Create", 6usize, |this| ((**(this as *const *const IHttpFormUrlEncodedContentFactory_Vtbl)).Create)(this, content, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Create,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpFormUrlEncodedContentFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IHttpGetBufferResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpGetBufferResult", "ExtendedError", 6usize, |this| ((**(this as *const *const IHttpGetBufferResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpGetBufferResult", "RequestMessage", 7usize, |this| ((**(this as *const *const IHttpGetBufferResult_Vtbl)).RequestMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpGetBufferResult", "ResponseMessage", 8usize, |this| ((**(this as *const *const IHttpGetBufferResult_Vtbl)).ResponseMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Succeeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpGetBufferResult", "Succeeded", 9usize, |this| ((**(this as *const *const IHttpGetBufferResult_Vtbl)).Succeeded)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpGetBufferResult", "Value", 10usize, |this| ((**(this as *const *const IHttpGetBufferResult_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ExtendedError,
+            RequestMessage,
+            ResponseMessage,
+            Succeeded,
+            #[cfg(feature = "Storage_Streams")]
+            Value,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Value: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpGetBufferResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IHttpGetInputStreamResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpGetInputStreamResult", "ExtendedError", 6usize, |this| ((**(this as *const *const IHttpGetInputStreamResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpGetInputStreamResult", "RequestMessage", 7usize, |this| ((**(this as *const *const IHttpGetInputStreamResult_Vtbl)).RequestMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpGetInputStreamResult", "ResponseMessage", 8usize, |this| ((**(this as *const *const IHttpGetInputStreamResult_Vtbl)).ResponseMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Succeeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpGetInputStreamResult", "Succeeded", 9usize, |this| ((**(this as *const *const IHttpGetInputStreamResult_Vtbl)).Succeeded)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpGetInputStreamResult", "Value", 10usize, |this| ((**(this as *const *const IHttpGetInputStreamResult_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ExtendedError,
+            RequestMessage,
+            ResponseMessage,
+            Succeeded,
+            #[cfg(feature = "Storage_Streams")]
+            Value,
+            #[cfg(not(feature = "Storage_Streams"))]
+            Value: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpGetInputStreamResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpGetStringResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpGetStringResult", "ExtendedError", 6usize, |this| ((**(this as *const *const IHttpGetStringResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpGetStringResult", "RequestMessage", 7usize, |this| ((**(this as *const *const IHttpGetStringResult_Vtbl)).RequestMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpGetStringResult", "ResponseMessage", 8usize, |this| ((**(this as *const *const IHttpGetStringResult_Vtbl)).ResponseMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Succeeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpGetStringResult", "Succeeded", 9usize, |this| ((**(this as *const *const IHttpGetStringResult_Vtbl)).Succeeded)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpGetStringResult", "Value", 10usize, |this| ((**(this as *const *const IHttpGetStringResult_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ExtendedError,
+            RequestMessage,
+            ResponseMessage,
+            Succeeded,
+            Value,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpGetStringResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpMethod_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Method(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMethod", "Method", 6usize, |this| ((**(this as *const *const IHttpMethod_Vtbl)).Method)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Method }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpMethod as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpMethodFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, method: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMethodFactory", "Create", 6usize, |this| ((**(this as *const *const IHttpMethodFactory_Vtbl)).Create)(this, method, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpMethodFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpMethodStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMethodStatics", "Delete", 6usize, |this| ((**(this as *const *const IHttpMethodStatics_Vtbl)).Delete)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMethodStatics", "Get", 7usize, |this| ((**(this as *const *const IHttpMethodStatics_Vtbl)).Get)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Head(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMethodStatics", "Head", 8usize, |this| ((**(this as *const *const IHttpMethodStatics_Vtbl)).Head)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Options(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMethodStatics", "Options", 9usize, |this| ((**(this as *const *const IHttpMethodStatics_Vtbl)).Options)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Patch(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMethodStatics", "Patch", 10usize, |this| ((**(this as *const *const IHttpMethodStatics_Vtbl)).Patch)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Post(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMethodStatics", "Post", 11usize, |this| ((**(this as *const *const IHttpMethodStatics_Vtbl)).Post)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Put(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMethodStatics", "Put", 12usize, |this| ((**(this as *const *const IHttpMethodStatics_Vtbl)).Put)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Delete, Get, Head, Options, Patch, Post, Put }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpMethodStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpMultipartContent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMultipartContent", "Add", 6usize, |this| ((**(this as *const *const IHttpMultipartContent_Vtbl)).Add)(this, content), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Add }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpMultipartContent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpMultipartContentFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithSubtype(this: *mut core::ffi::c_void, subtype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMultipartContentFactory", "CreateWithSubtype", 6usize, |this| ((**(this as *const *const IHttpMultipartContentFactory_Vtbl)).CreateWithSubtype)(this, subtype, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWithSubtypeAndBoundary(this: *mut core::ffi::c_void, subtype: core::mem::MaybeUninit<windows_core::HSTRING>, boundary: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMultipartContentFactory", "CreateWithSubtypeAndBoundary", 7usize, |this| ((**(this as *const *const IHttpMultipartContentFactory_Vtbl)).CreateWithSubtypeAndBoundary)(this, subtype, boundary, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithSubtype, CreateWithSubtypeAndBoundary }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpMultipartContentFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpMultipartFormDataContent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMultipartFormDataContent", "Add", 6usize, |this| ((**(this as *const *const IHttpMultipartFormDataContent_Vtbl)).Add)(this, content), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddWithName(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMultipartFormDataContent", "AddWithName", 7usize, |this| ((**(this as *const *const IHttpMultipartFormDataContent_Vtbl)).AddWithName)(this, content, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddWithNameAndFileName(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, filename: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMultipartFormDataContent", "AddWithNameAndFileName", 8usize, |this| ((**(this as *const *const IHttpMultipartFormDataContent_Vtbl)).AddWithNameAndFileName)(this, content, name, filename), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Add, AddWithName, AddWithNameAndFileName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpMultipartFormDataContent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpMultipartFormDataContentFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithBoundary(this: *mut core::ffi::c_void, boundary: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpMultipartFormDataContentFactory", "CreateWithBoundary", 6usize, |this| ((**(this as *const *const IHttpMultipartFormDataContentFactory_Vtbl)).CreateWithBoundary)(this, boundary, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithBoundary }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpMultipartFormDataContentFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Web_Http_Headers"))]
+impl windows_core::imp::ProxyVtable for IHttpRequestMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestMessage", "Content", 6usize, |this| ((**(this as *const *const IHttpRequestMessage_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContent(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestMessage", "SetContent", 7usize, |this| ((**(this as *const *const IHttpRequestMessage_Vtbl)).SetContent)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Http_Headers")]
+        unsafe extern "system" fn Headers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestMessage", "Headers", 8usize, |this| ((**(this as *const *const IHttpRequestMessage_Vtbl)).Headers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Method(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestMessage", "Method", 9usize, |this| ((**(this as *const *const IHttpRequestMessage_Vtbl)).Method)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMethod(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestMessage", "SetMethod", 10usize, |this| ((**(this as *const *const IHttpRequestMessage_Vtbl)).SetMethod)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestMessage", "Properties", 11usize, |this| ((**(this as *const *const IHttpRequestMessage_Vtbl)).Properties)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestMessage", "RequestUri", 12usize, |this| ((**(this as *const *const IHttpRequestMessage_Vtbl)).RequestUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestMessage", "SetRequestUri", 13usize, |this| ((**(this as *const *const IHttpRequestMessage_Vtbl)).SetRequestUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransportInformation(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestMessage", "TransportInformation", 14usize, |this| ((**(this as *const *const IHttpRequestMessage_Vtbl)).TransportInformation)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Content,
+            SetContent,
+            #[cfg(feature = "Web_Http_Headers")]
+            Headers,
+            #[cfg(not(feature = "Web_Http_Headers"))]
+            Headers: 0,
+            Method,
+            SetMethod,
+            #[cfg(feature = "Foundation_Collections")]
+            Properties,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Properties: 0,
+            RequestUri,
+            SetRequestUri,
+            TransportInformation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpRequestMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpRequestMessage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrivacyAnnotation(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestMessage2", "PrivacyAnnotation", 6usize, |this| ((**(this as *const *const IHttpRequestMessage2_Vtbl)).PrivacyAnnotation)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivacyAnnotation(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestMessage2", "SetPrivacyAnnotation", 7usize, |this| ((**(this as *const *const IHttpRequestMessage2_Vtbl)).SetPrivacyAnnotation)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrivacyAnnotation, SetPrivacyAnnotation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpRequestMessage2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpRequestMessageFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, method: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestMessageFactory", "Create", 6usize, |this| ((**(this as *const *const IHttpRequestMessageFactory_Vtbl)).Create)(this, method, uri, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpRequestMessageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpRequestResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExtendedError(this: *mut core::ffi::c_void, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestResult", "ExtendedError", 6usize, |this| ((**(this as *const *const IHttpRequestResult_Vtbl)).ExtendedError)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestResult", "RequestMessage", 7usize, |this| ((**(this as *const *const IHttpRequestResult_Vtbl)).RequestMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestResult", "ResponseMessage", 8usize, |this| ((**(this as *const *const IHttpRequestResult_Vtbl)).ResponseMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Succeeded(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpRequestResult", "Succeeded", 9usize, |this| ((**(this as *const *const IHttpRequestResult_Vtbl)).Succeeded)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExtendedError, RequestMessage, ResponseMessage, Succeeded }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpRequestResult as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Web_Http_Headers")]
+impl windows_core::imp::ProxyVtable for IHttpResponseMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessage", "Content", 6usize, |this| ((**(this as *const *const IHttpResponseMessage_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContent(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessage", "SetContent", 7usize, |this| ((**(this as *const *const IHttpResponseMessage_Vtbl)).SetContent)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Http_Headers")]
+        unsafe extern "system" fn Headers(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessage", "Headers", 8usize, |this| ((**(this as *const *const IHttpResponseMessage_Vtbl)).Headers)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSuccessStatusCode(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessage", "IsSuccessStatusCode", 9usize, |this| ((**(this as *const *const IHttpResponseMessage_Vtbl)).IsSuccessStatusCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReasonPhrase(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessage", "ReasonPhrase", 10usize, |this| ((**(this as *const *const IHttpResponseMessage_Vtbl)).ReasonPhrase)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReasonPhrase(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessage", "SetReasonPhrase", 11usize, |this| ((**(this as *const *const IHttpResponseMessage_Vtbl)).SetReasonPhrase)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestMessage(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessage", "RequestMessage", 12usize, |this| ((**(this as *const *const IHttpResponseMessage_Vtbl)).RequestMessage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestMessage(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessage", "SetRequestMessage", 13usize, |this| ((**(this as *const *const IHttpResponseMessage_Vtbl)).SetRequestMessage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut HttpResponseMessageSource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessage", "Source", 14usize, |this| ((**(this as *const *const IHttpResponseMessage_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, value: HttpResponseMessageSource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessage", "SetSource", 15usize, |this| ((**(this as *const *const IHttpResponseMessage_Vtbl)).SetSource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, result__: *mut HttpStatusCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessage", "StatusCode", 16usize, |this| ((**(this as *const *const IHttpResponseMessage_Vtbl)).StatusCode)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatusCode(this: *mut core::ffi::c_void, value: HttpStatusCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessage", "SetStatusCode", 17usize, |this| ((**(this as *const *const IHttpResponseMessage_Vtbl)).SetStatusCode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, result__: *mut HttpVersion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessage", "Version", 18usize, |this| ((**(this as *const *const IHttpResponseMessage_Vtbl)).Version)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVersion(this: *mut core::ffi::c_void, value: HttpVersion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessage", "SetVersion", 19usize, |this| ((**(this as *const *const IHttpResponseMessage_Vtbl)).SetVersion)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnsureSuccessStatusCode(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessage", "EnsureSuccessStatusCode", 20usize, |this| ((**(this as *const *const IHttpResponseMessage_Vtbl)).EnsureSuccessStatusCode)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Content,
+            SetContent,
+            #[cfg(feature = "Web_Http_Headers")]
+            Headers,
+            #[cfg(not(feature = "Web_Http_Headers"))]
+            Headers: 0,
+            IsSuccessStatusCode,
+            ReasonPhrase,
+            SetReasonPhrase,
+            RequestMessage,
+            SetRequestMessage,
+            Source,
+            SetSource,
+            StatusCode,
+            SetStatusCode,
+            Version,
+            SetVersion,
+            EnsureSuccessStatusCode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpResponseMessage as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpResponseMessageFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, statuscode: HttpStatusCode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpResponseMessageFactory", "Create", 6usize, |this| ((**(this as *const *const IHttpResponseMessageFactory_Vtbl)).Create)(this, statuscode, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpResponseMessageFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IHttpStreamContentFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromInputStream(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpStreamContentFactory", "CreateFromInputStream", 6usize, |this| ((**(this as *const *const IHttpStreamContentFactory_Vtbl)).CreateFromInputStream)(this, content, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromInputStream,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromInputStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpStreamContentFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IHttpStringContentFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromString(this: *mut core::ffi::c_void, content: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpStringContentFactory", "CreateFromString", 6usize, |this| ((**(this as *const *const IHttpStringContentFactory_Vtbl)).CreateFromString)(this, content, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromStringWithEncoding(this: *mut core::ffi::c_void, content: core::mem::MaybeUninit<windows_core::HSTRING>, encoding: super::super::Storage::Streams::UnicodeEncoding, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpStringContentFactory", "CreateFromStringWithEncoding", 7usize, |this| ((**(this as *const *const IHttpStringContentFactory_Vtbl)).CreateFromStringWithEncoding)(this, content, encoding, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CreateFromStringWithEncodingAndMediaType(this: *mut core::ffi::c_void, content: core::mem::MaybeUninit<windows_core::HSTRING>, encoding: super::super::Storage::Streams::UnicodeEncoding, mediatype: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpStringContentFactory", "CreateFromStringWithEncodingAndMediaType", 8usize, |this| ((**(this as *const *const IHttpStringContentFactory_Vtbl)).CreateFromStringWithEncodingAndMediaType)(this, content, encoding, mediatype, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFromString,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromStringWithEncoding,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromStringWithEncoding: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CreateFromStringWithEncodingAndMediaType,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CreateFromStringWithEncodingAndMediaType: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpStringContentFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Collections", feature = "Networking_Sockets", feature = "Security_Cryptography_Certificates"))]
+impl windows_core::imp::ProxyVtable for IHttpTransportInformation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Cryptography_Certificates")]
+        unsafe extern "system" fn ServerCertificate(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpTransportInformation", "ServerCertificate", 6usize, |this| ((**(this as *const *const IHttpTransportInformation_Vtbl)).ServerCertificate)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Networking_Sockets")]
+        unsafe extern "system" fn ServerCertificateErrorSeverity(this: *mut core::ffi::c_void, result__: *mut super::super::Networking::Sockets::SocketSslErrorSeverity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpTransportInformation", "ServerCertificateErrorSeverity", 7usize, |this| ((**(this as *const *const IHttpTransportInformation_Vtbl)).ServerCertificateErrorSeverity)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn ServerCertificateErrors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpTransportInformation", "ServerCertificateErrors", 8usize, |this| ((**(this as *const *const IHttpTransportInformation_Vtbl)).ServerCertificateErrors)(this, result__), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+        unsafe extern "system" fn ServerIntermediateCertificates(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpTransportInformation", "ServerIntermediateCertificates", 9usize, |this| ((**(this as *const *const IHttpTransportInformation_Vtbl)).ServerIntermediateCertificates)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Cryptography_Certificates")]
+            ServerCertificate,
+            #[cfg(not(feature = "Security_Cryptography_Certificates"))]
+            ServerCertificate: 0,
+            #[cfg(feature = "Networking_Sockets")]
+            ServerCertificateErrorSeverity,
+            #[cfg(not(feature = "Networking_Sockets"))]
+            ServerCertificateErrorSeverity: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            ServerCertificateErrors,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            ServerCertificateErrors: 0,
+            #[cfg(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates"))]
+            ServerIntermediateCertificates,
+            #[cfg(not(all(feature = "Foundation_Collections", feature = "Security_Cryptography_Certificates")))]
+            ServerIntermediateCertificates: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpTransportInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
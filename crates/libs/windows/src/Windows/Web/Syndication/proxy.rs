@@ -0,0 +1,845 @@
+impl windows_core::imp::ProxyVtable for ISyndicationAttribute_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationAttribute", "Name", 6usize, |this| ((**(this as *const *const ISyndicationAttribute_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationAttribute", "SetName", 7usize, |this| ((**(this as *const *const ISyndicationAttribute_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Namespace(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationAttribute", "Namespace", 8usize, |this| ((**(this as *const *const ISyndicationAttribute_Vtbl)).Namespace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNamespace(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationAttribute", "SetNamespace", 9usize, |this| ((**(this as *const *const ISyndicationAttribute_Vtbl)).SetNamespace)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationAttribute", "Value", 10usize, |this| ((**(this as *const *const ISyndicationAttribute_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationAttribute", "SetValue", 11usize, |this| ((**(this as *const *const ISyndicationAttribute_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, SetName, Namespace, SetNamespace, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationAttribute as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyndicationAttributeFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSyndicationAttribute(this: *mut core::ffi::c_void, attributename: core::mem::MaybeUninit<windows_core::HSTRING>, attributenamespace: core::mem::MaybeUninit<windows_core::HSTRING>, attributevalue: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationAttributeFactory", "CreateSyndicationAttribute", 6usize, |this| ((**(this as *const *const ISyndicationAttributeFactory_Vtbl)).CreateSyndicationAttribute)(this, attributename, attributenamespace, attributevalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSyndicationAttribute }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationAttributeFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for ISyndicationCategory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationCategory", "Label", 6usize, |this| ((**(this as *const *const ISyndicationCategory_Vtbl)).Label)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationCategory", "SetLabel", 7usize, |this| ((**(this as *const *const ISyndicationCategory_Vtbl)).SetLabel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scheme(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationCategory", "Scheme", 8usize, |this| ((**(this as *const *const ISyndicationCategory_Vtbl)).Scheme)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScheme(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationCategory", "SetScheme", 9usize, |this| ((**(this as *const *const ISyndicationCategory_Vtbl)).SetScheme)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Term(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationCategory", "Term", 10usize, |this| ((**(this as *const *const ISyndicationCategory_Vtbl)).Term)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTerm(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationCategory", "SetTerm", 11usize, |this| ((**(this as *const *const ISyndicationCategory_Vtbl)).SetTerm)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Label, SetLabel, Scheme, SetScheme, Term, SetTerm }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationCategory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyndicationCategoryFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSyndicationCategory(this: *mut core::ffi::c_void, term: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationCategoryFactory", "CreateSyndicationCategory", 6usize, |this| ((**(this as *const *const ISyndicationCategoryFactory_Vtbl)).CreateSyndicationCategory)(this, term, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSyndicationCategoryEx(this: *mut core::ffi::c_void, term: core::mem::MaybeUninit<windows_core::HSTRING>, scheme: core::mem::MaybeUninit<windows_core::HSTRING>, label: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationCategoryFactory", "CreateSyndicationCategoryEx", 7usize, |this| ((**(this as *const *const ISyndicationCategoryFactory_Vtbl)).CreateSyndicationCategoryEx)(this, term, scheme, label, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSyndicationCategory, CreateSyndicationCategoryEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationCategoryFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for ISyndicationClient_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn ServerCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationClient", "ServerCredential", 6usize, |this| ((**(this as *const *const ISyndicationClient_Vtbl)).ServerCredential)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetServerCredential(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationClient", "SetServerCredential", 7usize, |this| ((**(this as *const *const ISyndicationClient_Vtbl)).SetServerCredential)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn ProxyCredential(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationClient", "ProxyCredential", 8usize, |this| ((**(this as *const *const ISyndicationClient_Vtbl)).ProxyCredential)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn SetProxyCredential(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationClient", "SetProxyCredential", 9usize, |this| ((**(this as *const *const ISyndicationClient_Vtbl)).SetProxyCredential)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxResponseBufferSize(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationClient", "MaxResponseBufferSize", 10usize, |this| ((**(this as *const *const ISyndicationClient_Vtbl)).MaxResponseBufferSize)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxResponseBufferSize(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationClient", "SetMaxResponseBufferSize", 11usize, |this| ((**(this as *const *const ISyndicationClient_Vtbl)).SetMaxResponseBufferSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timeout(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationClient", "Timeout", 12usize, |this| ((**(this as *const *const ISyndicationClient_Vtbl)).Timeout)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimeout(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationClient", "SetTimeout", 13usize, |this| ((**(this as *const *const ISyndicationClient_Vtbl)).SetTimeout)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BypassCacheOnRetrieve(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationClient", "BypassCacheOnRetrieve", 14usize, |this| ((**(this as *const *const ISyndicationClient_Vtbl)).BypassCacheOnRetrieve)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBypassCacheOnRetrieve(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationClient", "SetBypassCacheOnRetrieve", 15usize, |this| ((**(this as *const *const ISyndicationClient_Vtbl)).SetBypassCacheOnRetrieve)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestHeader(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationClient", "SetRequestHeader", 16usize, |this| ((**(this as *const *const ISyndicationClient_Vtbl)).SetRequestHeader)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetrieveFeedAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationClient", "RetrieveFeedAsync", 17usize, |this| ((**(this as *const *const ISyndicationClient_Vtbl)).RetrieveFeedAsync)(this, uri, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            ServerCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            ServerCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetServerCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetServerCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            ProxyCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            ProxyCredential: 0,
+            #[cfg(feature = "Security_Credentials")]
+            SetProxyCredential,
+            #[cfg(not(feature = "Security_Credentials"))]
+            SetProxyCredential: 0,
+            MaxResponseBufferSize,
+            SetMaxResponseBufferSize,
+            Timeout,
+            SetTimeout,
+            BypassCacheOnRetrieve,
+            SetBypassCacheOnRetrieve,
+            SetRequestHeader,
+            RetrieveFeedAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationClient as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Security_Credentials")]
+impl windows_core::imp::ProxyVtable for ISyndicationClientFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Security_Credentials")]
+        unsafe extern "system" fn CreateSyndicationClient(this: *mut core::ffi::c_void, servercredential: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationClientFactory", "CreateSyndicationClient", 6usize, |this| ((**(this as *const *const ISyndicationClientFactory_Vtbl)).CreateSyndicationClient)(this, servercredential, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Security_Credentials")]
+            CreateSyndicationClient,
+            #[cfg(not(feature = "Security_Credentials"))]
+            CreateSyndicationClient: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationClientFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for ISyndicationContent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SourceUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationContent", "SourceUri", 6usize, |this| ((**(this as *const *const ISyndicationContent_Vtbl)).SourceUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationContent", "SetSourceUri", 7usize, |this| ((**(this as *const *const ISyndicationContent_Vtbl)).SetSourceUri)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SourceUri, SetSourceUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationContent as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyndicationContentFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSyndicationContent(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, r#type: SyndicationTextType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationContentFactory", "CreateSyndicationContent", 6usize, |this| ((**(this as *const *const ISyndicationContentFactory_Vtbl)).CreateSyndicationContent)(this, text, r#type, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSyndicationContentWithSourceUri(this: *mut core::ffi::c_void, sourceuri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationContentFactory", "CreateSyndicationContentWithSourceUri", 7usize, |this| ((**(this as *const *const ISyndicationContentFactory_Vtbl)).CreateSyndicationContentWithSourceUri)(this, sourceuri, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateSyndicationContent,
+            CreateSyndicationContentWithSourceUri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationContentFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyndicationErrorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, hresult: i32, result__: *mut SyndicationErrorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationErrorStatics", "GetStatus", 6usize, |this| ((**(this as *const *const ISyndicationErrorStatics_Vtbl)).GetStatus)(this, hresult, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationErrorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for ISyndicationFeed_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Authors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "Authors", 6usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).Authors)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Categories(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "Categories", 7usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).Categories)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Contributors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "Contributors", 8usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).Contributors)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Generator(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "Generator", 9usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).Generator)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGenerator(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "SetGenerator", 10usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).SetGenerator)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IconUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "IconUri", 11usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).IconUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIconUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "SetIconUri", 12usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).SetIconUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "Id", 13usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "SetId", 14usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Items(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "Items", 15usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).Items)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastUpdatedTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "LastUpdatedTime", 16usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).LastUpdatedTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastUpdatedTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "SetLastUpdatedTime", 17usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).SetLastUpdatedTime)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Links(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "Links", 18usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).Links)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImageUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "ImageUri", 19usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).ImageUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetImageUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "SetImageUri", 20usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).SetImageUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rights(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "Rights", 21usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).Rights)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRights(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "SetRights", 22usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).SetRights)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subtitle(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "Subtitle", 23usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).Subtitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubtitle(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "SetSubtitle", 24usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).SetSubtitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "Title", 25usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "SetTitle", 26usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "FirstUri", 27usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).FirstUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "LastUri", 28usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).LastUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "NextUri", 29usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).NextUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreviousUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "PreviousUri", 30usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).PreviousUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceFormat(this: *mut core::ffi::c_void, result__: *mut SyndicationFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "SourceFormat", 31usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).SourceFormat)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, feed: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "Load", 32usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).Load)(this, feed), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn LoadFromXml(this: *mut core::ffi::c_void, feeddocument: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeed", "LoadFromXml", 33usize, |this| ((**(this as *const *const ISyndicationFeed_Vtbl)).LoadFromXml)(this, feeddocument), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Authors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Authors: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Categories,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Categories: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Contributors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Contributors: 0,
+            Generator,
+            SetGenerator,
+            IconUri,
+            SetIconUri,
+            Id,
+            SetId,
+            #[cfg(feature = "Foundation_Collections")]
+            Items,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Items: 0,
+            LastUpdatedTime,
+            SetLastUpdatedTime,
+            #[cfg(feature = "Foundation_Collections")]
+            Links,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Links: 0,
+            ImageUri,
+            SetImageUri,
+            Rights,
+            SetRights,
+            Subtitle,
+            SetSubtitle,
+            Title,
+            SetTitle,
+            FirstUri,
+            LastUri,
+            NextUri,
+            PreviousUri,
+            SourceFormat,
+            Load,
+            #[cfg(feature = "Data_Xml_Dom")]
+            LoadFromXml,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            LoadFromXml: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationFeed as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyndicationFeedFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSyndicationFeed(this: *mut core::ffi::c_void, title: core::mem::MaybeUninit<windows_core::HSTRING>, subtitle: core::mem::MaybeUninit<windows_core::HSTRING>, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationFeedFactory", "CreateSyndicationFeed", 6usize, |this| ((**(this as *const *const ISyndicationFeedFactory_Vtbl)).CreateSyndicationFeed)(this, title, subtitle, uri, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSyndicationFeed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationFeedFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyndicationGenerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationGenerator", "Text", 6usize, |this| ((**(this as *const *const ISyndicationGenerator_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationGenerator", "SetText", 7usize, |this| ((**(this as *const *const ISyndicationGenerator_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationGenerator", "Uri", 8usize, |this| ((**(this as *const *const ISyndicationGenerator_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationGenerator", "SetUri", 9usize, |this| ((**(this as *const *const ISyndicationGenerator_Vtbl)).SetUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationGenerator", "Version", 10usize, |this| ((**(this as *const *const ISyndicationGenerator_Vtbl)).Version)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVersion(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationGenerator", "SetVersion", 11usize, |this| ((**(this as *const *const ISyndicationGenerator_Vtbl)).SetVersion)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Text, SetText, Uri, SetUri, Version, SetVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationGenerator as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyndicationGeneratorFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSyndicationGenerator(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationGeneratorFactory", "CreateSyndicationGenerator", 6usize, |this| ((**(this as *const *const ISyndicationGeneratorFactory_Vtbl)).CreateSyndicationGenerator)(this, text, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSyndicationGenerator }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationGeneratorFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for ISyndicationItem_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Authors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "Authors", 6usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).Authors)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Categories(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "Categories", 7usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).Categories)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Contributors(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "Contributors", 8usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).Contributors)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "Content", 9usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).Content)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContent(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "SetContent", 10usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).SetContent)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "Id", 11usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "SetId", 12usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).SetId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastUpdatedTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "LastUpdatedTime", 13usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).LastUpdatedTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastUpdatedTime(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "SetLastUpdatedTime", 14usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).SetLastUpdatedTime)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn Links(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "Links", 15usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).Links)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublishedDate(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "PublishedDate", 16usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).PublishedDate)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPublishedDate(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "SetPublishedDate", 17usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).SetPublishedDate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rights(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "Rights", 18usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).Rights)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRights(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "SetRights", 19usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).SetRights)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "Source", 20usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "SetSource", 21usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).SetSource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Summary(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "Summary", 22usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).Summary)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSummary(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "SetSummary", 23usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).SetSummary)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "Title", 24usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "SetTitle", 25usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommentsUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "CommentsUri", 26usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).CommentsUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCommentsUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "SetCommentsUri", 27usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).SetCommentsUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EditUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "EditUri", 28usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).EditUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EditMediaUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "EditMediaUri", 29usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).EditMediaUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ETag(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "ETag", 30usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).ETag)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "ItemUri", 31usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).ItemUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, item: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "Load", 32usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).Load)(this, item), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn LoadFromXml(this: *mut core::ffi::c_void, itemdocument: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItem", "LoadFromXml", 33usize, |this| ((**(this as *const *const ISyndicationItem_Vtbl)).LoadFromXml)(this, itemdocument), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Collections")]
+            Authors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Authors: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Categories,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Categories: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            Contributors,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Contributors: 0,
+            Content,
+            SetContent,
+            Id,
+            SetId,
+            LastUpdatedTime,
+            SetLastUpdatedTime,
+            #[cfg(feature = "Foundation_Collections")]
+            Links,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            Links: 0,
+            PublishedDate,
+            SetPublishedDate,
+            Rights,
+            SetRights,
+            Source,
+            SetSource,
+            Summary,
+            SetSummary,
+            Title,
+            SetTitle,
+            CommentsUri,
+            SetCommentsUri,
+            EditUri,
+            EditMediaUri,
+            ETag,
+            ItemUri,
+            Load,
+            #[cfg(feature = "Data_Xml_Dom")]
+            LoadFromXml,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            LoadFromXml: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationItem as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyndicationItemFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSyndicationItem(this: *mut core::ffi::c_void, title: core::mem::MaybeUninit<windows_core::HSTRING>, content: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationItemFactory", "CreateSyndicationItem", 6usize, |this| ((**(this as *const *const ISyndicationItemFactory_Vtbl)).CreateSyndicationItem)(this, title, content, uri, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSyndicationItem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationItemFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for ISyndicationLink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationLink", "Length", 6usize, |this| ((**(this as *const *const ISyndicationLink_Vtbl)).Length)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLength(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationLink", "SetLength", 7usize, |this| ((**(this as *const *const ISyndicationLink_Vtbl)).SetLength)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationLink", "MediaType", 8usize, |this| ((**(this as *const *const ISyndicationLink_Vtbl)).MediaType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaType(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationLink", "SetMediaType", 9usize, |this| ((**(this as *const *const ISyndicationLink_Vtbl)).SetMediaType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Relationship(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationLink", "Relationship", 10usize, |this| ((**(this as *const *const ISyndicationLink_Vtbl)).Relationship)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRelationship(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationLink", "SetRelationship", 11usize, |this| ((**(this as *const *const ISyndicationLink_Vtbl)).SetRelationship)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationLink", "Title", 12usize, |this| ((**(this as *const *const ISyndicationLink_Vtbl)).Title)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationLink", "SetTitle", 13usize, |this| ((**(this as *const *const ISyndicationLink_Vtbl)).SetTitle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationLink", "Uri", 14usize, |this| ((**(this as *const *const ISyndicationLink_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationLink", "SetUri", 15usize, |this| ((**(this as *const *const ISyndicationLink_Vtbl)).SetUri)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceLanguage(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationLink", "ResourceLanguage", 16usize, |this| ((**(this as *const *const ISyndicationLink_Vtbl)).ResourceLanguage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResourceLanguage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationLink", "SetResourceLanguage", 17usize, |this| ((**(this as *const *const ISyndicationLink_Vtbl)).SetResourceLanguage)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Length,
+            SetLength,
+            MediaType,
+            SetMediaType,
+            Relationship,
+            SetRelationship,
+            Title,
+            SetTitle,
+            Uri,
+            SetUri,
+            ResourceLanguage,
+            SetResourceLanguage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationLink as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyndicationLinkFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSyndicationLink(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationLinkFactory", "CreateSyndicationLink", 6usize, |this| ((**(this as *const *const ISyndicationLinkFactory_Vtbl)).CreateSyndicationLink)(this, uri, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSyndicationLinkEx(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, relationship: core::mem::MaybeUninit<windows_core::HSTRING>, title: core::mem::MaybeUninit<windows_core::HSTRING>, mediatype: core::mem::MaybeUninit<windows_core::HSTRING>, length: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationLinkFactory", "CreateSyndicationLinkEx", 7usize, |this| ((**(this as *const *const ISyndicationLinkFactory_Vtbl)).CreateSyndicationLinkEx)(this, uri, relationship, title, mediatype, length, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSyndicationLink, CreateSyndicationLinkEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationLinkFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for ISyndicationNode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NodeName(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationNode", "NodeName", 6usize, |this| ((**(this as *const *const ISyndicationNode_Vtbl)).NodeName)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNodeName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationNode", "SetNodeName", 7usize, |this| ((**(this as *const *const ISyndicationNode_Vtbl)).SetNodeName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NodeNamespace(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationNode", "NodeNamespace", 8usize, |this| ((**(this as *const *const ISyndicationNode_Vtbl)).NodeNamespace)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNodeNamespace(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationNode", "SetNodeNamespace", 9usize, |this| ((**(this as *const *const ISyndicationNode_Vtbl)).SetNodeNamespace)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NodeValue(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationNode", "NodeValue", 10usize, |this| ((**(this as *const *const ISyndicationNode_Vtbl)).NodeValue)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNodeValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationNode", "SetNodeValue", 11usize, |this| ((**(this as *const *const ISyndicationNode_Vtbl)).SetNodeValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationNode", "Language", 12usize, |this| ((**(this as *const *const ISyndicationNode_Vtbl)).Language)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationNode", "SetLanguage", 13usize, |this| ((**(this as *const *const ISyndicationNode_Vtbl)).SetLanguage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BaseUri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationNode", "BaseUri", 14usize, |this| ((**(this as *const *const ISyndicationNode_Vtbl)).BaseUri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBaseUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationNode", "SetBaseUri", 15usize, |this| ((**(this as *const *const ISyndicationNode_Vtbl)).SetBaseUri)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn AttributeExtensions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationNode", "AttributeExtensions", 16usize, |this| ((**(this as *const *const ISyndicationNode_Vtbl)).AttributeExtensions)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn ElementExtensions(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationNode", "ElementExtensions", 17usize, |this| ((**(this as *const *const ISyndicationNode_Vtbl)).ElementExtensions)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn GetXmlDocument(this: *mut core::ffi::c_void, format: SyndicationFormat, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationNode", "GetXmlDocument", 18usize, |this| ((**(this as *const *const ISyndicationNode_Vtbl)).GetXmlDocument)(this, format, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NodeName,
+            SetNodeName,
+            NodeNamespace,
+            SetNodeNamespace,
+            NodeValue,
+            SetNodeValue,
+            Language,
+            SetLanguage,
+            BaseUri,
+            SetBaseUri,
+            #[cfg(feature = "Foundation_Collections")]
+            AttributeExtensions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            AttributeExtensions: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            ElementExtensions,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            ElementExtensions: 0,
+            #[cfg(feature = "Data_Xml_Dom")]
+            GetXmlDocument,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            GetXmlDocument: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationNode as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyndicationNodeFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSyndicationNode(this: *mut core::ffi::c_void, nodename: core::mem::MaybeUninit<windows_core::HSTRING>, nodenamespace: core::mem::MaybeUninit<windows_core::HSTRING>, nodevalue: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationNodeFactory", "CreateSyndicationNode", 6usize, |this| ((**(this as *const *const ISyndicationNodeFactory_Vtbl)).CreateSyndicationNode)(this, nodename, nodenamespace, nodevalue, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSyndicationNode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationNodeFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for ISyndicationPerson_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Email(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationPerson", "Email", 6usize, |this| ((**(this as *const *const ISyndicationPerson_Vtbl)).Email)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEmail(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationPerson", "SetEmail", 7usize, |this| ((**(this as *const *const ISyndicationPerson_Vtbl)).SetEmail)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationPerson", "Name", 8usize, |this| ((**(this as *const *const ISyndicationPerson_Vtbl)).Name)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationPerson", "SetName", 9usize, |this| ((**(this as *const *const ISyndicationPerson_Vtbl)).SetName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationPerson", "Uri", 10usize, |this| ((**(this as *const *const ISyndicationPerson_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUri(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationPerson", "SetUri", 11usize, |this| ((**(this as *const *const ISyndicationPerson_Vtbl)).SetUri)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Email, SetEmail, Name, SetName, Uri, SetUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationPerson as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyndicationPersonFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSyndicationPerson(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationPersonFactory", "CreateSyndicationPerson", 6usize, |this| ((**(this as *const *const ISyndicationPersonFactory_Vtbl)).CreateSyndicationPerson)(this, name, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSyndicationPersonEx(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::HSTRING>, email: core::mem::MaybeUninit<windows_core::HSTRING>, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationPersonFactory", "CreateSyndicationPersonEx", 7usize, |this| ((**(this as *const *const ISyndicationPersonFactory_Vtbl)).CreateSyndicationPersonEx)(this, name, email, uri, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSyndicationPerson, CreateSyndicationPersonEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationPersonFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections"))]
+impl windows_core::imp::ProxyVtable for ISyndicationText_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationText", "Text", 6usize, |this| ((**(this as *const *const ISyndicationText_Vtbl)).Text)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationText", "SetText", 7usize, |this| ((**(this as *const *const ISyndicationText_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationText", "Type", 8usize, |this| ((**(this as *const *const ISyndicationText_Vtbl)).Type)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationText", "SetType", 9usize, |this| ((**(this as *const *const ISyndicationText_Vtbl)).SetType)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn Xml(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationText", "Xml", 10usize, |this| ((**(this as *const *const ISyndicationText_Vtbl)).Xml)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Data_Xml_Dom")]
+        unsafe extern "system" fn SetXml(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationText", "SetXml", 11usize, |this| ((**(this as *const *const ISyndicationText_Vtbl)).SetXml)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Text,
+            SetText,
+            Type,
+            SetType,
+            #[cfg(feature = "Data_Xml_Dom")]
+            Xml,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            Xml: 0,
+            #[cfg(feature = "Data_Xml_Dom")]
+            SetXml,
+            #[cfg(not(feature = "Data_Xml_Dom"))]
+            SetXml: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationText as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyndicationTextFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSyndicationText(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationTextFactory", "CreateSyndicationText", 6usize, |this| ((**(this as *const *const ISyndicationTextFactory_Vtbl)).CreateSyndicationText)(this, text, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSyndicationTextEx(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>, r#type: SyndicationTextType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyndicationTextFactory", "CreateSyndicationTextEx", 7usize, |this| ((**(this as *const *const ISyndicationTextFactory_Vtbl)).CreateSyndicationTextEx)(this, text, r#type, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSyndicationText, CreateSyndicationTextEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyndicationTextFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
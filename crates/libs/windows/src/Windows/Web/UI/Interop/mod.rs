@@ -847,6 +847,7 @@ impl WebViewControlAcceleratorKeyRoutingStage {
 impl windows_core::TypeKind for WebViewControlAcceleratorKeyRoutingStage {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebViewControlAcceleratorKeyRoutingStage {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebViewControlAcceleratorKeyRoutingStage").field(&self.0).finish()
@@ -866,6 +867,7 @@ impl WebViewControlMoveFocusReason {
 impl windows_core::TypeKind for WebViewControlMoveFocusReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebViewControlMoveFocusReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebViewControlMoveFocusReason").field(&self.0).finish()
@@ -885,6 +887,7 @@ impl WebViewControlProcessCapabilityState {
 impl windows_core::TypeKind for WebViewControlProcessCapabilityState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebViewControlProcessCapabilityState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebViewControlProcessCapabilityState").field(&self.0).finish()
@@ -893,3 +896,5 @@ impl core::fmt::Debug for WebViewControlProcessCapabilityState {
 impl windows_core::RuntimeType for WebViewControlProcessCapabilityState {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::from_slice(b"enum(Windows.Web.UI.Interop.WebViewControlProcessCapabilityState;i4)");
 }
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,231 @@
+#[cfg(all(feature = "System", feature = "UI_Core"))]
+impl windows_core::imp::ProxyVtable for IWebViewControlAcceleratorKeyPressedEventArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn EventType(this: *mut core::ffi::c_void, result__: *mut super::super::super::UI::Core::CoreAcceleratorKeyEventType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlAcceleratorKeyPressedEventArgs", "EventType", 6usize, |this| ((**(this as *const *const IWebViewControlAcceleratorKeyPressedEventArgs_Vtbl)).EventType)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "System")]
+        unsafe extern "system" fn VirtualKey(this: *mut core::ffi::c_void, result__: *mut super::super::super::System::VirtualKey) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlAcceleratorKeyPressedEventArgs", "VirtualKey", 7usize, |this| ((**(this as *const *const IWebViewControlAcceleratorKeyPressedEventArgs_Vtbl)).VirtualKey)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Core")]
+        unsafe extern "system" fn KeyStatus(this: *mut core::ffi::c_void, result__: *mut super::super::super::UI::Core::CorePhysicalKeyStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlAcceleratorKeyPressedEventArgs", "KeyStatus", 8usize, |this| ((**(this as *const *const IWebViewControlAcceleratorKeyPressedEventArgs_Vtbl)).KeyStatus)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoutingStage(this: *mut core::ffi::c_void, result__: *mut WebViewControlAcceleratorKeyRoutingStage) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlAcceleratorKeyPressedEventArgs", "RoutingStage", 9usize, |this| ((**(this as *const *const IWebViewControlAcceleratorKeyPressedEventArgs_Vtbl)).RoutingStage)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlAcceleratorKeyPressedEventArgs", "Handled", 10usize, |this| ((**(this as *const *const IWebViewControlAcceleratorKeyPressedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlAcceleratorKeyPressedEventArgs", "SetHandled", 11usize, |this| ((**(this as *const *const IWebViewControlAcceleratorKeyPressedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Core")]
+            EventType,
+            #[cfg(not(feature = "UI_Core"))]
+            EventType: 0,
+            #[cfg(feature = "System")]
+            VirtualKey,
+            #[cfg(not(feature = "System"))]
+            VirtualKey: 0,
+            #[cfg(feature = "UI_Core")]
+            KeyStatus,
+            #[cfg(not(feature = "UI_Core"))]
+            KeyStatus: 0,
+            RoutingStage,
+            Handled,
+            SetHandled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlAcceleratorKeyPressedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlMoveFocusRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, result__: *mut WebViewControlMoveFocusReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlMoveFocusRequestedEventArgs", "Reason", 6usize, |this| ((**(this as *const *const IWebViewControlMoveFocusRequestedEventArgs_Vtbl)).Reason)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlMoveFocusRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Collections")]
+impl windows_core::imp::ProxyVtable for IWebViewControlProcess_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProcessId(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlProcess", "ProcessId", 6usize, |this| ((**(this as *const *const IWebViewControlProcess_Vtbl)).ProcessId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnterpriseId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlProcess", "EnterpriseId", 7usize, |this| ((**(this as *const *const IWebViewControlProcess_Vtbl)).EnterpriseId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPrivateNetworkClientServerCapabilityEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlProcess", "IsPrivateNetworkClientServerCapabilityEnabled", 8usize, |this| ((**(this as *const *const IWebViewControlProcess_Vtbl)).IsPrivateNetworkClientServerCapabilityEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateWebViewControlAsync(this: *mut core::ffi::c_void, hostwindowhandle: i64, bounds: super::super::super::Foundation::Rect, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlProcess", "CreateWebViewControlAsync", 9usize, |this| ((**(this as *const *const IWebViewControlProcess_Vtbl)).CreateWebViewControlAsync)(this, hostwindowhandle, bounds, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn GetWebViewControls(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlProcess", "GetWebViewControls", 10usize, |this| ((**(this as *const *const IWebViewControlProcess_Vtbl)).GetWebViewControls)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlProcess", "Terminate", 11usize, |this| ((**(this as *const *const IWebViewControlProcess_Vtbl)).Terminate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessExited(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlProcess", "ProcessExited", 12usize, |this| ((**(this as *const *const IWebViewControlProcess_Vtbl)).ProcessExited)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProcessExited(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlProcess", "RemoveProcessExited", 13usize, |this| ((**(this as *const *const IWebViewControlProcess_Vtbl)).RemoveProcessExited)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProcessId,
+            EnterpriseId,
+            IsPrivateNetworkClientServerCapabilityEnabled,
+            CreateWebViewControlAsync,
+            #[cfg(feature = "Foundation_Collections")]
+            GetWebViewControls,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            GetWebViewControls: 0,
+            Terminate,
+            ProcessExited,
+            RemoveProcessExited,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlProcess as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlProcessFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWithOptions(this: *mut core::ffi::c_void, processoptions: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlProcessFactory", "CreateWithOptions", 6usize, |this| ((**(this as *const *const IWebViewControlProcessFactory_Vtbl)).CreateWithOptions)(this, processoptions, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWithOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlProcessFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlProcessOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEnterpriseId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlProcessOptions", "SetEnterpriseId", 6usize, |this| ((**(this as *const *const IWebViewControlProcessOptions_Vtbl)).SetEnterpriseId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnterpriseId(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlProcessOptions", "EnterpriseId", 7usize, |this| ((**(this as *const *const IWebViewControlProcessOptions_Vtbl)).EnterpriseId)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateNetworkClientServerCapability(this: *mut core::ffi::c_void, value: WebViewControlProcessCapabilityState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlProcessOptions", "SetPrivateNetworkClientServerCapability", 8usize, |this| ((**(this as *const *const IWebViewControlProcessOptions_Vtbl)).SetPrivateNetworkClientServerCapability)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivateNetworkClientServerCapability(this: *mut core::ffi::c_void, result__: *mut WebViewControlProcessCapabilityState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlProcessOptions", "PrivateNetworkClientServerCapability", 9usize, |this| ((**(this as *const *const IWebViewControlProcessOptions_Vtbl)).PrivateNetworkClientServerCapability)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetEnterpriseId,
+            EnterpriseId,
+            SetPrivateNetworkClientServerCapability,
+            PrivateNetworkClientServerCapability,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlProcessOptions as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Process(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite", "Process", 6usize, |this| ((**(this as *const *const IWebViewControlSite_Vtbl)).Process)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScale(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite", "SetScale", 7usize, |this| ((**(this as *const *const IWebViewControlSite_Vtbl)).SetScale)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scale(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite", "Scale", 8usize, |this| ((**(this as *const *const IWebViewControlSite_Vtbl)).Scale)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBounds(this: *mut core::ffi::c_void, value: super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite", "SetBounds", 9usize, |this| ((**(this as *const *const IWebViewControlSite_Vtbl)).SetBounds)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bounds(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite", "Bounds", 10usize, |this| ((**(this as *const *const IWebViewControlSite_Vtbl)).Bounds)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsVisible(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite", "SetIsVisible", 11usize, |this| ((**(this as *const *const IWebViewControlSite_Vtbl)).SetIsVisible)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVisible(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite", "IsVisible", 12usize, |this| ((**(this as *const *const IWebViewControlSite_Vtbl)).IsVisible)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite", "Close", 13usize, |this| ((**(this as *const *const IWebViewControlSite_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveFocus(this: *mut core::ffi::c_void, reason: WebViewControlMoveFocusReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite", "MoveFocus", 14usize, |this| ((**(this as *const *const IWebViewControlSite_Vtbl)).MoveFocus)(this, reason), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveFocusRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite", "MoveFocusRequested", 15usize, |this| ((**(this as *const *const IWebViewControlSite_Vtbl)).MoveFocusRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMoveFocusRequested(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite", "RemoveMoveFocusRequested", 16usize, |this| ((**(this as *const *const IWebViewControlSite_Vtbl)).RemoveMoveFocusRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceleratorKeyPressed(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite", "AcceleratorKeyPressed", 17usize, |this| ((**(this as *const *const IWebViewControlSite_Vtbl)).AcceleratorKeyPressed)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAcceleratorKeyPressed(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite", "RemoveAcceleratorKeyPressed", 18usize, |this| ((**(this as *const *const IWebViewControlSite_Vtbl)).RemoveAcceleratorKeyPressed)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Process,
+            SetScale,
+            Scale,
+            SetBounds,
+            Bounds,
+            SetIsVisible,
+            IsVisible,
+            Close,
+            MoveFocus,
+            MoveFocusRequested,
+            RemoveMoveFocusRequested,
+            AcceleratorKeyPressed,
+            RemoveAcceleratorKeyPressed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlSite as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlSite2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GotFocus(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite2", "GotFocus", 6usize, |this| ((**(this as *const *const IWebViewControlSite2_Vtbl)).GotFocus)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGotFocus(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite2", "RemoveGotFocus", 7usize, |this| ((**(this as *const *const IWebViewControlSite2_Vtbl)).RemoveGotFocus)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn LostFocus(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite2", "LostFocus", 8usize, |this| ((**(this as *const *const IWebViewControlSite2_Vtbl)).LostFocus)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLostFocus(this: *mut core::ffi::c_void, token: super::super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSite2", "RemoveLostFocus", 9usize, |this| ((**(this as *const *const IWebViewControlSite2_Vtbl)).RemoveLostFocus)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GotFocus, RemoveGotFocus, LostFocus, RemoveLostFocus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlSite2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
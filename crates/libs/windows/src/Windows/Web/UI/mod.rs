@@ -1233,6 +1233,7 @@ impl WebViewControlPermissionState {
 impl windows_core::TypeKind for WebViewControlPermissionState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebViewControlPermissionState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebViewControlPermissionState").field(&self.0).finish()
@@ -1256,6 +1257,7 @@ impl WebViewControlPermissionType {
 impl windows_core::TypeKind for WebViewControlPermissionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebViewControlPermissionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebViewControlPermissionType").field(&self.0).finish()
@@ -1266,3 +1268,5 @@ impl windows_core::RuntimeType for WebViewControlPermissionType {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,594 @@
+#[cfg(all(feature = "ApplicationModel_DataTransfer", feature = "Foundation_Collections", feature = "Storage_Streams", feature = "UI", feature = "Web_Http"))]
+impl windows_core::imp::ProxyVtable for IWebViewControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "Source", 6usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).Source)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "SetSource", 7usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).SetSource)(this, source), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentTitle(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "DocumentTitle", 8usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).DocumentTitle)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanGoBack(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "CanGoBack", 9usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).CanGoBack)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanGoForward(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "CanGoForward", 10usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).CanGoForward)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn SetDefaultBackgroundColor(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "SetDefaultBackgroundColor", 11usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).SetDefaultBackgroundColor)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "UI")]
+        unsafe extern "system" fn DefaultBackgroundColor(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "DefaultBackgroundColor", 12usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).DefaultBackgroundColor)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainsFullScreenElement(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "ContainsFullScreenElement", 13usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).ContainsFullScreenElement)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Settings(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "Settings", 14usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).Settings)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn DeferredPermissionRequests(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "DeferredPermissionRequests", 15usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).DeferredPermissionRequests)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GoForward(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "GoForward", 16usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).GoForward)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GoBack(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "GoBack", 17usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).GoBack)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "Refresh", 18usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "Stop", 19usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Navigate(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "Navigate", 20usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).Navigate)(this, source), |result| Some(*result))
+        }
+        unsafe extern "system" fn NavigateToString(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "NavigateToString", 21usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).NavigateToString)(this, text), |result| Some(*result))
+        }
+        unsafe extern "system" fn NavigateToLocalStreamUri(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, streamresolver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "NavigateToLocalStreamUri", 22usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).NavigateToLocalStreamUri)(this, source, streamresolver), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Http")]
+        unsafe extern "system" fn NavigateWithHttpRequestMessage(this: *mut core::ffi::c_void, requestmessage: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "NavigateWithHttpRequestMessage", 23usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).NavigateWithHttpRequestMessage)(this, requestmessage), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Collections")]
+        unsafe extern "system" fn InvokeScriptAsync(this: *mut core::ffi::c_void, scriptname: core::mem::MaybeUninit<windows_core::HSTRING>, arguments: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "InvokeScriptAsync", 24usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).InvokeScriptAsync)(this, scriptname, arguments, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn CapturePreviewToStreamAsync(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "CapturePreviewToStreamAsync", 25usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).CapturePreviewToStreamAsync)(this, stream, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "ApplicationModel_DataTransfer")]
+        unsafe extern "system" fn CaptureSelectedContentToDataPackageAsync(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "CaptureSelectedContentToDataPackageAsync", 26usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).CaptureSelectedContentToDataPackageAsync)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuildLocalStreamUri(this: *mut core::ffi::c_void, contentidentifier: core::mem::MaybeUninit<windows_core::HSTRING>, relativepath: core::mem::MaybeUninit<windows_core::HSTRING>, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "BuildLocalStreamUri", 27usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).BuildLocalStreamUri)(this, contentidentifier, relativepath, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferredPermissionRequestById(this: *mut core::ffi::c_void, id: u32, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "GetDeferredPermissionRequestById", 28usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).GetDeferredPermissionRequestById)(this, id, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn NavigationStarting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "NavigationStarting", 29usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).NavigationStarting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNavigationStarting(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveNavigationStarting", 30usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveNavigationStarting)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentLoading(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "ContentLoading", 31usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).ContentLoading)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveContentLoading(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveContentLoading", 32usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveContentLoading)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn DOMContentLoaded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "DOMContentLoaded", 33usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).DOMContentLoaded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDOMContentLoaded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveDOMContentLoaded", 34usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveDOMContentLoaded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn NavigationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "NavigationCompleted", 35usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).NavigationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNavigationCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveNavigationCompleted", 36usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveNavigationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameNavigationStarting(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "FrameNavigationStarting", 37usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).FrameNavigationStarting)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFrameNavigationStarting(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveFrameNavigationStarting", 38usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveFrameNavigationStarting)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameContentLoading(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "FrameContentLoading", 39usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).FrameContentLoading)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFrameContentLoading(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveFrameContentLoading", 40usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveFrameContentLoading)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameDOMContentLoaded(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "FrameDOMContentLoaded", 41usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).FrameDOMContentLoaded)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFrameDOMContentLoaded(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveFrameDOMContentLoaded", 42usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveFrameDOMContentLoaded)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameNavigationCompleted(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "FrameNavigationCompleted", 43usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).FrameNavigationCompleted)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFrameNavigationCompleted(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveFrameNavigationCompleted", 44usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveFrameNavigationCompleted)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScriptNotify(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "ScriptNotify", 45usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).ScriptNotify)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveScriptNotify(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveScriptNotify", 46usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveScriptNotify)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn LongRunningScriptDetected(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "LongRunningScriptDetected", 47usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).LongRunningScriptDetected)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLongRunningScriptDetected(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveLongRunningScriptDetected", 48usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveLongRunningScriptDetected)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnsafeContentWarningDisplaying(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "UnsafeContentWarningDisplaying", 49usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).UnsafeContentWarningDisplaying)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUnsafeContentWarningDisplaying(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveUnsafeContentWarningDisplaying", 50usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveUnsafeContentWarningDisplaying)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnviewableContentIdentified(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "UnviewableContentIdentified", 51usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).UnviewableContentIdentified)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUnviewableContentIdentified(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveUnviewableContentIdentified", 52usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveUnviewableContentIdentified)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermissionRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "PermissionRequested", 53usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).PermissionRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePermissionRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemovePermissionRequested", 54usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemovePermissionRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnsupportedUriSchemeIdentified(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "UnsupportedUriSchemeIdentified", 55usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).UnsupportedUriSchemeIdentified)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveUnsupportedUriSchemeIdentified(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveUnsupportedUriSchemeIdentified", 56usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveUnsupportedUriSchemeIdentified)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewWindowRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "NewWindowRequested", 57usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).NewWindowRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNewWindowRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveNewWindowRequested", 58usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveNewWindowRequested)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainsFullScreenElementChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "ContainsFullScreenElementChanged", 59usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).ContainsFullScreenElementChanged)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveContainsFullScreenElementChanged(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveContainsFullScreenElementChanged", 60usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveContainsFullScreenElementChanged)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn WebResourceRequested(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "WebResourceRequested", 61usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).WebResourceRequested)(this, handler, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveWebResourceRequested(this: *mut core::ffi::c_void, token: super::super::Foundation::EventRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl", "RemoveWebResourceRequested", 62usize, |this| ((**(this as *const *const IWebViewControl_Vtbl)).RemoveWebResourceRequested)(this, token), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Source,
+            SetSource,
+            DocumentTitle,
+            CanGoBack,
+            CanGoForward,
+            #[cfg(feature = "UI")]
+            SetDefaultBackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            SetDefaultBackgroundColor: 0,
+            #[cfg(feature = "UI")]
+            DefaultBackgroundColor,
+            #[cfg(not(feature = "UI"))]
+            DefaultBackgroundColor: 0,
+            ContainsFullScreenElement,
+            Settings,
+            #[cfg(feature = "Foundation_Collections")]
+            DeferredPermissionRequests,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            DeferredPermissionRequests: 0,
+            GoForward,
+            GoBack,
+            Refresh,
+            Stop,
+            Navigate,
+            NavigateToString,
+            NavigateToLocalStreamUri,
+            #[cfg(feature = "Web_Http")]
+            NavigateWithHttpRequestMessage,
+            #[cfg(not(feature = "Web_Http"))]
+            NavigateWithHttpRequestMessage: 0,
+            #[cfg(feature = "Foundation_Collections")]
+            InvokeScriptAsync,
+            #[cfg(not(feature = "Foundation_Collections"))]
+            InvokeScriptAsync: 0,
+            #[cfg(feature = "Storage_Streams")]
+            CapturePreviewToStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            CapturePreviewToStreamAsync: 0,
+            #[cfg(feature = "ApplicationModel_DataTransfer")]
+            CaptureSelectedContentToDataPackageAsync,
+            #[cfg(not(feature = "ApplicationModel_DataTransfer"))]
+            CaptureSelectedContentToDataPackageAsync: 0,
+            BuildLocalStreamUri,
+            GetDeferredPermissionRequestById,
+            NavigationStarting,
+            RemoveNavigationStarting,
+            ContentLoading,
+            RemoveContentLoading,
+            DOMContentLoaded,
+            RemoveDOMContentLoaded,
+            NavigationCompleted,
+            RemoveNavigationCompleted,
+            FrameNavigationStarting,
+            RemoveFrameNavigationStarting,
+            FrameContentLoading,
+            RemoveFrameContentLoading,
+            FrameDOMContentLoaded,
+            RemoveFrameDOMContentLoaded,
+            FrameNavigationCompleted,
+            RemoveFrameNavigationCompleted,
+            ScriptNotify,
+            RemoveScriptNotify,
+            LongRunningScriptDetected,
+            RemoveLongRunningScriptDetected,
+            UnsafeContentWarningDisplaying,
+            RemoveUnsafeContentWarningDisplaying,
+            UnviewableContentIdentified,
+            RemoveUnviewableContentIdentified,
+            PermissionRequested,
+            RemovePermissionRequested,
+            UnsupportedUriSchemeIdentified,
+            RemoveUnsupportedUriSchemeIdentified,
+            NewWindowRequested,
+            RemoveNewWindowRequested,
+            ContainsFullScreenElementChanged,
+            RemoveContainsFullScreenElementChanged,
+            WebResourceRequested,
+            RemoveWebResourceRequested,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControl as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddInitializeScript(this: *mut core::ffi::c_void, script: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControl2", "AddInitializeScript", 6usize, |this| ((**(this as *const *const IWebViewControl2_Vtbl)).AddInitializeScript)(this, script), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddInitializeScript }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControl2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlContentLoadingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlContentLoadingEventArgs", "Uri", 6usize, |this| ((**(this as *const *const IWebViewControlContentLoadingEventArgs_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlContentLoadingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlDOMContentLoadedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlDOMContentLoadedEventArgs", "Uri", 6usize, |this| ((**(this as *const *const IWebViewControlDOMContentLoadedEventArgs_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlDOMContentLoadedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlDeferredPermissionRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlDeferredPermissionRequest", "Id", 6usize, |this| ((**(this as *const *const IWebViewControlDeferredPermissionRequest_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlDeferredPermissionRequest", "Uri", 7usize, |this| ((**(this as *const *const IWebViewControlDeferredPermissionRequest_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermissionType(this: *mut core::ffi::c_void, result__: *mut WebViewControlPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlDeferredPermissionRequest", "PermissionType", 8usize, |this| ((**(this as *const *const IWebViewControlDeferredPermissionRequest_Vtbl)).PermissionType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Allow(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlDeferredPermissionRequest", "Allow", 9usize, |this| ((**(this as *const *const IWebViewControlDeferredPermissionRequest_Vtbl)).Allow)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deny(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlDeferredPermissionRequest", "Deny", 10usize, |this| ((**(this as *const *const IWebViewControlDeferredPermissionRequest_Vtbl)).Deny)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Uri, PermissionType, Allow, Deny }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlDeferredPermissionRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlLongRunningScriptDetectedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExecutionTime(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlLongRunningScriptDetectedEventArgs", "ExecutionTime", 6usize, |this| ((**(this as *const *const IWebViewControlLongRunningScriptDetectedEventArgs_Vtbl)).ExecutionTime)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopPageScriptExecution(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlLongRunningScriptDetectedEventArgs", "StopPageScriptExecution", 7usize, |this| ((**(this as *const *const IWebViewControlLongRunningScriptDetectedEventArgs_Vtbl)).StopPageScriptExecution)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStopPageScriptExecution(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlLongRunningScriptDetectedEventArgs", "SetStopPageScriptExecution", 8usize, |this| ((**(this as *const *const IWebViewControlLongRunningScriptDetectedEventArgs_Vtbl)).SetStopPageScriptExecution)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ExecutionTime,
+            StopPageScriptExecution,
+            SetStopPageScriptExecution,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlLongRunningScriptDetectedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlNavigationCompletedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlNavigationCompletedEventArgs", "Uri", 6usize, |this| ((**(this as *const *const IWebViewControlNavigationCompletedEventArgs_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSuccess(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlNavigationCompletedEventArgs", "IsSuccess", 7usize, |this| ((**(this as *const *const IWebViewControlNavigationCompletedEventArgs_Vtbl)).IsSuccess)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn WebErrorStatus(this: *mut core::ffi::c_void, result__: *mut super::WebErrorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlNavigationCompletedEventArgs", "WebErrorStatus", 8usize, |this| ((**(this as *const *const IWebViewControlNavigationCompletedEventArgs_Vtbl)).WebErrorStatus)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uri, IsSuccess, WebErrorStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlNavigationCompletedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlNavigationStartingEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlNavigationStartingEventArgs", "Uri", 6usize, |this| ((**(this as *const *const IWebViewControlNavigationStartingEventArgs_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlNavigationStartingEventArgs", "Cancel", 7usize, |this| ((**(this as *const *const IWebViewControlNavigationStartingEventArgs_Vtbl)).Cancel)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCancel(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlNavigationStartingEventArgs", "SetCancel", 8usize, |this| ((**(this as *const *const IWebViewControlNavigationStartingEventArgs_Vtbl)).SetCancel)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uri, Cancel, SetCancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlNavigationStartingEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlNewWindowRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlNewWindowRequestedEventArgs", "Uri", 6usize, |this| ((**(this as *const *const IWebViewControlNewWindowRequestedEventArgs_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Referrer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlNewWindowRequestedEventArgs", "Referrer", 7usize, |this| ((**(this as *const *const IWebViewControlNewWindowRequestedEventArgs_Vtbl)).Referrer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlNewWindowRequestedEventArgs", "Handled", 8usize, |this| ((**(this as *const *const IWebViewControlNewWindowRequestedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlNewWindowRequestedEventArgs", "SetHandled", 9usize, |this| ((**(this as *const *const IWebViewControlNewWindowRequestedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uri, Referrer, Handled, SetHandled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlNewWindowRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlNewWindowRequestedEventArgs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NewWindow(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlNewWindowRequestedEventArgs2", "NewWindow", 6usize, |this| ((**(this as *const *const IWebViewControlNewWindowRequestedEventArgs2_Vtbl)).NewWindow)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNewWindow(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlNewWindowRequestedEventArgs2", "SetNewWindow", 7usize, |this| ((**(this as *const *const IWebViewControlNewWindowRequestedEventArgs2_Vtbl)).SetNewWindow)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlNewWindowRequestedEventArgs2", "GetDeferral", 8usize, |this| ((**(this as *const *const IWebViewControlNewWindowRequestedEventArgs2_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NewWindow, SetNewWindow, GetDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlNewWindowRequestedEventArgs2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlPermissionRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlPermissionRequest", "Id", 6usize, |this| ((**(this as *const *const IWebViewControlPermissionRequest_Vtbl)).Id)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlPermissionRequest", "Uri", 7usize, |this| ((**(this as *const *const IWebViewControlPermissionRequest_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermissionType(this: *mut core::ffi::c_void, result__: *mut WebViewControlPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlPermissionRequest", "PermissionType", 8usize, |this| ((**(this as *const *const IWebViewControlPermissionRequest_Vtbl)).PermissionType)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, result__: *mut WebViewControlPermissionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlPermissionRequest", "State", 9usize, |this| ((**(this as *const *const IWebViewControlPermissionRequest_Vtbl)).State)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Defer(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlPermissionRequest", "Defer", 10usize, |this| ((**(this as *const *const IWebViewControlPermissionRequest_Vtbl)).Defer)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Allow(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlPermissionRequest", "Allow", 11usize, |this| ((**(this as *const *const IWebViewControlPermissionRequest_Vtbl)).Allow)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deny(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlPermissionRequest", "Deny", 12usize, |this| ((**(this as *const *const IWebViewControlPermissionRequest_Vtbl)).Deny)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Uri, PermissionType, State, Defer, Allow, Deny }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlPermissionRequest as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlPermissionRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PermissionRequest(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlPermissionRequestedEventArgs", "PermissionRequest", 6usize, |this| ((**(this as *const *const IWebViewControlPermissionRequestedEventArgs_Vtbl)).PermissionRequest)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PermissionRequest }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlPermissionRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlScriptNotifyEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlScriptNotifyEventArgs", "Uri", 6usize, |this| ((**(this as *const *const IWebViewControlScriptNotifyEventArgs_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlScriptNotifyEventArgs", "Value", 7usize, |this| ((**(this as *const *const IWebViewControlScriptNotifyEventArgs_Vtbl)).Value)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uri, Value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlScriptNotifyEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIsJavaScriptEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSettings", "SetIsJavaScriptEnabled", 6usize, |this| ((**(this as *const *const IWebViewControlSettings_Vtbl)).SetIsJavaScriptEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsJavaScriptEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSettings", "IsJavaScriptEnabled", 7usize, |this| ((**(this as *const *const IWebViewControlSettings_Vtbl)).IsJavaScriptEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsIndexedDBEnabled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSettings", "SetIsIndexedDBEnabled", 8usize, |this| ((**(this as *const *const IWebViewControlSettings_Vtbl)).SetIsIndexedDBEnabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIndexedDBEnabled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSettings", "IsIndexedDBEnabled", 9usize, |this| ((**(this as *const *const IWebViewControlSettings_Vtbl)).IsIndexedDBEnabled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsScriptNotifyAllowed(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSettings", "SetIsScriptNotifyAllowed", 10usize, |this| ((**(this as *const *const IWebViewControlSettings_Vtbl)).SetIsScriptNotifyAllowed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsScriptNotifyAllowed(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlSettings", "IsScriptNotifyAllowed", 11usize, |this| ((**(this as *const *const IWebViewControlSettings_Vtbl)).IsScriptNotifyAllowed)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetIsJavaScriptEnabled,
+            IsJavaScriptEnabled,
+            SetIsIndexedDBEnabled,
+            IsIndexedDBEnabled,
+            SetIsScriptNotifyAllowed,
+            IsScriptNotifyAllowed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlSettings as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlUnsupportedUriSchemeIdentifiedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlUnsupportedUriSchemeIdentifiedEventArgs", "Uri", 6usize, |this| ((**(this as *const *const IWebViewControlUnsupportedUriSchemeIdentifiedEventArgs_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handled(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlUnsupportedUriSchemeIdentifiedEventArgs", "Handled", 7usize, |this| ((**(this as *const *const IWebViewControlUnsupportedUriSchemeIdentifiedEventArgs_Vtbl)).Handled)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandled(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlUnsupportedUriSchemeIdentifiedEventArgs", "SetHandled", 8usize, |this| ((**(this as *const *const IWebViewControlUnsupportedUriSchemeIdentifiedEventArgs_Vtbl)).SetHandled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uri, Handled, SetHandled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlUnsupportedUriSchemeIdentifiedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebViewControlUnviewableContentIdentifiedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Uri(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlUnviewableContentIdentifiedEventArgs", "Uri", 6usize, |this| ((**(this as *const *const IWebViewControlUnviewableContentIdentifiedEventArgs_Vtbl)).Uri)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn Referrer(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlUnviewableContentIdentifiedEventArgs", "Referrer", 7usize, |this| ((**(this as *const *const IWebViewControlUnviewableContentIdentifiedEventArgs_Vtbl)).Referrer)(this, result__), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaType(this: *mut core::ffi::c_void, result__: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlUnviewableContentIdentifiedEventArgs", "MediaType", 8usize, |this| ((**(this as *const *const IWebViewControlUnviewableContentIdentifiedEventArgs_Vtbl)).MediaType)(this, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Uri, Referrer, MediaType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlUnviewableContentIdentifiedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Web_Http")]
+impl windows_core::imp::ProxyVtable for IWebViewControlWebResourceRequestedEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferral(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlWebResourceRequestedEventArgs", "GetDeferral", 6usize, |this| ((**(this as *const *const IWebViewControlWebResourceRequestedEventArgs_Vtbl)).GetDeferral)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Http")]
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlWebResourceRequestedEventArgs", "Request", 7usize, |this| ((**(this as *const *const IWebViewControlWebResourceRequestedEventArgs_Vtbl)).Request)(this, result__), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Http")]
+        unsafe extern "system" fn SetResponse(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlWebResourceRequestedEventArgs", "SetResponse", 8usize, |this| ((**(this as *const *const IWebViewControlWebResourceRequestedEventArgs_Vtbl)).SetResponse)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Web_Http")]
+        unsafe extern "system" fn Response(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebViewControlWebResourceRequestedEventArgs", "Response", 9usize, |this| ((**(this as *const *const IWebViewControlWebResourceRequestedEventArgs_Vtbl)).Response)(this, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeferral,
+            #[cfg(feature = "Web_Http")]
+            Request,
+            #[cfg(not(feature = "Web_Http"))]
+            Request: 0,
+            #[cfg(feature = "Web_Http")]
+            SetResponse,
+            #[cfg(not(feature = "Web_Http"))]
+            SetResponse: 0,
+            #[cfg(feature = "Web_Http")]
+            Response,
+            #[cfg(not(feature = "Web_Http"))]
+            Response: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebViewControlWebResourceRequestedEventArgs as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -127,6 +127,7 @@ impl WebErrorStatus {
 impl windows_core::TypeKind for WebErrorStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebErrorStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebErrorStatus").field(&self.0).finish()
@@ -137,3 +138,5 @@ impl windows_core::RuntimeType for WebErrorStatus {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,32 @@
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::imp::ProxyVtable for IUriToStreamResolver_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Storage_Streams")]
+        unsafe extern "system" fn UriToStreamAsync(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriToStreamResolver", "UriToStreamAsync", 6usize, |this| ((**(this as *const *const IUriToStreamResolver_Vtbl)).UriToStreamAsync)(this, uri, result__), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Storage_Streams")]
+            UriToStreamAsync,
+            #[cfg(not(feature = "Storage_Streams"))]
+            UriToStreamAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUriToStreamResolver as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebErrorStatics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, hresult: i32, result__: *mut WebErrorStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebErrorStatics", "GetStatus", 6usize, |this| ((**(this as *const *const IWebErrorStatics_Vtbl)).GetStatus)(this, hresult, result__), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebErrorStatics as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
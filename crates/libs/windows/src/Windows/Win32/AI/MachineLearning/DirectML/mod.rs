@@ -583,6 +583,7 @@ pub struct DML_AXIS_DIRECTION(pub i32);
 impl windows_core::TypeKind for DML_AXIS_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_AXIS_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_AXIS_DIRECTION").field(&self.0).finish()
@@ -594,6 +595,7 @@ pub struct DML_BINDING_TYPE(pub i32);
 impl windows_core::TypeKind for DML_BINDING_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_BINDING_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_BINDING_TYPE").field(&self.0).finish()
@@ -605,6 +607,7 @@ pub struct DML_CONVOLUTION_DIRECTION(pub i32);
 impl windows_core::TypeKind for DML_CONVOLUTION_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_CONVOLUTION_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_CONVOLUTION_DIRECTION").field(&self.0).finish()
@@ -616,6 +619,7 @@ pub struct DML_CONVOLUTION_MODE(pub i32);
 impl windows_core::TypeKind for DML_CONVOLUTION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_CONVOLUTION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_CONVOLUTION_MODE").field(&self.0).finish()
@@ -627,6 +631,7 @@ pub struct DML_CREATE_DEVICE_FLAGS(pub i32);
 impl windows_core::TypeKind for DML_CREATE_DEVICE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_CREATE_DEVICE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_CREATE_DEVICE_FLAGS").field(&self.0).finish()
@@ -671,6 +676,7 @@ pub struct DML_DEPTH_SPACE_ORDER(pub i32);
 impl windows_core::TypeKind for DML_DEPTH_SPACE_ORDER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_DEPTH_SPACE_ORDER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_DEPTH_SPACE_ORDER").field(&self.0).finish()
@@ -682,6 +688,7 @@ pub struct DML_EXECUTION_FLAGS(pub i32);
 impl windows_core::TypeKind for DML_EXECUTION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_EXECUTION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_EXECUTION_FLAGS").field(&self.0).finish()
@@ -726,6 +733,7 @@ pub struct DML_FEATURE(pub i32);
 impl windows_core::TypeKind for DML_FEATURE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_FEATURE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_FEATURE").field(&self.0).finish()
@@ -737,6 +745,7 @@ pub struct DML_FEATURE_LEVEL(pub i32);
 impl windows_core::TypeKind for DML_FEATURE_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_FEATURE_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_FEATURE_LEVEL").field(&self.0).finish()
@@ -748,6 +757,7 @@ pub struct DML_GRAPH_EDGE_TYPE(pub i32);
 impl windows_core::TypeKind for DML_GRAPH_EDGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_GRAPH_EDGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_GRAPH_EDGE_TYPE").field(&self.0).finish()
@@ -759,6 +769,7 @@ pub struct DML_GRAPH_NODE_TYPE(pub i32);
 impl windows_core::TypeKind for DML_GRAPH_NODE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_GRAPH_NODE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_GRAPH_NODE_TYPE").field(&self.0).finish()
@@ -770,6 +781,7 @@ pub struct DML_INTERPOLATION_MODE(pub i32);
 impl windows_core::TypeKind for DML_INTERPOLATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_INTERPOLATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_INTERPOLATION_MODE").field(&self.0).finish()
@@ -781,6 +793,7 @@ pub struct DML_IS_INFINITY_MODE(pub i32);
 impl windows_core::TypeKind for DML_IS_INFINITY_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_IS_INFINITY_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_IS_INFINITY_MODE").field(&self.0).finish()
@@ -792,6 +805,7 @@ pub struct DML_MATRIX_TRANSFORM(pub i32);
 impl windows_core::TypeKind for DML_MATRIX_TRANSFORM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_MATRIX_TRANSFORM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_MATRIX_TRANSFORM").field(&self.0).finish()
@@ -803,6 +817,7 @@ pub struct DML_OPERATOR_TYPE(pub i32);
 impl windows_core::TypeKind for DML_OPERATOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_OPERATOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_OPERATOR_TYPE").field(&self.0).finish()
@@ -814,6 +829,7 @@ pub struct DML_PADDING_MODE(pub i32);
 impl windows_core::TypeKind for DML_PADDING_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_PADDING_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_PADDING_MODE").field(&self.0).finish()
@@ -825,6 +841,7 @@ pub struct DML_RANDOM_GENERATOR_TYPE(pub i32);
 impl windows_core::TypeKind for DML_RANDOM_GENERATOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_RANDOM_GENERATOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_RANDOM_GENERATOR_TYPE").field(&self.0).finish()
@@ -836,6 +853,7 @@ pub struct DML_RECURRENT_NETWORK_DIRECTION(pub i32);
 impl windows_core::TypeKind for DML_RECURRENT_NETWORK_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_RECURRENT_NETWORK_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_RECURRENT_NETWORK_DIRECTION").field(&self.0).finish()
@@ -847,6 +865,7 @@ pub struct DML_REDUCE_FUNCTION(pub i32);
 impl windows_core::TypeKind for DML_REDUCE_FUNCTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_REDUCE_FUNCTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_REDUCE_FUNCTION").field(&self.0).finish()
@@ -858,6 +877,7 @@ pub struct DML_ROUNDING_MODE(pub i32);
 impl windows_core::TypeKind for DML_ROUNDING_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_ROUNDING_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_ROUNDING_MODE").field(&self.0).finish()
@@ -869,6 +889,7 @@ pub struct DML_TENSOR_DATA_TYPE(pub i32);
 impl windows_core::TypeKind for DML_TENSOR_DATA_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_TENSOR_DATA_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_TENSOR_DATA_TYPE").field(&self.0).finish()
@@ -880,6 +901,7 @@ pub struct DML_TENSOR_FLAGS(pub i32);
 impl windows_core::TypeKind for DML_TENSOR_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_TENSOR_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_TENSOR_FLAGS").field(&self.0).finish()
@@ -924,13 +946,15 @@ pub struct DML_TENSOR_TYPE(pub i32);
 impl windows_core::TypeKind for DML_TENSOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DML_TENSOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DML_TENSOR_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_CELU_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -945,7 +969,8 @@ impl Default for DML_ACTIVATION_CELU_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_ELU_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -960,7 +985,8 @@ impl Default for DML_ACTIVATION_ELU_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_HARDMAX_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -974,7 +1000,8 @@ impl Default for DML_ACTIVATION_HARDMAX_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_HARD_SIGMOID_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -990,7 +1017,8 @@ impl Default for DML_ACTIVATION_HARD_SIGMOID_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_IDENTITY_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1004,7 +1032,8 @@ impl Default for DML_ACTIVATION_IDENTITY_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_LEAKY_RELU_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1019,7 +1048,8 @@ impl Default for DML_ACTIVATION_LEAKY_RELU_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_LINEAR_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1035,7 +1065,8 @@ impl Default for DML_ACTIVATION_LINEAR_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_LOG_SOFTMAX_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1049,7 +1080,8 @@ impl Default for DML_ACTIVATION_LOG_SOFTMAX_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_PARAMETERIZED_RELU_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub SlopeTensor: *const DML_TENSOR_DESC,
@@ -1064,7 +1096,8 @@ impl Default for DML_ACTIVATION_PARAMETERIZED_RELU_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_PARAMETRIC_SOFTPLUS_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1080,7 +1113,8 @@ impl Default for DML_ACTIVATION_PARAMETRIC_SOFTPLUS_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_RELU_GRAD_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub InputGradientTensor: *const DML_TENSOR_DESC,
@@ -1095,7 +1129,8 @@ impl Default for DML_ACTIVATION_RELU_GRAD_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_RELU_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1109,7 +1144,8 @@ impl Default for DML_ACTIVATION_RELU_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_SCALED_ELU_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1125,7 +1161,8 @@ impl Default for DML_ACTIVATION_SCALED_ELU_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_SCALED_TANH_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1141,7 +1178,8 @@ impl Default for DML_ACTIVATION_SCALED_TANH_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_SHRINK_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1157,7 +1195,8 @@ impl Default for DML_ACTIVATION_SHRINK_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_SIGMOID_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1171,7 +1210,8 @@ impl Default for DML_ACTIVATION_SIGMOID_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_SOFTMAX_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1185,7 +1225,8 @@ impl Default for DML_ACTIVATION_SOFTMAX_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_SOFTPLUS_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1200,7 +1241,8 @@ impl Default for DML_ACTIVATION_SOFTPLUS_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_SOFTSIGN_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1214,7 +1256,8 @@ impl Default for DML_ACTIVATION_SOFTSIGN_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_TANH_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1228,7 +1271,8 @@ impl Default for DML_ACTIVATION_TANH_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ACTIVATION_THRESHOLDED_RELU_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1243,7 +1287,8 @@ impl Default for DML_ACTIVATION_THRESHOLDED_RELU_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ADAM_OPTIMIZER_OPERATOR_DESC {
     pub InputParametersTensor: *const DML_TENSOR_DESC,
     pub InputFirstMomentTensor: *const DML_TENSOR_DESC,
@@ -1267,7 +1312,8 @@ impl Default for DML_ADAM_OPTIMIZER_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ARGMAX_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1284,7 +1330,8 @@ impl Default for DML_ARGMAX_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ARGMIN_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1301,7 +1348,8 @@ impl Default for DML_ARGMIN_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_AVERAGE_POOLING_GRAD_OPERATOR_DESC {
     pub InputGradientTensor: *const DML_TENSOR_DESC,
     pub OutputGradientTensor: *const DML_TENSOR_DESC,
@@ -1321,7 +1369,8 @@ impl Default for DML_AVERAGE_POOLING_GRAD_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_AVERAGE_POOLING_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1341,7 +1390,8 @@ impl Default for DML_AVERAGE_POOLING_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_BATCH_NORMALIZATION_GRAD_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub InputGradientTensor: *const DML_TENSOR_DESC,
@@ -1362,7 +1412,8 @@ impl Default for DML_BATCH_NORMALIZATION_GRAD_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_BATCH_NORMALIZATION_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub MeanTensor: *const DML_TENSOR_DESC,
@@ -1383,7 +1434,8 @@ impl Default for DML_BATCH_NORMALIZATION_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_BINDING_DESC {
     pub Type: DML_BINDING_TYPE,
     pub Desc: *const core::ffi::c_void,
@@ -1397,7 +1449,8 @@ impl Default for DML_BINDING_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_BINDING_PROPERTIES {
     pub RequiredDescriptorCount: u32,
     pub TemporaryResourceSize: u64,
@@ -1413,7 +1466,8 @@ impl Default for DML_BINDING_PROPERTIES {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_BINDING_TABLE_DESC {
     pub Dispatchable: core::mem::ManuallyDrop<Option<IDMLDispatchable>>,
     pub CPUDescriptorHandle: super::super::super::Graphics::Direct3D12::D3D12_CPU_DESCRIPTOR_HANDLE,
@@ -1438,7 +1492,8 @@ impl Default for DML_BINDING_TABLE_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_BUFFER_ARRAY_BINDING {
     pub BindingCount: u32,
     pub Bindings: *const DML_BUFFER_BINDING,
@@ -1455,7 +1510,8 @@ impl Default for DML_BUFFER_ARRAY_BINDING {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_BUFFER_BINDING {
     pub Buffer: core::mem::ManuallyDrop<Option<super::super::super::Graphics::Direct3D12::ID3D12Resource>>,
     pub Offset: u64,
@@ -1478,7 +1534,8 @@ impl Default for DML_BUFFER_BINDING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_BUFFER_TENSOR_DESC {
     pub DataType: DML_TENSOR_DATA_TYPE,
     pub Flags: DML_TENSOR_FLAGS,
@@ -1497,7 +1554,8 @@ impl Default for DML_BUFFER_TENSOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_CAST_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1511,7 +1569,8 @@ impl Default for DML_CAST_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_CONVOLUTION_INTEGER_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub InputZeroPointTensor: *const DML_TENSOR_DESC,
@@ -1534,7 +1593,8 @@ impl Default for DML_CONVOLUTION_INTEGER_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_CONVOLUTION_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub FilterTensor: *const DML_TENSOR_DESC,
@@ -1560,7 +1620,8 @@ impl Default for DML_CONVOLUTION_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_CUMULATIVE_PRODUCT_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1577,7 +1638,8 @@ impl Default for DML_CUMULATIVE_PRODUCT_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_CUMULATIVE_SUMMATION_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1594,7 +1656,8 @@ impl Default for DML_CUMULATIVE_SUMMATION_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_DEPTH_TO_SPACE1_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1610,7 +1673,8 @@ impl Default for DML_DEPTH_TO_SPACE1_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_DEPTH_TO_SPACE_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1625,7 +1689,8 @@ impl Default for DML_DEPTH_TO_SPACE_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_DIAGONAL_MATRIX_OPERATOR_DESC {
     pub OutputTensor: *const DML_TENSOR_DESC,
     pub Offset: i32,
@@ -1640,7 +1705,8 @@ impl Default for DML_DIAGONAL_MATRIX_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_DYNAMIC_QUANTIZE_LINEAR_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1656,7 +1722,8 @@ impl Default for DML_DYNAMIC_QUANTIZE_LINEAR_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_ABS_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1671,7 +1738,8 @@ impl Default for DML_ELEMENT_WISE_ABS_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_ACOSH_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1686,7 +1754,8 @@ impl Default for DML_ELEMENT_WISE_ACOSH_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_ACOS_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1701,7 +1770,8 @@ impl Default for DML_ELEMENT_WISE_ACOS_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_ADD1_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -1717,7 +1787,8 @@ impl Default for DML_ELEMENT_WISE_ADD1_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_ADD_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -1732,7 +1803,8 @@ impl Default for DML_ELEMENT_WISE_ADD_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_ASINH_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1747,7 +1819,8 @@ impl Default for DML_ELEMENT_WISE_ASINH_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_ASIN_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1762,7 +1835,8 @@ impl Default for DML_ELEMENT_WISE_ASIN_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_ATANH_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1777,7 +1851,8 @@ impl Default for DML_ELEMENT_WISE_ATANH_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_ATAN_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1792,7 +1867,8 @@ impl Default for DML_ELEMENT_WISE_ATAN_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_ATAN_YX_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -1807,7 +1883,8 @@ impl Default for DML_ELEMENT_WISE_ATAN_YX_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_BIT_AND_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -1822,7 +1899,8 @@ impl Default for DML_ELEMENT_WISE_BIT_AND_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_BIT_COUNT_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1836,7 +1914,8 @@ impl Default for DML_ELEMENT_WISE_BIT_COUNT_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_BIT_NOT_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1850,7 +1929,8 @@ impl Default for DML_ELEMENT_WISE_BIT_NOT_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_BIT_OR_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -1865,7 +1945,8 @@ impl Default for DML_ELEMENT_WISE_BIT_OR_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_BIT_SHIFT_LEFT_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -1880,7 +1961,8 @@ impl Default for DML_ELEMENT_WISE_BIT_SHIFT_LEFT_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_BIT_SHIFT_RIGHT_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -1895,7 +1977,8 @@ impl Default for DML_ELEMENT_WISE_BIT_SHIFT_RIGHT_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_BIT_XOR_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -1910,7 +1993,8 @@ impl Default for DML_ELEMENT_WISE_BIT_XOR_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_CEIL_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1925,7 +2009,8 @@ impl Default for DML_ELEMENT_WISE_CEIL_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_CLIP_GRAD_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub InputGradientTensor: *const DML_TENSOR_DESC,
@@ -1942,7 +2027,8 @@ impl Default for DML_ELEMENT_WISE_CLIP_GRAD_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_CLIP_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1959,7 +2045,8 @@ impl Default for DML_ELEMENT_WISE_CLIP_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_CONSTANT_POW_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1975,7 +2062,8 @@ impl Default for DML_ELEMENT_WISE_CONSTANT_POW_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_COSH_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -1990,7 +2078,8 @@ impl Default for DML_ELEMENT_WISE_COSH_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_COS_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2005,7 +2094,8 @@ impl Default for DML_ELEMENT_WISE_COS_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_DEQUANTIZE_LINEAR_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub ScaleTensor: *const DML_TENSOR_DESC,
@@ -2021,7 +2111,8 @@ impl Default for DML_ELEMENT_WISE_DEQUANTIZE_LINEAR_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_DIFFERENCE_SQUARE_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2036,7 +2127,8 @@ impl Default for DML_ELEMENT_WISE_DIFFERENCE_SQUARE_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_DIVIDE_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2051,7 +2143,8 @@ impl Default for DML_ELEMENT_WISE_DIVIDE_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_ERF_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2066,7 +2159,8 @@ impl Default for DML_ELEMENT_WISE_ERF_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_EXP_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2081,7 +2175,8 @@ impl Default for DML_ELEMENT_WISE_EXP_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_FLOOR_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2096,7 +2191,8 @@ impl Default for DML_ELEMENT_WISE_FLOOR_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_IDENTITY_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2111,7 +2207,8 @@ impl Default for DML_ELEMENT_WISE_IDENTITY_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_IF_OPERATOR_DESC {
     pub ConditionTensor: *const DML_TENSOR_DESC,
     pub ATensor: *const DML_TENSOR_DESC,
@@ -2127,7 +2224,8 @@ impl Default for DML_ELEMENT_WISE_IF_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_IS_INFINITY_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2142,7 +2240,8 @@ impl Default for DML_ELEMENT_WISE_IS_INFINITY_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_IS_NAN_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2156,7 +2255,8 @@ impl Default for DML_ELEMENT_WISE_IS_NAN_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_LOGICAL_AND_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2171,7 +2271,8 @@ impl Default for DML_ELEMENT_WISE_LOGICAL_AND_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_LOGICAL_EQUALS_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2186,7 +2287,8 @@ impl Default for DML_ELEMENT_WISE_LOGICAL_EQUALS_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_LOGICAL_GREATER_THAN_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2201,7 +2303,8 @@ impl Default for DML_ELEMENT_WISE_LOGICAL_GREATER_THAN_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_LOGICAL_GREATER_THAN_OR_EQUAL_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2216,7 +2319,8 @@ impl Default for DML_ELEMENT_WISE_LOGICAL_GREATER_THAN_OR_EQUAL_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_LOGICAL_LESS_THAN_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2231,7 +2335,8 @@ impl Default for DML_ELEMENT_WISE_LOGICAL_LESS_THAN_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_LOGICAL_LESS_THAN_OR_EQUAL_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2246,7 +2351,8 @@ impl Default for DML_ELEMENT_WISE_LOGICAL_LESS_THAN_OR_EQUAL_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_LOGICAL_NOT_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2260,7 +2366,8 @@ impl Default for DML_ELEMENT_WISE_LOGICAL_NOT_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_LOGICAL_OR_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2275,7 +2382,8 @@ impl Default for DML_ELEMENT_WISE_LOGICAL_OR_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_LOGICAL_XOR_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2290,7 +2398,8 @@ impl Default for DML_ELEMENT_WISE_LOGICAL_XOR_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_LOG_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2305,7 +2414,8 @@ impl Default for DML_ELEMENT_WISE_LOG_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_MAX_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2320,7 +2430,8 @@ impl Default for DML_ELEMENT_WISE_MAX_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_MEAN_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2335,7 +2446,8 @@ impl Default for DML_ELEMENT_WISE_MEAN_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_MIN_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2350,7 +2462,8 @@ impl Default for DML_ELEMENT_WISE_MIN_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_MODULUS_FLOOR_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2365,7 +2478,8 @@ impl Default for DML_ELEMENT_WISE_MODULUS_FLOOR_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_MODULUS_TRUNCATE_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2380,7 +2494,8 @@ impl Default for DML_ELEMENT_WISE_MODULUS_TRUNCATE_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_MULTIPLY_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2395,7 +2510,8 @@ impl Default for DML_ELEMENT_WISE_MULTIPLY_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_POW_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub ExponentTensor: *const DML_TENSOR_DESC,
@@ -2411,7 +2527,8 @@ impl Default for DML_ELEMENT_WISE_POW_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_QUANTIZED_LINEAR_ADD_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub AScaleTensor: *const DML_TENSOR_DESC,
@@ -2432,7 +2549,8 @@ impl Default for DML_ELEMENT_WISE_QUANTIZED_LINEAR_ADD_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_QUANTIZE_LINEAR_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub ScaleTensor: *const DML_TENSOR_DESC,
@@ -2448,7 +2566,8 @@ impl Default for DML_ELEMENT_WISE_QUANTIZE_LINEAR_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_RECIP_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2463,7 +2582,8 @@ impl Default for DML_ELEMENT_WISE_RECIP_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_ROUND_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2478,7 +2598,8 @@ impl Default for DML_ELEMENT_WISE_ROUND_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_SIGN_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2492,7 +2613,8 @@ impl Default for DML_ELEMENT_WISE_SIGN_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_SINH_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2507,7 +2629,8 @@ impl Default for DML_ELEMENT_WISE_SINH_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_SIN_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2522,7 +2645,8 @@ impl Default for DML_ELEMENT_WISE_SIN_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_SQRT_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2537,7 +2661,8 @@ impl Default for DML_ELEMENT_WISE_SQRT_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_SUBTRACT_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2552,7 +2677,8 @@ impl Default for DML_ELEMENT_WISE_SUBTRACT_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_TANH_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2567,7 +2693,8 @@ impl Default for DML_ELEMENT_WISE_TANH_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_TAN_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2582,7 +2709,8 @@ impl Default for DML_ELEMENT_WISE_TAN_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ELEMENT_WISE_THRESHOLD_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2598,7 +2726,8 @@ impl Default for DML_ELEMENT_WISE_THRESHOLD_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_FEATURE_DATA_FEATURE_LEVELS {
     pub MaxSupportedFeatureLevel: DML_FEATURE_LEVEL,
 }
@@ -2611,7 +2740,8 @@ impl Default for DML_FEATURE_DATA_FEATURE_LEVELS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_FEATURE_DATA_TENSOR_DATA_TYPE_SUPPORT {
     pub IsSupported: super::super::super::Foundation::BOOL,
 }
@@ -2624,7 +2754,8 @@ impl Default for DML_FEATURE_DATA_TENSOR_DATA_TYPE_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_FEATURE_QUERY_FEATURE_LEVELS {
     pub RequestedFeatureLevelCount: u32,
     pub RequestedFeatureLevels: *const DML_FEATURE_LEVEL,
@@ -2638,7 +2769,8 @@ impl Default for DML_FEATURE_QUERY_FEATURE_LEVELS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_FEATURE_QUERY_TENSOR_DATA_TYPE_SUPPORT {
     pub DataType: DML_TENSOR_DATA_TYPE,
 }
@@ -2682,7 +2814,8 @@ impl Default for DML_FILL_VALUE_SEQUENCE_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_GATHER_ELEMENTS_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub IndicesTensor: *const DML_TENSOR_DESC,
@@ -2698,7 +2831,8 @@ impl Default for DML_GATHER_ELEMENTS_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_GATHER_ND1_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub IndicesTensor: *const DML_TENSOR_DESC,
@@ -2716,7 +2850,8 @@ impl Default for DML_GATHER_ND1_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_GATHER_ND_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub IndicesTensor: *const DML_TENSOR_DESC,
@@ -2733,7 +2868,8 @@ impl Default for DML_GATHER_ND_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_GATHER_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub IndicesTensor: *const DML_TENSOR_DESC,
@@ -2750,7 +2886,8 @@ impl Default for DML_GATHER_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_GEMM_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub BTensor: *const DML_TENSOR_DESC,
@@ -2771,7 +2908,8 @@ impl Default for DML_GEMM_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_GRAPH_DESC {
     pub InputCount: u32,
     pub OutputCount: u32,
@@ -2793,7 +2931,8 @@ impl Default for DML_GRAPH_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_GRAPH_EDGE_DESC {
     pub Type: DML_GRAPH_EDGE_TYPE,
     pub Desc: *const core::ffi::c_void,
@@ -2807,7 +2946,8 @@ impl Default for DML_GRAPH_EDGE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_GRAPH_NODE_DESC {
     pub Type: DML_GRAPH_NODE_TYPE,
     pub Desc: *const core::ffi::c_void,
@@ -2821,7 +2961,8 @@ impl Default for DML_GRAPH_NODE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_GRU_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub WeightTensor: *const DML_TENSOR_DESC,
@@ -2845,7 +2986,8 @@ impl Default for DML_GRU_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_INPUT_GRAPH_EDGE_DESC {
     pub GraphInputIndex: u32,
     pub ToNodeIndex: u32,
@@ -2861,7 +3003,8 @@ impl Default for DML_INPUT_GRAPH_EDGE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_INTERMEDIATE_GRAPH_EDGE_DESC {
     pub FromNodeIndex: u32,
     pub FromNodeOutputIndex: u32,
@@ -2878,7 +3021,8 @@ impl Default for DML_INTERMEDIATE_GRAPH_EDGE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_JOIN_OPERATOR_DESC {
     pub InputCount: u32,
     pub InputTensors: *const DML_TENSOR_DESC,
@@ -2894,7 +3038,8 @@ impl Default for DML_JOIN_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_LOCAL_RESPONSE_NORMALIZATION_GRAD_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub InputGradientTensor: *const DML_TENSOR_DESC,
@@ -2914,7 +3059,8 @@ impl Default for DML_LOCAL_RESPONSE_NORMALIZATION_GRAD_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_LOCAL_RESPONSE_NORMALIZATION_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2933,7 +3079,8 @@ impl Default for DML_LOCAL_RESPONSE_NORMALIZATION_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_LP_NORMALIZATION_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2950,7 +3097,8 @@ impl Default for DML_LP_NORMALIZATION_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_LP_POOLING_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -2970,7 +3118,8 @@ impl Default for DML_LP_POOLING_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_LSTM_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub WeightTensor: *const DML_TENSOR_DESC,
@@ -2999,7 +3148,8 @@ impl Default for DML_LSTM_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_MATRIX_MULTIPLY_INTEGER_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub AZeroPointTensor: *const DML_TENSOR_DESC,
@@ -3016,7 +3166,8 @@ impl Default for DML_MATRIX_MULTIPLY_INTEGER_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_MAX_POOLING1_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -3036,7 +3187,8 @@ impl Default for DML_MAX_POOLING1_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_MAX_POOLING2_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -3057,7 +3209,8 @@ impl Default for DML_MAX_POOLING2_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_MAX_POOLING_GRAD_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub InputGradientTensor: *const DML_TENSOR_DESC,
@@ -3078,7 +3231,8 @@ impl Default for DML_MAX_POOLING_GRAD_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_MAX_POOLING_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -3097,7 +3251,8 @@ impl Default for DML_MAX_POOLING_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_MAX_UNPOOLING_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub IndicesTensor: *const DML_TENSOR_DESC,
@@ -3112,7 +3267,8 @@ impl Default for DML_MAX_UNPOOLING_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_MEAN_VARIANCE_NORMALIZATION1_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub ScaleTensor: *const DML_TENSOR_DESC,
@@ -3133,7 +3289,8 @@ impl Default for DML_MEAN_VARIANCE_NORMALIZATION1_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_MEAN_VARIANCE_NORMALIZATION_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub ScaleTensor: *const DML_TENSOR_DESC,
@@ -3153,7 +3310,8 @@ impl Default for DML_MEAN_VARIANCE_NORMALIZATION_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_NONZERO_COORDINATES_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputCountTensor: *const DML_TENSOR_DESC,
@@ -3168,7 +3326,8 @@ impl Default for DML_NONZERO_COORDINATES_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ONE_HOT_OPERATOR_DESC {
     pub IndicesTensor: *const DML_TENSOR_DESC,
     pub ValuesTensor: *const DML_TENSOR_DESC,
@@ -3184,7 +3343,8 @@ impl Default for DML_ONE_HOT_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_OPERATOR_DESC {
     pub Type: DML_OPERATOR_TYPE,
     pub Desc: *const core::ffi::c_void,
@@ -3198,7 +3358,8 @@ impl Default for DML_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_OPERATOR_GRAPH_NODE_DESC {
     pub Operator: core::mem::ManuallyDrop<Option<IDMLOperator>>,
     pub Name: windows_core::PCSTR,
@@ -3217,7 +3378,8 @@ impl Default for DML_OPERATOR_GRAPH_NODE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_OUTPUT_GRAPH_EDGE_DESC {
     pub FromNodeIndex: u32,
     pub FromNodeOutputIndex: u32,
@@ -3233,7 +3395,8 @@ impl Default for DML_OUTPUT_GRAPH_EDGE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_PADDING_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -3252,7 +3415,8 @@ impl Default for DML_PADDING_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_QUANTIZED_LINEAR_CONVOLUTION_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub InputScaleTensor: *const DML_TENSOR_DESC,
@@ -3280,7 +3444,8 @@ impl Default for DML_QUANTIZED_LINEAR_CONVOLUTION_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_QUANTIZED_LINEAR_MATRIX_MULTIPLY_OPERATOR_DESC {
     pub ATensor: *const DML_TENSOR_DESC,
     pub AScaleTensor: *const DML_TENSOR_DESC,
@@ -3301,7 +3466,8 @@ impl Default for DML_QUANTIZED_LINEAR_MATRIX_MULTIPLY_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_RANDOM_GENERATOR_OPERATOR_DESC {
     pub InputStateTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -3317,7 +3483,8 @@ impl Default for DML_RANDOM_GENERATOR_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_REDUCE_OPERATOR_DESC {
     pub Function: DML_REDUCE_FUNCTION,
     pub InputTensor: *const DML_TENSOR_DESC,
@@ -3334,7 +3501,8 @@ impl Default for DML_REDUCE_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_RESAMPLE1_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -3353,7 +3521,8 @@ impl Default for DML_RESAMPLE1_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_RESAMPLE_GRAD_OPERATOR_DESC {
     pub InputGradientTensor: *const DML_TENSOR_DESC,
     pub OutputGradientTensor: *const DML_TENSOR_DESC,
@@ -3372,7 +3541,8 @@ impl Default for DML_RESAMPLE_GRAD_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_RESAMPLE_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -3389,7 +3559,8 @@ impl Default for DML_RESAMPLE_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_REVERSE_SUBSEQUENCES_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub SequenceLengthsTensor: *const DML_TENSOR_DESC,
@@ -3405,7 +3576,8 @@ impl Default for DML_REVERSE_SUBSEQUENCES_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_RNN_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub WeightTensor: *const DML_TENSOR_DESC,
@@ -3428,7 +3600,8 @@ impl Default for DML_RNN_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ROI_ALIGN1_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub ROITensor: *const DML_TENSOR_DESC,
@@ -3454,7 +3627,8 @@ impl Default for DML_ROI_ALIGN1_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ROI_ALIGN_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub ROITensor: *const DML_TENSOR_DESC,
@@ -3477,7 +3651,8 @@ impl Default for DML_ROI_ALIGN_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_ROI_POOLING_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub ROITensor: *const DML_TENSOR_DESC,
@@ -3517,7 +3692,8 @@ impl Default for DML_SCALAR_UNION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_SCALE_BIAS {
     pub Scale: f32,
     pub Bias: f32,
@@ -3531,7 +3707,8 @@ impl Default for DML_SCALE_BIAS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_SCATTER_ND_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub IndicesTensor: *const DML_TENSOR_DESC,
@@ -3549,7 +3726,8 @@ impl Default for DML_SCATTER_ND_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_SCATTER_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub IndicesTensor: *const DML_TENSOR_DESC,
@@ -3566,7 +3744,8 @@ impl Default for DML_SCATTER_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_SIZE_2D {
     pub Width: u32,
     pub Height: u32,
@@ -3580,7 +3759,8 @@ impl Default for DML_SIZE_2D {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_SLICE1_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -3598,7 +3778,8 @@ impl Default for DML_SLICE1_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_SLICE_GRAD_OPERATOR_DESC {
     pub InputGradientTensor: *const DML_TENSOR_DESC,
     pub OutputGradientTensor: *const DML_TENSOR_DESC,
@@ -3616,7 +3797,8 @@ impl Default for DML_SLICE_GRAD_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_SLICE_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -3634,7 +3816,8 @@ impl Default for DML_SLICE_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_SPACE_TO_DEPTH1_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -3650,7 +3833,8 @@ impl Default for DML_SPACE_TO_DEPTH1_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_SPACE_TO_DEPTH_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -3665,7 +3849,8 @@ impl Default for DML_SPACE_TO_DEPTH_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_SPLIT_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputCount: u32,
@@ -3681,7 +3866,8 @@ impl Default for DML_SPLIT_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_TENSOR_DESC {
     pub Type: DML_TENSOR_TYPE,
     pub Desc: *const core::ffi::c_void,
@@ -3695,7 +3881,8 @@ impl Default for DML_TENSOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_TILE_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -3711,7 +3898,8 @@ impl Default for DML_TILE_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_TOP_K1_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputValueTensor: *const DML_TENSOR_DESC,
@@ -3729,7 +3917,8 @@ impl Default for DML_TOP_K1_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_TOP_K_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputValueTensor: *const DML_TENSOR_DESC,
@@ -3746,7 +3935,8 @@ impl Default for DML_TOP_K_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_UPSAMPLE_2D_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -3762,7 +3952,8 @@ impl Default for DML_UPSAMPLE_2D_OPERATOR_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DML_VALUE_SCALE_2D_OPERATOR_DESC {
     pub InputTensor: *const DML_TENSOR_DESC,
     pub OutputTensor: *const DML_TENSOR_DESC,
@@ -3780,3 +3971,5 @@ impl Default for DML_VALUE_SCALE_2D_OPERATOR_DESC {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,215 @@
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for IDMLBindingTable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BindInputs(this: *mut core::ffi::c_void, bindingcount: u32, bindings: *const DML_BINDING_DESC) {
+            windows_core::imp::proxy_call(this, "IDMLBindingTable", "BindInputs", 8usize, |this| ((**(this as *const *const IDMLBindingTable_Vtbl)).BindInputs)(this, bindingcount, bindings), |_| None)
+        }
+        unsafe extern "system" fn BindOutputs(this: *mut core::ffi::c_void, bindingcount: u32, bindings: *const DML_BINDING_DESC) {
+            windows_core::imp::proxy_call(this, "IDMLBindingTable", "BindOutputs", 9usize, |this| ((**(this as *const *const IDMLBindingTable_Vtbl)).BindOutputs)(this, bindingcount, bindings), |_| None)
+        }
+        unsafe extern "system" fn BindTemporaryResource(this: *mut core::ffi::c_void, binding: *const DML_BINDING_DESC) {
+            windows_core::imp::proxy_call(this, "IDMLBindingTable", "BindTemporaryResource", 10usize, |this| ((**(this as *const *const IDMLBindingTable_Vtbl)).BindTemporaryResource)(this, binding), |_| None)
+        }
+        unsafe extern "system" fn BindPersistentResource(this: *mut core::ffi::c_void, binding: *const DML_BINDING_DESC) {
+            windows_core::imp::proxy_call(this, "IDMLBindingTable", "BindPersistentResource", 11usize, |this| ((**(this as *const *const IDMLBindingTable_Vtbl)).BindPersistentResource)(this, binding), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, desc: *const DML_BINDING_TABLE_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLBindingTable", "Reset", 12usize, |this| ((**(this as *const *const IDMLBindingTable_Vtbl)).Reset)(this, desc), |result| Some(*result))
+        }
+        Self {
+            base__: <IDMLDeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BindInputs,
+            BindOutputs,
+            BindTemporaryResource,
+            BindPersistentResource,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            Reset,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            Reset: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDMLBindingTable as windows_core::Interface>::IID || <IDMLDeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for IDMLCommandRecorder_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn RecordDispatch(this: *mut core::ffi::c_void, commandlist: *mut core::ffi::c_void, dispatchable: *mut core::ffi::c_void, bindings: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IDMLCommandRecorder", "RecordDispatch", 8usize, |this| ((**(this as *const *const IDMLCommandRecorder_Vtbl)).RecordDispatch)(this, commandlist, dispatchable, bindings), |_| None)
+        }
+        Self {
+            base__: <IDMLDeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            RecordDispatch,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            RecordDispatch: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDMLCommandRecorder as windows_core::Interface>::IID || <IDMLDeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDMLCompiledOperator_Vtbl {
+    const PROXY: Self = { Self { base__: <IDMLDispatchable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDMLCompiledOperator as windows_core::Interface>::IID || <IDMLDispatchable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDMLDebugDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMuteDebugOutput(this: *mut core::ffi::c_void, mute: super::super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "IDMLDebugDevice", "SetMuteDebugOutput", 3usize, |this| ((**(this as *const *const IDMLDebugDevice_Vtbl)).SetMuteDebugOutput)(this, mute), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMuteDebugOutput }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDMLDebugDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for IDMLDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CheckFeatureSupport(this: *mut core::ffi::c_void, feature: DML_FEATURE, featurequerydatasize: u32, featurequerydata: *const core::ffi::c_void, featuresupportdatasize: u32, featuresupportdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLDevice", "CheckFeatureSupport", 7usize, |this| ((**(this as *const *const IDMLDevice_Vtbl)).CheckFeatureSupport)(this, feature, featurequerydatasize, featurequerydata, featuresupportdatasize, featuresupportdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOperator(this: *mut core::ffi::c_void, desc: *const DML_OPERATOR_DESC, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLDevice", "CreateOperator", 8usize, |this| ((**(this as *const *const IDMLDevice_Vtbl)).CreateOperator)(this, desc, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompileOperator(this: *mut core::ffi::c_void, op: *mut core::ffi::c_void, flags: DML_EXECUTION_FLAGS, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLDevice", "CompileOperator", 9usize, |this| ((**(this as *const *const IDMLDevice_Vtbl)).CompileOperator)(this, op, flags, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOperatorInitializer(this: *mut core::ffi::c_void, operatorcount: u32, operators: *const *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLDevice", "CreateOperatorInitializer", 10usize, |this| ((**(this as *const *const IDMLDevice_Vtbl)).CreateOperatorInitializer)(this, operatorcount, operators, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCommandRecorder(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLDevice", "CreateCommandRecorder", 11usize, |this| ((**(this as *const *const IDMLDevice_Vtbl)).CreateCommandRecorder)(this, riid, ppv), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn CreateBindingTable(this: *mut core::ffi::c_void, desc: *const DML_BINDING_TABLE_DESC, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLDevice", "CreateBindingTable", 12usize, |this| ((**(this as *const *const IDMLDevice_Vtbl)).CreateBindingTable)(this, desc, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn Evict(this: *mut core::ffi::c_void, count: u32, ppobjects: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLDevice", "Evict", 13usize, |this| ((**(this as *const *const IDMLDevice_Vtbl)).Evict)(this, count, ppobjects), |result| Some(*result))
+        }
+        unsafe extern "system" fn MakeResident(this: *mut core::ffi::c_void, count: u32, ppobjects: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLDevice", "MakeResident", 14usize, |this| ((**(this as *const *const IDMLDevice_Vtbl)).MakeResident)(this, count, ppobjects), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceRemovedReason(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLDevice", "GetDeviceRemovedReason", 15usize, |this| ((**(this as *const *const IDMLDevice_Vtbl)).GetDeviceRemovedReason)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParentDevice(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLDevice", "GetParentDevice", 16usize, |this| ((**(this as *const *const IDMLDevice_Vtbl)).GetParentDevice)(this, riid, ppv), |result| Some(*result))
+        }
+        Self {
+            base__: <IDMLObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CheckFeatureSupport,
+            CreateOperator,
+            CompileOperator,
+            CreateOperatorInitializer,
+            CreateCommandRecorder,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            CreateBindingTable,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            CreateBindingTable: 0,
+            Evict,
+            MakeResident,
+            GetDeviceRemovedReason,
+            GetParentDevice,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDMLDevice as windows_core::Interface>::IID || <IDMLObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for IDMLDevice1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CompileGraph(this: *mut core::ffi::c_void, desc: *const DML_GRAPH_DESC, flags: DML_EXECUTION_FLAGS, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLDevice1", "CompileGraph", 17usize, |this| ((**(this as *const *const IDMLDevice1_Vtbl)).CompileGraph)(this, desc, flags, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <IDMLDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CompileGraph }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDMLDevice1 as windows_core::Interface>::IID || <IDMLDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDMLDeviceChild_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLDeviceChild", "GetDevice", 7usize, |this| ((**(this as *const *const IDMLDeviceChild_Vtbl)).GetDevice)(this, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <IDMLObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDMLDeviceChild as windows_core::Interface>::IID || <IDMLObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDMLDispatchable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBindingProperties(this: *mut core::ffi::c_void, result__: *mut DML_BINDING_PROPERTIES) {
+            windows_core::imp::proxy_call(this, "IDMLDispatchable", "GetBindingProperties", 8usize, |this| ((**(this as *const *const IDMLDispatchable_Vtbl)).GetBindingProperties)(this, result__), |_| None)
+        }
+        Self { base__: <IDMLPageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBindingProperties }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDMLDispatchable as windows_core::Interface>::IID || <IDMLPageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDMLObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPrivateData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, datasize: *mut u32, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLObject", "GetPrivateData", 3usize, |this| ((**(this as *const *const IDMLObject_Vtbl)).GetPrivateData)(this, guid, datasize, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, datasize: u32, data: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLObject", "SetPrivateData", 4usize, |this| ((**(this as *const *const IDMLObject_Vtbl)).SetPrivateData)(this, guid, datasize, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateDataInterface(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLObject", "SetPrivateDataInterface", 5usize, |this| ((**(this as *const *const IDMLObject_Vtbl)).SetPrivateDataInterface)(this, guid, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLObject", "SetName", 6usize, |this| ((**(this as *const *const IDMLObject_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPrivateData, SetPrivateData, SetPrivateDataInterface, SetName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDMLObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDMLOperator_Vtbl {
+    const PROXY: Self = { Self { base__: <IDMLDeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDMLOperator as windows_core::Interface>::IID || <IDMLDeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDMLOperatorInitializer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, operatorcount: u32, operators: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMLOperatorInitializer", "Reset", 9usize, |this| ((**(this as *const *const IDMLOperatorInitializer_Vtbl)).Reset)(this, operatorcount, operators), |result| Some(*result))
+        }
+        Self { base__: <IDMLDispatchable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDMLOperatorInitializer as windows_core::Interface>::IID || <IDMLDispatchable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDMLPageable_Vtbl {
+    const PROXY: Self = { Self { base__: <IDMLDeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDMLPageable as windows_core::Interface>::IID || <IDMLDeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -606,6 +606,7 @@ impl MLOperatorAttributeType {
 impl windows_core::TypeKind for MLOperatorAttributeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MLOperatorAttributeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MLOperatorAttributeType").field(&self.0).finish()
@@ -621,6 +622,7 @@ impl MLOperatorEdgeType {
 impl windows_core::TypeKind for MLOperatorEdgeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MLOperatorEdgeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MLOperatorEdgeType").field(&self.0).finish()
@@ -637,6 +639,7 @@ impl MLOperatorExecutionType {
 impl windows_core::TypeKind for MLOperatorExecutionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MLOperatorExecutionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MLOperatorExecutionType").field(&self.0).finish()
@@ -652,6 +655,7 @@ impl MLOperatorKernelOptions {
 impl windows_core::TypeKind for MLOperatorKernelOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MLOperatorKernelOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MLOperatorKernelOptions").field(&self.0).finish()
@@ -701,6 +705,7 @@ impl MLOperatorParameterOptions {
 impl windows_core::TypeKind for MLOperatorParameterOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MLOperatorParameterOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MLOperatorParameterOptions").field(&self.0).finish()
@@ -749,6 +754,7 @@ impl MLOperatorSchemaEdgeTypeFormat {
 impl windows_core::TypeKind for MLOperatorSchemaEdgeTypeFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MLOperatorSchemaEdgeTypeFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MLOperatorSchemaEdgeTypeFormat").field(&self.0).finish()
@@ -778,6 +784,7 @@ impl MLOperatorTensorDataType {
 impl windows_core::TypeKind for MLOperatorTensorDataType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MLOperatorTensorDataType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MLOperatorTensorDataType").field(&self.0).finish()
@@ -789,6 +796,7 @@ pub struct WINML_BINDING_TYPE(pub i32);
 impl windows_core::TypeKind for WINML_BINDING_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINML_BINDING_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINML_BINDING_TYPE").field(&self.0).finish()
@@ -800,6 +808,7 @@ pub struct WINML_FEATURE_TYPE(pub i32);
 impl windows_core::TypeKind for WINML_FEATURE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINML_FEATURE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINML_FEATURE_TYPE").field(&self.0).finish()
@@ -811,6 +820,7 @@ pub struct WINML_RUNTIME_TYPE(pub i32);
 impl windows_core::TypeKind for WINML_RUNTIME_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINML_RUNTIME_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINML_RUNTIME_TYPE").field(&self.0).finish()
@@ -822,13 +832,15 @@ pub struct WINML_TENSOR_DATA_TYPE(pub i32);
 impl windows_core::TypeKind for WINML_TENSOR_DATA_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINML_TENSOR_DATA_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINML_TENSOR_DATA_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MLOperatorAttribute {
     pub name: windows_core::PCSTR,
     pub r#type: MLOperatorAttributeType,
@@ -903,7 +915,8 @@ impl Default for MLOperatorEdgeDescription_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MLOperatorEdgeTypeConstraint {
     pub typeLabel: windows_core::PCSTR,
     pub allowedTypes: *const MLOperatorEdgeDescription,
@@ -918,7 +931,8 @@ impl Default for MLOperatorEdgeTypeConstraint {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MLOperatorKernelDescription {
     pub domain: windows_core::PCSTR,
     pub name: windows_core::PCSTR,
@@ -940,7 +954,8 @@ impl Default for MLOperatorKernelDescription {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MLOperatorSchemaDescription {
     pub name: windows_core::PCSTR,
     pub operatorSetVersionAtLastChange: i32,
@@ -994,7 +1009,8 @@ impl Default for MLOperatorSchemaEdgeDescription_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MLOperatorSetId {
     pub domain: windows_core::PCSTR,
     pub version: i32,
@@ -1056,7 +1072,8 @@ impl Default for WINML_BINDING_DESC_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINML_IMAGE_BINDING_DESC {
     pub ElementType: WINML_TENSOR_DATA_TYPE,
     pub NumDimensions: u32,
@@ -1073,7 +1090,8 @@ impl Default for WINML_IMAGE_BINDING_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINML_IMAGE_VARIABLE_DESC {
     pub ElementType: WINML_TENSOR_DATA_TYPE,
     pub NumDimensions: u32,
@@ -1135,7 +1153,8 @@ impl Default for WINML_MAP_BINDING_DESC_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINML_MAP_VARIABLE_DESC {
     pub KeyType: WINML_TENSOR_DATA_TYPE,
     pub Fields: WINML_TENSOR_DATA_TYPE,
@@ -1149,7 +1168,8 @@ impl Default for WINML_MAP_VARIABLE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINML_MODEL_DESC {
     pub Author: windows_core::PWSTR,
     pub Name: windows_core::PWSTR,
@@ -1167,7 +1187,8 @@ impl Default for WINML_MODEL_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINML_RESOURCE_BINDING_DESC {
     pub ElementType: WINML_TENSOR_DATA_TYPE,
     pub NumDimensions: u32,
@@ -1222,7 +1243,8 @@ impl Default for WINML_SEQUENCE_BINDING_DESC_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINML_SEQUENCE_VARIABLE_DESC {
     pub ElementType: WINML_TENSOR_DATA_TYPE,
 }
@@ -1235,7 +1257,8 @@ impl Default for WINML_SEQUENCE_VARIABLE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINML_TENSOR_BINDING_DESC {
     pub DataType: WINML_TENSOR_DATA_TYPE,
     pub NumDimensions: u32,
@@ -1252,7 +1275,8 @@ impl Default for WINML_TENSOR_BINDING_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINML_TENSOR_VARIABLE_DESC {
     pub ElementType: WINML_TENSOR_DATA_TYPE,
     pub NumDimensions: u32,
@@ -1301,3 +1325,5 @@ impl Default for WINML_VARIABLE_DESC_0 {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
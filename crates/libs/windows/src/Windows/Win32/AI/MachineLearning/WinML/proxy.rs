@@ -0,0 +1,415 @@
+impl windows_core::imp::ProxyVtable for IMLOperatorAttributes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAttributeElementCount(this: *mut core::ffi::c_void, name: windows_core::PCSTR, r#type: MLOperatorAttributeType, elementcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorAttributes", "GetAttributeElementCount", 3usize, |this| ((**(this as *const *const IMLOperatorAttributes_Vtbl)).GetAttributeElementCount)(this, name, r#type, elementcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttribute(this: *mut core::ffi::c_void, name: windows_core::PCSTR, r#type: MLOperatorAttributeType, elementcount: u32, elementbytesize: usize, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorAttributes", "GetAttribute", 4usize, |this| ((**(this as *const *const IMLOperatorAttributes_Vtbl)).GetAttribute)(this, name, r#type, elementcount, elementbytesize, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringAttributeElementLength(this: *mut core::ffi::c_void, name: windows_core::PCSTR, elementindex: u32, attributeelementbytesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorAttributes", "GetStringAttributeElementLength", 5usize, |this| ((**(this as *const *const IMLOperatorAttributes_Vtbl)).GetStringAttributeElementLength)(this, name, elementindex, attributeelementbytesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringAttributeElement(this: *mut core::ffi::c_void, name: windows_core::PCSTR, elementindex: u32, attributeelementbytesize: u32, attributeelement: windows_core::PSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorAttributes", "GetStringAttributeElement", 6usize, |this| ((**(this as *const *const IMLOperatorAttributes_Vtbl)).GetStringAttributeElement)(this, name, elementindex, attributeelementbytesize, attributeelement), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAttributeElementCount,
+            GetAttribute,
+            GetStringAttributeElementLength,
+            GetStringAttributeElement,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLOperatorAttributes as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLOperatorKernel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Compute(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernel", "Compute", 3usize, |this| ((**(this as *const *const IMLOperatorKernel_Vtbl)).Compute)(this, context), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Compute }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLOperatorKernel as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLOperatorKernelContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInputTensor(this: *mut core::ffi::c_void, inputindex: u32, tensor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernelContext", "GetInputTensor", 3usize, |this| ((**(this as *const *const IMLOperatorKernelContext_Vtbl)).GetInputTensor)(this, inputindex, tensor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputTensor(this: *mut core::ffi::c_void, outputindex: u32, dimensioncount: u32, dimensionsizes: *const u32, tensor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernelContext", "GetOutputTensor", 4usize, |this| ((**(this as *const *const IMLOperatorKernelContext_Vtbl)).GetOutputTensor)(this, outputindex, dimensioncount, dimensionsizes, tensor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputTensor2(this: *mut core::ffi::c_void, outputindex: u32, tensor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernelContext", "GetOutputTensor2", 5usize, |this| ((**(this as *const *const IMLOperatorKernelContext_Vtbl)).GetOutputTensor2)(this, outputindex, tensor), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocateTemporaryData(this: *mut core::ffi::c_void, size: usize, data: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernelContext", "AllocateTemporaryData", 6usize, |this| ((**(this as *const *const IMLOperatorKernelContext_Vtbl)).AllocateTemporaryData)(this, size, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExecutionInterface(this: *mut core::ffi::c_void, executionobject: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernelContext", "GetExecutionInterface", 7usize, |this| ((**(this as *const *const IMLOperatorKernelContext_Vtbl)).GetExecutionInterface)(this, executionobject), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetInputTensor,
+            GetOutputTensor,
+            GetOutputTensor2,
+            AllocateTemporaryData,
+            GetExecutionInterface,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLOperatorKernelContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLOperatorKernelCreationContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInputCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernelCreationContext", "GetInputCount", 7usize, |this| ((**(this as *const *const IMLOperatorKernelCreationContext_Vtbl)).GetInputCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetOutputCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernelCreationContext", "GetOutputCount", 8usize, |this| ((**(this as *const *const IMLOperatorKernelCreationContext_Vtbl)).GetOutputCount)(this), |_| None)
+        }
+        unsafe extern "system" fn IsInputValid(this: *mut core::ffi::c_void, inputindex: u32) -> bool {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernelCreationContext", "IsInputValid", 9usize, |this| ((**(this as *const *const IMLOperatorKernelCreationContext_Vtbl)).IsInputValid)(this, inputindex), |_| None)
+        }
+        unsafe extern "system" fn IsOutputValid(this: *mut core::ffi::c_void, outputindex: u32) -> bool {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernelCreationContext", "IsOutputValid", 10usize, |this| ((**(this as *const *const IMLOperatorKernelCreationContext_Vtbl)).IsOutputValid)(this, outputindex), |_| None)
+        }
+        unsafe extern "system" fn GetInputEdgeDescription(this: *mut core::ffi::c_void, inputindex: u32, edgedescription: *mut MLOperatorEdgeDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernelCreationContext", "GetInputEdgeDescription", 11usize, |this| ((**(this as *const *const IMLOperatorKernelCreationContext_Vtbl)).GetInputEdgeDescription)(this, inputindex, edgedescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputEdgeDescription(this: *mut core::ffi::c_void, outputindex: u32, edgedescription: *mut MLOperatorEdgeDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernelCreationContext", "GetOutputEdgeDescription", 12usize, |this| ((**(this as *const *const IMLOperatorKernelCreationContext_Vtbl)).GetOutputEdgeDescription)(this, outputindex, edgedescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasTensorShapeDescription(this: *mut core::ffi::c_void) -> bool {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernelCreationContext", "HasTensorShapeDescription", 13usize, |this| ((**(this as *const *const IMLOperatorKernelCreationContext_Vtbl)).HasTensorShapeDescription)(this), |_| None)
+        }
+        unsafe extern "system" fn GetTensorShapeDescription(this: *mut core::ffi::c_void, shapedescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernelCreationContext", "GetTensorShapeDescription", 14usize, |this| ((**(this as *const *const IMLOperatorKernelCreationContext_Vtbl)).GetTensorShapeDescription)(this, shapedescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExecutionInterface(this: *mut core::ffi::c_void, executionobject: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernelCreationContext", "GetExecutionInterface", 15usize, |this| ((**(this as *const *const IMLOperatorKernelCreationContext_Vtbl)).GetExecutionInterface)(this, executionobject), |_| None)
+        }
+        Self {
+            base__: <IMLOperatorAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetInputCount,
+            GetOutputCount,
+            IsInputValid,
+            IsOutputValid,
+            GetInputEdgeDescription,
+            GetOutputEdgeDescription,
+            HasTensorShapeDescription,
+            GetTensorShapeDescription,
+            GetExecutionInterface,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLOperatorKernelCreationContext as windows_core::Interface>::IID || <IMLOperatorAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLOperatorKernelFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateKernel(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void, kernel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorKernelFactory", "CreateKernel", 3usize, |this| ((**(this as *const *const IMLOperatorKernelFactory_Vtbl)).CreateKernel)(this, context, kernel), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateKernel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLOperatorKernelFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLOperatorRegistry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterOperatorSetSchema(this: *mut core::ffi::c_void, operatorsetid: *const MLOperatorSetId, baselineversion: i32, schema: *const *const MLOperatorSchemaDescription, schemacount: u32, typeinferrer: *mut core::ffi::c_void, shapeinferrer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorRegistry", "RegisterOperatorSetSchema", 3usize, |this| ((**(this as *const *const IMLOperatorRegistry_Vtbl)).RegisterOperatorSetSchema)(this, operatorsetid, baselineversion, schema, schemacount, typeinferrer, shapeinferrer), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterOperatorKernel(this: *mut core::ffi::c_void, operatorkernel: *const MLOperatorKernelDescription, operatorkernelfactory: *mut core::ffi::c_void, shapeinferrer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorRegistry", "RegisterOperatorKernel", 4usize, |this| ((**(this as *const *const IMLOperatorRegistry_Vtbl)).RegisterOperatorKernel)(this, operatorkernel, operatorkernelfactory, shapeinferrer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterOperatorSetSchema, RegisterOperatorKernel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLOperatorRegistry as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLOperatorShapeInferenceContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInputCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMLOperatorShapeInferenceContext", "GetInputCount", 7usize, |this| ((**(this as *const *const IMLOperatorShapeInferenceContext_Vtbl)).GetInputCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetOutputCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMLOperatorShapeInferenceContext", "GetOutputCount", 8usize, |this| ((**(this as *const *const IMLOperatorShapeInferenceContext_Vtbl)).GetOutputCount)(this), |_| None)
+        }
+        unsafe extern "system" fn IsInputValid(this: *mut core::ffi::c_void, inputindex: u32) -> bool {
+            windows_core::imp::proxy_call(this, "IMLOperatorShapeInferenceContext", "IsInputValid", 9usize, |this| ((**(this as *const *const IMLOperatorShapeInferenceContext_Vtbl)).IsInputValid)(this, inputindex), |_| None)
+        }
+        unsafe extern "system" fn IsOutputValid(this: *mut core::ffi::c_void, outputindex: u32) -> bool {
+            windows_core::imp::proxy_call(this, "IMLOperatorShapeInferenceContext", "IsOutputValid", 10usize, |this| ((**(this as *const *const IMLOperatorShapeInferenceContext_Vtbl)).IsOutputValid)(this, outputindex), |_| None)
+        }
+        unsafe extern "system" fn GetInputEdgeDescription(this: *mut core::ffi::c_void, inputindex: u32, edgedescription: *mut MLOperatorEdgeDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorShapeInferenceContext", "GetInputEdgeDescription", 11usize, |this| ((**(this as *const *const IMLOperatorShapeInferenceContext_Vtbl)).GetInputEdgeDescription)(this, inputindex, edgedescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputTensorDimensionCount(this: *mut core::ffi::c_void, inputindex: u32, dimensioncount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorShapeInferenceContext", "GetInputTensorDimensionCount", 12usize, |this| ((**(this as *const *const IMLOperatorShapeInferenceContext_Vtbl)).GetInputTensorDimensionCount)(this, inputindex, dimensioncount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputTensorShape(this: *mut core::ffi::c_void, inputindex: u32, dimensioncount: u32, dimensions: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorShapeInferenceContext", "GetInputTensorShape", 13usize, |this| ((**(this as *const *const IMLOperatorShapeInferenceContext_Vtbl)).GetInputTensorShape)(this, inputindex, dimensioncount, dimensions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputTensorShape(this: *mut core::ffi::c_void, outputindex: u32, dimensioncount: u32, dimensions: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorShapeInferenceContext", "SetOutputTensorShape", 14usize, |this| ((**(this as *const *const IMLOperatorShapeInferenceContext_Vtbl)).SetOutputTensorShape)(this, outputindex, dimensioncount, dimensions), |result| Some(*result))
+        }
+        Self {
+            base__: <IMLOperatorAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetInputCount,
+            GetOutputCount,
+            IsInputValid,
+            IsOutputValid,
+            GetInputEdgeDescription,
+            GetInputTensorDimensionCount,
+            GetInputTensorShape,
+            SetOutputTensorShape,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLOperatorShapeInferenceContext as windows_core::Interface>::IID || <IMLOperatorAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLOperatorShapeInferrer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InferOutputShapes(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorShapeInferrer", "InferOutputShapes", 3usize, |this| ((**(this as *const *const IMLOperatorShapeInferrer_Vtbl)).InferOutputShapes)(this, context), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InferOutputShapes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLOperatorShapeInferrer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLOperatorTensor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDimensionCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMLOperatorTensor", "GetDimensionCount", 3usize, |this| ((**(this as *const *const IMLOperatorTensor_Vtbl)).GetDimensionCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetShape(this: *mut core::ffi::c_void, dimensioncount: u32, dimensions: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorTensor", "GetShape", 4usize, |this| ((**(this as *const *const IMLOperatorTensor_Vtbl)).GetShape)(this, dimensioncount, dimensions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTensorDataType(this: *mut core::ffi::c_void) -> MLOperatorTensorDataType {
+            windows_core::imp::proxy_call(this, "IMLOperatorTensor", "GetTensorDataType", 5usize, |this| ((**(this as *const *const IMLOperatorTensor_Vtbl)).GetTensorDataType)(this), |_| None)
+        }
+        unsafe extern "system" fn IsCpuData(this: *mut core::ffi::c_void) -> bool {
+            windows_core::imp::proxy_call(this, "IMLOperatorTensor", "IsCpuData", 6usize, |this| ((**(this as *const *const IMLOperatorTensor_Vtbl)).IsCpuData)(this), |_| None)
+        }
+        unsafe extern "system" fn IsDataInterface(this: *mut core::ffi::c_void) -> bool {
+            windows_core::imp::proxy_call(this, "IMLOperatorTensor", "IsDataInterface", 7usize, |this| ((**(this as *const *const IMLOperatorTensor_Vtbl)).IsDataInterface)(this), |_| None)
+        }
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "IMLOperatorTensor", "GetData", 8usize, |this| ((**(this as *const *const IMLOperatorTensor_Vtbl)).GetData)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDataInterface(this: *mut core::ffi::c_void, datainterface: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMLOperatorTensor", "GetDataInterface", 9usize, |this| ((**(this as *const *const IMLOperatorTensor_Vtbl)).GetDataInterface)(this, datainterface), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDimensionCount,
+            GetShape,
+            GetTensorDataType,
+            IsCpuData,
+            IsDataInterface,
+            GetData,
+            GetDataInterface,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLOperatorTensor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLOperatorTensorShapeDescription_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInputTensorDimensionCount(this: *mut core::ffi::c_void, inputindex: u32, dimensioncount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorTensorShapeDescription", "GetInputTensorDimensionCount", 3usize, |this| ((**(this as *const *const IMLOperatorTensorShapeDescription_Vtbl)).GetInputTensorDimensionCount)(this, inputindex, dimensioncount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputTensorShape(this: *mut core::ffi::c_void, inputindex: u32, dimensioncount: u32, dimensions: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorTensorShapeDescription", "GetInputTensorShape", 4usize, |this| ((**(this as *const *const IMLOperatorTensorShapeDescription_Vtbl)).GetInputTensorShape)(this, inputindex, dimensioncount, dimensions), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasOutputShapeDescription(this: *mut core::ffi::c_void) -> bool {
+            windows_core::imp::proxy_call(this, "IMLOperatorTensorShapeDescription", "HasOutputShapeDescription", 5usize, |this| ((**(this as *const *const IMLOperatorTensorShapeDescription_Vtbl)).HasOutputShapeDescription)(this), |_| None)
+        }
+        unsafe extern "system" fn GetOutputTensorDimensionCount(this: *mut core::ffi::c_void, outputindex: u32, dimensioncount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorTensorShapeDescription", "GetOutputTensorDimensionCount", 6usize, |this| ((**(this as *const *const IMLOperatorTensorShapeDescription_Vtbl)).GetOutputTensorDimensionCount)(this, outputindex, dimensioncount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputTensorShape(this: *mut core::ffi::c_void, outputindex: u32, dimensioncount: u32, dimensions: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorTensorShapeDescription", "GetOutputTensorShape", 7usize, |this| ((**(this as *const *const IMLOperatorTensorShapeDescription_Vtbl)).GetOutputTensorShape)(this, outputindex, dimensioncount, dimensions), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetInputTensorDimensionCount,
+            GetInputTensorShape,
+            HasOutputShapeDescription,
+            GetOutputTensorDimensionCount,
+            GetOutputTensorShape,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLOperatorTensorShapeDescription as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLOperatorTypeInferenceContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInputCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMLOperatorTypeInferenceContext", "GetInputCount", 7usize, |this| ((**(this as *const *const IMLOperatorTypeInferenceContext_Vtbl)).GetInputCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetOutputCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMLOperatorTypeInferenceContext", "GetOutputCount", 8usize, |this| ((**(this as *const *const IMLOperatorTypeInferenceContext_Vtbl)).GetOutputCount)(this), |_| None)
+        }
+        unsafe extern "system" fn IsInputValid(this: *mut core::ffi::c_void, inputindex: u32) -> bool {
+            windows_core::imp::proxy_call(this, "IMLOperatorTypeInferenceContext", "IsInputValid", 9usize, |this| ((**(this as *const *const IMLOperatorTypeInferenceContext_Vtbl)).IsInputValid)(this, inputindex), |_| None)
+        }
+        unsafe extern "system" fn IsOutputValid(this: *mut core::ffi::c_void, outputindex: u32) -> bool {
+            windows_core::imp::proxy_call(this, "IMLOperatorTypeInferenceContext", "IsOutputValid", 10usize, |this| ((**(this as *const *const IMLOperatorTypeInferenceContext_Vtbl)).IsOutputValid)(this, outputindex), |_| None)
+        }
+        unsafe extern "system" fn GetInputEdgeDescription(this: *mut core::ffi::c_void, inputindex: u32, edgedescription: *mut MLOperatorEdgeDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorTypeInferenceContext", "GetInputEdgeDescription", 11usize, |this| ((**(this as *const *const IMLOperatorTypeInferenceContext_Vtbl)).GetInputEdgeDescription)(this, inputindex, edgedescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputEdgeDescription(this: *mut core::ffi::c_void, outputindex: u32, edgedescription: *const MLOperatorEdgeDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorTypeInferenceContext", "SetOutputEdgeDescription", 12usize, |this| ((**(this as *const *const IMLOperatorTypeInferenceContext_Vtbl)).SetOutputEdgeDescription)(this, outputindex, edgedescription), |result| Some(*result))
+        }
+        Self {
+            base__: <IMLOperatorAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetInputCount,
+            GetOutputCount,
+            IsInputValid,
+            IsOutputValid,
+            GetInputEdgeDescription,
+            SetOutputEdgeDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLOperatorTypeInferenceContext as windows_core::Interface>::IID || <IMLOperatorAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLOperatorTypeInferrer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InferOutputTypes(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLOperatorTypeInferrer", "InferOutputTypes", 3usize, |this| ((**(this as *const *const IMLOperatorTypeInferrer_Vtbl)).InferOutputTypes)(this, context), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InferOutputTypes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLOperatorTypeInferrer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for IWinMLEvaluationContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn BindValue(this: *mut core::ffi::c_void, pdescriptor: *const WINML_BINDING_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinMLEvaluationContext", "BindValue", 3usize, |this| ((**(this as *const *const IWinMLEvaluationContext_Vtbl)).BindValue)(this, pdescriptor), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn GetValueByName(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, pdescriptor: *mut *mut WINML_BINDING_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinMLEvaluationContext", "GetValueByName", 4usize, |this| ((**(this as *const *const IWinMLEvaluationContext_Vtbl)).GetValueByName)(this, name, pdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinMLEvaluationContext", "Clear", 5usize, |this| ((**(this as *const *const IWinMLEvaluationContext_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            BindValue,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            BindValue: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            GetValueByName,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            GetValueByName: 0,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWinMLEvaluationContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWinMLModel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, ppdescription: *mut *mut WINML_MODEL_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinMLModel", "GetDescription", 3usize, |this| ((**(this as *const *const IWinMLModel_Vtbl)).GetDescription)(this, ppdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateMetadata(this: *mut core::ffi::c_void, index: u32, pkey: *mut windows_core::PCWSTR, pvalue: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinMLModel", "EnumerateMetadata", 4usize, |this| ((**(this as *const *const IWinMLModel_Vtbl)).EnumerateMetadata)(this, index, pkey, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateModelInputs(this: *mut core::ffi::c_void, index: u32, ppinputdescriptor: *mut *mut WINML_VARIABLE_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinMLModel", "EnumerateModelInputs", 5usize, |this| ((**(this as *const *const IWinMLModel_Vtbl)).EnumerateModelInputs)(this, index, ppinputdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateModelOutputs(this: *mut core::ffi::c_void, index: u32, ppoutputdescriptor: *mut *mut WINML_VARIABLE_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinMLModel", "EnumerateModelOutputs", 6usize, |this| ((**(this as *const *const IWinMLModel_Vtbl)).EnumerateModelOutputs)(this, index, ppoutputdescriptor), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDescription,
+            EnumerateMetadata,
+            EnumerateModelInputs,
+            EnumerateModelOutputs,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWinMLModel as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for IWinMLRuntime_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadModel(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, ppmodel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinMLRuntime", "LoadModel", 3usize, |this| ((**(this as *const *const IWinMLRuntime_Vtbl)).LoadModel)(this, path, ppmodel), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn CreateEvaluationContext(this: *mut core::ffi::c_void, device: *mut core::ffi::c_void, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinMLRuntime", "CreateEvaluationContext", 4usize, |this| ((**(this as *const *const IWinMLRuntime_Vtbl)).CreateEvaluationContext)(this, device, ppcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn EvaluateModel(this: *mut core::ffi::c_void, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinMLRuntime", "EvaluateModel", 5usize, |this| ((**(this as *const *const IWinMLRuntime_Vtbl)).EvaluateModel)(this, pcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LoadModel,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            CreateEvaluationContext,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            CreateEvaluationContext: 0,
+            EvaluateModel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWinMLRuntime as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWinMLRuntimeFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateRuntime(this: *mut core::ffi::c_void, runtimetype: WINML_RUNTIME_TYPE, ppruntime: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinMLRuntimeFactory", "CreateRuntime", 3usize, |this| ((**(this as *const *const IWinMLRuntimeFactory_Vtbl)).CreateRuntime)(this, runtimetype, ppruntime), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateRuntime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWinMLRuntimeFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,354 @@
+impl windows_core::imp::ProxyVtable for IITDatabase_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, lpszhost: windows_core::PCWSTR, lpszmoniker: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITDatabase", "Open", 3usize, |this| ((**(this as *const *const IITDatabase_Vtbl)).Open)(this, lpszhost, lpszmoniker, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITDatabase", "Close", 4usize, |this| ((**(this as *const *const IITDatabase_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateObject(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, pdwobjinstance: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITDatabase", "CreateObject", 5usize, |this| ((**(this as *const *const IITDatabase_Vtbl)).CreateObject)(this, rclsid, pdwobjinstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, dwobjinstance: u32, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITDatabase", "GetObject", 6usize, |this| ((**(this as *const *const IITDatabase_Vtbl)).GetObject)(this, dwobjinstance, riid, ppvobj), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectPersistence(this: *mut core::ffi::c_void, lpwszobject: windows_core::PCWSTR, dwobjinstance: u32, ppvpersistence: *mut *mut core::ffi::c_void, fstream: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITDatabase", "GetObjectPersistence", 7usize, |this| ((**(this as *const *const IITDatabase_Vtbl)).GetObjectPersistence)(this, lpwszobject, dwobjinstance, ppvpersistence, fstream), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Open, Close, CreateObject, GetObject, GetObjectPersistence }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IITDatabase as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IITPropList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Set(this: *mut core::ffi::c_void, propid: u32, lpszwstring: windows_core::PCWSTR, dwoperation: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "Set", 9usize, |this| ((**(this as *const *const IITPropList_Vtbl)).Set)(this, propid, lpszwstring, dwoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set2(this: *mut core::ffi::c_void, propid: u32, lpvdata: *mut core::ffi::c_void, cbdata: u32, dwoperation: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "Set2", 10usize, |this| ((**(this as *const *const IITPropList_Vtbl)).Set2)(this, propid, lpvdata, cbdata, dwoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set3(this: *mut core::ffi::c_void, propid: u32, dwdata: u32, dwoperation: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "Set3", 11usize, |this| ((**(this as *const *const IITPropList_Vtbl)).Set3)(this, propid, dwdata, dwoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, prop: *mut CProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "Add", 12usize, |this| ((**(this as *const *const IITPropList_Vtbl)).Add)(this, prop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, propid: u32, property: *mut CProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "Get", 13usize, |this| ((**(this as *const *const IITPropList_Vtbl)).Get)(this, propid, property), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "Clear", 14usize, |this| ((**(this as *const *const IITPropList_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPersist(this: *mut core::ffi::c_void, fpersist: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "SetPersist", 15usize, |this| ((**(this as *const *const IITPropList_Vtbl)).SetPersist)(this, fpersist), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPersist2(this: *mut core::ffi::c_void, propid: u32, fpersist: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "SetPersist2", 16usize, |this| ((**(this as *const *const IITPropList_Vtbl)).SetPersist2)(this, propid, fpersist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFirst(this: *mut core::ffi::c_void, property: *mut CProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "GetFirst", 17usize, |this| ((**(this as *const *const IITPropList_Vtbl)).GetFirst)(this, property), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNext(this: *mut core::ffi::c_void, property: *mut CProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "GetNext", 18usize, |this| ((**(this as *const *const IITPropList_Vtbl)).GetNext)(this, property), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropCount(this: *mut core::ffi::c_void, cprop: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "GetPropCount", 19usize, |this| ((**(this as *const *const IITPropList_Vtbl)).GetPropCount)(this, cprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveHeader(this: *mut core::ffi::c_void, lpvdata: *mut core::ffi::c_void, dwhdrsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "SaveHeader", 20usize, |this| ((**(this as *const *const IITPropList_Vtbl)).SaveHeader)(this, lpvdata, dwhdrsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveData(this: *mut core::ffi::c_void, lpvheader: *mut core::ffi::c_void, dwhdrsize: u32, lpvdata: *mut core::ffi::c_void, dwbufsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "SaveData", 21usize, |this| ((**(this as *const *const IITPropList_Vtbl)).SaveData)(this, lpvheader, dwhdrsize, lpvdata, dwbufsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHeaderSize(this: *mut core::ffi::c_void, dwhdrsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "GetHeaderSize", 22usize, |this| ((**(this as *const *const IITPropList_Vtbl)).GetHeaderSize)(this, dwhdrsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataSize(this: *mut core::ffi::c_void, lpvheader: *mut core::ffi::c_void, dwhdrsize: u32, dwdatasize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "GetDataSize", 23usize, |this| ((**(this as *const *const IITPropList_Vtbl)).GetDataSize)(this, lpvheader, dwhdrsize, dwdatasize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SaveDataToStream(this: *mut core::ffi::c_void, lpvheader: *mut core::ffi::c_void, dwhdrsize: u32, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "SaveDataToStream", 24usize, |this| ((**(this as *const *const IITPropList_Vtbl)).SaveDataToStream)(this, lpvheader, dwhdrsize, pstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadFromMem(this: *mut core::ffi::c_void, lpvdata: *mut core::ffi::c_void, dwbufsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "LoadFromMem", 25usize, |this| ((**(this as *const *const IITPropList_Vtbl)).LoadFromMem)(this, lpvdata, dwbufsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveToMem(this: *mut core::ffi::c_void, lpvdata: *mut core::ffi::c_void, dwbufsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITPropList", "SaveToMem", 26usize, |this| ((**(this as *const *const IITPropList_Vtbl)).SaveToMem)(this, lpvdata, dwbufsize), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IPersistStreamInit_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Set,
+            Set2,
+            Set3,
+            Add,
+            Get,
+            Clear,
+            SetPersist,
+            SetPersist2,
+            GetFirst,
+            GetNext,
+            GetPropCount,
+            SaveHeader,
+            SaveData,
+            GetHeaderSize,
+            GetDataSize,
+            #[cfg(feature = "Win32_System_Com")]
+            SaveDataToStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SaveDataToStream: 0,
+            LoadFromMem,
+            SaveToMem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IITPropList as windows_core::Interface>::IID || <super::super::System::Com::IPersistStreamInit_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IITResultSet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetColumnPriority(this: *mut core::ffi::c_void, lcolumnindex: i32, columnpriority: PRIORITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "SetColumnPriority", 3usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).SetColumnPriority)(this, lcolumnindex, columnpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColumnHeap(this: *mut core::ffi::c_void, lcolumnindex: i32, lpvheap: *mut core::ffi::c_void, pfncolheapfree: PFNCOLHEAPFREE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "SetColumnHeap", 4usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).SetColumnHeap)(this, lcolumnindex, lpvheap, pfncolheapfree), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeyProp(this: *mut core::ffi::c_void, propid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "SetKeyProp", 5usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).SetKeyProp)(this, propid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, propid: u32, dwdefaultdata: u32, priority: PRIORITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "Add", 6usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).Add)(this, propid, dwdefaultdata, priority), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add2(this: *mut core::ffi::c_void, propid: u32, lpszwdefault: windows_core::PCWSTR, priority: PRIORITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "Add2", 7usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).Add2)(this, propid, lpszwdefault, priority), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add3(this: *mut core::ffi::c_void, propid: u32, lpvdefaultdata: *mut core::ffi::c_void, cbdata: u32, priority: PRIORITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "Add3", 8usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).Add3)(this, propid, lpvdefaultdata, cbdata, priority), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add4(this: *mut core::ffi::c_void, lpvhdr: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "Add4", 9usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).Add4)(this, lpvhdr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, lpvhdr: *mut core::ffi::c_void, lpvdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "Append", 10usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).Append)(this, lpvhdr, lpvdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set(this: *mut core::ffi::c_void, lrowindex: i32, lcolumnindex: i32, lpvdata: *mut core::ffi::c_void, cbdata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "Set", 11usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).Set)(this, lrowindex, lcolumnindex, lpvdata, cbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set2(this: *mut core::ffi::c_void, lrowindex: i32, lcolumnindex: i32, lpwstr: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "Set2", 12usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).Set2)(this, lrowindex, lcolumnindex, lpwstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set3(this: *mut core::ffi::c_void, lrowindex: i32, lcolumnindex: i32, dwdata: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "Set3", 13usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).Set3)(this, lrowindex, lcolumnindex, dwdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set4(this: *mut core::ffi::c_void, lrowindex: i32, lpvhdr: *mut core::ffi::c_void, lpvdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "Set4", 14usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).Set4)(this, lrowindex, lpvhdr, lpvdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, prscopy: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "Copy", 15usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).Copy)(this, prscopy), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendRows(this: *mut core::ffi::c_void, pressrc: *mut core::ffi::c_void, lrowsrcfirst: i32, csrcrows: i32, lrowfirstdest: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "AppendRows", 16usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).AppendRows)(this, pressrc, lrowsrcfirst, csrcrows, lrowfirstdest), |result| Some(*result))
+        }
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, lrowindex: i32, lcolumnindex: i32, prop: *mut CProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "Get", 17usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).Get)(this, lrowindex, lcolumnindex, prop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeyProp(this: *mut core::ffi::c_void, keypropid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "GetKeyProp", 18usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).GetKeyProp)(this, keypropid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnPriority(this: *mut core::ffi::c_void, lcolumnindex: i32, columnpriority: *mut PRIORITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "GetColumnPriority", 19usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).GetColumnPriority)(this, lcolumnindex, columnpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRowCount(this: *mut core::ffi::c_void, lnumberofrows: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "GetRowCount", 20usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).GetRowCount)(this, lnumberofrows), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnCount(this: *mut core::ffi::c_void, lnumberofcolumns: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "GetColumnCount", 21usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).GetColumnCount)(this, lnumberofcolumns), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumn(this: *mut core::ffi::c_void, lcolumnindex: i32, propid: *mut u32, dwtype: *mut u32, lpvdefaultvalue: *mut *mut core::ffi::c_void, cbsize: *mut u32, columnpriority: *mut PRIORITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "GetColumn", 22usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).GetColumn)(this, lcolumnindex, propid, dwtype, lpvdefaultvalue, cbsize, columnpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumn2(this: *mut core::ffi::c_void, lcolumnindex: i32, propid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "GetColumn2", 23usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).GetColumn2)(this, lcolumnindex, propid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnFromPropID(this: *mut core::ffi::c_void, propid: u32, lcolumnindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "GetColumnFromPropID", 24usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).GetColumnFromPropID)(this, propid, lcolumnindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "Clear", 25usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearRows(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "ClearRows", 26usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).ClearRows)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Free(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "Free", 27usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).Free)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCompleted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "IsCompleted", 28usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).IsCompleted)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "Cancel", 29usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void, fpause: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "Pause", 30usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).Pause)(this, fpause), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRowStatus(this: *mut core::ffi::c_void, lrowfirst: i32, crows: i32, lprowstatus: *mut ROWSTATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "GetRowStatus", 31usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).GetRowStatus)(this, lrowfirst, crows, lprowstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnStatus(this: *mut core::ffi::c_void, lpcolstatus: *mut COLUMNSTATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IITResultSet", "GetColumnStatus", 32usize, |this| ((**(this as *const *const IITResultSet_Vtbl)).GetColumnStatus)(this, lpcolstatus), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetColumnPriority,
+            SetColumnHeap,
+            SetKeyProp,
+            Add,
+            Add2,
+            Add3,
+            Add4,
+            Append,
+            Set,
+            Set2,
+            Set3,
+            Set4,
+            Copy,
+            AppendRows,
+            Get,
+            GetKeyProp,
+            GetColumnPriority,
+            GetRowCount,
+            GetColumnCount,
+            GetColumn,
+            GetColumn2,
+            GetColumnFromPropID,
+            Clear,
+            ClearRows,
+            Free,
+            IsCompleted,
+            Cancel,
+            Pause,
+            GetRowStatus,
+            GetColumnStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IITResultSet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStemSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PutAltWord(this: *mut core::ffi::c_void, pwcinbuf: windows_core::PCWSTR, cwc: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStemSink", "PutAltWord", 3usize, |this| ((**(this as *const *const IStemSink_Vtbl)).PutAltWord)(this, pwcinbuf, cwc), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutWord(this: *mut core::ffi::c_void, pwcinbuf: windows_core::PCWSTR, cwc: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStemSink", "PutWord", 4usize, |this| ((**(this as *const *const IStemSink_Vtbl)).PutWord)(this, pwcinbuf, cwc), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PutAltWord, PutWord }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStemSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IStemmerConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLocaleInfo(this: *mut core::ffi::c_void, dwcodepageid: u32, lcid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStemmerConfig", "SetLocaleInfo", 3usize, |this| ((**(this as *const *const IStemmerConfig_Vtbl)).SetLocaleInfo)(this, dwcodepageid, lcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocaleInfo(this: *mut core::ffi::c_void, pdwcodepageid: *mut u32, plcid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStemmerConfig", "GetLocaleInfo", 4usize, |this| ((**(this as *const *const IStemmerConfig_Vtbl)).GetLocaleInfo)(this, pdwcodepageid, plcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetControlInfo(this: *mut core::ffi::c_void, grfstemflags: u32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStemmerConfig", "SetControlInfo", 5usize, |this| ((**(this as *const *const IStemmerConfig_Vtbl)).SetControlInfo)(this, grfstemflags, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetControlInfo(this: *mut core::ffi::c_void, pgrfstemflags: *mut u32, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStemmerConfig", "GetControlInfo", 6usize, |this| ((**(this as *const *const IStemmerConfig_Vtbl)).GetControlInfo)(this, pgrfstemflags, pdwreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LoadExternalStemmerData(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, dwextdatatype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStemmerConfig", "LoadExternalStemmerData", 7usize, |this| ((**(this as *const *const IStemmerConfig_Vtbl)).LoadExternalStemmerData)(this, pstream, dwextdatatype), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetLocaleInfo,
+            GetLocaleInfo,
+            SetControlInfo,
+            GetControlInfo,
+            #[cfg(feature = "Win32_System_Com")]
+            LoadExternalStemmerData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LoadExternalStemmerData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStemmerConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search"))]
+impl windows_core::imp::ProxyVtable for IWordBreakerConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLocaleInfo(this: *mut core::ffi::c_void, dwcodepageid: u32, lcid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordBreakerConfig", "SetLocaleInfo", 3usize, |this| ((**(this as *const *const IWordBreakerConfig_Vtbl)).SetLocaleInfo)(this, dwcodepageid, lcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocaleInfo(this: *mut core::ffi::c_void, pdwcodepageid: *mut u32, plcid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordBreakerConfig", "GetLocaleInfo", 4usize, |this| ((**(this as *const *const IWordBreakerConfig_Vtbl)).GetLocaleInfo)(this, pdwcodepageid, plcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBreakWordType(this: *mut core::ffi::c_void, dwbreakwordtype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordBreakerConfig", "SetBreakWordType", 5usize, |this| ((**(this as *const *const IWordBreakerConfig_Vtbl)).SetBreakWordType)(this, dwbreakwordtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBreakWordType(this: *mut core::ffi::c_void, pdwbreakwordtype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordBreakerConfig", "GetBreakWordType", 6usize, |this| ((**(this as *const *const IWordBreakerConfig_Vtbl)).GetBreakWordType)(this, pdwbreakwordtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetControlInfo(this: *mut core::ffi::c_void, grfbreakflags: u32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordBreakerConfig", "SetControlInfo", 7usize, |this| ((**(this as *const *const IWordBreakerConfig_Vtbl)).SetControlInfo)(this, grfbreakflags, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetControlInfo(this: *mut core::ffi::c_void, pgrfbreakflags: *mut u32, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordBreakerConfig", "GetControlInfo", 8usize, |this| ((**(this as *const *const IWordBreakerConfig_Vtbl)).GetControlInfo)(this, pgrfbreakflags, pdwreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LoadExternalBreakerData(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, dwextdatatype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordBreakerConfig", "LoadExternalBreakerData", 9usize, |this| ((**(this as *const *const IWordBreakerConfig_Vtbl)).LoadExternalBreakerData)(this, pstream, dwextdatatype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Search")]
+        unsafe extern "system" fn SetWordStemmer(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, pstemmer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordBreakerConfig", "SetWordStemmer", 10usize, |this| ((**(this as *const *const IWordBreakerConfig_Vtbl)).SetWordStemmer)(this, rclsid, pstemmer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Search")]
+        unsafe extern "system" fn GetWordStemmer(this: *mut core::ffi::c_void, ppstemmer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordBreakerConfig", "GetWordStemmer", 11usize, |this| ((**(this as *const *const IWordBreakerConfig_Vtbl)).GetWordStemmer)(this, ppstemmer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetLocaleInfo,
+            GetLocaleInfo,
+            SetBreakWordType,
+            GetBreakWordType,
+            SetControlInfo,
+            GetControlInfo,
+            #[cfg(feature = "Win32_System_Com")]
+            LoadExternalBreakerData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LoadExternalBreakerData: 0,
+            #[cfg(feature = "Win32_System_Search")]
+            SetWordStemmer,
+            #[cfg(not(feature = "Win32_System_Search"))]
+            SetWordStemmer: 0,
+            #[cfg(feature = "Win32_System_Search")]
+            GetWordStemmer,
+            #[cfg(not(feature = "Win32_System_Search"))]
+            GetWordStemmer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWordBreakerConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
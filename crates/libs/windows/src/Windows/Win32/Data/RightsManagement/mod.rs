@@ -699,6 +699,7 @@ pub struct DRMATTESTTYPE(pub i32);
 impl windows_core::TypeKind for DRMATTESTTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRMATTESTTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRMATTESTTYPE").field(&self.0).finish()
@@ -710,6 +711,7 @@ pub struct DRMENCODINGTYPE(pub i32);
 impl windows_core::TypeKind for DRMENCODINGTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRMENCODINGTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRMENCODINGTYPE").field(&self.0).finish()
@@ -721,6 +723,7 @@ pub struct DRMGLOBALOPTIONS(pub i32);
 impl windows_core::TypeKind for DRMGLOBALOPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRMGLOBALOPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRMGLOBALOPTIONS").field(&self.0).finish()
@@ -732,6 +735,7 @@ pub struct DRMSECURITYPROVIDERTYPE(pub i32);
 impl windows_core::TypeKind for DRMSECURITYPROVIDERTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRMSECURITYPROVIDERTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRMSECURITYPROVIDERTYPE").field(&self.0).finish()
@@ -743,6 +747,7 @@ pub struct DRMSPECTYPE(pub i32);
 impl windows_core::TypeKind for DRMSPECTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRMSPECTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRMSPECTYPE").field(&self.0).finish()
@@ -754,6 +759,7 @@ pub struct DRMTIMETYPE(pub i32);
 impl windows_core::TypeKind for DRMTIMETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRMTIMETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRMTIMETYPE").field(&self.0).finish()
@@ -765,6 +771,7 @@ pub struct DRM_DISTRIBUTION_POINT_INFO(pub i32);
 impl windows_core::TypeKind for DRM_DISTRIBUTION_POINT_INFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRM_DISTRIBUTION_POINT_INFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRM_DISTRIBUTION_POINT_INFO").field(&self.0).finish()
@@ -776,6 +783,7 @@ pub struct DRM_STATUS_MSG(pub i32);
 impl windows_core::TypeKind for DRM_STATUS_MSG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRM_STATUS_MSG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRM_STATUS_MSG").field(&self.0).finish()
@@ -787,13 +795,15 @@ pub struct DRM_USAGEPOLICY_TYPE(pub i32);
 impl windows_core::TypeKind for DRM_USAGEPOLICY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRM_USAGEPOLICY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRM_USAGEPOLICY_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRMBOUNDLICENSEPARAMS {
     pub uVersion: u32,
     pub hEnablingPrincipal: u32,
@@ -815,7 +825,8 @@ impl Default for DRMBOUNDLICENSEPARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRMID {
     pub uVersion: u32,
     pub wszIDType: windows_core::PWSTR,
@@ -830,7 +841,8 @@ impl Default for DRMID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRM_ACTSERV_INFO {
     pub uVersion: u32,
     pub wszPubKey: windows_core::PWSTR,
@@ -845,7 +857,8 @@ impl Default for DRM_ACTSERV_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRM_CLIENT_VERSION_INFO {
     pub uStructVersion: u32,
     pub dwVersion: [u32; 4],
@@ -862,7 +875,8 @@ impl Default for DRM_CLIENT_VERSION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRM_LICENSE_ACQ_DATA {
     pub uVersion: u32,
     pub wszURL: windows_core::PWSTR,
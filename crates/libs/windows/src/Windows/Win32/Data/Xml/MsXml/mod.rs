@@ -6328,6 +6328,7 @@ pub struct DOMNodeType(pub i32);
 impl windows_core::TypeKind for DOMNodeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DOMNodeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DOMNodeType").field(&self.0).finish()
@@ -6339,6 +6340,7 @@ pub struct SCHEMACONTENTTYPE(pub i32);
 impl windows_core::TypeKind for SCHEMACONTENTTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCHEMACONTENTTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCHEMACONTENTTYPE").field(&self.0).finish()
@@ -6350,6 +6352,7 @@ pub struct SCHEMADERIVATIONMETHOD(pub i32);
 impl windows_core::TypeKind for SCHEMADERIVATIONMETHOD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCHEMADERIVATIONMETHOD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCHEMADERIVATIONMETHOD").field(&self.0).finish()
@@ -6361,6 +6364,7 @@ pub struct SCHEMAPROCESSCONTENTS(pub i32);
 impl windows_core::TypeKind for SCHEMAPROCESSCONTENTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCHEMAPROCESSCONTENTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCHEMAPROCESSCONTENTS").field(&self.0).finish()
@@ -6372,6 +6376,7 @@ pub struct SCHEMATYPEVARIETY(pub i32);
 impl windows_core::TypeKind for SCHEMATYPEVARIETY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCHEMATYPEVARIETY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCHEMATYPEVARIETY").field(&self.0).finish()
@@ -6383,6 +6388,7 @@ pub struct SCHEMAUSE(pub i32);
 impl windows_core::TypeKind for SCHEMAUSE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCHEMAUSE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCHEMAUSE").field(&self.0).finish()
@@ -6394,6 +6400,7 @@ pub struct SCHEMAWHITESPACE(pub i32);
 impl windows_core::TypeKind for SCHEMAWHITESPACE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCHEMAWHITESPACE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCHEMAWHITESPACE").field(&self.0).finish()
@@ -6405,6 +6412,7 @@ pub struct SERVERXMLHTTP_OPTION(pub i32);
 impl windows_core::TypeKind for SERVERXMLHTTP_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SERVERXMLHTTP_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SERVERXMLHTTP_OPTION").field(&self.0).finish()
@@ -6416,6 +6424,7 @@ pub struct SOMITEMTYPE(pub i32);
 impl windows_core::TypeKind for SOMITEMTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SOMITEMTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SOMITEMTYPE").field(&self.0).finish()
@@ -6427,6 +6436,7 @@ pub struct SXH_PROXY_SETTING(pub i32);
 impl windows_core::TypeKind for SXH_PROXY_SETTING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SXH_PROXY_SETTING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SXH_PROXY_SETTING").field(&self.0).finish()
@@ -6438,6 +6448,7 @@ pub struct SXH_SERVER_CERT_OPTION(pub i32);
 impl windows_core::TypeKind for SXH_SERVER_CERT_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SXH_SERVER_CERT_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SXH_SERVER_CERT_OPTION").field(&self.0).finish()
@@ -6449,6 +6460,7 @@ pub struct XHR_AUTH(pub i32);
 impl windows_core::TypeKind for XHR_AUTH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XHR_AUTH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XHR_AUTH").field(&self.0).finish()
@@ -6460,6 +6472,7 @@ pub struct XHR_CERT_ERROR_FLAG(pub u32);
 impl windows_core::TypeKind for XHR_CERT_ERROR_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XHR_CERT_ERROR_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XHR_CERT_ERROR_FLAG").field(&self.0).finish()
@@ -6471,6 +6484,7 @@ pub struct XHR_CERT_IGNORE_FLAG(pub u32);
 impl windows_core::TypeKind for XHR_CERT_IGNORE_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XHR_CERT_IGNORE_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XHR_CERT_IGNORE_FLAG").field(&self.0).finish()
@@ -6482,6 +6496,7 @@ pub struct XHR_COOKIE_FLAG(pub i32);
 impl windows_core::TypeKind for XHR_COOKIE_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XHR_COOKIE_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XHR_COOKIE_FLAG").field(&self.0).finish()
@@ -6493,6 +6508,7 @@ pub struct XHR_COOKIE_STATE(pub i32);
 impl windows_core::TypeKind for XHR_COOKIE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XHR_COOKIE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XHR_COOKIE_STATE").field(&self.0).finish()
@@ -6504,6 +6520,7 @@ pub struct XHR_CRED_PROMPT(pub i32);
 impl windows_core::TypeKind for XHR_CRED_PROMPT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XHR_CRED_PROMPT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XHR_CRED_PROMPT").field(&self.0).finish()
@@ -6515,6 +6532,7 @@ pub struct XHR_PROPERTY(pub i32);
 impl windows_core::TypeKind for XHR_PROPERTY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XHR_PROPERTY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XHR_PROPERTY").field(&self.0).finish()
@@ -6526,6 +6544,7 @@ pub struct XMLELEM_TYPE(pub i32);
 impl windows_core::TypeKind for XMLELEM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XMLELEM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XMLELEM_TYPE").field(&self.0).finish()
@@ -6543,7 +6562,8 @@ pub const SAXAttributes60: windows_core::GUID = windows_core::GUID::from_u128(0x
 pub const SAXXMLReader60: windows_core::GUID = windows_core::GUID::from_u128(0x88d96a0c_f192_11d4_a65f_0040963251e5);
 pub const ServerXMLHTTP60: windows_core::GUID = windows_core::GUID::from_u128(0x88d96a0b_f192_11d4_a65f_0040963251e5);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XHR_CERT {
     pub cbCert: u32,
     pub pbCert: *mut u8,
@@ -6557,7 +6577,8 @@ impl Default for XHR_CERT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XHR_COOKIE {
     pub pwszUrl: windows_core::PWSTR,
     pub pwszName: windows_core::PWSTR,
@@ -6580,7 +6601,8 @@ pub const XMLHTTP60: windows_core::GUID = windows_core::GUID::from_u128(0x88d96a
 pub const XMLHTTPRequest: windows_core::GUID = windows_core::GUID::from_u128(0xed8c108e_4349_11d2_91a4_00c04f7969e8);
 pub const XMLSchemaCache60: windows_core::GUID = windows_core::GUID::from_u128(0x88d96a07_f192_11d4_a65f_0040963251e5);
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XML_ERROR {
     pub _nLine: u32,
     pub _pchBuf: core::mem::ManuallyDrop<windows_core::BSTR>,
@@ -6606,7 +6628,8 @@ impl Default for XML_ERROR {
 }
 pub const XSLTemplate60: windows_core::GUID = windows_core::GUID::from_u128(0x88d96a08_f192_11d4_a65f_0040963251e5);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct __msxml6_ReferenceRemainingTypes__ {
     pub __tagDomNodeType__: DOMNodeType,
     pub __domNodeType__: DOMNodeType,
@@ -6641,3 +6664,5 @@ impl Default for __msxml6_ReferenceRemainingTypes__ {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,3783 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMXAttributes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn addAttribute(this: *mut core::ffi::c_void, struri: core::mem::MaybeUninit<windows_core::BSTR>, strlocalname: core::mem::MaybeUninit<windows_core::BSTR>, strqname: core::mem::MaybeUninit<windows_core::BSTR>, strtype: core::mem::MaybeUninit<windows_core::BSTR>, strvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXAttributes", "addAttribute", 7usize, |this| ((**(this as *const *const IMXAttributes_Vtbl)).addAttribute)(this, struri, strlocalname, strqname, strtype, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn addAttributeFromIndex(this: *mut core::ffi::c_void, varatts: core::mem::MaybeUninit<windows_core::VARIANT>, nindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXAttributes", "addAttributeFromIndex", 8usize, |this| ((**(this as *const *const IMXAttributes_Vtbl)).addAttributeFromIndex)(this, varatts, nindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXAttributes", "clear", 9usize, |this| ((**(this as *const *const IMXAttributes_Vtbl)).clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn removeAttribute(this: *mut core::ffi::c_void, nindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXAttributes", "removeAttribute", 10usize, |this| ((**(this as *const *const IMXAttributes_Vtbl)).removeAttribute)(this, nindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn setAttribute(this: *mut core::ffi::c_void, nindex: i32, struri: core::mem::MaybeUninit<windows_core::BSTR>, strlocalname: core::mem::MaybeUninit<windows_core::BSTR>, strqname: core::mem::MaybeUninit<windows_core::BSTR>, strtype: core::mem::MaybeUninit<windows_core::BSTR>, strvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXAttributes", "setAttribute", 11usize, |this| ((**(this as *const *const IMXAttributes_Vtbl)).setAttribute)(this, nindex, struri, strlocalname, strqname, strtype, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn setAttributes(this: *mut core::ffi::c_void, varatts: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXAttributes", "setAttributes", 12usize, |this| ((**(this as *const *const IMXAttributes_Vtbl)).setAttributes)(this, varatts), |result| Some(*result))
+        }
+        unsafe extern "system" fn setLocalName(this: *mut core::ffi::c_void, nindex: i32, strlocalname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXAttributes", "setLocalName", 13usize, |this| ((**(this as *const *const IMXAttributes_Vtbl)).setLocalName)(this, nindex, strlocalname), |result| Some(*result))
+        }
+        unsafe extern "system" fn setQName(this: *mut core::ffi::c_void, nindex: i32, strqname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXAttributes", "setQName", 14usize, |this| ((**(this as *const *const IMXAttributes_Vtbl)).setQName)(this, nindex, strqname), |result| Some(*result))
+        }
+        unsafe extern "system" fn setType(this: *mut core::ffi::c_void, nindex: i32, strtype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXAttributes", "setType", 15usize, |this| ((**(this as *const *const IMXAttributes_Vtbl)).setType)(this, nindex, strtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn setURI(this: *mut core::ffi::c_void, nindex: i32, struri: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXAttributes", "setURI", 16usize, |this| ((**(this as *const *const IMXAttributes_Vtbl)).setURI)(this, nindex, struri), |result| Some(*result))
+        }
+        unsafe extern "system" fn setValue(this: *mut core::ffi::c_void, nindex: i32, strvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXAttributes", "setValue", 17usize, |this| ((**(this as *const *const IMXAttributes_Vtbl)).setValue)(this, nindex, strvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            addAttribute,
+            addAttributeFromIndex,
+            clear,
+            removeAttribute,
+            setAttribute,
+            setAttributes,
+            setLocalName,
+            setQName,
+            setType,
+            setURI,
+            setValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMXAttributes as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMXNamespaceManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn putAllowOverride(this: *mut core::ffi::c_void, foverride: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXNamespaceManager", "putAllowOverride", 3usize, |this| ((**(this as *const *const IMXNamespaceManager_Vtbl)).putAllowOverride)(this, foverride), |result| Some(*result))
+        }
+        unsafe extern "system" fn getAllowOverride(this: *mut core::ffi::c_void, foverride: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXNamespaceManager", "getAllowOverride", 4usize, |this| ((**(this as *const *const IMXNamespaceManager_Vtbl)).getAllowOverride)(this, foverride), |result| Some(*result))
+        }
+        unsafe extern "system" fn reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXNamespaceManager", "reset", 5usize, |this| ((**(this as *const *const IMXNamespaceManager_Vtbl)).reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn pushContext(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXNamespaceManager", "pushContext", 6usize, |this| ((**(this as *const *const IMXNamespaceManager_Vtbl)).pushContext)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn pushNodeContext(this: *mut core::ffi::c_void, contextnode: *mut core::ffi::c_void, fdeep: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXNamespaceManager", "pushNodeContext", 7usize, |this| ((**(this as *const *const IMXNamespaceManager_Vtbl)).pushNodeContext)(this, contextnode, fdeep), |result| Some(*result))
+        }
+        unsafe extern "system" fn popContext(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXNamespaceManager", "popContext", 8usize, |this| ((**(this as *const *const IMXNamespaceManager_Vtbl)).popContext)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn declarePrefix(this: *mut core::ffi::c_void, prefix: windows_core::PCWSTR, namespaceuri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXNamespaceManager", "declarePrefix", 9usize, |this| ((**(this as *const *const IMXNamespaceManager_Vtbl)).declarePrefix)(this, prefix, namespaceuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn getDeclaredPrefix(this: *mut core::ffi::c_void, nindex: i32, pwchprefix: windows_core::PWSTR, pcchprefix: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXNamespaceManager", "getDeclaredPrefix", 10usize, |this| ((**(this as *const *const IMXNamespaceManager_Vtbl)).getDeclaredPrefix)(this, nindex, pwchprefix, pcchprefix), |result| Some(*result))
+        }
+        unsafe extern "system" fn getPrefix(this: *mut core::ffi::c_void, pwsznamespaceuri: windows_core::PCWSTR, nindex: i32, pwchprefix: windows_core::PWSTR, pcchprefix: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXNamespaceManager", "getPrefix", 11usize, |this| ((**(this as *const *const IMXNamespaceManager_Vtbl)).getPrefix)(this, pwsznamespaceuri, nindex, pwchprefix, pcchprefix), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getURI(this: *mut core::ffi::c_void, pwchprefix: windows_core::PCWSTR, pcontextnode: *mut core::ffi::c_void, pwchuri: windows_core::PWSTR, pcchuri: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXNamespaceManager", "getURI", 12usize, |this| ((**(this as *const *const IMXNamespaceManager_Vtbl)).getURI)(this, pwchprefix, pcontextnode, pwchuri, pcchuri), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            putAllowOverride,
+            getAllowOverride,
+            reset,
+            pushContext,
+            #[cfg(feature = "Win32_System_Com")]
+            pushNodeContext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            pushNodeContext: 0,
+            popContext,
+            declarePrefix,
+            getDeclaredPrefix,
+            getPrefix,
+            #[cfg(feature = "Win32_System_Com")]
+            getURI,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getURI: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMXNamespaceManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMXNamespacePrefixes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_item(this: *mut core::ffi::c_void, index: i32, prefix: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXNamespacePrefixes", "get_item", 7usize, |this| ((**(this as *const *const IMXNamespacePrefixes_Vtbl)).get_item)(this, index, prefix), |result| Some(*result))
+        }
+        unsafe extern "system" fn length(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXNamespacePrefixes", "length", 8usize, |this| ((**(this as *const *const IMXNamespacePrefixes_Vtbl)).length)(this, length), |result| Some(*result))
+        }
+        unsafe extern "system" fn _newEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXNamespacePrefixes", "_newEnum", 9usize, |this| ((**(this as *const *const IMXNamespacePrefixes_Vtbl))._newEnum)(this, ppunk), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_item, length, _newEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMXNamespacePrefixes as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMXReaderControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXReaderControl", "abort", 7usize, |this| ((**(this as *const *const IMXReaderControl_Vtbl)).abort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXReaderControl", "resume", 8usize, |this| ((**(this as *const *const IMXReaderControl_Vtbl)).resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn suspend(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXReaderControl", "suspend", 9usize, |this| ((**(this as *const *const IMXReaderControl_Vtbl)).suspend)(this), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, abort, resume, suspend }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMXReaderControl as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMXSchemaDeclHandler_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn schemaElementDecl(this: *mut core::ffi::c_void, oschemaelement: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXSchemaDeclHandler", "schemaElementDecl", 7usize, |this| ((**(this as *const *const IMXSchemaDeclHandler_Vtbl)).schemaElementDecl)(this, oschemaelement), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            schemaElementDecl,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            schemaElementDecl: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMXSchemaDeclHandler as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMXWriter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Setoutput(this: *mut core::ffi::c_void, vardestination: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "Setoutput", 7usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).Setoutput)(this, vardestination), |result| Some(*result))
+        }
+        unsafe extern "system" fn output(this: *mut core::ffi::c_void, vardestination: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "output", 8usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).output)(this, vardestination), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setencoding(this: *mut core::ffi::c_void, strencoding: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "Setencoding", 9usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).Setencoding)(this, strencoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn encoding(this: *mut core::ffi::c_void, strencoding: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "encoding", 10usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).encoding)(this, strencoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetbyteOrderMark(this: *mut core::ffi::c_void, fwritebyteordermark: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "SetbyteOrderMark", 11usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).SetbyteOrderMark)(this, fwritebyteordermark), |result| Some(*result))
+        }
+        unsafe extern "system" fn byteOrderMark(this: *mut core::ffi::c_void, fwritebyteordermark: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "byteOrderMark", 12usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).byteOrderMark)(this, fwritebyteordermark), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setindent(this: *mut core::ffi::c_void, findentmode: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "Setindent", 13usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).Setindent)(this, findentmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn indent(this: *mut core::ffi::c_void, findentmode: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "indent", 14usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).indent)(this, findentmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setstandalone(this: *mut core::ffi::c_void, fvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "Setstandalone", 15usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).Setstandalone)(this, fvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn standalone(this: *mut core::ffi::c_void, fvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "standalone", 16usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).standalone)(this, fvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetomitXMLDeclaration(this: *mut core::ffi::c_void, fvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "SetomitXMLDeclaration", 17usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).SetomitXMLDeclaration)(this, fvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn omitXMLDeclaration(this: *mut core::ffi::c_void, fvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "omitXMLDeclaration", 18usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).omitXMLDeclaration)(this, fvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setversion(this: *mut core::ffi::c_void, strversion: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "Setversion", 19usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).Setversion)(this, strversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn version(this: *mut core::ffi::c_void, strversion: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "version", 20usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).version)(this, strversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetdisableOutputEscaping(this: *mut core::ffi::c_void, fvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "SetdisableOutputEscaping", 21usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).SetdisableOutputEscaping)(this, fvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn disableOutputEscaping(this: *mut core::ffi::c_void, fvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "disableOutputEscaping", 22usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).disableOutputEscaping)(this, fvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXWriter", "flush", 23usize, |this| ((**(this as *const *const IMXWriter_Vtbl)).flush)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Setoutput,
+            output,
+            Setencoding,
+            encoding,
+            SetbyteOrderMark,
+            byteOrderMark,
+            Setindent,
+            indent,
+            Setstandalone,
+            standalone,
+            SetomitXMLDeclaration,
+            omitXMLDeclaration,
+            Setversion,
+            version,
+            SetdisableOutputEscaping,
+            disableOutputEscaping,
+            flush,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMXWriter as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMXXMLFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn getFeature(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, fvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXXMLFilter", "getFeature", 7usize, |this| ((**(this as *const *const IMXXMLFilter_Vtbl)).getFeature)(this, strname, fvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn putFeature(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, fvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXXMLFilter", "putFeature", 8usize, |this| ((**(this as *const *const IMXXMLFilter_Vtbl)).putFeature)(this, strname, fvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn getProperty(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, varvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXXMLFilter", "getProperty", 9usize, |this| ((**(this as *const *const IMXXMLFilter_Vtbl)).getProperty)(this, strname, varvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn putProperty(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, varvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXXMLFilter", "putProperty", 10usize, |this| ((**(this as *const *const IMXXMLFilter_Vtbl)).putProperty)(this, strname, varvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn entityResolver(this: *mut core::ffi::c_void, oresolver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXXMLFilter", "entityResolver", 11usize, |this| ((**(this as *const *const IMXXMLFilter_Vtbl)).entityResolver)(this, oresolver), |result| Some(*result))
+        }
+        unsafe extern "system" fn putref_entityResolver(this: *mut core::ffi::c_void, oresolver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXXMLFilter", "putref_entityResolver", 12usize, |this| ((**(this as *const *const IMXXMLFilter_Vtbl)).putref_entityResolver)(this, oresolver), |result| Some(*result))
+        }
+        unsafe extern "system" fn contentHandler(this: *mut core::ffi::c_void, ohandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXXMLFilter", "contentHandler", 13usize, |this| ((**(this as *const *const IMXXMLFilter_Vtbl)).contentHandler)(this, ohandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn putref_contentHandler(this: *mut core::ffi::c_void, ohandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXXMLFilter", "putref_contentHandler", 14usize, |this| ((**(this as *const *const IMXXMLFilter_Vtbl)).putref_contentHandler)(this, ohandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn dtdHandler(this: *mut core::ffi::c_void, ohandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXXMLFilter", "dtdHandler", 15usize, |this| ((**(this as *const *const IMXXMLFilter_Vtbl)).dtdHandler)(this, ohandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn putref_dtdHandler(this: *mut core::ffi::c_void, ohandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXXMLFilter", "putref_dtdHandler", 16usize, |this| ((**(this as *const *const IMXXMLFilter_Vtbl)).putref_dtdHandler)(this, ohandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn errorHandler(this: *mut core::ffi::c_void, ohandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXXMLFilter", "errorHandler", 17usize, |this| ((**(this as *const *const IMXXMLFilter_Vtbl)).errorHandler)(this, ohandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn putref_errorHandler(this: *mut core::ffi::c_void, ohandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMXXMLFilter", "putref_errorHandler", 18usize, |this| ((**(this as *const *const IMXXMLFilter_Vtbl)).putref_errorHandler)(this, ohandler), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            getFeature,
+            putFeature,
+            getProperty,
+            putProperty,
+            entityResolver,
+            putref_entityResolver,
+            contentHandler,
+            putref_contentHandler,
+            dtdHandler,
+            putref_dtdHandler,
+            errorHandler,
+            putref_errorHandler,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMXXMLFilter as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISAXAttributes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn getLength(this: *mut core::ffi::c_void, pnlength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXAttributes", "getLength", 3usize, |this| ((**(this as *const *const ISAXAttributes_Vtbl)).getLength)(this, pnlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn getURI(this: *mut core::ffi::c_void, nindex: i32, ppwchuri: *mut *mut u16, pcchuri: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXAttributes", "getURI", 4usize, |this| ((**(this as *const *const ISAXAttributes_Vtbl)).getURI)(this, nindex, ppwchuri, pcchuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn getLocalName(this: *mut core::ffi::c_void, nindex: i32, ppwchlocalname: *mut *mut u16, pcchlocalname: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXAttributes", "getLocalName", 5usize, |this| ((**(this as *const *const ISAXAttributes_Vtbl)).getLocalName)(this, nindex, ppwchlocalname, pcchlocalname), |result| Some(*result))
+        }
+        unsafe extern "system" fn getQName(this: *mut core::ffi::c_void, nindex: i32, ppwchqname: *mut *mut u16, pcchqname: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXAttributes", "getQName", 6usize, |this| ((**(this as *const *const ISAXAttributes_Vtbl)).getQName)(this, nindex, ppwchqname, pcchqname), |result| Some(*result))
+        }
+        unsafe extern "system" fn getName(this: *mut core::ffi::c_void, nindex: i32, ppwchuri: *mut *mut u16, pcchuri: *mut i32, ppwchlocalname: *mut *mut u16, pcchlocalname: *mut i32, ppwchqname: *mut *mut u16, pcchqname: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXAttributes", "getName", 7usize, |this| ((**(this as *const *const ISAXAttributes_Vtbl)).getName)(this, nindex, ppwchuri, pcchuri, ppwchlocalname, pcchlocalname, ppwchqname, pcchqname), |result| Some(*result))
+        }
+        unsafe extern "system" fn getIndexFromName(this: *mut core::ffi::c_void, pwchuri: windows_core::PCWSTR, cchuri: i32, pwchlocalname: windows_core::PCWSTR, cchlocalname: i32, pnindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXAttributes", "getIndexFromName", 8usize, |this| ((**(this as *const *const ISAXAttributes_Vtbl)).getIndexFromName)(this, pwchuri, cchuri, pwchlocalname, cchlocalname, pnindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn getIndexFromQName(this: *mut core::ffi::c_void, pwchqname: windows_core::PCWSTR, cchqname: i32, pnindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXAttributes", "getIndexFromQName", 9usize, |this| ((**(this as *const *const ISAXAttributes_Vtbl)).getIndexFromQName)(this, pwchqname, cchqname, pnindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn getType(this: *mut core::ffi::c_void, nindex: i32, ppwchtype: *mut *mut u16, pcchtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXAttributes", "getType", 10usize, |this| ((**(this as *const *const ISAXAttributes_Vtbl)).getType)(this, nindex, ppwchtype, pcchtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn getTypeFromName(this: *mut core::ffi::c_void, pwchuri: windows_core::PCWSTR, cchuri: i32, pwchlocalname: windows_core::PCWSTR, cchlocalname: i32, ppwchtype: *mut *mut u16, pcchtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXAttributes", "getTypeFromName", 11usize, |this| ((**(this as *const *const ISAXAttributes_Vtbl)).getTypeFromName)(this, pwchuri, cchuri, pwchlocalname, cchlocalname, ppwchtype, pcchtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn getTypeFromQName(this: *mut core::ffi::c_void, pwchqname: windows_core::PCWSTR, cchqname: i32, ppwchtype: *mut *mut u16, pcchtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXAttributes", "getTypeFromQName", 12usize, |this| ((**(this as *const *const ISAXAttributes_Vtbl)).getTypeFromQName)(this, pwchqname, cchqname, ppwchtype, pcchtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn getValue(this: *mut core::ffi::c_void, nindex: i32, ppwchvalue: *mut *mut u16, pcchvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXAttributes", "getValue", 13usize, |this| ((**(this as *const *const ISAXAttributes_Vtbl)).getValue)(this, nindex, ppwchvalue, pcchvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn getValueFromName(this: *mut core::ffi::c_void, pwchuri: windows_core::PCWSTR, cchuri: i32, pwchlocalname: windows_core::PCWSTR, cchlocalname: i32, ppwchvalue: *mut *mut u16, pcchvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXAttributes", "getValueFromName", 14usize, |this| ((**(this as *const *const ISAXAttributes_Vtbl)).getValueFromName)(this, pwchuri, cchuri, pwchlocalname, cchlocalname, ppwchvalue, pcchvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn getValueFromQName(this: *mut core::ffi::c_void, pwchqname: windows_core::PCWSTR, cchqname: i32, ppwchvalue: *mut *mut u16, pcchvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXAttributes", "getValueFromQName", 15usize, |this| ((**(this as *const *const ISAXAttributes_Vtbl)).getValueFromQName)(this, pwchqname, cchqname, ppwchvalue, pcchvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            getLength,
+            getURI,
+            getLocalName,
+            getQName,
+            getName,
+            getIndexFromName,
+            getIndexFromQName,
+            getType,
+            getTypeFromName,
+            getTypeFromQName,
+            getValue,
+            getValueFromName,
+            getValueFromQName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISAXAttributes as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISAXContentHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn putDocumentLocator(this: *mut core::ffi::c_void, plocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXContentHandler", "putDocumentLocator", 3usize, |this| ((**(this as *const *const ISAXContentHandler_Vtbl)).putDocumentLocator)(this, plocator), |result| Some(*result))
+        }
+        unsafe extern "system" fn startDocument(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXContentHandler", "startDocument", 4usize, |this| ((**(this as *const *const ISAXContentHandler_Vtbl)).startDocument)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn endDocument(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXContentHandler", "endDocument", 5usize, |this| ((**(this as *const *const ISAXContentHandler_Vtbl)).endDocument)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn startPrefixMapping(this: *mut core::ffi::c_void, pwchprefix: windows_core::PCWSTR, cchprefix: i32, pwchuri: windows_core::PCWSTR, cchuri: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXContentHandler", "startPrefixMapping", 6usize, |this| ((**(this as *const *const ISAXContentHandler_Vtbl)).startPrefixMapping)(this, pwchprefix, cchprefix, pwchuri, cchuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn endPrefixMapping(this: *mut core::ffi::c_void, pwchprefix: windows_core::PCWSTR, cchprefix: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXContentHandler", "endPrefixMapping", 7usize, |this| ((**(this as *const *const ISAXContentHandler_Vtbl)).endPrefixMapping)(this, pwchprefix, cchprefix), |result| Some(*result))
+        }
+        unsafe extern "system" fn startElement(this: *mut core::ffi::c_void, pwchnamespaceuri: windows_core::PCWSTR, cchnamespaceuri: i32, pwchlocalname: windows_core::PCWSTR, cchlocalname: i32, pwchqname: windows_core::PCWSTR, cchqname: i32, pattributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXContentHandler", "startElement", 8usize, |this| ((**(this as *const *const ISAXContentHandler_Vtbl)).startElement)(this, pwchnamespaceuri, cchnamespaceuri, pwchlocalname, cchlocalname, pwchqname, cchqname, pattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn endElement(this: *mut core::ffi::c_void, pwchnamespaceuri: windows_core::PCWSTR, cchnamespaceuri: i32, pwchlocalname: windows_core::PCWSTR, cchlocalname: i32, pwchqname: windows_core::PCWSTR, cchqname: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXContentHandler", "endElement", 9usize, |this| ((**(this as *const *const ISAXContentHandler_Vtbl)).endElement)(this, pwchnamespaceuri, cchnamespaceuri, pwchlocalname, cchlocalname, pwchqname, cchqname), |result| Some(*result))
+        }
+        unsafe extern "system" fn characters(this: *mut core::ffi::c_void, pwchchars: windows_core::PCWSTR, cchchars: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXContentHandler", "characters", 10usize, |this| ((**(this as *const *const ISAXContentHandler_Vtbl)).characters)(this, pwchchars, cchchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn ignorableWhitespace(this: *mut core::ffi::c_void, pwchchars: windows_core::PCWSTR, cchchars: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXContentHandler", "ignorableWhitespace", 11usize, |this| ((**(this as *const *const ISAXContentHandler_Vtbl)).ignorableWhitespace)(this, pwchchars, cchchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn processingInstruction(this: *mut core::ffi::c_void, pwchtarget: windows_core::PCWSTR, cchtarget: i32, pwchdata: windows_core::PCWSTR, cchdata: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXContentHandler", "processingInstruction", 12usize, |this| ((**(this as *const *const ISAXContentHandler_Vtbl)).processingInstruction)(this, pwchtarget, cchtarget, pwchdata, cchdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn skippedEntity(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXContentHandler", "skippedEntity", 13usize, |this| ((**(this as *const *const ISAXContentHandler_Vtbl)).skippedEntity)(this, pwchname, cchname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            putDocumentLocator,
+            startDocument,
+            endDocument,
+            startPrefixMapping,
+            endPrefixMapping,
+            startElement,
+            endElement,
+            characters,
+            ignorableWhitespace,
+            processingInstruction,
+            skippedEntity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISAXContentHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISAXDTDHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn notationDecl(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32, pwchpublicid: windows_core::PCWSTR, cchpublicid: i32, pwchsystemid: windows_core::PCWSTR, cchsystemid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXDTDHandler", "notationDecl", 3usize, |this| ((**(this as *const *const ISAXDTDHandler_Vtbl)).notationDecl)(this, pwchname, cchname, pwchpublicid, cchpublicid, pwchsystemid, cchsystemid), |result| Some(*result))
+        }
+        unsafe extern "system" fn unparsedEntityDecl(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32, pwchpublicid: windows_core::PCWSTR, cchpublicid: i32, pwchsystemid: windows_core::PCWSTR, cchsystemid: i32, pwchnotationname: windows_core::PCWSTR, cchnotationname: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXDTDHandler", "unparsedEntityDecl", 4usize, |this| ((**(this as *const *const ISAXDTDHandler_Vtbl)).unparsedEntityDecl)(this, pwchname, cchname, pwchpublicid, cchpublicid, pwchsystemid, cchsystemid, pwchnotationname, cchnotationname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, notationDecl, unparsedEntityDecl }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISAXDTDHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISAXDeclHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn elementDecl(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32, pwchmodel: windows_core::PCWSTR, cchmodel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXDeclHandler", "elementDecl", 3usize, |this| ((**(this as *const *const ISAXDeclHandler_Vtbl)).elementDecl)(this, pwchname, cchname, pwchmodel, cchmodel), |result| Some(*result))
+        }
+        unsafe extern "system" fn attributeDecl(this: *mut core::ffi::c_void, pwchelementname: windows_core::PCWSTR, cchelementname: i32, pwchattributename: windows_core::PCWSTR, cchattributename: i32, pwchtype: windows_core::PCWSTR, cchtype: i32, pwchvaluedefault: windows_core::PCWSTR, cchvaluedefault: i32, pwchvalue: windows_core::PCWSTR, cchvalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXDeclHandler", "attributeDecl", 4usize, |this| ((**(this as *const *const ISAXDeclHandler_Vtbl)).attributeDecl)(this, pwchelementname, cchelementname, pwchattributename, cchattributename, pwchtype, cchtype, pwchvaluedefault, cchvaluedefault, pwchvalue, cchvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn internalEntityDecl(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32, pwchvalue: windows_core::PCWSTR, cchvalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXDeclHandler", "internalEntityDecl", 5usize, |this| ((**(this as *const *const ISAXDeclHandler_Vtbl)).internalEntityDecl)(this, pwchname, cchname, pwchvalue, cchvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn externalEntityDecl(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32, pwchpublicid: windows_core::PCWSTR, cchpublicid: i32, pwchsystemid: windows_core::PCWSTR, cchsystemid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXDeclHandler", "externalEntityDecl", 6usize, |this| ((**(this as *const *const ISAXDeclHandler_Vtbl)).externalEntityDecl)(this, pwchname, cchname, pwchpublicid, cchpublicid, pwchsystemid, cchsystemid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            elementDecl,
+            attributeDecl,
+            internalEntityDecl,
+            externalEntityDecl,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISAXDeclHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISAXEntityResolver_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn resolveEntity(this: *mut core::ffi::c_void, pwchpublicid: windows_core::PCWSTR, pwchsystemid: windows_core::PCWSTR, pvarinput: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXEntityResolver", "resolveEntity", 3usize, |this| ((**(this as *const *const ISAXEntityResolver_Vtbl)).resolveEntity)(this, pwchpublicid, pwchsystemid, pvarinput), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, resolveEntity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISAXEntityResolver as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISAXErrorHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn error(this: *mut core::ffi::c_void, plocator: *mut core::ffi::c_void, pwcherrormessage: windows_core::PCWSTR, hrerrorcode: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXErrorHandler", "error", 3usize, |this| ((**(this as *const *const ISAXErrorHandler_Vtbl)).error)(this, plocator, pwcherrormessage, hrerrorcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn fatalError(this: *mut core::ffi::c_void, plocator: *mut core::ffi::c_void, pwcherrormessage: windows_core::PCWSTR, hrerrorcode: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXErrorHandler", "fatalError", 4usize, |this| ((**(this as *const *const ISAXErrorHandler_Vtbl)).fatalError)(this, plocator, pwcherrormessage, hrerrorcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn ignorableWarning(this: *mut core::ffi::c_void, plocator: *mut core::ffi::c_void, pwcherrormessage: windows_core::PCWSTR, hrerrorcode: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXErrorHandler", "ignorableWarning", 5usize, |this| ((**(this as *const *const ISAXErrorHandler_Vtbl)).ignorableWarning)(this, plocator, pwcherrormessage, hrerrorcode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, error, fatalError, ignorableWarning }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISAXErrorHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISAXLexicalHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn startDTD(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32, pwchpublicid: windows_core::PCWSTR, cchpublicid: i32, pwchsystemid: windows_core::PCWSTR, cchsystemid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXLexicalHandler", "startDTD", 3usize, |this| ((**(this as *const *const ISAXLexicalHandler_Vtbl)).startDTD)(this, pwchname, cchname, pwchpublicid, cchpublicid, pwchsystemid, cchsystemid), |result| Some(*result))
+        }
+        unsafe extern "system" fn endDTD(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXLexicalHandler", "endDTD", 4usize, |this| ((**(this as *const *const ISAXLexicalHandler_Vtbl)).endDTD)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn startEntity(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXLexicalHandler", "startEntity", 5usize, |this| ((**(this as *const *const ISAXLexicalHandler_Vtbl)).startEntity)(this, pwchname, cchname), |result| Some(*result))
+        }
+        unsafe extern "system" fn endEntity(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXLexicalHandler", "endEntity", 6usize, |this| ((**(this as *const *const ISAXLexicalHandler_Vtbl)).endEntity)(this, pwchname, cchname), |result| Some(*result))
+        }
+        unsafe extern "system" fn startCDATA(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXLexicalHandler", "startCDATA", 7usize, |this| ((**(this as *const *const ISAXLexicalHandler_Vtbl)).startCDATA)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn endCDATA(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXLexicalHandler", "endCDATA", 8usize, |this| ((**(this as *const *const ISAXLexicalHandler_Vtbl)).endCDATA)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn comment(this: *mut core::ffi::c_void, pwchchars: windows_core::PCWSTR, cchchars: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXLexicalHandler", "comment", 9usize, |this| ((**(this as *const *const ISAXLexicalHandler_Vtbl)).comment)(this, pwchchars, cchchars), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            startDTD,
+            endDTD,
+            startEntity,
+            endEntity,
+            startCDATA,
+            endCDATA,
+            comment,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISAXLexicalHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISAXLocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn getColumnNumber(this: *mut core::ffi::c_void, pncolumn: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXLocator", "getColumnNumber", 3usize, |this| ((**(this as *const *const ISAXLocator_Vtbl)).getColumnNumber)(this, pncolumn), |result| Some(*result))
+        }
+        unsafe extern "system" fn getLineNumber(this: *mut core::ffi::c_void, pnline: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXLocator", "getLineNumber", 4usize, |this| ((**(this as *const *const ISAXLocator_Vtbl)).getLineNumber)(this, pnline), |result| Some(*result))
+        }
+        unsafe extern "system" fn getPublicId(this: *mut core::ffi::c_void, ppwchpublicid: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXLocator", "getPublicId", 5usize, |this| ((**(this as *const *const ISAXLocator_Vtbl)).getPublicId)(this, ppwchpublicid), |result| Some(*result))
+        }
+        unsafe extern "system" fn getSystemId(this: *mut core::ffi::c_void, ppwchsystemid: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXLocator", "getSystemId", 6usize, |this| ((**(this as *const *const ISAXLocator_Vtbl)).getSystemId)(this, ppwchsystemid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, getColumnNumber, getLineNumber, getPublicId, getSystemId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISAXLocator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISAXXMLFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn getParent(this: *mut core::ffi::c_void, ppreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLFilter", "getParent", 21usize, |this| ((**(this as *const *const ISAXXMLFilter_Vtbl)).getParent)(this, ppreader), |result| Some(*result))
+        }
+        unsafe extern "system" fn putParent(this: *mut core::ffi::c_void, preader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLFilter", "putParent", 22usize, |this| ((**(this as *const *const ISAXXMLFilter_Vtbl)).putParent)(this, preader), |result| Some(*result))
+        }
+        Self { base__: <ISAXXMLReader_Vtbl as windows_core::imp::ProxyVtable>::PROXY, getParent, putParent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISAXXMLFilter as windows_core::Interface>::IID || <ISAXXMLReader_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISAXXMLReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn getFeature(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, pvfvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "getFeature", 3usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).getFeature)(this, pwchname, pvfvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn putFeature(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, vfvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "putFeature", 4usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).putFeature)(this, pwchname, vfvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn getProperty(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "getProperty", 5usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).getProperty)(this, pwchname, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn putProperty(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, varvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "putProperty", 6usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).putProperty)(this, pwchname, varvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn getEntityResolver(this: *mut core::ffi::c_void, ppresolver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "getEntityResolver", 7usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).getEntityResolver)(this, ppresolver), |result| Some(*result))
+        }
+        unsafe extern "system" fn putEntityResolver(this: *mut core::ffi::c_void, presolver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "putEntityResolver", 8usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).putEntityResolver)(this, presolver), |result| Some(*result))
+        }
+        unsafe extern "system" fn getContentHandler(this: *mut core::ffi::c_void, pphandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "getContentHandler", 9usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).getContentHandler)(this, pphandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn putContentHandler(this: *mut core::ffi::c_void, phandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "putContentHandler", 10usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).putContentHandler)(this, phandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn getDTDHandler(this: *mut core::ffi::c_void, pphandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "getDTDHandler", 11usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).getDTDHandler)(this, pphandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn putDTDHandler(this: *mut core::ffi::c_void, phandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "putDTDHandler", 12usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).putDTDHandler)(this, phandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn getErrorHandler(this: *mut core::ffi::c_void, pphandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "getErrorHandler", 13usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).getErrorHandler)(this, pphandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn putErrorHandler(this: *mut core::ffi::c_void, phandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "putErrorHandler", 14usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).putErrorHandler)(this, phandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn getBaseURL(this: *mut core::ffi::c_void, ppwchbaseurl: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "getBaseURL", 15usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).getBaseURL)(this, ppwchbaseurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn putBaseURL(this: *mut core::ffi::c_void, pwchbaseurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "putBaseURL", 16usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).putBaseURL)(this, pwchbaseurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn getSecureBaseURL(this: *mut core::ffi::c_void, ppwchsecurebaseurl: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "getSecureBaseURL", 17usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).getSecureBaseURL)(this, ppwchsecurebaseurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn putSecureBaseURL(this: *mut core::ffi::c_void, pwchsecurebaseurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "putSecureBaseURL", 18usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).putSecureBaseURL)(this, pwchsecurebaseurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn parse(this: *mut core::ffi::c_void, varinput: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "parse", 19usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).parse)(this, varinput), |result| Some(*result))
+        }
+        unsafe extern "system" fn parseURL(this: *mut core::ffi::c_void, pwchurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISAXXMLReader", "parseURL", 20usize, |this| ((**(this as *const *const ISAXXMLReader_Vtbl)).parseURL)(this, pwchurl), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            getFeature,
+            putFeature,
+            getProperty,
+            putProperty,
+            getEntityResolver,
+            putEntityResolver,
+            getContentHandler,
+            putContentHandler,
+            getDTDHandler,
+            putDTDHandler,
+            getErrorHandler,
+            putErrorHandler,
+            getBaseURL,
+            putBaseURL,
+            getSecureBaseURL,
+            putSecureBaseURL,
+            parse,
+            parseURL,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISAXXMLReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISchema_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn targetNamespace(this: *mut core::ffi::c_void, targetnamespace: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchema", "targetNamespace", 14usize, |this| ((**(this as *const *const ISchema_Vtbl)).targetNamespace)(this, targetnamespace), |result| Some(*result))
+        }
+        unsafe extern "system" fn version(this: *mut core::ffi::c_void, version: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchema", "version", 15usize, |this| ((**(this as *const *const ISchema_Vtbl)).version)(this, version), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn types(this: *mut core::ffi::c_void, types: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchema", "types", 16usize, |this| ((**(this as *const *const ISchema_Vtbl)).types)(this, types), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn elements(this: *mut core::ffi::c_void, elements: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchema", "elements", 17usize, |this| ((**(this as *const *const ISchema_Vtbl)).elements)(this, elements), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn attributes(this: *mut core::ffi::c_void, attributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchema", "attributes", 18usize, |this| ((**(this as *const *const ISchema_Vtbl)).attributes)(this, attributes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn attributeGroups(this: *mut core::ffi::c_void, attributegroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchema", "attributeGroups", 19usize, |this| ((**(this as *const *const ISchema_Vtbl)).attributeGroups)(this, attributegroups), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn modelGroups(this: *mut core::ffi::c_void, modelgroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchema", "modelGroups", 20usize, |this| ((**(this as *const *const ISchema_Vtbl)).modelGroups)(this, modelgroups), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn notations(this: *mut core::ffi::c_void, notations: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchema", "notations", 21usize, |this| ((**(this as *const *const ISchema_Vtbl)).notations)(this, notations), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn schemaLocations(this: *mut core::ffi::c_void, schemalocations: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchema", "schemaLocations", 22usize, |this| ((**(this as *const *const ISchema_Vtbl)).schemaLocations)(this, schemalocations), |result| Some(*result))
+        }
+        Self {
+            base__: <ISchemaItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            targetNamespace,
+            version,
+            #[cfg(feature = "Win32_System_Com")]
+            types,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            types: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            elements,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            elements: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            attributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            attributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            attributeGroups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            attributeGroups: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            modelGroups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            modelGroups: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            notations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            notations: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            schemaLocations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            schemaLocations: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchema as windows_core::Interface>::IID || <ISchemaItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISchemaAny_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn namespaces(this: *mut core::ffi::c_void, namespaces: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaAny", "namespaces", 16usize, |this| ((**(this as *const *const ISchemaAny_Vtbl)).namespaces)(this, namespaces), |result| Some(*result))
+        }
+        unsafe extern "system" fn processContents(this: *mut core::ffi::c_void, processcontents: *mut SCHEMAPROCESSCONTENTS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaAny", "processContents", 17usize, |this| ((**(this as *const *const ISchemaAny_Vtbl)).processContents)(this, processcontents), |result| Some(*result))
+        }
+        Self {
+            base__: <ISchemaParticle_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            namespaces,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            namespaces: 0,
+            processContents,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaAny as windows_core::Interface>::IID || <ISchemaParticle_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISchemaAttribute_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn r#type(this: *mut core::ffi::c_void, r#type: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaAttribute", "r#type", 14usize, |this| ((**(this as *const *const ISchemaAttribute_Vtbl)).r#type)(this, r#type), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn scope(this: *mut core::ffi::c_void, scope: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaAttribute", "scope", 15usize, |this| ((**(this as *const *const ISchemaAttribute_Vtbl)).scope)(this, scope), |result| Some(*result))
+        }
+        unsafe extern "system" fn defaultValue(this: *mut core::ffi::c_void, defaultvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaAttribute", "defaultValue", 16usize, |this| ((**(this as *const *const ISchemaAttribute_Vtbl)).defaultValue)(this, defaultvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn fixedValue(this: *mut core::ffi::c_void, fixedvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaAttribute", "fixedValue", 17usize, |this| ((**(this as *const *const ISchemaAttribute_Vtbl)).fixedValue)(this, fixedvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn r#use(this: *mut core::ffi::c_void, r#use: *mut SCHEMAUSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaAttribute", "r#use", 18usize, |this| ((**(this as *const *const ISchemaAttribute_Vtbl)).r#use)(this, r#use), |result| Some(*result))
+        }
+        unsafe extern "system" fn isReference(this: *mut core::ffi::c_void, reference: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaAttribute", "isReference", 19usize, |this| ((**(this as *const *const ISchemaAttribute_Vtbl)).isReference)(this, reference), |result| Some(*result))
+        }
+        Self {
+            base__: <ISchemaItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            r#type,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            r#type: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            scope,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            scope: 0,
+            defaultValue,
+            fixedValue,
+            r#use,
+            isReference,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaAttribute as windows_core::Interface>::IID || <ISchemaItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISchemaAttributeGroup_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn anyAttribute(this: *mut core::ffi::c_void, anyattribute: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaAttributeGroup", "anyAttribute", 14usize, |this| ((**(this as *const *const ISchemaAttributeGroup_Vtbl)).anyAttribute)(this, anyattribute), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn attributes(this: *mut core::ffi::c_void, attributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaAttributeGroup", "attributes", 15usize, |this| ((**(this as *const *const ISchemaAttributeGroup_Vtbl)).attributes)(this, attributes), |result| Some(*result))
+        }
+        Self {
+            base__: <ISchemaItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            anyAttribute,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            anyAttribute: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            attributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            attributes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaAttributeGroup as windows_core::Interface>::IID || <ISchemaItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISchemaComplexType_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn isAbstract(this: *mut core::ffi::c_void, r#abstract: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaComplexType", "isAbstract", 31usize, |this| ((**(this as *const *const ISchemaComplexType_Vtbl)).isAbstract)(this, r#abstract), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn anyAttribute(this: *mut core::ffi::c_void, anyattribute: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaComplexType", "anyAttribute", 32usize, |this| ((**(this as *const *const ISchemaComplexType_Vtbl)).anyAttribute)(this, anyattribute), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn attributes(this: *mut core::ffi::c_void, attributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaComplexType", "attributes", 33usize, |this| ((**(this as *const *const ISchemaComplexType_Vtbl)).attributes)(this, attributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn contentType(this: *mut core::ffi::c_void, contenttype: *mut SCHEMACONTENTTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaComplexType", "contentType", 34usize, |this| ((**(this as *const *const ISchemaComplexType_Vtbl)).contentType)(this, contenttype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn contentModel(this: *mut core::ffi::c_void, contentmodel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaComplexType", "contentModel", 35usize, |this| ((**(this as *const *const ISchemaComplexType_Vtbl)).contentModel)(this, contentmodel), |result| Some(*result))
+        }
+        unsafe extern "system" fn prohibitedSubstitutions(this: *mut core::ffi::c_void, prohibited: *mut SCHEMADERIVATIONMETHOD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaComplexType", "prohibitedSubstitutions", 36usize, |this| ((**(this as *const *const ISchemaComplexType_Vtbl)).prohibitedSubstitutions)(this, prohibited), |result| Some(*result))
+        }
+        Self {
+            base__: <ISchemaType_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            isAbstract,
+            #[cfg(feature = "Win32_System_Com")]
+            anyAttribute,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            anyAttribute: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            attributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            attributes: 0,
+            contentType,
+            #[cfg(feature = "Win32_System_Com")]
+            contentModel,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            contentModel: 0,
+            prohibitedSubstitutions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaComplexType as windows_core::Interface>::IID || <ISchemaType_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISchemaElement_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn r#type(this: *mut core::ffi::c_void, r#type: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaElement", "r#type", 16usize, |this| ((**(this as *const *const ISchemaElement_Vtbl)).r#type)(this, r#type), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn scope(this: *mut core::ffi::c_void, scope: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaElement", "scope", 17usize, |this| ((**(this as *const *const ISchemaElement_Vtbl)).scope)(this, scope), |result| Some(*result))
+        }
+        unsafe extern "system" fn defaultValue(this: *mut core::ffi::c_void, defaultvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaElement", "defaultValue", 18usize, |this| ((**(this as *const *const ISchemaElement_Vtbl)).defaultValue)(this, defaultvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn fixedValue(this: *mut core::ffi::c_void, fixedvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaElement", "fixedValue", 19usize, |this| ((**(this as *const *const ISchemaElement_Vtbl)).fixedValue)(this, fixedvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn isNillable(this: *mut core::ffi::c_void, nillable: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaElement", "isNillable", 20usize, |this| ((**(this as *const *const ISchemaElement_Vtbl)).isNillable)(this, nillable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn identityConstraints(this: *mut core::ffi::c_void, constraints: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaElement", "identityConstraints", 21usize, |this| ((**(this as *const *const ISchemaElement_Vtbl)).identityConstraints)(this, constraints), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn substitutionGroup(this: *mut core::ffi::c_void, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaElement", "substitutionGroup", 22usize, |this| ((**(this as *const *const ISchemaElement_Vtbl)).substitutionGroup)(this, element), |result| Some(*result))
+        }
+        unsafe extern "system" fn substitutionGroupExclusions(this: *mut core::ffi::c_void, exclusions: *mut SCHEMADERIVATIONMETHOD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaElement", "substitutionGroupExclusions", 23usize, |this| ((**(this as *const *const ISchemaElement_Vtbl)).substitutionGroupExclusions)(this, exclusions), |result| Some(*result))
+        }
+        unsafe extern "system" fn disallowedSubstitutions(this: *mut core::ffi::c_void, disallowed: *mut SCHEMADERIVATIONMETHOD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaElement", "disallowedSubstitutions", 24usize, |this| ((**(this as *const *const ISchemaElement_Vtbl)).disallowedSubstitutions)(this, disallowed), |result| Some(*result))
+        }
+        unsafe extern "system" fn isAbstract(this: *mut core::ffi::c_void, r#abstract: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaElement", "isAbstract", 25usize, |this| ((**(this as *const *const ISchemaElement_Vtbl)).isAbstract)(this, r#abstract), |result| Some(*result))
+        }
+        unsafe extern "system" fn isReference(this: *mut core::ffi::c_void, reference: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaElement", "isReference", 26usize, |this| ((**(this as *const *const ISchemaElement_Vtbl)).isReference)(this, reference), |result| Some(*result))
+        }
+        Self {
+            base__: <ISchemaParticle_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            r#type,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            r#type: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            scope,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            scope: 0,
+            defaultValue,
+            fixedValue,
+            isNillable,
+            #[cfg(feature = "Win32_System_Com")]
+            identityConstraints,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            identityConstraints: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            substitutionGroup,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            substitutionGroup: 0,
+            substitutionGroupExclusions,
+            disallowedSubstitutions,
+            isAbstract,
+            isReference,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaElement as windows_core::Interface>::IID || <ISchemaParticle_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISchemaIdentityConstraint_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn selector(this: *mut core::ffi::c_void, selector: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaIdentityConstraint", "selector", 14usize, |this| ((**(this as *const *const ISchemaIdentityConstraint_Vtbl)).selector)(this, selector), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn fields(this: *mut core::ffi::c_void, fields: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaIdentityConstraint", "fields", 15usize, |this| ((**(this as *const *const ISchemaIdentityConstraint_Vtbl)).fields)(this, fields), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn referencedKey(this: *mut core::ffi::c_void, key: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaIdentityConstraint", "referencedKey", 16usize, |this| ((**(this as *const *const ISchemaIdentityConstraint_Vtbl)).referencedKey)(this, key), |result| Some(*result))
+        }
+        Self {
+            base__: <ISchemaItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            selector,
+            #[cfg(feature = "Win32_System_Com")]
+            fields,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            fields: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            referencedKey,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            referencedKey: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaIdentityConstraint as windows_core::Interface>::IID || <ISchemaItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISchemaItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaItem", "name", 7usize, |this| ((**(this as *const *const ISchemaItem_Vtbl)).name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn namespaceURI(this: *mut core::ffi::c_void, namespaceuri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaItem", "namespaceURI", 8usize, |this| ((**(this as *const *const ISchemaItem_Vtbl)).namespaceURI)(this, namespaceuri), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn schema(this: *mut core::ffi::c_void, schema: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaItem", "schema", 9usize, |this| ((**(this as *const *const ISchemaItem_Vtbl)).schema)(this, schema), |result| Some(*result))
+        }
+        unsafe extern "system" fn id(this: *mut core::ffi::c_void, id: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaItem", "id", 10usize, |this| ((**(this as *const *const ISchemaItem_Vtbl)).id)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn itemType(this: *mut core::ffi::c_void, itemtype: *mut SOMITEMTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaItem", "itemType", 11usize, |this| ((**(this as *const *const ISchemaItem_Vtbl)).itemType)(this, itemtype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn unhandledAttributes(this: *mut core::ffi::c_void, attributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaItem", "unhandledAttributes", 12usize, |this| ((**(this as *const *const ISchemaItem_Vtbl)).unhandledAttributes)(this, attributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn writeAnnotation(this: *mut core::ffi::c_void, annotationsink: *mut core::ffi::c_void, iswritten: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaItem", "writeAnnotation", 13usize, |this| ((**(this as *const *const ISchemaItem_Vtbl)).writeAnnotation)(this, annotationsink, iswritten), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            name,
+            namespaceURI,
+            #[cfg(feature = "Win32_System_Com")]
+            schema,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            schema: 0,
+            id,
+            itemType,
+            #[cfg(feature = "Win32_System_Com")]
+            unhandledAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            unhandledAttributes: 0,
+            writeAnnotation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaItem as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISchemaItemCollection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_item(this: *mut core::ffi::c_void, index: i32, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaItemCollection", "get_item", 7usize, |this| ((**(this as *const *const ISchemaItemCollection_Vtbl)).get_item)(this, index, item), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn itemByName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaItemCollection", "itemByName", 8usize, |this| ((**(this as *const *const ISchemaItemCollection_Vtbl)).itemByName)(this, name, item), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn itemByQName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, namespaceuri: core::mem::MaybeUninit<windows_core::BSTR>, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaItemCollection", "itemByQName", 9usize, |this| ((**(this as *const *const ISchemaItemCollection_Vtbl)).itemByQName)(this, name, namespaceuri, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn length(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaItemCollection", "length", 10usize, |this| ((**(this as *const *const ISchemaItemCollection_Vtbl)).length)(this, length), |result| Some(*result))
+        }
+        unsafe extern "system" fn _newEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaItemCollection", "_newEnum", 11usize, |this| ((**(this as *const *const ISchemaItemCollection_Vtbl))._newEnum)(this, ppunk), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            itemByName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            itemByName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            itemByQName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            itemByQName: 0,
+            length,
+            _newEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaItemCollection as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISchemaModelGroup_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn particles(this: *mut core::ffi::c_void, particles: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaModelGroup", "particles", 16usize, |this| ((**(this as *const *const ISchemaModelGroup_Vtbl)).particles)(this, particles), |result| Some(*result))
+        }
+        Self {
+            base__: <ISchemaParticle_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            particles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            particles: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaModelGroup as windows_core::Interface>::IID || <ISchemaParticle_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISchemaNotation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn systemIdentifier(this: *mut core::ffi::c_void, uri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaNotation", "systemIdentifier", 14usize, |this| ((**(this as *const *const ISchemaNotation_Vtbl)).systemIdentifier)(this, uri), |result| Some(*result))
+        }
+        unsafe extern "system" fn publicIdentifier(this: *mut core::ffi::c_void, uri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaNotation", "publicIdentifier", 15usize, |this| ((**(this as *const *const ISchemaNotation_Vtbl)).publicIdentifier)(this, uri), |result| Some(*result))
+        }
+        Self { base__: <ISchemaItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY, systemIdentifier, publicIdentifier }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaNotation as windows_core::Interface>::IID || <ISchemaItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISchemaParticle_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn minOccurs(this: *mut core::ffi::c_void, minoccurs: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaParticle", "minOccurs", 14usize, |this| ((**(this as *const *const ISchemaParticle_Vtbl)).minOccurs)(this, minoccurs), |result| Some(*result))
+        }
+        unsafe extern "system" fn maxOccurs(this: *mut core::ffi::c_void, maxoccurs: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaParticle", "maxOccurs", 15usize, |this| ((**(this as *const *const ISchemaParticle_Vtbl)).maxOccurs)(this, maxoccurs), |result| Some(*result))
+        }
+        Self { base__: <ISchemaItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY, minOccurs, maxOccurs }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaParticle as windows_core::Interface>::IID || <ISchemaItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISchemaStringCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_item(this: *mut core::ffi::c_void, index: i32, bstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaStringCollection", "get_item", 7usize, |this| ((**(this as *const *const ISchemaStringCollection_Vtbl)).get_item)(this, index, bstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn length(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaStringCollection", "length", 8usize, |this| ((**(this as *const *const ISchemaStringCollection_Vtbl)).length)(this, length), |result| Some(*result))
+        }
+        unsafe extern "system" fn _newEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaStringCollection", "_newEnum", 9usize, |this| ((**(this as *const *const ISchemaStringCollection_Vtbl))._newEnum)(this, ppunk), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_item, length, _newEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaStringCollection as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISchemaType_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn baseTypes(this: *mut core::ffi::c_void, basetypes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "baseTypes", 14usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).baseTypes)(this, basetypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn r#final(this: *mut core::ffi::c_void, r#final: *mut SCHEMADERIVATIONMETHOD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "r#final", 15usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).r#final)(this, r#final), |result| Some(*result))
+        }
+        unsafe extern "system" fn variety(this: *mut core::ffi::c_void, variety: *mut SCHEMATYPEVARIETY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "variety", 16usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).variety)(this, variety), |result| Some(*result))
+        }
+        unsafe extern "system" fn derivedBy(this: *mut core::ffi::c_void, derivedby: *mut SCHEMADERIVATIONMETHOD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "derivedBy", 17usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).derivedBy)(this, derivedby), |result| Some(*result))
+        }
+        unsafe extern "system" fn isValid(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::BSTR>, valid: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "isValid", 18usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).isValid)(this, data, valid), |result| Some(*result))
+        }
+        unsafe extern "system" fn minExclusive(this: *mut core::ffi::c_void, minexclusive: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "minExclusive", 19usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).minExclusive)(this, minexclusive), |result| Some(*result))
+        }
+        unsafe extern "system" fn minInclusive(this: *mut core::ffi::c_void, mininclusive: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "minInclusive", 20usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).minInclusive)(this, mininclusive), |result| Some(*result))
+        }
+        unsafe extern "system" fn maxExclusive(this: *mut core::ffi::c_void, maxexclusive: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "maxExclusive", 21usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).maxExclusive)(this, maxexclusive), |result| Some(*result))
+        }
+        unsafe extern "system" fn maxInclusive(this: *mut core::ffi::c_void, maxinclusive: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "maxInclusive", 22usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).maxInclusive)(this, maxinclusive), |result| Some(*result))
+        }
+        unsafe extern "system" fn totalDigits(this: *mut core::ffi::c_void, totaldigits: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "totalDigits", 23usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).totalDigits)(this, totaldigits), |result| Some(*result))
+        }
+        unsafe extern "system" fn fractionDigits(this: *mut core::ffi::c_void, fractiondigits: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "fractionDigits", 24usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).fractionDigits)(this, fractiondigits), |result| Some(*result))
+        }
+        unsafe extern "system" fn length(this: *mut core::ffi::c_void, length: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "length", 25usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).length)(this, length), |result| Some(*result))
+        }
+        unsafe extern "system" fn minLength(this: *mut core::ffi::c_void, minlength: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "minLength", 26usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).minLength)(this, minlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn maxLength(this: *mut core::ffi::c_void, maxlength: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "maxLength", 27usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).maxLength)(this, maxlength), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn enumeration(this: *mut core::ffi::c_void, enumeration: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "enumeration", 28usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).enumeration)(this, enumeration), |result| Some(*result))
+        }
+        unsafe extern "system" fn whitespace(this: *mut core::ffi::c_void, whitespace: *mut SCHEMAWHITESPACE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "whitespace", 29usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).whitespace)(this, whitespace), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn patterns(this: *mut core::ffi::c_void, patterns: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaType", "patterns", 30usize, |this| ((**(this as *const *const ISchemaType_Vtbl)).patterns)(this, patterns), |result| Some(*result))
+        }
+        Self {
+            base__: <ISchemaItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            baseTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            baseTypes: 0,
+            r#final,
+            variety,
+            derivedBy,
+            isValid,
+            minExclusive,
+            minInclusive,
+            maxExclusive,
+            maxInclusive,
+            totalDigits,
+            fractionDigits,
+            length,
+            minLength,
+            maxLength,
+            #[cfg(feature = "Win32_System_Com")]
+            enumeration,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            enumeration: 0,
+            whitespace,
+            #[cfg(feature = "Win32_System_Com")]
+            patterns,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            patterns: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaType as windows_core::Interface>::IID || <ISchemaItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IServerXMLHTTPRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn setTimeouts(this: *mut core::ffi::c_void, resolvetimeout: i32, connecttimeout: i32, sendtimeout: i32, receivetimeout: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerXMLHTTPRequest", "setTimeouts", 21usize, |this| ((**(this as *const *const IServerXMLHTTPRequest_Vtbl)).setTimeouts)(this, resolvetimeout, connecttimeout, sendtimeout, receivetimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn waitForResponse(this: *mut core::ffi::c_void, timeoutinseconds: core::mem::MaybeUninit<windows_core::VARIANT>, issuccessful: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerXMLHTTPRequest", "waitForResponse", 22usize, |this| ((**(this as *const *const IServerXMLHTTPRequest_Vtbl)).waitForResponse)(this, timeoutinseconds, issuccessful), |result| Some(*result))
+        }
+        unsafe extern "system" fn getOption(this: *mut core::ffi::c_void, option: SERVERXMLHTTP_OPTION, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerXMLHTTPRequest", "getOption", 23usize, |this| ((**(this as *const *const IServerXMLHTTPRequest_Vtbl)).getOption)(this, option, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn setOption(this: *mut core::ffi::c_void, option: SERVERXMLHTTP_OPTION, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerXMLHTTPRequest", "setOption", 24usize, |this| ((**(this as *const *const IServerXMLHTTPRequest_Vtbl)).setOption)(this, option, value), |result| Some(*result))
+        }
+        Self { base__: <IXMLHTTPRequest_Vtbl as windows_core::imp::ProxyVtable>::PROXY, setTimeouts, waitForResponse, getOption, setOption }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServerXMLHTTPRequest as windows_core::Interface>::IID || <IXMLHTTPRequest_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IServerXMLHTTPRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn setProxy(this: *mut core::ffi::c_void, proxysetting: SXH_PROXY_SETTING, varproxyserver: core::mem::MaybeUninit<windows_core::VARIANT>, varbypasslist: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerXMLHTTPRequest2", "setProxy", 25usize, |this| ((**(this as *const *const IServerXMLHTTPRequest2_Vtbl)).setProxy)(this, proxysetting, varproxyserver, varbypasslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn setProxyCredentials(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerXMLHTTPRequest2", "setProxyCredentials", 26usize, |this| ((**(this as *const *const IServerXMLHTTPRequest2_Vtbl)).setProxyCredentials)(this, bstrusername, bstrpassword), |result| Some(*result))
+        }
+        Self { base__: <IServerXMLHTTPRequest_Vtbl as windows_core::imp::ProxyVtable>::PROXY, setProxy, setProxyCredentials }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServerXMLHTTPRequest2 as windows_core::Interface>::IID || <IServerXMLHTTPRequest_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IVBMXNamespaceManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetallowOverride(this: *mut core::ffi::c_void, foverride: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBMXNamespaceManager", "SetallowOverride", 7usize, |this| ((**(this as *const *const IVBMXNamespaceManager_Vtbl)).SetallowOverride)(this, foverride), |result| Some(*result))
+        }
+        unsafe extern "system" fn allowOverride(this: *mut core::ffi::c_void, foverride: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBMXNamespaceManager", "allowOverride", 8usize, |this| ((**(this as *const *const IVBMXNamespaceManager_Vtbl)).allowOverride)(this, foverride), |result| Some(*result))
+        }
+        unsafe extern "system" fn reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBMXNamespaceManager", "reset", 9usize, |this| ((**(this as *const *const IVBMXNamespaceManager_Vtbl)).reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn pushContext(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBMXNamespaceManager", "pushContext", 10usize, |this| ((**(this as *const *const IVBMXNamespaceManager_Vtbl)).pushContext)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn pushNodeContext(this: *mut core::ffi::c_void, contextnode: *mut core::ffi::c_void, fdeep: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBMXNamespaceManager", "pushNodeContext", 11usize, |this| ((**(this as *const *const IVBMXNamespaceManager_Vtbl)).pushNodeContext)(this, contextnode, fdeep), |result| Some(*result))
+        }
+        unsafe extern "system" fn popContext(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBMXNamespaceManager", "popContext", 12usize, |this| ((**(this as *const *const IVBMXNamespaceManager_Vtbl)).popContext)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn declarePrefix(this: *mut core::ffi::c_void, prefix: core::mem::MaybeUninit<windows_core::BSTR>, namespaceuri: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBMXNamespaceManager", "declarePrefix", 13usize, |this| ((**(this as *const *const IVBMXNamespaceManager_Vtbl)).declarePrefix)(this, prefix, namespaceuri), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getDeclaredPrefixes(this: *mut core::ffi::c_void, prefixes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBMXNamespaceManager", "getDeclaredPrefixes", 14usize, |this| ((**(this as *const *const IVBMXNamespaceManager_Vtbl)).getDeclaredPrefixes)(this, prefixes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getPrefixes(this: *mut core::ffi::c_void, namespaceuri: core::mem::MaybeUninit<windows_core::BSTR>, prefixes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBMXNamespaceManager", "getPrefixes", 15usize, |this| ((**(this as *const *const IVBMXNamespaceManager_Vtbl)).getPrefixes)(this, namespaceuri, prefixes), |result| Some(*result))
+        }
+        unsafe extern "system" fn getURI(this: *mut core::ffi::c_void, prefix: core::mem::MaybeUninit<windows_core::BSTR>, uri: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBMXNamespaceManager", "getURI", 16usize, |this| ((**(this as *const *const IVBMXNamespaceManager_Vtbl)).getURI)(this, prefix, uri), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getURIFromNode(this: *mut core::ffi::c_void, strprefix: core::mem::MaybeUninit<windows_core::BSTR>, contextnode: *mut core::ffi::c_void, uri: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBMXNamespaceManager", "getURIFromNode", 17usize, |this| ((**(this as *const *const IVBMXNamespaceManager_Vtbl)).getURIFromNode)(this, strprefix, contextnode, uri), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetallowOverride,
+            allowOverride,
+            reset,
+            pushContext,
+            #[cfg(feature = "Win32_System_Com")]
+            pushNodeContext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            pushNodeContext: 0,
+            popContext,
+            declarePrefix,
+            #[cfg(feature = "Win32_System_Com")]
+            getDeclaredPrefixes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getDeclaredPrefixes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getPrefixes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getPrefixes: 0,
+            getURI,
+            #[cfg(feature = "Win32_System_Com")]
+            getURIFromNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getURIFromNode: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVBMXNamespaceManager as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IVBSAXAttributes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn length(this: *mut core::ffi::c_void, nlength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXAttributes", "length", 7usize, |this| ((**(this as *const *const IVBSAXAttributes_Vtbl)).length)(this, nlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn getURI(this: *mut core::ffi::c_void, nindex: i32, struri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXAttributes", "getURI", 8usize, |this| ((**(this as *const *const IVBSAXAttributes_Vtbl)).getURI)(this, nindex, struri), |result| Some(*result))
+        }
+        unsafe extern "system" fn getLocalName(this: *mut core::ffi::c_void, nindex: i32, strlocalname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXAttributes", "getLocalName", 9usize, |this| ((**(this as *const *const IVBSAXAttributes_Vtbl)).getLocalName)(this, nindex, strlocalname), |result| Some(*result))
+        }
+        unsafe extern "system" fn getQName(this: *mut core::ffi::c_void, nindex: i32, strqname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXAttributes", "getQName", 10usize, |this| ((**(this as *const *const IVBSAXAttributes_Vtbl)).getQName)(this, nindex, strqname), |result| Some(*result))
+        }
+        unsafe extern "system" fn getIndexFromName(this: *mut core::ffi::c_void, struri: core::mem::MaybeUninit<windows_core::BSTR>, strlocalname: core::mem::MaybeUninit<windows_core::BSTR>, nindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXAttributes", "getIndexFromName", 11usize, |this| ((**(this as *const *const IVBSAXAttributes_Vtbl)).getIndexFromName)(this, struri, strlocalname, nindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn getIndexFromQName(this: *mut core::ffi::c_void, strqname: core::mem::MaybeUninit<windows_core::BSTR>, nindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXAttributes", "getIndexFromQName", 12usize, |this| ((**(this as *const *const IVBSAXAttributes_Vtbl)).getIndexFromQName)(this, strqname, nindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn getType(this: *mut core::ffi::c_void, nindex: i32, strtype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXAttributes", "getType", 13usize, |this| ((**(this as *const *const IVBSAXAttributes_Vtbl)).getType)(this, nindex, strtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn getTypeFromName(this: *mut core::ffi::c_void, struri: core::mem::MaybeUninit<windows_core::BSTR>, strlocalname: core::mem::MaybeUninit<windows_core::BSTR>, strtype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXAttributes", "getTypeFromName", 14usize, |this| ((**(this as *const *const IVBSAXAttributes_Vtbl)).getTypeFromName)(this, struri, strlocalname, strtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn getTypeFromQName(this: *mut core::ffi::c_void, strqname: core::mem::MaybeUninit<windows_core::BSTR>, strtype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXAttributes", "getTypeFromQName", 15usize, |this| ((**(this as *const *const IVBSAXAttributes_Vtbl)).getTypeFromQName)(this, strqname, strtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn getValue(this: *mut core::ffi::c_void, nindex: i32, strvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXAttributes", "getValue", 16usize, |this| ((**(this as *const *const IVBSAXAttributes_Vtbl)).getValue)(this, nindex, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn getValueFromName(this: *mut core::ffi::c_void, struri: core::mem::MaybeUninit<windows_core::BSTR>, strlocalname: core::mem::MaybeUninit<windows_core::BSTR>, strvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXAttributes", "getValueFromName", 17usize, |this| ((**(this as *const *const IVBSAXAttributes_Vtbl)).getValueFromName)(this, struri, strlocalname, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn getValueFromQName(this: *mut core::ffi::c_void, strqname: core::mem::MaybeUninit<windows_core::BSTR>, strvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXAttributes", "getValueFromQName", 18usize, |this| ((**(this as *const *const IVBSAXAttributes_Vtbl)).getValueFromQName)(this, strqname, strvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            length,
+            getURI,
+            getLocalName,
+            getQName,
+            getIndexFromName,
+            getIndexFromQName,
+            getType,
+            getTypeFromName,
+            getTypeFromQName,
+            getValue,
+            getValueFromName,
+            getValueFromQName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVBSAXAttributes as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IVBSAXContentHandler_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_documentLocator(this: *mut core::ffi::c_void, olocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXContentHandler", "putref_documentLocator", 7usize, |this| ((**(this as *const *const IVBSAXContentHandler_Vtbl)).putref_documentLocator)(this, olocator), |result| Some(*result))
+        }
+        unsafe extern "system" fn startDocument(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXContentHandler", "startDocument", 8usize, |this| ((**(this as *const *const IVBSAXContentHandler_Vtbl)).startDocument)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn endDocument(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXContentHandler", "endDocument", 9usize, |this| ((**(this as *const *const IVBSAXContentHandler_Vtbl)).endDocument)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn startPrefixMapping(this: *mut core::ffi::c_void, strprefix: *mut core::mem::MaybeUninit<windows_core::BSTR>, struri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXContentHandler", "startPrefixMapping", 10usize, |this| ((**(this as *const *const IVBSAXContentHandler_Vtbl)).startPrefixMapping)(this, strprefix, struri), |result| Some(*result))
+        }
+        unsafe extern "system" fn endPrefixMapping(this: *mut core::ffi::c_void, strprefix: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXContentHandler", "endPrefixMapping", 11usize, |this| ((**(this as *const *const IVBSAXContentHandler_Vtbl)).endPrefixMapping)(this, strprefix), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn startElement(this: *mut core::ffi::c_void, strnamespaceuri: *mut core::mem::MaybeUninit<windows_core::BSTR>, strlocalname: *mut core::mem::MaybeUninit<windows_core::BSTR>, strqname: *mut core::mem::MaybeUninit<windows_core::BSTR>, oattributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXContentHandler", "startElement", 12usize, |this| ((**(this as *const *const IVBSAXContentHandler_Vtbl)).startElement)(this, strnamespaceuri, strlocalname, strqname, oattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn endElement(this: *mut core::ffi::c_void, strnamespaceuri: *mut core::mem::MaybeUninit<windows_core::BSTR>, strlocalname: *mut core::mem::MaybeUninit<windows_core::BSTR>, strqname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXContentHandler", "endElement", 13usize, |this| ((**(this as *const *const IVBSAXContentHandler_Vtbl)).endElement)(this, strnamespaceuri, strlocalname, strqname), |result| Some(*result))
+        }
+        unsafe extern "system" fn characters(this: *mut core::ffi::c_void, strchars: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXContentHandler", "characters", 14usize, |this| ((**(this as *const *const IVBSAXContentHandler_Vtbl)).characters)(this, strchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn ignorableWhitespace(this: *mut core::ffi::c_void, strchars: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXContentHandler", "ignorableWhitespace", 15usize, |this| ((**(this as *const *const IVBSAXContentHandler_Vtbl)).ignorableWhitespace)(this, strchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn processingInstruction(this: *mut core::ffi::c_void, strtarget: *mut core::mem::MaybeUninit<windows_core::BSTR>, strdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXContentHandler", "processingInstruction", 16usize, |this| ((**(this as *const *const IVBSAXContentHandler_Vtbl)).processingInstruction)(this, strtarget, strdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn skippedEntity(this: *mut core::ffi::c_void, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXContentHandler", "skippedEntity", 17usize, |this| ((**(this as *const *const IVBSAXContentHandler_Vtbl)).skippedEntity)(this, strname), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_documentLocator,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_documentLocator: 0,
+            startDocument,
+            endDocument,
+            startPrefixMapping,
+            endPrefixMapping,
+            #[cfg(feature = "Win32_System_Com")]
+            startElement,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            startElement: 0,
+            endElement,
+            characters,
+            ignorableWhitespace,
+            processingInstruction,
+            skippedEntity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVBSAXContentHandler as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IVBSAXDTDHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn notationDecl(this: *mut core::ffi::c_void, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>, strpublicid: *mut core::mem::MaybeUninit<windows_core::BSTR>, strsystemid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXDTDHandler", "notationDecl", 7usize, |this| ((**(this as *const *const IVBSAXDTDHandler_Vtbl)).notationDecl)(this, strname, strpublicid, strsystemid), |result| Some(*result))
+        }
+        unsafe extern "system" fn unparsedEntityDecl(this: *mut core::ffi::c_void, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>, strpublicid: *mut core::mem::MaybeUninit<windows_core::BSTR>, strsystemid: *mut core::mem::MaybeUninit<windows_core::BSTR>, strnotationname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXDTDHandler", "unparsedEntityDecl", 8usize, |this| ((**(this as *const *const IVBSAXDTDHandler_Vtbl)).unparsedEntityDecl)(this, strname, strpublicid, strsystemid, strnotationname), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, notationDecl, unparsedEntityDecl }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVBSAXDTDHandler as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IVBSAXDeclHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn elementDecl(this: *mut core::ffi::c_void, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>, strmodel: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXDeclHandler", "elementDecl", 7usize, |this| ((**(this as *const *const IVBSAXDeclHandler_Vtbl)).elementDecl)(this, strname, strmodel), |result| Some(*result))
+        }
+        unsafe extern "system" fn attributeDecl(this: *mut core::ffi::c_void, strelementname: *mut core::mem::MaybeUninit<windows_core::BSTR>, strattributename: *mut core::mem::MaybeUninit<windows_core::BSTR>, strtype: *mut core::mem::MaybeUninit<windows_core::BSTR>, strvaluedefault: *mut core::mem::MaybeUninit<windows_core::BSTR>, strvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXDeclHandler", "attributeDecl", 8usize, |this| ((**(this as *const *const IVBSAXDeclHandler_Vtbl)).attributeDecl)(this, strelementname, strattributename, strtype, strvaluedefault, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn internalEntityDecl(this: *mut core::ffi::c_void, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>, strvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXDeclHandler", "internalEntityDecl", 9usize, |this| ((**(this as *const *const IVBSAXDeclHandler_Vtbl)).internalEntityDecl)(this, strname, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn externalEntityDecl(this: *mut core::ffi::c_void, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>, strpublicid: *mut core::mem::MaybeUninit<windows_core::BSTR>, strsystemid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXDeclHandler", "externalEntityDecl", 10usize, |this| ((**(this as *const *const IVBSAXDeclHandler_Vtbl)).externalEntityDecl)(this, strname, strpublicid, strsystemid), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            elementDecl,
+            attributeDecl,
+            internalEntityDecl,
+            externalEntityDecl,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVBSAXDeclHandler as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IVBSAXEntityResolver_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn resolveEntity(this: *mut core::ffi::c_void, strpublicid: *mut core::mem::MaybeUninit<windows_core::BSTR>, strsystemid: *mut core::mem::MaybeUninit<windows_core::BSTR>, varinput: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXEntityResolver", "resolveEntity", 7usize, |this| ((**(this as *const *const IVBSAXEntityResolver_Vtbl)).resolveEntity)(this, strpublicid, strsystemid, varinput), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, resolveEntity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVBSAXEntityResolver as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IVBSAXErrorHandler_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn error(this: *mut core::ffi::c_void, olocator: *mut core::ffi::c_void, strerrormessage: *mut core::mem::MaybeUninit<windows_core::BSTR>, nerrorcode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXErrorHandler", "error", 7usize, |this| ((**(this as *const *const IVBSAXErrorHandler_Vtbl)).error)(this, olocator, strerrormessage, nerrorcode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn fatalError(this: *mut core::ffi::c_void, olocator: *mut core::ffi::c_void, strerrormessage: *mut core::mem::MaybeUninit<windows_core::BSTR>, nerrorcode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXErrorHandler", "fatalError", 8usize, |this| ((**(this as *const *const IVBSAXErrorHandler_Vtbl)).fatalError)(this, olocator, strerrormessage, nerrorcode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ignorableWarning(this: *mut core::ffi::c_void, olocator: *mut core::ffi::c_void, strerrormessage: *mut core::mem::MaybeUninit<windows_core::BSTR>, nerrorcode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXErrorHandler", "ignorableWarning", 9usize, |this| ((**(this as *const *const IVBSAXErrorHandler_Vtbl)).ignorableWarning)(this, olocator, strerrormessage, nerrorcode), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            error,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            error: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            fatalError,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            fatalError: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ignorableWarning,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ignorableWarning: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVBSAXErrorHandler as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IVBSAXLexicalHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn startDTD(this: *mut core::ffi::c_void, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>, strpublicid: *mut core::mem::MaybeUninit<windows_core::BSTR>, strsystemid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXLexicalHandler", "startDTD", 7usize, |this| ((**(this as *const *const IVBSAXLexicalHandler_Vtbl)).startDTD)(this, strname, strpublicid, strsystemid), |result| Some(*result))
+        }
+        unsafe extern "system" fn endDTD(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXLexicalHandler", "endDTD", 8usize, |this| ((**(this as *const *const IVBSAXLexicalHandler_Vtbl)).endDTD)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn startEntity(this: *mut core::ffi::c_void, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXLexicalHandler", "startEntity", 9usize, |this| ((**(this as *const *const IVBSAXLexicalHandler_Vtbl)).startEntity)(this, strname), |result| Some(*result))
+        }
+        unsafe extern "system" fn endEntity(this: *mut core::ffi::c_void, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXLexicalHandler", "endEntity", 10usize, |this| ((**(this as *const *const IVBSAXLexicalHandler_Vtbl)).endEntity)(this, strname), |result| Some(*result))
+        }
+        unsafe extern "system" fn startCDATA(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXLexicalHandler", "startCDATA", 11usize, |this| ((**(this as *const *const IVBSAXLexicalHandler_Vtbl)).startCDATA)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn endCDATA(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXLexicalHandler", "endCDATA", 12usize, |this| ((**(this as *const *const IVBSAXLexicalHandler_Vtbl)).endCDATA)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn comment(this: *mut core::ffi::c_void, strchars: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXLexicalHandler", "comment", 13usize, |this| ((**(this as *const *const IVBSAXLexicalHandler_Vtbl)).comment)(this, strchars), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            startDTD,
+            endDTD,
+            startEntity,
+            endEntity,
+            startCDATA,
+            endCDATA,
+            comment,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVBSAXLexicalHandler as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IVBSAXLocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn columnNumber(this: *mut core::ffi::c_void, ncolumn: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXLocator", "columnNumber", 7usize, |this| ((**(this as *const *const IVBSAXLocator_Vtbl)).columnNumber)(this, ncolumn), |result| Some(*result))
+        }
+        unsafe extern "system" fn lineNumber(this: *mut core::ffi::c_void, nline: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXLocator", "lineNumber", 8usize, |this| ((**(this as *const *const IVBSAXLocator_Vtbl)).lineNumber)(this, nline), |result| Some(*result))
+        }
+        unsafe extern "system" fn publicId(this: *mut core::ffi::c_void, strpublicid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXLocator", "publicId", 9usize, |this| ((**(this as *const *const IVBSAXLocator_Vtbl)).publicId)(this, strpublicid), |result| Some(*result))
+        }
+        unsafe extern "system" fn systemId(this: *mut core::ffi::c_void, strsystemid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXLocator", "systemId", 10usize, |this| ((**(this as *const *const IVBSAXLocator_Vtbl)).systemId)(this, strsystemid), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            columnNumber,
+            lineNumber,
+            publicId,
+            systemId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVBSAXLocator as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IVBSAXXMLFilter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn parent(this: *mut core::ffi::c_void, oreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLFilter", "parent", 7usize, |this| ((**(this as *const *const IVBSAXXMLFilter_Vtbl)).parent)(this, oreader), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_parent(this: *mut core::ffi::c_void, oreader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLFilter", "putref_parent", 8usize, |this| ((**(this as *const *const IVBSAXXMLFilter_Vtbl)).putref_parent)(this, oreader), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            parent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            parent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_parent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_parent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVBSAXXMLFilter as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IVBSAXXMLReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn getFeature(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, fvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "getFeature", 7usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).getFeature)(this, strname, fvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn putFeature(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, fvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "putFeature", 8usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).putFeature)(this, strname, fvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn getProperty(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, varvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "getProperty", 9usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).getProperty)(this, strname, varvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn putProperty(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, varvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "putProperty", 10usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).putProperty)(this, strname, varvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn entityResolver(this: *mut core::ffi::c_void, oresolver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "entityResolver", 11usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).entityResolver)(this, oresolver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_entityResolver(this: *mut core::ffi::c_void, oresolver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "putref_entityResolver", 12usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).putref_entityResolver)(this, oresolver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn contentHandler(this: *mut core::ffi::c_void, ohandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "contentHandler", 13usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).contentHandler)(this, ohandler), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_contentHandler(this: *mut core::ffi::c_void, ohandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "putref_contentHandler", 14usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).putref_contentHandler)(this, ohandler), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn dtdHandler(this: *mut core::ffi::c_void, ohandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "dtdHandler", 15usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).dtdHandler)(this, ohandler), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_dtdHandler(this: *mut core::ffi::c_void, ohandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "putref_dtdHandler", 16usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).putref_dtdHandler)(this, ohandler), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn errorHandler(this: *mut core::ffi::c_void, ohandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "errorHandler", 17usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).errorHandler)(this, ohandler), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_errorHandler(this: *mut core::ffi::c_void, ohandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "putref_errorHandler", 18usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).putref_errorHandler)(this, ohandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn baseURL(this: *mut core::ffi::c_void, strbaseurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "baseURL", 19usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).baseURL)(this, strbaseurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetbaseURL(this: *mut core::ffi::c_void, strbaseurl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "SetbaseURL", 20usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).SetbaseURL)(this, strbaseurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn secureBaseURL(this: *mut core::ffi::c_void, strsecurebaseurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "secureBaseURL", 21usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).secureBaseURL)(this, strsecurebaseurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetsecureBaseURL(this: *mut core::ffi::c_void, strsecurebaseurl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "SetsecureBaseURL", 22usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).SetsecureBaseURL)(this, strsecurebaseurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn parse(this: *mut core::ffi::c_void, varinput: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "parse", 23usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).parse)(this, varinput), |result| Some(*result))
+        }
+        unsafe extern "system" fn parseURL(this: *mut core::ffi::c_void, strurl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBSAXXMLReader", "parseURL", 24usize, |this| ((**(this as *const *const IVBSAXXMLReader_Vtbl)).parseURL)(this, strurl), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            getFeature,
+            putFeature,
+            getProperty,
+            putProperty,
+            #[cfg(feature = "Win32_System_Com")]
+            entityResolver,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            entityResolver: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_entityResolver,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_entityResolver: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            contentHandler,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            contentHandler: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_contentHandler,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_contentHandler: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            dtdHandler,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            dtdHandler: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_dtdHandler,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_dtdHandler: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            errorHandler,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            errorHandler: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_errorHandler,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_errorHandler: 0,
+            baseURL,
+            SetbaseURL,
+            secureBaseURL,
+            SetsecureBaseURL,
+            parse,
+            parseURL,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVBSAXXMLReader as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLAttribute_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn name(this: *mut core::ffi::c_void, n: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLAttribute", "name", 7usize, |this| ((**(this as *const *const IXMLAttribute_Vtbl)).name)(this, n), |result| Some(*result))
+        }
+        unsafe extern "system" fn value(this: *mut core::ffi::c_void, v: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLAttribute", "value", 8usize, |this| ((**(this as *const *const IXMLAttribute_Vtbl)).value)(this, v), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, name, value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLAttribute as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMAttribute_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn name(this: *mut core::ffi::c_void, attributename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMAttribute", "name", 43usize, |this| ((**(this as *const *const IXMLDOMAttribute_Vtbl)).name)(this, attributename), |result| Some(*result))
+        }
+        unsafe extern "system" fn value(this: *mut core::ffi::c_void, attributevalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMAttribute", "value", 44usize, |this| ((**(this as *const *const IXMLDOMAttribute_Vtbl)).value)(this, attributevalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setvalue(this: *mut core::ffi::c_void, attributevalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMAttribute", "Setvalue", 45usize, |this| ((**(this as *const *const IXMLDOMAttribute_Vtbl)).Setvalue)(this, attributevalue), |result| Some(*result))
+        }
+        Self { base__: <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY, name, value, Setvalue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMAttribute as windows_core::Interface>::IID || <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMCDATASection_Vtbl {
+    const PROXY: Self = { Self { base__: <IXMLDOMText_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMCDATASection as windows_core::Interface>::IID || <IXMLDOMText_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMCharacterData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn data(this: *mut core::ffi::c_void, data: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMCharacterData", "data", 43usize, |this| ((**(this as *const *const IXMLDOMCharacterData_Vtbl)).data)(this, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setdata(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMCharacterData", "Setdata", 44usize, |this| ((**(this as *const *const IXMLDOMCharacterData_Vtbl)).Setdata)(this, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn length(this: *mut core::ffi::c_void, datalength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMCharacterData", "length", 45usize, |this| ((**(this as *const *const IXMLDOMCharacterData_Vtbl)).length)(this, datalength), |result| Some(*result))
+        }
+        unsafe extern "system" fn substringData(this: *mut core::ffi::c_void, offset: i32, count: i32, data: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMCharacterData", "substringData", 46usize, |this| ((**(this as *const *const IXMLDOMCharacterData_Vtbl)).substringData)(this, offset, count, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn appendData(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMCharacterData", "appendData", 47usize, |this| ((**(this as *const *const IXMLDOMCharacterData_Vtbl)).appendData)(this, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn insertData(this: *mut core::ffi::c_void, offset: i32, data: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMCharacterData", "insertData", 48usize, |this| ((**(this as *const *const IXMLDOMCharacterData_Vtbl)).insertData)(this, offset, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn deleteData(this: *mut core::ffi::c_void, offset: i32, count: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMCharacterData", "deleteData", 49usize, |this| ((**(this as *const *const IXMLDOMCharacterData_Vtbl)).deleteData)(this, offset, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn replaceData(this: *mut core::ffi::c_void, offset: i32, count: i32, data: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMCharacterData", "replaceData", 50usize, |this| ((**(this as *const *const IXMLDOMCharacterData_Vtbl)).replaceData)(this, offset, count, data), |result| Some(*result))
+        }
+        Self {
+            base__: <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            data,
+            Setdata,
+            length,
+            substringData,
+            appendData,
+            insertData,
+            deleteData,
+            replaceData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMCharacterData as windows_core::Interface>::IID || <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMComment_Vtbl {
+    const PROXY: Self = { Self { base__: <IXMLDOMCharacterData_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMComment as windows_core::Interface>::IID || <IXMLDOMCharacterData_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMDocument_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn doctype(this: *mut core::ffi::c_void, documenttype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "doctype", 43usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).doctype)(this, documenttype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn implementation(this: *mut core::ffi::c_void, r#impl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "implementation", 44usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).implementation)(this, r#impl), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn documentElement(this: *mut core::ffi::c_void, domelement: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "documentElement", 45usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).documentElement)(this, domelement), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_documentElement(this: *mut core::ffi::c_void, domelement: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "putref_documentElement", 46usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).putref_documentElement)(this, domelement), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn createElement(this: *mut core::ffi::c_void, tagname: core::mem::MaybeUninit<windows_core::BSTR>, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "createElement", 47usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).createElement)(this, tagname, element), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn createDocumentFragment(this: *mut core::ffi::c_void, docfrag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "createDocumentFragment", 48usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).createDocumentFragment)(this, docfrag), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn createTextNode(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::BSTR>, text: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "createTextNode", 49usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).createTextNode)(this, data, text), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn createComment(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::BSTR>, comment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "createComment", 50usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).createComment)(this, data, comment), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn createCDATASection(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::BSTR>, cdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "createCDATASection", 51usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).createCDATASection)(this, data, cdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn createProcessingInstruction(this: *mut core::ffi::c_void, target: core::mem::MaybeUninit<windows_core::BSTR>, data: core::mem::MaybeUninit<windows_core::BSTR>, pi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "createProcessingInstruction", 52usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).createProcessingInstruction)(this, target, data, pi), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn createAttribute(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, attribute: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "createAttribute", 53usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).createAttribute)(this, name, attribute), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn createEntityReference(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, entityref: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "createEntityReference", 54usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).createEntityReference)(this, name, entityref), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getElementsByTagName(this: *mut core::ffi::c_void, tagname: core::mem::MaybeUninit<windows_core::BSTR>, resultlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "getElementsByTagName", 55usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).getElementsByTagName)(this, tagname, resultlist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn createNode(this: *mut core::ffi::c_void, r#type: core::mem::MaybeUninit<windows_core::VARIANT>, name: core::mem::MaybeUninit<windows_core::BSTR>, namespaceuri: core::mem::MaybeUninit<windows_core::BSTR>, node: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "createNode", 56usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).createNode)(this, r#type, name, namespaceuri, node), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn nodeFromID(this: *mut core::ffi::c_void, idstring: core::mem::MaybeUninit<windows_core::BSTR>, node: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "nodeFromID", 57usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).nodeFromID)(this, idstring, node), |result| Some(*result))
+        }
+        unsafe extern "system" fn load(this: *mut core::ffi::c_void, xmlsource: core::mem::MaybeUninit<windows_core::VARIANT>, issuccessful: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "load", 58usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).load)(this, xmlsource, issuccessful), |result| Some(*result))
+        }
+        unsafe extern "system" fn readyState(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "readyState", 59usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).readyState)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn parseError(this: *mut core::ffi::c_void, errorobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "parseError", 60usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).parseError)(this, errorobj), |result| Some(*result))
+        }
+        unsafe extern "system" fn url(this: *mut core::ffi::c_void, urlstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "url", 61usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).url)(this, urlstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn r#async(this: *mut core::ffi::c_void, isasync: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "r#async", 62usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).r#async)(this, isasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setasync(this: *mut core::ffi::c_void, isasync: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "Setasync", 63usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).Setasync)(this, isasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "abort", 64usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).abort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn loadXML(this: *mut core::ffi::c_void, bstrxml: core::mem::MaybeUninit<windows_core::BSTR>, issuccessful: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "loadXML", 65usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).loadXML)(this, bstrxml, issuccessful), |result| Some(*result))
+        }
+        unsafe extern "system" fn save(this: *mut core::ffi::c_void, destination: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "save", 66usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).save)(this, destination), |result| Some(*result))
+        }
+        unsafe extern "system" fn validateOnParse(this: *mut core::ffi::c_void, isvalidating: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "validateOnParse", 67usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).validateOnParse)(this, isvalidating), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetvalidateOnParse(this: *mut core::ffi::c_void, isvalidating: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "SetvalidateOnParse", 68usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).SetvalidateOnParse)(this, isvalidating), |result| Some(*result))
+        }
+        unsafe extern "system" fn resolveExternals(this: *mut core::ffi::c_void, isresolving: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "resolveExternals", 69usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).resolveExternals)(this, isresolving), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetresolveExternals(this: *mut core::ffi::c_void, isresolving: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "SetresolveExternals", 70usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).SetresolveExternals)(this, isresolving), |result| Some(*result))
+        }
+        unsafe extern "system" fn preserveWhiteSpace(this: *mut core::ffi::c_void, ispreserving: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "preserveWhiteSpace", 71usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).preserveWhiteSpace)(this, ispreserving), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetpreserveWhiteSpace(this: *mut core::ffi::c_void, ispreserving: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "SetpreserveWhiteSpace", 72usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).SetpreserveWhiteSpace)(this, ispreserving), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setonreadystatechange(this: *mut core::ffi::c_void, readystatechangesink: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "Setonreadystatechange", 73usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).Setonreadystatechange)(this, readystatechangesink), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setondataavailable(this: *mut core::ffi::c_void, ondataavailablesink: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "Setondataavailable", 74usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).Setondataavailable)(this, ondataavailablesink), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setontransformnode(this: *mut core::ffi::c_void, ontransformnodesink: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument", "Setontransformnode", 75usize, |this| ((**(this as *const *const IXMLDOMDocument_Vtbl)).Setontransformnode)(this, ontransformnodesink), |result| Some(*result))
+        }
+        Self {
+            base__: <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            doctype,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            doctype: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            implementation,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            implementation: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            documentElement,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            documentElement: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_documentElement,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_documentElement: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            createElement,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            createElement: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            createDocumentFragment,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            createDocumentFragment: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            createTextNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            createTextNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            createComment,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            createComment: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            createCDATASection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            createCDATASection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            createProcessingInstruction,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            createProcessingInstruction: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            createAttribute,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            createAttribute: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            createEntityReference,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            createEntityReference: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getElementsByTagName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getElementsByTagName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            createNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            createNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            nodeFromID,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            nodeFromID: 0,
+            load,
+            readyState,
+            #[cfg(feature = "Win32_System_Com")]
+            parseError,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            parseError: 0,
+            url,
+            r#async,
+            Setasync,
+            abort,
+            loadXML,
+            save,
+            validateOnParse,
+            SetvalidateOnParse,
+            resolveExternals,
+            SetresolveExternals,
+            preserveWhiteSpace,
+            SetpreserveWhiteSpace,
+            Setonreadystatechange,
+            Setondataavailable,
+            Setontransformnode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMDocument as windows_core::Interface>::IID || <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMDocument2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn namespaces(this: *mut core::ffi::c_void, namespacecollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument2", "namespaces", 76usize, |this| ((**(this as *const *const IXMLDOMDocument2_Vtbl)).namespaces)(this, namespacecollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn schemas(this: *mut core::ffi::c_void, othercollection: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument2", "schemas", 77usize, |this| ((**(this as *const *const IXMLDOMDocument2_Vtbl)).schemas)(this, othercollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn putref_schemas(this: *mut core::ffi::c_void, othercollection: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument2", "putref_schemas", 78usize, |this| ((**(this as *const *const IXMLDOMDocument2_Vtbl)).putref_schemas)(this, othercollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn validate(this: *mut core::ffi::c_void, errorobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument2", "validate", 79usize, |this| ((**(this as *const *const IXMLDOMDocument2_Vtbl)).validate)(this, errorobj), |result| Some(*result))
+        }
+        unsafe extern "system" fn setProperty(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument2", "setProperty", 80usize, |this| ((**(this as *const *const IXMLDOMDocument2_Vtbl)).setProperty)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn getProperty(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument2", "getProperty", 81usize, |this| ((**(this as *const *const IXMLDOMDocument2_Vtbl)).getProperty)(this, name, value), |result| Some(*result))
+        }
+        Self {
+            base__: <IXMLDOMDocument_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            namespaces,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            namespaces: 0,
+            schemas,
+            putref_schemas,
+            #[cfg(feature = "Win32_System_Com")]
+            validate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            validate: 0,
+            setProperty,
+            getProperty,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMDocument2 as windows_core::Interface>::IID || <IXMLDOMDocument_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMDocument3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn validateNode(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, errorobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument3", "validateNode", 82usize, |this| ((**(this as *const *const IXMLDOMDocument3_Vtbl)).validateNode)(this, node, errorobj), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn importNode(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, deep: super::super::super::Foundation::VARIANT_BOOL, clone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocument3", "importNode", 83usize, |this| ((**(this as *const *const IXMLDOMDocument3_Vtbl)).importNode)(this, node, deep, clone), |result| Some(*result))
+        }
+        Self {
+            base__: <IXMLDOMDocument2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            validateNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            validateNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            importNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            importNode: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMDocument3 as windows_core::Interface>::IID || <IXMLDOMDocument2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMDocumentFragment_Vtbl {
+    const PROXY: Self = { Self { base__: <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMDocumentFragment as windows_core::Interface>::IID || <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMDocumentType_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn name(this: *mut core::ffi::c_void, rootname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocumentType", "name", 43usize, |this| ((**(this as *const *const IXMLDOMDocumentType_Vtbl)).name)(this, rootname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn entities(this: *mut core::ffi::c_void, entitymap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocumentType", "entities", 44usize, |this| ((**(this as *const *const IXMLDOMDocumentType_Vtbl)).entities)(this, entitymap), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn notations(this: *mut core::ffi::c_void, notationmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMDocumentType", "notations", 45usize, |this| ((**(this as *const *const IXMLDOMDocumentType_Vtbl)).notations)(this, notationmap), |result| Some(*result))
+        }
+        Self {
+            base__: <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            name,
+            #[cfg(feature = "Win32_System_Com")]
+            entities,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            entities: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            notations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            notations: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMDocumentType as windows_core::Interface>::IID || <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn tagName(this: *mut core::ffi::c_void, tagname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMElement", "tagName", 43usize, |this| ((**(this as *const *const IXMLDOMElement_Vtbl)).tagName)(this, tagname), |result| Some(*result))
+        }
+        unsafe extern "system" fn getAttribute(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMElement", "getAttribute", 44usize, |this| ((**(this as *const *const IXMLDOMElement_Vtbl)).getAttribute)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn setAttribute(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMElement", "setAttribute", 45usize, |this| ((**(this as *const *const IXMLDOMElement_Vtbl)).setAttribute)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn removeAttribute(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMElement", "removeAttribute", 46usize, |this| ((**(this as *const *const IXMLDOMElement_Vtbl)).removeAttribute)(this, name), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getAttributeNode(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, attributenode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMElement", "getAttributeNode", 47usize, |this| ((**(this as *const *const IXMLDOMElement_Vtbl)).getAttributeNode)(this, name, attributenode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn setAttributeNode(this: *mut core::ffi::c_void, domattribute: *mut core::ffi::c_void, attributenode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMElement", "setAttributeNode", 48usize, |this| ((**(this as *const *const IXMLDOMElement_Vtbl)).setAttributeNode)(this, domattribute, attributenode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn removeAttributeNode(this: *mut core::ffi::c_void, domattribute: *mut core::ffi::c_void, attributenode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMElement", "removeAttributeNode", 49usize, |this| ((**(this as *const *const IXMLDOMElement_Vtbl)).removeAttributeNode)(this, domattribute, attributenode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getElementsByTagName(this: *mut core::ffi::c_void, tagname: core::mem::MaybeUninit<windows_core::BSTR>, resultlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMElement", "getElementsByTagName", 50usize, |this| ((**(this as *const *const IXMLDOMElement_Vtbl)).getElementsByTagName)(this, tagname, resultlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn normalize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMElement", "normalize", 51usize, |this| ((**(this as *const *const IXMLDOMElement_Vtbl)).normalize)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            tagName,
+            getAttribute,
+            setAttribute,
+            removeAttribute,
+            #[cfg(feature = "Win32_System_Com")]
+            getAttributeNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getAttributeNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            setAttributeNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            setAttributeNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            removeAttributeNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            removeAttributeNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getElementsByTagName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getElementsByTagName: 0,
+            normalize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMElement as windows_core::Interface>::IID || <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMEntity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn publicId(this: *mut core::ffi::c_void, publicid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMEntity", "publicId", 43usize, |this| ((**(this as *const *const IXMLDOMEntity_Vtbl)).publicId)(this, publicid), |result| Some(*result))
+        }
+        unsafe extern "system" fn systemId(this: *mut core::ffi::c_void, systemid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMEntity", "systemId", 44usize, |this| ((**(this as *const *const IXMLDOMEntity_Vtbl)).systemId)(this, systemid), |result| Some(*result))
+        }
+        unsafe extern "system" fn notationName(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMEntity", "notationName", 45usize, |this| ((**(this as *const *const IXMLDOMEntity_Vtbl)).notationName)(this, name), |result| Some(*result))
+        }
+        Self { base__: <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY, publicId, systemId, notationName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMEntity as windows_core::Interface>::IID || <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMEntityReference_Vtbl {
+    const PROXY: Self = { Self { base__: <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMEntityReference as windows_core::Interface>::IID || <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMImplementation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn hasFeature(this: *mut core::ffi::c_void, feature: core::mem::MaybeUninit<windows_core::BSTR>, version: core::mem::MaybeUninit<windows_core::BSTR>, hasfeature: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMImplementation", "hasFeature", 7usize, |this| ((**(this as *const *const IXMLDOMImplementation_Vtbl)).hasFeature)(this, feature, version, hasfeature), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, hasFeature }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMImplementation as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMNamedNodeMap_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getNamedItem(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, nameditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNamedNodeMap", "getNamedItem", 7usize, |this| ((**(this as *const *const IXMLDOMNamedNodeMap_Vtbl)).getNamedItem)(this, name, nameditem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn setNamedItem(this: *mut core::ffi::c_void, newitem: *mut core::ffi::c_void, nameitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNamedNodeMap", "setNamedItem", 8usize, |this| ((**(this as *const *const IXMLDOMNamedNodeMap_Vtbl)).setNamedItem)(this, newitem, nameitem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn removeNamedItem(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, nameditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNamedNodeMap", "removeNamedItem", 9usize, |this| ((**(this as *const *const IXMLDOMNamedNodeMap_Vtbl)).removeNamedItem)(this, name, nameditem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_item(this: *mut core::ffi::c_void, index: i32, listitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNamedNodeMap", "get_item", 10usize, |this| ((**(this as *const *const IXMLDOMNamedNodeMap_Vtbl)).get_item)(this, index, listitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn length(this: *mut core::ffi::c_void, listlength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNamedNodeMap", "length", 11usize, |this| ((**(this as *const *const IXMLDOMNamedNodeMap_Vtbl)).length)(this, listlength), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getQualifiedItem(this: *mut core::ffi::c_void, basename: core::mem::MaybeUninit<windows_core::BSTR>, namespaceuri: core::mem::MaybeUninit<windows_core::BSTR>, qualifieditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNamedNodeMap", "getQualifiedItem", 12usize, |this| ((**(this as *const *const IXMLDOMNamedNodeMap_Vtbl)).getQualifiedItem)(this, basename, namespaceuri, qualifieditem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn removeQualifiedItem(this: *mut core::ffi::c_void, basename: core::mem::MaybeUninit<windows_core::BSTR>, namespaceuri: core::mem::MaybeUninit<windows_core::BSTR>, qualifieditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNamedNodeMap", "removeQualifiedItem", 13usize, |this| ((**(this as *const *const IXMLDOMNamedNodeMap_Vtbl)).removeQualifiedItem)(this, basename, namespaceuri, qualifieditem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn nextNode(this: *mut core::ffi::c_void, nextitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNamedNodeMap", "nextNode", 14usize, |this| ((**(this as *const *const IXMLDOMNamedNodeMap_Vtbl)).nextNode)(this, nextitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNamedNodeMap", "reset", 15usize, |this| ((**(this as *const *const IXMLDOMNamedNodeMap_Vtbl)).reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn _newEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNamedNodeMap", "_newEnum", 16usize, |this| ((**(this as *const *const IXMLDOMNamedNodeMap_Vtbl))._newEnum)(this, ppunk), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            getNamedItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getNamedItem: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            setNamedItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            setNamedItem: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            removeNamedItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            removeNamedItem: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_item: 0,
+            length,
+            #[cfg(feature = "Win32_System_Com")]
+            getQualifiedItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getQualifiedItem: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            removeQualifiedItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            removeQualifiedItem: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            nextNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            nextNode: 0,
+            reset,
+            _newEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMNamedNodeMap as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMNode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn nodeName(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "nodeName", 7usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).nodeName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn nodeValue(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "nodeValue", 8usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).nodeValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetnodeValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "SetnodeValue", 9usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).SetnodeValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn nodeType(this: *mut core::ffi::c_void, r#type: *mut DOMNodeType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "nodeType", 10usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).nodeType)(this, r#type), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn parentNode(this: *mut core::ffi::c_void, parent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "parentNode", 11usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).parentNode)(this, parent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn childNodes(this: *mut core::ffi::c_void, childlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "childNodes", 12usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).childNodes)(this, childlist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn firstChild(this: *mut core::ffi::c_void, firstchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "firstChild", 13usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).firstChild)(this, firstchild), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn lastChild(this: *mut core::ffi::c_void, lastchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "lastChild", 14usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).lastChild)(this, lastchild), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn previousSibling(this: *mut core::ffi::c_void, previoussibling: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "previousSibling", 15usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).previousSibling)(this, previoussibling), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn nextSibling(this: *mut core::ffi::c_void, nextsibling: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "nextSibling", 16usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).nextSibling)(this, nextsibling), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn attributes(this: *mut core::ffi::c_void, attributemap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "attributes", 17usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).attributes)(this, attributemap), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn insertBefore(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, refchild: core::mem::MaybeUninit<windows_core::VARIANT>, outnewchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "insertBefore", 18usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).insertBefore)(this, newchild, refchild, outnewchild), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn replaceChild(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, oldchild: *mut core::ffi::c_void, outoldchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "replaceChild", 19usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).replaceChild)(this, newchild, oldchild, outoldchild), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn removeChild(this: *mut core::ffi::c_void, childnode: *mut core::ffi::c_void, oldchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "removeChild", 20usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).removeChild)(this, childnode, oldchild), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn appendChild(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, outnewchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "appendChild", 21usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).appendChild)(this, newchild, outnewchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn hasChildNodes(this: *mut core::ffi::c_void, haschild: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "hasChildNodes", 22usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).hasChildNodes)(this, haschild), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ownerDocument(this: *mut core::ffi::c_void, xmldomdocument: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "ownerDocument", 23usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).ownerDocument)(this, xmldomdocument), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn cloneNode(this: *mut core::ffi::c_void, deep: super::super::super::Foundation::VARIANT_BOOL, cloneroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "cloneNode", 24usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).cloneNode)(this, deep, cloneroot), |result| Some(*result))
+        }
+        unsafe extern "system" fn nodeTypeString(this: *mut core::ffi::c_void, nodetype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "nodeTypeString", 25usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).nodeTypeString)(this, nodetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn text(this: *mut core::ffi::c_void, text: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "text", 26usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).text)(this, text), |result| Some(*result))
+        }
+        unsafe extern "system" fn Settext(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "Settext", 27usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).Settext)(this, text), |result| Some(*result))
+        }
+        unsafe extern "system" fn specified(this: *mut core::ffi::c_void, isspecified: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "specified", 28usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).specified)(this, isspecified), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn definition(this: *mut core::ffi::c_void, definitionnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "definition", 29usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).definition)(this, definitionnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn nodeTypedValue(this: *mut core::ffi::c_void, typedvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "nodeTypedValue", 30usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).nodeTypedValue)(this, typedvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetnodeTypedValue(this: *mut core::ffi::c_void, typedvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "SetnodeTypedValue", 31usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).SetnodeTypedValue)(this, typedvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn dataType(this: *mut core::ffi::c_void, datatypename: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "dataType", 32usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).dataType)(this, datatypename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetdataType(this: *mut core::ffi::c_void, datatypename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "SetdataType", 33usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).SetdataType)(this, datatypename), |result| Some(*result))
+        }
+        unsafe extern "system" fn xml(this: *mut core::ffi::c_void, xmlstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "xml", 34usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).xml)(this, xmlstring), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn transformNode(this: *mut core::ffi::c_void, stylesheet: *mut core::ffi::c_void, xmlstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "transformNode", 35usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).transformNode)(this, stylesheet, xmlstring), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn selectNodes(this: *mut core::ffi::c_void, querystring: core::mem::MaybeUninit<windows_core::BSTR>, resultlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "selectNodes", 36usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).selectNodes)(this, querystring, resultlist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn selectSingleNode(this: *mut core::ffi::c_void, querystring: core::mem::MaybeUninit<windows_core::BSTR>, resultnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "selectSingleNode", 37usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).selectSingleNode)(this, querystring, resultnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn parsed(this: *mut core::ffi::c_void, isparsed: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "parsed", 38usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).parsed)(this, isparsed), |result| Some(*result))
+        }
+        unsafe extern "system" fn namespaceURI(this: *mut core::ffi::c_void, namespaceuri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "namespaceURI", 39usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).namespaceURI)(this, namespaceuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn prefix(this: *mut core::ffi::c_void, prefixstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "prefix", 40usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).prefix)(this, prefixstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn baseName(this: *mut core::ffi::c_void, namestring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "baseName", 41usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).baseName)(this, namestring), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn transformNodeToObject(this: *mut core::ffi::c_void, stylesheet: *mut core::ffi::c_void, outputobject: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNode", "transformNodeToObject", 42usize, |this| ((**(this as *const *const IXMLDOMNode_Vtbl)).transformNodeToObject)(this, stylesheet, outputobject), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            nodeName,
+            nodeValue,
+            SetnodeValue,
+            nodeType,
+            #[cfg(feature = "Win32_System_Com")]
+            parentNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            parentNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            childNodes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            childNodes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            firstChild,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            firstChild: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            lastChild,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            lastChild: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            previousSibling,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            previousSibling: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            nextSibling,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            nextSibling: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            attributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            attributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            insertBefore,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            insertBefore: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            replaceChild,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            replaceChild: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            removeChild,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            removeChild: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            appendChild,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            appendChild: 0,
+            hasChildNodes,
+            #[cfg(feature = "Win32_System_Com")]
+            ownerDocument,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ownerDocument: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            cloneNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            cloneNode: 0,
+            nodeTypeString,
+            text,
+            Settext,
+            specified,
+            #[cfg(feature = "Win32_System_Com")]
+            definition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            definition: 0,
+            nodeTypedValue,
+            SetnodeTypedValue,
+            dataType,
+            SetdataType,
+            xml,
+            #[cfg(feature = "Win32_System_Com")]
+            transformNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            transformNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            selectNodes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            selectNodes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            selectSingleNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            selectSingleNode: 0,
+            parsed,
+            namespaceURI,
+            prefix,
+            baseName,
+            #[cfg(feature = "Win32_System_Com")]
+            transformNodeToObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            transformNodeToObject: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMNode as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMNodeList_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_item(this: *mut core::ffi::c_void, index: i32, listitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNodeList", "get_item", 7usize, |this| ((**(this as *const *const IXMLDOMNodeList_Vtbl)).get_item)(this, index, listitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn length(this: *mut core::ffi::c_void, listlength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNodeList", "length", 8usize, |this| ((**(this as *const *const IXMLDOMNodeList_Vtbl)).length)(this, listlength), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn nextNode(this: *mut core::ffi::c_void, nextitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNodeList", "nextNode", 9usize, |this| ((**(this as *const *const IXMLDOMNodeList_Vtbl)).nextNode)(this, nextitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNodeList", "reset", 10usize, |this| ((**(this as *const *const IXMLDOMNodeList_Vtbl)).reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn _newEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNodeList", "_newEnum", 11usize, |this| ((**(this as *const *const IXMLDOMNodeList_Vtbl))._newEnum)(this, ppunk), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_item: 0,
+            length,
+            #[cfg(feature = "Win32_System_Com")]
+            nextNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            nextNode: 0,
+            reset,
+            _newEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMNodeList as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMNotation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn publicId(this: *mut core::ffi::c_void, publicid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNotation", "publicId", 43usize, |this| ((**(this as *const *const IXMLDOMNotation_Vtbl)).publicId)(this, publicid), |result| Some(*result))
+        }
+        unsafe extern "system" fn systemId(this: *mut core::ffi::c_void, systemid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMNotation", "systemId", 44usize, |this| ((**(this as *const *const IXMLDOMNotation_Vtbl)).systemId)(this, systemid), |result| Some(*result))
+        }
+        Self { base__: <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY, publicId, systemId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMNotation as windows_core::Interface>::IID || <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMParseError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn errorCode(this: *mut core::ffi::c_void, errorcode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseError", "errorCode", 7usize, |this| ((**(this as *const *const IXMLDOMParseError_Vtbl)).errorCode)(this, errorcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn url(this: *mut core::ffi::c_void, urlstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseError", "url", 8usize, |this| ((**(this as *const *const IXMLDOMParseError_Vtbl)).url)(this, urlstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn reason(this: *mut core::ffi::c_void, reasonstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseError", "reason", 9usize, |this| ((**(this as *const *const IXMLDOMParseError_Vtbl)).reason)(this, reasonstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn srcText(this: *mut core::ffi::c_void, sourcestring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseError", "srcText", 10usize, |this| ((**(this as *const *const IXMLDOMParseError_Vtbl)).srcText)(this, sourcestring), |result| Some(*result))
+        }
+        unsafe extern "system" fn line(this: *mut core::ffi::c_void, linenumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseError", "line", 11usize, |this| ((**(this as *const *const IXMLDOMParseError_Vtbl)).line)(this, linenumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn linepos(this: *mut core::ffi::c_void, lineposition: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseError", "linepos", 12usize, |this| ((**(this as *const *const IXMLDOMParseError_Vtbl)).linepos)(this, lineposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn filepos(this: *mut core::ffi::c_void, fileposition: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseError", "filepos", 13usize, |this| ((**(this as *const *const IXMLDOMParseError_Vtbl)).filepos)(this, fileposition), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            errorCode,
+            url,
+            reason,
+            srcText,
+            line,
+            linepos,
+            filepos,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMParseError as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMParseError2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn errorXPath(this: *mut core::ffi::c_void, xpathexpr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseError2", "errorXPath", 14usize, |this| ((**(this as *const *const IXMLDOMParseError2_Vtbl)).errorXPath)(this, xpathexpr), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn allErrors(this: *mut core::ffi::c_void, allerrors: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseError2", "allErrors", 15usize, |this| ((**(this as *const *const IXMLDOMParseError2_Vtbl)).allErrors)(this, allerrors), |result| Some(*result))
+        }
+        unsafe extern "system" fn errorParameters(this: *mut core::ffi::c_void, index: i32, param1: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseError2", "errorParameters", 16usize, |this| ((**(this as *const *const IXMLDOMParseError2_Vtbl)).errorParameters)(this, index, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn errorParametersCount(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseError2", "errorParametersCount", 17usize, |this| ((**(this as *const *const IXMLDOMParseError2_Vtbl)).errorParametersCount)(this, count), |result| Some(*result))
+        }
+        Self {
+            base__: <IXMLDOMParseError_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            errorXPath,
+            #[cfg(feature = "Win32_System_Com")]
+            allErrors,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            allErrors: 0,
+            errorParameters,
+            errorParametersCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMParseError2 as windows_core::Interface>::IID || <IXMLDOMParseError_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMParseErrorCollection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_item(this: *mut core::ffi::c_void, index: i32, error: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseErrorCollection", "get_item", 7usize, |this| ((**(this as *const *const IXMLDOMParseErrorCollection_Vtbl)).get_item)(this, index, error), |result| Some(*result))
+        }
+        unsafe extern "system" fn length(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseErrorCollection", "length", 8usize, |this| ((**(this as *const *const IXMLDOMParseErrorCollection_Vtbl)).length)(this, length), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn next(this: *mut core::ffi::c_void, error: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseErrorCollection", "next", 9usize, |this| ((**(this as *const *const IXMLDOMParseErrorCollection_Vtbl)).next)(this, error), |result| Some(*result))
+        }
+        unsafe extern "system" fn reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseErrorCollection", "reset", 10usize, |this| ((**(this as *const *const IXMLDOMParseErrorCollection_Vtbl)).reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn _newEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMParseErrorCollection", "_newEnum", 11usize, |this| ((**(this as *const *const IXMLDOMParseErrorCollection_Vtbl))._newEnum)(this, ppunk), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_item: 0,
+            length,
+            #[cfg(feature = "Win32_System_Com")]
+            next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            next: 0,
+            reset,
+            _newEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMParseErrorCollection as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMProcessingInstruction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn target(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMProcessingInstruction", "target", 43usize, |this| ((**(this as *const *const IXMLDOMProcessingInstruction_Vtbl)).target)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn data(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMProcessingInstruction", "data", 44usize, |this| ((**(this as *const *const IXMLDOMProcessingInstruction_Vtbl)).data)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setdata(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMProcessingInstruction", "Setdata", 45usize, |this| ((**(this as *const *const IXMLDOMProcessingInstruction_Vtbl)).Setdata)(this, value), |result| Some(*result))
+        }
+        Self { base__: <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY, target, data, Setdata }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMProcessingInstruction as windows_core::Interface>::IID || <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMSchemaCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn add(this: *mut core::ffi::c_void, namespaceuri: core::mem::MaybeUninit<windows_core::BSTR>, var: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSchemaCollection", "add", 7usize, |this| ((**(this as *const *const IXMLDOMSchemaCollection_Vtbl)).add)(this, namespaceuri, var), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get(this: *mut core::ffi::c_void, namespaceuri: core::mem::MaybeUninit<windows_core::BSTR>, schemanode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSchemaCollection", "get", 8usize, |this| ((**(this as *const *const IXMLDOMSchemaCollection_Vtbl)).get)(this, namespaceuri, schemanode), |result| Some(*result))
+        }
+        unsafe extern "system" fn remove(this: *mut core::ffi::c_void, namespaceuri: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSchemaCollection", "remove", 9usize, |this| ((**(this as *const *const IXMLDOMSchemaCollection_Vtbl)).remove)(this, namespaceuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn length(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSchemaCollection", "length", 10usize, |this| ((**(this as *const *const IXMLDOMSchemaCollection_Vtbl)).length)(this, length), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_namespaceURI(this: *mut core::ffi::c_void, index: i32, length: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSchemaCollection", "get_namespaceURI", 11usize, |this| ((**(this as *const *const IXMLDOMSchemaCollection_Vtbl)).get_namespaceURI)(this, index, length), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn addCollection(this: *mut core::ffi::c_void, othercollection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSchemaCollection", "addCollection", 12usize, |this| ((**(this as *const *const IXMLDOMSchemaCollection_Vtbl)).addCollection)(this, othercollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn _newEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSchemaCollection", "_newEnum", 13usize, |this| ((**(this as *const *const IXMLDOMSchemaCollection_Vtbl))._newEnum)(this, ppunk), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            add,
+            #[cfg(feature = "Win32_System_Com")]
+            get,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get: 0,
+            remove,
+            length,
+            get_namespaceURI,
+            #[cfg(feature = "Win32_System_Com")]
+            addCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            addCollection: 0,
+            _newEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMSchemaCollection as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMSchemaCollection2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn validate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSchemaCollection2", "validate", 14usize, |this| ((**(this as *const *const IXMLDOMSchemaCollection2_Vtbl)).validate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetvalidateOnLoad(this: *mut core::ffi::c_void, validateonload: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSchemaCollection2", "SetvalidateOnLoad", 15usize, |this| ((**(this as *const *const IXMLDOMSchemaCollection2_Vtbl)).SetvalidateOnLoad)(this, validateonload), |result| Some(*result))
+        }
+        unsafe extern "system" fn validateOnLoad(this: *mut core::ffi::c_void, validateonload: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSchemaCollection2", "validateOnLoad", 16usize, |this| ((**(this as *const *const IXMLDOMSchemaCollection2_Vtbl)).validateOnLoad)(this, validateonload), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getSchema(this: *mut core::ffi::c_void, namespaceuri: core::mem::MaybeUninit<windows_core::BSTR>, schema: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSchemaCollection2", "getSchema", 17usize, |this| ((**(this as *const *const IXMLDOMSchemaCollection2_Vtbl)).getSchema)(this, namespaceuri, schema), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getDeclaration(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSchemaCollection2", "getDeclaration", 18usize, |this| ((**(this as *const *const IXMLDOMSchemaCollection2_Vtbl)).getDeclaration)(this, node, item), |result| Some(*result))
+        }
+        Self {
+            base__: <IXMLDOMSchemaCollection_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            validate,
+            SetvalidateOnLoad,
+            validateOnLoad,
+            #[cfg(feature = "Win32_System_Com")]
+            getSchema,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getSchema: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getDeclaration,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getDeclaration: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMSchemaCollection2 as windows_core::Interface>::IID || <IXMLDOMSchemaCollection_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMSelection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn expr(this: *mut core::ffi::c_void, expression: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSelection", "expr", 12usize, |this| ((**(this as *const *const IXMLDOMSelection_Vtbl)).expr)(this, expression), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setexpr(this: *mut core::ffi::c_void, expression: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSelection", "Setexpr", 13usize, |this| ((**(this as *const *const IXMLDOMSelection_Vtbl)).Setexpr)(this, expression), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn context(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSelection", "context", 14usize, |this| ((**(this as *const *const IXMLDOMSelection_Vtbl)).context)(this, ppnode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_context(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSelection", "putref_context", 15usize, |this| ((**(this as *const *const IXMLDOMSelection_Vtbl)).putref_context)(this, pnode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn peekNode(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSelection", "peekNode", 16usize, |this| ((**(this as *const *const IXMLDOMSelection_Vtbl)).peekNode)(this, ppnode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn matches(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSelection", "matches", 17usize, |this| ((**(this as *const *const IXMLDOMSelection_Vtbl)).matches)(this, pnode, ppnode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn removeNext(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSelection", "removeNext", 18usize, |this| ((**(this as *const *const IXMLDOMSelection_Vtbl)).removeNext)(this, ppnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn removeAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSelection", "removeAll", 19usize, |this| ((**(this as *const *const IXMLDOMSelection_Vtbl)).removeAll)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn clone(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSelection", "clone", 20usize, |this| ((**(this as *const *const IXMLDOMSelection_Vtbl)).clone)(this, ppnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn getProperty(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSelection", "getProperty", 21usize, |this| ((**(this as *const *const IXMLDOMSelection_Vtbl)).getProperty)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn setProperty(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMSelection", "setProperty", 22usize, |this| ((**(this as *const *const IXMLDOMSelection_Vtbl)).setProperty)(this, name, value), |result| Some(*result))
+        }
+        Self {
+            base__: <IXMLDOMNodeList_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            expr,
+            Setexpr,
+            #[cfg(feature = "Win32_System_Com")]
+            context,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            context: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_context,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_context: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            peekNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            peekNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            matches,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            matches: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            removeNext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            removeNext: 0,
+            removeAll,
+            #[cfg(feature = "Win32_System_Com")]
+            clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            clone: 0,
+            getProperty,
+            setProperty,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMSelection as windows_core::Interface>::IID || <IXMLDOMNodeList_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDOMText_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn splitText(this: *mut core::ffi::c_void, offset: i32, righthandtextnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDOMText", "splitText", 51usize, |this| ((**(this as *const *const IXMLDOMText_Vtbl)).splitText)(this, offset, righthandtextnode), |result| Some(*result))
+        }
+        Self {
+            base__: <IXMLDOMCharacterData_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            splitText,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            splitText: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMText as windows_core::Interface>::IID || <IXMLDOMCharacterData_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDSOControl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn XMLDocument(this: *mut core::ffi::c_void, ppdoc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDSOControl", "XMLDocument", 7usize, |this| ((**(this as *const *const IXMLDSOControl_Vtbl)).XMLDocument)(this, ppdoc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetXMLDocument(this: *mut core::ffi::c_void, ppdoc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDSOControl", "SetXMLDocument", 8usize, |this| ((**(this as *const *const IXMLDSOControl_Vtbl)).SetXMLDocument)(this, ppdoc), |result| Some(*result))
+        }
+        unsafe extern "system" fn JavaDSOCompatible(this: *mut core::ffi::c_void, fjavadsocompatible: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDSOControl", "JavaDSOCompatible", 9usize, |this| ((**(this as *const *const IXMLDSOControl_Vtbl)).JavaDSOCompatible)(this, fjavadsocompatible), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetJavaDSOCompatible(this: *mut core::ffi::c_void, fjavadsocompatible: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDSOControl", "SetJavaDSOCompatible", 10usize, |this| ((**(this as *const *const IXMLDSOControl_Vtbl)).SetJavaDSOCompatible)(this, fjavadsocompatible), |result| Some(*result))
+        }
+        unsafe extern "system" fn readyState(this: *mut core::ffi::c_void, state: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDSOControl", "readyState", 11usize, |this| ((**(this as *const *const IXMLDSOControl_Vtbl)).readyState)(this, state), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            XMLDocument,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            XMLDocument: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetXMLDocument,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetXMLDocument: 0,
+            JavaDSOCompatible,
+            SetJavaDSOCompatible,
+            readyState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDSOControl as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDocument_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn root(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument", "root", 7usize, |this| ((**(this as *const *const IXMLDocument_Vtbl)).root)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn fileSize(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument", "fileSize", 8usize, |this| ((**(this as *const *const IXMLDocument_Vtbl)).fileSize)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn fileModifiedDate(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument", "fileModifiedDate", 9usize, |this| ((**(this as *const *const IXMLDocument_Vtbl)).fileModifiedDate)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn fileUpdatedDate(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument", "fileUpdatedDate", 10usize, |this| ((**(this as *const *const IXMLDocument_Vtbl)).fileUpdatedDate)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn URL(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument", "URL", 11usize, |this| ((**(this as *const *const IXMLDocument_Vtbl)).URL)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetURL(this: *mut core::ffi::c_void, p: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument", "SetURL", 12usize, |this| ((**(this as *const *const IXMLDocument_Vtbl)).SetURL)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn mimeType(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument", "mimeType", 13usize, |this| ((**(this as *const *const IXMLDocument_Vtbl)).mimeType)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn readyState(this: *mut core::ffi::c_void, pl: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument", "readyState", 14usize, |this| ((**(this as *const *const IXMLDocument_Vtbl)).readyState)(this, pl), |result| Some(*result))
+        }
+        unsafe extern "system" fn charset(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument", "charset", 15usize, |this| ((**(this as *const *const IXMLDocument_Vtbl)).charset)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setcharset(this: *mut core::ffi::c_void, p: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument", "Setcharset", 16usize, |this| ((**(this as *const *const IXMLDocument_Vtbl)).Setcharset)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn version(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument", "version", 17usize, |this| ((**(this as *const *const IXMLDocument_Vtbl)).version)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn doctype(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument", "doctype", 18usize, |this| ((**(this as *const *const IXMLDocument_Vtbl)).doctype)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn dtdURL(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument", "dtdURL", 19usize, |this| ((**(this as *const *const IXMLDocument_Vtbl)).dtdURL)(this, p), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn createElement(this: *mut core::ffi::c_void, vtype: core::mem::MaybeUninit<windows_core::VARIANT>, var1: core::mem::MaybeUninit<windows_core::VARIANT>, ppelem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument", "createElement", 20usize, |this| ((**(this as *const *const IXMLDocument_Vtbl)).createElement)(this, vtype, var1, ppelem), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            root,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            root: 0,
+            fileSize,
+            fileModifiedDate,
+            fileUpdatedDate,
+            URL,
+            SetURL,
+            mimeType,
+            readyState,
+            charset,
+            Setcharset,
+            version,
+            doctype,
+            dtdURL,
+            #[cfg(feature = "Win32_System_Com")]
+            createElement,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            createElement: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDocument as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLDocument2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn root(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "root", 7usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).root)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn fileSize(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "fileSize", 8usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).fileSize)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn fileModifiedDate(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "fileModifiedDate", 9usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).fileModifiedDate)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn fileUpdatedDate(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "fileUpdatedDate", 10usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).fileUpdatedDate)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn URL(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "URL", 11usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).URL)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetURL(this: *mut core::ffi::c_void, p: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "SetURL", 12usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).SetURL)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn mimeType(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "mimeType", 13usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).mimeType)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn readyState(this: *mut core::ffi::c_void, pl: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "readyState", 14usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).readyState)(this, pl), |result| Some(*result))
+        }
+        unsafe extern "system" fn charset(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "charset", 15usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).charset)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setcharset(this: *mut core::ffi::c_void, p: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "Setcharset", 16usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).Setcharset)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn version(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "version", 17usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).version)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn doctype(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "doctype", 18usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).doctype)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn dtdURL(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "dtdURL", 19usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).dtdURL)(this, p), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn createElement(this: *mut core::ffi::c_void, vtype: core::mem::MaybeUninit<windows_core::VARIANT>, var1: core::mem::MaybeUninit<windows_core::VARIANT>, ppelem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "createElement", 20usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).createElement)(this, vtype, var1, ppelem), |result| Some(*result))
+        }
+        unsafe extern "system" fn r#async(this: *mut core::ffi::c_void, pf: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "r#async", 21usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).r#async)(this, pf), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setasync(this: *mut core::ffi::c_void, f: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLDocument2", "Setasync", 22usize, |this| ((**(this as *const *const IXMLDocument2_Vtbl)).Setasync)(this, f), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            root,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            root: 0,
+            fileSize,
+            fileModifiedDate,
+            fileUpdatedDate,
+            URL,
+            SetURL,
+            mimeType,
+            readyState,
+            charset,
+            Setcharset,
+            version,
+            doctype,
+            dtdURL,
+            #[cfg(feature = "Win32_System_Com")]
+            createElement,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            createElement: 0,
+            r#async,
+            Setasync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDocument2 as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn tagName(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement", "tagName", 7usize, |this| ((**(this as *const *const IXMLElement_Vtbl)).tagName)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn SettagName(this: *mut core::ffi::c_void, p: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement", "SettagName", 8usize, |this| ((**(this as *const *const IXMLElement_Vtbl)).SettagName)(this, p), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn parent(this: *mut core::ffi::c_void, ppparent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement", "parent", 9usize, |this| ((**(this as *const *const IXMLElement_Vtbl)).parent)(this, ppparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn setAttribute(this: *mut core::ffi::c_void, strpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertyvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement", "setAttribute", 10usize, |this| ((**(this as *const *const IXMLElement_Vtbl)).setAttribute)(this, strpropertyname, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn getAttribute(this: *mut core::ffi::c_void, strpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement", "getAttribute", 11usize, |this| ((**(this as *const *const IXMLElement_Vtbl)).getAttribute)(this, strpropertyname, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn removeAttribute(this: *mut core::ffi::c_void, strpropertyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement", "removeAttribute", 12usize, |this| ((**(this as *const *const IXMLElement_Vtbl)).removeAttribute)(this, strpropertyname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn children(this: *mut core::ffi::c_void, pp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement", "children", 13usize, |this| ((**(this as *const *const IXMLElement_Vtbl)).children)(this, pp), |result| Some(*result))
+        }
+        unsafe extern "system" fn r#type(this: *mut core::ffi::c_void, pltype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement", "r#type", 14usize, |this| ((**(this as *const *const IXMLElement_Vtbl)).r#type)(this, pltype), |result| Some(*result))
+        }
+        unsafe extern "system" fn text(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement", "text", 15usize, |this| ((**(this as *const *const IXMLElement_Vtbl)).text)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn Settext(this: *mut core::ffi::c_void, p: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement", "Settext", 16usize, |this| ((**(this as *const *const IXMLElement_Vtbl)).Settext)(this, p), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn addChild(this: *mut core::ffi::c_void, pchildelem: *mut core::ffi::c_void, lindex: i32, lreserved: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement", "addChild", 17usize, |this| ((**(this as *const *const IXMLElement_Vtbl)).addChild)(this, pchildelem, lindex, lreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn removeChild(this: *mut core::ffi::c_void, pchildelem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement", "removeChild", 18usize, |this| ((**(this as *const *const IXMLElement_Vtbl)).removeChild)(this, pchildelem), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            tagName,
+            SettagName,
+            #[cfg(feature = "Win32_System_Com")]
+            parent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            parent: 0,
+            setAttribute,
+            getAttribute,
+            removeAttribute,
+            #[cfg(feature = "Win32_System_Com")]
+            children,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            children: 0,
+            r#type,
+            text,
+            Settext,
+            #[cfg(feature = "Win32_System_Com")]
+            addChild,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            addChild: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            removeChild,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            removeChild: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLElement as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLElement2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn tagName(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement2", "tagName", 7usize, |this| ((**(this as *const *const IXMLElement2_Vtbl)).tagName)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn SettagName(this: *mut core::ffi::c_void, p: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement2", "SettagName", 8usize, |this| ((**(this as *const *const IXMLElement2_Vtbl)).SettagName)(this, p), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn parent(this: *mut core::ffi::c_void, ppparent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement2", "parent", 9usize, |this| ((**(this as *const *const IXMLElement2_Vtbl)).parent)(this, ppparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn setAttribute(this: *mut core::ffi::c_void, strpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertyvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement2", "setAttribute", 10usize, |this| ((**(this as *const *const IXMLElement2_Vtbl)).setAttribute)(this, strpropertyname, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn getAttribute(this: *mut core::ffi::c_void, strpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement2", "getAttribute", 11usize, |this| ((**(this as *const *const IXMLElement2_Vtbl)).getAttribute)(this, strpropertyname, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn removeAttribute(this: *mut core::ffi::c_void, strpropertyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement2", "removeAttribute", 12usize, |this| ((**(this as *const *const IXMLElement2_Vtbl)).removeAttribute)(this, strpropertyname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn children(this: *mut core::ffi::c_void, pp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement2", "children", 13usize, |this| ((**(this as *const *const IXMLElement2_Vtbl)).children)(this, pp), |result| Some(*result))
+        }
+        unsafe extern "system" fn r#type(this: *mut core::ffi::c_void, pltype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement2", "r#type", 14usize, |this| ((**(this as *const *const IXMLElement2_Vtbl)).r#type)(this, pltype), |result| Some(*result))
+        }
+        unsafe extern "system" fn text(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement2", "text", 15usize, |this| ((**(this as *const *const IXMLElement2_Vtbl)).text)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn Settext(this: *mut core::ffi::c_void, p: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement2", "Settext", 16usize, |this| ((**(this as *const *const IXMLElement2_Vtbl)).Settext)(this, p), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn addChild(this: *mut core::ffi::c_void, pchildelem: *mut core::ffi::c_void, lindex: i32, lreserved: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement2", "addChild", 17usize, |this| ((**(this as *const *const IXMLElement2_Vtbl)).addChild)(this, pchildelem, lindex, lreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn removeChild(this: *mut core::ffi::c_void, pchildelem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement2", "removeChild", 18usize, |this| ((**(this as *const *const IXMLElement2_Vtbl)).removeChild)(this, pchildelem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn attributes(this: *mut core::ffi::c_void, pp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElement2", "attributes", 19usize, |this| ((**(this as *const *const IXMLElement2_Vtbl)).attributes)(this, pp), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            tagName,
+            SettagName,
+            #[cfg(feature = "Win32_System_Com")]
+            parent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            parent: 0,
+            setAttribute,
+            getAttribute,
+            removeAttribute,
+            #[cfg(feature = "Win32_System_Com")]
+            children,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            children: 0,
+            r#type,
+            text,
+            Settext,
+            #[cfg(feature = "Win32_System_Com")]
+            addChild,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            addChild: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            removeChild,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            removeChild: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            attributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            attributes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLElement2 as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLElementCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Setlength(this: *mut core::ffi::c_void, v: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElementCollection", "Setlength", 7usize, |this| ((**(this as *const *const IXMLElementCollection_Vtbl)).Setlength)(this, v), |result| Some(*result))
+        }
+        unsafe extern "system" fn length(this: *mut core::ffi::c_void, p: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElementCollection", "length", 8usize, |this| ((**(this as *const *const IXMLElementCollection_Vtbl)).length)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn _newEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElementCollection", "_newEnum", 9usize, |this| ((**(this as *const *const IXMLElementCollection_Vtbl))._newEnum)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn item(this: *mut core::ffi::c_void, var1: core::mem::MaybeUninit<windows_core::VARIANT>, var2: core::mem::MaybeUninit<windows_core::VARIANT>, ppdisp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLElementCollection", "item", 10usize, |this| ((**(this as *const *const IXMLElementCollection_Vtbl)).item)(this, var1, var2, ppdisp), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Setlength,
+            length,
+            _newEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLElementCollection as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXMLError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetErrorInfo(this: *mut core::ffi::c_void, perrorreturn: *mut XML_ERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLError", "GetErrorInfo", 3usize, |this| ((**(this as *const *const IXMLError_Vtbl)).GetErrorInfo)(this, perrorreturn), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetErrorInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLError as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLHTTPRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn open(this: *mut core::ffi::c_void, bstrmethod: core::mem::MaybeUninit<windows_core::BSTR>, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, varasync: core::mem::MaybeUninit<windows_core::VARIANT>, bstruser: core::mem::MaybeUninit<windows_core::VARIANT>, bstrpassword: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest", "open", 7usize, |this| ((**(this as *const *const IXMLHTTPRequest_Vtbl)).open)(this, bstrmethod, bstrurl, varasync, bstruser, bstrpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn setRequestHeader(this: *mut core::ffi::c_void, bstrheader: core::mem::MaybeUninit<windows_core::BSTR>, bstrvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest", "setRequestHeader", 8usize, |this| ((**(this as *const *const IXMLHTTPRequest_Vtbl)).setRequestHeader)(this, bstrheader, bstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn getResponseHeader(this: *mut core::ffi::c_void, bstrheader: core::mem::MaybeUninit<windows_core::BSTR>, pbstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest", "getResponseHeader", 9usize, |this| ((**(this as *const *const IXMLHTTPRequest_Vtbl)).getResponseHeader)(this, bstrheader, pbstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn getAllResponseHeaders(this: *mut core::ffi::c_void, pbstrheaders: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest", "getAllResponseHeaders", 10usize, |this| ((**(this as *const *const IXMLHTTPRequest_Vtbl)).getAllResponseHeaders)(this, pbstrheaders), |result| Some(*result))
+        }
+        unsafe extern "system" fn send(this: *mut core::ffi::c_void, varbody: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest", "send", 11usize, |this| ((**(this as *const *const IXMLHTTPRequest_Vtbl)).send)(this, varbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest", "abort", 12usize, |this| ((**(this as *const *const IXMLHTTPRequest_Vtbl)).abort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn status(this: *mut core::ffi::c_void, plstatus: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest", "status", 13usize, |this| ((**(this as *const *const IXMLHTTPRequest_Vtbl)).status)(this, plstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn statusText(this: *mut core::ffi::c_void, pbstrstatus: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest", "statusText", 14usize, |this| ((**(this as *const *const IXMLHTTPRequest_Vtbl)).statusText)(this, pbstrstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn responseXML(this: *mut core::ffi::c_void, ppbody: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest", "responseXML", 15usize, |this| ((**(this as *const *const IXMLHTTPRequest_Vtbl)).responseXML)(this, ppbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn responseText(this: *mut core::ffi::c_void, pbstrbody: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest", "responseText", 16usize, |this| ((**(this as *const *const IXMLHTTPRequest_Vtbl)).responseText)(this, pbstrbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn responseBody(this: *mut core::ffi::c_void, pvarbody: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest", "responseBody", 17usize, |this| ((**(this as *const *const IXMLHTTPRequest_Vtbl)).responseBody)(this, pvarbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn responseStream(this: *mut core::ffi::c_void, pvarbody: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest", "responseStream", 18usize, |this| ((**(this as *const *const IXMLHTTPRequest_Vtbl)).responseStream)(this, pvarbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn readyState(this: *mut core::ffi::c_void, plstate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest", "readyState", 19usize, |this| ((**(this as *const *const IXMLHTTPRequest_Vtbl)).readyState)(this, plstate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Setonreadystatechange(this: *mut core::ffi::c_void, preadystatesink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest", "Setonreadystatechange", 20usize, |this| ((**(this as *const *const IXMLHTTPRequest_Vtbl)).Setonreadystatechange)(this, preadystatesink), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            open,
+            setRequestHeader,
+            getResponseHeader,
+            getAllResponseHeaders,
+            send,
+            abort,
+            status,
+            statusText,
+            #[cfg(feature = "Win32_System_Com")]
+            responseXML,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            responseXML: 0,
+            responseText,
+            responseBody,
+            responseStream,
+            readyState,
+            #[cfg(feature = "Win32_System_Com")]
+            Setonreadystatechange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Setonreadystatechange: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLHTTPRequest as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLHTTPRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, pwszmethod: windows_core::PCWSTR, pwszurl: windows_core::PCWSTR, pstatuscallback: *mut core::ffi::c_void, pwszusername: windows_core::PCWSTR, pwszpassword: windows_core::PCWSTR, pwszproxyusername: windows_core::PCWSTR, pwszproxypassword: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest2", "Open", 3usize, |this| ((**(this as *const *const IXMLHTTPRequest2_Vtbl)).Open)(this, pwszmethod, pwszurl, pstatuscallback, pwszusername, pwszpassword, pwszproxyusername, pwszproxypassword), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Send(this: *mut core::ffi::c_void, pbody: *mut core::ffi::c_void, cbbody: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest2", "Send", 4usize, |this| ((**(this as *const *const IXMLHTTPRequest2_Vtbl)).Send)(this, pbody, cbbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest2", "Abort", 5usize, |this| ((**(this as *const *const IXMLHTTPRequest2_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCookie(this: *mut core::ffi::c_void, pcookie: *const XHR_COOKIE, pdwcookiestate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest2", "SetCookie", 6usize, |this| ((**(this as *const *const IXMLHTTPRequest2_Vtbl)).SetCookie)(this, pcookie, pdwcookiestate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetCustomResponseStream(this: *mut core::ffi::c_void, psequentialstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest2", "SetCustomResponseStream", 7usize, |this| ((**(this as *const *const IXMLHTTPRequest2_Vtbl)).SetCustomResponseStream)(this, psequentialstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, eproperty: XHR_PROPERTY, ullvalue: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest2", "SetProperty", 8usize, |this| ((**(this as *const *const IXMLHTTPRequest2_Vtbl)).SetProperty)(this, eproperty, ullvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestHeader(this: *mut core::ffi::c_void, pwszheader: windows_core::PCWSTR, pwszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest2", "SetRequestHeader", 9usize, |this| ((**(this as *const *const IXMLHTTPRequest2_Vtbl)).SetRequestHeader)(this, pwszheader, pwszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllResponseHeaders(this: *mut core::ffi::c_void, ppwszheaders: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest2", "GetAllResponseHeaders", 10usize, |this| ((**(this as *const *const IXMLHTTPRequest2_Vtbl)).GetAllResponseHeaders)(this, ppwszheaders), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCookie(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, pwszname: windows_core::PCWSTR, dwflags: u32, pccookies: *mut u32, ppcookies: *mut *mut XHR_COOKIE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest2", "GetCookie", 11usize, |this| ((**(this as *const *const IXMLHTTPRequest2_Vtbl)).GetCookie)(this, pwszurl, pwszname, dwflags, pccookies, ppcookies), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResponseHeader(this: *mut core::ffi::c_void, pwszheader: windows_core::PCWSTR, ppwszvalue: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest2", "GetResponseHeader", 12usize, |this| ((**(this as *const *const IXMLHTTPRequest2_Vtbl)).GetResponseHeader)(this, pwszheader, ppwszvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Open,
+            #[cfg(feature = "Win32_System_Com")]
+            Send,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Send: 0,
+            Abort,
+            SetCookie,
+            #[cfg(feature = "Win32_System_Com")]
+            SetCustomResponseStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetCustomResponseStream: 0,
+            SetProperty,
+            SetRequestHeader,
+            GetAllResponseHeaders,
+            GetCookie,
+            GetResponseHeader,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLHTTPRequest2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLHTTPRequest2Callback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnRedirect(this: *mut core::ffi::c_void, pxhr: *mut core::ffi::c_void, pwszredirecturl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest2Callback", "OnRedirect", 3usize, |this| ((**(this as *const *const IXMLHTTPRequest2Callback_Vtbl)).OnRedirect)(this, pxhr, pwszredirecturl), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnHeadersAvailable(this: *mut core::ffi::c_void, pxhr: *mut core::ffi::c_void, dwstatus: u32, pwszstatus: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest2Callback", "OnHeadersAvailable", 4usize, |this| ((**(this as *const *const IXMLHTTPRequest2Callback_Vtbl)).OnHeadersAvailable)(this, pxhr, dwstatus, pwszstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnDataAvailable(this: *mut core::ffi::c_void, pxhr: *mut core::ffi::c_void, presponsestream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest2Callback", "OnDataAvailable", 5usize, |this| ((**(this as *const *const IXMLHTTPRequest2Callback_Vtbl)).OnDataAvailable)(this, pxhr, presponsestream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnResponseReceived(this: *mut core::ffi::c_void, pxhr: *mut core::ffi::c_void, presponsestream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest2Callback", "OnResponseReceived", 6usize, |this| ((**(this as *const *const IXMLHTTPRequest2Callback_Vtbl)).OnResponseReceived)(this, pxhr, presponsestream), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnError(this: *mut core::ffi::c_void, pxhr: *mut core::ffi::c_void, hrerror: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest2Callback", "OnError", 7usize, |this| ((**(this as *const *const IXMLHTTPRequest2Callback_Vtbl)).OnError)(this, pxhr, hrerror), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnRedirect,
+            OnHeadersAvailable,
+            #[cfg(feature = "Win32_System_Com")]
+            OnDataAvailable,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnDataAvailable: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnResponseReceived,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnResponseReceived: 0,
+            OnError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLHTTPRequest2Callback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLHTTPRequest3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetClientCertificate(this: *mut core::ffi::c_void, cbclientcertificatehash: u32, pbclientcertificatehash: *const u8, pwszpin: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest3", "SetClientCertificate", 13usize, |this| ((**(this as *const *const IXMLHTTPRequest3_Vtbl)).SetClientCertificate)(this, cbclientcertificatehash, pbclientcertificatehash, pwszpin), |result| Some(*result))
+        }
+        Self { base__: <IXMLHTTPRequest2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetClientCertificate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLHTTPRequest3 as windows_core::Interface>::IID || <IXMLHTTPRequest2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXMLHTTPRequest3Callback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnServerCertificateReceived(this: *mut core::ffi::c_void, pxhr: *mut core::ffi::c_void, dwcertificateerrors: u32, cservercertificatechain: u32, rgservercertificatechain: *const XHR_CERT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest3Callback", "OnServerCertificateReceived", 8usize, |this| ((**(this as *const *const IXMLHTTPRequest3Callback_Vtbl)).OnServerCertificateReceived)(this, pxhr, dwcertificateerrors, cservercertificatechain, rgservercertificatechain), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnClientCertificateRequested(this: *mut core::ffi::c_void, pxhr: *mut core::ffi::c_void, cissuerlist: u32, rgpwszissuerlist: *const *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLHTTPRequest3Callback", "OnClientCertificateRequested", 9usize, |this| ((**(this as *const *const IXMLHTTPRequest3Callback_Vtbl)).OnClientCertificateRequested)(this, pxhr, cissuerlist, rgpwszissuerlist), |result| Some(*result))
+        }
+        Self { base__: <IXMLHTTPRequest2Callback_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnServerCertificateReceived, OnClientCertificateRequested }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLHTTPRequest3Callback as windows_core::Interface>::IID || <IXMLHTTPRequest2Callback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXSLProcessor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Setinput(this: *mut core::ffi::c_void, var: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLProcessor", "Setinput", 7usize, |this| ((**(this as *const *const IXSLProcessor_Vtbl)).Setinput)(this, var), |result| Some(*result))
+        }
+        unsafe extern "system" fn input(this: *mut core::ffi::c_void, pvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLProcessor", "input", 8usize, |this| ((**(this as *const *const IXSLProcessor_Vtbl)).input)(this, pvar), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ownerTemplate(this: *mut core::ffi::c_void, pptemplate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLProcessor", "ownerTemplate", 9usize, |this| ((**(this as *const *const IXSLProcessor_Vtbl)).ownerTemplate)(this, pptemplate), |result| Some(*result))
+        }
+        unsafe extern "system" fn setStartMode(this: *mut core::ffi::c_void, mode: core::mem::MaybeUninit<windows_core::BSTR>, namespaceuri: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLProcessor", "setStartMode", 10usize, |this| ((**(this as *const *const IXSLProcessor_Vtbl)).setStartMode)(this, mode, namespaceuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn startMode(this: *mut core::ffi::c_void, mode: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLProcessor", "startMode", 11usize, |this| ((**(this as *const *const IXSLProcessor_Vtbl)).startMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn startModeURI(this: *mut core::ffi::c_void, namespaceuri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLProcessor", "startModeURI", 12usize, |this| ((**(this as *const *const IXSLProcessor_Vtbl)).startModeURI)(this, namespaceuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setoutput(this: *mut core::ffi::c_void, output: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLProcessor", "Setoutput", 13usize, |this| ((**(this as *const *const IXSLProcessor_Vtbl)).Setoutput)(this, output), |result| Some(*result))
+        }
+        unsafe extern "system" fn output(this: *mut core::ffi::c_void, poutput: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLProcessor", "output", 14usize, |this| ((**(this as *const *const IXSLProcessor_Vtbl)).output)(this, poutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn transform(this: *mut core::ffi::c_void, pdone: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLProcessor", "transform", 15usize, |this| ((**(this as *const *const IXSLProcessor_Vtbl)).transform)(this, pdone), |result| Some(*result))
+        }
+        unsafe extern "system" fn reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLProcessor", "reset", 16usize, |this| ((**(this as *const *const IXSLProcessor_Vtbl)).reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn readyState(this: *mut core::ffi::c_void, preadystate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLProcessor", "readyState", 17usize, |this| ((**(this as *const *const IXSLProcessor_Vtbl)).readyState)(this, preadystate), |result| Some(*result))
+        }
+        unsafe extern "system" fn addParameter(this: *mut core::ffi::c_void, basename: core::mem::MaybeUninit<windows_core::BSTR>, parameter: core::mem::MaybeUninit<windows_core::VARIANT>, namespaceuri: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLProcessor", "addParameter", 18usize, |this| ((**(this as *const *const IXSLProcessor_Vtbl)).addParameter)(this, basename, parameter, namespaceuri), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn addObject(this: *mut core::ffi::c_void, obj: *mut core::ffi::c_void, namespaceuri: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLProcessor", "addObject", 19usize, |this| ((**(this as *const *const IXSLProcessor_Vtbl)).addObject)(this, obj, namespaceuri), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn stylesheet(this: *mut core::ffi::c_void, stylesheet: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLProcessor", "stylesheet", 20usize, |this| ((**(this as *const *const IXSLProcessor_Vtbl)).stylesheet)(this, stylesheet), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Setinput,
+            input,
+            #[cfg(feature = "Win32_System_Com")]
+            ownerTemplate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ownerTemplate: 0,
+            setStartMode,
+            startMode,
+            startModeURI,
+            Setoutput,
+            output,
+            transform,
+            reset,
+            readyState,
+            addParameter,
+            #[cfg(feature = "Win32_System_Com")]
+            addObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            addObject: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            stylesheet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            stylesheet: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXSLProcessor as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXSLTemplate_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_stylesheet(this: *mut core::ffi::c_void, stylesheet: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLTemplate", "putref_stylesheet", 7usize, |this| ((**(this as *const *const IXSLTemplate_Vtbl)).putref_stylesheet)(this, stylesheet), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn stylesheet(this: *mut core::ffi::c_void, stylesheet: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLTemplate", "stylesheet", 8usize, |this| ((**(this as *const *const IXSLTemplate_Vtbl)).stylesheet)(this, stylesheet), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn createProcessor(this: *mut core::ffi::c_void, ppprocessor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXSLTemplate", "createProcessor", 9usize, |this| ((**(this as *const *const IXSLTemplate_Vtbl)).createProcessor)(this, ppprocessor), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_stylesheet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_stylesheet: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            stylesheet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            stylesheet: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            createProcessor,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            createProcessor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXSLTemplate as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXTLRuntime_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn uniqueID(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, pid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXTLRuntime", "uniqueID", 43usize, |this| ((**(this as *const *const IXTLRuntime_Vtbl)).uniqueID)(this, pnode, pid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn depth(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, pdepth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXTLRuntime", "depth", 44usize, |this| ((**(this as *const *const IXTLRuntime_Vtbl)).depth)(this, pnode, pdepth), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn childNumber(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, pnumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXTLRuntime", "childNumber", 45usize, |this| ((**(this as *const *const IXTLRuntime_Vtbl)).childNumber)(this, pnode, pnumber), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ancestorChildNumber(this: *mut core::ffi::c_void, bstrnodename: core::mem::MaybeUninit<windows_core::BSTR>, pnode: *mut core::ffi::c_void, pnumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXTLRuntime", "ancestorChildNumber", 46usize, |this| ((**(this as *const *const IXTLRuntime_Vtbl)).ancestorChildNumber)(this, bstrnodename, pnode, pnumber), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn absoluteChildNumber(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, pnumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXTLRuntime", "absoluteChildNumber", 47usize, |this| ((**(this as *const *const IXTLRuntime_Vtbl)).absoluteChildNumber)(this, pnode, pnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn formatIndex(this: *mut core::ffi::c_void, lindex: i32, bstrformat: core::mem::MaybeUninit<windows_core::BSTR>, pbstrformattedstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXTLRuntime", "formatIndex", 48usize, |this| ((**(this as *const *const IXTLRuntime_Vtbl)).formatIndex)(this, lindex, bstrformat, pbstrformattedstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn formatNumber(this: *mut core::ffi::c_void, dblnumber: f64, bstrformat: core::mem::MaybeUninit<windows_core::BSTR>, pbstrformattedstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXTLRuntime", "formatNumber", 49usize, |this| ((**(this as *const *const IXTLRuntime_Vtbl)).formatNumber)(this, dblnumber, bstrformat, pbstrformattedstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn formatDate(this: *mut core::ffi::c_void, vardate: core::mem::MaybeUninit<windows_core::VARIANT>, bstrformat: core::mem::MaybeUninit<windows_core::BSTR>, vardestlocale: core::mem::MaybeUninit<windows_core::VARIANT>, pbstrformattedstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXTLRuntime", "formatDate", 50usize, |this| ((**(this as *const *const IXTLRuntime_Vtbl)).formatDate)(this, vardate, bstrformat, vardestlocale, pbstrformattedstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn formatTime(this: *mut core::ffi::c_void, vartime: core::mem::MaybeUninit<windows_core::VARIANT>, bstrformat: core::mem::MaybeUninit<windows_core::BSTR>, vardestlocale: core::mem::MaybeUninit<windows_core::VARIANT>, pbstrformattedstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXTLRuntime", "formatTime", 51usize, |this| ((**(this as *const *const IXTLRuntime_Vtbl)).formatTime)(this, vartime, bstrformat, vardestlocale, pbstrformattedstring), |result| Some(*result))
+        }
+        Self {
+            base__: <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            uniqueID,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            uniqueID: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            depth,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            depth: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            childNumber,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            childNumber: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ancestorChildNumber,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ancestorChildNumber: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            absoluteChildNumber,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            absoluteChildNumber: 0,
+            formatIndex,
+            formatNumber,
+            formatDate,
+            formatTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXTLRuntime as windows_core::Interface>::IID || <IXMLDOMNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for XMLDOMDocumentEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<XMLDOMDocumentEvents as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -724,6 +724,7 @@ pub struct DtdProcessing(pub i32);
 impl windows_core::TypeKind for DtdProcessing {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DtdProcessing {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DtdProcessing").field(&self.0).finish()
@@ -735,6 +736,7 @@ pub struct XmlConformanceLevel(pub i32);
 impl windows_core::TypeKind for XmlConformanceLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XmlConformanceLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XmlConformanceLevel").field(&self.0).finish()
@@ -746,6 +748,7 @@ pub struct XmlError(pub i32);
 impl windows_core::TypeKind for XmlError {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XmlError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XmlError").field(&self.0).finish()
@@ -757,6 +760,7 @@ pub struct XmlNodeType(pub i32);
 impl windows_core::TypeKind for XmlNodeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XmlNodeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XmlNodeType").field(&self.0).finish()
@@ -768,6 +772,7 @@ pub struct XmlReadState(pub i32);
 impl windows_core::TypeKind for XmlReadState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XmlReadState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XmlReadState").field(&self.0).finish()
@@ -779,6 +784,7 @@ pub struct XmlReaderProperty(pub i32);
 impl windows_core::TypeKind for XmlReaderProperty {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XmlReaderProperty {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XmlReaderProperty").field(&self.0).finish()
@@ -790,6 +796,7 @@ pub struct XmlStandalone(pub i32);
 impl windows_core::TypeKind for XmlStandalone {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XmlStandalone {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XmlStandalone").field(&self.0).finish()
@@ -801,6 +808,7 @@ pub struct XmlWriterProperty(pub i32);
 impl windows_core::TypeKind for XmlWriterProperty {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XmlWriterProperty {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XmlWriterProperty").field(&self.0).finish()
@@ -808,3 +816,5 @@ impl core::fmt::Debug for XmlWriterProperty {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
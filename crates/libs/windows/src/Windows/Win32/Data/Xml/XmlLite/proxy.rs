@@ -0,0 +1,365 @@
+impl windows_core::imp::ProxyVtable for IXmlReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetInput(this: *mut core::ffi::c_void, pinput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "SetInput", 3usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).SetInput)(this, pinput), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, nproperty: u32, ppvalue: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "GetProperty", 4usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).GetProperty)(this, nproperty, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, nproperty: u32, pvalue: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "SetProperty", 5usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).SetProperty)(this, nproperty, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, pnodetype: *mut XmlNodeType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "Read", 6usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).Read)(this, pnodetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNodeType(this: *mut core::ffi::c_void, pnodetype: *mut XmlNodeType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "GetNodeType", 7usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).GetNodeType)(this, pnodetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveToFirstAttribute(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "MoveToFirstAttribute", 8usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).MoveToFirstAttribute)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveToNextAttribute(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "MoveToNextAttribute", 9usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).MoveToNextAttribute)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveToAttributeByName(this: *mut core::ffi::c_void, pwszlocalname: windows_core::PCWSTR, pwsznamespaceuri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "MoveToAttributeByName", 10usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).MoveToAttributeByName)(this, pwszlocalname, pwsznamespaceuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveToElement(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "MoveToElement", 11usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).MoveToElement)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQualifiedName(this: *mut core::ffi::c_void, ppwszqualifiedname: *mut windows_core::PCWSTR, pcwchqualifiedname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "GetQualifiedName", 12usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).GetQualifiedName)(this, ppwszqualifiedname, pcwchqualifiedname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamespaceUri(this: *mut core::ffi::c_void, ppwsznamespaceuri: *mut windows_core::PCWSTR, pcwchnamespaceuri: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "GetNamespaceUri", 13usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).GetNamespaceUri)(this, ppwsznamespaceuri, pcwchnamespaceuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalName(this: *mut core::ffi::c_void, ppwszlocalname: *mut windows_core::PCWSTR, pcwchlocalname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "GetLocalName", 14usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).GetLocalName)(this, ppwszlocalname, pcwchlocalname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrefix(this: *mut core::ffi::c_void, ppwszprefix: *mut windows_core::PCWSTR, pcwchprefix: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "GetPrefix", 15usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).GetPrefix)(this, ppwszprefix, pcwchprefix), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, ppwszvalue: *mut windows_core::PCWSTR, pcwchvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "GetValue", 16usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).GetValue)(this, ppwszvalue, pcwchvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadValueChunk(this: *mut core::ffi::c_void, pwchbuffer: windows_core::PWSTR, cwchchunksize: u32, pcwchread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "ReadValueChunk", 17usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).ReadValueChunk)(this, pwchbuffer, cwchchunksize, pcwchread), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBaseUri(this: *mut core::ffi::c_void, ppwszbaseuri: *mut windows_core::PCWSTR, pcwchbaseuri: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "GetBaseUri", 18usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).GetBaseUri)(this, ppwszbaseuri, pcwchbaseuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDefault(this: *mut core::ffi::c_void) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IXmlReader", "IsDefault", 19usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).IsDefault)(this), |_| None)
+        }
+        unsafe extern "system" fn IsEmptyElement(this: *mut core::ffi::c_void) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IXmlReader", "IsEmptyElement", 20usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).IsEmptyElement)(this), |_| None)
+        }
+        unsafe extern "system" fn GetLineNumber(this: *mut core::ffi::c_void, pnlinenumber: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "GetLineNumber", 21usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).GetLineNumber)(this, pnlinenumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLinePosition(this: *mut core::ffi::c_void, pnlineposition: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "GetLinePosition", 22usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).GetLinePosition)(this, pnlineposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeCount(this: *mut core::ffi::c_void, pnattributecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "GetAttributeCount", 23usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).GetAttributeCount)(this, pnattributecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDepth(this: *mut core::ffi::c_void, pndepth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlReader", "GetDepth", 24usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).GetDepth)(this, pndepth), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEOF(this: *mut core::ffi::c_void) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IXmlReader", "IsEOF", 25usize, |this| ((**(this as *const *const IXmlReader_Vtbl)).IsEOF)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetInput,
+            GetProperty,
+            SetProperty,
+            Read,
+            GetNodeType,
+            MoveToFirstAttribute,
+            MoveToNextAttribute,
+            MoveToAttributeByName,
+            MoveToElement,
+            GetQualifiedName,
+            GetNamespaceUri,
+            GetLocalName,
+            GetPrefix,
+            GetValue,
+            ReadValueChunk,
+            GetBaseUri,
+            IsDefault,
+            IsEmptyElement,
+            GetLineNumber,
+            GetLinePosition,
+            GetAttributeCount,
+            GetDepth,
+            IsEOF,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlResolver_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResolveUri(this: *mut core::ffi::c_void, pwszbaseuri: windows_core::PCWSTR, pwszpublicidentifier: windows_core::PCWSTR, pwszsystemidentifier: windows_core::PCWSTR, ppresolvedinput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlResolver", "ResolveUri", 3usize, |this| ((**(this as *const *const IXmlResolver_Vtbl)).ResolveUri)(this, pwszbaseuri, pwszpublicidentifier, pwszsystemidentifier, ppresolvedinput), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ResolveUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlResolver as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlWriter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOutput(this: *mut core::ffi::c_void, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "SetOutput", 3usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).SetOutput)(this, poutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, nproperty: u32, ppvalue: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "GetProperty", 4usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).GetProperty)(this, nproperty, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, nproperty: u32, pvalue: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "SetProperty", 5usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).SetProperty)(this, nproperty, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteAttributes(this: *mut core::ffi::c_void, preader: *mut core::ffi::c_void, fwritedefaultattributes: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteAttributes", 6usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteAttributes)(this, preader, fwritedefaultattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteAttributeString(this: *mut core::ffi::c_void, pwszprefix: windows_core::PCWSTR, pwszlocalname: windows_core::PCWSTR, pwsznamespaceuri: windows_core::PCWSTR, pwszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteAttributeString", 7usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteAttributeString)(this, pwszprefix, pwszlocalname, pwsznamespaceuri, pwszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteCData(this: *mut core::ffi::c_void, pwsztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteCData", 8usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteCData)(this, pwsztext), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteCharEntity(this: *mut core::ffi::c_void, wch: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteCharEntity", 9usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteCharEntity)(this, wch), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteChars(this: *mut core::ffi::c_void, pwch: windows_core::PCWSTR, cwch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteChars", 10usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteChars)(this, pwch, cwch), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteComment(this: *mut core::ffi::c_void, pwszcomment: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteComment", 11usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteComment)(this, pwszcomment), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteDocType(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, pwszpublicid: windows_core::PCWSTR, pwszsystemid: windows_core::PCWSTR, pwszsubset: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteDocType", 12usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteDocType)(this, pwszname, pwszpublicid, pwszsystemid, pwszsubset), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteElementString(this: *mut core::ffi::c_void, pwszprefix: windows_core::PCWSTR, pwszlocalname: windows_core::PCWSTR, pwsznamespaceuri: windows_core::PCWSTR, pwszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteElementString", 13usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteElementString)(this, pwszprefix, pwszlocalname, pwsznamespaceuri, pwszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteEndDocument(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteEndDocument", 14usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteEndDocument)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteEndElement(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteEndElement", 15usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteEndElement)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteEntityRef(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteEntityRef", 16usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteEntityRef)(this, pwszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteFullEndElement(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteFullEndElement", 17usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteFullEndElement)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteName(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteName", 18usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteName)(this, pwszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteNmToken(this: *mut core::ffi::c_void, pwsznmtoken: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteNmToken", 19usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteNmToken)(this, pwsznmtoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteNode(this: *mut core::ffi::c_void, preader: *mut core::ffi::c_void, fwritedefaultattributes: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteNode", 20usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteNode)(this, preader, fwritedefaultattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteNodeShallow(this: *mut core::ffi::c_void, preader: *mut core::ffi::c_void, fwritedefaultattributes: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteNodeShallow", 21usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteNodeShallow)(this, preader, fwritedefaultattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteProcessingInstruction(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, pwsztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteProcessingInstruction", 22usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteProcessingInstruction)(this, pwszname, pwsztext), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteQualifiedName(this: *mut core::ffi::c_void, pwszlocalname: windows_core::PCWSTR, pwsznamespaceuri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteQualifiedName", 23usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteQualifiedName)(this, pwszlocalname, pwsznamespaceuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteRaw(this: *mut core::ffi::c_void, pwszdata: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteRaw", 24usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteRaw)(this, pwszdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteRawChars(this: *mut core::ffi::c_void, pwch: windows_core::PCWSTR, cwch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteRawChars", 25usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteRawChars)(this, pwch, cwch), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteStartDocument(this: *mut core::ffi::c_void, standalone: XmlStandalone) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteStartDocument", 26usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteStartDocument)(this, standalone), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteStartElement(this: *mut core::ffi::c_void, pwszprefix: windows_core::PCWSTR, pwszlocalname: windows_core::PCWSTR, pwsznamespaceuri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteStartElement", 27usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteStartElement)(this, pwszprefix, pwszlocalname, pwsznamespaceuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteString(this: *mut core::ffi::c_void, pwsztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteString", 28usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteString)(this, pwsztext), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteSurrogateCharEntity(this: *mut core::ffi::c_void, wchlow: u16, wchhigh: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteSurrogateCharEntity", 29usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteSurrogateCharEntity)(this, wchlow, wchhigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteWhitespace(this: *mut core::ffi::c_void, pwszwhitespace: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "WriteWhitespace", 30usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).WriteWhitespace)(this, pwszwhitespace), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriter", "Flush", 31usize, |this| ((**(this as *const *const IXmlWriter_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetOutput,
+            GetProperty,
+            SetProperty,
+            WriteAttributes,
+            WriteAttributeString,
+            WriteCData,
+            WriteCharEntity,
+            WriteChars,
+            WriteComment,
+            WriteDocType,
+            WriteElementString,
+            WriteEndDocument,
+            WriteEndElement,
+            WriteEntityRef,
+            WriteFullEndElement,
+            WriteName,
+            WriteNmToken,
+            WriteNode,
+            WriteNodeShallow,
+            WriteProcessingInstruction,
+            WriteQualifiedName,
+            WriteRaw,
+            WriteRawChars,
+            WriteStartDocument,
+            WriteStartElement,
+            WriteString,
+            WriteSurrogateCharEntity,
+            WriteWhitespace,
+            Flush,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlWriter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXmlWriterLite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOutput(this: *mut core::ffi::c_void, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "SetOutput", 3usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).SetOutput)(this, poutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, nproperty: u32, ppvalue: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "GetProperty", 4usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).GetProperty)(this, nproperty, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, nproperty: u32, pvalue: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "SetProperty", 5usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).SetProperty)(this, nproperty, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteAttributes(this: *mut core::ffi::c_void, preader: *mut core::ffi::c_void, fwritedefaultattributes: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteAttributes", 6usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteAttributes)(this, preader, fwritedefaultattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteAttributeString(this: *mut core::ffi::c_void, pwszqname: windows_core::PCWSTR, cwszqname: u32, pwszvalue: windows_core::PCWSTR, cwszvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteAttributeString", 7usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteAttributeString)(this, pwszqname, cwszqname, pwszvalue, cwszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteCData(this: *mut core::ffi::c_void, pwsztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteCData", 8usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteCData)(this, pwsztext), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteCharEntity(this: *mut core::ffi::c_void, wch: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteCharEntity", 9usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteCharEntity)(this, wch), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteChars(this: *mut core::ffi::c_void, pwch: windows_core::PCWSTR, cwch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteChars", 10usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteChars)(this, pwch, cwch), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteComment(this: *mut core::ffi::c_void, pwszcomment: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteComment", 11usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteComment)(this, pwszcomment), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteDocType(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, pwszpublicid: windows_core::PCWSTR, pwszsystemid: windows_core::PCWSTR, pwszsubset: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteDocType", 12usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteDocType)(this, pwszname, pwszpublicid, pwszsystemid, pwszsubset), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteElementString(this: *mut core::ffi::c_void, pwszqname: windows_core::PCWSTR, cwszqname: u32, pwszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteElementString", 13usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteElementString)(this, pwszqname, cwszqname, pwszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteEndDocument(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteEndDocument", 14usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteEndDocument)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteEndElement(this: *mut core::ffi::c_void, pwszqname: windows_core::PCWSTR, cwszqname: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteEndElement", 15usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteEndElement)(this, pwszqname, cwszqname), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteEntityRef(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteEntityRef", 16usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteEntityRef)(this, pwszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteFullEndElement(this: *mut core::ffi::c_void, pwszqname: windows_core::PCWSTR, cwszqname: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteFullEndElement", 17usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteFullEndElement)(this, pwszqname, cwszqname), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteName(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteName", 18usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteName)(this, pwszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteNmToken(this: *mut core::ffi::c_void, pwsznmtoken: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteNmToken", 19usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteNmToken)(this, pwsznmtoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteNode(this: *mut core::ffi::c_void, preader: *mut core::ffi::c_void, fwritedefaultattributes: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteNode", 20usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteNode)(this, preader, fwritedefaultattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteNodeShallow(this: *mut core::ffi::c_void, preader: *mut core::ffi::c_void, fwritedefaultattributes: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteNodeShallow", 21usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteNodeShallow)(this, preader, fwritedefaultattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteProcessingInstruction(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, pwsztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteProcessingInstruction", 22usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteProcessingInstruction)(this, pwszname, pwsztext), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteRaw(this: *mut core::ffi::c_void, pwszdata: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteRaw", 23usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteRaw)(this, pwszdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteRawChars(this: *mut core::ffi::c_void, pwch: windows_core::PCWSTR, cwch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteRawChars", 24usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteRawChars)(this, pwch, cwch), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteStartDocument(this: *mut core::ffi::c_void, standalone: XmlStandalone) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteStartDocument", 25usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteStartDocument)(this, standalone), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteStartElement(this: *mut core::ffi::c_void, pwszqname: windows_core::PCWSTR, cwszqname: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteStartElement", 26usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteStartElement)(this, pwszqname, cwszqname), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteString(this: *mut core::ffi::c_void, pwsztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteString", 27usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteString)(this, pwsztext), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteSurrogateCharEntity(this: *mut core::ffi::c_void, wchlow: u16, wchhigh: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteSurrogateCharEntity", 28usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteSurrogateCharEntity)(this, wchlow, wchhigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteWhitespace(this: *mut core::ffi::c_void, pwszwhitespace: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "WriteWhitespace", 29usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).WriteWhitespace)(this, pwszwhitespace), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXmlWriterLite", "Flush", 30usize, |this| ((**(this as *const *const IXmlWriterLite_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetOutput,
+            GetProperty,
+            SetProperty,
+            WriteAttributes,
+            WriteAttributeString,
+            WriteCData,
+            WriteCharEntity,
+            WriteChars,
+            WriteComment,
+            WriteDocType,
+            WriteElementString,
+            WriteEndDocument,
+            WriteEndElement,
+            WriteEntityRef,
+            WriteFullEndElement,
+            WriteName,
+            WriteNmToken,
+            WriteNode,
+            WriteNodeShallow,
+            WriteProcessingInstruction,
+            WriteRaw,
+            WriteRawChars,
+            WriteStartDocument,
+            WriteStartElement,
+            WriteString,
+            WriteSurrogateCharEntity,
+            WriteWhitespace,
+            Flush,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXmlWriterLite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
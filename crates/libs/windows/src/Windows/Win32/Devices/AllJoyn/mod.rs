@@ -5040,6 +5040,7 @@ pub struct QStatus(pub i32);
 impl windows_core::TypeKind for QStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QStatus").field(&self.0).finish()
@@ -5051,6 +5052,7 @@ pub struct alljoyn_about_announceflag(pub i32);
 impl windows_core::TypeKind for alljoyn_about_announceflag {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for alljoyn_about_announceflag {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("alljoyn_about_announceflag").field(&self.0).finish()
@@ -5062,6 +5064,7 @@ pub struct alljoyn_applicationstate(pub i32);
 impl windows_core::TypeKind for alljoyn_applicationstate {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for alljoyn_applicationstate {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("alljoyn_applicationstate").field(&self.0).finish()
@@ -5073,6 +5076,7 @@ pub struct alljoyn_claimcapability_masks(pub i32);
 impl windows_core::TypeKind for alljoyn_claimcapability_masks {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for alljoyn_claimcapability_masks {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("alljoyn_claimcapability_masks").field(&self.0).finish()
@@ -5084,6 +5088,7 @@ pub struct alljoyn_claimcapabilityadditionalinfo_masks(pub i32);
 impl windows_core::TypeKind for alljoyn_claimcapabilityadditionalinfo_masks {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for alljoyn_claimcapabilityadditionalinfo_masks {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("alljoyn_claimcapabilityadditionalinfo_masks").field(&self.0).finish()
@@ -5095,6 +5100,7 @@ pub struct alljoyn_interfacedescription_securitypolicy(pub i32);
 impl windows_core::TypeKind for alljoyn_interfacedescription_securitypolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for alljoyn_interfacedescription_securitypolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("alljoyn_interfacedescription_securitypolicy").field(&self.0).finish()
@@ -5106,6 +5112,7 @@ pub struct alljoyn_messagetype(pub i32);
 impl windows_core::TypeKind for alljoyn_messagetype {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for alljoyn_messagetype {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("alljoyn_messagetype").field(&self.0).finish()
@@ -5117,6 +5124,7 @@ pub struct alljoyn_sessionlostreason(pub i32);
 impl windows_core::TypeKind for alljoyn_sessionlostreason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for alljoyn_sessionlostreason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("alljoyn_sessionlostreason").field(&self.0).finish()
@@ -5128,13 +5136,15 @@ pub struct alljoyn_typeid(pub i32);
 impl windows_core::TypeKind for alljoyn_typeid {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for alljoyn_typeid {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("alljoyn_typeid").field(&self.0).finish()
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_aboutdata(pub isize);
 impl Default for alljoyn_aboutdata {
     fn default() -> Self {
@@ -5145,7 +5155,8 @@ impl windows_core::TypeKind for alljoyn_aboutdata {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_aboutdatalistener(pub isize);
 impl Default for alljoyn_aboutdatalistener {
     fn default() -> Self {
@@ -5156,7 +5167,8 @@ impl windows_core::TypeKind for alljoyn_aboutdatalistener {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_aboutdatalistener_callbacks {
     pub about_datalistener_getaboutdata: alljoyn_aboutdatalistener_getaboutdata_ptr,
     pub about_datalistener_getannouncedaboutdata: alljoyn_aboutdatalistener_getannouncedaboutdata_ptr,
@@ -5170,7 +5182,8 @@ impl Default for alljoyn_aboutdatalistener_callbacks {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_abouticon(pub isize);
 impl Default for alljoyn_abouticon {
     fn default() -> Self {
@@ -5181,7 +5194,8 @@ impl windows_core::TypeKind for alljoyn_abouticon {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_abouticonobj(pub isize);
 impl Default for alljoyn_abouticonobj {
     fn default() -> Self {
@@ -5192,7 +5206,8 @@ impl windows_core::TypeKind for alljoyn_abouticonobj {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_abouticonproxy(pub isize);
 impl Default for alljoyn_abouticonproxy {
     fn default() -> Self {
@@ -5203,7 +5218,8 @@ impl windows_core::TypeKind for alljoyn_abouticonproxy {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_aboutlistener(pub isize);
 impl Default for alljoyn_aboutlistener {
     fn default() -> Self {
@@ -5214,7 +5230,8 @@ impl windows_core::TypeKind for alljoyn_aboutlistener {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_aboutlistener_callback {
     pub about_listener_announced: alljoyn_about_announced_ptr,
 }
@@ -5227,7 +5244,8 @@ impl Default for alljoyn_aboutlistener_callback {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_aboutobj(pub isize);
 impl Default for alljoyn_aboutobj {
     fn default() -> Self {
@@ -5238,7 +5256,8 @@ impl windows_core::TypeKind for alljoyn_aboutobj {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_aboutobjectdescription(pub isize);
 impl Default for alljoyn_aboutobjectdescription {
     fn default() -> Self {
@@ -5249,7 +5268,8 @@ impl windows_core::TypeKind for alljoyn_aboutobjectdescription {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_aboutproxy(pub isize);
 impl Default for alljoyn_aboutproxy {
     fn default() -> Self {
@@ -5260,7 +5280,8 @@ impl windows_core::TypeKind for alljoyn_aboutproxy {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_applicationstatelistener(pub isize);
 impl Default for alljoyn_applicationstatelistener {
     fn default() -> Self {
@@ -5271,7 +5292,8 @@ impl windows_core::TypeKind for alljoyn_applicationstatelistener {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_applicationstatelistener_callbacks {
     pub state: alljoyn_applicationstatelistener_state_ptr,
 }
@@ -5284,7 +5306,8 @@ impl Default for alljoyn_applicationstatelistener_callbacks {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_authlistener(pub isize);
 impl Default for alljoyn_authlistener {
     fn default() -> Self {
@@ -5295,7 +5318,8 @@ impl windows_core::TypeKind for alljoyn_authlistener {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_authlistener_callbacks {
     pub request_credentials: alljoyn_authlistener_requestcredentials_ptr,
     pub verify_credentials: alljoyn_authlistener_verifycredentials_ptr,
@@ -5311,7 +5335,8 @@ impl Default for alljoyn_authlistener_callbacks {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_authlistenerasync_callbacks {
     pub request_credentials: alljoyn_authlistener_requestcredentialsasync_ptr,
     pub verify_credentials: alljoyn_authlistener_verifycredentialsasync_ptr,
@@ -5327,7 +5352,8 @@ impl Default for alljoyn_authlistenerasync_callbacks {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_autopinger(pub isize);
 impl Default for alljoyn_autopinger {
     fn default() -> Self {
@@ -5338,7 +5364,8 @@ impl windows_core::TypeKind for alljoyn_autopinger {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_busattachment(pub isize);
 impl Default for alljoyn_busattachment {
     fn default() -> Self {
@@ -5349,7 +5376,8 @@ impl windows_core::TypeKind for alljoyn_busattachment {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_buslistener(pub isize);
 impl Default for alljoyn_buslistener {
     fn default() -> Self {
@@ -5360,7 +5388,8 @@ impl windows_core::TypeKind for alljoyn_buslistener {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_buslistener_callbacks {
     pub listener_registered: alljoyn_buslistener_listener_registered_ptr,
     pub listener_unregistered: alljoyn_buslistener_listener_unregistered_ptr,
@@ -5380,7 +5409,8 @@ impl Default for alljoyn_buslistener_callbacks {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_busobject(pub isize);
 impl Default for alljoyn_busobject {
     fn default() -> Self {
@@ -5391,7 +5421,8 @@ impl windows_core::TypeKind for alljoyn_busobject {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_busobject_callbacks {
     pub property_get: alljoyn_busobject_prop_get_ptr,
     pub property_set: alljoyn_busobject_prop_set_ptr,
@@ -5407,7 +5438,8 @@ impl Default for alljoyn_busobject_callbacks {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_busobject_methodentry {
     pub member: *const alljoyn_interfacedescription_member,
     pub method_handler: alljoyn_messagereceiver_methodhandler_ptr,
@@ -5421,7 +5453,8 @@ impl Default for alljoyn_busobject_methodentry {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_certificateid {
     pub serial: *mut u8,
     pub serialLen: usize,
@@ -5438,7 +5471,8 @@ impl Default for alljoyn_certificateid {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_certificateidarray {
     pub count: usize,
     pub ids: *mut alljoyn_certificateid,
@@ -5452,7 +5486,8 @@ impl Default for alljoyn_certificateidarray {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_credentials(pub isize);
 impl Default for alljoyn_credentials {
     fn default() -> Self {
@@ -5463,7 +5498,8 @@ impl windows_core::TypeKind for alljoyn_credentials {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_interfacedescription(pub isize);
 impl Default for alljoyn_interfacedescription {
     fn default() -> Self {
@@ -5474,7 +5510,8 @@ impl windows_core::TypeKind for alljoyn_interfacedescription {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_interfacedescription_member {
     pub iface: alljoyn_interfacedescription,
     pub memberType: alljoyn_messagetype,
@@ -5493,7 +5530,8 @@ impl Default for alljoyn_interfacedescription_member {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_interfacedescription_property {
     pub name: windows_core::PCSTR,
     pub signature: windows_core::PCSTR,
@@ -5509,7 +5547,8 @@ impl Default for alljoyn_interfacedescription_property {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_keystore(pub isize);
 impl Default for alljoyn_keystore {
     fn default() -> Self {
@@ -5520,7 +5559,8 @@ impl windows_core::TypeKind for alljoyn_keystore {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_keystorelistener(pub isize);
 impl Default for alljoyn_keystorelistener {
     fn default() -> Self {
@@ -5531,7 +5571,8 @@ impl windows_core::TypeKind for alljoyn_keystorelistener {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_keystorelistener_callbacks {
     pub load_request: alljoyn_keystorelistener_loadrequest_ptr,
     pub store_request: alljoyn_keystorelistener_storerequest_ptr,
@@ -5545,7 +5586,8 @@ impl Default for alljoyn_keystorelistener_callbacks {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_keystorelistener_with_synchronization_callbacks {
     pub load_request: alljoyn_keystorelistener_loadrequest_ptr,
     pub store_request: alljoyn_keystorelistener_storerequest_ptr,
@@ -5561,7 +5603,8 @@ impl Default for alljoyn_keystorelistener_with_synchronization_callbacks {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_manifestarray {
     pub count: usize,
     pub xmls: *mut *mut i8,
@@ -5575,7 +5618,8 @@ impl Default for alljoyn_manifestarray {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_message(pub isize);
 impl Default for alljoyn_message {
     fn default() -> Self {
@@ -5586,7 +5630,8 @@ impl windows_core::TypeKind for alljoyn_message {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_msgarg(pub isize);
 impl Default for alljoyn_msgarg {
     fn default() -> Self {
@@ -5597,7 +5642,8 @@ impl windows_core::TypeKind for alljoyn_msgarg {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_observer(pub isize);
 impl Default for alljoyn_observer {
     fn default() -> Self {
@@ -5608,7 +5654,8 @@ impl windows_core::TypeKind for alljoyn_observer {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_observerlistener(pub isize);
 impl Default for alljoyn_observerlistener {
     fn default() -> Self {
@@ -5619,7 +5666,8 @@ impl windows_core::TypeKind for alljoyn_observerlistener {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_observerlistener_callback {
     pub object_discovered: alljoyn_observer_object_discovered_ptr,
     pub object_lost: alljoyn_observer_object_lost_ptr,
@@ -5633,7 +5681,8 @@ impl Default for alljoyn_observerlistener_callback {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_permissionconfigurationlistener(pub isize);
 impl Default for alljoyn_permissionconfigurationlistener {
     fn default() -> Self {
@@ -5644,7 +5693,8 @@ impl windows_core::TypeKind for alljoyn_permissionconfigurationlistener {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_permissionconfigurationlistener_callbacks {
     pub factory_reset: alljoyn_permissionconfigurationlistener_factoryreset_ptr,
     pub policy_changed: alljoyn_permissionconfigurationlistener_policychanged_ptr,
@@ -5660,7 +5710,8 @@ impl Default for alljoyn_permissionconfigurationlistener_callbacks {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_permissionconfigurator(pub isize);
 impl Default for alljoyn_permissionconfigurator {
     fn default() -> Self {
@@ -5671,7 +5722,8 @@ impl windows_core::TypeKind for alljoyn_permissionconfigurator {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_pinglistener(pub isize);
 impl Default for alljoyn_pinglistener {
     fn default() -> Self {
@@ -5682,7 +5734,8 @@ impl windows_core::TypeKind for alljoyn_pinglistener {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_pinglistener_callback {
     pub destination_found: alljoyn_autopinger_destination_found_ptr,
     pub destination_lost: alljoyn_autopinger_destination_lost_ptr,
@@ -5696,7 +5749,8 @@ impl Default for alljoyn_pinglistener_callback {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_proxybusobject(pub isize);
 impl Default for alljoyn_proxybusobject {
     fn default() -> Self {
@@ -5707,7 +5761,8 @@ impl windows_core::TypeKind for alljoyn_proxybusobject {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_proxybusobject_ref(pub isize);
 impl Default for alljoyn_proxybusobject_ref {
     fn default() -> Self {
@@ -5718,7 +5773,8 @@ impl windows_core::TypeKind for alljoyn_proxybusobject_ref {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_securityapplicationproxy(pub isize);
 impl Default for alljoyn_securityapplicationproxy {
     fn default() -> Self {
@@ -5729,7 +5785,8 @@ impl windows_core::TypeKind for alljoyn_securityapplicationproxy {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_sessionlistener(pub isize);
 impl Default for alljoyn_sessionlistener {
     fn default() -> Self {
@@ -5740,7 +5797,8 @@ impl windows_core::TypeKind for alljoyn_sessionlistener {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_sessionlistener_callbacks {
     pub session_lost: alljoyn_sessionlistener_sessionlost_ptr,
     pub session_member_added: alljoyn_sessionlistener_sessionmemberadded_ptr,
@@ -5755,7 +5813,8 @@ impl Default for alljoyn_sessionlistener_callbacks {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_sessionopts(pub isize);
 impl Default for alljoyn_sessionopts {
     fn default() -> Self {
@@ -5766,7 +5825,8 @@ impl windows_core::TypeKind for alljoyn_sessionopts {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_sessionportlistener(pub isize);
 impl Default for alljoyn_sessionportlistener {
     fn default() -> Self {
@@ -5777,7 +5837,8 @@ impl windows_core::TypeKind for alljoyn_sessionportlistener {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct alljoyn_sessionportlistener_callbacks {
     pub accept_session_joiner: alljoyn_sessionportlistener_acceptsessionjoiner_ptr,
     pub session_joined: alljoyn_sessionportlistener_sessionjoined_ptr,
@@ -1232,6 +1232,7 @@ pub struct AUTHENTICATION_REQUIREMENTS(pub i32);
 impl windows_core::TypeKind for AUTHENTICATION_REQUIREMENTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AUTHENTICATION_REQUIREMENTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AUTHENTICATION_REQUIREMENTS").field(&self.0).finish()
@@ -1243,6 +1244,7 @@ pub struct BLUETOOTH_AUTHENTICATION_METHOD(pub i32);
 impl windows_core::TypeKind for BLUETOOTH_AUTHENTICATION_METHOD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BLUETOOTH_AUTHENTICATION_METHOD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BLUETOOTH_AUTHENTICATION_METHOD").field(&self.0).finish()
@@ -1254,6 +1256,7 @@ pub struct BLUETOOTH_AUTHENTICATION_REQUIREMENTS(pub i32);
 impl windows_core::TypeKind for BLUETOOTH_AUTHENTICATION_REQUIREMENTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BLUETOOTH_AUTHENTICATION_REQUIREMENTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BLUETOOTH_AUTHENTICATION_REQUIREMENTS").field(&self.0).finish()
@@ -1265,6 +1268,7 @@ pub struct BLUETOOTH_IO_CAPABILITY(pub i32);
 impl windows_core::TypeKind for BLUETOOTH_IO_CAPABILITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BLUETOOTH_IO_CAPABILITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BLUETOOTH_IO_CAPABILITY").field(&self.0).finish()
@@ -1276,6 +1280,7 @@ pub struct BTH_LE_GATT_DESCRIPTOR_TYPE(pub i32);
 impl windows_core::TypeKind for BTH_LE_GATT_DESCRIPTOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BTH_LE_GATT_DESCRIPTOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BTH_LE_GATT_DESCRIPTOR_TYPE").field(&self.0).finish()
@@ -1287,6 +1292,7 @@ pub struct BTH_LE_GATT_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for BTH_LE_GATT_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BTH_LE_GATT_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BTH_LE_GATT_EVENT_TYPE").field(&self.0).finish()
@@ -1298,6 +1304,7 @@ pub struct IO_CAPABILITY(pub i32);
 impl windows_core::TypeKind for IO_CAPABILITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IO_CAPABILITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IO_CAPABILITY").field(&self.0).finish()
@@ -1309,6 +1316,7 @@ pub struct NodeContainerType(pub i32);
 impl windows_core::TypeKind for NodeContainerType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NodeContainerType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NodeContainerType").field(&self.0).finish()
@@ -1320,6 +1328,7 @@ pub struct SDP_SPECIFICTYPE(pub i32);
 impl windows_core::TypeKind for SDP_SPECIFICTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SDP_SPECIFICTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SDP_SPECIFICTYPE").field(&self.0).finish()
@@ -1331,6 +1340,7 @@ pub struct SDP_TYPE(pub i32);
 impl windows_core::TypeKind for SDP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SDP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SDP_TYPE").field(&self.0).finish()
@@ -1427,7 +1437,8 @@ impl Default for BLUETOOTH_AUTHENTICATION_CALLBACK_PARAMS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BLUETOOTH_COD_PAIRS {
     pub ulCODMask: u32,
     pub pcszDescription: windows_core::PCWSTR,
@@ -1462,7 +1473,8 @@ impl Default for BLUETOOTH_DEVICE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BLUETOOTH_DEVICE_SEARCH_PARAMS {
     pub dwSize: u32,
     pub fReturnAuthenticated: super::super::Foundation::BOOL,
@@ -1482,7 +1494,8 @@ impl Default for BLUETOOTH_DEVICE_SEARCH_PARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BLUETOOTH_FIND_RADIO_PARAMS {
     pub dwSize: u32,
 }
@@ -1495,7 +1508,8 @@ impl Default for BLUETOOTH_FIND_RADIO_PARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BLUETOOTH_GATT_VALUE_CHANGED_EVENT {
     pub ChangedAttributeHandle: u16,
     pub CharacteristicValueDataSize: usize,
@@ -1540,7 +1554,8 @@ impl Default for BLUETOOTH_LOCAL_SERVICE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BLUETOOTH_NUMERIC_COMPARISON_INFO {
     pub NumericValue: u32,
 }
@@ -1553,7 +1568,8 @@ impl Default for BLUETOOTH_NUMERIC_COMPARISON_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BLUETOOTH_OOB_DATA_INFO {
     pub C: [u8; 16],
     pub R: [u8; 16],
@@ -1567,7 +1583,8 @@ impl Default for BLUETOOTH_OOB_DATA_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BLUETOOTH_PASSKEY_INFO {
     pub passkey: u32,
 }
@@ -1580,7 +1597,8 @@ impl Default for BLUETOOTH_PASSKEY_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BLUETOOTH_PIN_INFO {
     pub pin: [u8; 16],
     pub pinLength: u8,
@@ -1612,7 +1630,8 @@ impl Default for BLUETOOTH_RADIO_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BLUETOOTH_SELECT_DEVICE_PARAMS {
     pub dwSize: u32,
     pub cNumOfClasses: u32,
@@ -1639,7 +1658,8 @@ impl Default for BLUETOOTH_SELECT_DEVICE_PARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BTH_DEVICE_INFO {
     pub flags: u32,
     pub address: u64,
@@ -1655,7 +1675,8 @@ impl Default for BTH_DEVICE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BTH_HCI_EVENT_INFO {
     pub bthAddress: u64,
     pub connectionType: u8,
@@ -1713,7 +1734,8 @@ impl Default for BTH_INFO_RSP_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BTH_L2CAP_EVENT_INFO {
     pub bthAddress: u64,
     pub psm: u16,
@@ -1753,7 +1775,8 @@ impl Default for BTH_LE_GATT_CHARACTERISTIC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BTH_LE_GATT_CHARACTERISTIC_VALUE {
     pub DataSize: u32,
     pub Data: [u8; 1],
@@ -1817,7 +1840,8 @@ impl Default for BTH_LE_GATT_DESCRIPTOR_VALUE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BTH_LE_GATT_DESCRIPTOR_VALUE_0_0 {
     pub IsReliableWriteEnabled: super::super::Foundation::BOOLEAN,
     pub IsAuxiliariesWritable: super::super::Foundation::BOOLEAN,
@@ -1848,7 +1872,8 @@ impl Default for BTH_LE_GATT_DESCRIPTOR_VALUE_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BTH_LE_GATT_DESCRIPTOR_VALUE_0_2 {
     pub IsSubscribeToNotification: super::super::Foundation::BOOLEAN,
     pub IsSubscribeToIndication: super::super::Foundation::BOOLEAN,
@@ -1862,7 +1887,8 @@ impl Default for BTH_LE_GATT_DESCRIPTOR_VALUE_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BTH_LE_GATT_DESCRIPTOR_VALUE_0_3 {
     pub IsBroadcast: super::super::Foundation::BOOLEAN,
 }
@@ -1932,7 +1958,8 @@ impl Default for BTH_PING_REQ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BTH_PING_RSP {
     pub dataLen: u8,
     pub data: [u8; 44],
@@ -1977,7 +2004,8 @@ impl Default for BTH_QUERY_SERVICE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BTH_RADIO_IN_RANGE {
     pub deviceInfo: BTH_DEVICE_INFO,
     pub previousDeviceFlags: u32,
@@ -2009,7 +2037,8 @@ impl Default for BTH_SET_SERVICE {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HANDLE_SDP_TYPE(pub u64);
 impl HANDLE_SDP_TYPE {
     pub fn is_invalid(&self) -> bool {
@@ -2025,7 +2054,8 @@ impl windows_core::TypeKind for HANDLE_SDP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HBLUETOOTH_DEVICE_FIND(pub *mut core::ffi::c_void);
 impl HBLUETOOTH_DEVICE_FIND {
     pub fn is_invalid(&self) -> bool {
@@ -2049,7 +2079,8 @@ impl windows_core::TypeKind for HBLUETOOTH_DEVICE_FIND {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HBLUETOOTH_RADIO_FIND(pub *mut core::ffi::c_void);
 impl HBLUETOOTH_RADIO_FIND {
     pub fn is_invalid(&self) -> bool {
@@ -2102,7 +2133,8 @@ impl Default for RFCOMM_COMMAND_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RFCOMM_MSC_DATA {
     pub Signals: u8,
     pub Break: u8,
@@ -2116,7 +2148,8 @@ impl Default for RFCOMM_MSC_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RFCOMM_RLS_DATA {
     pub LineStatus: u8,
 }
@@ -2129,7 +2162,8 @@ impl Default for RFCOMM_RLS_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RFCOMM_RPN_DATA {
     pub Baud: u8,
     pub Data: u8,
@@ -2193,7 +2227,8 @@ impl Default for SDP_ELEMENT_DATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SDP_ELEMENT_DATA_0_0 {
     pub value: *mut u8,
     pub length: u32,
@@ -2207,7 +2242,8 @@ impl Default for SDP_ELEMENT_DATA_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SDP_ELEMENT_DATA_0_1 {
     pub value: *mut u8,
     pub length: u32,
@@ -2221,7 +2257,8 @@ impl Default for SDP_ELEMENT_DATA_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SDP_ELEMENT_DATA_0_2 {
     pub value: *mut u8,
     pub length: u32,
@@ -2235,7 +2272,8 @@ impl Default for SDP_ELEMENT_DATA_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SDP_ELEMENT_DATA_0_3 {
     pub value: *mut u8,
     pub length: u32,
@@ -2249,7 +2287,8 @@ impl Default for SDP_ELEMENT_DATA_0_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SDP_LARGE_INTEGER_16 {
     pub LowPart: u64,
     pub HighPart: i64,
@@ -2263,7 +2302,8 @@ impl Default for SDP_LARGE_INTEGER_16 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SDP_STRING_TYPE_DATA {
     pub encoding: u16,
     pub mibeNum: u16,
@@ -2278,7 +2318,8 @@ impl Default for SDP_STRING_TYPE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SDP_ULARGE_INTEGER_16 {
     pub LowPart: u64,
     pub HighPart: u64,
@@ -2308,7 +2349,8 @@ impl Default for SOCKADDR_BTH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SdpAttributeRange {
     pub minAttribute: u16,
     pub maxAttribute: u16,
@@ -400,6 +400,7 @@ pub struct CLEAR_COMM_ERROR_FLAGS(pub u32);
 impl windows_core::TypeKind for CLEAR_COMM_ERROR_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLEAR_COMM_ERROR_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLEAR_COMM_ERROR_FLAGS").field(&self.0).finish()
@@ -444,6 +445,7 @@ pub struct COMMPROP_STOP_PARITY(pub u16);
 impl windows_core::TypeKind for COMMPROP_STOP_PARITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMMPROP_STOP_PARITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMMPROP_STOP_PARITY").field(&self.0).finish()
@@ -488,6 +490,7 @@ pub struct COMM_EVENT_MASK(pub u32);
 impl windows_core::TypeKind for COMM_EVENT_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMM_EVENT_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMM_EVENT_MASK").field(&self.0).finish()
@@ -532,6 +535,7 @@ pub struct DCB_PARITY(pub u8);
 impl windows_core::TypeKind for DCB_PARITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DCB_PARITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DCB_PARITY").field(&self.0).finish()
@@ -543,6 +547,7 @@ pub struct DCB_STOP_BITS(pub u8);
 impl windows_core::TypeKind for DCB_STOP_BITS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DCB_STOP_BITS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DCB_STOP_BITS").field(&self.0).finish()
@@ -554,6 +559,7 @@ pub struct ESCAPE_COMM_FUNCTION(pub u32);
 impl windows_core::TypeKind for ESCAPE_COMM_FUNCTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ESCAPE_COMM_FUNCTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ESCAPE_COMM_FUNCTION").field(&self.0).finish()
@@ -565,6 +571,7 @@ pub struct MODEMDEVCAPS_DIAL_OPTIONS(pub u32);
 impl windows_core::TypeKind for MODEMDEVCAPS_DIAL_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MODEMDEVCAPS_DIAL_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MODEMDEVCAPS_DIAL_OPTIONS").field(&self.0).finish()
@@ -609,6 +616,7 @@ pub struct MODEMDEVCAPS_SPEAKER_MODE(pub u32);
 impl windows_core::TypeKind for MODEMDEVCAPS_SPEAKER_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MODEMDEVCAPS_SPEAKER_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MODEMDEVCAPS_SPEAKER_MODE").field(&self.0).finish()
@@ -653,6 +661,7 @@ pub struct MODEMDEVCAPS_SPEAKER_VOLUME(pub u32);
 impl windows_core::TypeKind for MODEMDEVCAPS_SPEAKER_VOLUME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MODEMDEVCAPS_SPEAKER_VOLUME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MODEMDEVCAPS_SPEAKER_VOLUME").field(&self.0).finish()
@@ -697,6 +706,7 @@ pub struct MODEMSETTINGS_SPEAKER_MODE(pub u32);
 impl windows_core::TypeKind for MODEMSETTINGS_SPEAKER_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MODEMSETTINGS_SPEAKER_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MODEMSETTINGS_SPEAKER_MODE").field(&self.0).finish()
@@ -708,6 +718,7 @@ pub struct MODEM_SPEAKER_VOLUME(pub u32);
 impl windows_core::TypeKind for MODEM_SPEAKER_VOLUME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MODEM_SPEAKER_VOLUME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MODEM_SPEAKER_VOLUME").field(&self.0).finish()
@@ -719,6 +730,7 @@ pub struct MODEM_STATUS_FLAGS(pub u32);
 impl windows_core::TypeKind for MODEM_STATUS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MODEM_STATUS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MODEM_STATUS_FLAGS").field(&self.0).finish()
@@ -763,6 +775,7 @@ pub struct PURGE_COMM_FLAGS(pub u32);
 impl windows_core::TypeKind for PURGE_COMM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PURGE_COMM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PURGE_COMM_FLAGS").field(&self.0).finish()
@@ -802,7 +815,8 @@ impl core::ops::Not for PURGE_COMM_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMMCONFIG {
     pub dwSize: u32,
     pub wVersion: u16,
@@ -822,7 +836,8 @@ impl Default for COMMCONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMMPROP {
     pub wPacketLength: u16,
     pub wPacketVersion: u16,
@@ -852,7 +867,8 @@ impl Default for COMMPROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMMTIMEOUTS {
     pub ReadIntervalTimeout: u32,
     pub ReadTotalTimeoutMultiplier: u32,
@@ -869,12 +885,63 @@ impl Default for COMMTIMEOUTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMSTAT {
     pub _bitfield: u32,
     pub cbInQue: u32,
     pub cbOutQue: u32,
 }
+impl COMSTAT {
+    pub const fn fCtsHold(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_fCtsHold(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn fDsrHold(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_fDsrHold(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn fRlsdHold(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_fRlsdHold(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn fXoffHold(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_fXoffHold(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn fXoffSent(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_fXoffSent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn fEof(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_fEof(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn fTxim(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_fTxim(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn fReserved(&self) -> u32 {
+        (self._bitfield >> 7) & 33554431
+    }
+    pub fn set_fReserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(33554431 << 7)) | ((value & 33554431) << 7);
+    }
+}
 impl windows_core::TypeKind for COMSTAT {
     type TypeKind = windows_core::CopyType;
 }
@@ -884,7 +951,8 @@ impl Default for COMSTAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DCB {
     pub DCBlength: u32,
     pub BaudRate: u32,
@@ -902,6 +970,92 @@ pub struct DCB {
     pub EvtChar: i8,
     pub wReserved1: u16,
 }
+impl DCB {
+    pub const fn fBinary(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_fBinary(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn fParity(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_fParity(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn fOutxCtsFlow(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_fOutxCtsFlow(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn fOutxDsrFlow(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_fOutxDsrFlow(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn fDtrControl(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_fDtrControl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn fDsrSensitivity(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_fDsrSensitivity(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn fTXContinueOnXoff(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_fTXContinueOnXoff(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn fOutX(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_fOutX(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn fInX(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_fInX(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn fErrorChar(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_fErrorChar(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn fNull(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_fNull(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn fRtsControl(&self) -> u32 {
+        (self._bitfield >> 12) & 3
+    }
+    pub fn set_fRtsControl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 12)) | ((value & 3) << 12);
+    }
+    pub const fn fAbortOnError(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_fAbortOnError(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn fDummy2(&self) -> u32 {
+        (self._bitfield >> 15) & 131071
+    }
+    pub fn set_fDummy2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(131071 << 15)) | ((value & 131071) << 15);
+    }
+}
 impl windows_core::TypeKind for DCB {
     type TypeKind = windows_core::CopyType;
 }
@@ -911,7 +1065,8 @@ impl Default for DCB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MODEMDEVCAPS {
     pub dwActualSize: u32,
     pub dwRequiredSize: u32,
@@ -943,7 +1098,8 @@ impl Default for MODEMDEVCAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MODEMSETTINGS {
     pub dwActualSize: u32,
     pub dwRequiredSize: u32,
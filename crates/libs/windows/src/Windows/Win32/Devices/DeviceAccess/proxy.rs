@@ -0,0 +1,51 @@
+impl windows_core::imp::ProxyVtable for ICreateDeviceAccessAsync_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateDeviceAccessAsync", "Cancel", 3usize, |this| ((**(this as *const *const ICreateDeviceAccessAsync_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wait(this: *mut core::ffi::c_void, timeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateDeviceAccessAsync", "Wait", 4usize, |this| ((**(this as *const *const ICreateDeviceAccessAsync_Vtbl)).Wait)(this, timeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateDeviceAccessAsync", "Close", 5usize, |this| ((**(this as *const *const ICreateDeviceAccessAsync_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResult(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, deviceaccess: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateDeviceAccessAsync", "GetResult", 6usize, |this| ((**(this as *const *const ICreateDeviceAccessAsync_Vtbl)).GetResult)(this, riid, deviceaccess), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cancel, Wait, Close, GetResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateDeviceAccessAsync as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceIoControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceIoControlSync(this: *mut core::ffi::c_void, iocontrolcode: u32, inputbuffer: *const u8, inputbuffersize: u32, outputbuffer: *mut u8, outputbuffersize: u32, bytesreturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceIoControl", "DeviceIoControlSync", 3usize, |this| ((**(this as *const *const IDeviceIoControl_Vtbl)).DeviceIoControlSync)(this, iocontrolcode, inputbuffer, inputbuffersize, outputbuffer, outputbuffersize, bytesreturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceIoControlAsync(this: *mut core::ffi::c_void, iocontrolcode: u32, inputbuffer: *const u8, inputbuffersize: u32, outputbuffer: *mut u8, outputbuffersize: u32, requestcompletioncallback: *mut core::ffi::c_void, cancelcontext: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceIoControl", "DeviceIoControlAsync", 4usize, |this| ((**(this as *const *const IDeviceIoControl_Vtbl)).DeviceIoControlAsync)(this, iocontrolcode, inputbuffer, inputbuffersize, outputbuffer, outputbuffersize, requestcompletioncallback, cancelcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelOperation(this: *mut core::ffi::c_void, cancelcontext: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceIoControl", "CancelOperation", 5usize, |this| ((**(this as *const *const IDeviceIoControl_Vtbl)).CancelOperation)(this, cancelcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceIoControlSync, DeviceIoControlAsync, CancelOperation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceIoControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceRequestCompletionCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, requestresult: windows_core::HRESULT, bytesreturned: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceRequestCompletionCallback", "Invoke", 3usize, |this| ((**(this as *const *const IDeviceRequestCompletionCallback_Vtbl)).Invoke)(this, requestresult, bytesreturned), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Invoke }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceRequestCompletionCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
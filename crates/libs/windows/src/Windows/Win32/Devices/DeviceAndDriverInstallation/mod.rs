@@ -5866,6 +5866,7 @@ pub struct CM_CDFLAGS(pub u32);
 impl windows_core::TypeKind for CM_CDFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_CDFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_CDFLAGS").field(&self.0).finish()
@@ -5910,6 +5911,7 @@ pub struct CM_CDMASK(pub u32);
 impl windows_core::TypeKind for CM_CDMASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_CDMASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_CDMASK").field(&self.0).finish()
@@ -5954,6 +5956,7 @@ pub struct CM_DEVCAP(pub u32);
 impl windows_core::TypeKind for CM_DEVCAP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_DEVCAP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_DEVCAP").field(&self.0).finish()
@@ -5998,6 +6001,7 @@ pub struct CM_DEVNODE_STATUS_FLAGS(pub u32);
 impl windows_core::TypeKind for CM_DEVNODE_STATUS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_DEVNODE_STATUS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_DEVNODE_STATUS_FLAGS").field(&self.0).finish()
@@ -6042,6 +6046,7 @@ pub struct CM_ENUMERATE_FLAGS(pub u32);
 impl windows_core::TypeKind for CM_ENUMERATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_ENUMERATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_ENUMERATE_FLAGS").field(&self.0).finish()
@@ -6086,6 +6091,7 @@ pub struct CM_GET_DEVICE_INTERFACE_LIST_FLAGS(pub u32);
 impl windows_core::TypeKind for CM_GET_DEVICE_INTERFACE_LIST_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_GET_DEVICE_INTERFACE_LIST_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_GET_DEVICE_INTERFACE_LIST_FLAGS").field(&self.0).finish()
@@ -6097,6 +6103,7 @@ pub struct CM_INSTALL_STATE(pub u32);
 impl windows_core::TypeKind for CM_INSTALL_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_INSTALL_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_INSTALL_STATE").field(&self.0).finish()
@@ -6108,6 +6115,7 @@ pub struct CM_LOCATE_DEVNODE_FLAGS(pub u32);
 impl windows_core::TypeKind for CM_LOCATE_DEVNODE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_LOCATE_DEVNODE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_LOCATE_DEVNODE_FLAGS").field(&self.0).finish()
@@ -6119,6 +6127,7 @@ pub struct CM_LOG_CONF(pub u32);
 impl windows_core::TypeKind for CM_LOG_CONF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_LOG_CONF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_LOG_CONF").field(&self.0).finish()
@@ -6130,6 +6139,7 @@ pub struct CM_NOTIFY_ACTION(pub i32);
 impl windows_core::TypeKind for CM_NOTIFY_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_NOTIFY_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_NOTIFY_ACTION").field(&self.0).finish()
@@ -6141,6 +6151,7 @@ pub struct CM_NOTIFY_FILTER_TYPE(pub i32);
 impl windows_core::TypeKind for CM_NOTIFY_FILTER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_NOTIFY_FILTER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_NOTIFY_FILTER_TYPE").field(&self.0).finish()
@@ -6152,6 +6163,7 @@ pub struct CM_PROB(pub u32);
 impl windows_core::TypeKind for CM_PROB {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_PROB {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_PROB").field(&self.0).finish()
@@ -6163,6 +6175,7 @@ pub struct CM_REENUMERATE_FLAGS(pub u32);
 impl windows_core::TypeKind for CM_REENUMERATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_REENUMERATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_REENUMERATE_FLAGS").field(&self.0).finish()
@@ -6207,6 +6220,7 @@ pub struct CM_REMOVAL_POLICY(pub u32);
 impl windows_core::TypeKind for CM_REMOVAL_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_REMOVAL_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_REMOVAL_POLICY").field(&self.0).finish()
@@ -6218,6 +6232,7 @@ pub struct CM_RESTYPE(pub u32);
 impl windows_core::TypeKind for CM_RESTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_RESTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_RESTYPE").field(&self.0).finish()
@@ -6229,6 +6244,7 @@ pub struct CONFIGRET(pub u32);
 impl windows_core::TypeKind for CONFIGRET {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONFIGRET {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONFIGRET").field(&self.0).finish()
@@ -6240,6 +6256,7 @@ pub struct DD_FLAGS(pub u32);
 impl windows_core::TypeKind for DD_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DD_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DD_FLAGS").field(&self.0).finish()
@@ -6284,6 +6301,7 @@ pub struct DIINSTALLDEVICE_FLAGS(pub u32);
 impl windows_core::TypeKind for DIINSTALLDEVICE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIINSTALLDEVICE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIINSTALLDEVICE_FLAGS").field(&self.0).finish()
@@ -6328,6 +6346,7 @@ pub struct DIINSTALLDRIVER_FLAGS(pub u32);
 impl windows_core::TypeKind for DIINSTALLDRIVER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIINSTALLDRIVER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIINSTALLDRIVER_FLAGS").field(&self.0).finish()
@@ -6372,6 +6391,7 @@ pub struct DIROLLBACKDRIVER_FLAGS(pub u32);
 impl windows_core::TypeKind for DIROLLBACKDRIVER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIROLLBACKDRIVER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIROLLBACKDRIVER_FLAGS").field(&self.0).finish()
@@ -6416,6 +6436,7 @@ pub struct DIUNINSTALLDRIVER_FLAGS(pub u32);
 impl windows_core::TypeKind for DIUNINSTALLDRIVER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIUNINSTALLDRIVER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIUNINSTALLDRIVER_FLAGS").field(&self.0).finish()
@@ -6460,6 +6481,7 @@ pub struct DI_FUNCTION(pub u32);
 impl windows_core::TypeKind for DI_FUNCTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DI_FUNCTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DI_FUNCTION").field(&self.0).finish()
@@ -6471,6 +6493,7 @@ pub struct FILE_COMPRESSION_TYPE(pub u32);
 impl windows_core::TypeKind for FILE_COMPRESSION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILE_COMPRESSION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILE_COMPRESSION_TYPE").field(&self.0).finish()
@@ -6515,6 +6538,7 @@ pub struct INF_STYLE(pub u32);
 impl windows_core::TypeKind for INF_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INF_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INF_STYLE").field(&self.0).finish()
@@ -6559,6 +6583,7 @@ pub struct IOD_DESFLAGS(pub u32);
 impl windows_core::TypeKind for IOD_DESFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IOD_DESFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IOD_DESFLAGS").field(&self.0).finish()
@@ -6603,6 +6628,7 @@ pub struct IRQD_FLAGS(pub u32);
 impl windows_core::TypeKind for IRQD_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IRQD_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IRQD_FLAGS").field(&self.0).finish()
@@ -6647,6 +6673,7 @@ pub struct MD_FLAGS(pub u32);
 impl windows_core::TypeKind for MD_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MD_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MD_FLAGS").field(&self.0).finish()
@@ -6691,6 +6718,7 @@ pub struct OEM_SOURCE_MEDIA_TYPE(pub u32);
 impl windows_core::TypeKind for OEM_SOURCE_MEDIA_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OEM_SOURCE_MEDIA_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OEM_SOURCE_MEDIA_TYPE").field(&self.0).finish()
@@ -6702,6 +6730,7 @@ pub struct PCD_FLAGS(pub u32);
 impl windows_core::TypeKind for PCD_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCD_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCD_FLAGS").field(&self.0).finish()
@@ -6746,6 +6775,7 @@ pub struct PMF_FLAGS(pub u32);
 impl windows_core::TypeKind for PMF_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PMF_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PMF_FLAGS").field(&self.0).finish()
@@ -6790,6 +6820,7 @@ pub struct PNP_VETO_TYPE(pub i32);
 impl windows_core::TypeKind for PNP_VETO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PNP_VETO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PNP_VETO_TYPE").field(&self.0).finish()
@@ -6801,6 +6832,7 @@ pub struct SETUPSCANFILEQUEUE_FLAGS(pub u32);
 impl windows_core::TypeKind for SETUPSCANFILEQUEUE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SETUPSCANFILEQUEUE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SETUPSCANFILEQUEUE_FLAGS").field(&self.0).finish()
@@ -6845,6 +6877,7 @@ pub struct SETUP_DI_DEVICE_CONFIGURATION_FLAGS(pub u32);
 impl windows_core::TypeKind for SETUP_DI_DEVICE_CONFIGURATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SETUP_DI_DEVICE_CONFIGURATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SETUP_DI_DEVICE_CONFIGURATION_FLAGS").field(&self.0).finish()
@@ -6889,6 +6922,7 @@ pub struct SETUP_DI_DEVICE_CREATION_FLAGS(pub u32);
 impl windows_core::TypeKind for SETUP_DI_DEVICE_CREATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SETUP_DI_DEVICE_CREATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SETUP_DI_DEVICE_CREATION_FLAGS").field(&self.0).finish()
@@ -6933,6 +6967,7 @@ pub struct SETUP_DI_DEVICE_INSTALL_FLAGS(pub u32);
 impl windows_core::TypeKind for SETUP_DI_DEVICE_INSTALL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SETUP_DI_DEVICE_INSTALL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SETUP_DI_DEVICE_INSTALL_FLAGS").field(&self.0).finish()
@@ -6977,6 +7012,7 @@ pub struct SETUP_DI_DEVICE_INSTALL_FLAGS_EX(pub u32);
 impl windows_core::TypeKind for SETUP_DI_DEVICE_INSTALL_FLAGS_EX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SETUP_DI_DEVICE_INSTALL_FLAGS_EX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SETUP_DI_DEVICE_INSTALL_FLAGS_EX").field(&self.0).finish()
@@ -7021,6 +7057,7 @@ pub struct SETUP_DI_DRIVER_INSTALL_FLAGS(pub u32);
 impl windows_core::TypeKind for SETUP_DI_DRIVER_INSTALL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SETUP_DI_DRIVER_INSTALL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SETUP_DI_DRIVER_INSTALL_FLAGS").field(&self.0).finish()
@@ -7065,6 +7102,7 @@ pub struct SETUP_DI_DRIVER_TYPE(pub u32);
 impl windows_core::TypeKind for SETUP_DI_DRIVER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SETUP_DI_DRIVER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SETUP_DI_DRIVER_TYPE").field(&self.0).finish()
@@ -7076,6 +7114,7 @@ pub struct SETUP_DI_GET_CLASS_DEVS_FLAGS(pub u32);
 impl windows_core::TypeKind for SETUP_DI_GET_CLASS_DEVS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SETUP_DI_GET_CLASS_DEVS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SETUP_DI_GET_CLASS_DEVS_FLAGS").field(&self.0).finish()
@@ -7120,6 +7159,7 @@ pub struct SETUP_DI_PROPERTY_CHANGE_SCOPE(pub u32);
 impl windows_core::TypeKind for SETUP_DI_PROPERTY_CHANGE_SCOPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SETUP_DI_PROPERTY_CHANGE_SCOPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SETUP_DI_PROPERTY_CHANGE_SCOPE").field(&self.0).finish()
@@ -7131,6 +7171,7 @@ pub struct SETUP_DI_REGISTRY_PROPERTY(pub u32);
 impl windows_core::TypeKind for SETUP_DI_REGISTRY_PROPERTY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SETUP_DI_REGISTRY_PROPERTY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SETUP_DI_REGISTRY_PROPERTY").field(&self.0).finish()
@@ -7142,6 +7183,7 @@ pub struct SETUP_DI_REMOVE_DEVICE_SCOPE(pub u32);
 impl windows_core::TypeKind for SETUP_DI_REMOVE_DEVICE_SCOPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SETUP_DI_REMOVE_DEVICE_SCOPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SETUP_DI_REMOVE_DEVICE_SCOPE").field(&self.0).finish()
@@ -7153,6 +7195,7 @@ pub struct SETUP_DI_STATE_CHANGE(pub u32);
 impl windows_core::TypeKind for SETUP_DI_STATE_CHANGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SETUP_DI_STATE_CHANGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SETUP_DI_STATE_CHANGE").field(&self.0).finish()
@@ -7164,6 +7207,7 @@ pub struct SETUP_FILE_OPERATION(pub u32);
 impl windows_core::TypeKind for SETUP_FILE_OPERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SETUP_FILE_OPERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SETUP_FILE_OPERATION").field(&self.0).finish()
@@ -7175,6 +7219,7 @@ pub struct SPSVCINST_FLAGS(pub u32);
 impl windows_core::TypeKind for SPSVCINST_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPSVCINST_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPSVCINST_FLAGS").field(&self.0).finish()
@@ -7219,6 +7264,7 @@ pub struct SP_COPY_STYLE(pub u32);
 impl windows_core::TypeKind for SP_COPY_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SP_COPY_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SP_COPY_STYLE").field(&self.0).finish()
@@ -7263,6 +7309,7 @@ pub struct SetupFileLogInfo(pub i32);
 impl windows_core::TypeKind for SetupFileLogInfo {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SetupFileLogInfo {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SetupFileLogInfo").field(&self.0).finish()
@@ -7274,6 +7321,7 @@ pub struct UPDATEDRIVERFORPLUGANDPLAYDEVICES_FLAGS(pub u32);
 impl windows_core::TypeKind for UPDATEDRIVERFORPLUGANDPLAYDEVICES_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UPDATEDRIVERFORPLUGANDPLAYDEVICES_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UPDATEDRIVERFORPLUGANDPLAYDEVICES_FLAGS").field(&self.0).finish()
@@ -7470,7 +7518,8 @@ impl Default for CM_NOTIFY_EVENT_DATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_NOTIFY_EVENT_DATA_0_0 {
     pub EventGuid: windows_core::GUID,
     pub NameOffset: i32,
@@ -7486,7 +7535,8 @@ impl Default for CM_NOTIFY_EVENT_DATA_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_NOTIFY_EVENT_DATA_0_1 {
     pub InstanceId: [u16; 1],
 }
@@ -7499,7 +7549,8 @@ impl Default for CM_NOTIFY_EVENT_DATA_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_NOTIFY_EVENT_DATA_0_2 {
     pub ClassGuid: windows_core::GUID,
     pub SymbolicLink: [u16; 1],
@@ -7545,7 +7596,8 @@ impl Default for CM_NOTIFY_FILTER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_NOTIFY_FILTER_0_0 {
     pub hTarget: super::super::Foundation::HANDLE,
 }
@@ -7558,7 +7610,8 @@ impl Default for CM_NOTIFY_FILTER_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_NOTIFY_FILTER_0_1 {
     pub InstanceId: [u16; 200],
 }
@@ -7571,7 +7624,8 @@ impl Default for CM_NOTIFY_FILTER_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_NOTIFY_FILTER_0_2 {
     pub ClassGuid: windows_core::GUID,
 }
@@ -7620,7 +7674,8 @@ impl Default for COINSTALLER_CONTEXT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONFLICT_DETAILS_A {
     pub CD_ulSize: u32,
     pub CD_ulMask: CM_CDMASK,
@@ -7638,7 +7693,8 @@ impl Default for CONFLICT_DETAILS_A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONFLICT_DETAILS_W {
     pub CD_ulSize: u32,
     pub CD_ulMask: CM_CDMASK,
@@ -8063,7 +8119,8 @@ impl Default for FILE_IN_CABINET_INFO_W {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCMNOTIFICATION(pub *mut core::ffi::c_void);
 impl HCMNOTIFICATION {
     pub fn is_invalid(&self) -> bool {
@@ -8087,7 +8144,8 @@ impl windows_core::TypeKind for HCMNOTIFICATION {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDEVINFO(pub isize);
 impl HDEVINFO {
     pub fn is_invalid(&self) -> bool {
@@ -261,6 +261,7 @@ pub struct DEVPROP_OPERATOR(pub u32);
 impl windows_core::TypeKind for DEVPROP_OPERATOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVPROP_OPERATOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVPROP_OPERATOR").field(&self.0).finish()
@@ -305,6 +306,7 @@ pub struct DEV_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for DEV_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEV_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEV_OBJECT_TYPE").field(&self.0).finish()
@@ -316,6 +318,7 @@ pub struct DEV_QUERY_FLAGS(pub i32);
 impl windows_core::TypeKind for DEV_QUERY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEV_QUERY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEV_QUERY_FLAGS").field(&self.0).finish()
@@ -327,6 +330,7 @@ pub struct DEV_QUERY_RESULT_ACTION(pub i32);
 impl windows_core::TypeKind for DEV_QUERY_RESULT_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEV_QUERY_RESULT_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEV_QUERY_RESULT_ACTION").field(&self.0).finish()
@@ -338,6 +342,7 @@ pub struct DEV_QUERY_STATE(pub i32);
 impl windows_core::TypeKind for DEV_QUERY_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEV_QUERY_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEV_QUERY_STATE").field(&self.0).finish()
@@ -345,7 +350,8 @@ impl core::fmt::Debug for DEV_QUERY_STATE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Devices_Properties")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVPROP_FILTER_EXPRESSION {
     pub Operator: DEVPROP_OPERATOR,
     pub Property: super::Properties::DEVPROPERTY,
@@ -362,7 +368,8 @@ impl Default for DEVPROP_FILTER_EXPRESSION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Devices_Properties")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEV_OBJECT {
     pub ObjectType: DEV_OBJECT_TYPE,
     pub pszObjectId: windows_core::PCWSTR,
@@ -381,7 +388,8 @@ impl Default for DEV_OBJECT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Devices_Properties")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEV_QUERY_PARAMETER {
     pub Key: super::Properties::DEVPROPKEY,
     pub Type: super::Properties::DEVPROPTYPE,
@@ -433,7 +441,8 @@ impl Default for DEV_QUERY_RESULT_ACTION_DATA_0 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDEVQUERY(pub *mut core::ffi::c_void);
 impl HDEVQUERY {
     pub fn is_invalid(&self) -> bool {
@@ -1812,6 +1812,7 @@ pub struct AR_STATE(pub i32);
 impl windows_core::TypeKind for AR_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AR_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AR_STATE").field(&self.0).finish()
@@ -1856,6 +1857,7 @@ pub struct BACKLIGHT_OPTIMIZATION_LEVEL(pub i32);
 impl windows_core::TypeKind for BACKLIGHT_OPTIMIZATION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BACKLIGHT_OPTIMIZATION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BACKLIGHT_OPTIMIZATION_LEVEL").field(&self.0).finish()
@@ -1867,6 +1869,7 @@ pub struct BRIGHTNESS_INTERFACE_VERSION(pub i32);
 impl windows_core::TypeKind for BRIGHTNESS_INTERFACE_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BRIGHTNESS_INTERFACE_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BRIGHTNESS_INTERFACE_VERSION").field(&self.0).finish()
@@ -1878,6 +1881,7 @@ pub struct BlackScreenDiagnosticsCalloutParam(pub i32);
 impl windows_core::TypeKind for BlackScreenDiagnosticsCalloutParam {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BlackScreenDiagnosticsCalloutParam {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BlackScreenDiagnosticsCalloutParam").field(&self.0).finish()
@@ -1889,6 +1893,7 @@ pub struct COLORSPACE_TRANSFORM_DATA_TYPE(pub i32);
 impl windows_core::TypeKind for COLORSPACE_TRANSFORM_DATA_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COLORSPACE_TRANSFORM_DATA_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COLORSPACE_TRANSFORM_DATA_TYPE").field(&self.0).finish()
@@ -1900,6 +1905,7 @@ pub struct COLORSPACE_TRANSFORM_STAGE_CONTROL(pub i32);
 impl windows_core::TypeKind for COLORSPACE_TRANSFORM_STAGE_CONTROL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COLORSPACE_TRANSFORM_STAGE_CONTROL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COLORSPACE_TRANSFORM_STAGE_CONTROL").field(&self.0).finish()
@@ -1911,6 +1917,7 @@ pub struct COLORSPACE_TRANSFORM_TARGET_CAPS_VERSION(pub i32);
 impl windows_core::TypeKind for COLORSPACE_TRANSFORM_TARGET_CAPS_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COLORSPACE_TRANSFORM_TARGET_CAPS_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COLORSPACE_TRANSFORM_TARGET_CAPS_VERSION").field(&self.0).finish()
@@ -1922,6 +1929,7 @@ pub struct COLORSPACE_TRANSFORM_TYPE(pub i32);
 impl windows_core::TypeKind for COLORSPACE_TRANSFORM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COLORSPACE_TRANSFORM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COLORSPACE_TRANSFORM_TYPE").field(&self.0).finish()
@@ -1933,6 +1941,7 @@ pub struct DISPLAYCONFIG_DEVICE_INFO_TYPE(pub i32);
 impl windows_core::TypeKind for DISPLAYCONFIG_DEVICE_INFO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPLAYCONFIG_DEVICE_INFO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPLAYCONFIG_DEVICE_INFO_TYPE").field(&self.0).finish()
@@ -1944,6 +1953,7 @@ pub struct DISPLAYCONFIG_MODE_INFO_TYPE(pub i32);
 impl windows_core::TypeKind for DISPLAYCONFIG_MODE_INFO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPLAYCONFIG_MODE_INFO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPLAYCONFIG_MODE_INFO_TYPE").field(&self.0).finish()
@@ -1955,6 +1965,7 @@ pub struct DISPLAYCONFIG_PIXELFORMAT(pub i32);
 impl windows_core::TypeKind for DISPLAYCONFIG_PIXELFORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPLAYCONFIG_PIXELFORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPLAYCONFIG_PIXELFORMAT").field(&self.0).finish()
@@ -1966,6 +1977,7 @@ pub struct DISPLAYCONFIG_ROTATION(pub i32);
 impl windows_core::TypeKind for DISPLAYCONFIG_ROTATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPLAYCONFIG_ROTATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPLAYCONFIG_ROTATION").field(&self.0).finish()
@@ -1977,6 +1989,7 @@ pub struct DISPLAYCONFIG_SCALING(pub i32);
 impl windows_core::TypeKind for DISPLAYCONFIG_SCALING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPLAYCONFIG_SCALING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPLAYCONFIG_SCALING").field(&self.0).finish()
@@ -1988,6 +2001,7 @@ pub struct DISPLAYCONFIG_SCANLINE_ORDERING(pub i32);
 impl windows_core::TypeKind for DISPLAYCONFIG_SCANLINE_ORDERING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPLAYCONFIG_SCANLINE_ORDERING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPLAYCONFIG_SCANLINE_ORDERING").field(&self.0).finish()
@@ -1999,6 +2013,7 @@ pub struct DISPLAYCONFIG_TOPOLOGY_ID(pub i32);
 impl windows_core::TypeKind for DISPLAYCONFIG_TOPOLOGY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPLAYCONFIG_TOPOLOGY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPLAYCONFIG_TOPOLOGY_ID").field(&self.0).finish()
@@ -2010,6 +2025,7 @@ pub struct DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY(pub i32);
 impl windows_core::TypeKind for DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY").field(&self.0).finish()
@@ -2021,6 +2037,7 @@ pub struct DSI_CONTROL_TRANSMISSION_MODE(pub i32);
 impl windows_core::TypeKind for DSI_CONTROL_TRANSMISSION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DSI_CONTROL_TRANSMISSION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DSI_CONTROL_TRANSMISSION_MODE").field(&self.0).finish()
@@ -2032,6 +2049,7 @@ pub struct ENG_DEVICE_ATTRIBUTE(pub i32);
 impl windows_core::TypeKind for ENG_DEVICE_ATTRIBUTE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ENG_DEVICE_ATTRIBUTE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ENG_DEVICE_ATTRIBUTE").field(&self.0).finish()
@@ -2043,6 +2061,7 @@ pub struct ENG_SYSTEM_ATTRIBUTE(pub i32);
 impl windows_core::TypeKind for ENG_SYSTEM_ATTRIBUTE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ENG_SYSTEM_ATTRIBUTE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ENG_SYSTEM_ATTRIBUTE").field(&self.0).finish()
@@ -2054,6 +2073,7 @@ pub struct MC_COLOR_TEMPERATURE(pub i32);
 impl windows_core::TypeKind for MC_COLOR_TEMPERATURE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MC_COLOR_TEMPERATURE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MC_COLOR_TEMPERATURE").field(&self.0).finish()
@@ -2065,6 +2085,7 @@ pub struct MC_DISPLAY_TECHNOLOGY_TYPE(pub i32);
 impl windows_core::TypeKind for MC_DISPLAY_TECHNOLOGY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MC_DISPLAY_TECHNOLOGY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MC_DISPLAY_TECHNOLOGY_TYPE").field(&self.0).finish()
@@ -2076,6 +2097,7 @@ pub struct MC_DRIVE_TYPE(pub i32);
 impl windows_core::TypeKind for MC_DRIVE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MC_DRIVE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MC_DRIVE_TYPE").field(&self.0).finish()
@@ -2087,6 +2109,7 @@ pub struct MC_GAIN_TYPE(pub i32);
 impl windows_core::TypeKind for MC_GAIN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MC_GAIN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MC_GAIN_TYPE").field(&self.0).finish()
@@ -2098,6 +2121,7 @@ pub struct MC_POSITION_TYPE(pub i32);
 impl windows_core::TypeKind for MC_POSITION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MC_POSITION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MC_POSITION_TYPE").field(&self.0).finish()
@@ -2109,6 +2133,7 @@ pub struct MC_SIZE_TYPE(pub i32);
 impl windows_core::TypeKind for MC_SIZE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MC_SIZE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MC_SIZE_TYPE").field(&self.0).finish()
@@ -2120,6 +2145,7 @@ pub struct MC_VCP_CODE_TYPE(pub i32);
 impl windows_core::TypeKind for MC_VCP_CODE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MC_VCP_CODE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MC_VCP_CODE_TYPE").field(&self.0).finish()
@@ -2131,6 +2157,7 @@ pub struct ORIENTATION_PREFERENCE(pub i32);
 impl windows_core::TypeKind for ORIENTATION_PREFERENCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ORIENTATION_PREFERENCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ORIENTATION_PREFERENCE").field(&self.0).finish()
@@ -2175,6 +2202,7 @@ pub struct OUTPUT_COLOR_ENCODING(pub i32);
 impl windows_core::TypeKind for OUTPUT_COLOR_ENCODING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OUTPUT_COLOR_ENCODING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OUTPUT_COLOR_ENCODING").field(&self.0).finish()
@@ -2186,6 +2214,7 @@ pub struct OUTPUT_WIRE_COLOR_SPACE_TYPE(pub i32);
 impl windows_core::TypeKind for OUTPUT_WIRE_COLOR_SPACE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OUTPUT_WIRE_COLOR_SPACE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OUTPUT_WIRE_COLOR_SPACE_TYPE").field(&self.0).finish()
@@ -2197,6 +2226,7 @@ pub struct QUERY_DISPLAY_CONFIG_FLAGS(pub u32);
 impl windows_core::TypeKind for QUERY_DISPLAY_CONFIG_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QUERY_DISPLAY_CONFIG_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QUERY_DISPLAY_CONFIG_FLAGS").field(&self.0).finish()
@@ -2241,6 +2271,7 @@ pub struct SET_DISPLAY_CONFIG_FLAGS(pub u32);
 impl windows_core::TypeKind for SET_DISPLAY_CONFIG_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SET_DISPLAY_CONFIG_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SET_DISPLAY_CONFIG_FLAGS").field(&self.0).finish()
@@ -2285,6 +2316,7 @@ pub struct VIDEO_BANK_TYPE(pub i32);
 impl windows_core::TypeKind for VIDEO_BANK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VIDEO_BANK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VIDEO_BANK_TYPE").field(&self.0).finish()
@@ -2296,6 +2328,7 @@ pub struct VIDEO_POWER_STATE(pub i32);
 impl windows_core::TypeKind for VIDEO_POWER_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VIDEO_POWER_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VIDEO_POWER_STATE").field(&self.0).finish()
@@ -2307,13 +2340,15 @@ pub struct VIDEO_WIN32K_CALLBACKS_PARAMS_TYPE(pub i32);
 impl windows_core::TypeKind for VIDEO_WIN32K_CALLBACKS_PARAMS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VIDEO_WIN32K_CALLBACKS_PARAMS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VIDEO_WIN32K_CALLBACKS_PARAMS_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Adapter {
     pub AdapterName: [u16; 128],
     pub numSources: i32,
@@ -2328,7 +2363,8 @@ impl Default for Adapter {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Adapters {
     pub numAdapters: i32,
     pub adapter: [Adapter; 1],
@@ -2342,7 +2378,8 @@ impl Default for Adapters {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BACKLIGHT_REDUCTION_GAMMA_RAMP {
     pub R: [u16; 256],
     pub G: [u16; 256],
@@ -2357,7 +2394,8 @@ impl Default for BACKLIGHT_REDUCTION_GAMMA_RAMP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BANK_POSITION {
     pub ReadBankPosition: u32,
     pub WriteBankPosition: u32,
@@ -2372,7 +2410,8 @@ impl Default for BANK_POSITION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BLENDOBJ {
     pub BlendFunction: super::super::Graphics::Gdi::BLENDFUNCTION,
 }
@@ -2387,7 +2426,8 @@ impl Default for BLENDOBJ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BRIGHTNESS_LEVEL {
     pub Count: u8,
     pub Level: [u8; 103],
@@ -2401,7 +2441,8 @@ impl Default for BRIGHTNESS_LEVEL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BRIGHTNESS_NIT_RANGE {
     pub MinLevelInMillinit: u32,
     pub MaxLevelInMillinit: u32,
@@ -2416,7 +2457,8 @@ impl Default for BRIGHTNESS_NIT_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BRIGHTNESS_NIT_RANGES {
     pub NormalRangeCount: u32,
     pub RangeCount: u32,
@@ -2432,7 +2474,8 @@ impl Default for BRIGHTNESS_NIT_RANGES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BRUSHOBJ {
     pub iSolidColor: u32,
     pub pvRbrush: *mut core::ffi::c_void,
@@ -2447,7 +2490,8 @@ impl Default for BRUSHOBJ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CDDDXGK_REDIRBITMAPPRESENTINFO {
     pub NumDirtyRects: u32,
     pub DirtyRect: *mut super::super::Foundation::RECT,
@@ -2481,7 +2525,8 @@ impl Default for CHAR_IMAGE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CHROMATICITY_COORDINATE {
     pub x: f32,
     pub y: f32,
@@ -2495,7 +2540,8 @@ impl Default for CHROMATICITY_COORDINATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CIECHROMA {
     pub x: i32,
     pub y: i32,
@@ -2510,7 +2556,8 @@ impl Default for CIECHROMA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLIPLINE {
     pub ptfxA: POINTFIX,
     pub ptfxB: POINTFIX,
@@ -2527,7 +2574,8 @@ impl Default for CLIPLINE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLIPOBJ {
     pub iUniq: u32,
     pub rclBounds: super::super::Foundation::RECTL,
@@ -2545,7 +2593,8 @@ impl Default for CLIPOBJ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COLORINFO {
     pub Red: CIECHROMA,
     pub Green: CIECHROMA,
@@ -2617,7 +2666,8 @@ impl Default for COLORSPACE_TRANSFORM_1DLUT_CAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COLORSPACE_TRANSFORM_3x4 {
     pub ColorMatrix3x4: [f32; 12],
     pub ScalarMultiplier: f32,
@@ -2663,10 +2713,25 @@ impl Default for COLORSPACE_TRANSFORM_DATA_CAP_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COLORSPACE_TRANSFORM_DATA_CAP_0_0 {
     pub _bitfield: u32,
 }
+impl COLORSPACE_TRANSFORM_DATA_CAP_0_0 {
+    pub const fn BitCountOfInteger(&self) -> u32 {
+        self._bitfield & 63
+    }
+    pub fn set_BitCountOfInteger(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !63) | (value & 63);
+    }
+    pub const fn BitCountOfFraction(&self) -> u32 {
+        (self._bitfield >> 6) & 63
+    }
+    pub fn set_BitCountOfFraction(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 6)) | ((value & 63) << 6);
+    }
+}
 impl windows_core::TypeKind for COLORSPACE_TRANSFORM_DATA_CAP_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2676,10 +2741,25 @@ impl Default for COLORSPACE_TRANSFORM_DATA_CAP_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COLORSPACE_TRANSFORM_DATA_CAP_0_1 {
     pub _bitfield: u32,
 }
+impl COLORSPACE_TRANSFORM_DATA_CAP_0_1 {
+    pub const fn BitCountOfExponent(&self) -> u32 {
+        self._bitfield & 63
+    }
+    pub fn set_BitCountOfExponent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !63) | (value & 63);
+    }
+    pub const fn BitCountOfMantissa(&self) -> u32 {
+        (self._bitfield >> 6) & 63
+    }
+    pub fn set_BitCountOfMantissa(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 6)) | ((value & 63) << 6);
+    }
+}
 impl windows_core::TypeKind for COLORSPACE_TRANSFORM_DATA_CAP_0_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2717,10 +2797,25 @@ impl Default for COLORSPACE_TRANSFORM_MATRIX_CAP_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COLORSPACE_TRANSFORM_MATRIX_CAP_0_0 {
     pub _bitfield: u32,
 }
+impl COLORSPACE_TRANSFORM_MATRIX_CAP_0_0 {
+    pub const fn MatrixSizeX(&self) -> u32 {
+        self._bitfield & 1023
+    }
+    pub fn set_MatrixSizeX(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1023) | (value & 1023);
+    }
+    pub const fn MatrixSizeY(&self) -> u32 {
+        (self._bitfield >> 10) & 1023
+    }
+    pub fn set_MatrixSizeY(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1023 << 10)) | ((value & 1023) << 10);
+    }
+}
 impl windows_core::TypeKind for COLORSPACE_TRANSFORM_MATRIX_CAP_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2730,7 +2825,8 @@ impl Default for COLORSPACE_TRANSFORM_MATRIX_CAP_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COLORSPACE_TRANSFORM_MATRIX_V2 {
     pub StageControlLookupTable1DDegamma: COLORSPACE_TRANSFORM_STAGE_CONTROL,
     pub LookupTable1DDegamma: [GAMMA_RAMP_RGB; 4096],
@@ -2779,7 +2875,8 @@ impl Default for COLORSPACE_TRANSFORM_TARGET_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVHTADJDATA {
     pub DeviceFlags: u32,
     pub DeviceXDPI: u32,
@@ -2796,7 +2893,8 @@ impl Default for DEVHTADJDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVHTINFO {
     pub HTFlags: u32,
     pub HTPatternSize: u32,
@@ -2813,7 +2911,8 @@ impl Default for DEVHTINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVINFO {
     pub flGraphicsCaps: u32,
     pub lfDefaultFont: super::super::Graphics::Gdi::LOGFONTW,
@@ -2837,7 +2936,8 @@ impl Default for DEVINFO {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DHPDEV(pub *mut core::ffi::c_void);
 impl DHPDEV {
     pub fn is_invalid(&self) -> bool {
@@ -2853,7 +2953,8 @@ impl windows_core::TypeKind for DHPDEV {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DHSURF(pub *mut core::ffi::c_void);
 impl DHSURF {
     pub fn is_invalid(&self) -> bool {
@@ -2869,7 +2970,8 @@ impl windows_core::TypeKind for DHSURF {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_2DREGION {
     pub cx: u32,
     pub cy: u32,
@@ -2883,7 +2985,8 @@ impl Default for DISPLAYCONFIG_2DREGION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_ADAPTER_NAME {
     pub header: DISPLAYCONFIG_DEVICE_INFO_HEADER,
     pub adapterDevicePath: [u16; 128],
@@ -2897,7 +3000,8 @@ impl Default for DISPLAYCONFIG_ADAPTER_NAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_DESKTOP_IMAGE_INFO {
     pub PathSourceSize: super::super::Foundation::POINTL,
     pub DesktopImageRegion: super::super::Foundation::RECTL,
@@ -2912,7 +3016,8 @@ impl Default for DISPLAYCONFIG_DESKTOP_IMAGE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_DEVICE_INFO_HEADER {
     pub r#type: DISPLAYCONFIG_DEVICE_INFO_TYPE,
     pub size: u32,
@@ -2965,11 +3070,45 @@ impl Default for DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO_0_0 {
     pub _bitfield: u32,
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO_0_0 {
+    pub const fn advancedColorSupported(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_advancedColorSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn advancedColorEnabled(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_advancedColorEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn wideColorEnforced(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_wideColorEnforced(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn advancedColorForceDisabled(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_advancedColorForceDisabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::TypeKind for DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3008,10 +3147,37 @@ impl Default for DISPLAYCONFIG_GET_MONITOR_SPECIALIZATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_GET_MONITOR_SPECIALIZATION_0_0 {
     pub _bitfield: u32,
 }
+impl DISPLAYCONFIG_GET_MONITOR_SPECIALIZATION_0_0 {
+    pub const fn isSpecializationEnabled(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_isSpecializationEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn isSpecializationAvailableForMonitor(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_isSpecializationAvailableForMonitor(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn isSpecializationAvailableForSystem(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_isSpecializationAvailableForSystem(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for DISPLAYCONFIG_GET_MONITOR_SPECIALIZATION_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3097,10 +3263,25 @@ impl Default for DISPLAYCONFIG_PATH_SOURCE_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_PATH_SOURCE_INFO_0_0 {
     pub _bitfield: u32,
 }
+impl DISPLAYCONFIG_PATH_SOURCE_INFO_0_0 {
+    pub const fn cloneGroupId(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_cloneGroupId(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn sourceModeInfoIdx(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_sourceModeInfoIdx(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for DISPLAYCONFIG_PATH_SOURCE_INFO_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3146,10 +3327,25 @@ impl Default for DISPLAYCONFIG_PATH_TARGET_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_PATH_TARGET_INFO_0_0 {
     pub _bitfield: u32,
 }
+impl DISPLAYCONFIG_PATH_TARGET_INFO_0_0 {
+    pub const fn desktopModeInfoIdx(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_desktopModeInfoIdx(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn targetModeInfoIdx(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_targetModeInfoIdx(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for DISPLAYCONFIG_PATH_TARGET_INFO_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3159,7 +3355,8 @@ impl Default for DISPLAYCONFIG_PATH_TARGET_INFO_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_RATIONAL {
     pub Numerator: u32,
     pub Denominator: u32,
@@ -3173,7 +3370,8 @@ impl Default for DISPLAYCONFIG_RATIONAL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_SDR_WHITE_LEVEL {
     pub header: DISPLAYCONFIG_DEVICE_INFO_HEADER,
     pub SDRWhiteLevel: u32,
@@ -3215,10 +3413,25 @@ impl Default for DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE_0_0 {
     pub _bitfield: u32,
 }
+impl DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE_0_0 {
+    pub const fn enableAdvancedColor(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_enableAdvancedColor(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3259,10 +3472,25 @@ impl Default for DISPLAYCONFIG_SET_MONITOR_SPECIALIZATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_SET_MONITOR_SPECIALIZATION_0_0 {
     pub _bitfield: u32,
 }
+impl DISPLAYCONFIG_SET_MONITOR_SPECIALIZATION_0_0 {
+    pub const fn isSpecializationEnabled(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_isSpecializationEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for DISPLAYCONFIG_SET_MONITOR_SPECIALIZATION_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3300,10 +3528,25 @@ impl Default for DISPLAYCONFIG_SET_TARGET_PERSISTENCE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_SET_TARGET_PERSISTENCE_0_0 {
     pub _bitfield: u32,
 }
+impl DISPLAYCONFIG_SET_TARGET_PERSISTENCE_0_0 {
+    pub const fn bootPersistenceOn(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_bootPersistenceOn(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for DISPLAYCONFIG_SET_TARGET_PERSISTENCE_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3313,7 +3556,8 @@ impl Default for DISPLAYCONFIG_SET_TARGET_PERSISTENCE_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_SOURCE_DEVICE_NAME {
     pub header: DISPLAYCONFIG_DEVICE_INFO_HEADER,
     pub viewGdiDeviceName: [u16; 32],
@@ -3327,7 +3571,8 @@ impl Default for DISPLAYCONFIG_SOURCE_DEVICE_NAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_SOURCE_MODE {
     pub width: u32,
     pub height: u32,
@@ -3371,10 +3616,25 @@ impl Default for DISPLAYCONFIG_SUPPORT_VIRTUAL_RESOLUTION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_SUPPORT_VIRTUAL_RESOLUTION_0_0 {
     pub _bitfield: u32,
 }
+impl DISPLAYCONFIG_SUPPORT_VIRTUAL_RESOLUTION_0_0 {
+    pub const fn disableMonitorVirtualResolution(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_disableMonitorVirtualResolution(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for DISPLAYCONFIG_SUPPORT_VIRTUAL_RESOLUTION_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3384,7 +3644,8 @@ impl Default for DISPLAYCONFIG_SUPPORT_VIRTUAL_RESOLUTION_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_TARGET_BASE_TYPE {
     pub header: DISPLAYCONFIG_DEVICE_INFO_HEADER,
     pub baseOutputTechnology: DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY,
@@ -3445,10 +3706,37 @@ impl Default for DISPLAYCONFIG_TARGET_DEVICE_NAME_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_TARGET_DEVICE_NAME_FLAGS_0_0 {
     pub _bitfield: u32,
 }
+impl DISPLAYCONFIG_TARGET_DEVICE_NAME_FLAGS_0_0 {
+    pub const fn friendlyNameFromEdid(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_friendlyNameFromEdid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn friendlyNameForced(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_friendlyNameForced(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn edidIdsValid(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_edidIdsValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for DISPLAYCONFIG_TARGET_DEVICE_NAME_FLAGS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3520,10 +3808,31 @@ impl Default for DISPLAYCONFIG_VIDEO_SIGNAL_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAYCONFIG_VIDEO_SIGNAL_INFO_0_0 {
     pub _bitfield: u32,
 }
+impl DISPLAYCONFIG_VIDEO_SIGNAL_INFO_0_0 {
+    pub const fn videoStandard(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_videoStandard(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn vSyncFreqDivider(&self) -> u32 {
+        (self._bitfield >> 16) & 63
+    }
+    pub fn set_vSyncFreqDivider(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 16)) | ((value & 63) << 16);
+    }
+    pub const fn reserved(&self) -> u32 {
+        (self._bitfield >> 22) & 1023
+    }
+    pub fn set_reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1023 << 22)) | ((value & 1023) << 22);
+    }
+}
 impl windows_core::TypeKind for DISPLAYCONFIG_VIDEO_SIGNAL_INFO_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3533,7 +3842,8 @@ impl Default for DISPLAYCONFIG_VIDEO_SIGNAL_INFO_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPLAY_BRIGHTNESS {
     pub ucDisplayPolicy: u8,
     pub ucACBrightness: u8,
@@ -3548,7 +3858,8 @@ impl Default for DISPLAY_BRIGHTNESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRH_APIBITMAPDATA {
     pub pso: *mut SURFOBJ,
     pub b: super::super::Foundation::BOOL,
@@ -3562,7 +3873,8 @@ impl Default for DRH_APIBITMAPDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRIVEROBJ {
     pub pvObj: *mut core::ffi::c_void,
     pub pFreeProc: FREEOBJPROC,
@@ -3578,7 +3890,8 @@ impl Default for DRIVEROBJ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRVENABLEDATA {
     pub iDriverVersion: u32,
     pub c: u32,
@@ -3593,7 +3906,8 @@ impl Default for DRVENABLEDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRVFN {
     pub iFunc: u32,
     pub pfn: PFN,
@@ -3607,7 +3921,8 @@ impl Default for DRVFN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGK_WIN32K_PARAM_DATA {
     pub PathsArray: *mut core::ffi::c_void,
     pub ModesArray: *mut core::ffi::c_void,
@@ -3659,7 +3974,8 @@ impl Default for DisplayModes {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EMFINFO {
     pub nSize: u32,
     pub hdc: super::super::Graphics::Gdi::HDC,
@@ -3677,7 +3993,8 @@ impl Default for EMFINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ENGSAFESEMAPHORE {
     pub hsem: HSEMAPHORE,
     pub lCount: i32,
@@ -3691,7 +4008,8 @@ impl Default for ENGSAFESEMAPHORE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ENG_EVENT {
     pub pKEvent: *mut core::ffi::c_void,
     pub fFlags: u32,
@@ -3705,7 +4023,8 @@ impl Default for ENG_EVENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ENG_TIME_FIELDS {
     pub usYear: u16,
     pub usMonth: u16,
@@ -3725,7 +4044,8 @@ impl Default for ENG_TIME_FIELDS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ENUMRECTS {
     pub c: u32,
     pub arcl: [super::super::Foundation::RECTL; 1],
@@ -3739,7 +4059,8 @@ impl Default for ENUMRECTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FD_DEVICEMETRICS {
     pub flRealizedType: u32,
     pub pteBase: POINTE,
@@ -3773,7 +4094,8 @@ impl Default for FD_DEVICEMETRICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FD_GLYPHATTR {
     pub cjThis: u32,
     pub cGlyphs: u32,
@@ -3789,7 +4111,8 @@ impl Default for FD_GLYPHATTR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FD_GLYPHSET {
     pub cjThis: u32,
     pub flAccel: u32,
@@ -3806,7 +4129,8 @@ impl Default for FD_GLYPHSET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FD_KERNINGPAIR {
     pub wcFirst: u16,
     pub wcSecond: u16,
@@ -3821,7 +4145,8 @@ impl Default for FD_KERNINGPAIR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FD_LIGATURE {
     pub culThis: u32,
     pub ulType: u32,
@@ -3838,7 +4163,8 @@ impl Default for FD_LIGATURE {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FD_XFORM {
     pub eXX: f32,
     pub eXY: f32,
@@ -3857,7 +4183,8 @@ impl Default for FD_XFORM {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FD_XFORM {
     pub eXX: u32,
     pub eXY: u32,
@@ -3876,7 +4203,8 @@ impl Default for FD_XFORM {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FLOATOBJ {
     pub ul1: u32,
     pub ul2: u32,
@@ -3893,7 +4221,8 @@ impl Default for FLOATOBJ {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FLOATOBJ_XFORM {
     pub eM11: f32,
     pub eM12: f32,
@@ -3914,7 +4243,8 @@ impl Default for FLOATOBJ_XFORM {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FLOATOBJ_XFORM {
     pub eM11: FLOATOBJ,
     pub eM12: FLOATOBJ,
@@ -3968,7 +4298,8 @@ impl Default for FLOAT_LONG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FONTDIFF {
     pub jReserved1: u8,
     pub jReserved2: u8,
@@ -3989,7 +4320,8 @@ impl Default for FONTDIFF {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FONTINFO {
     pub cjThis: u32,
     pub flCaps: u32,
@@ -4008,7 +4340,8 @@ impl Default for FONTINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FONTOBJ {
     pub iUniq: u32,
     pub iFace: u32,
@@ -4030,7 +4363,8 @@ impl Default for FONTOBJ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FONTSIM {
     pub dpBold: i32,
     pub dpItalic: i32,
@@ -4046,7 +4380,8 @@ impl Default for FONTSIM {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Console")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FONT_IMAGE_INFO {
     pub FontSize: super::super::System::Console::COORD,
     pub ImageBits: *mut u8,
@@ -4063,7 +4398,8 @@ impl Default for FONT_IMAGE_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Console")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FSCNTL_SCREEN_INFO {
     pub Position: super::super::System::Console::COORD,
     pub ScreenSize: super::super::System::Console::COORD,
@@ -4081,7 +4417,8 @@ impl Default for FSCNTL_SCREEN_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Console")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FSVIDEO_COPY_FRAME_BUFFER {
     pub SrcScreen: FSCNTL_SCREEN_INFO,
     pub DestScreen: FSCNTL_SCREEN_INFO,
@@ -4097,7 +4434,8 @@ impl Default for FSVIDEO_COPY_FRAME_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FSVIDEO_CURSOR_POSITION {
     pub Coord: VIDEO_CURSOR_POSITION,
     pub dwType: u32,
@@ -4111,7 +4449,8 @@ impl Default for FSVIDEO_CURSOR_POSITION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FSVIDEO_MODE_INFORMATION {
     pub VideoMode: VIDEO_MODE_INFORMATION,
     pub VideoMemory: VIDEO_MEMORY_INFORMATION,
@@ -4126,7 +4465,8 @@ impl Default for FSVIDEO_MODE_INFORMATION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Console")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FSVIDEO_REVERSE_MOUSE_POINTER {
     pub Screen: FSCNTL_SCREEN_INFO,
     pub dwType: u32,
@@ -4143,7 +4483,8 @@ impl Default for FSVIDEO_REVERSE_MOUSE_POINTER {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Console")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FSVIDEO_SCREEN_INFORMATION {
     pub ScreenSize: super::super::System::Console::COORD,
     pub FontSize: super::super::System::Console::COORD,
@@ -4160,7 +4501,8 @@ impl Default for FSVIDEO_SCREEN_INFORMATION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Console")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FSVIDEO_WRITE_TO_FRAME_BUFFER {
     pub SrcBuffer: *mut CHAR_IMAGE_INFO,
     pub DestScreen: FSCNTL_SCREEN_INFO,
@@ -4176,7 +4518,8 @@ impl Default for FSVIDEO_WRITE_TO_FRAME_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GAMMARAMP {
     pub Red: [u16; 256],
     pub Green: [u16; 256],
@@ -4191,7 +4534,8 @@ impl Default for GAMMARAMP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GAMMA_RAMP_DXGI_1 {
     pub Scale: GAMMA_RAMP_RGB,
     pub Offset: GAMMA_RAMP_RGB,
@@ -4206,7 +4550,8 @@ impl Default for GAMMA_RAMP_DXGI_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GAMMA_RAMP_RGB {
     pub Red: f32,
     pub Green: f32,
@@ -4221,7 +4566,8 @@ impl Default for GAMMA_RAMP_RGB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GAMMA_RAMP_RGB256x3x16 {
     pub Red: [u16; 256],
     pub Green: [u16; 256],
@@ -4236,7 +4582,8 @@ impl Default for GAMMA_RAMP_RGB256x3x16 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GDIINFO {
     pub ulVersion: u32,
     pub ulTechnology: u32,
@@ -4293,7 +4640,8 @@ impl Default for GDIINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GLYPHBITS {
     pub ptlOrigin: super::super::Foundation::POINTL,
     pub sizlBitmap: super::super::Foundation::SIZE,
@@ -4343,7 +4691,8 @@ impl Default for GLYPHDEF {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GLYPHPOS {
     pub hg: u32,
     pub pgdf: *mut GLYPHDEF,
@@ -4358,7 +4707,8 @@ impl Default for GLYPHPOS {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HBM(pub *mut core::ffi::c_void);
 impl HBM {
     pub fn is_invalid(&self) -> bool {
@@ -4374,7 +4724,8 @@ impl windows_core::TypeKind for HBM {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDEV(pub *mut core::ffi::c_void);
 impl HDEV {
     pub fn is_invalid(&self) -> bool {
@@ -4390,7 +4741,8 @@ impl windows_core::TypeKind for HDEV {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDRVOBJ(pub *mut core::ffi::c_void);
 impl HDRVOBJ {
     pub fn is_invalid(&self) -> bool {
@@ -4406,7 +4758,8 @@ impl windows_core::TypeKind for HDRVOBJ {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HFASTMUTEX(pub *mut core::ffi::c_void);
 impl HFASTMUTEX {
     pub fn is_invalid(&self) -> bool {
@@ -4422,7 +4775,8 @@ impl windows_core::TypeKind for HFASTMUTEX {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HSEMAPHORE(pub *mut core::ffi::c_void);
 impl HSEMAPHORE {
     pub fn is_invalid(&self) -> bool {
@@ -4446,7 +4800,8 @@ impl windows_core::TypeKind for HSEMAPHORE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HSURF(pub *mut core::ffi::c_void);
 impl HSURF {
     pub fn is_invalid(&self) -> bool {
@@ -4462,7 +4817,8 @@ impl windows_core::TypeKind for HSURF {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IFIEXTRA {
     pub ulIdentifier: u32,
     pub dpFontSig: i32,
@@ -4482,7 +4838,8 @@ impl Default for IFIEXTRA {
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IFIMETRICS {
     pub cjThis: u32,
     pub cjIfiExtra: u32,
@@ -4560,7 +4917,8 @@ impl Default for IFIMETRICS {
 #[repr(C)]
 #[cfg(target_arch = "x86")]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IFIMETRICS {
     pub cjThis: u32,
     pub cjIfiExtra: u32,
@@ -4635,7 +4993,8 @@ impl Default for IFIMETRICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INDIRECT_DISPLAY_INFO {
     pub DisplayAdapterLuid: super::super::Foundation::LUID,
     pub Flags: u32,
@@ -4651,7 +5010,8 @@ impl Default for INDIRECT_DISPLAY_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LIGATURE {
     pub culSize: u32,
     pub pwsz: windows_core::PWSTR,
@@ -4728,7 +5088,8 @@ impl Default for MC_TIMING_REPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIPI_DSI_CAPS {
     pub DSITypeMajor: u8,
     pub DSITypeMinor: u8,
@@ -4788,10 +5149,25 @@ impl Default for MIPI_DSI_PACKET_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIPI_DSI_PACKET_0_0 {
     pub _bitfield: u8,
 }
+impl MIPI_DSI_PACKET_0_0 {
+    pub const fn DataType(&self) -> u8 {
+        self._bitfield & 63
+    }
+    pub fn set_DataType(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !63) | (value & 63);
+    }
+    pub const fn VirtualChannel(&self) -> u8 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_VirtualChannel(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+}
 impl windows_core::TypeKind for MIPI_DSI_PACKET_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4815,7 +5191,8 @@ impl Default for MIPI_DSI_PACKET_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIPI_DSI_PACKET_1_0 {
     pub Data0: u8,
     pub Data1: u8,
@@ -4857,10 +5234,31 @@ impl Default for MIPI_DSI_RESET_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIPI_DSI_RESET_0_0 {
     pub _bitfield: u32,
 }
+impl MIPI_DSI_RESET_0_0 {
+    pub const fn MipiErrors(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_MipiErrors(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn ResetFailed(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_ResetFailed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn NeedModeSet(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_NeedModeSet(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+}
 impl windows_core::TypeKind for MIPI_DSI_RESET_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4891,10 +5289,49 @@ impl Default for MIPI_DSI_TRANSMISSION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIPI_DSI_TRANSMISSION_0 {
     pub _bitfield: u16,
 }
+impl MIPI_DSI_TRANSMISSION_0 {
+    pub const fn TransmissionMode(&self) -> u16 {
+        self._bitfield & 3
+    }
+    pub fn set_TransmissionMode(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn ReportMipiErrors(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ReportMipiErrors(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ClearMipiErrors(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ClearMipiErrors(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn SecondaryPort(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_SecondaryPort(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn ManufacturingMode(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_ManufacturingMode(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 6) & 1023
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1023 << 6)) | ((value & 1023) << 6);
+    }
+}
 impl windows_core::TypeKind for MIPI_DSI_TRANSMISSION_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4904,7 +5341,8 @@ impl Default for MIPI_DSI_TRANSMISSION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OUTPUT_WIRE_FORMAT {
     pub ColorEncoding: OUTPUT_COLOR_ENCODING,
     pub BitsPerPixel: u32,
@@ -4918,7 +5356,8 @@ impl Default for OUTPUT_WIRE_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PALOBJ {
     pub ulReserved: u32,
 }
@@ -4961,10 +5400,37 @@ impl Default for PANEL_BRIGHTNESS_SENSOR_DATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PANEL_BRIGHTNESS_SENSOR_DATA_0_0 {
     pub _bitfield: u32,
 }
+impl PANEL_BRIGHTNESS_SENSOR_DATA_0_0 {
+    pub const fn AlsReadingValid(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_AlsReadingValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ChromaticityCoordinateValid(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ChromaticityCoordinateValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ColorTemperatureValid(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ColorTemperatureValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for PANEL_BRIGHTNESS_SENSOR_DATA_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4974,7 +5440,8 @@ impl Default for PANEL_BRIGHTNESS_SENSOR_DATA_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PANEL_GET_BACKLIGHT_REDUCTION {
     pub BacklightUsersetting: u16,
     pub BacklightEffective: u16,
@@ -5017,7 +5484,8 @@ impl Default for PANEL_GET_BRIGHTNESS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PANEL_GET_BRIGHTNESS_0_0 {
     pub CurrentInMillinits: u32,
     pub TargetInMillinits: u32,
@@ -5059,10 +5527,37 @@ impl Default for PANEL_QUERY_BRIGHTNESS_CAPS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PANEL_QUERY_BRIGHTNESS_CAPS_0_0 {
     pub _bitfield: u32,
 }
+impl PANEL_QUERY_BRIGHTNESS_CAPS_0_0 {
+    pub const fn Smooth(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Smooth(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Adaptive(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Adaptive(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn NitsCalibrated(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_NitsCalibrated(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for PANEL_QUERY_BRIGHTNESS_CAPS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5100,7 +5595,8 @@ impl Default for PANEL_QUERY_BRIGHTNESS_RANGES_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PANEL_SET_BACKLIGHT_OPTIMIZATION {
     pub Level: BACKLIGHT_OPTIMIZATION_LEVEL,
 }
@@ -5183,10 +5679,25 @@ impl Default for PANEL_SET_BRIGHTNESS_STATE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PANEL_SET_BRIGHTNESS_STATE_0_0 {
     pub _bitfield: u32,
 }
+impl PANEL_SET_BRIGHTNESS_STATE_0_0 {
+    pub const fn Smooth(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Smooth(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for PANEL_SET_BRIGHTNESS_STATE_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5196,7 +5707,8 @@ impl Default for PANEL_SET_BRIGHTNESS_STATE_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PATHDATA {
     pub flags: u32,
     pub count: u32,
@@ -5211,7 +5723,8 @@ impl Default for PATHDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PATHOBJ {
     pub fl: u32,
     pub cCurves: u32,
@@ -5225,7 +5738,8 @@ impl Default for PATHOBJ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERBANDINFO {
     pub bRepeatThisBand: super::super::Foundation::BOOL,
     pub szlBand: super::super::Foundation::SIZE,
@@ -5256,7 +5770,8 @@ impl Default for PHYSICAL_MONITOR {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POINTE {
     pub x: f32,
     pub y: f32,
@@ -5273,7 +5788,8 @@ impl Default for POINTE {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POINTE {
     pub x: u32,
     pub y: u32,
@@ -5289,7 +5805,8 @@ impl Default for POINTE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POINTFIX {
     pub x: i32,
     pub y: i32,
@@ -5303,7 +5820,8 @@ impl Default for POINTFIX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POINTQF {
     pub x: i64,
     pub y: i64,
@@ -5317,7 +5835,8 @@ impl Default for POINTQF {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RECTFX {
     pub xLeft: i32,
     pub yTop: i32,
@@ -5333,7 +5852,8 @@ impl Default for RECTFX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RUN {
     pub iStart: i32,
     pub iStop: i32,
@@ -5347,7 +5867,8 @@ impl Default for RUN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SET_ACTIVE_COLOR_PROFILE_NAME {
     pub ColorProfileName: [u16; 1],
 }
@@ -5360,7 +5881,8 @@ impl Default for SET_ACTIVE_COLOR_PROFILE_NAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STROBJ {
     pub cGlyphs: u32,
     pub flAccel: u32,
@@ -5378,7 +5900,8 @@ impl Default for STROBJ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SURFOBJ {
     pub dhsurf: DHSURF,
     pub hsurf: HSURF,
@@ -5403,7 +5926,8 @@ impl Default for SURFOBJ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Sources {
     pub sourceId: u32,
     pub numTargets: i32,
@@ -5418,7 +5942,8 @@ impl Default for Sources {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TYPE1_FONT {
     pub hPFM: super::super::Foundation::HANDLE,
     pub hPFB: super::super::Foundation::HANDLE,
@@ -5433,7 +5958,8 @@ impl Default for TYPE1_FONT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VGA_CHAR {
     pub Char: i8,
     pub Attributes: i8,
@@ -5447,7 +5973,8 @@ impl Default for VGA_CHAR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEOPARAMETERS {
     pub Guid: windows_core::GUID,
     pub dwOffset: u32,
@@ -5482,7 +6009,8 @@ impl Default for VIDEOPARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_BANK_SELECT {
     pub Length: u32,
     pub Size: u32,
@@ -5507,7 +6035,8 @@ impl Default for VIDEO_BANK_SELECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_BRIGHTNESS_POLICY {
     pub DefaultToBiosPolicy: super::super::Foundation::BOOLEAN,
     pub LevelCount: u8,
@@ -5522,7 +6051,8 @@ impl Default for VIDEO_BRIGHTNESS_POLICY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_BRIGHTNESS_POLICY_0 {
     pub BatteryLevel: u8,
     pub Brightness: u8,
@@ -5565,7 +6095,8 @@ impl Default for VIDEO_CLUT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_CLUTDATA {
     pub Red: u8,
     pub Green: u8,
@@ -5581,7 +6112,8 @@ impl Default for VIDEO_CLUTDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_COLOR_CAPABILITIES {
     pub Length: u32,
     pub AttributeFlags: u32,
@@ -5611,7 +6143,8 @@ impl Default for VIDEO_COLOR_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_COLOR_LUT_DATA {
     pub Length: u32,
     pub LutDataFormat: u32,
@@ -5626,7 +6159,8 @@ impl Default for VIDEO_COLOR_LUT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_CURSOR_ATTRIBUTES {
     pub Width: u16,
     pub Height: u16,
@@ -5644,7 +6178,8 @@ impl Default for VIDEO_CURSOR_ATTRIBUTES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_CURSOR_POSITION {
     pub Column: i16,
     pub Row: i16,
@@ -5658,7 +6193,8 @@ impl Default for VIDEO_CURSOR_POSITION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_DEVICE_SESSION_STATUS {
     pub bEnable: u32,
     pub bSuccess: u32,
@@ -5672,7 +6208,8 @@ impl Default for VIDEO_DEVICE_SESSION_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_HARDWARE_STATE {
     pub StateHeader: *mut VIDEO_HARDWARE_STATE_HEADER,
     pub StateLength: u32,
@@ -5686,7 +6223,8 @@ impl Default for VIDEO_HARDWARE_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_HARDWARE_STATE_HEADER {
     pub Length: u32,
     pub PortValue: [u8; 48],
@@ -5728,7 +6266,8 @@ impl Default for VIDEO_HARDWARE_STATE_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_LOAD_FONT_INFORMATION {
     pub WidthInPixels: u16,
     pub HeightInPixels: u16,
@@ -5744,7 +6283,8 @@ impl Default for VIDEO_LOAD_FONT_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_LUT_RGB256WORDS {
     pub Red: [u16; 256],
     pub Green: [u16; 256],
@@ -5759,7 +6299,8 @@ impl Default for VIDEO_LUT_RGB256WORDS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_MEMORY {
     pub RequestedVirtualAddress: *mut core::ffi::c_void,
 }
@@ -5772,7 +6313,8 @@ impl Default for VIDEO_MEMORY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_MEMORY_INFORMATION {
     pub VideoRamBase: *mut core::ffi::c_void,
     pub VideoRamLength: u32,
@@ -5788,7 +6330,8 @@ impl Default for VIDEO_MEMORY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_MODE {
     pub RequestedMode: u32,
 }
@@ -5801,7 +6344,8 @@ impl Default for VIDEO_MODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_MODE_INFORMATION {
     pub Length: u32,
     pub ModeIndex: u32,
@@ -5833,7 +6377,8 @@ impl Default for VIDEO_MODE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_MONITOR_DESCRIPTOR {
     pub DescriptorSize: u32,
     pub Descriptor: [u8; 1],
@@ -5847,7 +6392,8 @@ impl Default for VIDEO_MONITOR_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_NUM_MODES {
     pub NumModes: u32,
     pub ModeInformationLength: u32,
@@ -5861,7 +6407,8 @@ impl Default for VIDEO_NUM_MODES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_PALETTE_DATA {
     pub NumEntries: u16,
     pub FirstEntry: u16,
@@ -5876,7 +6423,8 @@ impl Default for VIDEO_PALETTE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_PERFORMANCE_COUNTER {
     pub NbOfAllocationEvicted: [u64; 10],
     pub NbOfAllocationMarked: [u64; 10],
@@ -5909,7 +6457,8 @@ impl Default for VIDEO_PERFORMANCE_COUNTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_POINTER_ATTRIBUTES {
     pub Flags: u32,
     pub Width: u32,
@@ -5929,7 +6478,8 @@ impl Default for VIDEO_POINTER_ATTRIBUTES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_POINTER_CAPABILITIES {
     pub Flags: u32,
     pub MaxWidth: u32,
@@ -5946,7 +6496,8 @@ impl Default for VIDEO_POINTER_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_POINTER_POSITION {
     pub Column: i16,
     pub Row: i16,
@@ -5960,7 +6511,8 @@ impl Default for VIDEO_POINTER_POSITION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_POWER_MANAGEMENT {
     pub Length: u32,
     pub DPMSVersion: u32,
@@ -5975,7 +6527,8 @@ impl Default for VIDEO_POWER_MANAGEMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_PUBLIC_ACCESS_RANGES {
     pub InIoSpace: u32,
     pub MappedInIoSpace: u32,
@@ -5990,7 +6543,8 @@ impl Default for VIDEO_PUBLIC_ACCESS_RANGES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_QUERY_PERFORMANCE_COUNTER {
     pub BufferSize: u32,
     pub Buffer: *mut VIDEO_PERFORMANCE_COUNTER,
@@ -6004,7 +6558,8 @@ impl Default for VIDEO_QUERY_PERFORMANCE_COUNTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_REGISTER_VDM {
     pub MinimumStateSize: u32,
 }
@@ -6017,7 +6572,8 @@ impl Default for VIDEO_REGISTER_VDM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_SHARE_MEMORY {
     pub ProcessHandle: super::super::Foundation::HANDLE,
     pub ViewOffset: u32,
@@ -6033,7 +6589,8 @@ impl Default for VIDEO_SHARE_MEMORY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_SHARE_MEMORY_INFORMATION {
     pub SharedViewOffset: u32,
     pub SharedViewSize: u32,
@@ -6048,7 +6605,8 @@ impl Default for VIDEO_SHARE_MEMORY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_VDM {
     pub ProcessHandle: super::super::Foundation::HANDLE,
 }
@@ -6061,7 +6619,8 @@ impl Default for VIDEO_VDM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_WIN32K_CALLBACKS {
     pub PhysDisp: *mut core::ffi::c_void,
     pub Callout: PVIDEO_WIN32K_CALLOUT,
@@ -6078,7 +6637,8 @@ impl Default for VIDEO_WIN32K_CALLBACKS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_WIN32K_CALLBACKS_PARAMS {
     pub CalloutType: VIDEO_WIN32K_CALLBACKS_PARAMS_TYPE,
     pub PhysDisp: *mut core::ffi::c_void,
@@ -6098,7 +6658,8 @@ impl Default for VIDEO_WIN32K_CALLBACKS_PARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WCRUN {
     pub wcLow: u16,
     pub cGlyphs: u16,
@@ -6113,7 +6674,8 @@ impl Default for WCRUN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WNDOBJ {
     pub coClient: CLIPOBJ,
     pub pvConsumer: *mut core::ffi::c_void,
@@ -6130,7 +6692,8 @@ impl Default for WNDOBJ {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XFORML {
     pub eM11: f32,
     pub eM12: f32,
@@ -6151,7 +6714,8 @@ impl Default for XFORML {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XFORML {
     pub eM11: u32,
     pub eM12: u32,
@@ -6171,7 +6735,8 @@ impl Default for XFORML {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XFORMOBJ {
     pub ulReserved: u32,
 }
@@ -6184,7 +6749,8 @@ impl Default for XFORMOBJ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XLATEOBJ {
     pub iUniq: u32,
     pub flXlate: u32,
@@ -6319,3 +6885,5 @@ pub type SORTCOMP = Option<unsafe extern "system" fn(pv1: *const core::ffi::c_vo
 pub type WNDOBJCHANGEPROC = Option<unsafe extern "system" fn(pwo: *mut WNDOBJ, fl: u32)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
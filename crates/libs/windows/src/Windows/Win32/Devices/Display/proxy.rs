@@ -0,0 +1,61 @@
+impl windows_core::imp::ProxyVtable for ICloneViewHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetConnectedIDs(this: *mut core::ffi::c_void, wszadaptorname: windows_core::PCWSTR, pulcount: *mut u32, pulid: *mut u32, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICloneViewHelper", "GetConnectedIDs", 3usize, |this| ((**(this as *const *const ICloneViewHelper_Vtbl)).GetConnectedIDs)(this, wszadaptorname, pulcount, pulid, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveTopology(this: *mut core::ffi::c_void, wszadaptorname: windows_core::PCWSTR, ulsourceid: u32, pulcount: *mut u32, pultargetid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICloneViewHelper", "GetActiveTopology", 4usize, |this| ((**(this as *const *const ICloneViewHelper_Vtbl)).GetActiveTopology)(this, wszadaptorname, ulsourceid, pulcount, pultargetid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActiveTopology(this: *mut core::ffi::c_void, wszadaptorname: windows_core::PCWSTR, ulsourceid: u32, ulcount: u32, pultargetid: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICloneViewHelper", "SetActiveTopology", 5usize, |this| ((**(this as *const *const ICloneViewHelper_Vtbl)).SetActiveTopology)(this, wszadaptorname, ulsourceid, ulcount, pultargetid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, ffinalcall: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICloneViewHelper", "Commit", 6usize, |this| ((**(this as *const *const ICloneViewHelper_Vtbl)).Commit)(this, ffinalcall), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetConnectedIDs, GetActiveTopology, SetActiveTopology, Commit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICloneViewHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IViewHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetConnectedIDs(this: *mut core::ffi::c_void, wszadaptorname: windows_core::PCWSTR, pulcount: *mut u32, pulid: *mut u32, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewHelper", "GetConnectedIDs", 3usize, |this| ((**(this as *const *const IViewHelper_Vtbl)).GetConnectedIDs)(this, wszadaptorname, pulcount, pulid, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveTopology(this: *mut core::ffi::c_void, wszadaptorname: windows_core::PCWSTR, ulsourceid: u32, pulcount: *mut u32, pultargetid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewHelper", "GetActiveTopology", 4usize, |this| ((**(this as *const *const IViewHelper_Vtbl)).GetActiveTopology)(this, wszadaptorname, ulsourceid, pulcount, pultargetid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActiveTopology(this: *mut core::ffi::c_void, wszadaptorname: windows_core::PCWSTR, ulsourceid: u32, ulcount: u32, pultargetid: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewHelper", "SetActiveTopology", 5usize, |this| ((**(this as *const *const IViewHelper_Vtbl)).SetActiveTopology)(this, wszadaptorname, ulsourceid, ulcount, pultargetid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewHelper", "Commit", 6usize, |this| ((**(this as *const *const IViewHelper_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetConfiguration(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, pulstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewHelper", "SetConfiguration", 7usize, |this| ((**(this as *const *const IViewHelper_Vtbl)).SetConfiguration)(this, pistream, pulstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProceedOnNewConfiguration(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewHelper", "GetProceedOnNewConfiguration", 8usize, |this| ((**(this as *const *const IViewHelper_Vtbl)).GetProceedOnNewConfiguration)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetConnectedIDs,
+            GetActiveTopology,
+            SetActiveTopology,
+            Commit,
+            #[cfg(feature = "Win32_System_Com")]
+            SetConfiguration,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetConfiguration: 0,
+            GetProceedOnNewConfiguration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IViewHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
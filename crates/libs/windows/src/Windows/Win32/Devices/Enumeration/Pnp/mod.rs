@@ -1152,6 +1152,7 @@ pub struct SW_DEVICE_CAPABILITIES(pub i32);
 impl windows_core::TypeKind for SW_DEVICE_CAPABILITIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SW_DEVICE_CAPABILITIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SW_DEVICE_CAPABILITIES").field(&self.0).finish()
@@ -1163,13 +1164,15 @@ pub struct SW_DEVICE_LIFETIME(pub i32);
 impl windows_core::TypeKind for SW_DEVICE_LIFETIME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SW_DEVICE_LIFETIME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SW_DEVICE_LIFETIME").field(&self.0).finish()
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HSWDEVICE(pub *mut core::ffi::c_void);
 impl HSWDEVICE {
     pub fn is_invalid(&self) -> bool {
@@ -1194,7 +1197,8 @@ impl windows_core::TypeKind for HSWDEVICE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Security")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SW_DEVICE_CREATE_INFO {
     pub cbSize: u32,
     pub pszInstanceId: windows_core::PCWSTR,
@@ -1229,3 +1233,5 @@ pub const UPnPServices: windows_core::GUID = windows_core::GUID::from_u128(0xc0b
 pub type SW_DEVICE_CREATE_CALLBACK = Option<unsafe extern "system" fn(hswdevice: HSWDEVICE, createresult: windows_core::HRESULT, pcontext: *const core::ffi::c_void, pszdeviceinstanceid: windows_core::PCWSTR)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
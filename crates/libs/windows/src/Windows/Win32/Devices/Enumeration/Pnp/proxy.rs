@@ -0,0 +1,658 @@
+impl windows_core::imp::ProxyVtable for IUPnPAddressFamilyControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAddressFamily(this: *mut core::ffi::c_void, dwflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPAddressFamilyControl", "SetAddressFamily", 3usize, |this| ((**(this as *const *const IUPnPAddressFamilyControl_Vtbl)).SetAddressFamily)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAddressFamily(this: *mut core::ffi::c_void, pdwflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPAddressFamilyControl", "GetAddressFamily", 4usize, |this| ((**(this as *const *const IUPnPAddressFamilyControl_Vtbl)).GetAddressFamily)(this, pdwflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAddressFamily, GetAddressFamily }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPAddressFamilyControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUPnPAsyncResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AsyncOperationComplete(this: *mut core::ffi::c_void, ullrequestid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPAsyncResult", "AsyncOperationComplete", 3usize, |this| ((**(this as *const *const IUPnPAsyncResult_Vtbl)).AsyncOperationComplete)(this, ullrequestid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AsyncOperationComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPAsyncResult as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUPnPDescriptionDocument_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadyState(this: *mut core::ffi::c_void, plreadystate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDescriptionDocument", "ReadyState", 7usize, |this| ((**(this as *const *const IUPnPDescriptionDocument_Vtbl)).ReadyState)(this, plreadystate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDescriptionDocument", "Load", 8usize, |this| ((**(this as *const *const IUPnPDescriptionDocument_Vtbl)).Load)(this, bstrurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadAsync(this: *mut core::ffi::c_void, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, punkcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDescriptionDocument", "LoadAsync", 9usize, |this| ((**(this as *const *const IUPnPDescriptionDocument_Vtbl)).LoadAsync)(this, bstrurl, punkcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadResult(this: *mut core::ffi::c_void, phrerror: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDescriptionDocument", "LoadResult", 10usize, |this| ((**(this as *const *const IUPnPDescriptionDocument_Vtbl)).LoadResult)(this, phrerror), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDescriptionDocument", "Abort", 11usize, |this| ((**(this as *const *const IUPnPDescriptionDocument_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RootDevice(this: *mut core::ffi::c_void, ppudrootdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDescriptionDocument", "RootDevice", 12usize, |this| ((**(this as *const *const IUPnPDescriptionDocument_Vtbl)).RootDevice)(this, ppudrootdevice), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DeviceByUDN(this: *mut core::ffi::c_void, bstrudn: core::mem::MaybeUninit<windows_core::BSTR>, ppuddevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDescriptionDocument", "DeviceByUDN", 13usize, |this| ((**(this as *const *const IUPnPDescriptionDocument_Vtbl)).DeviceByUDN)(this, bstrudn, ppuddevice), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReadyState,
+            Load,
+            LoadAsync,
+            LoadResult,
+            Abort,
+            #[cfg(feature = "Win32_System_Com")]
+            RootDevice,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RootDevice: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DeviceByUDN,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DeviceByUDN: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPDescriptionDocument as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUPnPDescriptionDocumentCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadComplete(this: *mut core::ffi::c_void, hrloadresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDescriptionDocumentCallback", "LoadComplete", 3usize, |this| ((**(this as *const *const IUPnPDescriptionDocumentCallback_Vtbl)).LoadComplete)(this, hrloadresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LoadComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPDescriptionDocumentCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUPnPDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsRootDevice(this: *mut core::ffi::c_void, pvarb: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "IsRootDevice", 7usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).IsRootDevice)(this, pvarb), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RootDevice(this: *mut core::ffi::c_void, ppudrootdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "RootDevice", 8usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).RootDevice)(this, ppudrootdevice), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ParentDevice(this: *mut core::ffi::c_void, ppuddeviceparent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "ParentDevice", 9usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).ParentDevice)(this, ppuddeviceparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasChildren(this: *mut core::ffi::c_void, pvarb: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "HasChildren", 10usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).HasChildren)(this, pvarb), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Children(this: *mut core::ffi::c_void, ppudchildren: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "Children", 11usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).Children)(this, ppudchildren), |result| Some(*result))
+        }
+        unsafe extern "system" fn UniqueDeviceName(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "UniqueDeviceName", 12usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).UniqueDeviceName)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "FriendlyName", 13usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).FriendlyName)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "Type", 14usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).Type)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn PresentationURL(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "PresentationURL", 15usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).PresentationURL)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManufacturerName(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "ManufacturerName", 16usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).ManufacturerName)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManufacturerURL(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "ManufacturerURL", 17usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).ManufacturerURL)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModelName(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "ModelName", 18usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).ModelName)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModelNumber(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "ModelNumber", 19usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).ModelNumber)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "Description", 20usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).Description)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModelURL(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "ModelURL", 21usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).ModelURL)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn UPC(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "UPC", 22usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).UPC)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SerialNumber(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "SerialNumber", 23usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).SerialNumber)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn IconURL(this: *mut core::ffi::c_void, bstrencodingformat: core::mem::MaybeUninit<windows_core::BSTR>, lsizex: i32, lsizey: i32, lbitdepth: i32, pbstriconurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "IconURL", 24usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).IconURL)(this, bstrencodingformat, lsizex, lsizey, lbitdepth, pbstriconurl), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Services(this: *mut core::ffi::c_void, ppusservices: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevice", "Services", 25usize, |this| ((**(this as *const *const IUPnPDevice_Vtbl)).Services)(this, ppusservices), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsRootDevice,
+            #[cfg(feature = "Win32_System_Com")]
+            RootDevice,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RootDevice: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ParentDevice,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ParentDevice: 0,
+            HasChildren,
+            #[cfg(feature = "Win32_System_Com")]
+            Children,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Children: 0,
+            UniqueDeviceName,
+            FriendlyName,
+            Type,
+            PresentationURL,
+            ManufacturerName,
+            ManufacturerURL,
+            ModelName,
+            ModelNumber,
+            Description,
+            ModelURL,
+            UPC,
+            SerialNumber,
+            IconURL,
+            #[cfg(feature = "Win32_System_Com")]
+            Services,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Services: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPDevice as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUPnPDeviceControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, bstrxmldesc: core::mem::MaybeUninit<windows_core::BSTR>, bstrdeviceidentifier: core::mem::MaybeUninit<windows_core::BSTR>, bstrinitstring: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceControl", "Initialize", 3usize, |this| ((**(this as *const *const IUPnPDeviceControl_Vtbl)).Initialize)(this, bstrxmldesc, bstrdeviceidentifier, bstrinitstring), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetServiceObject(this: *mut core::ffi::c_void, bstrudn: core::mem::MaybeUninit<windows_core::BSTR>, bstrserviceid: core::mem::MaybeUninit<windows_core::BSTR>, ppdispservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceControl", "GetServiceObject", 4usize, |this| ((**(this as *const *const IUPnPDeviceControl_Vtbl)).GetServiceObject)(this, bstrudn, bstrserviceid, ppdispservice), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            #[cfg(feature = "Win32_System_Com")]
+            GetServiceObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetServiceObject: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPDeviceControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUPnPDeviceControlHttpHeaders_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAdditionalResponseHeaders(this: *mut core::ffi::c_void, bstrhttpresponseheaders: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceControlHttpHeaders", "GetAdditionalResponseHeaders", 3usize, |this| ((**(this as *const *const IUPnPDeviceControlHttpHeaders_Vtbl)).GetAdditionalResponseHeaders)(this, bstrhttpresponseheaders), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAdditionalResponseHeaders }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPDeviceControlHttpHeaders as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUPnPDeviceDocumentAccess_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocumentURL(this: *mut core::ffi::c_void, pbstrdocument: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceDocumentAccess", "GetDocumentURL", 3usize, |this| ((**(this as *const *const IUPnPDeviceDocumentAccess_Vtbl)).GetDocumentURL)(this, pbstrdocument), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDocumentURL }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPDeviceDocumentAccess as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUPnPDeviceDocumentAccessEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocument(this: *mut core::ffi::c_void, pbstrdocument: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceDocumentAccessEx", "GetDocument", 3usize, |this| ((**(this as *const *const IUPnPDeviceDocumentAccessEx_Vtbl)).GetDocument)(this, pbstrdocument), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDocument }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPDeviceDocumentAccessEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUPnPDeviceFinder_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FindByType(this: *mut core::ffi::c_void, bstrtypeuri: core::mem::MaybeUninit<windows_core::BSTR>, dwflags: u32, pdevices: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceFinder", "FindByType", 7usize, |this| ((**(this as *const *const IUPnPDeviceFinder_Vtbl)).FindByType)(this, bstrtypeuri, dwflags, pdevices), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAsyncFind(this: *mut core::ffi::c_void, bstrtypeuri: core::mem::MaybeUninit<windows_core::BSTR>, dwflags: u32, punkdevicefindercallback: *mut core::ffi::c_void, plfinddata: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceFinder", "CreateAsyncFind", 8usize, |this| ((**(this as *const *const IUPnPDeviceFinder_Vtbl)).CreateAsyncFind)(this, bstrtypeuri, dwflags, punkdevicefindercallback, plfinddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAsyncFind(this: *mut core::ffi::c_void, lfinddata: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceFinder", "StartAsyncFind", 9usize, |this| ((**(this as *const *const IUPnPDeviceFinder_Vtbl)).StartAsyncFind)(this, lfinddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelAsyncFind(this: *mut core::ffi::c_void, lfinddata: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceFinder", "CancelAsyncFind", 10usize, |this| ((**(this as *const *const IUPnPDeviceFinder_Vtbl)).CancelAsyncFind)(this, lfinddata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FindByUDN(this: *mut core::ffi::c_void, bstrudn: core::mem::MaybeUninit<windows_core::BSTR>, pdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceFinder", "FindByUDN", 11usize, |this| ((**(this as *const *const IUPnPDeviceFinder_Vtbl)).FindByUDN)(this, bstrudn, pdevice), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            FindByType,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FindByType: 0,
+            CreateAsyncFind,
+            StartAsyncFind,
+            CancelAsyncFind,
+            #[cfg(feature = "Win32_System_Com")]
+            FindByUDN,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FindByUDN: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPDeviceFinder as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUPnPDeviceFinderAddCallbackWithInterface_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DeviceAddedWithInterface(this: *mut core::ffi::c_void, lfinddata: i32, pdevice: *mut core::ffi::c_void, pguidinterface: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceFinderAddCallbackWithInterface", "DeviceAddedWithInterface", 3usize, |this| ((**(this as *const *const IUPnPDeviceFinderAddCallbackWithInterface_Vtbl)).DeviceAddedWithInterface)(this, lfinddata, pdevice, pguidinterface), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            DeviceAddedWithInterface,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DeviceAddedWithInterface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPDeviceFinderAddCallbackWithInterface as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUPnPDeviceFinderCallback_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DeviceAdded(this: *mut core::ffi::c_void, lfinddata: i32, pdevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceFinderCallback", "DeviceAdded", 3usize, |this| ((**(this as *const *const IUPnPDeviceFinderCallback_Vtbl)).DeviceAdded)(this, lfinddata, pdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceRemoved(this: *mut core::ffi::c_void, lfinddata: i32, bstrudn: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceFinderCallback", "DeviceRemoved", 4usize, |this| ((**(this as *const *const IUPnPDeviceFinderCallback_Vtbl)).DeviceRemoved)(this, lfinddata, bstrudn), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchComplete(this: *mut core::ffi::c_void, lfinddata: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceFinderCallback", "SearchComplete", 5usize, |this| ((**(this as *const *const IUPnPDeviceFinderCallback_Vtbl)).SearchComplete)(this, lfinddata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            DeviceAdded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DeviceAdded: 0,
+            DeviceRemoved,
+            SearchComplete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPDeviceFinderCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUPnPDeviceProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, bstrinitstring: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceProvider", "Start", 3usize, |this| ((**(this as *const *const IUPnPDeviceProvider_Vtbl)).Start)(this, bstrinitstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDeviceProvider", "Stop", 4usize, |this| ((**(this as *const *const IUPnPDeviceProvider_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, Stop }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPDeviceProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUPnPDevices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevices", "Count", 7usize, |this| ((**(this as *const *const IUPnPDevices_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevices", "_NewEnum", 8usize, |this| ((**(this as *const *const IUPnPDevices_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, bstrudn: core::mem::MaybeUninit<windows_core::BSTR>, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPDevices", "get_Item", 9usize, |this| ((**(this as *const *const IUPnPDevices_Vtbl)).get_Item)(this, bstrudn, ppdevice), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPDevices as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUPnPEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStateChanged(this: *mut core::ffi::c_void, cchanges: u32, rgdispidchanges: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPEventSink", "OnStateChanged", 3usize, |this| ((**(this as *const *const IUPnPEventSink_Vtbl)).OnStateChanged)(this, cchanges, rgdispidchanges), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStateChangedSafe(this: *mut core::ffi::c_void, varsadispidchanges: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPEventSink", "OnStateChangedSafe", 4usize, |this| ((**(this as *const *const IUPnPEventSink_Vtbl)).OnStateChangedSafe)(this, varsadispidchanges), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnStateChanged, OnStateChangedSafe }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUPnPEventSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, pessubscriber: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPEventSource", "Advise", 3usize, |this| ((**(this as *const *const IUPnPEventSource_Vtbl)).Advise)(this, pessubscriber), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, pessubscriber: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPEventSource", "Unadvise", 4usize, |this| ((**(this as *const *const IUPnPEventSource_Vtbl)).Unadvise)(this, pessubscriber), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Advise, Unadvise }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPEventSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUPnPHttpHeaderControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddRequestHeaders(this: *mut core::ffi::c_void, bstrhttpheaders: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPHttpHeaderControl", "AddRequestHeaders", 3usize, |this| ((**(this as *const *const IUPnPHttpHeaderControl_Vtbl)).AddRequestHeaders)(this, bstrhttpheaders), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddRequestHeaders }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPHttpHeaderControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUPnPRegistrar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterDevice(this: *mut core::ffi::c_void, bstrxmldesc: core::mem::MaybeUninit<windows_core::BSTR>, bstrprogiddevicecontrolclass: core::mem::MaybeUninit<windows_core::BSTR>, bstrinitstring: core::mem::MaybeUninit<windows_core::BSTR>, bstrcontainerid: core::mem::MaybeUninit<windows_core::BSTR>, bstrresourcepath: core::mem::MaybeUninit<windows_core::BSTR>, nlifetime: i32, pbstrdeviceidentifier: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPRegistrar", "RegisterDevice", 3usize, |this| ((**(this as *const *const IUPnPRegistrar_Vtbl)).RegisterDevice)(this, bstrxmldesc, bstrprogiddevicecontrolclass, bstrinitstring, bstrcontainerid, bstrresourcepath, nlifetime, pbstrdeviceidentifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterRunningDevice(this: *mut core::ffi::c_void, bstrxmldesc: core::mem::MaybeUninit<windows_core::BSTR>, punkdevicecontrol: *mut core::ffi::c_void, bstrinitstring: core::mem::MaybeUninit<windows_core::BSTR>, bstrresourcepath: core::mem::MaybeUninit<windows_core::BSTR>, nlifetime: i32, pbstrdeviceidentifier: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPRegistrar", "RegisterRunningDevice", 4usize, |this| ((**(this as *const *const IUPnPRegistrar_Vtbl)).RegisterRunningDevice)(this, bstrxmldesc, punkdevicecontrol, bstrinitstring, bstrresourcepath, nlifetime, pbstrdeviceidentifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterDeviceProvider(this: *mut core::ffi::c_void, bstrprovidername: core::mem::MaybeUninit<windows_core::BSTR>, bstrprogidproviderclass: core::mem::MaybeUninit<windows_core::BSTR>, bstrinitstring: core::mem::MaybeUninit<windows_core::BSTR>, bstrcontainerid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPRegistrar", "RegisterDeviceProvider", 5usize, |this| ((**(this as *const *const IUPnPRegistrar_Vtbl)).RegisterDeviceProvider)(this, bstrprovidername, bstrprogidproviderclass, bstrinitstring, bstrcontainerid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUniqueDeviceName(this: *mut core::ffi::c_void, bstrdeviceidentifier: core::mem::MaybeUninit<windows_core::BSTR>, bstrtemplateudn: core::mem::MaybeUninit<windows_core::BSTR>, pbstrudn: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPRegistrar", "GetUniqueDeviceName", 6usize, |this| ((**(this as *const *const IUPnPRegistrar_Vtbl)).GetUniqueDeviceName)(this, bstrdeviceidentifier, bstrtemplateudn, pbstrudn), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterDevice(this: *mut core::ffi::c_void, bstrdeviceidentifier: core::mem::MaybeUninit<windows_core::BSTR>, fpermanent: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPRegistrar", "UnregisterDevice", 7usize, |this| ((**(this as *const *const IUPnPRegistrar_Vtbl)).UnregisterDevice)(this, bstrdeviceidentifier, fpermanent), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterDeviceProvider(this: *mut core::ffi::c_void, bstrprovidername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPRegistrar", "UnregisterDeviceProvider", 8usize, |this| ((**(this as *const *const IUPnPRegistrar_Vtbl)).UnregisterDeviceProvider)(this, bstrprovidername), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterDevice,
+            RegisterRunningDevice,
+            RegisterDeviceProvider,
+            GetUniqueDeviceName,
+            UnregisterDevice,
+            UnregisterDeviceProvider,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPRegistrar as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUPnPRemoteEndpointInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDwordValue(this: *mut core::ffi::c_void, bstrvaluename: core::mem::MaybeUninit<windows_core::BSTR>, pdwvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPRemoteEndpointInfo", "GetDwordValue", 3usize, |this| ((**(this as *const *const IUPnPRemoteEndpointInfo_Vtbl)).GetDwordValue)(this, bstrvaluename, pdwvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringValue(this: *mut core::ffi::c_void, bstrvaluename: core::mem::MaybeUninit<windows_core::BSTR>, pbstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPRemoteEndpointInfo", "GetStringValue", 4usize, |this| ((**(this as *const *const IUPnPRemoteEndpointInfo_Vtbl)).GetStringValue)(this, bstrvaluename, pbstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGuidValue(this: *mut core::ffi::c_void, bstrvaluename: core::mem::MaybeUninit<windows_core::BSTR>, pguidvalue: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPRemoteEndpointInfo", "GetGuidValue", 5usize, |this| ((**(this as *const *const IUPnPRemoteEndpointInfo_Vtbl)).GetGuidValue)(this, bstrvaluename, pguidvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDwordValue, GetStringValue, GetGuidValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPRemoteEndpointInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUPnPReregistrar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReregisterDevice(this: *mut core::ffi::c_void, bstrdeviceidentifier: core::mem::MaybeUninit<windows_core::BSTR>, bstrxmldesc: core::mem::MaybeUninit<windows_core::BSTR>, bstrprogiddevicecontrolclass: core::mem::MaybeUninit<windows_core::BSTR>, bstrinitstring: core::mem::MaybeUninit<windows_core::BSTR>, bstrcontainerid: core::mem::MaybeUninit<windows_core::BSTR>, bstrresourcepath: core::mem::MaybeUninit<windows_core::BSTR>, nlifetime: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPReregistrar", "ReregisterDevice", 3usize, |this| ((**(this as *const *const IUPnPReregistrar_Vtbl)).ReregisterDevice)(this, bstrdeviceidentifier, bstrxmldesc, bstrprogiddevicecontrolclass, bstrinitstring, bstrcontainerid, bstrresourcepath, nlifetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReregisterRunningDevice(this: *mut core::ffi::c_void, bstrdeviceidentifier: core::mem::MaybeUninit<windows_core::BSTR>, bstrxmldesc: core::mem::MaybeUninit<windows_core::BSTR>, punkdevicecontrol: *mut core::ffi::c_void, bstrinitstring: core::mem::MaybeUninit<windows_core::BSTR>, bstrresourcepath: core::mem::MaybeUninit<windows_core::BSTR>, nlifetime: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPReregistrar", "ReregisterRunningDevice", 4usize, |this| ((**(this as *const *const IUPnPReregistrar_Vtbl)).ReregisterRunningDevice)(this, bstrdeviceidentifier, bstrxmldesc, punkdevicecontrol, bstrinitstring, bstrresourcepath, nlifetime), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReregisterDevice, ReregisterRunningDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPReregistrar as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUPnPService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryStateVariable(this: *mut core::ffi::c_void, bstrvariablename: core::mem::MaybeUninit<windows_core::BSTR>, pvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPService", "QueryStateVariable", 7usize, |this| ((**(this as *const *const IUPnPService_Vtbl)).QueryStateVariable)(this, bstrvariablename, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvokeAction(this: *mut core::ffi::c_void, bstractionname: core::mem::MaybeUninit<windows_core::BSTR>, vinactionargs: core::mem::MaybeUninit<windows_core::VARIANT>, pvoutactionargs: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pvretval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPService", "InvokeAction", 8usize, |this| ((**(this as *const *const IUPnPService_Vtbl)).InvokeAction)(this, bstractionname, vinactionargs, pvoutactionargs, pvretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceTypeIdentifier(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPService", "ServiceTypeIdentifier", 9usize, |this| ((**(this as *const *const IUPnPService_Vtbl)).ServiceTypeIdentifier)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddCallback(this: *mut core::ffi::c_void, punkcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPService", "AddCallback", 10usize, |this| ((**(this as *const *const IUPnPService_Vtbl)).AddCallback)(this, punkcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pbstrid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPService", "Id", 11usize, |this| ((**(this as *const *const IUPnPService_Vtbl)).Id)(this, pbstrid), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastTransportStatus(this: *mut core::ffi::c_void, plvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPService", "LastTransportStatus", 12usize, |this| ((**(this as *const *const IUPnPService_Vtbl)).LastTransportStatus)(this, plvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryStateVariable,
+            InvokeAction,
+            ServiceTypeIdentifier,
+            AddCallback,
+            Id,
+            LastTransportStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPService as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUPnPServiceAsync_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginInvokeAction(this: *mut core::ffi::c_void, bstractionname: core::mem::MaybeUninit<windows_core::BSTR>, vinactionargs: core::mem::MaybeUninit<windows_core::VARIANT>, pasyncresult: *mut core::ffi::c_void, pullrequestid: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServiceAsync", "BeginInvokeAction", 3usize, |this| ((**(this as *const *const IUPnPServiceAsync_Vtbl)).BeginInvokeAction)(this, bstractionname, vinactionargs, pasyncresult, pullrequestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndInvokeAction(this: *mut core::ffi::c_void, ullrequestid: u64, pvoutactionargs: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pvretval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServiceAsync", "EndInvokeAction", 4usize, |this| ((**(this as *const *const IUPnPServiceAsync_Vtbl)).EndInvokeAction)(this, ullrequestid, pvoutactionargs, pvretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginQueryStateVariable(this: *mut core::ffi::c_void, bstrvariablename: core::mem::MaybeUninit<windows_core::BSTR>, pasyncresult: *mut core::ffi::c_void, pullrequestid: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServiceAsync", "BeginQueryStateVariable", 5usize, |this| ((**(this as *const *const IUPnPServiceAsync_Vtbl)).BeginQueryStateVariable)(this, bstrvariablename, pasyncresult, pullrequestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndQueryStateVariable(this: *mut core::ffi::c_void, ullrequestid: u64, pvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServiceAsync", "EndQueryStateVariable", 6usize, |this| ((**(this as *const *const IUPnPServiceAsync_Vtbl)).EndQueryStateVariable)(this, ullrequestid, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginSubscribeToEvents(this: *mut core::ffi::c_void, punkcallback: *mut core::ffi::c_void, pasyncresult: *mut core::ffi::c_void, pullrequestid: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServiceAsync", "BeginSubscribeToEvents", 7usize, |this| ((**(this as *const *const IUPnPServiceAsync_Vtbl)).BeginSubscribeToEvents)(this, punkcallback, pasyncresult, pullrequestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndSubscribeToEvents(this: *mut core::ffi::c_void, ullrequestid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServiceAsync", "EndSubscribeToEvents", 8usize, |this| ((**(this as *const *const IUPnPServiceAsync_Vtbl)).EndSubscribeToEvents)(this, ullrequestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginSCPDDownload(this: *mut core::ffi::c_void, pasyncresult: *mut core::ffi::c_void, pullrequestid: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServiceAsync", "BeginSCPDDownload", 9usize, |this| ((**(this as *const *const IUPnPServiceAsync_Vtbl)).BeginSCPDDownload)(this, pasyncresult, pullrequestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndSCPDDownload(this: *mut core::ffi::c_void, ullrequestid: u64, pbstrscpddoc: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServiceAsync", "EndSCPDDownload", 10usize, |this| ((**(this as *const *const IUPnPServiceAsync_Vtbl)).EndSCPDDownload)(this, ullrequestid, pbstrscpddoc), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelAsyncOperation(this: *mut core::ffi::c_void, ullrequestid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServiceAsync", "CancelAsyncOperation", 11usize, |this| ((**(this as *const *const IUPnPServiceAsync_Vtbl)).CancelAsyncOperation)(this, ullrequestid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BeginInvokeAction,
+            EndInvokeAction,
+            BeginQueryStateVariable,
+            EndQueryStateVariable,
+            BeginSubscribeToEvents,
+            EndSubscribeToEvents,
+            BeginSCPDDownload,
+            EndSCPDDownload,
+            CancelAsyncOperation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPServiceAsync as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUPnPServiceCallback_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn StateVariableChanged(this: *mut core::ffi::c_void, pus: *mut core::ffi::c_void, pcwszstatevarname: windows_core::PCWSTR, vavalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServiceCallback", "StateVariableChanged", 3usize, |this| ((**(this as *const *const IUPnPServiceCallback_Vtbl)).StateVariableChanged)(this, pus, pcwszstatevarname, vavalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ServiceInstanceDied(this: *mut core::ffi::c_void, pus: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServiceCallback", "ServiceInstanceDied", 4usize, |this| ((**(this as *const *const IUPnPServiceCallback_Vtbl)).ServiceInstanceDied)(this, pus), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            StateVariableChanged,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            StateVariableChanged: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ServiceInstanceDied,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ServiceInstanceDied: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPServiceCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUPnPServiceDocumentAccess_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocumentURL(this: *mut core::ffi::c_void, pbstrdocurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServiceDocumentAccess", "GetDocumentURL", 3usize, |this| ((**(this as *const *const IUPnPServiceDocumentAccess_Vtbl)).GetDocumentURL)(this, pbstrdocurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocument(this: *mut core::ffi::c_void, pbstrdoc: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServiceDocumentAccess", "GetDocument", 4usize, |this| ((**(this as *const *const IUPnPServiceDocumentAccess_Vtbl)).GetDocument)(this, pbstrdoc), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDocumentURL, GetDocument }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPServiceDocumentAccess as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUPnPServiceEnumProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetServiceEnumProperty(this: *mut core::ffi::c_void, dwmask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServiceEnumProperty", "SetServiceEnumProperty", 3usize, |this| ((**(this as *const *const IUPnPServiceEnumProperty_Vtbl)).SetServiceEnumProperty)(this, dwmask), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetServiceEnumProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPServiceEnumProperty as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUPnPServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServices", "Count", 7usize, |this| ((**(this as *const *const IUPnPServices_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServices", "_NewEnum", 8usize, |this| ((**(this as *const *const IUPnPServices_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, bstrserviceid: core::mem::MaybeUninit<windows_core::BSTR>, ppservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPServices", "get_Item", 9usize, |this| ((**(this as *const *const IUPnPServices_Vtbl)).get_Item)(this, bstrserviceid, ppservice), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPServices as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -6122,6 +6122,7 @@ pub struct FAXROUTE_ENABLE(pub i32);
 impl windows_core::TypeKind for FAXROUTE_ENABLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAXROUTE_ENABLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAXROUTE_ENABLE").field(&self.0).finish()
@@ -6133,6 +6134,7 @@ pub struct FAX_ACCESS_RIGHTS_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_ACCESS_RIGHTS_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_ACCESS_RIGHTS_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_ACCESS_RIGHTS_ENUM").field(&self.0).finish()
@@ -6144,6 +6146,7 @@ pub struct FAX_ACCESS_RIGHTS_ENUM_2(pub i32);
 impl windows_core::TypeKind for FAX_ACCESS_RIGHTS_ENUM_2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_ACCESS_RIGHTS_ENUM_2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_ACCESS_RIGHTS_ENUM_2").field(&self.0).finish()
@@ -6155,6 +6158,7 @@ pub struct FAX_ACCOUNT_EVENTS_TYPE_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_ACCOUNT_EVENTS_TYPE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_ACCOUNT_EVENTS_TYPE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_ACCOUNT_EVENTS_TYPE_ENUM").field(&self.0).finish()
@@ -6166,6 +6170,7 @@ pub struct FAX_COVERPAGE_TYPE_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_COVERPAGE_TYPE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_COVERPAGE_TYPE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_COVERPAGE_TYPE_ENUM").field(&self.0).finish()
@@ -6177,6 +6182,7 @@ pub struct FAX_DEVICE_RECEIVE_MODE_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_DEVICE_RECEIVE_MODE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_DEVICE_RECEIVE_MODE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_DEVICE_RECEIVE_MODE_ENUM").field(&self.0).finish()
@@ -6188,6 +6194,7 @@ pub struct FAX_ENUM_DELIVERY_REPORT_TYPES(pub i32);
 impl windows_core::TypeKind for FAX_ENUM_DELIVERY_REPORT_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_ENUM_DELIVERY_REPORT_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_ENUM_DELIVERY_REPORT_TYPES").field(&self.0).finish()
@@ -6199,6 +6206,7 @@ pub struct FAX_ENUM_DEVICE_ID_SOURCE(pub i32);
 impl windows_core::TypeKind for FAX_ENUM_DEVICE_ID_SOURCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_ENUM_DEVICE_ID_SOURCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_ENUM_DEVICE_ID_SOURCE").field(&self.0).finish()
@@ -6210,6 +6218,7 @@ pub struct FAX_ENUM_JOB_COMMANDS(pub i32);
 impl windows_core::TypeKind for FAX_ENUM_JOB_COMMANDS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_ENUM_JOB_COMMANDS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_ENUM_JOB_COMMANDS").field(&self.0).finish()
@@ -6221,6 +6230,7 @@ pub struct FAX_ENUM_JOB_SEND_ATTRIBUTES(pub i32);
 impl windows_core::TypeKind for FAX_ENUM_JOB_SEND_ATTRIBUTES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_ENUM_JOB_SEND_ATTRIBUTES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_ENUM_JOB_SEND_ATTRIBUTES").field(&self.0).finish()
@@ -6232,6 +6242,7 @@ pub struct FAX_ENUM_LOG_CATEGORIES(pub i32);
 impl windows_core::TypeKind for FAX_ENUM_LOG_CATEGORIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_ENUM_LOG_CATEGORIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_ENUM_LOG_CATEGORIES").field(&self.0).finish()
@@ -6243,6 +6254,7 @@ pub struct FAX_ENUM_LOG_LEVELS(pub i32);
 impl windows_core::TypeKind for FAX_ENUM_LOG_LEVELS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_ENUM_LOG_LEVELS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_ENUM_LOG_LEVELS").field(&self.0).finish()
@@ -6254,6 +6266,7 @@ pub struct FAX_ENUM_PORT_OPEN_TYPE(pub i32);
 impl windows_core::TypeKind for FAX_ENUM_PORT_OPEN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_ENUM_PORT_OPEN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_ENUM_PORT_OPEN_TYPE").field(&self.0).finish()
@@ -6265,6 +6278,7 @@ pub struct FAX_GROUP_STATUS_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_GROUP_STATUS_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_GROUP_STATUS_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_GROUP_STATUS_ENUM").field(&self.0).finish()
@@ -6276,6 +6290,7 @@ pub struct FAX_JOB_EXTENDED_STATUS_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_JOB_EXTENDED_STATUS_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_JOB_EXTENDED_STATUS_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_JOB_EXTENDED_STATUS_ENUM").field(&self.0).finish()
@@ -6287,6 +6302,7 @@ pub struct FAX_JOB_OPERATIONS_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_JOB_OPERATIONS_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_JOB_OPERATIONS_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_JOB_OPERATIONS_ENUM").field(&self.0).finish()
@@ -6298,6 +6314,7 @@ pub struct FAX_JOB_STATUS_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_JOB_STATUS_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_JOB_STATUS_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_JOB_STATUS_ENUM").field(&self.0).finish()
@@ -6309,6 +6326,7 @@ pub struct FAX_JOB_TYPE_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_JOB_TYPE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_JOB_TYPE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_JOB_TYPE_ENUM").field(&self.0).finish()
@@ -6320,6 +6338,7 @@ pub struct FAX_LOG_LEVEL_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_LOG_LEVEL_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_LOG_LEVEL_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_LOG_LEVEL_ENUM").field(&self.0).finish()
@@ -6331,6 +6350,7 @@ pub struct FAX_PRIORITY_TYPE_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_PRIORITY_TYPE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_PRIORITY_TYPE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_PRIORITY_TYPE_ENUM").field(&self.0).finish()
@@ -6342,6 +6362,7 @@ pub struct FAX_PROVIDER_STATUS_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_PROVIDER_STATUS_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_PROVIDER_STATUS_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_PROVIDER_STATUS_ENUM").field(&self.0).finish()
@@ -6353,6 +6374,7 @@ pub struct FAX_RECEIPT_TYPE_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_RECEIPT_TYPE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_RECEIPT_TYPE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_RECEIPT_TYPE_ENUM").field(&self.0).finish()
@@ -6364,6 +6386,7 @@ pub struct FAX_ROUTING_RULE_CODE_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_ROUTING_RULE_CODE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_ROUTING_RULE_CODE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_ROUTING_RULE_CODE_ENUM").field(&self.0).finish()
@@ -6375,6 +6398,7 @@ pub struct FAX_RULE_STATUS_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_RULE_STATUS_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_RULE_STATUS_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_RULE_STATUS_ENUM").field(&self.0).finish()
@@ -6386,6 +6410,7 @@ pub struct FAX_SCHEDULE_TYPE_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_SCHEDULE_TYPE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_SCHEDULE_TYPE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_SCHEDULE_TYPE_ENUM").field(&self.0).finish()
@@ -6397,6 +6422,7 @@ pub struct FAX_SERVER_APIVERSION_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_SERVER_APIVERSION_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_SERVER_APIVERSION_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_SERVER_APIVERSION_ENUM").field(&self.0).finish()
@@ -6408,6 +6434,7 @@ pub struct FAX_SERVER_EVENTS_TYPE_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_SERVER_EVENTS_TYPE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_SERVER_EVENTS_TYPE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_SERVER_EVENTS_TYPE_ENUM").field(&self.0).finish()
@@ -6419,6 +6446,7 @@ pub struct FAX_SMTP_AUTHENTICATION_TYPE_ENUM(pub i32);
 impl windows_core::TypeKind for FAX_SMTP_AUTHENTICATION_TYPE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAX_SMTP_AUTHENTICATION_TYPE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAX_SMTP_AUTHENTICATION_TYPE_ENUM").field(&self.0).finish()
@@ -6430,6 +6458,7 @@ pub struct STI_DEVICE_MJ_TYPE(pub i32);
 impl windows_core::TypeKind for STI_DEVICE_MJ_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STI_DEVICE_MJ_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STI_DEVICE_MJ_TYPE").field(&self.0).finish()
@@ -6441,13 +6470,15 @@ pub struct SendToMode(pub i32);
 impl windows_core::TypeKind for SendToMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SendToMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SendToMode").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_CONFIGURATIONA {
     pub SizeOfStruct: u32,
     pub Retries: u32,
@@ -6472,7 +6503,8 @@ impl Default for FAX_CONFIGURATIONA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_CONFIGURATIONW {
     pub SizeOfStruct: u32,
     pub Retries: u32,
@@ -6498,7 +6530,8 @@ impl Default for FAX_CONFIGURATIONW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_CONTEXT_INFOA {
     pub SizeOfStruct: u32,
     pub hDC: super::super::Graphics::Gdi::HDC,
@@ -6516,7 +6549,8 @@ impl Default for FAX_CONTEXT_INFOA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_CONTEXT_INFOW {
     pub SizeOfStruct: u32,
     pub hDC: super::super::Graphics::Gdi::HDC,
@@ -6533,7 +6567,8 @@ impl Default for FAX_CONTEXT_INFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_COVERPAGE_INFOA {
     pub SizeOfStruct: u32,
     pub CoverPageName: windows_core::PCSTR,
@@ -6574,7 +6609,8 @@ impl Default for FAX_COVERPAGE_INFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_COVERPAGE_INFOW {
     pub SizeOfStruct: u32,
     pub CoverPageName: windows_core::PCWSTR,
@@ -6615,7 +6651,8 @@ impl Default for FAX_COVERPAGE_INFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_DEVICE_STATUSA {
     pub SizeOfStruct: u32,
     pub CallerId: windows_core::PCSTR,
@@ -6647,7 +6684,8 @@ impl Default for FAX_DEVICE_STATUSA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_DEVICE_STATUSW {
     pub SizeOfStruct: u32,
     pub CallerId: windows_core::PCWSTR,
@@ -6679,7 +6717,8 @@ impl Default for FAX_DEVICE_STATUSW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_DEV_STATUS {
     pub SizeOfStruct: u32,
     pub StatusId: u32,
@@ -6700,7 +6739,8 @@ impl Default for FAX_DEV_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_EVENTA {
     pub SizeOfStruct: u32,
     pub TimeStamp: super::super::Foundation::FILETIME,
@@ -6717,7 +6757,8 @@ impl Default for FAX_EVENTA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_EVENTW {
     pub SizeOfStruct: u32,
     pub TimeStamp: super::super::Foundation::FILETIME,
@@ -6734,7 +6775,8 @@ impl Default for FAX_EVENTW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_GLOBAL_ROUTING_INFOA {
     pub SizeOfStruct: u32,
     pub Priority: u32,
@@ -6753,7 +6795,8 @@ impl Default for FAX_GLOBAL_ROUTING_INFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_GLOBAL_ROUTING_INFOW {
     pub SizeOfStruct: u32,
     pub Priority: u32,
@@ -6772,7 +6815,8 @@ impl Default for FAX_GLOBAL_ROUTING_INFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_JOB_ENTRYA {
     pub SizeOfStruct: u32,
     pub JobId: u32,
@@ -6804,7 +6848,8 @@ impl Default for FAX_JOB_ENTRYA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_JOB_ENTRYW {
     pub SizeOfStruct: u32,
     pub JobId: u32,
@@ -6836,7 +6881,8 @@ impl Default for FAX_JOB_ENTRYW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_JOB_PARAMA {
     pub SizeOfStruct: u32,
     pub RecipientNumber: windows_core::PCSTR,
@@ -6863,7 +6909,8 @@ impl Default for FAX_JOB_PARAMA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_JOB_PARAMW {
     pub SizeOfStruct: u32,
     pub RecipientNumber: windows_core::PCWSTR,
@@ -6890,7 +6937,8 @@ impl Default for FAX_JOB_PARAMW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_LOG_CATEGORYA {
     pub Name: windows_core::PCSTR,
     pub Category: u32,
@@ -6905,7 +6953,8 @@ impl Default for FAX_LOG_CATEGORYA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_LOG_CATEGORYW {
     pub Name: windows_core::PCWSTR,
     pub Category: u32,
@@ -6920,7 +6969,8 @@ impl Default for FAX_LOG_CATEGORYW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_PORT_INFOA {
     pub SizeOfStruct: u32,
     pub DeviceId: u32,
@@ -6941,7 +6991,8 @@ impl Default for FAX_PORT_INFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_PORT_INFOW {
     pub SizeOfStruct: u32,
     pub DeviceId: u32,
@@ -6962,7 +7013,8 @@ impl Default for FAX_PORT_INFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_PRINT_INFOA {
     pub SizeOfStruct: u32,
     pub DocName: windows_core::PCSTR,
@@ -6985,7 +7037,8 @@ impl Default for FAX_PRINT_INFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_PRINT_INFOW {
     pub SizeOfStruct: u32,
     pub DocName: windows_core::PCWSTR,
@@ -7008,7 +7061,8 @@ impl Default for FAX_PRINT_INFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_RECEIVE {
     pub SizeOfStruct: u32,
     pub FileName: windows_core::PWSTR,
@@ -7025,7 +7079,8 @@ impl Default for FAX_RECEIVE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_ROUTE {
     pub SizeOfStruct: u32,
     pub JobId: u32,
@@ -7052,7 +7107,8 @@ impl Default for FAX_ROUTE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_ROUTE_CALLBACKROUTINES {
     pub SizeOfStruct: u32,
     pub FaxRouteAddFile: PFAXROUTEADDFILE,
@@ -7070,7 +7126,8 @@ impl Default for FAX_ROUTE_CALLBACKROUTINES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_ROUTING_METHODA {
     pub SizeOfStruct: u32,
     pub DeviceId: u32,
@@ -7091,7 +7148,8 @@ impl Default for FAX_ROUTING_METHODA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_ROUTING_METHODW {
     pub SizeOfStruct: u32,
     pub DeviceId: u32,
@@ -7112,7 +7170,8 @@ impl Default for FAX_ROUTING_METHODW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_SEND {
     pub SizeOfStruct: u32,
     pub FileName: windows_core::PWSTR,
@@ -7133,7 +7192,8 @@ impl Default for FAX_SEND {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FAX_TIME {
     pub Hour: u16,
     pub Minute: u16,
@@ -7197,7 +7257,8 @@ pub const FaxSecurity2: windows_core::GUID = windows_core::GUID::from_u128(0x735
 pub const FaxSender: windows_core::GUID = windows_core::GUID::from_u128(0x265d84d0_1850_4360_b7c8_758bbb5f0b96);
 pub const FaxServer: windows_core::GUID = windows_core::GUID::from_u128(0xcda8acb0_8cf5_4f6c_9ba2_5931d40c8cae);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STINOTIFY {
     pub dwSize: u32,
     pub guidNotificationCode: windows_core::GUID,
@@ -7212,7 +7273,8 @@ impl Default for STINOTIFY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STISUBSCRIBE {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -7230,7 +7292,8 @@ impl Default for STISUBSCRIBE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STI_DEVICE_INFORMATIONW {
     pub dwSize: u32,
     pub DeviceType: u32,
@@ -7252,7 +7315,8 @@ impl Default for STI_DEVICE_INFORMATIONW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STI_DEVICE_STATUS {
     pub dwSize: u32,
     pub StatusMask: u32,
@@ -7270,7 +7334,8 @@ impl Default for STI_DEVICE_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STI_DEV_CAPS {
     pub dwGeneric: u32,
 }
@@ -7283,7 +7348,8 @@ impl Default for STI_DEV_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STI_DIAG {
     pub dwSize: u32,
     pub dwBasicDiagCode: u32,
@@ -7300,7 +7366,8 @@ impl Default for STI_DIAG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STI_USD_CAPS {
     pub dwVersion: u32,
     pub dwGenericCaps: u32,
@@ -7314,7 +7381,8 @@ impl Default for STI_USD_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STI_WIA_DEVICE_INFORMATIONW {
     pub dwSize: u32,
     pub DeviceType: u32,
@@ -7338,7 +7406,8 @@ impl Default for STI_WIA_DEVICE_INFORMATIONW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _ERROR_INFOW {
     pub dwSize: u32,
     pub dwGenericError: u32,
@@ -7451,3 +7520,5 @@ pub type PFAX_SEND_CALLBACK = Option<unsafe extern "system" fn(faxhandle: super:
 pub type PFAX_SERVICE_CALLBACK = Option<unsafe extern "system" fn(faxhandle: super::super::Foundation::HANDLE, deviceid: u32, param1: usize, param2: usize, param3: usize) -> super::super::Foundation::BOOL>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,3844 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxAccount_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccountName(this: *mut core::ffi::c_void, pbstraccountname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccount", "AccountName", 7usize, |this| ((**(this as *const *const IFaxAccount_Vtbl)).AccountName)(this, pbstraccountname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Folders(this: *mut core::ffi::c_void, ppfolders: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccount", "Folders", 8usize, |this| ((**(this as *const *const IFaxAccount_Vtbl)).Folders)(this, ppfolders), |result| Some(*result))
+        }
+        unsafe extern "system" fn ListenToAccountEvents(this: *mut core::ffi::c_void, eventtypes: FAX_ACCOUNT_EVENTS_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccount", "ListenToAccountEvents", 9usize, |this| ((**(this as *const *const IFaxAccount_Vtbl)).ListenToAccountEvents)(this, eventtypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisteredEvents(this: *mut core::ffi::c_void, pregisteredevents: *mut FAX_ACCOUNT_EVENTS_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccount", "RegisteredEvents", 10usize, |this| ((**(this as *const *const IFaxAccount_Vtbl)).RegisteredEvents)(this, pregisteredevents), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AccountName,
+            #[cfg(feature = "Win32_System_Com")]
+            Folders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Folders: 0,
+            ListenToAccountEvents,
+            RegisteredEvents,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxAccount as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxAccountFolders_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OutgoingQueue(this: *mut core::ffi::c_void, pfaxoutgoingqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountFolders", "OutgoingQueue", 7usize, |this| ((**(this as *const *const IFaxAccountFolders_Vtbl)).OutgoingQueue)(this, pfaxoutgoingqueue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IncomingQueue(this: *mut core::ffi::c_void, pfaxincomingqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountFolders", "IncomingQueue", 8usize, |this| ((**(this as *const *const IFaxAccountFolders_Vtbl)).IncomingQueue)(this, pfaxincomingqueue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IncomingArchive(this: *mut core::ffi::c_void, pfaxincomingarchive: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountFolders", "IncomingArchive", 9usize, |this| ((**(this as *const *const IFaxAccountFolders_Vtbl)).IncomingArchive)(this, pfaxincomingarchive), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OutgoingArchive(this: *mut core::ffi::c_void, pfaxoutgoingarchive: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountFolders", "OutgoingArchive", 10usize, |this| ((**(this as *const *const IFaxAccountFolders_Vtbl)).OutgoingArchive)(this, pfaxoutgoingarchive), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OutgoingQueue,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OutgoingQueue: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            IncomingQueue,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IncomingQueue: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            IncomingArchive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IncomingArchive: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OutgoingArchive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OutgoingArchive: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxAccountFolders as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxAccountIncomingArchive_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SizeLow(this: *mut core::ffi::c_void, plsizelow: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountIncomingArchive", "SizeLow", 7usize, |this| ((**(this as *const *const IFaxAccountIncomingArchive_Vtbl)).SizeLow)(this, plsizelow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeHigh(this: *mut core::ffi::c_void, plsizehigh: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountIncomingArchive", "SizeHigh", 8usize, |this| ((**(this as *const *const IFaxAccountIncomingArchive_Vtbl)).SizeHigh)(this, plsizehigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountIncomingArchive", "Refresh", 9usize, |this| ((**(this as *const *const IFaxAccountIncomingArchive_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetMessages(this: *mut core::ffi::c_void, lprefetchsize: i32, pfaxincomingmessageiterator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountIncomingArchive", "GetMessages", 10usize, |this| ((**(this as *const *const IFaxAccountIncomingArchive_Vtbl)).GetMessages)(this, lprefetchsize, pfaxincomingmessageiterator), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetMessage(this: *mut core::ffi::c_void, bstrmessageid: core::mem::MaybeUninit<windows_core::BSTR>, pfaxincomingmessage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountIncomingArchive", "GetMessage", 11usize, |this| ((**(this as *const *const IFaxAccountIncomingArchive_Vtbl)).GetMessage)(this, bstrmessageid, pfaxincomingmessage), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SizeLow,
+            SizeHigh,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            GetMessages,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetMessages: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetMessage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetMessage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxAccountIncomingArchive as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxAccountIncomingQueue_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetJobs(this: *mut core::ffi::c_void, pfaxincomingjobs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountIncomingQueue", "GetJobs", 7usize, |this| ((**(this as *const *const IFaxAccountIncomingQueue_Vtbl)).GetJobs)(this, pfaxincomingjobs), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetJob(this: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>, pfaxincomingjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountIncomingQueue", "GetJob", 8usize, |this| ((**(this as *const *const IFaxAccountIncomingQueue_Vtbl)).GetJob)(this, bstrjobid, pfaxincomingjob), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetJobs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetJobs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetJob,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetJob: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxAccountIncomingQueue as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxAccountNotify_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnIncomingJobAdded(this: *mut core::ffi::c_void, pfaxaccount: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountNotify", "OnIncomingJobAdded", 7usize, |this| ((**(this as *const *const IFaxAccountNotify_Vtbl)).OnIncomingJobAdded)(this, pfaxaccount, bstrjobid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnIncomingJobRemoved(this: *mut core::ffi::c_void, pfaxaccount: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountNotify", "OnIncomingJobRemoved", 8usize, |this| ((**(this as *const *const IFaxAccountNotify_Vtbl)).OnIncomingJobRemoved)(this, pfaxaccount, bstrjobid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnIncomingJobChanged(this: *mut core::ffi::c_void, pfaxaccount: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>, pjobstatus: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountNotify", "OnIncomingJobChanged", 9usize, |this| ((**(this as *const *const IFaxAccountNotify_Vtbl)).OnIncomingJobChanged)(this, pfaxaccount, bstrjobid, pjobstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnOutgoingJobAdded(this: *mut core::ffi::c_void, pfaxaccount: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountNotify", "OnOutgoingJobAdded", 10usize, |this| ((**(this as *const *const IFaxAccountNotify_Vtbl)).OnOutgoingJobAdded)(this, pfaxaccount, bstrjobid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnOutgoingJobRemoved(this: *mut core::ffi::c_void, pfaxaccount: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountNotify", "OnOutgoingJobRemoved", 11usize, |this| ((**(this as *const *const IFaxAccountNotify_Vtbl)).OnOutgoingJobRemoved)(this, pfaxaccount, bstrjobid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnOutgoingJobChanged(this: *mut core::ffi::c_void, pfaxaccount: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>, pjobstatus: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountNotify", "OnOutgoingJobChanged", 12usize, |this| ((**(this as *const *const IFaxAccountNotify_Vtbl)).OnOutgoingJobChanged)(this, pfaxaccount, bstrjobid, pjobstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnIncomingMessageAdded(this: *mut core::ffi::c_void, pfaxaccount: *mut core::ffi::c_void, bstrmessageid: core::mem::MaybeUninit<windows_core::BSTR>, faddedtoreceivefolder: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountNotify", "OnIncomingMessageAdded", 13usize, |this| ((**(this as *const *const IFaxAccountNotify_Vtbl)).OnIncomingMessageAdded)(this, pfaxaccount, bstrmessageid, faddedtoreceivefolder), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnIncomingMessageRemoved(this: *mut core::ffi::c_void, pfaxaccount: *mut core::ffi::c_void, bstrmessageid: core::mem::MaybeUninit<windows_core::BSTR>, fremovedfromreceivefolder: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountNotify", "OnIncomingMessageRemoved", 14usize, |this| ((**(this as *const *const IFaxAccountNotify_Vtbl)).OnIncomingMessageRemoved)(this, pfaxaccount, bstrmessageid, fremovedfromreceivefolder), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnOutgoingMessageAdded(this: *mut core::ffi::c_void, pfaxaccount: *mut core::ffi::c_void, bstrmessageid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountNotify", "OnOutgoingMessageAdded", 15usize, |this| ((**(this as *const *const IFaxAccountNotify_Vtbl)).OnOutgoingMessageAdded)(this, pfaxaccount, bstrmessageid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnOutgoingMessageRemoved(this: *mut core::ffi::c_void, pfaxaccount: *mut core::ffi::c_void, bstrmessageid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountNotify", "OnOutgoingMessageRemoved", 16usize, |this| ((**(this as *const *const IFaxAccountNotify_Vtbl)).OnOutgoingMessageRemoved)(this, pfaxaccount, bstrmessageid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnServerShutDown(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountNotify", "OnServerShutDown", 17usize, |this| ((**(this as *const *const IFaxAccountNotify_Vtbl)).OnServerShutDown)(this, pfaxserver), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OnIncomingJobAdded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnIncomingJobAdded: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnIncomingJobRemoved,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnIncomingJobRemoved: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnIncomingJobChanged,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnIncomingJobChanged: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnOutgoingJobAdded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnOutgoingJobAdded: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnOutgoingJobRemoved,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnOutgoingJobRemoved: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnOutgoingJobChanged,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnOutgoingJobChanged: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnIncomingMessageAdded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnIncomingMessageAdded: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnIncomingMessageRemoved,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnIncomingMessageRemoved: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnOutgoingMessageAdded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnOutgoingMessageAdded: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnOutgoingMessageRemoved,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnOutgoingMessageRemoved: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnServerShutDown,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnServerShutDown: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxAccountNotify as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxAccountOutgoingArchive_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SizeLow(this: *mut core::ffi::c_void, plsizelow: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountOutgoingArchive", "SizeLow", 7usize, |this| ((**(this as *const *const IFaxAccountOutgoingArchive_Vtbl)).SizeLow)(this, plsizelow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeHigh(this: *mut core::ffi::c_void, plsizehigh: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountOutgoingArchive", "SizeHigh", 8usize, |this| ((**(this as *const *const IFaxAccountOutgoingArchive_Vtbl)).SizeHigh)(this, plsizehigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountOutgoingArchive", "Refresh", 9usize, |this| ((**(this as *const *const IFaxAccountOutgoingArchive_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetMessages(this: *mut core::ffi::c_void, lprefetchsize: i32, pfaxoutgoingmessageiterator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountOutgoingArchive", "GetMessages", 10usize, |this| ((**(this as *const *const IFaxAccountOutgoingArchive_Vtbl)).GetMessages)(this, lprefetchsize, pfaxoutgoingmessageiterator), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetMessage(this: *mut core::ffi::c_void, bstrmessageid: core::mem::MaybeUninit<windows_core::BSTR>, pfaxoutgoingmessage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountOutgoingArchive", "GetMessage", 11usize, |this| ((**(this as *const *const IFaxAccountOutgoingArchive_Vtbl)).GetMessage)(this, bstrmessageid, pfaxoutgoingmessage), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SizeLow,
+            SizeHigh,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            GetMessages,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetMessages: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetMessage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetMessage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxAccountOutgoingArchive as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxAccountOutgoingQueue_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetJobs(this: *mut core::ffi::c_void, pfaxoutgoingjobs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountOutgoingQueue", "GetJobs", 7usize, |this| ((**(this as *const *const IFaxAccountOutgoingQueue_Vtbl)).GetJobs)(this, pfaxoutgoingjobs), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetJob(this: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>, pfaxoutgoingjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountOutgoingQueue", "GetJob", 8usize, |this| ((**(this as *const *const IFaxAccountOutgoingQueue_Vtbl)).GetJob)(this, bstrjobid, pfaxoutgoingjob), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetJobs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetJobs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetJob,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetJob: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxAccountOutgoingQueue as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxAccountSet_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAccounts(this: *mut core::ffi::c_void, ppfaxaccounts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountSet", "GetAccounts", 7usize, |this| ((**(this as *const *const IFaxAccountSet_Vtbl)).GetAccounts)(this, ppfaxaccounts), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAccount(this: *mut core::ffi::c_void, bstraccountname: core::mem::MaybeUninit<windows_core::BSTR>, pfaxaccount: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountSet", "GetAccount", 8usize, |this| ((**(this as *const *const IFaxAccountSet_Vtbl)).GetAccount)(this, bstraccountname, pfaxaccount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddAccount(this: *mut core::ffi::c_void, bstraccountname: core::mem::MaybeUninit<windows_core::BSTR>, pfaxaccount: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountSet", "AddAccount", 9usize, |this| ((**(this as *const *const IFaxAccountSet_Vtbl)).AddAccount)(this, bstraccountname, pfaxaccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAccount(this: *mut core::ffi::c_void, bstraccountname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccountSet", "RemoveAccount", 10usize, |this| ((**(this as *const *const IFaxAccountSet_Vtbl)).RemoveAccount)(this, bstraccountname), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAccounts,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAccounts: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAccount,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAccount: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddAccount,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddAccount: 0,
+            RemoveAccount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxAccountSet as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxAccounts_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccounts", "_NewEnum", 7usize, |this| ((**(this as *const *const IFaxAccounts_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, vindex: core::mem::MaybeUninit<windows_core::VARIANT>, pfaxaccount: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccounts", "get_Item", 8usize, |this| ((**(this as *const *const IFaxAccounts_Vtbl)).get_Item)(this, vindex, pfaxaccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxAccounts", "Count", 9usize, |this| ((**(this as *const *const IFaxAccounts_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxAccounts as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxActivity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IncomingMessages(this: *mut core::ffi::c_void, plincomingmessages: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxActivity", "IncomingMessages", 7usize, |this| ((**(this as *const *const IFaxActivity_Vtbl)).IncomingMessages)(this, plincomingmessages), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoutingMessages(this: *mut core::ffi::c_void, plroutingmessages: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxActivity", "RoutingMessages", 8usize, |this| ((**(this as *const *const IFaxActivity_Vtbl)).RoutingMessages)(this, plroutingmessages), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutgoingMessages(this: *mut core::ffi::c_void, ploutgoingmessages: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxActivity", "OutgoingMessages", 9usize, |this| ((**(this as *const *const IFaxActivity_Vtbl)).OutgoingMessages)(this, ploutgoingmessages), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueuedMessages(this: *mut core::ffi::c_void, plqueuedmessages: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxActivity", "QueuedMessages", 10usize, |this| ((**(this as *const *const IFaxActivity_Vtbl)).QueuedMessages)(this, plqueuedmessages), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxActivity", "Refresh", 11usize, |this| ((**(this as *const *const IFaxActivity_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IncomingMessages,
+            RoutingMessages,
+            OutgoingMessages,
+            QueuedMessages,
+            Refresh,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxActivity as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxActivityLogging_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LogIncoming(this: *mut core::ffi::c_void, pblogincoming: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxActivityLogging", "LogIncoming", 7usize, |this| ((**(this as *const *const IFaxActivityLogging_Vtbl)).LogIncoming)(this, pblogincoming), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogIncoming(this: *mut core::ffi::c_void, blogincoming: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxActivityLogging", "SetLogIncoming", 8usize, |this| ((**(this as *const *const IFaxActivityLogging_Vtbl)).SetLogIncoming)(this, blogincoming), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogOutgoing(this: *mut core::ffi::c_void, pblogoutgoing: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxActivityLogging", "LogOutgoing", 9usize, |this| ((**(this as *const *const IFaxActivityLogging_Vtbl)).LogOutgoing)(this, pblogoutgoing), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogOutgoing(this: *mut core::ffi::c_void, blogoutgoing: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxActivityLogging", "SetLogOutgoing", 10usize, |this| ((**(this as *const *const IFaxActivityLogging_Vtbl)).SetLogOutgoing)(this, blogoutgoing), |result| Some(*result))
+        }
+        unsafe extern "system" fn DatabasePath(this: *mut core::ffi::c_void, pbstrdatabasepath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxActivityLogging", "DatabasePath", 11usize, |this| ((**(this as *const *const IFaxActivityLogging_Vtbl)).DatabasePath)(this, pbstrdatabasepath), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDatabasePath(this: *mut core::ffi::c_void, bstrdatabasepath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxActivityLogging", "SetDatabasePath", 12usize, |this| ((**(this as *const *const IFaxActivityLogging_Vtbl)).SetDatabasePath)(this, bstrdatabasepath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxActivityLogging", "Refresh", 13usize, |this| ((**(this as *const *const IFaxActivityLogging_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxActivityLogging", "Save", 14usize, |this| ((**(this as *const *const IFaxActivityLogging_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LogIncoming,
+            SetLogIncoming,
+            LogOutgoing,
+            SetLogOutgoing,
+            DatabasePath,
+            SetDatabasePath,
+            Refresh,
+            Save,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxActivityLogging as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UseArchive(this: *mut core::ffi::c_void, pbusearchive: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "UseArchive", 7usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).UseArchive)(this, pbusearchive), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseArchive(this: *mut core::ffi::c_void, busearchive: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetUseArchive", 8usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetUseArchive)(this, busearchive), |result| Some(*result))
+        }
+        unsafe extern "system" fn ArchiveLocation(this: *mut core::ffi::c_void, pbstrarchivelocation: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "ArchiveLocation", 9usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).ArchiveLocation)(this, pbstrarchivelocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetArchiveLocation(this: *mut core::ffi::c_void, bstrarchivelocation: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetArchiveLocation", 10usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetArchiveLocation)(this, bstrarchivelocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeQuotaWarning(this: *mut core::ffi::c_void, pbsizequotawarning: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SizeQuotaWarning", 11usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SizeQuotaWarning)(this, pbsizequotawarning), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSizeQuotaWarning(this: *mut core::ffi::c_void, bsizequotawarning: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetSizeQuotaWarning", 12usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetSizeQuotaWarning)(this, bsizequotawarning), |result| Some(*result))
+        }
+        unsafe extern "system" fn HighQuotaWaterMark(this: *mut core::ffi::c_void, plhighquotawatermark: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "HighQuotaWaterMark", 13usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).HighQuotaWaterMark)(this, plhighquotawatermark), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHighQuotaWaterMark(this: *mut core::ffi::c_void, lhighquotawatermark: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetHighQuotaWaterMark", 14usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetHighQuotaWaterMark)(this, lhighquotawatermark), |result| Some(*result))
+        }
+        unsafe extern "system" fn LowQuotaWaterMark(this: *mut core::ffi::c_void, pllowquotawatermark: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "LowQuotaWaterMark", 15usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).LowQuotaWaterMark)(this, pllowquotawatermark), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLowQuotaWaterMark(this: *mut core::ffi::c_void, llowquotawatermark: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetLowQuotaWaterMark", 16usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetLowQuotaWaterMark)(this, llowquotawatermark), |result| Some(*result))
+        }
+        unsafe extern "system" fn ArchiveAgeLimit(this: *mut core::ffi::c_void, plarchiveagelimit: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "ArchiveAgeLimit", 17usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).ArchiveAgeLimit)(this, plarchiveagelimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetArchiveAgeLimit(this: *mut core::ffi::c_void, larchiveagelimit: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetArchiveAgeLimit", 18usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetArchiveAgeLimit)(this, larchiveagelimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn ArchiveSizeLow(this: *mut core::ffi::c_void, plsizelow: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "ArchiveSizeLow", 19usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).ArchiveSizeLow)(this, plsizelow), |result| Some(*result))
+        }
+        unsafe extern "system" fn ArchiveSizeHigh(this: *mut core::ffi::c_void, plsizehigh: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "ArchiveSizeHigh", 20usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).ArchiveSizeHigh)(this, plsizehigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutgoingQueueBlocked(this: *mut core::ffi::c_void, pboutgoingblocked: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "OutgoingQueueBlocked", 21usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).OutgoingQueueBlocked)(this, pboutgoingblocked), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutgoingQueueBlocked(this: *mut core::ffi::c_void, boutgoingblocked: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetOutgoingQueueBlocked", 22usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetOutgoingQueueBlocked)(this, boutgoingblocked), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutgoingQueuePaused(this: *mut core::ffi::c_void, pboutgoingpaused: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "OutgoingQueuePaused", 23usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).OutgoingQueuePaused)(this, pboutgoingpaused), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutgoingQueuePaused(this: *mut core::ffi::c_void, boutgoingpaused: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetOutgoingQueuePaused", 24usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetOutgoingQueuePaused)(this, boutgoingpaused), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowPersonalCoverPages(this: *mut core::ffi::c_void, pballowpersonalcoverpages: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "AllowPersonalCoverPages", 25usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).AllowPersonalCoverPages)(this, pballowpersonalcoverpages), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowPersonalCoverPages(this: *mut core::ffi::c_void, ballowpersonalcoverpages: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetAllowPersonalCoverPages", 26usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetAllowPersonalCoverPages)(this, ballowpersonalcoverpages), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseDeviceTSID(this: *mut core::ffi::c_void, pbusedevicetsid: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "UseDeviceTSID", 27usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).UseDeviceTSID)(this, pbusedevicetsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseDeviceTSID(this: *mut core::ffi::c_void, busedevicetsid: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetUseDeviceTSID", 28usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetUseDeviceTSID)(this, busedevicetsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Retries(this: *mut core::ffi::c_void, plretries: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "Retries", 29usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).Retries)(this, plretries), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRetries(this: *mut core::ffi::c_void, lretries: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetRetries", 30usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetRetries)(this, lretries), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetryDelay(this: *mut core::ffi::c_void, plretrydelay: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "RetryDelay", 31usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).RetryDelay)(this, plretrydelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRetryDelay(this: *mut core::ffi::c_void, lretrydelay: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetRetryDelay", 32usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetRetryDelay)(this, lretrydelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscountRateStart(this: *mut core::ffi::c_void, pdatediscountratestart: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "DiscountRateStart", 33usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).DiscountRateStart)(this, pdatediscountratestart), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDiscountRateStart(this: *mut core::ffi::c_void, datediscountratestart: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetDiscountRateStart", 34usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetDiscountRateStart)(this, datediscountratestart), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscountRateEnd(this: *mut core::ffi::c_void, pdatediscountrateend: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "DiscountRateEnd", 35usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).DiscountRateEnd)(this, pdatediscountrateend), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDiscountRateEnd(this: *mut core::ffi::c_void, datediscountrateend: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetDiscountRateEnd", 36usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetDiscountRateEnd)(this, datediscountrateend), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutgoingQueueAgeLimit(this: *mut core::ffi::c_void, ploutgoingqueueagelimit: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "OutgoingQueueAgeLimit", 37usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).OutgoingQueueAgeLimit)(this, ploutgoingqueueagelimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutgoingQueueAgeLimit(this: *mut core::ffi::c_void, loutgoingqueueagelimit: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetOutgoingQueueAgeLimit", 38usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetOutgoingQueueAgeLimit)(this, loutgoingqueueagelimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn Branding(this: *mut core::ffi::c_void, pbbranding: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "Branding", 39usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).Branding)(this, pbbranding), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBranding(this: *mut core::ffi::c_void, bbranding: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetBranding", 40usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetBranding)(this, bbranding), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncomingQueueBlocked(this: *mut core::ffi::c_void, pbincomingblocked: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "IncomingQueueBlocked", 41usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).IncomingQueueBlocked)(this, pbincomingblocked), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncomingQueueBlocked(this: *mut core::ffi::c_void, bincomingblocked: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetIncomingQueueBlocked", 42usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetIncomingQueueBlocked)(this, bincomingblocked), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoCreateAccountOnConnect(this: *mut core::ffi::c_void, pbautocreateaccountonconnect: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "AutoCreateAccountOnConnect", 43usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).AutoCreateAccountOnConnect)(this, pbautocreateaccountonconnect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoCreateAccountOnConnect(this: *mut core::ffi::c_void, bautocreateaccountonconnect: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetAutoCreateAccountOnConnect", 44usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetAutoCreateAccountOnConnect)(this, bautocreateaccountonconnect), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncomingFaxesArePublic(this: *mut core::ffi::c_void, pbincomingfaxesarepublic: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "IncomingFaxesArePublic", 45usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).IncomingFaxesArePublic)(this, pbincomingfaxesarepublic), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncomingFaxesArePublic(this: *mut core::ffi::c_void, bincomingfaxesarepublic: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "SetIncomingFaxesArePublic", 46usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).SetIncomingFaxesArePublic)(this, bincomingfaxesarepublic), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "Refresh", 47usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxConfiguration", "Save", 48usize, |this| ((**(this as *const *const IFaxConfiguration_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UseArchive,
+            SetUseArchive,
+            ArchiveLocation,
+            SetArchiveLocation,
+            SizeQuotaWarning,
+            SetSizeQuotaWarning,
+            HighQuotaWaterMark,
+            SetHighQuotaWaterMark,
+            LowQuotaWaterMark,
+            SetLowQuotaWaterMark,
+            ArchiveAgeLimit,
+            SetArchiveAgeLimit,
+            ArchiveSizeLow,
+            ArchiveSizeHigh,
+            OutgoingQueueBlocked,
+            SetOutgoingQueueBlocked,
+            OutgoingQueuePaused,
+            SetOutgoingQueuePaused,
+            AllowPersonalCoverPages,
+            SetAllowPersonalCoverPages,
+            UseDeviceTSID,
+            SetUseDeviceTSID,
+            Retries,
+            SetRetries,
+            RetryDelay,
+            SetRetryDelay,
+            DiscountRateStart,
+            SetDiscountRateStart,
+            DiscountRateEnd,
+            SetDiscountRateEnd,
+            OutgoingQueueAgeLimit,
+            SetOutgoingQueueAgeLimit,
+            Branding,
+            SetBranding,
+            IncomingQueueBlocked,
+            SetIncomingQueueBlocked,
+            AutoCreateAccountOnConnect,
+            SetAutoCreateAccountOnConnect,
+            IncomingFaxesArePublic,
+            SetIncomingFaxesArePublic,
+            Refresh,
+            Save,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxConfiguration as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, plid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "Id", 7usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).Id)(this, plid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceName(this: *mut core::ffi::c_void, pbstrdevicename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "DeviceName", 8usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).DeviceName)(this, pbstrdevicename), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderUniqueName(this: *mut core::ffi::c_void, pbstrprovideruniquename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "ProviderUniqueName", 9usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).ProviderUniqueName)(this, pbstrprovideruniquename), |result| Some(*result))
+        }
+        unsafe extern "system" fn PoweredOff(this: *mut core::ffi::c_void, pbpoweredoff: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "PoweredOff", 10usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).PoweredOff)(this, pbpoweredoff), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceivingNow(this: *mut core::ffi::c_void, pbreceivingnow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "ReceivingNow", 11usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).ReceivingNow)(this, pbreceivingnow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendingNow(this: *mut core::ffi::c_void, pbsendingnow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "SendingNow", 12usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).SendingNow)(this, pbsendingnow), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsedRoutingMethods(this: *mut core::ffi::c_void, pvusedroutingmethods: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "UsedRoutingMethods", 13usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).UsedRoutingMethods)(this, pvusedroutingmethods), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "Description", 14usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).Description)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "SetDescription", 15usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendEnabled(this: *mut core::ffi::c_void, pbsendenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "SendEnabled", 16usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).SendEnabled)(this, pbsendenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSendEnabled(this: *mut core::ffi::c_void, bsendenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "SetSendEnabled", 17usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).SetSendEnabled)(this, bsendenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceiveMode(this: *mut core::ffi::c_void, preceivemode: *mut FAX_DEVICE_RECEIVE_MODE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "ReceiveMode", 18usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).ReceiveMode)(this, preceivemode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReceiveMode(this: *mut core::ffi::c_void, receivemode: FAX_DEVICE_RECEIVE_MODE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "SetReceiveMode", 19usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).SetReceiveMode)(this, receivemode), |result| Some(*result))
+        }
+        unsafe extern "system" fn RingsBeforeAnswer(this: *mut core::ffi::c_void, plringsbeforeanswer: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "RingsBeforeAnswer", 20usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).RingsBeforeAnswer)(this, plringsbeforeanswer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRingsBeforeAnswer(this: *mut core::ffi::c_void, lringsbeforeanswer: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "SetRingsBeforeAnswer", 21usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).SetRingsBeforeAnswer)(this, lringsbeforeanswer), |result| Some(*result))
+        }
+        unsafe extern "system" fn CSID(this: *mut core::ffi::c_void, pbstrcsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "CSID", 22usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).CSID)(this, pbstrcsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCSID(this: *mut core::ffi::c_void, bstrcsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "SetCSID", 23usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).SetCSID)(this, bstrcsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TSID(this: *mut core::ffi::c_void, pbstrtsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "TSID", 24usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).TSID)(this, pbstrtsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTSID(this: *mut core::ffi::c_void, bstrtsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "SetTSID", 25usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).SetTSID)(this, bstrtsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "Refresh", 26usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "Save", 27usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExtensionProperty(this: *mut core::ffi::c_void, bstrguid: core::mem::MaybeUninit<windows_core::BSTR>, pvproperty: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "GetExtensionProperty", 28usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).GetExtensionProperty)(this, bstrguid, pvproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtensionProperty(this: *mut core::ffi::c_void, bstrguid: core::mem::MaybeUninit<windows_core::BSTR>, vproperty: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "SetExtensionProperty", 29usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).SetExtensionProperty)(this, bstrguid, vproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseRoutingMethod(this: *mut core::ffi::c_void, bstrmethodguid: core::mem::MaybeUninit<windows_core::BSTR>, buse: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "UseRoutingMethod", 30usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).UseRoutingMethod)(this, bstrmethodguid, buse), |result| Some(*result))
+        }
+        unsafe extern "system" fn RingingNow(this: *mut core::ffi::c_void, pbringingnow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "RingingNow", 31usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).RingingNow)(this, pbringingnow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnswerCall(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevice", "AnswerCall", 32usize, |this| ((**(this as *const *const IFaxDevice_Vtbl)).AnswerCall)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            DeviceName,
+            ProviderUniqueName,
+            PoweredOff,
+            ReceivingNow,
+            SendingNow,
+            UsedRoutingMethods,
+            Description,
+            SetDescription,
+            SendEnabled,
+            SetSendEnabled,
+            ReceiveMode,
+            SetReceiveMode,
+            RingsBeforeAnswer,
+            SetRingsBeforeAnswer,
+            CSID,
+            SetCSID,
+            TSID,
+            SetTSID,
+            Refresh,
+            Save,
+            GetExtensionProperty,
+            SetExtensionProperty,
+            UseRoutingMethod,
+            RingingNow,
+            AnswerCall,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxDevice as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxDeviceIds_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceIds", "_NewEnum", 7usize, |this| ((**(this as *const *const IFaxDeviceIds_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, pldeviceid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceIds", "get_Item", 8usize, |this| ((**(this as *const *const IFaxDeviceIds_Vtbl)).get_Item)(this, lindex, pldeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceIds", "Count", 9usize, |this| ((**(this as *const *const IFaxDeviceIds_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, ldeviceid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceIds", "Add", 10usize, |this| ((**(this as *const *const IFaxDeviceIds_Vtbl)).Add)(this, ldeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, lindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceIds", "Remove", 11usize, |this| ((**(this as *const *const IFaxDeviceIds_Vtbl)).Remove)(this, lindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOrder(this: *mut core::ffi::c_void, ldeviceid: i32, lneworder: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceIds", "SetOrder", 12usize, |this| ((**(this as *const *const IFaxDeviceIds_Vtbl)).SetOrder)(this, ldeviceid, lneworder), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, _NewEnum, get_Item, Count, Add, Remove, SetOrder }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxDeviceIds as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxDeviceProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, pbstrfriendlyname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceProvider", "FriendlyName", 7usize, |this| ((**(this as *const *const IFaxDeviceProvider_Vtbl)).FriendlyName)(this, pbstrfriendlyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImageName(this: *mut core::ffi::c_void, pbstrimagename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceProvider", "ImageName", 8usize, |this| ((**(this as *const *const IFaxDeviceProvider_Vtbl)).ImageName)(this, pbstrimagename), |result| Some(*result))
+        }
+        unsafe extern "system" fn UniqueName(this: *mut core::ffi::c_void, pbstruniquename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceProvider", "UniqueName", 9usize, |this| ((**(this as *const *const IFaxDeviceProvider_Vtbl)).UniqueName)(this, pbstruniquename), |result| Some(*result))
+        }
+        unsafe extern "system" fn TapiProviderName(this: *mut core::ffi::c_void, pbstrtapiprovidername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceProvider", "TapiProviderName", 10usize, |this| ((**(this as *const *const IFaxDeviceProvider_Vtbl)).TapiProviderName)(this, pbstrtapiprovidername), |result| Some(*result))
+        }
+        unsafe extern "system" fn MajorVersion(this: *mut core::ffi::c_void, plmajorversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceProvider", "MajorVersion", 11usize, |this| ((**(this as *const *const IFaxDeviceProvider_Vtbl)).MajorVersion)(this, plmajorversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinorVersion(this: *mut core::ffi::c_void, plminorversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceProvider", "MinorVersion", 12usize, |this| ((**(this as *const *const IFaxDeviceProvider_Vtbl)).MinorVersion)(this, plminorversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn MajorBuild(this: *mut core::ffi::c_void, plmajorbuild: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceProvider", "MajorBuild", 13usize, |this| ((**(this as *const *const IFaxDeviceProvider_Vtbl)).MajorBuild)(this, plmajorbuild), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinorBuild(this: *mut core::ffi::c_void, plminorbuild: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceProvider", "MinorBuild", 14usize, |this| ((**(this as *const *const IFaxDeviceProvider_Vtbl)).MinorBuild)(this, plminorbuild), |result| Some(*result))
+        }
+        unsafe extern "system" fn Debug(this: *mut core::ffi::c_void, pbdebug: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceProvider", "Debug", 15usize, |this| ((**(this as *const *const IFaxDeviceProvider_Vtbl)).Debug)(this, pbdebug), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, pstatus: *mut FAX_PROVIDER_STATUS_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceProvider", "Status", 16usize, |this| ((**(this as *const *const IFaxDeviceProvider_Vtbl)).Status)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitErrorCode(this: *mut core::ffi::c_void, pliniterrorcode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceProvider", "InitErrorCode", 17usize, |this| ((**(this as *const *const IFaxDeviceProvider_Vtbl)).InitErrorCode)(this, pliniterrorcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceIds(this: *mut core::ffi::c_void, pvdeviceids: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceProvider", "DeviceIds", 18usize, |this| ((**(this as *const *const IFaxDeviceProvider_Vtbl)).DeviceIds)(this, pvdeviceids), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FriendlyName,
+            ImageName,
+            UniqueName,
+            TapiProviderName,
+            MajorVersion,
+            MinorVersion,
+            MajorBuild,
+            MinorBuild,
+            Debug,
+            Status,
+            InitErrorCode,
+            DeviceIds,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxDeviceProvider as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxDeviceProviders_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceProviders", "_NewEnum", 7usize, |this| ((**(this as *const *const IFaxDeviceProviders_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, vindex: core::mem::MaybeUninit<windows_core::VARIANT>, pfaxdeviceprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceProviders", "get_Item", 8usize, |this| ((**(this as *const *const IFaxDeviceProviders_Vtbl)).get_Item)(this, vindex, pfaxdeviceprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDeviceProviders", "Count", 9usize, |this| ((**(this as *const *const IFaxDeviceProviders_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxDeviceProviders as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxDevices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevices", "_NewEnum", 7usize, |this| ((**(this as *const *const IFaxDevices_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, vindex: core::mem::MaybeUninit<windows_core::VARIANT>, pfaxdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevices", "get_Item", 8usize, |this| ((**(this as *const *const IFaxDevices_Vtbl)).get_Item)(this, vindex, pfaxdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevices", "Count", 9usize, |this| ((**(this as *const *const IFaxDevices_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemById(this: *mut core::ffi::c_void, lid: i32, ppfaxdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDevices", "get_ItemById", 10usize, |this| ((**(this as *const *const IFaxDevices_Vtbl)).get_ItemById)(this, lid, ppfaxdevice), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemById,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemById: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxDevices as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxDocument_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Body(this: *mut core::ffi::c_void, pbstrbody: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "Body", 7usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).Body)(this, pbstrbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBody(this: *mut core::ffi::c_void, bstrbody: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "SetBody", 8usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).SetBody)(this, bstrbody), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Sender(this: *mut core::ffi::c_void, ppfaxsender: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "Sender", 9usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).Sender)(this, ppfaxsender), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Recipients(this: *mut core::ffi::c_void, ppfaxrecipients: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "Recipients", 10usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).Recipients)(this, ppfaxrecipients), |result| Some(*result))
+        }
+        unsafe extern "system" fn CoverPage(this: *mut core::ffi::c_void, pbstrcoverpage: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "CoverPage", 11usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).CoverPage)(this, pbstrcoverpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCoverPage(this: *mut core::ffi::c_void, bstrcoverpage: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "SetCoverPage", 12usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).SetCoverPage)(this, bstrcoverpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, pbstrsubject: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "Subject", 13usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).Subject)(this, pbstrsubject), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubject(this: *mut core::ffi::c_void, bstrsubject: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "SetSubject", 14usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).SetSubject)(this, bstrsubject), |result| Some(*result))
+        }
+        unsafe extern "system" fn Note(this: *mut core::ffi::c_void, pbstrnote: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "Note", 15usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).Note)(this, pbstrnote), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNote(this: *mut core::ffi::c_void, bstrnote: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "SetNote", 16usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).SetNote)(this, bstrnote), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScheduleTime(this: *mut core::ffi::c_void, pdatescheduletime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "ScheduleTime", 17usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).ScheduleTime)(this, pdatescheduletime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScheduleTime(this: *mut core::ffi::c_void, datescheduletime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "SetScheduleTime", 18usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).SetScheduleTime)(this, datescheduletime), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceiptAddress(this: *mut core::ffi::c_void, pbstrreceiptaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "ReceiptAddress", 19usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).ReceiptAddress)(this, pbstrreceiptaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReceiptAddress(this: *mut core::ffi::c_void, bstrreceiptaddress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "SetReceiptAddress", 20usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).SetReceiptAddress)(this, bstrreceiptaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentName(this: *mut core::ffi::c_void, pbstrdocumentname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "DocumentName", 21usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).DocumentName)(this, pbstrdocumentname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDocumentName(this: *mut core::ffi::c_void, bstrdocumentname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "SetDocumentName", 22usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).SetDocumentName)(this, bstrdocumentname), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallHandle(this: *mut core::ffi::c_void, plcallhandle: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "CallHandle", 23usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).CallHandle)(this, plcallhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCallHandle(this: *mut core::ffi::c_void, lcallhandle: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "SetCallHandle", 24usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).SetCallHandle)(this, lcallhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn CoverPageType(this: *mut core::ffi::c_void, pcoverpagetype: *mut FAX_COVERPAGE_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "CoverPageType", 25usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).CoverPageType)(this, pcoverpagetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCoverPageType(this: *mut core::ffi::c_void, coverpagetype: FAX_COVERPAGE_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "SetCoverPageType", 26usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).SetCoverPageType)(this, coverpagetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScheduleType(this: *mut core::ffi::c_void, pscheduletype: *mut FAX_SCHEDULE_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "ScheduleType", 27usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).ScheduleType)(this, pscheduletype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScheduleType(this: *mut core::ffi::c_void, scheduletype: FAX_SCHEDULE_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "SetScheduleType", 28usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).SetScheduleType)(this, scheduletype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceiptType(this: *mut core::ffi::c_void, preceipttype: *mut FAX_RECEIPT_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "ReceiptType", 29usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).ReceiptType)(this, preceipttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReceiptType(this: *mut core::ffi::c_void, receipttype: FAX_RECEIPT_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "SetReceiptType", 30usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).SetReceiptType)(this, receipttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GroupBroadcastReceipts(this: *mut core::ffi::c_void, pbusegrouping: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "GroupBroadcastReceipts", 31usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).GroupBroadcastReceipts)(this, pbusegrouping), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroupBroadcastReceipts(this: *mut core::ffi::c_void, busegrouping: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "SetGroupBroadcastReceipts", 32usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).SetGroupBroadcastReceipts)(this, busegrouping), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, ppriority: *mut FAX_PRIORITY_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "Priority", 33usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).Priority)(this, ppriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, priority: FAX_PRIORITY_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "SetPriority", 34usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).SetPriority)(this, priority), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TapiConnection(this: *mut core::ffi::c_void, pptapiconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "TapiConnection", 35usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).TapiConnection)(this, pptapiconnection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_TapiConnection(this: *mut core::ffi::c_void, ptapiconnection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "putref_TapiConnection", 36usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).putref_TapiConnection)(this, ptapiconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn Submit(this: *mut core::ffi::c_void, bstrfaxservername: core::mem::MaybeUninit<windows_core::BSTR>, pvfaxoutgoingjobids: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "Submit", 37usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).Submit)(this, bstrfaxservername, pvfaxoutgoingjobids), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ConnectedSubmit(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, pvfaxoutgoingjobids: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "ConnectedSubmit", 38usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).ConnectedSubmit)(this, pfaxserver, pvfaxoutgoingjobids), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachFaxToReceipt(this: *mut core::ffi::c_void, pbattachfax: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "AttachFaxToReceipt", 39usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).AttachFaxToReceipt)(this, pbattachfax), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttachFaxToReceipt(this: *mut core::ffi::c_void, battachfax: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument", "SetAttachFaxToReceipt", 40usize, |this| ((**(this as *const *const IFaxDocument_Vtbl)).SetAttachFaxToReceipt)(this, battachfax), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Body,
+            SetBody,
+            #[cfg(feature = "Win32_System_Com")]
+            Sender,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Sender: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Recipients,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Recipients: 0,
+            CoverPage,
+            SetCoverPage,
+            Subject,
+            SetSubject,
+            Note,
+            SetNote,
+            ScheduleTime,
+            SetScheduleTime,
+            ReceiptAddress,
+            SetReceiptAddress,
+            DocumentName,
+            SetDocumentName,
+            CallHandle,
+            SetCallHandle,
+            CoverPageType,
+            SetCoverPageType,
+            ScheduleType,
+            SetScheduleType,
+            ReceiptType,
+            SetReceiptType,
+            GroupBroadcastReceipts,
+            SetGroupBroadcastReceipts,
+            Priority,
+            SetPriority,
+            #[cfg(feature = "Win32_System_Com")]
+            TapiConnection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TapiConnection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_TapiConnection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_TapiConnection: 0,
+            Submit,
+            #[cfg(feature = "Win32_System_Com")]
+            ConnectedSubmit,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ConnectedSubmit: 0,
+            AttachFaxToReceipt,
+            SetAttachFaxToReceipt,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxDocument as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxDocument2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SubmissionId(this: *mut core::ffi::c_void, pbstrsubmissionid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument2", "SubmissionId", 41usize, |this| ((**(this as *const *const IFaxDocument2_Vtbl)).SubmissionId)(this, pbstrsubmissionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bodies(this: *mut core::ffi::c_void, pvbodies: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument2", "Bodies", 42usize, |this| ((**(this as *const *const IFaxDocument2_Vtbl)).Bodies)(this, pvbodies), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBodies(this: *mut core::ffi::c_void, vbodies: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument2", "SetBodies", 43usize, |this| ((**(this as *const *const IFaxDocument2_Vtbl)).SetBodies)(this, vbodies), |result| Some(*result))
+        }
+        unsafe extern "system" fn Submit2(this: *mut core::ffi::c_void, bstrfaxservername: core::mem::MaybeUninit<windows_core::BSTR>, pvfaxoutgoingjobids: *mut core::mem::MaybeUninit<windows_core::VARIANT>, plerrorbodyfile: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument2", "Submit2", 44usize, |this| ((**(this as *const *const IFaxDocument2_Vtbl)).Submit2)(this, bstrfaxservername, pvfaxoutgoingjobids, plerrorbodyfile), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ConnectedSubmit2(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, pvfaxoutgoingjobids: *mut core::mem::MaybeUninit<windows_core::VARIANT>, plerrorbodyfile: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxDocument2", "ConnectedSubmit2", 45usize, |this| ((**(this as *const *const IFaxDocument2_Vtbl)).ConnectedSubmit2)(this, pfaxserver, pvfaxoutgoingjobids, plerrorbodyfile), |result| Some(*result))
+        }
+        Self {
+            base__: <IFaxDocument_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SubmissionId,
+            Bodies,
+            SetBodies,
+            Submit2,
+            #[cfg(feature = "Win32_System_Com")]
+            ConnectedSubmit2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ConnectedSubmit2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxDocument2 as windows_core::Interface>::IID || <IFaxDocument_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxEventLogging_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitEventsLevel(this: *mut core::ffi::c_void, piniteventlevel: *mut FAX_LOG_LEVEL_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxEventLogging", "InitEventsLevel", 7usize, |this| ((**(this as *const *const IFaxEventLogging_Vtbl)).InitEventsLevel)(this, piniteventlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitEventsLevel(this: *mut core::ffi::c_void, initeventlevel: FAX_LOG_LEVEL_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxEventLogging", "SetInitEventsLevel", 8usize, |this| ((**(this as *const *const IFaxEventLogging_Vtbl)).SetInitEventsLevel)(this, initeventlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn InboundEventsLevel(this: *mut core::ffi::c_void, pinboundeventlevel: *mut FAX_LOG_LEVEL_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxEventLogging", "InboundEventsLevel", 9usize, |this| ((**(this as *const *const IFaxEventLogging_Vtbl)).InboundEventsLevel)(this, pinboundeventlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInboundEventsLevel(this: *mut core::ffi::c_void, inboundeventlevel: FAX_LOG_LEVEL_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxEventLogging", "SetInboundEventsLevel", 10usize, |this| ((**(this as *const *const IFaxEventLogging_Vtbl)).SetInboundEventsLevel)(this, inboundeventlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutboundEventsLevel(this: *mut core::ffi::c_void, poutboundeventlevel: *mut FAX_LOG_LEVEL_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxEventLogging", "OutboundEventsLevel", 11usize, |this| ((**(this as *const *const IFaxEventLogging_Vtbl)).OutboundEventsLevel)(this, poutboundeventlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutboundEventsLevel(this: *mut core::ffi::c_void, outboundeventlevel: FAX_LOG_LEVEL_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxEventLogging", "SetOutboundEventsLevel", 12usize, |this| ((**(this as *const *const IFaxEventLogging_Vtbl)).SetOutboundEventsLevel)(this, outboundeventlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GeneralEventsLevel(this: *mut core::ffi::c_void, pgeneraleventlevel: *mut FAX_LOG_LEVEL_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxEventLogging", "GeneralEventsLevel", 13usize, |this| ((**(this as *const *const IFaxEventLogging_Vtbl)).GeneralEventsLevel)(this, pgeneraleventlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGeneralEventsLevel(this: *mut core::ffi::c_void, generaleventlevel: FAX_LOG_LEVEL_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxEventLogging", "SetGeneralEventsLevel", 14usize, |this| ((**(this as *const *const IFaxEventLogging_Vtbl)).SetGeneralEventsLevel)(this, generaleventlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxEventLogging", "Refresh", 15usize, |this| ((**(this as *const *const IFaxEventLogging_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxEventLogging", "Save", 16usize, |this| ((**(this as *const *const IFaxEventLogging_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitEventsLevel,
+            SetInitEventsLevel,
+            InboundEventsLevel,
+            SetInboundEventsLevel,
+            OutboundEventsLevel,
+            SetOutboundEventsLevel,
+            GeneralEventsLevel,
+            SetGeneralEventsLevel,
+            Refresh,
+            Save,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxEventLogging as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxFolders_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OutgoingQueue(this: *mut core::ffi::c_void, pfaxoutgoingqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxFolders", "OutgoingQueue", 7usize, |this| ((**(this as *const *const IFaxFolders_Vtbl)).OutgoingQueue)(this, pfaxoutgoingqueue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IncomingQueue(this: *mut core::ffi::c_void, pfaxincomingqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxFolders", "IncomingQueue", 8usize, |this| ((**(this as *const *const IFaxFolders_Vtbl)).IncomingQueue)(this, pfaxincomingqueue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IncomingArchive(this: *mut core::ffi::c_void, pfaxincomingarchive: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxFolders", "IncomingArchive", 9usize, |this| ((**(this as *const *const IFaxFolders_Vtbl)).IncomingArchive)(this, pfaxincomingarchive), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OutgoingArchive(this: *mut core::ffi::c_void, pfaxoutgoingarchive: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxFolders", "OutgoingArchive", 10usize, |this| ((**(this as *const *const IFaxFolders_Vtbl)).OutgoingArchive)(this, pfaxoutgoingarchive), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OutgoingQueue,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OutgoingQueue: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            IncomingQueue,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IncomingQueue: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            IncomingArchive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IncomingArchive: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OutgoingArchive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OutgoingArchive: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxFolders as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxInboundRouting_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetExtensions(this: *mut core::ffi::c_void, pfaxinboundroutingextensions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRouting", "GetExtensions", 7usize, |this| ((**(this as *const *const IFaxInboundRouting_Vtbl)).GetExtensions)(this, pfaxinboundroutingextensions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetMethods(this: *mut core::ffi::c_void, pfaxinboundroutingmethods: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRouting", "GetMethods", 8usize, |this| ((**(this as *const *const IFaxInboundRouting_Vtbl)).GetMethods)(this, pfaxinboundroutingmethods), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetExtensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetExtensions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetMethods,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetMethods: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxInboundRouting as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxInboundRoutingExtension_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, pbstrfriendlyname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingExtension", "FriendlyName", 7usize, |this| ((**(this as *const *const IFaxInboundRoutingExtension_Vtbl)).FriendlyName)(this, pbstrfriendlyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImageName(this: *mut core::ffi::c_void, pbstrimagename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingExtension", "ImageName", 8usize, |this| ((**(this as *const *const IFaxInboundRoutingExtension_Vtbl)).ImageName)(this, pbstrimagename), |result| Some(*result))
+        }
+        unsafe extern "system" fn UniqueName(this: *mut core::ffi::c_void, pbstruniquename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingExtension", "UniqueName", 9usize, |this| ((**(this as *const *const IFaxInboundRoutingExtension_Vtbl)).UniqueName)(this, pbstruniquename), |result| Some(*result))
+        }
+        unsafe extern "system" fn MajorVersion(this: *mut core::ffi::c_void, plmajorversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingExtension", "MajorVersion", 10usize, |this| ((**(this as *const *const IFaxInboundRoutingExtension_Vtbl)).MajorVersion)(this, plmajorversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinorVersion(this: *mut core::ffi::c_void, plminorversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingExtension", "MinorVersion", 11usize, |this| ((**(this as *const *const IFaxInboundRoutingExtension_Vtbl)).MinorVersion)(this, plminorversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn MajorBuild(this: *mut core::ffi::c_void, plmajorbuild: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingExtension", "MajorBuild", 12usize, |this| ((**(this as *const *const IFaxInboundRoutingExtension_Vtbl)).MajorBuild)(this, plmajorbuild), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinorBuild(this: *mut core::ffi::c_void, plminorbuild: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingExtension", "MinorBuild", 13usize, |this| ((**(this as *const *const IFaxInboundRoutingExtension_Vtbl)).MinorBuild)(this, plminorbuild), |result| Some(*result))
+        }
+        unsafe extern "system" fn Debug(this: *mut core::ffi::c_void, pbdebug: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingExtension", "Debug", 14usize, |this| ((**(this as *const *const IFaxInboundRoutingExtension_Vtbl)).Debug)(this, pbdebug), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, pstatus: *mut FAX_PROVIDER_STATUS_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingExtension", "Status", 15usize, |this| ((**(this as *const *const IFaxInboundRoutingExtension_Vtbl)).Status)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitErrorCode(this: *mut core::ffi::c_void, pliniterrorcode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingExtension", "InitErrorCode", 16usize, |this| ((**(this as *const *const IFaxInboundRoutingExtension_Vtbl)).InitErrorCode)(this, pliniterrorcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Methods(this: *mut core::ffi::c_void, pvmethods: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingExtension", "Methods", 17usize, |this| ((**(this as *const *const IFaxInboundRoutingExtension_Vtbl)).Methods)(this, pvmethods), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FriendlyName,
+            ImageName,
+            UniqueName,
+            MajorVersion,
+            MinorVersion,
+            MajorBuild,
+            MinorBuild,
+            Debug,
+            Status,
+            InitErrorCode,
+            Methods,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxInboundRoutingExtension as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxInboundRoutingExtensions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingExtensions", "_NewEnum", 7usize, |this| ((**(this as *const *const IFaxInboundRoutingExtensions_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, vindex: core::mem::MaybeUninit<windows_core::VARIANT>, pfaxinboundroutingextension: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingExtensions", "get_Item", 8usize, |this| ((**(this as *const *const IFaxInboundRoutingExtensions_Vtbl)).get_Item)(this, vindex, pfaxinboundroutingextension), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingExtensions", "Count", 9usize, |this| ((**(this as *const *const IFaxInboundRoutingExtensions_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxInboundRoutingExtensions as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxInboundRoutingMethod_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingMethod", "Name", 7usize, |this| ((**(this as *const *const IFaxInboundRoutingMethod_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GUID(this: *mut core::ffi::c_void, pbstrguid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingMethod", "GUID", 8usize, |this| ((**(this as *const *const IFaxInboundRoutingMethod_Vtbl)).GUID)(this, pbstrguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn FunctionName(this: *mut core::ffi::c_void, pbstrfunctionname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingMethod", "FunctionName", 9usize, |this| ((**(this as *const *const IFaxInboundRoutingMethod_Vtbl)).FunctionName)(this, pbstrfunctionname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtensionFriendlyName(this: *mut core::ffi::c_void, pbstrextensionfriendlyname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingMethod", "ExtensionFriendlyName", 10usize, |this| ((**(this as *const *const IFaxInboundRoutingMethod_Vtbl)).ExtensionFriendlyName)(this, pbstrextensionfriendlyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtensionImageName(this: *mut core::ffi::c_void, pbstrextensionimagename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingMethod", "ExtensionImageName", 11usize, |this| ((**(this as *const *const IFaxInboundRoutingMethod_Vtbl)).ExtensionImageName)(this, pbstrextensionimagename), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, plpriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingMethod", "Priority", 12usize, |this| ((**(this as *const *const IFaxInboundRoutingMethod_Vtbl)).Priority)(this, plpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, lpriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingMethod", "SetPriority", 13usize, |this| ((**(this as *const *const IFaxInboundRoutingMethod_Vtbl)).SetPriority)(this, lpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingMethod", "Refresh", 14usize, |this| ((**(this as *const *const IFaxInboundRoutingMethod_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingMethod", "Save", 15usize, |this| ((**(this as *const *const IFaxInboundRoutingMethod_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            GUID,
+            FunctionName,
+            ExtensionFriendlyName,
+            ExtensionImageName,
+            Priority,
+            SetPriority,
+            Refresh,
+            Save,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxInboundRoutingMethod as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxInboundRoutingMethods_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingMethods", "_NewEnum", 7usize, |this| ((**(this as *const *const IFaxInboundRoutingMethods_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, vindex: core::mem::MaybeUninit<windows_core::VARIANT>, pfaxinboundroutingmethod: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingMethods", "get_Item", 8usize, |this| ((**(this as *const *const IFaxInboundRoutingMethods_Vtbl)).get_Item)(this, vindex, pfaxinboundroutingmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxInboundRoutingMethods", "Count", 9usize, |this| ((**(this as *const *const IFaxInboundRoutingMethods_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxInboundRoutingMethods as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxIncomingArchive_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UseArchive(this: *mut core::ffi::c_void, pbusearchive: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "UseArchive", 7usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).UseArchive)(this, pbusearchive), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseArchive(this: *mut core::ffi::c_void, busearchive: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "SetUseArchive", 8usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).SetUseArchive)(this, busearchive), |result| Some(*result))
+        }
+        unsafe extern "system" fn ArchiveFolder(this: *mut core::ffi::c_void, pbstrarchivefolder: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "ArchiveFolder", 9usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).ArchiveFolder)(this, pbstrarchivefolder), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetArchiveFolder(this: *mut core::ffi::c_void, bstrarchivefolder: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "SetArchiveFolder", 10usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).SetArchiveFolder)(this, bstrarchivefolder), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeQuotaWarning(this: *mut core::ffi::c_void, pbsizequotawarning: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "SizeQuotaWarning", 11usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).SizeQuotaWarning)(this, pbsizequotawarning), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSizeQuotaWarning(this: *mut core::ffi::c_void, bsizequotawarning: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "SetSizeQuotaWarning", 12usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).SetSizeQuotaWarning)(this, bsizequotawarning), |result| Some(*result))
+        }
+        unsafe extern "system" fn HighQuotaWaterMark(this: *mut core::ffi::c_void, plhighquotawatermark: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "HighQuotaWaterMark", 13usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).HighQuotaWaterMark)(this, plhighquotawatermark), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHighQuotaWaterMark(this: *mut core::ffi::c_void, lhighquotawatermark: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "SetHighQuotaWaterMark", 14usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).SetHighQuotaWaterMark)(this, lhighquotawatermark), |result| Some(*result))
+        }
+        unsafe extern "system" fn LowQuotaWaterMark(this: *mut core::ffi::c_void, pllowquotawatermark: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "LowQuotaWaterMark", 15usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).LowQuotaWaterMark)(this, pllowquotawatermark), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLowQuotaWaterMark(this: *mut core::ffi::c_void, llowquotawatermark: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "SetLowQuotaWaterMark", 16usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).SetLowQuotaWaterMark)(this, llowquotawatermark), |result| Some(*result))
+        }
+        unsafe extern "system" fn AgeLimit(this: *mut core::ffi::c_void, plagelimit: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "AgeLimit", 17usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).AgeLimit)(this, plagelimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAgeLimit(this: *mut core::ffi::c_void, lagelimit: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "SetAgeLimit", 18usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).SetAgeLimit)(this, lagelimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeLow(this: *mut core::ffi::c_void, plsizelow: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "SizeLow", 19usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).SizeLow)(this, plsizelow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeHigh(this: *mut core::ffi::c_void, plsizehigh: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "SizeHigh", 20usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).SizeHigh)(this, plsizehigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "Refresh", 21usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "Save", 22usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetMessages(this: *mut core::ffi::c_void, lprefetchsize: i32, pfaxincomingmessageiterator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "GetMessages", 23usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).GetMessages)(this, lprefetchsize, pfaxincomingmessageiterator), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetMessage(this: *mut core::ffi::c_void, bstrmessageid: core::mem::MaybeUninit<windows_core::BSTR>, pfaxincomingmessage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingArchive", "GetMessage", 24usize, |this| ((**(this as *const *const IFaxIncomingArchive_Vtbl)).GetMessage)(this, bstrmessageid, pfaxincomingmessage), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UseArchive,
+            SetUseArchive,
+            ArchiveFolder,
+            SetArchiveFolder,
+            SizeQuotaWarning,
+            SetSizeQuotaWarning,
+            HighQuotaWaterMark,
+            SetHighQuotaWaterMark,
+            LowQuotaWaterMark,
+            SetLowQuotaWaterMark,
+            AgeLimit,
+            SetAgeLimit,
+            SizeLow,
+            SizeHigh,
+            Refresh,
+            Save,
+            #[cfg(feature = "Win32_System_Com")]
+            GetMessages,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetMessages: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetMessage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetMessage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxIncomingArchive as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxIncomingJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, plsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "Size", 7usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).Size)(this, plsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pbstrid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "Id", 8usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).Id)(this, pbstrid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPage(this: *mut core::ffi::c_void, plcurrentpage: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "CurrentPage", 9usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).CurrentPage)(this, plcurrentpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, pldeviceid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "DeviceId", 10usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).DeviceId)(this, pldeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, pstatus: *mut FAX_JOB_STATUS_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "Status", 11usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).Status)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedStatusCode(this: *mut core::ffi::c_void, pextendedstatuscode: *mut FAX_JOB_EXTENDED_STATUS_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "ExtendedStatusCode", 12usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).ExtendedStatusCode)(this, pextendedstatuscode), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedStatus(this: *mut core::ffi::c_void, pbstrextendedstatus: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "ExtendedStatus", 13usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).ExtendedStatus)(this, pbstrextendedstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvailableOperations(this: *mut core::ffi::c_void, pavailableoperations: *mut FAX_JOB_OPERATIONS_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "AvailableOperations", 14usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).AvailableOperations)(this, pavailableoperations), |result| Some(*result))
+        }
+        unsafe extern "system" fn Retries(this: *mut core::ffi::c_void, plretries: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "Retries", 15usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).Retries)(this, plretries), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransmissionStart(this: *mut core::ffi::c_void, pdatetransmissionstart: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "TransmissionStart", 16usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).TransmissionStart)(this, pdatetransmissionstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransmissionEnd(this: *mut core::ffi::c_void, pdatetransmissionend: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "TransmissionEnd", 17usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).TransmissionEnd)(this, pdatetransmissionend), |result| Some(*result))
+        }
+        unsafe extern "system" fn CSID(this: *mut core::ffi::c_void, pbstrcsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "CSID", 18usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).CSID)(this, pbstrcsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TSID(this: *mut core::ffi::c_void, pbstrtsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "TSID", 19usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).TSID)(this, pbstrtsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallerId(this: *mut core::ffi::c_void, pbstrcallerid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "CallerId", 20usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).CallerId)(this, pbstrcallerid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoutingInformation(this: *mut core::ffi::c_void, pbstrroutinginformation: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "RoutingInformation", 21usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).RoutingInformation)(this, pbstrroutinginformation), |result| Some(*result))
+        }
+        unsafe extern "system" fn JobType(this: *mut core::ffi::c_void, pjobtype: *mut FAX_JOB_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "JobType", 22usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).JobType)(this, pjobtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "Cancel", 23usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "Refresh", 24usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyTiff(this: *mut core::ffi::c_void, bstrtiffpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJob", "CopyTiff", 25usize, |this| ((**(this as *const *const IFaxIncomingJob_Vtbl)).CopyTiff)(this, bstrtiffpath), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Size,
+            Id,
+            CurrentPage,
+            DeviceId,
+            Status,
+            ExtendedStatusCode,
+            ExtendedStatus,
+            AvailableOperations,
+            Retries,
+            TransmissionStart,
+            TransmissionEnd,
+            CSID,
+            TSID,
+            CallerId,
+            RoutingInformation,
+            JobType,
+            Cancel,
+            Refresh,
+            CopyTiff,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxIncomingJob as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxIncomingJobs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJobs", "_NewEnum", 7usize, |this| ((**(this as *const *const IFaxIncomingJobs_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, vindex: core::mem::MaybeUninit<windows_core::VARIANT>, pfaxincomingjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJobs", "get_Item", 8usize, |this| ((**(this as *const *const IFaxIncomingJobs_Vtbl)).get_Item)(this, vindex, pfaxincomingjob), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingJobs", "Count", 9usize, |this| ((**(this as *const *const IFaxIncomingJobs_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxIncomingJobs as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxIncomingMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pbstrid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage", "Id", 7usize, |this| ((**(this as *const *const IFaxIncomingMessage_Vtbl)).Id)(this, pbstrid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pages(this: *mut core::ffi::c_void, plpages: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage", "Pages", 8usize, |this| ((**(this as *const *const IFaxIncomingMessage_Vtbl)).Pages)(this, plpages), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, plsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage", "Size", 9usize, |this| ((**(this as *const *const IFaxIncomingMessage_Vtbl)).Size)(this, plsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceName(this: *mut core::ffi::c_void, pbstrdevicename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage", "DeviceName", 10usize, |this| ((**(this as *const *const IFaxIncomingMessage_Vtbl)).DeviceName)(this, pbstrdevicename), |result| Some(*result))
+        }
+        unsafe extern "system" fn Retries(this: *mut core::ffi::c_void, plretries: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage", "Retries", 11usize, |this| ((**(this as *const *const IFaxIncomingMessage_Vtbl)).Retries)(this, plretries), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransmissionStart(this: *mut core::ffi::c_void, pdatetransmissionstart: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage", "TransmissionStart", 12usize, |this| ((**(this as *const *const IFaxIncomingMessage_Vtbl)).TransmissionStart)(this, pdatetransmissionstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransmissionEnd(this: *mut core::ffi::c_void, pdatetransmissionend: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage", "TransmissionEnd", 13usize, |this| ((**(this as *const *const IFaxIncomingMessage_Vtbl)).TransmissionEnd)(this, pdatetransmissionend), |result| Some(*result))
+        }
+        unsafe extern "system" fn CSID(this: *mut core::ffi::c_void, pbstrcsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage", "CSID", 14usize, |this| ((**(this as *const *const IFaxIncomingMessage_Vtbl)).CSID)(this, pbstrcsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TSID(this: *mut core::ffi::c_void, pbstrtsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage", "TSID", 15usize, |this| ((**(this as *const *const IFaxIncomingMessage_Vtbl)).TSID)(this, pbstrtsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallerId(this: *mut core::ffi::c_void, pbstrcallerid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage", "CallerId", 16usize, |this| ((**(this as *const *const IFaxIncomingMessage_Vtbl)).CallerId)(this, pbstrcallerid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoutingInformation(this: *mut core::ffi::c_void, pbstrroutinginformation: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage", "RoutingInformation", 17usize, |this| ((**(this as *const *const IFaxIncomingMessage_Vtbl)).RoutingInformation)(this, pbstrroutinginformation), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyTiff(this: *mut core::ffi::c_void, bstrtiffpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage", "CopyTiff", 18usize, |this| ((**(this as *const *const IFaxIncomingMessage_Vtbl)).CopyTiff)(this, bstrtiffpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage", "Delete", 19usize, |this| ((**(this as *const *const IFaxIncomingMessage_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            Pages,
+            Size,
+            DeviceName,
+            Retries,
+            TransmissionStart,
+            TransmissionEnd,
+            CSID,
+            TSID,
+            CallerId,
+            RoutingInformation,
+            CopyTiff,
+            Delete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxIncomingMessage as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxIncomingMessage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, pbstrsubject: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "Subject", 20usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).Subject)(this, pbstrsubject), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubject(this: *mut core::ffi::c_void, bstrsubject: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "SetSubject", 21usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).SetSubject)(this, bstrsubject), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderName(this: *mut core::ffi::c_void, pbstrsendername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "SenderName", 22usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).SenderName)(this, pbstrsendername), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSenderName(this: *mut core::ffi::c_void, bstrsendername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "SetSenderName", 23usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).SetSenderName)(this, bstrsendername), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderFaxNumber(this: *mut core::ffi::c_void, pbstrsenderfaxnumber: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "SenderFaxNumber", 24usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).SenderFaxNumber)(this, pbstrsenderfaxnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSenderFaxNumber(this: *mut core::ffi::c_void, bstrsenderfaxnumber: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "SetSenderFaxNumber", 25usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).SetSenderFaxNumber)(this, bstrsenderfaxnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasCoverPage(this: *mut core::ffi::c_void, pbhascoverpage: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "HasCoverPage", 26usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).HasCoverPage)(this, pbhascoverpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHasCoverPage(this: *mut core::ffi::c_void, bhascoverpage: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "SetHasCoverPage", 27usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).SetHasCoverPage)(this, bhascoverpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn Recipients(this: *mut core::ffi::c_void, pbstrrecipients: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "Recipients", 28usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).Recipients)(this, pbstrrecipients), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecipients(this: *mut core::ffi::c_void, bstrrecipients: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "SetRecipients", 29usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).SetRecipients)(this, bstrrecipients), |result| Some(*result))
+        }
+        unsafe extern "system" fn WasReAssigned(this: *mut core::ffi::c_void, pbwasreassigned: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "WasReAssigned", 30usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).WasReAssigned)(this, pbwasreassigned), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, pbread: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "Read", 31usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).Read)(this, pbread), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRead(this: *mut core::ffi::c_void, bread: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "SetRead", 32usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).SetRead)(this, bread), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReAssign(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "ReAssign", 33usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).ReAssign)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "Save", 34usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessage2", "Refresh", 35usize, |this| ((**(this as *const *const IFaxIncomingMessage2_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IFaxIncomingMessage_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Subject,
+            SetSubject,
+            SenderName,
+            SetSenderName,
+            SenderFaxNumber,
+            SetSenderFaxNumber,
+            HasCoverPage,
+            SetHasCoverPage,
+            Recipients,
+            SetRecipients,
+            WasReAssigned,
+            Read,
+            SetRead,
+            ReAssign,
+            Save,
+            Refresh,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxIncomingMessage2 as windows_core::Interface>::IID || <IFaxIncomingMessage_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxIncomingMessageIterator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, pfaxincomingmessage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessageIterator", "Message", 7usize, |this| ((**(this as *const *const IFaxIncomingMessageIterator_Vtbl)).Message)(this, pfaxincomingmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrefetchSize(this: *mut core::ffi::c_void, plprefetchsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessageIterator", "PrefetchSize", 8usize, |this| ((**(this as *const *const IFaxIncomingMessageIterator_Vtbl)).PrefetchSize)(this, plprefetchsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrefetchSize(this: *mut core::ffi::c_void, lprefetchsize: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessageIterator", "SetPrefetchSize", 9usize, |this| ((**(this as *const *const IFaxIncomingMessageIterator_Vtbl)).SetPrefetchSize)(this, lprefetchsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn AtEOF(this: *mut core::ffi::c_void, pbeof: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessageIterator", "AtEOF", 10usize, |this| ((**(this as *const *const IFaxIncomingMessageIterator_Vtbl)).AtEOF)(this, pbeof), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveFirst(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessageIterator", "MoveFirst", 11usize, |this| ((**(this as *const *const IFaxIncomingMessageIterator_Vtbl)).MoveFirst)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingMessageIterator", "MoveNext", 12usize, |this| ((**(this as *const *const IFaxIncomingMessageIterator_Vtbl)).MoveNext)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Message,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Message: 0,
+            PrefetchSize,
+            SetPrefetchSize,
+            AtEOF,
+            MoveFirst,
+            MoveNext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxIncomingMessageIterator as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxIncomingQueue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Blocked(this: *mut core::ffi::c_void, pbblocked: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingQueue", "Blocked", 7usize, |this| ((**(this as *const *const IFaxIncomingQueue_Vtbl)).Blocked)(this, pbblocked), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlocked(this: *mut core::ffi::c_void, bblocked: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingQueue", "SetBlocked", 8usize, |this| ((**(this as *const *const IFaxIncomingQueue_Vtbl)).SetBlocked)(this, bblocked), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingQueue", "Refresh", 9usize, |this| ((**(this as *const *const IFaxIncomingQueue_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingQueue", "Save", 10usize, |this| ((**(this as *const *const IFaxIncomingQueue_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetJobs(this: *mut core::ffi::c_void, pfaxincomingjobs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingQueue", "GetJobs", 11usize, |this| ((**(this as *const *const IFaxIncomingQueue_Vtbl)).GetJobs)(this, pfaxincomingjobs), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetJob(this: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>, pfaxincomingjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxIncomingQueue", "GetJob", 12usize, |this| ((**(this as *const *const IFaxIncomingQueue_Vtbl)).GetJob)(this, bstrjobid, pfaxincomingjob), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Blocked,
+            SetBlocked,
+            Refresh,
+            Save,
+            #[cfg(feature = "Win32_System_Com")]
+            GetJobs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetJobs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetJob,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetJob: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxIncomingQueue as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxJobStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, pstatus: *mut FAX_JOB_STATUS_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "Status", 7usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).Status)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pages(this: *mut core::ffi::c_void, plpages: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "Pages", 8usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).Pages)(this, plpages), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, plsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "Size", 9usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).Size)(this, plsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPage(this: *mut core::ffi::c_void, plcurrentpage: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "CurrentPage", 10usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).CurrentPage)(this, plcurrentpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, pldeviceid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "DeviceId", 11usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).DeviceId)(this, pldeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CSID(this: *mut core::ffi::c_void, pbstrcsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "CSID", 12usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).CSID)(this, pbstrcsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TSID(this: *mut core::ffi::c_void, pbstrtsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "TSID", 13usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).TSID)(this, pbstrtsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedStatusCode(this: *mut core::ffi::c_void, pextendedstatuscode: *mut FAX_JOB_EXTENDED_STATUS_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "ExtendedStatusCode", 14usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).ExtendedStatusCode)(this, pextendedstatuscode), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedStatus(this: *mut core::ffi::c_void, pbstrextendedstatus: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "ExtendedStatus", 15usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).ExtendedStatus)(this, pbstrextendedstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvailableOperations(this: *mut core::ffi::c_void, pavailableoperations: *mut FAX_JOB_OPERATIONS_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "AvailableOperations", 16usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).AvailableOperations)(this, pavailableoperations), |result| Some(*result))
+        }
+        unsafe extern "system" fn Retries(this: *mut core::ffi::c_void, plretries: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "Retries", 17usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).Retries)(this, plretries), |result| Some(*result))
+        }
+        unsafe extern "system" fn JobType(this: *mut core::ffi::c_void, pjobtype: *mut FAX_JOB_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "JobType", 18usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).JobType)(this, pjobtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScheduledTime(this: *mut core::ffi::c_void, pdatescheduledtime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "ScheduledTime", 19usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).ScheduledTime)(this, pdatescheduledtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransmissionStart(this: *mut core::ffi::c_void, pdatetransmissionstart: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "TransmissionStart", 20usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).TransmissionStart)(this, pdatetransmissionstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransmissionEnd(this: *mut core::ffi::c_void, pdatetransmissionend: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "TransmissionEnd", 21usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).TransmissionEnd)(this, pdatetransmissionend), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallerId(this: *mut core::ffi::c_void, pbstrcallerid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "CallerId", 22usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).CallerId)(this, pbstrcallerid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoutingInformation(this: *mut core::ffi::c_void, pbstrroutinginformation: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxJobStatus", "RoutingInformation", 23usize, |this| ((**(this as *const *const IFaxJobStatus_Vtbl)).RoutingInformation)(this, pbstrroutinginformation), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            Pages,
+            Size,
+            CurrentPage,
+            DeviceId,
+            CSID,
+            TSID,
+            ExtendedStatusCode,
+            ExtendedStatus,
+            AvailableOperations,
+            Retries,
+            JobType,
+            ScheduledTime,
+            TransmissionStart,
+            TransmissionEnd,
+            CallerId,
+            RoutingInformation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxJobStatus as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxLoggingOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EventLogging(this: *mut core::ffi::c_void, pfaxeventlogging: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxLoggingOptions", "EventLogging", 7usize, |this| ((**(this as *const *const IFaxLoggingOptions_Vtbl)).EventLogging)(this, pfaxeventlogging), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ActivityLogging(this: *mut core::ffi::c_void, pfaxactivitylogging: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxLoggingOptions", "ActivityLogging", 8usize, |this| ((**(this as *const *const IFaxLoggingOptions_Vtbl)).ActivityLogging)(this, pfaxactivitylogging), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            EventLogging,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EventLogging: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ActivityLogging,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ActivityLogging: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxLoggingOptions as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxOutboundRouting_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetGroups(this: *mut core::ffi::c_void, pfaxoutboundroutinggroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRouting", "GetGroups", 7usize, |this| ((**(this as *const *const IFaxOutboundRouting_Vtbl)).GetGroups)(this, pfaxoutboundroutinggroups), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRules(this: *mut core::ffi::c_void, pfaxoutboundroutingrules: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRouting", "GetRules", 8usize, |this| ((**(this as *const *const IFaxOutboundRouting_Vtbl)).GetRules)(this, pfaxoutboundroutingrules), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetGroups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetGroups: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRules,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRules: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxOutboundRouting as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxOutboundRoutingGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingGroup", "Name", 7usize, |this| ((**(this as *const *const IFaxOutboundRoutingGroup_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, pstatus: *mut FAX_GROUP_STATUS_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingGroup", "Status", 8usize, |this| ((**(this as *const *const IFaxOutboundRoutingGroup_Vtbl)).Status)(this, pstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DeviceIds(this: *mut core::ffi::c_void, pfaxdeviceids: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingGroup", "DeviceIds", 9usize, |this| ((**(this as *const *const IFaxOutboundRoutingGroup_Vtbl)).DeviceIds)(this, pfaxdeviceids), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Status,
+            #[cfg(feature = "Win32_System_Com")]
+            DeviceIds,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DeviceIds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxOutboundRoutingGroup as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxOutboundRoutingGroups_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingGroups", "_NewEnum", 7usize, |this| ((**(this as *const *const IFaxOutboundRoutingGroups_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, vindex: core::mem::MaybeUninit<windows_core::VARIANT>, pfaxoutboundroutinggroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingGroups", "get_Item", 8usize, |this| ((**(this as *const *const IFaxOutboundRoutingGroups_Vtbl)).get_Item)(this, vindex, pfaxoutboundroutinggroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingGroups", "Count", 9usize, |this| ((**(this as *const *const IFaxOutboundRoutingGroups_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pfaxoutboundroutinggroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingGroups", "Add", 10usize, |this| ((**(this as *const *const IFaxOutboundRoutingGroups_Vtbl)).Add)(this, bstrname, pfaxoutboundroutinggroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, vindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingGroups", "Remove", 11usize, |this| ((**(this as *const *const IFaxOutboundRoutingGroups_Vtbl)).Remove)(this, vindex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxOutboundRoutingGroups as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxOutboundRoutingRule_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CountryCode(this: *mut core::ffi::c_void, plcountrycode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRule", "CountryCode", 7usize, |this| ((**(this as *const *const IFaxOutboundRoutingRule_Vtbl)).CountryCode)(this, plcountrycode), |result| Some(*result))
+        }
+        unsafe extern "system" fn AreaCode(this: *mut core::ffi::c_void, plareacode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRule", "AreaCode", 8usize, |this| ((**(this as *const *const IFaxOutboundRoutingRule_Vtbl)).AreaCode)(this, plareacode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, pstatus: *mut FAX_RULE_STATUS_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRule", "Status", 9usize, |this| ((**(this as *const *const IFaxOutboundRoutingRule_Vtbl)).Status)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseDevice(this: *mut core::ffi::c_void, pbusedevice: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRule", "UseDevice", 10usize, |this| ((**(this as *const *const IFaxOutboundRoutingRule_Vtbl)).UseDevice)(this, pbusedevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseDevice(this: *mut core::ffi::c_void, busedevice: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRule", "SetUseDevice", 11usize, |this| ((**(this as *const *const IFaxOutboundRoutingRule_Vtbl)).SetUseDevice)(this, busedevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, pldeviceid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRule", "DeviceId", 12usize, |this| ((**(this as *const *const IFaxOutboundRoutingRule_Vtbl)).DeviceId)(this, pldeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeviceId(this: *mut core::ffi::c_void, deviceid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRule", "SetDeviceId", 13usize, |this| ((**(this as *const *const IFaxOutboundRoutingRule_Vtbl)).SetDeviceId)(this, deviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GroupName(this: *mut core::ffi::c_void, pbstrgroupname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRule", "GroupName", 14usize, |this| ((**(this as *const *const IFaxOutboundRoutingRule_Vtbl)).GroupName)(this, pbstrgroupname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroupName(this: *mut core::ffi::c_void, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRule", "SetGroupName", 15usize, |this| ((**(this as *const *const IFaxOutboundRoutingRule_Vtbl)).SetGroupName)(this, bstrgroupname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRule", "Refresh", 16usize, |this| ((**(this as *const *const IFaxOutboundRoutingRule_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRule", "Save", 17usize, |this| ((**(this as *const *const IFaxOutboundRoutingRule_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CountryCode,
+            AreaCode,
+            Status,
+            UseDevice,
+            SetUseDevice,
+            DeviceId,
+            SetDeviceId,
+            GroupName,
+            SetGroupName,
+            Refresh,
+            Save,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxOutboundRoutingRule as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxOutboundRoutingRules_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRules", "_NewEnum", 7usize, |this| ((**(this as *const *const IFaxOutboundRoutingRules_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, pfaxoutboundroutingrule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRules", "get_Item", 8usize, |this| ((**(this as *const *const IFaxOutboundRoutingRules_Vtbl)).get_Item)(this, lindex, pfaxoutboundroutingrule), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRules", "Count", 9usize, |this| ((**(this as *const *const IFaxOutboundRoutingRules_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ItemByCountryAndArea(this: *mut core::ffi::c_void, lcountrycode: i32, lareacode: i32, pfaxoutboundroutingrule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRules", "ItemByCountryAndArea", 10usize, |this| ((**(this as *const *const IFaxOutboundRoutingRules_Vtbl)).ItemByCountryAndArea)(this, lcountrycode, lareacode, pfaxoutboundroutingrule), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveByCountryAndArea(this: *mut core::ffi::c_void, lcountrycode: i32, lareacode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRules", "RemoveByCountryAndArea", 11usize, |this| ((**(this as *const *const IFaxOutboundRoutingRules_Vtbl)).RemoveByCountryAndArea)(this, lcountrycode, lareacode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, lindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRules", "Remove", 12usize, |this| ((**(this as *const *const IFaxOutboundRoutingRules_Vtbl)).Remove)(this, lindex), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, lcountrycode: i32, lareacode: i32, busedevice: super::super::Foundation::VARIANT_BOOL, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>, ldeviceid: i32, pfaxoutboundroutingrule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutboundRoutingRules", "Add", 13usize, |this| ((**(this as *const *const IFaxOutboundRoutingRules_Vtbl)).Add)(this, lcountrycode, lareacode, busedevice, bstrgroupname, ldeviceid, pfaxoutboundroutingrule), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            ItemByCountryAndArea,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ItemByCountryAndArea: 0,
+            RemoveByCountryAndArea,
+            Remove,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxOutboundRoutingRules as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxOutgoingArchive_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UseArchive(this: *mut core::ffi::c_void, pbusearchive: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "UseArchive", 7usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).UseArchive)(this, pbusearchive), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseArchive(this: *mut core::ffi::c_void, busearchive: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "SetUseArchive", 8usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).SetUseArchive)(this, busearchive), |result| Some(*result))
+        }
+        unsafe extern "system" fn ArchiveFolder(this: *mut core::ffi::c_void, pbstrarchivefolder: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "ArchiveFolder", 9usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).ArchiveFolder)(this, pbstrarchivefolder), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetArchiveFolder(this: *mut core::ffi::c_void, bstrarchivefolder: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "SetArchiveFolder", 10usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).SetArchiveFolder)(this, bstrarchivefolder), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeQuotaWarning(this: *mut core::ffi::c_void, pbsizequotawarning: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "SizeQuotaWarning", 11usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).SizeQuotaWarning)(this, pbsizequotawarning), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSizeQuotaWarning(this: *mut core::ffi::c_void, bsizequotawarning: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "SetSizeQuotaWarning", 12usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).SetSizeQuotaWarning)(this, bsizequotawarning), |result| Some(*result))
+        }
+        unsafe extern "system" fn HighQuotaWaterMark(this: *mut core::ffi::c_void, plhighquotawatermark: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "HighQuotaWaterMark", 13usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).HighQuotaWaterMark)(this, plhighquotawatermark), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHighQuotaWaterMark(this: *mut core::ffi::c_void, lhighquotawatermark: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "SetHighQuotaWaterMark", 14usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).SetHighQuotaWaterMark)(this, lhighquotawatermark), |result| Some(*result))
+        }
+        unsafe extern "system" fn LowQuotaWaterMark(this: *mut core::ffi::c_void, pllowquotawatermark: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "LowQuotaWaterMark", 15usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).LowQuotaWaterMark)(this, pllowquotawatermark), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLowQuotaWaterMark(this: *mut core::ffi::c_void, llowquotawatermark: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "SetLowQuotaWaterMark", 16usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).SetLowQuotaWaterMark)(this, llowquotawatermark), |result| Some(*result))
+        }
+        unsafe extern "system" fn AgeLimit(this: *mut core::ffi::c_void, plagelimit: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "AgeLimit", 17usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).AgeLimit)(this, plagelimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAgeLimit(this: *mut core::ffi::c_void, lagelimit: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "SetAgeLimit", 18usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).SetAgeLimit)(this, lagelimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeLow(this: *mut core::ffi::c_void, plsizelow: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "SizeLow", 19usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).SizeLow)(this, plsizelow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeHigh(this: *mut core::ffi::c_void, plsizehigh: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "SizeHigh", 20usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).SizeHigh)(this, plsizehigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "Refresh", 21usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "Save", 22usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetMessages(this: *mut core::ffi::c_void, lprefetchsize: i32, pfaxoutgoingmessageiterator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "GetMessages", 23usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).GetMessages)(this, lprefetchsize, pfaxoutgoingmessageiterator), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetMessage(this: *mut core::ffi::c_void, bstrmessageid: core::mem::MaybeUninit<windows_core::BSTR>, pfaxoutgoingmessage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingArchive", "GetMessage", 24usize, |this| ((**(this as *const *const IFaxOutgoingArchive_Vtbl)).GetMessage)(this, bstrmessageid, pfaxoutgoingmessage), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UseArchive,
+            SetUseArchive,
+            ArchiveFolder,
+            SetArchiveFolder,
+            SizeQuotaWarning,
+            SetSizeQuotaWarning,
+            HighQuotaWaterMark,
+            SetHighQuotaWaterMark,
+            LowQuotaWaterMark,
+            SetLowQuotaWaterMark,
+            AgeLimit,
+            SetAgeLimit,
+            SizeLow,
+            SizeHigh,
+            Refresh,
+            Save,
+            #[cfg(feature = "Win32_System_Com")]
+            GetMessages,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetMessages: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetMessage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetMessage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxOutgoingArchive as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxOutgoingJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, pbstrsubject: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "Subject", 7usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).Subject)(this, pbstrsubject), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentName(this: *mut core::ffi::c_void, pbstrdocumentname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "DocumentName", 8usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).DocumentName)(this, pbstrdocumentname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pages(this: *mut core::ffi::c_void, plpages: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "Pages", 9usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).Pages)(this, plpages), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, plsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "Size", 10usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).Size)(this, plsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubmissionId(this: *mut core::ffi::c_void, pbstrsubmissionid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "SubmissionId", 11usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).SubmissionId)(this, pbstrsubmissionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pbstrid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "Id", 12usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).Id)(this, pbstrid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OriginalScheduledTime(this: *mut core::ffi::c_void, pdateoriginalscheduledtime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "OriginalScheduledTime", 13usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).OriginalScheduledTime)(this, pdateoriginalscheduledtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubmissionTime(this: *mut core::ffi::c_void, pdatesubmissiontime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "SubmissionTime", 14usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).SubmissionTime)(this, pdatesubmissiontime), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceiptType(this: *mut core::ffi::c_void, preceipttype: *mut FAX_RECEIPT_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "ReceiptType", 15usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).ReceiptType)(this, preceipttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, ppriority: *mut FAX_PRIORITY_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "Priority", 16usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).Priority)(this, ppriority), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Sender(this: *mut core::ffi::c_void, ppfaxsender: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "Sender", 17usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).Sender)(this, ppfaxsender), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Recipient(this: *mut core::ffi::c_void, ppfaxrecipient: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "Recipient", 18usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).Recipient)(this, ppfaxrecipient), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPage(this: *mut core::ffi::c_void, plcurrentpage: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "CurrentPage", 19usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).CurrentPage)(this, plcurrentpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, pldeviceid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "DeviceId", 20usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).DeviceId)(this, pldeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, pstatus: *mut FAX_JOB_STATUS_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "Status", 21usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).Status)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedStatusCode(this: *mut core::ffi::c_void, pextendedstatuscode: *mut FAX_JOB_EXTENDED_STATUS_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "ExtendedStatusCode", 22usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).ExtendedStatusCode)(this, pextendedstatuscode), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedStatus(this: *mut core::ffi::c_void, pbstrextendedstatus: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "ExtendedStatus", 23usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).ExtendedStatus)(this, pbstrextendedstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvailableOperations(this: *mut core::ffi::c_void, pavailableoperations: *mut FAX_JOB_OPERATIONS_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "AvailableOperations", 24usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).AvailableOperations)(this, pavailableoperations), |result| Some(*result))
+        }
+        unsafe extern "system" fn Retries(this: *mut core::ffi::c_void, plretries: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "Retries", 25usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).Retries)(this, plretries), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScheduledTime(this: *mut core::ffi::c_void, pdatescheduledtime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "ScheduledTime", 26usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).ScheduledTime)(this, pdatescheduledtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransmissionStart(this: *mut core::ffi::c_void, pdatetransmissionstart: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "TransmissionStart", 27usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).TransmissionStart)(this, pdatetransmissionstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransmissionEnd(this: *mut core::ffi::c_void, pdatetransmissionend: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "TransmissionEnd", 28usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).TransmissionEnd)(this, pdatetransmissionend), |result| Some(*result))
+        }
+        unsafe extern "system" fn CSID(this: *mut core::ffi::c_void, pbstrcsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "CSID", 29usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).CSID)(this, pbstrcsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TSID(this: *mut core::ffi::c_void, pbstrtsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "TSID", 30usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).TSID)(this, pbstrtsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GroupBroadcastReceipts(this: *mut core::ffi::c_void, pbgroupbroadcastreceipts: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "GroupBroadcastReceipts", 31usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).GroupBroadcastReceipts)(this, pbgroupbroadcastreceipts), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "Pause", 32usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "Resume", 33usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Restart(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "Restart", 34usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).Restart)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyTiff(this: *mut core::ffi::c_void, bstrtiffpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "CopyTiff", 35usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).CopyTiff)(this, bstrtiffpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "Refresh", 36usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob", "Cancel", 37usize, |this| ((**(this as *const *const IFaxOutgoingJob_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Subject,
+            DocumentName,
+            Pages,
+            Size,
+            SubmissionId,
+            Id,
+            OriginalScheduledTime,
+            SubmissionTime,
+            ReceiptType,
+            Priority,
+            #[cfg(feature = "Win32_System_Com")]
+            Sender,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Sender: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Recipient,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Recipient: 0,
+            CurrentPage,
+            DeviceId,
+            Status,
+            ExtendedStatusCode,
+            ExtendedStatus,
+            AvailableOperations,
+            Retries,
+            ScheduledTime,
+            TransmissionStart,
+            TransmissionEnd,
+            CSID,
+            TSID,
+            GroupBroadcastReceipts,
+            Pause,
+            Resume,
+            Restart,
+            CopyTiff,
+            Refresh,
+            Cancel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxOutgoingJob as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxOutgoingJob2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasCoverPage(this: *mut core::ffi::c_void, pbhascoverpage: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob2", "HasCoverPage", 38usize, |this| ((**(this as *const *const IFaxOutgoingJob2_Vtbl)).HasCoverPage)(this, pbhascoverpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceiptAddress(this: *mut core::ffi::c_void, pbstrreceiptaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob2", "ReceiptAddress", 39usize, |this| ((**(this as *const *const IFaxOutgoingJob2_Vtbl)).ReceiptAddress)(this, pbstrreceiptaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScheduleType(this: *mut core::ffi::c_void, pscheduletype: *mut FAX_SCHEDULE_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJob2", "ScheduleType", 40usize, |this| ((**(this as *const *const IFaxOutgoingJob2_Vtbl)).ScheduleType)(this, pscheduletype), |result| Some(*result))
+        }
+        Self { base__: <IFaxOutgoingJob_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HasCoverPage, ReceiptAddress, ScheduleType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxOutgoingJob2 as windows_core::Interface>::IID || <IFaxOutgoingJob_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxOutgoingJobs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJobs", "_NewEnum", 7usize, |this| ((**(this as *const *const IFaxOutgoingJobs_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, vindex: core::mem::MaybeUninit<windows_core::VARIANT>, pfaxoutgoingjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJobs", "get_Item", 8usize, |this| ((**(this as *const *const IFaxOutgoingJobs_Vtbl)).get_Item)(this, vindex, pfaxoutgoingjob), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingJobs", "Count", 9usize, |this| ((**(this as *const *const IFaxOutgoingJobs_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxOutgoingJobs as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxOutgoingMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SubmissionId(this: *mut core::ffi::c_void, pbstrsubmissionid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "SubmissionId", 7usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).SubmissionId)(this, pbstrsubmissionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pbstrid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "Id", 8usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).Id)(this, pbstrid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, pbstrsubject: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "Subject", 9usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).Subject)(this, pbstrsubject), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentName(this: *mut core::ffi::c_void, pbstrdocumentname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "DocumentName", 10usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).DocumentName)(this, pbstrdocumentname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Retries(this: *mut core::ffi::c_void, plretries: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "Retries", 11usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).Retries)(this, plretries), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pages(this: *mut core::ffi::c_void, plpages: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "Pages", 12usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).Pages)(this, plpages), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, plsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "Size", 13usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).Size)(this, plsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn OriginalScheduledTime(this: *mut core::ffi::c_void, pdateoriginalscheduledtime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "OriginalScheduledTime", 14usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).OriginalScheduledTime)(this, pdateoriginalscheduledtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubmissionTime(this: *mut core::ffi::c_void, pdatesubmissiontime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "SubmissionTime", 15usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).SubmissionTime)(this, pdatesubmissiontime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, ppriority: *mut FAX_PRIORITY_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "Priority", 16usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).Priority)(this, ppriority), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Sender(this: *mut core::ffi::c_void, ppfaxsender: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "Sender", 17usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).Sender)(this, ppfaxsender), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Recipient(this: *mut core::ffi::c_void, ppfaxrecipient: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "Recipient", 18usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).Recipient)(this, ppfaxrecipient), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceName(this: *mut core::ffi::c_void, pbstrdevicename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "DeviceName", 19usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).DeviceName)(this, pbstrdevicename), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransmissionStart(this: *mut core::ffi::c_void, pdatetransmissionstart: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "TransmissionStart", 20usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).TransmissionStart)(this, pdatetransmissionstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransmissionEnd(this: *mut core::ffi::c_void, pdatetransmissionend: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "TransmissionEnd", 21usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).TransmissionEnd)(this, pdatetransmissionend), |result| Some(*result))
+        }
+        unsafe extern "system" fn CSID(this: *mut core::ffi::c_void, pbstrcsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "CSID", 22usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).CSID)(this, pbstrcsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TSID(this: *mut core::ffi::c_void, pbstrtsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "TSID", 23usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).TSID)(this, pbstrtsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyTiff(this: *mut core::ffi::c_void, bstrtiffpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "CopyTiff", 24usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).CopyTiff)(this, bstrtiffpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage", "Delete", 25usize, |this| ((**(this as *const *const IFaxOutgoingMessage_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SubmissionId,
+            Id,
+            Subject,
+            DocumentName,
+            Retries,
+            Pages,
+            Size,
+            OriginalScheduledTime,
+            SubmissionTime,
+            Priority,
+            #[cfg(feature = "Win32_System_Com")]
+            Sender,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Sender: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Recipient,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Recipient: 0,
+            DeviceName,
+            TransmissionStart,
+            TransmissionEnd,
+            CSID,
+            TSID,
+            CopyTiff,
+            Delete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxOutgoingMessage as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxOutgoingMessage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasCoverPage(this: *mut core::ffi::c_void, pbhascoverpage: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage2", "HasCoverPage", 26usize, |this| ((**(this as *const *const IFaxOutgoingMessage2_Vtbl)).HasCoverPage)(this, pbhascoverpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceiptType(this: *mut core::ffi::c_void, preceipttype: *mut FAX_RECEIPT_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage2", "ReceiptType", 27usize, |this| ((**(this as *const *const IFaxOutgoingMessage2_Vtbl)).ReceiptType)(this, preceipttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceiptAddress(this: *mut core::ffi::c_void, pbstrreceiptaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage2", "ReceiptAddress", 28usize, |this| ((**(this as *const *const IFaxOutgoingMessage2_Vtbl)).ReceiptAddress)(this, pbstrreceiptaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, pbread: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage2", "Read", 29usize, |this| ((**(this as *const *const IFaxOutgoingMessage2_Vtbl)).Read)(this, pbread), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRead(this: *mut core::ffi::c_void, bread: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage2", "SetRead", 30usize, |this| ((**(this as *const *const IFaxOutgoingMessage2_Vtbl)).SetRead)(this, bread), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage2", "Save", 31usize, |this| ((**(this as *const *const IFaxOutgoingMessage2_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessage2", "Refresh", 32usize, |this| ((**(this as *const *const IFaxOutgoingMessage2_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IFaxOutgoingMessage_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HasCoverPage,
+            ReceiptType,
+            ReceiptAddress,
+            Read,
+            SetRead,
+            Save,
+            Refresh,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxOutgoingMessage2 as windows_core::Interface>::IID || <IFaxOutgoingMessage_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxOutgoingMessageIterator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, pfaxoutgoingmessage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessageIterator", "Message", 7usize, |this| ((**(this as *const *const IFaxOutgoingMessageIterator_Vtbl)).Message)(this, pfaxoutgoingmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn AtEOF(this: *mut core::ffi::c_void, pbeof: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessageIterator", "AtEOF", 8usize, |this| ((**(this as *const *const IFaxOutgoingMessageIterator_Vtbl)).AtEOF)(this, pbeof), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrefetchSize(this: *mut core::ffi::c_void, plprefetchsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessageIterator", "PrefetchSize", 9usize, |this| ((**(this as *const *const IFaxOutgoingMessageIterator_Vtbl)).PrefetchSize)(this, plprefetchsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrefetchSize(this: *mut core::ffi::c_void, lprefetchsize: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessageIterator", "SetPrefetchSize", 10usize, |this| ((**(this as *const *const IFaxOutgoingMessageIterator_Vtbl)).SetPrefetchSize)(this, lprefetchsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveFirst(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessageIterator", "MoveFirst", 11usize, |this| ((**(this as *const *const IFaxOutgoingMessageIterator_Vtbl)).MoveFirst)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingMessageIterator", "MoveNext", 12usize, |this| ((**(this as *const *const IFaxOutgoingMessageIterator_Vtbl)).MoveNext)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Message,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Message: 0,
+            AtEOF,
+            PrefetchSize,
+            SetPrefetchSize,
+            MoveFirst,
+            MoveNext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxOutgoingMessageIterator as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxOutgoingQueue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Blocked(this: *mut core::ffi::c_void, pbblocked: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "Blocked", 7usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).Blocked)(this, pbblocked), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlocked(this: *mut core::ffi::c_void, bblocked: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "SetBlocked", 8usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).SetBlocked)(this, bblocked), |result| Some(*result))
+        }
+        unsafe extern "system" fn Paused(this: *mut core::ffi::c_void, pbpaused: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "Paused", 9usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).Paused)(this, pbpaused), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPaused(this: *mut core::ffi::c_void, bpaused: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "SetPaused", 10usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).SetPaused)(this, bpaused), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowPersonalCoverPages(this: *mut core::ffi::c_void, pballowpersonalcoverpages: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "AllowPersonalCoverPages", 11usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).AllowPersonalCoverPages)(this, pballowpersonalcoverpages), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowPersonalCoverPages(this: *mut core::ffi::c_void, ballowpersonalcoverpages: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "SetAllowPersonalCoverPages", 12usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).SetAllowPersonalCoverPages)(this, ballowpersonalcoverpages), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseDeviceTSID(this: *mut core::ffi::c_void, pbusedevicetsid: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "UseDeviceTSID", 13usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).UseDeviceTSID)(this, pbusedevicetsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseDeviceTSID(this: *mut core::ffi::c_void, busedevicetsid: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "SetUseDeviceTSID", 14usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).SetUseDeviceTSID)(this, busedevicetsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Retries(this: *mut core::ffi::c_void, plretries: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "Retries", 15usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).Retries)(this, plretries), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRetries(this: *mut core::ffi::c_void, lretries: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "SetRetries", 16usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).SetRetries)(this, lretries), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetryDelay(this: *mut core::ffi::c_void, plretrydelay: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "RetryDelay", 17usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).RetryDelay)(this, plretrydelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRetryDelay(this: *mut core::ffi::c_void, lretrydelay: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "SetRetryDelay", 18usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).SetRetryDelay)(this, lretrydelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscountRateStart(this: *mut core::ffi::c_void, pdatediscountratestart: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "DiscountRateStart", 19usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).DiscountRateStart)(this, pdatediscountratestart), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDiscountRateStart(this: *mut core::ffi::c_void, datediscountratestart: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "SetDiscountRateStart", 20usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).SetDiscountRateStart)(this, datediscountratestart), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscountRateEnd(this: *mut core::ffi::c_void, pdatediscountrateend: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "DiscountRateEnd", 21usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).DiscountRateEnd)(this, pdatediscountrateend), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDiscountRateEnd(this: *mut core::ffi::c_void, datediscountrateend: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "SetDiscountRateEnd", 22usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).SetDiscountRateEnd)(this, datediscountrateend), |result| Some(*result))
+        }
+        unsafe extern "system" fn AgeLimit(this: *mut core::ffi::c_void, plagelimit: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "AgeLimit", 23usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).AgeLimit)(this, plagelimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAgeLimit(this: *mut core::ffi::c_void, lagelimit: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "SetAgeLimit", 24usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).SetAgeLimit)(this, lagelimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn Branding(this: *mut core::ffi::c_void, pbbranding: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "Branding", 25usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).Branding)(this, pbbranding), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBranding(this: *mut core::ffi::c_void, bbranding: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "SetBranding", 26usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).SetBranding)(this, bbranding), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "Refresh", 27usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "Save", 28usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetJobs(this: *mut core::ffi::c_void, pfaxoutgoingjobs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "GetJobs", 29usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).GetJobs)(this, pfaxoutgoingjobs), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetJob(this: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>, pfaxoutgoingjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxOutgoingQueue", "GetJob", 30usize, |this| ((**(this as *const *const IFaxOutgoingQueue_Vtbl)).GetJob)(this, bstrjobid, pfaxoutgoingjob), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Blocked,
+            SetBlocked,
+            Paused,
+            SetPaused,
+            AllowPersonalCoverPages,
+            SetAllowPersonalCoverPages,
+            UseDeviceTSID,
+            SetUseDeviceTSID,
+            Retries,
+            SetRetries,
+            RetryDelay,
+            SetRetryDelay,
+            DiscountRateStart,
+            SetDiscountRateStart,
+            DiscountRateEnd,
+            SetDiscountRateEnd,
+            AgeLimit,
+            SetAgeLimit,
+            Branding,
+            SetBranding,
+            Refresh,
+            Save,
+            #[cfg(feature = "Win32_System_Com")]
+            GetJobs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetJobs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetJob,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetJob: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxOutgoingQueue as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxReceiptOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AuthenticationType(this: *mut core::ffi::c_void, ptype: *mut FAX_SMTP_AUTHENTICATION_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "AuthenticationType", 7usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).AuthenticationType)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationType(this: *mut core::ffi::c_void, r#type: FAX_SMTP_AUTHENTICATION_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "SetAuthenticationType", 8usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).SetAuthenticationType)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn SMTPServer(this: *mut core::ffi::c_void, pbstrsmtpserver: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "SMTPServer", 9usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).SMTPServer)(this, pbstrsmtpserver), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSMTPServer(this: *mut core::ffi::c_void, bstrsmtpserver: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "SetSMTPServer", 10usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).SetSMTPServer)(this, bstrsmtpserver), |result| Some(*result))
+        }
+        unsafe extern "system" fn SMTPPort(this: *mut core::ffi::c_void, plsmtpport: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "SMTPPort", 11usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).SMTPPort)(this, plsmtpport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSMTPPort(this: *mut core::ffi::c_void, lsmtpport: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "SetSMTPPort", 12usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).SetSMTPPort)(this, lsmtpport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SMTPSender(this: *mut core::ffi::c_void, pbstrsmtpsender: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "SMTPSender", 13usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).SMTPSender)(this, pbstrsmtpsender), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSMTPSender(this: *mut core::ffi::c_void, bstrsmtpsender: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "SetSMTPSender", 14usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).SetSMTPSender)(this, bstrsmtpsender), |result| Some(*result))
+        }
+        unsafe extern "system" fn SMTPUser(this: *mut core::ffi::c_void, pbstrsmtpuser: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "SMTPUser", 15usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).SMTPUser)(this, pbstrsmtpuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSMTPUser(this: *mut core::ffi::c_void, bstrsmtpuser: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "SetSMTPUser", 16usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).SetSMTPUser)(this, bstrsmtpuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowedReceipts(this: *mut core::ffi::c_void, pallowedreceipts: *mut FAX_RECEIPT_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "AllowedReceipts", 17usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).AllowedReceipts)(this, pallowedreceipts), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowedReceipts(this: *mut core::ffi::c_void, allowedreceipts: FAX_RECEIPT_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "SetAllowedReceipts", 18usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).SetAllowedReceipts)(this, allowedreceipts), |result| Some(*result))
+        }
+        unsafe extern "system" fn SMTPPassword(this: *mut core::ffi::c_void, pbstrsmtppassword: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "SMTPPassword", 19usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).SMTPPassword)(this, pbstrsmtppassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSMTPPassword(this: *mut core::ffi::c_void, bstrsmtppassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "SetSMTPPassword", 20usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).SetSMTPPassword)(this, bstrsmtppassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "Refresh", 21usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "Save", 22usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseForInboundRouting(this: *mut core::ffi::c_void, pbuseforinboundrouting: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "UseForInboundRouting", 23usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).UseForInboundRouting)(this, pbuseforinboundrouting), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseForInboundRouting(this: *mut core::ffi::c_void, buseforinboundrouting: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxReceiptOptions", "SetUseForInboundRouting", 24usize, |this| ((**(this as *const *const IFaxReceiptOptions_Vtbl)).SetUseForInboundRouting)(this, buseforinboundrouting), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AuthenticationType,
+            SetAuthenticationType,
+            SMTPServer,
+            SetSMTPServer,
+            SMTPPort,
+            SetSMTPPort,
+            SMTPSender,
+            SetSMTPSender,
+            SMTPUser,
+            SetSMTPUser,
+            AllowedReceipts,
+            SetAllowedReceipts,
+            SMTPPassword,
+            SetSMTPPassword,
+            Refresh,
+            Save,
+            UseForInboundRouting,
+            SetUseForInboundRouting,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxReceiptOptions as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxRecipient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FaxNumber(this: *mut core::ffi::c_void, pbstrfaxnumber: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxRecipient", "FaxNumber", 7usize, |this| ((**(this as *const *const IFaxRecipient_Vtbl)).FaxNumber)(this, pbstrfaxnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFaxNumber(this: *mut core::ffi::c_void, bstrfaxnumber: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxRecipient", "SetFaxNumber", 8usize, |this| ((**(this as *const *const IFaxRecipient_Vtbl)).SetFaxNumber)(this, bstrfaxnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxRecipient", "Name", 9usize, |this| ((**(this as *const *const IFaxRecipient_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxRecipient", "SetName", 10usize, |this| ((**(this as *const *const IFaxRecipient_Vtbl)).SetName)(this, bstrname), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FaxNumber, SetFaxNumber, Name, SetName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxRecipient as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxRecipients_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxRecipients", "_NewEnum", 7usize, |this| ((**(this as *const *const IFaxRecipients_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, ppfaxrecipient: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxRecipients", "get_Item", 8usize, |this| ((**(this as *const *const IFaxRecipients_Vtbl)).get_Item)(this, lindex, ppfaxrecipient), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxRecipients", "Count", 9usize, |this| ((**(this as *const *const IFaxRecipients_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, bstrfaxnumber: core::mem::MaybeUninit<windows_core::BSTR>, bstrrecipientname: core::mem::MaybeUninit<windows_core::BSTR>, ppfaxrecipient: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxRecipients", "Add", 10usize, |this| ((**(this as *const *const IFaxRecipients_Vtbl)).Add)(this, bstrfaxnumber, bstrrecipientname, ppfaxrecipient), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, lindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxRecipients", "Remove", 11usize, |this| ((**(this as *const *const IFaxRecipients_Vtbl)).Remove)(this, lindex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxRecipients as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxSecurity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Descriptor(this: *mut core::ffi::c_void, pvdescriptor: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSecurity", "Descriptor", 7usize, |this| ((**(this as *const *const IFaxSecurity_Vtbl)).Descriptor)(this, pvdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescriptor(this: *mut core::ffi::c_void, vdescriptor: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSecurity", "SetDescriptor", 8usize, |this| ((**(this as *const *const IFaxSecurity_Vtbl)).SetDescriptor)(this, vdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GrantedRights(this: *mut core::ffi::c_void, pgrantedrights: *mut FAX_ACCESS_RIGHTS_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSecurity", "GrantedRights", 9usize, |this| ((**(this as *const *const IFaxSecurity_Vtbl)).GrantedRights)(this, pgrantedrights), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSecurity", "Refresh", 10usize, |this| ((**(this as *const *const IFaxSecurity_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSecurity", "Save", 11usize, |this| ((**(this as *const *const IFaxSecurity_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InformationType(this: *mut core::ffi::c_void, plinformationtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSecurity", "InformationType", 12usize, |this| ((**(this as *const *const IFaxSecurity_Vtbl)).InformationType)(this, plinformationtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInformationType(this: *mut core::ffi::c_void, linformationtype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSecurity", "SetInformationType", 13usize, |this| ((**(this as *const *const IFaxSecurity_Vtbl)).SetInformationType)(this, linformationtype), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Descriptor,
+            SetDescriptor,
+            GrantedRights,
+            Refresh,
+            Save,
+            InformationType,
+            SetInformationType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxSecurity as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxSecurity2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Descriptor(this: *mut core::ffi::c_void, pvdescriptor: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSecurity2", "Descriptor", 7usize, |this| ((**(this as *const *const IFaxSecurity2_Vtbl)).Descriptor)(this, pvdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescriptor(this: *mut core::ffi::c_void, vdescriptor: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSecurity2", "SetDescriptor", 8usize, |this| ((**(this as *const *const IFaxSecurity2_Vtbl)).SetDescriptor)(this, vdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GrantedRights(this: *mut core::ffi::c_void, pgrantedrights: *mut FAX_ACCESS_RIGHTS_ENUM_2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSecurity2", "GrantedRights", 9usize, |this| ((**(this as *const *const IFaxSecurity2_Vtbl)).GrantedRights)(this, pgrantedrights), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSecurity2", "Refresh", 10usize, |this| ((**(this as *const *const IFaxSecurity2_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSecurity2", "Save", 11usize, |this| ((**(this as *const *const IFaxSecurity2_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InformationType(this: *mut core::ffi::c_void, plinformationtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSecurity2", "InformationType", 12usize, |this| ((**(this as *const *const IFaxSecurity2_Vtbl)).InformationType)(this, plinformationtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInformationType(this: *mut core::ffi::c_void, linformationtype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSecurity2", "SetInformationType", 13usize, |this| ((**(this as *const *const IFaxSecurity2_Vtbl)).SetInformationType)(this, linformationtype), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Descriptor,
+            SetDescriptor,
+            GrantedRights,
+            Refresh,
+            Save,
+            InformationType,
+            SetInformationType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxSecurity2 as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxSender_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BillingCode(this: *mut core::ffi::c_void, pbstrbillingcode: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "BillingCode", 7usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).BillingCode)(this, pbstrbillingcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBillingCode(this: *mut core::ffi::c_void, bstrbillingcode: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetBillingCode", 8usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetBillingCode)(this, bstrbillingcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn City(this: *mut core::ffi::c_void, pbstrcity: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "City", 9usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).City)(this, pbstrcity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCity(this: *mut core::ffi::c_void, bstrcity: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetCity", 10usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetCity)(this, bstrcity), |result| Some(*result))
+        }
+        unsafe extern "system" fn Company(this: *mut core::ffi::c_void, pbstrcompany: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "Company", 11usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).Company)(this, pbstrcompany), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompany(this: *mut core::ffi::c_void, bstrcompany: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetCompany", 12usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetCompany)(this, bstrcompany), |result| Some(*result))
+        }
+        unsafe extern "system" fn Country(this: *mut core::ffi::c_void, pbstrcountry: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "Country", 13usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).Country)(this, pbstrcountry), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCountry(this: *mut core::ffi::c_void, bstrcountry: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetCountry", 14usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetCountry)(this, bstrcountry), |result| Some(*result))
+        }
+        unsafe extern "system" fn Department(this: *mut core::ffi::c_void, pbstrdepartment: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "Department", 15usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).Department)(this, pbstrdepartment), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDepartment(this: *mut core::ffi::c_void, bstrdepartment: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetDepartment", 16usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetDepartment)(this, bstrdepartment), |result| Some(*result))
+        }
+        unsafe extern "system" fn Email(this: *mut core::ffi::c_void, pbstremail: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "Email", 17usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).Email)(this, pbstremail), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEmail(this: *mut core::ffi::c_void, bstremail: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetEmail", 18usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetEmail)(this, bstremail), |result| Some(*result))
+        }
+        unsafe extern "system" fn FaxNumber(this: *mut core::ffi::c_void, pbstrfaxnumber: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "FaxNumber", 19usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).FaxNumber)(this, pbstrfaxnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFaxNumber(this: *mut core::ffi::c_void, bstrfaxnumber: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetFaxNumber", 20usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetFaxNumber)(this, bstrfaxnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn HomePhone(this: *mut core::ffi::c_void, pbstrhomephone: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "HomePhone", 21usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).HomePhone)(this, pbstrhomephone), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHomePhone(this: *mut core::ffi::c_void, bstrhomephone: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetHomePhone", 22usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetHomePhone)(this, bstrhomephone), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "Name", 23usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetName", 24usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetName)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn TSID(this: *mut core::ffi::c_void, pbstrtsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "TSID", 25usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).TSID)(this, pbstrtsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTSID(this: *mut core::ffi::c_void, bstrtsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetTSID", 26usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetTSID)(this, bstrtsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OfficePhone(this: *mut core::ffi::c_void, pbstrofficephone: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "OfficePhone", 27usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).OfficePhone)(this, pbstrofficephone), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOfficePhone(this: *mut core::ffi::c_void, bstrofficephone: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetOfficePhone", 28usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetOfficePhone)(this, bstrofficephone), |result| Some(*result))
+        }
+        unsafe extern "system" fn OfficeLocation(this: *mut core::ffi::c_void, pbstrofficelocation: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "OfficeLocation", 29usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).OfficeLocation)(this, pbstrofficelocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOfficeLocation(this: *mut core::ffi::c_void, bstrofficelocation: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetOfficeLocation", 30usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetOfficeLocation)(this, bstrofficelocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, pbstrstate: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "State", 31usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).State)(this, pbstrstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, bstrstate: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetState", 32usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetState)(this, bstrstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn StreetAddress(this: *mut core::ffi::c_void, pbstrstreetaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "StreetAddress", 33usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).StreetAddress)(this, pbstrstreetaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreetAddress(this: *mut core::ffi::c_void, bstrstreetaddress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetStreetAddress", 34usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetStreetAddress)(this, bstrstreetaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, pbstrtitle: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "Title", 35usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).Title)(this, pbstrtitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, bstrtitle: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetTitle", 36usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetTitle)(this, bstrtitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZipCode(this: *mut core::ffi::c_void, pbstrzipcode: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "ZipCode", 37usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).ZipCode)(this, pbstrzipcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetZipCode(this: *mut core::ffi::c_void, bstrzipcode: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SetZipCode", 38usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SetZipCode)(this, bstrzipcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadDefaultSender(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "LoadDefaultSender", 39usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).LoadDefaultSender)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveDefaultSender(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxSender", "SaveDefaultSender", 40usize, |this| ((**(this as *const *const IFaxSender_Vtbl)).SaveDefaultSender)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BillingCode,
+            SetBillingCode,
+            City,
+            SetCity,
+            Company,
+            SetCompany,
+            Country,
+            SetCountry,
+            Department,
+            SetDepartment,
+            Email,
+            SetEmail,
+            FaxNumber,
+            SetFaxNumber,
+            HomePhone,
+            SetHomePhone,
+            Name,
+            SetName,
+            TSID,
+            SetTSID,
+            OfficePhone,
+            SetOfficePhone,
+            OfficeLocation,
+            SetOfficeLocation,
+            State,
+            SetState,
+            StreetAddress,
+            SetStreetAddress,
+            Title,
+            SetTitle,
+            ZipCode,
+            SetZipCode,
+            LoadDefaultSender,
+            SaveDefaultSender,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxSender as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxServer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, bstrservername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "Connect", 7usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).Connect)(this, bstrservername), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerName(this: *mut core::ffi::c_void, pbstrservername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "ServerName", 8usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).ServerName)(this, pbstrservername), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDeviceProviders(this: *mut core::ffi::c_void, ppfaxdeviceproviders: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "GetDeviceProviders", 9usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).GetDeviceProviders)(this, ppfaxdeviceproviders), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDevices(this: *mut core::ffi::c_void, ppfaxdevices: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "GetDevices", 10usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).GetDevices)(this, ppfaxdevices), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InboundRouting(this: *mut core::ffi::c_void, ppfaxinboundrouting: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "InboundRouting", 11usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).InboundRouting)(this, ppfaxinboundrouting), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Folders(this: *mut core::ffi::c_void, pfaxfolders: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "Folders", 12usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).Folders)(this, pfaxfolders), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LoggingOptions(this: *mut core::ffi::c_void, ppfaxloggingoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "LoggingOptions", 13usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).LoggingOptions)(this, ppfaxloggingoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn MajorVersion(this: *mut core::ffi::c_void, plmajorversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "MajorVersion", 14usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).MajorVersion)(this, plmajorversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinorVersion(this: *mut core::ffi::c_void, plminorversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "MinorVersion", 15usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).MinorVersion)(this, plminorversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn MajorBuild(this: *mut core::ffi::c_void, plmajorbuild: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "MajorBuild", 16usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).MajorBuild)(this, plmajorbuild), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinorBuild(this: *mut core::ffi::c_void, plminorbuild: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "MinorBuild", 17usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).MinorBuild)(this, plminorbuild), |result| Some(*result))
+        }
+        unsafe extern "system" fn Debug(this: *mut core::ffi::c_void, pbdebug: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "Debug", 18usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).Debug)(this, pbdebug), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Activity(this: *mut core::ffi::c_void, ppfaxactivity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "Activity", 19usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).Activity)(this, ppfaxactivity), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OutboundRouting(this: *mut core::ffi::c_void, ppfaxoutboundrouting: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "OutboundRouting", 20usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).OutboundRouting)(this, ppfaxoutboundrouting), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiptOptions(this: *mut core::ffi::c_void, ppfaxreceiptoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "ReceiptOptions", 21usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).ReceiptOptions)(this, ppfaxreceiptoptions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Security(this: *mut core::ffi::c_void, ppfaxsecurity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "Security", 22usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).Security)(this, ppfaxsecurity), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "Disconnect", 23usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExtensionProperty(this: *mut core::ffi::c_void, bstrguid: core::mem::MaybeUninit<windows_core::BSTR>, pvproperty: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "GetExtensionProperty", 24usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).GetExtensionProperty)(this, bstrguid, pvproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtensionProperty(this: *mut core::ffi::c_void, bstrguid: core::mem::MaybeUninit<windows_core::BSTR>, vproperty: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "SetExtensionProperty", 25usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).SetExtensionProperty)(this, bstrguid, vproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn ListenToServerEvents(this: *mut core::ffi::c_void, eventtypes: FAX_SERVER_EVENTS_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "ListenToServerEvents", 26usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).ListenToServerEvents)(this, eventtypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterDeviceProvider(this: *mut core::ffi::c_void, bstrguid: core::mem::MaybeUninit<windows_core::BSTR>, bstrfriendlyname: core::mem::MaybeUninit<windows_core::BSTR>, bstrimagename: core::mem::MaybeUninit<windows_core::BSTR>, tspname: core::mem::MaybeUninit<windows_core::BSTR>, lfspiversion: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "RegisterDeviceProvider", 27usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).RegisterDeviceProvider)(this, bstrguid, bstrfriendlyname, bstrimagename, tspname, lfspiversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterDeviceProvider(this: *mut core::ffi::c_void, bstruniquename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "UnregisterDeviceProvider", 28usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).UnregisterDeviceProvider)(this, bstruniquename), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterInboundRoutingExtension(this: *mut core::ffi::c_void, bstrextensionname: core::mem::MaybeUninit<windows_core::BSTR>, bstrfriendlyname: core::mem::MaybeUninit<windows_core::BSTR>, bstrimagename: core::mem::MaybeUninit<windows_core::BSTR>, vmethods: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "RegisterInboundRoutingExtension", 29usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).RegisterInboundRoutingExtension)(this, bstrextensionname, bstrfriendlyname, bstrimagename, vmethods), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterInboundRoutingExtension(this: *mut core::ffi::c_void, bstrextensionuniquename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "UnregisterInboundRoutingExtension", 30usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).UnregisterInboundRoutingExtension)(this, bstrextensionuniquename), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisteredEvents(this: *mut core::ffi::c_void, peventtypes: *mut FAX_SERVER_EVENTS_TYPE_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "RegisteredEvents", 31usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).RegisteredEvents)(this, peventtypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn APIVersion(this: *mut core::ffi::c_void, papiversion: *mut FAX_SERVER_APIVERSION_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer", "APIVersion", 32usize, |this| ((**(this as *const *const IFaxServer_Vtbl)).APIVersion)(this, papiversion), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Connect,
+            ServerName,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDeviceProviders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDeviceProviders: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDevices,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDevices: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InboundRouting,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InboundRouting: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Folders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Folders: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            LoggingOptions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LoggingOptions: 0,
+            MajorVersion,
+            MinorVersion,
+            MajorBuild,
+            MinorBuild,
+            Debug,
+            #[cfg(feature = "Win32_System_Com")]
+            Activity,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Activity: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OutboundRouting,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OutboundRouting: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiptOptions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiptOptions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Security,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Security: 0,
+            Disconnect,
+            GetExtensionProperty,
+            SetExtensionProperty,
+            ListenToServerEvents,
+            RegisterDeviceProvider,
+            UnregisterDeviceProvider,
+            RegisterInboundRoutingExtension,
+            UnregisterInboundRoutingExtension,
+            RegisteredEvents,
+            APIVersion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxServer as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxServer2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, ppfaxconfiguration: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer2", "Configuration", 33usize, |this| ((**(this as *const *const IFaxServer2_Vtbl)).Configuration)(this, ppfaxconfiguration), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CurrentAccount(this: *mut core::ffi::c_void, ppcurrentaccount: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer2", "CurrentAccount", 34usize, |this| ((**(this as *const *const IFaxServer2_Vtbl)).CurrentAccount)(this, ppcurrentaccount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FaxAccountSet(this: *mut core::ffi::c_void, ppfaxaccountset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer2", "FaxAccountSet", 35usize, |this| ((**(this as *const *const IFaxServer2_Vtbl)).FaxAccountSet)(this, ppfaxaccountset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Security2(this: *mut core::ffi::c_void, ppfaxsecurity2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServer2", "Security2", 36usize, |this| ((**(this as *const *const IFaxServer2_Vtbl)).Security2)(this, ppfaxsecurity2), |result| Some(*result))
+        }
+        Self {
+            base__: <IFaxServer_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Configuration,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Configuration: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CurrentAccount,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CurrentAccount: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            FaxAccountSet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FaxAccountSet: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Security2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Security2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxServer2 as windows_core::Interface>::IID || <IFaxServer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxServerNotify_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxServerNotify as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFaxServerNotify2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnIncomingJobAdded(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnIncomingJobAdded", 7usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnIncomingJobAdded)(this, pfaxserver, bstrjobid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnIncomingJobRemoved(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnIncomingJobRemoved", 8usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnIncomingJobRemoved)(this, pfaxserver, bstrjobid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnIncomingJobChanged(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>, pjobstatus: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnIncomingJobChanged", 9usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnIncomingJobChanged)(this, pfaxserver, bstrjobid, pjobstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnOutgoingJobAdded(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnOutgoingJobAdded", 10usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnOutgoingJobAdded)(this, pfaxserver, bstrjobid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnOutgoingJobRemoved(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnOutgoingJobRemoved", 11usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnOutgoingJobRemoved)(this, pfaxserver, bstrjobid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnOutgoingJobChanged(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, bstrjobid: core::mem::MaybeUninit<windows_core::BSTR>, pjobstatus: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnOutgoingJobChanged", 12usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnOutgoingJobChanged)(this, pfaxserver, bstrjobid, pjobstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnIncomingMessageAdded(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, bstrmessageid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnIncomingMessageAdded", 13usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnIncomingMessageAdded)(this, pfaxserver, bstrmessageid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnIncomingMessageRemoved(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, bstrmessageid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnIncomingMessageRemoved", 14usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnIncomingMessageRemoved)(this, pfaxserver, bstrmessageid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnOutgoingMessageAdded(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, bstrmessageid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnOutgoingMessageAdded", 15usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnOutgoingMessageAdded)(this, pfaxserver, bstrmessageid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnOutgoingMessageRemoved(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, bstrmessageid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnOutgoingMessageRemoved", 16usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnOutgoingMessageRemoved)(this, pfaxserver, bstrmessageid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnReceiptOptionsChange(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnReceiptOptionsChange", 17usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnReceiptOptionsChange)(this, pfaxserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnActivityLoggingConfigChange(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnActivityLoggingConfigChange", 18usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnActivityLoggingConfigChange)(this, pfaxserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnSecurityConfigChange(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnSecurityConfigChange", 19usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnSecurityConfigChange)(this, pfaxserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnEventLoggingConfigChange(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnEventLoggingConfigChange", 20usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnEventLoggingConfigChange)(this, pfaxserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnOutgoingQueueConfigChange(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnOutgoingQueueConfigChange", 21usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnOutgoingQueueConfigChange)(this, pfaxserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnOutgoingArchiveConfigChange(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnOutgoingArchiveConfigChange", 22usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnOutgoingArchiveConfigChange)(this, pfaxserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnIncomingArchiveConfigChange(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnIncomingArchiveConfigChange", 23usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnIncomingArchiveConfigChange)(this, pfaxserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnDevicesConfigChange(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnDevicesConfigChange", 24usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnDevicesConfigChange)(this, pfaxserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnOutboundRoutingGroupsConfigChange(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnOutboundRoutingGroupsConfigChange", 25usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnOutboundRoutingGroupsConfigChange)(this, pfaxserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnOutboundRoutingRulesConfigChange(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnOutboundRoutingRulesConfigChange", 26usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnOutboundRoutingRulesConfigChange)(this, pfaxserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnServerActivityChange(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, lincomingmessages: i32, lroutingmessages: i32, loutgoingmessages: i32, lqueuedmessages: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnServerActivityChange", 27usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnServerActivityChange)(this, pfaxserver, lincomingmessages, lroutingmessages, loutgoingmessages, lqueuedmessages), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnQueuesStatusChange(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, boutgoingqueueblocked: super::super::Foundation::VARIANT_BOOL, boutgoingqueuepaused: super::super::Foundation::VARIANT_BOOL, bincomingqueueblocked: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnQueuesStatusChange", 28usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnQueuesStatusChange)(this, pfaxserver, boutgoingqueueblocked, boutgoingqueuepaused, bincomingqueueblocked), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnNewCall(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, lcallid: i32, ldeviceid: i32, bstrcallerid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnNewCall", 29usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnNewCall)(this, pfaxserver, lcallid, ldeviceid, bstrcallerid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnServerShutDown(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnServerShutDown", 30usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnServerShutDown)(this, pfaxserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnDeviceStatusChange(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void, ldeviceid: i32, bpoweredoff: super::super::Foundation::VARIANT_BOOL, bsending: super::super::Foundation::VARIANT_BOOL, breceiving: super::super::Foundation::VARIANT_BOOL, bringing: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnDeviceStatusChange", 31usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnDeviceStatusChange)(this, pfaxserver, ldeviceid, bpoweredoff, bsending, breceiving, bringing), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnGeneralServerConfigChanged(this: *mut core::ffi::c_void, pfaxserver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFaxServerNotify2", "OnGeneralServerConfigChanged", 32usize, |this| ((**(this as *const *const IFaxServerNotify2_Vtbl)).OnGeneralServerConfigChanged)(this, pfaxserver), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OnIncomingJobAdded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnIncomingJobAdded: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnIncomingJobRemoved,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnIncomingJobRemoved: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnIncomingJobChanged,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnIncomingJobChanged: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnOutgoingJobAdded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnOutgoingJobAdded: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnOutgoingJobRemoved,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnOutgoingJobRemoved: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnOutgoingJobChanged,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnOutgoingJobChanged: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnIncomingMessageAdded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnIncomingMessageAdded: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnIncomingMessageRemoved,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnIncomingMessageRemoved: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnOutgoingMessageAdded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnOutgoingMessageAdded: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnOutgoingMessageRemoved,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnOutgoingMessageRemoved: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnReceiptOptionsChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnReceiptOptionsChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnActivityLoggingConfigChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnActivityLoggingConfigChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnSecurityConfigChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnSecurityConfigChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnEventLoggingConfigChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnEventLoggingConfigChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnOutgoingQueueConfigChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnOutgoingQueueConfigChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnOutgoingArchiveConfigChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnOutgoingArchiveConfigChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnIncomingArchiveConfigChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnIncomingArchiveConfigChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnDevicesConfigChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnDevicesConfigChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnOutboundRoutingGroupsConfigChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnOutboundRoutingGroupsConfigChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnOutboundRoutingRulesConfigChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnOutboundRoutingRulesConfigChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnServerActivityChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnServerActivityChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnQueuesStatusChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnQueuesStatusChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnNewCall,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnNewCall: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnServerShutDown,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnServerShutDown: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnDeviceStatusChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnDeviceStatusChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnGeneralServerConfigChanged,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnGeneralServerConfigChanged: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFaxServerNotify2 as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_IO")]
+impl windows_core::imp::ProxyVtable for IStiDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, hinst: super::super::Foundation::HINSTANCE, pwszdevicename: windows_core::PCWSTR, dwversion: u32, dwmode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "Initialize", 3usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).Initialize)(this, hinst, pwszdevicename, dwversion, dwmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, pdevcaps: *mut STI_DEV_CAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "GetCapabilities", 4usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).GetCapabilities)(this, pdevcaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdevstatus: *mut STI_DEVICE_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "GetStatus", 5usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).GetStatus)(this, pdevstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceReset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "DeviceReset", 6usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).DeviceReset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Diagnostic(this: *mut core::ffi::c_void, pbuffer: *mut STI_DIAG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "Diagnostic", 7usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).Diagnostic)(this, pbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Escape(this: *mut core::ffi::c_void, escapefunction: u32, lpindata: *const core::ffi::c_void, cbindatasize: u32, poutdata: *mut core::ffi::c_void, dwoutdatasize: u32, pdwactualdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "Escape", 8usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).Escape)(this, escapefunction, lpindata, cbindatasize, poutdata, dwoutdatasize, pdwactualdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastError(this: *mut core::ffi::c_void, pdwlastdeviceerror: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "GetLastError", 9usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).GetLastError)(this, pdwlastdeviceerror), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockDevice(this: *mut core::ffi::c_void, dwtimeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "LockDevice", 10usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).LockDevice)(this, dwtimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnLockDevice(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "UnLockDevice", 11usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).UnLockDevice)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_IO")]
+        unsafe extern "system" fn RawReadData(this: *mut core::ffi::c_void, lpbuffer: *mut core::ffi::c_void, lpdwnumberofbytes: *mut u32, lpoverlapped: *const super::super::System::IO::OVERLAPPED) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "RawReadData", 12usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).RawReadData)(this, lpbuffer, lpdwnumberofbytes, lpoverlapped), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_IO")]
+        unsafe extern "system" fn RawWriteData(this: *mut core::ffi::c_void, lpbuffer: *const core::ffi::c_void, nnumberofbytes: u32, lpoverlapped: *const super::super::System::IO::OVERLAPPED) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "RawWriteData", 13usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).RawWriteData)(this, lpbuffer, nnumberofbytes, lpoverlapped), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_IO")]
+        unsafe extern "system" fn RawReadCommand(this: *mut core::ffi::c_void, lpbuffer: *mut core::ffi::c_void, lpdwnumberofbytes: *mut u32, lpoverlapped: *const super::super::System::IO::OVERLAPPED) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "RawReadCommand", 14usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).RawReadCommand)(this, lpbuffer, lpdwnumberofbytes, lpoverlapped), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_IO")]
+        unsafe extern "system" fn RawWriteCommand(this: *mut core::ffi::c_void, lpbuffer: *const core::ffi::c_void, nnumberofbytes: u32, lpoverlapped: *const super::super::System::IO::OVERLAPPED) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "RawWriteCommand", 15usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).RawWriteCommand)(this, lpbuffer, nnumberofbytes, lpoverlapped), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subscribe(this: *mut core::ffi::c_void, lpsubsribe: *mut STISUBSCRIBE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "Subscribe", 16usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).Subscribe)(this, lpsubsribe), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastNotificationData(this: *mut core::ffi::c_void, lpnotify: *mut STINOTIFY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "GetLastNotificationData", 17usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).GetLastNotificationData)(this, lpnotify), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnSubscribe(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "UnSubscribe", 18usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).UnSubscribe)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastErrorInfo(this: *mut core::ffi::c_void, plasterrorinfo: *mut _ERROR_INFOW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDevice", "GetLastErrorInfo", 19usize, |this| ((**(this as *const *const IStiDevice_Vtbl)).GetLastErrorInfo)(this, plasterrorinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetCapabilities,
+            GetStatus,
+            DeviceReset,
+            Diagnostic,
+            Escape,
+            GetLastError,
+            LockDevice,
+            UnLockDevice,
+            #[cfg(feature = "Win32_System_IO")]
+            RawReadData,
+            #[cfg(not(feature = "Win32_System_IO"))]
+            RawReadData: 0,
+            #[cfg(feature = "Win32_System_IO")]
+            RawWriteData,
+            #[cfg(not(feature = "Win32_System_IO"))]
+            RawWriteData: 0,
+            #[cfg(feature = "Win32_System_IO")]
+            RawReadCommand,
+            #[cfg(not(feature = "Win32_System_IO"))]
+            RawReadCommand: 0,
+            #[cfg(feature = "Win32_System_IO")]
+            RawWriteCommand,
+            #[cfg(not(feature = "Win32_System_IO"))]
+            RawWriteCommand: 0,
+            Subscribe,
+            GetLastNotificationData,
+            UnSubscribe,
+            GetLastErrorInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStiDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_IO")]
+impl windows_core::imp::ProxyVtable for IStiDeviceControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, dwdevicetype: u32, dwmode: u32, pwszportname: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDeviceControl", "Initialize", 3usize, |this| ((**(this as *const *const IStiDeviceControl_Vtbl)).Initialize)(this, dwdevicetype, dwmode, pwszportname, dwflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_IO")]
+        unsafe extern "system" fn RawReadData(this: *mut core::ffi::c_void, lpbuffer: *mut core::ffi::c_void, lpdwnumberofbytes: *mut u32, lpoverlapped: *mut super::super::System::IO::OVERLAPPED) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDeviceControl", "RawReadData", 4usize, |this| ((**(this as *const *const IStiDeviceControl_Vtbl)).RawReadData)(this, lpbuffer, lpdwnumberofbytes, lpoverlapped), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_IO")]
+        unsafe extern "system" fn RawWriteData(this: *mut core::ffi::c_void, lpbuffer: *mut core::ffi::c_void, nnumberofbytes: u32, lpoverlapped: *mut super::super::System::IO::OVERLAPPED) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDeviceControl", "RawWriteData", 5usize, |this| ((**(this as *const *const IStiDeviceControl_Vtbl)).RawWriteData)(this, lpbuffer, nnumberofbytes, lpoverlapped), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_IO")]
+        unsafe extern "system" fn RawReadCommand(this: *mut core::ffi::c_void, lpbuffer: *mut core::ffi::c_void, lpdwnumberofbytes: *mut u32, lpoverlapped: *mut super::super::System::IO::OVERLAPPED) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDeviceControl", "RawReadCommand", 6usize, |this| ((**(this as *const *const IStiDeviceControl_Vtbl)).RawReadCommand)(this, lpbuffer, lpdwnumberofbytes, lpoverlapped), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_IO")]
+        unsafe extern "system" fn RawWriteCommand(this: *mut core::ffi::c_void, lpbuffer: *mut core::ffi::c_void, nnumberofbytes: u32, lpoverlapped: *mut super::super::System::IO::OVERLAPPED) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDeviceControl", "RawWriteCommand", 7usize, |this| ((**(this as *const *const IStiDeviceControl_Vtbl)).RawWriteCommand)(this, lpbuffer, nnumberofbytes, lpoverlapped), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawDeviceControl(this: *mut core::ffi::c_void, escapefunction: u32, lpindata: *mut core::ffi::c_void, cbindatasize: u32, poutdata: *mut core::ffi::c_void, dwoutdatasize: u32, pdwactualdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDeviceControl", "RawDeviceControl", 8usize, |this| ((**(this as *const *const IStiDeviceControl_Vtbl)).RawDeviceControl)(this, escapefunction, lpindata, cbindatasize, poutdata, dwoutdatasize, pdwactualdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastError(this: *mut core::ffi::c_void, lpdwlasterror: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDeviceControl", "GetLastError", 9usize, |this| ((**(this as *const *const IStiDeviceControl_Vtbl)).GetLastError)(this, lpdwlasterror), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMyDevicePortName(this: *mut core::ffi::c_void, lpszdevicepath: windows_core::PWSTR, cwdevicepathsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDeviceControl", "GetMyDevicePortName", 10usize, |this| ((**(this as *const *const IStiDeviceControl_Vtbl)).GetMyDevicePortName)(this, lpszdevicepath, cwdevicepathsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMyDeviceHandle(this: *mut core::ffi::c_void, lph: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDeviceControl", "GetMyDeviceHandle", 11usize, |this| ((**(this as *const *const IStiDeviceControl_Vtbl)).GetMyDeviceHandle)(this, lph), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMyDeviceOpenMode(this: *mut core::ffi::c_void, pdwopenmode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDeviceControl", "GetMyDeviceOpenMode", 12usize, |this| ((**(this as *const *const IStiDeviceControl_Vtbl)).GetMyDeviceOpenMode)(this, pdwopenmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteToErrorLog(this: *mut core::ffi::c_void, dwmessagetype: u32, pszmessage: windows_core::PCWSTR, dwerrorcode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiDeviceControl", "WriteToErrorLog", 13usize, |this| ((**(this as *const *const IStiDeviceControl_Vtbl)).WriteToErrorLog)(this, dwmessagetype, pszmessage, dwerrorcode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            #[cfg(feature = "Win32_System_IO")]
+            RawReadData,
+            #[cfg(not(feature = "Win32_System_IO"))]
+            RawReadData: 0,
+            #[cfg(feature = "Win32_System_IO")]
+            RawWriteData,
+            #[cfg(not(feature = "Win32_System_IO"))]
+            RawWriteData: 0,
+            #[cfg(feature = "Win32_System_IO")]
+            RawReadCommand,
+            #[cfg(not(feature = "Win32_System_IO"))]
+            RawReadCommand: 0,
+            #[cfg(feature = "Win32_System_IO")]
+            RawWriteCommand,
+            #[cfg(not(feature = "Win32_System_IO"))]
+            RawWriteCommand: 0,
+            RawDeviceControl,
+            GetLastError,
+            GetMyDevicePortName,
+            GetMyDeviceHandle,
+            GetMyDeviceOpenMode,
+            WriteToErrorLog,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStiDeviceControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_IO", feature = "Win32_System_Registry"))]
+impl windows_core::imp::ProxyVtable for IStiUSD_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Registry")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pheldcb: *mut core::ffi::c_void, dwstiversion: u32, hparameterskey: super::super::System::Registry::HKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "Initialize", 3usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).Initialize)(this, pheldcb, dwstiversion, hparameterskey), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, pdevcaps: *mut STI_USD_CAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "GetCapabilities", 4usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).GetCapabilities)(this, pdevcaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdevstatus: *mut STI_DEVICE_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "GetStatus", 5usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).GetStatus)(this, pdevstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceReset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "DeviceReset", 6usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).DeviceReset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Diagnostic(this: *mut core::ffi::c_void, pbuffer: *mut STI_DIAG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "Diagnostic", 7usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).Diagnostic)(this, pbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Escape(this: *mut core::ffi::c_void, escapefunction: u32, lpindata: *const core::ffi::c_void, cbindatasize: u32, poutdata: *mut core::ffi::c_void, cboutdatasize: u32, pdwactualdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "Escape", 8usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).Escape)(this, escapefunction, lpindata, cbindatasize, poutdata, cboutdatasize, pdwactualdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastError(this: *mut core::ffi::c_void, pdwlastdeviceerror: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "GetLastError", 9usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).GetLastError)(this, pdwlastdeviceerror), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockDevice(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "LockDevice", 10usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).LockDevice)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnLockDevice(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "UnLockDevice", 11usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).UnLockDevice)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_IO")]
+        unsafe extern "system" fn RawReadData(this: *mut core::ffi::c_void, lpbuffer: *mut core::ffi::c_void, lpdwnumberofbytes: *mut u32, lpoverlapped: *const super::super::System::IO::OVERLAPPED) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "RawReadData", 12usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).RawReadData)(this, lpbuffer, lpdwnumberofbytes, lpoverlapped), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_IO")]
+        unsafe extern "system" fn RawWriteData(this: *mut core::ffi::c_void, lpbuffer: *const core::ffi::c_void, nnumberofbytes: u32, lpoverlapped: *const super::super::System::IO::OVERLAPPED) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "RawWriteData", 13usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).RawWriteData)(this, lpbuffer, nnumberofbytes, lpoverlapped), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_IO")]
+        unsafe extern "system" fn RawReadCommand(this: *mut core::ffi::c_void, lpbuffer: *mut core::ffi::c_void, lpdwnumberofbytes: *mut u32, lpoverlapped: *const super::super::System::IO::OVERLAPPED) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "RawReadCommand", 14usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).RawReadCommand)(this, lpbuffer, lpdwnumberofbytes, lpoverlapped), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_IO")]
+        unsafe extern "system" fn RawWriteCommand(this: *mut core::ffi::c_void, lpbuffer: *const core::ffi::c_void, nnumberofbytes: u32, lpoverlapped: *const super::super::System::IO::OVERLAPPED) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "RawWriteCommand", 15usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).RawWriteCommand)(this, lpbuffer, nnumberofbytes, lpoverlapped), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotificationHandle(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "SetNotificationHandle", 16usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).SetNotificationHandle)(this, hevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNotificationData(this: *mut core::ffi::c_void, lpnotify: *mut STINOTIFY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "GetNotificationData", 17usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).GetNotificationData)(this, lpnotify), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastErrorInfo(this: *mut core::ffi::c_void, plasterrorinfo: *mut _ERROR_INFOW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStiUSD", "GetLastErrorInfo", 18usize, |this| ((**(this as *const *const IStiUSD_Vtbl)).GetLastErrorInfo)(this, plasterrorinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Registry")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Registry"))]
+            Initialize: 0,
+            GetCapabilities,
+            GetStatus,
+            DeviceReset,
+            Diagnostic,
+            Escape,
+            GetLastError,
+            LockDevice,
+            UnLockDevice,
+            #[cfg(feature = "Win32_System_IO")]
+            RawReadData,
+            #[cfg(not(feature = "Win32_System_IO"))]
+            RawReadData: 0,
+            #[cfg(feature = "Win32_System_IO")]
+            RawWriteData,
+            #[cfg(not(feature = "Win32_System_IO"))]
+            RawWriteData: 0,
+            #[cfg(feature = "Win32_System_IO")]
+            RawReadCommand,
+            #[cfg(not(feature = "Win32_System_IO"))]
+            RawReadCommand: 0,
+            #[cfg(feature = "Win32_System_IO")]
+            RawWriteCommand,
+            #[cfg(not(feature = "Win32_System_IO"))]
+            RawWriteCommand: 0,
+            SetNotificationHandle,
+            GetNotificationData,
+            GetLastErrorInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStiUSD as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStillImageW_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, hinst: super::super::Foundation::HINSTANCE, dwversion: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStillImageW", "Initialize", 3usize, |this| ((**(this as *const *const IStillImageW_Vtbl)).Initialize)(this, hinst, dwversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceList(this: *mut core::ffi::c_void, dwtype: u32, dwflags: u32, pdwitemsreturned: *mut u32, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStillImageW", "GetDeviceList", 4usize, |this| ((**(this as *const *const IStillImageW_Vtbl)).GetDeviceList)(this, dwtype, dwflags, pdwitemsreturned, ppbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceInfo(this: *mut core::ffi::c_void, pwszdevicename: windows_core::PCWSTR, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStillImageW", "GetDeviceInfo", 5usize, |this| ((**(this as *const *const IStillImageW_Vtbl)).GetDeviceInfo)(this, pwszdevicename, ppbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, pwszdevicename: windows_core::PCWSTR, dwmode: u32, pdevice: *mut *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStillImageW", "CreateDevice", 6usize, |this| ((**(this as *const *const IStillImageW_Vtbl)).CreateDevice)(this, pwszdevicename, dwmode, pdevice, punkouter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceValue(this: *mut core::ffi::c_void, pwszdevicename: windows_core::PCWSTR, pvaluename: windows_core::PCWSTR, ptype: *mut u32, pdata: *mut u8, cbdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStillImageW", "GetDeviceValue", 7usize, |this| ((**(this as *const *const IStillImageW_Vtbl)).GetDeviceValue)(this, pwszdevicename, pvaluename, ptype, pdata, cbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeviceValue(this: *mut core::ffi::c_void, pwszdevicename: windows_core::PCWSTR, pvaluename: windows_core::PCWSTR, r#type: u32, pdata: *const u8, cbdata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStillImageW", "SetDeviceValue", 8usize, |this| ((**(this as *const *const IStillImageW_Vtbl)).SetDeviceValue)(this, pwszdevicename, pvaluename, r#type, pdata, cbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSTILaunchInformation(this: *mut core::ffi::c_void, pwszdevicename: windows_core::PWSTR, pdweventcode: *mut u32, pwszeventname: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStillImageW", "GetSTILaunchInformation", 9usize, |this| ((**(this as *const *const IStillImageW_Vtbl)).GetSTILaunchInformation)(this, pwszdevicename, pdweventcode, pwszeventname), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterLaunchApplication(this: *mut core::ffi::c_void, pwszappname: windows_core::PCWSTR, pwszcommandline: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStillImageW", "RegisterLaunchApplication", 10usize, |this| ((**(this as *const *const IStillImageW_Vtbl)).RegisterLaunchApplication)(this, pwszappname, pwszcommandline), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterLaunchApplication(this: *mut core::ffi::c_void, pwszappname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStillImageW", "UnregisterLaunchApplication", 11usize, |this| ((**(this as *const *const IStillImageW_Vtbl)).UnregisterLaunchApplication)(this, pwszappname), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableHwNotifications(this: *mut core::ffi::c_void, pwszdevicename: windows_core::PCWSTR, bnewstate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStillImageW", "EnableHwNotifications", 12usize, |this| ((**(this as *const *const IStillImageW_Vtbl)).EnableHwNotifications)(this, pwszdevicename, bnewstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHwNotificationState(this: *mut core::ffi::c_void, pwszdevicename: windows_core::PCWSTR, pbcurrentstate: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStillImageW", "GetHwNotificationState", 13usize, |this| ((**(this as *const *const IStillImageW_Vtbl)).GetHwNotificationState)(this, pwszdevicename, pbcurrentstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefreshDeviceBus(this: *mut core::ffi::c_void, pwszdevicename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStillImageW", "RefreshDeviceBus", 14usize, |this| ((**(this as *const *const IStillImageW_Vtbl)).RefreshDeviceBus)(this, pwszdevicename), |result| Some(*result))
+        }
+        unsafe extern "system" fn LaunchApplicationForDevice(this: *mut core::ffi::c_void, pwszdevicename: windows_core::PCWSTR, pwszappname: windows_core::PCWSTR, pstinotify: *const STINOTIFY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStillImageW", "LaunchApplicationForDevice", 15usize, |this| ((**(this as *const *const IStillImageW_Vtbl)).LaunchApplicationForDevice)(this, pwszdevicename, pwszappname, pstinotify), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetupDeviceParameters(this: *mut core::ffi::c_void, param0: *mut STI_DEVICE_INFORMATIONW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStillImageW", "SetupDeviceParameters", 16usize, |this| ((**(this as *const *const IStillImageW_Vtbl)).SetupDeviceParameters)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteToErrorLog(this: *mut core::ffi::c_void, dwmessagetype: u32, pszmessage: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStillImageW", "WriteToErrorLog", 17usize, |this| ((**(this as *const *const IStillImageW_Vtbl)).WriteToErrorLog)(this, dwmessagetype, pszmessage), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetDeviceList,
+            GetDeviceInfo,
+            CreateDevice,
+            GetDeviceValue,
+            SetDeviceValue,
+            GetSTILaunchInformation,
+            RegisterLaunchApplication,
+            UnregisterLaunchApplication,
+            EnableHwNotifications,
+            GetHwNotificationState,
+            RefreshDeviceBus,
+            LaunchApplicationForDevice,
+            SetupDeviceParameters,
+            WriteToErrorLog,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStillImageW as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1453,6 +1453,7 @@ pub struct PropertyConstraint(pub i32);
 impl windows_core::TypeKind for PropertyConstraint {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PropertyConstraint {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PropertyConstraint").field(&self.0).finish()
@@ -1464,6 +1465,7 @@ pub struct QueryCategoryType(pub i32);
 impl windows_core::TypeKind for QueryCategoryType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QueryCategoryType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QueryCategoryType").field(&self.0).finish()
@@ -1475,6 +1477,7 @@ pub struct QueryUpdateAction(pub i32);
 impl windows_core::TypeKind for QueryUpdateAction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QueryUpdateAction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QueryUpdateAction").field(&self.0).finish()
@@ -1486,6 +1489,7 @@ pub struct SystemVisibilityFlags(pub i32);
 impl windows_core::TypeKind for SystemVisibilityFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SystemVisibilityFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SystemVisibilityFlags").field(&self.0).finish()
@@ -1499,3 +1503,5 @@ pub const PropertyStore: windows_core::GUID = windows_core::GUID::from_u128(0xe4
 pub const PropertyStoreCollection: windows_core::GUID = windows_core::GUID::from_u128(0xedd36029_d753_4862_aa5b_5bccad2a4d29);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,586 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFunctionDiscovery_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInstanceCollection(this: *mut core::ffi::c_void, pszcategory: windows_core::PCWSTR, pszsubcategory: windows_core::PCWSTR, fincludeallsubcategories: super::super::Foundation::BOOL, ppifunctioninstancecollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscovery", "GetInstanceCollection", 3usize, |this| ((**(this as *const *const IFunctionDiscovery_Vtbl)).GetInstanceCollection)(this, pszcategory, pszsubcategory, fincludeallsubcategories, ppifunctioninstancecollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetInstance(this: *mut core::ffi::c_void, pszfunctioninstanceidentity: windows_core::PCWSTR, ppifunctioninstance: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscovery", "GetInstance", 4usize, |this| ((**(this as *const *const IFunctionDiscovery_Vtbl)).GetInstance)(this, pszfunctioninstanceidentity, ppifunctioninstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstanceCollectionQuery(this: *mut core::ffi::c_void, pszcategory: windows_core::PCWSTR, pszsubcategory: windows_core::PCWSTR, fincludeallsubcategories: super::super::Foundation::BOOL, pifunctiondiscoverynotification: *mut core::ffi::c_void, pfdqcquerycontext: *mut u64, ppifunctioninstancecollectionquery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscovery", "CreateInstanceCollectionQuery", 5usize, |this| ((**(this as *const *const IFunctionDiscovery_Vtbl)).CreateInstanceCollectionQuery)(this, pszcategory, pszsubcategory, fincludeallsubcategories, pifunctiondiscoverynotification, pfdqcquerycontext, ppifunctioninstancecollectionquery), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstanceQuery(this: *mut core::ffi::c_void, pszfunctioninstanceidentity: windows_core::PCWSTR, pifunctiondiscoverynotification: *mut core::ffi::c_void, pfdqcquerycontext: *mut u64, ppifunctioninstancequery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscovery", "CreateInstanceQuery", 6usize, |this| ((**(this as *const *const IFunctionDiscovery_Vtbl)).CreateInstanceQuery)(this, pszfunctioninstanceidentity, pifunctiondiscoverynotification, pfdqcquerycontext, ppifunctioninstancequery), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddInstance(this: *mut core::ffi::c_void, enumsystemvisibility: SystemVisibilityFlags, pszcategory: windows_core::PCWSTR, pszsubcategory: windows_core::PCWSTR, pszcategoryidentity: windows_core::PCWSTR, ppifunctioninstance: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscovery", "AddInstance", 7usize, |this| ((**(this as *const *const IFunctionDiscovery_Vtbl)).AddInstance)(this, enumsystemvisibility, pszcategory, pszsubcategory, pszcategoryidentity, ppifunctioninstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveInstance(this: *mut core::ffi::c_void, enumsystemvisibility: SystemVisibilityFlags, pszcategory: windows_core::PCWSTR, pszsubcategory: windows_core::PCWSTR, pszcategoryidentity: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscovery", "RemoveInstance", 8usize, |this| ((**(this as *const *const IFunctionDiscovery_Vtbl)).RemoveInstance)(this, enumsystemvisibility, pszcategory, pszsubcategory, pszcategoryidentity), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetInstanceCollection,
+            #[cfg(feature = "Win32_System_Com")]
+            GetInstance,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetInstance: 0,
+            CreateInstanceCollectionQuery,
+            CreateInstanceQuery,
+            #[cfg(feature = "Win32_System_Com")]
+            AddInstance,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddInstance: 0,
+            RemoveInstance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFunctionDiscovery as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFunctionDiscoveryNotification_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnUpdate(this: *mut core::ffi::c_void, enumqueryupdateaction: QueryUpdateAction, fdqcquerycontext: u64, pifunctioninstance: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryNotification", "OnUpdate", 3usize, |this| ((**(this as *const *const IFunctionDiscoveryNotification_Vtbl)).OnUpdate)(this, enumqueryupdateaction, fdqcquerycontext, pifunctioninstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnError(this: *mut core::ffi::c_void, hr: windows_core::HRESULT, fdqcquerycontext: u64, pszprovider: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryNotification", "OnError", 4usize, |this| ((**(this as *const *const IFunctionDiscoveryNotification_Vtbl)).OnError)(this, hr, fdqcquerycontext, pszprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEvent(this: *mut core::ffi::c_void, dweventid: u32, fdqcquerycontext: u64, pszprovider: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryNotification", "OnEvent", 5usize, |this| ((**(this as *const *const IFunctionDiscoveryNotification_Vtbl)).OnEvent)(this, dweventid, fdqcquerycontext, pszprovider), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OnUpdate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnUpdate: 0,
+            OnError,
+            OnEvent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFunctionDiscoveryNotification as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for IFunctionDiscoveryProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pifunctiondiscoveryproviderfactory: *mut core::ffi::c_void, pifunctiondiscoverynotification: *mut core::ffi::c_void, lciduserdefault: u32, pdwstgaccesscapabilities: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryProvider", "Initialize", 3usize, |this| ((**(this as *const *const IFunctionDiscoveryProvider_Vtbl)).Initialize)(this, pifunctiondiscoveryproviderfactory, pifunctiondiscoverynotification, lciduserdefault, pdwstgaccesscapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn Query(this: *mut core::ffi::c_void, pifunctiondiscoveryproviderquery: *mut core::ffi::c_void, ppifunctioninstancecollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryProvider", "Query", 4usize, |this| ((**(this as *const *const IFunctionDiscoveryProvider_Vtbl)).Query)(this, pifunctiondiscoveryproviderquery, ppifunctioninstancecollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndQuery(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryProvider", "EndQuery", 5usize, |this| ((**(this as *const *const IFunctionDiscoveryProvider_Vtbl)).EndQuery)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InstancePropertyStoreValidateAccess(this: *mut core::ffi::c_void, pifunctioninstance: *mut core::ffi::c_void, iproviderinstancecontext: isize, dwstgaccess: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryProvider", "InstancePropertyStoreValidateAccess", 6usize, |this| ((**(this as *const *const IFunctionDiscoveryProvider_Vtbl)).InstancePropertyStoreValidateAccess)(this, pifunctioninstance, iproviderinstancecontext, dwstgaccess), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn InstancePropertyStoreOpen(this: *mut core::ffi::c_void, pifunctioninstance: *mut core::ffi::c_void, iproviderinstancecontext: isize, dwstgaccess: u32, ppipropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryProvider", "InstancePropertyStoreOpen", 7usize, |this| ((**(this as *const *const IFunctionDiscoveryProvider_Vtbl)).InstancePropertyStoreOpen)(this, pifunctioninstance, iproviderinstancecontext, dwstgaccess, ppipropertystore), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InstancePropertyStoreFlush(this: *mut core::ffi::c_void, pifunctioninstance: *mut core::ffi::c_void, iproviderinstancecontext: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryProvider", "InstancePropertyStoreFlush", 8usize, |this| ((**(this as *const *const IFunctionDiscoveryProvider_Vtbl)).InstancePropertyStoreFlush)(this, pifunctioninstance, iproviderinstancecontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InstanceQueryService(this: *mut core::ffi::c_void, pifunctioninstance: *mut core::ffi::c_void, iproviderinstancecontext: isize, guidservice: *const windows_core::GUID, riid: *const windows_core::GUID, ppiunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryProvider", "InstanceQueryService", 9usize, |this| ((**(this as *const *const IFunctionDiscoveryProvider_Vtbl)).InstanceQueryService)(this, pifunctioninstance, iproviderinstancecontext, guidservice, riid, ppiunknown), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InstanceReleased(this: *mut core::ffi::c_void, pifunctioninstance: *mut core::ffi::c_void, iproviderinstancecontext: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryProvider", "InstanceReleased", 10usize, |this| ((**(this as *const *const IFunctionDiscoveryProvider_Vtbl)).InstanceReleased)(this, pifunctioninstance, iproviderinstancecontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Query,
+            EndQuery,
+            #[cfg(feature = "Win32_System_Com")]
+            InstancePropertyStoreValidateAccess,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InstancePropertyStoreValidateAccess: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            InstancePropertyStoreOpen,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            InstancePropertyStoreOpen: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InstancePropertyStoreFlush,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InstancePropertyStoreFlush: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InstanceQueryService,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InstanceQueryService: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InstanceReleased,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InstanceReleased: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFunctionDiscoveryProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for IFunctionDiscoveryProviderFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn CreatePropertyStore(this: *mut core::ffi::c_void, ppipropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryProviderFactory", "CreatePropertyStore", 3usize, |this| ((**(this as *const *const IFunctionDiscoveryProviderFactory_Vtbl)).CreatePropertyStore)(this, ppipropertystore), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, pszsubcategory: windows_core::PCWSTR, pszproviderinstanceidentity: windows_core::PCWSTR, iproviderinstancecontext: isize, pipropertystore: *mut core::ffi::c_void, pifunctiondiscoveryprovider: *mut core::ffi::c_void, ppifunctioninstance: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryProviderFactory", "CreateInstance", 4usize, |this| ((**(this as *const *const IFunctionDiscoveryProviderFactory_Vtbl)).CreateInstance)(this, pszsubcategory, pszproviderinstanceidentity, iproviderinstancecontext, pipropertystore, pifunctiondiscoveryprovider, ppifunctioninstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFunctionInstanceCollection(this: *mut core::ffi::c_void, ppifunctioninstancecollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryProviderFactory", "CreateFunctionInstanceCollection", 5usize, |this| ((**(this as *const *const IFunctionDiscoveryProviderFactory_Vtbl)).CreateFunctionInstanceCollection)(this, ppifunctioninstancecollection), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            CreatePropertyStore,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CreatePropertyStore: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CreateInstance,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            CreateInstance: 0,
+            CreateFunctionInstanceCollection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFunctionDiscoveryProviderFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFunctionDiscoveryProviderQuery_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsInstanceQuery(this: *mut core::ffi::c_void, pisinstancequery: *mut super::super::Foundation::BOOL, ppszconstraintvalue: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryProviderQuery", "IsInstanceQuery", 3usize, |this| ((**(this as *const *const IFunctionDiscoveryProviderQuery_Vtbl)).IsInstanceQuery)(this, pisinstancequery, ppszconstraintvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSubcategoryQuery(this: *mut core::ffi::c_void, pissubcategoryquery: *mut super::super::Foundation::BOOL, ppszconstraintvalue: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryProviderQuery", "IsSubcategoryQuery", 4usize, |this| ((**(this as *const *const IFunctionDiscoveryProviderQuery_Vtbl)).IsSubcategoryQuery)(this, pissubcategoryquery, ppszconstraintvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQueryConstraints(this: *mut core::ffi::c_void, ppiproviderqueryconstraints: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryProviderQuery", "GetQueryConstraints", 5usize, |this| ((**(this as *const *const IFunctionDiscoveryProviderQuery_Vtbl)).GetQueryConstraints)(this, ppiproviderqueryconstraints), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyConstraints(this: *mut core::ffi::c_void, ppiproviderpropertyconstraints: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryProviderQuery", "GetPropertyConstraints", 6usize, |this| ((**(this as *const *const IFunctionDiscoveryProviderQuery_Vtbl)).GetPropertyConstraints)(this, ppiproviderpropertyconstraints), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsInstanceQuery,
+            IsSubcategoryQuery,
+            GetQueryConstraints,
+            GetPropertyConstraints,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFunctionDiscoveryProviderQuery as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFunctionDiscoveryServiceProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pifunctioninstance: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionDiscoveryServiceProvider", "Initialize", 3usize, |this| ((**(this as *const *const IFunctionDiscoveryServiceProvider_Vtbl)).Initialize)(this, pifunctioninstance, riid, ppv), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFunctionDiscoveryServiceProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for IFunctionInstance_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetID(this: *mut core::ffi::c_void, ppszcomemidentity: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionInstance", "GetID", 4usize, |this| ((**(this as *const *const IFunctionInstance_Vtbl)).GetID)(this, ppszcomemidentity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProviderInstanceID(this: *mut core::ffi::c_void, ppszcomemproviderinstanceidentity: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionInstance", "GetProviderInstanceID", 5usize, |this| ((**(this as *const *const IFunctionInstance_Vtbl)).GetProviderInstanceID)(this, ppszcomemproviderinstanceidentity), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn OpenPropertyStore(this: *mut core::ffi::c_void, dwstgaccess: super::super::System::Com::STGM, ppipropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionInstance", "OpenPropertyStore", 6usize, |this| ((**(this as *const *const IFunctionInstance_Vtbl)).OpenPropertyStore)(this, dwstgaccess, ppipropertystore), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCategory(this: *mut core::ffi::c_void, ppszcomemcategory: *mut *mut u16, ppszcomemsubcategory: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionInstance", "GetCategory", 7usize, |this| ((**(this as *const *const IFunctionInstance_Vtbl)).GetCategory)(this, ppszcomemcategory, ppszcomemsubcategory), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IServiceProvider_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetID,
+            GetProviderInstanceID,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            OpenPropertyStore,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            OpenPropertyStore: 0,
+            GetCategory,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFunctionInstance as windows_core::Interface>::IID || <super::super::System::Com::IServiceProvider_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFunctionInstanceCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionInstanceCollection", "GetCount", 3usize, |this| ((**(this as *const *const IFunctionInstanceCollection_Vtbl)).GetCount)(this, pdwcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, pszinstanceidentity: windows_core::PCWSTR, pdwindex: *mut u32, ppifunctioninstance: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionInstanceCollection", "Get", 4usize, |this| ((**(this as *const *const IFunctionInstanceCollection_Vtbl)).Get)(this, pszinstanceidentity, pdwindex, ppifunctioninstance), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, dwindex: u32, ppifunctioninstance: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionInstanceCollection", "Item", 5usize, |this| ((**(this as *const *const IFunctionInstanceCollection_Vtbl)).Item)(this, dwindex, ppifunctioninstance), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pifunctioninstance: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionInstanceCollection", "Add", 6usize, |this| ((**(this as *const *const IFunctionInstanceCollection_Vtbl)).Add)(this, pifunctioninstance), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, dwindex: u32, ppifunctioninstance: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionInstanceCollection", "Remove", 7usize, |this| ((**(this as *const *const IFunctionInstanceCollection_Vtbl)).Remove)(this, dwindex, ppifunctioninstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, dwindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionInstanceCollection", "Delete", 8usize, |this| ((**(this as *const *const IFunctionInstanceCollection_Vtbl)).Delete)(this, dwindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionInstanceCollection", "DeleteAll", 9usize, |this| ((**(this as *const *const IFunctionInstanceCollection_Vtbl)).DeleteAll)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            #[cfg(feature = "Win32_System_Com")]
+            Get,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Get: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Remove,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Remove: 0,
+            Delete,
+            DeleteAll,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFunctionInstanceCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IFunctionInstanceCollectionQuery_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddQueryConstraint(this: *mut core::ffi::c_void, pszconstraintname: windows_core::PCWSTR, pszconstraintvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionInstanceCollectionQuery", "AddQueryConstraint", 3usize, |this| ((**(this as *const *const IFunctionInstanceCollectionQuery_Vtbl)).AddQueryConstraint)(this, pszconstraintname, pszconstraintvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn AddPropertyConstraint(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pv: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, enumpropertyconstraint: PropertyConstraint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionInstanceCollectionQuery", "AddPropertyConstraint", 4usize, |this| ((**(this as *const *const IFunctionInstanceCollectionQuery_Vtbl)).AddPropertyConstraint)(this, key, pv, enumpropertyconstraint), |result| Some(*result))
+        }
+        unsafe extern "system" fn Execute(this: *mut core::ffi::c_void, ppifunctioninstancecollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionInstanceCollectionQuery", "Execute", 5usize, |this| ((**(this as *const *const IFunctionInstanceCollectionQuery_Vtbl)).Execute)(this, ppifunctioninstancecollection), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddQueryConstraint,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            AddPropertyConstraint,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            AddPropertyConstraint: 0,
+            Execute,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFunctionInstanceCollectionQuery as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFunctionInstanceQuery_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Execute(this: *mut core::ffi::c_void, ppifunctioninstance: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFunctionInstanceQuery", "Execute", 3usize, |this| ((**(this as *const *const IFunctionInstanceQuery_Vtbl)).Execute)(this, ppifunctioninstance), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Execute,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Execute: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFunctionInstanceQuery as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPNPXAssociation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Associate(this: *mut core::ffi::c_void, pszsubcategory: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPNPXAssociation", "Associate", 3usize, |this| ((**(this as *const *const IPNPXAssociation_Vtbl)).Associate)(this, pszsubcategory), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unassociate(this: *mut core::ffi::c_void, pszsubcategory: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPNPXAssociation", "Unassociate", 4usize, |this| ((**(this as *const *const IPNPXAssociation_Vtbl)).Unassociate)(this, pszsubcategory), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, pszsubcategory: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPNPXAssociation", "Delete", 5usize, |this| ((**(this as *const *const IPNPXAssociation_Vtbl)).Delete)(this, pszsubcategory), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Associate, Unassociate, Delete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPNPXAssociation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPNPXDeviceAssociation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Associate(this: *mut core::ffi::c_void, pszsubcategory: windows_core::PCWSTR, pifunctiondiscoverynotification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPNPXDeviceAssociation", "Associate", 3usize, |this| ((**(this as *const *const IPNPXDeviceAssociation_Vtbl)).Associate)(this, pszsubcategory, pifunctiondiscoverynotification), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unassociate(this: *mut core::ffi::c_void, pszsubcategory: windows_core::PCWSTR, pifunctiondiscoverynotification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPNPXDeviceAssociation", "Unassociate", 4usize, |this| ((**(this as *const *const IPNPXDeviceAssociation_Vtbl)).Unassociate)(this, pszsubcategory, pifunctiondiscoverynotification), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, pszsubcategory: windows_core::PCWSTR, pifunctiondiscoverynotification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPNPXDeviceAssociation", "Delete", 5usize, |this| ((**(this as *const *const IPNPXDeviceAssociation_Vtbl)).Delete)(this, pszsubcategory, pifunctiondiscoverynotification), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Associate, Unassociate, Delete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPNPXDeviceAssociation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IPropertyStoreCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStoreCollection", "GetCount", 3usize, |this| ((**(this as *const *const IPropertyStoreCollection_Vtbl)).GetCount)(this, pdwcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, pszinstanceidentity: windows_core::PCWSTR, pdwindex: *mut u32, ppipropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStoreCollection", "Get", 4usize, |this| ((**(this as *const *const IPropertyStoreCollection_Vtbl)).Get)(this, pszinstanceidentity, pdwindex, ppipropertystore), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, dwindex: u32, ppipropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStoreCollection", "Item", 5usize, |this| ((**(this as *const *const IPropertyStoreCollection_Vtbl)).Item)(this, dwindex, ppipropertystore), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pipropertystore: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStoreCollection", "Add", 6usize, |this| ((**(this as *const *const IPropertyStoreCollection_Vtbl)).Add)(this, pipropertystore), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, dwindex: u32, pipropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStoreCollection", "Remove", 7usize, |this| ((**(this as *const *const IPropertyStoreCollection_Vtbl)).Remove)(this, dwindex, pipropertystore), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, dwindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStoreCollection", "Delete", 8usize, |this| ((**(this as *const *const IPropertyStoreCollection_Vtbl)).Delete)(this, dwindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStoreCollection", "DeleteAll", 9usize, |this| ((**(this as *const *const IPropertyStoreCollection_Vtbl)).DeleteAll)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Get,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Get: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Item,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Item: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Add,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Add: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Remove,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Remove: 0,
+            Delete,
+            DeleteAll,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyStoreCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for IProviderProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pifunctioninstance: *mut core::ffi::c_void, iproviderinstancecontext: isize, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderProperties", "GetCount", 3usize, |this| ((**(this as *const *const IProviderProperties_Vtbl)).GetCount)(this, pifunctioninstance, iproviderinstancecontext, pdwcount), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, pifunctioninstance: *mut core::ffi::c_void, iproviderinstancecontext: isize, dwindex: u32, pkey: *mut super::super::UI::Shell::PropertiesSystem::PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderProperties", "GetAt", 4usize, |this| ((**(this as *const *const IProviderProperties_Vtbl)).GetAt)(this, pifunctioninstance, iproviderinstancecontext, dwindex, pkey), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, pifunctioninstance: *mut core::ffi::c_void, iproviderinstancecontext: isize, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppropvar: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderProperties", "GetValue", 5usize, |this| ((**(this as *const *const IProviderProperties_Vtbl)).GetValue)(this, pifunctioninstance, iproviderinstancecontext, key, ppropvar), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, pifunctioninstance: *mut core::ffi::c_void, iproviderinstancecontext: isize, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppropvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderProperties", "SetValue", 6usize, |this| ((**(this as *const *const IProviderProperties_Vtbl)).SetValue)(this, pifunctioninstance, iproviderinstancecontext, key, ppropvar), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCount,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCount: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetAt,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            GetAt: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetValue,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            GetValue: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetValue,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            SetValue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProviderProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IProviderPropertyConstraintCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderPropertyConstraintCollection", "GetCount", 3usize, |this| ((**(this as *const *const IProviderPropertyConstraintCollection_Vtbl)).GetCount)(this, pdwcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppropvar: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>, pdwpropertyconstraint: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderPropertyConstraintCollection", "Get", 4usize, |this| ((**(this as *const *const IProviderPropertyConstraintCollection_Vtbl)).Get)(this, key, ppropvar, pdwpropertyconstraint), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, dwindex: u32, pkey: *mut super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppropvar: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>, pdwpropertyconstraint: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderPropertyConstraintCollection", "Item", 5usize, |this| ((**(this as *const *const IProviderPropertyConstraintCollection_Vtbl)).Item)(this, dwindex, pkey, ppropvar, pdwpropertyconstraint), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, pkey: *mut super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppropvar: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>, pdwpropertyconstraint: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderPropertyConstraintCollection", "Next", 6usize, |this| ((**(this as *const *const IProviderPropertyConstraintCollection_Vtbl)).Next)(this, pkey, ppropvar, pdwpropertyconstraint), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderPropertyConstraintCollection", "Skip", 7usize, |this| ((**(this as *const *const IProviderPropertyConstraintCollection_Vtbl)).Skip)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderPropertyConstraintCollection", "Reset", 8usize, |this| ((**(this as *const *const IProviderPropertyConstraintCollection_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Get,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Get: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Item,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Item: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Next,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Next: 0,
+            Skip,
+            Reset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProviderPropertyConstraintCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IProviderPublishing_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, enumvisibilityflags: SystemVisibilityFlags, pszsubcategory: windows_core::PCWSTR, pszproviderinstanceidentity: windows_core::PCWSTR, ppifunctioninstance: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderPublishing", "CreateInstance", 3usize, |this| ((**(this as *const *const IProviderPublishing_Vtbl)).CreateInstance)(this, enumvisibilityflags, pszsubcategory, pszproviderinstanceidentity, ppifunctioninstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveInstance(this: *mut core::ffi::c_void, enumvisibilityflags: SystemVisibilityFlags, pszsubcategory: windows_core::PCWSTR, pszproviderinstanceidentity: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderPublishing", "RemoveInstance", 4usize, |this| ((**(this as *const *const IProviderPublishing_Vtbl)).RemoveInstance)(this, enumvisibilityflags, pszsubcategory, pszproviderinstanceidentity), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateInstance,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateInstance: 0,
+            RemoveInstance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProviderPublishing as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProviderQueryConstraintCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderQueryConstraintCollection", "GetCount", 3usize, |this| ((**(this as *const *const IProviderQueryConstraintCollection_Vtbl)).GetCount)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, pszconstraintname: windows_core::PCWSTR, ppszconstraintvalue: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderQueryConstraintCollection", "Get", 4usize, |this| ((**(this as *const *const IProviderQueryConstraintCollection_Vtbl)).Get)(this, pszconstraintname, ppszconstraintvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, dwindex: u32, ppszconstraintname: *mut *mut u16, ppszconstraintvalue: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderQueryConstraintCollection", "Item", 5usize, |this| ((**(this as *const *const IProviderQueryConstraintCollection_Vtbl)).Item)(this, dwindex, ppszconstraintname, ppszconstraintvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ppszconstraintname: *mut *mut u16, ppszconstraintvalue: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderQueryConstraintCollection", "Next", 6usize, |this| ((**(this as *const *const IProviderQueryConstraintCollection_Vtbl)).Next)(this, ppszconstraintname, ppszconstraintvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderQueryConstraintCollection", "Skip", 7usize, |this| ((**(this as *const *const IProviderQueryConstraintCollection_Vtbl)).Skip)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderQueryConstraintCollection", "Reset", 8usize, |this| ((**(this as *const *const IProviderQueryConstraintCollection_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, Get, Item, Next, Skip, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProviderQueryConstraintCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,381 @@
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for ICivicAddressReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAddressLine1(this: *mut core::ffi::c_void, pbstraddress1: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICivicAddressReport", "GetAddressLine1", 6usize, |this| ((**(this as *const *const ICivicAddressReport_Vtbl)).GetAddressLine1)(this, pbstraddress1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAddressLine2(this: *mut core::ffi::c_void, pbstraddress2: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICivicAddressReport", "GetAddressLine2", 7usize, |this| ((**(this as *const *const ICivicAddressReport_Vtbl)).GetAddressLine2)(this, pbstraddress2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCity(this: *mut core::ffi::c_void, pbstrcity: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICivicAddressReport", "GetCity", 8usize, |this| ((**(this as *const *const ICivicAddressReport_Vtbl)).GetCity)(this, pbstrcity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStateProvince(this: *mut core::ffi::c_void, pbstrstateprovince: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICivicAddressReport", "GetStateProvince", 9usize, |this| ((**(this as *const *const ICivicAddressReport_Vtbl)).GetStateProvince)(this, pbstrstateprovince), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPostalCode(this: *mut core::ffi::c_void, pbstrpostalcode: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICivicAddressReport", "GetPostalCode", 10usize, |this| ((**(this as *const *const ICivicAddressReport_Vtbl)).GetPostalCode)(this, pbstrpostalcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountryRegion(this: *mut core::ffi::c_void, pbstrcountryregion: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICivicAddressReport", "GetCountryRegion", 11usize, |this| ((**(this as *const *const ICivicAddressReport_Vtbl)).GetCountryRegion)(this, pbstrcountryregion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDetailLevel(this: *mut core::ffi::c_void, pdetaillevel: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICivicAddressReport", "GetDetailLevel", 12usize, |this| ((**(this as *const *const ICivicAddressReport_Vtbl)).GetDetailLevel)(this, pdetaillevel), |result| Some(*result))
+        }
+        Self {
+            base__: <ILocationReport_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAddressLine1,
+            GetAddressLine2,
+            GetCity,
+            GetStateProvince,
+            GetPostalCode,
+            GetCountryRegion,
+            GetDetailLevel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICivicAddressReport as windows_core::Interface>::IID || <ILocationReport_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICivicAddressReportFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CivicAddressReport(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICivicAddressReportFactory", "CivicAddressReport", 15usize, |this| ((**(this as *const *const ICivicAddressReportFactory_Vtbl)).CivicAddressReport)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <ILocationReportFactory_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CivicAddressReport,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CivicAddressReport: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICivicAddressReportFactory as windows_core::Interface>::IID || <ILocationReportFactory_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDefaultLocation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetReport(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, plocationreport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDefaultLocation", "SetReport", 3usize, |this| ((**(this as *const *const IDefaultLocation_Vtbl)).SetReport)(this, reporttype, plocationreport), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReport(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, pplocationreport: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDefaultLocation", "GetReport", 4usize, |this| ((**(this as *const *const IDefaultLocation_Vtbl)).GetReport)(this, reporttype, pplocationreport), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetReport, GetReport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDefaultLocation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDispCivicAddressReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddressLine1(this: *mut core::ffi::c_void, paddress1: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispCivicAddressReport", "AddressLine1", 7usize, |this| ((**(this as *const *const IDispCivicAddressReport_Vtbl)).AddressLine1)(this, paddress1), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddressLine2(this: *mut core::ffi::c_void, paddress2: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispCivicAddressReport", "AddressLine2", 8usize, |this| ((**(this as *const *const IDispCivicAddressReport_Vtbl)).AddressLine2)(this, paddress2), |result| Some(*result))
+        }
+        unsafe extern "system" fn City(this: *mut core::ffi::c_void, pcity: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispCivicAddressReport", "City", 9usize, |this| ((**(this as *const *const IDispCivicAddressReport_Vtbl)).City)(this, pcity), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateProvince(this: *mut core::ffi::c_void, pstateprovince: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispCivicAddressReport", "StateProvince", 10usize, |this| ((**(this as *const *const IDispCivicAddressReport_Vtbl)).StateProvince)(this, pstateprovince), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostalCode(this: *mut core::ffi::c_void, ppostalcode: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispCivicAddressReport", "PostalCode", 11usize, |this| ((**(this as *const *const IDispCivicAddressReport_Vtbl)).PostalCode)(this, ppostalcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn CountryRegion(this: *mut core::ffi::c_void, pcountryregion: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispCivicAddressReport", "CountryRegion", 12usize, |this| ((**(this as *const *const IDispCivicAddressReport_Vtbl)).CountryRegion)(this, pcountryregion), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetailLevel(this: *mut core::ffi::c_void, pdetaillevel: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispCivicAddressReport", "DetailLevel", 13usize, |this| ((**(this as *const *const IDispCivicAddressReport_Vtbl)).DetailLevel)(this, pdetaillevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispCivicAddressReport", "Timestamp", 14usize, |this| ((**(this as *const *const IDispCivicAddressReport_Vtbl)).Timestamp)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddressLine1,
+            AddressLine2,
+            City,
+            StateProvince,
+            PostalCode,
+            CountryRegion,
+            DetailLevel,
+            Timestamp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDispCivicAddressReport as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDispLatLongReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Latitude(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispLatLongReport", "Latitude", 7usize, |this| ((**(this as *const *const IDispLatLongReport_Vtbl)).Latitude)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Longitude(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispLatLongReport", "Longitude", 8usize, |this| ((**(this as *const *const IDispLatLongReport_Vtbl)).Longitude)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorRadius(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispLatLongReport", "ErrorRadius", 9usize, |this| ((**(this as *const *const IDispLatLongReport_Vtbl)).ErrorRadius)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Altitude(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispLatLongReport", "Altitude", 10usize, |this| ((**(this as *const *const IDispLatLongReport_Vtbl)).Altitude)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn AltitudeError(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispLatLongReport", "AltitudeError", 11usize, |this| ((**(this as *const *const IDispLatLongReport_Vtbl)).AltitudeError)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispLatLongReport", "Timestamp", 12usize, |this| ((**(this as *const *const IDispLatLongReport_Vtbl)).Timestamp)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Latitude,
+            Longitude,
+            ErrorRadius,
+            Altitude,
+            AltitudeError,
+            Timestamp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDispLatLongReport as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for ILatLongReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLatitude(this: *mut core::ffi::c_void, platitude: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILatLongReport", "GetLatitude", 6usize, |this| ((**(this as *const *const ILatLongReport_Vtbl)).GetLatitude)(this, platitude), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLongitude(this: *mut core::ffi::c_void, plongitude: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILatLongReport", "GetLongitude", 7usize, |this| ((**(this as *const *const ILatLongReport_Vtbl)).GetLongitude)(this, plongitude), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorRadius(this: *mut core::ffi::c_void, perrorradius: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILatLongReport", "GetErrorRadius", 8usize, |this| ((**(this as *const *const ILatLongReport_Vtbl)).GetErrorRadius)(this, perrorradius), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAltitude(this: *mut core::ffi::c_void, paltitude: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILatLongReport", "GetAltitude", 9usize, |this| ((**(this as *const *const ILatLongReport_Vtbl)).GetAltitude)(this, paltitude), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAltitudeError(this: *mut core::ffi::c_void, paltitudeerror: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILatLongReport", "GetAltitudeError", 10usize, |this| ((**(this as *const *const ILatLongReport_Vtbl)).GetAltitudeError)(this, paltitudeerror), |result| Some(*result))
+        }
+        Self {
+            base__: <ILocationReport_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLatitude,
+            GetLongitude,
+            GetErrorRadius,
+            GetAltitude,
+            GetAltitudeError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILatLongReport as windows_core::Interface>::IID || <ILocationReport_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ILatLongReportFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LatLongReport(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILatLongReportFactory", "LatLongReport", 15usize, |this| ((**(this as *const *const ILatLongReportFactory_Vtbl)).LatLongReport)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <ILocationReportFactory_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            LatLongReport,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LatLongReport: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILatLongReportFactory as windows_core::Interface>::IID || <ILocationReportFactory_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Devices_Sensors")]
+impl windows_core::imp::ProxyVtable for ILocation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterForReport(this: *mut core::ffi::c_void, pevents: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, dwrequestedreportinterval: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocation", "RegisterForReport", 3usize, |this| ((**(this as *const *const ILocation_Vtbl)).RegisterForReport)(this, pevents, reporttype, dwrequestedreportinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterForReport(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocation", "UnregisterForReport", 4usize, |this| ((**(this as *const *const ILocation_Vtbl)).UnregisterForReport)(this, reporttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReport(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, pplocationreport: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocation", "GetReport", 5usize, |this| ((**(this as *const *const ILocation_Vtbl)).GetReport)(this, reporttype, pplocationreport), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReportStatus(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, pstatus: *mut LOCATION_REPORT_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocation", "GetReportStatus", 6usize, |this| ((**(this as *const *const ILocation_Vtbl)).GetReportStatus)(this, reporttype, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReportInterval(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, pmilliseconds: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocation", "GetReportInterval", 7usize, |this| ((**(this as *const *const ILocation_Vtbl)).GetReportInterval)(this, reporttype, pmilliseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportInterval(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, millisecondsrequested: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocation", "SetReportInterval", 8usize, |this| ((**(this as *const *const ILocation_Vtbl)).SetReportInterval)(this, reporttype, millisecondsrequested), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Devices_Sensors")]
+        unsafe extern "system" fn GetDesiredAccuracy(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, pdesiredaccuracy: *mut super::Sensors::LOCATION_DESIRED_ACCURACY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocation", "GetDesiredAccuracy", 9usize, |this| ((**(this as *const *const ILocation_Vtbl)).GetDesiredAccuracy)(this, reporttype, pdesiredaccuracy), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Devices_Sensors")]
+        unsafe extern "system" fn SetDesiredAccuracy(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, desiredaccuracy: super::Sensors::LOCATION_DESIRED_ACCURACY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocation", "SetDesiredAccuracy", 10usize, |this| ((**(this as *const *const ILocation_Vtbl)).SetDesiredAccuracy)(this, reporttype, desiredaccuracy), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPermissions(this: *mut core::ffi::c_void, hparent: super::super::Foundation::HWND, preporttypes: *const windows_core::GUID, count: u32, fmodal: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocation", "RequestPermissions", 11usize, |this| ((**(this as *const *const ILocation_Vtbl)).RequestPermissions)(this, hparent, preporttypes, count, fmodal), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterForReport,
+            UnregisterForReport,
+            GetReport,
+            GetReportStatus,
+            GetReportInterval,
+            SetReportInterval,
+            #[cfg(feature = "Win32_Devices_Sensors")]
+            GetDesiredAccuracy,
+            #[cfg(not(feature = "Win32_Devices_Sensors"))]
+            GetDesiredAccuracy: 0,
+            #[cfg(feature = "Win32_Devices_Sensors")]
+            SetDesiredAccuracy,
+            #[cfg(not(feature = "Win32_Devices_Sensors"))]
+            SetDesiredAccuracy: 0,
+            RequestPermissions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILocationEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnLocationChanged(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, plocationreport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationEvents", "OnLocationChanged", 3usize, |this| ((**(this as *const *const ILocationEvents_Vtbl)).OnLocationChanged)(this, reporttype, plocationreport), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStatusChanged(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, newstatus: LOCATION_REPORT_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationEvents", "OnStatusChanged", 4usize, |this| ((**(this as *const *const ILocationEvents_Vtbl)).OnStatusChanged)(this, reporttype, newstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnLocationChanged, OnStatusChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocationEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILocationPower_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationPower", "Connect", 3usize, |this| ((**(this as *const *const ILocationPower_Vtbl)).Connect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationPower", "Disconnect", 4usize, |this| ((**(this as *const *const ILocationPower_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Connect, Disconnect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocationPower as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for ILocationReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSensorID(this: *mut core::ffi::c_void, psensorid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationReport", "GetSensorID", 3usize, |this| ((**(this as *const *const ILocationReport_Vtbl)).GetSensorID)(this, psensorid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimestamp(this: *mut core::ffi::c_void, pcreationtime: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationReport", "GetTimestamp", 4usize, |this| ((**(this as *const *const ILocationReport_Vtbl)).GetTimestamp)(this, pcreationtime), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, pkey: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationReport", "GetValue", 5usize, |this| ((**(this as *const *const ILocationReport_Vtbl)).GetValue)(this, pkey, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSensorID,
+            GetTimestamp,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetValue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocationReport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ILocationReportFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ListenForReports(this: *mut core::ffi::c_void, requestedreportinterval: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationReportFactory", "ListenForReports", 7usize, |this| ((**(this as *const *const ILocationReportFactory_Vtbl)).ListenForReports)(this, requestedreportinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopListeningForReports(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationReportFactory", "StopListeningForReports", 8usize, |this| ((**(this as *const *const ILocationReportFactory_Vtbl)).StopListeningForReports)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationReportFactory", "Status", 9usize, |this| ((**(this as *const *const ILocationReportFactory_Vtbl)).Status)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportInterval(this: *mut core::ffi::c_void, pmilliseconds: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationReportFactory", "ReportInterval", 10usize, |this| ((**(this as *const *const ILocationReportFactory_Vtbl)).ReportInterval)(this, pmilliseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportInterval(this: *mut core::ffi::c_void, millisecondsrequested: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationReportFactory", "SetReportInterval", 11usize, |this| ((**(this as *const *const ILocationReportFactory_Vtbl)).SetReportInterval)(this, millisecondsrequested), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredAccuracy(this: *mut core::ffi::c_void, pdesiredaccuracy: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationReportFactory", "DesiredAccuracy", 12usize, |this| ((**(this as *const *const ILocationReportFactory_Vtbl)).DesiredAccuracy)(this, pdesiredaccuracy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredAccuracy(this: *mut core::ffi::c_void, desiredaccuracy: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationReportFactory", "SetDesiredAccuracy", 13usize, |this| ((**(this as *const *const ILocationReportFactory_Vtbl)).SetDesiredAccuracy)(this, desiredaccuracy), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPermissions(this: *mut core::ffi::c_void, hwnd: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationReportFactory", "RequestPermissions", 14usize, |this| ((**(this as *const *const ILocationReportFactory_Vtbl)).RequestPermissions)(this, hwnd), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ListenForReports,
+            StopListeningForReports,
+            Status,
+            ReportInterval,
+            SetReportInterval,
+            DesiredAccuracy,
+            SetDesiredAccuracy,
+            RequestPermissions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocationReportFactory as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _ICivicAddressReportFactoryEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_ICivicAddressReportFactoryEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _ILatLongReportFactoryEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_ILatLongReportFactoryEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
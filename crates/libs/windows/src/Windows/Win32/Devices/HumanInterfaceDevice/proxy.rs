@@ -0,0 +1,990 @@
+impl windows_core::imp::ProxyVtable for IDirectInput2A_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindDevice(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: windows_core::PCSTR, param2: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput2A", "FindDevice", 8usize, |this| ((**(this as *const *const IDirectInput2A_Vtbl)).FindDevice)(this, param0, param1, param2), |result| Some(*result))
+        }
+        Self { base__: <IDirectInputA_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInput2A as windows_core::Interface>::IID || <IDirectInputA_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInput2W_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindDevice(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: windows_core::PCWSTR, param2: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput2W", "FindDevice", 8usize, |this| ((**(this as *const *const IDirectInput2W_Vtbl)).FindDevice)(this, param0, param1, param2), |result| Some(*result))
+        }
+        Self { base__: <IDirectInputW_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInput2W as windows_core::Interface>::IID || <IDirectInputW_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInput7A_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDeviceEx(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *const windows_core::GUID, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput7A", "CreateDeviceEx", 9usize, |this| ((**(this as *const *const IDirectInput7A_Vtbl)).CreateDeviceEx)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        Self { base__: <IDirectInput2A_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateDeviceEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInput7A as windows_core::Interface>::IID || <IDirectInput2A_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInput7W_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDeviceEx(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *const windows_core::GUID, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput7W", "CreateDeviceEx", 9usize, |this| ((**(this as *const *const IDirectInput7W_Vtbl)).CreateDeviceEx)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        Self { base__: <IDirectInput2W_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateDeviceEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInput7W as windows_core::Interface>::IID || <IDirectInput2W_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInput8A_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8A", "CreateDevice", 3usize, |this| ((**(this as *const *const IDirectInput8A_Vtbl)).CreateDevice)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDevices(this: *mut core::ffi::c_void, param0: u32, param1: LPDIENUMDEVICESCALLBACKA, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8A", "EnumDevices", 4usize, |this| ((**(this as *const *const IDirectInput8A_Vtbl)).EnumDevices)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceStatus(this: *mut core::ffi::c_void, param0: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8A", "GetDeviceStatus", 5usize, |this| ((**(this as *const *const IDirectInput8A_Vtbl)).GetDeviceStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunControlPanel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8A", "RunControlPanel", 6usize, |this| ((**(this as *const *const IDirectInput8A_Vtbl)).RunControlPanel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8A", "Initialize", 7usize, |this| ((**(this as *const *const IDirectInput8A_Vtbl)).Initialize)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindDevice(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: windows_core::PCSTR, param2: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8A", "FindDevice", 8usize, |this| ((**(this as *const *const IDirectInput8A_Vtbl)).FindDevice)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDevicesBySemantics(this: *mut core::ffi::c_void, param0: windows_core::PCSTR, param1: *mut DIACTIONFORMATA, param2: LPDIENUMDEVICESBYSEMANTICSCBA, param3: *mut core::ffi::c_void, param4: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8A", "EnumDevicesBySemantics", 9usize, |this| ((**(this as *const *const IDirectInput8A_Vtbl)).EnumDevicesBySemantics)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConfigureDevices(this: *mut core::ffi::c_void, param0: LPDICONFIGUREDEVICESCALLBACK, param1: *mut DICONFIGUREDEVICESPARAMSA, param2: u32, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8A", "ConfigureDevices", 10usize, |this| ((**(this as *const *const IDirectInput8A_Vtbl)).ConfigureDevices)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateDevice,
+            EnumDevices,
+            GetDeviceStatus,
+            RunControlPanel,
+            Initialize,
+            FindDevice,
+            EnumDevicesBySemantics,
+            ConfigureDevices,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInput8A as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInput8W_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8W", "CreateDevice", 3usize, |this| ((**(this as *const *const IDirectInput8W_Vtbl)).CreateDevice)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDevices(this: *mut core::ffi::c_void, param0: u32, param1: LPDIENUMDEVICESCALLBACKW, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8W", "EnumDevices", 4usize, |this| ((**(this as *const *const IDirectInput8W_Vtbl)).EnumDevices)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceStatus(this: *mut core::ffi::c_void, param0: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8W", "GetDeviceStatus", 5usize, |this| ((**(this as *const *const IDirectInput8W_Vtbl)).GetDeviceStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunControlPanel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8W", "RunControlPanel", 6usize, |this| ((**(this as *const *const IDirectInput8W_Vtbl)).RunControlPanel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8W", "Initialize", 7usize, |this| ((**(this as *const *const IDirectInput8W_Vtbl)).Initialize)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindDevice(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: windows_core::PCWSTR, param2: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8W", "FindDevice", 8usize, |this| ((**(this as *const *const IDirectInput8W_Vtbl)).FindDevice)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDevicesBySemantics(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: *mut DIACTIONFORMATW, param2: LPDIENUMDEVICESBYSEMANTICSCBW, param3: *mut core::ffi::c_void, param4: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8W", "EnumDevicesBySemantics", 9usize, |this| ((**(this as *const *const IDirectInput8W_Vtbl)).EnumDevicesBySemantics)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConfigureDevices(this: *mut core::ffi::c_void, param0: LPDICONFIGUREDEVICESCALLBACK, param1: *mut DICONFIGUREDEVICESPARAMSW, param2: u32, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInput8W", "ConfigureDevices", 10usize, |this| ((**(this as *const *const IDirectInput8W_Vtbl)).ConfigureDevices)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateDevice,
+            EnumDevices,
+            GetDeviceStatus,
+            RunControlPanel,
+            Initialize,
+            FindDevice,
+            EnumDevicesBySemantics,
+            ConfigureDevices,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInput8W as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInputA_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputA", "CreateDevice", 3usize, |this| ((**(this as *const *const IDirectInputA_Vtbl)).CreateDevice)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDevices(this: *mut core::ffi::c_void, param0: u32, param1: LPDIENUMDEVICESCALLBACKA, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputA", "EnumDevices", 4usize, |this| ((**(this as *const *const IDirectInputA_Vtbl)).EnumDevices)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceStatus(this: *mut core::ffi::c_void, param0: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputA", "GetDeviceStatus", 5usize, |this| ((**(this as *const *const IDirectInputA_Vtbl)).GetDeviceStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunControlPanel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputA", "RunControlPanel", 6usize, |this| ((**(this as *const *const IDirectInputA_Vtbl)).RunControlPanel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputA", "Initialize", 7usize, |this| ((**(this as *const *const IDirectInputA_Vtbl)).Initialize)(this, param0, param1), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateDevice,
+            EnumDevices,
+            GetDeviceStatus,
+            RunControlPanel,
+            Initialize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInputA as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInputDevice2A_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateEffect(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIEFFECT, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2A", "CreateEffect", 18usize, |this| ((**(this as *const *const IDirectInputDevice2A_Vtbl)).CreateEffect)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumEffects(this: *mut core::ffi::c_void, param0: LPDIENUMEFFECTSCALLBACKA, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2A", "EnumEffects", 19usize, |this| ((**(this as *const *const IDirectInputDevice2A_Vtbl)).EnumEffects)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEffectInfo(this: *mut core::ffi::c_void, param0: *mut DIEFFECTINFOA, param1: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2A", "GetEffectInfo", 20usize, |this| ((**(this as *const *const IDirectInputDevice2A_Vtbl)).GetEffectInfo)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForceFeedbackState(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2A", "GetForceFeedbackState", 21usize, |this| ((**(this as *const *const IDirectInputDevice2A_Vtbl)).GetForceFeedbackState)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendForceFeedbackCommand(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2A", "SendForceFeedbackCommand", 22usize, |this| ((**(this as *const *const IDirectInputDevice2A_Vtbl)).SendForceFeedbackCommand)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumCreatedEffectObjects(this: *mut core::ffi::c_void, param0: LPDIENUMCREATEDEFFECTOBJECTSCALLBACK, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2A", "EnumCreatedEffectObjects", 23usize, |this| ((**(this as *const *const IDirectInputDevice2A_Vtbl)).EnumCreatedEffectObjects)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Escape(this: *mut core::ffi::c_void, param0: *mut DIEFFESCAPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2A", "Escape", 24usize, |this| ((**(this as *const *const IDirectInputDevice2A_Vtbl)).Escape)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Poll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2A", "Poll", 25usize, |this| ((**(this as *const *const IDirectInputDevice2A_Vtbl)).Poll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendDeviceData(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2A", "SendDeviceData", 26usize, |this| ((**(this as *const *const IDirectInputDevice2A_Vtbl)).SendDeviceData)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        Self {
+            base__: <IDirectInputDeviceA_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateEffect,
+            EnumEffects,
+            GetEffectInfo,
+            GetForceFeedbackState,
+            SendForceFeedbackCommand,
+            EnumCreatedEffectObjects,
+            Escape,
+            Poll,
+            SendDeviceData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInputDevice2A as windows_core::Interface>::IID || <IDirectInputDeviceA_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInputDevice2W_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateEffect(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIEFFECT, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2W", "CreateEffect", 18usize, |this| ((**(this as *const *const IDirectInputDevice2W_Vtbl)).CreateEffect)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumEffects(this: *mut core::ffi::c_void, param0: LPDIENUMEFFECTSCALLBACKW, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2W", "EnumEffects", 19usize, |this| ((**(this as *const *const IDirectInputDevice2W_Vtbl)).EnumEffects)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEffectInfo(this: *mut core::ffi::c_void, param0: *mut DIEFFECTINFOW, param1: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2W", "GetEffectInfo", 20usize, |this| ((**(this as *const *const IDirectInputDevice2W_Vtbl)).GetEffectInfo)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForceFeedbackState(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2W", "GetForceFeedbackState", 21usize, |this| ((**(this as *const *const IDirectInputDevice2W_Vtbl)).GetForceFeedbackState)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendForceFeedbackCommand(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2W", "SendForceFeedbackCommand", 22usize, |this| ((**(this as *const *const IDirectInputDevice2W_Vtbl)).SendForceFeedbackCommand)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumCreatedEffectObjects(this: *mut core::ffi::c_void, param0: LPDIENUMCREATEDEFFECTOBJECTSCALLBACK, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2W", "EnumCreatedEffectObjects", 23usize, |this| ((**(this as *const *const IDirectInputDevice2W_Vtbl)).EnumCreatedEffectObjects)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Escape(this: *mut core::ffi::c_void, param0: *mut DIEFFESCAPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2W", "Escape", 24usize, |this| ((**(this as *const *const IDirectInputDevice2W_Vtbl)).Escape)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Poll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2W", "Poll", 25usize, |this| ((**(this as *const *const IDirectInputDevice2W_Vtbl)).Poll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendDeviceData(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice2W", "SendDeviceData", 26usize, |this| ((**(this as *const *const IDirectInputDevice2W_Vtbl)).SendDeviceData)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        Self {
+            base__: <IDirectInputDeviceW_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateEffect,
+            EnumEffects,
+            GetEffectInfo,
+            GetForceFeedbackState,
+            SendForceFeedbackCommand,
+            EnumCreatedEffectObjects,
+            Escape,
+            Poll,
+            SendDeviceData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInputDevice2W as windows_core::Interface>::IID || <IDirectInputDeviceW_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInputDevice7A_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumEffectsInFile(this: *mut core::ffi::c_void, param0: windows_core::PCSTR, param1: LPDIENUMEFFECTSINFILECALLBACK, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice7A", "EnumEffectsInFile", 27usize, |this| ((**(this as *const *const IDirectInputDevice7A_Vtbl)).EnumEffectsInFile)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteEffectToFile(this: *mut core::ffi::c_void, param0: windows_core::PCSTR, param1: u32, param2: *mut DIFILEEFFECT, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice7A", "WriteEffectToFile", 28usize, |this| ((**(this as *const *const IDirectInputDevice7A_Vtbl)).WriteEffectToFile)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        Self { base__: <IDirectInputDevice2A_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumEffectsInFile, WriteEffectToFile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInputDevice7A as windows_core::Interface>::IID || <IDirectInputDevice2A_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInputDevice7W_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumEffectsInFile(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: LPDIENUMEFFECTSINFILECALLBACK, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice7W", "EnumEffectsInFile", 27usize, |this| ((**(this as *const *const IDirectInputDevice7W_Vtbl)).EnumEffectsInFile)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteEffectToFile(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: u32, param2: *mut DIFILEEFFECT, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice7W", "WriteEffectToFile", 28usize, |this| ((**(this as *const *const IDirectInputDevice7W_Vtbl)).WriteEffectToFile)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        Self { base__: <IDirectInputDevice2W_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumEffectsInFile, WriteEffectToFile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInputDevice7W as windows_core::Interface>::IID || <IDirectInputDevice2W_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInputDevice8A_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, param0: *mut DIDEVCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "GetCapabilities", 3usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).GetCapabilities)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumObjects(this: *mut core::ffi::c_void, param0: LPDIENUMDEVICEOBJECTSCALLBACKA, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "EnumObjects", 4usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).EnumObjects)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "GetProperty", 5usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).GetProperty)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "SetProperty", 6usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).SetProperty)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn Acquire(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "Acquire", 7usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).Acquire)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unacquire(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "Unacquire", 8usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).Unacquire)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceState(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "GetDeviceState", 9usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).GetDeviceState)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceData(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "GetDeviceData", 10usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).GetDeviceData)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataFormat(this: *mut core::ffi::c_void, param0: *mut DIDATAFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "SetDataFormat", 11usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).SetDataFormat)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventNotification(this: *mut core::ffi::c_void, param0: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "SetEventNotification", 12usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).SetEventNotification)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCooperativeLevel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "SetCooperativeLevel", 13usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).SetCooperativeLevel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectInfo(this: *mut core::ffi::c_void, param0: *mut DIDEVICEOBJECTINSTANCEA, param1: u32, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "GetObjectInfo", 14usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).GetObjectInfo)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceInfo(this: *mut core::ffi::c_void, param0: *mut DIDEVICEINSTANCEA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "GetDeviceInfo", 15usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).GetDeviceInfo)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunControlPanel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "RunControlPanel", 16usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).RunControlPanel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32, param2: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "Initialize", 17usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).Initialize)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEffect(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIEFFECT, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "CreateEffect", 18usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).CreateEffect)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumEffects(this: *mut core::ffi::c_void, param0: LPDIENUMEFFECTSCALLBACKA, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "EnumEffects", 19usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).EnumEffects)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEffectInfo(this: *mut core::ffi::c_void, param0: *mut DIEFFECTINFOA, param1: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "GetEffectInfo", 20usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).GetEffectInfo)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForceFeedbackState(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "GetForceFeedbackState", 21usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).GetForceFeedbackState)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendForceFeedbackCommand(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "SendForceFeedbackCommand", 22usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).SendForceFeedbackCommand)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumCreatedEffectObjects(this: *mut core::ffi::c_void, param0: LPDIENUMCREATEDEFFECTOBJECTSCALLBACK, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "EnumCreatedEffectObjects", 23usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).EnumCreatedEffectObjects)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Escape(this: *mut core::ffi::c_void, param0: *mut DIEFFESCAPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "Escape", 24usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).Escape)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Poll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "Poll", 25usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).Poll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendDeviceData(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "SendDeviceData", 26usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).SendDeviceData)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumEffectsInFile(this: *mut core::ffi::c_void, param0: windows_core::PCSTR, param1: LPDIENUMEFFECTSINFILECALLBACK, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "EnumEffectsInFile", 27usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).EnumEffectsInFile)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteEffectToFile(this: *mut core::ffi::c_void, param0: windows_core::PCSTR, param1: u32, param2: *mut DIFILEEFFECT, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "WriteEffectToFile", 28usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).WriteEffectToFile)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuildActionMap(this: *mut core::ffi::c_void, param0: *mut DIACTIONFORMATA, param1: windows_core::PCSTR, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "BuildActionMap", 29usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).BuildActionMap)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActionMap(this: *mut core::ffi::c_void, param0: *mut DIACTIONFORMATA, param1: windows_core::PCSTR, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "SetActionMap", 30usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).SetActionMap)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImageInfo(this: *mut core::ffi::c_void, param0: *mut DIDEVICEIMAGEINFOHEADERA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8A", "GetImageInfo", 31usize, |this| ((**(this as *const *const IDirectInputDevice8A_Vtbl)).GetImageInfo)(this, param0), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCapabilities,
+            EnumObjects,
+            GetProperty,
+            SetProperty,
+            Acquire,
+            Unacquire,
+            GetDeviceState,
+            GetDeviceData,
+            SetDataFormat,
+            SetEventNotification,
+            SetCooperativeLevel,
+            GetObjectInfo,
+            GetDeviceInfo,
+            RunControlPanel,
+            Initialize,
+            CreateEffect,
+            EnumEffects,
+            GetEffectInfo,
+            GetForceFeedbackState,
+            SendForceFeedbackCommand,
+            EnumCreatedEffectObjects,
+            Escape,
+            Poll,
+            SendDeviceData,
+            EnumEffectsInFile,
+            WriteEffectToFile,
+            BuildActionMap,
+            SetActionMap,
+            GetImageInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInputDevice8A as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInputDevice8W_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, param0: *mut DIDEVCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "GetCapabilities", 3usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).GetCapabilities)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumObjects(this: *mut core::ffi::c_void, param0: LPDIENUMDEVICEOBJECTSCALLBACKW, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "EnumObjects", 4usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).EnumObjects)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "GetProperty", 5usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).GetProperty)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "SetProperty", 6usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).SetProperty)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn Acquire(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "Acquire", 7usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).Acquire)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unacquire(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "Unacquire", 8usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).Unacquire)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceState(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "GetDeviceState", 9usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).GetDeviceState)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceData(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "GetDeviceData", 10usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).GetDeviceData)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataFormat(this: *mut core::ffi::c_void, param0: *mut DIDATAFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "SetDataFormat", 11usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).SetDataFormat)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventNotification(this: *mut core::ffi::c_void, param0: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "SetEventNotification", 12usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).SetEventNotification)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCooperativeLevel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "SetCooperativeLevel", 13usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).SetCooperativeLevel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectInfo(this: *mut core::ffi::c_void, param0: *mut DIDEVICEOBJECTINSTANCEW, param1: u32, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "GetObjectInfo", 14usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).GetObjectInfo)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceInfo(this: *mut core::ffi::c_void, param0: *mut DIDEVICEINSTANCEW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "GetDeviceInfo", 15usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).GetDeviceInfo)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunControlPanel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "RunControlPanel", 16usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).RunControlPanel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32, param2: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "Initialize", 17usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).Initialize)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEffect(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIEFFECT, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "CreateEffect", 18usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).CreateEffect)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumEffects(this: *mut core::ffi::c_void, param0: LPDIENUMEFFECTSCALLBACKW, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "EnumEffects", 19usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).EnumEffects)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEffectInfo(this: *mut core::ffi::c_void, param0: *mut DIEFFECTINFOW, param1: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "GetEffectInfo", 20usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).GetEffectInfo)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForceFeedbackState(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "GetForceFeedbackState", 21usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).GetForceFeedbackState)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendForceFeedbackCommand(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "SendForceFeedbackCommand", 22usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).SendForceFeedbackCommand)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumCreatedEffectObjects(this: *mut core::ffi::c_void, param0: LPDIENUMCREATEDEFFECTOBJECTSCALLBACK, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "EnumCreatedEffectObjects", 23usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).EnumCreatedEffectObjects)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Escape(this: *mut core::ffi::c_void, param0: *mut DIEFFESCAPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "Escape", 24usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).Escape)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Poll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "Poll", 25usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).Poll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendDeviceData(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "SendDeviceData", 26usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).SendDeviceData)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumEffectsInFile(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: LPDIENUMEFFECTSINFILECALLBACK, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "EnumEffectsInFile", 27usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).EnumEffectsInFile)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteEffectToFile(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: u32, param2: *mut DIFILEEFFECT, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "WriteEffectToFile", 28usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).WriteEffectToFile)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuildActionMap(this: *mut core::ffi::c_void, param0: *mut DIACTIONFORMATW, param1: windows_core::PCWSTR, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "BuildActionMap", 29usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).BuildActionMap)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActionMap(this: *mut core::ffi::c_void, param0: *mut DIACTIONFORMATW, param1: windows_core::PCWSTR, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "SetActionMap", 30usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).SetActionMap)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImageInfo(this: *mut core::ffi::c_void, param0: *mut DIDEVICEIMAGEINFOHEADERW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDevice8W", "GetImageInfo", 31usize, |this| ((**(this as *const *const IDirectInputDevice8W_Vtbl)).GetImageInfo)(this, param0), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCapabilities,
+            EnumObjects,
+            GetProperty,
+            SetProperty,
+            Acquire,
+            Unacquire,
+            GetDeviceState,
+            GetDeviceData,
+            SetDataFormat,
+            SetEventNotification,
+            SetCooperativeLevel,
+            GetObjectInfo,
+            GetDeviceInfo,
+            RunControlPanel,
+            Initialize,
+            CreateEffect,
+            EnumEffects,
+            GetEffectInfo,
+            GetForceFeedbackState,
+            SendForceFeedbackCommand,
+            EnumCreatedEffectObjects,
+            Escape,
+            Poll,
+            SendDeviceData,
+            EnumEffectsInFile,
+            WriteEffectToFile,
+            BuildActionMap,
+            SetActionMap,
+            GetImageInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInputDevice8W as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInputDeviceA_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, param0: *mut DIDEVCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceA", "GetCapabilities", 3usize, |this| ((**(this as *const *const IDirectInputDeviceA_Vtbl)).GetCapabilities)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumObjects(this: *mut core::ffi::c_void, param0: LPDIENUMDEVICEOBJECTSCALLBACKA, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceA", "EnumObjects", 4usize, |this| ((**(this as *const *const IDirectInputDeviceA_Vtbl)).EnumObjects)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceA", "GetProperty", 5usize, |this| ((**(this as *const *const IDirectInputDeviceA_Vtbl)).GetProperty)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceA", "SetProperty", 6usize, |this| ((**(this as *const *const IDirectInputDeviceA_Vtbl)).SetProperty)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn Acquire(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceA", "Acquire", 7usize, |this| ((**(this as *const *const IDirectInputDeviceA_Vtbl)).Acquire)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unacquire(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceA", "Unacquire", 8usize, |this| ((**(this as *const *const IDirectInputDeviceA_Vtbl)).Unacquire)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceState(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceA", "GetDeviceState", 9usize, |this| ((**(this as *const *const IDirectInputDeviceA_Vtbl)).GetDeviceState)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceData(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceA", "GetDeviceData", 10usize, |this| ((**(this as *const *const IDirectInputDeviceA_Vtbl)).GetDeviceData)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataFormat(this: *mut core::ffi::c_void, param0: *mut DIDATAFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceA", "SetDataFormat", 11usize, |this| ((**(this as *const *const IDirectInputDeviceA_Vtbl)).SetDataFormat)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventNotification(this: *mut core::ffi::c_void, param0: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceA", "SetEventNotification", 12usize, |this| ((**(this as *const *const IDirectInputDeviceA_Vtbl)).SetEventNotification)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCooperativeLevel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceA", "SetCooperativeLevel", 13usize, |this| ((**(this as *const *const IDirectInputDeviceA_Vtbl)).SetCooperativeLevel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectInfo(this: *mut core::ffi::c_void, param0: *mut DIDEVICEOBJECTINSTANCEA, param1: u32, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceA", "GetObjectInfo", 14usize, |this| ((**(this as *const *const IDirectInputDeviceA_Vtbl)).GetObjectInfo)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceInfo(this: *mut core::ffi::c_void, param0: *mut DIDEVICEINSTANCEA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceA", "GetDeviceInfo", 15usize, |this| ((**(this as *const *const IDirectInputDeviceA_Vtbl)).GetDeviceInfo)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunControlPanel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceA", "RunControlPanel", 16usize, |this| ((**(this as *const *const IDirectInputDeviceA_Vtbl)).RunControlPanel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32, param2: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceA", "Initialize", 17usize, |this| ((**(this as *const *const IDirectInputDeviceA_Vtbl)).Initialize)(this, param0, param1, param2), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCapabilities,
+            EnumObjects,
+            GetProperty,
+            SetProperty,
+            Acquire,
+            Unacquire,
+            GetDeviceState,
+            GetDeviceData,
+            SetDataFormat,
+            SetEventNotification,
+            SetCooperativeLevel,
+            GetObjectInfo,
+            GetDeviceInfo,
+            RunControlPanel,
+            Initialize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInputDeviceA as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInputDeviceW_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, param0: *mut DIDEVCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceW", "GetCapabilities", 3usize, |this| ((**(this as *const *const IDirectInputDeviceW_Vtbl)).GetCapabilities)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumObjects(this: *mut core::ffi::c_void, param0: LPDIENUMDEVICEOBJECTSCALLBACKW, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceW", "EnumObjects", 4usize, |this| ((**(this as *const *const IDirectInputDeviceW_Vtbl)).EnumObjects)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceW", "GetProperty", 5usize, |this| ((**(this as *const *const IDirectInputDeviceW_Vtbl)).GetProperty)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceW", "SetProperty", 6usize, |this| ((**(this as *const *const IDirectInputDeviceW_Vtbl)).SetProperty)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn Acquire(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceW", "Acquire", 7usize, |this| ((**(this as *const *const IDirectInputDeviceW_Vtbl)).Acquire)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unacquire(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceW", "Unacquire", 8usize, |this| ((**(this as *const *const IDirectInputDeviceW_Vtbl)).Unacquire)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceState(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceW", "GetDeviceState", 9usize, |this| ((**(this as *const *const IDirectInputDeviceW_Vtbl)).GetDeviceState)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceData(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceW", "GetDeviceData", 10usize, |this| ((**(this as *const *const IDirectInputDeviceW_Vtbl)).GetDeviceData)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataFormat(this: *mut core::ffi::c_void, param0: *mut DIDATAFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceW", "SetDataFormat", 11usize, |this| ((**(this as *const *const IDirectInputDeviceW_Vtbl)).SetDataFormat)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventNotification(this: *mut core::ffi::c_void, param0: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceW", "SetEventNotification", 12usize, |this| ((**(this as *const *const IDirectInputDeviceW_Vtbl)).SetEventNotification)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCooperativeLevel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceW", "SetCooperativeLevel", 13usize, |this| ((**(this as *const *const IDirectInputDeviceW_Vtbl)).SetCooperativeLevel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectInfo(this: *mut core::ffi::c_void, param0: *mut DIDEVICEOBJECTINSTANCEW, param1: u32, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceW", "GetObjectInfo", 14usize, |this| ((**(this as *const *const IDirectInputDeviceW_Vtbl)).GetObjectInfo)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceInfo(this: *mut core::ffi::c_void, param0: *mut DIDEVICEINSTANCEW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceW", "GetDeviceInfo", 15usize, |this| ((**(this as *const *const IDirectInputDeviceW_Vtbl)).GetDeviceInfo)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunControlPanel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceW", "RunControlPanel", 16usize, |this| ((**(this as *const *const IDirectInputDeviceW_Vtbl)).RunControlPanel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32, param2: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputDeviceW", "Initialize", 17usize, |this| ((**(this as *const *const IDirectInputDeviceW_Vtbl)).Initialize)(this, param0, param1, param2), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCapabilities,
+            EnumObjects,
+            GetProperty,
+            SetProperty,
+            Acquire,
+            Unacquire,
+            GetDeviceState,
+            GetDeviceData,
+            SetDataFormat,
+            SetEventNotification,
+            SetCooperativeLevel,
+            GetObjectInfo,
+            GetDeviceInfo,
+            RunControlPanel,
+            Initialize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInputDeviceW as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInputEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32, param2: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffect", "Initialize", 3usize, |this| ((**(this as *const *const IDirectInputEffect_Vtbl)).Initialize)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEffectGuid(this: *mut core::ffi::c_void, param0: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffect", "GetEffectGuid", 4usize, |this| ((**(this as *const *const IDirectInputEffect_Vtbl)).GetEffectGuid)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParameters(this: *mut core::ffi::c_void, param0: *mut DIEFFECT, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffect", "GetParameters", 5usize, |this| ((**(this as *const *const IDirectInputEffect_Vtbl)).GetParameters)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParameters(this: *mut core::ffi::c_void, param0: *mut DIEFFECT, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffect", "SetParameters", 6usize, |this| ((**(this as *const *const IDirectInputEffect_Vtbl)).SetParameters)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, param0: u32, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffect", "Start", 7usize, |this| ((**(this as *const *const IDirectInputEffect_Vtbl)).Start)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffect", "Stop", 8usize, |this| ((**(this as *const *const IDirectInputEffect_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEffectStatus(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffect", "GetEffectStatus", 9usize, |this| ((**(this as *const *const IDirectInputEffect_Vtbl)).GetEffectStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Download(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffect", "Download", 10usize, |this| ((**(this as *const *const IDirectInputEffect_Vtbl)).Download)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffect", "Unload", 11usize, |this| ((**(this as *const *const IDirectInputEffect_Vtbl)).Unload)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Escape(this: *mut core::ffi::c_void, param0: *mut DIEFFESCAPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffect", "Escape", 12usize, |this| ((**(this as *const *const IDirectInputEffect_Vtbl)).Escape)(this, param0), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetEffectGuid,
+            GetParameters,
+            SetParameters,
+            Start,
+            Stop,
+            GetEffectStatus,
+            Download,
+            Unload,
+            Escape,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInputEffect as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInputEffectDriver_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceID(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32, param3: u32, param4: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffectDriver", "DeviceID", 3usize, |this| ((**(this as *const *const IDirectInputEffectDriver_Vtbl)).DeviceID)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersions(this: *mut core::ffi::c_void, param0: *mut DIDRIVERVERSIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffectDriver", "GetVersions", 4usize, |this| ((**(this as *const *const IDirectInputEffectDriver_Vtbl)).GetVersions)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Escape(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut DIEFFESCAPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffectDriver", "Escape", 5usize, |this| ((**(this as *const *const IDirectInputEffectDriver_Vtbl)).Escape)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGain(this: *mut core::ffi::c_void, param0: u32, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffectDriver", "SetGain", 6usize, |this| ((**(this as *const *const IDirectInputEffectDriver_Vtbl)).SetGain)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendForceFeedbackCommand(this: *mut core::ffi::c_void, param0: u32, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffectDriver", "SendForceFeedbackCommand", 7usize, |this| ((**(this as *const *const IDirectInputEffectDriver_Vtbl)).SendForceFeedbackCommand)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForceFeedbackState(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICESTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffectDriver", "GetForceFeedbackState", 8usize, |this| ((**(this as *const *const IDirectInputEffectDriver_Vtbl)).GetForceFeedbackState)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadEffect(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut u32, param3: *mut DIEFFECT, param4: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffectDriver", "DownloadEffect", 9usize, |this| ((**(this as *const *const IDirectInputEffectDriver_Vtbl)).DownloadEffect)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestroyEffect(this: *mut core::ffi::c_void, param0: u32, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffectDriver", "DestroyEffect", 10usize, |this| ((**(this as *const *const IDirectInputEffectDriver_Vtbl)).DestroyEffect)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartEffect(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffectDriver", "StartEffect", 11usize, |this| ((**(this as *const *const IDirectInputEffectDriver_Vtbl)).StartEffect)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopEffect(this: *mut core::ffi::c_void, param0: u32, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffectDriver", "StopEffect", 12usize, |this| ((**(this as *const *const IDirectInputEffectDriver_Vtbl)).StopEffect)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEffectStatus(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputEffectDriver", "GetEffectStatus", 13usize, |this| ((**(this as *const *const IDirectInputEffectDriver_Vtbl)).GetEffectStatus)(this, param0, param1, param2), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceID,
+            GetVersions,
+            Escape,
+            SetGain,
+            SendForceFeedbackCommand,
+            GetForceFeedbackState,
+            DownloadEffect,
+            DestroyEffect,
+            StartEffect,
+            StopEffect,
+            GetEffectStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInputEffectDriver as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Registry")]
+impl windows_core::imp::ProxyVtable for IDirectInputJoyConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Acquire(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "Acquire", 3usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).Acquire)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unacquire(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "Unacquire", 4usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).Unacquire)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCooperativeLevel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "SetCooperativeLevel", 5usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).SetCooperativeLevel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendNotify(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "SendNotify", 6usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).SendNotify)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumTypes(this: *mut core::ffi::c_void, param0: LPDIJOYTYPECALLBACK, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "EnumTypes", 7usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).EnumTypes)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypeInfo(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: *mut DIJOYTYPEINFO, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "GetTypeInfo", 8usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).GetTypeInfo)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTypeInfo(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: *mut DIJOYTYPEINFO, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "SetTypeInfo", 9usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).SetTypeInfo)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteType(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "DeleteType", 10usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).DeleteType)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConfig(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIJOYCONFIG, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "GetConfig", 11usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).GetConfig)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConfig(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIJOYCONFIG, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "SetConfig", 12usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).SetConfig)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteConfig(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "DeleteConfig", 13usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).DeleteConfig)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserValues(this: *mut core::ffi::c_void, param0: *mut DIJOYUSERVALUES, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "GetUserValues", 14usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).GetUserValues)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserValues(this: *mut core::ffi::c_void, param0: *mut DIJOYUSERVALUES, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "SetUserValues", 15usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).SetUserValues)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddNewHardware(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "AddNewHardware", 16usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).AddNewHardware)(this, param0, param1), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Registry")]
+        unsafe extern "system" fn OpenTypeKey(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: u32, param2: *mut super::super::System::Registry::HKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "OpenTypeKey", 17usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).OpenTypeKey)(this, param0, param1, param2), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Registry")]
+        unsafe extern "system" fn OpenConfigKey(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut super::super::System::Registry::HKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig", "OpenConfigKey", 18usize, |this| ((**(this as *const *const IDirectInputJoyConfig_Vtbl)).OpenConfigKey)(this, param0, param1, param2), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Acquire,
+            Unacquire,
+            SetCooperativeLevel,
+            SendNotify,
+            EnumTypes,
+            GetTypeInfo,
+            SetTypeInfo,
+            DeleteType,
+            GetConfig,
+            SetConfig,
+            DeleteConfig,
+            GetUserValues,
+            SetUserValues,
+            AddNewHardware,
+            #[cfg(feature = "Win32_System_Registry")]
+            OpenTypeKey,
+            #[cfg(not(feature = "Win32_System_Registry"))]
+            OpenTypeKey: 0,
+            #[cfg(feature = "Win32_System_Registry")]
+            OpenConfigKey,
+            #[cfg(not(feature = "Win32_System_Registry"))]
+            OpenConfigKey: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInputJoyConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Registry")]
+impl windows_core::imp::ProxyVtable for IDirectInputJoyConfig8_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Acquire(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "Acquire", 3usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).Acquire)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unacquire(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "Unacquire", 4usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).Unacquire)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCooperativeLevel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "SetCooperativeLevel", 5usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).SetCooperativeLevel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendNotify(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "SendNotify", 6usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).SendNotify)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumTypes(this: *mut core::ffi::c_void, param0: LPDIJOYTYPECALLBACK, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "EnumTypes", 7usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).EnumTypes)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypeInfo(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: *mut DIJOYTYPEINFO, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "GetTypeInfo", 8usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).GetTypeInfo)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTypeInfo(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: *mut DIJOYTYPEINFO, param2: u32, param3: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "SetTypeInfo", 9usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).SetTypeInfo)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteType(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "DeleteType", 10usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).DeleteType)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConfig(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIJOYCONFIG, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "GetConfig", 11usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).GetConfig)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConfig(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIJOYCONFIG, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "SetConfig", 12usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).SetConfig)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteConfig(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "DeleteConfig", 13usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).DeleteConfig)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserValues(this: *mut core::ffi::c_void, param0: *mut DIJOYUSERVALUES, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "GetUserValues", 14usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).GetUserValues)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserValues(this: *mut core::ffi::c_void, param0: *mut DIJOYUSERVALUES, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "SetUserValues", 15usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).SetUserValues)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddNewHardware(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "AddNewHardware", 16usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).AddNewHardware)(this, param0, param1), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Registry")]
+        unsafe extern "system" fn OpenTypeKey(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: u32, param2: *mut super::super::System::Registry::HKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "OpenTypeKey", 17usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).OpenTypeKey)(this, param0, param1, param2), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Registry")]
+        unsafe extern "system" fn OpenAppStatusKey(this: *mut core::ffi::c_void, param0: *mut super::super::System::Registry::HKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputJoyConfig8", "OpenAppStatusKey", 18usize, |this| ((**(this as *const *const IDirectInputJoyConfig8_Vtbl)).OpenAppStatusKey)(this, param0), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Acquire,
+            Unacquire,
+            SetCooperativeLevel,
+            SendNotify,
+            EnumTypes,
+            GetTypeInfo,
+            SetTypeInfo,
+            DeleteType,
+            GetConfig,
+            SetConfig,
+            DeleteConfig,
+            GetUserValues,
+            SetUserValues,
+            AddNewHardware,
+            #[cfg(feature = "Win32_System_Registry")]
+            OpenTypeKey,
+            #[cfg(not(feature = "Win32_System_Registry"))]
+            OpenTypeKey: 0,
+            #[cfg(feature = "Win32_System_Registry")]
+            OpenAppStatusKey,
+            #[cfg(not(feature = "Win32_System_Registry"))]
+            OpenAppStatusKey: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInputJoyConfig8 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectInputW_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputW", "CreateDevice", 3usize, |this| ((**(this as *const *const IDirectInputW_Vtbl)).CreateDevice)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDevices(this: *mut core::ffi::c_void, param0: u32, param1: LPDIENUMDEVICESCALLBACKW, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputW", "EnumDevices", 4usize, |this| ((**(this as *const *const IDirectInputW_Vtbl)).EnumDevices)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceStatus(this: *mut core::ffi::c_void, param0: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputW", "GetDeviceStatus", 5usize, |this| ((**(this as *const *const IDirectInputW_Vtbl)).GetDeviceStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunControlPanel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputW", "RunControlPanel", 6usize, |this| ((**(this as *const *const IDirectInputW_Vtbl)).RunControlPanel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectInputW", "Initialize", 7usize, |this| ((**(this as *const *const IDirectInputW_Vtbl)).Initialize)(this, param0, param1), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateDevice,
+            EnumDevices,
+            GetDeviceStatus,
+            RunControlPanel,
+            Initialize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectInputW as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,1061 @@
+impl windows_core::imp::ProxyVtable for IEnumWIA_DEV_CAPS_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut WIA_DEV_CAP, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWIA_DEV_CAPS", "Next", 3usize, |this| ((**(this as *const *const IEnumWIA_DEV_CAPS_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWIA_DEV_CAPS", "Skip", 4usize, |this| ((**(this as *const *const IEnumWIA_DEV_CAPS_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWIA_DEV_CAPS", "Reset", 5usize, |this| ((**(this as *const *const IEnumWIA_DEV_CAPS_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWIA_DEV_CAPS", "Clone", 6usize, |this| ((**(this as *const *const IEnumWIA_DEV_CAPS_Vtbl)).Clone)(this, ppienum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWIA_DEV_CAPS", "GetCount", 7usize, |this| ((**(this as *const *const IEnumWIA_DEV_CAPS_Vtbl)).GetCount)(this, pcelt), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumWIA_DEV_CAPS as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumWIA_DEV_INFO_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWIA_DEV_INFO", "Next", 3usize, |this| ((**(this as *const *const IEnumWIA_DEV_INFO_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWIA_DEV_INFO", "Skip", 4usize, |this| ((**(this as *const *const IEnumWIA_DEV_INFO_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWIA_DEV_INFO", "Reset", 5usize, |this| ((**(this as *const *const IEnumWIA_DEV_INFO_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWIA_DEV_INFO", "Clone", 6usize, |this| ((**(this as *const *const IEnumWIA_DEV_INFO_Vtbl)).Clone)(this, ppienum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, celt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWIA_DEV_INFO", "GetCount", 7usize, |this| ((**(this as *const *const IEnumWIA_DEV_INFO_Vtbl)).GetCount)(this, celt), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumWIA_DEV_INFO as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumWIA_FORMAT_INFO_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut WIA_FORMAT_INFO, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWIA_FORMAT_INFO", "Next", 3usize, |this| ((**(this as *const *const IEnumWIA_FORMAT_INFO_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWIA_FORMAT_INFO", "Skip", 4usize, |this| ((**(this as *const *const IEnumWIA_FORMAT_INFO_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWIA_FORMAT_INFO", "Reset", 5usize, |this| ((**(this as *const *const IEnumWIA_FORMAT_INFO_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWIA_FORMAT_INFO", "Clone", 6usize, |this| ((**(this as *const *const IEnumWIA_FORMAT_INFO_Vtbl)).Clone)(this, ppienum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWIA_FORMAT_INFO", "GetCount", 7usize, |this| ((**(this as *const *const IEnumWIA_FORMAT_INFO_Vtbl)).GetCount)(this, pcelt), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumWIA_FORMAT_INFO as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumWiaItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppiwiaitem: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWiaItem", "Next", 3usize, |this| ((**(this as *const *const IEnumWiaItem_Vtbl)).Next)(this, celt, ppiwiaitem, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWiaItem", "Skip", 4usize, |this| ((**(this as *const *const IEnumWiaItem_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWiaItem", "Reset", 5usize, |this| ((**(this as *const *const IEnumWiaItem_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWiaItem", "Clone", 6usize, |this| ((**(this as *const *const IEnumWiaItem_Vtbl)).Clone)(this, ppienum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, celt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWiaItem", "GetCount", 7usize, |this| ((**(this as *const *const IEnumWiaItem_Vtbl)).GetCount)(this, celt), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumWiaItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumWiaItem2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppiwiaitem2: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWiaItem2", "Next", 3usize, |this| ((**(this as *const *const IEnumWiaItem2_Vtbl)).Next)(this, celt, ppiwiaitem2, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWiaItem2", "Skip", 4usize, |this| ((**(this as *const *const IEnumWiaItem2_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWiaItem2", "Reset", 5usize, |this| ((**(this as *const *const IEnumWiaItem2_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWiaItem2", "Clone", 6usize, |this| ((**(this as *const *const IEnumWiaItem2_Vtbl)).Clone)(this, ppienum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, celt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWiaItem2", "GetCount", 7usize, |this| ((**(this as *const *const IEnumWiaItem2_Vtbl)).GetCount)(this, celt), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumWiaItem2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaAppErrorHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetWindow(this: *mut core::ffi::c_void, phwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaAppErrorHandler", "GetWindow", 3usize, |this| ((**(this as *const *const IWiaAppErrorHandler_Vtbl)).GetWindow)(this, phwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportStatus(this: *mut core::ffi::c_void, lflags: i32, pwiaitem2: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT, lpercentcomplete: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaAppErrorHandler", "ReportStatus", 4usize, |this| ((**(this as *const *const IWiaAppErrorHandler_Vtbl)).ReportStatus)(this, lflags, pwiaitem2, hrstatus, lpercentcomplete), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetWindow, ReportStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaAppErrorHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaDataCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BandedDataCallback(this: *mut core::ffi::c_void, lmessage: i32, lstatus: i32, lpercentcomplete: i32, loffset: i32, llength: i32, lreserved: i32, lreslength: i32, pbbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDataCallback", "BandedDataCallback", 3usize, |this| ((**(this as *const *const IWiaDataCallback_Vtbl)).BandedDataCallback)(this, lmessage, lstatus, lpercentcomplete, loffset, llength, lreserved, lreslength, pbbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BandedDataCallback }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaDataCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IWiaDataTransfer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn idtGetData(this: *mut core::ffi::c_void, pmedium: *mut super::super::System::Com::STGMEDIUM, piwiadatacallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDataTransfer", "idtGetData", 3usize, |this| ((**(this as *const *const IWiaDataTransfer_Vtbl)).idtGetData)(this, pmedium, piwiadatacallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn idtGetBandedData(this: *mut core::ffi::c_void, pwiadatatransinfo: *mut WIA_DATA_TRANSFER_INFO, piwiadatacallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDataTransfer", "idtGetBandedData", 4usize, |this| ((**(this as *const *const IWiaDataTransfer_Vtbl)).idtGetBandedData)(this, pwiadatatransinfo, piwiadatacallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn idtQueryGetData(this: *mut core::ffi::c_void, pfe: *const WIA_FORMAT_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDataTransfer", "idtQueryGetData", 5usize, |this| ((**(this as *const *const IWiaDataTransfer_Vtbl)).idtQueryGetData)(this, pfe), |result| Some(*result))
+        }
+        unsafe extern "system" fn idtEnumWIA_FORMAT_INFO(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDataTransfer", "idtEnumWIA_FORMAT_INFO", 6usize, |this| ((**(this as *const *const IWiaDataTransfer_Vtbl)).idtEnumWIA_FORMAT_INFO)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn idtGetExtendedTransferInfo(this: *mut core::ffi::c_void, pextendedtransferinfo: *mut WIA_EXTENDED_TRANSFER_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDataTransfer", "idtGetExtendedTransferInfo", 7usize, |this| ((**(this as *const *const IWiaDataTransfer_Vtbl)).idtGetExtendedTransferInfo)(this, pextendedtransferinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+            idtGetData,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage")))]
+            idtGetData: 0,
+            idtGetBandedData,
+            idtQueryGetData,
+            idtEnumWIA_FORMAT_INFO,
+            idtGetExtendedTransferInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaDataTransfer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaDevMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumDeviceInfo(this: *mut core::ffi::c_void, lflag: i32, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr", "EnumDeviceInfo", 3usize, |this| ((**(this as *const *const IWiaDevMgr_Vtbl)).EnumDeviceInfo)(this, lflag, ppienum), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, bstrdeviceid: core::mem::MaybeUninit<windows_core::BSTR>, ppwiaitemroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr", "CreateDevice", 4usize, |this| ((**(this as *const *const IWiaDevMgr_Vtbl)).CreateDevice)(this, bstrdeviceid, ppwiaitemroot), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectDeviceDlg(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, ldevicetype: i32, lflags: i32, pbstrdeviceid: *mut core::mem::MaybeUninit<windows_core::BSTR>, ppitemroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr", "SelectDeviceDlg", 5usize, |this| ((**(this as *const *const IWiaDevMgr_Vtbl)).SelectDeviceDlg)(this, hwndparent, ldevicetype, lflags, pbstrdeviceid, ppitemroot), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectDeviceDlgID(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, ldevicetype: i32, lflags: i32, pbstrdeviceid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr", "SelectDeviceDlgID", 6usize, |this| ((**(this as *const *const IWiaDevMgr_Vtbl)).SelectDeviceDlgID)(this, hwndparent, ldevicetype, lflags, pbstrdeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImageDlg(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, ldevicetype: i32, lflags: i32, lintent: i32, pitemroot: *mut core::ffi::c_void, bstrfilename: core::mem::MaybeUninit<windows_core::BSTR>, pguidformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr", "GetImageDlg", 7usize, |this| ((**(this as *const *const IWiaDevMgr_Vtbl)).GetImageDlg)(this, hwndparent, ldevicetype, lflags, lintent, pitemroot, bstrfilename, pguidformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterEventCallbackProgram(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: core::mem::MaybeUninit<windows_core::BSTR>, peventguid: *const windows_core::GUID, bstrcommandline: core::mem::MaybeUninit<windows_core::BSTR>, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>, bstricon: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr", "RegisterEventCallbackProgram", 8usize, |this| ((**(this as *const *const IWiaDevMgr_Vtbl)).RegisterEventCallbackProgram)(this, lflags, bstrdeviceid, peventguid, bstrcommandline, bstrname, bstrdescription, bstricon), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterEventCallbackInterface(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: core::mem::MaybeUninit<windows_core::BSTR>, peventguid: *const windows_core::GUID, piwiaeventcallback: *mut core::ffi::c_void, peventobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr", "RegisterEventCallbackInterface", 9usize, |this| ((**(this as *const *const IWiaDevMgr_Vtbl)).RegisterEventCallbackInterface)(this, lflags, bstrdeviceid, peventguid, piwiaeventcallback, peventobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterEventCallbackCLSID(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: core::mem::MaybeUninit<windows_core::BSTR>, peventguid: *const windows_core::GUID, pclsid: *const windows_core::GUID, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>, bstricon: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr", "RegisterEventCallbackCLSID", 10usize, |this| ((**(this as *const *const IWiaDevMgr_Vtbl)).RegisterEventCallbackCLSID)(this, lflags, bstrdeviceid, peventguid, pclsid, bstrname, bstrdescription, bstricon), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDeviceDlg(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr", "AddDeviceDlg", 11usize, |this| ((**(this as *const *const IWiaDevMgr_Vtbl)).AddDeviceDlg)(this, hwndparent, lflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumDeviceInfo,
+            CreateDevice,
+            SelectDeviceDlg,
+            SelectDeviceDlgID,
+            GetImageDlg,
+            RegisterEventCallbackProgram,
+            RegisterEventCallbackInterface,
+            RegisterEventCallbackCLSID,
+            AddDeviceDlg,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaDevMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaDevMgr2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumDeviceInfo(this: *mut core::ffi::c_void, lflags: i32, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr2", "EnumDeviceInfo", 3usize, |this| ((**(this as *const *const IWiaDevMgr2_Vtbl)).EnumDeviceInfo)(this, lflags, ppienum), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: core::mem::MaybeUninit<windows_core::BSTR>, ppwiaitem2root: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr2", "CreateDevice", 4usize, |this| ((**(this as *const *const IWiaDevMgr2_Vtbl)).CreateDevice)(this, lflags, bstrdeviceid, ppwiaitem2root), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectDeviceDlg(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, ldevicetype: i32, lflags: i32, pbstrdeviceid: *mut core::mem::MaybeUninit<windows_core::BSTR>, ppitemroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr2", "SelectDeviceDlg", 5usize, |this| ((**(this as *const *const IWiaDevMgr2_Vtbl)).SelectDeviceDlg)(this, hwndparent, ldevicetype, lflags, pbstrdeviceid, ppitemroot), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectDeviceDlgID(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, ldevicetype: i32, lflags: i32, pbstrdeviceid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr2", "SelectDeviceDlgID", 6usize, |this| ((**(this as *const *const IWiaDevMgr2_Vtbl)).SelectDeviceDlgID)(this, hwndparent, ldevicetype, lflags, pbstrdeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterEventCallbackInterface(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: core::mem::MaybeUninit<windows_core::BSTR>, peventguid: *const windows_core::GUID, piwiaeventcallback: *mut core::ffi::c_void, peventobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr2", "RegisterEventCallbackInterface", 7usize, |this| ((**(this as *const *const IWiaDevMgr2_Vtbl)).RegisterEventCallbackInterface)(this, lflags, bstrdeviceid, peventguid, piwiaeventcallback, peventobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterEventCallbackProgram(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: core::mem::MaybeUninit<windows_core::BSTR>, peventguid: *const windows_core::GUID, bstrfullappname: core::mem::MaybeUninit<windows_core::BSTR>, bstrcommandlinearg: core::mem::MaybeUninit<windows_core::BSTR>, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>, bstricon: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr2", "RegisterEventCallbackProgram", 8usize, |this| ((**(this as *const *const IWiaDevMgr2_Vtbl)).RegisterEventCallbackProgram)(this, lflags, bstrdeviceid, peventguid, bstrfullappname, bstrcommandlinearg, bstrname, bstrdescription, bstricon), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterEventCallbackCLSID(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: core::mem::MaybeUninit<windows_core::BSTR>, peventguid: *const windows_core::GUID, pclsid: *const windows_core::GUID, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>, bstricon: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr2", "RegisterEventCallbackCLSID", 9usize, |this| ((**(this as *const *const IWiaDevMgr2_Vtbl)).RegisterEventCallbackCLSID)(this, lflags, bstrdeviceid, peventguid, pclsid, bstrname, bstrdescription, bstricon), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImageDlg(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: core::mem::MaybeUninit<windows_core::BSTR>, hwndparent: super::super::Foundation::HWND, bstrfoldername: core::mem::MaybeUninit<windows_core::BSTR>, bstrfilename: core::mem::MaybeUninit<windows_core::BSTR>, plnumfiles: *mut i32, ppbstrfilepaths: *mut *mut windows_core::BSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDevMgr2", "GetImageDlg", 10usize, |this| ((**(this as *const *const IWiaDevMgr2_Vtbl)).GetImageDlg)(this, lflags, bstrdeviceid, hwndparent, bstrfoldername, bstrfilename, plnumfiles, ppbstrfilepaths, ppitem), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumDeviceInfo,
+            CreateDevice,
+            SelectDeviceDlg,
+            SelectDeviceDlgID,
+            RegisterEventCallbackInterface,
+            RegisterEventCallbackProgram,
+            RegisterEventCallbackCLSID,
+            GetImageDlg,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaDevMgr2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaDrvItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItemFlags(this: *mut core::ffi::c_void, __midl__iwiadrvitem0000: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDrvItem", "GetItemFlags", 3usize, |this| ((**(this as *const *const IWiaDrvItem_Vtbl)).GetItemFlags)(this, __midl__iwiadrvitem0000), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceSpecContext(this: *mut core::ffi::c_void, __midl__iwiadrvitem0001: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDrvItem", "GetDeviceSpecContext", 4usize, |this| ((**(this as *const *const IWiaDrvItem_Vtbl)).GetDeviceSpecContext)(this, __midl__iwiadrvitem0001), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFullItemName(this: *mut core::ffi::c_void, __midl__iwiadrvitem0002: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDrvItem", "GetFullItemName", 5usize, |this| ((**(this as *const *const IWiaDrvItem_Vtbl)).GetFullItemName)(this, __midl__iwiadrvitem0002), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemName(this: *mut core::ffi::c_void, __midl__iwiadrvitem0003: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDrvItem", "GetItemName", 6usize, |this| ((**(this as *const *const IWiaDrvItem_Vtbl)).GetItemName)(this, __midl__iwiadrvitem0003), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddItemToFolder(this: *mut core::ffi::c_void, __midl__iwiadrvitem0004: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDrvItem", "AddItemToFolder", 7usize, |this| ((**(this as *const *const IWiaDrvItem_Vtbl)).AddItemToFolder)(this, __midl__iwiadrvitem0004), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlinkItemTree(this: *mut core::ffi::c_void, __midl__iwiadrvitem0005: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDrvItem", "UnlinkItemTree", 8usize, |this| ((**(this as *const *const IWiaDrvItem_Vtbl)).UnlinkItemTree)(this, __midl__iwiadrvitem0005), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItemFromFolder(this: *mut core::ffi::c_void, __midl__iwiadrvitem0006: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDrvItem", "RemoveItemFromFolder", 9usize, |this| ((**(this as *const *const IWiaDrvItem_Vtbl)).RemoveItemFromFolder)(this, __midl__iwiadrvitem0006), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindItemByName(this: *mut core::ffi::c_void, __midl__iwiadrvitem0007: i32, __midl__iwiadrvitem0008: core::mem::MaybeUninit<windows_core::BSTR>, __midl__iwiadrvitem0009: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDrvItem", "FindItemByName", 10usize, |this| ((**(this as *const *const IWiaDrvItem_Vtbl)).FindItemByName)(this, __midl__iwiadrvitem0007, __midl__iwiadrvitem0008, __midl__iwiadrvitem0009), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindChildItemByName(this: *mut core::ffi::c_void, __midl__iwiadrvitem0010: core::mem::MaybeUninit<windows_core::BSTR>, __midl__iwiadrvitem0011: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDrvItem", "FindChildItemByName", 11usize, |this| ((**(this as *const *const IWiaDrvItem_Vtbl)).FindChildItemByName)(this, __midl__iwiadrvitem0010, __midl__iwiadrvitem0011), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParentItem(this: *mut core::ffi::c_void, __midl__iwiadrvitem0012: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDrvItem", "GetParentItem", 12usize, |this| ((**(this as *const *const IWiaDrvItem_Vtbl)).GetParentItem)(this, __midl__iwiadrvitem0012), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFirstChildItem(this: *mut core::ffi::c_void, __midl__iwiadrvitem0013: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDrvItem", "GetFirstChildItem", 13usize, |this| ((**(this as *const *const IWiaDrvItem_Vtbl)).GetFirstChildItem)(this, __midl__iwiadrvitem0013), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextSiblingItem(this: *mut core::ffi::c_void, __midl__iwiadrvitem0014: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDrvItem", "GetNextSiblingItem", 14usize, |this| ((**(this as *const *const IWiaDrvItem_Vtbl)).GetNextSiblingItem)(this, __midl__iwiadrvitem0014), |result| Some(*result))
+        }
+        unsafe extern "system" fn DumpItemData(this: *mut core::ffi::c_void, __midl__iwiadrvitem0015: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaDrvItem", "DumpItemData", 15usize, |this| ((**(this as *const *const IWiaDrvItem_Vtbl)).DumpItemData)(this, __midl__iwiadrvitem0015), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetItemFlags,
+            GetDeviceSpecContext,
+            GetFullItemName,
+            GetItemName,
+            AddItemToFolder,
+            UnlinkItemTree,
+            RemoveItemFromFolder,
+            FindItemByName,
+            FindChildItemByName,
+            GetParentItem,
+            GetFirstChildItem,
+            GetNextSiblingItem,
+            DumpItemData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaDrvItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaErrorHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportStatus(this: *mut core::ffi::c_void, lflags: i32, hwndparent: super::super::Foundation::HWND, pwiaitem2: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT, lpercentcomplete: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaErrorHandler", "ReportStatus", 3usize, |this| ((**(this as *const *const IWiaErrorHandler_Vtbl)).ReportStatus)(this, lflags, hwndparent, pwiaitem2, hrstatus, lpercentcomplete), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatusDescription(this: *mut core::ffi::c_void, lflags: i32, pwiaitem2: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaErrorHandler", "GetStatusDescription", 4usize, |this| ((**(this as *const *const IWiaErrorHandler_Vtbl)).GetStatusDescription)(this, lflags, pwiaitem2, hrstatus, pbstrdescription), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportStatus, GetStatusDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaErrorHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaEventCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ImageEventCallback(this: *mut core::ffi::c_void, peventguid: *const windows_core::GUID, bstreventdescription: core::mem::MaybeUninit<windows_core::BSTR>, bstrdeviceid: core::mem::MaybeUninit<windows_core::BSTR>, bstrdevicedescription: core::mem::MaybeUninit<windows_core::BSTR>, dwdevicetype: u32, bstrfullitemname: core::mem::MaybeUninit<windows_core::BSTR>, puleventtype: *mut u32, ulreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaEventCallback", "ImageEventCallback", 3usize, |this| ((**(this as *const *const IWiaEventCallback_Vtbl)).ImageEventCallback)(this, peventguid, bstreventdescription, bstrdeviceid, bstrdevicedescription, dwdevicetype, bstrfullitemname, puleventtype, ulreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ImageEventCallback }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaEventCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWiaImageFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeFilter(this: *mut core::ffi::c_void, pwiaitem2: *mut core::ffi::c_void, pwiatransfercallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaImageFilter", "InitializeFilter", 3usize, |this| ((**(this as *const *const IWiaImageFilter_Vtbl)).InitializeFilter)(this, pwiaitem2, pwiatransfercallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNewCallback(this: *mut core::ffi::c_void, pwiatransfercallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaImageFilter", "SetNewCallback", 4usize, |this| ((**(this as *const *const IWiaImageFilter_Vtbl)).SetNewCallback)(this, pwiatransfercallback), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FilterPreviewImage(this: *mut core::ffi::c_void, lflags: i32, pwiachilditem2: *mut core::ffi::c_void, inputimageextents: super::super::Foundation::RECT, pinputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaImageFilter", "FilterPreviewImage", 5usize, |this| ((**(this as *const *const IWiaImageFilter_Vtbl)).FilterPreviewImage)(this, lflags, pwiachilditem2, inputimageextents, pinputstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyProperties(this: *mut core::ffi::c_void, pwiapropertystorage: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaImageFilter", "ApplyProperties", 6usize, |this| ((**(this as *const *const IWiaImageFilter_Vtbl)).ApplyProperties)(this, pwiapropertystorage), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeFilter,
+            SetNewCallback,
+            #[cfg(feature = "Win32_System_Com")]
+            FilterPreviewImage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FilterPreviewImage: 0,
+            ApplyProperties,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaImageFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItemType(this: *mut core::ffi::c_void, pitemtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem", "GetItemType", 3usize, |this| ((**(this as *const *const IWiaItem_Vtbl)).GetItemType)(this, pitemtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnalyzeItem(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem", "AnalyzeItem", 4usize, |this| ((**(this as *const *const IWiaItem_Vtbl)).AnalyzeItem)(this, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumChildItems(this: *mut core::ffi::c_void, ppienumwiaitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem", "EnumChildItems", 5usize, |this| ((**(this as *const *const IWiaItem_Vtbl)).EnumChildItems)(this, ppienumwiaitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteItem(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem", "DeleteItem", 6usize, |this| ((**(this as *const *const IWiaItem_Vtbl)).DeleteItem)(this, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateChildItem(this: *mut core::ffi::c_void, lflags: i32, bstritemname: core::mem::MaybeUninit<windows_core::BSTR>, bstrfullitemname: core::mem::MaybeUninit<windows_core::BSTR>, ppiwiaitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem", "CreateChildItem", 7usize, |this| ((**(this as *const *const IWiaItem_Vtbl)).CreateChildItem)(this, lflags, bstritemname, bstrfullitemname, ppiwiaitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumRegisterEventInfo(this: *mut core::ffi::c_void, lflags: i32, peventguid: *const windows_core::GUID, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem", "EnumRegisterEventInfo", 8usize, |this| ((**(this as *const *const IWiaItem_Vtbl)).EnumRegisterEventInfo)(this, lflags, peventguid, ppienum), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindItemByName(this: *mut core::ffi::c_void, lflags: i32, bstrfullitemname: core::mem::MaybeUninit<windows_core::BSTR>, ppiwiaitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem", "FindItemByName", 9usize, |this| ((**(this as *const *const IWiaItem_Vtbl)).FindItemByName)(this, lflags, bstrfullitemname, ppiwiaitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceDlg(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, lflags: i32, lintent: i32, plitemcount: *mut i32, ppiwiaitem: *mut *mut Option<IWiaItem>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem", "DeviceDlg", 10usize, |this| ((**(this as *const *const IWiaItem_Vtbl)).DeviceDlg)(this, hwndparent, lflags, lintent, plitemcount, ppiwiaitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceCommand(this: *mut core::ffi::c_void, lflags: i32, pcmdguid: *const windows_core::GUID, piwiaitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem", "DeviceCommand", 11usize, |this| ((**(this as *const *const IWiaItem_Vtbl)).DeviceCommand)(this, lflags, pcmdguid, piwiaitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRootItem(this: *mut core::ffi::c_void, ppiwiaitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem", "GetRootItem", 12usize, |this| ((**(this as *const *const IWiaItem_Vtbl)).GetRootItem)(this, ppiwiaitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDeviceCapabilities(this: *mut core::ffi::c_void, lflags: i32, ppienumwia_dev_caps: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem", "EnumDeviceCapabilities", 13usize, |this| ((**(this as *const *const IWiaItem_Vtbl)).EnumDeviceCapabilities)(this, lflags, ppienumwia_dev_caps), |result| Some(*result))
+        }
+        unsafe extern "system" fn DumpItemData(this: *mut core::ffi::c_void, bstrdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem", "DumpItemData", 14usize, |this| ((**(this as *const *const IWiaItem_Vtbl)).DumpItemData)(this, bstrdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn DumpDrvItemData(this: *mut core::ffi::c_void, bstrdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem", "DumpDrvItemData", 15usize, |this| ((**(this as *const *const IWiaItem_Vtbl)).DumpDrvItemData)(this, bstrdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn DumpTreeItemData(this: *mut core::ffi::c_void, bstrdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem", "DumpTreeItemData", 16usize, |this| ((**(this as *const *const IWiaItem_Vtbl)).DumpTreeItemData)(this, bstrdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Diagnostic(this: *mut core::ffi::c_void, ulsize: u32, pbuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem", "Diagnostic", 17usize, |this| ((**(this as *const *const IWiaItem_Vtbl)).Diagnostic)(this, ulsize, pbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetItemType,
+            AnalyzeItem,
+            EnumChildItems,
+            DeleteItem,
+            CreateChildItem,
+            EnumRegisterEventInfo,
+            FindItemByName,
+            DeviceDlg,
+            DeviceCommand,
+            GetRootItem,
+            EnumDeviceCapabilities,
+            DumpItemData,
+            DumpDrvItemData,
+            DumpTreeItemData,
+            Diagnostic,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaItem2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateChildItem(this: *mut core::ffi::c_void, litemflags: i32, lcreationflags: i32, bstritemname: core::mem::MaybeUninit<windows_core::BSTR>, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "CreateChildItem", 3usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).CreateChildItem)(this, litemflags, lcreationflags, bstritemname, ppiwiaitem2), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteItem(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "DeleteItem", 4usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).DeleteItem)(this, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumChildItems(this: *mut core::ffi::c_void, pcategoryguid: *const windows_core::GUID, ppienumwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "EnumChildItems", 5usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).EnumChildItems)(this, pcategoryguid, ppienumwiaitem2), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindItemByName(this: *mut core::ffi::c_void, lflags: i32, bstrfullitemname: core::mem::MaybeUninit<windows_core::BSTR>, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "FindItemByName", 6usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).FindItemByName)(this, lflags, bstrfullitemname, ppiwiaitem2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemCategory(this: *mut core::ffi::c_void, pitemcategoryguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "GetItemCategory", 7usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).GetItemCategory)(this, pitemcategoryguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemType(this: *mut core::ffi::c_void, pitemtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "GetItemType", 8usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).GetItemType)(this, pitemtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceDlg(this: *mut core::ffi::c_void, lflags: i32, hwndparent: super::super::Foundation::HWND, bstrfoldername: core::mem::MaybeUninit<windows_core::BSTR>, bstrfilename: core::mem::MaybeUninit<windows_core::BSTR>, plnumfiles: *mut i32, ppbstrfilepaths: *mut *mut windows_core::BSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "DeviceDlg", 9usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).DeviceDlg)(this, lflags, hwndparent, bstrfoldername, bstrfilename, plnumfiles, ppbstrfilepaths, ppitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceCommand(this: *mut core::ffi::c_void, lflags: i32, pcmdguid: *const windows_core::GUID, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "DeviceCommand", 10usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).DeviceCommand)(this, lflags, pcmdguid, ppiwiaitem2), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDeviceCapabilities(this: *mut core::ffi::c_void, lflags: i32, ppienumwia_dev_caps: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "EnumDeviceCapabilities", 11usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).EnumDeviceCapabilities)(this, lflags, ppienumwia_dev_caps), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckExtension(this: *mut core::ffi::c_void, lflags: i32, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, riidextensioninterface: *const windows_core::GUID, pbextensionexists: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "CheckExtension", 12usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).CheckExtension)(this, lflags, bstrname, riidextensioninterface, pbextensionexists), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExtension(this: *mut core::ffi::c_void, lflags: i32, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, riidextensioninterface: *const windows_core::GUID, ppout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "GetExtension", 13usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).GetExtension)(this, lflags, bstrname, riidextensioninterface, ppout), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParentItem(this: *mut core::ffi::c_void, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "GetParentItem", 14usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).GetParentItem)(this, ppiwiaitem2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRootItem(this: *mut core::ffi::c_void, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "GetRootItem", 15usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).GetRootItem)(this, ppiwiaitem2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviewComponent(this: *mut core::ffi::c_void, lflags: i32, ppwiapreview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "GetPreviewComponent", 16usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).GetPreviewComponent)(this, lflags, ppwiapreview), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumRegisterEventInfo(this: *mut core::ffi::c_void, lflags: i32, peventguid: *const windows_core::GUID, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "EnumRegisterEventInfo", 17usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).EnumRegisterEventInfo)(this, lflags, peventguid, ppienum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Diagnostic(this: *mut core::ffi::c_void, ulsize: u32, pbuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItem2", "Diagnostic", 18usize, |this| ((**(this as *const *const IWiaItem2_Vtbl)).Diagnostic)(this, ulsize, pbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateChildItem,
+            DeleteItem,
+            EnumChildItems,
+            FindItemByName,
+            GetItemCategory,
+            GetItemType,
+            DeviceDlg,
+            DeviceCommand,
+            EnumDeviceCapabilities,
+            CheckExtension,
+            GetExtension,
+            GetParentItem,
+            GetRootItem,
+            GetPreviewComponent,
+            EnumRegisterEventInfo,
+            Diagnostic,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaItem2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaItemExtras_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetExtendedErrorInfo(this: *mut core::ffi::c_void, bstrerrortext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItemExtras", "GetExtendedErrorInfo", 3usize, |this| ((**(this as *const *const IWiaItemExtras_Vtbl)).GetExtendedErrorInfo)(this, bstrerrortext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Escape(this: *mut core::ffi::c_void, dwescapecode: u32, lpindata: *const u8, cbindatasize: u32, poutdata: *mut u8, dwoutdatasize: u32, pdwactualdatasize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItemExtras", "Escape", 4usize, |this| ((**(this as *const *const IWiaItemExtras_Vtbl)).Escape)(this, dwescapecode, lpindata, cbindatasize, poutdata, dwoutdatasize, pdwactualdatasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelPendingIO(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaItemExtras", "CancelPendingIO", 5usize, |this| ((**(this as *const *const IWiaItemExtras_Vtbl)).CancelPendingIO)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetExtendedErrorInfo, Escape, CancelPendingIO }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaItemExtras as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaLog_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeLog(this: *mut core::ffi::c_void, hinstance: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaLog", "InitializeLog", 3usize, |this| ((**(this as *const *const IWiaLog_Vtbl)).InitializeLog)(this, hinstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn hResult(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaLog", "hResult", 4usize, |this| ((**(this as *const *const IWiaLog_Vtbl)).hResult)(this, hresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn Log(this: *mut core::ffi::c_void, lflags: i32, lresid: i32, ldetail: i32, bstrtext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaLog", "Log", 5usize, |this| ((**(this as *const *const IWiaLog_Vtbl)).Log)(this, lflags, lresid, ldetail, bstrtext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeLog, hResult, Log }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaLog as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaLogEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeLogEx(this: *mut core::ffi::c_void, hinstance: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaLogEx", "InitializeLogEx", 3usize, |this| ((**(this as *const *const IWiaLogEx_Vtbl)).InitializeLogEx)(this, hinstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn hResult(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaLogEx", "hResult", 4usize, |this| ((**(this as *const *const IWiaLogEx_Vtbl)).hResult)(this, hresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn Log(this: *mut core::ffi::c_void, lflags: i32, lresid: i32, ldetail: i32, bstrtext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaLogEx", "Log", 5usize, |this| ((**(this as *const *const IWiaLogEx_Vtbl)).Log)(this, lflags, lresid, ldetail, bstrtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn hResultEx(this: *mut core::ffi::c_void, lmethodid: i32, hresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaLogEx", "hResultEx", 6usize, |this| ((**(this as *const *const IWiaLogEx_Vtbl)).hResultEx)(this, lmethodid, hresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogEx(this: *mut core::ffi::c_void, lmethodid: i32, lflags: i32, lresid: i32, ldetail: i32, bstrtext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaLogEx", "LogEx", 7usize, |this| ((**(this as *const *const IWiaLogEx_Vtbl)).LogEx)(this, lmethodid, lflags, lresid, ldetail, bstrtext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeLogEx, hResult, Log, hResultEx, LogEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaLogEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IWiaMiniDrv_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn drvInitializeWia(this: *mut core::ffi::c_void, __midl__iwiaminidrv0000: *const u8, __midl__iwiaminidrv0001: i32, __midl__iwiaminidrv0002: core::mem::MaybeUninit<windows_core::BSTR>, __midl__iwiaminidrv0003: core::mem::MaybeUninit<windows_core::BSTR>, __midl__iwiaminidrv0004: *mut core::ffi::c_void, __midl__iwiaminidrv0005: *mut core::ffi::c_void, __midl__iwiaminidrv0006: *mut *mut core::ffi::c_void, __midl__iwiaminidrv0007: *mut *mut core::ffi::c_void, __midl__iwiaminidrv0008: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvInitializeWia", 3usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvInitializeWia)(this, __midl__iwiaminidrv0000, __midl__iwiaminidrv0001, __midl__iwiaminidrv0002, __midl__iwiaminidrv0003, __midl__iwiaminidrv0004, __midl__iwiaminidrv0005, __midl__iwiaminidrv0006, __midl__iwiaminidrv0007, __midl__iwiaminidrv0008), |result| Some(*result))
+        }
+        unsafe extern "system" fn drvAcquireItemData(this: *mut core::ffi::c_void, __midl__iwiaminidrv0009: *const u8, __midl__iwiaminidrv0010: i32, __midl__iwiaminidrv0011: *mut MINIDRV_TRANSFER_CONTEXT, __midl__iwiaminidrv0012: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvAcquireItemData", 4usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvAcquireItemData)(this, __midl__iwiaminidrv0009, __midl__iwiaminidrv0010, __midl__iwiaminidrv0011, __midl__iwiaminidrv0012), |result| Some(*result))
+        }
+        unsafe extern "system" fn drvInitItemProperties(this: *mut core::ffi::c_void, __midl__iwiaminidrv0013: *const u8, __midl__iwiaminidrv0014: i32, __midl__iwiaminidrv0015: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvInitItemProperties", 5usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvInitItemProperties)(this, __midl__iwiaminidrv0013, __midl__iwiaminidrv0014, __midl__iwiaminidrv0015), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn drvValidateItemProperties(this: *mut core::ffi::c_void, __midl__iwiaminidrv0016: *const u8, __midl__iwiaminidrv0017: i32, __midl__iwiaminidrv0018: u32, __midl__iwiaminidrv0019: *const super::super::System::Com::StructuredStorage::PROPSPEC, __midl__iwiaminidrv0020: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvValidateItemProperties", 6usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvValidateItemProperties)(this, __midl__iwiaminidrv0016, __midl__iwiaminidrv0017, __midl__iwiaminidrv0018, __midl__iwiaminidrv0019, __midl__iwiaminidrv0020), |result| Some(*result))
+        }
+        unsafe extern "system" fn drvWriteItemProperties(this: *mut core::ffi::c_void, __midl__iwiaminidrv0021: *const u8, __midl__iwiaminidrv0022: i32, __midl__iwiaminidrv0023: *const MINIDRV_TRANSFER_CONTEXT, __midl__iwiaminidrv0024: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvWriteItemProperties", 7usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvWriteItemProperties)(this, __midl__iwiaminidrv0021, __midl__iwiaminidrv0022, __midl__iwiaminidrv0023, __midl__iwiaminidrv0024), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn drvReadItemProperties(this: *mut core::ffi::c_void, __midl__iwiaminidrv0025: *const u8, __midl__iwiaminidrv0026: i32, __midl__iwiaminidrv0027: u32, __midl__iwiaminidrv0028: *const super::super::System::Com::StructuredStorage::PROPSPEC, __midl__iwiaminidrv0029: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvReadItemProperties", 8usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvReadItemProperties)(this, __midl__iwiaminidrv0025, __midl__iwiaminidrv0026, __midl__iwiaminidrv0027, __midl__iwiaminidrv0028, __midl__iwiaminidrv0029), |result| Some(*result))
+        }
+        unsafe extern "system" fn drvLockWiaDevice(this: *mut core::ffi::c_void, __midl__iwiaminidrv0030: *const u8, __midl__iwiaminidrv0031: i32, __midl__iwiaminidrv0032: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvLockWiaDevice", 9usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvLockWiaDevice)(this, __midl__iwiaminidrv0030, __midl__iwiaminidrv0031, __midl__iwiaminidrv0032), |result| Some(*result))
+        }
+        unsafe extern "system" fn drvUnLockWiaDevice(this: *mut core::ffi::c_void, __midl__iwiaminidrv0033: *const u8, __midl__iwiaminidrv0034: i32, __midl__iwiaminidrv0035: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvUnLockWiaDevice", 10usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvUnLockWiaDevice)(this, __midl__iwiaminidrv0033, __midl__iwiaminidrv0034, __midl__iwiaminidrv0035), |result| Some(*result))
+        }
+        unsafe extern "system" fn drvAnalyzeItem(this: *mut core::ffi::c_void, __midl__iwiaminidrv0036: *const u8, __midl__iwiaminidrv0037: i32, __midl__iwiaminidrv0038: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvAnalyzeItem", 11usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvAnalyzeItem)(this, __midl__iwiaminidrv0036, __midl__iwiaminidrv0037, __midl__iwiaminidrv0038), |result| Some(*result))
+        }
+        unsafe extern "system" fn drvGetDeviceErrorStr(this: *mut core::ffi::c_void, __midl__iwiaminidrv0039: i32, __midl__iwiaminidrv0040: i32, __midl__iwiaminidrv0041: *mut windows_core::PWSTR, __midl__iwiaminidrv0042: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvGetDeviceErrorStr", 12usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvGetDeviceErrorStr)(this, __midl__iwiaminidrv0039, __midl__iwiaminidrv0040, __midl__iwiaminidrv0041, __midl__iwiaminidrv0042), |result| Some(*result))
+        }
+        unsafe extern "system" fn drvDeviceCommand(this: *mut core::ffi::c_void, __midl__iwiaminidrv0043: *const u8, __midl__iwiaminidrv0044: i32, __midl__iwiaminidrv0045: *const windows_core::GUID, __midl__iwiaminidrv0046: *mut *mut core::ffi::c_void, __midl__iwiaminidrv0047: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvDeviceCommand", 13usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvDeviceCommand)(this, __midl__iwiaminidrv0043, __midl__iwiaminidrv0044, __midl__iwiaminidrv0045, __midl__iwiaminidrv0046, __midl__iwiaminidrv0047), |result| Some(*result))
+        }
+        unsafe extern "system" fn drvGetCapabilities(this: *mut core::ffi::c_void, __midl__iwiaminidrv0048: *const u8, __midl__iwiaminidrv0049: i32, __midl__iwiaminidrv0050: *mut i32, __midl__iwiaminidrv0051: *mut *mut WIA_DEV_CAP_DRV, __midl__iwiaminidrv0052: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvGetCapabilities", 14usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvGetCapabilities)(this, __midl__iwiaminidrv0048, __midl__iwiaminidrv0049, __midl__iwiaminidrv0050, __midl__iwiaminidrv0051, __midl__iwiaminidrv0052), |result| Some(*result))
+        }
+        unsafe extern "system" fn drvDeleteItem(this: *mut core::ffi::c_void, __midl__iwiaminidrv0053: *const u8, __midl__iwiaminidrv0054: i32, __midl__iwiaminidrv0055: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvDeleteItem", 15usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvDeleteItem)(this, __midl__iwiaminidrv0053, __midl__iwiaminidrv0054, __midl__iwiaminidrv0055), |result| Some(*result))
+        }
+        unsafe extern "system" fn drvFreeDrvItemContext(this: *mut core::ffi::c_void, __midl__iwiaminidrv0056: i32, __midl__iwiaminidrv0057: *const u8, __midl__iwiaminidrv0058: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvFreeDrvItemContext", 16usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvFreeDrvItemContext)(this, __midl__iwiaminidrv0056, __midl__iwiaminidrv0057, __midl__iwiaminidrv0058), |result| Some(*result))
+        }
+        unsafe extern "system" fn drvGetWiaFormatInfo(this: *mut core::ffi::c_void, __midl__iwiaminidrv0059: *const u8, __midl__iwiaminidrv0060: i32, __midl__iwiaminidrv0061: *mut i32, __midl__iwiaminidrv0062: *mut *mut WIA_FORMAT_INFO, __midl__iwiaminidrv0063: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvGetWiaFormatInfo", 17usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvGetWiaFormatInfo)(this, __midl__iwiaminidrv0059, __midl__iwiaminidrv0060, __midl__iwiaminidrv0061, __midl__iwiaminidrv0062, __midl__iwiaminidrv0063), |result| Some(*result))
+        }
+        unsafe extern "system" fn drvNotifyPnpEvent(this: *mut core::ffi::c_void, peventguid: *const windows_core::GUID, bstrdeviceid: core::mem::MaybeUninit<windows_core::BSTR>, ulreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvNotifyPnpEvent", 18usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvNotifyPnpEvent)(this, peventguid, bstrdeviceid, ulreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn drvUnInitializeWia(this: *mut core::ffi::c_void, __midl__iwiaminidrv0064: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrv", "drvUnInitializeWia", 19usize, |this| ((**(this as *const *const IWiaMiniDrv_Vtbl)).drvUnInitializeWia)(this, __midl__iwiaminidrv0064), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            drvInitializeWia,
+            drvAcquireItemData,
+            drvInitItemProperties,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            drvValidateItemProperties,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            drvValidateItemProperties: 0,
+            drvWriteItemProperties,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            drvReadItemProperties,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            drvReadItemProperties: 0,
+            drvLockWiaDevice,
+            drvUnLockWiaDevice,
+            drvAnalyzeItem,
+            drvGetDeviceErrorStr,
+            drvDeviceCommand,
+            drvGetCapabilities,
+            drvDeleteItem,
+            drvFreeDrvItemContext,
+            drvGetWiaFormatInfo,
+            drvNotifyPnpEvent,
+            drvUnInitializeWia,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaMiniDrv as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaMiniDrvCallBack_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MiniDrvCallback(this: *mut core::ffi::c_void, lreason: i32, lstatus: i32, lpercentcomplete: i32, loffset: i32, llength: i32, ptranctx: *const MINIDRV_TRANSFER_CONTEXT, lreserved: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrvCallBack", "MiniDrvCallback", 3usize, |this| ((**(this as *const *const IWiaMiniDrvCallBack_Vtbl)).MiniDrvCallback)(this, lreason, lstatus, lpercentcomplete, loffset, llength, ptranctx, lreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MiniDrvCallback }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaMiniDrvCallBack as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWiaMiniDrvTransferCallback_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetNextStream(this: *mut core::ffi::c_void, lflags: i32, bstritemname: core::mem::MaybeUninit<windows_core::BSTR>, bstrfullitemname: core::mem::MaybeUninit<windows_core::BSTR>, ppistream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrvTransferCallback", "GetNextStream", 3usize, |this| ((**(this as *const *const IWiaMiniDrvTransferCallback_Vtbl)).GetNextStream)(this, lflags, bstritemname, bstrfullitemname, ppistream), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendMessage(this: *mut core::ffi::c_void, lflags: i32, pwiatransferparams: *const WiaTransferParams) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaMiniDrvTransferCallback", "SendMessage", 4usize, |this| ((**(this as *const *const IWiaMiniDrvTransferCallback_Vtbl)).SendMessage)(this, lflags, pwiatransferparams), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetNextStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetNextStream: 0,
+            SendMessage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaMiniDrvTransferCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaNotifyDevMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NewDeviceArrival(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaNotifyDevMgr", "NewDeviceArrival", 3usize, |this| ((**(this as *const *const IWiaNotifyDevMgr_Vtbl)).NewDeviceArrival)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NewDeviceArrival }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaNotifyDevMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaPreview_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNewPreview(this: *mut core::ffi::c_void, lflags: i32, pwiaitem2: *mut core::ffi::c_void, pwiatransfercallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPreview", "GetNewPreview", 3usize, |this| ((**(this as *const *const IWiaPreview_Vtbl)).GetNewPreview)(this, lflags, pwiaitem2, pwiatransfercallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdatePreview(this: *mut core::ffi::c_void, lflags: i32, pchildwiaitem2: *mut core::ffi::c_void, pwiatransfercallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPreview", "UpdatePreview", 4usize, |this| ((**(this as *const *const IWiaPreview_Vtbl)).UpdatePreview)(this, lflags, pchildwiaitem2, pwiatransfercallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetectRegions(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPreview", "DetectRegions", 5usize, |this| ((**(this as *const *const IWiaPreview_Vtbl)).DetectRegions)(this, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPreview", "Clear", 6usize, |this| ((**(this as *const *const IWiaPreview_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNewPreview, UpdatePreview, DetectRegions, Clear }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaPreview as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IWiaPropertyStorage_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn ReadMultiple(this: *mut core::ffi::c_void, cpspec: u32, rgpspec: *const super::super::System::Com::StructuredStorage::PROPSPEC, rgpropvar: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "ReadMultiple", 3usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).ReadMultiple)(this, cpspec, rgpspec, rgpropvar), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn WriteMultiple(this: *mut core::ffi::c_void, cpspec: u32, rgpspec: *const super::super::System::Com::StructuredStorage::PROPSPEC, rgpropvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, propidnamefirst: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "WriteMultiple", 4usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).WriteMultiple)(this, cpspec, rgpspec, rgpropvar, propidnamefirst), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn DeleteMultiple(this: *mut core::ffi::c_void, cpspec: u32, rgpspec: *const super::super::System::Com::StructuredStorage::PROPSPEC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "DeleteMultiple", 5usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).DeleteMultiple)(this, cpspec, rgpspec), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadPropertyNames(this: *mut core::ffi::c_void, cpropid: u32, rgpropid: *const u32, rglpwstrname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "ReadPropertyNames", 6usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).ReadPropertyNames)(this, cpropid, rgpropid, rglpwstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn WritePropertyNames(this: *mut core::ffi::c_void, cpropid: u32, rgpropid: *const u32, rglpwstrname: *const windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "WritePropertyNames", 7usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).WritePropertyNames)(this, cpropid, rgpropid, rglpwstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePropertyNames(this: *mut core::ffi::c_void, cpropid: u32, rgpropid: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "DeletePropertyNames", 8usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).DeletePropertyNames)(this, cpropid, rgpropid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, grfcommitflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "Commit", 9usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).Commit)(this, grfcommitflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Revert(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "Revert", 10usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).Revert)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn Enum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "Enum", 11usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).Enum)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimes(this: *mut core::ffi::c_void, pctime: *const super::super::Foundation::FILETIME, patime: *const super::super::Foundation::FILETIME, pmtime: *const super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "SetTimes", 12usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).SetTimes)(this, pctime, patime, pmtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClass(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "SetClass", 13usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).SetClass)(this, clsid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn Stat(this: *mut core::ffi::c_void, pstatpsstg: *mut super::super::System::Com::StructuredStorage::STATPROPSETSTG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "Stat", 14usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).Stat)(this, pstatpsstg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn GetPropertyAttributes(this: *mut core::ffi::c_void, cpspec: u32, rgpspec: *const super::super::System::Com::StructuredStorage::PROPSPEC, rgflags: *mut u32, rgpropvar: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "GetPropertyAttributes", 15usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).GetPropertyAttributes)(this, cpspec, rgpspec, rgflags, rgpropvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pulnumprops: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "GetCount", 16usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).GetCount)(this, pulnumprops), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetPropertyStream(this: *mut core::ffi::c_void, pcompatibilityid: *mut windows_core::GUID, ppistream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "GetPropertyStream", 17usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).GetPropertyStream)(this, pcompatibilityid, ppistream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetPropertyStream(this: *mut core::ffi::c_void, pcompatibilityid: *mut windows_core::GUID, pistream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaPropertyStorage", "SetPropertyStream", 18usize, |this| ((**(this as *const *const IWiaPropertyStorage_Vtbl)).SetPropertyStream)(this, pcompatibilityid, pistream), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            ReadMultiple,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            ReadMultiple: 0,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            WriteMultiple,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            WriteMultiple: 0,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            DeleteMultiple,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            DeleteMultiple: 0,
+            ReadPropertyNames,
+            WritePropertyNames,
+            DeletePropertyNames,
+            Commit,
+            Revert,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            Enum,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            Enum: 0,
+            SetTimes,
+            SetClass,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            Stat,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            Stat: 0,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            GetPropertyAttributes,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            GetPropertyAttributes: 0,
+            GetCount,
+            #[cfg(feature = "Win32_System_Com")]
+            GetPropertyStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetPropertyStream: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetPropertyStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetPropertyStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaPropertyStorage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWiaSegmentationFilter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DetectRegions(this: *mut core::ffi::c_void, lflags: i32, pinputstream: *mut core::ffi::c_void, pwiaitem2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaSegmentationFilter", "DetectRegions", 3usize, |this| ((**(this as *const *const IWiaSegmentationFilter_Vtbl)).DetectRegions)(this, lflags, pinputstream, pwiaitem2), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            DetectRegions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DetectRegions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaSegmentationFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWiaTransfer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Download(this: *mut core::ffi::c_void, lflags: i32, piwiatransfercallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaTransfer", "Download", 3usize, |this| ((**(this as *const *const IWiaTransfer_Vtbl)).Download)(this, lflags, piwiatransfercallback), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Upload(this: *mut core::ffi::c_void, lflags: i32, psource: *mut core::ffi::c_void, piwiatransfercallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaTransfer", "Upload", 4usize, |this| ((**(this as *const *const IWiaTransfer_Vtbl)).Upload)(this, lflags, psource, piwiatransfercallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaTransfer", "Cancel", 5usize, |this| ((**(this as *const *const IWiaTransfer_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumWIA_FORMAT_INFO(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaTransfer", "EnumWIA_FORMAT_INFO", 6usize, |this| ((**(this as *const *const IWiaTransfer_Vtbl)).EnumWIA_FORMAT_INFO)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Download,
+            #[cfg(feature = "Win32_System_Com")]
+            Upload,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Upload: 0,
+            Cancel,
+            EnumWIA_FORMAT_INFO,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaTransfer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWiaTransferCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransferCallback(this: *mut core::ffi::c_void, lflags: i32, pwiatransferparams: *const WiaTransferParams) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaTransferCallback", "TransferCallback", 3usize, |this| ((**(this as *const *const IWiaTransferCallback_Vtbl)).TransferCallback)(this, lflags, pwiatransferparams), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetNextStream(this: *mut core::ffi::c_void, lflags: i32, bstritemname: core::mem::MaybeUninit<windows_core::BSTR>, bstrfullitemname: core::mem::MaybeUninit<windows_core::BSTR>, ppdestination: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaTransferCallback", "GetNextStream", 4usize, |this| ((**(this as *const *const IWiaTransferCallback_Vtbl)).GetNextStream)(this, lflags, bstritemname, bstrfullitemname, ppdestination), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TransferCallback,
+            #[cfg(feature = "Win32_System_Com")]
+            GetNextStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetNextStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaTransferCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IWiaUIExtension_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceDialog(this: *mut core::ffi::c_void, pdevicedialogdata: *const DEVICEDIALOGDATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaUIExtension", "DeviceDialog", 3usize, |this| ((**(this as *const *const IWiaUIExtension_Vtbl)).DeviceDialog)(this, pdevicedialogdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn GetDeviceIcon(this: *mut core::ffi::c_void, bstrdeviceid: core::mem::MaybeUninit<windows_core::BSTR>, phicon: *mut super::super::UI::WindowsAndMessaging::HICON, nsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaUIExtension", "GetDeviceIcon", 4usize, |this| ((**(this as *const *const IWiaUIExtension_Vtbl)).GetDeviceIcon)(this, bstrdeviceid, phicon, nsize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetDeviceBitmapLogo(this: *mut core::ffi::c_void, bstrdeviceid: core::mem::MaybeUninit<windows_core::BSTR>, phbitmap: *mut super::super::Graphics::Gdi::HBITMAP, nmaxwidth: u32, nmaxheight: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaUIExtension", "GetDeviceBitmapLogo", 5usize, |this| ((**(this as *const *const IWiaUIExtension_Vtbl)).GetDeviceBitmapLogo)(this, bstrdeviceid, phbitmap, nmaxwidth, nmaxheight), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceDialog,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            GetDeviceIcon,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            GetDeviceIcon: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetDeviceBitmapLogo,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetDeviceBitmapLogo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaUIExtension as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IWiaUIExtension2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceDialog(this: *mut core::ffi::c_void, pdevicedialogdata: *const DEVICEDIALOGDATA2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaUIExtension2", "DeviceDialog", 3usize, |this| ((**(this as *const *const IWiaUIExtension2_Vtbl)).DeviceDialog)(this, pdevicedialogdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn GetDeviceIcon(this: *mut core::ffi::c_void, bstrdeviceid: core::mem::MaybeUninit<windows_core::BSTR>, phicon: *mut super::super::UI::WindowsAndMessaging::HICON, nsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaUIExtension2", "GetDeviceIcon", 4usize, |this| ((**(this as *const *const IWiaUIExtension2_Vtbl)).GetDeviceIcon)(this, bstrdeviceid, phicon, nsize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceDialog,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            GetDeviceIcon,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            GetDeviceIcon: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaUIExtension2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWiaVideo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PreviewVisible(this: *mut core::ffi::c_void, pbpreviewvisible: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaVideo", "PreviewVisible", 3usize, |this| ((**(this as *const *const IWiaVideo_Vtbl)).PreviewVisible)(this, pbpreviewvisible), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreviewVisible(this: *mut core::ffi::c_void, bpreviewvisible: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaVideo", "SetPreviewVisible", 4usize, |this| ((**(this as *const *const IWiaVideo_Vtbl)).SetPreviewVisible)(this, bpreviewvisible), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImagesDirectory(this: *mut core::ffi::c_void, pbstrimagedirectory: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaVideo", "ImagesDirectory", 5usize, |this| ((**(this as *const *const IWiaVideo_Vtbl)).ImagesDirectory)(this, pbstrimagedirectory), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetImagesDirectory(this: *mut core::ffi::c_void, bstrimagedirectory: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaVideo", "SetImagesDirectory", 6usize, |this| ((**(this as *const *const IWiaVideo_Vtbl)).SetImagesDirectory)(this, bstrimagedirectory), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVideoByWiaDevID(this: *mut core::ffi::c_void, bstrwiadeviceid: core::mem::MaybeUninit<windows_core::BSTR>, hwndparent: super::super::Foundation::HWND, bstretchtofitparent: super::super::Foundation::BOOL, bautobeginplayback: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaVideo", "CreateVideoByWiaDevID", 7usize, |this| ((**(this as *const *const IWiaVideo_Vtbl)).CreateVideoByWiaDevID)(this, bstrwiadeviceid, hwndparent, bstretchtofitparent, bautobeginplayback), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVideoByDevNum(this: *mut core::ffi::c_void, uidevicenumber: u32, hwndparent: super::super::Foundation::HWND, bstretchtofitparent: super::super::Foundation::BOOL, bautobeginplayback: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaVideo", "CreateVideoByDevNum", 8usize, |this| ((**(this as *const *const IWiaVideo_Vtbl)).CreateVideoByDevNum)(this, uidevicenumber, hwndparent, bstretchtofitparent, bautobeginplayback), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVideoByName(this: *mut core::ffi::c_void, bstrfriendlyname: core::mem::MaybeUninit<windows_core::BSTR>, hwndparent: super::super::Foundation::HWND, bstretchtofitparent: super::super::Foundation::BOOL, bautobeginplayback: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaVideo", "CreateVideoByName", 9usize, |this| ((**(this as *const *const IWiaVideo_Vtbl)).CreateVideoByName)(this, bstrfriendlyname, hwndparent, bstretchtofitparent, bautobeginplayback), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestroyVideo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaVideo", "DestroyVideo", 10usize, |this| ((**(this as *const *const IWiaVideo_Vtbl)).DestroyVideo)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Play(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaVideo", "Play", 11usize, |this| ((**(this as *const *const IWiaVideo_Vtbl)).Play)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaVideo", "Pause", 12usize, |this| ((**(this as *const *const IWiaVideo_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TakePicture(this: *mut core::ffi::c_void, pbstrnewimagefilename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaVideo", "TakePicture", 13usize, |this| ((**(this as *const *const IWiaVideo_Vtbl)).TakePicture)(this, pbstrnewimagefilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResizeVideo(this: *mut core::ffi::c_void, bstretchtofitparent: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaVideo", "ResizeVideo", 14usize, |this| ((**(this as *const *const IWiaVideo_Vtbl)).ResizeVideo)(this, bstretchtofitparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentState(this: *mut core::ffi::c_void, pstate: *mut WIAVIDEO_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWiaVideo", "GetCurrentState", 15usize, |this| ((**(this as *const *const IWiaVideo_Vtbl)).GetCurrentState)(this, pstate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PreviewVisible,
+            SetPreviewVisible,
+            ImagesDirectory,
+            SetImagesDirectory,
+            CreateVideoByWiaDevID,
+            CreateVideoByDevNum,
+            CreateVideoByName,
+            DestroyVideo,
+            Play,
+            Pause,
+            TakePicture,
+            ResizeVideo,
+            GetCurrentState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaVideo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -3742,6 +3742,7 @@ pub struct DELETE_OBJECT_OPTIONS(pub i32);
 impl windows_core::TypeKind for DELETE_OBJECT_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DELETE_OBJECT_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DELETE_OBJECT_OPTIONS").field(&self.0).finish()
@@ -3753,6 +3754,7 @@ pub struct DEVICE_RADIO_STATE(pub i32);
 impl windows_core::TypeKind for DEVICE_RADIO_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVICE_RADIO_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVICE_RADIO_STATE").field(&self.0).finish()
@@ -3764,6 +3766,7 @@ pub struct SMS_MESSAGE_TYPES(pub i32);
 impl windows_core::TypeKind for SMS_MESSAGE_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SMS_MESSAGE_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SMS_MESSAGE_TYPES").field(&self.0).finish()
@@ -3775,6 +3778,7 @@ pub struct SYSTEM_RADIO_STATE(pub i32);
 impl windows_core::TypeKind for SYSTEM_RADIO_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSTEM_RADIO_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSTEM_RADIO_STATE").field(&self.0).finish()
@@ -3786,6 +3790,7 @@ pub struct WPD_BITRATE_TYPES(pub i32);
 impl windows_core::TypeKind for WPD_BITRATE_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_BITRATE_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_BITRATE_TYPES").field(&self.0).finish()
@@ -3797,6 +3802,7 @@ pub struct WPD_CAPTURE_MODES(pub i32);
 impl windows_core::TypeKind for WPD_CAPTURE_MODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_CAPTURE_MODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_CAPTURE_MODES").field(&self.0).finish()
@@ -3808,6 +3814,7 @@ pub struct WPD_COLOR_CORRECTED_STATUS_VALUES(pub i32);
 impl windows_core::TypeKind for WPD_COLOR_CORRECTED_STATUS_VALUES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_COLOR_CORRECTED_STATUS_VALUES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_COLOR_CORRECTED_STATUS_VALUES").field(&self.0).finish()
@@ -3819,6 +3826,7 @@ pub struct WPD_COMMAND_ACCESS_TYPES(pub i32);
 impl windows_core::TypeKind for WPD_COMMAND_ACCESS_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_COMMAND_ACCESS_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_COMMAND_ACCESS_TYPES").field(&self.0).finish()
@@ -3830,6 +3838,7 @@ pub struct WPD_CROPPED_STATUS_VALUES(pub i32);
 impl windows_core::TypeKind for WPD_CROPPED_STATUS_VALUES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_CROPPED_STATUS_VALUES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_CROPPED_STATUS_VALUES").field(&self.0).finish()
@@ -3841,6 +3850,7 @@ pub struct WPD_DEVICE_TRANSPORTS(pub i32);
 impl windows_core::TypeKind for WPD_DEVICE_TRANSPORTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_DEVICE_TRANSPORTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_DEVICE_TRANSPORTS").field(&self.0).finish()
@@ -3852,6 +3862,7 @@ pub struct WPD_DEVICE_TYPES(pub i32);
 impl windows_core::TypeKind for WPD_DEVICE_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_DEVICE_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_DEVICE_TYPES").field(&self.0).finish()
@@ -3863,6 +3874,7 @@ pub struct WPD_EFFECT_MODES(pub i32);
 impl windows_core::TypeKind for WPD_EFFECT_MODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_EFFECT_MODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_EFFECT_MODES").field(&self.0).finish()
@@ -3874,6 +3886,7 @@ pub struct WPD_EXPOSURE_METERING_MODES(pub i32);
 impl windows_core::TypeKind for WPD_EXPOSURE_METERING_MODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_EXPOSURE_METERING_MODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_EXPOSURE_METERING_MODES").field(&self.0).finish()
@@ -3885,6 +3898,7 @@ pub struct WPD_EXPOSURE_PROGRAM_MODES(pub i32);
 impl windows_core::TypeKind for WPD_EXPOSURE_PROGRAM_MODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_EXPOSURE_PROGRAM_MODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_EXPOSURE_PROGRAM_MODES").field(&self.0).finish()
@@ -3896,6 +3910,7 @@ pub struct WPD_FLASH_MODES(pub i32);
 impl windows_core::TypeKind for WPD_FLASH_MODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_FLASH_MODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_FLASH_MODES").field(&self.0).finish()
@@ -3907,6 +3922,7 @@ pub struct WPD_FOCUS_METERING_MODES(pub i32);
 impl windows_core::TypeKind for WPD_FOCUS_METERING_MODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_FOCUS_METERING_MODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_FOCUS_METERING_MODES").field(&self.0).finish()
@@ -3918,6 +3934,7 @@ pub struct WPD_FOCUS_MODES(pub i32);
 impl windows_core::TypeKind for WPD_FOCUS_MODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_FOCUS_MODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_FOCUS_MODES").field(&self.0).finish()
@@ -3929,6 +3946,7 @@ pub struct WPD_META_GENRES(pub i32);
 impl windows_core::TypeKind for WPD_META_GENRES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_META_GENRES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_META_GENRES").field(&self.0).finish()
@@ -3940,6 +3958,7 @@ pub struct WPD_OPERATION_STATES(pub i32);
 impl windows_core::TypeKind for WPD_OPERATION_STATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_OPERATION_STATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_OPERATION_STATES").field(&self.0).finish()
@@ -3951,6 +3970,7 @@ pub struct WPD_PARAMETER_USAGE_TYPES(pub i32);
 impl windows_core::TypeKind for WPD_PARAMETER_USAGE_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_PARAMETER_USAGE_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_PARAMETER_USAGE_TYPES").field(&self.0).finish()
@@ -3962,6 +3982,7 @@ pub struct WPD_POWER_SOURCES(pub i32);
 impl windows_core::TypeKind for WPD_POWER_SOURCES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_POWER_SOURCES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_POWER_SOURCES").field(&self.0).finish()
@@ -3973,6 +3994,7 @@ pub struct WPD_RENDERING_INFORMATION_PROFILE_ENTRY_TYPES(pub i32);
 impl windows_core::TypeKind for WPD_RENDERING_INFORMATION_PROFILE_ENTRY_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_RENDERING_INFORMATION_PROFILE_ENTRY_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_RENDERING_INFORMATION_PROFILE_ENTRY_TYPES").field(&self.0).finish()
@@ -3984,6 +4006,7 @@ pub struct WPD_SECTION_DATA_UNITS_VALUES(pub i32);
 impl windows_core::TypeKind for WPD_SECTION_DATA_UNITS_VALUES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_SECTION_DATA_UNITS_VALUES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_SECTION_DATA_UNITS_VALUES").field(&self.0).finish()
@@ -3995,6 +4018,7 @@ pub struct WPD_SERVICE_INHERITANCE_TYPES(pub i32);
 impl windows_core::TypeKind for WPD_SERVICE_INHERITANCE_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_SERVICE_INHERITANCE_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_SERVICE_INHERITANCE_TYPES").field(&self.0).finish()
@@ -4006,6 +4030,7 @@ pub struct WPD_SMS_ENCODING_TYPES(pub i32);
 impl windows_core::TypeKind for WPD_SMS_ENCODING_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_SMS_ENCODING_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_SMS_ENCODING_TYPES").field(&self.0).finish()
@@ -4017,6 +4042,7 @@ pub struct WPD_STORAGE_ACCESS_CAPABILITY_VALUES(pub i32);
 impl windows_core::TypeKind for WPD_STORAGE_ACCESS_CAPABILITY_VALUES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_STORAGE_ACCESS_CAPABILITY_VALUES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_STORAGE_ACCESS_CAPABILITY_VALUES").field(&self.0).finish()
@@ -4028,6 +4054,7 @@ pub struct WPD_STORAGE_TYPE_VALUES(pub i32);
 impl windows_core::TypeKind for WPD_STORAGE_TYPE_VALUES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_STORAGE_TYPE_VALUES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_STORAGE_TYPE_VALUES").field(&self.0).finish()
@@ -4039,6 +4066,7 @@ pub struct WPD_STREAM_UNITS(pub i32);
 impl windows_core::TypeKind for WPD_STREAM_UNITS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_STREAM_UNITS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_STREAM_UNITS").field(&self.0).finish()
@@ -4050,6 +4078,7 @@ pub struct WPD_VIDEO_SCAN_TYPES(pub i32);
 impl windows_core::TypeKind for WPD_VIDEO_SCAN_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_VIDEO_SCAN_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_VIDEO_SCAN_TYPES").field(&self.0).finish()
@@ -4061,6 +4090,7 @@ pub struct WPD_WHITE_BALANCE_SETTINGS(pub i32);
 impl windows_core::TypeKind for WPD_WHITE_BALANCE_SETTINGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPD_WHITE_BALANCE_SETTINGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPD_WHITE_BALANCE_SETTINGS").field(&self.0).finish()
@@ -4072,6 +4102,7 @@ pub struct WpdAttributeForm(pub i32);
 impl windows_core::TypeKind for WpdAttributeForm {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WpdAttributeForm {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WpdAttributeForm").field(&self.0).finish()
@@ -4083,6 +4114,7 @@ pub struct WpdParameterAttributeForm(pub i32);
 impl windows_core::TypeKind for WpdParameterAttributeForm {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WpdParameterAttributeForm {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WpdParameterAttributeForm").field(&self.0).finish()
@@ -4102,7 +4134,8 @@ pub const PortableDeviceValuesCollection: windows_core::GUID = windows_core::GUI
 pub const PortableDeviceWebControl: windows_core::GUID = windows_core::GUID::from_u128(0x186dd02c_2dec_41b5_a7d4_b59056fade51);
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WPD_COMMAND_ACCESS_LOOKUP_ENTRY {
     pub Command: super::super::UI::Shell::PropertiesSystem::PROPERTYKEY,
     pub AccessType: u32,
@@ -4121,3 +4154,5 @@ impl Default for WPD_COMMAND_ACCESS_LOOKUP_ENTRY {
 pub const WpdSerializer: windows_core::GUID = windows_core::GUID::from_u128(0x0b91a74b_ad7c_4a9d_b563_29eef9167172);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
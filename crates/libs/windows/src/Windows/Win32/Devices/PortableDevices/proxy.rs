@@ -0,0 +1,1306 @@
+impl windows_core::imp::ProxyVtable for IConnectionRequestCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnComplete(this: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionRequestCallback", "OnComplete", 3usize, |this| ((**(this as *const *const IConnectionRequestCallback_Vtbl)).OnComplete)(this, hrstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionRequestCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumPortableDeviceConnectors_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, crequested: u32, pconnectors: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPortableDeviceConnectors", "Next", 3usize, |this| ((**(this as *const *const IEnumPortableDeviceConnectors_Vtbl)).Next)(this, crequested, pconnectors, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cconnectors: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPortableDeviceConnectors", "Skip", 4usize, |this| ((**(this as *const *const IEnumPortableDeviceConnectors_Vtbl)).Skip)(this, cconnectors), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPortableDeviceConnectors", "Reset", 5usize, |this| ((**(this as *const *const IEnumPortableDeviceConnectors_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPortableDeviceConnectors", "Clone", 6usize, |this| ((**(this as *const *const IEnumPortableDeviceConnectors_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumPortableDeviceConnectors as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumPortableDeviceObjectIDs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cobjects: u32, pobjids: *mut windows_core::PWSTR, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPortableDeviceObjectIDs", "Next", 3usize, |this| ((**(this as *const *const IEnumPortableDeviceObjectIDs_Vtbl)).Next)(this, cobjects, pobjids, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cobjects: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPortableDeviceObjectIDs", "Skip", 4usize, |this| ((**(this as *const *const IEnumPortableDeviceObjectIDs_Vtbl)).Skip)(this, cobjects), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPortableDeviceObjectIDs", "Reset", 5usize, |this| ((**(this as *const *const IEnumPortableDeviceObjectIDs_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPortableDeviceObjectIDs", "Clone", 6usize, |this| ((**(this as *const *const IEnumPortableDeviceObjectIDs_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPortableDeviceObjectIDs", "Cancel", 7usize, |this| ((**(this as *const *const IEnumPortableDeviceObjectIDs_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumPortableDeviceObjectIDs as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaRadioManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRadioInstances(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaRadioManager", "GetRadioInstances", 3usize, |this| ((**(this as *const *const IMediaRadioManager_Vtbl)).GetRadioInstances)(this, ppcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSystemRadioStateChange(this: *mut core::ffi::c_void, sysradiostate: SYSTEM_RADIO_STATE, utimeoutsec: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaRadioManager", "OnSystemRadioStateChange", 4usize, |this| ((**(this as *const *const IMediaRadioManager_Vtbl)).OnSystemRadioStateChange)(this, sysradiostate, utimeoutsec), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRadioInstances, OnSystemRadioStateChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaRadioManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaRadioManagerNotifySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnInstanceAdd(this: *mut core::ffi::c_void, pradioinstance: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaRadioManagerNotifySink", "OnInstanceAdd", 3usize, |this| ((**(this as *const *const IMediaRadioManagerNotifySink_Vtbl)).OnInstanceAdd)(this, pradioinstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnInstanceRemove(this: *mut core::ffi::c_void, bstrradioinstanceid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaRadioManagerNotifySink", "OnInstanceRemove", 4usize, |this| ((**(this as *const *const IMediaRadioManagerNotifySink_Vtbl)).OnInstanceRemove)(this, bstrradioinstanceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnInstanceRadioChange(this: *mut core::ffi::c_void, bstrradioinstanceid: core::mem::MaybeUninit<windows_core::BSTR>, radiostate: DEVICE_RADIO_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaRadioManagerNotifySink", "OnInstanceRadioChange", 5usize, |this| ((**(this as *const *const IMediaRadioManagerNotifySink_Vtbl)).OnInstanceRadioChange)(this, bstrradioinstanceid, radiostate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnInstanceAdd, OnInstanceRemove, OnInstanceRadioChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaRadioManagerNotifySink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPortableDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, pszpnpdeviceid: windows_core::PCWSTR, pclientinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevice", "Open", 3usize, |this| ((**(this as *const *const IPortableDevice_Vtbl)).Open)(this, pszpnpdeviceid, pclientinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendCommand(this: *mut core::ffi::c_void, dwflags: u32, pparameters: *mut core::ffi::c_void, ppresults: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevice", "SendCommand", 4usize, |this| ((**(this as *const *const IPortableDevice_Vtbl)).SendCommand)(this, dwflags, pparameters, ppresults), |result| Some(*result))
+        }
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, ppcontent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevice", "Content", 5usize, |this| ((**(this as *const *const IPortableDevice_Vtbl)).Content)(this, ppcontent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Capabilities(this: *mut core::ffi::c_void, ppcapabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevice", "Capabilities", 6usize, |this| ((**(this as *const *const IPortableDevice_Vtbl)).Capabilities)(this, ppcapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevice", "Cancel", 7usize, |this| ((**(this as *const *const IPortableDevice_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevice", "Close", 8usize, |this| ((**(this as *const *const IPortableDevice_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, dwflags: u32, pcallback: *mut core::ffi::c_void, pparameters: *mut core::ffi::c_void, ppszcookie: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevice", "Advise", 9usize, |this| ((**(this as *const *const IPortableDevice_Vtbl)).Advise)(this, dwflags, pcallback, pparameters, ppszcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, pszcookie: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevice", "Unadvise", 10usize, |this| ((**(this as *const *const IPortableDevice_Vtbl)).Unadvise)(this, pszcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPnPDeviceID(this: *mut core::ffi::c_void, ppszpnpdeviceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevice", "GetPnPDeviceID", 11usize, |this| ((**(this as *const *const IPortableDevice_Vtbl)).GetPnPDeviceID)(this, ppszpnpdeviceid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Open,
+            SendCommand,
+            Content,
+            Capabilities,
+            Cancel,
+            Close,
+            Advise,
+            Unadvise,
+            GetPnPDeviceID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IPortableDeviceCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSupportedCommands(this: *mut core::ffi::c_void, ppcommands: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceCapabilities", "GetSupportedCommands", 3usize, |this| ((**(this as *const *const IPortableDeviceCapabilities_Vtbl)).GetSupportedCommands)(this, ppcommands), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetCommandOptions(this: *mut core::ffi::c_void, command: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceCapabilities", "GetCommandOptions", 4usize, |this| ((**(this as *const *const IPortableDeviceCapabilities_Vtbl)).GetCommandOptions)(this, command, ppoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionalCategories(this: *mut core::ffi::c_void, ppcategories: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceCapabilities", "GetFunctionalCategories", 5usize, |this| ((**(this as *const *const IPortableDeviceCapabilities_Vtbl)).GetFunctionalCategories)(this, ppcategories), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionalObjects(this: *mut core::ffi::c_void, category: *const windows_core::GUID, ppobjectids: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceCapabilities", "GetFunctionalObjects", 6usize, |this| ((**(this as *const *const IPortableDeviceCapabilities_Vtbl)).GetFunctionalObjects)(this, category, ppobjectids), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedContentTypes(this: *mut core::ffi::c_void, category: *const windows_core::GUID, ppcontenttypes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceCapabilities", "GetSupportedContentTypes", 7usize, |this| ((**(this as *const *const IPortableDeviceCapabilities_Vtbl)).GetSupportedContentTypes)(this, category, ppcontenttypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedFormats(this: *mut core::ffi::c_void, contenttype: *const windows_core::GUID, ppformats: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceCapabilities", "GetSupportedFormats", 8usize, |this| ((**(this as *const *const IPortableDeviceCapabilities_Vtbl)).GetSupportedFormats)(this, contenttype, ppformats), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedFormatProperties(this: *mut core::ffi::c_void, format: *const windows_core::GUID, ppkeys: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceCapabilities", "GetSupportedFormatProperties", 9usize, |this| ((**(this as *const *const IPortableDeviceCapabilities_Vtbl)).GetSupportedFormatProperties)(this, format, ppkeys), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetFixedPropertyAttributes(this: *mut core::ffi::c_void, format: *const windows_core::GUID, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceCapabilities", "GetFixedPropertyAttributes", 10usize, |this| ((**(this as *const *const IPortableDeviceCapabilities_Vtbl)).GetFixedPropertyAttributes)(this, format, key, ppattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceCapabilities", "Cancel", 11usize, |this| ((**(this as *const *const IPortableDeviceCapabilities_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedEvents(this: *mut core::ffi::c_void, ppevents: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceCapabilities", "GetSupportedEvents", 12usize, |this| ((**(this as *const *const IPortableDeviceCapabilities_Vtbl)).GetSupportedEvents)(this, ppevents), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventOptions(this: *mut core::ffi::c_void, event: *const windows_core::GUID, ppoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceCapabilities", "GetEventOptions", 13usize, |this| ((**(this as *const *const IPortableDeviceCapabilities_Vtbl)).GetEventOptions)(this, event, ppoptions), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSupportedCommands,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetCommandOptions,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetCommandOptions: 0,
+            GetFunctionalCategories,
+            GetFunctionalObjects,
+            GetSupportedContentTypes,
+            GetSupportedFormats,
+            GetSupportedFormatProperties,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetFixedPropertyAttributes,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetFixedPropertyAttributes: 0,
+            Cancel,
+            GetSupportedEvents,
+            GetEventOptions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceCapabilities as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Devices_Properties")]
+impl windows_core::imp::ProxyVtable for IPortableDeviceConnector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceConnector", "Connect", 3usize, |this| ((**(this as *const *const IPortableDeviceConnector_Vtbl)).Connect)(this, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceConnector", "Disconnect", 4usize, |this| ((**(this as *const *const IPortableDeviceConnector_Vtbl)).Disconnect)(this, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceConnector", "Cancel", 5usize, |this| ((**(this as *const *const IPortableDeviceConnector_Vtbl)).Cancel)(this, pcallback), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Devices_Properties")]
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, ppropertykey: *const super::Properties::DEVPROPKEY, ppropertytype: *mut super::Properties::DEVPROPTYPE, ppdata: *mut *mut u8, pcbdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceConnector", "GetProperty", 6usize, |this| ((**(this as *const *const IPortableDeviceConnector_Vtbl)).GetProperty)(this, ppropertykey, ppropertytype, ppdata, pcbdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Devices_Properties")]
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, ppropertykey: *const super::Properties::DEVPROPKEY, propertytype: super::Properties::DEVPROPTYPE, pdata: *const u8, cbdata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceConnector", "SetProperty", 7usize, |this| ((**(this as *const *const IPortableDeviceConnector_Vtbl)).SetProperty)(this, ppropertykey, propertytype, pdata, cbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPnPID(this: *mut core::ffi::c_void, ppwszpnpid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceConnector", "GetPnPID", 8usize, |this| ((**(this as *const *const IPortableDeviceConnector_Vtbl)).GetPnPID)(this, ppwszpnpid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Connect,
+            Disconnect,
+            Cancel,
+            #[cfg(feature = "Win32_Devices_Properties")]
+            GetProperty,
+            #[cfg(not(feature = "Win32_Devices_Properties"))]
+            GetProperty: 0,
+            #[cfg(feature = "Win32_Devices_Properties")]
+            SetProperty,
+            #[cfg(not(feature = "Win32_Devices_Properties"))]
+            SetProperty: 0,
+            GetPnPID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceConnector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPortableDeviceContent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumObjects(this: *mut core::ffi::c_void, dwflags: u32, pszparentobjectid: windows_core::PCWSTR, pfilter: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceContent", "EnumObjects", 3usize, |this| ((**(this as *const *const IPortableDeviceContent_Vtbl)).EnumObjects)(this, dwflags, pszparentobjectid, pfilter, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceContent", "Properties", 4usize, |this| ((**(this as *const *const IPortableDeviceContent_Vtbl)).Properties)(this, ppproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn Transfer(this: *mut core::ffi::c_void, ppresources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceContent", "Transfer", 5usize, |this| ((**(this as *const *const IPortableDeviceContent_Vtbl)).Transfer)(this, ppresources), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateObjectWithPropertiesOnly(this: *mut core::ffi::c_void, pvalues: *mut core::ffi::c_void, ppszobjectid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceContent", "CreateObjectWithPropertiesOnly", 6usize, |this| ((**(this as *const *const IPortableDeviceContent_Vtbl)).CreateObjectWithPropertiesOnly)(this, pvalues, ppszobjectid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateObjectWithPropertiesAndData(this: *mut core::ffi::c_void, pvalues: *mut core::ffi::c_void, ppdata: *mut *mut core::ffi::c_void, pdwoptimalwritebuffersize: *mut u32, ppszcookie: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceContent", "CreateObjectWithPropertiesAndData", 7usize, |this| ((**(this as *const *const IPortableDeviceContent_Vtbl)).CreateObjectWithPropertiesAndData)(this, pvalues, ppdata, pdwoptimalwritebuffersize, ppszcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, dwoptions: u32, pobjectids: *mut core::ffi::c_void, ppresults: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceContent", "Delete", 8usize, |this| ((**(this as *const *const IPortableDeviceContent_Vtbl)).Delete)(this, dwoptions, pobjectids, ppresults), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectIDsFromPersistentUniqueIDs(this: *mut core::ffi::c_void, ppersistentuniqueids: *mut core::ffi::c_void, ppobjectids: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceContent", "GetObjectIDsFromPersistentUniqueIDs", 9usize, |this| ((**(this as *const *const IPortableDeviceContent_Vtbl)).GetObjectIDsFromPersistentUniqueIDs)(this, ppersistentuniqueids, ppobjectids), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceContent", "Cancel", 10usize, |this| ((**(this as *const *const IPortableDeviceContent_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, pobjectids: *mut core::ffi::c_void, pszdestinationfolderobjectid: windows_core::PCWSTR, ppresults: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceContent", "Move", 11usize, |this| ((**(this as *const *const IPortableDeviceContent_Vtbl)).Move)(this, pobjectids, pszdestinationfolderobjectid, ppresults), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, pobjectids: *mut core::ffi::c_void, pszdestinationfolderobjectid: windows_core::PCWSTR, ppresults: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceContent", "Copy", 12usize, |this| ((**(this as *const *const IPortableDeviceContent_Vtbl)).Copy)(this, pobjectids, pszdestinationfolderobjectid, ppresults), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumObjects,
+            Properties,
+            Transfer,
+            CreateObjectWithPropertiesOnly,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateObjectWithPropertiesAndData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateObjectWithPropertiesAndData: 0,
+            Delete,
+            GetObjectIDsFromPersistentUniqueIDs,
+            Cancel,
+            Move,
+            Copy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceContent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPortableDeviceContent2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UpdateObjectWithPropertiesAndData(this: *mut core::ffi::c_void, pszobjectid: windows_core::PCWSTR, pproperties: *mut core::ffi::c_void, ppdata: *mut *mut core::ffi::c_void, pdwoptimalwritebuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceContent2", "UpdateObjectWithPropertiesAndData", 13usize, |this| ((**(this as *const *const IPortableDeviceContent2_Vtbl)).UpdateObjectWithPropertiesAndData)(this, pszobjectid, pproperties, ppdata, pdwoptimalwritebuffersize), |result| Some(*result))
+        }
+        Self {
+            base__: <IPortableDeviceContent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            UpdateObjectWithPropertiesAndData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UpdateObjectWithPropertiesAndData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceContent2 as windows_core::Interface>::IID || <IPortableDeviceContent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPortableDeviceDataStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObjectID(this: *mut core::ffi::c_void, ppszobjectid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceDataStream", "GetObjectID", 14usize, |this| ((**(this as *const *const IPortableDeviceDataStream_Vtbl)).GetObjectID)(this, ppszobjectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceDataStream", "Cancel", 15usize, |this| ((**(this as *const *const IPortableDeviceDataStream_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetObjectID, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceDataStream as windows_core::Interface>::IID || <super::super::System::Com::IStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPortableDeviceDispatchFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDeviceDispatch(this: *mut core::ffi::c_void, pszpnpdeviceid: windows_core::PCWSTR, ppdevicedispatch: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceDispatchFactory", "GetDeviceDispatch", 3usize, |this| ((**(this as *const *const IPortableDeviceDispatchFactory_Vtbl)).GetDeviceDispatch)(this, pszpnpdeviceid, ppdevicedispatch), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDeviceDispatch,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDeviceDispatch: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceDispatchFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPortableDeviceEventCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnEvent(this: *mut core::ffi::c_void, peventparameters: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceEventCallback", "OnEvent", 3usize, |this| ((**(this as *const *const IPortableDeviceEventCallback_Vtbl)).OnEvent)(this, peventparameters), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceEventCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IPortableDeviceKeyCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelems: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceKeyCollection", "GetCount", 3usize, |this| ((**(this as *const *const IPortableDeviceKeyCollection_Vtbl)).GetCount)(this, pcelems), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, dwindex: u32, pkey: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceKeyCollection", "GetAt", 4usize, |this| ((**(this as *const *const IPortableDeviceKeyCollection_Vtbl)).GetAt)(this, dwindex, pkey), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceKeyCollection", "Add", 5usize, |this| ((**(this as *const *const IPortableDeviceKeyCollection_Vtbl)).Add)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceKeyCollection", "Clear", 6usize, |this| ((**(this as *const *const IPortableDeviceKeyCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, dwindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceKeyCollection", "RemoveAt", 7usize, |this| ((**(this as *const *const IPortableDeviceKeyCollection_Vtbl)).RemoveAt)(this, dwindex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetAt,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetAt: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Add,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Add: 0,
+            Clear,
+            RemoveAt,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceKeyCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPortableDeviceManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevices(this: *mut core::ffi::c_void, ppnpdeviceids: *mut windows_core::PWSTR, pcpnpdeviceids: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceManager", "GetDevices", 3usize, |this| ((**(this as *const *const IPortableDeviceManager_Vtbl)).GetDevices)(this, ppnpdeviceids, pcpnpdeviceids), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefreshDeviceList(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceManager", "RefreshDeviceList", 4usize, |this| ((**(this as *const *const IPortableDeviceManager_Vtbl)).RefreshDeviceList)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceFriendlyName(this: *mut core::ffi::c_void, pszpnpdeviceid: windows_core::PCWSTR, pdevicefriendlyname: windows_core::PWSTR, pcchdevicefriendlyname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceManager", "GetDeviceFriendlyName", 5usize, |this| ((**(this as *const *const IPortableDeviceManager_Vtbl)).GetDeviceFriendlyName)(this, pszpnpdeviceid, pdevicefriendlyname, pcchdevicefriendlyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceDescription(this: *mut core::ffi::c_void, pszpnpdeviceid: windows_core::PCWSTR, pdevicedescription: windows_core::PWSTR, pcchdevicedescription: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceManager", "GetDeviceDescription", 6usize, |this| ((**(this as *const *const IPortableDeviceManager_Vtbl)).GetDeviceDescription)(this, pszpnpdeviceid, pdevicedescription, pcchdevicedescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceManufacturer(this: *mut core::ffi::c_void, pszpnpdeviceid: windows_core::PCWSTR, pdevicemanufacturer: windows_core::PWSTR, pcchdevicemanufacturer: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceManager", "GetDeviceManufacturer", 7usize, |this| ((**(this as *const *const IPortableDeviceManager_Vtbl)).GetDeviceManufacturer)(this, pszpnpdeviceid, pdevicemanufacturer, pcchdevicemanufacturer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceProperty(this: *mut core::ffi::c_void, pszpnpdeviceid: windows_core::PCWSTR, pszdevicepropertyname: windows_core::PCWSTR, pdata: *mut u8, pcbdata: *mut u32, pdwtype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceManager", "GetDeviceProperty", 8usize, |this| ((**(this as *const *const IPortableDeviceManager_Vtbl)).GetDeviceProperty)(this, pszpnpdeviceid, pszdevicepropertyname, pdata, pcbdata, pdwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivateDevices(this: *mut core::ffi::c_void, ppnpdeviceids: *mut windows_core::PWSTR, pcpnpdeviceids: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceManager", "GetPrivateDevices", 9usize, |this| ((**(this as *const *const IPortableDeviceManager_Vtbl)).GetPrivateDevices)(this, ppnpdeviceids, pcpnpdeviceids), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDevices,
+            RefreshDeviceList,
+            GetDeviceFriendlyName,
+            GetDeviceDescription,
+            GetDeviceManufacturer,
+            GetDeviceProperty,
+            GetPrivateDevices,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPortableDevicePropVariantCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelems: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevicePropVariantCollection", "GetCount", 3usize, |this| ((**(this as *const *const IPortableDevicePropVariantCollection_Vtbl)).GetCount)(this, pcelems), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, dwindex: u32, pvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevicePropVariantCollection", "GetAt", 4usize, |this| ((**(this as *const *const IPortableDevicePropVariantCollection_Vtbl)).GetAt)(this, dwindex, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevicePropVariantCollection", "Add", 5usize, |this| ((**(this as *const *const IPortableDevicePropVariantCollection_Vtbl)).Add)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pvt: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevicePropVariantCollection", "GetType", 6usize, |this| ((**(this as *const *const IPortableDevicePropVariantCollection_Vtbl)).GetType)(this, pvt), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeType(this: *mut core::ffi::c_void, vt: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevicePropVariantCollection", "ChangeType", 7usize, |this| ((**(this as *const *const IPortableDevicePropVariantCollection_Vtbl)).ChangeType)(this, vt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevicePropVariantCollection", "Clear", 8usize, |this| ((**(this as *const *const IPortableDevicePropVariantCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, dwindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevicePropVariantCollection", "RemoveAt", 9usize, |this| ((**(this as *const *const IPortableDevicePropVariantCollection_Vtbl)).RemoveAt)(this, dwindex), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt, Add, GetType, ChangeType, Clear, RemoveAt }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDevicePropVariantCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IPortableDeviceProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSupportedProperties(this: *mut core::ffi::c_void, pszobjectid: windows_core::PCWSTR, ppkeys: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceProperties", "GetSupportedProperties", 3usize, |this| ((**(this as *const *const IPortableDeviceProperties_Vtbl)).GetSupportedProperties)(this, pszobjectid, ppkeys), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetPropertyAttributes(this: *mut core::ffi::c_void, pszobjectid: windows_core::PCWSTR, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceProperties", "GetPropertyAttributes", 4usize, |this| ((**(this as *const *const IPortableDeviceProperties_Vtbl)).GetPropertyAttributes)(this, pszobjectid, key, ppattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValues(this: *mut core::ffi::c_void, pszobjectid: windows_core::PCWSTR, pkeys: *mut core::ffi::c_void, ppvalues: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceProperties", "GetValues", 5usize, |this| ((**(this as *const *const IPortableDeviceProperties_Vtbl)).GetValues)(this, pszobjectid, pkeys, ppvalues), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValues(this: *mut core::ffi::c_void, pszobjectid: windows_core::PCWSTR, pvalues: *mut core::ffi::c_void, ppresults: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceProperties", "SetValues", 6usize, |this| ((**(this as *const *const IPortableDeviceProperties_Vtbl)).SetValues)(this, pszobjectid, pvalues, ppresults), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, pszobjectid: windows_core::PCWSTR, pkeys: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceProperties", "Delete", 7usize, |this| ((**(this as *const *const IPortableDeviceProperties_Vtbl)).Delete)(this, pszobjectid, pkeys), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceProperties", "Cancel", 8usize, |this| ((**(this as *const *const IPortableDeviceProperties_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSupportedProperties,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetPropertyAttributes,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetPropertyAttributes: 0,
+            GetValues,
+            SetValues,
+            Delete,
+            Cancel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPortableDevicePropertiesBulk_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueueGetValuesByObjectList(this: *mut core::ffi::c_void, pobjectids: *mut core::ffi::c_void, pkeys: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pcontext: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevicePropertiesBulk", "QueueGetValuesByObjectList", 3usize, |this| ((**(this as *const *const IPortableDevicePropertiesBulk_Vtbl)).QueueGetValuesByObjectList)(this, pobjectids, pkeys, pcallback, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueueGetValuesByObjectFormat(this: *mut core::ffi::c_void, pguidobjectformat: *const windows_core::GUID, pszparentobjectid: windows_core::PCWSTR, dwdepth: u32, pkeys: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pcontext: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevicePropertiesBulk", "QueueGetValuesByObjectFormat", 4usize, |this| ((**(this as *const *const IPortableDevicePropertiesBulk_Vtbl)).QueueGetValuesByObjectFormat)(this, pguidobjectformat, pszparentobjectid, dwdepth, pkeys, pcallback, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueueSetValuesByObjectList(this: *mut core::ffi::c_void, pobjectvalues: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pcontext: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevicePropertiesBulk", "QueueSetValuesByObjectList", 5usize, |this| ((**(this as *const *const IPortableDevicePropertiesBulk_Vtbl)).QueueSetValuesByObjectList)(this, pobjectvalues, pcallback, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, pcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevicePropertiesBulk", "Start", 6usize, |this| ((**(this as *const *const IPortableDevicePropertiesBulk_Vtbl)).Start)(this, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void, pcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevicePropertiesBulk", "Cancel", 7usize, |this| ((**(this as *const *const IPortableDevicePropertiesBulk_Vtbl)).Cancel)(this, pcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueueGetValuesByObjectList,
+            QueueGetValuesByObjectFormat,
+            QueueSetValuesByObjectList,
+            Start,
+            Cancel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDevicePropertiesBulk as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPortableDevicePropertiesBulkCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStart(this: *mut core::ffi::c_void, pcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevicePropertiesBulkCallback", "OnStart", 3usize, |this| ((**(this as *const *const IPortableDevicePropertiesBulkCallback_Vtbl)).OnStart)(this, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnProgress(this: *mut core::ffi::c_void, pcontext: *const windows_core::GUID, presults: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevicePropertiesBulkCallback", "OnProgress", 4usize, |this| ((**(this as *const *const IPortableDevicePropertiesBulkCallback_Vtbl)).OnProgress)(this, pcontext, presults), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEnd(this: *mut core::ffi::c_void, pcontext: *const windows_core::GUID, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDevicePropertiesBulkCallback", "OnEnd", 5usize, |this| ((**(this as *const *const IPortableDevicePropertiesBulkCallback_Vtbl)).OnEnd)(this, pcontext, hrstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnStart, OnProgress, OnEnd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDevicePropertiesBulkCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for IPortableDeviceResources_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSupportedResources(this: *mut core::ffi::c_void, pszobjectid: windows_core::PCWSTR, ppkeys: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceResources", "GetSupportedResources", 3usize, |this| ((**(this as *const *const IPortableDeviceResources_Vtbl)).GetSupportedResources)(this, pszobjectid, ppkeys), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetResourceAttributes(this: *mut core::ffi::c_void, pszobjectid: windows_core::PCWSTR, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppresourceattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceResources", "GetResourceAttributes", 4usize, |this| ((**(this as *const *const IPortableDeviceResources_Vtbl)).GetResourceAttributes)(this, pszobjectid, key, ppresourceattributes), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, pszobjectid: windows_core::PCWSTR, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, dwmode: u32, pdwoptimalbuffersize: *mut u32, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceResources", "GetStream", 5usize, |this| ((**(this as *const *const IPortableDeviceResources_Vtbl)).GetStream)(this, pszobjectid, key, dwmode, pdwoptimalbuffersize, ppstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, pszobjectid: windows_core::PCWSTR, pkeys: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceResources", "Delete", 6usize, |this| ((**(this as *const *const IPortableDeviceResources_Vtbl)).Delete)(this, pszobjectid, pkeys), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceResources", "Cancel", 7usize, |this| ((**(this as *const *const IPortableDeviceResources_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateResource(this: *mut core::ffi::c_void, presourceattributes: *mut core::ffi::c_void, ppdata: *mut *mut core::ffi::c_void, pdwoptimalwritebuffersize: *mut u32, ppszcookie: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceResources", "CreateResource", 8usize, |this| ((**(this as *const *const IPortableDeviceResources_Vtbl)).CreateResource)(this, presourceattributes, ppdata, pdwoptimalwritebuffersize, ppszcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSupportedResources,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetResourceAttributes,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetResourceAttributes: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetStream,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            GetStream: 0,
+            Delete,
+            Cancel,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateResource,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateResource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceResources as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPortableDeviceService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, pszpnpserviceid: windows_core::PCWSTR, pclientinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceService", "Open", 3usize, |this| ((**(this as *const *const IPortableDeviceService_Vtbl)).Open)(this, pszpnpserviceid, pclientinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Capabilities(this: *mut core::ffi::c_void, ppcapabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceService", "Capabilities", 4usize, |this| ((**(this as *const *const IPortableDeviceService_Vtbl)).Capabilities)(this, ppcapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, ppcontent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceService", "Content", 5usize, |this| ((**(this as *const *const IPortableDeviceService_Vtbl)).Content)(this, ppcontent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Methods(this: *mut core::ffi::c_void, ppmethods: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceService", "Methods", 6usize, |this| ((**(this as *const *const IPortableDeviceService_Vtbl)).Methods)(this, ppmethods), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceService", "Cancel", 7usize, |this| ((**(this as *const *const IPortableDeviceService_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceService", "Close", 8usize, |this| ((**(this as *const *const IPortableDeviceService_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceObjectID(this: *mut core::ffi::c_void, ppszserviceobjectid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceService", "GetServiceObjectID", 9usize, |this| ((**(this as *const *const IPortableDeviceService_Vtbl)).GetServiceObjectID)(this, ppszserviceobjectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPnPServiceID(this: *mut core::ffi::c_void, ppszpnpserviceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceService", "GetPnPServiceID", 10usize, |this| ((**(this as *const *const IPortableDeviceService_Vtbl)).GetPnPServiceID)(this, ppszpnpserviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, dwflags: u32, pcallback: *mut core::ffi::c_void, pparameters: *mut core::ffi::c_void, ppszcookie: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceService", "Advise", 11usize, |this| ((**(this as *const *const IPortableDeviceService_Vtbl)).Advise)(this, dwflags, pcallback, pparameters, ppszcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, pszcookie: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceService", "Unadvise", 12usize, |this| ((**(this as *const *const IPortableDeviceService_Vtbl)).Unadvise)(this, pszcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendCommand(this: *mut core::ffi::c_void, dwflags: u32, pparameters: *mut core::ffi::c_void, ppresults: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceService", "SendCommand", 13usize, |this| ((**(this as *const *const IPortableDeviceService_Vtbl)).SendCommand)(this, dwflags, pparameters, ppresults), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Open,
+            Capabilities,
+            Content,
+            Methods,
+            Cancel,
+            Close,
+            GetServiceObjectID,
+            GetPnPServiceID,
+            Advise,
+            Unadvise,
+            SendCommand,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPortableDeviceServiceActivation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenAsync(this: *mut core::ffi::c_void, pszpnpserviceid: windows_core::PCWSTR, pclientinfo: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceActivation", "OpenAsync", 3usize, |this| ((**(this as *const *const IPortableDeviceServiceActivation_Vtbl)).OpenAsync)(this, pszpnpserviceid, pclientinfo, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelOpenAsync(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceActivation", "CancelOpenAsync", 4usize, |this| ((**(this as *const *const IPortableDeviceServiceActivation_Vtbl)).CancelOpenAsync)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenAsync, CancelOpenAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceServiceActivation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IPortableDeviceServiceCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSupportedMethods(this: *mut core::ffi::c_void, ppmethods: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "GetSupportedMethods", 3usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).GetSupportedMethods)(this, ppmethods), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedMethodsByFormat(this: *mut core::ffi::c_void, format: *const windows_core::GUID, ppmethods: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "GetSupportedMethodsByFormat", 4usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).GetSupportedMethodsByFormat)(this, format, ppmethods), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMethodAttributes(this: *mut core::ffi::c_void, method: *const windows_core::GUID, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "GetMethodAttributes", 5usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).GetMethodAttributes)(this, method, ppattributes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetMethodParameterAttributes(this: *mut core::ffi::c_void, method: *const windows_core::GUID, parameter: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "GetMethodParameterAttributes", 6usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).GetMethodParameterAttributes)(this, method, parameter, ppattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedFormats(this: *mut core::ffi::c_void, ppformats: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "GetSupportedFormats", 7usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).GetSupportedFormats)(this, ppformats), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormatAttributes(this: *mut core::ffi::c_void, format: *const windows_core::GUID, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "GetFormatAttributes", 8usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).GetFormatAttributes)(this, format, ppattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedFormatProperties(this: *mut core::ffi::c_void, format: *const windows_core::GUID, ppkeys: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "GetSupportedFormatProperties", 9usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).GetSupportedFormatProperties)(this, format, ppkeys), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetFormatPropertyAttributes(this: *mut core::ffi::c_void, format: *const windows_core::GUID, property: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "GetFormatPropertyAttributes", 10usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).GetFormatPropertyAttributes)(this, format, property, ppattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedEvents(this: *mut core::ffi::c_void, ppevents: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "GetSupportedEvents", 11usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).GetSupportedEvents)(this, ppevents), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventAttributes(this: *mut core::ffi::c_void, event: *const windows_core::GUID, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "GetEventAttributes", 12usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).GetEventAttributes)(this, event, ppattributes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetEventParameterAttributes(this: *mut core::ffi::c_void, event: *const windows_core::GUID, parameter: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "GetEventParameterAttributes", 13usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).GetEventParameterAttributes)(this, event, parameter, ppattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInheritedServices(this: *mut core::ffi::c_void, dwinheritancetype: u32, ppservices: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "GetInheritedServices", 14usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).GetInheritedServices)(this, dwinheritancetype, ppservices), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormatRenderingProfiles(this: *mut core::ffi::c_void, format: *const windows_core::GUID, pprenderingprofiles: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "GetFormatRenderingProfiles", 15usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).GetFormatRenderingProfiles)(this, format, pprenderingprofiles), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedCommands(this: *mut core::ffi::c_void, ppcommands: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "GetSupportedCommands", 16usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).GetSupportedCommands)(this, ppcommands), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetCommandOptions(this: *mut core::ffi::c_void, command: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "GetCommandOptions", 17usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).GetCommandOptions)(this, command, ppoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceCapabilities", "Cancel", 18usize, |this| ((**(this as *const *const IPortableDeviceServiceCapabilities_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSupportedMethods,
+            GetSupportedMethodsByFormat,
+            GetMethodAttributes,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetMethodParameterAttributes,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetMethodParameterAttributes: 0,
+            GetSupportedFormats,
+            GetFormatAttributes,
+            GetSupportedFormatProperties,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetFormatPropertyAttributes,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetFormatPropertyAttributes: 0,
+            GetSupportedEvents,
+            GetEventAttributes,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetEventParameterAttributes,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetEventParameterAttributes: 0,
+            GetInheritedServices,
+            GetFormatRenderingProfiles,
+            GetSupportedCommands,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetCommandOptions,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetCommandOptions: 0,
+            Cancel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceServiceCapabilities as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPortableDeviceServiceManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceServices(this: *mut core::ffi::c_void, pszpnpdeviceid: windows_core::PCWSTR, guidservicecategory: *const windows_core::GUID, pservices: *mut windows_core::PWSTR, pcservices: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceManager", "GetDeviceServices", 3usize, |this| ((**(this as *const *const IPortableDeviceServiceManager_Vtbl)).GetDeviceServices)(this, pszpnpdeviceid, guidservicecategory, pservices, pcservices), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceForService(this: *mut core::ffi::c_void, pszpnpserviceid: windows_core::PCWSTR, ppszpnpdeviceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceManager", "GetDeviceForService", 4usize, |this| ((**(this as *const *const IPortableDeviceServiceManager_Vtbl)).GetDeviceForService)(this, pszpnpserviceid, ppszpnpdeviceid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceServices, GetDeviceForService }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceServiceManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPortableDeviceServiceMethodCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnComplete(this: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT, presults: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceMethodCallback", "OnComplete", 3usize, |this| ((**(this as *const *const IPortableDeviceServiceMethodCallback_Vtbl)).OnComplete)(this, hrstatus, presults), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceServiceMethodCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPortableDeviceServiceMethods_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, method: *const windows_core::GUID, pparameters: *mut core::ffi::c_void, ppresults: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceMethods", "Invoke", 3usize, |this| ((**(this as *const *const IPortableDeviceServiceMethods_Vtbl)).Invoke)(this, method, pparameters, ppresults), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvokeAsync(this: *mut core::ffi::c_void, method: *const windows_core::GUID, pparameters: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceMethods", "InvokeAsync", 4usize, |this| ((**(this as *const *const IPortableDeviceServiceMethods_Vtbl)).InvokeAsync)(this, method, pparameters, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceMethods", "Cancel", 5usize, |this| ((**(this as *const *const IPortableDeviceServiceMethods_Vtbl)).Cancel)(this, pcallback), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Invoke, InvokeAsync, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceServiceMethods as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPortableDeviceServiceOpenCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnComplete(this: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceServiceOpenCallback", "OnComplete", 3usize, |this| ((**(this as *const *const IPortableDeviceServiceOpenCallback_Vtbl)).OnComplete)(this, hrstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceServiceOpenCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPortableDeviceUnitsStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SeekInUnits(this: *mut core::ffi::c_void, dlibmove: i64, units: WPD_STREAM_UNITS, dworigin: u32, plibnewposition: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceUnitsStream", "SeekInUnits", 3usize, |this| ((**(this as *const *const IPortableDeviceUnitsStream_Vtbl)).SeekInUnits)(this, dlibmove, units, dworigin, plibnewposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceUnitsStream", "Cancel", 4usize, |this| ((**(this as *const *const IPortableDeviceUnitsStream_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SeekInUnits, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceUnitsStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IPortableDeviceValues_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelt: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetCount", 3usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetCount)(this, pcelt), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, pkey: *mut super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetAt", 4usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetAt)(this, index, pkey, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetValue", 5usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetValue", 6usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetStringValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, value: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetStringValue", 7usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetStringValue)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetStringValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetStringValue", 8usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetStringValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetUnsignedIntegerValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetUnsignedIntegerValue", 9usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetUnsignedIntegerValue)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetUnsignedIntegerValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetUnsignedIntegerValue", 10usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetUnsignedIntegerValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetSignedIntegerValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetSignedIntegerValue", 11usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetSignedIntegerValue)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetSignedIntegerValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetSignedIntegerValue", 12usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetSignedIntegerValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetUnsignedLargeIntegerValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetUnsignedLargeIntegerValue", 13usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetUnsignedLargeIntegerValue)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetUnsignedLargeIntegerValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetUnsignedLargeIntegerValue", 14usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetUnsignedLargeIntegerValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetSignedLargeIntegerValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, value: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetSignedLargeIntegerValue", 15usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetSignedLargeIntegerValue)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetSignedLargeIntegerValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetSignedLargeIntegerValue", 16usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetSignedLargeIntegerValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetFloatValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetFloatValue", 17usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetFloatValue)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetFloatValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetFloatValue", 18usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetFloatValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetErrorValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, value: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetErrorValue", 19usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetErrorValue)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetErrorValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetErrorValue", 20usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetErrorValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetKeyValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, value: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetKeyValue", 21usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetKeyValue)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetKeyValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut super::super::UI::Shell::PropertiesSystem::PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetKeyValue", 22usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetKeyValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetBoolValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, value: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetBoolValue", 23usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetBoolValue)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetBoolValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetBoolValue", 24usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetBoolValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetIUnknownValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetIUnknownValue", 25usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetIUnknownValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetIUnknownValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetIUnknownValue", 26usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetIUnknownValue)(this, key, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetGuidValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, value: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetGuidValue", 27usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetGuidValue)(this, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetGuidValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetGuidValue", 28usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetGuidValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetBufferValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *const u8, cbvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetBufferValue", 29usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetBufferValue)(this, key, pvalue, cbvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetBufferValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppvalue: *mut *mut u8, pcbvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetBufferValue", 30usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetBufferValue)(this, key, ppvalue, pcbvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetIPortableDeviceValuesValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetIPortableDeviceValuesValue", 31usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetIPortableDeviceValuesValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetIPortableDeviceValuesValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetIPortableDeviceValuesValue", 32usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetIPortableDeviceValuesValue)(this, key, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetIPortableDevicePropVariantCollectionValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetIPortableDevicePropVariantCollectionValue", 33usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetIPortableDevicePropVariantCollectionValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetIPortableDevicePropVariantCollectionValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetIPortableDevicePropVariantCollectionValue", 34usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetIPortableDevicePropVariantCollectionValue)(this, key, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetIPortableDeviceKeyCollectionValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetIPortableDeviceKeyCollectionValue", 35usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetIPortableDeviceKeyCollectionValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetIPortableDeviceKeyCollectionValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetIPortableDeviceKeyCollectionValue", 36usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetIPortableDeviceKeyCollectionValue)(this, key, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetIPortableDeviceValuesCollectionValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "SetIPortableDeviceValuesCollectionValue", 37usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).SetIPortableDeviceValuesCollectionValue)(this, key, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetIPortableDeviceValuesCollectionValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "GetIPortableDeviceValuesCollectionValue", 38usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).GetIPortableDeviceValuesCollectionValue)(this, key, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn RemoveValue(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "RemoveValue", 39usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).RemoveValue)(this, key), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn CopyValuesFromPropertyStore(this: *mut core::ffi::c_void, pstore: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "CopyValuesFromPropertyStore", 40usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).CopyValuesFromPropertyStore)(this, pstore), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn CopyValuesToPropertyStore(this: *mut core::ffi::c_void, pstore: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "CopyValuesToPropertyStore", 41usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).CopyValuesToPropertyStore)(this, pstore), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValues", "Clear", 42usize, |this| ((**(this as *const *const IPortableDeviceValues_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetAt,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetAt: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetStringValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetStringValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetStringValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetStringValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetUnsignedIntegerValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetUnsignedIntegerValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetUnsignedIntegerValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetUnsignedIntegerValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetSignedIntegerValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetSignedIntegerValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetSignedIntegerValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetSignedIntegerValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetUnsignedLargeIntegerValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetUnsignedLargeIntegerValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetUnsignedLargeIntegerValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetUnsignedLargeIntegerValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetSignedLargeIntegerValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetSignedLargeIntegerValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetSignedLargeIntegerValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetSignedLargeIntegerValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetFloatValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetFloatValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetFloatValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetFloatValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetErrorValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetErrorValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetErrorValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetErrorValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetKeyValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetKeyValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetKeyValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetKeyValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetBoolValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetBoolValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetBoolValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetBoolValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetIUnknownValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetIUnknownValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetIUnknownValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetIUnknownValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetGuidValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetGuidValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetGuidValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetGuidValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetBufferValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetBufferValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetBufferValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetBufferValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetIPortableDeviceValuesValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetIPortableDeviceValuesValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetIPortableDeviceValuesValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetIPortableDeviceValuesValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetIPortableDevicePropVariantCollectionValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetIPortableDevicePropVariantCollectionValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetIPortableDevicePropVariantCollectionValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetIPortableDevicePropVariantCollectionValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetIPortableDeviceKeyCollectionValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetIPortableDeviceKeyCollectionValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetIPortableDeviceKeyCollectionValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetIPortableDeviceKeyCollectionValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetIPortableDeviceValuesCollectionValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetIPortableDeviceValuesCollectionValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetIPortableDeviceValuesCollectionValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetIPortableDeviceValuesCollectionValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            RemoveValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            RemoveValue: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            CopyValuesFromPropertyStore,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CopyValuesFromPropertyStore: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            CopyValuesToPropertyStore,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CopyValuesToPropertyStore: 0,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceValues as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPortableDeviceValuesCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelems: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValuesCollection", "GetCount", 3usize, |this| ((**(this as *const *const IPortableDeviceValuesCollection_Vtbl)).GetCount)(this, pcelems), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, dwindex: u32, ppvalues: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValuesCollection", "GetAt", 4usize, |this| ((**(this as *const *const IPortableDeviceValuesCollection_Vtbl)).GetAt)(this, dwindex, ppvalues), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pvalues: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValuesCollection", "Add", 5usize, |this| ((**(this as *const *const IPortableDeviceValuesCollection_Vtbl)).Add)(this, pvalues), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValuesCollection", "Clear", 6usize, |this| ((**(this as *const *const IPortableDeviceValuesCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, dwindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceValuesCollection", "RemoveAt", 7usize, |this| ((**(this as *const *const IPortableDeviceValuesCollection_Vtbl)).RemoveAt)(this, dwindex), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt, Add, Clear, RemoveAt }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceValuesCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPortableDeviceWebControl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDeviceFromId(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::BSTR>, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceWebControl", "GetDeviceFromId", 7usize, |this| ((**(this as *const *const IPortableDeviceWebControl_Vtbl)).GetDeviceFromId)(this, deviceid, ppdevice), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDeviceFromIdAsync(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::BSTR>, pcompletionhandler: *mut core::ffi::c_void, perrorhandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPortableDeviceWebControl", "GetDeviceFromIdAsync", 8usize, |this| ((**(this as *const *const IPortableDeviceWebControl_Vtbl)).GetDeviceFromIdAsync)(this, deviceid, pcompletionhandler, perrorhandler), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDeviceFromId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDeviceFromId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDeviceFromIdAsync,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDeviceFromIdAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPortableDeviceWebControl as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadioInstance_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRadioManagerSignature(this: *mut core::ffi::c_void, pguidsignature: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadioInstance", "GetRadioManagerSignature", 3usize, |this| ((**(this as *const *const IRadioInstance_Vtbl)).GetRadioManagerSignature)(this, pguidsignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInstanceSignature(this: *mut core::ffi::c_void, pbstrid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadioInstance", "GetInstanceSignature", 4usize, |this| ((**(this as *const *const IRadioInstance_Vtbl)).GetInstanceSignature)(this, pbstrid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFriendlyName(this: *mut core::ffi::c_void, lcid: u32, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadioInstance", "GetFriendlyName", 5usize, |this| ((**(this as *const *const IRadioInstance_Vtbl)).GetFriendlyName)(this, lcid, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRadioState(this: *mut core::ffi::c_void, pradiostate: *mut DEVICE_RADIO_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadioInstance", "GetRadioState", 6usize, |this| ((**(this as *const *const IRadioInstance_Vtbl)).GetRadioState)(this, pradiostate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRadioState(this: *mut core::ffi::c_void, radiostate: DEVICE_RADIO_STATE, utimeoutsec: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadioInstance", "SetRadioState", 7usize, |this| ((**(this as *const *const IRadioInstance_Vtbl)).SetRadioState)(this, radiostate, utimeoutsec), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMultiComm(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IRadioInstance", "IsMultiComm", 8usize, |this| ((**(this as *const *const IRadioInstance_Vtbl)).IsMultiComm)(this), |_| None)
+        }
+        unsafe extern "system" fn IsAssociatingDevice(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IRadioInstance", "IsAssociatingDevice", 9usize, |this| ((**(this as *const *const IRadioInstance_Vtbl)).IsAssociatingDevice)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetRadioManagerSignature,
+            GetInstanceSignature,
+            GetFriendlyName,
+            GetRadioState,
+            SetRadioState,
+            IsMultiComm,
+            IsAssociatingDevice,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadioInstance as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadioInstanceCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcinstance: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadioInstanceCollection", "GetCount", 3usize, |this| ((**(this as *const *const IRadioInstanceCollection_Vtbl)).GetCount)(this, pcinstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, uindex: u32, ppradioinstance: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadioInstanceCollection", "GetAt", 4usize, |this| ((**(this as *const *const IRadioInstanceCollection_Vtbl)).GetAt)(this, uindex, ppradioinstance), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadioInstanceCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWpdSerializer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIPortableDeviceValuesFromBuffer(this: *mut core::ffi::c_void, pbuffer: *const u8, dwinputbufferlength: u32, ppparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWpdSerializer", "GetIPortableDeviceValuesFromBuffer", 3usize, |this| ((**(this as *const *const IWpdSerializer_Vtbl)).GetIPortableDeviceValuesFromBuffer)(this, pbuffer, dwinputbufferlength, ppparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteIPortableDeviceValuesToBuffer(this: *mut core::ffi::c_void, dwoutputbufferlength: u32, presults: *mut core::ffi::c_void, pbuffer: *mut u8, pdwbyteswritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWpdSerializer", "WriteIPortableDeviceValuesToBuffer", 4usize, |this| ((**(this as *const *const IWpdSerializer_Vtbl)).WriteIPortableDeviceValuesToBuffer)(this, dwoutputbufferlength, presults, pbuffer, pdwbyteswritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferFromIPortableDeviceValues(this: *mut core::ffi::c_void, psource: *mut core::ffi::c_void, ppbuffer: *mut *mut u8, pdwbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWpdSerializer", "GetBufferFromIPortableDeviceValues", 5usize, |this| ((**(this as *const *const IWpdSerializer_Vtbl)).GetBufferFromIPortableDeviceValues)(this, psource, ppbuffer, pdwbuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSerializedSize(this: *mut core::ffi::c_void, psource: *mut core::ffi::c_void, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWpdSerializer", "GetSerializedSize", 6usize, |this| ((**(this as *const *const IWpdSerializer_Vtbl)).GetSerializedSize)(this, psource, pdwsize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetIPortableDeviceValuesFromBuffer,
+            WriteIPortableDeviceValuesToBuffer,
+            GetBufferFromIPortableDeviceValues,
+            GetSerializedSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWpdSerializer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1098,6 +1098,7 @@ pub struct ACTIVITY_STATE(pub i32);
 impl windows_core::TypeKind for ACTIVITY_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACTIVITY_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACTIVITY_STATE").field(&self.0).finish()
@@ -1109,6 +1110,7 @@ pub struct ACTIVITY_STATE_COUNT(pub i32);
 impl windows_core::TypeKind for ACTIVITY_STATE_COUNT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACTIVITY_STATE_COUNT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACTIVITY_STATE_COUNT").field(&self.0).finish()
@@ -1120,6 +1122,7 @@ pub struct AXIS(pub i32);
 impl windows_core::TypeKind for AXIS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AXIS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AXIS").field(&self.0).finish()
@@ -1131,6 +1134,7 @@ pub struct ELEVATION_CHANGE_MODE(pub i32);
 impl windows_core::TypeKind for ELEVATION_CHANGE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ELEVATION_CHANGE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ELEVATION_CHANGE_MODE").field(&self.0).finish()
@@ -1142,6 +1146,7 @@ pub struct HUMAN_PRESENCE_DETECTION_TYPE(pub i32);
 impl windows_core::TypeKind for HUMAN_PRESENCE_DETECTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HUMAN_PRESENCE_DETECTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HUMAN_PRESENCE_DETECTION_TYPE").field(&self.0).finish()
@@ -1153,6 +1158,7 @@ pub struct HUMAN_PRESENCE_DETECTION_TYPE_COUNT(pub i32);
 impl windows_core::TypeKind for HUMAN_PRESENCE_DETECTION_TYPE_COUNT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HUMAN_PRESENCE_DETECTION_TYPE_COUNT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HUMAN_PRESENCE_DETECTION_TYPE_COUNT").field(&self.0).finish()
@@ -1164,6 +1170,7 @@ pub struct LOCATION_DESIRED_ACCURACY(pub i32);
 impl windows_core::TypeKind for LOCATION_DESIRED_ACCURACY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LOCATION_DESIRED_ACCURACY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LOCATION_DESIRED_ACCURACY").field(&self.0).finish()
@@ -1175,6 +1182,7 @@ pub struct LOCATION_POSITION_SOURCE(pub i32);
 impl windows_core::TypeKind for LOCATION_POSITION_SOURCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LOCATION_POSITION_SOURCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LOCATION_POSITION_SOURCE").field(&self.0).finish()
@@ -1186,6 +1194,7 @@ pub struct MAGNETOMETER_ACCURACY(pub i32);
 impl windows_core::TypeKind for MAGNETOMETER_ACCURACY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MAGNETOMETER_ACCURACY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MAGNETOMETER_ACCURACY").field(&self.0).finish()
@@ -1197,6 +1206,7 @@ pub struct MagnetometerAccuracy(pub i32);
 impl windows_core::TypeKind for MagnetometerAccuracy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MagnetometerAccuracy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MagnetometerAccuracy").field(&self.0).finish()
@@ -1208,6 +1218,7 @@ pub struct PEDOMETER_STEP_TYPE(pub i32);
 impl windows_core::TypeKind for PEDOMETER_STEP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEDOMETER_STEP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEDOMETER_STEP_TYPE").field(&self.0).finish()
@@ -1219,6 +1230,7 @@ pub struct PEDOMETER_STEP_TYPE_COUNT(pub i32);
 impl windows_core::TypeKind for PEDOMETER_STEP_TYPE_COUNT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEDOMETER_STEP_TYPE_COUNT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEDOMETER_STEP_TYPE_COUNT").field(&self.0).finish()
@@ -1230,6 +1242,7 @@ pub struct PROXIMITY_SENSOR_CAPABILITIES(pub i32);
 impl windows_core::TypeKind for PROXIMITY_SENSOR_CAPABILITIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROXIMITY_SENSOR_CAPABILITIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROXIMITY_SENSOR_CAPABILITIES").field(&self.0).finish()
@@ -1241,6 +1254,7 @@ pub struct PROXIMITY_TYPE(pub i32);
 impl windows_core::TypeKind for PROXIMITY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROXIMITY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROXIMITY_TYPE").field(&self.0).finish()
@@ -1252,6 +1266,7 @@ pub struct SENSOR_CONNECTION_TYPES(pub i32);
 impl windows_core::TypeKind for SENSOR_CONNECTION_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SENSOR_CONNECTION_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SENSOR_CONNECTION_TYPES").field(&self.0).finish()
@@ -1263,6 +1278,7 @@ pub struct SENSOR_STATE(pub i32);
 impl windows_core::TypeKind for SENSOR_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SENSOR_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SENSOR_STATE").field(&self.0).finish()
@@ -1274,6 +1290,7 @@ pub struct SIMPLE_DEVICE_ORIENTATION(pub i32);
 impl windows_core::TypeKind for SIMPLE_DEVICE_ORIENTATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SIMPLE_DEVICE_ORIENTATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SIMPLE_DEVICE_ORIENTATION").field(&self.0).finish()
@@ -1285,6 +1302,7 @@ pub struct SensorConnectionType(pub i32);
 impl windows_core::TypeKind for SensorConnectionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SensorConnectionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SensorConnectionType").field(&self.0).finish()
@@ -1296,6 +1314,7 @@ pub struct SensorState(pub i32);
 impl windows_core::TypeKind for SensorState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SensorState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SensorState").field(&self.0).finish()
@@ -1307,6 +1326,7 @@ pub struct SimpleDeviceOrientation(pub i32);
 impl windows_core::TypeKind for SimpleDeviceOrientation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SimpleDeviceOrientation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SimpleDeviceOrientation").field(&self.0).finish()
@@ -1341,7 +1361,8 @@ impl Default for MATRIX3X3_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MATRIX3X3_0_0 {
     pub A11: f32,
     pub A12: f32,
@@ -1362,7 +1383,8 @@ impl Default for MATRIX3X3_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MATRIX3X3_0_1 {
     pub V1: VEC3D,
     pub V2: VEC3D,
@@ -1377,7 +1399,8 @@ impl Default for MATRIX3X3_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct QUATERNION {
     pub X: f32,
     pub Y: f32,
@@ -1394,7 +1417,8 @@ impl Default for QUATERNION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SENSOR_COLLECTION_LIST {
     pub AllocatedSizeInBytes: u32,
     pub Count: u32,
@@ -1418,7 +1442,8 @@ impl Default for SENSOR_COLLECTION_LIST {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SENSOR_PROPERTY_LIST {
     pub AllocatedSizeInBytes: u32,
     pub Count: u32,
@@ -1436,7 +1461,8 @@ impl Default for SENSOR_PROPERTY_LIST {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SENSOR_VALUE_PAIR {
     pub Key: super::super::UI::Shell::PropertiesSystem::PROPERTYKEY,
     pub Value: core::mem::ManuallyDrop<windows_core::PROPVARIANT>,
@@ -1462,7 +1488,8 @@ pub const SensorCollection: windows_core::GUID = windows_core::GUID::from_u128(0
 pub const SensorDataReport: windows_core::GUID = windows_core::GUID::from_u128(0x4ea9d6ef_694b_4218_8816_ccda8da74bba);
 pub const SensorManager: windows_core::GUID = windows_core::GUID::from_u128(0x77a1c827_fcd2_4689_8915_9d613cc5fa3e);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VEC3D {
     pub X: f32,
     pub Y: f32,
@@ -1478,3 +1505,5 @@ impl Default for VEC3D {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,241 @@
+impl windows_core::imp::ProxyVtable for ILocationPermissions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGlobalLocationPermission(this: *mut core::ffi::c_void, pfenabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationPermissions", "GetGlobalLocationPermission", 3usize, |this| ((**(this as *const *const ILocationPermissions_Vtbl)).GetGlobalLocationPermission)(this, pfenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckLocationCapability(this: *mut core::ffi::c_void, dwclientthreadid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocationPermissions", "CheckLocationCapability", 4usize, |this| ((**(this as *const *const ILocationPermissions_Vtbl)).CheckLocationCapability)(this, dwclientthreadid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetGlobalLocationPermission, CheckLocationCapability }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocationPermissions as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Devices_PortableDevices", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for ISensor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetID(this: *mut core::ffi::c_void, pid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensor", "GetID", 3usize, |this| ((**(this as *const *const ISensor_Vtbl)).GetID)(this, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCategory(this: *mut core::ffi::c_void, psensorcategory: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensor", "GetCategory", 4usize, |this| ((**(this as *const *const ISensor_Vtbl)).GetCategory)(this, psensorcategory), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, psensortype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensor", "GetType", 5usize, |this| ((**(this as *const *const ISensor_Vtbl)).GetType)(this, psensortype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFriendlyName(this: *mut core::ffi::c_void, pfriendlyname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensor", "GetFriendlyName", 6usize, |this| ((**(this as *const *const ISensor_Vtbl)).GetFriendlyName)(this, pfriendlyname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pproperty: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensor", "GetProperty", 7usize, |this| ((**(this as *const *const ISensor_Vtbl)).GetProperty)(this, key, pproperty), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Devices_PortableDevices")]
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pkeys: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensor", "GetProperties", 8usize, |this| ((**(this as *const *const ISensor_Vtbl)).GetProperties)(this, pkeys, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Devices_PortableDevices")]
+        unsafe extern "system" fn GetSupportedDataFields(this: *mut core::ffi::c_void, ppdatafields: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensor", "GetSupportedDataFields", 9usize, |this| ((**(this as *const *const ISensor_Vtbl)).GetSupportedDataFields)(this, ppdatafields), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Devices_PortableDevices")]
+        unsafe extern "system" fn SetProperties(this: *mut core::ffi::c_void, pproperties: *mut core::ffi::c_void, ppresults: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensor", "SetProperties", 10usize, |this| ((**(this as *const *const ISensor_Vtbl)).SetProperties)(this, pproperties, ppresults), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SupportsDataField(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pissupported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensor", "SupportsDataField", 11usize, |this| ((**(this as *const *const ISensor_Vtbl)).SupportsDataField)(this, key, pissupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, pstate: *mut SensorState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensor", "GetState", 12usize, |this| ((**(this as *const *const ISensor_Vtbl)).GetState)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, ppdatareport: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensor", "GetData", 13usize, |this| ((**(this as *const *const ISensor_Vtbl)).GetData)(this, ppdatareport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportsEvent(this: *mut core::ffi::c_void, eventguid: *const windows_core::GUID, pissupported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensor", "SupportsEvent", 14usize, |this| ((**(this as *const *const ISensor_Vtbl)).SupportsEvent)(this, eventguid, pissupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventInterest(this: *mut core::ffi::c_void, ppvalues: *mut *mut windows_core::GUID, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensor", "GetEventInterest", 15usize, |this| ((**(this as *const *const ISensor_Vtbl)).GetEventInterest)(this, ppvalues, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventInterest(this: *mut core::ffi::c_void, pvalues: *const windows_core::GUID, count: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensor", "SetEventInterest", 16usize, |this| ((**(this as *const *const ISensor_Vtbl)).SetEventInterest)(this, pvalues, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventSink(this: *mut core::ffi::c_void, pevents: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensor", "SetEventSink", 17usize, |this| ((**(this as *const *const ISensor_Vtbl)).SetEventSink)(this, pevents), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetID,
+            GetCategory,
+            GetType,
+            GetFriendlyName,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetProperty,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetProperty: 0,
+            #[cfg(feature = "Win32_Devices_PortableDevices")]
+            GetProperties,
+            #[cfg(not(feature = "Win32_Devices_PortableDevices"))]
+            GetProperties: 0,
+            #[cfg(feature = "Win32_Devices_PortableDevices")]
+            GetSupportedDataFields,
+            #[cfg(not(feature = "Win32_Devices_PortableDevices"))]
+            GetSupportedDataFields: 0,
+            #[cfg(feature = "Win32_Devices_PortableDevices")]
+            SetProperties,
+            #[cfg(not(feature = "Win32_Devices_PortableDevices"))]
+            SetProperties: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SupportsDataField,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SupportsDataField: 0,
+            GetState,
+            GetData,
+            SupportsEvent,
+            GetEventInterest,
+            SetEventInterest,
+            SetEventSink,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISensorCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, ulindex: u32, ppsensor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorCollection", "GetAt", 3usize, |this| ((**(this as *const *const ISensorCollection_Vtbl)).GetAt)(this, ulindex, ppsensor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorCollection", "GetCount", 4usize, |this| ((**(this as *const *const ISensorCollection_Vtbl)).GetCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, psensor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorCollection", "Add", 5usize, |this| ((**(this as *const *const ISensorCollection_Vtbl)).Add)(this, psensor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, psensor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorCollection", "Remove", 6usize, |this| ((**(this as *const *const ISensorCollection_Vtbl)).Remove)(this, psensor), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveByID(this: *mut core::ffi::c_void, sensorid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorCollection", "RemoveByID", 7usize, |this| ((**(this as *const *const ISensorCollection_Vtbl)).RemoveByID)(this, sensorid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorCollection", "Clear", 8usize, |this| ((**(this as *const *const ISensorCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAt, GetCount, Add, Remove, RemoveByID, Clear }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensorCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Devices_PortableDevices", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for ISensorDataReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTimestamp(this: *mut core::ffi::c_void, ptimestamp: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorDataReport", "GetTimestamp", 3usize, |this| ((**(this as *const *const ISensorDataReport_Vtbl)).GetTimestamp)(this, ptimestamp), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetSensorValue(this: *mut core::ffi::c_void, pkey: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorDataReport", "GetSensorValue", 4usize, |this| ((**(this as *const *const ISensorDataReport_Vtbl)).GetSensorValue)(this, pkey, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Devices_PortableDevices")]
+        unsafe extern "system" fn GetSensorValues(this: *mut core::ffi::c_void, pkeys: *mut core::ffi::c_void, ppvalues: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorDataReport", "GetSensorValues", 5usize, |this| ((**(this as *const *const ISensorDataReport_Vtbl)).GetSensorValues)(this, pkeys, ppvalues), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTimestamp,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetSensorValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetSensorValue: 0,
+            #[cfg(feature = "Win32_Devices_PortableDevices")]
+            GetSensorValues,
+            #[cfg(not(feature = "Win32_Devices_PortableDevices"))]
+            GetSensorValues: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensorDataReport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Devices_PortableDevices")]
+impl windows_core::imp::ProxyVtable for ISensorEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStateChanged(this: *mut core::ffi::c_void, psensor: *mut core::ffi::c_void, state: SensorState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorEvents", "OnStateChanged", 3usize, |this| ((**(this as *const *const ISensorEvents_Vtbl)).OnStateChanged)(this, psensor, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDataUpdated(this: *mut core::ffi::c_void, psensor: *mut core::ffi::c_void, pnewdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorEvents", "OnDataUpdated", 4usize, |this| ((**(this as *const *const ISensorEvents_Vtbl)).OnDataUpdated)(this, psensor, pnewdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Devices_PortableDevices")]
+        unsafe extern "system" fn OnEvent(this: *mut core::ffi::c_void, psensor: *mut core::ffi::c_void, eventid: *const windows_core::GUID, peventdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorEvents", "OnEvent", 5usize, |this| ((**(this as *const *const ISensorEvents_Vtbl)).OnEvent)(this, psensor, eventid, peventdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLeave(this: *mut core::ffi::c_void, id: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorEvents", "OnLeave", 6usize, |this| ((**(this as *const *const ISensorEvents_Vtbl)).OnLeave)(this, id), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnStateChanged,
+            OnDataUpdated,
+            #[cfg(feature = "Win32_Devices_PortableDevices")]
+            OnEvent,
+            #[cfg(not(feature = "Win32_Devices_PortableDevices"))]
+            OnEvent: 0,
+            OnLeave,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensorEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISensorManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSensorsByCategory(this: *mut core::ffi::c_void, sensorcategory: *const windows_core::GUID, ppsensorsfound: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorManager", "GetSensorsByCategory", 3usize, |this| ((**(this as *const *const ISensorManager_Vtbl)).GetSensorsByCategory)(this, sensorcategory, ppsensorsfound), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSensorsByType(this: *mut core::ffi::c_void, sensortype: *const windows_core::GUID, ppsensorsfound: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorManager", "GetSensorsByType", 4usize, |this| ((**(this as *const *const ISensorManager_Vtbl)).GetSensorsByType)(this, sensortype, ppsensorsfound), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSensorByID(this: *mut core::ffi::c_void, sensorid: *const windows_core::GUID, ppsensor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorManager", "GetSensorByID", 5usize, |this| ((**(this as *const *const ISensorManager_Vtbl)).GetSensorByID)(this, sensorid, ppsensor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventSink(this: *mut core::ffi::c_void, pevents: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorManager", "SetEventSink", 6usize, |this| ((**(this as *const *const ISensorManager_Vtbl)).SetEventSink)(this, pevents), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestPermissions(this: *mut core::ffi::c_void, hparent: super::super::Foundation::HWND, psensors: *mut core::ffi::c_void, fmodal: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorManager", "RequestPermissions", 7usize, |this| ((**(this as *const *const ISensorManager_Vtbl)).RequestPermissions)(this, hparent, psensors, fmodal), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSensorsByCategory,
+            GetSensorsByType,
+            GetSensorByID,
+            SetEventSink,
+            RequestPermissions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensorManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISensorManagerEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSensorEnter(this: *mut core::ffi::c_void, psensor: *mut core::ffi::c_void, state: SensorState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensorManagerEvents", "OnSensorEnter", 3usize, |this| ((**(this as *const *const ISensorManagerEvents_Vtbl)).OnSensorEnter)(this, psensor, state), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnSensorEnter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensorManagerEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
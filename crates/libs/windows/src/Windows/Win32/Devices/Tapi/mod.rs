@@ -9350,6 +9350,7 @@ pub struct ACDGROUP_EVENT(pub i32);
 impl windows_core::TypeKind for ACDGROUP_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACDGROUP_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACDGROUP_EVENT").field(&self.0).finish()
@@ -9361,6 +9362,7 @@ pub struct ACDQUEUE_EVENT(pub i32);
 impl windows_core::TypeKind for ACDQUEUE_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACDQUEUE_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACDQUEUE_EVENT").field(&self.0).finish()
@@ -9372,6 +9374,7 @@ pub struct ADDRESS_CAPABILITY(pub i32);
 impl windows_core::TypeKind for ADDRESS_CAPABILITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ADDRESS_CAPABILITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ADDRESS_CAPABILITY").field(&self.0).finish()
@@ -9383,6 +9386,7 @@ pub struct ADDRESS_CAPABILITY_STRING(pub i32);
 impl windows_core::TypeKind for ADDRESS_CAPABILITY_STRING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ADDRESS_CAPABILITY_STRING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ADDRESS_CAPABILITY_STRING").field(&self.0).finish()
@@ -9394,6 +9398,7 @@ pub struct ADDRESS_EVENT(pub i32);
 impl windows_core::TypeKind for ADDRESS_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ADDRESS_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ADDRESS_EVENT").field(&self.0).finish()
@@ -9405,6 +9410,7 @@ pub struct ADDRESS_STATE(pub i32);
 impl windows_core::TypeKind for ADDRESS_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ADDRESS_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ADDRESS_STATE").field(&self.0).finish()
@@ -9416,6 +9422,7 @@ pub struct AGENTHANDLER_EVENT(pub i32);
 impl windows_core::TypeKind for AGENTHANDLER_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AGENTHANDLER_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AGENTHANDLER_EVENT").field(&self.0).finish()
@@ -9427,6 +9434,7 @@ pub struct AGENT_EVENT(pub i32);
 impl windows_core::TypeKind for AGENT_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AGENT_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AGENT_EVENT").field(&self.0).finish()
@@ -9438,6 +9446,7 @@ pub struct AGENT_SESSION_EVENT(pub i32);
 impl windows_core::TypeKind for AGENT_SESSION_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AGENT_SESSION_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AGENT_SESSION_EVENT").field(&self.0).finish()
@@ -9449,6 +9458,7 @@ pub struct AGENT_SESSION_STATE(pub i32);
 impl windows_core::TypeKind for AGENT_SESSION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AGENT_SESSION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AGENT_SESSION_STATE").field(&self.0).finish()
@@ -9460,6 +9470,7 @@ pub struct AGENT_STATE(pub i32);
 impl windows_core::TypeKind for AGENT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AGENT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AGENT_STATE").field(&self.0).finish()
@@ -9471,6 +9482,7 @@ pub struct CALLHUB_EVENT(pub i32);
 impl windows_core::TypeKind for CALLHUB_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALLHUB_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALLHUB_EVENT").field(&self.0).finish()
@@ -9482,6 +9494,7 @@ pub struct CALLHUB_STATE(pub i32);
 impl windows_core::TypeKind for CALLHUB_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALLHUB_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALLHUB_STATE").field(&self.0).finish()
@@ -9493,6 +9506,7 @@ pub struct CALLINFOCHANGE_CAUSE(pub i32);
 impl windows_core::TypeKind for CALLINFOCHANGE_CAUSE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALLINFOCHANGE_CAUSE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALLINFOCHANGE_CAUSE").field(&self.0).finish()
@@ -9504,6 +9518,7 @@ pub struct CALLINFO_BUFFER(pub i32);
 impl windows_core::TypeKind for CALLINFO_BUFFER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALLINFO_BUFFER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALLINFO_BUFFER").field(&self.0).finish()
@@ -9515,6 +9530,7 @@ pub struct CALLINFO_LONG(pub i32);
 impl windows_core::TypeKind for CALLINFO_LONG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALLINFO_LONG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALLINFO_LONG").field(&self.0).finish()
@@ -9526,6 +9542,7 @@ pub struct CALLINFO_STRING(pub i32);
 impl windows_core::TypeKind for CALLINFO_STRING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALLINFO_STRING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALLINFO_STRING").field(&self.0).finish()
@@ -9537,6 +9554,7 @@ pub struct CALL_MEDIA_EVENT(pub i32);
 impl windows_core::TypeKind for CALL_MEDIA_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALL_MEDIA_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALL_MEDIA_EVENT").field(&self.0).finish()
@@ -9548,6 +9566,7 @@ pub struct CALL_MEDIA_EVENT_CAUSE(pub i32);
 impl windows_core::TypeKind for CALL_MEDIA_EVENT_CAUSE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALL_MEDIA_EVENT_CAUSE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALL_MEDIA_EVENT_CAUSE").field(&self.0).finish()
@@ -9559,6 +9578,7 @@ pub struct CALL_NOTIFICATION_EVENT(pub i32);
 impl windows_core::TypeKind for CALL_NOTIFICATION_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALL_NOTIFICATION_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALL_NOTIFICATION_EVENT").field(&self.0).finish()
@@ -9570,6 +9590,7 @@ pub struct CALL_PRIVILEGE(pub i32);
 impl windows_core::TypeKind for CALL_PRIVILEGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALL_PRIVILEGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALL_PRIVILEGE").field(&self.0).finish()
@@ -9581,6 +9602,7 @@ pub struct CALL_STATE(pub i32);
 impl windows_core::TypeKind for CALL_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALL_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALL_STATE").field(&self.0).finish()
@@ -9592,6 +9614,7 @@ pub struct CALL_STATE_EVENT_CAUSE(pub i32);
 impl windows_core::TypeKind for CALL_STATE_EVENT_CAUSE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALL_STATE_EVENT_CAUSE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALL_STATE_EVENT_CAUSE").field(&self.0).finish()
@@ -9603,6 +9626,7 @@ pub struct DIRECTORY_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for DIRECTORY_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTORY_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTORY_OBJECT_TYPE").field(&self.0).finish()
@@ -9614,6 +9638,7 @@ pub struct DIRECTORY_TYPE(pub i32);
 impl windows_core::TypeKind for DIRECTORY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTORY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTORY_TYPE").field(&self.0).finish()
@@ -9625,6 +9650,7 @@ pub struct DISCONNECT_CODE(pub i32);
 impl windows_core::TypeKind for DISCONNECT_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISCONNECT_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISCONNECT_CODE").field(&self.0).finish()
@@ -9636,6 +9662,7 @@ pub struct FINISH_MODE(pub i32);
 impl windows_core::TypeKind for FINISH_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FINISH_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FINISH_MODE").field(&self.0).finish()
@@ -9647,6 +9674,7 @@ pub struct FT_STATE_EVENT_CAUSE(pub i32);
 impl windows_core::TypeKind for FT_STATE_EVENT_CAUSE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FT_STATE_EVENT_CAUSE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FT_STATE_EVENT_CAUSE").field(&self.0).finish()
@@ -9658,6 +9686,7 @@ pub struct FULLDUPLEX_SUPPORT(pub i32);
 impl windows_core::TypeKind for FULLDUPLEX_SUPPORT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FULLDUPLEX_SUPPORT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FULLDUPLEX_SUPPORT").field(&self.0).finish()
@@ -9669,6 +9698,7 @@ pub struct MSP_ADDRESS_EVENT(pub i32);
 impl windows_core::TypeKind for MSP_ADDRESS_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSP_ADDRESS_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSP_ADDRESS_EVENT").field(&self.0).finish()
@@ -9680,6 +9710,7 @@ pub struct MSP_CALL_EVENT(pub i32);
 impl windows_core::TypeKind for MSP_CALL_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSP_CALL_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSP_CALL_EVENT").field(&self.0).finish()
@@ -9691,6 +9722,7 @@ pub struct MSP_CALL_EVENT_CAUSE(pub i32);
 impl windows_core::TypeKind for MSP_CALL_EVENT_CAUSE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSP_CALL_EVENT_CAUSE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSP_CALL_EVENT_CAUSE").field(&self.0).finish()
@@ -9702,6 +9734,7 @@ pub struct MSP_EVENT(pub i32);
 impl windows_core::TypeKind for MSP_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSP_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSP_EVENT").field(&self.0).finish()
@@ -9713,6 +9746,7 @@ pub struct PHONECAPS_BUFFER(pub i32);
 impl windows_core::TypeKind for PHONECAPS_BUFFER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PHONECAPS_BUFFER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PHONECAPS_BUFFER").field(&self.0).finish()
@@ -9724,6 +9758,7 @@ pub struct PHONECAPS_LONG(pub i32);
 impl windows_core::TypeKind for PHONECAPS_LONG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PHONECAPS_LONG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PHONECAPS_LONG").field(&self.0).finish()
@@ -9735,6 +9770,7 @@ pub struct PHONECAPS_STRING(pub i32);
 impl windows_core::TypeKind for PHONECAPS_STRING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PHONECAPS_STRING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PHONECAPS_STRING").field(&self.0).finish()
@@ -9746,6 +9782,7 @@ pub struct PHONE_BUTTON_FUNCTION(pub i32);
 impl windows_core::TypeKind for PHONE_BUTTON_FUNCTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PHONE_BUTTON_FUNCTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PHONE_BUTTON_FUNCTION").field(&self.0).finish()
@@ -9757,6 +9794,7 @@ pub struct PHONE_BUTTON_MODE(pub i32);
 impl windows_core::TypeKind for PHONE_BUTTON_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PHONE_BUTTON_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PHONE_BUTTON_MODE").field(&self.0).finish()
@@ -9768,6 +9806,7 @@ pub struct PHONE_BUTTON_STATE(pub i32);
 impl windows_core::TypeKind for PHONE_BUTTON_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PHONE_BUTTON_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PHONE_BUTTON_STATE").field(&self.0).finish()
@@ -9779,6 +9818,7 @@ pub struct PHONE_EVENT(pub i32);
 impl windows_core::TypeKind for PHONE_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PHONE_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PHONE_EVENT").field(&self.0).finish()
@@ -9790,6 +9830,7 @@ pub struct PHONE_HOOK_SWITCH_DEVICE(pub i32);
 impl windows_core::TypeKind for PHONE_HOOK_SWITCH_DEVICE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PHONE_HOOK_SWITCH_DEVICE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PHONE_HOOK_SWITCH_DEVICE").field(&self.0).finish()
@@ -9801,6 +9842,7 @@ pub struct PHONE_HOOK_SWITCH_STATE(pub i32);
 impl windows_core::TypeKind for PHONE_HOOK_SWITCH_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PHONE_HOOK_SWITCH_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PHONE_HOOK_SWITCH_STATE").field(&self.0).finish()
@@ -9812,6 +9854,7 @@ pub struct PHONE_LAMP_MODE(pub i32);
 impl windows_core::TypeKind for PHONE_LAMP_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PHONE_LAMP_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PHONE_LAMP_MODE").field(&self.0).finish()
@@ -9823,6 +9866,7 @@ pub struct PHONE_PRIVILEGE(pub i32);
 impl windows_core::TypeKind for PHONE_PRIVILEGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PHONE_PRIVILEGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PHONE_PRIVILEGE").field(&self.0).finish()
@@ -9834,6 +9878,7 @@ pub struct PHONE_TONE(pub i32);
 impl windows_core::TypeKind for PHONE_TONE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PHONE_TONE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PHONE_TONE").field(&self.0).finish()
@@ -9845,6 +9890,7 @@ pub struct QOS_EVENT(pub i32);
 impl windows_core::TypeKind for QOS_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QOS_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QOS_EVENT").field(&self.0).finish()
@@ -9856,6 +9902,7 @@ pub struct QOS_SERVICE_LEVEL(pub i32);
 impl windows_core::TypeKind for QOS_SERVICE_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QOS_SERVICE_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QOS_SERVICE_LEVEL").field(&self.0).finish()
@@ -9867,6 +9914,7 @@ pub struct RND_ADVERTISING_SCOPE(pub i32);
 impl windows_core::TypeKind for RND_ADVERTISING_SCOPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RND_ADVERTISING_SCOPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RND_ADVERTISING_SCOPE").field(&self.0).finish()
@@ -9878,6 +9926,7 @@ pub struct TAPIOBJECT_EVENT(pub i32);
 impl windows_core::TypeKind for TAPIOBJECT_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TAPIOBJECT_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TAPIOBJECT_EVENT").field(&self.0).finish()
@@ -9889,6 +9938,7 @@ pub struct TAPI_EVENT(pub i32);
 impl windows_core::TypeKind for TAPI_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TAPI_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TAPI_EVENT").field(&self.0).finish()
@@ -9900,6 +9950,7 @@ pub struct TAPI_GATHERTERM(pub i32);
 impl windows_core::TypeKind for TAPI_GATHERTERM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TAPI_GATHERTERM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TAPI_GATHERTERM").field(&self.0).finish()
@@ -9911,6 +9962,7 @@ pub struct TAPI_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for TAPI_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TAPI_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TAPI_OBJECT_TYPE").field(&self.0).finish()
@@ -9922,6 +9974,7 @@ pub struct TAPI_TONEMODE(pub i32);
 impl windows_core::TypeKind for TAPI_TONEMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TAPI_TONEMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TAPI_TONEMODE").field(&self.0).finish()
@@ -9933,6 +9986,7 @@ pub struct TERMINAL_DIRECTION(pub i32);
 impl windows_core::TypeKind for TERMINAL_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TERMINAL_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TERMINAL_DIRECTION").field(&self.0).finish()
@@ -9944,6 +9998,7 @@ pub struct TERMINAL_MEDIA_STATE(pub i32);
 impl windows_core::TypeKind for TERMINAL_MEDIA_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TERMINAL_MEDIA_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TERMINAL_MEDIA_STATE").field(&self.0).finish()
@@ -9955,6 +10010,7 @@ pub struct TERMINAL_STATE(pub i32);
 impl windows_core::TypeKind for TERMINAL_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TERMINAL_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TERMINAL_STATE").field(&self.0).finish()
@@ -9966,13 +10022,15 @@ pub struct TERMINAL_TYPE(pub i32);
 impl windows_core::TypeKind for TERMINAL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TERMINAL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TERMINAL_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ADDRALIAS {
     pub rgchName: [i8; 41],
     pub rgchEName: [i8; 11],
@@ -10009,7 +10067,8 @@ impl Default for DTR {
 }
 pub const DispatchMapper: windows_core::GUID = windows_core::GUID::from_u128(0xe9225296_c759_11d1_a02b_00c04fb6809f);
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDRVCALL(pub *mut core::ffi::c_void);
 impl HDRVCALL {
     pub fn is_invalid(&self) -> bool {
@@ -10025,7 +10084,8 @@ impl windows_core::TypeKind for HDRVCALL {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDRVDIALOGINSTANCE(pub *mut core::ffi::c_void);
 impl HDRVDIALOGINSTANCE {
     pub fn is_invalid(&self) -> bool {
@@ -10041,7 +10101,8 @@ impl windows_core::TypeKind for HDRVDIALOGINSTANCE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDRVLINE(pub *mut core::ffi::c_void);
 impl HDRVLINE {
     pub fn is_invalid(&self) -> bool {
@@ -10057,7 +10118,8 @@ impl windows_core::TypeKind for HDRVLINE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDRVMSPLINE(pub *mut core::ffi::c_void);
 impl HDRVMSPLINE {
     pub fn is_invalid(&self) -> bool {
@@ -10073,7 +10135,8 @@ impl windows_core::TypeKind for HDRVMSPLINE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDRVPHONE(pub *mut core::ffi::c_void);
 impl HDRVPHONE {
     pub fn is_invalid(&self) -> bool {
@@ -10089,7 +10152,8 @@ impl windows_core::TypeKind for HDRVPHONE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HPROVIDER(pub *mut core::ffi::c_void);
 impl HPROVIDER {
     pub fn is_invalid(&self) -> bool {
@@ -10105,7 +10169,8 @@ impl windows_core::TypeKind for HPROVIDER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HTAPICALL(pub *mut core::ffi::c_void);
 impl HTAPICALL {
     pub fn is_invalid(&self) -> bool {
@@ -10121,7 +10186,8 @@ impl windows_core::TypeKind for HTAPICALL {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HTAPILINE(pub *mut core::ffi::c_void);
 impl HTAPILINE {
     pub fn is_invalid(&self) -> bool {
@@ -10137,7 +10203,8 @@ impl windows_core::TypeKind for HTAPILINE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HTAPIPHONE(pub *mut core::ffi::c_void);
 impl HTAPIPHONE {
     pub fn is_invalid(&self) -> bool {
@@ -11659,7 +11726,8 @@ impl Default for LINEQUEUELIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LINEREQMAKECALL {
     pub szDestAddress: [i8; 80],
     pub szAppName: [i8; 40],
@@ -11848,7 +11916,8 @@ impl Default for MSP_EVENT_INFO_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MSP_EVENT_INFO_0_0 {
     pub Type: MSP_ADDRESS_EVENT,
     pub pTerminal: core::mem::ManuallyDrop<Option<ITTerminal>>,
@@ -11871,7 +11940,8 @@ impl Default for MSP_EVENT_INFO_0_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MSP_EVENT_INFO_0_1 {
     pub pASRTerminal: core::mem::ManuallyDrop<Option<ITTerminal>>,
     pub hrErrorCode: windows_core::HRESULT,
@@ -11894,7 +11964,8 @@ impl Default for MSP_EVENT_INFO_0_1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MSP_EVENT_INFO_0_2 {
     pub Type: MSP_CALL_EVENT,
     pub Cause: MSP_CALL_EVENT_CAUSE,
@@ -11920,7 +11991,8 @@ impl Default for MSP_EVENT_INFO_0_2 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MSP_EVENT_INFO_0_3 {
     pub pParentFileTerminal: core::mem::ManuallyDrop<Option<ITTerminal>>,
     pub pFileTrack: core::mem::ManuallyDrop<Option<ITFileTrack>>,
@@ -11946,7 +12018,8 @@ impl Default for MSP_EVENT_INFO_0_3 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MSP_EVENT_INFO_0_4 {
     pub pEvent: core::mem::ManuallyDrop<Option<super::super::System::Com::IDispatch>>,
     pub lEventCode: i32,
@@ -11969,7 +12042,8 @@ impl Default for MSP_EVENT_INFO_0_4 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MSP_EVENT_INFO_0_5 {
     pub pToneTerminal: core::mem::ManuallyDrop<Option<ITTerminal>>,
     pub hrErrorCode: windows_core::HRESULT,
@@ -11992,7 +12066,8 @@ impl Default for MSP_EVENT_INFO_0_5 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MSP_EVENT_INFO_0_6 {
     pub dwBufferSize: u32,
     pub pBuffer: [u8; 1],
@@ -12009,7 +12084,8 @@ impl Default for MSP_EVENT_INFO_0_6 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MSP_EVENT_INFO_0_7 {
     pub pTTSTerminal: core::mem::ManuallyDrop<Option<ITTerminal>>,
     pub hrErrorCode: windows_core::HRESULT,
@@ -12266,7 +12342,8 @@ impl Default for RENDDATA {
 pub const Rendezvous: windows_core::GUID = windows_core::GUID::from_u128(0xf1029e5b_cb5b_11d0_8d59_00c04fd91ac0);
 pub const RequestMakeCall: windows_core::GUID = windows_core::GUID::from_u128(0xac48ffe0_f8c4_11d1_a030_00c04fb6809f);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STnefProblem {
     pub ulComponent: u32,
     pub ulAttribute: u32,
@@ -12282,7 +12359,8 @@ impl Default for STnefProblem {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STnefProblemArray {
     pub cProblem: u32,
     pub aProblem: [STnefProblem; 1],
@@ -12297,7 +12375,8 @@ impl Default for STnefProblemArray {
 }
 pub const TAPI: windows_core::GUID = windows_core::GUID::from_u128(0x21d6d48e_a88b_11d0_83dd_00aa003ccabd);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TAPI_CUSTOMTONE {
     pub dwFrequency: u32,
     pub dwCadenceOn: u32,
@@ -12313,7 +12392,8 @@ impl Default for TAPI_CUSTOMTONE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TAPI_DETECTTONE {
     pub dwAppSpecific: u32,
     pub dwDuration: u32,
@@ -12330,7 +12410,8 @@ impl Default for TAPI_DETECTTONE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRP {
     pub trpid: u16,
     pub cbgrtrp: u16,
@@ -12346,7 +12427,8 @@ impl Default for TRP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TUISPICREATEDIALOGINSTANCEPARAMS {
     pub dwRequestID: u32,
     pub hdDlgInst: HDRVDIALOGINSTANCE,
@@ -12395,3 +12477,5 @@ pub type PHONEEVENT = Option<unsafe extern "system" fn(htphone: HTAPIPHONE, dwms
 pub type TUISPIDLLCALLBACK = Option<unsafe extern "system" fn(dwobjectid: usize, dwobjecttype: u32, lpparams: *mut core::ffi::c_void, dwsize: u32) -> i32>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
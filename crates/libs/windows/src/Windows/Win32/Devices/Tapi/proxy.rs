@@ -0,0 +1,4556 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumACDGroup_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumACDGroup", "Next", 3usize, |this| ((**(this as *const *const IEnumACDGroup_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumACDGroup", "Reset", 4usize, |this| ((**(this as *const *const IEnumACDGroup_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumACDGroup", "Skip", 5usize, |this| ((**(this as *const *const IEnumACDGroup_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumACDGroup", "Clone", 6usize, |this| ((**(this as *const *const IEnumACDGroup_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumACDGroup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumAddress_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAddress", "Next", 3usize, |this| ((**(this as *const *const IEnumAddress_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAddress", "Reset", 4usize, |this| ((**(this as *const *const IEnumAddress_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAddress", "Skip", 5usize, |this| ((**(this as *const *const IEnumAddress_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAddress", "Clone", 6usize, |this| ((**(this as *const *const IEnumAddress_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumAddress as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumAgent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAgent", "Next", 3usize, |this| ((**(this as *const *const IEnumAgent_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAgent", "Reset", 4usize, |this| ((**(this as *const *const IEnumAgent_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAgent", "Skip", 5usize, |this| ((**(this as *const *const IEnumAgent_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAgent", "Clone", 6usize, |this| ((**(this as *const *const IEnumAgent_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumAgent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumAgentHandler_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAgentHandler", "Next", 3usize, |this| ((**(this as *const *const IEnumAgentHandler_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAgentHandler", "Reset", 4usize, |this| ((**(this as *const *const IEnumAgentHandler_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAgentHandler", "Skip", 5usize, |this| ((**(this as *const *const IEnumAgentHandler_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAgentHandler", "Clone", 6usize, |this| ((**(this as *const *const IEnumAgentHandler_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumAgentHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumAgentSession_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAgentSession", "Next", 3usize, |this| ((**(this as *const *const IEnumAgentSession_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAgentSession", "Reset", 4usize, |this| ((**(this as *const *const IEnumAgentSession_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAgentSession", "Skip", 5usize, |this| ((**(this as *const *const IEnumAgentSession_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumAgentSession", "Clone", 6usize, |this| ((**(this as *const *const IEnumAgentSession_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumAgentSession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumBstr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppstrings: *mut core::mem::MaybeUninit<windows_core::BSTR>, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBstr", "Next", 3usize, |this| ((**(this as *const *const IEnumBstr_Vtbl)).Next)(this, celt, ppstrings, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBstr", "Reset", 4usize, |this| ((**(this as *const *const IEnumBstr_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBstr", "Skip", 5usize, |this| ((**(this as *const *const IEnumBstr_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBstr", "Clone", 6usize, |this| ((**(this as *const *const IEnumBstr_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Reset, Skip, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumBstr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumCall_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCall", "Next", 3usize, |this| ((**(this as *const *const IEnumCall_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCall", "Reset", 4usize, |this| ((**(this as *const *const IEnumCall_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCall", "Skip", 5usize, |this| ((**(this as *const *const IEnumCall_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCall", "Clone", 6usize, |this| ((**(this as *const *const IEnumCall_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumCall as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumCallHub_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCallHub", "Next", 3usize, |this| ((**(this as *const *const IEnumCallHub_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCallHub", "Reset", 4usize, |this| ((**(this as *const *const IEnumCallHub_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCallHub", "Skip", 5usize, |this| ((**(this as *const *const IEnumCallHub_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCallHub", "Clone", 6usize, |this| ((**(this as *const *const IEnumCallHub_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumCallHub as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumCallingCard_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCallingCard", "Next", 3usize, |this| ((**(this as *const *const IEnumCallingCard_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCallingCard", "Reset", 4usize, |this| ((**(this as *const *const IEnumCallingCard_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCallingCard", "Skip", 5usize, |this| ((**(this as *const *const IEnumCallingCard_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCallingCard", "Clone", 6usize, |this| ((**(this as *const *const IEnumCallingCard_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumCallingCard as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumDialableAddrs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut core::mem::MaybeUninit<windows_core::BSTR>, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDialableAddrs", "Next", 3usize, |this| ((**(this as *const *const IEnumDialableAddrs_Vtbl)).Next)(this, celt, ppelements, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDialableAddrs", "Reset", 4usize, |this| ((**(this as *const *const IEnumDialableAddrs_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDialableAddrs", "Skip", 5usize, |this| ((**(this as *const *const IEnumDialableAddrs_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDialableAddrs", "Clone", 6usize, |this| ((**(this as *const *const IEnumDialableAddrs_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Reset, Skip, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDialableAddrs as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumDirectory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDirectory", "Next", 3usize, |this| ((**(this as *const *const IEnumDirectory_Vtbl)).Next)(this, celt, ppelements, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDirectory", "Reset", 4usize, |this| ((**(this as *const *const IEnumDirectory_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDirectory", "Skip", 5usize, |this| ((**(this as *const *const IEnumDirectory_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDirectory", "Clone", 6usize, |this| ((**(this as *const *const IEnumDirectory_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDirectory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumDirectoryObject_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, pval: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDirectoryObject", "Next", 3usize, |this| ((**(this as *const *const IEnumDirectoryObject_Vtbl)).Next)(this, celt, pval, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDirectoryObject", "Reset", 4usize, |this| ((**(this as *const *const IEnumDirectoryObject_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDirectoryObject", "Skip", 5usize, |this| ((**(this as *const *const IEnumDirectoryObject_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDirectoryObject", "Clone", 6usize, |this| ((**(this as *const *const IEnumDirectoryObject_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDirectoryObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumLocation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumLocation", "Next", 3usize, |this| ((**(this as *const *const IEnumLocation_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumLocation", "Reset", 4usize, |this| ((**(this as *const *const IEnumLocation_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumLocation", "Skip", 5usize, |this| ((**(this as *const *const IEnumLocation_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumLocation", "Clone", 6usize, |this| ((**(this as *const *const IEnumLocation_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumLocation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumMcastScope_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppscopes: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMcastScope", "Next", 3usize, |this| ((**(this as *const *const IEnumMcastScope_Vtbl)).Next)(this, celt, ppscopes, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMcastScope", "Reset", 4usize, |this| ((**(this as *const *const IEnumMcastScope_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMcastScope", "Skip", 5usize, |this| ((**(this as *const *const IEnumMcastScope_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMcastScope", "Clone", 6usize, |this| ((**(this as *const *const IEnumMcastScope_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumMcastScope as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumPhone_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPhone", "Next", 3usize, |this| ((**(this as *const *const IEnumPhone_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPhone", "Reset", 4usize, |this| ((**(this as *const *const IEnumPhone_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPhone", "Skip", 5usize, |this| ((**(this as *const *const IEnumPhone_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPhone", "Clone", 6usize, |this| ((**(this as *const *const IEnumPhone_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumPhone as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumPluggableSuperclassInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPluggableSuperclassInfo", "Next", 3usize, |this| ((**(this as *const *const IEnumPluggableSuperclassInfo_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPluggableSuperclassInfo", "Reset", 4usize, |this| ((**(this as *const *const IEnumPluggableSuperclassInfo_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPluggableSuperclassInfo", "Skip", 5usize, |this| ((**(this as *const *const IEnumPluggableSuperclassInfo_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPluggableSuperclassInfo", "Clone", 6usize, |this| ((**(this as *const *const IEnumPluggableSuperclassInfo_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumPluggableSuperclassInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumPluggableTerminalClassInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPluggableTerminalClassInfo", "Next", 3usize, |this| ((**(this as *const *const IEnumPluggableTerminalClassInfo_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPluggableTerminalClassInfo", "Reset", 4usize, |this| ((**(this as *const *const IEnumPluggableTerminalClassInfo_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPluggableTerminalClassInfo", "Skip", 5usize, |this| ((**(this as *const *const IEnumPluggableTerminalClassInfo_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPluggableTerminalClassInfo", "Clone", 6usize, |this| ((**(this as *const *const IEnumPluggableTerminalClassInfo_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumPluggableTerminalClassInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumQueue_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumQueue", "Next", 3usize, |this| ((**(this as *const *const IEnumQueue_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumQueue", "Reset", 4usize, |this| ((**(this as *const *const IEnumQueue_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumQueue", "Skip", 5usize, |this| ((**(this as *const *const IEnumQueue_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumQueue", "Clone", 6usize, |this| ((**(this as *const *const IEnumQueue_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumQueue as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumStream_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumStream", "Next", 3usize, |this| ((**(this as *const *const IEnumStream_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumStream", "Reset", 4usize, |this| ((**(this as *const *const IEnumStream_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumStream", "Skip", 5usize, |this| ((**(this as *const *const IEnumStream_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumStream", "Clone", 6usize, |this| ((**(this as *const *const IEnumStream_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumSubStream_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSubStream", "Next", 3usize, |this| ((**(this as *const *const IEnumSubStream_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSubStream", "Reset", 4usize, |this| ((**(this as *const *const IEnumSubStream_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSubStream", "Skip", 5usize, |this| ((**(this as *const *const IEnumSubStream_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSubStream", "Clone", 6usize, |this| ((**(this as *const *const IEnumSubStream_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSubStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumTerminal_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTerminal", "Next", 3usize, |this| ((**(this as *const *const IEnumTerminal_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTerminal", "Reset", 4usize, |this| ((**(this as *const *const IEnumTerminal_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTerminal", "Skip", 5usize, |this| ((**(this as *const *const IEnumTerminal_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTerminal", "Clone", 6usize, |this| ((**(this as *const *const IEnumTerminal_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Reset,
+            Skip,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTerminal as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumTerminalClass_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, pelements: *mut windows_core::GUID, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTerminalClass", "Next", 3usize, |this| ((**(this as *const *const IEnumTerminalClass_Vtbl)).Next)(this, celt, pelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTerminalClass", "Reset", 4usize, |this| ((**(this as *const *const IEnumTerminalClass_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTerminalClass", "Skip", 5usize, |this| ((**(this as *const *const IEnumTerminalClass_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTerminalClass", "Clone", 6usize, |this| ((**(this as *const *const IEnumTerminalClass_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Reset, Skip, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTerminalClass as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMcastAddressAllocation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Scopes(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastAddressAllocation", "Scopes", 7usize, |this| ((**(this as *const *const IMcastAddressAllocation_Vtbl)).Scopes)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateScopes(this: *mut core::ffi::c_void, ppenummcastscope: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastAddressAllocation", "EnumerateScopes", 8usize, |this| ((**(this as *const *const IMcastAddressAllocation_Vtbl)).EnumerateScopes)(this, ppenummcastscope), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RequestAddress(this: *mut core::ffi::c_void, pscope: *mut core::ffi::c_void, leasestarttime: f64, leasestoptime: f64, numaddresses: i32, ppleaseresponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastAddressAllocation", "RequestAddress", 9usize, |this| ((**(this as *const *const IMcastAddressAllocation_Vtbl)).RequestAddress)(this, pscope, leasestarttime, leasestoptime, numaddresses, ppleaseresponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RenewAddress(this: *mut core::ffi::c_void, lreserved: i32, prenewrequest: *mut core::ffi::c_void, pprenewresponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastAddressAllocation", "RenewAddress", 10usize, |this| ((**(this as *const *const IMcastAddressAllocation_Vtbl)).RenewAddress)(this, lreserved, prenewrequest, pprenewresponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReleaseAddress(this: *mut core::ffi::c_void, preleaserequest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastAddressAllocation", "ReleaseAddress", 11usize, |this| ((**(this as *const *const IMcastAddressAllocation_Vtbl)).ReleaseAddress)(this, preleaserequest), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateLeaseInfo(this: *mut core::ffi::c_void, leasestarttime: f64, leasestoptime: f64, dwnumaddresses: u32, ppaddresses: *const windows_core::PCWSTR, prequestid: windows_core::PCWSTR, pserveraddress: windows_core::PCWSTR, ppreleaserequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastAddressAllocation", "CreateLeaseInfo", 12usize, |this| ((**(this as *const *const IMcastAddressAllocation_Vtbl)).CreateLeaseInfo)(this, leasestarttime, leasestoptime, dwnumaddresses, ppaddresses, prequestid, pserveraddress, ppreleaserequest), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateLeaseInfoFromVariant(this: *mut core::ffi::c_void, leasestarttime: f64, leasestoptime: f64, vaddresses: core::mem::MaybeUninit<windows_core::VARIANT>, prequestid: core::mem::MaybeUninit<windows_core::BSTR>, pserveraddress: core::mem::MaybeUninit<windows_core::BSTR>, ppreleaserequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastAddressAllocation", "CreateLeaseInfoFromVariant", 13usize, |this| ((**(this as *const *const IMcastAddressAllocation_Vtbl)).CreateLeaseInfoFromVariant)(this, leasestarttime, leasestoptime, vaddresses, prequestid, pserveraddress, ppreleaserequest), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Scopes,
+            EnumerateScopes,
+            #[cfg(feature = "Win32_System_Com")]
+            RequestAddress,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RequestAddress: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RenewAddress,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RenewAddress: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReleaseAddress,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReleaseAddress: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateLeaseInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateLeaseInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateLeaseInfoFromVariant,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateLeaseInfoFromVariant: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMcastAddressAllocation as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMcastLeaseInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestID(this: *mut core::ffi::c_void, pprequestid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastLeaseInfo", "RequestID", 7usize, |this| ((**(this as *const *const IMcastLeaseInfo_Vtbl)).RequestID)(this, pprequestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeaseStartTime(this: *mut core::ffi::c_void, ptime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastLeaseInfo", "LeaseStartTime", 8usize, |this| ((**(this as *const *const IMcastLeaseInfo_Vtbl)).LeaseStartTime)(this, ptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLeaseStartTime(this: *mut core::ffi::c_void, time: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastLeaseInfo", "SetLeaseStartTime", 9usize, |this| ((**(this as *const *const IMcastLeaseInfo_Vtbl)).SetLeaseStartTime)(this, time), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeaseStopTime(this: *mut core::ffi::c_void, ptime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastLeaseInfo", "LeaseStopTime", 10usize, |this| ((**(this as *const *const IMcastLeaseInfo_Vtbl)).LeaseStopTime)(this, ptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLeaseStopTime(this: *mut core::ffi::c_void, time: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastLeaseInfo", "SetLeaseStopTime", 11usize, |this| ((**(this as *const *const IMcastLeaseInfo_Vtbl)).SetLeaseStopTime)(this, time), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddressCount(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastLeaseInfo", "AddressCount", 12usize, |this| ((**(this as *const *const IMcastLeaseInfo_Vtbl)).AddressCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerAddress(this: *mut core::ffi::c_void, ppaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastLeaseInfo", "ServerAddress", 13usize, |this| ((**(this as *const *const IMcastLeaseInfo_Vtbl)).ServerAddress)(this, ppaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn TTL(this: *mut core::ffi::c_void, pttl: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastLeaseInfo", "TTL", 14usize, |this| ((**(this as *const *const IMcastLeaseInfo_Vtbl)).TTL)(this, pttl), |result| Some(*result))
+        }
+        unsafe extern "system" fn Addresses(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastLeaseInfo", "Addresses", 15usize, |this| ((**(this as *const *const IMcastLeaseInfo_Vtbl)).Addresses)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateAddresses(this: *mut core::ffi::c_void, ppenumaddresses: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastLeaseInfo", "EnumerateAddresses", 16usize, |this| ((**(this as *const *const IMcastLeaseInfo_Vtbl)).EnumerateAddresses)(this, ppenumaddresses), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestID,
+            LeaseStartTime,
+            SetLeaseStartTime,
+            LeaseStopTime,
+            SetLeaseStopTime,
+            AddressCount,
+            ServerAddress,
+            TTL,
+            Addresses,
+            EnumerateAddresses,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMcastLeaseInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMcastScope_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScopeID(this: *mut core::ffi::c_void, pid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastScope", "ScopeID", 7usize, |this| ((**(this as *const *const IMcastScope_Vtbl)).ScopeID)(this, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerID(this: *mut core::ffi::c_void, pid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastScope", "ServerID", 8usize, |this| ((**(this as *const *const IMcastScope_Vtbl)).ServerID)(this, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn InterfaceID(this: *mut core::ffi::c_void, pid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastScope", "InterfaceID", 9usize, |this| ((**(this as *const *const IMcastScope_Vtbl)).InterfaceID)(this, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScopeDescription(this: *mut core::ffi::c_void, ppdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastScope", "ScopeDescription", 10usize, |this| ((**(this as *const *const IMcastScope_Vtbl)).ScopeDescription)(this, ppdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn TTL(this: *mut core::ffi::c_void, pttl: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMcastScope", "TTL", 11usize, |this| ((**(this as *const *const IMcastScope_Vtbl)).TTL)(this, pttl), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ScopeID,
+            ServerID,
+            InterfaceID,
+            ScopeDescription,
+            TTL,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMcastScope as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITACDGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, ppname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITACDGroup", "Name", 7usize, |this| ((**(this as *const *const ITACDGroup_Vtbl)).Name)(this, ppname), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateQueues(this: *mut core::ffi::c_void, ppenumqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITACDGroup", "EnumerateQueues", 8usize, |this| ((**(this as *const *const ITACDGroup_Vtbl)).EnumerateQueues)(this, ppenumqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Queues(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITACDGroup", "Queues", 9usize, |this| ((**(this as *const *const ITACDGroup_Vtbl)).Queues)(this, pvariant), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, EnumerateQueues, Queues }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITACDGroup as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITACDGroupEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Group(this: *mut core::ffi::c_void, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITACDGroupEvent", "Group", 7usize, |this| ((**(this as *const *const ITACDGroupEvent_Vtbl)).Group)(this, ppgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn Event(this: *mut core::ffi::c_void, pevent: *mut ACDGROUP_EVENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITACDGroupEvent", "Event", 8usize, |this| ((**(this as *const *const ITACDGroupEvent_Vtbl)).Event)(this, pevent), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Group,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Group: 0,
+            Event,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITACDGroupEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for ITAMMediaFormat_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn MediaFormat(this: *mut core::ffi::c_void, ppmt: *mut *mut super::super::Media::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAMMediaFormat", "MediaFormat", 3usize, |this| ((**(this as *const *const ITAMMediaFormat_Vtbl)).MediaFormat)(this, ppmt), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn SetMediaFormat(this: *mut core::ffi::c_void, pmt: *const super::super::Media::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAMMediaFormat", "SetMediaFormat", 4usize, |this| ((**(this as *const *const ITAMMediaFormat_Vtbl)).SetMediaFormat)(this, pmt), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            MediaFormat,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            MediaFormat: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            SetMediaFormat,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            SetMediaFormat: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAMMediaFormat as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITASRTerminalEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Terminal(this: *mut core::ffi::c_void, ppterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITASRTerminalEvent", "Terminal", 7usize, |this| ((**(this as *const *const ITASRTerminalEvent_Vtbl)).Terminal)(this, ppterminal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcall: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITASRTerminalEvent", "Call", 8usize, |this| ((**(this as *const *const ITASRTerminalEvent_Vtbl)).Call)(this, ppcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, phrerrorcode: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITASRTerminalEvent", "Error", 9usize, |this| ((**(this as *const *const ITASRTerminalEvent_Vtbl)).Error)(this, phrerrorcode), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Terminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Terminal: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+            Error,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITASRTerminalEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITAddress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, paddressstate: *mut ADDRESS_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress", "State", 7usize, |this| ((**(this as *const *const ITAddress_Vtbl)).State)(this, paddressstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddressName(this: *mut core::ffi::c_void, ppname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress", "AddressName", 8usize, |this| ((**(this as *const *const ITAddress_Vtbl)).AddressName)(this, ppname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceProviderName(this: *mut core::ffi::c_void, ppname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress", "ServiceProviderName", 9usize, |this| ((**(this as *const *const ITAddress_Vtbl)).ServiceProviderName)(this, ppname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TAPIObject(this: *mut core::ffi::c_void, pptapiobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress", "TAPIObject", 10usize, |this| ((**(this as *const *const ITAddress_Vtbl)).TAPIObject)(this, pptapiobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateCall(this: *mut core::ffi::c_void, pdestaddress: core::mem::MaybeUninit<windows_core::BSTR>, laddresstype: i32, lmediatypes: i32, ppcall: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress", "CreateCall", 11usize, |this| ((**(this as *const *const ITAddress_Vtbl)).CreateCall)(this, pdestaddress, laddresstype, lmediatypes, ppcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn Calls(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress", "Calls", 12usize, |this| ((**(this as *const *const ITAddress_Vtbl)).Calls)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateCalls(this: *mut core::ffi::c_void, ppcallenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress", "EnumerateCalls", 13usize, |this| ((**(this as *const *const ITAddress_Vtbl)).EnumerateCalls)(this, ppcallenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn DialableAddress(this: *mut core::ffi::c_void, pdialableaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress", "DialableAddress", 14usize, |this| ((**(this as *const *const ITAddress_Vtbl)).DialableAddress)(this, pdialableaddress), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateForwardInfoObject(this: *mut core::ffi::c_void, ppforwardinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress", "CreateForwardInfoObject", 15usize, |this| ((**(this as *const *const ITAddress_Vtbl)).CreateForwardInfoObject)(this, ppforwardinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Forward(this: *mut core::ffi::c_void, pforwardinfo: *mut core::ffi::c_void, pcall: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress", "Forward", 16usize, |this| ((**(this as *const *const ITAddress_Vtbl)).Forward)(this, pforwardinfo, pcall), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CurrentForwardInfo(this: *mut core::ffi::c_void, ppforwardinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress", "CurrentForwardInfo", 17usize, |this| ((**(this as *const *const ITAddress_Vtbl)).CurrentForwardInfo)(this, ppforwardinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageWaiting(this: *mut core::ffi::c_void, fmessagewaiting: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress", "SetMessageWaiting", 18usize, |this| ((**(this as *const *const ITAddress_Vtbl)).SetMessageWaiting)(this, fmessagewaiting), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageWaiting(this: *mut core::ffi::c_void, pfmessagewaiting: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress", "MessageWaiting", 19usize, |this| ((**(this as *const *const ITAddress_Vtbl)).MessageWaiting)(this, pfmessagewaiting), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDoNotDisturb(this: *mut core::ffi::c_void, fdonotdisturb: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress", "SetDoNotDisturb", 20usize, |this| ((**(this as *const *const ITAddress_Vtbl)).SetDoNotDisturb)(this, fdonotdisturb), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoNotDisturb(this: *mut core::ffi::c_void, pfdonotdisturb: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress", "DoNotDisturb", 21usize, |this| ((**(this as *const *const ITAddress_Vtbl)).DoNotDisturb)(this, pfdonotdisturb), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            State,
+            AddressName,
+            ServiceProviderName,
+            #[cfg(feature = "Win32_System_Com")]
+            TAPIObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TAPIObject: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateCall,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateCall: 0,
+            Calls,
+            EnumerateCalls,
+            DialableAddress,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateForwardInfoObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateForwardInfoObject: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Forward,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Forward: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CurrentForwardInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CurrentForwardInfo: 0,
+            SetMessageWaiting,
+            MessageWaiting,
+            SetDoNotDisturb,
+            DoNotDisturb,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAddress as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITAddress2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Phones(this: *mut core::ffi::c_void, pphones: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress2", "Phones", 22usize, |this| ((**(this as *const *const ITAddress2_Vtbl)).Phones)(this, pphones), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumeratePhones(this: *mut core::ffi::c_void, ppenumphone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress2", "EnumeratePhones", 23usize, |this| ((**(this as *const *const ITAddress2_Vtbl)).EnumeratePhones)(this, ppenumphone), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetPhoneFromTerminal(this: *mut core::ffi::c_void, pterminal: *mut core::ffi::c_void, ppphone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress2", "GetPhoneFromTerminal", 24usize, |this| ((**(this as *const *const ITAddress2_Vtbl)).GetPhoneFromTerminal)(this, pterminal, ppphone), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredPhones(this: *mut core::ffi::c_void, pphones: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress2", "PreferredPhones", 25usize, |this| ((**(this as *const *const ITAddress2_Vtbl)).PreferredPhones)(this, pphones), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumeratePreferredPhones(this: *mut core::ffi::c_void, ppenumphone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress2", "EnumeratePreferredPhones", 26usize, |this| ((**(this as *const *const ITAddress2_Vtbl)).EnumeratePreferredPhones)(this, ppenumphone), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_EventFilter(this: *mut core::ffi::c_void, tapievent: TAPI_EVENT, lsubevent: i32, penable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress2", "get_EventFilter", 27usize, |this| ((**(this as *const *const ITAddress2_Vtbl)).get_EventFilter)(this, tapievent, lsubevent, penable), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_EventFilter(this: *mut core::ffi::c_void, tapievent: TAPI_EVENT, lsubevent: i32, benable: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress2", "put_EventFilter", 28usize, |this| ((**(this as *const *const ITAddress2_Vtbl)).put_EventFilter)(this, tapievent, lsubevent, benable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DeviceSpecific(this: *mut core::ffi::c_void, pcall: *mut core::ffi::c_void, pparams: *const u8, dwsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress2", "DeviceSpecific", 29usize, |this| ((**(this as *const *const ITAddress2_Vtbl)).DeviceSpecific)(this, pcall, pparams, dwsize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DeviceSpecificVariant(this: *mut core::ffi::c_void, pcall: *mut core::ffi::c_void, vardevspecificbytearray: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress2", "DeviceSpecificVariant", 30usize, |this| ((**(this as *const *const ITAddress2_Vtbl)).DeviceSpecificVariant)(this, pcall, vardevspecificbytearray), |result| Some(*result))
+        }
+        unsafe extern "system" fn NegotiateExtVersion(this: *mut core::ffi::c_void, llowversion: i32, lhighversion: i32, plextversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddress2", "NegotiateExtVersion", 31usize, |this| ((**(this as *const *const ITAddress2_Vtbl)).NegotiateExtVersion)(this, llowversion, lhighversion, plextversion), |result| Some(*result))
+        }
+        Self {
+            base__: <ITAddress_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Phones,
+            EnumeratePhones,
+            #[cfg(feature = "Win32_System_Com")]
+            GetPhoneFromTerminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetPhoneFromTerminal: 0,
+            PreferredPhones,
+            EnumeratePreferredPhones,
+            get_EventFilter,
+            put_EventFilter,
+            #[cfg(feature = "Win32_System_Com")]
+            DeviceSpecific,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DeviceSpecific: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DeviceSpecificVariant,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DeviceSpecificVariant: 0,
+            NegotiateExtVersion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAddress2 as windows_core::Interface>::IID || <ITAddress_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITAddressCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_AddressCapability(this: *mut core::ffi::c_void, addresscap: ADDRESS_CAPABILITY, plcapability: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressCapabilities", "get_AddressCapability", 7usize, |this| ((**(this as *const *const ITAddressCapabilities_Vtbl)).get_AddressCapability)(this, addresscap, plcapability), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AddressCapabilityString(this: *mut core::ffi::c_void, addresscapstring: ADDRESS_CAPABILITY_STRING, ppcapabilitystring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressCapabilities", "get_AddressCapabilityString", 8usize, |this| ((**(this as *const *const ITAddressCapabilities_Vtbl)).get_AddressCapabilityString)(this, addresscapstring, ppcapabilitystring), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallTreatments(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressCapabilities", "CallTreatments", 9usize, |this| ((**(this as *const *const ITAddressCapabilities_Vtbl)).CallTreatments)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateCallTreatments(this: *mut core::ffi::c_void, ppenumcalltreatment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressCapabilities", "EnumerateCallTreatments", 10usize, |this| ((**(this as *const *const ITAddressCapabilities_Vtbl)).EnumerateCallTreatments)(this, ppenumcalltreatment), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompletionMessages(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressCapabilities", "CompletionMessages", 11usize, |this| ((**(this as *const *const ITAddressCapabilities_Vtbl)).CompletionMessages)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateCompletionMessages(this: *mut core::ffi::c_void, ppenumcompletionmessage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressCapabilities", "EnumerateCompletionMessages", 12usize, |this| ((**(this as *const *const ITAddressCapabilities_Vtbl)).EnumerateCompletionMessages)(this, ppenumcompletionmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceClasses(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressCapabilities", "DeviceClasses", 13usize, |this| ((**(this as *const *const ITAddressCapabilities_Vtbl)).DeviceClasses)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateDeviceClasses(this: *mut core::ffi::c_void, ppenumdeviceclass: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressCapabilities", "EnumerateDeviceClasses", 14usize, |this| ((**(this as *const *const ITAddressCapabilities_Vtbl)).EnumerateDeviceClasses)(this, ppenumdeviceclass), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_AddressCapability,
+            get_AddressCapabilityString,
+            CallTreatments,
+            EnumerateCallTreatments,
+            CompletionMessages,
+            EnumerateCompletionMessages,
+            DeviceClasses,
+            EnumerateDeviceClasses,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAddressCapabilities as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITAddressDeviceSpecificEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, ppaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressDeviceSpecificEvent", "Address", 7usize, |this| ((**(this as *const *const ITAddressDeviceSpecificEvent_Vtbl)).Address)(this, ppaddress), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcall: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressDeviceSpecificEvent", "Call", 8usize, |this| ((**(this as *const *const ITAddressDeviceSpecificEvent_Vtbl)).Call)(this, ppcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn lParam1(this: *mut core::ffi::c_void, pparam1: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressDeviceSpecificEvent", "lParam1", 9usize, |this| ((**(this as *const *const ITAddressDeviceSpecificEvent_Vtbl)).lParam1)(this, pparam1), |result| Some(*result))
+        }
+        unsafe extern "system" fn lParam2(this: *mut core::ffi::c_void, pparam2: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressDeviceSpecificEvent", "lParam2", 10usize, |this| ((**(this as *const *const ITAddressDeviceSpecificEvent_Vtbl)).lParam2)(this, pparam2), |result| Some(*result))
+        }
+        unsafe extern "system" fn lParam3(this: *mut core::ffi::c_void, pparam3: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressDeviceSpecificEvent", "lParam3", 11usize, |this| ((**(this as *const *const ITAddressDeviceSpecificEvent_Vtbl)).lParam3)(this, pparam3), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Address,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Address: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+            lParam1,
+            lParam2,
+            lParam3,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAddressDeviceSpecificEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITAddressEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, ppaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressEvent", "Address", 7usize, |this| ((**(this as *const *const ITAddressEvent_Vtbl)).Address)(this, ppaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Event(this: *mut core::ffi::c_void, pevent: *mut ADDRESS_EVENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressEvent", "Event", 8usize, |this| ((**(this as *const *const ITAddressEvent_Vtbl)).Event)(this, pevent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Terminal(this: *mut core::ffi::c_void, ppterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressEvent", "Terminal", 9usize, |this| ((**(this as *const *const ITAddressEvent_Vtbl)).Terminal)(this, ppterminal), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Address,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Address: 0,
+            Event,
+            #[cfg(feature = "Win32_System_Com")]
+            Terminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Terminal: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAddressEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITAddressTranslation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TranslateAddress(this: *mut core::ffi::c_void, paddresstotranslate: core::mem::MaybeUninit<windows_core::BSTR>, lcard: i32, ltranslateoptions: i32, pptranslated: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressTranslation", "TranslateAddress", 7usize, |this| ((**(this as *const *const ITAddressTranslation_Vtbl)).TranslateAddress)(this, paddresstotranslate, lcard, ltranslateoptions, pptranslated), |result| Some(*result))
+        }
+        unsafe extern "system" fn TranslateDialog(this: *mut core::ffi::c_void, hwndowner: isize, paddressin: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressTranslation", "TranslateDialog", 8usize, |this| ((**(this as *const *const ITAddressTranslation_Vtbl)).TranslateDialog)(this, hwndowner, paddressin), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateLocations(this: *mut core::ffi::c_void, ppenumlocation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressTranslation", "EnumerateLocations", 9usize, |this| ((**(this as *const *const ITAddressTranslation_Vtbl)).EnumerateLocations)(this, ppenumlocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn Locations(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressTranslation", "Locations", 10usize, |this| ((**(this as *const *const ITAddressTranslation_Vtbl)).Locations)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateCallingCards(this: *mut core::ffi::c_void, ppenumcallingcard: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressTranslation", "EnumerateCallingCards", 11usize, |this| ((**(this as *const *const ITAddressTranslation_Vtbl)).EnumerateCallingCards)(this, ppenumcallingcard), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallingCards(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressTranslation", "CallingCards", 12usize, |this| ((**(this as *const *const ITAddressTranslation_Vtbl)).CallingCards)(this, pvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            TranslateAddress,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TranslateAddress: 0,
+            TranslateDialog,
+            EnumerateLocations,
+            Locations,
+            EnumerateCallingCards,
+            CallingCards,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAddressTranslation as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITAddressTranslationInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DialableString(this: *mut core::ffi::c_void, ppdialablestring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressTranslationInfo", "DialableString", 7usize, |this| ((**(this as *const *const ITAddressTranslationInfo_Vtbl)).DialableString)(this, ppdialablestring), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayableString(this: *mut core::ffi::c_void, ppdisplayablestring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressTranslationInfo", "DisplayableString", 8usize, |this| ((**(this as *const *const ITAddressTranslationInfo_Vtbl)).DisplayableString)(this, ppdisplayablestring), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentCountryCode(this: *mut core::ffi::c_void, countrycode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressTranslationInfo", "CurrentCountryCode", 9usize, |this| ((**(this as *const *const ITAddressTranslationInfo_Vtbl)).CurrentCountryCode)(this, countrycode), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationCountryCode(this: *mut core::ffi::c_void, countrycode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressTranslationInfo", "DestinationCountryCode", 10usize, |this| ((**(this as *const *const ITAddressTranslationInfo_Vtbl)).DestinationCountryCode)(this, countrycode), |result| Some(*result))
+        }
+        unsafe extern "system" fn TranslationResults(this: *mut core::ffi::c_void, plresults: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAddressTranslationInfo", "TranslationResults", 11usize, |this| ((**(this as *const *const ITAddressTranslationInfo_Vtbl)).TranslationResults)(this, plresults), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DialableString,
+            DisplayableString,
+            CurrentCountryCode,
+            DestinationCountryCode,
+            TranslationResults,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAddressTranslationInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITAgent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumerateAgentSessions(this: *mut core::ffi::c_void, ppenumagentsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "EnumerateAgentSessions", 7usize, |this| ((**(this as *const *const ITAgent_Vtbl)).EnumerateAgentSessions)(this, ppenumagentsession), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateSession(this: *mut core::ffi::c_void, pacdgroup: *mut core::ffi::c_void, paddress: *mut core::ffi::c_void, ppagentsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "CreateSession", 8usize, |this| ((**(this as *const *const ITAgent_Vtbl)).CreateSession)(this, pacdgroup, paddress, ppagentsession), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateSessionWithPIN(this: *mut core::ffi::c_void, pacdgroup: *mut core::ffi::c_void, paddress: *mut core::ffi::c_void, ppin: core::mem::MaybeUninit<windows_core::BSTR>, ppagentsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "CreateSessionWithPIN", 9usize, |this| ((**(this as *const *const ITAgent_Vtbl)).CreateSessionWithPIN)(this, pacdgroup, paddress, ppin, ppagentsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn ID(this: *mut core::ffi::c_void, ppid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "ID", 10usize, |this| ((**(this as *const *const ITAgent_Vtbl)).ID)(this, ppid), |result| Some(*result))
+        }
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, ppuser: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "User", 11usize, |this| ((**(this as *const *const ITAgent_Vtbl)).User)(this, ppuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, agentstate: AGENT_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "SetState", 12usize, |this| ((**(this as *const *const ITAgent_Vtbl)).SetState)(this, agentstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, pagentstate: *mut AGENT_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "State", 13usize, |this| ((**(this as *const *const ITAgent_Vtbl)).State)(this, pagentstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMeasurementPeriod(this: *mut core::ffi::c_void, lperiod: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "SetMeasurementPeriod", 14usize, |this| ((**(this as *const *const ITAgent_Vtbl)).SetMeasurementPeriod)(this, lperiod), |result| Some(*result))
+        }
+        unsafe extern "system" fn MeasurementPeriod(this: *mut core::ffi::c_void, plperiod: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "MeasurementPeriod", 15usize, |this| ((**(this as *const *const ITAgent_Vtbl)).MeasurementPeriod)(this, plperiod), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OverallCallRate(this: *mut core::ffi::c_void, pcycallrate: *mut super::super::System::Com::CY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "OverallCallRate", 16usize, |this| ((**(this as *const *const ITAgent_Vtbl)).OverallCallRate)(this, pcycallrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfACDCalls(this: *mut core::ffi::c_void, plcalls: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "NumberOfACDCalls", 17usize, |this| ((**(this as *const *const ITAgent_Vtbl)).NumberOfACDCalls)(this, plcalls), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfIncomingCalls(this: *mut core::ffi::c_void, plcalls: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "NumberOfIncomingCalls", 18usize, |this| ((**(this as *const *const ITAgent_Vtbl)).NumberOfIncomingCalls)(this, plcalls), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfOutgoingCalls(this: *mut core::ffi::c_void, plcalls: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "NumberOfOutgoingCalls", 19usize, |this| ((**(this as *const *const ITAgent_Vtbl)).NumberOfOutgoingCalls)(this, plcalls), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalACDTalkTime(this: *mut core::ffi::c_void, pltalktime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "TotalACDTalkTime", 20usize, |this| ((**(this as *const *const ITAgent_Vtbl)).TotalACDTalkTime)(this, pltalktime), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalACDCallTime(this: *mut core::ffi::c_void, plcalltime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "TotalACDCallTime", 21usize, |this| ((**(this as *const *const ITAgent_Vtbl)).TotalACDCallTime)(this, plcalltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalWrapUpTime(this: *mut core::ffi::c_void, plwrapuptime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "TotalWrapUpTime", 22usize, |this| ((**(this as *const *const ITAgent_Vtbl)).TotalWrapUpTime)(this, plwrapuptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn AgentSessions(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgent", "AgentSessions", 23usize, |this| ((**(this as *const *const ITAgent_Vtbl)).AgentSessions)(this, pvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumerateAgentSessions,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateSession,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateSession: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateSessionWithPIN,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateSessionWithPIN: 0,
+            ID,
+            User,
+            SetState,
+            State,
+            SetMeasurementPeriod,
+            MeasurementPeriod,
+            #[cfg(feature = "Win32_System_Com")]
+            OverallCallRate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OverallCallRate: 0,
+            NumberOfACDCalls,
+            NumberOfIncomingCalls,
+            NumberOfOutgoingCalls,
+            TotalACDTalkTime,
+            TotalACDCallTime,
+            TotalWrapUpTime,
+            AgentSessions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAgent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITAgentEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Agent(this: *mut core::ffi::c_void, ppagent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentEvent", "Agent", 7usize, |this| ((**(this as *const *const ITAgentEvent_Vtbl)).Agent)(this, ppagent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Event(this: *mut core::ffi::c_void, pevent: *mut AGENT_EVENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentEvent", "Event", 8usize, |this| ((**(this as *const *const ITAgentEvent_Vtbl)).Event)(this, pevent), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Agent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Agent: 0,
+            Event,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAgentEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITAgentHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, ppname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentHandler", "Name", 7usize, |this| ((**(this as *const *const ITAgentHandler_Vtbl)).Name)(this, ppname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateAgent(this: *mut core::ffi::c_void, ppagent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentHandler", "CreateAgent", 8usize, |this| ((**(this as *const *const ITAgentHandler_Vtbl)).CreateAgent)(this, ppagent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateAgentWithID(this: *mut core::ffi::c_void, pid: core::mem::MaybeUninit<windows_core::BSTR>, ppin: core::mem::MaybeUninit<windows_core::BSTR>, ppagent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentHandler", "CreateAgentWithID", 9usize, |this| ((**(this as *const *const ITAgentHandler_Vtbl)).CreateAgentWithID)(this, pid, ppin, ppagent), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateACDGroups(this: *mut core::ffi::c_void, ppenumacdgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentHandler", "EnumerateACDGroups", 10usize, |this| ((**(this as *const *const ITAgentHandler_Vtbl)).EnumerateACDGroups)(this, ppenumacdgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateUsableAddresses(this: *mut core::ffi::c_void, ppenumaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentHandler", "EnumerateUsableAddresses", 11usize, |this| ((**(this as *const *const ITAgentHandler_Vtbl)).EnumerateUsableAddresses)(this, ppenumaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn ACDGroups(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentHandler", "ACDGroups", 12usize, |this| ((**(this as *const *const ITAgentHandler_Vtbl)).ACDGroups)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsableAddresses(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentHandler", "UsableAddresses", 13usize, |this| ((**(this as *const *const ITAgentHandler_Vtbl)).UsableAddresses)(this, pvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateAgent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateAgent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateAgentWithID,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateAgentWithID: 0,
+            EnumerateACDGroups,
+            EnumerateUsableAddresses,
+            ACDGroups,
+            UsableAddresses,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAgentHandler as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITAgentHandlerEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AgentHandler(this: *mut core::ffi::c_void, ppagenthandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentHandlerEvent", "AgentHandler", 7usize, |this| ((**(this as *const *const ITAgentHandlerEvent_Vtbl)).AgentHandler)(this, ppagenthandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn Event(this: *mut core::ffi::c_void, pevent: *mut AGENTHANDLER_EVENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentHandlerEvent", "Event", 8usize, |this| ((**(this as *const *const ITAgentHandlerEvent_Vtbl)).Event)(this, pevent), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AgentHandler,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AgentHandler: 0,
+            Event,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAgentHandlerEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITAgentSession_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Agent(this: *mut core::ffi::c_void, ppagent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "Agent", 7usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).Agent)(this, ppagent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, ppaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "Address", 8usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).Address)(this, ppaddress), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ACDGroup(this: *mut core::ffi::c_void, ppacdgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "ACDGroup", 9usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).ACDGroup)(this, ppacdgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, sessionstate: AGENT_SESSION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "SetState", 10usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).SetState)(this, sessionstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, psessionstate: *mut AGENT_SESSION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "State", 11usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).State)(this, psessionstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionStartTime(this: *mut core::ffi::c_void, pdatesessionstart: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "SessionStartTime", 12usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).SessionStartTime)(this, pdatesessionstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionDuration(this: *mut core::ffi::c_void, plduration: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "SessionDuration", 13usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).SessionDuration)(this, plduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfCalls(this: *mut core::ffi::c_void, plcalls: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "NumberOfCalls", 14usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).NumberOfCalls)(this, plcalls), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalTalkTime(this: *mut core::ffi::c_void, pltalktime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "TotalTalkTime", 15usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).TotalTalkTime)(this, pltalktime), |result| Some(*result))
+        }
+        unsafe extern "system" fn AverageTalkTime(this: *mut core::ffi::c_void, pltalktime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "AverageTalkTime", 16usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).AverageTalkTime)(this, pltalktime), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalCallTime(this: *mut core::ffi::c_void, plcalltime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "TotalCallTime", 17usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).TotalCallTime)(this, plcalltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn AverageCallTime(this: *mut core::ffi::c_void, plcalltime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "AverageCallTime", 18usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).AverageCallTime)(this, plcalltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalWrapUpTime(this: *mut core::ffi::c_void, plwrapuptime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "TotalWrapUpTime", 19usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).TotalWrapUpTime)(this, plwrapuptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn AverageWrapUpTime(this: *mut core::ffi::c_void, plwrapuptime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "AverageWrapUpTime", 20usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).AverageWrapUpTime)(this, plwrapuptime), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ACDCallRate(this: *mut core::ffi::c_void, pcycallrate: *mut super::super::System::Com::CY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "ACDCallRate", 21usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).ACDCallRate)(this, pcycallrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn LongestTimeToAnswer(this: *mut core::ffi::c_void, planswertime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "LongestTimeToAnswer", 22usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).LongestTimeToAnswer)(this, planswertime), |result| Some(*result))
+        }
+        unsafe extern "system" fn AverageTimeToAnswer(this: *mut core::ffi::c_void, planswertime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSession", "AverageTimeToAnswer", 23usize, |this| ((**(this as *const *const ITAgentSession_Vtbl)).AverageTimeToAnswer)(this, planswertime), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Agent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Agent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Address,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Address: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ACDGroup,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ACDGroup: 0,
+            SetState,
+            State,
+            SessionStartTime,
+            SessionDuration,
+            NumberOfCalls,
+            TotalTalkTime,
+            AverageTalkTime,
+            TotalCallTime,
+            AverageCallTime,
+            TotalWrapUpTime,
+            AverageWrapUpTime,
+            #[cfg(feature = "Win32_System_Com")]
+            ACDCallRate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ACDCallRate: 0,
+            LongestTimeToAnswer,
+            AverageTimeToAnswer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAgentSession as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITAgentSessionEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSessionEvent", "Session", 7usize, |this| ((**(this as *const *const ITAgentSessionEvent_Vtbl)).Session)(this, ppsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn Event(this: *mut core::ffi::c_void, pevent: *mut AGENT_SESSION_EVENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAgentSessionEvent", "Event", 8usize, |this| ((**(this as *const *const ITAgentSessionEvent_Vtbl)).Event)(this, pevent), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Session,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Session: 0,
+            Event,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAgentSessionEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_DirectShow")]
+impl windows_core::imp::ProxyVtable for ITAllocatorProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_DirectShow")]
+        unsafe extern "system" fn SetAllocatorProperties(this: *mut core::ffi::c_void, pallocproperties: *const super::super::Media::DirectShow::ALLOCATOR_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAllocatorProperties", "SetAllocatorProperties", 3usize, |this| ((**(this as *const *const ITAllocatorProperties_Vtbl)).SetAllocatorProperties)(this, pallocproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_DirectShow")]
+        unsafe extern "system" fn GetAllocatorProperties(this: *mut core::ffi::c_void, pallocproperties: *mut super::super::Media::DirectShow::ALLOCATOR_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAllocatorProperties", "GetAllocatorProperties", 4usize, |this| ((**(this as *const *const ITAllocatorProperties_Vtbl)).GetAllocatorProperties)(this, pallocproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllocateBuffers(this: *mut core::ffi::c_void, ballocbuffers: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAllocatorProperties", "SetAllocateBuffers", 5usize, |this| ((**(this as *const *const ITAllocatorProperties_Vtbl)).SetAllocateBuffers)(this, ballocbuffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllocateBuffers(this: *mut core::ffi::c_void, pballocbuffers: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAllocatorProperties", "GetAllocateBuffers", 6usize, |this| ((**(this as *const *const ITAllocatorProperties_Vtbl)).GetAllocateBuffers)(this, pballocbuffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferSize(this: *mut core::ffi::c_void, buffersize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAllocatorProperties", "SetBufferSize", 7usize, |this| ((**(this as *const *const ITAllocatorProperties_Vtbl)).SetBufferSize)(this, buffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferSize(this: *mut core::ffi::c_void, pbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAllocatorProperties", "GetBufferSize", 8usize, |this| ((**(this as *const *const ITAllocatorProperties_Vtbl)).GetBufferSize)(this, pbuffersize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_DirectShow")]
+            SetAllocatorProperties,
+            #[cfg(not(feature = "Win32_Media_DirectShow"))]
+            SetAllocatorProperties: 0,
+            #[cfg(feature = "Win32_Media_DirectShow")]
+            GetAllocatorProperties,
+            #[cfg(not(feature = "Win32_Media_DirectShow"))]
+            GetAllocatorProperties: 0,
+            SetAllocateBuffers,
+            GetAllocateBuffers,
+            SetBufferSize,
+            GetBufferSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAllocatorProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITAutomatedPhoneControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartTone(this: *mut core::ffi::c_void, tone: PHONE_TONE, lduration: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "StartTone", 7usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).StartTone)(this, tone, lduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopTone(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "StopTone", 8usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).StopTone)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tone(this: *mut core::ffi::c_void, ptone: *mut PHONE_TONE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "Tone", 9usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).Tone)(this, ptone), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartRinger(this: *mut core::ffi::c_void, lringmode: i32, lduration: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "StartRinger", 10usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).StartRinger)(this, lringmode, lduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopRinger(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "StopRinger", 11usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).StopRinger)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ringer(this: *mut core::ffi::c_void, pfringing: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "Ringer", 12usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).Ringer)(this, pfringing), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPhoneHandlingEnabled(this: *mut core::ffi::c_void, fenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "SetPhoneHandlingEnabled", 13usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).SetPhoneHandlingEnabled)(this, fenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhoneHandlingEnabled(this: *mut core::ffi::c_void, pfenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "PhoneHandlingEnabled", 14usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).PhoneHandlingEnabled)(this, pfenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoEndOfNumberTimeout(this: *mut core::ffi::c_void, ltimeout: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "SetAutoEndOfNumberTimeout", 15usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).SetAutoEndOfNumberTimeout)(this, ltimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoEndOfNumberTimeout(this: *mut core::ffi::c_void, pltimeout: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "AutoEndOfNumberTimeout", 16usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).AutoEndOfNumberTimeout)(this, pltimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoDialtone(this: *mut core::ffi::c_void, fenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "SetAutoDialtone", 17usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).SetAutoDialtone)(this, fenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoDialtone(this: *mut core::ffi::c_void, pfenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "AutoDialtone", 18usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).AutoDialtone)(this, pfenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoStopTonesOnOnHook(this: *mut core::ffi::c_void, fenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "SetAutoStopTonesOnOnHook", 19usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).SetAutoStopTonesOnOnHook)(this, fenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoStopTonesOnOnHook(this: *mut core::ffi::c_void, pfenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "AutoStopTonesOnOnHook", 20usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).AutoStopTonesOnOnHook)(this, pfenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoStopRingOnOffHook(this: *mut core::ffi::c_void, fenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "SetAutoStopRingOnOffHook", 21usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).SetAutoStopRingOnOffHook)(this, fenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoStopRingOnOffHook(this: *mut core::ffi::c_void, pfenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "AutoStopRingOnOffHook", 22usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).AutoStopRingOnOffHook)(this, pfenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoKeypadTones(this: *mut core::ffi::c_void, fenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "SetAutoKeypadTones", 23usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).SetAutoKeypadTones)(this, fenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoKeypadTones(this: *mut core::ffi::c_void, pfenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "AutoKeypadTones", 24usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).AutoKeypadTones)(this, pfenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoKeypadTonesMinimumDuration(this: *mut core::ffi::c_void, lduration: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "SetAutoKeypadTonesMinimumDuration", 25usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).SetAutoKeypadTonesMinimumDuration)(this, lduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoKeypadTonesMinimumDuration(this: *mut core::ffi::c_void, plduration: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "AutoKeypadTonesMinimumDuration", 26usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).AutoKeypadTonesMinimumDuration)(this, plduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoVolumeControl(this: *mut core::ffi::c_void, fenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "SetAutoVolumeControl", 27usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).SetAutoVolumeControl)(this, fenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoVolumeControl(this: *mut core::ffi::c_void, fenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "AutoVolumeControl", 28usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).AutoVolumeControl)(this, fenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoVolumeControlStep(this: *mut core::ffi::c_void, lstepsize: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "SetAutoVolumeControlStep", 29usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).SetAutoVolumeControlStep)(this, lstepsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoVolumeControlStep(this: *mut core::ffi::c_void, plstepsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "AutoVolumeControlStep", 30usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).AutoVolumeControlStep)(this, plstepsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoVolumeControlRepeatDelay(this: *mut core::ffi::c_void, ldelay: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "SetAutoVolumeControlRepeatDelay", 31usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).SetAutoVolumeControlRepeatDelay)(this, ldelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoVolumeControlRepeatDelay(this: *mut core::ffi::c_void, pldelay: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "AutoVolumeControlRepeatDelay", 32usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).AutoVolumeControlRepeatDelay)(this, pldelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoVolumeControlRepeatPeriod(this: *mut core::ffi::c_void, lperiod: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "SetAutoVolumeControlRepeatPeriod", 33usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).SetAutoVolumeControlRepeatPeriod)(this, lperiod), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoVolumeControlRepeatPeriod(this: *mut core::ffi::c_void, plperiod: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "AutoVolumeControlRepeatPeriod", 34usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).AutoVolumeControlRepeatPeriod)(this, plperiod), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SelectCall(this: *mut core::ffi::c_void, pcall: *mut core::ffi::c_void, fselectdefaultterminals: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "SelectCall", 35usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).SelectCall)(this, pcall, fselectdefaultterminals), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UnselectCall(this: *mut core::ffi::c_void, pcall: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "UnselectCall", 36usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).UnselectCall)(this, pcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateSelectedCalls(this: *mut core::ffi::c_void, ppcallenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "EnumerateSelectedCalls", 37usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).EnumerateSelectedCalls)(this, ppcallenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectedCalls(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITAutomatedPhoneControl", "SelectedCalls", 38usize, |this| ((**(this as *const *const ITAutomatedPhoneControl_Vtbl)).SelectedCalls)(this, pvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartTone,
+            StopTone,
+            Tone,
+            StartRinger,
+            StopRinger,
+            Ringer,
+            SetPhoneHandlingEnabled,
+            PhoneHandlingEnabled,
+            SetAutoEndOfNumberTimeout,
+            AutoEndOfNumberTimeout,
+            SetAutoDialtone,
+            AutoDialtone,
+            SetAutoStopTonesOnOnHook,
+            AutoStopTonesOnOnHook,
+            SetAutoStopRingOnOffHook,
+            AutoStopRingOnOffHook,
+            SetAutoKeypadTones,
+            AutoKeypadTones,
+            SetAutoKeypadTonesMinimumDuration,
+            AutoKeypadTonesMinimumDuration,
+            SetAutoVolumeControl,
+            AutoVolumeControl,
+            SetAutoVolumeControlStep,
+            AutoVolumeControlStep,
+            SetAutoVolumeControlRepeatDelay,
+            AutoVolumeControlRepeatDelay,
+            SetAutoVolumeControlRepeatPeriod,
+            AutoVolumeControlRepeatPeriod,
+            #[cfg(feature = "Win32_System_Com")]
+            SelectCall,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SelectCall: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            UnselectCall,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UnselectCall: 0,
+            EnumerateSelectedCalls,
+            SelectedCalls,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITAutomatedPhoneControl as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITBasicAudioTerminal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetVolume(this: *mut core::ffi::c_void, lvolume: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicAudioTerminal", "SetVolume", 7usize, |this| ((**(this as *const *const ITBasicAudioTerminal_Vtbl)).SetVolume)(this, lvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn Volume(this: *mut core::ffi::c_void, plvolume: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicAudioTerminal", "Volume", 8usize, |this| ((**(this as *const *const ITBasicAudioTerminal_Vtbl)).Volume)(this, plvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBalance(this: *mut core::ffi::c_void, lbalance: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicAudioTerminal", "SetBalance", 9usize, |this| ((**(this as *const *const ITBasicAudioTerminal_Vtbl)).SetBalance)(this, lbalance), |result| Some(*result))
+        }
+        unsafe extern "system" fn Balance(this: *mut core::ffi::c_void, plbalance: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicAudioTerminal", "Balance", 10usize, |this| ((**(this as *const *const ITBasicAudioTerminal_Vtbl)).Balance)(this, plbalance), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetVolume, Volume, SetBalance, Balance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITBasicAudioTerminal as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITBasicCallControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, fsync: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "Connect", 7usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).Connect)(this, fsync), |result| Some(*result))
+        }
+        unsafe extern "system" fn Answer(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "Answer", 8usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).Answer)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void, code: DISCONNECT_CODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "Disconnect", 9usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).Disconnect)(this, code), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hold(this: *mut core::ffi::c_void, fhold: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "Hold", 10usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).Hold)(this, fhold), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandoffDirect(this: *mut core::ffi::c_void, papplicationname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "HandoffDirect", 11usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).HandoffDirect)(this, papplicationname), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandoffIndirect(this: *mut core::ffi::c_void, lmediatype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "HandoffIndirect", 12usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).HandoffIndirect)(this, lmediatype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Conference(this: *mut core::ffi::c_void, pcall: *mut core::ffi::c_void, fsync: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "Conference", 13usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).Conference)(this, pcall, fsync), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Transfer(this: *mut core::ffi::c_void, pcall: *mut core::ffi::c_void, fsync: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "Transfer", 14usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).Transfer)(this, pcall, fsync), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlindTransfer(this: *mut core::ffi::c_void, pdestaddress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "BlindTransfer", 15usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).BlindTransfer)(this, pdestaddress), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SwapHold(this: *mut core::ffi::c_void, pcall: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "SwapHold", 16usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).SwapHold)(this, pcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParkDirect(this: *mut core::ffi::c_void, pparkaddress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "ParkDirect", 17usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).ParkDirect)(this, pparkaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParkIndirect(this: *mut core::ffi::c_void, ppnondiraddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "ParkIndirect", 18usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).ParkIndirect)(this, ppnondiraddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unpark(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "Unpark", 19usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).Unpark)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQOS(this: *mut core::ffi::c_void, lmediatype: i32, servicelevel: QOS_SERVICE_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "SetQOS", 20usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).SetQOS)(this, lmediatype, servicelevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pickup(this: *mut core::ffi::c_void, pgroupid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "Pickup", 21usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).Pickup)(this, pgroupid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Dial(this: *mut core::ffi::c_void, pdestaddress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "Dial", 22usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).Dial)(this, pdestaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish(this: *mut core::ffi::c_void, finishmode: FINISH_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "Finish", 23usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).Finish)(this, finishmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFromConference(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl", "RemoveFromConference", 24usize, |this| ((**(this as *const *const ITBasicCallControl_Vtbl)).RemoveFromConference)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Connect,
+            Answer,
+            Disconnect,
+            Hold,
+            HandoffDirect,
+            HandoffIndirect,
+            #[cfg(feature = "Win32_System_Com")]
+            Conference,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Conference: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Transfer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Transfer: 0,
+            BlindTransfer,
+            #[cfg(feature = "Win32_System_Com")]
+            SwapHold,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SwapHold: 0,
+            ParkDirect,
+            ParkIndirect,
+            Unpark,
+            SetQOS,
+            Pickup,
+            Dial,
+            Finish,
+            RemoveFromConference,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITBasicCallControl as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITBasicCallControl2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RequestTerminal(this: *mut core::ffi::c_void, bstrterminalclassguid: core::mem::MaybeUninit<windows_core::BSTR>, lmediatype: i32, direction: TERMINAL_DIRECTION, ppterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl2", "RequestTerminal", 25usize, |this| ((**(this as *const *const ITBasicCallControl2_Vtbl)).RequestTerminal)(this, bstrterminalclassguid, lmediatype, direction, ppterminal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SelectTerminalOnCall(this: *mut core::ffi::c_void, pterminal: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl2", "SelectTerminalOnCall", 26usize, |this| ((**(this as *const *const ITBasicCallControl2_Vtbl)).SelectTerminalOnCall)(this, pterminal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UnselectTerminalOnCall(this: *mut core::ffi::c_void, pterminal: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITBasicCallControl2", "UnselectTerminalOnCall", 27usize, |this| ((**(this as *const *const ITBasicCallControl2_Vtbl)).UnselectTerminalOnCall)(this, pterminal), |result| Some(*result))
+        }
+        Self {
+            base__: <ITBasicCallControl_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            RequestTerminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RequestTerminal: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SelectTerminalOnCall,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SelectTerminalOnCall: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            UnselectTerminalOnCall,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UnselectTerminalOnCall: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITBasicCallControl2 as windows_core::Interface>::IID || <ITBasicCallControl_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITCallHub_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallHub", "Clear", 7usize, |this| ((**(this as *const *const ITCallHub_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateCalls(this: *mut core::ffi::c_void, ppenumcall: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallHub", "EnumerateCalls", 8usize, |this| ((**(this as *const *const ITCallHub_Vtbl)).EnumerateCalls)(this, ppenumcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn Calls(this: *mut core::ffi::c_void, pcalls: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallHub", "Calls", 9usize, |this| ((**(this as *const *const ITCallHub_Vtbl)).Calls)(this, pcalls), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumCalls(this: *mut core::ffi::c_void, plcalls: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallHub", "NumCalls", 10usize, |this| ((**(this as *const *const ITCallHub_Vtbl)).NumCalls)(this, plcalls), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, pstate: *mut CALLHUB_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallHub", "State", 11usize, |this| ((**(this as *const *const ITCallHub_Vtbl)).State)(this, pstate), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clear, EnumerateCalls, Calls, NumCalls, State }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITCallHub as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITCallHubEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Event(this: *mut core::ffi::c_void, pevent: *mut CALLHUB_EVENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallHubEvent", "Event", 7usize, |this| ((**(this as *const *const ITCallHubEvent_Vtbl)).Event)(this, pevent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CallHub(this: *mut core::ffi::c_void, ppcallhub: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallHubEvent", "CallHub", 8usize, |this| ((**(this as *const *const ITCallHubEvent_Vtbl)).CallHub)(this, ppcallhub), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcall: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallHubEvent", "Call", 9usize, |this| ((**(this as *const *const ITCallHubEvent_Vtbl)).Call)(this, ppcall), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Event,
+            #[cfg(feature = "Win32_System_Com")]
+            CallHub,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CallHub: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITCallHubEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITCallInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, ppaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfo", "Address", 7usize, |this| ((**(this as *const *const ITCallInfo_Vtbl)).Address)(this, ppaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallState(this: *mut core::ffi::c_void, pcallstate: *mut CALL_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfo", "CallState", 8usize, |this| ((**(this as *const *const ITCallInfo_Vtbl)).CallState)(this, pcallstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Privilege(this: *mut core::ffi::c_void, pprivilege: *mut CALL_PRIVILEGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfo", "Privilege", 9usize, |this| ((**(this as *const *const ITCallInfo_Vtbl)).Privilege)(this, pprivilege), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CallHub(this: *mut core::ffi::c_void, ppcallhub: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfo", "CallHub", 10usize, |this| ((**(this as *const *const ITCallInfo_Vtbl)).CallHub)(this, ppcallhub), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_CallInfoLong(this: *mut core::ffi::c_void, callinfolong: CALLINFO_LONG, plcallinfolongval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfo", "get_CallInfoLong", 11usize, |this| ((**(this as *const *const ITCallInfo_Vtbl)).get_CallInfoLong)(this, callinfolong, plcallinfolongval), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_CallInfoLong(this: *mut core::ffi::c_void, callinfolong: CALLINFO_LONG, lcallinfolongval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfo", "put_CallInfoLong", 12usize, |this| ((**(this as *const *const ITCallInfo_Vtbl)).put_CallInfoLong)(this, callinfolong, lcallinfolongval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_CallInfoString(this: *mut core::ffi::c_void, callinfostring: CALLINFO_STRING, ppcallinfostring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfo", "get_CallInfoString", 13usize, |this| ((**(this as *const *const ITCallInfo_Vtbl)).get_CallInfoString)(this, callinfostring, ppcallinfostring), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_CallInfoString(this: *mut core::ffi::c_void, callinfostring: CALLINFO_STRING, pcallinfostring: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfo", "put_CallInfoString", 14usize, |this| ((**(this as *const *const ITCallInfo_Vtbl)).put_CallInfoString)(this, callinfostring, pcallinfostring), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_CallInfoBuffer(this: *mut core::ffi::c_void, callinfobuffer: CALLINFO_BUFFER, ppcallinfobuffer: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfo", "get_CallInfoBuffer", 15usize, |this| ((**(this as *const *const ITCallInfo_Vtbl)).get_CallInfoBuffer)(this, callinfobuffer, ppcallinfobuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_CallInfoBuffer(this: *mut core::ffi::c_void, callinfobuffer: CALLINFO_BUFFER, pcallinfobuffer: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfo", "put_CallInfoBuffer", 16usize, |this| ((**(this as *const *const ITCallInfo_Vtbl)).put_CallInfoBuffer)(this, callinfobuffer, pcallinfobuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCallInfoBuffer(this: *mut core::ffi::c_void, callinfobuffer: CALLINFO_BUFFER, pdwsize: *mut u32, ppcallinfobuffer: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfo", "GetCallInfoBuffer", 17usize, |this| ((**(this as *const *const ITCallInfo_Vtbl)).GetCallInfoBuffer)(this, callinfobuffer, pdwsize, ppcallinfobuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCallInfoBuffer(this: *mut core::ffi::c_void, callinfobuffer: CALLINFO_BUFFER, dwsize: u32, pcallinfobuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfo", "SetCallInfoBuffer", 18usize, |this| ((**(this as *const *const ITCallInfo_Vtbl)).SetCallInfoBuffer)(this, callinfobuffer, dwsize, pcallinfobuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseUserUserInfo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfo", "ReleaseUserUserInfo", 19usize, |this| ((**(this as *const *const ITCallInfo_Vtbl)).ReleaseUserUserInfo)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Address,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Address: 0,
+            CallState,
+            Privilege,
+            #[cfg(feature = "Win32_System_Com")]
+            CallHub,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CallHub: 0,
+            get_CallInfoLong,
+            put_CallInfoLong,
+            get_CallInfoString,
+            put_CallInfoString,
+            get_CallInfoBuffer,
+            put_CallInfoBuffer,
+            GetCallInfoBuffer,
+            SetCallInfoBuffer,
+            ReleaseUserUserInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITCallInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITCallInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_EventFilter(this: *mut core::ffi::c_void, tapievent: TAPI_EVENT, lsubevent: i32, penable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfo2", "get_EventFilter", 20usize, |this| ((**(this as *const *const ITCallInfo2_Vtbl)).get_EventFilter)(this, tapievent, lsubevent, penable), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_EventFilter(this: *mut core::ffi::c_void, tapievent: TAPI_EVENT, lsubevent: i32, benable: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfo2", "put_EventFilter", 21usize, |this| ((**(this as *const *const ITCallInfo2_Vtbl)).put_EventFilter)(this, tapievent, lsubevent, benable), |result| Some(*result))
+        }
+        Self { base__: <ITCallInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_EventFilter, put_EventFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITCallInfo2 as windows_core::Interface>::IID || <ITCallInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITCallInfoChangeEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcall: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfoChangeEvent", "Call", 7usize, |this| ((**(this as *const *const ITCallInfoChangeEvent_Vtbl)).Call)(this, ppcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cause(this: *mut core::ffi::c_void, pcic: *mut CALLINFOCHANGE_CAUSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfoChangeEvent", "Cause", 8usize, |this| ((**(this as *const *const ITCallInfoChangeEvent_Vtbl)).Cause)(this, pcic), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallbackInstance(this: *mut core::ffi::c_void, plcallbackinstance: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallInfoChangeEvent", "CallbackInstance", 9usize, |this| ((**(this as *const *const ITCallInfoChangeEvent_Vtbl)).CallbackInstance)(this, plcallbackinstance), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+            Cause,
+            CallbackInstance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITCallInfoChangeEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITCallMediaEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcallinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallMediaEvent", "Call", 7usize, |this| ((**(this as *const *const ITCallMediaEvent_Vtbl)).Call)(this, ppcallinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Event(this: *mut core::ffi::c_void, pcallmediaevent: *mut CALL_MEDIA_EVENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallMediaEvent", "Event", 8usize, |this| ((**(this as *const *const ITCallMediaEvent_Vtbl)).Event)(this, pcallmediaevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, phrerror: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallMediaEvent", "Error", 9usize, |this| ((**(this as *const *const ITCallMediaEvent_Vtbl)).Error)(this, phrerror), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Terminal(this: *mut core::ffi::c_void, ppterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallMediaEvent", "Terminal", 10usize, |this| ((**(this as *const *const ITCallMediaEvent_Vtbl)).Terminal)(this, ppterminal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Stream(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallMediaEvent", "Stream", 11usize, |this| ((**(this as *const *const ITCallMediaEvent_Vtbl)).Stream)(this, ppstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cause(this: *mut core::ffi::c_void, pcause: *mut CALL_MEDIA_EVENT_CAUSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallMediaEvent", "Cause", 12usize, |this| ((**(this as *const *const ITCallMediaEvent_Vtbl)).Cause)(this, pcause), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+            Event,
+            Error,
+            #[cfg(feature = "Win32_System_Com")]
+            Terminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Terminal: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Stream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Stream: 0,
+            Cause,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITCallMediaEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITCallNotificationEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcall: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallNotificationEvent", "Call", 7usize, |this| ((**(this as *const *const ITCallNotificationEvent_Vtbl)).Call)(this, ppcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn Event(this: *mut core::ffi::c_void, pcallnotificationevent: *mut CALL_NOTIFICATION_EVENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallNotificationEvent", "Event", 8usize, |this| ((**(this as *const *const ITCallNotificationEvent_Vtbl)).Event)(this, pcallnotificationevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallbackInstance(this: *mut core::ffi::c_void, plcallbackinstance: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallNotificationEvent", "CallbackInstance", 9usize, |this| ((**(this as *const *const ITCallNotificationEvent_Vtbl)).CallbackInstance)(this, plcallbackinstance), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+            Event,
+            CallbackInstance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITCallNotificationEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITCallStateEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcallinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallStateEvent", "Call", 7usize, |this| ((**(this as *const *const ITCallStateEvent_Vtbl)).Call)(this, ppcallinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, pcallstate: *mut CALL_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallStateEvent", "State", 8usize, |this| ((**(this as *const *const ITCallStateEvent_Vtbl)).State)(this, pcallstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cause(this: *mut core::ffi::c_void, pcec: *mut CALL_STATE_EVENT_CAUSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallStateEvent", "Cause", 9usize, |this| ((**(this as *const *const ITCallStateEvent_Vtbl)).Cause)(this, pcec), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallbackInstance(this: *mut core::ffi::c_void, plcallbackinstance: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallStateEvent", "CallbackInstance", 10usize, |this| ((**(this as *const *const ITCallStateEvent_Vtbl)).CallbackInstance)(this, plcallbackinstance), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+            State,
+            Cause,
+            CallbackInstance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITCallStateEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITCallingCard_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PermanentCardID(this: *mut core::ffi::c_void, plcardid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallingCard", "PermanentCardID", 7usize, |this| ((**(this as *const *const ITCallingCard_Vtbl)).PermanentCardID)(this, plcardid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfDigits(this: *mut core::ffi::c_void, pldigits: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallingCard", "NumberOfDigits", 8usize, |this| ((**(this as *const *const ITCallingCard_Vtbl)).NumberOfDigits)(this, pldigits), |result| Some(*result))
+        }
+        unsafe extern "system" fn Options(this: *mut core::ffi::c_void, ploptions: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallingCard", "Options", 9usize, |this| ((**(this as *const *const ITCallingCard_Vtbl)).Options)(this, ploptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn CardName(this: *mut core::ffi::c_void, ppcardname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallingCard", "CardName", 10usize, |this| ((**(this as *const *const ITCallingCard_Vtbl)).CardName)(this, ppcardname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SameAreaDialingRule(this: *mut core::ffi::c_void, pprule: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallingCard", "SameAreaDialingRule", 11usize, |this| ((**(this as *const *const ITCallingCard_Vtbl)).SameAreaDialingRule)(this, pprule), |result| Some(*result))
+        }
+        unsafe extern "system" fn LongDistanceDialingRule(this: *mut core::ffi::c_void, pprule: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallingCard", "LongDistanceDialingRule", 12usize, |this| ((**(this as *const *const ITCallingCard_Vtbl)).LongDistanceDialingRule)(this, pprule), |result| Some(*result))
+        }
+        unsafe extern "system" fn InternationalDialingRule(this: *mut core::ffi::c_void, pprule: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCallingCard", "InternationalDialingRule", 13usize, |this| ((**(this as *const *const ITCallingCard_Vtbl)).InternationalDialingRule)(this, pprule), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PermanentCardID,
+            NumberOfDigits,
+            Options,
+            CardName,
+            SameAreaDialingRule,
+            LongDistanceDialingRule,
+            InternationalDialingRule,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITCallingCard as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, lcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCollection", "Count", 7usize, |this| ((**(this as *const *const ITCollection_Vtbl)).Count)(this, lcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCollection", "get_Item", 8usize, |this| ((**(this as *const *const ITCollection_Vtbl)).get_Item)(this, index, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppnewenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const ITCollection_Vtbl))._NewEnum)(this, ppnewenum), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, get_Item, _NewEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITCollection2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, index: i32, pvariant: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCollection2", "Add", 10usize, |this| ((**(this as *const *const ITCollection2_Vtbl)).Add)(this, index, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCollection2", "Remove", 11usize, |this| ((**(this as *const *const ITCollection2_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        Self { base__: <ITCollection_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Add, Remove }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITCollection2 as windows_core::Interface>::IID || <ITCollection_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITCustomTone_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Frequency(this: *mut core::ffi::c_void, plfrequency: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCustomTone", "Frequency", 7usize, |this| ((**(this as *const *const ITCustomTone_Vtbl)).Frequency)(this, plfrequency), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrequency(this: *mut core::ffi::c_void, lfrequency: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCustomTone", "SetFrequency", 8usize, |this| ((**(this as *const *const ITCustomTone_Vtbl)).SetFrequency)(this, lfrequency), |result| Some(*result))
+        }
+        unsafe extern "system" fn CadenceOn(this: *mut core::ffi::c_void, plcadenceon: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCustomTone", "CadenceOn", 9usize, |this| ((**(this as *const *const ITCustomTone_Vtbl)).CadenceOn)(this, plcadenceon), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCadenceOn(this: *mut core::ffi::c_void, cadenceon: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCustomTone", "SetCadenceOn", 10usize, |this| ((**(this as *const *const ITCustomTone_Vtbl)).SetCadenceOn)(this, cadenceon), |result| Some(*result))
+        }
+        unsafe extern "system" fn CadenceOff(this: *mut core::ffi::c_void, plcadenceoff: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCustomTone", "CadenceOff", 11usize, |this| ((**(this as *const *const ITCustomTone_Vtbl)).CadenceOff)(this, plcadenceoff), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCadenceOff(this: *mut core::ffi::c_void, lcadenceoff: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCustomTone", "SetCadenceOff", 12usize, |this| ((**(this as *const *const ITCustomTone_Vtbl)).SetCadenceOff)(this, lcadenceoff), |result| Some(*result))
+        }
+        unsafe extern "system" fn Volume(this: *mut core::ffi::c_void, plvolume: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCustomTone", "Volume", 13usize, |this| ((**(this as *const *const ITCustomTone_Vtbl)).Volume)(this, plvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolume(this: *mut core::ffi::c_void, lvolume: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITCustomTone", "SetVolume", 14usize, |this| ((**(this as *const *const ITCustomTone_Vtbl)).SetVolume)(this, lvolume), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Frequency,
+            SetFrequency,
+            CadenceOn,
+            SetCadenceOn,
+            CadenceOff,
+            SetCadenceOff,
+            Volume,
+            SetVolume,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITCustomTone as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITDetectTone_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppSpecific(this: *mut core::ffi::c_void, plappspecific: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDetectTone", "AppSpecific", 7usize, |this| ((**(this as *const *const ITDetectTone_Vtbl)).AppSpecific)(this, plappspecific), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppSpecific(this: *mut core::ffi::c_void, lappspecific: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDetectTone", "SetAppSpecific", 8usize, |this| ((**(this as *const *const ITDetectTone_Vtbl)).SetAppSpecific)(this, lappspecific), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, plduration: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDetectTone", "Duration", 9usize, |this| ((**(this as *const *const ITDetectTone_Vtbl)).Duration)(this, plduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, lduration: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDetectTone", "SetDuration", 10usize, |this| ((**(this as *const *const ITDetectTone_Vtbl)).SetDuration)(this, lduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Frequency(this: *mut core::ffi::c_void, index: i32, plfrequency: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDetectTone", "get_Frequency", 11usize, |this| ((**(this as *const *const ITDetectTone_Vtbl)).get_Frequency)(this, index, plfrequency), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Frequency(this: *mut core::ffi::c_void, index: i32, lfrequency: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDetectTone", "put_Frequency", 12usize, |this| ((**(this as *const *const ITDetectTone_Vtbl)).put_Frequency)(this, index, lfrequency), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppSpecific,
+            SetAppSpecific,
+            Duration,
+            SetDuration,
+            get_Frequency,
+            put_Frequency,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITDetectTone as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITDigitDetectionEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcallinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDigitDetectionEvent", "Call", 7usize, |this| ((**(this as *const *const ITDigitDetectionEvent_Vtbl)).Call)(this, ppcallinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Digit(this: *mut core::ffi::c_void, pucdigit: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDigitDetectionEvent", "Digit", 8usize, |this| ((**(this as *const *const ITDigitDetectionEvent_Vtbl)).Digit)(this, pucdigit), |result| Some(*result))
+        }
+        unsafe extern "system" fn DigitMode(this: *mut core::ffi::c_void, pdigitmode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDigitDetectionEvent", "DigitMode", 9usize, |this| ((**(this as *const *const ITDigitDetectionEvent_Vtbl)).DigitMode)(this, pdigitmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn TickCount(this: *mut core::ffi::c_void, pltickcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDigitDetectionEvent", "TickCount", 10usize, |this| ((**(this as *const *const ITDigitDetectionEvent_Vtbl)).TickCount)(this, pltickcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallbackInstance(this: *mut core::ffi::c_void, plcallbackinstance: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDigitDetectionEvent", "CallbackInstance", 11usize, |this| ((**(this as *const *const ITDigitDetectionEvent_Vtbl)).CallbackInstance)(this, plcallbackinstance), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+            Digit,
+            DigitMode,
+            TickCount,
+            CallbackInstance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITDigitDetectionEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITDigitGenerationEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcallinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDigitGenerationEvent", "Call", 7usize, |this| ((**(this as *const *const ITDigitGenerationEvent_Vtbl)).Call)(this, ppcallinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerationTermination(this: *mut core::ffi::c_void, plgenerationtermination: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDigitGenerationEvent", "GenerationTermination", 8usize, |this| ((**(this as *const *const ITDigitGenerationEvent_Vtbl)).GenerationTermination)(this, plgenerationtermination), |result| Some(*result))
+        }
+        unsafe extern "system" fn TickCount(this: *mut core::ffi::c_void, pltickcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDigitGenerationEvent", "TickCount", 9usize, |this| ((**(this as *const *const ITDigitGenerationEvent_Vtbl)).TickCount)(this, pltickcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallbackInstance(this: *mut core::ffi::c_void, plcallbackinstance: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDigitGenerationEvent", "CallbackInstance", 10usize, |this| ((**(this as *const *const ITDigitGenerationEvent_Vtbl)).CallbackInstance)(this, plcallbackinstance), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+            GenerationTermination,
+            TickCount,
+            CallbackInstance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITDigitGenerationEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITDigitsGatheredEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcallinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDigitsGatheredEvent", "Call", 7usize, |this| ((**(this as *const *const ITDigitsGatheredEvent_Vtbl)).Call)(this, ppcallinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Digits(this: *mut core::ffi::c_void, ppdigits: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDigitsGatheredEvent", "Digits", 8usize, |this| ((**(this as *const *const ITDigitsGatheredEvent_Vtbl)).Digits)(this, ppdigits), |result| Some(*result))
+        }
+        unsafe extern "system" fn GatherTermination(this: *mut core::ffi::c_void, pgathertermination: *mut TAPI_GATHERTERM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDigitsGatheredEvent", "GatherTermination", 9usize, |this| ((**(this as *const *const ITDigitsGatheredEvent_Vtbl)).GatherTermination)(this, pgathertermination), |result| Some(*result))
+        }
+        unsafe extern "system" fn TickCount(this: *mut core::ffi::c_void, pltickcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDigitsGatheredEvent", "TickCount", 10usize, |this| ((**(this as *const *const ITDigitsGatheredEvent_Vtbl)).TickCount)(this, pltickcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallbackInstance(this: *mut core::ffi::c_void, plcallbackinstance: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDigitsGatheredEvent", "CallbackInstance", 11usize, |this| ((**(this as *const *const ITDigitsGatheredEvent_Vtbl)).CallbackInstance)(this, plcallbackinstance), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+            Digits,
+            GatherTermination,
+            TickCount,
+            CallbackInstance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITDigitsGatheredEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITDirectory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DirectoryType(this: *mut core::ffi::c_void, pdirectorytype: *mut DIRECTORY_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectory", "DirectoryType", 7usize, |this| ((**(this as *const *const ITDirectory_Vtbl)).DirectoryType)(this, pdirectorytype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectory", "DisplayName", 8usize, |this| ((**(this as *const *const ITDirectory_Vtbl)).DisplayName)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDynamic(this: *mut core::ffi::c_void, pfdynamic: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectory", "IsDynamic", 9usize, |this| ((**(this as *const *const ITDirectory_Vtbl)).IsDynamic)(this, pfdynamic), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultObjectTTL(this: *mut core::ffi::c_void, pttl: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectory", "DefaultObjectTTL", 10usize, |this| ((**(this as *const *const ITDirectory_Vtbl)).DefaultObjectTTL)(this, pttl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultObjectTTL(this: *mut core::ffi::c_void, ttl: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectory", "SetDefaultObjectTTL", 11usize, |this| ((**(this as *const *const ITDirectory_Vtbl)).SetDefaultObjectTTL)(this, ttl), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableAutoRefresh(this: *mut core::ffi::c_void, fenable: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectory", "EnableAutoRefresh", 12usize, |this| ((**(this as *const *const ITDirectory_Vtbl)).EnableAutoRefresh)(this, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, fsecure: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectory", "Connect", 13usize, |this| ((**(this as *const *const ITDirectory_Vtbl)).Connect)(this, fsecure), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bind(this: *mut core::ffi::c_void, pdomainname: core::mem::MaybeUninit<windows_core::BSTR>, pusername: core::mem::MaybeUninit<windows_core::BSTR>, ppassword: core::mem::MaybeUninit<windows_core::BSTR>, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectory", "Bind", 14usize, |this| ((**(this as *const *const ITDirectory_Vtbl)).Bind)(this, pdomainname, pusername, ppassword, lflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddDirectoryObject(this: *mut core::ffi::c_void, pdirectoryobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectory", "AddDirectoryObject", 15usize, |this| ((**(this as *const *const ITDirectory_Vtbl)).AddDirectoryObject)(this, pdirectoryobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ModifyDirectoryObject(this: *mut core::ffi::c_void, pdirectoryobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectory", "ModifyDirectoryObject", 16usize, |this| ((**(this as *const *const ITDirectory_Vtbl)).ModifyDirectoryObject)(this, pdirectoryobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RefreshDirectoryObject(this: *mut core::ffi::c_void, pdirectoryobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectory", "RefreshDirectoryObject", 17usize, |this| ((**(this as *const *const ITDirectory_Vtbl)).RefreshDirectoryObject)(this, pdirectoryobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DeleteDirectoryObject(this: *mut core::ffi::c_void, pdirectoryobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectory", "DeleteDirectoryObject", 18usize, |this| ((**(this as *const *const ITDirectory_Vtbl)).DeleteDirectoryObject)(this, pdirectoryobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_DirectoryObjects(this: *mut core::ffi::c_void, directoryobjecttype: DIRECTORY_OBJECT_TYPE, pname: core::mem::MaybeUninit<windows_core::BSTR>, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectory", "get_DirectoryObjects", 19usize, |this| ((**(this as *const *const ITDirectory_Vtbl)).get_DirectoryObjects)(this, directoryobjecttype, pname, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateDirectoryObjects(this: *mut core::ffi::c_void, directoryobjecttype: DIRECTORY_OBJECT_TYPE, pname: core::mem::MaybeUninit<windows_core::BSTR>, ppenumobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectory", "EnumerateDirectoryObjects", 20usize, |this| ((**(this as *const *const ITDirectory_Vtbl)).EnumerateDirectoryObjects)(this, directoryobjecttype, pname, ppenumobject), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DirectoryType,
+            DisplayName,
+            IsDynamic,
+            DefaultObjectTTL,
+            SetDefaultObjectTTL,
+            EnableAutoRefresh,
+            Connect,
+            Bind,
+            #[cfg(feature = "Win32_System_Com")]
+            AddDirectoryObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddDirectoryObject: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ModifyDirectoryObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ModifyDirectoryObject: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RefreshDirectoryObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RefreshDirectoryObject: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DeleteDirectoryObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DeleteDirectoryObject: 0,
+            get_DirectoryObjects,
+            EnumerateDirectoryObjects,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITDirectory as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITDirectoryObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ObjectType(this: *mut core::ffi::c_void, pobjecttype: *mut DIRECTORY_OBJECT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObject", "ObjectType", 7usize, |this| ((**(this as *const *const ITDirectoryObject_Vtbl)).ObjectType)(this, pobjecttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, ppname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObject", "Name", 8usize, |this| ((**(this as *const *const ITDirectoryObject_Vtbl)).Name)(this, ppname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, pname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObject", "SetName", 9usize, |this| ((**(this as *const *const ITDirectoryObject_Vtbl)).SetName)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_DialableAddrs(this: *mut core::ffi::c_void, dwaddresstype: i32, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObject", "get_DialableAddrs", 10usize, |this| ((**(this as *const *const ITDirectoryObject_Vtbl)).get_DialableAddrs)(this, dwaddresstype, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateDialableAddrs(this: *mut core::ffi::c_void, dwaddresstype: u32, ppenumdialableaddrs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObject", "EnumerateDialableAddrs", 11usize, |this| ((**(this as *const *const ITDirectoryObject_Vtbl)).EnumerateDialableAddrs)(this, dwaddresstype, ppenumdialableaddrs), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SecurityDescriptor(this: *mut core::ffi::c_void, ppsecdes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObject", "SecurityDescriptor", 12usize, |this| ((**(this as *const *const ITDirectoryObject_Vtbl)).SecurityDescriptor)(this, ppsecdes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSecurityDescriptor(this: *mut core::ffi::c_void, psecdes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObject", "SetSecurityDescriptor", 13usize, |this| ((**(this as *const *const ITDirectoryObject_Vtbl)).SetSecurityDescriptor)(this, psecdes), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ObjectType,
+            Name,
+            SetName,
+            get_DialableAddrs,
+            EnumerateDialableAddrs,
+            #[cfg(feature = "Win32_System_Com")]
+            SecurityDescriptor,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SecurityDescriptor: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSecurityDescriptor,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSecurityDescriptor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITDirectoryObject as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITDirectoryObjectConference_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Protocol(this: *mut core::ffi::c_void, ppprotocol: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectConference", "Protocol", 7usize, |this| ((**(this as *const *const ITDirectoryObjectConference_Vtbl)).Protocol)(this, ppprotocol), |result| Some(*result))
+        }
+        unsafe extern "system" fn Originator(this: *mut core::ffi::c_void, pporiginator: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectConference", "Originator", 8usize, |this| ((**(this as *const *const ITDirectoryObjectConference_Vtbl)).Originator)(this, pporiginator), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOriginator(this: *mut core::ffi::c_void, poriginator: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectConference", "SetOriginator", 9usize, |this| ((**(this as *const *const ITDirectoryObjectConference_Vtbl)).SetOriginator)(this, poriginator), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdvertisingScope(this: *mut core::ffi::c_void, padvertisingscope: *mut RND_ADVERTISING_SCOPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectConference", "AdvertisingScope", 10usize, |this| ((**(this as *const *const ITDirectoryObjectConference_Vtbl)).AdvertisingScope)(this, padvertisingscope), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAdvertisingScope(this: *mut core::ffi::c_void, advertisingscope: RND_ADVERTISING_SCOPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectConference", "SetAdvertisingScope", 11usize, |this| ((**(this as *const *const ITDirectoryObjectConference_Vtbl)).SetAdvertisingScope)(this, advertisingscope), |result| Some(*result))
+        }
+        unsafe extern "system" fn Url(this: *mut core::ffi::c_void, ppurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectConference", "Url", 12usize, |this| ((**(this as *const *const ITDirectoryObjectConference_Vtbl)).Url)(this, ppurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUrl(this: *mut core::ffi::c_void, purl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectConference", "SetUrl", 13usize, |this| ((**(this as *const *const ITDirectoryObjectConference_Vtbl)).SetUrl)(this, purl), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, ppdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectConference", "Description", 14usize, |this| ((**(this as *const *const ITDirectoryObjectConference_Vtbl)).Description)(this, ppdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, pdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectConference", "SetDescription", 15usize, |this| ((**(this as *const *const ITDirectoryObjectConference_Vtbl)).SetDescription)(this, pdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEncrypted(this: *mut core::ffi::c_void, pfencrypted: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectConference", "IsEncrypted", 16usize, |this| ((**(this as *const *const ITDirectoryObjectConference_Vtbl)).IsEncrypted)(this, pfencrypted), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEncrypted(this: *mut core::ffi::c_void, fencrypted: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectConference", "SetIsEncrypted", 17usize, |this| ((**(this as *const *const ITDirectoryObjectConference_Vtbl)).SetIsEncrypted)(this, fencrypted), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, pdate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectConference", "StartTime", 18usize, |this| ((**(this as *const *const ITDirectoryObjectConference_Vtbl)).StartTime)(this, pdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, date: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectConference", "SetStartTime", 19usize, |this| ((**(this as *const *const ITDirectoryObjectConference_Vtbl)).SetStartTime)(this, date), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopTime(this: *mut core::ffi::c_void, pdate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectConference", "StopTime", 20usize, |this| ((**(this as *const *const ITDirectoryObjectConference_Vtbl)).StopTime)(this, pdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStopTime(this: *mut core::ffi::c_void, date: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectConference", "SetStopTime", 21usize, |this| ((**(this as *const *const ITDirectoryObjectConference_Vtbl)).SetStopTime)(this, date), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Protocol,
+            Originator,
+            SetOriginator,
+            AdvertisingScope,
+            SetAdvertisingScope,
+            Url,
+            SetUrl,
+            Description,
+            SetDescription,
+            IsEncrypted,
+            SetIsEncrypted,
+            StartTime,
+            SetStartTime,
+            StopTime,
+            SetStopTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITDirectoryObjectConference as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITDirectoryObjectUser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IPPhonePrimary(this: *mut core::ffi::c_void, ppname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectUser", "IPPhonePrimary", 7usize, |this| ((**(this as *const *const ITDirectoryObjectUser_Vtbl)).IPPhonePrimary)(this, ppname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIPPhonePrimary(this: *mut core::ffi::c_void, pname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDirectoryObjectUser", "SetIPPhonePrimary", 8usize, |this| ((**(this as *const *const ITDirectoryObjectUser_Vtbl)).SetIPPhonePrimary)(this, pname), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IPPhonePrimary, SetIPPhonePrimary }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITDirectoryObjectUser as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITDispatchMapper_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryDispatchInterface(this: *mut core::ffi::c_void, piid: core::mem::MaybeUninit<windows_core::BSTR>, pinterfacetomap: *mut core::ffi::c_void, ppreturnedinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITDispatchMapper", "QueryDispatchInterface", 7usize, |this| ((**(this as *const *const ITDispatchMapper_Vtbl)).QueryDispatchInterface)(this, piid, pinterfacetomap, ppreturnedinterface), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryDispatchInterface,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryDispatchInterface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITDispatchMapper as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITFileTerminalEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Terminal(this: *mut core::ffi::c_void, ppterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITFileTerminalEvent", "Terminal", 7usize, |this| ((**(this as *const *const ITFileTerminalEvent_Vtbl)).Terminal)(this, ppterminal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Track(this: *mut core::ffi::c_void, pptrackterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITFileTerminalEvent", "Track", 8usize, |this| ((**(this as *const *const ITFileTerminalEvent_Vtbl)).Track)(this, pptrackterminal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcall: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITFileTerminalEvent", "Call", 9usize, |this| ((**(this as *const *const ITFileTerminalEvent_Vtbl)).Call)(this, ppcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, pstate: *mut TERMINAL_MEDIA_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITFileTerminalEvent", "State", 10usize, |this| ((**(this as *const *const ITFileTerminalEvent_Vtbl)).State)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cause(this: *mut core::ffi::c_void, pcause: *mut FT_STATE_EVENT_CAUSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITFileTerminalEvent", "Cause", 11usize, |this| ((**(this as *const *const ITFileTerminalEvent_Vtbl)).Cause)(this, pcause), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, phrerrorcode: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITFileTerminalEvent", "Error", 12usize, |this| ((**(this as *const *const ITFileTerminalEvent_Vtbl)).Error)(this, phrerrorcode), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Terminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Terminal: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Track,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Track: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+            State,
+            Cause,
+            Error,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITFileTerminalEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ITFileTrack_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, ppmt: *mut *mut super::super::Media::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITFileTrack", "Format", 7usize, |this| ((**(this as *const *const ITFileTrack_Vtbl)).Format)(this, ppmt), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, pmt: *const super::super::Media::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITFileTrack", "SetFormat", 8usize, |this| ((**(this as *const *const ITFileTrack_Vtbl)).SetFormat)(this, pmt), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ControllingTerminal(this: *mut core::ffi::c_void, ppcontrollingterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITFileTrack", "ControllingTerminal", 9usize, |this| ((**(this as *const *const ITFileTrack_Vtbl)).ControllingTerminal)(this, ppcontrollingterminal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AudioFormatForScripting(this: *mut core::ffi::c_void, ppaudioformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITFileTrack", "AudioFormatForScripting", 10usize, |this| ((**(this as *const *const ITFileTrack_Vtbl)).AudioFormatForScripting)(this, ppaudioformat), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetAudioFormatForScripting(this: *mut core::ffi::c_void, paudioformat: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITFileTrack", "SetAudioFormatForScripting", 11usize, |this| ((**(this as *const *const ITFileTrack_Vtbl)).SetAudioFormatForScripting)(this, paudioformat), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EmptyAudioFormatForScripting(this: *mut core::ffi::c_void, ppaudioformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITFileTrack", "EmptyAudioFormatForScripting", 12usize, |this| ((**(this as *const *const ITFileTrack_Vtbl)).EmptyAudioFormatForScripting)(this, ppaudioformat), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            Format,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            Format: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            SetFormat,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            SetFormat: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ControllingTerminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ControllingTerminal: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AudioFormatForScripting,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AudioFormatForScripting: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetAudioFormatForScripting,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetAudioFormatForScripting: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EmptyAudioFormatForScripting,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EmptyAudioFormatForScripting: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITFileTrack as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITForwardInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetNumRingsNoAnswer(this: *mut core::ffi::c_void, lnumrings: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITForwardInformation", "SetNumRingsNoAnswer", 7usize, |this| ((**(this as *const *const ITForwardInformation_Vtbl)).SetNumRingsNoAnswer)(this, lnumrings), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumRingsNoAnswer(this: *mut core::ffi::c_void, plnumrings: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITForwardInformation", "NumRingsNoAnswer", 8usize, |this| ((**(this as *const *const ITForwardInformation_Vtbl)).NumRingsNoAnswer)(this, plnumrings), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForwardType(this: *mut core::ffi::c_void, forwardtype: i32, pdestaddress: core::mem::MaybeUninit<windows_core::BSTR>, pcalleraddress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITForwardInformation", "SetForwardType", 9usize, |this| ((**(this as *const *const ITForwardInformation_Vtbl)).SetForwardType)(this, forwardtype, pdestaddress, pcalleraddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ForwardTypeDestination(this: *mut core::ffi::c_void, forwardtype: i32, ppdestaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITForwardInformation", "get_ForwardTypeDestination", 10usize, |this| ((**(this as *const *const ITForwardInformation_Vtbl)).get_ForwardTypeDestination)(this, forwardtype, ppdestaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ForwardTypeCaller(this: *mut core::ffi::c_void, forwardtype: i32, ppcalleraddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITForwardInformation", "get_ForwardTypeCaller", 11usize, |this| ((**(this as *const *const ITForwardInformation_Vtbl)).get_ForwardTypeCaller)(this, forwardtype, ppcalleraddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForwardType(this: *mut core::ffi::c_void, forwardtype: i32, ppdestinationaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>, ppcalleraddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITForwardInformation", "GetForwardType", 12usize, |this| ((**(this as *const *const ITForwardInformation_Vtbl)).GetForwardType)(this, forwardtype, ppdestinationaddress, ppcalleraddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITForwardInformation", "Clear", 13usize, |this| ((**(this as *const *const ITForwardInformation_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetNumRingsNoAnswer,
+            NumRingsNoAnswer,
+            SetForwardType,
+            get_ForwardTypeDestination,
+            get_ForwardTypeCaller,
+            GetForwardType,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITForwardInformation as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITForwardInformation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetForwardType2(this: *mut core::ffi::c_void, forwardtype: i32, pdestaddress: core::mem::MaybeUninit<windows_core::BSTR>, destaddresstype: i32, pcalleraddress: core::mem::MaybeUninit<windows_core::BSTR>, calleraddresstype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITForwardInformation2", "SetForwardType2", 14usize, |this| ((**(this as *const *const ITForwardInformation2_Vtbl)).SetForwardType2)(this, forwardtype, pdestaddress, destaddresstype, pcalleraddress, calleraddresstype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForwardType2(this: *mut core::ffi::c_void, forwardtype: i32, ppdestinationaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>, pdestaddresstype: *mut i32, ppcalleraddress: *mut core::mem::MaybeUninit<windows_core::BSTR>, pcalleraddresstype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITForwardInformation2", "GetForwardType2", 15usize, |this| ((**(this as *const *const ITForwardInformation2_Vtbl)).GetForwardType2)(this, forwardtype, ppdestinationaddress, pdestaddresstype, ppcalleraddress, pcalleraddresstype), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ForwardTypeDestinationAddressType(this: *mut core::ffi::c_void, forwardtype: i32, pdestaddresstype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITForwardInformation2", "get_ForwardTypeDestinationAddressType", 16usize, |this| ((**(this as *const *const ITForwardInformation2_Vtbl)).get_ForwardTypeDestinationAddressType)(this, forwardtype, pdestaddresstype), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ForwardTypeCallerAddressType(this: *mut core::ffi::c_void, forwardtype: i32, pcalleraddresstype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITForwardInformation2", "get_ForwardTypeCallerAddressType", 17usize, |this| ((**(this as *const *const ITForwardInformation2_Vtbl)).get_ForwardTypeCallerAddressType)(this, forwardtype, pcalleraddresstype), |result| Some(*result))
+        }
+        Self {
+            base__: <ITForwardInformation_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetForwardType2,
+            GetForwardType2,
+            get_ForwardTypeDestinationAddressType,
+            get_ForwardTypeCallerAddressType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITForwardInformation2 as windows_core::Interface>::IID || <ITForwardInformation_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITILSConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Port(this: *mut core::ffi::c_void, pport: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITILSConfig", "Port", 7usize, |this| ((**(this as *const *const ITILSConfig_Vtbl)).Port)(this, pport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPort(this: *mut core::ffi::c_void, port: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITILSConfig", "SetPort", 8usize, |this| ((**(this as *const *const ITILSConfig_Vtbl)).SetPort)(this, port), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Port, SetPort }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITILSConfig as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITLegacyAddressMediaControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetID(this: *mut core::ffi::c_void, pdeviceclass: core::mem::MaybeUninit<windows_core::BSTR>, pdwsize: *mut u32, ppdeviceid: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyAddressMediaControl", "GetID", 3usize, |this| ((**(this as *const *const ITLegacyAddressMediaControl_Vtbl)).GetID)(this, pdeviceclass, pdwsize, ppdeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDevConfig(this: *mut core::ffi::c_void, pdeviceclass: core::mem::MaybeUninit<windows_core::BSTR>, pdwsize: *mut u32, ppdeviceconfig: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyAddressMediaControl", "GetDevConfig", 4usize, |this| ((**(this as *const *const ITLegacyAddressMediaControl_Vtbl)).GetDevConfig)(this, pdeviceclass, pdwsize, ppdeviceconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDevConfig(this: *mut core::ffi::c_void, pdeviceclass: core::mem::MaybeUninit<windows_core::BSTR>, dwsize: u32, pdeviceconfig: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyAddressMediaControl", "SetDevConfig", 5usize, |this| ((**(this as *const *const ITLegacyAddressMediaControl_Vtbl)).SetDevConfig)(this, pdeviceclass, dwsize, pdeviceconfig), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetID, GetDevConfig, SetDevConfig }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITLegacyAddressMediaControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITLegacyAddressMediaControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConfigDialog(this: *mut core::ffi::c_void, hwndowner: super::super::Foundation::HWND, pdeviceclass: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyAddressMediaControl2", "ConfigDialog", 6usize, |this| ((**(this as *const *const ITLegacyAddressMediaControl2_Vtbl)).ConfigDialog)(this, hwndowner, pdeviceclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConfigDialogEdit(this: *mut core::ffi::c_void, hwndowner: super::super::Foundation::HWND, pdeviceclass: core::mem::MaybeUninit<windows_core::BSTR>, dwsizein: u32, pdeviceconfigin: *const u8, pdwsizeout: *mut u32, ppdeviceconfigout: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyAddressMediaControl2", "ConfigDialogEdit", 7usize, |this| ((**(this as *const *const ITLegacyAddressMediaControl2_Vtbl)).ConfigDialogEdit)(this, hwndowner, pdeviceclass, dwsizein, pdeviceconfigin, pdwsizeout, ppdeviceconfigout), |result| Some(*result))
+        }
+        Self { base__: <ITLegacyAddressMediaControl_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConfigDialog, ConfigDialogEdit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITLegacyAddressMediaControl2 as windows_core::Interface>::IID || <ITLegacyAddressMediaControl_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITLegacyCallMediaControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DetectDigits(this: *mut core::ffi::c_void, digitmode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyCallMediaControl", "DetectDigits", 7usize, |this| ((**(this as *const *const ITLegacyCallMediaControl_Vtbl)).DetectDigits)(this, digitmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateDigits(this: *mut core::ffi::c_void, pdigits: core::mem::MaybeUninit<windows_core::BSTR>, digitmode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyCallMediaControl", "GenerateDigits", 8usize, |this| ((**(this as *const *const ITLegacyCallMediaControl_Vtbl)).GenerateDigits)(this, pdigits, digitmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetID(this: *mut core::ffi::c_void, pdeviceclass: core::mem::MaybeUninit<windows_core::BSTR>, pdwsize: *mut u32, ppdeviceid: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyCallMediaControl", "GetID", 9usize, |this| ((**(this as *const *const ITLegacyCallMediaControl_Vtbl)).GetID)(this, pdeviceclass, pdwsize, ppdeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaType(this: *mut core::ffi::c_void, lmediatype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyCallMediaControl", "SetMediaType", 10usize, |this| ((**(this as *const *const ITLegacyCallMediaControl_Vtbl)).SetMediaType)(this, lmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonitorMedia(this: *mut core::ffi::c_void, lmediatype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyCallMediaControl", "MonitorMedia", 11usize, |this| ((**(this as *const *const ITLegacyCallMediaControl_Vtbl)).MonitorMedia)(this, lmediatype), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DetectDigits,
+            GenerateDigits,
+            GetID,
+            SetMediaType,
+            MonitorMedia,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITLegacyCallMediaControl as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITLegacyCallMediaControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GenerateDigits2(this: *mut core::ffi::c_void, pdigits: core::mem::MaybeUninit<windows_core::BSTR>, digitmode: i32, lduration: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyCallMediaControl2", "GenerateDigits2", 12usize, |this| ((**(this as *const *const ITLegacyCallMediaControl2_Vtbl)).GenerateDigits2)(this, pdigits, digitmode, lduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GatherDigits(this: *mut core::ffi::c_void, digitmode: i32, lnumdigits: i32, pterminationdigits: core::mem::MaybeUninit<windows_core::BSTR>, lfirstdigittimeout: i32, linterdigittimeout: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyCallMediaControl2", "GatherDigits", 13usize, |this| ((**(this as *const *const ITLegacyCallMediaControl2_Vtbl)).GatherDigits)(this, digitmode, lnumdigits, pterminationdigits, lfirstdigittimeout, linterdigittimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetectTones(this: *mut core::ffi::c_void, ptonelist: *const TAPI_DETECTTONE, lnumtones: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyCallMediaControl2", "DetectTones", 14usize, |this| ((**(this as *const *const ITLegacyCallMediaControl2_Vtbl)).DetectTones)(this, ptonelist, lnumtones), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DetectTonesByCollection(this: *mut core::ffi::c_void, pdetecttonecollection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyCallMediaControl2", "DetectTonesByCollection", 15usize, |this| ((**(this as *const *const ITLegacyCallMediaControl2_Vtbl)).DetectTonesByCollection)(this, pdetecttonecollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateTone(this: *mut core::ffi::c_void, tonemode: TAPI_TONEMODE, lduration: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyCallMediaControl2", "GenerateTone", 16usize, |this| ((**(this as *const *const ITLegacyCallMediaControl2_Vtbl)).GenerateTone)(this, tonemode, lduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateCustomTones(this: *mut core::ffi::c_void, ptonelist: *const TAPI_CUSTOMTONE, lnumtones: i32, lduration: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyCallMediaControl2", "GenerateCustomTones", 17usize, |this| ((**(this as *const *const ITLegacyCallMediaControl2_Vtbl)).GenerateCustomTones)(this, ptonelist, lnumtones, lduration), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GenerateCustomTonesByCollection(this: *mut core::ffi::c_void, pcustomtonecollection: *mut core::ffi::c_void, lduration: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyCallMediaControl2", "GenerateCustomTonesByCollection", 18usize, |this| ((**(this as *const *const ITLegacyCallMediaControl2_Vtbl)).GenerateCustomTonesByCollection)(this, pcustomtonecollection, lduration), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateDetectToneObject(this: *mut core::ffi::c_void, ppdetecttone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyCallMediaControl2", "CreateDetectToneObject", 19usize, |this| ((**(this as *const *const ITLegacyCallMediaControl2_Vtbl)).CreateDetectToneObject)(this, ppdetecttone), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateCustomToneObject(this: *mut core::ffi::c_void, ppcustomtone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyCallMediaControl2", "CreateCustomToneObject", 20usize, |this| ((**(this as *const *const ITLegacyCallMediaControl2_Vtbl)).CreateCustomToneObject)(this, ppcustomtone), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIDAsVariant(this: *mut core::ffi::c_void, bstrdeviceclass: core::mem::MaybeUninit<windows_core::BSTR>, pvardeviceid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyCallMediaControl2", "GetIDAsVariant", 21usize, |this| ((**(this as *const *const ITLegacyCallMediaControl2_Vtbl)).GetIDAsVariant)(this, bstrdeviceclass, pvardeviceid), |result| Some(*result))
+        }
+        Self {
+            base__: <ITLegacyCallMediaControl_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GenerateDigits2,
+            GatherDigits,
+            DetectTones,
+            #[cfg(feature = "Win32_System_Com")]
+            DetectTonesByCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DetectTonesByCollection: 0,
+            GenerateTone,
+            GenerateCustomTones,
+            #[cfg(feature = "Win32_System_Com")]
+            GenerateCustomTonesByCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GenerateCustomTonesByCollection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateDetectToneObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateDetectToneObject: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateCustomToneObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateCustomToneObject: 0,
+            GetIDAsVariant,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITLegacyCallMediaControl2 as windows_core::Interface>::IID || <ITLegacyCallMediaControl_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITLegacyWaveSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsFullDuplex(this: *mut core::ffi::c_void, psupport: *mut FULLDUPLEX_SUPPORT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLegacyWaveSupport", "IsFullDuplex", 7usize, |this| ((**(this as *const *const ITLegacyWaveSupport_Vtbl)).IsFullDuplex)(this, psupport), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsFullDuplex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITLegacyWaveSupport as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITLocationInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PermanentLocationID(this: *mut core::ffi::c_void, pllocationid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLocationInfo", "PermanentLocationID", 7usize, |this| ((**(this as *const *const ITLocationInfo_Vtbl)).PermanentLocationID)(this, pllocationid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CountryCode(this: *mut core::ffi::c_void, plcountrycode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLocationInfo", "CountryCode", 8usize, |this| ((**(this as *const *const ITLocationInfo_Vtbl)).CountryCode)(this, plcountrycode), |result| Some(*result))
+        }
+        unsafe extern "system" fn CountryID(this: *mut core::ffi::c_void, plcountryid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLocationInfo", "CountryID", 9usize, |this| ((**(this as *const *const ITLocationInfo_Vtbl)).CountryID)(this, plcountryid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Options(this: *mut core::ffi::c_void, ploptions: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLocationInfo", "Options", 10usize, |this| ((**(this as *const *const ITLocationInfo_Vtbl)).Options)(this, ploptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredCardID(this: *mut core::ffi::c_void, plcardid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLocationInfo", "PreferredCardID", 11usize, |this| ((**(this as *const *const ITLocationInfo_Vtbl)).PreferredCardID)(this, plcardid), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocationName(this: *mut core::ffi::c_void, pplocationname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLocationInfo", "LocationName", 12usize, |this| ((**(this as *const *const ITLocationInfo_Vtbl)).LocationName)(this, pplocationname), |result| Some(*result))
+        }
+        unsafe extern "system" fn CityCode(this: *mut core::ffi::c_void, ppcode: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLocationInfo", "CityCode", 13usize, |this| ((**(this as *const *const ITLocationInfo_Vtbl)).CityCode)(this, ppcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalAccessCode(this: *mut core::ffi::c_void, ppcode: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLocationInfo", "LocalAccessCode", 14usize, |this| ((**(this as *const *const ITLocationInfo_Vtbl)).LocalAccessCode)(this, ppcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn LongDistanceAccessCode(this: *mut core::ffi::c_void, ppcode: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLocationInfo", "LongDistanceAccessCode", 15usize, |this| ((**(this as *const *const ITLocationInfo_Vtbl)).LongDistanceAccessCode)(this, ppcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn TollPrefixList(this: *mut core::ffi::c_void, pptolllist: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLocationInfo", "TollPrefixList", 16usize, |this| ((**(this as *const *const ITLocationInfo_Vtbl)).TollPrefixList)(this, pptolllist), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelCallWaitingCode(this: *mut core::ffi::c_void, ppcode: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITLocationInfo", "CancelCallWaitingCode", 17usize, |this| ((**(this as *const *const ITLocationInfo_Vtbl)).CancelCallWaitingCode)(this, ppcode), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PermanentLocationID,
+            CountryCode,
+            CountryID,
+            Options,
+            PreferredCardID,
+            LocationName,
+            CityCode,
+            LocalAccessCode,
+            LongDistanceAccessCode,
+            TollPrefixList,
+            CancelCallWaitingCode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITLocationInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITMSPAddress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, hevent: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMSPAddress", "Initialize", 3usize, |this| ((**(this as *const *const ITMSPAddress_Vtbl)).Initialize)(this, hevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMSPAddress", "Shutdown", 4usize, |this| ((**(this as *const *const ITMSPAddress_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMSPCall(this: *mut core::ffi::c_void, hcall: *const i32, dwreserved: u32, dwmediatype: u32, pouterunknown: *mut core::ffi::c_void, ppstreamcontrol: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMSPAddress", "CreateMSPCall", 5usize, |this| ((**(this as *const *const ITMSPAddress_Vtbl)).CreateMSPCall)(this, hcall, dwreserved, dwmediatype, pouterunknown, ppstreamcontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutdownMSPCall(this: *mut core::ffi::c_void, pstreamcontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMSPAddress", "ShutdownMSPCall", 6usize, |this| ((**(this as *const *const ITMSPAddress_Vtbl)).ShutdownMSPCall)(this, pstreamcontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceiveTSPData(this: *mut core::ffi::c_void, pmspcall: *mut core::ffi::c_void, pbuffer: *const u8, dwsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMSPAddress", "ReceiveTSPData", 7usize, |this| ((**(this as *const *const ITMSPAddress_Vtbl)).ReceiveTSPData)(this, pmspcall, pbuffer, dwsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEvent(this: *mut core::ffi::c_void, pdwsize: *mut u32, peventbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMSPAddress", "GetEvent", 8usize, |this| ((**(this as *const *const ITMSPAddress_Vtbl)).GetEvent)(this, pdwsize, peventbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Shutdown,
+            CreateMSPCall,
+            ShutdownMSPCall,
+            ReceiveTSPData,
+            GetEvent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITMSPAddress as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITMediaControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMediaControl", "Start", 7usize, |this| ((**(this as *const *const ITMediaControl_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMediaControl", "Stop", 8usize, |this| ((**(this as *const *const ITMediaControl_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMediaControl", "Pause", 9usize, |this| ((**(this as *const *const ITMediaControl_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaState(this: *mut core::ffi::c_void, pterminalmediastate: *mut TERMINAL_MEDIA_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMediaControl", "MediaState", 10usize, |this| ((**(this as *const *const ITMediaControl_Vtbl)).MediaState)(this, pterminalmediastate), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, Stop, Pause, MediaState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITMediaControl as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITMediaPlayback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPlayList(this: *mut core::ffi::c_void, playlistvariant: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMediaPlayback", "SetPlayList", 7usize, |this| ((**(this as *const *const ITMediaPlayback_Vtbl)).SetPlayList)(this, playlistvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayList(this: *mut core::ffi::c_void, pplaylistvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMediaPlayback", "PlayList", 8usize, |this| ((**(this as *const *const ITMediaPlayback_Vtbl)).PlayList)(this, pplaylistvariant), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPlayList, PlayList }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITMediaPlayback as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITMediaRecord_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFileName(this: *mut core::ffi::c_void, bstrfilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMediaRecord", "SetFileName", 7usize, |this| ((**(this as *const *const ITMediaRecord_Vtbl)).SetFileName)(this, bstrfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileName(this: *mut core::ffi::c_void, pbstrfilename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMediaRecord", "FileName", 8usize, |this| ((**(this as *const *const ITMediaRecord_Vtbl)).FileName)(this, pbstrfilename), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetFileName, FileName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITMediaRecord as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITMediaSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MediaTypes(this: *mut core::ffi::c_void, plmediatypes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMediaSupport", "MediaTypes", 7usize, |this| ((**(this as *const *const ITMediaSupport_Vtbl)).MediaTypes)(this, plmediatypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryMediaType(this: *mut core::ffi::c_void, lmediatype: i32, pfsupport: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMediaSupport", "QueryMediaType", 8usize, |this| ((**(this as *const *const ITMediaSupport_Vtbl)).QueryMediaType)(this, lmediatype, pfsupport), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MediaTypes, QueryMediaType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITMediaSupport as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITMultiTrackTerminal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TrackTerminals(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMultiTrackTerminal", "TrackTerminals", 7usize, |this| ((**(this as *const *const ITMultiTrackTerminal_Vtbl)).TrackTerminals)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateTrackTerminals(this: *mut core::ffi::c_void, ppenumterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMultiTrackTerminal", "EnumerateTrackTerminals", 8usize, |this| ((**(this as *const *const ITMultiTrackTerminal_Vtbl)).EnumerateTrackTerminals)(this, ppenumterminal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateTrackTerminal(this: *mut core::ffi::c_void, mediatype: i32, terminaldirection: TERMINAL_DIRECTION, ppterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMultiTrackTerminal", "CreateTrackTerminal", 9usize, |this| ((**(this as *const *const ITMultiTrackTerminal_Vtbl)).CreateTrackTerminal)(this, mediatype, terminaldirection, ppterminal), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaTypesInUse(this: *mut core::ffi::c_void, plmediatypesinuse: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMultiTrackTerminal", "MediaTypesInUse", 10usize, |this| ((**(this as *const *const ITMultiTrackTerminal_Vtbl)).MediaTypesInUse)(this, plmediatypesinuse), |result| Some(*result))
+        }
+        unsafe extern "system" fn DirectionsInUse(this: *mut core::ffi::c_void, pldirectionsinused: *mut TERMINAL_DIRECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMultiTrackTerminal", "DirectionsInUse", 11usize, |this| ((**(this as *const *const ITMultiTrackTerminal_Vtbl)).DirectionsInUse)(this, pldirectionsinused), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RemoveTrackTerminal(this: *mut core::ffi::c_void, ptrackterminaltoremove: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITMultiTrackTerminal", "RemoveTrackTerminal", 12usize, |this| ((**(this as *const *const ITMultiTrackTerminal_Vtbl)).RemoveTrackTerminal)(this, ptrackterminaltoremove), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TrackTerminals,
+            EnumerateTrackTerminals,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateTrackTerminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateTrackTerminal: 0,
+            MediaTypesInUse,
+            DirectionsInUse,
+            #[cfg(feature = "Win32_System_Com")]
+            RemoveTrackTerminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RemoveTrackTerminal: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITMultiTrackTerminal as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITPhone_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, privilege: PHONE_PRIVILEGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "Open", 7usize, |this| ((**(this as *const *const ITPhone_Vtbl)).Open)(this, privilege), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "Close", 8usize, |this| ((**(this as *const *const ITPhone_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Addresses(this: *mut core::ffi::c_void, paddresses: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "Addresses", 9usize, |this| ((**(this as *const *const ITPhone_Vtbl)).Addresses)(this, paddresses), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateAddresses(this: *mut core::ffi::c_void, ppenumaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "EnumerateAddresses", 10usize, |this| ((**(this as *const *const ITPhone_Vtbl)).EnumerateAddresses)(this, ppenumaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PhoneCapsLong(this: *mut core::ffi::c_void, pclcap: PHONECAPS_LONG, plcapability: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "get_PhoneCapsLong", 11usize, |this| ((**(this as *const *const ITPhone_Vtbl)).get_PhoneCapsLong)(this, pclcap, plcapability), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PhoneCapsString(this: *mut core::ffi::c_void, pcscap: PHONECAPS_STRING, ppcapability: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "get_PhoneCapsString", 12usize, |this| ((**(this as *const *const ITPhone_Vtbl)).get_PhoneCapsString)(this, pcscap, ppcapability), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Terminals(this: *mut core::ffi::c_void, paddress: *mut core::ffi::c_void, pterminals: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "get_Terminals", 13usize, |this| ((**(this as *const *const ITPhone_Vtbl)).get_Terminals)(this, paddress, pterminals), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumerateTerminals(this: *mut core::ffi::c_void, paddress: *mut core::ffi::c_void, ppenumterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "EnumerateTerminals", 14usize, |this| ((**(this as *const *const ITPhone_Vtbl)).EnumerateTerminals)(this, paddress, ppenumterminal), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ButtonMode(this: *mut core::ffi::c_void, lbuttonid: i32, pbuttonmode: *mut PHONE_BUTTON_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "get_ButtonMode", 15usize, |this| ((**(this as *const *const ITPhone_Vtbl)).get_ButtonMode)(this, lbuttonid, pbuttonmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_ButtonMode(this: *mut core::ffi::c_void, lbuttonid: i32, buttonmode: PHONE_BUTTON_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "put_ButtonMode", 16usize, |this| ((**(this as *const *const ITPhone_Vtbl)).put_ButtonMode)(this, lbuttonid, buttonmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ButtonFunction(this: *mut core::ffi::c_void, lbuttonid: i32, pbuttonfunction: *mut PHONE_BUTTON_FUNCTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "get_ButtonFunction", 17usize, |this| ((**(this as *const *const ITPhone_Vtbl)).get_ButtonFunction)(this, lbuttonid, pbuttonfunction), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_ButtonFunction(this: *mut core::ffi::c_void, lbuttonid: i32, buttonfunction: PHONE_BUTTON_FUNCTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "put_ButtonFunction", 18usize, |this| ((**(this as *const *const ITPhone_Vtbl)).put_ButtonFunction)(this, lbuttonid, buttonfunction), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ButtonText(this: *mut core::ffi::c_void, lbuttonid: i32, ppbuttontext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "get_ButtonText", 19usize, |this| ((**(this as *const *const ITPhone_Vtbl)).get_ButtonText)(this, lbuttonid, ppbuttontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_ButtonText(this: *mut core::ffi::c_void, lbuttonid: i32, bstrbuttontext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "put_ButtonText", 20usize, |this| ((**(this as *const *const ITPhone_Vtbl)).put_ButtonText)(this, lbuttonid, bstrbuttontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ButtonState(this: *mut core::ffi::c_void, lbuttonid: i32, pbuttonstate: *mut PHONE_BUTTON_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "get_ButtonState", 21usize, |this| ((**(this as *const *const ITPhone_Vtbl)).get_ButtonState)(this, lbuttonid, pbuttonstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_HookSwitchState(this: *mut core::ffi::c_void, hookswitchdevice: PHONE_HOOK_SWITCH_DEVICE, phookswitchstate: *mut PHONE_HOOK_SWITCH_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "get_HookSwitchState", 22usize, |this| ((**(this as *const *const ITPhone_Vtbl)).get_HookSwitchState)(this, hookswitchdevice, phookswitchstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_HookSwitchState(this: *mut core::ffi::c_void, hookswitchdevice: PHONE_HOOK_SWITCH_DEVICE, hookswitchstate: PHONE_HOOK_SWITCH_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "put_HookSwitchState", 23usize, |this| ((**(this as *const *const ITPhone_Vtbl)).put_HookSwitchState)(this, hookswitchdevice, hookswitchstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRingMode(this: *mut core::ffi::c_void, lringmode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "SetRingMode", 24usize, |this| ((**(this as *const *const ITPhone_Vtbl)).SetRingMode)(this, lringmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn RingMode(this: *mut core::ffi::c_void, plringmode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "RingMode", 25usize, |this| ((**(this as *const *const ITPhone_Vtbl)).RingMode)(this, plringmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRingVolume(this: *mut core::ffi::c_void, lringvolume: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "SetRingVolume", 26usize, |this| ((**(this as *const *const ITPhone_Vtbl)).SetRingVolume)(this, lringvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn RingVolume(this: *mut core::ffi::c_void, plringvolume: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "RingVolume", 27usize, |this| ((**(this as *const *const ITPhone_Vtbl)).RingVolume)(this, plringvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn Privilege(this: *mut core::ffi::c_void, pprivilege: *mut PHONE_PRIVILEGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "Privilege", 28usize, |this| ((**(this as *const *const ITPhone_Vtbl)).Privilege)(this, pprivilege), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPhoneCapsBuffer(this: *mut core::ffi::c_void, pcbcaps: PHONECAPS_BUFFER, pdwsize: *mut u32, ppphonecapsbuffer: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "GetPhoneCapsBuffer", 29usize, |this| ((**(this as *const *const ITPhone_Vtbl)).GetPhoneCapsBuffer)(this, pcbcaps, pdwsize, ppphonecapsbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PhoneCapsBuffer(this: *mut core::ffi::c_void, pcbcaps: PHONECAPS_BUFFER, pvarbuffer: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "get_PhoneCapsBuffer", 30usize, |this| ((**(this as *const *const ITPhone_Vtbl)).get_PhoneCapsBuffer)(this, pcbcaps, pvarbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_LampMode(this: *mut core::ffi::c_void, llampid: i32, plampmode: *mut PHONE_LAMP_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "get_LampMode", 31usize, |this| ((**(this as *const *const ITPhone_Vtbl)).get_LampMode)(this, llampid, plampmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_LampMode(this: *mut core::ffi::c_void, llampid: i32, lampmode: PHONE_LAMP_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "put_LampMode", 32usize, |this| ((**(this as *const *const ITPhone_Vtbl)).put_LampMode)(this, llampid, lampmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Display(this: *mut core::ffi::c_void, pbstrdisplay: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "Display", 33usize, |this| ((**(this as *const *const ITPhone_Vtbl)).Display)(this, pbstrdisplay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplay(this: *mut core::ffi::c_void, lrow: i32, lcolumn: i32, bstrdisplay: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "SetDisplay", 34usize, |this| ((**(this as *const *const ITPhone_Vtbl)).SetDisplay)(this, lrow, lcolumn, bstrdisplay), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredAddresses(this: *mut core::ffi::c_void, paddresses: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "PreferredAddresses", 35usize, |this| ((**(this as *const *const ITPhone_Vtbl)).PreferredAddresses)(this, paddresses), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumeratePreferredAddresses(this: *mut core::ffi::c_void, ppenumaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "EnumeratePreferredAddresses", 36usize, |this| ((**(this as *const *const ITPhone_Vtbl)).EnumeratePreferredAddresses)(this, ppenumaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceSpecific(this: *mut core::ffi::c_void, pparams: *const u8, dwsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "DeviceSpecific", 37usize, |this| ((**(this as *const *const ITPhone_Vtbl)).DeviceSpecific)(this, pparams, dwsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceSpecificVariant(this: *mut core::ffi::c_void, vardevspecificbytearray: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "DeviceSpecificVariant", 38usize, |this| ((**(this as *const *const ITPhone_Vtbl)).DeviceSpecificVariant)(this, vardevspecificbytearray), |result| Some(*result))
+        }
+        unsafe extern "system" fn NegotiateExtVersion(this: *mut core::ffi::c_void, llowversion: i32, lhighversion: i32, plextversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhone", "NegotiateExtVersion", 39usize, |this| ((**(this as *const *const ITPhone_Vtbl)).NegotiateExtVersion)(this, llowversion, lhighversion, plextversion), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Open,
+            Close,
+            Addresses,
+            EnumerateAddresses,
+            get_PhoneCapsLong,
+            get_PhoneCapsString,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Terminals,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Terminals: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumerateTerminals,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumerateTerminals: 0,
+            get_ButtonMode,
+            put_ButtonMode,
+            get_ButtonFunction,
+            put_ButtonFunction,
+            get_ButtonText,
+            put_ButtonText,
+            get_ButtonState,
+            get_HookSwitchState,
+            put_HookSwitchState,
+            SetRingMode,
+            RingMode,
+            SetRingVolume,
+            RingVolume,
+            Privilege,
+            GetPhoneCapsBuffer,
+            get_PhoneCapsBuffer,
+            get_LampMode,
+            put_LampMode,
+            Display,
+            SetDisplay,
+            PreferredAddresses,
+            EnumeratePreferredAddresses,
+            DeviceSpecific,
+            DeviceSpecificVariant,
+            NegotiateExtVersion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITPhone as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITPhoneDeviceSpecificEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Phone(this: *mut core::ffi::c_void, ppphone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhoneDeviceSpecificEvent", "Phone", 7usize, |this| ((**(this as *const *const ITPhoneDeviceSpecificEvent_Vtbl)).Phone)(this, ppphone), |result| Some(*result))
+        }
+        unsafe extern "system" fn lParam1(this: *mut core::ffi::c_void, pparam1: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhoneDeviceSpecificEvent", "lParam1", 8usize, |this| ((**(this as *const *const ITPhoneDeviceSpecificEvent_Vtbl)).lParam1)(this, pparam1), |result| Some(*result))
+        }
+        unsafe extern "system" fn lParam2(this: *mut core::ffi::c_void, pparam2: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhoneDeviceSpecificEvent", "lParam2", 9usize, |this| ((**(this as *const *const ITPhoneDeviceSpecificEvent_Vtbl)).lParam2)(this, pparam2), |result| Some(*result))
+        }
+        unsafe extern "system" fn lParam3(this: *mut core::ffi::c_void, pparam3: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhoneDeviceSpecificEvent", "lParam3", 10usize, |this| ((**(this as *const *const ITPhoneDeviceSpecificEvent_Vtbl)).lParam3)(this, pparam3), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Phone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Phone: 0,
+            lParam1,
+            lParam2,
+            lParam3,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITPhoneDeviceSpecificEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITPhoneEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Phone(this: *mut core::ffi::c_void, ppphone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhoneEvent", "Phone", 7usize, |this| ((**(this as *const *const ITPhoneEvent_Vtbl)).Phone)(this, ppphone), |result| Some(*result))
+        }
+        unsafe extern "system" fn Event(this: *mut core::ffi::c_void, pevent: *mut PHONE_EVENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhoneEvent", "Event", 8usize, |this| ((**(this as *const *const ITPhoneEvent_Vtbl)).Event)(this, pevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonState(this: *mut core::ffi::c_void, pstate: *mut PHONE_BUTTON_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhoneEvent", "ButtonState", 9usize, |this| ((**(this as *const *const ITPhoneEvent_Vtbl)).ButtonState)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn HookSwitchState(this: *mut core::ffi::c_void, pstate: *mut PHONE_HOOK_SWITCH_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhoneEvent", "HookSwitchState", 10usize, |this| ((**(this as *const *const ITPhoneEvent_Vtbl)).HookSwitchState)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn HookSwitchDevice(this: *mut core::ffi::c_void, pdevice: *mut PHONE_HOOK_SWITCH_DEVICE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhoneEvent", "HookSwitchDevice", 11usize, |this| ((**(this as *const *const ITPhoneEvent_Vtbl)).HookSwitchDevice)(this, pdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn RingMode(this: *mut core::ffi::c_void, plringmode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhoneEvent", "RingMode", 12usize, |this| ((**(this as *const *const ITPhoneEvent_Vtbl)).RingMode)(this, plringmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonLampId(this: *mut core::ffi::c_void, plbuttonlampid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhoneEvent", "ButtonLampId", 13usize, |this| ((**(this as *const *const ITPhoneEvent_Vtbl)).ButtonLampId)(this, plbuttonlampid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberGathered(this: *mut core::ffi::c_void, ppnumber: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhoneEvent", "NumberGathered", 14usize, |this| ((**(this as *const *const ITPhoneEvent_Vtbl)).NumberGathered)(this, ppnumber), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcallinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPhoneEvent", "Call", 15usize, |this| ((**(this as *const *const ITPhoneEvent_Vtbl)).Call)(this, ppcallinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Phone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Phone: 0,
+            Event,
+            ButtonState,
+            HookSwitchState,
+            HookSwitchDevice,
+            RingMode,
+            ButtonLampId,
+            NumberGathered,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITPhoneEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITPluggableTerminalClassInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPluggableTerminalClassInfo", "Name", 7usize, |this| ((**(this as *const *const ITPluggableTerminalClassInfo_Vtbl)).Name)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Company(this: *mut core::ffi::c_void, pcompany: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPluggableTerminalClassInfo", "Company", 8usize, |this| ((**(this as *const *const ITPluggableTerminalClassInfo_Vtbl)).Company)(this, pcompany), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, pversion: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPluggableTerminalClassInfo", "Version", 9usize, |this| ((**(this as *const *const ITPluggableTerminalClassInfo_Vtbl)).Version)(this, pversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminalClass(this: *mut core::ffi::c_void, pterminalclass: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPluggableTerminalClassInfo", "TerminalClass", 10usize, |this| ((**(this as *const *const ITPluggableTerminalClassInfo_Vtbl)).TerminalClass)(this, pterminalclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn CLSID(this: *mut core::ffi::c_void, pclsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPluggableTerminalClassInfo", "CLSID", 11usize, |this| ((**(this as *const *const ITPluggableTerminalClassInfo_Vtbl)).CLSID)(this, pclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Direction(this: *mut core::ffi::c_void, pdirection: *mut TERMINAL_DIRECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPluggableTerminalClassInfo", "Direction", 12usize, |this| ((**(this as *const *const ITPluggableTerminalClassInfo_Vtbl)).Direction)(this, pdirection), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaTypes(this: *mut core::ffi::c_void, pmediatypes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPluggableTerminalClassInfo", "MediaTypes", 13usize, |this| ((**(this as *const *const ITPluggableTerminalClassInfo_Vtbl)).MediaTypes)(this, pmediatypes), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Company,
+            Version,
+            TerminalClass,
+            CLSID,
+            Direction,
+            MediaTypes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITPluggableTerminalClassInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITPluggableTerminalEventSink_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FireEvent(this: *mut core::ffi::c_void, pmspeventinfo: *const MSP_EVENT_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPluggableTerminalEventSink", "FireEvent", 3usize, |this| ((**(this as *const *const ITPluggableTerminalEventSink_Vtbl)).FireEvent)(this, pmspeventinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            FireEvent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FireEvent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITPluggableTerminalEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITPluggableTerminalEventSinkRegistration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterSink(this: *mut core::ffi::c_void, peventsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPluggableTerminalEventSinkRegistration", "RegisterSink", 3usize, |this| ((**(this as *const *const ITPluggableTerminalEventSinkRegistration_Vtbl)).RegisterSink)(this, peventsink), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterSink(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPluggableTerminalEventSinkRegistration", "UnregisterSink", 4usize, |this| ((**(this as *const *const ITPluggableTerminalEventSinkRegistration_Vtbl)).UnregisterSink)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterSink, UnregisterSink }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITPluggableTerminalEventSinkRegistration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITPluggableTerminalSuperclassInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPluggableTerminalSuperclassInfo", "Name", 7usize, |this| ((**(this as *const *const ITPluggableTerminalSuperclassInfo_Vtbl)).Name)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn CLSID(this: *mut core::ffi::c_void, pclsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPluggableTerminalSuperclassInfo", "CLSID", 8usize, |this| ((**(this as *const *const ITPluggableTerminalSuperclassInfo_Vtbl)).CLSID)(this, pclsid), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, CLSID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITPluggableTerminalSuperclassInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITPrivateEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, ppaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPrivateEvent", "Address", 7usize, |this| ((**(this as *const *const ITPrivateEvent_Vtbl)).Address)(this, ppaddress), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcallinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPrivateEvent", "Call", 8usize, |this| ((**(this as *const *const ITPrivateEvent_Vtbl)).Call)(this, ppcallinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CallHub(this: *mut core::ffi::c_void, ppcallhub: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPrivateEvent", "CallHub", 9usize, |this| ((**(this as *const *const ITPrivateEvent_Vtbl)).CallHub)(this, ppcallhub), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventCode(this: *mut core::ffi::c_void, pleventcode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPrivateEvent", "EventCode", 10usize, |this| ((**(this as *const *const ITPrivateEvent_Vtbl)).EventCode)(this, pleventcode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EventInterface(this: *mut core::ffi::c_void, peventinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITPrivateEvent", "EventInterface", 11usize, |this| ((**(this as *const *const ITPrivateEvent_Vtbl)).EventInterface)(this, peventinterface), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Address,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Address: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CallHub,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CallHub: 0,
+            EventCode,
+            #[cfg(feature = "Win32_System_Com")]
+            EventInterface,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EventInterface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITPrivateEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITQOSEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcall: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQOSEvent", "Call", 7usize, |this| ((**(this as *const *const ITQOSEvent_Vtbl)).Call)(this, ppcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn Event(this: *mut core::ffi::c_void, pqosevent: *mut QOS_EVENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQOSEvent", "Event", 8usize, |this| ((**(this as *const *const ITQOSEvent_Vtbl)).Event)(this, pqosevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaType(this: *mut core::ffi::c_void, plmediatype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQOSEvent", "MediaType", 9usize, |this| ((**(this as *const *const ITQOSEvent_Vtbl)).MediaType)(this, plmediatype), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+            Event,
+            MediaType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITQOSEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITQueue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMeasurementPeriod(this: *mut core::ffi::c_void, lperiod: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQueue", "SetMeasurementPeriod", 7usize, |this| ((**(this as *const *const ITQueue_Vtbl)).SetMeasurementPeriod)(this, lperiod), |result| Some(*result))
+        }
+        unsafe extern "system" fn MeasurementPeriod(this: *mut core::ffi::c_void, plperiod: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQueue", "MeasurementPeriod", 8usize, |this| ((**(this as *const *const ITQueue_Vtbl)).MeasurementPeriod)(this, plperiod), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalCallsQueued(this: *mut core::ffi::c_void, plcalls: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQueue", "TotalCallsQueued", 9usize, |this| ((**(this as *const *const ITQueue_Vtbl)).TotalCallsQueued)(this, plcalls), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentCallsQueued(this: *mut core::ffi::c_void, plcalls: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQueue", "CurrentCallsQueued", 10usize, |this| ((**(this as *const *const ITQueue_Vtbl)).CurrentCallsQueued)(this, plcalls), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalCallsAbandoned(this: *mut core::ffi::c_void, plcalls: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQueue", "TotalCallsAbandoned", 11usize, |this| ((**(this as *const *const ITQueue_Vtbl)).TotalCallsAbandoned)(this, plcalls), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalCallsFlowedIn(this: *mut core::ffi::c_void, plcalls: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQueue", "TotalCallsFlowedIn", 12usize, |this| ((**(this as *const *const ITQueue_Vtbl)).TotalCallsFlowedIn)(this, plcalls), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalCallsFlowedOut(this: *mut core::ffi::c_void, plcalls: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQueue", "TotalCallsFlowedOut", 13usize, |this| ((**(this as *const *const ITQueue_Vtbl)).TotalCallsFlowedOut)(this, plcalls), |result| Some(*result))
+        }
+        unsafe extern "system" fn LongestEverWaitTime(this: *mut core::ffi::c_void, plwaittime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQueue", "LongestEverWaitTime", 14usize, |this| ((**(this as *const *const ITQueue_Vtbl)).LongestEverWaitTime)(this, plwaittime), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentLongestWaitTime(this: *mut core::ffi::c_void, plwaittime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQueue", "CurrentLongestWaitTime", 15usize, |this| ((**(this as *const *const ITQueue_Vtbl)).CurrentLongestWaitTime)(this, plwaittime), |result| Some(*result))
+        }
+        unsafe extern "system" fn AverageWaitTime(this: *mut core::ffi::c_void, plwaittime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQueue", "AverageWaitTime", 16usize, |this| ((**(this as *const *const ITQueue_Vtbl)).AverageWaitTime)(this, plwaittime), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinalDisposition(this: *mut core::ffi::c_void, plcalls: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQueue", "FinalDisposition", 17usize, |this| ((**(this as *const *const ITQueue_Vtbl)).FinalDisposition)(this, plcalls), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, ppname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQueue", "Name", 18usize, |this| ((**(this as *const *const ITQueue_Vtbl)).Name)(this, ppname), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMeasurementPeriod,
+            MeasurementPeriod,
+            TotalCallsQueued,
+            CurrentCallsQueued,
+            TotalCallsAbandoned,
+            TotalCallsFlowedIn,
+            TotalCallsFlowedOut,
+            LongestEverWaitTime,
+            CurrentLongestWaitTime,
+            AverageWaitTime,
+            FinalDisposition,
+            Name,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITQueue as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITQueueEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Queue(this: *mut core::ffi::c_void, ppqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQueueEvent", "Queue", 7usize, |this| ((**(this as *const *const ITQueueEvent_Vtbl)).Queue)(this, ppqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Event(this: *mut core::ffi::c_void, pevent: *mut ACDQUEUE_EVENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITQueueEvent", "Event", 8usize, |this| ((**(this as *const *const ITQueueEvent_Vtbl)).Event)(this, pevent), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Queue,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Queue: 0,
+            Event,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITQueueEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITRendezvous_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DefaultDirectories(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITRendezvous", "DefaultDirectories", 7usize, |this| ((**(this as *const *const ITRendezvous_Vtbl)).DefaultDirectories)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateDefaultDirectories(this: *mut core::ffi::c_void, ppenumdirectory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITRendezvous", "EnumerateDefaultDirectories", 8usize, |this| ((**(this as *const *const ITRendezvous_Vtbl)).EnumerateDefaultDirectories)(this, ppenumdirectory), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateDirectory(this: *mut core::ffi::c_void, directorytype: DIRECTORY_TYPE, pname: core::mem::MaybeUninit<windows_core::BSTR>, ppdir: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITRendezvous", "CreateDirectory", 9usize, |this| ((**(this as *const *const ITRendezvous_Vtbl)).CreateDirectory)(this, directorytype, pname, ppdir), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateDirectoryObject(this: *mut core::ffi::c_void, directoryobjecttype: DIRECTORY_OBJECT_TYPE, pname: core::mem::MaybeUninit<windows_core::BSTR>, ppdirectoryobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITRendezvous", "CreateDirectoryObject", 10usize, |this| ((**(this as *const *const ITRendezvous_Vtbl)).CreateDirectoryObject)(this, directoryobjecttype, pname, ppdirectoryobject), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DefaultDirectories,
+            EnumerateDefaultDirectories,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateDirectory,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateDirectory: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateDirectoryObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateDirectoryObject: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITRendezvous as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MakeCall(this: *mut core::ffi::c_void, pdestaddress: core::mem::MaybeUninit<windows_core::BSTR>, pappname: core::mem::MaybeUninit<windows_core::BSTR>, pcalledparty: core::mem::MaybeUninit<windows_core::BSTR>, pcomment: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITRequest", "MakeCall", 7usize, |this| ((**(this as *const *const ITRequest_Vtbl)).MakeCall)(this, pdestaddress, pappname, pcalledparty, pcomment), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MakeCall }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITRequest as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITRequestEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegistrationInstance(this: *mut core::ffi::c_void, plregistrationinstance: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITRequestEvent", "RegistrationInstance", 7usize, |this| ((**(this as *const *const ITRequestEvent_Vtbl)).RegistrationInstance)(this, plregistrationinstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestMode(this: *mut core::ffi::c_void, plrequestmode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITRequestEvent", "RequestMode", 8usize, |this| ((**(this as *const *const ITRequestEvent_Vtbl)).RequestMode)(this, plrequestmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestAddress(this: *mut core::ffi::c_void, ppdestaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITRequestEvent", "DestAddress", 9usize, |this| ((**(this as *const *const ITRequestEvent_Vtbl)).DestAddress)(this, ppdestaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppName(this: *mut core::ffi::c_void, ppappname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITRequestEvent", "AppName", 10usize, |this| ((**(this as *const *const ITRequestEvent_Vtbl)).AppName)(this, ppappname), |result| Some(*result))
+        }
+        unsafe extern "system" fn CalledParty(this: *mut core::ffi::c_void, ppcalledparty: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITRequestEvent", "CalledParty", 11usize, |this| ((**(this as *const *const ITRequestEvent_Vtbl)).CalledParty)(this, ppcalledparty), |result| Some(*result))
+        }
+        unsafe extern "system" fn Comment(this: *mut core::ffi::c_void, ppcomment: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITRequestEvent", "Comment", 12usize, |this| ((**(this as *const *const ITRequestEvent_Vtbl)).Comment)(this, ppcomment), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegistrationInstance,
+            RequestMode,
+            DestAddress,
+            AppName,
+            CalledParty,
+            Comment,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITRequestEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITScriptableAudioFormat_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Channels(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITScriptableAudioFormat", "Channels", 7usize, |this| ((**(this as *const *const ITScriptableAudioFormat_Vtbl)).Channels)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChannels(this: *mut core::ffi::c_void, nnewval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITScriptableAudioFormat", "SetChannels", 8usize, |this| ((**(this as *const *const ITScriptableAudioFormat_Vtbl)).SetChannels)(this, nnewval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SamplesPerSec(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITScriptableAudioFormat", "SamplesPerSec", 9usize, |this| ((**(this as *const *const ITScriptableAudioFormat_Vtbl)).SamplesPerSec)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSamplesPerSec(this: *mut core::ffi::c_void, nnewval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITScriptableAudioFormat", "SetSamplesPerSec", 10usize, |this| ((**(this as *const *const ITScriptableAudioFormat_Vtbl)).SetSamplesPerSec)(this, nnewval), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvgBytesPerSec(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITScriptableAudioFormat", "AvgBytesPerSec", 11usize, |this| ((**(this as *const *const ITScriptableAudioFormat_Vtbl)).AvgBytesPerSec)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAvgBytesPerSec(this: *mut core::ffi::c_void, nnewval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITScriptableAudioFormat", "SetAvgBytesPerSec", 12usize, |this| ((**(this as *const *const ITScriptableAudioFormat_Vtbl)).SetAvgBytesPerSec)(this, nnewval), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlockAlign(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITScriptableAudioFormat", "BlockAlign", 13usize, |this| ((**(this as *const *const ITScriptableAudioFormat_Vtbl)).BlockAlign)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlockAlign(this: *mut core::ffi::c_void, nnewval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITScriptableAudioFormat", "SetBlockAlign", 14usize, |this| ((**(this as *const *const ITScriptableAudioFormat_Vtbl)).SetBlockAlign)(this, nnewval), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitsPerSample(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITScriptableAudioFormat", "BitsPerSample", 15usize, |this| ((**(this as *const *const ITScriptableAudioFormat_Vtbl)).BitsPerSample)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBitsPerSample(this: *mut core::ffi::c_void, nnewval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITScriptableAudioFormat", "SetBitsPerSample", 16usize, |this| ((**(this as *const *const ITScriptableAudioFormat_Vtbl)).SetBitsPerSample)(this, nnewval), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatTag(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITScriptableAudioFormat", "FormatTag", 17usize, |this| ((**(this as *const *const ITScriptableAudioFormat_Vtbl)).FormatTag)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormatTag(this: *mut core::ffi::c_void, nnewval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITScriptableAudioFormat", "SetFormatTag", 18usize, |this| ((**(this as *const *const ITScriptableAudioFormat_Vtbl)).SetFormatTag)(this, nnewval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Channels,
+            SetChannels,
+            SamplesPerSec,
+            SetSamplesPerSec,
+            AvgBytesPerSec,
+            SetAvgBytesPerSec,
+            BlockAlign,
+            SetBlockAlign,
+            BitsPerSample,
+            SetBitsPerSample,
+            FormatTag,
+            SetFormatTag,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITScriptableAudioFormat as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITStaticAudioTerminal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WaveId(this: *mut core::ffi::c_void, plwaveid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITStaticAudioTerminal", "WaveId", 7usize, |this| ((**(this as *const *const ITStaticAudioTerminal_Vtbl)).WaveId)(this, plwaveid), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WaveId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITStaticAudioTerminal as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MediaType(this: *mut core::ffi::c_void, plmediatype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITStream", "MediaType", 7usize, |this| ((**(this as *const *const ITStream_Vtbl)).MediaType)(this, plmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Direction(this: *mut core::ffi::c_void, ptd: *mut TERMINAL_DIRECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITStream", "Direction", 8usize, |this| ((**(this as *const *const ITStream_Vtbl)).Direction)(this, ptd), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, ppname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITStream", "Name", 9usize, |this| ((**(this as *const *const ITStream_Vtbl)).Name)(this, ppname), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartStream(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITStream", "StartStream", 10usize, |this| ((**(this as *const *const ITStream_Vtbl)).StartStream)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PauseStream(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITStream", "PauseStream", 11usize, |this| ((**(this as *const *const ITStream_Vtbl)).PauseStream)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopStream(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITStream", "StopStream", 12usize, |this| ((**(this as *const *const ITStream_Vtbl)).StopStream)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SelectTerminal(this: *mut core::ffi::c_void, pterminal: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITStream", "SelectTerminal", 13usize, |this| ((**(this as *const *const ITStream_Vtbl)).SelectTerminal)(this, pterminal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UnselectTerminal(this: *mut core::ffi::c_void, pterminal: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITStream", "UnselectTerminal", 14usize, |this| ((**(this as *const *const ITStream_Vtbl)).UnselectTerminal)(this, pterminal), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateTerminals(this: *mut core::ffi::c_void, ppenumterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITStream", "EnumerateTerminals", 15usize, |this| ((**(this as *const *const ITStream_Vtbl)).EnumerateTerminals)(this, ppenumterminal), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminals(this: *mut core::ffi::c_void, pterminals: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITStream", "Terminals", 16usize, |this| ((**(this as *const *const ITStream_Vtbl)).Terminals)(this, pterminals), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MediaType,
+            Direction,
+            Name,
+            StartStream,
+            PauseStream,
+            StopStream,
+            #[cfg(feature = "Win32_System_Com")]
+            SelectTerminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SelectTerminal: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            UnselectTerminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UnselectTerminal: 0,
+            EnumerateTerminals,
+            Terminals,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITStream as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITStreamControl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateStream(this: *mut core::ffi::c_void, lmediatype: i32, td: TERMINAL_DIRECTION, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITStreamControl", "CreateStream", 7usize, |this| ((**(this as *const *const ITStreamControl_Vtbl)).CreateStream)(this, lmediatype, td, ppstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RemoveStream(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITStreamControl", "RemoveStream", 8usize, |this| ((**(this as *const *const ITStreamControl_Vtbl)).RemoveStream)(this, pstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateStreams(this: *mut core::ffi::c_void, ppenumstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITStreamControl", "EnumerateStreams", 9usize, |this| ((**(this as *const *const ITStreamControl_Vtbl)).EnumerateStreams)(this, ppenumstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn Streams(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITStreamControl", "Streams", 10usize, |this| ((**(this as *const *const ITStreamControl_Vtbl)).Streams)(this, pvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateStream: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RemoveStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RemoveStream: 0,
+            EnumerateStreams,
+            Streams,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITStreamControl as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITSubStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartSubStream(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSubStream", "StartSubStream", 7usize, |this| ((**(this as *const *const ITSubStream_Vtbl)).StartSubStream)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PauseSubStream(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSubStream", "PauseSubStream", 8usize, |this| ((**(this as *const *const ITSubStream_Vtbl)).PauseSubStream)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopSubStream(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSubStream", "StopSubStream", 9usize, |this| ((**(this as *const *const ITSubStream_Vtbl)).StopSubStream)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SelectTerminal(this: *mut core::ffi::c_void, pterminal: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSubStream", "SelectTerminal", 10usize, |this| ((**(this as *const *const ITSubStream_Vtbl)).SelectTerminal)(this, pterminal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UnselectTerminal(this: *mut core::ffi::c_void, pterminal: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSubStream", "UnselectTerminal", 11usize, |this| ((**(this as *const *const ITSubStream_Vtbl)).UnselectTerminal)(this, pterminal), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateTerminals(this: *mut core::ffi::c_void, ppenumterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSubStream", "EnumerateTerminals", 12usize, |this| ((**(this as *const *const ITSubStream_Vtbl)).EnumerateTerminals)(this, ppenumterminal), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminals(this: *mut core::ffi::c_void, pterminals: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSubStream", "Terminals", 13usize, |this| ((**(this as *const *const ITSubStream_Vtbl)).Terminals)(this, pterminals), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Stream(this: *mut core::ffi::c_void, ppitstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSubStream", "Stream", 14usize, |this| ((**(this as *const *const ITSubStream_Vtbl)).Stream)(this, ppitstream), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartSubStream,
+            PauseSubStream,
+            StopSubStream,
+            #[cfg(feature = "Win32_System_Com")]
+            SelectTerminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SelectTerminal: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            UnselectTerminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UnselectTerminal: 0,
+            EnumerateTerminals,
+            Terminals,
+            #[cfg(feature = "Win32_System_Com")]
+            Stream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Stream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITSubStream as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITSubStreamControl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateSubStream(this: *mut core::ffi::c_void, ppsubstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSubStreamControl", "CreateSubStream", 7usize, |this| ((**(this as *const *const ITSubStreamControl_Vtbl)).CreateSubStream)(this, ppsubstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RemoveSubStream(this: *mut core::ffi::c_void, psubstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSubStreamControl", "RemoveSubStream", 8usize, |this| ((**(this as *const *const ITSubStreamControl_Vtbl)).RemoveSubStream)(this, psubstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateSubStreams(this: *mut core::ffi::c_void, ppenumsubstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSubStreamControl", "EnumerateSubStreams", 9usize, |this| ((**(this as *const *const ITSubStreamControl_Vtbl)).EnumerateSubStreams)(this, ppenumsubstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubStreams(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSubStreamControl", "SubStreams", 10usize, |this| ((**(this as *const *const ITSubStreamControl_Vtbl)).SubStreams)(this, pvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateSubStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateSubStream: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RemoveSubStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RemoveSubStream: 0,
+            EnumerateSubStreams,
+            SubStreams,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITSubStreamControl as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITTAPI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "Initialize", 7usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).Initialize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "Shutdown", 8usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Addresses(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "Addresses", 9usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).Addresses)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateAddresses(this: *mut core::ffi::c_void, ppenumaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "EnumerateAddresses", 10usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).EnumerateAddresses)(this, ppenumaddress), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RegisterCallNotifications(this: *mut core::ffi::c_void, paddress: *mut core::ffi::c_void, fmonitor: super::super::Foundation::VARIANT_BOOL, fowner: super::super::Foundation::VARIANT_BOOL, lmediatypes: i32, lcallbackinstance: i32, plregister: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "RegisterCallNotifications", 11usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).RegisterCallNotifications)(this, paddress, fmonitor, fowner, lmediatypes, lcallbackinstance, plregister), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterNotifications(this: *mut core::ffi::c_void, lregister: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "UnregisterNotifications", 12usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).UnregisterNotifications)(this, lregister), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallHubs(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "CallHubs", 13usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).CallHubs)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateCallHubs(this: *mut core::ffi::c_void, ppenumcallhub: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "EnumerateCallHubs", 14usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).EnumerateCallHubs)(this, ppenumcallhub), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCallHubTracking(this: *mut core::ffi::c_void, paddresses: core::mem::MaybeUninit<windows_core::VARIANT>, btracking: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "SetCallHubTracking", 15usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).SetCallHubTracking)(this, paddresses, btracking), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumeratePrivateTAPIObjects(this: *mut core::ffi::c_void, ppenumunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "EnumeratePrivateTAPIObjects", 16usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).EnumeratePrivateTAPIObjects)(this, ppenumunknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivateTAPIObjects(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "PrivateTAPIObjects", 17usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).PrivateTAPIObjects)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterRequestRecipient(this: *mut core::ffi::c_void, lregistrationinstance: i32, lrequestmode: i32, fenable: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "RegisterRequestRecipient", 18usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).RegisterRequestRecipient)(this, lregistrationinstance, lrequestmode, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAssistedTelephonyPriority(this: *mut core::ffi::c_void, pappfilename: core::mem::MaybeUninit<windows_core::BSTR>, fpriority: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "SetAssistedTelephonyPriority", 19usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).SetAssistedTelephonyPriority)(this, pappfilename, fpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationPriority(this: *mut core::ffi::c_void, pappfilename: core::mem::MaybeUninit<windows_core::BSTR>, lmediatype: i32, fpriority: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "SetApplicationPriority", 20usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).SetApplicationPriority)(this, pappfilename, lmediatype, fpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventFilter(this: *mut core::ffi::c_void, lfiltermask: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "SetEventFilter", 21usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).SetEventFilter)(this, lfiltermask), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventFilter(this: *mut core::ffi::c_void, plfiltermask: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI", "EventFilter", 22usize, |this| ((**(this as *const *const ITTAPI_Vtbl)).EventFilter)(this, plfiltermask), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Shutdown,
+            Addresses,
+            EnumerateAddresses,
+            #[cfg(feature = "Win32_System_Com")]
+            RegisterCallNotifications,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RegisterCallNotifications: 0,
+            UnregisterNotifications,
+            CallHubs,
+            EnumerateCallHubs,
+            SetCallHubTracking,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumeratePrivateTAPIObjects,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumeratePrivateTAPIObjects: 0,
+            PrivateTAPIObjects,
+            RegisterRequestRecipient,
+            SetAssistedTelephonyPriority,
+            SetApplicationPriority,
+            SetEventFilter,
+            EventFilter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITTAPI as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITTAPI2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Phones(this: *mut core::ffi::c_void, pphones: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI2", "Phones", 23usize, |this| ((**(this as *const *const ITTAPI2_Vtbl)).Phones)(this, pphones), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumeratePhones(this: *mut core::ffi::c_void, ppenumphone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI2", "EnumeratePhones", 24usize, |this| ((**(this as *const *const ITTAPI2_Vtbl)).EnumeratePhones)(this, ppenumphone), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateEmptyCollectionObject(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPI2", "CreateEmptyCollectionObject", 25usize, |this| ((**(this as *const *const ITTAPI2_Vtbl)).CreateEmptyCollectionObject)(this, ppcollection), |result| Some(*result))
+        }
+        Self {
+            base__: <ITTAPI_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Phones,
+            EnumeratePhones,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateEmptyCollectionObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateEmptyCollectionObject: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITTAPI2 as windows_core::Interface>::IID || <ITTAPI_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITTAPICallCenter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumerateAgentHandlers(this: *mut core::ffi::c_void, ppenumhandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPICallCenter", "EnumerateAgentHandlers", 7usize, |this| ((**(this as *const *const ITTAPICallCenter_Vtbl)).EnumerateAgentHandlers)(this, ppenumhandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn AgentHandlers(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPICallCenter", "AgentHandlers", 8usize, |this| ((**(this as *const *const ITTAPICallCenter_Vtbl)).AgentHandlers)(this, pvariant), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumerateAgentHandlers, AgentHandlers }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITTAPICallCenter as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITTAPIDispatchEventNotification_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITTAPIDispatchEventNotification as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITTAPIEventNotification_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Event(this: *mut core::ffi::c_void, tapievent: TAPI_EVENT, pevent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPIEventNotification", "Event", 3usize, |this| ((**(this as *const *const ITTAPIEventNotification_Vtbl)).Event)(this, tapievent, pevent), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Event,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Event: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITTAPIEventNotification as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITTAPIObjectEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TAPIObject(this: *mut core::ffi::c_void, pptapiobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPIObjectEvent", "TAPIObject", 7usize, |this| ((**(this as *const *const ITTAPIObjectEvent_Vtbl)).TAPIObject)(this, pptapiobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn Event(this: *mut core::ffi::c_void, pevent: *mut TAPIOBJECT_EVENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPIObjectEvent", "Event", 8usize, |this| ((**(this as *const *const ITTAPIObjectEvent_Vtbl)).Event)(this, pevent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, ppaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPIObjectEvent", "Address", 9usize, |this| ((**(this as *const *const ITTAPIObjectEvent_Vtbl)).Address)(this, ppaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallbackInstance(this: *mut core::ffi::c_void, plcallbackinstance: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPIObjectEvent", "CallbackInstance", 10usize, |this| ((**(this as *const *const ITTAPIObjectEvent_Vtbl)).CallbackInstance)(this, plcallbackinstance), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            TAPIObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TAPIObject: 0,
+            Event,
+            #[cfg(feature = "Win32_System_Com")]
+            Address,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Address: 0,
+            CallbackInstance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITTAPIObjectEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITTAPIObjectEvent2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Phone(this: *mut core::ffi::c_void, ppphone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTAPIObjectEvent2", "Phone", 11usize, |this| ((**(this as *const *const ITTAPIObjectEvent2_Vtbl)).Phone)(this, ppphone), |result| Some(*result))
+        }
+        Self {
+            base__: <ITTAPIObjectEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Phone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Phone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITTAPIObjectEvent2 as windows_core::Interface>::IID || <ITTAPIObjectEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITTTSTerminalEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Terminal(this: *mut core::ffi::c_void, ppterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTTSTerminalEvent", "Terminal", 7usize, |this| ((**(this as *const *const ITTTSTerminalEvent_Vtbl)).Terminal)(this, ppterminal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcall: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTTSTerminalEvent", "Call", 8usize, |this| ((**(this as *const *const ITTTSTerminalEvent_Vtbl)).Call)(this, ppcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, phrerrorcode: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTTSTerminalEvent", "Error", 9usize, |this| ((**(this as *const *const ITTTSTerminalEvent_Vtbl)).Error)(this, phrerrorcode), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Terminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Terminal: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+            Error,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITTTSTerminalEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITTerminal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, ppname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminal", "Name", 7usize, |this| ((**(this as *const *const ITTerminal_Vtbl)).Name)(this, ppname), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, pterminalstate: *mut TERMINAL_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminal", "State", 8usize, |this| ((**(this as *const *const ITTerminal_Vtbl)).State)(this, pterminalstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminalType(this: *mut core::ffi::c_void, ptype: *mut TERMINAL_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminal", "TerminalType", 9usize, |this| ((**(this as *const *const ITTerminal_Vtbl)).TerminalType)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminalClass(this: *mut core::ffi::c_void, ppterminalclass: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminal", "TerminalClass", 10usize, |this| ((**(this as *const *const ITTerminal_Vtbl)).TerminalClass)(this, ppterminalclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaType(this: *mut core::ffi::c_void, plmediatype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminal", "MediaType", 11usize, |this| ((**(this as *const *const ITTerminal_Vtbl)).MediaType)(this, plmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Direction(this: *mut core::ffi::c_void, pdirection: *mut TERMINAL_DIRECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminal", "Direction", 12usize, |this| ((**(this as *const *const ITTerminal_Vtbl)).Direction)(this, pdirection), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            State,
+            TerminalType,
+            TerminalClass,
+            MediaType,
+            Direction,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITTerminal as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITTerminalSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StaticTerminals(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminalSupport", "StaticTerminals", 7usize, |this| ((**(this as *const *const ITTerminalSupport_Vtbl)).StaticTerminals)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateStaticTerminals(this: *mut core::ffi::c_void, ppterminalenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminalSupport", "EnumerateStaticTerminals", 8usize, |this| ((**(this as *const *const ITTerminalSupport_Vtbl)).EnumerateStaticTerminals)(this, ppterminalenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn DynamicTerminalClasses(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminalSupport", "DynamicTerminalClasses", 9usize, |this| ((**(this as *const *const ITTerminalSupport_Vtbl)).DynamicTerminalClasses)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateDynamicTerminalClasses(this: *mut core::ffi::c_void, ppterminalclassenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminalSupport", "EnumerateDynamicTerminalClasses", 10usize, |this| ((**(this as *const *const ITTerminalSupport_Vtbl)).EnumerateDynamicTerminalClasses)(this, ppterminalclassenumerator), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateTerminal(this: *mut core::ffi::c_void, pterminalclass: core::mem::MaybeUninit<windows_core::BSTR>, lmediatype: i32, direction: TERMINAL_DIRECTION, ppterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminalSupport", "CreateTerminal", 11usize, |this| ((**(this as *const *const ITTerminalSupport_Vtbl)).CreateTerminal)(this, pterminalclass, lmediatype, direction, ppterminal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDefaultStaticTerminal(this: *mut core::ffi::c_void, lmediatype: i32, direction: TERMINAL_DIRECTION, ppterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminalSupport", "GetDefaultStaticTerminal", 12usize, |this| ((**(this as *const *const ITTerminalSupport_Vtbl)).GetDefaultStaticTerminal)(this, lmediatype, direction, ppterminal), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StaticTerminals,
+            EnumerateStaticTerminals,
+            DynamicTerminalClasses,
+            EnumerateDynamicTerminalClasses,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateTerminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateTerminal: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDefaultStaticTerminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDefaultStaticTerminal: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITTerminalSupport as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITTerminalSupport2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PluggableSuperclasses(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminalSupport2", "PluggableSuperclasses", 13usize, |this| ((**(this as *const *const ITTerminalSupport2_Vtbl)).PluggableSuperclasses)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumeratePluggableSuperclasses(this: *mut core::ffi::c_void, ppsuperclassenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminalSupport2", "EnumeratePluggableSuperclasses", 14usize, |this| ((**(this as *const *const ITTerminalSupport2_Vtbl)).EnumeratePluggableSuperclasses)(this, ppsuperclassenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PluggableTerminalClasses(this: *mut core::ffi::c_void, bstrterminalsuperclass: core::mem::MaybeUninit<windows_core::BSTR>, lmediatype: i32, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminalSupport2", "get_PluggableTerminalClasses", 15usize, |this| ((**(this as *const *const ITTerminalSupport2_Vtbl)).get_PluggableTerminalClasses)(this, bstrterminalsuperclass, lmediatype, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumeratePluggableTerminalClasses(this: *mut core::ffi::c_void, iidterminalsuperclass: windows_core::GUID, lmediatype: i32, ppclassenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITTerminalSupport2", "EnumeratePluggableTerminalClasses", 16usize, |this| ((**(this as *const *const ITTerminalSupport2_Vtbl)).EnumeratePluggableTerminalClasses)(this, iidterminalsuperclass, lmediatype, ppclassenumerator), |result| Some(*result))
+        }
+        Self {
+            base__: <ITTerminalSupport_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PluggableSuperclasses,
+            EnumeratePluggableSuperclasses,
+            get_PluggableTerminalClasses,
+            EnumeratePluggableTerminalClasses,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITTerminalSupport2 as windows_core::Interface>::IID || <ITTerminalSupport_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITToneDetectionEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcallinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITToneDetectionEvent", "Call", 7usize, |this| ((**(this as *const *const ITToneDetectionEvent_Vtbl)).Call)(this, ppcallinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppSpecific(this: *mut core::ffi::c_void, plappspecific: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITToneDetectionEvent", "AppSpecific", 8usize, |this| ((**(this as *const *const ITToneDetectionEvent_Vtbl)).AppSpecific)(this, plappspecific), |result| Some(*result))
+        }
+        unsafe extern "system" fn TickCount(this: *mut core::ffi::c_void, pltickcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITToneDetectionEvent", "TickCount", 9usize, |this| ((**(this as *const *const ITToneDetectionEvent_Vtbl)).TickCount)(this, pltickcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallbackInstance(this: *mut core::ffi::c_void, plcallbackinstance: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITToneDetectionEvent", "CallbackInstance", 10usize, |this| ((**(this as *const *const ITToneDetectionEvent_Vtbl)).CallbackInstance)(this, plcallbackinstance), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+            AppSpecific,
+            TickCount,
+            CallbackInstance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITToneDetectionEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITToneTerminalEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Terminal(this: *mut core::ffi::c_void, ppterminal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITToneTerminalEvent", "Terminal", 7usize, |this| ((**(this as *const *const ITToneTerminalEvent_Vtbl)).Terminal)(this, ppterminal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, ppcall: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITToneTerminalEvent", "Call", 8usize, |this| ((**(this as *const *const ITToneTerminalEvent_Vtbl)).Call)(this, ppcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, phrerrorcode: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITToneTerminalEvent", "Error", 9usize, |this| ((**(this as *const *const ITToneTerminalEvent_Vtbl)).Error)(this, phrerrorcode), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Terminal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Terminal: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Call,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Call: 0,
+            Error,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITToneTerminalEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_AddressBook", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ITnef_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_AddressBook")]
+        unsafe extern "system" fn AddProps(this: *mut core::ffi::c_void, ulflags: u32, ulelemid: u32, lpvdata: *mut core::ffi::c_void, lpproplist: *mut super::super::System::AddressBook::SPropTagArray) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITnef", "AddProps", 3usize, |this| ((**(this as *const *const ITnef_Vtbl)).AddProps)(this, ulflags, ulelemid, lpvdata, lpproplist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_AddressBook")]
+        unsafe extern "system" fn ExtractProps(this: *mut core::ffi::c_void, ulflags: u32, lpproplist: *mut super::super::System::AddressBook::SPropTagArray, lpproblems: *mut *mut STnefProblemArray) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITnef", "ExtractProps", 4usize, |this| ((**(this as *const *const ITnef_Vtbl)).ExtractProps)(this, ulflags, lpproplist, lpproblems), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish(this: *mut core::ffi::c_void, ulflags: u32, lpkey: *mut u16, lpproblems: *mut *mut STnefProblemArray) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITnef", "Finish", 5usize, |this| ((**(this as *const *const ITnef_Vtbl)).Finish)(this, ulflags, lpkey, lpproblems), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_AddressBook", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn OpenTaggedBody(this: *mut core::ffi::c_void, lpmessage: *mut core::ffi::c_void, ulflags: u32, lppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITnef", "OpenTaggedBody", 6usize, |this| ((**(this as *const *const ITnef_Vtbl)).OpenTaggedBody)(this, lpmessage, ulflags, lppstream), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_AddressBook", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn SetProps(this: *mut core::ffi::c_void, ulflags: u32, ulelemid: u32, cvalues: u32, lpprops: *mut super::super::System::AddressBook::SPropValue) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITnef", "SetProps", 7usize, |this| ((**(this as *const *const ITnef_Vtbl)).SetProps)(this, ulflags, ulelemid, cvalues, lpprops), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_AddressBook")]
+        unsafe extern "system" fn EncodeRecips(this: *mut core::ffi::c_void, ulflags: u32, lprecipienttable: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITnef", "EncodeRecips", 8usize, |this| ((**(this as *const *const ITnef_Vtbl)).EncodeRecips)(this, ulflags, lprecipienttable), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_AddressBook", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn FinishComponent(this: *mut core::ffi::c_void, ulflags: u32, ulcomponentid: u32, lpcustomproplist: *mut super::super::System::AddressBook::SPropTagArray, lpcustomprops: *mut super::super::System::AddressBook::SPropValue, lpproplist: *mut super::super::System::AddressBook::SPropTagArray, lpproblems: *mut *mut STnefProblemArray) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITnef", "FinishComponent", 9usize, |this| ((**(this as *const *const ITnef_Vtbl)).FinishComponent)(this, ulflags, ulcomponentid, lpcustomproplist, lpcustomprops, lpproplist, lpproblems), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_AddressBook")]
+            AddProps,
+            #[cfg(not(feature = "Win32_System_AddressBook"))]
+            AddProps: 0,
+            #[cfg(feature = "Win32_System_AddressBook")]
+            ExtractProps,
+            #[cfg(not(feature = "Win32_System_AddressBook"))]
+            ExtractProps: 0,
+            Finish,
+            #[cfg(all(feature = "Win32_System_AddressBook", feature = "Win32_System_Com"))]
+            OpenTaggedBody,
+            #[cfg(not(all(feature = "Win32_System_AddressBook", feature = "Win32_System_Com")))]
+            OpenTaggedBody: 0,
+            #[cfg(all(feature = "Win32_System_AddressBook", feature = "Win32_System_Com"))]
+            SetProps,
+            #[cfg(not(all(feature = "Win32_System_AddressBook", feature = "Win32_System_Com")))]
+            SetProps: 0,
+            #[cfg(feature = "Win32_System_AddressBook")]
+            EncodeRecips,
+            #[cfg(not(feature = "Win32_System_AddressBook"))]
+            EncodeRecips: 0,
+            #[cfg(all(feature = "Win32_System_AddressBook", feature = "Win32_System_Com"))]
+            FinishComponent,
+            #[cfg(not(all(feature = "Win32_System_AddressBook", feature = "Win32_System_Com")))]
+            FinishComponent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITnef as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
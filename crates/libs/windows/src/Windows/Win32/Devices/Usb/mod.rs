@@ -959,6 +959,7 @@ pub struct PIPE_TYPE(pub i32);
 impl windows_core::TypeKind for PIPE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PIPE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PIPE_TYPE").field(&self.0).finish()
@@ -970,6 +971,7 @@ pub struct RAW_PIPE_TYPE(pub i32);
 impl windows_core::TypeKind for RAW_PIPE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RAW_PIPE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RAW_PIPE_TYPE").field(&self.0).finish()
@@ -981,6 +983,7 @@ pub struct USBD_ENDPOINT_OFFLOAD_MODE(pub i32);
 impl windows_core::TypeKind for USBD_ENDPOINT_OFFLOAD_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USBD_ENDPOINT_OFFLOAD_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USBD_ENDPOINT_OFFLOAD_MODE").field(&self.0).finish()
@@ -992,6 +995,7 @@ pub struct USBD_PIPE_TYPE(pub i32);
 impl windows_core::TypeKind for USBD_PIPE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USBD_PIPE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USBD_PIPE_TYPE").field(&self.0).finish()
@@ -1003,6 +1007,7 @@ pub struct USBFN_BUS_SPEED(pub i32);
 impl windows_core::TypeKind for USBFN_BUS_SPEED {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USBFN_BUS_SPEED {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USBFN_BUS_SPEED").field(&self.0).finish()
@@ -1014,6 +1019,7 @@ pub struct USBFN_DEVICE_STATE(pub i32);
 impl windows_core::TypeKind for USBFN_DEVICE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USBFN_DEVICE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USBFN_DEVICE_STATE").field(&self.0).finish()
@@ -1025,6 +1031,7 @@ pub struct USBFN_DIRECTION(pub i32);
 impl windows_core::TypeKind for USBFN_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USBFN_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USBFN_DIRECTION").field(&self.0).finish()
@@ -1036,6 +1043,7 @@ pub struct USBFN_EVENT(pub i32);
 impl windows_core::TypeKind for USBFN_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USBFN_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USBFN_EVENT").field(&self.0).finish()
@@ -1047,6 +1055,7 @@ pub struct USBFN_PORT_TYPE(pub i32);
 impl windows_core::TypeKind for USBFN_PORT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USBFN_PORT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USBFN_PORT_TYPE").field(&self.0).finish()
@@ -1058,6 +1067,7 @@ pub struct USB_CONNECTION_STATUS(pub i32);
 impl windows_core::TypeKind for USB_CONNECTION_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USB_CONNECTION_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USB_CONNECTION_STATUS").field(&self.0).finish()
@@ -1069,6 +1079,7 @@ pub struct USB_CONTROLLER_FLAVOR(pub i32);
 impl windows_core::TypeKind for USB_CONTROLLER_FLAVOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USB_CONTROLLER_FLAVOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USB_CONTROLLER_FLAVOR").field(&self.0).finish()
@@ -1080,6 +1091,7 @@ pub struct USB_DEVICE_SPEED(pub i32);
 impl windows_core::TypeKind for USB_DEVICE_SPEED {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USB_DEVICE_SPEED {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USB_DEVICE_SPEED").field(&self.0).finish()
@@ -1091,6 +1103,7 @@ pub struct USB_DEVICE_TYPE(pub i32);
 impl windows_core::TypeKind for USB_DEVICE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USB_DEVICE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USB_DEVICE_TYPE").field(&self.0).finish()
@@ -1102,6 +1115,7 @@ pub struct USB_HUB_NODE(pub i32);
 impl windows_core::TypeKind for USB_HUB_NODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USB_HUB_NODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USB_HUB_NODE").field(&self.0).finish()
@@ -1113,6 +1127,7 @@ pub struct USB_HUB_TYPE(pub i32);
 impl windows_core::TypeKind for USB_HUB_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USB_HUB_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USB_HUB_TYPE").field(&self.0).finish()
@@ -1124,6 +1139,7 @@ pub struct USB_NOTIFICATION_TYPE(pub i32);
 impl windows_core::TypeKind for USB_NOTIFICATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USB_NOTIFICATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USB_NOTIFICATION_TYPE").field(&self.0).finish()
@@ -1135,6 +1151,7 @@ pub struct USB_USER_ERROR_CODE(pub i32);
 impl windows_core::TypeKind for USB_USER_ERROR_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USB_USER_ERROR_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USB_USER_ERROR_CODE").field(&self.0).finish()
@@ -1146,6 +1163,7 @@ pub struct USB_WMI_DEVICE_NODE_TYPE(pub i32);
 impl windows_core::TypeKind for USB_WMI_DEVICE_NODE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USB_WMI_DEVICE_NODE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USB_WMI_DEVICE_NODE_TYPE").field(&self.0).finish()
@@ -1157,6 +1175,7 @@ pub struct WDMUSB_POWER_STATE(pub i32);
 impl windows_core::TypeKind for WDMUSB_POWER_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDMUSB_POWER_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDMUSB_POWER_STATE").field(&self.0).finish()
@@ -1168,6 +1187,7 @@ pub struct WINUSB_PIPE_POLICY(pub u32);
 impl windows_core::TypeKind for WINUSB_PIPE_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINUSB_PIPE_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINUSB_PIPE_POLICY").field(&self.0).finish()
@@ -1179,13 +1199,15 @@ pub struct WINUSB_POWER_POLICY(pub u32);
 impl windows_core::TypeKind for WINUSB_POWER_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINUSB_POWER_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINUSB_POWER_POLICY").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ALTERNATE_INTERFACE {
     pub InterfaceNumber: u16,
     pub AlternateInterfaceNumber: u16,
@@ -1213,10 +1235,37 @@ impl Default for BM_REQUEST_TYPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BM_REQUEST_TYPE_0 {
     pub _bitfield: u8,
 }
+impl BM_REQUEST_TYPE_0 {
+    pub const fn Recipient(&self) -> u8 {
+        self._bitfield & 3
+    }
+    pub fn set_Recipient(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 2) & 7
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 2)) | ((value & 7) << 2);
+    }
+    pub const fn Type(&self) -> u8 {
+        (self._bitfield >> 5) & 3
+    }
+    pub fn set_Type(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 5)) | ((value & 3) << 5);
+    }
+    pub const fn Dir(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Dir(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for BM_REQUEST_TYPE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1226,7 +1275,8 @@ impl Default for BM_REQUEST_TYPE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CHANNEL_INFO {
     pub EventChannelSize: u32,
     pub uReadDataAlignment: u32,
@@ -1241,7 +1291,8 @@ impl Default for CHANNEL_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVICE_DESCRIPTOR {
     pub usVendorId: u16,
     pub usProductId: u16,
@@ -1257,7 +1308,8 @@ impl Default for DEVICE_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRV_VERSION {
     pub major: u32,
     pub minor: u32,
@@ -1383,7 +1435,8 @@ impl Default for HUB_DEVICE_CONFIG_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_BLOCK {
     pub uOffset: u32,
     pub uLength: u32,
@@ -1399,7 +1452,8 @@ impl Default for IO_BLOCK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_BLOCK_EX {
     pub uOffset: u32,
     pub uLength: u32,
@@ -1595,6 +1649,56 @@ pub struct USBD_ENDPOINT_OFFLOAD_INFORMATION {
     pub EventRingSize: usize,
     pub EventRingInitialCycleBit: u32,
 }
+impl USBD_ENDPOINT_OFFLOAD_INFORMATION {
+    pub const fn RootHubPortNumber(&self) -> u32 {
+        self._bitfield1 & 255
+    }
+    pub fn set_RootHubPortNumber(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !255) | (value & 255);
+    }
+    pub const fn RouteString(&self) -> u32 {
+        (self._bitfield1 >> 8) & 1048575
+    }
+    pub fn set_RouteString(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1048575 << 8)) | ((value & 1048575) << 8);
+    }
+    pub const fn Speed(&self) -> u32 {
+        (self._bitfield1 >> 28) & 15
+    }
+    pub fn set_Speed(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(15 << 28)) | ((value & 15) << 28);
+    }
+    pub const fn UsbDeviceAddress(&self) -> u32 {
+        self._bitfield2 & 255
+    }
+    pub fn set_UsbDeviceAddress(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !255) | (value & 255);
+    }
+    pub const fn SlotId(&self) -> u32 {
+        (self._bitfield2 >> 8) & 255
+    }
+    pub fn set_SlotId(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn MultiTT(&self) -> u32 {
+        (self._bitfield2 >> 16) & 1
+    }
+    pub fn set_MultiTT(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn LSOrFSDeviceConnectedToTTHub(&self) -> u32 {
+        (self._bitfield2 >> 17) & 1
+    }
+    pub fn set_LSOrFSDeviceConnectedToTTHub(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield2 >> 18) & 16383
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(16383 << 18)) | ((value & 16383) << 18);
+    }
+}
 impl windows_core::TypeKind for USBD_ENDPOINT_OFFLOAD_INFORMATION {
     type TypeKind = windows_core::CopyType;
 }
@@ -1604,7 +1708,8 @@ impl Default for USBD_ENDPOINT_OFFLOAD_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USBD_INTERFACE_INFORMATION {
     pub Length: u16,
     pub InterfaceNumber: u8,
@@ -1626,7 +1731,8 @@ impl Default for USBD_INTERFACE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USBD_ISO_PACKET_DESCRIPTOR {
     pub Offset: u32,
     pub Length: u32,
@@ -1641,7 +1747,8 @@ impl Default for USBD_ISO_PACKET_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USBD_PIPE_INFORMATION {
     pub MaximumPacketSize: u16,
     pub EndpointAddress: u8,
@@ -1660,7 +1767,8 @@ impl Default for USBD_PIPE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USBD_STREAM_INFORMATION {
     pub PipeHandle: *mut core::ffi::c_void,
     pub StreamID: u32,
@@ -1676,7 +1784,8 @@ impl Default for USBD_STREAM_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USBD_VERSION_INFORMATION {
     pub USBDI_Version: u32,
     pub Supported_USB_Version: u32,
@@ -1690,7 +1799,8 @@ impl Default for USBD_VERSION_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USBFN_BUS_CONFIGURATION_INFO {
     pub ConfigurationName: [u16; 40],
     pub IsCurrent: super::super::Foundation::BOOLEAN,
@@ -1772,7 +1882,8 @@ impl Default for USBFN_CLASS_INTERFACE_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USBFN_INTERFACE_INFO {
     pub InterfaceNumber: u8,
     pub Speed: USBFN_BUS_SPEED,
@@ -1833,7 +1944,8 @@ impl Default for USBFN_PIPE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USBFN_USB_STRING {
     pub StringIndex: u8,
     pub UsbString: [u16; 255],
@@ -1847,7 +1959,8 @@ impl Default for USBFN_USB_STRING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USBSCAN_GET_DESCRIPTOR {
     pub DescriptorType: u8,
     pub Index: u8,
@@ -1862,7 +1975,8 @@ impl Default for USBSCAN_GET_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USBSCAN_PIPE_CONFIGURATION {
     pub NumberOfPipes: u32,
     pub PipeInfo: [USBSCAN_PIPE_INFORMATION; 8],
@@ -1876,7 +1990,8 @@ impl Default for USBSCAN_PIPE_CONFIGURATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USBSCAN_PIPE_INFORMATION {
     pub MaximumPacketSize: u16,
     pub EndpointAddress: u8,
@@ -1892,7 +2007,8 @@ impl Default for USBSCAN_PIPE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USBSCAN_TIMEOUT {
     pub TimeoutRead: u32,
     pub TimeoutWrite: u32,
@@ -2165,6 +2281,44 @@ impl Default for USB_20_PORT_CHANGE {
 pub struct USB_20_PORT_CHANGE_0 {
     pub _bitfield: u16,
 }
+impl USB_20_PORT_CHANGE_0 {
+    pub const fn ConnectStatusChange(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_ConnectStatusChange(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PortEnableDisableChange(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PortEnableDisableChange(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn SuspendChange(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_SuspendChange(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn OverCurrentIndicatorChange(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_OverCurrentIndicatorChange(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ResetChange(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_ResetChange(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved2(&self) -> u16 {
+        (self._bitfield >> 5) & 2047
+    }
+    pub fn set_Reserved2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(2047 << 5)) | ((value & 2047) << 5);
+    }
+}
 impl windows_core::TypeKind for USB_20_PORT_CHANGE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2192,6 +2346,86 @@ impl Default for USB_20_PORT_STATUS {
 pub struct USB_20_PORT_STATUS_0 {
     pub _bitfield: u16,
 }
+impl USB_20_PORT_STATUS_0 {
+    pub const fn CurrentConnectStatus(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_CurrentConnectStatus(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PortEnabledDisabled(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PortEnabledDisabled(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Suspend(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Suspend(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn OverCurrent(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_OverCurrent(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reset(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Reset(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn L1(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_L1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved0(&self) -> u16 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_Reserved0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn PortPower(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_PortPower(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn LowSpeedDeviceAttached(&self) -> u16 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_LowSpeedDeviceAttached(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn HighSpeedDeviceAttached(&self) -> u16 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_HighSpeedDeviceAttached(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn PortTestMode(&self) -> u16 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_PortTestMode(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn PortIndicatorControl(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_PortIndicatorControl(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn Reserved1(&self) -> u16 {
+        (self._bitfield >> 13) & 7
+    }
+    pub fn set_Reserved1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 13)) | ((value & 7) << 13);
+    }
+}
 impl windows_core::TypeKind for USB_20_PORT_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2240,6 +2474,56 @@ impl Default for USB_30_PORT_CHANGE {
 pub struct USB_30_PORT_CHANGE_0 {
     pub _bitfield: u16,
 }
+impl USB_30_PORT_CHANGE_0 {
+    pub const fn ConnectStatusChange(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_ConnectStatusChange(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved2(&self) -> u16 {
+        (self._bitfield >> 1) & 3
+    }
+    pub fn set_Reserved2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 1)) | ((value & 3) << 1);
+    }
+    pub const fn OverCurrentIndicatorChange(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_OverCurrentIndicatorChange(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ResetChange(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_ResetChange(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn BHResetChange(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_BHResetChange(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn PortLinkStateChange(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_PortLinkStateChange(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn PortConfigErrorChange(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_PortConfigErrorChange(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Reserved3(&self) -> u16 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_Reserved3(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+}
 impl windows_core::TypeKind for USB_30_PORT_CHANGE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2267,6 +2551,62 @@ impl Default for USB_30_PORT_STATUS {
 pub struct USB_30_PORT_STATUS_0 {
     pub _bitfield: u16,
 }
+impl USB_30_PORT_STATUS_0 {
+    pub const fn CurrentConnectStatus(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_CurrentConnectStatus(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PortEnabledDisabled(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PortEnabledDisabled(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved0(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Reserved0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn OverCurrent(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_OverCurrent(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reset(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Reset(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PortLinkState(&self) -> u16 {
+        (self._bitfield >> 5) & 15
+    }
+    pub fn set_PortLinkState(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 5)) | ((value & 15) << 5);
+    }
+    pub const fn PortPower(&self) -> u16 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_PortPower(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn NegotiatedDeviceSpeed(&self) -> u16 {
+        (self._bitfield >> 10) & 7
+    }
+    pub fn set_NegotiatedDeviceSpeed(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 10)) | ((value & 7) << 10);
+    }
+    pub const fn Reserved1(&self) -> u16 {
+        (self._bitfield >> 13) & 7
+    }
+    pub fn set_Reserved1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 13)) | ((value & 7) << 13);
+    }
+}
 impl windows_core::TypeKind for USB_30_PORT_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2374,7 +2714,8 @@ impl Default for USB_BUS_STATISTICS_0 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USB_CHANGE_REGISTRATION_HANDLE(pub *mut core::ffi::c_void);
 impl USB_CHANGE_REGISTRATION_HANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -2403,7 +2744,8 @@ impl Default for USB_CLOSE_RAW_DEVICE_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USB_COMMON_DESCRIPTOR {
     pub bLength: u8,
     pub bDescriptorType: u8,
@@ -2434,7 +2776,8 @@ impl Default for USB_COMPOSITE_DEVICE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USB_COMPOSITE_FUNCTION_INFO {
     pub FunctionNumber: u8,
     pub BaseInterfaceNumber: u8,
@@ -2593,7 +2936,8 @@ impl Default for USB_DEFAULT_PIPE_SETUP_PACKET_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USB_DEFAULT_PIPE_SETUP_PACKET_0_0 {
     pub LowByte: u8,
     pub HiByte: u8,
@@ -2621,7 +2965,8 @@ impl Default for USB_DEFAULT_PIPE_SETUP_PACKET_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USB_DEFAULT_PIPE_SETUP_PACKET_1_0 {
     pub LowByte: u8,
     pub HiByte: u8,
@@ -2722,6 +3067,26 @@ impl Default for USB_DEVICE_CAPABILITY_BILLBOARD_DESCRIPTOR_1 {
 pub struct USB_DEVICE_CAPABILITY_BILLBOARD_DESCRIPTOR_1_0 {
     pub _bitfield: u16,
 }
+impl USB_DEVICE_CAPABILITY_BILLBOARD_DESCRIPTOR_1_0 {
+    pub const fn VConnPowerNeededForFullFunctionality(&self) -> u16 {
+        self._bitfield & 7
+    }
+    pub fn set_VConnPowerNeededForFullFunctionality(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 3) & 4095
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(4095 << 3)) | ((value & 4095) << 3);
+    }
+    pub const fn NoVconnPowerRequired(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_NoVconnPowerRequired(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for USB_DEVICE_CAPABILITY_BILLBOARD_DESCRIPTOR_1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2731,7 +3096,8 @@ impl Default for USB_DEVICE_CAPABILITY_BILLBOARD_DESCRIPTOR_1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USB_DEVICE_CAPABILITY_CONTAINER_ID_DESCRIPTOR {
     pub bLength: u8,
     pub bDescriptorType: u8,
@@ -2748,7 +3114,8 @@ impl Default for USB_DEVICE_CAPABILITY_CONTAINER_ID_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USB_DEVICE_CAPABILITY_DESCRIPTOR {
     pub bLength: u8,
     pub bDescriptorType: u8,
@@ -2798,6 +3165,26 @@ impl Default for USB_DEVICE_CAPABILITY_FIRMWARE_STATUS_DESCRIPTOR_0 {
 pub struct USB_DEVICE_CAPABILITY_FIRMWARE_STATUS_DESCRIPTOR_0_0 {
     pub _bitfield: u32,
 }
+impl USB_DEVICE_CAPABILITY_FIRMWARE_STATUS_DESCRIPTOR_0_0 {
+    pub const fn GetFirmwareImageHashSupport(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_GetFirmwareImageHashSupport(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DisallowFirmwareUpdateSupport(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DisallowFirmwareUpdateSupport(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for USB_DEVICE_CAPABILITY_FIRMWARE_STATUS_DESCRIPTOR_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2848,6 +3235,32 @@ impl Default for USB_DEVICE_CAPABILITY_PD_CONSUMER_PORT_DESCRIPTOR_0 {
 pub struct USB_DEVICE_CAPABILITY_PD_CONSUMER_PORT_DESCRIPTOR_0_0 {
     pub _bitfield: u16,
 }
+impl USB_DEVICE_CAPABILITY_PD_CONSUMER_PORT_DESCRIPTOR_0_0 {
+    pub const fn BatteryCharging(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_BatteryCharging(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn USBPowerDelivery(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_USBPowerDelivery(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn USBTypeCCurrent(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_USBTypeCCurrent(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 3) & 8191
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(8191 << 3)) | ((value & 8191) << 3);
+    }
+}
 impl windows_core::TypeKind for USB_DEVICE_CAPABILITY_PD_CONSUMER_PORT_DESCRIPTOR_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2915,6 +3328,92 @@ impl Default for USB_DEVICE_CAPABILITY_POWER_DELIVERY_DESCRIPTOR_0 {
 pub struct USB_DEVICE_CAPABILITY_POWER_DELIVERY_DESCRIPTOR_0_0 {
     pub _bitfield: u32,
 }
+impl USB_DEVICE_CAPABILITY_POWER_DELIVERY_DESCRIPTOR_0_0 {
+    pub const fn Reserved1(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn BatteryCharging(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_BatteryCharging(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn USBPowerDelivery(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_USBPowerDelivery(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Provider(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Provider(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Consumer(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Consumer(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn ChargingPolicy(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_ChargingPolicy(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn TypeCCurrent(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_TypeCCurrent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn ACSupply(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_ACSupply(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Battery(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_Battery(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Other(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_Other(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn NumBatteries(&self) -> u32 {
+        (self._bitfield >> 11) & 7
+    }
+    pub fn set_NumBatteries(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 11)) | ((value & 7) << 11);
+    }
+    pub const fn UsesVbus(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_UsesVbus(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn Reserved3(&self) -> u32 {
+        (self._bitfield >> 15) & 131071
+    }
+    pub fn set_Reserved3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(131071 << 15)) | ((value & 131071) << 15);
+    }
+}
 impl windows_core::TypeKind for USB_DEVICE_CAPABILITY_POWER_DELIVERY_DESCRIPTOR_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2942,6 +3441,50 @@ impl Default for USB_DEVICE_CAPABILITY_SUPERSPEEDPLUS_SPEED {
 pub struct USB_DEVICE_CAPABILITY_SUPERSPEEDPLUS_SPEED_0 {
     pub _bitfield: u32,
 }
+impl USB_DEVICE_CAPABILITY_SUPERSPEEDPLUS_SPEED_0 {
+    pub const fn SublinkSpeedAttrID(&self) -> u32 {
+        self._bitfield & 15
+    }
+    pub fn set_SublinkSpeedAttrID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn LaneSpeedExponent(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_LaneSpeedExponent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn SublinkTypeMode(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_SublinkTypeMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn SublinkTypeDir(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_SublinkTypeDir(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 8) & 63
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 8)) | ((value & 63) << 8);
+    }
+    pub const fn LinkProtocol(&self) -> u32 {
+        (self._bitfield >> 14) & 3
+    }
+    pub fn set_LinkProtocol(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 14)) | ((value & 3) << 14);
+    }
+    pub const fn LaneSpeedMantissa(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_LaneSpeedMantissa(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for USB_DEVICE_CAPABILITY_SUPERSPEEDPLUS_SPEED_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2989,6 +3532,26 @@ impl Default for USB_DEVICE_CAPABILITY_SUPERSPEEDPLUS_USB_DESCRIPTOR_0 {
 pub struct USB_DEVICE_CAPABILITY_SUPERSPEEDPLUS_USB_DESCRIPTOR_0_0 {
     pub _bitfield: u32,
 }
+impl USB_DEVICE_CAPABILITY_SUPERSPEEDPLUS_USB_DESCRIPTOR_0_0 {
+    pub const fn SublinkSpeedAttrCount(&self) -> u32 {
+        self._bitfield & 31
+    }
+    pub fn set_SublinkSpeedAttrCount(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn SublinkSpeedIDCount(&self) -> u32 {
+        (self._bitfield >> 5) & 15
+    }
+    pub fn set_SublinkSpeedIDCount(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 5)) | ((value & 15) << 5);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 9) & 8388607
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8388607 << 9)) | ((value & 8388607) << 9);
+    }
+}
 impl windows_core::TypeKind for USB_DEVICE_CAPABILITY_SUPERSPEEDPLUS_USB_DESCRIPTOR_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3016,6 +3579,32 @@ impl Default for USB_DEVICE_CAPABILITY_SUPERSPEEDPLUS_USB_DESCRIPTOR_1 {
 pub struct USB_DEVICE_CAPABILITY_SUPERSPEEDPLUS_USB_DESCRIPTOR_1_0 {
     pub _bitfield: u16,
 }
+impl USB_DEVICE_CAPABILITY_SUPERSPEEDPLUS_USB_DESCRIPTOR_1_0 {
+    pub const fn SublinkSpeedAttrID(&self) -> u16 {
+        self._bitfield & 15
+    }
+    pub fn set_SublinkSpeedAttrID(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn MinRxLaneCount(&self) -> u16 {
+        (self._bitfield >> 8) & 15
+    }
+    pub fn set_MinRxLaneCount(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 8)) | ((value & 15) << 8);
+    }
+    pub const fn MinTxLaneCount(&self) -> u16 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_MinTxLaneCount(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+}
 impl windows_core::TypeKind for USB_DEVICE_CAPABILITY_SUPERSPEEDPLUS_USB_DESCRIPTOR_1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3079,6 +3668,62 @@ impl Default for USB_DEVICE_CAPABILITY_USB20_EXTENSION_DESCRIPTOR_0 {
 pub struct USB_DEVICE_CAPABILITY_USB20_EXTENSION_DESCRIPTOR_0_0 {
     pub _bitfield: u32,
 }
+impl USB_DEVICE_CAPABILITY_USB20_EXTENSION_DESCRIPTOR_0_0 {
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn LPMCapable(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_LPMCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn BESLAndAlternateHIRDSupported(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_BESLAndAlternateHIRDSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn BaselineBESLValid(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_BaselineBESLValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn DeepBESLValid(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_DeepBESLValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn BaselineBESL(&self) -> u32 {
+        (self._bitfield >> 8) & 15
+    }
+    pub fn set_BaselineBESL(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 8)) | ((value & 15) << 8);
+    }
+    pub const fn DeepBESL(&self) -> u32 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_DeepBESL(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for USB_DEVICE_CAPABILITY_USB20_EXTENSION_DESCRIPTOR_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3252,6 +3897,20 @@ impl Default for USB_DEVICE_QUALIFIER_DESCRIPTOR {
 pub struct USB_DEVICE_STATE {
     pub _bitfield: u32,
 }
+impl USB_DEVICE_STATE {
+    pub const fn DeviceConnected(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_DeviceConnected(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DeviceStarted(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DeviceStarted(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+}
 impl windows_core::TypeKind for USB_DEVICE_STATE {
     type TypeKind = windows_core::CopyType;
 }
@@ -3279,6 +3938,44 @@ impl Default for USB_DEVICE_STATUS {
 pub struct USB_DEVICE_STATUS_0 {
     pub _bitfield: u16,
 }
+impl USB_DEVICE_STATUS_0 {
+    pub const fn SelfPowered(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_SelfPowered(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn RemoteWakeup(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_RemoteWakeup(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn U1Enable(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_U1Enable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn U2Enable(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_U2Enable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn LtmEnable(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_LtmEnable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 5) & 2047
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(2047 << 5)) | ((value & 2047) << 5);
+    }
+}
 impl windows_core::TypeKind for USB_DEVICE_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3342,6 +4039,20 @@ impl Default for USB_ENDPOINT_STATUS {
 pub struct USB_ENDPOINT_STATUS_0 {
     pub _bitfield: u16,
 }
+impl USB_ENDPOINT_STATUS_0 {
+    pub const fn Halt(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_Halt(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 1) & 32767
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(32767 << 1)) | ((value & 32767) << 1);
+    }
+}
 impl windows_core::TypeKind for USB_ENDPOINT_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3388,10 +4099,31 @@ impl Default for USB_FUNCTION_SUSPEND_OPTIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USB_FUNCTION_SUSPEND_OPTIONS_0 {
     pub _bitfield: u8,
 }
+impl USB_FUNCTION_SUSPEND_OPTIONS_0 {
+    pub const fn PowerState(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_PowerState(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn RemoteWakeEnabled(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_RemoteWakeEnabled(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+}
 impl windows_core::TypeKind for USB_FUNCTION_SUSPEND_OPTIONS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3432,6 +4164,26 @@ impl Default for USB_HIGH_SPEED_MAXPACKET {
 pub struct USB_HIGH_SPEED_MAXPACKET_0 {
     pub _bitfield: u16,
 }
+impl USB_HIGH_SPEED_MAXPACKET_0 {
+    pub const fn MaxPacket(&self) -> u16 {
+        self._bitfield & 2047
+    }
+    pub fn set_MaxPacket(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !2047) | (value & 2047);
+    }
+    pub const fn HSmux(&self) -> u16 {
+        (self._bitfield >> 11) & 3
+    }
+    pub fn set_HSmux(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 11)) | ((value & 3) << 11);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 13) & 7
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 13)) | ((value & 7) << 13);
+    }
+}
 impl windows_core::TypeKind for USB_HIGH_SPEED_MAXPACKET_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3455,10 +4207,37 @@ impl Default for USB_HUB_30_PORT_REMOTE_WAKE_MASK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USB_HUB_30_PORT_REMOTE_WAKE_MASK_0 {
     pub _bitfield: u8,
 }
+impl USB_HUB_30_PORT_REMOTE_WAKE_MASK_0 {
+    pub const fn ConnectRemoteWakeEnable(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_ConnectRemoteWakeEnable(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DisconnectRemoteWakeEnable(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DisconnectRemoteWakeEnable(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn OverCurrentRemoteWakeEnable(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_OverCurrentRemoteWakeEnable(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved0(&self) -> u8 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Reserved0(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+}
 impl windows_core::TypeKind for USB_HUB_30_PORT_REMOTE_WAKE_MASK_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3472,6 +4251,14 @@ impl Default for USB_HUB_30_PORT_REMOTE_WAKE_MASK_0 {
 pub struct USB_HUB_CAPABILITIES {
     pub _bitfield: u32,
 }
+impl USB_HUB_CAPABILITIES {
+    pub const fn HubIs2xCapable(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_HubIs2xCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+}
 impl windows_core::TypeKind for USB_HUB_CAPABILITIES {
     type TypeKind = windows_core::CopyType;
 }
@@ -3512,6 +4299,56 @@ impl Default for USB_HUB_CAP_FLAGS {
 pub struct USB_HUB_CAP_FLAGS_0 {
     pub _bitfield: u32,
 }
+impl USB_HUB_CAP_FLAGS_0 {
+    pub const fn HubIsHighSpeedCapable(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_HubIsHighSpeedCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn HubIsHighSpeed(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_HubIsHighSpeed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn HubIsMultiTtCapable(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_HubIsMultiTtCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn HubIsMultiTt(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_HubIsMultiTt(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn HubIsRoot(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_HubIsRoot(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn HubIsArmedWakeOnConnect(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_HubIsArmedWakeOnConnect(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn HubIsBusPowered(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_HubIsBusPowered(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn ReservedMBZ(&self) -> u32 {
+        (self._bitfield >> 7) & 33554431
+    }
+    pub fn set_ReservedMBZ(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(33554431 << 7)) | ((value & 33554431) << 7);
+    }
+}
 impl windows_core::TypeKind for USB_HUB_CAP_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3539,6 +4376,26 @@ impl Default for USB_HUB_CHANGE {
 pub struct USB_HUB_CHANGE_0 {
     pub _bitfield: u16,
 }
+impl USB_HUB_CHANGE_0 {
+    pub const fn LocalPowerChange(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_LocalPowerChange(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn OverCurrentChange(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_OverCurrentChange(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 2) & 16383
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(16383 << 2)) | ((value & 16383) << 2);
+    }
+}
 impl windows_core::TypeKind for USB_HUB_CHANGE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3698,6 +4555,26 @@ impl Default for USB_HUB_STATUS {
 pub struct USB_HUB_STATUS_0 {
     pub _bitfield: u16,
 }
+impl USB_HUB_STATUS_0 {
+    pub const fn LocalPowerLost(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_LocalPowerLost(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn OverCurrent(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_OverCurrent(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 2) & 16383
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(16383 << 2)) | ((value & 16383) << 2);
+    }
+}
 impl windows_core::TypeKind for USB_HUB_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3735,7 +4612,8 @@ impl Default for USB_HUB_STATUS_AND_CHANGE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USB_IDLE_CALLBACK_INFO {
     pub IdleCallback: USB_IDLE_CALLBACK,
     pub IdleContext: *mut core::ffi::c_void,
@@ -3765,7 +4643,8 @@ impl Default for USB_ID_STRING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USB_INTERFACE_ASSOCIATION_DESCRIPTOR {
     pub bLength: u8,
     pub bDescriptorType: u8,
@@ -3785,7 +4664,8 @@ impl Default for USB_INTERFACE_ASSOCIATION_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USB_INTERFACE_DESCRIPTOR {
     pub bLength: u8,
     pub bDescriptorType: u8,
@@ -3848,6 +4728,26 @@ impl Default for USB_INTERFACE_STATUS {
 pub struct USB_INTERFACE_STATUS_0 {
     pub _bitfield: u16,
 }
+impl USB_INTERFACE_STATUS_0 {
+    pub const fn RemoteWakeupCapable(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_RemoteWakeupCapable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn RemoteWakeupEnabled(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_RemoteWakeupEnabled(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 2) & 16383
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(16383 << 2)) | ((value & 16383) << 2);
+    }
+}
 impl windows_core::TypeKind for USB_INTERFACE_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3976,6 +4876,38 @@ impl Default for USB_NODE_CONNECTION_INFORMATION_EX_V2_FLAGS {
 pub struct USB_NODE_CONNECTION_INFORMATION_EX_V2_FLAGS_0 {
     pub _bitfield: u32,
 }
+impl USB_NODE_CONNECTION_INFORMATION_EX_V2_FLAGS_0 {
+    pub const fn DeviceIsOperatingAtSuperSpeedOrHigher(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_DeviceIsOperatingAtSuperSpeedOrHigher(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DeviceIsSuperSpeedCapableOrHigher(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DeviceIsSuperSpeedCapableOrHigher(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn DeviceIsOperatingAtSuperSpeedPlusOrHigher(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_DeviceIsOperatingAtSuperSpeedPlusOrHigher(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn DeviceIsSuperSpeedPlusCapableOrHigher(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_DeviceIsSuperSpeedPlusCapableOrHigher(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ReservedMBZ(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_ReservedMBZ(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for USB_NODE_CONNECTION_INFORMATION_EX_V2_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4135,6 +5067,38 @@ impl Default for USB_PORT_EXT_STATUS {
 pub struct USB_PORT_EXT_STATUS_0 {
     pub _bitfield: u32,
 }
+impl USB_PORT_EXT_STATUS_0 {
+    pub const fn RxSublinkSpeedID(&self) -> u32 {
+        self._bitfield & 15
+    }
+    pub fn set_RxSublinkSpeedID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn TxSublinkSpeedID(&self) -> u32 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_TxSublinkSpeedID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn RxLaneCount(&self) -> u32 {
+        (self._bitfield >> 8) & 15
+    }
+    pub fn set_RxLaneCount(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 8)) | ((value & 15) << 8);
+    }
+    pub const fn TxLaneCount(&self) -> u32 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_TxLaneCount(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for USB_PORT_EXT_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4190,6 +5154,38 @@ impl Default for USB_PORT_PROPERTIES {
 pub struct USB_PORT_PROPERTIES_0 {
     pub _bitfield: u32,
 }
+impl USB_PORT_PROPERTIES_0 {
+    pub const fn PortIsUserConnectable(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_PortIsUserConnectable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PortIsDebugCapable(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PortIsDebugCapable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn PortHasMultipleCompanions(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_PortHasMultipleCompanions(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn PortConnectorIsTypeC(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_PortConnectorIsTypeC(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ReservedMBZ(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_ReservedMBZ(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for USB_PORT_PROPERTIES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4282,6 +5278,32 @@ impl Default for USB_PROTOCOLS {
 pub struct USB_PROTOCOLS_0 {
     pub _bitfield: u32,
 }
+impl USB_PROTOCOLS_0 {
+    pub const fn Usb110(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Usb110(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Usb200(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Usb200(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Usb300(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Usb300(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ReservedMBZ(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_ReservedMBZ(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for USB_PROTOCOLS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4418,10 +5440,25 @@ impl Default for USB_SUPERSPEED_ENDPOINT_COMPANION_DESCRIPTOR_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USB_SUPERSPEED_ENDPOINT_COMPANION_DESCRIPTOR_0_0 {
     pub _bitfield: u8,
 }
+impl USB_SUPERSPEED_ENDPOINT_COMPANION_DESCRIPTOR_0_0 {
+    pub const fn MaxStreams(&self) -> u8 {
+        self._bitfield & 31
+    }
+    pub fn set_MaxStreams(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn Reserved1(&self) -> u8 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_Reserved1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+}
 impl windows_core::TypeKind for USB_SUPERSPEED_ENDPOINT_COMPANION_DESCRIPTOR_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4431,10 +5468,31 @@ impl Default for USB_SUPERSPEED_ENDPOINT_COMPANION_DESCRIPTOR_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USB_SUPERSPEED_ENDPOINT_COMPANION_DESCRIPTOR_0_1 {
     pub _bitfield: u8,
 }
+impl USB_SUPERSPEED_ENDPOINT_COMPANION_DESCRIPTOR_0_1 {
+    pub const fn Mult(&self) -> u8 {
+        self._bitfield & 3
+    }
+    pub fn set_Mult(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn Reserved2(&self) -> u8 {
+        (self._bitfield >> 2) & 31
+    }
+    pub fn set_Reserved2(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(31 << 2)) | ((value & 31) << 2);
+    }
+    pub const fn SspCompanion(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_SspCompanion(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for USB_SUPERSPEED_ENDPOINT_COMPANION_DESCRIPTOR_0_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4444,7 +5502,8 @@ impl Default for USB_SUPERSPEED_ENDPOINT_COMPANION_DESCRIPTOR_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USB_TOPOLOGY_ADDRESS {
     pub PciBusNumber: u32,
     pub PciDeviceNumber: u32,
@@ -4548,7 +5607,8 @@ impl Default for USB_USB2HW_VERSION_PARAMETERS {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINUSB_INTERFACE_HANDLE(pub *mut core::ffi::c_void);
 impl WINUSB_INTERFACE_HANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -4572,7 +5632,8 @@ impl windows_core::TypeKind for WINUSB_INTERFACE_HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINUSB_PIPE_INFORMATION {
     pub PipeType: USBD_PIPE_TYPE,
     pub PipeId: u8,
@@ -4588,7 +5649,8 @@ impl Default for WINUSB_PIPE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINUSB_PIPE_INFORMATION_EX {
     pub PipeType: USBD_PIPE_TYPE,
     pub PipeId: u8,
@@ -4622,7 +5684,8 @@ impl Default for WINUSB_SETUP_PACKET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_BULK_OR_INTERRUPT_TRANSFER {
     pub Hdr: _URB_HEADER,
     pub PipeHandle: *mut core::ffi::c_void,
@@ -4642,7 +5705,8 @@ impl Default for _URB_BULK_OR_INTERRUPT_TRANSFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_CONTROL_DESCRIPTOR_REQUEST {
     pub Hdr: _URB_HEADER,
     pub Reserved: *mut core::ffi::c_void,
@@ -4667,7 +5731,8 @@ impl Default for _URB_CONTROL_DESCRIPTOR_REQUEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_CONTROL_FEATURE_REQUEST {
     pub Hdr: _URB_HEADER,
     pub Reserved: *mut core::ffi::c_void,
@@ -4691,7 +5756,8 @@ impl Default for _URB_CONTROL_FEATURE_REQUEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_CONTROL_GET_CONFIGURATION_REQUEST {
     pub Hdr: _URB_HEADER,
     pub Reserved: *mut core::ffi::c_void,
@@ -4712,7 +5778,8 @@ impl Default for _URB_CONTROL_GET_CONFIGURATION_REQUEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_CONTROL_GET_INTERFACE_REQUEST {
     pub Hdr: _URB_HEADER,
     pub Reserved: *mut core::ffi::c_void,
@@ -4735,7 +5802,8 @@ impl Default for _URB_CONTROL_GET_INTERFACE_REQUEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_CONTROL_GET_STATUS_REQUEST {
     pub Hdr: _URB_HEADER,
     pub Reserved: *mut core::ffi::c_void,
@@ -4758,7 +5826,8 @@ impl Default for _URB_CONTROL_GET_STATUS_REQUEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_CONTROL_TRANSFER {
     pub Hdr: _URB_HEADER,
     pub PipeHandle: *mut core::ffi::c_void,
@@ -4779,7 +5848,8 @@ impl Default for _URB_CONTROL_TRANSFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_CONTROL_TRANSFER_EX {
     pub Hdr: _URB_HEADER,
     pub PipeHandle: *mut core::ffi::c_void,
@@ -4800,7 +5870,8 @@ impl Default for _URB_CONTROL_TRANSFER_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_CONTROL_VENDOR_OR_CLASS_REQUEST {
     pub Hdr: _URB_HEADER,
     pub Reserved: *mut core::ffi::c_void,
@@ -4825,7 +5896,8 @@ impl Default for _URB_CONTROL_VENDOR_OR_CLASS_REQUEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_FRAME_LENGTH_CONTROL {
     pub Hdr: _URB_HEADER,
 }
@@ -4838,7 +5910,8 @@ impl Default for _URB_FRAME_LENGTH_CONTROL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_GET_CURRENT_FRAME_NUMBER {
     pub Hdr: _URB_HEADER,
     pub FrameNumber: u32,
@@ -4852,7 +5925,8 @@ impl Default for _URB_GET_CURRENT_FRAME_NUMBER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_GET_FRAME_LENGTH {
     pub Hdr: _URB_HEADER,
     pub FrameLength: u32,
@@ -4867,7 +5941,8 @@ impl Default for _URB_GET_FRAME_LENGTH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_GET_ISOCH_PIPE_TRANSFER_PATH_DELAYS {
     pub Hdr: _URB_HEADER,
     pub PipeHandle: *mut core::ffi::c_void,
@@ -4883,7 +5958,8 @@ impl Default for _URB_GET_ISOCH_PIPE_TRANSFER_PATH_DELAYS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_HCD_AREA {
     pub Reserved8: [*mut core::ffi::c_void; 8],
 }
@@ -4896,7 +5972,8 @@ impl Default for _URB_HCD_AREA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_HEADER {
     pub Length: u16,
     pub Function: u16,
@@ -4913,7 +5990,8 @@ impl Default for _URB_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_ISOCH_TRANSFER {
     pub Hdr: _URB_HEADER,
     pub PipeHandle: *mut core::ffi::c_void,
@@ -4937,7 +6015,8 @@ impl Default for _URB_ISOCH_TRANSFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_OPEN_STATIC_STREAMS {
     pub Hdr: _URB_HEADER,
     pub PipeHandle: *mut core::ffi::c_void,
@@ -4955,7 +6034,8 @@ impl Default for _URB_OPEN_STATIC_STREAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_OS_FEATURE_DESCRIPTOR_REQUEST {
     pub Hdr: _URB_HEADER,
     pub Reserved: *mut core::ffi::c_void,
@@ -4972,6 +6052,20 @@ pub struct _URB_OS_FEATURE_DESCRIPTOR_REQUEST {
     pub MS_FeatureDescriptorIndex: u16,
     pub Reserved3: u16,
 }
+impl _URB_OS_FEATURE_DESCRIPTOR_REQUEST {
+    pub const fn Recipient(&self) -> u8 {
+        self._bitfield & 31
+    }
+    pub fn set_Recipient(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn Reserved1(&self) -> u8 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_Reserved1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+}
 impl windows_core::TypeKind for _URB_OS_FEATURE_DESCRIPTOR_REQUEST {
     type TypeKind = windows_core::CopyType;
 }
@@ -4981,7 +6075,8 @@ impl Default for _URB_OS_FEATURE_DESCRIPTOR_REQUEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_PIPE_REQUEST {
     pub Hdr: _URB_HEADER,
     pub PipeHandle: *mut core::ffi::c_void,
@@ -4996,7 +6091,8 @@ impl Default for _URB_PIPE_REQUEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_SELECT_CONFIGURATION {
     pub Hdr: _URB_HEADER,
     pub ConfigurationDescriptor: *mut USB_CONFIGURATION_DESCRIPTOR,
@@ -5012,7 +6108,8 @@ impl Default for _URB_SELECT_CONFIGURATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_SELECT_INTERFACE {
     pub Hdr: _URB_HEADER,
     pub ConfigurationHandle: *mut core::ffi::c_void,
@@ -5027,7 +6124,8 @@ impl Default for _URB_SELECT_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _URB_SET_FRAME_LENGTH {
     pub Hdr: _URB_HEADER,
     pub FrameLengthDelta: i32,
@@ -0,0 +1,919 @@
+impl windows_core::imp::ProxyVtable for IWSDAddress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, pszbuffer: windows_core::PWSTR, cchlength: u32, fsafe: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDAddress", "Serialize", 3usize, |this| ((**(this as *const *const IWSDAddress_Vtbl)).Serialize)(this, pszbuffer, cchlength, fsafe), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deserialize(this: *mut core::ffi::c_void, pszbuffer: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDAddress", "Deserialize", 4usize, |this| ((**(this as *const *const IWSDAddress_Vtbl)).Deserialize)(this, pszbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Serialize, Deserialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDAddress as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDAsyncCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AsyncOperationComplete(this: *mut core::ffi::c_void, pasyncresult: *mut core::ffi::c_void, pasyncstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDAsyncCallback", "AsyncOperationComplete", 3usize, |this| ((**(this as *const *const IWSDAsyncCallback_Vtbl)).AsyncOperationComplete)(this, pasyncresult, pasyncstate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AsyncOperationComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDAsyncCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDAsyncResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCallback(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pasyncstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDAsyncResult", "SetCallback", 3usize, |this| ((**(this as *const *const IWSDAsyncResult_Vtbl)).SetCallback)(this, pcallback, pasyncstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWaitHandle(this: *mut core::ffi::c_void, hwaithandle: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDAsyncResult", "SetWaitHandle", 4usize, |this| ((**(this as *const *const IWSDAsyncResult_Vtbl)).SetWaitHandle)(this, hwaithandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasCompleted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDAsyncResult", "HasCompleted", 5usize, |this| ((**(this as *const *const IWSDAsyncResult_Vtbl)).HasCompleted)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAsyncState(this: *mut core::ffi::c_void, ppasyncstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDAsyncResult", "GetAsyncState", 6usize, |this| ((**(this as *const *const IWSDAsyncResult_Vtbl)).GetAsyncState)(this, ppasyncstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDAsyncResult", "Abort", 7usize, |this| ((**(this as *const *const IWSDAsyncResult_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEvent(this: *mut core::ffi::c_void, pevent: *mut WSD_EVENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDAsyncResult", "GetEvent", 8usize, |this| ((**(this as *const *const IWSDAsyncResult_Vtbl)).GetEvent)(this, pevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEndpointProxy(this: *mut core::ffi::c_void, ppendpoint: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDAsyncResult", "GetEndpointProxy", 9usize, |this| ((**(this as *const *const IWSDAsyncResult_Vtbl)).GetEndpointProxy)(this, ppendpoint), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetCallback,
+            SetWaitHandle,
+            HasCompleted,
+            GetAsyncState,
+            Abort,
+            GetEvent,
+            GetEndpointProxy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDAsyncResult as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDAttachment_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDAttachment as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDDeviceHost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, pszlocalid: windows_core::PCWSTR, pcontext: *mut core::ffi::c_void, pphostaddresses: *const *mut core::ffi::c_void, dwhostaddresscount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceHost", "Init", 3usize, |this| ((**(this as *const *const IWSDDeviceHost_Vtbl)).Init)(this, pszlocalid, pcontext, pphostaddresses, dwhostaddresscount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, ullinstanceid: u64, pscopelist: *const WSD_URI_LIST, pnotificationsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceHost", "Start", 4usize, |this| ((**(this as *const *const IWSDDeviceHost_Vtbl)).Start)(this, ullinstanceid, pscopelist, pnotificationsink), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceHost", "Stop", 5usize, |this| ((**(this as *const *const IWSDDeviceHost_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceHost", "Terminate", 6usize, |this| ((**(this as *const *const IWSDDeviceHost_Vtbl)).Terminate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterPortType(this: *mut core::ffi::c_void, pporttype: *const WSD_PORT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceHost", "RegisterPortType", 7usize, |this| ((**(this as *const *const IWSDDeviceHost_Vtbl)).RegisterPortType)(this, pporttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMetadata(this: *mut core::ffi::c_void, pthismodelmetadata: *const WSD_THIS_MODEL_METADATA, pthisdevicemetadata: *const WSD_THIS_DEVICE_METADATA, phostmetadata: *const WSD_HOST_METADATA, pcustommetadata: *const WSD_METADATA_SECTION_LIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceHost", "SetMetadata", 8usize, |this| ((**(this as *const *const IWSDDeviceHost_Vtbl)).SetMetadata)(this, pthismodelmetadata, pthisdevicemetadata, phostmetadata, pcustommetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterService(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR, pservice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceHost", "RegisterService", 9usize, |this| ((**(this as *const *const IWSDDeviceHost_Vtbl)).RegisterService)(this, pszserviceid, pservice), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetireService(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceHost", "RetireService", 10usize, |this| ((**(this as *const *const IWSDDeviceHost_Vtbl)).RetireService)(this, pszserviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDynamicService(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR, pszendpointaddress: windows_core::PCWSTR, pporttype: *const WSD_PORT_TYPE, pportname: *const WSDXML_NAME, pany: *const WSDXML_ELEMENT, pservice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceHost", "AddDynamicService", 11usize, |this| ((**(this as *const *const IWSDDeviceHost_Vtbl)).AddDynamicService)(this, pszserviceid, pszendpointaddress, pporttype, pportname, pany, pservice), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDynamicService(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceHost", "RemoveDynamicService", 12usize, |this| ((**(this as *const *const IWSDDeviceHost_Vtbl)).RemoveDynamicService)(this, pszserviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceDiscoverable(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR, fdiscoverable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceHost", "SetServiceDiscoverable", 13usize, |this| ((**(this as *const *const IWSDDeviceHost_Vtbl)).SetServiceDiscoverable)(this, pszserviceid, fdiscoverable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalEvent(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR, pbody: *const core::ffi::c_void, poperation: *const WSD_OPERATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceHost", "SignalEvent", 14usize, |this| ((**(this as *const *const IWSDDeviceHost_Vtbl)).SignalEvent)(this, pszserviceid, pbody, poperation), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Init,
+            Start,
+            Stop,
+            Terminate,
+            RegisterPortType,
+            SetMetadata,
+            RegisterService,
+            RetireService,
+            AddDynamicService,
+            RemoveDynamicService,
+            SetServiceDiscoverable,
+            SignalEvent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDDeviceHost as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDDeviceHostNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetService(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR, ppservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceHostNotify", "GetService", 3usize, |this| ((**(this as *const *const IWSDDeviceHostNotify_Vtbl)).GetService)(this, pszserviceid, ppservice), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetService }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDDeviceHostNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDDeviceProxy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, pszdeviceid: windows_core::PCWSTR, pdeviceaddress: *mut core::ffi::c_void, pszlocalid: windows_core::PCWSTR, pcontext: *mut core::ffi::c_void, psponsor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceProxy", "Init", 3usize, |this| ((**(this as *const *const IWSDDeviceProxy_Vtbl)).Init)(this, pszdeviceid, pdeviceaddress, pszlocalid, pcontext, psponsor), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginGetMetadata(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceProxy", "BeginGetMetadata", 4usize, |this| ((**(this as *const *const IWSDDeviceProxy_Vtbl)).BeginGetMetadata)(this, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndGetMetadata(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceProxy", "EndGetMetadata", 5usize, |this| ((**(this as *const *const IWSDDeviceProxy_Vtbl)).EndGetMetadata)(this, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHostMetadata(this: *mut core::ffi::c_void, pphostmetadata: *mut *mut WSD_HOST_METADATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceProxy", "GetHostMetadata", 6usize, |this| ((**(this as *const *const IWSDDeviceProxy_Vtbl)).GetHostMetadata)(this, pphostmetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThisModelMetadata(this: *mut core::ffi::c_void, ppmanufacturermetadata: *mut *mut WSD_THIS_MODEL_METADATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceProxy", "GetThisModelMetadata", 7usize, |this| ((**(this as *const *const IWSDDeviceProxy_Vtbl)).GetThisModelMetadata)(this, ppmanufacturermetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThisDeviceMetadata(this: *mut core::ffi::c_void, ppthisdevicemetadata: *mut *mut WSD_THIS_DEVICE_METADATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceProxy", "GetThisDeviceMetadata", 8usize, |this| ((**(this as *const *const IWSDDeviceProxy_Vtbl)).GetThisDeviceMetadata)(this, ppthisdevicemetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllMetadata(this: *mut core::ffi::c_void, ppmetadata: *mut *mut WSD_METADATA_SECTION_LIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceProxy", "GetAllMetadata", 9usize, |this| ((**(this as *const *const IWSDDeviceProxy_Vtbl)).GetAllMetadata)(this, ppmetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceProxyById(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR, ppserviceproxy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceProxy", "GetServiceProxyById", 10usize, |this| ((**(this as *const *const IWSDDeviceProxy_Vtbl)).GetServiceProxyById)(this, pszserviceid, ppserviceproxy), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceProxyByType(this: *mut core::ffi::c_void, ptype: *const WSDXML_NAME, ppserviceproxy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceProxy", "GetServiceProxyByType", 11usize, |this| ((**(this as *const *const IWSDDeviceProxy_Vtbl)).GetServiceProxyByType)(this, ptype, ppserviceproxy), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEndpointProxy(this: *mut core::ffi::c_void, ppproxy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDDeviceProxy", "GetEndpointProxy", 12usize, |this| ((**(this as *const *const IWSDDeviceProxy_Vtbl)).GetEndpointProxy)(this, ppproxy), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Init,
+            BeginGetMetadata,
+            EndGetMetadata,
+            GetHostMetadata,
+            GetThisModelMetadata,
+            GetThisDeviceMetadata,
+            GetAllMetadata,
+            GetServiceProxyById,
+            GetServiceProxyByType,
+            GetEndpointProxy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDDeviceProxy as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDEndpointProxy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendOneWayRequest(this: *mut core::ffi::c_void, pbody: *const core::ffi::c_void, poperation: *const WSD_OPERATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDEndpointProxy", "SendOneWayRequest", 3usize, |this| ((**(this as *const *const IWSDEndpointProxy_Vtbl)).SendOneWayRequest)(this, pbody, poperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendTwoWayRequest(this: *mut core::ffi::c_void, pbody: *const core::ffi::c_void, poperation: *const WSD_OPERATION, presponsecontext: *const WSD_SYNCHRONOUS_RESPONSE_CONTEXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDEndpointProxy", "SendTwoWayRequest", 4usize, |this| ((**(this as *const *const IWSDEndpointProxy_Vtbl)).SendTwoWayRequest)(this, pbody, poperation, presponsecontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendTwoWayRequestAsync(this: *mut core::ffi::c_void, pbody: *const core::ffi::c_void, poperation: *const WSD_OPERATION, pasyncstate: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, presult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDEndpointProxy", "SendTwoWayRequestAsync", 5usize, |this| ((**(this as *const *const IWSDEndpointProxy_Vtbl)).SendTwoWayRequestAsync)(this, pbody, poperation, pasyncstate, pcallback, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbortAsyncOperation(this: *mut core::ffi::c_void, pasyncresult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDEndpointProxy", "AbortAsyncOperation", 6usize, |this| ((**(this as *const *const IWSDEndpointProxy_Vtbl)).AbortAsyncOperation)(this, pasyncresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessFault(this: *mut core::ffi::c_void, pfault: *const WSD_SOAP_FAULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDEndpointProxy", "ProcessFault", 7usize, |this| ((**(this as *const *const IWSDEndpointProxy_Vtbl)).ProcessFault)(this, pfault), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorInfo(this: *mut core::ffi::c_void, ppszerrorinfo: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDEndpointProxy", "GetErrorInfo", 8usize, |this| ((**(this as *const *const IWSDEndpointProxy_Vtbl)).GetErrorInfo)(this, ppszerrorinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFaultInfo(this: *mut core::ffi::c_void, ppfault: *mut *mut WSD_SOAP_FAULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDEndpointProxy", "GetFaultInfo", 9usize, |this| ((**(this as *const *const IWSDEndpointProxy_Vtbl)).GetFaultInfo)(this, ppfault), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SendOneWayRequest,
+            SendTwoWayRequest,
+            SendTwoWayRequestAsync,
+            AbortAsyncOperation,
+            ProcessFault,
+            GetErrorInfo,
+            GetFaultInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDEndpointProxy as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDEventingStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SubscriptionRenewed(this: *mut core::ffi::c_void, pszsubscriptionaction: windows_core::PCWSTR) {
+            windows_core::imp::proxy_call(this, "IWSDEventingStatus", "SubscriptionRenewed", 3usize, |this| ((**(this as *const *const IWSDEventingStatus_Vtbl)).SubscriptionRenewed)(this, pszsubscriptionaction), |_| None)
+        }
+        unsafe extern "system" fn SubscriptionRenewalFailed(this: *mut core::ffi::c_void, pszsubscriptionaction: windows_core::PCWSTR, hr: windows_core::HRESULT) {
+            windows_core::imp::proxy_call(this, "IWSDEventingStatus", "SubscriptionRenewalFailed", 4usize, |this| ((**(this as *const *const IWSDEventingStatus_Vtbl)).SubscriptionRenewalFailed)(this, pszsubscriptionaction, hr), |_| None)
+        }
+        unsafe extern "system" fn SubscriptionEnded(this: *mut core::ffi::c_void, pszsubscriptionaction: windows_core::PCWSTR) {
+            windows_core::imp::proxy_call(this, "IWSDEventingStatus", "SubscriptionEnded", 5usize, |this| ((**(this as *const *const IWSDEventingStatus_Vtbl)).SubscriptionEnded)(this, pszsubscriptionaction), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SubscriptionRenewed,
+            SubscriptionRenewalFailed,
+            SubscriptionEnded,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDEventingStatus as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDHttpAddress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSecure(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDHttpAddress", "GetSecure", 10usize, |this| ((**(this as *const *const IWSDHttpAddress_Vtbl)).GetSecure)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecure(this: *mut core::ffi::c_void, fsecure: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDHttpAddress", "SetSecure", 11usize, |this| ((**(this as *const *const IWSDHttpAddress_Vtbl)).SetSecure)(this, fsecure), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPath(this: *mut core::ffi::c_void, ppszpath: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDHttpAddress", "GetPath", 12usize, |this| ((**(this as *const *const IWSDHttpAddress_Vtbl)).GetPath)(this, ppszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPath(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDHttpAddress", "SetPath", 13usize, |this| ((**(this as *const *const IWSDHttpAddress_Vtbl)).SetPath)(this, pszpath), |result| Some(*result))
+        }
+        Self { base__: <IWSDTransportAddress_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSecure, SetSecure, GetPath, SetPath }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDHttpAddress as windows_core::Interface>::IID || <IWSDTransportAddress_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDHttpAuthParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClientAccessToken(this: *mut core::ffi::c_void, phtoken: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDHttpAuthParameters", "GetClientAccessToken", 3usize, |this| ((**(this as *const *const IWSDHttpAuthParameters_Vtbl)).GetClientAccessToken)(this, phtoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAuthType(this: *mut core::ffi::c_void, pauthtype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDHttpAuthParameters", "GetAuthType", 4usize, |this| ((**(this as *const *const IWSDHttpAuthParameters_Vtbl)).GetAuthType)(this, pauthtype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetClientAccessToken, GetAuthType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDHttpAuthParameters as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDHttpMessageParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetInboundHttpHeaders(this: *mut core::ffi::c_void, pszheaders: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDHttpMessageParameters", "SetInboundHttpHeaders", 8usize, |this| ((**(this as *const *const IWSDHttpMessageParameters_Vtbl)).SetInboundHttpHeaders)(this, pszheaders), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInboundHttpHeaders(this: *mut core::ffi::c_void, ppszheaders: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDHttpMessageParameters", "GetInboundHttpHeaders", 9usize, |this| ((**(this as *const *const IWSDHttpMessageParameters_Vtbl)).GetInboundHttpHeaders)(this, ppszheaders), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutboundHttpHeaders(this: *mut core::ffi::c_void, pszheaders: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDHttpMessageParameters", "SetOutboundHttpHeaders", 10usize, |this| ((**(this as *const *const IWSDHttpMessageParameters_Vtbl)).SetOutboundHttpHeaders)(this, pszheaders), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutboundHttpHeaders(this: *mut core::ffi::c_void, ppszheaders: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDHttpMessageParameters", "GetOutboundHttpHeaders", 11usize, |this| ((**(this as *const *const IWSDHttpMessageParameters_Vtbl)).GetOutboundHttpHeaders)(this, ppszheaders), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetID(this: *mut core::ffi::c_void, pszid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDHttpMessageParameters", "SetID", 12usize, |this| ((**(this as *const *const IWSDHttpMessageParameters_Vtbl)).SetID)(this, pszid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetID(this: *mut core::ffi::c_void, ppszid: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDHttpMessageParameters", "GetID", 13usize, |this| ((**(this as *const *const IWSDHttpMessageParameters_Vtbl)).GetID)(this, ppszid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContext(this: *mut core::ffi::c_void, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDHttpMessageParameters", "SetContext", 14usize, |this| ((**(this as *const *const IWSDHttpMessageParameters_Vtbl)).SetContext)(this, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContext(this: *mut core::ffi::c_void, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDHttpMessageParameters", "GetContext", 15usize, |this| ((**(this as *const *const IWSDHttpMessageParameters_Vtbl)).GetContext)(this, ppcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDHttpMessageParameters", "Clear", 16usize, |this| ((**(this as *const *const IWSDHttpMessageParameters_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IWSDMessageParameters_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetInboundHttpHeaders,
+            GetInboundHttpHeaders,
+            SetOutboundHttpHeaders,
+            GetOutboundHttpHeaders,
+            SetID,
+            GetID,
+            SetContext,
+            GetContext,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDHttpMessageParameters as windows_core::Interface>::IID || <IWSDMessageParameters_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDInboundAttachment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, pbuffer: *mut u8, dwbytestoread: u32, pdwnumberofbytesread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDInboundAttachment", "Read", 3usize, |this| ((**(this as *const *const IWSDInboundAttachment_Vtbl)).Read)(this, pbuffer, dwbytestoread, pdwnumberofbytesread), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDInboundAttachment", "Close", 4usize, |this| ((**(this as *const *const IWSDInboundAttachment_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <IWSDAttachment_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Read, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDInboundAttachment as windows_core::Interface>::IID || <IWSDAttachment_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDMessageParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLocalAddress(this: *mut core::ffi::c_void, ppaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDMessageParameters", "GetLocalAddress", 3usize, |this| ((**(this as *const *const IWSDMessageParameters_Vtbl)).GetLocalAddress)(this, ppaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalAddress(this: *mut core::ffi::c_void, paddress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDMessageParameters", "SetLocalAddress", 4usize, |this| ((**(this as *const *const IWSDMessageParameters_Vtbl)).SetLocalAddress)(this, paddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRemoteAddress(this: *mut core::ffi::c_void, ppaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDMessageParameters", "GetRemoteAddress", 5usize, |this| ((**(this as *const *const IWSDMessageParameters_Vtbl)).GetRemoteAddress)(this, ppaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteAddress(this: *mut core::ffi::c_void, paddress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDMessageParameters", "SetRemoteAddress", 6usize, |this| ((**(this as *const *const IWSDMessageParameters_Vtbl)).SetRemoteAddress)(this, paddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLowerParameters(this: *mut core::ffi::c_void, pptxparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDMessageParameters", "GetLowerParameters", 7usize, |this| ((**(this as *const *const IWSDMessageParameters_Vtbl)).GetLowerParameters)(this, pptxparams), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLocalAddress,
+            SetLocalAddress,
+            GetRemoteAddress,
+            SetRemoteAddress,
+            GetLowerParameters,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDMessageParameters as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDMetadataExchange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMetadata(this: *mut core::ffi::c_void, metadataout: *mut *mut WSD_METADATA_SECTION_LIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDMetadataExchange", "GetMetadata", 3usize, |this| ((**(this as *const *const IWSDMetadataExchange_Vtbl)).GetMetadata)(this, metadataout), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMetadata }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDMetadataExchange as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDOutboundAttachment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, pbuffer: *const u8, dwbytestowrite: u32, pdwnumberofbyteswritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDOutboundAttachment", "Write", 3usize, |this| ((**(this as *const *const IWSDOutboundAttachment_Vtbl)).Write)(this, pbuffer, dwbytestowrite, pdwnumberofbyteswritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDOutboundAttachment", "Close", 4usize, |this| ((**(this as *const *const IWSDOutboundAttachment_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDOutboundAttachment", "Abort", 5usize, |this| ((**(this as *const *const IWSDOutboundAttachment_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        Self { base__: <IWSDAttachment_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Write, Close, Abort }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDOutboundAttachment as windows_core::Interface>::IID || <IWSDAttachment_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Security_Cryptography")]
+impl windows_core::imp::ProxyVtable for IWSDSSLClientCertificate_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Security_Cryptography")]
+        unsafe extern "system" fn GetClientCertificate(this: *mut core::ffi::c_void, ppcertcontext: *mut *mut super::super::Security::Cryptography::CERT_CONTEXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDSSLClientCertificate", "GetClientCertificate", 3usize, |this| ((**(this as *const *const IWSDSSLClientCertificate_Vtbl)).GetClientCertificate)(this, ppcertcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMappedAccessToken(this: *mut core::ffi::c_void, phtoken: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDSSLClientCertificate", "GetMappedAccessToken", 4usize, |this| ((**(this as *const *const IWSDSSLClientCertificate_Vtbl)).GetMappedAccessToken)(this, phtoken), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Security_Cryptography")]
+            GetClientCertificate,
+            #[cfg(not(feature = "Win32_Security_Cryptography"))]
+            GetClientCertificate: 0,
+            GetMappedAccessToken,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDSSLClientCertificate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDScopeMatchingRule_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetScopeRule(this: *mut core::ffi::c_void, ppszscopematchingrule: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDScopeMatchingRule", "GetScopeRule", 3usize, |this| ((**(this as *const *const IWSDScopeMatchingRule_Vtbl)).GetScopeRule)(this, ppszscopematchingrule), |result| Some(*result))
+        }
+        unsafe extern "system" fn MatchScopes(this: *mut core::ffi::c_void, pszscope1: windows_core::PCWSTR, pszscope2: windows_core::PCWSTR, pfmatch: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDScopeMatchingRule", "MatchScopes", 4usize, |this| ((**(this as *const *const IWSDScopeMatchingRule_Vtbl)).MatchScopes)(this, pszscope1, pszscope2, pfmatch), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetScopeRule, MatchScopes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDScopeMatchingRule as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDServiceMessaging_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendResponse(this: *mut core::ffi::c_void, pbody: *const core::ffi::c_void, poperation: *const WSD_OPERATION, pmessageparameters: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceMessaging", "SendResponse", 3usize, |this| ((**(this as *const *const IWSDServiceMessaging_Vtbl)).SendResponse)(this, pbody, poperation, pmessageparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn FaultRequest(this: *mut core::ffi::c_void, prequestheader: *const WSD_SOAP_HEADER, pmessageparameters: *mut core::ffi::c_void, pfault: *const WSD_SOAP_FAULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceMessaging", "FaultRequest", 4usize, |this| ((**(this as *const *const IWSDServiceMessaging_Vtbl)).FaultRequest)(this, prequestheader, pmessageparameters, pfault), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SendResponse, FaultRequest }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDServiceMessaging as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDServiceProxy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginGetMetadata(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxy", "BeginGetMetadata", 4usize, |this| ((**(this as *const *const IWSDServiceProxy_Vtbl)).BeginGetMetadata)(this, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndGetMetadata(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, ppmetadata: *mut *mut WSD_METADATA_SECTION_LIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxy", "EndGetMetadata", 5usize, |this| ((**(this as *const *const IWSDServiceProxy_Vtbl)).EndGetMetadata)(this, presult, ppmetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceMetadata(this: *mut core::ffi::c_void, ppservicemetadata: *mut *mut WSD_SERVICE_METADATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxy", "GetServiceMetadata", 6usize, |this| ((**(this as *const *const IWSDServiceProxy_Vtbl)).GetServiceMetadata)(this, ppservicemetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubscribeToOperation(this: *mut core::ffi::c_void, poperation: *const WSD_OPERATION, punknown: *mut core::ffi::c_void, pany: *const WSDXML_ELEMENT, ppany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxy", "SubscribeToOperation", 7usize, |this| ((**(this as *const *const IWSDServiceProxy_Vtbl)).SubscribeToOperation)(this, poperation, punknown, pany, ppany), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnsubscribeToOperation(this: *mut core::ffi::c_void, poperation: *const WSD_OPERATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxy", "UnsubscribeToOperation", 8usize, |this| ((**(this as *const *const IWSDServiceProxy_Vtbl)).UnsubscribeToOperation)(this, poperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventingStatusCallback(this: *mut core::ffi::c_void, pstatus: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxy", "SetEventingStatusCallback", 9usize, |this| ((**(this as *const *const IWSDServiceProxy_Vtbl)).SetEventingStatusCallback)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEndpointProxy(this: *mut core::ffi::c_void, ppproxy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxy", "GetEndpointProxy", 10usize, |this| ((**(this as *const *const IWSDServiceProxy_Vtbl)).GetEndpointProxy)(this, ppproxy), |result| Some(*result))
+        }
+        Self {
+            base__: <IWSDMetadataExchange_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BeginGetMetadata,
+            EndGetMetadata,
+            GetServiceMetadata,
+            SubscribeToOperation,
+            UnsubscribeToOperation,
+            SetEventingStatusCallback,
+            GetEndpointProxy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDServiceProxy as windows_core::Interface>::IID || <IWSDMetadataExchange_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDServiceProxyEventing_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SubscribeToMultipleOperations(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, punknown: *mut core::ffi::c_void, pexpires: *const WSD_EVENTING_EXPIRES, pany: *const WSDXML_ELEMENT, ppexpires: *mut *mut WSD_EVENTING_EXPIRES, ppany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxyEventing", "SubscribeToMultipleOperations", 11usize, |this| ((**(this as *const *const IWSDServiceProxyEventing_Vtbl)).SubscribeToMultipleOperations)(this, poperations, dwoperationcount, punknown, pexpires, pany, ppexpires, ppany), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginSubscribeToMultipleOperations(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, punknown: *mut core::ffi::c_void, pexpires: *const WSD_EVENTING_EXPIRES, pany: *const WSDXML_ELEMENT, pasyncstate: *mut core::ffi::c_void, pasynccallback: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxyEventing", "BeginSubscribeToMultipleOperations", 12usize, |this| ((**(this as *const *const IWSDServiceProxyEventing_Vtbl)).BeginSubscribeToMultipleOperations)(this, poperations, dwoperationcount, punknown, pexpires, pany, pasyncstate, pasynccallback, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndSubscribeToMultipleOperations(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, presult: *mut core::ffi::c_void, ppexpires: *mut *mut WSD_EVENTING_EXPIRES, ppany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxyEventing", "EndSubscribeToMultipleOperations", 13usize, |this| ((**(this as *const *const IWSDServiceProxyEventing_Vtbl)).EndSubscribeToMultipleOperations)(this, poperations, dwoperationcount, presult, ppexpires, ppany), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnsubscribeToMultipleOperations(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, pany: *const WSDXML_ELEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxyEventing", "UnsubscribeToMultipleOperations", 14usize, |this| ((**(this as *const *const IWSDServiceProxyEventing_Vtbl)).UnsubscribeToMultipleOperations)(this, poperations, dwoperationcount, pany), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginUnsubscribeToMultipleOperations(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, pany: *const WSDXML_ELEMENT, pasyncstate: *mut core::ffi::c_void, pasynccallback: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxyEventing", "BeginUnsubscribeToMultipleOperations", 15usize, |this| ((**(this as *const *const IWSDServiceProxyEventing_Vtbl)).BeginUnsubscribeToMultipleOperations)(this, poperations, dwoperationcount, pany, pasyncstate, pasynccallback, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndUnsubscribeToMultipleOperations(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, presult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxyEventing", "EndUnsubscribeToMultipleOperations", 16usize, |this| ((**(this as *const *const IWSDServiceProxyEventing_Vtbl)).EndUnsubscribeToMultipleOperations)(this, poperations, dwoperationcount, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenewMultipleOperations(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, pexpires: *const WSD_EVENTING_EXPIRES, pany: *const WSDXML_ELEMENT, ppexpires: *mut *mut WSD_EVENTING_EXPIRES, ppany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxyEventing", "RenewMultipleOperations", 17usize, |this| ((**(this as *const *const IWSDServiceProxyEventing_Vtbl)).RenewMultipleOperations)(this, poperations, dwoperationcount, pexpires, pany, ppexpires, ppany), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginRenewMultipleOperations(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, pexpires: *const WSD_EVENTING_EXPIRES, pany: *const WSDXML_ELEMENT, pasyncstate: *mut core::ffi::c_void, pasynccallback: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxyEventing", "BeginRenewMultipleOperations", 18usize, |this| ((**(this as *const *const IWSDServiceProxyEventing_Vtbl)).BeginRenewMultipleOperations)(this, poperations, dwoperationcount, pexpires, pany, pasyncstate, pasynccallback, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndRenewMultipleOperations(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, presult: *mut core::ffi::c_void, ppexpires: *mut *mut WSD_EVENTING_EXPIRES, ppany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxyEventing", "EndRenewMultipleOperations", 19usize, |this| ((**(this as *const *const IWSDServiceProxyEventing_Vtbl)).EndRenewMultipleOperations)(this, poperations, dwoperationcount, presult, ppexpires, ppany), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatusForMultipleOperations(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, pany: *const WSDXML_ELEMENT, ppexpires: *mut *mut WSD_EVENTING_EXPIRES, ppany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxyEventing", "GetStatusForMultipleOperations", 20usize, |this| ((**(this as *const *const IWSDServiceProxyEventing_Vtbl)).GetStatusForMultipleOperations)(this, poperations, dwoperationcount, pany, ppexpires, ppany), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginGetStatusForMultipleOperations(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, pany: *const WSDXML_ELEMENT, pasyncstate: *mut core::ffi::c_void, pasynccallback: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxyEventing", "BeginGetStatusForMultipleOperations", 21usize, |this| ((**(this as *const *const IWSDServiceProxyEventing_Vtbl)).BeginGetStatusForMultipleOperations)(this, poperations, dwoperationcount, pany, pasyncstate, pasynccallback, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndGetStatusForMultipleOperations(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, presult: *mut core::ffi::c_void, ppexpires: *mut *mut WSD_EVENTING_EXPIRES, ppany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDServiceProxyEventing", "EndGetStatusForMultipleOperations", 22usize, |this| ((**(this as *const *const IWSDServiceProxyEventing_Vtbl)).EndGetStatusForMultipleOperations)(this, poperations, dwoperationcount, presult, ppexpires, ppany), |result| Some(*result))
+        }
+        Self {
+            base__: <IWSDServiceProxy_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SubscribeToMultipleOperations,
+            BeginSubscribeToMultipleOperations,
+            EndSubscribeToMultipleOperations,
+            UnsubscribeToMultipleOperations,
+            BeginUnsubscribeToMultipleOperations,
+            EndUnsubscribeToMultipleOperations,
+            RenewMultipleOperations,
+            BeginRenewMultipleOperations,
+            EndRenewMultipleOperations,
+            GetStatusForMultipleOperations,
+            BeginGetStatusForMultipleOperations,
+            EndGetStatusForMultipleOperations,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDServiceProxyEventing as windows_core::Interface>::IID || <IWSDServiceProxy_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDSignatureProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsMessageSigned(this: *mut core::ffi::c_void, pbsigned: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDSignatureProperty", "IsMessageSigned", 3usize, |this| ((**(this as *const *const IWSDSignatureProperty_Vtbl)).IsMessageSigned)(this, pbsigned), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMessageSignatureTrusted(this: *mut core::ffi::c_void, pbsignaturetrusted: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDSignatureProperty", "IsMessageSignatureTrusted", 4usize, |this| ((**(this as *const *const IWSDSignatureProperty_Vtbl)).IsMessageSignatureTrusted)(this, pbsignaturetrusted), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeyInfo(this: *mut core::ffi::c_void, pbkeyinfo: *mut u8, pdwkeyinfosize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDSignatureProperty", "GetKeyInfo", 5usize, |this| ((**(this as *const *const IWSDSignatureProperty_Vtbl)).GetKeyInfo)(this, pbkeyinfo, pdwkeyinfosize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignature(this: *mut core::ffi::c_void, pbsignature: *mut u8, pdwsignaturesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDSignatureProperty", "GetSignature", 6usize, |this| ((**(this as *const *const IWSDSignatureProperty_Vtbl)).GetSignature)(this, pbsignature, pdwsignaturesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignedInfoHash(this: *mut core::ffi::c_void, pbsignedinfohash: *mut u8, pdwhashsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDSignatureProperty", "GetSignedInfoHash", 7usize, |this| ((**(this as *const *const IWSDSignatureProperty_Vtbl)).GetSignedInfoHash)(this, pbsignedinfohash, pdwhashsize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsMessageSigned,
+            IsMessageSignatureTrusted,
+            GetKeyInfo,
+            GetSignature,
+            GetSignedInfoHash,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDSignatureProperty as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDTransportAddress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPort(this: *mut core::ffi::c_void, pwport: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDTransportAddress", "GetPort", 5usize, |this| ((**(this as *const *const IWSDTransportAddress_Vtbl)).GetPort)(this, pwport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPort(this: *mut core::ffi::c_void, wport: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDTransportAddress", "SetPort", 6usize, |this| ((**(this as *const *const IWSDTransportAddress_Vtbl)).SetPort)(this, wport), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransportAddress(this: *mut core::ffi::c_void, ppszaddress: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDTransportAddress", "GetTransportAddress", 7usize, |this| ((**(this as *const *const IWSDTransportAddress_Vtbl)).GetTransportAddress)(this, ppszaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransportAddressEx(this: *mut core::ffi::c_void, fsafe: super::super::Foundation::BOOL, ppszaddress: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDTransportAddress", "GetTransportAddressEx", 8usize, |this| ((**(this as *const *const IWSDTransportAddress_Vtbl)).GetTransportAddressEx)(this, fsafe, ppszaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransportAddress(this: *mut core::ffi::c_void, pszaddress: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDTransportAddress", "SetTransportAddress", 9usize, |this| ((**(this as *const *const IWSDTransportAddress_Vtbl)).SetTransportAddress)(this, pszaddress), |result| Some(*result))
+        }
+        Self {
+            base__: <IWSDAddress_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPort,
+            SetPort,
+            GetTransportAddress,
+            GetTransportAddressEx,
+            SetTransportAddress,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDTransportAddress as windows_core::Interface>::IID || <IWSDAddress_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Networking_WinSock")]
+impl windows_core::imp::ProxyVtable for IWSDUdpAddress_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Networking_WinSock")]
+        unsafe extern "system" fn SetSockaddr(this: *mut core::ffi::c_void, psockaddr: *const super::super::Networking::WinSock::SOCKADDR_STORAGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDUdpAddress", "SetSockaddr", 10usize, |this| ((**(this as *const *const IWSDUdpAddress_Vtbl)).SetSockaddr)(this, psockaddr), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Networking_WinSock")]
+        unsafe extern "system" fn GetSockaddr(this: *mut core::ffi::c_void, psockaddr: *mut super::super::Networking::WinSock::SOCKADDR_STORAGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDUdpAddress", "GetSockaddr", 11usize, |this| ((**(this as *const *const IWSDUdpAddress_Vtbl)).GetSockaddr)(this, psockaddr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExclusive(this: *mut core::ffi::c_void, fexclusive: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDUdpAddress", "SetExclusive", 12usize, |this| ((**(this as *const *const IWSDUdpAddress_Vtbl)).SetExclusive)(this, fexclusive), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExclusive(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDUdpAddress", "GetExclusive", 13usize, |this| ((**(this as *const *const IWSDUdpAddress_Vtbl)).GetExclusive)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageType(this: *mut core::ffi::c_void, messagetype: WSDUdpMessageType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDUdpAddress", "SetMessageType", 14usize, |this| ((**(this as *const *const IWSDUdpAddress_Vtbl)).SetMessageType)(this, messagetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageType(this: *mut core::ffi::c_void, pmessagetype: *mut WSDUdpMessageType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDUdpAddress", "GetMessageType", 15usize, |this| ((**(this as *const *const IWSDUdpAddress_Vtbl)).GetMessageType)(this, pmessagetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTTL(this: *mut core::ffi::c_void, dwttl: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDUdpAddress", "SetTTL", 16usize, |this| ((**(this as *const *const IWSDUdpAddress_Vtbl)).SetTTL)(this, dwttl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTTL(this: *mut core::ffi::c_void, pdwttl: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDUdpAddress", "GetTTL", 17usize, |this| ((**(this as *const *const IWSDUdpAddress_Vtbl)).GetTTL)(this, pdwttl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlias(this: *mut core::ffi::c_void, palias: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDUdpAddress", "SetAlias", 18usize, |this| ((**(this as *const *const IWSDUdpAddress_Vtbl)).SetAlias)(this, palias), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlias(this: *mut core::ffi::c_void, palias: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDUdpAddress", "GetAlias", 19usize, |this| ((**(this as *const *const IWSDUdpAddress_Vtbl)).GetAlias)(this, palias), |result| Some(*result))
+        }
+        Self {
+            base__: <IWSDTransportAddress_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Networking_WinSock")]
+            SetSockaddr,
+            #[cfg(not(feature = "Win32_Networking_WinSock"))]
+            SetSockaddr: 0,
+            #[cfg(feature = "Win32_Networking_WinSock")]
+            GetSockaddr,
+            #[cfg(not(feature = "Win32_Networking_WinSock"))]
+            GetSockaddr: 0,
+            SetExclusive,
+            GetExclusive,
+            SetMessageType,
+            GetMessageType,
+            SetTTL,
+            GetTTL,
+            SetAlias,
+            GetAlias,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDUdpAddress as windows_core::Interface>::IID || <IWSDTransportAddress_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDUdpMessageParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRetransmitParams(this: *mut core::ffi::c_void, pparams: *const WSDUdpRetransmitParams) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDUdpMessageParameters", "SetRetransmitParams", 8usize, |this| ((**(this as *const *const IWSDUdpMessageParameters_Vtbl)).SetRetransmitParams)(this, pparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRetransmitParams(this: *mut core::ffi::c_void, pparams: *mut WSDUdpRetransmitParams) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDUdpMessageParameters", "GetRetransmitParams", 9usize, |this| ((**(this as *const *const IWSDUdpMessageParameters_Vtbl)).GetRetransmitParams)(this, pparams), |result| Some(*result))
+        }
+        Self { base__: <IWSDMessageParameters_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetRetransmitParams, GetRetransmitParams }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDUdpMessageParameters as windows_core::Interface>::IID || <IWSDMessageParameters_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDXMLContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddNamespace(this: *mut core::ffi::c_void, pszuri: windows_core::PCWSTR, pszsuggestedprefix: windows_core::PCWSTR, ppnamespace: *mut *mut WSDXML_NAMESPACE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDXMLContext", "AddNamespace", 3usize, |this| ((**(this as *const *const IWSDXMLContext_Vtbl)).AddNamespace)(this, pszuri, pszsuggestedprefix, ppnamespace), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddNameToNamespace(this: *mut core::ffi::c_void, pszuri: windows_core::PCWSTR, pszname: windows_core::PCWSTR, ppname: *mut *mut WSDXML_NAME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDXMLContext", "AddNameToNamespace", 4usize, |this| ((**(this as *const *const IWSDXMLContext_Vtbl)).AddNameToNamespace)(this, pszuri, pszname, ppname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNamespaces(this: *mut core::ffi::c_void, pnamespaces: *const *const WSDXML_NAMESPACE, wnamespacescount: u16, blayernumber: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDXMLContext", "SetNamespaces", 5usize, |this| ((**(this as *const *const IWSDXMLContext_Vtbl)).SetNamespaces)(this, pnamespaces, wnamespacescount, blayernumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTypes(this: *mut core::ffi::c_void, ptypes: *const *const WSDXML_TYPE, dwtypescount: u32, blayernumber: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDXMLContext", "SetTypes", 6usize, |this| ((**(this as *const *const IWSDXMLContext_Vtbl)).SetTypes)(this, ptypes, dwtypescount, blayernumber), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddNamespace, AddNameToNamespace, SetNamespaces, SetTypes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDXMLContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDiscoveredService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEndpointReference(this: *mut core::ffi::c_void, ppendpointreference: *mut *mut WSD_ENDPOINT_REFERENCE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveredService", "GetEndpointReference", 3usize, |this| ((**(this as *const *const IWSDiscoveredService_Vtbl)).GetEndpointReference)(this, ppendpointreference), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypes(this: *mut core::ffi::c_void, pptypeslist: *mut *mut WSD_NAME_LIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveredService", "GetTypes", 4usize, |this| ((**(this as *const *const IWSDiscoveredService_Vtbl)).GetTypes)(this, pptypeslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScopes(this: *mut core::ffi::c_void, ppscopeslist: *mut *mut WSD_URI_LIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveredService", "GetScopes", 5usize, |this| ((**(this as *const *const IWSDiscoveredService_Vtbl)).GetScopes)(this, ppscopeslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXAddrs(this: *mut core::ffi::c_void, ppxaddrslist: *mut *mut WSD_URI_LIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveredService", "GetXAddrs", 6usize, |this| ((**(this as *const *const IWSDiscoveredService_Vtbl)).GetXAddrs)(this, ppxaddrslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMetadataVersion(this: *mut core::ffi::c_void, pullmetadataversion: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveredService", "GetMetadataVersion", 7usize, |this| ((**(this as *const *const IWSDiscoveredService_Vtbl)).GetMetadataVersion)(this, pullmetadataversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExtendedDiscoXML(this: *mut core::ffi::c_void, ppheaderany: *mut *mut WSDXML_ELEMENT, ppbodyany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveredService", "GetExtendedDiscoXML", 8usize, |this| ((**(this as *const *const IWSDiscoveredService_Vtbl)).GetExtendedDiscoXML)(this, ppheaderany, ppbodyany), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProbeResolveTag(this: *mut core::ffi::c_void, ppsztag: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveredService", "GetProbeResolveTag", 9usize, |this| ((**(this as *const *const IWSDiscoveredService_Vtbl)).GetProbeResolveTag)(this, ppsztag), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRemoteTransportAddress(this: *mut core::ffi::c_void, ppszremotetransportaddress: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveredService", "GetRemoteTransportAddress", 10usize, |this| ((**(this as *const *const IWSDiscoveredService_Vtbl)).GetRemoteTransportAddress)(this, ppszremotetransportaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalTransportAddress(this: *mut core::ffi::c_void, ppszlocaltransportaddress: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveredService", "GetLocalTransportAddress", 11usize, |this| ((**(this as *const *const IWSDiscoveredService_Vtbl)).GetLocalTransportAddress)(this, ppszlocaltransportaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalInterfaceGUID(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveredService", "GetLocalInterfaceGUID", 12usize, |this| ((**(this as *const *const IWSDiscoveredService_Vtbl)).GetLocalInterfaceGUID)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInstanceId(this: *mut core::ffi::c_void, pullinstanceid: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveredService", "GetInstanceId", 13usize, |this| ((**(this as *const *const IWSDiscoveredService_Vtbl)).GetInstanceId)(this, pullinstanceid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetEndpointReference,
+            GetTypes,
+            GetScopes,
+            GetXAddrs,
+            GetMetadataVersion,
+            GetExtendedDiscoXML,
+            GetProbeResolveTag,
+            GetRemoteTransportAddress,
+            GetLocalTransportAddress,
+            GetLocalInterfaceGUID,
+            GetInstanceId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDiscoveredService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDiscoveryProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAddressFamily(this: *mut core::ffi::c_void, dwaddressfamily: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryProvider", "SetAddressFamily", 3usize, |this| ((**(this as *const *const IWSDiscoveryProvider_Vtbl)).SetAddressFamily)(this, dwaddressfamily), |result| Some(*result))
+        }
+        unsafe extern "system" fn Attach(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryProvider", "Attach", 4usize, |this| ((**(this as *const *const IWSDiscoveryProvider_Vtbl)).Attach)(this, psink), |result| Some(*result))
+        }
+        unsafe extern "system" fn Detach(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryProvider", "Detach", 5usize, |this| ((**(this as *const *const IWSDiscoveryProvider_Vtbl)).Detach)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchById(this: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, psztag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryProvider", "SearchById", 6usize, |this| ((**(this as *const *const IWSDiscoveryProvider_Vtbl)).SearchById)(this, pszid, psztag), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchByAddress(this: *mut core::ffi::c_void, pszaddress: windows_core::PCWSTR, psztag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryProvider", "SearchByAddress", 7usize, |this| ((**(this as *const *const IWSDiscoveryProvider_Vtbl)).SearchByAddress)(this, pszaddress, psztag), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchByType(this: *mut core::ffi::c_void, ptypeslist: *const WSD_NAME_LIST, pscopeslist: *const WSD_URI_LIST, pszmatchby: windows_core::PCWSTR, psztag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryProvider", "SearchByType", 8usize, |this| ((**(this as *const *const IWSDiscoveryProvider_Vtbl)).SearchByType)(this, ptypeslist, pscopeslist, pszmatchby, psztag), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXMLContext(this: *mut core::ffi::c_void, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryProvider", "GetXMLContext", 9usize, |this| ((**(this as *const *const IWSDiscoveryProvider_Vtbl)).GetXMLContext)(this, ppcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAddressFamily,
+            Attach,
+            Detach,
+            SearchById,
+            SearchByAddress,
+            SearchByType,
+            GetXMLContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDiscoveryProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDiscoveryProviderNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pservice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryProviderNotify", "Add", 3usize, |this| ((**(this as *const *const IWSDiscoveryProviderNotify_Vtbl)).Add)(this, pservice), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, pservice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryProviderNotify", "Remove", 4usize, |this| ((**(this as *const *const IWSDiscoveryProviderNotify_Vtbl)).Remove)(this, pservice), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchFailed(this: *mut core::ffi::c_void, hr: windows_core::HRESULT, psztag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryProviderNotify", "SearchFailed", 5usize, |this| ((**(this as *const *const IWSDiscoveryProviderNotify_Vtbl)).SearchFailed)(this, hr, psztag), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchComplete(this: *mut core::ffi::c_void, psztag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryProviderNotify", "SearchComplete", 6usize, |this| ((**(this as *const *const IWSDiscoveryProviderNotify_Vtbl)).SearchComplete)(this, psztag), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Add, Remove, SearchFailed, SearchComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDiscoveryProviderNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDiscoveryPublisher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAddressFamily(this: *mut core::ffi::c_void, dwaddressfamily: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryPublisher", "SetAddressFamily", 3usize, |this| ((**(this as *const *const IWSDiscoveryPublisher_Vtbl)).SetAddressFamily)(this, dwaddressfamily), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterNotificationSink(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryPublisher", "RegisterNotificationSink", 4usize, |this| ((**(this as *const *const IWSDiscoveryPublisher_Vtbl)).RegisterNotificationSink)(this, psink), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnRegisterNotificationSink(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryPublisher", "UnRegisterNotificationSink", 5usize, |this| ((**(this as *const *const IWSDiscoveryPublisher_Vtbl)).UnRegisterNotificationSink)(this, psink), |result| Some(*result))
+        }
+        unsafe extern "system" fn Publish(this: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, ullmetadataversion: u64, ullinstanceid: u64, ullmessagenumber: u64, pszsessionid: windows_core::PCWSTR, ptypeslist: *const WSD_NAME_LIST, pscopeslist: *const WSD_URI_LIST, pxaddrslist: *const WSD_URI_LIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryPublisher", "Publish", 6usize, |this| ((**(this as *const *const IWSDiscoveryPublisher_Vtbl)).Publish)(this, pszid, ullmetadataversion, ullinstanceid, ullmessagenumber, pszsessionid, ptypeslist, pscopeslist, pxaddrslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnPublish(this: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, ullinstanceid: u64, ullmessagenumber: u64, pszsessionid: windows_core::PCWSTR, pany: *const WSDXML_ELEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryPublisher", "UnPublish", 7usize, |this| ((**(this as *const *const IWSDiscoveryPublisher_Vtbl)).UnPublish)(this, pszid, ullinstanceid, ullmessagenumber, pszsessionid, pany), |result| Some(*result))
+        }
+        unsafe extern "system" fn MatchProbe(this: *mut core::ffi::c_void, pprobemessage: *const WSD_SOAP_MESSAGE, pmessageparameters: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, ullmetadataversion: u64, ullinstanceid: u64, ullmessagenumber: u64, pszsessionid: windows_core::PCWSTR, ptypeslist: *const WSD_NAME_LIST, pscopeslist: *const WSD_URI_LIST, pxaddrslist: *const WSD_URI_LIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryPublisher", "MatchProbe", 8usize, |this| ((**(this as *const *const IWSDiscoveryPublisher_Vtbl)).MatchProbe)(this, pprobemessage, pmessageparameters, pszid, ullmetadataversion, ullinstanceid, ullmessagenumber, pszsessionid, ptypeslist, pscopeslist, pxaddrslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn MatchResolve(this: *mut core::ffi::c_void, presolvemessage: *const WSD_SOAP_MESSAGE, pmessageparameters: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, ullmetadataversion: u64, ullinstanceid: u64, ullmessagenumber: u64, pszsessionid: windows_core::PCWSTR, ptypeslist: *const WSD_NAME_LIST, pscopeslist: *const WSD_URI_LIST, pxaddrslist: *const WSD_URI_LIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryPublisher", "MatchResolve", 9usize, |this| ((**(this as *const *const IWSDiscoveryPublisher_Vtbl)).MatchResolve)(this, presolvemessage, pmessageparameters, pszid, ullmetadataversion, ullinstanceid, ullmessagenumber, pszsessionid, ptypeslist, pscopeslist, pxaddrslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublishEx(this: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, ullmetadataversion: u64, ullinstanceid: u64, ullmessagenumber: u64, pszsessionid: windows_core::PCWSTR, ptypeslist: *const WSD_NAME_LIST, pscopeslist: *const WSD_URI_LIST, pxaddrslist: *const WSD_URI_LIST, pheaderany: *const WSDXML_ELEMENT, preferenceparameterany: *const WSDXML_ELEMENT, ppolicyany: *const WSDXML_ELEMENT, pendpointreferenceany: *const WSDXML_ELEMENT, pany: *const WSDXML_ELEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryPublisher", "PublishEx", 10usize, |this| ((**(this as *const *const IWSDiscoveryPublisher_Vtbl)).PublishEx)(this, pszid, ullmetadataversion, ullinstanceid, ullmessagenumber, pszsessionid, ptypeslist, pscopeslist, pxaddrslist, pheaderany, preferenceparameterany, ppolicyany, pendpointreferenceany, pany), |result| Some(*result))
+        }
+        unsafe extern "system" fn MatchProbeEx(this: *mut core::ffi::c_void, pprobemessage: *const WSD_SOAP_MESSAGE, pmessageparameters: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, ullmetadataversion: u64, ullinstanceid: u64, ullmessagenumber: u64, pszsessionid: windows_core::PCWSTR, ptypeslist: *const WSD_NAME_LIST, pscopeslist: *const WSD_URI_LIST, pxaddrslist: *const WSD_URI_LIST, pheaderany: *const WSDXML_ELEMENT, preferenceparameterany: *const WSDXML_ELEMENT, ppolicyany: *const WSDXML_ELEMENT, pendpointreferenceany: *const WSDXML_ELEMENT, pany: *const WSDXML_ELEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryPublisher", "MatchProbeEx", 11usize, |this| ((**(this as *const *const IWSDiscoveryPublisher_Vtbl)).MatchProbeEx)(this, pprobemessage, pmessageparameters, pszid, ullmetadataversion, ullinstanceid, ullmessagenumber, pszsessionid, ptypeslist, pscopeslist, pxaddrslist, pheaderany, preferenceparameterany, ppolicyany, pendpointreferenceany, pany), |result| Some(*result))
+        }
+        unsafe extern "system" fn MatchResolveEx(this: *mut core::ffi::c_void, presolvemessage: *const WSD_SOAP_MESSAGE, pmessageparameters: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, ullmetadataversion: u64, ullinstanceid: u64, ullmessagenumber: u64, pszsessionid: windows_core::PCWSTR, ptypeslist: *const WSD_NAME_LIST, pscopeslist: *const WSD_URI_LIST, pxaddrslist: *const WSD_URI_LIST, pheaderany: *const WSDXML_ELEMENT, preferenceparameterany: *const WSDXML_ELEMENT, ppolicyany: *const WSDXML_ELEMENT, pendpointreferenceany: *const WSDXML_ELEMENT, pany: *const WSDXML_ELEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryPublisher", "MatchResolveEx", 12usize, |this| ((**(this as *const *const IWSDiscoveryPublisher_Vtbl)).MatchResolveEx)(this, presolvemessage, pmessageparameters, pszid, ullmetadataversion, ullinstanceid, ullmessagenumber, pszsessionid, ptypeslist, pscopeslist, pxaddrslist, pheaderany, preferenceparameterany, ppolicyany, pendpointreferenceany, pany), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterScopeMatchingRule(this: *mut core::ffi::c_void, pscopematchingrule: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryPublisher", "RegisterScopeMatchingRule", 13usize, |this| ((**(this as *const *const IWSDiscoveryPublisher_Vtbl)).RegisterScopeMatchingRule)(this, pscopematchingrule), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnRegisterScopeMatchingRule(this: *mut core::ffi::c_void, pscopematchingrule: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryPublisher", "UnRegisterScopeMatchingRule", 14usize, |this| ((**(this as *const *const IWSDiscoveryPublisher_Vtbl)).UnRegisterScopeMatchingRule)(this, pscopematchingrule), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXMLContext(this: *mut core::ffi::c_void, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryPublisher", "GetXMLContext", 15usize, |this| ((**(this as *const *const IWSDiscoveryPublisher_Vtbl)).GetXMLContext)(this, ppcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAddressFamily,
+            RegisterNotificationSink,
+            UnRegisterNotificationSink,
+            Publish,
+            UnPublish,
+            MatchProbe,
+            MatchResolve,
+            PublishEx,
+            MatchProbeEx,
+            MatchResolveEx,
+            RegisterScopeMatchingRule,
+            UnRegisterScopeMatchingRule,
+            GetXMLContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDiscoveryPublisher as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSDiscoveryPublisherNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProbeHandler(this: *mut core::ffi::c_void, psoap: *const WSD_SOAP_MESSAGE, pmessageparameters: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryPublisherNotify", "ProbeHandler", 3usize, |this| ((**(this as *const *const IWSDiscoveryPublisherNotify_Vtbl)).ProbeHandler)(this, psoap, pmessageparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolveHandler(this: *mut core::ffi::c_void, psoap: *const WSD_SOAP_MESSAGE, pmessageparameters: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSDiscoveryPublisherNotify", "ResolveHandler", 4usize, |this| ((**(this as *const *const IWSDiscoveryPublisherNotify_Vtbl)).ResolveHandler)(this, psoap, pmessageparameters), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProbeHandler, ResolveHandler }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDiscoveryPublisherNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
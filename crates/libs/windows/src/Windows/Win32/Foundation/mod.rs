@@ -10320,6 +10320,7 @@ pub struct DUPLICATE_HANDLE_OPTIONS(pub u32);
 impl windows_core::TypeKind for DUPLICATE_HANDLE_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DUPLICATE_HANDLE_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DUPLICATE_HANDLE_OPTIONS").field(&self.0).finish()
@@ -10364,6 +10365,7 @@ pub struct GENERIC_ACCESS_RIGHTS(pub u32);
 impl windows_core::TypeKind for GENERIC_ACCESS_RIGHTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GENERIC_ACCESS_RIGHTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GENERIC_ACCESS_RIGHTS").field(&self.0).finish()
@@ -10408,6 +10410,7 @@ pub struct HANDLE_FLAGS(pub u32);
 impl windows_core::TypeKind for HANDLE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HANDLE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HANDLE_FLAGS").field(&self.0).finish()
@@ -10452,6 +10455,7 @@ pub struct NTSTATUS_FACILITY_CODE(pub u32);
 impl windows_core::TypeKind for NTSTATUS_FACILITY_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NTSTATUS_FACILITY_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NTSTATUS_FACILITY_CODE").field(&self.0).finish()
@@ -10463,6 +10467,7 @@ pub struct NTSTATUS_SEVERITY_CODE(pub u32);
 impl windows_core::TypeKind for NTSTATUS_SEVERITY_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NTSTATUS_SEVERITY_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NTSTATUS_SEVERITY_CODE").field(&self.0).finish()
@@ -10474,6 +10479,7 @@ pub struct WAIT_EVENT(pub u32);
 impl windows_core::TypeKind for WAIT_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WAIT_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WAIT_EVENT").field(&self.0).finish()
@@ -10486,13 +10492,15 @@ pub struct WIN32_ERROR(pub u32);
 impl windows_core::TypeKind for WIN32_ERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WIN32_ERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WIN32_ERROR").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APP_LOCAL_DEVICE_ID {
     pub value: [u8; 32],
 }
@@ -10506,7 +10514,8 @@ impl Default for APP_LOCAL_DEVICE_ID {
 }
 #[must_use]
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BOOL(pub i32);
 impl Default for BOOL {
     fn default() -> Self {
@@ -10517,7 +10526,8 @@ impl windows_core::TypeKind for BOOL {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BOOLEAN(pub u8);
 impl Default for BOOLEAN {
     fn default() -> Self {
@@ -10528,7 +10538,8 @@ impl windows_core::TypeKind for BOOLEAN {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COLORREF(pub u32);
 impl Default for COLORREF {
     fn default() -> Self {
@@ -10569,7 +10580,8 @@ impl Default for DECIMAL_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DECIMAL_0_0 {
     pub scale: u8,
     pub sign: u8,
@@ -10597,7 +10609,8 @@ impl Default for DECIMAL_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DECIMAL_1_0 {
     pub Lo32: u32,
     pub Mid32: u32,
@@ -10611,7 +10624,8 @@ impl Default for DECIMAL_1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILETIME {
     pub dwLowDateTime: u32,
     pub dwHighDateTime: u32,
@@ -10625,7 +10639,8 @@ impl Default for FILETIME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FLOAT128 {
     pub LowPart: i64,
     pub HighPart: i64,
@@ -10639,7 +10654,8 @@ impl Default for FLOAT128 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HANDLE(pub *mut core::ffi::c_void);
 impl HANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -10663,7 +10679,8 @@ impl windows_core::TypeKind for HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HANDLE_PTR(pub usize);
 impl Default for HANDLE_PTR {
     fn default() -> Self {
@@ -10674,7 +10691,8 @@ impl windows_core::TypeKind for HANDLE_PTR {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HGLOBAL(pub *mut core::ffi::c_void);
 impl HGLOBAL {
     pub fn is_invalid(&self) -> bool {
@@ -10698,7 +10716,8 @@ impl windows_core::TypeKind for HGLOBAL {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HINSTANCE(pub *mut core::ffi::c_void);
 impl HINSTANCE {
     pub fn is_invalid(&self) -> bool {
@@ -10728,7 +10747,8 @@ impl From<HINSTANCE> for HMODULE {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HLOCAL(pub *mut core::ffi::c_void);
 impl HLOCAL {
     pub fn is_invalid(&self) -> bool {
@@ -10752,7 +10772,8 @@ impl windows_core::TypeKind for HLOCAL {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HLSURF(pub *mut core::ffi::c_void);
 impl HLSURF {
     pub fn is_invalid(&self) -> bool {
@@ -10768,7 +10789,8 @@ impl windows_core::TypeKind for HLSURF {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HMODULE(pub *mut core::ffi::c_void);
 impl HMODULE {
     pub fn is_invalid(&self) -> bool {
@@ -10798,7 +10820,8 @@ impl From<HMODULE> for HINSTANCE {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HRSRC(pub *mut core::ffi::c_void);
 impl HRSRC {
     pub fn is_invalid(&self) -> bool {
@@ -10814,7 +10837,8 @@ impl windows_core::TypeKind for HRSRC {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HSPRITE(pub *mut core::ffi::c_void);
 impl HSPRITE {
     pub fn is_invalid(&self) -> bool {
@@ -10830,7 +10854,8 @@ impl windows_core::TypeKind for HSPRITE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HSTR(pub *mut core::ffi::c_void);
 impl HSTR {
     pub fn is_invalid(&self) -> bool {
@@ -10846,7 +10871,8 @@ impl windows_core::TypeKind for HSTR {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HUMPD(pub *mut core::ffi::c_void);
 impl HUMPD {
     pub fn is_invalid(&self) -> bool {
@@ -10862,7 +10888,8 @@ impl windows_core::TypeKind for HUMPD {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HWND(pub *mut core::ffi::c_void);
 impl HWND {
     pub fn is_invalid(&self) -> bool {
@@ -10884,7 +10911,8 @@ impl From<HWND> for HANDLE {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LPARAM(pub isize);
 impl Default for LPARAM {
     fn default() -> Self {
@@ -10895,7 +10923,8 @@ impl windows_core::TypeKind for LPARAM {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LRESULT(pub isize);
 impl Default for LRESULT {
     fn default() -> Self {
@@ -10906,7 +10935,8 @@ impl windows_core::TypeKind for LRESULT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LUID {
     pub LowPart: u32,
     pub HighPart: i32,
@@ -10921,7 +10951,8 @@ impl Default for LUID {
 }
 #[must_use]
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NTSTATUS(pub i32);
 impl Default for NTSTATUS {
     fn default() -> Self {
@@ -10932,7 +10963,8 @@ impl windows_core::TypeKind for NTSTATUS {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POINT {
     pub x: i32,
     pub y: i32,
@@ -10946,7 +10978,8 @@ impl Default for POINT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POINTL {
     pub x: i32,
     pub y: i32,
@@ -10960,7 +10993,8 @@ impl Default for POINTL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POINTS {
     pub x: i16,
     pub y: i16,
@@ -10974,7 +11008,8 @@ impl Default for POINTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RECT {
     pub left: i32,
     pub top: i32,
@@ -10990,7 +11025,8 @@ impl Default for RECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RECTL {
     pub left: i32,
     pub top: i32,
@@ -11006,7 +11042,8 @@ impl Default for RECTL {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SHANDLE_PTR(pub isize);
 impl Default for SHANDLE_PTR {
     fn default() -> Self {
@@ -11017,7 +11054,8 @@ impl windows_core::TypeKind for SHANDLE_PTR {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SIZE {
     pub cx: i32,
     pub cy: i32,
@@ -11031,7 +11069,8 @@ impl Default for SIZE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYSTEMTIME {
     pub wYear: u16,
     pub wMonth: u16,
@@ -11051,7 +11090,8 @@ impl Default for SYSTEMTIME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UNICODE_STRING {
     pub Length: u16,
     pub MaximumLength: u16,
@@ -11066,7 +11106,8 @@ impl Default for UNICODE_STRING {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VARIANT_BOOL(pub i16);
 impl Default for VARIANT_BOOL {
     fn default() -> Self {
@@ -11077,7 +11118,8 @@ impl windows_core::TypeKind for VARIANT_BOOL {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WPARAM(pub usize);
 impl Default for WPARAM {
     fn default() -> Self {
@@ -11091,3 +11133,4 @@ pub type FARPROC = Option<unsafe extern "system" fn() -> isize>;
 pub type NEARPROC = Option<unsafe extern "system" fn() -> isize>;
 pub type PAPCFUNC = Option<unsafe extern "system" fn(parameter: usize)>;
 pub type PROC = Option<unsafe extern "system" fn() -> isize>;
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/Foundation/RawWindow.rs"));
@@ -660,6 +660,7 @@ pub struct GAMESTATS_OPEN_RESULT(pub i32);
 impl windows_core::TypeKind for GAMESTATS_OPEN_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GAMESTATS_OPEN_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GAMESTATS_OPEN_RESULT").field(&self.0).finish()
@@ -671,6 +672,7 @@ pub struct GAMESTATS_OPEN_TYPE(pub i32);
 impl windows_core::TypeKind for GAMESTATS_OPEN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GAMESTATS_OPEN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GAMESTATS_OPEN_TYPE").field(&self.0).finish()
@@ -682,6 +684,7 @@ pub struct GAME_INSTALL_SCOPE(pub i32);
 impl windows_core::TypeKind for GAME_INSTALL_SCOPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GAME_INSTALL_SCOPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GAME_INSTALL_SCOPE").field(&self.0).finish()
@@ -693,6 +696,7 @@ pub struct GAMING_DEVICE_DEVICE_ID(pub i32);
 impl windows_core::TypeKind for GAMING_DEVICE_DEVICE_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GAMING_DEVICE_DEVICE_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GAMING_DEVICE_DEVICE_ID").field(&self.0).finish()
@@ -704,6 +708,7 @@ pub struct GAMING_DEVICE_VENDOR_ID(pub i32);
 impl windows_core::TypeKind for GAMING_DEVICE_VENDOR_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GAMING_DEVICE_VENDOR_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GAMING_DEVICE_VENDOR_ID").field(&self.0).finish()
@@ -715,6 +720,7 @@ pub struct KnownGamingPrivileges(pub i32);
 impl windows_core::TypeKind for KnownGamingPrivileges {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KnownGamingPrivileges {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KnownGamingPrivileges").field(&self.0).finish()
@@ -726,13 +732,15 @@ pub struct XBL_IDP_AUTH_TOKEN_STATUS(pub i32);
 impl windows_core::TypeKind for XBL_IDP_AUTH_TOKEN_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XBL_IDP_AUTH_TOKEN_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XBL_IDP_AUTH_TOKEN_STATUS").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GAMING_DEVICE_MODEL_INFORMATION {
     pub vendorId: GAMING_DEVICE_VENDOR_ID,
     pub deviceId: GAMING_DEVICE_DEVICE_ID,
@@ -753,3 +761,5 @@ pub type GameUICompletionRoutine = Option<unsafe extern "system" fn(returncode:
 pub type PlayerPickerUICompletionRoutine = Option<unsafe extern "system" fn(returncode: windows_core::HRESULT, context: *const core::ffi::c_void, selectedxuids: *const windows_core::HSTRING, selectedxuidscount: usize)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
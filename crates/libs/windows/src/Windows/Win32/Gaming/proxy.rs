@@ -0,0 +1,274 @@
+impl windows_core::imp::ProxyVtable for IGameExplorer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddGame(this: *mut core::ffi::c_void, bstrgdfbinarypath: core::mem::MaybeUninit<windows_core::BSTR>, bstrgameinstalldirectory: core::mem::MaybeUninit<windows_core::BSTR>, installscope: GAME_INSTALL_SCOPE, pguidinstanceid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameExplorer", "AddGame", 3usize, |this| ((**(this as *const *const IGameExplorer_Vtbl)).AddGame)(this, bstrgdfbinarypath, bstrgameinstalldirectory, installscope, pguidinstanceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGame(this: *mut core::ffi::c_void, guidinstanceid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameExplorer", "RemoveGame", 4usize, |this| ((**(this as *const *const IGameExplorer_Vtbl)).RemoveGame)(this, guidinstanceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateGame(this: *mut core::ffi::c_void, guidinstanceid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameExplorer", "UpdateGame", 5usize, |this| ((**(this as *const *const IGameExplorer_Vtbl)).UpdateGame)(this, guidinstanceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerifyAccess(this: *mut core::ffi::c_void, bstrgdfbinarypath: core::mem::MaybeUninit<windows_core::BSTR>, pfhasaccess: *mut super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameExplorer", "VerifyAccess", 6usize, |this| ((**(this as *const *const IGameExplorer_Vtbl)).VerifyAccess)(this, bstrgdfbinarypath, pfhasaccess), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddGame, RemoveGame, UpdateGame, VerifyAccess }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameExplorer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameExplorer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstallGame(this: *mut core::ffi::c_void, binarygdfpath: windows_core::PCWSTR, installdirectory: windows_core::PCWSTR, installscope: GAME_INSTALL_SCOPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameExplorer2", "InstallGame", 3usize, |this| ((**(this as *const *const IGameExplorer2_Vtbl)).InstallGame)(this, binarygdfpath, installdirectory, installscope), |result| Some(*result))
+        }
+        unsafe extern "system" fn UninstallGame(this: *mut core::ffi::c_void, binarygdfpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameExplorer2", "UninstallGame", 4usize, |this| ((**(this as *const *const IGameExplorer2_Vtbl)).UninstallGame)(this, binarygdfpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckAccess(this: *mut core::ffi::c_void, binarygdfpath: windows_core::PCWSTR, phasaccess: *mut super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameExplorer2", "CheckAccess", 5usize, |this| ((**(this as *const *const IGameExplorer2_Vtbl)).CheckAccess)(this, binarygdfpath, phasaccess), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InstallGame, UninstallGame, CheckAccess }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameExplorer2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameStatistics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMaxCategoryLength(this: *mut core::ffi::c_void, cch: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameStatistics", "GetMaxCategoryLength", 3usize, |this| ((**(this as *const *const IGameStatistics_Vtbl)).GetMaxCategoryLength)(this, cch), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxNameLength(this: *mut core::ffi::c_void, cch: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameStatistics", "GetMaxNameLength", 4usize, |this| ((**(this as *const *const IGameStatistics_Vtbl)).GetMaxNameLength)(this, cch), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxValueLength(this: *mut core::ffi::c_void, cch: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameStatistics", "GetMaxValueLength", 5usize, |this| ((**(this as *const *const IGameStatistics_Vtbl)).GetMaxValueLength)(this, cch), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxCategories(this: *mut core::ffi::c_void, pmax: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameStatistics", "GetMaxCategories", 6usize, |this| ((**(this as *const *const IGameStatistics_Vtbl)).GetMaxCategories)(this, pmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxStatsPerCategory(this: *mut core::ffi::c_void, pmax: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameStatistics", "GetMaxStatsPerCategory", 7usize, |this| ((**(this as *const *const IGameStatistics_Vtbl)).GetMaxStatsPerCategory)(this, pmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCategoryTitle(this: *mut core::ffi::c_void, categoryindex: u16, title: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameStatistics", "SetCategoryTitle", 8usize, |this| ((**(this as *const *const IGameStatistics_Vtbl)).SetCategoryTitle)(this, categoryindex, title), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCategoryTitle(this: *mut core::ffi::c_void, categoryindex: u16, ptitle: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameStatistics", "GetCategoryTitle", 9usize, |this| ((**(this as *const *const IGameStatistics_Vtbl)).GetCategoryTitle)(this, categoryindex, ptitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatistic(this: *mut core::ffi::c_void, categoryindex: u16, statindex: u16, pname: *mut windows_core::PWSTR, pvalue: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameStatistics", "GetStatistic", 10usize, |this| ((**(this as *const *const IGameStatistics_Vtbl)).GetStatistic)(this, categoryindex, statindex, pname, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatistic(this: *mut core::ffi::c_void, categoryindex: u16, statindex: u16, name: windows_core::PCWSTR, value: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameStatistics", "SetStatistic", 11usize, |this| ((**(this as *const *const IGameStatistics_Vtbl)).SetStatistic)(this, categoryindex, statindex, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, trackchanges: super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameStatistics", "Save", 12usize, |this| ((**(this as *const *const IGameStatistics_Vtbl)).Save)(this, trackchanges), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastPlayedCategory(this: *mut core::ffi::c_void, categoryindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameStatistics", "SetLastPlayedCategory", 13usize, |this| ((**(this as *const *const IGameStatistics_Vtbl)).SetLastPlayedCategory)(this, categoryindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastPlayedCategory(this: *mut core::ffi::c_void, pcategoryindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameStatistics", "GetLastPlayedCategory", 14usize, |this| ((**(this as *const *const IGameStatistics_Vtbl)).GetLastPlayedCategory)(this, pcategoryindex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMaxCategoryLength,
+            GetMaxNameLength,
+            GetMaxValueLength,
+            GetMaxCategories,
+            GetMaxStatsPerCategory,
+            SetCategoryTitle,
+            GetCategoryTitle,
+            GetStatistic,
+            SetStatistic,
+            Save,
+            SetLastPlayedCategory,
+            GetLastPlayedCategory,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameStatistics as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGameStatisticsMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGameStatistics(this: *mut core::ffi::c_void, gdfbinarypath: windows_core::PCWSTR, opentype: GAMESTATS_OPEN_TYPE, popenresult: *mut GAMESTATS_OPEN_RESULT, ppistats: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameStatisticsMgr", "GetGameStatistics", 3usize, |this| ((**(this as *const *const IGameStatisticsMgr_Vtbl)).GetGameStatistics)(this, gdfbinarypath, opentype, popenresult, ppistats), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGameStatistics(this: *mut core::ffi::c_void, gdfbinarypath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGameStatisticsMgr", "RemoveGameStatistics", 4usize, |this| ((**(this as *const *const IGameStatisticsMgr_Vtbl)).RemoveGameStatistics)(this, gdfbinarypath), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetGameStatistics, RemoveGameStatistics }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGameStatisticsMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXblIdpAuthManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetGamerAccount(this: *mut core::ffi::c_void, msaaccountid: windows_core::PCWSTR, xuid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthManager", "SetGamerAccount", 3usize, |this| ((**(this as *const *const IXblIdpAuthManager_Vtbl)).SetGamerAccount)(this, msaaccountid, xuid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGamerAccount(this: *mut core::ffi::c_void, msaaccountid: *mut windows_core::PWSTR, xuid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthManager", "GetGamerAccount", 4usize, |this| ((**(this as *const *const IXblIdpAuthManager_Vtbl)).GetGamerAccount)(this, msaaccountid, xuid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppViewInitialized(this: *mut core::ffi::c_void, appsid: windows_core::PCWSTR, msaaccountid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthManager", "SetAppViewInitialized", 5usize, |this| ((**(this as *const *const IXblIdpAuthManager_Vtbl)).SetAppViewInitialized)(this, appsid, msaaccountid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnvironment(this: *mut core::ffi::c_void, environment: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthManager", "GetEnvironment", 6usize, |this| ((**(this as *const *const IXblIdpAuthManager_Vtbl)).GetEnvironment)(this, environment), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSandbox(this: *mut core::ffi::c_void, sandbox: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthManager", "GetSandbox", 7usize, |this| ((**(this as *const *const IXblIdpAuthManager_Vtbl)).GetSandbox)(this, sandbox), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTokenAndSignatureWithTokenResult(this: *mut core::ffi::c_void, msaaccountid: windows_core::PCWSTR, appsid: windows_core::PCWSTR, msatarget: windows_core::PCWSTR, msapolicy: windows_core::PCWSTR, httpmethod: windows_core::PCWSTR, uri: windows_core::PCWSTR, headers: windows_core::PCWSTR, body: *const u8, bodysize: u32, forcerefresh: super::Foundation::BOOL, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthManager", "GetTokenAndSignatureWithTokenResult", 8usize, |this| ((**(this as *const *const IXblIdpAuthManager_Vtbl)).GetTokenAndSignatureWithTokenResult)(this, msaaccountid, appsid, msatarget, msapolicy, httpmethod, uri, headers, body, bodysize, forcerefresh, result), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetGamerAccount,
+            GetGamerAccount,
+            SetAppViewInitialized,
+            GetEnvironment,
+            GetSandbox,
+            GetTokenAndSignatureWithTokenResult,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXblIdpAuthManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXblIdpAuthManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUserlessTokenAndSignatureWithTokenResult(this: *mut core::ffi::c_void, appsid: windows_core::PCWSTR, msatarget: windows_core::PCWSTR, msapolicy: windows_core::PCWSTR, httpmethod: windows_core::PCWSTR, uri: windows_core::PCWSTR, headers: windows_core::PCWSTR, body: *const u8, bodysize: u32, forcerefresh: super::Foundation::BOOL, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthManager2", "GetUserlessTokenAndSignatureWithTokenResult", 3usize, |this| ((**(this as *const *const IXblIdpAuthManager2_Vtbl)).GetUserlessTokenAndSignatureWithTokenResult)(this, appsid, msatarget, msapolicy, httpmethod, uri, headers, body, bodysize, forcerefresh, result), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUserlessTokenAndSignatureWithTokenResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXblIdpAuthManager2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXblIdpAuthTokenResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, status: *mut XBL_IDP_AUTH_TOKEN_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetStatus", 3usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetStatus)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorCode(this: *mut core::ffi::c_void, errorcode: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetErrorCode", 4usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetErrorCode)(this, errorcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetToken(this: *mut core::ffi::c_void, token: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetToken", 5usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetToken)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignature(this: *mut core::ffi::c_void, signature: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetSignature", 6usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetSignature)(this, signature), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSandbox(this: *mut core::ffi::c_void, sandbox: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetSandbox", 7usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetSandbox)(this, sandbox), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnvironment(this: *mut core::ffi::c_void, environment: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetEnvironment", 8usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetEnvironment)(this, environment), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMsaAccountId(this: *mut core::ffi::c_void, msaaccountid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetMsaAccountId", 9usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetMsaAccountId)(this, msaaccountid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXuid(this: *mut core::ffi::c_void, xuid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetXuid", 10usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetXuid)(this, xuid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGamertag(this: *mut core::ffi::c_void, gamertag: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetGamertag", 11usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetGamertag)(this, gamertag), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAgeGroup(this: *mut core::ffi::c_void, agegroup: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetAgeGroup", 12usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetAgeGroup)(this, agegroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivileges(this: *mut core::ffi::c_void, privileges: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetPrivileges", 13usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetPrivileges)(this, privileges), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMsaTarget(this: *mut core::ffi::c_void, msatarget: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetMsaTarget", 14usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetMsaTarget)(this, msatarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMsaPolicy(this: *mut core::ffi::c_void, msapolicy: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetMsaPolicy", 15usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetMsaPolicy)(this, msapolicy), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMsaAppId(this: *mut core::ffi::c_void, msaappid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetMsaAppId", 16usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetMsaAppId)(this, msaappid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRedirect(this: *mut core::ffi::c_void, redirect: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetRedirect", 17usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetRedirect)(this, redirect), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessage(this: *mut core::ffi::c_void, message: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetMessage", 18usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetMessage)(this, message), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHelpId(this: *mut core::ffi::c_void, helpid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetHelpId", 19usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetHelpId)(this, helpid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnforcementBans(this: *mut core::ffi::c_void, enforcementbans: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetEnforcementBans", 20usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetEnforcementBans)(this, enforcementbans), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRestrictions(this: *mut core::ffi::c_void, restrictions: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetRestrictions", 21usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetRestrictions)(this, restrictions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTitleRestrictions(this: *mut core::ffi::c_void, titlerestrictions: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult", "GetTitleRestrictions", 22usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult_Vtbl)).GetTitleRestrictions)(this, titlerestrictions), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStatus,
+            GetErrorCode,
+            GetToken,
+            GetSignature,
+            GetSandbox,
+            GetEnvironment,
+            GetMsaAccountId,
+            GetXuid,
+            GetGamertag,
+            GetAgeGroup,
+            GetPrivileges,
+            GetMsaTarget,
+            GetMsaPolicy,
+            GetMsaAppId,
+            GetRedirect,
+            GetMessage,
+            GetHelpId,
+            GetEnforcementBans,
+            GetRestrictions,
+            GetTitleRestrictions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXblIdpAuthTokenResult as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXblIdpAuthTokenResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetModernGamertag(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult2", "GetModernGamertag", 3usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult2_Vtbl)).GetModernGamertag)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModernGamertagSuffix(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult2", "GetModernGamertagSuffix", 4usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult2_Vtbl)).GetModernGamertagSuffix)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUniqueModernGamertag(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXblIdpAuthTokenResult2", "GetUniqueModernGamertag", 5usize, |this| ((**(this as *const *const IXblIdpAuthTokenResult2_Vtbl)).GetUniqueModernGamertag)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetModernGamertag,
+            GetModernGamertagSuffix,
+            GetUniqueModernGamertag,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXblIdpAuthTokenResult2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
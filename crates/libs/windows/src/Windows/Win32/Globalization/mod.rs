@@ -11415,6 +11415,7 @@ pub struct CALDATETIME_DATEUNIT(pub i32);
 impl windows_core::TypeKind for CALDATETIME_DATEUNIT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALDATETIME_DATEUNIT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALDATETIME_DATEUNIT").field(&self.0).finish()
@@ -11426,6 +11427,7 @@ pub struct COMPARESTRING_RESULT(pub i32);
 impl windows_core::TypeKind for COMPARESTRING_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMPARESTRING_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMPARESTRING_RESULT").field(&self.0).finish()
@@ -11437,6 +11439,7 @@ pub struct COMPARE_STRING_FLAGS(pub u32);
 impl windows_core::TypeKind for COMPARE_STRING_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMPARE_STRING_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMPARE_STRING_FLAGS").field(&self.0).finish()
@@ -11481,6 +11484,7 @@ pub struct CORRECTIVE_ACTION(pub i32);
 impl windows_core::TypeKind for CORRECTIVE_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CORRECTIVE_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CORRECTIVE_ACTION").field(&self.0).finish()
@@ -11492,6 +11496,7 @@ pub struct ENUM_DATE_FORMATS_FLAGS(pub u32);
 impl windows_core::TypeKind for ENUM_DATE_FORMATS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ENUM_DATE_FORMATS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ENUM_DATE_FORMATS_FLAGS").field(&self.0).finish()
@@ -11503,6 +11508,7 @@ pub struct ENUM_SYSTEM_CODE_PAGES_FLAGS(pub u32);
 impl windows_core::TypeKind for ENUM_SYSTEM_CODE_PAGES_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ENUM_SYSTEM_CODE_PAGES_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ENUM_SYSTEM_CODE_PAGES_FLAGS").field(&self.0).finish()
@@ -11514,6 +11520,7 @@ pub struct ENUM_SYSTEM_LANGUAGE_GROUPS_FLAGS(pub u32);
 impl windows_core::TypeKind for ENUM_SYSTEM_LANGUAGE_GROUPS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ENUM_SYSTEM_LANGUAGE_GROUPS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ENUM_SYSTEM_LANGUAGE_GROUPS_FLAGS").field(&self.0).finish()
@@ -11525,6 +11532,7 @@ pub struct FOLD_STRING_MAP_FLAGS(pub u32);
 impl windows_core::TypeKind for FOLD_STRING_MAP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FOLD_STRING_MAP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FOLD_STRING_MAP_FLAGS").field(&self.0).finish()
@@ -11569,6 +11577,7 @@ pub struct IS_TEXT_UNICODE_RESULT(pub u32);
 impl windows_core::TypeKind for IS_TEXT_UNICODE_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IS_TEXT_UNICODE_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IS_TEXT_UNICODE_RESULT").field(&self.0).finish()
@@ -11613,6 +11622,7 @@ pub struct IS_VALID_LOCALE_FLAGS(pub u32);
 impl windows_core::TypeKind for IS_VALID_LOCALE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IS_VALID_LOCALE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IS_VALID_LOCALE_FLAGS").field(&self.0).finish()
@@ -11624,6 +11634,7 @@ pub struct MIMECONTF(pub i32);
 impl windows_core::TypeKind for MIMECONTF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MIMECONTF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MIMECONTF").field(&self.0).finish()
@@ -11635,6 +11646,7 @@ pub struct MLCONVCHAR(pub i32);
 impl windows_core::TypeKind for MLCONVCHAR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MLCONVCHAR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MLCONVCHAR").field(&self.0).finish()
@@ -11646,6 +11658,7 @@ pub struct MLCP(pub i32);
 impl windows_core::TypeKind for MLCP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MLCP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MLCP").field(&self.0).finish()
@@ -11657,6 +11670,7 @@ pub struct MLDETECTCP(pub i32);
 impl windows_core::TypeKind for MLDETECTCP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MLDETECTCP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MLDETECTCP").field(&self.0).finish()
@@ -11668,6 +11682,7 @@ pub struct MLSTR_FLAGS(pub i32);
 impl windows_core::TypeKind for MLSTR_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MLSTR_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MLSTR_FLAGS").field(&self.0).finish()
@@ -11679,6 +11694,7 @@ pub struct MULTI_BYTE_TO_WIDE_CHAR_FLAGS(pub u32);
 impl windows_core::TypeKind for MULTI_BYTE_TO_WIDE_CHAR_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MULTI_BYTE_TO_WIDE_CHAR_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MULTI_BYTE_TO_WIDE_CHAR_FLAGS").field(&self.0).finish()
@@ -11723,6 +11739,7 @@ pub struct NORM_FORM(pub i32);
 impl windows_core::TypeKind for NORM_FORM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NORM_FORM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NORM_FORM").field(&self.0).finish()
@@ -11734,6 +11751,7 @@ pub struct SCRIPTCONTF(pub i32);
 impl windows_core::TypeKind for SCRIPTCONTF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCRIPTCONTF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCRIPTCONTF").field(&self.0).finish()
@@ -11745,6 +11763,7 @@ pub struct SCRIPTFONTCONTF(pub i32);
 impl windows_core::TypeKind for SCRIPTFONTCONTF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCRIPTFONTCONTF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCRIPTFONTCONTF").field(&self.0).finish()
@@ -11756,6 +11775,7 @@ pub struct SCRIPT_IS_COMPLEX_FLAGS(pub u32);
 impl windows_core::TypeKind for SCRIPT_IS_COMPLEX_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCRIPT_IS_COMPLEX_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCRIPT_IS_COMPLEX_FLAGS").field(&self.0).finish()
@@ -11767,6 +11787,7 @@ pub struct SCRIPT_JUSTIFY(pub i32);
 impl windows_core::TypeKind for SCRIPT_JUSTIFY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCRIPT_JUSTIFY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCRIPT_JUSTIFY").field(&self.0).finish()
@@ -11778,6 +11799,7 @@ pub struct SYSGEOCLASS(pub i32);
 impl windows_core::TypeKind for SYSGEOCLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSGEOCLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSGEOCLASS").field(&self.0).finish()
@@ -11789,6 +11811,7 @@ pub struct SYSGEOTYPE(pub i32);
 impl windows_core::TypeKind for SYSGEOTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSGEOTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSGEOTYPE").field(&self.0).finish()
@@ -11800,6 +11823,7 @@ pub struct SYSNLS_FUNCTION(pub i32);
 impl windows_core::TypeKind for SYSNLS_FUNCTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSNLS_FUNCTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSNLS_FUNCTION").field(&self.0).finish()
@@ -11811,6 +11835,7 @@ pub struct TIME_FORMAT_FLAGS(pub u32);
 impl windows_core::TypeKind for TIME_FORMAT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TIME_FORMAT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TIME_FORMAT_FLAGS").field(&self.0).finish()
@@ -11855,6 +11880,7 @@ pub struct TRANSLATE_CHARSET_INFO_FLAGS(pub u32);
 impl windows_core::TypeKind for TRANSLATE_CHARSET_INFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRANSLATE_CHARSET_INFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRANSLATE_CHARSET_INFO_FLAGS").field(&self.0).finish()
@@ -11866,6 +11892,7 @@ pub struct UAcceptResult(pub i32);
 impl windows_core::TypeKind for UAcceptResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UAcceptResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UAcceptResult").field(&self.0).finish()
@@ -11877,6 +11904,7 @@ pub struct UAlphabeticIndexLabelType(pub i32);
 impl windows_core::TypeKind for UAlphabeticIndexLabelType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UAlphabeticIndexLabelType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UAlphabeticIndexLabelType").field(&self.0).finish()
@@ -11888,6 +11916,7 @@ pub struct UBiDiDirection(pub i32);
 impl windows_core::TypeKind for UBiDiDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UBiDiDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UBiDiDirection").field(&self.0).finish()
@@ -11899,6 +11928,7 @@ pub struct UBiDiMirroring(pub i32);
 impl windows_core::TypeKind for UBiDiMirroring {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UBiDiMirroring {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UBiDiMirroring").field(&self.0).finish()
@@ -11910,6 +11940,7 @@ pub struct UBiDiOrder(pub i32);
 impl windows_core::TypeKind for UBiDiOrder {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UBiDiOrder {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UBiDiOrder").field(&self.0).finish()
@@ -11921,6 +11952,7 @@ pub struct UBiDiReorderingMode(pub i32);
 impl windows_core::TypeKind for UBiDiReorderingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UBiDiReorderingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UBiDiReorderingMode").field(&self.0).finish()
@@ -11932,6 +11964,7 @@ pub struct UBiDiReorderingOption(pub i32);
 impl windows_core::TypeKind for UBiDiReorderingOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UBiDiReorderingOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UBiDiReorderingOption").field(&self.0).finish()
@@ -11943,6 +11976,7 @@ pub struct UBidiPairedBracketType(pub i32);
 impl windows_core::TypeKind for UBidiPairedBracketType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UBidiPairedBracketType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UBidiPairedBracketType").field(&self.0).finish()
@@ -11954,6 +11988,7 @@ pub struct UBlockCode(pub i32);
 impl windows_core::TypeKind for UBlockCode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UBlockCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UBlockCode").field(&self.0).finish()
@@ -11965,6 +12000,7 @@ pub struct UBreakIteratorType(pub i32);
 impl windows_core::TypeKind for UBreakIteratorType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UBreakIteratorType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UBreakIteratorType").field(&self.0).finish()
@@ -11976,6 +12012,7 @@ pub struct UCPMapRangeOption(pub i32);
 impl windows_core::TypeKind for UCPMapRangeOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCPMapRangeOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCPMapRangeOption").field(&self.0).finish()
@@ -11987,6 +12024,7 @@ pub struct UCPTrieType(pub i32);
 impl windows_core::TypeKind for UCPTrieType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCPTrieType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCPTrieType").field(&self.0).finish()
@@ -11998,6 +12036,7 @@ pub struct UCPTrieValueWidth(pub i32);
 impl windows_core::TypeKind for UCPTrieValueWidth {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCPTrieValueWidth {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCPTrieValueWidth").field(&self.0).finish()
@@ -12009,6 +12048,7 @@ pub struct UCalendarAMPMs(pub i32);
 impl windows_core::TypeKind for UCalendarAMPMs {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCalendarAMPMs {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCalendarAMPMs").field(&self.0).finish()
@@ -12020,6 +12060,7 @@ pub struct UCalendarAttribute(pub i32);
 impl windows_core::TypeKind for UCalendarAttribute {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCalendarAttribute {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCalendarAttribute").field(&self.0).finish()
@@ -12031,6 +12072,7 @@ pub struct UCalendarDateFields(pub i32);
 impl windows_core::TypeKind for UCalendarDateFields {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCalendarDateFields {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCalendarDateFields").field(&self.0).finish()
@@ -12042,6 +12084,7 @@ pub struct UCalendarDaysOfWeek(pub i32);
 impl windows_core::TypeKind for UCalendarDaysOfWeek {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCalendarDaysOfWeek {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCalendarDaysOfWeek").field(&self.0).finish()
@@ -12053,6 +12096,7 @@ pub struct UCalendarDisplayNameType(pub i32);
 impl windows_core::TypeKind for UCalendarDisplayNameType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCalendarDisplayNameType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCalendarDisplayNameType").field(&self.0).finish()
@@ -12064,6 +12108,7 @@ pub struct UCalendarLimitType(pub i32);
 impl windows_core::TypeKind for UCalendarLimitType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCalendarLimitType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCalendarLimitType").field(&self.0).finish()
@@ -12075,6 +12120,7 @@ pub struct UCalendarMonths(pub i32);
 impl windows_core::TypeKind for UCalendarMonths {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCalendarMonths {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCalendarMonths").field(&self.0).finish()
@@ -12086,6 +12132,7 @@ pub struct UCalendarType(pub i32);
 impl windows_core::TypeKind for UCalendarType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCalendarType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCalendarType").field(&self.0).finish()
@@ -12097,6 +12144,7 @@ pub struct UCalendarWallTimeOption(pub i32);
 impl windows_core::TypeKind for UCalendarWallTimeOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCalendarWallTimeOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCalendarWallTimeOption").field(&self.0).finish()
@@ -12108,6 +12156,7 @@ pub struct UCalendarWeekdayType(pub i32);
 impl windows_core::TypeKind for UCalendarWeekdayType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCalendarWeekdayType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCalendarWeekdayType").field(&self.0).finish()
@@ -12119,6 +12168,7 @@ pub struct UCharCategory(pub i32);
 impl windows_core::TypeKind for UCharCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCharCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCharCategory").field(&self.0).finish()
@@ -12130,6 +12180,7 @@ pub struct UCharDirection(pub i32);
 impl windows_core::TypeKind for UCharDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCharDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCharDirection").field(&self.0).finish()
@@ -12141,6 +12192,7 @@ pub struct UCharIteratorOrigin(pub i32);
 impl windows_core::TypeKind for UCharIteratorOrigin {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCharIteratorOrigin {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCharIteratorOrigin").field(&self.0).finish()
@@ -12152,6 +12204,7 @@ pub struct UCharNameChoice(pub i32);
 impl windows_core::TypeKind for UCharNameChoice {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCharNameChoice {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCharNameChoice").field(&self.0).finish()
@@ -12163,6 +12216,7 @@ pub struct UColAttribute(pub i32);
 impl windows_core::TypeKind for UColAttribute {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UColAttribute {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UColAttribute").field(&self.0).finish()
@@ -12174,6 +12228,7 @@ pub struct UColAttributeValue(pub i32);
 impl windows_core::TypeKind for UColAttributeValue {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UColAttributeValue {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UColAttributeValue").field(&self.0).finish()
@@ -12185,6 +12240,7 @@ pub struct UColBoundMode(pub i32);
 impl windows_core::TypeKind for UColBoundMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UColBoundMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UColBoundMode").field(&self.0).finish()
@@ -12196,6 +12252,7 @@ pub struct UColReorderCode(pub i32);
 impl windows_core::TypeKind for UColReorderCode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UColReorderCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UColReorderCode").field(&self.0).finish()
@@ -12207,6 +12264,7 @@ pub struct UColRuleOption(pub i32);
 impl windows_core::TypeKind for UColRuleOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UColRuleOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UColRuleOption").field(&self.0).finish()
@@ -12218,6 +12276,7 @@ pub struct UCollationResult(pub i32);
 impl windows_core::TypeKind for UCollationResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCollationResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCollationResult").field(&self.0).finish()
@@ -12229,6 +12288,7 @@ pub struct UConverterCallbackReason(pub i32);
 impl windows_core::TypeKind for UConverterCallbackReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UConverterCallbackReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UConverterCallbackReason").field(&self.0).finish()
@@ -12240,6 +12300,7 @@ pub struct UConverterPlatform(pub i32);
 impl windows_core::TypeKind for UConverterPlatform {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UConverterPlatform {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UConverterPlatform").field(&self.0).finish()
@@ -12251,6 +12312,7 @@ pub struct UConverterType(pub i32);
 impl windows_core::TypeKind for UConverterType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UConverterType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UConverterType").field(&self.0).finish()
@@ -12262,6 +12324,7 @@ pub struct UConverterUnicodeSet(pub i32);
 impl windows_core::TypeKind for UConverterUnicodeSet {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UConverterUnicodeSet {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UConverterUnicodeSet").field(&self.0).finish()
@@ -12273,6 +12336,7 @@ pub struct UCurrCurrencyType(pub i32);
 impl windows_core::TypeKind for UCurrCurrencyType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCurrCurrencyType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCurrCurrencyType").field(&self.0).finish()
@@ -12284,6 +12348,7 @@ pub struct UCurrNameStyle(pub i32);
 impl windows_core::TypeKind for UCurrNameStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCurrNameStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCurrNameStyle").field(&self.0).finish()
@@ -12295,6 +12360,7 @@ pub struct UCurrencySpacing(pub i32);
 impl windows_core::TypeKind for UCurrencySpacing {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCurrencySpacing {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCurrencySpacing").field(&self.0).finish()
@@ -12306,6 +12372,7 @@ pub struct UCurrencyUsage(pub i32);
 impl windows_core::TypeKind for UCurrencyUsage {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UCurrencyUsage {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UCurrencyUsage").field(&self.0).finish()
@@ -12317,6 +12384,7 @@ pub struct UDateAbsoluteUnit(pub i32);
 impl windows_core::TypeKind for UDateAbsoluteUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDateAbsoluteUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDateAbsoluteUnit").field(&self.0).finish()
@@ -12328,6 +12396,7 @@ pub struct UDateDirection(pub i32);
 impl windows_core::TypeKind for UDateDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDateDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDateDirection").field(&self.0).finish()
@@ -12339,6 +12408,7 @@ pub struct UDateFormatBooleanAttribute(pub i32);
 impl windows_core::TypeKind for UDateFormatBooleanAttribute {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDateFormatBooleanAttribute {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDateFormatBooleanAttribute").field(&self.0).finish()
@@ -12350,6 +12420,7 @@ pub struct UDateFormatField(pub i32);
 impl windows_core::TypeKind for UDateFormatField {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDateFormatField {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDateFormatField").field(&self.0).finish()
@@ -12361,6 +12432,7 @@ pub struct UDateFormatStyle(pub i32);
 impl windows_core::TypeKind for UDateFormatStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDateFormatStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDateFormatStyle").field(&self.0).finish()
@@ -12372,6 +12444,7 @@ pub struct UDateFormatSymbolType(pub i32);
 impl windows_core::TypeKind for UDateFormatSymbolType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDateFormatSymbolType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDateFormatSymbolType").field(&self.0).finish()
@@ -12383,6 +12456,7 @@ pub struct UDateRelativeDateTimeFormatterStyle(pub i32);
 impl windows_core::TypeKind for UDateRelativeDateTimeFormatterStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDateRelativeDateTimeFormatterStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDateRelativeDateTimeFormatterStyle").field(&self.0).finish()
@@ -12394,6 +12468,7 @@ pub struct UDateRelativeUnit(pub i32);
 impl windows_core::TypeKind for UDateRelativeUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDateRelativeUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDateRelativeUnit").field(&self.0).finish()
@@ -12405,6 +12480,7 @@ pub struct UDateTimePGDisplayWidth(pub i32);
 impl windows_core::TypeKind for UDateTimePGDisplayWidth {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDateTimePGDisplayWidth {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDateTimePGDisplayWidth").field(&self.0).finish()
@@ -12416,6 +12492,7 @@ pub struct UDateTimePatternConflict(pub i32);
 impl windows_core::TypeKind for UDateTimePatternConflict {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDateTimePatternConflict {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDateTimePatternConflict").field(&self.0).finish()
@@ -12427,6 +12504,7 @@ pub struct UDateTimePatternField(pub i32);
 impl windows_core::TypeKind for UDateTimePatternField {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDateTimePatternField {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDateTimePatternField").field(&self.0).finish()
@@ -12438,6 +12516,7 @@ pub struct UDateTimePatternMatchOptions(pub i32);
 impl windows_core::TypeKind for UDateTimePatternMatchOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDateTimePatternMatchOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDateTimePatternMatchOptions").field(&self.0).finish()
@@ -12449,6 +12528,7 @@ pub struct UDateTimeScale(pub i32);
 impl windows_core::TypeKind for UDateTimeScale {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDateTimeScale {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDateTimeScale").field(&self.0).finish()
@@ -12460,6 +12540,7 @@ pub struct UDecompositionType(pub i32);
 impl windows_core::TypeKind for UDecompositionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDecompositionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDecompositionType").field(&self.0).finish()
@@ -12471,6 +12552,7 @@ pub struct UDialectHandling(pub i32);
 impl windows_core::TypeKind for UDialectHandling {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDialectHandling {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDialectHandling").field(&self.0).finish()
@@ -12482,6 +12564,7 @@ pub struct UDisplayContext(pub i32);
 impl windows_core::TypeKind for UDisplayContext {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDisplayContext {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDisplayContext").field(&self.0).finish()
@@ -12493,6 +12576,7 @@ pub struct UDisplayContextType(pub i32);
 impl windows_core::TypeKind for UDisplayContextType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDisplayContextType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDisplayContextType").field(&self.0).finish()
@@ -12504,6 +12588,7 @@ pub struct UEastAsianWidth(pub i32);
 impl windows_core::TypeKind for UEastAsianWidth {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UEastAsianWidth {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UEastAsianWidth").field(&self.0).finish()
@@ -12515,6 +12600,7 @@ pub struct UErrorCode(pub i32);
 impl windows_core::TypeKind for UErrorCode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UErrorCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UErrorCode").field(&self.0).finish()
@@ -12526,6 +12612,7 @@ pub struct UFieldCategory(pub i32);
 impl windows_core::TypeKind for UFieldCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UFieldCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UFieldCategory").field(&self.0).finish()
@@ -12537,6 +12624,7 @@ pub struct UFormattableType(pub i32);
 impl windows_core::TypeKind for UFormattableType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UFormattableType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UFormattableType").field(&self.0).finish()
@@ -12548,6 +12636,7 @@ pub struct UGender(pub i32);
 impl windows_core::TypeKind for UGender {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UGender {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UGender").field(&self.0).finish()
@@ -12559,6 +12648,7 @@ pub struct UGraphemeClusterBreak(pub i32);
 impl windows_core::TypeKind for UGraphemeClusterBreak {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UGraphemeClusterBreak {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UGraphemeClusterBreak").field(&self.0).finish()
@@ -12570,6 +12660,7 @@ pub struct UHangulSyllableType(pub i32);
 impl windows_core::TypeKind for UHangulSyllableType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UHangulSyllableType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UHangulSyllableType").field(&self.0).finish()
@@ -12581,6 +12672,7 @@ pub struct UIndicPositionalCategory(pub i32);
 impl windows_core::TypeKind for UIndicPositionalCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIndicPositionalCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIndicPositionalCategory").field(&self.0).finish()
@@ -12592,6 +12684,7 @@ pub struct UIndicSyllabicCategory(pub i32);
 impl windows_core::TypeKind for UIndicSyllabicCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIndicSyllabicCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIndicSyllabicCategory").field(&self.0).finish()
@@ -12603,6 +12696,7 @@ pub struct UJoiningGroup(pub i32);
 impl windows_core::TypeKind for UJoiningGroup {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UJoiningGroup {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UJoiningGroup").field(&self.0).finish()
@@ -12614,6 +12708,7 @@ pub struct UJoiningType(pub i32);
 impl windows_core::TypeKind for UJoiningType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UJoiningType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UJoiningType").field(&self.0).finish()
@@ -12625,6 +12720,7 @@ pub struct ULayoutType(pub i32);
 impl windows_core::TypeKind for ULayoutType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ULayoutType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ULayoutType").field(&self.0).finish()
@@ -12636,6 +12732,7 @@ pub struct ULineBreak(pub i32);
 impl windows_core::TypeKind for ULineBreak {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ULineBreak {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ULineBreak").field(&self.0).finish()
@@ -12647,6 +12744,7 @@ pub struct ULineBreakTag(pub i32);
 impl windows_core::TypeKind for ULineBreakTag {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ULineBreakTag {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ULineBreakTag").field(&self.0).finish()
@@ -12658,6 +12756,7 @@ pub struct UListFormatterField(pub i32);
 impl windows_core::TypeKind for UListFormatterField {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UListFormatterField {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UListFormatterField").field(&self.0).finish()
@@ -12669,6 +12768,7 @@ pub struct UListFormatterType(pub i32);
 impl windows_core::TypeKind for UListFormatterType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UListFormatterType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UListFormatterType").field(&self.0).finish()
@@ -12680,6 +12780,7 @@ pub struct UListFormatterWidth(pub i32);
 impl windows_core::TypeKind for UListFormatterWidth {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UListFormatterWidth {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UListFormatterWidth").field(&self.0).finish()
@@ -12691,6 +12792,7 @@ pub struct ULocAvailableType(pub i32);
 impl windows_core::TypeKind for ULocAvailableType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ULocAvailableType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ULocAvailableType").field(&self.0).finish()
@@ -12702,6 +12804,7 @@ pub struct ULocDataLocaleType(pub i32);
 impl windows_core::TypeKind for ULocDataLocaleType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ULocDataLocaleType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ULocDataLocaleType").field(&self.0).finish()
@@ -12713,6 +12816,7 @@ pub struct ULocaleDataDelimiterType(pub i32);
 impl windows_core::TypeKind for ULocaleDataDelimiterType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ULocaleDataDelimiterType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ULocaleDataDelimiterType").field(&self.0).finish()
@@ -12724,6 +12828,7 @@ pub struct ULocaleDataExemplarSetType(pub i32);
 impl windows_core::TypeKind for ULocaleDataExemplarSetType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ULocaleDataExemplarSetType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ULocaleDataExemplarSetType").field(&self.0).finish()
@@ -12735,6 +12840,7 @@ pub struct UMeasureFormatWidth(pub i32);
 impl windows_core::TypeKind for UMeasureFormatWidth {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UMeasureFormatWidth {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UMeasureFormatWidth").field(&self.0).finish()
@@ -12746,6 +12852,7 @@ pub struct UMeasurementSystem(pub i32);
 impl windows_core::TypeKind for UMeasurementSystem {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UMeasurementSystem {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UMeasurementSystem").field(&self.0).finish()
@@ -12757,6 +12864,7 @@ pub struct UMessagePatternApostropheMode(pub i32);
 impl windows_core::TypeKind for UMessagePatternApostropheMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UMessagePatternApostropheMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UMessagePatternApostropheMode").field(&self.0).finish()
@@ -12768,6 +12876,7 @@ pub struct UMessagePatternArgType(pub i32);
 impl windows_core::TypeKind for UMessagePatternArgType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UMessagePatternArgType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UMessagePatternArgType").field(&self.0).finish()
@@ -12779,6 +12888,7 @@ pub struct UMessagePatternPartType(pub i32);
 impl windows_core::TypeKind for UMessagePatternPartType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UMessagePatternPartType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UMessagePatternPartType").field(&self.0).finish()
@@ -12790,6 +12900,7 @@ pub struct UNormalization2Mode(pub i32);
 impl windows_core::TypeKind for UNormalization2Mode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNormalization2Mode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNormalization2Mode").field(&self.0).finish()
@@ -12801,6 +12912,7 @@ pub struct UNormalizationCheckResult(pub i32);
 impl windows_core::TypeKind for UNormalizationCheckResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNormalizationCheckResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNormalizationCheckResult").field(&self.0).finish()
@@ -12812,6 +12924,7 @@ pub struct UNormalizationMode(pub i32);
 impl windows_core::TypeKind for UNormalizationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNormalizationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNormalizationMode").field(&self.0).finish()
@@ -12823,6 +12936,7 @@ pub struct UNumberCompactStyle(pub i32);
 impl windows_core::TypeKind for UNumberCompactStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberCompactStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberCompactStyle").field(&self.0).finish()
@@ -12834,6 +12948,7 @@ pub struct UNumberDecimalSeparatorDisplay(pub i32);
 impl windows_core::TypeKind for UNumberDecimalSeparatorDisplay {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberDecimalSeparatorDisplay {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberDecimalSeparatorDisplay").field(&self.0).finish()
@@ -12845,6 +12960,7 @@ pub struct UNumberFormatAttribute(pub i32);
 impl windows_core::TypeKind for UNumberFormatAttribute {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberFormatAttribute {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberFormatAttribute").field(&self.0).finish()
@@ -12856,6 +12972,7 @@ pub struct UNumberFormatAttributeValue(pub i32);
 impl windows_core::TypeKind for UNumberFormatAttributeValue {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberFormatAttributeValue {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberFormatAttributeValue").field(&self.0).finish()
@@ -12867,6 +12984,7 @@ pub struct UNumberFormatFields(pub i32);
 impl windows_core::TypeKind for UNumberFormatFields {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberFormatFields {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberFormatFields").field(&self.0).finish()
@@ -12878,6 +12996,7 @@ pub struct UNumberFormatPadPosition(pub i32);
 impl windows_core::TypeKind for UNumberFormatPadPosition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberFormatPadPosition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberFormatPadPosition").field(&self.0).finish()
@@ -12889,6 +13008,7 @@ pub struct UNumberFormatRoundingMode(pub i32);
 impl windows_core::TypeKind for UNumberFormatRoundingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberFormatRoundingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberFormatRoundingMode").field(&self.0).finish()
@@ -12900,6 +13020,7 @@ pub struct UNumberFormatStyle(pub i32);
 impl windows_core::TypeKind for UNumberFormatStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberFormatStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberFormatStyle").field(&self.0).finish()
@@ -12911,6 +13032,7 @@ pub struct UNumberFormatSymbol(pub i32);
 impl windows_core::TypeKind for UNumberFormatSymbol {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberFormatSymbol {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberFormatSymbol").field(&self.0).finish()
@@ -12922,6 +13044,7 @@ pub struct UNumberFormatTextAttribute(pub i32);
 impl windows_core::TypeKind for UNumberFormatTextAttribute {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberFormatTextAttribute {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberFormatTextAttribute").field(&self.0).finish()
@@ -12933,6 +13056,7 @@ pub struct UNumberGroupingStrategy(pub i32);
 impl windows_core::TypeKind for UNumberGroupingStrategy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberGroupingStrategy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberGroupingStrategy").field(&self.0).finish()
@@ -12944,6 +13068,7 @@ pub struct UNumberRangeCollapse(pub i32);
 impl windows_core::TypeKind for UNumberRangeCollapse {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberRangeCollapse {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberRangeCollapse").field(&self.0).finish()
@@ -12955,6 +13080,7 @@ pub struct UNumberRangeIdentityFallback(pub i32);
 impl windows_core::TypeKind for UNumberRangeIdentityFallback {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberRangeIdentityFallback {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberRangeIdentityFallback").field(&self.0).finish()
@@ -12966,6 +13092,7 @@ pub struct UNumberRangeIdentityResult(pub i32);
 impl windows_core::TypeKind for UNumberRangeIdentityResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberRangeIdentityResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberRangeIdentityResult").field(&self.0).finish()
@@ -12977,6 +13104,7 @@ pub struct UNumberSignDisplay(pub i32);
 impl windows_core::TypeKind for UNumberSignDisplay {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberSignDisplay {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberSignDisplay").field(&self.0).finish()
@@ -12988,6 +13116,7 @@ pub struct UNumberUnitWidth(pub i32);
 impl windows_core::TypeKind for UNumberUnitWidth {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumberUnitWidth {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumberUnitWidth").field(&self.0).finish()
@@ -12999,6 +13128,7 @@ pub struct UNumericType(pub i32);
 impl windows_core::TypeKind for UNumericType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNumericType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNumericType").field(&self.0).finish()
@@ -13010,6 +13140,7 @@ pub struct UPluralType(pub i32);
 impl windows_core::TypeKind for UPluralType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UPluralType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UPluralType").field(&self.0).finish()
@@ -13021,6 +13152,7 @@ pub struct UProperty(pub i32);
 impl windows_core::TypeKind for UProperty {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UProperty {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UProperty").field(&self.0).finish()
@@ -13032,6 +13164,7 @@ pub struct UPropertyNameChoice(pub i32);
 impl windows_core::TypeKind for UPropertyNameChoice {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UPropertyNameChoice {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UPropertyNameChoice").field(&self.0).finish()
@@ -13043,6 +13176,7 @@ pub struct URegexpFlag(pub i32);
 impl windows_core::TypeKind for URegexpFlag {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for URegexpFlag {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("URegexpFlag").field(&self.0).finish()
@@ -13054,6 +13188,7 @@ pub struct URegionType(pub i32);
 impl windows_core::TypeKind for URegionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for URegionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("URegionType").field(&self.0).finish()
@@ -13065,6 +13200,7 @@ pub struct URelativeDateTimeFormatterField(pub i32);
 impl windows_core::TypeKind for URelativeDateTimeFormatterField {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for URelativeDateTimeFormatterField {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("URelativeDateTimeFormatterField").field(&self.0).finish()
@@ -13076,6 +13212,7 @@ pub struct URelativeDateTimeUnit(pub i32);
 impl windows_core::TypeKind for URelativeDateTimeUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for URelativeDateTimeUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("URelativeDateTimeUnit").field(&self.0).finish()
@@ -13087,6 +13224,7 @@ pub struct UResType(pub i32);
 impl windows_core::TypeKind for UResType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UResType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UResType").field(&self.0).finish()
@@ -13098,6 +13236,7 @@ pub struct URestrictionLevel(pub i32);
 impl windows_core::TypeKind for URestrictionLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for URestrictionLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("URestrictionLevel").field(&self.0).finish()
@@ -13109,6 +13248,7 @@ pub struct UScriptCode(pub i32);
 impl windows_core::TypeKind for UScriptCode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UScriptCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UScriptCode").field(&self.0).finish()
@@ -13120,6 +13260,7 @@ pub struct UScriptUsage(pub i32);
 impl windows_core::TypeKind for UScriptUsage {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UScriptUsage {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UScriptUsage").field(&self.0).finish()
@@ -13131,6 +13272,7 @@ pub struct USearchAttribute(pub i32);
 impl windows_core::TypeKind for USearchAttribute {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USearchAttribute {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USearchAttribute").field(&self.0).finish()
@@ -13142,6 +13284,7 @@ pub struct USearchAttributeValue(pub i32);
 impl windows_core::TypeKind for USearchAttributeValue {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USearchAttributeValue {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USearchAttributeValue").field(&self.0).finish()
@@ -13153,6 +13296,7 @@ pub struct USentenceBreak(pub i32);
 impl windows_core::TypeKind for USentenceBreak {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USentenceBreak {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USentenceBreak").field(&self.0).finish()
@@ -13164,6 +13308,7 @@ pub struct USentenceBreakTag(pub i32);
 impl windows_core::TypeKind for USentenceBreakTag {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USentenceBreakTag {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USentenceBreakTag").field(&self.0).finish()
@@ -13175,6 +13320,7 @@ pub struct USetSpanCondition(pub i32);
 impl windows_core::TypeKind for USetSpanCondition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USetSpanCondition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USetSpanCondition").field(&self.0).finish()
@@ -13186,6 +13332,7 @@ pub struct USpoofChecks(pub i32);
 impl windows_core::TypeKind for USpoofChecks {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USpoofChecks {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USpoofChecks").field(&self.0).finish()
@@ -13197,6 +13344,7 @@ pub struct UStringPrepProfileType(pub i32);
 impl windows_core::TypeKind for UStringPrepProfileType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UStringPrepProfileType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UStringPrepProfileType").field(&self.0).finish()
@@ -13208,6 +13356,7 @@ pub struct UStringTrieBuildOption(pub i32);
 impl windows_core::TypeKind for UStringTrieBuildOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UStringTrieBuildOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UStringTrieBuildOption").field(&self.0).finish()
@@ -13219,6 +13368,7 @@ pub struct UStringTrieResult(pub i32);
 impl windows_core::TypeKind for UStringTrieResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UStringTrieResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UStringTrieResult").field(&self.0).finish()
@@ -13230,6 +13380,7 @@ pub struct USystemTimeZoneType(pub i32);
 impl windows_core::TypeKind for USystemTimeZoneType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USystemTimeZoneType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USystemTimeZoneType").field(&self.0).finish()
@@ -13241,6 +13392,7 @@ pub struct UTimeScaleValue(pub i32);
 impl windows_core::TypeKind for UTimeScaleValue {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UTimeScaleValue {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UTimeScaleValue").field(&self.0).finish()
@@ -13252,6 +13404,7 @@ pub struct UTimeZoneFormatGMTOffsetPatternType(pub i32);
 impl windows_core::TypeKind for UTimeZoneFormatGMTOffsetPatternType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UTimeZoneFormatGMTOffsetPatternType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UTimeZoneFormatGMTOffsetPatternType").field(&self.0).finish()
@@ -13263,6 +13416,7 @@ pub struct UTimeZoneFormatParseOption(pub i32);
 impl windows_core::TypeKind for UTimeZoneFormatParseOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UTimeZoneFormatParseOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UTimeZoneFormatParseOption").field(&self.0).finish()
@@ -13274,6 +13428,7 @@ pub struct UTimeZoneFormatStyle(pub i32);
 impl windows_core::TypeKind for UTimeZoneFormatStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UTimeZoneFormatStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UTimeZoneFormatStyle").field(&self.0).finish()
@@ -13285,6 +13440,7 @@ pub struct UTimeZoneFormatTimeType(pub i32);
 impl windows_core::TypeKind for UTimeZoneFormatTimeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UTimeZoneFormatTimeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UTimeZoneFormatTimeType").field(&self.0).finish()
@@ -13296,6 +13452,7 @@ pub struct UTimeZoneNameType(pub i32);
 impl windows_core::TypeKind for UTimeZoneNameType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UTimeZoneNameType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UTimeZoneNameType").field(&self.0).finish()
@@ -13307,6 +13464,7 @@ pub struct UTimeZoneTransitionType(pub i32);
 impl windows_core::TypeKind for UTimeZoneTransitionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UTimeZoneTransitionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UTimeZoneTransitionType").field(&self.0).finish()
@@ -13318,6 +13476,7 @@ pub struct UTraceFunctionNumber(pub i32);
 impl windows_core::TypeKind for UTraceFunctionNumber {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UTraceFunctionNumber {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UTraceFunctionNumber").field(&self.0).finish()
@@ -13329,6 +13488,7 @@ pub struct UTraceLevel(pub i32);
 impl windows_core::TypeKind for UTraceLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UTraceLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UTraceLevel").field(&self.0).finish()
@@ -13340,6 +13500,7 @@ pub struct UTransDirection(pub i32);
 impl windows_core::TypeKind for UTransDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UTransDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UTransDirection").field(&self.0).finish()
@@ -13351,6 +13512,7 @@ pub struct UVerticalOrientation(pub i32);
 impl windows_core::TypeKind for UVerticalOrientation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UVerticalOrientation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UVerticalOrientation").field(&self.0).finish()
@@ -13362,6 +13524,7 @@ pub struct UWordBreak(pub i32);
 impl windows_core::TypeKind for UWordBreak {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UWordBreak {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UWordBreak").field(&self.0).finish()
@@ -13373,6 +13536,7 @@ pub struct UWordBreakValues(pub i32);
 impl windows_core::TypeKind for UWordBreakValues {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UWordBreakValues {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UWordBreakValues").field(&self.0).finish()
@@ -13384,13 +13548,15 @@ pub struct WORDLIST_TYPE(pub i32);
 impl windows_core::TypeKind for WORDLIST_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WORDLIST_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WORDLIST_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CALDATETIME {
     pub CalId: u32,
     pub Era: u32,
@@ -13412,7 +13578,8 @@ impl Default for CALDATETIME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CHARSETINFO {
     pub ciCharset: u32,
     pub ciACP: u32,
@@ -13430,7 +13597,8 @@ pub const CMLangConvertCharset: windows_core::GUID = windows_core::GUID::from_u1
 pub const CMLangString: windows_core::GUID = windows_core::GUID::from_u128(0xc04d65cf_b70d_11d0_b188_00aa0038c969);
 pub const CMultiLanguage: windows_core::GUID = windows_core::GUID::from_u128(0x275c23e2_3747_11d0_9fea_00aa003f8646);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CPINFO {
     pub MaxCharSize: u32,
     pub DefaultChar: [u8; 2],
@@ -13445,7 +13613,8 @@ impl Default for CPINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CPINFOEXA {
     pub MaxCharSize: u32,
     pub DefaultChar: [u8; 2],
@@ -13463,7 +13632,8 @@ impl Default for CPINFOEXA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CPINFOEXW {
     pub MaxCharSize: u32,
     pub DefaultChar: [u8; 2],
@@ -13481,7 +13651,8 @@ impl Default for CPINFOEXW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CURRENCYFMTA {
     pub NumDigits: u32,
     pub LeadingZero: u32,
@@ -13501,7 +13672,8 @@ impl Default for CURRENCYFMTA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CURRENCYFMTW {
     pub NumDigits: u32,
     pub LeadingZero: u32,
@@ -13521,7 +13693,8 @@ impl Default for CURRENCYFMTW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DetectEncodingInfo {
     pub nLangID: u32,
     pub nCodePage: u32,
@@ -13538,7 +13711,8 @@ impl Default for DetectEncodingInfo {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ENUMTEXTMETRICA {
     pub etmNewTextMetricEx: NEWTEXTMETRICEXA,
     pub etmAxesList: super::Graphics::Gdi::AXESLISTA,
@@ -13555,7 +13729,8 @@ impl Default for ENUMTEXTMETRICA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ENUMTEXTMETRICW {
     pub etmNewTextMetricEx: NEWTEXTMETRICEXW,
     pub etmAxesList: super::Graphics::Gdi::AXESLISTW,
@@ -13571,7 +13746,8 @@ impl Default for ENUMTEXTMETRICW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILEMUIINFO {
     pub dwSize: u32,
     pub dwVersion: u32,
@@ -13596,7 +13772,8 @@ impl Default for FILEMUIINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FONTSIGNATURE {
     pub fsUsb: [u32; 4],
     pub fsCsb: [u32; 2],
@@ -13610,7 +13787,8 @@ impl Default for FONTSIGNATURE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GOFFSET {
     pub du: i32,
     pub dv: i32,
@@ -13624,7 +13802,8 @@ impl Default for GOFFSET {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HSAVEDUILANGUAGES(pub *mut core::ffi::c_void);
 impl HSAVEDUILANGUAGES {
     pub fn is_invalid(&self) -> bool {
@@ -13640,7 +13819,8 @@ impl windows_core::TypeKind for HSAVEDUILANGUAGES {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LOCALESIGNATURE {
     pub lsUsb: [u32; 4],
     pub lsCsbDefault: [u32; 2],
@@ -13655,7 +13835,8 @@ impl Default for LOCALESIGNATURE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MAPPING_DATA_RANGE {
     pub dwStartIndex: u32,
     pub dwEndIndex: u32,
@@ -13677,7 +13858,8 @@ impl Default for MAPPING_DATA_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MAPPING_ENUM_OPTIONS {
     pub Size: usize,
     pub pszCategory: windows_core::PWSTR,
@@ -13690,6 +13872,20 @@ pub struct MAPPING_ENUM_OPTIONS {
     pub pGuid: *mut windows_core::GUID,
     pub _bitfield: u32,
 }
+impl MAPPING_ENUM_OPTIONS {
+    pub const fn OnlineService(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_OnlineService(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn ServiceType(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_ServiceType(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+}
 impl windows_core::TypeKind for MAPPING_ENUM_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
@@ -13699,7 +13895,8 @@ impl Default for MAPPING_ENUM_OPTIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MAPPING_OPTIONS {
     pub Size: usize,
     pub pszInputLanguage: windows_core::PWSTR,
@@ -13718,6 +13915,14 @@ pub struct MAPPING_OPTIONS {
     pub dwServiceFlag: u32,
     pub _bitfield: u32,
 }
+impl MAPPING_OPTIONS {
+    pub const fn GetActionDisplayName(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_GetActionDisplayName(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+}
 impl windows_core::TypeKind for MAPPING_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
@@ -13727,7 +13932,8 @@ impl Default for MAPPING_OPTIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MAPPING_PROPERTY_BAG {
     pub Size: usize,
     pub prgResultRanges: *mut MAPPING_DATA_RANGE,
@@ -13747,7 +13953,8 @@ impl Default for MAPPING_PROPERTY_BAG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MAPPING_SERVICE_INFO {
     pub Size: usize,
     pub pszCopyright: windows_core::PWSTR,
@@ -13775,6 +13982,32 @@ pub struct MAPPING_SERVICE_INFO {
     pub pContext: *mut core::ffi::c_void,
     pub _bitfield: u32,
 }
+impl MAPPING_SERVICE_INFO {
+    pub const fn IsOneToOneLanguageMapping(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_IsOneToOneLanguageMapping(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn HasSubservices(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_HasSubservices(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn OnlineOnly(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_OnlineOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ServiceType(&self) -> u32 {
+        (self._bitfield >> 3) & 3
+    }
+    pub fn set_ServiceType(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 3)) | ((value & 3) << 3);
+    }
+}
 impl windows_core::TypeKind for MAPPING_SERVICE_INFO {
     type TypeKind = windows_core::CopyType;
 }
@@ -13784,7 +14017,8 @@ impl Default for MAPPING_SERVICE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIMECPINFO {
     pub dwFlags: u32,
     pub uiCodePage: u32,
@@ -13806,7 +14040,8 @@ impl Default for MIMECPINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIMECSETINFO {
     pub uiCodePage: u32,
     pub uiInternetEncoding: u32,
@@ -13822,7 +14057,8 @@ impl Default for MIMECSETINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NEWTEXTMETRICEXA {
     pub ntmTm: super::Graphics::Gdi::NEWTEXTMETRICA,
     pub ntmFontSig: FONTSIGNATURE,
@@ -13839,7 +14075,8 @@ impl Default for NEWTEXTMETRICEXA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NEWTEXTMETRICEXW {
     pub ntmTm: super::Graphics::Gdi::NEWTEXTMETRICW,
     pub ntmFontSig: FONTSIGNATURE,
@@ -13855,7 +14092,8 @@ impl Default for NEWTEXTMETRICEXW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NLSVERSIONINFO {
     pub dwNLSVersionInfoSize: u32,
     pub dwNLSVersion: u32,
@@ -13872,7 +14110,8 @@ impl Default for NLSVERSIONINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NLSVERSIONINFOEX {
     pub dwNLSVersionInfoSize: u32,
     pub dwNLSVersion: u32,
@@ -13889,7 +14128,8 @@ impl Default for NLSVERSIONINFOEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NUMBERFMTA {
     pub NumDigits: u32,
     pub LeadingZero: u32,
@@ -13907,7 +14147,8 @@ impl Default for NUMBERFMTA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NUMBERFMTW {
     pub NumDigits: u32,
     pub LeadingZero: u32,
@@ -13925,7 +14166,8 @@ impl Default for NUMBERFMTW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OPENTYPE_FEATURE_RECORD {
     pub tagFeature: u32,
     pub lParameter: i32,
@@ -13939,7 +14181,8 @@ impl Default for OPENTYPE_FEATURE_RECORD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RFC1766INFO {
     pub lcid: u32,
     pub wszRfc1766: [u16; 6],
@@ -13954,7 +14197,8 @@ impl Default for RFC1766INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRIPTFONTINFO {
     pub scripts: i64,
     pub wszFont: [u16; 32],
@@ -13968,7 +14212,8 @@ impl Default for SCRIPTFONTINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRIPTINFO {
     pub ScriptId: u8,
     pub uiCodePage: u32,
@@ -13985,11 +14230,56 @@ impl Default for SCRIPTINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRIPT_ANALYSIS {
     pub _bitfield: u16,
     pub s: SCRIPT_STATE,
 }
+impl SCRIPT_ANALYSIS {
+    pub const fn eScript(&self) -> u16 {
+        self._bitfield & 1023
+    }
+    pub fn set_eScript(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1023) | (value & 1023);
+    }
+    pub const fn fRTL(&self) -> u16 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_fRTL(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn fLayoutRTL(&self) -> u16 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_fLayoutRTL(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn fLinkBefore(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_fLinkBefore(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn fLinkAfter(&self) -> u16 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_fLinkAfter(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn fLogicalOrder(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_fLogicalOrder(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn fNoGlyphIndex(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_fNoGlyphIndex(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for SCRIPT_ANALYSIS {
     type TypeKind = windows_core::CopyType;
 }
@@ -13999,10 +14289,25 @@ impl Default for SCRIPT_ANALYSIS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRIPT_CHARPROP {
     pub _bitfield: u16,
 }
+impl SCRIPT_CHARPROP {
+    pub const fn fCanGlyphAlone(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_fCanGlyphAlone(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn reserved(&self) -> u16 {
+        (self._bitfield >> 1) & 32767
+    }
+    pub fn set_reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(32767 << 1)) | ((value & 32767) << 1);
+    }
+}
 impl windows_core::TypeKind for SCRIPT_CHARPROP {
     type TypeKind = windows_core::CopyType;
 }
@@ -14012,10 +14317,85 @@ impl Default for SCRIPT_CHARPROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRIPT_CONTROL {
     pub _bitfield: u32,
 }
+impl SCRIPT_CONTROL {
+    pub const fn uDefaultLanguage(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_uDefaultLanguage(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn fContextDigits(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_fContextDigits(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn fInvertPreBoundDir(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_fInvertPreBoundDir(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn fInvertPostBoundDir(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_fInvertPostBoundDir(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn fLinkStringBefore(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_fLinkStringBefore(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn fLinkStringAfter(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_fLinkStringAfter(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn fNeutralOverride(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_fNeutralOverride(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn fNumericOverride(&self) -> u32 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_fNumericOverride(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn fLegacyBidiClass(&self) -> u32 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_fLegacyBidiClass(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn fMergeNeutralItems(&self) -> u32 {
+        (self._bitfield >> 24) & 1
+    }
+    pub fn set_fMergeNeutralItems(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 24)) | ((value & 1) << 24);
+    }
+    pub const fn fUseStandardBidi(&self) -> u32 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_fUseStandardBidi(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn fReserved(&self) -> u32 {
+        (self._bitfield >> 26) & 63
+    }
+    pub fn set_fReserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 26)) | ((value & 63) << 26);
+    }
+}
 impl windows_core::TypeKind for SCRIPT_CONTROL {
     type TypeKind = windows_core::CopyType;
 }
@@ -14025,12 +14405,33 @@ impl Default for SCRIPT_CONTROL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRIPT_DIGITSUBSTITUTE {
     pub _bitfield1: u32,
     pub _bitfield2: u32,
     pub dwReserved: u32,
 }
+impl SCRIPT_DIGITSUBSTITUTE {
+    pub const fn NationalDigitLanguage(&self) -> u32 {
+        self._bitfield1 & 65535
+    }
+    pub fn set_NationalDigitLanguage(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !65535) | (value & 65535);
+    }
+    pub const fn TraditionalDigitLanguage(&self) -> u32 {
+        (self._bitfield1 >> 16) & 65535
+    }
+    pub fn set_TraditionalDigitLanguage(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+    pub const fn DigitSubstitute(&self) -> u32 {
+        self._bitfield2 & 255
+    }
+    pub fn set_DigitSubstitute(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !255) | (value & 255);
+    }
+}
 impl windows_core::TypeKind for SCRIPT_DIGITSUBSTITUTE {
     type TypeKind = windows_core::CopyType;
 }
@@ -14040,7 +14441,8 @@ impl Default for SCRIPT_DIGITSUBSTITUTE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRIPT_FONTPROPERTIES {
     pub cBytes: i32,
     pub wgBlank: u16,
@@ -14058,7 +14460,8 @@ impl Default for SCRIPT_FONTPROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRIPT_GLYPHPROP {
     pub sva: SCRIPT_VISATTR,
     pub reserved: u16,
@@ -14072,7 +14475,8 @@ impl Default for SCRIPT_GLYPHPROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRIPT_ITEM {
     pub iCharPos: i32,
     pub a: SCRIPT_ANALYSIS,
@@ -14086,10 +14490,49 @@ impl Default for SCRIPT_ITEM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRIPT_LOGATTR {
     pub _bitfield: u8,
 }
+impl SCRIPT_LOGATTR {
+    pub const fn fSoftBreak(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_fSoftBreak(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn fWhiteSpace(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_fWhiteSpace(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn fCharStop(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_fCharStop(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn fWordStop(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_fWordStop(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn fInvalid(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_fInvalid(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn fReserved(&self) -> u8 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_fReserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+}
 impl windows_core::TypeKind for SCRIPT_LOGATTR {
     type TypeKind = windows_core::CopyType;
 }
@@ -14099,11 +14542,104 @@ impl Default for SCRIPT_LOGATTR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRIPT_PROPERTIES {
     pub _bitfield1: u32,
     pub _bitfield2: u32,
 }
+impl SCRIPT_PROPERTIES {
+    pub const fn langid(&self) -> u32 {
+        self._bitfield1 & 65535
+    }
+    pub fn set_langid(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !65535) | (value & 65535);
+    }
+    pub const fn fNumeric(&self) -> u32 {
+        (self._bitfield1 >> 16) & 1
+    }
+    pub fn set_fNumeric(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn fComplex(&self) -> u32 {
+        (self._bitfield1 >> 17) & 1
+    }
+    pub fn set_fComplex(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn fNeedsWordBreaking(&self) -> u32 {
+        (self._bitfield1 >> 18) & 1
+    }
+    pub fn set_fNeedsWordBreaking(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn fNeedsCaretInfo(&self) -> u32 {
+        (self._bitfield1 >> 19) & 1
+    }
+    pub fn set_fNeedsCaretInfo(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn bCharSet(&self) -> u32 {
+        (self._bitfield1 >> 20) & 255
+    }
+    pub fn set_bCharSet(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(255 << 20)) | ((value & 255) << 20);
+    }
+    pub const fn fControl(&self) -> u32 {
+        (self._bitfield1 >> 28) & 1
+    }
+    pub fn set_fControl(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 28)) | ((value & 1) << 28);
+    }
+    pub const fn fPrivateUseArea(&self) -> u32 {
+        (self._bitfield1 >> 29) & 1
+    }
+    pub fn set_fPrivateUseArea(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 29)) | ((value & 1) << 29);
+    }
+    pub const fn fNeedsCharacterJustify(&self) -> u32 {
+        (self._bitfield1 >> 30) & 1
+    }
+    pub fn set_fNeedsCharacterJustify(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 30)) | ((value & 1) << 30);
+    }
+    pub const fn fInvalidGlyph(&self) -> u32 {
+        (self._bitfield1 >> 31) & 1
+    }
+    pub fn set_fInvalidGlyph(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 31)) | ((value & 1) << 31);
+    }
+    pub const fn fInvalidLogAttr(&self) -> u32 {
+        self._bitfield2 & 1
+    }
+    pub fn set_fInvalidLogAttr(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !1) | (value & 1);
+    }
+    pub const fn fCDM(&self) -> u32 {
+        (self._bitfield2 >> 1) & 1
+    }
+    pub fn set_fCDM(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn fAmbiguousCharSet(&self) -> u32 {
+        (self._bitfield2 >> 2) & 1
+    }
+    pub fn set_fAmbiguousCharSet(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn fClusterSizeVaries(&self) -> u32 {
+        (self._bitfield2 >> 3) & 1
+    }
+    pub fn set_fClusterSizeVaries(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn fRejectInvalid(&self) -> u32 {
+        (self._bitfield2 >> 4) & 1
+    }
+    pub fn set_fRejectInvalid(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 4)) | ((value & 1) << 4);
+    }
+}
 impl windows_core::TypeKind for SCRIPT_PROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
@@ -14113,10 +14649,79 @@ impl Default for SCRIPT_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRIPT_STATE {
     pub _bitfield: u16,
 }
+impl SCRIPT_STATE {
+    pub const fn uBidiLevel(&self) -> u16 {
+        self._bitfield & 31
+    }
+    pub fn set_uBidiLevel(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn fOverrideDirection(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_fOverrideDirection(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn fInhibitSymSwap(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_fInhibitSymSwap(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn fCharShape(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_fCharShape(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn fDigitSubstitute(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_fDigitSubstitute(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn fInhibitLigate(&self) -> u16 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_fInhibitLigate(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn fDisplayZWG(&self) -> u16 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_fDisplayZWG(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn fArabicNumContext(&self) -> u16 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_fArabicNumContext(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn fGcpClusters(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_fGcpClusters(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn fReserved(&self) -> u16 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_fReserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn fEngineReserved(&self) -> u16 {
+        (self._bitfield >> 14) & 3
+    }
+    pub fn set_fEngineReserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 14)) | ((value & 3) << 14);
+    }
+}
 impl windows_core::TypeKind for SCRIPT_STATE {
     type TypeKind = windows_core::CopyType;
 }
@@ -14126,7 +14731,8 @@ impl Default for SCRIPT_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRIPT_TABDEF {
     pub cTabStops: i32,
     pub iScale: i32,
@@ -14142,10 +14748,49 @@ impl Default for SCRIPT_TABDEF {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRIPT_VISATTR {
     pub _bitfield: u16,
 }
+impl SCRIPT_VISATTR {
+    pub const fn uJustification(&self) -> u16 {
+        self._bitfield & 15
+    }
+    pub fn set_uJustification(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn fClusterStart(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_fClusterStart(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn fDiacritic(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_fDiacritic(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn fZeroWidth(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_fZeroWidth(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn fReserved(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_fReserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn fShapeReserved(&self) -> u16 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_fShapeReserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+}
 impl windows_core::TypeKind for SCRIPT_VISATTR {
     type TypeKind = windows_core::CopyType;
 }
@@ -14156,7 +14801,8 @@ impl Default for SCRIPT_VISATTR {
 }
 pub const SpellCheckerFactory: windows_core::GUID = windows_core::GUID::from_u128(0x7ab36653_1796_484b_bdfa_e74f1db7c1dc);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TEXTRANGE_PROPERTIES {
     pub potfRecords: *mut OPENTYPE_FEATURE_RECORD,
     pub cotfRecords: i32,
@@ -14170,7 +14816,8 @@ impl Default for TEXTRANGE_PROPERTIES {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UBiDi(pub isize);
 impl Default for UBiDi {
     fn default() -> Self {
@@ -14181,7 +14828,8 @@ impl windows_core::TypeKind for UBiDi {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UBiDiTransform(pub isize);
 impl Default for UBiDiTransform {
     fn default() -> Self {
@@ -14192,7 +14840,8 @@ impl windows_core::TypeKind for UBiDiTransform {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UBreakIterator(pub isize);
 impl Default for UBreakIterator {
     fn default() -> Self {
@@ -14203,7 +14852,8 @@ impl windows_core::TypeKind for UBreakIterator {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UCPMap(pub isize);
 impl Default for UCPMap {
     fn default() -> Self {
@@ -14255,7 +14905,8 @@ impl Default for UCPTrieData {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UCaseMap(pub isize);
 impl Default for UCaseMap {
     fn default() -> Self {
@@ -14266,7 +14917,8 @@ impl windows_core::TypeKind for UCaseMap {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UCharIterator {
     pub context: *const core::ffi::c_void,
     pub length: i32,
@@ -14294,7 +14946,8 @@ impl Default for UCharIterator {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UCharsetDetector(pub isize);
 impl Default for UCharsetDetector {
     fn default() -> Self {
@@ -14305,7 +14958,8 @@ impl windows_core::TypeKind for UCharsetDetector {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UCharsetMatch(pub isize);
 impl Default for UCharsetMatch {
     fn default() -> Self {
@@ -14316,7 +14970,8 @@ impl windows_core::TypeKind for UCharsetMatch {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UCollationElements(pub isize);
 impl Default for UCollationElements {
     fn default() -> Self {
@@ -14327,7 +14982,8 @@ impl windows_core::TypeKind for UCollationElements {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UCollator(pub isize);
 impl Default for UCollator {
     fn default() -> Self {
@@ -14338,7 +14994,8 @@ impl windows_core::TypeKind for UCollator {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UConstrainedFieldPosition(pub isize);
 impl Default for UConstrainedFieldPosition {
     fn default() -> Self {
@@ -14349,7 +15006,8 @@ impl windows_core::TypeKind for UConstrainedFieldPosition {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UConverter(pub isize);
 impl Default for UConverter {
     fn default() -> Self {
@@ -14360,7 +15018,8 @@ impl windows_core::TypeKind for UConverter {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UConverterFromUnicodeArgs {
     pub size: u16,
     pub flush: i8,
@@ -14380,7 +15039,8 @@ impl Default for UConverterFromUnicodeArgs {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UConverterSelector(pub isize);
 impl Default for UConverterSelector {
     fn default() -> Self {
@@ -14391,7 +15051,8 @@ impl windows_core::TypeKind for UConverterSelector {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UConverterToUnicodeArgs {
     pub size: u16,
     pub flush: i8,
@@ -14411,7 +15072,8 @@ impl Default for UConverterToUnicodeArgs {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UDateFormatSymbols(pub isize);
 impl Default for UDateFormatSymbols {
     fn default() -> Self {
@@ -14422,7 +15084,8 @@ impl windows_core::TypeKind for UDateFormatSymbols {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UDateIntervalFormat(pub isize);
 impl Default for UDateIntervalFormat {
     fn default() -> Self {
@@ -14433,7 +15096,8 @@ impl windows_core::TypeKind for UDateIntervalFormat {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UEnumeration(pub isize);
 impl Default for UEnumeration {
     fn default() -> Self {
@@ -14444,7 +15108,8 @@ impl windows_core::TypeKind for UEnumeration {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UFieldPosition {
     pub field: i32,
     pub beginIndex: i32,
@@ -14459,7 +15124,8 @@ impl Default for UFieldPosition {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UFieldPositionIterator(pub isize);
 impl Default for UFieldPositionIterator {
     fn default() -> Self {
@@ -14470,7 +15136,8 @@ impl windows_core::TypeKind for UFieldPositionIterator {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UFormattedDateInterval(pub isize);
 impl Default for UFormattedDateInterval {
     fn default() -> Self {
@@ -14481,7 +15148,8 @@ impl windows_core::TypeKind for UFormattedDateInterval {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UFormattedList(pub isize);
 impl Default for UFormattedList {
     fn default() -> Self {
@@ -14492,7 +15160,8 @@ impl windows_core::TypeKind for UFormattedList {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UFormattedNumber(pub isize);
 impl Default for UFormattedNumber {
     fn default() -> Self {
@@ -14503,7 +15172,8 @@ impl windows_core::TypeKind for UFormattedNumber {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UFormattedNumberRange(pub isize);
 impl Default for UFormattedNumberRange {
     fn default() -> Self {
@@ -14514,7 +15184,8 @@ impl windows_core::TypeKind for UFormattedNumberRange {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UFormattedRelativeDateTime(pub isize);
 impl Default for UFormattedRelativeDateTime {
     fn default() -> Self {
@@ -14525,7 +15196,8 @@ impl windows_core::TypeKind for UFormattedRelativeDateTime {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UFormattedValue(pub isize);
 impl Default for UFormattedValue {
     fn default() -> Self {
@@ -14536,7 +15208,8 @@ impl windows_core::TypeKind for UFormattedValue {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UGenderInfo(pub isize);
 impl Default for UGenderInfo {
     fn default() -> Self {
@@ -14547,7 +15220,8 @@ impl windows_core::TypeKind for UGenderInfo {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UHashtable(pub isize);
 impl Default for UHashtable {
     fn default() -> Self {
@@ -14558,7 +15232,8 @@ impl windows_core::TypeKind for UHashtable {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UIDNA(pub isize);
 impl Default for UIDNA {
     fn default() -> Self {
@@ -14569,7 +15244,8 @@ impl windows_core::TypeKind for UIDNA {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UIDNAInfo {
     pub size: i16,
     pub isTransitionalDifferent: i8,
@@ -14587,7 +15263,8 @@ impl Default for UIDNAInfo {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UListFormatter(pub isize);
 impl Default for UListFormatter {
     fn default() -> Self {
@@ -14598,7 +15275,8 @@ impl windows_core::TypeKind for UListFormatter {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ULocaleData(pub isize);
 impl Default for ULocaleData {
     fn default() -> Self {
@@ -14609,7 +15287,8 @@ impl windows_core::TypeKind for ULocaleData {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ULocaleDisplayNames(pub isize);
 impl Default for ULocaleDisplayNames {
     fn default() -> Self {
@@ -14620,7 +15299,8 @@ impl windows_core::TypeKind for ULocaleDisplayNames {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UMutableCPTrie(pub isize);
 impl Default for UMutableCPTrie {
     fn default() -> Self {
@@ -14631,7 +15311,8 @@ impl windows_core::TypeKind for UMutableCPTrie {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UNICODERANGE {
     pub wcFrom: u16,
     pub wcTo: u16,
@@ -14645,7 +15326,8 @@ impl Default for UNICODERANGE {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UNormalizer2(pub isize);
 impl Default for UNormalizer2 {
     fn default() -> Self {
@@ -14656,7 +15338,8 @@ impl windows_core::TypeKind for UNormalizer2 {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UNumberFormatter(pub isize);
 impl Default for UNumberFormatter {
     fn default() -> Self {
@@ -14667,7 +15350,8 @@ impl windows_core::TypeKind for UNumberFormatter {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UNumberingSystem(pub isize);
 impl Default for UNumberingSystem {
     fn default() -> Self {
@@ -14678,7 +15362,8 @@ impl windows_core::TypeKind for UNumberingSystem {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UParseError {
     pub line: i32,
     pub offset: i32,
@@ -14694,7 +15379,8 @@ impl Default for UParseError {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UPluralRules(pub isize);
 impl Default for UPluralRules {
     fn default() -> Self {
@@ -14705,7 +15391,8 @@ impl windows_core::TypeKind for UPluralRules {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct URegion(pub isize);
 impl Default for URegion {
     fn default() -> Self {
@@ -14716,7 +15403,8 @@ impl windows_core::TypeKind for URegion {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct URegularExpression(pub isize);
 impl Default for URegularExpression {
     fn default() -> Self {
@@ -14727,7 +15415,8 @@ impl windows_core::TypeKind for URegularExpression {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct URelativeDateTimeFormatter(pub isize);
 impl Default for URelativeDateTimeFormatter {
     fn default() -> Self {
@@ -14738,7 +15427,8 @@ impl windows_core::TypeKind for URelativeDateTimeFormatter {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UReplaceableCallbacks {
     pub length: isize,
     pub charAt: isize,
@@ -14756,7 +15446,8 @@ impl Default for UReplaceableCallbacks {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UResourceBundle(pub isize);
 impl Default for UResourceBundle {
     fn default() -> Self {
@@ -14767,7 +15458,8 @@ impl windows_core::TypeKind for UResourceBundle {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USearch(pub isize);
 impl Default for USearch {
     fn default() -> Self {
@@ -14778,7 +15470,8 @@ impl windows_core::TypeKind for USearch {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USerializedSet {
     pub array: *const u16,
     pub bmpLength: i32,
@@ -14794,7 +15487,8 @@ impl Default for USerializedSet {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USet(pub isize);
 impl Default for USet {
     fn default() -> Self {
@@ -14805,7 +15499,8 @@ impl windows_core::TypeKind for USet {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USpoofCheckResult(pub isize);
 impl Default for USpoofCheckResult {
     fn default() -> Self {
@@ -14816,7 +15511,8 @@ impl windows_core::TypeKind for USpoofCheckResult {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USpoofChecker(pub isize);
 impl Default for USpoofChecker {
     fn default() -> Self {
@@ -14827,7 +15523,8 @@ impl windows_core::TypeKind for USpoofChecker {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UStringPrepProfile(pub isize);
 impl Default for UStringPrepProfile {
     fn default() -> Self {
@@ -14838,7 +15535,8 @@ impl windows_core::TypeKind for UStringPrepProfile {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UStringSearch(pub isize);
 impl Default for UStringSearch {
     fn default() -> Self {
@@ -14849,7 +15547,8 @@ impl windows_core::TypeKind for UStringSearch {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UText {
     pub magic: u32,
     pub flags: i32,
@@ -14885,7 +15584,8 @@ impl Default for UText {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UTextFuncs {
     pub tableSize: i32,
     pub reserved1: i32,
@@ -14913,7 +15613,8 @@ impl Default for UTextFuncs {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UTransPosition {
     pub contextStart: i32,
     pub contextLimit: i32,
@@ -14992,3 +15693,5 @@ pub type UTraceEntry = Option<unsafe extern "system" fn(context: *const core::ff
 pub type UTraceExit = Option<unsafe extern "system" fn(context: *const core::ffi::c_void, fnnumber: i32, fmt: windows_core::PCSTR, args: *mut i8)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
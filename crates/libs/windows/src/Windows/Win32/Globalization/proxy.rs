@@ -0,0 +1,934 @@
+impl windows_core::imp::ProxyVtable for IComprehensiveSpellCheckProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ComprehensiveCheck(this: *mut core::ffi::c_void, text: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComprehensiveSpellCheckProvider", "ComprehensiveCheck", 3usize, |this| ((**(this as *const *const IComprehensiveSpellCheckProvider_Vtbl)).ComprehensiveCheck)(this, text, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ComprehensiveCheck }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComprehensiveSpellCheckProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumCodePage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCodePage", "Clone", 3usize, |this| ((**(this as *const *const IEnumCodePage_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut MIMECPINFO, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCodePage", "Next", 4usize, |this| ((**(this as *const *const IEnumCodePage_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCodePage", "Reset", 5usize, |this| ((**(this as *const *const IEnumCodePage_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCodePage", "Skip", 6usize, |this| ((**(this as *const *const IEnumCodePage_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumCodePage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumRfc1766_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRfc1766", "Clone", 3usize, |this| ((**(this as *const *const IEnumRfc1766_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut RFC1766INFO, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRfc1766", "Next", 4usize, |this| ((**(this as *const *const IEnumRfc1766_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRfc1766", "Reset", 5usize, |this| ((**(this as *const *const IEnumRfc1766_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRfc1766", "Skip", 6usize, |this| ((**(this as *const *const IEnumRfc1766_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumRfc1766 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumScript_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumScript", "Clone", 3usize, |this| ((**(this as *const *const IEnumScript_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut SCRIPTINFO, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumScript", "Next", 4usize, |this| ((**(this as *const *const IEnumScript_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumScript", "Reset", 5usize, |this| ((**(this as *const *const IEnumScript_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumScript", "Skip", 6usize, |this| ((**(this as *const *const IEnumScript_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumScript as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumSpellingError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSpellingError", "Next", 3usize, |this| ((**(this as *const *const IEnumSpellingError_Vtbl)).Next)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSpellingError as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLangCodePages_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCharCodePages(this: *mut core::ffi::c_void, chsrc: u16, pdwcodepages: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangCodePages", "GetCharCodePages", 3usize, |this| ((**(this as *const *const IMLangCodePages_Vtbl)).GetCharCodePages)(this, chsrc, pdwcodepages), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrCodePages(this: *mut core::ffi::c_void, pszsrc: windows_core::PCWSTR, cchsrc: i32, dwprioritycodepages: u32, pdwcodepages: *mut u32, pcchcodepages: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangCodePages", "GetStrCodePages", 4usize, |this| ((**(this as *const *const IMLangCodePages_Vtbl)).GetStrCodePages)(this, pszsrc, cchsrc, dwprioritycodepages, pdwcodepages, pcchcodepages), |result| Some(*result))
+        }
+        unsafe extern "system" fn CodePageToCodePages(this: *mut core::ffi::c_void, ucodepage: u32, pdwcodepages: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangCodePages", "CodePageToCodePages", 5usize, |this| ((**(this as *const *const IMLangCodePages_Vtbl)).CodePageToCodePages)(this, ucodepage, pdwcodepages), |result| Some(*result))
+        }
+        unsafe extern "system" fn CodePagesToCodePage(this: *mut core::ffi::c_void, dwcodepages: u32, udefaultcodepage: u32, pucodepage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangCodePages", "CodePagesToCodePage", 6usize, |this| ((**(this as *const *const IMLangCodePages_Vtbl)).CodePagesToCodePage)(this, dwcodepages, udefaultcodepage, pucodepage), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCharCodePages,
+            GetStrCodePages,
+            CodePageToCodePages,
+            CodePagesToCodePage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLangCodePages as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLangConvertCharset_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, uisrccodepage: u32, uidstcodepage: u32, dwproperty: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangConvertCharset", "Initialize", 3usize, |this| ((**(this as *const *const IMLangConvertCharset_Vtbl)).Initialize)(this, uisrccodepage, uidstcodepage, dwproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceCodePage(this: *mut core::ffi::c_void, puisrccodepage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangConvertCharset", "GetSourceCodePage", 4usize, |this| ((**(this as *const *const IMLangConvertCharset_Vtbl)).GetSourceCodePage)(this, puisrccodepage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDestinationCodePage(this: *mut core::ffi::c_void, puidstcodepage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangConvertCharset", "GetDestinationCodePage", 5usize, |this| ((**(this as *const *const IMLangConvertCharset_Vtbl)).GetDestinationCodePage)(this, puidstcodepage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, pdwproperty: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangConvertCharset", "GetProperty", 6usize, |this| ((**(this as *const *const IMLangConvertCharset_Vtbl)).GetProperty)(this, pdwproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoConversion(this: *mut core::ffi::c_void, psrcstr: *const u8, pcsrcsize: *mut u32, pdststr: *mut u8, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangConvertCharset", "DoConversion", 7usize, |this| ((**(this as *const *const IMLangConvertCharset_Vtbl)).DoConversion)(this, psrcstr, pcsrcsize, pdststr, pcdstsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoConversionToUnicode(this: *mut core::ffi::c_void, psrcstr: windows_core::PCSTR, pcsrcsize: *mut u32, pdststr: windows_core::PWSTR, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangConvertCharset", "DoConversionToUnicode", 8usize, |this| ((**(this as *const *const IMLangConvertCharset_Vtbl)).DoConversionToUnicode)(this, psrcstr, pcsrcsize, pdststr, pcdstsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoConversionFromUnicode(this: *mut core::ffi::c_void, psrcstr: windows_core::PCWSTR, pcsrcsize: *mut u32, pdststr: windows_core::PSTR, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangConvertCharset", "DoConversionFromUnicode", 9usize, |this| ((**(this as *const *const IMLangConvertCharset_Vtbl)).DoConversionFromUnicode)(this, psrcstr, pcsrcsize, pdststr, pcdstsize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetSourceCodePage,
+            GetDestinationCodePage,
+            GetProperty,
+            DoConversion,
+            DoConversionToUnicode,
+            DoConversionFromUnicode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLangConvertCharset as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IMLangFontLink_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetFontCodePages(this: *mut core::ffi::c_void, hdc: super::Graphics::Gdi::HDC, hfont: super::Graphics::Gdi::HFONT, pdwcodepages: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangFontLink", "GetFontCodePages", 7usize, |this| ((**(this as *const *const IMLangFontLink_Vtbl)).GetFontCodePages)(this, hdc, hfont, pdwcodepages), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn MapFont(this: *mut core::ffi::c_void, hdc: super::Graphics::Gdi::HDC, dwcodepages: u32, hsrcfont: super::Graphics::Gdi::HFONT, phdestfont: *mut super::Graphics::Gdi::HFONT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangFontLink", "MapFont", 8usize, |this| ((**(this as *const *const IMLangFontLink_Vtbl)).MapFont)(this, hdc, dwcodepages, hsrcfont, phdestfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn ReleaseFont(this: *mut core::ffi::c_void, hfont: super::Graphics::Gdi::HFONT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangFontLink", "ReleaseFont", 9usize, |this| ((**(this as *const *const IMLangFontLink_Vtbl)).ReleaseFont)(this, hfont), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetFontMapping(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangFontLink", "ResetFontMapping", 10usize, |this| ((**(this as *const *const IMLangFontLink_Vtbl)).ResetFontMapping)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IMLangCodePages_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetFontCodePages,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetFontCodePages: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            MapFont,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            MapFont: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            ReleaseFont,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            ReleaseFont: 0,
+            ResetFontMapping,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLangFontLink as windows_core::Interface>::IID || <IMLangCodePages_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IMLangFontLink2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetFontCodePages(this: *mut core::ffi::c_void, hdc: super::Graphics::Gdi::HDC, hfont: super::Graphics::Gdi::HFONT, pdwcodepages: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangFontLink2", "GetFontCodePages", 7usize, |this| ((**(this as *const *const IMLangFontLink2_Vtbl)).GetFontCodePages)(this, hdc, hfont, pdwcodepages), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn ReleaseFont(this: *mut core::ffi::c_void, hfont: super::Graphics::Gdi::HFONT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangFontLink2", "ReleaseFont", 8usize, |this| ((**(this as *const *const IMLangFontLink2_Vtbl)).ReleaseFont)(this, hfont), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetFontMapping(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangFontLink2", "ResetFontMapping", 9usize, |this| ((**(this as *const *const IMLangFontLink2_Vtbl)).ResetFontMapping)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn MapFont(this: *mut core::ffi::c_void, hdc: super::Graphics::Gdi::HDC, dwcodepages: u32, chsrc: u16, pfont: *mut super::Graphics::Gdi::HFONT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangFontLink2", "MapFont", 10usize, |this| ((**(this as *const *const IMLangFontLink2_Vtbl)).MapFont)(this, hdc, dwcodepages, chsrc, pfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetFontUnicodeRanges(this: *mut core::ffi::c_void, hdc: super::Graphics::Gdi::HDC, puiranges: *const u32, puranges: *mut UNICODERANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangFontLink2", "GetFontUnicodeRanges", 11usize, |this| ((**(this as *const *const IMLangFontLink2_Vtbl)).GetFontUnicodeRanges)(this, hdc, puiranges, puranges), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScriptFontInfo(this: *mut core::ffi::c_void, sid: u8, dwflags: u32, puifonts: *mut u32, pscriptfont: *mut SCRIPTFONTINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangFontLink2", "GetScriptFontInfo", 12usize, |this| ((**(this as *const *const IMLangFontLink2_Vtbl)).GetScriptFontInfo)(this, sid, dwflags, puifonts, pscriptfont), |result| Some(*result))
+        }
+        unsafe extern "system" fn CodePageToScriptID(this: *mut core::ffi::c_void, uicodepage: u32, psid: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangFontLink2", "CodePageToScriptID", 13usize, |this| ((**(this as *const *const IMLangFontLink2_Vtbl)).CodePageToScriptID)(this, uicodepage, psid), |result| Some(*result))
+        }
+        Self {
+            base__: <IMLangCodePages_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetFontCodePages,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetFontCodePages: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            ReleaseFont,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            ReleaseFont: 0,
+            ResetFontMapping,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            MapFont,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            MapFont: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetFontUnicodeRanges,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetFontUnicodeRanges: 0,
+            GetScriptFontInfo,
+            CodePageToScriptID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLangFontLink2 as windows_core::Interface>::IID || <IMLangCodePages_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLangLineBreakConsole_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BreakLineML(this: *mut core::ffi::c_void, psrcmlstr: *mut core::ffi::c_void, lsrcpos: i32, lsrclen: i32, cmincolumns: i32, cmaxcolumns: i32, pllinelen: *mut i32, plskiplen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangLineBreakConsole", "BreakLineML", 3usize, |this| ((**(this as *const *const IMLangLineBreakConsole_Vtbl)).BreakLineML)(this, psrcmlstr, lsrcpos, lsrclen, cmincolumns, cmaxcolumns, pllinelen, plskiplen), |result| Some(*result))
+        }
+        unsafe extern "system" fn BreakLineW(this: *mut core::ffi::c_void, locale: u32, pszsrc: windows_core::PCWSTR, cchsrc: i32, cmaxcolumns: i32, pcchline: *mut i32, pcchskip: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangLineBreakConsole", "BreakLineW", 4usize, |this| ((**(this as *const *const IMLangLineBreakConsole_Vtbl)).BreakLineW)(this, locale, pszsrc, cchsrc, cmaxcolumns, pcchline, pcchskip), |result| Some(*result))
+        }
+        unsafe extern "system" fn BreakLineA(this: *mut core::ffi::c_void, locale: u32, ucodepage: u32, pszsrc: windows_core::PCSTR, cchsrc: i32, cmaxcolumns: i32, pcchline: *mut i32, pcchskip: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangLineBreakConsole", "BreakLineA", 5usize, |this| ((**(this as *const *const IMLangLineBreakConsole_Vtbl)).BreakLineA)(this, locale, ucodepage, pszsrc, cchsrc, cmaxcolumns, pcchline, pcchskip), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BreakLineML, BreakLineW, BreakLineA }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLangLineBreakConsole as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLangString_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Sync(this: *mut core::ffi::c_void, fnoaccess: super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangString", "Sync", 3usize, |this| ((**(this as *const *const IMLangString_Vtbl)).Sync)(this, fnoaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pllen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangString", "GetLength", 4usize, |this| ((**(this as *const *const IMLangString_Vtbl)).GetLength)(this, pllen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMLStr(this: *mut core::ffi::c_void, ldestpos: i32, ldestlen: i32, psrcmlstr: *mut core::ffi::c_void, lsrcpos: i32, lsrclen: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangString", "SetMLStr", 5usize, |this| ((**(this as *const *const IMLangString_Vtbl)).SetMLStr)(this, ldestpos, ldestlen, psrcmlstr, lsrcpos, lsrclen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMLStr(this: *mut core::ffi::c_void, lsrcpos: i32, lsrclen: i32, punkouter: *mut core::ffi::c_void, dwclscontext: u32, piid: *const windows_core::GUID, ppdestmlstr: *mut *mut core::ffi::c_void, pldestpos: *mut i32, pldestlen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangString", "GetMLStr", 6usize, |this| ((**(this as *const *const IMLangString_Vtbl)).GetMLStr)(this, lsrcpos, lsrclen, punkouter, dwclscontext, piid, ppdestmlstr, pldestpos, pldestlen), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Sync, GetLength, SetMLStr, GetMLStr }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLangString as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLangStringAStr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAStr(this: *mut core::ffi::c_void, ldestpos: i32, ldestlen: i32, ucodepage: u32, pszsrc: windows_core::PCSTR, cchsrc: i32, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringAStr", "SetAStr", 7usize, |this| ((**(this as *const *const IMLangStringAStr_Vtbl)).SetAStr)(this, ldestpos, ldestlen, ucodepage, pszsrc, cchsrc, pcchactual, plactuallen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrBufA(this: *mut core::ffi::c_void, ldestpos: i32, ldestlen: i32, ucodepage: u32, psrcbuf: *mut core::ffi::c_void, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringAStr", "SetStrBufA", 8usize, |this| ((**(this as *const *const IMLangStringAStr_Vtbl)).SetStrBufA)(this, ldestpos, ldestlen, ucodepage, psrcbuf, pcchactual, plactuallen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAStr(this: *mut core::ffi::c_void, lsrcpos: i32, lsrclen: i32, ucodepagein: u32, pucodepageout: *const u32, pszdest: windows_core::PSTR, cchdest: i32, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringAStr", "GetAStr", 9usize, |this| ((**(this as *const *const IMLangStringAStr_Vtbl)).GetAStr)(this, lsrcpos, lsrclen, ucodepagein, pucodepageout, pszdest, cchdest, pcchactual, plactuallen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrBufA(this: *mut core::ffi::c_void, lsrcpos: i32, lsrcmaxlen: i32, pudestcodepage: *mut u32, ppdestbuf: *mut *mut core::ffi::c_void, pldestlen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringAStr", "GetStrBufA", 10usize, |this| ((**(this as *const *const IMLangStringAStr_Vtbl)).GetStrBufA)(this, lsrcpos, lsrcmaxlen, pudestcodepage, ppdestbuf, pldestlen), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockAStr(this: *mut core::ffi::c_void, lsrcpos: i32, lsrclen: i32, lflags: i32, ucodepagein: u32, cchrequest: i32, pucodepageout: *mut u32, ppszdest: *mut windows_core::PSTR, pcchdest: *mut i32, pldestlen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringAStr", "LockAStr", 11usize, |this| ((**(this as *const *const IMLangStringAStr_Vtbl)).LockAStr)(this, lsrcpos, lsrclen, lflags, ucodepagein, cchrequest, pucodepageout, ppszdest, pcchdest, pldestlen), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockAStr(this: *mut core::ffi::c_void, pszsrc: windows_core::PCSTR, cchsrc: i32, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringAStr", "UnlockAStr", 12usize, |this| ((**(this as *const *const IMLangStringAStr_Vtbl)).UnlockAStr)(this, pszsrc, cchsrc, pcchactual, plactuallen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocale(this: *mut core::ffi::c_void, ldestpos: i32, ldestlen: i32, locale: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringAStr", "SetLocale", 13usize, |this| ((**(this as *const *const IMLangStringAStr_Vtbl)).SetLocale)(this, ldestpos, ldestlen, locale), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocale(this: *mut core::ffi::c_void, lsrcpos: i32, lsrcmaxlen: i32, plocale: *mut u32, pllocalepos: *mut i32, pllocalelen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringAStr", "GetLocale", 14usize, |this| ((**(this as *const *const IMLangStringAStr_Vtbl)).GetLocale)(this, lsrcpos, lsrcmaxlen, plocale, pllocalepos, pllocalelen), |result| Some(*result))
+        }
+        Self {
+            base__: <IMLangString_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAStr,
+            SetStrBufA,
+            GetAStr,
+            GetStrBufA,
+            LockAStr,
+            UnlockAStr,
+            SetLocale,
+            GetLocale,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLangStringAStr as windows_core::Interface>::IID || <IMLangString_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLangStringBufA_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, plflags: *mut i32, pcchbuf: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringBufA", "GetStatus", 3usize, |this| ((**(this as *const *const IMLangStringBufA_Vtbl)).GetStatus)(this, plflags, pcchbuf), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockBuf(this: *mut core::ffi::c_void, cchoffset: i32, cchmaxlock: i32, ppszbuf: *mut *mut i8, pcchbuf: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringBufA", "LockBuf", 4usize, |this| ((**(this as *const *const IMLangStringBufA_Vtbl)).LockBuf)(this, cchoffset, cchmaxlock, ppszbuf, pcchbuf), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockBuf(this: *mut core::ffi::c_void, pszbuf: windows_core::PCSTR, cchoffset: i32, cchwrite: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringBufA", "UnlockBuf", 5usize, |this| ((**(this as *const *const IMLangStringBufA_Vtbl)).UnlockBuf)(this, pszbuf, cchoffset, cchwrite), |result| Some(*result))
+        }
+        unsafe extern "system" fn Insert(this: *mut core::ffi::c_void, cchoffset: i32, cchmaxinsert: i32, pcchactual: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringBufA", "Insert", 6usize, |this| ((**(this as *const *const IMLangStringBufA_Vtbl)).Insert)(this, cchoffset, cchmaxinsert, pcchactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, cchoffset: i32, cchdelete: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringBufA", "Delete", 7usize, |this| ((**(this as *const *const IMLangStringBufA_Vtbl)).Delete)(this, cchoffset, cchdelete), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStatus, LockBuf, UnlockBuf, Insert, Delete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLangStringBufA as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLangStringBufW_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, plflags: *mut i32, pcchbuf: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringBufW", "GetStatus", 3usize, |this| ((**(this as *const *const IMLangStringBufW_Vtbl)).GetStatus)(this, plflags, pcchbuf), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockBuf(this: *mut core::ffi::c_void, cchoffset: i32, cchmaxlock: i32, ppszbuf: *mut *mut u16, pcchbuf: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringBufW", "LockBuf", 4usize, |this| ((**(this as *const *const IMLangStringBufW_Vtbl)).LockBuf)(this, cchoffset, cchmaxlock, ppszbuf, pcchbuf), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockBuf(this: *mut core::ffi::c_void, pszbuf: windows_core::PCWSTR, cchoffset: i32, cchwrite: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringBufW", "UnlockBuf", 5usize, |this| ((**(this as *const *const IMLangStringBufW_Vtbl)).UnlockBuf)(this, pszbuf, cchoffset, cchwrite), |result| Some(*result))
+        }
+        unsafe extern "system" fn Insert(this: *mut core::ffi::c_void, cchoffset: i32, cchmaxinsert: i32, pcchactual: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringBufW", "Insert", 6usize, |this| ((**(this as *const *const IMLangStringBufW_Vtbl)).Insert)(this, cchoffset, cchmaxinsert, pcchactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, cchoffset: i32, cchdelete: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringBufW", "Delete", 7usize, |this| ((**(this as *const *const IMLangStringBufW_Vtbl)).Delete)(this, cchoffset, cchdelete), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStatus, LockBuf, UnlockBuf, Insert, Delete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLangStringBufW as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMLangStringWStr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWStr(this: *mut core::ffi::c_void, ldestpos: i32, ldestlen: i32, pszsrc: windows_core::PCWSTR, cchsrc: i32, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringWStr", "SetWStr", 7usize, |this| ((**(this as *const *const IMLangStringWStr_Vtbl)).SetWStr)(this, ldestpos, ldestlen, pszsrc, cchsrc, pcchactual, plactuallen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrBufW(this: *mut core::ffi::c_void, ldestpos: i32, ldestlen: i32, psrcbuf: *mut core::ffi::c_void, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringWStr", "SetStrBufW", 8usize, |this| ((**(this as *const *const IMLangStringWStr_Vtbl)).SetStrBufW)(this, ldestpos, ldestlen, psrcbuf, pcchactual, plactuallen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWStr(this: *mut core::ffi::c_void, lsrcpos: i32, lsrclen: i32, pszdest: windows_core::PWSTR, cchdest: i32, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringWStr", "GetWStr", 9usize, |this| ((**(this as *const *const IMLangStringWStr_Vtbl)).GetWStr)(this, lsrcpos, lsrclen, pszdest, cchdest, pcchactual, plactuallen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrBufW(this: *mut core::ffi::c_void, lsrcpos: i32, lsrcmaxlen: i32, ppdestbuf: *mut *mut core::ffi::c_void, pldestlen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringWStr", "GetStrBufW", 10usize, |this| ((**(this as *const *const IMLangStringWStr_Vtbl)).GetStrBufW)(this, lsrcpos, lsrcmaxlen, ppdestbuf, pldestlen), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockWStr(this: *mut core::ffi::c_void, lsrcpos: i32, lsrclen: i32, lflags: i32, cchrequest: i32, ppszdest: *mut windows_core::PWSTR, pcchdest: *mut i32, pldestlen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringWStr", "LockWStr", 11usize, |this| ((**(this as *const *const IMLangStringWStr_Vtbl)).LockWStr)(this, lsrcpos, lsrclen, lflags, cchrequest, ppszdest, pcchdest, pldestlen), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockWStr(this: *mut core::ffi::c_void, pszsrc: windows_core::PCWSTR, cchsrc: i32, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringWStr", "UnlockWStr", 12usize, |this| ((**(this as *const *const IMLangStringWStr_Vtbl)).UnlockWStr)(this, pszsrc, cchsrc, pcchactual, plactuallen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocale(this: *mut core::ffi::c_void, ldestpos: i32, ldestlen: i32, locale: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringWStr", "SetLocale", 13usize, |this| ((**(this as *const *const IMLangStringWStr_Vtbl)).SetLocale)(this, ldestpos, ldestlen, locale), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocale(this: *mut core::ffi::c_void, lsrcpos: i32, lsrcmaxlen: i32, plocale: *mut u32, pllocalepos: *mut i32, pllocalelen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMLangStringWStr", "GetLocale", 14usize, |this| ((**(this as *const *const IMLangStringWStr_Vtbl)).GetLocale)(this, lsrcpos, lsrcmaxlen, plocale, pllocalepos, pllocalelen), |result| Some(*result))
+        }
+        Self {
+            base__: <IMLangString_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetWStr,
+            SetStrBufW,
+            GetWStr,
+            GetStrBufW,
+            LockWStr,
+            UnlockWStr,
+            SetLocale,
+            GetLocale,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMLangStringWStr as windows_core::Interface>::IID || <IMLangString_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMultiLanguage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNumberOfCodePageInfo(this: *mut core::ffi::c_void, pccodepage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage", "GetNumberOfCodePageInfo", 3usize, |this| ((**(this as *const *const IMultiLanguage_Vtbl)).GetNumberOfCodePageInfo)(this, pccodepage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodePageInfo(this: *mut core::ffi::c_void, uicodepage: u32, pcodepageinfo: *mut MIMECPINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage", "GetCodePageInfo", 4usize, |this| ((**(this as *const *const IMultiLanguage_Vtbl)).GetCodePageInfo)(this, uicodepage, pcodepageinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFamilyCodePage(this: *mut core::ffi::c_void, uicodepage: u32, puifamilycodepage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage", "GetFamilyCodePage", 5usize, |this| ((**(this as *const *const IMultiLanguage_Vtbl)).GetFamilyCodePage)(this, uicodepage, puifamilycodepage), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumCodePages(this: *mut core::ffi::c_void, grfflags: u32, ppenumcodepage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage", "EnumCodePages", 6usize, |this| ((**(this as *const *const IMultiLanguage_Vtbl)).EnumCodePages)(this, grfflags, ppenumcodepage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCharsetInfo(this: *mut core::ffi::c_void, charset: core::mem::MaybeUninit<windows_core::BSTR>, pcharsetinfo: *mut MIMECSETINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage", "GetCharsetInfo", 7usize, |this| ((**(this as *const *const IMultiLanguage_Vtbl)).GetCharsetInfo)(this, charset, pcharsetinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConvertible(this: *mut core::ffi::c_void, dwsrcencoding: u32, dwdstencoding: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage", "IsConvertible", 8usize, |this| ((**(this as *const *const IMultiLanguage_Vtbl)).IsConvertible)(this, dwsrcencoding, dwdstencoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertString(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwsrcencoding: u32, dwdstencoding: u32, psrcstr: *const u8, pcsrcsize: *mut u32, pdststr: *mut u8, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage", "ConvertString", 9usize, |this| ((**(this as *const *const IMultiLanguage_Vtbl)).ConvertString)(this, pdwmode, dwsrcencoding, dwdstencoding, psrcstr, pcsrcsize, pdststr, pcdstsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertStringToUnicode(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwencoding: u32, psrcstr: windows_core::PCSTR, pcsrcsize: *mut u32, pdststr: windows_core::PWSTR, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage", "ConvertStringToUnicode", 10usize, |this| ((**(this as *const *const IMultiLanguage_Vtbl)).ConvertStringToUnicode)(this, pdwmode, dwencoding, psrcstr, pcsrcsize, pdststr, pcdstsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertStringFromUnicode(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwencoding: u32, psrcstr: windows_core::PCWSTR, pcsrcsize: *mut u32, pdststr: windows_core::PSTR, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage", "ConvertStringFromUnicode", 11usize, |this| ((**(this as *const *const IMultiLanguage_Vtbl)).ConvertStringFromUnicode)(this, pdwmode, dwencoding, psrcstr, pcsrcsize, pdststr, pcdstsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertStringReset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage", "ConvertStringReset", 12usize, |this| ((**(this as *const *const IMultiLanguage_Vtbl)).ConvertStringReset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRfc1766FromLcid(this: *mut core::ffi::c_void, locale: u32, pbstrrfc1766: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage", "GetRfc1766FromLcid", 13usize, |this| ((**(this as *const *const IMultiLanguage_Vtbl)).GetRfc1766FromLcid)(this, locale, pbstrrfc1766), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLcidFromRfc1766(this: *mut core::ffi::c_void, plocale: *mut u32, bstrrfc1766: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage", "GetLcidFromRfc1766", 14usize, |this| ((**(this as *const *const IMultiLanguage_Vtbl)).GetLcidFromRfc1766)(this, plocale, bstrrfc1766), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumRfc1766(this: *mut core::ffi::c_void, ppenumrfc1766: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage", "EnumRfc1766", 15usize, |this| ((**(this as *const *const IMultiLanguage_Vtbl)).EnumRfc1766)(this, ppenumrfc1766), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRfc1766Info(this: *mut core::ffi::c_void, locale: u32, prfc1766info: *mut RFC1766INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage", "GetRfc1766Info", 16usize, |this| ((**(this as *const *const IMultiLanguage_Vtbl)).GetRfc1766Info)(this, locale, prfc1766info), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateConvertCharset(this: *mut core::ffi::c_void, uisrccodepage: u32, uidstcodepage: u32, dwproperty: u32, ppmlangconvertcharset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage", "CreateConvertCharset", 17usize, |this| ((**(this as *const *const IMultiLanguage_Vtbl)).CreateConvertCharset)(this, uisrccodepage, uidstcodepage, dwproperty, ppmlangconvertcharset), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNumberOfCodePageInfo,
+            GetCodePageInfo,
+            GetFamilyCodePage,
+            EnumCodePages,
+            GetCharsetInfo,
+            IsConvertible,
+            ConvertString,
+            ConvertStringToUnicode,
+            ConvertStringFromUnicode,
+            ConvertStringReset,
+            GetRfc1766FromLcid,
+            GetLcidFromRfc1766,
+            EnumRfc1766,
+            GetRfc1766Info,
+            CreateConvertCharset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultiLanguage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMultiLanguage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNumberOfCodePageInfo(this: *mut core::ffi::c_void, pccodepage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "GetNumberOfCodePageInfo", 3usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).GetNumberOfCodePageInfo)(this, pccodepage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodePageInfo(this: *mut core::ffi::c_void, uicodepage: u32, langid: u16, pcodepageinfo: *mut MIMECPINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "GetCodePageInfo", 4usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).GetCodePageInfo)(this, uicodepage, langid, pcodepageinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFamilyCodePage(this: *mut core::ffi::c_void, uicodepage: u32, puifamilycodepage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "GetFamilyCodePage", 5usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).GetFamilyCodePage)(this, uicodepage, puifamilycodepage), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumCodePages(this: *mut core::ffi::c_void, grfflags: u32, langid: u16, ppenumcodepage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "EnumCodePages", 6usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).EnumCodePages)(this, grfflags, langid, ppenumcodepage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCharsetInfo(this: *mut core::ffi::c_void, charset: core::mem::MaybeUninit<windows_core::BSTR>, pcharsetinfo: *mut MIMECSETINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "GetCharsetInfo", 7usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).GetCharsetInfo)(this, charset, pcharsetinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConvertible(this: *mut core::ffi::c_void, dwsrcencoding: u32, dwdstencoding: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "IsConvertible", 8usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).IsConvertible)(this, dwsrcencoding, dwdstencoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertString(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwsrcencoding: u32, dwdstencoding: u32, psrcstr: *const u8, pcsrcsize: *mut u32, pdststr: *mut u8, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "ConvertString", 9usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).ConvertString)(this, pdwmode, dwsrcencoding, dwdstencoding, psrcstr, pcsrcsize, pdststr, pcdstsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertStringToUnicode(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwencoding: u32, psrcstr: windows_core::PCSTR, pcsrcsize: *mut u32, pdststr: windows_core::PWSTR, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "ConvertStringToUnicode", 10usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).ConvertStringToUnicode)(this, pdwmode, dwencoding, psrcstr, pcsrcsize, pdststr, pcdstsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertStringFromUnicode(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwencoding: u32, psrcstr: windows_core::PCWSTR, pcsrcsize: *mut u32, pdststr: windows_core::PSTR, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "ConvertStringFromUnicode", 11usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).ConvertStringFromUnicode)(this, pdwmode, dwencoding, psrcstr, pcsrcsize, pdststr, pcdstsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertStringReset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "ConvertStringReset", 12usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).ConvertStringReset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRfc1766FromLcid(this: *mut core::ffi::c_void, locale: u32, pbstrrfc1766: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "GetRfc1766FromLcid", 13usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).GetRfc1766FromLcid)(this, locale, pbstrrfc1766), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLcidFromRfc1766(this: *mut core::ffi::c_void, plocale: *mut u32, bstrrfc1766: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "GetLcidFromRfc1766", 14usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).GetLcidFromRfc1766)(this, plocale, bstrrfc1766), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumRfc1766(this: *mut core::ffi::c_void, langid: u16, ppenumrfc1766: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "EnumRfc1766", 15usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).EnumRfc1766)(this, langid, ppenumrfc1766), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRfc1766Info(this: *mut core::ffi::c_void, locale: u32, langid: u16, prfc1766info: *mut RFC1766INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "GetRfc1766Info", 16usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).GetRfc1766Info)(this, locale, langid, prfc1766info), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateConvertCharset(this: *mut core::ffi::c_void, uisrccodepage: u32, uidstcodepage: u32, dwproperty: u32, ppmlangconvertcharset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "CreateConvertCharset", 17usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).CreateConvertCharset)(this, uisrccodepage, uidstcodepage, dwproperty, ppmlangconvertcharset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ConvertStringInIStream(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwflag: u32, lpfallback: windows_core::PCWSTR, dwsrcencoding: u32, dwdstencoding: u32, pstmin: *mut core::ffi::c_void, pstmout: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "ConvertStringInIStream", 18usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).ConvertStringInIStream)(this, pdwmode, dwflag, lpfallback, dwsrcencoding, dwdstencoding, pstmin, pstmout), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertStringToUnicodeEx(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwencoding: u32, psrcstr: windows_core::PCSTR, pcsrcsize: *mut u32, pdststr: windows_core::PWSTR, pcdstsize: *mut u32, dwflag: u32, lpfallback: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "ConvertStringToUnicodeEx", 19usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).ConvertStringToUnicodeEx)(this, pdwmode, dwencoding, psrcstr, pcsrcsize, pdststr, pcdstsize, dwflag, lpfallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertStringFromUnicodeEx(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwencoding: u32, psrcstr: windows_core::PCWSTR, pcsrcsize: *mut u32, pdststr: windows_core::PSTR, pcdstsize: *mut u32, dwflag: u32, lpfallback: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "ConvertStringFromUnicodeEx", 20usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).ConvertStringFromUnicodeEx)(this, pdwmode, dwencoding, psrcstr, pcsrcsize, pdststr, pcdstsize, dwflag, lpfallback), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DetectCodepageInIStream(this: *mut core::ffi::c_void, dwflag: u32, dwprefwincodepage: u32, pstmin: *mut core::ffi::c_void, lpencoding: *mut DetectEncodingInfo, pnscores: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "DetectCodepageInIStream", 21usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).DetectCodepageInIStream)(this, dwflag, dwprefwincodepage, pstmin, lpencoding, pnscores), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetectInputCodepage(this: *mut core::ffi::c_void, dwflag: u32, dwprefwincodepage: u32, psrcstr: windows_core::PCSTR, pcsrcsize: *mut i32, lpencoding: *mut DetectEncodingInfo, pnscores: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "DetectInputCodepage", 22usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).DetectInputCodepage)(this, dwflag, dwprefwincodepage, psrcstr, pcsrcsize, lpencoding, pnscores), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateCodePage(this: *mut core::ffi::c_void, uicodepage: u32, hwnd: super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "ValidateCodePage", 23usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).ValidateCodePage)(this, uicodepage, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodePageDescription(this: *mut core::ffi::c_void, uicodepage: u32, lcid: u32, lpwidecharstr: windows_core::PWSTR, cchwidechar: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "GetCodePageDescription", 24usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).GetCodePageDescription)(this, uicodepage, lcid, lpwidecharstr, cchwidechar), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCodePageInstallable(this: *mut core::ffi::c_void, uicodepage: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "IsCodePageInstallable", 25usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).IsCodePageInstallable)(this, uicodepage), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMimeDBSource(this: *mut core::ffi::c_void, dwsource: MIMECONTF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "SetMimeDBSource", 26usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).SetMimeDBSource)(this, dwsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberOfScripts(this: *mut core::ffi::c_void, pnscripts: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "GetNumberOfScripts", 27usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).GetNumberOfScripts)(this, pnscripts), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumScripts(this: *mut core::ffi::c_void, dwflags: u32, langid: u16, ppenumscript: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "EnumScripts", 28usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).EnumScripts)(this, dwflags, langid, ppenumscript), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateCodePageEx(this: *mut core::ffi::c_void, uicodepage: u32, hwnd: super::Foundation::HWND, dwfiodcontrol: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage2", "ValidateCodePageEx", 29usize, |this| ((**(this as *const *const IMultiLanguage2_Vtbl)).ValidateCodePageEx)(this, uicodepage, hwnd, dwfiodcontrol), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNumberOfCodePageInfo,
+            GetCodePageInfo,
+            GetFamilyCodePage,
+            EnumCodePages,
+            GetCharsetInfo,
+            IsConvertible,
+            ConvertString,
+            ConvertStringToUnicode,
+            ConvertStringFromUnicode,
+            ConvertStringReset,
+            GetRfc1766FromLcid,
+            GetLcidFromRfc1766,
+            EnumRfc1766,
+            GetRfc1766Info,
+            CreateConvertCharset,
+            #[cfg(feature = "Win32_System_Com")]
+            ConvertStringInIStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ConvertStringInIStream: 0,
+            ConvertStringToUnicodeEx,
+            ConvertStringFromUnicodeEx,
+            #[cfg(feature = "Win32_System_Com")]
+            DetectCodepageInIStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DetectCodepageInIStream: 0,
+            DetectInputCodepage,
+            ValidateCodePage,
+            GetCodePageDescription,
+            IsCodePageInstallable,
+            SetMimeDBSource,
+            GetNumberOfScripts,
+            EnumScripts,
+            ValidateCodePageEx,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultiLanguage2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMultiLanguage3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DetectOutboundCodePage(this: *mut core::ffi::c_void, dwflags: u32, lpwidecharstr: windows_core::PCWSTR, cchwidechar: u32, puipreferredcodepages: *const u32, npreferredcodepages: u32, puidetectedcodepages: *mut u32, pndetectedcodepages: *mut u32, lpspecialchar: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage3", "DetectOutboundCodePage", 30usize, |this| ((**(this as *const *const IMultiLanguage3_Vtbl)).DetectOutboundCodePage)(this, dwflags, lpwidecharstr, cchwidechar, puipreferredcodepages, npreferredcodepages, puidetectedcodepages, pndetectedcodepages, lpspecialchar), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DetectOutboundCodePageInIStream(this: *mut core::ffi::c_void, dwflags: u32, pstrin: *mut core::ffi::c_void, puipreferredcodepages: *const u32, npreferredcodepages: u32, puidetectedcodepages: *mut u32, pndetectedcodepages: *mut u32, lpspecialchar: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiLanguage3", "DetectOutboundCodePageInIStream", 31usize, |this| ((**(this as *const *const IMultiLanguage3_Vtbl)).DetectOutboundCodePageInIStream)(this, dwflags, pstrin, puipreferredcodepages, npreferredcodepages, puidetectedcodepages, pndetectedcodepages, lpspecialchar), |result| Some(*result))
+        }
+        Self {
+            base__: <IMultiLanguage2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DetectOutboundCodePage,
+            #[cfg(feature = "Win32_System_Com")]
+            DetectOutboundCodePageInIStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DetectOutboundCodePageInIStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultiLanguage3 as windows_core::Interface>::IID || <IMultiLanguage2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOptionDescription_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOptionDescription", "Id", 3usize, |this| ((**(this as *const *const IOptionDescription_Vtbl)).Id)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Heading(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOptionDescription", "Heading", 4usize, |this| ((**(this as *const *const IOptionDescription_Vtbl)).Heading)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOptionDescription", "Description", 5usize, |this| ((**(this as *const *const IOptionDescription_Vtbl)).Description)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Labels(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOptionDescription", "Labels", 6usize, |this| ((**(this as *const *const IOptionDescription_Vtbl)).Labels)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            Heading,
+            Description,
+            #[cfg(feature = "Win32_System_Com")]
+            Labels,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Labels: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOptionDescription as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpellCheckProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LanguageTag(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckProvider", "LanguageTag", 3usize, |this| ((**(this as *const *const ISpellCheckProvider_Vtbl)).LanguageTag)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Check(this: *mut core::ffi::c_void, text: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckProvider", "Check", 4usize, |this| ((**(this as *const *const ISpellCheckProvider_Vtbl)).Check)(this, text, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Suggest(this: *mut core::ffi::c_void, word: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckProvider", "Suggest", 5usize, |this| ((**(this as *const *const ISpellCheckProvider_Vtbl)).Suggest)(this, word, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptionValue(this: *mut core::ffi::c_void, optionid: windows_core::PCWSTR, value: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckProvider", "GetOptionValue", 6usize, |this| ((**(this as *const *const ISpellCheckProvider_Vtbl)).GetOptionValue)(this, optionid, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOptionValue(this: *mut core::ffi::c_void, optionid: windows_core::PCWSTR, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckProvider", "SetOptionValue", 7usize, |this| ((**(this as *const *const ISpellCheckProvider_Vtbl)).SetOptionValue)(this, optionid, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OptionIds(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckProvider", "OptionIds", 8usize, |this| ((**(this as *const *const ISpellCheckProvider_Vtbl)).OptionIds)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckProvider", "Id", 9usize, |this| ((**(this as *const *const ISpellCheckProvider_Vtbl)).Id)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalizedName(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckProvider", "LocalizedName", 10usize, |this| ((**(this as *const *const ISpellCheckProvider_Vtbl)).LocalizedName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptionDescription(this: *mut core::ffi::c_void, optionid: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckProvider", "GetOptionDescription", 11usize, |this| ((**(this as *const *const ISpellCheckProvider_Vtbl)).GetOptionDescription)(this, optionid, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeWordlist(this: *mut core::ffi::c_void, wordlisttype: WORDLIST_TYPE, words: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckProvider", "InitializeWordlist", 12usize, |this| ((**(this as *const *const ISpellCheckProvider_Vtbl)).InitializeWordlist)(this, wordlisttype, words), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LanguageTag,
+            Check,
+            #[cfg(feature = "Win32_System_Com")]
+            Suggest,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Suggest: 0,
+            GetOptionValue,
+            SetOptionValue,
+            #[cfg(feature = "Win32_System_Com")]
+            OptionIds,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OptionIds: 0,
+            Id,
+            LocalizedName,
+            GetOptionDescription,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeWordlist,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeWordlist: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpellCheckProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpellCheckProviderFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SupportedLanguages(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckProviderFactory", "SupportedLanguages", 3usize, |this| ((**(this as *const *const ISpellCheckProviderFactory_Vtbl)).SupportedLanguages)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, languagetag: windows_core::PCWSTR, value: *mut super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckProviderFactory", "IsSupported", 4usize, |this| ((**(this as *const *const ISpellCheckProviderFactory_Vtbl)).IsSupported)(this, languagetag, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSpellCheckProvider(this: *mut core::ffi::c_void, languagetag: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckProviderFactory", "CreateSpellCheckProvider", 5usize, |this| ((**(this as *const *const ISpellCheckProviderFactory_Vtbl)).CreateSpellCheckProvider)(this, languagetag, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            SupportedLanguages,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SupportedLanguages: 0,
+            IsSupported,
+            CreateSpellCheckProvider,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpellCheckProviderFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpellChecker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LanguageTag(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellChecker", "LanguageTag", 3usize, |this| ((**(this as *const *const ISpellChecker_Vtbl)).LanguageTag)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Check(this: *mut core::ffi::c_void, text: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellChecker", "Check", 4usize, |this| ((**(this as *const *const ISpellChecker_Vtbl)).Check)(this, text, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Suggest(this: *mut core::ffi::c_void, word: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellChecker", "Suggest", 5usize, |this| ((**(this as *const *const ISpellChecker_Vtbl)).Suggest)(this, word, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, word: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellChecker", "Add", 6usize, |this| ((**(this as *const *const ISpellChecker_Vtbl)).Add)(this, word), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ignore(this: *mut core::ffi::c_void, word: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellChecker", "Ignore", 7usize, |this| ((**(this as *const *const ISpellChecker_Vtbl)).Ignore)(this, word), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoCorrect(this: *mut core::ffi::c_void, from: windows_core::PCWSTR, to: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellChecker", "AutoCorrect", 8usize, |this| ((**(this as *const *const ISpellChecker_Vtbl)).AutoCorrect)(this, from, to), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptionValue(this: *mut core::ffi::c_void, optionid: windows_core::PCWSTR, value: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellChecker", "GetOptionValue", 9usize, |this| ((**(this as *const *const ISpellChecker_Vtbl)).GetOptionValue)(this, optionid, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OptionIds(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellChecker", "OptionIds", 10usize, |this| ((**(this as *const *const ISpellChecker_Vtbl)).OptionIds)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellChecker", "Id", 11usize, |this| ((**(this as *const *const ISpellChecker_Vtbl)).Id)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalizedName(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellChecker", "LocalizedName", 12usize, |this| ((**(this as *const *const ISpellChecker_Vtbl)).LocalizedName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn add_SpellCheckerChanged(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, eventcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellChecker", "add_SpellCheckerChanged", 13usize, |this| ((**(this as *const *const ISpellChecker_Vtbl)).add_SpellCheckerChanged)(this, handler, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn remove_SpellCheckerChanged(this: *mut core::ffi::c_void, eventcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellChecker", "remove_SpellCheckerChanged", 14usize, |this| ((**(this as *const *const ISpellChecker_Vtbl)).remove_SpellCheckerChanged)(this, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptionDescription(this: *mut core::ffi::c_void, optionid: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellChecker", "GetOptionDescription", 15usize, |this| ((**(this as *const *const ISpellChecker_Vtbl)).GetOptionDescription)(this, optionid, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComprehensiveCheck(this: *mut core::ffi::c_void, text: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellChecker", "ComprehensiveCheck", 16usize, |this| ((**(this as *const *const ISpellChecker_Vtbl)).ComprehensiveCheck)(this, text, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LanguageTag,
+            Check,
+            #[cfg(feature = "Win32_System_Com")]
+            Suggest,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Suggest: 0,
+            Add,
+            Ignore,
+            AutoCorrect,
+            GetOptionValue,
+            #[cfg(feature = "Win32_System_Com")]
+            OptionIds,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OptionIds: 0,
+            Id,
+            LocalizedName,
+            add_SpellCheckerChanged,
+            remove_SpellCheckerChanged,
+            GetOptionDescription,
+            ComprehensiveCheck,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpellChecker as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpellChecker2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, word: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellChecker2", "Remove", 17usize, |this| ((**(this as *const *const ISpellChecker2_Vtbl)).Remove)(this, word), |result| Some(*result))
+        }
+        Self { base__: <ISpellChecker_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Remove }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpellChecker2 as windows_core::Interface>::IID || <ISpellChecker_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpellCheckerChangedEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckerChangedEventHandler", "Invoke", 3usize, |this| ((**(this as *const *const ISpellCheckerChangedEventHandler_Vtbl)).Invoke)(this, sender), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Invoke }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpellCheckerChangedEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpellCheckerFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SupportedLanguages(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckerFactory", "SupportedLanguages", 3usize, |this| ((**(this as *const *const ISpellCheckerFactory_Vtbl)).SupportedLanguages)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, languagetag: windows_core::PCWSTR, value: *mut super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckerFactory", "IsSupported", 4usize, |this| ((**(this as *const *const ISpellCheckerFactory_Vtbl)).IsSupported)(this, languagetag, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSpellChecker(this: *mut core::ffi::c_void, languagetag: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellCheckerFactory", "CreateSpellChecker", 5usize, |this| ((**(this as *const *const ISpellCheckerFactory_Vtbl)).CreateSpellChecker)(this, languagetag, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            SupportedLanguages,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SupportedLanguages: 0,
+            IsSupported,
+            CreateSpellChecker,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpellCheckerFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpellingError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartIndex(this: *mut core::ffi::c_void, value: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellingError", "StartIndex", 3usize, |this| ((**(this as *const *const ISpellingError_Vtbl)).StartIndex)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, value: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellingError", "Length", 4usize, |this| ((**(this as *const *const ISpellingError_Vtbl)).Length)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CorrectiveAction(this: *mut core::ffi::c_void, value: *mut CORRECTIVE_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellingError", "CorrectiveAction", 5usize, |this| ((**(this as *const *const ISpellingError_Vtbl)).CorrectiveAction)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Replacement(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpellingError", "Replacement", 6usize, |this| ((**(this as *const *const ISpellingError_Vtbl)).Replacement)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartIndex, Length, CorrectiveAction, Replacement }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpellingError as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserDictionariesRegistrar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterUserDictionary(this: *mut core::ffi::c_void, dictionarypath: windows_core::PCWSTR, languagetag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDictionariesRegistrar", "RegisterUserDictionary", 3usize, |this| ((**(this as *const *const IUserDictionariesRegistrar_Vtbl)).RegisterUserDictionary)(this, dictionarypath, languagetag), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterUserDictionary(this: *mut core::ffi::c_void, dictionarypath: windows_core::PCWSTR, languagetag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserDictionariesRegistrar", "UnregisterUserDictionary", 4usize, |this| ((**(this as *const *const IUserDictionariesRegistrar_Vtbl)).UnregisterUserDictionary)(this, dictionarypath, languagetag), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterUserDictionary, UnregisterUserDictionary }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserDictionariesRegistrar as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -342,6 +342,7 @@ pub struct CompositionFrameInstanceKind(pub i32);
 impl windows_core::TypeKind for CompositionFrameInstanceKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositionFrameInstanceKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositionFrameInstanceKind").field(&self.0).finish()
@@ -353,6 +354,7 @@ pub struct PresentStatisticsKind(pub i32);
 impl windows_core::TypeKind for PresentStatisticsKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PresentStatisticsKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PresentStatisticsKind").field(&self.0).finish()
@@ -364,6 +366,7 @@ pub struct PresentStatus(pub i32);
 impl windows_core::TypeKind for PresentStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PresentStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PresentStatus").field(&self.0).finish()
@@ -371,7 +374,8 @@ impl core::fmt::Debug for PresentStatus {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CompositionFrameDisplayInstance {
     pub displayAdapterLUID: super::super::Foundation::LUID,
     pub displayVidPnSourceId: u32,
@@ -393,7 +397,8 @@ impl Default for CompositionFrameDisplayInstance {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PresentationTransform {
     pub M11: f32,
     pub M12: f32,
@@ -411,7 +416,8 @@ impl Default for PresentationTransform {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SystemInterruptTime {
     pub value: u64,
 }
@@ -425,3 +431,5 @@ impl Default for SystemInterruptTime {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
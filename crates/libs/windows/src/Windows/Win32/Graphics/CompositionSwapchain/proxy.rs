@@ -0,0 +1,254 @@
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ICompositionFramePresentStatistics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetContentTag(this: *mut core::ffi::c_void) -> usize {
+            windows_core::imp::proxy_call(this, "ICompositionFramePresentStatistics", "GetContentTag", 5usize, |this| ((**(this as *const *const ICompositionFramePresentStatistics_Vtbl)).GetContentTag)(this), |_| None)
+        }
+        unsafe extern "system" fn GetCompositionFrameId(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ICompositionFramePresentStatistics", "GetCompositionFrameId", 6usize, |this| ((**(this as *const *const ICompositionFramePresentStatistics_Vtbl)).GetCompositionFrameId)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDisplayInstanceArray(this: *mut core::ffi::c_void, displayinstancearraycount: *mut u32, displayinstancearray: *mut *mut CompositionFrameDisplayInstance) {
+            windows_core::imp::proxy_call(this, "ICompositionFramePresentStatistics", "GetDisplayInstanceArray", 7usize, |this| ((**(this as *const *const ICompositionFramePresentStatistics_Vtbl)).GetDisplayInstanceArray)(this, displayinstancearraycount, displayinstancearray), |_| None)
+        }
+        Self {
+            base__: <IPresentStatistics_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetContentTag,
+            GetCompositionFrameId,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDisplayInstanceArray,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDisplayInstanceArray: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionFramePresentStatistics as windows_core::Interface>::IID || <IPresentStatistics_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIndependentFlipFramePresentStatistics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOutputAdapterLUID(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::LUID) {
+            windows_core::imp::proxy_call(this, "IIndependentFlipFramePresentStatistics", "GetOutputAdapterLUID", 5usize, |this| ((**(this as *const *const IIndependentFlipFramePresentStatistics_Vtbl)).GetOutputAdapterLUID)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn GetOutputVidPnSourceId(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IIndependentFlipFramePresentStatistics", "GetOutputVidPnSourceId", 6usize, |this| ((**(this as *const *const IIndependentFlipFramePresentStatistics_Vtbl)).GetOutputVidPnSourceId)(this), |_| None)
+        }
+        unsafe extern "system" fn GetContentTag(this: *mut core::ffi::c_void) -> usize {
+            windows_core::imp::proxy_call(this, "IIndependentFlipFramePresentStatistics", "GetContentTag", 7usize, |this| ((**(this as *const *const IIndependentFlipFramePresentStatistics_Vtbl)).GetContentTag)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDisplayedTime(this: *mut core::ffi::c_void, result__: *mut SystemInterruptTime) {
+            windows_core::imp::proxy_call(this, "IIndependentFlipFramePresentStatistics", "GetDisplayedTime", 8usize, |this| ((**(this as *const *const IIndependentFlipFramePresentStatistics_Vtbl)).GetDisplayedTime)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn GetPresentDuration(this: *mut core::ffi::c_void, result__: *mut SystemInterruptTime) {
+            windows_core::imp::proxy_call(this, "IIndependentFlipFramePresentStatistics", "GetPresentDuration", 9usize, |this| ((**(this as *const *const IIndependentFlipFramePresentStatistics_Vtbl)).GetPresentDuration)(this, result__), |_| None)
+        }
+        Self {
+            base__: <IPresentStatistics_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOutputAdapterLUID,
+            GetOutputVidPnSourceId,
+            GetContentTag,
+            GetDisplayedTime,
+            GetPresentDuration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIndependentFlipFramePresentStatistics as windows_core::Interface>::IID || <IPresentStatistics_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPresentStatistics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPresentId(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "IPresentStatistics", "GetPresentId", 3usize, |this| ((**(this as *const *const IPresentStatistics_Vtbl)).GetPresentId)(this), |_| None)
+        }
+        unsafe extern "system" fn GetKind(this: *mut core::ffi::c_void) -> PresentStatisticsKind {
+            windows_core::imp::proxy_call(this, "IPresentStatistics", "GetKind", 4usize, |this| ((**(this as *const *const IPresentStatistics_Vtbl)).GetKind)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPresentId, GetKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPresentStatistics as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPresentStatusPresentStatistics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCompositionFrameId(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "IPresentStatusPresentStatistics", "GetCompositionFrameId", 5usize, |this| ((**(this as *const *const IPresentStatusPresentStatistics_Vtbl)).GetCompositionFrameId)(this), |_| None)
+        }
+        unsafe extern "system" fn GetPresentStatus(this: *mut core::ffi::c_void) -> PresentStatus {
+            windows_core::imp::proxy_call(this, "IPresentStatusPresentStatistics", "GetPresentStatus", 6usize, |this| ((**(this as *const *const IPresentStatusPresentStatistics_Vtbl)).GetPresentStatus)(this), |_| None)
+        }
+        Self { base__: <IPresentStatistics_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCompositionFrameId, GetPresentStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPresentStatusPresentStatistics as windows_core::Interface>::IID || <IPresentStatistics_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPresentationBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAvailableEvent(this: *mut core::ffi::c_void, availableeventhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationBuffer", "GetAvailableEvent", 3usize, |this| ((**(this as *const *const IPresentationBuffer_Vtbl)).GetAvailableEvent)(this, availableeventhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAvailable(this: *mut core::ffi::c_void, isavailable: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationBuffer", "IsAvailable", 4usize, |this| ((**(this as *const *const IPresentationBuffer_Vtbl)).IsAvailable)(this, isavailable), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAvailableEvent, IsAvailable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPresentationBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPresentationContent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, tag: usize) {
+            windows_core::imp::proxy_call(this, "IPresentationContent", "SetTag", 3usize, |this| ((**(this as *const *const IPresentationContent_Vtbl)).SetTag)(this, tag), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTag }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPresentationContent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPresentationFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPresentationSupported(this: *mut core::ffi::c_void) -> u8 {
+            windows_core::imp::proxy_call(this, "IPresentationFactory", "IsPresentationSupported", 3usize, |this| ((**(this as *const *const IPresentationFactory_Vtbl)).IsPresentationSupported)(this), |_| None)
+        }
+        unsafe extern "system" fn IsPresentationSupportedWithIndependentFlip(this: *mut core::ffi::c_void) -> u8 {
+            windows_core::imp::proxy_call(this, "IPresentationFactory", "IsPresentationSupportedWithIndependentFlip", 4usize, |this| ((**(this as *const *const IPresentationFactory_Vtbl)).IsPresentationSupportedWithIndependentFlip)(this), |_| None)
+        }
+        unsafe extern "system" fn CreatePresentationManager(this: *mut core::ffi::c_void, pppresentationmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationFactory", "CreatePresentationManager", 5usize, |this| ((**(this as *const *const IPresentationFactory_Vtbl)).CreatePresentationManager)(this, pppresentationmanager), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsPresentationSupported,
+            IsPresentationSupportedWithIndependentFlip,
+            CreatePresentationManager,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPresentationFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPresentationManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddBufferFromResource(this: *mut core::ffi::c_void, resource: *mut core::ffi::c_void, presentationbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationManager", "AddBufferFromResource", 3usize, |this| ((**(this as *const *const IPresentationManager_Vtbl)).AddBufferFromResource)(this, resource, presentationbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePresentationSurface(this: *mut core::ffi::c_void, compositionsurfacehandle: super::super::Foundation::HANDLE, presentationsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationManager", "CreatePresentationSurface", 4usize, |this| ((**(this as *const *const IPresentationManager_Vtbl)).CreatePresentationSurface)(this, compositionsurfacehandle, presentationsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextPresentId(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "IPresentationManager", "GetNextPresentId", 5usize, |this| ((**(this as *const *const IPresentationManager_Vtbl)).GetNextPresentId)(this), |_| None)
+        }
+        unsafe extern "system" fn SetTargetTime(this: *mut core::ffi::c_void, targettime: SystemInterruptTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationManager", "SetTargetTime", 6usize, |this| ((**(this as *const *const IPresentationManager_Vtbl)).SetTargetTime)(this, targettime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredPresentDuration(this: *mut core::ffi::c_void, preferredduration: SystemInterruptTime, deviationtolerance: SystemInterruptTime) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationManager", "SetPreferredPresentDuration", 7usize, |this| ((**(this as *const *const IPresentationManager_Vtbl)).SetPreferredPresentDuration)(this, preferredduration, deviationtolerance), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceVSyncInterrupt(this: *mut core::ffi::c_void, forcevsyncinterrupt: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationManager", "ForceVSyncInterrupt", 8usize, |this| ((**(this as *const *const IPresentationManager_Vtbl)).ForceVSyncInterrupt)(this, forcevsyncinterrupt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Present(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationManager", "Present", 9usize, |this| ((**(this as *const *const IPresentationManager_Vtbl)).Present)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresentRetiringFence(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, fence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationManager", "GetPresentRetiringFence", 10usize, |this| ((**(this as *const *const IPresentationManager_Vtbl)).GetPresentRetiringFence)(this, riid, fence), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelPresentsFrom(this: *mut core::ffi::c_void, presentidtocancelfrom: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationManager", "CancelPresentsFrom", 11usize, |this| ((**(this as *const *const IPresentationManager_Vtbl)).CancelPresentsFrom)(this, presentidtocancelfrom), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLostEvent(this: *mut core::ffi::c_void, losteventhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationManager", "GetLostEvent", 12usize, |this| ((**(this as *const *const IPresentationManager_Vtbl)).GetLostEvent)(this, losteventhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresentStatisticsAvailableEvent(this: *mut core::ffi::c_void, presentstatisticsavailableeventhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationManager", "GetPresentStatisticsAvailableEvent", 13usize, |this| ((**(this as *const *const IPresentationManager_Vtbl)).GetPresentStatisticsAvailableEvent)(this, presentstatisticsavailableeventhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnablePresentStatisticsKind(this: *mut core::ffi::c_void, presentstatisticskind: PresentStatisticsKind, enabled: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationManager", "EnablePresentStatisticsKind", 14usize, |this| ((**(this as *const *const IPresentationManager_Vtbl)).EnablePresentStatisticsKind)(this, presentstatisticskind, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextPresentStatistics(this: *mut core::ffi::c_void, nextpresentstatistics: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationManager", "GetNextPresentStatistics", 15usize, |this| ((**(this as *const *const IPresentationManager_Vtbl)).GetNextPresentStatistics)(this, nextpresentstatistics), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddBufferFromResource,
+            CreatePresentationSurface,
+            GetNextPresentId,
+            SetTargetTime,
+            SetPreferredPresentDuration,
+            ForceVSyncInterrupt,
+            Present,
+            GetPresentRetiringFence,
+            CancelPresentsFrom,
+            GetLostEvent,
+            GetPresentStatisticsAvailableEvent,
+            EnablePresentStatisticsKind,
+            GetNextPresentStatistics,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPresentationManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IPresentationSurface_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBuffer(this: *mut core::ffi::c_void, presentationbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationSurface", "SetBuffer", 4usize, |this| ((**(this as *const *const IPresentationSurface_Vtbl)).SetBuffer)(this, presentationbuffer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn SetColorSpace(this: *mut core::ffi::c_void, colorspace: super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationSurface", "SetColorSpace", 5usize, |this| ((**(this as *const *const IPresentationSurface_Vtbl)).SetColorSpace)(this, colorspace), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn SetAlphaMode(this: *mut core::ffi::c_void, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationSurface", "SetAlphaMode", 6usize, |this| ((**(this as *const *const IPresentationSurface_Vtbl)).SetAlphaMode)(this, alphamode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceRect(this: *mut core::ffi::c_void, sourcerect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationSurface", "SetSourceRect", 7usize, |this| ((**(this as *const *const IPresentationSurface_Vtbl)).SetSourceRect)(this, sourcerect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransform(this: *mut core::ffi::c_void, transform: *const PresentationTransform) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationSurface", "SetTransform", 8usize, |this| ((**(this as *const *const IPresentationSurface_Vtbl)).SetTransform)(this, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestrictToOutput(this: *mut core::ffi::c_void, output: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationSurface", "RestrictToOutput", 9usize, |this| ((**(this as *const *const IPresentationSurface_Vtbl)).RestrictToOutput)(this, output), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisableReadback(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationSurface", "SetDisableReadback", 10usize, |this| ((**(this as *const *const IPresentationSurface_Vtbl)).SetDisableReadback)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLetterboxingMargins(this: *mut core::ffi::c_void, leftletterboxsize: f32, topletterboxsize: f32, rightletterboxsize: f32, bottomletterboxsize: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPresentationSurface", "SetLetterboxingMargins", 11usize, |this| ((**(this as *const *const IPresentationSurface_Vtbl)).SetLetterboxingMargins)(this, leftletterboxsize, topletterboxsize, rightletterboxsize, bottomletterboxsize), |result| Some(*result))
+        }
+        Self {
+            base__: <IPresentationContent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetBuffer,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            SetColorSpace,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            SetColorSpace: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            SetAlphaMode,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            SetAlphaMode: 0,
+            SetSourceRect,
+            SetTransform,
+            RestrictToOutput,
+            SetDisableReadback,
+            SetLetterboxingMargins,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPresentationSurface as windows_core::Interface>::IID || <IPresentationContent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,117 @@
+impl windows_core::imp::ProxyVtable for IDXCoreAdapter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsValid(this: *mut core::ffi::c_void) -> bool {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapter", "IsValid", 3usize, |this| ((**(this as *const *const IDXCoreAdapter_Vtbl)).IsValid)(this), |_| None)
+        }
+        unsafe extern "system" fn IsAttributeSupported(this: *mut core::ffi::c_void, attributeguid: *const windows_core::GUID) -> bool {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapter", "IsAttributeSupported", 4usize, |this| ((**(this as *const *const IDXCoreAdapter_Vtbl)).IsAttributeSupported)(this, attributeguid), |_| None)
+        }
+        unsafe extern "system" fn IsPropertySupported(this: *mut core::ffi::c_void, property: DXCoreAdapterProperty) -> bool {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapter", "IsPropertySupported", 5usize, |this| ((**(this as *const *const IDXCoreAdapter_Vtbl)).IsPropertySupported)(this, property), |_| None)
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, property: DXCoreAdapterProperty, buffersize: usize, propertydata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapter", "GetProperty", 6usize, |this| ((**(this as *const *const IDXCoreAdapter_Vtbl)).GetProperty)(this, property, buffersize, propertydata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertySize(this: *mut core::ffi::c_void, property: DXCoreAdapterProperty, buffersize: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapter", "GetPropertySize", 7usize, |this| ((**(this as *const *const IDXCoreAdapter_Vtbl)).GetPropertySize)(this, property, buffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsQueryStateSupported(this: *mut core::ffi::c_void, property: DXCoreAdapterState) -> bool {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapter", "IsQueryStateSupported", 8usize, |this| ((**(this as *const *const IDXCoreAdapter_Vtbl)).IsQueryStateSupported)(this, property), |_| None)
+        }
+        unsafe extern "system" fn QueryState(this: *mut core::ffi::c_void, state: DXCoreAdapterState, inputstatedetailssize: usize, inputstatedetails: *const core::ffi::c_void, outputbuffersize: usize, outputbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapter", "QueryState", 9usize, |this| ((**(this as *const *const IDXCoreAdapter_Vtbl)).QueryState)(this, state, inputstatedetailssize, inputstatedetails, outputbuffersize, outputbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSetStateSupported(this: *mut core::ffi::c_void, property: DXCoreAdapterState) -> bool {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapter", "IsSetStateSupported", 10usize, |this| ((**(this as *const *const IDXCoreAdapter_Vtbl)).IsSetStateSupported)(this, property), |_| None)
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, state: DXCoreAdapterState, inputstatedetailssize: usize, inputstatedetails: *const core::ffi::c_void, inputdatasize: usize, inputdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapter", "SetState", 11usize, |this| ((**(this as *const *const IDXCoreAdapter_Vtbl)).SetState)(this, state, inputstatedetailssize, inputstatedetails, inputdatasize, inputdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFactory(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvfactory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapter", "GetFactory", 12usize, |this| ((**(this as *const *const IDXCoreAdapter_Vtbl)).GetFactory)(this, riid, ppvfactory), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsValid,
+            IsAttributeSupported,
+            IsPropertySupported,
+            GetProperty,
+            GetPropertySize,
+            IsQueryStateSupported,
+            QueryState,
+            IsSetStateSupported,
+            SetState,
+            GetFactory,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXCoreAdapter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXCoreAdapterFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAdapterList(this: *mut core::ffi::c_void, numattributes: u32, filterattributes: *const windows_core::GUID, riid: *const windows_core::GUID, ppvadapterlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapterFactory", "CreateAdapterList", 3usize, |this| ((**(this as *const *const IDXCoreAdapterFactory_Vtbl)).CreateAdapterList)(this, numattributes, filterattributes, riid, ppvadapterlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAdapterByLuid(this: *mut core::ffi::c_void, adapterluid: *const super::super::Foundation::LUID, riid: *const windows_core::GUID, ppvadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapterFactory", "GetAdapterByLuid", 4usize, |this| ((**(this as *const *const IDXCoreAdapterFactory_Vtbl)).GetAdapterByLuid)(this, adapterluid, riid, ppvadapter), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNotificationTypeSupported(this: *mut core::ffi::c_void, notificationtype: DXCoreNotificationType) -> bool {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapterFactory", "IsNotificationTypeSupported", 5usize, |this| ((**(this as *const *const IDXCoreAdapterFactory_Vtbl)).IsNotificationTypeSupported)(this, notificationtype), |_| None)
+        }
+        unsafe extern "system" fn RegisterEventNotification(this: *mut core::ffi::c_void, dxcoreobject: *mut core::ffi::c_void, notificationtype: DXCoreNotificationType, callbackfunction: PFN_DXCORE_NOTIFICATION_CALLBACK, callbackcontext: *const core::ffi::c_void, eventcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapterFactory", "RegisterEventNotification", 6usize, |this| ((**(this as *const *const IDXCoreAdapterFactory_Vtbl)).RegisterEventNotification)(this, dxcoreobject, notificationtype, callbackfunction, callbackcontext, eventcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterEventNotification(this: *mut core::ffi::c_void, eventcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapterFactory", "UnregisterEventNotification", 7usize, |this| ((**(this as *const *const IDXCoreAdapterFactory_Vtbl)).UnregisterEventNotification)(this, eventcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateAdapterList,
+            GetAdapterByLuid,
+            IsNotificationTypeSupported,
+            RegisterEventNotification,
+            UnregisterEventNotification,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXCoreAdapterFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXCoreAdapterList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAdapter(this: *mut core::ffi::c_void, index: u32, riid: *const windows_core::GUID, ppvadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapterList", "GetAdapter", 3usize, |this| ((**(this as *const *const IDXCoreAdapterList_Vtbl)).GetAdapter)(this, index, riid, ppvadapter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAdapterCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapterList", "GetAdapterCount", 4usize, |this| ((**(this as *const *const IDXCoreAdapterList_Vtbl)).GetAdapterCount)(this), |_| None)
+        }
+        unsafe extern "system" fn IsStale(this: *mut core::ffi::c_void) -> bool {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapterList", "IsStale", 5usize, |this| ((**(this as *const *const IDXCoreAdapterList_Vtbl)).IsStale)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFactory(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvfactory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapterList", "GetFactory", 6usize, |this| ((**(this as *const *const IDXCoreAdapterList_Vtbl)).GetFactory)(this, riid, ppvfactory), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sort(this: *mut core::ffi::c_void, numpreferences: u32, preferences: *const DXCoreAdapterPreference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapterList", "Sort", 7usize, |this| ((**(this as *const *const IDXCoreAdapterList_Vtbl)).Sort)(this, numpreferences, preferences), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAdapterPreferenceSupported(this: *mut core::ffi::c_void, preference: DXCoreAdapterPreference) -> bool {
+            windows_core::imp::proxy_call(this, "IDXCoreAdapterList", "IsAdapterPreferenceSupported", 8usize, |this| ((**(this as *const *const IDXCoreAdapterList_Vtbl)).IsAdapterPreferenceSupported)(this, preference), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAdapter,
+            GetAdapterCount,
+            IsStale,
+            GetFactory,
+            Sort,
+            IsAdapterPreferenceSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXCoreAdapterList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -112,6 +112,7 @@ pub struct D2D1_2DAFFINETRANSFORM_INTERPOLATION_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_2DAFFINETRANSFORM_INTERPOLATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_2DAFFINETRANSFORM_INTERPOLATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_2DAFFINETRANSFORM_INTERPOLATION_MODE").field(&self.0).finish()
@@ -123,6 +124,7 @@ pub struct D2D1_ALPHA_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_ALPHA_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_ALPHA_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_ALPHA_MODE").field(&self.0).finish()
@@ -134,6 +136,7 @@ pub struct D2D1_BLEND_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_BLEND_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_BLEND_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_BLEND_MODE").field(&self.0).finish()
@@ -145,6 +148,7 @@ pub struct D2D1_BORDER_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_BORDER_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_BORDER_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_BORDER_MODE").field(&self.0).finish()
@@ -156,6 +160,7 @@ pub struct D2D1_COLORMATRIX_ALPHA_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_COLORMATRIX_ALPHA_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_COLORMATRIX_ALPHA_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_COLORMATRIX_ALPHA_MODE").field(&self.0).finish()
@@ -167,6 +172,7 @@ pub struct D2D1_COMPOSITE_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_COMPOSITE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_COMPOSITE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_COMPOSITE_MODE").field(&self.0).finish()
@@ -178,6 +184,7 @@ pub struct D2D1_FIGURE_BEGIN(pub i32);
 impl windows_core::TypeKind for D2D1_FIGURE_BEGIN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_FIGURE_BEGIN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_FIGURE_BEGIN").field(&self.0).finish()
@@ -189,6 +196,7 @@ pub struct D2D1_FIGURE_END(pub i32);
 impl windows_core::TypeKind for D2D1_FIGURE_END {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_FIGURE_END {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_FIGURE_END").field(&self.0).finish()
@@ -200,6 +208,7 @@ pub struct D2D1_FILL_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_FILL_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_FILL_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_FILL_MODE").field(&self.0).finish()
@@ -211,6 +220,7 @@ pub struct D2D1_PATH_SEGMENT(pub i32);
 impl windows_core::TypeKind for D2D1_PATH_SEGMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_PATH_SEGMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_PATH_SEGMENT").field(&self.0).finish()
@@ -255,13 +265,15 @@ pub struct D2D1_TURBULENCE_NOISE(pub i32);
 impl windows_core::TypeKind for D2D1_TURBULENCE_NOISE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_TURBULENCE_NOISE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_TURBULENCE_NOISE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_BEZIER_SEGMENT {
     pub point1: D2D_POINT_2F,
     pub point2: D2D_POINT_2F,
@@ -276,7 +288,8 @@ impl Default for D2D1_BEZIER_SEGMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_COLOR_F {
     pub r: f32,
     pub g: f32,
@@ -292,7 +305,8 @@ impl Default for D2D1_COLOR_F {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_GRADIENT_STOP {
     pub position: f32,
     pub color: D2D1_COLOR_F,
@@ -307,7 +321,8 @@ impl Default for D2D1_GRADIENT_STOP {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_PIXEL_FORMAT {
     pub format: super::super::Dxgi::Common::DXGI_FORMAT,
     pub alphaMode: D2D1_ALPHA_MODE,
@@ -323,7 +338,8 @@ impl Default for D2D1_PIXEL_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D_COLOR_F {
     pub r: f32,
     pub g: f32,
@@ -366,7 +382,8 @@ impl Default for D2D_MATRIX_4X3_F_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D_MATRIX_4X3_F_0_0 {
     pub _11: f32,
     pub _12: f32,
@@ -417,7 +434,8 @@ impl Default for D2D_MATRIX_4X4_F_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D_MATRIX_4X4_F_0_0 {
     pub _11: f32,
     pub _12: f32,
@@ -472,7 +490,8 @@ impl Default for D2D_MATRIX_5X4_F_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D_MATRIX_5X4_F_0_0 {
     pub _11: f32,
     pub _12: f32,
@@ -504,7 +523,8 @@ impl Default for D2D_MATRIX_5X4_F_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D_POINT_2F {
     pub x: f32,
     pub y: f32,
@@ -518,7 +538,8 @@ impl Default for D2D_POINT_2F {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D_POINT_2U {
     pub x: u32,
     pub y: u32,
@@ -532,7 +553,8 @@ impl Default for D2D_POINT_2U {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D_RECT_F {
     pub left: f32,
     pub top: f32,
@@ -548,7 +570,8 @@ impl Default for D2D_RECT_F {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D_RECT_U {
     pub left: u32,
     pub top: u32,
@@ -564,7 +587,8 @@ impl Default for D2D_RECT_U {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D_SIZE_F {
     pub width: f32,
     pub height: f32,
@@ -578,7 +602,8 @@ impl Default for D2D_SIZE_F {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D_SIZE_U {
     pub width: u32,
     pub height: u32,
@@ -592,7 +617,8 @@ impl Default for D2D_SIZE_U {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D_VECTOR_2F {
     pub x: f32,
     pub y: f32,
@@ -606,7 +632,8 @@ impl Default for D2D_VECTOR_2F {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D_VECTOR_3F {
     pub x: f32,
     pub y: f32,
@@ -621,7 +648,8 @@ impl Default for D2D_VECTOR_3F {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D_VECTOR_4F {
     pub x: f32,
     pub y: f32,
@@ -638,3 +666,5 @@ impl Default for D2D_VECTOR_4F {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,39 @@
+impl windows_core::imp::ProxyVtable for ID2D1SimplifiedGeometrySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFillMode(this: *mut core::ffi::c_void, fillmode: D2D1_FILL_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1SimplifiedGeometrySink", "SetFillMode", 3usize, |this| ((**(this as *const *const ID2D1SimplifiedGeometrySink_Vtbl)).SetFillMode)(this, fillmode), |_| None)
+        }
+        unsafe extern "system" fn SetSegmentFlags(this: *mut core::ffi::c_void, vertexflags: D2D1_PATH_SEGMENT) {
+            windows_core::imp::proxy_call(this, "ID2D1SimplifiedGeometrySink", "SetSegmentFlags", 4usize, |this| ((**(this as *const *const ID2D1SimplifiedGeometrySink_Vtbl)).SetSegmentFlags)(this, vertexflags), |_| None)
+        }
+        unsafe extern "system" fn BeginFigure(this: *mut core::ffi::c_void, startpoint: D2D_POINT_2F, figurebegin: D2D1_FIGURE_BEGIN) {
+            windows_core::imp::proxy_call(this, "ID2D1SimplifiedGeometrySink", "BeginFigure", 5usize, |this| ((**(this as *const *const ID2D1SimplifiedGeometrySink_Vtbl)).BeginFigure)(this, startpoint, figurebegin), |_| None)
+        }
+        unsafe extern "system" fn AddLines(this: *mut core::ffi::c_void, points: *const D2D_POINT_2F, pointscount: u32) {
+            windows_core::imp::proxy_call(this, "ID2D1SimplifiedGeometrySink", "AddLines", 6usize, |this| ((**(this as *const *const ID2D1SimplifiedGeometrySink_Vtbl)).AddLines)(this, points, pointscount), |_| None)
+        }
+        unsafe extern "system" fn AddBeziers(this: *mut core::ffi::c_void, beziers: *const D2D1_BEZIER_SEGMENT, bezierscount: u32) {
+            windows_core::imp::proxy_call(this, "ID2D1SimplifiedGeometrySink", "AddBeziers", 7usize, |this| ((**(this as *const *const ID2D1SimplifiedGeometrySink_Vtbl)).AddBeziers)(this, beziers, bezierscount), |_| None)
+        }
+        unsafe extern "system" fn EndFigure(this: *mut core::ffi::c_void, figureend: D2D1_FIGURE_END) {
+            windows_core::imp::proxy_call(this, "ID2D1SimplifiedGeometrySink", "EndFigure", 8usize, |this| ((**(this as *const *const ID2D1SimplifiedGeometrySink_Vtbl)).EndFigure)(this, figureend), |_| None)
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SimplifiedGeometrySink", "Close", 9usize, |this| ((**(this as *const *const ID2D1SimplifiedGeometrySink_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetFillMode,
+            SetSegmentFlags,
+            BeginFigure,
+            AddLines,
+            AddBeziers,
+            EndFigure,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1SimplifiedGeometrySink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -6373,6 +6373,7 @@ pub struct D2D1_2DAFFINETRANSFORM_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_2DAFFINETRANSFORM_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_2DAFFINETRANSFORM_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_2DAFFINETRANSFORM_PROP").field(&self.0).finish()
@@ -6384,6 +6385,7 @@ pub struct D2D1_3DPERSPECTIVETRANSFORM_INTERPOLATION_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_3DPERSPECTIVETRANSFORM_INTERPOLATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_3DPERSPECTIVETRANSFORM_INTERPOLATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_3DPERSPECTIVETRANSFORM_INTERPOLATION_MODE").field(&self.0).finish()
@@ -6395,6 +6397,7 @@ pub struct D2D1_3DPERSPECTIVETRANSFORM_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_3DPERSPECTIVETRANSFORM_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_3DPERSPECTIVETRANSFORM_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_3DPERSPECTIVETRANSFORM_PROP").field(&self.0).finish()
@@ -6406,6 +6409,7 @@ pub struct D2D1_3DTRANSFORM_INTERPOLATION_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_3DTRANSFORM_INTERPOLATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_3DTRANSFORM_INTERPOLATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_3DTRANSFORM_INTERPOLATION_MODE").field(&self.0).finish()
@@ -6417,6 +6421,7 @@ pub struct D2D1_3DTRANSFORM_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_3DTRANSFORM_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_3DTRANSFORM_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_3DTRANSFORM_PROP").field(&self.0).finish()
@@ -6428,6 +6433,7 @@ pub struct D2D1_ANTIALIAS_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_ANTIALIAS_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_ANTIALIAS_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_ANTIALIAS_MODE").field(&self.0).finish()
@@ -6439,6 +6445,7 @@ pub struct D2D1_ARC_SIZE(pub i32);
 impl windows_core::TypeKind for D2D1_ARC_SIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_ARC_SIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_ARC_SIZE").field(&self.0).finish()
@@ -6450,6 +6457,7 @@ pub struct D2D1_ARITHMETICCOMPOSITE_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_ARITHMETICCOMPOSITE_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_ARITHMETICCOMPOSITE_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_ARITHMETICCOMPOSITE_PROP").field(&self.0).finish()
@@ -6461,6 +6469,7 @@ pub struct D2D1_ATLAS_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_ATLAS_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_ATLAS_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_ATLAS_PROP").field(&self.0).finish()
@@ -6472,6 +6481,7 @@ pub struct D2D1_BITMAPSOURCE_ALPHA_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_BITMAPSOURCE_ALPHA_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_BITMAPSOURCE_ALPHA_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_BITMAPSOURCE_ALPHA_MODE").field(&self.0).finish()
@@ -6483,6 +6493,7 @@ pub struct D2D1_BITMAPSOURCE_INTERPOLATION_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_BITMAPSOURCE_INTERPOLATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_BITMAPSOURCE_INTERPOLATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_BITMAPSOURCE_INTERPOLATION_MODE").field(&self.0).finish()
@@ -6494,6 +6505,7 @@ pub struct D2D1_BITMAPSOURCE_ORIENTATION(pub i32);
 impl windows_core::TypeKind for D2D1_BITMAPSOURCE_ORIENTATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_BITMAPSOURCE_ORIENTATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_BITMAPSOURCE_ORIENTATION").field(&self.0).finish()
@@ -6505,6 +6517,7 @@ pub struct D2D1_BITMAPSOURCE_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_BITMAPSOURCE_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_BITMAPSOURCE_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_BITMAPSOURCE_PROP").field(&self.0).finish()
@@ -6516,6 +6529,7 @@ pub struct D2D1_BITMAP_INTERPOLATION_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_BITMAP_INTERPOLATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_BITMAP_INTERPOLATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_BITMAP_INTERPOLATION_MODE").field(&self.0).finish()
@@ -6527,6 +6541,7 @@ pub struct D2D1_BITMAP_OPTIONS(pub i32);
 impl windows_core::TypeKind for D2D1_BITMAP_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_BITMAP_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_BITMAP_OPTIONS").field(&self.0).finish()
@@ -6571,6 +6586,7 @@ pub struct D2D1_BLEND(pub i32);
 impl windows_core::TypeKind for D2D1_BLEND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_BLEND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_BLEND").field(&self.0).finish()
@@ -6582,6 +6598,7 @@ pub struct D2D1_BLEND_OPERATION(pub i32);
 impl windows_core::TypeKind for D2D1_BLEND_OPERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_BLEND_OPERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_BLEND_OPERATION").field(&self.0).finish()
@@ -6593,6 +6610,7 @@ pub struct D2D1_BLEND_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_BLEND_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_BLEND_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_BLEND_PROP").field(&self.0).finish()
@@ -6604,6 +6622,7 @@ pub struct D2D1_BORDER_EDGE_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_BORDER_EDGE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_BORDER_EDGE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_BORDER_EDGE_MODE").field(&self.0).finish()
@@ -6615,6 +6634,7 @@ pub struct D2D1_BORDER_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_BORDER_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_BORDER_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_BORDER_PROP").field(&self.0).finish()
@@ -6626,6 +6646,7 @@ pub struct D2D1_BRIGHTNESS_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_BRIGHTNESS_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_BRIGHTNESS_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_BRIGHTNESS_PROP").field(&self.0).finish()
@@ -6637,6 +6658,7 @@ pub struct D2D1_BUFFER_PRECISION(pub i32);
 impl windows_core::TypeKind for D2D1_BUFFER_PRECISION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_BUFFER_PRECISION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_BUFFER_PRECISION").field(&self.0).finish()
@@ -6648,6 +6670,7 @@ pub struct D2D1_CAP_STYLE(pub i32);
 impl windows_core::TypeKind for D2D1_CAP_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_CAP_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_CAP_STYLE").field(&self.0).finish()
@@ -6659,6 +6682,7 @@ pub struct D2D1_CHANGE_TYPE(pub i32);
 impl windows_core::TypeKind for D2D1_CHANGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_CHANGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_CHANGE_TYPE").field(&self.0).finish()
@@ -6703,6 +6727,7 @@ pub struct D2D1_CHANNEL_DEPTH(pub i32);
 impl windows_core::TypeKind for D2D1_CHANNEL_DEPTH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_CHANNEL_DEPTH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_CHANNEL_DEPTH").field(&self.0).finish()
@@ -6714,6 +6739,7 @@ pub struct D2D1_CHANNEL_SELECTOR(pub i32);
 impl windows_core::TypeKind for D2D1_CHANNEL_SELECTOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_CHANNEL_SELECTOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_CHANNEL_SELECTOR").field(&self.0).finish()
@@ -6725,6 +6751,7 @@ pub struct D2D1_CHROMAKEY_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_CHROMAKEY_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_CHROMAKEY_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_CHROMAKEY_PROP").field(&self.0).finish()
@@ -6736,6 +6763,7 @@ pub struct D2D1_COLORMANAGEMENT_ALPHA_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_COLORMANAGEMENT_ALPHA_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_COLORMANAGEMENT_ALPHA_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_COLORMANAGEMENT_ALPHA_MODE").field(&self.0).finish()
@@ -6747,6 +6775,7 @@ pub struct D2D1_COLORMANAGEMENT_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_COLORMANAGEMENT_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_COLORMANAGEMENT_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_COLORMANAGEMENT_PROP").field(&self.0).finish()
@@ -6758,6 +6787,7 @@ pub struct D2D1_COLORMANAGEMENT_QUALITY(pub i32);
 impl windows_core::TypeKind for D2D1_COLORMANAGEMENT_QUALITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_COLORMANAGEMENT_QUALITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_COLORMANAGEMENT_QUALITY").field(&self.0).finish()
@@ -6769,6 +6799,7 @@ pub struct D2D1_COLORMANAGEMENT_RENDERING_INTENT(pub i32);
 impl windows_core::TypeKind for D2D1_COLORMANAGEMENT_RENDERING_INTENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_COLORMANAGEMENT_RENDERING_INTENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_COLORMANAGEMENT_RENDERING_INTENT").field(&self.0).finish()
@@ -6780,6 +6811,7 @@ pub struct D2D1_COLORMATRIX_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_COLORMATRIX_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_COLORMATRIX_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_COLORMATRIX_PROP").field(&self.0).finish()
@@ -6791,6 +6823,7 @@ pub struct D2D1_COLOR_BITMAP_GLYPH_SNAP_OPTION(pub i32);
 impl windows_core::TypeKind for D2D1_COLOR_BITMAP_GLYPH_SNAP_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_COLOR_BITMAP_GLYPH_SNAP_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_COLOR_BITMAP_GLYPH_SNAP_OPTION").field(&self.0).finish()
@@ -6802,6 +6835,7 @@ pub struct D2D1_COLOR_CONTEXT_TYPE(pub i32);
 impl windows_core::TypeKind for D2D1_COLOR_CONTEXT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_COLOR_CONTEXT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_COLOR_CONTEXT_TYPE").field(&self.0).finish()
@@ -6813,6 +6847,7 @@ pub struct D2D1_COLOR_INTERPOLATION_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_COLOR_INTERPOLATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_COLOR_INTERPOLATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_COLOR_INTERPOLATION_MODE").field(&self.0).finish()
@@ -6824,6 +6859,7 @@ pub struct D2D1_COLOR_SPACE(pub i32);
 impl windows_core::TypeKind for D2D1_COLOR_SPACE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_COLOR_SPACE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_COLOR_SPACE").field(&self.0).finish()
@@ -6835,6 +6871,7 @@ pub struct D2D1_COMBINE_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_COMBINE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_COMBINE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_COMBINE_MODE").field(&self.0).finish()
@@ -6846,6 +6883,7 @@ pub struct D2D1_COMPATIBLE_RENDER_TARGET_OPTIONS(pub i32);
 impl windows_core::TypeKind for D2D1_COMPATIBLE_RENDER_TARGET_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_COMPATIBLE_RENDER_TARGET_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_COMPATIBLE_RENDER_TARGET_OPTIONS").field(&self.0).finish()
@@ -6890,6 +6928,7 @@ pub struct D2D1_COMPOSITE_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_COMPOSITE_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_COMPOSITE_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_COMPOSITE_PROP").field(&self.0).finish()
@@ -6901,6 +6940,7 @@ pub struct D2D1_CONTRAST_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_CONTRAST_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_CONTRAST_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_CONTRAST_PROP").field(&self.0).finish()
@@ -6912,6 +6952,7 @@ pub struct D2D1_CONVOLVEMATRIX_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_CONVOLVEMATRIX_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_CONVOLVEMATRIX_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_CONVOLVEMATRIX_PROP").field(&self.0).finish()
@@ -6923,6 +6964,7 @@ pub struct D2D1_CONVOLVEMATRIX_SCALE_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_CONVOLVEMATRIX_SCALE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_CONVOLVEMATRIX_SCALE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_CONVOLVEMATRIX_SCALE_MODE").field(&self.0).finish()
@@ -6934,6 +6976,7 @@ pub struct D2D1_CROP_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_CROP_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_CROP_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_CROP_PROP").field(&self.0).finish()
@@ -6945,6 +6988,7 @@ pub struct D2D1_CROSSFADE_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_CROSSFADE_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_CROSSFADE_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_CROSSFADE_PROP").field(&self.0).finish()
@@ -6956,6 +7000,7 @@ pub struct D2D1_DASH_STYLE(pub i32);
 impl windows_core::TypeKind for D2D1_DASH_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_DASH_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_DASH_STYLE").field(&self.0).finish()
@@ -6967,6 +7012,7 @@ pub struct D2D1_DC_INITIALIZE_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_DC_INITIALIZE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_DC_INITIALIZE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_DC_INITIALIZE_MODE").field(&self.0).finish()
@@ -6978,6 +7024,7 @@ pub struct D2D1_DEBUG_LEVEL(pub i32);
 impl windows_core::TypeKind for D2D1_DEBUG_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_DEBUG_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_DEBUG_LEVEL").field(&self.0).finish()
@@ -6989,6 +7036,7 @@ pub struct D2D1_DEVICE_CONTEXT_OPTIONS(pub i32);
 impl windows_core::TypeKind for D2D1_DEVICE_CONTEXT_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_DEVICE_CONTEXT_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_DEVICE_CONTEXT_OPTIONS").field(&self.0).finish()
@@ -7033,6 +7081,7 @@ pub struct D2D1_DIRECTIONALBLUR_OPTIMIZATION(pub i32);
 impl windows_core::TypeKind for D2D1_DIRECTIONALBLUR_OPTIMIZATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_DIRECTIONALBLUR_OPTIMIZATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_DIRECTIONALBLUR_OPTIMIZATION").field(&self.0).finish()
@@ -7044,6 +7093,7 @@ pub struct D2D1_DIRECTIONALBLUR_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_DIRECTIONALBLUR_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_DIRECTIONALBLUR_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_DIRECTIONALBLUR_PROP").field(&self.0).finish()
@@ -7055,6 +7105,7 @@ pub struct D2D1_DISCRETETRANSFER_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_DISCRETETRANSFER_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_DISCRETETRANSFER_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_DISCRETETRANSFER_PROP").field(&self.0).finish()
@@ -7066,6 +7117,7 @@ pub struct D2D1_DISPLACEMENTMAP_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_DISPLACEMENTMAP_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_DISPLACEMENTMAP_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_DISPLACEMENTMAP_PROP").field(&self.0).finish()
@@ -7077,6 +7129,7 @@ pub struct D2D1_DISTANTDIFFUSE_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_DISTANTDIFFUSE_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_DISTANTDIFFUSE_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_DISTANTDIFFUSE_PROP").field(&self.0).finish()
@@ -7088,6 +7141,7 @@ pub struct D2D1_DISTANTDIFFUSE_SCALE_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_DISTANTDIFFUSE_SCALE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_DISTANTDIFFUSE_SCALE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_DISTANTDIFFUSE_SCALE_MODE").field(&self.0).finish()
@@ -7099,6 +7153,7 @@ pub struct D2D1_DISTANTSPECULAR_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_DISTANTSPECULAR_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_DISTANTSPECULAR_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_DISTANTSPECULAR_PROP").field(&self.0).finish()
@@ -7110,6 +7165,7 @@ pub struct D2D1_DISTANTSPECULAR_SCALE_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_DISTANTSPECULAR_SCALE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_DISTANTSPECULAR_SCALE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_DISTANTSPECULAR_SCALE_MODE").field(&self.0).finish()
@@ -7121,6 +7177,7 @@ pub struct D2D1_DPICOMPENSATION_INTERPOLATION_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_DPICOMPENSATION_INTERPOLATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_DPICOMPENSATION_INTERPOLATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_DPICOMPENSATION_INTERPOLATION_MODE").field(&self.0).finish()
@@ -7132,6 +7189,7 @@ pub struct D2D1_DPICOMPENSATION_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_DPICOMPENSATION_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_DPICOMPENSATION_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_DPICOMPENSATION_PROP").field(&self.0).finish()
@@ -7143,6 +7201,7 @@ pub struct D2D1_DRAW_TEXT_OPTIONS(pub i32);
 impl windows_core::TypeKind for D2D1_DRAW_TEXT_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_DRAW_TEXT_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_DRAW_TEXT_OPTIONS").field(&self.0).finish()
@@ -7187,6 +7246,7 @@ pub struct D2D1_EDGEDETECTION_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_EDGEDETECTION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_EDGEDETECTION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_EDGEDETECTION_MODE").field(&self.0).finish()
@@ -7198,6 +7258,7 @@ pub struct D2D1_EDGEDETECTION_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_EDGEDETECTION_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_EDGEDETECTION_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_EDGEDETECTION_PROP").field(&self.0).finish()
@@ -7209,6 +7270,7 @@ pub struct D2D1_EMBOSS_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_EMBOSS_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_EMBOSS_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_EMBOSS_PROP").field(&self.0).finish()
@@ -7220,6 +7282,7 @@ pub struct D2D1_EXPOSURE_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_EXPOSURE_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_EXPOSURE_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_EXPOSURE_PROP").field(&self.0).finish()
@@ -7231,6 +7294,7 @@ pub struct D2D1_EXTEND_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_EXTEND_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_EXTEND_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_EXTEND_MODE").field(&self.0).finish()
@@ -7242,6 +7306,7 @@ pub struct D2D1_FACTORY_TYPE(pub i32);
 impl windows_core::TypeKind for D2D1_FACTORY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_FACTORY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_FACTORY_TYPE").field(&self.0).finish()
@@ -7253,6 +7318,7 @@ pub struct D2D1_FEATURE(pub i32);
 impl windows_core::TypeKind for D2D1_FEATURE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_FEATURE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_FEATURE").field(&self.0).finish()
@@ -7264,6 +7330,7 @@ pub struct D2D1_FEATURE_LEVEL(pub i32);
 impl windows_core::TypeKind for D2D1_FEATURE_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_FEATURE_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_FEATURE_LEVEL").field(&self.0).finish()
@@ -7275,6 +7342,7 @@ pub struct D2D1_FILTER(pub i32);
 impl windows_core::TypeKind for D2D1_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_FILTER").field(&self.0).finish()
@@ -7286,6 +7354,7 @@ pub struct D2D1_FLOOD_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_FLOOD_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_FLOOD_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_FLOOD_PROP").field(&self.0).finish()
@@ -7297,6 +7366,7 @@ pub struct D2D1_GAMMA(pub i32);
 impl windows_core::TypeKind for D2D1_GAMMA {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_GAMMA {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_GAMMA").field(&self.0).finish()
@@ -7308,6 +7378,7 @@ pub struct D2D1_GAMMA1(pub i32);
 impl windows_core::TypeKind for D2D1_GAMMA1 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_GAMMA1 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_GAMMA1").field(&self.0).finish()
@@ -7319,6 +7390,7 @@ pub struct D2D1_GAMMATRANSFER_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_GAMMATRANSFER_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_GAMMATRANSFER_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_GAMMATRANSFER_PROP").field(&self.0).finish()
@@ -7330,6 +7402,7 @@ pub struct D2D1_GAUSSIANBLUR_OPTIMIZATION(pub i32);
 impl windows_core::TypeKind for D2D1_GAUSSIANBLUR_OPTIMIZATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_GAUSSIANBLUR_OPTIMIZATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_GAUSSIANBLUR_OPTIMIZATION").field(&self.0).finish()
@@ -7341,6 +7414,7 @@ pub struct D2D1_GAUSSIANBLUR_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_GAUSSIANBLUR_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_GAUSSIANBLUR_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_GAUSSIANBLUR_PROP").field(&self.0).finish()
@@ -7352,6 +7426,7 @@ pub struct D2D1_GEOMETRY_RELATION(pub i32);
 impl windows_core::TypeKind for D2D1_GEOMETRY_RELATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_GEOMETRY_RELATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_GEOMETRY_RELATION").field(&self.0).finish()
@@ -7363,6 +7438,7 @@ pub struct D2D1_GEOMETRY_SIMPLIFICATION_OPTION(pub i32);
 impl windows_core::TypeKind for D2D1_GEOMETRY_SIMPLIFICATION_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_GEOMETRY_SIMPLIFICATION_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_GEOMETRY_SIMPLIFICATION_OPTION").field(&self.0).finish()
@@ -7374,6 +7450,7 @@ pub struct D2D1_HDRTONEMAP_DISPLAY_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_HDRTONEMAP_DISPLAY_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_HDRTONEMAP_DISPLAY_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_HDRTONEMAP_DISPLAY_MODE").field(&self.0).finish()
@@ -7385,6 +7462,7 @@ pub struct D2D1_HDRTONEMAP_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_HDRTONEMAP_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_HDRTONEMAP_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_HDRTONEMAP_PROP").field(&self.0).finish()
@@ -7396,6 +7474,7 @@ pub struct D2D1_HIGHLIGHTSANDSHADOWS_INPUT_GAMMA(pub i32);
 impl windows_core::TypeKind for D2D1_HIGHLIGHTSANDSHADOWS_INPUT_GAMMA {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_HIGHLIGHTSANDSHADOWS_INPUT_GAMMA {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_HIGHLIGHTSANDSHADOWS_INPUT_GAMMA").field(&self.0).finish()
@@ -7407,6 +7486,7 @@ pub struct D2D1_HIGHLIGHTSANDSHADOWS_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_HIGHLIGHTSANDSHADOWS_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_HIGHLIGHTSANDSHADOWS_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_HIGHLIGHTSANDSHADOWS_PROP").field(&self.0).finish()
@@ -7418,6 +7498,7 @@ pub struct D2D1_HISTOGRAM_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_HISTOGRAM_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_HISTOGRAM_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_HISTOGRAM_PROP").field(&self.0).finish()
@@ -7429,6 +7510,7 @@ pub struct D2D1_HUEROTATION_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_HUEROTATION_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_HUEROTATION_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_HUEROTATION_PROP").field(&self.0).finish()
@@ -7440,6 +7522,7 @@ pub struct D2D1_HUETORGB_INPUT_COLOR_SPACE(pub i32);
 impl windows_core::TypeKind for D2D1_HUETORGB_INPUT_COLOR_SPACE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_HUETORGB_INPUT_COLOR_SPACE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_HUETORGB_INPUT_COLOR_SPACE").field(&self.0).finish()
@@ -7451,6 +7534,7 @@ pub struct D2D1_HUETORGB_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_HUETORGB_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_HUETORGB_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_HUETORGB_PROP").field(&self.0).finish()
@@ -7462,6 +7546,7 @@ pub struct D2D1_IMAGE_SOURCE_FROM_DXGI_OPTIONS(pub i32);
 impl windows_core::TypeKind for D2D1_IMAGE_SOURCE_FROM_DXGI_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_IMAGE_SOURCE_FROM_DXGI_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_IMAGE_SOURCE_FROM_DXGI_OPTIONS").field(&self.0).finish()
@@ -7506,6 +7591,7 @@ pub struct D2D1_IMAGE_SOURCE_LOADING_OPTIONS(pub i32);
 impl windows_core::TypeKind for D2D1_IMAGE_SOURCE_LOADING_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_IMAGE_SOURCE_LOADING_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_IMAGE_SOURCE_LOADING_OPTIONS").field(&self.0).finish()
@@ -7550,6 +7636,7 @@ pub struct D2D1_INK_NIB_SHAPE(pub i32);
 impl windows_core::TypeKind for D2D1_INK_NIB_SHAPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_INK_NIB_SHAPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_INK_NIB_SHAPE").field(&self.0).finish()
@@ -7561,6 +7648,7 @@ pub struct D2D1_INTERPOLATION_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_INTERPOLATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_INTERPOLATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_INTERPOLATION_MODE").field(&self.0).finish()
@@ -7572,6 +7660,7 @@ pub struct D2D1_INTERPOLATION_MODE_DEFINITION(pub i32);
 impl windows_core::TypeKind for D2D1_INTERPOLATION_MODE_DEFINITION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_INTERPOLATION_MODE_DEFINITION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_INTERPOLATION_MODE_DEFINITION").field(&self.0).finish()
@@ -7583,6 +7672,7 @@ pub struct D2D1_LAYER_OPTIONS(pub i32);
 impl windows_core::TypeKind for D2D1_LAYER_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_LAYER_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_LAYER_OPTIONS").field(&self.0).finish()
@@ -7627,6 +7717,7 @@ pub struct D2D1_LAYER_OPTIONS1(pub i32);
 impl windows_core::TypeKind for D2D1_LAYER_OPTIONS1 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_LAYER_OPTIONS1 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_LAYER_OPTIONS1").field(&self.0).finish()
@@ -7671,6 +7762,7 @@ pub struct D2D1_LINEARTRANSFER_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_LINEARTRANSFER_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_LINEARTRANSFER_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_LINEARTRANSFER_PROP").field(&self.0).finish()
@@ -7682,6 +7774,7 @@ pub struct D2D1_LINE_JOIN(pub i32);
 impl windows_core::TypeKind for D2D1_LINE_JOIN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_LINE_JOIN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_LINE_JOIN").field(&self.0).finish()
@@ -7693,6 +7786,7 @@ pub struct D2D1_LOOKUPTABLE3D_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_LOOKUPTABLE3D_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_LOOKUPTABLE3D_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_LOOKUPTABLE3D_PROP").field(&self.0).finish()
@@ -7704,6 +7798,7 @@ pub struct D2D1_MAP_OPTIONS(pub i32);
 impl windows_core::TypeKind for D2D1_MAP_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_MAP_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_MAP_OPTIONS").field(&self.0).finish()
@@ -7748,6 +7843,7 @@ pub struct D2D1_MORPHOLOGY_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_MORPHOLOGY_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_MORPHOLOGY_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_MORPHOLOGY_MODE").field(&self.0).finish()
@@ -7759,6 +7855,7 @@ pub struct D2D1_MORPHOLOGY_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_MORPHOLOGY_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_MORPHOLOGY_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_MORPHOLOGY_PROP").field(&self.0).finish()
@@ -7770,6 +7867,7 @@ pub struct D2D1_OPACITYMETADATA_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_OPACITYMETADATA_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_OPACITYMETADATA_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_OPACITYMETADATA_PROP").field(&self.0).finish()
@@ -7781,6 +7879,7 @@ pub struct D2D1_OPACITY_MASK_CONTENT(pub i32);
 impl windows_core::TypeKind for D2D1_OPACITY_MASK_CONTENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_OPACITY_MASK_CONTENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_OPACITY_MASK_CONTENT").field(&self.0).finish()
@@ -7792,6 +7891,7 @@ pub struct D2D1_OPACITY_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_OPACITY_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_OPACITY_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_OPACITY_PROP").field(&self.0).finish()
@@ -7803,6 +7903,7 @@ pub struct D2D1_ORIENTATION(pub i32);
 impl windows_core::TypeKind for D2D1_ORIENTATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_ORIENTATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_ORIENTATION").field(&self.0).finish()
@@ -7814,6 +7915,7 @@ pub struct D2D1_PATCH_EDGE_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_PATCH_EDGE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_PATCH_EDGE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_PATCH_EDGE_MODE").field(&self.0).finish()
@@ -7825,6 +7927,7 @@ pub struct D2D1_PIXEL_OPTIONS(pub i32);
 impl windows_core::TypeKind for D2D1_PIXEL_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_PIXEL_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_PIXEL_OPTIONS").field(&self.0).finish()
@@ -7869,6 +7972,7 @@ pub struct D2D1_POINTDIFFUSE_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_POINTDIFFUSE_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_POINTDIFFUSE_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_POINTDIFFUSE_PROP").field(&self.0).finish()
@@ -7880,6 +7984,7 @@ pub struct D2D1_POINTDIFFUSE_SCALE_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_POINTDIFFUSE_SCALE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_POINTDIFFUSE_SCALE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_POINTDIFFUSE_SCALE_MODE").field(&self.0).finish()
@@ -7891,6 +7996,7 @@ pub struct D2D1_POINTSPECULAR_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_POINTSPECULAR_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_POINTSPECULAR_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_POINTSPECULAR_PROP").field(&self.0).finish()
@@ -7902,6 +8008,7 @@ pub struct D2D1_POINTSPECULAR_SCALE_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_POINTSPECULAR_SCALE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_POINTSPECULAR_SCALE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_POINTSPECULAR_SCALE_MODE").field(&self.0).finish()
@@ -7913,6 +8020,7 @@ pub struct D2D1_POSTERIZE_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_POSTERIZE_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_POSTERIZE_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_POSTERIZE_PROP").field(&self.0).finish()
@@ -7924,6 +8032,7 @@ pub struct D2D1_PRESENT_OPTIONS(pub i32);
 impl windows_core::TypeKind for D2D1_PRESENT_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_PRESENT_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_PRESENT_OPTIONS").field(&self.0).finish()
@@ -7968,6 +8077,7 @@ pub struct D2D1_PRIMITIVE_BLEND(pub i32);
 impl windows_core::TypeKind for D2D1_PRIMITIVE_BLEND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_PRIMITIVE_BLEND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_PRIMITIVE_BLEND").field(&self.0).finish()
@@ -7979,6 +8089,7 @@ pub struct D2D1_PRINT_FONT_SUBSET_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_PRINT_FONT_SUBSET_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_PRINT_FONT_SUBSET_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_PRINT_FONT_SUBSET_MODE").field(&self.0).finish()
@@ -7990,6 +8101,7 @@ pub struct D2D1_PROPERTY(pub i32);
 impl windows_core::TypeKind for D2D1_PROPERTY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_PROPERTY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_PROPERTY").field(&self.0).finish()
@@ -8001,6 +8113,7 @@ pub struct D2D1_PROPERTY_TYPE(pub i32);
 impl windows_core::TypeKind for D2D1_PROPERTY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_PROPERTY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_PROPERTY_TYPE").field(&self.0).finish()
@@ -8012,6 +8125,7 @@ pub struct D2D1_RENDERING_PRIORITY(pub i32);
 impl windows_core::TypeKind for D2D1_RENDERING_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_RENDERING_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_RENDERING_PRIORITY").field(&self.0).finish()
@@ -8023,6 +8137,7 @@ pub struct D2D1_RENDER_TARGET_TYPE(pub i32);
 impl windows_core::TypeKind for D2D1_RENDER_TARGET_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_RENDER_TARGET_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_RENDER_TARGET_TYPE").field(&self.0).finish()
@@ -8034,6 +8149,7 @@ pub struct D2D1_RENDER_TARGET_USAGE(pub i32);
 impl windows_core::TypeKind for D2D1_RENDER_TARGET_USAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_RENDER_TARGET_USAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_RENDER_TARGET_USAGE").field(&self.0).finish()
@@ -8078,6 +8194,7 @@ pub struct D2D1_RGBTOHUE_OUTPUT_COLOR_SPACE(pub i32);
 impl windows_core::TypeKind for D2D1_RGBTOHUE_OUTPUT_COLOR_SPACE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_RGBTOHUE_OUTPUT_COLOR_SPACE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_RGBTOHUE_OUTPUT_COLOR_SPACE").field(&self.0).finish()
@@ -8089,6 +8206,7 @@ pub struct D2D1_RGBTOHUE_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_RGBTOHUE_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_RGBTOHUE_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_RGBTOHUE_PROP").field(&self.0).finish()
@@ -8100,6 +8218,7 @@ pub struct D2D1_SATURATION_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_SATURATION_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SATURATION_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SATURATION_PROP").field(&self.0).finish()
@@ -8111,6 +8230,7 @@ pub struct D2D1_SCALE_INTERPOLATION_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_SCALE_INTERPOLATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SCALE_INTERPOLATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SCALE_INTERPOLATION_MODE").field(&self.0).finish()
@@ -8122,6 +8242,7 @@ pub struct D2D1_SCALE_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_SCALE_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SCALE_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SCALE_PROP").field(&self.0).finish()
@@ -8133,6 +8254,7 @@ pub struct D2D1_SEPIA_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_SEPIA_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SEPIA_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SEPIA_PROP").field(&self.0).finish()
@@ -8144,6 +8266,7 @@ pub struct D2D1_SHADOW_OPTIMIZATION(pub i32);
 impl windows_core::TypeKind for D2D1_SHADOW_OPTIMIZATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SHADOW_OPTIMIZATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SHADOW_OPTIMIZATION").field(&self.0).finish()
@@ -8155,6 +8278,7 @@ pub struct D2D1_SHADOW_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_SHADOW_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SHADOW_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SHADOW_PROP").field(&self.0).finish()
@@ -8166,6 +8290,7 @@ pub struct D2D1_SHARPEN_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_SHARPEN_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SHARPEN_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SHARPEN_PROP").field(&self.0).finish()
@@ -8177,6 +8302,7 @@ pub struct D2D1_SPOTDIFFUSE_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_SPOTDIFFUSE_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SPOTDIFFUSE_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SPOTDIFFUSE_PROP").field(&self.0).finish()
@@ -8188,6 +8314,7 @@ pub struct D2D1_SPOTDIFFUSE_SCALE_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_SPOTDIFFUSE_SCALE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SPOTDIFFUSE_SCALE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SPOTDIFFUSE_SCALE_MODE").field(&self.0).finish()
@@ -8199,6 +8326,7 @@ pub struct D2D1_SPOTSPECULAR_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_SPOTSPECULAR_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SPOTSPECULAR_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SPOTSPECULAR_PROP").field(&self.0).finish()
@@ -8210,6 +8338,7 @@ pub struct D2D1_SPOTSPECULAR_SCALE_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_SPOTSPECULAR_SCALE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SPOTSPECULAR_SCALE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SPOTSPECULAR_SCALE_MODE").field(&self.0).finish()
@@ -8221,6 +8350,7 @@ pub struct D2D1_SPRITE_OPTIONS(pub i32);
 impl windows_core::TypeKind for D2D1_SPRITE_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SPRITE_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SPRITE_OPTIONS").field(&self.0).finish()
@@ -8265,6 +8395,7 @@ pub struct D2D1_STRAIGHTEN_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_STRAIGHTEN_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_STRAIGHTEN_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_STRAIGHTEN_PROP").field(&self.0).finish()
@@ -8276,6 +8407,7 @@ pub struct D2D1_STRAIGHTEN_SCALE_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_STRAIGHTEN_SCALE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_STRAIGHTEN_SCALE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_STRAIGHTEN_SCALE_MODE").field(&self.0).finish()
@@ -8287,6 +8419,7 @@ pub struct D2D1_STROKE_TRANSFORM_TYPE(pub i32);
 impl windows_core::TypeKind for D2D1_STROKE_TRANSFORM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_STROKE_TRANSFORM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_STROKE_TRANSFORM_TYPE").field(&self.0).finish()
@@ -8298,6 +8431,7 @@ pub struct D2D1_SUBPROPERTY(pub i32);
 impl windows_core::TypeKind for D2D1_SUBPROPERTY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SUBPROPERTY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SUBPROPERTY").field(&self.0).finish()
@@ -8309,6 +8443,7 @@ pub struct D2D1_SVG_ASPECT_ALIGN(pub i32);
 impl windows_core::TypeKind for D2D1_SVG_ASPECT_ALIGN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SVG_ASPECT_ALIGN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SVG_ASPECT_ALIGN").field(&self.0).finish()
@@ -8320,6 +8455,7 @@ pub struct D2D1_SVG_ASPECT_SCALING(pub i32);
 impl windows_core::TypeKind for D2D1_SVG_ASPECT_SCALING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SVG_ASPECT_SCALING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SVG_ASPECT_SCALING").field(&self.0).finish()
@@ -8331,6 +8467,7 @@ pub struct D2D1_SVG_ATTRIBUTE_POD_TYPE(pub i32);
 impl windows_core::TypeKind for D2D1_SVG_ATTRIBUTE_POD_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SVG_ATTRIBUTE_POD_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SVG_ATTRIBUTE_POD_TYPE").field(&self.0).finish()
@@ -8342,6 +8479,7 @@ pub struct D2D1_SVG_ATTRIBUTE_STRING_TYPE(pub i32);
 impl windows_core::TypeKind for D2D1_SVG_ATTRIBUTE_STRING_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SVG_ATTRIBUTE_STRING_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SVG_ATTRIBUTE_STRING_TYPE").field(&self.0).finish()
@@ -8353,6 +8491,7 @@ pub struct D2D1_SVG_DISPLAY(pub i32);
 impl windows_core::TypeKind for D2D1_SVG_DISPLAY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SVG_DISPLAY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SVG_DISPLAY").field(&self.0).finish()
@@ -8364,6 +8503,7 @@ pub struct D2D1_SVG_LENGTH_UNITS(pub i32);
 impl windows_core::TypeKind for D2D1_SVG_LENGTH_UNITS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SVG_LENGTH_UNITS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SVG_LENGTH_UNITS").field(&self.0).finish()
@@ -8375,6 +8515,7 @@ pub struct D2D1_SVG_LINE_CAP(pub i32);
 impl windows_core::TypeKind for D2D1_SVG_LINE_CAP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SVG_LINE_CAP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SVG_LINE_CAP").field(&self.0).finish()
@@ -8386,6 +8527,7 @@ pub struct D2D1_SVG_LINE_JOIN(pub i32);
 impl windows_core::TypeKind for D2D1_SVG_LINE_JOIN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SVG_LINE_JOIN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SVG_LINE_JOIN").field(&self.0).finish()
@@ -8397,6 +8539,7 @@ pub struct D2D1_SVG_OVERFLOW(pub i32);
 impl windows_core::TypeKind for D2D1_SVG_OVERFLOW {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SVG_OVERFLOW {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SVG_OVERFLOW").field(&self.0).finish()
@@ -8408,6 +8551,7 @@ pub struct D2D1_SVG_PAINT_TYPE(pub i32);
 impl windows_core::TypeKind for D2D1_SVG_PAINT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SVG_PAINT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SVG_PAINT_TYPE").field(&self.0).finish()
@@ -8419,6 +8563,7 @@ pub struct D2D1_SVG_PATH_COMMAND(pub i32);
 impl windows_core::TypeKind for D2D1_SVG_PATH_COMMAND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SVG_PATH_COMMAND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SVG_PATH_COMMAND").field(&self.0).finish()
@@ -8430,6 +8575,7 @@ pub struct D2D1_SVG_UNIT_TYPE(pub i32);
 impl windows_core::TypeKind for D2D1_SVG_UNIT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SVG_UNIT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SVG_UNIT_TYPE").field(&self.0).finish()
@@ -8441,6 +8587,7 @@ pub struct D2D1_SVG_VISIBILITY(pub i32);
 impl windows_core::TypeKind for D2D1_SVG_VISIBILITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SVG_VISIBILITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SVG_VISIBILITY").field(&self.0).finish()
@@ -8452,6 +8599,7 @@ pub struct D2D1_SWEEP_DIRECTION(pub i32);
 impl windows_core::TypeKind for D2D1_SWEEP_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_SWEEP_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_SWEEP_DIRECTION").field(&self.0).finish()
@@ -8463,6 +8611,7 @@ pub struct D2D1_TABLETRANSFER_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_TABLETRANSFER_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_TABLETRANSFER_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_TABLETRANSFER_PROP").field(&self.0).finish()
@@ -8474,6 +8623,7 @@ pub struct D2D1_TEMPERATUREANDTINT_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_TEMPERATUREANDTINT_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_TEMPERATUREANDTINT_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_TEMPERATUREANDTINT_PROP").field(&self.0).finish()
@@ -8485,6 +8635,7 @@ pub struct D2D1_TEXT_ANTIALIAS_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_TEXT_ANTIALIAS_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_TEXT_ANTIALIAS_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_TEXT_ANTIALIAS_MODE").field(&self.0).finish()
@@ -8496,6 +8647,7 @@ pub struct D2D1_THREADING_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_THREADING_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_THREADING_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_THREADING_MODE").field(&self.0).finish()
@@ -8507,6 +8659,7 @@ pub struct D2D1_TILE_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_TILE_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_TILE_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_TILE_PROP").field(&self.0).finish()
@@ -8518,6 +8671,7 @@ pub struct D2D1_TINT_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_TINT_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_TINT_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_TINT_PROP").field(&self.0).finish()
@@ -8529,6 +8683,7 @@ pub struct D2D1_TRANSFORMED_IMAGE_SOURCE_OPTIONS(pub i32);
 impl windows_core::TypeKind for D2D1_TRANSFORMED_IMAGE_SOURCE_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_TRANSFORMED_IMAGE_SOURCE_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_TRANSFORMED_IMAGE_SOURCE_OPTIONS").field(&self.0).finish()
@@ -8573,6 +8728,7 @@ pub struct D2D1_TURBULENCE_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_TURBULENCE_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_TURBULENCE_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_TURBULENCE_PROP").field(&self.0).finish()
@@ -8584,6 +8740,7 @@ pub struct D2D1_UNIT_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_UNIT_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_UNIT_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_UNIT_MODE").field(&self.0).finish()
@@ -8595,6 +8752,7 @@ pub struct D2D1_VERTEX_OPTIONS(pub i32);
 impl windows_core::TypeKind for D2D1_VERTEX_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_VERTEX_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_VERTEX_OPTIONS").field(&self.0).finish()
@@ -8639,6 +8797,7 @@ pub struct D2D1_VERTEX_USAGE(pub i32);
 impl windows_core::TypeKind for D2D1_VERTEX_USAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_VERTEX_USAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_VERTEX_USAGE").field(&self.0).finish()
@@ -8650,6 +8809,7 @@ pub struct D2D1_VIGNETTE_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_VIGNETTE_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_VIGNETTE_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_VIGNETTE_PROP").field(&self.0).finish()
@@ -8661,6 +8821,7 @@ pub struct D2D1_WHITELEVELADJUSTMENT_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_WHITELEVELADJUSTMENT_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_WHITELEVELADJUSTMENT_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_WHITELEVELADJUSTMENT_PROP").field(&self.0).finish()
@@ -8672,6 +8833,7 @@ pub struct D2D1_WINDOW_STATE(pub i32);
 impl windows_core::TypeKind for D2D1_WINDOW_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_WINDOW_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_WINDOW_STATE").field(&self.0).finish()
@@ -8716,6 +8878,7 @@ pub struct D2D1_YCBCR_CHROMA_SUBSAMPLING(pub i32);
 impl windows_core::TypeKind for D2D1_YCBCR_CHROMA_SUBSAMPLING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_YCBCR_CHROMA_SUBSAMPLING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_YCBCR_CHROMA_SUBSAMPLING").field(&self.0).finish()
@@ -8727,6 +8890,7 @@ pub struct D2D1_YCBCR_INTERPOLATION_MODE(pub i32);
 impl windows_core::TypeKind for D2D1_YCBCR_INTERPOLATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_YCBCR_INTERPOLATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_YCBCR_INTERPOLATION_MODE").field(&self.0).finish()
@@ -8738,6 +8902,7 @@ pub struct D2D1_YCBCR_PROP(pub i32);
 impl windows_core::TypeKind for D2D1_YCBCR_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D2D1_YCBCR_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D2D1_YCBCR_PROP").field(&self.0).finish()
@@ -8745,7 +8910,8 @@ impl core::fmt::Debug for D2D1_YCBCR_PROP {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_ARC_SEGMENT {
     pub point: Common::D2D_POINT_2F,
     pub size: Common::D2D_SIZE_F,
@@ -8764,7 +8930,8 @@ impl Default for D2D1_ARC_SEGMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_BITMAP_BRUSH_PROPERTIES {
     pub extendModeX: D2D1_EXTEND_MODE,
     pub extendModeY: D2D1_EXTEND_MODE,
@@ -8779,7 +8946,8 @@ impl Default for D2D1_BITMAP_BRUSH_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_BITMAP_BRUSH_PROPERTIES1 {
     pub extendModeX: D2D1_EXTEND_MODE,
     pub extendModeY: D2D1_EXTEND_MODE,
@@ -8795,7 +8963,8 @@ impl Default for D2D1_BITMAP_BRUSH_PROPERTIES1 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_BITMAP_PROPERTIES {
     pub pixelFormat: Common::D2D1_PIXEL_FORMAT,
     pub dpiX: f32,
@@ -8813,7 +8982,8 @@ impl Default for D2D1_BITMAP_PROPERTIES {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_BITMAP_PROPERTIES1 {
     pub pixelFormat: Common::D2D1_PIXEL_FORMAT,
     pub dpiX: f32,
@@ -8838,7 +9008,8 @@ impl Default for D2D1_BITMAP_PROPERTIES1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_BLEND_DESCRIPTION {
     pub sourceBlend: D2D1_BLEND,
     pub destinationBlend: D2D1_BLEND,
@@ -8858,7 +9029,8 @@ impl Default for D2D1_BLEND_DESCRIPTION {
 }
 #[repr(C)]
 #[cfg(feature = "Foundation_Numerics")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_BRUSH_PROPERTIES {
     pub opacity: f32,
     pub transform: super::super::super::Foundation::Numerics::Matrix3x2,
@@ -8874,7 +9046,8 @@ impl Default for D2D1_BRUSH_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_CREATION_PROPERTIES {
     pub threadingMode: D2D1_THREADING_MODE,
     pub debugLevel: D2D1_DEBUG_LEVEL,
@@ -8890,7 +9063,8 @@ impl Default for D2D1_CREATION_PROPERTIES {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_CUSTOM_VERTEX_BUFFER_PROPERTIES {
     pub shaderBufferWithInputSignature: *const u8,
     pub shaderBufferSize: u32,
@@ -8910,7 +9084,8 @@ impl Default for D2D1_CUSTOM_VERTEX_BUFFER_PROPERTIES {
 }
 #[repr(C)]
 #[cfg(feature = "Foundation_Numerics")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_DRAWING_STATE_DESCRIPTION {
     pub antialiasMode: D2D1_ANTIALIAS_MODE,
     pub textAntialiasMode: D2D1_TEXT_ANTIALIAS_MODE,
@@ -8930,7 +9105,8 @@ impl Default for D2D1_DRAWING_STATE_DESCRIPTION {
 }
 #[repr(C)]
 #[cfg(feature = "Foundation_Numerics")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_DRAWING_STATE_DESCRIPTION1 {
     pub antialiasMode: D2D1_ANTIALIAS_MODE,
     pub textAntialiasMode: D2D1_TEXT_ANTIALIAS_MODE,
@@ -8952,7 +9128,8 @@ impl Default for D2D1_DRAWING_STATE_DESCRIPTION1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_EFFECT_INPUT_DESCRIPTION {
     pub effect: core::mem::ManuallyDrop<Option<ID2D1Effect>>,
     pub inputIndex: u32,
@@ -8976,7 +9153,8 @@ impl Default for D2D1_EFFECT_INPUT_DESCRIPTION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_ELLIPSE {
     pub point: Common::D2D_POINT_2F,
     pub radiusX: f32,
@@ -8993,7 +9171,8 @@ impl Default for D2D1_ELLIPSE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_FACTORY_OPTIONS {
     pub debugLevel: D2D1_DEBUG_LEVEL,
 }
@@ -9006,7 +9185,8 @@ impl Default for D2D1_FACTORY_OPTIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_FEATURE_DATA_D3D10_X_HARDWARE_OPTIONS {
     pub computeShaders_Plus_RawAndStructuredBuffers_Via_Shader_4_x: super::super::Foundation::BOOL,
 }
@@ -9019,7 +9199,8 @@ impl Default for D2D1_FEATURE_DATA_D3D10_X_HARDWARE_OPTIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_FEATURE_DATA_DOUBLES {
     pub doublePrecisionFloatShaderOps: super::super::Foundation::BOOL,
 }
@@ -9033,7 +9214,8 @@ impl Default for D2D1_FEATURE_DATA_DOUBLES {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_GRADIENT_MESH_PATCH {
     pub point00: Common::D2D_POINT_2F,
     pub point01: Common::D2D_POINT_2F,
@@ -9072,7 +9254,8 @@ impl Default for D2D1_GRADIENT_MESH_PATCH {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_HWND_RENDER_TARGET_PROPERTIES {
     pub hwnd: super::super::Foundation::HWND,
     pub pixelSize: Common::D2D_SIZE_U,
@@ -9090,7 +9273,8 @@ impl Default for D2D1_HWND_RENDER_TARGET_PROPERTIES {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_IMAGE_BRUSH_PROPERTIES {
     pub sourceRectangle: Common::D2D_RECT_F,
     pub extendModeX: D2D1_EXTEND_MODE,
@@ -9108,7 +9292,8 @@ impl Default for D2D1_IMAGE_BRUSH_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_INK_BEZIER_SEGMENT {
     pub point1: D2D1_INK_POINT,
     pub point2: D2D1_INK_POINT,
@@ -9123,7 +9308,8 @@ impl Default for D2D1_INK_BEZIER_SEGMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_INK_POINT {
     pub x: f32,
     pub y: f32,
@@ -9139,7 +9325,8 @@ impl Default for D2D1_INK_POINT {
 }
 #[repr(C)]
 #[cfg(feature = "Foundation_Numerics")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_INK_STYLE_PROPERTIES {
     pub nibShape: D2D1_INK_NIB_SHAPE,
     pub nibTransform: super::super::super::Foundation::Numerics::Matrix3x2,
@@ -9155,7 +9342,8 @@ impl Default for D2D1_INK_STYLE_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_INPUT_DESCRIPTION {
     pub filter: D2D1_FILTER,
     pub levelOfDetailCount: u32,
@@ -9170,7 +9358,8 @@ impl Default for D2D1_INPUT_DESCRIPTION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_INPUT_ELEMENT_DESC {
     pub semanticName: windows_core::PCSTR,
     pub semanticIndex: u32,
@@ -9190,7 +9379,8 @@ impl Default for D2D1_INPUT_ELEMENT_DESC {
 }
 #[repr(C)]
 #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_LAYER_PARAMETERS {
     pub contentBounds: Common::D2D_RECT_F,
     pub geometricMask: core::mem::ManuallyDrop<Option<ID2D1Geometry>>,
@@ -9218,7 +9408,8 @@ impl Default for D2D1_LAYER_PARAMETERS {
 }
 #[repr(C)]
 #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_LAYER_PARAMETERS1 {
     pub contentBounds: Common::D2D_RECT_F,
     pub geometricMask: core::mem::ManuallyDrop<Option<ID2D1Geometry>>,
@@ -9246,7 +9437,8 @@ impl Default for D2D1_LAYER_PARAMETERS1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_LINEAR_GRADIENT_BRUSH_PROPERTIES {
     pub startPoint: Common::D2D_POINT_2F,
     pub endPoint: Common::D2D_POINT_2F,
@@ -9262,7 +9454,8 @@ impl Default for D2D1_LINEAR_GRADIENT_BRUSH_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_MAPPED_RECT {
     pub pitch: u32,
     pub bits: *mut u8,
@@ -9277,7 +9470,8 @@ impl Default for D2D1_MAPPED_RECT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_POINT_DESCRIPTION {
     pub point: Common::D2D_POINT_2F,
     pub unitTangentVector: Common::D2D_POINT_2F,
@@ -9296,7 +9490,8 @@ impl Default for D2D1_POINT_DESCRIPTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_PRINT_CONTROL_PROPERTIES {
     pub fontSubset: D2D1_PRINT_FONT_SUBSET_MODE,
     pub rasterDPI: f32,
@@ -9311,7 +9506,8 @@ impl Default for D2D1_PRINT_CONTROL_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_PROPERTY_BINDING {
     pub propertyName: windows_core::PCWSTR,
     pub setFunction: PD2D1_PROPERTY_SET_FUNCTION,
@@ -9327,7 +9523,8 @@ impl Default for D2D1_PROPERTY_BINDING {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_QUADRATIC_BEZIER_SEGMENT {
     pub point1: Common::D2D_POINT_2F,
     pub point2: Common::D2D_POINT_2F,
@@ -9344,7 +9541,8 @@ impl Default for D2D1_QUADRATIC_BEZIER_SEGMENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_RADIAL_GRADIENT_BRUSH_PROPERTIES {
     pub center: Common::D2D_POINT_2F,
     pub gradientOriginOffset: Common::D2D_POINT_2F,
@@ -9363,7 +9561,8 @@ impl Default for D2D1_RADIAL_GRADIENT_BRUSH_PROPERTIES {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_RENDERING_CONTROLS {
     pub bufferPrecision: D2D1_BUFFER_PRECISION,
     pub tileSize: Common::D2D_SIZE_U,
@@ -9380,7 +9579,8 @@ impl Default for D2D1_RENDERING_CONTROLS {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_RENDER_TARGET_PROPERTIES {
     pub r#type: D2D1_RENDER_TARGET_TYPE,
     pub pixelFormat: Common::D2D1_PIXEL_FORMAT,
@@ -9400,7 +9600,8 @@ impl Default for D2D1_RENDER_TARGET_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_RESOURCE_TEXTURE_PROPERTIES {
     pub extents: *const u32,
     pub dimensions: u32,
@@ -9419,7 +9620,8 @@ impl Default for D2D1_RESOURCE_TEXTURE_PROPERTIES {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_ROUNDED_RECT {
     pub rect: Common::D2D_RECT_F,
     pub radiusX: f32,
@@ -9437,7 +9639,8 @@ impl Default for D2D1_ROUNDED_RECT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_SIMPLE_COLOR_PROFILE {
     pub redPrimary: Common::D2D_POINT_2F,
     pub greenPrimary: Common::D2D_POINT_2F,
@@ -9456,7 +9659,8 @@ impl Default for D2D1_SIMPLE_COLOR_PROFILE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_STROKE_STYLE_PROPERTIES {
     pub startCap: D2D1_CAP_STYLE,
     pub endCap: D2D1_CAP_STYLE,
@@ -9475,7 +9679,8 @@ impl Default for D2D1_STROKE_STYLE_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_STROKE_STYLE_PROPERTIES1 {
     pub startCap: D2D1_CAP_STYLE,
     pub endCap: D2D1_CAP_STYLE,
@@ -9495,7 +9700,8 @@ impl Default for D2D1_STROKE_STYLE_PROPERTIES1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_SVG_LENGTH {
     pub value: f32,
     pub units: D2D1_SVG_LENGTH_UNITS,
@@ -9509,7 +9715,8 @@ impl Default for D2D1_SVG_LENGTH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_SVG_PRESERVE_ASPECT_RATIO {
     pub defer: super::super::Foundation::BOOL,
     pub align: D2D1_SVG_ASPECT_ALIGN,
@@ -9524,7 +9731,8 @@ impl Default for D2D1_SVG_PRESERVE_ASPECT_RATIO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_SVG_VIEWBOX {
     pub x: f32,
     pub y: f32,
@@ -9540,7 +9748,8 @@ impl Default for D2D1_SVG_VIEWBOX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_TRANSFORMED_IMAGE_SOURCE_PROPERTIES {
     pub orientation: D2D1_ORIENTATION,
     pub scaleX: f32,
@@ -9558,7 +9767,8 @@ impl Default for D2D1_TRANSFORMED_IMAGE_SOURCE_PROPERTIES {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_TRIANGLE {
     pub point1: Common::D2D_POINT_2F,
     pub point2: Common::D2D_POINT_2F,
@@ -9575,7 +9785,8 @@ impl Default for D2D1_TRIANGLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_VERTEX_BUFFER_PROPERTIES {
     pub inputCount: u32,
     pub usage: D2D1_VERTEX_USAGE,
@@ -9591,7 +9802,8 @@ impl Default for D2D1_VERTEX_BUFFER_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D2D1_VERTEX_RANGE {
     pub startVertex: u32,
     pub vertexCount: u32,
@@ -9609,3 +9821,5 @@ pub type PD2D1_PROPERTY_GET_FUNCTION = Option<unsafe extern "system" fn(effect:
 pub type PD2D1_PROPERTY_SET_FUNCTION = Option<unsafe extern "system" fn(effect: Option<windows_core::IUnknown>, data: *const u8, datasize: u32) -> windows_core::HRESULT>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
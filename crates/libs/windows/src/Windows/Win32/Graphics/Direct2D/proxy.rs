@@ -0,0 +1,4107 @@
+impl windows_core::imp::ProxyVtable for ID2D1AnalysisTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProcessAnalysisResults(this: *mut core::ffi::c_void, analysisdata: *const u8, analysisdatacount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1AnalysisTransform", "ProcessAnalysisResults", 3usize, |this| ((**(this as *const *const ID2D1AnalysisTransform_Vtbl)).ProcessAnalysisResults)(this, analysisdata, analysisdatacount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProcessAnalysisResults }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1AnalysisTransform as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1Bitmap_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, result__: *mut Common::D2D_SIZE_F) {
+            windows_core::imp::proxy_call(this, "ID2D1Bitmap", "GetSize", 4usize, |this| ((**(this as *const *const ID2D1Bitmap_Vtbl)).GetSize)(this, result__), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetPixelSize(this: *mut core::ffi::c_void, result__: *mut Common::D2D_SIZE_U) {
+            windows_core::imp::proxy_call(this, "ID2D1Bitmap", "GetPixelSize", 5usize, |this| ((**(this as *const *const ID2D1Bitmap_Vtbl)).GetPixelSize)(this, result__), |_| None)
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn GetPixelFormat(this: *mut core::ffi::c_void, result__: *mut Common::D2D1_PIXEL_FORMAT) {
+            windows_core::imp::proxy_call(this, "ID2D1Bitmap", "GetPixelFormat", 6usize, |this| ((**(this as *const *const ID2D1Bitmap_Vtbl)).GetPixelFormat)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn GetDpi(this: *mut core::ffi::c_void, dpix: *mut f32, dpiy: *mut f32) {
+            windows_core::imp::proxy_call(this, "ID2D1Bitmap", "GetDpi", 7usize, |this| ((**(this as *const *const ID2D1Bitmap_Vtbl)).GetDpi)(this, dpix, dpiy), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CopyFromBitmap(this: *mut core::ffi::c_void, destpoint: *const Common::D2D_POINT_2U, bitmap: *mut core::ffi::c_void, srcrect: *const Common::D2D_RECT_U) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Bitmap", "CopyFromBitmap", 8usize, |this| ((**(this as *const *const ID2D1Bitmap_Vtbl)).CopyFromBitmap)(this, destpoint, bitmap, srcrect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CopyFromRenderTarget(this: *mut core::ffi::c_void, destpoint: *const Common::D2D_POINT_2U, rendertarget: *mut core::ffi::c_void, srcrect: *const Common::D2D_RECT_U) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Bitmap", "CopyFromRenderTarget", 9usize, |this| ((**(this as *const *const ID2D1Bitmap_Vtbl)).CopyFromRenderTarget)(this, destpoint, rendertarget, srcrect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CopyFromMemory(this: *mut core::ffi::c_void, dstrect: *const Common::D2D_RECT_U, srcdata: *const core::ffi::c_void, pitch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Bitmap", "CopyFromMemory", 10usize, |this| ((**(this as *const *const ID2D1Bitmap_Vtbl)).CopyFromMemory)(this, dstrect, srcdata, pitch), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Image_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetSize,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetSize: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetPixelSize,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetPixelSize: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+            GetPixelFormat,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common")))]
+            GetPixelFormat: 0,
+            GetDpi,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CopyFromBitmap,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CopyFromBitmap: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CopyFromRenderTarget,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CopyFromRenderTarget: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CopyFromMemory,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CopyFromMemory: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Bitmap as windows_core::Interface>::IID || <ID2D1Image_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1Bitmap1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetColorContext(this: *mut core::ffi::c_void, colorcontext: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1Bitmap1", "GetColorContext", 11usize, |this| ((**(this as *const *const ID2D1Bitmap1_Vtbl)).GetColorContext)(this, colorcontext), |_| None)
+        }
+        unsafe extern "system" fn GetOptions(this: *mut core::ffi::c_void) -> D2D1_BITMAP_OPTIONS {
+            windows_core::imp::proxy_call(this, "ID2D1Bitmap1", "GetOptions", 12usize, |this| ((**(this as *const *const ID2D1Bitmap1_Vtbl)).GetOptions)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn GetSurface(this: *mut core::ffi::c_void, dxgisurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Bitmap1", "GetSurface", 13usize, |this| ((**(this as *const *const ID2D1Bitmap1_Vtbl)).GetSurface)(this, dxgisurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn Map(this: *mut core::ffi::c_void, options: D2D1_MAP_OPTIONS, mappedrect: *mut D2D1_MAPPED_RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Bitmap1", "Map", 14usize, |this| ((**(this as *const *const ID2D1Bitmap1_Vtbl)).Map)(this, options, mappedrect), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unmap(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Bitmap1", "Unmap", 15usize, |this| ((**(this as *const *const ID2D1Bitmap1_Vtbl)).Unmap)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Bitmap_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetColorContext,
+            GetOptions,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            GetSurface,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            GetSurface: 0,
+            Map,
+            Unmap,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Bitmap1 as windows_core::Interface>::IID || <ID2D1Bitmap_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ID2D1BitmapBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetExtendModeX(this: *mut core::ffi::c_void, extendmodex: D2D1_EXTEND_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1BitmapBrush", "SetExtendModeX", 8usize, |this| ((**(this as *const *const ID2D1BitmapBrush_Vtbl)).SetExtendModeX)(this, extendmodex), |_| None)
+        }
+        unsafe extern "system" fn SetExtendModeY(this: *mut core::ffi::c_void, extendmodey: D2D1_EXTEND_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1BitmapBrush", "SetExtendModeY", 9usize, |this| ((**(this as *const *const ID2D1BitmapBrush_Vtbl)).SetExtendModeY)(this, extendmodey), |_| None)
+        }
+        unsafe extern "system" fn SetInterpolationMode(this: *mut core::ffi::c_void, interpolationmode: D2D1_BITMAP_INTERPOLATION_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1BitmapBrush", "SetInterpolationMode", 10usize, |this| ((**(this as *const *const ID2D1BitmapBrush_Vtbl)).SetInterpolationMode)(this, interpolationmode), |_| None)
+        }
+        unsafe extern "system" fn SetBitmap(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1BitmapBrush", "SetBitmap", 11usize, |this| ((**(this as *const *const ID2D1BitmapBrush_Vtbl)).SetBitmap)(this, bitmap), |_| None)
+        }
+        unsafe extern "system" fn GetExtendModeX(this: *mut core::ffi::c_void) -> D2D1_EXTEND_MODE {
+            windows_core::imp::proxy_call(this, "ID2D1BitmapBrush", "GetExtendModeX", 12usize, |this| ((**(this as *const *const ID2D1BitmapBrush_Vtbl)).GetExtendModeX)(this), |_| None)
+        }
+        unsafe extern "system" fn GetExtendModeY(this: *mut core::ffi::c_void) -> D2D1_EXTEND_MODE {
+            windows_core::imp::proxy_call(this, "ID2D1BitmapBrush", "GetExtendModeY", 13usize, |this| ((**(this as *const *const ID2D1BitmapBrush_Vtbl)).GetExtendModeY)(this), |_| None)
+        }
+        unsafe extern "system" fn GetInterpolationMode(this: *mut core::ffi::c_void) -> D2D1_BITMAP_INTERPOLATION_MODE {
+            windows_core::imp::proxy_call(this, "ID2D1BitmapBrush", "GetInterpolationMode", 14usize, |this| ((**(this as *const *const ID2D1BitmapBrush_Vtbl)).GetInterpolationMode)(this), |_| None)
+        }
+        unsafe extern "system" fn GetBitmap(this: *mut core::ffi::c_void, bitmap: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1BitmapBrush", "GetBitmap", 15usize, |this| ((**(this as *const *const ID2D1BitmapBrush_Vtbl)).GetBitmap)(this, bitmap), |_| None)
+        }
+        Self {
+            base__: <ID2D1Brush_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetExtendModeX,
+            SetExtendModeY,
+            SetInterpolationMode,
+            SetBitmap,
+            GetExtendModeX,
+            GetExtendModeY,
+            GetInterpolationMode,
+            GetBitmap,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1BitmapBrush as windows_core::Interface>::IID || <ID2D1Brush_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ID2D1BitmapBrush1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetInterpolationMode1(this: *mut core::ffi::c_void, interpolationmode: D2D1_INTERPOLATION_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1BitmapBrush1", "SetInterpolationMode1", 16usize, |this| ((**(this as *const *const ID2D1BitmapBrush1_Vtbl)).SetInterpolationMode1)(this, interpolationmode), |_| None)
+        }
+        unsafe extern "system" fn GetInterpolationMode1(this: *mut core::ffi::c_void) -> D2D1_INTERPOLATION_MODE {
+            windows_core::imp::proxy_call(this, "ID2D1BitmapBrush1", "GetInterpolationMode1", 17usize, |this| ((**(this as *const *const ID2D1BitmapBrush1_Vtbl)).GetInterpolationMode1)(this), |_| None)
+        }
+        Self { base__: <ID2D1BitmapBrush_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetInterpolationMode1, GetInterpolationMode1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1BitmapBrush1 as windows_core::Interface>::IID || <ID2D1BitmapBrush_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ID2D1BitmapRenderTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBitmap(this: *mut core::ffi::c_void, bitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1BitmapRenderTarget", "GetBitmap", 57usize, |this| ((**(this as *const *const ID2D1BitmapRenderTarget_Vtbl)).GetBitmap)(this, bitmap), |result| Some(*result))
+        }
+        Self { base__: <ID2D1RenderTarget_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBitmap }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1BitmapRenderTarget as windows_core::Interface>::IID || <ID2D1RenderTarget_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1BlendTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, description: *const D2D1_BLEND_DESCRIPTION) {
+            windows_core::imp::proxy_call(this, "ID2D1BlendTransform", "SetDescription", 6usize, |this| ((**(this as *const *const ID2D1BlendTransform_Vtbl)).SetDescription)(this, description), |_| None)
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, description: *mut D2D1_BLEND_DESCRIPTION) {
+            windows_core::imp::proxy_call(this, "ID2D1BlendTransform", "GetDescription", 7usize, |this| ((**(this as *const *const ID2D1BlendTransform_Vtbl)).GetDescription)(this, description), |_| None)
+        }
+        Self { base__: <ID2D1ConcreteTransform_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDescription, GetDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1BlendTransform as windows_core::Interface>::IID || <ID2D1ConcreteTransform_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1BorderTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetExtendModeX(this: *mut core::ffi::c_void, extendmode: D2D1_EXTEND_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1BorderTransform", "SetExtendModeX", 6usize, |this| ((**(this as *const *const ID2D1BorderTransform_Vtbl)).SetExtendModeX)(this, extendmode), |_| None)
+        }
+        unsafe extern "system" fn SetExtendModeY(this: *mut core::ffi::c_void, extendmode: D2D1_EXTEND_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1BorderTransform", "SetExtendModeY", 7usize, |this| ((**(this as *const *const ID2D1BorderTransform_Vtbl)).SetExtendModeY)(this, extendmode), |_| None)
+        }
+        unsafe extern "system" fn GetExtendModeX(this: *mut core::ffi::c_void) -> D2D1_EXTEND_MODE {
+            windows_core::imp::proxy_call(this, "ID2D1BorderTransform", "GetExtendModeX", 8usize, |this| ((**(this as *const *const ID2D1BorderTransform_Vtbl)).GetExtendModeX)(this), |_| None)
+        }
+        unsafe extern "system" fn GetExtendModeY(this: *mut core::ffi::c_void) -> D2D1_EXTEND_MODE {
+            windows_core::imp::proxy_call(this, "ID2D1BorderTransform", "GetExtendModeY", 9usize, |this| ((**(this as *const *const ID2D1BorderTransform_Vtbl)).GetExtendModeY)(this), |_| None)
+        }
+        Self { base__: <ID2D1ConcreteTransform_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetExtendModeX, SetExtendModeY, GetExtendModeX, GetExtendModeY }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1BorderTransform as windows_core::Interface>::IID || <ID2D1ConcreteTransform_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1BoundsAdjustmentTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOutputBounds(this: *mut core::ffi::c_void, outputbounds: *const super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID2D1BoundsAdjustmentTransform", "SetOutputBounds", 4usize, |this| ((**(this as *const *const ID2D1BoundsAdjustmentTransform_Vtbl)).SetOutputBounds)(this, outputbounds), |_| None)
+        }
+        unsafe extern "system" fn GetOutputBounds(this: *mut core::ffi::c_void, outputbounds: *mut super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID2D1BoundsAdjustmentTransform", "GetOutputBounds", 5usize, |this| ((**(this as *const *const ID2D1BoundsAdjustmentTransform_Vtbl)).GetOutputBounds)(this, outputbounds), |_| None)
+        }
+        Self { base__: <ID2D1TransformNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetOutputBounds, GetOutputBounds }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1BoundsAdjustmentTransform as windows_core::Interface>::IID || <ID2D1TransformNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ID2D1Brush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOpacity(this: *mut core::ffi::c_void, opacity: f32) {
+            windows_core::imp::proxy_call(this, "ID2D1Brush", "SetOpacity", 4usize, |this| ((**(this as *const *const ID2D1Brush_Vtbl)).SetOpacity)(this, opacity), |_| None)
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetTransform(this: *mut core::ffi::c_void, transform: *const super::super::super::Foundation::Numerics::Matrix3x2) {
+            windows_core::imp::proxy_call(this, "ID2D1Brush", "SetTransform", 5usize, |this| ((**(this as *const *const ID2D1Brush_Vtbl)).SetTransform)(this, transform), |_| None)
+        }
+        unsafe extern "system" fn GetOpacity(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "ID2D1Brush", "GetOpacity", 6usize, |this| ((**(this as *const *const ID2D1Brush_Vtbl)).GetOpacity)(this), |_| None)
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn GetTransform(this: *mut core::ffi::c_void, transform: *mut super::super::super::Foundation::Numerics::Matrix3x2) {
+            windows_core::imp::proxy_call(this, "ID2D1Brush", "GetTransform", 7usize, |this| ((**(this as *const *const ID2D1Brush_Vtbl)).GetTransform)(this, transform), |_| None)
+        }
+        Self {
+            base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetOpacity,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetTransform: 0,
+            GetOpacity,
+            #[cfg(feature = "Foundation_Numerics")]
+            GetTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            GetTransform: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Brush as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1ColorContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetColorSpace(this: *mut core::ffi::c_void) -> D2D1_COLOR_SPACE {
+            windows_core::imp::proxy_call(this, "ID2D1ColorContext", "GetColorSpace", 4usize, |this| ((**(this as *const *const ID2D1ColorContext_Vtbl)).GetColorSpace)(this), |_| None)
+        }
+        unsafe extern "system" fn GetProfileSize(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1ColorContext", "GetProfileSize", 5usize, |this| ((**(this as *const *const ID2D1ColorContext_Vtbl)).GetProfileSize)(this), |_| None)
+        }
+        unsafe extern "system" fn GetProfile(this: *mut core::ffi::c_void, profile: *mut u8, profilesize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1ColorContext", "GetProfile", 6usize, |this| ((**(this as *const *const ID2D1ColorContext_Vtbl)).GetProfile)(this, profile, profilesize), |result| Some(*result))
+        }
+        Self { base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetColorSpace, GetProfileSize, GetProfile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1ColorContext as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1ColorContext1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetColorContextType(this: *mut core::ffi::c_void) -> D2D1_COLOR_CONTEXT_TYPE {
+            windows_core::imp::proxy_call(this, "ID2D1ColorContext1", "GetColorContextType", 7usize, |this| ((**(this as *const *const ID2D1ColorContext1_Vtbl)).GetColorContextType)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDXGIColorSpace(this: *mut core::ffi::c_void) -> super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE {
+            windows_core::imp::proxy_call(this, "ID2D1ColorContext1", "GetDXGIColorSpace", 8usize, |this| ((**(this as *const *const ID2D1ColorContext1_Vtbl)).GetDXGIColorSpace)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetSimpleColorProfile(this: *mut core::ffi::c_void, simpleprofile: *mut D2D1_SIMPLE_COLOR_PROFILE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1ColorContext1", "GetSimpleColorProfile", 9usize, |this| ((**(this as *const *const ID2D1ColorContext1_Vtbl)).GetSimpleColorProfile)(this, simpleprofile), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1ColorContext_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetColorContextType,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDXGIColorSpace,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDXGIColorSpace: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetSimpleColorProfile,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetSimpleColorProfile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1ColorContext1 as windows_core::Interface>::IID || <ID2D1ColorContext_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1CommandList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Stream(this: *mut core::ffi::c_void, sink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandList", "Stream", 4usize, |this| ((**(this as *const *const ID2D1CommandList_Vtbl)).Stream)(this, sink), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandList", "Close", 5usize, |this| ((**(this as *const *const ID2D1CommandList_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <ID2D1Image_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Stream, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1CommandList as windows_core::Interface>::IID || <ID2D1Image_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+impl windows_core::imp::ProxyVtable for ID2D1CommandSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginDraw(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "BeginDraw", 3usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).BeginDraw)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndDraw(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "EndDraw", 4usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).EndDraw)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAntialiasMode(this: *mut core::ffi::c_void, antialiasmode: D2D1_ANTIALIAS_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "SetAntialiasMode", 5usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).SetAntialiasMode)(this, antialiasmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTags(this: *mut core::ffi::c_void, tag1: u64, tag2: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "SetTags", 6usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).SetTags)(this, tag1, tag2), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextAntialiasMode(this: *mut core::ffi::c_void, textantialiasmode: D2D1_TEXT_ANTIALIAS_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "SetTextAntialiasMode", 7usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).SetTextAntialiasMode)(this, textantialiasmode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectWrite")]
+        unsafe extern "system" fn SetTextRenderingParams(this: *mut core::ffi::c_void, textrenderingparams: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "SetTextRenderingParams", 8usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).SetTextRenderingParams)(this, textrenderingparams), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetTransform(this: *mut core::ffi::c_void, transform: *const super::super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "SetTransform", 9usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).SetTransform)(this, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrimitiveBlend(this: *mut core::ffi::c_void, primitiveblend: D2D1_PRIMITIVE_BLEND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "SetPrimitiveBlend", 10usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).SetPrimitiveBlend)(this, primitiveblend), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnitMode(this: *mut core::ffi::c_void, unitmode: D2D1_UNIT_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "SetUnitMode", 11usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).SetUnitMode)(this, unitmode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void, color: *const Common::D2D1_COLOR_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "Clear", 12usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).Clear)(this, color), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn DrawGlyphRun(this: *mut core::ffi::c_void, baselineorigin: Common::D2D_POINT_2F, glyphrun: *const super::DirectWrite::DWRITE_GLYPH_RUN, glyphrundescription: *const super::DirectWrite::DWRITE_GLYPH_RUN_DESCRIPTION, foregroundbrush: *mut core::ffi::c_void, measuringmode: super::DirectWrite::DWRITE_MEASURING_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "DrawGlyphRun", 13usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).DrawGlyphRun)(this, baselineorigin, glyphrun, glyphrundescription, foregroundbrush, measuringmode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn DrawLine(this: *mut core::ffi::c_void, point0: Common::D2D_POINT_2F, point1: Common::D2D_POINT_2F, brush: *mut core::ffi::c_void, strokewidth: f32, strokestyle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "DrawLine", 14usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).DrawLine)(this, point0, point1, brush, strokewidth, strokestyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawGeometry(this: *mut core::ffi::c_void, geometry: *mut core::ffi::c_void, brush: *mut core::ffi::c_void, strokewidth: f32, strokestyle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "DrawGeometry", 15usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).DrawGeometry)(this, geometry, brush, strokewidth, strokestyle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn DrawRectangle(this: *mut core::ffi::c_void, rect: *const Common::D2D_RECT_F, brush: *mut core::ffi::c_void, strokewidth: f32, strokestyle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "DrawRectangle", 16usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).DrawRectangle)(this, rect, brush, strokewidth, strokestyle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn DrawBitmap(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, destinationrectangle: *const Common::D2D_RECT_F, opacity: f32, interpolationmode: D2D1_INTERPOLATION_MODE, sourcerectangle: *const Common::D2D_RECT_F, perspectivetransform: *const Common::D2D_MATRIX_4X4_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "DrawBitmap", 17usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).DrawBitmap)(this, bitmap, destinationrectangle, opacity, interpolationmode, sourcerectangle, perspectivetransform), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn DrawImage(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void, targetoffset: *const Common::D2D_POINT_2F, imagerectangle: *const Common::D2D_RECT_F, interpolationmode: D2D1_INTERPOLATION_MODE, compositemode: Common::D2D1_COMPOSITE_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "DrawImage", 18usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).DrawImage)(this, image, targetoffset, imagerectangle, interpolationmode, compositemode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn DrawGdiMetafile(this: *mut core::ffi::c_void, gdimetafile: *mut core::ffi::c_void, targetoffset: *const Common::D2D_POINT_2F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "DrawGdiMetafile", 19usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).DrawGdiMetafile)(this, gdimetafile, targetoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn FillMesh(this: *mut core::ffi::c_void, mesh: *mut core::ffi::c_void, brush: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "FillMesh", 20usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).FillMesh)(this, mesh, brush), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn FillOpacityMask(this: *mut core::ffi::c_void, opacitymask: *mut core::ffi::c_void, brush: *mut core::ffi::c_void, destinationrectangle: *const Common::D2D_RECT_F, sourcerectangle: *const Common::D2D_RECT_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "FillOpacityMask", 21usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).FillOpacityMask)(this, opacitymask, brush, destinationrectangle, sourcerectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn FillGeometry(this: *mut core::ffi::c_void, geometry: *mut core::ffi::c_void, brush: *mut core::ffi::c_void, opacitybrush: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "FillGeometry", 22usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).FillGeometry)(this, geometry, brush, opacitybrush), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn FillRectangle(this: *mut core::ffi::c_void, rect: *const Common::D2D_RECT_F, brush: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "FillRectangle", 23usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).FillRectangle)(this, rect, brush), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn PushAxisAlignedClip(this: *mut core::ffi::c_void, cliprect: *const Common::D2D_RECT_F, antialiasmode: D2D1_ANTIALIAS_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "PushAxisAlignedClip", 24usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).PushAxisAlignedClip)(this, cliprect, antialiasmode), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn PushLayer(this: *mut core::ffi::c_void, layerparameters1: *const D2D1_LAYER_PARAMETERS1, layer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "PushLayer", 25usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).PushLayer)(this, layerparameters1, layer), |result| Some(*result))
+        }
+        unsafe extern "system" fn PopAxisAlignedClip(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "PopAxisAlignedClip", 26usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).PopAxisAlignedClip)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PopLayer(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink", "PopLayer", 27usize, |this| ((**(this as *const *const ID2D1CommandSink_Vtbl)).PopLayer)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BeginDraw,
+            EndDraw,
+            SetAntialiasMode,
+            SetTags,
+            SetTextAntialiasMode,
+            #[cfg(feature = "Win32_Graphics_DirectWrite")]
+            SetTextRenderingParams,
+            #[cfg(not(feature = "Win32_Graphics_DirectWrite"))]
+            SetTextRenderingParams: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetTransform: 0,
+            SetPrimitiveBlend,
+            SetUnitMode,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            Clear,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            Clear: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+            DrawGlyphRun,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite")))]
+            DrawGlyphRun: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            DrawLine,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            DrawLine: 0,
+            DrawGeometry,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            DrawRectangle,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            DrawRectangle: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            DrawBitmap,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            DrawBitmap: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            DrawImage,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            DrawImage: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            DrawGdiMetafile,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            DrawGdiMetafile: 0,
+            FillMesh,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            FillOpacityMask,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            FillOpacityMask: 0,
+            FillGeometry,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            FillRectangle,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            FillRectangle: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            PushAxisAlignedClip,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            PushAxisAlignedClip: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            PushLayer,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            PushLayer: 0,
+            PopAxisAlignedClip,
+            PopLayer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1CommandSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+impl windows_core::imp::ProxyVtable for ID2D1CommandSink1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPrimitiveBlend1(this: *mut core::ffi::c_void, primitiveblend: D2D1_PRIMITIVE_BLEND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink1", "SetPrimitiveBlend1", 28usize, |this| ((**(this as *const *const ID2D1CommandSink1_Vtbl)).SetPrimitiveBlend1)(this, primitiveblend), |result| Some(*result))
+        }
+        Self { base__: <ID2D1CommandSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPrimitiveBlend1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1CommandSink1 as windows_core::Interface>::IID || <ID2D1CommandSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+impl windows_core::imp::ProxyVtable for ID2D1CommandSink2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DrawInk(this: *mut core::ffi::c_void, ink: *mut core::ffi::c_void, brush: *mut core::ffi::c_void, inkstyle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink2", "DrawInk", 29usize, |this| ((**(this as *const *const ID2D1CommandSink2_Vtbl)).DrawInk)(this, ink, brush, inkstyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawGradientMesh(this: *mut core::ffi::c_void, gradientmesh: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink2", "DrawGradientMesh", 30usize, |this| ((**(this as *const *const ID2D1CommandSink2_Vtbl)).DrawGradientMesh)(this, gradientmesh), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn DrawGdiMetafile(this: *mut core::ffi::c_void, gdimetafile: *mut core::ffi::c_void, destinationrectangle: *const Common::D2D_RECT_F, sourcerectangle: *const Common::D2D_RECT_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink2", "DrawGdiMetafile", 31usize, |this| ((**(this as *const *const ID2D1CommandSink2_Vtbl)).DrawGdiMetafile)(this, gdimetafile, destinationrectangle, sourcerectangle), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1CommandSink1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DrawInk,
+            DrawGradientMesh,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            DrawGdiMetafile,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            DrawGdiMetafile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1CommandSink2 as windows_core::Interface>::IID || <ID2D1CommandSink1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+impl windows_core::imp::ProxyVtable for ID2D1CommandSink3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DrawSpriteBatch(this: *mut core::ffi::c_void, spritebatch: *mut core::ffi::c_void, startindex: u32, spritecount: u32, bitmap: *mut core::ffi::c_void, interpolationmode: D2D1_BITMAP_INTERPOLATION_MODE, spriteoptions: D2D1_SPRITE_OPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink3", "DrawSpriteBatch", 32usize, |this| ((**(this as *const *const ID2D1CommandSink3_Vtbl)).DrawSpriteBatch)(this, spritebatch, startindex, spritecount, bitmap, interpolationmode, spriteoptions), |result| Some(*result))
+        }
+        Self { base__: <ID2D1CommandSink2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DrawSpriteBatch }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1CommandSink3 as windows_core::Interface>::IID || <ID2D1CommandSink2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+impl windows_core::imp::ProxyVtable for ID2D1CommandSink4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPrimitiveBlend2(this: *mut core::ffi::c_void, primitiveblend: D2D1_PRIMITIVE_BLEND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink4", "SetPrimitiveBlend2", 33usize, |this| ((**(this as *const *const ID2D1CommandSink4_Vtbl)).SetPrimitiveBlend2)(this, primitiveblend), |result| Some(*result))
+        }
+        Self { base__: <ID2D1CommandSink3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPrimitiveBlend2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1CommandSink4 as windows_core::Interface>::IID || <ID2D1CommandSink3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+impl windows_core::imp::ProxyVtable for ID2D1CommandSink5_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn BlendImage(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void, blendmode: Common::D2D1_BLEND_MODE, targetoffset: *const Common::D2D_POINT_2F, imagerectangle: *const Common::D2D_RECT_F, interpolationmode: D2D1_INTERPOLATION_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1CommandSink5", "BlendImage", 34usize, |this| ((**(this as *const *const ID2D1CommandSink5_Vtbl)).BlendImage)(this, image, blendmode, targetoffset, imagerectangle, interpolationmode), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1CommandSink4_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            BlendImage,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            BlendImage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1CommandSink5 as windows_core::Interface>::IID || <ID2D1CommandSink4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1ComputeInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetComputeShaderConstantBuffer(this: *mut core::ffi::c_void, buffer: *const u8, buffercount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1ComputeInfo", "SetComputeShaderConstantBuffer", 7usize, |this| ((**(this as *const *const ID2D1ComputeInfo_Vtbl)).SetComputeShaderConstantBuffer)(this, buffer, buffercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComputeShader(this: *mut core::ffi::c_void, shaderid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1ComputeInfo", "SetComputeShader", 8usize, |this| ((**(this as *const *const ID2D1ComputeInfo_Vtbl)).SetComputeShader)(this, shaderid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResourceTexture(this: *mut core::ffi::c_void, textureindex: u32, resourcetexture: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1ComputeInfo", "SetResourceTexture", 9usize, |this| ((**(this as *const *const ID2D1ComputeInfo_Vtbl)).SetResourceTexture)(this, textureindex, resourcetexture), |result| Some(*result))
+        }
+        Self { base__: <ID2D1RenderInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetComputeShaderConstantBuffer, SetComputeShader, SetResourceTexture }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1ComputeInfo as windows_core::Interface>::IID || <ID2D1RenderInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1ComputeTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetComputeInfo(this: *mut core::ffi::c_void, computeinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1ComputeTransform", "SetComputeInfo", 7usize, |this| ((**(this as *const *const ID2D1ComputeTransform_Vtbl)).SetComputeInfo)(this, computeinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn CalculateThreadgroups(this: *mut core::ffi::c_void, outputrect: *const super::super::Foundation::RECT, dimensionx: *mut u32, dimensiony: *mut u32, dimensionz: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1ComputeTransform", "CalculateThreadgroups", 8usize, |this| ((**(this as *const *const ID2D1ComputeTransform_Vtbl)).CalculateThreadgroups)(this, outputrect, dimensionx, dimensiony, dimensionz), |result| Some(*result))
+        }
+        Self { base__: <ID2D1Transform_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetComputeInfo, CalculateThreadgroups }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1ComputeTransform as windows_core::Interface>::IID || <ID2D1Transform_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1ConcreteTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOutputBuffer(this: *mut core::ffi::c_void, bufferprecision: D2D1_BUFFER_PRECISION, channeldepth: D2D1_CHANNEL_DEPTH) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1ConcreteTransform", "SetOutputBuffer", 4usize, |this| ((**(this as *const *const ID2D1ConcreteTransform_Vtbl)).SetOutputBuffer)(this, bufferprecision, channeldepth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCached(this: *mut core::ffi::c_void, iscached: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID2D1ConcreteTransform", "SetCached", 5usize, |this| ((**(this as *const *const ID2D1ConcreteTransform_Vtbl)).SetCached)(this, iscached), |_| None)
+        }
+        Self { base__: <ID2D1TransformNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetOutputBuffer, SetCached }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1ConcreteTransform as windows_core::Interface>::IID || <ID2D1TransformNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi", feature = "Win32_Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ID2D1DCRenderTarget_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn BindDC(this: *mut core::ffi::c_void, hdc: super::Gdi::HDC, psubrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DCRenderTarget", "BindDC", 57usize, |this| ((**(this as *const *const ID2D1DCRenderTarget_Vtbl)).BindDC)(this, hdc, psubrect), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1RenderTarget_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            BindDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            BindDC: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1DCRenderTarget as windows_core::Interface>::IID || <ID2D1RenderTarget_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Imaging", feature = "Win32_Storage_Xps_Printing"))]
+impl windows_core::imp::ProxyVtable for ID2D1Device_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDeviceContext(this: *mut core::ffi::c_void, options: D2D1_DEVICE_CONTEXT_OPTIONS, devicecontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Device", "CreateDeviceContext", 4usize, |this| ((**(this as *const *const ID2D1Device_Vtbl)).CreateDeviceContext)(this, options, devicecontext), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Imaging", feature = "Win32_Storage_Xps_Printing"))]
+        unsafe extern "system" fn CreatePrintControl(this: *mut core::ffi::c_void, wicfactory: *mut core::ffi::c_void, documenttarget: *mut core::ffi::c_void, printcontrolproperties: *const D2D1_PRINT_CONTROL_PROPERTIES, printcontrol: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Device", "CreatePrintControl", 5usize, |this| ((**(this as *const *const ID2D1Device_Vtbl)).CreatePrintControl)(this, wicfactory, documenttarget, printcontrolproperties, printcontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaximumTextureMemory(this: *mut core::ffi::c_void, maximuminbytes: u64) {
+            windows_core::imp::proxy_call(this, "ID2D1Device", "SetMaximumTextureMemory", 6usize, |this| ((**(this as *const *const ID2D1Device_Vtbl)).SetMaximumTextureMemory)(this, maximuminbytes), |_| None)
+        }
+        unsafe extern "system" fn GetMaximumTextureMemory(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID2D1Device", "GetMaximumTextureMemory", 7usize, |this| ((**(this as *const *const ID2D1Device_Vtbl)).GetMaximumTextureMemory)(this), |_| None)
+        }
+        unsafe extern "system" fn ClearResources(this: *mut core::ffi::c_void, millisecondssinceuse: u32) {
+            windows_core::imp::proxy_call(this, "ID2D1Device", "ClearResources", 8usize, |this| ((**(this as *const *const ID2D1Device_Vtbl)).ClearResources)(this, millisecondssinceuse), |_| None)
+        }
+        Self {
+            base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateDeviceContext,
+            #[cfg(all(feature = "Win32_Graphics_Imaging", feature = "Win32_Storage_Xps_Printing"))]
+            CreatePrintControl,
+            #[cfg(not(all(feature = "Win32_Graphics_Imaging", feature = "Win32_Storage_Xps_Printing")))]
+            CreatePrintControl: 0,
+            SetMaximumTextureMemory,
+            GetMaximumTextureMemory,
+            ClearResources,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Device as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Imaging", feature = "Win32_Storage_Xps_Printing"))]
+impl windows_core::imp::ProxyVtable for ID2D1Device1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRenderingPriority(this: *mut core::ffi::c_void) -> D2D1_RENDERING_PRIORITY {
+            windows_core::imp::proxy_call(this, "ID2D1Device1", "GetRenderingPriority", 9usize, |this| ((**(this as *const *const ID2D1Device1_Vtbl)).GetRenderingPriority)(this), |_| None)
+        }
+        unsafe extern "system" fn SetRenderingPriority(this: *mut core::ffi::c_void, renderingpriority: D2D1_RENDERING_PRIORITY) {
+            windows_core::imp::proxy_call(this, "ID2D1Device1", "SetRenderingPriority", 10usize, |this| ((**(this as *const *const ID2D1Device1_Vtbl)).SetRenderingPriority)(this, renderingpriority), |_| None)
+        }
+        unsafe extern "system" fn CreateDeviceContext(this: *mut core::ffi::c_void, options: D2D1_DEVICE_CONTEXT_OPTIONS, devicecontext1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Device1", "CreateDeviceContext", 11usize, |this| ((**(this as *const *const ID2D1Device1_Vtbl)).CreateDeviceContext)(this, options, devicecontext1), |result| Some(*result))
+        }
+        Self { base__: <ID2D1Device_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRenderingPriority, SetRenderingPriority, CreateDeviceContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Device1 as windows_core::Interface>::IID || <ID2D1Device_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi", feature = "Win32_Graphics_Imaging", feature = "Win32_Storage_Xps_Printing"))]
+impl windows_core::imp::ProxyVtable for ID2D1Device2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDeviceContext(this: *mut core::ffi::c_void, options: D2D1_DEVICE_CONTEXT_OPTIONS, devicecontext2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Device2", "CreateDeviceContext", 12usize, |this| ((**(this as *const *const ID2D1Device2_Vtbl)).CreateDeviceContext)(this, options, devicecontext2), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushDeviceContexts(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1Device2", "FlushDeviceContexts", 13usize, |this| ((**(this as *const *const ID2D1Device2_Vtbl)).FlushDeviceContexts)(this, bitmap), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn GetDxgiDevice(this: *mut core::ffi::c_void, dxgidevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Device2", "GetDxgiDevice", 14usize, |this| ((**(this as *const *const ID2D1Device2_Vtbl)).GetDxgiDevice)(this, dxgidevice), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Device1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateDeviceContext,
+            FlushDeviceContexts,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            GetDxgiDevice,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            GetDxgiDevice: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Device2 as windows_core::Interface>::IID || <ID2D1Device1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi", feature = "Win32_Graphics_Imaging", feature = "Win32_Storage_Xps_Printing"))]
+impl windows_core::imp::ProxyVtable for ID2D1Device3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDeviceContext(this: *mut core::ffi::c_void, options: D2D1_DEVICE_CONTEXT_OPTIONS, devicecontext3: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Device3", "CreateDeviceContext", 15usize, |this| ((**(this as *const *const ID2D1Device3_Vtbl)).CreateDeviceContext)(this, options, devicecontext3), |result| Some(*result))
+        }
+        Self { base__: <ID2D1Device2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateDeviceContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Device3 as windows_core::Interface>::IID || <ID2D1Device2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi", feature = "Win32_Graphics_Imaging", feature = "Win32_Storage_Xps_Printing"))]
+impl windows_core::imp::ProxyVtable for ID2D1Device4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDeviceContext(this: *mut core::ffi::c_void, options: D2D1_DEVICE_CONTEXT_OPTIONS, devicecontext4: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Device4", "CreateDeviceContext", 16usize, |this| ((**(this as *const *const ID2D1Device4_Vtbl)).CreateDeviceContext)(this, options, devicecontext4), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaximumColorGlyphCacheMemory(this: *mut core::ffi::c_void, maximuminbytes: u64) {
+            windows_core::imp::proxy_call(this, "ID2D1Device4", "SetMaximumColorGlyphCacheMemory", 17usize, |this| ((**(this as *const *const ID2D1Device4_Vtbl)).SetMaximumColorGlyphCacheMemory)(this, maximuminbytes), |_| None)
+        }
+        unsafe extern "system" fn GetMaximumColorGlyphCacheMemory(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID2D1Device4", "GetMaximumColorGlyphCacheMemory", 18usize, |this| ((**(this as *const *const ID2D1Device4_Vtbl)).GetMaximumColorGlyphCacheMemory)(this), |_| None)
+        }
+        Self {
+            base__: <ID2D1Device3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateDeviceContext,
+            SetMaximumColorGlyphCacheMemory,
+            GetMaximumColorGlyphCacheMemory,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Device4 as windows_core::Interface>::IID || <ID2D1Device3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi", feature = "Win32_Graphics_Imaging", feature = "Win32_Storage_Xps_Printing"))]
+impl windows_core::imp::ProxyVtable for ID2D1Device5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDeviceContext(this: *mut core::ffi::c_void, options: D2D1_DEVICE_CONTEXT_OPTIONS, devicecontext5: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Device5", "CreateDeviceContext", 19usize, |this| ((**(this as *const *const ID2D1Device5_Vtbl)).CreateDeviceContext)(this, options, devicecontext5), |result| Some(*result))
+        }
+        Self { base__: <ID2D1Device4_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateDeviceContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Device5 as windows_core::Interface>::IID || <ID2D1Device4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi", feature = "Win32_Graphics_Imaging", feature = "Win32_Storage_Xps_Printing"))]
+impl windows_core::imp::ProxyVtable for ID2D1Device6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDeviceContext(this: *mut core::ffi::c_void, options: D2D1_DEVICE_CONTEXT_OPTIONS, devicecontext6: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Device6", "CreateDeviceContext", 20usize, |this| ((**(this as *const *const ID2D1Device6_Vtbl)).CreateDeviceContext)(this, options, devicecontext6), |result| Some(*result))
+        }
+        Self { base__: <ID2D1Device5_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateDeviceContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Device6 as windows_core::Interface>::IID || <ID2D1Device5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi", feature = "Win32_Graphics_Imaging", feature = "Win32_Storage_Xps_Printing"))]
+impl windows_core::imp::ProxyVtable for ID2D1Device7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDeviceContext(this: *mut core::ffi::c_void, options: D2D1_DEVICE_CONTEXT_OPTIONS, devicecontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Device7", "CreateDeviceContext", 21usize, |this| ((**(this as *const *const ID2D1Device7_Vtbl)).CreateDeviceContext)(this, options, devicecontext), |result| Some(*result))
+        }
+        Self { base__: <ID2D1Device6_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateDeviceContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Device7 as windows_core::Interface>::IID || <ID2D1Device6_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ID2D1DeviceContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateBitmap(this: *mut core::ffi::c_void, size: Common::D2D_SIZE_U, sourcedata: *const core::ffi::c_void, pitch: u32, bitmapproperties: *const D2D1_BITMAP_PROPERTIES1, bitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "CreateBitmap", 57usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).CreateBitmap)(this, size, sourcedata, pitch, bitmapproperties, bitmap), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+        unsafe extern "system" fn CreateBitmapFromWicBitmap(this: *mut core::ffi::c_void, wicbitmapsource: *mut core::ffi::c_void, bitmapproperties: *const D2D1_BITMAP_PROPERTIES1, bitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "CreateBitmapFromWicBitmap", 58usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).CreateBitmapFromWicBitmap)(this, wicbitmapsource, bitmapproperties, bitmap), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateColorContext(this: *mut core::ffi::c_void, space: D2D1_COLOR_SPACE, profile: *const u8, profilesize: u32, colorcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "CreateColorContext", 59usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).CreateColorContext)(this, space, profile, profilesize, colorcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateColorContextFromFilename(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, colorcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "CreateColorContextFromFilename", 60usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).CreateColorContextFromFilename)(this, filename, colorcontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Imaging")]
+        unsafe extern "system" fn CreateColorContextFromWicColorContext(this: *mut core::ffi::c_void, wiccolorcontext: *mut core::ffi::c_void, colorcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "CreateColorContextFromWicColorContext", 61usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).CreateColorContextFromWicColorContext)(this, wiccolorcontext, colorcontext), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateBitmapFromDxgiSurface(this: *mut core::ffi::c_void, surface: *mut core::ffi::c_void, bitmapproperties: *const D2D1_BITMAP_PROPERTIES1, bitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "CreateBitmapFromDxgiSurface", 62usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).CreateBitmapFromDxgiSurface)(this, surface, bitmapproperties, bitmap), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEffect(this: *mut core::ffi::c_void, effectid: *const windows_core::GUID, effect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "CreateEffect", 63usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).CreateEffect)(this, effectid, effect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CreateGradientStopCollection(this: *mut core::ffi::c_void, straightalphagradientstops: *const Common::D2D1_GRADIENT_STOP, straightalphagradientstopscount: u32, preinterpolationspace: D2D1_COLOR_SPACE, postinterpolationspace: D2D1_COLOR_SPACE, bufferprecision: D2D1_BUFFER_PRECISION, extendmode: D2D1_EXTEND_MODE, colorinterpolationmode: D2D1_COLOR_INTERPOLATION_MODE, gradientstopcollection1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "CreateGradientStopCollection", 64usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).CreateGradientStopCollection)(this, straightalphagradientstops, straightalphagradientstopscount, preinterpolationspace, postinterpolationspace, bufferprecision, extendmode, colorinterpolationmode, gradientstopcollection1), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn CreateImageBrush(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void, imagebrushproperties: *const D2D1_IMAGE_BRUSH_PROPERTIES, brushproperties: *const D2D1_BRUSH_PROPERTIES, imagebrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "CreateImageBrush", 65usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).CreateImageBrush)(this, image, imagebrushproperties, brushproperties, imagebrush), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CreateBitmapBrush(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, bitmapbrushproperties: *const D2D1_BITMAP_BRUSH_PROPERTIES1, brushproperties: *const D2D1_BRUSH_PROPERTIES, bitmapbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "CreateBitmapBrush", 66usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).CreateBitmapBrush)(this, bitmap, bitmapbrushproperties, brushproperties, bitmapbrush), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCommandList(this: *mut core::ffi::c_void, commandlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "CreateCommandList", 67usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).CreateCommandList)(this, commandlist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn IsDxgiFormatSupported(this: *mut core::ffi::c_void, format: super::Dxgi::Common::DXGI_FORMAT) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "IsDxgiFormatSupported", 68usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).IsDxgiFormatSupported)(this, format), |_| None)
+        }
+        unsafe extern "system" fn IsBufferPrecisionSupported(this: *mut core::ffi::c_void, bufferprecision: D2D1_BUFFER_PRECISION) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "IsBufferPrecisionSupported", 69usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).IsBufferPrecisionSupported)(this, bufferprecision), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetImageLocalBounds(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void, localbounds: *mut Common::D2D_RECT_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "GetImageLocalBounds", 70usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).GetImageLocalBounds)(this, image, localbounds), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetImageWorldBounds(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void, worldbounds: *mut Common::D2D_RECT_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "GetImageWorldBounds", 71usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).GetImageWorldBounds)(this, image, worldbounds), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn GetGlyphRunWorldBounds(this: *mut core::ffi::c_void, baselineorigin: Common::D2D_POINT_2F, glyphrun: *const super::DirectWrite::DWRITE_GLYPH_RUN, measuringmode: super::DirectWrite::DWRITE_MEASURING_MODE, bounds: *mut Common::D2D_RECT_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "GetGlyphRunWorldBounds", 72usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).GetGlyphRunWorldBounds)(this, baselineorigin, glyphrun, measuringmode, bounds), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, device: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "GetDevice", 73usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).GetDevice)(this, device), |_| None)
+        }
+        unsafe extern "system" fn SetTarget(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "SetTarget", 74usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).SetTarget)(this, image), |_| None)
+        }
+        unsafe extern "system" fn GetTarget(this: *mut core::ffi::c_void, image: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "GetTarget", 75usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).GetTarget)(this, image), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetRenderingControls(this: *mut core::ffi::c_void, renderingcontrols: *const D2D1_RENDERING_CONTROLS) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "SetRenderingControls", 76usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).SetRenderingControls)(this, renderingcontrols), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetRenderingControls(this: *mut core::ffi::c_void, renderingcontrols: *mut D2D1_RENDERING_CONTROLS) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "GetRenderingControls", 77usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).GetRenderingControls)(this, renderingcontrols), |_| None)
+        }
+        unsafe extern "system" fn SetPrimitiveBlend(this: *mut core::ffi::c_void, primitiveblend: D2D1_PRIMITIVE_BLEND) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "SetPrimitiveBlend", 78usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).SetPrimitiveBlend)(this, primitiveblend), |_| None)
+        }
+        unsafe extern "system" fn GetPrimitiveBlend(this: *mut core::ffi::c_void) -> D2D1_PRIMITIVE_BLEND {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "GetPrimitiveBlend", 79usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).GetPrimitiveBlend)(this), |_| None)
+        }
+        unsafe extern "system" fn SetUnitMode(this: *mut core::ffi::c_void, unitmode: D2D1_UNIT_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "SetUnitMode", 80usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).SetUnitMode)(this, unitmode), |_| None)
+        }
+        unsafe extern "system" fn GetUnitMode(this: *mut core::ffi::c_void) -> D2D1_UNIT_MODE {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "GetUnitMode", 81usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).GetUnitMode)(this), |_| None)
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn DrawGlyphRun(this: *mut core::ffi::c_void, baselineorigin: Common::D2D_POINT_2F, glyphrun: *const super::DirectWrite::DWRITE_GLYPH_RUN, glyphrundescription: *const super::DirectWrite::DWRITE_GLYPH_RUN_DESCRIPTION, foregroundbrush: *mut core::ffi::c_void, measuringmode: super::DirectWrite::DWRITE_MEASURING_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "DrawGlyphRun", 82usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).DrawGlyphRun)(this, baselineorigin, glyphrun, glyphrundescription, foregroundbrush, measuringmode), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn DrawImage(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void, targetoffset: *const Common::D2D_POINT_2F, imagerectangle: *const Common::D2D_RECT_F, interpolationmode: D2D1_INTERPOLATION_MODE, compositemode: Common::D2D1_COMPOSITE_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "DrawImage", 83usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).DrawImage)(this, image, targetoffset, imagerectangle, interpolationmode, compositemode), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn DrawGdiMetafile(this: *mut core::ffi::c_void, gdimetafile: *mut core::ffi::c_void, targetoffset: *const Common::D2D_POINT_2F) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "DrawGdiMetafile", 84usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).DrawGdiMetafile)(this, gdimetafile, targetoffset), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn DrawBitmap(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, destinationrectangle: *const Common::D2D_RECT_F, opacity: f32, interpolationmode: D2D1_INTERPOLATION_MODE, sourcerectangle: *const Common::D2D_RECT_F, perspectivetransform: *const Common::D2D_MATRIX_4X4_F) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "DrawBitmap", 85usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).DrawBitmap)(this, bitmap, destinationrectangle, opacity, interpolationmode, sourcerectangle, perspectivetransform), |_| None)
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn PushLayer(this: *mut core::ffi::c_void, layerparameters: *const D2D1_LAYER_PARAMETERS1, layer: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "PushLayer", 86usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).PushLayer)(this, layerparameters, layer), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn InvalidateEffectInputRectangle(this: *mut core::ffi::c_void, effect: *mut core::ffi::c_void, input: u32, inputrectangle: *const Common::D2D_RECT_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "InvalidateEffectInputRectangle", 87usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).InvalidateEffectInputRectangle)(this, effect, input, inputrectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEffectInvalidRectangleCount(this: *mut core::ffi::c_void, effect: *mut core::ffi::c_void, rectanglecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "GetEffectInvalidRectangleCount", 88usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).GetEffectInvalidRectangleCount)(this, effect, rectanglecount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetEffectInvalidRectangles(this: *mut core::ffi::c_void, effect: *mut core::ffi::c_void, rectangles: *mut Common::D2D_RECT_F, rectanglescount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "GetEffectInvalidRectangles", 89usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).GetEffectInvalidRectangles)(this, effect, rectangles, rectanglescount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetEffectRequiredInputRectangles(this: *mut core::ffi::c_void, rendereffect: *mut core::ffi::c_void, renderimagerectangle: *const Common::D2D_RECT_F, inputdescriptions: *const D2D1_EFFECT_INPUT_DESCRIPTION, requiredinputrects: *mut Common::D2D_RECT_F, inputcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "GetEffectRequiredInputRectangles", 90usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).GetEffectRequiredInputRectangles)(this, rendereffect, renderimagerectangle, inputdescriptions, requiredinputrects, inputcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn FillOpacityMask(this: *mut core::ffi::c_void, opacitymask: *mut core::ffi::c_void, brush: *mut core::ffi::c_void, destinationrectangle: *const Common::D2D_RECT_F, sourcerectangle: *const Common::D2D_RECT_F) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext", "FillOpacityMask", 91usize, |this| ((**(this as *const *const ID2D1DeviceContext_Vtbl)).FillOpacityMask)(this, opacitymask, brush, destinationrectangle, sourcerectangle), |_| None)
+        }
+        Self {
+            base__: <ID2D1RenderTarget_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateBitmap,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateBitmap: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+            CreateBitmapFromWicBitmap,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging")))]
+            CreateBitmapFromWicBitmap: 0,
+            CreateColorContext,
+            CreateColorContextFromFilename,
+            #[cfg(feature = "Win32_Graphics_Imaging")]
+            CreateColorContextFromWicColorContext,
+            #[cfg(not(feature = "Win32_Graphics_Imaging"))]
+            CreateColorContextFromWicColorContext: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateBitmapFromDxgiSurface,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateBitmapFromDxgiSurface: 0,
+            CreateEffect,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CreateGradientStopCollection,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CreateGradientStopCollection: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            CreateImageBrush,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            CreateImageBrush: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            CreateBitmapBrush,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CreateBitmapBrush: 0,
+            CreateCommandList,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            IsDxgiFormatSupported,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            IsDxgiFormatSupported: 0,
+            IsBufferPrecisionSupported,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetImageLocalBounds,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetImageLocalBounds: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetImageWorldBounds,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetImageWorldBounds: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+            GetGlyphRunWorldBounds,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite")))]
+            GetGlyphRunWorldBounds: 0,
+            GetDevice,
+            SetTarget,
+            GetTarget,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetRenderingControls,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetRenderingControls: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetRenderingControls,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetRenderingControls: 0,
+            SetPrimitiveBlend,
+            GetPrimitiveBlend,
+            SetUnitMode,
+            GetUnitMode,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+            DrawGlyphRun,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite")))]
+            DrawGlyphRun: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            DrawImage,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            DrawImage: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            DrawGdiMetafile,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            DrawGdiMetafile: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            DrawBitmap,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            DrawBitmap: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            PushLayer,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            PushLayer: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            InvalidateEffectInputRectangle,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            InvalidateEffectInputRectangle: 0,
+            GetEffectInvalidRectangleCount,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetEffectInvalidRectangles,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetEffectInvalidRectangles: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetEffectRequiredInputRectangles,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetEffectRequiredInputRectangles: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            FillOpacityMask,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            FillOpacityMask: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1DeviceContext as windows_core::Interface>::IID || <ID2D1RenderTarget_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ID2D1DeviceContext1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFilledGeometryRealization(this: *mut core::ffi::c_void, geometry: *mut core::ffi::c_void, flatteningtolerance: f32, geometryrealization: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext1", "CreateFilledGeometryRealization", 92usize, |this| ((**(this as *const *const ID2D1DeviceContext1_Vtbl)).CreateFilledGeometryRealization)(this, geometry, flatteningtolerance, geometryrealization), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStrokedGeometryRealization(this: *mut core::ffi::c_void, geometry: *mut core::ffi::c_void, flatteningtolerance: f32, strokewidth: f32, strokestyle: *mut core::ffi::c_void, geometryrealization: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext1", "CreateStrokedGeometryRealization", 93usize, |this| ((**(this as *const *const ID2D1DeviceContext1_Vtbl)).CreateStrokedGeometryRealization)(this, geometry, flatteningtolerance, strokewidth, strokestyle, geometryrealization), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawGeometryRealization(this: *mut core::ffi::c_void, geometryrealization: *mut core::ffi::c_void, brush: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext1", "DrawGeometryRealization", 94usize, |this| ((**(this as *const *const ID2D1DeviceContext1_Vtbl)).DrawGeometryRealization)(this, geometryrealization, brush), |_| None)
+        }
+        Self {
+            base__: <ID2D1DeviceContext_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFilledGeometryRealization,
+            CreateStrokedGeometryRealization,
+            DrawGeometryRealization,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1DeviceContext1 as windows_core::Interface>::IID || <ID2D1DeviceContext_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ID2D1DeviceContext2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInk(this: *mut core::ffi::c_void, startpoint: *const D2D1_INK_POINT, ink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext2", "CreateInk", 95usize, |this| ((**(this as *const *const ID2D1DeviceContext2_Vtbl)).CreateInk)(this, startpoint, ink), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CreateInkStyle(this: *mut core::ffi::c_void, inkstyleproperties: *const D2D1_INK_STYLE_PROPERTIES, inkstyle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext2", "CreateInkStyle", 96usize, |this| ((**(this as *const *const ID2D1DeviceContext2_Vtbl)).CreateInkStyle)(this, inkstyleproperties, inkstyle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CreateGradientMesh(this: *mut core::ffi::c_void, patches: *const D2D1_GRADIENT_MESH_PATCH, patchescount: u32, gradientmesh: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext2", "CreateGradientMesh", 97usize, |this| ((**(this as *const *const ID2D1DeviceContext2_Vtbl)).CreateGradientMesh)(this, patches, patchescount, gradientmesh), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Imaging"))]
+        unsafe extern "system" fn CreateImageSourceFromWic(this: *mut core::ffi::c_void, wicbitmapsource: *mut core::ffi::c_void, loadingoptions: D2D1_IMAGE_SOURCE_LOADING_OPTIONS, alphamode: Common::D2D1_ALPHA_MODE, imagesource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext2", "CreateImageSourceFromWic", 98usize, |this| ((**(this as *const *const ID2D1DeviceContext2_Vtbl)).CreateImageSourceFromWic)(this, wicbitmapsource, loadingoptions, alphamode, imagesource), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLookupTable3D(this: *mut core::ffi::c_void, precision: D2D1_BUFFER_PRECISION, extents: *const u32, data: *const u8, datacount: u32, strides: *const u32, lookuptable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext2", "CreateLookupTable3D", 99usize, |this| ((**(this as *const *const ID2D1DeviceContext2_Vtbl)).CreateLookupTable3D)(this, precision, extents, data, datacount, strides, lookuptable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateImageSourceFromDxgi(this: *mut core::ffi::c_void, surfaces: *const *mut core::ffi::c_void, surfacecount: u32, colorspace: super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE, options: D2D1_IMAGE_SOURCE_FROM_DXGI_OPTIONS, imagesource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext2", "CreateImageSourceFromDxgi", 100usize, |this| ((**(this as *const *const ID2D1DeviceContext2_Vtbl)).CreateImageSourceFromDxgi)(this, surfaces, surfacecount, colorspace, options, imagesource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetGradientMeshWorldBounds(this: *mut core::ffi::c_void, gradientmesh: *mut core::ffi::c_void, pbounds: *mut Common::D2D_RECT_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext2", "GetGradientMeshWorldBounds", 101usize, |this| ((**(this as *const *const ID2D1DeviceContext2_Vtbl)).GetGradientMeshWorldBounds)(this, gradientmesh, pbounds), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawInk(this: *mut core::ffi::c_void, ink: *mut core::ffi::c_void, brush: *mut core::ffi::c_void, inkstyle: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext2", "DrawInk", 102usize, |this| ((**(this as *const *const ID2D1DeviceContext2_Vtbl)).DrawInk)(this, ink, brush, inkstyle), |_| None)
+        }
+        unsafe extern "system" fn DrawGradientMesh(this: *mut core::ffi::c_void, gradientmesh: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext2", "DrawGradientMesh", 103usize, |this| ((**(this as *const *const ID2D1DeviceContext2_Vtbl)).DrawGradientMesh)(this, gradientmesh), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn DrawGdiMetafile(this: *mut core::ffi::c_void, gdimetafile: *mut core::ffi::c_void, destinationrectangle: *const Common::D2D_RECT_F, sourcerectangle: *const Common::D2D_RECT_F) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext2", "DrawGdiMetafile", 104usize, |this| ((**(this as *const *const ID2D1DeviceContext2_Vtbl)).DrawGdiMetafile)(this, gdimetafile, destinationrectangle, sourcerectangle), |_| None)
+        }
+        unsafe extern "system" fn CreateTransformedImageSource(this: *mut core::ffi::c_void, imagesource: *mut core::ffi::c_void, properties: *const D2D1_TRANSFORMED_IMAGE_SOURCE_PROPERTIES, transformedimagesource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext2", "CreateTransformedImageSource", 105usize, |this| ((**(this as *const *const ID2D1DeviceContext2_Vtbl)).CreateTransformedImageSource)(this, imagesource, properties, transformedimagesource), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1DeviceContext1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateInk,
+            #[cfg(feature = "Foundation_Numerics")]
+            CreateInkStyle,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CreateInkStyle: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CreateGradientMesh,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CreateGradientMesh: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Imaging"))]
+            CreateImageSourceFromWic,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Imaging")))]
+            CreateImageSourceFromWic: 0,
+            CreateLookupTable3D,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateImageSourceFromDxgi,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateImageSourceFromDxgi: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetGradientMeshWorldBounds,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetGradientMeshWorldBounds: 0,
+            DrawInk,
+            DrawGradientMesh,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            DrawGdiMetafile,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            DrawGdiMetafile: 0,
+            CreateTransformedImageSource,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1DeviceContext2 as windows_core::Interface>::IID || <ID2D1DeviceContext1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ID2D1DeviceContext3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSpriteBatch(this: *mut core::ffi::c_void, spritebatch: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext3", "CreateSpriteBatch", 106usize, |this| ((**(this as *const *const ID2D1DeviceContext3_Vtbl)).CreateSpriteBatch)(this, spritebatch), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawSpriteBatch(this: *mut core::ffi::c_void, spritebatch: *mut core::ffi::c_void, startindex: u32, spritecount: u32, bitmap: *mut core::ffi::c_void, interpolationmode: D2D1_BITMAP_INTERPOLATION_MODE, spriteoptions: D2D1_SPRITE_OPTIONS) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext3", "DrawSpriteBatch", 107usize, |this| ((**(this as *const *const ID2D1DeviceContext3_Vtbl)).DrawSpriteBatch)(this, spritebatch, startindex, spritecount, bitmap, interpolationmode, spriteoptions), |_| None)
+        }
+        Self { base__: <ID2D1DeviceContext2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSpriteBatch, DrawSpriteBatch }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1DeviceContext3 as windows_core::Interface>::IID || <ID2D1DeviceContext2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ID2D1DeviceContext4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSvgGlyphStyle(this: *mut core::ffi::c_void, svgglyphstyle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext4", "CreateSvgGlyphStyle", 108usize, |this| ((**(this as *const *const ID2D1DeviceContext4_Vtbl)).CreateSvgGlyphStyle)(this, svgglyphstyle), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn DrawText(this: *mut core::ffi::c_void, string: windows_core::PCWSTR, stringlength: u32, textformat: *mut core::ffi::c_void, layoutrect: *const Common::D2D_RECT_F, defaultfillbrush: *mut core::ffi::c_void, svgglyphstyle: *mut core::ffi::c_void, colorpaletteindex: u32, options: D2D1_DRAW_TEXT_OPTIONS, measuringmode: super::DirectWrite::DWRITE_MEASURING_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext4", "DrawText", 109usize, |this| ((**(this as *const *const ID2D1DeviceContext4_Vtbl)).DrawText)(this, string, stringlength, textformat, layoutrect, defaultfillbrush, svgglyphstyle, colorpaletteindex, options, measuringmode), |_| None)
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn DrawTextLayout(this: *mut core::ffi::c_void, origin: Common::D2D_POINT_2F, textlayout: *mut core::ffi::c_void, defaultfillbrush: *mut core::ffi::c_void, svgglyphstyle: *mut core::ffi::c_void, colorpaletteindex: u32, options: D2D1_DRAW_TEXT_OPTIONS) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext4", "DrawTextLayout", 110usize, |this| ((**(this as *const *const ID2D1DeviceContext4_Vtbl)).DrawTextLayout)(this, origin, textlayout, defaultfillbrush, svgglyphstyle, colorpaletteindex, options), |_| None)
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn DrawColorBitmapGlyphRun(this: *mut core::ffi::c_void, glyphimageformat: super::DirectWrite::DWRITE_GLYPH_IMAGE_FORMATS, baselineorigin: Common::D2D_POINT_2F, glyphrun: *const super::DirectWrite::DWRITE_GLYPH_RUN, measuringmode: super::DirectWrite::DWRITE_MEASURING_MODE, bitmapsnapoption: D2D1_COLOR_BITMAP_GLYPH_SNAP_OPTION) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext4", "DrawColorBitmapGlyphRun", 111usize, |this| ((**(this as *const *const ID2D1DeviceContext4_Vtbl)).DrawColorBitmapGlyphRun)(this, glyphimageformat, baselineorigin, glyphrun, measuringmode, bitmapsnapoption), |_| None)
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn DrawSvgGlyphRun(this: *mut core::ffi::c_void, baselineorigin: Common::D2D_POINT_2F, glyphrun: *const super::DirectWrite::DWRITE_GLYPH_RUN, defaultfillbrush: *mut core::ffi::c_void, svgglyphstyle: *mut core::ffi::c_void, colorpaletteindex: u32, measuringmode: super::DirectWrite::DWRITE_MEASURING_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext4", "DrawSvgGlyphRun", 112usize, |this| ((**(this as *const *const ID2D1DeviceContext4_Vtbl)).DrawSvgGlyphRun)(this, baselineorigin, glyphrun, defaultfillbrush, svgglyphstyle, colorpaletteindex, measuringmode), |_| None)
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn GetColorBitmapGlyphImage(this: *mut core::ffi::c_void, glyphimageformat: super::DirectWrite::DWRITE_GLYPH_IMAGE_FORMATS, glyphorigin: Common::D2D_POINT_2F, fontface: *mut core::ffi::c_void, fontemsize: f32, glyphindex: u16, issideways: super::super::Foundation::BOOL, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, dpix: f32, dpiy: f32, glyphtransform: *mut super::super::super::Foundation::Numerics::Matrix3x2, glyphimage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext4", "GetColorBitmapGlyphImage", 113usize, |this| ((**(this as *const *const ID2D1DeviceContext4_Vtbl)).GetColorBitmapGlyphImage)(this, glyphimageformat, glyphorigin, fontface, fontemsize, glyphindex, issideways, worldtransform, dpix, dpiy, glyphtransform, glyphimage), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn GetSvgGlyphImage(this: *mut core::ffi::c_void, glyphorigin: Common::D2D_POINT_2F, fontface: *mut core::ffi::c_void, fontemsize: f32, glyphindex: u16, issideways: super::super::Foundation::BOOL, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, defaultfillbrush: *mut core::ffi::c_void, svgglyphstyle: *mut core::ffi::c_void, colorpaletteindex: u32, glyphtransform: *mut super::super::super::Foundation::Numerics::Matrix3x2, glyphimage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext4", "GetSvgGlyphImage", 114usize, |this| ((**(this as *const *const ID2D1DeviceContext4_Vtbl)).GetSvgGlyphImage)(this, glyphorigin, fontface, fontemsize, glyphindex, issideways, worldtransform, defaultfillbrush, svgglyphstyle, colorpaletteindex, glyphtransform, glyphimage), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1DeviceContext3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateSvgGlyphStyle,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+            DrawText,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite")))]
+            DrawText: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+            DrawTextLayout,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite")))]
+            DrawTextLayout: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+            DrawColorBitmapGlyphRun,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite")))]
+            DrawColorBitmapGlyphRun: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+            DrawSvgGlyphRun,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite")))]
+            DrawSvgGlyphRun: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+            GetColorBitmapGlyphImage,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite")))]
+            GetColorBitmapGlyphImage: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+            GetSvgGlyphImage,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite")))]
+            GetSvgGlyphImage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1DeviceContext4 as windows_core::Interface>::IID || <ID2D1DeviceContext3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ID2D1DeviceContext5_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateSvgDocument(this: *mut core::ffi::c_void, inputxmlstream: *mut core::ffi::c_void, viewportsize: Common::D2D_SIZE_F, svgdocument: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext5", "CreateSvgDocument", 115usize, |this| ((**(this as *const *const ID2D1DeviceContext5_Vtbl)).CreateSvgDocument)(this, inputxmlstream, viewportsize, svgdocument), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawSvgDocument(this: *mut core::ffi::c_void, svgdocument: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext5", "DrawSvgDocument", 116usize, |this| ((**(this as *const *const ID2D1DeviceContext5_Vtbl)).DrawSvgDocument)(this, svgdocument), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateColorContextFromDxgiColorSpace(this: *mut core::ffi::c_void, colorspace: super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE, colorcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext5", "CreateColorContextFromDxgiColorSpace", 117usize, |this| ((**(this as *const *const ID2D1DeviceContext5_Vtbl)).CreateColorContextFromDxgiColorSpace)(this, colorspace, colorcontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CreateColorContextFromSimpleColorProfile(this: *mut core::ffi::c_void, simpleprofile: *const D2D1_SIMPLE_COLOR_PROFILE, colorcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext5", "CreateColorContextFromSimpleColorProfile", 118usize, |this| ((**(this as *const *const ID2D1DeviceContext5_Vtbl)).CreateColorContextFromSimpleColorProfile)(this, simpleprofile, colorcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1DeviceContext4_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_System_Com"))]
+            CreateSvgDocument,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_System_Com")))]
+            CreateSvgDocument: 0,
+            DrawSvgDocument,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateColorContextFromDxgiColorSpace,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateColorContextFromDxgiColorSpace: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CreateColorContextFromSimpleColorProfile,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CreateColorContextFromSimpleColorProfile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1DeviceContext5 as windows_core::Interface>::IID || <ID2D1DeviceContext4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ID2D1DeviceContext6_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn BlendImage(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void, blendmode: Common::D2D1_BLEND_MODE, targetoffset: *const Common::D2D_POINT_2F, imagerectangle: *const Common::D2D_RECT_F, interpolationmode: D2D1_INTERPOLATION_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext6", "BlendImage", 119usize, |this| ((**(this as *const *const ID2D1DeviceContext6_Vtbl)).BlendImage)(this, image, blendmode, targetoffset, imagerectangle, interpolationmode), |_| None)
+        }
+        Self {
+            base__: <ID2D1DeviceContext5_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            BlendImage,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            BlendImage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1DeviceContext6 as windows_core::Interface>::IID || <ID2D1DeviceContext5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ID2D1DeviceContext7_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_DirectWrite")]
+        unsafe extern "system" fn GetPaintFeatureLevel(this: *mut core::ffi::c_void) -> super::DirectWrite::DWRITE_PAINT_FEATURE_LEVEL {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext7", "GetPaintFeatureLevel", 120usize, |this| ((**(this as *const *const ID2D1DeviceContext7_Vtbl)).GetPaintFeatureLevel)(this), |_| None)
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn DrawPaintGlyphRun(this: *mut core::ffi::c_void, baselineorigin: Common::D2D_POINT_2F, glyphrun: *const super::DirectWrite::DWRITE_GLYPH_RUN, defaultfillbrush: *mut core::ffi::c_void, colorpaletteindex: u32, measuringmode: super::DirectWrite::DWRITE_MEASURING_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext7", "DrawPaintGlyphRun", 121usize, |this| ((**(this as *const *const ID2D1DeviceContext7_Vtbl)).DrawPaintGlyphRun)(this, baselineorigin, glyphrun, defaultfillbrush, colorpaletteindex, measuringmode), |_| None)
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn DrawGlyphRunWithColorSupport(this: *mut core::ffi::c_void, baselineorigin: Common::D2D_POINT_2F, glyphrun: *const super::DirectWrite::DWRITE_GLYPH_RUN, glyphrundescription: *const super::DirectWrite::DWRITE_GLYPH_RUN_DESCRIPTION, foregroundbrush: *mut core::ffi::c_void, svgglyphstyle: *mut core::ffi::c_void, colorpaletteindex: u32, measuringmode: super::DirectWrite::DWRITE_MEASURING_MODE, bitmapsnapoption: D2D1_COLOR_BITMAP_GLYPH_SNAP_OPTION) {
+            windows_core::imp::proxy_call(this, "ID2D1DeviceContext7", "DrawGlyphRunWithColorSupport", 122usize, |this| ((**(this as *const *const ID2D1DeviceContext7_Vtbl)).DrawGlyphRunWithColorSupport)(this, baselineorigin, glyphrun, glyphrundescription, foregroundbrush, svgglyphstyle, colorpaletteindex, measuringmode, bitmapsnapoption), |_| None)
+        }
+        Self {
+            base__: <ID2D1DeviceContext6_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_DirectWrite")]
+            GetPaintFeatureLevel,
+            #[cfg(not(feature = "Win32_Graphics_DirectWrite"))]
+            GetPaintFeatureLevel: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+            DrawPaintGlyphRun,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite")))]
+            DrawPaintGlyphRun: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+            DrawGlyphRunWithColorSupport,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite")))]
+            DrawGlyphRunWithColorSupport: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1DeviceContext7 as windows_core::Interface>::IID || <ID2D1DeviceContext6_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1DrawInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPixelShaderConstantBuffer(this: *mut core::ffi::c_void, buffer: *const u8, buffercount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DrawInfo", "SetPixelShaderConstantBuffer", 7usize, |this| ((**(this as *const *const ID2D1DrawInfo_Vtbl)).SetPixelShaderConstantBuffer)(this, buffer, buffercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResourceTexture(this: *mut core::ffi::c_void, textureindex: u32, resourcetexture: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DrawInfo", "SetResourceTexture", 8usize, |this| ((**(this as *const *const ID2D1DrawInfo_Vtbl)).SetResourceTexture)(this, textureindex, resourcetexture), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVertexShaderConstantBuffer(this: *mut core::ffi::c_void, buffer: *const u8, buffercount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DrawInfo", "SetVertexShaderConstantBuffer", 9usize, |this| ((**(this as *const *const ID2D1DrawInfo_Vtbl)).SetVertexShaderConstantBuffer)(this, buffer, buffercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPixelShader(this: *mut core::ffi::c_void, shaderid: *const windows_core::GUID, pixeloptions: D2D1_PIXEL_OPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DrawInfo", "SetPixelShader", 10usize, |this| ((**(this as *const *const ID2D1DrawInfo_Vtbl)).SetPixelShader)(this, shaderid, pixeloptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVertexProcessing(this: *mut core::ffi::c_void, vertexbuffer: *mut core::ffi::c_void, vertexoptions: D2D1_VERTEX_OPTIONS, blenddescription: *const D2D1_BLEND_DESCRIPTION, vertexrange: *const D2D1_VERTEX_RANGE, vertexshader: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DrawInfo", "SetVertexProcessing", 11usize, |this| ((**(this as *const *const ID2D1DrawInfo_Vtbl)).SetVertexProcessing)(this, vertexbuffer, vertexoptions, blenddescription, vertexrange, vertexshader), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1RenderInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPixelShaderConstantBuffer,
+            SetResourceTexture,
+            SetVertexShaderConstantBuffer,
+            SetPixelShader,
+            SetVertexProcessing,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1DrawInfo as windows_core::Interface>::IID || <ID2D1RenderInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1DrawTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDrawInfo(this: *mut core::ffi::c_void, drawinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1DrawTransform", "SetDrawInfo", 7usize, |this| ((**(this as *const *const ID2D1DrawTransform_Vtbl)).SetDrawInfo)(this, drawinfo), |result| Some(*result))
+        }
+        Self { base__: <ID2D1Transform_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDrawInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1DrawTransform as windows_core::Interface>::IID || <ID2D1Transform_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_DirectWrite"))]
+impl windows_core::imp::ProxyVtable for ID2D1DrawingStateBlock_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, statedescription: *mut D2D1_DRAWING_STATE_DESCRIPTION) {
+            windows_core::imp::proxy_call(this, "ID2D1DrawingStateBlock", "GetDescription", 4usize, |this| ((**(this as *const *const ID2D1DrawingStateBlock_Vtbl)).GetDescription)(this, statedescription), |_| None)
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, statedescription: *const D2D1_DRAWING_STATE_DESCRIPTION) {
+            windows_core::imp::proxy_call(this, "ID2D1DrawingStateBlock", "SetDescription", 5usize, |this| ((**(this as *const *const ID2D1DrawingStateBlock_Vtbl)).SetDescription)(this, statedescription), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_DirectWrite")]
+        unsafe extern "system" fn SetTextRenderingParams(this: *mut core::ffi::c_void, textrenderingparams: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1DrawingStateBlock", "SetTextRenderingParams", 6usize, |this| ((**(this as *const *const ID2D1DrawingStateBlock_Vtbl)).SetTextRenderingParams)(this, textrenderingparams), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_DirectWrite")]
+        unsafe extern "system" fn GetTextRenderingParams(this: *mut core::ffi::c_void, textrenderingparams: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1DrawingStateBlock", "GetTextRenderingParams", 7usize, |this| ((**(this as *const *const ID2D1DrawingStateBlock_Vtbl)).GetTextRenderingParams)(this, textrenderingparams), |_| None)
+        }
+        Self {
+            base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            GetDescription,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            GetDescription: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetDescription,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetDescription: 0,
+            #[cfg(feature = "Win32_Graphics_DirectWrite")]
+            SetTextRenderingParams,
+            #[cfg(not(feature = "Win32_Graphics_DirectWrite"))]
+            SetTextRenderingParams: 0,
+            #[cfg(feature = "Win32_Graphics_DirectWrite")]
+            GetTextRenderingParams,
+            #[cfg(not(feature = "Win32_Graphics_DirectWrite"))]
+            GetTextRenderingParams: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1DrawingStateBlock as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_DirectWrite"))]
+impl windows_core::imp::ProxyVtable for ID2D1DrawingStateBlock1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, statedescription: *mut D2D1_DRAWING_STATE_DESCRIPTION1) {
+            windows_core::imp::proxy_call(this, "ID2D1DrawingStateBlock1", "GetDescription", 8usize, |this| ((**(this as *const *const ID2D1DrawingStateBlock1_Vtbl)).GetDescription)(this, statedescription), |_| None)
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, statedescription: *const D2D1_DRAWING_STATE_DESCRIPTION1) {
+            windows_core::imp::proxy_call(this, "ID2D1DrawingStateBlock1", "SetDescription", 9usize, |this| ((**(this as *const *const ID2D1DrawingStateBlock1_Vtbl)).SetDescription)(this, statedescription), |_| None)
+        }
+        Self {
+            base__: <ID2D1DrawingStateBlock_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            GetDescription,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            GetDescription: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetDescription,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetDescription: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1DrawingStateBlock1 as windows_core::Interface>::IID || <ID2D1DrawingStateBlock_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1Effect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetInput(this: *mut core::ffi::c_void, index: u32, input: *mut core::ffi::c_void, invalidate: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID2D1Effect", "SetInput", 14usize, |this| ((**(this as *const *const ID2D1Effect_Vtbl)).SetInput)(this, index, input, invalidate), |_| None)
+        }
+        unsafe extern "system" fn SetInputCount(this: *mut core::ffi::c_void, inputcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Effect", "SetInputCount", 15usize, |this| ((**(this as *const *const ID2D1Effect_Vtbl)).SetInputCount)(this, inputcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInput(this: *mut core::ffi::c_void, index: u32, input: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1Effect", "GetInput", 16usize, |this| ((**(this as *const *const ID2D1Effect_Vtbl)).GetInput)(this, index, input), |_| None)
+        }
+        unsafe extern "system" fn GetInputCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1Effect", "GetInputCount", 17usize, |this| ((**(this as *const *const ID2D1Effect_Vtbl)).GetInputCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetOutput(this: *mut core::ffi::c_void, outputimage: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1Effect", "GetOutput", 18usize, |this| ((**(this as *const *const ID2D1Effect_Vtbl)).GetOutput)(this, outputimage), |_| None)
+        }
+        Self { base__: <ID2D1Properties_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetInput, SetInputCount, GetInput, GetInputCount, GetOutput }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Effect as windows_core::Interface>::IID || <ID2D1Properties_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ID2D1EffectContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDpi(this: *mut core::ffi::c_void, dpix: *mut f32, dpiy: *mut f32) {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "GetDpi", 3usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).GetDpi)(this, dpix, dpiy), |_| None)
+        }
+        unsafe extern "system" fn CreateEffect(this: *mut core::ffi::c_void, effectid: *const windows_core::GUID, effect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "CreateEffect", 4usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).CreateEffect)(this, effectid, effect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetMaximumSupportedFeatureLevel(this: *mut core::ffi::c_void, featurelevels: *const super::Direct3D::D3D_FEATURE_LEVEL, featurelevelscount: u32, maximumsupportedfeaturelevel: *mut super::Direct3D::D3D_FEATURE_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "GetMaximumSupportedFeatureLevel", 5usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).GetMaximumSupportedFeatureLevel)(this, featurelevels, featurelevelscount, maximumsupportedfeaturelevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTransformNodeFromEffect(this: *mut core::ffi::c_void, effect: *mut core::ffi::c_void, transformnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "CreateTransformNodeFromEffect", 6usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).CreateTransformNodeFromEffect)(this, effect, transformnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBlendTransform(this: *mut core::ffi::c_void, numinputs: u32, blenddescription: *const D2D1_BLEND_DESCRIPTION, transform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "CreateBlendTransform", 7usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).CreateBlendTransform)(this, numinputs, blenddescription, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBorderTransform(this: *mut core::ffi::c_void, extendmodex: D2D1_EXTEND_MODE, extendmodey: D2D1_EXTEND_MODE, transform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "CreateBorderTransform", 8usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).CreateBorderTransform)(this, extendmodex, extendmodey, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOffsetTransform(this: *mut core::ffi::c_void, offset: super::super::Foundation::POINT, transform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "CreateOffsetTransform", 9usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).CreateOffsetTransform)(this, offset, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBoundsAdjustmentTransform(this: *mut core::ffi::c_void, outputrectangle: *const super::super::Foundation::RECT, transform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "CreateBoundsAdjustmentTransform", 10usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).CreateBoundsAdjustmentTransform)(this, outputrectangle, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadPixelShader(this: *mut core::ffi::c_void, shaderid: *const windows_core::GUID, shaderbuffer: *const u8, shaderbuffercount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "LoadPixelShader", 11usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).LoadPixelShader)(this, shaderid, shaderbuffer, shaderbuffercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadVertexShader(this: *mut core::ffi::c_void, resourceid: *const windows_core::GUID, shaderbuffer: *const u8, shaderbuffercount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "LoadVertexShader", 12usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).LoadVertexShader)(this, resourceid, shaderbuffer, shaderbuffercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadComputeShader(this: *mut core::ffi::c_void, resourceid: *const windows_core::GUID, shaderbuffer: *const u8, shaderbuffercount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "LoadComputeShader", 13usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).LoadComputeShader)(this, resourceid, shaderbuffer, shaderbuffercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsShaderLoaded(this: *mut core::ffi::c_void, shaderid: *const windows_core::GUID) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "IsShaderLoaded", 14usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).IsShaderLoaded)(this, shaderid), |_| None)
+        }
+        unsafe extern "system" fn CreateResourceTexture(this: *mut core::ffi::c_void, resourceid: *const windows_core::GUID, resourcetextureproperties: *const D2D1_RESOURCE_TEXTURE_PROPERTIES, data: *const u8, strides: *const u32, datasize: u32, resourcetexture: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "CreateResourceTexture", 15usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).CreateResourceTexture)(this, resourceid, resourcetextureproperties, data, strides, datasize, resourcetexture), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindResourceTexture(this: *mut core::ffi::c_void, resourceid: *const windows_core::GUID, resourcetexture: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "FindResourceTexture", 16usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).FindResourceTexture)(this, resourceid, resourcetexture), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateVertexBuffer(this: *mut core::ffi::c_void, vertexbufferproperties: *const D2D1_VERTEX_BUFFER_PROPERTIES, resourceid: *const windows_core::GUID, customvertexbufferproperties: *const D2D1_CUSTOM_VERTEX_BUFFER_PROPERTIES, buffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "CreateVertexBuffer", 17usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).CreateVertexBuffer)(this, vertexbufferproperties, resourceid, customvertexbufferproperties, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindVertexBuffer(this: *mut core::ffi::c_void, resourceid: *const windows_core::GUID, buffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "FindVertexBuffer", 18usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).FindVertexBuffer)(this, resourceid, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateColorContext(this: *mut core::ffi::c_void, space: D2D1_COLOR_SPACE, profile: *const u8, profilesize: u32, colorcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "CreateColorContext", 19usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).CreateColorContext)(this, space, profile, profilesize, colorcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateColorContextFromFilename(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, colorcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "CreateColorContextFromFilename", 20usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).CreateColorContextFromFilename)(this, filename, colorcontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Imaging")]
+        unsafe extern "system" fn CreateColorContextFromWicColorContext(this: *mut core::ffi::c_void, wiccolorcontext: *mut core::ffi::c_void, colorcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "CreateColorContextFromWicColorContext", 21usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).CreateColorContextFromWicColorContext)(this, wiccolorcontext, colorcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckFeatureSupport(this: *mut core::ffi::c_void, feature: D2D1_FEATURE, featuresupportdata: *mut core::ffi::c_void, featuresupportdatasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "CheckFeatureSupport", 22usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).CheckFeatureSupport)(this, feature, featuresupportdata, featuresupportdatasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBufferPrecisionSupported(this: *mut core::ffi::c_void, bufferprecision: D2D1_BUFFER_PRECISION) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext", "IsBufferPrecisionSupported", 23usize, |this| ((**(this as *const *const ID2D1EffectContext_Vtbl)).IsBufferPrecisionSupported)(this, bufferprecision), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDpi,
+            CreateEffect,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetMaximumSupportedFeatureLevel,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetMaximumSupportedFeatureLevel: 0,
+            CreateTransformNodeFromEffect,
+            CreateBlendTransform,
+            CreateBorderTransform,
+            CreateOffsetTransform,
+            CreateBoundsAdjustmentTransform,
+            LoadPixelShader,
+            LoadVertexShader,
+            LoadComputeShader,
+            IsShaderLoaded,
+            CreateResourceTexture,
+            FindResourceTexture,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateVertexBuffer,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateVertexBuffer: 0,
+            FindVertexBuffer,
+            CreateColorContext,
+            CreateColorContextFromFilename,
+            #[cfg(feature = "Win32_Graphics_Imaging")]
+            CreateColorContextFromWicColorContext,
+            #[cfg(not(feature = "Win32_Graphics_Imaging"))]
+            CreateColorContextFromWicColorContext: 0,
+            CheckFeatureSupport,
+            IsBufferPrecisionSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1EffectContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ID2D1EffectContext1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateLookupTable3D(this: *mut core::ffi::c_void, precision: D2D1_BUFFER_PRECISION, extents: *const u32, data: *const u8, datacount: u32, strides: *const u32, lookuptable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext1", "CreateLookupTable3D", 24usize, |this| ((**(this as *const *const ID2D1EffectContext1_Vtbl)).CreateLookupTable3D)(this, precision, extents, data, datacount, strides, lookuptable), |result| Some(*result))
+        }
+        Self { base__: <ID2D1EffectContext_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateLookupTable3D }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1EffectContext1 as windows_core::Interface>::IID || <ID2D1EffectContext_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ID2D1EffectContext2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateColorContextFromDxgiColorSpace(this: *mut core::ffi::c_void, colorspace: super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE, colorcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext2", "CreateColorContextFromDxgiColorSpace", 25usize, |this| ((**(this as *const *const ID2D1EffectContext2_Vtbl)).CreateColorContextFromDxgiColorSpace)(this, colorspace, colorcontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CreateColorContextFromSimpleColorProfile(this: *mut core::ffi::c_void, simpleprofile: *const D2D1_SIMPLE_COLOR_PROFILE, colorcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectContext2", "CreateColorContextFromSimpleColorProfile", 26usize, |this| ((**(this as *const *const ID2D1EffectContext2_Vtbl)).CreateColorContextFromSimpleColorProfile)(this, simpleprofile, colorcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1EffectContext1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateColorContextFromDxgiColorSpace,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateColorContextFromDxgiColorSpace: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CreateColorContextFromSimpleColorProfile,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CreateColorContextFromSimpleColorProfile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1EffectContext2 as windows_core::Interface>::IID || <ID2D1EffectContext1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1EffectImpl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, effectcontext: *mut core::ffi::c_void, transformgraph: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectImpl", "Initialize", 3usize, |this| ((**(this as *const *const ID2D1EffectImpl_Vtbl)).Initialize)(this, effectcontext, transformgraph), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrepareForRender(this: *mut core::ffi::c_void, changetype: D2D1_CHANGE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectImpl", "PrepareForRender", 4usize, |this| ((**(this as *const *const ID2D1EffectImpl_Vtbl)).PrepareForRender)(this, changetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGraph(this: *mut core::ffi::c_void, transformgraph: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1EffectImpl", "SetGraph", 5usize, |this| ((**(this as *const *const ID2D1EffectImpl_Vtbl)).SetGraph)(this, transformgraph), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, PrepareForRender, SetGraph }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1EffectImpl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1EllipseGeometry_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetEllipse(this: *mut core::ffi::c_void, ellipse: *mut D2D1_ELLIPSE) {
+            windows_core::imp::proxy_call(this, "ID2D1EllipseGeometry", "GetEllipse", 17usize, |this| ((**(this as *const *const ID2D1EllipseGeometry_Vtbl)).GetEllipse)(this, ellipse), |_| None)
+        }
+        Self {
+            base__: <ID2D1Geometry_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetEllipse,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetEllipse: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1EllipseGeometry as windows_core::Interface>::IID || <ID2D1Geometry_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ID2D1Factory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReloadSystemMetrics(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory", "ReloadSystemMetrics", 3usize, |this| ((**(this as *const *const ID2D1Factory_Vtbl)).ReloadSystemMetrics)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDesktopDpi(this: *mut core::ffi::c_void, dpix: *mut f32, dpiy: *mut f32) {
+            windows_core::imp::proxy_call(this, "ID2D1Factory", "GetDesktopDpi", 4usize, |this| ((**(this as *const *const ID2D1Factory_Vtbl)).GetDesktopDpi)(this, dpix, dpiy), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CreateRectangleGeometry(this: *mut core::ffi::c_void, rectangle: *const Common::D2D_RECT_F, rectanglegeometry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory", "CreateRectangleGeometry", 5usize, |this| ((**(this as *const *const ID2D1Factory_Vtbl)).CreateRectangleGeometry)(this, rectangle, rectanglegeometry), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CreateRoundedRectangleGeometry(this: *mut core::ffi::c_void, roundedrectangle: *const D2D1_ROUNDED_RECT, roundedrectanglegeometry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory", "CreateRoundedRectangleGeometry", 6usize, |this| ((**(this as *const *const ID2D1Factory_Vtbl)).CreateRoundedRectangleGeometry)(this, roundedrectangle, roundedrectanglegeometry), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CreateEllipseGeometry(this: *mut core::ffi::c_void, ellipse: *const D2D1_ELLIPSE, ellipsegeometry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory", "CreateEllipseGeometry", 7usize, |this| ((**(this as *const *const ID2D1Factory_Vtbl)).CreateEllipseGeometry)(this, ellipse, ellipsegeometry), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CreateGeometryGroup(this: *mut core::ffi::c_void, fillmode: Common::D2D1_FILL_MODE, geometries: *const *mut core::ffi::c_void, geometriescount: u32, geometrygroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory", "CreateGeometryGroup", 8usize, |this| ((**(this as *const *const ID2D1Factory_Vtbl)).CreateGeometryGroup)(this, fillmode, geometries, geometriescount, geometrygroup), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CreateTransformedGeometry(this: *mut core::ffi::c_void, sourcegeometry: *mut core::ffi::c_void, transform: *const super::super::super::Foundation::Numerics::Matrix3x2, transformedgeometry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory", "CreateTransformedGeometry", 9usize, |this| ((**(this as *const *const ID2D1Factory_Vtbl)).CreateTransformedGeometry)(this, sourcegeometry, transform, transformedgeometry), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePathGeometry(this: *mut core::ffi::c_void, pathgeometry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory", "CreatePathGeometry", 10usize, |this| ((**(this as *const *const ID2D1Factory_Vtbl)).CreatePathGeometry)(this, pathgeometry), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStrokeStyle(this: *mut core::ffi::c_void, strokestyleproperties: *const D2D1_STROKE_STYLE_PROPERTIES, dashes: *const f32, dashescount: u32, strokestyle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory", "CreateStrokeStyle", 11usize, |this| ((**(this as *const *const ID2D1Factory_Vtbl)).CreateStrokeStyle)(this, strokestyleproperties, dashes, dashescount, strokestyle), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn CreateDrawingStateBlock(this: *mut core::ffi::c_void, drawingstatedescription: *const D2D1_DRAWING_STATE_DESCRIPTION, textrenderingparams: *mut core::ffi::c_void, drawingstateblock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory", "CreateDrawingStateBlock", 12usize, |this| ((**(this as *const *const ID2D1Factory_Vtbl)).CreateDrawingStateBlock)(this, drawingstatedescription, textrenderingparams, drawingstateblock), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+        unsafe extern "system" fn CreateWicBitmapRenderTarget(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, rendertargetproperties: *const D2D1_RENDER_TARGET_PROPERTIES, rendertarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory", "CreateWicBitmapRenderTarget", 13usize, |this| ((**(this as *const *const ID2D1Factory_Vtbl)).CreateWicBitmapRenderTarget)(this, target, rendertargetproperties, rendertarget), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateHwndRenderTarget(this: *mut core::ffi::c_void, rendertargetproperties: *const D2D1_RENDER_TARGET_PROPERTIES, hwndrendertargetproperties: *const D2D1_HWND_RENDER_TARGET_PROPERTIES, hwndrendertarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory", "CreateHwndRenderTarget", 14usize, |this| ((**(this as *const *const ID2D1Factory_Vtbl)).CreateHwndRenderTarget)(this, rendertargetproperties, hwndrendertargetproperties, hwndrendertarget), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateDxgiSurfaceRenderTarget(this: *mut core::ffi::c_void, dxgisurface: *mut core::ffi::c_void, rendertargetproperties: *const D2D1_RENDER_TARGET_PROPERTIES, rendertarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory", "CreateDxgiSurfaceRenderTarget", 15usize, |this| ((**(this as *const *const ID2D1Factory_Vtbl)).CreateDxgiSurfaceRenderTarget)(this, dxgisurface, rendertargetproperties, rendertarget), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateDCRenderTarget(this: *mut core::ffi::c_void, rendertargetproperties: *const D2D1_RENDER_TARGET_PROPERTIES, dcrendertarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory", "CreateDCRenderTarget", 16usize, |this| ((**(this as *const *const ID2D1Factory_Vtbl)).CreateDCRenderTarget)(this, rendertargetproperties, dcrendertarget), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReloadSystemMetrics,
+            GetDesktopDpi,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CreateRectangleGeometry,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CreateRectangleGeometry: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CreateRoundedRectangleGeometry,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CreateRoundedRectangleGeometry: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CreateEllipseGeometry,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CreateEllipseGeometry: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CreateGeometryGroup,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CreateGeometryGroup: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            CreateTransformedGeometry,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CreateTransformedGeometry: 0,
+            CreatePathGeometry,
+            CreateStrokeStyle,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_DirectWrite"))]
+            CreateDrawingStateBlock,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_DirectWrite")))]
+            CreateDrawingStateBlock: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+            CreateWicBitmapRenderTarget,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging")))]
+            CreateWicBitmapRenderTarget: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateHwndRenderTarget,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateHwndRenderTarget: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateDxgiSurfaceRenderTarget,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateDxgiSurfaceRenderTarget: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateDCRenderTarget,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateDCRenderTarget: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Factory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ID2D1Factory1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, dxgidevice: *mut core::ffi::c_void, d2ddevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory1", "CreateDevice", 17usize, |this| ((**(this as *const *const ID2D1Factory1_Vtbl)).CreateDevice)(this, dxgidevice, d2ddevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStrokeStyle(this: *mut core::ffi::c_void, strokestyleproperties: *const D2D1_STROKE_STYLE_PROPERTIES1, dashes: *const f32, dashescount: u32, strokestyle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory1", "CreateStrokeStyle", 18usize, |this| ((**(this as *const *const ID2D1Factory1_Vtbl)).CreateStrokeStyle)(this, strokestyleproperties, dashes, dashescount, strokestyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePathGeometry(this: *mut core::ffi::c_void, pathgeometry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory1", "CreatePathGeometry", 19usize, |this| ((**(this as *const *const ID2D1Factory1_Vtbl)).CreatePathGeometry)(this, pathgeometry), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn CreateDrawingStateBlock(this: *mut core::ffi::c_void, drawingstatedescription: *const D2D1_DRAWING_STATE_DESCRIPTION1, textrenderingparams: *mut core::ffi::c_void, drawingstateblock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory1", "CreateDrawingStateBlock", 20usize, |this| ((**(this as *const *const ID2D1Factory1_Vtbl)).CreateDrawingStateBlock)(this, drawingstatedescription, textrenderingparams, drawingstateblock), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateGdiMetafile(this: *mut core::ffi::c_void, metafilestream: *mut core::ffi::c_void, metafile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory1", "CreateGdiMetafile", 21usize, |this| ((**(this as *const *const ID2D1Factory1_Vtbl)).CreateGdiMetafile)(this, metafilestream, metafile), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RegisterEffectFromStream(this: *mut core::ffi::c_void, classid: *const windows_core::GUID, propertyxml: *mut core::ffi::c_void, bindings: *const D2D1_PROPERTY_BINDING, bindingscount: u32, effectfactory: PD2D1_EFFECT_FACTORY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory1", "RegisterEffectFromStream", 22usize, |this| ((**(this as *const *const ID2D1Factory1_Vtbl)).RegisterEffectFromStream)(this, classid, propertyxml, bindings, bindingscount, effectfactory), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterEffectFromString(this: *mut core::ffi::c_void, classid: *const windows_core::GUID, propertyxml: windows_core::PCWSTR, bindings: *const D2D1_PROPERTY_BINDING, bindingscount: u32, effectfactory: PD2D1_EFFECT_FACTORY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory1", "RegisterEffectFromString", 23usize, |this| ((**(this as *const *const ID2D1Factory1_Vtbl)).RegisterEffectFromString)(this, classid, propertyxml, bindings, bindingscount, effectfactory), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterEffect(this: *mut core::ffi::c_void, classid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory1", "UnregisterEffect", 24usize, |this| ((**(this as *const *const ID2D1Factory1_Vtbl)).UnregisterEffect)(this, classid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRegisteredEffects(this: *mut core::ffi::c_void, effects: *mut windows_core::GUID, effectscount: u32, effectsreturned: *mut u32, effectsregistered: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory1", "GetRegisteredEffects", 25usize, |this| ((**(this as *const *const ID2D1Factory1_Vtbl)).GetRegisteredEffects)(this, effects, effectscount, effectsreturned, effectsregistered), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEffectProperties(this: *mut core::ffi::c_void, effectid: *const windows_core::GUID, properties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory1", "GetEffectProperties", 26usize, |this| ((**(this as *const *const ID2D1Factory1_Vtbl)).GetEffectProperties)(this, effectid, properties), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Factory_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            CreateDevice,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            CreateDevice: 0,
+            CreateStrokeStyle,
+            CreatePathGeometry,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_DirectWrite"))]
+            CreateDrawingStateBlock,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_DirectWrite")))]
+            CreateDrawingStateBlock: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateGdiMetafile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateGdiMetafile: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RegisterEffectFromStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RegisterEffectFromStream: 0,
+            RegisterEffectFromString,
+            UnregisterEffect,
+            GetRegisteredEffects,
+            GetEffectProperties,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Factory1 as windows_core::Interface>::IID || <ID2D1Factory_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ID2D1Factory2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, dxgidevice: *mut core::ffi::c_void, d2ddevice1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory2", "CreateDevice", 27usize, |this| ((**(this as *const *const ID2D1Factory2_Vtbl)).CreateDevice)(this, dxgidevice, d2ddevice1), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Factory1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            CreateDevice,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            CreateDevice: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Factory2 as windows_core::Interface>::IID || <ID2D1Factory1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ID2D1Factory3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, dxgidevice: *mut core::ffi::c_void, d2ddevice2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory3", "CreateDevice", 28usize, |this| ((**(this as *const *const ID2D1Factory3_Vtbl)).CreateDevice)(this, dxgidevice, d2ddevice2), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Factory2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            CreateDevice,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            CreateDevice: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Factory3 as windows_core::Interface>::IID || <ID2D1Factory2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ID2D1Factory4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, dxgidevice: *mut core::ffi::c_void, d2ddevice3: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory4", "CreateDevice", 29usize, |this| ((**(this as *const *const ID2D1Factory4_Vtbl)).CreateDevice)(this, dxgidevice, d2ddevice3), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Factory3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            CreateDevice,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            CreateDevice: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Factory4 as windows_core::Interface>::IID || <ID2D1Factory3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ID2D1Factory5_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, dxgidevice: *mut core::ffi::c_void, d2ddevice4: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory5", "CreateDevice", 30usize, |this| ((**(this as *const *const ID2D1Factory5_Vtbl)).CreateDevice)(this, dxgidevice, d2ddevice4), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Factory4_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            CreateDevice,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            CreateDevice: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Factory5 as windows_core::Interface>::IID || <ID2D1Factory4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ID2D1Factory6_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, dxgidevice: *mut core::ffi::c_void, d2ddevice5: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory6", "CreateDevice", 31usize, |this| ((**(this as *const *const ID2D1Factory6_Vtbl)).CreateDevice)(this, dxgidevice, d2ddevice5), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Factory5_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            CreateDevice,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            CreateDevice: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Factory6 as windows_core::Interface>::IID || <ID2D1Factory5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ID2D1Factory7_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, dxgidevice: *mut core::ffi::c_void, d2ddevice6: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory7", "CreateDevice", 32usize, |this| ((**(this as *const *const ID2D1Factory7_Vtbl)).CreateDevice)(this, dxgidevice, d2ddevice6), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Factory6_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            CreateDevice,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            CreateDevice: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Factory7 as windows_core::Interface>::IID || <ID2D1Factory6_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ID2D1Factory8_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, dxgidevice: *mut core::ffi::c_void, d2ddevice6: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Factory8", "CreateDevice", 33usize, |this| ((**(this as *const *const ID2D1Factory8_Vtbl)).CreateDevice)(this, dxgidevice, d2ddevice6), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Factory7_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            CreateDevice,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            CreateDevice: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Factory8 as windows_core::Interface>::IID || <ID2D1Factory7_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for ID2D1GdiInteropRenderTarget_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetDC(this: *mut core::ffi::c_void, mode: D2D1_DC_INITIALIZE_MODE, hdc: *mut super::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1GdiInteropRenderTarget", "GetDC", 3usize, |this| ((**(this as *const *const ID2D1GdiInteropRenderTarget_Vtbl)).GetDC)(this, mode, hdc), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseDC(this: *mut core::ffi::c_void, update: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1GdiInteropRenderTarget", "ReleaseDC", 4usize, |this| ((**(this as *const *const ID2D1GdiInteropRenderTarget_Vtbl)).ReleaseDC)(this, update), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetDC: 0,
+            ReleaseDC,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1GdiInteropRenderTarget as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for ID2D1GdiMetafile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Stream(this: *mut core::ffi::c_void, sink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1GdiMetafile", "Stream", 4usize, |this| ((**(this as *const *const ID2D1GdiMetafile_Vtbl)).Stream)(this, sink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetBounds(this: *mut core::ffi::c_void, bounds: *mut Common::D2D_RECT_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1GdiMetafile", "GetBounds", 5usize, |this| ((**(this as *const *const ID2D1GdiMetafile_Vtbl)).GetBounds)(this, bounds), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Stream,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetBounds,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetBounds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1GdiMetafile as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for ID2D1GdiMetafile1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDpi(this: *mut core::ffi::c_void, dpix: *mut f32, dpiy: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1GdiMetafile1", "GetDpi", 6usize, |this| ((**(this as *const *const ID2D1GdiMetafile1_Vtbl)).GetDpi)(this, dpix, dpiy), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetSourceBounds(this: *mut core::ffi::c_void, bounds: *mut Common::D2D_RECT_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1GdiMetafile1", "GetSourceBounds", 7usize, |this| ((**(this as *const *const ID2D1GdiMetafile1_Vtbl)).GetSourceBounds)(this, bounds), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1GdiMetafile_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDpi,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetSourceBounds,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetSourceBounds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1GdiMetafile1 as windows_core::Interface>::IID || <ID2D1GdiMetafile_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1GdiMetafileSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProcessRecord(this: *mut core::ffi::c_void, recordtype: u32, recorddata: *const core::ffi::c_void, recorddatasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1GdiMetafileSink", "ProcessRecord", 3usize, |this| ((**(this as *const *const ID2D1GdiMetafileSink_Vtbl)).ProcessRecord)(this, recordtype, recorddata, recorddatasize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProcessRecord }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1GdiMetafileSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1GdiMetafileSink1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProcessRecord(this: *mut core::ffi::c_void, recordtype: u32, recorddata: *const core::ffi::c_void, recorddatasize: u32, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1GdiMetafileSink1", "ProcessRecord", 4usize, |this| ((**(this as *const *const ID2D1GdiMetafileSink1_Vtbl)).ProcessRecord)(this, recordtype, recorddata, recorddatasize, flags), |result| Some(*result))
+        }
+        Self { base__: <ID2D1GdiMetafileSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProcessRecord }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1GdiMetafileSink1 as windows_core::Interface>::IID || <ID2D1GdiMetafileSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1Geometry_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn GetBounds(this: *mut core::ffi::c_void, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, bounds: *mut Common::D2D_RECT_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Geometry", "GetBounds", 4usize, |this| ((**(this as *const *const ID2D1Geometry_Vtbl)).GetBounds)(this, worldtransform, bounds), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn GetWidenedBounds(this: *mut core::ffi::c_void, strokewidth: f32, strokestyle: *mut core::ffi::c_void, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, flatteningtolerance: f32, bounds: *mut Common::D2D_RECT_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Geometry", "GetWidenedBounds", 5usize, |this| ((**(this as *const *const ID2D1Geometry_Vtbl)).GetWidenedBounds)(this, strokewidth, strokestyle, worldtransform, flatteningtolerance, bounds), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn StrokeContainsPoint(this: *mut core::ffi::c_void, point: Common::D2D_POINT_2F, strokewidth: f32, strokestyle: *mut core::ffi::c_void, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, flatteningtolerance: f32, contains: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Geometry", "StrokeContainsPoint", 6usize, |this| ((**(this as *const *const ID2D1Geometry_Vtbl)).StrokeContainsPoint)(this, point, strokewidth, strokestyle, worldtransform, flatteningtolerance, contains), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn FillContainsPoint(this: *mut core::ffi::c_void, point: Common::D2D_POINT_2F, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, flatteningtolerance: f32, contains: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Geometry", "FillContainsPoint", 7usize, |this| ((**(this as *const *const ID2D1Geometry_Vtbl)).FillContainsPoint)(this, point, worldtransform, flatteningtolerance, contains), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CompareWithGeometry(this: *mut core::ffi::c_void, inputgeometry: *mut core::ffi::c_void, inputgeometrytransform: *const super::super::super::Foundation::Numerics::Matrix3x2, flatteningtolerance: f32, relation: *mut D2D1_GEOMETRY_RELATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Geometry", "CompareWithGeometry", 8usize, |this| ((**(this as *const *const ID2D1Geometry_Vtbl)).CompareWithGeometry)(this, inputgeometry, inputgeometrytransform, flatteningtolerance, relation), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn Simplify(this: *mut core::ffi::c_void, simplificationoption: D2D1_GEOMETRY_SIMPLIFICATION_OPTION, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, flatteningtolerance: f32, geometrysink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Geometry", "Simplify", 9usize, |this| ((**(this as *const *const ID2D1Geometry_Vtbl)).Simplify)(this, simplificationoption, worldtransform, flatteningtolerance, geometrysink), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn Tessellate(this: *mut core::ffi::c_void, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, flatteningtolerance: f32, tessellationsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Geometry", "Tessellate", 10usize, |this| ((**(this as *const *const ID2D1Geometry_Vtbl)).Tessellate)(this, worldtransform, flatteningtolerance, tessellationsink), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn CombineWithGeometry(this: *mut core::ffi::c_void, inputgeometry: *mut core::ffi::c_void, combinemode: D2D1_COMBINE_MODE, inputgeometrytransform: *const super::super::super::Foundation::Numerics::Matrix3x2, flatteningtolerance: f32, geometrysink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Geometry", "CombineWithGeometry", 11usize, |this| ((**(this as *const *const ID2D1Geometry_Vtbl)).CombineWithGeometry)(this, inputgeometry, combinemode, inputgeometrytransform, flatteningtolerance, geometrysink), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn Outline(this: *mut core::ffi::c_void, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, flatteningtolerance: f32, geometrysink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Geometry", "Outline", 12usize, |this| ((**(this as *const *const ID2D1Geometry_Vtbl)).Outline)(this, worldtransform, flatteningtolerance, geometrysink), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn ComputeArea(this: *mut core::ffi::c_void, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, flatteningtolerance: f32, area: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Geometry", "ComputeArea", 13usize, |this| ((**(this as *const *const ID2D1Geometry_Vtbl)).ComputeArea)(this, worldtransform, flatteningtolerance, area), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn ComputeLength(this: *mut core::ffi::c_void, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, flatteningtolerance: f32, length: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Geometry", "ComputeLength", 14usize, |this| ((**(this as *const *const ID2D1Geometry_Vtbl)).ComputeLength)(this, worldtransform, flatteningtolerance, length), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn ComputePointAtLength(this: *mut core::ffi::c_void, length: f32, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, flatteningtolerance: f32, point: *mut Common::D2D_POINT_2F, unittangentvector: *mut Common::D2D_POINT_2F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Geometry", "ComputePointAtLength", 15usize, |this| ((**(this as *const *const ID2D1Geometry_Vtbl)).ComputePointAtLength)(this, length, worldtransform, flatteningtolerance, point, unittangentvector), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn Widen(this: *mut core::ffi::c_void, strokewidth: f32, strokestyle: *mut core::ffi::c_void, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, flatteningtolerance: f32, geometrysink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Geometry", "Widen", 16usize, |this| ((**(this as *const *const ID2D1Geometry_Vtbl)).Widen)(this, strokewidth, strokestyle, worldtransform, flatteningtolerance, geometrysink), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            GetBounds,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            GetBounds: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            GetWidenedBounds,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            GetWidenedBounds: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            StrokeContainsPoint,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            StrokeContainsPoint: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            FillContainsPoint,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            FillContainsPoint: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            CompareWithGeometry,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CompareWithGeometry: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            Simplify,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            Simplify: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            Tessellate,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            Tessellate: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            CombineWithGeometry,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            CombineWithGeometry: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            Outline,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            Outline: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            ComputeArea,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            ComputeArea: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            ComputeLength,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            ComputeLength: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            ComputePointAtLength,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            ComputePointAtLength: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            Widen,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            Widen: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Geometry as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1GeometryGroup_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetFillMode(this: *mut core::ffi::c_void) -> Common::D2D1_FILL_MODE {
+            windows_core::imp::proxy_call(this, "ID2D1GeometryGroup", "GetFillMode", 17usize, |this| ((**(this as *const *const ID2D1GeometryGroup_Vtbl)).GetFillMode)(this), |_| None)
+        }
+        unsafe extern "system" fn GetSourceGeometryCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1GeometryGroup", "GetSourceGeometryCount", 18usize, |this| ((**(this as *const *const ID2D1GeometryGroup_Vtbl)).GetSourceGeometryCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetSourceGeometries(this: *mut core::ffi::c_void, geometries: *mut *mut core::ffi::c_void, geometriescount: u32) {
+            windows_core::imp::proxy_call(this, "ID2D1GeometryGroup", "GetSourceGeometries", 19usize, |this| ((**(this as *const *const ID2D1GeometryGroup_Vtbl)).GetSourceGeometries)(this, geometries, geometriescount), |_| None)
+        }
+        Self {
+            base__: <ID2D1Geometry_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetFillMode,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetFillMode: 0,
+            GetSourceGeometryCount,
+            GetSourceGeometries,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1GeometryGroup as windows_core::Interface>::IID || <ID2D1Geometry_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1GeometryRealization_Vtbl {
+    const PROXY: Self = { Self { base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1GeometryRealization as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for ID2D1GeometrySink_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn AddLine(this: *mut core::ffi::c_void, point: Common::D2D_POINT_2F) {
+            windows_core::imp::proxy_call(this, "ID2D1GeometrySink", "AddLine", 10usize, |this| ((**(this as *const *const ID2D1GeometrySink_Vtbl)).AddLine)(this, point), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn AddBezier(this: *mut core::ffi::c_void, bezier: *const Common::D2D1_BEZIER_SEGMENT) {
+            windows_core::imp::proxy_call(this, "ID2D1GeometrySink", "AddBezier", 11usize, |this| ((**(this as *const *const ID2D1GeometrySink_Vtbl)).AddBezier)(this, bezier), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn AddQuadraticBezier(this: *mut core::ffi::c_void, bezier: *const D2D1_QUADRATIC_BEZIER_SEGMENT) {
+            windows_core::imp::proxy_call(this, "ID2D1GeometrySink", "AddQuadraticBezier", 12usize, |this| ((**(this as *const *const ID2D1GeometrySink_Vtbl)).AddQuadraticBezier)(this, bezier), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn AddQuadraticBeziers(this: *mut core::ffi::c_void, beziers: *const D2D1_QUADRATIC_BEZIER_SEGMENT, bezierscount: u32) {
+            windows_core::imp::proxy_call(this, "ID2D1GeometrySink", "AddQuadraticBeziers", 13usize, |this| ((**(this as *const *const ID2D1GeometrySink_Vtbl)).AddQuadraticBeziers)(this, beziers, bezierscount), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn AddArc(this: *mut core::ffi::c_void, arc: *const D2D1_ARC_SEGMENT) {
+            windows_core::imp::proxy_call(this, "ID2D1GeometrySink", "AddArc", 14usize, |this| ((**(this as *const *const ID2D1GeometrySink_Vtbl)).AddArc)(this, arc), |_| None)
+        }
+        Self {
+            base__: <Common::ID2D1SimplifiedGeometrySink_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            AddLine,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            AddLine: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            AddBezier,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            AddBezier: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            AddQuadraticBezier,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            AddQuadraticBezier: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            AddQuadraticBeziers,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            AddQuadraticBeziers: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            AddArc,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            AddArc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1GeometrySink as windows_core::Interface>::IID || <Common::ID2D1SimplifiedGeometrySink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for ID2D1GradientMesh_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPatchCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1GradientMesh", "GetPatchCount", 4usize, |this| ((**(this as *const *const ID2D1GradientMesh_Vtbl)).GetPatchCount)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetPatches(this: *mut core::ffi::c_void, startindex: u32, patches: *mut D2D1_GRADIENT_MESH_PATCH, patchescount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1GradientMesh", "GetPatches", 5usize, |this| ((**(this as *const *const ID2D1GradientMesh_Vtbl)).GetPatches)(this, startindex, patches, patchescount), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPatchCount,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetPatches,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetPatches: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1GradientMesh as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for ID2D1GradientStopCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGradientStopCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1GradientStopCollection", "GetGradientStopCount", 4usize, |this| ((**(this as *const *const ID2D1GradientStopCollection_Vtbl)).GetGradientStopCount)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetGradientStops(this: *mut core::ffi::c_void, gradientstops: *mut Common::D2D1_GRADIENT_STOP, gradientstopscount: u32) {
+            windows_core::imp::proxy_call(this, "ID2D1GradientStopCollection", "GetGradientStops", 5usize, |this| ((**(this as *const *const ID2D1GradientStopCollection_Vtbl)).GetGradientStops)(this, gradientstops, gradientstopscount), |_| None)
+        }
+        unsafe extern "system" fn GetColorInterpolationGamma(this: *mut core::ffi::c_void) -> D2D1_GAMMA {
+            windows_core::imp::proxy_call(this, "ID2D1GradientStopCollection", "GetColorInterpolationGamma", 6usize, |this| ((**(this as *const *const ID2D1GradientStopCollection_Vtbl)).GetColorInterpolationGamma)(this), |_| None)
+        }
+        unsafe extern "system" fn GetExtendMode(this: *mut core::ffi::c_void) -> D2D1_EXTEND_MODE {
+            windows_core::imp::proxy_call(this, "ID2D1GradientStopCollection", "GetExtendMode", 7usize, |this| ((**(this as *const *const ID2D1GradientStopCollection_Vtbl)).GetExtendMode)(this), |_| None)
+        }
+        Self {
+            base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetGradientStopCount,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetGradientStops,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetGradientStops: 0,
+            GetColorInterpolationGamma,
+            GetExtendMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1GradientStopCollection as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for ID2D1GradientStopCollection1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetGradientStops1(this: *mut core::ffi::c_void, gradientstops: *mut Common::D2D1_GRADIENT_STOP, gradientstopscount: u32) {
+            windows_core::imp::proxy_call(this, "ID2D1GradientStopCollection1", "GetGradientStops1", 8usize, |this| ((**(this as *const *const ID2D1GradientStopCollection1_Vtbl)).GetGradientStops1)(this, gradientstops, gradientstopscount), |_| None)
+        }
+        unsafe extern "system" fn GetPreInterpolationSpace(this: *mut core::ffi::c_void) -> D2D1_COLOR_SPACE {
+            windows_core::imp::proxy_call(this, "ID2D1GradientStopCollection1", "GetPreInterpolationSpace", 9usize, |this| ((**(this as *const *const ID2D1GradientStopCollection1_Vtbl)).GetPreInterpolationSpace)(this), |_| None)
+        }
+        unsafe extern "system" fn GetPostInterpolationSpace(this: *mut core::ffi::c_void) -> D2D1_COLOR_SPACE {
+            windows_core::imp::proxy_call(this, "ID2D1GradientStopCollection1", "GetPostInterpolationSpace", 10usize, |this| ((**(this as *const *const ID2D1GradientStopCollection1_Vtbl)).GetPostInterpolationSpace)(this), |_| None)
+        }
+        unsafe extern "system" fn GetBufferPrecision(this: *mut core::ffi::c_void) -> D2D1_BUFFER_PRECISION {
+            windows_core::imp::proxy_call(this, "ID2D1GradientStopCollection1", "GetBufferPrecision", 11usize, |this| ((**(this as *const *const ID2D1GradientStopCollection1_Vtbl)).GetBufferPrecision)(this), |_| None)
+        }
+        unsafe extern "system" fn GetColorInterpolationMode(this: *mut core::ffi::c_void) -> D2D1_COLOR_INTERPOLATION_MODE {
+            windows_core::imp::proxy_call(this, "ID2D1GradientStopCollection1", "GetColorInterpolationMode", 12usize, |this| ((**(this as *const *const ID2D1GradientStopCollection1_Vtbl)).GetColorInterpolationMode)(this), |_| None)
+        }
+        Self {
+            base__: <ID2D1GradientStopCollection_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetGradientStops1,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetGradientStops1: 0,
+            GetPreInterpolationSpace,
+            GetPostInterpolationSpace,
+            GetBufferPrecision,
+            GetColorInterpolationMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1GradientStopCollection1 as windows_core::Interface>::IID || <ID2D1GradientStopCollection_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ID2D1HwndRenderTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CheckWindowState(this: *mut core::ffi::c_void) -> D2D1_WINDOW_STATE {
+            windows_core::imp::proxy_call(this, "ID2D1HwndRenderTarget", "CheckWindowState", 57usize, |this| ((**(this as *const *const ID2D1HwndRenderTarget_Vtbl)).CheckWindowState)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn Resize(this: *mut core::ffi::c_void, pixelsize: *const Common::D2D_SIZE_U) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1HwndRenderTarget", "Resize", 58usize, |this| ((**(this as *const *const ID2D1HwndRenderTarget_Vtbl)).Resize)(this, pixelsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHwnd(this: *mut core::ffi::c_void) -> super::super::Foundation::HWND {
+            windows_core::imp::proxy_call(this, "ID2D1HwndRenderTarget", "GetHwnd", 59usize, |this| ((**(this as *const *const ID2D1HwndRenderTarget_Vtbl)).GetHwnd)(this), |_| None)
+        }
+        Self {
+            base__: <ID2D1RenderTarget_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CheckWindowState,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            Resize,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            Resize: 0,
+            GetHwnd,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1HwndRenderTarget as windows_core::Interface>::IID || <ID2D1RenderTarget_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1Image_Vtbl {
+    const PROXY: Self = { Self { base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Image as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1ImageBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetImage(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1ImageBrush", "SetImage", 8usize, |this| ((**(this as *const *const ID2D1ImageBrush_Vtbl)).SetImage)(this, image), |_| None)
+        }
+        unsafe extern "system" fn SetExtendModeX(this: *mut core::ffi::c_void, extendmodex: D2D1_EXTEND_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1ImageBrush", "SetExtendModeX", 9usize, |this| ((**(this as *const *const ID2D1ImageBrush_Vtbl)).SetExtendModeX)(this, extendmodex), |_| None)
+        }
+        unsafe extern "system" fn SetExtendModeY(this: *mut core::ffi::c_void, extendmodey: D2D1_EXTEND_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1ImageBrush", "SetExtendModeY", 10usize, |this| ((**(this as *const *const ID2D1ImageBrush_Vtbl)).SetExtendModeY)(this, extendmodey), |_| None)
+        }
+        unsafe extern "system" fn SetInterpolationMode(this: *mut core::ffi::c_void, interpolationmode: D2D1_INTERPOLATION_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1ImageBrush", "SetInterpolationMode", 11usize, |this| ((**(this as *const *const ID2D1ImageBrush_Vtbl)).SetInterpolationMode)(this, interpolationmode), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetSourceRectangle(this: *mut core::ffi::c_void, sourcerectangle: *const Common::D2D_RECT_F) {
+            windows_core::imp::proxy_call(this, "ID2D1ImageBrush", "SetSourceRectangle", 12usize, |this| ((**(this as *const *const ID2D1ImageBrush_Vtbl)).SetSourceRectangle)(this, sourcerectangle), |_| None)
+        }
+        unsafe extern "system" fn GetImage(this: *mut core::ffi::c_void, image: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1ImageBrush", "GetImage", 13usize, |this| ((**(this as *const *const ID2D1ImageBrush_Vtbl)).GetImage)(this, image), |_| None)
+        }
+        unsafe extern "system" fn GetExtendModeX(this: *mut core::ffi::c_void) -> D2D1_EXTEND_MODE {
+            windows_core::imp::proxy_call(this, "ID2D1ImageBrush", "GetExtendModeX", 14usize, |this| ((**(this as *const *const ID2D1ImageBrush_Vtbl)).GetExtendModeX)(this), |_| None)
+        }
+        unsafe extern "system" fn GetExtendModeY(this: *mut core::ffi::c_void) -> D2D1_EXTEND_MODE {
+            windows_core::imp::proxy_call(this, "ID2D1ImageBrush", "GetExtendModeY", 15usize, |this| ((**(this as *const *const ID2D1ImageBrush_Vtbl)).GetExtendModeY)(this), |_| None)
+        }
+        unsafe extern "system" fn GetInterpolationMode(this: *mut core::ffi::c_void) -> D2D1_INTERPOLATION_MODE {
+            windows_core::imp::proxy_call(this, "ID2D1ImageBrush", "GetInterpolationMode", 16usize, |this| ((**(this as *const *const ID2D1ImageBrush_Vtbl)).GetInterpolationMode)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetSourceRectangle(this: *mut core::ffi::c_void, sourcerectangle: *mut Common::D2D_RECT_F) {
+            windows_core::imp::proxy_call(this, "ID2D1ImageBrush", "GetSourceRectangle", 17usize, |this| ((**(this as *const *const ID2D1ImageBrush_Vtbl)).GetSourceRectangle)(this, sourcerectangle), |_| None)
+        }
+        Self {
+            base__: <ID2D1Brush_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetImage,
+            SetExtendModeX,
+            SetExtendModeY,
+            SetInterpolationMode,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetSourceRectangle,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetSourceRectangle: 0,
+            GetImage,
+            GetExtendModeX,
+            GetExtendModeY,
+            GetInterpolationMode,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetSourceRectangle,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetSourceRectangle: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1ImageBrush as windows_core::Interface>::IID || <ID2D1Brush_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1ImageSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OfferResources(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1ImageSource", "OfferResources", 4usize, |this| ((**(this as *const *const ID2D1ImageSource_Vtbl)).OfferResources)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryReclaimResources(this: *mut core::ffi::c_void, resourcesdiscarded: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1ImageSource", "TryReclaimResources", 5usize, |this| ((**(this as *const *const ID2D1ImageSource_Vtbl)).TryReclaimResources)(this, resourcesdiscarded), |result| Some(*result))
+        }
+        Self { base__: <ID2D1Image_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OfferResources, TryReclaimResources }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1ImageSource as windows_core::Interface>::IID || <ID2D1Image_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ID2D1ImageSourceFromWic_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn EnsureCached(this: *mut core::ffi::c_void, rectangletofill: *const Common::D2D_RECT_U) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1ImageSourceFromWic", "EnsureCached", 6usize, |this| ((**(this as *const *const ID2D1ImageSourceFromWic_Vtbl)).EnsureCached)(this, rectangletofill), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn TrimCache(this: *mut core::ffi::c_void, rectangletopreserve: *const Common::D2D_RECT_U) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1ImageSourceFromWic", "TrimCache", 7usize, |this| ((**(this as *const *const ID2D1ImageSourceFromWic_Vtbl)).TrimCache)(this, rectangletopreserve), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Imaging")]
+        unsafe extern "system" fn GetSource(this: *mut core::ffi::c_void, wicbitmapsource: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1ImageSourceFromWic", "GetSource", 8usize, |this| ((**(this as *const *const ID2D1ImageSourceFromWic_Vtbl)).GetSource)(this, wicbitmapsource), |_| None)
+        }
+        Self {
+            base__: <ID2D1ImageSource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            EnsureCached,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            EnsureCached: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            TrimCache,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            TrimCache: 0,
+            #[cfg(feature = "Win32_Graphics_Imaging")]
+            GetSource,
+            #[cfg(not(feature = "Win32_Graphics_Imaging"))]
+            GetSource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1ImageSourceFromWic as windows_core::Interface>::IID || <ID2D1ImageSource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1Ink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStartPoint(this: *mut core::ffi::c_void, startpoint: *const D2D1_INK_POINT) {
+            windows_core::imp::proxy_call(this, "ID2D1Ink", "SetStartPoint", 4usize, |this| ((**(this as *const *const ID2D1Ink_Vtbl)).SetStartPoint)(this, startpoint), |_| None)
+        }
+        unsafe extern "system" fn GetStartPoint(this: *mut core::ffi::c_void, result__: *mut D2D1_INK_POINT) {
+            windows_core::imp::proxy_call(this, "ID2D1Ink", "GetStartPoint", 5usize, |this| ((**(this as *const *const ID2D1Ink_Vtbl)).GetStartPoint)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn AddSegments(this: *mut core::ffi::c_void, segments: *const D2D1_INK_BEZIER_SEGMENT, segmentscount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Ink", "AddSegments", 6usize, |this| ((**(this as *const *const ID2D1Ink_Vtbl)).AddSegments)(this, segments, segmentscount), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSegmentsAtEnd(this: *mut core::ffi::c_void, segmentscount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Ink", "RemoveSegmentsAtEnd", 7usize, |this| ((**(this as *const *const ID2D1Ink_Vtbl)).RemoveSegmentsAtEnd)(this, segmentscount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSegments(this: *mut core::ffi::c_void, startsegment: u32, segments: *const D2D1_INK_BEZIER_SEGMENT, segmentscount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Ink", "SetSegments", 8usize, |this| ((**(this as *const *const ID2D1Ink_Vtbl)).SetSegments)(this, startsegment, segments, segmentscount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSegmentAtEnd(this: *mut core::ffi::c_void, segment: *const D2D1_INK_BEZIER_SEGMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Ink", "SetSegmentAtEnd", 9usize, |this| ((**(this as *const *const ID2D1Ink_Vtbl)).SetSegmentAtEnd)(this, segment), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSegmentCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1Ink", "GetSegmentCount", 10usize, |this| ((**(this as *const *const ID2D1Ink_Vtbl)).GetSegmentCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetSegments(this: *mut core::ffi::c_void, startsegment: u32, segments: *mut D2D1_INK_BEZIER_SEGMENT, segmentscount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Ink", "GetSegments", 11usize, |this| ((**(this as *const *const ID2D1Ink_Vtbl)).GetSegments)(this, startsegment, segments, segmentscount), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn StreamAsGeometry(this: *mut core::ffi::c_void, inkstyle: *mut core::ffi::c_void, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, flatteningtolerance: f32, geometrysink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Ink", "StreamAsGeometry", 12usize, |this| ((**(this as *const *const ID2D1Ink_Vtbl)).StreamAsGeometry)(this, inkstyle, worldtransform, flatteningtolerance, geometrysink), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn GetBounds(this: *mut core::ffi::c_void, inkstyle: *mut core::ffi::c_void, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, bounds: *mut Common::D2D_RECT_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Ink", "GetBounds", 13usize, |this| ((**(this as *const *const ID2D1Ink_Vtbl)).GetBounds)(this, inkstyle, worldtransform, bounds), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetStartPoint,
+            GetStartPoint,
+            AddSegments,
+            RemoveSegmentsAtEnd,
+            SetSegments,
+            SetSegmentAtEnd,
+            GetSegmentCount,
+            GetSegments,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            StreamAsGeometry,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            StreamAsGeometry: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            GetBounds,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            GetBounds: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Ink as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for ID2D1InkStyle_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetNibTransform(this: *mut core::ffi::c_void, transform: *const super::super::super::Foundation::Numerics::Matrix3x2) {
+            windows_core::imp::proxy_call(this, "ID2D1InkStyle", "SetNibTransform", 4usize, |this| ((**(this as *const *const ID2D1InkStyle_Vtbl)).SetNibTransform)(this, transform), |_| None)
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn GetNibTransform(this: *mut core::ffi::c_void, transform: *mut super::super::super::Foundation::Numerics::Matrix3x2) {
+            windows_core::imp::proxy_call(this, "ID2D1InkStyle", "GetNibTransform", 5usize, |this| ((**(this as *const *const ID2D1InkStyle_Vtbl)).GetNibTransform)(this, transform), |_| None)
+        }
+        unsafe extern "system" fn SetNibShape(this: *mut core::ffi::c_void, nibshape: D2D1_INK_NIB_SHAPE) {
+            windows_core::imp::proxy_call(this, "ID2D1InkStyle", "SetNibShape", 6usize, |this| ((**(this as *const *const ID2D1InkStyle_Vtbl)).SetNibShape)(this, nibshape), |_| None)
+        }
+        unsafe extern "system" fn GetNibShape(this: *mut core::ffi::c_void) -> D2D1_INK_NIB_SHAPE {
+            windows_core::imp::proxy_call(this, "ID2D1InkStyle", "GetNibShape", 7usize, |this| ((**(this as *const *const ID2D1InkStyle_Vtbl)).GetNibShape)(this), |_| None)
+        }
+        Self {
+            base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetNibTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetNibTransform: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            GetNibTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            GetNibTransform: 0,
+            SetNibShape,
+            GetNibShape,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1InkStyle as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for ID2D1Layer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, result__: *mut Common::D2D_SIZE_F) {
+            windows_core::imp::proxy_call(this, "ID2D1Layer", "GetSize", 4usize, |this| ((**(this as *const *const ID2D1Layer_Vtbl)).GetSize)(this, result__), |_| None)
+        }
+        Self {
+            base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetSize,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetSize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Layer as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1LinearGradientBrush_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetStartPoint(this: *mut core::ffi::c_void, startpoint: Common::D2D_POINT_2F) {
+            windows_core::imp::proxy_call(this, "ID2D1LinearGradientBrush", "SetStartPoint", 8usize, |this| ((**(this as *const *const ID2D1LinearGradientBrush_Vtbl)).SetStartPoint)(this, startpoint), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetEndPoint(this: *mut core::ffi::c_void, endpoint: Common::D2D_POINT_2F) {
+            windows_core::imp::proxy_call(this, "ID2D1LinearGradientBrush", "SetEndPoint", 9usize, |this| ((**(this as *const *const ID2D1LinearGradientBrush_Vtbl)).SetEndPoint)(this, endpoint), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetStartPoint(this: *mut core::ffi::c_void, result__: *mut Common::D2D_POINT_2F) {
+            windows_core::imp::proxy_call(this, "ID2D1LinearGradientBrush", "GetStartPoint", 10usize, |this| ((**(this as *const *const ID2D1LinearGradientBrush_Vtbl)).GetStartPoint)(this, result__), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetEndPoint(this: *mut core::ffi::c_void, result__: *mut Common::D2D_POINT_2F) {
+            windows_core::imp::proxy_call(this, "ID2D1LinearGradientBrush", "GetEndPoint", 11usize, |this| ((**(this as *const *const ID2D1LinearGradientBrush_Vtbl)).GetEndPoint)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn GetGradientStopCollection(this: *mut core::ffi::c_void, gradientstopcollection: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1LinearGradientBrush", "GetGradientStopCollection", 12usize, |this| ((**(this as *const *const ID2D1LinearGradientBrush_Vtbl)).GetGradientStopCollection)(this, gradientstopcollection), |_| None)
+        }
+        Self {
+            base__: <ID2D1Brush_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetStartPoint,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetStartPoint: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetEndPoint,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetEndPoint: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetStartPoint,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetStartPoint: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetEndPoint,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetEndPoint: 0,
+            GetGradientStopCollection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1LinearGradientBrush as windows_core::Interface>::IID || <ID2D1Brush_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1LookupTable3D_Vtbl {
+    const PROXY: Self = { Self { base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1LookupTable3D as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1Mesh_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, tessellationsink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Mesh", "Open", 4usize, |this| ((**(this as *const *const ID2D1Mesh_Vtbl)).Open)(this, tessellationsink), |result| Some(*result))
+        }
+        Self { base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Open }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Mesh as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1Multithread_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMultithreadProtected(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID2D1Multithread", "GetMultithreadProtected", 3usize, |this| ((**(this as *const *const ID2D1Multithread_Vtbl)).GetMultithreadProtected)(this), |_| None)
+        }
+        unsafe extern "system" fn Enter(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1Multithread", "Enter", 4usize, |this| ((**(this as *const *const ID2D1Multithread_Vtbl)).Enter)(this), |_| None)
+        }
+        unsafe extern "system" fn Leave(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1Multithread", "Leave", 5usize, |this| ((**(this as *const *const ID2D1Multithread_Vtbl)).Leave)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMultithreadProtected, Enter, Leave }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Multithread as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1OffsetTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, offset: super::super::Foundation::POINT) {
+            windows_core::imp::proxy_call(this, "ID2D1OffsetTransform", "SetOffset", 4usize, |this| ((**(this as *const *const ID2D1OffsetTransform_Vtbl)).SetOffset)(this, offset), |_| None)
+        }
+        unsafe extern "system" fn GetOffset(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::POINT) {
+            windows_core::imp::proxy_call(this, "ID2D1OffsetTransform", "GetOffset", 5usize, |this| ((**(this as *const *const ID2D1OffsetTransform_Vtbl)).GetOffset)(this, result__), |_| None)
+        }
+        Self { base__: <ID2D1TransformNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetOffset, GetOffset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1OffsetTransform as windows_core::Interface>::IID || <ID2D1TransformNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1PathGeometry_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, geometrysink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1PathGeometry", "Open", 17usize, |this| ((**(this as *const *const ID2D1PathGeometry_Vtbl)).Open)(this, geometrysink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn Stream(this: *mut core::ffi::c_void, geometrysink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1PathGeometry", "Stream", 18usize, |this| ((**(this as *const *const ID2D1PathGeometry_Vtbl)).Stream)(this, geometrysink), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSegmentCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1PathGeometry", "GetSegmentCount", 19usize, |this| ((**(this as *const *const ID2D1PathGeometry_Vtbl)).GetSegmentCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFigureCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1PathGeometry", "GetFigureCount", 20usize, |this| ((**(this as *const *const ID2D1PathGeometry_Vtbl)).GetFigureCount)(this, count), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Geometry_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            Open,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            Open: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            Stream,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            Stream: 0,
+            GetSegmentCount,
+            GetFigureCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1PathGeometry as windows_core::Interface>::IID || <ID2D1Geometry_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1PathGeometry1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn ComputePointAndSegmentAtLength(this: *mut core::ffi::c_void, length: f32, startsegment: u32, worldtransform: *const super::super::super::Foundation::Numerics::Matrix3x2, flatteningtolerance: f32, pointdescription: *mut D2D1_POINT_DESCRIPTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1PathGeometry1", "ComputePointAndSegmentAtLength", 21usize, |this| ((**(this as *const *const ID2D1PathGeometry1_Vtbl)).ComputePointAndSegmentAtLength)(this, length, startsegment, worldtransform, flatteningtolerance, pointdescription), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1PathGeometry_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            ComputePointAndSegmentAtLength,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            ComputePointAndSegmentAtLength: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1PathGeometry1 as windows_core::Interface>::IID || <ID2D1PathGeometry_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ID2D1PrintControl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn AddPage(this: *mut core::ffi::c_void, commandlist: *mut core::ffi::c_void, pagesize: Common::D2D_SIZE_F, pageprintticketstream: *mut core::ffi::c_void, tag1: *mut u64, tag2: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1PrintControl", "AddPage", 3usize, |this| ((**(this as *const *const ID2D1PrintControl_Vtbl)).AddPage)(this, commandlist, pagesize, pageprintticketstream, tag1, tag2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1PrintControl", "Close", 4usize, |this| ((**(this as *const *const ID2D1PrintControl_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_System_Com"))]
+            AddPage,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_System_Com")))]
+            AddPage: 0,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1PrintControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1Properties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1Properties", "GetPropertyCount", 3usize, |this| ((**(this as *const *const ID2D1Properties_Vtbl)).GetPropertyCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetPropertyName(this: *mut core::ffi::c_void, index: u32, name: windows_core::PWSTR, namecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Properties", "GetPropertyName", 4usize, |this| ((**(this as *const *const ID2D1Properties_Vtbl)).GetPropertyName)(this, index, name, namecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyNameLength(this: *mut core::ffi::c_void, index: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1Properties", "GetPropertyNameLength", 5usize, |this| ((**(this as *const *const ID2D1Properties_Vtbl)).GetPropertyNameLength)(this, index), |_| None)
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, index: u32) -> D2D1_PROPERTY_TYPE {
+            windows_core::imp::proxy_call(this, "ID2D1Properties", "GetType", 6usize, |this| ((**(this as *const *const ID2D1Properties_Vtbl)).GetType)(this, index), |_| None)
+        }
+        unsafe extern "system" fn GetPropertyIndex(this: *mut core::ffi::c_void, name: windows_core::PCWSTR) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1Properties", "GetPropertyIndex", 7usize, |this| ((**(this as *const *const ID2D1Properties_Vtbl)).GetPropertyIndex)(this, name), |_| None)
+        }
+        unsafe extern "system" fn SetValueByName(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, r#type: D2D1_PROPERTY_TYPE, data: *const u8, datasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Properties", "SetValueByName", 8usize, |this| ((**(this as *const *const ID2D1Properties_Vtbl)).SetValueByName)(this, name, r#type, data, datasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, index: u32, r#type: D2D1_PROPERTY_TYPE, data: *const u8, datasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Properties", "SetValue", 9usize, |this| ((**(this as *const *const ID2D1Properties_Vtbl)).SetValue)(this, index, r#type, data, datasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValueByName(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, r#type: D2D1_PROPERTY_TYPE, data: *mut u8, datasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Properties", "GetValueByName", 10usize, |this| ((**(this as *const *const ID2D1Properties_Vtbl)).GetValueByName)(this, name, r#type, data, datasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, index: u32, r#type: D2D1_PROPERTY_TYPE, data: *mut u8, datasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Properties", "GetValue", 11usize, |this| ((**(this as *const *const ID2D1Properties_Vtbl)).GetValue)(this, index, r#type, data, datasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValueSize(this: *mut core::ffi::c_void, index: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1Properties", "GetValueSize", 12usize, |this| ((**(this as *const *const ID2D1Properties_Vtbl)).GetValueSize)(this, index), |_| None)
+        }
+        unsafe extern "system" fn GetSubProperties(this: *mut core::ffi::c_void, index: u32, subproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Properties", "GetSubProperties", 13usize, |this| ((**(this as *const *const ID2D1Properties_Vtbl)).GetSubProperties)(this, index, subproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPropertyCount,
+            GetPropertyName,
+            GetPropertyNameLength,
+            GetType,
+            GetPropertyIndex,
+            SetValueByName,
+            SetValue,
+            GetValueByName,
+            GetValue,
+            GetValueSize,
+            GetSubProperties,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Properties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1RadialGradientBrush_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetCenter(this: *mut core::ffi::c_void, center: Common::D2D_POINT_2F) {
+            windows_core::imp::proxy_call(this, "ID2D1RadialGradientBrush", "SetCenter", 8usize, |this| ((**(this as *const *const ID2D1RadialGradientBrush_Vtbl)).SetCenter)(this, center), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetGradientOriginOffset(this: *mut core::ffi::c_void, gradientoriginoffset: Common::D2D_POINT_2F) {
+            windows_core::imp::proxy_call(this, "ID2D1RadialGradientBrush", "SetGradientOriginOffset", 9usize, |this| ((**(this as *const *const ID2D1RadialGradientBrush_Vtbl)).SetGradientOriginOffset)(this, gradientoriginoffset), |_| None)
+        }
+        unsafe extern "system" fn SetRadiusX(this: *mut core::ffi::c_void, radiusx: f32) {
+            windows_core::imp::proxy_call(this, "ID2D1RadialGradientBrush", "SetRadiusX", 10usize, |this| ((**(this as *const *const ID2D1RadialGradientBrush_Vtbl)).SetRadiusX)(this, radiusx), |_| None)
+        }
+        unsafe extern "system" fn SetRadiusY(this: *mut core::ffi::c_void, radiusy: f32) {
+            windows_core::imp::proxy_call(this, "ID2D1RadialGradientBrush", "SetRadiusY", 11usize, |this| ((**(this as *const *const ID2D1RadialGradientBrush_Vtbl)).SetRadiusY)(this, radiusy), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetCenter(this: *mut core::ffi::c_void, result__: *mut Common::D2D_POINT_2F) {
+            windows_core::imp::proxy_call(this, "ID2D1RadialGradientBrush", "GetCenter", 12usize, |this| ((**(this as *const *const ID2D1RadialGradientBrush_Vtbl)).GetCenter)(this, result__), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetGradientOriginOffset(this: *mut core::ffi::c_void, result__: *mut Common::D2D_POINT_2F) {
+            windows_core::imp::proxy_call(this, "ID2D1RadialGradientBrush", "GetGradientOriginOffset", 13usize, |this| ((**(this as *const *const ID2D1RadialGradientBrush_Vtbl)).GetGradientOriginOffset)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn GetRadiusX(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "ID2D1RadialGradientBrush", "GetRadiusX", 14usize, |this| ((**(this as *const *const ID2D1RadialGradientBrush_Vtbl)).GetRadiusX)(this), |_| None)
+        }
+        unsafe extern "system" fn GetRadiusY(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "ID2D1RadialGradientBrush", "GetRadiusY", 15usize, |this| ((**(this as *const *const ID2D1RadialGradientBrush_Vtbl)).GetRadiusY)(this), |_| None)
+        }
+        unsafe extern "system" fn GetGradientStopCollection(this: *mut core::ffi::c_void, gradientstopcollection: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RadialGradientBrush", "GetGradientStopCollection", 16usize, |this| ((**(this as *const *const ID2D1RadialGradientBrush_Vtbl)).GetGradientStopCollection)(this, gradientstopcollection), |_| None)
+        }
+        Self {
+            base__: <ID2D1Brush_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetCenter,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetCenter: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetGradientOriginOffset,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetGradientOriginOffset: 0,
+            SetRadiusX,
+            SetRadiusY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetCenter,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetCenter: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetGradientOriginOffset,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetGradientOriginOffset: 0,
+            GetRadiusX,
+            GetRadiusY,
+            GetGradientStopCollection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1RadialGradientBrush as windows_core::Interface>::IID || <ID2D1Brush_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1RectangleGeometry_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetRect(this: *mut core::ffi::c_void, rect: *mut Common::D2D_RECT_F) {
+            windows_core::imp::proxy_call(this, "ID2D1RectangleGeometry", "GetRect", 17usize, |this| ((**(this as *const *const ID2D1RectangleGeometry_Vtbl)).GetRect)(this, rect), |_| None)
+        }
+        Self {
+            base__: <ID2D1Geometry_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetRect,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetRect: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1RectangleGeometry as windows_core::Interface>::IID || <ID2D1Geometry_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1RenderInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetInputDescription(this: *mut core::ffi::c_void, inputindex: u32, inputdescription: D2D1_INPUT_DESCRIPTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1RenderInfo", "SetInputDescription", 3usize, |this| ((**(this as *const *const ID2D1RenderInfo_Vtbl)).SetInputDescription)(this, inputindex, inputdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputBuffer(this: *mut core::ffi::c_void, bufferprecision: D2D1_BUFFER_PRECISION, channeldepth: D2D1_CHANNEL_DEPTH) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1RenderInfo", "SetOutputBuffer", 4usize, |this| ((**(this as *const *const ID2D1RenderInfo_Vtbl)).SetOutputBuffer)(this, bufferprecision, channeldepth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCached(this: *mut core::ffi::c_void, iscached: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderInfo", "SetCached", 5usize, |this| ((**(this as *const *const ID2D1RenderInfo_Vtbl)).SetCached)(this, iscached), |_| None)
+        }
+        unsafe extern "system" fn SetInstructionCountHint(this: *mut core::ffi::c_void, instructioncount: u32) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderInfo", "SetInstructionCountHint", 6usize, |this| ((**(this as *const *const ID2D1RenderInfo_Vtbl)).SetInstructionCountHint)(this, instructioncount), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetInputDescription,
+            SetOutputBuffer,
+            SetCached,
+            SetInstructionCountHint,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1RenderInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for ID2D1RenderTarget_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateBitmap(this: *mut core::ffi::c_void, size: Common::D2D_SIZE_U, srcdata: *const core::ffi::c_void, pitch: u32, bitmapproperties: *const D2D1_BITMAP_PROPERTIES, bitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "CreateBitmap", 4usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).CreateBitmap)(this, size, srcdata, pitch, bitmapproperties, bitmap), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+        unsafe extern "system" fn CreateBitmapFromWicBitmap(this: *mut core::ffi::c_void, wicbitmapsource: *mut core::ffi::c_void, bitmapproperties: *const D2D1_BITMAP_PROPERTIES, bitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "CreateBitmapFromWicBitmap", 5usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).CreateBitmapFromWicBitmap)(this, wicbitmapsource, bitmapproperties, bitmap), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateSharedBitmap(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, data: *mut core::ffi::c_void, bitmapproperties: *const D2D1_BITMAP_PROPERTIES, bitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "CreateSharedBitmap", 6usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).CreateSharedBitmap)(this, riid, data, bitmapproperties, bitmap), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn CreateBitmapBrush(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, bitmapbrushproperties: *const D2D1_BITMAP_BRUSH_PROPERTIES, brushproperties: *const D2D1_BRUSH_PROPERTIES, bitmapbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "CreateBitmapBrush", 7usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).CreateBitmapBrush)(this, bitmap, bitmapbrushproperties, brushproperties, bitmapbrush), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn CreateSolidColorBrush(this: *mut core::ffi::c_void, color: *const Common::D2D1_COLOR_F, brushproperties: *const D2D1_BRUSH_PROPERTIES, solidcolorbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "CreateSolidColorBrush", 8usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).CreateSolidColorBrush)(this, color, brushproperties, solidcolorbrush), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CreateGradientStopCollection(this: *mut core::ffi::c_void, gradientstops: *const Common::D2D1_GRADIENT_STOP, gradientstopscount: u32, colorinterpolationgamma: D2D1_GAMMA, extendmode: D2D1_EXTEND_MODE, gradientstopcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "CreateGradientStopCollection", 9usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).CreateGradientStopCollection)(this, gradientstops, gradientstopscount, colorinterpolationgamma, extendmode, gradientstopcollection), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn CreateLinearGradientBrush(this: *mut core::ffi::c_void, lineargradientbrushproperties: *const D2D1_LINEAR_GRADIENT_BRUSH_PROPERTIES, brushproperties: *const D2D1_BRUSH_PROPERTIES, gradientstopcollection: *mut core::ffi::c_void, lineargradientbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "CreateLinearGradientBrush", 10usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).CreateLinearGradientBrush)(this, lineargradientbrushproperties, brushproperties, gradientstopcollection, lineargradientbrush), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn CreateRadialGradientBrush(this: *mut core::ffi::c_void, radialgradientbrushproperties: *const D2D1_RADIAL_GRADIENT_BRUSH_PROPERTIES, brushproperties: *const D2D1_BRUSH_PROPERTIES, gradientstopcollection: *mut core::ffi::c_void, radialgradientbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "CreateRadialGradientBrush", 11usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).CreateRadialGradientBrush)(this, radialgradientbrushproperties, brushproperties, gradientstopcollection, radialgradientbrush), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateCompatibleRenderTarget(this: *mut core::ffi::c_void, desiredsize: *const Common::D2D_SIZE_F, desiredpixelsize: *const Common::D2D_SIZE_U, desiredformat: *const Common::D2D1_PIXEL_FORMAT, options: D2D1_COMPATIBLE_RENDER_TARGET_OPTIONS, bitmaprendertarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "CreateCompatibleRenderTarget", 12usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).CreateCompatibleRenderTarget)(this, desiredsize, desiredpixelsize, desiredformat, options, bitmaprendertarget), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CreateLayer(this: *mut core::ffi::c_void, size: *const Common::D2D_SIZE_F, layer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "CreateLayer", 13usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).CreateLayer)(this, size, layer), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMesh(this: *mut core::ffi::c_void, mesh: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "CreateMesh", 14usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).CreateMesh)(this, mesh), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn DrawLine(this: *mut core::ffi::c_void, point0: Common::D2D_POINT_2F, point1: Common::D2D_POINT_2F, brush: *mut core::ffi::c_void, strokewidth: f32, strokestyle: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "DrawLine", 15usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).DrawLine)(this, point0, point1, brush, strokewidth, strokestyle), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn DrawRectangle(this: *mut core::ffi::c_void, rect: *const Common::D2D_RECT_F, brush: *mut core::ffi::c_void, strokewidth: f32, strokestyle: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "DrawRectangle", 16usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).DrawRectangle)(this, rect, brush, strokewidth, strokestyle), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn FillRectangle(this: *mut core::ffi::c_void, rect: *const Common::D2D_RECT_F, brush: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "FillRectangle", 17usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).FillRectangle)(this, rect, brush), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn DrawRoundedRectangle(this: *mut core::ffi::c_void, roundedrect: *const D2D1_ROUNDED_RECT, brush: *mut core::ffi::c_void, strokewidth: f32, strokestyle: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "DrawRoundedRectangle", 18usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).DrawRoundedRectangle)(this, roundedrect, brush, strokewidth, strokestyle), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn FillRoundedRectangle(this: *mut core::ffi::c_void, roundedrect: *const D2D1_ROUNDED_RECT, brush: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "FillRoundedRectangle", 19usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).FillRoundedRectangle)(this, roundedrect, brush), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn DrawEllipse(this: *mut core::ffi::c_void, ellipse: *const D2D1_ELLIPSE, brush: *mut core::ffi::c_void, strokewidth: f32, strokestyle: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "DrawEllipse", 20usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).DrawEllipse)(this, ellipse, brush, strokewidth, strokestyle), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn FillEllipse(this: *mut core::ffi::c_void, ellipse: *const D2D1_ELLIPSE, brush: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "FillEllipse", 21usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).FillEllipse)(this, ellipse, brush), |_| None)
+        }
+        unsafe extern "system" fn DrawGeometry(this: *mut core::ffi::c_void, geometry: *mut core::ffi::c_void, brush: *mut core::ffi::c_void, strokewidth: f32, strokestyle: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "DrawGeometry", 22usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).DrawGeometry)(this, geometry, brush, strokewidth, strokestyle), |_| None)
+        }
+        unsafe extern "system" fn FillGeometry(this: *mut core::ffi::c_void, geometry: *mut core::ffi::c_void, brush: *mut core::ffi::c_void, opacitybrush: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "FillGeometry", 23usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).FillGeometry)(this, geometry, brush, opacitybrush), |_| None)
+        }
+        unsafe extern "system" fn FillMesh(this: *mut core::ffi::c_void, mesh: *mut core::ffi::c_void, brush: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "FillMesh", 24usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).FillMesh)(this, mesh, brush), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn FillOpacityMask(this: *mut core::ffi::c_void, opacitymask: *mut core::ffi::c_void, brush: *mut core::ffi::c_void, content: D2D1_OPACITY_MASK_CONTENT, destinationrectangle: *const Common::D2D_RECT_F, sourcerectangle: *const Common::D2D_RECT_F) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "FillOpacityMask", 25usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).FillOpacityMask)(this, opacitymask, brush, content, destinationrectangle, sourcerectangle), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn DrawBitmap(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, destinationrectangle: *const Common::D2D_RECT_F, opacity: f32, interpolationmode: D2D1_BITMAP_INTERPOLATION_MODE, sourcerectangle: *const Common::D2D_RECT_F) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "DrawBitmap", 26usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).DrawBitmap)(this, bitmap, destinationrectangle, opacity, interpolationmode, sourcerectangle), |_| None)
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn DrawText(this: *mut core::ffi::c_void, string: windows_core::PCWSTR, stringlength: u32, textformat: *mut core::ffi::c_void, layoutrect: *const Common::D2D_RECT_F, defaultfillbrush: *mut core::ffi::c_void, options: D2D1_DRAW_TEXT_OPTIONS, measuringmode: super::DirectWrite::DWRITE_MEASURING_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "DrawText", 27usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).DrawText)(this, string, stringlength, textformat, layoutrect, defaultfillbrush, options, measuringmode), |_| None)
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn DrawTextLayout(this: *mut core::ffi::c_void, origin: Common::D2D_POINT_2F, textlayout: *mut core::ffi::c_void, defaultfillbrush: *mut core::ffi::c_void, options: D2D1_DRAW_TEXT_OPTIONS) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "DrawTextLayout", 28usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).DrawTextLayout)(this, origin, textlayout, defaultfillbrush, options), |_| None)
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+        unsafe extern "system" fn DrawGlyphRun(this: *mut core::ffi::c_void, baselineorigin: Common::D2D_POINT_2F, glyphrun: *const super::DirectWrite::DWRITE_GLYPH_RUN, foregroundbrush: *mut core::ffi::c_void, measuringmode: super::DirectWrite::DWRITE_MEASURING_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "DrawGlyphRun", 29usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).DrawGlyphRun)(this, baselineorigin, glyphrun, foregroundbrush, measuringmode), |_| None)
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetTransform(this: *mut core::ffi::c_void, transform: *const super::super::super::Foundation::Numerics::Matrix3x2) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "SetTransform", 30usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).SetTransform)(this, transform), |_| None)
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn GetTransform(this: *mut core::ffi::c_void, transform: *mut super::super::super::Foundation::Numerics::Matrix3x2) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "GetTransform", 31usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).GetTransform)(this, transform), |_| None)
+        }
+        unsafe extern "system" fn SetAntialiasMode(this: *mut core::ffi::c_void, antialiasmode: D2D1_ANTIALIAS_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "SetAntialiasMode", 32usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).SetAntialiasMode)(this, antialiasmode), |_| None)
+        }
+        unsafe extern "system" fn GetAntialiasMode(this: *mut core::ffi::c_void) -> D2D1_ANTIALIAS_MODE {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "GetAntialiasMode", 33usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).GetAntialiasMode)(this), |_| None)
+        }
+        unsafe extern "system" fn SetTextAntialiasMode(this: *mut core::ffi::c_void, textantialiasmode: D2D1_TEXT_ANTIALIAS_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "SetTextAntialiasMode", 34usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).SetTextAntialiasMode)(this, textantialiasmode), |_| None)
+        }
+        unsafe extern "system" fn GetTextAntialiasMode(this: *mut core::ffi::c_void) -> D2D1_TEXT_ANTIALIAS_MODE {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "GetTextAntialiasMode", 35usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).GetTextAntialiasMode)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_DirectWrite")]
+        unsafe extern "system" fn SetTextRenderingParams(this: *mut core::ffi::c_void, textrenderingparams: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "SetTextRenderingParams", 36usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).SetTextRenderingParams)(this, textrenderingparams), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_DirectWrite")]
+        unsafe extern "system" fn GetTextRenderingParams(this: *mut core::ffi::c_void, textrenderingparams: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "GetTextRenderingParams", 37usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).GetTextRenderingParams)(this, textrenderingparams), |_| None)
+        }
+        unsafe extern "system" fn SetTags(this: *mut core::ffi::c_void, tag1: u64, tag2: u64) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "SetTags", 38usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).SetTags)(this, tag1, tag2), |_| None)
+        }
+        unsafe extern "system" fn GetTags(this: *mut core::ffi::c_void, tag1: *mut u64, tag2: *mut u64) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "GetTags", 39usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).GetTags)(this, tag1, tag2), |_| None)
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn PushLayer(this: *mut core::ffi::c_void, layerparameters: *const D2D1_LAYER_PARAMETERS, layer: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "PushLayer", 40usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).PushLayer)(this, layerparameters, layer), |_| None)
+        }
+        unsafe extern "system" fn PopLayer(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "PopLayer", 41usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).PopLayer)(this), |_| None)
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void, tag1: *mut u64, tag2: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "Flush", 42usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).Flush)(this, tag1, tag2), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveDrawingState(this: *mut core::ffi::c_void, drawingstateblock: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "SaveDrawingState", 43usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).SaveDrawingState)(this, drawingstateblock), |_| None)
+        }
+        unsafe extern "system" fn RestoreDrawingState(this: *mut core::ffi::c_void, drawingstateblock: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "RestoreDrawingState", 44usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).RestoreDrawingState)(this, drawingstateblock), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn PushAxisAlignedClip(this: *mut core::ffi::c_void, cliprect: *const Common::D2D_RECT_F, antialiasmode: D2D1_ANTIALIAS_MODE) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "PushAxisAlignedClip", 45usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).PushAxisAlignedClip)(this, cliprect, antialiasmode), |_| None)
+        }
+        unsafe extern "system" fn PopAxisAlignedClip(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "PopAxisAlignedClip", 46usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).PopAxisAlignedClip)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void, clearcolor: *const Common::D2D1_COLOR_F) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "Clear", 47usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).Clear)(this, clearcolor), |_| None)
+        }
+        unsafe extern "system" fn BeginDraw(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "BeginDraw", 48usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).BeginDraw)(this), |_| None)
+        }
+        unsafe extern "system" fn EndDraw(this: *mut core::ffi::c_void, tag1: *mut u64, tag2: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "EndDraw", 49usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).EndDraw)(this, tag1, tag2), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn GetPixelFormat(this: *mut core::ffi::c_void, result__: *mut Common::D2D1_PIXEL_FORMAT) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "GetPixelFormat", 50usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).GetPixelFormat)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn SetDpi(this: *mut core::ffi::c_void, dpix: f32, dpiy: f32) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "SetDpi", 51usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).SetDpi)(this, dpix, dpiy), |_| None)
+        }
+        unsafe extern "system" fn GetDpi(this: *mut core::ffi::c_void, dpix: *mut f32, dpiy: *mut f32) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "GetDpi", 52usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).GetDpi)(this, dpix, dpiy), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, result__: *mut Common::D2D_SIZE_F) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "GetSize", 53usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).GetSize)(this, result__), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetPixelSize(this: *mut core::ffi::c_void, result__: *mut Common::D2D_SIZE_U) {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "GetPixelSize", 54usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).GetPixelSize)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn GetMaximumBitmapSize(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "GetMaximumBitmapSize", 55usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).GetMaximumBitmapSize)(this), |_| None)
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, rendertargetproperties: *const D2D1_RENDER_TARGET_PROPERTIES) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID2D1RenderTarget", "IsSupported", 56usize, |this| ((**(this as *const *const ID2D1RenderTarget_Vtbl)).IsSupported)(this, rendertargetproperties), |_| None)
+        }
+        Self {
+            base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateBitmap,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateBitmap: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging"))]
+            CreateBitmapFromWicBitmap,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Imaging")))]
+            CreateBitmapFromWicBitmap: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateSharedBitmap,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateSharedBitmap: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            CreateBitmapBrush,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            CreateBitmapBrush: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            CreateSolidColorBrush,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            CreateSolidColorBrush: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CreateGradientStopCollection,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CreateGradientStopCollection: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            CreateLinearGradientBrush,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            CreateLinearGradientBrush: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            CreateRadialGradientBrush,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            CreateRadialGradientBrush: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateCompatibleRenderTarget,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateCompatibleRenderTarget: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CreateLayer,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CreateLayer: 0,
+            CreateMesh,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            DrawLine,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            DrawLine: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            DrawRectangle,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            DrawRectangle: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            FillRectangle,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            FillRectangle: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            DrawRoundedRectangle,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            DrawRoundedRectangle: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            FillRoundedRectangle,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            FillRoundedRectangle: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            DrawEllipse,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            DrawEllipse: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            FillEllipse,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            FillEllipse: 0,
+            DrawGeometry,
+            FillGeometry,
+            FillMesh,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            FillOpacityMask,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            FillOpacityMask: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            DrawBitmap,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            DrawBitmap: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+            DrawText,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite")))]
+            DrawText: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+            DrawTextLayout,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite")))]
+            DrawTextLayout: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite"))]
+            DrawGlyphRun,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_DirectWrite")))]
+            DrawGlyphRun: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetTransform: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            GetTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            GetTransform: 0,
+            SetAntialiasMode,
+            GetAntialiasMode,
+            SetTextAntialiasMode,
+            GetTextAntialiasMode,
+            #[cfg(feature = "Win32_Graphics_DirectWrite")]
+            SetTextRenderingParams,
+            #[cfg(not(feature = "Win32_Graphics_DirectWrite"))]
+            SetTextRenderingParams: 0,
+            #[cfg(feature = "Win32_Graphics_DirectWrite")]
+            GetTextRenderingParams,
+            #[cfg(not(feature = "Win32_Graphics_DirectWrite"))]
+            GetTextRenderingParams: 0,
+            SetTags,
+            GetTags,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            PushLayer,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            PushLayer: 0,
+            PopLayer,
+            Flush,
+            SaveDrawingState,
+            RestoreDrawingState,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            PushAxisAlignedClip,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            PushAxisAlignedClip: 0,
+            PopAxisAlignedClip,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            Clear,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            Clear: 0,
+            BeginDraw,
+            EndDraw,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+            GetPixelFormat,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common")))]
+            GetPixelFormat: 0,
+            SetDpi,
+            GetDpi,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetSize,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetSize: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetPixelSize,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetPixelSize: 0,
+            GetMaximumBitmapSize,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+            IsSupported,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common")))]
+            IsSupported: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1RenderTarget as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1Resource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFactory(this: *mut core::ffi::c_void, factory: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1Resource", "GetFactory", 3usize, |this| ((**(this as *const *const ID2D1Resource_Vtbl)).GetFactory)(this, factory), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFactory }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Resource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1ResourceTexture_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, minimumextents: *const u32, maximimumextents: *const u32, strides: *const u32, dimensions: u32, data: *const u8, datacount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1ResourceTexture", "Update", 3usize, |this| ((**(this as *const *const ID2D1ResourceTexture_Vtbl)).Update)(this, minimumextents, maximimumextents, strides, dimensions, data, datacount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Update }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1ResourceTexture as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1RoundedRectangleGeometry_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetRoundedRect(this: *mut core::ffi::c_void, roundedrect: *mut D2D1_ROUNDED_RECT) {
+            windows_core::imp::proxy_call(this, "ID2D1RoundedRectangleGeometry", "GetRoundedRect", 17usize, |this| ((**(this as *const *const ID2D1RoundedRectangleGeometry_Vtbl)).GetRoundedRect)(this, roundedrect), |_| None)
+        }
+        Self {
+            base__: <ID2D1Geometry_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetRoundedRect,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetRoundedRect: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1RoundedRectangleGeometry as windows_core::Interface>::IID || <ID2D1Geometry_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1SolidColorBrush_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, color: *const Common::D2D1_COLOR_F) {
+            windows_core::imp::proxy_call(this, "ID2D1SolidColorBrush", "SetColor", 8usize, |this| ((**(this as *const *const ID2D1SolidColorBrush_Vtbl)).SetColor)(this, color), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetColor(this: *mut core::ffi::c_void, result__: *mut Common::D2D1_COLOR_F) {
+            windows_core::imp::proxy_call(this, "ID2D1SolidColorBrush", "GetColor", 9usize, |this| ((**(this as *const *const ID2D1SolidColorBrush_Vtbl)).GetColor)(this, result__), |_| None)
+        }
+        Self {
+            base__: <ID2D1Brush_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetColor,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetColor: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetColor,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetColor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1SolidColorBrush as windows_core::Interface>::IID || <ID2D1Brush_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for ID2D1SourceTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRenderInfo(this: *mut core::ffi::c_void, renderinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SourceTransform", "SetRenderInfo", 7usize, |this| ((**(this as *const *const ID2D1SourceTransform_Vtbl)).SetRenderInfo)(this, renderinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn Draw(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, drawrect: *const super::super::Foundation::RECT, targetorigin: Common::D2D_POINT_2U) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SourceTransform", "Draw", 8usize, |this| ((**(this as *const *const ID2D1SourceTransform_Vtbl)).Draw)(this, target, drawrect, targetorigin), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Transform_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetRenderInfo,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            Draw,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            Draw: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1SourceTransform as windows_core::Interface>::IID || <ID2D1Transform_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1SpriteBatch_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn AddSprites(this: *mut core::ffi::c_void, spritecount: u32, destinationrectangles: *const Common::D2D_RECT_F, sourcerectangles: *const Common::D2D_RECT_U, colors: *const Common::D2D1_COLOR_F, transforms: *const super::super::super::Foundation::Numerics::Matrix3x2, destinationrectanglesstride: u32, sourcerectanglesstride: u32, colorsstride: u32, transformsstride: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SpriteBatch", "AddSprites", 4usize, |this| ((**(this as *const *const ID2D1SpriteBatch_Vtbl)).AddSprites)(this, spritecount, destinationrectangles, sourcerectangles, colors, transforms, destinationrectanglesstride, sourcerectanglesstride, colorsstride, transformsstride), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn SetSprites(this: *mut core::ffi::c_void, startindex: u32, spritecount: u32, destinationrectangles: *const Common::D2D_RECT_F, sourcerectangles: *const Common::D2D_RECT_U, colors: *const Common::D2D1_COLOR_F, transforms: *const super::super::super::Foundation::Numerics::Matrix3x2, destinationrectanglesstride: u32, sourcerectanglesstride: u32, colorsstride: u32, transformsstride: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SpriteBatch", "SetSprites", 5usize, |this| ((**(this as *const *const ID2D1SpriteBatch_Vtbl)).SetSprites)(this, startindex, spritecount, destinationrectangles, sourcerectangles, colors, transforms, destinationrectanglesstride, sourcerectanglesstride, colorsstride, transformsstride), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+        unsafe extern "system" fn GetSprites(this: *mut core::ffi::c_void, startindex: u32, spritecount: u32, destinationrectangles: *mut Common::D2D_RECT_F, sourcerectangles: *mut Common::D2D_RECT_U, colors: *mut Common::D2D1_COLOR_F, transforms: *mut super::super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SpriteBatch", "GetSprites", 6usize, |this| ((**(this as *const *const ID2D1SpriteBatch_Vtbl)).GetSprites)(this, startindex, spritecount, destinationrectangles, sourcerectangles, colors, transforms), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpriteCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1SpriteBatch", "GetSpriteCount", 7usize, |this| ((**(this as *const *const ID2D1SpriteBatch_Vtbl)).GetSpriteCount)(this), |_| None)
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1SpriteBatch", "Clear", 8usize, |this| ((**(this as *const *const ID2D1SpriteBatch_Vtbl)).Clear)(this), |_| None)
+        }
+        Self {
+            base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            AddSprites,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            AddSprites: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            SetSprites,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            SetSprites: 0,
+            #[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+            GetSprites,
+            #[cfg(not(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common")))]
+            GetSprites: 0,
+            GetSpriteCount,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1SpriteBatch as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1StrokeStyle_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStartCap(this: *mut core::ffi::c_void) -> D2D1_CAP_STYLE {
+            windows_core::imp::proxy_call(this, "ID2D1StrokeStyle", "GetStartCap", 4usize, |this| ((**(this as *const *const ID2D1StrokeStyle_Vtbl)).GetStartCap)(this), |_| None)
+        }
+        unsafe extern "system" fn GetEndCap(this: *mut core::ffi::c_void) -> D2D1_CAP_STYLE {
+            windows_core::imp::proxy_call(this, "ID2D1StrokeStyle", "GetEndCap", 5usize, |this| ((**(this as *const *const ID2D1StrokeStyle_Vtbl)).GetEndCap)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDashCap(this: *mut core::ffi::c_void) -> D2D1_CAP_STYLE {
+            windows_core::imp::proxy_call(this, "ID2D1StrokeStyle", "GetDashCap", 6usize, |this| ((**(this as *const *const ID2D1StrokeStyle_Vtbl)).GetDashCap)(this), |_| None)
+        }
+        unsafe extern "system" fn GetMiterLimit(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "ID2D1StrokeStyle", "GetMiterLimit", 7usize, |this| ((**(this as *const *const ID2D1StrokeStyle_Vtbl)).GetMiterLimit)(this), |_| None)
+        }
+        unsafe extern "system" fn GetLineJoin(this: *mut core::ffi::c_void) -> D2D1_LINE_JOIN {
+            windows_core::imp::proxy_call(this, "ID2D1StrokeStyle", "GetLineJoin", 8usize, |this| ((**(this as *const *const ID2D1StrokeStyle_Vtbl)).GetLineJoin)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDashOffset(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "ID2D1StrokeStyle", "GetDashOffset", 9usize, |this| ((**(this as *const *const ID2D1StrokeStyle_Vtbl)).GetDashOffset)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDashStyle(this: *mut core::ffi::c_void) -> D2D1_DASH_STYLE {
+            windows_core::imp::proxy_call(this, "ID2D1StrokeStyle", "GetDashStyle", 10usize, |this| ((**(this as *const *const ID2D1StrokeStyle_Vtbl)).GetDashStyle)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDashesCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1StrokeStyle", "GetDashesCount", 11usize, |this| ((**(this as *const *const ID2D1StrokeStyle_Vtbl)).GetDashesCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDashes(this: *mut core::ffi::c_void, dashes: *mut f32, dashescount: u32) {
+            windows_core::imp::proxy_call(this, "ID2D1StrokeStyle", "GetDashes", 12usize, |this| ((**(this as *const *const ID2D1StrokeStyle_Vtbl)).GetDashes)(this, dashes, dashescount), |_| None)
+        }
+        Self {
+            base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStartCap,
+            GetEndCap,
+            GetDashCap,
+            GetMiterLimit,
+            GetLineJoin,
+            GetDashOffset,
+            GetDashStyle,
+            GetDashesCount,
+            GetDashes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1StrokeStyle as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1StrokeStyle1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStrokeTransformType(this: *mut core::ffi::c_void) -> D2D1_STROKE_TRANSFORM_TYPE {
+            windows_core::imp::proxy_call(this, "ID2D1StrokeStyle1", "GetStrokeTransformType", 13usize, |this| ((**(this as *const *const ID2D1StrokeStyle1_Vtbl)).GetStrokeTransformType)(this), |_| None)
+        }
+        Self { base__: <ID2D1StrokeStyle_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStrokeTransformType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1StrokeStyle1 as windows_core::Interface>::IID || <ID2D1StrokeStyle_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1SvgAttribute_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetElement(this: *mut core::ffi::c_void, element: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1SvgAttribute", "GetElement", 4usize, |this| ((**(this as *const *const ID2D1SvgAttribute_Vtbl)).GetElement)(this, element), |_| None)
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, attribute: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgAttribute", "Clone", 5usize, |this| ((**(this as *const *const ID2D1SvgAttribute_Vtbl)).Clone)(this, attribute), |result| Some(*result))
+        }
+        Self { base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetElement, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1SvgAttribute as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ID2D1SvgDocument_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetViewportSize(this: *mut core::ffi::c_void, viewportsize: Common::D2D_SIZE_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgDocument", "SetViewportSize", 4usize, |this| ((**(this as *const *const ID2D1SvgDocument_Vtbl)).SetViewportSize)(this, viewportsize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetViewportSize(this: *mut core::ffi::c_void, result__: *mut Common::D2D_SIZE_F) {
+            windows_core::imp::proxy_call(this, "ID2D1SvgDocument", "GetViewportSize", 5usize, |this| ((**(this as *const *const ID2D1SvgDocument_Vtbl)).GetViewportSize)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn SetRoot(this: *mut core::ffi::c_void, root: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgDocument", "SetRoot", 6usize, |this| ((**(this as *const *const ID2D1SvgDocument_Vtbl)).SetRoot)(this, root), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRoot(this: *mut core::ffi::c_void, root: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1SvgDocument", "GetRoot", 7usize, |this| ((**(this as *const *const ID2D1SvgDocument_Vtbl)).GetRoot)(this, root), |_| None)
+        }
+        unsafe extern "system" fn FindElementById(this: *mut core::ffi::c_void, id: windows_core::PCWSTR, svgelement: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgDocument", "FindElementById", 8usize, |this| ((**(this as *const *const ID2D1SvgDocument_Vtbl)).FindElementById)(this, id, svgelement), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, outputxmlstream: *mut core::ffi::c_void, subtree: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgDocument", "Serialize", 9usize, |this| ((**(this as *const *const ID2D1SvgDocument_Vtbl)).Serialize)(this, outputxmlstream, subtree), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Deserialize(this: *mut core::ffi::c_void, inputxmlstream: *mut core::ffi::c_void, subtree: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgDocument", "Deserialize", 10usize, |this| ((**(this as *const *const ID2D1SvgDocument_Vtbl)).Deserialize)(this, inputxmlstream, subtree), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CreatePaint(this: *mut core::ffi::c_void, painttype: D2D1_SVG_PAINT_TYPE, color: *const Common::D2D1_COLOR_F, id: windows_core::PCWSTR, paint: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgDocument", "CreatePaint", 11usize, |this| ((**(this as *const *const ID2D1SvgDocument_Vtbl)).CreatePaint)(this, painttype, color, id, paint), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStrokeDashArray(this: *mut core::ffi::c_void, dashes: *const D2D1_SVG_LENGTH, dashescount: u32, strokedasharray: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgDocument", "CreateStrokeDashArray", 12usize, |this| ((**(this as *const *const ID2D1SvgDocument_Vtbl)).CreateStrokeDashArray)(this, dashes, dashescount, strokedasharray), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CreatePointCollection(this: *mut core::ffi::c_void, points: *const Common::D2D_POINT_2F, pointscount: u32, pointcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgDocument", "CreatePointCollection", 13usize, |this| ((**(this as *const *const ID2D1SvgDocument_Vtbl)).CreatePointCollection)(this, points, pointscount, pointcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePathData(this: *mut core::ffi::c_void, segmentdata: *const f32, segmentdatacount: u32, commands: *const D2D1_SVG_PATH_COMMAND, commandscount: u32, pathdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgDocument", "CreatePathData", 14usize, |this| ((**(this as *const *const ID2D1SvgDocument_Vtbl)).CreatePathData)(this, segmentdata, segmentdatacount, commands, commandscount, pathdata), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetViewportSize,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetViewportSize: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetViewportSize,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetViewportSize: 0,
+            SetRoot,
+            GetRoot,
+            FindElementById,
+            #[cfg(feature = "Win32_System_Com")]
+            Serialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Serialize: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Deserialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Deserialize: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CreatePaint,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CreatePaint: 0,
+            CreateStrokeDashArray,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CreatePointCollection,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CreatePointCollection: 0,
+            CreatePathData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1SvgDocument as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1SvgElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocument(this: *mut core::ffi::c_void, document: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetDocument", 4usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetDocument)(this, document), |_| None)
+        }
+        unsafe extern "system" fn GetTagName(this: *mut core::ffi::c_void, name: windows_core::PWSTR, namecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetTagName", 5usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetTagName)(this, name, namecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTagNameLength(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetTagNameLength", 6usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetTagNameLength)(this), |_| None)
+        }
+        unsafe extern "system" fn IsTextContent(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "IsTextContent", 7usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).IsTextContent)(this), |_| None)
+        }
+        unsafe extern "system" fn GetParent(this: *mut core::ffi::c_void, parent: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetParent", 8usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetParent)(this, parent), |_| None)
+        }
+        unsafe extern "system" fn HasChildren(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "HasChildren", 9usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).HasChildren)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFirstChild(this: *mut core::ffi::c_void, child: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetFirstChild", 10usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetFirstChild)(this, child), |_| None)
+        }
+        unsafe extern "system" fn GetLastChild(this: *mut core::ffi::c_void, child: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetLastChild", 11usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetLastChild)(this, child), |_| None)
+        }
+        unsafe extern "system" fn GetPreviousChild(this: *mut core::ffi::c_void, referencechild: *mut core::ffi::c_void, previouschild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetPreviousChild", 12usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetPreviousChild)(this, referencechild, previouschild), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextChild(this: *mut core::ffi::c_void, referencechild: *mut core::ffi::c_void, nextchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetNextChild", 13usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetNextChild)(this, referencechild, nextchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertChildBefore(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, referencechild: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "InsertChildBefore", 14usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).InsertChildBefore)(this, newchild, referencechild), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendChild(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "AppendChild", 15usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).AppendChild)(this, newchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplaceChild(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, oldchild: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "ReplaceChild", 16usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).ReplaceChild)(this, newchild, oldchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveChild(this: *mut core::ffi::c_void, oldchild: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "RemoveChild", 17usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).RemoveChild)(this, oldchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateChild(this: *mut core::ffi::c_void, tagname: windows_core::PCWSTR, newchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "CreateChild", 18usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).CreateChild)(this, tagname, newchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAttributeSpecified(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, inherited: *mut super::super::Foundation::BOOL) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "IsAttributeSpecified", 19usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).IsAttributeSpecified)(this, name, inherited), |_| None)
+        }
+        unsafe extern "system" fn GetSpecifiedAttributeCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetSpecifiedAttributeCount", 20usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetSpecifiedAttributeCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetSpecifiedAttributeName(this: *mut core::ffi::c_void, index: u32, name: windows_core::PWSTR, namecount: u32, inherited: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetSpecifiedAttributeName", 21usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetSpecifiedAttributeName)(this, index, name, namecount, inherited), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpecifiedAttributeNameLength(this: *mut core::ffi::c_void, index: u32, namelength: *mut u32, inherited: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetSpecifiedAttributeNameLength", 22usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetSpecifiedAttributeNameLength)(this, index, namelength, inherited), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAttribute(this: *mut core::ffi::c_void, name: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "RemoveAttribute", 23usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).RemoveAttribute)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextValue(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, namecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "SetTextValue", 24usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).SetTextValue)(this, name, namecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextValue(this: *mut core::ffi::c_void, name: windows_core::PWSTR, namecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetTextValue", 25usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetTextValue)(this, name, namecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextValueLength(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetTextValueLength", 26usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetTextValueLength)(this), |_| None)
+        }
+        unsafe extern "system" fn SetAttributeValue(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "SetAttributeValue", 27usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).SetAttributeValue)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttributeValue2(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, r#type: D2D1_SVG_ATTRIBUTE_POD_TYPE, value: *const core::ffi::c_void, valuesizeinbytes: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "SetAttributeValue2", 28usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).SetAttributeValue2)(this, name, r#type, value, valuesizeinbytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttributeValue3(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, r#type: D2D1_SVG_ATTRIBUTE_STRING_TYPE, value: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "SetAttributeValue3", 29usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).SetAttributeValue3)(this, name, r#type, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeValue(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, riid: *const windows_core::GUID, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetAttributeValue", 30usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetAttributeValue)(this, name, riid, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeValue2(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, r#type: D2D1_SVG_ATTRIBUTE_POD_TYPE, value: *mut core::ffi::c_void, valuesizeinbytes: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetAttributeValue2", 31usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetAttributeValue2)(this, name, r#type, value, valuesizeinbytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeValue3(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, r#type: D2D1_SVG_ATTRIBUTE_STRING_TYPE, value: windows_core::PWSTR, valuecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetAttributeValue3", 32usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetAttributeValue3)(this, name, r#type, value, valuecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeValueLength(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, r#type: D2D1_SVG_ATTRIBUTE_STRING_TYPE, valuelength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgElement", "GetAttributeValueLength", 33usize, |this| ((**(this as *const *const ID2D1SvgElement_Vtbl)).GetAttributeValueLength)(this, name, r#type, valuelength), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDocument,
+            GetTagName,
+            GetTagNameLength,
+            IsTextContent,
+            GetParent,
+            HasChildren,
+            GetFirstChild,
+            GetLastChild,
+            GetPreviousChild,
+            GetNextChild,
+            InsertChildBefore,
+            AppendChild,
+            ReplaceChild,
+            RemoveChild,
+            CreateChild,
+            IsAttributeSpecified,
+            GetSpecifiedAttributeCount,
+            GetSpecifiedAttributeName,
+            GetSpecifiedAttributeNameLength,
+            RemoveAttribute,
+            SetTextValue,
+            GetTextValue,
+            GetTextValueLength,
+            SetAttributeValue,
+            SetAttributeValue2,
+            SetAttributeValue3,
+            GetAttributeValue,
+            GetAttributeValue2,
+            GetAttributeValue3,
+            GetAttributeValueLength,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1SvgElement as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1SvgGlyphStyle_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFill(this: *mut core::ffi::c_void, brush: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgGlyphStyle", "SetFill", 4usize, |this| ((**(this as *const *const ID2D1SvgGlyphStyle_Vtbl)).SetFill)(this, brush), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFill(this: *mut core::ffi::c_void, brush: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1SvgGlyphStyle", "GetFill", 5usize, |this| ((**(this as *const *const ID2D1SvgGlyphStyle_Vtbl)).GetFill)(this, brush), |_| None)
+        }
+        unsafe extern "system" fn SetStroke(this: *mut core::ffi::c_void, brush: *mut core::ffi::c_void, strokewidth: f32, dashes: *const f32, dashescount: u32, dashoffset: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgGlyphStyle", "SetStroke", 6usize, |this| ((**(this as *const *const ID2D1SvgGlyphStyle_Vtbl)).SetStroke)(this, brush, strokewidth, dashes, dashescount, dashoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrokeDashesCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1SvgGlyphStyle", "GetStrokeDashesCount", 7usize, |this| ((**(this as *const *const ID2D1SvgGlyphStyle_Vtbl)).GetStrokeDashesCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetStroke(this: *mut core::ffi::c_void, brush: *mut *mut core::ffi::c_void, strokewidth: *mut f32, dashes: *mut f32, dashescount: u32, dashoffset: *mut f32) {
+            windows_core::imp::proxy_call(this, "ID2D1SvgGlyphStyle", "GetStroke", 8usize, |this| ((**(this as *const *const ID2D1SvgGlyphStyle_Vtbl)).GetStroke)(this, brush, strokewidth, dashes, dashescount, dashoffset), |_| None)
+        }
+        Self { base__: <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetFill, GetFill, SetStroke, GetStrokeDashesCount, GetStroke }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1SvgGlyphStyle as windows_core::Interface>::IID || <ID2D1Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for ID2D1SvgPaint_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPaintType(this: *mut core::ffi::c_void, painttype: D2D1_SVG_PAINT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPaint", "SetPaintType", 6usize, |this| ((**(this as *const *const ID2D1SvgPaint_Vtbl)).SetPaintType)(this, painttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPaintType(this: *mut core::ffi::c_void) -> D2D1_SVG_PAINT_TYPE {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPaint", "GetPaintType", 7usize, |this| ((**(this as *const *const ID2D1SvgPaint_Vtbl)).GetPaintType)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, color: *const Common::D2D1_COLOR_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPaint", "SetColor", 8usize, |this| ((**(this as *const *const ID2D1SvgPaint_Vtbl)).SetColor)(this, color), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetColor(this: *mut core::ffi::c_void, color: *mut Common::D2D1_COLOR_F) {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPaint", "GetColor", 9usize, |this| ((**(this as *const *const ID2D1SvgPaint_Vtbl)).GetColor)(this, color), |_| None)
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, id: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPaint", "SetId", 10usize, |this| ((**(this as *const *const ID2D1SvgPaint_Vtbl)).SetId)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetId(this: *mut core::ffi::c_void, id: windows_core::PWSTR, idcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPaint", "GetId", 11usize, |this| ((**(this as *const *const ID2D1SvgPaint_Vtbl)).GetId)(this, id, idcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIdLength(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPaint", "GetIdLength", 12usize, |this| ((**(this as *const *const ID2D1SvgPaint_Vtbl)).GetIdLength)(this), |_| None)
+        }
+        Self {
+            base__: <ID2D1SvgAttribute_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPaintType,
+            GetPaintType,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetColor,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetColor: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetColor,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetColor: 0,
+            SetId,
+            GetId,
+            GetIdLength,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1SvgPaint as windows_core::Interface>::IID || <ID2D1SvgAttribute_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for ID2D1SvgPathData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoveSegmentDataAtEnd(this: *mut core::ffi::c_void, datacount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPathData", "RemoveSegmentDataAtEnd", 6usize, |this| ((**(this as *const *const ID2D1SvgPathData_Vtbl)).RemoveSegmentDataAtEnd)(this, datacount), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateSegmentData(this: *mut core::ffi::c_void, data: *const f32, datacount: u32, startindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPathData", "UpdateSegmentData", 7usize, |this| ((**(this as *const *const ID2D1SvgPathData_Vtbl)).UpdateSegmentData)(this, data, datacount, startindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSegmentData(this: *mut core::ffi::c_void, data: *mut f32, datacount: u32, startindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPathData", "GetSegmentData", 8usize, |this| ((**(this as *const *const ID2D1SvgPathData_Vtbl)).GetSegmentData)(this, data, datacount, startindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSegmentDataCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPathData", "GetSegmentDataCount", 9usize, |this| ((**(this as *const *const ID2D1SvgPathData_Vtbl)).GetSegmentDataCount)(this), |_| None)
+        }
+        unsafe extern "system" fn RemoveCommandsAtEnd(this: *mut core::ffi::c_void, commandscount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPathData", "RemoveCommandsAtEnd", 10usize, |this| ((**(this as *const *const ID2D1SvgPathData_Vtbl)).RemoveCommandsAtEnd)(this, commandscount), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateCommands(this: *mut core::ffi::c_void, commands: *const D2D1_SVG_PATH_COMMAND, commandscount: u32, startindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPathData", "UpdateCommands", 11usize, |this| ((**(this as *const *const ID2D1SvgPathData_Vtbl)).UpdateCommands)(this, commands, commandscount, startindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCommands(this: *mut core::ffi::c_void, commands: *mut D2D1_SVG_PATH_COMMAND, commandscount: u32, startindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPathData", "GetCommands", 12usize, |this| ((**(this as *const *const ID2D1SvgPathData_Vtbl)).GetCommands)(this, commands, commandscount, startindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCommandsCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPathData", "GetCommandsCount", 13usize, |this| ((**(this as *const *const ID2D1SvgPathData_Vtbl)).GetCommandsCount)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn CreatePathGeometry(this: *mut core::ffi::c_void, fillmode: Common::D2D1_FILL_MODE, pathgeometry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPathData", "CreatePathGeometry", 14usize, |this| ((**(this as *const *const ID2D1SvgPathData_Vtbl)).CreatePathGeometry)(this, fillmode, pathgeometry), |result| Some(*result))
+        }
+        Self {
+            base__: <ID2D1SvgAttribute_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RemoveSegmentDataAtEnd,
+            UpdateSegmentData,
+            GetSegmentData,
+            GetSegmentDataCount,
+            RemoveCommandsAtEnd,
+            UpdateCommands,
+            GetCommands,
+            GetCommandsCount,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            CreatePathGeometry,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            CreatePathGeometry: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1SvgPathData as windows_core::Interface>::IID || <ID2D1SvgAttribute_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for ID2D1SvgPointCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemovePointsAtEnd(this: *mut core::ffi::c_void, pointscount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPointCollection", "RemovePointsAtEnd", 6usize, |this| ((**(this as *const *const ID2D1SvgPointCollection_Vtbl)).RemovePointsAtEnd)(this, pointscount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn UpdatePoints(this: *mut core::ffi::c_void, points: *const Common::D2D_POINT_2F, pointscount: u32, startindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPointCollection", "UpdatePoints", 7usize, |this| ((**(this as *const *const ID2D1SvgPointCollection_Vtbl)).UpdatePoints)(this, points, pointscount, startindex), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetPoints(this: *mut core::ffi::c_void, points: *mut Common::D2D_POINT_2F, pointscount: u32, startindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPointCollection", "GetPoints", 8usize, |this| ((**(this as *const *const ID2D1SvgPointCollection_Vtbl)).GetPoints)(this, points, pointscount, startindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPointsCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1SvgPointCollection", "GetPointsCount", 9usize, |this| ((**(this as *const *const ID2D1SvgPointCollection_Vtbl)).GetPointsCount)(this), |_| None)
+        }
+        Self {
+            base__: <ID2D1SvgAttribute_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RemovePointsAtEnd,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            UpdatePoints,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            UpdatePoints: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetPoints,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetPoints: 0,
+            GetPointsCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1SvgPointCollection as windows_core::Interface>::IID || <ID2D1SvgAttribute_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1SvgStrokeDashArray_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoveDashesAtEnd(this: *mut core::ffi::c_void, dashescount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgStrokeDashArray", "RemoveDashesAtEnd", 6usize, |this| ((**(this as *const *const ID2D1SvgStrokeDashArray_Vtbl)).RemoveDashesAtEnd)(this, dashescount), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateDashes(this: *mut core::ffi::c_void, dashes: *const D2D1_SVG_LENGTH, dashescount: u32, startindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgStrokeDashArray", "UpdateDashes", 7usize, |this| ((**(this as *const *const ID2D1SvgStrokeDashArray_Vtbl)).UpdateDashes)(this, dashes, dashescount, startindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateDashes2(this: *mut core::ffi::c_void, dashes: *const f32, dashescount: u32, startindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgStrokeDashArray", "UpdateDashes2", 8usize, |this| ((**(this as *const *const ID2D1SvgStrokeDashArray_Vtbl)).UpdateDashes2)(this, dashes, dashescount, startindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDashes(this: *mut core::ffi::c_void, dashes: *mut D2D1_SVG_LENGTH, dashescount: u32, startindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgStrokeDashArray", "GetDashes", 9usize, |this| ((**(this as *const *const ID2D1SvgStrokeDashArray_Vtbl)).GetDashes)(this, dashes, dashescount, startindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDashes2(this: *mut core::ffi::c_void, dashes: *mut f32, dashescount: u32, startindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1SvgStrokeDashArray", "GetDashes2", 10usize, |this| ((**(this as *const *const ID2D1SvgStrokeDashArray_Vtbl)).GetDashes2)(this, dashes, dashescount, startindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDashesCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1SvgStrokeDashArray", "GetDashesCount", 11usize, |this| ((**(this as *const *const ID2D1SvgStrokeDashArray_Vtbl)).GetDashesCount)(this), |_| None)
+        }
+        Self {
+            base__: <ID2D1SvgAttribute_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RemoveDashesAtEnd,
+            UpdateDashes,
+            UpdateDashes2,
+            GetDashes,
+            GetDashes2,
+            GetDashesCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1SvgStrokeDashArray as windows_core::Interface>::IID || <ID2D1SvgAttribute_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for ID2D1TessellationSink_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn AddTriangles(this: *mut core::ffi::c_void, triangles: *const D2D1_TRIANGLE, trianglescount: u32) {
+            windows_core::imp::proxy_call(this, "ID2D1TessellationSink", "AddTriangles", 3usize, |this| ((**(this as *const *const ID2D1TessellationSink_Vtbl)).AddTriangles)(this, triangles, trianglescount), |_| None)
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1TessellationSink", "Close", 4usize, |this| ((**(this as *const *const ID2D1TessellationSink_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            AddTriangles,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            AddTriangles: 0,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1TessellationSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1Transform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MapOutputRectToInputRects(this: *mut core::ffi::c_void, outputrect: *const super::super::Foundation::RECT, inputrects: *mut super::super::Foundation::RECT, inputrectscount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Transform", "MapOutputRectToInputRects", 4usize, |this| ((**(this as *const *const ID2D1Transform_Vtbl)).MapOutputRectToInputRects)(this, outputrect, inputrects, inputrectscount), |result| Some(*result))
+        }
+        unsafe extern "system" fn MapInputRectsToOutputRect(this: *mut core::ffi::c_void, inputrects: *const super::super::Foundation::RECT, inputopaquesubrects: *const super::super::Foundation::RECT, inputrectcount: u32, outputrect: *mut super::super::Foundation::RECT, outputopaquesubrect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Transform", "MapInputRectsToOutputRect", 5usize, |this| ((**(this as *const *const ID2D1Transform_Vtbl)).MapInputRectsToOutputRect)(this, inputrects, inputopaquesubrects, inputrectcount, outputrect, outputopaquesubrect), |result| Some(*result))
+        }
+        unsafe extern "system" fn MapInvalidRect(this: *mut core::ffi::c_void, inputindex: u32, invalidinputrect: super::super::Foundation::RECT, invalidoutputrect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1Transform", "MapInvalidRect", 6usize, |this| ((**(this as *const *const ID2D1Transform_Vtbl)).MapInvalidRect)(this, inputindex, invalidinputrect, invalidoutputrect), |result| Some(*result))
+        }
+        Self { base__: <ID2D1TransformNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MapOutputRectToInputRects, MapInputRectsToOutputRect, MapInvalidRect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1Transform as windows_core::Interface>::IID || <ID2D1TransformNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1TransformGraph_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInputCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1TransformGraph", "GetInputCount", 3usize, |this| ((**(this as *const *const ID2D1TransformGraph_Vtbl)).GetInputCount)(this), |_| None)
+        }
+        unsafe extern "system" fn SetSingleTransformNode(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1TransformGraph", "SetSingleTransformNode", 4usize, |this| ((**(this as *const *const ID2D1TransformGraph_Vtbl)).SetSingleTransformNode)(this, node), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddNode(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1TransformGraph", "AddNode", 5usize, |this| ((**(this as *const *const ID2D1TransformGraph_Vtbl)).AddNode)(this, node), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNode(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1TransformGraph", "RemoveNode", 6usize, |this| ((**(this as *const *const ID2D1TransformGraph_Vtbl)).RemoveNode)(this, node), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputNode(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1TransformGraph", "SetOutputNode", 7usize, |this| ((**(this as *const *const ID2D1TransformGraph_Vtbl)).SetOutputNode)(this, node), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectNode(this: *mut core::ffi::c_void, fromnode: *mut core::ffi::c_void, tonode: *mut core::ffi::c_void, tonodeinputindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1TransformGraph", "ConnectNode", 8usize, |this| ((**(this as *const *const ID2D1TransformGraph_Vtbl)).ConnectNode)(this, fromnode, tonode, tonodeinputindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectToEffectInput(this: *mut core::ffi::c_void, toeffectinputindex: u32, node: *mut core::ffi::c_void, tonodeinputindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1TransformGraph", "ConnectToEffectInput", 9usize, |this| ((**(this as *const *const ID2D1TransformGraph_Vtbl)).ConnectToEffectInput)(this, toeffectinputindex, node, tonodeinputindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1TransformGraph", "Clear", 10usize, |this| ((**(this as *const *const ID2D1TransformGraph_Vtbl)).Clear)(this), |_| None)
+        }
+        unsafe extern "system" fn SetPassthroughGraph(this: *mut core::ffi::c_void, effectinputindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1TransformGraph", "SetPassthroughGraph", 11usize, |this| ((**(this as *const *const ID2D1TransformGraph_Vtbl)).SetPassthroughGraph)(this, effectinputindex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetInputCount,
+            SetSingleTransformNode,
+            AddNode,
+            RemoveNode,
+            SetOutputNode,
+            ConnectNode,
+            ConnectToEffectInput,
+            Clear,
+            SetPassthroughGraph,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1TransformGraph as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1TransformNode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInputCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID2D1TransformNode", "GetInputCount", 3usize, |this| ((**(this as *const *const ID2D1TransformNode_Vtbl)).GetInputCount)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInputCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1TransformNode as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for ID2D1TransformedGeometry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSourceGeometry(this: *mut core::ffi::c_void, sourcegeometry: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1TransformedGeometry", "GetSourceGeometry", 17usize, |this| ((**(this as *const *const ID2D1TransformedGeometry_Vtbl)).GetSourceGeometry)(this, sourcegeometry), |_| None)
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn GetTransform(this: *mut core::ffi::c_void, transform: *mut super::super::super::Foundation::Numerics::Matrix3x2) {
+            windows_core::imp::proxy_call(this, "ID2D1TransformedGeometry", "GetTransform", 18usize, |this| ((**(this as *const *const ID2D1TransformedGeometry_Vtbl)).GetTransform)(this, transform), |_| None)
+        }
+        Self {
+            base__: <ID2D1Geometry_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSourceGeometry,
+            #[cfg(feature = "Foundation_Numerics")]
+            GetTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            GetTransform: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1TransformedGeometry as windows_core::Interface>::IID || <ID2D1Geometry_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1TransformedImageSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSource(this: *mut core::ffi::c_void, imagesource: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID2D1TransformedImageSource", "GetSource", 4usize, |this| ((**(this as *const *const ID2D1TransformedImageSource_Vtbl)).GetSource)(this, imagesource), |_| None)
+        }
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, properties: *mut D2D1_TRANSFORMED_IMAGE_SOURCE_PROPERTIES) {
+            windows_core::imp::proxy_call(this, "ID2D1TransformedImageSource", "GetProperties", 5usize, |this| ((**(this as *const *const ID2D1TransformedImageSource_Vtbl)).GetProperties)(this, properties), |_| None)
+        }
+        Self { base__: <ID2D1Image_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSource, GetProperties }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1TransformedImageSource as windows_core::Interface>::IID || <ID2D1Image_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID2D1VertexBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Map(this: *mut core::ffi::c_void, data: *mut *mut u8, buffersize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1VertexBuffer", "Map", 3usize, |this| ((**(this as *const *const ID2D1VertexBuffer_Vtbl)).Map)(this, data, buffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unmap(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID2D1VertexBuffer", "Unmap", 4usize, |this| ((**(this as *const *const ID2D1VertexBuffer_Vtbl)).Unmap)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Map, Unmap }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID2D1VertexBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1078,6 +1078,7 @@ pub struct DXC_CP(pub u32);
 impl windows_core::TypeKind for DXC_CP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXC_CP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXC_CP").field(&self.0).finish()
@@ -1089,13 +1090,15 @@ pub struct DXC_OUT_KIND(pub i32);
 impl windows_core::TypeKind for DXC_OUT_KIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXC_OUT_KIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXC_OUT_KIND").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DxcArgPair {
     pub pName: windows_core::PCWSTR,
     pub pValue: windows_core::PCWSTR,
@@ -1109,7 +1112,8 @@ impl Default for DxcArgPair {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DxcBuffer {
     pub Ptr: *const core::ffi::c_void,
     pub Size: usize,
@@ -1124,7 +1128,8 @@ impl Default for DxcBuffer {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DxcDefine {
     pub Name: windows_core::PCWSTR,
     pub Value: windows_core::PCWSTR,
@@ -1138,7 +1143,8 @@ impl Default for DxcDefine {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DxcShaderHash {
     pub Flags: u32,
     pub HashDigest: [u8; 16],
@@ -1156,3 +1162,5 @@ pub type DxcCreateInstance2Proc = Option<unsafe extern "system" fn(pmalloc: Opti
 pub type DxcCreateInstanceProc = Option<unsafe extern "system" fn(rclsid: *const windows_core::GUID, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
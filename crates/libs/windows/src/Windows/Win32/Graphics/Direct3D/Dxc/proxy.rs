@@ -0,0 +1,647 @@
+impl windows_core::imp::ProxyVtable for IDxcAssembler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AssembleToContainer(this: *mut core::ffi::c_void, pshader: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcAssembler", "AssembleToContainer", 3usize, |this| ((**(this as *const *const IDxcAssembler_Vtbl)).AssembleToContainer)(this, pshader, ppresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AssembleToContainer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcAssembler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcBlob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBufferPointer(this: *mut core::ffi::c_void) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "IDxcBlob", "GetBufferPointer", 3usize, |this| ((**(this as *const *const IDxcBlob_Vtbl)).GetBufferPointer)(this), |_| None)
+        }
+        unsafe extern "system" fn GetBufferSize(this: *mut core::ffi::c_void) -> usize {
+            windows_core::imp::proxy_call(this, "IDxcBlob", "GetBufferSize", 4usize, |this| ((**(this as *const *const IDxcBlob_Vtbl)).GetBufferSize)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBufferPointer, GetBufferSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcBlob as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcBlobEncoding_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEncoding(this: *mut core::ffi::c_void, pknown: *mut super::super::super::Foundation::BOOL, pcodepage: *mut DXC_CP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcBlobEncoding", "GetEncoding", 5usize, |this| ((**(this as *const *const IDxcBlobEncoding_Vtbl)).GetEncoding)(this, pknown, pcodepage), |result| Some(*result))
+        }
+        Self { base__: <IDxcBlob_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEncoding }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcBlobEncoding as windows_core::Interface>::IID || <IDxcBlob_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcBlobUtf16_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStringPointer(this: *mut core::ffi::c_void) -> windows_core::PCWSTR {
+            windows_core::imp::proxy_call(this, "IDxcBlobUtf16", "GetStringPointer", 6usize, |this| ((**(this as *const *const IDxcBlobUtf16_Vtbl)).GetStringPointer)(this), |_| None)
+        }
+        unsafe extern "system" fn GetStringLength(this: *mut core::ffi::c_void) -> usize {
+            windows_core::imp::proxy_call(this, "IDxcBlobUtf16", "GetStringLength", 7usize, |this| ((**(this as *const *const IDxcBlobUtf16_Vtbl)).GetStringLength)(this), |_| None)
+        }
+        Self { base__: <IDxcBlobEncoding_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStringPointer, GetStringLength }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcBlobUtf16 as windows_core::Interface>::IID || <IDxcBlobEncoding_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcBlobUtf8_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStringPointer(this: *mut core::ffi::c_void) -> windows_core::PCSTR {
+            windows_core::imp::proxy_call(this, "IDxcBlobUtf8", "GetStringPointer", 6usize, |this| ((**(this as *const *const IDxcBlobUtf8_Vtbl)).GetStringPointer)(this), |_| None)
+        }
+        unsafe extern "system" fn GetStringLength(this: *mut core::ffi::c_void) -> usize {
+            windows_core::imp::proxy_call(this, "IDxcBlobUtf8", "GetStringLength", 7usize, |this| ((**(this as *const *const IDxcBlobUtf8_Vtbl)).GetStringLength)(this), |_| None)
+        }
+        Self { base__: <IDxcBlobEncoding_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStringPointer, GetStringLength }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcBlobUtf8 as windows_core::Interface>::IID || <IDxcBlobEncoding_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcCompiler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Compile(this: *mut core::ffi::c_void, psource: *mut core::ffi::c_void, psourcename: windows_core::PCWSTR, pentrypoint: windows_core::PCWSTR, ptargetprofile: windows_core::PCWSTR, parguments: *const windows_core::PCWSTR, argcount: u32, pdefines: *const DxcDefine, definecount: u32, pincludehandler: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcCompiler", "Compile", 3usize, |this| ((**(this as *const *const IDxcCompiler_Vtbl)).Compile)(this, psource, psourcename, pentrypoint, ptargetprofile, parguments, argcount, pdefines, definecount, pincludehandler, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn Preprocess(this: *mut core::ffi::c_void, psource: *mut core::ffi::c_void, psourcename: windows_core::PCWSTR, parguments: *const windows_core::PCWSTR, argcount: u32, pdefines: *const DxcDefine, definecount: u32, pincludehandler: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcCompiler", "Preprocess", 4usize, |this| ((**(this as *const *const IDxcCompiler_Vtbl)).Preprocess)(this, psource, psourcename, parguments, argcount, pdefines, definecount, pincludehandler, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disassemble(this: *mut core::ffi::c_void, psource: *mut core::ffi::c_void, ppdisassembly: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcCompiler", "Disassemble", 5usize, |this| ((**(this as *const *const IDxcCompiler_Vtbl)).Disassemble)(this, psource, ppdisassembly), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Compile, Preprocess, Disassemble }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcCompiler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcCompiler2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CompileWithDebug(this: *mut core::ffi::c_void, psource: *mut core::ffi::c_void, psourcename: windows_core::PCWSTR, pentrypoint: windows_core::PCWSTR, ptargetprofile: windows_core::PCWSTR, parguments: *const windows_core::PCWSTR, argcount: u32, pdefines: *const DxcDefine, definecount: u32, pincludehandler: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void, ppdebugblobname: *mut windows_core::PWSTR, ppdebugblob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcCompiler2", "CompileWithDebug", 6usize, |this| ((**(this as *const *const IDxcCompiler2_Vtbl)).CompileWithDebug)(this, psource, psourcename, pentrypoint, ptargetprofile, parguments, argcount, pdefines, definecount, pincludehandler, ppresult, ppdebugblobname, ppdebugblob), |result| Some(*result))
+        }
+        Self { base__: <IDxcCompiler_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CompileWithDebug }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcCompiler2 as windows_core::Interface>::IID || <IDxcCompiler_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcCompiler3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Compile(this: *mut core::ffi::c_void, psource: *const DxcBuffer, parguments: *const windows_core::PCWSTR, argcount: u32, pincludehandler: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcCompiler3", "Compile", 3usize, |this| ((**(this as *const *const IDxcCompiler3_Vtbl)).Compile)(this, psource, parguments, argcount, pincludehandler, riid, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disassemble(this: *mut core::ffi::c_void, pobject: *const DxcBuffer, riid: *const windows_core::GUID, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcCompiler3", "Disassemble", 4usize, |this| ((**(this as *const *const IDxcCompiler3_Vtbl)).Disassemble)(this, pobject, riid, ppresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Compile, Disassemble }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcCompiler3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcCompilerArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetArguments(this: *mut core::ffi::c_void) -> *mut windows_core::PCWSTR {
+            windows_core::imp::proxy_call(this, "IDxcCompilerArgs", "GetArguments", 3usize, |this| ((**(this as *const *const IDxcCompilerArgs_Vtbl)).GetArguments)(this), |_| None)
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDxcCompilerArgs", "GetCount", 4usize, |this| ((**(this as *const *const IDxcCompilerArgs_Vtbl)).GetCount)(this), |_| None)
+        }
+        unsafe extern "system" fn AddArguments(this: *mut core::ffi::c_void, parguments: *const windows_core::PCWSTR, argcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcCompilerArgs", "AddArguments", 5usize, |this| ((**(this as *const *const IDxcCompilerArgs_Vtbl)).AddArguments)(this, parguments, argcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddArgumentsUTF8(this: *mut core::ffi::c_void, parguments: *const windows_core::PCSTR, argcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcCompilerArgs", "AddArgumentsUTF8", 6usize, |this| ((**(this as *const *const IDxcCompilerArgs_Vtbl)).AddArgumentsUTF8)(this, parguments, argcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDefines(this: *mut core::ffi::c_void, pdefines: *const DxcDefine, definecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcCompilerArgs", "AddDefines", 7usize, |this| ((**(this as *const *const IDxcCompilerArgs_Vtbl)).AddDefines)(this, pdefines, definecount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetArguments,
+            GetCount,
+            AddArguments,
+            AddArgumentsUTF8,
+            AddDefines,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcCompilerArgs as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcContainerBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, pdxilcontainerheader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcContainerBuilder", "Load", 3usize, |this| ((**(this as *const *const IDxcContainerBuilder_Vtbl)).Load)(this, pdxilcontainerheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPart(this: *mut core::ffi::c_void, fourcc: u32, psource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcContainerBuilder", "AddPart", 4usize, |this| ((**(this as *const *const IDxcContainerBuilder_Vtbl)).AddPart)(this, fourcc, psource), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePart(this: *mut core::ffi::c_void, fourcc: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcContainerBuilder", "RemovePart", 5usize, |this| ((**(this as *const *const IDxcContainerBuilder_Vtbl)).RemovePart)(this, fourcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SerializeContainer(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcContainerBuilder", "SerializeContainer", 6usize, |this| ((**(this as *const *const IDxcContainerBuilder_Vtbl)).SerializeContainer)(this, ppresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Load, AddPart, RemovePart, SerializeContainer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcContainerBuilder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcContainerReflection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, pcontainer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcContainerReflection", "Load", 3usize, |this| ((**(this as *const *const IDxcContainerReflection_Vtbl)).Load)(this, pcontainer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPartCount(this: *mut core::ffi::c_void, presult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcContainerReflection", "GetPartCount", 4usize, |this| ((**(this as *const *const IDxcContainerReflection_Vtbl)).GetPartCount)(this, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPartKind(this: *mut core::ffi::c_void, idx: u32, presult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcContainerReflection", "GetPartKind", 5usize, |this| ((**(this as *const *const IDxcContainerReflection_Vtbl)).GetPartKind)(this, idx, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPartContent(this: *mut core::ffi::c_void, idx: u32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcContainerReflection", "GetPartContent", 6usize, |this| ((**(this as *const *const IDxcContainerReflection_Vtbl)).GetPartContent)(this, idx, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFirstPartKind(this: *mut core::ffi::c_void, kind: u32, presult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcContainerReflection", "FindFirstPartKind", 7usize, |this| ((**(this as *const *const IDxcContainerReflection_Vtbl)).FindFirstPartKind)(this, kind, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPartReflection(this: *mut core::ffi::c_void, idx: u32, iid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcContainerReflection", "GetPartReflection", 8usize, |this| ((**(this as *const *const IDxcContainerReflection_Vtbl)).GetPartReflection)(this, idx, iid, ppvobject), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Load,
+            GetPartCount,
+            GetPartKind,
+            GetPartContent,
+            FindFirstPartKind,
+            GetPartReflection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcContainerReflection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcExtraOutputs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOutputCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDxcExtraOutputs", "GetOutputCount", 3usize, |this| ((**(this as *const *const IDxcExtraOutputs_Vtbl)).GetOutputCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetOutput(this: *mut core::ffi::c_void, uindex: u32, iid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void, ppoutputtype: *mut *mut core::ffi::c_void, ppoutputname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcExtraOutputs", "GetOutput", 4usize, |this| ((**(this as *const *const IDxcExtraOutputs_Vtbl)).GetOutput)(this, uindex, iid, ppvobject, ppoutputtype, ppoutputname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOutputCount, GetOutput }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcExtraOutputs as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcIncludeHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadSource(this: *mut core::ffi::c_void, pfilename: windows_core::PCWSTR, ppincludesource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcIncludeHandler", "LoadSource", 3usize, |this| ((**(this as *const *const IDxcIncludeHandler_Vtbl)).LoadSource)(this, pfilename, ppincludesource), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LoadSource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcIncludeHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDxcLibrary_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetMalloc(this: *mut core::ffi::c_void, pmalloc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcLibrary", "SetMalloc", 3usize, |this| ((**(this as *const *const IDxcLibrary_Vtbl)).SetMalloc)(this, pmalloc), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBlobFromBlob(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, offset: u32, length: u32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcLibrary", "CreateBlobFromBlob", 4usize, |this| ((**(this as *const *const IDxcLibrary_Vtbl)).CreateBlobFromBlob)(this, pblob, offset, length, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBlobFromFile(this: *mut core::ffi::c_void, pfilename: windows_core::PCWSTR, codepage: *const DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcLibrary", "CreateBlobFromFile", 5usize, |this| ((**(this as *const *const IDxcLibrary_Vtbl)).CreateBlobFromFile)(this, pfilename, codepage, pblobencoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBlobWithEncodingFromPinned(this: *mut core::ffi::c_void, ptext: *const core::ffi::c_void, size: u32, codepage: DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcLibrary", "CreateBlobWithEncodingFromPinned", 6usize, |this| ((**(this as *const *const IDxcLibrary_Vtbl)).CreateBlobWithEncodingFromPinned)(this, ptext, size, codepage, pblobencoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBlobWithEncodingOnHeapCopy(this: *mut core::ffi::c_void, ptext: *const core::ffi::c_void, size: u32, codepage: DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcLibrary", "CreateBlobWithEncodingOnHeapCopy", 7usize, |this| ((**(this as *const *const IDxcLibrary_Vtbl)).CreateBlobWithEncodingOnHeapCopy)(this, ptext, size, codepage, pblobencoding), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateBlobWithEncodingOnMalloc(this: *mut core::ffi::c_void, ptext: *const core::ffi::c_void, pimalloc: *mut core::ffi::c_void, size: u32, codepage: DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcLibrary", "CreateBlobWithEncodingOnMalloc", 8usize, |this| ((**(this as *const *const IDxcLibrary_Vtbl)).CreateBlobWithEncodingOnMalloc)(this, ptext, pimalloc, size, codepage, pblobencoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateIncludeHandler(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcLibrary", "CreateIncludeHandler", 9usize, |this| ((**(this as *const *const IDxcLibrary_Vtbl)).CreateIncludeHandler)(this, ppresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateStreamFromBlobReadOnly(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcLibrary", "CreateStreamFromBlobReadOnly", 10usize, |this| ((**(this as *const *const IDxcLibrary_Vtbl)).CreateStreamFromBlobReadOnly)(this, pblob, ppstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBlobAsUtf8(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcLibrary", "GetBlobAsUtf8", 11usize, |this| ((**(this as *const *const IDxcLibrary_Vtbl)).GetBlobAsUtf8)(this, pblob, pblobencoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBlobAsUtf16(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcLibrary", "GetBlobAsUtf16", 12usize, |this| ((**(this as *const *const IDxcLibrary_Vtbl)).GetBlobAsUtf16)(this, pblob, pblobencoding), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            SetMalloc,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetMalloc: 0,
+            CreateBlobFromBlob,
+            CreateBlobFromFile,
+            CreateBlobWithEncodingFromPinned,
+            CreateBlobWithEncodingOnHeapCopy,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateBlobWithEncodingOnMalloc,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateBlobWithEncodingOnMalloc: 0,
+            CreateIncludeHandler,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateStreamFromBlobReadOnly,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateStreamFromBlobReadOnly: 0,
+            GetBlobAsUtf8,
+            GetBlobAsUtf16,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcLibrary as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcLinker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterLibrary(this: *mut core::ffi::c_void, plibname: windows_core::PCWSTR, plib: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcLinker", "RegisterLibrary", 3usize, |this| ((**(this as *const *const IDxcLinker_Vtbl)).RegisterLibrary)(this, plibname, plib), |result| Some(*result))
+        }
+        unsafe extern "system" fn Link(this: *mut core::ffi::c_void, pentryname: windows_core::PCWSTR, ptargetprofile: windows_core::PCWSTR, plibnames: *const windows_core::PCWSTR, libcount: u32, parguments: *const windows_core::PCWSTR, argcount: u32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcLinker", "Link", 4usize, |this| ((**(this as *const *const IDxcLinker_Vtbl)).Link)(this, pentryname, ptargetprofile, plibnames, libcount, parguments, argcount, ppresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterLibrary, Link }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcLinker as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcOperationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pstatus: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcOperationResult", "GetStatus", 3usize, |this| ((**(this as *const *const IDxcOperationResult_Vtbl)).GetStatus)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResult(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcOperationResult", "GetResult", 4usize, |this| ((**(this as *const *const IDxcOperationResult_Vtbl)).GetResult)(this, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorBuffer(this: *mut core::ffi::c_void, pperrors: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcOperationResult", "GetErrorBuffer", 5usize, |this| ((**(this as *const *const IDxcOperationResult_Vtbl)).GetErrorBuffer)(this, pperrors), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStatus, GetResult, GetErrorBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcOperationResult as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcOptimizer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAvailablePassCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcOptimizer", "GetAvailablePassCount", 3usize, |this| ((**(this as *const *const IDxcOptimizer_Vtbl)).GetAvailablePassCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAvailablePass(this: *mut core::ffi::c_void, index: u32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcOptimizer", "GetAvailablePass", 4usize, |this| ((**(this as *const *const IDxcOptimizer_Vtbl)).GetAvailablePass)(this, index, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunOptimizer(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, ppoptions: *const windows_core::PCWSTR, optioncount: u32, poutputmodule: *mut *mut core::ffi::c_void, ppoutputtext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcOptimizer", "RunOptimizer", 5usize, |this| ((**(this as *const *const IDxcOptimizer_Vtbl)).RunOptimizer)(this, pblob, ppoptions, optioncount, poutputmodule, ppoutputtext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAvailablePassCount, GetAvailablePass, RunOptimizer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcOptimizer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcOptimizerPass_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOptionName(this: *mut core::ffi::c_void, ppresult: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcOptimizerPass", "GetOptionName", 3usize, |this| ((**(this as *const *const IDxcOptimizerPass_Vtbl)).GetOptionName)(this, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, ppresult: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcOptimizerPass", "GetDescription", 4usize, |this| ((**(this as *const *const IDxcOptimizerPass_Vtbl)).GetDescription)(this, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptionArgCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcOptimizerPass", "GetOptionArgCount", 5usize, |this| ((**(this as *const *const IDxcOptimizerPass_Vtbl)).GetOptionArgCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptionArgName(this: *mut core::ffi::c_void, argindex: u32, ppresult: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcOptimizerPass", "GetOptionArgName", 6usize, |this| ((**(this as *const *const IDxcOptimizerPass_Vtbl)).GetOptionArgName)(this, argindex, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptionArgDescription(this: *mut core::ffi::c_void, argindex: u32, ppresult: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcOptimizerPass", "GetOptionArgDescription", 7usize, |this| ((**(this as *const *const IDxcOptimizerPass_Vtbl)).GetOptionArgDescription)(this, argindex, ppresult), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOptionName,
+            GetDescription,
+            GetOptionArgCount,
+            GetOptionArgName,
+            GetOptionArgDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcOptimizerPass as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcPdbUtils_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, ppdbordxil: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "Load", 3usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).Load)(this, ppdbordxil), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetSourceCount", 4usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetSourceCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSource(this: *mut core::ffi::c_void, uindex: u32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetSource", 5usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetSource)(this, uindex, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceName(this: *mut core::ffi::c_void, uindex: u32, presult: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetSourceName", 6usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetSourceName)(this, uindex, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlagCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetFlagCount", 7usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetFlagCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlag(this: *mut core::ffi::c_void, uindex: u32, presult: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetFlag", 8usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetFlag)(this, uindex, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetArgCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetArgCount", 9usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetArgCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetArg(this: *mut core::ffi::c_void, uindex: u32, presult: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetArg", 10usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetArg)(this, uindex, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetArgPairCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetArgPairCount", 11usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetArgPairCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetArgPair(this: *mut core::ffi::c_void, uindex: u32, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetArgPair", 12usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetArgPair)(this, uindex, pname, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefineCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetDefineCount", 13usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetDefineCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefine(this: *mut core::ffi::c_void, uindex: u32, presult: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetDefine", 14usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetDefine)(this, uindex, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTargetProfile(this: *mut core::ffi::c_void, presult: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetTargetProfile", 15usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetTargetProfile)(this, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEntryPoint(this: *mut core::ffi::c_void, presult: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetEntryPoint", 16usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetEntryPoint)(this, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMainFileName(this: *mut core::ffi::c_void, presult: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetMainFileName", 17usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetMainFileName)(this, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHash(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetHash", 18usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetHash)(this, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, presult: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetName", 19usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetName)(this, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFullPDB(this: *mut core::ffi::c_void) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "IsFullPDB", 20usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).IsFullPDB)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFullPDB(this: *mut core::ffi::c_void, ppfullpdb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetFullPDB", 21usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetFullPDB)(this, ppfullpdb), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionInfo(this: *mut core::ffi::c_void, ppversioninfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "GetVersionInfo", 22usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).GetVersionInfo)(this, ppversioninfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompiler(this: *mut core::ffi::c_void, pcompiler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "SetCompiler", 23usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).SetCompiler)(this, pcompiler), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompileForFullPDB(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "CompileForFullPDB", 24usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).CompileForFullPDB)(this, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn OverrideArgs(this: *mut core::ffi::c_void, pargpairs: *const DxcArgPair, unumargpairs: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "OverrideArgs", 25usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).OverrideArgs)(this, pargpairs, unumargpairs), |result| Some(*result))
+        }
+        unsafe extern "system" fn OverrideRootSignature(this: *mut core::ffi::c_void, prootsignature: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcPdbUtils", "OverrideRootSignature", 26usize, |this| ((**(this as *const *const IDxcPdbUtils_Vtbl)).OverrideRootSignature)(this, prootsignature), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Load,
+            GetSourceCount,
+            GetSource,
+            GetSourceName,
+            GetFlagCount,
+            GetFlag,
+            GetArgCount,
+            GetArg,
+            GetArgPairCount,
+            GetArgPair,
+            GetDefineCount,
+            GetDefine,
+            GetTargetProfile,
+            GetEntryPoint,
+            GetMainFileName,
+            GetHash,
+            GetName,
+            IsFullPDB,
+            GetFullPDB,
+            GetVersionInfo,
+            SetCompiler,
+            CompileForFullPDB,
+            OverrideArgs,
+            OverrideRootSignature,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcPdbUtils as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasOutput(this: *mut core::ffi::c_void, dxcoutkind: DXC_OUT_KIND) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDxcResult", "HasOutput", 6usize, |this| ((**(this as *const *const IDxcResult_Vtbl)).HasOutput)(this, dxcoutkind), |_| None)
+        }
+        unsafe extern "system" fn GetOutput(this: *mut core::ffi::c_void, dxcoutkind: DXC_OUT_KIND, iid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void, ppoutputname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcResult", "GetOutput", 7usize, |this| ((**(this as *const *const IDxcResult_Vtbl)).GetOutput)(this, dxcoutkind, iid, ppvobject, ppoutputname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumOutputs(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDxcResult", "GetNumOutputs", 8usize, |this| ((**(this as *const *const IDxcResult_Vtbl)).GetNumOutputs)(this), |_| None)
+        }
+        unsafe extern "system" fn GetOutputByIndex(this: *mut core::ffi::c_void, index: u32) -> DXC_OUT_KIND {
+            windows_core::imp::proxy_call(this, "IDxcResult", "GetOutputByIndex", 9usize, |this| ((**(this as *const *const IDxcResult_Vtbl)).GetOutputByIndex)(this, index), |_| None)
+        }
+        unsafe extern "system" fn PrimaryOutput(this: *mut core::ffi::c_void) -> DXC_OUT_KIND {
+            windows_core::imp::proxy_call(this, "IDxcResult", "PrimaryOutput", 10usize, |this| ((**(this as *const *const IDxcResult_Vtbl)).PrimaryOutput)(this), |_| None)
+        }
+        Self {
+            base__: <IDxcOperationResult_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HasOutput,
+            GetOutput,
+            GetNumOutputs,
+            GetOutputByIndex,
+            PrimaryOutput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcResult as windows_core::Interface>::IID || <IDxcOperationResult_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDxcUtils_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateBlobFromBlob(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, offset: u32, length: u32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcUtils", "CreateBlobFromBlob", 3usize, |this| ((**(this as *const *const IDxcUtils_Vtbl)).CreateBlobFromBlob)(this, pblob, offset, length, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBlobFromPinned(this: *mut core::ffi::c_void, pdata: *const core::ffi::c_void, size: u32, codepage: DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcUtils", "CreateBlobFromPinned", 4usize, |this| ((**(this as *const *const IDxcUtils_Vtbl)).CreateBlobFromPinned)(this, pdata, size, codepage, pblobencoding), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MoveToBlob(this: *mut core::ffi::c_void, pdata: *const core::ffi::c_void, pimalloc: *mut core::ffi::c_void, size: u32, codepage: DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcUtils", "MoveToBlob", 5usize, |this| ((**(this as *const *const IDxcUtils_Vtbl)).MoveToBlob)(this, pdata, pimalloc, size, codepage, pblobencoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBlob(this: *mut core::ffi::c_void, pdata: *const core::ffi::c_void, size: u32, codepage: DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcUtils", "CreateBlob", 6usize, |this| ((**(this as *const *const IDxcUtils_Vtbl)).CreateBlob)(this, pdata, size, codepage, pblobencoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadFile(this: *mut core::ffi::c_void, pfilename: windows_core::PCWSTR, pcodepage: *const DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcUtils", "LoadFile", 7usize, |this| ((**(this as *const *const IDxcUtils_Vtbl)).LoadFile)(this, pfilename, pcodepage, pblobencoding), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateReadOnlyStreamFromBlob(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcUtils", "CreateReadOnlyStreamFromBlob", 8usize, |this| ((**(this as *const *const IDxcUtils_Vtbl)).CreateReadOnlyStreamFromBlob)(this, pblob, ppstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDefaultIncludeHandler(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcUtils", "CreateDefaultIncludeHandler", 9usize, |this| ((**(this as *const *const IDxcUtils_Vtbl)).CreateDefaultIncludeHandler)(this, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBlobAsUtf8(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcUtils", "GetBlobAsUtf8", 10usize, |this| ((**(this as *const *const IDxcUtils_Vtbl)).GetBlobAsUtf8)(this, pblob, pblobencoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBlobAsUtf16(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcUtils", "GetBlobAsUtf16", 11usize, |this| ((**(this as *const *const IDxcUtils_Vtbl)).GetBlobAsUtf16)(this, pblob, pblobencoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDxilContainerPart(this: *mut core::ffi::c_void, pshader: *const DxcBuffer, dxcpart: u32, pppartdata: *mut *mut core::ffi::c_void, ppartsizeinbytes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcUtils", "GetDxilContainerPart", 12usize, |this| ((**(this as *const *const IDxcUtils_Vtbl)).GetDxilContainerPart)(this, pshader, dxcpart, pppartdata, ppartsizeinbytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateReflection(this: *mut core::ffi::c_void, pdata: *const DxcBuffer, iid: *const windows_core::GUID, ppvreflection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcUtils", "CreateReflection", 13usize, |this| ((**(this as *const *const IDxcUtils_Vtbl)).CreateReflection)(this, pdata, iid, ppvreflection), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuildArguments(this: *mut core::ffi::c_void, psourcename: windows_core::PCWSTR, pentrypoint: windows_core::PCWSTR, ptargetprofile: windows_core::PCWSTR, parguments: *const windows_core::PCWSTR, argcount: u32, pdefines: *const DxcDefine, definecount: u32, ppargs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcUtils", "BuildArguments", 14usize, |this| ((**(this as *const *const IDxcUtils_Vtbl)).BuildArguments)(this, psourcename, pentrypoint, ptargetprofile, parguments, argcount, pdefines, definecount, ppargs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPDBContents(this: *mut core::ffi::c_void, ppdbblob: *mut core::ffi::c_void, pphash: *mut *mut core::ffi::c_void, ppcontainer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcUtils", "GetPDBContents", 15usize, |this| ((**(this as *const *const IDxcUtils_Vtbl)).GetPDBContents)(this, ppdbblob, pphash, ppcontainer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateBlobFromBlob,
+            CreateBlobFromPinned,
+            #[cfg(feature = "Win32_System_Com")]
+            MoveToBlob,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MoveToBlob: 0,
+            CreateBlob,
+            LoadFile,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateReadOnlyStreamFromBlob,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateReadOnlyStreamFromBlob: 0,
+            CreateDefaultIncludeHandler,
+            GetBlobAsUtf8,
+            GetBlobAsUtf16,
+            GetDxilContainerPart,
+            CreateReflection,
+            BuildArguments,
+            GetPDBContents,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcUtils as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcValidator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Validate(this: *mut core::ffi::c_void, pshader: *mut core::ffi::c_void, flags: u32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcValidator", "Validate", 3usize, |this| ((**(this as *const *const IDxcValidator_Vtbl)).Validate)(this, pshader, flags, ppresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Validate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcValidator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcValidator2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ValidateWithDebug(this: *mut core::ffi::c_void, pshader: *mut core::ffi::c_void, flags: u32, poptdebugbitcode: *const DxcBuffer, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcValidator2", "ValidateWithDebug", 4usize, |this| ((**(this as *const *const IDxcValidator2_Vtbl)).ValidateWithDebug)(this, pshader, flags, poptdebugbitcode, ppresult), |result| Some(*result))
+        }
+        Self { base__: <IDxcValidator_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ValidateWithDebug }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcValidator2 as windows_core::Interface>::IID || <IDxcValidator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcVersionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVersion(this: *mut core::ffi::c_void, pmajor: *mut u32, pminor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcVersionInfo", "GetVersion", 3usize, |this| ((**(this as *const *const IDxcVersionInfo_Vtbl)).GetVersion)(this, pmajor, pminor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcVersionInfo", "GetFlags", 4usize, |this| ((**(this as *const *const IDxcVersionInfo_Vtbl)).GetFlags)(this, pflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetVersion, GetFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcVersionInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcVersionInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCommitInfo(this: *mut core::ffi::c_void, pcommitcount: *mut u32, pcommithash: *mut *mut i8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcVersionInfo2", "GetCommitInfo", 5usize, |this| ((**(this as *const *const IDxcVersionInfo2_Vtbl)).GetCommitInfo)(this, pcommitcount, pcommithash), |result| Some(*result))
+        }
+        Self { base__: <IDxcVersionInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCommitInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcVersionInfo2 as windows_core::Interface>::IID || <IDxcVersionInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDxcVersionInfo3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCustomVersionString(this: *mut core::ffi::c_void, pversionstring: *mut *mut i8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDxcVersionInfo3", "GetCustomVersionString", 3usize, |this| ((**(this as *const *const IDxcVersionInfo3_Vtbl)).GetCustomVersionString)(this, pversionstring), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCustomVersionString }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDxcVersionInfo3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -255,6 +255,7 @@ pub struct D3DCOMPILER_STRIP_FLAGS(pub i32);
 impl windows_core::TypeKind for D3DCOMPILER_STRIP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3DCOMPILER_STRIP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3DCOMPILER_STRIP_FLAGS").field(&self.0).finish()
@@ -266,13 +267,15 @@ pub struct D3D_BLOB_PART(pub i32);
 impl windows_core::TypeKind for D3D_BLOB_PART {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_BLOB_PART {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_BLOB_PART").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D_SHADER_DATA {
     pub pBytecode: *const core::ffi::c_void,
     pub BytecodeLength: usize,
@@ -728,6 +728,7 @@ pub struct D3D_CBUFFER_TYPE(pub i32);
 impl windows_core::TypeKind for D3D_CBUFFER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_CBUFFER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_CBUFFER_TYPE").field(&self.0).finish()
@@ -739,6 +740,7 @@ pub struct D3D_DRIVER_TYPE(pub i32);
 impl windows_core::TypeKind for D3D_DRIVER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_DRIVER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_DRIVER_TYPE").field(&self.0).finish()
@@ -750,6 +752,7 @@ pub struct D3D_FEATURE_LEVEL(pub i32);
 impl windows_core::TypeKind for D3D_FEATURE_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_FEATURE_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_FEATURE_LEVEL").field(&self.0).finish()
@@ -761,6 +764,7 @@ pub struct D3D_FORMAT_COMPONENT_INTERPRETATION(pub i32);
 impl windows_core::TypeKind for D3D_FORMAT_COMPONENT_INTERPRETATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_FORMAT_COMPONENT_INTERPRETATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_FORMAT_COMPONENT_INTERPRETATION").field(&self.0).finish()
@@ -772,6 +776,7 @@ pub struct D3D_FORMAT_COMPONENT_NAME(pub i32);
 impl windows_core::TypeKind for D3D_FORMAT_COMPONENT_NAME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_FORMAT_COMPONENT_NAME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_FORMAT_COMPONENT_NAME").field(&self.0).finish()
@@ -783,6 +788,7 @@ pub struct D3D_FORMAT_LAYOUT(pub i32);
 impl windows_core::TypeKind for D3D_FORMAT_LAYOUT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_FORMAT_LAYOUT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_FORMAT_LAYOUT").field(&self.0).finish()
@@ -794,6 +800,7 @@ pub struct D3D_FORMAT_TYPE_LEVEL(pub i32);
 impl windows_core::TypeKind for D3D_FORMAT_TYPE_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_FORMAT_TYPE_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_FORMAT_TYPE_LEVEL").field(&self.0).finish()
@@ -805,6 +812,7 @@ pub struct D3D_INCLUDE_TYPE(pub i32);
 impl windows_core::TypeKind for D3D_INCLUDE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_INCLUDE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_INCLUDE_TYPE").field(&self.0).finish()
@@ -816,6 +824,7 @@ pub struct D3D_INTERPOLATION_MODE(pub i32);
 impl windows_core::TypeKind for D3D_INTERPOLATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_INTERPOLATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_INTERPOLATION_MODE").field(&self.0).finish()
@@ -827,6 +836,7 @@ pub struct D3D_MIN_PRECISION(pub i32);
 impl windows_core::TypeKind for D3D_MIN_PRECISION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_MIN_PRECISION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_MIN_PRECISION").field(&self.0).finish()
@@ -838,6 +848,7 @@ pub struct D3D_NAME(pub i32);
 impl windows_core::TypeKind for D3D_NAME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_NAME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_NAME").field(&self.0).finish()
@@ -849,6 +860,7 @@ pub struct D3D_PARAMETER_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D_PARAMETER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_PARAMETER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_PARAMETER_FLAGS").field(&self.0).finish()
@@ -860,6 +872,7 @@ pub struct D3D_PRIMITIVE(pub i32);
 impl windows_core::TypeKind for D3D_PRIMITIVE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_PRIMITIVE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_PRIMITIVE").field(&self.0).finish()
@@ -871,6 +884,7 @@ pub struct D3D_PRIMITIVE_TOPOLOGY(pub i32);
 impl windows_core::TypeKind for D3D_PRIMITIVE_TOPOLOGY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_PRIMITIVE_TOPOLOGY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_PRIMITIVE_TOPOLOGY").field(&self.0).finish()
@@ -882,6 +896,7 @@ pub struct D3D_REGISTER_COMPONENT_TYPE(pub i32);
 impl windows_core::TypeKind for D3D_REGISTER_COMPONENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_REGISTER_COMPONENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_REGISTER_COMPONENT_TYPE").field(&self.0).finish()
@@ -893,6 +908,7 @@ pub struct D3D_RESOURCE_RETURN_TYPE(pub i32);
 impl windows_core::TypeKind for D3D_RESOURCE_RETURN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_RESOURCE_RETURN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_RESOURCE_RETURN_TYPE").field(&self.0).finish()
@@ -904,6 +920,7 @@ pub struct D3D_SHADER_CBUFFER_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D_SHADER_CBUFFER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_SHADER_CBUFFER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_SHADER_CBUFFER_FLAGS").field(&self.0).finish()
@@ -915,6 +932,7 @@ pub struct D3D_SHADER_INPUT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D_SHADER_INPUT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_SHADER_INPUT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_SHADER_INPUT_FLAGS").field(&self.0).finish()
@@ -926,6 +944,7 @@ pub struct D3D_SHADER_INPUT_TYPE(pub i32);
 impl windows_core::TypeKind for D3D_SHADER_INPUT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_SHADER_INPUT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_SHADER_INPUT_TYPE").field(&self.0).finish()
@@ -937,6 +956,7 @@ pub struct D3D_SHADER_VARIABLE_CLASS(pub i32);
 impl windows_core::TypeKind for D3D_SHADER_VARIABLE_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_SHADER_VARIABLE_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_SHADER_VARIABLE_CLASS").field(&self.0).finish()
@@ -948,6 +968,7 @@ pub struct D3D_SHADER_VARIABLE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D_SHADER_VARIABLE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_SHADER_VARIABLE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_SHADER_VARIABLE_FLAGS").field(&self.0).finish()
@@ -959,6 +980,7 @@ pub struct D3D_SHADER_VARIABLE_TYPE(pub i32);
 impl windows_core::TypeKind for D3D_SHADER_VARIABLE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_SHADER_VARIABLE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_SHADER_VARIABLE_TYPE").field(&self.0).finish()
@@ -970,6 +992,7 @@ pub struct D3D_SRV_DIMENSION(pub i32);
 impl windows_core::TypeKind for D3D_SRV_DIMENSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_SRV_DIMENSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_SRV_DIMENSION").field(&self.0).finish()
@@ -981,6 +1004,7 @@ pub struct D3D_TESSELLATOR_DOMAIN(pub i32);
 impl windows_core::TypeKind for D3D_TESSELLATOR_DOMAIN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_TESSELLATOR_DOMAIN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_TESSELLATOR_DOMAIN").field(&self.0).finish()
@@ -992,6 +1016,7 @@ pub struct D3D_TESSELLATOR_OUTPUT_PRIMITIVE(pub i32);
 impl windows_core::TypeKind for D3D_TESSELLATOR_OUTPUT_PRIMITIVE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_TESSELLATOR_OUTPUT_PRIMITIVE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_TESSELLATOR_OUTPUT_PRIMITIVE").field(&self.0).finish()
@@ -1003,13 +1028,15 @@ pub struct D3D_TESSELLATOR_PARTITIONING(pub i32);
 impl windows_core::TypeKind for D3D_TESSELLATOR_PARTITIONING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_TESSELLATOR_PARTITIONING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_TESSELLATOR_PARTITIONING").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DVECTOR {
     pub x: f32,
     pub y: f32,
@@ -1024,7 +1051,8 @@ impl Default for D3DVECTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D_SHADER_MACRO {
     pub Name: windows_core::PCSTR,
     pub Definition: windows_core::PCSTR,
@@ -1040,3 +1068,5 @@ impl Default for D3D_SHADER_MACRO {
 pub type PFN_DESTRUCTION_CALLBACK = Option<unsafe extern "system" fn(pdata: *mut core::ffi::c_void)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,30 @@
+impl windows_core::imp::ProxyVtable for ID3DBlob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBufferPointer(this: *mut core::ffi::c_void) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "ID3DBlob", "GetBufferPointer", 3usize, |this| ((**(this as *const *const ID3DBlob_Vtbl)).GetBufferPointer)(this), |_| None)
+        }
+        unsafe extern "system" fn GetBufferSize(this: *mut core::ffi::c_void) -> usize {
+            windows_core::imp::proxy_call(this, "ID3DBlob", "GetBufferSize", 4usize, |this| ((**(this as *const *const ID3DBlob_Vtbl)).GetBufferSize)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBufferPointer, GetBufferSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3DBlob as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3DDestructionNotifier_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterDestructionCallback(this: *mut core::ffi::c_void, callbackfn: PFN_DESTRUCTION_CALLBACK, pdata: *const core::ffi::c_void, pcallbackid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3DDestructionNotifier", "RegisterDestructionCallback", 3usize, |this| ((**(this as *const *const ID3DDestructionNotifier_Vtbl)).RegisterDestructionCallback)(this, callbackfn, pdata, pcallbackid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterDestructionCallback(this: *mut core::ffi::c_void, callbackid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3DDestructionNotifier", "UnregisterDestructionCallback", 4usize, |this| ((**(this as *const *const ID3DDestructionNotifier_Vtbl)).UnregisterDestructionCallback)(this, callbackid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterDestructionCallback, UnregisterDestructionCallback }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3DDestructionNotifier as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
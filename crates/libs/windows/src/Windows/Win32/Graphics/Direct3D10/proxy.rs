@@ -0,0 +1,1334 @@
+impl windows_core::imp::ProxyVtable for ID3D10Asynchronous_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Asynchronous", "Begin", 7usize, |this| ((**(this as *const *const ID3D10Asynchronous_Vtbl)).Begin)(this), |_| None)
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Asynchronous", "End", 8usize, |this| ((**(this as *const *const ID3D10Asynchronous_Vtbl)).End)(this), |_| None)
+        }
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, pdata: *mut core::ffi::c_void, datasize: u32, getdataflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Asynchronous", "GetData", 9usize, |this| ((**(this as *const *const ID3D10Asynchronous_Vtbl)).GetData)(this, pdata, datasize, getdataflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataSize(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D10Asynchronous", "GetDataSize", 10usize, |this| ((**(this as *const *const ID3D10Asynchronous_Vtbl)).GetDataSize)(this), |_| None)
+        }
+        Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin, End, GetData, GetDataSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Asynchronous as windows_core::Interface>::IID || <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10BlendState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_BLEND_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D10BlendState", "GetDesc", 7usize, |this| ((**(this as *const *const ID3D10BlendState_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10BlendState as windows_core::Interface>::IID || <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10BlendState1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc1(this: *mut core::ffi::c_void, pdesc: *mut D3D10_BLEND_DESC1) {
+            windows_core::imp::proxy_call(this, "ID3D10BlendState1", "GetDesc1", 8usize, |this| ((**(this as *const *const ID3D10BlendState1_Vtbl)).GetDesc1)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D10BlendState_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10BlendState1 as windows_core::Interface>::IID || <ID3D10BlendState_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10Buffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Map(this: *mut core::ffi::c_void, maptype: D3D10_MAP, mapflags: u32, ppdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Buffer", "Map", 10usize, |this| ((**(this as *const *const ID3D10Buffer_Vtbl)).Map)(this, maptype, mapflags, ppdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unmap(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Buffer", "Unmap", 11usize, |this| ((**(this as *const *const ID3D10Buffer_Vtbl)).Unmap)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_BUFFER_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D10Buffer", "GetDesc", 12usize, |this| ((**(this as *const *const ID3D10Buffer_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D10Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Map, Unmap, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Buffer as windows_core::Interface>::IID || <ID3D10Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10Counter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_COUNTER_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D10Counter", "GetDesc", 11usize, |this| ((**(this as *const *const ID3D10Counter_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D10Asynchronous_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Counter as windows_core::Interface>::IID || <ID3D10Asynchronous_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi")]
+impl windows_core::imp::ProxyVtable for ID3D10Debug_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFeatureMask(this: *mut core::ffi::c_void, mask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Debug", "SetFeatureMask", 3usize, |this| ((**(this as *const *const ID3D10Debug_Vtbl)).SetFeatureMask)(this, mask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeatureMask(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D10Debug", "GetFeatureMask", 4usize, |this| ((**(this as *const *const ID3D10Debug_Vtbl)).GetFeatureMask)(this), |_| None)
+        }
+        unsafe extern "system" fn SetPresentPerRenderOpDelay(this: *mut core::ffi::c_void, milliseconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Debug", "SetPresentPerRenderOpDelay", 5usize, |this| ((**(this as *const *const ID3D10Debug_Vtbl)).SetPresentPerRenderOpDelay)(this, milliseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresentPerRenderOpDelay(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D10Debug", "GetPresentPerRenderOpDelay", 6usize, |this| ((**(this as *const *const ID3D10Debug_Vtbl)).GetPresentPerRenderOpDelay)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn SetSwapChain(this: *mut core::ffi::c_void, pswapchain: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Debug", "SetSwapChain", 7usize, |this| ((**(this as *const *const ID3D10Debug_Vtbl)).SetSwapChain)(this, pswapchain), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn GetSwapChain(this: *mut core::ffi::c_void, ppswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Debug", "GetSwapChain", 8usize, |this| ((**(this as *const *const ID3D10Debug_Vtbl)).GetSwapChain)(this, ppswapchain), |result| Some(*result))
+        }
+        unsafe extern "system" fn Validate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Debug", "Validate", 9usize, |this| ((**(this as *const *const ID3D10Debug_Vtbl)).Validate)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetFeatureMask,
+            GetFeatureMask,
+            SetPresentPerRenderOpDelay,
+            GetPresentPerRenderOpDelay,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            SetSwapChain,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            SetSwapChain: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            GetSwapChain,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            GetSwapChain: 0,
+            Validate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Debug as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10DepthStencilState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_DEPTH_STENCIL_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D10DepthStencilState", "GetDesc", 7usize, |this| ((**(this as *const *const ID3D10DepthStencilState_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10DepthStencilState as windows_core::Interface>::IID || <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D10DepthStencilView_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_DEPTH_STENCIL_VIEW_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D10DepthStencilView", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D10DepthStencilView_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D10View_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10DepthStencilView as windows_core::Interface>::IID || <ID3D10View_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D10Device_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VSSetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "VSSetConstantBuffers", 3usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).VSSetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn PSSetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "PSSetShaderResources", 4usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).PSSetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn PSSetShader(this: *mut core::ffi::c_void, ppixelshader: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "PSSetShader", 5usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).PSSetShader)(this, ppixelshader), |_| None)
+        }
+        unsafe extern "system" fn PSSetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "PSSetSamplers", 6usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).PSSetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn VSSetShader(this: *mut core::ffi::c_void, pvertexshader: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "VSSetShader", 7usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).VSSetShader)(this, pvertexshader), |_| None)
+        }
+        unsafe extern "system" fn DrawIndexed(this: *mut core::ffi::c_void, indexcount: u32, startindexlocation: u32, basevertexlocation: i32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "DrawIndexed", 8usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).DrawIndexed)(this, indexcount, startindexlocation, basevertexlocation), |_| None)
+        }
+        unsafe extern "system" fn Draw(this: *mut core::ffi::c_void, vertexcount: u32, startvertexlocation: u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "Draw", 9usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).Draw)(this, vertexcount, startvertexlocation), |_| None)
+        }
+        unsafe extern "system" fn PSSetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "PSSetConstantBuffers", 10usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).PSSetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn IASetInputLayout(this: *mut core::ffi::c_void, pinputlayout: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "IASetInputLayout", 11usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).IASetInputLayout)(this, pinputlayout), |_| None)
+        }
+        unsafe extern "system" fn IASetVertexBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppvertexbuffers: *const *mut core::ffi::c_void, pstrides: *const u32, poffsets: *const u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "IASetVertexBuffers", 12usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).IASetVertexBuffers)(this, startslot, numbuffers, ppvertexbuffers, pstrides, poffsets), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn IASetIndexBuffer(this: *mut core::ffi::c_void, pindexbuffer: *mut core::ffi::c_void, format: super::Dxgi::Common::DXGI_FORMAT, offset: u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "IASetIndexBuffer", 13usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).IASetIndexBuffer)(this, pindexbuffer, format, offset), |_| None)
+        }
+        unsafe extern "system" fn DrawIndexedInstanced(this: *mut core::ffi::c_void, indexcountperinstance: u32, instancecount: u32, startindexlocation: u32, basevertexlocation: i32, startinstancelocation: u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "DrawIndexedInstanced", 14usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).DrawIndexedInstanced)(this, indexcountperinstance, instancecount, startindexlocation, basevertexlocation, startinstancelocation), |_| None)
+        }
+        unsafe extern "system" fn DrawInstanced(this: *mut core::ffi::c_void, vertexcountperinstance: u32, instancecount: u32, startvertexlocation: u32, startinstancelocation: u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "DrawInstanced", 15usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).DrawInstanced)(this, vertexcountperinstance, instancecount, startvertexlocation, startinstancelocation), |_| None)
+        }
+        unsafe extern "system" fn GSSetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "GSSetConstantBuffers", 16usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).GSSetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn GSSetShader(this: *mut core::ffi::c_void, pshader: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "GSSetShader", 17usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).GSSetShader)(this, pshader), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn IASetPrimitiveTopology(this: *mut core::ffi::c_void, topology: super::Direct3D::D3D_PRIMITIVE_TOPOLOGY) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "IASetPrimitiveTopology", 18usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).IASetPrimitiveTopology)(this, topology), |_| None)
+        }
+        unsafe extern "system" fn VSSetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "VSSetShaderResources", 19usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).VSSetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn VSSetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "VSSetSamplers", 20usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).VSSetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn SetPredication(this: *mut core::ffi::c_void, ppredicate: *mut core::ffi::c_void, predicatevalue: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "SetPredication", 21usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).SetPredication)(this, ppredicate, predicatevalue), |_| None)
+        }
+        unsafe extern "system" fn GSSetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "GSSetShaderResources", 22usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).GSSetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn GSSetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "GSSetSamplers", 23usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).GSSetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn OMSetRenderTargets(this: *mut core::ffi::c_void, numviews: u32, pprendertargetviews: *const *mut core::ffi::c_void, pdepthstencilview: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "OMSetRenderTargets", 24usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).OMSetRenderTargets)(this, numviews, pprendertargetviews, pdepthstencilview), |_| None)
+        }
+        unsafe extern "system" fn OMSetBlendState(this: *mut core::ffi::c_void, pblendstate: *mut core::ffi::c_void, blendfactor: *const f32, samplemask: u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "OMSetBlendState", 25usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).OMSetBlendState)(this, pblendstate, blendfactor, samplemask), |_| None)
+        }
+        unsafe extern "system" fn OMSetDepthStencilState(this: *mut core::ffi::c_void, pdepthstencilstate: *mut core::ffi::c_void, stencilref: u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "OMSetDepthStencilState", 26usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).OMSetDepthStencilState)(this, pdepthstencilstate, stencilref), |_| None)
+        }
+        unsafe extern "system" fn SOSetTargets(this: *mut core::ffi::c_void, numbuffers: u32, ppsotargets: *const *mut core::ffi::c_void, poffsets: *const u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "SOSetTargets", 27usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).SOSetTargets)(this, numbuffers, ppsotargets, poffsets), |_| None)
+        }
+        unsafe extern "system" fn DrawAuto(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "DrawAuto", 28usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).DrawAuto)(this), |_| None)
+        }
+        unsafe extern "system" fn RSSetState(this: *mut core::ffi::c_void, prasterizerstate: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "RSSetState", 29usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).RSSetState)(this, prasterizerstate), |_| None)
+        }
+        unsafe extern "system" fn RSSetViewports(this: *mut core::ffi::c_void, numviewports: u32, pviewports: *const D3D10_VIEWPORT) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "RSSetViewports", 30usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).RSSetViewports)(this, numviewports, pviewports), |_| None)
+        }
+        unsafe extern "system" fn RSSetScissorRects(this: *mut core::ffi::c_void, numrects: u32, prects: *const super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "RSSetScissorRects", 31usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).RSSetScissorRects)(this, numrects, prects), |_| None)
+        }
+        unsafe extern "system" fn CopySubresourceRegion(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, dstx: u32, dsty: u32, dstz: u32, psrcresource: *mut core::ffi::c_void, srcsubresource: u32, psrcbox: *const D3D10_BOX) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CopySubresourceRegion", 32usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CopySubresourceRegion)(this, pdstresource, dstsubresource, dstx, dsty, dstz, psrcresource, srcsubresource, psrcbox), |_| None)
+        }
+        unsafe extern "system" fn CopyResource(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, psrcresource: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CopyResource", 33usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CopyResource)(this, pdstresource, psrcresource), |_| None)
+        }
+        unsafe extern "system" fn UpdateSubresource(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, pdstbox: *const D3D10_BOX, psrcdata: *const core::ffi::c_void, srcrowpitch: u32, srcdepthpitch: u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "UpdateSubresource", 34usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).UpdateSubresource)(this, pdstresource, dstsubresource, pdstbox, psrcdata, srcrowpitch, srcdepthpitch), |_| None)
+        }
+        unsafe extern "system" fn ClearRenderTargetView(this: *mut core::ffi::c_void, prendertargetview: *mut core::ffi::c_void, colorrgba: *const f32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "ClearRenderTargetView", 35usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).ClearRenderTargetView)(this, prendertargetview, colorrgba), |_| None)
+        }
+        unsafe extern "system" fn ClearDepthStencilView(this: *mut core::ffi::c_void, pdepthstencilview: *mut core::ffi::c_void, clearflags: u32, depth: f32, stencil: u8) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "ClearDepthStencilView", 36usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).ClearDepthStencilView)(this, pdepthstencilview, clearflags, depth, stencil), |_| None)
+        }
+        unsafe extern "system" fn GenerateMips(this: *mut core::ffi::c_void, pshaderresourceview: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "GenerateMips", 37usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).GenerateMips)(this, pshaderresourceview), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn ResolveSubresource(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, psrcresource: *mut core::ffi::c_void, srcsubresource: u32, format: super::Dxgi::Common::DXGI_FORMAT) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "ResolveSubresource", 38usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).ResolveSubresource)(this, pdstresource, dstsubresource, psrcresource, srcsubresource, format), |_| None)
+        }
+        unsafe extern "system" fn VSGetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "VSGetConstantBuffers", 39usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).VSGetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn PSGetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "PSGetShaderResources", 40usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).PSGetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn PSGetShader(this: *mut core::ffi::c_void, pppixelshader: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "PSGetShader", 41usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).PSGetShader)(this, pppixelshader), |_| None)
+        }
+        unsafe extern "system" fn PSGetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "PSGetSamplers", 42usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).PSGetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn VSGetShader(this: *mut core::ffi::c_void, ppvertexshader: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "VSGetShader", 43usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).VSGetShader)(this, ppvertexshader), |_| None)
+        }
+        unsafe extern "system" fn PSGetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "PSGetConstantBuffers", 44usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).PSGetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn IAGetInputLayout(this: *mut core::ffi::c_void, ppinputlayout: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "IAGetInputLayout", 45usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).IAGetInputLayout)(this, ppinputlayout), |_| None)
+        }
+        unsafe extern "system" fn IAGetVertexBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppvertexbuffers: *mut *mut core::ffi::c_void, pstrides: *mut u32, poffsets: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "IAGetVertexBuffers", 46usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).IAGetVertexBuffers)(this, startslot, numbuffers, ppvertexbuffers, pstrides, poffsets), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn IAGetIndexBuffer(this: *mut core::ffi::c_void, pindexbuffer: *mut *mut core::ffi::c_void, format: *mut super::Dxgi::Common::DXGI_FORMAT, offset: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "IAGetIndexBuffer", 47usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).IAGetIndexBuffer)(this, pindexbuffer, format, offset), |_| None)
+        }
+        unsafe extern "system" fn GSGetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "GSGetConstantBuffers", 48usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).GSGetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn GSGetShader(this: *mut core::ffi::c_void, ppgeometryshader: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "GSGetShader", 49usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).GSGetShader)(this, ppgeometryshader), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn IAGetPrimitiveTopology(this: *mut core::ffi::c_void, ptopology: *mut super::Direct3D::D3D_PRIMITIVE_TOPOLOGY) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "IAGetPrimitiveTopology", 50usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).IAGetPrimitiveTopology)(this, ptopology), |_| None)
+        }
+        unsafe extern "system" fn VSGetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "VSGetShaderResources", 51usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).VSGetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn VSGetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "VSGetSamplers", 52usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).VSGetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn GetPredication(this: *mut core::ffi::c_void, pppredicate: *mut *mut core::ffi::c_void, ppredicatevalue: *mut super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "GetPredication", 53usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).GetPredication)(this, pppredicate, ppredicatevalue), |_| None)
+        }
+        unsafe extern "system" fn GSGetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "GSGetShaderResources", 54usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).GSGetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn GSGetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "GSGetSamplers", 55usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).GSGetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn OMGetRenderTargets(this: *mut core::ffi::c_void, numviews: u32, pprendertargetviews: *mut *mut core::ffi::c_void, ppdepthstencilview: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "OMGetRenderTargets", 56usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).OMGetRenderTargets)(this, numviews, pprendertargetviews, ppdepthstencilview), |_| None)
+        }
+        unsafe extern "system" fn OMGetBlendState(this: *mut core::ffi::c_void, ppblendstate: *mut *mut core::ffi::c_void, blendfactor: *mut f32, psamplemask: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "OMGetBlendState", 57usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).OMGetBlendState)(this, ppblendstate, blendfactor, psamplemask), |_| None)
+        }
+        unsafe extern "system" fn OMGetDepthStencilState(this: *mut core::ffi::c_void, ppdepthstencilstate: *mut *mut core::ffi::c_void, pstencilref: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "OMGetDepthStencilState", 58usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).OMGetDepthStencilState)(this, ppdepthstencilstate, pstencilref), |_| None)
+        }
+        unsafe extern "system" fn SOGetTargets(this: *mut core::ffi::c_void, numbuffers: u32, ppsotargets: *mut *mut core::ffi::c_void, poffsets: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "SOGetTargets", 59usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).SOGetTargets)(this, numbuffers, ppsotargets, poffsets), |_| None)
+        }
+        unsafe extern "system" fn RSGetState(this: *mut core::ffi::c_void, pprasterizerstate: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "RSGetState", 60usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).RSGetState)(this, pprasterizerstate), |_| None)
+        }
+        unsafe extern "system" fn RSGetViewports(this: *mut core::ffi::c_void, numviewports: *mut u32, pviewports: *mut D3D10_VIEWPORT) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "RSGetViewports", 61usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).RSGetViewports)(this, numviewports, pviewports), |_| None)
+        }
+        unsafe extern "system" fn RSGetScissorRects(this: *mut core::ffi::c_void, numrects: *mut u32, prects: *mut super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "RSGetScissorRects", 62usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).RSGetScissorRects)(this, numrects, prects), |_| None)
+        }
+        unsafe extern "system" fn GetDeviceRemovedReason(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "GetDeviceRemovedReason", 63usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).GetDeviceRemovedReason)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExceptionMode(this: *mut core::ffi::c_void, raiseflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "SetExceptionMode", 64usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).SetExceptionMode)(this, raiseflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExceptionMode(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "GetExceptionMode", 65usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).GetExceptionMode)(this), |_| None)
+        }
+        unsafe extern "system" fn GetPrivateData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdatasize: *mut u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "GetPrivateData", 66usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).GetPrivateData)(this, guid, pdatasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "SetPrivateData", 67usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).SetPrivateData)(this, guid, datasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateDataInterface(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "SetPrivateDataInterface", 68usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).SetPrivateDataInterface)(this, guid, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearState(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "ClearState", 69usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).ClearState)(this), |_| None)
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "Flush", 70usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).Flush)(this), |_| None)
+        }
+        unsafe extern "system" fn CreateBuffer(this: *mut core::ffi::c_void, pdesc: *const D3D10_BUFFER_DESC, pinitialdata: *const D3D10_SUBRESOURCE_DATA, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateBuffer", 71usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateBuffer)(this, pdesc, pinitialdata, ppbuffer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateTexture1D(this: *mut core::ffi::c_void, pdesc: *const D3D10_TEXTURE1D_DESC, pinitialdata: *const D3D10_SUBRESOURCE_DATA, pptexture1d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateTexture1D", 72usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateTexture1D)(this, pdesc, pinitialdata, pptexture1d), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateTexture2D(this: *mut core::ffi::c_void, pdesc: *const D3D10_TEXTURE2D_DESC, pinitialdata: *const D3D10_SUBRESOURCE_DATA, pptexture2d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateTexture2D", 73usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateTexture2D)(this, pdesc, pinitialdata, pptexture2d), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateTexture3D(this: *mut core::ffi::c_void, pdesc: *const D3D10_TEXTURE3D_DESC, pinitialdata: *const D3D10_SUBRESOURCE_DATA, pptexture3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateTexture3D", 74usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateTexture3D)(this, pdesc, pinitialdata, pptexture3d), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateShaderResourceView(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D10_SHADER_RESOURCE_VIEW_DESC, ppsrview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateShaderResourceView", 75usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateShaderResourceView)(this, presource, pdesc, ppsrview), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateRenderTargetView(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D10_RENDER_TARGET_VIEW_DESC, pprtview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateRenderTargetView", 76usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateRenderTargetView)(this, presource, pdesc, pprtview), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateDepthStencilView(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D10_DEPTH_STENCIL_VIEW_DESC, ppdepthstencilview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateDepthStencilView", 77usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateDepthStencilView)(this, presource, pdesc, ppdepthstencilview), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateInputLayout(this: *mut core::ffi::c_void, pinputelementdescs: *const D3D10_INPUT_ELEMENT_DESC, numelements: u32, pshaderbytecodewithinputsignature: *const core::ffi::c_void, bytecodelength: usize, ppinputlayout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateInputLayout", 78usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateInputLayout)(this, pinputelementdescs, numelements, pshaderbytecodewithinputsignature, bytecodelength, ppinputlayout), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVertexShader(this: *mut core::ffi::c_void, pshaderbytecode: *const core::ffi::c_void, bytecodelength: usize, ppvertexshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateVertexShader", 79usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateVertexShader)(this, pshaderbytecode, bytecodelength, ppvertexshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGeometryShader(this: *mut core::ffi::c_void, pshaderbytecode: *const core::ffi::c_void, bytecodelength: usize, ppgeometryshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateGeometryShader", 80usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateGeometryShader)(this, pshaderbytecode, bytecodelength, ppgeometryshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGeometryShaderWithStreamOutput(this: *mut core::ffi::c_void, pshaderbytecode: *const core::ffi::c_void, bytecodelength: usize, psodeclaration: *const D3D10_SO_DECLARATION_ENTRY, numentries: u32, outputstreamstride: u32, ppgeometryshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateGeometryShaderWithStreamOutput", 81usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateGeometryShaderWithStreamOutput)(this, pshaderbytecode, bytecodelength, psodeclaration, numentries, outputstreamstride, ppgeometryshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePixelShader(this: *mut core::ffi::c_void, pshaderbytecode: *const core::ffi::c_void, bytecodelength: usize, pppixelshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreatePixelShader", 82usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreatePixelShader)(this, pshaderbytecode, bytecodelength, pppixelshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBlendState(this: *mut core::ffi::c_void, pblendstatedesc: *const D3D10_BLEND_DESC, ppblendstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateBlendState", 83usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateBlendState)(this, pblendstatedesc, ppblendstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDepthStencilState(this: *mut core::ffi::c_void, pdepthstencildesc: *const D3D10_DEPTH_STENCIL_DESC, ppdepthstencilstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateDepthStencilState", 84usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateDepthStencilState)(this, pdepthstencildesc, ppdepthstencilstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRasterizerState(this: *mut core::ffi::c_void, prasterizerdesc: *const D3D10_RASTERIZER_DESC, pprasterizerstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateRasterizerState", 85usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateRasterizerState)(this, prasterizerdesc, pprasterizerstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSamplerState(this: *mut core::ffi::c_void, psamplerdesc: *const D3D10_SAMPLER_DESC, ppsamplerstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateSamplerState", 86usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateSamplerState)(this, psamplerdesc, ppsamplerstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateQuery(this: *mut core::ffi::c_void, pquerydesc: *const D3D10_QUERY_DESC, ppquery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateQuery", 87usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateQuery)(this, pquerydesc, ppquery), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePredicate(this: *mut core::ffi::c_void, ppredicatedesc: *const D3D10_QUERY_DESC, pppredicate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreatePredicate", 88usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreatePredicate)(this, ppredicatedesc, pppredicate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCounter(this: *mut core::ffi::c_void, pcounterdesc: *const D3D10_COUNTER_DESC, ppcounter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CreateCounter", 89usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CreateCounter)(this, pcounterdesc, ppcounter), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CheckFormatSupport(this: *mut core::ffi::c_void, format: super::Dxgi::Common::DXGI_FORMAT, pformatsupport: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CheckFormatSupport", 90usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CheckFormatSupport)(this, format, pformatsupport), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CheckMultisampleQualityLevels(this: *mut core::ffi::c_void, format: super::Dxgi::Common::DXGI_FORMAT, samplecount: u32, pnumqualitylevels: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CheckMultisampleQualityLevels", 91usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CheckMultisampleQualityLevels)(this, format, samplecount, pnumqualitylevels), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckCounterInfo(this: *mut core::ffi::c_void, pcounterinfo: *mut D3D10_COUNTER_INFO) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CheckCounterInfo", 92usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CheckCounterInfo)(this, pcounterinfo), |_| None)
+        }
+        unsafe extern "system" fn CheckCounter(this: *mut core::ffi::c_void, pdesc: *const D3D10_COUNTER_DESC, ptype: *mut D3D10_COUNTER_TYPE, pactivecounters: *mut u32, szname: windows_core::PSTR, pnamelength: *mut u32, szunits: windows_core::PSTR, punitslength: *mut u32, szdescription: windows_core::PSTR, pdescriptionlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "CheckCounter", 93usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).CheckCounter)(this, pdesc, ptype, pactivecounters, szname, pnamelength, szunits, punitslength, szdescription, pdescriptionlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCreationFlags(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "GetCreationFlags", 94usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).GetCreationFlags)(this), |_| None)
+        }
+        unsafe extern "system" fn OpenSharedResource(this: *mut core::ffi::c_void, hresource: super::super::Foundation::HANDLE, returnedinterface: *const windows_core::GUID, ppresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "OpenSharedResource", 95usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).OpenSharedResource)(this, hresource, returnedinterface, ppresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextFilterSize(this: *mut core::ffi::c_void, width: u32, height: u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "SetTextFilterSize", 96usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).SetTextFilterSize)(this, width, height), |_| None)
+        }
+        unsafe extern "system" fn GetTextFilterSize(this: *mut core::ffi::c_void, pwidth: *mut u32, pheight: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Device", "GetTextFilterSize", 97usize, |this| ((**(this as *const *const ID3D10Device_Vtbl)).GetTextFilterSize)(this, pwidth, pheight), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            VSSetConstantBuffers,
+            PSSetShaderResources,
+            PSSetShader,
+            PSSetSamplers,
+            VSSetShader,
+            DrawIndexed,
+            Draw,
+            PSSetConstantBuffers,
+            IASetInputLayout,
+            IASetVertexBuffers,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            IASetIndexBuffer,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            IASetIndexBuffer: 0,
+            DrawIndexedInstanced,
+            DrawInstanced,
+            GSSetConstantBuffers,
+            GSSetShader,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            IASetPrimitiveTopology,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            IASetPrimitiveTopology: 0,
+            VSSetShaderResources,
+            VSSetSamplers,
+            SetPredication,
+            GSSetShaderResources,
+            GSSetSamplers,
+            OMSetRenderTargets,
+            OMSetBlendState,
+            OMSetDepthStencilState,
+            SOSetTargets,
+            DrawAuto,
+            RSSetState,
+            RSSetViewports,
+            RSSetScissorRects,
+            CopySubresourceRegion,
+            CopyResource,
+            UpdateSubresource,
+            ClearRenderTargetView,
+            ClearDepthStencilView,
+            GenerateMips,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            ResolveSubresource,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            ResolveSubresource: 0,
+            VSGetConstantBuffers,
+            PSGetShaderResources,
+            PSGetShader,
+            PSGetSamplers,
+            VSGetShader,
+            PSGetConstantBuffers,
+            IAGetInputLayout,
+            IAGetVertexBuffers,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            IAGetIndexBuffer,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            IAGetIndexBuffer: 0,
+            GSGetConstantBuffers,
+            GSGetShader,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            IAGetPrimitiveTopology,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            IAGetPrimitiveTopology: 0,
+            VSGetShaderResources,
+            VSGetSamplers,
+            GetPredication,
+            GSGetShaderResources,
+            GSGetSamplers,
+            OMGetRenderTargets,
+            OMGetBlendState,
+            OMGetDepthStencilState,
+            SOGetTargets,
+            RSGetState,
+            RSGetViewports,
+            RSGetScissorRects,
+            GetDeviceRemovedReason,
+            SetExceptionMode,
+            GetExceptionMode,
+            GetPrivateData,
+            SetPrivateData,
+            SetPrivateDataInterface,
+            ClearState,
+            Flush,
+            CreateBuffer,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateTexture1D,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateTexture1D: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateTexture2D,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateTexture2D: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateTexture3D,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateTexture3D: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateShaderResourceView,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateShaderResourceView: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateRenderTargetView,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateRenderTargetView: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateDepthStencilView,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateDepthStencilView: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateInputLayout,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateInputLayout: 0,
+            CreateVertexShader,
+            CreateGeometryShader,
+            CreateGeometryShaderWithStreamOutput,
+            CreatePixelShader,
+            CreateBlendState,
+            CreateDepthStencilState,
+            CreateRasterizerState,
+            CreateSamplerState,
+            CreateQuery,
+            CreatePredicate,
+            CreateCounter,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CheckFormatSupport,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CheckFormatSupport: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CheckMultisampleQualityLevels,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CheckMultisampleQualityLevels: 0,
+            CheckCounterInfo,
+            CheckCounter,
+            GetCreationFlags,
+            OpenSharedResource,
+            SetTextFilterSize,
+            GetTextFilterSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Device as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D10Device1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateShaderResourceView1(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D10_SHADER_RESOURCE_VIEW_DESC1, ppsrview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device1", "CreateShaderResourceView1", 98usize, |this| ((**(this as *const *const ID3D10Device1_Vtbl)).CreateShaderResourceView1)(this, presource, pdesc, ppsrview), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBlendState1(this: *mut core::ffi::c_void, pblendstatedesc: *const D3D10_BLEND_DESC1, ppblendstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Device1", "CreateBlendState1", 99usize, |this| ((**(this as *const *const ID3D10Device1_Vtbl)).CreateBlendState1)(this, pblendstatedesc, ppblendstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeatureLevel(this: *mut core::ffi::c_void) -> D3D10_FEATURE_LEVEL1 {
+            windows_core::imp::proxy_call(this, "ID3D10Device1", "GetFeatureLevel", 100usize, |this| ((**(this as *const *const ID3D10Device1_Vtbl)).GetFeatureLevel)(this), |_| None)
+        }
+        Self {
+            base__: <ID3D10Device_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateShaderResourceView1,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateShaderResourceView1: 0,
+            CreateBlendState1,
+            GetFeatureLevel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Device1 as windows_core::Interface>::IID || <ID3D10Device_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10DeviceChild_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10DeviceChild", "GetDevice", 3usize, |this| ((**(this as *const *const ID3D10DeviceChild_Vtbl)).GetDevice)(this, ppdevice), |_| None)
+        }
+        unsafe extern "system" fn GetPrivateData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdatasize: *mut u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10DeviceChild", "GetPrivateData", 4usize, |this| ((**(this as *const *const ID3D10DeviceChild_Vtbl)).GetPrivateData)(this, guid, pdatasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10DeviceChild", "SetPrivateData", 5usize, |this| ((**(this as *const *const ID3D10DeviceChild_Vtbl)).SetPrivateData)(this, guid, datasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateDataInterface(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10DeviceChild", "SetPrivateDataInterface", 6usize, |this| ((**(this as *const *const ID3D10DeviceChild_Vtbl)).SetPrivateDataInterface)(this, guid, pdata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDevice,
+            GetPrivateData,
+            SetPrivateData,
+            SetPrivateDataInterface,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10DeviceChild as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10Effect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsValid(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D10Effect", "IsValid", 3usize, |this| ((**(this as *const *const ID3D10Effect_Vtbl)).IsValid)(this), |_| None)
+        }
+        unsafe extern "system" fn IsPool(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D10Effect", "IsPool", 4usize, |this| ((**(this as *const *const ID3D10Effect_Vtbl)).IsPool)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Effect", "GetDevice", 5usize, |this| ((**(this as *const *const ID3D10Effect_Vtbl)).GetDevice)(this, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_EFFECT_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Effect", "GetDesc", 6usize, |this| ((**(this as *const *const ID3D10Effect_Vtbl)).GetDesc)(this, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConstantBufferByIndex(this: *mut core::ffi::c_void, index: u32) -> Option<ID3D10EffectConstantBuffer> {
+            windows_core::imp::proxy_call(this, "ID3D10Effect", "GetConstantBufferByIndex", 7usize, |this| ((**(this as *const *const ID3D10Effect_Vtbl)).GetConstantBufferByIndex)(this, index), |_| None)
+        }
+        unsafe extern "system" fn GetConstantBufferByName(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D10EffectConstantBuffer> {
+            windows_core::imp::proxy_call(this, "ID3D10Effect", "GetConstantBufferByName", 8usize, |this| ((**(this as *const *const ID3D10Effect_Vtbl)).GetConstantBufferByName)(this, name), |_| None)
+        }
+        unsafe extern "system" fn GetVariableByIndex(this: *mut core::ffi::c_void, index: u32) -> Option<ID3D10EffectVariable> {
+            windows_core::imp::proxy_call(this, "ID3D10Effect", "GetVariableByIndex", 9usize, |this| ((**(this as *const *const ID3D10Effect_Vtbl)).GetVariableByIndex)(this, index), |_| None)
+        }
+        unsafe extern "system" fn GetVariableByName(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D10EffectVariable> {
+            windows_core::imp::proxy_call(this, "ID3D10Effect", "GetVariableByName", 10usize, |this| ((**(this as *const *const ID3D10Effect_Vtbl)).GetVariableByName)(this, name), |_| None)
+        }
+        unsafe extern "system" fn GetVariableBySemantic(this: *mut core::ffi::c_void, semantic: windows_core::PCSTR) -> Option<ID3D10EffectVariable> {
+            windows_core::imp::proxy_call(this, "ID3D10Effect", "GetVariableBySemantic", 11usize, |this| ((**(this as *const *const ID3D10Effect_Vtbl)).GetVariableBySemantic)(this, semantic), |_| None)
+        }
+        unsafe extern "system" fn GetTechniqueByIndex(this: *mut core::ffi::c_void, index: u32) -> Option<ID3D10EffectTechnique> {
+            windows_core::imp::proxy_call(this, "ID3D10Effect", "GetTechniqueByIndex", 12usize, |this| ((**(this as *const *const ID3D10Effect_Vtbl)).GetTechniqueByIndex)(this, index), |_| None)
+        }
+        unsafe extern "system" fn GetTechniqueByName(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D10EffectTechnique> {
+            windows_core::imp::proxy_call(this, "ID3D10Effect", "GetTechniqueByName", 13usize, |this| ((**(this as *const *const ID3D10Effect_Vtbl)).GetTechniqueByName)(this, name), |_| None)
+        }
+        unsafe extern "system" fn Optimize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Effect", "Optimize", 14usize, |this| ((**(this as *const *const ID3D10Effect_Vtbl)).Optimize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOptimized(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D10Effect", "IsOptimized", 15usize, |this| ((**(this as *const *const ID3D10Effect_Vtbl)).IsOptimized)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsValid,
+            IsPool,
+            GetDevice,
+            GetDesc,
+            GetConstantBufferByIndex,
+            GetConstantBufferByName,
+            GetVariableByIndex,
+            GetVariableByName,
+            GetVariableBySemantic,
+            GetTechniqueByIndex,
+            GetTechniqueByName,
+            Optimize,
+            IsOptimized,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Effect as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10EffectPool_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AsEffect(this: *mut core::ffi::c_void) -> Option<ID3D10Effect> {
+            windows_core::imp::proxy_call(this, "ID3D10EffectPool", "AsEffect", 3usize, |this| ((**(this as *const *const ID3D10EffectPool_Vtbl)).AsEffect)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AsEffect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10EffectPool as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10GeometryShader_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10GeometryShader as windows_core::Interface>::IID || <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10InfoQueue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMessageCountLimit(this: *mut core::ffi::c_void, messagecountlimit: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "SetMessageCountLimit", 3usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).SetMessageCountLimit)(this, messagecountlimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearStoredMessages(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "ClearStoredMessages", 4usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).ClearStoredMessages)(this), |_| None)
+        }
+        unsafe extern "system" fn GetMessage(this: *mut core::ffi::c_void, messageindex: u64, pmessage: *mut D3D10_MESSAGE, pmessagebytelength: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "GetMessage", 5usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).GetMessage)(this, messageindex, pmessage, pmessagebytelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumMessagesAllowedByStorageFilter(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "GetNumMessagesAllowedByStorageFilter", 6usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).GetNumMessagesAllowedByStorageFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNumMessagesDeniedByStorageFilter(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "GetNumMessagesDeniedByStorageFilter", 7usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).GetNumMessagesDeniedByStorageFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNumStoredMessages(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "GetNumStoredMessages", 8usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).GetNumStoredMessages)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNumStoredMessagesAllowedByRetrievalFilter(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "GetNumStoredMessagesAllowedByRetrievalFilter", 9usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).GetNumStoredMessagesAllowedByRetrievalFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNumMessagesDiscardedByMessageCountLimit(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "GetNumMessagesDiscardedByMessageCountLimit", 10usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).GetNumMessagesDiscardedByMessageCountLimit)(this), |_| None)
+        }
+        unsafe extern "system" fn GetMessageCountLimit(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "GetMessageCountLimit", 11usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).GetMessageCountLimit)(this), |_| None)
+        }
+        unsafe extern "system" fn AddStorageFilterEntries(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "AddStorageFilterEntries", 12usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).AddStorageFilterEntries)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStorageFilter(this: *mut core::ffi::c_void, pfilter: *mut D3D10_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "GetStorageFilter", 13usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).GetStorageFilter)(this, pfilter, pfilterbytelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearStorageFilter(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "ClearStorageFilter", 14usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).ClearStorageFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn PushEmptyStorageFilter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "PushEmptyStorageFilter", 15usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).PushEmptyStorageFilter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushCopyOfStorageFilter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "PushCopyOfStorageFilter", 16usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).PushCopyOfStorageFilter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushStorageFilter(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "PushStorageFilter", 17usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).PushStorageFilter)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn PopStorageFilter(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "PopStorageFilter", 18usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).PopStorageFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn GetStorageFilterStackSize(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "GetStorageFilterStackSize", 19usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).GetStorageFilterStackSize)(this), |_| None)
+        }
+        unsafe extern "system" fn AddRetrievalFilterEntries(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "AddRetrievalFilterEntries", 20usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).AddRetrievalFilterEntries)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRetrievalFilter(this: *mut core::ffi::c_void, pfilter: *mut D3D10_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "GetRetrievalFilter", 21usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).GetRetrievalFilter)(this, pfilter, pfilterbytelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearRetrievalFilter(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "ClearRetrievalFilter", 22usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).ClearRetrievalFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn PushEmptyRetrievalFilter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "PushEmptyRetrievalFilter", 23usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).PushEmptyRetrievalFilter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushCopyOfRetrievalFilter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "PushCopyOfRetrievalFilter", 24usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).PushCopyOfRetrievalFilter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushRetrievalFilter(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "PushRetrievalFilter", 25usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).PushRetrievalFilter)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn PopRetrievalFilter(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "PopRetrievalFilter", 26usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).PopRetrievalFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn GetRetrievalFilterStackSize(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "GetRetrievalFilterStackSize", 27usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).GetRetrievalFilterStackSize)(this), |_| None)
+        }
+        unsafe extern "system" fn AddMessage(this: *mut core::ffi::c_void, category: D3D10_MESSAGE_CATEGORY, severity: D3D10_MESSAGE_SEVERITY, id: D3D10_MESSAGE_ID, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "AddMessage", 28usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).AddMessage)(this, category, severity, id, pdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddApplicationMessage(this: *mut core::ffi::c_void, severity: D3D10_MESSAGE_SEVERITY, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "AddApplicationMessage", 29usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).AddApplicationMessage)(this, severity, pdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBreakOnCategory(this: *mut core::ffi::c_void, category: D3D10_MESSAGE_CATEGORY, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "SetBreakOnCategory", 30usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).SetBreakOnCategory)(this, category, benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBreakOnSeverity(this: *mut core::ffi::c_void, severity: D3D10_MESSAGE_SEVERITY, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "SetBreakOnSeverity", 31usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).SetBreakOnSeverity)(this, severity, benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBreakOnID(this: *mut core::ffi::c_void, id: D3D10_MESSAGE_ID, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "SetBreakOnID", 32usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).SetBreakOnID)(this, id, benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBreakOnCategory(this: *mut core::ffi::c_void, category: D3D10_MESSAGE_CATEGORY) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "GetBreakOnCategory", 33usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).GetBreakOnCategory)(this, category), |_| None)
+        }
+        unsafe extern "system" fn GetBreakOnSeverity(this: *mut core::ffi::c_void, severity: D3D10_MESSAGE_SEVERITY) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "GetBreakOnSeverity", 34usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).GetBreakOnSeverity)(this, severity), |_| None)
+        }
+        unsafe extern "system" fn GetBreakOnID(this: *mut core::ffi::c_void, id: D3D10_MESSAGE_ID) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "GetBreakOnID", 35usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).GetBreakOnID)(this, id), |_| None)
+        }
+        unsafe extern "system" fn SetMuteDebugOutput(this: *mut core::ffi::c_void, bmute: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "SetMuteDebugOutput", 36usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).SetMuteDebugOutput)(this, bmute), |_| None)
+        }
+        unsafe extern "system" fn GetMuteDebugOutput(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D10InfoQueue", "GetMuteDebugOutput", 37usize, |this| ((**(this as *const *const ID3D10InfoQueue_Vtbl)).GetMuteDebugOutput)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMessageCountLimit,
+            ClearStoredMessages,
+            GetMessage,
+            GetNumMessagesAllowedByStorageFilter,
+            GetNumMessagesDeniedByStorageFilter,
+            GetNumStoredMessages,
+            GetNumStoredMessagesAllowedByRetrievalFilter,
+            GetNumMessagesDiscardedByMessageCountLimit,
+            GetMessageCountLimit,
+            AddStorageFilterEntries,
+            GetStorageFilter,
+            ClearStorageFilter,
+            PushEmptyStorageFilter,
+            PushCopyOfStorageFilter,
+            PushStorageFilter,
+            PopStorageFilter,
+            GetStorageFilterStackSize,
+            AddRetrievalFilterEntries,
+            GetRetrievalFilter,
+            ClearRetrievalFilter,
+            PushEmptyRetrievalFilter,
+            PushCopyOfRetrievalFilter,
+            PushRetrievalFilter,
+            PopRetrievalFilter,
+            GetRetrievalFilterStackSize,
+            AddMessage,
+            AddApplicationMessage,
+            SetBreakOnCategory,
+            SetBreakOnSeverity,
+            SetBreakOnID,
+            GetBreakOnCategory,
+            GetBreakOnSeverity,
+            GetBreakOnID,
+            SetMuteDebugOutput,
+            GetMuteDebugOutput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10InfoQueue as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10InputLayout_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10InputLayout as windows_core::Interface>::IID || <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10Multithread_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enter(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Multithread", "Enter", 3usize, |this| ((**(this as *const *const ID3D10Multithread_Vtbl)).Enter)(this), |_| None)
+        }
+        unsafe extern "system" fn Leave(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10Multithread", "Leave", 4usize, |this| ((**(this as *const *const ID3D10Multithread_Vtbl)).Leave)(this), |_| None)
+        }
+        unsafe extern "system" fn SetMultithreadProtected(this: *mut core::ffi::c_void, bmtprotect: super::super::Foundation::BOOL) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D10Multithread", "SetMultithreadProtected", 5usize, |this| ((**(this as *const *const ID3D10Multithread_Vtbl)).SetMultithreadProtected)(this, bmtprotect), |_| None)
+        }
+        unsafe extern "system" fn GetMultithreadProtected(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D10Multithread", "GetMultithreadProtected", 6usize, |this| ((**(this as *const *const ID3D10Multithread_Vtbl)).GetMultithreadProtected)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Enter, Leave, SetMultithreadProtected, GetMultithreadProtected }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Multithread as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10PixelShader_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10PixelShader as windows_core::Interface>::IID || <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10Predicate_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D10Query_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Predicate as windows_core::Interface>::IID || <ID3D10Query_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10Query_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_QUERY_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D10Query", "GetDesc", 11usize, |this| ((**(this as *const *const ID3D10Query_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D10Asynchronous_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Query as windows_core::Interface>::IID || <ID3D10Asynchronous_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10RasterizerState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_RASTERIZER_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D10RasterizerState", "GetDesc", 7usize, |this| ((**(this as *const *const ID3D10RasterizerState_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10RasterizerState as windows_core::Interface>::IID || <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D10RenderTargetView_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_RENDER_TARGET_VIEW_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D10RenderTargetView", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D10RenderTargetView_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D10View_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10RenderTargetView as windows_core::Interface>::IID || <ID3D10View_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10Resource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, rtype: *mut D3D10_RESOURCE_DIMENSION) {
+            windows_core::imp::proxy_call(this, "ID3D10Resource", "GetType", 7usize, |this| ((**(this as *const *const ID3D10Resource_Vtbl)).GetType)(this, rtype), |_| None)
+        }
+        unsafe extern "system" fn SetEvictionPriority(this: *mut core::ffi::c_void, evictionpriority: u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Resource", "SetEvictionPriority", 8usize, |this| ((**(this as *const *const ID3D10Resource_Vtbl)).SetEvictionPriority)(this, evictionpriority), |_| None)
+        }
+        unsafe extern "system" fn GetEvictionPriority(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D10Resource", "GetEvictionPriority", 9usize, |this| ((**(this as *const *const ID3D10Resource_Vtbl)).GetEvictionPriority)(this), |_| None)
+        }
+        Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetType, SetEvictionPriority, GetEvictionPriority }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Resource as windows_core::Interface>::IID || <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10SamplerState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_SAMPLER_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D10SamplerState", "GetDesc", 7usize, |this| ((**(this as *const *const ID3D10SamplerState_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10SamplerState as windows_core::Interface>::IID || <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
+impl windows_core::imp::ProxyVtable for ID3D10ShaderReflection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_SHADER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection", "GetDesc", 3usize, |this| ((**(this as *const *const ID3D10ShaderReflection_Vtbl)).GetDesc)(this, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConstantBufferByIndex(this: *mut core::ffi::c_void, index: u32) -> Option<ID3D10ShaderReflectionConstantBuffer> {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection", "GetConstantBufferByIndex", 4usize, |this| ((**(this as *const *const ID3D10ShaderReflection_Vtbl)).GetConstantBufferByIndex)(this, index), |_| None)
+        }
+        unsafe extern "system" fn GetConstantBufferByName(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D10ShaderReflectionConstantBuffer> {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection", "GetConstantBufferByName", 5usize, |this| ((**(this as *const *const ID3D10ShaderReflection_Vtbl)).GetConstantBufferByName)(this, name), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetResourceBindingDesc(this: *mut core::ffi::c_void, resourceindex: u32, pdesc: *mut D3D10_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection", "GetResourceBindingDesc", 6usize, |this| ((**(this as *const *const ID3D10ShaderReflection_Vtbl)).GetResourceBindingDesc)(this, resourceindex, pdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetInputParameterDesc(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D10_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection", "GetInputParameterDesc", 7usize, |this| ((**(this as *const *const ID3D10ShaderReflection_Vtbl)).GetInputParameterDesc)(this, parameterindex, pdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetOutputParameterDesc(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D10_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection", "GetOutputParameterDesc", 8usize, |this| ((**(this as *const *const ID3D10ShaderReflection_Vtbl)).GetOutputParameterDesc)(this, parameterindex, pdesc), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetDesc: 0,
+            GetConstantBufferByIndex,
+            GetConstantBufferByName,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetResourceBindingDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetResourceBindingDesc: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetInputParameterDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetInputParameterDesc: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetOutputParameterDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetOutputParameterDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10ShaderReflection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
+impl windows_core::imp::ProxyVtable for ID3D10ShaderReflection1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_SHADER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection1", "GetDesc", 3usize, |this| ((**(this as *const *const ID3D10ShaderReflection1_Vtbl)).GetDesc)(this, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConstantBufferByIndex(this: *mut core::ffi::c_void, index: u32) -> Option<ID3D10ShaderReflectionConstantBuffer> {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection1", "GetConstantBufferByIndex", 4usize, |this| ((**(this as *const *const ID3D10ShaderReflection1_Vtbl)).GetConstantBufferByIndex)(this, index), |_| None)
+        }
+        unsafe extern "system" fn GetConstantBufferByName(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D10ShaderReflectionConstantBuffer> {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection1", "GetConstantBufferByName", 5usize, |this| ((**(this as *const *const ID3D10ShaderReflection1_Vtbl)).GetConstantBufferByName)(this, name), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetResourceBindingDesc(this: *mut core::ffi::c_void, resourceindex: u32, pdesc: *mut D3D10_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection1", "GetResourceBindingDesc", 6usize, |this| ((**(this as *const *const ID3D10ShaderReflection1_Vtbl)).GetResourceBindingDesc)(this, resourceindex, pdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetInputParameterDesc(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D10_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection1", "GetInputParameterDesc", 7usize, |this| ((**(this as *const *const ID3D10ShaderReflection1_Vtbl)).GetInputParameterDesc)(this, parameterindex, pdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetOutputParameterDesc(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D10_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection1", "GetOutputParameterDesc", 8usize, |this| ((**(this as *const *const ID3D10ShaderReflection1_Vtbl)).GetOutputParameterDesc)(this, parameterindex, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVariableByName(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D10ShaderReflectionVariable> {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection1", "GetVariableByName", 9usize, |this| ((**(this as *const *const ID3D10ShaderReflection1_Vtbl)).GetVariableByName)(this, name), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetResourceBindingDescByName(this: *mut core::ffi::c_void, name: windows_core::PCSTR, pdesc: *mut D3D10_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection1", "GetResourceBindingDescByName", 10usize, |this| ((**(this as *const *const ID3D10ShaderReflection1_Vtbl)).GetResourceBindingDescByName)(this, name, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMovInstructionCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection1", "GetMovInstructionCount", 11usize, |this| ((**(this as *const *const ID3D10ShaderReflection1_Vtbl)).GetMovInstructionCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMovcInstructionCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection1", "GetMovcInstructionCount", 12usize, |this| ((**(this as *const *const ID3D10ShaderReflection1_Vtbl)).GetMovcInstructionCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversionInstructionCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection1", "GetConversionInstructionCount", 13usize, |this| ((**(this as *const *const ID3D10ShaderReflection1_Vtbl)).GetConversionInstructionCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBitwiseInstructionCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection1", "GetBitwiseInstructionCount", 14usize, |this| ((**(this as *const *const ID3D10ShaderReflection1_Vtbl)).GetBitwiseInstructionCount)(this, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetGSInputPrimitive(this: *mut core::ffi::c_void, pprim: *mut super::Direct3D::D3D_PRIMITIVE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection1", "GetGSInputPrimitive", 15usize, |this| ((**(this as *const *const ID3D10ShaderReflection1_Vtbl)).GetGSInputPrimitive)(this, pprim), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLevel9Shader(this: *mut core::ffi::c_void, pblevel9shader: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection1", "IsLevel9Shader", 16usize, |this| ((**(this as *const *const ID3D10ShaderReflection1_Vtbl)).IsLevel9Shader)(this, pblevel9shader), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSampleFrequencyShader(this: *mut core::ffi::c_void, pbsamplefrequency: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderReflection1", "IsSampleFrequencyShader", 17usize, |this| ((**(this as *const *const ID3D10ShaderReflection1_Vtbl)).IsSampleFrequencyShader)(this, pbsamplefrequency), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetDesc: 0,
+            GetConstantBufferByIndex,
+            GetConstantBufferByName,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetResourceBindingDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetResourceBindingDesc: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetInputParameterDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetInputParameterDesc: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetOutputParameterDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetOutputParameterDesc: 0,
+            GetVariableByName,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetResourceBindingDescByName,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetResourceBindingDescByName: 0,
+            GetMovInstructionCount,
+            GetMovcInstructionCount,
+            GetConversionInstructionCount,
+            GetBitwiseInstructionCount,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetGSInputPrimitive,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetGSInputPrimitive: 0,
+            IsLevel9Shader,
+            IsSampleFrequencyShader,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10ShaderReflection1 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D10ShaderResourceView_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_SHADER_RESOURCE_VIEW_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderResourceView", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D10ShaderResourceView_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D10View_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common")))]
+            GetDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10ShaderResourceView as windows_core::Interface>::IID || <ID3D10View_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D10ShaderResourceView1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn GetDesc1(this: *mut core::ffi::c_void, pdesc: *mut D3D10_SHADER_RESOURCE_VIEW_DESC1) {
+            windows_core::imp::proxy_call(this, "ID3D10ShaderResourceView1", "GetDesc1", 9usize, |this| ((**(this as *const *const ID3D10ShaderResourceView1_Vtbl)).GetDesc1)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D10ShaderResourceView_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc1,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common")))]
+            GetDesc1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10ShaderResourceView1 as windows_core::Interface>::IID || <ID3D10ShaderResourceView_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10StateBlock_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Capture(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10StateBlock", "Capture", 3usize, |this| ((**(this as *const *const ID3D10StateBlock_Vtbl)).Capture)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Apply(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10StateBlock", "Apply", 4usize, |this| ((**(this as *const *const ID3D10StateBlock_Vtbl)).Apply)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseAllDeviceObjects(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10StateBlock", "ReleaseAllDeviceObjects", 5usize, |this| ((**(this as *const *const ID3D10StateBlock_Vtbl)).ReleaseAllDeviceObjects)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10StateBlock", "GetDevice", 6usize, |this| ((**(this as *const *const ID3D10StateBlock_Vtbl)).GetDevice)(this, ppdevice), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Capture, Apply, ReleaseAllDeviceObjects, GetDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10StateBlock as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10SwitchToRef_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetUseRef(this: *mut core::ffi::c_void, useref: super::super::Foundation::BOOL) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D10SwitchToRef", "SetUseRef", 3usize, |this| ((**(this as *const *const ID3D10SwitchToRef_Vtbl)).SetUseRef)(this, useref), |_| None)
+        }
+        unsafe extern "system" fn GetUseRef(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D10SwitchToRef", "GetUseRef", 4usize, |this| ((**(this as *const *const ID3D10SwitchToRef_Vtbl)).GetUseRef)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetUseRef, GetUseRef }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10SwitchToRef as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D10Texture1D_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Map(this: *mut core::ffi::c_void, subresource: u32, maptype: D3D10_MAP, mapflags: u32, ppdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Texture1D", "Map", 10usize, |this| ((**(this as *const *const ID3D10Texture1D_Vtbl)).Map)(this, subresource, maptype, mapflags, ppdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unmap(this: *mut core::ffi::c_void, subresource: u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Texture1D", "Unmap", 11usize, |this| ((**(this as *const *const ID3D10Texture1D_Vtbl)).Unmap)(this, subresource), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_TEXTURE1D_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D10Texture1D", "GetDesc", 12usize, |this| ((**(this as *const *const ID3D10Texture1D_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D10Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Map,
+            Unmap,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Texture1D as windows_core::Interface>::IID || <ID3D10Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D10Texture2D_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Map(this: *mut core::ffi::c_void, subresource: u32, maptype: D3D10_MAP, mapflags: u32, pmappedtex2d: *mut D3D10_MAPPED_TEXTURE2D) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Texture2D", "Map", 10usize, |this| ((**(this as *const *const ID3D10Texture2D_Vtbl)).Map)(this, subresource, maptype, mapflags, pmappedtex2d), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unmap(this: *mut core::ffi::c_void, subresource: u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Texture2D", "Unmap", 11usize, |this| ((**(this as *const *const ID3D10Texture2D_Vtbl)).Unmap)(this, subresource), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_TEXTURE2D_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D10Texture2D", "GetDesc", 12usize, |this| ((**(this as *const *const ID3D10Texture2D_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D10Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Map,
+            Unmap,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Texture2D as windows_core::Interface>::IID || <ID3D10Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D10Texture3D_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Map(this: *mut core::ffi::c_void, subresource: u32, maptype: D3D10_MAP, mapflags: u32, pmappedtex3d: *mut D3D10_MAPPED_TEXTURE3D) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D10Texture3D", "Map", 10usize, |this| ((**(this as *const *const ID3D10Texture3D_Vtbl)).Map)(this, subresource, maptype, mapflags, pmappedtex3d), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unmap(this: *mut core::ffi::c_void, subresource: u32) {
+            windows_core::imp::proxy_call(this, "ID3D10Texture3D", "Unmap", 11usize, |this| ((**(this as *const *const ID3D10Texture3D_Vtbl)).Unmap)(this, subresource), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D10_TEXTURE3D_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D10Texture3D", "GetDesc", 12usize, |this| ((**(this as *const *const ID3D10Texture3D_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D10Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Map,
+            Unmap,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Texture3D as windows_core::Interface>::IID || <ID3D10Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10VertexShader_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10VertexShader as windows_core::Interface>::IID || <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D10View_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetResource(this: *mut core::ffi::c_void, ppresource: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D10View", "GetResource", 7usize, |this| ((**(this as *const *const ID3D10View_Vtbl)).GetResource)(this, ppresource), |_| None)
+        }
+        Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetResource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10View as windows_core::Interface>::IID || <ID3D10DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
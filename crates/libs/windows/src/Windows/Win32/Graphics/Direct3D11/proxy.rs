@@ -0,0 +1,3119 @@
+impl windows_core::imp::ProxyVtable for ID3D11Asynchronous_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDataSize(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11Asynchronous", "GetDataSize", 7usize, |this| ((**(this as *const *const ID3D11Asynchronous_Vtbl)).GetDataSize)(this), |_| None)
+        }
+        Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDataSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Asynchronous as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11AuthenticatedChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCertificateSize(this: *mut core::ffi::c_void, pcertificatesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11AuthenticatedChannel", "GetCertificateSize", 7usize, |this| ((**(this as *const *const ID3D11AuthenticatedChannel_Vtbl)).GetCertificateSize)(this, pcertificatesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificate(this: *mut core::ffi::c_void, certificatesize: u32, pcertificate: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11AuthenticatedChannel", "GetCertificate", 8usize, |this| ((**(this as *const *const ID3D11AuthenticatedChannel_Vtbl)).GetCertificate)(this, certificatesize, pcertificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChannelHandle(this: *mut core::ffi::c_void, pchannelhandle: *mut super::super::Foundation::HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D11AuthenticatedChannel", "GetChannelHandle", 9usize, |this| ((**(this as *const *const ID3D11AuthenticatedChannel_Vtbl)).GetChannelHandle)(this, pchannelhandle), |_| None)
+        }
+        Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCertificateSize, GetCertificate, GetChannelHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11AuthenticatedChannel as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11BlendState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_BLEND_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11BlendState", "GetDesc", 7usize, |this| ((**(this as *const *const ID3D11BlendState_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11BlendState as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11BlendState1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc1(this: *mut core::ffi::c_void, pdesc: *mut D3D11_BLEND_DESC1) {
+            windows_core::imp::proxy_call(this, "ID3D11BlendState1", "GetDesc1", 8usize, |this| ((**(this as *const *const ID3D11BlendState1_Vtbl)).GetDesc1)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D11BlendState_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11BlendState1 as windows_core::Interface>::IID || <ID3D11BlendState_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11Buffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_BUFFER_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11Buffer", "GetDesc", 10usize, |this| ((**(this as *const *const ID3D11Buffer_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D11Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Buffer as windows_core::Interface>::IID || <ID3D11Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11ClassInstance_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClassLinkage(this: *mut core::ffi::c_void, pplinkage: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11ClassInstance", "GetClassLinkage", 7usize, |this| ((**(this as *const *const ID3D11ClassInstance_Vtbl)).GetClassLinkage)(this, pplinkage), |_| None)
+        }
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_CLASS_INSTANCE_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11ClassInstance", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D11ClassInstance_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        unsafe extern "system" fn GetInstanceName(this: *mut core::ffi::c_void, pinstancename: windows_core::PSTR, pbufferlength: *mut usize) {
+            windows_core::imp::proxy_call(this, "ID3D11ClassInstance", "GetInstanceName", 9usize, |this| ((**(this as *const *const ID3D11ClassInstance_Vtbl)).GetInstanceName)(this, pinstancename, pbufferlength), |_| None)
+        }
+        unsafe extern "system" fn GetTypeName(this: *mut core::ffi::c_void, ptypename: windows_core::PSTR, pbufferlength: *mut usize) {
+            windows_core::imp::proxy_call(this, "ID3D11ClassInstance", "GetTypeName", 10usize, |this| ((**(this as *const *const ID3D11ClassInstance_Vtbl)).GetTypeName)(this, ptypename, pbufferlength), |_| None)
+        }
+        Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetClassLinkage, GetDesc, GetInstanceName, GetTypeName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11ClassInstance as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11ClassLinkage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClassInstance(this: *mut core::ffi::c_void, pclassinstancename: windows_core::PCSTR, instanceindex: u32, ppinstance: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ClassLinkage", "GetClassInstance", 7usize, |this| ((**(this as *const *const ID3D11ClassLinkage_Vtbl)).GetClassInstance)(this, pclassinstancename, instanceindex, ppinstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateClassInstance(this: *mut core::ffi::c_void, pclasstypename: windows_core::PCSTR, constantbufferoffset: u32, constantvectoroffset: u32, textureoffset: u32, sampleroffset: u32, ppinstance: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ClassLinkage", "CreateClassInstance", 8usize, |this| ((**(this as *const *const ID3D11ClassLinkage_Vtbl)).CreateClassInstance)(this, pclasstypename, constantbufferoffset, constantvectoroffset, textureoffset, sampleroffset, ppinstance), |result| Some(*result))
+        }
+        Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetClassInstance, CreateClassInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11ClassLinkage as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11CommandList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetContextFlags(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11CommandList", "GetContextFlags", 7usize, |this| ((**(this as *const *const ID3D11CommandList_Vtbl)).GetContextFlags)(this), |_| None)
+        }
+        Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetContextFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11CommandList as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11ComputeShader_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11ComputeShader as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11Counter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_COUNTER_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11Counter", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D11Counter_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D11Asynchronous_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Counter as windows_core::Interface>::IID || <ID3D11Asynchronous_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11CryptoSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCryptoType(this: *mut core::ffi::c_void, pcryptotype: *mut windows_core::GUID) {
+            windows_core::imp::proxy_call(this, "ID3D11CryptoSession", "GetCryptoType", 7usize, |this| ((**(this as *const *const ID3D11CryptoSession_Vtbl)).GetCryptoType)(this, pcryptotype), |_| None)
+        }
+        unsafe extern "system" fn GetDecoderProfile(this: *mut core::ffi::c_void, pdecoderprofile: *mut windows_core::GUID) {
+            windows_core::imp::proxy_call(this, "ID3D11CryptoSession", "GetDecoderProfile", 8usize, |this| ((**(this as *const *const ID3D11CryptoSession_Vtbl)).GetDecoderProfile)(this, pdecoderprofile), |_| None)
+        }
+        unsafe extern "system" fn GetCertificateSize(this: *mut core::ffi::c_void, pcertificatesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11CryptoSession", "GetCertificateSize", 9usize, |this| ((**(this as *const *const ID3D11CryptoSession_Vtbl)).GetCertificateSize)(this, pcertificatesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificate(this: *mut core::ffi::c_void, certificatesize: u32, pcertificate: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11CryptoSession", "GetCertificate", 10usize, |this| ((**(this as *const *const ID3D11CryptoSession_Vtbl)).GetCertificate)(this, certificatesize, pcertificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCryptoSessionHandle(this: *mut core::ffi::c_void, pcryptosessionhandle: *mut super::super::Foundation::HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D11CryptoSession", "GetCryptoSessionHandle", 11usize, |this| ((**(this as *const *const ID3D11CryptoSession_Vtbl)).GetCryptoSessionHandle)(this, pcryptosessionhandle), |_| None)
+        }
+        Self {
+            base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCryptoType,
+            GetDecoderProfile,
+            GetCertificateSize,
+            GetCertificate,
+            GetCryptoSessionHandle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11CryptoSession as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi")]
+impl windows_core::imp::ProxyVtable for ID3D11Debug_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFeatureMask(this: *mut core::ffi::c_void, mask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Debug", "SetFeatureMask", 3usize, |this| ((**(this as *const *const ID3D11Debug_Vtbl)).SetFeatureMask)(this, mask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeatureMask(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11Debug", "GetFeatureMask", 4usize, |this| ((**(this as *const *const ID3D11Debug_Vtbl)).GetFeatureMask)(this), |_| None)
+        }
+        unsafe extern "system" fn SetPresentPerRenderOpDelay(this: *mut core::ffi::c_void, milliseconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Debug", "SetPresentPerRenderOpDelay", 5usize, |this| ((**(this as *const *const ID3D11Debug_Vtbl)).SetPresentPerRenderOpDelay)(this, milliseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresentPerRenderOpDelay(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11Debug", "GetPresentPerRenderOpDelay", 6usize, |this| ((**(this as *const *const ID3D11Debug_Vtbl)).GetPresentPerRenderOpDelay)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn SetSwapChain(this: *mut core::ffi::c_void, pswapchain: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Debug", "SetSwapChain", 7usize, |this| ((**(this as *const *const ID3D11Debug_Vtbl)).SetSwapChain)(this, pswapchain), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn GetSwapChain(this: *mut core::ffi::c_void, ppswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Debug", "GetSwapChain", 8usize, |this| ((**(this as *const *const ID3D11Debug_Vtbl)).GetSwapChain)(this, ppswapchain), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateContext(this: *mut core::ffi::c_void, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Debug", "ValidateContext", 9usize, |this| ((**(this as *const *const ID3D11Debug_Vtbl)).ValidateContext)(this, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportLiveDeviceObjects(this: *mut core::ffi::c_void, flags: D3D11_RLDO_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Debug", "ReportLiveDeviceObjects", 10usize, |this| ((**(this as *const *const ID3D11Debug_Vtbl)).ReportLiveDeviceObjects)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateContextForDispatch(this: *mut core::ffi::c_void, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Debug", "ValidateContextForDispatch", 11usize, |this| ((**(this as *const *const ID3D11Debug_Vtbl)).ValidateContextForDispatch)(this, pcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetFeatureMask,
+            GetFeatureMask,
+            SetPresentPerRenderOpDelay,
+            GetPresentPerRenderOpDelay,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            SetSwapChain,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            SetSwapChain: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            GetSwapChain,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            GetSwapChain: 0,
+            ValidateContext,
+            ReportLiveDeviceObjects,
+            ValidateContextForDispatch,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Debug as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11DepthStencilState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_DEPTH_STENCIL_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11DepthStencilState", "GetDesc", 7usize, |this| ((**(this as *const *const ID3D11DepthStencilState_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11DepthStencilState as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11DepthStencilView_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_DEPTH_STENCIL_VIEW_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11DepthStencilView", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D11DepthStencilView_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D11View_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11DepthStencilView as windows_core::Interface>::IID || <ID3D11View_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D11Device_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateBuffer(this: *mut core::ffi::c_void, pdesc: *const D3D11_BUFFER_DESC, pinitialdata: *const D3D11_SUBRESOURCE_DATA, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateBuffer", 3usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateBuffer)(this, pdesc, pinitialdata, ppbuffer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateTexture1D(this: *mut core::ffi::c_void, pdesc: *const D3D11_TEXTURE1D_DESC, pinitialdata: *const D3D11_SUBRESOURCE_DATA, pptexture1d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateTexture1D", 4usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateTexture1D)(this, pdesc, pinitialdata, pptexture1d), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateTexture2D(this: *mut core::ffi::c_void, pdesc: *const D3D11_TEXTURE2D_DESC, pinitialdata: *const D3D11_SUBRESOURCE_DATA, pptexture2d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateTexture2D", 5usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateTexture2D)(this, pdesc, pinitialdata, pptexture2d), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateTexture3D(this: *mut core::ffi::c_void, pdesc: *const D3D11_TEXTURE3D_DESC, pinitialdata: *const D3D11_SUBRESOURCE_DATA, pptexture3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateTexture3D", 6usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateTexture3D)(this, pdesc, pinitialdata, pptexture3d), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateShaderResourceView(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D11_SHADER_RESOURCE_VIEW_DESC, ppsrview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateShaderResourceView", 7usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateShaderResourceView)(this, presource, pdesc, ppsrview), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateUnorderedAccessView(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D11_UNORDERED_ACCESS_VIEW_DESC, ppuaview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateUnorderedAccessView", 8usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateUnorderedAccessView)(this, presource, pdesc, ppuaview), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateRenderTargetView(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D11_RENDER_TARGET_VIEW_DESC, pprtview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateRenderTargetView", 9usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateRenderTargetView)(this, presource, pdesc, pprtview), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateDepthStencilView(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D11_DEPTH_STENCIL_VIEW_DESC, ppdepthstencilview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateDepthStencilView", 10usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateDepthStencilView)(this, presource, pdesc, ppdepthstencilview), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateInputLayout(this: *mut core::ffi::c_void, pinputelementdescs: *const D3D11_INPUT_ELEMENT_DESC, numelements: u32, pshaderbytecodewithinputsignature: *const core::ffi::c_void, bytecodelength: usize, ppinputlayout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateInputLayout", 11usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateInputLayout)(this, pinputelementdescs, numelements, pshaderbytecodewithinputsignature, bytecodelength, ppinputlayout), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVertexShader(this: *mut core::ffi::c_void, pshaderbytecode: *const core::ffi::c_void, bytecodelength: usize, pclasslinkage: *mut core::ffi::c_void, ppvertexshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateVertexShader", 12usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateVertexShader)(this, pshaderbytecode, bytecodelength, pclasslinkage, ppvertexshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGeometryShader(this: *mut core::ffi::c_void, pshaderbytecode: *const core::ffi::c_void, bytecodelength: usize, pclasslinkage: *mut core::ffi::c_void, ppgeometryshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateGeometryShader", 13usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateGeometryShader)(this, pshaderbytecode, bytecodelength, pclasslinkage, ppgeometryshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGeometryShaderWithStreamOutput(this: *mut core::ffi::c_void, pshaderbytecode: *const core::ffi::c_void, bytecodelength: usize, psodeclaration: *const D3D11_SO_DECLARATION_ENTRY, numentries: u32, pbufferstrides: *const u32, numstrides: u32, rasterizedstream: u32, pclasslinkage: *mut core::ffi::c_void, ppgeometryshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateGeometryShaderWithStreamOutput", 14usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateGeometryShaderWithStreamOutput)(this, pshaderbytecode, bytecodelength, psodeclaration, numentries, pbufferstrides, numstrides, rasterizedstream, pclasslinkage, ppgeometryshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePixelShader(this: *mut core::ffi::c_void, pshaderbytecode: *const core::ffi::c_void, bytecodelength: usize, pclasslinkage: *mut core::ffi::c_void, pppixelshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreatePixelShader", 15usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreatePixelShader)(this, pshaderbytecode, bytecodelength, pclasslinkage, pppixelshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateHullShader(this: *mut core::ffi::c_void, pshaderbytecode: *const core::ffi::c_void, bytecodelength: usize, pclasslinkage: *mut core::ffi::c_void, pphullshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateHullShader", 16usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateHullShader)(this, pshaderbytecode, bytecodelength, pclasslinkage, pphullshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDomainShader(this: *mut core::ffi::c_void, pshaderbytecode: *const core::ffi::c_void, bytecodelength: usize, pclasslinkage: *mut core::ffi::c_void, ppdomainshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateDomainShader", 17usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateDomainShader)(this, pshaderbytecode, bytecodelength, pclasslinkage, ppdomainshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateComputeShader(this: *mut core::ffi::c_void, pshaderbytecode: *const core::ffi::c_void, bytecodelength: usize, pclasslinkage: *mut core::ffi::c_void, ppcomputeshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateComputeShader", 18usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateComputeShader)(this, pshaderbytecode, bytecodelength, pclasslinkage, ppcomputeshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateClassLinkage(this: *mut core::ffi::c_void, pplinkage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateClassLinkage", 19usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateClassLinkage)(this, pplinkage), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBlendState(this: *mut core::ffi::c_void, pblendstatedesc: *const D3D11_BLEND_DESC, ppblendstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateBlendState", 20usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateBlendState)(this, pblendstatedesc, ppblendstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDepthStencilState(this: *mut core::ffi::c_void, pdepthstencildesc: *const D3D11_DEPTH_STENCIL_DESC, ppdepthstencilstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateDepthStencilState", 21usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateDepthStencilState)(this, pdepthstencildesc, ppdepthstencilstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRasterizerState(this: *mut core::ffi::c_void, prasterizerdesc: *const D3D11_RASTERIZER_DESC, pprasterizerstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateRasterizerState", 22usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateRasterizerState)(this, prasterizerdesc, pprasterizerstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSamplerState(this: *mut core::ffi::c_void, psamplerdesc: *const D3D11_SAMPLER_DESC, ppsamplerstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateSamplerState", 23usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateSamplerState)(this, psamplerdesc, ppsamplerstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateQuery(this: *mut core::ffi::c_void, pquerydesc: *const D3D11_QUERY_DESC, ppquery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateQuery", 24usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateQuery)(this, pquerydesc, ppquery), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePredicate(this: *mut core::ffi::c_void, ppredicatedesc: *const D3D11_QUERY_DESC, pppredicate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreatePredicate", 25usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreatePredicate)(this, ppredicatedesc, pppredicate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCounter(this: *mut core::ffi::c_void, pcounterdesc: *const D3D11_COUNTER_DESC, ppcounter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateCounter", 26usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateCounter)(this, pcounterdesc, ppcounter), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDeferredContext(this: *mut core::ffi::c_void, contextflags: u32, ppdeferredcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CreateDeferredContext", 27usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CreateDeferredContext)(this, contextflags, ppdeferredcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenSharedResource(this: *mut core::ffi::c_void, hresource: super::super::Foundation::HANDLE, returnedinterface: *const windows_core::GUID, ppresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "OpenSharedResource", 28usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).OpenSharedResource)(this, hresource, returnedinterface, ppresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CheckFormatSupport(this: *mut core::ffi::c_void, format: super::Dxgi::Common::DXGI_FORMAT, pformatsupport: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CheckFormatSupport", 29usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CheckFormatSupport)(this, format, pformatsupport), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CheckMultisampleQualityLevels(this: *mut core::ffi::c_void, format: super::Dxgi::Common::DXGI_FORMAT, samplecount: u32, pnumqualitylevels: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CheckMultisampleQualityLevels", 30usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CheckMultisampleQualityLevels)(this, format, samplecount, pnumqualitylevels), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckCounterInfo(this: *mut core::ffi::c_void, pcounterinfo: *mut D3D11_COUNTER_INFO) {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CheckCounterInfo", 31usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CheckCounterInfo)(this, pcounterinfo), |_| None)
+        }
+        unsafe extern "system" fn CheckCounter(this: *mut core::ffi::c_void, pdesc: *const D3D11_COUNTER_DESC, ptype: *mut D3D11_COUNTER_TYPE, pactivecounters: *mut u32, szname: windows_core::PSTR, pnamelength: *mut u32, szunits: windows_core::PSTR, punitslength: *mut u32, szdescription: windows_core::PSTR, pdescriptionlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CheckCounter", 32usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CheckCounter)(this, pdesc, ptype, pactivecounters, szname, pnamelength, szunits, punitslength, szdescription, pdescriptionlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckFeatureSupport(this: *mut core::ffi::c_void, feature: D3D11_FEATURE, pfeaturesupportdata: *mut core::ffi::c_void, featuresupportdatasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "CheckFeatureSupport", 33usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).CheckFeatureSupport)(this, feature, pfeaturesupportdata, featuresupportdatasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivateData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdatasize: *mut u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "GetPrivateData", 34usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).GetPrivateData)(this, guid, pdatasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "SetPrivateData", 35usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).SetPrivateData)(this, guid, datasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateDataInterface(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "SetPrivateDataInterface", 36usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).SetPrivateDataInterface)(this, guid, pdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetFeatureLevel(this: *mut core::ffi::c_void) -> super::Direct3D::D3D_FEATURE_LEVEL {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "GetFeatureLevel", 37usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).GetFeatureLevel)(this), |_| None)
+        }
+        unsafe extern "system" fn GetCreationFlags(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "GetCreationFlags", 38usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).GetCreationFlags)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDeviceRemovedReason(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "GetDeviceRemovedReason", 39usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).GetDeviceRemovedReason)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImmediateContext(this: *mut core::ffi::c_void, ppimmediatecontext: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "GetImmediateContext", 40usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).GetImmediateContext)(this, ppimmediatecontext), |_| None)
+        }
+        unsafe extern "system" fn SetExceptionMode(this: *mut core::ffi::c_void, raiseflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "SetExceptionMode", 41usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).SetExceptionMode)(this, raiseflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExceptionMode(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11Device", "GetExceptionMode", 42usize, |this| ((**(this as *const *const ID3D11Device_Vtbl)).GetExceptionMode)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateBuffer,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateTexture1D,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateTexture1D: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateTexture2D,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateTexture2D: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateTexture3D,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateTexture3D: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateShaderResourceView,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateShaderResourceView: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateUnorderedAccessView,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateUnorderedAccessView: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateRenderTargetView,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateRenderTargetView: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateDepthStencilView,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateDepthStencilView: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateInputLayout,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateInputLayout: 0,
+            CreateVertexShader,
+            CreateGeometryShader,
+            CreateGeometryShaderWithStreamOutput,
+            CreatePixelShader,
+            CreateHullShader,
+            CreateDomainShader,
+            CreateComputeShader,
+            CreateClassLinkage,
+            CreateBlendState,
+            CreateDepthStencilState,
+            CreateRasterizerState,
+            CreateSamplerState,
+            CreateQuery,
+            CreatePredicate,
+            CreateCounter,
+            CreateDeferredContext,
+            OpenSharedResource,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CheckFormatSupport,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CheckFormatSupport: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CheckMultisampleQualityLevels,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CheckMultisampleQualityLevels: 0,
+            CheckCounterInfo,
+            CheckCounter,
+            CheckFeatureSupport,
+            GetPrivateData,
+            SetPrivateData,
+            SetPrivateDataInterface,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetFeatureLevel,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetFeatureLevel: 0,
+            GetCreationFlags,
+            GetDeviceRemovedReason,
+            GetImmediateContext,
+            SetExceptionMode,
+            GetExceptionMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Device as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D11Device1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetImmediateContext1(this: *mut core::ffi::c_void, ppimmediatecontext: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11Device1", "GetImmediateContext1", 43usize, |this| ((**(this as *const *const ID3D11Device1_Vtbl)).GetImmediateContext1)(this, ppimmediatecontext), |_| None)
+        }
+        unsafe extern "system" fn CreateDeferredContext1(this: *mut core::ffi::c_void, contextflags: u32, ppdeferredcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device1", "CreateDeferredContext1", 44usize, |this| ((**(this as *const *const ID3D11Device1_Vtbl)).CreateDeferredContext1)(this, contextflags, ppdeferredcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBlendState1(this: *mut core::ffi::c_void, pblendstatedesc: *const D3D11_BLEND_DESC1, ppblendstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device1", "CreateBlendState1", 45usize, |this| ((**(this as *const *const ID3D11Device1_Vtbl)).CreateBlendState1)(this, pblendstatedesc, ppblendstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRasterizerState1(this: *mut core::ffi::c_void, prasterizerdesc: *const D3D11_RASTERIZER_DESC1, pprasterizerstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device1", "CreateRasterizerState1", 46usize, |this| ((**(this as *const *const ID3D11Device1_Vtbl)).CreateRasterizerState1)(this, prasterizerdesc, pprasterizerstate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn CreateDeviceContextState(this: *mut core::ffi::c_void, flags: u32, pfeaturelevels: *const super::Direct3D::D3D_FEATURE_LEVEL, featurelevels: u32, sdkversion: u32, emulatedinterface: *const windows_core::GUID, pchosenfeaturelevel: *mut super::Direct3D::D3D_FEATURE_LEVEL, ppcontextstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device1", "CreateDeviceContextState", 47usize, |this| ((**(this as *const *const ID3D11Device1_Vtbl)).CreateDeviceContextState)(this, flags, pfeaturelevels, featurelevels, sdkversion, emulatedinterface, pchosenfeaturelevel, ppcontextstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenSharedResource1(this: *mut core::ffi::c_void, hresource: super::super::Foundation::HANDLE, returnedinterface: *const windows_core::GUID, ppresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device1", "OpenSharedResource1", 48usize, |this| ((**(this as *const *const ID3D11Device1_Vtbl)).OpenSharedResource1)(this, hresource, returnedinterface, ppresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenSharedResourceByName(this: *mut core::ffi::c_void, lpname: windows_core::PCWSTR, dwdesiredaccess: u32, returnedinterface: *const windows_core::GUID, ppresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device1", "OpenSharedResourceByName", 49usize, |this| ((**(this as *const *const ID3D11Device1_Vtbl)).OpenSharedResourceByName)(this, lpname, dwdesiredaccess, returnedinterface, ppresource), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D11Device_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetImmediateContext1,
+            CreateDeferredContext1,
+            CreateBlendState1,
+            CreateRasterizerState1,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            CreateDeviceContextState,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            CreateDeviceContextState: 0,
+            OpenSharedResource1,
+            OpenSharedResourceByName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Device1 as windows_core::Interface>::IID || <ID3D11Device_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D11Device2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetImmediateContext2(this: *mut core::ffi::c_void, ppimmediatecontext: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11Device2", "GetImmediateContext2", 50usize, |this| ((**(this as *const *const ID3D11Device2_Vtbl)).GetImmediateContext2)(this, ppimmediatecontext), |_| None)
+        }
+        unsafe extern "system" fn CreateDeferredContext2(this: *mut core::ffi::c_void, contextflags: u32, ppdeferredcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device2", "CreateDeferredContext2", 51usize, |this| ((**(this as *const *const ID3D11Device2_Vtbl)).CreateDeferredContext2)(this, contextflags, ppdeferredcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResourceTiling(this: *mut core::ffi::c_void, ptiledresource: *mut core::ffi::c_void, pnumtilesforentireresource: *mut u32, ppackedmipdesc: *mut D3D11_PACKED_MIP_DESC, pstandardtileshapefornonpackedmips: *mut D3D11_TILE_SHAPE, pnumsubresourcetilings: *mut u32, firstsubresourcetilingtoget: u32, psubresourcetilingsfornonpackedmips: *mut D3D11_SUBRESOURCE_TILING) {
+            windows_core::imp::proxy_call(this, "ID3D11Device2", "GetResourceTiling", 52usize, |this| ((**(this as *const *const ID3D11Device2_Vtbl)).GetResourceTiling)(this, ptiledresource, pnumtilesforentireresource, ppackedmipdesc, pstandardtileshapefornonpackedmips, pnumsubresourcetilings, firstsubresourcetilingtoget, psubresourcetilingsfornonpackedmips), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CheckMultisampleQualityLevels1(this: *mut core::ffi::c_void, format: super::Dxgi::Common::DXGI_FORMAT, samplecount: u32, flags: u32, pnumqualitylevels: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device2", "CheckMultisampleQualityLevels1", 53usize, |this| ((**(this as *const *const ID3D11Device2_Vtbl)).CheckMultisampleQualityLevels1)(this, format, samplecount, flags, pnumqualitylevels), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D11Device1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetImmediateContext2,
+            CreateDeferredContext2,
+            GetResourceTiling,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CheckMultisampleQualityLevels1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CheckMultisampleQualityLevels1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Device2 as windows_core::Interface>::IID || <ID3D11Device1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D11Device3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateTexture2D1(this: *mut core::ffi::c_void, pdesc1: *const D3D11_TEXTURE2D_DESC1, pinitialdata: *const D3D11_SUBRESOURCE_DATA, pptexture2d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device3", "CreateTexture2D1", 54usize, |this| ((**(this as *const *const ID3D11Device3_Vtbl)).CreateTexture2D1)(this, pdesc1, pinitialdata, pptexture2d), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateTexture3D1(this: *mut core::ffi::c_void, pdesc1: *const D3D11_TEXTURE3D_DESC1, pinitialdata: *const D3D11_SUBRESOURCE_DATA, pptexture3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device3", "CreateTexture3D1", 55usize, |this| ((**(this as *const *const ID3D11Device3_Vtbl)).CreateTexture3D1)(this, pdesc1, pinitialdata, pptexture3d), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRasterizerState2(this: *mut core::ffi::c_void, prasterizerdesc: *const D3D11_RASTERIZER_DESC2, pprasterizerstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device3", "CreateRasterizerState2", 56usize, |this| ((**(this as *const *const ID3D11Device3_Vtbl)).CreateRasterizerState2)(this, prasterizerdesc, pprasterizerstate), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateShaderResourceView1(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc1: *const D3D11_SHADER_RESOURCE_VIEW_DESC1, ppsrview1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device3", "CreateShaderResourceView1", 57usize, |this| ((**(this as *const *const ID3D11Device3_Vtbl)).CreateShaderResourceView1)(this, presource, pdesc1, ppsrview1), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateUnorderedAccessView1(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc1: *const D3D11_UNORDERED_ACCESS_VIEW_DESC1, ppuaview1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device3", "CreateUnorderedAccessView1", 58usize, |this| ((**(this as *const *const ID3D11Device3_Vtbl)).CreateUnorderedAccessView1)(this, presource, pdesc1, ppuaview1), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateRenderTargetView1(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc1: *const D3D11_RENDER_TARGET_VIEW_DESC1, pprtview1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device3", "CreateRenderTargetView1", 59usize, |this| ((**(this as *const *const ID3D11Device3_Vtbl)).CreateRenderTargetView1)(this, presource, pdesc1, pprtview1), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateQuery1(this: *mut core::ffi::c_void, pquerydesc1: *const D3D11_QUERY_DESC1, ppquery1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device3", "CreateQuery1", 60usize, |this| ((**(this as *const *const ID3D11Device3_Vtbl)).CreateQuery1)(this, pquerydesc1, ppquery1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImmediateContext3(this: *mut core::ffi::c_void, ppimmediatecontext: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11Device3", "GetImmediateContext3", 61usize, |this| ((**(this as *const *const ID3D11Device3_Vtbl)).GetImmediateContext3)(this, ppimmediatecontext), |_| None)
+        }
+        unsafe extern "system" fn CreateDeferredContext3(this: *mut core::ffi::c_void, contextflags: u32, ppdeferredcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device3", "CreateDeferredContext3", 62usize, |this| ((**(this as *const *const ID3D11Device3_Vtbl)).CreateDeferredContext3)(this, contextflags, ppdeferredcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteToSubresource(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, pdstbox: *const D3D11_BOX, psrcdata: *const core::ffi::c_void, srcrowpitch: u32, srcdepthpitch: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11Device3", "WriteToSubresource", 63usize, |this| ((**(this as *const *const ID3D11Device3_Vtbl)).WriteToSubresource)(this, pdstresource, dstsubresource, pdstbox, psrcdata, srcrowpitch, srcdepthpitch), |_| None)
+        }
+        unsafe extern "system" fn ReadFromSubresource(this: *mut core::ffi::c_void, pdstdata: *mut core::ffi::c_void, dstrowpitch: u32, dstdepthpitch: u32, psrcresource: *mut core::ffi::c_void, srcsubresource: u32, psrcbox: *const D3D11_BOX) {
+            windows_core::imp::proxy_call(this, "ID3D11Device3", "ReadFromSubresource", 64usize, |this| ((**(this as *const *const ID3D11Device3_Vtbl)).ReadFromSubresource)(this, pdstdata, dstrowpitch, dstdepthpitch, psrcresource, srcsubresource, psrcbox), |_| None)
+        }
+        Self {
+            base__: <ID3D11Device2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateTexture2D1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateTexture2D1: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateTexture3D1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateTexture3D1: 0,
+            CreateRasterizerState2,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateShaderResourceView1,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateShaderResourceView1: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateUnorderedAccessView1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateUnorderedAccessView1: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateRenderTargetView1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateRenderTargetView1: 0,
+            CreateQuery1,
+            GetImmediateContext3,
+            CreateDeferredContext3,
+            WriteToSubresource,
+            ReadFromSubresource,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Device3 as windows_core::Interface>::IID || <ID3D11Device2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D11Device4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterDeviceRemovedEvent(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device4", "RegisterDeviceRemovedEvent", 65usize, |this| ((**(this as *const *const ID3D11Device4_Vtbl)).RegisterDeviceRemovedEvent)(this, hevent, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterDeviceRemoved(this: *mut core::ffi::c_void, dwcookie: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11Device4", "UnregisterDeviceRemoved", 66usize, |this| ((**(this as *const *const ID3D11Device4_Vtbl)).UnregisterDeviceRemoved)(this, dwcookie), |_| None)
+        }
+        Self { base__: <ID3D11Device3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterDeviceRemovedEvent, UnregisterDeviceRemoved }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Device4 as windows_core::Interface>::IID || <ID3D11Device3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D11Device5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenSharedFence(this: *mut core::ffi::c_void, hfence: super::super::Foundation::HANDLE, returnedinterface: *const windows_core::GUID, ppfence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device5", "OpenSharedFence", 67usize, |this| ((**(this as *const *const ID3D11Device5_Vtbl)).OpenSharedFence)(this, hfence, returnedinterface, ppfence), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFence(this: *mut core::ffi::c_void, initialvalue: u64, flags: D3D11_FENCE_FLAG, returnedinterface: *const windows_core::GUID, ppfence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Device5", "CreateFence", 68usize, |this| ((**(this as *const *const ID3D11Device5_Vtbl)).CreateFence)(this, initialvalue, flags, returnedinterface, ppfence), |result| Some(*result))
+        }
+        Self { base__: <ID3D11Device4_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenSharedFence, CreateFence }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Device5 as windows_core::Interface>::IID || <ID3D11Device4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11DeviceChild_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceChild", "GetDevice", 3usize, |this| ((**(this as *const *const ID3D11DeviceChild_Vtbl)).GetDevice)(this, ppdevice), |_| None)
+        }
+        unsafe extern "system" fn GetPrivateData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdatasize: *mut u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceChild", "GetPrivateData", 4usize, |this| ((**(this as *const *const ID3D11DeviceChild_Vtbl)).GetPrivateData)(this, guid, pdatasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceChild", "SetPrivateData", 5usize, |this| ((**(this as *const *const ID3D11DeviceChild_Vtbl)).SetPrivateData)(this, guid, datasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateDataInterface(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceChild", "SetPrivateDataInterface", 6usize, |this| ((**(this as *const *const ID3D11DeviceChild_Vtbl)).SetPrivateDataInterface)(this, guid, pdata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDevice,
+            GetPrivateData,
+            SetPrivateData,
+            SetPrivateDataInterface,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11DeviceChild as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D11DeviceContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VSSetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "VSSetConstantBuffers", 7usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).VSSetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn PSSetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "PSSetShaderResources", 8usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).PSSetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn PSSetShader(this: *mut core::ffi::c_void, ppixelshader: *mut core::ffi::c_void, ppclassinstances: *const *mut core::ffi::c_void, numclassinstances: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "PSSetShader", 9usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).PSSetShader)(this, ppixelshader, ppclassinstances, numclassinstances), |_| None)
+        }
+        unsafe extern "system" fn PSSetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "PSSetSamplers", 10usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).PSSetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn VSSetShader(this: *mut core::ffi::c_void, pvertexshader: *mut core::ffi::c_void, ppclassinstances: *const *mut core::ffi::c_void, numclassinstances: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "VSSetShader", 11usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).VSSetShader)(this, pvertexshader, ppclassinstances, numclassinstances), |_| None)
+        }
+        unsafe extern "system" fn DrawIndexed(this: *mut core::ffi::c_void, indexcount: u32, startindexlocation: u32, basevertexlocation: i32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "DrawIndexed", 12usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).DrawIndexed)(this, indexcount, startindexlocation, basevertexlocation), |_| None)
+        }
+        unsafe extern "system" fn Draw(this: *mut core::ffi::c_void, vertexcount: u32, startvertexlocation: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "Draw", 13usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).Draw)(this, vertexcount, startvertexlocation), |_| None)
+        }
+        unsafe extern "system" fn Map(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, maptype: D3D11_MAP, mapflags: u32, pmappedresource: *mut D3D11_MAPPED_SUBRESOURCE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "Map", 14usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).Map)(this, presource, subresource, maptype, mapflags, pmappedresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unmap(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "Unmap", 15usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).Unmap)(this, presource, subresource), |_| None)
+        }
+        unsafe extern "system" fn PSSetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "PSSetConstantBuffers", 16usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).PSSetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn IASetInputLayout(this: *mut core::ffi::c_void, pinputlayout: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "IASetInputLayout", 17usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).IASetInputLayout)(this, pinputlayout), |_| None)
+        }
+        unsafe extern "system" fn IASetVertexBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppvertexbuffers: *const *mut core::ffi::c_void, pstrides: *const u32, poffsets: *const u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "IASetVertexBuffers", 18usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).IASetVertexBuffers)(this, startslot, numbuffers, ppvertexbuffers, pstrides, poffsets), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn IASetIndexBuffer(this: *mut core::ffi::c_void, pindexbuffer: *mut core::ffi::c_void, format: super::Dxgi::Common::DXGI_FORMAT, offset: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "IASetIndexBuffer", 19usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).IASetIndexBuffer)(this, pindexbuffer, format, offset), |_| None)
+        }
+        unsafe extern "system" fn DrawIndexedInstanced(this: *mut core::ffi::c_void, indexcountperinstance: u32, instancecount: u32, startindexlocation: u32, basevertexlocation: i32, startinstancelocation: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "DrawIndexedInstanced", 20usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).DrawIndexedInstanced)(this, indexcountperinstance, instancecount, startindexlocation, basevertexlocation, startinstancelocation), |_| None)
+        }
+        unsafe extern "system" fn DrawInstanced(this: *mut core::ffi::c_void, vertexcountperinstance: u32, instancecount: u32, startvertexlocation: u32, startinstancelocation: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "DrawInstanced", 21usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).DrawInstanced)(this, vertexcountperinstance, instancecount, startvertexlocation, startinstancelocation), |_| None)
+        }
+        unsafe extern "system" fn GSSetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "GSSetConstantBuffers", 22usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).GSSetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn GSSetShader(this: *mut core::ffi::c_void, pshader: *mut core::ffi::c_void, ppclassinstances: *const *mut core::ffi::c_void, numclassinstances: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "GSSetShader", 23usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).GSSetShader)(this, pshader, ppclassinstances, numclassinstances), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn IASetPrimitiveTopology(this: *mut core::ffi::c_void, topology: super::Direct3D::D3D_PRIMITIVE_TOPOLOGY) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "IASetPrimitiveTopology", 24usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).IASetPrimitiveTopology)(this, topology), |_| None)
+        }
+        unsafe extern "system" fn VSSetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "VSSetShaderResources", 25usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).VSSetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn VSSetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "VSSetSamplers", 26usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).VSSetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn Begin(this: *mut core::ffi::c_void, pasync: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "Begin", 27usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).Begin)(this, pasync), |_| None)
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void, pasync: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "End", 28usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).End)(this, pasync), |_| None)
+        }
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, pasync: *mut core::ffi::c_void, pdata: *mut core::ffi::c_void, datasize: u32, getdataflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "GetData", 29usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).GetData)(this, pasync, pdata, datasize, getdataflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPredication(this: *mut core::ffi::c_void, ppredicate: *mut core::ffi::c_void, predicatevalue: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "SetPredication", 30usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).SetPredication)(this, ppredicate, predicatevalue), |_| None)
+        }
+        unsafe extern "system" fn GSSetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "GSSetShaderResources", 31usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).GSSetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn GSSetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "GSSetSamplers", 32usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).GSSetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn OMSetRenderTargets(this: *mut core::ffi::c_void, numviews: u32, pprendertargetviews: *const *mut core::ffi::c_void, pdepthstencilview: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "OMSetRenderTargets", 33usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).OMSetRenderTargets)(this, numviews, pprendertargetviews, pdepthstencilview), |_| None)
+        }
+        unsafe extern "system" fn OMSetRenderTargetsAndUnorderedAccessViews(this: *mut core::ffi::c_void, numrtvs: u32, pprendertargetviews: *const *mut core::ffi::c_void, pdepthstencilview: *mut core::ffi::c_void, uavstartslot: u32, numuavs: u32, ppunorderedaccessviews: *const *mut core::ffi::c_void, puavinitialcounts: *const u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "OMSetRenderTargetsAndUnorderedAccessViews", 34usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).OMSetRenderTargetsAndUnorderedAccessViews)(this, numrtvs, pprendertargetviews, pdepthstencilview, uavstartslot, numuavs, ppunorderedaccessviews, puavinitialcounts), |_| None)
+        }
+        unsafe extern "system" fn OMSetBlendState(this: *mut core::ffi::c_void, pblendstate: *mut core::ffi::c_void, blendfactor: *const f32, samplemask: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "OMSetBlendState", 35usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).OMSetBlendState)(this, pblendstate, blendfactor, samplemask), |_| None)
+        }
+        unsafe extern "system" fn OMSetDepthStencilState(this: *mut core::ffi::c_void, pdepthstencilstate: *mut core::ffi::c_void, stencilref: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "OMSetDepthStencilState", 36usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).OMSetDepthStencilState)(this, pdepthstencilstate, stencilref), |_| None)
+        }
+        unsafe extern "system" fn SOSetTargets(this: *mut core::ffi::c_void, numbuffers: u32, ppsotargets: *const *mut core::ffi::c_void, poffsets: *const u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "SOSetTargets", 37usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).SOSetTargets)(this, numbuffers, ppsotargets, poffsets), |_| None)
+        }
+        unsafe extern "system" fn DrawAuto(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "DrawAuto", 38usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).DrawAuto)(this), |_| None)
+        }
+        unsafe extern "system" fn DrawIndexedInstancedIndirect(this: *mut core::ffi::c_void, pbufferforargs: *mut core::ffi::c_void, alignedbyteoffsetforargs: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "DrawIndexedInstancedIndirect", 39usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).DrawIndexedInstancedIndirect)(this, pbufferforargs, alignedbyteoffsetforargs), |_| None)
+        }
+        unsafe extern "system" fn DrawInstancedIndirect(this: *mut core::ffi::c_void, pbufferforargs: *mut core::ffi::c_void, alignedbyteoffsetforargs: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "DrawInstancedIndirect", 40usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).DrawInstancedIndirect)(this, pbufferforargs, alignedbyteoffsetforargs), |_| None)
+        }
+        unsafe extern "system" fn Dispatch(this: *mut core::ffi::c_void, threadgroupcountx: u32, threadgroupcounty: u32, threadgroupcountz: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "Dispatch", 41usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).Dispatch)(this, threadgroupcountx, threadgroupcounty, threadgroupcountz), |_| None)
+        }
+        unsafe extern "system" fn DispatchIndirect(this: *mut core::ffi::c_void, pbufferforargs: *mut core::ffi::c_void, alignedbyteoffsetforargs: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "DispatchIndirect", 42usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).DispatchIndirect)(this, pbufferforargs, alignedbyteoffsetforargs), |_| None)
+        }
+        unsafe extern "system" fn RSSetState(this: *mut core::ffi::c_void, prasterizerstate: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "RSSetState", 43usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).RSSetState)(this, prasterizerstate), |_| None)
+        }
+        unsafe extern "system" fn RSSetViewports(this: *mut core::ffi::c_void, numviewports: u32, pviewports: *const D3D11_VIEWPORT) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "RSSetViewports", 44usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).RSSetViewports)(this, numviewports, pviewports), |_| None)
+        }
+        unsafe extern "system" fn RSSetScissorRects(this: *mut core::ffi::c_void, numrects: u32, prects: *const super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "RSSetScissorRects", 45usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).RSSetScissorRects)(this, numrects, prects), |_| None)
+        }
+        unsafe extern "system" fn CopySubresourceRegion(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, dstx: u32, dsty: u32, dstz: u32, psrcresource: *mut core::ffi::c_void, srcsubresource: u32, psrcbox: *const D3D11_BOX) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "CopySubresourceRegion", 46usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).CopySubresourceRegion)(this, pdstresource, dstsubresource, dstx, dsty, dstz, psrcresource, srcsubresource, psrcbox), |_| None)
+        }
+        unsafe extern "system" fn CopyResource(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, psrcresource: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "CopyResource", 47usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).CopyResource)(this, pdstresource, psrcresource), |_| None)
+        }
+        unsafe extern "system" fn UpdateSubresource(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, pdstbox: *const D3D11_BOX, psrcdata: *const core::ffi::c_void, srcrowpitch: u32, srcdepthpitch: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "UpdateSubresource", 48usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).UpdateSubresource)(this, pdstresource, dstsubresource, pdstbox, psrcdata, srcrowpitch, srcdepthpitch), |_| None)
+        }
+        unsafe extern "system" fn CopyStructureCount(this: *mut core::ffi::c_void, pdstbuffer: *mut core::ffi::c_void, dstalignedbyteoffset: u32, psrcview: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "CopyStructureCount", 49usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).CopyStructureCount)(this, pdstbuffer, dstalignedbyteoffset, psrcview), |_| None)
+        }
+        unsafe extern "system" fn ClearRenderTargetView(this: *mut core::ffi::c_void, prendertargetview: *mut core::ffi::c_void, colorrgba: *const f32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "ClearRenderTargetView", 50usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).ClearRenderTargetView)(this, prendertargetview, colorrgba), |_| None)
+        }
+        unsafe extern "system" fn ClearUnorderedAccessViewUint(this: *mut core::ffi::c_void, punorderedaccessview: *mut core::ffi::c_void, values: *const u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "ClearUnorderedAccessViewUint", 51usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).ClearUnorderedAccessViewUint)(this, punorderedaccessview, values), |_| None)
+        }
+        unsafe extern "system" fn ClearUnorderedAccessViewFloat(this: *mut core::ffi::c_void, punorderedaccessview: *mut core::ffi::c_void, values: *const f32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "ClearUnorderedAccessViewFloat", 52usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).ClearUnorderedAccessViewFloat)(this, punorderedaccessview, values), |_| None)
+        }
+        unsafe extern "system" fn ClearDepthStencilView(this: *mut core::ffi::c_void, pdepthstencilview: *mut core::ffi::c_void, clearflags: u32, depth: f32, stencil: u8) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "ClearDepthStencilView", 53usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).ClearDepthStencilView)(this, pdepthstencilview, clearflags, depth, stencil), |_| None)
+        }
+        unsafe extern "system" fn GenerateMips(this: *mut core::ffi::c_void, pshaderresourceview: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "GenerateMips", 54usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).GenerateMips)(this, pshaderresourceview), |_| None)
+        }
+        unsafe extern "system" fn SetResourceMinLOD(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, minlod: f32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "SetResourceMinLOD", 55usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).SetResourceMinLOD)(this, presource, minlod), |_| None)
+        }
+        unsafe extern "system" fn GetResourceMinLOD(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "GetResourceMinLOD", 56usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).GetResourceMinLOD)(this, presource), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn ResolveSubresource(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, psrcresource: *mut core::ffi::c_void, srcsubresource: u32, format: super::Dxgi::Common::DXGI_FORMAT) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "ResolveSubresource", 57usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).ResolveSubresource)(this, pdstresource, dstsubresource, psrcresource, srcsubresource, format), |_| None)
+        }
+        unsafe extern "system" fn ExecuteCommandList(this: *mut core::ffi::c_void, pcommandlist: *mut core::ffi::c_void, restorecontextstate: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "ExecuteCommandList", 58usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).ExecuteCommandList)(this, pcommandlist, restorecontextstate), |_| None)
+        }
+        unsafe extern "system" fn HSSetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "HSSetShaderResources", 59usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).HSSetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn HSSetShader(this: *mut core::ffi::c_void, phullshader: *mut core::ffi::c_void, ppclassinstances: *const *mut core::ffi::c_void, numclassinstances: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "HSSetShader", 60usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).HSSetShader)(this, phullshader, ppclassinstances, numclassinstances), |_| None)
+        }
+        unsafe extern "system" fn HSSetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "HSSetSamplers", 61usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).HSSetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn HSSetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "HSSetConstantBuffers", 62usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).HSSetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn DSSetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "DSSetShaderResources", 63usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).DSSetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn DSSetShader(this: *mut core::ffi::c_void, pdomainshader: *mut core::ffi::c_void, ppclassinstances: *const *mut core::ffi::c_void, numclassinstances: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "DSSetShader", 64usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).DSSetShader)(this, pdomainshader, ppclassinstances, numclassinstances), |_| None)
+        }
+        unsafe extern "system" fn DSSetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "DSSetSamplers", 65usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).DSSetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn DSSetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "DSSetConstantBuffers", 66usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).DSSetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn CSSetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "CSSetShaderResources", 67usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).CSSetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn CSSetUnorderedAccessViews(this: *mut core::ffi::c_void, startslot: u32, numuavs: u32, ppunorderedaccessviews: *const *mut core::ffi::c_void, puavinitialcounts: *const u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "CSSetUnorderedAccessViews", 68usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).CSSetUnorderedAccessViews)(this, startslot, numuavs, ppunorderedaccessviews, puavinitialcounts), |_| None)
+        }
+        unsafe extern "system" fn CSSetShader(this: *mut core::ffi::c_void, pcomputeshader: *mut core::ffi::c_void, ppclassinstances: *const *mut core::ffi::c_void, numclassinstances: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "CSSetShader", 69usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).CSSetShader)(this, pcomputeshader, ppclassinstances, numclassinstances), |_| None)
+        }
+        unsafe extern "system" fn CSSetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "CSSetSamplers", 70usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).CSSetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn CSSetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "CSSetConstantBuffers", 71usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).CSSetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn VSGetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "VSGetConstantBuffers", 72usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).VSGetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn PSGetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "PSGetShaderResources", 73usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).PSGetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn PSGetShader(this: *mut core::ffi::c_void, pppixelshader: *mut *mut core::ffi::c_void, ppclassinstances: *mut *mut core::ffi::c_void, pnumclassinstances: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "PSGetShader", 74usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).PSGetShader)(this, pppixelshader, ppclassinstances, pnumclassinstances), |_| None)
+        }
+        unsafe extern "system" fn PSGetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "PSGetSamplers", 75usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).PSGetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn VSGetShader(this: *mut core::ffi::c_void, ppvertexshader: *mut *mut core::ffi::c_void, ppclassinstances: *mut *mut core::ffi::c_void, pnumclassinstances: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "VSGetShader", 76usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).VSGetShader)(this, ppvertexshader, ppclassinstances, pnumclassinstances), |_| None)
+        }
+        unsafe extern "system" fn PSGetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "PSGetConstantBuffers", 77usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).PSGetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn IAGetInputLayout(this: *mut core::ffi::c_void, ppinputlayout: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "IAGetInputLayout", 78usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).IAGetInputLayout)(this, ppinputlayout), |_| None)
+        }
+        unsafe extern "system" fn IAGetVertexBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppvertexbuffers: *mut *mut core::ffi::c_void, pstrides: *mut u32, poffsets: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "IAGetVertexBuffers", 79usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).IAGetVertexBuffers)(this, startslot, numbuffers, ppvertexbuffers, pstrides, poffsets), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn IAGetIndexBuffer(this: *mut core::ffi::c_void, pindexbuffer: *mut *mut core::ffi::c_void, format: *mut super::Dxgi::Common::DXGI_FORMAT, offset: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "IAGetIndexBuffer", 80usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).IAGetIndexBuffer)(this, pindexbuffer, format, offset), |_| None)
+        }
+        unsafe extern "system" fn GSGetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "GSGetConstantBuffers", 81usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).GSGetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn GSGetShader(this: *mut core::ffi::c_void, ppgeometryshader: *mut *mut core::ffi::c_void, ppclassinstances: *mut *mut core::ffi::c_void, pnumclassinstances: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "GSGetShader", 82usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).GSGetShader)(this, ppgeometryshader, ppclassinstances, pnumclassinstances), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn IAGetPrimitiveTopology(this: *mut core::ffi::c_void, ptopology: *mut super::Direct3D::D3D_PRIMITIVE_TOPOLOGY) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "IAGetPrimitiveTopology", 83usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).IAGetPrimitiveTopology)(this, ptopology), |_| None)
+        }
+        unsafe extern "system" fn VSGetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "VSGetShaderResources", 84usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).VSGetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn VSGetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "VSGetSamplers", 85usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).VSGetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn GetPredication(this: *mut core::ffi::c_void, pppredicate: *mut *mut core::ffi::c_void, ppredicatevalue: *mut super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "GetPredication", 86usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).GetPredication)(this, pppredicate, ppredicatevalue), |_| None)
+        }
+        unsafe extern "system" fn GSGetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "GSGetShaderResources", 87usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).GSGetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn GSGetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "GSGetSamplers", 88usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).GSGetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn OMGetRenderTargets(this: *mut core::ffi::c_void, numviews: u32, pprendertargetviews: *mut *mut core::ffi::c_void, ppdepthstencilview: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "OMGetRenderTargets", 89usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).OMGetRenderTargets)(this, numviews, pprendertargetviews, ppdepthstencilview), |_| None)
+        }
+        unsafe extern "system" fn OMGetRenderTargetsAndUnorderedAccessViews(this: *mut core::ffi::c_void, numrtvs: u32, pprendertargetviews: *mut *mut core::ffi::c_void, ppdepthstencilview: *mut *mut core::ffi::c_void, uavstartslot: u32, numuavs: u32, ppunorderedaccessviews: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "OMGetRenderTargetsAndUnorderedAccessViews", 90usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).OMGetRenderTargetsAndUnorderedAccessViews)(this, numrtvs, pprendertargetviews, ppdepthstencilview, uavstartslot, numuavs, ppunorderedaccessviews), |_| None)
+        }
+        unsafe extern "system" fn OMGetBlendState(this: *mut core::ffi::c_void, ppblendstate: *mut *mut core::ffi::c_void, blendfactor: *mut f32, psamplemask: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "OMGetBlendState", 91usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).OMGetBlendState)(this, ppblendstate, blendfactor, psamplemask), |_| None)
+        }
+        unsafe extern "system" fn OMGetDepthStencilState(this: *mut core::ffi::c_void, ppdepthstencilstate: *mut *mut core::ffi::c_void, pstencilref: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "OMGetDepthStencilState", 92usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).OMGetDepthStencilState)(this, ppdepthstencilstate, pstencilref), |_| None)
+        }
+        unsafe extern "system" fn SOGetTargets(this: *mut core::ffi::c_void, numbuffers: u32, ppsotargets: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "SOGetTargets", 93usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).SOGetTargets)(this, numbuffers, ppsotargets), |_| None)
+        }
+        unsafe extern "system" fn RSGetState(this: *mut core::ffi::c_void, pprasterizerstate: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "RSGetState", 94usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).RSGetState)(this, pprasterizerstate), |_| None)
+        }
+        unsafe extern "system" fn RSGetViewports(this: *mut core::ffi::c_void, pnumviewports: *mut u32, pviewports: *mut D3D11_VIEWPORT) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "RSGetViewports", 95usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).RSGetViewports)(this, pnumviewports, pviewports), |_| None)
+        }
+        unsafe extern "system" fn RSGetScissorRects(this: *mut core::ffi::c_void, pnumrects: *mut u32, prects: *mut super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "RSGetScissorRects", 96usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).RSGetScissorRects)(this, pnumrects, prects), |_| None)
+        }
+        unsafe extern "system" fn HSGetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "HSGetShaderResources", 97usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).HSGetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn HSGetShader(this: *mut core::ffi::c_void, pphullshader: *mut *mut core::ffi::c_void, ppclassinstances: *mut *mut core::ffi::c_void, pnumclassinstances: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "HSGetShader", 98usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).HSGetShader)(this, pphullshader, ppclassinstances, pnumclassinstances), |_| None)
+        }
+        unsafe extern "system" fn HSGetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "HSGetSamplers", 99usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).HSGetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn HSGetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "HSGetConstantBuffers", 100usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).HSGetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn DSGetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "DSGetShaderResources", 101usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).DSGetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn DSGetShader(this: *mut core::ffi::c_void, ppdomainshader: *mut *mut core::ffi::c_void, ppclassinstances: *mut *mut core::ffi::c_void, pnumclassinstances: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "DSGetShader", 102usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).DSGetShader)(this, ppdomainshader, ppclassinstances, pnumclassinstances), |_| None)
+        }
+        unsafe extern "system" fn DSGetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "DSGetSamplers", 103usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).DSGetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn DSGetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "DSGetConstantBuffers", 104usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).DSGetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn CSGetShaderResources(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "CSGetShaderResources", 105usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).CSGetShaderResources)(this, startslot, numviews, ppshaderresourceviews), |_| None)
+        }
+        unsafe extern "system" fn CSGetUnorderedAccessViews(this: *mut core::ffi::c_void, startslot: u32, numuavs: u32, ppunorderedaccessviews: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "CSGetUnorderedAccessViews", 106usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).CSGetUnorderedAccessViews)(this, startslot, numuavs, ppunorderedaccessviews), |_| None)
+        }
+        unsafe extern "system" fn CSGetShader(this: *mut core::ffi::c_void, ppcomputeshader: *mut *mut core::ffi::c_void, ppclassinstances: *mut *mut core::ffi::c_void, pnumclassinstances: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "CSGetShader", 107usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).CSGetShader)(this, ppcomputeshader, ppclassinstances, pnumclassinstances), |_| None)
+        }
+        unsafe extern "system" fn CSGetSamplers(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "CSGetSamplers", 108usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).CSGetSamplers)(this, startslot, numsamplers, ppsamplers), |_| None)
+        }
+        unsafe extern "system" fn CSGetConstantBuffers(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "CSGetConstantBuffers", 109usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).CSGetConstantBuffers)(this, startslot, numbuffers, ppconstantbuffers), |_| None)
+        }
+        unsafe extern "system" fn ClearState(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "ClearState", 110usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).ClearState)(this), |_| None)
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "Flush", 111usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).Flush)(this), |_| None)
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void) -> D3D11_DEVICE_CONTEXT_TYPE {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "GetType", 112usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).GetType)(this), |_| None)
+        }
+        unsafe extern "system" fn GetContextFlags(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "GetContextFlags", 113usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).GetContextFlags)(this), |_| None)
+        }
+        unsafe extern "system" fn FinishCommandList(this: *mut core::ffi::c_void, restoredeferredcontextstate: super::super::Foundation::BOOL, ppcommandlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext", "FinishCommandList", 114usize, |this| ((**(this as *const *const ID3D11DeviceContext_Vtbl)).FinishCommandList)(this, restoredeferredcontextstate, ppcommandlist), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            VSSetConstantBuffers,
+            PSSetShaderResources,
+            PSSetShader,
+            PSSetSamplers,
+            VSSetShader,
+            DrawIndexed,
+            Draw,
+            Map,
+            Unmap,
+            PSSetConstantBuffers,
+            IASetInputLayout,
+            IASetVertexBuffers,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            IASetIndexBuffer,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            IASetIndexBuffer: 0,
+            DrawIndexedInstanced,
+            DrawInstanced,
+            GSSetConstantBuffers,
+            GSSetShader,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            IASetPrimitiveTopology,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            IASetPrimitiveTopology: 0,
+            VSSetShaderResources,
+            VSSetSamplers,
+            Begin,
+            End,
+            GetData,
+            SetPredication,
+            GSSetShaderResources,
+            GSSetSamplers,
+            OMSetRenderTargets,
+            OMSetRenderTargetsAndUnorderedAccessViews,
+            OMSetBlendState,
+            OMSetDepthStencilState,
+            SOSetTargets,
+            DrawAuto,
+            DrawIndexedInstancedIndirect,
+            DrawInstancedIndirect,
+            Dispatch,
+            DispatchIndirect,
+            RSSetState,
+            RSSetViewports,
+            RSSetScissorRects,
+            CopySubresourceRegion,
+            CopyResource,
+            UpdateSubresource,
+            CopyStructureCount,
+            ClearRenderTargetView,
+            ClearUnorderedAccessViewUint,
+            ClearUnorderedAccessViewFloat,
+            ClearDepthStencilView,
+            GenerateMips,
+            SetResourceMinLOD,
+            GetResourceMinLOD,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            ResolveSubresource,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            ResolveSubresource: 0,
+            ExecuteCommandList,
+            HSSetShaderResources,
+            HSSetShader,
+            HSSetSamplers,
+            HSSetConstantBuffers,
+            DSSetShaderResources,
+            DSSetShader,
+            DSSetSamplers,
+            DSSetConstantBuffers,
+            CSSetShaderResources,
+            CSSetUnorderedAccessViews,
+            CSSetShader,
+            CSSetSamplers,
+            CSSetConstantBuffers,
+            VSGetConstantBuffers,
+            PSGetShaderResources,
+            PSGetShader,
+            PSGetSamplers,
+            VSGetShader,
+            PSGetConstantBuffers,
+            IAGetInputLayout,
+            IAGetVertexBuffers,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            IAGetIndexBuffer,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            IAGetIndexBuffer: 0,
+            GSGetConstantBuffers,
+            GSGetShader,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            IAGetPrimitiveTopology,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            IAGetPrimitiveTopology: 0,
+            VSGetShaderResources,
+            VSGetSamplers,
+            GetPredication,
+            GSGetShaderResources,
+            GSGetSamplers,
+            OMGetRenderTargets,
+            OMGetRenderTargetsAndUnorderedAccessViews,
+            OMGetBlendState,
+            OMGetDepthStencilState,
+            SOGetTargets,
+            RSGetState,
+            RSGetViewports,
+            RSGetScissorRects,
+            HSGetShaderResources,
+            HSGetShader,
+            HSGetSamplers,
+            HSGetConstantBuffers,
+            DSGetShaderResources,
+            DSGetShader,
+            DSGetSamplers,
+            DSGetConstantBuffers,
+            CSGetShaderResources,
+            CSGetUnorderedAccessViews,
+            CSGetShader,
+            CSGetSamplers,
+            CSGetConstantBuffers,
+            ClearState,
+            Flush,
+            GetType,
+            GetContextFlags,
+            FinishCommandList,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11DeviceContext as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D11DeviceContext1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CopySubresourceRegion1(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, dstx: u32, dsty: u32, dstz: u32, psrcresource: *mut core::ffi::c_void, srcsubresource: u32, psrcbox: *const D3D11_BOX, copyflags: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "CopySubresourceRegion1", 115usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).CopySubresourceRegion1)(this, pdstresource, dstsubresource, dstx, dsty, dstz, psrcresource, srcsubresource, psrcbox, copyflags), |_| None)
+        }
+        unsafe extern "system" fn UpdateSubresource1(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, pdstbox: *const D3D11_BOX, psrcdata: *const core::ffi::c_void, srcrowpitch: u32, srcdepthpitch: u32, copyflags: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "UpdateSubresource1", 116usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).UpdateSubresource1)(this, pdstresource, dstsubresource, pdstbox, psrcdata, srcrowpitch, srcdepthpitch, copyflags), |_| None)
+        }
+        unsafe extern "system" fn DiscardResource(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "DiscardResource", 117usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).DiscardResource)(this, presource), |_| None)
+        }
+        unsafe extern "system" fn DiscardView(this: *mut core::ffi::c_void, presourceview: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "DiscardView", 118usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).DiscardView)(this, presourceview), |_| None)
+        }
+        unsafe extern "system" fn VSSetConstantBuffers1(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void, pfirstconstant: *const u32, pnumconstants: *const u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "VSSetConstantBuffers1", 119usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).VSSetConstantBuffers1)(this, startslot, numbuffers, ppconstantbuffers, pfirstconstant, pnumconstants), |_| None)
+        }
+        unsafe extern "system" fn HSSetConstantBuffers1(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void, pfirstconstant: *const u32, pnumconstants: *const u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "HSSetConstantBuffers1", 120usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).HSSetConstantBuffers1)(this, startslot, numbuffers, ppconstantbuffers, pfirstconstant, pnumconstants), |_| None)
+        }
+        unsafe extern "system" fn DSSetConstantBuffers1(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void, pfirstconstant: *const u32, pnumconstants: *const u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "DSSetConstantBuffers1", 121usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).DSSetConstantBuffers1)(this, startslot, numbuffers, ppconstantbuffers, pfirstconstant, pnumconstants), |_| None)
+        }
+        unsafe extern "system" fn GSSetConstantBuffers1(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void, pfirstconstant: *const u32, pnumconstants: *const u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "GSSetConstantBuffers1", 122usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).GSSetConstantBuffers1)(this, startslot, numbuffers, ppconstantbuffers, pfirstconstant, pnumconstants), |_| None)
+        }
+        unsafe extern "system" fn PSSetConstantBuffers1(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void, pfirstconstant: *const u32, pnumconstants: *const u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "PSSetConstantBuffers1", 123usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).PSSetConstantBuffers1)(this, startslot, numbuffers, ppconstantbuffers, pfirstconstant, pnumconstants), |_| None)
+        }
+        unsafe extern "system" fn CSSetConstantBuffers1(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void, pfirstconstant: *const u32, pnumconstants: *const u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "CSSetConstantBuffers1", 124usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).CSSetConstantBuffers1)(this, startslot, numbuffers, ppconstantbuffers, pfirstconstant, pnumconstants), |_| None)
+        }
+        unsafe extern "system" fn VSGetConstantBuffers1(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void, pfirstconstant: *mut u32, pnumconstants: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "VSGetConstantBuffers1", 125usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).VSGetConstantBuffers1)(this, startslot, numbuffers, ppconstantbuffers, pfirstconstant, pnumconstants), |_| None)
+        }
+        unsafe extern "system" fn HSGetConstantBuffers1(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void, pfirstconstant: *mut u32, pnumconstants: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "HSGetConstantBuffers1", 126usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).HSGetConstantBuffers1)(this, startslot, numbuffers, ppconstantbuffers, pfirstconstant, pnumconstants), |_| None)
+        }
+        unsafe extern "system" fn DSGetConstantBuffers1(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void, pfirstconstant: *mut u32, pnumconstants: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "DSGetConstantBuffers1", 127usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).DSGetConstantBuffers1)(this, startslot, numbuffers, ppconstantbuffers, pfirstconstant, pnumconstants), |_| None)
+        }
+        unsafe extern "system" fn GSGetConstantBuffers1(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void, pfirstconstant: *mut u32, pnumconstants: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "GSGetConstantBuffers1", 128usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).GSGetConstantBuffers1)(this, startslot, numbuffers, ppconstantbuffers, pfirstconstant, pnumconstants), |_| None)
+        }
+        unsafe extern "system" fn PSGetConstantBuffers1(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void, pfirstconstant: *mut u32, pnumconstants: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "PSGetConstantBuffers1", 129usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).PSGetConstantBuffers1)(this, startslot, numbuffers, ppconstantbuffers, pfirstconstant, pnumconstants), |_| None)
+        }
+        unsafe extern "system" fn CSGetConstantBuffers1(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void, pfirstconstant: *mut u32, pnumconstants: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "CSGetConstantBuffers1", 130usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).CSGetConstantBuffers1)(this, startslot, numbuffers, ppconstantbuffers, pfirstconstant, pnumconstants), |_| None)
+        }
+        unsafe extern "system" fn SwapDeviceContextState(this: *mut core::ffi::c_void, pstate: *mut core::ffi::c_void, pppreviousstate: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "SwapDeviceContextState", 131usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).SwapDeviceContextState)(this, pstate, pppreviousstate), |_| None)
+        }
+        unsafe extern "system" fn ClearView(this: *mut core::ffi::c_void, pview: *mut core::ffi::c_void, color: *const f32, prect: *const super::super::Foundation::RECT, numrects: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "ClearView", 132usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).ClearView)(this, pview, color, prect, numrects), |_| None)
+        }
+        unsafe extern "system" fn DiscardView1(this: *mut core::ffi::c_void, presourceview: *mut core::ffi::c_void, prects: *const super::super::Foundation::RECT, numrects: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext1", "DiscardView1", 133usize, |this| ((**(this as *const *const ID3D11DeviceContext1_Vtbl)).DiscardView1)(this, presourceview, prects, numrects), |_| None)
+        }
+        Self {
+            base__: <ID3D11DeviceContext_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CopySubresourceRegion1,
+            UpdateSubresource1,
+            DiscardResource,
+            DiscardView,
+            VSSetConstantBuffers1,
+            HSSetConstantBuffers1,
+            DSSetConstantBuffers1,
+            GSSetConstantBuffers1,
+            PSSetConstantBuffers1,
+            CSSetConstantBuffers1,
+            VSGetConstantBuffers1,
+            HSGetConstantBuffers1,
+            DSGetConstantBuffers1,
+            GSGetConstantBuffers1,
+            PSGetConstantBuffers1,
+            CSGetConstantBuffers1,
+            SwapDeviceContextState,
+            ClearView,
+            DiscardView1,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11DeviceContext1 as windows_core::Interface>::IID || <ID3D11DeviceContext_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D11DeviceContext2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UpdateTileMappings(this: *mut core::ffi::c_void, ptiledresource: *mut core::ffi::c_void, numtiledresourceregions: u32, ptiledresourceregionstartcoordinates: *const D3D11_TILED_RESOURCE_COORDINATE, ptiledresourceregionsizes: *const D3D11_TILE_REGION_SIZE, ptilepool: *mut core::ffi::c_void, numranges: u32, prangeflags: *const u32, ptilepoolstartoffsets: *const u32, prangetilecounts: *const u32, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext2", "UpdateTileMappings", 134usize, |this| ((**(this as *const *const ID3D11DeviceContext2_Vtbl)).UpdateTileMappings)(this, ptiledresource, numtiledresourceregions, ptiledresourceregionstartcoordinates, ptiledresourceregionsizes, ptilepool, numranges, prangeflags, ptilepoolstartoffsets, prangetilecounts, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyTileMappings(this: *mut core::ffi::c_void, pdesttiledresource: *mut core::ffi::c_void, pdestregionstartcoordinate: *const D3D11_TILED_RESOURCE_COORDINATE, psourcetiledresource: *mut core::ffi::c_void, psourceregionstartcoordinate: *const D3D11_TILED_RESOURCE_COORDINATE, ptileregionsize: *const D3D11_TILE_REGION_SIZE, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext2", "CopyTileMappings", 135usize, |this| ((**(this as *const *const ID3D11DeviceContext2_Vtbl)).CopyTileMappings)(this, pdesttiledresource, pdestregionstartcoordinate, psourcetiledresource, psourceregionstartcoordinate, ptileregionsize, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyTiles(this: *mut core::ffi::c_void, ptiledresource: *mut core::ffi::c_void, ptileregionstartcoordinate: *const D3D11_TILED_RESOURCE_COORDINATE, ptileregionsize: *const D3D11_TILE_REGION_SIZE, pbuffer: *mut core::ffi::c_void, bufferstartoffsetinbytes: u64, flags: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext2", "CopyTiles", 136usize, |this| ((**(this as *const *const ID3D11DeviceContext2_Vtbl)).CopyTiles)(this, ptiledresource, ptileregionstartcoordinate, ptileregionsize, pbuffer, bufferstartoffsetinbytes, flags), |_| None)
+        }
+        unsafe extern "system" fn UpdateTiles(this: *mut core::ffi::c_void, pdesttiledresource: *mut core::ffi::c_void, pdesttileregionstartcoordinate: *const D3D11_TILED_RESOURCE_COORDINATE, pdesttileregionsize: *const D3D11_TILE_REGION_SIZE, psourcetiledata: *const core::ffi::c_void, flags: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext2", "UpdateTiles", 137usize, |this| ((**(this as *const *const ID3D11DeviceContext2_Vtbl)).UpdateTiles)(this, pdesttiledresource, pdesttileregionstartcoordinate, pdesttileregionsize, psourcetiledata, flags), |_| None)
+        }
+        unsafe extern "system" fn ResizeTilePool(this: *mut core::ffi::c_void, ptilepool: *mut core::ffi::c_void, newsizeinbytes: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext2", "ResizeTilePool", 138usize, |this| ((**(this as *const *const ID3D11DeviceContext2_Vtbl)).ResizeTilePool)(this, ptilepool, newsizeinbytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn TiledResourceBarrier(this: *mut core::ffi::c_void, ptiledresourceorviewaccessbeforebarrier: *mut core::ffi::c_void, ptiledresourceorviewaccessafterbarrier: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext2", "TiledResourceBarrier", 139usize, |this| ((**(this as *const *const ID3D11DeviceContext2_Vtbl)).TiledResourceBarrier)(this, ptiledresourceorviewaccessbeforebarrier, ptiledresourceorviewaccessafterbarrier), |_| None)
+        }
+        unsafe extern "system" fn IsAnnotationEnabled(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext2", "IsAnnotationEnabled", 140usize, |this| ((**(this as *const *const ID3D11DeviceContext2_Vtbl)).IsAnnotationEnabled)(this), |_| None)
+        }
+        unsafe extern "system" fn SetMarkerInt(this: *mut core::ffi::c_void, plabel: windows_core::PCWSTR, data: i32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext2", "SetMarkerInt", 141usize, |this| ((**(this as *const *const ID3D11DeviceContext2_Vtbl)).SetMarkerInt)(this, plabel, data), |_| None)
+        }
+        unsafe extern "system" fn BeginEventInt(this: *mut core::ffi::c_void, plabel: windows_core::PCWSTR, data: i32) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext2", "BeginEventInt", 142usize, |this| ((**(this as *const *const ID3D11DeviceContext2_Vtbl)).BeginEventInt)(this, plabel, data), |_| None)
+        }
+        unsafe extern "system" fn EndEvent(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext2", "EndEvent", 143usize, |this| ((**(this as *const *const ID3D11DeviceContext2_Vtbl)).EndEvent)(this), |_| None)
+        }
+        Self {
+            base__: <ID3D11DeviceContext1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UpdateTileMappings,
+            CopyTileMappings,
+            CopyTiles,
+            UpdateTiles,
+            ResizeTilePool,
+            TiledResourceBarrier,
+            IsAnnotationEnabled,
+            SetMarkerInt,
+            BeginEventInt,
+            EndEvent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11DeviceContext2 as windows_core::Interface>::IID || <ID3D11DeviceContext1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D11DeviceContext3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Flush1(this: *mut core::ffi::c_void, contexttype: D3D11_CONTEXT_TYPE, hevent: super::super::Foundation::HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext3", "Flush1", 144usize, |this| ((**(this as *const *const ID3D11DeviceContext3_Vtbl)).Flush1)(this, contexttype, hevent), |_| None)
+        }
+        unsafe extern "system" fn SetHardwareProtectionState(this: *mut core::ffi::c_void, hwprotectionenable: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext3", "SetHardwareProtectionState", 145usize, |this| ((**(this as *const *const ID3D11DeviceContext3_Vtbl)).SetHardwareProtectionState)(this, hwprotectionenable), |_| None)
+        }
+        unsafe extern "system" fn GetHardwareProtectionState(this: *mut core::ffi::c_void, phwprotectionenable: *mut super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext3", "GetHardwareProtectionState", 146usize, |this| ((**(this as *const *const ID3D11DeviceContext3_Vtbl)).GetHardwareProtectionState)(this, phwprotectionenable), |_| None)
+        }
+        Self { base__: <ID3D11DeviceContext2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Flush1, SetHardwareProtectionState, GetHardwareProtectionState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11DeviceContext3 as windows_core::Interface>::IID || <ID3D11DeviceContext2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D11DeviceContext4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Signal(this: *mut core::ffi::c_void, pfence: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext4", "Signal", 147usize, |this| ((**(this as *const *const ID3D11DeviceContext4_Vtbl)).Signal)(this, pfence, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wait(this: *mut core::ffi::c_void, pfence: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11DeviceContext4", "Wait", 148usize, |this| ((**(this as *const *const ID3D11DeviceContext4_Vtbl)).Wait)(this, pfence, value), |result| Some(*result))
+        }
+        Self { base__: <ID3D11DeviceContext3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Signal, Wait }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11DeviceContext4 as windows_core::Interface>::IID || <ID3D11DeviceContext3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11DomainShader_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11DomainShader as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Security")]
+impl windows_core::imp::ProxyVtable for ID3D11Fence_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn CreateSharedHandle(this: *mut core::ffi::c_void, pattributes: *const super::super::Security::SECURITY_ATTRIBUTES, dwaccess: u32, lpname: windows_core::PCWSTR, phandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Fence", "CreateSharedHandle", 7usize, |this| ((**(this as *const *const ID3D11Fence_Vtbl)).CreateSharedHandle)(this, pattributes, dwaccess, lpname, phandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCompletedValue(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D11Fence", "GetCompletedValue", 8usize, |this| ((**(this as *const *const ID3D11Fence_Vtbl)).GetCompletedValue)(this), |_| None)
+        }
+        unsafe extern "system" fn SetEventOnCompletion(this: *mut core::ffi::c_void, value: u64, hevent: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Fence", "SetEventOnCompletion", 9usize, |this| ((**(this as *const *const ID3D11Fence_Vtbl)).SetEventOnCompletion)(this, value, hevent), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Security")]
+            CreateSharedHandle,
+            #[cfg(not(feature = "Win32_Security"))]
+            CreateSharedHandle: 0,
+            GetCompletedValue,
+            SetEventOnCompletion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Fence as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
+impl windows_core::imp::ProxyVtable for ID3D11FunctionLinkingGraph_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn CreateModuleInstance(this: *mut core::ffi::c_void, ppmoduleinstance: *mut *mut core::ffi::c_void, pperrorbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11FunctionLinkingGraph", "CreateModuleInstance", 3usize, |this| ((**(this as *const *const ID3D11FunctionLinkingGraph_Vtbl)).CreateModuleInstance)(this, ppmoduleinstance, pperrorbuffer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn SetInputSignature(this: *mut core::ffi::c_void, pinputparameters: *const D3D11_PARAMETER_DESC, cinputparameters: u32, ppinputnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11FunctionLinkingGraph", "SetInputSignature", 4usize, |this| ((**(this as *const *const ID3D11FunctionLinkingGraph_Vtbl)).SetInputSignature)(this, pinputparameters, cinputparameters, ppinputnode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn SetOutputSignature(this: *mut core::ffi::c_void, poutputparameters: *const D3D11_PARAMETER_DESC, coutputparameters: u32, ppoutputnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11FunctionLinkingGraph", "SetOutputSignature", 5usize, |this| ((**(this as *const *const ID3D11FunctionLinkingGraph_Vtbl)).SetOutputSignature)(this, poutputparameters, coutputparameters, ppoutputnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallFunction(this: *mut core::ffi::c_void, pmoduleinstancenamespace: windows_core::PCSTR, pmodulewithfunctionprototype: *mut core::ffi::c_void, pfunctionname: windows_core::PCSTR, ppcallnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11FunctionLinkingGraph", "CallFunction", 6usize, |this| ((**(this as *const *const ID3D11FunctionLinkingGraph_Vtbl)).CallFunction)(this, pmoduleinstancenamespace, pmodulewithfunctionprototype, pfunctionname, ppcallnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn PassValue(this: *mut core::ffi::c_void, psrcnode: *mut core::ffi::c_void, srcparameterindex: i32, pdstnode: *mut core::ffi::c_void, dstparameterindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11FunctionLinkingGraph", "PassValue", 7usize, |this| ((**(this as *const *const ID3D11FunctionLinkingGraph_Vtbl)).PassValue)(this, psrcnode, srcparameterindex, pdstnode, dstparameterindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn PassValueWithSwizzle(this: *mut core::ffi::c_void, psrcnode: *mut core::ffi::c_void, srcparameterindex: i32, psrcswizzle: windows_core::PCSTR, pdstnode: *mut core::ffi::c_void, dstparameterindex: i32, pdstswizzle: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11FunctionLinkingGraph", "PassValueWithSwizzle", 8usize, |this| ((**(this as *const *const ID3D11FunctionLinkingGraph_Vtbl)).PassValueWithSwizzle)(this, psrcnode, srcparameterindex, psrcswizzle, pdstnode, dstparameterindex, pdstswizzle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetLastError(this: *mut core::ffi::c_void, pperrorbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11FunctionLinkingGraph", "GetLastError", 9usize, |this| ((**(this as *const *const ID3D11FunctionLinkingGraph_Vtbl)).GetLastError)(this, pperrorbuffer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GenerateHlsl(this: *mut core::ffi::c_void, uflags: u32, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11FunctionLinkingGraph", "GenerateHlsl", 10usize, |this| ((**(this as *const *const ID3D11FunctionLinkingGraph_Vtbl)).GenerateHlsl)(this, uflags, ppbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            CreateModuleInstance,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            CreateModuleInstance: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            SetInputSignature,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            SetInputSignature: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            SetOutputSignature,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            SetOutputSignature: 0,
+            CallFunction,
+            PassValue,
+            PassValueWithSwizzle,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetLastError,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetLastError: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GenerateHlsl,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GenerateHlsl: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11FunctionLinkingGraph as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11GeometryShader_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11GeometryShader as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11HullShader_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11HullShader as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11InfoQueue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMessageCountLimit(this: *mut core::ffi::c_void, messagecountlimit: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "SetMessageCountLimit", 3usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).SetMessageCountLimit)(this, messagecountlimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearStoredMessages(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "ClearStoredMessages", 4usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).ClearStoredMessages)(this), |_| None)
+        }
+        unsafe extern "system" fn GetMessage(this: *mut core::ffi::c_void, messageindex: u64, pmessage: *mut D3D11_MESSAGE, pmessagebytelength: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "GetMessage", 5usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).GetMessage)(this, messageindex, pmessage, pmessagebytelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumMessagesAllowedByStorageFilter(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "GetNumMessagesAllowedByStorageFilter", 6usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).GetNumMessagesAllowedByStorageFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNumMessagesDeniedByStorageFilter(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "GetNumMessagesDeniedByStorageFilter", 7usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).GetNumMessagesDeniedByStorageFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNumStoredMessages(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "GetNumStoredMessages", 8usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).GetNumStoredMessages)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNumStoredMessagesAllowedByRetrievalFilter(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "GetNumStoredMessagesAllowedByRetrievalFilter", 9usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).GetNumStoredMessagesAllowedByRetrievalFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNumMessagesDiscardedByMessageCountLimit(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "GetNumMessagesDiscardedByMessageCountLimit", 10usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).GetNumMessagesDiscardedByMessageCountLimit)(this), |_| None)
+        }
+        unsafe extern "system" fn GetMessageCountLimit(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "GetMessageCountLimit", 11usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).GetMessageCountLimit)(this), |_| None)
+        }
+        unsafe extern "system" fn AddStorageFilterEntries(this: *mut core::ffi::c_void, pfilter: *const D3D11_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "AddStorageFilterEntries", 12usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).AddStorageFilterEntries)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStorageFilter(this: *mut core::ffi::c_void, pfilter: *mut D3D11_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "GetStorageFilter", 13usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).GetStorageFilter)(this, pfilter, pfilterbytelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearStorageFilter(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "ClearStorageFilter", 14usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).ClearStorageFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn PushEmptyStorageFilter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "PushEmptyStorageFilter", 15usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).PushEmptyStorageFilter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushCopyOfStorageFilter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "PushCopyOfStorageFilter", 16usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).PushCopyOfStorageFilter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushStorageFilter(this: *mut core::ffi::c_void, pfilter: *const D3D11_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "PushStorageFilter", 17usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).PushStorageFilter)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn PopStorageFilter(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "PopStorageFilter", 18usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).PopStorageFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn GetStorageFilterStackSize(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "GetStorageFilterStackSize", 19usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).GetStorageFilterStackSize)(this), |_| None)
+        }
+        unsafe extern "system" fn AddRetrievalFilterEntries(this: *mut core::ffi::c_void, pfilter: *const D3D11_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "AddRetrievalFilterEntries", 20usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).AddRetrievalFilterEntries)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRetrievalFilter(this: *mut core::ffi::c_void, pfilter: *mut D3D11_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "GetRetrievalFilter", 21usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).GetRetrievalFilter)(this, pfilter, pfilterbytelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearRetrievalFilter(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "ClearRetrievalFilter", 22usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).ClearRetrievalFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn PushEmptyRetrievalFilter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "PushEmptyRetrievalFilter", 23usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).PushEmptyRetrievalFilter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushCopyOfRetrievalFilter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "PushCopyOfRetrievalFilter", 24usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).PushCopyOfRetrievalFilter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushRetrievalFilter(this: *mut core::ffi::c_void, pfilter: *const D3D11_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "PushRetrievalFilter", 25usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).PushRetrievalFilter)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn PopRetrievalFilter(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "PopRetrievalFilter", 26usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).PopRetrievalFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn GetRetrievalFilterStackSize(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "GetRetrievalFilterStackSize", 27usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).GetRetrievalFilterStackSize)(this), |_| None)
+        }
+        unsafe extern "system" fn AddMessage(this: *mut core::ffi::c_void, category: D3D11_MESSAGE_CATEGORY, severity: D3D11_MESSAGE_SEVERITY, id: D3D11_MESSAGE_ID, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "AddMessage", 28usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).AddMessage)(this, category, severity, id, pdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddApplicationMessage(this: *mut core::ffi::c_void, severity: D3D11_MESSAGE_SEVERITY, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "AddApplicationMessage", 29usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).AddApplicationMessage)(this, severity, pdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBreakOnCategory(this: *mut core::ffi::c_void, category: D3D11_MESSAGE_CATEGORY, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "SetBreakOnCategory", 30usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).SetBreakOnCategory)(this, category, benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBreakOnSeverity(this: *mut core::ffi::c_void, severity: D3D11_MESSAGE_SEVERITY, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "SetBreakOnSeverity", 31usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).SetBreakOnSeverity)(this, severity, benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBreakOnID(this: *mut core::ffi::c_void, id: D3D11_MESSAGE_ID, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "SetBreakOnID", 32usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).SetBreakOnID)(this, id, benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBreakOnCategory(this: *mut core::ffi::c_void, category: D3D11_MESSAGE_CATEGORY) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "GetBreakOnCategory", 33usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).GetBreakOnCategory)(this, category), |_| None)
+        }
+        unsafe extern "system" fn GetBreakOnSeverity(this: *mut core::ffi::c_void, severity: D3D11_MESSAGE_SEVERITY) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "GetBreakOnSeverity", 34usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).GetBreakOnSeverity)(this, severity), |_| None)
+        }
+        unsafe extern "system" fn GetBreakOnID(this: *mut core::ffi::c_void, id: D3D11_MESSAGE_ID) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "GetBreakOnID", 35usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).GetBreakOnID)(this, id), |_| None)
+        }
+        unsafe extern "system" fn SetMuteDebugOutput(this: *mut core::ffi::c_void, bmute: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "SetMuteDebugOutput", 36usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).SetMuteDebugOutput)(this, bmute), |_| None)
+        }
+        unsafe extern "system" fn GetMuteDebugOutput(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D11InfoQueue", "GetMuteDebugOutput", 37usize, |this| ((**(this as *const *const ID3D11InfoQueue_Vtbl)).GetMuteDebugOutput)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMessageCountLimit,
+            ClearStoredMessages,
+            GetMessage,
+            GetNumMessagesAllowedByStorageFilter,
+            GetNumMessagesDeniedByStorageFilter,
+            GetNumStoredMessages,
+            GetNumStoredMessagesAllowedByRetrievalFilter,
+            GetNumMessagesDiscardedByMessageCountLimit,
+            GetMessageCountLimit,
+            AddStorageFilterEntries,
+            GetStorageFilter,
+            ClearStorageFilter,
+            PushEmptyStorageFilter,
+            PushCopyOfStorageFilter,
+            PushStorageFilter,
+            PopStorageFilter,
+            GetStorageFilterStackSize,
+            AddRetrievalFilterEntries,
+            GetRetrievalFilter,
+            ClearRetrievalFilter,
+            PushEmptyRetrievalFilter,
+            PushCopyOfRetrievalFilter,
+            PushRetrievalFilter,
+            PopRetrievalFilter,
+            GetRetrievalFilterStackSize,
+            AddMessage,
+            AddApplicationMessage,
+            SetBreakOnCategory,
+            SetBreakOnSeverity,
+            SetBreakOnID,
+            GetBreakOnCategory,
+            GetBreakOnSeverity,
+            GetBreakOnID,
+            SetMuteDebugOutput,
+            GetMuteDebugOutput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11InfoQueue as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11InputLayout_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11InputLayout as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11LibraryReflection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_LIBRARY_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11LibraryReflection", "GetDesc", 3usize, |this| ((**(this as *const *const ID3D11LibraryReflection_Vtbl)).GetDesc)(this, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionByIndex(this: *mut core::ffi::c_void, functionindex: i32) -> Option<ID3D11FunctionReflection> {
+            windows_core::imp::proxy_call(this, "ID3D11LibraryReflection", "GetFunctionByIndex", 4usize, |this| ((**(this as *const *const ID3D11LibraryReflection_Vtbl)).GetFunctionByIndex)(this, functionindex), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc, GetFunctionByIndex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11LibraryReflection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
+impl windows_core::imp::ProxyVtable for ID3D11Linker_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn Link(this: *mut core::ffi::c_void, pentry: *mut core::ffi::c_void, pentryname: windows_core::PCSTR, ptargetname: windows_core::PCSTR, uflags: u32, ppshaderblob: *mut *mut core::ffi::c_void, pperrorbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Linker", "Link", 3usize, |this| ((**(this as *const *const ID3D11Linker_Vtbl)).Link)(this, pentry, pentryname, ptargetname, uflags, ppshaderblob, pperrorbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseLibrary(this: *mut core::ffi::c_void, plibrarymi: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Linker", "UseLibrary", 4usize, |this| ((**(this as *const *const ID3D11Linker_Vtbl)).UseLibrary)(this, plibrarymi), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddClipPlaneFromCBuffer(this: *mut core::ffi::c_void, ucbufferslot: u32, ucbufferentry: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Linker", "AddClipPlaneFromCBuffer", 5usize, |this| ((**(this as *const *const ID3D11Linker_Vtbl)).AddClipPlaneFromCBuffer)(this, ucbufferslot, ucbufferentry), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            Link,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            Link: 0,
+            UseLibrary,
+            AddClipPlaneFromCBuffer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Linker as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11LinkingNode_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11LinkingNode as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11Module_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, pnamespace: windows_core::PCSTR, ppmoduleinstance: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11Module", "CreateInstance", 3usize, |this| ((**(this as *const *const ID3D11Module_Vtbl)).CreateInstance)(this, pnamespace, ppmoduleinstance), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Module as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11ModuleInstance_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BindConstantBuffer(this: *mut core::ffi::c_void, usrcslot: u32, udstslot: u32, cbdstoffset: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ModuleInstance", "BindConstantBuffer", 3usize, |this| ((**(this as *const *const ID3D11ModuleInstance_Vtbl)).BindConstantBuffer)(this, usrcslot, udstslot, cbdstoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindConstantBufferByName(this: *mut core::ffi::c_void, pname: windows_core::PCSTR, udstslot: u32, cbdstoffset: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ModuleInstance", "BindConstantBufferByName", 4usize, |this| ((**(this as *const *const ID3D11ModuleInstance_Vtbl)).BindConstantBufferByName)(this, pname, udstslot, cbdstoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindResource(this: *mut core::ffi::c_void, usrcslot: u32, udstslot: u32, ucount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ModuleInstance", "BindResource", 5usize, |this| ((**(this as *const *const ID3D11ModuleInstance_Vtbl)).BindResource)(this, usrcslot, udstslot, ucount), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindResourceByName(this: *mut core::ffi::c_void, pname: windows_core::PCSTR, udstslot: u32, ucount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ModuleInstance", "BindResourceByName", 6usize, |this| ((**(this as *const *const ID3D11ModuleInstance_Vtbl)).BindResourceByName)(this, pname, udstslot, ucount), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindSampler(this: *mut core::ffi::c_void, usrcslot: u32, udstslot: u32, ucount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ModuleInstance", "BindSampler", 7usize, |this| ((**(this as *const *const ID3D11ModuleInstance_Vtbl)).BindSampler)(this, usrcslot, udstslot, ucount), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindSamplerByName(this: *mut core::ffi::c_void, pname: windows_core::PCSTR, udstslot: u32, ucount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ModuleInstance", "BindSamplerByName", 8usize, |this| ((**(this as *const *const ID3D11ModuleInstance_Vtbl)).BindSamplerByName)(this, pname, udstslot, ucount), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindUnorderedAccessView(this: *mut core::ffi::c_void, usrcslot: u32, udstslot: u32, ucount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ModuleInstance", "BindUnorderedAccessView", 9usize, |this| ((**(this as *const *const ID3D11ModuleInstance_Vtbl)).BindUnorderedAccessView)(this, usrcslot, udstslot, ucount), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindUnorderedAccessViewByName(this: *mut core::ffi::c_void, pname: windows_core::PCSTR, udstslot: u32, ucount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ModuleInstance", "BindUnorderedAccessViewByName", 10usize, |this| ((**(this as *const *const ID3D11ModuleInstance_Vtbl)).BindUnorderedAccessViewByName)(this, pname, udstslot, ucount), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindResourceAsUnorderedAccessView(this: *mut core::ffi::c_void, usrcsrvslot: u32, udstuavslot: u32, ucount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ModuleInstance", "BindResourceAsUnorderedAccessView", 11usize, |this| ((**(this as *const *const ID3D11ModuleInstance_Vtbl)).BindResourceAsUnorderedAccessView)(this, usrcsrvslot, udstuavslot, ucount), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindResourceAsUnorderedAccessViewByName(this: *mut core::ffi::c_void, psrvname: windows_core::PCSTR, udstuavslot: u32, ucount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ModuleInstance", "BindResourceAsUnorderedAccessViewByName", 12usize, |this| ((**(this as *const *const ID3D11ModuleInstance_Vtbl)).BindResourceAsUnorderedAccessViewByName)(this, psrvname, udstuavslot, ucount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BindConstantBuffer,
+            BindConstantBufferByName,
+            BindResource,
+            BindResourceByName,
+            BindSampler,
+            BindSamplerByName,
+            BindUnorderedAccessView,
+            BindUnorderedAccessViewByName,
+            BindResourceAsUnorderedAccessView,
+            BindResourceAsUnorderedAccessViewByName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11ModuleInstance as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11Multithread_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enter(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11Multithread", "Enter", 3usize, |this| ((**(this as *const *const ID3D11Multithread_Vtbl)).Enter)(this), |_| None)
+        }
+        unsafe extern "system" fn Leave(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11Multithread", "Leave", 4usize, |this| ((**(this as *const *const ID3D11Multithread_Vtbl)).Leave)(this), |_| None)
+        }
+        unsafe extern "system" fn SetMultithreadProtected(this: *mut core::ffi::c_void, bmtprotect: super::super::Foundation::BOOL) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D11Multithread", "SetMultithreadProtected", 5usize, |this| ((**(this as *const *const ID3D11Multithread_Vtbl)).SetMultithreadProtected)(this, bmtprotect), |_| None)
+        }
+        unsafe extern "system" fn GetMultithreadProtected(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D11Multithread", "GetMultithreadProtected", 6usize, |this| ((**(this as *const *const ID3D11Multithread_Vtbl)).GetMultithreadProtected)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Enter, Leave, SetMultithreadProtected, GetMultithreadProtected }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Multithread as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11PixelShader_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11PixelShader as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11Predicate_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D11Query_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Predicate as windows_core::Interface>::IID || <ID3D11Query_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11Query_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_QUERY_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11Query", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D11Query_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D11Asynchronous_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Query as windows_core::Interface>::IID || <ID3D11Asynchronous_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11Query1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc1(this: *mut core::ffi::c_void, pdesc1: *mut D3D11_QUERY_DESC1) {
+            windows_core::imp::proxy_call(this, "ID3D11Query1", "GetDesc1", 9usize, |this| ((**(this as *const *const ID3D11Query1_Vtbl)).GetDesc1)(this, pdesc1), |_| None)
+        }
+        Self { base__: <ID3D11Query_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Query1 as windows_core::Interface>::IID || <ID3D11Query_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11RasterizerState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_RASTERIZER_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11RasterizerState", "GetDesc", 7usize, |this| ((**(this as *const *const ID3D11RasterizerState_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11RasterizerState as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11RasterizerState1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc1(this: *mut core::ffi::c_void, pdesc: *mut D3D11_RASTERIZER_DESC1) {
+            windows_core::imp::proxy_call(this, "ID3D11RasterizerState1", "GetDesc1", 8usize, |this| ((**(this as *const *const ID3D11RasterizerState1_Vtbl)).GetDesc1)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D11RasterizerState_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11RasterizerState1 as windows_core::Interface>::IID || <ID3D11RasterizerState_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11RasterizerState2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc2(this: *mut core::ffi::c_void, pdesc: *mut D3D11_RASTERIZER_DESC2) {
+            windows_core::imp::proxy_call(this, "ID3D11RasterizerState2", "GetDesc2", 9usize, |this| ((**(this as *const *const ID3D11RasterizerState2_Vtbl)).GetDesc2)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D11RasterizerState1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11RasterizerState2 as windows_core::Interface>::IID || <ID3D11RasterizerState1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11RefDefaultTrackingOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTrackingOptions(this: *mut core::ffi::c_void, resourcetypeflags: u32, options: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11RefDefaultTrackingOptions", "SetTrackingOptions", 3usize, |this| ((**(this as *const *const ID3D11RefDefaultTrackingOptions_Vtbl)).SetTrackingOptions)(this, resourcetypeflags, options), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTrackingOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11RefDefaultTrackingOptions as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11RefTrackingOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTrackingOptions(this: *mut core::ffi::c_void, uoptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11RefTrackingOptions", "SetTrackingOptions", 3usize, |this| ((**(this as *const *const ID3D11RefTrackingOptions_Vtbl)).SetTrackingOptions)(this, uoptions), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTrackingOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11RefTrackingOptions as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11RenderTargetView_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_RENDER_TARGET_VIEW_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11RenderTargetView", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D11RenderTargetView_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D11View_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11RenderTargetView as windows_core::Interface>::IID || <ID3D11View_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11RenderTargetView1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc1(this: *mut core::ffi::c_void, pdesc1: *mut D3D11_RENDER_TARGET_VIEW_DESC1) {
+            windows_core::imp::proxy_call(this, "ID3D11RenderTargetView1", "GetDesc1", 9usize, |this| ((**(this as *const *const ID3D11RenderTargetView1_Vtbl)).GetDesc1)(this, pdesc1), |_| None)
+        }
+        Self {
+            base__: <ID3D11RenderTargetView_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11RenderTargetView1 as windows_core::Interface>::IID || <ID3D11RenderTargetView_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11Resource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, presourcedimension: *mut D3D11_RESOURCE_DIMENSION) {
+            windows_core::imp::proxy_call(this, "ID3D11Resource", "GetType", 7usize, |this| ((**(this as *const *const ID3D11Resource_Vtbl)).GetType)(this, presourcedimension), |_| None)
+        }
+        unsafe extern "system" fn SetEvictionPriority(this: *mut core::ffi::c_void, evictionpriority: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11Resource", "SetEvictionPriority", 8usize, |this| ((**(this as *const *const ID3D11Resource_Vtbl)).SetEvictionPriority)(this, evictionpriority), |_| None)
+        }
+        unsafe extern "system" fn GetEvictionPriority(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11Resource", "GetEvictionPriority", 9usize, |this| ((**(this as *const *const ID3D11Resource_Vtbl)).GetEvictionPriority)(this), |_| None)
+        }
+        Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetType, SetEvictionPriority, GetEvictionPriority }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Resource as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11SamplerState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_SAMPLER_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11SamplerState", "GetDesc", 7usize, |this| ((**(this as *const *const ID3D11SamplerState_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11SamplerState as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
+impl windows_core::imp::ProxyVtable for ID3D11ShaderReflection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_SHADER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetDesc", 3usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetDesc)(this, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConstantBufferByIndex(this: *mut core::ffi::c_void, index: u32) -> Option<ID3D11ShaderReflectionConstantBuffer> {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetConstantBufferByIndex", 4usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetConstantBufferByIndex)(this, index), |_| None)
+        }
+        unsafe extern "system" fn GetConstantBufferByName(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D11ShaderReflectionConstantBuffer> {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetConstantBufferByName", 5usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetConstantBufferByName)(this, name), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetResourceBindingDesc(this: *mut core::ffi::c_void, resourceindex: u32, pdesc: *mut D3D11_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetResourceBindingDesc", 6usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetResourceBindingDesc)(this, resourceindex, pdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetInputParameterDesc(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D11_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetInputParameterDesc", 7usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetInputParameterDesc)(this, parameterindex, pdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetOutputParameterDesc(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D11_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetOutputParameterDesc", 8usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetOutputParameterDesc)(this, parameterindex, pdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetPatchConstantParameterDesc(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D11_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetPatchConstantParameterDesc", 9usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetPatchConstantParameterDesc)(this, parameterindex, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVariableByName(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D11ShaderReflectionVariable> {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetVariableByName", 10usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetVariableByName)(this, name), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetResourceBindingDescByName(this: *mut core::ffi::c_void, name: windows_core::PCSTR, pdesc: *mut D3D11_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetResourceBindingDescByName", 11usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetResourceBindingDescByName)(this, name, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMovInstructionCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetMovInstructionCount", 12usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetMovInstructionCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetMovcInstructionCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetMovcInstructionCount", 13usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetMovcInstructionCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetConversionInstructionCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetConversionInstructionCount", 14usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetConversionInstructionCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetBitwiseInstructionCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetBitwiseInstructionCount", 15usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetBitwiseInstructionCount)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetGSInputPrimitive(this: *mut core::ffi::c_void) -> super::Direct3D::D3D_PRIMITIVE {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetGSInputPrimitive", 16usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetGSInputPrimitive)(this), |_| None)
+        }
+        unsafe extern "system" fn IsSampleFrequencyShader(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "IsSampleFrequencyShader", 17usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).IsSampleFrequencyShader)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNumInterfaceSlots(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetNumInterfaceSlots", 18usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetNumInterfaceSlots)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetMinFeatureLevel(this: *mut core::ffi::c_void, plevel: *mut super::Direct3D::D3D_FEATURE_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetMinFeatureLevel", 19usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetMinFeatureLevel)(this, plevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThreadGroupSize(this: *mut core::ffi::c_void, psizex: *mut u32, psizey: *mut u32, psizez: *mut u32) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetThreadGroupSize", 20usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetThreadGroupSize)(this, psizex, psizey, psizez), |_| None)
+        }
+        unsafe extern "system" fn GetRequiresFlags(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderReflection", "GetRequiresFlags", 21usize, |this| ((**(this as *const *const ID3D11ShaderReflection_Vtbl)).GetRequiresFlags)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetDesc: 0,
+            GetConstantBufferByIndex,
+            GetConstantBufferByName,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetResourceBindingDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetResourceBindingDesc: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetInputParameterDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetInputParameterDesc: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetOutputParameterDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetOutputParameterDesc: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetPatchConstantParameterDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetPatchConstantParameterDesc: 0,
+            GetVariableByName,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetResourceBindingDescByName,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetResourceBindingDescByName: 0,
+            GetMovInstructionCount,
+            GetMovcInstructionCount,
+            GetConversionInstructionCount,
+            GetBitwiseInstructionCount,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetGSInputPrimitive,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetGSInputPrimitive: 0,
+            IsSampleFrequencyShader,
+            GetNumInterfaceSlots,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetMinFeatureLevel,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetMinFeatureLevel: 0,
+            GetThreadGroupSize,
+            GetRequiresFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11ShaderReflection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D11ShaderResourceView_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_SHADER_RESOURCE_VIEW_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderResourceView", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D11ShaderResourceView_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D11View_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common")))]
+            GetDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11ShaderResourceView as windows_core::Interface>::IID || <ID3D11View_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D11ShaderResourceView1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn GetDesc1(this: *mut core::ffi::c_void, pdesc1: *mut D3D11_SHADER_RESOURCE_VIEW_DESC1) {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderResourceView1", "GetDesc1", 9usize, |this| ((**(this as *const *const ID3D11ShaderResourceView1_Vtbl)).GetDesc1)(this, pdesc1), |_| None)
+        }
+        Self {
+            base__: <ID3D11ShaderResourceView_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc1,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common")))]
+            GetDesc1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11ShaderResourceView1 as windows_core::Interface>::IID || <ID3D11ShaderResourceView_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11ShaderTrace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TraceReady(this: *mut core::ffi::c_void, ptestcount: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderTrace", "TraceReady", 3usize, |this| ((**(this as *const *const ID3D11ShaderTrace_Vtbl)).TraceReady)(this, ptestcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetTrace(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderTrace", "ResetTrace", 4usize, |this| ((**(this as *const *const ID3D11ShaderTrace_Vtbl)).ResetTrace)(this), |_| None)
+        }
+        unsafe extern "system" fn GetTraceStats(this: *mut core::ffi::c_void, ptracestats: *mut D3D11_TRACE_STATS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderTrace", "GetTraceStats", 5usize, |this| ((**(this as *const *const ID3D11ShaderTrace_Vtbl)).GetTraceStats)(this, ptracestats), |result| Some(*result))
+        }
+        unsafe extern "system" fn PSSelectStamp(this: *mut core::ffi::c_void, stampindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderTrace", "PSSelectStamp", 6usize, |this| ((**(this as *const *const ID3D11ShaderTrace_Vtbl)).PSSelectStamp)(this, stampindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInitialRegisterContents(this: *mut core::ffi::c_void, pregister: *const D3D11_TRACE_REGISTER, pvalue: *mut D3D11_TRACE_VALUE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderTrace", "GetInitialRegisterContents", 7usize, |this| ((**(this as *const *const ID3D11ShaderTrace_Vtbl)).GetInitialRegisterContents)(this, pregister, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStep(this: *mut core::ffi::c_void, stepindex: u32, ptracestep: *mut D3D11_TRACE_STEP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderTrace", "GetStep", 8usize, |this| ((**(this as *const *const ID3D11ShaderTrace_Vtbl)).GetStep)(this, stepindex, ptracestep), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWrittenRegister(this: *mut core::ffi::c_void, stepindex: u32, writtenregisterindex: u32, pregister: *mut D3D11_TRACE_REGISTER, pvalue: *mut D3D11_TRACE_VALUE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderTrace", "GetWrittenRegister", 9usize, |this| ((**(this as *const *const ID3D11ShaderTrace_Vtbl)).GetWrittenRegister)(this, stepindex, writtenregisterindex, pregister, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReadRegister(this: *mut core::ffi::c_void, stepindex: u32, readregisterindex: u32, pregister: *mut D3D11_TRACE_REGISTER, pvalue: *mut D3D11_TRACE_VALUE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderTrace", "GetReadRegister", 10usize, |this| ((**(this as *const *const ID3D11ShaderTrace_Vtbl)).GetReadRegister)(this, stepindex, readregisterindex, pregister, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TraceReady,
+            ResetTrace,
+            GetTraceStats,
+            PSSelectStamp,
+            GetInitialRegisterContents,
+            GetStep,
+            GetWrittenRegister,
+            GetReadRegister,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11ShaderTrace as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11ShaderTraceFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateShaderTrace(this: *mut core::ffi::c_void, pshader: *mut core::ffi::c_void, ptracedesc: *const D3D11_SHADER_TRACE_DESC, ppshadertrace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11ShaderTraceFactory", "CreateShaderTrace", 3usize, |this| ((**(this as *const *const ID3D11ShaderTraceFactory_Vtbl)).CreateShaderTrace)(this, pshader, ptracedesc, ppshadertrace), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateShaderTrace }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11ShaderTraceFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11SwitchToRef_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetUseRef(this: *mut core::ffi::c_void, useref: super::super::Foundation::BOOL) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D11SwitchToRef", "SetUseRef", 3usize, |this| ((**(this as *const *const ID3D11SwitchToRef_Vtbl)).SetUseRef)(this, useref), |_| None)
+        }
+        unsafe extern "system" fn GetUseRef(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D11SwitchToRef", "GetUseRef", 4usize, |this| ((**(this as *const *const ID3D11SwitchToRef_Vtbl)).GetUseRef)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetUseRef, GetUseRef }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11SwitchToRef as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11Texture1D_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_TEXTURE1D_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11Texture1D", "GetDesc", 10usize, |this| ((**(this as *const *const ID3D11Texture1D_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D11Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Texture1D as windows_core::Interface>::IID || <ID3D11Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11Texture2D_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_TEXTURE2D_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11Texture2D", "GetDesc", 10usize, |this| ((**(this as *const *const ID3D11Texture2D_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D11Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Texture2D as windows_core::Interface>::IID || <ID3D11Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11Texture2D1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc1(this: *mut core::ffi::c_void, pdesc: *mut D3D11_TEXTURE2D_DESC1) {
+            windows_core::imp::proxy_call(this, "ID3D11Texture2D1", "GetDesc1", 11usize, |this| ((**(this as *const *const ID3D11Texture2D1_Vtbl)).GetDesc1)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D11Texture2D_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Texture2D1 as windows_core::Interface>::IID || <ID3D11Texture2D_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11Texture3D_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_TEXTURE3D_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11Texture3D", "GetDesc", 10usize, |this| ((**(this as *const *const ID3D11Texture3D_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D11Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Texture3D as windows_core::Interface>::IID || <ID3D11Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11Texture3D1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc1(this: *mut core::ffi::c_void, pdesc: *mut D3D11_TEXTURE3D_DESC1) {
+            windows_core::imp::proxy_call(this, "ID3D11Texture3D1", "GetDesc1", 11usize, |this| ((**(this as *const *const ID3D11Texture3D1_Vtbl)).GetDesc1)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D11Texture3D_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11Texture3D1 as windows_core::Interface>::IID || <ID3D11Texture3D_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11TracingDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetShaderTrackingOptionsByType(this: *mut core::ffi::c_void, resourcetypeflags: u32, options: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11TracingDevice", "SetShaderTrackingOptionsByType", 3usize, |this| ((**(this as *const *const ID3D11TracingDevice_Vtbl)).SetShaderTrackingOptionsByType)(this, resourcetypeflags, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShaderTrackingOptions(this: *mut core::ffi::c_void, pshader: *mut core::ffi::c_void, options: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11TracingDevice", "SetShaderTrackingOptions", 4usize, |this| ((**(this as *const *const ID3D11TracingDevice_Vtbl)).SetShaderTrackingOptions)(this, pshader, options), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetShaderTrackingOptionsByType, SetShaderTrackingOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11TracingDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11UnorderedAccessView_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_UNORDERED_ACCESS_VIEW_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11UnorderedAccessView", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D11UnorderedAccessView_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D11View_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11UnorderedAccessView as windows_core::Interface>::IID || <ID3D11View_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11UnorderedAccessView1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc1(this: *mut core::ffi::c_void, pdesc1: *mut D3D11_UNORDERED_ACCESS_VIEW_DESC1) {
+            windows_core::imp::proxy_call(this, "ID3D11UnorderedAccessView1", "GetDesc1", 9usize, |this| ((**(this as *const *const ID3D11UnorderedAccessView1_Vtbl)).GetDesc1)(this, pdesc1), |_| None)
+        }
+        Self {
+            base__: <ID3D11UnorderedAccessView_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11UnorderedAccessView1 as windows_core::Interface>::IID || <ID3D11UnorderedAccessView_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11VertexShader_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11VertexShader as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11VideoContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDecoderBuffer(this: *mut core::ffi::c_void, pdecoder: *mut core::ffi::c_void, r#type: D3D11_VIDEO_DECODER_BUFFER_TYPE, pbuffersize: *mut u32, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "GetDecoderBuffer", 7usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).GetDecoderBuffer)(this, pdecoder, r#type, pbuffersize, ppbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseDecoderBuffer(this: *mut core::ffi::c_void, pdecoder: *mut core::ffi::c_void, r#type: D3D11_VIDEO_DECODER_BUFFER_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "ReleaseDecoderBuffer", 8usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).ReleaseDecoderBuffer)(this, pdecoder, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecoderBeginFrame(this: *mut core::ffi::c_void, pdecoder: *mut core::ffi::c_void, pview: *mut core::ffi::c_void, contentkeysize: u32, pcontentkey: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "DecoderBeginFrame", 9usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).DecoderBeginFrame)(this, pdecoder, pview, contentkeysize, pcontentkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecoderEndFrame(this: *mut core::ffi::c_void, pdecoder: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "DecoderEndFrame", 10usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).DecoderEndFrame)(this, pdecoder), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubmitDecoderBuffers(this: *mut core::ffi::c_void, pdecoder: *mut core::ffi::c_void, numbuffers: u32, pbufferdesc: *const D3D11_VIDEO_DECODER_BUFFER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "SubmitDecoderBuffers", 11usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).SubmitDecoderBuffers)(this, pdecoder, numbuffers, pbufferdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecoderExtension(this: *mut core::ffi::c_void, pdecoder: *mut core::ffi::c_void, pextensiondata: *const D3D11_VIDEO_DECODER_EXTENSION) -> i32 {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "DecoderExtension", 12usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).DecoderExtension)(this, pdecoder, pextensiondata), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetOutputTargetRect(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, enable: super::super::Foundation::BOOL, prect: *const super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetOutputTargetRect", 13usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetOutputTargetRect)(this, pvideoprocessor, enable, prect), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetOutputBackgroundColor(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, ycbcr: super::super::Foundation::BOOL, pcolor: *const D3D11_VIDEO_COLOR) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetOutputBackgroundColor", 14usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetOutputBackgroundColor)(this, pvideoprocessor, ycbcr, pcolor), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetOutputColorSpace(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, pcolorspace: *const D3D11_VIDEO_PROCESSOR_COLOR_SPACE) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetOutputColorSpace", 15usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetOutputColorSpace)(this, pvideoprocessor, pcolorspace), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetOutputAlphaFillMode(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, alphafillmode: D3D11_VIDEO_PROCESSOR_ALPHA_FILL_MODE, streamindex: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetOutputAlphaFillMode", 16usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetOutputAlphaFillMode)(this, pvideoprocessor, alphafillmode, streamindex), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetOutputConstriction(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, enable: super::super::Foundation::BOOL, size: super::super::Foundation::SIZE) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetOutputConstriction", 17usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetOutputConstriction)(this, pvideoprocessor, enable, size), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetOutputStereoMode(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, enable: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetOutputStereoMode", 18usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetOutputStereoMode)(this, pvideoprocessor, enable), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetOutputExtension(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, pextensionguid: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetOutputExtension", 19usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetOutputExtension)(this, pvideoprocessor, pextensionguid, datasize, pdata), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetOutputTargetRect(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::BOOL, prect: *mut super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetOutputTargetRect", 20usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetOutputTargetRect)(this, pvideoprocessor, enabled, prect), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetOutputBackgroundColor(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, pycbcr: *mut super::super::Foundation::BOOL, pcolor: *mut D3D11_VIDEO_COLOR) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetOutputBackgroundColor", 21usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetOutputBackgroundColor)(this, pvideoprocessor, pycbcr, pcolor), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetOutputColorSpace(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, pcolorspace: *mut D3D11_VIDEO_PROCESSOR_COLOR_SPACE) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetOutputColorSpace", 22usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetOutputColorSpace)(this, pvideoprocessor, pcolorspace), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetOutputAlphaFillMode(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, palphafillmode: *mut D3D11_VIDEO_PROCESSOR_ALPHA_FILL_MODE, pstreamindex: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetOutputAlphaFillMode", 23usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetOutputAlphaFillMode)(this, pvideoprocessor, palphafillmode, pstreamindex), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetOutputConstriction(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, penabled: *mut super::super::Foundation::BOOL, psize: *mut super::super::Foundation::SIZE) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetOutputConstriction", 24usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetOutputConstriction)(this, pvideoprocessor, penabled, psize), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetOutputStereoMode(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, penabled: *mut super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetOutputStereoMode", 25usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetOutputStereoMode)(this, pvideoprocessor, penabled), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetOutputExtension(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, pextensionguid: *const windows_core::GUID, datasize: u32, pdata: *mut core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetOutputExtension", 26usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetOutputExtension)(this, pvideoprocessor, pextensionguid, datasize, pdata), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetStreamFrameFormat(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, frameformat: D3D11_VIDEO_FRAME_FORMAT) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetStreamFrameFormat", 27usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetStreamFrameFormat)(this, pvideoprocessor, streamindex, frameformat), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetStreamColorSpace(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, pcolorspace: *const D3D11_VIDEO_PROCESSOR_COLOR_SPACE) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetStreamColorSpace", 28usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetStreamColorSpace)(this, pvideoprocessor, streamindex, pcolorspace), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn VideoProcessorSetStreamOutputRate(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, outputrate: D3D11_VIDEO_PROCESSOR_OUTPUT_RATE, repeatframe: super::super::Foundation::BOOL, pcustomrate: *const super::Dxgi::Common::DXGI_RATIONAL) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetStreamOutputRate", 29usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetStreamOutputRate)(this, pvideoprocessor, streamindex, outputrate, repeatframe, pcustomrate), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetStreamSourceRect(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, enable: super::super::Foundation::BOOL, prect: *const super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetStreamSourceRect", 30usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetStreamSourceRect)(this, pvideoprocessor, streamindex, enable, prect), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetStreamDestRect(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, enable: super::super::Foundation::BOOL, prect: *const super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetStreamDestRect", 31usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetStreamDestRect)(this, pvideoprocessor, streamindex, enable, prect), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetStreamAlpha(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, enable: super::super::Foundation::BOOL, alpha: f32) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetStreamAlpha", 32usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetStreamAlpha)(this, pvideoprocessor, streamindex, enable, alpha), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetStreamPalette(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, count: u32, pentries: *const u32) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetStreamPalette", 33usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetStreamPalette)(this, pvideoprocessor, streamindex, count, pentries), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn VideoProcessorSetStreamPixelAspectRatio(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, enable: super::super::Foundation::BOOL, psourceaspectratio: *const super::Dxgi::Common::DXGI_RATIONAL, pdestinationaspectratio: *const super::Dxgi::Common::DXGI_RATIONAL) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetStreamPixelAspectRatio", 34usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetStreamPixelAspectRatio)(this, pvideoprocessor, streamindex, enable, psourceaspectratio, pdestinationaspectratio), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetStreamLumaKey(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, enable: super::super::Foundation::BOOL, lower: f32, upper: f32) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetStreamLumaKey", 35usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetStreamLumaKey)(this, pvideoprocessor, streamindex, enable, lower, upper), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetStreamStereoFormat(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, enable: super::super::Foundation::BOOL, format: D3D11_VIDEO_PROCESSOR_STEREO_FORMAT, leftviewframe0: super::super::Foundation::BOOL, baseviewframe0: super::super::Foundation::BOOL, flipmode: D3D11_VIDEO_PROCESSOR_STEREO_FLIP_MODE, monooffset: i32) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetStreamStereoFormat", 36usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetStreamStereoFormat)(this, pvideoprocessor, streamindex, enable, format, leftviewframe0, baseviewframe0, flipmode, monooffset), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetStreamAutoProcessingMode(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, enable: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetStreamAutoProcessingMode", 37usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetStreamAutoProcessingMode)(this, pvideoprocessor, streamindex, enable), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetStreamFilter(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, filter: D3D11_VIDEO_PROCESSOR_FILTER, enable: super::super::Foundation::BOOL, level: i32) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetStreamFilter", 38usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetStreamFilter)(this, pvideoprocessor, streamindex, filter, enable, level), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetStreamExtension(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, pextensionguid: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetStreamExtension", 39usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetStreamExtension)(this, pvideoprocessor, streamindex, pextensionguid, datasize, pdata), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetStreamFrameFormat(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, pframeformat: *mut D3D11_VIDEO_FRAME_FORMAT) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetStreamFrameFormat", 40usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetStreamFrameFormat)(this, pvideoprocessor, streamindex, pframeformat), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetStreamColorSpace(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, pcolorspace: *mut D3D11_VIDEO_PROCESSOR_COLOR_SPACE) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetStreamColorSpace", 41usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetStreamColorSpace)(this, pvideoprocessor, streamindex, pcolorspace), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn VideoProcessorGetStreamOutputRate(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, poutputrate: *mut D3D11_VIDEO_PROCESSOR_OUTPUT_RATE, prepeatframe: *mut super::super::Foundation::BOOL, pcustomrate: *mut super::Dxgi::Common::DXGI_RATIONAL) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetStreamOutputRate", 42usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetStreamOutputRate)(this, pvideoprocessor, streamindex, poutputrate, prepeatframe, pcustomrate), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetStreamSourceRect(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, penabled: *mut super::super::Foundation::BOOL, prect: *mut super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetStreamSourceRect", 43usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetStreamSourceRect)(this, pvideoprocessor, streamindex, penabled, prect), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetStreamDestRect(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, penabled: *mut super::super::Foundation::BOOL, prect: *mut super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetStreamDestRect", 44usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetStreamDestRect)(this, pvideoprocessor, streamindex, penabled, prect), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetStreamAlpha(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, penabled: *mut super::super::Foundation::BOOL, palpha: *mut f32) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetStreamAlpha", 45usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetStreamAlpha)(this, pvideoprocessor, streamindex, penabled, palpha), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetStreamPalette(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, count: u32, pentries: *mut u32) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetStreamPalette", 46usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetStreamPalette)(this, pvideoprocessor, streamindex, count, pentries), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn VideoProcessorGetStreamPixelAspectRatio(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, penabled: *mut super::super::Foundation::BOOL, psourceaspectratio: *mut super::Dxgi::Common::DXGI_RATIONAL, pdestinationaspectratio: *mut super::Dxgi::Common::DXGI_RATIONAL) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetStreamPixelAspectRatio", 47usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetStreamPixelAspectRatio)(this, pvideoprocessor, streamindex, penabled, psourceaspectratio, pdestinationaspectratio), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetStreamLumaKey(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, penabled: *mut super::super::Foundation::BOOL, plower: *mut f32, pupper: *mut f32) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetStreamLumaKey", 48usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetStreamLumaKey)(this, pvideoprocessor, streamindex, penabled, plower, pupper), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetStreamStereoFormat(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, penable: *mut super::super::Foundation::BOOL, pformat: *mut D3D11_VIDEO_PROCESSOR_STEREO_FORMAT, pleftviewframe0: *mut super::super::Foundation::BOOL, pbaseviewframe0: *mut super::super::Foundation::BOOL, pflipmode: *mut D3D11_VIDEO_PROCESSOR_STEREO_FLIP_MODE, monooffset: *mut i32) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetStreamStereoFormat", 49usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetStreamStereoFormat)(this, pvideoprocessor, streamindex, penable, pformat, pleftviewframe0, pbaseviewframe0, pflipmode, monooffset), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetStreamAutoProcessingMode(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, penabled: *mut super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetStreamAutoProcessingMode", 50usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetStreamAutoProcessingMode)(this, pvideoprocessor, streamindex, penabled), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetStreamFilter(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, filter: D3D11_VIDEO_PROCESSOR_FILTER, penabled: *mut super::super::Foundation::BOOL, plevel: *mut i32) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetStreamFilter", 51usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetStreamFilter)(this, pvideoprocessor, streamindex, filter, penabled, plevel), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetStreamExtension(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, pextensionguid: *const windows_core::GUID, datasize: u32, pdata: *mut core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetStreamExtension", 52usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetStreamExtension)(this, pvideoprocessor, streamindex, pextensionguid, datasize, pdata), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorBlt(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, pview: *mut core::ffi::c_void, outputframe: u32, streamcount: u32, pstreams: *const D3D11_VIDEO_PROCESSOR_STREAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorBlt", 53usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorBlt)(this, pvideoprocessor, pview, outputframe, streamcount, pstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn NegotiateCryptoSessionKeyExchange(this: *mut core::ffi::c_void, pcryptosession: *mut core::ffi::c_void, datasize: u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "NegotiateCryptoSessionKeyExchange", 54usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).NegotiateCryptoSessionKeyExchange)(this, pcryptosession, datasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncryptionBlt(this: *mut core::ffi::c_void, pcryptosession: *mut core::ffi::c_void, psrcsurface: *mut core::ffi::c_void, pdstsurface: *mut core::ffi::c_void, ivsize: u32, piv: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "EncryptionBlt", 55usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).EncryptionBlt)(this, pcryptosession, psrcsurface, pdstsurface, ivsize, piv), |_| None)
+        }
+        unsafe extern "system" fn DecryptionBlt(this: *mut core::ffi::c_void, pcryptosession: *mut core::ffi::c_void, psrcsurface: *mut core::ffi::c_void, pdstsurface: *mut core::ffi::c_void, pencryptedblockinfo: *const D3D11_ENCRYPTED_BLOCK_INFO, contentkeysize: u32, pcontentkey: *const core::ffi::c_void, ivsize: u32, piv: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "DecryptionBlt", 56usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).DecryptionBlt)(this, pcryptosession, psrcsurface, pdstsurface, pencryptedblockinfo, contentkeysize, pcontentkey, ivsize, piv), |_| None)
+        }
+        unsafe extern "system" fn StartSessionKeyRefresh(this: *mut core::ffi::c_void, pcryptosession: *mut core::ffi::c_void, randomnumbersize: u32, prandomnumber: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "StartSessionKeyRefresh", 57usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).StartSessionKeyRefresh)(this, pcryptosession, randomnumbersize, prandomnumber), |_| None)
+        }
+        unsafe extern "system" fn FinishSessionKeyRefresh(this: *mut core::ffi::c_void, pcryptosession: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "FinishSessionKeyRefresh", 58usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).FinishSessionKeyRefresh)(this, pcryptosession), |_| None)
+        }
+        unsafe extern "system" fn GetEncryptionBltKey(this: *mut core::ffi::c_void, pcryptosession: *mut core::ffi::c_void, keysize: u32, preadbackkey: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "GetEncryptionBltKey", 59usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).GetEncryptionBltKey)(this, pcryptosession, keysize, preadbackkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn NegotiateAuthenticatedChannelKeyExchange(this: *mut core::ffi::c_void, pchannel: *mut core::ffi::c_void, datasize: u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "NegotiateAuthenticatedChannelKeyExchange", 60usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).NegotiateAuthenticatedChannelKeyExchange)(this, pchannel, datasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryAuthenticatedChannel(this: *mut core::ffi::c_void, pchannel: *mut core::ffi::c_void, inputsize: u32, pinput: *const core::ffi::c_void, outputsize: u32, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "QueryAuthenticatedChannel", 61usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).QueryAuthenticatedChannel)(this, pchannel, inputsize, pinput, outputsize, poutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConfigureAuthenticatedChannel(this: *mut core::ffi::c_void, pchannel: *mut core::ffi::c_void, inputsize: u32, pinput: *const core::ffi::c_void, poutput: *mut D3D11_AUTHENTICATED_CONFIGURE_OUTPUT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "ConfigureAuthenticatedChannel", 62usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).ConfigureAuthenticatedChannel)(this, pchannel, inputsize, pinput, poutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoProcessorSetStreamRotation(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, enable: super::super::Foundation::BOOL, rotation: D3D11_VIDEO_PROCESSOR_ROTATION) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorSetStreamRotation", 63usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorSetStreamRotation)(this, pvideoprocessor, streamindex, enable, rotation), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetStreamRotation(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, penable: *mut super::super::Foundation::BOOL, protation: *mut D3D11_VIDEO_PROCESSOR_ROTATION) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext", "VideoProcessorGetStreamRotation", 64usize, |this| ((**(this as *const *const ID3D11VideoContext_Vtbl)).VideoProcessorGetStreamRotation)(this, pvideoprocessor, streamindex, penable, protation), |_| None)
+        }
+        Self {
+            base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDecoderBuffer,
+            ReleaseDecoderBuffer,
+            DecoderBeginFrame,
+            DecoderEndFrame,
+            SubmitDecoderBuffers,
+            DecoderExtension,
+            VideoProcessorSetOutputTargetRect,
+            VideoProcessorSetOutputBackgroundColor,
+            VideoProcessorSetOutputColorSpace,
+            VideoProcessorSetOutputAlphaFillMode,
+            VideoProcessorSetOutputConstriction,
+            VideoProcessorSetOutputStereoMode,
+            VideoProcessorSetOutputExtension,
+            VideoProcessorGetOutputTargetRect,
+            VideoProcessorGetOutputBackgroundColor,
+            VideoProcessorGetOutputColorSpace,
+            VideoProcessorGetOutputAlphaFillMode,
+            VideoProcessorGetOutputConstriction,
+            VideoProcessorGetOutputStereoMode,
+            VideoProcessorGetOutputExtension,
+            VideoProcessorSetStreamFrameFormat,
+            VideoProcessorSetStreamColorSpace,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            VideoProcessorSetStreamOutputRate,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            VideoProcessorSetStreamOutputRate: 0,
+            VideoProcessorSetStreamSourceRect,
+            VideoProcessorSetStreamDestRect,
+            VideoProcessorSetStreamAlpha,
+            VideoProcessorSetStreamPalette,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            VideoProcessorSetStreamPixelAspectRatio,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            VideoProcessorSetStreamPixelAspectRatio: 0,
+            VideoProcessorSetStreamLumaKey,
+            VideoProcessorSetStreamStereoFormat,
+            VideoProcessorSetStreamAutoProcessingMode,
+            VideoProcessorSetStreamFilter,
+            VideoProcessorSetStreamExtension,
+            VideoProcessorGetStreamFrameFormat,
+            VideoProcessorGetStreamColorSpace,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            VideoProcessorGetStreamOutputRate,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            VideoProcessorGetStreamOutputRate: 0,
+            VideoProcessorGetStreamSourceRect,
+            VideoProcessorGetStreamDestRect,
+            VideoProcessorGetStreamAlpha,
+            VideoProcessorGetStreamPalette,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            VideoProcessorGetStreamPixelAspectRatio,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            VideoProcessorGetStreamPixelAspectRatio: 0,
+            VideoProcessorGetStreamLumaKey,
+            VideoProcessorGetStreamStereoFormat,
+            VideoProcessorGetStreamAutoProcessingMode,
+            VideoProcessorGetStreamFilter,
+            VideoProcessorGetStreamExtension,
+            VideoProcessorBlt,
+            NegotiateCryptoSessionKeyExchange,
+            EncryptionBlt,
+            DecryptionBlt,
+            StartSessionKeyRefresh,
+            FinishSessionKeyRefresh,
+            GetEncryptionBltKey,
+            NegotiateAuthenticatedChannelKeyExchange,
+            QueryAuthenticatedChannel,
+            ConfigureAuthenticatedChannel,
+            VideoProcessorSetStreamRotation,
+            VideoProcessorGetStreamRotation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11VideoContext as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11VideoContext1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SubmitDecoderBuffers1(this: *mut core::ffi::c_void, pdecoder: *mut core::ffi::c_void, numbuffers: u32, pbufferdesc: *const D3D11_VIDEO_DECODER_BUFFER_DESC1) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext1", "SubmitDecoderBuffers1", 65usize, |this| ((**(this as *const *const ID3D11VideoContext1_Vtbl)).SubmitDecoderBuffers1)(this, pdecoder, numbuffers, pbufferdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataForNewHardwareKey(this: *mut core::ffi::c_void, pcryptosession: *mut core::ffi::c_void, privateinputsize: u32, pprivatinputdata: *const core::ffi::c_void, pprivateoutputdata: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext1", "GetDataForNewHardwareKey", 66usize, |this| ((**(this as *const *const ID3D11VideoContext1_Vtbl)).GetDataForNewHardwareKey)(this, pcryptosession, privateinputsize, pprivatinputdata, pprivateoutputdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckCryptoSessionStatus(this: *mut core::ffi::c_void, pcryptosession: *mut core::ffi::c_void, pstatus: *mut D3D11_CRYPTO_SESSION_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext1", "CheckCryptoSessionStatus", 67usize, |this| ((**(this as *const *const ID3D11VideoContext1_Vtbl)).CheckCryptoSessionStatus)(this, pcryptosession, pstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn DecoderEnableDownsampling(this: *mut core::ffi::c_void, pdecoder: *mut core::ffi::c_void, inputcolorspace: super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE, poutputdesc: *const D3D11_VIDEO_SAMPLE_DESC, referenceframecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext1", "DecoderEnableDownsampling", 68usize, |this| ((**(this as *const *const ID3D11VideoContext1_Vtbl)).DecoderEnableDownsampling)(this, pdecoder, inputcolorspace, poutputdesc, referenceframecount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn DecoderUpdateDownsampling(this: *mut core::ffi::c_void, pdecoder: *mut core::ffi::c_void, poutputdesc: *const D3D11_VIDEO_SAMPLE_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext1", "DecoderUpdateDownsampling", 69usize, |this| ((**(this as *const *const ID3D11VideoContext1_Vtbl)).DecoderUpdateDownsampling)(this, pdecoder, poutputdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn VideoProcessorSetOutputColorSpace1(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, colorspace: super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext1", "VideoProcessorSetOutputColorSpace1", 70usize, |this| ((**(this as *const *const ID3D11VideoContext1_Vtbl)).VideoProcessorSetOutputColorSpace1)(this, pvideoprocessor, colorspace), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetOutputShaderUsage(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, shaderusage: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext1", "VideoProcessorSetOutputShaderUsage", 71usize, |this| ((**(this as *const *const ID3D11VideoContext1_Vtbl)).VideoProcessorSetOutputShaderUsage)(this, pvideoprocessor, shaderusage), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn VideoProcessorGetOutputColorSpace1(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, pcolorspace: *mut super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext1", "VideoProcessorGetOutputColorSpace1", 72usize, |this| ((**(this as *const *const ID3D11VideoContext1_Vtbl)).VideoProcessorGetOutputColorSpace1)(this, pvideoprocessor, pcolorspace), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetOutputShaderUsage(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, pshaderusage: *mut super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext1", "VideoProcessorGetOutputShaderUsage", 73usize, |this| ((**(this as *const *const ID3D11VideoContext1_Vtbl)).VideoProcessorGetOutputShaderUsage)(this, pvideoprocessor, pshaderusage), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn VideoProcessorSetStreamColorSpace1(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, colorspace: super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext1", "VideoProcessorSetStreamColorSpace1", 74usize, |this| ((**(this as *const *const ID3D11VideoContext1_Vtbl)).VideoProcessorSetStreamColorSpace1)(this, pvideoprocessor, streamindex, colorspace), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorSetStreamMirror(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, enable: super::super::Foundation::BOOL, fliphorizontal: super::super::Foundation::BOOL, flipvertical: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext1", "VideoProcessorSetStreamMirror", 75usize, |this| ((**(this as *const *const ID3D11VideoContext1_Vtbl)).VideoProcessorSetStreamMirror)(this, pvideoprocessor, streamindex, enable, fliphorizontal, flipvertical), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn VideoProcessorGetStreamColorSpace1(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, pcolorspace: *mut super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext1", "VideoProcessorGetStreamColorSpace1", 76usize, |this| ((**(this as *const *const ID3D11VideoContext1_Vtbl)).VideoProcessorGetStreamColorSpace1)(this, pvideoprocessor, streamindex, pcolorspace), |_| None)
+        }
+        unsafe extern "system" fn VideoProcessorGetStreamMirror(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, penable: *mut super::super::Foundation::BOOL, pfliphorizontal: *mut super::super::Foundation::BOOL, pflipvertical: *mut super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext1", "VideoProcessorGetStreamMirror", 77usize, |this| ((**(this as *const *const ID3D11VideoContext1_Vtbl)).VideoProcessorGetStreamMirror)(this, pvideoprocessor, streamindex, penable, pfliphorizontal, pflipvertical), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn VideoProcessorGetBehaviorHints(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, outputwidth: u32, outputheight: u32, outputformat: super::Dxgi::Common::DXGI_FORMAT, streamcount: u32, pstreams: *const D3D11_VIDEO_PROCESSOR_STREAM_BEHAVIOR_HINT, pbehaviorhints: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext1", "VideoProcessorGetBehaviorHints", 78usize, |this| ((**(this as *const *const ID3D11VideoContext1_Vtbl)).VideoProcessorGetBehaviorHints)(this, pvideoprocessor, outputwidth, outputheight, outputformat, streamcount, pstreams, pbehaviorhints), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D11VideoContext_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SubmitDecoderBuffers1,
+            GetDataForNewHardwareKey,
+            CheckCryptoSessionStatus,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            DecoderEnableDownsampling,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            DecoderEnableDownsampling: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            DecoderUpdateDownsampling,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            DecoderUpdateDownsampling: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            VideoProcessorSetOutputColorSpace1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            VideoProcessorSetOutputColorSpace1: 0,
+            VideoProcessorSetOutputShaderUsage,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            VideoProcessorGetOutputColorSpace1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            VideoProcessorGetOutputColorSpace1: 0,
+            VideoProcessorGetOutputShaderUsage,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            VideoProcessorSetStreamColorSpace1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            VideoProcessorSetStreamColorSpace1: 0,
+            VideoProcessorSetStreamMirror,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            VideoProcessorGetStreamColorSpace1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            VideoProcessorGetStreamColorSpace1: 0,
+            VideoProcessorGetStreamMirror,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            VideoProcessorGetBehaviorHints,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            VideoProcessorGetBehaviorHints: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11VideoContext1 as windows_core::Interface>::IID || <ID3D11VideoContext_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11VideoContext2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn VideoProcessorSetOutputHDRMetaData(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, r#type: super::Dxgi::DXGI_HDR_METADATA_TYPE, size: u32, phdrmetadata: *const core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext2", "VideoProcessorSetOutputHDRMetaData", 79usize, |this| ((**(this as *const *const ID3D11VideoContext2_Vtbl)).VideoProcessorSetOutputHDRMetaData)(this, pvideoprocessor, r#type, size, phdrmetadata), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn VideoProcessorGetOutputHDRMetaData(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, ptype: *mut super::Dxgi::DXGI_HDR_METADATA_TYPE, size: u32, pmetadata: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext2", "VideoProcessorGetOutputHDRMetaData", 80usize, |this| ((**(this as *const *const ID3D11VideoContext2_Vtbl)).VideoProcessorGetOutputHDRMetaData)(this, pvideoprocessor, ptype, size, pmetadata), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn VideoProcessorSetStreamHDRMetaData(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, r#type: super::Dxgi::DXGI_HDR_METADATA_TYPE, size: u32, phdrmetadata: *const core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext2", "VideoProcessorSetStreamHDRMetaData", 81usize, |this| ((**(this as *const *const ID3D11VideoContext2_Vtbl)).VideoProcessorSetStreamHDRMetaData)(this, pvideoprocessor, streamindex, r#type, size, phdrmetadata), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn VideoProcessorGetStreamHDRMetaData(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, streamindex: u32, ptype: *mut super::Dxgi::DXGI_HDR_METADATA_TYPE, size: u32, pmetadata: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext2", "VideoProcessorGetStreamHDRMetaData", 82usize, |this| ((**(this as *const *const ID3D11VideoContext2_Vtbl)).VideoProcessorGetStreamHDRMetaData)(this, pvideoprocessor, streamindex, ptype, size, pmetadata), |_| None)
+        }
+        Self {
+            base__: <ID3D11VideoContext1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            VideoProcessorSetOutputHDRMetaData,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            VideoProcessorSetOutputHDRMetaData: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            VideoProcessorGetOutputHDRMetaData,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            VideoProcessorGetOutputHDRMetaData: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            VideoProcessorSetStreamHDRMetaData,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            VideoProcessorSetStreamHDRMetaData: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            VideoProcessorGetStreamHDRMetaData,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            VideoProcessorGetStreamHDRMetaData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11VideoContext2 as windows_core::Interface>::IID || <ID3D11VideoContext1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11VideoContext3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DecoderBeginFrame1(this: *mut core::ffi::c_void, pdecoder: *mut core::ffi::c_void, pview: *mut core::ffi::c_void, contentkeysize: u32, pcontentkey: *const core::ffi::c_void, numcomponenthistograms: u32, phistogramoffsets: *const u32, pphistogrambuffers: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext3", "DecoderBeginFrame1", 83usize, |this| ((**(this as *const *const ID3D11VideoContext3_Vtbl)).DecoderBeginFrame1)(this, pdecoder, pview, contentkeysize, pcontentkey, numcomponenthistograms, phistogramoffsets, pphistogrambuffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubmitDecoderBuffers2(this: *mut core::ffi::c_void, pdecoder: *mut core::ffi::c_void, numbuffers: u32, pbufferdesc: *const D3D11_VIDEO_DECODER_BUFFER_DESC2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoContext3", "SubmitDecoderBuffers2", 84usize, |this| ((**(this as *const *const ID3D11VideoContext3_Vtbl)).SubmitDecoderBuffers2)(this, pdecoder, numbuffers, pbufferdesc), |result| Some(*result))
+        }
+        Self { base__: <ID3D11VideoContext2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DecoderBeginFrame1, SubmitDecoderBuffers2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11VideoContext3 as windows_core::Interface>::IID || <ID3D11VideoContext2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11VideoDecoder_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetCreationParameters(this: *mut core::ffi::c_void, pvideodesc: *mut D3D11_VIDEO_DECODER_DESC, pconfig: *mut D3D11_VIDEO_DECODER_CONFIG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDecoder", "GetCreationParameters", 7usize, |this| ((**(this as *const *const ID3D11VideoDecoder_Vtbl)).GetCreationParameters)(this, pvideodesc, pconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDriverHandle(this: *mut core::ffi::c_void, pdriverhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDecoder", "GetDriverHandle", 8usize, |this| ((**(this as *const *const ID3D11VideoDecoder_Vtbl)).GetDriverHandle)(this, pdriverhandle), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetCreationParameters,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetCreationParameters: 0,
+            GetDriverHandle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11VideoDecoder as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11VideoDecoderOutputView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_VIDEO_DECODER_OUTPUT_VIEW_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDecoderOutputView", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D11VideoDecoderOutputView_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D11View_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11VideoDecoderOutputView as windows_core::Interface>::IID || <ID3D11View_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11VideoDevice_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateVideoDecoder(this: *mut core::ffi::c_void, pvideodesc: *const D3D11_VIDEO_DECODER_DESC, pconfig: *const D3D11_VIDEO_DECODER_CONFIG, ppdecoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "CreateVideoDecoder", 3usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).CreateVideoDecoder)(this, pvideodesc, pconfig, ppdecoder), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVideoProcessor(this: *mut core::ffi::c_void, penum: *mut core::ffi::c_void, rateconversionindex: u32, ppvideoprocessor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "CreateVideoProcessor", 4usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).CreateVideoProcessor)(this, penum, rateconversionindex, ppvideoprocessor), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAuthenticatedChannel(this: *mut core::ffi::c_void, channeltype: D3D11_AUTHENTICATED_CHANNEL_TYPE, ppauthenticatedchannel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "CreateAuthenticatedChannel", 5usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).CreateAuthenticatedChannel)(this, channeltype, ppauthenticatedchannel), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCryptoSession(this: *mut core::ffi::c_void, pcryptotype: *const windows_core::GUID, pdecoderprofile: *const windows_core::GUID, pkeyexchangetype: *const windows_core::GUID, ppcryptosession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "CreateCryptoSession", 6usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).CreateCryptoSession)(this, pcryptotype, pdecoderprofile, pkeyexchangetype, ppcryptosession), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVideoDecoderOutputView(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D11_VIDEO_DECODER_OUTPUT_VIEW_DESC, ppvdovview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "CreateVideoDecoderOutputView", 7usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).CreateVideoDecoderOutputView)(this, presource, pdesc, ppvdovview), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVideoProcessorInputView(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, penum: *mut core::ffi::c_void, pdesc: *const D3D11_VIDEO_PROCESSOR_INPUT_VIEW_DESC, ppvpiview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "CreateVideoProcessorInputView", 8usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).CreateVideoProcessorInputView)(this, presource, penum, pdesc, ppvpiview), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVideoProcessorOutputView(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, penum: *mut core::ffi::c_void, pdesc: *const D3D11_VIDEO_PROCESSOR_OUTPUT_VIEW_DESC, ppvpoview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "CreateVideoProcessorOutputView", 9usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).CreateVideoProcessorOutputView)(this, presource, penum, pdesc, ppvpoview), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateVideoProcessorEnumerator(this: *mut core::ffi::c_void, pdesc: *const D3D11_VIDEO_PROCESSOR_CONTENT_DESC, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "CreateVideoProcessorEnumerator", 10usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).CreateVideoProcessorEnumerator)(this, pdesc, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoDecoderProfileCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "GetVideoDecoderProfileCount", 11usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).GetVideoDecoderProfileCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetVideoDecoderProfile(this: *mut core::ffi::c_void, index: u32, pdecoderprofile: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "GetVideoDecoderProfile", 12usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).GetVideoDecoderProfile)(this, index, pdecoderprofile), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CheckVideoDecoderFormat(this: *mut core::ffi::c_void, pdecoderprofile: *const windows_core::GUID, format: super::Dxgi::Common::DXGI_FORMAT, psupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "CheckVideoDecoderFormat", 13usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).CheckVideoDecoderFormat)(this, pdecoderprofile, format, psupported), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetVideoDecoderConfigCount(this: *mut core::ffi::c_void, pdesc: *const D3D11_VIDEO_DECODER_DESC, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "GetVideoDecoderConfigCount", 14usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).GetVideoDecoderConfigCount)(this, pdesc, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetVideoDecoderConfig(this: *mut core::ffi::c_void, pdesc: *const D3D11_VIDEO_DECODER_DESC, index: u32, pconfig: *mut D3D11_VIDEO_DECODER_CONFIG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "GetVideoDecoderConfig", 15usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).GetVideoDecoderConfig)(this, pdesc, index, pconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentProtectionCaps(this: *mut core::ffi::c_void, pcryptotype: *const windows_core::GUID, pdecoderprofile: *const windows_core::GUID, pcaps: *mut D3D11_VIDEO_CONTENT_PROTECTION_CAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "GetContentProtectionCaps", 16usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).GetContentProtectionCaps)(this, pcryptotype, pdecoderprofile, pcaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckCryptoKeyExchange(this: *mut core::ffi::c_void, pcryptotype: *const windows_core::GUID, pdecoderprofile: *const windows_core::GUID, index: u32, pkeyexchangetype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "CheckCryptoKeyExchange", 17usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).CheckCryptoKeyExchange)(this, pcryptotype, pdecoderprofile, index, pkeyexchangetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "SetPrivateData", 18usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).SetPrivateData)(this, guid, datasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateDataInterface(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice", "SetPrivateDataInterface", 19usize, |this| ((**(this as *const *const ID3D11VideoDevice_Vtbl)).SetPrivateDataInterface)(this, guid, pdata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateVideoDecoder,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateVideoDecoder: 0,
+            CreateVideoProcessor,
+            CreateAuthenticatedChannel,
+            CreateCryptoSession,
+            CreateVideoDecoderOutputView,
+            CreateVideoProcessorInputView,
+            CreateVideoProcessorOutputView,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateVideoProcessorEnumerator,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateVideoProcessorEnumerator: 0,
+            GetVideoDecoderProfileCount,
+            GetVideoDecoderProfile,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CheckVideoDecoderFormat,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CheckVideoDecoderFormat: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetVideoDecoderConfigCount,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetVideoDecoderConfigCount: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetVideoDecoderConfig,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetVideoDecoderConfig: 0,
+            GetContentProtectionCaps,
+            CheckCryptoKeyExchange,
+            SetPrivateData,
+            SetPrivateDataInterface,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11VideoDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11VideoDevice1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCryptoSessionPrivateDataSize(this: *mut core::ffi::c_void, pcryptotype: *const windows_core::GUID, pdecoderprofile: *const windows_core::GUID, pkeyexchangetype: *const windows_core::GUID, pprivateinputsize: *mut u32, pprivateoutputsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice1", "GetCryptoSessionPrivateDataSize", 20usize, |this| ((**(this as *const *const ID3D11VideoDevice1_Vtbl)).GetCryptoSessionPrivateDataSize)(this, pcryptotype, pdecoderprofile, pkeyexchangetype, pprivateinputsize, pprivateoutputsize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetVideoDecoderCaps(this: *mut core::ffi::c_void, pdecoderprofile: *const windows_core::GUID, samplewidth: u32, sampleheight: u32, pframerate: *const super::Dxgi::Common::DXGI_RATIONAL, bitrate: u32, pcryptotype: *const windows_core::GUID, pdecodercaps: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice1", "GetVideoDecoderCaps", 21usize, |this| ((**(this as *const *const ID3D11VideoDevice1_Vtbl)).GetVideoDecoderCaps)(this, pdecoderprofile, samplewidth, sampleheight, pframerate, bitrate, pcryptotype, pdecodercaps), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CheckVideoDecoderDownsampling(this: *mut core::ffi::c_void, pinputdesc: *const D3D11_VIDEO_DECODER_DESC, inputcolorspace: super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE, pinputconfig: *const D3D11_VIDEO_DECODER_CONFIG, pframerate: *const super::Dxgi::Common::DXGI_RATIONAL, poutputdesc: *const D3D11_VIDEO_SAMPLE_DESC, psupported: *mut super::super::Foundation::BOOL, prealtimehint: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice1", "CheckVideoDecoderDownsampling", 22usize, |this| ((**(this as *const *const ID3D11VideoDevice1_Vtbl)).CheckVideoDecoderDownsampling)(this, pinputdesc, inputcolorspace, pinputconfig, pframerate, poutputdesc, psupported, prealtimehint), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn RecommendVideoDecoderDownsampleParameters(this: *mut core::ffi::c_void, pinputdesc: *const D3D11_VIDEO_DECODER_DESC, inputcolorspace: super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE, pinputconfig: *const D3D11_VIDEO_DECODER_CONFIG, pframerate: *const super::Dxgi::Common::DXGI_RATIONAL, precommendedoutputdesc: *mut D3D11_VIDEO_SAMPLE_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice1", "RecommendVideoDecoderDownsampleParameters", 23usize, |this| ((**(this as *const *const ID3D11VideoDevice1_Vtbl)).RecommendVideoDecoderDownsampleParameters)(this, pinputdesc, inputcolorspace, pinputconfig, pframerate, precommendedoutputdesc), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D11VideoDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCryptoSessionPrivateDataSize,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetVideoDecoderCaps,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetVideoDecoderCaps: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CheckVideoDecoderDownsampling,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CheckVideoDecoderDownsampling: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            RecommendVideoDecoderDownsampleParameters,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            RecommendVideoDecoderDownsampleParameters: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11VideoDevice1 as windows_core::Interface>::IID || <ID3D11VideoDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11VideoDevice2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CheckFeatureSupport(this: *mut core::ffi::c_void, feature: D3D11_FEATURE_VIDEO, pfeaturesupportdata: *mut core::ffi::c_void, featuresupportdatasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice2", "CheckFeatureSupport", 24usize, |this| ((**(this as *const *const ID3D11VideoDevice2_Vtbl)).CheckFeatureSupport)(this, feature, pfeaturesupportdata, featuresupportdatasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn NegotiateCryptoSessionKeyExchangeMT(this: *mut core::ffi::c_void, pcryptosession: *mut core::ffi::c_void, flags: D3D11_CRYPTO_SESSION_KEY_EXCHANGE_FLAGS, datasize: u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoDevice2", "NegotiateCryptoSessionKeyExchangeMT", 25usize, |this| ((**(this as *const *const ID3D11VideoDevice2_Vtbl)).NegotiateCryptoSessionKeyExchangeMT)(this, pcryptosession, flags, datasize, pdata), |result| Some(*result))
+        }
+        Self { base__: <ID3D11VideoDevice1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CheckFeatureSupport, NegotiateCryptoSessionKeyExchangeMT }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11VideoDevice2 as windows_core::Interface>::IID || <ID3D11VideoDevice1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11VideoProcessor_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetContentDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_VIDEO_PROCESSOR_CONTENT_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoProcessor", "GetContentDesc", 7usize, |this| ((**(this as *const *const ID3D11VideoProcessor_Vtbl)).GetContentDesc)(this, pdesc), |_| None)
+        }
+        unsafe extern "system" fn GetRateConversionCaps(this: *mut core::ffi::c_void, pcaps: *mut D3D11_VIDEO_PROCESSOR_RATE_CONVERSION_CAPS) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoProcessor", "GetRateConversionCaps", 8usize, |this| ((**(this as *const *const ID3D11VideoProcessor_Vtbl)).GetRateConversionCaps)(this, pcaps), |_| None)
+        }
+        Self {
+            base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetContentDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetContentDesc: 0,
+            GetRateConversionCaps,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11VideoProcessor as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11VideoProcessorEnumerator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetVideoProcessorContentDesc(this: *mut core::ffi::c_void, pcontentdesc: *mut D3D11_VIDEO_PROCESSOR_CONTENT_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoProcessorEnumerator", "GetVideoProcessorContentDesc", 7usize, |this| ((**(this as *const *const ID3D11VideoProcessorEnumerator_Vtbl)).GetVideoProcessorContentDesc)(this, pcontentdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CheckVideoProcessorFormat(this: *mut core::ffi::c_void, format: super::Dxgi::Common::DXGI_FORMAT, pflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoProcessorEnumerator", "CheckVideoProcessorFormat", 8usize, |this| ((**(this as *const *const ID3D11VideoProcessorEnumerator_Vtbl)).CheckVideoProcessorFormat)(this, format, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoProcessorCaps(this: *mut core::ffi::c_void, pcaps: *mut D3D11_VIDEO_PROCESSOR_CAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoProcessorEnumerator", "GetVideoProcessorCaps", 9usize, |this| ((**(this as *const *const ID3D11VideoProcessorEnumerator_Vtbl)).GetVideoProcessorCaps)(this, pcaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoProcessorRateConversionCaps(this: *mut core::ffi::c_void, typeindex: u32, pcaps: *mut D3D11_VIDEO_PROCESSOR_RATE_CONVERSION_CAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoProcessorEnumerator", "GetVideoProcessorRateConversionCaps", 10usize, |this| ((**(this as *const *const ID3D11VideoProcessorEnumerator_Vtbl)).GetVideoProcessorRateConversionCaps)(this, typeindex, pcaps), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetVideoProcessorCustomRate(this: *mut core::ffi::c_void, typeindex: u32, customrateindex: u32, prate: *mut D3D11_VIDEO_PROCESSOR_CUSTOM_RATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoProcessorEnumerator", "GetVideoProcessorCustomRate", 11usize, |this| ((**(this as *const *const ID3D11VideoProcessorEnumerator_Vtbl)).GetVideoProcessorCustomRate)(this, typeindex, customrateindex, prate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoProcessorFilterRange(this: *mut core::ffi::c_void, filter: D3D11_VIDEO_PROCESSOR_FILTER, prange: *mut D3D11_VIDEO_PROCESSOR_FILTER_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoProcessorEnumerator", "GetVideoProcessorFilterRange", 12usize, |this| ((**(this as *const *const ID3D11VideoProcessorEnumerator_Vtbl)).GetVideoProcessorFilterRange)(this, filter, prange), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetVideoProcessorContentDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetVideoProcessorContentDesc: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CheckVideoProcessorFormat,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CheckVideoProcessorFormat: 0,
+            GetVideoProcessorCaps,
+            GetVideoProcessorRateConversionCaps,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetVideoProcessorCustomRate,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetVideoProcessorCustomRate: 0,
+            GetVideoProcessorFilterRange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11VideoProcessorEnumerator as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D11VideoProcessorEnumerator1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CheckVideoProcessorFormatConversion(this: *mut core::ffi::c_void, inputformat: super::Dxgi::Common::DXGI_FORMAT, inputcolorspace: super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE, outputformat: super::Dxgi::Common::DXGI_FORMAT, outputcolorspace: super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE, psupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11VideoProcessorEnumerator1", "CheckVideoProcessorFormatConversion", 13usize, |this| ((**(this as *const *const ID3D11VideoProcessorEnumerator1_Vtbl)).CheckVideoProcessorFormatConversion)(this, inputformat, inputcolorspace, outputformat, outputcolorspace, psupported), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D11VideoProcessorEnumerator_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CheckVideoProcessorFormatConversion,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CheckVideoProcessorFormatConversion: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11VideoProcessorEnumerator1 as windows_core::Interface>::IID || <ID3D11VideoProcessorEnumerator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11VideoProcessorInputView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_VIDEO_PROCESSOR_INPUT_VIEW_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoProcessorInputView", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D11VideoProcessorInputView_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D11View_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11VideoProcessorInputView as windows_core::Interface>::IID || <ID3D11View_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11VideoProcessorOutputView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D11_VIDEO_PROCESSOR_OUTPUT_VIEW_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D11VideoProcessorOutputView", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D11VideoProcessorOutputView_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D11View_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11VideoProcessorOutputView as windows_core::Interface>::IID || <ID3D11View_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D11View_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetResource(this: *mut core::ffi::c_void, ppresource: *mut *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D11View", "GetResource", 7usize, |this| ((**(this as *const *const ID3D11View_Vtbl)).GetResource)(this, ppresource), |_| None)
+        }
+        Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetResource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11View as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3DDeviceContextState_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3DDeviceContextState as windows_core::Interface>::IID || <ID3D11DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3DUserDefinedAnnotation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginEvent(this: *mut core::ffi::c_void, name: windows_core::PCWSTR) -> i32 {
+            windows_core::imp::proxy_call(this, "ID3DUserDefinedAnnotation", "BeginEvent", 3usize, |this| ((**(this as *const *const ID3DUserDefinedAnnotation_Vtbl)).BeginEvent)(this, name), |_| None)
+        }
+        unsafe extern "system" fn EndEvent(this: *mut core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "ID3DUserDefinedAnnotation", "EndEvent", 4usize, |this| ((**(this as *const *const ID3DUserDefinedAnnotation_Vtbl)).EndEvent)(this), |_| None)
+        }
+        unsafe extern "system" fn SetMarker(this: *mut core::ffi::c_void, name: windows_core::PCWSTR) {
+            windows_core::imp::proxy_call(this, "ID3DUserDefinedAnnotation", "SetMarker", 5usize, |this| ((**(this as *const *const ID3DUserDefinedAnnotation_Vtbl)).SetMarker)(this, name), |_| None)
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3DUserDefinedAnnotation", "GetStatus", 6usize, |this| ((**(this as *const *const ID3DUserDefinedAnnotation_Vtbl)).GetStatus)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginEvent, EndEvent, SetMarker, GetStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3DUserDefinedAnnotation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3DX11FFT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetForwardScale(this: *mut core::ffi::c_void, forwardscale: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3DX11FFT", "SetForwardScale", 3usize, |this| ((**(this as *const *const ID3DX11FFT_Vtbl)).SetForwardScale)(this, forwardscale), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForwardScale(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "ID3DX11FFT", "GetForwardScale", 4usize, |this| ((**(this as *const *const ID3DX11FFT_Vtbl)).GetForwardScale)(this), |_| None)
+        }
+        unsafe extern "system" fn SetInverseScale(this: *mut core::ffi::c_void, inversescale: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3DX11FFT", "SetInverseScale", 5usize, |this| ((**(this as *const *const ID3DX11FFT_Vtbl)).SetInverseScale)(this, inversescale), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInverseScale(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "ID3DX11FFT", "GetInverseScale", 6usize, |this| ((**(this as *const *const ID3DX11FFT_Vtbl)).GetInverseScale)(this), |_| None)
+        }
+        unsafe extern "system" fn AttachBuffersAndPrecompute(this: *mut core::ffi::c_void, numtempbuffers: u32, pptempbuffers: *const *mut core::ffi::c_void, numprecomputebuffers: u32, ppprecomputebuffersizes: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3DX11FFT", "AttachBuffersAndPrecompute", 7usize, |this| ((**(this as *const *const ID3DX11FFT_Vtbl)).AttachBuffersAndPrecompute)(this, numtempbuffers, pptempbuffers, numprecomputebuffers, ppprecomputebuffersizes), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForwardTransform(this: *mut core::ffi::c_void, pinputbuffer: *mut core::ffi::c_void, ppoutputbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3DX11FFT", "ForwardTransform", 8usize, |this| ((**(this as *const *const ID3DX11FFT_Vtbl)).ForwardTransform)(this, pinputbuffer, ppoutputbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn InverseTransform(this: *mut core::ffi::c_void, pinputbuffer: *mut core::ffi::c_void, ppoutputbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3DX11FFT", "InverseTransform", 9usize, |this| ((**(this as *const *const ID3DX11FFT_Vtbl)).InverseTransform)(this, pinputbuffer, ppoutputbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetForwardScale,
+            GetForwardScale,
+            SetInverseScale,
+            GetInverseScale,
+            AttachBuffersAndPrecompute,
+            ForwardTransform,
+            InverseTransform,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3DX11FFT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3DX11Scan_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetScanDirection(this: *mut core::ffi::c_void, direction: D3DX11_SCAN_DIRECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3DX11Scan", "SetScanDirection", 3usize, |this| ((**(this as *const *const ID3DX11Scan_Vtbl)).SetScanDirection)(this, direction), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scan(this: *mut core::ffi::c_void, elementtype: D3DX11_SCAN_DATA_TYPE, opcode: D3DX11_SCAN_OPCODE, elementscansize: u32, psrc: *mut core::ffi::c_void, pdst: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3DX11Scan", "Scan", 4usize, |this| ((**(this as *const *const ID3DX11Scan_Vtbl)).Scan)(this, elementtype, opcode, elementscansize, psrc, pdst), |result| Some(*result))
+        }
+        unsafe extern "system" fn Multiscan(this: *mut core::ffi::c_void, elementtype: D3DX11_SCAN_DATA_TYPE, opcode: D3DX11_SCAN_OPCODE, elementscansize: u32, elementscanpitch: u32, scancount: u32, psrc: *mut core::ffi::c_void, pdst: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3DX11Scan", "Multiscan", 5usize, |this| ((**(this as *const *const ID3DX11Scan_Vtbl)).Multiscan)(this, elementtype, opcode, elementscansize, elementscanpitch, scancount, psrc, pdst), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetScanDirection, Scan, Multiscan }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3DX11Scan as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3DX11SegmentedScan_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetScanDirection(this: *mut core::ffi::c_void, direction: D3DX11_SCAN_DIRECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3DX11SegmentedScan", "SetScanDirection", 3usize, |this| ((**(this as *const *const ID3DX11SegmentedScan_Vtbl)).SetScanDirection)(this, direction), |result| Some(*result))
+        }
+        unsafe extern "system" fn SegScan(this: *mut core::ffi::c_void, elementtype: D3DX11_SCAN_DATA_TYPE, opcode: D3DX11_SCAN_OPCODE, elementscansize: u32, psrc: *mut core::ffi::c_void, psrcelementflags: *mut core::ffi::c_void, pdst: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3DX11SegmentedScan", "SegScan", 4usize, |this| ((**(this as *const *const ID3DX11SegmentedScan_Vtbl)).SegScan)(this, elementtype, opcode, elementscansize, psrc, psrcelementflags, pdst), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetScanDirection, SegScan }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3DX11SegmentedScan as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(all(feature = "Win32_Graphics_Direct3D11", feature = "Win32_Graphics_Direct3D12"))]
+impl windows_core::imp::ProxyVtable for ID3D11On12Device_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn CreateWrappedResource(this: *mut core::ffi::c_void, presource12: *mut core::ffi::c_void, pflags11: *const D3D11_RESOURCE_FLAGS, instate: super::Direct3D12::D3D12_RESOURCE_STATES, outstate: super::Direct3D12::D3D12_RESOURCE_STATES, riid: *const windows_core::GUID, ppresource11: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11On12Device", "CreateWrappedResource", 3usize, |this| ((**(this as *const *const ID3D11On12Device_Vtbl)).CreateWrappedResource)(this, presource12, pflags11, instate, outstate, riid, ppresource11), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D11")]
+        unsafe extern "system" fn ReleaseWrappedResources(this: *mut core::ffi::c_void, ppresources: *const *mut core::ffi::c_void, numresources: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11On12Device", "ReleaseWrappedResources", 4usize, |this| ((**(this as *const *const ID3D11On12Device_Vtbl)).ReleaseWrappedResources)(this, ppresources, numresources), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D11")]
+        unsafe extern "system" fn AcquireWrappedResources(this: *mut core::ffi::c_void, ppresources: *const *mut core::ffi::c_void, numresources: u32) {
+            windows_core::imp::proxy_call(this, "ID3D11On12Device", "AcquireWrappedResources", 5usize, |this| ((**(this as *const *const ID3D11On12Device_Vtbl)).AcquireWrappedResources)(this, ppresources, numresources), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            CreateWrappedResource,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            CreateWrappedResource: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D11")]
+            ReleaseWrappedResources,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D11"))]
+            ReleaseWrappedResources: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D11")]
+            AcquireWrappedResources,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D11"))]
+            AcquireWrappedResources: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11On12Device as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D11", feature = "Win32_Graphics_Direct3D12"))]
+impl windows_core::imp::ProxyVtable for ID3D11On12Device1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetD3D12Device(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11On12Device1", "GetD3D12Device", 6usize, |this| ((**(this as *const *const ID3D11On12Device1_Vtbl)).GetD3D12Device)(this, riid, ppvdevice), |result| Some(*result))
+        }
+        Self { base__: <ID3D11On12Device_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetD3D12Device }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11On12Device1 as windows_core::Interface>::IID || <ID3D11On12Device_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D11", feature = "Win32_Graphics_Direct3D12"))]
+impl windows_core::imp::ProxyVtable for ID3D11On12Device2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct3D11", feature = "Win32_Graphics_Direct3D12"))]
+        unsafe extern "system" fn UnwrapUnderlyingResource(this: *mut core::ffi::c_void, presource11: *mut core::ffi::c_void, pcommandqueue: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvresource12: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11On12Device2", "UnwrapUnderlyingResource", 7usize, |this| ((**(this as *const *const ID3D11On12Device2_Vtbl)).UnwrapUnderlyingResource)(this, presource11, pcommandqueue, riid, ppvresource12), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D11", feature = "Win32_Graphics_Direct3D12"))]
+        unsafe extern "system" fn ReturnUnderlyingResource(this: *mut core::ffi::c_void, presource11: *mut core::ffi::c_void, numsync: u32, psignalvalues: *const u64, ppfences: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D11On12Device2", "ReturnUnderlyingResource", 8usize, |this| ((**(this as *const *const ID3D11On12Device2_Vtbl)).ReturnUnderlyingResource)(this, presource11, numsync, psignalvalues, ppfences), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D11On12Device1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D11", feature = "Win32_Graphics_Direct3D12"))]
+            UnwrapUnderlyingResource,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D11", feature = "Win32_Graphics_Direct3D12")))]
+            UnwrapUnderlyingResource: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D11", feature = "Win32_Graphics_Direct3D12"))]
+            ReturnUnderlyingResource,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D11", feature = "Win32_Graphics_Direct3D12")))]
+            ReturnUnderlyingResource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D11On12Device2 as windows_core::Interface>::IID || <ID3D11On12Device1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
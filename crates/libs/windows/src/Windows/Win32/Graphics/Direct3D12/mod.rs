@@ -6511,6 +6511,7 @@ pub struct D3D12_AUTO_BREADCRUMB_OP(pub i32);
 impl windows_core::TypeKind for D3D12_AUTO_BREADCRUMB_OP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_AUTO_BREADCRUMB_OP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_AUTO_BREADCRUMB_OP").field(&self.0).finish()
@@ -6522,6 +6523,7 @@ pub struct D3D12_AXIS_SHADING_RATE(pub i32);
 impl windows_core::TypeKind for D3D12_AXIS_SHADING_RATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_AXIS_SHADING_RATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_AXIS_SHADING_RATE").field(&self.0).finish()
@@ -6533,6 +6535,7 @@ pub struct D3D12_BACKGROUND_PROCESSING_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_BACKGROUND_PROCESSING_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_BACKGROUND_PROCESSING_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_BACKGROUND_PROCESSING_MODE").field(&self.0).finish()
@@ -6544,6 +6547,7 @@ pub struct D3D12_BARRIER_ACCESS(pub i32);
 impl windows_core::TypeKind for D3D12_BARRIER_ACCESS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_BARRIER_ACCESS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_BARRIER_ACCESS").field(&self.0).finish()
@@ -6588,6 +6592,7 @@ pub struct D3D12_BARRIER_LAYOUT(pub i32);
 impl windows_core::TypeKind for D3D12_BARRIER_LAYOUT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_BARRIER_LAYOUT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_BARRIER_LAYOUT").field(&self.0).finish()
@@ -6599,6 +6604,7 @@ pub struct D3D12_BARRIER_SYNC(pub i32);
 impl windows_core::TypeKind for D3D12_BARRIER_SYNC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_BARRIER_SYNC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_BARRIER_SYNC").field(&self.0).finish()
@@ -6643,6 +6649,7 @@ pub struct D3D12_BARRIER_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_BARRIER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_BARRIER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_BARRIER_TYPE").field(&self.0).finish()
@@ -6654,6 +6661,7 @@ pub struct D3D12_BLEND(pub i32);
 impl windows_core::TypeKind for D3D12_BLEND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_BLEND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_BLEND").field(&self.0).finish()
@@ -6665,6 +6673,7 @@ pub struct D3D12_BLEND_OP(pub i32);
 impl windows_core::TypeKind for D3D12_BLEND_OP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_BLEND_OP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_BLEND_OP").field(&self.0).finish()
@@ -6676,6 +6685,7 @@ pub struct D3D12_BUFFER_SRV_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_BUFFER_SRV_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_BUFFER_SRV_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_BUFFER_SRV_FLAGS").field(&self.0).finish()
@@ -6720,6 +6730,7 @@ pub struct D3D12_BUFFER_UAV_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_BUFFER_UAV_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_BUFFER_UAV_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_BUFFER_UAV_FLAGS").field(&self.0).finish()
@@ -6764,6 +6775,7 @@ pub struct D3D12_CLEAR_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_CLEAR_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_CLEAR_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_CLEAR_FLAGS").field(&self.0).finish()
@@ -6808,6 +6820,7 @@ pub struct D3D12_COLOR_WRITE_ENABLE(pub i32);
 impl windows_core::TypeKind for D3D12_COLOR_WRITE_ENABLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_COLOR_WRITE_ENABLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_COLOR_WRITE_ENABLE").field(&self.0).finish()
@@ -6819,6 +6832,7 @@ pub struct D3D12_COMMAND_LIST_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_COMMAND_LIST_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_COMMAND_LIST_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_COMMAND_LIST_FLAGS").field(&self.0).finish()
@@ -6863,6 +6877,7 @@ pub struct D3D12_COMMAND_LIST_SUPPORT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_COMMAND_LIST_SUPPORT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_COMMAND_LIST_SUPPORT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_COMMAND_LIST_SUPPORT_FLAGS").field(&self.0).finish()
@@ -6907,6 +6922,7 @@ pub struct D3D12_COMMAND_LIST_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_COMMAND_LIST_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_COMMAND_LIST_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_COMMAND_LIST_TYPE").field(&self.0).finish()
@@ -6918,6 +6934,7 @@ pub struct D3D12_COMMAND_POOL_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_COMMAND_POOL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_COMMAND_POOL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_COMMAND_POOL_FLAGS").field(&self.0).finish()
@@ -6962,6 +6979,7 @@ pub struct D3D12_COMMAND_QUEUE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_COMMAND_QUEUE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_COMMAND_QUEUE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_COMMAND_QUEUE_FLAGS").field(&self.0).finish()
@@ -7006,6 +7024,7 @@ pub struct D3D12_COMMAND_QUEUE_PRIORITY(pub i32);
 impl windows_core::TypeKind for D3D12_COMMAND_QUEUE_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_COMMAND_QUEUE_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_COMMAND_QUEUE_PRIORITY").field(&self.0).finish()
@@ -7017,6 +7036,7 @@ pub struct D3D12_COMMAND_RECORDER_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_COMMAND_RECORDER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_COMMAND_RECORDER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_COMMAND_RECORDER_FLAGS").field(&self.0).finish()
@@ -7061,6 +7081,7 @@ pub struct D3D12_COMPARISON_FUNC(pub i32);
 impl windows_core::TypeKind for D3D12_COMPARISON_FUNC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_COMPARISON_FUNC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_COMPARISON_FUNC").field(&self.0).finish()
@@ -7072,6 +7093,7 @@ pub struct D3D12_CONSERVATIVE_RASTERIZATION_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_CONSERVATIVE_RASTERIZATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_CONSERVATIVE_RASTERIZATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_CONSERVATIVE_RASTERIZATION_MODE").field(&self.0).finish()
@@ -7083,6 +7105,7 @@ pub struct D3D12_CONSERVATIVE_RASTERIZATION_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_CONSERVATIVE_RASTERIZATION_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_CONSERVATIVE_RASTERIZATION_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_CONSERVATIVE_RASTERIZATION_TIER").field(&self.0).finish()
@@ -7094,6 +7117,7 @@ pub struct D3D12_CPU_PAGE_PROPERTY(pub i32);
 impl windows_core::TypeKind for D3D12_CPU_PAGE_PROPERTY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_CPU_PAGE_PROPERTY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_CPU_PAGE_PROPERTY").field(&self.0).finish()
@@ -7105,6 +7129,7 @@ pub struct D3D12_CROSS_NODE_SHARING_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_CROSS_NODE_SHARING_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_CROSS_NODE_SHARING_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_CROSS_NODE_SHARING_TIER").field(&self.0).finish()
@@ -7116,6 +7141,7 @@ pub struct D3D12_CULL_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_CULL_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_CULL_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_CULL_MODE").field(&self.0).finish()
@@ -7127,6 +7153,7 @@ pub struct D3D12_DEBUG_COMMAND_LIST_PARAMETER_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_DEBUG_COMMAND_LIST_PARAMETER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DEBUG_COMMAND_LIST_PARAMETER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DEBUG_COMMAND_LIST_PARAMETER_TYPE").field(&self.0).finish()
@@ -7138,6 +7165,7 @@ pub struct D3D12_DEBUG_DEVICE_PARAMETER_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_DEBUG_DEVICE_PARAMETER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DEBUG_DEVICE_PARAMETER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DEBUG_DEVICE_PARAMETER_TYPE").field(&self.0).finish()
@@ -7149,6 +7177,7 @@ pub struct D3D12_DEBUG_FEATURE(pub i32);
 impl windows_core::TypeKind for D3D12_DEBUG_FEATURE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DEBUG_FEATURE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DEBUG_FEATURE").field(&self.0).finish()
@@ -7193,6 +7222,7 @@ pub struct D3D12_DEPTH_WRITE_MASK(pub i32);
 impl windows_core::TypeKind for D3D12_DEPTH_WRITE_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DEPTH_WRITE_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DEPTH_WRITE_MASK").field(&self.0).finish()
@@ -7204,6 +7234,7 @@ pub struct D3D12_DESCRIPTOR_HEAP_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_DESCRIPTOR_HEAP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DESCRIPTOR_HEAP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DESCRIPTOR_HEAP_FLAGS").field(&self.0).finish()
@@ -7248,6 +7279,7 @@ pub struct D3D12_DESCRIPTOR_HEAP_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_DESCRIPTOR_HEAP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DESCRIPTOR_HEAP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DESCRIPTOR_HEAP_TYPE").field(&self.0).finish()
@@ -7259,6 +7291,7 @@ pub struct D3D12_DESCRIPTOR_RANGE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_DESCRIPTOR_RANGE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DESCRIPTOR_RANGE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DESCRIPTOR_RANGE_FLAGS").field(&self.0).finish()
@@ -7303,6 +7336,7 @@ pub struct D3D12_DESCRIPTOR_RANGE_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_DESCRIPTOR_RANGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DESCRIPTOR_RANGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DESCRIPTOR_RANGE_TYPE").field(&self.0).finish()
@@ -7314,6 +7348,7 @@ pub struct D3D12_DEVICE_FACTORY_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_DEVICE_FACTORY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DEVICE_FACTORY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DEVICE_FACTORY_FLAGS").field(&self.0).finish()
@@ -7358,6 +7393,7 @@ pub struct D3D12_DEVICE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_DEVICE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DEVICE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DEVICE_FLAGS").field(&self.0).finish()
@@ -7402,6 +7438,7 @@ pub struct D3D12_DISPATCH_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_DISPATCH_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DISPATCH_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DISPATCH_MODE").field(&self.0).finish()
@@ -7413,6 +7450,7 @@ pub struct D3D12_DRED_ALLOCATION_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_DRED_ALLOCATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DRED_ALLOCATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DRED_ALLOCATION_TYPE").field(&self.0).finish()
@@ -7424,6 +7462,7 @@ pub struct D3D12_DRED_DEVICE_STATE(pub i32);
 impl windows_core::TypeKind for D3D12_DRED_DEVICE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DRED_DEVICE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DRED_DEVICE_STATE").field(&self.0).finish()
@@ -7435,6 +7474,7 @@ pub struct D3D12_DRED_ENABLEMENT(pub i32);
 impl windows_core::TypeKind for D3D12_DRED_ENABLEMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DRED_ENABLEMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DRED_ENABLEMENT").field(&self.0).finish()
@@ -7446,6 +7486,7 @@ pub struct D3D12_DRED_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_DRED_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DRED_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DRED_FLAGS").field(&self.0).finish()
@@ -7490,6 +7531,7 @@ pub struct D3D12_DRED_PAGE_FAULT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_DRED_PAGE_FAULT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DRED_PAGE_FAULT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DRED_PAGE_FAULT_FLAGS").field(&self.0).finish()
@@ -7534,6 +7576,7 @@ pub struct D3D12_DRED_VERSION(pub i32);
 impl windows_core::TypeKind for D3D12_DRED_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DRED_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DRED_VERSION").field(&self.0).finish()
@@ -7545,6 +7588,7 @@ pub struct D3D12_DRIVER_MATCHING_IDENTIFIER_STATUS(pub i32);
 impl windows_core::TypeKind for D3D12_DRIVER_MATCHING_IDENTIFIER_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DRIVER_MATCHING_IDENTIFIER_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DRIVER_MATCHING_IDENTIFIER_STATUS").field(&self.0).finish()
@@ -7556,6 +7600,7 @@ pub struct D3D12_DSV_DIMENSION(pub i32);
 impl windows_core::TypeKind for D3D12_DSV_DIMENSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DSV_DIMENSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DSV_DIMENSION").field(&self.0).finish()
@@ -7567,6 +7612,7 @@ pub struct D3D12_DSV_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_DSV_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_DSV_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_DSV_FLAGS").field(&self.0).finish()
@@ -7611,6 +7657,7 @@ pub struct D3D12_ELEMENTS_LAYOUT(pub i32);
 impl windows_core::TypeKind for D3D12_ELEMENTS_LAYOUT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_ELEMENTS_LAYOUT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_ELEMENTS_LAYOUT").field(&self.0).finish()
@@ -7622,6 +7669,7 @@ pub struct D3D12_EXECUTE_INDIRECT_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_EXECUTE_INDIRECT_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_EXECUTE_INDIRECT_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_EXECUTE_INDIRECT_TIER").field(&self.0).finish()
@@ -7633,6 +7681,7 @@ pub struct D3D12_EXPORT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_EXPORT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_EXPORT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_EXPORT_FLAGS").field(&self.0).finish()
@@ -7677,6 +7726,7 @@ pub struct D3D12_FEATURE(pub i32);
 impl windows_core::TypeKind for D3D12_FEATURE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_FEATURE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_FEATURE").field(&self.0).finish()
@@ -7688,6 +7738,7 @@ pub struct D3D12_FENCE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_FENCE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_FENCE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_FENCE_FLAGS").field(&self.0).finish()
@@ -7732,6 +7783,7 @@ pub struct D3D12_FILL_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_FILL_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_FILL_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_FILL_MODE").field(&self.0).finish()
@@ -7743,6 +7795,7 @@ pub struct D3D12_FILTER(pub i32);
 impl windows_core::TypeKind for D3D12_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_FILTER").field(&self.0).finish()
@@ -7754,6 +7807,7 @@ pub struct D3D12_FILTER_REDUCTION_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_FILTER_REDUCTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_FILTER_REDUCTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_FILTER_REDUCTION_TYPE").field(&self.0).finish()
@@ -7765,6 +7819,7 @@ pub struct D3D12_FILTER_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_FILTER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_FILTER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_FILTER_TYPE").field(&self.0).finish()
@@ -7776,6 +7831,7 @@ pub struct D3D12_FORMAT_SUPPORT1(pub i32);
 impl windows_core::TypeKind for D3D12_FORMAT_SUPPORT1 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_FORMAT_SUPPORT1 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_FORMAT_SUPPORT1").field(&self.0).finish()
@@ -7820,6 +7876,7 @@ pub struct D3D12_FORMAT_SUPPORT2(pub i32);
 impl windows_core::TypeKind for D3D12_FORMAT_SUPPORT2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_FORMAT_SUPPORT2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_FORMAT_SUPPORT2").field(&self.0).finish()
@@ -7864,6 +7921,7 @@ pub struct D3D12_GPU_BASED_VALIDATION_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_GPU_BASED_VALIDATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_GPU_BASED_VALIDATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_GPU_BASED_VALIDATION_FLAGS").field(&self.0).finish()
@@ -7908,6 +7966,7 @@ pub struct D3D12_GPU_BASED_VALIDATION_PIPELINE_STATE_CREATE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_GPU_BASED_VALIDATION_PIPELINE_STATE_CREATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_GPU_BASED_VALIDATION_PIPELINE_STATE_CREATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_GPU_BASED_VALIDATION_PIPELINE_STATE_CREATE_FLAGS").field(&self.0).finish()
@@ -7952,6 +8011,7 @@ pub struct D3D12_GPU_BASED_VALIDATION_SHADER_PATCH_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_GPU_BASED_VALIDATION_SHADER_PATCH_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_GPU_BASED_VALIDATION_SHADER_PATCH_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_GPU_BASED_VALIDATION_SHADER_PATCH_MODE").field(&self.0).finish()
@@ -7963,6 +8023,7 @@ pub struct D3D12_GRAPHICS_STATES(pub i32);
 impl windows_core::TypeKind for D3D12_GRAPHICS_STATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_GRAPHICS_STATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_GRAPHICS_STATES").field(&self.0).finish()
@@ -8007,6 +8068,7 @@ pub struct D3D12_HEAP_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_HEAP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_HEAP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_HEAP_FLAGS").field(&self.0).finish()
@@ -8051,6 +8113,7 @@ pub struct D3D12_HEAP_SERIALIZATION_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_HEAP_SERIALIZATION_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_HEAP_SERIALIZATION_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_HEAP_SERIALIZATION_TIER").field(&self.0).finish()
@@ -8062,6 +8125,7 @@ pub struct D3D12_HEAP_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_HEAP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_HEAP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_HEAP_TYPE").field(&self.0).finish()
@@ -8073,6 +8137,7 @@ pub struct D3D12_HIT_GROUP_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_HIT_GROUP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_HIT_GROUP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_HIT_GROUP_TYPE").field(&self.0).finish()
@@ -8084,6 +8149,7 @@ pub struct D3D12_HIT_KIND(pub i32);
 impl windows_core::TypeKind for D3D12_HIT_KIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_HIT_KIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_HIT_KIND").field(&self.0).finish()
@@ -8095,6 +8161,7 @@ pub struct D3D12_INDEX_BUFFER_STRIP_CUT_VALUE(pub i32);
 impl windows_core::TypeKind for D3D12_INDEX_BUFFER_STRIP_CUT_VALUE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_INDEX_BUFFER_STRIP_CUT_VALUE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_INDEX_BUFFER_STRIP_CUT_VALUE").field(&self.0).finish()
@@ -8106,6 +8173,7 @@ pub struct D3D12_INDIRECT_ARGUMENT_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_INDIRECT_ARGUMENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_INDIRECT_ARGUMENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_INDIRECT_ARGUMENT_TYPE").field(&self.0).finish()
@@ -8117,6 +8185,7 @@ pub struct D3D12_INPUT_CLASSIFICATION(pub i32);
 impl windows_core::TypeKind for D3D12_INPUT_CLASSIFICATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_INPUT_CLASSIFICATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_INPUT_CLASSIFICATION").field(&self.0).finish()
@@ -8128,6 +8197,7 @@ pub struct D3D12_LIFETIME_STATE(pub i32);
 impl windows_core::TypeKind for D3D12_LIFETIME_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_LIFETIME_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_LIFETIME_STATE").field(&self.0).finish()
@@ -8139,6 +8209,7 @@ pub struct D3D12_LINE_RASTERIZATION_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_LINE_RASTERIZATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_LINE_RASTERIZATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_LINE_RASTERIZATION_MODE").field(&self.0).finish()
@@ -8150,6 +8221,7 @@ pub struct D3D12_LOGIC_OP(pub i32);
 impl windows_core::TypeKind for D3D12_LOGIC_OP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_LOGIC_OP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_LOGIC_OP").field(&self.0).finish()
@@ -8161,6 +8233,7 @@ pub struct D3D12_MEASUREMENTS_ACTION(pub i32);
 impl windows_core::TypeKind for D3D12_MEASUREMENTS_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_MEASUREMENTS_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_MEASUREMENTS_ACTION").field(&self.0).finish()
@@ -8172,6 +8245,7 @@ pub struct D3D12_MEMORY_POOL(pub i32);
 impl windows_core::TypeKind for D3D12_MEMORY_POOL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_MEMORY_POOL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_MEMORY_POOL").field(&self.0).finish()
@@ -8183,6 +8257,7 @@ pub struct D3D12_MESH_SHADER_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_MESH_SHADER_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_MESH_SHADER_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_MESH_SHADER_TIER").field(&self.0).finish()
@@ -8194,6 +8269,7 @@ pub struct D3D12_MESSAGE_CALLBACK_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_MESSAGE_CALLBACK_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_MESSAGE_CALLBACK_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_MESSAGE_CALLBACK_FLAGS").field(&self.0).finish()
@@ -8238,6 +8314,7 @@ pub struct D3D12_MESSAGE_CATEGORY(pub i32);
 impl windows_core::TypeKind for D3D12_MESSAGE_CATEGORY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_MESSAGE_CATEGORY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_MESSAGE_CATEGORY").field(&self.0).finish()
@@ -8249,6 +8326,7 @@ pub struct D3D12_MESSAGE_ID(pub i32);
 impl windows_core::TypeKind for D3D12_MESSAGE_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_MESSAGE_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_MESSAGE_ID").field(&self.0).finish()
@@ -8260,6 +8338,7 @@ pub struct D3D12_MESSAGE_SEVERITY(pub i32);
 impl windows_core::TypeKind for D3D12_MESSAGE_SEVERITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_MESSAGE_SEVERITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_MESSAGE_SEVERITY").field(&self.0).finish()
@@ -8271,6 +8350,7 @@ pub struct D3D12_META_COMMAND_PARAMETER_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_META_COMMAND_PARAMETER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_META_COMMAND_PARAMETER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_META_COMMAND_PARAMETER_FLAGS").field(&self.0).finish()
@@ -8315,6 +8395,7 @@ pub struct D3D12_META_COMMAND_PARAMETER_STAGE(pub i32);
 impl windows_core::TypeKind for D3D12_META_COMMAND_PARAMETER_STAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_META_COMMAND_PARAMETER_STAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_META_COMMAND_PARAMETER_STAGE").field(&self.0).finish()
@@ -8326,6 +8407,7 @@ pub struct D3D12_META_COMMAND_PARAMETER_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_META_COMMAND_PARAMETER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_META_COMMAND_PARAMETER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_META_COMMAND_PARAMETER_TYPE").field(&self.0).finish()
@@ -8337,6 +8419,7 @@ pub struct D3D12_MULTIPLE_FENCE_WAIT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_MULTIPLE_FENCE_WAIT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_MULTIPLE_FENCE_WAIT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_MULTIPLE_FENCE_WAIT_FLAGS").field(&self.0).finish()
@@ -8381,6 +8464,7 @@ pub struct D3D12_MULTISAMPLE_QUALITY_LEVEL_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_MULTISAMPLE_QUALITY_LEVEL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_MULTISAMPLE_QUALITY_LEVEL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_MULTISAMPLE_QUALITY_LEVEL_FLAGS").field(&self.0).finish()
@@ -8425,6 +8509,7 @@ pub struct D3D12_NODE_OVERRIDES_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_NODE_OVERRIDES_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_NODE_OVERRIDES_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_NODE_OVERRIDES_TYPE").field(&self.0).finish()
@@ -8436,6 +8521,7 @@ pub struct D3D12_NODE_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_NODE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_NODE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_NODE_TYPE").field(&self.0).finish()
@@ -8447,6 +8533,7 @@ pub struct D3D12_PIPELINE_STATE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_PIPELINE_STATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_PIPELINE_STATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_PIPELINE_STATE_FLAGS").field(&self.0).finish()
@@ -8491,6 +8578,7 @@ pub struct D3D12_PIPELINE_STATE_SUBOBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_PIPELINE_STATE_SUBOBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_PIPELINE_STATE_SUBOBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_PIPELINE_STATE_SUBOBJECT_TYPE").field(&self.0).finish()
@@ -8502,6 +8590,7 @@ pub struct D3D12_PREDICATION_OP(pub i32);
 impl windows_core::TypeKind for D3D12_PREDICATION_OP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_PREDICATION_OP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_PREDICATION_OP").field(&self.0).finish()
@@ -8513,6 +8602,7 @@ pub struct D3D12_PRIMITIVE_TOPOLOGY_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_PRIMITIVE_TOPOLOGY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_PRIMITIVE_TOPOLOGY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_PRIMITIVE_TOPOLOGY_TYPE").field(&self.0).finish()
@@ -8524,6 +8614,7 @@ pub struct D3D12_PROGRAMMABLE_SAMPLE_POSITIONS_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_PROGRAMMABLE_SAMPLE_POSITIONS_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_PROGRAMMABLE_SAMPLE_POSITIONS_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_PROGRAMMABLE_SAMPLE_POSITIONS_TIER").field(&self.0).finish()
@@ -8535,6 +8626,7 @@ pub struct D3D12_PROGRAM_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_PROGRAM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_PROGRAM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_PROGRAM_TYPE").field(&self.0).finish()
@@ -8546,6 +8638,7 @@ pub struct D3D12_PROTECTED_RESOURCE_SESSION_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_PROTECTED_RESOURCE_SESSION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_PROTECTED_RESOURCE_SESSION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_PROTECTED_RESOURCE_SESSION_FLAGS").field(&self.0).finish()
@@ -8590,6 +8683,7 @@ pub struct D3D12_PROTECTED_RESOURCE_SESSION_SUPPORT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_PROTECTED_RESOURCE_SESSION_SUPPORT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_PROTECTED_RESOURCE_SESSION_SUPPORT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_PROTECTED_RESOURCE_SESSION_SUPPORT_FLAGS").field(&self.0).finish()
@@ -8634,6 +8728,7 @@ pub struct D3D12_PROTECTED_SESSION_STATUS(pub i32);
 impl windows_core::TypeKind for D3D12_PROTECTED_SESSION_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_PROTECTED_SESSION_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_PROTECTED_SESSION_STATUS").field(&self.0).finish()
@@ -8645,6 +8740,7 @@ pub struct D3D12_QUERY_HEAP_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_QUERY_HEAP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_QUERY_HEAP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_QUERY_HEAP_TYPE").field(&self.0).finish()
@@ -8656,6 +8752,7 @@ pub struct D3D12_QUERY_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_QUERY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_QUERY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_QUERY_TYPE").field(&self.0).finish()
@@ -8667,6 +8764,7 @@ pub struct D3D12_RAYTRACING_ACCELERATION_STRUCTURE_BUILD_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_RAYTRACING_ACCELERATION_STRUCTURE_BUILD_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RAYTRACING_ACCELERATION_STRUCTURE_BUILD_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RAYTRACING_ACCELERATION_STRUCTURE_BUILD_FLAGS").field(&self.0).finish()
@@ -8711,6 +8809,7 @@ pub struct D3D12_RAYTRACING_ACCELERATION_STRUCTURE_COPY_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_RAYTRACING_ACCELERATION_STRUCTURE_COPY_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RAYTRACING_ACCELERATION_STRUCTURE_COPY_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RAYTRACING_ACCELERATION_STRUCTURE_COPY_MODE").field(&self.0).finish()
@@ -8722,6 +8821,7 @@ pub struct D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_TYPE").field(&self.0).finish()
@@ -8733,6 +8833,7 @@ pub struct D3D12_RAYTRACING_ACCELERATION_STRUCTURE_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_RAYTRACING_ACCELERATION_STRUCTURE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RAYTRACING_ACCELERATION_STRUCTURE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RAYTRACING_ACCELERATION_STRUCTURE_TYPE").field(&self.0).finish()
@@ -8744,6 +8845,7 @@ pub struct D3D12_RAYTRACING_GEOMETRY_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_RAYTRACING_GEOMETRY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RAYTRACING_GEOMETRY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RAYTRACING_GEOMETRY_FLAGS").field(&self.0).finish()
@@ -8788,6 +8890,7 @@ pub struct D3D12_RAYTRACING_GEOMETRY_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_RAYTRACING_GEOMETRY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RAYTRACING_GEOMETRY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RAYTRACING_GEOMETRY_TYPE").field(&self.0).finish()
@@ -8799,6 +8902,7 @@ pub struct D3D12_RAYTRACING_INSTANCE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_RAYTRACING_INSTANCE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RAYTRACING_INSTANCE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RAYTRACING_INSTANCE_FLAGS").field(&self.0).finish()
@@ -8843,6 +8947,7 @@ pub struct D3D12_RAYTRACING_PIPELINE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_RAYTRACING_PIPELINE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RAYTRACING_PIPELINE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RAYTRACING_PIPELINE_FLAGS").field(&self.0).finish()
@@ -8887,6 +8992,7 @@ pub struct D3D12_RAYTRACING_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_RAYTRACING_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RAYTRACING_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RAYTRACING_TIER").field(&self.0).finish()
@@ -8898,6 +9004,7 @@ pub struct D3D12_RAY_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_RAY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RAY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RAY_FLAGS").field(&self.0).finish()
@@ -8942,6 +9049,7 @@ pub struct D3D12_RECREATE_AT_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_RECREATE_AT_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RECREATE_AT_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RECREATE_AT_TIER").field(&self.0).finish()
@@ -8953,6 +9061,7 @@ pub struct D3D12_RENDER_PASS_BEGINNING_ACCESS_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_RENDER_PASS_BEGINNING_ACCESS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RENDER_PASS_BEGINNING_ACCESS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RENDER_PASS_BEGINNING_ACCESS_TYPE").field(&self.0).finish()
@@ -8964,6 +9073,7 @@ pub struct D3D12_RENDER_PASS_ENDING_ACCESS_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_RENDER_PASS_ENDING_ACCESS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RENDER_PASS_ENDING_ACCESS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RENDER_PASS_ENDING_ACCESS_TYPE").field(&self.0).finish()
@@ -8975,6 +9085,7 @@ pub struct D3D12_RENDER_PASS_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_RENDER_PASS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RENDER_PASS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RENDER_PASS_FLAGS").field(&self.0).finish()
@@ -9019,6 +9130,7 @@ pub struct D3D12_RENDER_PASS_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_RENDER_PASS_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RENDER_PASS_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RENDER_PASS_TIER").field(&self.0).finish()
@@ -9030,6 +9142,7 @@ pub struct D3D12_RESIDENCY_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_RESIDENCY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RESIDENCY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RESIDENCY_FLAGS").field(&self.0).finish()
@@ -9074,6 +9187,7 @@ pub struct D3D12_RESIDENCY_PRIORITY(pub i32);
 impl windows_core::TypeKind for D3D12_RESIDENCY_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RESIDENCY_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RESIDENCY_PRIORITY").field(&self.0).finish()
@@ -9085,6 +9199,7 @@ pub struct D3D12_RESOLVE_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_RESOLVE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RESOLVE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RESOLVE_MODE").field(&self.0).finish()
@@ -9096,6 +9211,7 @@ pub struct D3D12_RESOURCE_BARRIER_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_RESOURCE_BARRIER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RESOURCE_BARRIER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RESOURCE_BARRIER_FLAGS").field(&self.0).finish()
@@ -9140,6 +9256,7 @@ pub struct D3D12_RESOURCE_BARRIER_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_RESOURCE_BARRIER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RESOURCE_BARRIER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RESOURCE_BARRIER_TYPE").field(&self.0).finish()
@@ -9151,6 +9268,7 @@ pub struct D3D12_RESOURCE_BINDING_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_RESOURCE_BINDING_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RESOURCE_BINDING_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RESOURCE_BINDING_TIER").field(&self.0).finish()
@@ -9162,6 +9280,7 @@ pub struct D3D12_RESOURCE_DIMENSION(pub i32);
 impl windows_core::TypeKind for D3D12_RESOURCE_DIMENSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RESOURCE_DIMENSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RESOURCE_DIMENSION").field(&self.0).finish()
@@ -9173,6 +9292,7 @@ pub struct D3D12_RESOURCE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_RESOURCE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RESOURCE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RESOURCE_FLAGS").field(&self.0).finish()
@@ -9217,6 +9337,7 @@ pub struct D3D12_RESOURCE_HEAP_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_RESOURCE_HEAP_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RESOURCE_HEAP_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RESOURCE_HEAP_TIER").field(&self.0).finish()
@@ -9228,6 +9349,7 @@ pub struct D3D12_RESOURCE_STATES(pub i32);
 impl windows_core::TypeKind for D3D12_RESOURCE_STATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RESOURCE_STATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RESOURCE_STATES").field(&self.0).finish()
@@ -9272,6 +9394,7 @@ pub struct D3D12_RLDO_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_RLDO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RLDO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RLDO_FLAGS").field(&self.0).finish()
@@ -9316,6 +9439,7 @@ pub struct D3D12_ROOT_DESCRIPTOR_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_ROOT_DESCRIPTOR_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_ROOT_DESCRIPTOR_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_ROOT_DESCRIPTOR_FLAGS").field(&self.0).finish()
@@ -9360,6 +9484,7 @@ pub struct D3D12_ROOT_PARAMETER_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_ROOT_PARAMETER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_ROOT_PARAMETER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_ROOT_PARAMETER_TYPE").field(&self.0).finish()
@@ -9371,6 +9496,7 @@ pub struct D3D12_ROOT_SIGNATURE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_ROOT_SIGNATURE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_ROOT_SIGNATURE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_ROOT_SIGNATURE_FLAGS").field(&self.0).finish()
@@ -9415,6 +9541,7 @@ pub struct D3D12_RTV_DIMENSION(pub i32);
 impl windows_core::TypeKind for D3D12_RTV_DIMENSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_RTV_DIMENSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_RTV_DIMENSION").field(&self.0).finish()
@@ -9426,6 +9553,7 @@ pub struct D3D12_SAMPLER_FEEDBACK_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_SAMPLER_FEEDBACK_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SAMPLER_FEEDBACK_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SAMPLER_FEEDBACK_TIER").field(&self.0).finish()
@@ -9437,6 +9565,7 @@ pub struct D3D12_SAMPLER_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_SAMPLER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SAMPLER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SAMPLER_FLAGS").field(&self.0).finish()
@@ -9481,6 +9610,7 @@ pub struct D3D12_SERIALIZED_DATA_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_SERIALIZED_DATA_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SERIALIZED_DATA_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SERIALIZED_DATA_TYPE").field(&self.0).finish()
@@ -9492,6 +9622,7 @@ pub struct D3D12_SET_WORK_GRAPH_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_SET_WORK_GRAPH_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SET_WORK_GRAPH_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SET_WORK_GRAPH_FLAGS").field(&self.0).finish()
@@ -9536,6 +9667,7 @@ pub struct D3D12_SHADER_CACHE_CONTROL_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_SHADER_CACHE_CONTROL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SHADER_CACHE_CONTROL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SHADER_CACHE_CONTROL_FLAGS").field(&self.0).finish()
@@ -9580,6 +9712,7 @@ pub struct D3D12_SHADER_CACHE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_SHADER_CACHE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SHADER_CACHE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SHADER_CACHE_FLAGS").field(&self.0).finish()
@@ -9624,6 +9757,7 @@ pub struct D3D12_SHADER_CACHE_KIND_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_SHADER_CACHE_KIND_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SHADER_CACHE_KIND_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SHADER_CACHE_KIND_FLAGS").field(&self.0).finish()
@@ -9668,6 +9802,7 @@ pub struct D3D12_SHADER_CACHE_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_SHADER_CACHE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SHADER_CACHE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SHADER_CACHE_MODE").field(&self.0).finish()
@@ -9679,6 +9814,7 @@ pub struct D3D12_SHADER_CACHE_SUPPORT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_SHADER_CACHE_SUPPORT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SHADER_CACHE_SUPPORT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SHADER_CACHE_SUPPORT_FLAGS").field(&self.0).finish()
@@ -9723,6 +9859,7 @@ pub struct D3D12_SHADER_COMPONENT_MAPPING(pub i32);
 impl windows_core::TypeKind for D3D12_SHADER_COMPONENT_MAPPING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SHADER_COMPONENT_MAPPING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SHADER_COMPONENT_MAPPING").field(&self.0).finish()
@@ -9734,6 +9871,7 @@ pub struct D3D12_SHADER_MIN_PRECISION_SUPPORT(pub i32);
 impl windows_core::TypeKind for D3D12_SHADER_MIN_PRECISION_SUPPORT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SHADER_MIN_PRECISION_SUPPORT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SHADER_MIN_PRECISION_SUPPORT").field(&self.0).finish()
@@ -9778,6 +9916,7 @@ pub struct D3D12_SHADER_VERSION_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_SHADER_VERSION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SHADER_VERSION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SHADER_VERSION_TYPE").field(&self.0).finish()
@@ -9789,6 +9928,7 @@ pub struct D3D12_SHADER_VISIBILITY(pub i32);
 impl windows_core::TypeKind for D3D12_SHADER_VISIBILITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SHADER_VISIBILITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SHADER_VISIBILITY").field(&self.0).finish()
@@ -9800,6 +9940,7 @@ pub struct D3D12_SHADING_RATE(pub i32);
 impl windows_core::TypeKind for D3D12_SHADING_RATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SHADING_RATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SHADING_RATE").field(&self.0).finish()
@@ -9811,6 +9952,7 @@ pub struct D3D12_SHADING_RATE_COMBINER(pub i32);
 impl windows_core::TypeKind for D3D12_SHADING_RATE_COMBINER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SHADING_RATE_COMBINER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SHADING_RATE_COMBINER").field(&self.0).finish()
@@ -9822,6 +9964,7 @@ pub struct D3D12_SHARED_RESOURCE_COMPATIBILITY_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_SHARED_RESOURCE_COMPATIBILITY_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SHARED_RESOURCE_COMPATIBILITY_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SHARED_RESOURCE_COMPATIBILITY_TIER").field(&self.0).finish()
@@ -9833,6 +9976,7 @@ pub struct D3D12_SRV_DIMENSION(pub i32);
 impl windows_core::TypeKind for D3D12_SRV_DIMENSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_SRV_DIMENSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_SRV_DIMENSION").field(&self.0).finish()
@@ -9844,6 +9988,7 @@ pub struct D3D12_STANDARD_MULTISAMPLE_QUALITY_LEVELS(pub i32);
 impl windows_core::TypeKind for D3D12_STANDARD_MULTISAMPLE_QUALITY_LEVELS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_STANDARD_MULTISAMPLE_QUALITY_LEVELS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_STANDARD_MULTISAMPLE_QUALITY_LEVELS").field(&self.0).finish()
@@ -9855,6 +10000,7 @@ pub struct D3D12_STATE_OBJECT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_STATE_OBJECT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_STATE_OBJECT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_STATE_OBJECT_FLAGS").field(&self.0).finish()
@@ -9899,6 +10045,7 @@ pub struct D3D12_STATE_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_STATE_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_STATE_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_STATE_OBJECT_TYPE").field(&self.0).finish()
@@ -9910,6 +10057,7 @@ pub struct D3D12_STATE_SUBOBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_STATE_SUBOBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_STATE_SUBOBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_STATE_SUBOBJECT_TYPE").field(&self.0).finish()
@@ -9921,6 +10069,7 @@ pub struct D3D12_STATIC_BORDER_COLOR(pub i32);
 impl windows_core::TypeKind for D3D12_STATIC_BORDER_COLOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_STATIC_BORDER_COLOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_STATIC_BORDER_COLOR").field(&self.0).finish()
@@ -9932,6 +10081,7 @@ pub struct D3D12_STENCIL_OP(pub i32);
 impl windows_core::TypeKind for D3D12_STENCIL_OP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_STENCIL_OP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_STENCIL_OP").field(&self.0).finish()
@@ -9943,6 +10093,7 @@ pub struct D3D12_TEXTURE_ADDRESS_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_TEXTURE_ADDRESS_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_TEXTURE_ADDRESS_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_TEXTURE_ADDRESS_MODE").field(&self.0).finish()
@@ -9954,6 +10105,7 @@ pub struct D3D12_TEXTURE_BARRIER_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_TEXTURE_BARRIER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_TEXTURE_BARRIER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_TEXTURE_BARRIER_FLAGS").field(&self.0).finish()
@@ -9998,6 +10150,7 @@ pub struct D3D12_TEXTURE_COPY_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_TEXTURE_COPY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_TEXTURE_COPY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_TEXTURE_COPY_TYPE").field(&self.0).finish()
@@ -10009,6 +10162,7 @@ pub struct D3D12_TEXTURE_LAYOUT(pub i32);
 impl windows_core::TypeKind for D3D12_TEXTURE_LAYOUT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_TEXTURE_LAYOUT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_TEXTURE_LAYOUT").field(&self.0).finish()
@@ -10020,6 +10174,7 @@ pub struct D3D12_TILED_RESOURCES_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_TILED_RESOURCES_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_TILED_RESOURCES_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_TILED_RESOURCES_TIER").field(&self.0).finish()
@@ -10031,6 +10186,7 @@ pub struct D3D12_TILE_COPY_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_TILE_COPY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_TILE_COPY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_TILE_COPY_FLAGS").field(&self.0).finish()
@@ -10075,6 +10231,7 @@ pub struct D3D12_TILE_MAPPING_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_TILE_MAPPING_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_TILE_MAPPING_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_TILE_MAPPING_FLAGS").field(&self.0).finish()
@@ -10119,6 +10276,7 @@ pub struct D3D12_TILE_RANGE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_TILE_RANGE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_TILE_RANGE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_TILE_RANGE_FLAGS").field(&self.0).finish()
@@ -10130,6 +10288,7 @@ pub struct D3D12_TRI_STATE(pub i32);
 impl windows_core::TypeKind for D3D12_TRI_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_TRI_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_TRI_STATE").field(&self.0).finish()
@@ -10141,6 +10300,7 @@ pub struct D3D12_UAV_DIMENSION(pub i32);
 impl windows_core::TypeKind for D3D12_UAV_DIMENSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_UAV_DIMENSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_UAV_DIMENSION").field(&self.0).finish()
@@ -10152,6 +10312,7 @@ pub struct D3D12_VARIABLE_SHADING_RATE_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_VARIABLE_SHADING_RATE_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VARIABLE_SHADING_RATE_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VARIABLE_SHADING_RATE_TIER").field(&self.0).finish()
@@ -10163,6 +10324,7 @@ pub struct D3D12_VIEW_INSTANCING_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIEW_INSTANCING_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIEW_INSTANCING_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIEW_INSTANCING_FLAGS").field(&self.0).finish()
@@ -10207,6 +10369,7 @@ pub struct D3D12_VIEW_INSTANCING_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_VIEW_INSTANCING_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIEW_INSTANCING_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIEW_INSTANCING_TIER").field(&self.0).finish()
@@ -10218,6 +10381,7 @@ pub struct D3D12_WAVE_MMA_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_WAVE_MMA_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_WAVE_MMA_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_WAVE_MMA_TIER").field(&self.0).finish()
@@ -10229,6 +10393,7 @@ pub struct D3D12_WORK_GRAPHS_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_WORK_GRAPHS_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_WORK_GRAPHS_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_WORK_GRAPHS_TIER").field(&self.0).finish()
@@ -10240,6 +10405,7 @@ pub struct D3D12_WORK_GRAPH_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_WORK_GRAPH_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_WORK_GRAPH_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_WORK_GRAPH_FLAGS").field(&self.0).finish()
@@ -10284,6 +10450,7 @@ pub struct D3D12_WRITEBUFFERIMMEDIATE_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_WRITEBUFFERIMMEDIATE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_WRITEBUFFERIMMEDIATE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_WRITEBUFFERIMMEDIATE_MODE").field(&self.0).finish()
@@ -10295,6 +10462,7 @@ pub struct D3D_ROOT_SIGNATURE_VERSION(pub i32);
 impl windows_core::TypeKind for D3D_ROOT_SIGNATURE_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_ROOT_SIGNATURE_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_ROOT_SIGNATURE_VERSION").field(&self.0).finish()
@@ -10306,13 +10474,15 @@ pub struct D3D_SHADER_MODEL(pub i32);
 impl windows_core::TypeKind for D3D_SHADER_MODEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D_SHADER_MODEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D_SHADER_MODEL").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_AUTO_BREADCRUMB_NODE {
     pub pCommandListDebugNameA: *const u8,
     pub pCommandListDebugNameW: windows_core::PCWSTR,
@@ -10339,7 +10509,8 @@ impl Default for D3D12_AUTO_BREADCRUMB_NODE {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_AUTO_BREADCRUMB_NODE1 {
     pub pCommandListDebugNameA: *const u8,
     pub pCommandListDebugNameW: windows_core::PCWSTR,
@@ -10398,7 +10569,8 @@ impl Default for D3D12_BARRIER_GROUP_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_BARRIER_SUBRESOURCE_RANGE {
     pub IndexOrFirstMipLevel: u32,
     pub NumMipLevels: u32,
@@ -10416,7 +10588,8 @@ impl Default for D3D12_BARRIER_SUBRESOURCE_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_BLEND_DESC {
     pub AlphaToCoverageEnable: super::super::Foundation::BOOL,
     pub IndependentBlendEnable: super::super::Foundation::BOOL,
@@ -10431,7 +10604,8 @@ impl Default for D3D12_BLEND_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_BOX {
     pub left: u32,
     pub top: u32,
@@ -10449,7 +10623,8 @@ impl Default for D3D12_BOX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_BROADCASTING_LAUNCH_OVERRIDES {
     pub pLocalRootArgumentsTableIndex: *const u32,
     pub pProgramEntry: *const super::super::Foundation::BOOL,
@@ -10469,7 +10644,8 @@ impl Default for D3D12_BROADCASTING_LAUNCH_OVERRIDES {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_BUFFER_BARRIER {
     pub SyncBefore: D3D12_BARRIER_SYNC,
     pub SyncAfter: D3D12_BARRIER_SYNC,
@@ -10493,7 +10669,8 @@ impl Default for D3D12_BUFFER_BARRIER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_BUFFER_RTV {
     pub FirstElement: u64,
     pub NumElements: u32,
@@ -10507,7 +10684,8 @@ impl Default for D3D12_BUFFER_RTV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_BUFFER_SRV {
     pub FirstElement: u64,
     pub NumElements: u32,
@@ -10523,7 +10701,8 @@ impl Default for D3D12_BUFFER_SRV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_BUFFER_UAV {
     pub FirstElement: u64,
     pub NumElements: u32,
@@ -10597,7 +10776,8 @@ impl Default for D3D12_BUILD_RAYTRACING_ACCELERATION_STRUCTURE_INPUTS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_BUILD_RAYTRACING_ACCELERATION_STRUCTURE_TOOLS_VISUALIZATION_HEADER {
     pub Type: D3D12_RAYTRACING_ACCELERATION_STRUCTURE_TYPE,
     pub NumDescs: u32,
@@ -10611,7 +10791,8 @@ impl Default for D3D12_BUILD_RAYTRACING_ACCELERATION_STRUCTURE_TOOLS_VISUALIZATI
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_CACHED_PIPELINE_STATE {
     pub pCachedBlob: *const core::ffi::c_void,
     pub CachedBlobSizeInBytes: usize,
@@ -10659,7 +10840,8 @@ impl Default for D3D12_CLEAR_VALUE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_COALESCING_LAUNCH_OVERRIDES {
     pub pLocalRootArgumentsTableIndex: *const u32,
     pub pProgramEntry: *const super::super::Foundation::BOOL,
@@ -10677,7 +10859,8 @@ impl Default for D3D12_COALESCING_LAUNCH_OVERRIDES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_COMMAND_QUEUE_DESC {
     pub Type: D3D12_COMMAND_LIST_TYPE,
     pub Priority: i32,
@@ -10693,7 +10876,8 @@ impl Default for D3D12_COMMAND_QUEUE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_COMMAND_SIGNATURE_DESC {
     pub ByteStride: u32,
     pub NumArgumentDescs: u32,
@@ -10709,7 +10893,8 @@ impl Default for D3D12_COMMAND_SIGNATURE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_COMMON_COMPUTE_NODE_OVERRIDES {
     pub pLocalRootArgumentsTableIndex: *const u32,
     pub pProgramEntry: *const super::super::Foundation::BOOL,
@@ -10727,7 +10912,8 @@ impl Default for D3D12_COMMON_COMPUTE_NODE_OVERRIDES {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_COMPUTE_PIPELINE_STATE_DESC {
     pub pRootSignature: core::mem::ManuallyDrop<Option<ID3D12RootSignature>>,
     pub CS: D3D12_SHADER_BYTECODE,
@@ -10749,7 +10935,8 @@ impl Default for D3D12_COMPUTE_PIPELINE_STATE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_CONSTANT_BUFFER_VIEW_DESC {
     pub BufferLocation: u64,
     pub SizeInBytes: u32,
@@ -10763,7 +10950,8 @@ impl Default for D3D12_CONSTANT_BUFFER_VIEW_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_CPU_DESCRIPTOR_HANDLE {
     pub ptr: usize,
 }
@@ -10776,7 +10964,8 @@ impl Default for D3D12_CPU_DESCRIPTOR_HANDLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DEBUG_COMMAND_LIST_GPU_BASED_VALIDATION_SETTINGS {
     pub ShaderPatchMode: D3D12_GPU_BASED_VALIDATION_SHADER_PATCH_MODE,
 }
@@ -10789,7 +10978,8 @@ impl Default for D3D12_DEBUG_COMMAND_LIST_GPU_BASED_VALIDATION_SETTINGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DEBUG_DEVICE_GPU_BASED_VALIDATION_SETTINGS {
     pub MaxMessagesPerCommandList: u32,
     pub DefaultShaderPatchMode: D3D12_GPU_BASED_VALIDATION_SHADER_PATCH_MODE,
@@ -10804,7 +10994,8 @@ impl Default for D3D12_DEBUG_DEVICE_GPU_BASED_VALIDATION_SETTINGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DEBUG_DEVICE_GPU_SLOWDOWN_PERFORMANCE_FACTOR {
     pub SlowdownFactor: f32,
 }
@@ -10817,7 +11008,8 @@ impl Default for D3D12_DEBUG_DEVICE_GPU_SLOWDOWN_PERFORMANCE_FACTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DEPTH_STENCILOP_DESC {
     pub StencilFailOp: D3D12_STENCIL_OP,
     pub StencilDepthFailOp: D3D12_STENCIL_OP,
@@ -10833,7 +11025,8 @@ impl Default for D3D12_DEPTH_STENCILOP_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DEPTH_STENCILOP_DESC1 {
     pub StencilFailOp: D3D12_STENCIL_OP,
     pub StencilDepthFailOp: D3D12_STENCIL_OP,
@@ -10851,7 +11044,8 @@ impl Default for D3D12_DEPTH_STENCILOP_DESC1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DEPTH_STENCIL_DESC {
     pub DepthEnable: super::super::Foundation::BOOL,
     pub DepthWriteMask: D3D12_DEPTH_WRITE_MASK,
@@ -10871,7 +11065,8 @@ impl Default for D3D12_DEPTH_STENCIL_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DEPTH_STENCIL_DESC1 {
     pub DepthEnable: super::super::Foundation::BOOL,
     pub DepthWriteMask: D3D12_DEPTH_WRITE_MASK,
@@ -10892,7 +11087,8 @@ impl Default for D3D12_DEPTH_STENCIL_DESC1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DEPTH_STENCIL_DESC2 {
     pub DepthEnable: super::super::Foundation::BOOL,
     pub DepthWriteMask: D3D12_DEPTH_WRITE_MASK,
@@ -10912,7 +11108,8 @@ impl Default for D3D12_DEPTH_STENCIL_DESC2 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DEPTH_STENCIL_FORMAT {
     pub DepthStencilFormat: super::Dxgi::Common::DXGI_FORMAT,
 }
@@ -10927,7 +11124,8 @@ impl Default for D3D12_DEPTH_STENCIL_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DEPTH_STENCIL_VALUE {
     pub Depth: f32,
     pub Stencil: u8,
@@ -10981,7 +11179,8 @@ impl Default for D3D12_DEPTH_STENCIL_VIEW_DESC_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DESCRIPTOR_HEAP_DESC {
     pub Type: D3D12_DESCRIPTOR_HEAP_TYPE,
     pub NumDescriptors: u32,
@@ -10997,7 +11196,8 @@ impl Default for D3D12_DESCRIPTOR_HEAP_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DESCRIPTOR_RANGE {
     pub RangeType: D3D12_DESCRIPTOR_RANGE_TYPE,
     pub NumDescriptors: u32,
@@ -11014,7 +11214,8 @@ impl Default for D3D12_DESCRIPTOR_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DESCRIPTOR_RANGE1 {
     pub RangeType: D3D12_DESCRIPTOR_RANGE_TYPE,
     pub NumDescriptors: u32,
@@ -11032,7 +11233,8 @@ impl Default for D3D12_DESCRIPTOR_RANGE1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DEVICE_CONFIGURATION_DESC {
     pub Flags: D3D12_DEVICE_FLAGS,
     pub GpuBasedValidationFlags: u32,
@@ -11048,7 +11250,8 @@ impl Default for D3D12_DEVICE_CONFIGURATION_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DEVICE_REMOVED_EXTENDED_DATA {
     pub Flags: D3D12_DRED_FLAGS,
     pub pHeadAutoBreadcrumbNode: *mut D3D12_AUTO_BREADCRUMB_NODE,
@@ -11062,7 +11265,8 @@ impl Default for D3D12_DEVICE_REMOVED_EXTENDED_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DEVICE_REMOVED_EXTENDED_DATA1 {
     pub DeviceRemovedReason: windows_core::HRESULT,
     pub AutoBreadcrumbsOutput: D3D12_DRED_AUTO_BREADCRUMBS_OUTPUT,
@@ -11077,7 +11281,8 @@ impl Default for D3D12_DEVICE_REMOVED_EXTENDED_DATA1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DEVICE_REMOVED_EXTENDED_DATA2 {
     pub DeviceRemovedReason: windows_core::HRESULT,
     pub AutoBreadcrumbsOutput: D3D12_DRED_AUTO_BREADCRUMBS_OUTPUT1,
@@ -11092,7 +11297,8 @@ impl Default for D3D12_DEVICE_REMOVED_EXTENDED_DATA2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DEVICE_REMOVED_EXTENDED_DATA3 {
     pub DeviceRemovedReason: windows_core::HRESULT,
     pub AutoBreadcrumbsOutput: D3D12_DRED_AUTO_BREADCRUMBS_OUTPUT1,
@@ -11108,7 +11314,8 @@ impl Default for D3D12_DEVICE_REMOVED_EXTENDED_DATA3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DISCARD_REGION {
     pub NumRects: u32,
     pub pRects: *const super::super::Foundation::RECT,
@@ -11124,7 +11331,8 @@ impl Default for D3D12_DISCARD_REGION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DISPATCH_ARGUMENTS {
     pub ThreadGroupCountX: u32,
     pub ThreadGroupCountY: u32,
@@ -11169,7 +11377,8 @@ impl Default for D3D12_DISPATCH_GRAPH_DESC_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DISPATCH_MESH_ARGUMENTS {
     pub ThreadGroupCountX: u32,
     pub ThreadGroupCountY: u32,
@@ -11184,7 +11393,8 @@ impl Default for D3D12_DISPATCH_MESH_ARGUMENTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DISPATCH_RAYS_DESC {
     pub RayGenerationShaderRecord: D3D12_GPU_VIRTUAL_ADDRESS_RANGE,
     pub MissShaderTable: D3D12_GPU_VIRTUAL_ADDRESS_RANGE_AND_STRIDE,
@@ -11203,7 +11413,8 @@ impl Default for D3D12_DISPATCH_RAYS_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DRAW_ARGUMENTS {
     pub VertexCountPerInstance: u32,
     pub InstanceCount: u32,
@@ -11219,7 +11430,8 @@ impl Default for D3D12_DRAW_ARGUMENTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DRAW_INDEXED_ARGUMENTS {
     pub IndexCountPerInstance: u32,
     pub InstanceCount: u32,
@@ -11236,7 +11448,8 @@ impl Default for D3D12_DRAW_INDEXED_ARGUMENTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DRED_ALLOCATION_NODE {
     pub ObjectNameA: *const u8,
     pub ObjectNameW: windows_core::PCWSTR,
@@ -11252,7 +11465,8 @@ impl Default for D3D12_DRED_ALLOCATION_NODE {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DRED_ALLOCATION_NODE1 {
     pub ObjectNameA: *const u8,
     pub ObjectNameW: windows_core::PCWSTR,
@@ -11274,7 +11488,8 @@ impl Default for D3D12_DRED_ALLOCATION_NODE1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DRED_AUTO_BREADCRUMBS_OUTPUT {
     pub pHeadAutoBreadcrumbNode: *const D3D12_AUTO_BREADCRUMB_NODE,
 }
@@ -11287,7 +11502,8 @@ impl Default for D3D12_DRED_AUTO_BREADCRUMBS_OUTPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DRED_AUTO_BREADCRUMBS_OUTPUT1 {
     pub pHeadAutoBreadcrumbNode: *const D3D12_AUTO_BREADCRUMB_NODE1,
 }
@@ -11300,7 +11516,8 @@ impl Default for D3D12_DRED_AUTO_BREADCRUMBS_OUTPUT1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DRED_BREADCRUMB_CONTEXT {
     pub BreadcrumbIndex: u32,
     pub pContextString: windows_core::PCWSTR,
@@ -11314,7 +11531,8 @@ impl Default for D3D12_DRED_BREADCRUMB_CONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DRED_PAGE_FAULT_OUTPUT {
     pub PageFaultVA: u64,
     pub pHeadExistingAllocationNode: *const D3D12_DRED_ALLOCATION_NODE,
@@ -11329,7 +11547,8 @@ impl Default for D3D12_DRED_PAGE_FAULT_OUTPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DRED_PAGE_FAULT_OUTPUT1 {
     pub PageFaultVA: u64,
     pub pHeadExistingAllocationNode: *const D3D12_DRED_ALLOCATION_NODE1,
@@ -11344,7 +11563,8 @@ impl Default for D3D12_DRED_PAGE_FAULT_OUTPUT1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DRED_PAGE_FAULT_OUTPUT2 {
     pub PageFaultVA: u64,
     pub pHeadExistingAllocationNode: *const D3D12_DRED_ALLOCATION_NODE1,
@@ -11360,7 +11580,8 @@ impl Default for D3D12_DRED_PAGE_FAULT_OUTPUT2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DXIL_LIBRARY_DESC {
     pub DXILLibrary: D3D12_SHADER_BYTECODE,
     pub NumExports: u32,
@@ -11375,7 +11596,8 @@ impl Default for D3D12_DXIL_LIBRARY_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_DXIL_SUBOBJECT_TO_EXPORTS_ASSOCIATION {
     pub SubobjectToAssociate: windows_core::PCWSTR,
     pub NumExports: u32,
@@ -11390,7 +11612,8 @@ impl Default for D3D12_DXIL_SUBOBJECT_TO_EXPORTS_ASSOCIATION {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_EXISTING_COLLECTION_DESC {
     pub pExistingCollection: core::mem::ManuallyDrop<Option<ID3D12StateObject>>,
     pub NumExports: u32,
@@ -11410,7 +11633,8 @@ impl Default for D3D12_EXISTING_COLLECTION_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_EXPORT_DESC {
     pub Name: windows_core::PCWSTR,
     pub ExportToRename: windows_core::PCWSTR,
@@ -11425,7 +11649,8 @@ impl Default for D3D12_EXPORT_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_ARCHITECTURE {
     pub NodeIndex: u32,
     pub TileBasedRenderer: super::super::Foundation::BOOL,
@@ -11441,7 +11666,8 @@ impl Default for D3D12_FEATURE_DATA_ARCHITECTURE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_ARCHITECTURE1 {
     pub NodeIndex: u32,
     pub TileBasedRenderer: super::super::Foundation::BOOL,
@@ -11458,7 +11684,8 @@ impl Default for D3D12_FEATURE_DATA_ARCHITECTURE1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_COMMAND_QUEUE_PRIORITY {
     pub CommandListType: D3D12_COMMAND_LIST_TYPE,
     pub Priority: u32,
@@ -11473,7 +11700,8 @@ impl Default for D3D12_FEATURE_DATA_COMMAND_QUEUE_PRIORITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_CROSS_NODE {
     pub SharingTier: D3D12_CROSS_NODE_SHARING_TIER,
     pub AtomicShaderInstructions: super::super::Foundation::BOOL,
@@ -11487,7 +11715,8 @@ impl Default for D3D12_FEATURE_DATA_CROSS_NODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS {
     pub DoublePrecisionFloatShaderOps: super::super::Foundation::BOOL,
     pub OutputMergerLogicOp: super::super::Foundation::BOOL,
@@ -11514,7 +11743,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS1 {
     pub WaveOps: super::super::Foundation::BOOL,
     pub WaveLaneCountMin: u32,
@@ -11532,7 +11762,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS10 {
     pub VariableRateShadingSumCombinerSupported: super::super::Foundation::BOOL,
     pub MeshShaderPerPrimitiveShadingRateSupported: super::super::Foundation::BOOL,
@@ -11546,7 +11777,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS10 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS11 {
     pub AtomicInt64OnDescriptorHeapResourceSupported: super::super::Foundation::BOOL,
 }
@@ -11559,7 +11791,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS11 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS12 {
     pub MSPrimitivesPipelineStatisticIncludesCulledPrimitives: D3D12_TRI_STATE,
     pub EnhancedBarriersSupported: super::super::Foundation::BOOL,
@@ -11574,7 +11807,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS12 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS13 {
     pub UnrestrictedBufferTextureCopyPitchSupported: super::super::Foundation::BOOL,
     pub UnrestrictedVertexElementAlignmentSupported: super::super::Foundation::BOOL,
@@ -11592,7 +11826,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS13 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS14 {
     pub AdvancedTextureOpsSupported: super::super::Foundation::BOOL,
     pub WriteableMSAATexturesSupported: super::super::Foundation::BOOL,
@@ -11607,7 +11842,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS14 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS15 {
     pub TriangleFanSupported: super::super::Foundation::BOOL,
     pub DynamicIndexBufferStripCutSupported: super::super::Foundation::BOOL,
@@ -11621,7 +11857,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS15 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS16 {
     pub DynamicDepthBiasSupported: super::super::Foundation::BOOL,
     pub GPUUploadHeapSupported: super::super::Foundation::BOOL,
@@ -11635,7 +11872,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS16 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS17 {
     pub NonNormalizedCoordinateSamplersSupported: super::super::Foundation::BOOL,
     pub ManualWriteTrackingResourceSupported: super::super::Foundation::BOOL,
@@ -11649,7 +11887,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS17 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS18 {
     pub RenderPassesValid: super::super::Foundation::BOOL,
 }
@@ -11662,7 +11901,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS18 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS19 {
     pub MismatchingOutputDimensionsSupported: super::super::Foundation::BOOL,
     pub SupportedSampleCountsWithNoOutputs: u32,
@@ -11684,7 +11924,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS19 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS2 {
     pub DepthBoundsTestSupported: super::super::Foundation::BOOL,
     pub ProgrammableSamplePositionsTier: D3D12_PROGRAMMABLE_SAMPLE_POSITIONS_TIER,
@@ -11698,7 +11939,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS20 {
     pub ComputeOnlyWriteWatchSupported: super::super::Foundation::BOOL,
     pub RecreateAtTier: D3D12_RECREATE_AT_TIER,
@@ -11712,7 +11954,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS20 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS21 {
     pub WorkGraphsTier: D3D12_WORK_GRAPHS_TIER,
     pub ExecuteIndirectTier: D3D12_EXECUTE_INDIRECT_TIER,
@@ -11728,7 +11971,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS21 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS3 {
     pub CopyQueueTimestampQueriesSupported: super::super::Foundation::BOOL,
     pub CastingFullyTypedFormatSupported: super::super::Foundation::BOOL,
@@ -11745,7 +11989,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS4 {
     pub MSAA64KBAlignedTextureSupported: super::super::Foundation::BOOL,
     pub SharedResourceCompatibilityTier: D3D12_SHARED_RESOURCE_COMPATIBILITY_TIER,
@@ -11760,7 +12005,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS4 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS5 {
     pub SRVOnlyTiledResourceTier3: super::super::Foundation::BOOL,
     pub RenderPassesTier: D3D12_RENDER_PASS_TIER,
@@ -11775,7 +12021,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS5 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS6 {
     pub AdditionalShadingRatesSupported: super::super::Foundation::BOOL,
     pub PerPrimitiveShadingRateSupportedWithViewportIndexing: super::super::Foundation::BOOL,
@@ -11792,7 +12039,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS6 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS7 {
     pub MeshShaderTier: D3D12_MESH_SHADER_TIER,
     pub SamplerFeedbackTier: D3D12_SAMPLER_FEEDBACK_TIER,
@@ -11806,7 +12054,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS7 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS8 {
     pub UnalignedBlockTexturesSupported: super::super::Foundation::BOOL,
 }
@@ -11819,7 +12068,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS8 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_D3D12_OPTIONS9 {
     pub MeshShaderPipelineStatsSupported: super::super::Foundation::BOOL,
     pub MeshShaderSupportsFullRangeRenderTargetArrayIndex: super::super::Foundation::BOOL,
@@ -11837,7 +12087,8 @@ impl Default for D3D12_FEATURE_DATA_D3D12_OPTIONS9 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_DISPLAYABLE {
     pub DisplayableTexture: super::super::Foundation::BOOL,
     pub SharedResourceCompatibilityTier: D3D12_SHARED_RESOURCE_COMPATIBILITY_TIER,
@@ -11851,7 +12102,8 @@ impl Default for D3D12_FEATURE_DATA_DISPLAYABLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_EXISTING_HEAPS {
     pub Supported: super::super::Foundation::BOOL,
 }
@@ -11865,7 +12117,8 @@ impl Default for D3D12_FEATURE_DATA_EXISTING_HEAPS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_FEATURE_LEVELS {
     pub NumFeatureLevels: u32,
     pub pFeatureLevelsRequested: *const super::Direct3D::D3D_FEATURE_LEVEL,
@@ -11883,7 +12136,8 @@ impl Default for D3D12_FEATURE_DATA_FEATURE_LEVELS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_FORMAT_INFO {
     pub Format: super::Dxgi::Common::DXGI_FORMAT,
     pub PlaneCount: u8,
@@ -11900,7 +12154,8 @@ impl Default for D3D12_FEATURE_DATA_FORMAT_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_FORMAT_SUPPORT {
     pub Format: super::Dxgi::Common::DXGI_FORMAT,
     pub Support1: D3D12_FORMAT_SUPPORT1,
@@ -11917,7 +12172,8 @@ impl Default for D3D12_FEATURE_DATA_FORMAT_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_GPU_VIRTUAL_ADDRESS_SUPPORT {
     pub MaxGPUVirtualAddressBitsPerResource: u32,
     pub MaxGPUVirtualAddressBitsPerProcess: u32,
@@ -11931,7 +12187,8 @@ impl Default for D3D12_FEATURE_DATA_GPU_VIRTUAL_ADDRESS_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_HARDWARE_COPY {
     pub Supported: super::super::Foundation::BOOL,
 }
@@ -11945,7 +12202,8 @@ impl Default for D3D12_FEATURE_DATA_HARDWARE_COPY {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_MULTISAMPLE_QUALITY_LEVELS {
     pub Format: super::Dxgi::Common::DXGI_FORMAT,
     pub SampleCount: u32,
@@ -11964,7 +12222,8 @@ impl Default for D3D12_FEATURE_DATA_MULTISAMPLE_QUALITY_LEVELS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_PLACED_RESOURCE_SUPPORT_INFO {
     pub Format: super::Dxgi::Common::DXGI_FORMAT,
     pub Dimension: D3D12_RESOURCE_DIMENSION,
@@ -11982,7 +12241,8 @@ impl Default for D3D12_FEATURE_DATA_PLACED_RESOURCE_SUPPORT_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_PREDICATION {
     pub Supported: super::super::Foundation::BOOL,
 }
@@ -11995,7 +12255,8 @@ impl Default for D3D12_FEATURE_DATA_PREDICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_PROTECTED_RESOURCE_SESSION_SUPPORT {
     pub NodeIndex: u32,
     pub Support: D3D12_PROTECTED_RESOURCE_SESSION_SUPPORT_FLAGS,
@@ -12009,7 +12270,8 @@ impl Default for D3D12_FEATURE_DATA_PROTECTED_RESOURCE_SESSION_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_PROTECTED_RESOURCE_SESSION_TYPES {
     pub NodeIndex: u32,
     pub Count: u32,
@@ -12024,7 +12286,8 @@ impl Default for D3D12_FEATURE_DATA_PROTECTED_RESOURCE_SESSION_TYPES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_PROTECTED_RESOURCE_SESSION_TYPE_COUNT {
     pub NodeIndex: u32,
     pub Count: u32,
@@ -12038,7 +12301,8 @@ impl Default for D3D12_FEATURE_DATA_PROTECTED_RESOURCE_SESSION_TYPE_COUNT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_QUERY_META_COMMAND {
     pub CommandId: windows_core::GUID,
     pub NodeMask: u32,
@@ -12056,7 +12320,8 @@ impl Default for D3D12_FEATURE_DATA_QUERY_META_COMMAND {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_ROOT_SIGNATURE {
     pub HighestVersion: D3D_ROOT_SIGNATURE_VERSION,
 }
@@ -12069,7 +12334,8 @@ impl Default for D3D12_FEATURE_DATA_ROOT_SIGNATURE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_SERIALIZATION {
     pub NodeIndex: u32,
     pub HeapSerializationTier: D3D12_HEAP_SERIALIZATION_TIER,
@@ -12083,7 +12349,8 @@ impl Default for D3D12_FEATURE_DATA_SERIALIZATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_SHADER_CACHE {
     pub SupportFlags: D3D12_SHADER_CACHE_SUPPORT_FLAGS,
 }
@@ -12096,7 +12363,8 @@ impl Default for D3D12_FEATURE_DATA_SHADER_CACHE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_SHADER_MODEL {
     pub HighestShaderModel: D3D_SHADER_MODEL,
 }
@@ -12110,7 +12378,8 @@ impl Default for D3D12_FEATURE_DATA_SHADER_MODEL {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FUNCTION_DESC {
     pub Version: u32,
     pub Creator: windows_core::PCSTR,
@@ -12157,7 +12426,8 @@ impl Default for D3D12_FUNCTION_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_GENERIC_PROGRAM_DESC {
     pub ProgramName: windows_core::PCWSTR,
     pub NumExports: u32,
@@ -12174,7 +12444,8 @@ impl Default for D3D12_GENERIC_PROGRAM_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_GLOBAL_BARRIER {
     pub SyncBefore: D3D12_BARRIER_SYNC,
     pub SyncAfter: D3D12_BARRIER_SYNC,
@@ -12190,7 +12461,8 @@ impl Default for D3D12_GLOBAL_BARRIER {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_GLOBAL_ROOT_SIGNATURE {
     pub pGlobalRootSignature: core::mem::ManuallyDrop<Option<ID3D12RootSignature>>,
 }
@@ -12208,7 +12480,8 @@ impl Default for D3D12_GLOBAL_ROOT_SIGNATURE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_GPU_DESCRIPTOR_HANDLE {
     pub ptr: u64,
 }
@@ -12221,7 +12494,8 @@ impl Default for D3D12_GPU_DESCRIPTOR_HANDLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_GPU_VIRTUAL_ADDRESS_AND_STRIDE {
     pub StartAddress: u64,
     pub StrideInBytes: u64,
@@ -12235,7 +12509,8 @@ impl Default for D3D12_GPU_VIRTUAL_ADDRESS_AND_STRIDE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_GPU_VIRTUAL_ADDRESS_RANGE {
     pub StartAddress: u64,
     pub SizeInBytes: u64,
@@ -12249,7 +12524,8 @@ impl Default for D3D12_GPU_VIRTUAL_ADDRESS_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_GPU_VIRTUAL_ADDRESS_RANGE_AND_STRIDE {
     pub StartAddress: u64,
     pub SizeInBytes: u64,
@@ -12265,7 +12541,8 @@ impl Default for D3D12_GPU_VIRTUAL_ADDRESS_RANGE_AND_STRIDE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_GRAPHICS_PIPELINE_STATE_DESC {
     pub pRootSignature: core::mem::ManuallyDrop<Option<ID3D12RootSignature>>,
     pub VS: D3D12_SHADER_BYTECODE,
@@ -12306,7 +12583,8 @@ impl Default for D3D12_GRAPHICS_PIPELINE_STATE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_HEAP_DESC {
     pub SizeInBytes: u64,
     pub Properties: D3D12_HEAP_PROPERTIES,
@@ -12322,7 +12600,8 @@ impl Default for D3D12_HEAP_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_HEAP_PROPERTIES {
     pub Type: D3D12_HEAP_TYPE,
     pub CPUPageProperty: D3D12_CPU_PAGE_PROPERTY,
@@ -12339,7 +12618,8 @@ impl Default for D3D12_HEAP_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_HIT_GROUP_DESC {
     pub HitGroupExport: windows_core::PCWSTR,
     pub Type: D3D12_HIT_GROUP_TYPE,
@@ -12356,7 +12636,8 @@ impl Default for D3D12_HIT_GROUP_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_IB_STRIP_CUT_VALUE {
     pub IndexBufferStripCutValue: D3D12_INDEX_BUFFER_STRIP_CUT_VALUE,
 }
@@ -12370,7 +12651,8 @@ impl Default for D3D12_IB_STRIP_CUT_VALUE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_INDEX_BUFFER_VIEW {
     pub BufferLocation: u64,
     pub SizeInBytes: u32,
@@ -12419,7 +12701,8 @@ impl Default for D3D12_INDIRECT_ARGUMENT_DESC_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_INDIRECT_ARGUMENT_DESC_0_0 {
     pub RootParameterIndex: u32,
 }
@@ -12432,7 +12715,8 @@ impl Default for D3D12_INDIRECT_ARGUMENT_DESC_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_INDIRECT_ARGUMENT_DESC_0_1 {
     pub RootParameterIndex: u32,
     pub DestOffsetIn32BitValues: u32,
@@ -12447,7 +12731,8 @@ impl Default for D3D12_INDIRECT_ARGUMENT_DESC_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_INDIRECT_ARGUMENT_DESC_0_2 {
     pub RootParameterIndex: u32,
     pub DestOffsetIn32BitValues: u32,
@@ -12461,7 +12746,8 @@ impl Default for D3D12_INDIRECT_ARGUMENT_DESC_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_INDIRECT_ARGUMENT_DESC_0_3 {
     pub RootParameterIndex: u32,
 }
@@ -12474,7 +12760,8 @@ impl Default for D3D12_INDIRECT_ARGUMENT_DESC_0_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_INDIRECT_ARGUMENT_DESC_0_4 {
     pub RootParameterIndex: u32,
 }
@@ -12487,7 +12774,8 @@ impl Default for D3D12_INDIRECT_ARGUMENT_DESC_0_4 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_INDIRECT_ARGUMENT_DESC_0_5 {
     pub Slot: u32,
 }
@@ -12500,7 +12788,8 @@ impl Default for D3D12_INDIRECT_ARGUMENT_DESC_0_5 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_INFO_QUEUE_FILTER {
     pub AllowList: D3D12_INFO_QUEUE_FILTER_DESC,
     pub DenyList: D3D12_INFO_QUEUE_FILTER_DESC,
@@ -12514,7 +12803,8 @@ impl Default for D3D12_INFO_QUEUE_FILTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_INFO_QUEUE_FILTER_DESC {
     pub NumCategories: u32,
     pub pCategoryList: *mut D3D12_MESSAGE_CATEGORY,
@@ -12533,7 +12823,8 @@ impl Default for D3D12_INFO_QUEUE_FILTER_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_INPUT_ELEMENT_DESC {
     pub SemanticName: windows_core::PCSTR,
     pub SemanticIndex: u32,
@@ -12555,7 +12846,8 @@ impl Default for D3D12_INPUT_ELEMENT_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_INPUT_LAYOUT_DESC {
     pub pInputElementDescs: *const D3D12_INPUT_ELEMENT_DESC,
     pub NumElements: u32,
@@ -12571,7 +12863,8 @@ impl Default for D3D12_INPUT_LAYOUT_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_LIBRARY_DESC {
     pub Creator: windows_core::PCSTR,
     pub Flags: u32,
@@ -12586,7 +12879,8 @@ impl Default for D3D12_LIBRARY_DESC {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_LOCAL_ROOT_SIGNATURE {
     pub pLocalRootSignature: core::mem::ManuallyDrop<Option<ID3D12RootSignature>>,
 }
@@ -12604,7 +12898,8 @@ impl Default for D3D12_LOCAL_ROOT_SIGNATURE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_MEMCPY_DEST {
     pub pData: *mut core::ffi::c_void,
     pub RowPitch: usize,
@@ -12619,7 +12914,8 @@ impl Default for D3D12_MEMCPY_DEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_MESSAGE {
     pub Category: D3D12_MESSAGE_CATEGORY,
     pub Severity: D3D12_MESSAGE_SEVERITY,
@@ -12636,7 +12932,8 @@ impl Default for D3D12_MESSAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_META_COMMAND_DESC {
     pub Id: windows_core::GUID,
     pub Name: windows_core::PCWSTR,
@@ -12652,7 +12949,8 @@ impl Default for D3D12_META_COMMAND_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_META_COMMAND_PARAMETER_DESC {
     pub Name: windows_core::PCWSTR,
     pub Type: D3D12_META_COMMAND_PARAMETER_TYPE,
@@ -12669,7 +12967,8 @@ impl Default for D3D12_META_COMMAND_PARAMETER_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_MIP_REGION {
     pub Width: u32,
     pub Height: u32,
@@ -12684,7 +12983,8 @@ impl Default for D3D12_MIP_REGION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_MULTI_NODE_CPU_INPUT {
     pub NumNodeInputs: u32,
     pub pNodeInputs: *const D3D12_NODE_CPU_INPUT,
@@ -12699,7 +12999,8 @@ impl Default for D3D12_MULTI_NODE_CPU_INPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_MULTI_NODE_GPU_INPUT {
     pub NumNodeInputs: u32,
     pub NodeInputs: D3D12_GPU_VIRTUAL_ADDRESS_AND_STRIDE,
@@ -12740,7 +13041,8 @@ impl Default for D3D12_NODE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_NODE_CPU_INPUT {
     pub EntrypointIndex: u32,
     pub NumRecords: u32,
@@ -12756,7 +13058,8 @@ impl Default for D3D12_NODE_CPU_INPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_NODE_GPU_INPUT {
     pub EntrypointIndex: u32,
     pub NumRecords: u32,
@@ -12771,7 +13074,8 @@ impl Default for D3D12_NODE_GPU_INPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_NODE_ID {
     pub Name: windows_core::PCWSTR,
     pub ArrayIndex: u32,
@@ -12785,7 +13089,8 @@ impl Default for D3D12_NODE_ID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_NODE_MASK {
     pub NodeMask: u32,
 }
@@ -12798,7 +13103,8 @@ impl Default for D3D12_NODE_MASK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_NODE_OUTPUT_OVERRIDES {
     pub OutputIndex: u32,
     pub pNewName: *const D3D12_NODE_ID,
@@ -12815,7 +13121,8 @@ impl Default for D3D12_NODE_OUTPUT_OVERRIDES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_PACKED_MIP_INFO {
     pub NumStandardMips: u8,
     pub NumPackedMips: u8,
@@ -12832,7 +13139,8 @@ impl Default for D3D12_PACKED_MIP_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_PARAMETER_DESC {
     pub Name: windows_core::PCSTR,
     pub SemanticName: windows_core::PCSTR,
@@ -12858,7 +13166,8 @@ impl Default for D3D12_PARAMETER_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_PIPELINE_STATE_STREAM_DESC {
     pub SizeInBytes: usize,
     pub pPipelineStateSubobjectStream: *mut core::ffi::c_void,
@@ -12873,7 +13182,8 @@ impl Default for D3D12_PIPELINE_STATE_STREAM_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_PLACED_SUBRESOURCE_FOOTPRINT {
     pub Offset: u64,
     pub Footprint: D3D12_SUBRESOURCE_FOOTPRINT,
@@ -12889,7 +13199,8 @@ impl Default for D3D12_PLACED_SUBRESOURCE_FOOTPRINT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_PRIMITIVE_TOPOLOGY_DESC {
     pub PrimitiveTopology: D3D12_PRIMITIVE_TOPOLOGY_TYPE,
 }
@@ -12902,7 +13213,8 @@ impl Default for D3D12_PRIMITIVE_TOPOLOGY_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_PROGRAM_IDENTIFIER {
     pub OpaqueData: [u64; 4],
 }
@@ -12915,7 +13227,8 @@ impl Default for D3D12_PROGRAM_IDENTIFIER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_PROTECTED_RESOURCE_SESSION_DESC {
     pub NodeMask: u32,
     pub Flags: D3D12_PROTECTED_RESOURCE_SESSION_FLAGS,
@@ -12929,7 +13242,8 @@ impl Default for D3D12_PROTECTED_RESOURCE_SESSION_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_PROTECTED_RESOURCE_SESSION_DESC1 {
     pub NodeMask: u32,
     pub Flags: D3D12_PROTECTED_RESOURCE_SESSION_FLAGS,
@@ -12944,7 +13258,8 @@ impl Default for D3D12_PROTECTED_RESOURCE_SESSION_DESC1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_QUERY_DATA_PIPELINE_STATISTICS {
     pub IAVertices: u64,
     pub IAPrimitives: u64,
@@ -12967,7 +13282,8 @@ impl Default for D3D12_QUERY_DATA_PIPELINE_STATISTICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_QUERY_DATA_PIPELINE_STATISTICS1 {
     pub IAVertices: u64,
     pub IAPrimitives: u64,
@@ -12993,7 +13309,8 @@ impl Default for D3D12_QUERY_DATA_PIPELINE_STATISTICS1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_QUERY_DATA_SO_STATISTICS {
     pub NumPrimitivesWritten: u64,
     pub PrimitivesStorageNeeded: u64,
@@ -13007,7 +13324,8 @@ impl Default for D3D12_QUERY_DATA_SO_STATISTICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_QUERY_HEAP_DESC {
     pub Type: D3D12_QUERY_HEAP_TYPE,
     pub Count: u32,
@@ -13022,7 +13340,8 @@ impl Default for D3D12_QUERY_HEAP_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RANGE {
     pub Begin: usize,
     pub End: usize,
@@ -13036,7 +13355,8 @@ impl Default for D3D12_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RANGE_UINT64 {
     pub Begin: u64,
     pub End: u64,
@@ -13050,7 +13370,8 @@ impl Default for D3D12_RANGE_UINT64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RASTERIZER_DESC {
     pub FillMode: D3D12_FILL_MODE,
     pub CullMode: D3D12_CULL_MODE,
@@ -13073,7 +13394,8 @@ impl Default for D3D12_RASTERIZER_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RASTERIZER_DESC1 {
     pub FillMode: D3D12_FILL_MODE,
     pub CullMode: D3D12_CULL_MODE,
@@ -13096,7 +13418,8 @@ impl Default for D3D12_RASTERIZER_DESC1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RASTERIZER_DESC2 {
     pub FillMode: D3D12_FILL_MODE,
     pub CullMode: D3D12_CULL_MODE,
@@ -13118,7 +13441,8 @@ impl Default for D3D12_RASTERIZER_DESC2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RAYTRACING_AABB {
     pub MinX: f32,
     pub MinY: f32,
@@ -13136,7 +13460,8 @@ impl Default for D3D12_RAYTRACING_AABB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_COMPACTED_SIZE_DESC {
     pub CompactedSizeInBytes: u64,
 }
@@ -13149,7 +13474,8 @@ impl Default for D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_COMPACTE
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_CURRENT_SIZE_DESC {
     pub CurrentSizeInBytes: u64,
 }
@@ -13162,7 +13488,8 @@ impl Default for D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_CURRENT_
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_DESC {
     pub DestBuffer: u64,
     pub InfoType: D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_TYPE,
@@ -13176,7 +13503,8 @@ impl Default for D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_SERIALIZATION_DESC {
     pub SerializedSizeInBytes: u64,
     pub NumBottomLevelAccelerationStructurePointers: u64,
@@ -13190,7 +13518,8 @@ impl Default for D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_SERIALIZ
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_TOOLS_VISUALIZATION_DESC {
     pub DecodedSizeInBytes: u64,
 }
@@ -13203,7 +13532,8 @@ impl Default for D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_TOOLS_VI
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RAYTRACING_ACCELERATION_STRUCTURE_PREBUILD_INFO {
     pub ResultDataMaxSizeInBytes: u64,
     pub ScratchDataSizeInBytes: u64,
@@ -13218,7 +13548,8 @@ impl Default for D3D12_RAYTRACING_ACCELERATION_STRUCTURE_PREBUILD_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RAYTRACING_ACCELERATION_STRUCTURE_SRV {
     pub Location: u64,
 }
@@ -13231,7 +13562,8 @@ impl Default for D3D12_RAYTRACING_ACCELERATION_STRUCTURE_SRV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RAYTRACING_GEOMETRY_AABBS_DESC {
     pub AABBCount: u64,
     pub AABBs: D3D12_GPU_VIRTUAL_ADDRESS_AND_STRIDE,
@@ -13281,7 +13613,8 @@ impl Default for D3D12_RAYTRACING_GEOMETRY_DESC_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RAYTRACING_GEOMETRY_TRIANGLES_DESC {
     pub Transform3x4: u64,
     pub IndexFormat: super::Dxgi::Common::DXGI_FORMAT,
@@ -13302,13 +13635,40 @@ impl Default for D3D12_RAYTRACING_GEOMETRY_TRIANGLES_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RAYTRACING_INSTANCE_DESC {
     pub Transform: [f32; 12],
     pub _bitfield1: u32,
     pub _bitfield2: u32,
     pub AccelerationStructure: u64,
 }
+impl D3D12_RAYTRACING_INSTANCE_DESC {
+    pub const fn InstanceID(&self) -> u32 {
+        self._bitfield1 & 16777215
+    }
+    pub fn set_InstanceID(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !16777215) | (value & 16777215);
+    }
+    pub const fn InstanceMask(&self) -> u32 {
+        (self._bitfield1 >> 24) & 255
+    }
+    pub fn set_InstanceMask(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(255 << 24)) | ((value & 255) << 24);
+    }
+    pub const fn InstanceContributionToHitGroupIndex(&self) -> u32 {
+        self._bitfield2 & 16777215
+    }
+    pub fn set_InstanceContributionToHitGroupIndex(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !16777215) | (value & 16777215);
+    }
+    pub const fn Flags(&self) -> u32 {
+        (self._bitfield2 >> 24) & 255
+    }
+    pub fn set_Flags(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for D3D12_RAYTRACING_INSTANCE_DESC {
     type TypeKind = windows_core::CopyType;
 }
@@ -13318,7 +13678,8 @@ impl Default for D3D12_RAYTRACING_INSTANCE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RAYTRACING_PIPELINE_CONFIG {
     pub MaxTraceRecursionDepth: u32,
 }
@@ -13331,7 +13692,8 @@ impl Default for D3D12_RAYTRACING_PIPELINE_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RAYTRACING_PIPELINE_CONFIG1 {
     pub MaxTraceRecursionDepth: u32,
     pub Flags: D3D12_RAYTRACING_PIPELINE_FLAGS,
@@ -13345,7 +13707,8 @@ impl Default for D3D12_RAYTRACING_PIPELINE_CONFIG1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RAYTRACING_SHADER_CONFIG {
     pub MaxPayloadSizeInBytes: u32,
     pub MaxAttributeSizeInBytes: u32,
@@ -13409,7 +13772,8 @@ impl Default for D3D12_RENDER_PASS_BEGINNING_ACCESS_CLEAR_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RENDER_PASS_BEGINNING_ACCESS_PRESERVE_LOCAL_PARAMETERS {
     pub AdditionalWidth: u32,
     pub AdditionalHeight: u32,
@@ -13492,7 +13856,8 @@ impl Default for D3D12_RENDER_PASS_ENDING_ACCESS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RENDER_PASS_ENDING_ACCESS_PRESERVE_LOCAL_PARAMETERS {
     pub AdditionalWidth: u32,
     pub AdditionalHeight: u32,
@@ -13507,7 +13872,8 @@ impl Default for D3D12_RENDER_PASS_ENDING_ACCESS_PRESERVE_LOCAL_PARAMETERS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RENDER_PASS_ENDING_ACCESS_RESOLVE_PARAMETERS {
     pub pSrcResource: core::mem::ManuallyDrop<Option<ID3D12Resource>>,
     pub pDstResource: core::mem::ManuallyDrop<Option<ID3D12Resource>>,
@@ -13534,7 +13900,8 @@ impl Default for D3D12_RENDER_PASS_ENDING_ACCESS_RESOLVE_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RENDER_PASS_ENDING_ACCESS_RESOLVE_SUBRESOURCE_PARAMETERS {
     pub SrcSubresource: u32,
     pub DstSubresource: u32,
@@ -13574,7 +13941,8 @@ impl Default for D3D12_RENDER_PASS_RENDER_TARGET_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RENDER_TARGET_BLEND_DESC {
     pub BlendEnable: super::super::Foundation::BOOL,
     pub LogicOpEnable: super::super::Foundation::BOOL,
@@ -13637,7 +14005,8 @@ impl Default for D3D12_RENDER_TARGET_VIEW_DESC_0 {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RESOURCE_ALIASING_BARRIER {
     pub pResourceBefore: core::mem::ManuallyDrop<Option<ID3D12Resource>>,
     pub pResourceAfter: core::mem::ManuallyDrop<Option<ID3D12Resource>>,
@@ -13656,7 +14025,8 @@ impl Default for D3D12_RESOURCE_ALIASING_BARRIER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RESOURCE_ALLOCATION_INFO {
     pub SizeInBytes: u64,
     pub Alignment: u64,
@@ -13670,7 +14040,8 @@ impl Default for D3D12_RESOURCE_ALLOCATION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RESOURCE_ALLOCATION_INFO1 {
     pub Offset: u64,
     pub Alignment: u64,
@@ -13724,7 +14095,8 @@ impl Default for D3D12_RESOURCE_BARRIER_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RESOURCE_DESC {
     pub Dimension: D3D12_RESOURCE_DIMENSION,
     pub Alignment: u64,
@@ -13749,7 +14121,8 @@ impl Default for D3D12_RESOURCE_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RESOURCE_DESC1 {
     pub Dimension: D3D12_RESOURCE_DIMENSION,
     pub Alignment: u64,
@@ -13774,7 +14147,8 @@ impl Default for D3D12_RESOURCE_DESC1 {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RESOURCE_TRANSITION_BARRIER {
     pub pResource: core::mem::ManuallyDrop<Option<ID3D12Resource>>,
     pub Subresource: u32,
@@ -13795,7 +14169,8 @@ impl Default for D3D12_RESOURCE_TRANSITION_BARRIER {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RESOURCE_UAV_BARRIER {
     pub pResource: core::mem::ManuallyDrop<Option<ID3D12Resource>>,
 }
@@ -13813,7 +14188,8 @@ impl Default for D3D12_RESOURCE_UAV_BARRIER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_ROOT_CONSTANTS {
     pub ShaderRegister: u32,
     pub RegisterSpace: u32,
@@ -13828,7 +14204,8 @@ impl Default for D3D12_ROOT_CONSTANTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_ROOT_DESCRIPTOR {
     pub ShaderRegister: u32,
     pub RegisterSpace: u32,
@@ -13842,7 +14219,8 @@ impl Default for D3D12_ROOT_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_ROOT_DESCRIPTOR1 {
     pub ShaderRegister: u32,
     pub RegisterSpace: u32,
@@ -13857,7 +14235,8 @@ impl Default for D3D12_ROOT_DESCRIPTOR1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_ROOT_DESCRIPTOR_TABLE {
     pub NumDescriptorRanges: u32,
     pub pDescriptorRanges: *const D3D12_DESCRIPTOR_RANGE,
@@ -13871,7 +14250,8 @@ impl Default for D3D12_ROOT_DESCRIPTOR_TABLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_ROOT_DESCRIPTOR_TABLE1 {
     pub NumDescriptorRanges: u32,
     pub pDescriptorRanges: *const D3D12_DESCRIPTOR_RANGE1,
@@ -13945,7 +14325,8 @@ impl Default for D3D12_ROOT_PARAMETER1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_ROOT_SIGNATURE_DESC {
     pub NumParameters: u32,
     pub pParameters: *const D3D12_ROOT_PARAMETER,
@@ -13962,7 +14343,8 @@ impl Default for D3D12_ROOT_SIGNATURE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_ROOT_SIGNATURE_DESC1 {
     pub NumParameters: u32,
     pub pParameters: *const D3D12_ROOT_PARAMETER1,
@@ -13979,7 +14361,8 @@ impl Default for D3D12_ROOT_SIGNATURE_DESC1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_ROOT_SIGNATURE_DESC2 {
     pub NumParameters: u32,
     pub pParameters: *const D3D12_ROOT_PARAMETER1,
@@ -13997,7 +14380,8 @@ impl Default for D3D12_ROOT_SIGNATURE_DESC2 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RT_FORMAT_ARRAY {
     pub RTFormats: [super::Dxgi::Common::DXGI_FORMAT; 8],
     pub NumRenderTargets: u32,
@@ -14013,7 +14397,8 @@ impl Default for D3D12_RT_FORMAT_ARRAY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SAMPLER_DESC {
     pub Filter: D3D12_FILTER,
     pub AddressU: D3D12_TEXTURE_ADDRESS_MODE,
@@ -14072,7 +14457,8 @@ impl Default for D3D12_SAMPLER_DESC2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SAMPLE_MASK {
     pub SampleMask: u32,
 }
@@ -14085,7 +14471,8 @@ impl Default for D3D12_SAMPLE_MASK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SAMPLE_POSITION {
     pub X: i8,
     pub Y: i8,
@@ -14099,7 +14486,8 @@ impl Default for D3D12_SAMPLE_POSITION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SERIALIZED_DATA_DRIVER_MATCHING_IDENTIFIER {
     pub DriverOpaqueGUID: windows_core::GUID,
     pub DriverOpaqueVersioningData: [u8; 16],
@@ -14113,7 +14501,8 @@ impl Default for D3D12_SERIALIZED_DATA_DRIVER_MATCHING_IDENTIFIER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SERIALIZED_RAYTRACING_ACCELERATION_STRUCTURE_HEADER {
     pub DriverMatchingIdentifier: D3D12_SERIALIZED_DATA_DRIVER_MATCHING_IDENTIFIER,
     pub SerializedSizeInBytesIncludingHeader: u64,
@@ -14129,7 +14518,8 @@ impl Default for D3D12_SERIALIZED_RAYTRACING_ACCELERATION_STRUCTURE_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SET_GENERIC_PIPELINE_DESC {
     pub ProgramIdentifier: D3D12_PROGRAM_IDENTIFIER,
 }
@@ -14171,7 +14561,8 @@ impl Default for D3D12_SET_PROGRAM_DESC_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SET_RAYTRACING_PIPELINE_DESC {
     pub ProgramIdentifier: D3D12_PROGRAM_IDENTIFIER,
 }
@@ -14184,7 +14575,8 @@ impl Default for D3D12_SET_RAYTRACING_PIPELINE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SET_WORK_GRAPH_DESC {
     pub ProgramIdentifier: D3D12_PROGRAM_IDENTIFIER,
     pub Flags: D3D12_SET_WORK_GRAPH_FLAGS,
@@ -14201,7 +14593,8 @@ impl Default for D3D12_SET_WORK_GRAPH_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SHADER_BUFFER_DESC {
     pub Name: windows_core::PCSTR,
     pub Type: super::Direct3D::D3D_CBUFFER_TYPE,
@@ -14220,7 +14613,8 @@ impl Default for D3D12_SHADER_BUFFER_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SHADER_BYTECODE {
     pub pShaderBytecode: *const core::ffi::c_void,
     pub BytecodeLength: usize,
@@ -14234,7 +14628,8 @@ impl Default for D3D12_SHADER_BYTECODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SHADER_CACHE_SESSION_DESC {
     pub Identifier: windows_core::GUID,
     pub Mode: D3D12_SHADER_CACHE_MODE,
@@ -14254,7 +14649,8 @@ impl Default for D3D12_SHADER_CACHE_SESSION_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SHADER_DESC {
     pub Version: u32,
     pub Creator: windows_core::PCSTR,
@@ -14307,7 +14703,8 @@ impl Default for D3D12_SHADER_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SHADER_INPUT_BIND_DESC {
     pub Name: windows_core::PCSTR,
     pub Type: super::Direct3D::D3D_SHADER_INPUT_TYPE,
@@ -14408,7 +14805,8 @@ impl Default for D3D12_SHADER_RESOURCE_VIEW_DESC_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SHADER_TYPE_DESC {
     pub Class: super::Direct3D::D3D_SHADER_VARIABLE_CLASS,
     pub Type: super::Direct3D::D3D_SHADER_VARIABLE_TYPE,
@@ -14430,7 +14828,8 @@ impl Default for D3D12_SHADER_TYPE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SHADER_VARIABLE_DESC {
     pub Name: windows_core::PCSTR,
     pub StartOffset: u32,
@@ -14452,7 +14851,8 @@ impl Default for D3D12_SHADER_VARIABLE_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SIGNATURE_PARAMETER_DESC {
     pub SemanticName: windows_core::PCSTR,
     pub SemanticIndex: u32,
@@ -14475,7 +14875,8 @@ impl Default for D3D12_SIGNATURE_PARAMETER_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SO_DECLARATION_ENTRY {
     pub Stream: u32,
     pub SemanticName: windows_core::PCSTR,
@@ -14493,7 +14894,8 @@ impl Default for D3D12_SO_DECLARATION_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_STATE_OBJECT_CONFIG {
     pub Flags: D3D12_STATE_OBJECT_FLAGS,
 }
@@ -14506,7 +14908,8 @@ impl Default for D3D12_STATE_OBJECT_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_STATE_OBJECT_DESC {
     pub Type: D3D12_STATE_OBJECT_TYPE,
     pub NumSubobjects: u32,
@@ -14521,7 +14924,8 @@ impl Default for D3D12_STATE_OBJECT_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_STATE_SUBOBJECT {
     pub Type: D3D12_STATE_SUBOBJECT_TYPE,
     pub pDesc: *const core::ffi::c_void,
@@ -14535,7 +14939,8 @@ impl Default for D3D12_STATE_SUBOBJECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_STATIC_SAMPLER_DESC {
     pub Filter: D3D12_FILTER,
     pub AddressU: D3D12_TEXTURE_ADDRESS_MODE,
@@ -14560,7 +14965,8 @@ impl Default for D3D12_STATIC_SAMPLER_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_STATIC_SAMPLER_DESC1 {
     pub Filter: D3D12_FILTER,
     pub AddressU: D3D12_TEXTURE_ADDRESS_MODE,
@@ -14586,7 +14992,8 @@ impl Default for D3D12_STATIC_SAMPLER_DESC1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_STREAM_OUTPUT_BUFFER_VIEW {
     pub BufferLocation: u64,
     pub SizeInBytes: u64,
@@ -14601,7 +15008,8 @@ impl Default for D3D12_STREAM_OUTPUT_BUFFER_VIEW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_STREAM_OUTPUT_DESC {
     pub pSODeclaration: *const D3D12_SO_DECLARATION_ENTRY,
     pub NumEntries: u32,
@@ -14618,7 +15026,8 @@ impl Default for D3D12_STREAM_OUTPUT_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SUBOBJECT_TO_EXPORTS_ASSOCIATION {
     pub pSubobjectToAssociate: *const D3D12_STATE_SUBOBJECT,
     pub NumExports: u32,
@@ -14633,7 +15042,8 @@ impl Default for D3D12_SUBOBJECT_TO_EXPORTS_ASSOCIATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SUBRESOURCE_DATA {
     pub pData: *const core::ffi::c_void,
     pub RowPitch: isize,
@@ -14649,7 +15059,8 @@ impl Default for D3D12_SUBRESOURCE_DATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SUBRESOURCE_FOOTPRINT {
     pub Format: super::Dxgi::Common::DXGI_FORMAT,
     pub Width: u32,
@@ -14668,7 +15079,8 @@ impl Default for D3D12_SUBRESOURCE_FOOTPRINT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SUBRESOURCE_INFO {
     pub Offset: u64,
     pub RowPitch: u32,
@@ -14683,7 +15095,8 @@ impl Default for D3D12_SUBRESOURCE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SUBRESOURCE_RANGE_UINT64 {
     pub Subresource: u32,
     pub Range: D3D12_RANGE_UINT64,
@@ -14697,7 +15110,8 @@ impl Default for D3D12_SUBRESOURCE_RANGE_UINT64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_SUBRESOURCE_TILING {
     pub WidthInTiles: u32,
     pub HeightInTiles: u16,
@@ -14713,7 +15127,8 @@ impl Default for D3D12_SUBRESOURCE_TILING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX1D_ARRAY_DSV {
     pub MipSlice: u32,
     pub FirstArraySlice: u32,
@@ -14728,7 +15143,8 @@ impl Default for D3D12_TEX1D_ARRAY_DSV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX1D_ARRAY_RTV {
     pub MipSlice: u32,
     pub FirstArraySlice: u32,
@@ -14743,7 +15159,8 @@ impl Default for D3D12_TEX1D_ARRAY_RTV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX1D_ARRAY_SRV {
     pub MostDetailedMip: u32,
     pub MipLevels: u32,
@@ -14760,7 +15177,8 @@ impl Default for D3D12_TEX1D_ARRAY_SRV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX1D_ARRAY_UAV {
     pub MipSlice: u32,
     pub FirstArraySlice: u32,
@@ -14775,7 +15193,8 @@ impl Default for D3D12_TEX1D_ARRAY_UAV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX1D_DSV {
     pub MipSlice: u32,
 }
@@ -14788,7 +15207,8 @@ impl Default for D3D12_TEX1D_DSV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX1D_RTV {
     pub MipSlice: u32,
 }
@@ -14801,7 +15221,8 @@ impl Default for D3D12_TEX1D_RTV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX1D_SRV {
     pub MostDetailedMip: u32,
     pub MipLevels: u32,
@@ -14816,7 +15237,8 @@ impl Default for D3D12_TEX1D_SRV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX1D_UAV {
     pub MipSlice: u32,
 }
@@ -14829,7 +15251,8 @@ impl Default for D3D12_TEX1D_UAV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2DMS_ARRAY_DSV {
     pub FirstArraySlice: u32,
     pub ArraySize: u32,
@@ -14843,7 +15266,8 @@ impl Default for D3D12_TEX2DMS_ARRAY_DSV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2DMS_ARRAY_RTV {
     pub FirstArraySlice: u32,
     pub ArraySize: u32,
@@ -14857,7 +15281,8 @@ impl Default for D3D12_TEX2DMS_ARRAY_RTV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2DMS_ARRAY_SRV {
     pub FirstArraySlice: u32,
     pub ArraySize: u32,
@@ -14871,7 +15296,8 @@ impl Default for D3D12_TEX2DMS_ARRAY_SRV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2DMS_ARRAY_UAV {
     pub FirstArraySlice: u32,
     pub ArraySize: u32,
@@ -14885,7 +15311,8 @@ impl Default for D3D12_TEX2DMS_ARRAY_UAV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2DMS_DSV {
     pub UnusedField_NothingToDefine: u32,
 }
@@ -14898,7 +15325,8 @@ impl Default for D3D12_TEX2DMS_DSV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2DMS_RTV {
     pub UnusedField_NothingToDefine: u32,
 }
@@ -14911,7 +15339,8 @@ impl Default for D3D12_TEX2DMS_RTV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2DMS_SRV {
     pub UnusedField_NothingToDefine: u32,
 }
@@ -14924,7 +15353,8 @@ impl Default for D3D12_TEX2DMS_SRV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2DMS_UAV {
     pub UnusedField_NothingToDefine: u32,
 }
@@ -14937,7 +15367,8 @@ impl Default for D3D12_TEX2DMS_UAV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2D_ARRAY_DSV {
     pub MipSlice: u32,
     pub FirstArraySlice: u32,
@@ -14952,7 +15383,8 @@ impl Default for D3D12_TEX2D_ARRAY_DSV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2D_ARRAY_RTV {
     pub MipSlice: u32,
     pub FirstArraySlice: u32,
@@ -14968,7 +15400,8 @@ impl Default for D3D12_TEX2D_ARRAY_RTV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2D_ARRAY_SRV {
     pub MostDetailedMip: u32,
     pub MipLevels: u32,
@@ -14986,7 +15419,8 @@ impl Default for D3D12_TEX2D_ARRAY_SRV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2D_ARRAY_UAV {
     pub MipSlice: u32,
     pub FirstArraySlice: u32,
@@ -15002,7 +15436,8 @@ impl Default for D3D12_TEX2D_ARRAY_UAV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2D_DSV {
     pub MipSlice: u32,
 }
@@ -15015,7 +15450,8 @@ impl Default for D3D12_TEX2D_DSV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2D_RTV {
     pub MipSlice: u32,
     pub PlaneSlice: u32,
@@ -15029,7 +15465,8 @@ impl Default for D3D12_TEX2D_RTV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2D_SRV {
     pub MostDetailedMip: u32,
     pub MipLevels: u32,
@@ -15045,7 +15482,8 @@ impl Default for D3D12_TEX2D_SRV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX2D_UAV {
     pub MipSlice: u32,
     pub PlaneSlice: u32,
@@ -15059,7 +15497,8 @@ impl Default for D3D12_TEX2D_UAV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX3D_RTV {
     pub MipSlice: u32,
     pub FirstWSlice: u32,
@@ -15074,7 +15513,8 @@ impl Default for D3D12_TEX3D_RTV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX3D_SRV {
     pub MostDetailedMip: u32,
     pub MipLevels: u32,
@@ -15089,7 +15529,8 @@ impl Default for D3D12_TEX3D_SRV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEX3D_UAV {
     pub MipSlice: u32,
     pub FirstWSlice: u32,
@@ -15104,7 +15545,8 @@ impl Default for D3D12_TEX3D_UAV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEXCUBE_ARRAY_SRV {
     pub MostDetailedMip: u32,
     pub MipLevels: u32,
@@ -15121,7 +15563,8 @@ impl Default for D3D12_TEXCUBE_ARRAY_SRV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEXCUBE_SRV {
     pub MostDetailedMip: u32,
     pub MipLevels: u32,
@@ -15136,7 +15579,8 @@ impl Default for D3D12_TEXCUBE_SRV {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TEXTURE_BARRIER {
     pub SyncBefore: D3D12_BARRIER_SYNC,
     pub SyncAfter: D3D12_BARRIER_SYNC,
@@ -15202,7 +15646,8 @@ impl Default for D3D12_TEXTURE_COPY_LOCATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_THREAD_LAUNCH_OVERRIDES {
     pub pLocalRootArgumentsTableIndex: *const u32,
     pub pProgramEntry: *const super::super::Foundation::BOOL,
@@ -15220,7 +15665,8 @@ impl Default for D3D12_THREAD_LAUNCH_OVERRIDES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TILED_RESOURCE_COORDINATE {
     pub X: u32,
     pub Y: u32,
@@ -15236,7 +15682,8 @@ impl Default for D3D12_TILED_RESOURCE_COORDINATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TILE_REGION_SIZE {
     pub NumTiles: u32,
     pub UseBox: super::super::Foundation::BOOL,
@@ -15253,7 +15700,8 @@ impl Default for D3D12_TILE_REGION_SIZE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_TILE_SHAPE {
     pub WidthInTexels: u32,
     pub HeightInTexels: u32,
@@ -15368,7 +15816,8 @@ impl Default for D3D12_VERSIONED_ROOT_SIGNATURE_DESC_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VERTEX_BUFFER_VIEW {
     pub BufferLocation: u64,
     pub SizeInBytes: u32,
@@ -15383,7 +15832,8 @@ impl Default for D3D12_VERTEX_BUFFER_VIEW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIEWPORT {
     pub TopLeftX: f32,
     pub TopLeftY: f32,
@@ -15401,7 +15851,8 @@ impl Default for D3D12_VIEWPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIEW_INSTANCE_LOCATION {
     pub ViewportArrayIndex: u32,
     pub RenderTargetArrayIndex: u32,
@@ -15415,7 +15866,8 @@ impl Default for D3D12_VIEW_INSTANCE_LOCATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIEW_INSTANCING_DESC {
     pub ViewInstanceCount: u32,
     pub pViewInstanceLocations: *const D3D12_VIEW_INSTANCE_LOCATION,
@@ -15430,7 +15882,8 @@ impl Default for D3D12_VIEW_INSTANCING_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_WORK_GRAPH_DESC {
     pub ProgramName: windows_core::PCWSTR,
     pub Flags: D3D12_WORK_GRAPH_FLAGS,
@@ -15448,7 +15901,8 @@ impl Default for D3D12_WORK_GRAPH_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_WORK_GRAPH_MEMORY_REQUIREMENTS {
     pub MinSizeInBytes: u64,
     pub MaxSizeInBytes: u64,
@@ -15463,7 +15917,8 @@ impl Default for D3D12_WORK_GRAPH_MEMORY_REQUIREMENTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_WRITEBUFFERIMMEDIATE_PARAMETER {
     pub Dest: u64,
     pub Value: u32,
@@ -15489,3 +15944,5 @@ pub type PFN_D3D12_SERIALIZE_ROOT_SIGNATURE = Option<unsafe extern "system" fn(p
 pub type PFN_D3D12_SERIALIZE_VERSIONED_ROOT_SIGNATURE = Option<unsafe extern "system" fn(prootsignature: *const D3D12_VERSIONED_ROOT_SIGNATURE_DESC, ppblob: *mut Option<super::Direct3D::ID3DBlob>, pperrorblob: *mut Option<super::Direct3D::ID3DBlob>) -> windows_core::HRESULT>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");